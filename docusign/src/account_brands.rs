@@ -1,6 +1,192 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`AccountBrands::brands_get`], set with a builder.
+pub struct AccountBrandsBrandsGetRequest<'a> {
+    client: &'a AccountBrands,
+    account_id: &'a str,
+    exclude_distributor_brand: Option<&'a str>,
+    include_logos: Option<&'a str>,
+}
+
+impl<'a> AccountBrandsBrandsGetRequest<'a> {
+    /// Sets the `exclude_distributor_brand` query parameter.
+    pub fn exclude_distributor_brand(mut self, exclude_distributor_brand: &'a str) -> Self {
+        self.exclude_distributor_brand = Some(exclude_distributor_brand);
+        self
+    }
+
+    /// Sets the `include_logos` query parameter.
+    pub fn include_logos(mut self, include_logos: &'a str) -> Self {
+        self.include_logos = Some(include_logos);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::AccountBrands>> {
+        let Self {
+            client,
+            account_id,
+            exclude_distributor_brand,
+            include_logos,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = exclude_distributor_brand {
+            query_args.push(("exclude_distributor_brand".to_string(), v.to_string()));
+        }
+        if let Some(v) = include_logos {
+            query_args.push(("include_logos".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v2.1/accounts/{}/brands?{}",
+                crate::progenitor_support::encode_path(account_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`AccountBrands::brand_get`], set with a builder.
+pub struct AccountBrandsBrandGetRequest<'a> {
+    client: &'a AccountBrands,
+    account_id: &'a str,
+    brand_id: &'a str,
+    include_external_references: Option<&'a str>,
+    include_logos: Option<&'a str>,
+}
+
+impl<'a> AccountBrandsBrandGetRequest<'a> {
+    /// Sets the `include_external_references` query parameter.
+    pub fn include_external_references(mut self, include_external_references: &'a str) -> Self {
+        self.include_external_references = Some(include_external_references);
+        self
+    }
+
+    /// Sets the `include_logos` query parameter.
+    pub fn include_logos(mut self, include_logos: &'a str) -> Self {
+        self.include_logos = Some(include_logos);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::Brand>> {
+        let Self {
+            client,
+            account_id,
+            brand_id,
+            include_external_references,
+            include_logos,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = include_external_references {
+            query_args.push(("include_external_references".to_string(), v.to_string()));
+        }
+        if let Some(v) = include_logos {
+            query_args.push(("include_logos".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v2.1/accounts/{}/brands/{}?{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(brand_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`AccountBrands::brand_resources_get`], set with a builder.
+pub struct AccountBrandsBrandResourcesGetRequest<'a> {
+    client: &'a AccountBrands,
+    account_id: &'a str,
+    brand_id: &'a str,
+    resource_content_type: &'a str,
+    langcode: Option<&'a str>,
+    return_master: Option<&'a str>,
+}
+
+impl<'a> AccountBrandsBrandResourcesGetRequest<'a> {
+    /// Sets the `langcode` query parameter.
+    pub fn langcode(mut self, langcode: &'a str) -> Self {
+        self.langcode = Some(langcode);
+        self
+    }
+
+    /// Sets the `return_master` query parameter.
+    pub fn return_master(mut self, return_master: &'a str) -> Self {
+        self.return_master = Some(return_master);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            account_id,
+            brand_id,
+            resource_content_type,
+            langcode,
+            return_master,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = langcode {
+            query_args.push(("langcode".to_string(), v.to_string()));
+        }
+        if let Some(v) = return_master {
+            query_args.push(("return_master".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v2.1/accounts/{}/brands/{}/resources/{}?{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(brand_id),
+                crate::progenitor_support::encode_path(resource_content_type),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct AccountBrands {
     pub client: Client,
 }
@@ -61,6 +247,28 @@ impl AccountBrands {
             )
             .await
     }
+    /**
+     * Gets a list of brands.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/brands` endpoint.
+     *
+     * As opposed to `brands_get`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * This method returns details about all of the brands associated with an account, including the default brand profiles.
+     *
+     * **Note**: Branding for either signing or sending must be enabled for the account (`canSelfBrandSend` , `canSelfBrandSign`, or both of these account settings must be **true**).
+     */
+    pub fn brands_get_request<'a>(
+        &'a self,
+        account_id: &'a str,
+    ) -> AccountBrandsBrandsGetRequest<'a> {
+        AccountBrandsBrandsGetRequest {
+            client: self,
+            account_id,
+            exclude_distributor_brand: None,
+            include_logos: None,
+        }
+    }
     /**
      * Creates one or more brand profiles for an account.
      *
@@ -188,6 +396,30 @@ impl AccountBrands {
             )
             .await
     }
+    /**
+     * Gets information about a brand.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/brands/{brandId}` endpoint.
+     *
+     * As opposed to `brand_get`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * This method returns details about an account brand.
+     *
+     * **Note**: Branding for either signing or sending must be enabled for the account (`canSelfBrandSend` , `canSelfBrandSign`, or both of these account settings must be **true**).
+     */
+    pub fn brand_get_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        brand_id: &'a str,
+    ) -> AccountBrandsBrandGetRequest<'a> {
+        AccountBrandsBrandGetRequest {
+            client: self,
+            account_id,
+            brand_id,
+            include_external_references: None,
+            include_logos: None,
+        }
+    }
     /**
      * Updates an existing brand.
      *
@@ -524,6 +756,36 @@ impl AccountBrands {
             )
             .await
     }
+    /**
+     * Returns a branding resource file.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/brands/{brandId}/resources/{resourceContentType}` endpoint.
+     *
+     * As opposed to `brand_resources_get`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * This method returns a specific branding resource file.
+     *
+     * A brand uses a set of brand resource files to control the sending, signing, email message, and captive (embedded) signing experiences.  You can modify the default email messages and formats in these files and upload them to your brand to customize the user experience.
+     *
+     * **Important**: When you upload a modified resource file, only the elements that differ from the master resource file are saved as your resource file. Similarly, when you download your resource files, only the modified elements are included in the file.
+     *
+     * **Note**: Branding for either signing or sending must be enabled for the account (`canSelfBrandSend` , `canSelfBrandSign`, or both of these account settings must be **true**).
+     */
+    pub fn brand_resources_get_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        brand_id: &'a str,
+        resource_content_type: &'a str,
+    ) -> AccountBrandsBrandResourcesGetRequest<'a> {
+        AccountBrandsBrandResourcesGetRequest {
+            client: self,
+            account_id,
+            brand_id,
+            resource_content_type,
+            langcode: None,
+            return_master: None,
+        }
+    }
     /**
      * Updates a branding resource file.
      *
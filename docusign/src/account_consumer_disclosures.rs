@@ -1,6 +1,109 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`AccountConsumerDisclosures::consumer_disclosure_get`], set with a builder.
+pub struct AccountConsumerDisclosuresConsumerDisclosureGetRequest<'a> {
+    client: &'a AccountConsumerDisclosures,
+    account_id: &'a str,
+    lang_code: Option<&'a str>,
+}
+
+impl<'a> AccountConsumerDisclosuresConsumerDisclosureGetRequest<'a> {
+    /// Sets the `langCode` query parameter.
+    pub fn lang_code(mut self, lang_code: &'a str) -> Self {
+        self.lang_code = Some(lang_code);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::AccountConsumerDisclosures>> {
+        let Self {
+            client,
+            account_id,
+            lang_code,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = lang_code {
+            query_args.push(("langCode".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v2.1/accounts/{}/consumer_disclosure?{}",
+                crate::progenitor_support::encode_path(account_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`AccountConsumerDisclosures::consumer_disclosure_put`], set with a builder.
+pub struct AccountConsumerDisclosuresConsumerDisclosurePutRequest<'a> {
+    client: &'a AccountConsumerDisclosures,
+    account_id: &'a str,
+    lang_code: &'a str,
+    include_metadata: Option<&'a str>,
+    body: &'a crate::types::ConsumerDisclosure,
+}
+
+impl<'a> AccountConsumerDisclosuresConsumerDisclosurePutRequest<'a> {
+    /// Sets the `include_metadata` query parameter.
+    pub fn include_metadata(mut self, include_metadata: &'a str) -> Self {
+        self.include_metadata = Some(include_metadata);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::ConsumerDisclosure>> {
+        let Self {
+            client,
+            account_id,
+            lang_code,
+            include_metadata,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = include_metadata {
+            query_args.push(("include_metadata".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v2.1/accounts/{}/consumer_disclosure/{}?{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(lang_code),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .put(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
 pub struct AccountConsumerDisclosures {
     pub client: Client,
 }
@@ -101,6 +204,29 @@ impl AccountConsumerDisclosures {
             )
             .await
     }
+    /**
+     * Gets the default Electronic Record and Signature Disclosure for an account.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/consumer_disclosure` endpoint.
+     *
+     * As opposed to `consumer_disclosure_get`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Retrieves the default, HTML-formatted Electronic Record and Signature Disclosure (ERSD) associated with the account.
+     *
+     * This is the default ERSD disclosure that DocuSign provides for the convenience of U.S.-based customers only. This default disclosure is only valid for transactions between U.S.-based parties.
+     *
+     * To set the language of the disclosure that you want to retrieve, use the optional `langCode` query parameter.
+     */
+    pub fn consumer_disclosure_get_request<'a>(
+        &'a self,
+        account_id: &'a str,
+    ) -> AccountConsumerDisclosuresConsumerDisclosureGetRequest<'a> {
+        AccountConsumerDisclosuresConsumerDisclosureGetRequest {
+            client: self,
+            account_id,
+            lang_code: None,
+        }
+    }
     /**
      * Gets the Electronic Record and Signature Disclosure for an account.
      *
@@ -325,4 +451,76 @@ impl AccountConsumerDisclosures {
             )
             .await
     }
+    /**
+     * Updates the Electronic Record and Signature Disclosure for an account.
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/consumer_disclosure/{langCode}` endpoint.
+     *
+     * As opposed to `consumer_disclosure_put`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Account administrators can use this method to perform the following tasks:
+     *
+     * - Customize values in the default disclosure.
+     * - Switch to a custom disclosure that uses your own text and HTML formatting.
+     * - Change values in your existing consumer disclosure.
+     *
+     * To specify the signer language version of the disclosure that you are updating, use the optional `langCode` query parameter.
+     *
+     * **Note**: Only account administrators can use this method. Each time you change the disclosure content, all unsigned recipients of outstanding documents will be required to accept a new version.
+     *
+     * ## Updating the default disclosure
+     *
+     * When you update the default disclosure, you can edit all properties except for the following ones:
+     *
+     * - `accountEsignId`: This property is read only.
+     * - `custom`: The default value is **false**. Editing this property causes the default disclosure to switch to a custom disclosure.
+     * - `esignAgreement`: This property is read only.
+     * - `esignText`: You cannot edit this property when `custom` is set to **false**. The API returns a 200 OK HTTP response, but does not update the `esignText`.
+     * - Metadata properties: These properties are read only.
+     *
+     * **Note**: The text of the default disclosure is always in English.
+     *
+     * ## Switching to a custom disclosure
+     *
+     * To switch to a custom disclosure, set the `custom` property to **true** and customize the value for the `eSignText` property.
+     *
+     * You can also edit all of the other properties except for the following ones:
+     *
+     * - `accountEsignId`: This property is read only.
+     * - `esignAgreement`: This property is read only.
+     * - Metadata properties: These properties are read only.
+     *
+     * **Note**: When you use a custom disclosure, you can create versions of it in different signer languages and se the `langCode` parameter to specify the signer language version that you are updating.
+     *
+     * **Important**:  When you switch from a default to a custom disclosure, note the following information:
+     *
+     * - You will not be able to return to using the default disclosure.
+     * - Only the disclosure for the currently selected signer language is saved. DocuSign will not automatically translate your custom disclosure. You must create a disclosure for each language that your signers use.
+     *
+     * ## Updating a custom disclosure
+     *
+     * When you update a custom disclosure, you can update all of the properties except for the following ones:
+     *
+     * - `accountEsignId`: This property is read only.
+     * - `esignAgreement`: This property is read only.
+     * - Metadata properties: These properties are read only.
+     *
+     * **Important**: Only the disclosure for the currently selected signer language is saved. DocuSign will not automatically translate your custom disclosure. You must create a disclosure for each language that your signers use.
+     *
+     *
+     */
+    pub fn consumer_disclosure_put_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        lang_code: &'a str,
+        body: &'a crate::types::ConsumerDisclosure,
+    ) -> AccountConsumerDisclosuresConsumerDisclosurePutRequest<'a> {
+        AccountConsumerDisclosuresConsumerDisclosurePutRequest {
+            client: self,
+            account_id,
+            lang_code,
+            include_metadata: None,
+            body,
+        }
+    }
 }
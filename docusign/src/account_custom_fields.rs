@@ -1,6 +1,160 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`AccountCustomFields::post`], set with a builder.
+pub struct AccountCustomFieldsPostRequest<'a> {
+    client: &'a AccountCustomFields,
+    account_id: &'a str,
+    apply_to_templates: Option<&'a str>,
+    body: &'a crate::types::CustomField,
+}
+
+impl<'a> AccountCustomFieldsPostRequest<'a> {
+    /// Sets the `apply_to_templates` query parameter.
+    pub fn apply_to_templates(mut self, apply_to_templates: &'a str) -> Self {
+        self.apply_to_templates = Some(apply_to_templates);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::AccountCustomFields>> {
+        let Self {
+            client,
+            account_id,
+            apply_to_templates,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = apply_to_templates {
+            query_args.push(("apply_to_templates".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v2.1/accounts/{}/custom_fields?{}",
+                crate::progenitor_support::encode_path(account_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`AccountCustomFields::put`], set with a builder.
+pub struct AccountCustomFieldsPutRequest<'a> {
+    client: &'a AccountCustomFields,
+    account_id: &'a str,
+    custom_field_id: &'a str,
+    apply_to_templates: Option<&'a str>,
+    body: &'a crate::types::CustomField,
+}
+
+impl<'a> AccountCustomFieldsPutRequest<'a> {
+    /// Sets the `apply_to_templates` query parameter.
+    pub fn apply_to_templates(mut self, apply_to_templates: &'a str) -> Self {
+        self.apply_to_templates = Some(apply_to_templates);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::AccountCustomFields>> {
+        let Self {
+            client,
+            account_id,
+            custom_field_id,
+            apply_to_templates,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = apply_to_templates {
+            query_args.push(("apply_to_templates".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v2.1/accounts/{}/custom_fields/{}?{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(custom_field_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .put(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`AccountCustomFields::delete`], set with a builder.
+pub struct AccountCustomFieldsDeleteRequest<'a> {
+    client: &'a AccountCustomFields,
+    account_id: &'a str,
+    custom_field_id: &'a str,
+    apply_to_templates: Option<&'a str>,
+}
+
+impl<'a> AccountCustomFieldsDeleteRequest<'a> {
+    /// Sets the `apply_to_templates` query parameter.
+    pub fn apply_to_templates(mut self, apply_to_templates: &'a str) -> Self {
+        self.apply_to_templates = Some(apply_to_templates);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            account_id,
+            custom_field_id,
+            apply_to_templates,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = apply_to_templates {
+            query_args.push(("apply_to_templates".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v2.1/accounts/{}/custom_fields/{}?{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(custom_field_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .delete(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct AccountCustomFields {
     pub client: Client,
 }
@@ -87,6 +241,27 @@ impl AccountCustomFields {
             )
             .await
     }
+    /**
+     * Creates an account custom field.
+     *
+     * This function performs a `POST` to the `/v2.1/accounts/{accountId}/custom_fields` endpoint.
+     *
+     * As opposed to `post`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * This method creates a custom field and makes it available for all new envelopes associated with an account.
+     */
+    pub fn post_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        body: &'a crate::types::CustomField,
+    ) -> AccountCustomFieldsPostRequest<'a> {
+        AccountCustomFieldsPostRequest {
+            client: self,
+            account_id,
+            apply_to_templates: None,
+            body,
+        }
+    }
     /**
      * Updates an account custom field.
      *
@@ -134,6 +309,29 @@ impl AccountCustomFields {
             )
             .await
     }
+    /**
+     * Updates an account custom field.
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/custom_fields/{customFieldId}` endpoint.
+     *
+     * As opposed to `put`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * This method updates an existing account custom field.
+     */
+    pub fn put_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        custom_field_id: &'a str,
+        body: &'a crate::types::CustomField,
+    ) -> AccountCustomFieldsPutRequest<'a> {
+        AccountCustomFieldsPutRequest {
+            client: self,
+            account_id,
+            custom_field_id,
+            apply_to_templates: None,
+            body,
+        }
+    }
     /**
      * Deletes an account custom field.
      *
@@ -180,4 +378,25 @@ impl AccountCustomFields {
             )
             .await
     }
+    /**
+     * Deletes an account custom field.
+     *
+     * This function performs a `DELETE` to the `/v2.1/accounts/{accountId}/custom_fields/{customFieldId}` endpoint.
+     *
+     * As opposed to `delete`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * This method deletes an existing account custom field.
+     */
+    pub fn delete_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        custom_field_id: &'a str,
+    ) -> AccountCustomFieldsDeleteRequest<'a> {
+        AccountCustomFieldsDeleteRequest {
+            client: self,
+            account_id,
+            custom_field_id,
+            apply_to_templates: None,
+        }
+    }
 }
@@ -1,6 +1,261 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`AccountPermissionProfiles::permission_profiles_get`], set with a builder.
+pub struct AccountPermissionProfilesPermissionProfilesGetRequest<'a> {
+    client: &'a AccountPermissionProfiles,
+    account_id: &'a str,
+    include: Option<&'a str>,
+}
+
+impl<'a> AccountPermissionProfilesPermissionProfilesGetRequest<'a> {
+    /// Sets the `include` query parameter.
+    pub fn include(mut self, include: &'a str) -> Self {
+        self.include = Some(include);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::PermissionProfileInformation>> {
+        let Self {
+            client,
+            account_id,
+            include,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = include {
+            query_args.push(("include".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v2.1/accounts/{}/permission_profiles?{}",
+                crate::progenitor_support::encode_path(account_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`AccountPermissionProfiles::permission_profiles_post`], set with a builder.
+pub struct AccountPermissionProfilesPermissionProfilesPostRequest<'a> {
+    client: &'a AccountPermissionProfiles,
+    account_id: &'a str,
+    include: Option<&'a str>,
+    body: &'a crate::types::PermissionProfile,
+}
+
+impl<'a> AccountPermissionProfilesPermissionProfilesPostRequest<'a> {
+    /// Sets the `include` query parameter.
+    pub fn include(mut self, include: &'a str) -> Self {
+        self.include = Some(include);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::PermissionProfile>> {
+        let Self {
+            client,
+            account_id,
+            include,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = include {
+            query_args.push(("include".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v2.1/accounts/{}/permission_profiles?{}",
+                crate::progenitor_support::encode_path(account_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`AccountPermissionProfiles::permission_profiles_get_profile`], set with a builder.
+pub struct AccountPermissionProfilesPermissionProfilesGetProfileRequest<'a> {
+    client: &'a AccountPermissionProfiles,
+    account_id: &'a str,
+    permission_profile_id: &'a str,
+    include: Option<&'a str>,
+}
+
+impl<'a> AccountPermissionProfilesPermissionProfilesGetProfileRequest<'a> {
+    /// Sets the `include` query parameter.
+    pub fn include(mut self, include: &'a str) -> Self {
+        self.include = Some(include);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::PermissionProfile>> {
+        let Self {
+            client,
+            account_id,
+            permission_profile_id,
+            include,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = include {
+            query_args.push(("include".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v2.1/accounts/{}/permission_profiles/{}?{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(permission_profile_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`AccountPermissionProfiles::permission_profiles_put`], set with a builder.
+pub struct AccountPermissionProfilesPermissionProfilesPutRequest<'a> {
+    client: &'a AccountPermissionProfiles,
+    account_id: &'a str,
+    permission_profile_id: &'a str,
+    include: Option<&'a str>,
+    body: &'a crate::types::PermissionProfile,
+}
+
+impl<'a> AccountPermissionProfilesPermissionProfilesPutRequest<'a> {
+    /// Sets the `include` query parameter.
+    pub fn include(mut self, include: &'a str) -> Self {
+        self.include = Some(include);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::PermissionProfile>> {
+        let Self {
+            client,
+            account_id,
+            permission_profile_id,
+            include,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = include {
+            query_args.push(("include".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v2.1/accounts/{}/permission_profiles/{}?{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(permission_profile_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .put(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`AccountPermissionProfiles::permission_profiles_delete`], set with a builder.
+pub struct AccountPermissionProfilesPermissionProfilesDeleteRequest<'a> {
+    client: &'a AccountPermissionProfiles,
+    account_id: &'a str,
+    permission_profile_id: &'a str,
+    move_users_to: Option<&'a str>,
+}
+
+impl<'a> AccountPermissionProfilesPermissionProfilesDeleteRequest<'a> {
+    /// Sets the `move_users_to` query parameter.
+    pub fn move_users_to(mut self, move_users_to: &'a str) -> Self {
+        self.move_users_to = Some(move_users_to);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            account_id,
+            permission_profile_id,
+            move_users_to,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = move_users_to {
+            query_args.push(("move_users_to".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v2.1/accounts/{}/permission_profiles/{}?{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(permission_profile_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .delete(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct AccountPermissionProfiles {
     pub client: Client,
 }
@@ -59,6 +314,25 @@ impl AccountPermissionProfiles {
             )
             .await
     }
+    /**
+     * Gets a list of permission profiles.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/permission_profiles` endpoint.
+     *
+     * As opposed to `permission_profiles_get`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * This method returns a list of permission profiles that are associated with an account.
+     */
+    pub fn permission_profiles_get_request<'a>(
+        &'a self,
+        account_id: &'a str,
+    ) -> AccountPermissionProfilesPermissionProfilesGetRequest<'a> {
+        AccountPermissionProfilesPermissionProfilesGetRequest {
+            client: self,
+            account_id,
+            include: None,
+        }
+    }
     /**
      * Creates a new permission profile for an account.
      *
@@ -100,6 +374,27 @@ impl AccountPermissionProfiles {
             )
             .await
     }
+    /**
+     * Creates a new permission profile for an account.
+     *
+     * This function performs a `POST` to the `/v2.1/accounts/{accountId}/permission_profiles` endpoint.
+     *
+     * As opposed to `permission_profiles_post`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * This method creates a new permission profile for an account.
+     */
+    pub fn permission_profiles_post_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        body: &'a crate::types::PermissionProfile,
+    ) -> AccountPermissionProfilesPermissionProfilesPostRequest<'a> {
+        AccountPermissionProfilesPermissionProfilesPostRequest {
+            client: self,
+            account_id,
+            include: None,
+            body,
+        }
+    }
     /**
      * Returns a permission profile for an account.
      *
@@ -149,6 +444,27 @@ impl AccountPermissionProfiles {
             )
             .await
     }
+    /**
+     * Returns a permission profile for an account.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/permission_profiles/{permissionProfileId}` endpoint.
+     *
+     * As opposed to `permission_profiles_get_profile`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * This method returns information about a specific permission profile that is associated with an account.
+     */
+    pub fn permission_profiles_get_profile_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        permission_profile_id: &'a str,
+    ) -> AccountPermissionProfilesPermissionProfilesGetProfileRequest<'a> {
+        AccountPermissionProfilesPermissionProfilesGetProfileRequest {
+            client: self,
+            account_id,
+            permission_profile_id,
+            include: None,
+        }
+    }
     /**
      * Updates a permission profile.
      *
@@ -199,6 +515,29 @@ impl AccountPermissionProfiles {
             )
             .await
     }
+    /**
+     * Updates a permission profile.
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/permission_profiles/{permissionProfileId}` endpoint.
+     *
+     * As opposed to `permission_profiles_put`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * This method updates an account permission profile.
+     */
+    pub fn permission_profiles_put_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        permission_profile_id: &'a str,
+        body: &'a crate::types::PermissionProfile,
+    ) -> AccountPermissionProfilesPermissionProfilesPutRequest<'a> {
+        AccountPermissionProfilesPermissionProfilesPutRequest {
+            client: self,
+            account_id,
+            permission_profile_id,
+            include: None,
+            body,
+        }
+    }
     /**
      * Deletes a permission profile from an account.
      *
@@ -250,4 +589,27 @@ impl AccountPermissionProfiles {
             )
             .await
     }
+    /**
+     * Deletes a permission profile from an account.
+     *
+     * This function performs a `DELETE` to the `/v2.1/accounts/{accountId}/permission_profiles/{permissionProfileId}` endpoint.
+     *
+     * As opposed to `permission_profiles_delete`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * This method deletes a permission profile from an account.
+     *
+     * To delete a permission profile, it must not have any users associated with it. When you use this method to delete a permission profile, you can reassign the users associated with it to a new permission profile at the same time by using the `move_users_to` query parameter.
+     */
+    pub fn permission_profiles_delete_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        permission_profile_id: &'a str,
+    ) -> AccountPermissionProfilesPermissionProfilesDeleteRequest<'a> {
+        AccountPermissionProfilesPermissionProfilesDeleteRequest {
+            client: self,
+            account_id,
+            permission_profile_id,
+            move_users_to: None,
+        }
+    }
 }
@@ -1,6 +1,291 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`AccountSignatures::get`], set with a builder.
+pub struct AccountSignaturesGetRequest<'a> {
+    client: &'a AccountSignatures,
+    account_id: &'a str,
+    stamp_format: Option<&'a str>,
+    stamp_name: Option<&'a str>,
+    stamp_type: Option<&'a str>,
+}
+
+impl<'a> AccountSignaturesGetRequest<'a> {
+    /// Sets the `stamp_format` query parameter.
+    pub fn stamp_format(mut self, stamp_format: &'a str) -> Self {
+        self.stamp_format = Some(stamp_format);
+        self
+    }
+
+    /// Sets the `stamp_name` query parameter.
+    pub fn stamp_name(mut self, stamp_name: &'a str) -> Self {
+        self.stamp_name = Some(stamp_name);
+        self
+    }
+
+    /// Sets the `stamp_type` query parameter.
+    pub fn stamp_type(mut self, stamp_type: &'a str) -> Self {
+        self.stamp_type = Some(stamp_type);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::AccountSignaturesInformation>> {
+        let Self {
+            client,
+            account_id,
+            stamp_format,
+            stamp_name,
+            stamp_type,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = stamp_format {
+            query_args.push(("stamp_format".to_string(), v.to_string()));
+        }
+        if let Some(v) = stamp_name {
+            query_args.push(("stamp_name".to_string(), v.to_string()));
+        }
+        if let Some(v) = stamp_type {
+            query_args.push(("stamp_type".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v2.1/accounts/{}/signatures?{}",
+                crate::progenitor_support::encode_path(account_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`AccountSignatures::post`], set with a builder.
+pub struct AccountSignaturesPostRequest<'a> {
+    client: &'a AccountSignatures,
+    account_id: &'a str,
+    decode_only: Option<&'a str>,
+    body: &'a crate::types::AccountSignaturesInformation,
+}
+
+impl<'a> AccountSignaturesPostRequest<'a> {
+    /// Sets the `decode_only` query parameter.
+    pub fn decode_only(mut self, decode_only: &'a str) -> Self {
+        self.decode_only = Some(decode_only);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::AccountSignaturesInformation>> {
+        let Self {
+            client,
+            account_id,
+            decode_only,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = decode_only {
+            query_args.push(("decode_only".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v2.1/accounts/{}/signatures?{}",
+                crate::progenitor_support::encode_path(account_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`AccountSignatures::put_signature_account_signatures`], set with a builder.
+pub struct AccountSignaturesPutSignatureAccountSignaturesRequest<'a> {
+    client: &'a AccountSignatures,
+    account_id: &'a str,
+    signature_id: &'a str,
+    close_existing_signature: Option<&'a str>,
+    body: &'a crate::types::AccountSignatureDefinition,
+}
+
+impl<'a> AccountSignaturesPutSignatureAccountSignaturesRequest<'a> {
+    /// Sets the `close_existing_signature` query parameter.
+    pub fn close_existing_signature(mut self, close_existing_signature: &'a str) -> Self {
+        self.close_existing_signature = Some(close_existing_signature);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::AccountSignature>> {
+        let Self {
+            client,
+            account_id,
+            signature_id,
+            close_existing_signature,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = close_existing_signature {
+            query_args.push(("close_existing_signature".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v2.1/accounts/{}/signatures/{}?{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(signature_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .put(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`AccountSignatures::get_signature_image`], set with a builder.
+pub struct AccountSignaturesGetSignatureImageRequest<'a> {
+    client: &'a AccountSignatures,
+    account_id: &'a str,
+    image_type: &'a str,
+    signature_id: &'a str,
+    include_chrome: Option<&'a str>,
+}
+
+impl<'a> AccountSignaturesGetSignatureImageRequest<'a> {
+    /// Sets the `include_chrome` query parameter.
+    pub fn include_chrome(mut self, include_chrome: &'a str) -> Self {
+        self.include_chrome = Some(include_chrome);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            account_id,
+            image_type,
+            signature_id,
+            include_chrome,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = include_chrome {
+            query_args.push(("include_chrome".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v2.1/accounts/{}/signatures/{}/{}?{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(signature_id),
+                crate::progenitor_support::encode_path(image_type),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`AccountSignatures::put_signature_image`], set with a builder.
+pub struct AccountSignaturesPutSignatureImageRequest<'a> {
+    client: &'a AccountSignatures,
+    account_id: &'a str,
+    image_type: &'a str,
+    signature_id: &'a str,
+    transparent_png: Option<&'a str>,
+}
+
+impl<'a> AccountSignaturesPutSignatureImageRequest<'a> {
+    /// Sets the `transparent_png` query parameter.
+    pub fn transparent_png(mut self, transparent_png: &'a str) -> Self {
+        self.transparent_png = Some(transparent_png);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::AccountSignature>> {
+        let Self {
+            client,
+            account_id,
+            image_type,
+            signature_id,
+            transparent_png,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = transparent_png {
+            query_args.push(("transparent_png".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v2.1/accounts/{}/signatures/{}/{}?{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(signature_id),
+                crate::progenitor_support::encode_path(image_type),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .put(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct AccountSignatures {
     pub client: Client,
 }
@@ -61,6 +346,24 @@ impl AccountSignatures {
             )
             .await
     }
+    /**
+     * Returns the managed signature definitions for the account.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/signatures` endpoint.
+     *
+     * As opposed to `get`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     *
+     */
+    pub fn get_request<'a>(&'a self, account_id: &'a str) -> AccountSignaturesGetRequest<'a> {
+        AccountSignaturesGetRequest {
+            client: self,
+            account_id,
+            stamp_format: None,
+            stamp_name: None,
+            stamp_type: None,
+        }
+    }
     /**
      * Updates an account signature.
     .
@@ -137,6 +440,28 @@ impl AccountSignatures {
             )
             .await
     }
+    /**
+     * Adds or updates one or more account signatures.
+    This request may include images in multi-part format.
+     *
+     * This function performs a `POST` to the `/v2.1/accounts/{accountId}/signatures` endpoint.
+     *
+     * As opposed to `post`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     *
+     */
+    pub fn post_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        body: &'a crate::types::AccountSignaturesInformation,
+    ) -> AccountSignaturesPostRequest<'a> {
+        AccountSignaturesPostRequest {
+            client: self,
+            account_id,
+            decode_only: None,
+            body,
+        }
+    }
     /**
      * Returns information about the specified signature.
      *
@@ -219,6 +544,29 @@ impl AccountSignatures {
             )
             .await
     }
+    /**
+     * Updates an account signature.
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/signatures/{signatureId}` endpoint.
+     *
+     * As opposed to `put_signature_account_signatures`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     *
+     */
+    pub fn put_signature_account_signatures_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        signature_id: &'a str,
+        body: &'a crate::types::AccountSignatureDefinition,
+    ) -> AccountSignaturesPutSignatureAccountSignaturesRequest<'a> {
+        AccountSignaturesPutSignatureAccountSignaturesRequest {
+            client: self,
+            account_id,
+            signature_id,
+            close_existing_signature: None,
+            body,
+        }
+    }
     /**
      * Close the specified signature by ID.
      *
@@ -303,6 +651,29 @@ impl AccountSignatures {
             )
             .await
     }
+    /**
+     * Returns a signature image, initials, or stamp.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/signatures/{signatureId}/{imageType}` endpoint.
+     *
+     * As opposed to `get_signature_image`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     *
+     */
+    pub fn get_signature_image_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        image_type: &'a str,
+        signature_id: &'a str,
+    ) -> AccountSignaturesGetSignatureImageRequest<'a> {
+        AccountSignaturesGetSignatureImageRequest {
+            client: self,
+            account_id,
+            image_type,
+            signature_id,
+            include_chrome: None,
+        }
+    }
     /**
      * Sets a signature image, initials, or stamp.
      *
@@ -352,6 +723,29 @@ impl AccountSignatures {
             )
             .await
     }
+    /**
+     * Sets a signature image, initials, or stamp.
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/signatures/{signatureId}/{imageType}` endpoint.
+     *
+     * As opposed to `put_signature_image`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     *
+     */
+    pub fn put_signature_image_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        image_type: &'a str,
+        signature_id: &'a str,
+    ) -> AccountSignaturesPutSignatureImageRequest<'a> {
+        AccountSignaturesPutSignatureImageRequest {
+            client: self,
+            account_id,
+            image_type,
+            signature_id,
+            transparent_png: None,
+        }
+    }
     /**
      * Deletes a signature image, initials, or stamp.
      *
@@ -1,6 +1,356 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Accounts::get`], set with a builder.
+pub struct AccountsGetRequest<'a> {
+    client: &'a Accounts,
+    account_id: &'a str,
+    include_account_settings: Option<&'a str>,
+}
+
+impl<'a> AccountsGetRequest<'a> {
+    /// Sets the `include_account_settings` query parameter.
+    pub fn include_account_settings(mut self, include_account_settings: &'a str) -> Self {
+        self.include_account_settings = Some(include_account_settings);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::AccountInformation>> {
+        let Self {
+            client,
+            account_id,
+            include_account_settings,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = include_account_settings {
+            query_args.push(("include_account_settings".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v2.1/accounts/{}?{}",
+                crate::progenitor_support::encode_path(account_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Accounts::billing_charges_get`], set with a builder.
+pub struct AccountsBillingChargesGetRequest<'a> {
+    client: &'a Accounts,
+    account_id: &'a str,
+    include_charges: Option<&'a str>,
+}
+
+impl<'a> AccountsBillingChargesGetRequest<'a> {
+    /// Sets the `include_charges` query parameter.
+    pub fn include_charges(mut self, include_charges: &'a str) -> Self {
+        self.include_charges = Some(include_charges);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::BillingChargeResponse>> {
+        let Self {
+            client,
+            account_id,
+            include_charges,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = include_charges {
+            query_args.push(("include_charges".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v2.1/accounts/{}/billing_charges?{}",
+                crate::progenitor_support::encode_path(account_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Accounts::recipient_names_get`], set with a builder.
+pub struct AccountsRecipientNamesGetRequest<'a> {
+    client: &'a Accounts,
+    account_id: &'a str,
+    email: Option<&'a str>,
+}
+
+impl<'a> AccountsRecipientNamesGetRequest<'a> {
+    /// Sets the `email` query parameter.
+    pub fn email(mut self, email: &'a str) -> Self {
+        self.email = Some(email);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::RecipientNamesResponse>> {
+        let Self {
+            client,
+            account_id,
+            email,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = email {
+            query_args.push(("email".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v2.1/accounts/{}/recipient_names?{}",
+                crate::progenitor_support::encode_path(account_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Accounts::shared_access_get`], set with a builder.
+pub struct AccountsSharedAccessGetRequest<'a> {
+    client: &'a Accounts,
+    account_id: &'a str,
+    count: Option<&'a str>,
+    envelopes_not_shared_user_status: Option<&'a str>,
+    folder_ids: Option<&'a str>,
+    item_type: Option<&'a str>,
+    search_text: Option<&'a str>,
+    shared: Option<&'a str>,
+    start_position: Option<&'a str>,
+    user_ids: Option<&'a str>,
+}
+
+impl<'a> AccountsSharedAccessGetRequest<'a> {
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: &'a str) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Sets the `envelopes_not_shared_user_status` query parameter.
+    pub fn envelopes_not_shared_user_status(
+        mut self,
+        envelopes_not_shared_user_status: &'a str,
+    ) -> Self {
+        self.envelopes_not_shared_user_status = Some(envelopes_not_shared_user_status);
+        self
+    }
+
+    /// Sets the `folder_ids` query parameter.
+    pub fn folder_ids(mut self, folder_ids: &'a str) -> Self {
+        self.folder_ids = Some(folder_ids);
+        self
+    }
+
+    /// Sets the `item_type` query parameter.
+    pub fn item_type(mut self, item_type: &'a str) -> Self {
+        self.item_type = Some(item_type);
+        self
+    }
+
+    /// Sets the `search_text` query parameter.
+    pub fn search_text(mut self, search_text: &'a str) -> Self {
+        self.search_text = Some(search_text);
+        self
+    }
+
+    /// Sets the `shared` query parameter.
+    pub fn shared(mut self, shared: &'a str) -> Self {
+        self.shared = Some(shared);
+        self
+    }
+
+    /// Sets the `start_position` query parameter.
+    pub fn start_position(mut self, start_position: &'a str) -> Self {
+        self.start_position = Some(start_position);
+        self
+    }
+
+    /// Sets the `user_ids` query parameter.
+    pub fn user_ids(mut self, user_ids: &'a str) -> Self {
+        self.user_ids = Some(user_ids);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::AccountSharedAccess>> {
+        let Self {
+            client,
+            account_id,
+            count,
+            envelopes_not_shared_user_status,
+            folder_ids,
+            item_type,
+            search_text,
+            shared,
+            start_position,
+            user_ids,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = count {
+            query_args.push(("count".to_string(), v.to_string()));
+        }
+        if let Some(v) = envelopes_not_shared_user_status {
+            query_args.push((
+                "envelopes_not_shared_user_status".to_string(),
+                v.to_string(),
+            ));
+        }
+        if let Some(v) = folder_ids {
+            query_args.push(("folder_ids".to_string(), v.to_string()));
+        }
+        if let Some(v) = item_type {
+            query_args.push(("item_type".to_string(), v.to_string()));
+        }
+        if let Some(v) = search_text {
+            query_args.push(("search_text".to_string(), v.to_string()));
+        }
+        if let Some(v) = shared {
+            query_args.push(("shared".to_string(), v.to_string()));
+        }
+        if let Some(v) = start_position {
+            query_args.push(("start_position".to_string(), v.to_string()));
+        }
+        if let Some(v) = user_ids {
+            query_args.push(("user_ids".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v2.1/accounts/{}/shared_access?{}",
+                crate::progenitor_support::encode_path(account_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Accounts::shared_access_put`], set with a builder.
+pub struct AccountsSharedAccessPutRequest<'a> {
+    client: &'a Accounts,
+    account_id: &'a str,
+    item_type: Option<&'a str>,
+    preserve_existing_shared_access: Option<&'a str>,
+    user_ids: Option<&'a str>,
+    body: &'a crate::types::AccountSharedAccess,
+}
+
+impl<'a> AccountsSharedAccessPutRequest<'a> {
+    /// Sets the `item_type` query parameter.
+    pub fn item_type(mut self, item_type: &'a str) -> Self {
+        self.item_type = Some(item_type);
+        self
+    }
+
+    /// Sets the `preserve_existing_shared_access` query parameter.
+    pub fn preserve_existing_shared_access(
+        mut self,
+        preserve_existing_shared_access: &'a str,
+    ) -> Self {
+        self.preserve_existing_shared_access = Some(preserve_existing_shared_access);
+        self
+    }
+
+    /// Sets the `user_ids` query parameter.
+    pub fn user_ids(mut self, user_ids: &'a str) -> Self {
+        self.user_ids = Some(user_ids);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::AccountSharedAccess>> {
+        let Self {
+            client,
+            account_id,
+            item_type,
+            preserve_existing_shared_access,
+            user_ids,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = item_type {
+            query_args.push(("item_type".to_string(), v.to_string()));
+        }
+        if let Some(v) = preserve_existing_shared_access {
+            query_args.push(("preserve_existing_shared_access".to_string(), v.to_string()));
+        }
+        if let Some(v) = user_ids {
+            query_args.push(("user_ids".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v2.1/accounts/{}/shared_access?{}",
+                crate::progenitor_support::encode_path(account_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .put(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
 pub struct Accounts {
     pub client: Client,
 }
@@ -225,6 +575,25 @@ impl Accounts {
             )
             .await
     }
+    /**
+     * Retrieves the account information for the specified account.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}` endpoint.
+     *
+     * As opposed to `get`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Retrieves the account information for the specified account.
+     *
+     * **Response**
+     * The `canUpgrade` property contains is a Boolean that indicates whether the account can be upgraded through the API.
+     */
+    pub fn get_request<'a>(&'a self, account_id: &'a str) -> AccountsGetRequest<'a> {
+        AccountsGetRequest {
+            client: self,
+            account_id,
+            include_account_settings: None,
+        }
+    }
     /**
      * Deletes the specified account.
      *
@@ -301,6 +670,27 @@ impl Accounts {
             )
             .await
     }
+    /**
+     * Gets list of recurring and usage charges for the account.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/billing_charges` endpoint.
+     *
+     * As opposed to `billing_charges_get`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Retrieves the list of recurring and usage charges for the account. This can be used to determine the charge structure and usage of charge plan items.
+     *
+     * Privileges required: account administrator
+     */
+    pub fn billing_charges_get_request<'a>(
+        &'a self,
+        account_id: &'a str,
+    ) -> AccountsBillingChargesGetRequest<'a> {
+        AccountsBillingChargesGetRequest {
+            client: self,
+            account_id,
+            include_charges: None,
+        }
+    }
     /**
      * Deletes the signature for one or more captive recipient records.
      *
@@ -377,6 +767,25 @@ impl Accounts {
             )
             .await
     }
+    /**
+     * Gets the recipient names associated with an email address.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/recipient_names` endpoint.
+     *
+     * As opposed to `recipient_names_get`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Retrieves a list of all of the names associated with the email address that you pass in. This list can include variants of a single recipient's name that are used for signing, as well as the names of multiple different recipients.
+     */
+    pub fn recipient_names_get_request<'a>(
+        &'a self,
+        account_id: &'a str,
+    ) -> AccountsRecipientNamesGetRequest<'a> {
+        AccountsRecipientNamesGetRequest {
+            client: self,
+            account_id,
+            email: None,
+        }
+    }
     /**
      * Gets account settings information.
      *
@@ -678,6 +1087,34 @@ impl Accounts {
             )
             .await
     }
+    /**
+     * Reserved: Gets the shared item status for one or more users.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/shared_access` endpoint.
+     *
+     * As opposed to `shared_access_get`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Retrieves shared item status for one or more users and types of items.
+     *
+     * Users with account administration privileges can retrieve shared access information for all account users. Users without account administrator privileges can only retrieve shared access information for themselves, and the returned information is limited to retrieving the status of the members of the account that are sharing their folders to the user. This is equivalent to setting the `shared` parameter to `shared_from`.
+     */
+    pub fn shared_access_get_request<'a>(
+        &'a self,
+        account_id: &'a str,
+    ) -> AccountsSharedAccessGetRequest<'a> {
+        AccountsSharedAccessGetRequest {
+            client: self,
+            account_id,
+            count: None,
+            envelopes_not_shared_user_status: None,
+            folder_ids: None,
+            item_type: None,
+            search_text: None,
+            shared: None,
+            start_position: None,
+            user_ids: None,
+        }
+    }
     /**
      * Reserved: Sets the shared access information for users.
      *
@@ -748,6 +1185,41 @@ impl Accounts {
             )
             .await
     }
+    /**
+     * Reserved: Sets the shared access information for users.
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/shared_access` endpoint.
+     *
+     * As opposed to `shared_access_put`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * This sets the shared access status for one or more users or templates.
+     *
+     * When setting user shared access, only users with account administration privileges can set shared access status for envelopes.
+     *
+     * When setting template shared access, only users who own a template and have sharing permission or with account administration privileges can set shared access for templates.
+     *
+     * Changes to the shared items status are not additive. The change always replaces the current status.
+     *
+     * To change template shared access, add the query parameter `item_type` = `templates` to the request. When this is set, the user and envelopes properties are not required.
+     *
+     * **Note**: This functionality is a newer version of the [Update Group Share](https://developers.docusign.com/docs/esign-rest-api/reference/Templates/Templates/updateGroupShare) functionality.
+     *
+     *
+     */
+    pub fn shared_access_put_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        body: &'a crate::types::AccountSharedAccess,
+    ) -> AccountsSharedAccessPutRequest<'a> {
+        AccountsSharedAccessPutRequest {
+            client: self,
+            account_id,
+            item_type: None,
+            preserve_existing_shared_access: None,
+            user_ids: None,
+            body,
+        }
+    }
     /**
      * Gets the supported languages for envelope recipients.
      *
@@ -1,6 +1,129 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`BccEmailArchive::get_list`], set with a builder.
+pub struct BccEmailArchiveGetListRequest<'a> {
+    client: &'a BccEmailArchive,
+    account_id: &'a str,
+    count: Option<&'a str>,
+    start_position: Option<&'a str>,
+}
+
+impl<'a> BccEmailArchiveGetListRequest<'a> {
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: &'a str) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Sets the `start_position` query parameter.
+    pub fn start_position(mut self, start_position: &'a str) -> Self {
+        self.start_position = Some(start_position);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::BccEmailArchiveList>> {
+        let Self {
+            client,
+            account_id,
+            count,
+            start_position,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = count {
+            query_args.push(("count".to_string(), v.to_string()));
+        }
+        if let Some(v) = start_position {
+            query_args.push(("start_position".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v2.1/accounts/{}/settings/bcc_email_archives?{}",
+                crate::progenitor_support::encode_path(account_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`BccEmailArchive::get_history_list`], set with a builder.
+pub struct BccEmailArchiveGetHistoryListRequest<'a> {
+    client: &'a BccEmailArchive,
+    account_id: &'a str,
+    bcc_email_archive_id: &'a str,
+    count: Option<&'a str>,
+    start_position: Option<&'a str>,
+}
+
+impl<'a> BccEmailArchiveGetHistoryListRequest<'a> {
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: &'a str) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Sets the `start_position` query parameter.
+    pub fn start_position(mut self, start_position: &'a str) -> Self {
+        self.start_position = Some(start_position);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::BccEmailArchiveHistoryList>> {
+        let Self {
+            client,
+            account_id,
+            bcc_email_archive_id,
+            count,
+            start_position,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = count {
+            query_args.push(("count".to_string(), v.to_string()));
+        }
+        if let Some(v) = start_position {
+            query_args.push(("start_position".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v2.1/accounts/{}/settings/bcc_email_archives/{}?{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(bcc_email_archive_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct BccEmailArchive {
     pub client: Client,
 }
@@ -56,6 +179,26 @@ impl BccEmailArchive {
             )
             .await
     }
+    /**
+     * Gets the BCC email archive configurations for an account.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/settings/bcc_email_archives` endpoint.
+     *
+     * As opposed to `get_list`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * This method retrieves all of the BCC email archive configurations associated with an account.
+     */
+    pub fn get_list_request<'a>(
+        &'a self,
+        account_id: &'a str,
+    ) -> BccEmailArchiveGetListRequest<'a> {
+        BccEmailArchiveGetListRequest {
+            client: self,
+            account_id,
+            count: None,
+            start_position: None,
+        }
+    }
     /**
      * Creates a BCC email archive configuration.
      *
@@ -142,6 +285,28 @@ impl BccEmailArchive {
             )
             .await
     }
+    /**
+     * Gets a BCC email archive configuration and its history.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/settings/bcc_email_archives/{bccEmailArchiveId}` endpoint.
+     *
+     * As opposed to `get_history_list`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * This method returns a specific BCC email archive configuration for an account, as well as the history of changes to the email address.
+     */
+    pub fn get_history_list_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        bcc_email_archive_id: &'a str,
+    ) -> BccEmailArchiveGetHistoryListRequest<'a> {
+        BccEmailArchiveGetHistoryListRequest {
+            client: self,
+            account_id,
+            bcc_email_archive_id,
+            count: None,
+            start_position: None,
+        }
+    }
     /**
      * Deletes a BCC email archive configuration.
      *
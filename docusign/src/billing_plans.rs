@@ -1,6 +1,133 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`BillingPlans::get`], set with a builder.
+pub struct BillingPlansGetRequest<'a> {
+    client: &'a BillingPlans,
+    account_id: &'a str,
+    include_credit_card_information: Option<&'a str>,
+    include_metadata: Option<&'a str>,
+    include_successor_plans: Option<&'a str>,
+}
+
+impl<'a> BillingPlansGetRequest<'a> {
+    /// Sets the `include_credit_card_information` query parameter.
+    pub fn include_credit_card_information(
+        mut self,
+        include_credit_card_information: &'a str,
+    ) -> Self {
+        self.include_credit_card_information = Some(include_credit_card_information);
+        self
+    }
+
+    /// Sets the `include_metadata` query parameter.
+    pub fn include_metadata(mut self, include_metadata: &'a str) -> Self {
+        self.include_metadata = Some(include_metadata);
+        self
+    }
+
+    /// Sets the `include_successor_plans` query parameter.
+    pub fn include_successor_plans(mut self, include_successor_plans: &'a str) -> Self {
+        self.include_successor_plans = Some(include_successor_plans);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::AccountBillingPlanResponse>> {
+        let Self {
+            client,
+            account_id,
+            include_credit_card_information,
+            include_metadata,
+            include_successor_plans,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = include_credit_card_information {
+            query_args.push(("include_credit_card_information".to_string(), v.to_string()));
+        }
+        if let Some(v) = include_metadata {
+            query_args.push(("include_metadata".to_string(), v.to_string()));
+        }
+        if let Some(v) = include_successor_plans {
+            query_args.push(("include_successor_plans".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v2.1/accounts/{}/billing_plan?{}",
+                crate::progenitor_support::encode_path(account_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`BillingPlans::put`], set with a builder.
+pub struct BillingPlansPutRequest<'a> {
+    client: &'a BillingPlans,
+    account_id: &'a str,
+    preview_billing_plan: Option<&'a str>,
+    body: &'a crate::types::BillingPlanInformation,
+}
+
+impl<'a> BillingPlansPutRequest<'a> {
+    /// Sets the `preview_billing_plan` query parameter.
+    pub fn preview_billing_plan(mut self, preview_billing_plan: &'a str) -> Self {
+        self.preview_billing_plan = Some(preview_billing_plan);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::BillingPlanUpdateResponse>> {
+        let Self {
+            client,
+            account_id,
+            preview_billing_plan,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = preview_billing_plan {
+            query_args.push(("preview_billing_plan".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v2.1/accounts/{}/billing_plan?{}",
+                crate::progenitor_support::encode_path(account_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .put(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
 pub struct BillingPlans {
     pub client: Client,
 }
@@ -76,6 +203,33 @@ impl BillingPlans {
             )
             .await
     }
+    /**
+     * Get Account Billing Plan.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/billing_plan` endpoint.
+     *
+     * As opposed to `get`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Retrieves the billing plan information for the specified account, including the current billing plan, successor plans, billing address, and billing credit card.
+     *
+     * By default the successor plan and credit card information is included in the response. You can exclude this information from the response by adding the appropriate optional query string and setting it to **false**.
+     *
+     * Response
+     *
+     * The response returns the billing plan information, including the currency code, for the plan. The `billingPlan` and `succesorPlans` property values are the same as those shown in the [Billing::getBillingPlan](https://developers.docusign.com/docs/esign-rest-api/reference/Billing/BillingPlans/get) reference. the `billingAddress` and `creditCardInformation` property values are the same as those shown in the [Billing::updatePlan](https://developers.docusign.com/docs/esign-rest-api/reference/Billing/BillingPlans/update) reference.
+     *
+     * **Note**: When credit card number information displays, a mask is applied to the response so that only the last 4 digits of the card number are visible.
+     *
+     */
+    pub fn get_request<'a>(&'a self, account_id: &'a str) -> BillingPlansGetRequest<'a> {
+        BillingPlansGetRequest {
+            client: self,
+            account_id,
+            include_credit_card_information: None,
+            include_metadata: None,
+            include_successor_plans: None,
+        }
+    }
     /**
      * Updates an account billing plan.
      *
@@ -120,6 +274,27 @@ impl BillingPlans {
             )
             .await
     }
+    /**
+     * Updates an account billing plan.
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/billing_plan` endpoint.
+     *
+     * As opposed to `put`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Updates the billing plan information, billing address, and credit card information for the specified account.
+     */
+    pub fn put_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        body: &'a crate::types::BillingPlanInformation,
+    ) -> BillingPlansPutRequest<'a> {
+        BillingPlansPutRequest {
+            client: self,
+            account_id,
+            preview_billing_plan: None,
+            body,
+        }
+    }
     /**
      * Get credit card information.
      *
@@ -0,0 +1,283 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`AccountBrands::brands_get`], set with a builder.
+pub struct AccountBrandsBrandsGetRequest<'a> {
+    inner: crate::account_brands::AccountBrandsBrandsGetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> AccountBrandsBrandsGetRequest<'a> {
+    /// Sets the `exclude_distributor_brand` query parameter.
+    pub fn exclude_distributor_brand(mut self, exclude_distributor_brand: &'a str) -> Self {
+        self.inner = self
+            .inner
+            .exclude_distributor_brand(exclude_distributor_brand);
+        self
+    }
+
+    /// Sets the `include_logos` query parameter.
+    pub fn include_logos(mut self, include_logos: &'a str) -> Self {
+        self.inner = self.inner.include_logos(include_logos);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::AccountBrands>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`AccountBrands::brand_get`], set with a builder.
+pub struct AccountBrandsBrandGetRequest<'a> {
+    inner: crate::account_brands::AccountBrandsBrandGetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> AccountBrandsBrandGetRequest<'a> {
+    /// Sets the `include_external_references` query parameter.
+    pub fn include_external_references(mut self, include_external_references: &'a str) -> Self {
+        self.inner = self
+            .inner
+            .include_external_references(include_external_references);
+        self
+    }
+
+    /// Sets the `include_logos` query parameter.
+    pub fn include_logos(mut self, include_logos: &'a str) -> Self {
+        self.inner = self.inner.include_logos(include_logos);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::Brand>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`AccountBrands::brand_resources_get`], set with a builder.
+pub struct AccountBrandsBrandResourcesGetRequest<'a> {
+    inner: crate::account_brands::AccountBrandsBrandResourcesGetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> AccountBrandsBrandResourcesGetRequest<'a> {
+    /// Sets the `langcode` query parameter.
+    pub fn langcode(mut self, langcode: &'a str) -> Self {
+        self.inner = self.inner.langcode(langcode);
+        self
+    }
+
+    /// Sets the `return_master` query parameter.
+    pub fn return_master(mut self, return_master: &'a str) -> Self {
+        self.inner = self.inner.return_master(return_master);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::account_brands::AccountBrands`].
+pub struct AccountBrands {
+    inner: crate::account_brands::AccountBrands,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl AccountBrands {
+    pub(crate) fn new(
+        inner: crate::account_brands::AccountBrands,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        AccountBrands { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::account_brands::AccountBrands::brands_get`].
+    pub fn brands_get(
+        &self,
+        account_id: &str,
+        exclude_distributor_brand: &str,
+        include_logos: &str,
+    ) -> ClientResult<crate::Response<crate::types::AccountBrands>> {
+        self.runtime.block_on(self.inner.brands_get(
+            account_id,
+            exclude_distributor_brand,
+            include_logos,
+        ))
+    }
+    /// The blocking version of [`crate::account_brands::AccountBrands::brands_get_request`].
+    pub fn brands_get_request<'a>(
+        &'a self,
+        account_id: &'a str,
+    ) -> AccountBrandsBrandsGetRequest<'a> {
+        AccountBrandsBrandsGetRequest {
+            inner: self.inner.brands_get_request(account_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::account_brands::AccountBrands::brands_post`].
+    pub fn brands_post(
+        &self,
+        account_id: &str,
+        body: &crate::types::Brand,
+    ) -> ClientResult<crate::Response<crate::types::AccountBrands>> {
+        self.runtime
+            .block_on(self.inner.brands_post(account_id, body))
+    }
+    /// The blocking version of [`crate::account_brands::AccountBrands::brands_delete`].
+    pub fn brands_delete(
+        &self,
+        account_id: &str,
+        body: &crate::types::BrandsRequest,
+    ) -> ClientResult<crate::Response<crate::types::AccountBrands>> {
+        self.runtime
+            .block_on(self.inner.brands_delete(account_id, body))
+    }
+    /// The blocking version of [`crate::account_brands::AccountBrands::brand_get`].
+    pub fn brand_get(
+        &self,
+        account_id: &str,
+        brand_id: &str,
+        include_external_references: &str,
+        include_logos: &str,
+    ) -> ClientResult<crate::Response<crate::types::Brand>> {
+        self.runtime.block_on(self.inner.brand_get(
+            account_id,
+            brand_id,
+            include_external_references,
+            include_logos,
+        ))
+    }
+    /// The blocking version of [`crate::account_brands::AccountBrands::brand_get_request`].
+    pub fn brand_get_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        brand_id: &'a str,
+    ) -> AccountBrandsBrandGetRequest<'a> {
+        AccountBrandsBrandGetRequest {
+            inner: self.inner.brand_get_request(account_id, brand_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::account_brands::AccountBrands::brand_put`].
+    pub fn brand_put(
+        &self,
+        account_id: &str,
+        brand_id: &str,
+        body: &crate::types::Brand,
+    ) -> ClientResult<crate::Response<crate::types::Brand>> {
+        self.runtime
+            .block_on(self.inner.brand_put(account_id, brand_id, body))
+    }
+    /// The blocking version of [`crate::account_brands::AccountBrands::brand_delete`].
+    pub fn brand_delete(
+        &self,
+        account_id: &str,
+        brand_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.brand_delete(account_id, brand_id))
+    }
+    /// The blocking version of [`crate::account_brands::AccountBrands::brand_export_get_file`].
+    pub fn brand_export_get_file(
+        &self,
+        account_id: &str,
+        brand_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.brand_export_get_file(account_id, brand_id))
+    }
+    /// The blocking version of [`crate::account_brands::AccountBrands::brand_logo_get`].
+    pub fn brand_logo_get(
+        &self,
+        account_id: &str,
+        brand_id: &str,
+        logo_type: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.brand_logo_get(account_id, brand_id, logo_type))
+    }
+    /// The blocking version of [`crate::account_brands::AccountBrands::brand_logo_put`].
+    pub fn brand_logo_put<T: Into<reqwest::Body>>(
+        &self,
+        account_id: &str,
+        brand_id: &str,
+        logo_type: &str,
+        body: bytes::Bytes,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .brand_logo_put::<T>(account_id, brand_id, logo_type, body),
+        )
+    }
+    /// The blocking version of [`crate::account_brands::AccountBrands::brand_logo_delete`].
+    pub fn brand_logo_delete(
+        &self,
+        account_id: &str,
+        brand_id: &str,
+        logo_type: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .brand_logo_delete(account_id, brand_id, logo_type),
+        )
+    }
+    /// The blocking version of [`crate::account_brands::AccountBrands::brand_resources_get_list`].
+    pub fn brand_resources_get_list(
+        &self,
+        account_id: &str,
+        brand_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::BrandResourcesList>> {
+        self.runtime
+            .block_on(self.inner.brand_resources_get_list(account_id, brand_id))
+    }
+    /// The blocking version of [`crate::account_brands::AccountBrands::brand_resources_get`].
+    pub fn brand_resources_get(
+        &self,
+        account_id: &str,
+        brand_id: &str,
+        resource_content_type: &str,
+        langcode: &str,
+        return_master: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.brand_resources_get(
+            account_id,
+            brand_id,
+            resource_content_type,
+            langcode,
+            return_master,
+        ))
+    }
+    /// The blocking version of [`crate::account_brands::AccountBrands::brand_resources_get_request`].
+    pub fn brand_resources_get_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        brand_id: &'a str,
+        resource_content_type: &'a str,
+    ) -> AccountBrandsBrandResourcesGetRequest<'a> {
+        AccountBrandsBrandResourcesGetRequest {
+            inner: self.inner.brand_resources_get_request(
+                account_id,
+                brand_id,
+                resource_content_type,
+            ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::account_brands::AccountBrands::brand_resources_put`].
+    pub fn brand_resources_put(
+        &self,
+        account_id: &str,
+        brand_id: &str,
+        resource_content_type: &str,
+    ) -> ClientResult<crate::Response<crate::types::BrandResources>> {
+        self.runtime.block_on(self.inner.brand_resources_put(
+            account_id,
+            brand_id,
+            resource_content_type,
+        ))
+    }
+}
@@ -0,0 +1,122 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`AccountConsumerDisclosures::consumer_disclosure_get`], set with a builder.
+pub struct AccountConsumerDisclosuresConsumerDisclosureGetRequest<'a> {
+    inner:
+        crate::account_consumer_disclosures::AccountConsumerDisclosuresConsumerDisclosureGetRequest<
+            'a,
+        >,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> AccountConsumerDisclosuresConsumerDisclosureGetRequest<'a> {
+    /// Sets the `langCode` query parameter.
+    pub fn lang_code(mut self, lang_code: &'a str) -> Self {
+        self.inner = self.inner.lang_code(lang_code);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::AccountConsumerDisclosures>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`AccountConsumerDisclosures::consumer_disclosure_put`], set with a builder.
+pub struct AccountConsumerDisclosuresConsumerDisclosurePutRequest<'a> {
+    inner:
+        crate::account_consumer_disclosures::AccountConsumerDisclosuresConsumerDisclosurePutRequest<
+            'a,
+        >,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> AccountConsumerDisclosuresConsumerDisclosurePutRequest<'a> {
+    /// Sets the `include_metadata` query parameter.
+    pub fn include_metadata(mut self, include_metadata: &'a str) -> Self {
+        self.inner = self.inner.include_metadata(include_metadata);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::ConsumerDisclosure>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::account_consumer_disclosures::AccountConsumerDisclosures`].
+pub struct AccountConsumerDisclosures {
+    inner: crate::account_consumer_disclosures::AccountConsumerDisclosures,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl AccountConsumerDisclosures {
+    pub(crate) fn new(
+        inner: crate::account_consumer_disclosures::AccountConsumerDisclosures,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        AccountConsumerDisclosures { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::account_consumer_disclosures::AccountConsumerDisclosures::consumer_disclosure_get`].
+    pub fn consumer_disclosure_get(
+        &self,
+        account_id: &str,
+        lang_code: &str,
+    ) -> ClientResult<crate::Response<crate::types::AccountConsumerDisclosures>> {
+        self.runtime
+            .block_on(self.inner.consumer_disclosure_get(account_id, lang_code))
+    }
+    /// The blocking version of [`crate::account_consumer_disclosures::AccountConsumerDisclosures::consumer_disclosure_get_request`].
+    pub fn consumer_disclosure_get_request<'a>(
+        &'a self,
+        account_id: &'a str,
+    ) -> AccountConsumerDisclosuresConsumerDisclosureGetRequest<'a> {
+        AccountConsumerDisclosuresConsumerDisclosureGetRequest {
+            inner: self.inner.consumer_disclosure_get_request(account_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::account_consumer_disclosures::AccountConsumerDisclosures::consumer_disclosure_get_lang_code`].
+    pub fn consumer_disclosure_get_lang_code(
+        &self,
+        account_id: &str,
+        lang_code: &str,
+    ) -> ClientResult<crate::Response<crate::types::AccountConsumerDisclosures>> {
+        self.runtime.block_on(
+            self.inner
+                .consumer_disclosure_get_lang_code(account_id, lang_code),
+        )
+    }
+    /// The blocking version of [`crate::account_consumer_disclosures::AccountConsumerDisclosures::consumer_disclosure_put`].
+    pub fn consumer_disclosure_put(
+        &self,
+        account_id: &str,
+        lang_code: &str,
+        include_metadata: &str,
+        body: &crate::types::ConsumerDisclosure,
+    ) -> ClientResult<crate::Response<crate::types::ConsumerDisclosure>> {
+        self.runtime.block_on(self.inner.consumer_disclosure_put(
+            account_id,
+            lang_code,
+            include_metadata,
+            body,
+        ))
+    }
+    /// The blocking version of [`crate::account_consumer_disclosures::AccountConsumerDisclosures::consumer_disclosure_put_request`].
+    pub fn consumer_disclosure_put_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        lang_code: &'a str,
+        body: &'a crate::types::ConsumerDisclosure,
+    ) -> AccountConsumerDisclosuresConsumerDisclosurePutRequest<'a> {
+        AccountConsumerDisclosuresConsumerDisclosurePutRequest {
+            inner: self
+                .inner
+                .consumer_disclosure_put_request(account_id, lang_code, body),
+            runtime: &self.runtime,
+        }
+    }
+}
@@ -0,0 +1,152 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`AccountCustomFields::post`], set with a builder.
+pub struct AccountCustomFieldsPostRequest<'a> {
+    inner: crate::account_custom_fields::AccountCustomFieldsPostRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> AccountCustomFieldsPostRequest<'a> {
+    /// Sets the `apply_to_templates` query parameter.
+    pub fn apply_to_templates(mut self, apply_to_templates: &'a str) -> Self {
+        self.inner = self.inner.apply_to_templates(apply_to_templates);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::AccountCustomFields>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`AccountCustomFields::put`], set with a builder.
+pub struct AccountCustomFieldsPutRequest<'a> {
+    inner: crate::account_custom_fields::AccountCustomFieldsPutRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> AccountCustomFieldsPutRequest<'a> {
+    /// Sets the `apply_to_templates` query parameter.
+    pub fn apply_to_templates(mut self, apply_to_templates: &'a str) -> Self {
+        self.inner = self.inner.apply_to_templates(apply_to_templates);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::AccountCustomFields>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`AccountCustomFields::delete`], set with a builder.
+pub struct AccountCustomFieldsDeleteRequest<'a> {
+    inner: crate::account_custom_fields::AccountCustomFieldsDeleteRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> AccountCustomFieldsDeleteRequest<'a> {
+    /// Sets the `apply_to_templates` query parameter.
+    pub fn apply_to_templates(mut self, apply_to_templates: &'a str) -> Self {
+        self.inner = self.inner.apply_to_templates(apply_to_templates);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::account_custom_fields::AccountCustomFields`].
+pub struct AccountCustomFields {
+    inner: crate::account_custom_fields::AccountCustomFields,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl AccountCustomFields {
+    pub(crate) fn new(
+        inner: crate::account_custom_fields::AccountCustomFields,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        AccountCustomFields { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::account_custom_fields::AccountCustomFields::get`].
+    pub fn get(
+        &self,
+        account_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::AccountCustomFields>> {
+        self.runtime.block_on(self.inner.get(account_id))
+    }
+    /// The blocking version of [`crate::account_custom_fields::AccountCustomFields::post`].
+    pub fn post(
+        &self,
+        account_id: &str,
+        apply_to_templates: &str,
+        body: &crate::types::CustomField,
+    ) -> ClientResult<crate::Response<crate::types::AccountCustomFields>> {
+        self.runtime
+            .block_on(self.inner.post(account_id, apply_to_templates, body))
+    }
+    /// The blocking version of [`crate::account_custom_fields::AccountCustomFields::post_request`].
+    pub fn post_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        body: &'a crate::types::CustomField,
+    ) -> AccountCustomFieldsPostRequest<'a> {
+        AccountCustomFieldsPostRequest {
+            inner: self.inner.post_request(account_id, body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::account_custom_fields::AccountCustomFields::put`].
+    pub fn put(
+        &self,
+        account_id: &str,
+        custom_field_id: &str,
+        apply_to_templates: &str,
+        body: &crate::types::CustomField,
+    ) -> ClientResult<crate::Response<crate::types::AccountCustomFields>> {
+        self.runtime.block_on(
+            self.inner
+                .put(account_id, custom_field_id, apply_to_templates, body),
+        )
+    }
+    /// The blocking version of [`crate::account_custom_fields::AccountCustomFields::put_request`].
+    pub fn put_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        custom_field_id: &'a str,
+        body: &'a crate::types::CustomField,
+    ) -> AccountCustomFieldsPutRequest<'a> {
+        AccountCustomFieldsPutRequest {
+            inner: self.inner.put_request(account_id, custom_field_id, body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::account_custom_fields::AccountCustomFields::delete`].
+    pub fn delete(
+        &self,
+        account_id: &str,
+        custom_field_id: &str,
+        apply_to_templates: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .delete(account_id, custom_field_id, apply_to_templates),
+        )
+    }
+    /// The blocking version of [`crate::account_custom_fields::AccountCustomFields::delete_request`].
+    pub fn delete_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        custom_field_id: &'a str,
+    ) -> AccountCustomFieldsDeleteRequest<'a> {
+        AccountCustomFieldsDeleteRequest {
+            inner: self.inner.delete_request(account_id, custom_field_id),
+            runtime: &self.runtime,
+        }
+    }
+}
@@ -0,0 +1,40 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::account_password_rules::AccountPasswordRules`].
+pub struct AccountPasswordRules {
+    inner: crate::account_password_rules::AccountPasswordRules,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl AccountPasswordRules {
+    pub(crate) fn new(
+        inner: crate::account_password_rules::AccountPasswordRules,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        AccountPasswordRules { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::account_password_rules::AccountPasswordRules::get`].
+    pub fn get(
+        &self,
+        account_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::AccountPasswordRulesData>> {
+        self.runtime.block_on(self.inner.get(account_id))
+    }
+    /// The blocking version of [`crate::account_password_rules::AccountPasswordRules::put`].
+    pub fn put(
+        &self,
+        account_id: &str,
+        body: &crate::types::AccountPasswordRulesData,
+    ) -> ClientResult<crate::Response<crate::types::AccountPasswordRulesData>> {
+        self.runtime.block_on(self.inner.put(account_id, body))
+    }
+    /// The blocking version of [`crate::account_password_rules::AccountPasswordRules::password_rules_get`].
+    pub fn password_rules_get(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::UserPasswordRules>> {
+        self.runtime.block_on(self.inner.password_rules_get())
+    }
+}
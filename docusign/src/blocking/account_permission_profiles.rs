@@ -0,0 +1,251 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`AccountPermissionProfiles::permission_profiles_get`], set with a builder.
+pub struct AccountPermissionProfilesPermissionProfilesGetRequest<'a> {
+    inner:
+        crate::account_permission_profiles::AccountPermissionProfilesPermissionProfilesGetRequest<
+            'a,
+        >,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> AccountPermissionProfilesPermissionProfilesGetRequest<'a> {
+    /// Sets the `include` query parameter.
+    pub fn include(mut self, include: &'a str) -> Self {
+        self.inner = self.inner.include(include);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::PermissionProfileInformation>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`AccountPermissionProfiles::permission_profiles_post`], set with a builder.
+pub struct AccountPermissionProfilesPermissionProfilesPostRequest<'a> {
+    inner:
+        crate::account_permission_profiles::AccountPermissionProfilesPermissionProfilesPostRequest<
+            'a,
+        >,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> AccountPermissionProfilesPermissionProfilesPostRequest<'a> {
+    /// Sets the `include` query parameter.
+    pub fn include(mut self, include: &'a str) -> Self {
+        self.inner = self.inner.include(include);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::PermissionProfile>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`AccountPermissionProfiles::permission_profiles_get_profile`], set with a builder.
+pub struct AccountPermissionProfilesPermissionProfilesGetProfileRequest<'a> {
+            inner: crate::account_permission_profiles::AccountPermissionProfilesPermissionProfilesGetProfileRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a> AccountPermissionProfilesPermissionProfilesGetProfileRequest<'a> {
+    /// Sets the `include` query parameter.
+    pub fn include(mut self, include: &'a str) -> Self {
+        self.inner = self.inner.include(include);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::PermissionProfile>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`AccountPermissionProfiles::permission_profiles_put`], set with a builder.
+pub struct AccountPermissionProfilesPermissionProfilesPutRequest<'a> {
+    inner:
+        crate::account_permission_profiles::AccountPermissionProfilesPermissionProfilesPutRequest<
+            'a,
+        >,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> AccountPermissionProfilesPermissionProfilesPutRequest<'a> {
+    /// Sets the `include` query parameter.
+    pub fn include(mut self, include: &'a str) -> Self {
+        self.inner = self.inner.include(include);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::PermissionProfile>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`AccountPermissionProfiles::permission_profiles_delete`], set with a builder.
+pub struct AccountPermissionProfilesPermissionProfilesDeleteRequest<'a> {
+            inner: crate::account_permission_profiles::AccountPermissionProfilesPermissionProfilesDeleteRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a> AccountPermissionProfilesPermissionProfilesDeleteRequest<'a> {
+    /// Sets the `move_users_to` query parameter.
+    pub fn move_users_to(mut self, move_users_to: &'a str) -> Self {
+        self.inner = self.inner.move_users_to(move_users_to);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::account_permission_profiles::AccountPermissionProfiles`].
+pub struct AccountPermissionProfiles {
+    inner: crate::account_permission_profiles::AccountPermissionProfiles,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl AccountPermissionProfiles {
+    pub(crate) fn new(
+        inner: crate::account_permission_profiles::AccountPermissionProfiles,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        AccountPermissionProfiles { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::account_permission_profiles::AccountPermissionProfiles::permission_profiles_get`].
+    pub fn permission_profiles_get(
+        &self,
+        account_id: &str,
+        include: &str,
+    ) -> ClientResult<crate::Response<crate::types::PermissionProfileInformation>> {
+        self.runtime
+            .block_on(self.inner.permission_profiles_get(account_id, include))
+    }
+    /// The blocking version of [`crate::account_permission_profiles::AccountPermissionProfiles::permission_profiles_get_request`].
+    pub fn permission_profiles_get_request<'a>(
+        &'a self,
+        account_id: &'a str,
+    ) -> AccountPermissionProfilesPermissionProfilesGetRequest<'a> {
+        AccountPermissionProfilesPermissionProfilesGetRequest {
+            inner: self.inner.permission_profiles_get_request(account_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::account_permission_profiles::AccountPermissionProfiles::permission_profiles_post`].
+    pub fn permission_profiles_post(
+        &self,
+        account_id: &str,
+        include: &str,
+        body: &crate::types::PermissionProfile,
+    ) -> ClientResult<crate::Response<crate::types::PermissionProfile>> {
+        self.runtime.block_on(
+            self.inner
+                .permission_profiles_post(account_id, include, body),
+        )
+    }
+    /// The blocking version of [`crate::account_permission_profiles::AccountPermissionProfiles::permission_profiles_post_request`].
+    pub fn permission_profiles_post_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        body: &'a crate::types::PermissionProfile,
+    ) -> AccountPermissionProfilesPermissionProfilesPostRequest<'a> {
+        AccountPermissionProfilesPermissionProfilesPostRequest {
+            inner: self
+                .inner
+                .permission_profiles_post_request(account_id, body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::account_permission_profiles::AccountPermissionProfiles::permission_profiles_get_profile`].
+    pub fn permission_profiles_get_profile(
+        &self,
+        account_id: &str,
+        permission_profile_id: &str,
+        include: &str,
+    ) -> ClientResult<crate::Response<crate::types::PermissionProfile>> {
+        self.runtime
+            .block_on(self.inner.permission_profiles_get_profile(
+                account_id,
+                permission_profile_id,
+                include,
+            ))
+    }
+    /// The blocking version of [`crate::account_permission_profiles::AccountPermissionProfiles::permission_profiles_get_profile_request`].
+    pub fn permission_profiles_get_profile_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        permission_profile_id: &'a str,
+    ) -> AccountPermissionProfilesPermissionProfilesGetProfileRequest<'a> {
+        AccountPermissionProfilesPermissionProfilesGetProfileRequest {
+            inner: self
+                .inner
+                .permission_profiles_get_profile_request(account_id, permission_profile_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::account_permission_profiles::AccountPermissionProfiles::permission_profiles_put`].
+    pub fn permission_profiles_put(
+        &self,
+        account_id: &str,
+        permission_profile_id: &str,
+        include: &str,
+        body: &crate::types::PermissionProfile,
+    ) -> ClientResult<crate::Response<crate::types::PermissionProfile>> {
+        self.runtime.block_on(self.inner.permission_profiles_put(
+            account_id,
+            permission_profile_id,
+            include,
+            body,
+        ))
+    }
+    /// The blocking version of [`crate::account_permission_profiles::AccountPermissionProfiles::permission_profiles_put_request`].
+    pub fn permission_profiles_put_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        permission_profile_id: &'a str,
+        body: &'a crate::types::PermissionProfile,
+    ) -> AccountPermissionProfilesPermissionProfilesPutRequest<'a> {
+        AccountPermissionProfilesPermissionProfilesPutRequest {
+            inner: self.inner.permission_profiles_put_request(
+                account_id,
+                permission_profile_id,
+                body,
+            ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::account_permission_profiles::AccountPermissionProfiles::permission_profiles_delete`].
+    pub fn permission_profiles_delete(
+        &self,
+        account_id: &str,
+        permission_profile_id: &str,
+        move_users_to: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.permission_profiles_delete(
+            account_id,
+            permission_profile_id,
+            move_users_to,
+        ))
+    }
+    /// The blocking version of [`crate::account_permission_profiles::AccountPermissionProfiles::permission_profiles_delete_request`].
+    pub fn permission_profiles_delete_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        permission_profile_id: &'a str,
+    ) -> AccountPermissionProfilesPermissionProfilesDeleteRequest<'a> {
+        AccountPermissionProfilesPermissionProfilesDeleteRequest {
+            inner: self
+                .inner
+                .permission_profiles_delete_request(account_id, permission_profile_id),
+            runtime: &self.runtime,
+        }
+    }
+}
@@ -0,0 +1,27 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::account_seal_providers::AccountSealProviders`].
+pub struct AccountSealProviders {
+    inner: crate::account_seal_providers::AccountSealProviders,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl AccountSealProviders {
+    pub(crate) fn new(
+        inner: crate::account_seal_providers::AccountSealProviders,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        AccountSealProviders { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::account_seal_providers::AccountSealProviders::account_signature_providers_get_seal`].
+    pub fn account_signature_providers_get_seal(
+        &self,
+        account_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::AccountSealProviders>> {
+        self.runtime
+            .block_on(self.inner.account_signature_providers_get_seal(account_id))
+    }
+}
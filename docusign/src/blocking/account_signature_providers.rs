@@ -0,0 +1,26 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::account_signature_providers::AccountSignatureProviders`].
+pub struct AccountSignatureProviders {
+    inner: crate::account_signature_providers::AccountSignatureProviders,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl AccountSignatureProviders {
+    pub(crate) fn new(
+        inner: crate::account_signature_providers::AccountSignatureProviders,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        AccountSignatureProviders { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::account_signature_providers::AccountSignatureProviders::get`].
+    pub fn get(
+        &self,
+        account_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::AccountSignatureProvidersData>> {
+        self.runtime.block_on(self.inner.get(account_id))
+    }
+}
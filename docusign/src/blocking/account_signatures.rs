@@ -0,0 +1,299 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`AccountSignatures::get`], set with a builder.
+pub struct AccountSignaturesGetRequest<'a> {
+    inner: crate::account_signatures::AccountSignaturesGetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> AccountSignaturesGetRequest<'a> {
+    /// Sets the `stamp_format` query parameter.
+    pub fn stamp_format(mut self, stamp_format: &'a str) -> Self {
+        self.inner = self.inner.stamp_format(stamp_format);
+        self
+    }
+
+    /// Sets the `stamp_name` query parameter.
+    pub fn stamp_name(mut self, stamp_name: &'a str) -> Self {
+        self.inner = self.inner.stamp_name(stamp_name);
+        self
+    }
+
+    /// Sets the `stamp_type` query parameter.
+    pub fn stamp_type(mut self, stamp_type: &'a str) -> Self {
+        self.inner = self.inner.stamp_type(stamp_type);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::AccountSignaturesInformation>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`AccountSignatures::post`], set with a builder.
+pub struct AccountSignaturesPostRequest<'a> {
+    inner: crate::account_signatures::AccountSignaturesPostRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> AccountSignaturesPostRequest<'a> {
+    /// Sets the `decode_only` query parameter.
+    pub fn decode_only(mut self, decode_only: &'a str) -> Self {
+        self.inner = self.inner.decode_only(decode_only);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::AccountSignaturesInformation>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`AccountSignatures::put_signature_account_signatures`], set with a builder.
+pub struct AccountSignaturesPutSignatureAccountSignaturesRequest<'a> {
+    inner: crate::account_signatures::AccountSignaturesPutSignatureAccountSignaturesRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> AccountSignaturesPutSignatureAccountSignaturesRequest<'a> {
+    /// Sets the `close_existing_signature` query parameter.
+    pub fn close_existing_signature(mut self, close_existing_signature: &'a str) -> Self {
+        self.inner = self
+            .inner
+            .close_existing_signature(close_existing_signature);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::AccountSignature>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`AccountSignatures::get_signature_image`], set with a builder.
+pub struct AccountSignaturesGetSignatureImageRequest<'a> {
+    inner: crate::account_signatures::AccountSignaturesGetSignatureImageRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> AccountSignaturesGetSignatureImageRequest<'a> {
+    /// Sets the `include_chrome` query parameter.
+    pub fn include_chrome(mut self, include_chrome: &'a str) -> Self {
+        self.inner = self.inner.include_chrome(include_chrome);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`AccountSignatures::put_signature_image`], set with a builder.
+pub struct AccountSignaturesPutSignatureImageRequest<'a> {
+    inner: crate::account_signatures::AccountSignaturesPutSignatureImageRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> AccountSignaturesPutSignatureImageRequest<'a> {
+    /// Sets the `transparent_png` query parameter.
+    pub fn transparent_png(mut self, transparent_png: &'a str) -> Self {
+        self.inner = self.inner.transparent_png(transparent_png);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::AccountSignature>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::account_signatures::AccountSignatures`].
+pub struct AccountSignatures {
+    inner: crate::account_signatures::AccountSignatures,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl AccountSignatures {
+    pub(crate) fn new(
+        inner: crate::account_signatures::AccountSignatures,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        AccountSignatures { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::account_signatures::AccountSignatures::get`].
+    pub fn get(
+        &self,
+        account_id: &str,
+        stamp_format: &str,
+        stamp_name: &str,
+        stamp_type: &str,
+    ) -> ClientResult<crate::Response<crate::types::AccountSignaturesInformation>> {
+        self.runtime.block_on(
+            self.inner
+                .get(account_id, stamp_format, stamp_name, stamp_type),
+        )
+    }
+    /// The blocking version of [`crate::account_signatures::AccountSignatures::get_request`].
+    pub fn get_request<'a>(&'a self, account_id: &'a str) -> AccountSignaturesGetRequest<'a> {
+        AccountSignaturesGetRequest {
+            inner: self.inner.get_request(account_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::account_signatures::AccountSignatures::put_signature`].
+    pub fn put_signature(
+        &self,
+        account_id: &str,
+        body: &crate::types::AccountSignaturesInformation,
+    ) -> ClientResult<crate::Response<crate::types::AccountSignaturesInformation>> {
+        self.runtime
+            .block_on(self.inner.put_signature(account_id, body))
+    }
+    /// The blocking version of [`crate::account_signatures::AccountSignatures::post`].
+    pub fn post(
+        &self,
+        account_id: &str,
+        decode_only: &str,
+        body: &crate::types::AccountSignaturesInformation,
+    ) -> ClientResult<crate::Response<crate::types::AccountSignaturesInformation>> {
+        self.runtime
+            .block_on(self.inner.post(account_id, decode_only, body))
+    }
+    /// The blocking version of [`crate::account_signatures::AccountSignatures::post_request`].
+    pub fn post_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        body: &'a crate::types::AccountSignaturesInformation,
+    ) -> AccountSignaturesPostRequest<'a> {
+        AccountSignaturesPostRequest {
+            inner: self.inner.post_request(account_id, body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::account_signatures::AccountSignatures::get_signature`].
+    pub fn get_signature(
+        &self,
+        account_id: &str,
+        signature_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::AccountSignature>> {
+        self.runtime
+            .block_on(self.inner.get_signature(account_id, signature_id))
+    }
+    /// The blocking version of [`crate::account_signatures::AccountSignatures::put_signature_account_signatures`].
+    pub fn put_signature_account_signatures(
+        &self,
+        account_id: &str,
+        signature_id: &str,
+        close_existing_signature: &str,
+        body: &crate::types::AccountSignatureDefinition,
+    ) -> ClientResult<crate::Response<crate::types::AccountSignature>> {
+        self.runtime
+            .block_on(self.inner.put_signature_account_signatures(
+                account_id,
+                signature_id,
+                close_existing_signature,
+                body,
+            ))
+    }
+    /// The blocking version of [`crate::account_signatures::AccountSignatures::put_signature_account_signatures_request`].
+    pub fn put_signature_account_signatures_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        signature_id: &'a str,
+        body: &'a crate::types::AccountSignatureDefinition,
+    ) -> AccountSignaturesPutSignatureAccountSignaturesRequest<'a> {
+        AccountSignaturesPutSignatureAccountSignaturesRequest {
+            inner: self.inner.put_signature_account_signatures_request(
+                account_id,
+                signature_id,
+                body,
+            ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::account_signatures::AccountSignatures::delete_signature`].
+    pub fn delete_signature(
+        &self,
+        account_id: &str,
+        signature_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete_signature(account_id, signature_id))
+    }
+    /// The blocking version of [`crate::account_signatures::AccountSignatures::get_signature_image`].
+    pub fn get_signature_image(
+        &self,
+        account_id: &str,
+        image_type: &str,
+        signature_id: &str,
+        include_chrome: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.get_signature_image(
+            account_id,
+            image_type,
+            signature_id,
+            include_chrome,
+        ))
+    }
+    /// The blocking version of [`crate::account_signatures::AccountSignatures::get_signature_image_request`].
+    pub fn get_signature_image_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        image_type: &'a str,
+        signature_id: &'a str,
+    ) -> AccountSignaturesGetSignatureImageRequest<'a> {
+        AccountSignaturesGetSignatureImageRequest {
+            inner: self
+                .inner
+                .get_signature_image_request(account_id, image_type, signature_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::account_signatures::AccountSignatures::put_signature_image`].
+    pub fn put_signature_image(
+        &self,
+        account_id: &str,
+        image_type: &str,
+        signature_id: &str,
+        transparent_png: &str,
+    ) -> ClientResult<crate::Response<crate::types::AccountSignature>> {
+        self.runtime.block_on(self.inner.put_signature_image(
+            account_id,
+            image_type,
+            signature_id,
+            transparent_png,
+        ))
+    }
+    /// The blocking version of [`crate::account_signatures::AccountSignatures::put_signature_image_request`].
+    pub fn put_signature_image_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        image_type: &'a str,
+        signature_id: &'a str,
+    ) -> AccountSignaturesPutSignatureImageRequest<'a> {
+        AccountSignaturesPutSignatureImageRequest {
+            inner: self
+                .inner
+                .put_signature_image_request(account_id, image_type, signature_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::account_signatures::AccountSignatures::delete_signature_image`].
+    pub fn delete_signature_image(
+        &self,
+        account_id: &str,
+        image_type: &str,
+        signature_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::AccountSignature>> {
+        self.runtime.block_on(self.inner.delete_signature_image(
+            account_id,
+            image_type,
+            signature_id,
+        ))
+    }
+}
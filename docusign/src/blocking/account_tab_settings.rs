@@ -0,0 +1,36 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::account_tab_settings::AccountTabSettings`].
+pub struct AccountTabSettings {
+    inner: crate::account_tab_settings::AccountTabSettings,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl AccountTabSettings {
+    pub(crate) fn new(
+        inner: crate::account_tab_settings::AccountTabSettings,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        AccountTabSettings { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::account_tab_settings::AccountTabSettings::tab_settings_get`].
+    pub fn tab_settings_get(
+        &self,
+        account_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::TabAccountSettings>> {
+        self.runtime
+            .block_on(self.inner.tab_settings_get(account_id))
+    }
+    /// The blocking version of [`crate::account_tab_settings::AccountTabSettings::tab_settings_put`].
+    pub fn tab_settings_put(
+        &self,
+        account_id: &str,
+        body: &crate::types::TabAccountSettings,
+    ) -> ClientResult<crate::Response<crate::types::TabAccountSettings>> {
+        self.runtime
+            .block_on(self.inner.tab_settings_put(account_id, body))
+    }
+}
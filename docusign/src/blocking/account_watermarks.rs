@@ -0,0 +1,44 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::account_watermarks::AccountWatermarks`].
+pub struct AccountWatermarks {
+    inner: crate::account_watermarks::AccountWatermarks,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl AccountWatermarks {
+    pub(crate) fn new(
+        inner: crate::account_watermarks::AccountWatermarks,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        AccountWatermarks { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::account_watermarks::AccountWatermarks::watermark_get`].
+    pub fn watermark_get(
+        &self,
+        account_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Watermark>> {
+        self.runtime.block_on(self.inner.watermark_get(account_id))
+    }
+    /// The blocking version of [`crate::account_watermarks::AccountWatermarks::watermark_put`].
+    pub fn watermark_put(
+        &self,
+        account_id: &str,
+        body: &crate::types::Watermark,
+    ) -> ClientResult<crate::Response<crate::types::Watermark>> {
+        self.runtime
+            .block_on(self.inner.watermark_put(account_id, body))
+    }
+    /// The blocking version of [`crate::account_watermarks::AccountWatermarks::watermark_preview_put`].
+    pub fn watermark_preview_put(
+        &self,
+        account_id: &str,
+        body: &crate::types::Watermark,
+    ) -> ClientResult<crate::Response<crate::types::Watermark>> {
+        self.runtime
+            .block_on(self.inner.watermark_preview_put(account_id, body))
+    }
+}
@@ -0,0 +1,406 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Accounts::get`], set with a builder.
+pub struct AccountsGetRequest<'a> {
+    inner: crate::accounts::AccountsGetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> AccountsGetRequest<'a> {
+    /// Sets the `include_account_settings` query parameter.
+    pub fn include_account_settings(mut self, include_account_settings: &'a str) -> Self {
+        self.inner = self
+            .inner
+            .include_account_settings(include_account_settings);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::AccountInformation>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Accounts::billing_charges_get`], set with a builder.
+pub struct AccountsBillingChargesGetRequest<'a> {
+    inner: crate::accounts::AccountsBillingChargesGetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> AccountsBillingChargesGetRequest<'a> {
+    /// Sets the `include_charges` query parameter.
+    pub fn include_charges(mut self, include_charges: &'a str) -> Self {
+        self.inner = self.inner.include_charges(include_charges);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::BillingChargeResponse>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Accounts::recipient_names_get`], set with a builder.
+pub struct AccountsRecipientNamesGetRequest<'a> {
+    inner: crate::accounts::AccountsRecipientNamesGetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> AccountsRecipientNamesGetRequest<'a> {
+    /// Sets the `email` query parameter.
+    pub fn email(mut self, email: &'a str) -> Self {
+        self.inner = self.inner.email(email);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::RecipientNamesResponse>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Accounts::shared_access_get`], set with a builder.
+pub struct AccountsSharedAccessGetRequest<'a> {
+    inner: crate::accounts::AccountsSharedAccessGetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> AccountsSharedAccessGetRequest<'a> {
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: &'a str) -> Self {
+        self.inner = self.inner.count(count);
+        self
+    }
+
+    /// Sets the `envelopes_not_shared_user_status` query parameter.
+    pub fn envelopes_not_shared_user_status(
+        mut self,
+        envelopes_not_shared_user_status: &'a str,
+    ) -> Self {
+        self.inner = self
+            .inner
+            .envelopes_not_shared_user_status(envelopes_not_shared_user_status);
+        self
+    }
+
+    /// Sets the `folder_ids` query parameter.
+    pub fn folder_ids(mut self, folder_ids: &'a str) -> Self {
+        self.inner = self.inner.folder_ids(folder_ids);
+        self
+    }
+
+    /// Sets the `item_type` query parameter.
+    pub fn item_type(mut self, item_type: &'a str) -> Self {
+        self.inner = self.inner.item_type(item_type);
+        self
+    }
+
+    /// Sets the `search_text` query parameter.
+    pub fn search_text(mut self, search_text: &'a str) -> Self {
+        self.inner = self.inner.search_text(search_text);
+        self
+    }
+
+    /// Sets the `shared` query parameter.
+    pub fn shared(mut self, shared: &'a str) -> Self {
+        self.inner = self.inner.shared(shared);
+        self
+    }
+
+    /// Sets the `start_position` query parameter.
+    pub fn start_position(mut self, start_position: &'a str) -> Self {
+        self.inner = self.inner.start_position(start_position);
+        self
+    }
+
+    /// Sets the `user_ids` query parameter.
+    pub fn user_ids(mut self, user_ids: &'a str) -> Self {
+        self.inner = self.inner.user_ids(user_ids);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::AccountSharedAccess>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Accounts::shared_access_put`], set with a builder.
+pub struct AccountsSharedAccessPutRequest<'a> {
+    inner: crate::accounts::AccountsSharedAccessPutRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> AccountsSharedAccessPutRequest<'a> {
+    /// Sets the `item_type` query parameter.
+    pub fn item_type(mut self, item_type: &'a str) -> Self {
+        self.inner = self.inner.item_type(item_type);
+        self
+    }
+
+    /// Sets the `preserve_existing_shared_access` query parameter.
+    pub fn preserve_existing_shared_access(
+        mut self,
+        preserve_existing_shared_access: &'a str,
+    ) -> Self {
+        self.inner = self
+            .inner
+            .preserve_existing_shared_access(preserve_existing_shared_access);
+        self
+    }
+
+    /// Sets the `user_ids` query parameter.
+    pub fn user_ids(mut self, user_ids: &'a str) -> Self {
+        self.inner = self.inner.user_ids(user_ids);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::AccountSharedAccess>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::accounts::Accounts`].
+pub struct Accounts {
+    inner: crate::accounts::Accounts,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Accounts {
+    pub(crate) fn new(
+        inner: crate::accounts::Accounts,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Accounts { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::accounts::Accounts::post`].
+    pub fn post(
+        &self,
+        body: &crate::types::NewAccountDefinition,
+    ) -> ClientResult<crate::Response<crate::types::NewAccountSummary>> {
+        self.runtime.block_on(self.inner.post(body))
+    }
+    /// The blocking version of [`crate::accounts::Accounts::get_provisioning`].
+    pub fn get_provisioning(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::ProvisioningInformation>> {
+        self.runtime.block_on(self.inner.get_provisioning())
+    }
+    /// The blocking version of [`crate::accounts::Accounts::get`].
+    pub fn get(
+        &self,
+        account_id: &str,
+        include_account_settings: &str,
+    ) -> ClientResult<crate::Response<crate::types::AccountInformation>> {
+        self.runtime
+            .block_on(self.inner.get(account_id, include_account_settings))
+    }
+    /// The blocking version of [`crate::accounts::Accounts::get_request`].
+    pub fn get_request<'a>(&'a self, account_id: &'a str) -> AccountsGetRequest<'a> {
+        AccountsGetRequest {
+            inner: self.inner.get_request(account_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::accounts::Accounts::delete`].
+    pub fn delete(&self, account_id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.delete(account_id))
+    }
+    /// The blocking version of [`crate::accounts::Accounts::billing_charges_get`].
+    pub fn billing_charges_get(
+        &self,
+        account_id: &str,
+        include_charges: &str,
+    ) -> ClientResult<crate::Response<crate::types::BillingChargeResponse>> {
+        self.runtime
+            .block_on(self.inner.billing_charges_get(account_id, include_charges))
+    }
+    /// The blocking version of [`crate::accounts::Accounts::billing_charges_get_request`].
+    pub fn billing_charges_get_request<'a>(
+        &'a self,
+        account_id: &'a str,
+    ) -> AccountsBillingChargesGetRequest<'a> {
+        AccountsBillingChargesGetRequest {
+            inner: self.inner.billing_charges_get_request(account_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::accounts::Accounts::captive_recipients_delete_part`].
+    pub fn captive_recipients_delete_part(
+        &self,
+        account_id: &str,
+        recipient_part: &str,
+        body: &crate::types::CaptiveRecipientInformation,
+    ) -> ClientResult<crate::Response<crate::types::CaptiveRecipientInformation>> {
+        self.runtime
+            .block_on(
+                self.inner
+                    .captive_recipients_delete_part(account_id, recipient_part, body),
+            )
+    }
+    /// The blocking version of [`crate::accounts::Accounts::recipient_names_get`].
+    pub fn recipient_names_get(
+        &self,
+        account_id: &str,
+        email: &str,
+    ) -> ClientResult<crate::Response<crate::types::RecipientNamesResponse>> {
+        self.runtime
+            .block_on(self.inner.recipient_names_get(account_id, email))
+    }
+    /// The blocking version of [`crate::accounts::Accounts::recipient_names_get_request`].
+    pub fn recipient_names_get_request<'a>(
+        &'a self,
+        account_id: &'a str,
+    ) -> AccountsRecipientNamesGetRequest<'a> {
+        AccountsRecipientNamesGetRequest {
+            inner: self.inner.recipient_names_get_request(account_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::accounts::Accounts::settings_get`].
+    pub fn settings_get(
+        &self,
+        account_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::AccountSettingsInformation>> {
+        self.runtime.block_on(self.inner.settings_get(account_id))
+    }
+    /// The blocking version of [`crate::accounts::Accounts::settings_put`].
+    pub fn settings_put(
+        &self,
+        account_id: &str,
+        body: &crate::types::AccountSettingsInformation,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.settings_put(account_id, body))
+    }
+    /// The blocking version of [`crate::accounts::Accounts::envelope_purge_configuration_get`].
+    pub fn envelope_purge_configuration_get(
+        &self,
+        account_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::EnvelopePurgeConfiguration>> {
+        self.runtime
+            .block_on(self.inner.envelope_purge_configuration_get(account_id))
+    }
+    /// The blocking version of [`crate::accounts::Accounts::envelope_purge_configuration_put`].
+    pub fn envelope_purge_configuration_put(
+        &self,
+        account_id: &str,
+        body: &crate::types::EnvelopePurgeConfiguration,
+    ) -> ClientResult<crate::Response<crate::types::EnvelopePurgeConfiguration>> {
+        self.runtime.block_on(
+            self.inner
+                .envelope_purge_configuration_put(account_id, body),
+        )
+    }
+    /// The blocking version of [`crate::accounts::Accounts::notification_defaults_get`].
+    pub fn notification_defaults_get(
+        &self,
+        account_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::NotificationDefaultsData>> {
+        self.runtime
+            .block_on(self.inner.notification_defaults_get(account_id))
+    }
+    /// The blocking version of [`crate::accounts::Accounts::notification_defaults_put`].
+    pub fn notification_defaults_put(
+        &self,
+        account_id: &str,
+        body: &crate::types::NotificationDefaultsData,
+    ) -> ClientResult<crate::Response<crate::types::NotificationDefaultsData>> {
+        self.runtime
+            .block_on(self.inner.notification_defaults_put(account_id, body))
+    }
+    /// The blocking version of [`crate::accounts::Accounts::shared_access_get`].
+    pub fn shared_access_get(
+        &self,
+        account_id: &str,
+        count: &str,
+        envelopes_not_shared_user_status: &str,
+        folder_ids: &str,
+        item_type: &str,
+        search_text: &str,
+        shared: &str,
+        start_position: &str,
+        user_ids: &str,
+    ) -> ClientResult<crate::Response<crate::types::AccountSharedAccess>> {
+        self.runtime.block_on(self.inner.shared_access_get(
+            account_id,
+            count,
+            envelopes_not_shared_user_status,
+            folder_ids,
+            item_type,
+            search_text,
+            shared,
+            start_position,
+            user_ids,
+        ))
+    }
+    /// The blocking version of [`crate::accounts::Accounts::shared_access_get_request`].
+    pub fn shared_access_get_request<'a>(
+        &'a self,
+        account_id: &'a str,
+    ) -> AccountsSharedAccessGetRequest<'a> {
+        AccountsSharedAccessGetRequest {
+            inner: self.inner.shared_access_get_request(account_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::accounts::Accounts::shared_access_put`].
+    pub fn shared_access_put(
+        &self,
+        account_id: &str,
+        item_type: &str,
+        preserve_existing_shared_access: &str,
+        user_ids: &str,
+        body: &crate::types::AccountSharedAccess,
+    ) -> ClientResult<crate::Response<crate::types::AccountSharedAccess>> {
+        self.runtime.block_on(self.inner.shared_access_put(
+            account_id,
+            item_type,
+            preserve_existing_shared_access,
+            user_ids,
+            body,
+        ))
+    }
+    /// The blocking version of [`crate::accounts::Accounts::shared_access_put_request`].
+    pub fn shared_access_put_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        body: &'a crate::types::AccountSharedAccess,
+    ) -> AccountsSharedAccessPutRequest<'a> {
+        AccountsSharedAccessPutRequest {
+            inner: self.inner.shared_access_put_request(account_id, body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::accounts::Accounts::supported_languages_get`].
+    pub fn supported_languages_get(
+        &self,
+        account_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::SupportedLanguages>> {
+        self.runtime
+            .block_on(self.inner.supported_languages_get(account_id))
+    }
+    /// The blocking version of [`crate::accounts::Accounts::unsupported_file_types_get`].
+    pub fn unsupported_file_types_get(
+        &self,
+        account_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::FileTypeList>> {
+        self.runtime
+            .block_on(self.inner.unsupported_file_types_get(account_id))
+    }
+    /// The blocking version of [`crate::accounts::Accounts::organization_exports_get_settings_export`].
+    pub fn organization_exports_get_settings_export(
+        &self,
+        organization_id: &str,
+        result_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .organization_exports_get_settings_export(organization_id, result_id),
+        )
+    }
+}
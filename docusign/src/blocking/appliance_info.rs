@@ -0,0 +1,270 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::appliance_info::ApplianceInfo`].
+pub struct ApplianceInfo {
+    inner: crate::appliance_info::ApplianceInfo,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl ApplianceInfo {
+    pub(crate) fn new(
+        inner: crate::appliance_info::ApplianceInfo,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        ApplianceInfo { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::appliance_info::ApplianceInfo::envelope_get_dynamic_system_setting`].
+    pub fn envelope_get_dynamic_system_setting(
+        &self,
+        account_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ApplianceInfo>> {
+        self.runtime
+            .block_on(self.inner.envelope_get_dynamic_system_setting(account_id))
+    }
+    /// The blocking version of [`crate::appliance_info::ApplianceInfo::envelope_get_template`].
+    pub fn envelope_get_template(
+        &self,
+        account_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ApplianceInfo>> {
+        self.runtime
+            .block_on(self.inner.envelope_get_template(account_id))
+    }
+    /// The blocking version of [`crate::appliance_info::ApplianceInfo::envelope_get`].
+    pub fn envelope_get(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ApplianceInfo>> {
+        self.runtime
+            .block_on(self.inner.envelope_get(account_id, envelope_id))
+    }
+    /// The blocking version of [`crate::appliance_info::ApplianceInfo::envelope_get_account`].
+    pub fn envelope_get_account(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::DisplayApplianceAccount>> {
+        self.runtime
+            .block_on(self.inner.envelope_get_account(account_id, envelope_id))
+    }
+    /// The blocking version of [`crate::appliance_info::ApplianceInfo::envelope_get_custom_field`].
+    pub fn envelope_get_custom_field(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ApplianceInfo>> {
+        self.runtime.block_on(
+            self.inner
+                .envelope_get_custom_field(account_id, envelope_id),
+        )
+    }
+    /// The blocking version of [`crate::appliance_info::ApplianceInfo::envelope_delete_custom_fields`].
+    pub fn envelope_delete_custom_fields(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .envelope_delete_custom_fields(account_id, envelope_id),
+        )
+    }
+    /// The blocking version of [`crate::appliance_info::ApplianceInfo::envelope_get_date_signed`].
+    pub fn envelope_get_date_signed(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ApplianceInfo>> {
+        self.runtime
+            .block_on(self.inner.envelope_get_date_signed(account_id, envelope_id))
+    }
+    /// The blocking version of [`crate::appliance_info::ApplianceInfo::envelope_put_document`].
+    pub fn envelope_put_document(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.envelope_put_document(
+            account_id,
+            document_id,
+            envelope_id,
+        ))
+    }
+    /// The blocking version of [`crate::appliance_info::ApplianceInfo::envelope_delete_document`].
+    pub fn envelope_delete_document(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.envelope_delete_document(
+            account_id,
+            document_id,
+            envelope_id,
+        ))
+    }
+    /// The blocking version of [`crate::appliance_info::ApplianceInfo::envelope_get_document_page`].
+    pub fn envelope_get_document_page(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ApplianceInfo>> {
+        self.runtime.block_on(
+            self.inner
+                .envelope_get_document_page(account_id, envelope_id),
+        )
+    }
+    /// The blocking version of [`crate::appliance_info::ApplianceInfo::envelope_get_image`].
+    pub fn envelope_get_image(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ApplianceInfo>> {
+        self.runtime
+            .block_on(self.inner.envelope_get_image(account_id, envelope_id))
+    }
+    /// The blocking version of [`crate::appliance_info::ApplianceInfo::envelope_get_locale_policy`].
+    pub fn envelope_get_locale_policy(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        user_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ApplianceInfo>> {
+        self.runtime.block_on(self.inner.envelope_get_locale_policy(
+            account_id,
+            envelope_id,
+            user_id,
+        ))
+    }
+    /// The blocking version of [`crate::appliance_info::ApplianceInfo::envelope_put_page`].
+    pub fn envelope_put_page(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.envelope_put_page(account_id, envelope_id))
+    }
+    /// The blocking version of [`crate::appliance_info::ApplianceInfo::envelope_post_page`].
+    pub fn envelope_post_page(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.envelope_post_page(account_id, envelope_id))
+    }
+    /// The blocking version of [`crate::appliance_info::ApplianceInfo::envelope_delete_page`].
+    pub fn envelope_delete_page(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.envelope_delete_page(account_id, envelope_id))
+    }
+    /// The blocking version of [`crate::appliance_info::ApplianceInfo::envelope_put_pdf`].
+    pub fn envelope_put_pdf(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ApplianceInfo>> {
+        self.runtime
+            .block_on(self.inner.envelope_put_pdf(account_id, envelope_id))
+    }
+    /// The blocking version of [`crate::appliance_info::ApplianceInfo::envelope_get_pdf`].
+    pub fn envelope_get_pdf(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        pdf_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ApplianceInfo>> {
+        self.runtime
+            .block_on(self.inner.envelope_get_pdf(account_id, envelope_id, pdf_id))
+    }
+    /// The blocking version of [`crate::appliance_info::ApplianceInfo::envelope_get_pdf_blob`].
+    pub fn envelope_get_pdf_blob(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ApplianceInfo>> {
+        self.runtime
+            .block_on(self.inner.envelope_get_pdf_blob(account_id, envelope_id))
+    }
+    /// The blocking version of [`crate::appliance_info::ApplianceInfo::envelope_put_pdf_blob`].
+    pub fn envelope_put_pdf_blob(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.envelope_put_pdf_blob(account_id, envelope_id))
+    }
+    /// The blocking version of [`crate::appliance_info::ApplianceInfo::envelope_post_pdf_blob`].
+    pub fn envelope_post_pdf_blob(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ApplianceInfo>> {
+        self.runtime
+            .block_on(self.inner.envelope_post_pdf_blob(account_id, envelope_id))
+    }
+    /// The blocking version of [`crate::appliance_info::ApplianceInfo::envelope_put_recipient_denied_document_copy`].
+    pub fn envelope_put_recipient_denied_document_copy(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .envelope_put_recipient_denied_document_copy(account_id, envelope_id),
+        )
+    }
+    /// The blocking version of [`crate::appliance_info::ApplianceInfo::envelope_delete_recipient_denied_document_copy`].
+    pub fn envelope_delete_recipient_denied_document_copy(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .envelope_delete_recipient_denied_document_copy(account_id, envelope_id),
+        )
+    }
+    /// The blocking version of [`crate::appliance_info::ApplianceInfo::envelope_get_signer_attachment`].
+    pub fn envelope_get_signer_attachment(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ApplianceInfo>> {
+        self.runtime.block_on(
+            self.inner
+                .envelope_get_signer_attachment(account_id, envelope_id),
+        )
+    }
+    /// The blocking version of [`crate::appliance_info::ApplianceInfo::envelope_delete_signer_attachment`].
+    pub fn envelope_delete_signer_attachment(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .envelope_delete_signer_attachment(account_id, envelope_id),
+        )
+    }
+    /// The blocking version of [`crate::appliance_info::ApplianceInfo::envelope_post_error`].
+    pub fn envelope_post_error(&self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.envelope_post_error())
+    }
+    /// The blocking version of [`crate::appliance_info::ApplianceInfo::envelope_post_redeem`].
+    pub fn envelope_post_redeem(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::ApplianceInfo>> {
+        self.runtime.block_on(self.inner.envelope_post_redeem())
+    }
+}
@@ -0,0 +1,134 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`BccEmailArchive::get_list`], set with a builder.
+pub struct BccEmailArchiveGetListRequest<'a> {
+    inner: crate::bcc_email_archive::BccEmailArchiveGetListRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> BccEmailArchiveGetListRequest<'a> {
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: &'a str) -> Self {
+        self.inner = self.inner.count(count);
+        self
+    }
+
+    /// Sets the `start_position` query parameter.
+    pub fn start_position(mut self, start_position: &'a str) -> Self {
+        self.inner = self.inner.start_position(start_position);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::BccEmailArchiveList>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`BccEmailArchive::get_history_list`], set with a builder.
+pub struct BccEmailArchiveGetHistoryListRequest<'a> {
+    inner: crate::bcc_email_archive::BccEmailArchiveGetHistoryListRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> BccEmailArchiveGetHistoryListRequest<'a> {
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: &'a str) -> Self {
+        self.inner = self.inner.count(count);
+        self
+    }
+
+    /// Sets the `start_position` query parameter.
+    pub fn start_position(mut self, start_position: &'a str) -> Self {
+        self.inner = self.inner.start_position(start_position);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::BccEmailArchiveHistoryList>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::bcc_email_archive::BccEmailArchive`].
+pub struct BccEmailArchive {
+    inner: crate::bcc_email_archive::BccEmailArchive,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl BccEmailArchive {
+    pub(crate) fn new(
+        inner: crate::bcc_email_archive::BccEmailArchive,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        BccEmailArchive { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::bcc_email_archive::BccEmailArchive::get_list`].
+    pub fn get_list(
+        &self,
+        account_id: &str,
+        count: &str,
+        start_position: &str,
+    ) -> ClientResult<crate::Response<crate::types::BccEmailArchiveList>> {
+        self.runtime
+            .block_on(self.inner.get_list(account_id, count, start_position))
+    }
+    /// The blocking version of [`crate::bcc_email_archive::BccEmailArchive::get_list_request`].
+    pub fn get_list_request<'a>(
+        &'a self,
+        account_id: &'a str,
+    ) -> BccEmailArchiveGetListRequest<'a> {
+        BccEmailArchiveGetListRequest {
+            inner: self.inner.get_list_request(account_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::bcc_email_archive::BccEmailArchive::post`].
+    pub fn post(
+        &self,
+        account_id: &str,
+        body: &crate::types::BccEmailArchiveData,
+    ) -> ClientResult<crate::Response<crate::types::BccEmailArchiveData>> {
+        self.runtime.block_on(self.inner.post(account_id, body))
+    }
+    /// The blocking version of [`crate::bcc_email_archive::BccEmailArchive::get_history_list`].
+    pub fn get_history_list(
+        &self,
+        account_id: &str,
+        bcc_email_archive_id: &str,
+        count: &str,
+        start_position: &str,
+    ) -> ClientResult<crate::Response<crate::types::BccEmailArchiveHistoryList>> {
+        self.runtime.block_on(self.inner.get_history_list(
+            account_id,
+            bcc_email_archive_id,
+            count,
+            start_position,
+        ))
+    }
+    /// The blocking version of [`crate::bcc_email_archive::BccEmailArchive::get_history_list_request`].
+    pub fn get_history_list_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        bcc_email_archive_id: &'a str,
+    ) -> BccEmailArchiveGetHistoryListRequest<'a> {
+        BccEmailArchiveGetHistoryListRequest {
+            inner: self
+                .inner
+                .get_history_list_request(account_id, bcc_email_archive_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::bcc_email_archive::BccEmailArchive::delete`].
+    pub fn delete(
+        &self,
+        account_id: &str,
+        bcc_email_archive_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete(account_id, bcc_email_archive_id))
+    }
+}
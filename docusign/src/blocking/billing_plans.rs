@@ -0,0 +1,164 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`BillingPlans::get`], set with a builder.
+pub struct BillingPlansGetRequest<'a> {
+    inner: crate::billing_plans::BillingPlansGetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> BillingPlansGetRequest<'a> {
+    /// Sets the `include_credit_card_information` query parameter.
+    pub fn include_credit_card_information(
+        mut self,
+        include_credit_card_information: &'a str,
+    ) -> Self {
+        self.inner = self
+            .inner
+            .include_credit_card_information(include_credit_card_information);
+        self
+    }
+
+    /// Sets the `include_metadata` query parameter.
+    pub fn include_metadata(mut self, include_metadata: &'a str) -> Self {
+        self.inner = self.inner.include_metadata(include_metadata);
+        self
+    }
+
+    /// Sets the `include_successor_plans` query parameter.
+    pub fn include_successor_plans(mut self, include_successor_plans: &'a str) -> Self {
+        self.inner = self.inner.include_successor_plans(include_successor_plans);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::AccountBillingPlanResponse>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`BillingPlans::put`], set with a builder.
+pub struct BillingPlansPutRequest<'a> {
+    inner: crate::billing_plans::BillingPlansPutRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> BillingPlansPutRequest<'a> {
+    /// Sets the `preview_billing_plan` query parameter.
+    pub fn preview_billing_plan(mut self, preview_billing_plan: &'a str) -> Self {
+        self.inner = self.inner.preview_billing_plan(preview_billing_plan);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::BillingPlanUpdateResponse>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::billing_plans::BillingPlans`].
+pub struct BillingPlans {
+    inner: crate::billing_plans::BillingPlans,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl BillingPlans {
+    pub(crate) fn new(
+        inner: crate::billing_plans::BillingPlans,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        BillingPlans { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::billing_plans::BillingPlans::get`].
+    pub fn get(
+        &self,
+        account_id: &str,
+        include_credit_card_information: &str,
+        include_metadata: &str,
+        include_successor_plans: &str,
+    ) -> ClientResult<crate::Response<crate::types::AccountBillingPlanResponse>> {
+        self.runtime.block_on(self.inner.get(
+            account_id,
+            include_credit_card_information,
+            include_metadata,
+            include_successor_plans,
+        ))
+    }
+    /// The blocking version of [`crate::billing_plans::BillingPlans::get_request`].
+    pub fn get_request<'a>(&'a self, account_id: &'a str) -> BillingPlansGetRequest<'a> {
+        BillingPlansGetRequest {
+            inner: self.inner.get_request(account_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::billing_plans::BillingPlans::put`].
+    pub fn put(
+        &self,
+        account_id: &str,
+        preview_billing_plan: &str,
+        body: &crate::types::BillingPlanInformation,
+    ) -> ClientResult<crate::Response<crate::types::BillingPlanUpdateResponse>> {
+        self.runtime
+            .block_on(self.inner.put(account_id, preview_billing_plan, body))
+    }
+    /// The blocking version of [`crate::billing_plans::BillingPlans::put_request`].
+    pub fn put_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        body: &'a crate::types::BillingPlanInformation,
+    ) -> BillingPlansPutRequest<'a> {
+        BillingPlansPutRequest {
+            inner: self.inner.put_request(account_id, body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::billing_plans::BillingPlans::get_credit_card_info`].
+    pub fn get_credit_card_info(
+        &self,
+        account_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::CreditCardInformation>> {
+        self.runtime
+            .block_on(self.inner.get_credit_card_info(account_id))
+    }
+    /// The blocking version of [`crate::billing_plans::BillingPlans::get_downgrade_request_info`].
+    pub fn get_downgrade_request_info(
+        &self,
+        account_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::DowngradRequestBillingInfoResponse>> {
+        self.runtime
+            .block_on(self.inner.get_downgrade_request_info(account_id))
+    }
+    /// The blocking version of [`crate::billing_plans::BillingPlans::put_downgrade_account`].
+    pub fn put_downgrade_account(
+        &self,
+        account_id: &str,
+        body: &crate::types::DowngradeBillingPlanInformation,
+    ) -> ClientResult<crate::Response<crate::types::DowngradePlanUpdateResponse>> {
+        self.runtime
+            .block_on(self.inner.put_downgrade_account(account_id, body))
+    }
+    /// The blocking version of [`crate::billing_plans::BillingPlans::purchased_envelopes_put`].
+    pub fn purchased_envelopes_put(
+        &self,
+        account_id: &str,
+        body: &crate::types::PurchasedEnvelopesInformation,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.purchased_envelopes_put(account_id, body))
+    }
+    /// The blocking version of [`crate::billing_plans::BillingPlans::get_billing_plans`].
+    pub fn get_billing_plans(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::BillingPlansResponse>> {
+        self.runtime.block_on(self.inner.get_billing_plans())
+    }
+    /// The blocking version of [`crate::billing_plans::BillingPlans::get_plan`].
+    pub fn get_plan(
+        &self,
+        billing_plan_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::BillingPlanResponse>> {
+        self.runtime.block_on(self.inner.get_plan(billing_plan_id))
+    }
+}
@@ -0,0 +1,177 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`BulkSend::batch_get_batche`], set with a builder.
+pub struct BulkSendBatchGetBatcheRequest<'a> {
+    inner: crate::bulk_send::BulkSendBatchGetBatcheRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> BulkSendBatchGetBatcheRequest<'a> {
+    /// Sets the `batch_ids` query parameter.
+    pub fn batch_ids(mut self, batch_ids: &'a str) -> Self {
+        self.inner = self.inner.batch_ids(batch_ids);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: &'a str) -> Self {
+        self.inner = self.inner.count(count);
+        self
+    }
+
+    /// Sets the `start_position` query parameter.
+    pub fn start_position(mut self, start_position: &'a str) -> Self {
+        self.inner = self.inner.start_position(start_position);
+        self
+    }
+
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: &'a str) -> Self {
+        self.inner = self.inner.status(status);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::BulkSendBatchSummaries>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::bulk_send::BulkSend`].
+pub struct BulkSend {
+    inner: crate::bulk_send::BulkSend,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl BulkSend {
+    pub(crate) fn new(
+        inner: crate::bulk_send::BulkSend,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        BulkSend { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::bulk_send::BulkSend::batch_get_batche`].
+    pub fn batch_get_batche(
+        &self,
+        account_id: &str,
+        batch_ids: &str,
+        count: &str,
+        start_position: &str,
+        status: &str,
+    ) -> ClientResult<crate::Response<crate::types::BulkSendBatchSummaries>> {
+        self.runtime.block_on(self.inner.batch_get_batche(
+            account_id,
+            batch_ids,
+            count,
+            start_position,
+            status,
+        ))
+    }
+    /// The blocking version of [`crate::bulk_send::BulkSend::batch_get_batche_request`].
+    pub fn batch_get_batche_request<'a>(
+        &'a self,
+        account_id: &'a str,
+    ) -> BulkSendBatchGetBatcheRequest<'a> {
+        BulkSendBatchGetBatcheRequest {
+            inner: self.inner.batch_get_batche_request(account_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::bulk_send::BulkSend::batch_get_statu`].
+    pub fn batch_get_statu(
+        &self,
+        account_id: &str,
+        bulk_send_batch_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::BulkSendBatchStatus>> {
+        self.runtime
+            .block_on(self.inner.batch_get_statu(account_id, bulk_send_batch_id))
+    }
+    /// The blocking version of [`crate::bulk_send::BulkSend::batch_put_status`].
+    pub fn batch_put_status(
+        &self,
+        account_id: &str,
+        bulk_send_batch_id: &str,
+        body: &crate::types::BulkSendBatchRequest,
+    ) -> ClientResult<crate::Response<crate::types::BulkSendBatchStatus>> {
+        self.runtime.block_on(
+            self.inner
+                .batch_put_status(account_id, bulk_send_batch_id, body),
+        )
+    }
+    /// The blocking version of [`crate::bulk_send::BulkSend::v_2crud_get_list`].
+    pub fn v_2crud_get_list(
+        &self,
+        account_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::BulkSendingListSummaries>> {
+        self.runtime
+            .block_on(self.inner.v_2crud_get_list(account_id))
+    }
+    /// The blocking version of [`crate::bulk_send::BulkSend::v_2crud_post_list`].
+    pub fn v_2crud_post_list(
+        &self,
+        account_id: &str,
+        body: &crate::types::BulkSendingList,
+    ) -> ClientResult<crate::Response<crate::types::BulkSendingList>> {
+        self.runtime
+            .block_on(self.inner.v_2crud_post_list(account_id, body))
+    }
+    /// The blocking version of [`crate::bulk_send::BulkSend::v_2crud_get_list_bulk_send`].
+    pub fn v_2crud_get_list_bulk_send(
+        &self,
+        account_id: &str,
+        bulk_send_list_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::BulkSendingList>> {
+        self.runtime.block_on(
+            self.inner
+                .v_2crud_get_list_bulk_send(account_id, bulk_send_list_id),
+        )
+    }
+    /// The blocking version of [`crate::bulk_send::BulkSend::v_2crud_put_list`].
+    pub fn v_2crud_put_list(
+        &self,
+        account_id: &str,
+        bulk_send_list_id: &str,
+        body: &crate::types::BulkSendingList,
+    ) -> ClientResult<crate::Response<crate::types::BulkSendingList>> {
+        self.runtime.block_on(
+            self.inner
+                .v_2crud_put_list(account_id, bulk_send_list_id, body),
+        )
+    }
+    /// The blocking version of [`crate::bulk_send::BulkSend::v_2crud_delete_list`].
+    pub fn v_2crud_delete_list(
+        &self,
+        account_id: &str,
+        bulk_send_list_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::BulkSendingListSummaries>> {
+        self.runtime.block_on(
+            self.inner
+                .v_2crud_delete_list(account_id, bulk_send_list_id),
+        )
+    }
+    /// The blocking version of [`crate::bulk_send::BulkSend::post_request`].
+    pub fn post_request(
+        &self,
+        account_id: &str,
+        bulk_send_list_id: &str,
+        body: &crate::types::BulkSendRequest,
+    ) -> ClientResult<crate::Response<crate::types::BulkSendResponse>> {
+        self.runtime
+            .block_on(self.inner.post_request(account_id, bulk_send_list_id, body))
+    }
+    /// The blocking version of [`crate::bulk_send::BulkSend::test_post_request`].
+    pub fn test_post_request(
+        &self,
+        account_id: &str,
+        bulk_send_list_id: &str,
+        body: &crate::types::BulkSendRequest,
+    ) -> ClientResult<crate::Response<crate::types::BulkSendTestResponse>> {
+        self.runtime.block_on(
+            self.inner
+                .test_post_request(account_id, bulk_send_list_id, body),
+        )
+    }
+}
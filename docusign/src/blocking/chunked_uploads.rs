@@ -0,0 +1,133 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`ChunkedUploads::get_upload`], set with a builder.
+pub struct ChunkedUploadsGetUploadRequest<'a> {
+    inner: crate::chunked_uploads::ChunkedUploadsGetUploadRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ChunkedUploadsGetUploadRequest<'a> {
+    /// Sets the `include` query parameter.
+    pub fn include(mut self, include: &'a str) -> Self {
+        self.inner = self.inner.include(include);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::ChunkedUploadResponse>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`ChunkedUploads::put`], set with a builder.
+pub struct ChunkedUploadsPutRequest<'a> {
+    inner: crate::chunked_uploads::ChunkedUploadsPutRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ChunkedUploadsPutRequest<'a> {
+    /// Sets the `action` query parameter.
+    pub fn action(mut self, action: &'a str) -> Self {
+        self.inner = self.inner.action(action);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::ChunkedUploadResponse>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::chunked_uploads::ChunkedUploads`].
+pub struct ChunkedUploads {
+    inner: crate::chunked_uploads::ChunkedUploads,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl ChunkedUploads {
+    pub(crate) fn new(
+        inner: crate::chunked_uploads::ChunkedUploads,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        ChunkedUploads { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::chunked_uploads::ChunkedUploads::post`].
+    pub fn post(
+        &self,
+        account_id: &str,
+        body: &crate::types::ChunkedUploadRequest,
+    ) -> ClientResult<crate::Response<crate::types::ChunkedUploadResponse>> {
+        self.runtime.block_on(self.inner.post(account_id, body))
+    }
+    /// The blocking version of [`crate::chunked_uploads::ChunkedUploads::get_upload`].
+    pub fn get_upload(
+        &self,
+        account_id: &str,
+        chunked_upload_id: &str,
+        include: &str,
+    ) -> ClientResult<crate::Response<crate::types::ChunkedUploadResponse>> {
+        self.runtime.block_on(
+            self.inner
+                .get_upload(account_id, chunked_upload_id, include),
+        )
+    }
+    /// The blocking version of [`crate::chunked_uploads::ChunkedUploads::get_upload_request`].
+    pub fn get_upload_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        chunked_upload_id: &'a str,
+    ) -> ChunkedUploadsGetUploadRequest<'a> {
+        ChunkedUploadsGetUploadRequest {
+            inner: self.inner.get_upload_request(account_id, chunked_upload_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::chunked_uploads::ChunkedUploads::put`].
+    pub fn put(
+        &self,
+        account_id: &str,
+        chunked_upload_id: &str,
+        action: &str,
+    ) -> ClientResult<crate::Response<crate::types::ChunkedUploadResponse>> {
+        self.runtime
+            .block_on(self.inner.put(account_id, chunked_upload_id, action))
+    }
+    /// The blocking version of [`crate::chunked_uploads::ChunkedUploads::put_request`].
+    pub fn put_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        chunked_upload_id: &'a str,
+    ) -> ChunkedUploadsPutRequest<'a> {
+        ChunkedUploadsPutRequest {
+            inner: self.inner.put_request(account_id, chunked_upload_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::chunked_uploads::ChunkedUploads::delete_upload`].
+    pub fn delete_upload(
+        &self,
+        account_id: &str,
+        chunked_upload_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ChunkedUploadResponse>> {
+        self.runtime
+            .block_on(self.inner.delete_upload(account_id, chunked_upload_id))
+    }
+    /// The blocking version of [`crate::chunked_uploads::ChunkedUploads::put_upload_part`].
+    pub fn put_upload_part(
+        &self,
+        account_id: &str,
+        chunked_upload_id: &str,
+        chunked_upload_part_seq: &str,
+        body: &crate::types::ChunkedUploadRequest,
+    ) -> ClientResult<crate::Response<crate::types::ChunkedUploadResponse>> {
+        self.runtime.block_on(self.inner.put_upload_part(
+            account_id,
+            chunked_upload_id,
+            chunked_upload_part_seq,
+            body,
+        ))
+    }
+}
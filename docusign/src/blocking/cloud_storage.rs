@@ -0,0 +1,212 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`CloudStorage::folder_get_all`], set with a builder.
+pub struct CloudStorageFolderGetAllRequest<'a> {
+    inner: crate::cloud_storage::CloudStorageFolderGetAllRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> CloudStorageFolderGetAllRequest<'a> {
+    /// Sets the `cloud_storage_folder_path` query parameter.
+    pub fn cloud_storage_folder_path(mut self, cloud_storage_folder_path: &'a str) -> Self {
+        self.inner = self
+            .inner
+            .cloud_storage_folder_path(cloud_storage_folder_path);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: &'a str) -> Self {
+        self.inner = self.inner.count(count);
+        self
+    }
+
+    /// Sets the `order` query parameter.
+    pub fn order(mut self, order: &'a str) -> Self {
+        self.inner = self.inner.order(order);
+        self
+    }
+
+    /// Sets the `order_by` query parameter.
+    pub fn order_by(mut self, order_by: &'a str) -> Self {
+        self.inner = self.inner.order_by(order_by);
+        self
+    }
+
+    /// Sets the `search_text` query parameter.
+    pub fn search_text(mut self, search_text: &'a str) -> Self {
+        self.inner = self.inner.search_text(search_text);
+        self
+    }
+
+    /// Sets the `start_position` query parameter.
+    pub fn start_position(mut self, start_position: &'a str) -> Self {
+        self.inner = self.inner.start_position(start_position);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::ExternalFolder>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`CloudStorage::folder_get`], set with a builder.
+pub struct CloudStorageFolderGetRequest<'a> {
+    inner: crate::cloud_storage::CloudStorageFolderGetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> CloudStorageFolderGetRequest<'a> {
+    /// Sets the `cloud_storage_folder_path` query parameter.
+    pub fn cloud_storage_folder_path(mut self, cloud_storage_folder_path: &'a str) -> Self {
+        self.inner = self
+            .inner
+            .cloud_storage_folder_path(cloud_storage_folder_path);
+        self
+    }
+
+    /// Sets the `cloud_storage_folderid_plain` query parameter.
+    pub fn cloud_storage_folderid_plain(mut self, cloud_storage_folderid_plain: &'a str) -> Self {
+        self.inner = self
+            .inner
+            .cloud_storage_folderid_plain(cloud_storage_folderid_plain);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: &'a str) -> Self {
+        self.inner = self.inner.count(count);
+        self
+    }
+
+    /// Sets the `order` query parameter.
+    pub fn order(mut self, order: &'a str) -> Self {
+        self.inner = self.inner.order(order);
+        self
+    }
+
+    /// Sets the `order_by` query parameter.
+    pub fn order_by(mut self, order_by: &'a str) -> Self {
+        self.inner = self.inner.order_by(order_by);
+        self
+    }
+
+    /// Sets the `search_text` query parameter.
+    pub fn search_text(mut self, search_text: &'a str) -> Self {
+        self.inner = self.inner.search_text(search_text);
+        self
+    }
+
+    /// Sets the `start_position` query parameter.
+    pub fn start_position(mut self, start_position: &'a str) -> Self {
+        self.inner = self.inner.start_position(start_position);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::ExternalFolder>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::cloud_storage::CloudStorage`].
+pub struct CloudStorage {
+    inner: crate::cloud_storage::CloudStorage,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl CloudStorage {
+    pub(crate) fn new(
+        inner: crate::cloud_storage::CloudStorage,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        CloudStorage { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::cloud_storage::CloudStorage::folder_get_all`].
+    pub fn folder_get_all(
+        &self,
+        account_id: &str,
+        service_id: &str,
+        user_id: &str,
+        cloud_storage_folder_path: &str,
+        count: &str,
+        order: &str,
+        order_by: &str,
+        search_text: &str,
+        start_position: &str,
+    ) -> ClientResult<crate::Response<crate::types::ExternalFolder>> {
+        self.runtime.block_on(self.inner.folder_get_all(
+            account_id,
+            service_id,
+            user_id,
+            cloud_storage_folder_path,
+            count,
+            order,
+            order_by,
+            search_text,
+            start_position,
+        ))
+    }
+    /// The blocking version of [`crate::cloud_storage::CloudStorage::folder_get_all_request`].
+    pub fn folder_get_all_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        service_id: &'a str,
+        user_id: &'a str,
+    ) -> CloudStorageFolderGetAllRequest<'a> {
+        CloudStorageFolderGetAllRequest {
+            inner: self
+                .inner
+                .folder_get_all_request(account_id, service_id, user_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::cloud_storage::CloudStorage::folder_get`].
+    pub fn folder_get(
+        &self,
+        account_id: &str,
+        folder_id: &str,
+        service_id: &str,
+        user_id: &str,
+        cloud_storage_folder_path: &str,
+        cloud_storage_folderid_plain: &str,
+        count: &str,
+        order: &str,
+        order_by: &str,
+        search_text: &str,
+        start_position: &str,
+    ) -> ClientResult<crate::Response<crate::types::ExternalFolder>> {
+        self.runtime.block_on(self.inner.folder_get(
+            account_id,
+            folder_id,
+            service_id,
+            user_id,
+            cloud_storage_folder_path,
+            cloud_storage_folderid_plain,
+            count,
+            order,
+            order_by,
+            search_text,
+            start_position,
+        ))
+    }
+    /// The blocking version of [`crate::cloud_storage::CloudStorage::folder_get_request`].
+    pub fn folder_get_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        folder_id: &'a str,
+        service_id: &'a str,
+        user_id: &'a str,
+    ) -> CloudStorageFolderGetRequest<'a> {
+        CloudStorageFolderGetRequest {
+            inner: self
+                .inner
+                .folder_get_request(account_id, folder_id, service_id, user_id),
+            runtime: &self.runtime,
+        }
+    }
+}
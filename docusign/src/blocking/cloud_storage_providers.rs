@@ -0,0 +1,146 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`CloudStorageProviders::cloud_storage_get_provider`], set with a builder.
+pub struct CloudStorageProvidersCloudStorageGetProviderRequest<'a> {
+    inner: crate::cloud_storage_providers::CloudStorageProvidersCloudStorageGetProviderRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> CloudStorageProvidersCloudStorageGetProviderRequest<'a> {
+    /// Sets the `redirectUrl` query parameter.
+    pub fn redirect_url(mut self, redirect_url: &'a str) -> Self {
+        self.inner = self.inner.redirect_url(redirect_url);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::CloudStorageProvidersData>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`CloudStorageProviders::cloud_storage_get`], set with a builder.
+pub struct CloudStorageProvidersCloudStorageGetRequest<'a> {
+    inner: crate::cloud_storage_providers::CloudStorageProvidersCloudStorageGetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> CloudStorageProvidersCloudStorageGetRequest<'a> {
+    /// Sets the `redirectUrl` query parameter.
+    pub fn redirect_url(mut self, redirect_url: &'a str) -> Self {
+        self.inner = self.inner.redirect_url(redirect_url);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::CloudStorageProvidersData>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::cloud_storage_providers::CloudStorageProviders`].
+pub struct CloudStorageProviders {
+    inner: crate::cloud_storage_providers::CloudStorageProviders,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl CloudStorageProviders {
+    pub(crate) fn new(
+        inner: crate::cloud_storage_providers::CloudStorageProviders,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        CloudStorageProviders { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::cloud_storage_providers::CloudStorageProviders::cloud_storage_get_provider`].
+    pub fn cloud_storage_get_provider(
+        &self,
+        account_id: &str,
+        user_id: &str,
+        redirect_url: &str,
+    ) -> ClientResult<crate::Response<crate::types::CloudStorageProvidersData>> {
+        self.runtime.block_on(self.inner.cloud_storage_get_provider(
+            account_id,
+            user_id,
+            redirect_url,
+        ))
+    }
+    /// The blocking version of [`crate::cloud_storage_providers::CloudStorageProviders::cloud_storage_get_provider_request`].
+    pub fn cloud_storage_get_provider_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        user_id: &'a str,
+    ) -> CloudStorageProvidersCloudStorageGetProviderRequest<'a> {
+        CloudStorageProvidersCloudStorageGetProviderRequest {
+            inner: self
+                .inner
+                .cloud_storage_get_provider_request(account_id, user_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::cloud_storage_providers::CloudStorageProviders::cloud_storage_post`].
+    pub fn cloud_storage_post(
+        &self,
+        account_id: &str,
+        user_id: &str,
+        body: &crate::types::CloudStorageProvidersData,
+    ) -> ClientResult<crate::Response<crate::types::CloudStorageProvidersData>> {
+        self.runtime
+            .block_on(self.inner.cloud_storage_post(account_id, user_id, body))
+    }
+    /// The blocking version of [`crate::cloud_storage_providers::CloudStorageProviders::cloud_storage_delete_providers`].
+    pub fn cloud_storage_delete_providers(
+        &self,
+        account_id: &str,
+        user_id: &str,
+        body: &crate::types::CloudStorageProvidersData,
+    ) -> ClientResult<crate::Response<crate::types::CloudStorageProvidersData>> {
+        self.runtime.block_on(
+            self.inner
+                .cloud_storage_delete_providers(account_id, user_id, body),
+        )
+    }
+    /// The blocking version of [`crate::cloud_storage_providers::CloudStorageProviders::cloud_storage_get`].
+    pub fn cloud_storage_get(
+        &self,
+        account_id: &str,
+        service_id: &str,
+        user_id: &str,
+        redirect_url: &str,
+    ) -> ClientResult<crate::Response<crate::types::CloudStorageProvidersData>> {
+        self.runtime.block_on(self.inner.cloud_storage_get(
+            account_id,
+            service_id,
+            user_id,
+            redirect_url,
+        ))
+    }
+    /// The blocking version of [`crate::cloud_storage_providers::CloudStorageProviders::cloud_storage_get_request`].
+    pub fn cloud_storage_get_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        service_id: &'a str,
+        user_id: &'a str,
+    ) -> CloudStorageProvidersCloudStorageGetRequest<'a> {
+        CloudStorageProvidersCloudStorageGetRequest {
+            inner: self
+                .inner
+                .cloud_storage_get_request(account_id, service_id, user_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::cloud_storage_providers::CloudStorageProviders::cloud_storage_delete`].
+    pub fn cloud_storage_delete(
+        &self,
+        account_id: &str,
+        service_id: &str,
+        user_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::CloudStorageProvidersData>> {
+        self.runtime.block_on(
+            self.inner
+                .cloud_storage_delete(account_id, service_id, user_id),
+        )
+    }
+}
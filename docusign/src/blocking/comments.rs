@@ -0,0 +1,59 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Comments::get_transcript`], set with a builder.
+pub struct CommentsGetTranscriptRequest<'a> {
+    inner: crate::comments::CommentsGetTranscriptRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> CommentsGetTranscriptRequest<'a> {
+    /// Sets the `encoding` query parameter.
+    pub fn encoding(mut self, encoding: &'a str) -> Self {
+        self.inner = self.inner.encoding(encoding);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::comments::Comments`].
+pub struct Comments {
+    inner: crate::comments::Comments,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Comments {
+    pub(crate) fn new(
+        inner: crate::comments::Comments,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Comments { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::comments::Comments::get_transcript`].
+    pub fn get_transcript(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        encoding: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.get_transcript(account_id, envelope_id, encoding))
+    }
+    /// The blocking version of [`crate::comments::Comments::get_transcript_request`].
+    pub fn get_transcript_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        envelope_id: &'a str,
+    ) -> CommentsGetTranscriptRequest<'a> {
+        CommentsGetTranscriptRequest {
+            inner: self.inner.get_transcript_request(account_id, envelope_id),
+            runtime: &self.runtime,
+        }
+    }
+}
@@ -0,0 +1,148 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`ConnectConfigurations::connect_get_user`], set with a builder.
+pub struct ConnectConfigurationsConnectGetUserRequest<'a> {
+    inner: crate::connect_configurations::ConnectConfigurationsConnectGetUserRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ConnectConfigurationsConnectGetUserRequest<'a> {
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: &'a str) -> Self {
+        self.inner = self.inner.count(count);
+        self
+    }
+
+    /// Sets the `email_substring` query parameter.
+    pub fn email_substring(mut self, email_substring: &'a str) -> Self {
+        self.inner = self.inner.email_substring(email_substring);
+        self
+    }
+
+    /// Sets the `list_included_users` query parameter.
+    pub fn list_included_users(mut self, list_included_users: &'a str) -> Self {
+        self.inner = self.inner.list_included_users(list_included_users);
+        self
+    }
+
+    /// Sets the `start_position` query parameter.
+    pub fn start_position(mut self, start_position: &'a str) -> Self {
+        self.inner = self.inner.start_position(start_position);
+        self
+    }
+
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: &'a str) -> Self {
+        self.inner = self.inner.status(status);
+        self
+    }
+
+    /// Sets the `user_name_substring` query parameter.
+    pub fn user_name_substring(mut self, user_name_substring: &'a str) -> Self {
+        self.inner = self.inner.user_name_substring(user_name_substring);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::IntegratedUserInfoList>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::connect_configurations::ConnectConfigurations`].
+pub struct ConnectConfigurations {
+    inner: crate::connect_configurations::ConnectConfigurations,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl ConnectConfigurations {
+    pub(crate) fn new(
+        inner: crate::connect_configurations::ConnectConfigurations,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        ConnectConfigurations { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::connect_configurations::ConnectConfigurations::connect_get_config`].
+    pub fn connect_get_config(
+        &self,
+        account_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ConnectConfigResults>> {
+        self.runtime
+            .block_on(self.inner.connect_get_config(account_id))
+    }
+    /// The blocking version of [`crate::connect_configurations::ConnectConfigurations::connect_put_configuration`].
+    pub fn connect_put_configuration(
+        &self,
+        account_id: &str,
+        body: &crate::types::ConnectCustomConfiguration,
+    ) -> ClientResult<crate::Response<crate::types::ConnectCustomConfiguration>> {
+        self.runtime
+            .block_on(self.inner.connect_put_configuration(account_id, body))
+    }
+    /// The blocking version of [`crate::connect_configurations::ConnectConfigurations::connect_post_configuration`].
+    pub fn connect_post_configuration(
+        &self,
+        account_id: &str,
+        body: &crate::types::ConnectCustomConfiguration,
+    ) -> ClientResult<crate::Response<crate::types::ConnectCustomConfiguration>> {
+        self.runtime
+            .block_on(self.inner.connect_post_configuration(account_id, body))
+    }
+    /// The blocking version of [`crate::connect_configurations::ConnectConfigurations::connect_get_config_connect_configurations`].
+    pub fn connect_get_config_connect_configurations(
+        &self,
+        account_id: &str,
+        connect_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ConnectConfigResults>> {
+        self.runtime.block_on(
+            self.inner
+                .connect_get_config_connect_configurations(account_id, connect_id),
+        )
+    }
+    /// The blocking version of [`crate::connect_configurations::ConnectConfigurations::connect_delete_config`].
+    pub fn connect_delete_config(
+        &self,
+        account_id: &str,
+        connect_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.connect_delete_config(account_id, connect_id))
+    }
+    /// The blocking version of [`crate::connect_configurations::ConnectConfigurations::connect_get_user`].
+    pub fn connect_get_user(
+        &self,
+        account_id: &str,
+        connect_id: &str,
+        count: &str,
+        email_substring: &str,
+        list_included_users: &str,
+        start_position: &str,
+        status: &str,
+        user_name_substring: &str,
+    ) -> ClientResult<crate::Response<crate::types::IntegratedUserInfoList>> {
+        self.runtime.block_on(self.inner.connect_get_user(
+            account_id,
+            connect_id,
+            count,
+            email_substring,
+            list_included_users,
+            start_position,
+            status,
+            user_name_substring,
+        ))
+    }
+    /// The blocking version of [`crate::connect_configurations::ConnectConfigurations::connect_get_user_request`].
+    pub fn connect_get_user_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        connect_id: &'a str,
+    ) -> ConnectConfigurationsConnectGetUserRequest<'a> {
+        ConnectConfigurationsConnectGetUserRequest {
+            inner: self.inner.connect_get_user_request(account_id, connect_id),
+            runtime: &self.runtime,
+        }
+    }
+}
@@ -0,0 +1,200 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`ConnectEvents::connect_failures_get_log`], set with a builder.
+pub struct ConnectEventsConnectFailuresGetLogRequest<'a> {
+    inner: crate::connect_events::ConnectEventsConnectFailuresGetLogRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ConnectEventsConnectFailuresGetLogRequest<'a> {
+    /// Sets the `from_date` query parameter.
+    pub fn from_date(mut self, from_date: &'a str) -> Self {
+        self.inner = self.inner.from_date(from_date);
+        self
+    }
+
+    /// Sets the `to_date` query parameter.
+    pub fn to_date(mut self, to_date: &'a str) -> Self {
+        self.inner = self.inner.to_date(to_date);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::ConnectLogs>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`ConnectEvents::connect_log_get_log`], set with a builder.
+pub struct ConnectEventsConnectLogGetLogRequest<'a> {
+    inner: crate::connect_events::ConnectEventsConnectLogGetLogRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ConnectEventsConnectLogGetLogRequest<'a> {
+    /// Sets the `from_date` query parameter.
+    pub fn from_date(mut self, from_date: &'a str) -> Self {
+        self.inner = self.inner.from_date(from_date);
+        self
+    }
+
+    /// Sets the `to_date` query parameter.
+    pub fn to_date(mut self, to_date: &'a str) -> Self {
+        self.inner = self.inner.to_date(to_date);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::ConnectLogs>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`ConnectEvents::connect_log_get`], set with a builder.
+pub struct ConnectEventsConnectLogGetRequest<'a> {
+    inner: crate::connect_events::ConnectEventsConnectLogGetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ConnectEventsConnectLogGetRequest<'a> {
+    /// Sets the `additional_info` query parameter.
+    pub fn additional_info(mut self, additional_info: &'a str) -> Self {
+        self.inner = self.inner.additional_info(additional_info);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::ConnectLog>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::connect_events::ConnectEvents`].
+pub struct ConnectEvents {
+    inner: crate::connect_events::ConnectEvents,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl ConnectEvents {
+    pub(crate) fn new(
+        inner: crate::connect_events::ConnectEvents,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        ConnectEvents { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::connect_events::ConnectEvents::connect_publish_put_retry`].
+    pub fn connect_publish_put_retry(
+        &self,
+        account_id: &str,
+        body: &crate::types::ConnectFailureFilter,
+    ) -> ClientResult<crate::Response<crate::types::ConnectFailureResults>> {
+        self.runtime
+            .block_on(self.inner.connect_publish_put_retry(account_id, body))
+    }
+    /// The blocking version of [`crate::connect_events::ConnectEvents::connect_publish_put_retry_envelope`].
+    pub fn connect_publish_put_retry_envelope(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ConnectFailureResults>> {
+        self.runtime.block_on(
+            self.inner
+                .connect_publish_put_retry_envelope(account_id, envelope_id),
+        )
+    }
+    /// The blocking version of [`crate::connect_events::ConnectEvents::connect_failures_get_log`].
+    pub fn connect_failures_get_log(
+        &self,
+        account_id: &str,
+        from_date: &str,
+        to_date: &str,
+    ) -> ClientResult<crate::Response<crate::types::ConnectLogs>> {
+        self.runtime.block_on(
+            self.inner
+                .connect_failures_get_log(account_id, from_date, to_date),
+        )
+    }
+    /// The blocking version of [`crate::connect_events::ConnectEvents::connect_failures_get_log_request`].
+    pub fn connect_failures_get_log_request<'a>(
+        &'a self,
+        account_id: &'a str,
+    ) -> ConnectEventsConnectFailuresGetLogRequest<'a> {
+        ConnectEventsConnectFailuresGetLogRequest {
+            inner: self.inner.connect_failures_get_log_request(account_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::connect_events::ConnectEvents::connect_failures_delete_failure_log`].
+    pub fn connect_failures_delete_failure_log(
+        &self,
+        account_id: &str,
+        failure_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .connect_failures_delete_failure_log(account_id, failure_id),
+        )
+    }
+    /// The blocking version of [`crate::connect_events::ConnectEvents::connect_log_get_log`].
+    pub fn connect_log_get_log(
+        &self,
+        account_id: &str,
+        from_date: &str,
+        to_date: &str,
+    ) -> ClientResult<crate::Response<crate::types::ConnectLogs>> {
+        self.runtime.block_on(
+            self.inner
+                .connect_log_get_log(account_id, from_date, to_date),
+        )
+    }
+    /// The blocking version of [`crate::connect_events::ConnectEvents::connect_log_get_log_request`].
+    pub fn connect_log_get_log_request<'a>(
+        &'a self,
+        account_id: &'a str,
+    ) -> ConnectEventsConnectLogGetLogRequest<'a> {
+        ConnectEventsConnectLogGetLogRequest {
+            inner: self.inner.connect_log_get_log_request(account_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::connect_events::ConnectEvents::connect_log_delete_logs`].
+    pub fn connect_log_delete_logs(&self, account_id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.connect_log_delete_logs(account_id))
+    }
+    /// The blocking version of [`crate::connect_events::ConnectEvents::connect_log_get`].
+    pub fn connect_log_get(
+        &self,
+        account_id: &str,
+        log_id: &str,
+        additional_info: &str,
+    ) -> ClientResult<crate::Response<crate::types::ConnectLog>> {
+        self.runtime.block_on(
+            self.inner
+                .connect_log_get(account_id, log_id, additional_info),
+        )
+    }
+    /// The blocking version of [`crate::connect_events::ConnectEvents::connect_log_get_request`].
+    pub fn connect_log_get_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        log_id: &'a str,
+    ) -> ConnectEventsConnectLogGetRequest<'a> {
+        ConnectEventsConnectLogGetRequest {
+            inner: self.inner.connect_log_get_request(account_id, log_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::connect_events::ConnectEvents::connect_log_delete`].
+    pub fn connect_log_delete(
+        &self,
+        account_id: &str,
+        log_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.connect_log_delete(account_id, log_id))
+    }
+}
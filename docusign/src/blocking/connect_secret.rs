@@ -0,0 +1,28 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::connect_secret::ConnectSecret`].
+pub struct ConnectSecret {
+    inner: crate::connect_secret::ConnectSecret,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl ConnectSecret {
+    pub(crate) fn new(
+        inner: crate::connect_secret::ConnectSecret,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        ConnectSecret { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::connect_secret::ConnectSecret::connect_hmac_delete_secret`].
+    pub fn connect_hmac_delete_secret(
+        &self,
+        account_id: &str,
+        key_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.connect_hmac_delete_secret(account_id, key_id))
+    }
+}
@@ -0,0 +1,92 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Contacts::get`], set with a builder.
+pub struct ContactsGetRequest<'a> {
+    inner: crate::contacts::ContactsGetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ContactsGetRequest<'a> {
+    /// Sets the `cloud_provider` query parameter.
+    pub fn cloud_provider(mut self, cloud_provider: &'a str) -> Self {
+        self.inner = self.inner.cloud_provider(cloud_provider);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::ContactGetResponse>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::contacts::Contacts`].
+pub struct Contacts {
+    inner: crate::contacts::Contacts,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Contacts {
+    pub(crate) fn new(
+        inner: crate::contacts::Contacts,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Contacts { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::contacts::Contacts::put`].
+    pub fn put(
+        &self,
+        account_id: &str,
+        body: &crate::types::ContactModRequest,
+    ) -> ClientResult<crate::Response<crate::types::ContactUpdateResponse>> {
+        self.runtime.block_on(self.inner.put(account_id, body))
+    }
+    /// The blocking version of [`crate::contacts::Contacts::post`].
+    pub fn post(
+        &self,
+        account_id: &str,
+        body: &crate::types::ContactModRequest,
+    ) -> ClientResult<crate::Response<crate::types::ContactUpdateResponse>> {
+        self.runtime.block_on(self.inner.post(account_id, body))
+    }
+    /// The blocking version of [`crate::contacts::Contacts::delete`].
+    pub fn delete(
+        &self,
+        account_id: &str,
+        body: &crate::types::ContactModRequest,
+    ) -> ClientResult<crate::Response<crate::types::ContactUpdateResponse>> {
+        self.runtime.block_on(self.inner.delete(account_id, body))
+    }
+    /// The blocking version of [`crate::contacts::Contacts::get`].
+    pub fn get(
+        &self,
+        account_id: &str,
+        contact_id: &str,
+        cloud_provider: &str,
+    ) -> ClientResult<crate::Response<crate::types::ContactGetResponse>> {
+        self.runtime
+            .block_on(self.inner.get(account_id, contact_id, cloud_provider))
+    }
+    /// The blocking version of [`crate::contacts::Contacts::get_request`].
+    pub fn get_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        contact_id: &'a str,
+    ) -> ContactsGetRequest<'a> {
+        ContactsGetRequest {
+            inner: self.inner.get_request(account_id, contact_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::contacts::Contacts::delete_contacts`].
+    pub fn delete_contacts(
+        &self,
+        account_id: &str,
+        contact_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ContactUpdateResponse>> {
+        self.runtime
+            .block_on(self.inner.delete_contacts(account_id, contact_id))
+    }
+}
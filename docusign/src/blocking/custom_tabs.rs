@@ -0,0 +1,96 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`CustomTabs::tabs_get_tab_definition`], set with a builder.
+pub struct CustomTabsTabsGetTabDefinitionRequest<'a> {
+    inner: crate::custom_tabs::CustomTabsTabsGetTabDefinitionRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> CustomTabsTabsGetTabDefinitionRequest<'a> {
+    /// Sets the `custom_tab_only` query parameter.
+    pub fn custom_tab_only(mut self, custom_tab_only: &'a str) -> Self {
+        self.inner = self.inner.custom_tab_only(custom_tab_only);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::TabMetadataList>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::custom_tabs::CustomTabs`].
+pub struct CustomTabs {
+    inner: crate::custom_tabs::CustomTabs,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl CustomTabs {
+    pub(crate) fn new(
+        inner: crate::custom_tabs::CustomTabs,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        CustomTabs { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::custom_tabs::CustomTabs::tabs_get_tab_definition`].
+    pub fn tabs_get_tab_definition(
+        &self,
+        account_id: &str,
+        custom_tab_only: &str,
+    ) -> ClientResult<crate::Response<crate::types::TabMetadataList>> {
+        self.runtime.block_on(
+            self.inner
+                .tabs_get_tab_definition(account_id, custom_tab_only),
+        )
+    }
+    /// The blocking version of [`crate::custom_tabs::CustomTabs::tabs_get_tab_definition_request`].
+    pub fn tabs_get_tab_definition_request<'a>(
+        &'a self,
+        account_id: &'a str,
+    ) -> CustomTabsTabsGetTabDefinitionRequest<'a> {
+        CustomTabsTabsGetTabDefinitionRequest {
+            inner: self.inner.tabs_get_tab_definition_request(account_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::custom_tabs::CustomTabs::tabs_post_tab_definitions`].
+    pub fn tabs_post_tab_definitions(
+        &self,
+        account_id: &str,
+        body: &crate::types::TabMetadata,
+    ) -> ClientResult<crate::Response<crate::types::TabMetadata>> {
+        self.runtime
+            .block_on(self.inner.tabs_post_tab_definitions(account_id, body))
+    }
+    /// The blocking version of [`crate::custom_tabs::CustomTabs::tab_get_custom`].
+    pub fn tab_get_custom(
+        &self,
+        account_id: &str,
+        custom_tab_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::TabMetadata>> {
+        self.runtime
+            .block_on(self.inner.tab_get_custom(account_id, custom_tab_id))
+    }
+    /// The blocking version of [`crate::custom_tabs::CustomTabs::tab_put_custom`].
+    pub fn tab_put_custom(
+        &self,
+        account_id: &str,
+        custom_tab_id: &str,
+        body: &crate::types::TabMetadata,
+    ) -> ClientResult<crate::Response<crate::types::TabMetadata>> {
+        self.runtime
+            .block_on(self.inner.tab_put_custom(account_id, custom_tab_id, body))
+    }
+    /// The blocking version of [`crate::custom_tabs::CustomTabs::tab_delete_custom`].
+    pub fn tab_delete_custom(
+        &self,
+        account_id: &str,
+        custom_tab_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.tab_delete_custom(account_id, custom_tab_id))
+    }
+}
@@ -0,0 +1,35 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::document_responsive_html_preview::DocumentResponsiveHtmlPreview`].
+pub struct DocumentResponsiveHtmlPreview {
+    inner: crate::document_responsive_html_preview::DocumentResponsiveHtmlPreview,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl DocumentResponsiveHtmlPreview {
+    pub(crate) fn new(
+        inner: crate::document_responsive_html_preview::DocumentResponsiveHtmlPreview,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        DocumentResponsiveHtmlPreview { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::document_responsive_html_preview::DocumentResponsiveHtmlPreview::responsive_html_post_document_preview`].
+    pub fn responsive_html_post_document_preview(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        body: &crate::types::DocumentHtmlDefinition,
+    ) -> ClientResult<crate::Response<crate::types::DocumentHtmlDefinitions>> {
+        self.runtime
+            .block_on(self.inner.responsive_html_post_document_preview(
+                account_id,
+                document_id,
+                envelope_id,
+                body,
+            ))
+    }
+}
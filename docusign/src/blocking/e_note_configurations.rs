@@ -0,0 +1,38 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::e_note_configurations::ENoteConfigurations`].
+pub struct ENoteConfigurations {
+    inner: crate::e_note_configurations::ENoteConfigurations,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl ENoteConfigurations {
+    pub(crate) fn new(
+        inner: crate::e_note_configurations::ENoteConfigurations,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        ENoteConfigurations { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::e_note_configurations::ENoteConfigurations::get`].
+    pub fn get(
+        &self,
+        account_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ENoteConfiguration>> {
+        self.runtime.block_on(self.inner.get(account_id))
+    }
+    /// The blocking version of [`crate::e_note_configurations::ENoteConfigurations::put`].
+    pub fn put(
+        &self,
+        account_id: &str,
+        body: &crate::types::ENoteConfiguration,
+    ) -> ClientResult<crate::Response<crate::types::ENoteConfiguration>> {
+        self.runtime.block_on(self.inner.put(account_id, body))
+    }
+    /// The blocking version of [`crate::e_note_configurations::ENoteConfigurations::delete`].
+    pub fn delete(&self, account_id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.delete(account_id))
+    }
+}
@@ -0,0 +1,76 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::envelope_attachments::EnvelopeAttachments`].
+pub struct EnvelopeAttachments {
+    inner: crate::envelope_attachments::EnvelopeAttachments,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl EnvelopeAttachments {
+    pub(crate) fn new(
+        inner: crate::envelope_attachments::EnvelopeAttachments,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        EnvelopeAttachments { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::envelope_attachments::EnvelopeAttachments::attachments_get`].
+    pub fn attachments_get(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::EnvelopeAttachmentsResult>> {
+        self.runtime
+            .block_on(self.inner.attachments_get(account_id, envelope_id))
+    }
+    /// The blocking version of [`crate::envelope_attachments::EnvelopeAttachments::attachments_put`].
+    pub fn attachments_put(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::EnvelopeAttachmentsRequest,
+    ) -> ClientResult<crate::Response<crate::types::EnvelopeAttachmentsResult>> {
+        self.runtime
+            .block_on(self.inner.attachments_put(account_id, envelope_id, body))
+    }
+    /// The blocking version of [`crate::envelope_attachments::EnvelopeAttachments::attachments_delete`].
+    pub fn attachments_delete(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::EnvelopeAttachmentsRequest,
+    ) -> ClientResult<crate::Response<crate::types::EnvelopeAttachmentsResult>> {
+        self.runtime
+            .block_on(self.inner.attachments_delete(account_id, envelope_id, body))
+    }
+    /// The blocking version of [`crate::envelope_attachments::EnvelopeAttachments::attachments_get_attachment`].
+    pub fn attachments_get_attachment(
+        &self,
+        account_id: &str,
+        attachment_id: &str,
+        envelope_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.attachments_get_attachment(
+            account_id,
+            attachment_id,
+            envelope_id,
+        ))
+    }
+    /// The blocking version of [`crate::envelope_attachments::EnvelopeAttachments::attachments_put_attachment`].
+    pub fn attachments_put_attachment(
+        &self,
+        account_id: &str,
+        attachment_id: &str,
+        envelope_id: &str,
+        body: &crate::types::Attachment,
+    ) -> ClientResult<crate::Response<crate::types::EnvelopeAttachmentsResult>> {
+        self.runtime.block_on(self.inner.attachments_put_attachment(
+            account_id,
+            attachment_id,
+            envelope_id,
+            body,
+        ))
+    }
+}
@@ -0,0 +1,90 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`EnvelopeConsumerDisclosures::consumer_disclosure_get_envelope_recipient`], set with a builder.
+pub struct EnvelopeConsumerDisclosuresConsumerDisclosureGetEnvelopeRecipientRequest<'a> {
+            inner: crate::envelope_consumer_disclosures::EnvelopeConsumerDisclosuresConsumerDisclosureGetEnvelopeRecipientRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a> EnvelopeConsumerDisclosuresConsumerDisclosureGetEnvelopeRecipientRequest<'a> {
+    /// Sets the `langCode` query parameter.
+    pub fn lang_code(mut self, lang_code: &'a str) -> Self {
+        self.inner = self.inner.lang_code(lang_code);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::ConsumerDisclosure>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::envelope_consumer_disclosures::EnvelopeConsumerDisclosures`].
+pub struct EnvelopeConsumerDisclosures {
+    inner: crate::envelope_consumer_disclosures::EnvelopeConsumerDisclosures,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl EnvelopeConsumerDisclosures {
+    pub(crate) fn new(
+        inner: crate::envelope_consumer_disclosures::EnvelopeConsumerDisclosures,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        EnvelopeConsumerDisclosures { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::envelope_consumer_disclosures::EnvelopeConsumerDisclosures::consumer_disclosure_get_envelope_recipient`].
+    pub fn consumer_disclosure_get_envelope_recipient(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+        lang_code: &str,
+    ) -> ClientResult<crate::Response<crate::types::ConsumerDisclosure>> {
+        self.runtime
+            .block_on(self.inner.consumer_disclosure_get_envelope_recipient(
+                account_id,
+                envelope_id,
+                recipient_id,
+                lang_code,
+            ))
+    }
+    /// The blocking version of [`crate::envelope_consumer_disclosures::EnvelopeConsumerDisclosures::consumer_disclosure_get_envelope_recipient_request`].
+    pub fn consumer_disclosure_get_envelope_recipient_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        envelope_id: &'a str,
+        recipient_id: &'a str,
+    ) -> EnvelopeConsumerDisclosuresConsumerDisclosureGetEnvelopeRecipientRequest<'a> {
+        EnvelopeConsumerDisclosuresConsumerDisclosureGetEnvelopeRecipientRequest {
+            inner: self
+                .inner
+                .consumer_disclosure_get_envelope_recipient_request(
+                    account_id,
+                    envelope_id,
+                    recipient_id,
+                ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::envelope_consumer_disclosures::EnvelopeConsumerDisclosures::consumer_disclosure_get_envelope_recipient_lang_code`].
+    pub fn consumer_disclosure_get_envelope_recipient_lang_code(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        lang_code: &str,
+        recipient_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ConsumerDisclosure>> {
+        self.runtime.block_on(
+            self.inner
+                .consumer_disclosure_get_envelope_recipient_lang_code(
+                    account_id,
+                    envelope_id,
+                    lang_code,
+                    recipient_id,
+                ),
+        )
+    }
+}
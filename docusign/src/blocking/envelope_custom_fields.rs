@@ -0,0 +1,60 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::envelope_custom_fields::EnvelopeCustomFields`].
+pub struct EnvelopeCustomFields {
+    inner: crate::envelope_custom_fields::EnvelopeCustomFields,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl EnvelopeCustomFields {
+    pub(crate) fn new(
+        inner: crate::envelope_custom_fields::EnvelopeCustomFields,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        EnvelopeCustomFields { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::envelope_custom_fields::EnvelopeCustomFields::custom_fields_get`].
+    pub fn custom_fields_get(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::CustomFieldsEnvelope>> {
+        self.runtime
+            .block_on(self.inner.custom_fields_get(account_id, envelope_id))
+    }
+    /// The blocking version of [`crate::envelope_custom_fields::EnvelopeCustomFields::custom_fields_put`].
+    pub fn custom_fields_put(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::EnvelopeCustomFields,
+    ) -> ClientResult<crate::Response<crate::types::EnvelopeCustomFields>> {
+        self.runtime
+            .block_on(self.inner.custom_fields_put(account_id, envelope_id, body))
+    }
+    /// The blocking version of [`crate::envelope_custom_fields::EnvelopeCustomFields::custom_fields_post`].
+    pub fn custom_fields_post(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::EnvelopeCustomFields,
+    ) -> ClientResult<crate::Response<crate::types::EnvelopeCustomFields>> {
+        self.runtime
+            .block_on(self.inner.custom_fields_post(account_id, envelope_id, body))
+    }
+    /// The blocking version of [`crate::envelope_custom_fields::EnvelopeCustomFields::custom_fields_delete`].
+    pub fn custom_fields_delete(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::EnvelopeCustomFields,
+    ) -> ClientResult<crate::Response<crate::types::EnvelopeCustomFields>> {
+        self.runtime.block_on(
+            self.inner
+                .custom_fields_delete(account_id, envelope_id, body),
+        )
+    }
+}
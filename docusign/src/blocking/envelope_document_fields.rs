@@ -0,0 +1,76 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::envelope_document_fields::EnvelopeDocumentFields`].
+pub struct EnvelopeDocumentFields {
+    inner: crate::envelope_document_fields::EnvelopeDocumentFields,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl EnvelopeDocumentFields {
+    pub(crate) fn new(
+        inner: crate::envelope_document_fields::EnvelopeDocumentFields,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        EnvelopeDocumentFields { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::envelope_document_fields::EnvelopeDocumentFields::document_fields_get`].
+    pub fn document_fields_get(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::EnvelopeDocumentFields>> {
+        self.runtime.block_on(
+            self.inner
+                .document_fields_get(account_id, document_id, envelope_id),
+        )
+    }
+    /// The blocking version of [`crate::envelope_document_fields::EnvelopeDocumentFields::document_fields_put`].
+    pub fn document_fields_put(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        body: &crate::types::EnvelopeDocumentFields,
+    ) -> ClientResult<crate::Response<crate::types::EnvelopeDocumentFields>> {
+        self.runtime.block_on(self.inner.document_fields_put(
+            account_id,
+            document_id,
+            envelope_id,
+            body,
+        ))
+    }
+    /// The blocking version of [`crate::envelope_document_fields::EnvelopeDocumentFields::document_fields_post`].
+    pub fn document_fields_post(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        body: &crate::types::EnvelopeDocumentFields,
+    ) -> ClientResult<crate::Response<crate::types::EnvelopeDocumentFields>> {
+        self.runtime.block_on(self.inner.document_fields_post(
+            account_id,
+            document_id,
+            envelope_id,
+            body,
+        ))
+    }
+    /// The blocking version of [`crate::envelope_document_fields::EnvelopeDocumentFields::document_fields_delete`].
+    pub fn document_fields_delete(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        body: &crate::types::EnvelopeDocumentFields,
+    ) -> ClientResult<crate::Response<crate::types::EnvelopeDocumentFields>> {
+        self.runtime.block_on(self.inner.document_fields_delete(
+            account_id,
+            document_id,
+            envelope_id,
+            body,
+        ))
+    }
+}
@@ -0,0 +1,33 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::envelope_document_html_definitions::EnvelopeDocumentHtmlDefinitions`].
+pub struct EnvelopeDocumentHtmlDefinitions {
+    inner: crate::envelope_document_html_definitions::EnvelopeDocumentHtmlDefinitions,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl EnvelopeDocumentHtmlDefinitions {
+    pub(crate) fn new(
+        inner: crate::envelope_document_html_definitions::EnvelopeDocumentHtmlDefinitions,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        EnvelopeDocumentHtmlDefinitions { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::envelope_document_html_definitions::EnvelopeDocumentHtmlDefinitions::responsive_html_get_envelope_document_definition`].
+    pub fn responsive_html_get_envelope_document_definition(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::DocumentHtmlDefinitionOriginals>> {
+        self.runtime
+            .block_on(self.inner.responsive_html_get_envelope_document_definition(
+                account_id,
+                document_id,
+                envelope_id,
+            ))
+    }
+}
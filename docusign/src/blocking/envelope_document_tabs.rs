@@ -0,0 +1,135 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`EnvelopeDocumentTabs::tabs_get_document`], set with a builder.
+pub struct EnvelopeDocumentTabsTabsGetDocumentRequest<'a> {
+    inner: crate::envelope_document_tabs::EnvelopeDocumentTabsTabsGetDocumentRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> EnvelopeDocumentTabsTabsGetDocumentRequest<'a> {
+    /// Sets the `include_metadata` query parameter.
+    pub fn include_metadata(mut self, include_metadata: &'a str) -> Self {
+        self.inner = self.inner.include_metadata(include_metadata);
+        self
+    }
+
+    /// Sets the `page_numbers` query parameter.
+    pub fn page_numbers(mut self, page_numbers: &'a str) -> Self {
+        self.inner = self.inner.page_numbers(page_numbers);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::EnvelopeDocumentTabs>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::envelope_document_tabs::EnvelopeDocumentTabs`].
+pub struct EnvelopeDocumentTabs {
+    inner: crate::envelope_document_tabs::EnvelopeDocumentTabs,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl EnvelopeDocumentTabs {
+    pub(crate) fn new(
+        inner: crate::envelope_document_tabs::EnvelopeDocumentTabs,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        EnvelopeDocumentTabs { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::envelope_document_tabs::EnvelopeDocumentTabs::tabs_get_page`].
+    pub fn tabs_get_page(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        page_number: &str,
+    ) -> ClientResult<crate::Response<crate::types::EnvelopeDocumentTabs>> {
+        self.runtime.block_on(self.inner.tabs_get_page(
+            account_id,
+            document_id,
+            envelope_id,
+            page_number,
+        ))
+    }
+    /// The blocking version of [`crate::envelope_document_tabs::EnvelopeDocumentTabs::tabs_get_document`].
+    pub fn tabs_get_document(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        include_metadata: &str,
+        page_numbers: &str,
+    ) -> ClientResult<crate::Response<crate::types::EnvelopeDocumentTabs>> {
+        self.runtime.block_on(self.inner.tabs_get_document(
+            account_id,
+            document_id,
+            envelope_id,
+            include_metadata,
+            page_numbers,
+        ))
+    }
+    /// The blocking version of [`crate::envelope_document_tabs::EnvelopeDocumentTabs::tabs_get_document_request`].
+    pub fn tabs_get_document_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        document_id: &'a str,
+        envelope_id: &'a str,
+    ) -> EnvelopeDocumentTabsTabsGetDocumentRequest<'a> {
+        EnvelopeDocumentTabsTabsGetDocumentRequest {
+            inner: self
+                .inner
+                .tabs_get_document_request(account_id, document_id, envelope_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::envelope_document_tabs::EnvelopeDocumentTabs::tabs_put_document`].
+    pub fn tabs_put_document(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        body: &crate::types::Tabs,
+    ) -> ClientResult<crate::Response<crate::types::Tabs>> {
+        self.runtime.block_on(self.inner.tabs_put_document(
+            account_id,
+            document_id,
+            envelope_id,
+            body,
+        ))
+    }
+    /// The blocking version of [`crate::envelope_document_tabs::EnvelopeDocumentTabs::tabs_post_document`].
+    pub fn tabs_post_document(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        body: &crate::types::Tabs,
+    ) -> ClientResult<crate::Response<crate::types::Tabs>> {
+        self.runtime.block_on(self.inner.tabs_post_document(
+            account_id,
+            document_id,
+            envelope_id,
+            body,
+        ))
+    }
+    /// The blocking version of [`crate::envelope_document_tabs::EnvelopeDocumentTabs::tabs_delete_document`].
+    pub fn tabs_delete_document(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        body: &crate::types::Tabs,
+    ) -> ClientResult<crate::Response<crate::types::Tabs>> {
+        self.runtime.block_on(self.inner.tabs_delete_document(
+            account_id,
+            document_id,
+            envelope_id,
+            body,
+        ))
+    }
+}
@@ -0,0 +1,49 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::envelope_document_visibility::EnvelopeDocumentVisibility`].
+pub struct EnvelopeDocumentVisibility {
+    inner: crate::envelope_document_visibility::EnvelopeDocumentVisibility,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl EnvelopeDocumentVisibility {
+    pub(crate) fn new(
+        inner: crate::envelope_document_visibility::EnvelopeDocumentVisibility,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        EnvelopeDocumentVisibility { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::envelope_document_visibility::EnvelopeDocumentVisibility::recipients_get_recipient_document_visibility`].
+    pub fn recipients_get_recipient_document_visibility(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::DocumentVisibilityList>> {
+        self.runtime
+            .block_on(self.inner.recipients_get_recipient_document_visibility(
+                account_id,
+                envelope_id,
+                recipient_id,
+            ))
+    }
+    /// The blocking version of [`crate::envelope_document_visibility::EnvelopeDocumentVisibility::recipients_put_recipient_document_visibility`].
+    pub fn recipients_put_recipient_document_visibility(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+        body: &crate::types::DocumentVisibilityList,
+    ) -> ClientResult<crate::Response<crate::types::DocumentVisibilityList>> {
+        self.runtime
+            .block_on(self.inner.recipients_put_recipient_document_visibility(
+                account_id,
+                envelope_id,
+                recipient_id,
+                body,
+            ))
+    }
+}
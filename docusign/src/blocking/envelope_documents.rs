@@ -0,0 +1,247 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`EnvelopeDocuments::documents_get`], set with a builder.
+pub struct EnvelopeDocumentsDocumentsGetRequest<'a> {
+    inner: crate::envelope_documents::EnvelopeDocumentsDocumentsGetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> EnvelopeDocumentsDocumentsGetRequest<'a> {
+    /// Sets the `documents_by_userid` query parameter.
+    pub fn documents_by_userid(mut self, documents_by_userid: &'a str) -> Self {
+        self.inner = self.inner.documents_by_userid(documents_by_userid);
+        self
+    }
+
+    /// Sets the `include_document_size` query parameter.
+    pub fn include_document_size(mut self, include_document_size: &'a str) -> Self {
+        self.inner = self.inner.include_document_size(include_document_size);
+        self
+    }
+
+    /// Sets the `include_metadata` query parameter.
+    pub fn include_metadata(mut self, include_metadata: &'a str) -> Self {
+        self.inner = self.inner.include_metadata(include_metadata);
+        self
+    }
+
+    /// Sets the `include_tabs` query parameter.
+    pub fn include_tabs(mut self, include_tabs: &'a str) -> Self {
+        self.inner = self.inner.include_tabs(include_tabs);
+        self
+    }
+
+    /// Sets the `recipient_id` query parameter.
+    pub fn recipient_id(mut self, recipient_id: &'a str) -> Self {
+        self.inner = self.inner.recipient_id(recipient_id);
+        self
+    }
+
+    /// Sets the `shared_user_id` query parameter.
+    pub fn shared_user_id(mut self, shared_user_id: &'a str) -> Self {
+        self.inner = self.inner.shared_user_id(shared_user_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::EnvelopeDocumentsResult>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`EnvelopeDocuments::documents_get_document`], set with a builder.
+pub struct EnvelopeDocumentsDocumentsGetDocumentRequest<'a> {
+    inner: crate::envelope_documents::EnvelopeDocumentsDocumentsGetDocumentRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> EnvelopeDocumentsDocumentsGetDocumentRequest<'a> {
+    /// Sets the `certificate` query parameter.
+    pub fn certificate(mut self, certificate: &'a str) -> Self {
+        self.inner = self.inner.certificate(certificate);
+        self
+    }
+
+    /// Sets the `documents_by_userid` query parameter.
+    pub fn documents_by_userid(mut self, documents_by_userid: &'a str) -> Self {
+        self.inner = self.inner.documents_by_userid(documents_by_userid);
+        self
+    }
+
+    /// Sets the `encoding` query parameter.
+    pub fn encoding(mut self, encoding: &'a str) -> Self {
+        self.inner = self.inner.encoding(encoding);
+        self
+    }
+
+    /// Sets the `encrypt` query parameter.
+    pub fn encrypt(mut self, encrypt: &'a str) -> Self {
+        self.inner = self.inner.encrypt(encrypt);
+        self
+    }
+
+    /// Sets the `language` query parameter.
+    pub fn language(mut self, language: &'a str) -> Self {
+        self.inner = self.inner.language(language);
+        self
+    }
+
+    /// Sets the `recipient_id` query parameter.
+    pub fn recipient_id(mut self, recipient_id: &'a str) -> Self {
+        self.inner = self.inner.recipient_id(recipient_id);
+        self
+    }
+
+    /// Sets the `shared_user_id` query parameter.
+    pub fn shared_user_id(mut self, shared_user_id: &'a str) -> Self {
+        self.inner = self.inner.shared_user_id(shared_user_id);
+        self
+    }
+
+    /// Sets the `show_changes` query parameter.
+    pub fn show_changes(mut self, show_changes: &'a str) -> Self {
+        self.inner = self.inner.show_changes(show_changes);
+        self
+    }
+
+    /// Sets the `watermark` query parameter.
+    pub fn watermark(mut self, watermark: &'a str) -> Self {
+        self.inner = self.inner.watermark(watermark);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::envelope_documents::EnvelopeDocuments`].
+pub struct EnvelopeDocuments {
+    inner: crate::envelope_documents::EnvelopeDocuments,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl EnvelopeDocuments {
+    pub(crate) fn new(
+        inner: crate::envelope_documents::EnvelopeDocuments,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        EnvelopeDocuments { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::envelope_documents::EnvelopeDocuments::documents_get`].
+    pub fn documents_get(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        documents_by_userid: &str,
+        include_document_size: &str,
+        include_metadata: &str,
+        include_tabs: &str,
+        recipient_id: &str,
+        shared_user_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::EnvelopeDocumentsResult>> {
+        self.runtime.block_on(self.inner.documents_get(
+            account_id,
+            envelope_id,
+            documents_by_userid,
+            include_document_size,
+            include_metadata,
+            include_tabs,
+            recipient_id,
+            shared_user_id,
+        ))
+    }
+    /// The blocking version of [`crate::envelope_documents::EnvelopeDocuments::documents_get_request`].
+    pub fn documents_get_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        envelope_id: &'a str,
+    ) -> EnvelopeDocumentsDocumentsGetRequest<'a> {
+        EnvelopeDocumentsDocumentsGetRequest {
+            inner: self.inner.documents_get_request(account_id, envelope_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::envelope_documents::EnvelopeDocuments::documents_put`].
+    pub fn documents_put(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::EnvelopeDefinition,
+    ) -> ClientResult<crate::Response<crate::types::EnvelopeDocumentsResult>> {
+        self.runtime
+            .block_on(self.inner.documents_put(account_id, envelope_id, body))
+    }
+    /// The blocking version of [`crate::envelope_documents::EnvelopeDocuments::documents_delete`].
+    pub fn documents_delete(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::EnvelopeDefinition,
+    ) -> ClientResult<crate::Response<crate::types::EnvelopeDocumentsResult>> {
+        self.runtime
+            .block_on(self.inner.documents_delete(account_id, envelope_id, body))
+    }
+    /// The blocking version of [`crate::envelope_documents::EnvelopeDocuments::documents_get_document`].
+    pub fn documents_get_document(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        certificate: &str,
+        documents_by_userid: &str,
+        encoding: &str,
+        encrypt: &str,
+        language: &str,
+        recipient_id: &str,
+        shared_user_id: &str,
+        show_changes: &str,
+        watermark: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.documents_get_document(
+            account_id,
+            document_id,
+            envelope_id,
+            certificate,
+            documents_by_userid,
+            encoding,
+            encrypt,
+            language,
+            recipient_id,
+            shared_user_id,
+            show_changes,
+            watermark,
+        ))
+    }
+    /// The blocking version of [`crate::envelope_documents::EnvelopeDocuments::documents_get_document_request`].
+    pub fn documents_get_document_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        document_id: &'a str,
+        envelope_id: &'a str,
+    ) -> EnvelopeDocumentsDocumentsGetDocumentRequest<'a> {
+        EnvelopeDocumentsDocumentsGetDocumentRequest {
+            inner: self
+                .inner
+                .documents_get_document_request(account_id, document_id, envelope_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::envelope_documents::EnvelopeDocuments::documents_put_document`].
+    pub fn documents_put_document(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::EnvelopeDocument>> {
+        self.runtime.block_on(self.inner.documents_put_document(
+            account_id,
+            document_id,
+            envelope_id,
+        ))
+    }
+}
@@ -0,0 +1,59 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::envelope_email_settings::EnvelopeEmailSettings`].
+pub struct EnvelopeEmailSettings {
+    inner: crate::envelope_email_settings::EnvelopeEmailSettings,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl EnvelopeEmailSettings {
+    pub(crate) fn new(
+        inner: crate::envelope_email_settings::EnvelopeEmailSettings,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        EnvelopeEmailSettings { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::envelope_email_settings::EnvelopeEmailSettings::email_settings_get`].
+    pub fn email_settings_get(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::EmailSettings>> {
+        self.runtime
+            .block_on(self.inner.email_settings_get(account_id, envelope_id))
+    }
+    /// The blocking version of [`crate::envelope_email_settings::EnvelopeEmailSettings::email_settings_put`].
+    pub fn email_settings_put(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::EmailSettings,
+    ) -> ClientResult<crate::Response<crate::types::EmailSettings>> {
+        self.runtime
+            .block_on(self.inner.email_settings_put(account_id, envelope_id, body))
+    }
+    /// The blocking version of [`crate::envelope_email_settings::EnvelopeEmailSettings::email_settings_post`].
+    pub fn email_settings_post(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::EmailSettings,
+    ) -> ClientResult<crate::Response<crate::types::EmailSettings>> {
+        self.runtime.block_on(
+            self.inner
+                .email_settings_post(account_id, envelope_id, body),
+        )
+    }
+    /// The blocking version of [`crate::envelope_email_settings::EnvelopeEmailSettings::email_settings_delete`].
+    pub fn email_settings_delete(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::EmailSettings>> {
+        self.runtime
+            .block_on(self.inner.email_settings_delete(account_id, envelope_id))
+    }
+}
@@ -0,0 +1,28 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::envelope_form_data::EnvelopeFormData`].
+pub struct EnvelopeFormData {
+    inner: crate::envelope_form_data::EnvelopeFormData,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl EnvelopeFormData {
+    pub(crate) fn new(
+        inner: crate::envelope_form_data::EnvelopeFormData,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        EnvelopeFormData { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::envelope_form_data::EnvelopeFormData::form_data_get`].
+    pub fn form_data_get(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::EnvelopeFormDataType>> {
+        self.runtime
+            .block_on(self.inner.form_data_get(account_id, envelope_id))
+    }
+}
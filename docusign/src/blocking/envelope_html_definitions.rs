@@ -0,0 +1,30 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::envelope_html_definitions::EnvelopeHtmlDefinitions`].
+pub struct EnvelopeHtmlDefinitions {
+    inner: crate::envelope_html_definitions::EnvelopeHtmlDefinitions,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl EnvelopeHtmlDefinitions {
+    pub(crate) fn new(
+        inner: crate::envelope_html_definitions::EnvelopeHtmlDefinitions,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        EnvelopeHtmlDefinitions { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::envelope_html_definitions::EnvelopeHtmlDefinitions::responsive_html_get_envelope_definition`].
+    pub fn responsive_html_get_envelope_definition(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::DocumentHtmlDefinitionOriginals>> {
+        self.runtime.block_on(
+            self.inner
+                .responsive_html_get_envelope_definition(account_id, envelope_id),
+        )
+    }
+}
@@ -0,0 +1,57 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::envelope_locks::EnvelopeLocks`].
+pub struct EnvelopeLocks {
+    inner: crate::envelope_locks::EnvelopeLocks,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl EnvelopeLocks {
+    pub(crate) fn new(
+        inner: crate::envelope_locks::EnvelopeLocks,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        EnvelopeLocks { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::envelope_locks::EnvelopeLocks::lock_get_envelope`].
+    pub fn lock_get_envelope(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::EnvelopeLocks>> {
+        self.runtime
+            .block_on(self.inner.lock_get_envelope(account_id, envelope_id))
+    }
+    /// The blocking version of [`crate::envelope_locks::EnvelopeLocks::lock_put_envelope`].
+    pub fn lock_put_envelope(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::LockRequest,
+    ) -> ClientResult<crate::Response<crate::types::EnvelopeLocks>> {
+        self.runtime
+            .block_on(self.inner.lock_put_envelope(account_id, envelope_id, body))
+    }
+    /// The blocking version of [`crate::envelope_locks::EnvelopeLocks::lock_post_envelope`].
+    pub fn lock_post_envelope(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::LockRequest,
+    ) -> ClientResult<crate::Response<crate::types::EnvelopeLocks>> {
+        self.runtime
+            .block_on(self.inner.lock_post_envelope(account_id, envelope_id, body))
+    }
+    /// The blocking version of [`crate::envelope_locks::EnvelopeLocks::lock_delete_envelope`].
+    pub fn lock_delete_envelope(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::EnvelopeLocks>> {
+        self.runtime
+            .block_on(self.inner.lock_delete_envelope(account_id, envelope_id))
+    }
+}
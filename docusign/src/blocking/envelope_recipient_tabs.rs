@@ -0,0 +1,129 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`EnvelopeRecipientTabs::recipients_get_recipient_tab`], set with a builder.
+pub struct EnvelopeRecipientTabsRecipientsGetRecipientTabRequest<'a> {
+    inner:
+        crate::envelope_recipient_tabs::EnvelopeRecipientTabsRecipientsGetRecipientTabRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> EnvelopeRecipientTabsRecipientsGetRecipientTabRequest<'a> {
+    /// Sets the `include_anchor_tab_locations` query parameter.
+    pub fn include_anchor_tab_locations(mut self, include_anchor_tab_locations: &'a str) -> Self {
+        self.inner = self
+            .inner
+            .include_anchor_tab_locations(include_anchor_tab_locations);
+        self
+    }
+
+    /// Sets the `include_metadata` query parameter.
+    pub fn include_metadata(mut self, include_metadata: &'a str) -> Self {
+        self.inner = self.inner.include_metadata(include_metadata);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::EnvelopeRecipientTabs>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::envelope_recipient_tabs::EnvelopeRecipientTabs`].
+pub struct EnvelopeRecipientTabs {
+    inner: crate::envelope_recipient_tabs::EnvelopeRecipientTabs,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl EnvelopeRecipientTabs {
+    pub(crate) fn new(
+        inner: crate::envelope_recipient_tabs::EnvelopeRecipientTabs,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        EnvelopeRecipientTabs { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::envelope_recipient_tabs::EnvelopeRecipientTabs::recipients_get_recipient_tab`].
+    pub fn recipients_get_recipient_tab(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+        include_anchor_tab_locations: &str,
+        include_metadata: &str,
+    ) -> ClientResult<crate::Response<crate::types::EnvelopeRecipientTabs>> {
+        self.runtime
+            .block_on(self.inner.recipients_get_recipient_tab(
+                account_id,
+                envelope_id,
+                recipient_id,
+                include_anchor_tab_locations,
+                include_metadata,
+            ))
+    }
+    /// The blocking version of [`crate::envelope_recipient_tabs::EnvelopeRecipientTabs::recipients_get_recipient_tab_request`].
+    pub fn recipients_get_recipient_tab_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        envelope_id: &'a str,
+        recipient_id: &'a str,
+    ) -> EnvelopeRecipientTabsRecipientsGetRecipientTabRequest<'a> {
+        EnvelopeRecipientTabsRecipientsGetRecipientTabRequest {
+            inner: self.inner.recipients_get_recipient_tab_request(
+                account_id,
+                envelope_id,
+                recipient_id,
+            ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::envelope_recipient_tabs::EnvelopeRecipientTabs::recipients_put_recipient_tabs`].
+    pub fn recipients_put_recipient_tabs(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+        body: &crate::types::EnvelopeRecipientTabs,
+    ) -> ClientResult<crate::Response<crate::types::EnvelopeRecipientTabs>> {
+        self.runtime
+            .block_on(self.inner.recipients_put_recipient_tabs(
+                account_id,
+                envelope_id,
+                recipient_id,
+                body,
+            ))
+    }
+    /// The blocking version of [`crate::envelope_recipient_tabs::EnvelopeRecipientTabs::recipients_post_recipient_tabs`].
+    pub fn recipients_post_recipient_tabs(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+        body: &crate::types::EnvelopeRecipientTabs,
+    ) -> ClientResult<crate::Response<crate::types::EnvelopeRecipientTabs>> {
+        self.runtime
+            .block_on(self.inner.recipients_post_recipient_tabs(
+                account_id,
+                envelope_id,
+                recipient_id,
+                body,
+            ))
+    }
+    /// The blocking version of [`crate::envelope_recipient_tabs::EnvelopeRecipientTabs::recipients_delete_recipient_tabs`].
+    pub fn recipients_delete_recipient_tabs(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+        body: &crate::types::EnvelopeRecipientTabs,
+    ) -> ClientResult<crate::Response<crate::types::EnvelopeRecipientTabs>> {
+        self.runtime
+            .block_on(self.inner.recipients_delete_recipient_tabs(
+                account_id,
+                envelope_id,
+                recipient_id,
+                body,
+            ))
+    }
+}
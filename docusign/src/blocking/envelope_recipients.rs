@@ -0,0 +1,278 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`EnvelopeRecipients::recipients_get`], set with a builder.
+pub struct EnvelopeRecipientsRecipientsGetRequest<'a> {
+    inner: crate::envelope_recipients::EnvelopeRecipientsRecipientsGetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> EnvelopeRecipientsRecipientsGetRequest<'a> {
+    /// Sets the `include_anchor_tab_locations` query parameter.
+    pub fn include_anchor_tab_locations(mut self, include_anchor_tab_locations: &'a str) -> Self {
+        self.inner = self
+            .inner
+            .include_anchor_tab_locations(include_anchor_tab_locations);
+        self
+    }
+
+    /// Sets the `include_extended` query parameter.
+    pub fn include_extended(mut self, include_extended: &'a str) -> Self {
+        self.inner = self.inner.include_extended(include_extended);
+        self
+    }
+
+    /// Sets the `include_metadata` query parameter.
+    pub fn include_metadata(mut self, include_metadata: &'a str) -> Self {
+        self.inner = self.inner.include_metadata(include_metadata);
+        self
+    }
+
+    /// Sets the `include_tabs` query parameter.
+    pub fn include_tabs(mut self, include_tabs: &'a str) -> Self {
+        self.inner = self.inner.include_tabs(include_tabs);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::EnvelopeRecipients>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`EnvelopeRecipients::recipients_put`], set with a builder.
+pub struct EnvelopeRecipientsRecipientsPutRequest<'a> {
+    inner: crate::envelope_recipients::EnvelopeRecipientsRecipientsPutRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> EnvelopeRecipientsRecipientsPutRequest<'a> {
+    /// Sets the `combine_same_order_recipients` query parameter.
+    pub fn combine_same_order_recipients(mut self, combine_same_order_recipients: &'a str) -> Self {
+        self.inner = self
+            .inner
+            .combine_same_order_recipients(combine_same_order_recipients);
+        self
+    }
+
+    /// Sets the `offline_signing` query parameter.
+    pub fn offline_signing(mut self, offline_signing: &'a str) -> Self {
+        self.inner = self.inner.offline_signing(offline_signing);
+        self
+    }
+
+    /// Sets the `resend_envelope` query parameter.
+    pub fn resend_envelope(mut self, resend_envelope: &'a str) -> Self {
+        self.inner = self.inner.resend_envelope(resend_envelope);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::RecipientsUpdateSummary>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`EnvelopeRecipients::recipients_post`], set with a builder.
+pub struct EnvelopeRecipientsRecipientsPostRequest<'a> {
+    inner: crate::envelope_recipients::EnvelopeRecipientsRecipientsPostRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> EnvelopeRecipientsRecipientsPostRequest<'a> {
+    /// Sets the `resend_envelope` query parameter.
+    pub fn resend_envelope(mut self, resend_envelope: &'a str) -> Self {
+        self.inner = self.inner.resend_envelope(resend_envelope);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::EnvelopeRecipients>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::envelope_recipients::EnvelopeRecipients`].
+pub struct EnvelopeRecipients {
+    inner: crate::envelope_recipients::EnvelopeRecipients,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl EnvelopeRecipients {
+    pub(crate) fn new(
+        inner: crate::envelope_recipients::EnvelopeRecipients,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        EnvelopeRecipients { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::envelope_recipients::EnvelopeRecipients::recipients_get`].
+    pub fn recipients_get(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        include_anchor_tab_locations: &str,
+        include_extended: &str,
+        include_metadata: &str,
+        include_tabs: &str,
+    ) -> ClientResult<crate::Response<crate::types::EnvelopeRecipients>> {
+        self.runtime.block_on(self.inner.recipients_get(
+            account_id,
+            envelope_id,
+            include_anchor_tab_locations,
+            include_extended,
+            include_metadata,
+            include_tabs,
+        ))
+    }
+    /// The blocking version of [`crate::envelope_recipients::EnvelopeRecipients::recipients_get_request`].
+    pub fn recipients_get_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        envelope_id: &'a str,
+    ) -> EnvelopeRecipientsRecipientsGetRequest<'a> {
+        EnvelopeRecipientsRecipientsGetRequest {
+            inner: self.inner.recipients_get_request(account_id, envelope_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::envelope_recipients::EnvelopeRecipients::recipients_put`].
+    pub fn recipients_put(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        combine_same_order_recipients: &str,
+        offline_signing: &str,
+        resend_envelope: &str,
+        body: &crate::types::EnvelopeRecipients,
+    ) -> ClientResult<crate::Response<crate::types::RecipientsUpdateSummary>> {
+        self.runtime.block_on(self.inner.recipients_put(
+            account_id,
+            envelope_id,
+            combine_same_order_recipients,
+            offline_signing,
+            resend_envelope,
+            body,
+        ))
+    }
+    /// The blocking version of [`crate::envelope_recipients::EnvelopeRecipients::recipients_put_request`].
+    pub fn recipients_put_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        envelope_id: &'a str,
+        body: &'a crate::types::EnvelopeRecipients,
+    ) -> EnvelopeRecipientsRecipientsPutRequest<'a> {
+        EnvelopeRecipientsRecipientsPutRequest {
+            inner: self
+                .inner
+                .recipients_put_request(account_id, envelope_id, body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::envelope_recipients::EnvelopeRecipients::recipients_post`].
+    pub fn recipients_post(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        resend_envelope: &str,
+        body: &crate::types::EnvelopeRecipients,
+    ) -> ClientResult<crate::Response<crate::types::EnvelopeRecipients>> {
+        self.runtime.block_on(self.inner.recipients_post(
+            account_id,
+            envelope_id,
+            resend_envelope,
+            body,
+        ))
+    }
+    /// The blocking version of [`crate::envelope_recipients::EnvelopeRecipients::recipients_post_request`].
+    pub fn recipients_post_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        envelope_id: &'a str,
+        body: &'a crate::types::EnvelopeRecipients,
+    ) -> EnvelopeRecipientsRecipientsPostRequest<'a> {
+        EnvelopeRecipientsRecipientsPostRequest {
+            inner: self
+                .inner
+                .recipients_post_request(account_id, envelope_id, body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::envelope_recipients::EnvelopeRecipients::recipients_delete`].
+    pub fn recipients_delete(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::EnvelopeRecipients,
+    ) -> ClientResult<crate::Response<crate::types::EnvelopeRecipients>> {
+        self.runtime
+            .block_on(self.inner.recipients_delete(account_id, envelope_id, body))
+    }
+    /// The blocking version of [`crate::envelope_recipients::EnvelopeRecipients::recipients_put_document_visibility`].
+    pub fn recipients_put_document_visibility(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::DocumentVisibilityList,
+    ) -> ClientResult<crate::Response<crate::types::DocumentVisibilityList>> {
+        self.runtime
+            .block_on(
+                self.inner
+                    .recipients_put_document_visibility(account_id, envelope_id, body),
+            )
+    }
+    /// The blocking version of [`crate::envelope_recipients::EnvelopeRecipients::recipients_delete_recipient`].
+    pub fn recipients_delete_recipient(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::EnvelopeRecipients>> {
+        self.runtime
+            .block_on(
+                self.inner
+                    .recipients_delete_recipient(account_id, envelope_id, recipient_id),
+            )
+    }
+    /// The blocking version of [`crate::envelope_recipients::EnvelopeRecipients::recipients_post_recipient_proof_file_resource_token`].
+    pub fn recipients_post_recipient_proof_file_resource_token(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ProofServiceResourceToken>> {
+        self.runtime.block_on(
+            self.inner
+                .recipients_post_recipient_proof_file_resource_token(
+                    account_id,
+                    envelope_id,
+                    recipient_id,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::envelope_recipients::EnvelopeRecipients::views_post_recipient_manual_review_view`].
+    pub fn views_post_recipient_manual_review_view(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ViewUrl>> {
+        self.runtime
+            .block_on(self.inner.views_post_recipient_manual_review_view(
+                account_id,
+                envelope_id,
+                recipient_id,
+            ))
+    }
+    /// The blocking version of [`crate::envelope_recipients::EnvelopeRecipients::views_post_preview`].
+    pub fn views_post_preview(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::RecipientPreviewRequest,
+    ) -> ClientResult<crate::Response<crate::types::ViewUrl>> {
+        self.runtime
+            .block_on(self.inner.views_post_preview(account_id, envelope_id, body))
+    }
+}
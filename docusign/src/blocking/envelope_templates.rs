@@ -0,0 +1,232 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`EnvelopeTemplates::templates_get_document`], set with a builder.
+pub struct EnvelopeTemplatesTemplatesGetDocumentRequest<'a> {
+    inner: crate::envelope_templates::EnvelopeTemplatesTemplatesGetDocumentRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> EnvelopeTemplatesTemplatesGetDocumentRequest<'a> {
+    /// Sets the `include` query parameter.
+    pub fn include(mut self, include: &'a str) -> Self {
+        self.inner = self.inner.include(include);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::TemplateInformation>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`EnvelopeTemplates::templates_post_document`], set with a builder.
+pub struct EnvelopeTemplatesTemplatesPostDocumentRequest<'a> {
+    inner: crate::envelope_templates::EnvelopeTemplatesTemplatesPostDocumentRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> EnvelopeTemplatesTemplatesPostDocumentRequest<'a> {
+    /// Sets the `preserve_template_recipient` query parameter.
+    pub fn preserve_template_recipient(mut self, preserve_template_recipient: &'a str) -> Self {
+        self.inner = self
+            .inner
+            .preserve_template_recipient(preserve_template_recipient);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::DocumentTemplateList>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`EnvelopeTemplates::templates_get_envelope`], set with a builder.
+pub struct EnvelopeTemplatesTemplatesGetEnvelopeRequest<'a> {
+    inner: crate::envelope_templates::EnvelopeTemplatesTemplatesGetEnvelopeRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> EnvelopeTemplatesTemplatesGetEnvelopeRequest<'a> {
+    /// Sets the `include` query parameter.
+    pub fn include(mut self, include: &'a str) -> Self {
+        self.inner = self.inner.include(include);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::TemplateInformation>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`EnvelopeTemplates::templates_post_envelope`], set with a builder.
+pub struct EnvelopeTemplatesTemplatesPostEnvelopeRequest<'a> {
+    inner: crate::envelope_templates::EnvelopeTemplatesTemplatesPostEnvelopeRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> EnvelopeTemplatesTemplatesPostEnvelopeRequest<'a> {
+    /// Sets the `preserve_template_recipient` query parameter.
+    pub fn preserve_template_recipient(mut self, preserve_template_recipient: &'a str) -> Self {
+        self.inner = self
+            .inner
+            .preserve_template_recipient(preserve_template_recipient);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::DocumentTemplateList>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::envelope_templates::EnvelopeTemplates`].
+pub struct EnvelopeTemplates {
+    inner: crate::envelope_templates::EnvelopeTemplates,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl EnvelopeTemplates {
+    pub(crate) fn new(
+        inner: crate::envelope_templates::EnvelopeTemplates,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        EnvelopeTemplates { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::envelope_templates::EnvelopeTemplates::templates_get_document`].
+    pub fn templates_get_document(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        include: &str,
+    ) -> ClientResult<crate::Response<crate::types::TemplateInformation>> {
+        self.runtime.block_on(self.inner.templates_get_document(
+            account_id,
+            document_id,
+            envelope_id,
+            include,
+        ))
+    }
+    /// The blocking version of [`crate::envelope_templates::EnvelopeTemplates::templates_get_document_request`].
+    pub fn templates_get_document_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        document_id: &'a str,
+        envelope_id: &'a str,
+    ) -> EnvelopeTemplatesTemplatesGetDocumentRequest<'a> {
+        EnvelopeTemplatesTemplatesGetDocumentRequest {
+            inner: self
+                .inner
+                .templates_get_document_request(account_id, document_id, envelope_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::envelope_templates::EnvelopeTemplates::templates_post_document`].
+    pub fn templates_post_document(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        preserve_template_recipient: &str,
+        body: &crate::types::DocumentTemplateList,
+    ) -> ClientResult<crate::Response<crate::types::DocumentTemplateList>> {
+        self.runtime.block_on(self.inner.templates_post_document(
+            account_id,
+            document_id,
+            envelope_id,
+            preserve_template_recipient,
+            body,
+        ))
+    }
+    /// The blocking version of [`crate::envelope_templates::EnvelopeTemplates::templates_post_document_request`].
+    pub fn templates_post_document_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        document_id: &'a str,
+        envelope_id: &'a str,
+        body: &'a crate::types::DocumentTemplateList,
+    ) -> EnvelopeTemplatesTemplatesPostDocumentRequest<'a> {
+        EnvelopeTemplatesTemplatesPostDocumentRequest {
+            inner: self.inner.templates_post_document_request(
+                account_id,
+                document_id,
+                envelope_id,
+                body,
+            ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::envelope_templates::EnvelopeTemplates::templates_delete_document`].
+    pub fn templates_delete_document(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        template_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.templates_delete_document(
+            account_id,
+            document_id,
+            envelope_id,
+            template_id,
+        ))
+    }
+    /// The blocking version of [`crate::envelope_templates::EnvelopeTemplates::templates_get_envelope`].
+    pub fn templates_get_envelope(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        include: &str,
+    ) -> ClientResult<crate::Response<crate::types::TemplateInformation>> {
+        self.runtime.block_on(
+            self.inner
+                .templates_get_envelope(account_id, envelope_id, include),
+        )
+    }
+    /// The blocking version of [`crate::envelope_templates::EnvelopeTemplates::templates_get_envelope_request`].
+    pub fn templates_get_envelope_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        envelope_id: &'a str,
+    ) -> EnvelopeTemplatesTemplatesGetEnvelopeRequest<'a> {
+        EnvelopeTemplatesTemplatesGetEnvelopeRequest {
+            inner: self
+                .inner
+                .templates_get_envelope_request(account_id, envelope_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::envelope_templates::EnvelopeTemplates::templates_post_envelope`].
+    pub fn templates_post_envelope(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        preserve_template_recipient: &str,
+        body: &crate::types::DocumentTemplateList,
+    ) -> ClientResult<crate::Response<crate::types::DocumentTemplateList>> {
+        self.runtime.block_on(self.inner.templates_post_envelope(
+            account_id,
+            envelope_id,
+            preserve_template_recipient,
+            body,
+        ))
+    }
+    /// The blocking version of [`crate::envelope_templates::EnvelopeTemplates::templates_post_envelope_request`].
+    pub fn templates_post_envelope_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        envelope_id: &'a str,
+        body: &'a crate::types::DocumentTemplateList,
+    ) -> EnvelopeTemplatesTemplatesPostEnvelopeRequest<'a> {
+        EnvelopeTemplatesTemplatesPostEnvelopeRequest {
+            inner: self
+                .inner
+                .templates_post_envelope_request(account_id, envelope_id, body),
+            runtime: &self.runtime,
+        }
+    }
+}
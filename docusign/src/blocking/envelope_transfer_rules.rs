@@ -0,0 +1,100 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`EnvelopeTransferRules::get`], set with a builder.
+pub struct EnvelopeTransferRulesGetRequest<'a> {
+    inner: crate::envelope_transfer_rules::EnvelopeTransferRulesGetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> EnvelopeTransferRulesGetRequest<'a> {
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: &'a str) -> Self {
+        self.inner = self.inner.count(count);
+        self
+    }
+
+    /// Sets the `start_position` query parameter.
+    pub fn start_position(mut self, start_position: &'a str) -> Self {
+        self.inner = self.inner.start_position(start_position);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::EnvelopeTransferRuleInformation>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::envelope_transfer_rules::EnvelopeTransferRules`].
+pub struct EnvelopeTransferRules {
+    inner: crate::envelope_transfer_rules::EnvelopeTransferRules,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl EnvelopeTransferRules {
+    pub(crate) fn new(
+        inner: crate::envelope_transfer_rules::EnvelopeTransferRules,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        EnvelopeTransferRules { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::envelope_transfer_rules::EnvelopeTransferRules::get`].
+    pub fn get(
+        &self,
+        account_id: &str,
+        count: &str,
+        start_position: &str,
+    ) -> ClientResult<crate::Response<crate::types::EnvelopeTransferRuleInformation>> {
+        self.runtime
+            .block_on(self.inner.get(account_id, count, start_position))
+    }
+    /// The blocking version of [`crate::envelope_transfer_rules::EnvelopeTransferRules::get_request`].
+    pub fn get_request<'a>(&'a self, account_id: &'a str) -> EnvelopeTransferRulesGetRequest<'a> {
+        EnvelopeTransferRulesGetRequest {
+            inner: self.inner.get_request(account_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::envelope_transfer_rules::EnvelopeTransferRules::put`].
+    pub fn put(
+        &self,
+        account_id: &str,
+        body: &crate::types::EnvelopeTransferRuleInformation,
+    ) -> ClientResult<crate::Response<crate::types::EnvelopeTransferRuleInformation>> {
+        self.runtime.block_on(self.inner.put(account_id, body))
+    }
+    /// The blocking version of [`crate::envelope_transfer_rules::EnvelopeTransferRules::post`].
+    pub fn post(
+        &self,
+        account_id: &str,
+        body: &crate::types::EnvelopeTransferRuleRequest,
+    ) -> ClientResult<crate::Response<crate::types::EnvelopeTransferRuleInformation>> {
+        self.runtime.block_on(self.inner.post(account_id, body))
+    }
+    /// The blocking version of [`crate::envelope_transfer_rules::EnvelopeTransferRules::put_rule`].
+    pub fn put_rule(
+        &self,
+        account_id: &str,
+        envelope_transfer_rule_id: &str,
+        body: &crate::types::EnvelopeTransferRule,
+    ) -> ClientResult<crate::Response<crate::types::EnvelopeTransferRule>> {
+        self.runtime.block_on(
+            self.inner
+                .put_rule(account_id, envelope_transfer_rule_id, body),
+        )
+    }
+    /// The blocking version of [`crate::envelope_transfer_rules::EnvelopeTransferRules::delete`].
+    pub fn delete(
+        &self,
+        account_id: &str,
+        envelope_transfer_rule_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete(account_id, envelope_transfer_rule_id))
+    }
+}
@@ -0,0 +1,107 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::envelope_views::EnvelopeViews`].
+pub struct EnvelopeViews {
+    inner: crate::envelope_views::EnvelopeViews,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl EnvelopeViews {
+    pub(crate) fn new(
+        inner: crate::envelope_views::EnvelopeViews,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        EnvelopeViews { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::envelope_views::EnvelopeViews::views_post_envelope_correct_view`].
+    pub fn views_post_envelope_correct_view(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::CorrectViewRequest,
+    ) -> ClientResult<crate::Response<crate::types::EnvelopeViews>> {
+        self.runtime
+            .block_on(
+                self.inner
+                    .views_post_envelope_correct_view(account_id, envelope_id, body),
+            )
+    }
+    /// The blocking version of [`crate::envelope_views::EnvelopeViews::views_delete_envelope_correct_view`].
+    pub fn views_delete_envelope_correct_view(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::CorrectViewRequest,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(
+                self.inner
+                    .views_delete_envelope_correct_view(account_id, envelope_id, body),
+            )
+    }
+    /// The blocking version of [`crate::envelope_views::EnvelopeViews::views_post_envelope_edit_view`].
+    pub fn views_post_envelope_edit_view(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::ReturnUrlRequest,
+    ) -> ClientResult<crate::Response<crate::types::EnvelopeViews>> {
+        self.runtime
+            .block_on(
+                self.inner
+                    .views_post_envelope_edit_view(account_id, envelope_id, body),
+            )
+    }
+    /// The blocking version of [`crate::envelope_views::EnvelopeViews::views_post_envelope_recipient_view`].
+    pub fn views_post_envelope_recipient_view(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::RecipientViewRequest,
+    ) -> ClientResult<crate::Response<crate::types::EnvelopeViews>> {
+        self.runtime
+            .block_on(
+                self.inner
+                    .views_post_envelope_recipient_view(account_id, envelope_id, body),
+            )
+    }
+    /// The blocking version of [`crate::envelope_views::EnvelopeViews::views_post_envelope_sender_view`].
+    pub fn views_post_envelope_sender_view(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::ReturnUrlRequest,
+    ) -> ClientResult<crate::Response<crate::types::EnvelopeViews>> {
+        self.runtime
+            .block_on(
+                self.inner
+                    .views_post_envelope_sender_view(account_id, envelope_id, body),
+            )
+    }
+    /// The blocking version of [`crate::envelope_views::EnvelopeViews::views_post_envelope_recipient_shared_view`].
+    pub fn views_post_envelope_recipient_shared_view(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::RecipientViewRequest,
+    ) -> ClientResult<crate::Response<crate::types::ViewUrl>> {
+        self.runtime
+            .block_on(self.inner.views_post_envelope_recipient_shared_view(
+                account_id,
+                envelope_id,
+                body,
+            ))
+    }
+    /// The blocking version of [`crate::envelope_views::EnvelopeViews::views_post_account_console_view`].
+    pub fn views_post_account_console_view(
+        &self,
+        account_id: &str,
+        body: &crate::types::ConsoleViewRequest,
+    ) -> ClientResult<crate::Response<crate::types::EnvelopeViews>> {
+        self.runtime
+            .block_on(self.inner.views_post_account_console_view(account_id, body))
+    }
+}
@@ -0,0 +1,71 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::envelope_workflow_definition::EnvelopeWorkflowDefinition`].
+pub struct EnvelopeWorkflowDefinition {
+    inner: crate::envelope_workflow_definition::EnvelopeWorkflowDefinition,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl EnvelopeWorkflowDefinition {
+    pub(crate) fn new(
+        inner: crate::envelope_workflow_definition::EnvelopeWorkflowDefinition,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        EnvelopeWorkflowDefinition { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::envelope_workflow_definition::EnvelopeWorkflowDefinition::get`].
+    pub fn get(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Workflow>> {
+        self.runtime
+            .block_on(self.inner.get(account_id, envelope_id))
+    }
+    /// The blocking version of [`crate::envelope_workflow_definition::EnvelopeWorkflowDefinition::put`].
+    pub fn put(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::Workflow,
+    ) -> ClientResult<crate::Response<crate::types::Workflow>> {
+        self.runtime
+            .block_on(self.inner.put(account_id, envelope_id, body))
+    }
+    /// The blocking version of [`crate::envelope_workflow_definition::EnvelopeWorkflowDefinition::delete`].
+    pub fn delete(&self, account_id: &str, envelope_id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete(account_id, envelope_id))
+    }
+    /// The blocking version of [`crate::envelope_workflow_definition::EnvelopeWorkflowDefinition::get_template`].
+    pub fn get_template(
+        &self,
+        account_id: &str,
+        template_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Workflow>> {
+        self.runtime
+            .block_on(self.inner.get_template(account_id, template_id))
+    }
+    /// The blocking version of [`crate::envelope_workflow_definition::EnvelopeWorkflowDefinition::put_template`].
+    pub fn put_template(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        body: &crate::types::Workflow,
+    ) -> ClientResult<crate::Response<crate::types::Workflow>> {
+        self.runtime
+            .block_on(self.inner.put_template(account_id, template_id, body))
+    }
+    /// The blocking version of [`crate::envelope_workflow_definition::EnvelopeWorkflowDefinition::delete_template`].
+    pub fn delete_template(
+        &self,
+        account_id: &str,
+        template_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete_template(account_id, template_id))
+    }
+}
@@ -0,0 +1,974 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Envelopes::get`], set with a builder.
+pub struct EnvelopesGetRequest<'a> {
+    inner: crate::envelopes::EnvelopesGetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> EnvelopesGetRequest<'a> {
+    /// Sets the `ac_status` query parameter.
+    pub fn ac_status(mut self, ac_status: &'a str) -> Self {
+        self.inner = self.inner.ac_status(ac_status);
+        self
+    }
+
+    /// Sets the `block` query parameter.
+    pub fn block(mut self, block: &'a str) -> Self {
+        self.inner = self.inner.block(block);
+        self
+    }
+
+    /// Sets the `cdse_mode` query parameter.
+    pub fn cdse_mode(mut self, cdse_mode: &'a str) -> Self {
+        self.inner = self.inner.cdse_mode(cdse_mode);
+        self
+    }
+
+    /// Sets the `continuation_token` query parameter.
+    pub fn continuation_token(mut self, continuation_token: &'a str) -> Self {
+        self.inner = self.inner.continuation_token(continuation_token);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: &'a str) -> Self {
+        self.inner = self.inner.count(count);
+        self
+    }
+
+    /// Sets the `custom_field` query parameter.
+    pub fn custom_field(mut self, custom_field: &'a str) -> Self {
+        self.inner = self.inner.custom_field(custom_field);
+        self
+    }
+
+    /// Sets the `email` query parameter.
+    pub fn email(mut self, email: &'a str) -> Self {
+        self.inner = self.inner.email(email);
+        self
+    }
+
+    /// Sets the `envelope_ids` query parameter.
+    pub fn envelope_ids(mut self, envelope_ids: &'a str) -> Self {
+        self.inner = self.inner.envelope_ids(envelope_ids);
+        self
+    }
+
+    /// Sets the `exclude` query parameter.
+    pub fn exclude(mut self, exclude: &'a str) -> Self {
+        self.inner = self.inner.exclude(exclude);
+        self
+    }
+
+    /// Sets the `folder_ids` query parameter.
+    pub fn folder_ids(mut self, folder_ids: &'a str) -> Self {
+        self.inner = self.inner.folder_ids(folder_ids);
+        self
+    }
+
+    /// Sets the `folder_types` query parameter.
+    pub fn folder_types(mut self, folder_types: &'a str) -> Self {
+        self.inner = self.inner.folder_types(folder_types);
+        self
+    }
+
+    /// Sets the `from_date` query parameter.
+    pub fn from_date(mut self, from_date: &'a str) -> Self {
+        self.inner = self.inner.from_date(from_date);
+        self
+    }
+
+    /// Sets the `from_to_status` query parameter.
+    pub fn from_to_status(mut self, from_to_status: &'a str) -> Self {
+        self.inner = self.inner.from_to_status(from_to_status);
+        self
+    }
+
+    /// Sets the `include` query parameter.
+    pub fn include(mut self, include: &'a str) -> Self {
+        self.inner = self.inner.include(include);
+        self
+    }
+
+    /// Sets the `include_purge_information` query parameter.
+    pub fn include_purge_information(mut self, include_purge_information: &'a str) -> Self {
+        self.inner = self
+            .inner
+            .include_purge_information(include_purge_information);
+        self
+    }
+
+    /// Sets the `intersecting_folder_ids` query parameter.
+    pub fn intersecting_folder_ids(mut self, intersecting_folder_ids: &'a str) -> Self {
+        self.inner = self.inner.intersecting_folder_ids(intersecting_folder_ids);
+        self
+    }
+
+    /// Sets the `last_queried_date` query parameter.
+    pub fn last_queried_date(mut self, last_queried_date: &'a str) -> Self {
+        self.inner = self.inner.last_queried_date(last_queried_date);
+        self
+    }
+
+    /// Sets the `order` query parameter.
+    pub fn order(mut self, order: &'a str) -> Self {
+        self.inner = self.inner.order(order);
+        self
+    }
+
+    /// Sets the `order_by` query parameter.
+    pub fn order_by(mut self, order_by: &'a str) -> Self {
+        self.inner = self.inner.order_by(order_by);
+        self
+    }
+
+    /// Sets the `powerformids` query parameter.
+    pub fn powerformids(mut self, powerformids: &'a str) -> Self {
+        self.inner = self.inner.powerformids(powerformids);
+        self
+    }
+
+    /// Sets the `query_budget` query parameter.
+    pub fn query_budget(mut self, query_budget: &'a str) -> Self {
+        self.inner = self.inner.query_budget(query_budget);
+        self
+    }
+
+    /// Sets the `requester_date_format` query parameter.
+    pub fn requester_date_format(mut self, requester_date_format: &'a str) -> Self {
+        self.inner = self.inner.requester_date_format(requester_date_format);
+        self
+    }
+
+    /// Sets the `search_text` query parameter.
+    pub fn search_text(mut self, search_text: &'a str) -> Self {
+        self.inner = self.inner.search_text(search_text);
+        self
+    }
+
+    /// Sets the `start_position` query parameter.
+    pub fn start_position(mut self, start_position: &'a str) -> Self {
+        self.inner = self.inner.start_position(start_position);
+        self
+    }
+
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: &'a str) -> Self {
+        self.inner = self.inner.status(status);
+        self
+    }
+
+    /// Sets the `to_date` query parameter.
+    pub fn to_date(mut self, to_date: &'a str) -> Self {
+        self.inner = self.inner.to_date(to_date);
+        self
+    }
+
+    /// Sets the `transaction_ids` query parameter.
+    pub fn transaction_ids(mut self, transaction_ids: &'a str) -> Self {
+        self.inner = self.inner.transaction_ids(transaction_ids);
+        self
+    }
+
+    /// Sets the `user_filter` query parameter.
+    pub fn user_filter(mut self, user_filter: &'a str) -> Self {
+        self.inner = self.inner.user_filter(user_filter);
+        self
+    }
+
+    /// Sets the `user_id` query parameter.
+    pub fn user_id(mut self, user_id: &'a str) -> Self {
+        self.inner = self.inner.user_id(user_id);
+        self
+    }
+
+    /// Sets the `user_name` query parameter.
+    pub fn user_name(mut self, user_name: &'a str) -> Self {
+        self.inner = self.inner.user_name(user_name);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::EnvelopesInformation>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Envelopes::post`], set with a builder.
+pub struct EnvelopesPostRequest<'a> {
+    inner: crate::envelopes::EnvelopesPostRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> EnvelopesPostRequest<'a> {
+    /// Sets the `cdse_mode` query parameter.
+    pub fn cdse_mode(mut self, cdse_mode: &'a str) -> Self {
+        self.inner = self.inner.cdse_mode(cdse_mode);
+        self
+    }
+
+    /// Sets the `change_routing_order` query parameter.
+    pub fn change_routing_order(mut self, change_routing_order: &'a str) -> Self {
+        self.inner = self.inner.change_routing_order(change_routing_order);
+        self
+    }
+
+    /// Sets the `completed_documents_only` query parameter.
+    pub fn completed_documents_only(mut self, completed_documents_only: &'a str) -> Self {
+        self.inner = self
+            .inner
+            .completed_documents_only(completed_documents_only);
+        self
+    }
+
+    /// Sets the `merge_roles_on_draft` query parameter.
+    pub fn merge_roles_on_draft(mut self, merge_roles_on_draft: &'a str) -> Self {
+        self.inner = self.inner.merge_roles_on_draft(merge_roles_on_draft);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::EnvelopeSummary>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Envelopes::put_status`], set with a builder.
+pub struct EnvelopesPutStatusRequest<'a> {
+    inner: crate::envelopes::EnvelopesPutStatusRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> EnvelopesPutStatusRequest<'a> {
+    /// Sets the `ac_status` query parameter.
+    pub fn ac_status(mut self, ac_status: &'a str) -> Self {
+        self.inner = self.inner.ac_status(ac_status);
+        self
+    }
+
+    /// Sets the `block` query parameter.
+    pub fn block(mut self, block: &'a str) -> Self {
+        self.inner = self.inner.block(block);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: &'a str) -> Self {
+        self.inner = self.inner.count(count);
+        self
+    }
+
+    /// Sets the `email` query parameter.
+    pub fn email(mut self, email: &'a str) -> Self {
+        self.inner = self.inner.email(email);
+        self
+    }
+
+    /// Sets the `envelope_ids` query parameter.
+    pub fn envelope_ids(mut self, envelope_ids: &'a str) -> Self {
+        self.inner = self.inner.envelope_ids(envelope_ids);
+        self
+    }
+
+    /// Sets the `from_date` query parameter.
+    pub fn from_date(mut self, from_date: &'a str) -> Self {
+        self.inner = self.inner.from_date(from_date);
+        self
+    }
+
+    /// Sets the `from_to_status` query parameter.
+    pub fn from_to_status(mut self, from_to_status: &'a str) -> Self {
+        self.inner = self.inner.from_to_status(from_to_status);
+        self
+    }
+
+    /// Sets the `start_position` query parameter.
+    pub fn start_position(mut self, start_position: &'a str) -> Self {
+        self.inner = self.inner.start_position(start_position);
+        self
+    }
+
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: &'a str) -> Self {
+        self.inner = self.inner.status(status);
+        self
+    }
+
+    /// Sets the `to_date` query parameter.
+    pub fn to_date(mut self, to_date: &'a str) -> Self {
+        self.inner = self.inner.to_date(to_date);
+        self
+    }
+
+    /// Sets the `transaction_ids` query parameter.
+    pub fn transaction_ids(mut self, transaction_ids: &'a str) -> Self {
+        self.inner = self.inner.transaction_ids(transaction_ids);
+        self
+    }
+
+    /// Sets the `user_name` query parameter.
+    pub fn user_name(mut self, user_name: &'a str) -> Self {
+        self.inner = self.inner.user_name(user_name);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::EnvelopesInformation>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Envelopes::get_envelopes`], set with a builder.
+pub struct EnvelopesGetEnvelopesRequest<'a> {
+    inner: crate::envelopes::EnvelopesGetEnvelopesRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> EnvelopesGetEnvelopesRequest<'a> {
+    /// Sets the `advanced_update` query parameter.
+    pub fn advanced_update(mut self, advanced_update: &'a str) -> Self {
+        self.inner = self.inner.advanced_update(advanced_update);
+        self
+    }
+
+    /// Sets the `include` query parameter.
+    pub fn include(mut self, include: &'a str) -> Self {
+        self.inner = self.inner.include(include);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::Envelope>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Envelopes::put`], set with a builder.
+pub struct EnvelopesPutRequest<'a> {
+    inner: crate::envelopes::EnvelopesPutRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> EnvelopesPutRequest<'a> {
+    /// Sets the `advanced_update` query parameter.
+    pub fn advanced_update(mut self, advanced_update: &'a str) -> Self {
+        self.inner = self.inner.advanced_update(advanced_update);
+        self
+    }
+
+    /// Sets the `resend_envelope` query parameter.
+    pub fn resend_envelope(mut self, resend_envelope: &'a str) -> Self {
+        self.inner = self.inner.resend_envelope(resend_envelope);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::EnvelopeUpdateSummary>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Envelopes::pages_get_page_image`], set with a builder.
+pub struct EnvelopesPagesGetPageImageRequest<'a> {
+    inner: crate::envelopes::EnvelopesPagesGetPageImageRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> EnvelopesPagesGetPageImageRequest<'a> {
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: &'a str) -> Self {
+        self.inner = self.inner.count(count);
+        self
+    }
+
+    /// Sets the `dpi` query parameter.
+    pub fn dpi(mut self, dpi: &'a str) -> Self {
+        self.inner = self.inner.dpi(dpi);
+        self
+    }
+
+    /// Sets the `max_height` query parameter.
+    pub fn max_height(mut self, max_height: &'a str) -> Self {
+        self.inner = self.inner.max_height(max_height);
+        self
+    }
+
+    /// Sets the `max_width` query parameter.
+    pub fn max_width(mut self, max_width: &'a str) -> Self {
+        self.inner = self.inner.max_width(max_width);
+        self
+    }
+
+    /// Sets the `nocache` query parameter.
+    pub fn nocache(mut self, nocache: &'a str) -> Self {
+        self.inner = self.inner.nocache(nocache);
+        self
+    }
+
+    /// Sets the `show_changes` query parameter.
+    pub fn show_changes(mut self, show_changes: &'a str) -> Self {
+        self.inner = self.inner.show_changes(show_changes);
+        self
+    }
+
+    /// Sets the `start_position` query parameter.
+    pub fn start_position(mut self, start_position: &'a str) -> Self {
+        self.inner = self.inner.start_position(start_position);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::PageImages>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Envelopes::pages_get_page_image_envelopes`], set with a builder.
+pub struct EnvelopesPagesGetPageImageEnvelopesRequest<'a> {
+    inner: crate::envelopes::EnvelopesPagesGetPageImageEnvelopesRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> EnvelopesPagesGetPageImageEnvelopesRequest<'a> {
+    /// Sets the `dpi` query parameter.
+    pub fn dpi(mut self, dpi: &'a str) -> Self {
+        self.inner = self.inner.dpi(dpi);
+        self
+    }
+
+    /// Sets the `max_height` query parameter.
+    pub fn max_height(mut self, max_height: &'a str) -> Self {
+        self.inner = self.inner.max_height(max_height);
+        self
+    }
+
+    /// Sets the `max_width` query parameter.
+    pub fn max_width(mut self, max_width: &'a str) -> Self {
+        self.inner = self.inner.max_width(max_width);
+        self
+    }
+
+    /// Sets the `show_changes` query parameter.
+    pub fn show_changes(mut self, show_changes: &'a str) -> Self {
+        self.inner = self.inner.show_changes(show_changes);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Envelopes::recipients_get_recipient_initials_image`], set with a builder.
+pub struct EnvelopesRecipientsGetRecipientInitialsImageRequest<'a> {
+    inner: crate::envelopes::EnvelopesRecipientsGetRecipientInitialsImageRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> EnvelopesRecipientsGetRecipientInitialsImageRequest<'a> {
+    /// Sets the `include_chrome` query parameter.
+    pub fn include_chrome(mut self, include_chrome: &'a str) -> Self {
+        self.inner = self.inner.include_chrome(include_chrome);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Envelopes::recipients_get_recipient_signature_image`], set with a builder.
+pub struct EnvelopesRecipientsGetRecipientSignatureImageRequest<'a> {
+    inner: crate::envelopes::EnvelopesRecipientsGetRecipientSignatureImageRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> EnvelopesRecipientsGetRecipientSignatureImageRequest<'a> {
+    /// Sets the `include_chrome` query parameter.
+    pub fn include_chrome(mut self, include_chrome: &'a str) -> Self {
+        self.inner = self.inner.include_chrome(include_chrome);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::envelopes::Envelopes`].
+pub struct Envelopes {
+    inner: crate::envelopes::Envelopes,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Envelopes {
+    pub(crate) fn new(
+        inner: crate::envelopes::Envelopes,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Envelopes { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::envelopes::Envelopes::get`].
+    pub fn get(
+        &self,
+        account_id: &str,
+        ac_status: &str,
+        block: &str,
+        cdse_mode: &str,
+        continuation_token: &str,
+        count: &str,
+        custom_field: &str,
+        email: &str,
+        envelope_ids: &str,
+        exclude: &str,
+        folder_ids: &str,
+        folder_types: &str,
+        from_date: &str,
+        from_to_status: &str,
+        include: &str,
+        include_purge_information: &str,
+        intersecting_folder_ids: &str,
+        last_queried_date: &str,
+        order: &str,
+        order_by: &str,
+        powerformids: &str,
+        query_budget: &str,
+        requester_date_format: &str,
+        search_text: &str,
+        start_position: &str,
+        status: &str,
+        to_date: &str,
+        transaction_ids: &str,
+        user_filter: &str,
+        user_id: &str,
+        user_name: &str,
+    ) -> ClientResult<crate::Response<crate::types::EnvelopesInformation>> {
+        self.runtime.block_on(self.inner.get(
+            account_id,
+            ac_status,
+            block,
+            cdse_mode,
+            continuation_token,
+            count,
+            custom_field,
+            email,
+            envelope_ids,
+            exclude,
+            folder_ids,
+            folder_types,
+            from_date,
+            from_to_status,
+            include,
+            include_purge_information,
+            intersecting_folder_ids,
+            last_queried_date,
+            order,
+            order_by,
+            powerformids,
+            query_budget,
+            requester_date_format,
+            search_text,
+            start_position,
+            status,
+            to_date,
+            transaction_ids,
+            user_filter,
+            user_id,
+            user_name,
+        ))
+    }
+    /// The blocking version of [`crate::envelopes::Envelopes::get_request`].
+    pub fn get_request<'a>(&'a self, account_id: &'a str) -> EnvelopesGetRequest<'a> {
+        EnvelopesGetRequest {
+            inner: self.inner.get_request(account_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::envelopes::Envelopes::post`].
+    pub fn post(
+        &self,
+        account_id: &str,
+        cdse_mode: &str,
+        change_routing_order: &str,
+        completed_documents_only: &str,
+        merge_roles_on_draft: &str,
+        body: &crate::types::EnvelopeDefinition,
+    ) -> ClientResult<crate::Response<crate::types::EnvelopeSummary>> {
+        self.runtime.block_on(self.inner.post(
+            account_id,
+            cdse_mode,
+            change_routing_order,
+            completed_documents_only,
+            merge_roles_on_draft,
+            body,
+        ))
+    }
+    /// The blocking version of [`crate::envelopes::Envelopes::post_request`].
+    pub fn post_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        body: &'a crate::types::EnvelopeDefinition,
+    ) -> EnvelopesPostRequest<'a> {
+        EnvelopesPostRequest {
+            inner: self.inner.post_request(account_id, body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::envelopes::Envelopes::put_status`].
+    pub fn put_status(
+        &self,
+        account_id: &str,
+        ac_status: &str,
+        block: &str,
+        count: &str,
+        email: &str,
+        envelope_ids: &str,
+        from_date: &str,
+        from_to_status: &str,
+        start_position: &str,
+        status: &str,
+        to_date: &str,
+        transaction_ids: &str,
+        user_name: &str,
+        body: &crate::types::EnvelopeIdsRequest,
+    ) -> ClientResult<crate::Response<crate::types::EnvelopesInformation>> {
+        self.runtime.block_on(self.inner.put_status(
+            account_id,
+            ac_status,
+            block,
+            count,
+            email,
+            envelope_ids,
+            from_date,
+            from_to_status,
+            start_position,
+            status,
+            to_date,
+            transaction_ids,
+            user_name,
+            body,
+        ))
+    }
+    /// The blocking version of [`crate::envelopes::Envelopes::put_status_request`].
+    pub fn put_status_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        body: &'a crate::types::EnvelopeIdsRequest,
+    ) -> EnvelopesPutStatusRequest<'a> {
+        EnvelopesPutStatusRequest {
+            inner: self.inner.put_status_request(account_id, body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::envelopes::Envelopes::get_envelopes`].
+    pub fn get_envelopes(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        advanced_update: &str,
+        include: &str,
+    ) -> ClientResult<crate::Response<crate::types::Envelope>> {
+        self.runtime.block_on(self.inner.get_envelopes(
+            account_id,
+            envelope_id,
+            advanced_update,
+            include,
+        ))
+    }
+    /// The blocking version of [`crate::envelopes::Envelopes::get_envelopes_request`].
+    pub fn get_envelopes_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        envelope_id: &'a str,
+    ) -> EnvelopesGetEnvelopesRequest<'a> {
+        EnvelopesGetEnvelopesRequest {
+            inner: self.inner.get_envelopes_request(account_id, envelope_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::envelopes::Envelopes::put`].
+    pub fn put(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        advanced_update: &str,
+        resend_envelope: &str,
+        body: &crate::types::Envelope,
+    ) -> ClientResult<crate::Response<crate::types::EnvelopeUpdateSummary>> {
+        self.runtime.block_on(self.inner.put(
+            account_id,
+            envelope_id,
+            advanced_update,
+            resend_envelope,
+            body,
+        ))
+    }
+    /// The blocking version of [`crate::envelopes::Envelopes::put_request`].
+    pub fn put_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        envelope_id: &'a str,
+        body: &'a crate::types::Envelope,
+    ) -> EnvelopesPutRequest<'a> {
+        EnvelopesPutRequest {
+            inner: self.inner.put_request(account_id, envelope_id, body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::envelopes::Envelopes::audit_events_get`].
+    pub fn audit_events_get(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::EnvelopeAuditEventResponse>> {
+        self.runtime
+            .block_on(self.inner.audit_events_get(account_id, envelope_id))
+    }
+    /// The blocking version of [`crate::envelopes::Envelopes::pages_get_page_image`].
+    pub fn pages_get_page_image(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        count: &str,
+        dpi: &str,
+        max_height: &str,
+        max_width: &str,
+        nocache: &str,
+        show_changes: &str,
+        start_position: &str,
+    ) -> ClientResult<crate::Response<crate::types::PageImages>> {
+        self.runtime.block_on(self.inner.pages_get_page_image(
+            account_id,
+            document_id,
+            envelope_id,
+            count,
+            dpi,
+            max_height,
+            max_width,
+            nocache,
+            show_changes,
+            start_position,
+        ))
+    }
+    /// The blocking version of [`crate::envelopes::Envelopes::pages_get_page_image_request`].
+    pub fn pages_get_page_image_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        document_id: &'a str,
+        envelope_id: &'a str,
+    ) -> EnvelopesPagesGetPageImageRequest<'a> {
+        EnvelopesPagesGetPageImageRequest {
+            inner: self
+                .inner
+                .pages_get_page_image_request(account_id, document_id, envelope_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::envelopes::Envelopes::pages_delete_page`].
+    pub fn pages_delete_page(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        page_number: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.pages_delete_page(
+            account_id,
+            document_id,
+            envelope_id,
+            page_number,
+        ))
+    }
+    /// The blocking version of [`crate::envelopes::Envelopes::pages_get_page_image_envelopes`].
+    pub fn pages_get_page_image_envelopes(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        page_number: &str,
+        dpi: &str,
+        max_height: &str,
+        max_width: &str,
+        show_changes: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.pages_get_page_image_envelopes(
+                account_id,
+                document_id,
+                envelope_id,
+                page_number,
+                dpi,
+                max_height,
+                max_width,
+                show_changes,
+            ))
+    }
+    /// The blocking version of [`crate::envelopes::Envelopes::pages_get_page_image_envelopes_request`].
+    pub fn pages_get_page_image_envelopes_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        document_id: &'a str,
+        envelope_id: &'a str,
+        page_number: &'a str,
+    ) -> EnvelopesPagesGetPageImageEnvelopesRequest<'a> {
+        EnvelopesPagesGetPageImageEnvelopesRequest {
+            inner: self.inner.pages_get_page_image_envelopes_request(
+                account_id,
+                document_id,
+                envelope_id,
+                page_number,
+            ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::envelopes::Envelopes::pages_put_page_image`].
+    pub fn pages_put_page_image(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        page_number: &str,
+        body: &crate::types::PageRequest,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.pages_put_page_image(
+            account_id,
+            document_id,
+            envelope_id,
+            page_number,
+            body,
+        ))
+    }
+    /// The blocking version of [`crate::envelopes::Envelopes::notification_get`].
+    pub fn notification_get(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Notification>> {
+        self.runtime
+            .block_on(self.inner.notification_get(account_id, envelope_id))
+    }
+    /// The blocking version of [`crate::envelopes::Envelopes::notification_put`].
+    pub fn notification_put(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::EnvelopeNotificationRequest,
+    ) -> ClientResult<crate::Response<crate::types::Notification>> {
+        self.runtime
+            .block_on(self.inner.notification_put(account_id, envelope_id, body))
+    }
+    /// The blocking version of [`crate::envelopes::Envelopes::recipients_get_recipient_initials_image`].
+    pub fn recipients_get_recipient_initials_image(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+        include_chrome: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.recipients_get_recipient_initials_image(
+                account_id,
+                envelope_id,
+                recipient_id,
+                include_chrome,
+            ))
+    }
+    /// The blocking version of [`crate::envelopes::Envelopes::recipients_get_recipient_initials_image_request`].
+    pub fn recipients_get_recipient_initials_image_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        envelope_id: &'a str,
+        recipient_id: &'a str,
+    ) -> EnvelopesRecipientsGetRecipientInitialsImageRequest<'a> {
+        EnvelopesRecipientsGetRecipientInitialsImageRequest {
+            inner: self.inner.recipients_get_recipient_initials_image_request(
+                account_id,
+                envelope_id,
+                recipient_id,
+            ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::envelopes::Envelopes::recipients_put_recipient_initials_image`].
+    pub fn recipients_put_recipient_initials_image(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.recipients_put_recipient_initials_image(
+                account_id,
+                envelope_id,
+                recipient_id,
+            ))
+    }
+    /// The blocking version of [`crate::envelopes::Envelopes::recipients_get_recipient_signature`].
+    pub fn recipients_get_recipient_signature(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::UserSignature>> {
+        self.runtime
+            .block_on(self.inner.recipients_get_recipient_signature(
+                account_id,
+                envelope_id,
+                recipient_id,
+            ))
+    }
+    /// The blocking version of [`crate::envelopes::Envelopes::recipients_get_recipient_signature_image`].
+    pub fn recipients_get_recipient_signature_image(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+        include_chrome: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.recipients_get_recipient_signature_image(
+                account_id,
+                envelope_id,
+                recipient_id,
+                include_chrome,
+            ))
+    }
+    /// The blocking version of [`crate::envelopes::Envelopes::recipients_get_recipient_signature_image_request`].
+    pub fn recipients_get_recipient_signature_image_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        envelope_id: &'a str,
+        recipient_id: &'a str,
+    ) -> EnvelopesRecipientsGetRecipientSignatureImageRequest<'a> {
+        EnvelopesRecipientsGetRecipientSignatureImageRequest {
+            inner: self.inner.recipients_get_recipient_signature_image_request(
+                account_id,
+                envelope_id,
+                recipient_id,
+            ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::envelopes::Envelopes::recipients_put_recipient_signature_image`].
+    pub fn recipients_put_recipient_signature_image(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.recipients_put_recipient_signature_image(
+                account_id,
+                envelope_id,
+                recipient_id,
+            ))
+    }
+}
@@ -0,0 +1,44 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::favorite_templates::FavoriteTemplates`].
+pub struct FavoriteTemplates {
+    inner: crate::favorite_templates::FavoriteTemplates,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl FavoriteTemplates {
+    pub(crate) fn new(
+        inner: crate::favorite_templates::FavoriteTemplates,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        FavoriteTemplates { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::favorite_templates::FavoriteTemplates::get`].
+    pub fn get(
+        &self,
+        account_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::FavoriteTemplatesInfo>> {
+        self.runtime.block_on(self.inner.get(account_id))
+    }
+    /// The blocking version of [`crate::favorite_templates::FavoriteTemplates::put_template`].
+    pub fn put_template(
+        &self,
+        account_id: &str,
+        body: &crate::types::FavoriteTemplatesInfo,
+    ) -> ClientResult<crate::Response<crate::types::FavoriteTemplatesInfo>> {
+        self.runtime
+            .block_on(self.inner.put_template(account_id, body))
+    }
+    /// The blocking version of [`crate::favorite_templates::FavoriteTemplates::un_template`].
+    pub fn un_template(
+        &self,
+        account_id: &str,
+        body: &crate::types::FavoriteTemplatesInfo,
+    ) -> ClientResult<crate::Response<crate::types::FavoriteTemplatesInfo>> {
+        self.runtime
+            .block_on(self.inner.un_template(account_id, body))
+    }
+}
@@ -0,0 +1,298 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Folders::get`], set with a builder.
+pub struct FoldersGetRequest<'a> {
+    inner: crate::folders::FoldersGetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> FoldersGetRequest<'a> {
+    /// Sets the `include` query parameter.
+    pub fn include(mut self, include: &'a str) -> Self {
+        self.inner = self.inner.include(include);
+        self
+    }
+
+    /// Sets the `include_items` query parameter.
+    pub fn include_items(mut self, include_items: &'a str) -> Self {
+        self.inner = self.inner.include_items(include_items);
+        self
+    }
+
+    /// Sets the `start_position` query parameter.
+    pub fn start_position(mut self, start_position: &'a str) -> Self {
+        self.inner = self.inner.start_position(start_position);
+        self
+    }
+
+    /// Sets the `template` query parameter.
+    pub fn template(mut self, template: &'a str) -> Self {
+        self.inner = self.inner.template(template);
+        self
+    }
+
+    /// Sets the `user_filter` query parameter.
+    pub fn user_filter(mut self, user_filter: &'a str) -> Self {
+        self.inner = self.inner.user_filter(user_filter);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::FoldersResponse>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Folders::get_items`], set with a builder.
+pub struct FoldersGetItemsRequest<'a> {
+    inner: crate::folders::FoldersGetItemsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> FoldersGetItemsRequest<'a> {
+    /// Sets the `from_date` query parameter.
+    pub fn from_date(mut self, from_date: &'a str) -> Self {
+        self.inner = self.inner.from_date(from_date);
+        self
+    }
+
+    /// Sets the `include_items` query parameter.
+    pub fn include_items(mut self, include_items: &'a str) -> Self {
+        self.inner = self.inner.include_items(include_items);
+        self
+    }
+
+    /// Sets the `owner_email` query parameter.
+    pub fn owner_email(mut self, owner_email: &'a str) -> Self {
+        self.inner = self.inner.owner_email(owner_email);
+        self
+    }
+
+    /// Sets the `owner_name` query parameter.
+    pub fn owner_name(mut self, owner_name: &'a str) -> Self {
+        self.inner = self.inner.owner_name(owner_name);
+        self
+    }
+
+    /// Sets the `search_text` query parameter.
+    pub fn search_text(mut self, search_text: &'a str) -> Self {
+        self.inner = self.inner.search_text(search_text);
+        self
+    }
+
+    /// Sets the `start_position` query parameter.
+    pub fn start_position(mut self, start_position: &'a str) -> Self {
+        self.inner = self.inner.start_position(start_position);
+        self
+    }
+
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: &'a str) -> Self {
+        self.inner = self.inner.status(status);
+        self
+    }
+
+    /// Sets the `to_date` query parameter.
+    pub fn to_date(mut self, to_date: &'a str) -> Self {
+        self.inner = self.inner.to_date(to_date);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::FolderItemsResponse>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Folders::search_get_contents`], set with a builder.
+pub struct FoldersSearchGetContentsRequest<'a> {
+    inner: crate::folders::FoldersSearchGetContentsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> FoldersSearchGetContentsRequest<'a> {
+    /// Sets the `all` query parameter.
+    pub fn all(mut self, all: &'a str) -> Self {
+        self.inner = self.inner.all(all);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: &'a str) -> Self {
+        self.inner = self.inner.count(count);
+        self
+    }
+
+    /// Sets the `from_date` query parameter.
+    pub fn from_date(mut self, from_date: &'a str) -> Self {
+        self.inner = self.inner.from_date(from_date);
+        self
+    }
+
+    /// Sets the `include_recipients` query parameter.
+    pub fn include_recipients(mut self, include_recipients: &'a str) -> Self {
+        self.inner = self.inner.include_recipients(include_recipients);
+        self
+    }
+
+    /// Sets the `order` query parameter.
+    pub fn order(mut self, order: &'a str) -> Self {
+        self.inner = self.inner.order(order);
+        self
+    }
+
+    /// Sets the `order_by` query parameter.
+    pub fn order_by(mut self, order_by: &'a str) -> Self {
+        self.inner = self.inner.order_by(order_by);
+        self
+    }
+
+    /// Sets the `start_position` query parameter.
+    pub fn start_position(mut self, start_position: &'a str) -> Self {
+        self.inner = self.inner.start_position(start_position);
+        self
+    }
+
+    /// Sets the `to_date` query parameter.
+    pub fn to_date(mut self, to_date: &'a str) -> Self {
+        self.inner = self.inner.to_date(to_date);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::FolderItemResponse>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::folders::Folders`].
+pub struct Folders {
+    inner: crate::folders::Folders,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Folders {
+    pub(crate) fn new(
+        inner: crate::folders::Folders,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Folders { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::folders::Folders::get`].
+    pub fn get(
+        &self,
+        account_id: &str,
+        include: &str,
+        include_items: &str,
+        start_position: &str,
+        template: &str,
+        user_filter: &str,
+    ) -> ClientResult<crate::Response<crate::types::FoldersResponse>> {
+        self.runtime.block_on(self.inner.get(
+            account_id,
+            include,
+            include_items,
+            start_position,
+            template,
+            user_filter,
+        ))
+    }
+    /// The blocking version of [`crate::folders::Folders::get_request`].
+    pub fn get_request<'a>(&'a self, account_id: &'a str) -> FoldersGetRequest<'a> {
+        FoldersGetRequest {
+            inner: self.inner.get_request(account_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::folders::Folders::get_items`].
+    pub fn get_items(
+        &self,
+        account_id: &str,
+        folder_id: &str,
+        from_date: &str,
+        include_items: &str,
+        owner_email: &str,
+        owner_name: &str,
+        search_text: &str,
+        start_position: &str,
+        status: &str,
+        to_date: &str,
+    ) -> ClientResult<crate::Response<crate::types::FolderItemsResponse>> {
+        self.runtime.block_on(self.inner.get_items(
+            account_id,
+            folder_id,
+            from_date,
+            include_items,
+            owner_email,
+            owner_name,
+            search_text,
+            start_position,
+            status,
+            to_date,
+        ))
+    }
+    /// The blocking version of [`crate::folders::Folders::get_items_request`].
+    pub fn get_items_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        folder_id: &'a str,
+    ) -> FoldersGetItemsRequest<'a> {
+        FoldersGetItemsRequest {
+            inner: self.inner.get_items_request(account_id, folder_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::folders::Folders::put`].
+    pub fn put(
+        &self,
+        account_id: &str,
+        folder_id: &str,
+        body: &crate::types::FoldersRequest,
+    ) -> ClientResult<crate::Response<crate::types::FoldersResponse>> {
+        self.runtime
+            .block_on(self.inner.put(account_id, folder_id, body))
+    }
+    /// The blocking version of [`crate::folders::Folders::search_get_contents`].
+    pub fn search_get_contents(
+        &self,
+        account_id: &str,
+        search_folder_id: &str,
+        all: &str,
+        count: &str,
+        from_date: &str,
+        include_recipients: &str,
+        order: &str,
+        order_by: &str,
+        start_position: &str,
+        to_date: &str,
+    ) -> ClientResult<crate::Response<crate::types::FolderItemResponse>> {
+        self.runtime.block_on(self.inner.search_get_contents(
+            account_id,
+            search_folder_id,
+            all,
+            count,
+            from_date,
+            include_recipients,
+            order,
+            order_by,
+            start_position,
+            to_date,
+        ))
+    }
+    /// The blocking version of [`crate::folders::Folders::search_get_contents_request`].
+    pub fn search_get_contents_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        search_folder_id: &'a str,
+    ) -> FoldersSearchGetContentsRequest<'a> {
+        FoldersSearchGetContentsRequest {
+            inner: self
+                .inner
+                .search_get_contents_request(account_id, search_folder_id),
+            runtime: &self.runtime,
+        }
+    }
+}
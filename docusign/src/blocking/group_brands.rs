@@ -0,0 +1,48 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::group_brands::GroupBrands`].
+pub struct GroupBrands {
+    inner: crate::group_brands::GroupBrands,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl GroupBrands {
+    pub(crate) fn new(
+        inner: crate::group_brands::GroupBrands,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        GroupBrands { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::group_brands::GroupBrands::brands_get_group`].
+    pub fn brands_get_group(
+        &self,
+        account_id: &str,
+        group_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::GroupBrands>> {
+        self.runtime
+            .block_on(self.inner.brands_get_group(account_id, group_id))
+    }
+    /// The blocking version of [`crate::group_brands::GroupBrands::brands_put_group`].
+    pub fn brands_put_group(
+        &self,
+        account_id: &str,
+        group_id: &str,
+        body: &crate::types::BrandsRequest,
+    ) -> ClientResult<crate::Response<crate::types::GroupBrands>> {
+        self.runtime
+            .block_on(self.inner.brands_put_group(account_id, group_id, body))
+    }
+    /// The blocking version of [`crate::group_brands::GroupBrands::brands_delete_group`].
+    pub fn brands_delete_group(
+        &self,
+        account_id: &str,
+        group_id: &str,
+        body: &crate::types::BrandsRequest,
+    ) -> ClientResult<crate::Response<crate::types::GroupBrands>> {
+        self.runtime
+            .block_on(self.inner.brands_delete_group(account_id, group_id, body))
+    }
+}
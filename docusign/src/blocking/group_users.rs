@@ -0,0 +1,88 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`GroupUsers::groups_get`], set with a builder.
+pub struct GroupUsersGroupsGetRequest<'a> {
+    inner: crate::group_users::GroupUsersGroupsGetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> GroupUsersGroupsGetRequest<'a> {
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: &'a str) -> Self {
+        self.inner = self.inner.count(count);
+        self
+    }
+
+    /// Sets the `start_position` query parameter.
+    pub fn start_position(mut self, start_position: &'a str) -> Self {
+        self.inner = self.inner.start_position(start_position);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::UsersResponse>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::group_users::GroupUsers`].
+pub struct GroupUsers {
+    inner: crate::group_users::GroupUsers,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl GroupUsers {
+    pub(crate) fn new(
+        inner: crate::group_users::GroupUsers,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        GroupUsers { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::group_users::GroupUsers::groups_get`].
+    pub fn groups_get(
+        &self,
+        account_id: &str,
+        group_id: &str,
+        count: &str,
+        start_position: &str,
+    ) -> ClientResult<crate::Response<crate::types::UsersResponse>> {
+        self.runtime.block_on(
+            self.inner
+                .groups_get(account_id, group_id, count, start_position),
+        )
+    }
+    /// The blocking version of [`crate::group_users::GroupUsers::groups_get_request`].
+    pub fn groups_get_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        group_id: &'a str,
+    ) -> GroupUsersGroupsGetRequest<'a> {
+        GroupUsersGroupsGetRequest {
+            inner: self.inner.groups_get_request(account_id, group_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::group_users::GroupUsers::groups_put`].
+    pub fn groups_put(
+        &self,
+        account_id: &str,
+        group_id: &str,
+        body: &crate::types::UserInfoList,
+    ) -> ClientResult<crate::Response<crate::types::UsersResponse>> {
+        self.runtime
+            .block_on(self.inner.groups_put(account_id, group_id, body))
+    }
+    /// The blocking version of [`crate::group_users::GroupUsers::groups_delete`].
+    pub fn groups_delete(
+        &self,
+        account_id: &str,
+        group_id: &str,
+        body: &crate::types::UserInfoList,
+    ) -> ClientResult<crate::Response<crate::types::UsersResponse>> {
+        self.runtime
+            .block_on(self.inner.groups_delete(account_id, group_id, body))
+    }
+}
@@ -0,0 +1,109 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Groups::get`], set with a builder.
+pub struct GroupsGetRequest<'a> {
+    inner: crate::groups::GroupsGetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> GroupsGetRequest<'a> {
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: &'a str) -> Self {
+        self.inner = self.inner.count(count);
+        self
+    }
+
+    /// Sets the `group_type` query parameter.
+    pub fn group_type(mut self, group_type: &'a str) -> Self {
+        self.inner = self.inner.group_type(group_type);
+        self
+    }
+
+    /// Sets the `include_usercount` query parameter.
+    pub fn include_usercount(mut self, include_usercount: &'a str) -> Self {
+        self.inner = self.inner.include_usercount(include_usercount);
+        self
+    }
+
+    /// Sets the `search_text` query parameter.
+    pub fn search_text(mut self, search_text: &'a str) -> Self {
+        self.inner = self.inner.search_text(search_text);
+        self
+    }
+
+    /// Sets the `start_position` query parameter.
+    pub fn start_position(mut self, start_position: &'a str) -> Self {
+        self.inner = self.inner.start_position(start_position);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::GroupInformation>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::groups::Groups`].
+pub struct Groups {
+    inner: crate::groups::Groups,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Groups {
+    pub(crate) fn new(inner: crate::groups::Groups, runtime: Arc<tokio::runtime::Runtime>) -> Self {
+        Groups { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::groups::Groups::get`].
+    pub fn get(
+        &self,
+        account_id: &str,
+        count: &str,
+        group_type: &str,
+        include_usercount: &str,
+        search_text: &str,
+        start_position: &str,
+    ) -> ClientResult<crate::Response<crate::types::GroupInformation>> {
+        self.runtime.block_on(self.inner.get(
+            account_id,
+            count,
+            group_type,
+            include_usercount,
+            search_text,
+            start_position,
+        ))
+    }
+    /// The blocking version of [`crate::groups::Groups::get_request`].
+    pub fn get_request<'a>(&'a self, account_id: &'a str) -> GroupsGetRequest<'a> {
+        GroupsGetRequest {
+            inner: self.inner.get_request(account_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::groups::Groups::put`].
+    pub fn put(
+        &self,
+        account_id: &str,
+        body: &crate::types::GroupInformation,
+    ) -> ClientResult<crate::Response<crate::types::GroupInformation>> {
+        self.runtime.block_on(self.inner.put(account_id, body))
+    }
+    /// The blocking version of [`crate::groups::Groups::post`].
+    pub fn post(
+        &self,
+        account_id: &str,
+        body: &crate::types::GroupInformation,
+    ) -> ClientResult<crate::Response<crate::types::GroupInformation>> {
+        self.runtime.block_on(self.inner.post(account_id, body))
+    }
+    /// The blocking version of [`crate::groups::Groups::delete`].
+    pub fn delete(
+        &self,
+        account_id: &str,
+        body: &crate::types::GroupInformation,
+    ) -> ClientResult<crate::Response<crate::types::GroupInformation>> {
+        self.runtime.block_on(self.inner.delete(account_id, body))
+    }
+}
@@ -0,0 +1,26 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::identity_verifications::IdentityVerifications`].
+pub struct IdentityVerifications {
+    inner: crate::identity_verifications::IdentityVerifications,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl IdentityVerifications {
+    pub(crate) fn new(
+        inner: crate::identity_verifications::IdentityVerifications,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        IdentityVerifications { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::identity_verifications::IdentityVerifications::account_get`].
+    pub fn account_get(
+        &self,
+        account_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::AccountIdentityVerificationResponse>> {
+        self.runtime.block_on(self.inner.account_get(account_id))
+    }
+}
@@ -0,0 +1,78 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Invoices::billing_get`], set with a builder.
+pub struct InvoicesBillingGetRequest<'a> {
+    inner: crate::invoices::InvoicesBillingGetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> InvoicesBillingGetRequest<'a> {
+    /// Sets the `from_date` query parameter.
+    pub fn from_date(mut self, from_date: &'a str) -> Self {
+        self.inner = self.inner.from_date(from_date);
+        self
+    }
+
+    /// Sets the `to_date` query parameter.
+    pub fn to_date(mut self, to_date: &'a str) -> Self {
+        self.inner = self.inner.to_date(to_date);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::BillingInvoicesResponse>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::invoices::Invoices`].
+pub struct Invoices {
+    inner: crate::invoices::Invoices,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Invoices {
+    pub(crate) fn new(
+        inner: crate::invoices::Invoices,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Invoices { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::invoices::Invoices::billing_get`].
+    pub fn billing_get(
+        &self,
+        account_id: &str,
+        from_date: &str,
+        to_date: &str,
+    ) -> ClientResult<crate::Response<crate::types::BillingInvoicesResponse>> {
+        self.runtime
+            .block_on(self.inner.billing_get(account_id, from_date, to_date))
+    }
+    /// The blocking version of [`crate::invoices::Invoices::billing_get_request`].
+    pub fn billing_get_request<'a>(&'a self, account_id: &'a str) -> InvoicesBillingGetRequest<'a> {
+        InvoicesBillingGetRequest {
+            inner: self.inner.billing_get_request(account_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::invoices::Invoices::billing_get_invoices`].
+    pub fn billing_get_invoices(
+        &self,
+        account_id: &str,
+        invoice_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::BillingInvoice>> {
+        self.runtime
+            .block_on(self.inner.billing_get_invoices(account_id, invoice_id))
+    }
+    /// The blocking version of [`crate::invoices::Invoices::billing_get_past_due`].
+    pub fn billing_get_past_due(
+        &self,
+        account_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::BillingInvoicesSummary>> {
+        self.runtime
+            .block_on(self.inner.billing_get_past_due(account_id))
+    }
+}
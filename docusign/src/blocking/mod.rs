@@ -0,0 +1,739 @@
+//! A blocking client, for the programs that are not async.
+//!
+//! It has the functions of the async [`Client`](crate::Client), which it runs on a
+//! runtime of its own, so they must not be called from within an async runtime. The
+//! `*_stream` functions are left out, the `*_all` ones returning all the pages at once.
+
+use std::{future::Future, sync::Arc};
+
+pub mod account_brands;
+pub mod account_consumer_disclosures;
+pub mod account_custom_fields;
+pub mod account_password_rules;
+pub mod account_permission_profiles;
+pub mod account_seal_providers;
+pub mod account_signature_providers;
+pub mod account_signatures;
+pub mod account_tab_settings;
+pub mod account_watermarks;
+pub mod accounts;
+pub mod appliance_info;
+pub mod bcc_email_archive;
+pub mod billing_plans;
+pub mod bulk_send;
+pub mod chunked_uploads;
+pub mod cloud_storage;
+pub mod cloud_storage_providers;
+pub mod comments;
+pub mod connect_configurations;
+pub mod connect_events;
+pub mod connect_secret;
+pub mod contacts;
+pub mod custom_tabs;
+pub mod document_responsive_html_preview;
+pub mod e_note_configurations;
+pub mod envelope_attachments;
+pub mod envelope_consumer_disclosures;
+pub mod envelope_custom_fields;
+pub mod envelope_document_fields;
+pub mod envelope_document_html_definitions;
+pub mod envelope_document_tabs;
+pub mod envelope_document_visibility;
+pub mod envelope_documents;
+pub mod envelope_email_settings;
+pub mod envelope_form_data;
+pub mod envelope_html_definitions;
+pub mod envelope_locks;
+pub mod envelope_recipient_tabs;
+pub mod envelope_recipients;
+pub mod envelope_templates;
+pub mod envelope_transfer_rules;
+pub mod envelope_views;
+pub mod envelope_workflow_definition;
+pub mod envelopes;
+pub mod favorite_templates;
+pub mod folders;
+pub mod group_brands;
+pub mod group_users;
+pub mod groups;
+pub mod identity_verifications;
+pub mod invoices;
+pub mod notary;
+pub mod notary_journals;
+pub mod notary_jurisdiction;
+pub mod payment_gateway_accounts;
+pub mod payments;
+pub mod power_form_data;
+pub mod power_forms;
+pub mod reports;
+pub mod request_logs;
+pub mod resources;
+pub mod responsive_html_preview;
+pub mod services;
+pub mod signing_group_users;
+pub mod signing_groups;
+pub mod tabs_blob;
+pub mod template_bulk_recipients;
+pub mod template_custom_fields;
+pub mod template_document_fields;
+pub mod template_document_html_definitions;
+pub mod template_document_responsive_html_preview;
+pub mod template_document_tabs;
+pub mod template_document_visibility;
+pub mod template_documents;
+pub mod template_html_definitions;
+pub mod template_locks;
+pub mod template_recipient_tabs;
+pub mod template_recipients;
+pub mod template_responsive_html_preview;
+pub mod template_views;
+pub mod templates;
+pub mod user_custom_settings;
+pub mod user_profiles;
+pub mod user_signatures;
+pub mod users;
+pub mod workspace_items;
+pub mod workspaces;
+
+/// A blocking client, sending its requests with an async [`Client`](crate::Client).
+#[derive(Clone)]
+pub struct Client {
+    inner: crate::Client,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Client {
+    /// Wraps an async client, creating the runtime its requests run on.
+    pub fn new(client: crate::Client) -> std::io::Result<Self> {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()?;
+
+        Ok(Client {
+            inner: client,
+            runtime: Arc::new(runtime),
+        })
+    }
+
+    /// Returns the async client the requests are sent with.
+    pub fn inner(&self) -> &crate::Client {
+        &self.inner
+    }
+
+    /// Runs a future to completion on the runtime of the client, for the async
+    /// functions that have no blocking version.
+    pub fn block_on<F: Future>(&self, future: F) -> F::Output {
+        self.runtime.block_on(future)
+    }
+
+    /// The blocking version of [`crate::Client::account_brands`].
+    pub fn account_brands(&self) -> account_brands::AccountBrands {
+        account_brands::AccountBrands::new(self.inner.account_brands(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::account_consumer_disclosures`].
+    pub fn account_consumer_disclosures(
+        &self,
+    ) -> account_consumer_disclosures::AccountConsumerDisclosures {
+        account_consumer_disclosures::AccountConsumerDisclosures::new(
+            self.inner.account_consumer_disclosures(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::account_custom_fields`].
+    pub fn account_custom_fields(&self) -> account_custom_fields::AccountCustomFields {
+        account_custom_fields::AccountCustomFields::new(
+            self.inner.account_custom_fields(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::account_password_rules`].
+    pub fn account_password_rules(&self) -> account_password_rules::AccountPasswordRules {
+        account_password_rules::AccountPasswordRules::new(
+            self.inner.account_password_rules(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::account_permission_profiles`].
+    pub fn account_permission_profiles(
+        &self,
+    ) -> account_permission_profiles::AccountPermissionProfiles {
+        account_permission_profiles::AccountPermissionProfiles::new(
+            self.inner.account_permission_profiles(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::account_seal_providers`].
+    pub fn account_seal_providers(&self) -> account_seal_providers::AccountSealProviders {
+        account_seal_providers::AccountSealProviders::new(
+            self.inner.account_seal_providers(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::account_signature_providers`].
+    pub fn account_signature_providers(
+        &self,
+    ) -> account_signature_providers::AccountSignatureProviders {
+        account_signature_providers::AccountSignatureProviders::new(
+            self.inner.account_signature_providers(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::account_signatures`].
+    pub fn account_signatures(&self) -> account_signatures::AccountSignatures {
+        account_signatures::AccountSignatures::new(
+            self.inner.account_signatures(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::account_tab_settings`].
+    pub fn account_tab_settings(&self) -> account_tab_settings::AccountTabSettings {
+        account_tab_settings::AccountTabSettings::new(
+            self.inner.account_tab_settings(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::account_watermarks`].
+    pub fn account_watermarks(&self) -> account_watermarks::AccountWatermarks {
+        account_watermarks::AccountWatermarks::new(
+            self.inner.account_watermarks(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::accounts`].
+    pub fn accounts(&self) -> accounts::Accounts {
+        accounts::Accounts::new(self.inner.accounts(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::appliance_info`].
+    pub fn appliance_info(&self) -> appliance_info::ApplianceInfo {
+        appliance_info::ApplianceInfo::new(self.inner.appliance_info(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::bcc_email_archive`].
+    pub fn bcc_email_archive(&self) -> bcc_email_archive::BccEmailArchive {
+        bcc_email_archive::BccEmailArchive::new(
+            self.inner.bcc_email_archive(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::billing_plans`].
+    pub fn billing_plans(&self) -> billing_plans::BillingPlans {
+        billing_plans::BillingPlans::new(self.inner.billing_plans(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::bulk_send`].
+    pub fn bulk_send(&self) -> bulk_send::BulkSend {
+        bulk_send::BulkSend::new(self.inner.bulk_send(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::chunked_uploads`].
+    pub fn chunked_uploads(&self) -> chunked_uploads::ChunkedUploads {
+        chunked_uploads::ChunkedUploads::new(self.inner.chunked_uploads(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::cloud_storage`].
+    pub fn cloud_storage(&self) -> cloud_storage::CloudStorage {
+        cloud_storage::CloudStorage::new(self.inner.cloud_storage(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::cloud_storage_providers`].
+    pub fn cloud_storage_providers(&self) -> cloud_storage_providers::CloudStorageProviders {
+        cloud_storage_providers::CloudStorageProviders::new(
+            self.inner.cloud_storage_providers(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::comments`].
+    pub fn comments(&self) -> comments::Comments {
+        comments::Comments::new(self.inner.comments(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::connect_configurations`].
+    pub fn connect_configurations(&self) -> connect_configurations::ConnectConfigurations {
+        connect_configurations::ConnectConfigurations::new(
+            self.inner.connect_configurations(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::connect_events`].
+    pub fn connect_events(&self) -> connect_events::ConnectEvents {
+        connect_events::ConnectEvents::new(self.inner.connect_events(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::connect_secret`].
+    pub fn connect_secret(&self) -> connect_secret::ConnectSecret {
+        connect_secret::ConnectSecret::new(self.inner.connect_secret(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::contacts`].
+    pub fn contacts(&self) -> contacts::Contacts {
+        contacts::Contacts::new(self.inner.contacts(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::custom_tabs`].
+    pub fn custom_tabs(&self) -> custom_tabs::CustomTabs {
+        custom_tabs::CustomTabs::new(self.inner.custom_tabs(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::document_responsive_html_preview`].
+    pub fn document_responsive_html_preview(
+        &self,
+    ) -> document_responsive_html_preview::DocumentResponsiveHtmlPreview {
+        document_responsive_html_preview::DocumentResponsiveHtmlPreview::new(
+            self.inner.document_responsive_html_preview(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::e_note_configurations`].
+    pub fn e_note_configurations(&self) -> e_note_configurations::ENoteConfigurations {
+        e_note_configurations::ENoteConfigurations::new(
+            self.inner.e_note_configurations(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::envelope_attachments`].
+    pub fn envelope_attachments(&self) -> envelope_attachments::EnvelopeAttachments {
+        envelope_attachments::EnvelopeAttachments::new(
+            self.inner.envelope_attachments(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::envelope_consumer_disclosures`].
+    pub fn envelope_consumer_disclosures(
+        &self,
+    ) -> envelope_consumer_disclosures::EnvelopeConsumerDisclosures {
+        envelope_consumer_disclosures::EnvelopeConsumerDisclosures::new(
+            self.inner.envelope_consumer_disclosures(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::envelope_custom_fields`].
+    pub fn envelope_custom_fields(&self) -> envelope_custom_fields::EnvelopeCustomFields {
+        envelope_custom_fields::EnvelopeCustomFields::new(
+            self.inner.envelope_custom_fields(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::envelope_document_fields`].
+    pub fn envelope_document_fields(&self) -> envelope_document_fields::EnvelopeDocumentFields {
+        envelope_document_fields::EnvelopeDocumentFields::new(
+            self.inner.envelope_document_fields(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::envelope_document_html_definitions`].
+    pub fn envelope_document_html_definitions(
+        &self,
+    ) -> envelope_document_html_definitions::EnvelopeDocumentHtmlDefinitions {
+        envelope_document_html_definitions::EnvelopeDocumentHtmlDefinitions::new(
+            self.inner.envelope_document_html_definitions(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::envelope_document_tabs`].
+    pub fn envelope_document_tabs(&self) -> envelope_document_tabs::EnvelopeDocumentTabs {
+        envelope_document_tabs::EnvelopeDocumentTabs::new(
+            self.inner.envelope_document_tabs(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::envelope_document_visibility`].
+    pub fn envelope_document_visibility(
+        &self,
+    ) -> envelope_document_visibility::EnvelopeDocumentVisibility {
+        envelope_document_visibility::EnvelopeDocumentVisibility::new(
+            self.inner.envelope_document_visibility(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::envelope_documents`].
+    pub fn envelope_documents(&self) -> envelope_documents::EnvelopeDocuments {
+        envelope_documents::EnvelopeDocuments::new(
+            self.inner.envelope_documents(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::envelope_email_settings`].
+    pub fn envelope_email_settings(&self) -> envelope_email_settings::EnvelopeEmailSettings {
+        envelope_email_settings::EnvelopeEmailSettings::new(
+            self.inner.envelope_email_settings(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::envelope_form_data`].
+    pub fn envelope_form_data(&self) -> envelope_form_data::EnvelopeFormData {
+        envelope_form_data::EnvelopeFormData::new(
+            self.inner.envelope_form_data(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::envelope_html_definitions`].
+    pub fn envelope_html_definitions(&self) -> envelope_html_definitions::EnvelopeHtmlDefinitions {
+        envelope_html_definitions::EnvelopeHtmlDefinitions::new(
+            self.inner.envelope_html_definitions(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::envelope_locks`].
+    pub fn envelope_locks(&self) -> envelope_locks::EnvelopeLocks {
+        envelope_locks::EnvelopeLocks::new(self.inner.envelope_locks(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::envelope_recipient_tabs`].
+    pub fn envelope_recipient_tabs(&self) -> envelope_recipient_tabs::EnvelopeRecipientTabs {
+        envelope_recipient_tabs::EnvelopeRecipientTabs::new(
+            self.inner.envelope_recipient_tabs(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::envelope_recipients`].
+    pub fn envelope_recipients(&self) -> envelope_recipients::EnvelopeRecipients {
+        envelope_recipients::EnvelopeRecipients::new(
+            self.inner.envelope_recipients(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::envelope_templates`].
+    pub fn envelope_templates(&self) -> envelope_templates::EnvelopeTemplates {
+        envelope_templates::EnvelopeTemplates::new(
+            self.inner.envelope_templates(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::envelope_transfer_rules`].
+    pub fn envelope_transfer_rules(&self) -> envelope_transfer_rules::EnvelopeTransferRules {
+        envelope_transfer_rules::EnvelopeTransferRules::new(
+            self.inner.envelope_transfer_rules(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::envelope_views`].
+    pub fn envelope_views(&self) -> envelope_views::EnvelopeViews {
+        envelope_views::EnvelopeViews::new(self.inner.envelope_views(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::envelope_workflow_definition`].
+    pub fn envelope_workflow_definition(
+        &self,
+    ) -> envelope_workflow_definition::EnvelopeWorkflowDefinition {
+        envelope_workflow_definition::EnvelopeWorkflowDefinition::new(
+            self.inner.envelope_workflow_definition(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::envelopes`].
+    pub fn envelopes(&self) -> envelopes::Envelopes {
+        envelopes::Envelopes::new(self.inner.envelopes(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::favorite_templates`].
+    pub fn favorite_templates(&self) -> favorite_templates::FavoriteTemplates {
+        favorite_templates::FavoriteTemplates::new(
+            self.inner.favorite_templates(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::folders`].
+    pub fn folders(&self) -> folders::Folders {
+        folders::Folders::new(self.inner.folders(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::group_brands`].
+    pub fn group_brands(&self) -> group_brands::GroupBrands {
+        group_brands::GroupBrands::new(self.inner.group_brands(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::group_users`].
+    pub fn group_users(&self) -> group_users::GroupUsers {
+        group_users::GroupUsers::new(self.inner.group_users(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::groups`].
+    pub fn groups(&self) -> groups::Groups {
+        groups::Groups::new(self.inner.groups(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::identity_verifications`].
+    pub fn identity_verifications(&self) -> identity_verifications::IdentityVerifications {
+        identity_verifications::IdentityVerifications::new(
+            self.inner.identity_verifications(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::invoices`].
+    pub fn invoices(&self) -> invoices::Invoices {
+        invoices::Invoices::new(self.inner.invoices(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::notary`].
+    pub fn notary(&self) -> notary::Notary {
+        notary::Notary::new(self.inner.notary(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::notary_journals`].
+    pub fn notary_journals(&self) -> notary_journals::NotaryJournals {
+        notary_journals::NotaryJournals::new(self.inner.notary_journals(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::notary_jurisdiction`].
+    pub fn notary_jurisdiction(&self) -> notary_jurisdiction::NotaryJurisdiction {
+        notary_jurisdiction::NotaryJurisdiction::new(
+            self.inner.notary_jurisdiction(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::payment_gateway_accounts`].
+    pub fn payment_gateway_accounts(&self) -> payment_gateway_accounts::PaymentGatewayAccounts {
+        payment_gateway_accounts::PaymentGatewayAccounts::new(
+            self.inner.payment_gateway_accounts(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::payments`].
+    pub fn payments(&self) -> payments::Payments {
+        payments::Payments::new(self.inner.payments(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::power_form_data`].
+    pub fn power_form_data(&self) -> power_form_data::PowerFormData {
+        power_form_data::PowerFormData::new(self.inner.power_form_data(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::power_forms`].
+    pub fn power_forms(&self) -> power_forms::PowerForms {
+        power_forms::PowerForms::new(self.inner.power_forms(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::reports`].
+    pub fn reports(&self) -> reports::Reports {
+        reports::Reports::new(self.inner.reports(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::request_logs`].
+    pub fn request_logs(&self) -> request_logs::RequestLogs {
+        request_logs::RequestLogs::new(self.inner.request_logs(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::resources`].
+    pub fn resources(&self) -> resources::Resources {
+        resources::Resources::new(self.inner.resources(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::responsive_html_preview`].
+    pub fn responsive_html_preview(&self) -> responsive_html_preview::ResponsiveHtmlPreview {
+        responsive_html_preview::ResponsiveHtmlPreview::new(
+            self.inner.responsive_html_preview(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::services`].
+    pub fn services(&self) -> services::Services {
+        services::Services::new(self.inner.services(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::signing_group_users`].
+    pub fn signing_group_users(&self) -> signing_group_users::SigningGroupUsers {
+        signing_group_users::SigningGroupUsers::new(
+            self.inner.signing_group_users(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::signing_groups`].
+    pub fn signing_groups(&self) -> signing_groups::SigningGroups {
+        signing_groups::SigningGroups::new(self.inner.signing_groups(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::tabs_blob`].
+    pub fn tabs_blob(&self) -> tabs_blob::TabsBlob {
+        tabs_blob::TabsBlob::new(self.inner.tabs_blob(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::template_bulk_recipients`].
+    pub fn template_bulk_recipients(&self) -> template_bulk_recipients::TemplateBulkRecipients {
+        template_bulk_recipients::TemplateBulkRecipients::new(
+            self.inner.template_bulk_recipients(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::template_custom_fields`].
+    pub fn template_custom_fields(&self) -> template_custom_fields::TemplateCustomFields {
+        template_custom_fields::TemplateCustomFields::new(
+            self.inner.template_custom_fields(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::template_document_fields`].
+    pub fn template_document_fields(&self) -> template_document_fields::TemplateDocumentFields {
+        template_document_fields::TemplateDocumentFields::new(
+            self.inner.template_document_fields(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::template_document_html_definitions`].
+    pub fn template_document_html_definitions(
+        &self,
+    ) -> template_document_html_definitions::TemplateDocumentHtmlDefinitions {
+        template_document_html_definitions::TemplateDocumentHtmlDefinitions::new(
+            self.inner.template_document_html_definitions(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::template_document_responsive_html_preview`].
+    pub fn template_document_responsive_html_preview(
+        &self,
+    ) -> template_document_responsive_html_preview::TemplateDocumentResponsiveHtmlPreview {
+        template_document_responsive_html_preview::TemplateDocumentResponsiveHtmlPreview::new(
+            self.inner.template_document_responsive_html_preview(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::template_document_tabs`].
+    pub fn template_document_tabs(&self) -> template_document_tabs::TemplateDocumentTabs {
+        template_document_tabs::TemplateDocumentTabs::new(
+            self.inner.template_document_tabs(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::template_document_visibility`].
+    pub fn template_document_visibility(
+        &self,
+    ) -> template_document_visibility::TemplateDocumentVisibility {
+        template_document_visibility::TemplateDocumentVisibility::new(
+            self.inner.template_document_visibility(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::template_documents`].
+    pub fn template_documents(&self) -> template_documents::TemplateDocuments {
+        template_documents::TemplateDocuments::new(
+            self.inner.template_documents(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::template_html_definitions`].
+    pub fn template_html_definitions(&self) -> template_html_definitions::TemplateHtmlDefinitions {
+        template_html_definitions::TemplateHtmlDefinitions::new(
+            self.inner.template_html_definitions(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::template_locks`].
+    pub fn template_locks(&self) -> template_locks::TemplateLocks {
+        template_locks::TemplateLocks::new(self.inner.template_locks(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::template_recipient_tabs`].
+    pub fn template_recipient_tabs(&self) -> template_recipient_tabs::TemplateRecipientTabs {
+        template_recipient_tabs::TemplateRecipientTabs::new(
+            self.inner.template_recipient_tabs(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::template_recipients`].
+    pub fn template_recipients(&self) -> template_recipients::TemplateRecipients {
+        template_recipients::TemplateRecipients::new(
+            self.inner.template_recipients(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::template_responsive_html_preview`].
+    pub fn template_responsive_html_preview(
+        &self,
+    ) -> template_responsive_html_preview::TemplateResponsiveHtmlPreview {
+        template_responsive_html_preview::TemplateResponsiveHtmlPreview::new(
+            self.inner.template_responsive_html_preview(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::template_views`].
+    pub fn template_views(&self) -> template_views::TemplateViews {
+        template_views::TemplateViews::new(self.inner.template_views(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::templates`].
+    pub fn templates(&self) -> templates::Templates {
+        templates::Templates::new(self.inner.templates(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::user_custom_settings`].
+    pub fn user_custom_settings(&self) -> user_custom_settings::UserCustomSettings {
+        user_custom_settings::UserCustomSettings::new(
+            self.inner.user_custom_settings(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::user_profiles`].
+    pub fn user_profiles(&self) -> user_profiles::UserProfiles {
+        user_profiles::UserProfiles::new(self.inner.user_profiles(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::user_signatures`].
+    pub fn user_signatures(&self) -> user_signatures::UserSignatures {
+        user_signatures::UserSignatures::new(self.inner.user_signatures(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::users`].
+    pub fn users(&self) -> users::Users {
+        users::Users::new(self.inner.users(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::workspace_items`].
+    pub fn workspace_items(&self) -> workspace_items::WorkspaceItems {
+        workspace_items::WorkspaceItems::new(self.inner.workspace_items(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::workspaces`].
+    pub fn workspaces(&self) -> workspaces::Workspaces {
+        workspaces::Workspaces::new(self.inner.workspaces(), self.runtime.clone())
+    }
+}
@@ -0,0 +1,63 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Notary::get`], set with a builder.
+pub struct NotaryGetRequest<'a> {
+    inner: crate::notary::NotaryGetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> NotaryGetRequest<'a> {
+    /// Sets the `include_jurisdictions` query parameter.
+    pub fn include_jurisdictions(mut self, include_jurisdictions: &'a str) -> Self {
+        self.inner = self.inner.include_jurisdictions(include_jurisdictions);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::NotaryResult>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::notary::Notary`].
+pub struct Notary {
+    inner: crate::notary::Notary,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Notary {
+    pub(crate) fn new(inner: crate::notary::Notary, runtime: Arc<tokio::runtime::Runtime>) -> Self {
+        Notary { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::notary::Notary::get`].
+    pub fn get(
+        &self,
+        include_jurisdictions: &str,
+    ) -> ClientResult<crate::Response<crate::types::NotaryResult>> {
+        self.runtime.block_on(self.inner.get(include_jurisdictions))
+    }
+    /// The blocking version of [`crate::notary::Notary::get_request`].
+    pub fn get_request(&self) -> NotaryGetRequest<'_> {
+        NotaryGetRequest {
+            inner: self.inner.get_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::notary::Notary::put`].
+    pub fn put(
+        &self,
+        body: &crate::types::NotaryData,
+    ) -> ClientResult<crate::Response<crate::types::NotaryData>> {
+        self.runtime.block_on(self.inner.put(body))
+    }
+    /// The blocking version of [`crate::notary::Notary::post`].
+    pub fn post(
+        &self,
+        body: &crate::types::NotaryData,
+    ) -> ClientResult<crate::Response<crate::types::NotaryData>> {
+        self.runtime.block_on(self.inner.post(body))
+    }
+}
@@ -0,0 +1,67 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`NotaryJournals::get`], set with a builder.
+pub struct NotaryJournalsGetRequest<'a> {
+    inner: crate::notary_journals::NotaryJournalsGetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> NotaryJournalsGetRequest<'a> {
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: &'a str) -> Self {
+        self.inner = self.inner.count(count);
+        self
+    }
+
+    /// Sets the `search_text` query parameter.
+    pub fn search_text(mut self, search_text: &'a str) -> Self {
+        self.inner = self.inner.search_text(search_text);
+        self
+    }
+
+    /// Sets the `start_position` query parameter.
+    pub fn start_position(mut self, start_position: &'a str) -> Self {
+        self.inner = self.inner.start_position(start_position);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::NotaryJournalList>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::notary_journals::NotaryJournals`].
+pub struct NotaryJournals {
+    inner: crate::notary_journals::NotaryJournals,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl NotaryJournals {
+    pub(crate) fn new(
+        inner: crate::notary_journals::NotaryJournals,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        NotaryJournals { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::notary_journals::NotaryJournals::get`].
+    pub fn get(
+        &self,
+        count: &str,
+        search_text: &str,
+        start_position: &str,
+    ) -> ClientResult<crate::Response<crate::types::NotaryJournalList>> {
+        self.runtime
+            .block_on(self.inner.get(count, search_text, start_position))
+    }
+    /// The blocking version of [`crate::notary_journals::NotaryJournals::get_request`].
+    pub fn get_request(&self) -> NotaryJournalsGetRequest<'_> {
+        NotaryJournalsGetRequest {
+            inner: self.inner.get_request(),
+            runtime: &self.runtime,
+        }
+    }
+}
@@ -0,0 +1,55 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::notary_jurisdiction::NotaryJurisdiction`].
+pub struct NotaryJurisdiction {
+    inner: crate::notary_jurisdiction::NotaryJurisdiction,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl NotaryJurisdiction {
+    pub(crate) fn new(
+        inner: crate::notary_jurisdiction::NotaryJurisdiction,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        NotaryJurisdiction { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::notary_jurisdiction::NotaryJurisdiction::s_get`].
+    pub fn s_get(&self) -> ClientResult<crate::Response<crate::types::NotaryJurisdictionList>> {
+        self.runtime.block_on(self.inner.s_get())
+    }
+    /// The blocking version of [`crate::notary_jurisdiction::NotaryJurisdiction::s_post`].
+    pub fn s_post(
+        &self,
+        body: &crate::types::NotaryJurisdictionData,
+    ) -> ClientResult<crate::Response<crate::types::NotaryJurisdictionData>> {
+        self.runtime.block_on(self.inner.s_post(body))
+    }
+    /// The blocking version of [`crate::notary_jurisdiction::NotaryJurisdiction::s_get_jurisdiction`].
+    pub fn s_get_jurisdiction(
+        &self,
+        jurisdiction_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::NotaryJurisdictionData>> {
+        self.runtime
+            .block_on(self.inner.s_get_jurisdiction(jurisdiction_id))
+    }
+    /// The blocking version of [`crate::notary_jurisdiction::NotaryJurisdiction::s_put_jurisdiction`].
+    pub fn s_put_jurisdiction(
+        &self,
+        jurisdiction_id: &str,
+        body: &crate::types::NotaryJurisdictionData,
+    ) -> ClientResult<crate::Response<crate::types::NotaryJurisdictionData>> {
+        self.runtime
+            .block_on(self.inner.s_put_jurisdiction(jurisdiction_id, body))
+    }
+    /// The blocking version of [`crate::notary_jurisdiction::NotaryJurisdiction::s_delete_jurisdiction`].
+    pub fn s_delete_jurisdiction(
+        &self,
+        jurisdiction_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.s_delete_jurisdiction(jurisdiction_id))
+    }
+}
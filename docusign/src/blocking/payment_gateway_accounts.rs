@@ -0,0 +1,26 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::payment_gateway_accounts::PaymentGatewayAccounts`].
+pub struct PaymentGatewayAccounts {
+    inner: crate::payment_gateway_accounts::PaymentGatewayAccounts,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl PaymentGatewayAccounts {
+    pub(crate) fn new(
+        inner: crate::payment_gateway_accounts::PaymentGatewayAccounts,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        PaymentGatewayAccounts { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::payment_gateway_accounts::PaymentGatewayAccounts::get_all`].
+    pub fn get_all(
+        &self,
+        account_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::PaymentGatewayAccountsInfo>> {
+        self.runtime.block_on(self.inner.get_all(account_id))
+    }
+}
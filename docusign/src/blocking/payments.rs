@@ -0,0 +1,82 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Payments::billing_get_list`], set with a builder.
+pub struct PaymentsBillingGetListRequest<'a> {
+    inner: crate::payments::PaymentsBillingGetListRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> PaymentsBillingGetListRequest<'a> {
+    /// Sets the `from_date` query parameter.
+    pub fn from_date(mut self, from_date: &'a str) -> Self {
+        self.inner = self.inner.from_date(from_date);
+        self
+    }
+
+    /// Sets the `to_date` query parameter.
+    pub fn to_date(mut self, to_date: &'a str) -> Self {
+        self.inner = self.inner.to_date(to_date);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::BillingPaymentsResponse>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::payments::Payments`].
+pub struct Payments {
+    inner: crate::payments::Payments,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Payments {
+    pub(crate) fn new(
+        inner: crate::payments::Payments,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Payments { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::payments::Payments::billing_get_list`].
+    pub fn billing_get_list(
+        &self,
+        account_id: &str,
+        from_date: &str,
+        to_date: &str,
+    ) -> ClientResult<crate::Response<crate::types::BillingPaymentsResponse>> {
+        self.runtime
+            .block_on(self.inner.billing_get_list(account_id, from_date, to_date))
+    }
+    /// The blocking version of [`crate::payments::Payments::billing_get_list_request`].
+    pub fn billing_get_list_request<'a>(
+        &'a self,
+        account_id: &'a str,
+    ) -> PaymentsBillingGetListRequest<'a> {
+        PaymentsBillingGetListRequest {
+            inner: self.inner.billing_get_list_request(account_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::payments::Payments::billing_post`].
+    pub fn billing_post(
+        &self,
+        account_id: &str,
+        body: &crate::types::BillingPaymentRequest,
+    ) -> ClientResult<crate::Response<crate::types::BillingPaymentResponse>> {
+        self.runtime
+            .block_on(self.inner.billing_post(account_id, body))
+    }
+    /// The blocking version of [`crate::payments::Payments::billing_get`].
+    pub fn billing_get(
+        &self,
+        account_id: &str,
+        payment_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::BillingPaymentItem>> {
+        self.runtime
+            .block_on(self.inner.billing_get(account_id, payment_id))
+    }
+}
@@ -0,0 +1,80 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`PowerFormData::power_forms_get_form_data`], set with a builder.
+pub struct PowerFormDataPowerFormsGetFormDataRequest<'a> {
+    inner: crate::power_form_data::PowerFormDataPowerFormsGetFormDataRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> PowerFormDataPowerFormsGetFormDataRequest<'a> {
+    /// Sets the `data_layout` query parameter.
+    pub fn data_layout(mut self, data_layout: &'a str) -> Self {
+        self.inner = self.inner.data_layout(data_layout);
+        self
+    }
+
+    /// Sets the `from_date` query parameter.
+    pub fn from_date(mut self, from_date: &'a str) -> Self {
+        self.inner = self.inner.from_date(from_date);
+        self
+    }
+
+    /// Sets the `to_date` query parameter.
+    pub fn to_date(mut self, to_date: &'a str) -> Self {
+        self.inner = self.inner.to_date(to_date);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::PowerFormsFormDataResponse>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::power_form_data::PowerFormData`].
+pub struct PowerFormData {
+    inner: crate::power_form_data::PowerFormData,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl PowerFormData {
+    pub(crate) fn new(
+        inner: crate::power_form_data::PowerFormData,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        PowerFormData { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::power_form_data::PowerFormData::power_forms_get_form_data`].
+    pub fn power_forms_get_form_data(
+        &self,
+        account_id: &str,
+        power_form_id: &str,
+        data_layout: &str,
+        from_date: &str,
+        to_date: &str,
+    ) -> ClientResult<crate::Response<crate::types::PowerFormsFormDataResponse>> {
+        self.runtime.block_on(self.inner.power_forms_get_form_data(
+            account_id,
+            power_form_id,
+            data_layout,
+            from_date,
+            to_date,
+        ))
+    }
+    /// The blocking version of [`crate::power_form_data::PowerFormData::power_forms_get_form_data_request`].
+    pub fn power_forms_get_form_data_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        power_form_id: &'a str,
+    ) -> PowerFormDataPowerFormsGetFormDataRequest<'a> {
+        PowerFormDataPowerFormsGetFormDataRequest {
+            inner: self
+                .inner
+                .power_forms_get_form_data_request(account_id, power_form_id),
+            runtime: &self.runtime,
+        }
+    }
+}
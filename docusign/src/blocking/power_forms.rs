@@ -0,0 +1,161 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`PowerForms::get_list`], set with a builder.
+pub struct PowerFormsGetListRequest<'a> {
+    inner: crate::power_forms::PowerFormsGetListRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> PowerFormsGetListRequest<'a> {
+    /// Sets the `from_date` query parameter.
+    pub fn from_date(mut self, from_date: &'a str) -> Self {
+        self.inner = self.inner.from_date(from_date);
+        self
+    }
+
+    /// Sets the `order` query parameter.
+    pub fn order(mut self, order: &'a str) -> Self {
+        self.inner = self.inner.order(order);
+        self
+    }
+
+    /// Sets the `order_by` query parameter.
+    pub fn order_by(mut self, order_by: &'a str) -> Self {
+        self.inner = self.inner.order_by(order_by);
+        self
+    }
+
+    /// Sets the `to_date` query parameter.
+    pub fn to_date(mut self, to_date: &'a str) -> Self {
+        self.inner = self.inner.to_date(to_date);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::PowerFormsResponse>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`PowerForms::get_senders`], set with a builder.
+pub struct PowerFormsGetSendersRequest<'a> {
+    inner: crate::power_forms::PowerFormsGetSendersRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> PowerFormsGetSendersRequest<'a> {
+    /// Sets the `start_position` query parameter.
+    pub fn start_position(mut self, start_position: &'a str) -> Self {
+        self.inner = self.inner.start_position(start_position);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::PowerFormSendersResponse>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::power_forms::PowerForms`].
+pub struct PowerForms {
+    inner: crate::power_forms::PowerForms,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl PowerForms {
+    pub(crate) fn new(
+        inner: crate::power_forms::PowerForms,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        PowerForms { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::power_forms::PowerForms::get_list`].
+    pub fn get_list(
+        &self,
+        account_id: &str,
+        from_date: &str,
+        order: &str,
+        order_by: &str,
+        to_date: &str,
+    ) -> ClientResult<crate::Response<crate::types::PowerFormsResponse>> {
+        self.runtime.block_on(
+            self.inner
+                .get_list(account_id, from_date, order, order_by, to_date),
+        )
+    }
+    /// The blocking version of [`crate::power_forms::PowerForms::get_list_request`].
+    pub fn get_list_request<'a>(&'a self, account_id: &'a str) -> PowerFormsGetListRequest<'a> {
+        PowerFormsGetListRequest {
+            inner: self.inner.get_list_request(account_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::power_forms::PowerForms::post_form`].
+    pub fn post_form(
+        &self,
+        account_id: &str,
+        body: &crate::types::PowerForm,
+    ) -> ClientResult<crate::Response<crate::types::PowerForm>> {
+        self.runtime
+            .block_on(self.inner.post_form(account_id, body))
+    }
+    /// The blocking version of [`crate::power_forms::PowerForms::delete_list`].
+    pub fn delete_list(
+        &self,
+        account_id: &str,
+        body: &crate::types::PowerFormsRequest,
+    ) -> ClientResult<crate::Response<crate::types::PowerFormsResponse>> {
+        self.runtime
+            .block_on(self.inner.delete_list(account_id, body))
+    }
+    /// The blocking version of [`crate::power_forms::PowerForms::get_senders`].
+    pub fn get_senders(
+        &self,
+        account_id: &str,
+        start_position: &str,
+    ) -> ClientResult<crate::Response<crate::types::PowerFormSendersResponse>> {
+        self.runtime
+            .block_on(self.inner.get_senders(account_id, start_position))
+    }
+    /// The blocking version of [`crate::power_forms::PowerForms::get_senders_request`].
+    pub fn get_senders_request<'a>(
+        &'a self,
+        account_id: &'a str,
+    ) -> PowerFormsGetSendersRequest<'a> {
+        PowerFormsGetSendersRequest {
+            inner: self.inner.get_senders_request(account_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::power_forms::PowerForms::get_form`].
+    pub fn get_form(
+        &self,
+        account_id: &str,
+        power_form_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::PowerForm>> {
+        self.runtime
+            .block_on(self.inner.get_form(account_id, power_form_id))
+    }
+    /// The blocking version of [`crate::power_forms::PowerForms::put_form`].
+    pub fn put_form(
+        &self,
+        account_id: &str,
+        power_form_id: &str,
+        body: &crate::types::PowerForm,
+    ) -> ClientResult<crate::Response<crate::types::PowerForm>> {
+        self.runtime
+            .block_on(self.inner.put_form(account_id, power_form_id, body))
+    }
+    /// The blocking version of [`crate::power_forms::PowerForms::delete_form`].
+    pub fn delete_form(
+        &self,
+        account_id: &str,
+        power_form_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete_form(account_id, power_form_id))
+    }
+}
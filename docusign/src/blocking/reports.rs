@@ -0,0 +1,82 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::reports::Reports`].
+pub struct Reports {
+    inner: crate::reports::Reports,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Reports {
+    pub(crate) fn new(
+        inner: crate::reports::Reports,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Reports { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::reports::Reports::product_get_list`].
+    pub fn product_get_list(
+        &self,
+        account_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ReportInProductList>> {
+        self.runtime
+            .block_on(self.inner.product_get_list(account_id))
+    }
+    /// The blocking version of [`crate::reports::Reports::product_post_create`].
+    pub fn product_post_create(
+        &self,
+        account_id: &str,
+        body: &crate::types::ReportInProductRunRequest,
+    ) -> ClientResult<crate::Response<crate::types::ReportInProductSaveResponse>> {
+        self.runtime
+            .block_on(self.inner.product_post_create(account_id, body))
+    }
+    /// The blocking version of [`crate::reports::Reports::product_put_run_results`].
+    pub fn product_put_run_results(
+        &self,
+        account_id: &str,
+        body: &crate::types::ReportInProductRunRequest,
+    ) -> ClientResult<crate::Response<crate::types::ReportInProductRunResponse>> {
+        self.runtime
+            .block_on(self.inner.product_put_run_results(account_id, body))
+    }
+    /// The blocking version of [`crate::reports::Reports::product_put_results_csv`].
+    pub fn product_put_results_csv(
+        &self,
+        account_id: &str,
+        body: &crate::types::ReportInProductCsvRunRequest,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.product_put_results_csv(account_id, body))
+    }
+    /// The blocking version of [`crate::reports::Reports::product_get`].
+    pub fn product_get(
+        &self,
+        account_id: &str,
+        id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ReportInProductGet>> {
+        self.runtime
+            .block_on(self.inner.product_get(account_id, id))
+    }
+    /// The blocking version of [`crate::reports::Reports::product_put_save`].
+    pub fn product_put_save(
+        &self,
+        account_id: &str,
+        id: &str,
+        body: &crate::types::ReportInProductRunRequest,
+    ) -> ClientResult<crate::Response<crate::types::ReportInProductSaveResponse>> {
+        self.runtime
+            .block_on(self.inner.product_put_save(account_id, id, body))
+    }
+    /// The blocking version of [`crate::reports::Reports::product_delete`].
+    pub fn product_delete(
+        &self,
+        account_id: &str,
+        id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ReportInProductSaveResponse>> {
+        self.runtime
+            .block_on(self.inner.product_delete(account_id, id))
+    }
+}
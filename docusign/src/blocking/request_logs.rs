@@ -0,0 +1,73 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`RequestLogs::api_get_log`], set with a builder.
+pub struct RequestLogsApiGetLogRequest<'a> {
+    inner: crate::request_logs::RequestLogsApiGetLogRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> RequestLogsApiGetLogRequest<'a> {
+    /// Sets the `encoding` query parameter.
+    pub fn encoding(mut self, encoding: &'a str) -> Self {
+        self.inner = self.inner.encoding(encoding);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::ApiRequestLogsResult>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::request_logs::RequestLogs`].
+pub struct RequestLogs {
+    inner: crate::request_logs::RequestLogs,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl RequestLogs {
+    pub(crate) fn new(
+        inner: crate::request_logs::RequestLogs,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        RequestLogs { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::request_logs::RequestLogs::api_get_log`].
+    pub fn api_get_log(
+        &self,
+        encoding: &str,
+    ) -> ClientResult<crate::Response<crate::types::ApiRequestLogsResult>> {
+        self.runtime.block_on(self.inner.api_get_log(encoding))
+    }
+    /// The blocking version of [`crate::request_logs::RequestLogs::api_get_log_request`].
+    pub fn api_get_log_request(&self) -> RequestLogsApiGetLogRequest<'_> {
+        RequestLogsApiGetLogRequest {
+            inner: self.inner.api_get_log_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::request_logs::RequestLogs::api_delete_logs`].
+    pub fn api_delete_logs(&self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.api_delete_logs())
+    }
+    /// The blocking version of [`crate::request_logs::RequestLogs::api_get`].
+    pub fn api_get(&self, request_log_id: &str) -> ClientResult<crate::Response<bytes::Bytes>> {
+        self.runtime.block_on(self.inner.api_get(request_log_id))
+    }
+    /// The blocking version of [`crate::request_logs::RequestLogs::api_get_setting`].
+    pub fn api_get_setting(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::DiagnosticsSettingsInformation>> {
+        self.runtime.block_on(self.inner.api_get_setting())
+    }
+    /// The blocking version of [`crate::request_logs::RequestLogs::api_put_settings`].
+    pub fn api_put_settings(
+        &self,
+        body: &crate::types::DiagnosticsSettingsInformation,
+    ) -> ClientResult<crate::Response<crate::types::DiagnosticsSettingsInformation>> {
+        self.runtime.block_on(self.inner.api_put_settings(body))
+    }
+}
@@ -0,0 +1,25 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::resources::Resources`].
+pub struct Resources {
+    inner: crate::resources::Resources,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Resources {
+    pub(crate) fn new(
+        inner: crate::resources::Resources,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Resources { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::resources::Resources::service_information_get`].
+    pub fn service_information_get(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::ResourceInformation>> {
+        self.runtime.block_on(self.inner.service_information_get())
+    }
+}
@@ -0,0 +1,32 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::responsive_html_preview::ResponsiveHtmlPreview`].
+pub struct ResponsiveHtmlPreview {
+    inner: crate::responsive_html_preview::ResponsiveHtmlPreview,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl ResponsiveHtmlPreview {
+    pub(crate) fn new(
+        inner: crate::responsive_html_preview::ResponsiveHtmlPreview,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        ResponsiveHtmlPreview { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::responsive_html_preview::ResponsiveHtmlPreview::responsive_html_post_preview`].
+    pub fn responsive_html_post_preview(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::DocumentHtmlDefinition,
+    ) -> ClientResult<crate::Response<crate::types::DocumentHtmlDefinitions>> {
+        self.runtime
+            .block_on(
+                self.inner
+                    .responsive_html_post_preview(account_id, envelope_id, body),
+            )
+    }
+}
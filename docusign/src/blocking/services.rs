@@ -0,0 +1,25 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::services::Services`].
+pub struct Services {
+    inner: crate::services::Services,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Services {
+    pub(crate) fn new(
+        inner: crate::services::Services,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Services { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::services::Services::information_get`].
+    pub fn information_get(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::ServiceInformation>> {
+        self.runtime.block_on(self.inner.information_get())
+    }
+}
@@ -0,0 +1,57 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::signing_group_users::SigningGroupUsers`].
+pub struct SigningGroupUsers {
+    inner: crate::signing_group_users::SigningGroupUsers,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl SigningGroupUsers {
+    pub(crate) fn new(
+        inner: crate::signing_group_users::SigningGroupUsers,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        SigningGroupUsers { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::signing_group_users::SigningGroupUsers::signing_groups_get_group_user`].
+    pub fn signing_groups_get_group_user(
+        &self,
+        account_id: &str,
+        signing_group_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::SigningGroupUsersData>> {
+        self.runtime.block_on(
+            self.inner
+                .signing_groups_get_group_user(account_id, signing_group_id),
+        )
+    }
+    /// The blocking version of [`crate::signing_group_users::SigningGroupUsers::signing_groups_put_group_users`].
+    pub fn signing_groups_put_group_users(
+        &self,
+        account_id: &str,
+        signing_group_id: &str,
+        body: &crate::types::SigningGroupUsersData,
+    ) -> ClientResult<crate::Response<crate::types::SigningGroupUsersData>> {
+        self.runtime
+            .block_on(
+                self.inner
+                    .signing_groups_put_group_users(account_id, signing_group_id, body),
+            )
+    }
+    /// The blocking version of [`crate::signing_group_users::SigningGroupUsers::signing_groups_delete_group_users`].
+    pub fn signing_groups_delete_group_users(
+        &self,
+        account_id: &str,
+        signing_group_id: &str,
+        body: &crate::types::SigningGroupUsersData,
+    ) -> ClientResult<crate::Response<crate::types::SigningGroupUsersData>> {
+        self.runtime
+            .block_on(self.inner.signing_groups_delete_group_users(
+                account_id,
+                signing_group_id,
+                body,
+            ))
+    }
+}
@@ -0,0 +1,104 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`SigningGroups::get`], set with a builder.
+pub struct SigningGroupsGetRequest<'a> {
+    inner: crate::signing_groups::SigningGroupsGetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> SigningGroupsGetRequest<'a> {
+    /// Sets the `group_type` query parameter.
+    pub fn group_type(mut self, group_type: &'a str) -> Self {
+        self.inner = self.inner.group_type(group_type);
+        self
+    }
+
+    /// Sets the `include_users` query parameter.
+    pub fn include_users(mut self, include_users: &'a str) -> Self {
+        self.inner = self.inner.include_users(include_users);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::SigningGroupInformation>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::signing_groups::SigningGroups`].
+pub struct SigningGroups {
+    inner: crate::signing_groups::SigningGroups,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl SigningGroups {
+    pub(crate) fn new(
+        inner: crate::signing_groups::SigningGroups,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        SigningGroups { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::signing_groups::SigningGroups::get`].
+    pub fn get(
+        &self,
+        account_id: &str,
+        group_type: &str,
+        include_users: &str,
+    ) -> ClientResult<crate::Response<crate::types::SigningGroupInformation>> {
+        self.runtime
+            .block_on(self.inner.get(account_id, group_type, include_users))
+    }
+    /// The blocking version of [`crate::signing_groups::SigningGroups::get_request`].
+    pub fn get_request<'a>(&'a self, account_id: &'a str) -> SigningGroupsGetRequest<'a> {
+        SigningGroupsGetRequest {
+            inner: self.inner.get_request(account_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::signing_groups::SigningGroups::put`].
+    pub fn put(
+        &self,
+        account_id: &str,
+        body: &crate::types::SigningGroupInformation,
+    ) -> ClientResult<crate::Response<crate::types::SigningGroupInformation>> {
+        self.runtime.block_on(self.inner.put(account_id, body))
+    }
+    /// The blocking version of [`crate::signing_groups::SigningGroups::post`].
+    pub fn post(
+        &self,
+        account_id: &str,
+        body: &crate::types::SigningGroupInformation,
+    ) -> ClientResult<crate::Response<crate::types::SigningGroupInformation>> {
+        self.runtime.block_on(self.inner.post(account_id, body))
+    }
+    /// The blocking version of [`crate::signing_groups::SigningGroups::delete`].
+    pub fn delete(
+        &self,
+        account_id: &str,
+        body: &crate::types::SigningGroupInformation,
+    ) -> ClientResult<crate::Response<crate::types::SigningGroupInformation>> {
+        self.runtime.block_on(self.inner.delete(account_id, body))
+    }
+    /// The blocking version of [`crate::signing_groups::SigningGroups::get_group`].
+    pub fn get_group(
+        &self,
+        account_id: &str,
+        signing_group_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::SigningGroup>> {
+        self.runtime
+            .block_on(self.inner.get_group(account_id, signing_group_id))
+    }
+    /// The blocking version of [`crate::signing_groups::SigningGroups::put_group`].
+    pub fn put_group(
+        &self,
+        account_id: &str,
+        signing_group_id: &str,
+        body: &crate::types::SigningGroup,
+    ) -> ClientResult<crate::Response<crate::types::SigningGroup>> {
+        self.runtime
+            .block_on(self.inner.put_group(account_id, signing_group_id, body))
+    }
+}
@@ -0,0 +1,29 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::tabs_blob::TabsBlob`].
+pub struct TabsBlob {
+    inner: crate::tabs_blob::TabsBlob,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl TabsBlob {
+    pub(crate) fn new(
+        inner: crate::tabs_blob::TabsBlob,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        TabsBlob { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::tabs_blob::TabsBlob::get`].
+    pub fn get(&self, account_id: &str, envelope_id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.get(account_id, envelope_id))
+    }
+    /// The blocking version of [`crate::tabs_blob::TabsBlob::put`].
+    pub fn put(&self, account_id: &str, envelope_id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.put(account_id, envelope_id))
+    }
+}
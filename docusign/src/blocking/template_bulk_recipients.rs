@@ -0,0 +1,109 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`TemplateBulkRecipients::recipients_get_template_bulk`], set with a builder.
+pub struct TemplateBulkRecipientsRecipientsGetTemplateBulkRequest<'a> {
+    inner:
+        crate::template_bulk_recipients::TemplateBulkRecipientsRecipientsGetTemplateBulkRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> TemplateBulkRecipientsRecipientsGetTemplateBulkRequest<'a> {
+    /// Sets the `include_tabs` query parameter.
+    pub fn include_tabs(mut self, include_tabs: &'a str) -> Self {
+        self.inner = self.inner.include_tabs(include_tabs);
+        self
+    }
+
+    /// Sets the `start_position` query parameter.
+    pub fn start_position(mut self, start_position: &'a str) -> Self {
+        self.inner = self.inner.start_position(start_position);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::BulkRecipientsResponse>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::template_bulk_recipients::TemplateBulkRecipients`].
+pub struct TemplateBulkRecipients {
+    inner: crate::template_bulk_recipients::TemplateBulkRecipients,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl TemplateBulkRecipients {
+    pub(crate) fn new(
+        inner: crate::template_bulk_recipients::TemplateBulkRecipients,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        TemplateBulkRecipients { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::template_bulk_recipients::TemplateBulkRecipients::recipients_get_template_bulk`].
+    pub fn recipients_get_template_bulk(
+        &self,
+        account_id: &str,
+        recipient_id: &str,
+        template_id: &str,
+        include_tabs: &str,
+        start_position: &str,
+    ) -> ClientResult<crate::Response<crate::types::BulkRecipientsResponse>> {
+        self.runtime
+            .block_on(self.inner.recipients_get_template_bulk(
+                account_id,
+                recipient_id,
+                template_id,
+                include_tabs,
+                start_position,
+            ))
+    }
+    /// The blocking version of [`crate::template_bulk_recipients::TemplateBulkRecipients::recipients_get_template_bulk_request`].
+    pub fn recipients_get_template_bulk_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        recipient_id: &'a str,
+        template_id: &'a str,
+    ) -> TemplateBulkRecipientsRecipientsGetTemplateBulkRequest<'a> {
+        TemplateBulkRecipientsRecipientsGetTemplateBulkRequest {
+            inner: self.inner.recipients_get_template_bulk_request(
+                account_id,
+                recipient_id,
+                template_id,
+            ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::template_bulk_recipients::TemplateBulkRecipients::recipients_put_template_bulk`].
+    pub fn recipients_put_template_bulk(
+        &self,
+        account_id: &str,
+        recipient_id: &str,
+        template_id: &str,
+        body: &crate::types::BulkRecipientsRequest,
+    ) -> ClientResult<crate::Response<crate::types::BulkRecipientsSummaryResponse>> {
+        self.runtime
+            .block_on(self.inner.recipients_put_template_bulk(
+                account_id,
+                recipient_id,
+                template_id,
+                body,
+            ))
+    }
+    /// The blocking version of [`crate::template_bulk_recipients::TemplateBulkRecipients::recipients_delete_template_bulk_file`].
+    pub fn recipients_delete_template_bulk_file(
+        &self,
+        account_id: &str,
+        recipient_id: &str,
+        template_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::BulkRecipientsUpdateResponse>> {
+        self.runtime
+            .block_on(self.inner.recipients_delete_template_bulk_file(
+                account_id,
+                recipient_id,
+                template_id,
+            ))
+    }
+}
@@ -0,0 +1,67 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::template_custom_fields::TemplateCustomFields`].
+pub struct TemplateCustomFields {
+    inner: crate::template_custom_fields::TemplateCustomFields,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl TemplateCustomFields {
+    pub(crate) fn new(
+        inner: crate::template_custom_fields::TemplateCustomFields,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        TemplateCustomFields { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::template_custom_fields::TemplateCustomFields::custom_fields_get_template`].
+    pub fn custom_fields_get_template(
+        &self,
+        account_id: &str,
+        template_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::CustomFields>> {
+        self.runtime.block_on(
+            self.inner
+                .custom_fields_get_template(account_id, template_id),
+        )
+    }
+    /// The blocking version of [`crate::template_custom_fields::TemplateCustomFields::custom_fields_put_template`].
+    pub fn custom_fields_put_template(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        body: &crate::types::TemplateCustomFieldsData,
+    ) -> ClientResult<crate::Response<crate::types::CustomFields>> {
+        self.runtime.block_on(
+            self.inner
+                .custom_fields_put_template(account_id, template_id, body),
+        )
+    }
+    /// The blocking version of [`crate::template_custom_fields::TemplateCustomFields::custom_fields_post_template`].
+    pub fn custom_fields_post_template(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        body: &crate::types::TemplateCustomFieldsData,
+    ) -> ClientResult<crate::Response<crate::types::CustomFields>> {
+        self.runtime.block_on(
+            self.inner
+                .custom_fields_post_template(account_id, template_id, body),
+        )
+    }
+    /// The blocking version of [`crate::template_custom_fields::TemplateCustomFields::custom_fields_delete_template`].
+    pub fn custom_fields_delete_template(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        body: &crate::types::TemplateCustomFieldsData,
+    ) -> ClientResult<crate::Response<crate::types::CustomFields>> {
+        self.runtime
+            .block_on(
+                self.inner
+                    .custom_fields_delete_template(account_id, template_id, body),
+            )
+    }
+}
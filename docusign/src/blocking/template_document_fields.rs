@@ -0,0 +1,80 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::template_document_fields::TemplateDocumentFields`].
+pub struct TemplateDocumentFields {
+    inner: crate::template_document_fields::TemplateDocumentFields,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl TemplateDocumentFields {
+    pub(crate) fn new(
+        inner: crate::template_document_fields::TemplateDocumentFields,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        TemplateDocumentFields { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::template_document_fields::TemplateDocumentFields::document_fields_get_template`].
+    pub fn document_fields_get_template(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::DocumentFieldsInformation>> {
+        self.runtime
+            .block_on(
+                self.inner
+                    .document_fields_get_template(account_id, document_id, template_id),
+            )
+    }
+    /// The blocking version of [`crate::template_document_fields::TemplateDocumentFields::document_fields_put_template`].
+    pub fn document_fields_put_template(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+        body: &crate::types::DocumentFieldsInformation,
+    ) -> ClientResult<crate::Response<crate::types::DocumentFieldsInformation>> {
+        self.runtime
+            .block_on(self.inner.document_fields_put_template(
+                account_id,
+                document_id,
+                template_id,
+                body,
+            ))
+    }
+    /// The blocking version of [`crate::template_document_fields::TemplateDocumentFields::document_fields_post_template`].
+    pub fn document_fields_post_template(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+        body: &crate::types::DocumentFieldsInformation,
+    ) -> ClientResult<crate::Response<crate::types::DocumentFieldsInformation>> {
+        self.runtime
+            .block_on(self.inner.document_fields_post_template(
+                account_id,
+                document_id,
+                template_id,
+                body,
+            ))
+    }
+    /// The blocking version of [`crate::template_document_fields::TemplateDocumentFields::document_fields_delete_template`].
+    pub fn document_fields_delete_template(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+        body: &crate::types::DocumentFieldsInformation,
+    ) -> ClientResult<crate::Response<crate::types::DocumentFieldsInformation>> {
+        self.runtime
+            .block_on(self.inner.document_fields_delete_template(
+                account_id,
+                document_id,
+                template_id,
+                body,
+            ))
+    }
+}
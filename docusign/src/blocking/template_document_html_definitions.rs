@@ -0,0 +1,33 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::template_document_html_definitions::TemplateDocumentHtmlDefinitions`].
+pub struct TemplateDocumentHtmlDefinitions {
+    inner: crate::template_document_html_definitions::TemplateDocumentHtmlDefinitions,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl TemplateDocumentHtmlDefinitions {
+    pub(crate) fn new(
+        inner: crate::template_document_html_definitions::TemplateDocumentHtmlDefinitions,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        TemplateDocumentHtmlDefinitions { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::template_document_html_definitions::TemplateDocumentHtmlDefinitions::responsive_html_get_template_document_definition`].
+    pub fn responsive_html_get_template_document_definition(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::DocumentHtmlDefinitionOriginals>> {
+        self.runtime
+            .block_on(self.inner.responsive_html_get_template_document_definition(
+                account_id,
+                document_id,
+                template_id,
+            ))
+    }
+}
@@ -0,0 +1,35 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::template_document_responsive_html_preview::TemplateDocumentResponsiveHtmlPreview`].
+pub struct TemplateDocumentResponsiveHtmlPreview {
+    inner: crate::template_document_responsive_html_preview::TemplateDocumentResponsiveHtmlPreview,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl TemplateDocumentResponsiveHtmlPreview {
+    pub(crate) fn new(
+        inner: crate::template_document_responsive_html_preview::TemplateDocumentResponsiveHtmlPreview,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        TemplateDocumentResponsiveHtmlPreview { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::template_document_responsive_html_preview::TemplateDocumentResponsiveHtmlPreview::responsive_html_post_template_document_preview`].
+    pub fn responsive_html_post_template_document_preview(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+        body: &crate::types::DocumentHtmlDefinition,
+    ) -> ClientResult<crate::Response<crate::types::DocumentHtmlDefinitions>> {
+        self.runtime
+            .block_on(self.inner.responsive_html_post_template_document_preview(
+                account_id,
+                document_id,
+                template_id,
+                body,
+            ))
+    }
+}
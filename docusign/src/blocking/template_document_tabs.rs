@@ -0,0 +1,131 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`TemplateDocumentTabs::tabs_get_template_document`], set with a builder.
+pub struct TemplateDocumentTabsTabsGetTemplateDocumentRequest<'a> {
+    inner: crate::template_document_tabs::TemplateDocumentTabsTabsGetTemplateDocumentRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> TemplateDocumentTabsTabsGetTemplateDocumentRequest<'a> {
+    /// Sets the `page_numbers` query parameter.
+    pub fn page_numbers(mut self, page_numbers: &'a str) -> Self {
+        self.inner = self.inner.page_numbers(page_numbers);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::TemplateDocumentTabs>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::template_document_tabs::TemplateDocumentTabs`].
+pub struct TemplateDocumentTabs {
+    inner: crate::template_document_tabs::TemplateDocumentTabs,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl TemplateDocumentTabs {
+    pub(crate) fn new(
+        inner: crate::template_document_tabs::TemplateDocumentTabs,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        TemplateDocumentTabs { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::template_document_tabs::TemplateDocumentTabs::tabs_get_template_page`].
+    pub fn tabs_get_template_page(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        page_number: &str,
+        template_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::TemplateDocumentTabs>> {
+        self.runtime.block_on(self.inner.tabs_get_template_page(
+            account_id,
+            document_id,
+            page_number,
+            template_id,
+        ))
+    }
+    /// The blocking version of [`crate::template_document_tabs::TemplateDocumentTabs::tabs_get_template_document`].
+    pub fn tabs_get_template_document(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+        page_numbers: &str,
+    ) -> ClientResult<crate::Response<crate::types::TemplateDocumentTabs>> {
+        self.runtime.block_on(self.inner.tabs_get_template_document(
+            account_id,
+            document_id,
+            template_id,
+            page_numbers,
+        ))
+    }
+    /// The blocking version of [`crate::template_document_tabs::TemplateDocumentTabs::tabs_get_template_document_request`].
+    pub fn tabs_get_template_document_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        document_id: &'a str,
+        template_id: &'a str,
+    ) -> TemplateDocumentTabsTabsGetTemplateDocumentRequest<'a> {
+        TemplateDocumentTabsTabsGetTemplateDocumentRequest {
+            inner: self.inner.tabs_get_template_document_request(
+                account_id,
+                document_id,
+                template_id,
+            ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::template_document_tabs::TemplateDocumentTabs::tabs_put_template_document`].
+    pub fn tabs_put_template_document(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+        body: &crate::types::TemplateTabs,
+    ) -> ClientResult<crate::Response<crate::types::Tabs>> {
+        self.runtime.block_on(self.inner.tabs_put_template_document(
+            account_id,
+            document_id,
+            template_id,
+            body,
+        ))
+    }
+    /// The blocking version of [`crate::template_document_tabs::TemplateDocumentTabs::tabs_post_template_document`].
+    pub fn tabs_post_template_document(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+        body: &crate::types::TemplateTabs,
+    ) -> ClientResult<crate::Response<crate::types::Tabs>> {
+        self.runtime
+            .block_on(self.inner.tabs_post_template_document(
+                account_id,
+                document_id,
+                template_id,
+                body,
+            ))
+    }
+    /// The blocking version of [`crate::template_document_tabs::TemplateDocumentTabs::tabs_delete_template_document`].
+    pub fn tabs_delete_template_document(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+        body: &crate::types::TemplateTabs,
+    ) -> ClientResult<crate::Response<crate::types::Tabs>> {
+        self.runtime
+            .block_on(self.inner.tabs_delete_template_document(
+                account_id,
+                document_id,
+                template_id,
+                body,
+            ))
+    }
+}
@@ -0,0 +1,63 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::template_document_visibility::TemplateDocumentVisibility`].
+pub struct TemplateDocumentVisibility {
+    inner: crate::template_document_visibility::TemplateDocumentVisibility,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl TemplateDocumentVisibility {
+    pub(crate) fn new(
+        inner: crate::template_document_visibility::TemplateDocumentVisibility,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        TemplateDocumentVisibility { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::template_document_visibility::TemplateDocumentVisibility::recipients_put`].
+    pub fn recipients_put(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        body: &crate::types::TemplateDocumentVisibilityList,
+    ) -> ClientResult<crate::Response<crate::types::TemplateDocumentVisibilityList>> {
+        self.runtime
+            .block_on(self.inner.recipients_put(account_id, template_id, body))
+    }
+    /// The blocking version of [`crate::template_document_visibility::TemplateDocumentVisibility::recipients_get_template_recipient_document_visibility`].
+    pub fn recipients_get_template_recipient_document_visibility(
+        &self,
+        account_id: &str,
+        recipient_id: &str,
+        template_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::DocumentVisibilityList>> {
+        self.runtime.block_on(
+            self.inner
+                .recipients_get_template_recipient_document_visibility(
+                    account_id,
+                    recipient_id,
+                    template_id,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::template_document_visibility::TemplateDocumentVisibility::recipients_put_template_recipient_document_visibility`].
+    pub fn recipients_put_template_recipient_document_visibility(
+        &self,
+        account_id: &str,
+        recipient_id: &str,
+        template_id: &str,
+        body: &crate::types::TemplateDocumentVisibilityList,
+    ) -> ClientResult<crate::Response<crate::types::TemplateDocumentVisibilityList>> {
+        self.runtime.block_on(
+            self.inner
+                .recipients_put_template_recipient_document_visibility(
+                    account_id,
+                    recipient_id,
+                    template_id,
+                    body,
+                ),
+        )
+    }
+}
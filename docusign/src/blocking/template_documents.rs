@@ -0,0 +1,195 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`TemplateDocuments::documents_get_template`], set with a builder.
+pub struct TemplateDocumentsDocumentsGetTemplateRequest<'a> {
+    inner: crate::template_documents::TemplateDocumentsDocumentsGetTemplateRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> TemplateDocumentsDocumentsGetTemplateRequest<'a> {
+    /// Sets the `include_tabs` query parameter.
+    pub fn include_tabs(mut self, include_tabs: &'a str) -> Self {
+        self.inner = self.inner.include_tabs(include_tabs);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::TemplateDocumentsResult>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`TemplateDocuments::documents_get`], set with a builder.
+pub struct TemplateDocumentsDocumentsGetRequest<'a> {
+    inner: crate::template_documents::TemplateDocumentsDocumentsGetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> TemplateDocumentsDocumentsGetRequest<'a> {
+    /// Sets the `encrypt` query parameter.
+    pub fn encrypt(mut self, encrypt: &'a str) -> Self {
+        self.inner = self.inner.encrypt(encrypt);
+        self
+    }
+
+    /// Sets the `show_changes` query parameter.
+    pub fn show_changes(mut self, show_changes: &'a str) -> Self {
+        self.inner = self.inner.show_changes(show_changes);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`TemplateDocuments::documents_put`], set with a builder.
+pub struct TemplateDocumentsDocumentsPutRequest<'a> {
+    inner: crate::template_documents::TemplateDocumentsDocumentsPutRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> TemplateDocumentsDocumentsPutRequest<'a> {
+    /// Sets the `is_envelope_definition` query parameter.
+    pub fn is_envelope_definition(mut self, is_envelope_definition: &'a str) -> Self {
+        self.inner = self.inner.is_envelope_definition(is_envelope_definition);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::EnvelopeDocument>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::template_documents::TemplateDocuments`].
+pub struct TemplateDocuments {
+    inner: crate::template_documents::TemplateDocuments,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl TemplateDocuments {
+    pub(crate) fn new(
+        inner: crate::template_documents::TemplateDocuments,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        TemplateDocuments { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::template_documents::TemplateDocuments::documents_get_template`].
+    pub fn documents_get_template(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        include_tabs: &str,
+    ) -> ClientResult<crate::Response<crate::types::TemplateDocumentsResult>> {
+        self.runtime.block_on(self.inner.documents_get_template(
+            account_id,
+            template_id,
+            include_tabs,
+        ))
+    }
+    /// The blocking version of [`crate::template_documents::TemplateDocuments::documents_get_template_request`].
+    pub fn documents_get_template_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        template_id: &'a str,
+    ) -> TemplateDocumentsDocumentsGetTemplateRequest<'a> {
+        TemplateDocumentsDocumentsGetTemplateRequest {
+            inner: self
+                .inner
+                .documents_get_template_request(account_id, template_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::template_documents::TemplateDocuments::documents_put_template`].
+    pub fn documents_put_template(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        body: &crate::types::EnvelopeDefinition,
+    ) -> ClientResult<crate::Response<crate::types::TemplateDocumentsResult>> {
+        self.runtime.block_on(
+            self.inner
+                .documents_put_template(account_id, template_id, body),
+        )
+    }
+    /// The blocking version of [`crate::template_documents::TemplateDocuments::documents_delete_template`].
+    pub fn documents_delete_template(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        body: &crate::types::EnvelopeDefinition,
+    ) -> ClientResult<crate::Response<crate::types::TemplateDocumentsResult>> {
+        self.runtime.block_on(
+            self.inner
+                .documents_delete_template(account_id, template_id, body),
+        )
+    }
+    /// The blocking version of [`crate::template_documents::TemplateDocuments::documents_get`].
+    pub fn documents_get(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+        encrypt: &str,
+        show_changes: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.documents_get(
+            account_id,
+            document_id,
+            template_id,
+            encrypt,
+            show_changes,
+        ))
+    }
+    /// The blocking version of [`crate::template_documents::TemplateDocuments::documents_get_request`].
+    pub fn documents_get_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        document_id: &'a str,
+        template_id: &'a str,
+    ) -> TemplateDocumentsDocumentsGetRequest<'a> {
+        TemplateDocumentsDocumentsGetRequest {
+            inner: self
+                .inner
+                .documents_get_request(account_id, document_id, template_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::template_documents::TemplateDocuments::documents_put`].
+    pub fn documents_put(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+        is_envelope_definition: &str,
+        body: &crate::types::EnvelopeDefinition,
+    ) -> ClientResult<crate::Response<crate::types::EnvelopeDocument>> {
+        self.runtime.block_on(self.inner.documents_put(
+            account_id,
+            document_id,
+            template_id,
+            is_envelope_definition,
+            body,
+        ))
+    }
+    /// The blocking version of [`crate::template_documents::TemplateDocuments::documents_put_request`].
+    pub fn documents_put_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        document_id: &'a str,
+        template_id: &'a str,
+        body: &'a crate::types::EnvelopeDefinition,
+    ) -> TemplateDocumentsDocumentsPutRequest<'a> {
+        TemplateDocumentsDocumentsPutRequest {
+            inner: self
+                .inner
+                .documents_put_request(account_id, document_id, template_id, body),
+            runtime: &self.runtime,
+        }
+    }
+}
@@ -0,0 +1,30 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::template_html_definitions::TemplateHtmlDefinitions`].
+pub struct TemplateHtmlDefinitions {
+    inner: crate::template_html_definitions::TemplateHtmlDefinitions,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl TemplateHtmlDefinitions {
+    pub(crate) fn new(
+        inner: crate::template_html_definitions::TemplateHtmlDefinitions,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        TemplateHtmlDefinitions { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::template_html_definitions::TemplateHtmlDefinitions::responsive_html_get_template_definition`].
+    pub fn responsive_html_get_template_definition(
+        &self,
+        account_id: &str,
+        template_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::DocumentHtmlDefinitionOriginals>> {
+        self.runtime.block_on(
+            self.inner
+                .responsive_html_get_template_definition(account_id, template_id),
+        )
+    }
+}
@@ -0,0 +1,60 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::template_locks::TemplateLocks`].
+pub struct TemplateLocks {
+    inner: crate::template_locks::TemplateLocks,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl TemplateLocks {
+    pub(crate) fn new(
+        inner: crate::template_locks::TemplateLocks,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        TemplateLocks { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::template_locks::TemplateLocks::lock_get_template`].
+    pub fn lock_get_template(
+        &self,
+        account_id: &str,
+        template_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::LockInformation>> {
+        self.runtime
+            .block_on(self.inner.lock_get_template(account_id, template_id))
+    }
+    /// The blocking version of [`crate::template_locks::TemplateLocks::lock_put_template`].
+    pub fn lock_put_template(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        body: &crate::types::LockRequest,
+    ) -> ClientResult<crate::Response<crate::types::LockInformation>> {
+        self.runtime
+            .block_on(self.inner.lock_put_template(account_id, template_id, body))
+    }
+    /// The blocking version of [`crate::template_locks::TemplateLocks::lock_post_template`].
+    pub fn lock_post_template(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        body: &crate::types::LockRequest,
+    ) -> ClientResult<crate::Response<crate::types::LockInformation>> {
+        self.runtime
+            .block_on(self.inner.lock_post_template(account_id, template_id, body))
+    }
+    /// The blocking version of [`crate::template_locks::TemplateLocks::lock_delete_template`].
+    pub fn lock_delete_template(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        body: &crate::types::LockRequest,
+    ) -> ClientResult<crate::Response<crate::types::LockInformation>> {
+        self.runtime.block_on(
+            self.inner
+                .lock_delete_template(account_id, template_id, body),
+        )
+    }
+}
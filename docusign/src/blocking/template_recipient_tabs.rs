@@ -0,0 +1,122 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`TemplateRecipientTabs::recipients_get`], set with a builder.
+pub struct TemplateRecipientTabsRecipientsGetRequest<'a> {
+    inner: crate::template_recipient_tabs::TemplateRecipientTabsRecipientsGetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> TemplateRecipientTabsRecipientsGetRequest<'a> {
+    /// Sets the `include_anchor_tab_locations` query parameter.
+    pub fn include_anchor_tab_locations(mut self, include_anchor_tab_locations: &'a str) -> Self {
+        self.inner = self
+            .inner
+            .include_anchor_tab_locations(include_anchor_tab_locations);
+        self
+    }
+
+    /// Sets the `include_metadata` query parameter.
+    pub fn include_metadata(mut self, include_metadata: &'a str) -> Self {
+        self.inner = self.inner.include_metadata(include_metadata);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::Tabs>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::template_recipient_tabs::TemplateRecipientTabs`].
+pub struct TemplateRecipientTabs {
+    inner: crate::template_recipient_tabs::TemplateRecipientTabs,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl TemplateRecipientTabs {
+    pub(crate) fn new(
+        inner: crate::template_recipient_tabs::TemplateRecipientTabs,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        TemplateRecipientTabs { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::template_recipient_tabs::TemplateRecipientTabs::recipients_get`].
+    pub fn recipients_get(
+        &self,
+        account_id: &str,
+        recipient_id: &str,
+        template_id: &str,
+        include_anchor_tab_locations: &str,
+        include_metadata: &str,
+    ) -> ClientResult<crate::Response<crate::types::Tabs>> {
+        self.runtime.block_on(self.inner.recipients_get(
+            account_id,
+            recipient_id,
+            template_id,
+            include_anchor_tab_locations,
+            include_metadata,
+        ))
+    }
+    /// The blocking version of [`crate::template_recipient_tabs::TemplateRecipientTabs::recipients_get_request`].
+    pub fn recipients_get_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        recipient_id: &'a str,
+        template_id: &'a str,
+    ) -> TemplateRecipientTabsRecipientsGetRequest<'a> {
+        TemplateRecipientTabsRecipientsGetRequest {
+            inner: self
+                .inner
+                .recipients_get_request(account_id, recipient_id, template_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::template_recipient_tabs::TemplateRecipientTabs::recipients_put`].
+    pub fn recipients_put(
+        &self,
+        account_id: &str,
+        recipient_id: &str,
+        template_id: &str,
+        body: &crate::types::TemplateTabs,
+    ) -> ClientResult<crate::Response<crate::types::Tabs>> {
+        self.runtime.block_on(self.inner.recipients_put(
+            account_id,
+            recipient_id,
+            template_id,
+            body,
+        ))
+    }
+    /// The blocking version of [`crate::template_recipient_tabs::TemplateRecipientTabs::recipients_post`].
+    pub fn recipients_post(
+        &self,
+        account_id: &str,
+        recipient_id: &str,
+        template_id: &str,
+        body: &crate::types::TemplateTabs,
+    ) -> ClientResult<crate::Response<crate::types::Tabs>> {
+        self.runtime.block_on(self.inner.recipients_post(
+            account_id,
+            recipient_id,
+            template_id,
+            body,
+        ))
+    }
+    /// The blocking version of [`crate::template_recipient_tabs::TemplateRecipientTabs::recipients_delete`].
+    pub fn recipients_delete(
+        &self,
+        account_id: &str,
+        recipient_id: &str,
+        template_id: &str,
+        body: &crate::types::TemplateTabs,
+    ) -> ClientResult<crate::Response<crate::types::Tabs>> {
+        self.runtime.block_on(self.inner.recipients_delete(
+            account_id,
+            recipient_id,
+            template_id,
+            body,
+        ))
+    }
+}
@@ -0,0 +1,215 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`TemplateRecipients::recipients_get_template`], set with a builder.
+pub struct TemplateRecipientsRecipientsGetTemplateRequest<'a> {
+    inner: crate::template_recipients::TemplateRecipientsRecipientsGetTemplateRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> TemplateRecipientsRecipientsGetTemplateRequest<'a> {
+    /// Sets the `include_anchor_tab_locations` query parameter.
+    pub fn include_anchor_tab_locations(mut self, include_anchor_tab_locations: &'a str) -> Self {
+        self.inner = self
+            .inner
+            .include_anchor_tab_locations(include_anchor_tab_locations);
+        self
+    }
+
+    /// Sets the `include_extended` query parameter.
+    pub fn include_extended(mut self, include_extended: &'a str) -> Self {
+        self.inner = self.inner.include_extended(include_extended);
+        self
+    }
+
+    /// Sets the `include_tabs` query parameter.
+    pub fn include_tabs(mut self, include_tabs: &'a str) -> Self {
+        self.inner = self.inner.include_tabs(include_tabs);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::Recipients>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`TemplateRecipients::recipients_put_template`], set with a builder.
+pub struct TemplateRecipientsRecipientsPutTemplateRequest<'a> {
+    inner: crate::template_recipients::TemplateRecipientsRecipientsPutTemplateRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> TemplateRecipientsRecipientsPutTemplateRequest<'a> {
+    /// Sets the `resend_envelope` query parameter.
+    pub fn resend_envelope(mut self, resend_envelope: &'a str) -> Self {
+        self.inner = self.inner.resend_envelope(resend_envelope);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::RecipientsUpdateSummary>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`TemplateRecipients::recipients_post_template`], set with a builder.
+pub struct TemplateRecipientsRecipientsPostTemplateRequest<'a> {
+    inner: crate::template_recipients::TemplateRecipientsRecipientsPostTemplateRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> TemplateRecipientsRecipientsPostTemplateRequest<'a> {
+    /// Sets the `resend_envelope` query parameter.
+    pub fn resend_envelope(mut self, resend_envelope: &'a str) -> Self {
+        self.inner = self.inner.resend_envelope(resend_envelope);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::Recipients>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::template_recipients::TemplateRecipients`].
+pub struct TemplateRecipients {
+    inner: crate::template_recipients::TemplateRecipients,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl TemplateRecipients {
+    pub(crate) fn new(
+        inner: crate::template_recipients::TemplateRecipients,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        TemplateRecipients { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::template_recipients::TemplateRecipients::recipients_get_template`].
+    pub fn recipients_get_template(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        include_anchor_tab_locations: &str,
+        include_extended: &str,
+        include_tabs: &str,
+    ) -> ClientResult<crate::Response<crate::types::Recipients>> {
+        self.runtime.block_on(self.inner.recipients_get_template(
+            account_id,
+            template_id,
+            include_anchor_tab_locations,
+            include_extended,
+            include_tabs,
+        ))
+    }
+    /// The blocking version of [`crate::template_recipients::TemplateRecipients::recipients_get_template_request`].
+    pub fn recipients_get_template_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        template_id: &'a str,
+    ) -> TemplateRecipientsRecipientsGetTemplateRequest<'a> {
+        TemplateRecipientsRecipientsGetTemplateRequest {
+            inner: self
+                .inner
+                .recipients_get_template_request(account_id, template_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::template_recipients::TemplateRecipients::recipients_put_template`].
+    pub fn recipients_put_template(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        resend_envelope: &str,
+        body: &crate::types::TemplateRecipientsData,
+    ) -> ClientResult<crate::Response<crate::types::RecipientsUpdateSummary>> {
+        self.runtime.block_on(self.inner.recipients_put_template(
+            account_id,
+            template_id,
+            resend_envelope,
+            body,
+        ))
+    }
+    /// The blocking version of [`crate::template_recipients::TemplateRecipients::recipients_put_template_request`].
+    pub fn recipients_put_template_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        template_id: &'a str,
+        body: &'a crate::types::TemplateRecipientsData,
+    ) -> TemplateRecipientsRecipientsPutTemplateRequest<'a> {
+        TemplateRecipientsRecipientsPutTemplateRequest {
+            inner: self
+                .inner
+                .recipients_put_template_request(account_id, template_id, body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::template_recipients::TemplateRecipients::recipients_post_template`].
+    pub fn recipients_post_template(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        resend_envelope: &str,
+        body: &crate::types::TemplateRecipientsData,
+    ) -> ClientResult<crate::Response<crate::types::Recipients>> {
+        self.runtime.block_on(self.inner.recipients_post_template(
+            account_id,
+            template_id,
+            resend_envelope,
+            body,
+        ))
+    }
+    /// The blocking version of [`crate::template_recipients::TemplateRecipients::recipients_post_template_request`].
+    pub fn recipients_post_template_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        template_id: &'a str,
+        body: &'a crate::types::TemplateRecipientsData,
+    ) -> TemplateRecipientsRecipientsPostTemplateRequest<'a> {
+        TemplateRecipientsRecipientsPostTemplateRequest {
+            inner: self
+                .inner
+                .recipients_post_template_request(account_id, template_id, body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::template_recipients::TemplateRecipients::recipients_delete_template`].
+    pub fn recipients_delete_template(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        body: &crate::types::TemplateRecipientsData,
+    ) -> ClientResult<crate::Response<crate::types::Recipients>> {
+        self.runtime.block_on(
+            self.inner
+                .recipients_delete_template(account_id, template_id, body),
+        )
+    }
+    /// The blocking version of [`crate::template_recipients::TemplateRecipients::recipients_delete`].
+    pub fn recipients_delete(
+        &self,
+        account_id: &str,
+        recipient_id: &str,
+        template_id: &str,
+        body: &crate::types::TemplateRecipientsData,
+    ) -> ClientResult<crate::Response<crate::types::Recipients>> {
+        self.runtime.block_on(self.inner.recipients_delete(
+            account_id,
+            recipient_id,
+            template_id,
+            body,
+        ))
+    }
+    /// The blocking version of [`crate::template_recipients::TemplateRecipients::views_post_preview`].
+    pub fn views_post_preview(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        body: &crate::types::RecipientPreviewRequest,
+    ) -> ClientResult<crate::Response<crate::types::ViewUrl>> {
+        self.runtime
+            .block_on(self.inner.views_post_preview(account_id, template_id, body))
+    }
+}
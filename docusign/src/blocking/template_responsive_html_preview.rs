@@ -0,0 +1,33 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::template_responsive_html_preview::TemplateResponsiveHtmlPreview`].
+pub struct TemplateResponsiveHtmlPreview {
+    inner: crate::template_responsive_html_preview::TemplateResponsiveHtmlPreview,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl TemplateResponsiveHtmlPreview {
+    pub(crate) fn new(
+        inner: crate::template_responsive_html_preview::TemplateResponsiveHtmlPreview,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        TemplateResponsiveHtmlPreview { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::template_responsive_html_preview::TemplateResponsiveHtmlPreview::responsive_html_post_template_preview`].
+    pub fn responsive_html_post_template_preview(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        body: &crate::types::DocumentHtmlDefinition,
+    ) -> ClientResult<crate::Response<crate::types::DocumentHtmlDefinitions>> {
+        self.runtime
+            .block_on(self.inner.responsive_html_post_template_preview(
+                account_id,
+                template_id,
+                body,
+            ))
+    }
+}
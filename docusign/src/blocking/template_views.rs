@@ -0,0 +1,32 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::template_views::TemplateViews`].
+pub struct TemplateViews {
+    inner: crate::template_views::TemplateViews,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl TemplateViews {
+    pub(crate) fn new(
+        inner: crate::template_views::TemplateViews,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        TemplateViews { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::template_views::TemplateViews::views_post_template_edit_view`].
+    pub fn views_post_template_edit_view(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        body: &crate::types::ReturnUrlRequest,
+    ) -> ClientResult<crate::Response<crate::types::ViewUrl>> {
+        self.runtime
+            .block_on(
+                self.inner
+                    .views_post_template_edit_view(account_id, template_id, body),
+            )
+    }
+}
@@ -0,0 +1,546 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Templates::get`], set with a builder.
+pub struct TemplatesGetRequest<'a> {
+    inner: crate::templates::TemplatesGetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> TemplatesGetRequest<'a> {
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: &'a str) -> Self {
+        self.inner = self.inner.count(count);
+        self
+    }
+
+    /// Sets the `created_from_date` query parameter.
+    pub fn created_from_date(mut self, created_from_date: &'a str) -> Self {
+        self.inner = self.inner.created_from_date(created_from_date);
+        self
+    }
+
+    /// Sets the `created_to_date` query parameter.
+    pub fn created_to_date(mut self, created_to_date: &'a str) -> Self {
+        self.inner = self.inner.created_to_date(created_to_date);
+        self
+    }
+
+    /// Sets the `folder_ids` query parameter.
+    pub fn folder_ids(mut self, folder_ids: &'a str) -> Self {
+        self.inner = self.inner.folder_ids(folder_ids);
+        self
+    }
+
+    /// Sets the `folder_types` query parameter.
+    pub fn folder_types(mut self, folder_types: &'a str) -> Self {
+        self.inner = self.inner.folder_types(folder_types);
+        self
+    }
+
+    /// Sets the `from_date` query parameter.
+    pub fn from_date(mut self, from_date: &'a str) -> Self {
+        self.inner = self.inner.from_date(from_date);
+        self
+    }
+
+    /// Sets the `include` query parameter.
+    pub fn include(mut self, include: &'a str) -> Self {
+        self.inner = self.inner.include(include);
+        self
+    }
+
+    /// Sets the `is_deleted_template_only` query parameter.
+    pub fn is_deleted_template_only(mut self, is_deleted_template_only: &'a str) -> Self {
+        self.inner = self
+            .inner
+            .is_deleted_template_only(is_deleted_template_only);
+        self
+    }
+
+    /// Sets the `is_download` query parameter.
+    pub fn is_download(mut self, is_download: &'a str) -> Self {
+        self.inner = self.inner.is_download(is_download);
+        self
+    }
+
+    /// Sets the `modified_from_date` query parameter.
+    pub fn modified_from_date(mut self, modified_from_date: &'a str) -> Self {
+        self.inner = self.inner.modified_from_date(modified_from_date);
+        self
+    }
+
+    /// Sets the `modified_to_date` query parameter.
+    pub fn modified_to_date(mut self, modified_to_date: &'a str) -> Self {
+        self.inner = self.inner.modified_to_date(modified_to_date);
+        self
+    }
+
+    /// Sets the `order` query parameter.
+    pub fn order(mut self, order: &'a str) -> Self {
+        self.inner = self.inner.order(order);
+        self
+    }
+
+    /// Sets the `order_by` query parameter.
+    pub fn order_by(mut self, order_by: &'a str) -> Self {
+        self.inner = self.inner.order_by(order_by);
+        self
+    }
+
+    /// Sets the `search_fields` query parameter.
+    pub fn search_fields(mut self, search_fields: &'a str) -> Self {
+        self.inner = self.inner.search_fields(search_fields);
+        self
+    }
+
+    /// Sets the `search_text` query parameter.
+    pub fn search_text(mut self, search_text: &'a str) -> Self {
+        self.inner = self.inner.search_text(search_text);
+        self
+    }
+
+    /// Sets the `shared_by_me` query parameter.
+    pub fn shared_by_me(mut self, shared_by_me: &'a str) -> Self {
+        self.inner = self.inner.shared_by_me(shared_by_me);
+        self
+    }
+
+    /// Sets the `start_position` query parameter.
+    pub fn start_position(mut self, start_position: &'a str) -> Self {
+        self.inner = self.inner.start_position(start_position);
+        self
+    }
+
+    /// Sets the `template_ids` query parameter.
+    pub fn template_ids(mut self, template_ids: &'a str) -> Self {
+        self.inner = self.inner.template_ids(template_ids);
+        self
+    }
+
+    /// Sets the `to_date` query parameter.
+    pub fn to_date(mut self, to_date: &'a str) -> Self {
+        self.inner = self.inner.to_date(to_date);
+        self
+    }
+
+    /// Sets the `used_from_date` query parameter.
+    pub fn used_from_date(mut self, used_from_date: &'a str) -> Self {
+        self.inner = self.inner.used_from_date(used_from_date);
+        self
+    }
+
+    /// Sets the `used_to_date` query parameter.
+    pub fn used_to_date(mut self, used_to_date: &'a str) -> Self {
+        self.inner = self.inner.used_to_date(used_to_date);
+        self
+    }
+
+    /// Sets the `user_filter` query parameter.
+    pub fn user_filter(mut self, user_filter: &'a str) -> Self {
+        self.inner = self.inner.user_filter(user_filter);
+        self
+    }
+
+    /// Sets the `user_id` query parameter.
+    pub fn user_id(mut self, user_id: &'a str) -> Self {
+        self.inner = self.inner.user_id(user_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::EnvelopeTemplateResults>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Templates::get_templates`], set with a builder.
+pub struct TemplatesGetTemplatesRequest<'a> {
+    inner: crate::templates::TemplatesGetTemplatesRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> TemplatesGetTemplatesRequest<'a> {
+    /// Sets the `include` query parameter.
+    pub fn include(mut self, include: &'a str) -> Self {
+        self.inner = self.inner.include(include);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::EnvelopeTemplate>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Templates::pages_get_page_image`], set with a builder.
+pub struct TemplatesPagesGetPageImageRequest<'a> {
+    inner: crate::templates::TemplatesPagesGetPageImageRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> TemplatesPagesGetPageImageRequest<'a> {
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: &'a str) -> Self {
+        self.inner = self.inner.count(count);
+        self
+    }
+
+    /// Sets the `dpi` query parameter.
+    pub fn dpi(mut self, dpi: &'a str) -> Self {
+        self.inner = self.inner.dpi(dpi);
+        self
+    }
+
+    /// Sets the `max_height` query parameter.
+    pub fn max_height(mut self, max_height: &'a str) -> Self {
+        self.inner = self.inner.max_height(max_height);
+        self
+    }
+
+    /// Sets the `max_width` query parameter.
+    pub fn max_width(mut self, max_width: &'a str) -> Self {
+        self.inner = self.inner.max_width(max_width);
+        self
+    }
+
+    /// Sets the `nocache` query parameter.
+    pub fn nocache(mut self, nocache: &'a str) -> Self {
+        self.inner = self.inner.nocache(nocache);
+        self
+    }
+
+    /// Sets the `show_changes` query parameter.
+    pub fn show_changes(mut self, show_changes: &'a str) -> Self {
+        self.inner = self.inner.show_changes(show_changes);
+        self
+    }
+
+    /// Sets the `start_position` query parameter.
+    pub fn start_position(mut self, start_position: &'a str) -> Self {
+        self.inner = self.inner.start_position(start_position);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::PageImages>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Templates::pages_get_page_image_templates`], set with a builder.
+pub struct TemplatesPagesGetPageImageTemplatesRequest<'a> {
+    inner: crate::templates::TemplatesPagesGetPageImageTemplatesRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> TemplatesPagesGetPageImageTemplatesRequest<'a> {
+    /// Sets the `dpi` query parameter.
+    pub fn dpi(mut self, dpi: &'a str) -> Self {
+        self.inner = self.inner.dpi(dpi);
+        self
+    }
+
+    /// Sets the `max_height` query parameter.
+    pub fn max_height(mut self, max_height: &'a str) -> Self {
+        self.inner = self.inner.max_height(max_height);
+        self
+    }
+
+    /// Sets the `max_width` query parameter.
+    pub fn max_width(mut self, max_width: &'a str) -> Self {
+        self.inner = self.inner.max_width(max_width);
+        self
+    }
+
+    /// Sets the `show_changes` query parameter.
+    pub fn show_changes(mut self, show_changes: &'a str) -> Self {
+        self.inner = self.inner.show_changes(show_changes);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::templates::Templates`].
+pub struct Templates {
+    inner: crate::templates::Templates,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Templates {
+    pub(crate) fn new(
+        inner: crate::templates::Templates,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Templates { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::templates::Templates::get`].
+    pub fn get(
+        &self,
+        account_id: &str,
+        count: &str,
+        created_from_date: &str,
+        created_to_date: &str,
+        folder_ids: &str,
+        folder_types: &str,
+        from_date: &str,
+        include: &str,
+        is_deleted_template_only: &str,
+        is_download: &str,
+        modified_from_date: &str,
+        modified_to_date: &str,
+        order: &str,
+        order_by: &str,
+        search_fields: &str,
+        search_text: &str,
+        shared_by_me: &str,
+        start_position: &str,
+        template_ids: &str,
+        to_date: &str,
+        used_from_date: &str,
+        used_to_date: &str,
+        user_filter: &str,
+        user_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::EnvelopeTemplateResults>> {
+        self.runtime.block_on(self.inner.get(
+            account_id,
+            count,
+            created_from_date,
+            created_to_date,
+            folder_ids,
+            folder_types,
+            from_date,
+            include,
+            is_deleted_template_only,
+            is_download,
+            modified_from_date,
+            modified_to_date,
+            order,
+            order_by,
+            search_fields,
+            search_text,
+            shared_by_me,
+            start_position,
+            template_ids,
+            to_date,
+            used_from_date,
+            used_to_date,
+            user_filter,
+            user_id,
+        ))
+    }
+    /// The blocking version of [`crate::templates::Templates::get_request`].
+    pub fn get_request<'a>(&'a self, account_id: &'a str) -> TemplatesGetRequest<'a> {
+        TemplatesGetRequest {
+            inner: self.inner.get_request(account_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::templates::Templates::post`].
+    pub fn post(
+        &self,
+        account_id: &str,
+        body: &crate::types::EnvelopeTemplate,
+    ) -> ClientResult<crate::Response<crate::types::TemplateSummary>> {
+        self.runtime.block_on(self.inner.post(account_id, body))
+    }
+    /// The blocking version of [`crate::templates::Templates::get_templates`].
+    pub fn get_templates(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        include: &str,
+    ) -> ClientResult<crate::Response<crate::types::EnvelopeTemplate>> {
+        self.runtime
+            .block_on(self.inner.get_templates(account_id, template_id, include))
+    }
+    /// The blocking version of [`crate::templates::Templates::get_templates_request`].
+    pub fn get_templates_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        template_id: &'a str,
+    ) -> TemplatesGetTemplatesRequest<'a> {
+        TemplatesGetTemplatesRequest {
+            inner: self.inner.get_templates_request(account_id, template_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::templates::Templates::put`].
+    pub fn put(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        body: &crate::types::EnvelopeTemplate,
+    ) -> ClientResult<crate::Response<crate::types::TemplateUpdateSummary>> {
+        self.runtime
+            .block_on(self.inner.put(account_id, template_id, body))
+    }
+    /// The blocking version of [`crate::templates::Templates::pages_get_page_image`].
+    pub fn pages_get_page_image(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+        count: &str,
+        dpi: &str,
+        max_height: &str,
+        max_width: &str,
+        nocache: &str,
+        show_changes: &str,
+        start_position: &str,
+    ) -> ClientResult<crate::Response<crate::types::PageImages>> {
+        self.runtime.block_on(self.inner.pages_get_page_image(
+            account_id,
+            document_id,
+            template_id,
+            count,
+            dpi,
+            max_height,
+            max_width,
+            nocache,
+            show_changes,
+            start_position,
+        ))
+    }
+    /// The blocking version of [`crate::templates::Templates::pages_get_page_image_request`].
+    pub fn pages_get_page_image_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        document_id: &'a str,
+        template_id: &'a str,
+    ) -> TemplatesPagesGetPageImageRequest<'a> {
+        TemplatesPagesGetPageImageRequest {
+            inner: self
+                .inner
+                .pages_get_page_image_request(account_id, document_id, template_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::templates::Templates::pages_delete_page`].
+    pub fn pages_delete_page(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        page_number: &str,
+        template_id: &str,
+        body: &crate::types::PageRequest,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.pages_delete_page(
+            account_id,
+            document_id,
+            page_number,
+            template_id,
+            body,
+        ))
+    }
+    /// The blocking version of [`crate::templates::Templates::pages_get_page_image_templates`].
+    pub fn pages_get_page_image_templates(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        page_number: &str,
+        template_id: &str,
+        dpi: &str,
+        max_height: &str,
+        max_width: &str,
+        show_changes: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.pages_get_page_image_templates(
+                account_id,
+                document_id,
+                page_number,
+                template_id,
+                dpi,
+                max_height,
+                max_width,
+                show_changes,
+            ))
+    }
+    /// The blocking version of [`crate::templates::Templates::pages_get_page_image_templates_request`].
+    pub fn pages_get_page_image_templates_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        document_id: &'a str,
+        page_number: &'a str,
+        template_id: &'a str,
+    ) -> TemplatesPagesGetPageImageTemplatesRequest<'a> {
+        TemplatesPagesGetPageImageTemplatesRequest {
+            inner: self.inner.pages_get_page_image_templates_request(
+                account_id,
+                document_id,
+                page_number,
+                template_id,
+            ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::templates::Templates::pages_put_page_image`].
+    pub fn pages_put_page_image(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        page_number: &str,
+        template_id: &str,
+        body: &crate::types::PageRequest,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.pages_put_page_image(
+            account_id,
+            document_id,
+            page_number,
+            template_id,
+            body,
+        ))
+    }
+    /// The blocking version of [`crate::templates::Templates::notification_get`].
+    pub fn notification_get(
+        &self,
+        account_id: &str,
+        template_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Notification>> {
+        self.runtime
+            .block_on(self.inner.notification_get(account_id, template_id))
+    }
+    /// The blocking version of [`crate::templates::Templates::notification_put`].
+    pub fn notification_put(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        body: &crate::types::TemplateNotificationRequest,
+    ) -> ClientResult<crate::Response<crate::types::Notification>> {
+        self.runtime
+            .block_on(self.inner.notification_put(account_id, template_id, body))
+    }
+    /// The blocking version of [`crate::templates::Templates::put_part`].
+    pub fn put_part(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        template_part: &str,
+        body: &crate::types::GroupInformation,
+    ) -> ClientResult<crate::Response<crate::types::GroupInformation>> {
+        self.runtime.block_on(
+            self.inner
+                .put_part(account_id, template_id, template_part, body),
+        )
+    }
+    /// The blocking version of [`crate::templates::Templates::delete_part`].
+    pub fn delete_part(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        template_part: &str,
+        body: &crate::types::GroupInformation,
+    ) -> ClientResult<crate::Response<crate::types::GroupInformation>> {
+        self.runtime.block_on(
+            self.inner
+                .delete_part(account_id, template_id, template_part, body),
+        )
+    }
+}
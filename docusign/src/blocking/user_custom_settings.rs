@@ -0,0 +1,47 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::user_custom_settings::UserCustomSettings`].
+pub struct UserCustomSettings {
+    inner: crate::user_custom_settings::UserCustomSettings,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl UserCustomSettings {
+    pub(crate) fn new(
+        inner: crate::user_custom_settings::UserCustomSettings,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        UserCustomSettings { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::user_custom_settings::UserCustomSettings::get`].
+    pub fn get(
+        &self,
+        account_id: &str,
+        user_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::CustomSettingsInformation>> {
+        self.runtime.block_on(self.inner.get(account_id, user_id))
+    }
+    /// The blocking version of [`crate::user_custom_settings::UserCustomSettings::put`].
+    pub fn put(
+        &self,
+        account_id: &str,
+        user_id: &str,
+        body: &crate::types::CustomSettingsInformation,
+    ) -> ClientResult<crate::Response<crate::types::CustomSettingsInformation>> {
+        self.runtime
+            .block_on(self.inner.put(account_id, user_id, body))
+    }
+    /// The blocking version of [`crate::user_custom_settings::UserCustomSettings::delete`].
+    pub fn delete(
+        &self,
+        account_id: &str,
+        user_id: &str,
+        body: &crate::types::CustomSettingsInformation,
+    ) -> ClientResult<crate::Response<crate::types::CustomSettingsInformation>> {
+        self.runtime
+            .block_on(self.inner.delete(account_id, user_id, body))
+    }
+}
@@ -0,0 +1,37 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::user_profiles::UserProfiles`].
+pub struct UserProfiles {
+    inner: crate::user_profiles::UserProfiles,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl UserProfiles {
+    pub(crate) fn new(
+        inner: crate::user_profiles::UserProfiles,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        UserProfiles { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::user_profiles::UserProfiles::get`].
+    pub fn get(
+        &self,
+        account_id: &str,
+        user_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::UserProfile>> {
+        self.runtime.block_on(self.inner.get(account_id, user_id))
+    }
+    /// The blocking version of [`crate::user_profiles::UserProfiles::put`].
+    pub fn put(
+        &self,
+        account_id: &str,
+        user_id: &str,
+        body: &crate::types::UserProfile,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.put(account_id, user_id, body))
+    }
+}
@@ -0,0 +1,281 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`UserSignatures::get`], set with a builder.
+pub struct UserSignaturesGetRequest<'a> {
+    inner: crate::user_signatures::UserSignaturesGetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> UserSignaturesGetRequest<'a> {
+    /// Sets the `stamp_type` query parameter.
+    pub fn stamp_type(mut self, stamp_type: &'a str) -> Self {
+        self.inner = self.inner.stamp_type(stamp_type);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::UserSignaturesInformation>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`UserSignatures::put_signature_user_signatures`], set with a builder.
+pub struct UserSignaturesPutSignatureUserSignaturesRequest<'a> {
+    inner: crate::user_signatures::UserSignaturesPutSignatureUserSignaturesRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> UserSignaturesPutSignatureUserSignaturesRequest<'a> {
+    /// Sets the `close_existing_signature` query parameter.
+    pub fn close_existing_signature(mut self, close_existing_signature: &'a str) -> Self {
+        self.inner = self
+            .inner
+            .close_existing_signature(close_existing_signature);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::UserSignature>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`UserSignatures::get_signature_image`], set with a builder.
+pub struct UserSignaturesGetSignatureImageRequest<'a> {
+    inner: crate::user_signatures::UserSignaturesGetSignatureImageRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> UserSignaturesGetSignatureImageRequest<'a> {
+    /// Sets the `include_chrome` query parameter.
+    pub fn include_chrome(mut self, include_chrome: &'a str) -> Self {
+        self.inner = self.inner.include_chrome(include_chrome);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`UserSignatures::put_signature_image`], set with a builder.
+pub struct UserSignaturesPutSignatureImageRequest<'a> {
+    inner: crate::user_signatures::UserSignaturesPutSignatureImageRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> UserSignaturesPutSignatureImageRequest<'a> {
+    /// Sets the `transparent_png` query parameter.
+    pub fn transparent_png(mut self, transparent_png: &'a str) -> Self {
+        self.inner = self.inner.transparent_png(transparent_png);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::UserSignature>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::user_signatures::UserSignatures`].
+pub struct UserSignatures {
+    inner: crate::user_signatures::UserSignatures,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl UserSignatures {
+    pub(crate) fn new(
+        inner: crate::user_signatures::UserSignatures,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        UserSignatures { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::user_signatures::UserSignatures::get`].
+    pub fn get(
+        &self,
+        account_id: &str,
+        user_id: &str,
+        stamp_type: &str,
+    ) -> ClientResult<crate::Response<crate::types::UserSignaturesInformation>> {
+        self.runtime
+            .block_on(self.inner.get(account_id, user_id, stamp_type))
+    }
+    /// The blocking version of [`crate::user_signatures::UserSignatures::get_request`].
+    pub fn get_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        user_id: &'a str,
+    ) -> UserSignaturesGetRequest<'a> {
+        UserSignaturesGetRequest {
+            inner: self.inner.get_request(account_id, user_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::user_signatures::UserSignatures::put_signature`].
+    pub fn put_signature(
+        &self,
+        account_id: &str,
+        user_id: &str,
+        body: &crate::types::UserSignaturesInformation,
+    ) -> ClientResult<crate::Response<crate::types::UserSignaturesInformation>> {
+        self.runtime
+            .block_on(self.inner.put_signature(account_id, user_id, body))
+    }
+    /// The blocking version of [`crate::user_signatures::UserSignatures::post`].
+    pub fn post(
+        &self,
+        account_id: &str,
+        user_id: &str,
+        body: &crate::types::UserSignaturesInformation,
+    ) -> ClientResult<crate::Response<crate::types::UserSignaturesInformation>> {
+        self.runtime
+            .block_on(self.inner.post(account_id, user_id, body))
+    }
+    /// The blocking version of [`crate::user_signatures::UserSignatures::get_signature`].
+    pub fn get_signature(
+        &self,
+        account_id: &str,
+        signature_id: &str,
+        user_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::UserSignature>> {
+        self.runtime
+            .block_on(self.inner.get_signature(account_id, signature_id, user_id))
+    }
+    /// The blocking version of [`crate::user_signatures::UserSignatures::put_signature_user_signatures`].
+    pub fn put_signature_user_signatures(
+        &self,
+        account_id: &str,
+        signature_id: &str,
+        user_id: &str,
+        close_existing_signature: &str,
+        body: &crate::types::UserSignatureDefinition,
+    ) -> ClientResult<crate::Response<crate::types::UserSignature>> {
+        self.runtime
+            .block_on(self.inner.put_signature_user_signatures(
+                account_id,
+                signature_id,
+                user_id,
+                close_existing_signature,
+                body,
+            ))
+    }
+    /// The blocking version of [`crate::user_signatures::UserSignatures::put_signature_user_signatures_request`].
+    pub fn put_signature_user_signatures_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        signature_id: &'a str,
+        user_id: &'a str,
+        body: &'a crate::types::UserSignatureDefinition,
+    ) -> UserSignaturesPutSignatureUserSignaturesRequest<'a> {
+        UserSignaturesPutSignatureUserSignaturesRequest {
+            inner: self.inner.put_signature_user_signatures_request(
+                account_id,
+                signature_id,
+                user_id,
+                body,
+            ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::user_signatures::UserSignatures::delete_signature`].
+    pub fn delete_signature(
+        &self,
+        account_id: &str,
+        signature_id: &str,
+        user_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .delete_signature(account_id, signature_id, user_id),
+        )
+    }
+    /// The blocking version of [`crate::user_signatures::UserSignatures::get_signature_image`].
+    pub fn get_signature_image(
+        &self,
+        account_id: &str,
+        image_type: &str,
+        signature_id: &str,
+        user_id: &str,
+        include_chrome: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.get_signature_image(
+            account_id,
+            image_type,
+            signature_id,
+            user_id,
+            include_chrome,
+        ))
+    }
+    /// The blocking version of [`crate::user_signatures::UserSignatures::get_signature_image_request`].
+    pub fn get_signature_image_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        image_type: &'a str,
+        signature_id: &'a str,
+        user_id: &'a str,
+    ) -> UserSignaturesGetSignatureImageRequest<'a> {
+        UserSignaturesGetSignatureImageRequest {
+            inner: self.inner.get_signature_image_request(
+                account_id,
+                image_type,
+                signature_id,
+                user_id,
+            ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::user_signatures::UserSignatures::put_signature_image`].
+    pub fn put_signature_image(
+        &self,
+        account_id: &str,
+        image_type: &str,
+        signature_id: &str,
+        user_id: &str,
+        transparent_png: &str,
+    ) -> ClientResult<crate::Response<crate::types::UserSignature>> {
+        self.runtime.block_on(self.inner.put_signature_image(
+            account_id,
+            image_type,
+            signature_id,
+            user_id,
+            transparent_png,
+        ))
+    }
+    /// The blocking version of [`crate::user_signatures::UserSignatures::put_signature_image_request`].
+    pub fn put_signature_image_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        image_type: &'a str,
+        signature_id: &'a str,
+        user_id: &'a str,
+    ) -> UserSignaturesPutSignatureImageRequest<'a> {
+        UserSignaturesPutSignatureImageRequest {
+            inner: self.inner.put_signature_image_request(
+                account_id,
+                image_type,
+                signature_id,
+                user_id,
+            ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::user_signatures::UserSignatures::delete_signature_image`].
+    pub fn delete_signature_image(
+        &self,
+        account_id: &str,
+        image_type: &str,
+        signature_id: &str,
+        user_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::UserSignature>> {
+        self.runtime.block_on(self.inner.delete_signature_image(
+            account_id,
+            image_type,
+            signature_id,
+            user_id,
+        ))
+    }
+}
@@ -0,0 +1,327 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Users::get`], set with a builder.
+pub struct UsersGetRequest<'a> {
+    inner: crate::users::UsersGetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> UsersGetRequest<'a> {
+    /// Sets the `additional_info` query parameter.
+    pub fn additional_info(mut self, additional_info: &'a str) -> Self {
+        self.inner = self.inner.additional_info(additional_info);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: &'a str) -> Self {
+        self.inner = self.inner.count(count);
+        self
+    }
+
+    /// Sets the `email` query parameter.
+    pub fn email(mut self, email: &'a str) -> Self {
+        self.inner = self.inner.email(email);
+        self
+    }
+
+    /// Sets the `email_substring` query parameter.
+    pub fn email_substring(mut self, email_substring: &'a str) -> Self {
+        self.inner = self.inner.email_substring(email_substring);
+        self
+    }
+
+    /// Sets the `group_id` query parameter.
+    pub fn group_id(mut self, group_id: &'a str) -> Self {
+        self.inner = self.inner.group_id(group_id);
+        self
+    }
+
+    /// Sets the `include_usersettings_for_csv` query parameter.
+    pub fn include_usersettings_for_csv(mut self, include_usersettings_for_csv: &'a str) -> Self {
+        self.inner = self
+            .inner
+            .include_usersettings_for_csv(include_usersettings_for_csv);
+        self
+    }
+
+    /// Sets the `login_status` query parameter.
+    pub fn login_status(mut self, login_status: &'a str) -> Self {
+        self.inner = self.inner.login_status(login_status);
+        self
+    }
+
+    /// Sets the `not_group_id` query parameter.
+    pub fn not_group_id(mut self, not_group_id: &'a str) -> Self {
+        self.inner = self.inner.not_group_id(not_group_id);
+        self
+    }
+
+    /// Sets the `start_position` query parameter.
+    pub fn start_position(mut self, start_position: &'a str) -> Self {
+        self.inner = self.inner.start_position(start_position);
+        self
+    }
+
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: &'a str) -> Self {
+        self.inner = self.inner.status(status);
+        self
+    }
+
+    /// Sets the `user_name_substring` query parameter.
+    pub fn user_name_substring(mut self, user_name_substring: &'a str) -> Self {
+        self.inner = self.inner.user_name_substring(user_name_substring);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::UserInformationList>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Users::delete`], set with a builder.
+pub struct UsersDeleteRequest<'a> {
+    inner: crate::users::UsersDeleteRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> UsersDeleteRequest<'a> {
+    /// Sets the `delete` query parameter.
+    pub fn delete(mut self, delete: &'a str) -> Self {
+        self.inner = self.inner.delete(delete);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::UsersResponse>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Users::get_users`], set with a builder.
+pub struct UsersGetUsersRequest<'a> {
+    inner: crate::users::UsersGetUsersRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> UsersGetUsersRequest<'a> {
+    /// Sets the `additional_info` query parameter.
+    pub fn additional_info(mut self, additional_info: &'a str) -> Self {
+        self.inner = self.inner.additional_info(additional_info);
+        self
+    }
+
+    /// Sets the `email` query parameter.
+    pub fn email(mut self, email: &'a str) -> Self {
+        self.inner = self.inner.email(email);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::UserInformation>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Users::profile_image_get`], set with a builder.
+pub struct UsersProfileImageGetRequest<'a> {
+    inner: crate::users::UsersProfileImageGetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> UsersProfileImageGetRequest<'a> {
+    /// Sets the `encoding` query parameter.
+    pub fn encoding(mut self, encoding: &'a str) -> Self {
+        self.inner = self.inner.encoding(encoding);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::users::Users`].
+pub struct Users {
+    inner: crate::users::Users,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Users {
+    pub(crate) fn new(inner: crate::users::Users, runtime: Arc<tokio::runtime::Runtime>) -> Self {
+        Users { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::users::Users::get`].
+    pub fn get(
+        &self,
+        account_id: &str,
+        additional_info: &str,
+        count: &str,
+        email: &str,
+        email_substring: &str,
+        group_id: &str,
+        include_usersettings_for_csv: &str,
+        login_status: &str,
+        not_group_id: &str,
+        start_position: &str,
+        status: &str,
+        user_name_substring: &str,
+    ) -> ClientResult<crate::Response<crate::types::UserInformationList>> {
+        self.runtime.block_on(self.inner.get(
+            account_id,
+            additional_info,
+            count,
+            email,
+            email_substring,
+            group_id,
+            include_usersettings_for_csv,
+            login_status,
+            not_group_id,
+            start_position,
+            status,
+            user_name_substring,
+        ))
+    }
+    /// The blocking version of [`crate::users::Users::get_request`].
+    pub fn get_request<'a>(&'a self, account_id: &'a str) -> UsersGetRequest<'a> {
+        UsersGetRequest {
+            inner: self.inner.get_request(account_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::users::Users::put`].
+    pub fn put(
+        &self,
+        account_id: &str,
+        body: &crate::types::UserInformationList,
+    ) -> ClientResult<crate::Response<crate::types::UserInformationList>> {
+        self.runtime.block_on(self.inner.put(account_id, body))
+    }
+    /// The blocking version of [`crate::users::Users::post`].
+    pub fn post(
+        &self,
+        account_id: &str,
+        body: &crate::types::NewUsersDefinition,
+    ) -> ClientResult<crate::Response<crate::types::NewUsersSummary>> {
+        self.runtime.block_on(self.inner.post(account_id, body))
+    }
+    /// The blocking version of [`crate::users::Users::delete`].
+    pub fn delete(
+        &self,
+        account_id: &str,
+        delete: &str,
+        body: &crate::types::UserInfoList,
+    ) -> ClientResult<crate::Response<crate::types::UsersResponse>> {
+        self.runtime
+            .block_on(self.inner.delete(account_id, delete, body))
+    }
+    /// The blocking version of [`crate::users::Users::delete_request`].
+    pub fn delete_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        body: &'a crate::types::UserInfoList,
+    ) -> UsersDeleteRequest<'a> {
+        UsersDeleteRequest {
+            inner: self.inner.delete_request(account_id, body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::users::Users::get_users`].
+    pub fn get_users(
+        &self,
+        account_id: &str,
+        user_id: &str,
+        additional_info: &str,
+        email: &str,
+    ) -> ClientResult<crate::Response<crate::types::UserInformation>> {
+        self.runtime.block_on(
+            self.inner
+                .get_users(account_id, user_id, additional_info, email),
+        )
+    }
+    /// The blocking version of [`crate::users::Users::get_users_request`].
+    pub fn get_users_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        user_id: &'a str,
+    ) -> UsersGetUsersRequest<'a> {
+        UsersGetUsersRequest {
+            inner: self.inner.get_users_request(account_id, user_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::users::Users::put_users`].
+    pub fn put_users(
+        &self,
+        account_id: &str,
+        user_id: &str,
+        body: &crate::types::UserInformation,
+    ) -> ClientResult<crate::Response<crate::types::UserInformation>> {
+        self.runtime
+            .block_on(self.inner.put_users(account_id, user_id, body))
+    }
+    /// The blocking version of [`crate::users::Users::profile_image_get`].
+    pub fn profile_image_get(
+        &self,
+        account_id: &str,
+        user_id: &str,
+        encoding: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.profile_image_get(account_id, user_id, encoding))
+    }
+    /// The blocking version of [`crate::users::Users::profile_image_get_request`].
+    pub fn profile_image_get_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        user_id: &'a str,
+    ) -> UsersProfileImageGetRequest<'a> {
+        UsersProfileImageGetRequest {
+            inner: self.inner.profile_image_get_request(account_id, user_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::users::Users::profile_image_put`].
+    pub fn profile_image_put(
+        &self,
+        account_id: &str,
+        user_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.profile_image_put(account_id, user_id))
+    }
+    /// The blocking version of [`crate::users::Users::profile_image_delete`].
+    pub fn profile_image_delete(
+        &self,
+        account_id: &str,
+        user_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.profile_image_delete(account_id, user_id))
+    }
+    /// The blocking version of [`crate::users::Users::settings_get`].
+    pub fn settings_get(
+        &self,
+        account_id: &str,
+        user_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::UserSettingsInformation>> {
+        self.runtime
+            .block_on(self.inner.settings_get(account_id, user_id))
+    }
+    /// The blocking version of [`crate::users::Users::settings_put`].
+    pub fn settings_put(
+        &self,
+        account_id: &str,
+        user_id: &str,
+        body: &crate::types::UserSettingsInformation,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.settings_put(account_id, user_id, body))
+    }
+}
@@ -0,0 +1,307 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`WorkspaceItems::workspace_folder_get`], set with a builder.
+pub struct WorkspaceItemsWorkspaceFolderGetRequest<'a> {
+    inner: crate::workspace_items::WorkspaceItemsWorkspaceFolderGetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> WorkspaceItemsWorkspaceFolderGetRequest<'a> {
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: &'a str) -> Self {
+        self.inner = self.inner.count(count);
+        self
+    }
+
+    /// Sets the `include_files` query parameter.
+    pub fn include_files(mut self, include_files: &'a str) -> Self {
+        self.inner = self.inner.include_files(include_files);
+        self
+    }
+
+    /// Sets the `include_sub_folders` query parameter.
+    pub fn include_sub_folders(mut self, include_sub_folders: &'a str) -> Self {
+        self.inner = self.inner.include_sub_folders(include_sub_folders);
+        self
+    }
+
+    /// Sets the `include_thumbnails` query parameter.
+    pub fn include_thumbnails(mut self, include_thumbnails: &'a str) -> Self {
+        self.inner = self.inner.include_thumbnails(include_thumbnails);
+        self
+    }
+
+    /// Sets the `include_user_detail` query parameter.
+    pub fn include_user_detail(mut self, include_user_detail: &'a str) -> Self {
+        self.inner = self.inner.include_user_detail(include_user_detail);
+        self
+    }
+
+    /// Sets the `start_position` query parameter.
+    pub fn start_position(mut self, start_position: &'a str) -> Self {
+        self.inner = self.inner.start_position(start_position);
+        self
+    }
+
+    /// Sets the `workspace_user_id` query parameter.
+    pub fn workspace_user_id(mut self, workspace_user_id: &'a str) -> Self {
+        self.inner = self.inner.workspace_user_id(workspace_user_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::WorkspaceFolderContents>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`WorkspaceItems::workspace_file_get`], set with a builder.
+pub struct WorkspaceItemsWorkspaceFileGetRequest<'a> {
+    inner: crate::workspace_items::WorkspaceItemsWorkspaceFileGetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> WorkspaceItemsWorkspaceFileGetRequest<'a> {
+    /// Sets the `is_download` query parameter.
+    pub fn is_download(mut self, is_download: &'a str) -> Self {
+        self.inner = self.inner.is_download(is_download);
+        self
+    }
+
+    /// Sets the `pdf_version` query parameter.
+    pub fn pdf_version(mut self, pdf_version: &'a str) -> Self {
+        self.inner = self.inner.pdf_version(pdf_version);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`WorkspaceItems::workspace_file_pages_get`], set with a builder.
+pub struct WorkspaceItemsWorkspaceFilePagesGetRequest<'a> {
+    inner: crate::workspace_items::WorkspaceItemsWorkspaceFilePagesGetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> WorkspaceItemsWorkspaceFilePagesGetRequest<'a> {
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: &'a str) -> Self {
+        self.inner = self.inner.count(count);
+        self
+    }
+
+    /// Sets the `dpi` query parameter.
+    pub fn dpi(mut self, dpi: &'a str) -> Self {
+        self.inner = self.inner.dpi(dpi);
+        self
+    }
+
+    /// Sets the `max_height` query parameter.
+    pub fn max_height(mut self, max_height: &'a str) -> Self {
+        self.inner = self.inner.max_height(max_height);
+        self
+    }
+
+    /// Sets the `max_width` query parameter.
+    pub fn max_width(mut self, max_width: &'a str) -> Self {
+        self.inner = self.inner.max_width(max_width);
+        self
+    }
+
+    /// Sets the `start_position` query parameter.
+    pub fn start_position(mut self, start_position: &'a str) -> Self {
+        self.inner = self.inner.start_position(start_position);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::PageImages>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::workspace_items::WorkspaceItems`].
+pub struct WorkspaceItems {
+    inner: crate::workspace_items::WorkspaceItems,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl WorkspaceItems {
+    pub(crate) fn new(
+        inner: crate::workspace_items::WorkspaceItems,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        WorkspaceItems { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::workspace_items::WorkspaceItems::workspace_folder_get`].
+    pub fn workspace_folder_get(
+        &self,
+        account_id: &str,
+        folder_id: &str,
+        workspace_id: &str,
+        count: &str,
+        include_files: &str,
+        include_sub_folders: &str,
+        include_thumbnails: &str,
+        include_user_detail: &str,
+        start_position: &str,
+        workspace_user_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::WorkspaceFolderContents>> {
+        self.runtime.block_on(self.inner.workspace_folder_get(
+            account_id,
+            folder_id,
+            workspace_id,
+            count,
+            include_files,
+            include_sub_folders,
+            include_thumbnails,
+            include_user_detail,
+            start_position,
+            workspace_user_id,
+        ))
+    }
+    /// The blocking version of [`crate::workspace_items::WorkspaceItems::workspace_folder_get_request`].
+    pub fn workspace_folder_get_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        folder_id: &'a str,
+        workspace_id: &'a str,
+    ) -> WorkspaceItemsWorkspaceFolderGetRequest<'a> {
+        WorkspaceItemsWorkspaceFolderGetRequest {
+            inner: self
+                .inner
+                .workspace_folder_get_request(account_id, folder_id, workspace_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::workspace_items::WorkspaceItems::workspace_folder_delete_items`].
+    pub fn workspace_folder_delete_items(
+        &self,
+        account_id: &str,
+        folder_id: &str,
+        workspace_id: &str,
+        body: &crate::types::WorkspaceItemList,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.workspace_folder_delete_items(
+                account_id,
+                folder_id,
+                workspace_id,
+                body,
+            ))
+    }
+    /// The blocking version of [`crate::workspace_items::WorkspaceItems::workspace_file_post_files`].
+    pub fn workspace_file_post_files(
+        &self,
+        account_id: &str,
+        folder_id: &str,
+        workspace_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::WorkspaceItem>> {
+        self.runtime.block_on(self.inner.workspace_file_post_files(
+            account_id,
+            folder_id,
+            workspace_id,
+        ))
+    }
+    /// The blocking version of [`crate::workspace_items::WorkspaceItems::workspace_file_get`].
+    pub fn workspace_file_get(
+        &self,
+        account_id: &str,
+        file_id: &str,
+        folder_id: &str,
+        workspace_id: &str,
+        is_download: &str,
+        pdf_version: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.workspace_file_get(
+            account_id,
+            file_id,
+            folder_id,
+            workspace_id,
+            is_download,
+            pdf_version,
+        ))
+    }
+    /// The blocking version of [`crate::workspace_items::WorkspaceItems::workspace_file_get_request`].
+    pub fn workspace_file_get_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        file_id: &'a str,
+        folder_id: &'a str,
+        workspace_id: &'a str,
+    ) -> WorkspaceItemsWorkspaceFileGetRequest<'a> {
+        WorkspaceItemsWorkspaceFileGetRequest {
+            inner: self.inner.workspace_file_get_request(
+                account_id,
+                file_id,
+                folder_id,
+                workspace_id,
+            ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::workspace_items::WorkspaceItems::workspace_file_put`].
+    pub fn workspace_file_put(
+        &self,
+        account_id: &str,
+        file_id: &str,
+        folder_id: &str,
+        workspace_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::WorkspaceItem>> {
+        self.runtime.block_on(self.inner.workspace_file_put(
+            account_id,
+            file_id,
+            folder_id,
+            workspace_id,
+        ))
+    }
+    /// The blocking version of [`crate::workspace_items::WorkspaceItems::workspace_file_pages_get`].
+    pub fn workspace_file_pages_get(
+        &self,
+        account_id: &str,
+        file_id: &str,
+        folder_id: &str,
+        workspace_id: &str,
+        count: &str,
+        dpi: &str,
+        max_height: &str,
+        max_width: &str,
+        start_position: &str,
+    ) -> ClientResult<crate::Response<crate::types::PageImages>> {
+        self.runtime.block_on(self.inner.workspace_file_pages_get(
+            account_id,
+            file_id,
+            folder_id,
+            workspace_id,
+            count,
+            dpi,
+            max_height,
+            max_width,
+            start_position,
+        ))
+    }
+    /// The blocking version of [`crate::workspace_items::WorkspaceItems::workspace_file_pages_get_request`].
+    pub fn workspace_file_pages_get_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        file_id: &'a str,
+        folder_id: &'a str,
+        workspace_id: &'a str,
+    ) -> WorkspaceItemsWorkspaceFilePagesGetRequest<'a> {
+        WorkspaceItemsWorkspaceFilePagesGetRequest {
+            inner: self.inner.workspace_file_pages_get_request(
+                account_id,
+                file_id,
+                folder_id,
+                workspace_id,
+            ),
+            runtime: &self.runtime,
+        }
+    }
+}
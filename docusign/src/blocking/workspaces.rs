@@ -0,0 +1,62 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::workspaces::Workspaces`].
+pub struct Workspaces {
+    inner: crate::workspaces::Workspaces,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Workspaces {
+    pub(crate) fn new(
+        inner: crate::workspaces::Workspaces,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Workspaces { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::workspaces::Workspaces::get`].
+    pub fn get(
+        &self,
+        account_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::WorkspaceList>> {
+        self.runtime.block_on(self.inner.get(account_id))
+    }
+    /// The blocking version of [`crate::workspaces::Workspaces::post`].
+    pub fn post(
+        &self,
+        account_id: &str,
+        body: &crate::types::Workspace,
+    ) -> ClientResult<crate::Response<crate::types::Workspace>> {
+        self.runtime.block_on(self.inner.post(account_id, body))
+    }
+    /// The blocking version of [`crate::workspaces::Workspaces::get_workspaces`].
+    pub fn get_workspaces(
+        &self,
+        account_id: &str,
+        workspace_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Workspace>> {
+        self.runtime
+            .block_on(self.inner.get_workspaces(account_id, workspace_id))
+    }
+    /// The blocking version of [`crate::workspaces::Workspaces::put`].
+    pub fn put(
+        &self,
+        account_id: &str,
+        workspace_id: &str,
+        body: &crate::types::Workspace,
+    ) -> ClientResult<crate::Response<crate::types::Workspace>> {
+        self.runtime
+            .block_on(self.inner.put(account_id, workspace_id, body))
+    }
+    /// The blocking version of [`crate::workspaces::Workspaces::delete`].
+    pub fn delete(
+        &self,
+        account_id: &str,
+        workspace_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Workspace>> {
+        self.runtime
+            .block_on(self.inner.delete(account_id, workspace_id))
+    }
+}
@@ -1,6 +1,87 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`BulkSend::batch_get_batche`], set with a builder.
+pub struct BulkSendBatchGetBatcheRequest<'a> {
+    client: &'a BulkSend,
+    account_id: &'a str,
+    batch_ids: Option<&'a str>,
+    count: Option<&'a str>,
+    start_position: Option<&'a str>,
+    status: Option<&'a str>,
+}
+
+impl<'a> BulkSendBatchGetBatcheRequest<'a> {
+    /// Sets the `batch_ids` query parameter.
+    pub fn batch_ids(mut self, batch_ids: &'a str) -> Self {
+        self.batch_ids = Some(batch_ids);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: &'a str) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Sets the `start_position` query parameter.
+    pub fn start_position(mut self, start_position: &'a str) -> Self {
+        self.start_position = Some(start_position);
+        self
+    }
+
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: &'a str) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::BulkSendBatchSummaries>> {
+        let Self {
+            client,
+            account_id,
+            batch_ids,
+            count,
+            start_position,
+            status,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = batch_ids {
+            query_args.push(("batch_ids".to_string(), v.to_string()));
+        }
+        if let Some(v) = count {
+            query_args.push(("count".to_string(), v.to_string()));
+        }
+        if let Some(v) = start_position {
+            query_args.push(("start_position".to_string(), v.to_string()));
+        }
+        if let Some(v) = status {
+            query_args.push(("status".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v2.1/accounts/{}/bulk_send_batch?{}",
+                crate::progenitor_support::encode_path(account_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct BulkSend {
     pub client: Client,
 }
@@ -75,6 +156,30 @@ impl BulkSend {
             )
             .await
     }
+    /**
+     * Returns a list of bulk send batch summaries. .
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/bulk_send_batch` endpoint.
+     *
+     * As opposed to `batch_get_batche`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Returns a summary of bulk send batches.
+     *
+     * Use the `batch_ids` query parameter to narrow the list of batches.
+     */
+    pub fn batch_get_batche_request<'a>(
+        &'a self,
+        account_id: &'a str,
+    ) -> BulkSendBatchGetBatcheRequest<'a> {
+        BulkSendBatchGetBatcheRequest {
+            client: self,
+            account_id,
+            batch_ids: None,
+            count: None,
+            start_position: None,
+            status: None,
+        }
+    }
     /**
      * Gets the status of a specific bulk send batch.
      *
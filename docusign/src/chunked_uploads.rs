@@ -1,6 +1,108 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`ChunkedUploads::get_upload`], set with a builder.
+pub struct ChunkedUploadsGetUploadRequest<'a> {
+    client: &'a ChunkedUploads,
+    account_id: &'a str,
+    chunked_upload_id: &'a str,
+    include: Option<&'a str>,
+}
+
+impl<'a> ChunkedUploadsGetUploadRequest<'a> {
+    /// Sets the `include` query parameter.
+    pub fn include(mut self, include: &'a str) -> Self {
+        self.include = Some(include);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::ChunkedUploadResponse>> {
+        let Self {
+            client,
+            account_id,
+            chunked_upload_id,
+            include,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = include {
+            query_args.push(("include".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v2.1/accounts/{}/chunked_uploads/{}?{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(chunked_upload_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`ChunkedUploads::put`], set with a builder.
+pub struct ChunkedUploadsPutRequest<'a> {
+    client: &'a ChunkedUploads,
+    account_id: &'a str,
+    chunked_upload_id: &'a str,
+    action: Option<&'a str>,
+}
+
+impl<'a> ChunkedUploadsPutRequest<'a> {
+    /// Sets the `action` query parameter.
+    pub fn action(mut self, action: &'a str) -> Self {
+        self.action = Some(action);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::ChunkedUploadResponse>> {
+        let Self {
+            client,
+            account_id,
+            chunked_upload_id,
+            action,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = action {
+            query_args.push(("action".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v2.1/accounts/{}/chunked_uploads/{}?{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(chunked_upload_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .put(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct ChunkedUploads {
     pub client: Client,
 }
@@ -89,6 +191,29 @@ impl ChunkedUploads {
             )
             .await
     }
+    /**
+     * Retrieves metadata about a chunked upload.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/chunked_uploads/{chunkedUploadId}` endpoint.
+     *
+     * As opposed to `get_upload`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Returns the details (but not the content) about a chunked upload.
+     *
+     * **Note**: You cannot obtain details about a chunked upload that has expired, been deleted, or consumed by other actions.
+     */
+    pub fn get_upload_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        chunked_upload_id: &'a str,
+    ) -> ChunkedUploadsGetUploadRequest<'a> {
+        ChunkedUploadsGetUploadRequest {
+            client: self,
+            account_id,
+            chunked_upload_id,
+            include: None,
+        }
+    }
     /**
      * Commit a chunked upload.
      *
@@ -136,6 +261,31 @@ impl ChunkedUploads {
             )
             .await
     }
+    /**
+     * Commit a chunked upload.
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/chunked_uploads/{chunkedUploadId}` endpoint.
+     *
+     * As opposed to `put`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * This method checks the integrity of a chunked upload and then commits it. When this request is successful, the chunked upload is then ready to be referenced in other API calls.
+     *
+     * If the request is unsuccessful, ensure that you have uploaded all of the parts by using the Update method.
+     *
+     * **Note**: After you commit a chunked upload, it no longer accepts additional parts.
+     */
+    pub fn put_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        chunked_upload_id: &'a str,
+    ) -> ChunkedUploadsPutRequest<'a> {
+        ChunkedUploadsPutRequest {
+            client: self,
+            account_id,
+            chunked_upload_id,
+            action: None,
+        }
+    }
     /**
      * Deletes a chunked upload.
      *
@@ -1,6 +1,238 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`CloudStorage::folder_get_all`], set with a builder.
+pub struct CloudStorageFolderGetAllRequest<'a> {
+    client: &'a CloudStorage,
+    account_id: &'a str,
+    service_id: &'a str,
+    user_id: &'a str,
+    cloud_storage_folder_path: Option<&'a str>,
+    count: Option<&'a str>,
+    order: Option<&'a str>,
+    order_by: Option<&'a str>,
+    search_text: Option<&'a str>,
+    start_position: Option<&'a str>,
+}
+
+impl<'a> CloudStorageFolderGetAllRequest<'a> {
+    /// Sets the `cloud_storage_folder_path` query parameter.
+    pub fn cloud_storage_folder_path(mut self, cloud_storage_folder_path: &'a str) -> Self {
+        self.cloud_storage_folder_path = Some(cloud_storage_folder_path);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: &'a str) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Sets the `order` query parameter.
+    pub fn order(mut self, order: &'a str) -> Self {
+        self.order = Some(order);
+        self
+    }
+
+    /// Sets the `order_by` query parameter.
+    pub fn order_by(mut self, order_by: &'a str) -> Self {
+        self.order_by = Some(order_by);
+        self
+    }
+
+    /// Sets the `search_text` query parameter.
+    pub fn search_text(mut self, search_text: &'a str) -> Self {
+        self.search_text = Some(search_text);
+        self
+    }
+
+    /// Sets the `start_position` query parameter.
+    pub fn start_position(mut self, start_position: &'a str) -> Self {
+        self.start_position = Some(start_position);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::ExternalFolder>> {
+        let Self {
+            client,
+            account_id,
+            service_id,
+            user_id,
+            cloud_storage_folder_path,
+            count,
+            order,
+            order_by,
+            search_text,
+            start_position,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = cloud_storage_folder_path {
+            query_args.push(("cloud_storage_folder_path".to_string(), v.to_string()));
+        }
+        if let Some(v) = count {
+            query_args.push(("count".to_string(), v.to_string()));
+        }
+        if let Some(v) = order {
+            query_args.push(("order".to_string(), v.to_string()));
+        }
+        if let Some(v) = order_by {
+            query_args.push(("order_by".to_string(), v.to_string()));
+        }
+        if let Some(v) = search_text {
+            query_args.push(("search_text".to_string(), v.to_string()));
+        }
+        if let Some(v) = start_position {
+            query_args.push(("start_position".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v2.1/accounts/{}/users/{}/cloud_storage/{}/folders?{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(user_id),
+                crate::progenitor_support::encode_path(service_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`CloudStorage::folder_get`], set with a builder.
+pub struct CloudStorageFolderGetRequest<'a> {
+    client: &'a CloudStorage,
+    account_id: &'a str,
+    folder_id: &'a str,
+    service_id: &'a str,
+    user_id: &'a str,
+    cloud_storage_folder_path: Option<&'a str>,
+    cloud_storage_folderid_plain: Option<&'a str>,
+    count: Option<&'a str>,
+    order: Option<&'a str>,
+    order_by: Option<&'a str>,
+    search_text: Option<&'a str>,
+    start_position: Option<&'a str>,
+}
+
+impl<'a> CloudStorageFolderGetRequest<'a> {
+    /// Sets the `cloud_storage_folder_path` query parameter.
+    pub fn cloud_storage_folder_path(mut self, cloud_storage_folder_path: &'a str) -> Self {
+        self.cloud_storage_folder_path = Some(cloud_storage_folder_path);
+        self
+    }
+
+    /// Sets the `cloud_storage_folderid_plain` query parameter.
+    pub fn cloud_storage_folderid_plain(mut self, cloud_storage_folderid_plain: &'a str) -> Self {
+        self.cloud_storage_folderid_plain = Some(cloud_storage_folderid_plain);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: &'a str) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Sets the `order` query parameter.
+    pub fn order(mut self, order: &'a str) -> Self {
+        self.order = Some(order);
+        self
+    }
+
+    /// Sets the `order_by` query parameter.
+    pub fn order_by(mut self, order_by: &'a str) -> Self {
+        self.order_by = Some(order_by);
+        self
+    }
+
+    /// Sets the `search_text` query parameter.
+    pub fn search_text(mut self, search_text: &'a str) -> Self {
+        self.search_text = Some(search_text);
+        self
+    }
+
+    /// Sets the `start_position` query parameter.
+    pub fn start_position(mut self, start_position: &'a str) -> Self {
+        self.start_position = Some(start_position);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::ExternalFolder>> {
+        let Self {
+            client,
+            account_id,
+            folder_id,
+            service_id,
+            user_id,
+            cloud_storage_folder_path,
+            cloud_storage_folderid_plain,
+            count,
+            order,
+            order_by,
+            search_text,
+            start_position,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = cloud_storage_folder_path {
+            query_args.push(("cloud_storage_folder_path".to_string(), v.to_string()));
+        }
+        if let Some(v) = cloud_storage_folderid_plain {
+            query_args.push(("cloud_storage_folderid_plain".to_string(), v.to_string()));
+        }
+        if let Some(v) = count {
+            query_args.push(("count".to_string(), v.to_string()));
+        }
+        if let Some(v) = order {
+            query_args.push(("order".to_string(), v.to_string()));
+        }
+        if let Some(v) = order_by {
+            query_args.push(("order_by".to_string(), v.to_string()));
+        }
+        if let Some(v) = search_text {
+            query_args.push(("search_text".to_string(), v.to_string()));
+        }
+        if let Some(v) = start_position {
+            query_args.push(("start_position".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v2.1/accounts/{}/users/{}/cloud_storage/{}/folders/{}?{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(user_id),
+                crate::progenitor_support::encode_path(service_id),
+                crate::progenitor_support::encode_path(folder_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct CloudStorage {
     pub client: Client,
 }
@@ -101,6 +333,34 @@ impl CloudStorage {
             )
             .await
     }
+    /**
+     * Retrieves a list of all the items in a specified folder from the specified cloud storage provider.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/users/{userId}/cloud_storage/{serviceId}/folders` endpoint.
+     *
+     * As opposed to `folder_get_all`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Retrieves a list of all the items in a specified folder from the specified cloud storage provider.
+     */
+    pub fn folder_get_all_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        service_id: &'a str,
+        user_id: &'a str,
+    ) -> CloudStorageFolderGetAllRequest<'a> {
+        CloudStorageFolderGetAllRequest {
+            client: self,
+            account_id,
+            service_id,
+            user_id,
+            cloud_storage_folder_path: None,
+            count: None,
+            order: None,
+            order_by: None,
+            search_text: None,
+            start_position: None,
+        }
+    }
     /**
      * Gets a list of items from a cloud storage provider.
      *
@@ -204,4 +464,37 @@ impl CloudStorage {
             )
             .await
     }
+    /**
+     * Gets a list of items from a cloud storage provider.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/users/{userId}/cloud_storage/{serviceId}/folders/{folderId}` endpoint.
+     *
+     * As opposed to `folder_get`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Retrieves a list of the user's items from the specified cloud storage provider.
+     *
+     * To limit the scope of the items returned, provide a comma-separated list of folder ids in the request.
+     */
+    pub fn folder_get_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        folder_id: &'a str,
+        service_id: &'a str,
+        user_id: &'a str,
+    ) -> CloudStorageFolderGetRequest<'a> {
+        CloudStorageFolderGetRequest {
+            client: self,
+            account_id,
+            folder_id,
+            service_id,
+            user_id,
+            cloud_storage_folder_path: None,
+            cloud_storage_folderid_plain: None,
+            count: None,
+            order: None,
+            order_by: None,
+            search_text: None,
+            start_position: None,
+        }
+    }
 }
@@ -1,6 +1,115 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`CloudStorageProviders::cloud_storage_get_provider`], set with a builder.
+pub struct CloudStorageProvidersCloudStorageGetProviderRequest<'a> {
+    client: &'a CloudStorageProviders,
+    account_id: &'a str,
+    user_id: &'a str,
+    redirect_url: Option<&'a str>,
+}
+
+impl<'a> CloudStorageProvidersCloudStorageGetProviderRequest<'a> {
+    /// Sets the `redirectUrl` query parameter.
+    pub fn redirect_url(mut self, redirect_url: &'a str) -> Self {
+        self.redirect_url = Some(redirect_url);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::CloudStorageProvidersData>> {
+        let Self {
+            client,
+            account_id,
+            user_id,
+            redirect_url,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = redirect_url {
+            query_args.push(("redirectUrl".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v2.1/accounts/{}/users/{}/cloud_storage?{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(user_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`CloudStorageProviders::cloud_storage_get`], set with a builder.
+pub struct CloudStorageProvidersCloudStorageGetRequest<'a> {
+    client: &'a CloudStorageProviders,
+    account_id: &'a str,
+    service_id: &'a str,
+    user_id: &'a str,
+    redirect_url: Option<&'a str>,
+}
+
+impl<'a> CloudStorageProvidersCloudStorageGetRequest<'a> {
+    /// Sets the `redirectUrl` query parameter.
+    pub fn redirect_url(mut self, redirect_url: &'a str) -> Self {
+        self.redirect_url = Some(redirect_url);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::CloudStorageProvidersData>> {
+        let Self {
+            client,
+            account_id,
+            service_id,
+            user_id,
+            redirect_url,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = redirect_url {
+            query_args.push(("redirectUrl".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v2.1/accounts/{}/users/{}/cloud_storage/{}?{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(user_id),
+                crate::progenitor_support::encode_path(service_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct CloudStorageProviders {
     pub client: Client,
 }
@@ -59,6 +168,28 @@ impl CloudStorageProviders {
             )
             .await
     }
+    /**
+     * Get the Cloud Storage Provider configuration for the specified user.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/users/{userId}/cloud_storage` endpoint.
+     *
+     * As opposed to `cloud_storage_get_provider`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Retrieves the list of cloud storage providers enabled for the account and the configuration information for the user.
+     *
+     */
+    pub fn cloud_storage_get_provider_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        user_id: &'a str,
+    ) -> CloudStorageProvidersCloudStorageGetProviderRequest<'a> {
+        CloudStorageProvidersCloudStorageGetProviderRequest {
+            client: self,
+            account_id,
+            user_id,
+            redirect_url: None,
+        }
+    }
     /**
      * Configures the redirect URL information  for one or more cloud storage providers for the specified user.
      *
@@ -185,6 +316,29 @@ impl CloudStorageProviders {
             )
             .await
     }
+    /**
+     * Gets the specified Cloud Storage Provider configuration for the User.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/users/{userId}/cloud_storage/{serviceId}` endpoint.
+     *
+     * As opposed to `cloud_storage_get`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Retrieves the list of cloud storage providers enabled for the account and the configuration information for the user.
+     */
+    pub fn cloud_storage_get_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        service_id: &'a str,
+        user_id: &'a str,
+    ) -> CloudStorageProvidersCloudStorageGetRequest<'a> {
+        CloudStorageProvidersCloudStorageGetRequest {
+            client: self,
+            account_id,
+            service_id,
+            user_id,
+            redirect_url: None,
+        }
+    }
     /**
      * Deletes the user authentication information for the specified cloud storage provider.
      *
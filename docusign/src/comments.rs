@@ -1,6 +1,57 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Comments::get_transcript`], set with a builder.
+pub struct CommentsGetTranscriptRequest<'a> {
+    client: &'a Comments,
+    account_id: &'a str,
+    envelope_id: &'a str,
+    encoding: Option<&'a str>,
+}
+
+impl<'a> CommentsGetTranscriptRequest<'a> {
+    /// Sets the `encoding` query parameter.
+    pub fn encoding(mut self, encoding: &'a str) -> Self {
+        self.encoding = Some(encoding);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            account_id,
+            envelope_id,
+            encoding,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = encoding {
+            query_args.push(("encoding".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v2.1/accounts/{}/envelopes/{}/comments/transcript?{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct Comments {
     pub client: Client,
 }
@@ -56,4 +107,27 @@ impl Comments {
             )
             .await
     }
+    /**
+     * Gets a PDF transcript of all of the comments in an envelope.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/comments/transcript` endpoint.
+     *
+     * As opposed to `get_transcript`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Retrieves a PDF file containing all of the comments that senders and recipients have added to the documents in an envelope.
+     *
+     * **Note**: Comments are disabled by default. To use the comments feature, an account administrator must enable comments on the account (in the `accountSettingsInformation` object, set the `enableSigningExtensionComments` property to **true**).
+     */
+    pub fn get_transcript_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        envelope_id: &'a str,
+    ) -> CommentsGetTranscriptRequest<'a> {
+        CommentsGetTranscriptRequest {
+            client: self,
+            account_id,
+            envelope_id,
+            encoding: None,
+        }
+    }
 }
@@ -0,0 +1,393 @@
+//! For verifying and parsing the notifications DocuSign Connect sends to a listener,
+//! in the JSON SIM format or the legacy XML one.
+//!
+//! FROM: <https://developers.docusign.com/platform/webhooks/connect/hmac/>
+use base64::{engine::general_purpose::STANDARD, Engine};
+use hmac::{Hmac, Mac};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+
+use crate::types;
+
+/// The prefix of the headers DocuSign signs notifications with, one per HMAC key of
+/// the account, like `X-DocuSign-Signature-1`.
+pub const SIGNATURE_HEADER_PREFIX: &str = "X-DocuSign-Signature-";
+
+/// Errors returned while verifying or parsing a notification.
+#[derive(Debug, thiserror::Error)]
+pub enum WebhookError {
+    /// The notification has no `X-DocuSign-Signature-N` header
+    #[error("missing X-DocuSign-Signature header")]
+    MissingHeader,
+    /// None of the signatures of the notification match the payload
+    #[error("no signature matches the payload")]
+    NoMatchingSignature,
+    /// The JSON payload is not an event
+    #[error(transparent)]
+    SerdeJsonError(#[from] serde_json::Error),
+    /// The XML payload is not an envelope status
+    #[error(transparent)]
+    XmlError(#[from] quick_xml::DeError),
+}
+
+/// A notification of Connect, about an envelope or one of its recipients.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default, JsonSchema)]
+pub struct ConnectEvent {
+    /// The kind of the event, like `envelope-completed` or `recipient-signed`.
+    ///
+    /// Legacy XML notifications have none, so theirs is made from the status of the
+    /// envelope, like `envelope-completed`.
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub event: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "apiVersion"
+    )]
+    pub api_version: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub uri: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
+        rename = "retryCount"
+    )]
+    pub retry_count: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
+        rename = "configurationId"
+    )]
+    pub configuration_id: i64,
+    /// When the notification was generated. Legacy XML notifications have it in the
+    /// time zone of the account, without an offset.
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "generatedDateTime"
+    )]
+    pub generated_date_time: String,
+    #[serde(default)]
+    pub data: ConnectEventPayload,
+}
+
+/// The envelope a notification is about.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default, JsonSchema)]
+pub struct ConnectEventPayload {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "accountId"
+    )]
+    pub account_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "userId"
+    )]
+    pub user_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "envelopeId"
+    )]
+    pub envelope_id: String,
+    /// The recipient of `recipient-*` events.
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "recipientId"
+    )]
+    pub recipient_id: String,
+    /// The envelope, when the configuration includes its data.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "envelopeSummary"
+    )]
+    pub envelope_summary: Option<types::Envelope>,
+}
+
+/// Verifies the notifications of a Connect configuration, with the HMAC keys of the
+/// account.
+#[derive(Clone)]
+pub struct Webhook {
+    secrets: Vec<String>,
+}
+
+impl Webhook {
+    /// Create a new verifier from an HMAC key of the account.
+    pub fn new<S>(secret: S) -> Self
+    where
+        S: ToString,
+    {
+        Webhook {
+            secrets: vec![secret.to_string()],
+        }
+    }
+
+    /// Adds another HMAC key of the account, to keep verifying notifications while
+    /// keys are rotated.
+    pub fn with_secret<S>(&mut self, secret: S) -> &mut Self
+    where
+        S: ToString,
+    {
+        self.secrets.push(secret.to_string());
+        self
+    }
+
+    fn mac(secret: &str, payload: &[u8]) -> Hmac<Sha256> {
+        let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes())
+            .expect("HMAC can take a key of any size");
+        mac.update(payload);
+        mac
+    }
+
+    /// Returns the `X-DocuSign-Signature-N` headers DocuSign would send along with a
+    /// payload, one per key, to test listeners.
+    pub fn sign(&self, payload: &[u8]) -> Vec<(String, String)> {
+        self.secrets
+            .iter()
+            .enumerate()
+            .map(|(i, secret)| {
+                let signature = Webhook::mac(secret, payload).finalize().into_bytes();
+                (
+                    format!("{}{}", SIGNATURE_HEADER_PREFIX, i + 1),
+                    STANDARD.encode(signature),
+                )
+            })
+            .collect()
+    }
+
+    /// Verifies the `X-DocuSign-Signature-N` headers of a notification against its raw
+    /// body, passing if any of them matches any of the keys.
+    ///
+    /// DocuSign sends one signature per key of the account, numbered from 1, so they
+    /// are found by the prefix of their names rather than a fixed one.
+    pub fn verify<I, K, V>(&self, headers: I, payload: &[u8]) -> Result<(), WebhookError>
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: AsRef<str>,
+        V: AsRef<[u8]>,
+    {
+        let signatures = headers
+            .into_iter()
+            .filter(|(name, _)| {
+                name.as_ref()
+                    .get(..SIGNATURE_HEADER_PREFIX.len())
+                    .is_some_and(|prefix| prefix.eq_ignore_ascii_case(SIGNATURE_HEADER_PREFIX))
+            })
+            .map(|(_, value)| STANDARD.decode(value.as_ref().trim_ascii()))
+            .collect::<Vec<_>>();
+        if signatures.is_empty() {
+            return Err(WebhookError::MissingHeader);
+        }
+
+        let matches = signatures
+            .iter()
+            .filter_map(|signature| signature.as_ref().ok())
+            .any(|signature| {
+                self.secrets.iter().any(|secret| {
+                    Webhook::mac(secret, payload)
+                        .verify_slice(signature)
+                        .is_ok()
+                })
+            });
+        if matches {
+            Ok(())
+        } else {
+            Err(WebhookError::NoMatchingSignature)
+        }
+    }
+
+    /// Verifies a notification and parses its body into an event.
+    pub fn construct_event<I, K, V>(
+        &self,
+        headers: I,
+        payload: &[u8],
+    ) -> Result<ConnectEvent, WebhookError>
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: AsRef<str>,
+        V: AsRef<[u8]>,
+    {
+        self.verify(headers, payload)?;
+        parse_event(payload)
+    }
+}
+
+/// Parses the body of a notification into an event, without verifying it.
+///
+/// Both the JSON SIM format and the legacy XML one are supported, the legacy one
+/// being mapped onto the fields of the JSON one.
+pub fn parse_event(payload: &[u8]) -> Result<ConnectEvent, WebhookError> {
+    if payload.trim_ascii_start().starts_with(b"<") {
+        let payload = String::from_utf8_lossy(payload);
+        let information = quick_xml::de::from_str::<XmlEnvelopeInformation>(&payload)?;
+        return Ok(information.envelope_status.into_event()?);
+    }
+
+    Ok(serde_json::from_slice(payload)?)
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct XmlEnvelopeInformation {
+    envelope_status: XmlEnvelopeStatus,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(default, rename_all = "PascalCase")]
+struct XmlEnvelopeStatus {
+    recipient_statuses: XmlRecipientStatuses,
+    time_generated: String,
+    #[serde(rename = "EnvelopeID")]
+    envelope_id: String,
+    subject: String,
+    user_name: String,
+    email: String,
+    status: String,
+    created: String,
+    sent: String,
+    delivered: String,
+    signed: String,
+    completed: String,
+    declined: String,
+    voided: String,
+    voided_reason: String,
+    document_statuses: XmlDocumentStatuses,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(default, rename_all = "PascalCase")]
+struct XmlRecipientStatuses {
+    recipient_status: Vec<XmlRecipientStatus>,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(default, rename_all = "PascalCase")]
+struct XmlRecipientStatus {
+    #[serde(rename = "Type")]
+    type_: String,
+    email: String,
+    user_name: String,
+    routing_order: String,
+    sent: String,
+    delivered: String,
+    signed: String,
+    declined: String,
+    decline_reason: String,
+    status: String,
+    recipient_id: String,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(default, rename_all = "PascalCase")]
+struct XmlDocumentStatuses {
+    document_status: Vec<XmlDocumentStatus>,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(default, rename_all = "PascalCase")]
+struct XmlDocumentStatus {
+    #[serde(rename = "ID")]
+    id: String,
+    name: String,
+    sequence: String,
+}
+
+impl XmlRecipientStatus {
+    fn to_value(&self) -> serde_json::Value {
+        serde_json::json!({
+            "recipientId": self.recipient_id,
+            "email": self.email,
+            "name": self.user_name,
+            "routingOrder": self.routing_order,
+            "status": self.status.to_lowercase(),
+            "sentDateTime": self.sent,
+            "deliveredDateTime": self.delivered,
+            "signedDateTime": self.signed,
+            "declinedDateTime": self.declined,
+            "declinedReason": self.decline_reason,
+        })
+    }
+}
+
+impl XmlEnvelopeStatus {
+    fn into_event(self) -> serde_json::Result<ConnectEvent> {
+        let status = self.status.to_lowercase();
+        let recipients = |type_: &str| {
+            self.recipient_statuses
+                .recipient_status
+                .iter()
+                .filter(|recipient| recipient.type_ == type_)
+                .map(XmlRecipientStatus::to_value)
+                .collect::<Vec<_>>()
+        };
+        let documents = self
+            .document_statuses
+            .document_status
+            .iter()
+            .map(|document| {
+                serde_json::json!({
+                    "documentId": document.id,
+                    "name": document.name,
+                    "order": document.sequence,
+                })
+            })
+            .collect::<Vec<_>>();
+
+        // Only the recipients of the types with a status of their own in the legacy
+        // format are kept.
+        let envelope = serde_json::json!({
+            "envelopeId": self.envelope_id,
+            "status": status,
+            "emailSubject": self.subject,
+            "createdDateTime": self.created,
+            "sentDateTime": self.sent,
+            "deliveredDateTime": self.delivered,
+            "completedDateTime": self.completed,
+            "declinedDateTime": self.declined,
+            "voidedDateTime": self.voided,
+            "voidedReason": self.voided_reason,
+            "sender": {
+                "userName": self.user_name,
+                "email": self.email,
+            },
+            "recipients": {
+                "signers": recipients("Signer"),
+                "carbonCopies": recipients("CarbonCopy"),
+                "certifiedDeliveries": recipients("CertifiedDelivery"),
+            },
+            "envelopeDocuments": documents,
+        });
+
+        Ok(ConnectEvent {
+            event: format!("envelope-{}", status),
+            generated_date_time: self.time_generated,
+            data: ConnectEventPayload {
+                envelope_id: self.envelope_id,
+                envelope_summary: Some(serde_json::from_value(envelope)?),
+                ..Default::default()
+            },
+            ..Default::default()
+        })
+    }
+}
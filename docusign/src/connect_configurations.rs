@@ -1,6 +1,112 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`ConnectConfigurations::connect_get_user`], set with a builder.
+pub struct ConnectConfigurationsConnectGetUserRequest<'a> {
+    client: &'a ConnectConfigurations,
+    account_id: &'a str,
+    connect_id: &'a str,
+    count: Option<&'a str>,
+    email_substring: Option<&'a str>,
+    list_included_users: Option<&'a str>,
+    start_position: Option<&'a str>,
+    status: Option<&'a str>,
+    user_name_substring: Option<&'a str>,
+}
+
+impl<'a> ConnectConfigurationsConnectGetUserRequest<'a> {
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: &'a str) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Sets the `email_substring` query parameter.
+    pub fn email_substring(mut self, email_substring: &'a str) -> Self {
+        self.email_substring = Some(email_substring);
+        self
+    }
+
+    /// Sets the `list_included_users` query parameter.
+    pub fn list_included_users(mut self, list_included_users: &'a str) -> Self {
+        self.list_included_users = Some(list_included_users);
+        self
+    }
+
+    /// Sets the `start_position` query parameter.
+    pub fn start_position(mut self, start_position: &'a str) -> Self {
+        self.start_position = Some(start_position);
+        self
+    }
+
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: &'a str) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Sets the `user_name_substring` query parameter.
+    pub fn user_name_substring(mut self, user_name_substring: &'a str) -> Self {
+        self.user_name_substring = Some(user_name_substring);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::IntegratedUserInfoList>> {
+        let Self {
+            client,
+            account_id,
+            connect_id,
+            count,
+            email_substring,
+            list_included_users,
+            start_position,
+            status,
+            user_name_substring,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = count {
+            query_args.push(("count".to_string(), v.to_string()));
+        }
+        if let Some(v) = email_substring {
+            query_args.push(("email_substring".to_string(), v.to_string()));
+        }
+        if let Some(v) = list_included_users {
+            query_args.push(("list_included_users".to_string(), v.to_string()));
+        }
+        if let Some(v) = start_position {
+            query_args.push(("start_position".to_string(), v.to_string()));
+        }
+        if let Some(v) = status {
+            query_args.push(("status".to_string(), v.to_string()));
+        }
+        if let Some(v) = user_name_substring {
+            query_args.push(("user_name_substring".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v2.1/accounts/{}/connect/{}/users?{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(connect_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct ConnectConfigurations {
     pub client: Client,
 }
@@ -279,4 +385,30 @@ impl ConnectConfigurations {
             )
             .await
     }
+    /**
+     * Returns users from the configured Connect service.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/connect/{connectId}/users` endpoint.
+     *
+     * As opposed to `connect_get_user`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Returns users from the configured Connect service.
+     */
+    pub fn connect_get_user_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        connect_id: &'a str,
+    ) -> ConnectConfigurationsConnectGetUserRequest<'a> {
+        ConnectConfigurationsConnectGetUserRequest {
+            client: self,
+            account_id,
+            connect_id,
+            count: None,
+            email_substring: None,
+            list_included_users: None,
+            start_position: None,
+            status: None,
+            user_name_substring: None,
+        }
+    }
 }
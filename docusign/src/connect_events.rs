@@ -1,6 +1,175 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`ConnectEvents::connect_failures_get_log`], set with a builder.
+pub struct ConnectEventsConnectFailuresGetLogRequest<'a> {
+    client: &'a ConnectEvents,
+    account_id: &'a str,
+    from_date: Option<&'a str>,
+    to_date: Option<&'a str>,
+}
+
+impl<'a> ConnectEventsConnectFailuresGetLogRequest<'a> {
+    /// Sets the `from_date` query parameter.
+    pub fn from_date(mut self, from_date: &'a str) -> Self {
+        self.from_date = Some(from_date);
+        self
+    }
+
+    /// Sets the `to_date` query parameter.
+    pub fn to_date(mut self, to_date: &'a str) -> Self {
+        self.to_date = Some(to_date);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::ConnectLogs>> {
+        let Self {
+            client,
+            account_id,
+            from_date,
+            to_date,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = from_date {
+            query_args.push(("from_date".to_string(), v.to_string()));
+        }
+        if let Some(v) = to_date {
+            query_args.push(("to_date".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v2.1/accounts/{}/connect/failures?{}",
+                crate::progenitor_support::encode_path(account_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`ConnectEvents::connect_log_get_log`], set with a builder.
+pub struct ConnectEventsConnectLogGetLogRequest<'a> {
+    client: &'a ConnectEvents,
+    account_id: &'a str,
+    from_date: Option<&'a str>,
+    to_date: Option<&'a str>,
+}
+
+impl<'a> ConnectEventsConnectLogGetLogRequest<'a> {
+    /// Sets the `from_date` query parameter.
+    pub fn from_date(mut self, from_date: &'a str) -> Self {
+        self.from_date = Some(from_date);
+        self
+    }
+
+    /// Sets the `to_date` query parameter.
+    pub fn to_date(mut self, to_date: &'a str) -> Self {
+        self.to_date = Some(to_date);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::ConnectLogs>> {
+        let Self {
+            client,
+            account_id,
+            from_date,
+            to_date,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = from_date {
+            query_args.push(("from_date".to_string(), v.to_string()));
+        }
+        if let Some(v) = to_date {
+            query_args.push(("to_date".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v2.1/accounts/{}/connect/logs?{}",
+                crate::progenitor_support::encode_path(account_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`ConnectEvents::connect_log_get`], set with a builder.
+pub struct ConnectEventsConnectLogGetRequest<'a> {
+    client: &'a ConnectEvents,
+    account_id: &'a str,
+    log_id: &'a str,
+    additional_info: Option<&'a str>,
+}
+
+impl<'a> ConnectEventsConnectLogGetRequest<'a> {
+    /// Sets the `additional_info` query parameter.
+    pub fn additional_info(mut self, additional_info: &'a str) -> Self {
+        self.additional_info = Some(additional_info);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::ConnectLog>> {
+        let Self {
+            client,
+            account_id,
+            log_id,
+            additional_info,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = additional_info {
+            query_args.push(("additional_info".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v2.1/accounts/{}/connect/logs/{}?{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(log_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct ConnectEvents {
     pub client: Client,
 }
@@ -129,6 +298,26 @@ impl ConnectEvents {
             )
             .await
     }
+    /**
+     * Gets the Connect failure log information.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/connect/failures` endpoint.
+     *
+     * As opposed to `connect_failures_get_log`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Retrieves the Connect failure log information. You can use this log to determine which envelopes failed to post, in order to create a republish request.
+     */
+    pub fn connect_failures_get_log_request<'a>(
+        &'a self,
+        account_id: &'a str,
+    ) -> ConnectEventsConnectFailuresGetLogRequest<'a> {
+        ConnectEventsConnectFailuresGetLogRequest {
+            client: self,
+            account_id,
+            from_date: None,
+            to_date: None,
+        }
+    }
     /**
      * Deletes a Connect failure log entry.
      *
@@ -215,6 +404,28 @@ impl ConnectEvents {
             )
             .await
     }
+    /**
+     * Gets the Connect log.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/connect/logs` endpoint.
+     *
+     * As opposed to `connect_log_get_log`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Retrieves a list of connect log entries for your account.
+     *
+     * **Note**: The `enableLog` setting in the Connect configuration must be set to true to enable logging. If logging is not enabled, then no log entries are recorded.
+     */
+    pub fn connect_log_get_log_request<'a>(
+        &'a self,
+        account_id: &'a str,
+    ) -> ConnectEventsConnectLogGetLogRequest<'a> {
+        ConnectEventsConnectLogGetLogRequest {
+            client: self,
+            account_id,
+            from_date: None,
+            to_date: None,
+        }
+    }
     /**
      * Deletes a list of Connect log entries.
      *
@@ -292,6 +503,29 @@ impl ConnectEvents {
             )
             .await
     }
+    /**
+     * Gets a Connect log entry.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/connect/logs/{logId}` endpoint.
+     *
+     * As opposed to `connect_log_get`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Retrieves the specified Connect log entry for your account.
+     *
+     * **Note**: The `enableLog` setting in the Connect configuration must be set to true to enable logging. If logging is not enabled, then no log entries are recorded.
+     */
+    pub fn connect_log_get_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        log_id: &'a str,
+    ) -> ConnectEventsConnectLogGetRequest<'a> {
+        ConnectEventsConnectLogGetRequest {
+            client: self,
+            account_id,
+            log_id,
+            additional_info: None,
+        }
+    }
     /**
      * Deletes a specified Connect log entry.
      *
@@ -1,6 +1,57 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Contacts::get`], set with a builder.
+pub struct ContactsGetRequest<'a> {
+    client: &'a Contacts,
+    account_id: &'a str,
+    contact_id: &'a str,
+    cloud_provider: Option<&'a str>,
+}
+
+impl<'a> ContactsGetRequest<'a> {
+    /// Sets the `cloud_provider` query parameter.
+    pub fn cloud_provider(mut self, cloud_provider: &'a str) -> Self {
+        self.cloud_provider = Some(cloud_provider);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::ContactGetResponse>> {
+        let Self {
+            client,
+            account_id,
+            contact_id,
+            cloud_provider,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = cloud_provider {
+            query_args.push(("cloud_provider".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v2.1/accounts/{}/contacts/{}?{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(contact_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct Contacts {
     pub client: Client,
 }
@@ -169,6 +220,38 @@ impl Contacts {
             )
             .await
     }
+    /**
+     * Gets one or more contacts.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/contacts/{contactId}` endpoint.
+     *
+     * As opposed to `get`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * This method returns one or more contacts
+     * associated with a DocuSign account. You can also
+     * retrieve contacts from connected [cloud storage][CloudStorage] providers by using the
+     * `cloud_provider` query parameter. By default,
+     * contacts are retrieved from the DocuSign account's
+     * default address book.
+     *
+     * To return a specific contact, use the `contactId`
+     * query parameter. To return all contacts associated
+     * with an account, omit this parameter.
+     *
+     * [CloudStorage]: https://developers.docusign.com/docs/esign-rest-api/reference/CloudStorage
+     */
+    pub fn get_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        contact_id: &'a str,
+    ) -> ContactsGetRequest<'a> {
+        ContactsGetRequest {
+            client: self,
+            account_id,
+            contact_id,
+            cloud_provider: None,
+        }
+    }
     /**
      * Deletes a contact.
      *
@@ -1,6 +1,54 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`CustomTabs::tabs_get_tab_definition`], set with a builder.
+pub struct CustomTabsTabsGetTabDefinitionRequest<'a> {
+    client: &'a CustomTabs,
+    account_id: &'a str,
+    custom_tab_only: Option<&'a str>,
+}
+
+impl<'a> CustomTabsTabsGetTabDefinitionRequest<'a> {
+    /// Sets the `custom_tab_only` query parameter.
+    pub fn custom_tab_only(mut self, custom_tab_only: &'a str) -> Self {
+        self.custom_tab_only = Some(custom_tab_only);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::TabMetadataList>> {
+        let Self {
+            client,
+            account_id,
+            custom_tab_only,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = custom_tab_only {
+            query_args.push(("custom_tab_only".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v2.1/accounts/{}/tab_definitions?{}",
+                crate::progenitor_support::encode_path(account_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct CustomTabs {
     pub client: Client,
 }
@@ -51,6 +99,25 @@ impl CustomTabs {
             )
             .await
     }
+    /**
+     * Gets a list of all account tabs.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/tab_definitions` endpoint.
+     *
+     * As opposed to `tabs_get_tab_definition`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Retrieves a list of all tabs associated with the account.
+     */
+    pub fn tabs_get_tab_definition_request<'a>(
+        &'a self,
+        account_id: &'a str,
+    ) -> CustomTabsTabsGetTabDefinitionRequest<'a> {
+        CustomTabsTabsGetTabDefinitionRequest {
+            client: self,
+            account_id,
+            custom_tab_only: None,
+        }
+    }
     /**
      * Creates a custom tab.
      *
@@ -1,6 +1,60 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`EnvelopeConsumerDisclosures::consumer_disclosure_get_envelope_recipient`], set with a builder.
+pub struct EnvelopeConsumerDisclosuresConsumerDisclosureGetEnvelopeRecipientRequest<'a> {
+    client: &'a EnvelopeConsumerDisclosures,
+    account_id: &'a str,
+    envelope_id: &'a str,
+    recipient_id: &'a str,
+    lang_code: Option<&'a str>,
+}
+
+impl<'a> EnvelopeConsumerDisclosuresConsumerDisclosureGetEnvelopeRecipientRequest<'a> {
+    /// Sets the `langCode` query parameter.
+    pub fn lang_code(mut self, lang_code: &'a str) -> Self {
+        self.lang_code = Some(lang_code);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::ConsumerDisclosure>> {
+        let Self {
+            client,
+            account_id,
+            envelope_id,
+            recipient_id,
+            lang_code,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = lang_code {
+            query_args.push(("langCode".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v2.1/accounts/{}/envelopes/{}/recipients/{}/consumer_disclosure?{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id),
+                crate::progenitor_support::encode_path(recipient_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct EnvelopeConsumerDisclosures {
     pub client: Client,
 }
@@ -107,6 +161,33 @@ impl EnvelopeConsumerDisclosures {
             )
             .await
     }
+    /**
+     * Gets the default Electronic Record and Signature Disclosure for an envelope.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/recipients/{recipientId}/consumer_disclosure` endpoint.
+     *
+     * As opposed to `consumer_disclosure_get_envelope_recipient`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Retrieves the default, HTML-formatted Electronic Record and Signature Disclosure (ERSD) for the envelope that you specify.
+     *
+     * This is the default ERSD disclosure that DocuSign provides for the convenience of U.S.-based customers only. This default disclosure is only valid for transactions between U.S.-based parties.
+     *
+     * To set the language of the disclosure that you want to retrieve, use the optional `langCode` query parameter.
+     */
+    pub fn consumer_disclosure_get_envelope_recipient_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        envelope_id: &'a str,
+        recipient_id: &'a str,
+    ) -> EnvelopeConsumerDisclosuresConsumerDisclosureGetEnvelopeRecipientRequest<'a> {
+        EnvelopeConsumerDisclosuresConsumerDisclosureGetEnvelopeRecipientRequest {
+            client: self,
+            account_id,
+            envelope_id,
+            recipient_id,
+            lang_code: None,
+        }
+    }
     /**
      * Gets the Electronic Record and Signature Disclosure for a specific envelope recipient.
      *
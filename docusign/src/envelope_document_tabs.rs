@@ -1,6 +1,71 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`EnvelopeDocumentTabs::tabs_get_document`], set with a builder.
+pub struct EnvelopeDocumentTabsTabsGetDocumentRequest<'a> {
+    client: &'a EnvelopeDocumentTabs,
+    account_id: &'a str,
+    document_id: &'a str,
+    envelope_id: &'a str,
+    include_metadata: Option<&'a str>,
+    page_numbers: Option<&'a str>,
+}
+
+impl<'a> EnvelopeDocumentTabsTabsGetDocumentRequest<'a> {
+    /// Sets the `include_metadata` query parameter.
+    pub fn include_metadata(mut self, include_metadata: &'a str) -> Self {
+        self.include_metadata = Some(include_metadata);
+        self
+    }
+
+    /// Sets the `page_numbers` query parameter.
+    pub fn page_numbers(mut self, page_numbers: &'a str) -> Self {
+        self.page_numbers = Some(page_numbers);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::EnvelopeDocumentTabs>> {
+        let Self {
+            client,
+            account_id,
+            document_id,
+            envelope_id,
+            include_metadata,
+            page_numbers,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = include_metadata {
+            query_args.push(("include_metadata".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_numbers {
+            query_args.push(("page_numbers".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v2.1/accounts/{}/envelopes/{}/documents/{}/tabs?{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id),
+                crate::progenitor_support::encode_path(document_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct EnvelopeDocumentTabs {
     pub client: Client,
 }
@@ -107,6 +172,30 @@ impl EnvelopeDocumentTabs {
             )
             .await
     }
+    /**
+     * Returns the tabs on a document.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents/{documentId}/tabs` endpoint.
+     *
+     * As opposed to `tabs_get_document`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * This method returns the tabs associated with a document.
+     */
+    pub fn tabs_get_document_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        document_id: &'a str,
+        envelope_id: &'a str,
+    ) -> EnvelopeDocumentTabsTabsGetDocumentRequest<'a> {
+        EnvelopeDocumentTabsTabsGetDocumentRequest {
+            client: self,
+            account_id,
+            document_id,
+            envelope_id,
+            include_metadata: None,
+            page_numbers: None,
+        }
+    }
     /**
      * Updates the tabs for an envelope document.
      *
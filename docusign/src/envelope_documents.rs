@@ -1,6 +1,256 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`EnvelopeDocuments::documents_get`], set with a builder.
+pub struct EnvelopeDocumentsDocumentsGetRequest<'a> {
+    client: &'a EnvelopeDocuments,
+    account_id: &'a str,
+    envelope_id: &'a str,
+    documents_by_userid: Option<&'a str>,
+    include_document_size: Option<&'a str>,
+    include_metadata: Option<&'a str>,
+    include_tabs: Option<&'a str>,
+    recipient_id: Option<&'a str>,
+    shared_user_id: Option<&'a str>,
+}
+
+impl<'a> EnvelopeDocumentsDocumentsGetRequest<'a> {
+    /// Sets the `documents_by_userid` query parameter.
+    pub fn documents_by_userid(mut self, documents_by_userid: &'a str) -> Self {
+        self.documents_by_userid = Some(documents_by_userid);
+        self
+    }
+
+    /// Sets the `include_document_size` query parameter.
+    pub fn include_document_size(mut self, include_document_size: &'a str) -> Self {
+        self.include_document_size = Some(include_document_size);
+        self
+    }
+
+    /// Sets the `include_metadata` query parameter.
+    pub fn include_metadata(mut self, include_metadata: &'a str) -> Self {
+        self.include_metadata = Some(include_metadata);
+        self
+    }
+
+    /// Sets the `include_tabs` query parameter.
+    pub fn include_tabs(mut self, include_tabs: &'a str) -> Self {
+        self.include_tabs = Some(include_tabs);
+        self
+    }
+
+    /// Sets the `recipient_id` query parameter.
+    pub fn recipient_id(mut self, recipient_id: &'a str) -> Self {
+        self.recipient_id = Some(recipient_id);
+        self
+    }
+
+    /// Sets the `shared_user_id` query parameter.
+    pub fn shared_user_id(mut self, shared_user_id: &'a str) -> Self {
+        self.shared_user_id = Some(shared_user_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::EnvelopeDocumentsResult>> {
+        let Self {
+            client,
+            account_id,
+            envelope_id,
+            documents_by_userid,
+            include_document_size,
+            include_metadata,
+            include_tabs,
+            recipient_id,
+            shared_user_id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = documents_by_userid {
+            query_args.push(("documents_by_userid".to_string(), v.to_string()));
+        }
+        if let Some(v) = include_document_size {
+            query_args.push(("include_document_size".to_string(), v.to_string()));
+        }
+        if let Some(v) = include_metadata {
+            query_args.push(("include_metadata".to_string(), v.to_string()));
+        }
+        if let Some(v) = include_tabs {
+            query_args.push(("include_tabs".to_string(), v.to_string()));
+        }
+        if let Some(v) = recipient_id {
+            query_args.push(("recipient_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = shared_user_id {
+            query_args.push(("shared_user_id".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v2.1/accounts/{}/envelopes/{}/documents?{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`EnvelopeDocuments::documents_get_document`], set with a builder.
+pub struct EnvelopeDocumentsDocumentsGetDocumentRequest<'a> {
+    client: &'a EnvelopeDocuments,
+    account_id: &'a str,
+    document_id: &'a str,
+    envelope_id: &'a str,
+    certificate: Option<&'a str>,
+    documents_by_userid: Option<&'a str>,
+    encoding: Option<&'a str>,
+    encrypt: Option<&'a str>,
+    language: Option<&'a str>,
+    recipient_id: Option<&'a str>,
+    shared_user_id: Option<&'a str>,
+    show_changes: Option<&'a str>,
+    watermark: Option<&'a str>,
+}
+
+impl<'a> EnvelopeDocumentsDocumentsGetDocumentRequest<'a> {
+    /// Sets the `certificate` query parameter.
+    pub fn certificate(mut self, certificate: &'a str) -> Self {
+        self.certificate = Some(certificate);
+        self
+    }
+
+    /// Sets the `documents_by_userid` query parameter.
+    pub fn documents_by_userid(mut self, documents_by_userid: &'a str) -> Self {
+        self.documents_by_userid = Some(documents_by_userid);
+        self
+    }
+
+    /// Sets the `encoding` query parameter.
+    pub fn encoding(mut self, encoding: &'a str) -> Self {
+        self.encoding = Some(encoding);
+        self
+    }
+
+    /// Sets the `encrypt` query parameter.
+    pub fn encrypt(mut self, encrypt: &'a str) -> Self {
+        self.encrypt = Some(encrypt);
+        self
+    }
+
+    /// Sets the `language` query parameter.
+    pub fn language(mut self, language: &'a str) -> Self {
+        self.language = Some(language);
+        self
+    }
+
+    /// Sets the `recipient_id` query parameter.
+    pub fn recipient_id(mut self, recipient_id: &'a str) -> Self {
+        self.recipient_id = Some(recipient_id);
+        self
+    }
+
+    /// Sets the `shared_user_id` query parameter.
+    pub fn shared_user_id(mut self, shared_user_id: &'a str) -> Self {
+        self.shared_user_id = Some(shared_user_id);
+        self
+    }
+
+    /// Sets the `show_changes` query parameter.
+    pub fn show_changes(mut self, show_changes: &'a str) -> Self {
+        self.show_changes = Some(show_changes);
+        self
+    }
+
+    /// Sets the `watermark` query parameter.
+    pub fn watermark(mut self, watermark: &'a str) -> Self {
+        self.watermark = Some(watermark);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            account_id,
+            document_id,
+            envelope_id,
+            certificate,
+            documents_by_userid,
+            encoding,
+            encrypt,
+            language,
+            recipient_id,
+            shared_user_id,
+            show_changes,
+            watermark,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = certificate {
+            query_args.push(("certificate".to_string(), v.to_string()));
+        }
+        if let Some(v) = documents_by_userid {
+            query_args.push(("documents_by_userid".to_string(), v.to_string()));
+        }
+        if let Some(v) = encoding {
+            query_args.push(("encoding".to_string(), v.to_string()));
+        }
+        if let Some(v) = encrypt {
+            query_args.push(("encrypt".to_string(), v.to_string()));
+        }
+        if let Some(v) = language {
+            query_args.push(("language".to_string(), v.to_string()));
+        }
+        if let Some(v) = recipient_id {
+            query_args.push(("recipient_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = shared_user_id {
+            query_args.push(("shared_user_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = show_changes {
+            query_args.push(("show_changes".to_string(), v.to_string()));
+        }
+        if let Some(v) = watermark {
+            query_args.push(("watermark".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v2.1/accounts/{}/envelopes/{}/documents/{}?{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id),
+                crate::progenitor_support::encode_path(document_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct EnvelopeDocuments {
     pub client: Client,
 }
@@ -85,6 +335,32 @@ impl EnvelopeDocuments {
             )
             .await
     }
+    /**
+     * Gets a list of envelope documents.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents` endpoint.
+     *
+     * As opposed to `documents_get`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Retrieves a list of documents associated with the specified envelope.
+     */
+    pub fn documents_get_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        envelope_id: &'a str,
+    ) -> EnvelopeDocumentsDocumentsGetRequest<'a> {
+        EnvelopeDocumentsDocumentsGetRequest {
+            client: self,
+            account_id,
+            envelope_id,
+            documents_by_userid: None,
+            include_document_size: None,
+            include_metadata: None,
+            include_tabs: None,
+            recipient_id: None,
+            shared_user_id: None,
+        }
+    }
     /**
      * Adds one or more documents to an existing envelope document.
      *
@@ -282,6 +558,37 @@ impl EnvelopeDocuments {
             )
             .await
     }
+    /**
+     * Gets a document from an envelope.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents/{documentId}` endpoint.
+     *
+     * As opposed to `documents_get_document`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Retrieves the specified document from the envelope. If the account has the Highlight Data Changes feature enabled, there is an option to request that any changes in the envelope be highlighted.
+     */
+    pub fn documents_get_document_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        document_id: &'a str,
+        envelope_id: &'a str,
+    ) -> EnvelopeDocumentsDocumentsGetDocumentRequest<'a> {
+        EnvelopeDocumentsDocumentsGetDocumentRequest {
+            client: self,
+            account_id,
+            document_id,
+            envelope_id,
+            certificate: None,
+            documents_by_userid: None,
+            encoding: None,
+            encrypt: None,
+            language: None,
+            recipient_id: None,
+            shared_user_id: None,
+            show_changes: None,
+            watermark: None,
+        }
+    }
     /**
      * Adds a document to an existing draft envelope.
      *
@@ -1,6 +1,220 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`EnvelopeRecipients::recipients_get`], set with a builder.
+pub struct EnvelopeRecipientsRecipientsGetRequest<'a> {
+    client: &'a EnvelopeRecipients,
+    account_id: &'a str,
+    envelope_id: &'a str,
+    include_anchor_tab_locations: Option<&'a str>,
+    include_extended: Option<&'a str>,
+    include_metadata: Option<&'a str>,
+    include_tabs: Option<&'a str>,
+}
+
+impl<'a> EnvelopeRecipientsRecipientsGetRequest<'a> {
+    /// Sets the `include_anchor_tab_locations` query parameter.
+    pub fn include_anchor_tab_locations(mut self, include_anchor_tab_locations: &'a str) -> Self {
+        self.include_anchor_tab_locations = Some(include_anchor_tab_locations);
+        self
+    }
+
+    /// Sets the `include_extended` query parameter.
+    pub fn include_extended(mut self, include_extended: &'a str) -> Self {
+        self.include_extended = Some(include_extended);
+        self
+    }
+
+    /// Sets the `include_metadata` query parameter.
+    pub fn include_metadata(mut self, include_metadata: &'a str) -> Self {
+        self.include_metadata = Some(include_metadata);
+        self
+    }
+
+    /// Sets the `include_tabs` query parameter.
+    pub fn include_tabs(mut self, include_tabs: &'a str) -> Self {
+        self.include_tabs = Some(include_tabs);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::EnvelopeRecipients>> {
+        let Self {
+            client,
+            account_id,
+            envelope_id,
+            include_anchor_tab_locations,
+            include_extended,
+            include_metadata,
+            include_tabs,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = include_anchor_tab_locations {
+            query_args.push(("include_anchor_tab_locations".to_string(), v.to_string()));
+        }
+        if let Some(v) = include_extended {
+            query_args.push(("include_extended".to_string(), v.to_string()));
+        }
+        if let Some(v) = include_metadata {
+            query_args.push(("include_metadata".to_string(), v.to_string()));
+        }
+        if let Some(v) = include_tabs {
+            query_args.push(("include_tabs".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v2.1/accounts/{}/envelopes/{}/recipients?{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`EnvelopeRecipients::recipients_put`], set with a builder.
+pub struct EnvelopeRecipientsRecipientsPutRequest<'a> {
+    client: &'a EnvelopeRecipients,
+    account_id: &'a str,
+    envelope_id: &'a str,
+    combine_same_order_recipients: Option<&'a str>,
+    offline_signing: Option<&'a str>,
+    resend_envelope: Option<&'a str>,
+    body: &'a crate::types::EnvelopeRecipients,
+}
+
+impl<'a> EnvelopeRecipientsRecipientsPutRequest<'a> {
+    /// Sets the `combine_same_order_recipients` query parameter.
+    pub fn combine_same_order_recipients(mut self, combine_same_order_recipients: &'a str) -> Self {
+        self.combine_same_order_recipients = Some(combine_same_order_recipients);
+        self
+    }
+
+    /// Sets the `offline_signing` query parameter.
+    pub fn offline_signing(mut self, offline_signing: &'a str) -> Self {
+        self.offline_signing = Some(offline_signing);
+        self
+    }
+
+    /// Sets the `resend_envelope` query parameter.
+    pub fn resend_envelope(mut self, resend_envelope: &'a str) -> Self {
+        self.resend_envelope = Some(resend_envelope);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::RecipientsUpdateSummary>> {
+        let Self {
+            client,
+            account_id,
+            envelope_id,
+            combine_same_order_recipients,
+            offline_signing,
+            resend_envelope,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = combine_same_order_recipients {
+            query_args.push(("combine_same_order_recipients".to_string(), v.to_string()));
+        }
+        if let Some(v) = offline_signing {
+            query_args.push(("offline_signing".to_string(), v.to_string()));
+        }
+        if let Some(v) = resend_envelope {
+            query_args.push(("resend_envelope".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v2.1/accounts/{}/envelopes/{}/recipients?{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .put(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`EnvelopeRecipients::recipients_post`], set with a builder.
+pub struct EnvelopeRecipientsRecipientsPostRequest<'a> {
+    client: &'a EnvelopeRecipients,
+    account_id: &'a str,
+    envelope_id: &'a str,
+    resend_envelope: Option<&'a str>,
+    body: &'a crate::types::EnvelopeRecipients,
+}
+
+impl<'a> EnvelopeRecipientsRecipientsPostRequest<'a> {
+    /// Sets the `resend_envelope` query parameter.
+    pub fn resend_envelope(mut self, resend_envelope: &'a str) -> Self {
+        self.resend_envelope = Some(resend_envelope);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::EnvelopeRecipients>> {
+        let Self {
+            client,
+            account_id,
+            envelope_id,
+            resend_envelope,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = resend_envelope {
+            query_args.push(("resend_envelope".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v2.1/accounts/{}/envelopes/{}/recipients?{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct EnvelopeRecipients {
     pub client: Client,
 }
@@ -74,6 +288,32 @@ impl EnvelopeRecipients {
             )
             .await
     }
+    /**
+     * Gets the status of recipients for an envelope.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/recipients` endpoint.
+     *
+     * As opposed to `recipients_get`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Retrieves the status of all recipients in a single envelope and identifies the current recipient in the routing list. This method can also be used to retrieve the tab values.
+     *
+     * The `currentRoutingOrder` property of the response contains the `routingOrder` value of the current recipient indicating that the envelope has been sent to the recipient, but the recipient has not completed their actions.
+     */
+    pub fn recipients_get_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        envelope_id: &'a str,
+    ) -> EnvelopeRecipientsRecipientsGetRequest<'a> {
+        EnvelopeRecipientsRecipientsGetRequest {
+            client: self,
+            account_id,
+            envelope_id,
+            include_anchor_tab_locations: None,
+            include_extended: None,
+            include_metadata: None,
+            include_tabs: None,
+        }
+    }
     /**
      * Updates recipients in a draft envelope or corrects recipient information for an in-process envelope.
      *
@@ -160,6 +400,60 @@ impl EnvelopeRecipients {
             )
             .await
     }
+    /**
+     * Updates recipients in a draft envelope or corrects recipient information for an in-process envelope.
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/recipients` endpoint.
+     *
+     * As opposed to `recipients_put`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Updates the recipients of a draft envelope or corrects recipient information for an in-process envelope.
+     *
+     * If you send information for a recipient that does not already exist in a draft envelope, the recipient is added to the envelope (similar to the EnvelopeRecipients::Create method).
+     *
+     * You can also use this method to resend an envelope to a recipient by using the `resend_envelope` option.
+     *
+     * **Updating Sent Envelopes**
+     *
+     * After an envelope has been sent, you can edit only the following properties:
+     *
+     * - `accessCode`
+     * - `agentCanEditName`
+     * - `agentCanEditEmail`
+     * - `customFields`
+     * - `deliveryMethod`
+     * - `documentVisibility`
+     * - `email`
+     * - `emailNotification`
+     * - `idCheckConfigurationName`
+     * - `name`
+     * - `note`
+     * - `phoneAuthentication`
+     * - `recipientType` (For this to work, you must also change the recipient object to match the recipient type.)
+     * - `requireIdLookup`
+     * - `routingOrder`
+     * - `routingOrder`
+     * - `signingGroupId` (You can change this id to switch to a different signing group and its corresponding set of recipients.)
+     * - `smsAuthentication`
+     * - `suppressEmails`
+     * - `userName`
+     */
+    pub fn recipients_put_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        envelope_id: &'a str,
+        body: &'a crate::types::EnvelopeRecipients,
+    ) -> EnvelopeRecipientsRecipientsPutRequest<'a> {
+        EnvelopeRecipientsRecipientsPutRequest {
+            client: self,
+            account_id,
+            envelope_id,
+            combine_same_order_recipients: None,
+            offline_signing: None,
+            resend_envelope: None,
+            body,
+        }
+    }
     /**
      * Adds one or more recipients to an envelope.
      *
@@ -206,6 +500,31 @@ impl EnvelopeRecipients {
             )
             .await
     }
+    /**
+     * Adds one or more recipients to an envelope.
+     *
+     * This function performs a `POST` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/recipients` endpoint.
+     *
+     * As opposed to `recipients_post`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Adds one or more recipients to an envelope.
+     *
+     * For an in process envelope, one that has been sent and has not been completed or voided, an email is sent to a new recipient when they are reached in the routing order. If the new recipient's routing order is before or the same as the envelope's next recipient, an email is only sent if the optional `resend_envelope` query string is set to **true**.
+     */
+    pub fn recipients_post_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        envelope_id: &'a str,
+        body: &'a crate::types::EnvelopeRecipients,
+    ) -> EnvelopeRecipientsRecipientsPostRequest<'a> {
+        EnvelopeRecipientsRecipientsPostRequest {
+            client: self,
+            account_id,
+            envelope_id,
+            resend_envelope: None,
+            body,
+        }
+    }
     /**
      * Deletes recipients from an envelope.
      *
@@ -1,6 +1,220 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`EnvelopeTemplates::templates_get_document`], set with a builder.
+pub struct EnvelopeTemplatesTemplatesGetDocumentRequest<'a> {
+    client: &'a EnvelopeTemplates,
+    account_id: &'a str,
+    document_id: &'a str,
+    envelope_id: &'a str,
+    include: Option<&'a str>,
+}
+
+impl<'a> EnvelopeTemplatesTemplatesGetDocumentRequest<'a> {
+    /// Sets the `include` query parameter.
+    pub fn include(mut self, include: &'a str) -> Self {
+        self.include = Some(include);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::TemplateInformation>> {
+        let Self {
+            client,
+            account_id,
+            document_id,
+            envelope_id,
+            include,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = include {
+            query_args.push(("include".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v2.1/accounts/{}/envelopes/{}/documents/{}/templates?{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id),
+                crate::progenitor_support::encode_path(document_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`EnvelopeTemplates::templates_post_document`], set with a builder.
+pub struct EnvelopeTemplatesTemplatesPostDocumentRequest<'a> {
+    client: &'a EnvelopeTemplates,
+    account_id: &'a str,
+    document_id: &'a str,
+    envelope_id: &'a str,
+    preserve_template_recipient: Option<&'a str>,
+    body: &'a crate::types::DocumentTemplateList,
+}
+
+impl<'a> EnvelopeTemplatesTemplatesPostDocumentRequest<'a> {
+    /// Sets the `preserve_template_recipient` query parameter.
+    pub fn preserve_template_recipient(mut self, preserve_template_recipient: &'a str) -> Self {
+        self.preserve_template_recipient = Some(preserve_template_recipient);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::DocumentTemplateList>> {
+        let Self {
+            client,
+            account_id,
+            document_id,
+            envelope_id,
+            preserve_template_recipient,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = preserve_template_recipient {
+            query_args.push(("preserve_template_recipient".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v2.1/accounts/{}/envelopes/{}/documents/{}/templates?{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id),
+                crate::progenitor_support::encode_path(document_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`EnvelopeTemplates::templates_get_envelope`], set with a builder.
+pub struct EnvelopeTemplatesTemplatesGetEnvelopeRequest<'a> {
+    client: &'a EnvelopeTemplates,
+    account_id: &'a str,
+    envelope_id: &'a str,
+    include: Option<&'a str>,
+}
+
+impl<'a> EnvelopeTemplatesTemplatesGetEnvelopeRequest<'a> {
+    /// Sets the `include` query parameter.
+    pub fn include(mut self, include: &'a str) -> Self {
+        self.include = Some(include);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::TemplateInformation>> {
+        let Self {
+            client,
+            account_id,
+            envelope_id,
+            include,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = include {
+            query_args.push(("include".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v2.1/accounts/{}/envelopes/{}/templates?{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`EnvelopeTemplates::templates_post_envelope`], set with a builder.
+pub struct EnvelopeTemplatesTemplatesPostEnvelopeRequest<'a> {
+    client: &'a EnvelopeTemplates,
+    account_id: &'a str,
+    envelope_id: &'a str,
+    preserve_template_recipient: Option<&'a str>,
+    body: &'a crate::types::DocumentTemplateList,
+}
+
+impl<'a> EnvelopeTemplatesTemplatesPostEnvelopeRequest<'a> {
+    /// Sets the `preserve_template_recipient` query parameter.
+    pub fn preserve_template_recipient(mut self, preserve_template_recipient: &'a str) -> Self {
+        self.preserve_template_recipient = Some(preserve_template_recipient);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::DocumentTemplateList>> {
+        let Self {
+            client,
+            account_id,
+            envelope_id,
+            preserve_template_recipient,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = preserve_template_recipient {
+            query_args.push(("preserve_template_recipient".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v2.1/accounts/{}/envelopes/{}/templates?{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct EnvelopeTemplates {
     pub client: Client,
 }
@@ -62,6 +276,29 @@ impl EnvelopeTemplates {
             )
             .await
     }
+    /**
+     * Gets the templates associated with a document in an existing envelope.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents/{documentId}/templates` endpoint.
+     *
+     * As opposed to `templates_get_document`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Retrieves the templates associated with a document in the specified envelope.
+     */
+    pub fn templates_get_document_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        document_id: &'a str,
+        envelope_id: &'a str,
+    ) -> EnvelopeTemplatesTemplatesGetDocumentRequest<'a> {
+        EnvelopeTemplatesTemplatesGetDocumentRequest {
+            client: self,
+            account_id,
+            document_id,
+            envelope_id,
+            include: None,
+        }
+    }
     /**
      * Adds templates to a document in an  envelope.
      *
@@ -112,6 +349,31 @@ impl EnvelopeTemplates {
             )
             .await
     }
+    /**
+     * Adds templates to a document in an  envelope.
+     *
+     * This function performs a `POST` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents/{documentId}/templates` endpoint.
+     *
+     * As opposed to `templates_post_document`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Adds templates to a document in the specified envelope.
+     */
+    pub fn templates_post_document_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        document_id: &'a str,
+        envelope_id: &'a str,
+        body: &'a crate::types::DocumentTemplateList,
+    ) -> EnvelopeTemplatesTemplatesPostDocumentRequest<'a> {
+        EnvelopeTemplatesTemplatesPostDocumentRequest {
+            client: self,
+            account_id,
+            document_id,
+            envelope_id,
+            preserve_template_recipient: None,
+            body,
+        }
+    }
     /**
      * Deletes a template from a document in an existing envelope.
      *
@@ -197,6 +459,28 @@ impl EnvelopeTemplates {
             )
             .await
     }
+    /**
+     * Get List of Templates used in an Envelope.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/templates` endpoint.
+     *
+     * As opposed to `templates_get_envelope`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * This returns a list of the server-side templates, their name and ID, used in an envelope.
+     *
+     */
+    pub fn templates_get_envelope_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        envelope_id: &'a str,
+    ) -> EnvelopeTemplatesTemplatesGetEnvelopeRequest<'a> {
+        EnvelopeTemplatesTemplatesGetEnvelopeRequest {
+            client: self,
+            account_id,
+            envelope_id,
+            include: None,
+        }
+    }
     /**
      * Adds templates to an envelope.
      *
@@ -244,4 +528,27 @@ impl EnvelopeTemplates {
             )
             .await
     }
+    /**
+     * Adds templates to an envelope.
+     *
+     * This function performs a `POST` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/templates` endpoint.
+     *
+     * As opposed to `templates_post_envelope`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Adds templates to the specified envelope.
+     */
+    pub fn templates_post_envelope_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        envelope_id: &'a str,
+        body: &'a crate::types::DocumentTemplateList,
+    ) -> EnvelopeTemplatesTemplatesPostEnvelopeRequest<'a> {
+        EnvelopeTemplatesTemplatesPostEnvelopeRequest {
+            client: self,
+            account_id,
+            envelope_id,
+            preserve_template_recipient: None,
+            body,
+        }
+    }
 }
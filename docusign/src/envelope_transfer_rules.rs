@@ -1,6 +1,67 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`EnvelopeTransferRules::get`], set with a builder.
+pub struct EnvelopeTransferRulesGetRequest<'a> {
+    client: &'a EnvelopeTransferRules,
+    account_id: &'a str,
+    count: Option<&'a str>,
+    start_position: Option<&'a str>,
+}
+
+impl<'a> EnvelopeTransferRulesGetRequest<'a> {
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: &'a str) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Sets the `start_position` query parameter.
+    pub fn start_position(mut self, start_position: &'a str) -> Self {
+        self.start_position = Some(start_position);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::EnvelopeTransferRuleInformation>> {
+        let Self {
+            client,
+            account_id,
+            count,
+            start_position,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = count {
+            query_args.push(("count".to_string(), v.to_string()));
+        }
+        if let Some(v) = start_position {
+            query_args.push(("start_position".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v2.1/accounts/{}/envelopes/transfer_rules?{}",
+                crate::progenitor_support::encode_path(account_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct EnvelopeTransferRules {
     pub client: Client,
 }
@@ -58,6 +119,25 @@ impl EnvelopeTransferRules {
             )
             .await
     }
+    /**
+     * Gets envelope transfer rules.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/envelopes/transfer_rules` endpoint.
+     *
+     * As opposed to `get`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * This method retrieves a list of envelope transfer rules associated with an account.
+     *
+     * **Note**: Only Administrators can create and use envelope transfer rules. In addition, to use envelope transfer rules, the **Transfer Custody** feature must be enabled for your account.
+     */
+    pub fn get_request<'a>(&'a self, account_id: &'a str) -> EnvelopeTransferRulesGetRequest<'a> {
+        EnvelopeTransferRulesGetRequest {
+            client: self,
+            account_id,
+            count: None,
+            start_position: None,
+        }
+    }
     /**
      * Changes the status of multiple envelope transfer rules.
      *
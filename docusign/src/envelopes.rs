@@ -1,6 +1,1071 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Envelopes::get`], set with a builder.
+pub struct EnvelopesGetRequest<'a> {
+    client: &'a Envelopes,
+    account_id: &'a str,
+    ac_status: Option<&'a str>,
+    block: Option<&'a str>,
+    cdse_mode: Option<&'a str>,
+    continuation_token: Option<&'a str>,
+    count: Option<&'a str>,
+    custom_field: Option<&'a str>,
+    email: Option<&'a str>,
+    envelope_ids: Option<&'a str>,
+    exclude: Option<&'a str>,
+    folder_ids: Option<&'a str>,
+    folder_types: Option<&'a str>,
+    from_date: Option<&'a str>,
+    from_to_status: Option<&'a str>,
+    include: Option<&'a str>,
+    include_purge_information: Option<&'a str>,
+    intersecting_folder_ids: Option<&'a str>,
+    last_queried_date: Option<&'a str>,
+    order: Option<&'a str>,
+    order_by: Option<&'a str>,
+    powerformids: Option<&'a str>,
+    query_budget: Option<&'a str>,
+    requester_date_format: Option<&'a str>,
+    search_text: Option<&'a str>,
+    start_position: Option<&'a str>,
+    status: Option<&'a str>,
+    to_date: Option<&'a str>,
+    transaction_ids: Option<&'a str>,
+    user_filter: Option<&'a str>,
+    user_id: Option<&'a str>,
+    user_name: Option<&'a str>,
+}
+
+impl<'a> EnvelopesGetRequest<'a> {
+    /// Sets the `ac_status` query parameter.
+    pub fn ac_status(mut self, ac_status: &'a str) -> Self {
+        self.ac_status = Some(ac_status);
+        self
+    }
+
+    /// Sets the `block` query parameter.
+    pub fn block(mut self, block: &'a str) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// Sets the `cdse_mode` query parameter.
+    pub fn cdse_mode(mut self, cdse_mode: &'a str) -> Self {
+        self.cdse_mode = Some(cdse_mode);
+        self
+    }
+
+    /// Sets the `continuation_token` query parameter.
+    pub fn continuation_token(mut self, continuation_token: &'a str) -> Self {
+        self.continuation_token = Some(continuation_token);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: &'a str) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Sets the `custom_field` query parameter.
+    pub fn custom_field(mut self, custom_field: &'a str) -> Self {
+        self.custom_field = Some(custom_field);
+        self
+    }
+
+    /// Sets the `email` query parameter.
+    pub fn email(mut self, email: &'a str) -> Self {
+        self.email = Some(email);
+        self
+    }
+
+    /// Sets the `envelope_ids` query parameter.
+    pub fn envelope_ids(mut self, envelope_ids: &'a str) -> Self {
+        self.envelope_ids = Some(envelope_ids);
+        self
+    }
+
+    /// Sets the `exclude` query parameter.
+    pub fn exclude(mut self, exclude: &'a str) -> Self {
+        self.exclude = Some(exclude);
+        self
+    }
+
+    /// Sets the `folder_ids` query parameter.
+    pub fn folder_ids(mut self, folder_ids: &'a str) -> Self {
+        self.folder_ids = Some(folder_ids);
+        self
+    }
+
+    /// Sets the `folder_types` query parameter.
+    pub fn folder_types(mut self, folder_types: &'a str) -> Self {
+        self.folder_types = Some(folder_types);
+        self
+    }
+
+    /// Sets the `from_date` query parameter.
+    pub fn from_date(mut self, from_date: &'a str) -> Self {
+        self.from_date = Some(from_date);
+        self
+    }
+
+    /// Sets the `from_to_status` query parameter.
+    pub fn from_to_status(mut self, from_to_status: &'a str) -> Self {
+        self.from_to_status = Some(from_to_status);
+        self
+    }
+
+    /// Sets the `include` query parameter.
+    pub fn include(mut self, include: &'a str) -> Self {
+        self.include = Some(include);
+        self
+    }
+
+    /// Sets the `include_purge_information` query parameter.
+    pub fn include_purge_information(mut self, include_purge_information: &'a str) -> Self {
+        self.include_purge_information = Some(include_purge_information);
+        self
+    }
+
+    /// Sets the `intersecting_folder_ids` query parameter.
+    pub fn intersecting_folder_ids(mut self, intersecting_folder_ids: &'a str) -> Self {
+        self.intersecting_folder_ids = Some(intersecting_folder_ids);
+        self
+    }
+
+    /// Sets the `last_queried_date` query parameter.
+    pub fn last_queried_date(mut self, last_queried_date: &'a str) -> Self {
+        self.last_queried_date = Some(last_queried_date);
+        self
+    }
+
+    /// Sets the `order` query parameter.
+    pub fn order(mut self, order: &'a str) -> Self {
+        self.order = Some(order);
+        self
+    }
+
+    /// Sets the `order_by` query parameter.
+    pub fn order_by(mut self, order_by: &'a str) -> Self {
+        self.order_by = Some(order_by);
+        self
+    }
+
+    /// Sets the `powerformids` query parameter.
+    pub fn powerformids(mut self, powerformids: &'a str) -> Self {
+        self.powerformids = Some(powerformids);
+        self
+    }
+
+    /// Sets the `query_budget` query parameter.
+    pub fn query_budget(mut self, query_budget: &'a str) -> Self {
+        self.query_budget = Some(query_budget);
+        self
+    }
+
+    /// Sets the `requester_date_format` query parameter.
+    pub fn requester_date_format(mut self, requester_date_format: &'a str) -> Self {
+        self.requester_date_format = Some(requester_date_format);
+        self
+    }
+
+    /// Sets the `search_text` query parameter.
+    pub fn search_text(mut self, search_text: &'a str) -> Self {
+        self.search_text = Some(search_text);
+        self
+    }
+
+    /// Sets the `start_position` query parameter.
+    pub fn start_position(mut self, start_position: &'a str) -> Self {
+        self.start_position = Some(start_position);
+        self
+    }
+
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: &'a str) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Sets the `to_date` query parameter.
+    pub fn to_date(mut self, to_date: &'a str) -> Self {
+        self.to_date = Some(to_date);
+        self
+    }
+
+    /// Sets the `transaction_ids` query parameter.
+    pub fn transaction_ids(mut self, transaction_ids: &'a str) -> Self {
+        self.transaction_ids = Some(transaction_ids);
+        self
+    }
+
+    /// Sets the `user_filter` query parameter.
+    pub fn user_filter(mut self, user_filter: &'a str) -> Self {
+        self.user_filter = Some(user_filter);
+        self
+    }
+
+    /// Sets the `user_id` query parameter.
+    pub fn user_id(mut self, user_id: &'a str) -> Self {
+        self.user_id = Some(user_id);
+        self
+    }
+
+    /// Sets the `user_name` query parameter.
+    pub fn user_name(mut self, user_name: &'a str) -> Self {
+        self.user_name = Some(user_name);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::EnvelopesInformation>> {
+        let Self {
+            client,
+            account_id,
+            ac_status,
+            block,
+            cdse_mode,
+            continuation_token,
+            count,
+            custom_field,
+            email,
+            envelope_ids,
+            exclude,
+            folder_ids,
+            folder_types,
+            from_date,
+            from_to_status,
+            include,
+            include_purge_information,
+            intersecting_folder_ids,
+            last_queried_date,
+            order,
+            order_by,
+            powerformids,
+            query_budget,
+            requester_date_format,
+            search_text,
+            start_position,
+            status,
+            to_date,
+            transaction_ids,
+            user_filter,
+            user_id,
+            user_name,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = ac_status {
+            query_args.push(("ac_status".to_string(), v.to_string()));
+        }
+        if let Some(v) = block {
+            query_args.push(("block".to_string(), v.to_string()));
+        }
+        if let Some(v) = cdse_mode {
+            query_args.push(("cdse_mode".to_string(), v.to_string()));
+        }
+        if let Some(v) = continuation_token {
+            query_args.push(("continuation_token".to_string(), v.to_string()));
+        }
+        if let Some(v) = count {
+            query_args.push(("count".to_string(), v.to_string()));
+        }
+        if let Some(v) = custom_field {
+            query_args.push(("custom_field".to_string(), v.to_string()));
+        }
+        if let Some(v) = email {
+            query_args.push(("email".to_string(), v.to_string()));
+        }
+        if let Some(v) = envelope_ids {
+            query_args.push(("envelope_ids".to_string(), v.to_string()));
+        }
+        if let Some(v) = exclude {
+            query_args.push(("exclude".to_string(), v.to_string()));
+        }
+        if let Some(v) = folder_ids {
+            query_args.push(("folder_ids".to_string(), v.to_string()));
+        }
+        if let Some(v) = folder_types {
+            query_args.push(("folder_types".to_string(), v.to_string()));
+        }
+        if let Some(v) = from_date {
+            query_args.push(("from_date".to_string(), v.to_string()));
+        }
+        if let Some(v) = from_to_status {
+            query_args.push(("from_to_status".to_string(), v.to_string()));
+        }
+        if let Some(v) = include {
+            query_args.push(("include".to_string(), v.to_string()));
+        }
+        if let Some(v) = include_purge_information {
+            query_args.push(("include_purge_information".to_string(), v.to_string()));
+        }
+        if let Some(v) = intersecting_folder_ids {
+            query_args.push(("intersecting_folder_ids".to_string(), v.to_string()));
+        }
+        if let Some(v) = last_queried_date {
+            query_args.push(("last_queried_date".to_string(), v.to_string()));
+        }
+        if let Some(v) = order {
+            query_args.push(("order".to_string(), v.to_string()));
+        }
+        if let Some(v) = order_by {
+            query_args.push(("order_by".to_string(), v.to_string()));
+        }
+        if let Some(v) = powerformids {
+            query_args.push(("powerformids".to_string(), v.to_string()));
+        }
+        if let Some(v) = query_budget {
+            query_args.push(("query_budget".to_string(), v.to_string()));
+        }
+        if let Some(v) = requester_date_format {
+            query_args.push(("requester_date_format".to_string(), v.to_string()));
+        }
+        if let Some(v) = search_text {
+            query_args.push(("search_text".to_string(), v.to_string()));
+        }
+        if let Some(v) = start_position {
+            query_args.push(("start_position".to_string(), v.to_string()));
+        }
+        if let Some(v) = status {
+            query_args.push(("status".to_string(), v.to_string()));
+        }
+        if let Some(v) = to_date {
+            query_args.push(("to_date".to_string(), v.to_string()));
+        }
+        if let Some(v) = transaction_ids {
+            query_args.push(("transaction_ids".to_string(), v.to_string()));
+        }
+        if let Some(v) = user_filter {
+            query_args.push(("user_filter".to_string(), v.to_string()));
+        }
+        if let Some(v) = user_id {
+            query_args.push(("user_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = user_name {
+            query_args.push(("user_name".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v2.1/accounts/{}/envelopes?{}",
+                crate::progenitor_support::encode_path(account_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Envelopes::post`], set with a builder.
+pub struct EnvelopesPostRequest<'a> {
+    client: &'a Envelopes,
+    account_id: &'a str,
+    cdse_mode: Option<&'a str>,
+    change_routing_order: Option<&'a str>,
+    completed_documents_only: Option<&'a str>,
+    merge_roles_on_draft: Option<&'a str>,
+    body: &'a crate::types::EnvelopeDefinition,
+}
+
+impl<'a> EnvelopesPostRequest<'a> {
+    /// Sets the `cdse_mode` query parameter.
+    pub fn cdse_mode(mut self, cdse_mode: &'a str) -> Self {
+        self.cdse_mode = Some(cdse_mode);
+        self
+    }
+
+    /// Sets the `change_routing_order` query parameter.
+    pub fn change_routing_order(mut self, change_routing_order: &'a str) -> Self {
+        self.change_routing_order = Some(change_routing_order);
+        self
+    }
+
+    /// Sets the `completed_documents_only` query parameter.
+    pub fn completed_documents_only(mut self, completed_documents_only: &'a str) -> Self {
+        self.completed_documents_only = Some(completed_documents_only);
+        self
+    }
+
+    /// Sets the `merge_roles_on_draft` query parameter.
+    pub fn merge_roles_on_draft(mut self, merge_roles_on_draft: &'a str) -> Self {
+        self.merge_roles_on_draft = Some(merge_roles_on_draft);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::EnvelopeSummary>> {
+        let Self {
+            client,
+            account_id,
+            cdse_mode,
+            change_routing_order,
+            completed_documents_only,
+            merge_roles_on_draft,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = cdse_mode {
+            query_args.push(("cdse_mode".to_string(), v.to_string()));
+        }
+        if let Some(v) = change_routing_order {
+            query_args.push(("change_routing_order".to_string(), v.to_string()));
+        }
+        if let Some(v) = completed_documents_only {
+            query_args.push(("completed_documents_only".to_string(), v.to_string()));
+        }
+        if let Some(v) = merge_roles_on_draft {
+            query_args.push(("merge_roles_on_draft".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v2.1/accounts/{}/envelopes?{}",
+                crate::progenitor_support::encode_path(account_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Envelopes::put_status`], set with a builder.
+pub struct EnvelopesPutStatusRequest<'a> {
+    client: &'a Envelopes,
+    account_id: &'a str,
+    ac_status: Option<&'a str>,
+    block: Option<&'a str>,
+    count: Option<&'a str>,
+    email: Option<&'a str>,
+    envelope_ids: Option<&'a str>,
+    from_date: Option<&'a str>,
+    from_to_status: Option<&'a str>,
+    start_position: Option<&'a str>,
+    status: Option<&'a str>,
+    to_date: Option<&'a str>,
+    transaction_ids: Option<&'a str>,
+    user_name: Option<&'a str>,
+    body: &'a crate::types::EnvelopeIdsRequest,
+}
+
+impl<'a> EnvelopesPutStatusRequest<'a> {
+    /// Sets the `ac_status` query parameter.
+    pub fn ac_status(mut self, ac_status: &'a str) -> Self {
+        self.ac_status = Some(ac_status);
+        self
+    }
+
+    /// Sets the `block` query parameter.
+    pub fn block(mut self, block: &'a str) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: &'a str) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Sets the `email` query parameter.
+    pub fn email(mut self, email: &'a str) -> Self {
+        self.email = Some(email);
+        self
+    }
+
+    /// Sets the `envelope_ids` query parameter.
+    pub fn envelope_ids(mut self, envelope_ids: &'a str) -> Self {
+        self.envelope_ids = Some(envelope_ids);
+        self
+    }
+
+    /// Sets the `from_date` query parameter.
+    pub fn from_date(mut self, from_date: &'a str) -> Self {
+        self.from_date = Some(from_date);
+        self
+    }
+
+    /// Sets the `from_to_status` query parameter.
+    pub fn from_to_status(mut self, from_to_status: &'a str) -> Self {
+        self.from_to_status = Some(from_to_status);
+        self
+    }
+
+    /// Sets the `start_position` query parameter.
+    pub fn start_position(mut self, start_position: &'a str) -> Self {
+        self.start_position = Some(start_position);
+        self
+    }
+
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: &'a str) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Sets the `to_date` query parameter.
+    pub fn to_date(mut self, to_date: &'a str) -> Self {
+        self.to_date = Some(to_date);
+        self
+    }
+
+    /// Sets the `transaction_ids` query parameter.
+    pub fn transaction_ids(mut self, transaction_ids: &'a str) -> Self {
+        self.transaction_ids = Some(transaction_ids);
+        self
+    }
+
+    /// Sets the `user_name` query parameter.
+    pub fn user_name(mut self, user_name: &'a str) -> Self {
+        self.user_name = Some(user_name);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::EnvelopesInformation>> {
+        let Self {
+            client,
+            account_id,
+            ac_status,
+            block,
+            count,
+            email,
+            envelope_ids,
+            from_date,
+            from_to_status,
+            start_position,
+            status,
+            to_date,
+            transaction_ids,
+            user_name,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = ac_status {
+            query_args.push(("ac_status".to_string(), v.to_string()));
+        }
+        if let Some(v) = block {
+            query_args.push(("block".to_string(), v.to_string()));
+        }
+        if let Some(v) = count {
+            query_args.push(("count".to_string(), v.to_string()));
+        }
+        if let Some(v) = email {
+            query_args.push(("email".to_string(), v.to_string()));
+        }
+        if let Some(v) = envelope_ids {
+            query_args.push(("envelope_ids".to_string(), v.to_string()));
+        }
+        if let Some(v) = from_date {
+            query_args.push(("from_date".to_string(), v.to_string()));
+        }
+        if let Some(v) = from_to_status {
+            query_args.push(("from_to_status".to_string(), v.to_string()));
+        }
+        if let Some(v) = start_position {
+            query_args.push(("start_position".to_string(), v.to_string()));
+        }
+        if let Some(v) = status {
+            query_args.push(("status".to_string(), v.to_string()));
+        }
+        if let Some(v) = to_date {
+            query_args.push(("to_date".to_string(), v.to_string()));
+        }
+        if let Some(v) = transaction_ids {
+            query_args.push(("transaction_ids".to_string(), v.to_string()));
+        }
+        if let Some(v) = user_name {
+            query_args.push(("user_name".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v2.1/accounts/{}/envelopes/status?{}",
+                crate::progenitor_support::encode_path(account_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .put(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Envelopes::get_envelopes`], set with a builder.
+pub struct EnvelopesGetEnvelopesRequest<'a> {
+    client: &'a Envelopes,
+    account_id: &'a str,
+    envelope_id: &'a str,
+    advanced_update: Option<&'a str>,
+    include: Option<&'a str>,
+}
+
+impl<'a> EnvelopesGetEnvelopesRequest<'a> {
+    /// Sets the `advanced_update` query parameter.
+    pub fn advanced_update(mut self, advanced_update: &'a str) -> Self {
+        self.advanced_update = Some(advanced_update);
+        self
+    }
+
+    /// Sets the `include` query parameter.
+    pub fn include(mut self, include: &'a str) -> Self {
+        self.include = Some(include);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::Envelope>> {
+        let Self {
+            client,
+            account_id,
+            envelope_id,
+            advanced_update,
+            include,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = advanced_update {
+            query_args.push(("advanced_update".to_string(), v.to_string()));
+        }
+        if let Some(v) = include {
+            query_args.push(("include".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v2.1/accounts/{}/envelopes/{}?{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Envelopes::put`], set with a builder.
+pub struct EnvelopesPutRequest<'a> {
+    client: &'a Envelopes,
+    account_id: &'a str,
+    envelope_id: &'a str,
+    advanced_update: Option<&'a str>,
+    resend_envelope: Option<&'a str>,
+    body: &'a crate::types::Envelope,
+}
+
+impl<'a> EnvelopesPutRequest<'a> {
+    /// Sets the `advanced_update` query parameter.
+    pub fn advanced_update(mut self, advanced_update: &'a str) -> Self {
+        self.advanced_update = Some(advanced_update);
+        self
+    }
+
+    /// Sets the `resend_envelope` query parameter.
+    pub fn resend_envelope(mut self, resend_envelope: &'a str) -> Self {
+        self.resend_envelope = Some(resend_envelope);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::EnvelopeUpdateSummary>> {
+        let Self {
+            client,
+            account_id,
+            envelope_id,
+            advanced_update,
+            resend_envelope,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = advanced_update {
+            query_args.push(("advanced_update".to_string(), v.to_string()));
+        }
+        if let Some(v) = resend_envelope {
+            query_args.push(("resend_envelope".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v2.1/accounts/{}/envelopes/{}?{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .put(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Envelopes::pages_get_page_image`], set with a builder.
+pub struct EnvelopesPagesGetPageImageRequest<'a> {
+    client: &'a Envelopes,
+    account_id: &'a str,
+    document_id: &'a str,
+    envelope_id: &'a str,
+    count: Option<&'a str>,
+    dpi: Option<&'a str>,
+    max_height: Option<&'a str>,
+    max_width: Option<&'a str>,
+    nocache: Option<&'a str>,
+    show_changes: Option<&'a str>,
+    start_position: Option<&'a str>,
+}
+
+impl<'a> EnvelopesPagesGetPageImageRequest<'a> {
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: &'a str) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Sets the `dpi` query parameter.
+    pub fn dpi(mut self, dpi: &'a str) -> Self {
+        self.dpi = Some(dpi);
+        self
+    }
+
+    /// Sets the `max_height` query parameter.
+    pub fn max_height(mut self, max_height: &'a str) -> Self {
+        self.max_height = Some(max_height);
+        self
+    }
+
+    /// Sets the `max_width` query parameter.
+    pub fn max_width(mut self, max_width: &'a str) -> Self {
+        self.max_width = Some(max_width);
+        self
+    }
+
+    /// Sets the `nocache` query parameter.
+    pub fn nocache(mut self, nocache: &'a str) -> Self {
+        self.nocache = Some(nocache);
+        self
+    }
+
+    /// Sets the `show_changes` query parameter.
+    pub fn show_changes(mut self, show_changes: &'a str) -> Self {
+        self.show_changes = Some(show_changes);
+        self
+    }
+
+    /// Sets the `start_position` query parameter.
+    pub fn start_position(mut self, start_position: &'a str) -> Self {
+        self.start_position = Some(start_position);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::PageImages>> {
+        let Self {
+            client,
+            account_id,
+            document_id,
+            envelope_id,
+            count,
+            dpi,
+            max_height,
+            max_width,
+            nocache,
+            show_changes,
+            start_position,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = count {
+            query_args.push(("count".to_string(), v.to_string()));
+        }
+        if let Some(v) = dpi {
+            query_args.push(("dpi".to_string(), v.to_string()));
+        }
+        if let Some(v) = max_height {
+            query_args.push(("max_height".to_string(), v.to_string()));
+        }
+        if let Some(v) = max_width {
+            query_args.push(("max_width".to_string(), v.to_string()));
+        }
+        if let Some(v) = nocache {
+            query_args.push(("nocache".to_string(), v.to_string()));
+        }
+        if let Some(v) = show_changes {
+            query_args.push(("show_changes".to_string(), v.to_string()));
+        }
+        if let Some(v) = start_position {
+            query_args.push(("start_position".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v2.1/accounts/{}/envelopes/{}/documents/{}/pages?{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id),
+                crate::progenitor_support::encode_path(document_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Envelopes::pages_get_page_image_envelopes`], set with a builder.
+pub struct EnvelopesPagesGetPageImageEnvelopesRequest<'a> {
+    client: &'a Envelopes,
+    account_id: &'a str,
+    document_id: &'a str,
+    envelope_id: &'a str,
+    page_number: &'a str,
+    dpi: Option<&'a str>,
+    max_height: Option<&'a str>,
+    max_width: Option<&'a str>,
+    show_changes: Option<&'a str>,
+}
+
+impl<'a> EnvelopesPagesGetPageImageEnvelopesRequest<'a> {
+    /// Sets the `dpi` query parameter.
+    pub fn dpi(mut self, dpi: &'a str) -> Self {
+        self.dpi = Some(dpi);
+        self
+    }
+
+    /// Sets the `max_height` query parameter.
+    pub fn max_height(mut self, max_height: &'a str) -> Self {
+        self.max_height = Some(max_height);
+        self
+    }
+
+    /// Sets the `max_width` query parameter.
+    pub fn max_width(mut self, max_width: &'a str) -> Self {
+        self.max_width = Some(max_width);
+        self
+    }
+
+    /// Sets the `show_changes` query parameter.
+    pub fn show_changes(mut self, show_changes: &'a str) -> Self {
+        self.show_changes = Some(show_changes);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            account_id,
+            document_id,
+            envelope_id,
+            page_number,
+            dpi,
+            max_height,
+            max_width,
+            show_changes,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = dpi {
+            query_args.push(("dpi".to_string(), v.to_string()));
+        }
+        if let Some(v) = max_height {
+            query_args.push(("max_height".to_string(), v.to_string()));
+        }
+        if let Some(v) = max_width {
+            query_args.push(("max_width".to_string(), v.to_string()));
+        }
+        if let Some(v) = show_changes {
+            query_args.push(("show_changes".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v2.1/accounts/{}/envelopes/{}/documents/{}/pages/{}/page_image?{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id),
+                crate::progenitor_support::encode_path(document_id),
+                crate::progenitor_support::encode_path(page_number),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Envelopes::recipients_get_recipient_initials_image`], set with a builder.
+pub struct EnvelopesRecipientsGetRecipientInitialsImageRequest<'a> {
+    client: &'a Envelopes,
+    account_id: &'a str,
+    envelope_id: &'a str,
+    recipient_id: &'a str,
+    include_chrome: Option<&'a str>,
+}
+
+impl<'a> EnvelopesRecipientsGetRecipientInitialsImageRequest<'a> {
+    /// Sets the `include_chrome` query parameter.
+    pub fn include_chrome(mut self, include_chrome: &'a str) -> Self {
+        self.include_chrome = Some(include_chrome);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            account_id,
+            envelope_id,
+            recipient_id,
+            include_chrome,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = include_chrome {
+            query_args.push(("include_chrome".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v2.1/accounts/{}/envelopes/{}/recipients/{}/initials_image?{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id),
+                crate::progenitor_support::encode_path(recipient_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Envelopes::recipients_get_recipient_signature_image`], set with a builder.
+pub struct EnvelopesRecipientsGetRecipientSignatureImageRequest<'a> {
+    client: &'a Envelopes,
+    account_id: &'a str,
+    envelope_id: &'a str,
+    recipient_id: &'a str,
+    include_chrome: Option<&'a str>,
+}
+
+impl<'a> EnvelopesRecipientsGetRecipientSignatureImageRequest<'a> {
+    /// Sets the `include_chrome` query parameter.
+    pub fn include_chrome(mut self, include_chrome: &'a str) -> Self {
+        self.include_chrome = Some(include_chrome);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            account_id,
+            envelope_id,
+            recipient_id,
+            include_chrome,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = include_chrome {
+            query_args.push(("include_chrome".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v2.1/accounts/{}/envelopes/{}/recipients/{}/signature_image?{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(envelope_id),
+                crate::progenitor_support::encode_path(recipient_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct Envelopes {
     pub client: Client,
 }
@@ -427,6 +1492,122 @@ impl Envelopes {
             )
             .await
     }
+    /**
+     * Gets status changes for one or more envelopes.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/envelopes` endpoint.
+     *
+     * As opposed to `get`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Retrieves a list of envelopes that match your request.
+     * A large set of optional filters let you filter
+     * by date,
+     * by envelope ID,
+     * or by status codes.
+     *
+     * Your request must include one or more of the following parameters:
+     *
+     * * `from_date`
+     * * `envelope_ids`
+     * * `transaction_ids`
+     *
+     *
+     * Getting envelope status using `transaction_ids` is useful
+     * for offline signing situations where it can be used
+     * determine if an envelope was created or not. It can be used
+     * for the cases where a network connection was lost, before
+     * the envelope status could be returned.
+     *
+     * To avoid unnecessary database queries, the DocuSign
+     * signature platform first checks requests to ensure that the
+     * filter set supplied does not result in a zero-size response
+     * before querying the database.
+     *
+     * For example, for a request with a `from_to_status` of
+     * `delivered` and a current `status` of `created,sent`,
+     * DocuSign will always return an empty list.
+     * This is because the request translates to: find the
+     * envelopes that were delivered between the `from_date` and
+     * `to_date` dates that have a current status of `created` or
+     * `sent`. Since an envelope that has been delivered can
+     * never have a status of `created` or `sent`, a zero-size
+     * response would be generated.
+     * In this case, DocuSign does not query the database
+     * and returns an empty list immediately.
+     *
+     *
+     * The following table shows the valid current envelope
+     * statuses (`status` parameter) for the different status
+     * qualifiers (`from_to_status` parameter) in the request. If
+     * the status and status qualifiers in the API request do not
+     * contain any of the values shown in the Valid Current
+     * Statuses column, then an empty list is returned.
+     *
+     * Client applications should check that the statuses (`status`
+     * parameter) they are requesting make sense for a given
+     * `from_to_status` parameter value.
+     *
+     * | Status Qualifier<br>(`from_to_status`) | Effective Status Qualifier | Valid Current Statuses                                                      |
+     * | :------------------------------------- | :------------------------- | :-------------------------------------------------------------------------- |
+     * | any (changed)                          | StatusChanged              | any, created, sent, delivered, signed, completed, declined, voided, deleted |
+     * | created                                | Created                    | any, created, sent, delivered, signed, completed, declined, voided, deleted |
+     * | sent                                   | Sent                       | any, sent, delivered, signed, completed, declined, voided, deleted          |
+     * | delivered                              | StatusChanged              | any, delivered, signed, completed, declined, voided, deleted                |
+     * | signed                                 | StatusChanged              | any, signed, completed, declined, voided, deleted                           |
+     * | completed                              | Completed                  | any, completed, declined, voided, deleted                                   |
+     * | declined                               | StatusChanged              | any, declined, voided, deleted                                              |
+     * | timedout<br>always return zero results | StatusChanged              | any, voided, deleted                                                        |
+     * | voided                                 | Voided                     | any, voided, deleted                                                        |
+     * | deleted                                | StatusChanged              | any, deleted                                                                |
+     *
+     * ## Extraneous results
+     *
+     * In some cases, a request for a specific envelope status will
+     * include envelopes with additional statuses. For example, in
+     * a request with a `from_date` of 2017-01-01, a `to_date` of
+     * 2017-01-07 and the status qualifier (`from_to_status`) set
+     * to `delivered`, the response set might contain envelopes
+     * that were created during that time period, but not delivered
+     * during the time period. As a workaround, check the envelope
+     * status values in the result set as needed.
+     *
+     */
+    pub fn get_request<'a>(&'a self, account_id: &'a str) -> EnvelopesGetRequest<'a> {
+        EnvelopesGetRequest {
+            client: self,
+            account_id,
+            ac_status: None,
+            block: None,
+            cdse_mode: None,
+            continuation_token: None,
+            count: None,
+            custom_field: None,
+            email: None,
+            envelope_ids: None,
+            exclude: None,
+            folder_ids: None,
+            folder_types: None,
+            from_date: None,
+            from_to_status: None,
+            include: None,
+            include_purge_information: None,
+            intersecting_folder_ids: None,
+            last_queried_date: None,
+            order: None,
+            order_by: None,
+            powerformids: None,
+            query_budget: None,
+            requester_date_format: None,
+            search_text: None,
+            start_position: None,
+            status: None,
+            to_date: None,
+            transaction_ids: None,
+            user_filter: None,
+            user_id: None,
+            user_name: None,
+        }
+    }
     /**
      * Creates an envelope.
      *
@@ -563,6 +1744,99 @@ impl Envelopes {
             )
             .await
     }
+    /**
+     * Creates an envelope.
+     *
+     * This function performs a `POST` to the `/v2.1/accounts/{accountId}/envelopes` endpoint.
+     *
+     * As opposed to `post`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Creates and sends an envelope or creates a draft envelope.
+     * Envelopes are fundamental resources in the DocuSign platform.
+     *
+     * With this method you can:
+     *
+     * * Create and send an envelope
+     *   with [documents][], [recipients][], and [tabs][].
+     * * [Create and send an envelope from a template](https://developers.docusign.com/docs/esign-rest-api/esign101/concepts/templates/).
+     * * [Create and send an envelope from
+     *   a combination of documents and templates](https://developers.docusign.com/docs/esign-rest-api/esign101/concepts/templates/composite/).
+     * * Create a draft envelope.
+     *
+     *
+     * When you use this method
+     * to create and send an envelope
+     * in a single request,
+     * the following parameters in the request body (an [`envelopeDefinition`][envelopeDefinition]) are required:
+     *
+     * | Parameter      | Description |
+     * | :--------      | :---------- |
+     * | `status`       | Set to `sent` to send the envelope to recipients.<br>Set to `created` (or don't set at all) to save the envelope as a draft. |
+     * | `emailSubject` | The subject of the email used to send the envelope. |
+     * | `documents`    | The [documents][] to be signed. |
+     * | `recipients`   | The email addresses of the envelope [recipients][]. |
+     *
+     *
+     * **Note**: If the envelope has a workflow definition
+     * and the `workflowStatus` is `paused`,
+     * the envelope will not be sent immediately,
+     * even if the envelope's `status` is `sent`.
+     *
+     * There are many ways to use envelopes.
+     * You can create and send an envelope
+     * with a single API request,
+     * or you can use several API requests
+     * to create, populate, and send envelopes.
+     *
+     *
+     * | See:                  | To learn about:                                                                                                                    |
+     * | :----------------------- | :--------------------------------------------------------------------------------------------------------------------------------- |
+     * | [Envelopes][envelopes]   | Envelopes, [adding documents][addingdocs], [tracking][], [locking][], [deleting][], [templates][]                                  |
+     * | [Documents][documents]   | Documents, [attachments][], [supplemental documents][supdocs], [authoritative copies][authcopies], [purging][]                     |
+     * | [Recipients][recipients] | Recipients, [recipient types][reciptypes], [recipient status][recipstatus]                                                         |
+     * | [Tabs][tabs]             | Tabs, [anchoring tabs][tabanchor],   [custom tabs][tabcustom], [payments][] |
+     *
+     *
+     * [addingdocs]:           https://developers.docusign.com/docs/esign-rest-api/esign101/concepts/envelopes/
+     * [attachments]:          https://developers.docusign.com/docs/esign-rest-api/esign101/concepts/documents/
+     * [authcopies]:           https://developers.docusign.com/docs/esign-rest-api/esign101/concepts/documents/
+     * [conoverview]:          https://developers.docusign.com/docs/esign-rest-api/esign101/concepts/overview
+     * [deleting]:             https://developers.docusign.com/docs/esign-rest-api/esign101/concepts/envelopes/
+     * [documents]:            https://developers.docusign.com/docs/esign-rest-api/esign101/concepts/documents
+     * [envelopeDefinition]:   https://developers.docusign.com/docs/esign-rest-api/reference/Envelopes/Envelopes/create/
+     * [envelopes]:            https://developers.docusign.com/docs/esign-rest-api/esign101/concepts/envelopes
+     * [locking]:              https://developers.docusign.com/docs/esign-rest-api/esign101/concepts/envelopes/
+     * [payments]:             https://developers.docusign.com/docs/esign-rest-api/esign101/concepts/tabs/payment/
+     * [purging]:              https://developers.docusign.com/docs/esign-rest-api/esign101/concepts/documents/
+     * [recipients]:           https://developers.docusign.com/docs/esign-rest-api/esign101/concepts/recipients
+     * [recipstatus]:          https://developers.docusign.com/docs/esign-rest-api/esign101/concepts/recipients/
+     * [reciptypes]:           https://developers.docusign.com/docs/esign-rest-api/esign101/concepts/recipients/
+     * [supdocs]:              https://developers.docusign.com/docs/esign-rest-api/esign101/concepts/documents/
+     * [tabanchor]:            https://developers.docusign.com/docs/esign-rest-api/esign101/concepts/tabs/auto-place/
+     * [tabcustom]:            https://developers.docusign.com/docs/esign-rest-api/esign101/concepts/tabs/custom-tabs/
+     * [tabs]:                 https://developers.docusign.com/docs/esign-rest-api/esign101/concepts/tabs
+     * [tabtypes]:             https://developers.docusign.com/docs/esign-rest-api/esign101/concepts/tabs/
+     * [templates]:            https://developers.docusign.com/docs/esign-rest-api/esign101/concepts/envelopes/
+     * [tracking]:             https://developers.docusign.com/docs/esign-rest-api/esign101/concepts/envelopes/
+     *
+     * **Note**: When you create an envelope by using a [composite template](https://developers.docusign.com/docs/esign-rest-api/esign101/concepts/templates/composite/), you should specify the envelope custom fields in the inline template. Any custom fields that you specify at the root level are ignored.
+     *
+     */
+    pub fn post_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        body: &'a crate::types::EnvelopeDefinition,
+    ) -> EnvelopesPostRequest<'a> {
+        EnvelopesPostRequest {
+            client: self,
+            account_id,
+            cdse_mode: None,
+            change_routing_order: None,
+            completed_documents_only: None,
+            merge_roles_on_draft: None,
+            body,
+        }
+    }
     /**
      * Gets envelope statuses for a set of envelopes.
      *
@@ -749,6 +2023,65 @@ impl Envelopes {
             )
             .await
     }
+    /**
+     * Gets envelope statuses for a set of envelopes.
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/envelopes/status` endpoint.
+     *
+     * As opposed to `put_status`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Retrieves envelope statuses for a set of envelopes.
+     *
+     * You must specify _one_ of the following query parameters:
+     *
+     * | Parameter         | Description                                                                      |
+     * | :---------------- | :------------------------------------------------------------------------------- |
+     * | `from_date`       | a valid UTC DateTime:  `2016-01-01`                                              |
+     * | `envelope_ids`    | A comma-separated list of envelope IDs<br>or the special value `request_body`    |
+     * | `transaction_ids` | A comma-separated list of transaction IDs<br>or the special value `request_body` |
+     *
+     * When you use the special value `request_body`, the request body looks like this:
+     *
+     * ```
+     * {
+     *   "envelopeIds": [
+     *     "44c5ad6c-xxxx-xxxx-xxxx-ebda5e2dfe15",
+     *     "8e26040d-xxxx-xxxx-xxxx-1e29b924d237",
+     *     "c8b40a2d-xxxx-xxxx-xxxx-4fe56fe10f95"
+     *   ]
+     * }
+     * ```
+     *
+     * **Note**: It is an error omit the request body altogether.
+     * The request body must be at least `{}`.
+     *
+     * ### You can find an example of using this API endpoint in the following how-to:
+     *
+     * * [How to list envelope status changes](https://developers.docusign.com/docs/esign-rest-api/how-to/list-envelope-status-changes/)
+     */
+    pub fn put_status_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        body: &'a crate::types::EnvelopeIdsRequest,
+    ) -> EnvelopesPutStatusRequest<'a> {
+        EnvelopesPutStatusRequest {
+            client: self,
+            account_id,
+            ac_status: None,
+            block: None,
+            count: None,
+            email: None,
+            envelope_ids: None,
+            from_date: None,
+            from_to_status: None,
+            start_position: None,
+            status: None,
+            to_date: None,
+            transaction_ids: None,
+            user_name: None,
+            body,
+        }
+    }
     /**
      * Gets the status of a single envelope.
      *
@@ -810,6 +2143,30 @@ impl Envelopes {
             )
             .await
     }
+    /**
+     * Gets the status of a single envelope.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}` endpoint.
+     *
+     * As opposed to `get_envelopes`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Retrieves the overall status for the specified envelope.
+     * To get the status of a list of envelopes, use
+     * [Envelope: listStatusChanges ](https://developers.docusign.com/docs/esign-rest-api/reference/Envelopes/Envelopes/listStatusChanges/).
+     */
+    pub fn get_envelopes_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        envelope_id: &'a str,
+    ) -> EnvelopesGetEnvelopesRequest<'a> {
+        EnvelopesGetEnvelopesRequest {
+            client: self,
+            account_id,
+            envelope_id,
+            advanced_update: None,
+            include: None,
+        }
+    }
     /**
      * Send, void, or modify a draft envelope. Purge documents from a completed envelope.
      *
@@ -1023,6 +2380,194 @@ impl Envelopes {
             )
             .await
     }
+    /**
+     * Send, void, or modify a draft envelope. Purge documents from a completed envelope.
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}` endpoint.
+     *
+     * As opposed to `put`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * This method enables you to make changes to an envelope.
+     * You can use it to:
+     *
+     * * Send a draft envelope
+     * * Void an in-process envelope
+     * * Modify a draft envelope
+     * * Purge documents and envelope metadata from the DocuSign platform
+     *
+     *
+     * <div class="highlight highlight-info">
+     * <p markdown="1">
+     *
+     * Although the request body for this method
+     * is a complete envelope definition,
+     * you only need to provide
+     * the properties that
+     * you're updating.
+     *
+     * </p>
+     * </div>
+     *
+     *
+     * ## Sending a Draft Envelope
+     *
+     * To send a draft envelope, include the following code in the request body:
+     *
+     * ```json
+     * {
+     *   "status": "sent"
+     * }
+     * ```
+     *
+     * You can attach a workflow before sending the envelope:
+     *
+     * ```json
+     * {
+     *   "status": "sent",
+     *   "workflow": {
+     *     "workflowSteps": [
+     *       {
+     *         "action": "pause_before",
+     *         "description": "pause_before routing order 2",
+     *         "itemId": 2,
+     *         "triggerOnItem": "routing_order"
+     *       }
+     *     ]
+     *   }
+     * }
+     * ```
+     *
+     * ## Working with Workflows
+     *
+     * To unpause a workflow, the request body should include this:
+     *
+     * ```json
+     * {
+     *   "workflow": {
+     *     "workflowStatus": "in_progress"
+     *   }
+     * }
+     * ```
+     *
+     * ## Voiding an In-Process Envelope
+     *
+     * To void an in-process envelope, include the following code in the request body:
+     *
+     * ```json
+     * {
+     *   "status": "voided",
+     *   "voidedReason": "The reason for voiding the envelope"
+     * }
+     * ```
+     *
+     * ## Modifying Envelope Email Information
+     *
+     * To change the email subject and message of a draft envelope,
+     * include the following code in the request body:
+     *
+     * ```json
+     * {
+     *   "emailSubject": "new email subject",
+     *   "emailBlurb": "new email message"
+     * }
+     * ```
+     *
+     * ## Purging Documents from DocuSign
+     *
+     *
+     * To place only the documents
+     * in the purge queue,
+     * leaving any
+     * corresponding attachments
+     * and tabs in the DocuSign platform,
+     * set the `purgeState` property
+     * to `documents_queued`.
+     *
+     *
+     * ```json
+     * {
+     *   "purgeState": "documents_queued"
+     * }
+     * ```
+     *
+     * To place documents,
+     * attachments,
+     * and tabs
+     * in the purge queue,
+     * set the `purgeState` property
+     * to `documents_and_metadata_queued`.
+     *
+     * ```json
+     * {
+     *   "purgeState": "documents_and_metadata_queued"
+     * }
+     * ```
+     *
+     *
+     * You can purge documents
+     * only from completed envelopes
+     * that are not marked as the authoritative copy.
+     * The user requesting the purge
+     * must have permission to purge documents
+     * and
+     * must be the sender or be acting on behalf of the sender.
+     *
+     *
+     *
+     * When the purge request is initiated
+     * the items to be purged
+     * are placed in the purge queue
+     * for deletion in 14 days.
+     * The sender
+     * and
+     * all recipients with DocuSign accounts
+     * associated with the envelope
+     * get an email notification
+     * the the documents will be deleted in 14 days.
+     * The notification contains a link
+     * to the documents.
+     * A second email notification
+     * is sent 7 days later.
+     * At the end of the 14-day period
+     * the documents are deleted from the system.
+     * Recipients without DocuSign accounts
+     * do not receive email notifications.
+     *
+     *
+     * If your account has a Document Retention policy,
+     * envelope documents
+     * are automatically placed
+     * in the purge queue,
+     * and notification emails are sent
+     * at the end of the retention period.
+     * Setting a Document Retention policy is the same as setting a
+     * schedule for purging documents.
+     *
+     * ## Removing Documents from the Purge Queue
+     *
+     * To remove documents from the purge queue, include the following code in the request body:
+     *
+     * ```
+     * {
+     *   "purgeState": "documents_dequeued"
+     * }
+     * ```
+     */
+    pub fn put_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        envelope_id: &'a str,
+        body: &'a crate::types::Envelope,
+    ) -> EnvelopesPutRequest<'a> {
+        EnvelopesPutRequest {
+            client: self,
+            account_id,
+            envelope_id,
+            advanced_update: None,
+            resend_envelope: None,
+            body,
+        }
+    }
     /**
      * Gets the envelope audit events for an envelope.
      *
@@ -1134,6 +2679,35 @@ impl Envelopes {
             )
             .await
     }
+    /**
+     * Returns document page image(s) based on input.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents/{documentId}/pages` endpoint.
+     *
+     * As opposed to `pages_get_page_image`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Returns images of the pages in a document for display based on the parameters that you specify.
+     */
+    pub fn pages_get_page_image_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        document_id: &'a str,
+        envelope_id: &'a str,
+    ) -> EnvelopesPagesGetPageImageRequest<'a> {
+        EnvelopesPagesGetPageImageRequest {
+            client: self,
+            account_id,
+            document_id,
+            envelope_id,
+            count: None,
+            dpi: None,
+            max_height: None,
+            max_width: None,
+            nocache: None,
+            show_changes: None,
+            start_position: None,
+        }
+    }
     /**
      * Deletes a page from a document in an envelope.
      *
@@ -1239,6 +2813,34 @@ impl Envelopes {
             )
             .await
     }
+    /**
+     * Gets a page image from an envelope for display.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents/{documentId}/pages/{pageNumber}/page_image` endpoint.
+     *
+     * As opposed to `pages_get_page_image_envelopes`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Returns an image of a page in a document for display.
+     */
+    pub fn pages_get_page_image_envelopes_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        document_id: &'a str,
+        envelope_id: &'a str,
+        page_number: &'a str,
+    ) -> EnvelopesPagesGetPageImageEnvelopesRequest<'a> {
+        EnvelopesPagesGetPageImageEnvelopesRequest {
+            client: self,
+            account_id,
+            document_id,
+            envelope_id,
+            page_number,
+            dpi: None,
+            max_height: None,
+            max_width: None,
+            show_changes: None,
+        }
+    }
     /**
      * Rotates page image from an envelope for display.
      *
@@ -1408,6 +3010,37 @@ impl Envelopes {
             )
             .await
     }
+    /**
+     * Gets the initials image for a user.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/recipients/{recipientId}/initials_image` endpoint.
+     *
+     * As opposed to `recipients_get_recipient_initials_image`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Retrieves the initials image for the specified user. The image is returned in the same format as it was uploaded. In the request you can specify if the chrome (the added line and identifier around the initial image) is returned with the image.
+     *
+     * The userId specified in the endpoint must match the authenticated user's user id and the user must be a member of the account.
+     *
+     * The `signatureIdOrName` paramter accepts signature ID or signature name. DocuSign recommends you use signature ID (`signatureId`), since some names contain characters that do not properly URL encode. If you use the user name, it is likely that the name includes spaces and you might need to URL encode the name before using it in the endpoint.
+     *
+     * For example: "Bob Smith" to "Bob%20Smith"
+     *
+     * Older envelopes might only contain chromed images. If getting the non-chromed image fails, try getting the chromed image.
+     */
+    pub fn recipients_get_recipient_initials_image_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        envelope_id: &'a str,
+        recipient_id: &'a str,
+    ) -> EnvelopesRecipientsGetRecipientInitialsImageRequest<'a> {
+        EnvelopesRecipientsGetRecipientInitialsImageRequest {
+            client: self,
+            account_id,
+            envelope_id,
+            recipient_id,
+            include_chrome: None,
+        }
+    }
     /**
      * Sets the initials image for an accountless signer.
      *
@@ -1540,6 +3173,37 @@ impl Envelopes {
             )
             .await
     }
+    /**
+     * Retrieve signature image information for a signer/sign-in-person recipient.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/recipients/{recipientId}/signature_image` endpoint.
+     *
+     * As opposed to `recipients_get_recipient_signature_image`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Retrieves the specified user signature image. The image is returned in the same format as uploaded. In the request you can specify if the chrome (the added line and identifier around the initial image) is returned with the image.
+     *
+     * The userId specified in the endpoint must match the authenticated user's user ID and the user must be a member of the account.
+     *
+     * The `signatureIdOrName` parameter accepts signature ID or signature name. DocuSign recommends you use signature ID (`signatureId`), since some names contain characters that don't properly URL encode. If you use the user name, it is likely that the name includes spaces and you might need to URL encode the name before using it in the endpoint.
+     *
+     * For example: "Bob Smith" to "Bob%20Smith"
+     *
+     * Older envelopes might only have chromed images. If getting the non-chromed image fails, try getting the chromed image.
+     */
+    pub fn recipients_get_recipient_signature_image_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        envelope_id: &'a str,
+        recipient_id: &'a str,
+    ) -> EnvelopesRecipientsGetRecipientSignatureImageRequest<'a> {
+        EnvelopesRecipientsGetRecipientSignatureImageRequest {
+            client: self,
+            account_id,
+            envelope_id,
+            recipient_id,
+            include_chrome: None,
+        }
+    }
     /**
      * Sets the signature image for an accountless signer.
      *
@@ -0,0 +1,84 @@
+//! The errors returned by the API.
+
+use std::fmt;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// An error returned by the DocuSign API.
+///
+/// FROM: <https://developers.docusign.com/docs/esign-rest-api/esign101/error-codes/>
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default, JsonSchema)]
+pub struct ApiError {
+    /// The DocuSign error code, such as `ENVELOPE_DOES_NOT_EXIST`.
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "errorCode"
+    )]
+    pub error_code: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub message: String,
+}
+
+impl ApiError {
+    fn from_body(body: &[u8]) -> Option<Self> {
+        serde_json::from_slice::<Self>(body)
+            .ok()
+            .filter(|error| !error.error_code.is_empty())
+    }
+}
+
+impl fmt::Display for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({})", self.message, self.error_code)
+    }
+}
+
+impl crate::ClientError {
+    /// Turns an unsuccessful response into an error, parsing its body into an `ApiError`
+    /// when it holds one. Responses to requests that were rate limited become
+    /// `ClientError::RateLimited`. Those keep their `ApiError` as well.
+    pub(crate) fn from_response(
+        status: http::StatusCode,
+        headers: reqwest::header::HeaderMap,
+        body: &[u8],
+    ) -> Self {
+        let rate_limit = crate::RateLimitInfo::from_headers(&headers).unwrap_or_default();
+        if status == http::StatusCode::TOO_MANY_REQUESTS {
+            return crate::ClientError::RateLimited {
+                status,
+                duration: rate_limit.wait().map(|wait| wait.as_secs()),
+                info: rate_limit,
+                error: ApiError::from_body(body).map(Box::new),
+                headers,
+            };
+        }
+
+        if body.is_empty() {
+            return crate::ClientError::HttpError {
+                status,
+                headers,
+                error: "empty response".into(),
+            };
+        }
+
+        match ApiError::from_body(body) {
+            Some(error) => crate::ClientError::ApiError {
+                status,
+                headers,
+                error: Box::new(error),
+            },
+            None => crate::ClientError::HttpError {
+                status,
+                headers,
+                error: String::from_utf8_lossy(body).into(),
+            },
+        }
+    }
+}
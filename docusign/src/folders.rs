@@ -1,6 +1,354 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Folders::get`], set with a builder.
+pub struct FoldersGetRequest<'a> {
+    client: &'a Folders,
+    account_id: &'a str,
+    include: Option<&'a str>,
+    include_items: Option<&'a str>,
+    start_position: Option<&'a str>,
+    template: Option<&'a str>,
+    user_filter: Option<&'a str>,
+}
+
+impl<'a> FoldersGetRequest<'a> {
+    /// Sets the `include` query parameter.
+    pub fn include(mut self, include: &'a str) -> Self {
+        self.include = Some(include);
+        self
+    }
+
+    /// Sets the `include_items` query parameter.
+    pub fn include_items(mut self, include_items: &'a str) -> Self {
+        self.include_items = Some(include_items);
+        self
+    }
+
+    /// Sets the `start_position` query parameter.
+    pub fn start_position(mut self, start_position: &'a str) -> Self {
+        self.start_position = Some(start_position);
+        self
+    }
+
+    /// Sets the `template` query parameter.
+    pub fn template(mut self, template: &'a str) -> Self {
+        self.template = Some(template);
+        self
+    }
+
+    /// Sets the `user_filter` query parameter.
+    pub fn user_filter(mut self, user_filter: &'a str) -> Self {
+        self.user_filter = Some(user_filter);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::FoldersResponse>> {
+        let Self {
+            client,
+            account_id,
+            include,
+            include_items,
+            start_position,
+            template,
+            user_filter,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = include {
+            query_args.push(("include".to_string(), v.to_string()));
+        }
+        if let Some(v) = include_items {
+            query_args.push(("include_items".to_string(), v.to_string()));
+        }
+        if let Some(v) = start_position {
+            query_args.push(("start_position".to_string(), v.to_string()));
+        }
+        if let Some(v) = template {
+            query_args.push(("template".to_string(), v.to_string()));
+        }
+        if let Some(v) = user_filter {
+            query_args.push(("user_filter".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v2.1/accounts/{}/folders?{}",
+                crate::progenitor_support::encode_path(account_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Folders::get_items`], set with a builder.
+pub struct FoldersGetItemsRequest<'a> {
+    client: &'a Folders,
+    account_id: &'a str,
+    folder_id: &'a str,
+    from_date: Option<&'a str>,
+    include_items: Option<&'a str>,
+    owner_email: Option<&'a str>,
+    owner_name: Option<&'a str>,
+    search_text: Option<&'a str>,
+    start_position: Option<&'a str>,
+    status: Option<&'a str>,
+    to_date: Option<&'a str>,
+}
+
+impl<'a> FoldersGetItemsRequest<'a> {
+    /// Sets the `from_date` query parameter.
+    pub fn from_date(mut self, from_date: &'a str) -> Self {
+        self.from_date = Some(from_date);
+        self
+    }
+
+    /// Sets the `include_items` query parameter.
+    pub fn include_items(mut self, include_items: &'a str) -> Self {
+        self.include_items = Some(include_items);
+        self
+    }
+
+    /// Sets the `owner_email` query parameter.
+    pub fn owner_email(mut self, owner_email: &'a str) -> Self {
+        self.owner_email = Some(owner_email);
+        self
+    }
+
+    /// Sets the `owner_name` query parameter.
+    pub fn owner_name(mut self, owner_name: &'a str) -> Self {
+        self.owner_name = Some(owner_name);
+        self
+    }
+
+    /// Sets the `search_text` query parameter.
+    pub fn search_text(mut self, search_text: &'a str) -> Self {
+        self.search_text = Some(search_text);
+        self
+    }
+
+    /// Sets the `start_position` query parameter.
+    pub fn start_position(mut self, start_position: &'a str) -> Self {
+        self.start_position = Some(start_position);
+        self
+    }
+
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: &'a str) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Sets the `to_date` query parameter.
+    pub fn to_date(mut self, to_date: &'a str) -> Self {
+        self.to_date = Some(to_date);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::FolderItemsResponse>> {
+        let Self {
+            client,
+            account_id,
+            folder_id,
+            from_date,
+            include_items,
+            owner_email,
+            owner_name,
+            search_text,
+            start_position,
+            status,
+            to_date,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = from_date {
+            query_args.push(("from_date".to_string(), v.to_string()));
+        }
+        if let Some(v) = include_items {
+            query_args.push(("include_items".to_string(), v.to_string()));
+        }
+        if let Some(v) = owner_email {
+            query_args.push(("owner_email".to_string(), v.to_string()));
+        }
+        if let Some(v) = owner_name {
+            query_args.push(("owner_name".to_string(), v.to_string()));
+        }
+        if let Some(v) = search_text {
+            query_args.push(("search_text".to_string(), v.to_string()));
+        }
+        if let Some(v) = start_position {
+            query_args.push(("start_position".to_string(), v.to_string()));
+        }
+        if let Some(v) = status {
+            query_args.push(("status".to_string(), v.to_string()));
+        }
+        if let Some(v) = to_date {
+            query_args.push(("to_date".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v2.1/accounts/{}/folders/{}?{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(folder_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Folders::search_get_contents`], set with a builder.
+pub struct FoldersSearchGetContentsRequest<'a> {
+    client: &'a Folders,
+    account_id: &'a str,
+    search_folder_id: &'a str,
+    all: Option<&'a str>,
+    count: Option<&'a str>,
+    from_date: Option<&'a str>,
+    include_recipients: Option<&'a str>,
+    order: Option<&'a str>,
+    order_by: Option<&'a str>,
+    start_position: Option<&'a str>,
+    to_date: Option<&'a str>,
+}
+
+impl<'a> FoldersSearchGetContentsRequest<'a> {
+    /// Sets the `all` query parameter.
+    pub fn all(mut self, all: &'a str) -> Self {
+        self.all = Some(all);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: &'a str) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Sets the `from_date` query parameter.
+    pub fn from_date(mut self, from_date: &'a str) -> Self {
+        self.from_date = Some(from_date);
+        self
+    }
+
+    /// Sets the `include_recipients` query parameter.
+    pub fn include_recipients(mut self, include_recipients: &'a str) -> Self {
+        self.include_recipients = Some(include_recipients);
+        self
+    }
+
+    /// Sets the `order` query parameter.
+    pub fn order(mut self, order: &'a str) -> Self {
+        self.order = Some(order);
+        self
+    }
+
+    /// Sets the `order_by` query parameter.
+    pub fn order_by(mut self, order_by: &'a str) -> Self {
+        self.order_by = Some(order_by);
+        self
+    }
+
+    /// Sets the `start_position` query parameter.
+    pub fn start_position(mut self, start_position: &'a str) -> Self {
+        self.start_position = Some(start_position);
+        self
+    }
+
+    /// Sets the `to_date` query parameter.
+    pub fn to_date(mut self, to_date: &'a str) -> Self {
+        self.to_date = Some(to_date);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::FolderItemResponse>> {
+        let Self {
+            client,
+            account_id,
+            search_folder_id,
+            all,
+            count,
+            from_date,
+            include_recipients,
+            order,
+            order_by,
+            start_position,
+            to_date,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = all {
+            query_args.push(("all".to_string(), v.to_string()));
+        }
+        if let Some(v) = count {
+            query_args.push(("count".to_string(), v.to_string()));
+        }
+        if let Some(v) = from_date {
+            query_args.push(("from_date".to_string(), v.to_string()));
+        }
+        if let Some(v) = include_recipients {
+            query_args.push(("include_recipients".to_string(), v.to_string()));
+        }
+        if let Some(v) = order {
+            query_args.push(("order".to_string(), v.to_string()));
+        }
+        if let Some(v) = order_by {
+            query_args.push(("order_by".to_string(), v.to_string()));
+        }
+        if let Some(v) = start_position {
+            query_args.push(("start_position".to_string(), v.to_string()));
+        }
+        if let Some(v) = to_date {
+            query_args.push(("to_date".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v2.1/accounts/{}/search_folders/{}?{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(search_folder_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct Folders {
     pub client: Client,
 }
@@ -82,6 +430,26 @@ impl Folders {
             )
             .await
     }
+    /**
+     * Gets a list of the folders for the account.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/folders` endpoint.
+     *
+     * As opposed to `get`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Retrieves a list of the folders for the account, including the folder hierarchy. You can specify whether to return just the template folder or template folder and normal folders by setting the `template` query string parameter.
+     */
+    pub fn get_request<'a>(&'a self, account_id: &'a str) -> FoldersGetRequest<'a> {
+        FoldersGetRequest {
+            client: self,
+            account_id,
+            include: None,
+            include_items: None,
+            start_position: None,
+            template: None,
+            user_filter: None,
+        }
+    }
     /**
      * Gets a list of the envelopes in the specified folder.
      *
@@ -160,6 +528,34 @@ impl Folders {
             )
             .await
     }
+    /**
+     * Gets a list of the envelopes in the specified folder.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/folders/{folderId}` endpoint.
+     *
+     * As opposed to `get_items`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Retrieves a list of the envelopes in the specified folder. You can narrow the query by specifying search criteria in the query string parameters.
+     */
+    pub fn get_items_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        folder_id: &'a str,
+    ) -> FoldersGetItemsRequest<'a> {
+        FoldersGetItemsRequest {
+            client: self,
+            account_id,
+            folder_id,
+            from_date: None,
+            include_items: None,
+            owner_email: None,
+            owner_name: None,
+            search_text: None,
+            start_position: None,
+            status: None,
+            to_date: None,
+        }
+    }
     /**
      * Moves an envelope from its current folder to the specified folder.
      *
@@ -288,4 +684,38 @@ impl Folders {
             )
             .await
     }
+    /**
+     * Gets a list of envelopes in folders matching the specified criteria.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/search_folders/{searchFolderId}` endpoint.
+     *
+     * As opposed to `search_get_contents`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * **This method is deprecated in API v2.1.**
+     *
+     * Use  [Envelopes::listStatusChanges](https://developers.docusign.com/docs/esign-rest-api/reference/Envelopes/Envelopes/listStatusChanges) instead.
+     *
+     * Retrieves a list of items that match the criteria specified in the query.
+     *
+     * If the user ID of the user making the call is the same as the user ID for any returned recipient, then the userId property is added to the returned information for those recipients.
+     */
+    pub fn search_get_contents_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        search_folder_id: &'a str,
+    ) -> FoldersSearchGetContentsRequest<'a> {
+        FoldersSearchGetContentsRequest {
+            client: self,
+            account_id,
+            search_folder_id,
+            all: None,
+            count: None,
+            from_date: None,
+            include_recipients: None,
+            order: None,
+            order_by: None,
+            start_position: None,
+            to_date: None,
+        }
+    }
 }
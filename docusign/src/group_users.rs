@@ -1,6 +1,68 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`GroupUsers::groups_get`], set with a builder.
+pub struct GroupUsersGroupsGetRequest<'a> {
+    client: &'a GroupUsers,
+    account_id: &'a str,
+    group_id: &'a str,
+    count: Option<&'a str>,
+    start_position: Option<&'a str>,
+}
+
+impl<'a> GroupUsersGroupsGetRequest<'a> {
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: &'a str) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Sets the `start_position` query parameter.
+    pub fn start_position(mut self, start_position: &'a str) -> Self {
+        self.start_position = Some(start_position);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::UsersResponse>> {
+        let Self {
+            client,
+            account_id,
+            group_id,
+            count,
+            start_position,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = count {
+            query_args.push(("count".to_string(), v.to_string()));
+        }
+        if let Some(v) = start_position {
+            query_args.push(("start_position".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v2.1/accounts/{}/groups/{}/users?{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(group_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct GroupUsers {
     pub client: Client,
 }
@@ -59,6 +121,28 @@ impl GroupUsers {
             )
             .await
     }
+    /**
+     * Gets a list of users in a group.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/groups/{groupId}/users` endpoint.
+     *
+     * As opposed to `groups_get`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Retrieves a list of users in a group.
+     */
+    pub fn groups_get_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        group_id: &'a str,
+    ) -> GroupUsersGroupsGetRequest<'a> {
+        GroupUsersGroupsGetRequest {
+            client: self,
+            account_id,
+            group_id,
+            count: None,
+            start_position: None,
+        }
+    }
     /**
      * Adds one or more users to an existing group.
      *
@@ -1,6 +1,98 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Groups::get`], set with a builder.
+pub struct GroupsGetRequest<'a> {
+    client: &'a Groups,
+    account_id: &'a str,
+    count: Option<&'a str>,
+    group_type: Option<&'a str>,
+    include_usercount: Option<&'a str>,
+    search_text: Option<&'a str>,
+    start_position: Option<&'a str>,
+}
+
+impl<'a> GroupsGetRequest<'a> {
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: &'a str) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Sets the `group_type` query parameter.
+    pub fn group_type(mut self, group_type: &'a str) -> Self {
+        self.group_type = Some(group_type);
+        self
+    }
+
+    /// Sets the `include_usercount` query parameter.
+    pub fn include_usercount(mut self, include_usercount: &'a str) -> Self {
+        self.include_usercount = Some(include_usercount);
+        self
+    }
+
+    /// Sets the `search_text` query parameter.
+    pub fn search_text(mut self, search_text: &'a str) -> Self {
+        self.search_text = Some(search_text);
+        self
+    }
+
+    /// Sets the `start_position` query parameter.
+    pub fn start_position(mut self, start_position: &'a str) -> Self {
+        self.start_position = Some(start_position);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::GroupInformation>> {
+        let Self {
+            client,
+            account_id,
+            count,
+            group_type,
+            include_usercount,
+            search_text,
+            start_position,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = count {
+            query_args.push(("count".to_string(), v.to_string()));
+        }
+        if let Some(v) = group_type {
+            query_args.push(("group_type".to_string(), v.to_string()));
+        }
+        if let Some(v) = include_usercount {
+            query_args.push(("include_usercount".to_string(), v.to_string()));
+        }
+        if let Some(v) = search_text {
+            query_args.push(("search_text".to_string(), v.to_string()));
+        }
+        if let Some(v) = start_position {
+            query_args.push(("start_position".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v2.1/accounts/{}/groups?{}",
+                crate::progenitor_support::encode_path(account_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct Groups {
     pub client: Client,
 }
@@ -74,6 +166,26 @@ impl Groups {
             )
             .await
     }
+    /**
+     * Gets information about groups associated with the account.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/groups` endpoint.
+     *
+     * As opposed to `get`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Retrieves information about groups associated with the account.
+     */
+    pub fn get_request<'a>(&'a self, account_id: &'a str) -> GroupsGetRequest<'a> {
+        GroupsGetRequest {
+            client: self,
+            account_id,
+            count: None,
+            group_type: None,
+            include_usercount: None,
+            search_text: None,
+            start_position: None,
+        }
+    }
     /**
      * Updates the group information for a group.
      *
@@ -1,6 +1,67 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Invoices::billing_get`], set with a builder.
+pub struct InvoicesBillingGetRequest<'a> {
+    client: &'a Invoices,
+    account_id: &'a str,
+    from_date: Option<&'a str>,
+    to_date: Option<&'a str>,
+}
+
+impl<'a> InvoicesBillingGetRequest<'a> {
+    /// Sets the `from_date` query parameter.
+    pub fn from_date(mut self, from_date: &'a str) -> Self {
+        self.from_date = Some(from_date);
+        self
+    }
+
+    /// Sets the `to_date` query parameter.
+    pub fn to_date(mut self, to_date: &'a str) -> Self {
+        self.to_date = Some(to_date);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::BillingInvoicesResponse>> {
+        let Self {
+            client,
+            account_id,
+            from_date,
+            to_date,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = from_date {
+            query_args.push(("from_date".to_string(), v.to_string()));
+        }
+        if let Some(v) = to_date {
+            query_args.push(("to_date".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v2.1/accounts/{}/billing_invoices?{}",
+                crate::progenitor_support::encode_path(account_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct Invoices {
     pub client: Client,
 }
@@ -58,6 +119,25 @@ impl Invoices {
             )
             .await
     }
+    /**
+     * Get a List of Billing Invoices.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/billing_invoices` endpoint.
+     *
+     * As opposed to `billing_get`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Retrieves a list of invoices for the account. If the from date or to date queries are not specified, the response returns invoices for the last 365 days.
+     *
+     * Privileges required: account administrator
+     */
+    pub fn billing_get_request<'a>(&'a self, account_id: &'a str) -> InvoicesBillingGetRequest<'a> {
+        InvoicesBillingGetRequest {
+            client: self,
+            account_id,
+            from_date: None,
+            to_date: None,
+        }
+    }
     /**
      * Retrieves a billing invoice.
      *
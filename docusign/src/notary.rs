@@ -1,6 +1,47 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Notary::get`], set with a builder.
+pub struct NotaryGetRequest<'a> {
+    client: &'a Notary,
+    include_jurisdictions: Option<&'a str>,
+}
+
+impl<'a> NotaryGetRequest<'a> {
+    /// Sets the `include_jurisdictions` query parameter.
+    pub fn include_jurisdictions(mut self, include_jurisdictions: &'a str) -> Self {
+        self.include_jurisdictions = Some(include_jurisdictions);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::NotaryResult>> {
+        let Self {
+            client,
+            include_jurisdictions,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = include_jurisdictions {
+            query_args.push(("include_jurisdictions".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/v2.1/current_user/notary?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct Notary {
     pub client: Client,
 }
@@ -48,6 +89,22 @@ impl Notary {
             )
             .await
     }
+    /**
+     * Gets settings for a  notary user.
+     *
+     * This function performs a `GET` to the `/v2.1/current_user/notary` endpoint.
+     *
+     * As opposed to `get`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Gets settings for a notary user.
+     * The current user must be a notary.
+     */
+    pub fn get_request(&self) -> NotaryGetRequest<'_> {
+        NotaryGetRequest {
+            client: self,
+            include_jurisdictions: None,
+        }
+    }
     /**
      * Updates notary information for the current user.
      *
@@ -1,6 +1,70 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`NotaryJournals::get`], set with a builder.
+pub struct NotaryJournalsGetRequest<'a> {
+    client: &'a NotaryJournals,
+    count: Option<&'a str>,
+    search_text: Option<&'a str>,
+    start_position: Option<&'a str>,
+}
+
+impl<'a> NotaryJournalsGetRequest<'a> {
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: &'a str) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Sets the `search_text` query parameter.
+    pub fn search_text(mut self, search_text: &'a str) -> Self {
+        self.search_text = Some(search_text);
+        self
+    }
+
+    /// Sets the `start_position` query parameter.
+    pub fn start_position(mut self, start_position: &'a str) -> Self {
+        self.start_position = Some(start_position);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::NotaryJournalList>> {
+        let Self {
+            client,
+            count,
+            search_text,
+            start_position,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = count {
+            query_args.push(("count".to_string(), v.to_string()));
+        }
+        if let Some(v) = search_text {
+            query_args.push(("search_text".to_string(), v.to_string()));
+        }
+        if let Some(v) = start_position {
+            query_args.push(("start_position".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/v2.1/current_user/notary/journals?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct NotaryJournals {
     pub client: Client,
 }
@@ -55,4 +119,21 @@ impl NotaryJournals {
             )
             .await
     }
+    /**
+     * Gets notary jurisdictions for a user.
+     *
+     * This function performs a `GET` to the `/v2.1/current_user/notary/journals` endpoint.
+     *
+     * As opposed to `get`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     *
+     */
+    pub fn get_request(&self) -> NotaryJournalsGetRequest<'_> {
+        NotaryJournalsGetRequest {
+            client: self,
+            count: None,
+            search_text: None,
+            start_position: None,
+        }
+    }
 }
@@ -1,6 +1,67 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Payments::billing_get_list`], set with a builder.
+pub struct PaymentsBillingGetListRequest<'a> {
+    client: &'a Payments,
+    account_id: &'a str,
+    from_date: Option<&'a str>,
+    to_date: Option<&'a str>,
+}
+
+impl<'a> PaymentsBillingGetListRequest<'a> {
+    /// Sets the `from_date` query parameter.
+    pub fn from_date(mut self, from_date: &'a str) -> Self {
+        self.from_date = Some(from_date);
+        self
+    }
+
+    /// Sets the `to_date` query parameter.
+    pub fn to_date(mut self, to_date: &'a str) -> Self {
+        self.to_date = Some(to_date);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::BillingPaymentsResponse>> {
+        let Self {
+            client,
+            account_id,
+            from_date,
+            to_date,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = from_date {
+            query_args.push(("from_date".to_string(), v.to_string()));
+        }
+        if let Some(v) = to_date {
+            query_args.push(("to_date".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v2.1/accounts/{}/billing_payments?{}",
+                crate::progenitor_support::encode_path(account_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct Payments {
     pub client: Client,
 }
@@ -58,6 +119,28 @@ impl Payments {
             )
             .await
     }
+    /**
+     * Gets payment information for one or more payments.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/billing_payments` endpoint.
+     *
+     * As opposed to `billing_get_list`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Retrieves a list containing information about one or more payments. If the from date or to date queries are not used, the response returns payment information for the last 365 days.
+     *
+     * Privileges required: account administrator
+     */
+    pub fn billing_get_list_request<'a>(
+        &'a self,
+        account_id: &'a str,
+    ) -> PaymentsBillingGetListRequest<'a> {
+        PaymentsBillingGetListRequest {
+            client: self,
+            account_id,
+            from_date: None,
+            to_date: None,
+        }
+    }
     /**
      * Posts a payment to a past due invoice.
      *
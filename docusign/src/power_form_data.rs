@@ -1,6 +1,81 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`PowerFormData::power_forms_get_form_data`], set with a builder.
+pub struct PowerFormDataPowerFormsGetFormDataRequest<'a> {
+    client: &'a PowerFormData,
+    account_id: &'a str,
+    power_form_id: &'a str,
+    data_layout: Option<&'a str>,
+    from_date: Option<&'a str>,
+    to_date: Option<&'a str>,
+}
+
+impl<'a> PowerFormDataPowerFormsGetFormDataRequest<'a> {
+    /// Sets the `data_layout` query parameter.
+    pub fn data_layout(mut self, data_layout: &'a str) -> Self {
+        self.data_layout = Some(data_layout);
+        self
+    }
+
+    /// Sets the `from_date` query parameter.
+    pub fn from_date(mut self, from_date: &'a str) -> Self {
+        self.from_date = Some(from_date);
+        self
+    }
+
+    /// Sets the `to_date` query parameter.
+    pub fn to_date(mut self, to_date: &'a str) -> Self {
+        self.to_date = Some(to_date);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::PowerFormsFormDataResponse>> {
+        let Self {
+            client,
+            account_id,
+            power_form_id,
+            data_layout,
+            from_date,
+            to_date,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = data_layout {
+            query_args.push(("data_layout".to_string(), v.to_string()));
+        }
+        if let Some(v) = from_date {
+            query_args.push(("from_date".to_string(), v.to_string()));
+        }
+        if let Some(v) = to_date {
+            query_args.push(("to_date".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v2.1/accounts/{}/powerforms/{}/form_data?{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(power_form_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct PowerFormData {
     pub client: Client,
 }
@@ -80,4 +155,36 @@ impl PowerFormData {
             )
             .await
     }
+    /**
+     * Returns the data that users entered in a PowerForm.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/powerforms/{powerFormId}/form_data` endpoint.
+     *
+     * As opposed to `power_forms_get_form_data`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * This method enables Powerform Administrators or the sender of a PowerForm to download the data that recipients have entered into a PowerForm.
+     *
+     * You specify the format in which you want to retrieve the data in the `Accept` header. This header accepts the following values:
+     *
+     *
+     * - `application/json`: JSON format
+     * - `application/xml`: XML format
+     * - `text/csv`: Comma-separated value (CSV) format
+     *
+     * **Note**: Only PowerForm Administrators or the PowerForm Sender can download the data associated with a PowerForm.
+     */
+    pub fn power_forms_get_form_data_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        power_form_id: &'a str,
+    ) -> PowerFormDataPowerFormsGetFormDataRequest<'a> {
+        PowerFormDataPowerFormsGetFormDataRequest {
+            client: self,
+            account_id,
+            power_form_id,
+            data_layout: None,
+            from_date: None,
+            to_date: None,
+        }
+    }
 }
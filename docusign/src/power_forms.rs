@@ -1,6 +1,137 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`PowerForms::get_list`], set with a builder.
+pub struct PowerFormsGetListRequest<'a> {
+    client: &'a PowerForms,
+    account_id: &'a str,
+    from_date: Option<&'a str>,
+    order: Option<&'a str>,
+    order_by: Option<&'a str>,
+    to_date: Option<&'a str>,
+}
+
+impl<'a> PowerFormsGetListRequest<'a> {
+    /// Sets the `from_date` query parameter.
+    pub fn from_date(mut self, from_date: &'a str) -> Self {
+        self.from_date = Some(from_date);
+        self
+    }
+
+    /// Sets the `order` query parameter.
+    pub fn order(mut self, order: &'a str) -> Self {
+        self.order = Some(order);
+        self
+    }
+
+    /// Sets the `order_by` query parameter.
+    pub fn order_by(mut self, order_by: &'a str) -> Self {
+        self.order_by = Some(order_by);
+        self
+    }
+
+    /// Sets the `to_date` query parameter.
+    pub fn to_date(mut self, to_date: &'a str) -> Self {
+        self.to_date = Some(to_date);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::PowerFormsResponse>> {
+        let Self {
+            client,
+            account_id,
+            from_date,
+            order,
+            order_by,
+            to_date,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = from_date {
+            query_args.push(("from_date".to_string(), v.to_string()));
+        }
+        if let Some(v) = order {
+            query_args.push(("order".to_string(), v.to_string()));
+        }
+        if let Some(v) = order_by {
+            query_args.push(("order_by".to_string(), v.to_string()));
+        }
+        if let Some(v) = to_date {
+            query_args.push(("to_date".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v2.1/accounts/{}/powerforms?{}",
+                crate::progenitor_support::encode_path(account_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`PowerForms::get_senders`], set with a builder.
+pub struct PowerFormsGetSendersRequest<'a> {
+    client: &'a PowerForms,
+    account_id: &'a str,
+    start_position: Option<&'a str>,
+}
+
+impl<'a> PowerFormsGetSendersRequest<'a> {
+    /// Sets the `start_position` query parameter.
+    pub fn start_position(mut self, start_position: &'a str) -> Self {
+        self.start_position = Some(start_position);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::PowerFormSendersResponse>> {
+        let Self {
+            client,
+            account_id,
+            start_position,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = start_position {
+            query_args.push(("start_position".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v2.1/accounts/{}/powerforms/senders?{}",
+                crate::progenitor_support::encode_path(account_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct PowerForms {
     pub client: Client,
 }
@@ -81,6 +212,25 @@ impl PowerForms {
             )
             .await
     }
+    /**
+     * Returns a list of PowerForms.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/powerforms` endpoint.
+     *
+     * As opposed to `get_list`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * This method returns a list of PowerForms that are available to the user.
+     */
+    pub fn get_list_request<'a>(&'a self, account_id: &'a str) -> PowerFormsGetListRequest<'a> {
+        PowerFormsGetListRequest {
+            client: self,
+            account_id,
+            from_date: None,
+            order: None,
+            order_by: None,
+            to_date: None,
+        }
+    }
     /**
      * Creates a new PowerForm.
      *
@@ -226,6 +376,25 @@ impl PowerForms {
             )
             .await
     }
+    /**
+     * Gets PowerForm senders.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/powerforms/senders` endpoint.
+     *
+     * As opposed to `get_senders`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * This method returns a list of users who have sent PowerForms.
+     */
+    pub fn get_senders_request<'a>(
+        &'a self,
+        account_id: &'a str,
+    ) -> PowerFormsGetSendersRequest<'a> {
+        PowerFormsGetSendersRequest {
+            client: self,
+            account_id,
+            start_position: None,
+        }
+    }
     /**
      * Returns a single PowerForm.
      *
@@ -1,6 +1,44 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`RequestLogs::api_get_log`], set with a builder.
+pub struct RequestLogsApiGetLogRequest<'a> {
+    client: &'a RequestLogs,
+    encoding: Option<&'a str>,
+}
+
+impl<'a> RequestLogsApiGetLogRequest<'a> {
+    /// Sets the `encoding` query parameter.
+    pub fn encoding(mut self, encoding: &'a str) -> Self {
+        self.encoding = Some(encoding);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::ApiRequestLogsResult>> {
+        let Self { client, encoding } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = encoding {
+            query_args.push(("encoding".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/v2.1/diagnostics/request_logs?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct RequestLogs {
     pub client: Client,
 }
@@ -48,6 +86,25 @@ impl RequestLogs {
             )
             .await
     }
+    /**
+     * Gets the API request logging log files.
+     *
+     * This function performs a `GET` to the `/v2.1/diagnostics/request_logs` endpoint.
+     *
+     * As opposed to `api_get_log`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Retrieves a list of log entries as a JSON or xml object or as a zip file containing the entries.
+     *
+     * If the Accept header is set to application/zip, the response is a zip file containing individual text files, each representing an API request.
+     *
+     * If the Accept header is set to `application/json` or `application/xml`, the response returns list of log entries in either JSON or XML. An example JSON response body is shown below.
+     */
+    pub fn api_get_log_request(&self) -> RequestLogsApiGetLogRequest<'_> {
+        RequestLogsApiGetLogRequest {
+            client: self,
+            encoding: None,
+        }
+    }
     /**
      * Deletes the request log files.
      *
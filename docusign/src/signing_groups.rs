@@ -1,6 +1,67 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`SigningGroups::get`], set with a builder.
+pub struct SigningGroupsGetRequest<'a> {
+    client: &'a SigningGroups,
+    account_id: &'a str,
+    group_type: Option<&'a str>,
+    include_users: Option<&'a str>,
+}
+
+impl<'a> SigningGroupsGetRequest<'a> {
+    /// Sets the `group_type` query parameter.
+    pub fn group_type(mut self, group_type: &'a str) -> Self {
+        self.group_type = Some(group_type);
+        self
+    }
+
+    /// Sets the `include_users` query parameter.
+    pub fn include_users(mut self, include_users: &'a str) -> Self {
+        self.include_users = Some(include_users);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::SigningGroupInformation>> {
+        let Self {
+            client,
+            account_id,
+            group_type,
+            include_users,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = group_type {
+            query_args.push(("group_type".to_string(), v.to_string()));
+        }
+        if let Some(v) = include_users {
+            query_args.push(("include_users".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v2.1/accounts/{}/signing_groups?{}",
+                crate::progenitor_support::encode_path(account_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct SigningGroups {
     pub client: Client,
 }
@@ -56,6 +117,23 @@ impl SigningGroups {
             )
             .await
     }
+    /**
+     * Gets a list of the Signing Groups in an account.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/signing_groups` endpoint.
+     *
+     * As opposed to `get`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Retrieves a list of all signing groups in the specified account.
+     */
+    pub fn get_request<'a>(&'a self, account_id: &'a str) -> SigningGroupsGetRequest<'a> {
+        SigningGroupsGetRequest {
+            client: self,
+            account_id,
+            group_type: None,
+            include_users: None,
+        }
+    }
     /**
      * Updates signing group names.
      *
@@ -1,6 +1,71 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`TemplateBulkRecipients::recipients_get_template_bulk`], set with a builder.
+pub struct TemplateBulkRecipientsRecipientsGetTemplateBulkRequest<'a> {
+    client: &'a TemplateBulkRecipients,
+    account_id: &'a str,
+    recipient_id: &'a str,
+    template_id: &'a str,
+    include_tabs: Option<&'a str>,
+    start_position: Option<&'a str>,
+}
+
+impl<'a> TemplateBulkRecipientsRecipientsGetTemplateBulkRequest<'a> {
+    /// Sets the `include_tabs` query parameter.
+    pub fn include_tabs(mut self, include_tabs: &'a str) -> Self {
+        self.include_tabs = Some(include_tabs);
+        self
+    }
+
+    /// Sets the `start_position` query parameter.
+    pub fn start_position(mut self, start_position: &'a str) -> Self {
+        self.start_position = Some(start_position);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::BulkRecipientsResponse>> {
+        let Self {
+            client,
+            account_id,
+            recipient_id,
+            template_id,
+            include_tabs,
+            start_position,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = include_tabs {
+            query_args.push(("include_tabs".to_string(), v.to_string()));
+        }
+        if let Some(v) = start_position {
+            query_args.push(("start_position".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v2.1/accounts/{}/templates/{}/recipients/{}/bulk_recipients?{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(template_id),
+                crate::progenitor_support::encode_path(recipient_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct TemplateBulkRecipients {
     pub client: Client,
 }
@@ -62,6 +127,30 @@ impl TemplateBulkRecipients {
             )
             .await
     }
+    /**
+     * Gets the bulk recipient file from a template.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/templates/{templateId}/recipients/{recipientId}/bulk_recipients` endpoint.
+     *
+     * As opposed to `recipients_get_template_bulk`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Retrieves the bulk recipient file information from a template that has a bulk recipient.
+     */
+    pub fn recipients_get_template_bulk_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        recipient_id: &'a str,
+        template_id: &'a str,
+    ) -> TemplateBulkRecipientsRecipientsGetTemplateBulkRequest<'a> {
+        TemplateBulkRecipientsRecipientsGetTemplateBulkRequest {
+            client: self,
+            account_id,
+            recipient_id,
+            template_id,
+            include_tabs: None,
+            start_position: None,
+        }
+    }
     /**
      * Adds or replaces the bulk recipients list in a template.
      *
@@ -1,6 +1,60 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`TemplateDocumentTabs::tabs_get_template_document`], set with a builder.
+pub struct TemplateDocumentTabsTabsGetTemplateDocumentRequest<'a> {
+    client: &'a TemplateDocumentTabs,
+    account_id: &'a str,
+    document_id: &'a str,
+    template_id: &'a str,
+    page_numbers: Option<&'a str>,
+}
+
+impl<'a> TemplateDocumentTabsTabsGetTemplateDocumentRequest<'a> {
+    /// Sets the `page_numbers` query parameter.
+    pub fn page_numbers(mut self, page_numbers: &'a str) -> Self {
+        self.page_numbers = Some(page_numbers);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::TemplateDocumentTabs>> {
+        let Self {
+            client,
+            account_id,
+            document_id,
+            template_id,
+            page_numbers,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page_numbers {
+            query_args.push(("page_numbers".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v2.1/accounts/{}/templates/{}/documents/{}/tabs?{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(template_id),
+                crate::progenitor_support::encode_path(document_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct TemplateDocumentTabs {
     pub client: Client,
 }
@@ -100,6 +154,29 @@ impl TemplateDocumentTabs {
             )
             .await
     }
+    /**
+     * Returns tabs on the document.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/templates/{templateId}/documents/{documentId}/tabs` endpoint.
+     *
+     * As opposed to `tabs_get_template_document`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     *
+     */
+    pub fn tabs_get_template_document_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        document_id: &'a str,
+        template_id: &'a str,
+    ) -> TemplateDocumentTabsTabsGetTemplateDocumentRequest<'a> {
+        TemplateDocumentTabsTabsGetTemplateDocumentRequest {
+            client: self,
+            account_id,
+            document_id,
+            template_id,
+            page_numbers: None,
+        }
+    }
     /**
      * Updates the tabs for a template.
      *
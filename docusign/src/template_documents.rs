@@ -1,6 +1,180 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`TemplateDocuments::documents_get_template`], set with a builder.
+pub struct TemplateDocumentsDocumentsGetTemplateRequest<'a> {
+    client: &'a TemplateDocuments,
+    account_id: &'a str,
+    template_id: &'a str,
+    include_tabs: Option<&'a str>,
+}
+
+impl<'a> TemplateDocumentsDocumentsGetTemplateRequest<'a> {
+    /// Sets the `include_tabs` query parameter.
+    pub fn include_tabs(mut self, include_tabs: &'a str) -> Self {
+        self.include_tabs = Some(include_tabs);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::TemplateDocumentsResult>> {
+        let Self {
+            client,
+            account_id,
+            template_id,
+            include_tabs,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = include_tabs {
+            query_args.push(("include_tabs".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v2.1/accounts/{}/templates/{}/documents?{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(template_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`TemplateDocuments::documents_get`], set with a builder.
+pub struct TemplateDocumentsDocumentsGetRequest<'a> {
+    client: &'a TemplateDocuments,
+    account_id: &'a str,
+    document_id: &'a str,
+    template_id: &'a str,
+    encrypt: Option<&'a str>,
+    show_changes: Option<&'a str>,
+}
+
+impl<'a> TemplateDocumentsDocumentsGetRequest<'a> {
+    /// Sets the `encrypt` query parameter.
+    pub fn encrypt(mut self, encrypt: &'a str) -> Self {
+        self.encrypt = Some(encrypt);
+        self
+    }
+
+    /// Sets the `show_changes` query parameter.
+    pub fn show_changes(mut self, show_changes: &'a str) -> Self {
+        self.show_changes = Some(show_changes);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            account_id,
+            document_id,
+            template_id,
+            encrypt,
+            show_changes,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = encrypt {
+            query_args.push(("encrypt".to_string(), v.to_string()));
+        }
+        if let Some(v) = show_changes {
+            query_args.push(("show_changes".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v2.1/accounts/{}/templates/{}/documents/{}?{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(template_id),
+                crate::progenitor_support::encode_path(document_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`TemplateDocuments::documents_put`], set with a builder.
+pub struct TemplateDocumentsDocumentsPutRequest<'a> {
+    client: &'a TemplateDocuments,
+    account_id: &'a str,
+    document_id: &'a str,
+    template_id: &'a str,
+    is_envelope_definition: Option<&'a str>,
+    body: &'a crate::types::EnvelopeDefinition,
+}
+
+impl<'a> TemplateDocumentsDocumentsPutRequest<'a> {
+    /// Sets the `is_envelope_definition` query parameter.
+    pub fn is_envelope_definition(mut self, is_envelope_definition: &'a str) -> Self {
+        self.is_envelope_definition = Some(is_envelope_definition);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::EnvelopeDocument>> {
+        let Self {
+            client,
+            account_id,
+            document_id,
+            template_id,
+            is_envelope_definition,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = is_envelope_definition {
+            query_args.push(("is_envelope_definition".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v2.1/accounts/{}/templates/{}/documents/{}?{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(template_id),
+                crate::progenitor_support::encode_path(document_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .put(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct TemplateDocuments {
     pub client: Client,
 }
@@ -54,6 +228,27 @@ impl TemplateDocuments {
             )
             .await
     }
+    /**
+     * Gets a list of documents associated with a template.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/templates/{templateId}/documents` endpoint.
+     *
+     * As opposed to `documents_get_template`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Retrieves a list of documents associated with the specified template.
+     */
+    pub fn documents_get_template_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        template_id: &'a str,
+    ) -> TemplateDocumentsDocumentsGetTemplateRequest<'a> {
+        TemplateDocumentsDocumentsGetTemplateRequest {
+            client: self,
+            account_id,
+            template_id,
+            include_tabs: None,
+        }
+    }
     /**
      * Adds documents to a template document.
      *
@@ -193,6 +388,32 @@ impl TemplateDocuments {
             )
             .await
     }
+    /**
+     * Gets PDF documents from a template.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/templates/{templateId}/documents/{documentId}` endpoint.
+     *
+     * As opposed to `documents_get`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * This method retrieves one or more PDF documents from the template that you specify.
+     *
+     * You can specify the ID of the document to retrieve, or pass in the value `combined` to retrieve all documents in the template as a single PDF file.
+     */
+    pub fn documents_get_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        document_id: &'a str,
+        template_id: &'a str,
+    ) -> TemplateDocumentsDocumentsGetRequest<'a> {
+        TemplateDocumentsDocumentsGetRequest {
+            client: self,
+            account_id,
+            document_id,
+            template_id,
+            encrypt: None,
+            show_changes: None,
+        }
+    }
     /**
      * Updates a template document.
      *
@@ -243,4 +464,29 @@ impl TemplateDocuments {
             )
             .await
     }
+    /**
+     * Updates a template document.
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/templates/{templateId}/documents/{documentId}` endpoint.
+     *
+     * As opposed to `documents_put`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * This methods updates an existing template document.
+     */
+    pub fn documents_put_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        document_id: &'a str,
+        template_id: &'a str,
+        body: &'a crate::types::EnvelopeDefinition,
+    ) -> TemplateDocumentsDocumentsPutRequest<'a> {
+        TemplateDocumentsDocumentsPutRequest {
+            client: self,
+            account_id,
+            document_id,
+            template_id,
+            is_envelope_definition: None,
+            body,
+        }
+    }
 }
@@ -1,6 +1,71 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`TemplateRecipientTabs::recipients_get`], set with a builder.
+pub struct TemplateRecipientTabsRecipientsGetRequest<'a> {
+    client: &'a TemplateRecipientTabs,
+    account_id: &'a str,
+    recipient_id: &'a str,
+    template_id: &'a str,
+    include_anchor_tab_locations: Option<&'a str>,
+    include_metadata: Option<&'a str>,
+}
+
+impl<'a> TemplateRecipientTabsRecipientsGetRequest<'a> {
+    /// Sets the `include_anchor_tab_locations` query parameter.
+    pub fn include_anchor_tab_locations(mut self, include_anchor_tab_locations: &'a str) -> Self {
+        self.include_anchor_tab_locations = Some(include_anchor_tab_locations);
+        self
+    }
+
+    /// Sets the `include_metadata` query parameter.
+    pub fn include_metadata(mut self, include_metadata: &'a str) -> Self {
+        self.include_metadata = Some(include_metadata);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::Tabs>> {
+        let Self {
+            client,
+            account_id,
+            recipient_id,
+            template_id,
+            include_anchor_tab_locations,
+            include_metadata,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = include_anchor_tab_locations {
+            query_args.push(("include_anchor_tab_locations".to_string(), v.to_string()));
+        }
+        if let Some(v) = include_metadata {
+            query_args.push(("include_metadata".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v2.1/accounts/{}/templates/{}/recipients/{}/tabs?{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(template_id),
+                crate::progenitor_support::encode_path(recipient_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct TemplateRecipientTabs {
     pub client: Client,
 }
@@ -65,6 +130,30 @@ impl TemplateRecipientTabs {
             )
             .await
     }
+    /**
+     * Gets the tabs information for a signer or sign-in-person recipient in a template.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/templates/{templateId}/recipients/{recipientId}/tabs` endpoint.
+     *
+     * As opposed to `recipients_get`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Gets the tabs information for a signer or sign-in-person recipient in a template.
+     */
+    pub fn recipients_get_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        recipient_id: &'a str,
+        template_id: &'a str,
+    ) -> TemplateRecipientTabsRecipientsGetRequest<'a> {
+        TemplateRecipientTabsRecipientsGetRequest {
+            client: self,
+            account_id,
+            recipient_id,
+            template_id,
+            include_anchor_tab_locations: None,
+            include_metadata: None,
+        }
+    }
     /**
      * Updates the tabs for a recipient.
      *
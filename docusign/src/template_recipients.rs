@@ -1,6 +1,187 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`TemplateRecipients::recipients_get_template`], set with a builder.
+pub struct TemplateRecipientsRecipientsGetTemplateRequest<'a> {
+    client: &'a TemplateRecipients,
+    account_id: &'a str,
+    template_id: &'a str,
+    include_anchor_tab_locations: Option<&'a str>,
+    include_extended: Option<&'a str>,
+    include_tabs: Option<&'a str>,
+}
+
+impl<'a> TemplateRecipientsRecipientsGetTemplateRequest<'a> {
+    /// Sets the `include_anchor_tab_locations` query parameter.
+    pub fn include_anchor_tab_locations(mut self, include_anchor_tab_locations: &'a str) -> Self {
+        self.include_anchor_tab_locations = Some(include_anchor_tab_locations);
+        self
+    }
+
+    /// Sets the `include_extended` query parameter.
+    pub fn include_extended(mut self, include_extended: &'a str) -> Self {
+        self.include_extended = Some(include_extended);
+        self
+    }
+
+    /// Sets the `include_tabs` query parameter.
+    pub fn include_tabs(mut self, include_tabs: &'a str) -> Self {
+        self.include_tabs = Some(include_tabs);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::Recipients>> {
+        let Self {
+            client,
+            account_id,
+            template_id,
+            include_anchor_tab_locations,
+            include_extended,
+            include_tabs,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = include_anchor_tab_locations {
+            query_args.push(("include_anchor_tab_locations".to_string(), v.to_string()));
+        }
+        if let Some(v) = include_extended {
+            query_args.push(("include_extended".to_string(), v.to_string()));
+        }
+        if let Some(v) = include_tabs {
+            query_args.push(("include_tabs".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v2.1/accounts/{}/templates/{}/recipients?{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(template_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`TemplateRecipients::recipients_put_template`], set with a builder.
+pub struct TemplateRecipientsRecipientsPutTemplateRequest<'a> {
+    client: &'a TemplateRecipients,
+    account_id: &'a str,
+    template_id: &'a str,
+    resend_envelope: Option<&'a str>,
+    body: &'a crate::types::TemplateRecipientsData,
+}
+
+impl<'a> TemplateRecipientsRecipientsPutTemplateRequest<'a> {
+    /// Sets the `resend_envelope` query parameter.
+    pub fn resend_envelope(mut self, resend_envelope: &'a str) -> Self {
+        self.resend_envelope = Some(resend_envelope);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::RecipientsUpdateSummary>> {
+        let Self {
+            client,
+            account_id,
+            template_id,
+            resend_envelope,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = resend_envelope {
+            query_args.push(("resend_envelope".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v2.1/accounts/{}/templates/{}/recipients?{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(template_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .put(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`TemplateRecipients::recipients_post_template`], set with a builder.
+pub struct TemplateRecipientsRecipientsPostTemplateRequest<'a> {
+    client: &'a TemplateRecipients,
+    account_id: &'a str,
+    template_id: &'a str,
+    resend_envelope: Option<&'a str>,
+    body: &'a crate::types::TemplateRecipientsData,
+}
+
+impl<'a> TemplateRecipientsRecipientsPostTemplateRequest<'a> {
+    /// Sets the `resend_envelope` query parameter.
+    pub fn resend_envelope(mut self, resend_envelope: &'a str) -> Self {
+        self.resend_envelope = Some(resend_envelope);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::Recipients>> {
+        let Self {
+            client,
+            account_id,
+            template_id,
+            resend_envelope,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = resend_envelope {
+            query_args.push(("resend_envelope".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v2.1/accounts/{}/templates/{}/recipients?{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(template_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct TemplateRecipients {
     pub client: Client,
 }
@@ -67,6 +248,29 @@ impl TemplateRecipients {
             )
             .await
     }
+    /**
+     * Gets recipient information from a template.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/templates/{templateId}/recipients` endpoint.
+     *
+     * As opposed to `recipients_get_template`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Retrieves the information for all recipients in the specified template.
+     */
+    pub fn recipients_get_template_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        template_id: &'a str,
+    ) -> TemplateRecipientsRecipientsGetTemplateRequest<'a> {
+        TemplateRecipientsRecipientsGetTemplateRequest {
+            client: self,
+            account_id,
+            template_id,
+            include_anchor_tab_locations: None,
+            include_extended: None,
+            include_tabs: None,
+        }
+    }
     /**
      * Updates recipients in a template.
      *
@@ -115,6 +319,31 @@ impl TemplateRecipients {
             )
             .await
     }
+    /**
+     * Updates recipients in a template.
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/templates/{templateId}/recipients` endpoint.
+     *
+     * As opposed to `recipients_put_template`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Updates recipients in a template.
+     *
+     * You can edit the following properties: `email`, `userName`, `routingOrder`, `faxNumber`, `deliveryMethod`, `accessCode`, and `requireIdLookup`.
+     */
+    pub fn recipients_put_template_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        template_id: &'a str,
+        body: &'a crate::types::TemplateRecipientsData,
+    ) -> TemplateRecipientsRecipientsPutTemplateRequest<'a> {
+        TemplateRecipientsRecipientsPutTemplateRequest {
+            client: self,
+            account_id,
+            template_id,
+            resend_envelope: None,
+            body,
+        }
+    }
     /**
      * Adds tabs for a recipient.
      *
@@ -161,6 +390,29 @@ impl TemplateRecipients {
             )
             .await
     }
+    /**
+     * Adds tabs for a recipient.
+     *
+     * This function performs a `POST` to the `/v2.1/accounts/{accountId}/templates/{templateId}/recipients` endpoint.
+     *
+     * As opposed to `recipients_post_template`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Adds one or more recipients to a template.
+     */
+    pub fn recipients_post_template_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        template_id: &'a str,
+        body: &'a crate::types::TemplateRecipientsData,
+    ) -> TemplateRecipientsRecipientsPostTemplateRequest<'a> {
+        TemplateRecipientsRecipientsPostTemplateRequest {
+            client: self,
+            account_id,
+            template_id,
+            resend_envelope: None,
+            body,
+        }
+    }
     /**
      * Deletes recipients from a template.
      *
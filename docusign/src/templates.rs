@@ -1,6 +1,559 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Templates::get`], set with a builder.
+pub struct TemplatesGetRequest<'a> {
+    client: &'a Templates,
+    account_id: &'a str,
+    count: Option<&'a str>,
+    created_from_date: Option<&'a str>,
+    created_to_date: Option<&'a str>,
+    folder_ids: Option<&'a str>,
+    folder_types: Option<&'a str>,
+    from_date: Option<&'a str>,
+    include: Option<&'a str>,
+    is_deleted_template_only: Option<&'a str>,
+    is_download: Option<&'a str>,
+    modified_from_date: Option<&'a str>,
+    modified_to_date: Option<&'a str>,
+    order: Option<&'a str>,
+    order_by: Option<&'a str>,
+    search_fields: Option<&'a str>,
+    search_text: Option<&'a str>,
+    shared_by_me: Option<&'a str>,
+    start_position: Option<&'a str>,
+    template_ids: Option<&'a str>,
+    to_date: Option<&'a str>,
+    used_from_date: Option<&'a str>,
+    used_to_date: Option<&'a str>,
+    user_filter: Option<&'a str>,
+    user_id: Option<&'a str>,
+}
+
+impl<'a> TemplatesGetRequest<'a> {
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: &'a str) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Sets the `created_from_date` query parameter.
+    pub fn created_from_date(mut self, created_from_date: &'a str) -> Self {
+        self.created_from_date = Some(created_from_date);
+        self
+    }
+
+    /// Sets the `created_to_date` query parameter.
+    pub fn created_to_date(mut self, created_to_date: &'a str) -> Self {
+        self.created_to_date = Some(created_to_date);
+        self
+    }
+
+    /// Sets the `folder_ids` query parameter.
+    pub fn folder_ids(mut self, folder_ids: &'a str) -> Self {
+        self.folder_ids = Some(folder_ids);
+        self
+    }
+
+    /// Sets the `folder_types` query parameter.
+    pub fn folder_types(mut self, folder_types: &'a str) -> Self {
+        self.folder_types = Some(folder_types);
+        self
+    }
+
+    /// Sets the `from_date` query parameter.
+    pub fn from_date(mut self, from_date: &'a str) -> Self {
+        self.from_date = Some(from_date);
+        self
+    }
+
+    /// Sets the `include` query parameter.
+    pub fn include(mut self, include: &'a str) -> Self {
+        self.include = Some(include);
+        self
+    }
+
+    /// Sets the `is_deleted_template_only` query parameter.
+    pub fn is_deleted_template_only(mut self, is_deleted_template_only: &'a str) -> Self {
+        self.is_deleted_template_only = Some(is_deleted_template_only);
+        self
+    }
+
+    /// Sets the `is_download` query parameter.
+    pub fn is_download(mut self, is_download: &'a str) -> Self {
+        self.is_download = Some(is_download);
+        self
+    }
+
+    /// Sets the `modified_from_date` query parameter.
+    pub fn modified_from_date(mut self, modified_from_date: &'a str) -> Self {
+        self.modified_from_date = Some(modified_from_date);
+        self
+    }
+
+    /// Sets the `modified_to_date` query parameter.
+    pub fn modified_to_date(mut self, modified_to_date: &'a str) -> Self {
+        self.modified_to_date = Some(modified_to_date);
+        self
+    }
+
+    /// Sets the `order` query parameter.
+    pub fn order(mut self, order: &'a str) -> Self {
+        self.order = Some(order);
+        self
+    }
+
+    /// Sets the `order_by` query parameter.
+    pub fn order_by(mut self, order_by: &'a str) -> Self {
+        self.order_by = Some(order_by);
+        self
+    }
+
+    /// Sets the `search_fields` query parameter.
+    pub fn search_fields(mut self, search_fields: &'a str) -> Self {
+        self.search_fields = Some(search_fields);
+        self
+    }
+
+    /// Sets the `search_text` query parameter.
+    pub fn search_text(mut self, search_text: &'a str) -> Self {
+        self.search_text = Some(search_text);
+        self
+    }
+
+    /// Sets the `shared_by_me` query parameter.
+    pub fn shared_by_me(mut self, shared_by_me: &'a str) -> Self {
+        self.shared_by_me = Some(shared_by_me);
+        self
+    }
+
+    /// Sets the `start_position` query parameter.
+    pub fn start_position(mut self, start_position: &'a str) -> Self {
+        self.start_position = Some(start_position);
+        self
+    }
+
+    /// Sets the `template_ids` query parameter.
+    pub fn template_ids(mut self, template_ids: &'a str) -> Self {
+        self.template_ids = Some(template_ids);
+        self
+    }
+
+    /// Sets the `to_date` query parameter.
+    pub fn to_date(mut self, to_date: &'a str) -> Self {
+        self.to_date = Some(to_date);
+        self
+    }
+
+    /// Sets the `used_from_date` query parameter.
+    pub fn used_from_date(mut self, used_from_date: &'a str) -> Self {
+        self.used_from_date = Some(used_from_date);
+        self
+    }
+
+    /// Sets the `used_to_date` query parameter.
+    pub fn used_to_date(mut self, used_to_date: &'a str) -> Self {
+        self.used_to_date = Some(used_to_date);
+        self
+    }
+
+    /// Sets the `user_filter` query parameter.
+    pub fn user_filter(mut self, user_filter: &'a str) -> Self {
+        self.user_filter = Some(user_filter);
+        self
+    }
+
+    /// Sets the `user_id` query parameter.
+    pub fn user_id(mut self, user_id: &'a str) -> Self {
+        self.user_id = Some(user_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::EnvelopeTemplateResults>> {
+        let Self {
+            client,
+            account_id,
+            count,
+            created_from_date,
+            created_to_date,
+            folder_ids,
+            folder_types,
+            from_date,
+            include,
+            is_deleted_template_only,
+            is_download,
+            modified_from_date,
+            modified_to_date,
+            order,
+            order_by,
+            search_fields,
+            search_text,
+            shared_by_me,
+            start_position,
+            template_ids,
+            to_date,
+            used_from_date,
+            used_to_date,
+            user_filter,
+            user_id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = count {
+            query_args.push(("count".to_string(), v.to_string()));
+        }
+        if let Some(v) = created_from_date {
+            query_args.push(("created_from_date".to_string(), v.to_string()));
+        }
+        if let Some(v) = created_to_date {
+            query_args.push(("created_to_date".to_string(), v.to_string()));
+        }
+        if let Some(v) = folder_ids {
+            query_args.push(("folder_ids".to_string(), v.to_string()));
+        }
+        if let Some(v) = folder_types {
+            query_args.push(("folder_types".to_string(), v.to_string()));
+        }
+        if let Some(v) = from_date {
+            query_args.push(("from_date".to_string(), v.to_string()));
+        }
+        if let Some(v) = include {
+            query_args.push(("include".to_string(), v.to_string()));
+        }
+        if let Some(v) = is_deleted_template_only {
+            query_args.push(("is_deleted_template_only".to_string(), v.to_string()));
+        }
+        if let Some(v) = is_download {
+            query_args.push(("is_download".to_string(), v.to_string()));
+        }
+        if let Some(v) = modified_from_date {
+            query_args.push(("modified_from_date".to_string(), v.to_string()));
+        }
+        if let Some(v) = modified_to_date {
+            query_args.push(("modified_to_date".to_string(), v.to_string()));
+        }
+        if let Some(v) = order {
+            query_args.push(("order".to_string(), v.to_string()));
+        }
+        if let Some(v) = order_by {
+            query_args.push(("order_by".to_string(), v.to_string()));
+        }
+        if let Some(v) = search_fields {
+            query_args.push(("search_fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = search_text {
+            query_args.push(("search_text".to_string(), v.to_string()));
+        }
+        if let Some(v) = shared_by_me {
+            query_args.push(("shared_by_me".to_string(), v.to_string()));
+        }
+        if let Some(v) = start_position {
+            query_args.push(("start_position".to_string(), v.to_string()));
+        }
+        if let Some(v) = template_ids {
+            query_args.push(("template_ids".to_string(), v.to_string()));
+        }
+        if let Some(v) = to_date {
+            query_args.push(("to_date".to_string(), v.to_string()));
+        }
+        if let Some(v) = used_from_date {
+            query_args.push(("used_from_date".to_string(), v.to_string()));
+        }
+        if let Some(v) = used_to_date {
+            query_args.push(("used_to_date".to_string(), v.to_string()));
+        }
+        if let Some(v) = user_filter {
+            query_args.push(("user_filter".to_string(), v.to_string()));
+        }
+        if let Some(v) = user_id {
+            query_args.push(("user_id".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v2.1/accounts/{}/templates?{}",
+                crate::progenitor_support::encode_path(account_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Templates::get_templates`], set with a builder.
+pub struct TemplatesGetTemplatesRequest<'a> {
+    client: &'a Templates,
+    account_id: &'a str,
+    template_id: &'a str,
+    include: Option<&'a str>,
+}
+
+impl<'a> TemplatesGetTemplatesRequest<'a> {
+    /// Sets the `include` query parameter.
+    pub fn include(mut self, include: &'a str) -> Self {
+        self.include = Some(include);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::EnvelopeTemplate>> {
+        let Self {
+            client,
+            account_id,
+            template_id,
+            include,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = include {
+            query_args.push(("include".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v2.1/accounts/{}/templates/{}?{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(template_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Templates::pages_get_page_image`], set with a builder.
+pub struct TemplatesPagesGetPageImageRequest<'a> {
+    client: &'a Templates,
+    account_id: &'a str,
+    document_id: &'a str,
+    template_id: &'a str,
+    count: Option<&'a str>,
+    dpi: Option<&'a str>,
+    max_height: Option<&'a str>,
+    max_width: Option<&'a str>,
+    nocache: Option<&'a str>,
+    show_changes: Option<&'a str>,
+    start_position: Option<&'a str>,
+}
+
+impl<'a> TemplatesPagesGetPageImageRequest<'a> {
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: &'a str) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Sets the `dpi` query parameter.
+    pub fn dpi(mut self, dpi: &'a str) -> Self {
+        self.dpi = Some(dpi);
+        self
+    }
+
+    /// Sets the `max_height` query parameter.
+    pub fn max_height(mut self, max_height: &'a str) -> Self {
+        self.max_height = Some(max_height);
+        self
+    }
+
+    /// Sets the `max_width` query parameter.
+    pub fn max_width(mut self, max_width: &'a str) -> Self {
+        self.max_width = Some(max_width);
+        self
+    }
+
+    /// Sets the `nocache` query parameter.
+    pub fn nocache(mut self, nocache: &'a str) -> Self {
+        self.nocache = Some(nocache);
+        self
+    }
+
+    /// Sets the `show_changes` query parameter.
+    pub fn show_changes(mut self, show_changes: &'a str) -> Self {
+        self.show_changes = Some(show_changes);
+        self
+    }
+
+    /// Sets the `start_position` query parameter.
+    pub fn start_position(mut self, start_position: &'a str) -> Self {
+        self.start_position = Some(start_position);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::PageImages>> {
+        let Self {
+            client,
+            account_id,
+            document_id,
+            template_id,
+            count,
+            dpi,
+            max_height,
+            max_width,
+            nocache,
+            show_changes,
+            start_position,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = count {
+            query_args.push(("count".to_string(), v.to_string()));
+        }
+        if let Some(v) = dpi {
+            query_args.push(("dpi".to_string(), v.to_string()));
+        }
+        if let Some(v) = max_height {
+            query_args.push(("max_height".to_string(), v.to_string()));
+        }
+        if let Some(v) = max_width {
+            query_args.push(("max_width".to_string(), v.to_string()));
+        }
+        if let Some(v) = nocache {
+            query_args.push(("nocache".to_string(), v.to_string()));
+        }
+        if let Some(v) = show_changes {
+            query_args.push(("show_changes".to_string(), v.to_string()));
+        }
+        if let Some(v) = start_position {
+            query_args.push(("start_position".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v2.1/accounts/{}/templates/{}/documents/{}/pages?{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(template_id),
+                crate::progenitor_support::encode_path(document_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Templates::pages_get_page_image_templates`], set with a builder.
+pub struct TemplatesPagesGetPageImageTemplatesRequest<'a> {
+    client: &'a Templates,
+    account_id: &'a str,
+    document_id: &'a str,
+    page_number: &'a str,
+    template_id: &'a str,
+    dpi: Option<&'a str>,
+    max_height: Option<&'a str>,
+    max_width: Option<&'a str>,
+    show_changes: Option<&'a str>,
+}
+
+impl<'a> TemplatesPagesGetPageImageTemplatesRequest<'a> {
+    /// Sets the `dpi` query parameter.
+    pub fn dpi(mut self, dpi: &'a str) -> Self {
+        self.dpi = Some(dpi);
+        self
+    }
+
+    /// Sets the `max_height` query parameter.
+    pub fn max_height(mut self, max_height: &'a str) -> Self {
+        self.max_height = Some(max_height);
+        self
+    }
+
+    /// Sets the `max_width` query parameter.
+    pub fn max_width(mut self, max_width: &'a str) -> Self {
+        self.max_width = Some(max_width);
+        self
+    }
+
+    /// Sets the `show_changes` query parameter.
+    pub fn show_changes(mut self, show_changes: &'a str) -> Self {
+        self.show_changes = Some(show_changes);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            account_id,
+            document_id,
+            page_number,
+            template_id,
+            dpi,
+            max_height,
+            max_width,
+            show_changes,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = dpi {
+            query_args.push(("dpi".to_string(), v.to_string()));
+        }
+        if let Some(v) = max_height {
+            query_args.push(("max_height".to_string(), v.to_string()));
+        }
+        if let Some(v) = max_width {
+            query_args.push(("max_width".to_string(), v.to_string()));
+        }
+        if let Some(v) = show_changes {
+            query_args.push(("show_changes".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v2.1/accounts/{}/templates/{}/documents/{}/pages/{}/page_image?{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(template_id),
+                crate::progenitor_support::encode_path(document_id),
+                crate::progenitor_support::encode_path(page_number),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct Templates {
     pub client: Client,
 }
@@ -215,6 +768,44 @@ impl Templates {
             )
             .await
     }
+    /**
+     * Gets template definitions.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/templates` endpoint.
+     *
+     * As opposed to `get`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Retrieves the list of templates for the specified account. The request can be limited to a specific folder.
+     */
+    pub fn get_request<'a>(&'a self, account_id: &'a str) -> TemplatesGetRequest<'a> {
+        TemplatesGetRequest {
+            client: self,
+            account_id,
+            count: None,
+            created_from_date: None,
+            created_to_date: None,
+            folder_ids: None,
+            folder_types: None,
+            from_date: None,
+            include: None,
+            is_deleted_template_only: None,
+            is_download: None,
+            modified_from_date: None,
+            modified_to_date: None,
+            order: None,
+            order_by: None,
+            search_fields: None,
+            search_text: None,
+            shared_by_me: None,
+            start_position: None,
+            template_ids: None,
+            to_date: None,
+            used_from_date: None,
+            used_to_date: None,
+            user_filter: None,
+            user_id: None,
+        }
+    }
     /**
      * Creates one or more templates.
      *
@@ -349,6 +940,27 @@ impl Templates {
             )
             .await
     }
+    /**
+     * Gets a specific template associated with a specified account.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/templates/{templateId}` endpoint.
+     *
+     * As opposed to `get_templates`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Retrieves the definition of the specified template.
+     */
+    pub fn get_templates_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        template_id: &'a str,
+    ) -> TemplatesGetTemplatesRequest<'a> {
+        TemplatesGetTemplatesRequest {
+            client: self,
+            account_id,
+            template_id,
+            include: None,
+        }
+    }
     /**
      * Updates an existing template.
      *
@@ -461,6 +1073,35 @@ impl Templates {
             )
             .await
     }
+    /**
+     * Returns document page image(s) based on input.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/templates/{templateId}/documents/{documentId}/pages` endpoint.
+     *
+     * As opposed to `pages_get_page_image`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Returns images of the pages in a template document for display based on the parameters that you specify.
+     */
+    pub fn pages_get_page_image_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        document_id: &'a str,
+        template_id: &'a str,
+    ) -> TemplatesPagesGetPageImageRequest<'a> {
+        TemplatesPagesGetPageImageRequest {
+            client: self,
+            account_id,
+            document_id,
+            template_id,
+            count: None,
+            dpi: None,
+            max_height: None,
+            max_width: None,
+            nocache: None,
+            show_changes: None,
+            start_position: None,
+        }
+    }
     /**
      * Deletes a page from a document in an template.
      *
@@ -567,6 +1208,34 @@ impl Templates {
             )
             .await
     }
+    /**
+     * Gets a page image from a template for display.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/templates/{templateId}/documents/{documentId}/pages/{pageNumber}/page_image` endpoint.
+     *
+     * As opposed to `pages_get_page_image_templates`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Retrieves a page image for display from the specified template.
+     */
+    pub fn pages_get_page_image_templates_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        document_id: &'a str,
+        page_number: &'a str,
+        template_id: &'a str,
+    ) -> TemplatesPagesGetPageImageTemplatesRequest<'a> {
+        TemplatesPagesGetPageImageTemplatesRequest {
+            client: self,
+            account_id,
+            document_id,
+            page_number,
+            template_id,
+            dpi: None,
+            max_height: None,
+            max_width: None,
+            show_changes: None,
+        }
+    }
     /**
      * Rotates page image from a template for display.
      *
@@ -34,6 +34,10 @@ pub struct AccountBrands {
         rename = "senderBrandIdDefault"
     )]
     pub sender_brand_id_default: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Details about account consumer disclosures.
@@ -356,6 +360,10 @@ pub struct AccountConsumerDisclosures {
         rename = "withdrawState"
     )]
     pub withdraw_state: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// An `accountCustomField` is an envelope custom field that you set at the account level. Applying custom fields enables account administators to group and manage envelopes.
@@ -381,6 +389,10 @@ pub struct AccountCustomFields {
         rename = "textCustomFields"
     )]
     pub text_custom_fields: Vec<TextCustomField>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Contains details about the password rules for an account.
@@ -579,6 +591,10 @@ pub struct AccountPasswordRules {
         rename = "questionsRequiredMetadata"
     )]
     pub questions_required_metadata: Option<AccountPasswordQuestionsRequired>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The AccountPermissionProfiles resource provides methods that allow you to manage permission profiles for groups of account users.
@@ -648,6 +664,10 @@ pub struct AccountPermissionProfiles {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub users: Vec<UserInformation>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -662,6 +682,10 @@ pub struct AccountSealProviders {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub seals: Vec<SealIdentifier>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// This resource provides information on the Standards Based Signature providers that have been provisioned for an account.
@@ -679,6 +703,10 @@ pub struct AccountSignatureProviders {
         rename = "signatureProviders"
     )]
     pub signature_providers: Vec<AccountSignatureProvider>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -1001,6 +1029,10 @@ pub struct AccountSignatures {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub status: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Tab settings determine the tab types and tab functionality that are enabled for an account.
@@ -1405,6 +1437,10 @@ pub struct AccountTabSettings {
         rename = "textTabsMetadata"
     )]
     pub text_tabs_metadata: Option<SettingsMetadata>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -1496,6 +1532,10 @@ pub struct AccountWatermarks {
         rename = "watermarkText"
     )]
     pub watermark_text: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Account management
@@ -1869,11 +1909,20 @@ pub struct Accounts {
         rename = "useDisplayAppliance"
     )]
     pub use_display_appliance: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ApplianceInfo {}
+pub struct ApplianceInfo {
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
 
 /// The `EmailArchive` resource provides methods for managing your email archive configuration, which consists of the BCC email address or addresses that you want to use to archive DocuSign emails. Each account can use up to five BCC email addresses for archiving purposes.
 ///
@@ -1956,6 +2005,10 @@ pub struct BccEmailArchive {
         rename = "totalSetSize"
     )]
     pub total_set_size: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Billing plans
@@ -2054,6 +2107,10 @@ pub struct BillingPlans {
         rename = "successorPlans"
     )]
     pub successor_plans: Vec<BillingPlan>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The bulk send list resource provides methods that enable you to create and manage bulk sending lists, which you can use to send multiple copies of an envelope in a single batch.
@@ -2096,6 +2153,10 @@ pub struct BulkSend {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The ChunkedUploads resource provides methods to complete integrity checks, and to add, commit, retrieve, initiate and delete chunked uploads.
@@ -2189,6 +2250,10 @@ pub struct ChunkedUploads {
         rename = "totalSize"
     )]
     pub total_size: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Cloud storage
@@ -2290,6 +2355,10 @@ pub struct CloudStorage {
         rename = "totalSetSize"
     )]
     pub total_set_size: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The CloudStorageProviders resource provides methods that allow you to manage the cloud storage providers associate with an account.
@@ -2305,6 +2374,10 @@ pub struct CloudStorageProviders {
         rename = "storageProviders"
     )]
     pub storage_providers: Vec<CloudStorageProvider>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Details about envelope comments.
@@ -2512,6 +2585,10 @@ pub struct Comments {
         rename = "visibleTo"
     )]
     pub visible_to: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Contains information about a DocuSign Connect configuration.
@@ -2870,6 +2947,10 @@ pub struct ConnectConfigurations {
         rename = "userName"
     )]
     pub user_name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Connect event logging information. This object contains sections for regular Connect logs and for Connect failures.
@@ -2913,6 +2994,10 @@ pub struct ConnectEvents {
         rename = "type"
     )]
     pub type_: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -2956,6 +3041,10 @@ pub struct ConnectSecret {
         rename = "type"
     )]
     pub type_: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The `Contacts` resource enables you to manage the contact in an account's address book.
@@ -3085,6 +3174,10 @@ pub struct Contacts {
         rename = "signingGroupName"
     )]
     pub signing_group_name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Custom tabs
@@ -3553,6 +3646,10 @@ pub struct CustomTabs {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub width: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// This resource is used to create a responsive preview of a specific document.
@@ -3568,6 +3665,10 @@ pub struct DocumentResponsiveHtmlPreview {
         rename = "htmlDefinitions"
     )]
     pub html_definitions: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -3631,6 +3732,10 @@ pub struct ENoteConfigurations {
         rename = "userName"
     )]
     pub user_name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -3693,6 +3798,10 @@ pub struct EnvelopeAttachments {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Details about envelope consumer disclosures.
@@ -4015,6 +4124,10 @@ pub struct EnvelopeConsumerDisclosures {
         rename = "withdrawState"
     )]
     pub withdraw_state: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// An envelope custom field enables you to collect custom data about envelopes on a per-envelope basis. You can then use the custom data for sorting, organizing, searching, and other downstream processes. For example, you can use custom fields to copy envelopes or data to multiple areas in Salesforce. eOriginal customers can eVault their documents from the web app on a per-envelope basis by setting an envelope custom field with a name like "eVault with eOriginal?" to "Yes" or "No".
@@ -4046,6 +4159,10 @@ pub struct EnvelopeCustomFields {
         rename = "textCustomFields"
     )]
     pub text_custom_fields: Vec<TextCustomField>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Envelope document fields
@@ -4061,6 +4178,10 @@ pub struct EnvelopeDocumentFields {
         rename = "documentFields"
     )]
     pub document_fields: Vec<NameValue>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -4076,6 +4197,10 @@ pub struct EnvelopeDocumentHtmlDefinitions {
         rename = "htmlDefinitions"
     )]
     pub html_definitions: Vec<DocumentHtmlDefinitionOriginal>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Envelope Document Tabs resource
@@ -4470,6 +4595,10 @@ pub struct EnvelopeDocumentTabs {
         rename = "zipTabs"
     )]
     pub zip_tabs: Vec<Zip>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Document Visibility enables senders to control the visibility of the documents in an envelope at the recipient level. For example, if the parties associated with a legal proceeding should have access to different documents, the Document Visibility feature enables you to keep all of the documents in the same envelope and set view permissions for the documents by recipient. This functionality is enabled for envelopes and templates. It is not available for PowerForms.
@@ -4503,6 +4632,10 @@ pub struct EnvelopeDocumentVisibility {
         rename = "documentVisibility"
     )]
     pub document_visibility: Vec<DocumentVisibility>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Envelope documents
@@ -4528,6 +4661,10 @@ pub struct EnvelopeDocuments {
         rename = "envelopeId"
     )]
     pub envelope_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Envelope email settings
@@ -4563,6 +4700,10 @@ pub struct EnvelopeEmailSettings {
         rename = "replyEmailNameOverride"
     )]
     pub reply_email_name_override: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// This object contains the data that recipients have entered into the form fields associated with an envelope.
@@ -4637,6 +4778,10 @@ pub struct EnvelopeFormData {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub status: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -4652,6 +4797,10 @@ pub struct EnvelopeHtmlDefinitions {
         rename = "htmlDefinitions"
     )]
     pub html_definitions: Vec<DocumentHtmlDefinitionOriginal>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// This section provides information about envelope locks.
@@ -4735,6 +4884,10 @@ pub struct EnvelopeLocks {
         rename = "useScratchPad"
     )]
     pub use_scratch_pad: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the tabs associated with a recipient. Each property is a list of a type of tab.
@@ -5169,6 +5322,10 @@ pub struct EnvelopeRecipientTabs {
         rename = "zipTabs"
     )]
     pub zip_tabs: Vec<Zip>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Envelope recipients
@@ -5296,6 +5453,10 @@ pub struct EnvelopeRecipients {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub witnesses: Vec<Witness>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Envelope templates
@@ -5310,6 +5471,10 @@ pub struct EnvelopeTemplates {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub templates: Vec<TemplateSummary>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// This resource provides methods that enable account administrators to create and manage envelope transfer rules.
@@ -5385,6 +5550,10 @@ pub struct EnvelopeTransferRules {
         rename = "totalSetSize"
     )]
     pub total_set_size: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Embedding Envelope views
@@ -5399,6 +5568,10 @@ pub struct EnvelopeViews {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -5434,6 +5607,10 @@ pub struct EnvelopeWorkflowDefinition {
         rename = "workflowSteps"
     )]
     pub workflow_steps: Vec<WorkflowStep>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Envelope creation, management
@@ -6165,6 +6342,10 @@ pub struct Envelopes {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub workflow: Option<Workflow>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -6199,6 +6380,10 @@ pub struct FavoriteTemplates {
         rename = "templatesUpdatedCount"
     )]
     pub templates_updated_count: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Folder management
@@ -6282,6 +6467,10 @@ pub struct Folders {
         rename = "totalSetSize"
     )]
     pub total_set_size: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// If your account includes multiple signing brands, you can use the groups functionality to assign different brands to different groups. This resource enables you to manage group brands.
@@ -6317,6 +6506,10 @@ pub struct GroupBrands {
         rename = "senderBrandIdDefault"
     )]
     pub sender_brand_id_default: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Groups' users
@@ -6391,6 +6584,10 @@ pub struct GroupUsers {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub users: Vec<UserInfo>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Group information
@@ -6465,6 +6662,10 @@ pub struct Groups {
         rename = "totalSetSize"
     )]
     pub total_set_size: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Identity Verification enables you to verify a signer's identity before they can access a document. The `IdentityVerifications` resource provides a method that enables you to list the workflows that are available to an account.
@@ -6480,6 +6681,10 @@ pub struct IdentityVerifications {
         rename = "identityVerification"
     )]
     pub identity_verification: Vec<AccountIdentityVerificationWorkflow>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Invoices
@@ -6583,6 +6788,10 @@ pub struct Invoices {
         rename = "taxableAmount"
     )]
     pub taxable_amount: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Use DocuSign eNotary to notarize documents digitally.
@@ -6636,6 +6845,10 @@ pub struct Notary {
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "userInfo")]
     pub user_info: Option<UserInformation>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -6695,6 +6908,10 @@ pub struct NotaryJournals {
         rename = "signerName"
     )]
     pub signer_name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Creating, updating, and deleting notary jurisdiction objects.
@@ -6763,6 +6980,10 @@ pub struct NotaryJurisdiction {
         rename = "sealType"
     )]
     pub seal_type: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The `NotificationDefaults` resource provides methods that enable you to manage the default notifications for envelopes.
@@ -6786,6 +7007,10 @@ pub struct NotificationDefaults {
         rename = "emailNotifications"
     )]
     pub email_notifications: Option<NotificationDefaultSettings>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Information about a connected payment gateway account.
@@ -6924,6 +7149,10 @@ pub struct PaymentGatewayAccounts {
         rename = "zeroDecimalCurrencies"
     )]
     pub zero_decimal_currencies: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Payments
@@ -6977,6 +7206,10 @@ pub struct Payments {
         rename = "paymentNumber"
     )]
     pub payment_number: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Data that recipients have entered in PowerForm fields.
@@ -7001,6 +7234,10 @@ pub struct PowerFormData {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub recipients: Vec<PowerFormDataRecipient>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The PowerForms resource enables you to create fillable forms that you can email or make available for self service on the web.
@@ -7250,6 +7487,10 @@ pub struct PowerForms {
         rename = "usesRemaining"
     )]
     pub uses_remaining: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -7264,6 +7505,10 @@ pub struct Reports {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub reports: Vec<ReportInProductListItem>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Request logs
@@ -7299,6 +7544,10 @@ pub struct RequestLogs {
         rename = "apiRequestLogging"
     )]
     pub api_request_logging: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// API resource information
@@ -7313,6 +7562,10 @@ pub struct Resources {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub resources: Vec<NameValue>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// This resource is used to create a responsive preview of all of the documents in an envelope.
@@ -7328,6 +7581,10 @@ pub struct ResponsiveHtmlPreview {
         rename = "htmlDefinitions"
     )]
     pub html_definitions: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// API service information
@@ -7393,6 +7650,10 @@ pub struct Services {
         rename = "serviceVersions"
     )]
     pub service_versions: Vec<ServiceVersion>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Signing groups' users
@@ -7407,6 +7668,10 @@ pub struct SigningGroupUsers {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub users: Vec<SigningGroupUser>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Signing groups
@@ -7508,6 +7773,10 @@ pub struct SigningGroups {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub users: Vec<SigningGroupUser>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -7912,6 +8181,10 @@ pub struct TabsBlob {
         rename = "textTabsMetadata"
     )]
     pub text_tabs_metadata: Option<SettingsMetadata>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Template bulk recipients
@@ -7987,6 +8260,10 @@ pub struct TemplateBulkRecipients {
         rename = "totalSetSize"
     )]
     pub total_set_size: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A template custom field enables you to prepopulate custom metadata for all new envelopes that are created by using a specific template. You can then use the custom data for sorting, organizing, searching, and other downstream processes.
@@ -8012,6 +8289,10 @@ pub struct TemplateCustomFields {
         rename = "textCustomFields"
     )]
     pub text_custom_fields: Vec<TextCustomField>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Template document fields
@@ -8027,6 +8308,10 @@ pub struct TemplateDocumentFields {
         rename = "documentFields"
     )]
     pub document_fields: Vec<NameValue>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -8042,6 +8327,10 @@ pub struct TemplateDocumentHtmlDefinitions {
         rename = "htmlDefinitions"
     )]
     pub html_definitions: Vec<DocumentHtmlDefinitionOriginal>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// This resource is used to create a responsive preview of a specific template document.
@@ -8057,6 +8346,10 @@ pub struct TemplateDocumentResponsiveHtmlPreview {
         rename = "htmlDefinitions"
     )]
     pub html_definitions: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -8451,6 +8744,10 @@ pub struct TemplateDocumentTabs {
         rename = "zipTabs"
     )]
     pub zip_tabs: Vec<Zip>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Document Visibility enables senders to control the visibility of the documents in an envelope at the recipient level. For example, if the parties associated with a legal proceeding should have access to different documents, the Document Visibility feature enables you to keep all of the documents in the same envelope and set view permissions for the documents by recipient. This functionality is enabled for envelopes and templates. It is not available for PowerForms.
@@ -8484,6 +8781,10 @@ pub struct TemplateDocumentVisibility {
         rename = "documentVisibility"
     )]
     pub document_visibility: Vec<DocumentVisibility>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Template documents
@@ -8509,6 +8810,10 @@ pub struct TemplateDocuments {
         rename = "templateId"
     )]
     pub template_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -8524,6 +8829,10 @@ pub struct TemplateHtmlDefinitions {
         rename = "htmlDefinitions"
     )]
     pub html_definitions: Vec<DocumentHtmlDefinitionOriginal>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// This section provides information about template locks. You use template locks to prevent others from making changes to a template while you are modifying it.
@@ -8607,6 +8916,10 @@ pub struct TemplateLocks {
         rename = "useScratchPad"
     )]
     pub use_scratch_pad: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Template tabs
@@ -9001,6 +9314,10 @@ pub struct TemplateRecipientTabs {
         rename = "zipTabs"
     )]
     pub zip_tabs: Vec<Zip>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Template recipients
@@ -9128,6 +9445,10 @@ pub struct TemplateRecipients {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub witnesses: Vec<Witness>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// This resource is used to create a responsive preview of all of the documents associated with a template.
@@ -9143,6 +9464,10 @@ pub struct TemplateResponsiveHtmlPreview {
         rename = "htmlDefinitions"
     )]
     pub html_definitions: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A TemplateView contains a URL that you can embed in your application to generate a template view that uses the DocuSign user interface (UI).
@@ -9157,6 +9482,10 @@ pub struct TemplateViews {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Template management
@@ -10095,6 +10424,10 @@ pub struct Templates {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub workflow: Option<Workflow>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Users' custom settings
@@ -10110,6 +10443,10 @@ pub struct UserCustomSettings {
         rename = "customSettings"
     )]
     pub custom_settings: Vec<NameValue>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Users' profiles
@@ -10227,6 +10564,10 @@ pub struct UserProfiles {
         rename = "userProfileLastModifiedDate"
     )]
     pub user_profile_last_modified_date: Option<chrono::NaiveDate>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Users' signatures
@@ -10529,6 +10870,10 @@ pub struct UserSignatures {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub status: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The Users resource enables you to create and manage account users.
@@ -10942,6 +11287,10 @@ pub struct Users {
         rename = "workAddress"
     )]
     pub work_address: Option<AddressInformation>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// This object contains information about a file or folder in a workspace.
@@ -11173,6 +11522,10 @@ pub struct WorkspaceItems {
         rename = "userAuthorization"
     )]
     pub user_authorization: Option<WorkspaceUserAuthorization>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A DocuSign workspace is a collaboration area for sharing files and data.
@@ -11298,6 +11651,10 @@ pub struct Workspaces {
         rename = "workspaceUri"
     )]
     pub workspace_uri: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Object specifying the format of the string provided to a recipient in order to access an envelope.
@@ -11398,6 +11755,10 @@ pub struct AccessCodeFormat {
         rename = "specialCharacterRequiredMetadata"
     )]
     pub special_character_required_metadata: Option<SettingsMetadata>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Contains information about the address associated with the account.
@@ -11517,6 +11878,10 @@ pub struct AccountAddress {
         rename = "supportedCountries"
     )]
     pub supported_countries: Vec<Country>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Contains information about an account billing plan.
@@ -11761,6 +12126,10 @@ pub struct AccountBillingPlan {
         rename = "supportPlanFee"
     )]
     pub support_plan_fee: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Defines an account billing plan response object.
@@ -11859,6 +12228,10 @@ pub struct AccountBillingPlanResponse {
         rename = "successorPlans"
     )]
     pub successor_plans: Vec<BillingPlan>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -11893,6 +12266,10 @@ pub struct AccountIdentityInputOption {
         rename = "valueType"
     )]
     pub value_type: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -11908,6 +12285,10 @@ pub struct AccountIdentityVerificationResponse {
         rename = "identityVerification"
     )]
     pub identity_verification: Vec<AccountIdentityVerificationWorkflow>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Information about a specific step in an Identity Verification workflow.
@@ -11932,6 +12313,10 @@ pub struct AccountIdentityVerificationStep {
         rename = "type"
     )]
     pub type_: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Specifies an Identity Verification workflow.
@@ -11996,6 +12381,10 @@ pub struct AccountIdentityVerificationWorkflow {
         rename = "workflowResourceKey"
     )]
     pub workflow_resource_key: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Contains account Information.
@@ -12369,6 +12758,10 @@ pub struct AccountInformation {
         rename = "useDisplayAppliance"
     )]
     pub use_display_appliance: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -12394,6 +12787,10 @@ pub struct AccountMinimumPasswordLength {
         rename = "minimumLength"
     )]
     pub minimum_length: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A complex element that specifies notifications (expirations and reminders) for the envelope.
@@ -12419,6 +12816,10 @@ pub struct AccountNotification {
         rename = "userOverrideEnabled"
     )]
     pub user_override_enabled: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -12444,6 +12845,10 @@ pub struct AccountPasswordExpireDays {
         rename = "minimumDays"
     )]
     pub minimum_days: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -12469,6 +12874,10 @@ pub struct AccountPasswordLockoutDurationMinutes {
         rename = "minimumMinutes"
     )]
     pub minimum_minutes: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -12483,6 +12892,10 @@ pub struct AccountPasswordLockoutDurationType {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub options: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -12508,6 +12921,10 @@ pub struct AccountPasswordMinimumAgeDays {
         rename = "minimumAge"
     )]
     pub minimum_age: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Information about the number of password questions required (0 to 4) to confirm a user's identity when a user needs to reset their password.
@@ -12533,6 +12950,10 @@ pub struct AccountPasswordQuestionsRequired {
         rename = "minimumQuestions"
     )]
     pub minimum_questions: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -12731,6 +13152,10 @@ pub struct AccountPasswordRulesData {
         rename = "questionsRequiredMetadata"
     )]
     pub questions_required_metadata: Option<AccountPasswordQuestionsRequired>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -12745,6 +13170,10 @@ pub struct AccountPasswordStrengthType {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub options: Vec<AccountPasswordStrengthTypeOption>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -12819,6 +13248,10 @@ pub struct AccountPasswordStrengthTypeOption {
         rename = "passwordIncludeUpperCase"
     )]
     pub password_include_upper_case: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// This object defines account permissions for users who are associated with the account permission profile.
@@ -13518,6 +13951,10 @@ pub struct AccountRoleSettings {
         rename = "vaultingModeMetadata"
     )]
     pub vaulting_mode_metadata: Option<SettingsMetadata>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -13532,6 +13969,10 @@ pub struct AccountSeals {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub seals: Vec<SealIdentifier>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Contains account settings information. Used in requests to set property values. Used in responses to report property values.
@@ -18642,6 +19083,10 @@ pub struct AccountSettingsInformation {
         rename = "wurflMinAllowableScreenSizeMetadata"
     )]
     pub wurfl_min_allowable_screen_size_metadata: Option<SettingsMetadata>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Contains shared access information.
@@ -18736,6 +19181,10 @@ pub struct AccountSharedAccess {
         rename = "totalSetSize"
     )]
     pub total_set_size: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -19058,6 +19507,10 @@ pub struct AccountSignature {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub status: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -19222,6 +19675,10 @@ pub struct AccountSignatureDefinition {
         rename = "stampSizeMM"
     )]
     pub stamp_size_mm: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Contains information abotu the signature provider associated with the Identity Verification workflow.
@@ -19312,6 +19769,10 @@ pub struct AccountSignatureProvider {
         rename = "signatureProviderRequiredOptions"
     )]
     pub signature_provider_required_options: Vec<SignatureProviderRequiredOption>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Reserved for DocuSign.
@@ -19347,6 +19808,10 @@ pub struct AccountSignatureProviderOption {
         rename = "signatureProviderOptionName"
     )]
     pub signature_provider_option_name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -19362,6 +19827,10 @@ pub struct AccountSignatureProvidersData {
         rename = "signatureProviders"
     )]
     pub signature_providers: Vec<AccountSignatureProvider>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -19377,6 +19846,10 @@ pub struct AccountSignaturesInformation {
         rename = "accountSignatures"
     )]
     pub account_signatures: Vec<AccountSignature>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// An object that defines the options that are available to non-administrators in the UI.
@@ -19638,6 +20111,10 @@ pub struct AccountUiSettings {
         rename = "uploadNewImageToSignOrInitialMetadata"
     )]
     pub upload_new_image_to_sign_or_initial_metadata: Option<SettingsMetadata>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Contains information about add ons.
@@ -19680,6 +20157,10 @@ pub struct AddOn {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Contains address information.
@@ -19771,6 +20252,10 @@ pub struct AddressInformation {
         rename = "zipPlus4"
     )]
     pub zip_plus_4: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Contains address input information.
@@ -19805,6 +20290,10 @@ pub struct AddressInformationInput {
         rename = "receiveInResponse"
     )]
     pub receive_in_response: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -19830,6 +20319,10 @@ pub struct AdminMessage {
         rename = "moreInformation"
     )]
     pub more_information: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Contains information about an agent recipient. An agent is a recipient who can add name and email information for recipients that appear after the agent in routing order.
@@ -20500,6 +20993,10 @@ pub struct Agent {
         rename = "userId"
     )]
     pub user_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Contains API request log information.
@@ -20543,6 +21040,10 @@ pub struct ApiRequestLog {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub status: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Contains information about mutiple API request logs.
@@ -20558,6 +21059,10 @@ pub struct ApiRequestLogsResult {
         rename = "apiRequestLogs"
     )]
     pub api_request_logs: Vec<ApiRequestLog>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Contains information about an APP store product.
@@ -20583,6 +21088,10 @@ pub struct AppStoreProduct {
         rename = "productId"
     )]
     pub product_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Contains information about an APP store receipt.
@@ -20608,6 +21117,10 @@ pub struct AppStoreReceipt {
         rename = "receiptData"
     )]
     pub receipt_data: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A tab that allows the recipient to approve documents
@@ -21669,6 +22182,10 @@ pub struct Approve {
         rename = "yPositionMetadata"
     )]
     pub y_position_metadata: Option<PropertyMetadata>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -21710,6 +22227,10 @@ pub struct AskAdmin {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub phone: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Contains information about an attachment.
@@ -21782,6 +22303,10 @@ pub struct Attachment {
         rename = "remoteUrl"
     )]
     pub remote_url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Contains information about the method used for authentication.
@@ -21827,6 +22352,10 @@ pub struct AuthenticationMethod {
         rename = "totalCount"
     )]
     pub total_count: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A complex element that contains information about a user's authentication status.
@@ -22003,6 +22532,10 @@ pub struct AuthenticationStatus {
         rename = "yahooResult"
     )]
     pub yahoo_result: Option<EventResult>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Contains information about the BCC email address.
@@ -22027,6 +22560,10 @@ pub struct BccEmailAddress {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub email: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// This object contains information abut a BCC email archive configuration (a BCC email address used to archive DocuSign-generated emails).
@@ -22121,6 +22658,10 @@ pub struct BccEmailArchiveData {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub uri: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Contains details about the history of the BCC email archive configuration.
@@ -22181,6 +22722,10 @@ pub struct BccEmailArchiveHistory {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub status: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -22256,6 +22801,10 @@ pub struct BccEmailArchiveHistoryList {
         rename = "totalSetSize"
     )]
     pub total_set_size: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Contains a list of BCC email archive configurations.
@@ -22331,6 +22880,10 @@ pub struct BccEmailArchiveList {
         rename = "totalSetSize"
     )]
     pub total_set_size: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Contains information about a billing charge.
@@ -22463,6 +23016,10 @@ pub struct BillingCharge {
         rename = "usedQuantity"
     )]
     pub used_quantity: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Defines a billing charge response object.
@@ -22478,6 +23035,10 @@ pub struct BillingChargeResponse {
         rename = "billingChargeItems"
     )]
     pub billing_charge_items: Vec<BillingCharge>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -22512,6 +23073,10 @@ pub struct BillingDiscount {
         rename = "endQuantity"
     )]
     pub end_quantity: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Contains information about a billing invoice.
@@ -22615,6 +23180,10 @@ pub struct BillingInvoice {
         rename = "taxableAmount"
     )]
     pub taxable_amount: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Contains information about an item on a billing invoice.
@@ -22669,6 +23238,10 @@ pub struct BillingInvoiceItem {
         rename = "unitPrice"
     )]
     pub unit_price: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Defines a billing invoice response object.
@@ -22704,6 +23277,10 @@ pub struct BillingInvoicesResponse {
         rename = "previousUri"
     )]
     pub previous_uri: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -22739,6 +23316,10 @@ pub struct BillingInvoicesSummary {
         rename = "paymentAllowed"
     )]
     pub payment_allowed: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Contains information on a billing plan.
@@ -22773,6 +23354,10 @@ pub struct BillingPayment {
         rename = "paymentId"
     )]
     pub payment_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Defines a billing payment request object.
@@ -22826,6 +23411,10 @@ pub struct BillingPaymentItem {
         rename = "paymentNumber"
     )]
     pub payment_number: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -22841,6 +23430,10 @@ pub struct BillingPaymentRequest {
         rename = "paymentAmount"
     )]
     pub payment_amount: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Defines an billing payment response object.
@@ -22856,6 +23449,10 @@ pub struct BillingPaymentResponse {
         rename = "billingPayments"
     )]
     pub billing_payments: Vec<BillingPayment>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Defines a billing payments response object.
@@ -22891,6 +23488,10 @@ pub struct BillingPaymentsResponse {
         rename = "previousUri"
     )]
     pub previous_uri: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Contains information about a billing plan.
@@ -23046,6 +23647,10 @@ pub struct BillingPlan {
         rename = "supportPlanFee"
     )]
     pub support_plan_fee: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// This object contains details about a billing plan.
@@ -23224,6 +23829,10 @@ pub struct BillingPlanInformation {
         rename = "saleDiscountSeatPriceOverride"
     )]
     pub sale_discount_seat_price_override: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Information used to provide a preview of a billing plan.
@@ -23284,6 +23893,10 @@ pub struct BillingPlanPreview {
         rename = "totalAmount"
     )]
     pub total_amount: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Defines a billing plan response object.
@@ -23308,6 +23921,10 @@ pub struct BillingPlanResponse {
         rename = "successorPlans"
     )]
     pub successor_plans: Vec<BillingPlan>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Defines a billing plan update response object.
@@ -23392,6 +24009,10 @@ pub struct BillingPlanUpdateResponse {
         rename = "planName"
     )]
     pub plan_name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Defines a billing plans response object.
@@ -23407,6 +24028,10 @@ pub struct BillingPlansResponse {
         rename = "billingPlans"
     )]
     pub billing_plans: Vec<BillingPlan>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -23442,6 +24067,10 @@ pub struct BillingPrice {
         rename = "unitPrice"
     )]
     pub unit_price: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Information about a brand that is associated with an account. A brand applies custom styles and text to an envelope.
@@ -23581,6 +24210,10 @@ pub struct Brand {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub resources: Option<BrandResourceUrls>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Deprecated.
@@ -23625,6 +24258,10 @@ pub struct BrandEmailContent {
         rename = "linkText"
     )]
     pub link_text: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Information about a link that a brand uses.
@@ -23670,6 +24307,10 @@ pub struct BrandLink {
         rename = "urlOrMailTo"
     )]
     pub url_or_mail_to: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Information about the logos associated with the brand.
@@ -23702,6 +24343,10 @@ pub struct BrandLogos {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub secondary: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// This request object contains information about a specific brand.
@@ -23717,6 +24362,10 @@ pub struct BrandRequest {
         rename = "brandId"
     )]
     pub brand_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Brands use resource files to style the following experiences:
@@ -23794,6 +24443,10 @@ pub struct BrandResourceUrls {
         rename = "signingCaptive"
     )]
     pub signing_captive: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Information about the resource files that the brand uses for the email, signing, sending, and captive (embedded) signing experiences.
@@ -23877,6 +24530,10 @@ pub struct BrandResources {
         rename = "resourcesContentUri"
     )]
     pub resources_content_uri: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -23892,6 +24549,10 @@ pub struct BrandResourcesList {
         rename = "resourcesContentTypes"
     )]
     pub resources_content_types: Vec<BrandResources>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Details about one or more brands.
@@ -23906,6 +24567,10 @@ pub struct BrandsRequest {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub brands: Vec<BrandRequest>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -23940,6 +24605,10 @@ pub struct BrandsResponse {
         rename = "senderBrandIdDefault"
     )]
     pub sender_brand_id_default: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -24032,6 +24701,10 @@ pub struct BulkEnvelope {
         rename = "transactionId"
     )]
     pub transaction_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -24174,6 +24847,10 @@ pub struct BulkEnvelopeStatus {
         rename = "totalSetSize"
     )]
     pub total_set_size: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -24275,6 +24952,10 @@ pub struct BulkRecipient {
         rename = "tabLabels"
     )]
     pub tab_labels: Vec<BulkRecipientTabLabel>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -24298,6 +24979,10 @@ pub struct BulkRecipientSignatureProvider {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub value: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -24321,6 +25006,10 @@ pub struct BulkRecipientTabLabel {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub value: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -24336,6 +25025,10 @@ pub struct BulkRecipientsRequest {
         rename = "bulkRecipients"
     )]
     pub bulk_recipients: Vec<BulkRecipient>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -24411,6 +25104,10 @@ pub struct BulkRecipientsResponse {
         rename = "totalSetSize"
     )]
     pub total_set_size: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -24456,6 +25153,10 @@ pub struct BulkRecipientsSummaryResponse {
         rename = "errorDetails"
     )]
     pub error_details: Vec<ErrorDetails>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -24466,6 +25167,10 @@ pub struct BulkRecipientsUpdateResponse {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub signer: Option<Signer>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -24481,6 +25186,10 @@ pub struct BulkSendBatchRequest {
         rename = "batchName"
     )]
     pub batch_name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Result of `getBulkSendBatchStatus`
@@ -24613,6 +25322,10 @@ pub struct BulkSendBatchStatus {
         rename = "submittedDate"
     )]
     pub submitted_date: Option<chrono::NaiveDate>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A list of bulk send batch summaries.
@@ -24718,6 +25431,10 @@ pub struct BulkSendBatchSummaries {
         rename = "totalSetSize"
     )]
     pub total_set_size: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Summary status of a single batch.
@@ -24800,6 +25517,10 @@ pub struct BulkSendBatchSummary {
         rename = "submittedDate"
     )]
     pub submitted_date: Option<chrono::NaiveDate>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A single bulk send error report.
@@ -24834,6 +25555,10 @@ pub struct BulkSendErrorStatus {
         rename = "recipientEmails"
     )]
     pub recipient_emails: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// This object contains information about the envelope or template that you want to send in bulk.
@@ -24859,6 +25584,10 @@ pub struct BulkSendRequest {
         rename = "envelopeOrTemplateId"
     )]
     pub envelope_or_template_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The object contains the response to a bulk send request.
@@ -24943,6 +25672,10 @@ pub struct BulkSendResponse {
         rename = "totalQueued"
     )]
     pub total_queued: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// This object contains the results of a bulk send test.
@@ -24977,6 +25710,10 @@ pub struct BulkSendTestResponse {
         rename = "validationErrors"
     )]
     pub validation_errors: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// This object contains the details to use for a specific copy, or instance, of the envelope. When you send an envelope by using a bulk send list, you can customize these properties for each instance.
@@ -25021,6 +25758,10 @@ pub struct BulkSendingCopy {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub recipients: Vec<BulkSendingCopyRecipient>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// This object contains details about a custom field for a bulk send copy. In a bulk send request, each custom field in the bulk send list must match a custom field in the envelope or template that you want to send.
@@ -25044,6 +25785,10 @@ pub struct BulkSendingCopyCustomField {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub value: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// This object contains details about a bulk send recipient.
@@ -25281,6 +26026,10 @@ pub struct BulkSendingCopyRecipient {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub tabs: Vec<BulkSendingCopyTab>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A tab associated with the bulk send recipient. In a bulk send request, each recipient tab must match a recipient tab on the envelope or template that you want to send. To match up, the `tabLabel` for this tab and the `tabLabel` for the corresponding tab on the envelope or template must be the same.
@@ -25312,6 +26061,10 @@ pub struct BulkSendingCopyTab {
         rename = "tabLabel"
     )]
     pub tab_label: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// This object contains the details for the bulk send list.
@@ -25346,6 +26099,10 @@ pub struct BulkSendingList {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// This complex type contains summaries that provide basic information about the bulk send lists that belong to the current user.
@@ -25361,6 +26118,10 @@ pub struct BulkSendingListSummaries {
         rename = "bulkListSummaries"
     )]
     pub bulk_list_summaries: Vec<BulkSendingListSummary>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// This object contains basic information about a bulk send list.
@@ -25405,6 +26166,10 @@ pub struct BulkSendingListSummary {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// This object contains details about a captive (embedded) recipient.
@@ -25448,6 +26213,10 @@ pub struct CaptiveRecipient {
         rename = "userName"
     )]
     pub user_name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Contains information about captive (embedded) recipients.
@@ -25463,6 +26232,10 @@ pub struct CaptiveRecipientInformation {
         rename = "captiveRecipients"
     )]
     pub captive_recipients: Vec<CaptiveRecipient>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Contains information about a carbon copy recipient. Carbon copy recipients get a copy of the envelope but don't need to sign, initial, date or add information to any of the documents.
@@ -26163,6 +26936,10 @@ pub struct CarbonCopy {
         rename = "userId"
     )]
     pub user_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Contains information about a certified delivery recipient. Certified delivery recipients must receive the completed documents for the envelope to be completed. However, they don't need to sign, initial, date or add information to any of the documents.
@@ -26858,6 +27635,10 @@ pub struct CertifiedDelivery {
         rename = "userId"
     )]
     pub user_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A tab that allows the recipient to select a yes/no (on/off) option.
@@ -27837,6 +28618,10 @@ pub struct Checkbox {
         rename = "yPositionMetadata"
     )]
     pub y_position_metadata: Option<PropertyMetadata>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// An object that contains information about the chunked upload part.
@@ -27860,6 +28645,10 @@ pub struct ChunkedUploadPart {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub size: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// This is the request object for uploading a chunked upload.
@@ -27884,6 +28673,10 @@ pub struct ChunkedUploadRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub data: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// This response object is returned after you upload a chunked upload.
@@ -27977,6 +28770,10 @@ pub struct ChunkedUploadResponse {
         rename = "totalSize"
     )]
     pub total_size: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Contains details about a specific cloud storage provider.
@@ -28030,6 +28827,10 @@ pub struct CloudStorageProvider {
         rename = "serviceId"
     )]
     pub service_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -28045,6 +28846,10 @@ pub struct CloudStorageProvidersData {
         rename = "storageProviders"
     )]
     pub storage_providers: Vec<CloudStorageProvider>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -28252,6 +29057,10 @@ pub struct Comment {
         rename = "visibleTo"
     )]
     pub visible_to: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -28295,6 +29104,10 @@ pub struct CommentHistoryResult {
         rename = "startTimetoken"
     )]
     pub start_timetoken: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -28357,6 +29170,10 @@ pub struct CommentPublish {
         rename = "visibleTo"
     )]
     pub visible_to: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -29160,6 +29977,10 @@ pub struct CommentThread {
         rename = "yPositionMetadata"
     )]
     pub y_position_metadata: Option<PropertyMetadata>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -29175,6 +29996,10 @@ pub struct CommentsPublish {
         rename = "commentsToPublish"
     )]
     pub comments_to_publish: Vec<CommentPublish>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -30107,6 +30932,10 @@ pub struct CommissionCounty {
         rename = "yPositionMetadata"
     )]
     pub y_position_metadata: Option<PropertyMetadata>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -31039,6 +31868,10 @@ pub struct CommissionExpiration {
         rename = "yPositionMetadata"
     )]
     pub y_position_metadata: Option<PropertyMetadata>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -31971,6 +32804,10 @@ pub struct CommissionNumber {
         rename = "yPositionMetadata"
     )]
     pub y_position_metadata: Option<PropertyMetadata>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -32903,6 +33740,10 @@ pub struct CommissionState {
         rename = "yPositionMetadata"
     )]
     pub y_position_metadata: Option<PropertyMetadata>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A tab that displays the recipient's company name.
@@ -33935,6 +34776,10 @@ pub struct Company {
         rename = "yPositionMetadata"
     )]
     pub y_position_metadata: Option<PropertyMetadata>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -33969,6 +34814,10 @@ pub struct CompleteSignHashResponse {
         rename = "remainingSignatureRequests"
     )]
     pub remaining_signature_requests: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// This object contains information about a [composite template][composite], which you can use to to apply multiple templates to a single envelope, combine templates with PDF forms, and combine templates with documents from cloud sources.
@@ -34031,6 +34880,10 @@ pub struct CompositeTemplate {
         rename = "serverTemplates"
     )]
     pub server_templates: Vec<ServerTemplate>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -34073,6 +34926,10 @@ pub struct ConditionalRecipientRule {
         rename = "recipientId"
     )]
     pub recipient_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -34106,6 +34963,10 @@ pub struct ConditionalRecipientRuleCondition {
         rename = "recipientLabel"
     )]
     pub recipient_label: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -34168,6 +35029,10 @@ pub struct ConditionalRecipientRuleFilter {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub value: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// This object contains the results of a ConnectConfigurations::GET method.
@@ -34192,6 +35057,10 @@ pub struct ConnectConfigResults {
         rename = "totalRecords"
     )]
     pub total_records: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -34550,6 +35419,10 @@ pub struct ConnectCustomConfiguration {
         rename = "userName"
     )]
     pub user_name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -34603,6 +35476,10 @@ pub struct ConnectDebugLog {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub payload: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// This object lets you choose the data format of your Connect response.
@@ -34640,6 +35517,10 @@ pub struct ConnectEventData {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub version: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A list of failed envelope IDs to retry.
@@ -34664,6 +35545,10 @@ pub struct ConnectFailureFilter {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub synchronous: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// This object contains details about a Connect failure result.
@@ -34718,6 +35603,10 @@ pub struct ConnectFailureResult {
         rename = "statusMessage"
     )]
     pub status_message: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -34733,6 +35622,10 @@ pub struct ConnectFailureResults {
         rename = "retryQueue"
     )]
     pub retry_queue: Vec<ConnectFailureResult>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Contains information about a Connect log entry.
@@ -34913,6 +35806,10 @@ pub struct ConnectLog {
         rename = "userName"
     )]
     pub user_name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -34956,6 +35853,10 @@ pub struct ConnectLogs {
         rename = "type"
     )]
     pub type_: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// This object is used to match a DocuSign field to a Salesforce field so that Docusign can send information to your Salesforce account.
@@ -35040,6 +35941,10 @@ pub struct ConnectSalesforceField {
         rename = "sfLockedValue"
     )]
     pub sf_locked_value: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A `connectSalesforceObject` is an object that updates envelope and document status or recipient status in your Salesforce account.
@@ -35161,6 +36066,10 @@ pub struct ConnectSalesforceObject {
         rename = "updateFields"
     )]
     pub update_fields: Vec<ConnectSalesforceField>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -35214,6 +36123,10 @@ pub struct ConnectUserObject {
         rename = "senderSearchableItems"
     )]
     pub sender_searchable_items: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The request object for the EnvelopeViews::createConsole method.
@@ -35239,6 +36152,10 @@ pub struct ConsoleViewRequest {
         rename = "returnUrl"
     )]
     pub return_url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Details about consumer disclosures.
@@ -35561,6 +36478,10 @@ pub struct ConsumerDisclosure {
         rename = "withdrawState"
     )]
     pub withdraw_state: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -35690,6 +36611,10 @@ pub struct Contact {
         rename = "signingGroupName"
     )]
     pub signing_group_name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// This response object contains information about the contacts associated with an account.
@@ -35764,6 +36689,10 @@ pub struct ContactGetResponse {
         rename = "totalSetSize"
     )]
     pub total_set_size: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The request object containing the new information for the contacts.
@@ -35779,6 +36708,10 @@ pub struct ContactModRequest {
         rename = "contactList"
     )]
     pub contact_list: Vec<Contact>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Details about the phone numbers associated with a specific contact.
@@ -35804,6 +36737,10 @@ pub struct ContactPhoneNumber {
         rename = "phoneType"
     )]
     pub phone_type: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// This response objects shows the updated details for the contacts.
@@ -35818,6 +36755,10 @@ pub struct ContactUpdateResponse {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub contacts: Vec<Contact>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The request body for the EnvelopeViews::createCorrect method.
@@ -35853,6 +36794,10 @@ pub struct CorrectViewRequest {
         rename = "viewUrl"
     )]
     pub view_url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -35896,6 +36841,10 @@ pub struct Country {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub provinces: Vec<Province>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// This object contains information about a credit card that is associated with an account.
@@ -35966,6 +36915,10 @@ pub struct CreditCardInformation {
         rename = "nameOnCard"
     )]
     pub name_on_card: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -35981,6 +36934,10 @@ pub struct CreditCardTypes {
         rename = "cardTypes"
     )]
     pub card_types: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -36998,6 +37955,10 @@ pub struct Currency {
         rename = "yPositionMetadata"
     )]
     pub y_position_metadata: Option<PropertyMetadata>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Information about the price and currency associated with the feature set. Reserved for internal DocuSign use only.
@@ -37053,6 +38014,10 @@ pub struct CurrencyFeatureSetPrice {
         rename = "seatFee"
     )]
     pub seat_fee: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -37117,6 +38082,10 @@ pub struct CurrencyPlanPrice {
         rename = "supportedCardTypes"
     )]
     pub supported_card_types: Option<CreditCardTypes>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// This object provides details about a custom field.
@@ -37197,6 +38166,10 @@ pub struct CustomField {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub value: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Contains information about custom fields.
@@ -37222,6 +38195,10 @@ pub struct CustomFields {
         rename = "textCustomFields"
     )]
     pub text_custom_fields: Vec<TextCustomField>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -37247,6 +38224,10 @@ pub struct CustomFieldsEnvelope {
         rename = "textCustomFields"
     )]
     pub text_custom_fields: Vec<TextCustomField>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -37262,6 +38243,10 @@ pub struct CustomSettingsInformation {
         rename = "customSettings"
     )]
     pub custom_settings: Vec<NameValue>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A tab that allows the recipient to enter a date. Date tabs
@@ -39203,6 +40188,10 @@ pub struct Date {
         rename = "yPositionMetadata"
     )]
     pub y_position_metadata: Option<PropertyMetadata>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A tab that displays the date that the recipient signed the
@@ -40199,6 +41188,10 @@ pub struct DateSigned {
         rename = "yPositionMetadata"
     )]
     pub y_position_metadata: Option<PropertyMetadata>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Specifies the area in which a date stamp is placed. This parameter uses pixel positioning to draw a rectangle at the center of the stamp area. The stamp is superimposed on top of this central area.
@@ -40279,6 +41272,10 @@ pub struct DateStampProperties {
         rename = "dateAreaY"
     )]
     pub date_area_y: Option<chrono::NaiveDate>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A tab that allows the recipient the option of declining an
@@ -41365,6 +42362,10 @@ pub struct Decline {
         rename = "yPositionMetadata"
     )]
     pub y_position_metadata: Option<PropertyMetadata>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -41400,6 +42401,10 @@ pub struct DiagnosticsSettingsInformation {
         rename = "apiRequestLogging"
     )]
     pub api_request_logging: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Contains information about a bank that processes a customer's direct debit payments.
@@ -41523,6 +42528,10 @@ pub struct DirectDebitProcessorInformation {
         rename = "lastName"
     )]
     pub last_name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -41575,6 +42584,10 @@ pub struct DisplayApplianceAccount {
         rename = "tagHasSigBlock"
     )]
     pub tag_has_sig_block: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Complex type containing:
@@ -41626,6 +42639,10 @@ pub struct DobInformationInput {
         rename = "receiveInResponse"
     )]
     pub receive_in_response: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A document object.
@@ -41868,6 +42885,10 @@ pub struct Document {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub uri: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -41883,6 +42904,10 @@ pub struct DocumentFieldsInformation {
         rename = "documentFields"
     )]
     pub document_fields: Vec<NameValue>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -41987,6 +43012,10 @@ pub struct DocumentHtmlCollapsibleDisplaySettings {
         rename = "outerLabelAndArrowStyle"
     )]
     pub outer_label_and_arrow_style: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Holds the properties that define how to generate the responsive-formatted HTML for the document.
@@ -42101,6 +43130,10 @@ pub struct DocumentHtmlDefinition {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub source: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -42135,6 +43168,10 @@ pub struct DocumentHtmlDefinitionOriginal {
         rename = "htmlDefinition"
     )]
     pub html_definition: Option<DocumentHtmlDefinition>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -42150,6 +43187,10 @@ pub struct DocumentHtmlDefinitionOriginals {
         rename = "htmlDefinitions"
     )]
     pub html_definitions: Vec<DocumentHtmlDefinitionOriginal>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -42165,6 +43206,10 @@ pub struct DocumentHtmlDefinitions {
         rename = "htmlDefinitions"
     )]
     pub html_definitions: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -42226,6 +43271,10 @@ pub struct DocumentHtmlDisplayAnchor {
         rename = "startAnchor"
     )]
     pub start_anchor: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -42347,6 +43396,10 @@ pub struct DocumentHtmlDisplaySettings {
         rename = "tableStyle"
     )]
     pub table_style: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -42401,6 +43454,10 @@ pub struct DocumentTemplate {
         rename = "templateId"
     )]
     pub template_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -42416,6 +43473,10 @@ pub struct DocumentTemplateList {
         rename = "documentTemplates"
     )]
     pub document_templates: Vec<DocumentTemplate>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// This object configures a recipient's read/write access to a document.
@@ -42468,6 +43529,10 @@ pub struct DocumentVisibility {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub visible: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A list of `documentVisibility` objects that specify whether documents are visible to recipients.
@@ -42483,6 +43548,10 @@ pub struct DocumentVisibilityList {
         rename = "documentVisibility"
     )]
     pub document_visibility: Vec<DocumentVisibility>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -42507,6 +43576,10 @@ pub struct DowngradRequestBillingInfoResponse {
         rename = "paymentMethod"
     )]
     pub payment_method: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -42531,6 +43604,10 @@ pub struct DowngradeBillingPlanInformation {
         rename = "planInformation"
     )]
     pub plan_information: Option<PlanInformation>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -42605,6 +43682,10 @@ pub struct DowngradePlanUpdateResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub message: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -43334,6 +44415,10 @@ pub struct Draw {
         rename = "yPositionMetadata"
     )]
     pub y_position_metadata: Option<PropertyMetadata>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// This object contains information used to configure [eNote](https://www.docusign.com/products/enote) functionality. To use eNote, the Allow eNote for eOriginal account plan item must be on, and the Connect configuration for eOriginal must be set correctly.
@@ -43397,6 +44482,10 @@ pub struct ENoteConfiguration {
         rename = "userName"
     )]
     pub user_name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A complex type defining the management and access rights of a recipient assigned as an editor on the envelope. Editors have the same management and access rights for the envelope as the sender. They can make changes to the envelope as if they were using the Correct feature. This recipient can add name and email information, add or change the routing order and set authentication options for the remaining recipients. Additionally, this recipient can edit signature/initial tabs and text tabs for the remaining recipients.
@@ -44057,6 +45146,10 @@ pub struct Editor {
         rename = "userId"
     )]
     pub user_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A tab that allows the recipient to enter an email address.
@@ -46110,6 +47203,10 @@ pub struct Email {
         rename = "yPositionMetadata"
     )]
     pub y_position_metadata: Option<PropertyMetadata>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A tab that displays the recipient's email as entered in the
@@ -47106,6 +48203,10 @@ pub struct EmailAddress {
         rename = "yPositionMetadata"
     )]
     pub y_position_metadata: Option<PropertyMetadata>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A complex element that allows  the sender to override some envelope email setting information. This can be used to override the Reply To email address and name associated with the envelope and to override the BCC email addresses to which an envelope is sent.
@@ -47189,6 +48290,10 @@ pub struct EmailSettings {
         rename = "replyEmailNameOverride"
     )]
     pub reply_email_name_override: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -47920,6 +49025,10 @@ pub struct Envelope {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub workflow: Option<Workflow>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -47982,6 +49091,10 @@ pub struct EnvelopeAttachment {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -47996,6 +49109,10 @@ pub struct EnvelopeAttachmentsRequest {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub attachments: Vec<Attachment>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -48010,6 +49127,10 @@ pub struct EnvelopeAttachmentsResult {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub attachments: Vec<EnvelopeAttachment>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -48025,6 +49146,10 @@ pub struct EnvelopeAuditEvent {
         rename = "eventFields"
     )]
     pub event_fields: Vec<NameValue>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -48040,6 +49165,10 @@ pub struct EnvelopeAuditEventResponse {
         rename = "auditEvents"
     )]
     pub audit_events: Vec<EnvelopeAuditEvent>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Envelope object definition.
@@ -48856,6 +49985,10 @@ pub struct EnvelopeDefinition {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub workflow: Option<Workflow>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// This object contains details about the envelope document.
@@ -49100,6 +50233,10 @@ pub struct EnvelopeDocument {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub uri: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -49125,6 +50262,10 @@ pub struct EnvelopeDocumentsResult {
         rename = "envelopeId"
     )]
     pub envelope_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// For which envelope events should your webhook be called?
@@ -49150,6 +50291,10 @@ pub struct EnvelopeEvent {
         rename = "includeDocuments"
     )]
     pub include_documents: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -49224,6 +50369,10 @@ pub struct EnvelopeFormDataType {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub status: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A tab that displays the envelope ID. Recipients cannot enter
@@ -50198,6 +51347,10 @@ pub struct EnvelopeId {
         rename = "yPositionMetadata"
     )]
     pub y_position_metadata: Option<PropertyMetadata>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Lists of envelope and transaction IDs to use in the results.
@@ -50223,6 +51376,10 @@ pub struct EnvelopeIdsRequest {
         rename = "transactionIds"
     )]
     pub transaction_ids: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -50258,6 +51415,10 @@ pub struct EnvelopeMetadata {
         rename = "enableSignWithNotary"
     )]
     pub enable_sign_with_notary: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A complex element that specifies the notification settings for the envelope.
@@ -50283,6 +51444,10 @@ pub struct EnvelopeNotificationRequest {
         rename = "useAccountDefaults"
     )]
     pub use_account_defaults: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Contains information about the current envelope purge configuration for an account, which enables account administrators to purge documents from completed and voided envelopes after a set number of days (`retentionDays`).
@@ -50328,6 +51493,10 @@ pub struct EnvelopePurgeConfiguration {
         rename = "retentionDays"
     )]
     pub retention_days: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///  This object describes an envelope.
@@ -50389,6 +51558,10 @@ pub struct EnvelopeSummary {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub uri: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -51327,6 +52500,10 @@ pub struct EnvelopeTemplate {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub workflow: Option<Workflow>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Information about templates.
@@ -51411,6 +52588,10 @@ pub struct EnvelopeTemplateResults {
         rename = "totalSetSize"
     )]
     pub total_set_size: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -51454,6 +52635,10 @@ pub struct EnvelopeTransactionStatus {
         rename = "transactionId"
     )]
     pub transaction_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// This object contains details about an envelope transfer rule.
@@ -51537,6 +52722,10 @@ pub struct EnvelopeTransferRule {
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "toUser")]
     pub to_user: Option<UserInformation>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -51612,6 +52801,10 @@ pub struct EnvelopeTransferRuleInformation {
         rename = "totalSetSize"
     )]
     pub total_set_size: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// This object contains details about the envelope transfer rule that you want to create.
@@ -51705,6 +52898,10 @@ pub struct EnvelopeTransferRuleRequest {
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "toUser")]
     pub to_user: Option<UserInformation>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -51796,6 +52993,10 @@ pub struct EnvelopeUpdateSummary {
         rename = "textCustomFieldUpdateResults"
     )]
     pub text_custom_field_update_results: Vec<TextCustomField>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Result set for the Envelopes: listStatusChanges method
@@ -51889,6 +53090,10 @@ pub struct EnvelopesInformation {
         rename = "totalSetSize"
     )]
     pub total_set_size: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// This object describes errors that occur. It is only valid for responses and ignored in requests.
@@ -51913,6 +53118,10 @@ pub struct ErrorDetails {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub message: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Use this object to configure a [DocuSign Connect webhook](https://developers.docusign.com/platform/webhooks/connect/).
@@ -52082,6 +53291,10 @@ pub struct EventNotification {
         rename = "useSoapInterface"
     )]
     pub use_soap_interface: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Information about the result of an event.
@@ -52126,6 +53339,10 @@ pub struct EventResult {
         rename = "vendorFailureStatusCode"
     )]
     pub vendor_failure_status_code: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A complex element that specifies the expiration settings for the envelope.
@@ -52161,6 +53378,10 @@ pub struct Expirations {
         rename = "expireWarn"
     )]
     pub expire_warn: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///  Claims about the user from an external provider. This object is reserved for DocuSign internal use.
@@ -52204,6 +53425,10 @@ pub struct ExternalClaim {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub value: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -52238,6 +53463,10 @@ pub struct ExternalDocServiceErrorDetails {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub message: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A complex object specifying the external document sources.
@@ -52338,6 +53567,10 @@ pub struct ExternalDocumentSources {
         rename = "salesforceMetadata"
     )]
     pub salesforce_metadata: Option<SettingsMetadata>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// This object contains information about a file or folder in cloud storage.
@@ -52416,6 +53649,10 @@ pub struct ExternalFile {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub uri: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -52517,6 +53754,10 @@ pub struct ExternalFolder {
         rename = "totalSetSize"
     )]
     pub total_set_size: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -52551,6 +53792,10 @@ pub struct FavoriteTemplatesContentItem {
         rename = "templateId"
     )]
     pub template_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -52585,6 +53830,10 @@ pub struct FavoriteTemplatesInfo {
         rename = "templatesUpdatedCount"
     )]
     pub templates_updated_count: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -52609,6 +53858,10 @@ pub struct FeatureAvailableMetadata {
         rename = "featureName"
     )]
     pub feature_name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// This object provides details about a feature set, or add-on product that is associated with an account. It is reserved for DocuSign internal use only.
@@ -52703,6 +53956,10 @@ pub struct FeatureSet {
         rename = "seatFee"
     )]
     pub seat_fee: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -52728,6 +53985,10 @@ pub struct FileType {
         rename = "mimeType"
     )]
     pub mime_type: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -52743,6 +54004,10 @@ pub struct FileTypeList {
         rename = "fileTypes"
     )]
     pub file_types: Vec<FileType>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Use this object to create a filtered view of the items in a folder.
@@ -52855,6 +54120,10 @@ pub struct Filter {
         rename = "toDateTime"
     )]
     pub to_date_time: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A tab that displays the recipient's first name. This tab
@@ -54027,6 +55296,10 @@ pub struct FirstName {
         rename = "yPositionMetadata"
     )]
     pub y_position_metadata: Option<PropertyMetadata>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// This object contains details about a folder.
@@ -54168,6 +55441,10 @@ pub struct Folder {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub uri: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Results from a folder item request.
@@ -54243,6 +55520,10 @@ pub struct FolderItemResponse {
         rename = "totalRows"
     )]
     pub total_rows: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Information about folder item results.
@@ -54441,6 +55722,10 @@ pub struct FolderItemV2 {
         rename = "templateUri"
     )]
     pub template_uri: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -54524,6 +55809,10 @@ pub struct FolderItemsResponse {
         rename = "totalSetSize"
     )]
     pub total_set_size: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -54625,6 +55914,10 @@ pub struct FolderSharedItem {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub user: Option<UserInfo>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Information for a folder request.
@@ -54659,6 +55952,10 @@ pub struct FoldersRequest {
         rename = "fromFolderId"
     )]
     pub from_folder_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -54742,6 +56039,10 @@ pub struct FoldersResponse {
         rename = "totalSetSize"
     )]
     pub total_set_size: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A complex element that has up to four Question/Answer pairs for forgotten password information.
@@ -54827,6 +56128,10 @@ pub struct ForgottenPasswordInformation {
         rename = "forgottenPasswordQuestion4"
     )]
     pub forgotten_password_question_4: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -54879,6 +56184,10 @@ pub struct FormDataItem {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub value: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The value of a formula tab is calculated
@@ -58637,6 +59946,10 @@ pub struct FormulaTab {
         rename = "yPositionMetadata"
     )]
     pub y_position_metadata: Option<PropertyMetadata>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A tab that displays the recipient's full name.
@@ -59545,6 +60858,10 @@ pub struct FullName {
         rename = "yPositionMetadata"
     )]
     pub y_position_metadata: Option<PropertyMetadata>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -59580,6 +60897,10 @@ pub struct GraphicsContext {
         rename = "lineWeight"
     )]
     pub line_weight: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// This object contains information about a group.
@@ -59653,6 +60974,10 @@ pub struct Group {
         rename = "usersCount"
     )]
     pub users_count: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -59727,6 +61052,10 @@ pub struct GroupInformation {
         rename = "totalSetSize"
     )]
     pub total_set_size: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A complex object specifying ID check configuration.
@@ -59761,6 +61090,10 @@ pub struct IdCheckConfiguration {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A complex element that contains input information related to a recipient ID check.
@@ -59802,6 +61135,10 @@ pub struct IdCheckInformationInput {
         rename = "ssn9InformationInput"
     )]
     pub ssn_9_information_input: Option<Ssn9InformationInput>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -59817,6 +61154,10 @@ pub struct IdCheckSecurityStep {
         rename = "authType"
     )]
     pub auth_type: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Contains information about an in-person recipient. This is a DocuSign user,
@@ -61431,6 +62772,10 @@ pub struct InPersonSigner {
         rename = "userId"
     )]
     pub user_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A tab that allows the recipient to initial the document. May
@@ -62305,6 +63650,10 @@ pub struct InitialHere {
         rename = "yPositionMetadata"
     )]
     pub y_position_metadata: Option<PropertyMetadata>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -62347,6 +63696,10 @@ pub struct InlineTemplate {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub sequence: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -62431,6 +63784,10 @@ pub struct IntegratedUserInfoList {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub users: Vec<UserInfo>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Contains information about an intermediary recipient. An intermediary is a recipient who can, but is not required to, add name and email information for recipients at the same or subsequent level in the routing order, unless subsequent agents, editors or intermediaries are added.
@@ -63101,6 +64458,10 @@ pub struct Intermediary {
         rename = "userId"
     )]
     pub user_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Describes the jurisdiction of a notary.
@@ -63214,6 +64575,10 @@ pub struct Jurisdiction {
         rename = "stateNameInSeal"
     )]
     pub state_name_in_seal: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A tab that displays the recipient's last name. This tab
@@ -64386,6 +65751,10 @@ pub struct LastName {
         rename = "yPositionMetadata"
     )]
     pub y_position_metadata: Option<PropertyMetadata>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// This tab offers a list of options to choose from.
@@ -65833,6 +67202,10 @@ pub struct List {
         rename = "yPositionMetadata"
     )]
     pub y_position_metadata: Option<PropertyMetadata>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// This object represents a list custom field from which envelope creators and senders can select custom data.
@@ -65913,6 +67286,10 @@ pub struct ListCustomField {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub value: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// One of the selectable items
@@ -65986,6 +67363,10 @@ pub struct ListItem {
         rename = "valueMetadata"
     )]
     pub value_metadata: Option<PropertyMetadata>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -66408,6 +67789,10 @@ pub struct LocalePolicy {
         rename = "timeZoneMetadata"
     )]
     pub time_zone_metadata: Option<SettingsMetadata>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -66543,6 +67928,10 @@ pub struct LocalePolicyTab {
         rename = "timeZone"
     )]
     pub time_zone: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -66626,6 +68015,10 @@ pub struct LockInformation {
         rename = "useScratchPad"
     )]
     pub use_scratch_pad: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// This request object contains information about the lock that you want to create or update.
@@ -66681,6 +68074,10 @@ pub struct LockRequest {
         rename = "useScratchPad"
     )]
     pub use_scratch_pad: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -66794,6 +68191,10 @@ pub struct LoginAccount {
         rename = "userName"
     )]
     pub user_name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -66819,6 +68220,10 @@ pub struct LoginInformation {
         rename = "loginAccounts"
     )]
     pub login_accounts: Vec<LoginAccount>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -66872,6 +68277,10 @@ pub struct MatchBox {
         rename = "yPosition"
     )]
     pub y_position: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Information about items shared among groups.
@@ -66900,6 +68309,10 @@ pub struct MemberGroupSharedItem {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub shared: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Information about shared items.
@@ -66946,6 +68359,10 @@ pub struct MemberSharedItems {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub user: Option<UserInfo>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Contains information for transfering values between Salesforce data fields and DocuSign Tabs.
@@ -67063,6 +68480,10 @@ pub struct MergeField {
         rename = "writeBackMetadata"
     )]
     pub write_back_metadata: Option<PropertyMetadata>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -67096,6 +68517,10 @@ pub struct MobileNotifierConfiguration {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub platform: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -67111,6 +68536,10 @@ pub struct MobileNotifierConfigurationInformation {
         rename = "mobileNotifierConfigurations"
     )]
     pub mobile_notifier_configurations: Vec<MobileNotifierConfiguration>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Describes information
@@ -67153,6 +68582,10 @@ pub struct Money {
         rename = "displayAmount"
     )]
     pub display_amount: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A name-value pair that describes an item and provides a value for the item.
@@ -67195,6 +68628,10 @@ pub struct NameValue {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub value: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -67331,6 +68768,10 @@ pub struct NewAccountDefinition {
         rename = "socialAccountInformation"
     )]
     pub social_account_information: Option<SocialAccountInformation>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -67405,6 +68846,10 @@ pub struct NewAccountSummary {
         rename = "userId"
     )]
     pub user_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Object representing a new user.
@@ -67517,6 +68962,10 @@ pub struct NewUser {
         rename = "userStatus"
     )]
     pub user_status: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -67532,6 +68981,10 @@ pub struct NewUsersDefinition {
         rename = "newUsers"
     )]
     pub new_users: Vec<UserInformation>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Object representing a summary of data for new users.
@@ -67547,6 +69000,10 @@ pub struct NewUsersSummary {
         rename = "newUsers"
     )]
     pub new_users: Vec<NewUser>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A tab that alerts notary recipients that
@@ -68375,6 +69832,10 @@ pub struct Notarize {
         rename = "yPositionMetadata"
     )]
     pub y_position_metadata: Option<PropertyMetadata>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -68413,6 +69874,10 @@ pub struct NotaryData {
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "userInfo")]
     pub user_info: Option<UserInformation>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// This object is used only when `inPersonSigningType` in the `inPersonSigner` object is `notary`.
@@ -69597,6 +71062,10 @@ pub struct NotaryHost {
         rename = "userId"
     )]
     pub user_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -69656,6 +71125,10 @@ pub struct NotaryJournal {
         rename = "signerName"
     )]
     pub signer_name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -69689,6 +71162,10 @@ pub struct NotaryJournalCredibleWitness {
         rename = "signatureImage"
     )]
     pub signature_image: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -69764,6 +71241,10 @@ pub struct NotaryJournalList {
         rename = "totalSetSize"
     )]
     pub total_set_size: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -69808,6 +71289,10 @@ pub struct NotaryJournalMetaData {
         rename = "signerIdType"
     )]
     pub signer_id_type: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A notary jurisdiction.
@@ -69876,6 +71361,10 @@ pub struct NotaryJurisdictionData {
         rename = "sealType"
     )]
     pub seal_type: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A paged list of jurisdictions.
@@ -69951,6 +71440,10 @@ pub struct NotaryJurisdictionList {
         rename = "totalSetSize"
     )]
     pub total_set_size: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -70847,6 +72340,10 @@ pub struct NotaryRecipient {
         rename = "userId"
     )]
     pub user_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Describes a single notary jurisdiction.
@@ -70866,6 +72363,10 @@ pub struct NotaryResult {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub notary: Option<NotaryData>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -71568,6 +73069,10 @@ pub struct NotarySeal {
         rename = "yPositionMetadata"
     )]
     pub y_position_metadata: Option<PropertyMetadata>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A tab that displays additional information, in the form of a
@@ -72586,6 +74091,10 @@ pub struct Note {
         rename = "yPositionMetadata"
     )]
     pub y_position_metadata: Option<PropertyMetadata>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A complex element that specifies the notification settings for the envelope.
@@ -72611,6 +74120,10 @@ pub struct Notification {
         rename = "useAccountDefaults"
     )]
     pub use_account_defaults: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Contains details about the default notification settings for the envelope notifications that senders and signers receive.
@@ -72634,6 +74147,10 @@ pub struct NotificationDefaultSettings {
         rename = "signerEmailNotifications"
     )]
     pub signer_email_notifications: Option<SignerEmailNotifications>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -72657,6 +74174,10 @@ pub struct NotificationDefaultsData {
         rename = "emailNotifications"
     )]
     pub email_notifications: Option<NotificationDefaultSettings>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A tab that allows the recipient to enter numbers and decimal
@@ -73948,6 +75469,10 @@ pub struct Number {
         rename = "yPositionMetadata"
     )]
     pub y_position_metadata: Option<PropertyMetadata>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -74007,6 +75532,10 @@ pub struct OauthAccess {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub token_type: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Description of a page of a document.
@@ -74083,6 +75612,10 @@ pub struct Page {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub width: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -74157,6 +75690,10 @@ pub struct PageImages {
         rename = "totalSetSize"
     )]
     pub total_set_size: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -74180,6 +75717,10 @@ pub struct PageRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub rotate: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -74214,6 +75755,10 @@ pub struct PathExtendedElement {
         rename = "typeName"
     )]
     pub type_name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -74265,6 +75810,10 @@ pub struct PayPalLegacySettings {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub vendor: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// When a formula tab
@@ -74592,6 +76141,10 @@ pub struct PaymentDetails {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub total: Option<Money>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// This object contains details about a payment gateway account.
@@ -74730,6 +76283,10 @@ pub struct PaymentGatewayAccount {
         rename = "zeroDecimalCurrencies"
     )]
     pub zero_decimal_currencies: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -74775,6 +76332,10 @@ pub struct PaymentGatewayAccountSetting {
         rename = "merchantId"
     )]
     pub merchant_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Holds information about connected payment accounts.
@@ -74790,6 +76351,10 @@ pub struct PaymentGatewayAccountsInfo {
         rename = "paymentGatewayAccounts"
     )]
     pub payment_gateway_accounts: Vec<PaymentGatewayAccount>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A line item describes details
@@ -74853,6 +76418,10 @@ pub struct PaymentLineItem {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// This object contains information about a payment method that the gateway accepts and the payment options that are compatible with it.
@@ -74878,6 +76447,10 @@ pub struct PaymentMethodWithOptions {
         rename = "type"
     )]
     pub type_: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -74907,6 +76480,10 @@ pub struct PaymentProcessorInformation {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub email: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -74922,6 +76499,10 @@ pub struct PaymentSignerValues {
         rename = "paymentOption"
     )]
     pub payment_option: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// This object defines the account permissions for a profile that you can apply to a group of users.
@@ -74991,6 +76572,10 @@ pub struct PermissionProfile {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub users: Vec<UserInformation>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Contains details about the permission profiles associated with an account.
@@ -75006,6 +76591,10 @@ pub struct PermissionProfileInformation {
         rename = "permissionProfiles"
     )]
     pub permission_profiles: Vec<PermissionProfile>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -75938,6 +77527,10 @@ pub struct PhoneNumber {
         rename = "yPositionMetadata"
     )]
     pub y_position_metadata: Option<PropertyMetadata>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// An object used to identify the features and attributes of the account being created.
@@ -76003,6 +77596,10 @@ pub struct PlanInformation {
         rename = "recipientDomains"
     )]
     pub recipient_domains: Vec<RecipientDomain>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -76048,6 +77645,10 @@ pub struct PolyLine {
         rename = "y2"
     )]
     pub y_2: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// This tab enables users to strike through the text of a document. The tab is implemented as a line represented as a pair of x and y coordinates.
@@ -76778,6 +78379,10 @@ pub struct PolyLineOverlay {
         rename = "yPositionMetadata"
     )]
     pub y_position_metadata: Option<PropertyMetadata>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Contains details about a PowerForm.
@@ -77027,6 +78632,10 @@ pub struct PowerForm {
         rename = "usesRemaining"
     )]
     pub uses_remaining: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -77051,6 +78660,10 @@ pub struct PowerFormDataEnvelope {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub recipients: Vec<PowerFormDataRecipient>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -77094,6 +78707,10 @@ pub struct PowerFormDataRecipient {
         rename = "recipientId"
     )]
     pub recipient_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// **Note**: For a self-service PowerForm on a website, you can specify the intended recipients generically (for example, use `Member` as the `Name`), and omit personal details such as `email`.
@@ -77227,6 +78844,10 @@ pub struct PowerFormRecipient {
         rename = "userNameLocked"
     )]
     pub user_name_locked: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// This object includes information about the users who have sent PowerForms.
@@ -77302,6 +78923,10 @@ pub struct PowerFormSendersResponse {
         rename = "totalSetSize"
     )]
     pub total_set_size: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -77316,6 +78941,10 @@ pub struct PowerFormsFormDataResponse {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub envelopes: Vec<PowerFormDataEnvelope>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -77331,6 +78960,10 @@ pub struct PowerFormsRequest {
         rename = "powerForms"
     )]
     pub power_forms: Vec<PowerForm>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A list of PowerForms.
@@ -77406,6 +79039,10 @@ pub struct PowerFormsResponse {
         rename = "totalSetSize"
     )]
     pub total_set_size: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -77451,6 +79088,10 @@ pub struct PrefillTabs {
         rename = "textTabs"
     )]
     pub text_tabs: Vec<Text>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -77476,6 +79117,10 @@ pub struct ProofServiceResourceToken {
         rename = "resourceToken"
     )]
     pub resource_token: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -77491,6 +79136,10 @@ pub struct ProofServiceViewLink {
         rename = "ViewLink"
     )]
     pub view_link: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Metadata about a property.
@@ -77514,6 +79163,10 @@ pub struct PropertyMetadata {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub rights: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -77538,6 +79191,10 @@ pub struct Province {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -77613,6 +79270,10 @@ pub struct ProvisioningInformation {
         rename = "purchaseOrderOrPromAllowed"
     )]
     pub purchase_order_or_prom_allowed: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -77705,6 +79366,10 @@ pub struct PurchasedEnvelopesInformation {
         rename = "transactionId"
     )]
     pub transaction_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// One of the selectable radio buttons
@@ -78366,6 +80031,10 @@ pub struct Radio {
         rename = "yPositionMetadata"
     )]
     pub y_position_metadata: Option<PropertyMetadata>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// This group tab is used to place radio buttons on a document.
@@ -78793,6 +80462,10 @@ pub struct RadioGroup {
         rename = "tooltipMetadata"
     )]
     pub tooltip_metadata: Option<PropertyMetadata>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Describes an additional notification method.
@@ -78836,6 +80509,10 @@ pub struct RecipientAdditionalNotification {
         rename = "secondaryDeliveryStatus"
     )]
     pub secondary_delivery_status: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -78898,6 +80575,10 @@ pub struct RecipientAttachment {
         rename = "remoteUrl"
     )]
     pub remote_url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -78942,6 +80623,10 @@ pub struct RecipientDomain {
         rename = "recipientDomainId"
     )]
     pub recipient_domain_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -79004,6 +80689,10 @@ pub struct RecipientEmailNotification {
         rename = "supportedLanguageMetadata"
     )]
     pub supported_language_metadata: Option<PropertyMetadata>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -79029,6 +80718,10 @@ pub struct RecipientEvent {
         rename = "recipientEventStatusCode"
     )]
     pub recipient_event_status_code: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -79112,6 +80805,10 @@ pub struct RecipientFormData {
         rename = "recipientId"
     )]
     pub recipient_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Describes a group of recipients.
@@ -79146,6 +80843,10 @@ pub struct RecipientGroup {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub recipients: Vec<RecipientOption>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -79180,6 +80881,10 @@ pub struct RecipientIdentityInputOption {
         rename = "valueType"
     )]
     pub value_type: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -79203,6 +80908,10 @@ pub struct RecipientIdentityPhoneNumber {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub number: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Specifies ID Verification applied on an envelope by workflow ID.
@@ -79240,6 +80949,10 @@ pub struct RecipientIdentityVerification {
         rename = "workflowId"
     )]
     pub workflow_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// This response object contains a list of recipients.
@@ -79275,6 +80988,10 @@ pub struct RecipientNamesResponse {
         rename = "reservedRecipientEmail"
     )]
     pub reserved_recipient_email: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -79328,6 +81045,10 @@ pub struct RecipientOption {
         rename = "signingGroupId"
     )]
     pub signing_group_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A complex type that contains the elements:
@@ -79454,6 +81175,10 @@ pub struct RecipientPhoneAuthentication {
         rename = "validateRecipProvidedNumberMetadata"
     )]
     pub validate_recip_provided_number_metadata: Option<PropertyMetadata>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Describes the recipient phone number.
@@ -79496,6 +81221,10 @@ pub struct RecipientPhoneNumber {
         rename = "numberMetadata"
     )]
     pub number_metadata: Option<PropertyMetadata>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// This request object contains the information necessary to create a recipient preview.
@@ -79601,6 +81330,10 @@ pub struct RecipientPreviewRequest {
         rename = "xFrameOptionsAllowFromUrl"
     )]
     pub x_frame_options_allow_from_url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The proof file of the recipient. [ID Evidence](https://developers.docusign.com/idevidence-api) uses proof files to store the identification data that recipients submit when verifying their ID with [ID Verification](https://developers.docusign.com/docs/esign-rest-api/reference/Accounts/IdentityVerifications)
@@ -79616,6 +81349,10 @@ pub struct RecipientProofFile {
         rename = "isInProofFile"
     )]
     pub is_in_proof_file: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Describes the recipient routing rules.
@@ -79626,6 +81363,10 @@ pub struct RecipientRouting {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub rules: Option<RecipientRules>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -79641,6 +81382,10 @@ pub struct RecipientRules {
         rename = "conditionalRecipients"
     )]
     pub conditional_recipients: Vec<ConditionalRecipientRule>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Contains the element senderProvidedNumbers which is an Array  of phone numbers the recipient can use for SMS text authentication.
@@ -79665,6 +81410,10 @@ pub struct RecipientSmsAuthentication {
         rename = "senderProvidedNumbersMetadata"
     )]
     pub sender_provided_numbers_metadata: Option<PropertyMetadata>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Allows the sender to pre-specify the signature name, signature initials and signature font used in the signature stamp for the recipient.
@@ -79708,6 +81457,10 @@ pub struct RecipientSignatureInformation {
         rename = "signatureName"
     )]
     pub signature_name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// An Electronic or Standards Based Signature (digital signature) provider for the signer to use. [More information](https://developers.docusign.com/esign-rest-api/guides/standards-based-signatures).
@@ -79767,6 +81520,10 @@ pub struct RecipientSignatureProvider {
         rename = "signatureProviderOptions"
     )]
     pub signature_provider_options: Option<RecipientSignatureProviderOptions>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Option settings for the signature provider. Different providers require or use different options. [The current provider list and the options they require.](https://developers.docusign.com/esign-rest-api/guides/standards-based-signatures)
@@ -79847,6 +81604,10 @@ pub struct RecipientSignatureProviderOptions {
         rename = "smsMetadata"
     )]
     pub sms_metadata: Option<PropertyMetadata>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The recipient details that are returned after you update the recipient.
@@ -79895,6 +81656,10 @@ pub struct RecipientUpdateResponse {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub tabs: Option<EnvelopeRecipientTabs>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The request body for the EnvelopeViews::createRecipient and EnvelopeViews::createSharedRecipient methods.
@@ -80059,6 +81824,10 @@ pub struct RecipientViewRequest {
         rename = "xFrameOptionsAllowFromUrl"
     )]
     pub x_frame_options_allow_from_url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Specifies the envelope recipients.
@@ -80186,6 +81955,10 @@ pub struct Recipients {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub witnesses: Vec<Witness>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// This is the response that the API returns after you update recipients.
@@ -80201,6 +81974,10 @@ pub struct RecipientsUpdateSummary {
         rename = "recipientUpdateResults"
     )]
     pub recipient_update_results: Vec<RecipientUpdateResponse>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A complex type that contains the following information for entering referral and discount information. The following items are included in the referral information (all string content): enableSupport, includedSeats, saleDiscountPercent, saleDiscountAmount, saleDiscountFixedAmount, saleDiscountPeriods, saleDiscountSeatPriceOverride, planStartMonth, referralCode, referrerName, advertisementId, publisherId, shopperId, promoCode, groupMemberId, idType, and industry
@@ -80423,6 +82200,10 @@ pub struct ReferralInformation {
         rename = "shopperId"
     )]
     pub shopper_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A complex element that specifies reminder settings for the envelope.
@@ -80458,6 +82239,10 @@ pub struct Reminders {
         rename = "reminderFrequency"
     )]
     pub reminder_frequency: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -80720,6 +82505,10 @@ pub struct ReportInProductCsvRunRequest {
         rename = "startPosition"
     )]
     pub start_position: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -80763,6 +82552,10 @@ pub struct ReportInProductField {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub selected: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -81056,6 +82849,10 @@ pub struct ReportInProductGet {
         rename = "sortFieldName"
     )]
     pub sort_field_name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -81070,6 +82867,10 @@ pub struct ReportInProductList {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub reports: Vec<ReportInProductListItem>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -81215,6 +83016,10 @@ pub struct ReportInProductListItem {
         rename = "scheduleRenewDurationDays"
     )]
     pub schedule_renew_duration_days: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -81439,6 +83244,10 @@ pub struct ReportInProductRunRequest {
         rename = "startPosition"
     )]
     pub start_position: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -81513,6 +83322,10 @@ pub struct ReportInProductRunResponse {
         rename = "totalSetSize"
     )]
     pub total_set_size: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -81533,6 +83346,10 @@ pub struct ReportInProductRunResponseRow {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub fields: Option<ReportInProductRunResponseRowFields>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -82750,6 +84567,10 @@ pub struct ReportInProductRunResponseRowFields {
         rename = "voidedTs"
     )]
     pub voided_ts: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -82765,6 +84586,10 @@ pub struct ReportInProductSaveResponse {
         rename = "reportCustomizedId"
     )]
     pub report_customized_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -82788,6 +84613,10 @@ pub struct ReportInProductSentByDetails {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub users: Vec<UserInfo>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -82802,6 +84631,10 @@ pub struct ResourceInformation {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub resources: Vec<NameValue>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The request body for the EnvelopeViews::createSender method.
@@ -82817,6 +84650,10 @@ pub struct ReturnUrlRequest {
         rename = "returnUrl"
     )]
     pub return_url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Set of information related to the electronic seal used by the Trust Service Provider (TSP).
@@ -82841,6 +84678,10 @@ pub struct Seal {
         rename = "sealIdentifier"
     )]
     pub seal_identifier: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -82866,6 +84707,10 @@ pub struct SealIdentifier {
         rename = "sealName"
     )]
     pub seal_name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Specifies one or more electronic seals to apply on documents. An electronic seal recipient is a legal entity rather than an actual person. Electronic Seals can be used by organizations and governments to show evidence of origin and integrity of documents. Even though electronic seals can be represented by a tab in a document, they do not require user interaction and apply automatically in the order specified by the sender. The sender is therefore the person authorizing usage of the electronic seal in the flow.
@@ -84719,6 +86564,10 @@ pub struct SealSign {
         rename = "userId"
     )]
     pub user_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// This object contains information about a seat discount.
@@ -84754,6 +86603,10 @@ pub struct SeatDiscount {
         rename = "endSeatCount"
     )]
     pub end_seat_count: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Contains the settings for the email notifications that senders receive about the envelopes that they send.
@@ -84869,6 +86722,10 @@ pub struct SenderEmailNotifications {
         rename = "withdrawnConsent"
     )]
     pub withdrawn_consent: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -84893,6 +86750,10 @@ pub struct ServerTemplate {
         rename = "templateId"
     )]
     pub template_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -84958,6 +86819,10 @@ pub struct ServiceInformation {
         rename = "serviceVersions"
     )]
     pub service_versions: Vec<ServiceVersion>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -84982,6 +86847,10 @@ pub struct ServiceVersion {
         rename = "versionUrl"
     )]
     pub version_url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Metadata that indicates whether a property is editable and describes setting-specific options.
@@ -85045,6 +86914,10 @@ pub struct SettingsMetadata {
         rename = "uiType"
     )]
     pub ui_type: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Information about the shared item.
@@ -85073,6 +86946,10 @@ pub struct SharedItem {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub user: Option<UserInfo>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A tab that allows the recipient to sign a document. May be
@@ -85989,6 +87866,10 @@ pub struct SignHere {
         rename = "yPositionMetadata"
     )]
     pub y_position_metadata: Option<PropertyMetadata>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -86023,6 +87904,10 @@ pub struct SignatureGroup {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub rights: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -86047,6 +87932,10 @@ pub struct SignatureGroupDef {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub rights: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -86071,6 +87960,10 @@ pub struct SignatureProperties {
         rename = "subFilter"
     )]
     pub sub_filter: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Contains additional information that a specific signature provider requires.
@@ -86096,6 +87989,10 @@ pub struct SignatureProviderRequiredOption {
         rename = "signerType"
     )]
     pub signer_type: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// This object contains information about the type of signature.
@@ -86121,6 +88018,10 @@ pub struct SignatureType {
         rename = "type"
     )]
     pub type_: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -86165,6 +88066,10 @@ pub struct SignatureUser {
         rename = "userName"
     )]
     pub user_name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -86199,6 +88104,10 @@ pub struct SignatureUserDef {
         rename = "userId"
     )]
     pub user_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A complex type containing information about a signer recipient. A signer is a recipient who must take action on a document, such as sign, initial, date, or add data to form fields on a document.
@@ -87056,6 +88965,10 @@ pub struct Signer {
         rename = "userId"
     )]
     pub user_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A tab that allows the recipient to attach supporting
@@ -87930,6 +89843,10 @@ pub struct SignerAttachment {
         rename = "yPositionMetadata"
     )]
     pub y_position_metadata: Option<PropertyMetadata>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// An array of email notifications that specifies the email the user receives when they are a sender. When the specific email notification is set to true, the user receives those types of email notifications from DocuSign. The user inherits the default account sender email notification settings when the user is created.
@@ -88095,6 +90012,10 @@ pub struct SignerEmailNotifications {
         rename = "whenSigningGroupMember"
     )]
     pub when_signing_group_member: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Contains details about a signing group. Signing groups enable you to send an envelope to a predefined group of recipients and have any one member of the group sign your documents. When you send an envelope to a signing group, anyone in the group can open it and sign it with their own signature.
@@ -88196,6 +90117,10 @@ pub struct SigningGroup {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub users: Vec<SigningGroupUser>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -88210,6 +90135,10 @@ pub struct SigningGroupInformation {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub groups: Vec<SigningGroup>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -88243,6 +90172,10 @@ pub struct SigningGroupUser {
         rename = "userName"
     )]
     pub user_name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -88257,6 +90190,10 @@ pub struct SigningGroupUsersData {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub users: Vec<SigningGroupUser>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -88280,6 +90217,10 @@ pub struct SmartContractInformation {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub uri: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -89065,6 +91006,10 @@ pub struct SmartSection {
         rename = "yPositionMetadata"
     )]
     pub y_position_metadata: Option<PropertyMetadata>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -89100,6 +91045,10 @@ pub struct SmartSectionAnchorPosition {
         rename = "yPosition"
     )]
     pub y_position: f64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -89204,6 +91153,10 @@ pub struct SmartSectionCollapsibleDisplaySettings {
         rename = "outerLabelAndArrowStyle"
     )]
     pub outer_label_and_arrow_style: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// These properties define how a Smart Section displays. A Smart Section is a type of display section.
@@ -89325,6 +91278,10 @@ pub struct SmartSectionDisplaySettings {
         rename = "tableStyle"
     )]
     pub table_style: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -89377,6 +91334,10 @@ pub struct SocialAccountInformation {
         rename = "userName"
     )]
     pub user_name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -89391,6 +91352,10 @@ pub struct SocialAuthentication {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub authentication: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A one-line field that allows the recipient to enter a Social
@@ -90884,6 +92849,10 @@ pub struct Ssn {
         rename = "yPositionMetadata"
     )]
     pub y_position_metadata: Option<PropertyMetadata>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -90919,6 +92888,10 @@ pub struct Ssn4InformationInput {
         rename = "ssn4"
     )]
     pub ssn_4: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -90944,6 +92917,10 @@ pub struct Ssn9InformationInput {
         rename = "ssn9"
     )]
     pub ssn_9: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -91106,6 +93083,10 @@ pub struct Stamp {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub status: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A list of supported languages.
@@ -91120,6 +93101,10 @@ pub struct SupportedLanguages {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub languages: Vec<NameValue>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -91524,6 +93509,10 @@ pub struct TabAccountSettings {
         rename = "textTabsMetadata"
     )]
     pub text_tabs_metadata: Option<SettingsMetadata>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -92284,6 +94273,10 @@ pub struct TabGroup {
         rename = "yPositionMetadata"
     )]
     pub y_position_metadata: Option<PropertyMetadata>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -92752,6 +94745,10 @@ pub struct TabMetadata {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub width: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -92766,6 +94763,10 @@ pub struct TabMetadataList {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub tabs: Vec<TabMetadata>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Tabs indicate to recipients where they should sign, initial, or enter data on a document. They are represented graphically as symbols on documents at the time of signing. Tabs can also display data to the recipients.
@@ -93160,6 +95161,10 @@ pub struct Tabs {
         rename = "zipTabs"
     )]
     pub zip_tabs: Vec<Zip>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -93185,6 +95190,10 @@ pub struct TemplateCustomFieldsData {
         rename = "textCustomFields"
     )]
     pub text_custom_fields: Vec<TextCustomField>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A list of `documentVisibility` objects that specify whether the documents associated with a template are visible to recipients.
@@ -93200,6 +95209,10 @@ pub struct TemplateDocumentVisibilityList {
         rename = "documentVisibility"
     )]
     pub document_visibility: Vec<DocumentVisibility>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -93225,6 +95238,10 @@ pub struct TemplateDocumentsResult {
         rename = "templateId"
     )]
     pub template_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -93239,6 +95256,10 @@ pub struct TemplateInformation {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub templates: Vec<TemplateSummary>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -93274,6 +95295,10 @@ pub struct TemplateMatch {
         rename = "matchPercentage"
     )]
     pub match_percentage: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -93308,6 +95333,10 @@ pub struct TemplateNotificationRequest {
         rename = "useAccountDefaults"
     )]
     pub use_account_defaults: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -93435,6 +95464,10 @@ pub struct TemplateRecipientsData {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub witnesses: Vec<Witness>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -93562,6 +95595,10 @@ pub struct TemplateRole {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub tabs: Option<EnvelopeRecipientTabs>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Information about shared templates.
@@ -93639,6 +95676,10 @@ pub struct TemplateSharedItem {
         rename = "templateName"
     )]
     pub template_name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Summary of a template request.
@@ -93719,6 +95760,10 @@ pub struct TemplateSummary {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub uri: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -94113,6 +96158,10 @@ pub struct TemplateTabs {
         rename = "zipTabs"
     )]
     pub zip_tabs: Vec<Zip>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -94204,6 +96253,10 @@ pub struct TemplateUpdateSummary {
         rename = "textCustomFieldUpdateResults"
     )]
     pub text_custom_field_update_results: Vec<TextCustomField>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A tab that allows the recipient to enter any type of text.
@@ -95381,6 +97434,10 @@ pub struct Text {
         rename = "yPositionMetadata"
     )]
     pub y_position_metadata: Option<PropertyMetadata>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// This object represents a free text custom field where envelope creators and senders can enter custom data.
@@ -95451,6 +97508,10 @@ pub struct TextCustomField {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub value: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A tab that displays the recipient's title.
@@ -96483,6 +98544,10 @@ pub struct Title {
         rename = "yPositionMetadata"
     )]
     pub y_position_metadata: Option<PropertyMetadata>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A complex element consisting of:
@@ -96553,6 +98618,10 @@ pub struct UsageHistory {
         rename = "signedCount"
     )]
     pub signed_count: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Describes which account management capabilities a user has.
@@ -96720,6 +98789,10 @@ pub struct UserAccountManagementGranularInformation {
         rename = "canViewUsers"
     )]
     pub can_view_users: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -96852,6 +98925,10 @@ pub struct UserInfo {
         rename = "userType"
     )]
     pub user_type: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -96866,6 +98943,10 @@ pub struct UserInfoList {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub users: Vec<UserInfo>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// User information.
@@ -97289,6 +99370,10 @@ pub struct UserInformation {
         rename = "workAddress"
     )]
     pub work_address: Option<AddressInformation>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Contains a list of account users.
@@ -97363,6 +99448,10 @@ pub struct UserInformationList {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub users: Vec<UserInformation>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -97406,6 +99495,10 @@ pub struct UserPasswordInformation {
         rename = "newPassword"
     )]
     pub new_password: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Contains details about the password rules for a user.
@@ -97430,6 +99523,10 @@ pub struct UserPasswordRules {
         rename = "userId"
     )]
     pub user_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -97547,6 +99644,10 @@ pub struct UserProfile {
         rename = "userProfileLastModifiedDate"
     )]
     pub user_profile_last_modified_date: Option<chrono::NaiveDate>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Properties that configure the settings for a user. Some elements of this object have a `metadata` property, which includes the following:
@@ -99043,6 +101144,10 @@ pub struct UserSettingsInformation {
         rename = "vaultingModeMetadata"
     )]
     pub vaulting_mode_metadata: Option<SettingsMetadata>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Information about a shared item.
@@ -99071,6 +101176,10 @@ pub struct UserSharedItem {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub user: Option<UserInfo>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -99373,6 +101482,10 @@ pub struct UserSignature {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub status: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -99517,6 +101630,10 @@ pub struct UserSignatureDefinition {
         rename = "stampSizeMM"
     )]
     pub stamp_size_mm: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -99532,6 +101649,10 @@ pub struct UserSignaturesInformation {
         rename = "userSignatures"
     )]
     pub user_signatures: Vec<UserSignature>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -99557,6 +101678,10 @@ pub struct UserSocialResult {
         rename = "userId"
     )]
     pub user_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -99631,6 +101756,10 @@ pub struct UsersResponse {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub users: Vec<UserInfo>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// This tab is used with the Approve tab to handle supplemental documents.
@@ -100462,6 +102591,10 @@ pub struct View {
         rename = "yPositionMetadata"
     )]
     pub y_position_metadata: Option<PropertyMetadata>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -100476,6 +102609,10 @@ pub struct ViewUrl {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -100567,6 +102704,10 @@ pub struct Watermark {
         rename = "watermarkText"
     )]
     pub watermark_text: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A complex type containing information about a witness recipient. Witnesses are recipients whose signatures affirm that the identified signers have signed the documents in the envelope.
@@ -101453,6 +103594,10 @@ pub struct Witness {
         rename = "witnessForGuid"
     )]
     pub witness_for_guid: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Describes the workflow for an envelope.
@@ -101488,6 +103633,10 @@ pub struct Workflow {
         rename = "workflowSteps"
     )]
     pub workflow_steps: Vec<WorkflowStep>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Describes a single step in a workflow.
@@ -101570,6 +103719,10 @@ pub struct WorkflowStep {
         rename = "workflowStepId"
     )]
     pub workflow_step_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A DocuSign workspace is a collaboration area for sharing files and data.
@@ -101695,6 +103848,10 @@ pub struct Workspace {
         rename = "workspaceUri"
     )]
     pub workspace_uri: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// This object's properties describe the contents of a workspace folder.
@@ -101774,6 +103931,10 @@ pub struct WorkspaceFolderContents {
         rename = "workspaceId"
     )]
     pub workspace_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// This object represents an item in a workspace, which can be either a file or folder.
@@ -102005,6 +104166,10 @@ pub struct WorkspaceItem {
         rename = "userAuthorization"
     )]
     pub user_authorization: Option<WorkspaceUserAuthorization>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// An array of objects that describe the items in a workspace.
@@ -102019,6 +104184,10 @@ pub struct WorkspaceItemList {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub items: Vec<WorkspaceItem>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// This object contains a list of available workspaces.
@@ -102073,6 +104242,10 @@ pub struct WorkspaceList {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub workspaces: Vec<Workspace>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// This object provides information about the settings for the workspace.
@@ -102088,6 +104261,10 @@ pub struct WorkspaceSettings {
         rename = "commentsAllowed"
     )]
     pub comments_allowed: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// This object represents the workspace user. This property is only returned in response to user specific GET call.
@@ -102279,6 +104456,10 @@ pub struct WorkspaceUser {
         rename = "workspaceUserUri"
     )]
     pub workspace_user_uri: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Provides properties that describe user authorization to a workspace.
@@ -102390,6 +104571,10 @@ pub struct WorkspaceUserAuthorization {
         rename = "workspaceUserInformation"
     )]
     pub workspace_user_information: Option<WorkspaceUser>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A tab that allows the recipient to enter a ZIP code. The ZIP
@@ -104024,4 +106209,8 @@ pub struct Zip {
         rename = "yPositionMetadata"
     )]
     pub y_position_metadata: Option<PropertyMetadata>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
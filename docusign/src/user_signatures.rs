@@ -1,6 +1,229 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`UserSignatures::get`], set with a builder.
+pub struct UserSignaturesGetRequest<'a> {
+    client: &'a UserSignatures,
+    account_id: &'a str,
+    user_id: &'a str,
+    stamp_type: Option<&'a str>,
+}
+
+impl<'a> UserSignaturesGetRequest<'a> {
+    /// Sets the `stamp_type` query parameter.
+    pub fn stamp_type(mut self, stamp_type: &'a str) -> Self {
+        self.stamp_type = Some(stamp_type);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::UserSignaturesInformation>> {
+        let Self {
+            client,
+            account_id,
+            user_id,
+            stamp_type,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = stamp_type {
+            query_args.push(("stamp_type".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v2.1/accounts/{}/users/{}/signatures?{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(user_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`UserSignatures::put_signature_user_signatures`], set with a builder.
+pub struct UserSignaturesPutSignatureUserSignaturesRequest<'a> {
+    client: &'a UserSignatures,
+    account_id: &'a str,
+    signature_id: &'a str,
+    user_id: &'a str,
+    close_existing_signature: Option<&'a str>,
+    body: &'a crate::types::UserSignatureDefinition,
+}
+
+impl<'a> UserSignaturesPutSignatureUserSignaturesRequest<'a> {
+    /// Sets the `close_existing_signature` query parameter.
+    pub fn close_existing_signature(mut self, close_existing_signature: &'a str) -> Self {
+        self.close_existing_signature = Some(close_existing_signature);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::UserSignature>> {
+        let Self {
+            client,
+            account_id,
+            signature_id,
+            user_id,
+            close_existing_signature,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = close_existing_signature {
+            query_args.push(("close_existing_signature".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v2.1/accounts/{}/users/{}/signatures/{}?{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(user_id),
+                crate::progenitor_support::encode_path(signature_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .put(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`UserSignatures::get_signature_image`], set with a builder.
+pub struct UserSignaturesGetSignatureImageRequest<'a> {
+    client: &'a UserSignatures,
+    account_id: &'a str,
+    image_type: &'a str,
+    signature_id: &'a str,
+    user_id: &'a str,
+    include_chrome: Option<&'a str>,
+}
+
+impl<'a> UserSignaturesGetSignatureImageRequest<'a> {
+    /// Sets the `include_chrome` query parameter.
+    pub fn include_chrome(mut self, include_chrome: &'a str) -> Self {
+        self.include_chrome = Some(include_chrome);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            account_id,
+            image_type,
+            signature_id,
+            user_id,
+            include_chrome,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = include_chrome {
+            query_args.push(("include_chrome".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v2.1/accounts/{}/users/{}/signatures/{}/{}?{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(user_id),
+                crate::progenitor_support::encode_path(signature_id),
+                crate::progenitor_support::encode_path(image_type),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`UserSignatures::put_signature_image`], set with a builder.
+pub struct UserSignaturesPutSignatureImageRequest<'a> {
+    client: &'a UserSignatures,
+    account_id: &'a str,
+    image_type: &'a str,
+    signature_id: &'a str,
+    user_id: &'a str,
+    transparent_png: Option<&'a str>,
+}
+
+impl<'a> UserSignaturesPutSignatureImageRequest<'a> {
+    /// Sets the `transparent_png` query parameter.
+    pub fn transparent_png(mut self, transparent_png: &'a str) -> Self {
+        self.transparent_png = Some(transparent_png);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::UserSignature>> {
+        let Self {
+            client,
+            account_id,
+            image_type,
+            signature_id,
+            user_id,
+            transparent_png,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = transparent_png {
+            query_args.push(("transparent_png".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v2.1/accounts/{}/users/{}/signatures/{}/{}?{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(user_id),
+                crate::progenitor_support::encode_path(signature_id),
+                crate::progenitor_support::encode_path(image_type),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .put(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct UserSignatures {
     pub client: Client,
 }
@@ -65,6 +288,33 @@ impl UserSignatures {
             )
             .await
     }
+    /**
+     * Retrieves a list of signature definitions for a user.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/users/{userId}/signatures` endpoint.
+     *
+     * As opposed to `get`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * This method retrieves the signature definitions for the user that you specify.
+     *
+     * The `userId` parameter specified in the endpoint must match the authenticated user's user ID, and the user must be a member of the account.
+     *
+     * The `signatureId` parameter accepts a signature ID or a signature name. DocuSign recommends you use signature ID (`signatureId`), since some names contain characters that do not properly encode into a URL. If you use the user name, it is likely that the name includes spaces. In that case, URL encode the name before using it in the endpoint.
+     *
+     * For example, encode "Bob Smith" as "Bob%20Smith".
+     */
+    pub fn get_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        user_id: &'a str,
+    ) -> UserSignaturesGetRequest<'a> {
+        UserSignaturesGetRequest {
+            client: self,
+            account_id,
+            user_id,
+            stamp_type: None,
+        }
+    }
     /**
      * Adds/updates a user signature.
      *
@@ -261,6 +511,39 @@ impl UserSignatures {
             )
             .await
     }
+    /**
+     * Updates the user signature for a specified user.
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/users/{userId}/signatures/{signatureId}` endpoint.
+     *
+     * As opposed to `put_signature_user_signatures`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Creates, or updates, the signature font and initials for the specified user. When creating a signature, you use this resource to create the signature name and then add the signature and initials images into the signature.
+     *
+     * **Note**: This will also create a default signature for the user when one does not exist.
+     *
+     * The userId property specified in the endpoint must match the authenticated user's user ID and the user must be a member of the account.
+     *
+     * The `signatureId` parameter accepts a signature ID. DocuSign recommends you use signature ID (`signatureId`), since some names contain characters that do not properly encode into a URL. If you use the user name, it is likely that the name includes spaces. In that case, URL encode the name before using it in the endpoint.
+     *
+     * For example encode "Bob Smith" as "Bob%20Smith".
+     */
+    pub fn put_signature_user_signatures_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        signature_id: &'a str,
+        user_id: &'a str,
+        body: &'a crate::types::UserSignatureDefinition,
+    ) -> UserSignaturesPutSignatureUserSignaturesRequest<'a> {
+        UserSignaturesPutSignatureUserSignaturesRequest {
+            client: self,
+            account_id,
+            signature_id,
+            user_id,
+            close_existing_signature: None,
+            body,
+        }
+    }
     /**
      * Removes removes signature information for the specified user.
      *
@@ -367,6 +650,39 @@ impl UserSignatures {
             )
             .await
     }
+    /**
+     * Retrieves the user initials image or the  user signature image for the specified user.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/users/{userId}/signatures/{signatureId}/{imageType}` endpoint.
+     *
+     * As opposed to `get_signature_image`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Retrieves the specified initials image or signature image for the specified user. The image is returned in the same format in which it was uploaded. In the request you can specify if the chrome (the added line and identifier around the initial image) is returned with the image.
+     *
+     * The userId property specified in the endpoint must match the authenticated user's user ID and the user must be a member of the account.
+     *
+     * The `signatureId` parameter accepts a signature ID or a signature name. DocuSign recommends you use signature ID (`signatureId`), since some names contain characters that do not properly encode into a URL. If you use the user name, it is likely that the name includes spaces. In that case, URL encode the name before using it in the endpoint.
+     *
+     * For example encode "Bob Smith" as "Bob%20Smith".
+     *
+     * **Note**: Older envelopes might only have chromed images. If getting the non-chromed image fails, try getting the chromed image.
+     */
+    pub fn get_signature_image_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        image_type: &'a str,
+        signature_id: &'a str,
+        user_id: &'a str,
+    ) -> UserSignaturesGetSignatureImageRequest<'a> {
+        UserSignaturesGetSignatureImageRequest {
+            client: self,
+            account_id,
+            image_type,
+            signature_id,
+            user_id,
+            include_chrome: None,
+        }
+    }
     /**
      * Updates the user signature image or user initials image for the specified user.
      *
@@ -427,6 +743,38 @@ impl UserSignatures {
             )
             .await
     }
+    /**
+     * Updates the user signature image or user initials image for the specified user.
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/users/{userId}/signatures/{signatureId}/{imageType}` endpoint.
+     *
+     * As opposed to `put_signature_image`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Updates the user signature image or user initials image for the specified user. The supported image formats for this file are: gif, png, jpeg, and bmp. The file must be less than 200K.
+     *
+     * The userId property specified in the endpoint must match the authenticated user's user ID and the user must be a member of the account.
+     *
+     * The `signatureId` parameter accepts a signature ID or a signature name. DocuSign recommends you use signature ID (`signatureId`), since some names contain characters that do not properly encode into a URL. If you use the user name, it is likely that the name includes spaces. In that case, URL encode the name before using it in the endpoint.
+     *
+     * For example encode "Bob Smith" as "Bob%20Smith".
+     *
+     */
+    pub fn put_signature_image_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        image_type: &'a str,
+        signature_id: &'a str,
+        user_id: &'a str,
+    ) -> UserSignaturesPutSignatureImageRequest<'a> {
+        UserSignaturesPutSignatureImageRequest {
+            client: self,
+            account_id,
+            image_type,
+            signature_id,
+            user_id,
+            transparent_png: None,
+        }
+    }
     /**
      * Deletes the user initials image or the  user signature image for the specified user.
      *
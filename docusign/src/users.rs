@@ -1,6 +1,327 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Users::get`], set with a builder.
+pub struct UsersGetRequest<'a> {
+    client: &'a Users,
+    account_id: &'a str,
+    additional_info: Option<&'a str>,
+    count: Option<&'a str>,
+    email: Option<&'a str>,
+    email_substring: Option<&'a str>,
+    group_id: Option<&'a str>,
+    include_usersettings_for_csv: Option<&'a str>,
+    login_status: Option<&'a str>,
+    not_group_id: Option<&'a str>,
+    start_position: Option<&'a str>,
+    status: Option<&'a str>,
+    user_name_substring: Option<&'a str>,
+}
+
+impl<'a> UsersGetRequest<'a> {
+    /// Sets the `additional_info` query parameter.
+    pub fn additional_info(mut self, additional_info: &'a str) -> Self {
+        self.additional_info = Some(additional_info);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: &'a str) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Sets the `email` query parameter.
+    pub fn email(mut self, email: &'a str) -> Self {
+        self.email = Some(email);
+        self
+    }
+
+    /// Sets the `email_substring` query parameter.
+    pub fn email_substring(mut self, email_substring: &'a str) -> Self {
+        self.email_substring = Some(email_substring);
+        self
+    }
+
+    /// Sets the `group_id` query parameter.
+    pub fn group_id(mut self, group_id: &'a str) -> Self {
+        self.group_id = Some(group_id);
+        self
+    }
+
+    /// Sets the `include_usersettings_for_csv` query parameter.
+    pub fn include_usersettings_for_csv(mut self, include_usersettings_for_csv: &'a str) -> Self {
+        self.include_usersettings_for_csv = Some(include_usersettings_for_csv);
+        self
+    }
+
+    /// Sets the `login_status` query parameter.
+    pub fn login_status(mut self, login_status: &'a str) -> Self {
+        self.login_status = Some(login_status);
+        self
+    }
+
+    /// Sets the `not_group_id` query parameter.
+    pub fn not_group_id(mut self, not_group_id: &'a str) -> Self {
+        self.not_group_id = Some(not_group_id);
+        self
+    }
+
+    /// Sets the `start_position` query parameter.
+    pub fn start_position(mut self, start_position: &'a str) -> Self {
+        self.start_position = Some(start_position);
+        self
+    }
+
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: &'a str) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Sets the `user_name_substring` query parameter.
+    pub fn user_name_substring(mut self, user_name_substring: &'a str) -> Self {
+        self.user_name_substring = Some(user_name_substring);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::UserInformationList>> {
+        let Self {
+            client,
+            account_id,
+            additional_info,
+            count,
+            email,
+            email_substring,
+            group_id,
+            include_usersettings_for_csv,
+            login_status,
+            not_group_id,
+            start_position,
+            status,
+            user_name_substring,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = additional_info {
+            query_args.push(("additional_info".to_string(), v.to_string()));
+        }
+        if let Some(v) = count {
+            query_args.push(("count".to_string(), v.to_string()));
+        }
+        if let Some(v) = email {
+            query_args.push(("email".to_string(), v.to_string()));
+        }
+        if let Some(v) = email_substring {
+            query_args.push(("email_substring".to_string(), v.to_string()));
+        }
+        if let Some(v) = group_id {
+            query_args.push(("group_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = include_usersettings_for_csv {
+            query_args.push(("include_usersettings_for_csv".to_string(), v.to_string()));
+        }
+        if let Some(v) = login_status {
+            query_args.push(("login_status".to_string(), v.to_string()));
+        }
+        if let Some(v) = not_group_id {
+            query_args.push(("not_group_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = start_position {
+            query_args.push(("start_position".to_string(), v.to_string()));
+        }
+        if let Some(v) = status {
+            query_args.push(("status".to_string(), v.to_string()));
+        }
+        if let Some(v) = user_name_substring {
+            query_args.push(("user_name_substring".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v2.1/accounts/{}/users?{}",
+                crate::progenitor_support::encode_path(account_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Users::delete`], set with a builder.
+pub struct UsersDeleteRequest<'a> {
+    client: &'a Users,
+    account_id: &'a str,
+    delete: Option<&'a str>,
+    body: &'a crate::types::UserInfoList,
+}
+
+impl<'a> UsersDeleteRequest<'a> {
+    /// Sets the `delete` query parameter.
+    pub fn delete(mut self, delete: &'a str) -> Self {
+        self.delete = Some(delete);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::UsersResponse>> {
+        let Self {
+            client,
+            account_id,
+            delete,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = delete {
+            query_args.push(("delete".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v2.1/accounts/{}/users?{}",
+                crate::progenitor_support::encode_path(account_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .delete(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Users::get_users`], set with a builder.
+pub struct UsersGetUsersRequest<'a> {
+    client: &'a Users,
+    account_id: &'a str,
+    user_id: &'a str,
+    additional_info: Option<&'a str>,
+    email: Option<&'a str>,
+}
+
+impl<'a> UsersGetUsersRequest<'a> {
+    /// Sets the `additional_info` query parameter.
+    pub fn additional_info(mut self, additional_info: &'a str) -> Self {
+        self.additional_info = Some(additional_info);
+        self
+    }
+
+    /// Sets the `email` query parameter.
+    pub fn email(mut self, email: &'a str) -> Self {
+        self.email = Some(email);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::UserInformation>> {
+        let Self {
+            client,
+            account_id,
+            user_id,
+            additional_info,
+            email,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = additional_info {
+            query_args.push(("additional_info".to_string(), v.to_string()));
+        }
+        if let Some(v) = email {
+            query_args.push(("email".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v2.1/accounts/{}/users/{}?{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(user_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Users::profile_image_get`], set with a builder.
+pub struct UsersProfileImageGetRequest<'a> {
+    client: &'a Users,
+    account_id: &'a str,
+    user_id: &'a str,
+    encoding: Option<&'a str>,
+}
+
+impl<'a> UsersProfileImageGetRequest<'a> {
+    /// Sets the `encoding` query parameter.
+    pub fn encoding(mut self, encoding: &'a str) -> Self {
+        self.encoding = Some(encoding);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            account_id,
+            user_id,
+            encoding,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = encoding {
+            query_args.push(("encoding".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v2.1/accounts/{}/users/{}/profile/image?{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(user_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct Users {
     pub client: Client,
 }
@@ -122,6 +443,34 @@ impl Users {
             )
             .await
     }
+    /**
+     * Retrieves the list of users for the specified account.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/users` endpoint.
+     *
+     * As opposed to `get`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Retrieves the list of users for the specified account.
+     *
+     * The response returns the list of users for the account, with information about the result set. If the `additional_info` query is added to the endpoint and set to **true**, full user information is returned for each user.
+     */
+    pub fn get_request<'a>(&'a self, account_id: &'a str) -> UsersGetRequest<'a> {
+        UsersGetRequest {
+            client: self,
+            account_id,
+            additional_info: None,
+            count: None,
+            email: None,
+            email_substring: None,
+            group_id: None,
+            include_usersettings_for_csv: None,
+            login_status: None,
+            not_group_id: None,
+            start_position: None,
+            status: None,
+            user_name_substring: None,
+        }
+    }
     /**
      * Changes one or more users in the specified account.
      *
@@ -289,6 +638,29 @@ impl Users {
             )
             .await
     }
+    /**
+     * Removes users account privileges.
+     *
+     * This function performs a `DELETE` to the `/v2.1/accounts/{accountId}/users` endpoint.
+     *
+     * As opposed to `delete`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Closes one or more user records in the account. Users are never deleted from an account, but closing a user prevents them from using account functions.
+     *
+     * The response specifies whether the API execution succeeded (200 - OK) or failed (400 - Error). The response contains a user structure similar to the request and includes the user changes. If an error occurred during the DELETE operation for any of the users, the response for that user contains an `errorDetails` property with `errorCode` and `message` properties.
+     */
+    pub fn delete_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        body: &'a crate::types::UserInfoList,
+    ) -> UsersDeleteRequest<'a> {
+        UsersDeleteRequest {
+            client: self,
+            account_id,
+            delete: None,
+            body,
+        }
+    }
     /**
      * Gets the user information for a specified user.
      *
@@ -340,6 +712,30 @@ impl Users {
             )
             .await
     }
+    /**
+     * Gets the user information for a specified user.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/users/{userId}` endpoint.
+     *
+     * As opposed to `get_users`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Retrieves the user information for the specified user.
+     *
+     * To return additional user information that details the last login date, login status, and the user's password expiration date, set the optional `additional_info` query string parameter to **true**.
+     */
+    pub fn get_users_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        user_id: &'a str,
+    ) -> UsersGetUsersRequest<'a> {
+        UsersGetUsersRequest {
+            client: self,
+            account_id,
+            user_id,
+            additional_info: None,
+            email: None,
+        }
+    }
     /**
      * Updates user information for the specified user.
      *
@@ -425,6 +821,31 @@ impl Users {
             )
             .await
     }
+    /**
+     * Retrieves the user profile image for the specified user.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/users/{userId}/profile/image` endpoint.
+     *
+     * As opposed to `profile_image_get`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Retrieves the user profile picture for the specified user. The image is returned in the same format as uploaded.
+     *
+     * The userId parameter specified in the endpoint must match the authenticated user's user ID and the user must be a member of the specified account.
+     *
+     * If successful, the response returns a 200 - OK and the user profile image.
+     */
+    pub fn profile_image_get_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        user_id: &'a str,
+    ) -> UsersProfileImageGetRequest<'a> {
+        UsersProfileImageGetRequest {
+            client: self,
+            account_id,
+            user_id,
+            encoding: None,
+        }
+    }
     /**
      * Updates the user profile image for a specified user.
      *
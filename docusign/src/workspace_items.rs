@@ -1,6 +1,297 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`WorkspaceItems::workspace_folder_get`], set with a builder.
+pub struct WorkspaceItemsWorkspaceFolderGetRequest<'a> {
+    client: &'a WorkspaceItems,
+    account_id: &'a str,
+    folder_id: &'a str,
+    workspace_id: &'a str,
+    count: Option<&'a str>,
+    include_files: Option<&'a str>,
+    include_sub_folders: Option<&'a str>,
+    include_thumbnails: Option<&'a str>,
+    include_user_detail: Option<&'a str>,
+    start_position: Option<&'a str>,
+    workspace_user_id: Option<&'a str>,
+}
+
+impl<'a> WorkspaceItemsWorkspaceFolderGetRequest<'a> {
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: &'a str) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Sets the `include_files` query parameter.
+    pub fn include_files(mut self, include_files: &'a str) -> Self {
+        self.include_files = Some(include_files);
+        self
+    }
+
+    /// Sets the `include_sub_folders` query parameter.
+    pub fn include_sub_folders(mut self, include_sub_folders: &'a str) -> Self {
+        self.include_sub_folders = Some(include_sub_folders);
+        self
+    }
+
+    /// Sets the `include_thumbnails` query parameter.
+    pub fn include_thumbnails(mut self, include_thumbnails: &'a str) -> Self {
+        self.include_thumbnails = Some(include_thumbnails);
+        self
+    }
+
+    /// Sets the `include_user_detail` query parameter.
+    pub fn include_user_detail(mut self, include_user_detail: &'a str) -> Self {
+        self.include_user_detail = Some(include_user_detail);
+        self
+    }
+
+    /// Sets the `start_position` query parameter.
+    pub fn start_position(mut self, start_position: &'a str) -> Self {
+        self.start_position = Some(start_position);
+        self
+    }
+
+    /// Sets the `workspace_user_id` query parameter.
+    pub fn workspace_user_id(mut self, workspace_user_id: &'a str) -> Self {
+        self.workspace_user_id = Some(workspace_user_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::WorkspaceFolderContents>> {
+        let Self {
+            client,
+            account_id,
+            folder_id,
+            workspace_id,
+            count,
+            include_files,
+            include_sub_folders,
+            include_thumbnails,
+            include_user_detail,
+            start_position,
+            workspace_user_id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = count {
+            query_args.push(("count".to_string(), v.to_string()));
+        }
+        if let Some(v) = include_files {
+            query_args.push(("include_files".to_string(), v.to_string()));
+        }
+        if let Some(v) = include_sub_folders {
+            query_args.push(("include_sub_folders".to_string(), v.to_string()));
+        }
+        if let Some(v) = include_thumbnails {
+            query_args.push(("include_thumbnails".to_string(), v.to_string()));
+        }
+        if let Some(v) = include_user_detail {
+            query_args.push(("include_user_detail".to_string(), v.to_string()));
+        }
+        if let Some(v) = start_position {
+            query_args.push(("start_position".to_string(), v.to_string()));
+        }
+        if let Some(v) = workspace_user_id {
+            query_args.push(("workspace_user_id".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v2.1/accounts/{}/workspaces/{}/folders/{}?{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(workspace_id),
+                crate::progenitor_support::encode_path(folder_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`WorkspaceItems::workspace_file_get`], set with a builder.
+pub struct WorkspaceItemsWorkspaceFileGetRequest<'a> {
+    client: &'a WorkspaceItems,
+    account_id: &'a str,
+    file_id: &'a str,
+    folder_id: &'a str,
+    workspace_id: &'a str,
+    is_download: Option<&'a str>,
+    pdf_version: Option<&'a str>,
+}
+
+impl<'a> WorkspaceItemsWorkspaceFileGetRequest<'a> {
+    /// Sets the `is_download` query parameter.
+    pub fn is_download(mut self, is_download: &'a str) -> Self {
+        self.is_download = Some(is_download);
+        self
+    }
+
+    /// Sets the `pdf_version` query parameter.
+    pub fn pdf_version(mut self, pdf_version: &'a str) -> Self {
+        self.pdf_version = Some(pdf_version);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            account_id,
+            file_id,
+            folder_id,
+            workspace_id,
+            is_download,
+            pdf_version,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = is_download {
+            query_args.push(("is_download".to_string(), v.to_string()));
+        }
+        if let Some(v) = pdf_version {
+            query_args.push(("pdf_version".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v2.1/accounts/{}/workspaces/{}/folders/{}/files/{}?{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(workspace_id),
+                crate::progenitor_support::encode_path(folder_id),
+                crate::progenitor_support::encode_path(file_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`WorkspaceItems::workspace_file_pages_get`], set with a builder.
+pub struct WorkspaceItemsWorkspaceFilePagesGetRequest<'a> {
+    client: &'a WorkspaceItems,
+    account_id: &'a str,
+    file_id: &'a str,
+    folder_id: &'a str,
+    workspace_id: &'a str,
+    count: Option<&'a str>,
+    dpi: Option<&'a str>,
+    max_height: Option<&'a str>,
+    max_width: Option<&'a str>,
+    start_position: Option<&'a str>,
+}
+
+impl<'a> WorkspaceItemsWorkspaceFilePagesGetRequest<'a> {
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: &'a str) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Sets the `dpi` query parameter.
+    pub fn dpi(mut self, dpi: &'a str) -> Self {
+        self.dpi = Some(dpi);
+        self
+    }
+
+    /// Sets the `max_height` query parameter.
+    pub fn max_height(mut self, max_height: &'a str) -> Self {
+        self.max_height = Some(max_height);
+        self
+    }
+
+    /// Sets the `max_width` query parameter.
+    pub fn max_width(mut self, max_width: &'a str) -> Self {
+        self.max_width = Some(max_width);
+        self
+    }
+
+    /// Sets the `start_position` query parameter.
+    pub fn start_position(mut self, start_position: &'a str) -> Self {
+        self.start_position = Some(start_position);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::PageImages>> {
+        let Self {
+            client,
+            account_id,
+            file_id,
+            folder_id,
+            workspace_id,
+            count,
+            dpi,
+            max_height,
+            max_width,
+            start_position,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = count {
+            query_args.push(("count".to_string(), v.to_string()));
+        }
+        if let Some(v) = dpi {
+            query_args.push(("dpi".to_string(), v.to_string()));
+        }
+        if let Some(v) = max_height {
+            query_args.push(("max_height".to_string(), v.to_string()));
+        }
+        if let Some(v) = max_width {
+            query_args.push(("max_width".to_string(), v.to_string()));
+        }
+        if let Some(v) = start_position {
+            query_args.push(("start_position".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v2.1/accounts/{}/workspaces/{}/folders/{}/files/{}/pages?{}",
+                crate::progenitor_support::encode_path(account_id),
+                crate::progenitor_support::encode_path(workspace_id),
+                crate::progenitor_support::encode_path(folder_id),
+                crate::progenitor_support::encode_path(file_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct WorkspaceItems {
     pub client: Client,
 }
@@ -99,6 +390,35 @@ impl WorkspaceItems {
             )
             .await
     }
+    /**
+     * List workspace folder contents.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/workspaces/{workspaceId}/folders/{folderId}` endpoint.
+     *
+     * As opposed to `workspace_folder_get`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * This method returns the contents of a workspace folder, which can include sub-folders and files.
+     */
+    pub fn workspace_folder_get_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        folder_id: &'a str,
+        workspace_id: &'a str,
+    ) -> WorkspaceItemsWorkspaceFolderGetRequest<'a> {
+        WorkspaceItemsWorkspaceFolderGetRequest {
+            client: self,
+            account_id,
+            folder_id,
+            workspace_id,
+            count: None,
+            include_files: None,
+            include_sub_folders: None,
+            include_thumbnails: None,
+            include_user_detail: None,
+            start_position: None,
+            workspace_user_id: None,
+        }
+    }
     /**
      * Deletes files or sub-folders from a workspace.
      *
@@ -232,6 +552,32 @@ impl WorkspaceItems {
             )
             .await
     }
+    /**
+     * Gets a workspace file.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/workspaces/{workspaceId}/folders/{folderId}/files/{fileId}` endpoint.
+     *
+     * As opposed to `workspace_file_get`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * This method returns a binary version of a file in a workspace.
+     */
+    pub fn workspace_file_get_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        file_id: &'a str,
+        folder_id: &'a str,
+        workspace_id: &'a str,
+    ) -> WorkspaceItemsWorkspaceFileGetRequest<'a> {
+        WorkspaceItemsWorkspaceFileGetRequest {
+            client: self,
+            account_id,
+            file_id,
+            folder_id,
+            workspace_id,
+            is_download: None,
+            pdf_version: None,
+        }
+    }
     /**
      * Update workspace file or folder metadata.
      *
@@ -342,4 +688,33 @@ impl WorkspaceItems {
             )
             .await
     }
+    /**
+     * List File Pages.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/workspaces/{workspaceId}/folders/{folderId}/files/{fileId}/pages` endpoint.
+     *
+     * As opposed to `workspace_file_pages_get`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * This method returns a workspace file as rasterized pages.
+     */
+    pub fn workspace_file_pages_get_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        file_id: &'a str,
+        folder_id: &'a str,
+        workspace_id: &'a str,
+    ) -> WorkspaceItemsWorkspaceFilePagesGetRequest<'a> {
+        WorkspaceItemsWorkspaceFilePagesGetRequest {
+            client: self,
+            account_id,
+            file_id,
+            folder_id,
+            workspace_id,
+            count: None,
+            dpi: None,
+            max_height: None,
+            max_width: None,
+            start_position: None,
+        }
+    }
 }
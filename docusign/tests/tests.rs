@@ -1 +1,156 @@
+use std::mem;
 
+use wiremock::{
+    matchers::{method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+use docusign::{
+    connect::{self, Webhook, WebhookError},
+    Client,
+};
+
+#[test]
+fn test_webhook_verifies_signature() {
+    let payload = br#"{"event":"envelope-sent"}"#;
+    let mut webhook = Webhook::new("f2uCfy0WQ9Qu5Mxaq4uKzHnhnWCkOzGGEZ8rNNTNOMc=");
+
+    webhook
+        .verify(
+            [(
+                "X-DocuSign-Signature-1",
+                "NrLBWXPHEyL5SjaT8ctkVMXQqwN2iJ5LNuUcFfP1iGU=",
+            )],
+            payload,
+        )
+        .unwrap();
+    assert!(matches!(
+        webhook.verify([("X-DocuSign-Signature-1", "c2lnbmF0dXJl")], payload),
+        Err(WebhookError::NoMatchingSignature)
+    ));
+    assert!(matches!(
+        webhook.verify([("Content-Type", "application/json")], payload),
+        Err(WebhookError::MissingHeader)
+    ));
+
+    // Any signature may match any key, while keys are rotated.
+    let headers = Webhook::new("new-key").sign(payload);
+    webhook.with_secret("new-key");
+    webhook
+        .verify(
+            [
+                ("x-docusign-signature-1", "c2lnbmF0dXJl".to_string()),
+                ("x-docusign-signature-2", headers[0].1.clone()),
+            ],
+            payload,
+        )
+        .unwrap();
+}
+
+#[test]
+fn test_webhook_constructs_json_event() {
+    let payload = include_bytes!("fixtures/envelope_completed.json");
+    let webhook = Webhook::new("secret");
+    let event = webhook
+        .construct_event(webhook.sign(payload), payload)
+        .unwrap();
+
+    assert_eq!(event.event, "envelope-completed");
+    assert_eq!(event.configuration_id, 10418);
+    assert_eq!(
+        event.data.envelope_id,
+        "93be49ab-f0a8-4d45-9b4a-8a8c3f6e2b17"
+    );
+
+    let envelope = event.data.envelope_summary.unwrap();
+    assert_eq!(envelope.status, "completed");
+    assert_eq!(envelope.sender.unwrap().email, "jessie@example.com");
+    let signers = envelope.recipients.unwrap().signers;
+    assert_eq!(signers[0].name, "Rosario Jones");
+    assert_eq!(signers[0].status, "completed");
+    assert_eq!(envelope.envelope_documents[0].name, "lease.pdf");
+}
+
+#[test]
+fn test_parse_legacy_xml_event() {
+    let event = connect::parse_event(include_bytes!("fixtures/envelope_completed.xml")).unwrap();
+
+    assert_eq!(event.event, "envelope-completed");
+    assert_eq!(event.generated_date_time, "2020-01-21T14:21:51.8553212");
+    assert_eq!(
+        event.data.envelope_id,
+        "93be49ab-f0a8-4d45-9b4a-8a8c3f6e2b17"
+    );
+
+    let envelope = event.data.envelope_summary.unwrap();
+    assert_eq!(envelope.status, "completed");
+    assert_eq!(envelope.email_subject, "Please sign the lease");
+    assert_eq!(envelope.completed_date_time, "2020-01-21T14:21:50.789");
+    assert_eq!(envelope.sender.unwrap().user_name, "Jessie Smith");
+
+    let recipients = envelope.recipients.unwrap();
+    assert_eq!(recipients.signers.len(), 1);
+    assert_eq!(recipients.signers[0].email, "rosario@example.com");
+    assert_eq!(recipients.signers[0].status, "completed");
+    assert_eq!(
+        recipients.signers[0].signed_date_time,
+        "2020-01-21T14:21:49.003"
+    );
+    assert_eq!(recipients.signers[0].declined_reason, "");
+    assert_eq!(recipients.carbon_copies[0].name, "Jordan Lee");
+
+    assert_eq!(envelope.envelope_documents[0].document_id, "1");
+    assert_eq!(envelope.envelope_documents[0].name, "lease.pdf");
+}
+
+#[tokio::test]
+async fn test_list_pages_through_start_position() {
+    let server = MockServer::start().await;
+    let archives = "/v2.1/accounts/1/settings/bcc_email_archives";
+
+    for (start, end, next) in [("0", "1", "?start_position=2&count=2"), ("2", "2", "")] {
+        Mock::given(method("GET"))
+            .and(path(archives))
+            .and(query_param("count", "2"))
+            .and(query_param("start_position", start))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "bccEmailArchives": [{ "bccEmailArchiveId": format!("archive-{}", start) }],
+                "startPosition": start,
+                "endPosition": end,
+                "resultSetSize": "2",
+                "totalSetSize": "3",
+                "nextUri": if next.is_empty() { String::new() } else { format!("{}{}", archives, next) }
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+    }
+
+    let mut client = Client::new("client_id", "client_secret", "redirect_uri", "token", "");
+    client.with_host_override(server.uri());
+
+    // DocuSign lists hand back a `nextUri` until the last page, which ends the
+    // result set; the following page starts right after `endPosition`.
+    let mut ids = Vec::new();
+    let mut start = "0".to_string();
+    loop {
+        let page = client
+            .bcc_email_archive()
+            .get_list("1", "2", &start)
+            .await
+            .unwrap()
+            .body;
+        ids.extend(
+            page.bcc_email_archives
+                .into_iter()
+                .map(|a| a.bcc_email_archive_id),
+        );
+        if page.next_uri.is_empty() {
+            break;
+        }
+        start = (page.end_position.parse::<u64>().unwrap() + 1).to_string();
+    }
+    assert_eq!(ids, vec!["archive-0", "archive-2"]);
+
+    mem::drop(server)
+}
@@ -0,0 +1,119 @@
+use inflector::cases::snakecase::to_snake_case;
+
+use crate::{clean_name, functions::FileOutput, struct_name};
+
+const TEMPLATE: &str = r#"//! A blocking client, for the programs that are not async.
+//!
+//! It has the functions of the async [`Client`](crate::Client), which it runs on a
+//! runtime of its own, so they must not be called from within an async runtime. The
+//! `*_stream` functions are left out, the `*_all` ones returning all the pages at once.
+
+use std::{future::Future, sync::Arc};
+
+{mods}
+
+/// A blocking client, sending its requests with an async [`Client`](crate::Client).
+#[derive(Clone)]
+pub struct Client {
+    inner: crate::Client,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Client {
+    /// Wraps an async client, creating the runtime its requests run on.
+    pub fn new(client: crate::Client) -> std::io::Result<Self> {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()?;
+
+        Ok(Client {
+            inner: client,
+            runtime: Arc::new(runtime),
+        })
+    }
+
+    /// Returns the async client the requests are sent with.
+    pub fn inner(&self) -> &crate::Client {
+        &self.inner
+    }
+
+    /// Runs a future to completion on the runtime of the client, for the async
+    /// functions that have no blocking version.
+    pub fn block_on<F: Future>(&self, future: F) -> F::Output {
+        self.runtime.block_on(future)
+    }
+
+{fns}
+}
+"#;
+
+/// Returns the `blocking` module of a client, given the tags its functions are
+/// grouped by.
+pub fn generate_blocking<'a>(tags: impl IntoIterator<Item = &'a String>) -> String {
+    let mut mods = String::new();
+    let mut fns = String::new();
+    for tag in tags {
+        let m = to_snake_case(&clean_name(tag));
+        mods.push_str(&format!("pub mod {};\n", m));
+        fns.push_str(&format!(
+            r#"    /// The blocking version of [`crate::Client::{}`].
+    pub fn {}(&self) -> {}::{} {{
+        {}::{}::new(self.inner.{}(), self.runtime.clone())
+    }}
+
+"#,
+            m,
+            m,
+            m,
+            struct_name(tag),
+            m,
+            struct_name(tag),
+            m
+        ));
+    }
+
+    TEMPLATE
+        .replace("{mods}", mods.trim_end())
+        .replace("{fns}", fns.trim_end())
+}
+
+/// Returns the module of the blocking client for the functions of a tag.
+pub fn generate_blocking_tag(tag: &str, output: &FileOutput) -> String {
+    format!(
+        r#"
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+{}
+
+/// The blocking version of [`crate::{}::{}`].
+pub struct {} {{
+    inner: crate::{}::{},
+    runtime: Arc<tokio::runtime::Runtime>,
+}}
+
+impl {} {{
+    pub(crate) fn new(inner: crate::{}::{}, runtime: Arc<tokio::runtime::Runtime>) -> Self
+    {{
+        {} {{
+            inner,
+            runtime,
+        }}
+    }}
+
+    {}
+}}"#,
+        output.blocking_head,
+        to_snake_case(&clean_name(tag)),
+        struct_name(tag),
+        struct_name(tag),
+        to_snake_case(&clean_name(tag)),
+        struct_name(tag),
+        struct_name(tag),
+        to_snake_case(&clean_name(tag)),
+        struct_name(tag),
+        struct_name(tag),
+        output.blocking_impl_content,
+    )
+}
@@ -10,6 +10,7 @@ pub const GITHUB_TEMPLATE: &str = r#"/// Entrypoint for interacting with the API
 pub struct Client {
     host: String,
     host_override: Option<String>,
+    page_limits: crate::utils::PageLimits,
     agent: String,
     client: reqwest_middleware::ClientWithMiddleware,
     credentials: Option<crate::auth::Credentials>,
@@ -23,19 +24,7 @@ impl Client {
         A: Into<String>,
         C: Into<Option<crate::auth::Credentials>>,
     {
-        let http = reqwest::Client::builder().redirect(reqwest::redirect::Policy::none()).build()?;
-        let retry_policy = reqwest_retry::policies::ExponentialBackoff::builder().build_with_max_retries(3);
-        let client = reqwest_middleware::ClientBuilder::new(http)
-            // Trace HTTP requests. See the tracing crate to make use of these traces.
-            .with(reqwest_tracing::TracingMiddleware::default())
-            // Retry failed requests.
-            .with(
-                reqwest_conditional_middleware::ConditionalMiddleware::new(
-                    reqwest_retry::RetryTransientMiddleware::new_with_policy(retry_policy),
-                    |req: &reqwest::Request| req.try_clone().is_some()
-                )
-            )
-            .build();
+        let client = crate::ClientBuilder::default().build()?;
 
         #[cfg(feature = "httpcache")]
         {
@@ -66,6 +55,7 @@ impl Client {
         Self {
             host: RootDefaultServer::default().default_url().to_string(),
             host_override: None,
+            page_limits: Default::default(),
             agent: agent.into(),
             client: http,
             credentials: credentials.into(),
@@ -82,6 +72,7 @@ impl Client {
         Self {
             host: RootDefaultServer::default().default_url().to_string(),
             host_override: None,
+            page_limits: Default::default(),
             agent: agent.into(),
             client: http,
             credentials: credentials.into(),
@@ -108,6 +99,30 @@ impl Client {
         self.host_override.as_deref()
     }
 
+    /// Replaces the HTTP stack of the client with one built by `builder`, to configure
+    /// its retries, timeouts and middleware.
+    pub fn with_client_builder(&mut self, builder: crate::ClientBuilder) -> ClientResult<&mut Self>
+    {
+        self.client = builder.build()?;
+        Ok(self)
+    }
+
+    /// Stop walking the pages of a paginated endpoint after `max_pages` pages have been
+    /// fetched. The first page is always fetched, so a limit of zero behaves like one.
+    pub fn with_max_pages(&mut self, max_pages: usize) -> &mut Self
+    {
+        self.page_limits.max_pages = Some(max_pages.max(1));
+        self
+    }
+
+    /// Stop walking the pages of a paginated endpoint once `max_items` items have been
+    /// returned.
+    pub fn with_max_items(&mut self, max_items: usize) -> &mut Self
+    {
+        self.page_limits.max_items = Some(max_items);
+        self
+    }
+
     pub(crate) fn url(&self, path: &str, host: Option<&str>) -> String {
         format!("{}{}", self.get_host_override().or(host).unwrap_or(self.host.as_str()), path)
     }
@@ -177,6 +192,8 @@ impl Client {
                                     permissions: Default::default(),
                                     repositories: Default::default(),
                                     repository_ids: Default::default(),
+                                    #[cfg(feature = "extra-fields")]
+                                    extra: Default::default(),
                                 },
                             )
                             .await?;
@@ -253,11 +270,8 @@ impl Client {
 
         let response = req.send().await?;
 
-        #[cfg(not(feature = "httpcache"))]
-        let (remaining, reset) = crate::utils::get_header_values(response.headers());
-
         #[cfg(feature = "httpcache")]
-        let (remaining, reset, etag) = crate::utils::get_header_values(response.headers());
+        let (_, _, etag) = crate::utils::get_header_values(response.headers());
 
         let status = response.status();
         let headers = response.headers().clone();
@@ -330,23 +344,7 @@ impl Client {
                 }
             }
         } else {
-            let error = match (remaining, reset) {
-                (Some(remaining), Some(reset)) if remaining == 0 => {
-                    let now = std::time::SystemTime::now()
-                        .duration_since(std::time::UNIX_EPOCH)
-                        .unwrap()
-                        .as_secs();
-                    ClientError::RateLimited{duration: u64::from(reset).saturating_sub(now)}
-                },
-                _ => {
-                    if response_body.is_empty() {
-                        ClientError::HttpError{status, headers, error: "empty response".into()}
-                    } else {
-                        ClientError::HttpError{status, headers, error: String::from_utf8_lossy(&response_body).into()}
-                    }
-                }
-            };
-            Err(error)
+            Err(ClientError::from_response(status, headers, &response_body))
         }
     }
 
@@ -418,6 +416,34 @@ impl Client {
         ).await
     }
 
+    /// Lazily walk the pages of a `Link` header paginated endpoint, yielding
+    /// each item as it is received.
+    fn get_all_pages_stream<'a, D>(&'a self, uri: String) -> impl futures::Stream<Item = ClientResult<D>> + Unpin + 'a
+    where
+        D: serde::de::DeserializeOwned + 'static + Send + 'a,
+    {
+        use futures::TryStreamExt;
+
+        Box::pin(
+            futures::stream::try_unfold(
+                (Some(uri), crate::utils::Pages::new(self.page_limits)),
+                move |(uri, mut pages)| async move {
+                    let uri = match uri {
+                        Some(uri) if pages.visit(&uri)? => uri,
+                        _ => return Ok(None),
+                    };
+
+                    let (link, mut response) = self.get_pages::<D>(&uri).await?;
+                    let more = pages.keep(&mut response.body);
+                    let items = futures::stream::iter(response.body.into_iter().map(Ok));
+
+                    ClientResult::Ok(Some((items, (link.filter(|_| more).map(|l| l.0), pages))))
+                },
+            )
+            .try_flatten(),
+        )
+    }
+
     async fn post<D>(&self, uri: &str, message: Message) -> ClientResult<crate::Response<D>>
     where
         D: serde::de::DeserializeOwned + 'static + Send,
@@ -503,6 +529,11 @@ impl Client {
     }
 
     /// "unfold" paginated results of a vector of items
+    ///
+    /// Pages are followed until the server stops returning a next link, returns an
+    /// empty page or the client's page limits are reached. The walk fails with
+    /// `ClientError::IncompletePages` if the server points back at a page that was
+    /// already fetched.
     async fn unfold<D>(
         &self,
         uri: &str,
@@ -510,18 +541,22 @@ impl Client {
     where
         D: serde::de::DeserializeOwned + 'static + Send,
     {
+        let mut pages = crate::utils::Pages::new(self.page_limits);
+        pages.visit(uri)?;
+
         let mut global_items = Vec::new();
-        let (new_link, mut response) = self.get_pages(uri).await?;
-        let mut link = new_link;
-        while !response.body.is_empty() {
+        let (mut link, mut response) = self.get_pages(uri).await?;
+        loop {
+            let more = pages.keep(&mut response.body);
             global_items.append(&mut response.body);
             // We need to get the next link.
-            if let Some(url) = &link {
-                let url = reqwest::Url::parse(&url.0)?;
-                let (new_link, new_response) = self.get_pages_url(&url).await?;
-                link = new_link;
-                response = new_response;
-            }
+            let url = match link {
+                Some(url) if more && pages.visit(&url.0)? => reqwest::Url::parse(&url.0)?,
+                _ => break,
+            };
+            let (new_link, new_response) = self.get_pages_url(&url).await?;
+            link = new_link;
+            response = new_response;
         }
 
         Ok(Response::new(response.status, response.headers, global_items))
@@ -579,7 +614,22 @@ pub fn generate_client_generic_token(
         "{}?client_id={}&response_type=code&redirect_uri={}&state={}"
     };
 
-    let token_auth_template = get_token_auth_template(consent_pattern);
+    // Service accounts are only supported by Google, see service_account.rs.
+    let (service_account_struct, service_account_fn, refresh_prelude) =
+        if proper_name.starts_with("Google") {
+            (
+                "service_account: Option<Arc<crate::service_account::ServiceAccount>>,",
+                "service_account: None,",
+                r#"if let Some(service_account) = &self.service_account {
+        return self.refresh_service_account_token(service_account).await;
+    }
+"#,
+            )
+        } else {
+            ("", "", "")
+        };
+
+    let token_auth_template = get_token_auth_template(consent_pattern, refresh_prelude);
 
     let server_block = servers.server_block();
     let server_arg = servers.server_arg();
@@ -603,11 +653,13 @@ const USER_CONSENT_ENDPOINT: &str = "https://{}";
 pub struct Client {{
     host: String,
     host_override: Option<String>,
+    page_limits: crate::utils::PageLimits,
     token: Arc<RwLock<InnerToken>>,
     client_id: String,
     client_secret: String,
     redirect_uri: String,
     {}
+    {service_account_struct}
     auto_refresh: bool,
     client: reqwest_middleware::ClientWithMiddleware,
 }}
@@ -649,28 +701,15 @@ impl Client {{
         Q: ToString,
         {}
     {{
-        // Retry up to 3 times with increasing intervals between attempts.
-        let retry_policy = reqwest_retry::policies::ExponentialBackoff::builder().build_with_max_retries(3);
-        let client = reqwest::Client::builder().redirect(reqwest::redirect::Policy::none()).build();
+        let client = crate::ClientBuilder::default().build();
         match client {{
-            Ok(c) => {{
-                let client = reqwest_middleware::ClientBuilder::new(c)
-                    // Trace HTTP requests. See the tracing crate to make use of these traces.
-                    .with(reqwest_tracing::TracingMiddleware::default())
-                    // Retry failed requests.
-                    .with(
-                        reqwest_conditional_middleware::ConditionalMiddleware::new(
-                            reqwest_retry::RetryTransientMiddleware::new_with_policy(retry_policy),
-                            |req: &reqwest::Request| req.try_clone().is_some()
-                        )
-                    )
-                    .build();
-
+            Ok(client) => {{
                 {server_to_host}
 
                 Client {{
                     host,
                     host_override: None,
+                    page_limits: Default::default(),
                     client_id: client_id.to_string(),
                     client_secret: client_secret.to_string(),
                     redirect_uri: redirect_uri.to_string(),
@@ -680,6 +719,7 @@ impl Client {{
                         expires_at: None
                     }})),
                     {}
+                    {service_account_fn}
                     auto_refresh: false,
                     client,
                 }}
@@ -764,6 +804,30 @@ impl Client {{
         self.host_override.as_deref()
     }}
 
+    /// Replaces the HTTP stack of the client with one built by `builder`, to configure
+    /// its retries, timeouts and middleware.
+    pub fn with_client_builder(&mut self, builder: crate::ClientBuilder) -> ClientResult<&mut Self>
+    {{
+        self.client = builder.build()?;
+        Ok(self)
+    }}
+
+    /// Stop walking the pages of a paginated endpoint after `max_pages` pages have been
+    /// fetched. The first page is always fetched, so a limit of zero behaves like one.
+    pub fn with_max_pages(&mut self, max_pages: usize) -> &mut Self
+    {{
+        self.page_limits.max_pages = Some(max_pages.max(1));
+        self
+    }}
+
+    /// Stop walking the pages of a paginated endpoint once `max_items` items have been
+    /// returned.
+    pub fn with_max_items(&mut self, max_items: usize) -> &mut Self
+    {{
+        self.page_limits.max_items = Some(max_items);
+        self
+    }}
+
     pub(crate) fn url(&self, path: &str, host: Option<&str>) -> String {{
         format!("{{}}{{}}", self.get_host_override().or(host).unwrap_or(self.host.as_str()), path)
     }}
@@ -889,28 +953,16 @@ where
     let secret = yup_oauth2::parse_application_secret(decoded_google_key)
         .expect("failed to read from google credential env var");
 
-    let client = reqwest::Client::builder().redirect(reqwest::redirect::Policy::none()).build();
-    let retry_policy = reqwest_retry::policies::ExponentialBackoff::builder().build_with_max_retries(3);
+    let client = crate::ClientBuilder::default().build();
 
     match client {
-        Ok(c) => {
-            let client = reqwest_middleware::ClientBuilder::new(c)
-                // Trace HTTP requests. See the tracing crate to make use of these traces.
-                .with(reqwest_tracing::TracingMiddleware::default())
-                // Retry failed requests.
-                .with(
-                    reqwest_conditional_middleware::ConditionalMiddleware::new(
-                        reqwest_retry::RetryTransientMiddleware::new_with_policy(retry_policy),
-                        |req: &reqwest::Request| req.try_clone().is_some()
-                    )
-                )
-                .build();
-
+        Ok(client) => {
             let host = RootDefaultServer::default().default_url().to_string();
 
             Client {
                 host,
                 host_override: None,
+                page_limits: Default::default(),
                 client_id: secret.client_id.to_string(),
                 client_secret: secret.client_secret.to_string(),
                 redirect_uri: secret.redirect_uris[0].to_string(),
@@ -919,6 +971,7 @@ where
                     refresh_token: refresh_token.to_string(),
                     expires_at: None
                 })),
+                service_account: None,
                 auto_refresh: false,
                 client,
             }
@@ -952,6 +1005,7 @@ pub fn generate_client_generic_api_key(
 pub struct Client {{
     host: String,
     host_override: Option<String>,
+    page_limits: crate::utils::PageLimits,
     token: String,
 
     client: reqwest_middleware::ClientWithMiddleware,
@@ -970,27 +1024,15 @@ impl Client {{
     where
         T: ToString,
     {{
-        let client = reqwest::Client::builder().redirect(reqwest::redirect::Policy::none()).build();
-        let retry_policy = reqwest_retry::policies::ExponentialBackoff::builder().build_with_max_retries(3);
+        let client = crate::ClientBuilder::default().build();
         match client {{
-            Ok(c) => {{
-                let client = reqwest_middleware::ClientBuilder::new(c)
-                    // Trace HTTP requests. See the tracing crate to make use of these traces.
-                    .with(reqwest_tracing::TracingMiddleware::default())
-                    // Retry failed requests.
-                    .with(
-                        reqwest_conditional_middleware::ConditionalMiddleware::new(
-                            reqwest_retry::RetryTransientMiddleware::new_with_policy(retry_policy),
-                            |req: &reqwest::Request| req.try_clone().is_some()
-                        )
-                    )
-                    .build();
-
+            Ok(client) => {{
                 {server_to_host}
 
                 Client {{
                     host,
                     host_override: None,
+                    page_limits: Default::default(),
                     token: token.to_string(),
 
                     client,
@@ -1020,6 +1062,30 @@ impl Client {{
         self.host_override.as_deref()
     }}
 
+    /// Replaces the HTTP stack of the client with one built by `builder`, to configure
+    /// its retries, timeouts and middleware.
+    pub fn with_client_builder(&mut self, builder: crate::ClientBuilder) -> ClientResult<&mut Self>
+    {{
+        self.client = builder.build()?;
+        Ok(self)
+    }}
+
+    /// Stop walking the pages of a paginated endpoint after `max_pages` pages have been
+    /// fetched. The first page is always fetched, so a limit of zero behaves like one.
+    pub fn with_max_pages(&mut self, max_pages: usize) -> &mut Self
+    {{
+        self.page_limits.max_pages = Some(max_pages.max(1));
+        self
+    }}
+
+    /// Stop walking the pages of a paginated endpoint once `max_items` items have been
+    /// returned.
+    pub fn with_max_items(&mut self, max_items: usize) -> &mut Self
+    {{
+        self.page_limits.max_items = Some(max_items);
+        self
+    }}
+
     pub(crate) fn url(&self, path: &str, host: Option<&str>) -> String {{
         format!("{{}}{{}}", self.get_host_override().or(host).unwrap_or(self.host.as_str()), path)
     }}
@@ -1086,6 +1152,30 @@ fn get_shared_functions(proper_name: &str, add_post_header: &str) -> String {
         get_shared_raw_functions_without_refresh(&bearer, &post_header_args)
     };
 
+    // Slack reports failed calls with a 200 and `"ok": false` in the body.
+    let check_ok = if proper_name == "Slack" {
+        r#"
+    if let Some(error) = ClientError::from_slack_response(status, &headers, &response_body) {
+        return Err(error);
+    }"#
+    } else {
+        ""
+    };
+
+    // The streams of Ramp listings end on a 404 like their `get_all` do.
+    let stream_fetch = if proper_name == "Ramp" {
+        r#"match self.get(&uri, Message::default()).await {
+                    Ok(response) => response,
+                    // Ramp answers the `next` url that follows its last page with a 404.
+                    Err(ClientError::ApiError { status: http::StatusCode::NOT_FOUND, .. })
+                    | Err(ClientError::HttpError { status: http::StatusCode::NOT_FOUND, .. })
+                        if pages.followed() => return Ok(None),
+                    Err(e) => return Err(e),
+                }"#
+    } else {
+        "self.get(&uri, Message::default()).await?"
+    };
+
     format!(
         r#"
 {}
@@ -1105,6 +1195,7 @@ async fn request<Out>(
     let headers = response.headers().clone();
 
     let response_body = response.bytes().await?;
+{check_ok}
 
     if status.is_success() {{
         log::debug!("Received successful response. Read payload.");
@@ -1115,13 +1206,7 @@ async fn request<Out>(
         }};
         Ok(crate::Response::new(status, headers, parsed_response))
     }} else {{
-        let error = if response_body.is_empty() {{
-            ClientError::HttpError{{status, headers, error: "empty response".into()}}
-        }} else {{
-            ClientError::HttpError{{status, headers, error: String::from_utf8_lossy(&response_body).into()}}
-        }};
-
-        Err(error)
+        Err(ClientError::from_response(status, headers, &response_body))
     }}
 }}
 
@@ -1147,6 +1232,7 @@ where
         .and_then(crate::utils::next_link);
 
     let response_body = response.bytes().await?;
+{check_ok}
 
     if status.is_success() {{
         log::debug!("Received successful response. Read payload.");
@@ -1158,12 +1244,7 @@ where
         }};
         Ok((link, crate::Response::new(status, headers, parsed_response)))
     }} else {{
-        let error = if response_body.is_empty() {{
-            ClientError::HttpError{{status, headers, error: "empty response".into()}}
-        }} else {{
-            ClientError::HttpError{{status, headers, error: String::from_utf8_lossy(&response_body).into()}}
-        }};
-        Err(error)
+        Err(ClientError::from_response(status, headers, &response_body))
     }}
 }}
 
@@ -1201,6 +1282,7 @@ async fn post_form<Out>(
     let headers = response.headers().clone();
 
     let response_body = response.bytes().await?;
+{check_ok}
 
     if status.is_success() {{
         log::debug!("Received successful response. Read payload.");
@@ -1215,13 +1297,7 @@ async fn post_form<Out>(
         }};
         Ok(crate::Response::new(status, headers, parsed_response))
     }} else {{
-        let error = if response_body.is_empty() {{
-            ClientError::HttpError{{status, headers, error: "empty response".into()}}
-        }} else {{
-            ClientError::HttpError{{status, headers, error: String::from_utf8_lossy(&response_body).into()}}
-        }};
-
-        Err(error)
+        Err(ClientError::from_response(status, headers, &response_body))
     }}
 }}
 
@@ -1258,6 +1334,7 @@ async fn request_with_accept_mime<Out>(
     let headers = response.headers().clone();
 
     let response_body = response.bytes().await?;
+{check_ok}
 
     if status.is_success() {{
         log::debug!("Received successful response. Read payload.");
@@ -1272,13 +1349,7 @@ async fn request_with_accept_mime<Out>(
         }};
         Ok(crate::Response::new(status, headers, parsed_response))
     }} else {{
-        let error = if response_body.is_empty() {{
-            ClientError::HttpError{{status, headers, error: "empty response".into()}}
-        }} else {{
-            ClientError::HttpError{{status, headers, error: String::from_utf8_lossy(&response_body).into()}}
-        }};
-
-        Err(error)
+        Err(ClientError::from_response(status, headers, &response_body))
     }}
 }}
 
@@ -1335,6 +1406,7 @@ async fn request_with_mime<Out>(
     let headers = response.headers().clone();
 
     let response_body = response.bytes().await?;
+{check_ok}
 
     if status.is_success() {{
         log::debug!("Received successful response. Read payload.");
@@ -1345,13 +1417,7 @@ async fn request_with_mime<Out>(
         }};
         Ok(crate::Response::new(status, headers, parsed_response))
     }} else {{
-        let error = if response_body.is_empty() {{
-            ClientError::HttpError{{status, headers, error: "empty response".into()}}
-        }} else {{
-            ClientError::HttpError{{status, headers, error: String::from_utf8_lossy(&response_body).into()}}
-        }};
-
-        Err(error)
+        Err(ClientError::from_response(status, headers, &response_body))
     }}
 }}
 
@@ -1391,7 +1457,76 @@ where
     self.unfold(uri).await
 }}
 
+/// Lazily walk the pages of a `Link` header paginated endpoint, yielding
+/// each item as it is received.
+#[allow(dead_code)]
+fn get_all_pages_stream<'a, D>(&'a self, uri: String) -> impl futures::Stream<Item = ClientResult<D>> + Unpin + 'a
+where
+    D: serde::de::DeserializeOwned + 'static + Send + 'a,
+{{
+    use futures::TryStreamExt;
+
+    Box::pin(
+        futures::stream::try_unfold(
+            (Some(uri), crate::utils::Pages::new(self.page_limits)),
+            move |(uri, mut pages)| async move {{
+                let uri = match uri {{
+                    Some(uri) if pages.visit(&uri)? => uri,
+                    _ => return Ok(None),
+                }};
+
+                let (link, mut response) = self.get_pages::<D>(&uri).await?;
+                let more = pages.keep(&mut response.body);
+                let items = futures::stream::iter(response.body.into_iter().map(Ok));
+
+                ClientResult::Ok(Some((items, (link.filter(|_| more).map(|l| l.0), pages))))
+            }},
+        )
+        .try_flatten(),
+    )
+}}
+
+/// Lazily walk the pages of an endpoint that paginates through its response
+/// body, yielding each item as it is received.
+///
+/// `next` splits every page into its items and the url of the page that
+/// follows it, if there is one.
+#[allow(dead_code)]
+fn get_pages_stream<'a, R, D, F>(&'a self, uri: String, next: F) -> impl futures::Stream<Item = ClientResult<D>> + Unpin + 'a
+where
+    R: serde::de::DeserializeOwned + 'static + Send,
+    D: 'a,
+    F: FnMut(R) -> (Vec<D>, Option<String>) + 'a,
+{{
+    use futures::TryStreamExt;
+
+    Box::pin(
+        futures::stream::try_unfold(
+            (Some(uri), next, crate::utils::Pages::new(self.page_limits)),
+            move |(uri, mut next, mut pages)| async move {{
+                let uri = match uri {{
+                    Some(uri) if pages.visit(&uri)? => uri,
+                    _ => return Ok(None),
+                }};
+
+                let response: crate::Response<R> = {stream_fetch};
+                let (mut items, uri) = next(response.body);
+                let more = pages.keep(&mut items);
+                let items = futures::stream::iter(items.into_iter().map(Ok));
+
+                ClientResult::Ok(Some((items, (uri.filter(|_| more), next, pages))))
+            }},
+        )
+        .try_flatten(),
+    )
+}}
+
 /// "unfold" paginated results of a vector of items
+///
+/// Pages are followed until the server stops returning a next link, returns an
+/// empty page or the client's page limits are reached. The walk fails with
+/// `ClientError::IncompletePages` if the server points back at a page that was
+/// already fetched.
 #[allow(dead_code)]
 async fn unfold<D>(
     &self,
@@ -1400,18 +1535,22 @@ async fn unfold<D>(
 where
     D: serde::de::DeserializeOwned + 'static + Send,
 {{
+    let mut pages = crate::utils::Pages::new(self.page_limits);
+    pages.visit(uri)?;
+
     let mut global_items = Vec::new();
-    let (new_link, mut response) = self.get_pages(uri).await?;
-    let mut link = new_link;
-    while !response.body.is_empty() {{
+    let (mut link, mut response) = self.get_pages(uri).await?;
+    loop {{
+        let more = pages.keep(&mut response.body);
         global_items.append(&mut response.body);
         // We need to get the next link.
-        if let Some(url) = &link {{
-            let url = reqwest::Url::parse(&url.0)?;
-            let (new_link, new_response) = self.get_pages_url(&url).await?;
-            link = new_link;
-            response = new_response;
-        }}
+        let url = match link {{
+            Some(url) if more && pages.visit(&url.0)? => reqwest::Url::parse(&url.0)?,
+            _ => break,
+        }};
+        let (new_link, new_response) = self.get_pages_url(&url).await?;
+        link = new_link;
+        response = new_response;
     }}
 
     Ok(Response::new(response.status, response.headers, global_items))
@@ -1644,7 +1783,7 @@ async fn request_raw(
     )
 }
 
-fn get_token_auth_template<S: AsRef<str>>(consent_pattern: S) -> String {
+fn get_token_auth_template<S: AsRef<str>>(consent_pattern: S, refresh_prelude: &str) -> String {
     format!(
         r#"
 /// Return a user consent url with an optional set of scopes.
@@ -1668,6 +1807,7 @@ pub fn user_consent_url(&self, scopes: &[String]) -> String {{
 /// Refresh an access token from a refresh token. Client must have a refresh token
 /// for this to work.
 pub async fn refresh_access_token(&self) -> ClientResult<AccessToken> {{
+    {}
     let response = {{
         let refresh_token = &self.token.read().await.refresh_token;
 
@@ -1749,7 +1889,8 @@ pub async fn get_access_token(&mut self, code: &str, state: &str) -> ClientResul
 
     Ok(t)
 }}"#,
-        consent_pattern.as_ref()
+        consent_pattern.as_ref(),
+        refresh_prelude
     )
 }
 
@@ -1812,6 +1953,7 @@ const TOKEN_ENDPOINT: &str = "https://{}";
 pub struct Client {{
     host: String,
     host_override: Option<String>,
+    page_limits: crate::utils::PageLimits,
     token: String,
     client_id: String,
     client_secret: String,
@@ -1838,27 +1980,15 @@ impl Client {{
         K: ToString,
         T: ToString,
     {{
-        let client = reqwest::Client::builder().redirect(reqwest::redirect::Policy::none()).build();
-        let retry_policy = reqwest_retry::policies::ExponentialBackoff::builder().build_with_max_retries(3);
+        let client = crate::ClientBuilder::default().build();
         match client {{
-            Ok(c) => {{
-                let client = reqwest_middleware::ClientBuilder::new(c)
-                    // Trace HTTP requests. See the tracing crate to make use of these traces.
-                    .with(reqwest_tracing::TracingMiddleware::default())
-                    // Retry failed requests.
-                    .with(
-                        reqwest_conditional_middleware::ConditionalMiddleware::new(
-                            reqwest_retry::RetryTransientMiddleware::new_with_policy(retry_policy),
-                            |req: &reqwest::Request| req.try_clone().is_some()
-                        )
-                    )
-                    .build();
-
+            Ok(client) => {{
                 {server_to_host}
 
                 Client {{
                     host,
                     host_override: None,
+                    page_limits: Default::default(),
                     client_id: client_id.to_string(),
                     client_secret: client_secret.to_string(),
                     token: token.to_string(),
@@ -1890,6 +2020,30 @@ impl Client {{
         self.host_override.as_deref()
     }}
 
+    /// Replaces the HTTP stack of the client with one built by `builder`, to configure
+    /// its retries, timeouts and middleware.
+    pub fn with_client_builder(&mut self, builder: crate::ClientBuilder) -> ClientResult<&mut Self>
+    {{
+        self.client = builder.build()?;
+        Ok(self)
+    }}
+
+    /// Stop walking the pages of a paginated endpoint after `max_pages` pages have been
+    /// fetched. The first page is always fetched, so a limit of zero behaves like one.
+    pub fn with_max_pages(&mut self, max_pages: usize) -> &mut Self
+    {{
+        self.page_limits.max_pages = Some(max_pages.max(1));
+        self
+    }}
+
+    /// Stop walking the pages of a paginated endpoint once `max_items` items have been
+    /// returned.
+    pub fn with_max_items(&mut self, max_items: usize) -> &mut Self
+    {{
+        self.page_limits.max_items = Some(max_items);
+        self
+    }}
+
     pub(crate) fn url(&self, path: &str, host: Option<&str>) -> String {{
         format!("{{}}{{}}", self.get_host_override().or(host).unwrap_or(self.host.as_str()), path)
     }}
@@ -0,0 +1,768 @@
+const HEADER: &str = r#"//! The errors returned by the API.
+
+use std::fmt;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+"#;
+
+/// Renders `ClientError::from_response`, which only takes the response for a rate
+/// limited one on a 429, or on a 403 with the rate limit used up for the APIs that
+/// answer with one once it is.
+fn footer(proper_name: &str) -> String {
+    let (rate_limited, rate_limited_docs) = if proper_name == "GitHub" {
+        (
+            "status == http::StatusCode::TOO_MANY_REQUESTS\n            || (status == http::StatusCode::FORBIDDEN && rate_limit.remaining == Some(0))",
+            ", as are those forbidden once the\n    /// rate limit is used up",
+        )
+    } else {
+        ("status == http::StatusCode::TOO_MANY_REQUESTS", "")
+    };
+
+    format!(
+        r#"
+impl crate::ClientError {{
+    /// Turns an unsuccessful response into an error, parsing its body into an `ApiError`
+    /// when it holds one. Responses to requests that were rate limited become
+    /// `ClientError::RateLimited`{rate_limited_docs}. Those keep their `ApiError` as well.
+    pub(crate) fn from_response(
+        status: http::StatusCode,
+        headers: reqwest::header::HeaderMap,
+        body: &[u8],
+    ) -> Self {{
+        let rate_limit = crate::RateLimitInfo::from_headers(&headers).unwrap_or_default();
+        if {rate_limited} {{
+            return crate::ClientError::RateLimited {{
+                status,
+                duration: rate_limit.wait().map(|wait| wait.as_secs()),
+                info: rate_limit,
+                error: ApiError::from_body(body).map(Box::new),
+                headers,
+            }};
+        }}
+
+        if body.is_empty() {{
+            return crate::ClientError::HttpError {{
+                status,
+                headers,
+                error: "empty response".into(),
+            }};
+        }}
+
+        match ApiError::from_body(body) {{
+            Some(error) => crate::ClientError::ApiError {{
+                status,
+                headers,
+                error: Box::new(error),
+            }},
+            None => crate::ClientError::HttpError {{
+                status,
+                headers,
+                error: String::from_utf8_lossy(body).into(),
+            }},
+        }}
+    }}
+}}
+"#
+    )
+}
+
+const GITHUB: &str = r#"
+/// An error returned by the GitHub API.
+///
+/// FROM: <https://docs.github.com/en/rest/overview/resources-in-the-rest-api#client-errors>
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default, JsonSchema)]
+pub struct ApiError {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub message: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub documentation_url: String,
+    /// The validation errors of a `422 Unprocessable Entity` response.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<ApiErrorDetail>,
+}
+
+/// A validation error of a resource sent to the GitHub API.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default, JsonSchema)]
+pub struct ApiErrorDetail {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub resource: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub field: String,
+    /// What is wrong with the field: `missing`, `missing_field`, `invalid`,
+    /// `already_exists`, `unprocessable` or `custom`.
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub code: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub message: String,
+}
+
+impl ApiError {
+    fn from_body(body: &[u8]) -> Option<Self> {
+        serde_json::from_slice::<Self>(body)
+            .ok()
+            .filter(|error| !error.message.is_empty())
+    }
+}
+
+impl fmt::Display for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)?;
+        for error in &self.errors {
+            if error.message.is_empty() {
+                write!(f, "; {}.{}: {}", error.resource, error.field, error.code)?;
+            } else {
+                write!(f, "; {}", error.message)?;
+            }
+        }
+        Ok(())
+    }
+}
+"#;
+
+const STRIPE: &str = r#"
+/// An error returned by the Stripe API.
+///
+/// FROM: <https://stripe.com/docs/api/errors>
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default, JsonSchema)]
+pub struct ApiError {
+    /// The type of error: `api_error`, `card_error`, `idempotency_error` or
+    /// `invalid_request_error`.
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "type"
+    )]
+    pub type_: String,
+    /// A short string identifying the error, such as `resource_missing`.
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub code: String,
+    /// Why the card issuer declined a card error, such as `insufficient_funds`.
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub decline_code: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub message: String,
+    /// The parameter the error relates to.
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub param: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub doc_url: String,
+    /// The ID of the failed charge, for card errors.
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub charge: String,
+}
+
+impl ApiError {
+    fn from_body(body: &[u8]) -> Option<Self> {
+        #[derive(Deserialize)]
+        struct Envelope {
+            error: ApiError,
+        }
+
+        serde_json::from_slice::<Envelope>(body)
+            .ok()
+            .map(|envelope| envelope.error)
+    }
+}
+
+impl fmt::Display for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.code.as_str(), self.decline_code.as_str()) {
+            ("", _) => write!(f, "{}", self.message),
+            (code, "") => write!(f, "{} ({})", self.message, code),
+            (code, decline_code) => write!(f, "{} ({}: {})", self.message, code, decline_code),
+        }
+    }
+}
+"#;
+
+const SLACK: &str = r#"
+/// An error returned by the Slack API, in a response with `"ok": false`.
+///
+/// FROM: <https://api.slack.com/web#evaluating_responses>
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default, JsonSchema)]
+pub struct SlackError {
+    /// The error code, such as `channel_not_found`.
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub error: String,
+    /// The scopes the method needs, for `missing_scope` errors.
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub needed: String,
+    /// The scopes the token has, for `missing_scope` errors.
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub provided: String,
+    /// The warnings about the call, separated by commas, such as `missing_charset`.
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub warning: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub response_metadata: Option<ApiErrorMetadata>,
+}
+
+/// The details Slack attaches to an error.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default, JsonSchema)]
+pub struct ApiErrorMetadata {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub messages: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<String>,
+}
+
+/// The errors of the Slack API are `SlackError`s.
+pub type ApiError = SlackError;
+
+impl SlackError {
+    fn from_body(body: &[u8]) -> Option<Self> {
+        serde_json::from_slice::<Self>(body)
+            .ok()
+            .filter(|error| !error.error.is_empty())
+    }
+}
+
+impl fmt::Display for SlackError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.error)?;
+        if !self.needed.is_empty() {
+            write!(f, " (needed: {}, provided: {})", self.needed, self.provided)?;
+        }
+        Ok(())
+    }
+}
+
+impl crate::ClientError {
+    /// Turns a successful response that reports a failed call into an error.
+    ///
+    /// Calls that were rate limited become `ClientError::RateLimited`, which waits for as
+    /// long as the `Retry-After` header of the response asks, if it has one.
+    pub(crate) fn from_slack_response(
+        status: http::StatusCode,
+        headers: &reqwest::header::HeaderMap,
+        body: &[u8],
+    ) -> Option<Self> {
+        #[derive(Deserialize)]
+        struct Envelope {
+            ok: Option<bool>,
+            #[serde(flatten)]
+            error: SlackError,
+        }
+
+        if !status.is_success() {
+            return None;
+        }
+
+        match serde_json::from_slice::<Envelope>(body).ok()? {
+            Envelope {
+                ok: Some(false),
+                error,
+            } if error.error == "ratelimited" => {
+                let rate_limit = crate::RateLimitInfo::from_headers(headers).unwrap_or_default();
+                Some(crate::ClientError::RateLimited {
+                    status,
+                    headers: headers.clone(),
+                    duration: rate_limit.wait().map(|wait| wait.as_secs()),
+                    info: rate_limit,
+                    error: Some(Box::new(error)),
+                })
+            }
+            Envelope {
+                ok: Some(false),
+                error,
+            } => Some(crate::ClientError::ApiError {
+                status,
+                headers: headers.clone(),
+                error: Box::new(error),
+            }),
+            _ => None,
+        }
+    }
+}
+"#;
+
+const GOOGLE: &str = r#"
+/// An error returned by a Google API.
+///
+/// FROM: <https://cloud.google.com/apis/design/errors>
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default, JsonSchema)]
+pub struct ApiError {
+    /// The HTTP status code of the error.
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub code: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub message: String,
+    /// The canonical error code, such as `NOT_FOUND` or `PERMISSION_DENIED`.
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub status: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<ApiErrorDetail>,
+}
+
+/// One of the errors behind an error returned by a Google API.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default, JsonSchema)]
+pub struct ApiErrorDetail {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub domain: String,
+    /// Why the request failed, such as `notFound` or `rateLimitExceeded`.
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub reason: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub message: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub location: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "locationType"
+    )]
+    pub location_type: String,
+}
+
+impl ApiError {
+    fn from_body(body: &[u8]) -> Option<Self> {
+        #[derive(Deserialize)]
+        struct Envelope {
+            error: ApiError,
+        }
+
+        serde_json::from_slice::<Envelope>(body)
+            .ok()
+            .map(|envelope| envelope.error)
+    }
+}
+
+impl fmt::Display for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.errors.first() {
+            Some(error) if !error.reason.is_empty() => {
+                write!(f, "{} ({})", self.message, error.reason)
+            }
+            _ => write!(f, "{}", self.message),
+        }
+    }
+}
+"#;
+
+const ZOOM: &str = r#"
+/// An error returned by the Zoom API.
+///
+/// FROM: <https://marketplace.zoom.us/docs/api-reference/error-definitions>
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default, JsonSchema)]
+pub struct ApiError {
+    /// The Zoom error code, such as `1001` for a user that does not exist.
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub code: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub message: String,
+}
+
+impl ApiError {
+    fn from_body(body: &[u8]) -> Option<Self> {
+        serde_json::from_slice::<Self>(body)
+            .ok()
+            .filter(|error| error.code != 0 || !error.message.is_empty())
+    }
+}
+
+impl fmt::Display for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({})", self.message, self.code)
+    }
+}
+"#;
+
+const OKTA: &str = r#"
+/// An error returned by the Okta API.
+///
+/// FROM: <https://developer.okta.com/docs/reference/error-codes/>
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default, JsonSchema)]
+pub struct ApiError {
+    /// The Okta error code, such as `E0000007` for a resource that was not found.
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "errorCode"
+    )]
+    pub error_code: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "errorSummary"
+    )]
+    pub error_summary: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "errorLink"
+    )]
+    pub error_link: String,
+    /// The ID of the failed request, to hand over to Okta support.
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "errorId"
+    )]
+    pub error_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        rename = "errorCauses"
+    )]
+    pub error_causes: Vec<ApiErrorCause>,
+}
+
+/// One of the causes of an error returned by the Okta API.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default, JsonSchema)]
+pub struct ApiErrorCause {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "errorSummary"
+    )]
+    pub error_summary: String,
+}
+
+impl ApiError {
+    fn from_body(body: &[u8]) -> Option<Self> {
+        serde_json::from_slice::<Self>(body)
+            .ok()
+            .filter(|error| !error.error_code.is_empty())
+    }
+}
+
+impl fmt::Display for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({})", self.error_summary, self.error_code)?;
+        for cause in &self.error_causes {
+            write!(f, "; {}", cause.error_summary)?;
+        }
+        Ok(())
+    }
+}
+"#;
+
+const SENDGRID: &str = r#"
+/// An error returned by the SendGrid API.
+///
+/// FROM: <https://docs.sendgrid.com/api-reference/how-to-use-the-sendgrid-v3-api/responses>
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default, JsonSchema)]
+pub struct ApiError {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<ApiErrorDetail>,
+}
+
+/// One of the errors returned by the SendGrid API.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default, JsonSchema)]
+pub struct ApiErrorDetail {
+    /// The field of the request the error relates to.
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub field: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub message: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub error_id: String,
+}
+
+impl ApiError {
+    fn from_body(body: &[u8]) -> Option<Self> {
+        serde_json::from_slice::<Self>(body)
+            .ok()
+            .filter(|error| !error.errors.is_empty())
+    }
+}
+
+impl fmt::Display for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let errors = self
+            .errors
+            .iter()
+            .map(|error| {
+                if error.field.is_empty() {
+                    error.message.clone()
+                } else {
+                    format!("{}: {}", error.field, error.message)
+                }
+            })
+            .collect::<Vec<_>>();
+        write!(f, "{}", errors.join("; "))
+    }
+}
+"#;
+
+const DOCUSIGN: &str = r#"
+/// An error returned by the DocuSign API.
+///
+/// FROM: <https://developers.docusign.com/docs/esign-rest-api/esign101/error-codes/>
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default, JsonSchema)]
+pub struct ApiError {
+    /// The DocuSign error code, such as `ENVELOPE_DOES_NOT_EXIST`.
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "errorCode"
+    )]
+    pub error_code: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub message: String,
+}
+
+impl ApiError {
+    fn from_body(body: &[u8]) -> Option<Self> {
+        serde_json::from_slice::<Self>(body)
+            .ok()
+            .filter(|error| !error.error_code.is_empty())
+    }
+}
+
+impl fmt::Display for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({})", self.message, self.error_code)
+    }
+}
+"#;
+
+const PROBLEM_DETAILS: &str = r#"
+/// An error returned by the API, as problem details.
+///
+/// FROM: <https://www.rfc-editor.org/rfc/rfc7807>
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default, JsonSchema)]
+pub struct ApiError {
+    /// A URI identifying the type of problem.
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "type"
+    )]
+    pub type_: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub title: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub status: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub detail: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub instance: String,
+}
+
+impl ApiError {
+    fn from_body(body: &[u8]) -> Option<Self> {
+        serde_json::from_slice::<Self>(body)
+            .ok()
+            .filter(|error| !error.title.is_empty())
+    }
+}
+
+impl fmt::Display for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.detail.is_empty() {
+            write!(f, "{}", self.title)
+        } else {
+            write!(f, "{}: {}", self.title, self.detail)
+        }
+    }
+}
+"#;
+
+const GENERIC: &str = r#"
+/// An error returned by the API.
+///
+/// The API does not document a common shape for its errors, so this holds the whole
+/// body of the response along with the message found in it.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default, JsonSchema)]
+pub struct ApiError {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub message: String,
+    #[serde(default)]
+    pub body: serde_json::Value,
+}
+
+impl ApiError {
+    fn from_body(body: &[u8]) -> Option<Self> {
+        let body = serde_json::from_slice::<serde_json::Value>(body).ok()?;
+        let message = ["message", "error", "errors", "error_description", "detail", "meta"]
+            .iter()
+            .filter_map(|key| body.get(key))
+            .find_map(|value| {
+                value.as_str().or_else(|| {
+                    value
+                        .get("message")
+                        .or_else(|| value.get("msg"))
+                        .and_then(|message| message.as_str())
+                })
+            })
+            .map(|message| message.to_string())
+            .unwrap_or_else(|| body.to_string());
+
+        Some(ApiError { message, body })
+    }
+}
+
+impl fmt::Display for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+"#;
+
+/*
+ * Each API reports errors in its own shape, which its spec seldom describes in full, so
+ * the error model of each one is written by hand.
+ */
+pub fn generate_errors(proper_name: &str) -> String {
+    let model = match proper_name {
+        "GitHub" => GITHUB,
+        "Stripe" => STRIPE,
+        "Slack" => SLACK,
+        "Zoom" => ZOOM,
+        "Okta" => OKTA,
+        "SendGrid" => SENDGRID,
+        "DocuSign" => DOCUSIGN,
+        "MailChimp" | "Rev.ai" => PROBLEM_DETAILS,
+        name if name.starts_with("Google") => GOOGLE,
+        _ => GENERIC,
+    };
+
+    format!("{}{}{}", HEADER, model, footer(proper_name))
+}
@@ -13,6 +13,10 @@ use crate::{
 pub struct FileOutput {
     pub head: String,
     pub impl_content: String,
+    /// The same as `head`, for the module of the blocking client.
+    pub blocking_head: String,
+    /// The same as `impl_content`, for the module of the blocking client.
+    pub blocking_impl_content: String,
 }
 
 impl FileOutput {
@@ -23,6 +27,14 @@ impl FileOutput {
     fn add_content(&mut self, content: &str) {
         self.impl_content.push_str(content);
     }
+
+    fn add_blocking_head(&mut self, head: &str) {
+        self.blocking_head.push_str(head);
+    }
+
+    fn add_blocking_content(&mut self, content: &str) {
+        self.blocking_impl_content.push_str(content);
+    }
 }
 
 /*
@@ -212,10 +224,28 @@ pub fn generate_files(
                             // TODO: fix this later.
                             (None, None)
                         } else if ct == "application/x-www-form-urlencoded" {
-                            println!("got application/x-www-form-urlencoded for {}", oid);
-                            // Skip it for now.
-                            // TODO: fix this later.
-                            (None, None)
+                            // Form bodies get a typed request struct, just like json bodies,
+                            // but are serialized with serde_urlencoded.
+                            if let Some(s) = &mt.schema {
+                                let object_name = format!("{} request", oid_to_object_name(&od));
+                                let id = ts.select(Some(&object_name), s, "")?;
+                                let et = ts.id_to_entry.get(&id).unwrap();
+                                let is_empty = if let crate::TypeDetails::Object(p, _) = &et.details
+                                {
+                                    p.is_empty()
+                                } else {
+                                    false
+                                };
+
+                                if is_empty {
+                                    (None, None)
+                                } else {
+                                    let rt = ts.render_type(&id, false)?;
+                                    (Some(format!("&{}", rt)), Some("form".to_string()))
+                                }
+                            } else {
+                                (None, None)
+                            }
                         } else if let Some(s) = &mt.schema {
                             let tid = ts.select(None, s, "")?;
                             let rt = ts.render_type(&tid, false)?;
@@ -234,7 +264,30 @@ pub fn generate_files(
                     let object_name = format!("{} request", oid_to_object_name(&od));
                     let id = ts.select_ref(Some(&clean_name(&object_name)), reference)?;
                     let rt = ts.render_type(&id, false)?;
-                    (Some(format!("&{}", rt)), Some("json".to_string()))
+
+                    // Referenced request bodies can be form encoded as well, so check the
+                    // content type of the component we are pointing at.
+                    let is_form = api
+                        .components
+                        .as_ref()
+                        .and_then(|c| {
+                            c.request_bodies
+                                .get(reference.trim_start_matches("#/components/requestBodies/"))
+                        })
+                        .and_then(|rb| rb.item().ok())
+                        .map(|rb| {
+                            rb.content.len() == 1
+                                && rb.content.contains_key("application/x-www-form-urlencoded")
+                        })
+                        .unwrap_or(false);
+
+                    if is_form {
+                        body_content_type_header =
+                            Some("application/x-www-form-urlencoded".to_string());
+                        (Some(format!("&{}", rt)), Some("form".to_string()))
+                    } else {
+                        (Some(format!("&{}", rt)), Some("json".to_string()))
+                    }
                 } else {
                     (None, None)
                 }
@@ -268,7 +321,7 @@ pub fn generate_files(
              * Generate the URL for the request.
              */
             let tmp = parse(p)?;
-            let template = tmp.compile(query_params, &server_arg);
+            let template = tmp.compile(query_params.clone(), &server_arg);
 
             /*
              * Get the response type.
@@ -313,6 +366,11 @@ pub fn generate_files(
                 if let crate::TypeDetails::OneOf(one_of, _) = &te.details {
                     for itid in one_of {
                         let rt = ts.render_type(itid, false)?;
+                        let fn_name = to_snake_case(&struct_name(&format!(
+                            "{}_{}",
+                            oid.trim_start_matches(&tag).trim_start_matches('_'),
+                            to_snake_case(&rt.replace("crate::types::", ""))
+                        )));
                         out.add_content(&print_fn(
                             &docs,
                             &bounds,
@@ -321,11 +379,15 @@ pub fn generate_files(
                             &rt,
                             &template,
                             &fn_inner,
-                            &to_snake_case(&struct_name(&format!(
-                                "{}_{}",
-                                oid.trim_start_matches(&tag).trim_start_matches('_'),
-                                to_snake_case(&rt.replace("crate::types::", ""))
-                            ))),
+                            &fn_name,
+                        ));
+                        out.add_blocking_content(&get_blocking_fn(
+                            &tag,
+                            &bounds,
+                            &fn_params_str,
+                            &body_param,
+                            &rt,
+                            &fn_name,
                         ));
                     }
                 }
@@ -384,6 +446,45 @@ pub fn generate_files(
                 &fn_inner,
                 &fn_name,
             ));
+            out.add_blocking_content(&get_blocking_fn(
+                &tag,
+                &bounds,
+                &fn_params_str,
+                &body_param,
+                &frt,
+                &fn_name,
+            ));
+
+            // Adding an optional parameter to the function is a breaking change, so
+            // let's also give it a builder that only sets the parameters needed.
+            let builder_name = format!("{}_request", fn_name);
+            let required_params = get_required_params(o, parameters, op.parameters.clone())?;
+            if bounds.is_empty()
+                && query_params.keys().any(|n| !required_params.contains(n))
+                && !fn_names.contains(&(builder_name.clone() + &tag))
+            {
+                let docs = get_fn_docs_builder(o, m, p, &fn_name)?;
+                if let Some(builder) = get_fn_builder(
+                    &tag,
+                    p,
+                    &fn_name,
+                    &builder_name,
+                    &fn_params_str,
+                    &required_params,
+                    &query_params,
+                    &body_param,
+                    &frt,
+                    &server_arg,
+                    &fn_inner,
+                )? {
+                    fn_names.push(builder_name + &tag);
+                    out.add_head(&builder.head);
+                    out.add_content(&docs);
+                    out.add_content(&builder.impl_content);
+                    out.add_blocking_head(&builder.blocking_head);
+                    out.add_blocking_content(&builder.blocking_impl_content);
+                }
+            }
 
             // If we are returning a list of things and we have page, etc as
             // params, let's get all the pages.
@@ -462,6 +563,40 @@ pub fn generate_files(
                     &fn_inner,
                     &fn_name,
                 ));
+                out.add_blocking_content(&get_blocking_fn(
+                    &tag,
+                    &bounds,
+                    &fn_params_str,
+                    &body_param,
+                    &frt,
+                    &fn_name,
+                ));
+
+                // And a lazy version of it that only fetches the next page once
+                // the caller gets to it.
+                if bounds.is_empty() && body_param.is_none() {
+                    let docs = get_fn_docs_stream(o, m, p, &fn_name)?;
+                    let stream_inner =
+                        get_fn_stream_inner(proper_name, &response_type, &pagination_property)?;
+
+                    let mut content = docs;
+                    content.push_str(&format!("pub fn {}_stream(&self,", fn_name));
+                    if !fn_params_str.is_empty() {
+                        content.push_str(&fn_params_str.join(" "));
+                    }
+                    content.push_str(&format!(
+                        ") -> impl futures::Stream<Item = ClientResult<{}>> + Unpin + '_ {{",
+                        frt.strip_prefix("Vec<")
+                            .and_then(|t| t.strip_suffix('>'))
+                            .unwrap_or(&frt)
+                    ));
+                    content.push_str(&template);
+                    content.push_str(&stream_inner);
+                    content.push('}');
+
+                    fn_names.push(format!("{}_stream{}", fn_name, tag));
+                    out.add_content(&content);
+                }
             }
 
             // Add this to our map of functions based on the tag name.
@@ -843,6 +978,8 @@ fn get_fn_inner(
     let body = if let Some(f) = &body_func {
         if f == "json" {
             "Some(reqwest::Body::from(serde_json::to_vec(body)?))"
+        } else if f == "form" {
+            "Some(reqwest::Body::from(serde_urlencoded::to_string(body)?))"
         } else {
             "Some(body.into())"
         }
@@ -856,251 +993,8 @@ fn get_fn_inner(
 
     if all_pages && pagination_property.is_empty() {
         return Ok(format!("self.client.get_all_pages(&url, crate::Message {{ body: {}, content_type: None }}).await", body));
-    } else if all_pages && proper_name.starts_with("Stripe") {
-        // We will do a custom function here.
-        let inner = format!(
-            r#"let crate::Response::<{}> {{ mut status, mut headers, mut body }} = self.client.{}(&url, crate::Message {{ body: {}, content_type: None }}).await?;
-
-            let mut {} = body.{};
-            let mut has_more = body.has_more;
-            let mut page = "".to_string();
-
-            // Paginate if we should.
-            while has_more {{
-                if !{}.is_empty() {{
-                   let last = {}.last().unwrap();
-                   let j = serde_json::json!(last);
-                   if let serde_json::Value::Object(o) = j {{
-                       if let Some(serde_json::Value::String(s)) = o.get("id") {{
-                            page = s.to_string();
-                       }}
-                   }}
-                }}
-
-                if !url.contains('?') {{
-                    crate::Response::<{}> {{ status, headers, body }} = self.client.{}(&format!("{{}}?startng_after={{}}", url, page), crate::Message {{ body: {}, content_type: None }}).await?;
-                }} else {{
-                    crate::Response::<{}> {{ status, headers, body }} = self.client.{}(&format!("{{}}&starting_after={{}}", url, page), crate::Message {{ body: {}, content_type: None }}).await?;
-                }}
-
-
-                {}.append(&mut body.{});
-
-                has_more = body.has_more;
-            }}
-
-            // Return our response data.
-            Ok(crate::Response::new(status, headers, {}.to_vec()))"#,
-            response_type,
-            m.to_lowercase(),
-            body,
-            pagination_property,
-            pagination_property,
-            pagination_property,
-            pagination_property,
-            response_type,
-            m.to_lowercase(),
-            body,
-            response_type,
-            m.to_lowercase(),
-            body,
-            pagination_property,
-            pagination_property,
-            pagination_property,
-        );
-
-        return Ok(inner);
-    } else if all_pages && proper_name.starts_with("Google") {
-        // We will do a custom function here.
-        let inner = format!(
-            r#"let crate::Response::<{}> {{ mut status, mut headers, mut body }} = self.client.{}(&url, crate::Message {{ body: {}, content_type: None }}).await?;
-
-            let mut {} = body.{};
-            let mut page = body.next_page_token;
-
-            // Paginate if we should.
-            while !page.is_empty() {{
-                if !url.contains('?') {{
-                    crate::Response::<{}> {{ status, headers, body }} = self.client.{}(&format!("{{}}?pageToken={{}}", url, page), crate::Message {{ body: {}, content_type: None }}).await?;
-                }} else {{
-                    crate::Response::<{}> {{ status, headers, body }} = self.client.{}(&format!("{{}}&pageToken={{}}", url, page), crate::Message {{ body: {}, content_type: None }}).await?;
-                }}
-
-
-                {}.append(&mut body.{});
-
-                if !body.next_page_token.is_empty() && body.next_page_token != page {{
-                    page = body.next_page_token.to_string();
-                }} else {{
-                    page = "".to_string();
-                }}
-            }}
-
-            // Return our response data.
-            Ok(crate::Response::new(status, headers, {}))"#,
-            response_type,
-            m.to_lowercase(),
-            body,
-            pagination_property,
-            pagination_property,
-            response_type,
-            m.to_lowercase(),
-            body,
-            response_type,
-            m.to_lowercase(),
-            body,
-            pagination_property,
-            pagination_property,
-            pagination_property,
-        );
-
-        return Ok(inner);
-    } else if all_pages && proper_name == "Ramp" {
-        // We will do a custom function here.
-        let inner = format!(
-            r#"let crate::Response::<{}> {{ mut status, mut headers, body }} = self.client.{}(&url, crate::Message {{ body: {}, content_type: None }}).await?;
-
-            let mut {} = body.{};
-            let mut page = body.page.next.to_string();
-
-            // Paginate if we should.
-            while !page.is_empty() {{
-                match self.client.{}::<{}>(page.trim_start_matches(&self.client.host), crate::Message {{ body: {}, content_type: None }}).await {{
-                    Ok(mut resp) => {{
-                        {}.append(&mut resp.body.{});
-                        status = resp.status;
-                        headers = resp.headers;
-
-                        page = if body.page.next != page {{
-                            body.page.next.to_string()
-                        }} else {{
-                        "".to_string()
-                        }};
-                    }},
-                    Err(e) => {{
-                        if e.to_string().contains("404 Not Found") {{
-                            page = "".to_string();
-                        }} else {{
-                            return Err(e);
-                        }}
-                    }}
-                }}
-            }}
-
-            // Return our response data.
-            Ok(crate::Response::new(status, headers, {}))"#,
-            response_type,
-            m.to_lowercase(),
-            body,
-            pagination_property,
-            pagination_property,
-            m.to_lowercase(),
-            response_type,
-            body,
-            pagination_property,
-            pagination_property,
-            pagination_property,
-        );
-
-        return Ok(inner);
-    } else if all_pages && proper_name == "TripActions" {
-        // We will do a custom function here.
-        let inner = format!(
-            r#"
-            let crate::Response::<{}> {{ mut status, mut headers, mut body }} = if !url.contains('?') {{
-                self.client.{}(&format!("{{}}?page=0&size=100", url), crate::Message {{ body: {}, content_type: None }}).await?
-            }} else {{
-                self.client.{}(&format!("{{}}&page=0&size=100", url), crate::Message {{ body: {}, content_type: None }}).await?
-            }};
-
-            let mut {} = body.{};
-            let mut page = body.page.current_page + 1;
-
-            // Paginate if we should.
-            while page <= (body.page.total_pages - 1) {{
-                if !url.contains('?') {{
-                    crate::Response::<{}> {{ status, headers, body }} = self.client.{}(&format!("{{}}?page={{}}&size=100", url, page), crate::Message {{ body: {}, content_type: None }}).await?;
-                }} else {{
-                    crate::Response::<{}> {{ status, headers, body }} = self.client.{}(&format!("{{}}&page={{}}&size=100", url, page), crate::Message {{ body: {}, content_type: None }}).await?;
-                }}
-
-                {}.append(&mut body.{});
-
-                page = body.page.current_page + 1;
-            }}
-
-            // Return our response data.
-            Ok(crate::Response::new(status, headers, {}))"#,
-            response_type,
-            m.to_lowercase(),
-            body,
-            m.to_lowercase(),
-            body,
-            pagination_property,
-            pagination_property,
-            response_type,
-            m.to_lowercase(),
-            body,
-            response_type,
-            m.to_lowercase(),
-            body,
-            pagination_property,
-            pagination_property,
-            pagination_property,
-        );
-
-        return Ok(inner);
-    } else if all_pages && proper_name == "Zoom" {
-        // We will do a custom function here.
-        let inner = format!(
-            r#"let crate::Response::<{}> {{ mut status, mut headers, mut body }} = self.client.{}(&url, crate::Message {{ body: {}, content_type: None }}).await?;
-
-            let mut {} = body.{};
-            let mut page = body.next_page_token;
-
-            // Paginate if we should.
-            while !page.is_empty() {{
-                // Check if we already have URL params and need to concat the token.
-                if !url.contains('?') {{
-                    crate::Response::<{}> {{ status, headers, body }} = self.client.{}(&format!("{{}}?next_page_token={{}}", url, page), crate::Message {{ body: {}, content_type: None }}).await?;
-                }} else {{
-                    crate::Response::<{}> {{ status, headers, body }} = self.client.{}(&format!("{{}}&next_page_token={{}}", url, page), crate::Message {{ body: {}, content_type: None }}).await?;
-                }}
-
-                {}.append(&mut body.{});
-
-                if !body.next_page_token.is_empty() && body.next_page_token != page {{
-                    page = body.next_page_token.to_string();
-                }} else {{
-                    page = "".to_string();
-                }}
-            }}
-
-            // Return our response data.
-            Ok(crate::Response::new(status, headers, {}))"#,
-            response_type,
-            m.to_lowercase(),
-            body,
-            pagination_property,
-            pagination_property,
-            response_type,
-            m.to_lowercase(),
-            body,
-            response_type,
-            m.to_lowercase(),
-            body,
-            pagination_property,
-            pagination_property,
-            pagination_property,
-        );
-
-        return Ok(inner);
     } else if all_pages && !pagination_property.is_empty() {
-        bail!(
-            "must implement custom pagination function for {} {}",
-            proper_name,
-            pagination_property
-        );
+        return get_fn_all_pages_inner(proper_name, m, body, response_type, pagination_property);
     }
 
     if (m == http::Method::GET
@@ -1248,6 +1142,47 @@ fn get_fn_docs(
 }
 
 fn get_fn_docs_all(o: &openapiv3::Operation, m: &str, p: &str, fn_name: &str) -> Result<String> {
+    get_fn_docs_paginated(
+        o,
+        m,
+        p,
+        &format!(
+            "As opposed to `{}`, this function returns all the pages of the request at once.",
+            fn_name
+        ),
+    )
+}
+
+fn get_fn_docs_stream(o: &openapiv3::Operation, m: &str, p: &str, fn_name: &str) -> Result<String> {
+    get_fn_docs_paginated(
+        o,
+        m,
+        p,
+        &format!(
+            "As opposed to `{}`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.",
+            fn_name
+        ),
+    )
+}
+
+fn get_fn_docs_builder(
+    o: &openapiv3::Operation,
+    m: &str,
+    p: &str,
+    fn_name: &str,
+) -> Result<String> {
+    get_fn_docs_paginated(
+        o,
+        m,
+        p,
+        &format!(
+            "As opposed to `{}`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.",
+            fn_name
+        ),
+    )
+}
+
+fn get_fn_docs_paginated(o: &openapiv3::Operation, m: &str, p: &str, note: &str) -> Result<String> {
     let mut out = String::new();
 
     let mut a = |s: &str| {
@@ -1265,10 +1200,7 @@ fn get_fn_docs_all(o: &openapiv3::Operation, m: &str, p: &str, fn_name: &str) ->
         m, p
     ));
     a(" *");
-    a(&format!(
-        " * As opposed to `{}`, this function returns all the pages of the request at once.",
-        fn_name
-    ));
+    a(&format!(" * {}", note));
     if let Some(description) = &o.description {
         a(" *");
         a(&format!(" * {}", description.replace('\n', "\n * ")));
@@ -1282,6 +1214,563 @@ fn get_fn_docs_all(o: &openapiv3::Operation, m: &str, p: &str, fn_name: &str) ->
     Ok(out.trim().to_string())
 }
 
+/// The names of the arguments of the parameters the spec marks as required, as
+/// `get_fn_params` names them.
+fn get_required_params(
+    o: &openapiv3::Operation,
+    parameters: &BTreeMap<String, &openapiv3::Parameter>,
+    global_params: Vec<openapiv3::ReferenceOr<openapiv3::Parameter>>,
+) -> Result<Vec<String>> {
+    let mut required: Vec<String> = Default::default();
+    for par in global_params.iter().chain(o.parameters.iter()) {
+        let item = match par {
+            openapiv3::ReferenceOr::Reference { reference } => {
+                let param_name = struct_name(&reference.replace("#/components/parameters/", ""));
+                if let Some(param) = parameters.get(&param_name) {
+                    param
+                } else {
+                    bail!("could not find parameter with reference: {}", reference);
+                }
+            }
+            openapiv3::ReferenceOr::Item(item) => item,
+        };
+
+        let parameter_data = get_parameter_data(item).unwrap();
+        if !parameter_data.required {
+            continue;
+        }
+
+        let nam = to_snake_case(&parameter_data.name);
+        if nam == "ref"
+            || nam == "type"
+            || nam == "foo"
+            || nam == "enum"
+            || nam == "const"
+            || nam == "use"
+        {
+            required.push(format!("{}_", nam));
+        } else if nam == "i_ds" {
+            required.push("ids".to_string());
+        } else {
+            required.push(nam);
+        }
+    }
+
+    Ok(required)
+}
+
+/// The builder of the parameters of a function, and the function returning it, for
+/// both the async and the blocking clients.
+///
+/// The required parameters and the body are given to the function returning the
+/// builder, while the optional parameters are set on the builder, and only those that
+/// were set are sent.
+/// Returns None if a parameter has the name of a field or function of the builder.
+#[allow(clippy::too_many_arguments)]
+fn get_fn_builder(
+    tag: &str,
+    path: &str,
+    fn_name: &str,
+    builder_name: &str,
+    fn_params_str: &[String],
+    required_params: &[String],
+    query_params: &BTreeMap<String, (String, String)>,
+    body_param: &Option<String>,
+    response_type: &str,
+    server_arg: &str,
+    fn_inner: &str,
+) -> Result<Option<FileOutput>> {
+    let path_params: Vec<String> = path
+        .split('{')
+        .skip(1)
+        .filter_map(|c| c.split_once('}'))
+        .map(|(n, _)| to_snake_case(n))
+        .collect();
+    let is_path_param = |n: &str| path_params.iter().any(|p| p == n.trim_end_matches('_'));
+
+    // Only the parameters of the path and the query are ever sent, so the builder
+    // leaves the others out.
+    let (params, others): (Vec<_>, Vec<_>) = fn_params_str
+        .iter()
+        .filter_map(|p| p.trim_end_matches(',').split_once(": "))
+        .partition(|(n, _)| is_path_param(n) || query_params.contains_key(*n));
+    if params
+        .iter()
+        .any(|(n, _)| *n == "client" || *n == "body" || *n == "send")
+        || others.iter().any(|(n, _)| fn_inner.contains(n))
+    {
+        return Ok(None);
+    }
+
+    let is_required = |n: &str| is_path_param(n) || required_params.iter().any(|p| p == n);
+    // The type of an optional parameter, as the builder takes it.
+    let optional_type = |t: &str| {
+        t.strip_prefix("Option<")
+            .and_then(|t| t.strip_suffix('>'))
+            .unwrap_or(t)
+            .to_string()
+    };
+
+    let with_lifetime = |t: &str| t.replace('&', "&'a ");
+    let tag_name = struct_name(tag);
+    let builder = format!("{}{}", tag_name, to_pascal_case(builder_name));
+    let doc = format!(
+        "/// The parameters of [`{}::{}`], set with a builder.\n",
+        tag_name, fn_name
+    );
+
+    let mut head = doc.clone();
+    head.push_str(&format!("pub struct {}<'a> {{\n", builder));
+    head.push_str(&format!("client: &'a {},\n", tag_name));
+    for (n, t) in &params {
+        if is_required(n) {
+            head.push_str(&format!("{}: {},\n", n, with_lifetime(t)));
+        } else {
+            head.push_str(&format!(
+                "{}: Option<{}>,\n",
+                n,
+                with_lifetime(&optional_type(t))
+            ));
+        }
+    }
+    if let Some(b) = body_param {
+        head.push_str(&format!("body: {},\n", with_lifetime(b)));
+    }
+    head.push_str("}\n\n");
+
+    let mut blocking_head = doc;
+    blocking_head.push_str(&format!(
+        r#"pub struct {}<'a> {{
+            inner: crate::{}::{}<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }}
+
+        "#,
+        builder,
+        to_snake_case(&clean_name(tag)),
+        builder
+    ));
+
+    head.push_str(&format!("impl<'a> {}<'a> {{\n", builder));
+    blocking_head.push_str(&format!("impl<'a> {}<'a> {{\n", builder));
+    for (n, t) in &params {
+        if is_required(n) {
+            continue;
+        }
+
+        let doc = if let Some((_, prop)) = query_params.get(*n) {
+            // Some specs have line breaks in the names of their parameters.
+            format!(
+                "/// Sets the `{}` query parameter.",
+                prop.split_whitespace().collect::<Vec<_>>().join(" ")
+            )
+        } else {
+            format!("/// Sets the `{}` parameter.", n)
+        };
+        let t = with_lifetime(&optional_type(t));
+        head.push_str(&format!(
+            r#"{}
+            pub fn {}(mut self, {}: {}) -> Self {{
+                self.{} = Some({});
+                self
+            }}
+
+            "#,
+            doc, n, n, t, n, n
+        ));
+        blocking_head.push_str(&format!(
+            r#"{}
+            pub fn {}(mut self, {}: {}) -> Self {{
+                self.inner = self.inner.{}({});
+                self
+            }}
+
+            "#,
+            doc, n, n, t, n, n
+        ));
+    }
+
+    // The query of the builder only has the optional parameters that were set.
+    let builder_query_params: BTreeMap<String, (String, String)> = query_params
+        .iter()
+        .map(|(n, (t, prop))| {
+            if is_required(n) || t.starts_with("Option<") {
+                (n.to_string(), (t.to_string(), prop.to_string()))
+            } else {
+                (n.to_string(), (format!("Option<{}>", t), prop.to_string()))
+            }
+        })
+        .collect();
+    let template = parse(path)?.compile(builder_query_params, server_arg);
+    let mut fields: Vec<&str> = params.iter().map(|(n, _)| *n).collect();
+    if body_param.is_some() {
+        fields.push("body");
+    }
+    head.push_str(&format!(
+        r#"/// Sends the request, with the parameters that were set.
+        pub async fn send(self) -> ClientResult<crate::Response<{}>> {{
+            let Self {{ client, {} }} = self;
+            {}
+            {}
+        }}
+        }}
+
+        "#,
+        response_type,
+        fields.join(", "),
+        template.replace("self.client", "client.client"),
+        fn_inner.replace("self.client", "client.client")
+    ));
+    blocking_head.push_str(&format!(
+        r#"/// Sends the request, with the parameters that were set.
+        pub fn send(self) -> ClientResult<crate::Response<{}>> {{
+            self.runtime.block_on(self.inner.send())
+        }}
+        }}
+
+        "#,
+        response_type,
+    ));
+
+    let mut required: Vec<String> = Default::default();
+    let mut required_args: Vec<&str> = Default::default();
+    for (n, t) in &params {
+        if is_required(n) {
+            required.push(format!("{}: {},", n, with_lifetime(t)));
+            required_args.push(n);
+        }
+    }
+    if let Some(b) = body_param {
+        required.push(format!("body: {},", with_lifetime(b)));
+        required_args.push("body");
+    }
+    // Only name the lifetime if the builder borrows more than the client.
+    let signature = if required.iter().any(|r| r.contains("&'a ")) {
+        format!(
+            "pub fn {}<'a>(&'a self, {}) -> {}<'a> {{",
+            builder_name,
+            required.join(" "),
+            builder
+        )
+    } else {
+        format!(
+            "pub fn {}(&self, {}) -> {}<'_> {{",
+            builder_name,
+            required.join(" "),
+            builder
+        )
+    };
+
+    let mut blocking_content = format!(
+        "/// The blocking version of [`crate::{}::{}::{}`].\n",
+        to_snake_case(&clean_name(tag)),
+        tag_name,
+        builder_name
+    );
+    blocking_content.push_str(&signature);
+    blocking_content.push_str(&format!(
+        "{} {{ inner: self.inner.{}({}), runtime: &self.runtime }}}}",
+        builder,
+        builder_name,
+        required_args.join(", ")
+    ));
+
+    let mut content = signature;
+    content.push_str(&format!("{} {{ client: self,", builder));
+    for (n, _) in &params {
+        if is_required(n) {
+            content.push_str(&format!("{},", n));
+        } else {
+            content.push_str(&format!("{}: None,", n));
+        }
+    }
+    if body_param.is_some() {
+        content.push_str("body,");
+    }
+    content.push_str("}}");
+
+    Ok(Some(FileOutput {
+        head,
+        impl_content: content,
+        blocking_head,
+        blocking_impl_content: blocking_content,
+    }))
+}
+
+/// The function of the blocking client sending the request of the function of the
+/// same name of the async client.
+fn get_blocking_fn(
+    tag: &str,
+    bounds: &[String],
+    fn_params_str: &[String],
+    body_param: &Option<String>,
+    response_type: &str,
+    fn_name: &str,
+) -> String {
+    let mut content = format!(
+        "/// The blocking version of [`crate::{}::{}::{}`].\n",
+        to_snake_case(&clean_name(tag)),
+        struct_name(tag),
+        fn_name
+    );
+
+    if bounds.is_empty() {
+        content.push_str(&format!("pub fn {}(", fn_name));
+    } else {
+        content.push_str(&format!("pub fn {}<{}>(", fn_name, bounds.join(", ")));
+    }
+    content.push_str("&self,");
+    content.push_str(&fn_params_str.join(" "));
+    if let Some(bp) = body_param {
+        content.push_str(&format!("body: {}", bp));
+    }
+    content.push_str(&format!(
+        ") -> ClientResult<crate::Response<{}>> {{",
+        response_type
+    ));
+
+    let mut args: Vec<&str> = fn_params_str
+        .iter()
+        .filter_map(|p| p.split_once(':'))
+        .map(|(n, _)| n.trim())
+        .collect();
+    if body_param.is_some() {
+        args.push("body");
+    }
+    // The type parameters are not always those of the arguments, so name them.
+    let turbofish = if bounds.is_empty() {
+        "".to_string()
+    } else {
+        let names: Vec<&str> = bounds
+            .iter()
+            .filter_map(|b| b.split_once(':'))
+            .map(|(n, _)| n.trim())
+            .collect();
+        format!("::<{}>", names.join(", "))
+    };
+    content.push_str(&format!(
+        "self.runtime.block_on(self.inner.{}{}({}))",
+        fn_name,
+        turbofish,
+        args.join(", ")
+    ));
+    content.push('}');
+
+    content
+}
+
+/// The body of a `get_all` function of an endpoint that paginates through its
+/// response body, given that `url` holds the url of the first page.
+///
+/// Like `unfold`, pages are followed until the response has no next page, a page
+/// comes back empty or the client's page limits are reached, and the walk fails if
+/// the response points back at a page that was already fetched.
+fn get_fn_all_pages_inner(
+    proper_name: &str,
+    m: &str,
+    body: &str,
+    response_type: &str,
+    pagination_property: &str,
+) -> Result<String> {
+    let (first, next) = if proper_name.starts_with("Stripe") {
+        (
+            "url",
+            format!(
+                r#"if response.body.has_more {{
+                    response.body.{pagination_property}
+                        .last()
+                        .and_then(|last| serde_json::to_value(last).ok())
+                        .and_then(|last| last.get("id").and_then(|id| id.as_str()).map(|id| format!("{{}}{{}}starting_after={{}}", url, sep, id)))
+                }} else {{
+                    None
+                }}"#
+            ),
+        )
+    } else if proper_name.starts_with("Google") || proper_name == "Zoom" {
+        let param = if proper_name == "Zoom" {
+            "next_page_token"
+        } else {
+            "pageToken"
+        };
+
+        (
+            "url",
+            format!(
+                r#"if response.body.next_page_token.is_empty() {{
+                    None
+                }} else {{
+                    Some(format!("{{}}{{}}{param}={{}}", url, sep, response.body.next_page_token))
+                }}"#
+            ),
+        )
+    } else if proper_name == "Ramp" {
+        (
+            "url",
+            r#"if response.body.page.next.is_empty() {
+                    None
+                } else {
+                    Some(response.body.page.next.to_string())
+                }"#
+            .to_string(),
+        )
+    } else if proper_name == "TripActions" {
+        (
+            "first",
+            r#"if response.body.page.current_page + 1 < response.body.page.total_pages {
+                    Some(format!("{}{}page={}&size=100", url, sep, response.body.page.current_page + 1))
+                } else {
+                    None
+                }"#
+            .to_string(),
+        )
+    } else {
+        bail!(
+            "must implement custom pagination function for {} {}",
+            proper_name,
+            pagination_property
+        );
+    };
+
+    // Ramp hands back the full url of the next page, TripActions numbers its pages
+    // from the first one.
+    let sep = if proper_name == "Ramp" {
+        ""
+    } else if proper_name == "TripActions" {
+        r#"let sep = if url.contains('?') { '&' } else { '?' };
+            let first = format!("{}{}page=0&size=100", url, sep);"#
+    } else {
+        "let sep = if url.contains('?') { '&' } else { '?' };"
+    };
+
+    let m = m.to_lowercase();
+    let fetch = format!(
+        "self.client.{m}(&next, crate::Message {{ body: {body}, content_type: None }}).await"
+    );
+    let fetch_next = if proper_name == "Ramp" {
+        format!(
+            r#"match {fetch} {{
+                    Ok(response) => response,
+                    // Ramp answers the `next` url that follows its last page with a 404.
+                    Err(crate::ClientError::ApiError {{ status: http::StatusCode::NOT_FOUND, .. }})
+                    | Err(crate::ClientError::HttpError {{ status: http::StatusCode::NOT_FOUND, .. }}) => break,
+                    Err(e) => return Err(e),
+                }}"#
+        )
+    } else {
+        format!("{fetch}?")
+    };
+
+    Ok(format!(
+        r#"{sep}
+            let mut pages = crate::utils::Pages::new(self.client.page_limits);
+            pages.visit(&{first})?;
+
+            let mut response: crate::Response<{response_type}> = self.client.{m}(&{first}, crate::Message {{ body: {body}, content_type: None }}).await?;
+            let mut {pagination_property} = Vec::new();
+
+            // Paginate if we should.
+            loop {{
+                let page = {next};
+                let more = pages.keep(&mut response.body.{pagination_property});
+                {pagination_property}.append(&mut response.body.{pagination_property});
+
+                let next = match page {{
+                    Some(next) if more && pages.visit(&next)? => next,
+                    _ => break,
+                }};
+                response = {fetch_next};
+            }}
+
+            // Return our response data.
+            Ok(crate::Response::new(response.status, response.headers, {pagination_property}))"#
+    ))
+}
+
+/// The body of a `*_stream` function, given that `url` holds the url of the
+/// first page.
+fn get_fn_stream_inner(
+    proper_name: &str,
+    response_type: &str,
+    pagination_property: &str,
+) -> Result<String> {
+    if pagination_property.is_empty() {
+        return Ok("self.client.get_all_pages_stream(url)".to_string());
+    }
+
+    let sep = "let sep = if url.contains('?') { '&' } else { '?' };";
+
+    if proper_name.starts_with("Stripe") {
+        Ok(format!(
+            r#"{sep}
+            let next = format!("{{}}{{}}starting_after=", url, sep);
+            self.client.get_pages_stream(url, move |body: {response_type}| {{
+                let page = if body.has_more {{
+                    body.{pagination_property}
+                        .last()
+                        .and_then(|last| serde_json::to_value(last).ok())
+                        .and_then(|last| last.get("id").and_then(|id| id.as_str()).map(|id| format!("{{}}{{}}", next, id)))
+                }} else {{
+                    None
+                }};
+
+                (body.{pagination_property}.to_vec(), page)
+            }})"#
+        ))
+    } else if proper_name.starts_with("Google") || proper_name == "Zoom" {
+        let param = if proper_name == "Zoom" {
+            "next_page_token"
+        } else {
+            "pageToken"
+        };
+
+        Ok(format!(
+            r#"{sep}
+            let next = format!("{{}}{{}}{param}=", url, sep);
+            self.client.get_pages_stream(url, move |body: {response_type}| {{
+                let page = if body.next_page_token.is_empty() {{
+                    None
+                }} else {{
+                    Some(format!("{{}}{{}}", next, body.next_page_token))
+                }};
+
+                (body.{pagination_property}, page)
+            }})"#
+        ))
+    } else if proper_name == "Ramp" {
+        Ok(format!(
+            r#"self.client.get_pages_stream(url, |body: {response_type}| {{
+                let page = if body.page.next.is_empty() {{
+                    None
+                }} else {{
+                    Some(body.page.next)
+                }};
+
+                (body.{pagination_property}, page)
+            }})"#
+        ))
+    } else if proper_name == "TripActions" {
+        Ok(format!(
+            r#"{sep}
+            let first = format!("{{}}{{}}page=0&size=100", url, sep);
+            self.client.get_pages_stream(first, move |body: {response_type}| {{
+                let page = if body.page.current_page + 1 < body.page.total_pages {{
+                    Some(format!("{{}}{{}}page={{}}&size=100", url, sep, body.page.current_page + 1))
+                }} else {{
+                    None
+                }};
+
+                (body.{pagination_property}, page)
+            }})"#
+        ))
+    } else {
+        bail!(
+            "must implement custom pagination stream for {} {}",
+            proper_name,
+            pagination_property
+        );
+    }
+}
+
 fn is_page_param(s: &str, proper_name: &str) -> bool {
     s == "page"
         || s == "per_page"
@@ -1,5 +1,12 @@
+mod blocking;
 mod client;
+mod errors;
 mod functions;
+mod middleware;
+mod push_notifications;
+mod ratelimit;
+mod service_account;
+mod sync;
 mod template;
 mod types;
 mod utils;
@@ -682,14 +689,14 @@ impl PartialEq for TypeDetails {
                     return s == os;
                 }
             }
-            TypeDetails::OneOf(s, _d) => {
-                if let TypeDetails::OneOf(os, _od) = other {
-                    return s == os;
+            TypeDetails::OneOf(s, d) => {
+                if let TypeDetails::OneOf(os, od) = other {
+                    return s == os && d.discriminator == od.discriminator;
                 }
             }
-            TypeDetails::AnyOf(s, _d) => {
-                if let TypeDetails::AnyOf(os, _od) = other {
-                    return s == os;
+            TypeDetails::AnyOf(s, d) => {
+                if let TypeDetails::AnyOf(os, od) = other {
+                    return s == os && d.discriminator == od.discriminator;
                 }
             }
             TypeDetails::AllOf(s, _d) => {
@@ -1935,9 +1942,16 @@ impl TypeSpace {
                 omap.sort_unstable();
                 omap.dedup();
 
+                // Keep the discriminator, for the variants to be picked by it.
                 Ok((
                     Some(one_of_name),
-                    TypeDetails::OneOf(omap, Default::default()),
+                    TypeDetails::OneOf(
+                        omap,
+                        openapiv3::SchemaData {
+                            discriminator: s.schema_data.discriminator.clone(),
+                            ..Default::default()
+                        },
+                    ),
                 ))
             }
             openapiv3::SchemaKind::AnyOf { any_of } => {
@@ -1985,9 +1999,16 @@ impl TypeSpace {
                 omap.sort_unstable();
                 omap.dedup();
 
+                // Keep the discriminator, for the variants to be picked by it.
                 Ok((
                     Some(any_of_name),
-                    TypeDetails::AnyOf(omap, Default::default()),
+                    TypeDetails::AnyOf(
+                        omap,
+                        openapiv3::SchemaData {
+                            discriminator: s.schema_data.discriminator.clone(),
+                            ..Default::default()
+                        },
+                    ),
                 ))
             }
             openapiv3::SchemaKind::Any(any) => {
@@ -2207,6 +2228,10 @@ fn render_param(
             // TODO: do something for empty(?)
             continue;
         }
+        if struct_name(e) == "Unknown" {
+            // Kept by the wildcard, which has the same name.
+            continue;
+        }
         a(&format!(r#"#[serde(rename = "{}")]"#, e));
         a(&format!("{},", struct_name(e)));
     }
@@ -2215,9 +2240,11 @@ fn render_param(
         a("Noop,");
     }
 
-    // Let's add the wildcard.
-    a("#[serde(other)]");
-    a("FallthroughString");
+    // Let's add the wildcard, keeping the value so values added to the API after the
+    // spec round-trip rather than failing to deserialize.
+    a("#[serde(untagged)]");
+    a("#[schemars(skip)]");
+    a("Unknown(String),");
 
     a("}");
     a("");
@@ -2230,6 +2257,9 @@ fn render_param(
             // TODO: do something for empty(?)
             continue;
         }
+        if struct_name(e) == "Unknown" {
+            continue;
+        }
         a(&format!(r#"{}::{} => "{}","#, sn, struct_name(e), e));
     }
     if !required && default.is_none() {
@@ -2237,7 +2267,7 @@ fn render_param(
     }
 
     // Let's add the display format for the wildcard.
-    a(&format!(r#"{}::FallthroughString => "*","#, sn));
+    a(&format!(r#"{}::Unknown(s) => s.as_str(),"#, sn));
 
     a("}");
     a(".fmt(f)");
@@ -2253,11 +2283,12 @@ fn render_param(
             // Use the default that can be passed to the OpenAPI,
             // github is not using that currently for everything but we might want to
             // in the future.
-            a(&format!(
-                "{}::{}",
-                sn,
-                struct_name(&d.to_string().replace('"', ""))
-            ));
+            let d = d.to_string().replace('"', "");
+            if struct_name(&d) == "Unknown" {
+                a(&format!("{}::Unknown({:?}.to_string())", sn, d));
+            } else {
+                a(&format!("{}::{}", sn, struct_name(&d)));
+            }
         } else {
             a(&format!("{}::Noop", sn));
         }
@@ -2322,7 +2353,38 @@ fn gen(
     {
         a("pub mod traits;");
     }
+    if proper_name == "Stripe"
+        || proper_name == "GitHub"
+        || proper_name == "Slack"
+        || proper_name == "Shopify"
+        || proper_name == "Zoom"
+        || proper_name == "Okta"
+    {
+        a("pub mod webhooks;");
+    }
+    if proper_name == "DocuSign" {
+        a("pub mod connect;");
+    }
+    if proper_name == "ShipBob" || proper_name == "SendGrid" {
+        a("pub mod webhook_events;");
+    }
+    if proper_name == "SendGrid" {
+        a("pub mod inbound_parse;");
+    }
+    if proper_name.starts_with("Google") {
+        a("pub mod service_account;");
+    }
+    if proper_name == "Google Calendar" || proper_name == "Google Drive" {
+        a("pub mod push_notifications;");
+        a("pub mod sync;");
+    }
+    a(r#"#[cfg(feature = "blocking")]"#);
+    a(r#"#[cfg_attr(docsrs, doc(cfg(feature = "blocking")))]"#);
+    a("pub mod blocking;");
     // Hopefully there is never a "tag" named after these reserved libs.
+    a("pub mod errors;");
+    a("pub mod middleware;");
+    a("pub mod ratelimit;");
     a("pub mod types;");
     a("#[doc(hidden)]");
     a("pub mod utils;");
@@ -2376,6 +2438,8 @@ fn gen(
     a(r#"
 pub use reqwest::{StatusCode, header::HeaderMap};
 
+pub use crate::{errors::ApiError, middleware::ClientBuilder, ratelimit::RateLimitInfo};
+
 #[derive(Debug)]
 pub struct Response<T> {
     pub status: reqwest::StatusCode,
@@ -2391,6 +2455,11 @@ impl<T> Response<T> {
             body,
         }
     }
+
+    /// Returns the state of the rate limit of the API reported by the response, if any.
+    pub fn rate_limit(&self) -> Option<RateLimitInfo> {
+        RateLimitInfo::from_headers(&self.headers)
+    }
 }"#);
     a("");
 
@@ -2407,11 +2476,6 @@ pub enum ClientError {"#);
     match template_type {
         TemplateType::Github => {
             a(r#"// Github only
-            /// Ratelimited
-            #[error("Rate limited for the next {duration} seconds")]
-            RateLimited{
-                duration: u64,
-            },
             /// JWT errors from auth.rs
             #[error(transparent)]
             JsonWebTokenError(#[from] jsonwebtoken::errors::Error),
@@ -2437,6 +2501,12 @@ pub enum ClientError {"#);
         }
     }
 
+    if proper_name.starts_with("Google") {
+        a(r#"/// JWT errors from service_account.rs
+        #[error(transparent)]
+        JsonWebTokenError(#[from] jsonwebtoken::errors::Error),"#);
+    }
+
     // Google Drive only due to traits.rs
     if proper_name == "Google Drive" {
         a(r#"
@@ -2448,12 +2518,37 @@ pub enum ClientError {"#);
         ToStrError(#[from] reqwest::header::ToStrError),"#);
     }
 
-    a(r#"/// URL Parsing Error
+    a(
+        r#"/// A listing stopped before its last page, as the API handed back a page token or
+    /// url it already did, or the page limits of the client were reached where the
+    /// listing cannot be returned without its last page
+    #[error("Listing stopped before its last page, at page token {page_token:?}")]
+    IncompletePages{page_token: String},"#,
+    );
+
+    a(
+        r#"/// The API rate limited the request. Its body is parsed into an `ApiError` when it
+    /// holds one, and `duration` is how many seconds the API asked to wait, if it said.
+    #[error(
+        "Rate limited. Code: {status}{}",
+        .duration.map(|duration| format!(", retry in {} seconds", duration)).unwrap_or_default()
+    )]
+    RateLimited {
+        status: http::StatusCode,
+        headers: reqwest::header::HeaderMap,
+        duration: Option<u64>,
+        info: crate::RateLimitInfo,
+        error: Option<Box<crate::ApiError>>,
+    },
+    /// URL Parsing Error
     #[error(transparent)]
     UrlParserError(#[from] url::ParseError),
     /// Serde JSON parsing error
     #[error(transparent)]
     SerdeJsonError(#[from] serde_json::Error),
+    /// Serde form body encoding error
+    #[error(transparent)]
+    SerdeUrlencodedError(#[from] serde_urlencoded::ser::Error),
     /// Errors returned by reqwest
     #[error(transparent)]
     ReqwestError(#[from] reqwest::Error),
@@ -2463,6 +2558,13 @@ pub enum ClientError {"#);
     /// Errors returned by reqwest middleware
     #[error(transparent)]
     ReqwestMiddleWareError(#[from] reqwest_middleware::Error),
+    /// Error returned by the API, parsed from the body of the response
+    #[error("API Error. Code: {status}, message: {error}")]
+    ApiError {
+        status: http::StatusCode,
+        headers: reqwest::header::HeaderMap,
+        error: Box<crate::ApiError>,
+    },
     /// Generic HTTP Error
     #[error("HTTP Error. Code: {status}, message: {error}")]
     HttpError {
@@ -2471,7 +2573,8 @@ pub enum ClientError {"#);
         error: String,
     },
 }
-"#);
+"#,
+    );
 
     a("");
 
@@ -3193,7 +3296,7 @@ fn main() -> Result<()> {
                 let mut req: Vec<String> = Default::default();
                 if let Some(openapiv3::ReferenceOr::Item(body)) = &o.request_body {
                     for (ct, mt) in &body.content {
-                        if ct == "application/json" {
+                        if ct == "application/json" || ct == "application/x-www-form-urlencoded" {
                             if let Some(s) = &mt.schema {
                                 let object_name = format!("{} request", oid_to_object_name(&od));
                                 let id = ts.select(Some(&object_name), s, "")?;
@@ -3359,6 +3462,48 @@ yup-oauth2 = "^8""#
                     .to_string();
             }
 
+            // Verifying webhook signatures.
+            let mut webhooks_lib = "".to_string();
+            if proper_name == "Stripe"
+                || proper_name == "GitHub"
+                || proper_name == "Slack"
+                || proper_name == "Shopify"
+                || proper_name == "Zoom"
+            {
+                webhooks_lib = r#"
+hex = "0.4"
+hmac = "0.12"
+sha2 = "0.10""#
+                    .to_string();
+            }
+            // Shopify signs its webhooks in base64.
+            if proper_name == "Shopify" {
+                webhooks_lib.push_str(
+                    r#"
+base64 = "^0.21""#,
+                );
+            }
+            // DocuSign Connect signs its notifications in base64, and can send them as
+            // XML.
+            if proper_name == "DocuSign" {
+                webhooks_lib = r#"
+base64 = "^0.21"
+hmac = "0.12"
+quick-xml = { version = "0.31", features = ["serialize"] }
+sha2 = "0.10""#
+                    .to_string();
+            }
+            // SendGrid signs its Event Webhook with ECDSA, and posts inbound emails as
+            // multipart forms.
+            if proper_name == "SendGrid" {
+                webhooks_lib = r#"
+base64 = "^0.21"
+p256 = { version = "0.13", features = ["ecdsa", "pkcs8"] }
+encoding_rs = "0.8"
+multer = "2""#
+                    .to_string();
+            }
+
             let mut toml = root.clone();
             toml.push("Cargo.toml");
             let tomlout = format!(
@@ -3376,13 +3521,18 @@ license = "MIT"
 default = ["rustls-tls"]
 # enable etag-based http_cache functionality
 httpcache = ["dirs"]
+# keep the fields missing from the spec in an `extra` map on every type
+extra-fields = []
+# add a blocking client, wrapping the async one, in the `blocking` module
+blocking = ["tokio/rt"]
 native-tls = ["reqwest/default-tls", "openssl"]
 rustls-tls = ["reqwest/rustls-tls", "ring", "pem"]
 
 [dependencies]
 async-recursion = "^1.0"
-chrono = {{ version = "0.4", default-features = false, features = ["serde"] }}
+chrono = {{ version = "0.4", default-features = false, features = ["clock", "serde"] }}
 dirs = {{ version = "^3.0.2", optional = true }}
+futures = "0.3"
 http = "^0.2.4"
 jsonwebtoken = "8"
 log = {{ version = "^0.4", features = ["serde"] }}
@@ -3392,18 +3542,18 @@ parse_link_header = "0.3.3"
 pem = {{ version = "1.1.0",  default-features = false, optional = true }}
 percent-encoding = "2.2"
 reqwest = {{ version = "0.11.14", default-features = false, features = ["json", "multipart"] }}
-reqwest-conditional-middleware = "0.2.1"
 reqwest-middleware = "0.2.2"
-reqwest-retry = "0.2.2"
 reqwest-tracing = "0.4.4"
+retry-policies = "0.1.2"
 ring = {{ version = "0.16", default-features = false, optional = true }}
-schemars = {{ version = "0.8", features = ["bytes", "chrono", "url", "uuid1"] }}
-serde = {{ version = "1", features = ["derive"] }}
+schemars = {{ version = "0.8.21", features = ["bytes", "chrono", "url", "uuid1"] }}
+serde = {{ version = "1.0.181", features = ["derive"] }}
 serde_json = "1"
 serde_urlencoded = "^0.7"
-url = {{ version = "2", features = ["serde"] }}{}{}
+url = {{ version = "2", features = ["serde"] }}{}{}{}
+task-local-extensions = "0.1.4"
 thiserror = "1"
-tokio = {{ version = "1.25.0", default-features = false }}
+tokio = {{ version = "1.25.0", default-features = false, features = ["time"] }}
 
 [dev-dependencies]
 base64 = "^0.21"
@@ -3418,7 +3568,14 @@ wiremock = "0.5.17"
 all-features = true
 rustdoc-args = ["--cfg", "docsrs"]
 "#,
-                name, description, version, name, output_dir, uuid_lib, yup_oauth2_lib
+                name,
+                description,
+                version,
+                name,
+                output_dir,
+                uuid_lib,
+                yup_oauth2_lib,
+                webhooks_lib
             );
             save(&toml, tomlout.as_str())?;
 
@@ -3520,6 +3677,59 @@ rustdoc-args = ["--cfg", "docsrs"]
             utilsrs.push("utils.rs");
             save(utilsrs, utils.as_str())?;
 
+            /*
+             * Create the Rust errors module:
+             */
+            let errors = errors::generate_errors(&proper_name);
+            let mut errorsrs = src.clone();
+            errorsrs.push("errors.rs");
+            save(errorsrs, errors.as_str())?;
+
+            /*
+             * Create the Rust rate limit module:
+             */
+            let ratelimit = ratelimit::generate_ratelimit(&proper_name);
+            let mut ratelimitrs = src.clone();
+            ratelimitrs.push("ratelimit.rs");
+            save(ratelimitrs, ratelimit.as_str())?;
+
+            /*
+             * Create the Rust middleware module:
+             */
+            let middleware = middleware::generate_middleware();
+            let mut middlewarers = src.clone();
+            middlewarers.push("middleware.rs");
+            save(middlewarers, middleware.as_str())?;
+
+            /*
+             * Create the Rust push notifications module, for the APIs with channels:
+             */
+            if let Some(push_notifications) =
+                push_notifications::generate_push_notifications(&proper_name)
+            {
+                let mut push_notificationsrs = src.clone();
+                push_notificationsrs.push("push_notifications.rs");
+                save(push_notificationsrs, push_notifications.as_str())?;
+            }
+
+            /*
+             * Create the Rust service account module, for the Google APIs:
+             */
+            if let Some(service_account) = service_account::generate_service_account(&proper_name) {
+                let mut service_accountrs = src.clone();
+                service_accountrs.push("service_account.rs");
+                save(service_accountrs, service_account.as_str())?;
+            }
+
+            /*
+             * Create the Rust sync module, for the APIs with sync tokens:
+             */
+            if let Some(sync) = sync::generate_sync(&proper_name) {
+                let mut syncrs = src.clone();
+                syncrs.push("sync.rs");
+                save(syncrs, sync.as_str())?;
+            }
+
             /*
              * Create the Rust source types file containing the generated types:
              */
@@ -3534,8 +3744,39 @@ rustdoc-args = ["--cfg", "docsrs"]
 
             match functions::generate_files(&api, &proper_name, &mut ts, &parameters) {
                 Ok(files) => {
+                    /*
+                     * Create the Rust blocking module, with a file for each of the tags
+                     * as well:
+                     */
+                    // When the spec lists its tags, those are the only modules of the
+                    // client, even if some functions have other tags.
+                    let modules: Vec<String> = api
+                        .tags
+                        .iter()
+                        .map(|t| to_snake_case(&clean_name(&t.name)))
+                        .collect();
+                    let is_module = |f: &str| {
+                        modules.is_empty() || modules.contains(&to_snake_case(&clean_name(f)))
+                    };
+
+                    let mut blocking = src.clone();
+                    blocking.push("blocking");
+                    std::fs::create_dir_all(&blocking)?;
+                    let mut blockingrs = blocking.clone();
+                    blockingrs.push("mod.rs");
+                    save(
+                        blockingrs,
+                        &blocking::generate_blocking(files.keys().filter(|f| is_module(f))),
+                    )?;
+
                     // We have a map of our files, let's write to them.
                     for (f, output) in files {
+                        if is_module(&f) {
+                            let mut blockingrs = blocking.clone();
+                            blockingrs.push(format!("{}.rs", to_snake_case(&clean_name(&f))));
+                            save(blockingrs, &blocking::generate_blocking_tag(&f, &output))?;
+                        }
+
                         let mut tagrs = src.clone();
                         tagrs.push(format!("{}.rs", to_snake_case(&clean_name(&f))));
 
@@ -0,0 +1,237 @@
+const TEMPLATE: &str = r#"//! For authenticating as a service account, from its JSON key, rather than as a user
+//! through the consent flow.
+//!
+//! FROM: <https://developers.google.com/identity/protocols/oauth2/service-account>
+
+use std::{fmt, sync::Arc, time};
+
+use jsonwebtoken as jwt;
+use serde::{Deserialize, Serialize};
+use tokio::sync::RwLock;
+
+use crate::{AccessToken, Client, ClientError, ClientResult, InnerToken};
+
+/// The endpoint assertions are exchanged at, for keys that do not name one.
+const DEFAULT_TOKEN_URI: &str = "https://oauth2.googleapis.com/token";
+
+/// Google rejects assertions valid for longer than an hour.
+const MAX_ASSERTION_LIFE: time::Duration = time::Duration::from_secs(60 * 60);
+
+/// The JSON key of a service account, as downloaded from the Cloud Console.
+#[derive(Clone, Default, PartialEq, Eq, Deserialize)]
+pub struct ServiceAccountKey {
+    #[serde(
+        default,
+        rename = "type",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub type_: String,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub project_id: String,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub private_key_id: String,
+    /// The PEM encoded RSA key of the service account.
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub private_key: String,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub client_email: String,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub client_id: String,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub token_uri: String,
+}
+
+impl ServiceAccountKey {
+    /// Parses the JSON key of a service account.
+    pub fn from_json(json: &[u8]) -> ClientResult<Self> {
+        Ok(serde_json::from_slice(json)?)
+    }
+}
+
+impl fmt::Debug for ServiceAccountKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ServiceAccountKey")
+            .field("type_", &self.type_)
+            .field("project_id", &self.project_id)
+            .field("private_key_id", &self.private_key_id)
+            .field("private_key", &"***")
+            .field("client_email", &self.client_email)
+            .field("client_id", &self.client_id)
+            .field("token_uri", &self.token_uri)
+            .finish()
+    }
+}
+
+/// A service account, with the scopes its access tokens are requested for.
+///
+/// With domain-wide delegation, the service account can act as any user of the
+/// Workspace domain, which is how the Admin SDK is called from a backend.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ServiceAccount {
+    pub key: ServiceAccountKey,
+    pub scopes: Vec<String>,
+    /// The email of the user to impersonate, if any.
+    pub subject: Option<String>,
+}
+
+#[derive(Serialize)]
+struct ServiceAccountClaim<'a> {
+    iss: &'a str,
+    scope: String,
+    aud: &'a str,
+    iat: u64,
+    exp: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sub: Option<&'a str>,
+}
+
+impl ServiceAccount {
+    /// Create a new service account, requesting tokens for `scopes`.
+    pub fn new<S>(key: ServiceAccountKey, scopes: &[S]) -> Self
+    where
+        S: ToString,
+    {
+        ServiceAccount {
+            key,
+            scopes: scopes.iter().map(|scope| scope.to_string()).collect(),
+            subject: None,
+        }
+    }
+
+    /// Impersonates a user of the domain, through domain-wide delegation.
+    pub fn with_subject<S>(mut self, subject: S) -> Self
+    where
+        S: ToString,
+    {
+        self.subject = Some(subject.to_string());
+        self
+    }
+
+    /// Returns the endpoint the assertions of the service account are exchanged at.
+    pub fn token_uri(&self) -> &str {
+        if self.key.token_uri.is_empty() {
+            DEFAULT_TOKEN_URI
+        } else {
+            &self.key.token_uri
+        }
+    }
+
+    /// Signs a JWT assertion, to be exchanged for an access token.
+    pub fn assertion(&self) -> ClientResult<String> {
+        let now = time::SystemTime::now()
+            .duration_since(time::UNIX_EPOCH)
+            .unwrap();
+        let claim = ServiceAccountClaim {
+            iss: &self.key.client_email,
+            scope: self.scopes.join(" "),
+            aud: self.token_uri(),
+            iat: now.as_secs(),
+            exp: (now + MAX_ASSERTION_LIFE).as_secs(),
+            sub: self.subject.as_deref(),
+        };
+
+        let mut header = jwt::Header::new(jwt::Algorithm::RS256);
+        if !self.key.private_key_id.is_empty() {
+            header.kid = Some(self.key.private_key_id.clone());
+        }
+        let key = jwt::EncodingKey::from_rsa_pem(self.key.private_key.as_bytes())?;
+
+        Ok(jwt::encode(&header, &claim, &key)?)
+    }
+}
+
+impl Client {
+    /// Create a new Client struct authenticating as a service account. Access tokens
+    /// are requested on the first request, and refreshed before they expire.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the internal http client fails to create
+    pub fn new_from_service_account(service_account: ServiceAccount) -> Self {
+        let mut client = Client::new("", "", "", "", "");
+        client.token = Arc::new(RwLock::new(InnerToken {
+            access_token: String::new(),
+            refresh_token: String::new(),
+            // Expired already, for the first request to request a token.
+            expires_at: Some(std::time::Instant::now()),
+        }));
+        client.service_account = Some(Arc::new(service_account));
+        client.auto_refresh = true;
+        client
+    }
+
+    /// Returns the service account the client authenticates as, if any.
+    pub fn service_account(&self) -> Option<&ServiceAccount> {
+        self.service_account.as_deref()
+    }
+
+    /// Exchanges a new assertion of the service account for an access token.
+    pub(crate) async fn refresh_service_account_token(
+        &self,
+        service_account: &ServiceAccount,
+    ) -> ClientResult<AccessToken> {
+        let assertion = service_account.assertion()?;
+        let params = [
+            ("grant_type", "urn:ietf:params:oauth:grant-type:jwt-bearer"),
+            ("assertion", &assertion),
+        ];
+        let client = reqwest::Client::new();
+        let resp = client
+            .post(service_account.token_uri())
+            .header(
+                reqwest::header::ACCEPT,
+                reqwest::header::HeaderValue::from_static("application/json"),
+            )
+            .form(&params)
+            .send()
+            .await?;
+
+        // Delegation that is not set up for the scopes is only reported in the body.
+        let (status, headers) = (resp.status(), resp.headers().clone());
+        if !status.is_success() {
+            return Err(ClientError::HttpError {
+                status,
+                headers,
+                error: resp.text().await?,
+            });
+        }
+
+        let t: AccessToken = resp.json().await?;
+
+        *self.token.write().await = InnerToken {
+            access_token: t.access_token.clone(),
+            refresh_token: String::new(),
+            expires_at: Self::compute_expires_at(t.expires_in),
+        };
+
+        Ok(t)
+    }
+}
+"#;
+
+/// Returns the service account module of the Google APIs, if the API is one of them.
+pub fn generate_service_account(proper_name: &str) -> Option<String> {
+    if proper_name.starts_with("Google") {
+        Some(TEMPLATE.to_string())
+    } else {
+        None
+    }
+}
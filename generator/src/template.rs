@@ -51,6 +51,16 @@ impl Template {
                         r#"if let Some(u) = {} {{ query_args.push(("{}".to_string(), u.to_string())); }}"#,
                         nam, prop
                     ));
+                } else if value == "Option<&[String]>" {
+                    a(&format!(
+                        r#"if let Some(v) = {} {{ query_args.push(("{}".to_string(), v.join(" "))); }}"#,
+                        nam, prop
+                    ));
+                } else if value.starts_with("Option<") {
+                    a(&format!(
+                        r#"if let Some(v) = {} {{ query_args.push(("{}".to_string(), v.to_string())); }}"#,
+                        nam, prop
+                    ));
                 } else if value == "uuid::Uuid" {
                     a(&format!(
                         r#"if {}.to_string() != uuid::Uuid::nil().to_string() {{ query_args.push(("{}".to_string(), {}.to_string())); }}"#,
@@ -326,6 +336,28 @@ crate::progenitor_support::encode_path(&number.to_string()),), None);
         let want = r#"let url = self.client.url(
 &format!("/path/{}:command",
 crate::progenitor_support::encode_path(&param.to_string()),), None);
+"#;
+        Ok(assert_eq!(want, &out))
+    }
+
+    #[test]
+    fn compile_with_optional_query() -> Result<()> {
+        let t = parse("/measure")?;
+        let query_params = [
+            ("limit", "Option<u64>", "limit"),
+            ("tags", "Option<&[String]>", "tags"),
+        ]
+        .iter()
+        .map(|(n, t, p)| (n.to_string(), (t.to_string(), p.to_string())))
+        .collect();
+        let out = t.compile(query_params, "None");
+        let want = r#"let mut query_args: Vec<(String, String)> = Default::default();
+if let Some(v) = limit { query_args.push(("limit".to_string(), v.to_string())); }
+if let Some(v) = tags { query_args.push(("tags".to_string(), v.join(" "))); }
+let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+let url = self.client.url(
+&format!("/measure?{}",
+query_), None);
 "#;
         Ok(assert_eq!(want, &out))
     }
@@ -40,12 +40,14 @@ pub fn generate_types(ts: &mut TypeSpace, proper_name: &str) -> Result<String> {
                     );
                     a(&p);
                 }
-                TypeDetails::OneOf(omap, _) => a(&do_one_of_type(ts, omap, sn)),
-                TypeDetails::AnyOf(omap, _) => {
+                TypeDetails::OneOf(omap, sd) => {
+                    a(&do_one_of_type(ts, omap, sd.discriminator.as_ref(), sn))
+                }
+                TypeDetails::AnyOf(omap, sd) => {
                     // When Stripe uses anyof what they really mean is one of.
                     // Because something can not both be a string and an object.
                     if proper_name == "Stripe" {
-                        a(&do_one_of_type(ts, omap, sn));
+                        a(&do_one_of_type(ts, omap, sd.discriminator.as_ref(), sn));
                     } else {
                         a(&do_all_of_type(ts, omap, sn));
                     }
@@ -100,6 +102,7 @@ pub fn generate_types(ts: &mut TypeSpace, proper_name: &str) -> Result<String> {
                         || sn == "MinimalRepository"
                         || sn == "WorkflowRun"
                         || sn == "CheckAnnotation"
+                        || (sn.ends_with("Request") && proper_name == "Slack")
                     {
                         a(
                             "#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, \
@@ -110,6 +113,9 @@ pub fn generate_types(ts: &mut TypeSpace, proper_name: &str) -> Result<String> {
                              JsonSchema)]");
                     }
                     a(&format!("pub struct {} {{", sn));
+                    // The name of the map keeping the fields missing from the spec,
+                    // unless a property of the object already has it.
+                    let mut extra = "extra";
                     for (name, tid) in omap.iter() {
                         if let Ok(mut rt) = ts.render_type(tid, true) {
                             // Stripe has some really weird recursive types.
@@ -314,11 +320,22 @@ pub fn generate_types(ts: &mut TypeSpace, proper_name: &str) -> Result<String> {
                                 println!("{} {}", sn, prop);
                             }
 
+                            if prop == "extra" {
+                                extra = "extra_fields";
+                            }
+
                             a(&format!("pub {}: {},", prop, rt));
                         } else {
                             bail!("rendering type {} {:?} failed", name, tid);
                         }
                     }
+                    a("/// The fields of the object that are not in the spec.");
+                    a(r#"#[cfg(feature = "extra-fields")]"#);
+                    a("#[serde(flatten)]");
+                    a(&format!(
+                        "pub {}: serde_json::Map<String, serde_json::Value>,",
+                        extra
+                    ));
                     a("}");
                     a("");
                 }
@@ -334,7 +351,51 @@ pub fn generate_types(ts: &mut TypeSpace, proper_name: &str) -> Result<String> {
     Ok(out.to_string())
 }
 
-fn do_one_of_type(ts: &mut TypeSpace, omap: &[crate::TypeId], sn: String) -> String {
+/// Returns the value of the discriminator for each of the types, from the mapping
+/// of the discriminator or else the name of the schema.
+///
+/// Inline schemas can not be picked by the discriminator, if any of the types is
+/// one this returns None.
+fn discriminator_tags(
+    ts: &TypeSpace,
+    omap: &[crate::TypeId],
+    discriminator: &openapiv3::Discriminator,
+) -> Option<Vec<(String, crate::TypeId)>> {
+    let mut tags: Vec<(String, crate::TypeId)> = Default::default();
+    for (tag, reference) in &discriminator.mapping {
+        // The mapping can hold either a reference or the name of a schema.
+        let reference = if reference.starts_with('#') {
+            reference.to_string()
+        } else {
+            format!("#/components/schemas/{}", reference)
+        };
+
+        if let Some(tid) = ts.name_to_id.get(&reference) {
+            if omap.contains(tid) {
+                tags.push((tag.to_string(), tid.clone()));
+            }
+        }
+    }
+
+    for tid in omap {
+        if tags.iter().any(|(_, t)| t == tid) {
+            continue;
+        }
+
+        let name = ts.id_to_entry.get(tid)?.name.as_ref()?;
+        let schema = name.strip_prefix("#/components/schemas/")?;
+        tags.push((schema.to_string(), tid.clone()));
+    }
+
+    Some(tags)
+}
+
+fn do_one_of_type(
+    ts: &mut TypeSpace,
+    omap: &[crate::TypeId],
+    discriminator: Option<&openapiv3::Discriminator>,
+    sn: String,
+) -> String {
     let mut out = String::new();
 
     let mut a = |s: &str| {
@@ -342,6 +403,14 @@ fn do_one_of_type(ts: &mut TypeSpace, omap: &[crate::TypeId], sn: String) -> Str
         out.push('\n');
     };
 
+    // With a discriminator we can pick the variant by its value, rather than by
+    // the first type the object happens to parse as.
+    //
+    // None of the bundled specs declare a discriminator on a oneOf, so the unions of
+    // the generated crates, like the GitHub issue events or the Stripe payment
+    // sources, are still untagged, and read as before, until their specs carry one.
+    let tags = discriminator.and_then(|d| discriminator_tags(ts, omap, d));
+
     // Get the description.
     let mut description = "All of the following types:\n\n".to_string();
 
@@ -367,6 +436,13 @@ fn do_one_of_type(ts: &mut TypeSpace, omap: &[crate::TypeId], sn: String) -> Str
             flatten = false;
         }
     }
+    if let (Some(d), Some(_)) = (discriminator, &tags) {
+        description.push_str(&format!(
+            "\nThe type is picked by the value of the `{}` property, and kept as `Unknown` when \
+             the value is not one the spec knows of.\n",
+            d.property_name
+        ));
+    }
     description.push_str(
         "\nYou can easily convert this enum to the inner value with `From` and `Into`, as both \
          are implemented for each type.\n",
@@ -374,9 +450,16 @@ fn do_one_of_type(ts: &mut TypeSpace, omap: &[crate::TypeId], sn: String) -> Str
     description = format!("/// {}", description.replace('\n', "\n/// "));
     a(&description);
 
-    a("#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]");
-    if !flatten {
+    if tags.is_some() {
+        // The types declare the discriminator themselves, so they are serialized
+        // as is, and only deserializing needs to look at it.
+        a("#[derive(Serialize, PartialEq, Debug, Clone, JsonSchema)]");
         a("#[serde(untagged)]");
+    } else {
+        a("#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]");
+        if !flatten {
+            a("#[serde(untagged)]");
+        }
     }
     a(&format!("pub enum {} {{", sn));
     let mut name_map: BTreeMap<String, String> = Default::default();
@@ -384,6 +467,7 @@ fn do_one_of_type(ts: &mut TypeSpace, omap: &[crate::TypeId], sn: String) -> Str
     // sometimes parse the wrong value. It's better to instead use the functions we
     // inject that force the value to a specific type.
     let mut fns: Vec<String> = Default::default();
+    let mut variants: BTreeMap<crate::TypeId, String> = Default::default();
     for tid in omap.iter() {
         let name = ts.render_type(tid, true).unwrap();
 
@@ -399,6 +483,7 @@ fn do_one_of_type(ts: &mut TypeSpace, omap: &[crate::TypeId], sn: String) -> Str
         } else {
             struct_name(&name)
         };
+        variants.insert(tid.clone(), fn_name.to_string());
 
         if !fns.contains(&fn_name) {
             // Try to render the docs.
@@ -414,13 +499,53 @@ fn do_one_of_type(ts: &mut TypeSpace, omap: &[crate::TypeId], sn: String) -> Str
             fns.push(fn_name);
         }
     }
+    if tags.is_some() {
+        // Values the API adds after the spec was written are kept, rather than failing
+        // the whole response, or being read as whichever type they happen to parse as.
+        a("/// An object with a value of the discriminator the spec does not know of.");
+        a("Unknown(serde_json::Value),");
+    }
     a("}");
     a("");
 
+    if let (Some(d), Some(tags)) = (discriminator, &tags) {
+        let arms: Vec<String> = tags
+            .iter()
+            .map(|(tag, tid)| {
+                format!(
+                    "Some({:?}) => serde_json::from_value(value).map({}::{}),",
+                    tag, sn, variants[tid]
+                )
+            })
+            .collect();
+        a(&format!(
+            r#"impl<'de> Deserialize<'de> for {} {{
+                fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+                where
+                    D: serde::Deserializer<'de>,
+                {{
+                    let value = serde_json::Value::deserialize(deserializer)?;
+                    match value.get({:?}).and_then(serde_json::Value::as_str) {{
+                        {}
+                        Some(_) => Ok({}::Unknown(value)),
+                        None => Err(serde::de::Error::missing_field({:?})),
+                    }}
+                    .map_err(serde::de::Error::custom)
+                }}
+            }}"#,
+            sn,
+            d.property_name,
+            arms.join("\n"),
+            sn,
+            d.property_name,
+        ));
+        a("");
+    }
+
     // Render the implementation to easily unpack these things for the end user.
     a(&format!("impl {} {{", sn));
     for (fn_name, name) in &name_map {
-        if name_map.len() > 1 {
+        if name_map.len() > 1 || tags.is_some() {
             a(&format!(
                 r#"pub fn {}(&self) -> Option<&{}> {{
                             if let {}::{}(ref_) = self {{
@@ -586,3 +711,108 @@ fn do_all_of_type(ts: &mut TypeSpace, omap: &[crate::TypeId], sn: String) -> Str
 
     out
 }
+
+#[cfg(test)]
+mod test {
+    use anyhow::Result;
+
+    use super::generate_types;
+    use crate::{clean_name, TypeSpace};
+
+    fn generate(schemas: &str) -> Result<String> {
+        let components: openapiv3::Components = serde_yaml::from_str(schemas)?;
+
+        let mut ts = TypeSpace::new();
+        for (sn, s) in components.schemas.iter() {
+            let id = ts.select(Some(clean_name(sn).as_str()), s, "")?;
+            ts.populate_ref(Some(sn.as_str()), Some(id), "schema")?;
+        }
+
+        generate_types(&mut ts, "Test")
+    }
+
+    const SCHEMAS: &str = r##"
+schemas:
+  card:
+    type: object
+    properties:
+      object:
+        type: string
+      last4:
+        type: string
+  bank_account:
+    type: object
+    properties:
+      object:
+        type: string
+      routing_number:
+        type: string
+  payment_source:
+    oneOf:
+      - $ref: "#/components/schemas/card"
+      - $ref: "#/components/schemas/bank_account"
+"##;
+
+    #[test]
+    fn one_of_without_discriminator() -> Result<()> {
+        let types = generate(SCHEMAS)?;
+
+        assert!(types.contains("#[serde(untagged)]\npub enum PaymentSourceOneOf {"));
+        assert!(!types.contains("impl<'de> Deserialize<'de> for PaymentSourceOneOf"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn one_of_with_discriminator() -> Result<()> {
+        let types = generate(&format!(
+            "{}{}",
+            SCHEMAS,
+            r##"    discriminator:
+      propertyName: object
+      mapping:
+        bank: "#/components/schemas/bank_account"
+"##
+        ))?;
+
+        assert!(types.contains("impl<'de> Deserialize<'de> for PaymentSourceOneOf"));
+        assert!(types.contains(r#"match value.get("object")"#));
+        // From the mapping, and from the name of the schema.
+        assert!(types.contains(
+            r#"Some("bank") => serde_json::from_value(value).map(PaymentSourceOneOf::BankAccount),"#
+        ));
+        assert!(types.contains(
+            r#"Some("card") => serde_json::from_value(value).map(PaymentSourceOneOf::Card),"#
+        ));
+        // Any other tag is kept as is, while none at all is an error.
+        assert!(types.contains(r#"Some(_) => Ok(PaymentSourceOneOf::Unknown(value)),"#));
+        assert!(types.contains(r#"None => Err(serde::de::Error::missing_field("object")),"#));
+        // The fixture is compiled, and deserialized into, by tests/types.rs.
+        assert_eq!(
+            types,
+            include_str!("../tests/fixtures/one_of_with_discriminator.rs")
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn one_of_with_discriminator_and_inline_type() -> Result<()> {
+        let types = generate(&format!(
+            "{}{}",
+            SCHEMAS,
+            r##"      - type: object
+        properties:
+          object:
+            type: string
+    discriminator:
+      propertyName: object
+"##
+        ))?;
+
+        // The inline type has no value for the discriminator.
+        assert!(!types.contains("impl<'de> Deserialize<'de> for PaymentSourceOneOf"));
+
+        Ok(())
+    }
+}
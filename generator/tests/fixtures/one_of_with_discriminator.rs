@@ -0,0 +1,95 @@
+//! The data types sent to and returned from the API client.
+    use schemars::JsonSchema;
+    use serde::{Serialize, Deserialize};
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Card {
+#[serde(default,
+skip_serializing_if = "String::is_empty",
+                                        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+rename = "last4"
+)]
+pub last_4: String,
+#[serde(default,
+skip_serializing_if = "String::is_empty",
+                                        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+)]
+pub object: String,
+/// The fields of the object that are not in the spec.
+#[cfg(feature = "extra-fields")]
+#[serde(flatten)]
+pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct BankAccount {
+#[serde(default,
+skip_serializing_if = "String::is_empty",
+                                        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+)]
+pub object: String,
+#[serde(default,
+skip_serializing_if = "String::is_empty",
+                                        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+)]
+pub routing_number: String,
+/// The fields of the object that are not in the spec.
+#[cfg(feature = "extra-fields")]
+#[serde(flatten)]
+pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// All of the following types:
+/// 
+/// - `Card`
+/// - `BankAccount`
+/// 
+/// The type is picked by the value of the `object` property, and kept as `Unknown` when the value is not one the spec knows of.
+/// 
+/// You can easily convert this enum to the inner value with `From` and `Into`, as both are implemented for each type.
+/// 
+#[derive(Serialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(untagged)]
+pub enum PaymentSourceOneOf {
+Card(Card),
+BankAccount(BankAccount),
+/// An object with a value of the discriminator the spec does not know of.
+Unknown(serde_json::Value),
+}
+
+impl<'de> Deserialize<'de> for PaymentSourceOneOf {
+                fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+                where
+                    D: serde::Deserializer<'de>,
+                {
+                    let value = serde_json::Value::deserialize(deserializer)?;
+                    match value.get("object").and_then(serde_json::Value::as_str) {
+                        Some("bank") => serde_json::from_value(value).map(PaymentSourceOneOf::BankAccount),
+Some("card") => serde_json::from_value(value).map(PaymentSourceOneOf::Card),
+                        Some(_) => Ok(PaymentSourceOneOf::Unknown(value)),
+                        None => Err(serde::de::Error::missing_field("object")),
+                    }
+                    .map_err(serde::de::Error::custom)
+                }
+            }
+
+impl PaymentSourceOneOf {
+pub fn bank_account(&self) -> Option<&BankAccount> {
+                            if let PaymentSourceOneOf::BankAccount(ref_) = self {
+                                return Some(ref_);
+                            }
+                            None
+                        }
+
+pub fn card(&self) -> Option<&Card> {
+                            if let PaymentSourceOneOf::Card(ref_) = self {
+                                return Some(ref_);
+                            }
+                            None
+                        }
+
+}
+
+
+
+
@@ -0,0 +1,77 @@
+//! Compiles the types the generator renders for the specs in its unit tests.
+
+use serde_json::json;
+
+#[allow(unexpected_cfgs)]
+#[rustfmt::skip]
+#[path = "fixtures/one_of_with_discriminator.rs"]
+mod types;
+
+/// The helpers the rendered types call into, as in the utils of a generated crate.
+mod utils {
+    pub mod deserialize_null_string {
+        use serde::{Deserialize, Deserializer};
+
+        pub fn deserialize<'de, D>(deserializer: D) -> Result<String, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            Ok(Option::<String>::deserialize(deserializer)?.unwrap_or_default())
+        }
+    }
+}
+
+use types::{BankAccount, PaymentSourceOneOf};
+
+#[test]
+fn test_one_of_picks_type_by_discriminator() {
+    let bank = serde_json::from_value::<PaymentSourceOneOf>(json!({
+        "object": "bank",
+        "routing_number": "110000000"
+    }))
+    .unwrap();
+    assert_eq!(
+        bank,
+        PaymentSourceOneOf::BankAccount(BankAccount {
+            object: "bank".to_string(),
+            routing_number: "110000000".to_string(),
+        })
+    );
+
+    let card = serde_json::from_value::<PaymentSourceOneOf>(json!({
+        "object": "card",
+        "last4": "4242"
+    }))
+    .unwrap();
+    assert_eq!(card.card().unwrap().last_4, "4242");
+    assert!(card.bank_account().is_none());
+
+    // Both round trip through the types they were picked as.
+    for source in [bank, card] {
+        let value = serde_json::to_value(&source).unwrap();
+        assert_eq!(
+            serde_json::from_value::<PaymentSourceOneOf>(value).unwrap(),
+            source
+        );
+    }
+}
+
+#[test]
+fn test_one_of_keeps_unknown_discriminator() {
+    // A tag the spec does not know of is kept as is, rather than read as the
+    // card it would parse as.
+    let value = json!({
+        "object": "source",
+        "last4": "4242"
+    });
+    let source = serde_json::from_value::<PaymentSourceOneOf>(value.clone()).unwrap();
+    assert_eq!(source, PaymentSourceOneOf::Unknown(value.clone()));
+    assert!(source.card().is_none());
+    assert_eq!(serde_json::to_value(&source).unwrap(), value);
+
+    // A payload without the discriminator at all is an error.
+    let error =
+        serde_json::from_value::<PaymentSourceOneOf>(json!({ "last4": "4242" })).unwrap_err();
+    assert!(error.to_string().contains("missing field `object`"));
+    assert!(serde_json::from_value::<PaymentSourceOneOf>(json!("4242")).is_err());
+}
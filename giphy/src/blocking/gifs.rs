@@ -0,0 +1,204 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Gifs::get`], set with a builder.
+pub struct GifsGetRequest<'a> {
+    inner: crate::gifs::GifsGetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> GifsGetRequest<'a> {
+    /// Sets the `ids` query parameter.
+    pub fn ids(mut self, ids: &'a str) -> Self {
+        self.inner = self.inner.ids(ids);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::GetGifsByResponse>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Gifs::random`], set with a builder.
+pub struct GifsRandomRequest<'a> {
+    inner: crate::gifs::GifsRandomRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> GifsRandomRequest<'a> {
+    /// Sets the `tag` query parameter.
+    pub fn tag(mut self, tag: &'a str) -> Self {
+        self.inner = self.inner.tag(tag);
+        self
+    }
+
+    /// Sets the `rating` query parameter.
+    pub fn rating(mut self, rating: &'a str) -> Self {
+        self.inner = self.inner.rating(rating);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::RandomGifResponse>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Gifs::search`], set with a builder.
+pub struct GifsSearchRequest<'a> {
+    inner: crate::gifs::GifsSearchRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> GifsSearchRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.inner = self.inner.offset(offset);
+        self
+    }
+
+    /// Sets the `rating` query parameter.
+    pub fn rating(mut self, rating: &'a str) -> Self {
+        self.inner = self.inner.rating(rating);
+        self
+    }
+
+    /// Sets the `lang` query parameter.
+    pub fn lang(mut self, lang: &'a str) -> Self {
+        self.inner = self.inner.lang(lang);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::GetGifsByResponse>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Gifs::trending`], set with a builder.
+pub struct GifsTrendingRequest<'a> {
+    inner: crate::gifs::GifsTrendingRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> GifsTrendingRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.inner = self.inner.offset(offset);
+        self
+    }
+
+    /// Sets the `rating` query parameter.
+    pub fn rating(mut self, rating: &'a str) -> Self {
+        self.inner = self.inner.rating(rating);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::GetGifsByResponse>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::gifs::Gifs`].
+pub struct Gifs {
+    inner: crate::gifs::Gifs,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Gifs {
+    pub(crate) fn new(inner: crate::gifs::Gifs, runtime: Arc<tokio::runtime::Runtime>) -> Self {
+        Gifs { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::gifs::Gifs::get`].
+    pub fn get(&self, ids: &str) -> ClientResult<crate::Response<crate::types::GetGifsByResponse>> {
+        self.runtime.block_on(self.inner.get(ids))
+    }
+    /// The blocking version of [`crate::gifs::Gifs::get_request`].
+    pub fn get_request(&self) -> GifsGetRequest<'_> {
+        GifsGetRequest {
+            inner: self.inner.get_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::gifs::Gifs::random`].
+    pub fn random(
+        &self,
+        tag: &str,
+        rating: &str,
+    ) -> ClientResult<crate::Response<crate::types::RandomGifResponse>> {
+        self.runtime.block_on(self.inner.random(tag, rating))
+    }
+    /// The blocking version of [`crate::gifs::Gifs::random_request`].
+    pub fn random_request(&self) -> GifsRandomRequest<'_> {
+        GifsRandomRequest {
+            inner: self.inner.random_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::gifs::Gifs::search`].
+    pub fn search(
+        &self,
+        q: &str,
+        limit: i64,
+        offset: i64,
+        rating: &str,
+        lang: &str,
+    ) -> ClientResult<crate::Response<crate::types::GetGifsByResponse>> {
+        self.runtime
+            .block_on(self.inner.search(q, limit, offset, rating, lang))
+    }
+    /// The blocking version of [`crate::gifs::Gifs::search_request`].
+    pub fn search_request<'a>(&'a self, q: &'a str) -> GifsSearchRequest<'a> {
+        GifsSearchRequest {
+            inner: self.inner.search_request(q),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::gifs::Gifs::translate`].
+    pub fn translate(
+        &self,
+        s: &str,
+    ) -> ClientResult<crate::Response<crate::types::RandomGifResponse>> {
+        self.runtime.block_on(self.inner.translate(s))
+    }
+    /// The blocking version of [`crate::gifs::Gifs::trending`].
+    pub fn trending(
+        &self,
+        limit: i64,
+        offset: i64,
+        rating: &str,
+    ) -> ClientResult<crate::Response<crate::types::GetGifsByResponse>> {
+        self.runtime
+            .block_on(self.inner.trending(limit, offset, rating))
+    }
+    /// The blocking version of [`crate::gifs::Gifs::trending_request`].
+    pub fn trending_request(&self) -> GifsTrendingRequest<'_> {
+        GifsTrendingRequest {
+            inner: self.inner.trending_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::gifs::Gifs::get_gifs`].
+    pub fn get_gifs(
+        &self,
+        gif_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::RandomGifResponse>> {
+        self.runtime.block_on(self.inner.get_gifs(gif_id))
+    }
+}
@@ -0,0 +1,52 @@
+//! A blocking client, for the programs that are not async.
+//!
+//! It has the functions of the async [`Client`](crate::Client), which it runs on a
+//! runtime of its own, so they must not be called from within an async runtime. The
+//! `*_stream` functions are left out, the `*_all` ones returning all the pages at once.
+
+use std::{future::Future, sync::Arc};
+
+pub mod gifs;
+pub mod stickers;
+
+/// A blocking client, sending its requests with an async [`Client`](crate::Client).
+#[derive(Clone)]
+pub struct Client {
+    inner: crate::Client,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Client {
+    /// Wraps an async client, creating the runtime its requests run on.
+    pub fn new(client: crate::Client) -> std::io::Result<Self> {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()?;
+
+        Ok(Client {
+            inner: client,
+            runtime: Arc::new(runtime),
+        })
+    }
+
+    /// Returns the async client the requests are sent with.
+    pub fn inner(&self) -> &crate::Client {
+        &self.inner
+    }
+
+    /// Runs a future to completion on the runtime of the client, for the async
+    /// functions that have no blocking version.
+    pub fn block_on<F: Future>(&self, future: F) -> F::Output {
+        self.runtime.block_on(future)
+    }
+
+    /// The blocking version of [`crate::Client::gifs`].
+    pub fn gifs(&self) -> gifs::Gifs {
+        gifs::Gifs::new(self.inner.gifs(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::stickers`].
+    pub fn stickers(&self) -> stickers::Stickers {
+        stickers::Stickers::new(self.inner.stickers(), self.runtime.clone())
+    }
+}
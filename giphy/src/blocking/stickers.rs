@@ -0,0 +1,170 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Stickers::random`], set with a builder.
+pub struct StickersRandomRequest<'a> {
+    inner: crate::stickers::StickersRandomRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> StickersRandomRequest<'a> {
+    /// Sets the `tag` query parameter.
+    pub fn tag(mut self, tag: &'a str) -> Self {
+        self.inner = self.inner.tag(tag);
+        self
+    }
+
+    /// Sets the `rating` query parameter.
+    pub fn rating(mut self, rating: &'a str) -> Self {
+        self.inner = self.inner.rating(rating);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::RandomGifResponse>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Stickers::search`], set with a builder.
+pub struct StickersSearchRequest<'a> {
+    inner: crate::stickers::StickersSearchRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> StickersSearchRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.inner = self.inner.offset(offset);
+        self
+    }
+
+    /// Sets the `rating` query parameter.
+    pub fn rating(mut self, rating: &'a str) -> Self {
+        self.inner = self.inner.rating(rating);
+        self
+    }
+
+    /// Sets the `lang` query parameter.
+    pub fn lang(mut self, lang: &'a str) -> Self {
+        self.inner = self.inner.lang(lang);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::GetGifsByResponse>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Stickers::trending`], set with a builder.
+pub struct StickersTrendingRequest<'a> {
+    inner: crate::stickers::StickersTrendingRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> StickersTrendingRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.inner = self.inner.offset(offset);
+        self
+    }
+
+    /// Sets the `rating` query parameter.
+    pub fn rating(mut self, rating: &'a str) -> Self {
+        self.inner = self.inner.rating(rating);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::GetGifsByResponse>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::stickers::Stickers`].
+pub struct Stickers {
+    inner: crate::stickers::Stickers,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Stickers {
+    pub(crate) fn new(
+        inner: crate::stickers::Stickers,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Stickers { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::stickers::Stickers::random`].
+    pub fn random(
+        &self,
+        tag: &str,
+        rating: &str,
+    ) -> ClientResult<crate::Response<crate::types::RandomGifResponse>> {
+        self.runtime.block_on(self.inner.random(tag, rating))
+    }
+    /// The blocking version of [`crate::stickers::Stickers::random_request`].
+    pub fn random_request(&self) -> StickersRandomRequest<'_> {
+        StickersRandomRequest {
+            inner: self.inner.random_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::stickers::Stickers::search`].
+    pub fn search(
+        &self,
+        q: &str,
+        limit: i64,
+        offset: i64,
+        rating: &str,
+        lang: &str,
+    ) -> ClientResult<crate::Response<crate::types::GetGifsByResponse>> {
+        self.runtime
+            .block_on(self.inner.search(q, limit, offset, rating, lang))
+    }
+    /// The blocking version of [`crate::stickers::Stickers::search_request`].
+    pub fn search_request<'a>(&'a self, q: &'a str) -> StickersSearchRequest<'a> {
+        StickersSearchRequest {
+            inner: self.inner.search_request(q),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::stickers::Stickers::translate`].
+    pub fn translate(
+        &self,
+        s: &str,
+    ) -> ClientResult<crate::Response<crate::types::RandomGifResponse>> {
+        self.runtime.block_on(self.inner.translate(s))
+    }
+    /// The blocking version of [`crate::stickers::Stickers::trending`].
+    pub fn trending(
+        &self,
+        limit: i64,
+        offset: i64,
+        rating: &str,
+    ) -> ClientResult<crate::Response<crate::types::GetGifsByResponse>> {
+        self.runtime
+            .block_on(self.inner.trending(limit, offset, rating))
+    }
+    /// The blocking version of [`crate::stickers::Stickers::trending_request`].
+    pub fn trending_request(&self) -> StickersTrendingRequest<'_> {
+        StickersTrendingRequest {
+            inner: self.inner.trending_request(),
+            runtime: &self.runtime,
+        }
+    }
+}
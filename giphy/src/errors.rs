@@ -0,0 +1,99 @@
+//! The errors returned by the API.
+
+use std::fmt;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// An error returned by the API.
+///
+/// The API does not document a common shape for its errors, so this holds the whole
+/// body of the response along with the message found in it.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default, JsonSchema)]
+pub struct ApiError {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub message: String,
+    #[serde(default)]
+    pub body: serde_json::Value,
+}
+
+impl ApiError {
+    fn from_body(body: &[u8]) -> Option<Self> {
+        let body = serde_json::from_slice::<serde_json::Value>(body).ok()?;
+        let message = [
+            "message",
+            "error",
+            "errors",
+            "error_description",
+            "detail",
+            "meta",
+        ]
+        .iter()
+        .filter_map(|key| body.get(key))
+        .find_map(|value| {
+            value.as_str().or_else(|| {
+                value
+                    .get("message")
+                    .or_else(|| value.get("msg"))
+                    .and_then(|message| message.as_str())
+            })
+        })
+        .map(|message| message.to_string())
+        .unwrap_or_else(|| body.to_string());
+
+        Some(ApiError { message, body })
+    }
+}
+
+impl fmt::Display for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl crate::ClientError {
+    /// Turns an unsuccessful response into an error, parsing its body into an `ApiError`
+    /// when it holds one. Responses to requests that were rate limited become
+    /// `ClientError::RateLimited`. Those keep their `ApiError` as well.
+    pub(crate) fn from_response(
+        status: http::StatusCode,
+        headers: reqwest::header::HeaderMap,
+        body: &[u8],
+    ) -> Self {
+        let rate_limit = crate::RateLimitInfo::from_headers(&headers).unwrap_or_default();
+        if status == http::StatusCode::TOO_MANY_REQUESTS {
+            return crate::ClientError::RateLimited {
+                status,
+                duration: rate_limit.wait().map(|wait| wait.as_secs()),
+                info: rate_limit,
+                error: ApiError::from_body(body).map(Box::new),
+                headers,
+            };
+        }
+
+        if body.is_empty() {
+            return crate::ClientError::HttpError {
+                status,
+                headers,
+                error: "empty response".into(),
+            };
+        }
+
+        match ApiError::from_body(body) {
+            Some(error) => crate::ClientError::ApiError {
+                status,
+                headers,
+                error: Box::new(error),
+            },
+            None => crate::ClientError::HttpError {
+                status,
+                headers,
+                error: String::from_utf8_lossy(body).into(),
+            },
+        }
+    }
+}
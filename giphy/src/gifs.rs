@@ -1,6 +1,232 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Gifs::get`], set with a builder.
+pub struct GifsGetRequest<'a> {
+    client: &'a Gifs,
+    ids: Option<&'a str>,
+}
+
+impl<'a> GifsGetRequest<'a> {
+    /// Sets the `ids` query parameter.
+    pub fn ids(mut self, ids: &'a str) -> Self {
+        self.ids = Some(ids);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::GetGifsByResponse>> {
+        let Self { client, ids } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = ids {
+            query_args.push(("ids".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(&format!("/gifs?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Gifs::random`], set with a builder.
+pub struct GifsRandomRequest<'a> {
+    client: &'a Gifs,
+    tag: Option<&'a str>,
+    rating: Option<&'a str>,
+}
+
+impl<'a> GifsRandomRequest<'a> {
+    /// Sets the `tag` query parameter.
+    pub fn tag(mut self, tag: &'a str) -> Self {
+        self.tag = Some(tag);
+        self
+    }
+
+    /// Sets the `rating` query parameter.
+    pub fn rating(mut self, rating: &'a str) -> Self {
+        self.rating = Some(rating);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::RandomGifResponse>> {
+        let Self {
+            client,
+            tag,
+            rating,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = rating {
+            query_args.push(("rating".to_string(), v.to_string()));
+        }
+        if let Some(v) = tag {
+            query_args.push(("tag".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(&format!("/gifs/random?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Gifs::search`], set with a builder.
+pub struct GifsSearchRequest<'a> {
+    client: &'a Gifs,
+    q: &'a str,
+    limit: Option<i64>,
+    offset: Option<i64>,
+    rating: Option<&'a str>,
+    lang: Option<&'a str>,
+}
+
+impl<'a> GifsSearchRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Sets the `rating` query parameter.
+    pub fn rating(mut self, rating: &'a str) -> Self {
+        self.rating = Some(rating);
+        self
+    }
+
+    /// Sets the `lang` query parameter.
+    pub fn lang(mut self, lang: &'a str) -> Self {
+        self.lang = Some(lang);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::GetGifsByResponse>> {
+        let Self {
+            client,
+            q,
+            limit,
+            offset,
+            rating,
+            lang,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = lang {
+            query_args.push(("lang".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = offset {
+            query_args.push(("offset".to_string(), v.to_string()));
+        }
+        if !q.is_empty() {
+            query_args.push(("q".to_string(), q.to_string()));
+        }
+        if let Some(v) = rating {
+            query_args.push(("rating".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(&format!("/gifs/search?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Gifs::trending`], set with a builder.
+pub struct GifsTrendingRequest<'a> {
+    client: &'a Gifs,
+    limit: Option<i64>,
+    offset: Option<i64>,
+    rating: Option<&'a str>,
+}
+
+impl<'a> GifsTrendingRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Sets the `rating` query parameter.
+    pub fn rating(mut self, rating: &'a str) -> Self {
+        self.rating = Some(rating);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::GetGifsByResponse>> {
+        let Self {
+            client,
+            limit,
+            offset,
+            rating,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = offset {
+            query_args.push(("offset".to_string(), v.to_string()));
+        }
+        if let Some(v) = rating {
+            query_args.push(("rating".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/gifs/trending?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct Gifs {
     pub client: Client,
 }
@@ -43,6 +269,22 @@ impl Gifs {
             )
             .await
     }
+    /**
+     * Get GIFs by ID.
+     *
+     * This function performs a `GET` to the `/gifs` endpoint.
+     *
+     * As opposed to `get`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * A multiget version of the get GIF by ID endpoint.
+     *
+     */
+    pub fn get_request(&self) -> GifsGetRequest<'_> {
+        GifsGetRequest {
+            client: self,
+            ids: None,
+        }
+    }
     /**
      * Random GIF.
      *
@@ -80,6 +322,23 @@ impl Gifs {
             )
             .await
     }
+    /**
+     * Random GIF.
+     *
+     * This function performs a `GET` to the `/gifs/random` endpoint.
+     *
+     * As opposed to `random`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Returns a random GIF, limited by tag. Excluding the tag parameter will return a random GIF from the GIPHY catalog.
+     *
+     */
+    pub fn random_request(&self) -> GifsRandomRequest<'_> {
+        GifsRandomRequest {
+            client: self,
+            tag: None,
+            rating: None,
+        }
+    }
     /**
      * Search GIFs.
      *
@@ -132,6 +391,26 @@ impl Gifs {
             )
             .await
     }
+    /**
+     * Search GIFs.
+     *
+     * This function performs a `GET` to the `/gifs/search` endpoint.
+     *
+     * As opposed to `search`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Search all GIPHY GIFs for a word or phrase. Punctuation will be stripped and ignored.  Use a plus or url encode for phrases. Example paul+rudd, ryan+gosling or american+psycho.
+     *
+     */
+    pub fn search_request<'a>(&'a self, q: &'a str) -> GifsSearchRequest<'a> {
+        GifsSearchRequest {
+            client: self,
+            q,
+            limit: None,
+            offset: None,
+            rating: None,
+            lang: None,
+        }
+    }
     /**
      * Translate phrase to GIF.
      *
@@ -208,6 +487,24 @@ impl Gifs {
             )
             .await
     }
+    /**
+     * Trending GIFs.
+     *
+     * This function performs a `GET` to the `/gifs/trending` endpoint.
+     *
+     * As opposed to `trending`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Fetch GIFs currently trending online. Hand curated by the GIPHY editorial team.  The data returned mirrors the GIFs showcased on the GIPHY homepage. Returns 25 results by default.
+     *
+     */
+    pub fn trending_request(&self) -> GifsTrendingRequest<'_> {
+        GifsTrendingRequest {
+            client: self,
+            limit: None,
+            offset: None,
+            rating: None,
+        }
+    }
     /**
      * Get GIF by Id.
      *
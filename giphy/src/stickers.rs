@@ -1,6 +1,200 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Stickers::random`], set with a builder.
+pub struct StickersRandomRequest<'a> {
+    client: &'a Stickers,
+    tag: Option<&'a str>,
+    rating: Option<&'a str>,
+}
+
+impl<'a> StickersRandomRequest<'a> {
+    /// Sets the `tag` query parameter.
+    pub fn tag(mut self, tag: &'a str) -> Self {
+        self.tag = Some(tag);
+        self
+    }
+
+    /// Sets the `rating` query parameter.
+    pub fn rating(mut self, rating: &'a str) -> Self {
+        self.rating = Some(rating);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::RandomGifResponse>> {
+        let Self {
+            client,
+            tag,
+            rating,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = rating {
+            query_args.push(("rating".to_string(), v.to_string()));
+        }
+        if let Some(v) = tag {
+            query_args.push(("tag".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/stickers/random?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Stickers::search`], set with a builder.
+pub struct StickersSearchRequest<'a> {
+    client: &'a Stickers,
+    q: &'a str,
+    limit: Option<i64>,
+    offset: Option<i64>,
+    rating: Option<&'a str>,
+    lang: Option<&'a str>,
+}
+
+impl<'a> StickersSearchRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Sets the `rating` query parameter.
+    pub fn rating(mut self, rating: &'a str) -> Self {
+        self.rating = Some(rating);
+        self
+    }
+
+    /// Sets the `lang` query parameter.
+    pub fn lang(mut self, lang: &'a str) -> Self {
+        self.lang = Some(lang);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::GetGifsByResponse>> {
+        let Self {
+            client,
+            q,
+            limit,
+            offset,
+            rating,
+            lang,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = lang {
+            query_args.push(("lang".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = offset {
+            query_args.push(("offset".to_string(), v.to_string()));
+        }
+        if !q.is_empty() {
+            query_args.push(("q".to_string(), q.to_string()));
+        }
+        if let Some(v) = rating {
+            query_args.push(("rating".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/stickers/search?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Stickers::trending`], set with a builder.
+pub struct StickersTrendingRequest<'a> {
+    client: &'a Stickers,
+    limit: Option<i64>,
+    offset: Option<i64>,
+    rating: Option<&'a str>,
+}
+
+impl<'a> StickersTrendingRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Sets the `rating` query parameter.
+    pub fn rating(mut self, rating: &'a str) -> Self {
+        self.rating = Some(rating);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::GetGifsByResponse>> {
+        let Self {
+            client,
+            limit,
+            offset,
+            rating,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = offset {
+            query_args.push(("offset".to_string(), v.to_string()));
+        }
+        if let Some(v) = rating {
+            query_args.push(("rating".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/stickers/trending?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct Stickers {
     pub client: Client,
 }
@@ -50,6 +244,23 @@ impl Stickers {
             )
             .await
     }
+    /**
+     * Random Sticker.
+     *
+     * This function performs a `GET` to the `/stickers/random` endpoint.
+     *
+     * As opposed to `random`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Returns a random GIF, limited by tag. Excluding the tag parameter will return a random GIF from the GIPHY catalog.
+     *
+     */
+    pub fn random_request(&self) -> StickersRandomRequest<'_> {
+        StickersRandomRequest {
+            client: self,
+            tag: None,
+            rating: None,
+        }
+    }
     /**
      * Search Stickers.
      *
@@ -104,6 +315,26 @@ impl Stickers {
             )
             .await
     }
+    /**
+     * Search Stickers.
+     *
+     * This function performs a `GET` to the `/stickers/search` endpoint.
+     *
+     * As opposed to `search`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Replicates the functionality and requirements of the classic GIPHY search, but returns animated stickers rather than GIFs.
+     *
+     */
+    pub fn search_request<'a>(&'a self, q: &'a str) -> StickersSearchRequest<'a> {
+        StickersSearchRequest {
+            client: self,
+            q,
+            limit: None,
+            offset: None,
+            rating: None,
+            lang: None,
+        }
+    }
     /**
      * Translate phrase to Sticker.
      *
@@ -182,4 +413,22 @@ impl Stickers {
             )
             .await
     }
+    /**
+     * Trending Stickers.
+     *
+     * This function performs a `GET` to the `/stickers/trending` endpoint.
+     *
+     * As opposed to `trending`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Fetch Stickers currently trending online. Hand curated by the GIPHY editorial team. Returns 25 results by default.
+     *
+     */
+    pub fn trending_request(&self) -> StickersTrendingRequest<'_> {
+        StickersTrendingRequest {
+            client: self,
+            limit: None,
+            offset: None,
+            rating: None,
+        }
+    }
 }
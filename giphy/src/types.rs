@@ -121,6 +121,10 @@ pub struct Images {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub preview_gif: Option<LoopingAllOf>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -131,15 +135,16 @@ pub enum Type {
     #[serde(rename = "gif")]
     #[default]
     Gif,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Type {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Type::Gif => "gif",
-            Type::FallthroughString => "*",
+            Type::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -317,6 +322,10 @@ pub struct Gif {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub username: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -404,6 +413,10 @@ pub struct Image {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub width: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The Meta Object contains basic information regarding the request, whether it was successful, and the response given by the API.  Check `responses` to see a description of types of response codes the API might give you under different cirumstances.
@@ -440,6 +453,10 @@ pub struct Meta {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub status: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The Pagination Object contains information relating to the number of total results available as well as the number of results fetched and their relative positions.
@@ -476,6 +493,10 @@ pub struct Pagination {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_count: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The User Object contains information about the user associated with a GIF and URLs to assets such as that user's avatar image, profile, and more.
@@ -535,6 +556,10 @@ pub struct User {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub username: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -557,6 +582,10 @@ pub struct GetGifsByResponse {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub pagination: Option<Pagination>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -569,4 +598,8 @@ pub struct RandomGifResponse {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub meta: Option<Meta>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
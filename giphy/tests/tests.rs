@@ -1 +1,49 @@
+use std::mem;
 
+use wiremock::{
+    matchers::{method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+use giphy_api::Client;
+
+#[tokio::test]
+async fn test_search_pagination_gives_the_next_offset() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/gifs/search"))
+        .and(query_param("q", "cats"))
+        .and(query_param("limit", "2"))
+        .and(query_param("offset", "2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "data": [{ "id": "c" }, { "id": "d" }],
+            "pagination": { "count": 2, "offset": 2, "total_count": 5 }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut client = Client::new("api_key");
+    client.with_host_override(server.uri());
+
+    let page = client
+        .gifs()
+        .search("cats", 2, 2, "", "")
+        .await
+        .unwrap()
+        .body;
+    let ids = page
+        .data
+        .iter()
+        .map(|gif| gif.id.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(ids, vec!["c", "d"]);
+
+    // GIPHY pages through `offset`, its pagination object says where the next page starts.
+    let pagination = page.pagination.unwrap();
+    assert_eq!(pagination.offset + pagination.count, 4);
+    assert!(pagination.offset + pagination.count < pagination.total_count);
+
+    mem::drop(server)
+}
@@ -6,7 +6,7 @@ use octorust::http_cache::FileBasedCache;
 use octorust::{
     auth::{Credentials, InstallationTokenGenerator, JWTCredentials},
     types::{Order, ReposListOrgSort, ReposListOrgType},
-    Client,
+    Client, ClientBuilder,
 };
 
 #[tokio::main]
@@ -36,17 +36,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     let token_generator = InstallationTokenGenerator::new(app_installation_id, jwt);
 
-    let http = reqwest::Client::builder().build()?;
-    let retry_policy =
-        reqwest_retry::policies::ExponentialBackoff::builder().build_with_max_retries(3);
-    let client = reqwest_middleware::ClientBuilder::new(http)
-        // Trace HTTP requests. See the tracing crate to make use of these traces.
-        .with(reqwest_tracing::TracingMiddleware::default())
-        // Retry failed requests.
-        .with(reqwest_retry::RetryTransientMiddleware::new_with_policy(
-            retry_policy,
-        ))
-        .build();
+    let client = ClientBuilder::new().max_retries(3).build()?;
 
     #[cfg(not(feature = "httpcache"))]
     let github = Client::custom(
@@ -1,6 +1,1157 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Actions::list_selected_repositories_enabled_github_actions_organization`], set with a builder.
+pub struct ActionsListSelectedRepositoriesEnabledGithubActionsOrganizationRequest<'a> {
+    client: &'a Actions,
+    org: &'a str,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> ActionsListSelectedRepositoriesEnabledGithubActionsOrganizationRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<
+        crate::Response<
+            crate::types::ActionsListSelectedRepositoriesEnabledGithubOrganizationResponse,
+        >,
+    > {
+        let Self {
+            client,
+            org,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/orgs/{}/actions/permissions/repositories?{}",
+                crate::progenitor_support::encode_path(org),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Actions::list_self_hosted_runner_groups_for_org`], set with a builder.
+pub struct ActionsListSelfHostedRunnerGroupsForOrgRequest<'a> {
+    client: &'a Actions,
+    org: &'a str,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> ActionsListSelfHostedRunnerGroupsForOrgRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::ActionsListSelfHostedRunnerGroupsOrgResponse>>
+    {
+        let Self {
+            client,
+            org,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/orgs/{}/actions/runner-groups?{}",
+                crate::progenitor_support::encode_path(org),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Actions::list_repo_access_to_self_hosted_runner_group_in_org`], set with a builder.
+pub struct ActionsListRepoAccessToSelfHostedRunnerGroupInOrgRequest<'a> {
+    client: &'a Actions,
+    org: &'a str,
+    runner_group_id: i64,
+    page: Option<i64>,
+    per_page: Option<i64>,
+}
+
+impl<'a> ActionsListRepoAccessToSelfHostedRunnerGroupInOrgRequest<'a> {
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<
+        crate::Response<crate::types::ActionsListRepoAccessSelfHostedRunnerGroupInOrgResponse>,
+    > {
+        let Self {
+            client,
+            org,
+            runner_group_id,
+            page,
+            per_page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/orgs/{}/actions/runner-groups/{}/repositories?{}",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(&runner_group_id.to_string()),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Actions::list_self_hosted_runners_in_group_for_org`], set with a builder.
+pub struct ActionsListSelfHostedRunnersInGroupForOrgRequest<'a> {
+    client: &'a Actions,
+    org: &'a str,
+    runner_group_id: i64,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> ActionsListSelfHostedRunnersInGroupForOrgRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::ActionsListSelfHostedRunnersInGroupOrgResponse>>
+    {
+        let Self {
+            client,
+            org,
+            runner_group_id,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/orgs/{}/actions/runner-groups/{}/runners?{}",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(&runner_group_id.to_string()),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Actions::list_self_hosted_runners_for_org`], set with a builder.
+pub struct ActionsListSelfHostedRunnersForOrgRequest<'a> {
+    client: &'a Actions,
+    org: &'a str,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> ActionsListSelfHostedRunnersForOrgRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::ActionsListSelfHostedRunnersOrgResponse>> {
+        let Self {
+            client,
+            org,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/orgs/{}/actions/runners?{}",
+                crate::progenitor_support::encode_path(org),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Actions::list_org_secrets`], set with a builder.
+pub struct ActionsListOrgSecretsRequest<'a> {
+    client: &'a Actions,
+    org: &'a str,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> ActionsListOrgSecretsRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::ActionsListOrgSecretsResponse>> {
+        let Self {
+            client,
+            org,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/orgs/{}/actions/secrets?{}",
+                crate::progenitor_support::encode_path(org),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Actions::list_selected_repos_for_org_secret`], set with a builder.
+pub struct ActionsListSelectedReposForOrgSecretRequest<'a> {
+    client: &'a Actions,
+    org: &'a str,
+    secret_name: &'a str,
+    page: Option<i64>,
+    per_page: Option<i64>,
+}
+
+impl<'a> ActionsListSelectedReposForOrgSecretRequest<'a> {
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::ActionsListSelectedReposOrgSecretResponse>>
+    {
+        let Self {
+            client,
+            org,
+            secret_name,
+            page,
+            per_page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/orgs/{}/actions/secrets/{}/repositories?{}",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(secret_name),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Actions::list_artifacts_for_repo`], set with a builder.
+pub struct ActionsListArtifactsForRepoRequest<'a> {
+    client: &'a Actions,
+    owner: &'a str,
+    repo: &'a str,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> ActionsListArtifactsForRepoRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::ActionsListArtifactsRepoResponse>> {
+        let Self {
+            client,
+            owner,
+            repo,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/repos/{}/{}/actions/artifacts?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Actions::list_self_hosted_runners_for_repo`], set with a builder.
+pub struct ActionsListSelfHostedRunnersForRepoRequest<'a> {
+    client: &'a Actions,
+    owner: &'a str,
+    repo: &'a str,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> ActionsListSelfHostedRunnersForRepoRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::ActionsListSelfHostedRunnersOrgResponse>> {
+        let Self {
+            client,
+            owner,
+            repo,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/repos/{}/{}/actions/runners?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Actions::list_workflow_runs_for_repo`], set with a builder.
+pub struct ActionsListWorkflowRunsForRepoRequest<'a> {
+    client: &'a Actions,
+    owner: &'a str,
+    repo: &'a str,
+    actor: Option<&'a str>,
+    branch: Option<&'a str>,
+    event: Option<&'a str>,
+    status: Option<crate::types::WorkflowRunStatus>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+    created: Option<&'a str>,
+}
+
+impl<'a> ActionsListWorkflowRunsForRepoRequest<'a> {
+    /// Sets the `actor` query parameter.
+    pub fn actor(mut self, actor: &'a str) -> Self {
+        self.actor = Some(actor);
+        self
+    }
+
+    /// Sets the `branch` query parameter.
+    pub fn branch(mut self, branch: &'a str) -> Self {
+        self.branch = Some(branch);
+        self
+    }
+
+    /// Sets the `event` query parameter.
+    pub fn event(mut self, event: &'a str) -> Self {
+        self.event = Some(event);
+        self
+    }
+
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: crate::types::WorkflowRunStatus) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sets the `created` query parameter.
+    pub fn created(mut self, created: &'a str) -> Self {
+        self.created = Some(created);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::ActionsListWorkflowRunsResponse>> {
+        let Self {
+            client,
+            owner,
+            repo,
+            actor,
+            branch,
+            event,
+            status,
+            per_page,
+            page,
+            created,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = actor {
+            query_args.push(("actor".to_string(), v.to_string()));
+        }
+        if let Some(v) = branch {
+            query_args.push(("branch".to_string(), v.to_string()));
+        }
+        if let Some(v) = created {
+            query_args.push(("created".to_string(), v.to_string()));
+        }
+        if let Some(v) = event {
+            query_args.push(("event".to_string(), v.to_string()));
+        }
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        if let Some(v) = status {
+            query_args.push(("status".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/repos/{}/{}/actions/runs?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Actions::list_workflow_run_artifacts`], set with a builder.
+pub struct ActionsListWorkflowRunArtifactsRequest<'a> {
+    client: &'a Actions,
+    owner: &'a str,
+    repo: &'a str,
+    run_id: i64,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> ActionsListWorkflowRunArtifactsRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::ActionsListArtifactsRepoResponse>> {
+        let Self {
+            client,
+            owner,
+            repo,
+            run_id,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/repos/{}/{}/actions/runs/{}/artifacts?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&run_id.to_string()),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Actions::list_jobs_for_workflow_run`], set with a builder.
+pub struct ActionsListJobsForWorkflowRunRequest<'a> {
+    client: &'a Actions,
+    owner: &'a str,
+    repo: &'a str,
+    run_id: i64,
+    filter: Option<crate::types::ActionsListJobsWorkflowRunFilter>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> ActionsListJobsForWorkflowRunRequest<'a> {
+    /// Sets the `filter` query parameter.
+    pub fn filter(mut self, filter: crate::types::ActionsListJobsWorkflowRunFilter) -> Self {
+        self.filter = Some(filter);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::ActionsListJobsWorkflowRunResponse>> {
+        let Self {
+            client,
+            owner,
+            repo,
+            run_id,
+            filter,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = filter {
+            query_args.push(("filter".to_string(), v.to_string()));
+        }
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/repos/{}/{}/actions/runs/{}/jobs?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&run_id.to_string()),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Actions::list_repo_secrets`], set with a builder.
+pub struct ActionsListRepoSecretsRequest<'a> {
+    client: &'a Actions,
+    owner: &'a str,
+    repo: &'a str,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> ActionsListRepoSecretsRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::ActionsListRepoSecretsResponse>> {
+        let Self {
+            client,
+            owner,
+            repo,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/repos/{}/{}/actions/secrets?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Actions::list_repo_workflows`], set with a builder.
+pub struct ActionsListRepoWorkflowsRequest<'a> {
+    client: &'a Actions,
+    owner: &'a str,
+    repo: &'a str,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> ActionsListRepoWorkflowsRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::ActionsListRepoWorkflowsResponse>> {
+        let Self {
+            client,
+            owner,
+            repo,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/repos/{}/{}/actions/workflows?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Actions::list_workflow_runs`], set with a builder.
+pub struct ActionsListWorkflowRunsRequest<'a> {
+    client: &'a Actions,
+    owner: &'a str,
+    repo: &'a str,
+    workflow_id: &'a str,
+    actor: Option<&'a str>,
+    branch: Option<&'a str>,
+    event: Option<&'a str>,
+    status: Option<crate::types::WorkflowRunStatus>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+    created: Option<&'a str>,
+}
+
+impl<'a> ActionsListWorkflowRunsRequest<'a> {
+    /// Sets the `actor` query parameter.
+    pub fn actor(mut self, actor: &'a str) -> Self {
+        self.actor = Some(actor);
+        self
+    }
+
+    /// Sets the `branch` query parameter.
+    pub fn branch(mut self, branch: &'a str) -> Self {
+        self.branch = Some(branch);
+        self
+    }
+
+    /// Sets the `event` query parameter.
+    pub fn event(mut self, event: &'a str) -> Self {
+        self.event = Some(event);
+        self
+    }
+
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: crate::types::WorkflowRunStatus) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sets the `created` query parameter.
+    pub fn created(mut self, created: &'a str) -> Self {
+        self.created = Some(created);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::ActionsListWorkflowRunsResponse>> {
+        let Self {
+            client,
+            owner,
+            repo,
+            workflow_id,
+            actor,
+            branch,
+            event,
+            status,
+            per_page,
+            page,
+            created,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = actor {
+            query_args.push(("actor".to_string(), v.to_string()));
+        }
+        if let Some(v) = branch {
+            query_args.push(("branch".to_string(), v.to_string()));
+        }
+        if let Some(v) = created {
+            query_args.push(("created".to_string(), v.to_string()));
+        }
+        if let Some(v) = event {
+            query_args.push(("event".to_string(), v.to_string()));
+        }
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        if let Some(v) = status {
+            query_args.push(("status".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/repos/{}/{}/actions/workflows/{}/runs?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(workflow_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Actions::list_environment_secrets`], set with a builder.
+pub struct ActionsListEnvironmentSecretsRequest<'a> {
+    client: &'a Actions,
+    repository_id: i64,
+    environment_name: &'a str,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> ActionsListEnvironmentSecretsRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::ActionsListRepoSecretsResponse>> {
+        let Self {
+            client,
+            repository_id,
+            environment_name,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/repositories/{}/environments/{}/secrets?{}",
+                crate::progenitor_support::encode_path(&repository_id.to_string()),
+                crate::progenitor_support::encode_path(environment_name),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct Actions {
     pub client: Client,
 }
@@ -139,6 +1290,30 @@ impl Actions {
             )
             .await
     }
+    /**
+     * List selected repositories enabled for GitHub Actions in an organization.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/actions/permissions/repositories` endpoint.
+     *
+     * As opposed to `list_selected_repositories_enabled_github_actions_organization`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Lists the selected repositories that are enabled for GitHub Actions in an organization. To use this endpoint, the organization permission policy for `enabled_repositories` must be configured to `selected`. For more information, see "[Set GitHub Actions permissions for an organization](#set-github-actions-permissions-for-an-organization)."
+     *
+     * You must authenticate using an access token with the `admin:org` scope to use this endpoint. GitHub Apps must have the `administration` organization permission to use this API.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#list-selected-repositories-enabled-for-github-actions-in-an-organization>
+     */
+    pub fn list_selected_repositories_enabled_github_actions_organization_request<'a>(
+        &'a self,
+        org: &'a str,
+    ) -> ActionsListSelectedRepositoriesEnabledGithubActionsOrganizationRequest<'a> {
+        ActionsListSelectedRepositoriesEnabledGithubActionsOrganizationRequest {
+            client: self,
+            org,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * Set selected repositories enabled for GitHub Actions in an organization.
      *
@@ -383,6 +1558,32 @@ impl Actions {
             )
             .await
     }
+    /**
+     * List self-hosted runner groups for an organization.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/actions/runner-groups` endpoint.
+     *
+     * As opposed to `list_self_hosted_runner_groups_for_org`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * The self-hosted runner groups REST API is available with GitHub Enterprise Cloud. For more information, see "[GitHub's products](https://docs.github.com/github/getting-started-with-github/githubs-products)."
+     *
+     * Lists all self-hosted runner groups configured in an organization and inherited from an enterprise.
+     *
+     * You must authenticate using an access token with the `admin:org` scope to use this endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#list-self-hosted-runner-groups-for-an-organization>
+     */
+    pub fn list_self_hosted_runner_groups_for_org_request<'a>(
+        &'a self,
+        org: &'a str,
+    ) -> ActionsListSelfHostedRunnerGroupsForOrgRequest<'a> {
+        ActionsListSelfHostedRunnerGroupsForOrgRequest {
+            client: self,
+            org,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * Create a self-hosted runner group for an organization.
      *
@@ -602,6 +1803,34 @@ impl Actions {
             )
             .await
     }
+    /**
+     * List repository access to a self-hosted runner group in an organization.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/actions/runner-groups/{runner_group_id}/repositories` endpoint.
+     *
+     * As opposed to `list_repo_access_to_self_hosted_runner_group_in_org`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * The self-hosted runner groups REST API is available with GitHub Enterprise Cloud and GitHub Enterprise Server. For more information, see "[GitHub's products](https://docs.github.com/github/getting-started-with-github/githubs-products)."
+     *
+     * Lists the repositories with access to a self-hosted runner group configured in an organization.
+     *
+     * You must authenticate using an access token with the `admin:org` scope to use this endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#list-repository-access-to-a-self-hosted-runner-group-in-an-organization>
+     */
+    pub fn list_repo_access_to_self_hosted_runner_group_in_org_request<'a>(
+        &'a self,
+        org: &'a str,
+        runner_group_id: i64,
+    ) -> ActionsListRepoAccessToSelfHostedRunnerGroupInOrgRequest<'a> {
+        ActionsListRepoAccessToSelfHostedRunnerGroupInOrgRequest {
+            client: self,
+            org,
+            runner_group_id,
+            page: None,
+            per_page: None,
+        }
+    }
     /**
      * Set repository access for a self-hosted runner group in an organization.
      *
@@ -790,6 +2019,34 @@ impl Actions {
             )
             .await
     }
+    /**
+     * List self-hosted runners in a group for an organization.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/actions/runner-groups/{runner_group_id}/runners` endpoint.
+     *
+     * As opposed to `list_self_hosted_runners_in_group_for_org`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * The self-hosted runner groups REST API is available with GitHub Enterprise Cloud. For more information, see "[GitHub's products](https://docs.github.com/github/getting-started-with-github/githubs-products)."
+     *
+     * Lists self-hosted runners that are in a specific organization group.
+     *
+     * You must authenticate using an access token with the `admin:org` scope to use this endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#list-self-hosted-runners-in-a-group-for-an-organization>
+     */
+    pub fn list_self_hosted_runners_in_group_for_org_request<'a>(
+        &'a self,
+        org: &'a str,
+        runner_group_id: i64,
+    ) -> ActionsListSelfHostedRunnersInGroupForOrgRequest<'a> {
+        ActionsListSelfHostedRunnersInGroupForOrgRequest {
+            client: self,
+            org,
+            runner_group_id,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * Set self-hosted runners in a group for an organization.
      *
@@ -972,6 +2229,30 @@ impl Actions {
             )
             .await
     }
+    /**
+     * List self-hosted runners for an organization.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/actions/runners` endpoint.
+     *
+     * As opposed to `list_self_hosted_runners_for_org`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Lists all self-hosted runners configured in an organization.
+     *
+     * You must authenticate using an access token with the `admin:org` scope to use this endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#list-self-hosted-runners-for-an-organization>
+     */
+    pub fn list_self_hosted_runners_for_org_request<'a>(
+        &'a self,
+        org: &'a str,
+    ) -> ActionsListSelfHostedRunnersForOrgRequest<'a> {
+        ActionsListSelfHostedRunnersForOrgRequest {
+            client: self,
+            org,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List runner applications for an organization.
      *
@@ -1042,6 +2323,33 @@ impl Actions {
             )
             .await
     }
+    /**
+     * List runner applications for an organization.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/actions/runners/downloads` endpoint.
+     *
+     * As opposed to `list_all_runner_applications_for_org`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Lists binaries for the runner application that you can download and run.
+     *
+     * You must authenticate using an access token with the `admin:org` scope to use this endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#list-runner-applications-for-an-organization>
+     */
+    pub fn list_all_runner_applications_for_org_stream(
+        &self,
+        org: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::RunnerApplication>> + Unpin + '_
+    {
+        let url = self.client.url(
+            &format!(
+                "/orgs/{}/actions/runners/downloads",
+                crate::progenitor_support::encode_path(org),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Create a registration token for an organization.
      *
@@ -1256,6 +2564,28 @@ impl Actions {
             )
             .await
     }
+    /**
+     * List organization secrets.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/actions/secrets` endpoint.
+     *
+     * As opposed to `list_org_secrets`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Lists all secrets available in an organization without revealing their encrypted values. You must authenticate using an access token with the `admin:org` scope to use this endpoint. GitHub Apps must have the `secrets` organization permission to use this endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#list-organization-secrets>
+     */
+    pub fn list_org_secrets_request<'a>(
+        &'a self,
+        org: &'a str,
+    ) -> ActionsListOrgSecretsRequest<'a> {
+        ActionsListOrgSecretsRequest {
+            client: self,
+            org,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * Get an organization public key.
      *
@@ -1527,6 +2857,30 @@ impl Actions {
             )
             .await
     }
+    /**
+     * List selected repositories for an organization secret.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/actions/secrets/{secret_name}/repositories` endpoint.
+     *
+     * As opposed to `list_selected_repos_for_org_secret`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Lists all repositories that have been selected when the `visibility` for repository access to a secret is set to `selected`. You must authenticate using an access token with the `admin:org` scope to use this endpoint. GitHub Apps must have the `secrets` organization permission to use this endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#list-selected-repositories-for-an-organization-secret>
+     */
+    pub fn list_selected_repos_for_org_secret_request<'a>(
+        &'a self,
+        org: &'a str,
+        secret_name: &'a str,
+    ) -> ActionsListSelectedReposForOrgSecretRequest<'a> {
+        ActionsListSelectedReposForOrgSecretRequest {
+            client: self,
+            org,
+            secret_name,
+            page: None,
+            per_page: None,
+        }
+    }
     /**
      * Set selected repositories for an organization secret.
      *
@@ -1695,6 +3049,30 @@ impl Actions {
             )
             .await
     }
+    /**
+     * List artifacts for a repository.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/actions/artifacts` endpoint.
+     *
+     * As opposed to `list_artifacts_for_repo`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Lists all artifacts for a repository. Anyone with read access to the repository can use this endpoint. If the repository is private you must use an access token with the `repo` scope. GitHub Apps must have the `actions:read` permission to use this endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#list-artifacts-for-a-repository>
+     */
+    pub fn list_artifacts_for_repo_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+    ) -> ActionsListArtifactsForRepoRequest<'a> {
+        ActionsListArtifactsForRepoRequest {
+            client: self,
+            owner,
+            repo,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * Get an artifact.
      *
@@ -2119,6 +3497,30 @@ impl Actions {
             )
             .await
     }
+    /**
+     * List self-hosted runners for a repository.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/actions/runners` endpoint.
+     *
+     * As opposed to `list_self_hosted_runners_for_repo`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Lists all self-hosted runners configured in a repository. You must authenticate using an access token with the `repo` scope to use this endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#list-self-hosted-runners-for-a-repository>
+     */
+    pub fn list_self_hosted_runners_for_repo_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+    ) -> ActionsListSelfHostedRunnersForRepoRequest<'a> {
+        ActionsListSelfHostedRunnersForRepoRequest {
+            client: self,
+            owner,
+            repo,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List runner applications for a repository.
      *
@@ -2194,6 +3596,35 @@ impl Actions {
             )
             .await
     }
+    /**
+     * List runner applications for a repository.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/actions/runners/downloads` endpoint.
+     *
+     * As opposed to `list_all_runner_applications_for_repo`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Lists binaries for the runner application that you can download and run.
+     *
+     * You must authenticate using an access token with the `repo` scope to use this endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#list-runner-applications-for-a-repository>
+     */
+    pub fn list_all_runner_applications_for_repo_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::RunnerApplication>> + Unpin + '_
+    {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/actions/runners/downloads",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Create a registration token for a repository.
      *
@@ -2449,6 +3880,37 @@ impl Actions {
             )
             .await
     }
+    /**
+     * List workflow runs for a repository.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/actions/runs` endpoint.
+     *
+     * As opposed to `list_workflow_runs_for_repo`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Lists all workflow runs for a repository. You can use parameters to narrow the list of results. For more information about using parameters, see [Parameters](https://docs.github.com/rest/overview/resources-in-the-rest-api#parameters).
+     *
+     * Anyone with read access to the repository can use this endpoint. If the repository is private you must use an access token with the `repo` scope. GitHub Apps must have the `actions:read` permission to use this endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#list-workflow-runs-for-a-repository>
+     */
+    pub fn list_workflow_runs_for_repo_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+    ) -> ActionsListWorkflowRunsForRepoRequest<'a> {
+        ActionsListWorkflowRunsForRepoRequest {
+            client: self,
+            owner,
+            repo,
+            actor: None,
+            branch: None,
+            event: None,
+            status: None,
+            per_page: None,
+            page: None,
+            created: None,
+        }
+    }
     /**
      * Get a workflow run.
      *
@@ -2607,6 +4069,35 @@ impl Actions {
             )
             .await
     }
+    /**
+     * Get the review history for a workflow run.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/actions/runs/{run_id}/approvals` endpoint.
+     *
+     * As opposed to `get_all_reviews_for_run`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Anyone with read access to the repository can use this endpoint. If the repository is private, you must use an access token with the `repo` scope. GitHub Apps must have the `actions:read` permission to use this endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#get-the-review-history-for-a-workflow-run>
+     */
+    pub fn get_all_reviews_for_run_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        run_id: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::EnvironmentApproval>> + Unpin + '_
+    {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/actions/runs/{}/approvals",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&run_id.to_string()),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Approve a workflow run for a fork pull request.
      *
@@ -2702,6 +4193,32 @@ impl Actions {
             )
             .await
     }
+    /**
+     * List workflow run artifacts.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/actions/runs/{run_id}/artifacts` endpoint.
+     *
+     * As opposed to `list_workflow_run_artifacts`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Lists artifacts for a workflow run. Anyone with read access to the repository can use this endpoint. If the repository is private you must use an access token with the `repo` scope. GitHub Apps must have the `actions:read` permission to use this endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#list-workflow-run-artifacts>
+     */
+    pub fn list_workflow_run_artifacts_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+        run_id: i64,
+    ) -> ActionsListWorkflowRunArtifactsRequest<'a> {
+        ActionsListWorkflowRunArtifactsRequest {
+            client: self,
+            owner,
+            repo,
+            run_id,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * Cancel a workflow run.
      *
@@ -2802,6 +4319,33 @@ impl Actions {
             )
             .await
     }
+    /**
+     * List jobs for a workflow run.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/actions/runs/{run_id}/jobs` endpoint.
+     *
+     * As opposed to `list_jobs_for_workflow_run`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Lists jobs for a workflow run. Anyone with read access to the repository can use this endpoint. If the repository is private you must use an access token with the `repo` scope. GitHub Apps must have the `actions:read` permission to use this endpoint. You can use parameters to narrow the list of results. For more information about using parameters, see [Parameters](https://docs.github.com/rest/overview/resources-in-the-rest-api#parameters).
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#list-jobs-for-a-workflow-run>
+     */
+    pub fn list_jobs_for_workflow_run_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+        run_id: i64,
+    ) -> ActionsListJobsForWorkflowRunRequest<'a> {
+        ActionsListJobsForWorkflowRunRequest {
+            client: self,
+            owner,
+            repo,
+            run_id,
+            filter: None,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * Download workflow run logs.
      *
@@ -2965,6 +4509,37 @@ impl Actions {
             )
             .await
     }
+    /**
+     * Get pending deployments for a workflow run.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/actions/runs/{run_id}/pending_deployments` endpoint.
+     *
+     * As opposed to `get_all_pending_deployments_for_run`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Get all deployment environments for a workflow run that are waiting for protection rules to pass.
+     *
+     * Anyone with read access to the repository can use this endpoint. If the repository is private, you must use an access token with the `repo` scope. GitHub Apps must have the `actions:read` permission to use this endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#get-pending-deployments-for-a-workflow-run>
+     */
+    pub fn get_all_pending_deployments_for_run_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        run_id: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::PendingDeployment>> + Unpin + '_
+    {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/actions/runs/{}/pending_deployments",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&run_id.to_string()),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Review pending deployments for a workflow run.
      *
@@ -3180,6 +4755,30 @@ impl Actions {
             )
             .await
     }
+    /**
+     * List repository secrets.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/actions/secrets` endpoint.
+     *
+     * As opposed to `list_repo_secrets`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Lists all secrets available in a repository without revealing their encrypted values. You must authenticate using an access token with the `repo` scope to use this endpoint. GitHub Apps must have the `secrets` repository permission to use this endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#list-repository-secrets>
+     */
+    pub fn list_repo_secrets_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+    ) -> ActionsListRepoSecretsRequest<'a> {
+        ActionsListRepoSecretsRequest {
+            client: self,
+            owner,
+            repo,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * Get a repository public key.
      *
@@ -3462,6 +5061,30 @@ impl Actions {
             )
             .await
     }
+    /**
+     * List repository workflows.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/actions/workflows` endpoint.
+     *
+     * As opposed to `list_repo_workflows`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Lists the workflows in a repository. Anyone with read access to the repository can use this endpoint. If the repository is private you must use an access token with the `repo` scope. GitHub Apps must have the `actions:read` permission to use this endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#list-repository-workflows>
+     */
+    pub fn list_repo_workflows_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+    ) -> ActionsListRepoWorkflowsRequest<'a> {
+        ActionsListRepoWorkflowsRequest {
+            client: self,
+            owner,
+            repo,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * Get a workflow.
      *
@@ -3711,6 +5334,39 @@ impl Actions {
             )
             .await
     }
+    /**
+     * List workflow runs.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/actions/workflows/{workflow_id}/runs` endpoint.
+     *
+     * As opposed to `list_workflow_runs`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * List all workflow runs for a workflow. You can replace `workflow_id` with the workflow file name. For example, you could use `main.yaml`. You can use parameters to narrow the list of results. For more information about using parameters, see [Parameters](https://docs.github.com/rest/overview/resources-in-the-rest-api#parameters).
+     *
+     * Anyone with read access to the repository can use this endpoint. If the repository is private you must use an access token with the `repo` scope.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#list-workflow-runs>
+     */
+    pub fn list_workflow_runs_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+        workflow_id: &'a str,
+    ) -> ActionsListWorkflowRunsRequest<'a> {
+        ActionsListWorkflowRunsRequest {
+            client: self,
+            owner,
+            repo,
+            workflow_id,
+            actor: None,
+            branch: None,
+            event: None,
+            status: None,
+            per_page: None,
+            page: None,
+            created: None,
+        }
+    }
     /**
      * Get workflow usage.
      *
@@ -3803,6 +5459,30 @@ impl Actions {
             )
             .await
     }
+    /**
+     * List environment secrets.
+     *
+     * This function performs a `GET` to the `/repositories/{repository_id}/environments/{environment_name}/secrets` endpoint.
+     *
+     * As opposed to `list_environment_secrets`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Lists all secrets available in an environment without revealing their encrypted values. You must authenticate using an access token with the `repo` scope to use this endpoint. GitHub Apps must have the `secrets` repository permission to use this endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#list-environment-secrets>
+     */
+    pub fn list_environment_secrets_request<'a>(
+        &'a self,
+        repository_id: i64,
+        environment_name: &'a str,
+    ) -> ActionsListEnvironmentSecretsRequest<'a> {
+        ActionsListEnvironmentSecretsRequest {
+            client: self,
+            repository_id,
+            environment_name,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * Get an environment public key.
      *
@@ -1,6 +1,1134 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Activity::list_public_events`], set with a builder.
+pub struct ActivityListPublicEventsRequest<'a> {
+    client: &'a Activity,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> ActivityListPublicEventsRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Event>>> {
+        let Self {
+            client,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(&format!("/events?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Activity::list_public_events_for_repo_network`], set with a builder.
+pub struct ActivityListPublicEventsForRepoNetworkRequest<'a> {
+    client: &'a Activity,
+    owner: &'a str,
+    repo: &'a str,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> ActivityListPublicEventsForRepoNetworkRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Event>>> {
+        let Self {
+            client,
+            owner,
+            repo,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/networks/{}/{}/events?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Activity::list_notifications_for_authenticated_user`], set with a builder.
+pub struct ActivityListNotificationsForAuthenticatedUserRequest<'a> {
+    client: &'a Activity,
+    all: Option<bool>,
+    participating: Option<bool>,
+    since: Option<chrono::DateTime<chrono::Utc>>,
+    before: Option<chrono::DateTime<chrono::Utc>>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> ActivityListNotificationsForAuthenticatedUserRequest<'a> {
+    /// Sets the `all` query parameter.
+    pub fn all(mut self, all: bool) -> Self {
+        self.all = Some(all);
+        self
+    }
+
+    /// Sets the `participating` query parameter.
+    pub fn participating(mut self, participating: bool) -> Self {
+        self.participating = Some(participating);
+        self
+    }
+
+    /// Sets the `since` query parameter.
+    pub fn since(mut self, since: chrono::DateTime<chrono::Utc>) -> Self {
+        self.since = Some(since);
+        self
+    }
+
+    /// Sets the `before` query parameter.
+    pub fn before(mut self, before: chrono::DateTime<chrono::Utc>) -> Self {
+        self.before = Some(before);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Thread>>> {
+        let Self {
+            client,
+            all,
+            participating,
+            since,
+            before,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = all {
+            query_args.push(("all".to_string(), v.to_string()));
+        }
+        if let Some(date) = before {
+            query_args.push(("before".to_string(), date.to_rfc3339()));
+        }
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = participating {
+            query_args.push(("participating".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        if let Some(date) = since {
+            query_args.push(("since".to_string(), date.to_rfc3339()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/notifications?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Activity::list_public_org_events`], set with a builder.
+pub struct ActivityListPublicOrgEventsRequest<'a> {
+    client: &'a Activity,
+    org: &'a str,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> ActivityListPublicOrgEventsRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Event>>> {
+        let Self {
+            client,
+            org,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/orgs/{}/events?{}",
+                crate::progenitor_support::encode_path(org),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Activity::list_repo_events`], set with a builder.
+pub struct ActivityListRepoEventsRequest<'a> {
+    client: &'a Activity,
+    owner: &'a str,
+    repo: &'a str,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> ActivityListRepoEventsRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Event>>> {
+        let Self {
+            client,
+            owner,
+            repo,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/repos/{}/{}/events?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Activity::list_repo_notifications_for_authenticated_user`], set with a builder.
+pub struct ActivityListRepoNotificationsForAuthenticatedUserRequest<'a> {
+    client: &'a Activity,
+    owner: &'a str,
+    repo: &'a str,
+    all: Option<bool>,
+    participating: Option<bool>,
+    since: Option<chrono::DateTime<chrono::Utc>>,
+    before: Option<chrono::DateTime<chrono::Utc>>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> ActivityListRepoNotificationsForAuthenticatedUserRequest<'a> {
+    /// Sets the `all` query parameter.
+    pub fn all(mut self, all: bool) -> Self {
+        self.all = Some(all);
+        self
+    }
+
+    /// Sets the `participating` query parameter.
+    pub fn participating(mut self, participating: bool) -> Self {
+        self.participating = Some(participating);
+        self
+    }
+
+    /// Sets the `since` query parameter.
+    pub fn since(mut self, since: chrono::DateTime<chrono::Utc>) -> Self {
+        self.since = Some(since);
+        self
+    }
+
+    /// Sets the `before` query parameter.
+    pub fn before(mut self, before: chrono::DateTime<chrono::Utc>) -> Self {
+        self.before = Some(before);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Thread>>> {
+        let Self {
+            client,
+            owner,
+            repo,
+            all,
+            participating,
+            since,
+            before,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = all {
+            query_args.push(("all".to_string(), v.to_string()));
+        }
+        if let Some(date) = before {
+            query_args.push(("before".to_string(), date.to_rfc3339()));
+        }
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = participating {
+            query_args.push(("participating".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        if let Some(date) = since {
+            query_args.push(("since".to_string(), date.to_rfc3339()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/repos/{}/{}/notifications?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Activity::list_stargazers_for_repo`], set with a builder.
+pub struct ActivityListStargazersForRepoRequest<'a> {
+    client: &'a Activity,
+    owner: &'a str,
+    repo: &'a str,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> ActivityListStargazersForRepoRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::ActivityListStargazersRepoResponseAnyOf>> {
+        let Self {
+            client,
+            owner,
+            repo,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/repos/{}/{}/stargazers?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Activity::list_watchers_for_repo`], set with a builder.
+pub struct ActivityListWatchersForRepoRequest<'a> {
+    client: &'a Activity,
+    owner: &'a str,
+    repo: &'a str,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> ActivityListWatchersForRepoRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::SimpleUser>>> {
+        let Self {
+            client,
+            owner,
+            repo,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/repos/{}/{}/subscribers?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Activity::list_repos_starred_by_authenticated_user`], set with a builder.
+pub struct ActivityListReposStarredByAuthenticatedUserRequest<'a> {
+    client: &'a Activity,
+    sort: Option<crate::types::Sort>,
+    direction: Option<crate::types::Order>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> ActivityListReposStarredByAuthenticatedUserRequest<'a> {
+    /// Sets the `sort` query parameter.
+    pub fn sort(mut self, sort: crate::types::Sort) -> Self {
+        self.sort = Some(sort);
+        self
+    }
+
+    /// Sets the `direction` query parameter.
+    pub fn direction(mut self, direction: crate::types::Order) -> Self {
+        self.direction = Some(direction);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Repository>>> {
+        let Self {
+            client,
+            sort,
+            direction,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = direction {
+            query_args.push(("direction".to_string(), v.to_string()));
+        }
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        if let Some(v) = sort {
+            query_args.push(("sort".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/user/starred?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Activity::list_watched_repos_for_authenticated_user`], set with a builder.
+pub struct ActivityListWatchedReposForAuthenticatedUserRequest<'a> {
+    client: &'a Activity,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> ActivityListWatchedReposForAuthenticatedUserRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::MinimalRepository>>> {
+        let Self {
+            client,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/user/subscriptions?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Activity::list_events_for_authenticated_user`], set with a builder.
+pub struct ActivityListEventsForAuthenticatedUserRequest<'a> {
+    client: &'a Activity,
+    username: &'a str,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> ActivityListEventsForAuthenticatedUserRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Event>>> {
+        let Self {
+            client,
+            username,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/users/{}/events?{}",
+                crate::progenitor_support::encode_path(username),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Activity::list_org_events_for_authenticated_user`], set with a builder.
+pub struct ActivityListOrgEventsForAuthenticatedUserRequest<'a> {
+    client: &'a Activity,
+    username: &'a str,
+    org: &'a str,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> ActivityListOrgEventsForAuthenticatedUserRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Event>>> {
+        let Self {
+            client,
+            username,
+            org,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/users/{}/events/orgs/{}?{}",
+                crate::progenitor_support::encode_path(username),
+                crate::progenitor_support::encode_path(org),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Activity::list_public_events_for_user`], set with a builder.
+pub struct ActivityListPublicEventsForUserRequest<'a> {
+    client: &'a Activity,
+    username: &'a str,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> ActivityListPublicEventsForUserRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Event>>> {
+        let Self {
+            client,
+            username,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/users/{}/events/public?{}",
+                crate::progenitor_support::encode_path(username),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Activity::list_received_events_for_user`], set with a builder.
+pub struct ActivityListReceivedEventsForUserRequest<'a> {
+    client: &'a Activity,
+    username: &'a str,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> ActivityListReceivedEventsForUserRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Event>>> {
+        let Self {
+            client,
+            username,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/users/{}/received_events?{}",
+                crate::progenitor_support::encode_path(username),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Activity::list_received_public_events_for_user`], set with a builder.
+pub struct ActivityListReceivedPublicEventsForUserRequest<'a> {
+    client: &'a Activity,
+    username: &'a str,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> ActivityListReceivedPublicEventsForUserRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Event>>> {
+        let Self {
+            client,
+            username,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/users/{}/received_events/public?{}",
+                crate::progenitor_support::encode_path(username),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Activity::list_repos_starred_by_user`], set with a builder.
+pub struct ActivityListReposStarredByUserRequest<'a> {
+    client: &'a Activity,
+    username: &'a str,
+    sort: Option<crate::types::Sort>,
+    direction: Option<crate::types::Order>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> ActivityListReposStarredByUserRequest<'a> {
+    /// Sets the `sort` query parameter.
+    pub fn sort(mut self, sort: crate::types::Sort) -> Self {
+        self.sort = Some(sort);
+        self
+    }
+
+    /// Sets the `direction` query parameter.
+    pub fn direction(mut self, direction: crate::types::Order) -> Self {
+        self.direction = Some(direction);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::ActivityListReposStarredByUserResponseAnyOf>>
+    {
+        let Self {
+            client,
+            username,
+            sort,
+            direction,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = direction {
+            query_args.push(("direction".to_string(), v.to_string()));
+        }
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        if let Some(v) = sort {
+            query_args.push(("sort".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/users/{}/starred?{}",
+                crate::progenitor_support::encode_path(username),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Activity::list_repos_watched_by_user`], set with a builder.
+pub struct ActivityListReposWatchedByUserRequest<'a> {
+    client: &'a Activity,
+    username: &'a str,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> ActivityListReposWatchedByUserRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::MinimalRepository>>> {
+        let Self {
+            client,
+            username,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/users/{}/subscriptions?{}",
+                crate::progenitor_support::encode_path(username),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct Activity {
     pub client: Client,
 }
@@ -49,6 +1177,24 @@ impl Activity {
             )
             .await
     }
+    /**
+     * List public events.
+     *
+     * This function performs a `GET` to the `/events` endpoint.
+     *
+     * As opposed to `list_public_events`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * We delay the public events feed by five minutes, which means the most recent event returned by the public events API actually occurred at least five minutes ago.
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-public-events>
+     */
+    pub fn list_public_events_request(&self) -> ActivityListPublicEventsRequest<'_> {
+        ActivityListPublicEventsRequest {
+            client: self,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List public events.
      *
@@ -74,6 +1220,23 @@ impl Activity {
             )
             .await
     }
+    /**
+     * List public events.
+     *
+     * This function performs a `GET` to the `/events` endpoint.
+     *
+     * As opposed to `list_all_public_events`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * We delay the public events feed by five minutes, which means the most recent event returned by the public events API actually occurred at least five minutes ago.
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-public-events>
+     */
+    pub fn list_all_public_events_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Event>> + Unpin + '_ {
+        let url = self.client.url("/events", None);
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Get feeds.
      *
@@ -155,6 +1318,30 @@ impl Activity {
             )
             .await
     }
+    /**
+     * List public events for a network of repositories.
+     *
+     * This function performs a `GET` to the `/networks/{owner}/{repo}/events` endpoint.
+     *
+     * As opposed to `list_public_events_for_repo_network`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-public-events-for-a-network-of-repositories>
+     */
+    pub fn list_public_events_for_repo_network_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+    ) -> ActivityListPublicEventsForRepoNetworkRequest<'a> {
+        ActivityListPublicEventsForRepoNetworkRequest {
+            client: self,
+            owner,
+            repo,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List public events for a network of repositories.
      *
@@ -189,6 +1376,32 @@ impl Activity {
             )
             .await
     }
+    /**
+     * List public events for a network of repositories.
+     *
+     * This function performs a `GET` to the `/networks/{owner}/{repo}/events` endpoint.
+     *
+     * As opposed to `list_all_public_events_for_repo_network`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-public-events-for-a-network-of-repositories>
+     */
+    pub fn list_all_public_events_for_repo_network_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Event>> + Unpin + '_ {
+        let url = self.client.url(
+            &format!(
+                "/networks/{}/{}/events",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * List notifications for the authenticated user.
      *
@@ -200,21 +1413,94 @@ impl Activity {
      *
      * **Parameters:**
      *
-     * * `all: bool` -- If `true`, show notifications marked as read.
-     * * `participating: bool` -- If `true`, only shows notifications in which the user is directly participating or mentioned.
-     * * `since: chrono::DateTime<chrono::Utc>` -- Only show notifications updated after the given time. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
-     * * `before: chrono::DateTime<chrono::Utc>` -- Only show notifications updated before the given time. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `all: bool` -- If `true`, show notifications marked as read.
+     * * `participating: bool` -- If `true`, only shows notifications in which the user is directly participating or mentioned.
+     * * `since: chrono::DateTime<chrono::Utc>` -- Only show notifications updated after the given time. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
+     * * `before: chrono::DateTime<chrono::Utc>` -- Only show notifications updated before the given time. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
+     * * `per_page: i64` -- Results per page (max 100).
+     * * `page: i64` -- Page number of the results to fetch.
+     */
+    pub async fn list_notifications_for_authenticated_user(
+        &self,
+        all: bool,
+        participating: bool,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+        before: Option<chrono::DateTime<chrono::Utc>>,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Thread>>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if all {
+            query_args.push(("all".to_string(), all.to_string()));
+        }
+        if let Some(date) = before {
+            query_args.push(("before".to_string(), date.to_rfc3339()));
+        }
+        if page > 0 {
+            query_args.push(("page".to_string(), page.to_string()));
+        }
+        if participating {
+            query_args.push(("participating".to_string(), participating.to_string()));
+        }
+        if per_page > 0 {
+            query_args.push(("per_page".to_string(), per_page.to_string()));
+        }
+        if let Some(date) = since {
+            query_args.push(("since".to_string(), date.to_rfc3339()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/notifications?{}", query_), None);
+        self.client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+    /**
+     * List notifications for the authenticated user.
+     *
+     * This function performs a `GET` to the `/notifications` endpoint.
+     *
+     * As opposed to `list_notifications_for_authenticated_user`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * List all notifications for the current user, sorted by most recently updated.
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-notifications-for-the-authenticated-user>
+     */
+    pub fn list_notifications_for_authenticated_user_request(
+        &self,
+    ) -> ActivityListNotificationsForAuthenticatedUserRequest<'_> {
+        ActivityListNotificationsForAuthenticatedUserRequest {
+            client: self,
+            all: None,
+            participating: None,
+            since: None,
+            before: None,
+            per_page: None,
+            page: None,
+        }
+    }
+    /**
+     * List notifications for the authenticated user.
+     *
+     * This function performs a `GET` to the `/notifications` endpoint.
+     *
+     * As opposed to `list_notifications_for_authenticated_user`, this function returns all the pages of the request at once.
+     *
+     * List all notifications for the current user, sorted by most recently updated.
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-notifications-for-the-authenticated-user>
      */
-    pub async fn list_notifications_for_authenticated_user(
+    pub async fn list_all_notifications_for_authenticated_user(
         &self,
         all: bool,
         participating: bool,
         since: Option<chrono::DateTime<chrono::Utc>>,
         before: Option<chrono::DateTime<chrono::Utc>>,
-        per_page: i64,
-        page: i64,
     ) -> ClientResult<crate::Response<Vec<crate::types::Thread>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if all {
@@ -223,22 +1509,16 @@ impl Activity {
         if let Some(date) = before {
             query_args.push(("before".to_string(), date.to_rfc3339()));
         }
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
-        }
         if participating {
             query_args.push(("participating".to_string(), participating.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
-        }
         if let Some(date) = since {
             query_args.push(("since".to_string(), date.to_rfc3339()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/notifications?{}", query_), None);
         self.client
-            .get(
+            .get_all_pages(
                 &url,
                 crate::Message {
                     body: None,
@@ -252,19 +1532,19 @@ impl Activity {
      *
      * This function performs a `GET` to the `/notifications` endpoint.
      *
-     * As opposed to `list_notifications_for_authenticated_user`, this function returns all the pages of the request at once.
+     * As opposed to `list_all_notifications_for_authenticated_user`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
      *
      * List all notifications for the current user, sorted by most recently updated.
      *
      * FROM: <https://docs.github.com/rest/reference/activity#list-notifications-for-the-authenticated-user>
      */
-    pub async fn list_all_notifications_for_authenticated_user(
+    pub fn list_all_notifications_for_authenticated_user_stream(
         &self,
         all: bool,
         participating: bool,
         since: Option<chrono::DateTime<chrono::Utc>>,
         before: Option<chrono::DateTime<chrono::Utc>>,
-    ) -> ClientResult<crate::Response<Vec<crate::types::Thread>>> {
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Thread>> + Unpin + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if all {
             query_args.push(("all".to_string(), all.to_string()));
@@ -280,15 +1560,7 @@ impl Activity {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/notifications?{}", query_), None);
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(url)
     }
     /**
      * Mark notifications as read.
@@ -535,6 +1807,28 @@ impl Activity {
             )
             .await
     }
+    /**
+     * List public organization events.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/events` endpoint.
+     *
+     * As opposed to `list_public_org_events`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-public-organization-events>
+     */
+    pub fn list_public_org_events_request<'a>(
+        &'a self,
+        org: &'a str,
+    ) -> ActivityListPublicOrgEventsRequest<'a> {
+        ActivityListPublicOrgEventsRequest {
+            client: self,
+            org,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List public organization events.
      *
@@ -567,6 +1861,30 @@ impl Activity {
             )
             .await
     }
+    /**
+     * List public organization events.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/events` endpoint.
+     *
+     * As opposed to `list_all_public_org_events`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-public-organization-events>
+     */
+    pub fn list_all_public_org_events_stream(
+        &self,
+        org: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Event>> + Unpin + '_ {
+        let url = self.client.url(
+            &format!(
+                "/orgs/{}/events",
+                crate::progenitor_support::encode_path(org),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * List repository events.
      *
@@ -617,6 +1935,30 @@ impl Activity {
             )
             .await
     }
+    /**
+     * List repository events.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/events` endpoint.
+     *
+     * As opposed to `list_repo_events`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-repository-events>
+     */
+    pub fn list_repo_events_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+    ) -> ActivityListRepoEventsRequest<'a> {
+        ActivityListRepoEventsRequest {
+            client: self,
+            owner,
+            repo,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List repository events.
      *
@@ -651,6 +1993,32 @@ impl Activity {
             )
             .await
     }
+    /**
+     * List repository events.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/events` endpoint.
+     *
+     * As opposed to `list_all_repo_events`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-repository-events>
+     */
+    pub fn list_all_repo_events_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Event>> + Unpin + '_ {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/events",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * List repository notifications for the authenticated user.
      *
@@ -721,6 +2089,34 @@ impl Activity {
             )
             .await
     }
+    /**
+     * List repository notifications for the authenticated user.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/notifications` endpoint.
+     *
+     * As opposed to `list_repo_notifications_for_authenticated_user`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * List all notifications for the current user.
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-repository-notifications-for-the-authenticated-user>
+     */
+    pub fn list_repo_notifications_for_authenticated_user_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+    ) -> ActivityListRepoNotificationsForAuthenticatedUserRequest<'a> {
+        ActivityListRepoNotificationsForAuthenticatedUserRequest {
+            client: self,
+            owner,
+            repo,
+            all: None,
+            participating: None,
+            since: None,
+            before: None,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List repository notifications for the authenticated user.
      *
@@ -774,6 +2170,51 @@ impl Activity {
             )
             .await
     }
+    /**
+     * List repository notifications for the authenticated user.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/notifications` endpoint.
+     *
+     * As opposed to `list_all_repo_notifications_for_authenticated_user`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * List all notifications for the current user.
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-repository-notifications-for-the-authenticated-user>
+     */
+    pub fn list_all_repo_notifications_for_authenticated_user_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        all: bool,
+        participating: bool,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+        before: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Thread>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if all {
+            query_args.push(("all".to_string(), all.to_string()));
+        }
+        if let Some(date) = before {
+            query_args.push(("before".to_string(), date.to_rfc3339()));
+        }
+        if participating {
+            query_args.push(("participating".to_string(), participating.to_string()));
+        }
+        if let Some(date) = since {
+            query_args.push(("since".to_string(), date.to_rfc3339()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/notifications?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                query_
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Mark repository notifications as read.
      *
@@ -864,6 +2305,32 @@ impl Activity {
             )
             .await
     }
+    /**
+     * List stargazers.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/stargazers` endpoint.
+     *
+     * As opposed to `list_stargazers_for_repo`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Lists the people that have starred the repository.
+     *
+     * You can also find out _when_ stars were created by passing the following custom [media type](https://docs.github.com/rest/overview/media-types/) via the `Accept` header:
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-stargazers>
+     */
+    pub fn list_stargazers_for_repo_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+    ) -> ActivityListStargazersForRepoRequest<'a> {
+        ActivityListStargazersForRepoRequest {
+            client: self,
+            owner,
+            repo,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List watchers.
      *
@@ -914,6 +2381,30 @@ impl Activity {
             )
             .await
     }
+    /**
+     * List watchers.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/subscribers` endpoint.
+     *
+     * As opposed to `list_watchers_for_repo`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Lists the people watching the specified repository.
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-watchers>
+     */
+    pub fn list_watchers_for_repo_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+    ) -> ActivityListWatchersForRepoRequest<'a> {
+        ActivityListWatchersForRepoRequest {
+            client: self,
+            owner,
+            repo,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List watchers.
      *
@@ -948,6 +2439,32 @@ impl Activity {
             )
             .await
     }
+    /**
+     * List watchers.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/subscribers` endpoint.
+     *
+     * As opposed to `list_all_watchers_for_repo`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Lists the people watching the specified repository.
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-watchers>
+     */
+    pub fn list_all_watchers_for_repo_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::SimpleUser>> + Unpin + '_ {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/subscribers",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Get a repository subscription.
      *
@@ -1091,19 +2608,80 @@ impl Activity {
         if !direction.to_string().is_empty() {
             query_args.push(("direction".to_string(), direction.to_string()));
         }
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
-        }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
-        }
+        if page > 0 {
+            query_args.push(("page".to_string(), page.to_string()));
+        }
+        if per_page > 0 {
+            query_args.push(("per_page".to_string(), per_page.to_string()));
+        }
+        if !sort.to_string().is_empty() {
+            query_args.push(("sort".to_string(), sort.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/user/starred?{}", query_), None);
+        self.client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+    /**
+     * List repositories starred by the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/starred` endpoint.
+     *
+     * As opposed to `list_repos_starred_by_authenticated_user`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Lists repositories the authenticated user has starred.
+     *
+     * You can also find out _when_ stars were created by passing the following custom [media type](https://docs.github.com/rest/overview/media-types/) via the `Accept` header:
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-repositories-starred-by-the-authenticated-user>
+     */
+    pub fn list_repos_starred_by_authenticated_user_request(
+        &self,
+    ) -> ActivityListReposStarredByAuthenticatedUserRequest<'_> {
+        ActivityListReposStarredByAuthenticatedUserRequest {
+            client: self,
+            sort: None,
+            direction: None,
+            per_page: None,
+            page: None,
+        }
+    }
+    /**
+     * List repositories starred by the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/starred` endpoint.
+     *
+     * As opposed to `list_repos_starred_by_authenticated_user`, this function returns all the pages of the request at once.
+     *
+     * Lists repositories the authenticated user has starred.
+     *
+     * You can also find out _when_ stars were created by passing the following custom [media type](https://docs.github.com/rest/overview/media-types/) via the `Accept` header:
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-repositories-starred-by-the-authenticated-user>
+     */
+    pub async fn list_all_repos_starred_by_authenticated_user(
+        &self,
+        sort: crate::types::Sort,
+        direction: crate::types::Order,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Repository>>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !direction.to_string().is_empty() {
+            query_args.push(("direction".to_string(), direction.to_string()));
+        }
         if !sort.to_string().is_empty() {
             query_args.push(("sort".to_string(), sort.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/user/starred?{}", query_), None);
         self.client
-            .get(
+            .get_all_pages(
                 &url,
                 crate::Message {
                     body: None,
@@ -1117,7 +2695,7 @@ impl Activity {
      *
      * This function performs a `GET` to the `/user/starred` endpoint.
      *
-     * As opposed to `list_repos_starred_by_authenticated_user`, this function returns all the pages of the request at once.
+     * As opposed to `list_all_repos_starred_by_authenticated_user`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
      *
      * Lists repositories the authenticated user has starred.
      *
@@ -1125,11 +2703,11 @@ impl Activity {
      *
      * FROM: <https://docs.github.com/rest/reference/activity#list-repositories-starred-by-the-authenticated-user>
      */
-    pub async fn list_all_repos_starred_by_authenticated_user(
+    pub fn list_all_repos_starred_by_authenticated_user_stream(
         &self,
         sort: crate::types::Sort,
         direction: crate::types::Order,
-    ) -> ClientResult<crate::Response<Vec<crate::types::Repository>>> {
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Repository>> + Unpin + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !direction.to_string().is_empty() {
             query_args.push(("direction".to_string(), direction.to_string()));
@@ -1139,15 +2717,7 @@ impl Activity {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/user/starred?{}", query_), None);
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(url)
     }
     /**
      * Check if a repository is starred by the authenticated user.
@@ -1300,6 +2870,26 @@ impl Activity {
             )
             .await
     }
+    /**
+     * List repositories watched by the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/subscriptions` endpoint.
+     *
+     * As opposed to `list_watched_repos_for_authenticated_user`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Lists repositories the authenticated user is watching.
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-repositories-watched-by-the-authenticated-user>
+     */
+    pub fn list_watched_repos_for_authenticated_user_request(
+        &self,
+    ) -> ActivityListWatchedReposForAuthenticatedUserRequest<'_> {
+        ActivityListWatchedReposForAuthenticatedUserRequest {
+            client: self,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List repositories watched by the authenticated user.
      *
@@ -1325,6 +2915,24 @@ impl Activity {
             )
             .await
     }
+    /**
+     * List repositories watched by the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/subscriptions` endpoint.
+     *
+     * As opposed to `list_all_watched_repos_for_authenticated_user`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Lists repositories the authenticated user is watching.
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-repositories-watched-by-the-authenticated-user>
+     */
+    pub fn list_all_watched_repos_for_authenticated_user_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::MinimalRepository>> + Unpin + '_
+    {
+        let url = self.client.url("/user/subscriptions", None);
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * List events for the authenticated user.
      *
@@ -1372,6 +2980,28 @@ impl Activity {
             )
             .await
     }
+    /**
+     * List events for the authenticated user.
+     *
+     * This function performs a `GET` to the `/users/{username}/events` endpoint.
+     *
+     * As opposed to `list_events_for_authenticated_user`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * If you are authenticated as the given user, you will see your private events. Otherwise, you'll only see public events.
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-events-for-the-authenticated-user>
+     */
+    pub fn list_events_for_authenticated_user_request<'a>(
+        &'a self,
+        username: &'a str,
+    ) -> ActivityListEventsForAuthenticatedUserRequest<'a> {
+        ActivityListEventsForAuthenticatedUserRequest {
+            client: self,
+            username,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List events for the authenticated user.
      *
@@ -1404,6 +3034,30 @@ impl Activity {
             )
             .await
     }
+    /**
+     * List events for the authenticated user.
+     *
+     * This function performs a `GET` to the `/users/{username}/events` endpoint.
+     *
+     * As opposed to `list_all_events_for_authenticated_user`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * If you are authenticated as the given user, you will see your private events. Otherwise, you'll only see public events.
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-events-for-the-authenticated-user>
+     */
+    pub fn list_all_events_for_authenticated_user_stream(
+        &self,
+        username: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Event>> + Unpin + '_ {
+        let url = self.client.url(
+            &format!(
+                "/users/{}/events",
+                crate::progenitor_support::encode_path(username),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * List organization events for the authenticated user.
      *
@@ -1454,6 +3108,30 @@ impl Activity {
             )
             .await
     }
+    /**
+     * List organization events for the authenticated user.
+     *
+     * This function performs a `GET` to the `/users/{username}/events/orgs/{org}` endpoint.
+     *
+     * As opposed to `list_org_events_for_authenticated_user`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * This is the user's organization dashboard. You must be authenticated as the user to view this.
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-organization-events-for-the-authenticated-user>
+     */
+    pub fn list_org_events_for_authenticated_user_request<'a>(
+        &'a self,
+        username: &'a str,
+        org: &'a str,
+    ) -> ActivityListOrgEventsForAuthenticatedUserRequest<'a> {
+        ActivityListOrgEventsForAuthenticatedUserRequest {
+            client: self,
+            username,
+            org,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List organization events for the authenticated user.
      *
@@ -1488,6 +3166,32 @@ impl Activity {
             )
             .await
     }
+    /**
+     * List organization events for the authenticated user.
+     *
+     * This function performs a `GET` to the `/users/{username}/events/orgs/{org}` endpoint.
+     *
+     * As opposed to `list_all_org_events_for_authenticated_user`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * This is the user's organization dashboard. You must be authenticated as the user to view this.
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-organization-events-for-the-authenticated-user>
+     */
+    pub fn list_all_org_events_for_authenticated_user_stream(
+        &self,
+        username: &str,
+        org: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Event>> + Unpin + '_ {
+        let url = self.client.url(
+            &format!(
+                "/users/{}/events/orgs/{}",
+                crate::progenitor_support::encode_path(username),
+                crate::progenitor_support::encode_path(org),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * List public events for a user.
      *
@@ -1535,6 +3239,28 @@ impl Activity {
             )
             .await
     }
+    /**
+     * List public events for a user.
+     *
+     * This function performs a `GET` to the `/users/{username}/events/public` endpoint.
+     *
+     * As opposed to `list_public_events_for_user`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-public-events-for-a-user>
+     */
+    pub fn list_public_events_for_user_request<'a>(
+        &'a self,
+        username: &'a str,
+    ) -> ActivityListPublicEventsForUserRequest<'a> {
+        ActivityListPublicEventsForUserRequest {
+            client: self,
+            username,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List public events for a user.
      *
@@ -1567,6 +3293,30 @@ impl Activity {
             )
             .await
     }
+    /**
+     * List public events for a user.
+     *
+     * This function performs a `GET` to the `/users/{username}/events/public` endpoint.
+     *
+     * As opposed to `list_all_public_events_for_user`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-public-events-for-a-user>
+     */
+    pub fn list_all_public_events_for_user_stream(
+        &self,
+        username: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Event>> + Unpin + '_ {
+        let url = self.client.url(
+            &format!(
+                "/users/{}/events/public",
+                crate::progenitor_support::encode_path(username),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * List events received by the authenticated user.
      *
@@ -1614,6 +3364,28 @@ impl Activity {
             )
             .await
     }
+    /**
+     * List events received by the authenticated user.
+     *
+     * This function performs a `GET` to the `/users/{username}/received_events` endpoint.
+     *
+     * As opposed to `list_received_events_for_user`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * These are events that you've received by watching repos and following users. If you are authenticated as the given user, you will see private events. Otherwise, you'll only see public events.
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-events-received-by-the-authenticated-user>
+     */
+    pub fn list_received_events_for_user_request<'a>(
+        &'a self,
+        username: &'a str,
+    ) -> ActivityListReceivedEventsForUserRequest<'a> {
+        ActivityListReceivedEventsForUserRequest {
+            client: self,
+            username,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List events received by the authenticated user.
      *
@@ -1646,6 +3418,30 @@ impl Activity {
             )
             .await
     }
+    /**
+     * List events received by the authenticated user.
+     *
+     * This function performs a `GET` to the `/users/{username}/received_events` endpoint.
+     *
+     * As opposed to `list_all_received_events_for_user`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * These are events that you've received by watching repos and following users. If you are authenticated as the given user, you will see private events. Otherwise, you'll only see public events.
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-events-received-by-the-authenticated-user>
+     */
+    pub fn list_all_received_events_for_user_stream(
+        &self,
+        username: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Event>> + Unpin + '_ {
+        let url = self.client.url(
+            &format!(
+                "/users/{}/received_events",
+                crate::progenitor_support::encode_path(username),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * List public events received by a user.
      *
@@ -1693,6 +3489,28 @@ impl Activity {
             )
             .await
     }
+    /**
+     * List public events received by a user.
+     *
+     * This function performs a `GET` to the `/users/{username}/received_events/public` endpoint.
+     *
+     * As opposed to `list_received_public_events_for_user`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-public-events-received-by-a-user>
+     */
+    pub fn list_received_public_events_for_user_request<'a>(
+        &'a self,
+        username: &'a str,
+    ) -> ActivityListReceivedPublicEventsForUserRequest<'a> {
+        ActivityListReceivedPublicEventsForUserRequest {
+            client: self,
+            username,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List public events received by a user.
      *
@@ -1725,6 +3543,30 @@ impl Activity {
             )
             .await
     }
+    /**
+     * List public events received by a user.
+     *
+     * This function performs a `GET` to the `/users/{username}/received_events/public` endpoint.
+     *
+     * As opposed to `list_all_received_public_events_for_user`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-public-events-received-by-a-user>
+     */
+    pub fn list_all_received_public_events_for_user_stream(
+        &self,
+        username: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Event>> + Unpin + '_ {
+        let url = self.client.url(
+            &format!(
+                "/users/{}/received_events/public",
+                crate::progenitor_support::encode_path(username),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * List repositories starred by a user.
      *
@@ -1787,6 +3629,32 @@ impl Activity {
             )
             .await
     }
+    /**
+     * List repositories starred by a user.
+     *
+     * This function performs a `GET` to the `/users/{username}/starred` endpoint.
+     *
+     * As opposed to `list_repos_starred_by_user`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Lists repositories a user has starred.
+     *
+     * You can also find out _when_ stars were created by passing the following custom [media type](https://docs.github.com/rest/overview/media-types/) via the `Accept` header:
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-repositories-starred-by-a-user>
+     */
+    pub fn list_repos_starred_by_user_request<'a>(
+        &'a self,
+        username: &'a str,
+    ) -> ActivityListReposStarredByUserRequest<'a> {
+        ActivityListReposStarredByUserRequest {
+            client: self,
+            username,
+            sort: None,
+            direction: None,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List repositories watched by a user.
      *
@@ -1834,6 +3702,28 @@ impl Activity {
             )
             .await
     }
+    /**
+     * List repositories watched by a user.
+     *
+     * This function performs a `GET` to the `/users/{username}/subscriptions` endpoint.
+     *
+     * As opposed to `list_repos_watched_by_user`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Lists repositories a user is watching.
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-repositories-watched-by-a-user>
+     */
+    pub fn list_repos_watched_by_user_request<'a>(
+        &'a self,
+        username: &'a str,
+    ) -> ActivityListReposWatchedByUserRequest<'a> {
+        ActivityListReposWatchedByUserRequest {
+            client: self,
+            username,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List repositories watched by a user.
      *
@@ -1866,4 +3756,29 @@ impl Activity {
             )
             .await
     }
+    /**
+     * List repositories watched by a user.
+     *
+     * This function performs a `GET` to the `/users/{username}/subscriptions` endpoint.
+     *
+     * As opposed to `list_all_repos_watched_by_user`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Lists repositories a user is watching.
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-repositories-watched-by-a-user>
+     */
+    pub fn list_all_repos_watched_by_user_stream(
+        &self,
+        username: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::MinimalRepository>> + Unpin + '_
+    {
+        let url = self.client.url(
+            &format!(
+                "/users/{}/subscriptions",
+                crate::progenitor_support::encode_path(username),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
 }
@@ -1,6 +1,685 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Apps::list_webhook_deliveries`], set with a builder.
+pub struct AppsListWebhookDeliveriesRequest<'a> {
+    client: &'a Apps,
+    per_page: Option<i64>,
+    cursor: Option<&'a str>,
+}
+
+impl<'a> AppsListWebhookDeliveriesRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `cursor` query parameter.
+    pub fn cursor(mut self, cursor: &'a str) -> Self {
+        self.cursor = Some(cursor);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::HookDeliveryItem>>> {
+        let Self {
+            client,
+            per_page,
+            cursor,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = cursor {
+            query_args.push(("cursor".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/app/hook/deliveries?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Apps::list_installations`], set with a builder.
+pub struct AppsListInstallationsRequest<'a> {
+    client: &'a Apps,
+    per_page: Option<i64>,
+    page: Option<i64>,
+    since: Option<chrono::DateTime<chrono::Utc>>,
+    outdated: Option<&'a str>,
+}
+
+impl<'a> AppsListInstallationsRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sets the `since` query parameter.
+    pub fn since(mut self, since: chrono::DateTime<chrono::Utc>) -> Self {
+        self.since = Some(since);
+        self
+    }
+
+    /// Sets the `outdated` query parameter.
+    pub fn outdated(mut self, outdated: &'a str) -> Self {
+        self.outdated = Some(outdated);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Installation>>> {
+        let Self {
+            client,
+            per_page,
+            page,
+            since,
+            outdated,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = outdated {
+            query_args.push(("outdated".to_string(), v.to_string()));
+        }
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        if let Some(date) = since {
+            query_args.push(("since".to_string(), date.to_rfc3339()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/app/installations?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Apps::list_repos_accessible_to_installation`], set with a builder.
+pub struct AppsListReposAccessibleToInstallationRequest<'a> {
+    client: &'a Apps,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> AppsListReposAccessibleToInstallationRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::AppsListInstallationReposResponse>> {
+        let Self {
+            client,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/installation/repositories?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Apps::list_plans`], set with a builder.
+pub struct AppsListPlansRequest<'a> {
+    client: &'a Apps,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> AppsListPlansRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::MarketplaceListingPlan>>> {
+        let Self {
+            client,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/marketplace_listing/plans?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Apps::list_accounts_for_plan`], set with a builder.
+pub struct AppsListAccountsForPlanRequest<'a> {
+    client: &'a Apps,
+    plan_id: i64,
+    sort: Option<crate::types::Sort>,
+    direction: Option<crate::types::Order>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> AppsListAccountsForPlanRequest<'a> {
+    /// Sets the `sort` query parameter.
+    pub fn sort(mut self, sort: crate::types::Sort) -> Self {
+        self.sort = Some(sort);
+        self
+    }
+
+    /// Sets the `direction` query parameter.
+    pub fn direction(mut self, direction: crate::types::Order) -> Self {
+        self.direction = Some(direction);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::MarketplacePurchaseData>>> {
+        let Self {
+            client,
+            plan_id,
+            sort,
+            direction,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = direction {
+            query_args.push(("direction".to_string(), v.to_string()));
+        }
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        if let Some(v) = sort {
+            query_args.push(("sort".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/marketplace_listing/plans/{}/accounts?{}",
+                crate::progenitor_support::encode_path(&plan_id.to_string()),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Apps::list_plans_stubbed`], set with a builder.
+pub struct AppsListPlansStubbedRequest<'a> {
+    client: &'a Apps,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> AppsListPlansStubbedRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::MarketplaceListingPlan>>> {
+        let Self {
+            client,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/marketplace_listing/stubbed/plans?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Apps::list_accounts_for_plan_stubbed`], set with a builder.
+pub struct AppsListAccountsForPlanStubbedRequest<'a> {
+    client: &'a Apps,
+    plan_id: i64,
+    sort: Option<crate::types::Sort>,
+    direction: Option<crate::types::Order>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> AppsListAccountsForPlanStubbedRequest<'a> {
+    /// Sets the `sort` query parameter.
+    pub fn sort(mut self, sort: crate::types::Sort) -> Self {
+        self.sort = Some(sort);
+        self
+    }
+
+    /// Sets the `direction` query parameter.
+    pub fn direction(mut self, direction: crate::types::Order) -> Self {
+        self.direction = Some(direction);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::MarketplacePurchaseData>>> {
+        let Self {
+            client,
+            plan_id,
+            sort,
+            direction,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = direction {
+            query_args.push(("direction".to_string(), v.to_string()));
+        }
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        if let Some(v) = sort {
+            query_args.push(("sort".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/marketplace_listing/stubbed/plans/{}/accounts?{}",
+                crate::progenitor_support::encode_path(&plan_id.to_string()),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Apps::list_installations_for_authenticated_user`], set with a builder.
+pub struct AppsListInstallationsForAuthenticatedUserRequest<'a> {
+    client: &'a Apps,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> AppsListInstallationsForAuthenticatedUserRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::AppsListInstallationsResponse>> {
+        let Self {
+            client,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/user/installations?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Apps::list_installation_repos_for_authenticated_user`], set with a builder.
+pub struct AppsListInstallationReposForAuthenticatedUserRequest<'a> {
+    client: &'a Apps,
+    installation_id: i64,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> AppsListInstallationReposForAuthenticatedUserRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::AppsListInstallationReposResponse>> {
+        let Self {
+            client,
+            installation_id,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/user/installations/{}/repositories?{}",
+                crate::progenitor_support::encode_path(&installation_id.to_string()),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Apps::list_subscriptions_for_authenticated_user`], set with a builder.
+pub struct AppsListSubscriptionsForAuthenticatedUserRequest<'a> {
+    client: &'a Apps,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> AppsListSubscriptionsForAuthenticatedUserRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::UserMarketplacePurchase>>> {
+        let Self {
+            client,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/user/marketplace_purchases?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Apps::list_subscriptions_for_authenticated_user_stubbed`], set with a builder.
+pub struct AppsListSubscriptionsForAuthenticatedUserStubbedRequest<'a> {
+    client: &'a Apps,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> AppsListSubscriptionsForAuthenticatedUserStubbedRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::UserMarketplacePurchase>>> {
+        let Self {
+            client,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/user/marketplace_purchases/stubbed?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct Apps {
     pub client: Client,
 }
@@ -163,6 +842,26 @@ impl Apps {
             )
             .await
     }
+    /**
+     * List deliveries for an app webhook.
+     *
+     * This function performs a `GET` to the `/app/hook/deliveries` endpoint.
+     *
+     * As opposed to `list_webhook_deliveries`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Returns a list of webhook deliveries for the webhook configured for a GitHub App.
+     *
+     * You must use a [JWT](https://docs.github.com/apps/building-github-apps/authenticating-with-github-apps/#authenticating-as-a-github-app) to access this endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/apps#list-deliveries-for-an-app-webhook>
+     */
+    pub fn list_webhook_deliveries_request(&self) -> AppsListWebhookDeliveriesRequest<'_> {
+        AppsListWebhookDeliveriesRequest {
+            client: self,
+            per_page: None,
+            cursor: None,
+        }
+    }
     /**
      * List deliveries for an app webhook.
      *
@@ -198,6 +897,34 @@ impl Apps {
             )
             .await
     }
+    /**
+     * List deliveries for an app webhook.
+     *
+     * This function performs a `GET` to the `/app/hook/deliveries` endpoint.
+     *
+     * As opposed to `list_all_webhook_deliveries`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Returns a list of webhook deliveries for the webhook configured for a GitHub App.
+     *
+     * You must use a [JWT](https://docs.github.com/apps/building-github-apps/authenticating-with-github-apps/#authenticating-as-a-github-app) to access this endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/apps#list-deliveries-for-an-app-webhook>
+     */
+    pub fn list_all_webhook_deliveries_stream(
+        &self,
+        cursor: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::HookDeliveryItem>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !cursor.is_empty() {
+            query_args.push(("cursor".to_string(), cursor.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/app/hook/deliveries?{}", query_), None);
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Get a delivery for an app webhook.
      *
@@ -322,6 +1049,28 @@ impl Apps {
             )
             .await
     }
+    /**
+     * List installations for the authenticated app.
+     *
+     * This function performs a `GET` to the `/app/installations` endpoint.
+     *
+     * As opposed to `list_installations`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * You must use a [JWT](https://docs.github.com/apps/building-github-apps/authenticating-with-github-apps/#authenticating-as-a-github-app) to access this endpoint.
+     *
+     * The permissions the installation has are included under the `permissions` key.
+     *
+     * FROM: <https://docs.github.com/rest/reference/apps#list-installations-for-the-authenticated-app>
+     */
+    pub fn list_installations_request(&self) -> AppsListInstallationsRequest<'_> {
+        AppsListInstallationsRequest {
+            client: self,
+            per_page: None,
+            page: None,
+            since: None,
+            outdated: None,
+        }
+    }
     /**
      * List installations for the authenticated app.
      *
@@ -361,6 +1110,37 @@ impl Apps {
             )
             .await
     }
+    /**
+     * List installations for the authenticated app.
+     *
+     * This function performs a `GET` to the `/app/installations` endpoint.
+     *
+     * As opposed to `list_all_installations`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * You must use a [JWT](https://docs.github.com/apps/building-github-apps/authenticating-with-github-apps/#authenticating-as-a-github-app) to access this endpoint.
+     *
+     * The permissions the installation has are included under the `permissions` key.
+     *
+     * FROM: <https://docs.github.com/rest/reference/apps#list-installations-for-the-authenticated-app>
+     */
+    pub fn list_all_installations_stream(
+        &self,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+        outdated: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Installation>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !outdated.is_empty() {
+            query_args.push(("outdated".to_string(), outdated.to_string()));
+        }
+        if let Some(date) = since {
+            query_args.push(("since".to_string(), date.to_rfc3339()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/app/installations?{}", query_), None);
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Get an installation for the authenticated app.
      *
@@ -954,6 +1734,28 @@ impl Apps {
             )
             .await
     }
+    /**
+     * List repositories accessible to the app installation.
+     *
+     * This function performs a `GET` to the `/installation/repositories` endpoint.
+     *
+     * As opposed to `list_repos_accessible_to_installation`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * List repositories that an app installation can access.
+     *
+     * You must use an [installation access token](https://docs.github.com/apps/building-github-apps/authenticating-with-github-apps/#authenticating-as-an-installation) to access this endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/apps#list-repositories-accessible-to-the-app-installation>
+     */
+    pub fn list_repos_accessible_to_installation_request(
+        &self,
+    ) -> AppsListReposAccessibleToInstallationRequest<'_> {
+        AppsListReposAccessibleToInstallationRequest {
+            client: self,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * Revoke an installation access token.
      *
@@ -1057,6 +1859,26 @@ impl Apps {
             )
             .await
     }
+    /**
+     * List plans.
+     *
+     * This function performs a `GET` to the `/marketplace_listing/plans` endpoint.
+     *
+     * As opposed to `list_plans`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Lists all plans that are part of your GitHub Marketplace listing.
+     *
+     * GitHub Apps must use a [JWT](https://docs.github.com/apps/building-github-apps/authenticating-with-github-apps/#authenticating-as-a-github-app) to access this endpoint. OAuth Apps must use [basic authentication](https://docs.github.com/rest/overview/other-authentication-methods#basic-authentication) with their client ID and client secret to access this endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/apps#list-plans>
+     */
+    pub fn list_plans_request(&self) -> AppsListPlansRequest<'_> {
+        AppsListPlansRequest {
+            client: self,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List plans.
      *
@@ -1084,6 +1906,26 @@ impl Apps {
             )
             .await
     }
+    /**
+     * List plans.
+     *
+     * This function performs a `GET` to the `/marketplace_listing/plans` endpoint.
+     *
+     * As opposed to `list_all_plans`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Lists all plans that are part of your GitHub Marketplace listing.
+     *
+     * GitHub Apps must use a [JWT](https://docs.github.com/apps/building-github-apps/authenticating-with-github-apps/#authenticating-as-a-github-app) to access this endpoint. OAuth Apps must use [basic authentication](https://docs.github.com/rest/overview/other-authentication-methods#basic-authentication) with their client ID and client secret to access this endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/apps#list-plans>
+     */
+    pub fn list_all_plans_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::MarketplaceListingPlan>> + Unpin + '_
+    {
+        let url = self.client.url("/marketplace_listing/plans", None);
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * List accounts for a plan.
      *
@@ -1145,6 +1987,32 @@ impl Apps {
             )
             .await
     }
+    /**
+     * List accounts for a plan.
+     *
+     * This function performs a `GET` to the `/marketplace_listing/plans/{plan_id}/accounts` endpoint.
+     *
+     * As opposed to `list_accounts_for_plan`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Returns user and organization accounts associated with the specified plan, including free plans. For per-seat pricing, you see the list of accounts that have purchased the plan, including the number of seats purchased. When someone submits a plan change that won't be processed until the end of their billing cycle, you will also see the upcoming pending change.
+     *
+     * GitHub Apps must use a [JWT](https://docs.github.com/apps/building-github-apps/authenticating-with-github-apps/#authenticating-as-a-github-app) to access this endpoint. OAuth Apps must use [basic authentication](https://docs.github.com/rest/overview/other-authentication-methods#basic-authentication) with their client ID and client secret to access this endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/apps#list-accounts-for-a-plan>
+     */
+    pub fn list_accounts_for_plan_request(
+        &self,
+        plan_id: i64,
+    ) -> AppsListAccountsForPlanRequest<'_> {
+        AppsListAccountsForPlanRequest {
+            client: self,
+            plan_id,
+            sort: None,
+            direction: None,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List accounts for a plan.
      *
@@ -1190,6 +2058,44 @@ impl Apps {
             )
             .await
     }
+    /**
+     * List accounts for a plan.
+     *
+     * This function performs a `GET` to the `/marketplace_listing/plans/{plan_id}/accounts` endpoint.
+     *
+     * As opposed to `list_all_accounts_for_plan`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Returns user and organization accounts associated with the specified plan, including free plans. For per-seat pricing, you see the list of accounts that have purchased the plan, including the number of seats purchased. When someone submits a plan change that won't be processed until the end of their billing cycle, you will also see the upcoming pending change.
+     *
+     * GitHub Apps must use a [JWT](https://docs.github.com/apps/building-github-apps/authenticating-with-github-apps/#authenticating-as-a-github-app) to access this endpoint. OAuth Apps must use [basic authentication](https://docs.github.com/rest/overview/other-authentication-methods#basic-authentication) with their client ID and client secret to access this endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/apps#list-accounts-for-a-plan>
+     */
+    pub fn list_all_accounts_for_plan_stream(
+        &self,
+        plan_id: i64,
+        sort: crate::types::Sort,
+        direction: crate::types::Order,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::MarketplacePurchaseData>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !direction.to_string().is_empty() {
+            query_args.push(("direction".to_string(), direction.to_string()));
+        }
+        if !sort.to_string().is_empty() {
+            query_args.push(("sort".to_string(), sort.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/marketplace_listing/plans/{}/accounts?{}",
+                crate::progenitor_support::encode_path(&plan_id.to_string()),
+                query_
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Get a subscription plan for an account (stubbed).
      *
@@ -1269,6 +2175,26 @@ impl Apps {
             )
             .await
     }
+    /**
+     * List plans (stubbed).
+     *
+     * This function performs a `GET` to the `/marketplace_listing/stubbed/plans` endpoint.
+     *
+     * As opposed to `list_plans_stubbed`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Lists all plans that are part of your GitHub Marketplace listing.
+     *
+     * GitHub Apps must use a [JWT](https://docs.github.com/apps/building-github-apps/authenticating-with-github-apps/#authenticating-as-a-github-app) to access this endpoint. OAuth Apps must use [basic authentication](https://docs.github.com/rest/overview/other-authentication-methods#basic-authentication) with their client ID and client secret to access this endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/apps#list-plans-stubbed>
+     */
+    pub fn list_plans_stubbed_request(&self) -> AppsListPlansStubbedRequest<'_> {
+        AppsListPlansStubbedRequest {
+            client: self,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List plans (stubbed).
      *
@@ -1296,6 +2222,26 @@ impl Apps {
             )
             .await
     }
+    /**
+     * List plans (stubbed).
+     *
+     * This function performs a `GET` to the `/marketplace_listing/stubbed/plans` endpoint.
+     *
+     * As opposed to `list_all_plans_stubbed`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Lists all plans that are part of your GitHub Marketplace listing.
+     *
+     * GitHub Apps must use a [JWT](https://docs.github.com/apps/building-github-apps/authenticating-with-github-apps/#authenticating-as-a-github-app) to access this endpoint. OAuth Apps must use [basic authentication](https://docs.github.com/rest/overview/other-authentication-methods#basic-authentication) with their client ID and client secret to access this endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/apps#list-plans-stubbed>
+     */
+    pub fn list_all_plans_stubbed_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::MarketplaceListingPlan>> + Unpin + '_
+    {
+        let url = self.client.url("/marketplace_listing/stubbed/plans", None);
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * List accounts for a plan (stubbed).
      *
@@ -1357,6 +2303,32 @@ impl Apps {
             )
             .await
     }
+    /**
+     * List accounts for a plan (stubbed).
+     *
+     * This function performs a `GET` to the `/marketplace_listing/stubbed/plans/{plan_id}/accounts` endpoint.
+     *
+     * As opposed to `list_accounts_for_plan_stubbed`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Returns repository and organization accounts associated with the specified plan, including free plans. For per-seat pricing, you see the list of accounts that have purchased the plan, including the number of seats purchased. When someone submits a plan change that won't be processed until the end of their billing cycle, you will also see the upcoming pending change.
+     *
+     * GitHub Apps must use a [JWT](https://docs.github.com/apps/building-github-apps/authenticating-with-github-apps/#authenticating-as-a-github-app) to access this endpoint. OAuth Apps must use [basic authentication](https://docs.github.com/rest/overview/other-authentication-methods#basic-authentication) with their client ID and client secret to access this endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/apps#list-accounts-for-a-plan-stubbed>
+     */
+    pub fn list_accounts_for_plan_stubbed_request(
+        &self,
+        plan_id: i64,
+    ) -> AppsListAccountsForPlanStubbedRequest<'_> {
+        AppsListAccountsForPlanStubbedRequest {
+            client: self,
+            plan_id,
+            sort: None,
+            direction: None,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List accounts for a plan (stubbed).
      *
@@ -1402,6 +2374,44 @@ impl Apps {
             )
             .await
     }
+    /**
+     * List accounts for a plan (stubbed).
+     *
+     * This function performs a `GET` to the `/marketplace_listing/stubbed/plans/{plan_id}/accounts` endpoint.
+     *
+     * As opposed to `list_all_accounts_for_plan_stubbed`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Returns repository and organization accounts associated with the specified plan, including free plans. For per-seat pricing, you see the list of accounts that have purchased the plan, including the number of seats purchased. When someone submits a plan change that won't be processed until the end of their billing cycle, you will also see the upcoming pending change.
+     *
+     * GitHub Apps must use a [JWT](https://docs.github.com/apps/building-github-apps/authenticating-with-github-apps/#authenticating-as-a-github-app) to access this endpoint. OAuth Apps must use [basic authentication](https://docs.github.com/rest/overview/other-authentication-methods#basic-authentication) with their client ID and client secret to access this endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/apps#list-accounts-for-a-plan-stubbed>
+     */
+    pub fn list_all_accounts_for_plan_stubbed_stream(
+        &self,
+        plan_id: i64,
+        sort: crate::types::Sort,
+        direction: crate::types::Order,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::MarketplacePurchaseData>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !direction.to_string().is_empty() {
+            query_args.push(("direction".to_string(), direction.to_string()));
+        }
+        if !sort.to_string().is_empty() {
+            query_args.push(("sort".to_string(), sort.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/marketplace_listing/stubbed/plans/{}/accounts?{}",
+                crate::progenitor_support::encode_path(&plan_id.to_string()),
+                query_
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Get an organization installation for the authenticated app.
      *
@@ -1568,6 +2578,32 @@ impl Apps {
             )
             .await
     }
+    /**
+     * List app installations accessible to the user access token.
+     *
+     * This function performs a `GET` to the `/user/installations` endpoint.
+     *
+     * As opposed to `list_installations_for_authenticated_user`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Lists installations of your GitHub App that the authenticated user has explicit permission (`:read`, `:write`, or `:admin`) to access.
+     *
+     * You must use a [user-to-server OAuth access token](https://docs.github.com/apps/building-github-apps/identifying-and-authorizing-users-for-github-apps/#identifying-users-on-your-site), created for a user who has authorized your GitHub App, to access this endpoint.
+     *
+     * The authenticated user has explicit permission to access repositories they own, repositories where they are a collaborator, and repositories that they can access through an organization membership.
+     *
+     * You can find the permissions for the installation under the `permissions` key.
+     *
+     * FROM: <https://docs.github.com/rest/reference/apps#list-app-installations-accessible-to-the-user-access-token>
+     */
+    pub fn list_installations_for_authenticated_user_request(
+        &self,
+    ) -> AppsListInstallationsForAuthenticatedUserRequest<'_> {
+        AppsListInstallationsForAuthenticatedUserRequest {
+            client: self,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List repositories accessible to the user access token.
      *
@@ -1621,6 +2657,34 @@ impl Apps {
             )
             .await
     }
+    /**
+     * List repositories accessible to the user access token.
+     *
+     * This function performs a `GET` to the `/user/installations/{installation_id}/repositories` endpoint.
+     *
+     * As opposed to `list_installation_repos_for_authenticated_user`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * List repositories that the authenticated user has explicit permission (`:read`, `:write`, or `:admin`) to access for an installation.
+     *
+     * The authenticated user has explicit permission to access repositories they own, repositories where they are a collaborator, and repositories that they can access through an organization membership.
+     *
+     * You must use a [user-to-server OAuth access token](https://docs.github.com/apps/building-github-apps/identifying-and-authorizing-users-for-github-apps/#identifying-users-on-your-site), created for a user who has authorized your GitHub App, to access this endpoint.
+     *
+     * The access the user has to each repository is included in the hash under the `permissions` key.
+     *
+     * FROM: <https://docs.github.com/rest/reference/apps#list-repositories-accessible-to-the-user-access-token>
+     */
+    pub fn list_installation_repos_for_authenticated_user_request(
+        &self,
+        installation_id: i64,
+    ) -> AppsListInstallationReposForAuthenticatedUserRequest<'_> {
+        AppsListInstallationReposForAuthenticatedUserRequest {
+            client: self,
+            installation_id,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * Add a repository to an app installation.
      *
@@ -1739,6 +2803,26 @@ impl Apps {
             )
             .await
     }
+    /**
+     * List subscriptions for the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/marketplace_purchases` endpoint.
+     *
+     * As opposed to `list_subscriptions_for_authenticated_user`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Lists the active subscriptions for the authenticated user. You must use a [user-to-server OAuth access token](https://docs.github.com/apps/building-github-apps/identifying-and-authorizing-users-for-github-apps/#identifying-users-on-your-site), created for a user who has authorized your GitHub App, to access this endpoint. . OAuth Apps must authenticate using an [OAuth token](https://docs.github.com/apps/building-github-apps/authenticating-with-github-apps/).
+     *
+     * FROM: <https://docs.github.com/rest/reference/apps#list-subscriptions-for-the-authenticated-user>
+     */
+    pub fn list_subscriptions_for_authenticated_user_request(
+        &self,
+    ) -> AppsListSubscriptionsForAuthenticatedUserRequest<'_> {
+        AppsListSubscriptionsForAuthenticatedUserRequest {
+            client: self,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List subscriptions for the authenticated user.
      *
@@ -1764,6 +2848,24 @@ impl Apps {
             )
             .await
     }
+    /**
+     * List subscriptions for the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/marketplace_purchases` endpoint.
+     *
+     * As opposed to `list_all_subscriptions_for_authenticated_user`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Lists the active subscriptions for the authenticated user. You must use a [user-to-server OAuth access token](https://docs.github.com/apps/building-github-apps/identifying-and-authorizing-users-for-github-apps/#identifying-users-on-your-site), created for a user who has authorized your GitHub App, to access this endpoint. . OAuth Apps must authenticate using an [OAuth token](https://docs.github.com/apps/building-github-apps/authenticating-with-github-apps/).
+     *
+     * FROM: <https://docs.github.com/rest/reference/apps#list-subscriptions-for-the-authenticated-user>
+     */
+    pub fn list_all_subscriptions_for_authenticated_user_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::UserMarketplacePurchase>> + Unpin + '_
+    {
+        let url = self.client.url("/user/marketplace_purchases", None);
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * List subscriptions for the authenticated user (stubbed).
      *
@@ -1805,6 +2907,26 @@ impl Apps {
             )
             .await
     }
+    /**
+     * List subscriptions for the authenticated user (stubbed).
+     *
+     * This function performs a `GET` to the `/user/marketplace_purchases/stubbed` endpoint.
+     *
+     * As opposed to `list_subscriptions_for_authenticated_user_stubbed`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Lists the active subscriptions for the authenticated user. You must use a [user-to-server OAuth access token](https://docs.github.com/apps/building-github-apps/identifying-and-authorizing-users-for-github-apps/#identifying-users-on-your-site), created for a user who has authorized your GitHub App, to access this endpoint. . OAuth Apps must authenticate using an [OAuth token](https://docs.github.com/apps/building-github-apps/authenticating-with-github-apps/).
+     *
+     * FROM: <https://docs.github.com/rest/reference/apps#list-subscriptions-for-the-authenticated-user-stubbed>
+     */
+    pub fn list_subscriptions_for_authenticated_user_stubbed_request(
+        &self,
+    ) -> AppsListSubscriptionsForAuthenticatedUserStubbedRequest<'_> {
+        AppsListSubscriptionsForAuthenticatedUserStubbedRequest {
+            client: self,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List subscriptions for the authenticated user (stubbed).
      *
@@ -1830,6 +2952,24 @@ impl Apps {
             )
             .await
     }
+    /**
+     * List subscriptions for the authenticated user (stubbed).
+     *
+     * This function performs a `GET` to the `/user/marketplace_purchases/stubbed` endpoint.
+     *
+     * As opposed to `list_all_subscriptions_for_authenticated_user_stubbed`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Lists the active subscriptions for the authenticated user. You must use a [user-to-server OAuth access token](https://docs.github.com/apps/building-github-apps/identifying-and-authorizing-users-for-github-apps/#identifying-users-on-your-site), created for a user who has authorized your GitHub App, to access this endpoint. . OAuth Apps must authenticate using an [OAuth token](https://docs.github.com/apps/building-github-apps/authenticating-with-github-apps/).
+     *
+     * FROM: <https://docs.github.com/rest/reference/apps#list-subscriptions-for-the-authenticated-user-stubbed>
+     */
+    pub fn list_all_subscriptions_for_authenticated_user_stubbed_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::UserMarketplacePurchase>> + Unpin + '_
+    {
+        let url = self.client.url("/user/marketplace_purchases/stubbed", None);
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Get a user installation for the authenticated app.
      *
@@ -0,0 +1,1753 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Actions::list_selected_repositories_enabled_github_actions_organization`], set with a builder.
+pub struct ActionsListSelectedRepositoriesEnabledGithubActionsOrganizationRequest<'a> {
+    inner:
+        crate::actions::ActionsListSelectedRepositoriesEnabledGithubActionsOrganizationRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ActionsListSelectedRepositoriesEnabledGithubActionsOrganizationRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(
+        self,
+    ) -> ClientResult<
+        crate::Response<
+            crate::types::ActionsListSelectedRepositoriesEnabledGithubOrganizationResponse,
+        >,
+    > {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Actions::list_self_hosted_runner_groups_for_org`], set with a builder.
+pub struct ActionsListSelfHostedRunnerGroupsForOrgRequest<'a> {
+    inner: crate::actions::ActionsListSelfHostedRunnerGroupsForOrgRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ActionsListSelfHostedRunnerGroupsForOrgRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::ActionsListSelfHostedRunnerGroupsOrgResponse>>
+    {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Actions::list_repo_access_to_self_hosted_runner_group_in_org`], set with a builder.
+pub struct ActionsListRepoAccessToSelfHostedRunnerGroupInOrgRequest<'a> {
+    inner: crate::actions::ActionsListRepoAccessToSelfHostedRunnerGroupInOrgRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ActionsListRepoAccessToSelfHostedRunnerGroupInOrgRequest<'a> {
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(
+        self,
+    ) -> ClientResult<
+        crate::Response<crate::types::ActionsListRepoAccessSelfHostedRunnerGroupInOrgResponse>,
+    > {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Actions::list_self_hosted_runners_in_group_for_org`], set with a builder.
+pub struct ActionsListSelfHostedRunnersInGroupForOrgRequest<'a> {
+    inner: crate::actions::ActionsListSelfHostedRunnersInGroupForOrgRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ActionsListSelfHostedRunnersInGroupForOrgRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::ActionsListSelfHostedRunnersInGroupOrgResponse>>
+    {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Actions::list_self_hosted_runners_for_org`], set with a builder.
+pub struct ActionsListSelfHostedRunnersForOrgRequest<'a> {
+    inner: crate::actions::ActionsListSelfHostedRunnersForOrgRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ActionsListSelfHostedRunnersForOrgRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::ActionsListSelfHostedRunnersOrgResponse>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Actions::list_org_secrets`], set with a builder.
+pub struct ActionsListOrgSecretsRequest<'a> {
+    inner: crate::actions::ActionsListOrgSecretsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ActionsListOrgSecretsRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::ActionsListOrgSecretsResponse>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Actions::list_selected_repos_for_org_secret`], set with a builder.
+pub struct ActionsListSelectedReposForOrgSecretRequest<'a> {
+    inner: crate::actions::ActionsListSelectedReposForOrgSecretRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ActionsListSelectedReposForOrgSecretRequest<'a> {
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::ActionsListSelectedReposOrgSecretResponse>>
+    {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Actions::list_artifacts_for_repo`], set with a builder.
+pub struct ActionsListArtifactsForRepoRequest<'a> {
+    inner: crate::actions::ActionsListArtifactsForRepoRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ActionsListArtifactsForRepoRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::ActionsListArtifactsRepoResponse>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Actions::list_self_hosted_runners_for_repo`], set with a builder.
+pub struct ActionsListSelfHostedRunnersForRepoRequest<'a> {
+    inner: crate::actions::ActionsListSelfHostedRunnersForRepoRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ActionsListSelfHostedRunnersForRepoRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::ActionsListSelfHostedRunnersOrgResponse>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Actions::list_workflow_runs_for_repo`], set with a builder.
+pub struct ActionsListWorkflowRunsForRepoRequest<'a> {
+    inner: crate::actions::ActionsListWorkflowRunsForRepoRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ActionsListWorkflowRunsForRepoRequest<'a> {
+    /// Sets the `actor` query parameter.
+    pub fn actor(mut self, actor: &'a str) -> Self {
+        self.inner = self.inner.actor(actor);
+        self
+    }
+
+    /// Sets the `branch` query parameter.
+    pub fn branch(mut self, branch: &'a str) -> Self {
+        self.inner = self.inner.branch(branch);
+        self
+    }
+
+    /// Sets the `event` query parameter.
+    pub fn event(mut self, event: &'a str) -> Self {
+        self.inner = self.inner.event(event);
+        self
+    }
+
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: crate::types::WorkflowRunStatus) -> Self {
+        self.inner = self.inner.status(status);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sets the `created` query parameter.
+    pub fn created(mut self, created: &'a str) -> Self {
+        self.inner = self.inner.created(created);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::ActionsListWorkflowRunsResponse>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Actions::list_workflow_run_artifacts`], set with a builder.
+pub struct ActionsListWorkflowRunArtifactsRequest<'a> {
+    inner: crate::actions::ActionsListWorkflowRunArtifactsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ActionsListWorkflowRunArtifactsRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::ActionsListArtifactsRepoResponse>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Actions::list_jobs_for_workflow_run`], set with a builder.
+pub struct ActionsListJobsForWorkflowRunRequest<'a> {
+    inner: crate::actions::ActionsListJobsForWorkflowRunRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ActionsListJobsForWorkflowRunRequest<'a> {
+    /// Sets the `filter` query parameter.
+    pub fn filter(mut self, filter: crate::types::ActionsListJobsWorkflowRunFilter) -> Self {
+        self.inner = self.inner.filter(filter);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::ActionsListJobsWorkflowRunResponse>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Actions::list_repo_secrets`], set with a builder.
+pub struct ActionsListRepoSecretsRequest<'a> {
+    inner: crate::actions::ActionsListRepoSecretsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ActionsListRepoSecretsRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::ActionsListRepoSecretsResponse>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Actions::list_repo_workflows`], set with a builder.
+pub struct ActionsListRepoWorkflowsRequest<'a> {
+    inner: crate::actions::ActionsListRepoWorkflowsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ActionsListRepoWorkflowsRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::ActionsListRepoWorkflowsResponse>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Actions::list_workflow_runs`], set with a builder.
+pub struct ActionsListWorkflowRunsRequest<'a> {
+    inner: crate::actions::ActionsListWorkflowRunsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ActionsListWorkflowRunsRequest<'a> {
+    /// Sets the `actor` query parameter.
+    pub fn actor(mut self, actor: &'a str) -> Self {
+        self.inner = self.inner.actor(actor);
+        self
+    }
+
+    /// Sets the `branch` query parameter.
+    pub fn branch(mut self, branch: &'a str) -> Self {
+        self.inner = self.inner.branch(branch);
+        self
+    }
+
+    /// Sets the `event` query parameter.
+    pub fn event(mut self, event: &'a str) -> Self {
+        self.inner = self.inner.event(event);
+        self
+    }
+
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: crate::types::WorkflowRunStatus) -> Self {
+        self.inner = self.inner.status(status);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sets the `created` query parameter.
+    pub fn created(mut self, created: &'a str) -> Self {
+        self.inner = self.inner.created(created);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::ActionsListWorkflowRunsResponse>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Actions::list_environment_secrets`], set with a builder.
+pub struct ActionsListEnvironmentSecretsRequest<'a> {
+    inner: crate::actions::ActionsListEnvironmentSecretsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ActionsListEnvironmentSecretsRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::ActionsListRepoSecretsResponse>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::actions::Actions`].
+pub struct Actions {
+    inner: crate::actions::Actions,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Actions {
+    pub(crate) fn new(
+        inner: crate::actions::Actions,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Actions { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::actions::Actions::get_github_actions_permissions_organization`].
+    pub fn get_github_actions_permissions_organization(
+        &self,
+        org: &str,
+    ) -> ClientResult<crate::Response<crate::types::ActionsOrganizationPermissions>> {
+        self.runtime
+            .block_on(self.inner.get_github_actions_permissions_organization(org))
+    }
+    /// The blocking version of [`crate::actions::Actions::set_github_actions_permissions_organization`].
+    pub fn set_github_actions_permissions_organization(
+        &self,
+        org: &str,
+        body: &crate::types::ActionsSetGithubPermissionsOrganizationRequest,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .set_github_actions_permissions_organization(org, body),
+        )
+    }
+    /// The blocking version of [`crate::actions::Actions::list_selected_repositories_enabled_github_actions_organization`].
+    pub fn list_selected_repositories_enabled_github_actions_organization(
+        &self,
+        org: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<
+        crate::Response<
+            crate::types::ActionsListSelectedRepositoriesEnabledGithubOrganizationResponse,
+        >,
+    > {
+        self.runtime.block_on(
+            self.inner
+                .list_selected_repositories_enabled_github_actions_organization(
+                    org, per_page, page,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::actions::Actions::list_selected_repositories_enabled_github_actions_organization_request`].
+    pub fn list_selected_repositories_enabled_github_actions_organization_request<'a>(
+        &'a self,
+        org: &'a str,
+    ) -> ActionsListSelectedRepositoriesEnabledGithubActionsOrganizationRequest<'a> {
+        ActionsListSelectedRepositoriesEnabledGithubActionsOrganizationRequest {
+            inner: self
+                .inner
+                .list_selected_repositories_enabled_github_actions_organization_request(org),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::actions::Actions::set_selected_repositories_enabled_github_actions_organization`].
+    pub fn set_selected_repositories_enabled_github_actions_organization(
+        &self,
+        org: &str,
+        body: &crate::types::ActionsSetRepoAccessSelfHostedRunnerGroupInOrgRequest,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .set_selected_repositories_enabled_github_actions_organization(org, body),
+        )
+    }
+    /// The blocking version of [`crate::actions::Actions::enable_selected_repository_github_actions_organization`].
+    pub fn enable_selected_repository_github_actions_organization(
+        &self,
+        org: &str,
+        repository_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .enable_selected_repository_github_actions_organization(org, repository_id),
+        )
+    }
+    /// The blocking version of [`crate::actions::Actions::disable_selected_repository_github_actions_organization`].
+    pub fn disable_selected_repository_github_actions_organization(
+        &self,
+        org: &str,
+        repository_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .disable_selected_repository_github_actions_organization(org, repository_id),
+        )
+    }
+    /// The blocking version of [`crate::actions::Actions::get_allowed_actions_organization`].
+    pub fn get_allowed_actions_organization(
+        &self,
+        org: &str,
+    ) -> ClientResult<crate::Response<crate::types::SelectedActions>> {
+        self.runtime
+            .block_on(self.inner.get_allowed_actions_organization(org))
+    }
+    /// The blocking version of [`crate::actions::Actions::set_allowed_actions_organization`].
+    pub fn set_allowed_actions_organization(
+        &self,
+        org: &str,
+        body: &crate::types::SelectedActions,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.set_allowed_actions_organization(org, body))
+    }
+    /// The blocking version of [`crate::actions::Actions::list_self_hosted_runner_groups_for_org`].
+    pub fn list_self_hosted_runner_groups_for_org(
+        &self,
+        org: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<crate::types::ActionsListSelfHostedRunnerGroupsOrgResponse>>
+    {
+        self.runtime.block_on(
+            self.inner
+                .list_self_hosted_runner_groups_for_org(org, per_page, page),
+        )
+    }
+    /// The blocking version of [`crate::actions::Actions::list_self_hosted_runner_groups_for_org_request`].
+    pub fn list_self_hosted_runner_groups_for_org_request<'a>(
+        &'a self,
+        org: &'a str,
+    ) -> ActionsListSelfHostedRunnerGroupsForOrgRequest<'a> {
+        ActionsListSelfHostedRunnerGroupsForOrgRequest {
+            inner: self
+                .inner
+                .list_self_hosted_runner_groups_for_org_request(org),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::actions::Actions::create_self_hosted_runner_group_for_org`].
+    pub fn create_self_hosted_runner_group_for_org(
+        &self,
+        org: &str,
+        body: &crate::types::ActionsCreateSelfHostedRunnerGroupOrgRequest,
+    ) -> ClientResult<crate::Response<crate::types::RunnerGroupsOrg>> {
+        self.runtime.block_on(
+            self.inner
+                .create_self_hosted_runner_group_for_org(org, body),
+        )
+    }
+    /// The blocking version of [`crate::actions::Actions::get_self_hosted_runner_group_for_org`].
+    pub fn get_self_hosted_runner_group_for_org(
+        &self,
+        org: &str,
+        runner_group_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::RunnerGroupsOrg>> {
+        self.runtime.block_on(
+            self.inner
+                .get_self_hosted_runner_group_for_org(org, runner_group_id),
+        )
+    }
+    /// The blocking version of [`crate::actions::Actions::delete_self_hosted_runner_group_from_org`].
+    pub fn delete_self_hosted_runner_group_from_org(
+        &self,
+        org: &str,
+        runner_group_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .delete_self_hosted_runner_group_from_org(org, runner_group_id),
+        )
+    }
+    /// The blocking version of [`crate::actions::Actions::update_self_hosted_runner_group_for_org`].
+    pub fn update_self_hosted_runner_group_for_org(
+        &self,
+        org: &str,
+        runner_group_id: i64,
+        body: &crate::types::ActionsUpdateSelfHostedRunnerGroupOrgRequest,
+    ) -> ClientResult<crate::Response<crate::types::RunnerGroupsOrg>> {
+        self.runtime
+            .block_on(self.inner.update_self_hosted_runner_group_for_org(
+                org,
+                runner_group_id,
+                body,
+            ))
+    }
+    /// The blocking version of [`crate::actions::Actions::list_repo_access_to_self_hosted_runner_group_in_org`].
+    pub fn list_repo_access_to_self_hosted_runner_group_in_org(
+        &self,
+        org: &str,
+        runner_group_id: i64,
+        page: i64,
+        per_page: i64,
+    ) -> ClientResult<
+        crate::Response<crate::types::ActionsListRepoAccessSelfHostedRunnerGroupInOrgResponse>,
+    > {
+        self.runtime.block_on(
+            self.inner
+                .list_repo_access_to_self_hosted_runner_group_in_org(
+                    org,
+                    runner_group_id,
+                    page,
+                    per_page,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::actions::Actions::list_repo_access_to_self_hosted_runner_group_in_org_request`].
+    pub fn list_repo_access_to_self_hosted_runner_group_in_org_request<'a>(
+        &'a self,
+        org: &'a str,
+        runner_group_id: i64,
+    ) -> ActionsListRepoAccessToSelfHostedRunnerGroupInOrgRequest<'a> {
+        ActionsListRepoAccessToSelfHostedRunnerGroupInOrgRequest {
+            inner: self
+                .inner
+                .list_repo_access_to_self_hosted_runner_group_in_org_request(org, runner_group_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::actions::Actions::set_repo_access_to_self_hosted_runner_group_in_org`].
+    pub fn set_repo_access_to_self_hosted_runner_group_in_org(
+        &self,
+        org: &str,
+        runner_group_id: i64,
+        body: &crate::types::ActionsSetRepoAccessSelfHostedRunnerGroupInOrgRequest,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .set_repo_access_to_self_hosted_runner_group_in_org(org, runner_group_id, body),
+        )
+    }
+    /// The blocking version of [`crate::actions::Actions::add_repo_access_to_self_hosted_runner_group_in_org`].
+    pub fn add_repo_access_to_self_hosted_runner_group_in_org(
+        &self,
+        org: &str,
+        runner_group_id: i64,
+        repository_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .add_repo_access_to_self_hosted_runner_group_in_org(
+                    org,
+                    runner_group_id,
+                    repository_id,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::actions::Actions::remove_repo_access_to_self_hosted_runner_group_in_org`].
+    pub fn remove_repo_access_to_self_hosted_runner_group_in_org(
+        &self,
+        org: &str,
+        runner_group_id: i64,
+        repository_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .remove_repo_access_to_self_hosted_runner_group_in_org(
+                    org,
+                    runner_group_id,
+                    repository_id,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::actions::Actions::list_self_hosted_runners_in_group_for_org`].
+    pub fn list_self_hosted_runners_in_group_for_org(
+        &self,
+        org: &str,
+        runner_group_id: i64,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<crate::types::ActionsListSelfHostedRunnersInGroupOrgResponse>>
+    {
+        self.runtime
+            .block_on(self.inner.list_self_hosted_runners_in_group_for_org(
+                org,
+                runner_group_id,
+                per_page,
+                page,
+            ))
+    }
+    /// The blocking version of [`crate::actions::Actions::list_self_hosted_runners_in_group_for_org_request`].
+    pub fn list_self_hosted_runners_in_group_for_org_request<'a>(
+        &'a self,
+        org: &'a str,
+        runner_group_id: i64,
+    ) -> ActionsListSelfHostedRunnersInGroupForOrgRequest<'a> {
+        ActionsListSelfHostedRunnersInGroupForOrgRequest {
+            inner: self
+                .inner
+                .list_self_hosted_runners_in_group_for_org_request(org, runner_group_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::actions::Actions::set_self_hosted_runners_in_group_for_org`].
+    pub fn set_self_hosted_runners_in_group_for_org(
+        &self,
+        org: &str,
+        runner_group_id: i64,
+        body: &crate::types::ActionsSetSelfHostedRunnersInGroupOrgRequest,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.set_self_hosted_runners_in_group_for_org(
+                org,
+                runner_group_id,
+                body,
+            ))
+    }
+    /// The blocking version of [`crate::actions::Actions::add_self_hosted_runner_to_group_for_org`].
+    pub fn add_self_hosted_runner_to_group_for_org(
+        &self,
+        org: &str,
+        runner_group_id: i64,
+        runner_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.add_self_hosted_runner_to_group_for_org(
+                org,
+                runner_group_id,
+                runner_id,
+            ))
+    }
+    /// The blocking version of [`crate::actions::Actions::remove_self_hosted_runner_from_group_for_org`].
+    pub fn remove_self_hosted_runner_from_group_for_org(
+        &self,
+        org: &str,
+        runner_group_id: i64,
+        runner_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.remove_self_hosted_runner_from_group_for_org(
+                org,
+                runner_group_id,
+                runner_id,
+            ))
+    }
+    /// The blocking version of [`crate::actions::Actions::list_self_hosted_runners_for_org`].
+    pub fn list_self_hosted_runners_for_org(
+        &self,
+        org: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<crate::types::ActionsListSelfHostedRunnersOrgResponse>> {
+        self.runtime.block_on(
+            self.inner
+                .list_self_hosted_runners_for_org(org, per_page, page),
+        )
+    }
+    /// The blocking version of [`crate::actions::Actions::list_self_hosted_runners_for_org_request`].
+    pub fn list_self_hosted_runners_for_org_request<'a>(
+        &'a self,
+        org: &'a str,
+    ) -> ActionsListSelfHostedRunnersForOrgRequest<'a> {
+        ActionsListSelfHostedRunnersForOrgRequest {
+            inner: self.inner.list_self_hosted_runners_for_org_request(org),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::actions::Actions::list_runner_applications_for_org`].
+    pub fn list_runner_applications_for_org(
+        &self,
+        org: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::RunnerApplication>>> {
+        self.runtime
+            .block_on(self.inner.list_runner_applications_for_org(org))
+    }
+    /// The blocking version of [`crate::actions::Actions::list_all_runner_applications_for_org`].
+    pub fn list_all_runner_applications_for_org(
+        &self,
+        org: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::RunnerApplication>>> {
+        self.runtime
+            .block_on(self.inner.list_all_runner_applications_for_org(org))
+    }
+    /// The blocking version of [`crate::actions::Actions::create_registration_token_for_org`].
+    pub fn create_registration_token_for_org(
+        &self,
+        org: &str,
+    ) -> ClientResult<crate::Response<crate::types::AuthenticationToken>> {
+        self.runtime
+            .block_on(self.inner.create_registration_token_for_org(org))
+    }
+    /// The blocking version of [`crate::actions::Actions::create_remove_token_for_org`].
+    pub fn create_remove_token_for_org(
+        &self,
+        org: &str,
+    ) -> ClientResult<crate::Response<crate::types::AuthenticationToken>> {
+        self.runtime
+            .block_on(self.inner.create_remove_token_for_org(org))
+    }
+    /// The blocking version of [`crate::actions::Actions::get_self_hosted_runner_for_org`].
+    pub fn get_self_hosted_runner_for_org(
+        &self,
+        org: &str,
+        runner_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::Runner>> {
+        self.runtime
+            .block_on(self.inner.get_self_hosted_runner_for_org(org, runner_id))
+    }
+    /// The blocking version of [`crate::actions::Actions::delete_self_hosted_runner_from_org`].
+    pub fn delete_self_hosted_runner_from_org(
+        &self,
+        org: &str,
+        runner_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .delete_self_hosted_runner_from_org(org, runner_id),
+        )
+    }
+    /// The blocking version of [`crate::actions::Actions::list_org_secrets`].
+    pub fn list_org_secrets(
+        &self,
+        org: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<crate::types::ActionsListOrgSecretsResponse>> {
+        self.runtime
+            .block_on(self.inner.list_org_secrets(org, per_page, page))
+    }
+    /// The blocking version of [`crate::actions::Actions::list_org_secrets_request`].
+    pub fn list_org_secrets_request<'a>(
+        &'a self,
+        org: &'a str,
+    ) -> ActionsListOrgSecretsRequest<'a> {
+        ActionsListOrgSecretsRequest {
+            inner: self.inner.list_org_secrets_request(org),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::actions::Actions::get_org_public_key`].
+    pub fn get_org_public_key(
+        &self,
+        org: &str,
+    ) -> ClientResult<crate::Response<crate::types::ActionsPublicKey>> {
+        self.runtime.block_on(self.inner.get_org_public_key(org))
+    }
+    /// The blocking version of [`crate::actions::Actions::get_org_secret`].
+    pub fn get_org_secret(
+        &self,
+        org: &str,
+        secret_name: &str,
+    ) -> ClientResult<crate::Response<crate::types::OrganizationActionsSecret>> {
+        self.runtime
+            .block_on(self.inner.get_org_secret(org, secret_name))
+    }
+    /// The blocking version of [`crate::actions::Actions::create_or_update_org_secret`].
+    pub fn create_or_update_org_secret(
+        &self,
+        org: &str,
+        secret_name: &str,
+        body: &crate::types::ActionsCreateUpdateOrgSecretRequest,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .create_or_update_org_secret(org, secret_name, body),
+        )
+    }
+    /// The blocking version of [`crate::actions::Actions::delete_org_secret`].
+    pub fn delete_org_secret(
+        &self,
+        org: &str,
+        secret_name: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete_org_secret(org, secret_name))
+    }
+    /// The blocking version of [`crate::actions::Actions::list_selected_repos_for_org_secret`].
+    pub fn list_selected_repos_for_org_secret(
+        &self,
+        org: &str,
+        secret_name: &str,
+        page: i64,
+        per_page: i64,
+    ) -> ClientResult<crate::Response<crate::types::ActionsListSelectedReposOrgSecretResponse>>
+    {
+        self.runtime
+            .block_on(self.inner.list_selected_repos_for_org_secret(
+                org,
+                secret_name,
+                page,
+                per_page,
+            ))
+    }
+    /// The blocking version of [`crate::actions::Actions::list_selected_repos_for_org_secret_request`].
+    pub fn list_selected_repos_for_org_secret_request<'a>(
+        &'a self,
+        org: &'a str,
+        secret_name: &'a str,
+    ) -> ActionsListSelectedReposForOrgSecretRequest<'a> {
+        ActionsListSelectedReposForOrgSecretRequest {
+            inner: self
+                .inner
+                .list_selected_repos_for_org_secret_request(org, secret_name),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::actions::Actions::set_selected_repos_for_org_secret`].
+    pub fn set_selected_repos_for_org_secret(
+        &self,
+        org: &str,
+        secret_name: &str,
+        body: &crate::types::ActionsSetSelectedReposOrgSecretRequest,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .set_selected_repos_for_org_secret(org, secret_name, body),
+        )
+    }
+    /// The blocking version of [`crate::actions::Actions::add_selected_repo_to_org_secret`].
+    pub fn add_selected_repo_to_org_secret(
+        &self,
+        org: &str,
+        secret_name: &str,
+        repository_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(
+                self.inner
+                    .add_selected_repo_to_org_secret(org, secret_name, repository_id),
+            )
+    }
+    /// The blocking version of [`crate::actions::Actions::remove_selected_repo_from_org_secret`].
+    pub fn remove_selected_repo_from_org_secret(
+        &self,
+        org: &str,
+        secret_name: &str,
+        repository_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.remove_selected_repo_from_org_secret(
+                org,
+                secret_name,
+                repository_id,
+            ))
+    }
+    /// The blocking version of [`crate::actions::Actions::list_artifacts_for_repo`].
+    pub fn list_artifacts_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<crate::types::ActionsListArtifactsRepoResponse>> {
+        self.runtime.block_on(
+            self.inner
+                .list_artifacts_for_repo(owner, repo, per_page, page),
+        )
+    }
+    /// The blocking version of [`crate::actions::Actions::list_artifacts_for_repo_request`].
+    pub fn list_artifacts_for_repo_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+    ) -> ActionsListArtifactsForRepoRequest<'a> {
+        ActionsListArtifactsForRepoRequest {
+            inner: self.inner.list_artifacts_for_repo_request(owner, repo),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::actions::Actions::get_artifact`].
+    pub fn get_artifact(
+        &self,
+        owner: &str,
+        repo: &str,
+        artifact_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::Artifact>> {
+        self.runtime
+            .block_on(self.inner.get_artifact(owner, repo, artifact_id))
+    }
+    /// The blocking version of [`crate::actions::Actions::delete_artifact`].
+    pub fn delete_artifact(
+        &self,
+        owner: &str,
+        repo: &str,
+        artifact_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete_artifact(owner, repo, artifact_id))
+    }
+    /// The blocking version of [`crate::actions::Actions::download_artifact`].
+    pub fn download_artifact(
+        &self,
+        owner: &str,
+        repo: &str,
+        artifact_id: i64,
+        archive_format: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.download_artifact(
+            owner,
+            repo,
+            artifact_id,
+            archive_format,
+        ))
+    }
+    /// The blocking version of [`crate::actions::Actions::get_job_for_workflow_run`].
+    pub fn get_job_for_workflow_run(
+        &self,
+        owner: &str,
+        repo: &str,
+        job_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::Job>> {
+        self.runtime
+            .block_on(self.inner.get_job_for_workflow_run(owner, repo, job_id))
+    }
+    /// The blocking version of [`crate::actions::Actions::download_job_logs_for_workflow_run`].
+    pub fn download_job_logs_for_workflow_run(
+        &self,
+        owner: &str,
+        repo: &str,
+        job_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .download_job_logs_for_workflow_run(owner, repo, job_id),
+        )
+    }
+    /// The blocking version of [`crate::actions::Actions::get_github_actions_permissions_repository`].
+    pub fn get_github_actions_permissions_repository(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> ClientResult<crate::Response<crate::types::ActionsRepositoryPermissions>> {
+        self.runtime.block_on(
+            self.inner
+                .get_github_actions_permissions_repository(owner, repo),
+        )
+    }
+    /// The blocking version of [`crate::actions::Actions::set_github_actions_permissions_repository`].
+    pub fn set_github_actions_permissions_repository(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::ActionsSetGithubPermissionsRepositoryRequest,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .set_github_actions_permissions_repository(owner, repo, body),
+        )
+    }
+    /// The blocking version of [`crate::actions::Actions::get_allowed_actions_repository`].
+    pub fn get_allowed_actions_repository(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> ClientResult<crate::Response<crate::types::SelectedActions>> {
+        self.runtime
+            .block_on(self.inner.get_allowed_actions_repository(owner, repo))
+    }
+    /// The blocking version of [`crate::actions::Actions::set_allowed_actions_repository`].
+    pub fn set_allowed_actions_repository(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::SelectedActions,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.set_allowed_actions_repository(owner, repo, body))
+    }
+    /// The blocking version of [`crate::actions::Actions::list_self_hosted_runners_for_repo`].
+    pub fn list_self_hosted_runners_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<crate::types::ActionsListSelfHostedRunnersOrgResponse>> {
+        self.runtime.block_on(
+            self.inner
+                .list_self_hosted_runners_for_repo(owner, repo, per_page, page),
+        )
+    }
+    /// The blocking version of [`crate::actions::Actions::list_self_hosted_runners_for_repo_request`].
+    pub fn list_self_hosted_runners_for_repo_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+    ) -> ActionsListSelfHostedRunnersForRepoRequest<'a> {
+        ActionsListSelfHostedRunnersForRepoRequest {
+            inner: self
+                .inner
+                .list_self_hosted_runners_for_repo_request(owner, repo),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::actions::Actions::list_runner_applications_for_repo`].
+    pub fn list_runner_applications_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::RunnerApplication>>> {
+        self.runtime
+            .block_on(self.inner.list_runner_applications_for_repo(owner, repo))
+    }
+    /// The blocking version of [`crate::actions::Actions::list_all_runner_applications_for_repo`].
+    pub fn list_all_runner_applications_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::RunnerApplication>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_all_runner_applications_for_repo(owner, repo),
+        )
+    }
+    /// The blocking version of [`crate::actions::Actions::create_registration_token_for_repo`].
+    pub fn create_registration_token_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> ClientResult<crate::Response<crate::types::AuthenticationToken>> {
+        self.runtime
+            .block_on(self.inner.create_registration_token_for_repo(owner, repo))
+    }
+    /// The blocking version of [`crate::actions::Actions::create_remove_token_for_repo`].
+    pub fn create_remove_token_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> ClientResult<crate::Response<crate::types::AuthenticationToken>> {
+        self.runtime
+            .block_on(self.inner.create_remove_token_for_repo(owner, repo))
+    }
+    /// The blocking version of [`crate::actions::Actions::get_self_hosted_runner_for_repo`].
+    pub fn get_self_hosted_runner_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+        runner_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::Runner>> {
+        self.runtime.block_on(
+            self.inner
+                .get_self_hosted_runner_for_repo(owner, repo, runner_id),
+        )
+    }
+    /// The blocking version of [`crate::actions::Actions::delete_self_hosted_runner_from_repo`].
+    pub fn delete_self_hosted_runner_from_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+        runner_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .delete_self_hosted_runner_from_repo(owner, repo, runner_id),
+        )
+    }
+    /// The blocking version of [`crate::actions::Actions::list_workflow_runs_for_repo`].
+    pub fn list_workflow_runs_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+        actor: &str,
+        branch: &str,
+        event: &str,
+        status: crate::types::WorkflowRunStatus,
+        per_page: i64,
+        page: i64,
+        created: &str,
+    ) -> ClientResult<crate::Response<crate::types::ActionsListWorkflowRunsResponse>> {
+        self.runtime
+            .block_on(self.inner.list_workflow_runs_for_repo(
+                owner, repo, actor, branch, event, status, per_page, page, created,
+            ))
+    }
+    /// The blocking version of [`crate::actions::Actions::list_workflow_runs_for_repo_request`].
+    pub fn list_workflow_runs_for_repo_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+    ) -> ActionsListWorkflowRunsForRepoRequest<'a> {
+        ActionsListWorkflowRunsForRepoRequest {
+            inner: self.inner.list_workflow_runs_for_repo_request(owner, repo),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::actions::Actions::get_workflow_run`].
+    pub fn get_workflow_run(
+        &self,
+        owner: &str,
+        repo: &str,
+        run_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::WorkflowRun>> {
+        self.runtime
+            .block_on(self.inner.get_workflow_run(owner, repo, run_id))
+    }
+    /// The blocking version of [`crate::actions::Actions::delete_workflow_run`].
+    pub fn delete_workflow_run(
+        &self,
+        owner: &str,
+        repo: &str,
+        run_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete_workflow_run(owner, repo, run_id))
+    }
+    /// The blocking version of [`crate::actions::Actions::get_reviews_for_run`].
+    pub fn get_reviews_for_run(
+        &self,
+        owner: &str,
+        repo: &str,
+        run_id: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::EnvironmentApproval>>> {
+        self.runtime
+            .block_on(self.inner.get_reviews_for_run(owner, repo, run_id))
+    }
+    /// The blocking version of [`crate::actions::Actions::get_all_reviews_for_run`].
+    pub fn get_all_reviews_for_run(
+        &self,
+        owner: &str,
+        repo: &str,
+        run_id: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::EnvironmentApproval>>> {
+        self.runtime
+            .block_on(self.inner.get_all_reviews_for_run(owner, repo, run_id))
+    }
+    /// The blocking version of [`crate::actions::Actions::approve_workflow_run`].
+    pub fn approve_workflow_run(
+        &self,
+        owner: &str,
+        repo: &str,
+        run_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.approve_workflow_run(owner, repo, run_id))
+    }
+    /// The blocking version of [`crate::actions::Actions::list_workflow_run_artifacts`].
+    pub fn list_workflow_run_artifacts(
+        &self,
+        owner: &str,
+        repo: &str,
+        run_id: i64,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<crate::types::ActionsListArtifactsRepoResponse>> {
+        self.runtime.block_on(
+            self.inner
+                .list_workflow_run_artifacts(owner, repo, run_id, per_page, page),
+        )
+    }
+    /// The blocking version of [`crate::actions::Actions::list_workflow_run_artifacts_request`].
+    pub fn list_workflow_run_artifacts_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+        run_id: i64,
+    ) -> ActionsListWorkflowRunArtifactsRequest<'a> {
+        ActionsListWorkflowRunArtifactsRequest {
+            inner: self
+                .inner
+                .list_workflow_run_artifacts_request(owner, repo, run_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::actions::Actions::cancel_workflow_run`].
+    pub fn cancel_workflow_run(
+        &self,
+        owner: &str,
+        repo: &str,
+        run_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.cancel_workflow_run(owner, repo, run_id))
+    }
+    /// The blocking version of [`crate::actions::Actions::list_jobs_for_workflow_run`].
+    pub fn list_jobs_for_workflow_run(
+        &self,
+        owner: &str,
+        repo: &str,
+        run_id: i64,
+        filter: crate::types::ActionsListJobsWorkflowRunFilter,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<crate::types::ActionsListJobsWorkflowRunResponse>> {
+        self.runtime.block_on(
+            self.inner
+                .list_jobs_for_workflow_run(owner, repo, run_id, filter, per_page, page),
+        )
+    }
+    /// The blocking version of [`crate::actions::Actions::list_jobs_for_workflow_run_request`].
+    pub fn list_jobs_for_workflow_run_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+        run_id: i64,
+    ) -> ActionsListJobsForWorkflowRunRequest<'a> {
+        ActionsListJobsForWorkflowRunRequest {
+            inner: self
+                .inner
+                .list_jobs_for_workflow_run_request(owner, repo, run_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::actions::Actions::download_workflow_run_logs`].
+    pub fn download_workflow_run_logs(
+        &self,
+        owner: &str,
+        repo: &str,
+        run_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.download_workflow_run_logs(owner, repo, run_id))
+    }
+    /// The blocking version of [`crate::actions::Actions::delete_workflow_run_logs`].
+    pub fn delete_workflow_run_logs(
+        &self,
+        owner: &str,
+        repo: &str,
+        run_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete_workflow_run_logs(owner, repo, run_id))
+    }
+    /// The blocking version of [`crate::actions::Actions::get_pending_deployments_for_run`].
+    pub fn get_pending_deployments_for_run(
+        &self,
+        owner: &str,
+        repo: &str,
+        run_id: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::PendingDeployment>>> {
+        self.runtime.block_on(
+            self.inner
+                .get_pending_deployments_for_run(owner, repo, run_id),
+        )
+    }
+    /// The blocking version of [`crate::actions::Actions::get_all_pending_deployments_for_run`].
+    pub fn get_all_pending_deployments_for_run(
+        &self,
+        owner: &str,
+        repo: &str,
+        run_id: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::PendingDeployment>>> {
+        self.runtime.block_on(
+            self.inner
+                .get_all_pending_deployments_for_run(owner, repo, run_id),
+        )
+    }
+    /// The blocking version of [`crate::actions::Actions::review_pending_deployments_for_run`].
+    pub fn review_pending_deployments_for_run(
+        &self,
+        owner: &str,
+        repo: &str,
+        run_id: i64,
+        body: &crate::types::ActionsReviewPendingDeploymentsRunRequest,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Deployment>>> {
+        self.runtime.block_on(
+            self.inner
+                .review_pending_deployments_for_run(owner, repo, run_id, body),
+        )
+    }
+    /// The blocking version of [`crate::actions::Actions::re_run_workflow`].
+    pub fn re_run_workflow(
+        &self,
+        owner: &str,
+        repo: &str,
+        run_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.re_run_workflow(owner, repo, run_id))
+    }
+    /// The blocking version of [`crate::actions::Actions::re_run_workflow_failed_jobs`].
+    pub fn re_run_workflow_failed_jobs(
+        &self,
+        owner: &str,
+        repo: &str,
+        run_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.re_run_workflow_failed_jobs(owner, repo, run_id))
+    }
+    /// The blocking version of [`crate::actions::Actions::get_workflow_run_usage`].
+    pub fn get_workflow_run_usage(
+        &self,
+        owner: &str,
+        repo: &str,
+        run_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::WorkflowRunUsage>> {
+        self.runtime
+            .block_on(self.inner.get_workflow_run_usage(owner, repo, run_id))
+    }
+    /// The blocking version of [`crate::actions::Actions::list_repo_secrets`].
+    pub fn list_repo_secrets(
+        &self,
+        owner: &str,
+        repo: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<crate::types::ActionsListRepoSecretsResponse>> {
+        self.runtime
+            .block_on(self.inner.list_repo_secrets(owner, repo, per_page, page))
+    }
+    /// The blocking version of [`crate::actions::Actions::list_repo_secrets_request`].
+    pub fn list_repo_secrets_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+    ) -> ActionsListRepoSecretsRequest<'a> {
+        ActionsListRepoSecretsRequest {
+            inner: self.inner.list_repo_secrets_request(owner, repo),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::actions::Actions::get_repo_public_key`].
+    pub fn get_repo_public_key(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> ClientResult<crate::Response<crate::types::ActionsPublicKey>> {
+        self.runtime
+            .block_on(self.inner.get_repo_public_key(owner, repo))
+    }
+    /// The blocking version of [`crate::actions::Actions::get_repo_secret`].
+    pub fn get_repo_secret(
+        &self,
+        owner: &str,
+        repo: &str,
+        secret_name: &str,
+    ) -> ClientResult<crate::Response<crate::types::ActionsSecret>> {
+        self.runtime
+            .block_on(self.inner.get_repo_secret(owner, repo, secret_name))
+    }
+    /// The blocking version of [`crate::actions::Actions::create_or_update_repo_secret`].
+    pub fn create_or_update_repo_secret(
+        &self,
+        owner: &str,
+        repo: &str,
+        secret_name: &str,
+        body: &crate::types::ActionsCreateUpdateRepoSecretRequest,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(
+                self.inner
+                    .create_or_update_repo_secret(owner, repo, secret_name, body),
+            )
+    }
+    /// The blocking version of [`crate::actions::Actions::delete_repo_secret`].
+    pub fn delete_repo_secret(
+        &self,
+        owner: &str,
+        repo: &str,
+        secret_name: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete_repo_secret(owner, repo, secret_name))
+    }
+    /// The blocking version of [`crate::actions::Actions::list_repo_workflows`].
+    pub fn list_repo_workflows(
+        &self,
+        owner: &str,
+        repo: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<crate::types::ActionsListRepoWorkflowsResponse>> {
+        self.runtime
+            .block_on(self.inner.list_repo_workflows(owner, repo, per_page, page))
+    }
+    /// The blocking version of [`crate::actions::Actions::list_repo_workflows_request`].
+    pub fn list_repo_workflows_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+    ) -> ActionsListRepoWorkflowsRequest<'a> {
+        ActionsListRepoWorkflowsRequest {
+            inner: self.inner.list_repo_workflows_request(owner, repo),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::actions::Actions::get_workflow`].
+    pub fn get_workflow(
+        &self,
+        owner: &str,
+        repo: &str,
+        workflow_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Workflow>> {
+        self.runtime
+            .block_on(self.inner.get_workflow(owner, repo, workflow_id))
+    }
+    /// The blocking version of [`crate::actions::Actions::disable_workflow`].
+    pub fn disable_workflow(
+        &self,
+        owner: &str,
+        repo: &str,
+        workflow_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.disable_workflow(owner, repo, workflow_id))
+    }
+    /// The blocking version of [`crate::actions::Actions::create_workflow_dispatch`].
+    pub fn create_workflow_dispatch(
+        &self,
+        owner: &str,
+        repo: &str,
+        workflow_id: &str,
+        body: &crate::types::ActionsCreateWorkflowDispatchRequest,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .create_workflow_dispatch(owner, repo, workflow_id, body),
+        )
+    }
+    /// The blocking version of [`crate::actions::Actions::enable_workflow`].
+    pub fn enable_workflow(
+        &self,
+        owner: &str,
+        repo: &str,
+        workflow_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.enable_workflow(owner, repo, workflow_id))
+    }
+    /// The blocking version of [`crate::actions::Actions::list_workflow_runs`].
+    pub fn list_workflow_runs(
+        &self,
+        owner: &str,
+        repo: &str,
+        workflow_id: &str,
+        actor: &str,
+        branch: &str,
+        event: &str,
+        status: crate::types::WorkflowRunStatus,
+        per_page: i64,
+        page: i64,
+        created: &str,
+    ) -> ClientResult<crate::Response<crate::types::ActionsListWorkflowRunsResponse>> {
+        self.runtime.block_on(self.inner.list_workflow_runs(
+            owner,
+            repo,
+            workflow_id,
+            actor,
+            branch,
+            event,
+            status,
+            per_page,
+            page,
+            created,
+        ))
+    }
+    /// The blocking version of [`crate::actions::Actions::list_workflow_runs_request`].
+    pub fn list_workflow_runs_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+        workflow_id: &'a str,
+    ) -> ActionsListWorkflowRunsRequest<'a> {
+        ActionsListWorkflowRunsRequest {
+            inner: self
+                .inner
+                .list_workflow_runs_request(owner, repo, workflow_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::actions::Actions::get_workflow_usage`].
+    pub fn get_workflow_usage(
+        &self,
+        owner: &str,
+        repo: &str,
+        workflow_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::WorkflowUsage>> {
+        self.runtime
+            .block_on(self.inner.get_workflow_usage(owner, repo, workflow_id))
+    }
+    /// The blocking version of [`crate::actions::Actions::list_environment_secrets`].
+    pub fn list_environment_secrets(
+        &self,
+        repository_id: i64,
+        environment_name: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<crate::types::ActionsListRepoSecretsResponse>> {
+        self.runtime.block_on(self.inner.list_environment_secrets(
+            repository_id,
+            environment_name,
+            per_page,
+            page,
+        ))
+    }
+    /// The blocking version of [`crate::actions::Actions::list_environment_secrets_request`].
+    pub fn list_environment_secrets_request<'a>(
+        &'a self,
+        repository_id: i64,
+        environment_name: &'a str,
+    ) -> ActionsListEnvironmentSecretsRequest<'a> {
+        ActionsListEnvironmentSecretsRequest {
+            inner: self
+                .inner
+                .list_environment_secrets_request(repository_id, environment_name),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::actions::Actions::get_environment_public_key`].
+    pub fn get_environment_public_key(
+        &self,
+        repository_id: i64,
+        environment_name: &str,
+    ) -> ClientResult<crate::Response<crate::types::ActionsPublicKey>> {
+        self.runtime.block_on(
+            self.inner
+                .get_environment_public_key(repository_id, environment_name),
+        )
+    }
+    /// The blocking version of [`crate::actions::Actions::get_environment_secret`].
+    pub fn get_environment_secret(
+        &self,
+        repository_id: i64,
+        environment_name: &str,
+        secret_name: &str,
+    ) -> ClientResult<crate::Response<crate::types::ActionsSecret>> {
+        self.runtime.block_on(self.inner.get_environment_secret(
+            repository_id,
+            environment_name,
+            secret_name,
+        ))
+    }
+    /// The blocking version of [`crate::actions::Actions::create_or_update_environment_secret`].
+    pub fn create_or_update_environment_secret(
+        &self,
+        repository_id: i64,
+        environment_name: &str,
+        secret_name: &str,
+        body: &crate::types::ActionsCreateUpdateRepoSecretRequest,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.create_or_update_environment_secret(
+                repository_id,
+                environment_name,
+                secret_name,
+                body,
+            ))
+    }
+    /// The blocking version of [`crate::actions::Actions::delete_environment_secret`].
+    pub fn delete_environment_secret(
+        &self,
+        repository_id: i64,
+        environment_name: &str,
+        secret_name: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.delete_environment_secret(
+            repository_id,
+            environment_name,
+            secret_name,
+        ))
+    }
+}
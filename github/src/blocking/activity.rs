@@ -0,0 +1,1201 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Activity::list_public_events`], set with a builder.
+pub struct ActivityListPublicEventsRequest<'a> {
+    inner: crate::activity::ActivityListPublicEventsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ActivityListPublicEventsRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Event>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Activity::list_public_events_for_repo_network`], set with a builder.
+pub struct ActivityListPublicEventsForRepoNetworkRequest<'a> {
+    inner: crate::activity::ActivityListPublicEventsForRepoNetworkRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ActivityListPublicEventsForRepoNetworkRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Event>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Activity::list_notifications_for_authenticated_user`], set with a builder.
+pub struct ActivityListNotificationsForAuthenticatedUserRequest<'a> {
+    inner: crate::activity::ActivityListNotificationsForAuthenticatedUserRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ActivityListNotificationsForAuthenticatedUserRequest<'a> {
+    /// Sets the `all` query parameter.
+    pub fn all(mut self, all: bool) -> Self {
+        self.inner = self.inner.all(all);
+        self
+    }
+
+    /// Sets the `participating` query parameter.
+    pub fn participating(mut self, participating: bool) -> Self {
+        self.inner = self.inner.participating(participating);
+        self
+    }
+
+    /// Sets the `since` query parameter.
+    pub fn since(mut self, since: chrono::DateTime<chrono::Utc>) -> Self {
+        self.inner = self.inner.since(since);
+        self
+    }
+
+    /// Sets the `before` query parameter.
+    pub fn before(mut self, before: chrono::DateTime<chrono::Utc>) -> Self {
+        self.inner = self.inner.before(before);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Thread>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Activity::list_public_org_events`], set with a builder.
+pub struct ActivityListPublicOrgEventsRequest<'a> {
+    inner: crate::activity::ActivityListPublicOrgEventsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ActivityListPublicOrgEventsRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Event>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Activity::list_repo_events`], set with a builder.
+pub struct ActivityListRepoEventsRequest<'a> {
+    inner: crate::activity::ActivityListRepoEventsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ActivityListRepoEventsRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Event>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Activity::list_repo_notifications_for_authenticated_user`], set with a builder.
+pub struct ActivityListRepoNotificationsForAuthenticatedUserRequest<'a> {
+    inner: crate::activity::ActivityListRepoNotificationsForAuthenticatedUserRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ActivityListRepoNotificationsForAuthenticatedUserRequest<'a> {
+    /// Sets the `all` query parameter.
+    pub fn all(mut self, all: bool) -> Self {
+        self.inner = self.inner.all(all);
+        self
+    }
+
+    /// Sets the `participating` query parameter.
+    pub fn participating(mut self, participating: bool) -> Self {
+        self.inner = self.inner.participating(participating);
+        self
+    }
+
+    /// Sets the `since` query parameter.
+    pub fn since(mut self, since: chrono::DateTime<chrono::Utc>) -> Self {
+        self.inner = self.inner.since(since);
+        self
+    }
+
+    /// Sets the `before` query parameter.
+    pub fn before(mut self, before: chrono::DateTime<chrono::Utc>) -> Self {
+        self.inner = self.inner.before(before);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Thread>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Activity::list_stargazers_for_repo`], set with a builder.
+pub struct ActivityListStargazersForRepoRequest<'a> {
+    inner: crate::activity::ActivityListStargazersForRepoRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ActivityListStargazersForRepoRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::ActivityListStargazersRepoResponseAnyOf>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Activity::list_watchers_for_repo`], set with a builder.
+pub struct ActivityListWatchersForRepoRequest<'a> {
+    inner: crate::activity::ActivityListWatchersForRepoRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ActivityListWatchersForRepoRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::SimpleUser>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Activity::list_repos_starred_by_authenticated_user`], set with a builder.
+pub struct ActivityListReposStarredByAuthenticatedUserRequest<'a> {
+    inner: crate::activity::ActivityListReposStarredByAuthenticatedUserRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ActivityListReposStarredByAuthenticatedUserRequest<'a> {
+    /// Sets the `sort` query parameter.
+    pub fn sort(mut self, sort: crate::types::Sort) -> Self {
+        self.inner = self.inner.sort(sort);
+        self
+    }
+
+    /// Sets the `direction` query parameter.
+    pub fn direction(mut self, direction: crate::types::Order) -> Self {
+        self.inner = self.inner.direction(direction);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Repository>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Activity::list_watched_repos_for_authenticated_user`], set with a builder.
+pub struct ActivityListWatchedReposForAuthenticatedUserRequest<'a> {
+    inner: crate::activity::ActivityListWatchedReposForAuthenticatedUserRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ActivityListWatchedReposForAuthenticatedUserRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::MinimalRepository>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Activity::list_events_for_authenticated_user`], set with a builder.
+pub struct ActivityListEventsForAuthenticatedUserRequest<'a> {
+    inner: crate::activity::ActivityListEventsForAuthenticatedUserRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ActivityListEventsForAuthenticatedUserRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Event>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Activity::list_org_events_for_authenticated_user`], set with a builder.
+pub struct ActivityListOrgEventsForAuthenticatedUserRequest<'a> {
+    inner: crate::activity::ActivityListOrgEventsForAuthenticatedUserRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ActivityListOrgEventsForAuthenticatedUserRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Event>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Activity::list_public_events_for_user`], set with a builder.
+pub struct ActivityListPublicEventsForUserRequest<'a> {
+    inner: crate::activity::ActivityListPublicEventsForUserRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ActivityListPublicEventsForUserRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Event>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Activity::list_received_events_for_user`], set with a builder.
+pub struct ActivityListReceivedEventsForUserRequest<'a> {
+    inner: crate::activity::ActivityListReceivedEventsForUserRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ActivityListReceivedEventsForUserRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Event>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Activity::list_received_public_events_for_user`], set with a builder.
+pub struct ActivityListReceivedPublicEventsForUserRequest<'a> {
+    inner: crate::activity::ActivityListReceivedPublicEventsForUserRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ActivityListReceivedPublicEventsForUserRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Event>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Activity::list_repos_starred_by_user`], set with a builder.
+pub struct ActivityListReposStarredByUserRequest<'a> {
+    inner: crate::activity::ActivityListReposStarredByUserRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ActivityListReposStarredByUserRequest<'a> {
+    /// Sets the `sort` query parameter.
+    pub fn sort(mut self, sort: crate::types::Sort) -> Self {
+        self.inner = self.inner.sort(sort);
+        self
+    }
+
+    /// Sets the `direction` query parameter.
+    pub fn direction(mut self, direction: crate::types::Order) -> Self {
+        self.inner = self.inner.direction(direction);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::ActivityListReposStarredByUserResponseAnyOf>>
+    {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Activity::list_repos_watched_by_user`], set with a builder.
+pub struct ActivityListReposWatchedByUserRequest<'a> {
+    inner: crate::activity::ActivityListReposWatchedByUserRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ActivityListReposWatchedByUserRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::MinimalRepository>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::activity::Activity`].
+pub struct Activity {
+    inner: crate::activity::Activity,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Activity {
+    pub(crate) fn new(
+        inner: crate::activity::Activity,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Activity { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::activity::Activity::list_public_events`].
+    pub fn list_public_events(
+        &self,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Event>>> {
+        self.runtime
+            .block_on(self.inner.list_public_events(per_page, page))
+    }
+    /// The blocking version of [`crate::activity::Activity::list_public_events_request`].
+    pub fn list_public_events_request(&self) -> ActivityListPublicEventsRequest<'_> {
+        ActivityListPublicEventsRequest {
+            inner: self.inner.list_public_events_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::activity::Activity::list_all_public_events`].
+    pub fn list_all_public_events(
+        &self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Event>>> {
+        self.runtime.block_on(self.inner.list_all_public_events())
+    }
+    /// The blocking version of [`crate::activity::Activity::get_feeds`].
+    pub fn get_feeds(&self) -> ClientResult<crate::Response<crate::types::Feed>> {
+        self.runtime.block_on(self.inner.get_feeds())
+    }
+    /// The blocking version of [`crate::activity::Activity::list_public_events_for_repo_network`].
+    pub fn list_public_events_for_repo_network(
+        &self,
+        owner: &str,
+        repo: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Event>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_public_events_for_repo_network(owner, repo, per_page, page),
+        )
+    }
+    /// The blocking version of [`crate::activity::Activity::list_public_events_for_repo_network_request`].
+    pub fn list_public_events_for_repo_network_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+    ) -> ActivityListPublicEventsForRepoNetworkRequest<'a> {
+        ActivityListPublicEventsForRepoNetworkRequest {
+            inner: self
+                .inner
+                .list_public_events_for_repo_network_request(owner, repo),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::activity::Activity::list_all_public_events_for_repo_network`].
+    pub fn list_all_public_events_for_repo_network(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Event>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_all_public_events_for_repo_network(owner, repo),
+        )
+    }
+    /// The blocking version of [`crate::activity::Activity::list_notifications_for_authenticated_user`].
+    pub fn list_notifications_for_authenticated_user(
+        &self,
+        all: bool,
+        participating: bool,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+        before: Option<chrono::DateTime<chrono::Utc>>,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Thread>>> {
+        self.runtime
+            .block_on(self.inner.list_notifications_for_authenticated_user(
+                all,
+                participating,
+                since,
+                before,
+                per_page,
+                page,
+            ))
+    }
+    /// The blocking version of [`crate::activity::Activity::list_notifications_for_authenticated_user_request`].
+    pub fn list_notifications_for_authenticated_user_request(
+        &self,
+    ) -> ActivityListNotificationsForAuthenticatedUserRequest<'_> {
+        ActivityListNotificationsForAuthenticatedUserRequest {
+            inner: self
+                .inner
+                .list_notifications_for_authenticated_user_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::activity::Activity::list_all_notifications_for_authenticated_user`].
+    pub fn list_all_notifications_for_authenticated_user(
+        &self,
+        all: bool,
+        participating: bool,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+        before: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Thread>>> {
+        self.runtime
+            .block_on(self.inner.list_all_notifications_for_authenticated_user(
+                all,
+                participating,
+                since,
+                before,
+            ))
+    }
+    /// The blocking version of [`crate::activity::Activity::mark_notifications_as_read`].
+    pub fn mark_notifications_as_read(
+        &self,
+        body: &crate::types::ActivityMarkNotificationsAsReadRequest,
+    ) -> ClientResult<crate::Response<crate::types::Error>> {
+        self.runtime
+            .block_on(self.inner.mark_notifications_as_read(body))
+    }
+    /// The blocking version of [`crate::activity::Activity::get_thread`].
+    pub fn get_thread(
+        &self,
+        thread_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::Thread>> {
+        self.runtime.block_on(self.inner.get_thread(thread_id))
+    }
+    /// The blocking version of [`crate::activity::Activity::mark_thread_as_read`].
+    pub fn mark_thread_as_read(&self, thread_id: i64) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.mark_thread_as_read(thread_id))
+    }
+    /// The blocking version of [`crate::activity::Activity::get_thread_subscription_for_authenticated_user`].
+    pub fn get_thread_subscription_for_authenticated_user(
+        &self,
+        thread_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::ThreadSubscription>> {
+        self.runtime.block_on(
+            self.inner
+                .get_thread_subscription_for_authenticated_user(thread_id),
+        )
+    }
+    /// The blocking version of [`crate::activity::Activity::set_thread_subscription`].
+    pub fn set_thread_subscription(
+        &self,
+        thread_id: i64,
+        body: &crate::types::ActivitySetThreadSubscriptionRequest,
+    ) -> ClientResult<crate::Response<crate::types::ThreadSubscription>> {
+        self.runtime
+            .block_on(self.inner.set_thread_subscription(thread_id, body))
+    }
+    /// The blocking version of [`crate::activity::Activity::delete_thread_subscription`].
+    pub fn delete_thread_subscription(&self, thread_id: i64) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete_thread_subscription(thread_id))
+    }
+    /// The blocking version of [`crate::activity::Activity::list_public_org_events`].
+    pub fn list_public_org_events(
+        &self,
+        org: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Event>>> {
+        self.runtime
+            .block_on(self.inner.list_public_org_events(org, per_page, page))
+    }
+    /// The blocking version of [`crate::activity::Activity::list_public_org_events_request`].
+    pub fn list_public_org_events_request<'a>(
+        &'a self,
+        org: &'a str,
+    ) -> ActivityListPublicOrgEventsRequest<'a> {
+        ActivityListPublicOrgEventsRequest {
+            inner: self.inner.list_public_org_events_request(org),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::activity::Activity::list_all_public_org_events`].
+    pub fn list_all_public_org_events(
+        &self,
+        org: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Event>>> {
+        self.runtime
+            .block_on(self.inner.list_all_public_org_events(org))
+    }
+    /// The blocking version of [`crate::activity::Activity::list_repo_events`].
+    pub fn list_repo_events(
+        &self,
+        owner: &str,
+        repo: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Event>>> {
+        self.runtime
+            .block_on(self.inner.list_repo_events(owner, repo, per_page, page))
+    }
+    /// The blocking version of [`crate::activity::Activity::list_repo_events_request`].
+    pub fn list_repo_events_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+    ) -> ActivityListRepoEventsRequest<'a> {
+        ActivityListRepoEventsRequest {
+            inner: self.inner.list_repo_events_request(owner, repo),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::activity::Activity::list_all_repo_events`].
+    pub fn list_all_repo_events(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Event>>> {
+        self.runtime
+            .block_on(self.inner.list_all_repo_events(owner, repo))
+    }
+    /// The blocking version of [`crate::activity::Activity::list_repo_notifications_for_authenticated_user`].
+    pub fn list_repo_notifications_for_authenticated_user(
+        &self,
+        owner: &str,
+        repo: &str,
+        all: bool,
+        participating: bool,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+        before: Option<chrono::DateTime<chrono::Utc>>,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Thread>>> {
+        self.runtime
+            .block_on(self.inner.list_repo_notifications_for_authenticated_user(
+                owner,
+                repo,
+                all,
+                participating,
+                since,
+                before,
+                per_page,
+                page,
+            ))
+    }
+    /// The blocking version of [`crate::activity::Activity::list_repo_notifications_for_authenticated_user_request`].
+    pub fn list_repo_notifications_for_authenticated_user_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+    ) -> ActivityListRepoNotificationsForAuthenticatedUserRequest<'a> {
+        ActivityListRepoNotificationsForAuthenticatedUserRequest {
+            inner: self
+                .inner
+                .list_repo_notifications_for_authenticated_user_request(owner, repo),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::activity::Activity::list_all_repo_notifications_for_authenticated_user`].
+    pub fn list_all_repo_notifications_for_authenticated_user(
+        &self,
+        owner: &str,
+        repo: &str,
+        all: bool,
+        participating: bool,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+        before: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Thread>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_all_repo_notifications_for_authenticated_user(
+                    owner,
+                    repo,
+                    all,
+                    participating,
+                    since,
+                    before,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::activity::Activity::mark_repo_notifications_as_read`].
+    pub fn mark_repo_notifications_as_read(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::ActivityMarkRepoNotificationsAsReadRequest,
+    ) -> ClientResult<crate::Response<crate::types::PullsUpdateBranchResponse>> {
+        self.runtime.block_on(
+            self.inner
+                .mark_repo_notifications_as_read(owner, repo, body),
+        )
+    }
+    /// The blocking version of [`crate::activity::Activity::list_stargazers_for_repo`].
+    pub fn list_stargazers_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<crate::types::ActivityListStargazersRepoResponseAnyOf>> {
+        self.runtime.block_on(
+            self.inner
+                .list_stargazers_for_repo(owner, repo, per_page, page),
+        )
+    }
+    /// The blocking version of [`crate::activity::Activity::list_stargazers_for_repo_request`].
+    pub fn list_stargazers_for_repo_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+    ) -> ActivityListStargazersForRepoRequest<'a> {
+        ActivityListStargazersForRepoRequest {
+            inner: self.inner.list_stargazers_for_repo_request(owner, repo),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::activity::Activity::list_watchers_for_repo`].
+    pub fn list_watchers_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::SimpleUser>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_watchers_for_repo(owner, repo, per_page, page),
+        )
+    }
+    /// The blocking version of [`crate::activity::Activity::list_watchers_for_repo_request`].
+    pub fn list_watchers_for_repo_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+    ) -> ActivityListWatchersForRepoRequest<'a> {
+        ActivityListWatchersForRepoRequest {
+            inner: self.inner.list_watchers_for_repo_request(owner, repo),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::activity::Activity::list_all_watchers_for_repo`].
+    pub fn list_all_watchers_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::SimpleUser>>> {
+        self.runtime
+            .block_on(self.inner.list_all_watchers_for_repo(owner, repo))
+    }
+    /// The blocking version of [`crate::activity::Activity::get_repo_subscription`].
+    pub fn get_repo_subscription(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> ClientResult<crate::Response<crate::types::RepositorySubscription>> {
+        self.runtime
+            .block_on(self.inner.get_repo_subscription(owner, repo))
+    }
+    /// The blocking version of [`crate::activity::Activity::set_repo_subscription`].
+    pub fn set_repo_subscription(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::ActivitySetRepoSubscriptionRequest,
+    ) -> ClientResult<crate::Response<crate::types::RepositorySubscription>> {
+        self.runtime
+            .block_on(self.inner.set_repo_subscription(owner, repo, body))
+    }
+    /// The blocking version of [`crate::activity::Activity::delete_repo_subscription`].
+    pub fn delete_repo_subscription(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete_repo_subscription(owner, repo))
+    }
+    /// The blocking version of [`crate::activity::Activity::list_repos_starred_by_authenticated_user`].
+    pub fn list_repos_starred_by_authenticated_user(
+        &self,
+        sort: crate::types::Sort,
+        direction: crate::types::Order,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Repository>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_repos_starred_by_authenticated_user(sort, direction, per_page, page),
+        )
+    }
+    /// The blocking version of [`crate::activity::Activity::list_repos_starred_by_authenticated_user_request`].
+    pub fn list_repos_starred_by_authenticated_user_request(
+        &self,
+    ) -> ActivityListReposStarredByAuthenticatedUserRequest<'_> {
+        ActivityListReposStarredByAuthenticatedUserRequest {
+            inner: self
+                .inner
+                .list_repos_starred_by_authenticated_user_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::activity::Activity::list_all_repos_starred_by_authenticated_user`].
+    pub fn list_all_repos_starred_by_authenticated_user(
+        &self,
+        sort: crate::types::Sort,
+        direction: crate::types::Order,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Repository>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_all_repos_starred_by_authenticated_user(sort, direction),
+        )
+    }
+    /// The blocking version of [`crate::activity::Activity::check_repo_is_starred_by_authenticated_user`].
+    pub fn check_repo_is_starred_by_authenticated_user(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .check_repo_is_starred_by_authenticated_user(owner, repo),
+        )
+    }
+    /// The blocking version of [`crate::activity::Activity::star_repo_for_authenticated_user`].
+    pub fn star_repo_for_authenticated_user(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.star_repo_for_authenticated_user(owner, repo))
+    }
+    /// The blocking version of [`crate::activity::Activity::unstar_repo_for_authenticated_user`].
+    pub fn unstar_repo_for_authenticated_user(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.unstar_repo_for_authenticated_user(owner, repo))
+    }
+    /// The blocking version of [`crate::activity::Activity::list_watched_repos_for_authenticated_user`].
+    pub fn list_watched_repos_for_authenticated_user(
+        &self,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::MinimalRepository>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_watched_repos_for_authenticated_user(per_page, page),
+        )
+    }
+    /// The blocking version of [`crate::activity::Activity::list_watched_repos_for_authenticated_user_request`].
+    pub fn list_watched_repos_for_authenticated_user_request(
+        &self,
+    ) -> ActivityListWatchedReposForAuthenticatedUserRequest<'_> {
+        ActivityListWatchedReposForAuthenticatedUserRequest {
+            inner: self
+                .inner
+                .list_watched_repos_for_authenticated_user_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::activity::Activity::list_all_watched_repos_for_authenticated_user`].
+    pub fn list_all_watched_repos_for_authenticated_user(
+        &self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::MinimalRepository>>> {
+        self.runtime
+            .block_on(self.inner.list_all_watched_repos_for_authenticated_user())
+    }
+    /// The blocking version of [`crate::activity::Activity::list_events_for_authenticated_user`].
+    pub fn list_events_for_authenticated_user(
+        &self,
+        username: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Event>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_events_for_authenticated_user(username, per_page, page),
+        )
+    }
+    /// The blocking version of [`crate::activity::Activity::list_events_for_authenticated_user_request`].
+    pub fn list_events_for_authenticated_user_request<'a>(
+        &'a self,
+        username: &'a str,
+    ) -> ActivityListEventsForAuthenticatedUserRequest<'a> {
+        ActivityListEventsForAuthenticatedUserRequest {
+            inner: self
+                .inner
+                .list_events_for_authenticated_user_request(username),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::activity::Activity::list_all_events_for_authenticated_user`].
+    pub fn list_all_events_for_authenticated_user(
+        &self,
+        username: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Event>>> {
+        self.runtime
+            .block_on(self.inner.list_all_events_for_authenticated_user(username))
+    }
+    /// The blocking version of [`crate::activity::Activity::list_org_events_for_authenticated_user`].
+    pub fn list_org_events_for_authenticated_user(
+        &self,
+        username: &str,
+        org: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Event>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_org_events_for_authenticated_user(username, org, per_page, page),
+        )
+    }
+    /// The blocking version of [`crate::activity::Activity::list_org_events_for_authenticated_user_request`].
+    pub fn list_org_events_for_authenticated_user_request<'a>(
+        &'a self,
+        username: &'a str,
+        org: &'a str,
+    ) -> ActivityListOrgEventsForAuthenticatedUserRequest<'a> {
+        ActivityListOrgEventsForAuthenticatedUserRequest {
+            inner: self
+                .inner
+                .list_org_events_for_authenticated_user_request(username, org),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::activity::Activity::list_all_org_events_for_authenticated_user`].
+    pub fn list_all_org_events_for_authenticated_user(
+        &self,
+        username: &str,
+        org: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Event>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_all_org_events_for_authenticated_user(username, org),
+        )
+    }
+    /// The blocking version of [`crate::activity::Activity::list_public_events_for_user`].
+    pub fn list_public_events_for_user(
+        &self,
+        username: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Event>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_public_events_for_user(username, per_page, page),
+        )
+    }
+    /// The blocking version of [`crate::activity::Activity::list_public_events_for_user_request`].
+    pub fn list_public_events_for_user_request<'a>(
+        &'a self,
+        username: &'a str,
+    ) -> ActivityListPublicEventsForUserRequest<'a> {
+        ActivityListPublicEventsForUserRequest {
+            inner: self.inner.list_public_events_for_user_request(username),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::activity::Activity::list_all_public_events_for_user`].
+    pub fn list_all_public_events_for_user(
+        &self,
+        username: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Event>>> {
+        self.runtime
+            .block_on(self.inner.list_all_public_events_for_user(username))
+    }
+    /// The blocking version of [`crate::activity::Activity::list_received_events_for_user`].
+    pub fn list_received_events_for_user(
+        &self,
+        username: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Event>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_received_events_for_user(username, per_page, page),
+        )
+    }
+    /// The blocking version of [`crate::activity::Activity::list_received_events_for_user_request`].
+    pub fn list_received_events_for_user_request<'a>(
+        &'a self,
+        username: &'a str,
+    ) -> ActivityListReceivedEventsForUserRequest<'a> {
+        ActivityListReceivedEventsForUserRequest {
+            inner: self.inner.list_received_events_for_user_request(username),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::activity::Activity::list_all_received_events_for_user`].
+    pub fn list_all_received_events_for_user(
+        &self,
+        username: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Event>>> {
+        self.runtime
+            .block_on(self.inner.list_all_received_events_for_user(username))
+    }
+    /// The blocking version of [`crate::activity::Activity::list_received_public_events_for_user`].
+    pub fn list_received_public_events_for_user(
+        &self,
+        username: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Event>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_received_public_events_for_user(username, per_page, page),
+        )
+    }
+    /// The blocking version of [`crate::activity::Activity::list_received_public_events_for_user_request`].
+    pub fn list_received_public_events_for_user_request<'a>(
+        &'a self,
+        username: &'a str,
+    ) -> ActivityListReceivedPublicEventsForUserRequest<'a> {
+        ActivityListReceivedPublicEventsForUserRequest {
+            inner: self
+                .inner
+                .list_received_public_events_for_user_request(username),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::activity::Activity::list_all_received_public_events_for_user`].
+    pub fn list_all_received_public_events_for_user(
+        &self,
+        username: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Event>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_all_received_public_events_for_user(username),
+        )
+    }
+    /// The blocking version of [`crate::activity::Activity::list_repos_starred_by_user`].
+    pub fn list_repos_starred_by_user(
+        &self,
+        username: &str,
+        sort: crate::types::Sort,
+        direction: crate::types::Order,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<crate::types::ActivityListReposStarredByUserResponseAnyOf>>
+    {
+        self.runtime.block_on(
+            self.inner
+                .list_repos_starred_by_user(username, sort, direction, per_page, page),
+        )
+    }
+    /// The blocking version of [`crate::activity::Activity::list_repos_starred_by_user_request`].
+    pub fn list_repos_starred_by_user_request<'a>(
+        &'a self,
+        username: &'a str,
+    ) -> ActivityListReposStarredByUserRequest<'a> {
+        ActivityListReposStarredByUserRequest {
+            inner: self.inner.list_repos_starred_by_user_request(username),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::activity::Activity::list_repos_watched_by_user`].
+    pub fn list_repos_watched_by_user(
+        &self,
+        username: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::MinimalRepository>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_repos_watched_by_user(username, per_page, page),
+        )
+    }
+    /// The blocking version of [`crate::activity::Activity::list_repos_watched_by_user_request`].
+    pub fn list_repos_watched_by_user_request<'a>(
+        &'a self,
+        username: &'a str,
+    ) -> ActivityListReposWatchedByUserRequest<'a> {
+        ActivityListReposWatchedByUserRequest {
+            inner: self.inner.list_repos_watched_by_user_request(username),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::activity::Activity::list_all_repos_watched_by_user`].
+    pub fn list_all_repos_watched_by_user(
+        &self,
+        username: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::MinimalRepository>>> {
+        self.runtime
+            .block_on(self.inner.list_all_repos_watched_by_user(username))
+    }
+}
@@ -0,0 +1,884 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Apps::list_webhook_deliveries`], set with a builder.
+pub struct AppsListWebhookDeliveriesRequest<'a> {
+    inner: crate::apps::AppsListWebhookDeliveriesRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> AppsListWebhookDeliveriesRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `cursor` query parameter.
+    pub fn cursor(mut self, cursor: &'a str) -> Self {
+        self.inner = self.inner.cursor(cursor);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::HookDeliveryItem>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Apps::list_installations`], set with a builder.
+pub struct AppsListInstallationsRequest<'a> {
+    inner: crate::apps::AppsListInstallationsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> AppsListInstallationsRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sets the `since` query parameter.
+    pub fn since(mut self, since: chrono::DateTime<chrono::Utc>) -> Self {
+        self.inner = self.inner.since(since);
+        self
+    }
+
+    /// Sets the `outdated` query parameter.
+    pub fn outdated(mut self, outdated: &'a str) -> Self {
+        self.inner = self.inner.outdated(outdated);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Installation>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Apps::list_repos_accessible_to_installation`], set with a builder.
+pub struct AppsListReposAccessibleToInstallationRequest<'a> {
+    inner: crate::apps::AppsListReposAccessibleToInstallationRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> AppsListReposAccessibleToInstallationRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::AppsListInstallationReposResponse>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Apps::list_plans`], set with a builder.
+pub struct AppsListPlansRequest<'a> {
+    inner: crate::apps::AppsListPlansRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> AppsListPlansRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::MarketplaceListingPlan>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Apps::list_accounts_for_plan`], set with a builder.
+pub struct AppsListAccountsForPlanRequest<'a> {
+    inner: crate::apps::AppsListAccountsForPlanRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> AppsListAccountsForPlanRequest<'a> {
+    /// Sets the `sort` query parameter.
+    pub fn sort(mut self, sort: crate::types::Sort) -> Self {
+        self.inner = self.inner.sort(sort);
+        self
+    }
+
+    /// Sets the `direction` query parameter.
+    pub fn direction(mut self, direction: crate::types::Order) -> Self {
+        self.inner = self.inner.direction(direction);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::MarketplacePurchaseData>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Apps::list_plans_stubbed`], set with a builder.
+pub struct AppsListPlansStubbedRequest<'a> {
+    inner: crate::apps::AppsListPlansStubbedRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> AppsListPlansStubbedRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::MarketplaceListingPlan>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Apps::list_accounts_for_plan_stubbed`], set with a builder.
+pub struct AppsListAccountsForPlanStubbedRequest<'a> {
+    inner: crate::apps::AppsListAccountsForPlanStubbedRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> AppsListAccountsForPlanStubbedRequest<'a> {
+    /// Sets the `sort` query parameter.
+    pub fn sort(mut self, sort: crate::types::Sort) -> Self {
+        self.inner = self.inner.sort(sort);
+        self
+    }
+
+    /// Sets the `direction` query parameter.
+    pub fn direction(mut self, direction: crate::types::Order) -> Self {
+        self.inner = self.inner.direction(direction);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::MarketplacePurchaseData>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Apps::list_installations_for_authenticated_user`], set with a builder.
+pub struct AppsListInstallationsForAuthenticatedUserRequest<'a> {
+    inner: crate::apps::AppsListInstallationsForAuthenticatedUserRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> AppsListInstallationsForAuthenticatedUserRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::AppsListInstallationsResponse>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Apps::list_installation_repos_for_authenticated_user`], set with a builder.
+pub struct AppsListInstallationReposForAuthenticatedUserRequest<'a> {
+    inner: crate::apps::AppsListInstallationReposForAuthenticatedUserRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> AppsListInstallationReposForAuthenticatedUserRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::AppsListInstallationReposResponse>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Apps::list_subscriptions_for_authenticated_user`], set with a builder.
+pub struct AppsListSubscriptionsForAuthenticatedUserRequest<'a> {
+    inner: crate::apps::AppsListSubscriptionsForAuthenticatedUserRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> AppsListSubscriptionsForAuthenticatedUserRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::UserMarketplacePurchase>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Apps::list_subscriptions_for_authenticated_user_stubbed`], set with a builder.
+pub struct AppsListSubscriptionsForAuthenticatedUserStubbedRequest<'a> {
+    inner: crate::apps::AppsListSubscriptionsForAuthenticatedUserStubbedRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> AppsListSubscriptionsForAuthenticatedUserStubbedRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::UserMarketplacePurchase>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::apps::Apps`].
+pub struct Apps {
+    inner: crate::apps::Apps,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Apps {
+    pub(crate) fn new(inner: crate::apps::Apps, runtime: Arc<tokio::runtime::Runtime>) -> Self {
+        Apps { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::apps::Apps::get_authenticated`].
+    pub fn get_authenticated(&self) -> ClientResult<crate::Response<crate::types::GitHubApp>> {
+        self.runtime.block_on(self.inner.get_authenticated())
+    }
+    /// The blocking version of [`crate::apps::Apps::create_from_manifest`].
+    pub fn create_from_manifest(
+        &self,
+        code: &str,
+    ) -> ClientResult<crate::Response<crate::types::AppsCreateFromManifestResponseAllOf>> {
+        self.runtime.block_on(self.inner.create_from_manifest(code))
+    }
+    /// The blocking version of [`crate::apps::Apps::get_webhook_config_for_app`].
+    pub fn get_webhook_config_for_app(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::WebhookConfig>> {
+        self.runtime
+            .block_on(self.inner.get_webhook_config_for_app())
+    }
+    /// The blocking version of [`crate::apps::Apps::update_webhook_config_for_app`].
+    pub fn update_webhook_config_for_app(
+        &self,
+        body: &crate::types::AppsUpdateWebhookConfigAppRequest,
+    ) -> ClientResult<crate::Response<crate::types::WebhookConfig>> {
+        self.runtime
+            .block_on(self.inner.update_webhook_config_for_app(body))
+    }
+    /// The blocking version of [`crate::apps::Apps::list_webhook_deliveries`].
+    pub fn list_webhook_deliveries(
+        &self,
+        per_page: i64,
+        cursor: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::HookDeliveryItem>>> {
+        self.runtime
+            .block_on(self.inner.list_webhook_deliveries(per_page, cursor))
+    }
+    /// The blocking version of [`crate::apps::Apps::list_webhook_deliveries_request`].
+    pub fn list_webhook_deliveries_request(&self) -> AppsListWebhookDeliveriesRequest<'_> {
+        AppsListWebhookDeliveriesRequest {
+            inner: self.inner.list_webhook_deliveries_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::apps::Apps::list_all_webhook_deliveries`].
+    pub fn list_all_webhook_deliveries(
+        &self,
+        cursor: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::HookDeliveryItem>>> {
+        self.runtime
+            .block_on(self.inner.list_all_webhook_deliveries(cursor))
+    }
+    /// The blocking version of [`crate::apps::Apps::get_webhook_delivery`].
+    pub fn get_webhook_delivery(
+        &self,
+        delivery_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::HookDelivery>> {
+        self.runtime
+            .block_on(self.inner.get_webhook_delivery(delivery_id))
+    }
+    /// The blocking version of [`crate::apps::Apps::redeliver_webhook_delivery`].
+    pub fn redeliver_webhook_delivery(
+        &self,
+        delivery_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.redeliver_webhook_delivery(delivery_id))
+    }
+    /// The blocking version of [`crate::apps::Apps::list_installations`].
+    pub fn list_installations(
+        &self,
+        per_page: i64,
+        page: i64,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+        outdated: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Installation>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_installations(per_page, page, since, outdated),
+        )
+    }
+    /// The blocking version of [`crate::apps::Apps::list_installations_request`].
+    pub fn list_installations_request(&self) -> AppsListInstallationsRequest<'_> {
+        AppsListInstallationsRequest {
+            inner: self.inner.list_installations_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::apps::Apps::list_all_installations`].
+    pub fn list_all_installations(
+        &self,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+        outdated: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Installation>>> {
+        self.runtime
+            .block_on(self.inner.list_all_installations(since, outdated))
+    }
+    /// The blocking version of [`crate::apps::Apps::get_installation`].
+    pub fn get_installation(
+        &self,
+        installation_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::Installation>> {
+        self.runtime
+            .block_on(self.inner.get_installation(installation_id))
+    }
+    /// The blocking version of [`crate::apps::Apps::delete_installation`].
+    pub fn delete_installation(&self, installation_id: i64) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete_installation(installation_id))
+    }
+    /// The blocking version of [`crate::apps::Apps::create_installation_access_token`].
+    pub fn create_installation_access_token(
+        &self,
+        installation_id: i64,
+        body: &crate::types::AppsCreateInstallationAccessTokenRequest,
+    ) -> ClientResult<crate::Response<crate::types::InstallationToken>> {
+        self.runtime.block_on(
+            self.inner
+                .create_installation_access_token(installation_id, body),
+        )
+    }
+    /// The blocking version of [`crate::apps::Apps::suspend_installation`].
+    pub fn suspend_installation(&self, installation_id: i64) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.suspend_installation(installation_id))
+    }
+    /// The blocking version of [`crate::apps::Apps::unsuspend_installation`].
+    pub fn unsuspend_installation(
+        &self,
+        installation_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.unsuspend_installation(installation_id))
+    }
+    /// The blocking version of [`crate::apps::Apps::delete_authorization`].
+    pub fn delete_authorization(
+        &self,
+        client_id: &str,
+        body: &crate::types::AppsCheckTokenRequest,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete_authorization(client_id, body))
+    }
+    /// The blocking version of [`crate::apps::Apps::revoke_grant_for_application`].
+    pub fn revoke_grant_for_application(
+        &self,
+        client_id: &str,
+        access_token: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .revoke_grant_for_application(client_id, access_token),
+        )
+    }
+    /// The blocking version of [`crate::apps::Apps::check_token`].
+    pub fn check_token(
+        &self,
+        client_id: &str,
+        body: &crate::types::AppsCheckTokenRequest,
+    ) -> ClientResult<crate::Response<crate::types::Authorization>> {
+        self.runtime
+            .block_on(self.inner.check_token(client_id, body))
+    }
+    /// The blocking version of [`crate::apps::Apps::delete_token`].
+    pub fn delete_token(
+        &self,
+        client_id: &str,
+        body: &crate::types::AppsCheckTokenRequest,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete_token(client_id, body))
+    }
+    /// The blocking version of [`crate::apps::Apps::reset_token`].
+    pub fn reset_token(
+        &self,
+        client_id: &str,
+        body: &crate::types::AppsCheckTokenRequest,
+    ) -> ClientResult<crate::Response<crate::types::Authorization>> {
+        self.runtime
+            .block_on(self.inner.reset_token(client_id, body))
+    }
+    /// The blocking version of [`crate::apps::Apps::scope_token`].
+    pub fn scope_token(
+        &self,
+        client_id: &str,
+        body: &crate::types::AppsScopeTokenRequest,
+    ) -> ClientResult<crate::Response<crate::types::Authorization>> {
+        self.runtime
+            .block_on(self.inner.scope_token(client_id, body))
+    }
+    /// The blocking version of [`crate::apps::Apps::check_authorization`].
+    pub fn check_authorization(
+        &self,
+        client_id: &str,
+        access_token: &str,
+    ) -> ClientResult<crate::Response<crate::types::Authorization>> {
+        self.runtime
+            .block_on(self.inner.check_authorization(client_id, access_token))
+    }
+    /// The blocking version of [`crate::apps::Apps::reset_authorization`].
+    pub fn reset_authorization(
+        &self,
+        client_id: &str,
+        access_token: &str,
+    ) -> ClientResult<crate::Response<crate::types::Authorization>> {
+        self.runtime
+            .block_on(self.inner.reset_authorization(client_id, access_token))
+    }
+    /// The blocking version of [`crate::apps::Apps::revoke_authorization_for_application`].
+    pub fn revoke_authorization_for_application(
+        &self,
+        client_id: &str,
+        access_token: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .revoke_authorization_for_application(client_id, access_token),
+        )
+    }
+    /// The blocking version of [`crate::apps::Apps::get_by_slug`].
+    pub fn get_by_slug(
+        &self,
+        app_slug: &str,
+    ) -> ClientResult<crate::Response<crate::types::GitHubApp>> {
+        self.runtime.block_on(self.inner.get_by_slug(app_slug))
+    }
+    /// The blocking version of [`crate::apps::Apps::list_repos_accessible_to_installation`].
+    pub fn list_repos_accessible_to_installation(
+        &self,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<crate::types::AppsListInstallationReposResponse>> {
+        self.runtime.block_on(
+            self.inner
+                .list_repos_accessible_to_installation(per_page, page),
+        )
+    }
+    /// The blocking version of [`crate::apps::Apps::list_repos_accessible_to_installation_request`].
+    pub fn list_repos_accessible_to_installation_request(
+        &self,
+    ) -> AppsListReposAccessibleToInstallationRequest<'_> {
+        AppsListReposAccessibleToInstallationRequest {
+            inner: self.inner.list_repos_accessible_to_installation_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::apps::Apps::revoke_installation_access_token`].
+    pub fn revoke_installation_access_token(&self) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.revoke_installation_access_token())
+    }
+    /// The blocking version of [`crate::apps::Apps::get_subscription_plan_for_account`].
+    pub fn get_subscription_plan_for_account(
+        &self,
+        account_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::MarketplacePurchaseData>> {
+        self.runtime
+            .block_on(self.inner.get_subscription_plan_for_account(account_id))
+    }
+    /// The blocking version of [`crate::apps::Apps::list_plans`].
+    pub fn list_plans(
+        &self,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::MarketplaceListingPlan>>> {
+        self.runtime.block_on(self.inner.list_plans(per_page, page))
+    }
+    /// The blocking version of [`crate::apps::Apps::list_plans_request`].
+    pub fn list_plans_request(&self) -> AppsListPlansRequest<'_> {
+        AppsListPlansRequest {
+            inner: self.inner.list_plans_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::apps::Apps::list_all_plans`].
+    pub fn list_all_plans(
+        &self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::MarketplaceListingPlan>>> {
+        self.runtime.block_on(self.inner.list_all_plans())
+    }
+    /// The blocking version of [`crate::apps::Apps::list_accounts_for_plan`].
+    pub fn list_accounts_for_plan(
+        &self,
+        plan_id: i64,
+        sort: crate::types::Sort,
+        direction: crate::types::Order,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::MarketplacePurchaseData>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_accounts_for_plan(plan_id, sort, direction, per_page, page),
+        )
+    }
+    /// The blocking version of [`crate::apps::Apps::list_accounts_for_plan_request`].
+    pub fn list_accounts_for_plan_request(
+        &self,
+        plan_id: i64,
+    ) -> AppsListAccountsForPlanRequest<'_> {
+        AppsListAccountsForPlanRequest {
+            inner: self.inner.list_accounts_for_plan_request(plan_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::apps::Apps::list_all_accounts_for_plan`].
+    pub fn list_all_accounts_for_plan(
+        &self,
+        plan_id: i64,
+        sort: crate::types::Sort,
+        direction: crate::types::Order,
+    ) -> ClientResult<crate::Response<Vec<crate::types::MarketplacePurchaseData>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_all_accounts_for_plan(plan_id, sort, direction),
+        )
+    }
+    /// The blocking version of [`crate::apps::Apps::get_subscription_plan_for_account_stubbed`].
+    pub fn get_subscription_plan_for_account_stubbed(
+        &self,
+        account_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::MarketplacePurchaseData>> {
+        self.runtime.block_on(
+            self.inner
+                .get_subscription_plan_for_account_stubbed(account_id),
+        )
+    }
+    /// The blocking version of [`crate::apps::Apps::list_plans_stubbed`].
+    pub fn list_plans_stubbed(
+        &self,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::MarketplaceListingPlan>>> {
+        self.runtime
+            .block_on(self.inner.list_plans_stubbed(per_page, page))
+    }
+    /// The blocking version of [`crate::apps::Apps::list_plans_stubbed_request`].
+    pub fn list_plans_stubbed_request(&self) -> AppsListPlansStubbedRequest<'_> {
+        AppsListPlansStubbedRequest {
+            inner: self.inner.list_plans_stubbed_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::apps::Apps::list_all_plans_stubbed`].
+    pub fn list_all_plans_stubbed(
+        &self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::MarketplaceListingPlan>>> {
+        self.runtime.block_on(self.inner.list_all_plans_stubbed())
+    }
+    /// The blocking version of [`crate::apps::Apps::list_accounts_for_plan_stubbed`].
+    pub fn list_accounts_for_plan_stubbed(
+        &self,
+        plan_id: i64,
+        sort: crate::types::Sort,
+        direction: crate::types::Order,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::MarketplacePurchaseData>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_accounts_for_plan_stubbed(plan_id, sort, direction, per_page, page),
+        )
+    }
+    /// The blocking version of [`crate::apps::Apps::list_accounts_for_plan_stubbed_request`].
+    pub fn list_accounts_for_plan_stubbed_request(
+        &self,
+        plan_id: i64,
+    ) -> AppsListAccountsForPlanStubbedRequest<'_> {
+        AppsListAccountsForPlanStubbedRequest {
+            inner: self.inner.list_accounts_for_plan_stubbed_request(plan_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::apps::Apps::list_all_accounts_for_plan_stubbed`].
+    pub fn list_all_accounts_for_plan_stubbed(
+        &self,
+        plan_id: i64,
+        sort: crate::types::Sort,
+        direction: crate::types::Order,
+    ) -> ClientResult<crate::Response<Vec<crate::types::MarketplacePurchaseData>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_all_accounts_for_plan_stubbed(plan_id, sort, direction),
+        )
+    }
+    /// The blocking version of [`crate::apps::Apps::get_org_installation`].
+    pub fn get_org_installation(
+        &self,
+        org: &str,
+    ) -> ClientResult<crate::Response<crate::types::Installation>> {
+        self.runtime.block_on(self.inner.get_org_installation(org))
+    }
+    /// The blocking version of [`crate::apps::Apps::create_content_attachment`].
+    pub fn create_content_attachment(
+        &self,
+        owner: &str,
+        repo: &str,
+        content_reference_id: i64,
+        body: &crate::types::TeamsUpdateDiscussionInOrgRequest,
+    ) -> ClientResult<crate::Response<crate::types::ContentReferenceAttachment>> {
+        self.runtime.block_on(self.inner.create_content_attachment(
+            owner,
+            repo,
+            content_reference_id,
+            body,
+        ))
+    }
+    /// The blocking version of [`crate::apps::Apps::get_repo_installation`].
+    pub fn get_repo_installation(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> ClientResult<crate::Response<crate::types::Installation>> {
+        self.runtime
+            .block_on(self.inner.get_repo_installation(owner, repo))
+    }
+    /// The blocking version of [`crate::apps::Apps::list_installations_for_authenticated_user`].
+    pub fn list_installations_for_authenticated_user(
+        &self,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<crate::types::AppsListInstallationsResponse>> {
+        self.runtime.block_on(
+            self.inner
+                .list_installations_for_authenticated_user(per_page, page),
+        )
+    }
+    /// The blocking version of [`crate::apps::Apps::list_installations_for_authenticated_user_request`].
+    pub fn list_installations_for_authenticated_user_request(
+        &self,
+    ) -> AppsListInstallationsForAuthenticatedUserRequest<'_> {
+        AppsListInstallationsForAuthenticatedUserRequest {
+            inner: self
+                .inner
+                .list_installations_for_authenticated_user_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::apps::Apps::list_installation_repos_for_authenticated_user`].
+    pub fn list_installation_repos_for_authenticated_user(
+        &self,
+        installation_id: i64,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<crate::types::AppsListInstallationReposResponse>> {
+        self.runtime
+            .block_on(self.inner.list_installation_repos_for_authenticated_user(
+                installation_id,
+                per_page,
+                page,
+            ))
+    }
+    /// The blocking version of [`crate::apps::Apps::list_installation_repos_for_authenticated_user_request`].
+    pub fn list_installation_repos_for_authenticated_user_request(
+        &self,
+        installation_id: i64,
+    ) -> AppsListInstallationReposForAuthenticatedUserRequest<'_> {
+        AppsListInstallationReposForAuthenticatedUserRequest {
+            inner: self
+                .inner
+                .list_installation_repos_for_authenticated_user_request(installation_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::apps::Apps::add_repo_to_installation`].
+    pub fn add_repo_to_installation(
+        &self,
+        installation_id: i64,
+        repository_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .add_repo_to_installation(installation_id, repository_id),
+        )
+    }
+    /// The blocking version of [`crate::apps::Apps::remove_repo_from_installation`].
+    pub fn remove_repo_from_installation(
+        &self,
+        installation_id: i64,
+        repository_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .remove_repo_from_installation(installation_id, repository_id),
+        )
+    }
+    /// The blocking version of [`crate::apps::Apps::list_subscriptions_for_authenticated_user`].
+    pub fn list_subscriptions_for_authenticated_user(
+        &self,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::UserMarketplacePurchase>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_subscriptions_for_authenticated_user(per_page, page),
+        )
+    }
+    /// The blocking version of [`crate::apps::Apps::list_subscriptions_for_authenticated_user_request`].
+    pub fn list_subscriptions_for_authenticated_user_request(
+        &self,
+    ) -> AppsListSubscriptionsForAuthenticatedUserRequest<'_> {
+        AppsListSubscriptionsForAuthenticatedUserRequest {
+            inner: self
+                .inner
+                .list_subscriptions_for_authenticated_user_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::apps::Apps::list_all_subscriptions_for_authenticated_user`].
+    pub fn list_all_subscriptions_for_authenticated_user(
+        &self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::UserMarketplacePurchase>>> {
+        self.runtime
+            .block_on(self.inner.list_all_subscriptions_for_authenticated_user())
+    }
+    /// The blocking version of [`crate::apps::Apps::list_subscriptions_for_authenticated_user_stubbed`].
+    pub fn list_subscriptions_for_authenticated_user_stubbed(
+        &self,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::UserMarketplacePurchase>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_subscriptions_for_authenticated_user_stubbed(per_page, page),
+        )
+    }
+    /// The blocking version of [`crate::apps::Apps::list_subscriptions_for_authenticated_user_stubbed_request`].
+    pub fn list_subscriptions_for_authenticated_user_stubbed_request(
+        &self,
+    ) -> AppsListSubscriptionsForAuthenticatedUserStubbedRequest<'_> {
+        AppsListSubscriptionsForAuthenticatedUserStubbedRequest {
+            inner: self
+                .inner
+                .list_subscriptions_for_authenticated_user_stubbed_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::apps::Apps::list_all_subscriptions_for_authenticated_user_stubbed`].
+    pub fn list_all_subscriptions_for_authenticated_user_stubbed(
+        &self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::UserMarketplacePurchase>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_all_subscriptions_for_authenticated_user_stubbed(),
+        )
+    }
+    /// The blocking version of [`crate::apps::Apps::get_user_installation`].
+    pub fn get_user_installation(
+        &self,
+        username: &str,
+    ) -> ClientResult<crate::Response<crate::types::Installation>> {
+        self.runtime
+            .block_on(self.inner.get_user_installation(username))
+    }
+}
@@ -0,0 +1,91 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::billing::Billing`].
+pub struct Billing {
+    inner: crate::billing::Billing,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Billing {
+    pub(crate) fn new(
+        inner: crate::billing::Billing,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Billing { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::billing::Billing::get_github_actions_billing_ghe`].
+    pub fn get_github_actions_billing_ghe(
+        &self,
+        enterprise: &str,
+    ) -> ClientResult<crate::Response<crate::types::ActionsBillingUsage>> {
+        self.runtime
+            .block_on(self.inner.get_github_actions_billing_ghe(enterprise))
+    }
+    /// The blocking version of [`crate::billing::Billing::get_github_packages_billing_ghe`].
+    pub fn get_github_packages_billing_ghe(
+        &self,
+        enterprise: &str,
+    ) -> ClientResult<crate::Response<crate::types::PackagesBillingUsage>> {
+        self.runtime
+            .block_on(self.inner.get_github_packages_billing_ghe(enterprise))
+    }
+    /// The blocking version of [`crate::billing::Billing::get_shared_storage_billing_ghe`].
+    pub fn get_shared_storage_billing_ghe(
+        &self,
+        enterprise: &str,
+    ) -> ClientResult<crate::Response<crate::types::CombinedBillingUsage>> {
+        self.runtime
+            .block_on(self.inner.get_shared_storage_billing_ghe(enterprise))
+    }
+    /// The blocking version of [`crate::billing::Billing::get_github_actions_billing_org`].
+    pub fn get_github_actions_billing_org(
+        &self,
+        org: &str,
+    ) -> ClientResult<crate::Response<crate::types::ActionsBillingUsage>> {
+        self.runtime
+            .block_on(self.inner.get_github_actions_billing_org(org))
+    }
+    /// The blocking version of [`crate::billing::Billing::get_github_packages_billing_org`].
+    pub fn get_github_packages_billing_org(
+        &self,
+        org: &str,
+    ) -> ClientResult<crate::Response<crate::types::PackagesBillingUsage>> {
+        self.runtime
+            .block_on(self.inner.get_github_packages_billing_org(org))
+    }
+    /// The blocking version of [`crate::billing::Billing::get_shared_storage_billing_org`].
+    pub fn get_shared_storage_billing_org(
+        &self,
+        org: &str,
+    ) -> ClientResult<crate::Response<crate::types::CombinedBillingUsage>> {
+        self.runtime
+            .block_on(self.inner.get_shared_storage_billing_org(org))
+    }
+    /// The blocking version of [`crate::billing::Billing::get_github_actions_billing_user`].
+    pub fn get_github_actions_billing_user(
+        &self,
+        username: &str,
+    ) -> ClientResult<crate::Response<crate::types::ActionsBillingUsage>> {
+        self.runtime
+            .block_on(self.inner.get_github_actions_billing_user(username))
+    }
+    /// The blocking version of [`crate::billing::Billing::get_github_packages_billing_user`].
+    pub fn get_github_packages_billing_user(
+        &self,
+        username: &str,
+    ) -> ClientResult<crate::Response<crate::types::PackagesBillingUsage>> {
+        self.runtime
+            .block_on(self.inner.get_github_packages_billing_user(username))
+    }
+    /// The blocking version of [`crate::billing::Billing::get_shared_storage_billing_user`].
+    pub fn get_shared_storage_billing_user(
+        &self,
+        username: &str,
+    ) -> ClientResult<crate::Response<crate::types::CombinedBillingUsage>> {
+        self.runtime
+            .block_on(self.inner.get_shared_storage_billing_user(username))
+    }
+}
@@ -0,0 +1,375 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Checks::list_annotations`], set with a builder.
+pub struct ChecksListAnnotationsRequest<'a> {
+    inner: crate::checks::ChecksListAnnotationsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ChecksListAnnotationsRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::CheckAnnotation>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Checks::list_for_suite`], set with a builder.
+pub struct ChecksListForSuiteRequest<'a> {
+    inner: crate::checks::ChecksListForSuiteRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ChecksListForSuiteRequest<'a> {
+    /// Sets the `check_name` query parameter.
+    pub fn check_name(mut self, check_name: &'a str) -> Self {
+        self.inner = self.inner.check_name(check_name);
+        self
+    }
+
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: crate::types::JobStatus) -> Self {
+        self.inner = self.inner.status(status);
+        self
+    }
+
+    /// Sets the `filter` query parameter.
+    pub fn filter(mut self, filter: crate::types::ActionsListJobsWorkflowRunFilter) -> Self {
+        self.inner = self.inner.filter(filter);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::ChecksListRefResponse>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Checks::list_for_ref`], set with a builder.
+pub struct ChecksListForRefRequest<'a> {
+    inner: crate::checks::ChecksListForRefRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ChecksListForRefRequest<'a> {
+    /// Sets the `check_name` query parameter.
+    pub fn check_name(mut self, check_name: &'a str) -> Self {
+        self.inner = self.inner.check_name(check_name);
+        self
+    }
+
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: crate::types::JobStatus) -> Self {
+        self.inner = self.inner.status(status);
+        self
+    }
+
+    /// Sets the `filter` query parameter.
+    pub fn filter(mut self, filter: crate::types::ActionsListJobsWorkflowRunFilter) -> Self {
+        self.inner = self.inner.filter(filter);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sets the `app_id` query parameter.
+    pub fn app_id(mut self, app_id: i64) -> Self {
+        self.inner = self.inner.app_id(app_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::ChecksListRefResponse>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Checks::list_suites_for_ref`], set with a builder.
+pub struct ChecksListSuitesForRefRequest<'a> {
+    inner: crate::checks::ChecksListSuitesForRefRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ChecksListSuitesForRefRequest<'a> {
+    /// Sets the `app_id` query parameter.
+    pub fn app_id(mut self, app_id: i64) -> Self {
+        self.inner = self.inner.app_id(app_id);
+        self
+    }
+
+    /// Sets the `check_name` query parameter.
+    pub fn check_name(mut self, check_name: &'a str) -> Self {
+        self.inner = self.inner.check_name(check_name);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::ChecksListSuitesRefResponse>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::checks::Checks`].
+pub struct Checks {
+    inner: crate::checks::Checks,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Checks {
+    pub(crate) fn new(inner: crate::checks::Checks, runtime: Arc<tokio::runtime::Runtime>) -> Self {
+        Checks { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::checks::Checks::create`].
+    pub fn create(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::ChecksCreateRequest,
+    ) -> ClientResult<crate::Response<crate::types::CheckRun>> {
+        self.runtime.block_on(self.inner.create(owner, repo, body))
+    }
+    /// The blocking version of [`crate::checks::Checks::get`].
+    pub fn get(
+        &self,
+        owner: &str,
+        repo: &str,
+        check_run_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::CheckRun>> {
+        self.runtime
+            .block_on(self.inner.get(owner, repo, check_run_id))
+    }
+    /// The blocking version of [`crate::checks::Checks::update`].
+    pub fn update(
+        &self,
+        owner: &str,
+        repo: &str,
+        check_run_id: i64,
+        body: &crate::types::ChecksUpdateRequest,
+    ) -> ClientResult<crate::Response<crate::types::CheckRun>> {
+        self.runtime
+            .block_on(self.inner.update(owner, repo, check_run_id, body))
+    }
+    /// The blocking version of [`crate::checks::Checks::list_annotations`].
+    pub fn list_annotations(
+        &self,
+        owner: &str,
+        repo: &str,
+        check_run_id: i64,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::CheckAnnotation>>> {
+        self.runtime.block_on(self.inner.list_annotations(
+            owner,
+            repo,
+            check_run_id,
+            per_page,
+            page,
+        ))
+    }
+    /// The blocking version of [`crate::checks::Checks::list_annotations_request`].
+    pub fn list_annotations_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+        check_run_id: i64,
+    ) -> ChecksListAnnotationsRequest<'a> {
+        ChecksListAnnotationsRequest {
+            inner: self
+                .inner
+                .list_annotations_request(owner, repo, check_run_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::checks::Checks::list_all_annotations`].
+    pub fn list_all_annotations(
+        &self,
+        owner: &str,
+        repo: &str,
+        check_run_id: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::CheckAnnotation>>> {
+        self.runtime
+            .block_on(self.inner.list_all_annotations(owner, repo, check_run_id))
+    }
+    /// The blocking version of [`crate::checks::Checks::create_suite`].
+    pub fn create_suite(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::ChecksCreateSuiteRequest,
+    ) -> ClientResult<crate::Response<crate::types::CheckSuiteData>> {
+        self.runtime
+            .block_on(self.inner.create_suite(owner, repo, body))
+    }
+    /// The blocking version of [`crate::checks::Checks::set_suites_preferences`].
+    pub fn set_suites_preferences(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::Preferences,
+    ) -> ClientResult<crate::Response<crate::types::CheckSuitePreference>> {
+        self.runtime
+            .block_on(self.inner.set_suites_preferences(owner, repo, body))
+    }
+    /// The blocking version of [`crate::checks::Checks::get_suite`].
+    pub fn get_suite(
+        &self,
+        owner: &str,
+        repo: &str,
+        check_suite_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::CheckSuiteData>> {
+        self.runtime
+            .block_on(self.inner.get_suite(owner, repo, check_suite_id))
+    }
+    /// The blocking version of [`crate::checks::Checks::list_for_suite`].
+    pub fn list_for_suite(
+        &self,
+        owner: &str,
+        repo: &str,
+        check_suite_id: i64,
+        check_name: &str,
+        status: crate::types::JobStatus,
+        filter: crate::types::ActionsListJobsWorkflowRunFilter,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<crate::types::ChecksListRefResponse>> {
+        self.runtime.block_on(self.inner.list_for_suite(
+            owner,
+            repo,
+            check_suite_id,
+            check_name,
+            status,
+            filter,
+            per_page,
+            page,
+        ))
+    }
+    /// The blocking version of [`crate::checks::Checks::list_for_suite_request`].
+    pub fn list_for_suite_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+        check_suite_id: i64,
+    ) -> ChecksListForSuiteRequest<'a> {
+        ChecksListForSuiteRequest {
+            inner: self
+                .inner
+                .list_for_suite_request(owner, repo, check_suite_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::checks::Checks::rerequest_suite`].
+    pub fn rerequest_suite(
+        &self,
+        owner: &str,
+        repo: &str,
+        check_suite_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.rerequest_suite(owner, repo, check_suite_id))
+    }
+    /// The blocking version of [`crate::checks::Checks::list_for_ref`].
+    pub fn list_for_ref(
+        &self,
+        owner: &str,
+        repo: &str,
+        ref_: &str,
+        check_name: &str,
+        status: crate::types::JobStatus,
+        filter: crate::types::ActionsListJobsWorkflowRunFilter,
+        per_page: i64,
+        page: i64,
+        app_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::ChecksListRefResponse>> {
+        self.runtime.block_on(self.inner.list_for_ref(
+            owner, repo, ref_, check_name, status, filter, per_page, page, app_id,
+        ))
+    }
+    /// The blocking version of [`crate::checks::Checks::list_for_ref_request`].
+    pub fn list_for_ref_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+        ref_: &'a str,
+    ) -> ChecksListForRefRequest<'a> {
+        ChecksListForRefRequest {
+            inner: self.inner.list_for_ref_request(owner, repo, ref_),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::checks::Checks::list_suites_for_ref`].
+    pub fn list_suites_for_ref(
+        &self,
+        owner: &str,
+        repo: &str,
+        ref_: &str,
+        app_id: i64,
+        check_name: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<crate::types::ChecksListSuitesRefResponse>> {
+        self.runtime.block_on(
+            self.inner
+                .list_suites_for_ref(owner, repo, ref_, app_id, check_name, per_page, page),
+        )
+    }
+    /// The blocking version of [`crate::checks::Checks::list_suites_for_ref_request`].
+    pub fn list_suites_for_ref_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+        ref_: &'a str,
+    ) -> ChecksListSuitesForRefRequest<'a> {
+        ChecksListSuitesForRefRequest {
+            inner: self.inner.list_suites_for_ref_request(owner, repo, ref_),
+            runtime: &self.runtime,
+        }
+    }
+}
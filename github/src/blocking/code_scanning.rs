@@ -0,0 +1,375 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`CodeScanning::list_alerts_for_repo`], set with a builder.
+pub struct CodeScanningListAlertsForRepoRequest<'a> {
+    inner: crate::code_scanning::CodeScanningListAlertsForRepoRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> CodeScanningListAlertsForRepoRequest<'a> {
+    /// Sets the `tool_name` query parameter.
+    pub fn tool_name(mut self, tool_name: &'a str) -> Self {
+        self.inner = self.inner.tool_name(tool_name);
+        self
+    }
+
+    /// Sets the `tool_guid` query parameter.
+    pub fn tool_guid(mut self, tool_guid: &'a str) -> Self {
+        self.inner = self.inner.tool_guid(tool_guid);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `ref` query parameter.
+    pub fn ref_(mut self, ref_: &'a str) -> Self {
+        self.inner = self.inner.ref_(ref_);
+        self
+    }
+
+    /// Sets the `state` query parameter.
+    pub fn state(mut self, state: crate::types::CodeScanningAlertState) -> Self {
+        self.inner = self.inner.state(state);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::CodeScanningAlertItems>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`CodeScanning::list_alert_instances`], set with a builder.
+pub struct CodeScanningListAlertInstancesRequest<'a> {
+    inner: crate::code_scanning::CodeScanningListAlertInstancesRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> CodeScanningListAlertInstancesRequest<'a> {
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `ref` query parameter.
+    pub fn ref_(mut self, ref_: &'a str) -> Self {
+        self.inner = self.inner.ref_(ref_);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(
+        self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::CodeScanningAlertInstance>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`CodeScanning::list_recent_analyses`], set with a builder.
+pub struct CodeScanningListRecentAnalysesRequest<'a> {
+    inner: crate::code_scanning::CodeScanningListRecentAnalysesRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> CodeScanningListRecentAnalysesRequest<'a> {
+    /// Sets the `tool_name` query parameter.
+    pub fn tool_name(mut self, tool_name: &'a str) -> Self {
+        self.inner = self.inner.tool_name(tool_name);
+        self
+    }
+
+    /// Sets the `tool_guid` query parameter.
+    pub fn tool_guid(mut self, tool_guid: &'a str) -> Self {
+        self.inner = self.inner.tool_guid(tool_guid);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `ref` query parameter.
+    pub fn ref_(mut self, ref_: &'a str) -> Self {
+        self.inner = self.inner.ref_(ref_);
+        self
+    }
+
+    /// Sets the `sarif_id` query parameter.
+    pub fn sarif_id(mut self, sarif_id: &'a str) -> Self {
+        self.inner = self.inner.sarif_id(sarif_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::CodeScanningAnalysis>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`CodeScanning::delete_analysis`], set with a builder.
+pub struct CodeScanningDeleteAnalysisRequest<'a> {
+    inner: crate::code_scanning::CodeScanningDeleteAnalysisRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> CodeScanningDeleteAnalysisRequest<'a> {
+    /// Sets the `confirm_delete` query parameter.
+    pub fn confirm_delete(mut self, confirm_delete: &'a str) -> Self {
+        self.inner = self.inner.confirm_delete(confirm_delete);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::AnalysisDeletion>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::code_scanning::CodeScanning`].
+pub struct CodeScanning {
+    inner: crate::code_scanning::CodeScanning,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl CodeScanning {
+    pub(crate) fn new(
+        inner: crate::code_scanning::CodeScanning,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        CodeScanning { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::code_scanning::CodeScanning::list_alerts_for_repo`].
+    pub fn list_alerts_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+        tool_name: &str,
+        tool_guid: &str,
+        page: i64,
+        per_page: i64,
+        ref_: &str,
+        state: crate::types::CodeScanningAlertState,
+    ) -> ClientResult<crate::Response<Vec<crate::types::CodeScanningAlertItems>>> {
+        self.runtime.block_on(self.inner.list_alerts_for_repo(
+            owner, repo, tool_name, tool_guid, page, per_page, ref_, state,
+        ))
+    }
+    /// The blocking version of [`crate::code_scanning::CodeScanning::list_alerts_for_repo_request`].
+    pub fn list_alerts_for_repo_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+    ) -> CodeScanningListAlertsForRepoRequest<'a> {
+        CodeScanningListAlertsForRepoRequest {
+            inner: self.inner.list_alerts_for_repo_request(owner, repo),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::code_scanning::CodeScanning::list_all_alerts_for_repo`].
+    pub fn list_all_alerts_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+        tool_name: &str,
+        tool_guid: &str,
+        ref_: &str,
+        state: crate::types::CodeScanningAlertState,
+    ) -> ClientResult<crate::Response<Vec<crate::types::CodeScanningAlertItems>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_all_alerts_for_repo(owner, repo, tool_name, tool_guid, ref_, state),
+        )
+    }
+    /// The blocking version of [`crate::code_scanning::CodeScanning::get_alert`].
+    pub fn get_alert(
+        &self,
+        owner: &str,
+        repo: &str,
+        alert_number: i64,
+    ) -> ClientResult<crate::Response<crate::types::CodeScanningAlert>> {
+        self.runtime
+            .block_on(self.inner.get_alert(owner, repo, alert_number))
+    }
+    /// The blocking version of [`crate::code_scanning::CodeScanning::update_alert`].
+    pub fn update_alert(
+        &self,
+        owner: &str,
+        repo: &str,
+        alert_number: i64,
+        body: &crate::types::CodeScanningUpdateAlertRequest,
+    ) -> ClientResult<crate::Response<crate::types::CodeScanningAlert>> {
+        self.runtime
+            .block_on(self.inner.update_alert(owner, repo, alert_number, body))
+    }
+    /// The blocking version of [`crate::code_scanning::CodeScanning::list_alert_instances`].
+    pub fn list_alert_instances(
+        &self,
+        owner: &str,
+        repo: &str,
+        alert_number: i64,
+        page: i64,
+        per_page: i64,
+        ref_: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::CodeScanningAlertInstance>>> {
+        self.runtime.block_on(self.inner.list_alert_instances(
+            owner,
+            repo,
+            alert_number,
+            page,
+            per_page,
+            ref_,
+        ))
+    }
+    /// The blocking version of [`crate::code_scanning::CodeScanning::list_alert_instances_request`].
+    pub fn list_alert_instances_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+        alert_number: i64,
+    ) -> CodeScanningListAlertInstancesRequest<'a> {
+        CodeScanningListAlertInstancesRequest {
+            inner: self
+                .inner
+                .list_alert_instances_request(owner, repo, alert_number),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::code_scanning::CodeScanning::list_all_alert_instances`].
+    pub fn list_all_alert_instances(
+        &self,
+        owner: &str,
+        repo: &str,
+        alert_number: i64,
+        ref_: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::CodeScanningAlertInstance>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_all_alert_instances(owner, repo, alert_number, ref_),
+        )
+    }
+    /// The blocking version of [`crate::code_scanning::CodeScanning::list_recent_analyses`].
+    pub fn list_recent_analyses(
+        &self,
+        owner: &str,
+        repo: &str,
+        tool_name: &str,
+        tool_guid: &str,
+        page: i64,
+        per_page: i64,
+        ref_: &str,
+        sarif_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::CodeScanningAnalysis>>> {
+        self.runtime.block_on(self.inner.list_recent_analyses(
+            owner, repo, tool_name, tool_guid, page, per_page, ref_, sarif_id,
+        ))
+    }
+    /// The blocking version of [`crate::code_scanning::CodeScanning::list_recent_analyses_request`].
+    pub fn list_recent_analyses_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+    ) -> CodeScanningListRecentAnalysesRequest<'a> {
+        CodeScanningListRecentAnalysesRequest {
+            inner: self.inner.list_recent_analyses_request(owner, repo),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::code_scanning::CodeScanning::list_all_recent_analyses`].
+    pub fn list_all_recent_analyses(
+        &self,
+        owner: &str,
+        repo: &str,
+        tool_name: &str,
+        tool_guid: &str,
+        ref_: &str,
+        sarif_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::CodeScanningAnalysis>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_all_recent_analyses(owner, repo, tool_name, tool_guid, ref_, sarif_id),
+        )
+    }
+    /// The blocking version of [`crate::code_scanning::CodeScanning::get_analysis`].
+    pub fn get_analysis(
+        &self,
+        owner: &str,
+        repo: &str,
+        analysis_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::CodeScanningAnalysis>> {
+        self.runtime
+            .block_on(self.inner.get_analysis(owner, repo, analysis_id))
+    }
+    /// The blocking version of [`crate::code_scanning::CodeScanning::delete_analysis`].
+    pub fn delete_analysis(
+        &self,
+        owner: &str,
+        repo: &str,
+        analysis_id: i64,
+        confirm_delete: &str,
+    ) -> ClientResult<crate::Response<crate::types::AnalysisDeletion>> {
+        self.runtime.block_on(
+            self.inner
+                .delete_analysis(owner, repo, analysis_id, confirm_delete),
+        )
+    }
+    /// The blocking version of [`crate::code_scanning::CodeScanning::delete_analysis_request`].
+    pub fn delete_analysis_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+        analysis_id: i64,
+    ) -> CodeScanningDeleteAnalysisRequest<'a> {
+        CodeScanningDeleteAnalysisRequest {
+            inner: self.inner.delete_analysis_request(owner, repo, analysis_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::code_scanning::CodeScanning::upload_sarif`].
+    pub fn upload_sarif(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::CodeScanningUploadSarifRequest,
+    ) -> ClientResult<crate::Response<crate::types::CodeScanningSarifsReceipt>> {
+        self.runtime
+            .block_on(self.inner.upload_sarif(owner, repo, body))
+    }
+    /// The blocking version of [`crate::code_scanning::CodeScanning::get_sarif`].
+    pub fn get_sarif(
+        &self,
+        owner: &str,
+        repo: &str,
+        sarif_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::CodeScanningSarifsStatus>> {
+        self.runtime
+            .block_on(self.inner.get_sarif(owner, repo, sarif_id))
+    }
+}
@@ -0,0 +1,47 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::codes_of_conduct::CodesOfConduct`].
+pub struct CodesOfConduct {
+    inner: crate::codes_of_conduct::CodesOfConduct,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl CodesOfConduct {
+    pub(crate) fn new(
+        inner: crate::codes_of_conduct::CodesOfConduct,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        CodesOfConduct { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::codes_of_conduct::CodesOfConduct::get_all_codes_of_conduct`].
+    pub fn get_all_codes_of_conduct(
+        &self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::CodeOfConduct>>> {
+        self.runtime.block_on(self.inner.get_all_codes_of_conduct())
+    }
+    /// The blocking version of [`crate::codes_of_conduct::CodesOfConduct::get_all_all_codes_of_conduct`].
+    pub fn get_all_all_codes_of_conduct(
+        &self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::CodeOfConduct>>> {
+        self.runtime
+            .block_on(self.inner.get_all_all_codes_of_conduct())
+    }
+    /// The blocking version of [`crate::codes_of_conduct::CodesOfConduct::get_conduct_code`].
+    pub fn get_conduct_code(
+        &self,
+        key: &str,
+    ) -> ClientResult<crate::Response<crate::types::CodeOfConduct>> {
+        self.runtime.block_on(self.inner.get_conduct_code(key))
+    }
+    /// The blocking version of [`crate::codes_of_conduct::CodesOfConduct::get_for_repo`].
+    pub fn get_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> ClientResult<crate::Response<crate::types::CodeOfConduct>> {
+        self.runtime.block_on(self.inner.get_for_repo(owner, repo))
+    }
+}
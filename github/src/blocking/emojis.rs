@@ -0,0 +1,20 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::emojis::Emojis`].
+pub struct Emojis {
+    inner: crate::emojis::Emojis,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Emojis {
+    pub(crate) fn new(inner: crate::emojis::Emojis, runtime: Arc<tokio::runtime::Runtime>) -> Self {
+        Emojis { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::emojis::Emojis::get`].
+    pub fn get(&self) -> ClientResult<crate::Response<String>> {
+        self.runtime.block_on(self.inner.get())
+    }
+}
@@ -0,0 +1,970 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`EnterpriseAdmin::list_selected_organizations_enabled_github_actions_enterprise`], set with a builder.
+pub struct EnterpriseAdminListSelectedOrganizationsEnabledGithubActionsEnterpriseRequest<'a> {
+            inner: crate::enterprise_admin::EnterpriseAdminListSelectedOrganizationsEnabledGithubActionsEnterpriseRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a> EnterpriseAdminListSelectedOrganizationsEnabledGithubActionsEnterpriseRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(
+        self,
+    ) -> ClientResult<
+        crate::Response<crate::types::EnterpriseAdminListOrgAccessSelfHostedRunnerGroupInResponse>,
+    > {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`EnterpriseAdmin::list_self_hosted_runner_groups_for_enterprise`], set with a builder.
+pub struct EnterpriseAdminListSelfHostedRunnerGroupsForEnterpriseRequest<'a> {
+    inner:
+        crate::enterprise_admin::EnterpriseAdminListSelfHostedRunnerGroupsForEnterpriseRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> EnterpriseAdminListSelfHostedRunnerGroupsForEnterpriseRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(
+        self,
+    ) -> ClientResult<
+        crate::Response<crate::types::EnterpriseAdminListSelfHostedRunnerGroupsResponse>,
+    > {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`EnterpriseAdmin::list_org_access_to_self_hosted_runner_group_in_enterprise`], set with a builder.
+pub struct EnterpriseAdminListOrgAccessToSelfHostedRunnerGroupInEnterpriseRequest<'a> {
+            inner: crate::enterprise_admin::EnterpriseAdminListOrgAccessToSelfHostedRunnerGroupInEnterpriseRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a> EnterpriseAdminListOrgAccessToSelfHostedRunnerGroupInEnterpriseRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(
+        self,
+    ) -> ClientResult<
+        crate::Response<crate::types::EnterpriseAdminListOrgAccessSelfHostedRunnerGroupInResponse>,
+    > {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`EnterpriseAdmin::list_self_hosted_runners_in_group_for_enterprise`], set with a builder.
+pub struct EnterpriseAdminListSelfHostedRunnersInGroupForEnterpriseRequest<'a> {
+    inner: crate::enterprise_admin::EnterpriseAdminListSelfHostedRunnersInGroupForEnterpriseRequest<
+        'a,
+    >,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> EnterpriseAdminListSelfHostedRunnersInGroupForEnterpriseRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::ActionsListSelfHostedRunnersInGroupOrgResponse>>
+    {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`EnterpriseAdmin::list_self_hosted_runners_for_enterprise`], set with a builder.
+pub struct EnterpriseAdminListSelfHostedRunnersForEnterpriseRequest<'a> {
+    inner: crate::enterprise_admin::EnterpriseAdminListSelfHostedRunnersForEnterpriseRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> EnterpriseAdminListSelfHostedRunnersForEnterpriseRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::EnterpriseAdminListSelfHostedRunnersResponse>>
+    {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`EnterpriseAdmin::get_audit_log`], set with a builder.
+pub struct EnterpriseAdminGetAuditLogRequest<'a> {
+    inner: crate::enterprise_admin::EnterpriseAdminGetAuditLogRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> EnterpriseAdminGetAuditLogRequest<'a> {
+    /// Sets the `phrase` query parameter.
+    pub fn phrase(mut self, phrase: &'a str) -> Self {
+        self.inner = self.inner.phrase(phrase);
+        self
+    }
+
+    /// Sets the `include` query parameter.
+    pub fn include(mut self, include: crate::types::Include) -> Self {
+        self.inner = self.inner.include(include);
+        self
+    }
+
+    /// Sets the `after` query parameter.
+    pub fn after(mut self, after: &'a str) -> Self {
+        self.inner = self.inner.after(after);
+        self
+    }
+
+    /// Sets the `before` query parameter.
+    pub fn before(mut self, before: &'a str) -> Self {
+        self.inner = self.inner.before(before);
+        self
+    }
+
+    /// Sets the `order` query parameter.
+    pub fn order(mut self, order: crate::types::Order) -> Self {
+        self.inner = self.inner.order(order);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::AuditLogEvent>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`EnterpriseAdmin::list_provisioned_groups_enterprise`], set with a builder.
+pub struct EnterpriseAdminListProvisionedGroupsEnterpriseRequest<'a> {
+    inner: crate::enterprise_admin::EnterpriseAdminListProvisionedGroupsEnterpriseRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> EnterpriseAdminListProvisionedGroupsEnterpriseRequest<'a> {
+    /// Sets the `startIndex` query parameter.
+    pub fn start_index(mut self, start_index: i64) -> Self {
+        self.inner = self.inner.start_index(start_index);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.inner = self.inner.count(count);
+        self
+    }
+
+    /// Sets the `filter` query parameter.
+    pub fn filter(mut self, filter: &'a str) -> Self {
+        self.inner = self.inner.filter(filter);
+        self
+    }
+
+    /// Sets the `excludedAttributes` query parameter.
+    pub fn excluded_attributes(mut self, excluded_attributes: &'a str) -> Self {
+        self.inner = self.inner.excluded_attributes(excluded_attributes);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::ScimGroupListEnterprise>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`EnterpriseAdmin::get_provisioning_information_for_enterprise_group`], set with a builder.
+pub struct EnterpriseAdminGetProvisioningInformationForEnterpriseGroupRequest<'a> {
+    inner:
+        crate::enterprise_admin::EnterpriseAdminGetProvisioningInformationForEnterpriseGroupRequest<
+            'a,
+        >,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> EnterpriseAdminGetProvisioningInformationForEnterpriseGroupRequest<'a> {
+    /// Sets the `excludedAttributes` query parameter.
+    pub fn excluded_attributes(mut self, excluded_attributes: &'a str) -> Self {
+        self.inner = self.inner.excluded_attributes(excluded_attributes);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::ScimEnterpriseGroup>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`EnterpriseAdmin::list_provisioned_identities_enterprise`], set with a builder.
+pub struct EnterpriseAdminListProvisionedIdentitiesEnterpriseRequest<'a> {
+    inner: crate::enterprise_admin::EnterpriseAdminListProvisionedIdentitiesEnterpriseRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> EnterpriseAdminListProvisionedIdentitiesEnterpriseRequest<'a> {
+    /// Sets the `startIndex` query parameter.
+    pub fn start_index(mut self, start_index: i64) -> Self {
+        self.inner = self.inner.start_index(start_index);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.inner = self.inner.count(count);
+        self
+    }
+
+    /// Sets the `filter` query parameter.
+    pub fn filter(mut self, filter: &'a str) -> Self {
+        self.inner = self.inner.filter(filter);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::ScimUserListEnterprise>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::enterprise_admin::EnterpriseAdmin`].
+pub struct EnterpriseAdmin {
+    inner: crate::enterprise_admin::EnterpriseAdmin,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl EnterpriseAdmin {
+    pub(crate) fn new(
+        inner: crate::enterprise_admin::EnterpriseAdmin,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        EnterpriseAdmin { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::enterprise_admin::EnterpriseAdmin::get_github_actions_permissions_enterprise`].
+    pub fn get_github_actions_permissions_enterprise(
+        &self,
+        enterprise: &str,
+    ) -> ClientResult<crate::Response<crate::types::ActionsEnterprisePermissions>> {
+        self.runtime.block_on(
+            self.inner
+                .get_github_actions_permissions_enterprise(enterprise),
+        )
+    }
+    /// The blocking version of [`crate::enterprise_admin::EnterpriseAdmin::set_github_actions_permissions_enterprise`].
+    pub fn set_github_actions_permissions_enterprise(
+        &self,
+        enterprise: &str,
+        body: &crate::types::EnterpriseAdminSetGithubActionsPermissionsRequest,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .set_github_actions_permissions_enterprise(enterprise, body),
+        )
+    }
+    /// The blocking version of [`crate::enterprise_admin::EnterpriseAdmin::list_selected_organizations_enabled_github_actions_enterprise`].
+    pub fn list_selected_organizations_enabled_github_actions_enterprise(
+        &self,
+        enterprise: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<
+        crate::Response<crate::types::EnterpriseAdminListOrgAccessSelfHostedRunnerGroupInResponse>,
+    > {
+        self.runtime.block_on(
+            self.inner
+                .list_selected_organizations_enabled_github_actions_enterprise(
+                    enterprise, per_page, page,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::enterprise_admin::EnterpriseAdmin::list_selected_organizations_enabled_github_actions_enterprise_request`].
+    pub fn list_selected_organizations_enabled_github_actions_enterprise_request<'a>(
+        &'a self,
+        enterprise: &'a str,
+    ) -> EnterpriseAdminListSelectedOrganizationsEnabledGithubActionsEnterpriseRequest<'a> {
+        EnterpriseAdminListSelectedOrganizationsEnabledGithubActionsEnterpriseRequest {
+            inner: self
+                .inner
+                .list_selected_organizations_enabled_github_actions_enterprise_request(enterprise),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::enterprise_admin::EnterpriseAdmin::set_selected_organizations_enabled_github_actions_enterprise`].
+    pub fn set_selected_organizations_enabled_github_actions_enterprise(
+        &self,
+        enterprise: &str,
+        body: &crate::types::EnterpriseAdminSetOrgAccessSelfHostedRunnerGroupInRequest,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .set_selected_organizations_enabled_github_actions_enterprise(enterprise, body),
+        )
+    }
+    /// The blocking version of [`crate::enterprise_admin::EnterpriseAdmin::enable_selected_organization_github_actions_enterprise`].
+    pub fn enable_selected_organization_github_actions_enterprise(
+        &self,
+        enterprise: &str,
+        org_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .enable_selected_organization_github_actions_enterprise(enterprise, org_id),
+        )
+    }
+    /// The blocking version of [`crate::enterprise_admin::EnterpriseAdmin::disable_selected_organization_github_actions_enterprise`].
+    pub fn disable_selected_organization_github_actions_enterprise(
+        &self,
+        enterprise: &str,
+        org_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .disable_selected_organization_github_actions_enterprise(enterprise, org_id),
+        )
+    }
+    /// The blocking version of [`crate::enterprise_admin::EnterpriseAdmin::get_allowed_actions_enterprise`].
+    pub fn get_allowed_actions_enterprise(
+        &self,
+        enterprise: &str,
+    ) -> ClientResult<crate::Response<crate::types::SelectedActions>> {
+        self.runtime
+            .block_on(self.inner.get_allowed_actions_enterprise(enterprise))
+    }
+    /// The blocking version of [`crate::enterprise_admin::EnterpriseAdmin::set_allowed_actions_enterprise`].
+    pub fn set_allowed_actions_enterprise(
+        &self,
+        enterprise: &str,
+        body: &crate::types::SelectedActions,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.set_allowed_actions_enterprise(enterprise, body))
+    }
+    /// The blocking version of [`crate::enterprise_admin::EnterpriseAdmin::list_self_hosted_runner_groups_for_enterprise`].
+    pub fn list_self_hosted_runner_groups_for_enterprise(
+        &self,
+        enterprise: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<
+        crate::Response<crate::types::EnterpriseAdminListSelfHostedRunnerGroupsResponse>,
+    > {
+        self.runtime.block_on(
+            self.inner
+                .list_self_hosted_runner_groups_for_enterprise(enterprise, per_page, page),
+        )
+    }
+    /// The blocking version of [`crate::enterprise_admin::EnterpriseAdmin::list_self_hosted_runner_groups_for_enterprise_request`].
+    pub fn list_self_hosted_runner_groups_for_enterprise_request<'a>(
+        &'a self,
+        enterprise: &'a str,
+    ) -> EnterpriseAdminListSelfHostedRunnerGroupsForEnterpriseRequest<'a> {
+        EnterpriseAdminListSelfHostedRunnerGroupsForEnterpriseRequest {
+            inner: self
+                .inner
+                .list_self_hosted_runner_groups_for_enterprise_request(enterprise),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::enterprise_admin::EnterpriseAdmin::create_self_hosted_runner_group_for_enterprise`].
+    pub fn create_self_hosted_runner_group_for_enterprise(
+        &self,
+        enterprise: &str,
+        body: &crate::types::EnterpriseAdminCreateSelfHostedRunnerGroupRequest,
+    ) -> ClientResult<crate::Response<crate::types::RunnerGroupsEnterprise>> {
+        self.runtime.block_on(
+            self.inner
+                .create_self_hosted_runner_group_for_enterprise(enterprise, body),
+        )
+    }
+    /// The blocking version of [`crate::enterprise_admin::EnterpriseAdmin::get_self_hosted_runner_group_for_enterprise`].
+    pub fn get_self_hosted_runner_group_for_enterprise(
+        &self,
+        enterprise: &str,
+        runner_group_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::RunnerGroupsEnterprise>> {
+        self.runtime.block_on(
+            self.inner
+                .get_self_hosted_runner_group_for_enterprise(enterprise, runner_group_id),
+        )
+    }
+    /// The blocking version of [`crate::enterprise_admin::EnterpriseAdmin::delete_self_hosted_runner_group_from_enterprise`].
+    pub fn delete_self_hosted_runner_group_from_enterprise(
+        &self,
+        enterprise: &str,
+        runner_group_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .delete_self_hosted_runner_group_from_enterprise(enterprise, runner_group_id),
+        )
+    }
+    /// The blocking version of [`crate::enterprise_admin::EnterpriseAdmin::update_self_hosted_runner_group_for_enterprise`].
+    pub fn update_self_hosted_runner_group_for_enterprise(
+        &self,
+        enterprise: &str,
+        runner_group_id: i64,
+        body: &crate::types::EnterpriseAdminUpdateSelfHostedRunnerGroupRequest,
+    ) -> ClientResult<crate::Response<crate::types::RunnerGroupsEnterprise>> {
+        self.runtime
+            .block_on(self.inner.update_self_hosted_runner_group_for_enterprise(
+                enterprise,
+                runner_group_id,
+                body,
+            ))
+    }
+    /// The blocking version of [`crate::enterprise_admin::EnterpriseAdmin::list_org_access_to_self_hosted_runner_group_in_enterprise`].
+    pub fn list_org_access_to_self_hosted_runner_group_in_enterprise(
+        &self,
+        enterprise: &str,
+        runner_group_id: i64,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<
+        crate::Response<crate::types::EnterpriseAdminListOrgAccessSelfHostedRunnerGroupInResponse>,
+    > {
+        self.runtime.block_on(
+            self.inner
+                .list_org_access_to_self_hosted_runner_group_in_enterprise(
+                    enterprise,
+                    runner_group_id,
+                    per_page,
+                    page,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::enterprise_admin::EnterpriseAdmin::list_org_access_to_self_hosted_runner_group_in_enterprise_request`].
+    pub fn list_org_access_to_self_hosted_runner_group_in_enterprise_request<'a>(
+        &'a self,
+        enterprise: &'a str,
+        runner_group_id: i64,
+    ) -> EnterpriseAdminListOrgAccessToSelfHostedRunnerGroupInEnterpriseRequest<'a> {
+        EnterpriseAdminListOrgAccessToSelfHostedRunnerGroupInEnterpriseRequest {
+            inner: self
+                .inner
+                .list_org_access_to_self_hosted_runner_group_in_enterprise_request(
+                    enterprise,
+                    runner_group_id,
+                ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::enterprise_admin::EnterpriseAdmin::set_org_access_to_self_hosted_runner_group_in_enterprise`].
+    pub fn set_org_access_to_self_hosted_runner_group_in_enterprise(
+        &self,
+        enterprise: &str,
+        runner_group_id: i64,
+        body: &crate::types::EnterpriseAdminSetOrgAccessSelfHostedRunnerGroupInRequest,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .set_org_access_to_self_hosted_runner_group_in_enterprise(
+                    enterprise,
+                    runner_group_id,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::enterprise_admin::EnterpriseAdmin::add_org_access_to_self_hosted_runner_group_in_enterprise`].
+    pub fn add_org_access_to_self_hosted_runner_group_in_enterprise(
+        &self,
+        enterprise: &str,
+        runner_group_id: i64,
+        org_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .add_org_access_to_self_hosted_runner_group_in_enterprise(
+                    enterprise,
+                    runner_group_id,
+                    org_id,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::enterprise_admin::EnterpriseAdmin::remove_org_access_to_self_hosted_runner_group_in_enterprise`].
+    pub fn remove_org_access_to_self_hosted_runner_group_in_enterprise(
+        &self,
+        enterprise: &str,
+        runner_group_id: i64,
+        org_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .remove_org_access_to_self_hosted_runner_group_in_enterprise(
+                    enterprise,
+                    runner_group_id,
+                    org_id,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::enterprise_admin::EnterpriseAdmin::list_self_hosted_runners_in_group_for_enterprise`].
+    pub fn list_self_hosted_runners_in_group_for_enterprise(
+        &self,
+        enterprise: &str,
+        runner_group_id: i64,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<crate::types::ActionsListSelfHostedRunnersInGroupOrgResponse>>
+    {
+        self.runtime
+            .block_on(self.inner.list_self_hosted_runners_in_group_for_enterprise(
+                enterprise,
+                runner_group_id,
+                per_page,
+                page,
+            ))
+    }
+    /// The blocking version of [`crate::enterprise_admin::EnterpriseAdmin::list_self_hosted_runners_in_group_for_enterprise_request`].
+    pub fn list_self_hosted_runners_in_group_for_enterprise_request<'a>(
+        &'a self,
+        enterprise: &'a str,
+        runner_group_id: i64,
+    ) -> EnterpriseAdminListSelfHostedRunnersInGroupForEnterpriseRequest<'a> {
+        EnterpriseAdminListSelfHostedRunnersInGroupForEnterpriseRequest {
+            inner: self
+                .inner
+                .list_self_hosted_runners_in_group_for_enterprise_request(
+                    enterprise,
+                    runner_group_id,
+                ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::enterprise_admin::EnterpriseAdmin::set_self_hosted_runners_in_group_for_enterprise`].
+    pub fn set_self_hosted_runners_in_group_for_enterprise(
+        &self,
+        enterprise: &str,
+        runner_group_id: i64,
+        body: &crate::types::ActionsSetSelfHostedRunnersInGroupOrgRequest,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.set_self_hosted_runners_in_group_for_enterprise(
+                enterprise,
+                runner_group_id,
+                body,
+            ))
+    }
+    /// The blocking version of [`crate::enterprise_admin::EnterpriseAdmin::add_self_hosted_runner_to_group_for_enterprise`].
+    pub fn add_self_hosted_runner_to_group_for_enterprise(
+        &self,
+        enterprise: &str,
+        runner_group_id: i64,
+        runner_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.add_self_hosted_runner_to_group_for_enterprise(
+                enterprise,
+                runner_group_id,
+                runner_id,
+            ))
+    }
+    /// The blocking version of [`crate::enterprise_admin::EnterpriseAdmin::remove_self_hosted_runner_from_group_for_enterprise`].
+    pub fn remove_self_hosted_runner_from_group_for_enterprise(
+        &self,
+        enterprise: &str,
+        runner_group_id: i64,
+        runner_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .remove_self_hosted_runner_from_group_for_enterprise(
+                    enterprise,
+                    runner_group_id,
+                    runner_id,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::enterprise_admin::EnterpriseAdmin::list_self_hosted_runners_for_enterprise`].
+    pub fn list_self_hosted_runners_for_enterprise(
+        &self,
+        enterprise: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<crate::types::EnterpriseAdminListSelfHostedRunnersResponse>>
+    {
+        self.runtime.block_on(
+            self.inner
+                .list_self_hosted_runners_for_enterprise(enterprise, per_page, page),
+        )
+    }
+    /// The blocking version of [`crate::enterprise_admin::EnterpriseAdmin::list_self_hosted_runners_for_enterprise_request`].
+    pub fn list_self_hosted_runners_for_enterprise_request<'a>(
+        &'a self,
+        enterprise: &'a str,
+    ) -> EnterpriseAdminListSelfHostedRunnersForEnterpriseRequest<'a> {
+        EnterpriseAdminListSelfHostedRunnersForEnterpriseRequest {
+            inner: self
+                .inner
+                .list_self_hosted_runners_for_enterprise_request(enterprise),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::enterprise_admin::EnterpriseAdmin::list_runner_applications_for_enterprise`].
+    pub fn list_runner_applications_for_enterprise(
+        &self,
+        enterprise: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::RunnerApplication>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_runner_applications_for_enterprise(enterprise),
+        )
+    }
+    /// The blocking version of [`crate::enterprise_admin::EnterpriseAdmin::list_all_runner_applications_for_enterprise`].
+    pub fn list_all_runner_applications_for_enterprise(
+        &self,
+        enterprise: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::RunnerApplication>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_all_runner_applications_for_enterprise(enterprise),
+        )
+    }
+    /// The blocking version of [`crate::enterprise_admin::EnterpriseAdmin::create_registration_token_for_enterprise`].
+    pub fn create_registration_token_for_enterprise(
+        &self,
+        enterprise: &str,
+    ) -> ClientResult<crate::Response<crate::types::AuthenticationToken>> {
+        self.runtime.block_on(
+            self.inner
+                .create_registration_token_for_enterprise(enterprise),
+        )
+    }
+    /// The blocking version of [`crate::enterprise_admin::EnterpriseAdmin::create_remove_token_for_enterprise`].
+    pub fn create_remove_token_for_enterprise(
+        &self,
+        enterprise: &str,
+    ) -> ClientResult<crate::Response<crate::types::AuthenticationToken>> {
+        self.runtime
+            .block_on(self.inner.create_remove_token_for_enterprise(enterprise))
+    }
+    /// The blocking version of [`crate::enterprise_admin::EnterpriseAdmin::get_self_hosted_runner_for_enterprise`].
+    pub fn get_self_hosted_runner_for_enterprise(
+        &self,
+        enterprise: &str,
+        runner_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::Runner>> {
+        self.runtime.block_on(
+            self.inner
+                .get_self_hosted_runner_for_enterprise(enterprise, runner_id),
+        )
+    }
+    /// The blocking version of [`crate::enterprise_admin::EnterpriseAdmin::delete_self_hosted_runner_from_enterprise`].
+    pub fn delete_self_hosted_runner_from_enterprise(
+        &self,
+        enterprise: &str,
+        runner_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .delete_self_hosted_runner_from_enterprise(enterprise, runner_id),
+        )
+    }
+    /// The blocking version of [`crate::enterprise_admin::EnterpriseAdmin::get_audit_log`].
+    pub fn get_audit_log(
+        &self,
+        enterprise: &str,
+        phrase: &str,
+        include: crate::types::Include,
+        after: &str,
+        before: &str,
+        order: crate::types::Order,
+        page: i64,
+        per_page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::AuditLogEvent>>> {
+        self.runtime.block_on(self.inner.get_audit_log(
+            enterprise, phrase, include, after, before, order, page, per_page,
+        ))
+    }
+    /// The blocking version of [`crate::enterprise_admin::EnterpriseAdmin::get_audit_log_request`].
+    pub fn get_audit_log_request<'a>(
+        &'a self,
+        enterprise: &'a str,
+    ) -> EnterpriseAdminGetAuditLogRequest<'a> {
+        EnterpriseAdminGetAuditLogRequest {
+            inner: self.inner.get_audit_log_request(enterprise),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::enterprise_admin::EnterpriseAdmin::get_all_audit_log`].
+    pub fn get_all_audit_log(
+        &self,
+        enterprise: &str,
+        phrase: &str,
+        include: crate::types::Include,
+        after: &str,
+        before: &str,
+        order: crate::types::Order,
+    ) -> ClientResult<crate::Response<Vec<crate::types::AuditLogEvent>>> {
+        self.runtime.block_on(
+            self.inner
+                .get_all_audit_log(enterprise, phrase, include, after, before, order),
+        )
+    }
+    /// The blocking version of [`crate::enterprise_admin::EnterpriseAdmin::list_provisioned_groups_enterprise`].
+    pub fn list_provisioned_groups_enterprise(
+        &self,
+        enterprise: &str,
+        start_index: i64,
+        count: i64,
+        filter: &str,
+        excluded_attributes: &str,
+    ) -> ClientResult<crate::Response<crate::types::ScimGroupListEnterprise>> {
+        self.runtime
+            .block_on(self.inner.list_provisioned_groups_enterprise(
+                enterprise,
+                start_index,
+                count,
+                filter,
+                excluded_attributes,
+            ))
+    }
+    /// The blocking version of [`crate::enterprise_admin::EnterpriseAdmin::list_provisioned_groups_enterprise_request`].
+    pub fn list_provisioned_groups_enterprise_request<'a>(
+        &'a self,
+        enterprise: &'a str,
+    ) -> EnterpriseAdminListProvisionedGroupsEnterpriseRequest<'a> {
+        EnterpriseAdminListProvisionedGroupsEnterpriseRequest {
+            inner: self
+                .inner
+                .list_provisioned_groups_enterprise_request(enterprise),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::enterprise_admin::EnterpriseAdmin::provision_and_invite_enterprise_group`].
+    pub fn provision_and_invite_enterprise_group(
+        &self,
+        enterprise: &str,
+        body: &crate::types::EnterpriseAdminProvisionInviteGroupRequest,
+    ) -> ClientResult<crate::Response<crate::types::ScimEnterpriseGroup>> {
+        self.runtime.block_on(
+            self.inner
+                .provision_and_invite_enterprise_group(enterprise, body),
+        )
+    }
+    /// The blocking version of [`crate::enterprise_admin::EnterpriseAdmin::get_provisioning_information_for_enterprise_group`].
+    pub fn get_provisioning_information_for_enterprise_group(
+        &self,
+        enterprise: &str,
+        scim_group_id: &str,
+        excluded_attributes: &str,
+    ) -> ClientResult<crate::Response<crate::types::ScimEnterpriseGroup>> {
+        self.runtime.block_on(
+            self.inner
+                .get_provisioning_information_for_enterprise_group(
+                    enterprise,
+                    scim_group_id,
+                    excluded_attributes,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::enterprise_admin::EnterpriseAdmin::get_provisioning_information_for_enterprise_group_request`].
+    pub fn get_provisioning_information_for_enterprise_group_request<'a>(
+        &'a self,
+        enterprise: &'a str,
+        scim_group_id: &'a str,
+    ) -> EnterpriseAdminGetProvisioningInformationForEnterpriseGroupRequest<'a> {
+        EnterpriseAdminGetProvisioningInformationForEnterpriseGroupRequest {
+            inner: self
+                .inner
+                .get_provisioning_information_for_enterprise_group_request(
+                    enterprise,
+                    scim_group_id,
+                ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::enterprise_admin::EnterpriseAdmin::set_information_for_provisioned_enterprise_group`].
+    pub fn set_information_for_provisioned_enterprise_group(
+        &self,
+        enterprise: &str,
+        scim_group_id: &str,
+        body: &crate::types::EnterpriseAdminProvisionInviteGroupRequest,
+    ) -> ClientResult<crate::Response<crate::types::ScimEnterpriseGroup>> {
+        self.runtime
+            .block_on(self.inner.set_information_for_provisioned_enterprise_group(
+                enterprise,
+                scim_group_id,
+                body,
+            ))
+    }
+    /// The blocking version of [`crate::enterprise_admin::EnterpriseAdmin::delete_scim_group_from_enterprise`].
+    pub fn delete_scim_group_from_enterprise(
+        &self,
+        enterprise: &str,
+        scim_group_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .delete_scim_group_from_enterprise(enterprise, scim_group_id),
+        )
+    }
+    /// The blocking version of [`crate::enterprise_admin::EnterpriseAdmin::update_attribute_for_enterprise_group`].
+    pub fn update_attribute_for_enterprise_group(
+        &self,
+        enterprise: &str,
+        scim_group_id: &str,
+        body: &crate::types::EnterpriseAdminUpdateAttributeGroupRequest,
+    ) -> ClientResult<crate::Response<crate::types::ScimEnterpriseGroup>> {
+        self.runtime
+            .block_on(self.inner.update_attribute_for_enterprise_group(
+                enterprise,
+                scim_group_id,
+                body,
+            ))
+    }
+    /// The blocking version of [`crate::enterprise_admin::EnterpriseAdmin::list_provisioned_identities_enterprise`].
+    pub fn list_provisioned_identities_enterprise(
+        &self,
+        enterprise: &str,
+        start_index: i64,
+        count: i64,
+        filter: &str,
+    ) -> ClientResult<crate::Response<crate::types::ScimUserListEnterprise>> {
+        self.runtime
+            .block_on(self.inner.list_provisioned_identities_enterprise(
+                enterprise,
+                start_index,
+                count,
+                filter,
+            ))
+    }
+    /// The blocking version of [`crate::enterprise_admin::EnterpriseAdmin::list_provisioned_identities_enterprise_request`].
+    pub fn list_provisioned_identities_enterprise_request<'a>(
+        &'a self,
+        enterprise: &'a str,
+    ) -> EnterpriseAdminListProvisionedIdentitiesEnterpriseRequest<'a> {
+        EnterpriseAdminListProvisionedIdentitiesEnterpriseRequest {
+            inner: self
+                .inner
+                .list_provisioned_identities_enterprise_request(enterprise),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::enterprise_admin::EnterpriseAdmin::provision_and_invite_enterprise_user`].
+    pub fn provision_and_invite_enterprise_user(
+        &self,
+        enterprise: &str,
+        body: &crate::types::EnterpriseAdminProvisionInviteUserRequest,
+    ) -> ClientResult<crate::Response<crate::types::ScimEnterpriseUser>> {
+        self.runtime.block_on(
+            self.inner
+                .provision_and_invite_enterprise_user(enterprise, body),
+        )
+    }
+    /// The blocking version of [`crate::enterprise_admin::EnterpriseAdmin::get_provisioning_information_for_enterprise_user`].
+    pub fn get_provisioning_information_for_enterprise_user(
+        &self,
+        enterprise: &str,
+        scim_user_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ScimEnterpriseUser>> {
+        self.runtime.block_on(
+            self.inner
+                .get_provisioning_information_for_enterprise_user(enterprise, scim_user_id),
+        )
+    }
+    /// The blocking version of [`crate::enterprise_admin::EnterpriseAdmin::set_information_for_provisioned_enterprise_user`].
+    pub fn set_information_for_provisioned_enterprise_user(
+        &self,
+        enterprise: &str,
+        scim_user_id: &str,
+        body: &crate::types::EnterpriseAdminProvisionInviteUserRequest,
+    ) -> ClientResult<crate::Response<crate::types::ScimEnterpriseUser>> {
+        self.runtime
+            .block_on(self.inner.set_information_for_provisioned_enterprise_user(
+                enterprise,
+                scim_user_id,
+                body,
+            ))
+    }
+    /// The blocking version of [`crate::enterprise_admin::EnterpriseAdmin::delete_user_from_enterprise`].
+    pub fn delete_user_from_enterprise(
+        &self,
+        enterprise: &str,
+        scim_user_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .delete_user_from_enterprise(enterprise, scim_user_id),
+        )
+    }
+    /// The blocking version of [`crate::enterprise_admin::EnterpriseAdmin::update_attribute_for_enterprise_user`].
+    pub fn update_attribute_for_enterprise_user(
+        &self,
+        enterprise: &str,
+        scim_user_id: &str,
+        body: &crate::types::EnterpriseAdminUpdateAttributeUserRequest,
+    ) -> ClientResult<crate::Response<crate::types::ScimEnterpriseUser>> {
+        self.runtime
+            .block_on(self.inner.update_attribute_for_enterprise_user(
+                enterprise,
+                scim_user_id,
+                body,
+            ))
+    }
+}
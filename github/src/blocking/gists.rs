@@ -0,0 +1,470 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Gists::list`], set with a builder.
+pub struct GistsListRequest<'a> {
+    inner: crate::gists::GistsListRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> GistsListRequest<'a> {
+    /// Sets the `since` query parameter.
+    pub fn since(mut self, since: chrono::DateTime<chrono::Utc>) -> Self {
+        self.inner = self.inner.since(since);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::BaseGist>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Gists::list_public`], set with a builder.
+pub struct GistsListPublicRequest<'a> {
+    inner: crate::gists::GistsListPublicRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> GistsListPublicRequest<'a> {
+    /// Sets the `since` query parameter.
+    pub fn since(mut self, since: chrono::DateTime<chrono::Utc>) -> Self {
+        self.inner = self.inner.since(since);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::BaseGist>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Gists::list_starred`], set with a builder.
+pub struct GistsListStarredRequest<'a> {
+    inner: crate::gists::GistsListStarredRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> GistsListStarredRequest<'a> {
+    /// Sets the `since` query parameter.
+    pub fn since(mut self, since: chrono::DateTime<chrono::Utc>) -> Self {
+        self.inner = self.inner.since(since);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::BaseGist>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Gists::list_comments`], set with a builder.
+pub struct GistsListCommentsRequest<'a> {
+    inner: crate::gists::GistsListCommentsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> GistsListCommentsRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::GistComment>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Gists::list_commits`], set with a builder.
+pub struct GistsListCommitsRequest<'a> {
+    inner: crate::gists::GistsListCommitsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> GistsListCommitsRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::GistCommit>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Gists::list_forks`], set with a builder.
+pub struct GistsListForksRequest<'a> {
+    inner: crate::gists::GistsListForksRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> GistsListForksRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::GistSimple>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Gists::list_for_user`], set with a builder.
+pub struct GistsListForUserRequest<'a> {
+    inner: crate::gists::GistsListForUserRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> GistsListForUserRequest<'a> {
+    /// Sets the `since` query parameter.
+    pub fn since(mut self, since: chrono::DateTime<chrono::Utc>) -> Self {
+        self.inner = self.inner.since(since);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::BaseGist>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::gists::Gists`].
+pub struct Gists {
+    inner: crate::gists::Gists,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Gists {
+    pub(crate) fn new(inner: crate::gists::Gists, runtime: Arc<tokio::runtime::Runtime>) -> Self {
+        Gists { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::gists::Gists::list`].
+    pub fn list(
+        &self,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::BaseGist>>> {
+        self.runtime
+            .block_on(self.inner.list(since, per_page, page))
+    }
+    /// The blocking version of [`crate::gists::Gists::list_request`].
+    pub fn list_request(&self) -> GistsListRequest<'_> {
+        GistsListRequest {
+            inner: self.inner.list_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::gists::Gists::list_all`].
+    pub fn list_all(
+        &self,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> ClientResult<crate::Response<Vec<crate::types::BaseGist>>> {
+        self.runtime.block_on(self.inner.list_all(since))
+    }
+    /// The blocking version of [`crate::gists::Gists::create`].
+    pub fn create(
+        &self,
+        body: &crate::types::GistsCreateRequest,
+    ) -> ClientResult<crate::Response<crate::types::GistSimple>> {
+        self.runtime.block_on(self.inner.create(body))
+    }
+    /// The blocking version of [`crate::gists::Gists::list_public`].
+    pub fn list_public(
+        &self,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::BaseGist>>> {
+        self.runtime
+            .block_on(self.inner.list_public(since, per_page, page))
+    }
+    /// The blocking version of [`crate::gists::Gists::list_public_request`].
+    pub fn list_public_request(&self) -> GistsListPublicRequest<'_> {
+        GistsListPublicRequest {
+            inner: self.inner.list_public_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::gists::Gists::list_all_public`].
+    pub fn list_all_public(
+        &self,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> ClientResult<crate::Response<Vec<crate::types::BaseGist>>> {
+        self.runtime.block_on(self.inner.list_all_public(since))
+    }
+    /// The blocking version of [`crate::gists::Gists::list_starred`].
+    pub fn list_starred(
+        &self,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::BaseGist>>> {
+        self.runtime
+            .block_on(self.inner.list_starred(since, per_page, page))
+    }
+    /// The blocking version of [`crate::gists::Gists::list_starred_request`].
+    pub fn list_starred_request(&self) -> GistsListStarredRequest<'_> {
+        GistsListStarredRequest {
+            inner: self.inner.list_starred_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::gists::Gists::list_all_starred`].
+    pub fn list_all_starred(
+        &self,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> ClientResult<crate::Response<Vec<crate::types::BaseGist>>> {
+        self.runtime.block_on(self.inner.list_all_starred(since))
+    }
+    /// The blocking version of [`crate::gists::Gists::get`].
+    pub fn get(&self, gist_id: &str) -> ClientResult<crate::Response<crate::types::GistSimple>> {
+        self.runtime.block_on(self.inner.get(gist_id))
+    }
+    /// The blocking version of [`crate::gists::Gists::delete`].
+    pub fn delete(&self, gist_id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.delete(gist_id))
+    }
+    /// The blocking version of [`crate::gists::Gists::update`].
+    pub fn update(
+        &self,
+        gist_id: &str,
+        body: &crate::types::GistsUpdateRequest,
+    ) -> ClientResult<crate::Response<crate::types::GistSimple>> {
+        self.runtime.block_on(self.inner.update(gist_id, body))
+    }
+    /// The blocking version of [`crate::gists::Gists::list_comments`].
+    pub fn list_comments(
+        &self,
+        gist_id: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GistComment>>> {
+        self.runtime
+            .block_on(self.inner.list_comments(gist_id, per_page, page))
+    }
+    /// The blocking version of [`crate::gists::Gists::list_comments_request`].
+    pub fn list_comments_request<'a>(&'a self, gist_id: &'a str) -> GistsListCommentsRequest<'a> {
+        GistsListCommentsRequest {
+            inner: self.inner.list_comments_request(gist_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::gists::Gists::list_all_comments`].
+    pub fn list_all_comments(
+        &self,
+        gist_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GistComment>>> {
+        self.runtime.block_on(self.inner.list_all_comments(gist_id))
+    }
+    /// The blocking version of [`crate::gists::Gists::create_comment`].
+    pub fn create_comment(
+        &self,
+        gist_id: &str,
+        body: &crate::types::PullsUpdateReviewRequest,
+    ) -> ClientResult<crate::Response<crate::types::GistComment>> {
+        self.runtime
+            .block_on(self.inner.create_comment(gist_id, body))
+    }
+    /// The blocking version of [`crate::gists::Gists::get_comment`].
+    pub fn get_comment(
+        &self,
+        gist_id: &str,
+        comment_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::GistComment>> {
+        self.runtime
+            .block_on(self.inner.get_comment(gist_id, comment_id))
+    }
+    /// The blocking version of [`crate::gists::Gists::delete_comment`].
+    pub fn delete_comment(
+        &self,
+        gist_id: &str,
+        comment_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete_comment(gist_id, comment_id))
+    }
+    /// The blocking version of [`crate::gists::Gists::update_comment`].
+    pub fn update_comment(
+        &self,
+        gist_id: &str,
+        comment_id: i64,
+        body: &crate::types::PullsUpdateReviewRequest,
+    ) -> ClientResult<crate::Response<crate::types::GistComment>> {
+        self.runtime
+            .block_on(self.inner.update_comment(gist_id, comment_id, body))
+    }
+    /// The blocking version of [`crate::gists::Gists::list_commits`].
+    pub fn list_commits(
+        &self,
+        gist_id: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GistCommit>>> {
+        self.runtime
+            .block_on(self.inner.list_commits(gist_id, per_page, page))
+    }
+    /// The blocking version of [`crate::gists::Gists::list_commits_request`].
+    pub fn list_commits_request<'a>(&'a self, gist_id: &'a str) -> GistsListCommitsRequest<'a> {
+        GistsListCommitsRequest {
+            inner: self.inner.list_commits_request(gist_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::gists::Gists::list_all_commits`].
+    pub fn list_all_commits(
+        &self,
+        gist_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GistCommit>>> {
+        self.runtime.block_on(self.inner.list_all_commits(gist_id))
+    }
+    /// The blocking version of [`crate::gists::Gists::list_forks`].
+    pub fn list_forks(
+        &self,
+        gist_id: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GistSimple>>> {
+        self.runtime
+            .block_on(self.inner.list_forks(gist_id, per_page, page))
+    }
+    /// The blocking version of [`crate::gists::Gists::list_forks_request`].
+    pub fn list_forks_request<'a>(&'a self, gist_id: &'a str) -> GistsListForksRequest<'a> {
+        GistsListForksRequest {
+            inner: self.inner.list_forks_request(gist_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::gists::Gists::list_all_forks`].
+    pub fn list_all_forks(
+        &self,
+        gist_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GistSimple>>> {
+        self.runtime.block_on(self.inner.list_all_forks(gist_id))
+    }
+    /// The blocking version of [`crate::gists::Gists::fork`].
+    pub fn fork(&self, gist_id: &str) -> ClientResult<crate::Response<crate::types::BaseGist>> {
+        self.runtime.block_on(self.inner.fork(gist_id))
+    }
+    /// The blocking version of [`crate::gists::Gists::check_is_starred`].
+    pub fn check_is_starred(&self, gist_id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.check_is_starred(gist_id))
+    }
+    /// The blocking version of [`crate::gists::Gists::star`].
+    pub fn star(&self, gist_id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.star(gist_id))
+    }
+    /// The blocking version of [`crate::gists::Gists::unstar`].
+    pub fn unstar(&self, gist_id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.unstar(gist_id))
+    }
+    /// The blocking version of [`crate::gists::Gists::get_revision`].
+    pub fn get_revision(
+        &self,
+        gist_id: &str,
+        sha: &str,
+    ) -> ClientResult<crate::Response<crate::types::GistSimple>> {
+        self.runtime.block_on(self.inner.get_revision(gist_id, sha))
+    }
+    /// The blocking version of [`crate::gists::Gists::list_for_user`].
+    pub fn list_for_user(
+        &self,
+        username: &str,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::BaseGist>>> {
+        self.runtime
+            .block_on(self.inner.list_for_user(username, since, per_page, page))
+    }
+    /// The blocking version of [`crate::gists::Gists::list_for_user_request`].
+    pub fn list_for_user_request<'a>(&'a self, username: &'a str) -> GistsListForUserRequest<'a> {
+        GistsListForUserRequest {
+            inner: self.inner.list_for_user_request(username),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::gists::Gists::list_all_for_user`].
+    pub fn list_all_for_user(
+        &self,
+        username: &str,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> ClientResult<crate::Response<Vec<crate::types::BaseGist>>> {
+        self.runtime
+            .block_on(self.inner.list_all_for_user(username, since))
+    }
+}
@@ -0,0 +1,229 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Git::list_matching_refs`], set with a builder.
+pub struct GitListMatchingRefsRequest<'a> {
+    inner: crate::git::GitListMatchingRefsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> GitListMatchingRefsRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::GitRef>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Git::get_tree`], set with a builder.
+pub struct GitGetTreeRequest<'a> {
+    inner: crate::git::GitGetTreeRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> GitGetTreeRequest<'a> {
+    /// Sets the `recursive` query parameter.
+    pub fn recursive(mut self, recursive: &'a str) -> Self {
+        self.inner = self.inner.recursive(recursive);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::GitTreeData>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::git::Git`].
+pub struct Git {
+    inner: crate::git::Git,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Git {
+    pub(crate) fn new(inner: crate::git::Git, runtime: Arc<tokio::runtime::Runtime>) -> Self {
+        Git { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::git::Git::create_blob`].
+    pub fn create_blob(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::GitCreateBlobRequest,
+    ) -> ClientResult<crate::Response<crate::types::Tree>> {
+        self.runtime
+            .block_on(self.inner.create_blob(owner, repo, body))
+    }
+    /// The blocking version of [`crate::git::Git::get_blob`].
+    pub fn get_blob(
+        &self,
+        owner: &str,
+        repo: &str,
+        file_sha: &str,
+    ) -> ClientResult<crate::Response<crate::types::Blob>> {
+        self.runtime
+            .block_on(self.inner.get_blob(owner, repo, file_sha))
+    }
+    /// The blocking version of [`crate::git::Git::create_commit`].
+    pub fn create_commit(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::GitCreateCommitRequest,
+    ) -> ClientResult<crate::Response<crate::types::GitCommit>> {
+        self.runtime
+            .block_on(self.inner.create_commit(owner, repo, body))
+    }
+    /// The blocking version of [`crate::git::Git::get_commit`].
+    pub fn get_commit(
+        &self,
+        owner: &str,
+        repo: &str,
+        commit_sha: &str,
+    ) -> ClientResult<crate::Response<crate::types::GitCommit>> {
+        self.runtime
+            .block_on(self.inner.get_commit(owner, repo, commit_sha))
+    }
+    /// The blocking version of [`crate::git::Git::list_matching_refs`].
+    pub fn list_matching_refs(
+        &self,
+        owner: &str,
+        repo: &str,
+        ref_: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GitRef>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_matching_refs(owner, repo, ref_, per_page, page),
+        )
+    }
+    /// The blocking version of [`crate::git::Git::list_matching_refs_request`].
+    pub fn list_matching_refs_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+        ref_: &'a str,
+    ) -> GitListMatchingRefsRequest<'a> {
+        GitListMatchingRefsRequest {
+            inner: self.inner.list_matching_refs_request(owner, repo, ref_),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::git::Git::list_all_matching_refs`].
+    pub fn list_all_matching_refs(
+        &self,
+        owner: &str,
+        repo: &str,
+        ref_: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GitRef>>> {
+        self.runtime
+            .block_on(self.inner.list_all_matching_refs(owner, repo, ref_))
+    }
+    /// The blocking version of [`crate::git::Git::get_ref`].
+    pub fn get_ref(
+        &self,
+        owner: &str,
+        repo: &str,
+        ref_: &str,
+    ) -> ClientResult<crate::Response<crate::types::GitRef>> {
+        self.runtime.block_on(self.inner.get_ref(owner, repo, ref_))
+    }
+    /// The blocking version of [`crate::git::Git::create_ref`].
+    pub fn create_ref(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::GitCreateRefRequest,
+    ) -> ClientResult<crate::Response<crate::types::GitRef>> {
+        self.runtime
+            .block_on(self.inner.create_ref(owner, repo, body))
+    }
+    /// The blocking version of [`crate::git::Git::delete_ref`].
+    pub fn delete_ref(
+        &self,
+        owner: &str,
+        repo: &str,
+        ref_: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete_ref(owner, repo, ref_))
+    }
+    /// The blocking version of [`crate::git::Git::update_ref`].
+    pub fn update_ref(
+        &self,
+        owner: &str,
+        repo: &str,
+        ref_: &str,
+        body: &crate::types::GitUpdateRefRequest,
+    ) -> ClientResult<crate::Response<crate::types::GitRef>> {
+        self.runtime
+            .block_on(self.inner.update_ref(owner, repo, ref_, body))
+    }
+    /// The blocking version of [`crate::git::Git::create_tag`].
+    pub fn create_tag(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::GitCreateTagRequest,
+    ) -> ClientResult<crate::Response<crate::types::GitTag>> {
+        self.runtime
+            .block_on(self.inner.create_tag(owner, repo, body))
+    }
+    /// The blocking version of [`crate::git::Git::get_tag`].
+    pub fn get_tag(
+        &self,
+        owner: &str,
+        repo: &str,
+        tag_sha: &str,
+    ) -> ClientResult<crate::Response<crate::types::GitTag>> {
+        self.runtime
+            .block_on(self.inner.get_tag(owner, repo, tag_sha))
+    }
+    /// The blocking version of [`crate::git::Git::create_tree`].
+    pub fn create_tree(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::GitCreateTreeRequestData,
+    ) -> ClientResult<crate::Response<crate::types::GitTreeData>> {
+        self.runtime
+            .block_on(self.inner.create_tree(owner, repo, body))
+    }
+    /// The blocking version of [`crate::git::Git::get_tree`].
+    pub fn get_tree(
+        &self,
+        owner: &str,
+        repo: &str,
+        tree_sha: &str,
+        recursive: &str,
+    ) -> ClientResult<crate::Response<crate::types::GitTreeData>> {
+        self.runtime
+            .block_on(self.inner.get_tree(owner, repo, tree_sha, recursive))
+    }
+    /// The blocking version of [`crate::git::Git::get_tree_request`].
+    pub fn get_tree_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+        tree_sha: &'a str,
+    ) -> GitGetTreeRequest<'a> {
+        GitGetTreeRequest {
+            inner: self.inner.get_tree_request(owner, repo, tree_sha),
+            runtime: &self.runtime,
+        }
+    }
+}
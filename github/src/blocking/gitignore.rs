@@ -0,0 +1,34 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::gitignore::Gitignore`].
+pub struct Gitignore {
+    inner: crate::gitignore::Gitignore,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Gitignore {
+    pub(crate) fn new(
+        inner: crate::gitignore::Gitignore,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Gitignore { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::gitignore::Gitignore::get_all_templates`].
+    pub fn get_all_templates(&self) -> ClientResult<crate::Response<Vec<String>>> {
+        self.runtime.block_on(self.inner.get_all_templates())
+    }
+    /// The blocking version of [`crate::gitignore::Gitignore::get_all_all_templates`].
+    pub fn get_all_all_templates(&self) -> ClientResult<crate::Response<Vec<String>>> {
+        self.runtime.block_on(self.inner.get_all_all_templates())
+    }
+    /// The blocking version of [`crate::gitignore::Gitignore::get_template`].
+    pub fn get_template(
+        &self,
+        name: &str,
+    ) -> ClientResult<crate::Response<crate::types::GitignoreTemplate>> {
+        self.runtime.block_on(self.inner.get_template(name))
+    }
+}
@@ -0,0 +1,89 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::interactions::Interactions`].
+pub struct Interactions {
+    inner: crate::interactions::Interactions,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Interactions {
+    pub(crate) fn new(
+        inner: crate::interactions::Interactions,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Interactions { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::interactions::Interactions::get_restrictions_for_org`].
+    pub fn get_restrictions_for_org(
+        &self,
+        org: &str,
+    ) -> ClientResult<crate::Response<crate::types::InteractionsGetRestrictionsResponseAnyOf>> {
+        self.runtime
+            .block_on(self.inner.get_restrictions_for_org(org))
+    }
+    /// The blocking version of [`crate::interactions::Interactions::set_restrictions_for_org`].
+    pub fn set_restrictions_for_org(
+        &self,
+        org: &str,
+        body: &crate::types::InteractionLimit,
+    ) -> ClientResult<crate::Response<crate::types::InteractionLimits>> {
+        self.runtime
+            .block_on(self.inner.set_restrictions_for_org(org, body))
+    }
+    /// The blocking version of [`crate::interactions::Interactions::remove_restrictions_for_org`].
+    pub fn remove_restrictions_for_org(&self, org: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.remove_restrictions_for_org(org))
+    }
+    /// The blocking version of [`crate::interactions::Interactions::get_restrictions_for_repo`].
+    pub fn get_restrictions_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> ClientResult<crate::Response<crate::types::InteractionsGetRestrictionsResponseAnyOf>> {
+        self.runtime
+            .block_on(self.inner.get_restrictions_for_repo(owner, repo))
+    }
+    /// The blocking version of [`crate::interactions::Interactions::set_restrictions_for_repo`].
+    pub fn set_restrictions_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::InteractionLimit,
+    ) -> ClientResult<crate::Response<crate::types::InteractionLimits>> {
+        self.runtime
+            .block_on(self.inner.set_restrictions_for_repo(owner, repo, body))
+    }
+    /// The blocking version of [`crate::interactions::Interactions::remove_restrictions_for_repo`].
+    pub fn remove_restrictions_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.remove_restrictions_for_repo(owner, repo))
+    }
+    /// The blocking version of [`crate::interactions::Interactions::get_restrictions_for_authenticated_user`].
+    pub fn get_restrictions_for_authenticated_user(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::InteractionsGetRestrictionsResponseAnyOf>> {
+        self.runtime
+            .block_on(self.inner.get_restrictions_for_authenticated_user())
+    }
+    /// The blocking version of [`crate::interactions::Interactions::set_restrictions_for_authenticated_user`].
+    pub fn set_restrictions_for_authenticated_user(
+        &self,
+        body: &crate::types::InteractionLimit,
+    ) -> ClientResult<crate::Response<crate::types::InteractionLimits>> {
+        self.runtime
+            .block_on(self.inner.set_restrictions_for_authenticated_user(body))
+    }
+    /// The blocking version of [`crate::interactions::Interactions::remove_restrictions_for_authenticated_user`].
+    pub fn remove_restrictions_for_authenticated_user(&self) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.remove_restrictions_for_authenticated_user())
+    }
+}
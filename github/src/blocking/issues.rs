@@ -0,0 +1,1419 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Issues::list`], set with a builder.
+pub struct IssuesListRequest<'a> {
+    inner: crate::issues::IssuesListRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> IssuesListRequest<'a> {
+    /// Sets the `filter` query parameter.
+    pub fn filter(mut self, filter: crate::types::Filter) -> Self {
+        self.inner = self.inner.filter(filter);
+        self
+    }
+
+    /// Sets the `state` query parameter.
+    pub fn state(mut self, state: crate::types::IssuesListState) -> Self {
+        self.inner = self.inner.state(state);
+        self
+    }
+
+    /// Sets the `labels` query parameter.
+    pub fn labels(mut self, labels: &'a str) -> Self {
+        self.inner = self.inner.labels(labels);
+        self
+    }
+
+    /// Sets the `sort` query parameter.
+    pub fn sort(mut self, sort: crate::types::IssuesListSort) -> Self {
+        self.inner = self.inner.sort(sort);
+        self
+    }
+
+    /// Sets the `direction` query parameter.
+    pub fn direction(mut self, direction: crate::types::Order) -> Self {
+        self.inner = self.inner.direction(direction);
+        self
+    }
+
+    /// Sets the `since` query parameter.
+    pub fn since(mut self, since: chrono::DateTime<chrono::Utc>) -> Self {
+        self.inner = self.inner.since(since);
+        self
+    }
+
+    /// Sets the `collab` query parameter.
+    pub fn collab(mut self, collab: bool) -> Self {
+        self.inner = self.inner.collab(collab);
+        self
+    }
+
+    /// Sets the `orgs` query parameter.
+    pub fn orgs(mut self, orgs: bool) -> Self {
+        self.inner = self.inner.orgs(orgs);
+        self
+    }
+
+    /// Sets the `owned` query parameter.
+    pub fn owned(mut self, owned: bool) -> Self {
+        self.inner = self.inner.owned(owned);
+        self
+    }
+
+    /// Sets the `pulls` query parameter.
+    pub fn pulls(mut self, pulls: bool) -> Self {
+        self.inner = self.inner.pulls(pulls);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Issue>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Issues::list_for_org`], set with a builder.
+pub struct IssuesListForOrgRequest<'a> {
+    inner: crate::issues::IssuesListForOrgRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> IssuesListForOrgRequest<'a> {
+    /// Sets the `filter` query parameter.
+    pub fn filter(mut self, filter: crate::types::Filter) -> Self {
+        self.inner = self.inner.filter(filter);
+        self
+    }
+
+    /// Sets the `state` query parameter.
+    pub fn state(mut self, state: crate::types::IssuesListState) -> Self {
+        self.inner = self.inner.state(state);
+        self
+    }
+
+    /// Sets the `labels` query parameter.
+    pub fn labels(mut self, labels: &'a str) -> Self {
+        self.inner = self.inner.labels(labels);
+        self
+    }
+
+    /// Sets the `sort` query parameter.
+    pub fn sort(mut self, sort: crate::types::IssuesListSort) -> Self {
+        self.inner = self.inner.sort(sort);
+        self
+    }
+
+    /// Sets the `direction` query parameter.
+    pub fn direction(mut self, direction: crate::types::Order) -> Self {
+        self.inner = self.inner.direction(direction);
+        self
+    }
+
+    /// Sets the `since` query parameter.
+    pub fn since(mut self, since: chrono::DateTime<chrono::Utc>) -> Self {
+        self.inner = self.inner.since(since);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Issue>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Issues::list_assignees`], set with a builder.
+pub struct IssuesListAssigneesRequest<'a> {
+    inner: crate::issues::IssuesListAssigneesRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> IssuesListAssigneesRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::SimpleUser>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Issues::list_for_repo`], set with a builder.
+pub struct IssuesListForRepoRequest<'a> {
+    inner: crate::issues::IssuesListForRepoRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> IssuesListForRepoRequest<'a> {
+    /// Sets the `milestone` query parameter.
+    pub fn milestone(mut self, milestone: &'a str) -> Self {
+        self.inner = self.inner.milestone(milestone);
+        self
+    }
+
+    /// Sets the `state` query parameter.
+    pub fn state(mut self, state: crate::types::IssuesListState) -> Self {
+        self.inner = self.inner.state(state);
+        self
+    }
+
+    /// Sets the `assignee` query parameter.
+    pub fn assignee(mut self, assignee: &'a str) -> Self {
+        self.inner = self.inner.assignee(assignee);
+        self
+    }
+
+    /// Sets the `creator` query parameter.
+    pub fn creator(mut self, creator: &'a str) -> Self {
+        self.inner = self.inner.creator(creator);
+        self
+    }
+
+    /// Sets the `mentioned` query parameter.
+    pub fn mentioned(mut self, mentioned: &'a str) -> Self {
+        self.inner = self.inner.mentioned(mentioned);
+        self
+    }
+
+    /// Sets the `labels` query parameter.
+    pub fn labels(mut self, labels: &'a str) -> Self {
+        self.inner = self.inner.labels(labels);
+        self
+    }
+
+    /// Sets the `sort` query parameter.
+    pub fn sort(mut self, sort: crate::types::IssuesListSort) -> Self {
+        self.inner = self.inner.sort(sort);
+        self
+    }
+
+    /// Sets the `direction` query parameter.
+    pub fn direction(mut self, direction: crate::types::Order) -> Self {
+        self.inner = self.inner.direction(direction);
+        self
+    }
+
+    /// Sets the `since` query parameter.
+    pub fn since(mut self, since: chrono::DateTime<chrono::Utc>) -> Self {
+        self.inner = self.inner.since(since);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::IssueSimple>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Issues::list_comments_for_repo`], set with a builder.
+pub struct IssuesListCommentsForRepoRequest<'a> {
+    inner: crate::issues::IssuesListCommentsForRepoRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> IssuesListCommentsForRepoRequest<'a> {
+    /// Sets the `sort` query parameter.
+    pub fn sort(mut self, sort: crate::types::Sort) -> Self {
+        self.inner = self.inner.sort(sort);
+        self
+    }
+
+    /// Sets the `direction` query parameter.
+    pub fn direction(mut self, direction: crate::types::Order) -> Self {
+        self.inner = self.inner.direction(direction);
+        self
+    }
+
+    /// Sets the `since` query parameter.
+    pub fn since(mut self, since: chrono::DateTime<chrono::Utc>) -> Self {
+        self.inner = self.inner.since(since);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::IssueComment>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Issues::list_events_for_repo`], set with a builder.
+pub struct IssuesListEventsForRepoRequest<'a> {
+    inner: crate::issues::IssuesListEventsForRepoRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> IssuesListEventsForRepoRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::IssueEvent>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Issues::list_comments`], set with a builder.
+pub struct IssuesListCommentsRequest<'a> {
+    inner: crate::issues::IssuesListCommentsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> IssuesListCommentsRequest<'a> {
+    /// Sets the `since` query parameter.
+    pub fn since(mut self, since: chrono::DateTime<chrono::Utc>) -> Self {
+        self.inner = self.inner.since(since);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::IssueComment>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Issues::list_events`], set with a builder.
+pub struct IssuesListEventsRequest<'a> {
+    inner: crate::issues::IssuesListEventsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> IssuesListEventsRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::IssueEventAnyOf>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Issues::list_labels_on_issue`], set with a builder.
+pub struct IssuesListLabelsOnIssueRequest<'a> {
+    inner: crate::issues::IssuesListLabelsOnIssueRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> IssuesListLabelsOnIssueRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Label>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Issues::list_events_for_timeline`], set with a builder.
+pub struct IssuesListEventsForTimelineRequest<'a> {
+    inner: crate::issues::IssuesListEventsForTimelineRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> IssuesListEventsForTimelineRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Data>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Issues::list_labels_for_repo`], set with a builder.
+pub struct IssuesListLabelsForRepoRequest<'a> {
+    inner: crate::issues::IssuesListLabelsForRepoRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> IssuesListLabelsForRepoRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Label>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Issues::list_milestones`], set with a builder.
+pub struct IssuesListMilestonesRequest<'a> {
+    inner: crate::issues::IssuesListMilestonesRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> IssuesListMilestonesRequest<'a> {
+    /// Sets the `state` query parameter.
+    pub fn state(mut self, state: crate::types::IssuesListState) -> Self {
+        self.inner = self.inner.state(state);
+        self
+    }
+
+    /// Sets the `sort` query parameter.
+    pub fn sort(mut self, sort: crate::types::IssuesListMilestonesSort) -> Self {
+        self.inner = self.inner.sort(sort);
+        self
+    }
+
+    /// Sets the `direction` query parameter.
+    pub fn direction(mut self, direction: crate::types::Order) -> Self {
+        self.inner = self.inner.direction(direction);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Milestone>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Issues::list_labels_for_milestone`], set with a builder.
+pub struct IssuesListLabelsForMilestoneRequest<'a> {
+    inner: crate::issues::IssuesListLabelsForMilestoneRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> IssuesListLabelsForMilestoneRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Label>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Issues::list_for_authenticated_user`], set with a builder.
+pub struct IssuesListForAuthenticatedUserRequest<'a> {
+    inner: crate::issues::IssuesListForAuthenticatedUserRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> IssuesListForAuthenticatedUserRequest<'a> {
+    /// Sets the `filter` query parameter.
+    pub fn filter(mut self, filter: crate::types::Filter) -> Self {
+        self.inner = self.inner.filter(filter);
+        self
+    }
+
+    /// Sets the `state` query parameter.
+    pub fn state(mut self, state: crate::types::IssuesListState) -> Self {
+        self.inner = self.inner.state(state);
+        self
+    }
+
+    /// Sets the `labels` query parameter.
+    pub fn labels(mut self, labels: &'a str) -> Self {
+        self.inner = self.inner.labels(labels);
+        self
+    }
+
+    /// Sets the `sort` query parameter.
+    pub fn sort(mut self, sort: crate::types::IssuesListSort) -> Self {
+        self.inner = self.inner.sort(sort);
+        self
+    }
+
+    /// Sets the `direction` query parameter.
+    pub fn direction(mut self, direction: crate::types::Order) -> Self {
+        self.inner = self.inner.direction(direction);
+        self
+    }
+
+    /// Sets the `since` query parameter.
+    pub fn since(mut self, since: chrono::DateTime<chrono::Utc>) -> Self {
+        self.inner = self.inner.since(since);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Issue>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::issues::Issues`].
+pub struct Issues {
+    inner: crate::issues::Issues,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Issues {
+    pub(crate) fn new(inner: crate::issues::Issues, runtime: Arc<tokio::runtime::Runtime>) -> Self {
+        Issues { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::issues::Issues::list`].
+    pub fn list(
+        &self,
+        filter: crate::types::Filter,
+        state: crate::types::IssuesListState,
+        labels: &str,
+        sort: crate::types::IssuesListSort,
+        direction: crate::types::Order,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+        collab: bool,
+        orgs: bool,
+        owned: bool,
+        pulls: bool,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Issue>>> {
+        self.runtime.block_on(self.inner.list(
+            filter, state, labels, sort, direction, since, collab, orgs, owned, pulls, per_page,
+            page,
+        ))
+    }
+    /// The blocking version of [`crate::issues::Issues::list_request`].
+    pub fn list_request(&self) -> IssuesListRequest<'_> {
+        IssuesListRequest {
+            inner: self.inner.list_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::issues::Issues::list_all`].
+    pub fn list_all(
+        &self,
+        filter: crate::types::Filter,
+        state: crate::types::IssuesListState,
+        labels: &str,
+        sort: crate::types::IssuesListSort,
+        direction: crate::types::Order,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+        collab: bool,
+        orgs: bool,
+        owned: bool,
+        pulls: bool,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Issue>>> {
+        self.runtime.block_on(self.inner.list_all(
+            filter, state, labels, sort, direction, since, collab, orgs, owned, pulls,
+        ))
+    }
+    /// The blocking version of [`crate::issues::Issues::list_for_org`].
+    pub fn list_for_org(
+        &self,
+        org: &str,
+        filter: crate::types::Filter,
+        state: crate::types::IssuesListState,
+        labels: &str,
+        sort: crate::types::IssuesListSort,
+        direction: crate::types::Order,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Issue>>> {
+        self.runtime.block_on(self.inner.list_for_org(
+            org, filter, state, labels, sort, direction, since, per_page, page,
+        ))
+    }
+    /// The blocking version of [`crate::issues::Issues::list_for_org_request`].
+    pub fn list_for_org_request<'a>(&'a self, org: &'a str) -> IssuesListForOrgRequest<'a> {
+        IssuesListForOrgRequest {
+            inner: self.inner.list_for_org_request(org),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::issues::Issues::list_all_for_org`].
+    pub fn list_all_for_org(
+        &self,
+        org: &str,
+        filter: crate::types::Filter,
+        state: crate::types::IssuesListState,
+        labels: &str,
+        sort: crate::types::IssuesListSort,
+        direction: crate::types::Order,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Issue>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_all_for_org(org, filter, state, labels, sort, direction, since),
+        )
+    }
+    /// The blocking version of [`crate::issues::Issues::list_assignees`].
+    pub fn list_assignees(
+        &self,
+        owner: &str,
+        repo: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::SimpleUser>>> {
+        self.runtime
+            .block_on(self.inner.list_assignees(owner, repo, per_page, page))
+    }
+    /// The blocking version of [`crate::issues::Issues::list_assignees_request`].
+    pub fn list_assignees_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+    ) -> IssuesListAssigneesRequest<'a> {
+        IssuesListAssigneesRequest {
+            inner: self.inner.list_assignees_request(owner, repo),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::issues::Issues::list_all_assignees`].
+    pub fn list_all_assignees(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::SimpleUser>>> {
+        self.runtime
+            .block_on(self.inner.list_all_assignees(owner, repo))
+    }
+    /// The blocking version of [`crate::issues::Issues::check_user_can_be_assigned`].
+    pub fn check_user_can_be_assigned(
+        &self,
+        owner: &str,
+        repo: &str,
+        assignee: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.check_user_can_be_assigned(owner, repo, assignee))
+    }
+    /// The blocking version of [`crate::issues::Issues::list_for_repo`].
+    pub fn list_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+        milestone: &str,
+        state: crate::types::IssuesListState,
+        assignee: &str,
+        creator: &str,
+        mentioned: &str,
+        labels: &str,
+        sort: crate::types::IssuesListSort,
+        direction: crate::types::Order,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::IssueSimple>>> {
+        self.runtime.block_on(self.inner.list_for_repo(
+            owner, repo, milestone, state, assignee, creator, mentioned, labels, sort, direction,
+            since, per_page, page,
+        ))
+    }
+    /// The blocking version of [`crate::issues::Issues::list_for_repo_request`].
+    pub fn list_for_repo_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+    ) -> IssuesListForRepoRequest<'a> {
+        IssuesListForRepoRequest {
+            inner: self.inner.list_for_repo_request(owner, repo),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::issues::Issues::list_all_for_repo`].
+    pub fn list_all_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+        milestone: &str,
+        state: crate::types::IssuesListState,
+        assignee: &str,
+        creator: &str,
+        mentioned: &str,
+        labels: &str,
+        sort: crate::types::IssuesListSort,
+        direction: crate::types::Order,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> ClientResult<crate::Response<Vec<crate::types::IssueSimple>>> {
+        self.runtime.block_on(self.inner.list_all_for_repo(
+            owner, repo, milestone, state, assignee, creator, mentioned, labels, sort, direction,
+            since,
+        ))
+    }
+    /// The blocking version of [`crate::issues::Issues::create`].
+    pub fn create(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::IssuesCreateRequest,
+    ) -> ClientResult<crate::Response<crate::types::Issue>> {
+        self.runtime.block_on(self.inner.create(owner, repo, body))
+    }
+    /// The blocking version of [`crate::issues::Issues::list_comments_for_repo`].
+    pub fn list_comments_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+        sort: crate::types::Sort,
+        direction: crate::types::Order,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::IssueComment>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_comments_for_repo(owner, repo, sort, direction, since, per_page, page),
+        )
+    }
+    /// The blocking version of [`crate::issues::Issues::list_comments_for_repo_request`].
+    pub fn list_comments_for_repo_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+    ) -> IssuesListCommentsForRepoRequest<'a> {
+        IssuesListCommentsForRepoRequest {
+            inner: self.inner.list_comments_for_repo_request(owner, repo),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::issues::Issues::list_all_comments_for_repo`].
+    pub fn list_all_comments_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+        sort: crate::types::Sort,
+        direction: crate::types::Order,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> ClientResult<crate::Response<Vec<crate::types::IssueComment>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_all_comments_for_repo(owner, repo, sort, direction, since),
+        )
+    }
+    /// The blocking version of [`crate::issues::Issues::get_comment`].
+    pub fn get_comment(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::IssueComment>> {
+        self.runtime
+            .block_on(self.inner.get_comment(owner, repo, comment_id))
+    }
+    /// The blocking version of [`crate::issues::Issues::delete_comment`].
+    pub fn delete_comment(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete_comment(owner, repo, comment_id))
+    }
+    /// The blocking version of [`crate::issues::Issues::update_comment`].
+    pub fn update_comment(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+        body: &crate::types::PullsUpdateReviewRequest,
+    ) -> ClientResult<crate::Response<crate::types::IssueComment>> {
+        self.runtime
+            .block_on(self.inner.update_comment(owner, repo, comment_id, body))
+    }
+    /// The blocking version of [`crate::issues::Issues::list_events_for_repo`].
+    pub fn list_events_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::IssueEvent>>> {
+        self.runtime
+            .block_on(self.inner.list_events_for_repo(owner, repo, per_page, page))
+    }
+    /// The blocking version of [`crate::issues::Issues::list_events_for_repo_request`].
+    pub fn list_events_for_repo_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+    ) -> IssuesListEventsForRepoRequest<'a> {
+        IssuesListEventsForRepoRequest {
+            inner: self.inner.list_events_for_repo_request(owner, repo),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::issues::Issues::list_all_events_for_repo`].
+    pub fn list_all_events_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::IssueEvent>>> {
+        self.runtime
+            .block_on(self.inner.list_all_events_for_repo(owner, repo))
+    }
+    /// The blocking version of [`crate::issues::Issues::get_event`].
+    pub fn get_event(
+        &self,
+        owner: &str,
+        repo: &str,
+        event_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::IssueEvent>> {
+        self.runtime
+            .block_on(self.inner.get_event(owner, repo, event_id))
+    }
+    /// The blocking version of [`crate::issues::Issues::get`].
+    pub fn get(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+    ) -> ClientResult<crate::Response<crate::types::Issue>> {
+        self.runtime
+            .block_on(self.inner.get(owner, repo, issue_number))
+    }
+    /// The blocking version of [`crate::issues::Issues::update`].
+    pub fn update(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+        body: &crate::types::IssuesUpdateRequest,
+    ) -> ClientResult<crate::Response<crate::types::Issue>> {
+        self.runtime
+            .block_on(self.inner.update(owner, repo, issue_number, body))
+    }
+    /// The blocking version of [`crate::issues::Issues::add_assignees`].
+    pub fn add_assignees(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+        body: &crate::types::IssuesAddAssigneesRequest,
+    ) -> ClientResult<crate::Response<crate::types::IssueSimple>> {
+        self.runtime
+            .block_on(self.inner.add_assignees(owner, repo, issue_number, body))
+    }
+    /// The blocking version of [`crate::issues::Issues::remove_assignees`].
+    pub fn remove_assignees(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+        body: &crate::types::IssuesAddAssigneesRequest,
+    ) -> ClientResult<crate::Response<crate::types::IssueSimple>> {
+        self.runtime
+            .block_on(self.inner.remove_assignees(owner, repo, issue_number, body))
+    }
+    /// The blocking version of [`crate::issues::Issues::list_comments`].
+    pub fn list_comments(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::IssueComment>>> {
+        self.runtime.block_on(self.inner.list_comments(
+            owner,
+            repo,
+            issue_number,
+            since,
+            per_page,
+            page,
+        ))
+    }
+    /// The blocking version of [`crate::issues::Issues::list_comments_request`].
+    pub fn list_comments_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+        issue_number: i64,
+    ) -> IssuesListCommentsRequest<'a> {
+        IssuesListCommentsRequest {
+            inner: self.inner.list_comments_request(owner, repo, issue_number),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::issues::Issues::list_all_comments`].
+    pub fn list_all_comments(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> ClientResult<crate::Response<Vec<crate::types::IssueComment>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_all_comments(owner, repo, issue_number, since),
+        )
+    }
+    /// The blocking version of [`crate::issues::Issues::create_comment`].
+    pub fn create_comment(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+        body: &crate::types::PullsUpdateReviewRequest,
+    ) -> ClientResult<crate::Response<crate::types::IssueComment>> {
+        self.runtime
+            .block_on(self.inner.create_comment(owner, repo, issue_number, body))
+    }
+    /// The blocking version of [`crate::issues::Issues::list_events`].
+    pub fn list_events(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::IssueEventAnyOf>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_events(owner, repo, issue_number, per_page, page),
+        )
+    }
+    /// The blocking version of [`crate::issues::Issues::list_events_request`].
+    pub fn list_events_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+        issue_number: i64,
+    ) -> IssuesListEventsRequest<'a> {
+        IssuesListEventsRequest {
+            inner: self.inner.list_events_request(owner, repo, issue_number),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::issues::Issues::list_all_events`].
+    pub fn list_all_events(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::IssueEventAnyOf>>> {
+        self.runtime
+            .block_on(self.inner.list_all_events(owner, repo, issue_number))
+    }
+    /// The blocking version of [`crate::issues::Issues::list_labels_on_issue`].
+    pub fn list_labels_on_issue(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Label>>> {
+        self.runtime.block_on(self.inner.list_labels_on_issue(
+            owner,
+            repo,
+            issue_number,
+            per_page,
+            page,
+        ))
+    }
+    /// The blocking version of [`crate::issues::Issues::list_labels_on_issue_request`].
+    pub fn list_labels_on_issue_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+        issue_number: i64,
+    ) -> IssuesListLabelsOnIssueRequest<'a> {
+        IssuesListLabelsOnIssueRequest {
+            inner: self
+                .inner
+                .list_labels_on_issue_request(owner, repo, issue_number),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::issues::Issues::list_all_labels_on_issue`].
+    pub fn list_all_labels_on_issue(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Label>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_all_labels_on_issue(owner, repo, issue_number),
+        )
+    }
+    /// The blocking version of [`crate::issues::Issues::set_labels`].
+    pub fn set_labels(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+        body: &crate::types::IssuesSetLabelsRequestAnyOf,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Label>>> {
+        self.runtime
+            .block_on(self.inner.set_labels(owner, repo, issue_number, body))
+    }
+    /// The blocking version of [`crate::issues::Issues::add_labels`].
+    pub fn add_labels(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+        body: &crate::types::IssuesAddLabelsRequestOneOf,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Label>>> {
+        self.runtime
+            .block_on(self.inner.add_labels(owner, repo, issue_number, body))
+    }
+    /// The blocking version of [`crate::issues::Issues::remove_all_labels`].
+    pub fn remove_all_labels(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.remove_all_labels(owner, repo, issue_number))
+    }
+    /// The blocking version of [`crate::issues::Issues::remove_label`].
+    pub fn remove_label(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+        name: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Label>>> {
+        self.runtime
+            .block_on(self.inner.remove_label(owner, repo, issue_number, name))
+    }
+    /// The blocking version of [`crate::issues::Issues::lock`].
+    pub fn lock(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+        body: &crate::types::IssuesLockRequest,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.lock(owner, repo, issue_number, body))
+    }
+    /// The blocking version of [`crate::issues::Issues::unlock`].
+    pub fn unlock(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.unlock(owner, repo, issue_number))
+    }
+    /// The blocking version of [`crate::issues::Issues::list_events_for_timeline`].
+    pub fn list_events_for_timeline(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Data>>> {
+        self.runtime.block_on(self.inner.list_events_for_timeline(
+            owner,
+            repo,
+            issue_number,
+            per_page,
+            page,
+        ))
+    }
+    /// The blocking version of [`crate::issues::Issues::list_events_for_timeline_request`].
+    pub fn list_events_for_timeline_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+        issue_number: i64,
+    ) -> IssuesListEventsForTimelineRequest<'a> {
+        IssuesListEventsForTimelineRequest {
+            inner: self
+                .inner
+                .list_events_for_timeline_request(owner, repo, issue_number),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::issues::Issues::list_all_events_for_timeline`].
+    pub fn list_all_events_for_timeline(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Data>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_all_events_for_timeline(owner, repo, issue_number),
+        )
+    }
+    /// The blocking version of [`crate::issues::Issues::list_labels_for_repo`].
+    pub fn list_labels_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Label>>> {
+        self.runtime
+            .block_on(self.inner.list_labels_for_repo(owner, repo, per_page, page))
+    }
+    /// The blocking version of [`crate::issues::Issues::list_labels_for_repo_request`].
+    pub fn list_labels_for_repo_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+    ) -> IssuesListLabelsForRepoRequest<'a> {
+        IssuesListLabelsForRepoRequest {
+            inner: self.inner.list_labels_for_repo_request(owner, repo),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::issues::Issues::list_all_labels_for_repo`].
+    pub fn list_all_labels_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Label>>> {
+        self.runtime
+            .block_on(self.inner.list_all_labels_for_repo(owner, repo))
+    }
+    /// The blocking version of [`crate::issues::Issues::create_label`].
+    pub fn create_label(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::IssuesCreateLabelRequest,
+    ) -> ClientResult<crate::Response<crate::types::Label>> {
+        self.runtime
+            .block_on(self.inner.create_label(owner, repo, body))
+    }
+    /// The blocking version of [`crate::issues::Issues::get_label`].
+    pub fn get_label(
+        &self,
+        owner: &str,
+        repo: &str,
+        name: &str,
+    ) -> ClientResult<crate::Response<crate::types::Label>> {
+        self.runtime
+            .block_on(self.inner.get_label(owner, repo, name))
+    }
+    /// The blocking version of [`crate::issues::Issues::delete_label`].
+    pub fn delete_label(
+        &self,
+        owner: &str,
+        repo: &str,
+        name: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete_label(owner, repo, name))
+    }
+    /// The blocking version of [`crate::issues::Issues::update_label`].
+    pub fn update_label(
+        &self,
+        owner: &str,
+        repo: &str,
+        name: &str,
+        body: &crate::types::IssuesUpdateLabelRequest,
+    ) -> ClientResult<crate::Response<crate::types::Label>> {
+        self.runtime
+            .block_on(self.inner.update_label(owner, repo, name, body))
+    }
+    /// The blocking version of [`crate::issues::Issues::list_milestones`].
+    pub fn list_milestones(
+        &self,
+        owner: &str,
+        repo: &str,
+        state: crate::types::IssuesListState,
+        sort: crate::types::IssuesListMilestonesSort,
+        direction: crate::types::Order,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Milestone>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_milestones(owner, repo, state, sort, direction, per_page, page),
+        )
+    }
+    /// The blocking version of [`crate::issues::Issues::list_milestones_request`].
+    pub fn list_milestones_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+    ) -> IssuesListMilestonesRequest<'a> {
+        IssuesListMilestonesRequest {
+            inner: self.inner.list_milestones_request(owner, repo),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::issues::Issues::list_all_milestones`].
+    pub fn list_all_milestones(
+        &self,
+        owner: &str,
+        repo: &str,
+        state: crate::types::IssuesListState,
+        sort: crate::types::IssuesListMilestonesSort,
+        direction: crate::types::Order,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Milestone>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_all_milestones(owner, repo, state, sort, direction),
+        )
+    }
+    /// The blocking version of [`crate::issues::Issues::create_milestone`].
+    pub fn create_milestone(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::IssuesCreateMilestoneRequest,
+    ) -> ClientResult<crate::Response<crate::types::Milestone>> {
+        self.runtime
+            .block_on(self.inner.create_milestone(owner, repo, body))
+    }
+    /// The blocking version of [`crate::issues::Issues::get_milestone`].
+    pub fn get_milestone(
+        &self,
+        owner: &str,
+        repo: &str,
+        milestone_number: i64,
+    ) -> ClientResult<crate::Response<crate::types::Milestone>> {
+        self.runtime
+            .block_on(self.inner.get_milestone(owner, repo, milestone_number))
+    }
+    /// The blocking version of [`crate::issues::Issues::delete_milestone`].
+    pub fn delete_milestone(
+        &self,
+        owner: &str,
+        repo: &str,
+        milestone_number: i64,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete_milestone(owner, repo, milestone_number))
+    }
+    /// The blocking version of [`crate::issues::Issues::update_milestone`].
+    pub fn update_milestone(
+        &self,
+        owner: &str,
+        repo: &str,
+        milestone_number: i64,
+        body: &crate::types::IssuesCreateMilestoneRequest,
+    ) -> ClientResult<crate::Response<crate::types::Milestone>> {
+        self.runtime.block_on(
+            self.inner
+                .update_milestone(owner, repo, milestone_number, body),
+        )
+    }
+    /// The blocking version of [`crate::issues::Issues::list_labels_for_milestone`].
+    pub fn list_labels_for_milestone(
+        &self,
+        owner: &str,
+        repo: &str,
+        milestone_number: i64,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Label>>> {
+        self.runtime.block_on(self.inner.list_labels_for_milestone(
+            owner,
+            repo,
+            milestone_number,
+            per_page,
+            page,
+        ))
+    }
+    /// The blocking version of [`crate::issues::Issues::list_labels_for_milestone_request`].
+    pub fn list_labels_for_milestone_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+        milestone_number: i64,
+    ) -> IssuesListLabelsForMilestoneRequest<'a> {
+        IssuesListLabelsForMilestoneRequest {
+            inner: self
+                .inner
+                .list_labels_for_milestone_request(owner, repo, milestone_number),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::issues::Issues::list_all_labels_for_milestone`].
+    pub fn list_all_labels_for_milestone(
+        &self,
+        owner: &str,
+        repo: &str,
+        milestone_number: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Label>>> {
+        self.runtime
+            .block_on(
+                self.inner
+                    .list_all_labels_for_milestone(owner, repo, milestone_number),
+            )
+    }
+    /// The blocking version of [`crate::issues::Issues::list_for_authenticated_user`].
+    pub fn list_for_authenticated_user(
+        &self,
+        filter: crate::types::Filter,
+        state: crate::types::IssuesListState,
+        labels: &str,
+        sort: crate::types::IssuesListSort,
+        direction: crate::types::Order,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Issue>>> {
+        self.runtime
+            .block_on(self.inner.list_for_authenticated_user(
+                filter, state, labels, sort, direction, since, per_page, page,
+            ))
+    }
+    /// The blocking version of [`crate::issues::Issues::list_for_authenticated_user_request`].
+    pub fn list_for_authenticated_user_request(&self) -> IssuesListForAuthenticatedUserRequest<'_> {
+        IssuesListForAuthenticatedUserRequest {
+            inner: self.inner.list_for_authenticated_user_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::issues::Issues::list_all_for_authenticated_user`].
+    pub fn list_all_for_authenticated_user(
+        &self,
+        filter: crate::types::Filter,
+        state: crate::types::IssuesListState,
+        labels: &str,
+        sort: crate::types::IssuesListSort,
+        direction: crate::types::Order,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Issue>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_all_for_authenticated_user(filter, state, labels, sort, direction, since),
+        )
+    }
+}
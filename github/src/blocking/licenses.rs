@@ -0,0 +1,87 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Licenses::get_all_commonly_used`], set with a builder.
+pub struct LicensesGetAllCommonlyUsedRequest<'a> {
+    inner: crate::licenses::LicensesGetAllCommonlyUsedRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> LicensesGetAllCommonlyUsedRequest<'a> {
+    /// Sets the `featured` query parameter.
+    pub fn featured(mut self, featured: bool) -> Self {
+        self.inner = self.inner.featured(featured);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::LicenseSimple>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::licenses::Licenses`].
+pub struct Licenses {
+    inner: crate::licenses::Licenses,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Licenses {
+    pub(crate) fn new(
+        inner: crate::licenses::Licenses,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Licenses { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::licenses::Licenses::get_all_commonly_used`].
+    pub fn get_all_commonly_used(
+        &self,
+        featured: bool,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::LicenseSimple>>> {
+        self.runtime
+            .block_on(self.inner.get_all_commonly_used(featured, per_page, page))
+    }
+    /// The blocking version of [`crate::licenses::Licenses::get_all_commonly_used_request`].
+    pub fn get_all_commonly_used_request(&self) -> LicensesGetAllCommonlyUsedRequest<'_> {
+        LicensesGetAllCommonlyUsedRequest {
+            inner: self.inner.get_all_commonly_used_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::licenses::Licenses::get_all_all_commonly_used`].
+    pub fn get_all_all_commonly_used(
+        &self,
+        featured: bool,
+    ) -> ClientResult<crate::Response<Vec<crate::types::LicenseSimple>>> {
+        self.runtime
+            .block_on(self.inner.get_all_all_commonly_used(featured))
+    }
+    /// The blocking version of [`crate::licenses::Licenses::get`].
+    pub fn get(&self, license: &str) -> ClientResult<crate::Response<crate::types::LicenseData>> {
+        self.runtime.block_on(self.inner.get(license))
+    }
+    /// The blocking version of [`crate::licenses::Licenses::get_for_repo`].
+    pub fn get_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> ClientResult<crate::Response<crate::types::LicenseContent>> {
+        self.runtime.block_on(self.inner.get_for_repo(owner, repo))
+    }
+}
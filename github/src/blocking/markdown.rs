@@ -0,0 +1,33 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::markdown::Markdown`].
+pub struct Markdown {
+    inner: crate::markdown::Markdown,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Markdown {
+    pub(crate) fn new(
+        inner: crate::markdown::Markdown,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Markdown { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::markdown::Markdown::render`].
+    pub fn render(
+        &self,
+        body: &crate::types::MarkdownRenderRequest,
+    ) -> ClientResult<crate::Response<String>> {
+        self.runtime.block_on(self.inner.render(body))
+    }
+    /// The blocking version of [`crate::markdown::Markdown::render_raw`].
+    pub fn render_raw<T: Into<reqwest::Body>>(
+        &self,
+        body: T,
+    ) -> ClientResult<crate::Response<String>> {
+        self.runtime.block_on(self.inner.render_raw::<T>(body))
+    }
+}
@@ -0,0 +1,58 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Meta::get_octocat`], set with a builder.
+pub struct MetaGetOctocatRequest<'a> {
+    inner: crate::meta::MetaGetOctocatRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> MetaGetOctocatRequest<'a> {
+    /// Sets the `s` query parameter.
+    pub fn s(mut self, s: &'a str) -> Self {
+        self.inner = self.inner.s(s);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<String>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::meta::Meta`].
+pub struct Meta {
+    inner: crate::meta::Meta,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Meta {
+    pub(crate) fn new(inner: crate::meta::Meta, runtime: Arc<tokio::runtime::Runtime>) -> Self {
+        Meta { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::meta::Meta::root`].
+    pub fn root(&self) -> ClientResult<crate::Response<crate::types::MetaRootResponse>> {
+        self.runtime.block_on(self.inner.root())
+    }
+    /// The blocking version of [`crate::meta::Meta::get`].
+    pub fn get(&self) -> ClientResult<crate::Response<crate::types::ApiOverview>> {
+        self.runtime.block_on(self.inner.get())
+    }
+    /// The blocking version of [`crate::meta::Meta::get_octocat`].
+    pub fn get_octocat(&self, s: &str) -> ClientResult<crate::Response<String>> {
+        self.runtime.block_on(self.inner.get_octocat(s))
+    }
+    /// The blocking version of [`crate::meta::Meta::get_octocat_request`].
+    pub fn get_octocat_request(&self) -> MetaGetOctocatRequest<'_> {
+        MetaGetOctocatRequest {
+            inner: self.inner.get_octocat_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::meta::Meta::get_zen`].
+    pub fn get_zen(&self) -> ClientResult<crate::Response<String>> {
+        self.runtime.block_on(self.inner.get_zen())
+    }
+}
@@ -0,0 +1,515 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Migrations::list_for_org`], set with a builder.
+pub struct MigrationsListForOrgRequest<'a> {
+    inner: crate::migrations::MigrationsListForOrgRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> MigrationsListForOrgRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sets the `exclude` query parameter.
+    pub fn exclude(mut self, exclude: &'a [String]) -> Self {
+        self.inner = self.inner.exclude(exclude);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Migration>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Migrations::get_status_for_org`], set with a builder.
+pub struct MigrationsGetStatusForOrgRequest<'a> {
+    inner: crate::migrations::MigrationsGetStatusForOrgRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> MigrationsGetStatusForOrgRequest<'a> {
+    /// Sets the `exclude` query parameter.
+    pub fn exclude(mut self, exclude: &'a [String]) -> Self {
+        self.inner = self.inner.exclude(exclude);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::Migration>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Migrations::list_repos_for_org`], set with a builder.
+pub struct MigrationsListReposForOrgRequest<'a> {
+    inner: crate::migrations::MigrationsListReposForOrgRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> MigrationsListReposForOrgRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::MinimalRepository>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Migrations::get_commit_authors`], set with a builder.
+pub struct MigrationsGetCommitAuthorsRequest<'a> {
+    inner: crate::migrations::MigrationsGetCommitAuthorsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> MigrationsGetCommitAuthorsRequest<'a> {
+    /// Sets the `since` query parameter.
+    pub fn since(mut self, since: i64) -> Self {
+        self.inner = self.inner.since(since);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::PorterAuthor>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Migrations::list_for_authenticated_user`], set with a builder.
+pub struct MigrationsListForAuthenticatedUserRequest<'a> {
+    inner: crate::migrations::MigrationsListForAuthenticatedUserRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> MigrationsListForAuthenticatedUserRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Migration>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Migrations::get_status_for_authenticated_user`], set with a builder.
+pub struct MigrationsGetStatusForAuthenticatedUserRequest<'a> {
+    inner: crate::migrations::MigrationsGetStatusForAuthenticatedUserRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> MigrationsGetStatusForAuthenticatedUserRequest<'a> {
+    /// Sets the `exclude` query parameter.
+    pub fn exclude(mut self, exclude: &'a [String]) -> Self {
+        self.inner = self.inner.exclude(exclude);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::Migration>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Migrations::list_repos_for_user`], set with a builder.
+pub struct MigrationsListReposForUserRequest<'a> {
+    inner: crate::migrations::MigrationsListReposForUserRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> MigrationsListReposForUserRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::MinimalRepository>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::migrations::Migrations`].
+pub struct Migrations {
+    inner: crate::migrations::Migrations,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Migrations {
+    pub(crate) fn new(
+        inner: crate::migrations::Migrations,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Migrations { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::migrations::Migrations::list_for_org`].
+    pub fn list_for_org(
+        &self,
+        org: &str,
+        per_page: i64,
+        page: i64,
+        exclude: &[String],
+    ) -> ClientResult<crate::Response<Vec<crate::types::Migration>>> {
+        self.runtime
+            .block_on(self.inner.list_for_org(org, per_page, page, exclude))
+    }
+    /// The blocking version of [`crate::migrations::Migrations::list_for_org_request`].
+    pub fn list_for_org_request<'a>(&'a self, org: &'a str) -> MigrationsListForOrgRequest<'a> {
+        MigrationsListForOrgRequest {
+            inner: self.inner.list_for_org_request(org),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::migrations::Migrations::list_all_for_org`].
+    pub fn list_all_for_org(
+        &self,
+        org: &str,
+        exclude: &[String],
+    ) -> ClientResult<crate::Response<Vec<crate::types::Migration>>> {
+        self.runtime
+            .block_on(self.inner.list_all_for_org(org, exclude))
+    }
+    /// The blocking version of [`crate::migrations::Migrations::start_for_org`].
+    pub fn start_for_org(
+        &self,
+        org: &str,
+        body: &crate::types::MigrationsStartRequest,
+    ) -> ClientResult<crate::Response<crate::types::Migration>> {
+        self.runtime.block_on(self.inner.start_for_org(org, body))
+    }
+    /// The blocking version of [`crate::migrations::Migrations::get_status_for_org`].
+    pub fn get_status_for_org(
+        &self,
+        org: &str,
+        migration_id: i64,
+        exclude: &[String],
+    ) -> ClientResult<crate::Response<crate::types::Migration>> {
+        self.runtime
+            .block_on(self.inner.get_status_for_org(org, migration_id, exclude))
+    }
+    /// The blocking version of [`crate::migrations::Migrations::get_status_for_org_request`].
+    pub fn get_status_for_org_request<'a>(
+        &'a self,
+        org: &'a str,
+        migration_id: i64,
+    ) -> MigrationsGetStatusForOrgRequest<'a> {
+        MigrationsGetStatusForOrgRequest {
+            inner: self.inner.get_status_for_org_request(org, migration_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::migrations::Migrations::download_archive_for_org`].
+    pub fn download_archive_for_org(
+        &self,
+        org: &str,
+        migration_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.download_archive_for_org(org, migration_id))
+    }
+    /// The blocking version of [`crate::migrations::Migrations::delete_archive_for_org`].
+    pub fn delete_archive_for_org(
+        &self,
+        org: &str,
+        migration_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete_archive_for_org(org, migration_id))
+    }
+    /// The blocking version of [`crate::migrations::Migrations::unlock_repo_for_org`].
+    pub fn unlock_repo_for_org(
+        &self,
+        org: &str,
+        migration_id: i64,
+        repo_name: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.unlock_repo_for_org(org, migration_id, repo_name))
+    }
+    /// The blocking version of [`crate::migrations::Migrations::list_repos_for_org`].
+    pub fn list_repos_for_org(
+        &self,
+        org: &str,
+        migration_id: i64,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::MinimalRepository>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_repos_for_org(org, migration_id, per_page, page),
+        )
+    }
+    /// The blocking version of [`crate::migrations::Migrations::list_repos_for_org_request`].
+    pub fn list_repos_for_org_request<'a>(
+        &'a self,
+        org: &'a str,
+        migration_id: i64,
+    ) -> MigrationsListReposForOrgRequest<'a> {
+        MigrationsListReposForOrgRequest {
+            inner: self.inner.list_repos_for_org_request(org, migration_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::migrations::Migrations::list_all_repos_for_org`].
+    pub fn list_all_repos_for_org(
+        &self,
+        org: &str,
+        migration_id: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::MinimalRepository>>> {
+        self.runtime
+            .block_on(self.inner.list_all_repos_for_org(org, migration_id))
+    }
+    /// The blocking version of [`crate::migrations::Migrations::get_import_status`].
+    pub fn get_import_status(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> ClientResult<crate::Response<crate::types::Import>> {
+        self.runtime
+            .block_on(self.inner.get_import_status(owner, repo))
+    }
+    /// The blocking version of [`crate::migrations::Migrations::start_import`].
+    pub fn start_import(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::MigrationsStartImportRequest,
+    ) -> ClientResult<crate::Response<crate::types::Import>> {
+        self.runtime
+            .block_on(self.inner.start_import(owner, repo, body))
+    }
+    /// The blocking version of [`crate::migrations::Migrations::cancel_import`].
+    pub fn cancel_import(&self, owner: &str, repo: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.cancel_import(owner, repo))
+    }
+    /// The blocking version of [`crate::migrations::Migrations::update_import`].
+    pub fn update_import(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::MigrationsUpdateImportRequest,
+    ) -> ClientResult<crate::Response<crate::types::Import>> {
+        self.runtime
+            .block_on(self.inner.update_import(owner, repo, body))
+    }
+    /// The blocking version of [`crate::migrations::Migrations::get_commit_authors`].
+    pub fn get_commit_authors(
+        &self,
+        owner: &str,
+        repo: &str,
+        since: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::PorterAuthor>>> {
+        self.runtime
+            .block_on(self.inner.get_commit_authors(owner, repo, since))
+    }
+    /// The blocking version of [`crate::migrations::Migrations::get_commit_authors_request`].
+    pub fn get_commit_authors_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+    ) -> MigrationsGetCommitAuthorsRequest<'a> {
+        MigrationsGetCommitAuthorsRequest {
+            inner: self.inner.get_commit_authors_request(owner, repo),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::migrations::Migrations::get_all_commit_authors`].
+    pub fn get_all_commit_authors(
+        &self,
+        owner: &str,
+        repo: &str,
+        since: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::PorterAuthor>>> {
+        self.runtime
+            .block_on(self.inner.get_all_commit_authors(owner, repo, since))
+    }
+    /// The blocking version of [`crate::migrations::Migrations::map_commit_author`].
+    pub fn map_commit_author(
+        &self,
+        owner: &str,
+        repo: &str,
+        author_id: i64,
+        body: &crate::types::Author,
+    ) -> ClientResult<crate::Response<crate::types::PorterAuthor>> {
+        self.runtime
+            .block_on(self.inner.map_commit_author(owner, repo, author_id, body))
+    }
+    /// The blocking version of [`crate::migrations::Migrations::get_large_files`].
+    pub fn get_large_files(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::PorterLargeFile>>> {
+        self.runtime
+            .block_on(self.inner.get_large_files(owner, repo))
+    }
+    /// The blocking version of [`crate::migrations::Migrations::get_all_large_files`].
+    pub fn get_all_large_files(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::PorterLargeFile>>> {
+        self.runtime
+            .block_on(self.inner.get_all_large_files(owner, repo))
+    }
+    /// The blocking version of [`crate::migrations::Migrations::set_lfs_preference`].
+    pub fn set_lfs_preference(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::MigrationsSetLfsPreferenceRequest,
+    ) -> ClientResult<crate::Response<crate::types::Import>> {
+        self.runtime
+            .block_on(self.inner.set_lfs_preference(owner, repo, body))
+    }
+    /// The blocking version of [`crate::migrations::Migrations::list_for_authenticated_user`].
+    pub fn list_for_authenticated_user(
+        &self,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Migration>>> {
+        self.runtime
+            .block_on(self.inner.list_for_authenticated_user(per_page, page))
+    }
+    /// The blocking version of [`crate::migrations::Migrations::list_for_authenticated_user_request`].
+    pub fn list_for_authenticated_user_request(
+        &self,
+    ) -> MigrationsListForAuthenticatedUserRequest<'_> {
+        MigrationsListForAuthenticatedUserRequest {
+            inner: self.inner.list_for_authenticated_user_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::migrations::Migrations::list_all_for_authenticated_user`].
+    pub fn list_all_for_authenticated_user(
+        &self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Migration>>> {
+        self.runtime
+            .block_on(self.inner.list_all_for_authenticated_user())
+    }
+    /// The blocking version of [`crate::migrations::Migrations::start_for_authenticated_user`].
+    pub fn start_for_authenticated_user(
+        &self,
+        body: &crate::types::MigrationsStartRequest,
+    ) -> ClientResult<crate::Response<crate::types::Migration>> {
+        self.runtime
+            .block_on(self.inner.start_for_authenticated_user(body))
+    }
+    /// The blocking version of [`crate::migrations::Migrations::get_status_for_authenticated_user`].
+    pub fn get_status_for_authenticated_user(
+        &self,
+        migration_id: i64,
+        exclude: &[String],
+    ) -> ClientResult<crate::Response<crate::types::Migration>> {
+        self.runtime.block_on(
+            self.inner
+                .get_status_for_authenticated_user(migration_id, exclude),
+        )
+    }
+    /// The blocking version of [`crate::migrations::Migrations::get_status_for_authenticated_user_request`].
+    pub fn get_status_for_authenticated_user_request(
+        &self,
+        migration_id: i64,
+    ) -> MigrationsGetStatusForAuthenticatedUserRequest<'_> {
+        MigrationsGetStatusForAuthenticatedUserRequest {
+            inner: self
+                .inner
+                .get_status_for_authenticated_user_request(migration_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::migrations::Migrations::get_archive_for_authenticated_user`].
+    pub fn get_archive_for_authenticated_user(
+        &self,
+        migration_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.get_archive_for_authenticated_user(migration_id))
+    }
+    /// The blocking version of [`crate::migrations::Migrations::delete_archive_for_authenticated_user`].
+    pub fn delete_archive_for_authenticated_user(
+        &self,
+        migration_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .delete_archive_for_authenticated_user(migration_id),
+        )
+    }
+    /// The blocking version of [`crate::migrations::Migrations::unlock_repo_for_authenticated_user`].
+    pub fn unlock_repo_for_authenticated_user(
+        &self,
+        migration_id: i64,
+        repo_name: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .unlock_repo_for_authenticated_user(migration_id, repo_name),
+        )
+    }
+    /// The blocking version of [`crate::migrations::Migrations::list_repos_for_user`].
+    pub fn list_repos_for_user(
+        &self,
+        migration_id: i64,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::MinimalRepository>>> {
+        self.runtime
+            .block_on(self.inner.list_repos_for_user(migration_id, per_page, page))
+    }
+    /// The blocking version of [`crate::migrations::Migrations::list_repos_for_user_request`].
+    pub fn list_repos_for_user_request(
+        &self,
+        migration_id: i64,
+    ) -> MigrationsListReposForUserRequest<'_> {
+        MigrationsListReposForUserRequest {
+            inner: self.inner.list_repos_for_user_request(migration_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::migrations::Migrations::list_all_repos_for_user`].
+    pub fn list_all_repos_for_user(
+        &self,
+        migration_id: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::MinimalRepository>>> {
+        self.runtime
+            .block_on(self.inner.list_all_repos_for_user(migration_id))
+    }
+}
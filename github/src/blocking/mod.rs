@@ -0,0 +1,229 @@
+//! A blocking client, for the programs that are not async.
+//!
+//! It has the functions of the async [`Client`](crate::Client), which it runs on a
+//! runtime of its own, so they must not be called from within an async runtime. The
+//! `*_stream` functions are left out, the `*_all` ones returning all the pages at once.
+
+use std::{future::Future, sync::Arc};
+
+pub mod actions;
+pub mod activity;
+pub mod apps;
+pub mod billing;
+pub mod checks;
+pub mod code_scanning;
+pub mod codes_of_conduct;
+pub mod emojis;
+pub mod enterprise_admin;
+pub mod gists;
+pub mod git;
+pub mod gitignore;
+pub mod interactions;
+pub mod issues;
+pub mod licenses;
+pub mod markdown;
+pub mod meta;
+pub mod migrations;
+pub mod oauth_authorizations;
+pub mod orgs;
+pub mod packages;
+pub mod projects;
+pub mod pulls;
+pub mod rate_limit;
+pub mod reactions;
+pub mod repos;
+pub mod scim;
+pub mod search;
+pub mod secret_scanning;
+pub mod teams;
+pub mod users;
+
+/// A blocking client, sending its requests with an async [`Client`](crate::Client).
+#[derive(Clone)]
+pub struct Client {
+    inner: crate::Client,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Client {
+    /// Wraps an async client, creating the runtime its requests run on.
+    pub fn new(client: crate::Client) -> std::io::Result<Self> {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()?;
+
+        Ok(Client {
+            inner: client,
+            runtime: Arc::new(runtime),
+        })
+    }
+
+    /// Returns the async client the requests are sent with.
+    pub fn inner(&self) -> &crate::Client {
+        &self.inner
+    }
+
+    /// Runs a future to completion on the runtime of the client, for the async
+    /// functions that have no blocking version.
+    pub fn block_on<F: Future>(&self, future: F) -> F::Output {
+        self.runtime.block_on(future)
+    }
+
+    /// The blocking version of [`crate::Client::actions`].
+    pub fn actions(&self) -> actions::Actions {
+        actions::Actions::new(self.inner.actions(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::activity`].
+    pub fn activity(&self) -> activity::Activity {
+        activity::Activity::new(self.inner.activity(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::apps`].
+    pub fn apps(&self) -> apps::Apps {
+        apps::Apps::new(self.inner.apps(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::billing`].
+    pub fn billing(&self) -> billing::Billing {
+        billing::Billing::new(self.inner.billing(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::checks`].
+    pub fn checks(&self) -> checks::Checks {
+        checks::Checks::new(self.inner.checks(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::code_scanning`].
+    pub fn code_scanning(&self) -> code_scanning::CodeScanning {
+        code_scanning::CodeScanning::new(self.inner.code_scanning(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::codes_of_conduct`].
+    pub fn codes_of_conduct(&self) -> codes_of_conduct::CodesOfConduct {
+        codes_of_conduct::CodesOfConduct::new(self.inner.codes_of_conduct(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::emojis`].
+    pub fn emojis(&self) -> emojis::Emojis {
+        emojis::Emojis::new(self.inner.emojis(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::enterprise_admin`].
+    pub fn enterprise_admin(&self) -> enterprise_admin::EnterpriseAdmin {
+        enterprise_admin::EnterpriseAdmin::new(self.inner.enterprise_admin(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::gists`].
+    pub fn gists(&self) -> gists::Gists {
+        gists::Gists::new(self.inner.gists(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::git`].
+    pub fn git(&self) -> git::Git {
+        git::Git::new(self.inner.git(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::gitignore`].
+    pub fn gitignore(&self) -> gitignore::Gitignore {
+        gitignore::Gitignore::new(self.inner.gitignore(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::interactions`].
+    pub fn interactions(&self) -> interactions::Interactions {
+        interactions::Interactions::new(self.inner.interactions(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::issues`].
+    pub fn issues(&self) -> issues::Issues {
+        issues::Issues::new(self.inner.issues(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::licenses`].
+    pub fn licenses(&self) -> licenses::Licenses {
+        licenses::Licenses::new(self.inner.licenses(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::markdown`].
+    pub fn markdown(&self) -> markdown::Markdown {
+        markdown::Markdown::new(self.inner.markdown(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::meta`].
+    pub fn meta(&self) -> meta::Meta {
+        meta::Meta::new(self.inner.meta(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::migrations`].
+    pub fn migrations(&self) -> migrations::Migrations {
+        migrations::Migrations::new(self.inner.migrations(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::oauth_authorizations`].
+    pub fn oauth_authorizations(&self) -> oauth_authorizations::OauthAuthorizations {
+        oauth_authorizations::OauthAuthorizations::new(
+            self.inner.oauth_authorizations(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::orgs`].
+    pub fn orgs(&self) -> orgs::Orgs {
+        orgs::Orgs::new(self.inner.orgs(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::packages`].
+    pub fn packages(&self) -> packages::Packages {
+        packages::Packages::new(self.inner.packages(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::projects`].
+    pub fn projects(&self) -> projects::Projects {
+        projects::Projects::new(self.inner.projects(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::pulls`].
+    pub fn pulls(&self) -> pulls::Pulls {
+        pulls::Pulls::new(self.inner.pulls(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::rate_limit`].
+    pub fn rate_limit(&self) -> rate_limit::RateLimit {
+        rate_limit::RateLimit::new(self.inner.rate_limit(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::reactions`].
+    pub fn reactions(&self) -> reactions::Reactions {
+        reactions::Reactions::new(self.inner.reactions(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::repos`].
+    pub fn repos(&self) -> repos::Repos {
+        repos::Repos::new(self.inner.repos(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::scim`].
+    pub fn scim(&self) -> scim::Scim {
+        scim::Scim::new(self.inner.scim(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::search`].
+    pub fn search(&self) -> search::Search {
+        search::Search::new(self.inner.search(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::secret_scanning`].
+    pub fn secret_scanning(&self) -> secret_scanning::SecretScanning {
+        secret_scanning::SecretScanning::new(self.inner.secret_scanning(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::teams`].
+    pub fn teams(&self) -> teams::Teams {
+        teams::Teams::new(self.inner.teams(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::users`].
+    pub fn users(&self) -> users::Users {
+        users::Users::new(self.inner.users(), self.runtime.clone())
+    }
+}
@@ -0,0 +1,193 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`OauthAuthorizations::list_grants`], set with a builder.
+pub struct OauthAuthorizationsListGrantsRequest<'a> {
+    inner: crate::oauth_authorizations::OauthAuthorizationsListGrantsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OauthAuthorizationsListGrantsRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sets the `client_id` query parameter.
+    pub fn client_id(mut self, client_id: &'a str) -> Self {
+        self.inner = self.inner.client_id(client_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::ApplicationGrant>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`OauthAuthorizations::list_authorizations`], set with a builder.
+pub struct OauthAuthorizationsListAuthorizationsRequest<'a> {
+    inner: crate::oauth_authorizations::OauthAuthorizationsListAuthorizationsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OauthAuthorizationsListAuthorizationsRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sets the `client_id` query parameter.
+    pub fn client_id(mut self, client_id: &'a str) -> Self {
+        self.inner = self.inner.client_id(client_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Authorization>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::oauth_authorizations::OauthAuthorizations`].
+pub struct OauthAuthorizations {
+    inner: crate::oauth_authorizations::OauthAuthorizations,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl OauthAuthorizations {
+    pub(crate) fn new(
+        inner: crate::oauth_authorizations::OauthAuthorizations,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        OauthAuthorizations { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::oauth_authorizations::OauthAuthorizations::list_grants`].
+    pub fn list_grants(
+        &self,
+        per_page: i64,
+        page: i64,
+        client_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ApplicationGrant>>> {
+        self.runtime
+            .block_on(self.inner.list_grants(per_page, page, client_id))
+    }
+    /// The blocking version of [`crate::oauth_authorizations::OauthAuthorizations::list_grants_request`].
+    pub fn list_grants_request(&self) -> OauthAuthorizationsListGrantsRequest<'_> {
+        OauthAuthorizationsListGrantsRequest {
+            inner: self.inner.list_grants_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::oauth_authorizations::OauthAuthorizations::list_all_grants`].
+    pub fn list_all_grants(
+        &self,
+        client_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ApplicationGrant>>> {
+        self.runtime.block_on(self.inner.list_all_grants(client_id))
+    }
+    /// The blocking version of [`crate::oauth_authorizations::OauthAuthorizations::get_grant`].
+    pub fn get_grant(
+        &self,
+        grant_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::ApplicationGrant>> {
+        self.runtime.block_on(self.inner.get_grant(grant_id))
+    }
+    /// The blocking version of [`crate::oauth_authorizations::OauthAuthorizations::delete_grant`].
+    pub fn delete_grant(&self, grant_id: i64) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.delete_grant(grant_id))
+    }
+    /// The blocking version of [`crate::oauth_authorizations::OauthAuthorizations::list_authorizations`].
+    pub fn list_authorizations(
+        &self,
+        per_page: i64,
+        page: i64,
+        client_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Authorization>>> {
+        self.runtime
+            .block_on(self.inner.list_authorizations(per_page, page, client_id))
+    }
+    /// The blocking version of [`crate::oauth_authorizations::OauthAuthorizations::list_authorizations_request`].
+    pub fn list_authorizations_request(&self) -> OauthAuthorizationsListAuthorizationsRequest<'_> {
+        OauthAuthorizationsListAuthorizationsRequest {
+            inner: self.inner.list_authorizations_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::oauth_authorizations::OauthAuthorizations::list_all_authorizations`].
+    pub fn list_all_authorizations(
+        &self,
+        client_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Authorization>>> {
+        self.runtime
+            .block_on(self.inner.list_all_authorizations(client_id))
+    }
+    /// The blocking version of [`crate::oauth_authorizations::OauthAuthorizations::create_authorization`].
+    pub fn create_authorization(
+        &self,
+        body: &crate::types::OauthAuthorizationsCreateAuthorizationRequest,
+    ) -> ClientResult<crate::Response<crate::types::Authorization>> {
+        self.runtime.block_on(self.inner.create_authorization(body))
+    }
+    /// The blocking version of [`crate::oauth_authorizations::OauthAuthorizations::get_or_create_authorization_for_app`].
+    pub fn get_or_create_authorization_for_app(
+        &self,
+        client_id: &str,
+        body: &crate::types::OauthAuthorizationsGetCreateAuthorizationAppRequest,
+    ) -> ClientResult<crate::Response<crate::types::Authorization>> {
+        self.runtime.block_on(
+            self.inner
+                .get_or_create_authorization_for_app(client_id, body),
+        )
+    }
+    /// The blocking version of [`crate::oauth_authorizations::OauthAuthorizations::get_or_create_authorization_for_app_and_fingerprint`].
+    pub fn get_or_create_authorization_for_app_and_fingerprint(
+        &self,
+        client_id: &str,
+        fingerprint: &str,
+        body: &crate::types::OauthAuthorizationsGetCreateAuthorizationAppFingerprintRequest,
+    ) -> ClientResult<crate::Response<crate::types::Authorization>> {
+        self.runtime.block_on(
+            self.inner
+                .get_or_create_authorization_for_app_and_fingerprint(client_id, fingerprint, body),
+        )
+    }
+    /// The blocking version of [`crate::oauth_authorizations::OauthAuthorizations::get_authorization`].
+    pub fn get_authorization(
+        &self,
+        authorization_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::Authorization>> {
+        self.runtime
+            .block_on(self.inner.get_authorization(authorization_id))
+    }
+    /// The blocking version of [`crate::oauth_authorizations::OauthAuthorizations::delete_authorization`].
+    pub fn delete_authorization(&self, authorization_id: i64) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete_authorization(authorization_id))
+    }
+    /// The blocking version of [`crate::oauth_authorizations::OauthAuthorizations::update_authorization`].
+    pub fn update_authorization(
+        &self,
+        authorization_id: i64,
+        body: &crate::types::OauthAuthorizationsUpdateAuthorizationRequest,
+    ) -> ClientResult<crate::Response<crate::types::Authorization>> {
+        self.runtime
+            .block_on(self.inner.update_authorization(authorization_id, body))
+    }
+}
@@ -0,0 +1,1094 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Orgs::list`], set with a builder.
+pub struct OrgsListRequest<'a> {
+    inner: crate::orgs::OrgsListRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OrgsListRequest<'a> {
+    /// Sets the `since` query parameter.
+    pub fn since(mut self, since: i64) -> Self {
+        self.inner = self.inner.since(since);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::OrganizationSimple>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Orgs::get_audit_log`], set with a builder.
+pub struct OrgsGetAuditLogRequest<'a> {
+    inner: crate::orgs::OrgsGetAuditLogRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OrgsGetAuditLogRequest<'a> {
+    /// Sets the `phrase` query parameter.
+    pub fn phrase(mut self, phrase: &'a str) -> Self {
+        self.inner = self.inner.phrase(phrase);
+        self
+    }
+
+    /// Sets the `include` query parameter.
+    pub fn include(mut self, include: crate::types::Include) -> Self {
+        self.inner = self.inner.include(include);
+        self
+    }
+
+    /// Sets the `after` query parameter.
+    pub fn after(mut self, after: &'a str) -> Self {
+        self.inner = self.inner.after(after);
+        self
+    }
+
+    /// Sets the `before` query parameter.
+    pub fn before(mut self, before: &'a str) -> Self {
+        self.inner = self.inner.before(before);
+        self
+    }
+
+    /// Sets the `order` query parameter.
+    pub fn order(mut self, order: crate::types::Order) -> Self {
+        self.inner = self.inner.order(order);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::AuditLogEvent>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Orgs::list_failed_invitations`], set with a builder.
+pub struct OrgsListFailedInvitationsRequest<'a> {
+    inner: crate::orgs::OrgsListFailedInvitationsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OrgsListFailedInvitationsRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::OrganizationInvitation>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Orgs::list_webhooks`], set with a builder.
+pub struct OrgsListWebhooksRequest<'a> {
+    inner: crate::orgs::OrgsListWebhooksRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OrgsListWebhooksRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::OrgHook>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Orgs::list_webhook_deliveries`], set with a builder.
+pub struct OrgsListWebhookDeliveriesRequest<'a> {
+    inner: crate::orgs::OrgsListWebhookDeliveriesRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OrgsListWebhookDeliveriesRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `cursor` query parameter.
+    pub fn cursor(mut self, cursor: &'a str) -> Self {
+        self.inner = self.inner.cursor(cursor);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::HookDeliveryItem>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Orgs::list_app_installations`], set with a builder.
+pub struct OrgsListAppInstallationsRequest<'a> {
+    inner: crate::orgs::OrgsListAppInstallationsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OrgsListAppInstallationsRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::AppsListInstallationsResponse>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Orgs::list_pending_invitations`], set with a builder.
+pub struct OrgsListPendingInvitationsRequest<'a> {
+    inner: crate::orgs::OrgsListPendingInvitationsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OrgsListPendingInvitationsRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::OrganizationInvitation>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Orgs::list_invitation_teams`], set with a builder.
+pub struct OrgsListInvitationTeamsRequest<'a> {
+    inner: crate::orgs::OrgsListInvitationTeamsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OrgsListInvitationTeamsRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Team>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Orgs::list_members`], set with a builder.
+pub struct OrgsListMembersRequest<'a> {
+    inner: crate::orgs::OrgsListMembersRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OrgsListMembersRequest<'a> {
+    /// Sets the `filter` query parameter.
+    pub fn filter(mut self, filter: crate::types::OrgsListMembersFilter) -> Self {
+        self.inner = self.inner.filter(filter);
+        self
+    }
+
+    /// Sets the `role` query parameter.
+    pub fn role(mut self, role: crate::types::OrgsListMembersRole) -> Self {
+        self.inner = self.inner.role(role);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::SimpleUser>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Orgs::list_outside_collaborators`], set with a builder.
+pub struct OrgsListOutsideCollaboratorsRequest<'a> {
+    inner: crate::orgs::OrgsListOutsideCollaboratorsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OrgsListOutsideCollaboratorsRequest<'a> {
+    /// Sets the `filter` query parameter.
+    pub fn filter(mut self, filter: crate::types::OrgsListMembersFilter) -> Self {
+        self.inner = self.inner.filter(filter);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::SimpleUser>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Orgs::list_public_members`], set with a builder.
+pub struct OrgsListPublicMembersRequest<'a> {
+    inner: crate::orgs::OrgsListPublicMembersRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OrgsListPublicMembersRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::SimpleUser>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Orgs::list_memberships_for_authenticated_user`], set with a builder.
+pub struct OrgsListMembershipsForAuthenticatedUserRequest<'a> {
+    inner: crate::orgs::OrgsListMembershipsForAuthenticatedUserRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OrgsListMembershipsForAuthenticatedUserRequest<'a> {
+    /// Sets the `state` query parameter.
+    pub fn state(mut self, state: crate::types::OrgMembershipState) -> Self {
+        self.inner = self.inner.state(state);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::OrgMembership>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Orgs::list_for_authenticated_user`], set with a builder.
+pub struct OrgsListForAuthenticatedUserRequest<'a> {
+    inner: crate::orgs::OrgsListForAuthenticatedUserRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OrgsListForAuthenticatedUserRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::OrganizationSimple>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Orgs::list_for_user`], set with a builder.
+pub struct OrgsListForUserRequest<'a> {
+    inner: crate::orgs::OrgsListForUserRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OrgsListForUserRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::OrganizationSimple>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::orgs::Orgs`].
+pub struct Orgs {
+    inner: crate::orgs::Orgs,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Orgs {
+    pub(crate) fn new(inner: crate::orgs::Orgs, runtime: Arc<tokio::runtime::Runtime>) -> Self {
+        Orgs { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::orgs::Orgs::list`].
+    pub fn list(
+        &self,
+        since: i64,
+        per_page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::OrganizationSimple>>> {
+        self.runtime.block_on(self.inner.list(since, per_page))
+    }
+    /// The blocking version of [`crate::orgs::Orgs::list_request`].
+    pub fn list_request(&self) -> OrgsListRequest<'_> {
+        OrgsListRequest {
+            inner: self.inner.list_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::orgs::Orgs::list_all`].
+    pub fn list_all(
+        &self,
+        since: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::OrganizationSimple>>> {
+        self.runtime.block_on(self.inner.list_all(since))
+    }
+    /// The blocking version of [`crate::orgs::Orgs::get`].
+    pub fn get(&self, org: &str) -> ClientResult<crate::Response<crate::types::OrganizationFull>> {
+        self.runtime.block_on(self.inner.get(org))
+    }
+    /// The blocking version of [`crate::orgs::Orgs::update`].
+    pub fn update(
+        &self,
+        org: &str,
+        body: &crate::types::OrgsUpdateRequest,
+    ) -> ClientResult<crate::Response<crate::types::OrganizationFull>> {
+        self.runtime.block_on(self.inner.update(org, body))
+    }
+    /// The blocking version of [`crate::orgs::Orgs::get_audit_log`].
+    pub fn get_audit_log(
+        &self,
+        org: &str,
+        phrase: &str,
+        include: crate::types::Include,
+        after: &str,
+        before: &str,
+        order: crate::types::Order,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::AuditLogEvent>>> {
+        self.runtime.block_on(
+            self.inner
+                .get_audit_log(org, phrase, include, after, before, order, per_page, page),
+        )
+    }
+    /// The blocking version of [`crate::orgs::Orgs::get_audit_log_request`].
+    pub fn get_audit_log_request<'a>(&'a self, org: &'a str) -> OrgsGetAuditLogRequest<'a> {
+        OrgsGetAuditLogRequest {
+            inner: self.inner.get_audit_log_request(org),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::orgs::Orgs::get_all_audit_log`].
+    pub fn get_all_audit_log(
+        &self,
+        org: &str,
+        phrase: &str,
+        include: crate::types::Include,
+        after: &str,
+        before: &str,
+        order: crate::types::Order,
+    ) -> ClientResult<crate::Response<Vec<crate::types::AuditLogEvent>>> {
+        self.runtime.block_on(
+            self.inner
+                .get_all_audit_log(org, phrase, include, after, before, order),
+        )
+    }
+    /// The blocking version of [`crate::orgs::Orgs::list_blocked_users`].
+    pub fn list_blocked_users(
+        &self,
+        org: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::SimpleUser>>> {
+        self.runtime.block_on(self.inner.list_blocked_users(org))
+    }
+    /// The blocking version of [`crate::orgs::Orgs::list_all_blocked_users`].
+    pub fn list_all_blocked_users(
+        &self,
+        org: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::SimpleUser>>> {
+        self.runtime
+            .block_on(self.inner.list_all_blocked_users(org))
+    }
+    /// The blocking version of [`crate::orgs::Orgs::check_blocked_user`].
+    pub fn check_blocked_user(
+        &self,
+        org: &str,
+        username: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.check_blocked_user(org, username))
+    }
+    /// The blocking version of [`crate::orgs::Orgs::block_user`].
+    pub fn block_user(&self, org: &str, username: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.block_user(org, username))
+    }
+    /// The blocking version of [`crate::orgs::Orgs::unblock_user`].
+    pub fn unblock_user(&self, org: &str, username: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.unblock_user(org, username))
+    }
+    /// The blocking version of [`crate::orgs::Orgs::list_saml_sso_authorizations`].
+    pub fn list_saml_sso_authorizations(
+        &self,
+        org: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::CredentialAuthorization>>> {
+        self.runtime
+            .block_on(self.inner.list_saml_sso_authorizations(org))
+    }
+    /// The blocking version of [`crate::orgs::Orgs::list_all_saml_sso_authorizations`].
+    pub fn list_all_saml_sso_authorizations(
+        &self,
+        org: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::CredentialAuthorization>>> {
+        self.runtime
+            .block_on(self.inner.list_all_saml_sso_authorizations(org))
+    }
+    /// The blocking version of [`crate::orgs::Orgs::remove_saml_sso_authorization`].
+    pub fn remove_saml_sso_authorization(
+        &self,
+        org: &str,
+        credential_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.remove_saml_sso_authorization(org, credential_id))
+    }
+    /// The blocking version of [`crate::orgs::Orgs::list_failed_invitations`].
+    pub fn list_failed_invitations(
+        &self,
+        org: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::OrganizationInvitation>>> {
+        self.runtime
+            .block_on(self.inner.list_failed_invitations(org, per_page, page))
+    }
+    /// The blocking version of [`crate::orgs::Orgs::list_failed_invitations_request`].
+    pub fn list_failed_invitations_request<'a>(
+        &'a self,
+        org: &'a str,
+    ) -> OrgsListFailedInvitationsRequest<'a> {
+        OrgsListFailedInvitationsRequest {
+            inner: self.inner.list_failed_invitations_request(org),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::orgs::Orgs::list_all_failed_invitations`].
+    pub fn list_all_failed_invitations(
+        &self,
+        org: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::OrganizationInvitation>>> {
+        self.runtime
+            .block_on(self.inner.list_all_failed_invitations(org))
+    }
+    /// The blocking version of [`crate::orgs::Orgs::list_webhooks`].
+    pub fn list_webhooks(
+        &self,
+        org: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::OrgHook>>> {
+        self.runtime
+            .block_on(self.inner.list_webhooks(org, per_page, page))
+    }
+    /// The blocking version of [`crate::orgs::Orgs::list_webhooks_request`].
+    pub fn list_webhooks_request<'a>(&'a self, org: &'a str) -> OrgsListWebhooksRequest<'a> {
+        OrgsListWebhooksRequest {
+            inner: self.inner.list_webhooks_request(org),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::orgs::Orgs::list_all_webhooks`].
+    pub fn list_all_webhooks(
+        &self,
+        org: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::OrgHook>>> {
+        self.runtime.block_on(self.inner.list_all_webhooks(org))
+    }
+    /// The blocking version of [`crate::orgs::Orgs::create_webhook`].
+    pub fn create_webhook(
+        &self,
+        org: &str,
+        body: &crate::types::OrgsCreateWebhookRequest,
+    ) -> ClientResult<crate::Response<crate::types::OrgHook>> {
+        self.runtime.block_on(self.inner.create_webhook(org, body))
+    }
+    /// The blocking version of [`crate::orgs::Orgs::get_webhook`].
+    pub fn get_webhook(
+        &self,
+        org: &str,
+        hook_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::OrgHook>> {
+        self.runtime.block_on(self.inner.get_webhook(org, hook_id))
+    }
+    /// The blocking version of [`crate::orgs::Orgs::delete_webhook`].
+    pub fn delete_webhook(&self, org: &str, hook_id: i64) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete_webhook(org, hook_id))
+    }
+    /// The blocking version of [`crate::orgs::Orgs::update_webhook`].
+    pub fn update_webhook(
+        &self,
+        org: &str,
+        hook_id: i64,
+        body: &crate::types::OrgsUpdateWebhookRequest,
+    ) -> ClientResult<crate::Response<crate::types::OrgHook>> {
+        self.runtime
+            .block_on(self.inner.update_webhook(org, hook_id, body))
+    }
+    /// The blocking version of [`crate::orgs::Orgs::get_webhook_config_for_org`].
+    pub fn get_webhook_config_for_org(
+        &self,
+        org: &str,
+        hook_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::WebhookConfig>> {
+        self.runtime
+            .block_on(self.inner.get_webhook_config_for_org(org, hook_id))
+    }
+    /// The blocking version of [`crate::orgs::Orgs::update_webhook_config_for_org`].
+    pub fn update_webhook_config_for_org(
+        &self,
+        org: &str,
+        hook_id: i64,
+        body: &crate::types::AppsUpdateWebhookConfigAppRequest,
+    ) -> ClientResult<crate::Response<crate::types::WebhookConfig>> {
+        self.runtime
+            .block_on(self.inner.update_webhook_config_for_org(org, hook_id, body))
+    }
+    /// The blocking version of [`crate::orgs::Orgs::list_webhook_deliveries`].
+    pub fn list_webhook_deliveries(
+        &self,
+        org: &str,
+        hook_id: i64,
+        per_page: i64,
+        cursor: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::HookDeliveryItem>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_webhook_deliveries(org, hook_id, per_page, cursor),
+        )
+    }
+    /// The blocking version of [`crate::orgs::Orgs::list_webhook_deliveries_request`].
+    pub fn list_webhook_deliveries_request<'a>(
+        &'a self,
+        org: &'a str,
+        hook_id: i64,
+    ) -> OrgsListWebhookDeliveriesRequest<'a> {
+        OrgsListWebhookDeliveriesRequest {
+            inner: self.inner.list_webhook_deliveries_request(org, hook_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::orgs::Orgs::list_all_webhook_deliveries`].
+    pub fn list_all_webhook_deliveries(
+        &self,
+        org: &str,
+        hook_id: i64,
+        cursor: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::HookDeliveryItem>>> {
+        self.runtime
+            .block_on(self.inner.list_all_webhook_deliveries(org, hook_id, cursor))
+    }
+    /// The blocking version of [`crate::orgs::Orgs::get_webhook_delivery`].
+    pub fn get_webhook_delivery(
+        &self,
+        org: &str,
+        hook_id: i64,
+        delivery_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::HookDelivery>> {
+        self.runtime
+            .block_on(self.inner.get_webhook_delivery(org, hook_id, delivery_id))
+    }
+    /// The blocking version of [`crate::orgs::Orgs::redeliver_webhook_delivery`].
+    pub fn redeliver_webhook_delivery(
+        &self,
+        org: &str,
+        hook_id: i64,
+        delivery_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .redeliver_webhook_delivery(org, hook_id, delivery_id),
+        )
+    }
+    /// The blocking version of [`crate::orgs::Orgs::ping_webhook`].
+    pub fn ping_webhook(&self, org: &str, hook_id: i64) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.ping_webhook(org, hook_id))
+    }
+    /// The blocking version of [`crate::orgs::Orgs::list_app_installations`].
+    pub fn list_app_installations(
+        &self,
+        org: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<crate::types::AppsListInstallationsResponse>> {
+        self.runtime
+            .block_on(self.inner.list_app_installations(org, per_page, page))
+    }
+    /// The blocking version of [`crate::orgs::Orgs::list_app_installations_request`].
+    pub fn list_app_installations_request<'a>(
+        &'a self,
+        org: &'a str,
+    ) -> OrgsListAppInstallationsRequest<'a> {
+        OrgsListAppInstallationsRequest {
+            inner: self.inner.list_app_installations_request(org),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::orgs::Orgs::list_pending_invitations`].
+    pub fn list_pending_invitations(
+        &self,
+        org: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::OrganizationInvitation>>> {
+        self.runtime
+            .block_on(self.inner.list_pending_invitations(org, per_page, page))
+    }
+    /// The blocking version of [`crate::orgs::Orgs::list_pending_invitations_request`].
+    pub fn list_pending_invitations_request<'a>(
+        &'a self,
+        org: &'a str,
+    ) -> OrgsListPendingInvitationsRequest<'a> {
+        OrgsListPendingInvitationsRequest {
+            inner: self.inner.list_pending_invitations_request(org),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::orgs::Orgs::list_all_pending_invitations`].
+    pub fn list_all_pending_invitations(
+        &self,
+        org: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::OrganizationInvitation>>> {
+        self.runtime
+            .block_on(self.inner.list_all_pending_invitations(org))
+    }
+    /// The blocking version of [`crate::orgs::Orgs::create_invitation`].
+    pub fn create_invitation(
+        &self,
+        org: &str,
+        body: &crate::types::OrgsCreateInvitationRequest,
+    ) -> ClientResult<crate::Response<crate::types::OrganizationInvitation>> {
+        self.runtime
+            .block_on(self.inner.create_invitation(org, body))
+    }
+    /// The blocking version of [`crate::orgs::Orgs::cancel_invitation`].
+    pub fn cancel_invitation(
+        &self,
+        org: &str,
+        invitation_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.cancel_invitation(org, invitation_id))
+    }
+    /// The blocking version of [`crate::orgs::Orgs::list_invitation_teams`].
+    pub fn list_invitation_teams(
+        &self,
+        org: &str,
+        invitation_id: i64,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Team>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_invitation_teams(org, invitation_id, per_page, page),
+        )
+    }
+    /// The blocking version of [`crate::orgs::Orgs::list_invitation_teams_request`].
+    pub fn list_invitation_teams_request<'a>(
+        &'a self,
+        org: &'a str,
+        invitation_id: i64,
+    ) -> OrgsListInvitationTeamsRequest<'a> {
+        OrgsListInvitationTeamsRequest {
+            inner: self.inner.list_invitation_teams_request(org, invitation_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::orgs::Orgs::list_all_invitation_teams`].
+    pub fn list_all_invitation_teams(
+        &self,
+        org: &str,
+        invitation_id: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Team>>> {
+        self.runtime
+            .block_on(self.inner.list_all_invitation_teams(org, invitation_id))
+    }
+    /// The blocking version of [`crate::orgs::Orgs::list_members`].
+    pub fn list_members(
+        &self,
+        org: &str,
+        filter: crate::types::OrgsListMembersFilter,
+        role: crate::types::OrgsListMembersRole,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::SimpleUser>>> {
+        self.runtime
+            .block_on(self.inner.list_members(org, filter, role, per_page, page))
+    }
+    /// The blocking version of [`crate::orgs::Orgs::list_members_request`].
+    pub fn list_members_request<'a>(&'a self, org: &'a str) -> OrgsListMembersRequest<'a> {
+        OrgsListMembersRequest {
+            inner: self.inner.list_members_request(org),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::orgs::Orgs::list_all_members`].
+    pub fn list_all_members(
+        &self,
+        org: &str,
+        filter: crate::types::OrgsListMembersFilter,
+        role: crate::types::OrgsListMembersRole,
+    ) -> ClientResult<crate::Response<Vec<crate::types::SimpleUser>>> {
+        self.runtime
+            .block_on(self.inner.list_all_members(org, filter, role))
+    }
+    /// The blocking version of [`crate::orgs::Orgs::check_membership_for_user`].
+    pub fn check_membership_for_user(
+        &self,
+        org: &str,
+        username: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.check_membership_for_user(org, username))
+    }
+    /// The blocking version of [`crate::orgs::Orgs::remove_member`].
+    pub fn remove_member(&self, org: &str, username: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.remove_member(org, username))
+    }
+    /// The blocking version of [`crate::orgs::Orgs::get_membership_for_user`].
+    pub fn get_membership_for_user(
+        &self,
+        org: &str,
+        username: &str,
+    ) -> ClientResult<crate::Response<crate::types::OrgMembership>> {
+        self.runtime
+            .block_on(self.inner.get_membership_for_user(org, username))
+    }
+    /// The blocking version of [`crate::orgs::Orgs::set_membership_for_user`].
+    pub fn set_membership_for_user(
+        &self,
+        org: &str,
+        username: &str,
+        body: &crate::types::OrgsSetMembershipUserRequest,
+    ) -> ClientResult<crate::Response<crate::types::OrgMembership>> {
+        self.runtime
+            .block_on(self.inner.set_membership_for_user(org, username, body))
+    }
+    /// The blocking version of [`crate::orgs::Orgs::remove_membership_for_user`].
+    pub fn remove_membership_for_user(
+        &self,
+        org: &str,
+        username: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.remove_membership_for_user(org, username))
+    }
+    /// The blocking version of [`crate::orgs::Orgs::list_outside_collaborators`].
+    pub fn list_outside_collaborators(
+        &self,
+        org: &str,
+        filter: crate::types::OrgsListMembersFilter,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::SimpleUser>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_outside_collaborators(org, filter, per_page, page),
+        )
+    }
+    /// The blocking version of [`crate::orgs::Orgs::list_outside_collaborators_request`].
+    pub fn list_outside_collaborators_request<'a>(
+        &'a self,
+        org: &'a str,
+    ) -> OrgsListOutsideCollaboratorsRequest<'a> {
+        OrgsListOutsideCollaboratorsRequest {
+            inner: self.inner.list_outside_collaborators_request(org),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::orgs::Orgs::list_all_outside_collaborators`].
+    pub fn list_all_outside_collaborators(
+        &self,
+        org: &str,
+        filter: crate::types::OrgsListMembersFilter,
+    ) -> ClientResult<crate::Response<Vec<crate::types::SimpleUser>>> {
+        self.runtime
+            .block_on(self.inner.list_all_outside_collaborators(org, filter))
+    }
+    /// The blocking version of [`crate::orgs::Orgs::convert_member_to_outside_collaborator`].
+    pub fn convert_member_to_outside_collaborator(
+        &self,
+        org: &str,
+        username: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .convert_member_to_outside_collaborator(org, username),
+        )
+    }
+    /// The blocking version of [`crate::orgs::Orgs::remove_outside_collaborator`].
+    pub fn remove_outside_collaborator(
+        &self,
+        org: &str,
+        username: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.remove_outside_collaborator(org, username))
+    }
+    /// The blocking version of [`crate::orgs::Orgs::list_public_members`].
+    pub fn list_public_members(
+        &self,
+        org: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::SimpleUser>>> {
+        self.runtime
+            .block_on(self.inner.list_public_members(org, per_page, page))
+    }
+    /// The blocking version of [`crate::orgs::Orgs::list_public_members_request`].
+    pub fn list_public_members_request<'a>(
+        &'a self,
+        org: &'a str,
+    ) -> OrgsListPublicMembersRequest<'a> {
+        OrgsListPublicMembersRequest {
+            inner: self.inner.list_public_members_request(org),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::orgs::Orgs::list_all_public_members`].
+    pub fn list_all_public_members(
+        &self,
+        org: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::SimpleUser>>> {
+        self.runtime
+            .block_on(self.inner.list_all_public_members(org))
+    }
+    /// The blocking version of [`crate::orgs::Orgs::check_public_membership_for_user`].
+    pub fn check_public_membership_for_user(
+        &self,
+        org: &str,
+        username: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.check_public_membership_for_user(org, username))
+    }
+    /// The blocking version of [`crate::orgs::Orgs::set_public_membership_for_authenticated_user`].
+    pub fn set_public_membership_for_authenticated_user(
+        &self,
+        org: &str,
+        username: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .set_public_membership_for_authenticated_user(org, username),
+        )
+    }
+    /// The blocking version of [`crate::orgs::Orgs::remove_public_membership_for_authenticated_user`].
+    pub fn remove_public_membership_for_authenticated_user(
+        &self,
+        org: &str,
+        username: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .remove_public_membership_for_authenticated_user(org, username),
+        )
+    }
+    /// The blocking version of [`crate::orgs::Orgs::list_memberships_for_authenticated_user`].
+    pub fn list_memberships_for_authenticated_user(
+        &self,
+        state: crate::types::OrgMembershipState,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::OrgMembership>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_memberships_for_authenticated_user(state, per_page, page),
+        )
+    }
+    /// The blocking version of [`crate::orgs::Orgs::list_memberships_for_authenticated_user_request`].
+    pub fn list_memberships_for_authenticated_user_request(
+        &self,
+    ) -> OrgsListMembershipsForAuthenticatedUserRequest<'_> {
+        OrgsListMembershipsForAuthenticatedUserRequest {
+            inner: self.inner.list_memberships_for_authenticated_user_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::orgs::Orgs::list_all_memberships_for_authenticated_user`].
+    pub fn list_all_memberships_for_authenticated_user(
+        &self,
+        state: crate::types::OrgMembershipState,
+    ) -> ClientResult<crate::Response<Vec<crate::types::OrgMembership>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_all_memberships_for_authenticated_user(state),
+        )
+    }
+    /// The blocking version of [`crate::orgs::Orgs::get_membership_for_authenticated_user`].
+    pub fn get_membership_for_authenticated_user(
+        &self,
+        org: &str,
+    ) -> ClientResult<crate::Response<crate::types::OrgMembership>> {
+        self.runtime
+            .block_on(self.inner.get_membership_for_authenticated_user(org))
+    }
+    /// The blocking version of [`crate::orgs::Orgs::update_membership_for_authenticated_user`].
+    pub fn update_membership_for_authenticated_user(
+        &self,
+        org: &str,
+        body: &crate::types::OrgsUpdateMembershipRequest,
+    ) -> ClientResult<crate::Response<crate::types::OrgMembership>> {
+        self.runtime.block_on(
+            self.inner
+                .update_membership_for_authenticated_user(org, body),
+        )
+    }
+    /// The blocking version of [`crate::orgs::Orgs::list_for_authenticated_user`].
+    pub fn list_for_authenticated_user(
+        &self,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::OrganizationSimple>>> {
+        self.runtime
+            .block_on(self.inner.list_for_authenticated_user(per_page, page))
+    }
+    /// The blocking version of [`crate::orgs::Orgs::list_for_authenticated_user_request`].
+    pub fn list_for_authenticated_user_request(&self) -> OrgsListForAuthenticatedUserRequest<'_> {
+        OrgsListForAuthenticatedUserRequest {
+            inner: self.inner.list_for_authenticated_user_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::orgs::Orgs::list_all_for_authenticated_user`].
+    pub fn list_all_for_authenticated_user(
+        &self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::OrganizationSimple>>> {
+        self.runtime
+            .block_on(self.inner.list_all_for_authenticated_user())
+    }
+    /// The blocking version of [`crate::orgs::Orgs::list_for_user`].
+    pub fn list_for_user(
+        &self,
+        username: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::OrganizationSimple>>> {
+        self.runtime
+            .block_on(self.inner.list_for_user(username, per_page, page))
+    }
+    /// The blocking version of [`crate::orgs::Orgs::list_for_user_request`].
+    pub fn list_for_user_request<'a>(&'a self, username: &'a str) -> OrgsListForUserRequest<'a> {
+        OrgsListForUserRequest {
+            inner: self.inner.list_for_user_request(username),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::orgs::Orgs::list_all_for_user`].
+    pub fn list_all_for_user(
+        &self,
+        username: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::OrganizationSimple>>> {
+        self.runtime
+            .block_on(self.inner.list_all_for_user(username))
+    }
+}
@@ -0,0 +1,489 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Packages::restore_package_for_org`], set with a builder.
+pub struct PackagesRestorePackageForOrgRequest<'a> {
+    inner: crate::packages::PackagesRestorePackageForOrgRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> PackagesRestorePackageForOrgRequest<'a> {
+    /// Sets the `token` query parameter.
+    pub fn token(mut self, token: &'a str) -> Self {
+        self.inner = self.inner.token(token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Packages::get_all_package_versions_for_package_owned_by_org`], set with a builder.
+pub struct PackagesGetAllPackageVersionsForPackageOwnedByOrgRequest<'a> {
+    inner: crate::packages::PackagesGetAllPackageVersionsForPackageOwnedByOrgRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> PackagesGetAllPackageVersionsForPackageOwnedByOrgRequest<'a> {
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `state` query parameter.
+    pub fn state(
+        mut self,
+        state: crate::types::PackagesGetAllPackageVersionsOwnedByOrgState,
+    ) -> Self {
+        self.inner = self.inner.state(state);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::PackageVersion>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Packages::restore_package_for_authenticated_user`], set with a builder.
+pub struct PackagesRestorePackageForAuthenticatedUserRequest<'a> {
+    inner: crate::packages::PackagesRestorePackageForAuthenticatedUserRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> PackagesRestorePackageForAuthenticatedUserRequest<'a> {
+    /// Sets the `token` query parameter.
+    pub fn token(mut self, token: &'a str) -> Self {
+        self.inner = self.inner.token(token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Packages::get_all_package_versions_for_package_owned_by_authenticated_user`], set with a builder.
+pub struct PackagesGetAllPackageVersionsForPackageOwnedByAuthenticatedUserRequest<'a> {
+    inner:
+        crate::packages::PackagesGetAllPackageVersionsForPackageOwnedByAuthenticatedUserRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> PackagesGetAllPackageVersionsForPackageOwnedByAuthenticatedUserRequest<'a> {
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `state` query parameter.
+    pub fn state(
+        mut self,
+        state: crate::types::PackagesGetAllPackageVersionsOwnedByOrgState,
+    ) -> Self {
+        self.inner = self.inner.state(state);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::PackageVersion>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::packages::Packages`].
+pub struct Packages {
+    inner: crate::packages::Packages,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Packages {
+    pub(crate) fn new(
+        inner: crate::packages::Packages,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Packages { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::packages::Packages::get_package_for_organization`].
+    pub fn get_package_for_organization(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+        org: &str,
+    ) -> ClientResult<crate::Response<crate::types::Package>> {
+        self.runtime
+            .block_on(
+                self.inner
+                    .get_package_for_organization(package_type, package_name, org),
+            )
+    }
+    /// The blocking version of [`crate::packages::Packages::delete_package_for_org`].
+    pub fn delete_package_for_org(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+        org: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .delete_package_for_org(package_type, package_name, org),
+        )
+    }
+    /// The blocking version of [`crate::packages::Packages::restore_package_for_org`].
+    pub fn restore_package_for_org(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+        org: &str,
+        token: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.restore_package_for_org(
+            package_type,
+            package_name,
+            org,
+            token,
+        ))
+    }
+    /// The blocking version of [`crate::packages::Packages::restore_package_for_org_request`].
+    pub fn restore_package_for_org_request<'a>(
+        &'a self,
+        package_type: crate::types::PackageType,
+        package_name: &'a str,
+        org: &'a str,
+    ) -> PackagesRestorePackageForOrgRequest<'a> {
+        PackagesRestorePackageForOrgRequest {
+            inner: self
+                .inner
+                .restore_package_for_org_request(package_type, package_name, org),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::packages::Packages::get_all_package_versions_for_package_owned_by_org`].
+    pub fn get_all_package_versions_for_package_owned_by_org(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+        org: &str,
+        page: i64,
+        per_page: i64,
+        state: crate::types::PackagesGetAllPackageVersionsOwnedByOrgState,
+    ) -> ClientResult<crate::Response<Vec<crate::types::PackageVersion>>> {
+        self.runtime.block_on(
+            self.inner
+                .get_all_package_versions_for_package_owned_by_org(
+                    package_type,
+                    package_name,
+                    org,
+                    page,
+                    per_page,
+                    state,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::packages::Packages::get_all_package_versions_for_package_owned_by_org_request`].
+    pub fn get_all_package_versions_for_package_owned_by_org_request<'a>(
+        &'a self,
+        package_type: crate::types::PackageType,
+        package_name: &'a str,
+        org: &'a str,
+    ) -> PackagesGetAllPackageVersionsForPackageOwnedByOrgRequest<'a> {
+        PackagesGetAllPackageVersionsForPackageOwnedByOrgRequest {
+            inner: self
+                .inner
+                .get_all_package_versions_for_package_owned_by_org_request(
+                    package_type,
+                    package_name,
+                    org,
+                ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::packages::Packages::get_all_all_package_versions_for_package_owned_by_org`].
+    pub fn get_all_all_package_versions_for_package_owned_by_org(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+        org: &str,
+        state: crate::types::PackagesGetAllPackageVersionsOwnedByOrgState,
+    ) -> ClientResult<crate::Response<Vec<crate::types::PackageVersion>>> {
+        self.runtime.block_on(
+            self.inner
+                .get_all_all_package_versions_for_package_owned_by_org(
+                    package_type,
+                    package_name,
+                    org,
+                    state,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::packages::Packages::get_package_version_for_organization`].
+    pub fn get_package_version_for_organization(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+        org: &str,
+        package_version_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::PackageVersion>> {
+        self.runtime
+            .block_on(self.inner.get_package_version_for_organization(
+                package_type,
+                package_name,
+                org,
+                package_version_id,
+            ))
+    }
+    /// The blocking version of [`crate::packages::Packages::delete_package_version_for_org`].
+    pub fn delete_package_version_for_org(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+        org: &str,
+        package_version_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete_package_version_for_org(
+                package_type,
+                package_name,
+                org,
+                package_version_id,
+            ))
+    }
+    /// The blocking version of [`crate::packages::Packages::restore_package_version_for_org`].
+    pub fn restore_package_version_for_org(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+        org: &str,
+        package_version_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.restore_package_version_for_org(
+                package_type,
+                package_name,
+                org,
+                package_version_id,
+            ))
+    }
+    /// The blocking version of [`crate::packages::Packages::get_package_for_authenticated_user`].
+    pub fn get_package_for_authenticated_user(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+    ) -> ClientResult<crate::Response<crate::types::Package>> {
+        self.runtime.block_on(
+            self.inner
+                .get_package_for_authenticated_user(package_type, package_name),
+        )
+    }
+    /// The blocking version of [`crate::packages::Packages::delete_package_for_authenticated_user`].
+    pub fn delete_package_for_authenticated_user(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .delete_package_for_authenticated_user(package_type, package_name),
+        )
+    }
+    /// The blocking version of [`crate::packages::Packages::restore_package_for_authenticated_user`].
+    pub fn restore_package_for_authenticated_user(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+        token: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.restore_package_for_authenticated_user(
+                package_type,
+                package_name,
+                token,
+            ))
+    }
+    /// The blocking version of [`crate::packages::Packages::restore_package_for_authenticated_user_request`].
+    pub fn restore_package_for_authenticated_user_request<'a>(
+        &'a self,
+        package_type: crate::types::PackageType,
+        package_name: &'a str,
+    ) -> PackagesRestorePackageForAuthenticatedUserRequest<'a> {
+        PackagesRestorePackageForAuthenticatedUserRequest {
+            inner: self
+                .inner
+                .restore_package_for_authenticated_user_request(package_type, package_name),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::packages::Packages::get_all_package_versions_for_package_owned_by_authenticated_user`].
+    pub fn get_all_package_versions_for_package_owned_by_authenticated_user(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+        page: i64,
+        per_page: i64,
+        state: crate::types::PackagesGetAllPackageVersionsOwnedByOrgState,
+    ) -> ClientResult<crate::Response<Vec<crate::types::PackageVersion>>> {
+        self.runtime.block_on(
+            self.inner
+                .get_all_package_versions_for_package_owned_by_authenticated_user(
+                    package_type,
+                    package_name,
+                    page,
+                    per_page,
+                    state,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::packages::Packages::get_all_package_versions_for_package_owned_by_authenticated_user_request`].
+    pub fn get_all_package_versions_for_package_owned_by_authenticated_user_request<'a>(
+        &'a self,
+        package_type: crate::types::PackageType,
+        package_name: &'a str,
+    ) -> PackagesGetAllPackageVersionsForPackageOwnedByAuthenticatedUserRequest<'a> {
+        PackagesGetAllPackageVersionsForPackageOwnedByAuthenticatedUserRequest {
+            inner: self
+                .inner
+                .get_all_package_versions_for_package_owned_by_authenticated_user_request(
+                    package_type,
+                    package_name,
+                ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::packages::Packages::get_all_all_package_versions_for_package_owned_by_authenticated_user`].
+    pub fn get_all_all_package_versions_for_package_owned_by_authenticated_user(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+        state: crate::types::PackagesGetAllPackageVersionsOwnedByOrgState,
+    ) -> ClientResult<crate::Response<Vec<crate::types::PackageVersion>>> {
+        self.runtime.block_on(
+            self.inner
+                .get_all_all_package_versions_for_package_owned_by_authenticated_user(
+                    package_type,
+                    package_name,
+                    state,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::packages::Packages::get_package_version_for_authenticated_user`].
+    pub fn get_package_version_for_authenticated_user(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+        package_version_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::PackageVersion>> {
+        self.runtime
+            .block_on(self.inner.get_package_version_for_authenticated_user(
+                package_type,
+                package_name,
+                package_version_id,
+            ))
+    }
+    /// The blocking version of [`crate::packages::Packages::delete_package_version_for_authenticated_user`].
+    pub fn delete_package_version_for_authenticated_user(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+        package_version_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete_package_version_for_authenticated_user(
+                package_type,
+                package_name,
+                package_version_id,
+            ))
+    }
+    /// The blocking version of [`crate::packages::Packages::restore_package_version_for_authenticated_user`].
+    pub fn restore_package_version_for_authenticated_user(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+        package_version_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.restore_package_version_for_authenticated_user(
+                package_type,
+                package_name,
+                package_version_id,
+            ))
+    }
+    /// The blocking version of [`crate::packages::Packages::get_package_for_user`].
+    pub fn get_package_for_user(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+        username: &str,
+    ) -> ClientResult<crate::Response<crate::types::Package>> {
+        self.runtime.block_on(
+            self.inner
+                .get_package_for_user(package_type, package_name, username),
+        )
+    }
+    /// The blocking version of [`crate::packages::Packages::get_all_package_versions_for_package_owned_by_user`].
+    pub fn get_all_package_versions_for_package_owned_by_user(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+        username: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::PackageVersion>>> {
+        self.runtime.block_on(
+            self.inner
+                .get_all_package_versions_for_package_owned_by_user(
+                    package_type,
+                    package_name,
+                    username,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::packages::Packages::get_all_all_package_versions_for_package_owned_by_user`].
+    pub fn get_all_all_package_versions_for_package_owned_by_user(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+        username: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::PackageVersion>>> {
+        self.runtime.block_on(
+            self.inner
+                .get_all_all_package_versions_for_package_owned_by_user(
+                    package_type,
+                    package_name,
+                    username,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::packages::Packages::get_package_version_for_user`].
+    pub fn get_package_version_for_user(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+        package_version_id: i64,
+        username: &str,
+    ) -> ClientResult<crate::Response<crate::types::PackageVersion>> {
+        self.runtime
+            .block_on(self.inner.get_package_version_for_user(
+                package_type,
+                package_name,
+                package_version_id,
+                username,
+            ))
+    }
+}
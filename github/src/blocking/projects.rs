@@ -0,0 +1,521 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Projects::list_for_org`], set with a builder.
+pub struct ProjectsListForOrgRequest<'a> {
+    inner: crate::projects::ProjectsListForOrgRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProjectsListForOrgRequest<'a> {
+    /// Sets the `state` query parameter.
+    pub fn state(mut self, state: crate::types::IssuesListState) -> Self {
+        self.inner = self.inner.state(state);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Project>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Projects::list_cards`], set with a builder.
+pub struct ProjectsListCardsRequest<'a> {
+    inner: crate::projects::ProjectsListCardsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProjectsListCardsRequest<'a> {
+    /// Sets the `archived_state` query parameter.
+    pub fn archived_state(mut self, archived_state: crate::types::ArchivedState) -> Self {
+        self.inner = self.inner.archived_state(archived_state);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::ProjectCard>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Projects::list_collaborators`], set with a builder.
+pub struct ProjectsListCollaboratorsRequest<'a> {
+    inner: crate::projects::ProjectsListCollaboratorsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProjectsListCollaboratorsRequest<'a> {
+    /// Sets the `affiliation` query parameter.
+    pub fn affiliation(mut self, affiliation: crate::types::Affiliation) -> Self {
+        self.inner = self.inner.affiliation(affiliation);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::SimpleUser>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Projects::list_columns`], set with a builder.
+pub struct ProjectsListColumnsRequest<'a> {
+    inner: crate::projects::ProjectsListColumnsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProjectsListColumnsRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::ProjectColumn>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Projects::list_for_repo`], set with a builder.
+pub struct ProjectsListForRepoRequest<'a> {
+    inner: crate::projects::ProjectsListForRepoRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProjectsListForRepoRequest<'a> {
+    /// Sets the `state` query parameter.
+    pub fn state(mut self, state: crate::types::IssuesListState) -> Self {
+        self.inner = self.inner.state(state);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Project>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Projects::list_for_user`], set with a builder.
+pub struct ProjectsListForUserRequest<'a> {
+    inner: crate::projects::ProjectsListForUserRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProjectsListForUserRequest<'a> {
+    /// Sets the `state` query parameter.
+    pub fn state(mut self, state: crate::types::IssuesListState) -> Self {
+        self.inner = self.inner.state(state);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Project>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::projects::Projects`].
+pub struct Projects {
+    inner: crate::projects::Projects,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Projects {
+    pub(crate) fn new(
+        inner: crate::projects::Projects,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Projects { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::projects::Projects::list_for_org`].
+    pub fn list_for_org(
+        &self,
+        org: &str,
+        state: crate::types::IssuesListState,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Project>>> {
+        self.runtime
+            .block_on(self.inner.list_for_org(org, state, per_page, page))
+    }
+    /// The blocking version of [`crate::projects::Projects::list_for_org_request`].
+    pub fn list_for_org_request<'a>(&'a self, org: &'a str) -> ProjectsListForOrgRequest<'a> {
+        ProjectsListForOrgRequest {
+            inner: self.inner.list_for_org_request(org),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::projects::Projects::list_all_for_org`].
+    pub fn list_all_for_org(
+        &self,
+        org: &str,
+        state: crate::types::IssuesListState,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Project>>> {
+        self.runtime
+            .block_on(self.inner.list_all_for_org(org, state))
+    }
+    /// The blocking version of [`crate::projects::Projects::create_for_org`].
+    pub fn create_for_org(
+        &self,
+        org: &str,
+        body: &crate::types::ProjectsCreateRequest,
+    ) -> ClientResult<crate::Response<crate::types::Project>> {
+        self.runtime.block_on(self.inner.create_for_org(org, body))
+    }
+    /// The blocking version of [`crate::projects::Projects::get_card`].
+    pub fn get_card(
+        &self,
+        card_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::ProjectCard>> {
+        self.runtime.block_on(self.inner.get_card(card_id))
+    }
+    /// The blocking version of [`crate::projects::Projects::delete_card`].
+    pub fn delete_card(&self, card_id: i64) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.delete_card(card_id))
+    }
+    /// The blocking version of [`crate::projects::Projects::update_card`].
+    pub fn update_card(
+        &self,
+        card_id: i64,
+        body: &crate::types::ProjectsUpdateCardRequest,
+    ) -> ClientResult<crate::Response<crate::types::ProjectCard>> {
+        self.runtime.block_on(self.inner.update_card(card_id, body))
+    }
+    /// The blocking version of [`crate::projects::Projects::move_card`].
+    pub fn move_card(
+        &self,
+        card_id: i64,
+        body: &crate::types::ProjectsMoveCardRequest,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.move_card(card_id, body))
+    }
+    /// The blocking version of [`crate::projects::Projects::get_column`].
+    pub fn get_column(
+        &self,
+        column_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::ProjectColumn>> {
+        self.runtime.block_on(self.inner.get_column(column_id))
+    }
+    /// The blocking version of [`crate::projects::Projects::delete_column`].
+    pub fn delete_column(&self, column_id: i64) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.delete_column(column_id))
+    }
+    /// The blocking version of [`crate::projects::Projects::update_column`].
+    pub fn update_column(
+        &self,
+        column_id: i64,
+        body: &crate::types::ProjectsUpdateColumnRequest,
+    ) -> ClientResult<crate::Response<crate::types::ProjectColumn>> {
+        self.runtime
+            .block_on(self.inner.update_column(column_id, body))
+    }
+    /// The blocking version of [`crate::projects::Projects::list_cards`].
+    pub fn list_cards(
+        &self,
+        column_id: i64,
+        archived_state: crate::types::ArchivedState,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ProjectCard>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_cards(column_id, archived_state, per_page, page),
+        )
+    }
+    /// The blocking version of [`crate::projects::Projects::list_cards_request`].
+    pub fn list_cards_request(&self, column_id: i64) -> ProjectsListCardsRequest<'_> {
+        ProjectsListCardsRequest {
+            inner: self.inner.list_cards_request(column_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::projects::Projects::list_all_cards`].
+    pub fn list_all_cards(
+        &self,
+        column_id: i64,
+        archived_state: crate::types::ArchivedState,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ProjectCard>>> {
+        self.runtime
+            .block_on(self.inner.list_all_cards(column_id, archived_state))
+    }
+    /// The blocking version of [`crate::projects::Projects::create_card`].
+    pub fn create_card(
+        &self,
+        column_id: i64,
+        body: &crate::types::ProjectsCreateCardRequestOneOf,
+    ) -> ClientResult<crate::Response<crate::types::ProjectCard>> {
+        self.runtime
+            .block_on(self.inner.create_card(column_id, body))
+    }
+    /// The blocking version of [`crate::projects::Projects::move_column`].
+    pub fn move_column(
+        &self,
+        column_id: i64,
+        body: &crate::types::ProjectsMoveColumnRequest,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.move_column(column_id, body))
+    }
+    /// The blocking version of [`crate::projects::Projects::get`].
+    pub fn get(&self, project_id: i64) -> ClientResult<crate::Response<crate::types::Project>> {
+        self.runtime.block_on(self.inner.get(project_id))
+    }
+    /// The blocking version of [`crate::projects::Projects::delete`].
+    pub fn delete(&self, project_id: i64) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.delete(project_id))
+    }
+    /// The blocking version of [`crate::projects::Projects::update`].
+    pub fn update(
+        &self,
+        project_id: i64,
+        body: &crate::types::ProjectsUpdateRequest,
+    ) -> ClientResult<crate::Response<crate::types::Project>> {
+        self.runtime.block_on(self.inner.update(project_id, body))
+    }
+    /// The blocking version of [`crate::projects::Projects::list_collaborators`].
+    pub fn list_collaborators(
+        &self,
+        project_id: i64,
+        affiliation: crate::types::Affiliation,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::SimpleUser>>> {
+        self.runtime.block_on(self.inner.list_collaborators(
+            project_id,
+            affiliation,
+            per_page,
+            page,
+        ))
+    }
+    /// The blocking version of [`crate::projects::Projects::list_collaborators_request`].
+    pub fn list_collaborators_request(
+        &self,
+        project_id: i64,
+    ) -> ProjectsListCollaboratorsRequest<'_> {
+        ProjectsListCollaboratorsRequest {
+            inner: self.inner.list_collaborators_request(project_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::projects::Projects::list_all_collaborators`].
+    pub fn list_all_collaborators(
+        &self,
+        project_id: i64,
+        affiliation: crate::types::Affiliation,
+    ) -> ClientResult<crate::Response<Vec<crate::types::SimpleUser>>> {
+        self.runtime
+            .block_on(self.inner.list_all_collaborators(project_id, affiliation))
+    }
+    /// The blocking version of [`crate::projects::Projects::add_collaborator`].
+    pub fn add_collaborator(
+        &self,
+        project_id: i64,
+        username: &str,
+        body: &crate::types::ProjectsAddCollaboratorRequest,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.add_collaborator(project_id, username, body))
+    }
+    /// The blocking version of [`crate::projects::Projects::remove_collaborator`].
+    pub fn remove_collaborator(
+        &self,
+        project_id: i64,
+        username: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.remove_collaborator(project_id, username))
+    }
+    /// The blocking version of [`crate::projects::Projects::get_permission_for_user`].
+    pub fn get_permission_for_user(
+        &self,
+        project_id: i64,
+        username: &str,
+    ) -> ClientResult<crate::Response<crate::types::RepositoryCollaboratorPermission>> {
+        self.runtime
+            .block_on(self.inner.get_permission_for_user(project_id, username))
+    }
+    /// The blocking version of [`crate::projects::Projects::list_columns`].
+    pub fn list_columns(
+        &self,
+        project_id: i64,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ProjectColumn>>> {
+        self.runtime
+            .block_on(self.inner.list_columns(project_id, per_page, page))
+    }
+    /// The blocking version of [`crate::projects::Projects::list_columns_request`].
+    pub fn list_columns_request(&self, project_id: i64) -> ProjectsListColumnsRequest<'_> {
+        ProjectsListColumnsRequest {
+            inner: self.inner.list_columns_request(project_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::projects::Projects::list_all_columns`].
+    pub fn list_all_columns(
+        &self,
+        project_id: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ProjectColumn>>> {
+        self.runtime
+            .block_on(self.inner.list_all_columns(project_id))
+    }
+    /// The blocking version of [`crate::projects::Projects::create_column`].
+    pub fn create_column(
+        &self,
+        project_id: i64,
+        body: &crate::types::ProjectsUpdateColumnRequest,
+    ) -> ClientResult<crate::Response<crate::types::ProjectColumn>> {
+        self.runtime
+            .block_on(self.inner.create_column(project_id, body))
+    }
+    /// The blocking version of [`crate::projects::Projects::list_for_repo`].
+    pub fn list_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+        state: crate::types::IssuesListState,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Project>>> {
+        self.runtime
+            .block_on(self.inner.list_for_repo(owner, repo, state, per_page, page))
+    }
+    /// The blocking version of [`crate::projects::Projects::list_for_repo_request`].
+    pub fn list_for_repo_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+    ) -> ProjectsListForRepoRequest<'a> {
+        ProjectsListForRepoRequest {
+            inner: self.inner.list_for_repo_request(owner, repo),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::projects::Projects::list_all_for_repo`].
+    pub fn list_all_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+        state: crate::types::IssuesListState,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Project>>> {
+        self.runtime
+            .block_on(self.inner.list_all_for_repo(owner, repo, state))
+    }
+    /// The blocking version of [`crate::projects::Projects::create_for_repo`].
+    pub fn create_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::ProjectsCreateRequest,
+    ) -> ClientResult<crate::Response<crate::types::Project>> {
+        self.runtime
+            .block_on(self.inner.create_for_repo(owner, repo, body))
+    }
+    /// The blocking version of [`crate::projects::Projects::create_for_authenticated_user`].
+    pub fn create_for_authenticated_user(
+        &self,
+        body: &crate::types::ProjectsCreateRequest,
+    ) -> ClientResult<crate::Response<crate::types::Project>> {
+        self.runtime
+            .block_on(self.inner.create_for_authenticated_user(body))
+    }
+    /// The blocking version of [`crate::projects::Projects::list_for_user`].
+    pub fn list_for_user(
+        &self,
+        username: &str,
+        state: crate::types::IssuesListState,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Project>>> {
+        self.runtime
+            .block_on(self.inner.list_for_user(username, state, per_page, page))
+    }
+    /// The blocking version of [`crate::projects::Projects::list_for_user_request`].
+    pub fn list_for_user_request<'a>(
+        &'a self,
+        username: &'a str,
+    ) -> ProjectsListForUserRequest<'a> {
+        ProjectsListForUserRequest {
+            inner: self.inner.list_for_user_request(username),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::projects::Projects::list_all_for_user`].
+    pub fn list_all_for_user(
+        &self,
+        username: &str,
+        state: crate::types::IssuesListState,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Project>>> {
+        self.runtime
+            .block_on(self.inner.list_all_for_user(username, state))
+    }
+}
@@ -0,0 +1,839 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Pulls::list`], set with a builder.
+pub struct PullsListRequest<'a> {
+    inner: crate::pulls::PullsListRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> PullsListRequest<'a> {
+    /// Sets the `state` query parameter.
+    pub fn state(mut self, state: crate::types::IssuesListState) -> Self {
+        self.inner = self.inner.state(state);
+        self
+    }
+
+    /// Sets the `head` query parameter.
+    pub fn head(mut self, head: &'a str) -> Self {
+        self.inner = self.inner.head(head);
+        self
+    }
+
+    /// Sets the `base` query parameter.
+    pub fn base(mut self, base: &'a str) -> Self {
+        self.inner = self.inner.base(base);
+        self
+    }
+
+    /// Sets the `sort` query parameter.
+    pub fn sort(mut self, sort: crate::types::PullsListSort) -> Self {
+        self.inner = self.inner.sort(sort);
+        self
+    }
+
+    /// Sets the `direction` query parameter.
+    pub fn direction(mut self, direction: crate::types::Order) -> Self {
+        self.inner = self.inner.direction(direction);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::PullRequestSimple>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Pulls::list_review_comments_for_repo`], set with a builder.
+pub struct PullsListReviewCommentsForRepoRequest<'a> {
+    inner: crate::pulls::PullsListReviewCommentsForRepoRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> PullsListReviewCommentsForRepoRequest<'a> {
+    /// Sets the `sort` query parameter.
+    pub fn sort(mut self, sort: crate::types::PullsListReviewCommentsRepoSort) -> Self {
+        self.inner = self.inner.sort(sort);
+        self
+    }
+
+    /// Sets the `direction` query parameter.
+    pub fn direction(mut self, direction: crate::types::Order) -> Self {
+        self.inner = self.inner.direction(direction);
+        self
+    }
+
+    /// Sets the `since` query parameter.
+    pub fn since(mut self, since: chrono::DateTime<chrono::Utc>) -> Self {
+        self.inner = self.inner.since(since);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(
+        self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::PullRequestReviewComment>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Pulls::list_review_comments`], set with a builder.
+pub struct PullsListReviewCommentsRequest<'a> {
+    inner: crate::pulls::PullsListReviewCommentsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> PullsListReviewCommentsRequest<'a> {
+    /// Sets the `sort` query parameter.
+    pub fn sort(mut self, sort: crate::types::Sort) -> Self {
+        self.inner = self.inner.sort(sort);
+        self
+    }
+
+    /// Sets the `direction` query parameter.
+    pub fn direction(mut self, direction: crate::types::Order) -> Self {
+        self.inner = self.inner.direction(direction);
+        self
+    }
+
+    /// Sets the `since` query parameter.
+    pub fn since(mut self, since: chrono::DateTime<chrono::Utc>) -> Self {
+        self.inner = self.inner.since(since);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(
+        self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::PullRequestReviewComment>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Pulls::list_commits`], set with a builder.
+pub struct PullsListCommitsRequest<'a> {
+    inner: crate::pulls::PullsListCommitsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> PullsListCommitsRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::CommitDataType>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Pulls::list_files`], set with a builder.
+pub struct PullsListFilesRequest<'a> {
+    inner: crate::pulls::PullsListFilesRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> PullsListFilesRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::DiffEntry>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Pulls::list_requested_reviewers`], set with a builder.
+pub struct PullsListRequestedReviewersRequest<'a> {
+    inner: crate::pulls::PullsListRequestedReviewersRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> PullsListRequestedReviewersRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::PullRequestReview>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Pulls::list_reviews`], set with a builder.
+pub struct PullsListReviewsRequest<'a> {
+    inner: crate::pulls::PullsListReviewsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> PullsListReviewsRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::PullRequestReviewData>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Pulls::list_comments_for_review`], set with a builder.
+pub struct PullsListCommentsForReviewRequest<'a> {
+    inner: crate::pulls::PullsListCommentsForReviewRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> PullsListCommentsForReviewRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::ReviewComment>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::pulls::Pulls`].
+pub struct Pulls {
+    inner: crate::pulls::Pulls,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Pulls {
+    pub(crate) fn new(inner: crate::pulls::Pulls, runtime: Arc<tokio::runtime::Runtime>) -> Self {
+        Pulls { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::pulls::Pulls::list`].
+    pub fn list(
+        &self,
+        owner: &str,
+        repo: &str,
+        state: crate::types::IssuesListState,
+        head: &str,
+        base: &str,
+        sort: crate::types::PullsListSort,
+        direction: crate::types::Order,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::PullRequestSimple>>> {
+        self.runtime.block_on(self.inner.list(
+            owner, repo, state, head, base, sort, direction, per_page, page,
+        ))
+    }
+    /// The blocking version of [`crate::pulls::Pulls::list_request`].
+    pub fn list_request<'a>(&'a self, owner: &'a str, repo: &'a str) -> PullsListRequest<'a> {
+        PullsListRequest {
+            inner: self.inner.list_request(owner, repo),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::pulls::Pulls::list_all`].
+    pub fn list_all(
+        &self,
+        owner: &str,
+        repo: &str,
+        state: crate::types::IssuesListState,
+        head: &str,
+        base: &str,
+        sort: crate::types::PullsListSort,
+        direction: crate::types::Order,
+    ) -> ClientResult<crate::Response<Vec<crate::types::PullRequestSimple>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_all(owner, repo, state, head, base, sort, direction),
+        )
+    }
+    /// The blocking version of [`crate::pulls::Pulls::create`].
+    pub fn create(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::PullsCreateRequest,
+    ) -> ClientResult<crate::Response<crate::types::PullRequestData>> {
+        self.runtime.block_on(self.inner.create(owner, repo, body))
+    }
+    /// The blocking version of [`crate::pulls::Pulls::list_review_comments_for_repo`].
+    pub fn list_review_comments_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+        sort: crate::types::PullsListReviewCommentsRepoSort,
+        direction: crate::types::Order,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::PullRequestReviewComment>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_review_comments_for_repo(owner, repo, sort, direction, since, per_page, page),
+        )
+    }
+    /// The blocking version of [`crate::pulls::Pulls::list_review_comments_for_repo_request`].
+    pub fn list_review_comments_for_repo_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+    ) -> PullsListReviewCommentsForRepoRequest<'a> {
+        PullsListReviewCommentsForRepoRequest {
+            inner: self
+                .inner
+                .list_review_comments_for_repo_request(owner, repo),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::pulls::Pulls::list_all_review_comments_for_repo`].
+    pub fn list_all_review_comments_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+        sort: crate::types::PullsListReviewCommentsRepoSort,
+        direction: crate::types::Order,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> ClientResult<crate::Response<Vec<crate::types::PullRequestReviewComment>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_all_review_comments_for_repo(owner, repo, sort, direction, since),
+        )
+    }
+    /// The blocking version of [`crate::pulls::Pulls::get_review_comment`].
+    pub fn get_review_comment(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::PullRequestReviewComment>> {
+        self.runtime
+            .block_on(self.inner.get_review_comment(owner, repo, comment_id))
+    }
+    /// The blocking version of [`crate::pulls::Pulls::delete_review_comment`].
+    pub fn delete_review_comment(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete_review_comment(owner, repo, comment_id))
+    }
+    /// The blocking version of [`crate::pulls::Pulls::update_review_comment`].
+    pub fn update_review_comment(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+        body: &crate::types::PullsUpdateReviewRequest,
+    ) -> ClientResult<crate::Response<crate::types::PullRequestReviewComment>> {
+        self.runtime.block_on(
+            self.inner
+                .update_review_comment(owner, repo, comment_id, body),
+        )
+    }
+    /// The blocking version of [`crate::pulls::Pulls::get`].
+    pub fn get(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+    ) -> ClientResult<crate::Response<crate::types::PullRequestData>> {
+        self.runtime
+            .block_on(self.inner.get(owner, repo, pull_number))
+    }
+    /// The blocking version of [`crate::pulls::Pulls::update`].
+    pub fn update(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        body: &crate::types::PullsUpdateRequest,
+    ) -> ClientResult<crate::Response<crate::types::PullRequestData>> {
+        self.runtime
+            .block_on(self.inner.update(owner, repo, pull_number, body))
+    }
+    /// The blocking version of [`crate::pulls::Pulls::list_review_comments`].
+    pub fn list_review_comments(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        sort: crate::types::Sort,
+        direction: crate::types::Order,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::PullRequestReviewComment>>> {
+        self.runtime.block_on(self.inner.list_review_comments(
+            owner,
+            repo,
+            pull_number,
+            sort,
+            direction,
+            since,
+            per_page,
+            page,
+        ))
+    }
+    /// The blocking version of [`crate::pulls::Pulls::list_review_comments_request`].
+    pub fn list_review_comments_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+        pull_number: i64,
+    ) -> PullsListReviewCommentsRequest<'a> {
+        PullsListReviewCommentsRequest {
+            inner: self
+                .inner
+                .list_review_comments_request(owner, repo, pull_number),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::pulls::Pulls::list_all_review_comments`].
+    pub fn list_all_review_comments(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        sort: crate::types::Sort,
+        direction: crate::types::Order,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> ClientResult<crate::Response<Vec<crate::types::PullRequestReviewComment>>> {
+        self.runtime.block_on(self.inner.list_all_review_comments(
+            owner,
+            repo,
+            pull_number,
+            sort,
+            direction,
+            since,
+        ))
+    }
+    /// The blocking version of [`crate::pulls::Pulls::create_review_comment`].
+    pub fn create_review_comment(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        body: &crate::types::PullsCreateReviewCommentRequest,
+    ) -> ClientResult<crate::Response<crate::types::PullRequestReviewComment>> {
+        self.runtime.block_on(
+            self.inner
+                .create_review_comment(owner, repo, pull_number, body),
+        )
+    }
+    /// The blocking version of [`crate::pulls::Pulls::create_reply_for_review_comment`].
+    pub fn create_reply_for_review_comment(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        comment_id: i64,
+        body: &crate::types::PullsUpdateReviewRequest,
+    ) -> ClientResult<crate::Response<crate::types::PullRequestReviewComment>> {
+        self.runtime
+            .block_on(self.inner.create_reply_for_review_comment(
+                owner,
+                repo,
+                pull_number,
+                comment_id,
+                body,
+            ))
+    }
+    /// The blocking version of [`crate::pulls::Pulls::list_commits`].
+    pub fn list_commits(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::CommitDataType>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_commits(owner, repo, pull_number, per_page, page),
+        )
+    }
+    /// The blocking version of [`crate::pulls::Pulls::list_commits_request`].
+    pub fn list_commits_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+        pull_number: i64,
+    ) -> PullsListCommitsRequest<'a> {
+        PullsListCommitsRequest {
+            inner: self.inner.list_commits_request(owner, repo, pull_number),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::pulls::Pulls::list_all_commits`].
+    pub fn list_all_commits(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::CommitDataType>>> {
+        self.runtime
+            .block_on(self.inner.list_all_commits(owner, repo, pull_number))
+    }
+    /// The blocking version of [`crate::pulls::Pulls::list_files`].
+    pub fn list_files(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::DiffEntry>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_files(owner, repo, pull_number, per_page, page),
+        )
+    }
+    /// The blocking version of [`crate::pulls::Pulls::list_files_request`].
+    pub fn list_files_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+        pull_number: i64,
+    ) -> PullsListFilesRequest<'a> {
+        PullsListFilesRequest {
+            inner: self.inner.list_files_request(owner, repo, pull_number),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::pulls::Pulls::list_all_files`].
+    pub fn list_all_files(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::DiffEntry>>> {
+        self.runtime
+            .block_on(self.inner.list_all_files(owner, repo, pull_number))
+    }
+    /// The blocking version of [`crate::pulls::Pulls::check_if_merged`].
+    pub fn check_if_merged(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.check_if_merged(owner, repo, pull_number))
+    }
+    /// The blocking version of [`crate::pulls::Pulls::merge`].
+    pub fn merge(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        body: &crate::types::PullsMergeRequest,
+    ) -> ClientResult<crate::Response<crate::types::PullRequestMergeResult>> {
+        self.runtime
+            .block_on(self.inner.merge(owner, repo, pull_number, body))
+    }
+    /// The blocking version of [`crate::pulls::Pulls::list_requested_reviewers`].
+    pub fn list_requested_reviewers(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<crate::types::PullRequestReview>> {
+        self.runtime.block_on(self.inner.list_requested_reviewers(
+            owner,
+            repo,
+            pull_number,
+            per_page,
+            page,
+        ))
+    }
+    /// The blocking version of [`crate::pulls::Pulls::list_requested_reviewers_request`].
+    pub fn list_requested_reviewers_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+        pull_number: i64,
+    ) -> PullsListRequestedReviewersRequest<'a> {
+        PullsListRequestedReviewersRequest {
+            inner: self
+                .inner
+                .list_requested_reviewers_request(owner, repo, pull_number),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::pulls::Pulls::request_reviewers`].
+    pub fn request_reviewers(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        body: &crate::types::PullsRequestReviewers,
+    ) -> ClientResult<crate::Response<crate::types::PullRequestSimple>> {
+        self.runtime
+            .block_on(self.inner.request_reviewers(owner, repo, pull_number, body))
+    }
+    /// The blocking version of [`crate::pulls::Pulls::remove_requested_reviewers`].
+    pub fn remove_requested_reviewers(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        body: &crate::types::PullsRemoveRequestedReviewersRequest,
+    ) -> ClientResult<crate::Response<crate::types::PullRequestSimple>> {
+        self.runtime.block_on(
+            self.inner
+                .remove_requested_reviewers(owner, repo, pull_number, body),
+        )
+    }
+    /// The blocking version of [`crate::pulls::Pulls::list_reviews`].
+    pub fn list_reviews(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::PullRequestReviewData>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_reviews(owner, repo, pull_number, per_page, page),
+        )
+    }
+    /// The blocking version of [`crate::pulls::Pulls::list_reviews_request`].
+    pub fn list_reviews_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+        pull_number: i64,
+    ) -> PullsListReviewsRequest<'a> {
+        PullsListReviewsRequest {
+            inner: self.inner.list_reviews_request(owner, repo, pull_number),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::pulls::Pulls::list_all_reviews`].
+    pub fn list_all_reviews(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::PullRequestReviewData>>> {
+        self.runtime
+            .block_on(self.inner.list_all_reviews(owner, repo, pull_number))
+    }
+    /// The blocking version of [`crate::pulls::Pulls::create_review`].
+    pub fn create_review(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        body: &crate::types::PullsCreateReviewRequest,
+    ) -> ClientResult<crate::Response<crate::types::PullRequestReviewData>> {
+        self.runtime
+            .block_on(self.inner.create_review(owner, repo, pull_number, body))
+    }
+    /// The blocking version of [`crate::pulls::Pulls::get_review`].
+    pub fn get_review(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        review_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::PullRequestReviewData>> {
+        self.runtime
+            .block_on(self.inner.get_review(owner, repo, pull_number, review_id))
+    }
+    /// The blocking version of [`crate::pulls::Pulls::update_review`].
+    pub fn update_review(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        review_id: i64,
+        body: &crate::types::PullsUpdateReviewRequest,
+    ) -> ClientResult<crate::Response<crate::types::PullRequestReviewData>> {
+        self.runtime.block_on(
+            self.inner
+                .update_review(owner, repo, pull_number, review_id, body),
+        )
+    }
+    /// The blocking version of [`crate::pulls::Pulls::delete_pending_review`].
+    pub fn delete_pending_review(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        review_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::PullRequestReviewData>> {
+        self.runtime.block_on(
+            self.inner
+                .delete_pending_review(owner, repo, pull_number, review_id),
+        )
+    }
+    /// The blocking version of [`crate::pulls::Pulls::list_comments_for_review`].
+    pub fn list_comments_for_review(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        review_id: i64,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ReviewComment>>> {
+        self.runtime.block_on(self.inner.list_comments_for_review(
+            owner,
+            repo,
+            pull_number,
+            review_id,
+            per_page,
+            page,
+        ))
+    }
+    /// The blocking version of [`crate::pulls::Pulls::list_comments_for_review_request`].
+    pub fn list_comments_for_review_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+        pull_number: i64,
+        review_id: i64,
+    ) -> PullsListCommentsForReviewRequest<'a> {
+        PullsListCommentsForReviewRequest {
+            inner: self
+                .inner
+                .list_comments_for_review_request(owner, repo, pull_number, review_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::pulls::Pulls::list_all_comments_for_review`].
+    pub fn list_all_comments_for_review(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        review_id: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ReviewComment>>> {
+        self.runtime
+            .block_on(
+                self.inner
+                    .list_all_comments_for_review(owner, repo, pull_number, review_id),
+            )
+    }
+    /// The blocking version of [`crate::pulls::Pulls::dismiss_review`].
+    pub fn dismiss_review(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        review_id: i64,
+        body: &crate::types::PullsDismissReviewRequest,
+    ) -> ClientResult<crate::Response<crate::types::PullRequestReviewData>> {
+        self.runtime.block_on(
+            self.inner
+                .dismiss_review(owner, repo, pull_number, review_id, body),
+        )
+    }
+    /// The blocking version of [`crate::pulls::Pulls::submit_review`].
+    pub fn submit_review(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        review_id: i64,
+        body: &crate::types::PullsSubmitReviewRequest,
+    ) -> ClientResult<crate::Response<crate::types::PullRequestReviewData>> {
+        self.runtime.block_on(
+            self.inner
+                .submit_review(owner, repo, pull_number, review_id, body),
+        )
+    }
+    /// The blocking version of [`crate::pulls::Pulls::update_branch`].
+    pub fn update_branch(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        body: &crate::types::PullsUpdateBranchRequest,
+    ) -> ClientResult<crate::Response<crate::types::PullsUpdateBranchResponse>> {
+        self.runtime
+            .block_on(self.inner.update_branch(owner, repo, pull_number, body))
+    }
+}
@@ -0,0 +1,23 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::rate_limit::RateLimit`].
+pub struct RateLimit {
+    inner: crate::rate_limit::RateLimit,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl RateLimit {
+    pub(crate) fn new(
+        inner: crate::rate_limit::RateLimit,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        RateLimit { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::rate_limit::RateLimit::get`].
+    pub fn get(&self) -> ClientResult<crate::Response<crate::types::RateLimitOverview>> {
+        self.runtime.block_on(self.inner.get())
+    }
+}
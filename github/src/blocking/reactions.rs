@@ -0,0 +1,866 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Reactions::list_for_team_discussion_comment_in_org`], set with a builder.
+pub struct ReactionsListForTeamDiscussionCommentInOrgRequest<'a> {
+    inner: crate::reactions::ReactionsListForTeamDiscussionCommentInOrgRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ReactionsListForTeamDiscussionCommentInOrgRequest<'a> {
+    /// Sets the `content` query parameter.
+    pub fn content(mut self, content: crate::types::Content) -> Self {
+        self.inner = self.inner.content(content);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Reaction>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Reactions::list_for_team_discussion_in_org`], set with a builder.
+pub struct ReactionsListForTeamDiscussionInOrgRequest<'a> {
+    inner: crate::reactions::ReactionsListForTeamDiscussionInOrgRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ReactionsListForTeamDiscussionInOrgRequest<'a> {
+    /// Sets the `content` query parameter.
+    pub fn content(mut self, content: crate::types::Content) -> Self {
+        self.inner = self.inner.content(content);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Reaction>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Reactions::list_for_commit_comment`], set with a builder.
+pub struct ReactionsListForCommitCommentRequest<'a> {
+    inner: crate::reactions::ReactionsListForCommitCommentRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ReactionsListForCommitCommentRequest<'a> {
+    /// Sets the `content` query parameter.
+    pub fn content(mut self, content: crate::types::Content) -> Self {
+        self.inner = self.inner.content(content);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Reaction>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Reactions::list_for_issue_comment`], set with a builder.
+pub struct ReactionsListForIssueCommentRequest<'a> {
+    inner: crate::reactions::ReactionsListForIssueCommentRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ReactionsListForIssueCommentRequest<'a> {
+    /// Sets the `content` query parameter.
+    pub fn content(mut self, content: crate::types::Content) -> Self {
+        self.inner = self.inner.content(content);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Reaction>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Reactions::list_for_issue`], set with a builder.
+pub struct ReactionsListForIssueRequest<'a> {
+    inner: crate::reactions::ReactionsListForIssueRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ReactionsListForIssueRequest<'a> {
+    /// Sets the `content` query parameter.
+    pub fn content(mut self, content: crate::types::Content) -> Self {
+        self.inner = self.inner.content(content);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Reaction>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Reactions::list_for_pull_request_review_comment`], set with a builder.
+pub struct ReactionsListForPullRequestReviewCommentRequest<'a> {
+    inner: crate::reactions::ReactionsListForPullRequestReviewCommentRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ReactionsListForPullRequestReviewCommentRequest<'a> {
+    /// Sets the `content` query parameter.
+    pub fn content(mut self, content: crate::types::Content) -> Self {
+        self.inner = self.inner.content(content);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Reaction>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Reactions::list_for_team_discussion_comment_legacy`], set with a builder.
+pub struct ReactionsListForTeamDiscussionCommentLegacyRequest<'a> {
+    inner: crate::reactions::ReactionsListForTeamDiscussionCommentLegacyRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ReactionsListForTeamDiscussionCommentLegacyRequest<'a> {
+    /// Sets the `content` query parameter.
+    pub fn content(mut self, content: crate::types::Content) -> Self {
+        self.inner = self.inner.content(content);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Reaction>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Reactions::list_for_team_discussion_legacy`], set with a builder.
+pub struct ReactionsListForTeamDiscussionLegacyRequest<'a> {
+    inner: crate::reactions::ReactionsListForTeamDiscussionLegacyRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ReactionsListForTeamDiscussionLegacyRequest<'a> {
+    /// Sets the `content` query parameter.
+    pub fn content(mut self, content: crate::types::Content) -> Self {
+        self.inner = self.inner.content(content);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Reaction>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::reactions::Reactions`].
+pub struct Reactions {
+    inner: crate::reactions::Reactions,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Reactions {
+    pub(crate) fn new(
+        inner: crate::reactions::Reactions,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Reactions { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::reactions::Reactions::list_for_team_discussion_comment_in_org`].
+    pub fn list_for_team_discussion_comment_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+        discussion_number: i64,
+        comment_number: i64,
+        content: crate::types::Content,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Reaction>>> {
+        self.runtime
+            .block_on(self.inner.list_for_team_discussion_comment_in_org(
+                org,
+                team_slug,
+                discussion_number,
+                comment_number,
+                content,
+                per_page,
+                page,
+            ))
+    }
+    /// The blocking version of [`crate::reactions::Reactions::list_for_team_discussion_comment_in_org_request`].
+    pub fn list_for_team_discussion_comment_in_org_request<'a>(
+        &'a self,
+        org: &'a str,
+        team_slug: &'a str,
+        discussion_number: i64,
+        comment_number: i64,
+    ) -> ReactionsListForTeamDiscussionCommentInOrgRequest<'a> {
+        ReactionsListForTeamDiscussionCommentInOrgRequest {
+            inner: self.inner.list_for_team_discussion_comment_in_org_request(
+                org,
+                team_slug,
+                discussion_number,
+                comment_number,
+            ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::reactions::Reactions::list_all_for_team_discussion_comment_in_org`].
+    pub fn list_all_for_team_discussion_comment_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+        discussion_number: i64,
+        comment_number: i64,
+        content: crate::types::Content,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Reaction>>> {
+        self.runtime
+            .block_on(self.inner.list_all_for_team_discussion_comment_in_org(
+                org,
+                team_slug,
+                discussion_number,
+                comment_number,
+                content,
+            ))
+    }
+    /// The blocking version of [`crate::reactions::Reactions::create_for_team_discussion_comment_in_org`].
+    pub fn create_for_team_discussion_comment_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+        discussion_number: i64,
+        comment_number: i64,
+        body: &crate::types::ReactionsCreateIssueRequest,
+    ) -> ClientResult<crate::Response<crate::types::Reaction>> {
+        self.runtime
+            .block_on(self.inner.create_for_team_discussion_comment_in_org(
+                org,
+                team_slug,
+                discussion_number,
+                comment_number,
+                body,
+            ))
+    }
+    /// The blocking version of [`crate::reactions::Reactions::delete_for_team_discussion_comment`].
+    pub fn delete_for_team_discussion_comment(
+        &self,
+        org: &str,
+        team_slug: &str,
+        discussion_number: i64,
+        comment_number: i64,
+        reaction_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete_for_team_discussion_comment(
+                org,
+                team_slug,
+                discussion_number,
+                comment_number,
+                reaction_id,
+            ))
+    }
+    /// The blocking version of [`crate::reactions::Reactions::list_for_team_discussion_in_org`].
+    pub fn list_for_team_discussion_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+        discussion_number: i64,
+        content: crate::types::Content,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Reaction>>> {
+        self.runtime
+            .block_on(self.inner.list_for_team_discussion_in_org(
+                org,
+                team_slug,
+                discussion_number,
+                content,
+                per_page,
+                page,
+            ))
+    }
+    /// The blocking version of [`crate::reactions::Reactions::list_for_team_discussion_in_org_request`].
+    pub fn list_for_team_discussion_in_org_request<'a>(
+        &'a self,
+        org: &'a str,
+        team_slug: &'a str,
+        discussion_number: i64,
+    ) -> ReactionsListForTeamDiscussionInOrgRequest<'a> {
+        ReactionsListForTeamDiscussionInOrgRequest {
+            inner: self.inner.list_for_team_discussion_in_org_request(
+                org,
+                team_slug,
+                discussion_number,
+            ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::reactions::Reactions::list_all_for_team_discussion_in_org`].
+    pub fn list_all_for_team_discussion_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+        discussion_number: i64,
+        content: crate::types::Content,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Reaction>>> {
+        self.runtime
+            .block_on(self.inner.list_all_for_team_discussion_in_org(
+                org,
+                team_slug,
+                discussion_number,
+                content,
+            ))
+    }
+    /// The blocking version of [`crate::reactions::Reactions::create_for_team_discussion_in_org`].
+    pub fn create_for_team_discussion_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+        discussion_number: i64,
+        body: &crate::types::ReactionsCreateIssueRequest,
+    ) -> ClientResult<crate::Response<crate::types::Reaction>> {
+        self.runtime
+            .block_on(self.inner.create_for_team_discussion_in_org(
+                org,
+                team_slug,
+                discussion_number,
+                body,
+            ))
+    }
+    /// The blocking version of [`crate::reactions::Reactions::delete_for_team_discussion`].
+    pub fn delete_for_team_discussion(
+        &self,
+        org: &str,
+        team_slug: &str,
+        discussion_number: i64,
+        reaction_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.delete_for_team_discussion(
+            org,
+            team_slug,
+            discussion_number,
+            reaction_id,
+        ))
+    }
+    /// The blocking version of [`crate::reactions::Reactions::delete_legacy`].
+    pub fn delete_legacy(&self, reaction_id: i64) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.delete_legacy(reaction_id))
+    }
+    /// The blocking version of [`crate::reactions::Reactions::list_for_commit_comment`].
+    pub fn list_for_commit_comment(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+        content: crate::types::Content,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Reaction>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_for_commit_comment(owner, repo, comment_id, content, per_page, page),
+        )
+    }
+    /// The blocking version of [`crate::reactions::Reactions::list_for_commit_comment_request`].
+    pub fn list_for_commit_comment_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+        comment_id: i64,
+    ) -> ReactionsListForCommitCommentRequest<'a> {
+        ReactionsListForCommitCommentRequest {
+            inner: self
+                .inner
+                .list_for_commit_comment_request(owner, repo, comment_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::reactions::Reactions::list_all_for_commit_comment`].
+    pub fn list_all_for_commit_comment(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+        content: crate::types::Content,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Reaction>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_all_for_commit_comment(owner, repo, comment_id, content),
+        )
+    }
+    /// The blocking version of [`crate::reactions::Reactions::create_for_commit_comment`].
+    pub fn create_for_commit_comment(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+        body: &crate::types::ReactionsCreateIssueRequest,
+    ) -> ClientResult<crate::Response<crate::types::Reaction>> {
+        self.runtime.block_on(
+            self.inner
+                .create_for_commit_comment(owner, repo, comment_id, body),
+        )
+    }
+    /// The blocking version of [`crate::reactions::Reactions::delete_for_commit_comment`].
+    pub fn delete_for_commit_comment(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+        reaction_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.delete_for_commit_comment(
+            owner,
+            repo,
+            comment_id,
+            reaction_id,
+        ))
+    }
+    /// The blocking version of [`crate::reactions::Reactions::list_for_issue_comment`].
+    pub fn list_for_issue_comment(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+        content: crate::types::Content,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Reaction>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_for_issue_comment(owner, repo, comment_id, content, per_page, page),
+        )
+    }
+    /// The blocking version of [`crate::reactions::Reactions::list_for_issue_comment_request`].
+    pub fn list_for_issue_comment_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+        comment_id: i64,
+    ) -> ReactionsListForIssueCommentRequest<'a> {
+        ReactionsListForIssueCommentRequest {
+            inner: self
+                .inner
+                .list_for_issue_comment_request(owner, repo, comment_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::reactions::Reactions::list_all_for_issue_comment`].
+    pub fn list_all_for_issue_comment(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+        content: crate::types::Content,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Reaction>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_all_for_issue_comment(owner, repo, comment_id, content),
+        )
+    }
+    /// The blocking version of [`crate::reactions::Reactions::create_for_issue_comment`].
+    pub fn create_for_issue_comment(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+        body: &crate::types::ReactionsCreateIssueRequest,
+    ) -> ClientResult<crate::Response<crate::types::Reaction>> {
+        self.runtime.block_on(
+            self.inner
+                .create_for_issue_comment(owner, repo, comment_id, body),
+        )
+    }
+    /// The blocking version of [`crate::reactions::Reactions::delete_for_issue_comment`].
+    pub fn delete_for_issue_comment(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+        reaction_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.delete_for_issue_comment(
+            owner,
+            repo,
+            comment_id,
+            reaction_id,
+        ))
+    }
+    /// The blocking version of [`crate::reactions::Reactions::list_for_issue`].
+    pub fn list_for_issue(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+        content: crate::types::Content,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Reaction>>> {
+        self.runtime.block_on(self.inner.list_for_issue(
+            owner,
+            repo,
+            issue_number,
+            content,
+            per_page,
+            page,
+        ))
+    }
+    /// The blocking version of [`crate::reactions::Reactions::list_for_issue_request`].
+    pub fn list_for_issue_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+        issue_number: i64,
+    ) -> ReactionsListForIssueRequest<'a> {
+        ReactionsListForIssueRequest {
+            inner: self.inner.list_for_issue_request(owner, repo, issue_number),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::reactions::Reactions::list_all_for_issue`].
+    pub fn list_all_for_issue(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+        content: crate::types::Content,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Reaction>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_all_for_issue(owner, repo, issue_number, content),
+        )
+    }
+    /// The blocking version of [`crate::reactions::Reactions::create_for_issue`].
+    pub fn create_for_issue(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+        body: &crate::types::ReactionsCreateIssueRequest,
+    ) -> ClientResult<crate::Response<crate::types::Reaction>> {
+        self.runtime
+            .block_on(self.inner.create_for_issue(owner, repo, issue_number, body))
+    }
+    /// The blocking version of [`crate::reactions::Reactions::delete_for_issue`].
+    pub fn delete_for_issue(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+        reaction_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .delete_for_issue(owner, repo, issue_number, reaction_id),
+        )
+    }
+    /// The blocking version of [`crate::reactions::Reactions::list_for_pull_request_review_comment`].
+    pub fn list_for_pull_request_review_comment(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+        content: crate::types::Content,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Reaction>>> {
+        self.runtime.block_on(
+            self.inner.list_for_pull_request_review_comment(
+                owner, repo, comment_id, content, per_page, page,
+            ),
+        )
+    }
+    /// The blocking version of [`crate::reactions::Reactions::list_for_pull_request_review_comment_request`].
+    pub fn list_for_pull_request_review_comment_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+        comment_id: i64,
+    ) -> ReactionsListForPullRequestReviewCommentRequest<'a> {
+        ReactionsListForPullRequestReviewCommentRequest {
+            inner: self
+                .inner
+                .list_for_pull_request_review_comment_request(owner, repo, comment_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::reactions::Reactions::list_all_for_pull_request_review_comment`].
+    pub fn list_all_for_pull_request_review_comment(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+        content: crate::types::Content,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Reaction>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_all_for_pull_request_review_comment(owner, repo, comment_id, content),
+        )
+    }
+    /// The blocking version of [`crate::reactions::Reactions::create_for_pull_request_review_comment`].
+    pub fn create_for_pull_request_review_comment(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+        body: &crate::types::ReactionsCreateIssueRequest,
+    ) -> ClientResult<crate::Response<crate::types::Reaction>> {
+        self.runtime.block_on(
+            self.inner
+                .create_for_pull_request_review_comment(owner, repo, comment_id, body),
+        )
+    }
+    /// The blocking version of [`crate::reactions::Reactions::delete_for_pull_request_comment`].
+    pub fn delete_for_pull_request_comment(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+        reaction_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete_for_pull_request_comment(
+                owner,
+                repo,
+                comment_id,
+                reaction_id,
+            ))
+    }
+    /// The blocking version of [`crate::reactions::Reactions::create_for_release`].
+    pub fn create_for_release(
+        &self,
+        owner: &str,
+        repo: &str,
+        release_id: i64,
+        body: &crate::types::ReactionsCreateReleaseRequest,
+    ) -> ClientResult<crate::Response<crate::types::Reaction>> {
+        self.runtime
+            .block_on(self.inner.create_for_release(owner, repo, release_id, body))
+    }
+    /// The blocking version of [`crate::reactions::Reactions::list_for_team_discussion_comment_legacy`].
+    pub fn list_for_team_discussion_comment_legacy(
+        &self,
+        team_id: i64,
+        discussion_number: i64,
+        comment_number: i64,
+        content: crate::types::Content,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Reaction>>> {
+        self.runtime
+            .block_on(self.inner.list_for_team_discussion_comment_legacy(
+                team_id,
+                discussion_number,
+                comment_number,
+                content,
+                per_page,
+                page,
+            ))
+    }
+    /// The blocking version of [`crate::reactions::Reactions::list_for_team_discussion_comment_legacy_request`].
+    pub fn list_for_team_discussion_comment_legacy_request(
+        &self,
+        team_id: i64,
+        discussion_number: i64,
+        comment_number: i64,
+    ) -> ReactionsListForTeamDiscussionCommentLegacyRequest<'_> {
+        ReactionsListForTeamDiscussionCommentLegacyRequest {
+            inner: self.inner.list_for_team_discussion_comment_legacy_request(
+                team_id,
+                discussion_number,
+                comment_number,
+            ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::reactions::Reactions::list_all_for_team_discussion_comment_legacy`].
+    pub fn list_all_for_team_discussion_comment_legacy(
+        &self,
+        team_id: i64,
+        discussion_number: i64,
+        comment_number: i64,
+        content: crate::types::Content,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Reaction>>> {
+        self.runtime
+            .block_on(self.inner.list_all_for_team_discussion_comment_legacy(
+                team_id,
+                discussion_number,
+                comment_number,
+                content,
+            ))
+    }
+    /// The blocking version of [`crate::reactions::Reactions::create_for_team_discussion_comment_legacy`].
+    pub fn create_for_team_discussion_comment_legacy(
+        &self,
+        team_id: i64,
+        discussion_number: i64,
+        comment_number: i64,
+        body: &crate::types::ReactionsCreateIssueRequest,
+    ) -> ClientResult<crate::Response<crate::types::Reaction>> {
+        self.runtime
+            .block_on(self.inner.create_for_team_discussion_comment_legacy(
+                team_id,
+                discussion_number,
+                comment_number,
+                body,
+            ))
+    }
+    /// The blocking version of [`crate::reactions::Reactions::list_for_team_discussion_legacy`].
+    pub fn list_for_team_discussion_legacy(
+        &self,
+        team_id: i64,
+        discussion_number: i64,
+        content: crate::types::Content,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Reaction>>> {
+        self.runtime
+            .block_on(self.inner.list_for_team_discussion_legacy(
+                team_id,
+                discussion_number,
+                content,
+                per_page,
+                page,
+            ))
+    }
+    /// The blocking version of [`crate::reactions::Reactions::list_for_team_discussion_legacy_request`].
+    pub fn list_for_team_discussion_legacy_request(
+        &self,
+        team_id: i64,
+        discussion_number: i64,
+    ) -> ReactionsListForTeamDiscussionLegacyRequest<'_> {
+        ReactionsListForTeamDiscussionLegacyRequest {
+            inner: self
+                .inner
+                .list_for_team_discussion_legacy_request(team_id, discussion_number),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::reactions::Reactions::list_all_for_team_discussion_legacy`].
+    pub fn list_all_for_team_discussion_legacy(
+        &self,
+        team_id: i64,
+        discussion_number: i64,
+        content: crate::types::Content,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Reaction>>> {
+        self.runtime
+            .block_on(self.inner.list_all_for_team_discussion_legacy(
+                team_id,
+                discussion_number,
+                content,
+            ))
+    }
+    /// The blocking version of [`crate::reactions::Reactions::create_for_team_discussion_legacy`].
+    pub fn create_for_team_discussion_legacy(
+        &self,
+        team_id: i64,
+        discussion_number: i64,
+        body: &crate::types::ReactionsCreateIssueRequest,
+    ) -> ClientResult<crate::Response<crate::types::Reaction>> {
+        self.runtime
+            .block_on(self.inner.create_for_team_discussion_legacy(
+                team_id,
+                discussion_number,
+                body,
+            ))
+    }
+}
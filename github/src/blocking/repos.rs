@@ -0,0 +1,3584 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Repos::list_for_org`], set with a builder.
+pub struct ReposListForOrgRequest<'a> {
+    inner: crate::repos::ReposListForOrgRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ReposListForOrgRequest<'a> {
+    /// Sets the `type` query parameter.
+    pub fn type_(mut self, type_: crate::types::ReposListOrgType) -> Self {
+        self.inner = self.inner.type_(type_);
+        self
+    }
+
+    /// Sets the `sort` query parameter.
+    pub fn sort(mut self, sort: crate::types::ReposListOrgSort) -> Self {
+        self.inner = self.inner.sort(sort);
+        self
+    }
+
+    /// Sets the `direction` query parameter.
+    pub fn direction(mut self, direction: crate::types::Order) -> Self {
+        self.inner = self.inner.direction(direction);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::MinimalRepository>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Repos::list_autolinks`], set with a builder.
+pub struct ReposListAutolinksRequest<'a> {
+    inner: crate::repos::ReposListAutolinksRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ReposListAutolinksRequest<'a> {
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Autolink>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Repos::list_branches`], set with a builder.
+pub struct ReposListBranchesRequest<'a> {
+    inner: crate::repos::ReposListBranchesRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ReposListBranchesRequest<'a> {
+    /// Sets the `protected` query parameter.
+    pub fn protected(mut self, protected: bool) -> Self {
+        self.inner = self.inner.protected(protected);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::ShortBranch>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Repos::list_collaborators`], set with a builder.
+pub struct ReposListCollaboratorsRequest<'a> {
+    inner: crate::repos::ReposListCollaboratorsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ReposListCollaboratorsRequest<'a> {
+    /// Sets the `affiliation` query parameter.
+    pub fn affiliation(mut self, affiliation: crate::types::Affiliation) -> Self {
+        self.inner = self.inner.affiliation(affiliation);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Collaborator>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Repos::list_commit_comments_for_repo`], set with a builder.
+pub struct ReposListCommitCommentsForRepoRequest<'a> {
+    inner: crate::repos::ReposListCommitCommentsForRepoRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ReposListCommitCommentsForRepoRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::CommitComment>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Repos::list_commits`], set with a builder.
+pub struct ReposListCommitsRequest<'a> {
+    inner: crate::repos::ReposListCommitsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ReposListCommitsRequest<'a> {
+    /// Sets the `sha` query parameter.
+    pub fn sha(mut self, sha: &'a str) -> Self {
+        self.inner = self.inner.sha(sha);
+        self
+    }
+
+    /// Sets the `path` query parameter.
+    pub fn path(mut self, path: &'a str) -> Self {
+        self.inner = self.inner.path(path);
+        self
+    }
+
+    /// Sets the `author` query parameter.
+    pub fn author(mut self, author: &'a str) -> Self {
+        self.inner = self.inner.author(author);
+        self
+    }
+
+    /// Sets the `since` query parameter.
+    pub fn since(mut self, since: chrono::DateTime<chrono::Utc>) -> Self {
+        self.inner = self.inner.since(since);
+        self
+    }
+
+    /// Sets the `until` query parameter.
+    pub fn until(mut self, until: chrono::DateTime<chrono::Utc>) -> Self {
+        self.inner = self.inner.until(until);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::CommitDataType>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Repos::list_comments_for_commit`], set with a builder.
+pub struct ReposListCommentsForCommitRequest<'a> {
+    inner: crate::repos::ReposListCommentsForCommitRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ReposListCommentsForCommitRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::CommitComment>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Repos::list_pull_requests_associated_with_commit`], set with a builder.
+pub struct ReposListPullRequestsAssociatedWithCommitRequest<'a> {
+    inner: crate::repos::ReposListPullRequestsAssociatedWithCommitRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ReposListPullRequestsAssociatedWithCommitRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::PullRequestSimple>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Repos::get_commit`], set with a builder.
+pub struct ReposGetCommitRequest<'a> {
+    inner: crate::repos::ReposGetCommitRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ReposGetCommitRequest<'a> {
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::CommitDataType>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Repos::get_combined_status_for_ref`], set with a builder.
+pub struct ReposGetCombinedStatusForRefRequest<'a> {
+    inner: crate::repos::ReposGetCombinedStatusForRefRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ReposGetCombinedStatusForRefRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::CombinedCommitStatus>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Repos::list_commit_statuses_for_ref`], set with a builder.
+pub struct ReposListCommitStatusesForRefRequest<'a> {
+    inner: crate::repos::ReposListCommitStatusesForRefRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ReposListCommitStatusesForRefRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::StatusData>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Repos::compare_commits`], set with a builder.
+pub struct ReposCompareCommitsRequest<'a> {
+    inner: crate::repos::ReposCompareCommitsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ReposCompareCommitsRequest<'a> {
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::CommitComparison>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Repos::get_content`], set with a builder.
+pub struct ReposGetContentRequest<'a> {
+    inner: crate::repos::ReposGetContentRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ReposGetContentRequest<'a> {
+    /// Sets the `ref` query parameter.
+    pub fn ref_(mut self, ref_: &'a str) -> Self {
+        self.inner = self.inner.ref_(ref_);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::ReposGetContentResponseOneOf>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Repos::list_contributors`], set with a builder.
+pub struct ReposListContributorsRequest<'a> {
+    inner: crate::repos::ReposListContributorsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ReposListContributorsRequest<'a> {
+    /// Sets the `anon` query parameter.
+    pub fn anon(mut self, anon: &'a str) -> Self {
+        self.inner = self.inner.anon(anon);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Contributor>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Repos::list_deployments`], set with a builder.
+pub struct ReposListDeploymentsRequest<'a> {
+    inner: crate::repos::ReposListDeploymentsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ReposListDeploymentsRequest<'a> {
+    /// Sets the `sha` query parameter.
+    pub fn sha(mut self, sha: &'a str) -> Self {
+        self.inner = self.inner.sha(sha);
+        self
+    }
+
+    /// Sets the `ref` query parameter.
+    pub fn ref_(mut self, ref_: &'a str) -> Self {
+        self.inner = self.inner.ref_(ref_);
+        self
+    }
+
+    /// Sets the `task` query parameter.
+    pub fn task(mut self, task: &'a str) -> Self {
+        self.inner = self.inner.task(task);
+        self
+    }
+
+    /// Sets the `environment` query parameter.
+    pub fn environment(mut self, environment: &'a str) -> Self {
+        self.inner = self.inner.environment(environment);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Deployment>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Repos::list_deployment_statuses`], set with a builder.
+pub struct ReposListDeploymentStatusesRequest<'a> {
+    inner: crate::repos::ReposListDeploymentStatusesRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ReposListDeploymentStatusesRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::DeploymentStatus>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Repos::list_forks`], set with a builder.
+pub struct ReposListForksRequest<'a> {
+    inner: crate::repos::ReposListForksRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ReposListForksRequest<'a> {
+    /// Sets the `sort` query parameter.
+    pub fn sort(mut self, sort: crate::types::ReposListForksSort) -> Self {
+        self.inner = self.inner.sort(sort);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::MinimalRepository>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Repos::list_webhooks`], set with a builder.
+pub struct ReposListWebhooksRequest<'a> {
+    inner: crate::repos::ReposListWebhooksRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ReposListWebhooksRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Hook>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Repos::list_webhook_deliveries`], set with a builder.
+pub struct ReposListWebhookDeliveriesRequest<'a> {
+    inner: crate::repos::ReposListWebhookDeliveriesRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ReposListWebhookDeliveriesRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `cursor` query parameter.
+    pub fn cursor(mut self, cursor: &'a str) -> Self {
+        self.inner = self.inner.cursor(cursor);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::HookDeliveryItem>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Repos::list_invitations`], set with a builder.
+pub struct ReposListInvitationsRequest<'a> {
+    inner: crate::repos::ReposListInvitationsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ReposListInvitationsRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::RepositoryInvitation>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Repos::list_deploy_keys`], set with a builder.
+pub struct ReposListDeployKeysRequest<'a> {
+    inner: crate::repos::ReposListDeployKeysRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ReposListDeployKeysRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::DeployKey>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Repos::list_pages_builds`], set with a builder.
+pub struct ReposListPagesBuildsRequest<'a> {
+    inner: crate::repos::ReposListPagesBuildsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ReposListPagesBuildsRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::PageBuild>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Repos::get_readme`], set with a builder.
+pub struct ReposGetReadmeRequest<'a> {
+    inner: crate::repos::ReposGetReadmeRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ReposGetReadmeRequest<'a> {
+    /// Sets the `ref` query parameter.
+    pub fn ref_(mut self, ref_: &'a str) -> Self {
+        self.inner = self.inner.ref_(ref_);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::ContentFile>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Repos::get_readme_in_directory`], set with a builder.
+pub struct ReposGetReadmeInDirectoryRequest<'a> {
+    inner: crate::repos::ReposGetReadmeInDirectoryRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ReposGetReadmeInDirectoryRequest<'a> {
+    /// Sets the `ref` query parameter.
+    pub fn ref_(mut self, ref_: &'a str) -> Self {
+        self.inner = self.inner.ref_(ref_);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::ContentFile>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Repos::list_releases`], set with a builder.
+pub struct ReposListReleasesRequest<'a> {
+    inner: crate::repos::ReposListReleasesRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ReposListReleasesRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Release>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Repos::list_release_assets`], set with a builder.
+pub struct ReposListReleaseAssetsRequest<'a> {
+    inner: crate::repos::ReposListReleaseAssetsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ReposListReleaseAssetsRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::ReleaseAsset>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Repos::list_tags`], set with a builder.
+pub struct ReposListTagsRequest<'a> {
+    inner: crate::repos::ReposListTagsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ReposListTagsRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Tag>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Repos::list_teams`], set with a builder.
+pub struct ReposListTeamsRequest<'a> {
+    inner: crate::repos::ReposListTeamsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ReposListTeamsRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Team>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Repos::get_all_topics`], set with a builder.
+pub struct ReposGetAllTopicsRequest<'a> {
+    inner: crate::repos::ReposGetAllTopicsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ReposGetAllTopicsRequest<'a> {
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::Topic>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Repos::get_clones`], set with a builder.
+pub struct ReposGetClonesRequest<'a> {
+    inner: crate::repos::ReposGetClonesRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ReposGetClonesRequest<'a> {
+    /// Sets the `per` query parameter.
+    pub fn per(mut self, per: crate::types::Per) -> Self {
+        self.inner = self.inner.per(per);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::CloneTraffic>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Repos::get_views`], set with a builder.
+pub struct ReposGetViewsRequest<'a> {
+    inner: crate::repos::ReposGetViewsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ReposGetViewsRequest<'a> {
+    /// Sets the `per` query parameter.
+    pub fn per(mut self, per: crate::types::Per) -> Self {
+        self.inner = self.inner.per(per);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::ViewTraffic>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Repos::list_public`], set with a builder.
+pub struct ReposListPublicRequest<'a> {
+    inner: crate::repos::ReposListPublicRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ReposListPublicRequest<'a> {
+    /// Sets the `since` query parameter.
+    pub fn since(mut self, since: i64) -> Self {
+        self.inner = self.inner.since(since);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::MinimalRepository>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Repos::list_for_authenticated_user`], set with a builder.
+pub struct ReposListForAuthenticatedUserRequest<'a> {
+    inner: crate::repos::ReposListForAuthenticatedUserRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ReposListForAuthenticatedUserRequest<'a> {
+    /// Sets the `visibility` query parameter.
+    pub fn visibility(mut self, visibility: crate::types::ReposListVisibility) -> Self {
+        self.inner = self.inner.visibility(visibility);
+        self
+    }
+
+    /// Sets the `affiliation` query parameter.
+    pub fn affiliation(mut self, affiliation: &'a str) -> Self {
+        self.inner = self.inner.affiliation(affiliation);
+        self
+    }
+
+    /// Sets the `type` query parameter.
+    pub fn type_(mut self, type_: crate::types::ReposListType) -> Self {
+        self.inner = self.inner.type_(type_);
+        self
+    }
+
+    /// Sets the `sort` query parameter.
+    pub fn sort(mut self, sort: crate::types::ReposListOrgSort) -> Self {
+        self.inner = self.inner.sort(sort);
+        self
+    }
+
+    /// Sets the `direction` query parameter.
+    pub fn direction(mut self, direction: crate::types::Order) -> Self {
+        self.inner = self.inner.direction(direction);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sets the `since` query parameter.
+    pub fn since(mut self, since: chrono::DateTime<chrono::Utc>) -> Self {
+        self.inner = self.inner.since(since);
+        self
+    }
+
+    /// Sets the `before` query parameter.
+    pub fn before(mut self, before: chrono::DateTime<chrono::Utc>) -> Self {
+        self.inner = self.inner.before(before);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Repository>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Repos::list_invitations_for_authenticated_user`], set with a builder.
+pub struct ReposListInvitationsForAuthenticatedUserRequest<'a> {
+    inner: crate::repos::ReposListInvitationsForAuthenticatedUserRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ReposListInvitationsForAuthenticatedUserRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::RepositoryInvitation>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Repos::list_for_user`], set with a builder.
+pub struct ReposListForUserRequest<'a> {
+    inner: crate::repos::ReposListForUserRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ReposListForUserRequest<'a> {
+    /// Sets the `type` query parameter.
+    pub fn type_(mut self, type_: crate::types::ReposListUserType) -> Self {
+        self.inner = self.inner.type_(type_);
+        self
+    }
+
+    /// Sets the `sort` query parameter.
+    pub fn sort(mut self, sort: crate::types::ReposListOrgSort) -> Self {
+        self.inner = self.inner.sort(sort);
+        self
+    }
+
+    /// Sets the `direction` query parameter.
+    pub fn direction(mut self, direction: crate::types::Order) -> Self {
+        self.inner = self.inner.direction(direction);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::MinimalRepository>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::repos::Repos`].
+pub struct Repos {
+    inner: crate::repos::Repos,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Repos {
+    pub(crate) fn new(inner: crate::repos::Repos, runtime: Arc<tokio::runtime::Runtime>) -> Self {
+        Repos { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::repos::Repos::list_for_org`].
+    pub fn list_for_org(
+        &self,
+        org: &str,
+        type_: crate::types::ReposListOrgType,
+        sort: crate::types::ReposListOrgSort,
+        direction: crate::types::Order,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::MinimalRepository>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_for_org(org, type_, sort, direction, per_page, page),
+        )
+    }
+    /// The blocking version of [`crate::repos::Repos::list_for_org_request`].
+    pub fn list_for_org_request<'a>(&'a self, org: &'a str) -> ReposListForOrgRequest<'a> {
+        ReposListForOrgRequest {
+            inner: self.inner.list_for_org_request(org),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::repos::Repos::list_all_for_org`].
+    pub fn list_all_for_org(
+        &self,
+        org: &str,
+        type_: crate::types::ReposListOrgType,
+        sort: crate::types::ReposListOrgSort,
+        direction: crate::types::Order,
+    ) -> ClientResult<crate::Response<Vec<crate::types::MinimalRepository>>> {
+        self.runtime
+            .block_on(self.inner.list_all_for_org(org, type_, sort, direction))
+    }
+    /// The blocking version of [`crate::repos::Repos::create_in_org`].
+    pub fn create_in_org(
+        &self,
+        org: &str,
+        body: &crate::types::ReposCreateInOrgRequest,
+    ) -> ClientResult<crate::Response<crate::types::Repository>> {
+        self.runtime.block_on(self.inner.create_in_org(org, body))
+    }
+    /// The blocking version of [`crate::repos::Repos::get`].
+    pub fn get(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> ClientResult<crate::Response<crate::types::FullRepository>> {
+        self.runtime.block_on(self.inner.get(owner, repo))
+    }
+    /// The blocking version of [`crate::repos::Repos::delete`].
+    pub fn delete(&self, owner: &str, repo: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.delete(owner, repo))
+    }
+    /// The blocking version of [`crate::repos::Repos::update`].
+    pub fn update(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::ReposUpdateRequest,
+    ) -> ClientResult<crate::Response<crate::types::FullRepository>> {
+        self.runtime.block_on(self.inner.update(owner, repo, body))
+    }
+    /// The blocking version of [`crate::repos::Repos::list_autolinks`].
+    pub fn list_autolinks(
+        &self,
+        owner: &str,
+        repo: &str,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Autolink>>> {
+        self.runtime
+            .block_on(self.inner.list_autolinks(owner, repo, page))
+    }
+    /// The blocking version of [`crate::repos::Repos::list_autolinks_request`].
+    pub fn list_autolinks_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+    ) -> ReposListAutolinksRequest<'a> {
+        ReposListAutolinksRequest {
+            inner: self.inner.list_autolinks_request(owner, repo),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::repos::Repos::list_all_autolinks`].
+    pub fn list_all_autolinks(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Autolink>>> {
+        self.runtime
+            .block_on(self.inner.list_all_autolinks(owner, repo))
+    }
+    /// The blocking version of [`crate::repos::Repos::create_autolink`].
+    pub fn create_autolink(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::ReposCreateAutolinkRequest,
+    ) -> ClientResult<crate::Response<crate::types::Autolink>> {
+        self.runtime
+            .block_on(self.inner.create_autolink(owner, repo, body))
+    }
+    /// The blocking version of [`crate::repos::Repos::get_autolink`].
+    pub fn get_autolink(
+        &self,
+        owner: &str,
+        repo: &str,
+        autolink_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::Autolink>> {
+        self.runtime
+            .block_on(self.inner.get_autolink(owner, repo, autolink_id))
+    }
+    /// The blocking version of [`crate::repos::Repos::delete_autolink`].
+    pub fn delete_autolink(
+        &self,
+        owner: &str,
+        repo: &str,
+        autolink_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete_autolink(owner, repo, autolink_id))
+    }
+    /// The blocking version of [`crate::repos::Repos::enable_automated_security_fixes`].
+    pub fn enable_automated_security_fixes(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.enable_automated_security_fixes(owner, repo))
+    }
+    /// The blocking version of [`crate::repos::Repos::disable_automated_security_fixes`].
+    pub fn disable_automated_security_fixes(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.disable_automated_security_fixes(owner, repo))
+    }
+    /// The blocking version of [`crate::repos::Repos::list_branches`].
+    pub fn list_branches(
+        &self,
+        owner: &str,
+        repo: &str,
+        protected: bool,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ShortBranch>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_branches(owner, repo, protected, per_page, page),
+        )
+    }
+    /// The blocking version of [`crate::repos::Repos::list_branches_request`].
+    pub fn list_branches_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+    ) -> ReposListBranchesRequest<'a> {
+        ReposListBranchesRequest {
+            inner: self.inner.list_branches_request(owner, repo),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::repos::Repos::list_all_branches`].
+    pub fn list_all_branches(
+        &self,
+        owner: &str,
+        repo: &str,
+        protected: bool,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ShortBranch>>> {
+        self.runtime
+            .block_on(self.inner.list_all_branches(owner, repo, protected))
+    }
+    /// The blocking version of [`crate::repos::Repos::get_branch`].
+    pub fn get_branch(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> ClientResult<crate::Response<crate::types::BranchWithProtection>> {
+        self.runtime
+            .block_on(self.inner.get_branch(owner, repo, branch))
+    }
+    /// The blocking version of [`crate::repos::Repos::get_branch_protection`].
+    pub fn get_branch_protection(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> ClientResult<crate::Response<crate::types::BranchProtection>> {
+        self.runtime
+            .block_on(self.inner.get_branch_protection(owner, repo, branch))
+    }
+    /// The blocking version of [`crate::repos::Repos::update_branch_protection`].
+    pub fn update_branch_protection(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+        body: &crate::types::ReposUpdateBranchProtectionRequest,
+    ) -> ClientResult<crate::Response<crate::types::ProtectedBranch>> {
+        self.runtime.block_on(
+            self.inner
+                .update_branch_protection(owner, repo, branch, body),
+        )
+    }
+    /// The blocking version of [`crate::repos::Repos::delete_branch_protection`].
+    pub fn delete_branch_protection(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete_branch_protection(owner, repo, branch))
+    }
+    /// The blocking version of [`crate::repos::Repos::get_admin_branch_protection`].
+    pub fn get_admin_branch_protection(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> ClientResult<crate::Response<crate::types::EnforceAdmins>> {
+        self.runtime
+            .block_on(self.inner.get_admin_branch_protection(owner, repo, branch))
+    }
+    /// The blocking version of [`crate::repos::Repos::set_admin_branch_protection`].
+    pub fn set_admin_branch_protection(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> ClientResult<crate::Response<crate::types::EnforceAdmins>> {
+        self.runtime
+            .block_on(self.inner.set_admin_branch_protection(owner, repo, branch))
+    }
+    /// The blocking version of [`crate::repos::Repos::delete_admin_branch_protection`].
+    pub fn delete_admin_branch_protection(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .delete_admin_branch_protection(owner, repo, branch),
+        )
+    }
+    /// The blocking version of [`crate::repos::Repos::get_pull_request_review_protection`].
+    pub fn get_pull_request_review_protection(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> ClientResult<crate::Response<crate::types::ProtectedBranchPullRequestReview>> {
+        self.runtime.block_on(
+            self.inner
+                .get_pull_request_review_protection(owner, repo, branch),
+        )
+    }
+    /// The blocking version of [`crate::repos::Repos::delete_pull_request_review_protection`].
+    pub fn delete_pull_request_review_protection(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .delete_pull_request_review_protection(owner, repo, branch),
+        )
+    }
+    /// The blocking version of [`crate::repos::Repos::update_pull_request_review_protection`].
+    pub fn update_pull_request_review_protection(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+        body: &crate::types::ReposUpdatePullRequestReviewProtection,
+    ) -> ClientResult<crate::Response<crate::types::ProtectedBranchPullRequestReview>> {
+        self.runtime.block_on(
+            self.inner
+                .update_pull_request_review_protection(owner, repo, branch, body),
+        )
+    }
+    /// The blocking version of [`crate::repos::Repos::get_commit_signature_protection`].
+    pub fn get_commit_signature_protection(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> ClientResult<crate::Response<crate::types::EnforceAdmins>> {
+        self.runtime.block_on(
+            self.inner
+                .get_commit_signature_protection(owner, repo, branch),
+        )
+    }
+    /// The blocking version of [`crate::repos::Repos::create_commit_signature_protection`].
+    pub fn create_commit_signature_protection(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> ClientResult<crate::Response<crate::types::EnforceAdmins>> {
+        self.runtime.block_on(
+            self.inner
+                .create_commit_signature_protection(owner, repo, branch),
+        )
+    }
+    /// The blocking version of [`crate::repos::Repos::delete_commit_signature_protection`].
+    pub fn delete_commit_signature_protection(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .delete_commit_signature_protection(owner, repo, branch),
+        )
+    }
+    /// The blocking version of [`crate::repos::Repos::get_status_checks_protection`].
+    pub fn get_status_checks_protection(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> ClientResult<crate::Response<crate::types::StatusCheckPolicy>> {
+        self.runtime
+            .block_on(self.inner.get_status_checks_protection(owner, repo, branch))
+    }
+    /// The blocking version of [`crate::repos::Repos::remove_status_check_protection`].
+    pub fn remove_status_check_protection(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .remove_status_check_protection(owner, repo, branch),
+        )
+    }
+    /// The blocking version of [`crate::repos::Repos::update_status_check_protection`].
+    pub fn update_status_check_protection(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+        body: &crate::types::ReposUpdateStatusCheckProtectionRequest,
+    ) -> ClientResult<crate::Response<crate::types::StatusCheckPolicy>> {
+        self.runtime.block_on(
+            self.inner
+                .update_status_check_protection(owner, repo, branch, body),
+        )
+    }
+    /// The blocking version of [`crate::repos::Repos::get_all_status_check_contexts`].
+    pub fn get_all_status_check_contexts(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> ClientResult<crate::Response<Vec<String>>> {
+        self.runtime.block_on(
+            self.inner
+                .get_all_status_check_contexts(owner, repo, branch),
+        )
+    }
+    /// The blocking version of [`crate::repos::Repos::get_all_all_status_check_contexts`].
+    pub fn get_all_all_status_check_contexts(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> ClientResult<crate::Response<Vec<String>>> {
+        self.runtime.block_on(
+            self.inner
+                .get_all_all_status_check_contexts(owner, repo, branch),
+        )
+    }
+    /// The blocking version of [`crate::repos::Repos::set_status_check_contexts`].
+    pub fn set_status_check_contexts(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+        body: &crate::types::ReposAddStatusCheckContextsRequestOneOf,
+    ) -> ClientResult<crate::Response<Vec<String>>> {
+        self.runtime.block_on(
+            self.inner
+                .set_status_check_contexts(owner, repo, branch, body),
+        )
+    }
+    /// The blocking version of [`crate::repos::Repos::add_status_check_contexts`].
+    pub fn add_status_check_contexts(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+        body: &crate::types::ReposAddStatusCheckContextsRequestOneOf,
+    ) -> ClientResult<crate::Response<Vec<String>>> {
+        self.runtime.block_on(
+            self.inner
+                .add_status_check_contexts(owner, repo, branch, body),
+        )
+    }
+    /// The blocking version of [`crate::repos::Repos::remove_status_check_contexts`].
+    pub fn remove_status_check_contexts(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+        body: &crate::types::ReposAddStatusCheckContextsRequestOneOf,
+    ) -> ClientResult<crate::Response<Vec<String>>> {
+        self.runtime.block_on(
+            self.inner
+                .remove_status_check_contexts(owner, repo, branch, body),
+        )
+    }
+    /// The blocking version of [`crate::repos::Repos::get_access_restrictions`].
+    pub fn get_access_restrictions(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> ClientResult<crate::Response<crate::types::BranchRestrictionPolicy>> {
+        self.runtime
+            .block_on(self.inner.get_access_restrictions(owner, repo, branch))
+    }
+    /// The blocking version of [`crate::repos::Repos::delete_access_restrictions`].
+    pub fn delete_access_restrictions(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete_access_restrictions(owner, repo, branch))
+    }
+    /// The blocking version of [`crate::repos::Repos::get_apps_with_access_to_protected_branch`].
+    pub fn get_apps_with_access_to_protected_branch(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GitHubApp>>> {
+        self.runtime.block_on(
+            self.inner
+                .get_apps_with_access_to_protected_branch(owner, repo, branch),
+        )
+    }
+    /// The blocking version of [`crate::repos::Repos::get_all_apps_with_access_to_protected_branch`].
+    pub fn get_all_apps_with_access_to_protected_branch(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GitHubApp>>> {
+        self.runtime.block_on(
+            self.inner
+                .get_all_apps_with_access_to_protected_branch(owner, repo, branch),
+        )
+    }
+    /// The blocking version of [`crate::repos::Repos::set_app_access_restrictions`].
+    pub fn set_app_access_restrictions(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+        body: &crate::types::ReposAddAppAccessRestrictionsRequestOneOf,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GitHubApp>>> {
+        self.runtime.block_on(
+            self.inner
+                .set_app_access_restrictions(owner, repo, branch, body),
+        )
+    }
+    /// The blocking version of [`crate::repos::Repos::add_app_access_restrictions`].
+    pub fn add_app_access_restrictions(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+        body: &crate::types::ReposAddAppAccessRestrictionsRequestOneOf,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GitHubApp>>> {
+        self.runtime.block_on(
+            self.inner
+                .add_app_access_restrictions(owner, repo, branch, body),
+        )
+    }
+    /// The blocking version of [`crate::repos::Repos::remove_app_access_restrictions`].
+    pub fn remove_app_access_restrictions(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+        body: &crate::types::ReposAddAppAccessRestrictionsRequestOneOf,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GitHubApp>>> {
+        self.runtime.block_on(
+            self.inner
+                .remove_app_access_restrictions(owner, repo, branch, body),
+        )
+    }
+    /// The blocking version of [`crate::repos::Repos::get_teams_with_access_to_protected_branch`].
+    pub fn get_teams_with_access_to_protected_branch(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Team>>> {
+        self.runtime.block_on(
+            self.inner
+                .get_teams_with_access_to_protected_branch(owner, repo, branch),
+        )
+    }
+    /// The blocking version of [`crate::repos::Repos::get_all_teams_with_access_to_protected_branch`].
+    pub fn get_all_teams_with_access_to_protected_branch(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Team>>> {
+        self.runtime.block_on(
+            self.inner
+                .get_all_teams_with_access_to_protected_branch(owner, repo, branch),
+        )
+    }
+    /// The blocking version of [`crate::repos::Repos::set_team_access_restrictions`].
+    pub fn set_team_access_restrictions(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+        body: &crate::types::ReposAddTeamAccessRestrictionsRequestOneOf,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Team>>> {
+        self.runtime.block_on(
+            self.inner
+                .set_team_access_restrictions(owner, repo, branch, body),
+        )
+    }
+    /// The blocking version of [`crate::repos::Repos::add_team_access_restrictions`].
+    pub fn add_team_access_restrictions(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+        body: &crate::types::ReposAddTeamAccessRestrictionsRequestOneOf,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Team>>> {
+        self.runtime.block_on(
+            self.inner
+                .add_team_access_restrictions(owner, repo, branch, body),
+        )
+    }
+    /// The blocking version of [`crate::repos::Repos::remove_team_access_restrictions`].
+    pub fn remove_team_access_restrictions(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+        body: &crate::types::ReposAddTeamAccessRestrictionsRequestOneOf,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Team>>> {
+        self.runtime.block_on(
+            self.inner
+                .remove_team_access_restrictions(owner, repo, branch, body),
+        )
+    }
+    /// The blocking version of [`crate::repos::Repos::get_users_with_access_to_protected_branch`].
+    pub fn get_users_with_access_to_protected_branch(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::SimpleUser>>> {
+        self.runtime.block_on(
+            self.inner
+                .get_users_with_access_to_protected_branch(owner, repo, branch),
+        )
+    }
+    /// The blocking version of [`crate::repos::Repos::get_all_users_with_access_to_protected_branch`].
+    pub fn get_all_users_with_access_to_protected_branch(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::SimpleUser>>> {
+        self.runtime.block_on(
+            self.inner
+                .get_all_users_with_access_to_protected_branch(owner, repo, branch),
+        )
+    }
+    /// The blocking version of [`crate::repos::Repos::set_user_access_restrictions`].
+    pub fn set_user_access_restrictions(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+        body: &crate::types::ReposAddUserAccessRestrictionsRequestOneOf,
+    ) -> ClientResult<crate::Response<Vec<crate::types::SimpleUser>>> {
+        self.runtime.block_on(
+            self.inner
+                .set_user_access_restrictions(owner, repo, branch, body),
+        )
+    }
+    /// The blocking version of [`crate::repos::Repos::add_user_access_restrictions`].
+    pub fn add_user_access_restrictions(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+        body: &crate::types::ReposAddUserAccessRestrictionsRequestOneOf,
+    ) -> ClientResult<crate::Response<Vec<crate::types::SimpleUser>>> {
+        self.runtime.block_on(
+            self.inner
+                .add_user_access_restrictions(owner, repo, branch, body),
+        )
+    }
+    /// The blocking version of [`crate::repos::Repos::remove_user_access_restrictions`].
+    pub fn remove_user_access_restrictions(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+        body: &crate::types::ReposAddUserAccessRestrictionsRequestOneOf,
+    ) -> ClientResult<crate::Response<Vec<crate::types::SimpleUser>>> {
+        self.runtime.block_on(
+            self.inner
+                .remove_user_access_restrictions(owner, repo, branch, body),
+        )
+    }
+    /// The blocking version of [`crate::repos::Repos::rename_branch`].
+    pub fn rename_branch(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+        body: &crate::types::ReposRenameBranchRequest,
+    ) -> ClientResult<crate::Response<crate::types::BranchWithProtection>> {
+        self.runtime
+            .block_on(self.inner.rename_branch(owner, repo, branch, body))
+    }
+    /// The blocking version of [`crate::repos::Repos::list_collaborators`].
+    pub fn list_collaborators(
+        &self,
+        owner: &str,
+        repo: &str,
+        affiliation: crate::types::Affiliation,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Collaborator>>> {
+        self.runtime.block_on(self.inner.list_collaborators(
+            owner,
+            repo,
+            affiliation,
+            per_page,
+            page,
+        ))
+    }
+    /// The blocking version of [`crate::repos::Repos::list_collaborators_request`].
+    pub fn list_collaborators_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+    ) -> ReposListCollaboratorsRequest<'a> {
+        ReposListCollaboratorsRequest {
+            inner: self.inner.list_collaborators_request(owner, repo),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::repos::Repos::list_all_collaborators`].
+    pub fn list_all_collaborators(
+        &self,
+        owner: &str,
+        repo: &str,
+        affiliation: crate::types::Affiliation,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Collaborator>>> {
+        self.runtime
+            .block_on(self.inner.list_all_collaborators(owner, repo, affiliation))
+    }
+    /// The blocking version of [`crate::repos::Repos::check_collaborator`].
+    pub fn check_collaborator(
+        &self,
+        owner: &str,
+        repo: &str,
+        username: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.check_collaborator(owner, repo, username))
+    }
+    /// The blocking version of [`crate::repos::Repos::add_collaborator`].
+    pub fn add_collaborator(
+        &self,
+        owner: &str,
+        repo: &str,
+        username: &str,
+        body: &crate::types::ReposAddCollaboratorRequest,
+    ) -> ClientResult<crate::Response<crate::types::RepositoryInvitation>> {
+        self.runtime
+            .block_on(self.inner.add_collaborator(owner, repo, username, body))
+    }
+    /// The blocking version of [`crate::repos::Repos::remove_collaborator`].
+    pub fn remove_collaborator(
+        &self,
+        owner: &str,
+        repo: &str,
+        username: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.remove_collaborator(owner, repo, username))
+    }
+    /// The blocking version of [`crate::repos::Repos::get_collaborator_permission_level`].
+    pub fn get_collaborator_permission_level(
+        &self,
+        owner: &str,
+        repo: &str,
+        username: &str,
+    ) -> ClientResult<crate::Response<crate::types::RepositoryCollaboratorPermission>> {
+        self.runtime.block_on(
+            self.inner
+                .get_collaborator_permission_level(owner, repo, username),
+        )
+    }
+    /// The blocking version of [`crate::repos::Repos::list_commit_comments_for_repo`].
+    pub fn list_commit_comments_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::CommitComment>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_commit_comments_for_repo(owner, repo, per_page, page),
+        )
+    }
+    /// The blocking version of [`crate::repos::Repos::list_commit_comments_for_repo_request`].
+    pub fn list_commit_comments_for_repo_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+    ) -> ReposListCommitCommentsForRepoRequest<'a> {
+        ReposListCommitCommentsForRepoRequest {
+            inner: self
+                .inner
+                .list_commit_comments_for_repo_request(owner, repo),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::repos::Repos::list_all_commit_comments_for_repo`].
+    pub fn list_all_commit_comments_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::CommitComment>>> {
+        self.runtime
+            .block_on(self.inner.list_all_commit_comments_for_repo(owner, repo))
+    }
+    /// The blocking version of [`crate::repos::Repos::get_commit_comment`].
+    pub fn get_commit_comment(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::CommitComment>> {
+        self.runtime
+            .block_on(self.inner.get_commit_comment(owner, repo, comment_id))
+    }
+    /// The blocking version of [`crate::repos::Repos::delete_commit_comment`].
+    pub fn delete_commit_comment(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete_commit_comment(owner, repo, comment_id))
+    }
+    /// The blocking version of [`crate::repos::Repos::update_commit_comment`].
+    pub fn update_commit_comment(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+        body: &crate::types::PullsUpdateReviewRequest,
+    ) -> ClientResult<crate::Response<crate::types::CommitComment>> {
+        self.runtime.block_on(
+            self.inner
+                .update_commit_comment(owner, repo, comment_id, body),
+        )
+    }
+    /// The blocking version of [`crate::repos::Repos::list_commits`].
+    pub fn list_commits(
+        &self,
+        owner: &str,
+        repo: &str,
+        sha: &str,
+        path: &str,
+        author: &str,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+        until: Option<chrono::DateTime<chrono::Utc>>,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::CommitDataType>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_commits(owner, repo, sha, path, author, since, until, per_page, page),
+        )
+    }
+    /// The blocking version of [`crate::repos::Repos::list_commits_request`].
+    pub fn list_commits_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+    ) -> ReposListCommitsRequest<'a> {
+        ReposListCommitsRequest {
+            inner: self.inner.list_commits_request(owner, repo),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::repos::Repos::list_all_commits`].
+    pub fn list_all_commits(
+        &self,
+        owner: &str,
+        repo: &str,
+        sha: &str,
+        path: &str,
+        author: &str,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+        until: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> ClientResult<crate::Response<Vec<crate::types::CommitDataType>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_all_commits(owner, repo, sha, path, author, since, until),
+        )
+    }
+    /// The blocking version of [`crate::repos::Repos::list_branches_for_head_commit`].
+    pub fn list_branches_for_head_commit(
+        &self,
+        owner: &str,
+        repo: &str,
+        commit_sha: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::BranchShort>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_branches_for_head_commit(owner, repo, commit_sha),
+        )
+    }
+    /// The blocking version of [`crate::repos::Repos::list_all_branches_for_head_commit`].
+    pub fn list_all_branches_for_head_commit(
+        &self,
+        owner: &str,
+        repo: &str,
+        commit_sha: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::BranchShort>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_all_branches_for_head_commit(owner, repo, commit_sha),
+        )
+    }
+    /// The blocking version of [`crate::repos::Repos::list_comments_for_commit`].
+    pub fn list_comments_for_commit(
+        &self,
+        owner: &str,
+        repo: &str,
+        commit_sha: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::CommitComment>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_comments_for_commit(owner, repo, commit_sha, per_page, page),
+        )
+    }
+    /// The blocking version of [`crate::repos::Repos::list_comments_for_commit_request`].
+    pub fn list_comments_for_commit_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+        commit_sha: &'a str,
+    ) -> ReposListCommentsForCommitRequest<'a> {
+        ReposListCommentsForCommitRequest {
+            inner: self
+                .inner
+                .list_comments_for_commit_request(owner, repo, commit_sha),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::repos::Repos::list_all_comments_for_commit`].
+    pub fn list_all_comments_for_commit(
+        &self,
+        owner: &str,
+        repo: &str,
+        commit_sha: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::CommitComment>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_all_comments_for_commit(owner, repo, commit_sha),
+        )
+    }
+    /// The blocking version of [`crate::repos::Repos::create_commit_comment`].
+    pub fn create_commit_comment(
+        &self,
+        owner: &str,
+        repo: &str,
+        commit_sha: &str,
+        body: &crate::types::ReposCreateCommitCommentRequest,
+    ) -> ClientResult<crate::Response<crate::types::CommitComment>> {
+        self.runtime.block_on(
+            self.inner
+                .create_commit_comment(owner, repo, commit_sha, body),
+        )
+    }
+    /// The blocking version of [`crate::repos::Repos::list_pull_requests_associated_with_commit`].
+    pub fn list_pull_requests_associated_with_commit(
+        &self,
+        owner: &str,
+        repo: &str,
+        commit_sha: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::PullRequestSimple>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_pull_requests_associated_with_commit(owner, repo, commit_sha, per_page, page),
+        )
+    }
+    /// The blocking version of [`crate::repos::Repos::list_pull_requests_associated_with_commit_request`].
+    pub fn list_pull_requests_associated_with_commit_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+        commit_sha: &'a str,
+    ) -> ReposListPullRequestsAssociatedWithCommitRequest<'a> {
+        ReposListPullRequestsAssociatedWithCommitRequest {
+            inner: self
+                .inner
+                .list_pull_requests_associated_with_commit_request(owner, repo, commit_sha),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::repos::Repos::list_all_pull_requests_associated_with_commit`].
+    pub fn list_all_pull_requests_associated_with_commit(
+        &self,
+        owner: &str,
+        repo: &str,
+        commit_sha: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::PullRequestSimple>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_all_pull_requests_associated_with_commit(owner, repo, commit_sha),
+        )
+    }
+    /// The blocking version of [`crate::repos::Repos::get_commit`].
+    pub fn get_commit(
+        &self,
+        owner: &str,
+        repo: &str,
+        page: i64,
+        per_page: i64,
+        ref_: &str,
+    ) -> ClientResult<crate::Response<crate::types::CommitDataType>> {
+        self.runtime
+            .block_on(self.inner.get_commit(owner, repo, page, per_page, ref_))
+    }
+    /// The blocking version of [`crate::repos::Repos::get_commit_request`].
+    pub fn get_commit_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+        ref_: &'a str,
+    ) -> ReposGetCommitRequest<'a> {
+        ReposGetCommitRequest {
+            inner: self.inner.get_commit_request(owner, repo, ref_),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::repos::Repos::get_combined_status_for_ref`].
+    pub fn get_combined_status_for_ref(
+        &self,
+        owner: &str,
+        repo: &str,
+        ref_: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<crate::types::CombinedCommitStatus>> {
+        self.runtime.block_on(
+            self.inner
+                .get_combined_status_for_ref(owner, repo, ref_, per_page, page),
+        )
+    }
+    /// The blocking version of [`crate::repos::Repos::get_combined_status_for_ref_request`].
+    pub fn get_combined_status_for_ref_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+        ref_: &'a str,
+    ) -> ReposGetCombinedStatusForRefRequest<'a> {
+        ReposGetCombinedStatusForRefRequest {
+            inner: self
+                .inner
+                .get_combined_status_for_ref_request(owner, repo, ref_),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::repos::Repos::list_commit_statuses_for_ref`].
+    pub fn list_commit_statuses_for_ref(
+        &self,
+        owner: &str,
+        repo: &str,
+        ref_: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::StatusData>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_commit_statuses_for_ref(owner, repo, ref_, per_page, page),
+        )
+    }
+    /// The blocking version of [`crate::repos::Repos::list_commit_statuses_for_ref_request`].
+    pub fn list_commit_statuses_for_ref_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+        ref_: &'a str,
+    ) -> ReposListCommitStatusesForRefRequest<'a> {
+        ReposListCommitStatusesForRefRequest {
+            inner: self
+                .inner
+                .list_commit_statuses_for_ref_request(owner, repo, ref_),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::repos::Repos::list_all_commit_statuses_for_ref`].
+    pub fn list_all_commit_statuses_for_ref(
+        &self,
+        owner: &str,
+        repo: &str,
+        ref_: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::StatusData>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_all_commit_statuses_for_ref(owner, repo, ref_),
+        )
+    }
+    /// The blocking version of [`crate::repos::Repos::get_community_profile_metrics`].
+    pub fn get_community_profile_metrics(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> ClientResult<crate::Response<crate::types::CommunityProfile>> {
+        self.runtime
+            .block_on(self.inner.get_community_profile_metrics(owner, repo))
+    }
+    /// The blocking version of [`crate::repos::Repos::compare_commits`].
+    pub fn compare_commits(
+        &self,
+        owner: &str,
+        repo: &str,
+        page: i64,
+        per_page: i64,
+        basehead: &str,
+    ) -> ClientResult<crate::Response<crate::types::CommitComparison>> {
+        self.runtime.block_on(
+            self.inner
+                .compare_commits(owner, repo, page, per_page, basehead),
+        )
+    }
+    /// The blocking version of [`crate::repos::Repos::compare_commits_request`].
+    pub fn compare_commits_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+        basehead: &'a str,
+    ) -> ReposCompareCommitsRequest<'a> {
+        ReposCompareCommitsRequest {
+            inner: self.inner.compare_commits_request(owner, repo, basehead),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::repos::Repos::get_content_vec_entries`].
+    pub fn get_content_vec_entries(
+        &self,
+        owner: &str,
+        repo: &str,
+        path: &str,
+        ref_: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Entries>>> {
+        self.runtime
+            .block_on(self.inner.get_content_vec_entries(owner, repo, path, ref_))
+    }
+    /// The blocking version of [`crate::repos::Repos::get_content_file`].
+    pub fn get_content_file(
+        &self,
+        owner: &str,
+        repo: &str,
+        path: &str,
+        ref_: &str,
+    ) -> ClientResult<crate::Response<crate::types::ContentFile>> {
+        self.runtime
+            .block_on(self.inner.get_content_file(owner, repo, path, ref_))
+    }
+    /// The blocking version of [`crate::repos::Repos::get_content_symlink`].
+    pub fn get_content_symlink(
+        &self,
+        owner: &str,
+        repo: &str,
+        path: &str,
+        ref_: &str,
+    ) -> ClientResult<crate::Response<crate::types::SymlinkContent>> {
+        self.runtime
+            .block_on(self.inner.get_content_symlink(owner, repo, path, ref_))
+    }
+    /// The blocking version of [`crate::repos::Repos::get_content_submodule`].
+    pub fn get_content_submodule(
+        &self,
+        owner: &str,
+        repo: &str,
+        path: &str,
+        ref_: &str,
+    ) -> ClientResult<crate::Response<crate::types::ContentSubmodule>> {
+        self.runtime
+            .block_on(self.inner.get_content_submodule(owner, repo, path, ref_))
+    }
+    /// The blocking version of [`crate::repos::Repos::get_content`].
+    pub fn get_content(
+        &self,
+        owner: &str,
+        repo: &str,
+        path: &str,
+        ref_: &str,
+    ) -> ClientResult<crate::Response<crate::types::ReposGetContentResponseOneOf>> {
+        self.runtime
+            .block_on(self.inner.get_content(owner, repo, path, ref_))
+    }
+    /// The blocking version of [`crate::repos::Repos::get_content_request`].
+    pub fn get_content_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+        path: &'a str,
+    ) -> ReposGetContentRequest<'a> {
+        ReposGetContentRequest {
+            inner: self.inner.get_content_request(owner, repo, path),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::repos::Repos::create_or_update_file_contents`].
+    pub fn create_or_update_file_contents(
+        &self,
+        owner: &str,
+        repo: &str,
+        path: &str,
+        body: &crate::types::ReposCreateUpdateFileContentsRequest,
+    ) -> ClientResult<crate::Response<crate::types::FileCommitData>> {
+        self.runtime.block_on(
+            self.inner
+                .create_or_update_file_contents(owner, repo, path, body),
+        )
+    }
+    /// The blocking version of [`crate::repos::Repos::delete_file`].
+    pub fn delete_file(
+        &self,
+        owner: &str,
+        repo: &str,
+        path: &str,
+        body: &crate::types::ReposDeleteFileRequest,
+    ) -> ClientResult<crate::Response<crate::types::FileCommitData>> {
+        self.runtime
+            .block_on(self.inner.delete_file(owner, repo, path, body))
+    }
+    /// The blocking version of [`crate::repos::Repos::list_contributors`].
+    pub fn list_contributors(
+        &self,
+        owner: &str,
+        repo: &str,
+        anon: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Contributor>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_contributors(owner, repo, anon, per_page, page),
+        )
+    }
+    /// The blocking version of [`crate::repos::Repos::list_contributors_request`].
+    pub fn list_contributors_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+    ) -> ReposListContributorsRequest<'a> {
+        ReposListContributorsRequest {
+            inner: self.inner.list_contributors_request(owner, repo),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::repos::Repos::list_all_contributors`].
+    pub fn list_all_contributors(
+        &self,
+        owner: &str,
+        repo: &str,
+        anon: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Contributor>>> {
+        self.runtime
+            .block_on(self.inner.list_all_contributors(owner, repo, anon))
+    }
+    /// The blocking version of [`crate::repos::Repos::list_deployments`].
+    pub fn list_deployments(
+        &self,
+        owner: &str,
+        repo: &str,
+        sha: &str,
+        ref_: &str,
+        task: &str,
+        environment: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Deployment>>> {
+        self.runtime.block_on(self.inner.list_deployments(
+            owner,
+            repo,
+            sha,
+            ref_,
+            task,
+            environment,
+            per_page,
+            page,
+        ))
+    }
+    /// The blocking version of [`crate::repos::Repos::list_deployments_request`].
+    pub fn list_deployments_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+    ) -> ReposListDeploymentsRequest<'a> {
+        ReposListDeploymentsRequest {
+            inner: self.inner.list_deployments_request(owner, repo),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::repos::Repos::list_all_deployments`].
+    pub fn list_all_deployments(
+        &self,
+        owner: &str,
+        repo: &str,
+        sha: &str,
+        ref_: &str,
+        task: &str,
+        environment: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Deployment>>> {
+        self.runtime.block_on(self.inner.list_all_deployments(
+            owner,
+            repo,
+            sha,
+            ref_,
+            task,
+            environment,
+        ))
+    }
+    /// The blocking version of [`crate::repos::Repos::create_deployment`].
+    pub fn create_deployment(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::ReposCreateDeploymentRequest,
+    ) -> ClientResult<crate::Response<crate::types::Deployment>> {
+        self.runtime
+            .block_on(self.inner.create_deployment(owner, repo, body))
+    }
+    /// The blocking version of [`crate::repos::Repos::get_deployment`].
+    pub fn get_deployment(
+        &self,
+        owner: &str,
+        repo: &str,
+        deployment_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::Deployment>> {
+        self.runtime
+            .block_on(self.inner.get_deployment(owner, repo, deployment_id))
+    }
+    /// The blocking version of [`crate::repos::Repos::delete_deployment`].
+    pub fn delete_deployment(
+        &self,
+        owner: &str,
+        repo: &str,
+        deployment_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete_deployment(owner, repo, deployment_id))
+    }
+    /// The blocking version of [`crate::repos::Repos::list_deployment_statuses`].
+    pub fn list_deployment_statuses(
+        &self,
+        owner: &str,
+        repo: &str,
+        deployment_id: i64,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::DeploymentStatus>>> {
+        self.runtime.block_on(self.inner.list_deployment_statuses(
+            owner,
+            repo,
+            deployment_id,
+            per_page,
+            page,
+        ))
+    }
+    /// The blocking version of [`crate::repos::Repos::list_deployment_statuses_request`].
+    pub fn list_deployment_statuses_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+        deployment_id: i64,
+    ) -> ReposListDeploymentStatusesRequest<'a> {
+        ReposListDeploymentStatusesRequest {
+            inner: self
+                .inner
+                .list_deployment_statuses_request(owner, repo, deployment_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::repos::Repos::list_all_deployment_statuses`].
+    pub fn list_all_deployment_statuses(
+        &self,
+        owner: &str,
+        repo: &str,
+        deployment_id: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::DeploymentStatus>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_all_deployment_statuses(owner, repo, deployment_id),
+        )
+    }
+    /// The blocking version of [`crate::repos::Repos::create_deployment_status`].
+    pub fn create_deployment_status(
+        &self,
+        owner: &str,
+        repo: &str,
+        deployment_id: i64,
+        body: &crate::types::ReposCreateDeploymentStatusRequest,
+    ) -> ClientResult<crate::Response<crate::types::DeploymentStatus>> {
+        self.runtime.block_on(
+            self.inner
+                .create_deployment_status(owner, repo, deployment_id, body),
+        )
+    }
+    /// The blocking version of [`crate::repos::Repos::get_deployment_status`].
+    pub fn get_deployment_status(
+        &self,
+        owner: &str,
+        repo: &str,
+        deployment_id: i64,
+        status_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::DeploymentStatus>> {
+        self.runtime.block_on(self.inner.get_deployment_status(
+            owner,
+            repo,
+            deployment_id,
+            status_id,
+        ))
+    }
+    /// The blocking version of [`crate::repos::Repos::create_dispatch_event`].
+    pub fn create_dispatch_event(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::ReposCreateDispatchEventRequest,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.create_dispatch_event(owner, repo, body))
+    }
+    /// The blocking version of [`crate::repos::Repos::get_all_environments`].
+    pub fn get_all_environments(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> ClientResult<crate::Response<crate::types::ReposGetAllEnvironmentsResponse>> {
+        self.runtime
+            .block_on(self.inner.get_all_environments(owner, repo))
+    }
+    /// The blocking version of [`crate::repos::Repos::get_environment`].
+    pub fn get_environment(
+        &self,
+        owner: &str,
+        repo: &str,
+        environment_name: &str,
+    ) -> ClientResult<crate::Response<crate::types::EnvironmentData>> {
+        self.runtime
+            .block_on(self.inner.get_environment(owner, repo, environment_name))
+    }
+    /// The blocking version of [`crate::repos::Repos::create_or_update_environment`].
+    pub fn create_or_update_environment(
+        &self,
+        owner: &str,
+        repo: &str,
+        environment_name: &str,
+        body: &crate::types::ReposCreateUpdateEnvironmentRequest,
+    ) -> ClientResult<crate::Response<crate::types::EnvironmentData>> {
+        self.runtime
+            .block_on(
+                self.inner
+                    .create_or_update_environment(owner, repo, environment_name, body),
+            )
+    }
+    /// The blocking version of [`crate::repos::Repos::delete_an_environment`].
+    pub fn delete_an_environment(
+        &self,
+        owner: &str,
+        repo: &str,
+        environment_name: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .delete_an_environment(owner, repo, environment_name),
+        )
+    }
+    /// The blocking version of [`crate::repos::Repos::list_forks`].
+    pub fn list_forks(
+        &self,
+        owner: &str,
+        repo: &str,
+        sort: crate::types::ReposListForksSort,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::MinimalRepository>>> {
+        self.runtime
+            .block_on(self.inner.list_forks(owner, repo, sort, per_page, page))
+    }
+    /// The blocking version of [`crate::repos::Repos::list_forks_request`].
+    pub fn list_forks_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+    ) -> ReposListForksRequest<'a> {
+        ReposListForksRequest {
+            inner: self.inner.list_forks_request(owner, repo),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::repos::Repos::list_all_forks`].
+    pub fn list_all_forks(
+        &self,
+        owner: &str,
+        repo: &str,
+        sort: crate::types::ReposListForksSort,
+    ) -> ClientResult<crate::Response<Vec<crate::types::MinimalRepository>>> {
+        self.runtime
+            .block_on(self.inner.list_all_forks(owner, repo, sort))
+    }
+    /// The blocking version of [`crate::repos::Repos::create_fork`].
+    pub fn create_fork(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::ReposCreateForkRequest,
+    ) -> ClientResult<crate::Response<crate::types::FullRepository>> {
+        self.runtime
+            .block_on(self.inner.create_fork(owner, repo, body))
+    }
+    /// The blocking version of [`crate::repos::Repos::list_webhooks`].
+    pub fn list_webhooks(
+        &self,
+        owner: &str,
+        repo: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Hook>>> {
+        self.runtime
+            .block_on(self.inner.list_webhooks(owner, repo, per_page, page))
+    }
+    /// The blocking version of [`crate::repos::Repos::list_webhooks_request`].
+    pub fn list_webhooks_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+    ) -> ReposListWebhooksRequest<'a> {
+        ReposListWebhooksRequest {
+            inner: self.inner.list_webhooks_request(owner, repo),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::repos::Repos::list_all_webhooks`].
+    pub fn list_all_webhooks(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Hook>>> {
+        self.runtime
+            .block_on(self.inner.list_all_webhooks(owner, repo))
+    }
+    /// The blocking version of [`crate::repos::Repos::create_webhook`].
+    pub fn create_webhook(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::ReposCreateWebhookRequest,
+    ) -> ClientResult<crate::Response<crate::types::Hook>> {
+        self.runtime
+            .block_on(self.inner.create_webhook(owner, repo, body))
+    }
+    /// The blocking version of [`crate::repos::Repos::get_webhook`].
+    pub fn get_webhook(
+        &self,
+        owner: &str,
+        repo: &str,
+        hook_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::Hook>> {
+        self.runtime
+            .block_on(self.inner.get_webhook(owner, repo, hook_id))
+    }
+    /// The blocking version of [`crate::repos::Repos::delete_webhook`].
+    pub fn delete_webhook(
+        &self,
+        owner: &str,
+        repo: &str,
+        hook_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete_webhook(owner, repo, hook_id))
+    }
+    /// The blocking version of [`crate::repos::Repos::update_webhook`].
+    pub fn update_webhook(
+        &self,
+        owner: &str,
+        repo: &str,
+        hook_id: i64,
+        body: &crate::types::ReposUpdateWebhookRequest,
+    ) -> ClientResult<crate::Response<crate::types::Hook>> {
+        self.runtime
+            .block_on(self.inner.update_webhook(owner, repo, hook_id, body))
+    }
+    /// The blocking version of [`crate::repos::Repos::get_webhook_config_for_repo`].
+    pub fn get_webhook_config_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+        hook_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::WebhookConfig>> {
+        self.runtime
+            .block_on(self.inner.get_webhook_config_for_repo(owner, repo, hook_id))
+    }
+    /// The blocking version of [`crate::repos::Repos::update_webhook_config_for_repo`].
+    pub fn update_webhook_config_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+        hook_id: i64,
+        body: &crate::types::AppsUpdateWebhookConfigAppRequest,
+    ) -> ClientResult<crate::Response<crate::types::WebhookConfig>> {
+        self.runtime.block_on(
+            self.inner
+                .update_webhook_config_for_repo(owner, repo, hook_id, body),
+        )
+    }
+    /// The blocking version of [`crate::repos::Repos::list_webhook_deliveries`].
+    pub fn list_webhook_deliveries(
+        &self,
+        owner: &str,
+        repo: &str,
+        hook_id: i64,
+        per_page: i64,
+        cursor: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::HookDeliveryItem>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_webhook_deliveries(owner, repo, hook_id, per_page, cursor),
+        )
+    }
+    /// The blocking version of [`crate::repos::Repos::list_webhook_deliveries_request`].
+    pub fn list_webhook_deliveries_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+        hook_id: i64,
+    ) -> ReposListWebhookDeliveriesRequest<'a> {
+        ReposListWebhookDeliveriesRequest {
+            inner: self
+                .inner
+                .list_webhook_deliveries_request(owner, repo, hook_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::repos::Repos::list_all_webhook_deliveries`].
+    pub fn list_all_webhook_deliveries(
+        &self,
+        owner: &str,
+        repo: &str,
+        hook_id: i64,
+        cursor: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::HookDeliveryItem>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_all_webhook_deliveries(owner, repo, hook_id, cursor),
+        )
+    }
+    /// The blocking version of [`crate::repos::Repos::get_webhook_delivery`].
+    pub fn get_webhook_delivery(
+        &self,
+        owner: &str,
+        repo: &str,
+        hook_id: i64,
+        delivery_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::HookDelivery>> {
+        self.runtime.block_on(
+            self.inner
+                .get_webhook_delivery(owner, repo, hook_id, delivery_id),
+        )
+    }
+    /// The blocking version of [`crate::repos::Repos::redeliver_webhook_delivery`].
+    pub fn redeliver_webhook_delivery(
+        &self,
+        owner: &str,
+        repo: &str,
+        hook_id: i64,
+        delivery_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.redeliver_webhook_delivery(
+            owner,
+            repo,
+            hook_id,
+            delivery_id,
+        ))
+    }
+    /// The blocking version of [`crate::repos::Repos::ping_webhook`].
+    pub fn ping_webhook(
+        &self,
+        owner: &str,
+        repo: &str,
+        hook_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.ping_webhook(owner, repo, hook_id))
+    }
+    /// The blocking version of [`crate::repos::Repos::test_push_webhook`].
+    pub fn test_push_webhook(
+        &self,
+        owner: &str,
+        repo: &str,
+        hook_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.test_push_webhook(owner, repo, hook_id))
+    }
+    /// The blocking version of [`crate::repos::Repos::list_invitations`].
+    pub fn list_invitations(
+        &self,
+        owner: &str,
+        repo: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::RepositoryInvitation>>> {
+        self.runtime
+            .block_on(self.inner.list_invitations(owner, repo, per_page, page))
+    }
+    /// The blocking version of [`crate::repos::Repos::list_invitations_request`].
+    pub fn list_invitations_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+    ) -> ReposListInvitationsRequest<'a> {
+        ReposListInvitationsRequest {
+            inner: self.inner.list_invitations_request(owner, repo),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::repos::Repos::list_all_invitations`].
+    pub fn list_all_invitations(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::RepositoryInvitation>>> {
+        self.runtime
+            .block_on(self.inner.list_all_invitations(owner, repo))
+    }
+    /// The blocking version of [`crate::repos::Repos::delete_invitation`].
+    pub fn delete_invitation(
+        &self,
+        owner: &str,
+        repo: &str,
+        invitation_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete_invitation(owner, repo, invitation_id))
+    }
+    /// The blocking version of [`crate::repos::Repos::update_invitation`].
+    pub fn update_invitation(
+        &self,
+        owner: &str,
+        repo: &str,
+        invitation_id: i64,
+        body: &crate::types::ReposUpdateInvitationRequest,
+    ) -> ClientResult<crate::Response<crate::types::RepositoryInvitation>> {
+        self.runtime.block_on(
+            self.inner
+                .update_invitation(owner, repo, invitation_id, body),
+        )
+    }
+    /// The blocking version of [`crate::repos::Repos::list_deploy_keys`].
+    pub fn list_deploy_keys(
+        &self,
+        owner: &str,
+        repo: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::DeployKey>>> {
+        self.runtime
+            .block_on(self.inner.list_deploy_keys(owner, repo, per_page, page))
+    }
+    /// The blocking version of [`crate::repos::Repos::list_deploy_keys_request`].
+    pub fn list_deploy_keys_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+    ) -> ReposListDeployKeysRequest<'a> {
+        ReposListDeployKeysRequest {
+            inner: self.inner.list_deploy_keys_request(owner, repo),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::repos::Repos::list_all_deploy_keys`].
+    pub fn list_all_deploy_keys(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::DeployKey>>> {
+        self.runtime
+            .block_on(self.inner.list_all_deploy_keys(owner, repo))
+    }
+    /// The blocking version of [`crate::repos::Repos::create_deploy_key`].
+    pub fn create_deploy_key(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::ReposCreateDeployKeyRequest,
+    ) -> ClientResult<crate::Response<crate::types::DeployKey>> {
+        self.runtime
+            .block_on(self.inner.create_deploy_key(owner, repo, body))
+    }
+    /// The blocking version of [`crate::repos::Repos::get_deploy_key`].
+    pub fn get_deploy_key(
+        &self,
+        owner: &str,
+        repo: &str,
+        key_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::DeployKey>> {
+        self.runtime
+            .block_on(self.inner.get_deploy_key(owner, repo, key_id))
+    }
+    /// The blocking version of [`crate::repos::Repos::delete_deploy_key`].
+    pub fn delete_deploy_key(
+        &self,
+        owner: &str,
+        repo: &str,
+        key_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete_deploy_key(owner, repo, key_id))
+    }
+    /// The blocking version of [`crate::repos::Repos::list_languages`].
+    pub fn list_languages(&self, owner: &str, repo: &str) -> ClientResult<crate::Response<i64>> {
+        self.runtime
+            .block_on(self.inner.list_languages(owner, repo))
+    }
+    /// The blocking version of [`crate::repos::Repos::merge`].
+    pub fn merge(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::ReposMergeRequest,
+    ) -> ClientResult<crate::Response<crate::types::CommitDataType>> {
+        self.runtime.block_on(self.inner.merge(owner, repo, body))
+    }
+    /// The blocking version of [`crate::repos::Repos::get_pages`].
+    pub fn get_pages(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> ClientResult<crate::Response<crate::types::Page>> {
+        self.runtime.block_on(self.inner.get_pages(owner, repo))
+    }
+    /// The blocking version of [`crate::repos::Repos::update_information_about_pages_site`].
+    pub fn update_information_about_pages_site(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::ReposUpdateInformationAboutPagesSiteRequest,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .update_information_about_pages_site(owner, repo, body),
+        )
+    }
+    /// The blocking version of [`crate::repos::Repos::create_pages_site`].
+    pub fn create_pages_site(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::ReposCreatePagesSiteRequest,
+    ) -> ClientResult<crate::Response<crate::types::Page>> {
+        self.runtime
+            .block_on(self.inner.create_pages_site(owner, repo, body))
+    }
+    /// The blocking version of [`crate::repos::Repos::delete_pages_site`].
+    pub fn delete_pages_site(&self, owner: &str, repo: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete_pages_site(owner, repo))
+    }
+    /// The blocking version of [`crate::repos::Repos::list_pages_builds`].
+    pub fn list_pages_builds(
+        &self,
+        owner: &str,
+        repo: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::PageBuild>>> {
+        self.runtime
+            .block_on(self.inner.list_pages_builds(owner, repo, per_page, page))
+    }
+    /// The blocking version of [`crate::repos::Repos::list_pages_builds_request`].
+    pub fn list_pages_builds_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+    ) -> ReposListPagesBuildsRequest<'a> {
+        ReposListPagesBuildsRequest {
+            inner: self.inner.list_pages_builds_request(owner, repo),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::repos::Repos::list_all_pages_builds`].
+    pub fn list_all_pages_builds(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::PageBuild>>> {
+        self.runtime
+            .block_on(self.inner.list_all_pages_builds(owner, repo))
+    }
+    /// The blocking version of [`crate::repos::Repos::request_pages_build`].
+    pub fn request_pages_build(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> ClientResult<crate::Response<crate::types::PageBuildStatus>> {
+        self.runtime
+            .block_on(self.inner.request_pages_build(owner, repo))
+    }
+    /// The blocking version of [`crate::repos::Repos::get_latest_pages_build`].
+    pub fn get_latest_pages_build(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> ClientResult<crate::Response<crate::types::PageBuild>> {
+        self.runtime
+            .block_on(self.inner.get_latest_pages_build(owner, repo))
+    }
+    /// The blocking version of [`crate::repos::Repos::get_pages_build`].
+    pub fn get_pages_build(
+        &self,
+        owner: &str,
+        repo: &str,
+        build_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::PageBuild>> {
+        self.runtime
+            .block_on(self.inner.get_pages_build(owner, repo, build_id))
+    }
+    /// The blocking version of [`crate::repos::Repos::get_pages_health_check`].
+    pub fn get_pages_health_check(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> ClientResult<crate::Response<crate::types::PagesHealthCheck>> {
+        self.runtime
+            .block_on(self.inner.get_pages_health_check(owner, repo))
+    }
+    /// The blocking version of [`crate::repos::Repos::get_readme`].
+    pub fn get_readme(
+        &self,
+        owner: &str,
+        repo: &str,
+        ref_: &str,
+    ) -> ClientResult<crate::Response<crate::types::ContentFile>> {
+        self.runtime
+            .block_on(self.inner.get_readme(owner, repo, ref_))
+    }
+    /// The blocking version of [`crate::repos::Repos::get_readme_request`].
+    pub fn get_readme_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+    ) -> ReposGetReadmeRequest<'a> {
+        ReposGetReadmeRequest {
+            inner: self.inner.get_readme_request(owner, repo),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::repos::Repos::get_readme_in_directory`].
+    pub fn get_readme_in_directory(
+        &self,
+        owner: &str,
+        repo: &str,
+        dir: &str,
+        ref_: &str,
+    ) -> ClientResult<crate::Response<crate::types::ContentFile>> {
+        self.runtime
+            .block_on(self.inner.get_readme_in_directory(owner, repo, dir, ref_))
+    }
+    /// The blocking version of [`crate::repos::Repos::get_readme_in_directory_request`].
+    pub fn get_readme_in_directory_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+        dir: &'a str,
+    ) -> ReposGetReadmeInDirectoryRequest<'a> {
+        ReposGetReadmeInDirectoryRequest {
+            inner: self.inner.get_readme_in_directory_request(owner, repo, dir),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::repos::Repos::list_releases`].
+    pub fn list_releases(
+        &self,
+        owner: &str,
+        repo: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Release>>> {
+        self.runtime
+            .block_on(self.inner.list_releases(owner, repo, per_page, page))
+    }
+    /// The blocking version of [`crate::repos::Repos::list_releases_request`].
+    pub fn list_releases_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+    ) -> ReposListReleasesRequest<'a> {
+        ReposListReleasesRequest {
+            inner: self.inner.list_releases_request(owner, repo),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::repos::Repos::list_all_releases`].
+    pub fn list_all_releases(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Release>>> {
+        self.runtime
+            .block_on(self.inner.list_all_releases(owner, repo))
+    }
+    /// The blocking version of [`crate::repos::Repos::create_release`].
+    pub fn create_release(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::ReposCreateReleaseRequest,
+    ) -> ClientResult<crate::Response<crate::types::Release>> {
+        self.runtime
+            .block_on(self.inner.create_release(owner, repo, body))
+    }
+    /// The blocking version of [`crate::repos::Repos::get_release_asset`].
+    pub fn get_release_asset(
+        &self,
+        owner: &str,
+        repo: &str,
+        asset_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::ReleaseAsset>> {
+        self.runtime
+            .block_on(self.inner.get_release_asset(owner, repo, asset_id))
+    }
+    /// The blocking version of [`crate::repos::Repos::delete_release_asset`].
+    pub fn delete_release_asset(
+        &self,
+        owner: &str,
+        repo: &str,
+        asset_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete_release_asset(owner, repo, asset_id))
+    }
+    /// The blocking version of [`crate::repos::Repos::update_release_asset`].
+    pub fn update_release_asset(
+        &self,
+        owner: &str,
+        repo: &str,
+        asset_id: i64,
+        body: &crate::types::ReposUpdateReleaseAssetRequest,
+    ) -> ClientResult<crate::Response<crate::types::ReleaseAsset>> {
+        self.runtime
+            .block_on(self.inner.update_release_asset(owner, repo, asset_id, body))
+    }
+    /// The blocking version of [`crate::repos::Repos::get_latest_release`].
+    pub fn get_latest_release(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> ClientResult<crate::Response<crate::types::Release>> {
+        self.runtime
+            .block_on(self.inner.get_latest_release(owner, repo))
+    }
+    /// The blocking version of [`crate::repos::Repos::get_release_by_tag`].
+    pub fn get_release_by_tag(
+        &self,
+        owner: &str,
+        repo: &str,
+        tag: &str,
+    ) -> ClientResult<crate::Response<crate::types::Release>> {
+        self.runtime
+            .block_on(self.inner.get_release_by_tag(owner, repo, tag))
+    }
+    /// The blocking version of [`crate::repos::Repos::get_release`].
+    pub fn get_release(
+        &self,
+        owner: &str,
+        repo: &str,
+        release_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::Release>> {
+        self.runtime
+            .block_on(self.inner.get_release(owner, repo, release_id))
+    }
+    /// The blocking version of [`crate::repos::Repos::delete_release`].
+    pub fn delete_release(
+        &self,
+        owner: &str,
+        repo: &str,
+        release_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete_release(owner, repo, release_id))
+    }
+    /// The blocking version of [`crate::repos::Repos::update_release`].
+    pub fn update_release(
+        &self,
+        owner: &str,
+        repo: &str,
+        release_id: i64,
+        body: &crate::types::ReposCreateReleaseRequest,
+    ) -> ClientResult<crate::Response<crate::types::Release>> {
+        self.runtime
+            .block_on(self.inner.update_release(owner, repo, release_id, body))
+    }
+    /// The blocking version of [`crate::repos::Repos::list_release_assets`].
+    pub fn list_release_assets(
+        &self,
+        owner: &str,
+        repo: &str,
+        release_id: i64,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ReleaseAsset>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_release_assets(owner, repo, release_id, per_page, page),
+        )
+    }
+    /// The blocking version of [`crate::repos::Repos::list_release_assets_request`].
+    pub fn list_release_assets_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+        release_id: i64,
+    ) -> ReposListReleaseAssetsRequest<'a> {
+        ReposListReleaseAssetsRequest {
+            inner: self
+                .inner
+                .list_release_assets_request(owner, repo, release_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::repos::Repos::list_all_release_assets`].
+    pub fn list_all_release_assets(
+        &self,
+        owner: &str,
+        repo: &str,
+        release_id: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ReleaseAsset>>> {
+        self.runtime
+            .block_on(self.inner.list_all_release_assets(owner, repo, release_id))
+    }
+    /// The blocking version of [`crate::repos::Repos::upload_release_asset`].
+    pub fn upload_release_asset<B: Into<reqwest::Body>>(
+        &self,
+        owner: &str,
+        repo: &str,
+        release_id: i64,
+        name: &str,
+        label: &str,
+        body: B,
+    ) -> ClientResult<crate::Response<crate::types::ReleaseAsset>> {
+        self.runtime.block_on(
+            self.inner
+                .upload_release_asset::<B>(owner, repo, release_id, name, label, body),
+        )
+    }
+    /// The blocking version of [`crate::repos::Repos::get_code_frequency_stats`].
+    pub fn get_code_frequency_stats(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> ClientResult<crate::Response<Vec<Vec<i64>>>> {
+        self.runtime
+            .block_on(self.inner.get_code_frequency_stats(owner, repo))
+    }
+    /// The blocking version of [`crate::repos::Repos::get_all_code_frequency_stats`].
+    pub fn get_all_code_frequency_stats(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> ClientResult<crate::Response<Vec<Vec<i64>>>> {
+        self.runtime
+            .block_on(self.inner.get_all_code_frequency_stats(owner, repo))
+    }
+    /// The blocking version of [`crate::repos::Repos::get_commit_activity_stats`].
+    pub fn get_commit_activity_stats(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::CommitActivity>>> {
+        self.runtime
+            .block_on(self.inner.get_commit_activity_stats(owner, repo))
+    }
+    /// The blocking version of [`crate::repos::Repos::get_all_commit_activity_stats`].
+    pub fn get_all_commit_activity_stats(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::CommitActivity>>> {
+        self.runtime
+            .block_on(self.inner.get_all_commit_activity_stats(owner, repo))
+    }
+    /// The blocking version of [`crate::repos::Repos::get_contributors_stats`].
+    pub fn get_contributors_stats(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ContributorActivity>>> {
+        self.runtime
+            .block_on(self.inner.get_contributors_stats(owner, repo))
+    }
+    /// The blocking version of [`crate::repos::Repos::get_all_contributors_stats`].
+    pub fn get_all_contributors_stats(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ContributorActivity>>> {
+        self.runtime
+            .block_on(self.inner.get_all_contributors_stats(owner, repo))
+    }
+    /// The blocking version of [`crate::repos::Repos::get_participation_stats`].
+    pub fn get_participation_stats(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> ClientResult<crate::Response<crate::types::ParticipationStats>> {
+        self.runtime
+            .block_on(self.inner.get_participation_stats(owner, repo))
+    }
+    /// The blocking version of [`crate::repos::Repos::get_punch_card_stats`].
+    pub fn get_punch_card_stats(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> ClientResult<crate::Response<Vec<Vec<i64>>>> {
+        self.runtime
+            .block_on(self.inner.get_punch_card_stats(owner, repo))
+    }
+    /// The blocking version of [`crate::repos::Repos::get_all_punch_card_stats`].
+    pub fn get_all_punch_card_stats(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> ClientResult<crate::Response<Vec<Vec<i64>>>> {
+        self.runtime
+            .block_on(self.inner.get_all_punch_card_stats(owner, repo))
+    }
+    /// The blocking version of [`crate::repos::Repos::create_commit_status`].
+    pub fn create_commit_status(
+        &self,
+        owner: &str,
+        repo: &str,
+        sha: &str,
+        body: &crate::types::ReposCreateCommitStatusRequest,
+    ) -> ClientResult<crate::Response<crate::types::StatusData>> {
+        self.runtime
+            .block_on(self.inner.create_commit_status(owner, repo, sha, body))
+    }
+    /// The blocking version of [`crate::repos::Repos::list_tags`].
+    pub fn list_tags(
+        &self,
+        owner: &str,
+        repo: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Tag>>> {
+        self.runtime
+            .block_on(self.inner.list_tags(owner, repo, per_page, page))
+    }
+    /// The blocking version of [`crate::repos::Repos::list_tags_request`].
+    pub fn list_tags_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+    ) -> ReposListTagsRequest<'a> {
+        ReposListTagsRequest {
+            inner: self.inner.list_tags_request(owner, repo),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::repos::Repos::list_all_tags`].
+    pub fn list_all_tags(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Tag>>> {
+        self.runtime.block_on(self.inner.list_all_tags(owner, repo))
+    }
+    /// The blocking version of [`crate::repos::Repos::download_tarball_archive`].
+    pub fn download_tarball_archive(
+        &self,
+        owner: &str,
+        repo: &str,
+        ref_: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.download_tarball_archive(owner, repo, ref_))
+    }
+    /// The blocking version of [`crate::repos::Repos::list_teams`].
+    pub fn list_teams(
+        &self,
+        owner: &str,
+        repo: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Team>>> {
+        self.runtime
+            .block_on(self.inner.list_teams(owner, repo, per_page, page))
+    }
+    /// The blocking version of [`crate::repos::Repos::list_teams_request`].
+    pub fn list_teams_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+    ) -> ReposListTeamsRequest<'a> {
+        ReposListTeamsRequest {
+            inner: self.inner.list_teams_request(owner, repo),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::repos::Repos::list_all_teams`].
+    pub fn list_all_teams(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Team>>> {
+        self.runtime
+            .block_on(self.inner.list_all_teams(owner, repo))
+    }
+    /// The blocking version of [`crate::repos::Repos::get_all_topics`].
+    pub fn get_all_topics(
+        &self,
+        owner: &str,
+        repo: &str,
+        page: i64,
+        per_page: i64,
+    ) -> ClientResult<crate::Response<crate::types::Topic>> {
+        self.runtime
+            .block_on(self.inner.get_all_topics(owner, repo, page, per_page))
+    }
+    /// The blocking version of [`crate::repos::Repos::get_all_topics_request`].
+    pub fn get_all_topics_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+    ) -> ReposGetAllTopicsRequest<'a> {
+        ReposGetAllTopicsRequest {
+            inner: self.inner.get_all_topics_request(owner, repo),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::repos::Repos::replace_all_topics`].
+    pub fn replace_all_topics(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::Topic,
+    ) -> ClientResult<crate::Response<crate::types::Topic>> {
+        self.runtime
+            .block_on(self.inner.replace_all_topics(owner, repo, body))
+    }
+    /// The blocking version of [`crate::repos::Repos::get_clones`].
+    pub fn get_clones(
+        &self,
+        owner: &str,
+        repo: &str,
+        per: crate::types::Per,
+    ) -> ClientResult<crate::Response<crate::types::CloneTraffic>> {
+        self.runtime
+            .block_on(self.inner.get_clones(owner, repo, per))
+    }
+    /// The blocking version of [`crate::repos::Repos::get_clones_request`].
+    pub fn get_clones_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+    ) -> ReposGetClonesRequest<'a> {
+        ReposGetClonesRequest {
+            inner: self.inner.get_clones_request(owner, repo),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::repos::Repos::get_top_paths`].
+    pub fn get_top_paths(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ContentTraffic>>> {
+        self.runtime.block_on(self.inner.get_top_paths(owner, repo))
+    }
+    /// The blocking version of [`crate::repos::Repos::get_all_top_paths`].
+    pub fn get_all_top_paths(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ContentTraffic>>> {
+        self.runtime
+            .block_on(self.inner.get_all_top_paths(owner, repo))
+    }
+    /// The blocking version of [`crate::repos::Repos::get_top_referrers`].
+    pub fn get_top_referrers(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ReferrerTraffic>>> {
+        self.runtime
+            .block_on(self.inner.get_top_referrers(owner, repo))
+    }
+    /// The blocking version of [`crate::repos::Repos::get_all_top_referrers`].
+    pub fn get_all_top_referrers(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ReferrerTraffic>>> {
+        self.runtime
+            .block_on(self.inner.get_all_top_referrers(owner, repo))
+    }
+    /// The blocking version of [`crate::repos::Repos::get_views`].
+    pub fn get_views(
+        &self,
+        owner: &str,
+        repo: &str,
+        per: crate::types::Per,
+    ) -> ClientResult<crate::Response<crate::types::ViewTraffic>> {
+        self.runtime
+            .block_on(self.inner.get_views(owner, repo, per))
+    }
+    /// The blocking version of [`crate::repos::Repos::get_views_request`].
+    pub fn get_views_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+    ) -> ReposGetViewsRequest<'a> {
+        ReposGetViewsRequest {
+            inner: self.inner.get_views_request(owner, repo),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::repos::Repos::transfer`].
+    pub fn transfer(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::ReposTransferRequest,
+    ) -> ClientResult<crate::Response<crate::types::MinimalRepository>> {
+        self.runtime
+            .block_on(self.inner.transfer(owner, repo, body))
+    }
+    /// The blocking version of [`crate::repos::Repos::check_vulnerability_alerts`].
+    pub fn check_vulnerability_alerts(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.check_vulnerability_alerts(owner, repo))
+    }
+    /// The blocking version of [`crate::repos::Repos::enable_vulnerability_alerts`].
+    pub fn enable_vulnerability_alerts(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.enable_vulnerability_alerts(owner, repo))
+    }
+    /// The blocking version of [`crate::repos::Repos::disable_vulnerability_alerts`].
+    pub fn disable_vulnerability_alerts(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.disable_vulnerability_alerts(owner, repo))
+    }
+    /// The blocking version of [`crate::repos::Repos::download_zipball_archive`].
+    pub fn download_zipball_archive(
+        &self,
+        owner: &str,
+        repo: &str,
+        ref_: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.download_zipball_archive(owner, repo, ref_))
+    }
+    /// The blocking version of [`crate::repos::Repos::create_using_template`].
+    pub fn create_using_template(
+        &self,
+        template_owner: &str,
+        template_repo: &str,
+        body: &crate::types::ReposCreateUsingTemplateRequest,
+    ) -> ClientResult<crate::Response<crate::types::Repository>> {
+        self.runtime.block_on(
+            self.inner
+                .create_using_template(template_owner, template_repo, body),
+        )
+    }
+    /// The blocking version of [`crate::repos::Repos::list_public`].
+    pub fn list_public(
+        &self,
+        since: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::MinimalRepository>>> {
+        self.runtime.block_on(self.inner.list_public(since))
+    }
+    /// The blocking version of [`crate::repos::Repos::list_public_request`].
+    pub fn list_public_request(&self) -> ReposListPublicRequest<'_> {
+        ReposListPublicRequest {
+            inner: self.inner.list_public_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::repos::Repos::list_all_public`].
+    pub fn list_all_public(
+        &self,
+        since: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::MinimalRepository>>> {
+        self.runtime.block_on(self.inner.list_all_public(since))
+    }
+    /// The blocking version of [`crate::repos::Repos::list_for_authenticated_user`].
+    pub fn list_for_authenticated_user(
+        &self,
+        visibility: crate::types::ReposListVisibility,
+        affiliation: &str,
+        type_: crate::types::ReposListType,
+        sort: crate::types::ReposListOrgSort,
+        direction: crate::types::Order,
+        per_page: i64,
+        page: i64,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+        before: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Repository>>> {
+        self.runtime
+            .block_on(self.inner.list_for_authenticated_user(
+                visibility,
+                affiliation,
+                type_,
+                sort,
+                direction,
+                per_page,
+                page,
+                since,
+                before,
+            ))
+    }
+    /// The blocking version of [`crate::repos::Repos::list_for_authenticated_user_request`].
+    pub fn list_for_authenticated_user_request(&self) -> ReposListForAuthenticatedUserRequest<'_> {
+        ReposListForAuthenticatedUserRequest {
+            inner: self.inner.list_for_authenticated_user_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::repos::Repos::list_all_for_authenticated_user`].
+    pub fn list_all_for_authenticated_user(
+        &self,
+        visibility: crate::types::ReposListVisibility,
+        affiliation: &str,
+        type_: crate::types::ReposListType,
+        sort: crate::types::ReposListOrgSort,
+        direction: crate::types::Order,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+        before: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Repository>>> {
+        self.runtime
+            .block_on(self.inner.list_all_for_authenticated_user(
+                visibility,
+                affiliation,
+                type_,
+                sort,
+                direction,
+                since,
+                before,
+            ))
+    }
+    /// The blocking version of [`crate::repos::Repos::create_for_authenticated_user`].
+    pub fn create_for_authenticated_user(
+        &self,
+        body: &crate::types::ReposCreateRequest,
+    ) -> ClientResult<crate::Response<crate::types::Repository>> {
+        self.runtime
+            .block_on(self.inner.create_for_authenticated_user(body))
+    }
+    /// The blocking version of [`crate::repos::Repos::list_invitations_for_authenticated_user`].
+    pub fn list_invitations_for_authenticated_user(
+        &self,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::RepositoryInvitation>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_invitations_for_authenticated_user(per_page, page),
+        )
+    }
+    /// The blocking version of [`crate::repos::Repos::list_invitations_for_authenticated_user_request`].
+    pub fn list_invitations_for_authenticated_user_request(
+        &self,
+    ) -> ReposListInvitationsForAuthenticatedUserRequest<'_> {
+        ReposListInvitationsForAuthenticatedUserRequest {
+            inner: self.inner.list_invitations_for_authenticated_user_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::repos::Repos::list_all_invitations_for_authenticated_user`].
+    pub fn list_all_invitations_for_authenticated_user(
+        &self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::RepositoryInvitation>>> {
+        self.runtime
+            .block_on(self.inner.list_all_invitations_for_authenticated_user())
+    }
+    /// The blocking version of [`crate::repos::Repos::decline_invitation`].
+    pub fn decline_invitation(&self, invitation_id: i64) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.decline_invitation(invitation_id))
+    }
+    /// The blocking version of [`crate::repos::Repos::accept_invitation`].
+    pub fn accept_invitation(&self, invitation_id: i64) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.accept_invitation(invitation_id))
+    }
+    /// The blocking version of [`crate::repos::Repos::list_for_user`].
+    pub fn list_for_user(
+        &self,
+        username: &str,
+        type_: crate::types::ReposListUserType,
+        sort: crate::types::ReposListOrgSort,
+        direction: crate::types::Order,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::MinimalRepository>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_for_user(username, type_, sort, direction, per_page, page),
+        )
+    }
+    /// The blocking version of [`crate::repos::Repos::list_for_user_request`].
+    pub fn list_for_user_request<'a>(&'a self, username: &'a str) -> ReposListForUserRequest<'a> {
+        ReposListForUserRequest {
+            inner: self.inner.list_for_user_request(username),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::repos::Repos::list_all_for_user`].
+    pub fn list_all_for_user(
+        &self,
+        username: &str,
+        type_: crate::types::ReposListUserType,
+        sort: crate::types::ReposListOrgSort,
+        direction: crate::types::Order,
+    ) -> ClientResult<crate::Response<Vec<crate::types::MinimalRepository>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_all_for_user(username, type_, sort, direction),
+        )
+    }
+}
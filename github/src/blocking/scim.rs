@@ -0,0 +1,125 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Scim::list_provisioned_identities`], set with a builder.
+pub struct ScimListProvisionedIdentitiesRequest<'a> {
+    inner: crate::scim::ScimListProvisionedIdentitiesRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ScimListProvisionedIdentitiesRequest<'a> {
+    /// Sets the `startIndex` query parameter.
+    pub fn start_index(mut self, start_index: i64) -> Self {
+        self.inner = self.inner.start_index(start_index);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.inner = self.inner.count(count);
+        self
+    }
+
+    /// Sets the `filter` query parameter.
+    pub fn filter(mut self, filter: &'a str) -> Self {
+        self.inner = self.inner.filter(filter);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::ScimUserList>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::scim::Scim`].
+pub struct Scim {
+    inner: crate::scim::Scim,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Scim {
+    pub(crate) fn new(inner: crate::scim::Scim, runtime: Arc<tokio::runtime::Runtime>) -> Self {
+        Scim { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::scim::Scim::list_provisioned_identities`].
+    pub fn list_provisioned_identities(
+        &self,
+        org: &str,
+        start_index: i64,
+        count: i64,
+        filter: &str,
+    ) -> ClientResult<crate::Response<crate::types::ScimUserList>> {
+        self.runtime
+            .block_on(
+                self.inner
+                    .list_provisioned_identities(org, start_index, count, filter),
+            )
+    }
+    /// The blocking version of [`crate::scim::Scim::list_provisioned_identities_request`].
+    pub fn list_provisioned_identities_request<'a>(
+        &'a self,
+        org: &'a str,
+    ) -> ScimListProvisionedIdentitiesRequest<'a> {
+        ScimListProvisionedIdentitiesRequest {
+            inner: self.inner.list_provisioned_identities_request(org),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::scim::Scim::provision_and_invite_user`].
+    pub fn provision_and_invite_user(
+        &self,
+        org: &str,
+        body: &crate::types::ScimProvisionInviteUserRequest,
+    ) -> ClientResult<crate::Response<crate::types::ScimUser>> {
+        self.runtime
+            .block_on(self.inner.provision_and_invite_user(org, body))
+    }
+    /// The blocking version of [`crate::scim::Scim::get_provisioning_information_for_user`].
+    pub fn get_provisioning_information_for_user(
+        &self,
+        org: &str,
+        scim_user_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ScimUser>> {
+        self.runtime.block_on(
+            self.inner
+                .get_provisioning_information_for_user(org, scim_user_id),
+        )
+    }
+    /// The blocking version of [`crate::scim::Scim::set_information_for_provisioned_user`].
+    pub fn set_information_for_provisioned_user(
+        &self,
+        org: &str,
+        scim_user_id: &str,
+        body: &crate::types::ScimProvisionInviteUserRequest,
+    ) -> ClientResult<crate::Response<crate::types::ScimUser>> {
+        self.runtime
+            .block_on(
+                self.inner
+                    .set_information_for_provisioned_user(org, scim_user_id, body),
+            )
+    }
+    /// The blocking version of [`crate::scim::Scim::delete_user_from_org`].
+    pub fn delete_user_from_org(
+        &self,
+        org: &str,
+        scim_user_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete_user_from_org(org, scim_user_id))
+    }
+    /// The blocking version of [`crate::scim::Scim::update_attribute_for_user`].
+    pub fn update_attribute_for_user(
+        &self,
+        org: &str,
+        scim_user_id: &str,
+        body: &crate::types::ScimUpdateAttributeUserRequest,
+    ) -> ClientResult<crate::Response<crate::types::ScimUser>> {
+        self.runtime.block_on(
+            self.inner
+                .update_attribute_for_user(org, scim_user_id, body),
+        )
+    }
+}
@@ -0,0 +1,403 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Search::code`], set with a builder.
+pub struct SearchCodeRequest<'a> {
+    inner: crate::search::SearchCodeRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> SearchCodeRequest<'a> {
+    /// Sets the `sort` query parameter.
+    pub fn sort(mut self, sort: crate::types::SearchCodeSort) -> Self {
+        self.inner = self.inner.sort(sort);
+        self
+    }
+
+    /// Sets the `order` query parameter.
+    pub fn order(mut self, order: crate::types::Order) -> Self {
+        self.inner = self.inner.order(order);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::SearchCodeResponse>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Search::commits`], set with a builder.
+pub struct SearchCommitsRequest<'a> {
+    inner: crate::search::SearchCommitsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> SearchCommitsRequest<'a> {
+    /// Sets the `sort` query parameter.
+    pub fn sort(mut self, sort: crate::types::SearchCommitsSort) -> Self {
+        self.inner = self.inner.sort(sort);
+        self
+    }
+
+    /// Sets the `order` query parameter.
+    pub fn order(mut self, order: crate::types::Order) -> Self {
+        self.inner = self.inner.order(order);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::SearchCommitsResponse>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Search::issues_and_pull_requests`], set with a builder.
+pub struct SearchIssuesAndPullRequestsRequest<'a> {
+    inner: crate::search::SearchIssuesAndPullRequestsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> SearchIssuesAndPullRequestsRequest<'a> {
+    /// Sets the `sort` query parameter.
+    pub fn sort(mut self, sort: crate::types::SearchIssuesPullRequestsSort) -> Self {
+        self.inner = self.inner.sort(sort);
+        self
+    }
+
+    /// Sets the `order` query parameter.
+    pub fn order(mut self, order: crate::types::Order) -> Self {
+        self.inner = self.inner.order(order);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::SearchIssuesPullRequestsResponse>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Search::labels`], set with a builder.
+pub struct SearchLabelsRequest<'a> {
+    inner: crate::search::SearchLabelsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> SearchLabelsRequest<'a> {
+    /// Sets the `sort` query parameter.
+    pub fn sort(mut self, sort: crate::types::Sort) -> Self {
+        self.inner = self.inner.sort(sort);
+        self
+    }
+
+    /// Sets the `order` query parameter.
+    pub fn order(mut self, order: crate::types::Order) -> Self {
+        self.inner = self.inner.order(order);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::SearchLabelsResponse>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Search::repos`], set with a builder.
+pub struct SearchReposRequest<'a> {
+    inner: crate::search::SearchReposRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> SearchReposRequest<'a> {
+    /// Sets the `sort` query parameter.
+    pub fn sort(mut self, sort: crate::types::SearchReposSort) -> Self {
+        self.inner = self.inner.sort(sort);
+        self
+    }
+
+    /// Sets the `order` query parameter.
+    pub fn order(mut self, order: crate::types::Order) -> Self {
+        self.inner = self.inner.order(order);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::SearchReposResponse>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Search::topics`], set with a builder.
+pub struct SearchTopicsRequest<'a> {
+    inner: crate::search::SearchTopicsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> SearchTopicsRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::SearchTopicsResponse>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Search::users`], set with a builder.
+pub struct SearchUsersRequest<'a> {
+    inner: crate::search::SearchUsersRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> SearchUsersRequest<'a> {
+    /// Sets the `sort` query parameter.
+    pub fn sort(mut self, sort: crate::types::SearchUsersSort) -> Self {
+        self.inner = self.inner.sort(sort);
+        self
+    }
+
+    /// Sets the `order` query parameter.
+    pub fn order(mut self, order: crate::types::Order) -> Self {
+        self.inner = self.inner.order(order);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::SearchUsersResponse>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::search::Search`].
+pub struct Search {
+    inner: crate::search::Search,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Search {
+    pub(crate) fn new(inner: crate::search::Search, runtime: Arc<tokio::runtime::Runtime>) -> Self {
+        Search { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::search::Search::code`].
+    pub fn code(
+        &self,
+        q: &str,
+        sort: crate::types::SearchCodeSort,
+        order: crate::types::Order,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<crate::types::SearchCodeResponse>> {
+        self.runtime
+            .block_on(self.inner.code(q, sort, order, per_page, page))
+    }
+    /// The blocking version of [`crate::search::Search::code_request`].
+    pub fn code_request<'a>(&'a self, q: &'a str) -> SearchCodeRequest<'a> {
+        SearchCodeRequest {
+            inner: self.inner.code_request(q),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::search::Search::commits`].
+    pub fn commits(
+        &self,
+        q: &str,
+        sort: crate::types::SearchCommitsSort,
+        order: crate::types::Order,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<crate::types::SearchCommitsResponse>> {
+        self.runtime
+            .block_on(self.inner.commits(q, sort, order, per_page, page))
+    }
+    /// The blocking version of [`crate::search::Search::commits_request`].
+    pub fn commits_request<'a>(&'a self, q: &'a str) -> SearchCommitsRequest<'a> {
+        SearchCommitsRequest {
+            inner: self.inner.commits_request(q),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::search::Search::issues_and_pull_requests`].
+    pub fn issues_and_pull_requests(
+        &self,
+        q: &str,
+        sort: crate::types::SearchIssuesPullRequestsSort,
+        order: crate::types::Order,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<crate::types::SearchIssuesPullRequestsResponse>> {
+        self.runtime.block_on(
+            self.inner
+                .issues_and_pull_requests(q, sort, order, per_page, page),
+        )
+    }
+    /// The blocking version of [`crate::search::Search::issues_and_pull_requests_request`].
+    pub fn issues_and_pull_requests_request<'a>(
+        &'a self,
+        q: &'a str,
+    ) -> SearchIssuesAndPullRequestsRequest<'a> {
+        SearchIssuesAndPullRequestsRequest {
+            inner: self.inner.issues_and_pull_requests_request(q),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::search::Search::labels`].
+    pub fn labels(
+        &self,
+        repository_id: i64,
+        q: &str,
+        sort: crate::types::Sort,
+        order: crate::types::Order,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<crate::types::SearchLabelsResponse>> {
+        self.runtime.block_on(
+            self.inner
+                .labels(repository_id, q, sort, order, per_page, page),
+        )
+    }
+    /// The blocking version of [`crate::search::Search::labels_request`].
+    pub fn labels_request<'a>(&'a self, repository_id: i64, q: &'a str) -> SearchLabelsRequest<'a> {
+        SearchLabelsRequest {
+            inner: self.inner.labels_request(repository_id, q),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::search::Search::repos`].
+    pub fn repos(
+        &self,
+        q: &str,
+        sort: crate::types::SearchReposSort,
+        order: crate::types::Order,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<crate::types::SearchReposResponse>> {
+        self.runtime
+            .block_on(self.inner.repos(q, sort, order, per_page, page))
+    }
+    /// The blocking version of [`crate::search::Search::repos_request`].
+    pub fn repos_request<'a>(&'a self, q: &'a str) -> SearchReposRequest<'a> {
+        SearchReposRequest {
+            inner: self.inner.repos_request(q),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::search::Search::topics`].
+    pub fn topics(
+        &self,
+        q: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<crate::types::SearchTopicsResponse>> {
+        self.runtime.block_on(self.inner.topics(q, per_page, page))
+    }
+    /// The blocking version of [`crate::search::Search::topics_request`].
+    pub fn topics_request<'a>(&'a self, q: &'a str) -> SearchTopicsRequest<'a> {
+        SearchTopicsRequest {
+            inner: self.inner.topics_request(q),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::search::Search::users`].
+    pub fn users(
+        &self,
+        q: &str,
+        sort: crate::types::SearchUsersSort,
+        order: crate::types::Order,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<crate::types::SearchUsersResponse>> {
+        self.runtime
+            .block_on(self.inner.users(q, sort, order, per_page, page))
+    }
+    /// The blocking version of [`crate::search::Search::users_request`].
+    pub fn users_request<'a>(&'a self, q: &'a str) -> SearchUsersRequest<'a> {
+        SearchUsersRequest {
+            inner: self.inner.users_request(q),
+            runtime: &self.runtime,
+        }
+    }
+}
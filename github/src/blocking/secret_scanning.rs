@@ -0,0 +1,120 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`SecretScanning::list_alerts_for_repo`], set with a builder.
+pub struct SecretScanningListAlertsForRepoRequest<'a> {
+    inner: crate::secret_scanning::SecretScanningListAlertsForRepoRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> SecretScanningListAlertsForRepoRequest<'a> {
+    /// Sets the `state` query parameter.
+    pub fn state(mut self, state: crate::types::SecretScanningAlertState) -> Self {
+        self.inner = self.inner.state(state);
+        self
+    }
+
+    /// Sets the `secret_type` query parameter.
+    pub fn secret_type(mut self, secret_type: &'a str) -> Self {
+        self.inner = self.inner.secret_type(secret_type);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::SecretScanningAlert>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::secret_scanning::SecretScanning`].
+pub struct SecretScanning {
+    inner: crate::secret_scanning::SecretScanning,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl SecretScanning {
+    pub(crate) fn new(
+        inner: crate::secret_scanning::SecretScanning,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        SecretScanning { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::secret_scanning::SecretScanning::list_alerts_for_repo`].
+    pub fn list_alerts_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+        state: crate::types::SecretScanningAlertState,
+        secret_type: &str,
+        page: i64,
+        per_page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::SecretScanningAlert>>> {
+        self.runtime.block_on(self.inner.list_alerts_for_repo(
+            owner,
+            repo,
+            state,
+            secret_type,
+            page,
+            per_page,
+        ))
+    }
+    /// The blocking version of [`crate::secret_scanning::SecretScanning::list_alerts_for_repo_request`].
+    pub fn list_alerts_for_repo_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+    ) -> SecretScanningListAlertsForRepoRequest<'a> {
+        SecretScanningListAlertsForRepoRequest {
+            inner: self.inner.list_alerts_for_repo_request(owner, repo),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::secret_scanning::SecretScanning::list_all_alerts_for_repo`].
+    pub fn list_all_alerts_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+        state: crate::types::SecretScanningAlertState,
+        secret_type: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::SecretScanningAlert>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_all_alerts_for_repo(owner, repo, state, secret_type),
+        )
+    }
+    /// The blocking version of [`crate::secret_scanning::SecretScanning::get_alert`].
+    pub fn get_alert(
+        &self,
+        owner: &str,
+        repo: &str,
+        alert_number: i64,
+    ) -> ClientResult<crate::Response<crate::types::SecretScanningAlert>> {
+        self.runtime
+            .block_on(self.inner.get_alert(owner, repo, alert_number))
+    }
+    /// The blocking version of [`crate::secret_scanning::SecretScanning::update_alert`].
+    pub fn update_alert(
+        &self,
+        owner: &str,
+        repo: &str,
+        alert_number: i64,
+        body: &crate::types::SecretScanningUpdateAlertRequest,
+    ) -> ClientResult<crate::Response<crate::types::SecretScanningAlert>> {
+        self.runtime
+            .block_on(self.inner.update_alert(owner, repo, alert_number, body))
+    }
+}
@@ -0,0 +1,1557 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Teams::list_idp_groups_for_org`], set with a builder.
+pub struct TeamsListIdpGroupsForOrgRequest<'a> {
+    inner: crate::teams::TeamsListIdpGroupsForOrgRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> TeamsListIdpGroupsForOrgRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: &'a str) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::GroupMapping>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Teams::list`], set with a builder.
+pub struct TeamsListRequest<'a> {
+    inner: crate::teams::TeamsListRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> TeamsListRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Team>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Teams::list_discussions_in_org`], set with a builder.
+pub struct TeamsListDiscussionsInOrgRequest<'a> {
+    inner: crate::teams::TeamsListDiscussionsInOrgRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> TeamsListDiscussionsInOrgRequest<'a> {
+    /// Sets the `direction` query parameter.
+    pub fn direction(mut self, direction: crate::types::Order) -> Self {
+        self.inner = self.inner.direction(direction);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sets the `pinned` query parameter.
+    pub fn pinned(mut self, pinned: &'a str) -> Self {
+        self.inner = self.inner.pinned(pinned);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::TeamDiscussion>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Teams::list_discussion_comments_in_org`], set with a builder.
+pub struct TeamsListDiscussionCommentsInOrgRequest<'a> {
+    inner: crate::teams::TeamsListDiscussionCommentsInOrgRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> TeamsListDiscussionCommentsInOrgRequest<'a> {
+    /// Sets the `direction` query parameter.
+    pub fn direction(mut self, direction: crate::types::Order) -> Self {
+        self.inner = self.inner.direction(direction);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::TeamDiscussionComment>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Teams::list_pending_invitations_in_org`], set with a builder.
+pub struct TeamsListPendingInvitationsInOrgRequest<'a> {
+    inner: crate::teams::TeamsListPendingInvitationsInOrgRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> TeamsListPendingInvitationsInOrgRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::OrganizationInvitation>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Teams::list_members_in_org`], set with a builder.
+pub struct TeamsListMembersInOrgRequest<'a> {
+    inner: crate::teams::TeamsListMembersInOrgRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> TeamsListMembersInOrgRequest<'a> {
+    /// Sets the `role` query parameter.
+    pub fn role(mut self, role: crate::types::TeamsListMembersInOrgRole) -> Self {
+        self.inner = self.inner.role(role);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::SimpleUser>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Teams::list_projects_in_org`], set with a builder.
+pub struct TeamsListProjectsInOrgRequest<'a> {
+    inner: crate::teams::TeamsListProjectsInOrgRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> TeamsListProjectsInOrgRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::TeamProject>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Teams::list_repos_in_org`], set with a builder.
+pub struct TeamsListReposInOrgRequest<'a> {
+    inner: crate::teams::TeamsListReposInOrgRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> TeamsListReposInOrgRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::MinimalRepository>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Teams::list_child_in_org`], set with a builder.
+pub struct TeamsListChildInOrgRequest<'a> {
+    inner: crate::teams::TeamsListChildInOrgRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> TeamsListChildInOrgRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Team>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Teams::list_discussions_legacy`], set with a builder.
+pub struct TeamsListDiscussionsLegacyRequest<'a> {
+    inner: crate::teams::TeamsListDiscussionsLegacyRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> TeamsListDiscussionsLegacyRequest<'a> {
+    /// Sets the `direction` query parameter.
+    pub fn direction(mut self, direction: crate::types::Order) -> Self {
+        self.inner = self.inner.direction(direction);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::TeamDiscussion>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Teams::list_discussion_comments_legacy`], set with a builder.
+pub struct TeamsListDiscussionCommentsLegacyRequest<'a> {
+    inner: crate::teams::TeamsListDiscussionCommentsLegacyRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> TeamsListDiscussionCommentsLegacyRequest<'a> {
+    /// Sets the `direction` query parameter.
+    pub fn direction(mut self, direction: crate::types::Order) -> Self {
+        self.inner = self.inner.direction(direction);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::TeamDiscussionComment>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Teams::list_pending_invitations_legacy`], set with a builder.
+pub struct TeamsListPendingInvitationsLegacyRequest<'a> {
+    inner: crate::teams::TeamsListPendingInvitationsLegacyRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> TeamsListPendingInvitationsLegacyRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::OrganizationInvitation>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Teams::list_members_legacy`], set with a builder.
+pub struct TeamsListMembersLegacyRequest<'a> {
+    inner: crate::teams::TeamsListMembersLegacyRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> TeamsListMembersLegacyRequest<'a> {
+    /// Sets the `role` query parameter.
+    pub fn role(mut self, role: crate::types::TeamsListMembersInOrgRole) -> Self {
+        self.inner = self.inner.role(role);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::SimpleUser>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Teams::list_projects_legacy`], set with a builder.
+pub struct TeamsListProjectsLegacyRequest<'a> {
+    inner: crate::teams::TeamsListProjectsLegacyRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> TeamsListProjectsLegacyRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::TeamProject>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Teams::list_repos_legacy`], set with a builder.
+pub struct TeamsListReposLegacyRequest<'a> {
+    inner: crate::teams::TeamsListReposLegacyRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> TeamsListReposLegacyRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::MinimalRepository>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Teams::list_child_legacy`], set with a builder.
+pub struct TeamsListChildLegacyRequest<'a> {
+    inner: crate::teams::TeamsListChildLegacyRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> TeamsListChildLegacyRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Team>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Teams::list_for_authenticated_user`], set with a builder.
+pub struct TeamsListForAuthenticatedUserRequest<'a> {
+    inner: crate::teams::TeamsListForAuthenticatedUserRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> TeamsListForAuthenticatedUserRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::FullTeam>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::teams::Teams`].
+pub struct Teams {
+    inner: crate::teams::Teams,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Teams {
+    pub(crate) fn new(inner: crate::teams::Teams, runtime: Arc<tokio::runtime::Runtime>) -> Self {
+        Teams { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::teams::Teams::list_idp_groups_for_org`].
+    pub fn list_idp_groups_for_org(
+        &self,
+        org: &str,
+        per_page: i64,
+        page: &str,
+    ) -> ClientResult<crate::Response<crate::types::GroupMapping>> {
+        self.runtime
+            .block_on(self.inner.list_idp_groups_for_org(org, per_page, page))
+    }
+    /// The blocking version of [`crate::teams::Teams::list_idp_groups_for_org_request`].
+    pub fn list_idp_groups_for_org_request<'a>(
+        &'a self,
+        org: &'a str,
+    ) -> TeamsListIdpGroupsForOrgRequest<'a> {
+        TeamsListIdpGroupsForOrgRequest {
+            inner: self.inner.list_idp_groups_for_org_request(org),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::teams::Teams::list`].
+    pub fn list(
+        &self,
+        org: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Team>>> {
+        self.runtime.block_on(self.inner.list(org, per_page, page))
+    }
+    /// The blocking version of [`crate::teams::Teams::list_request`].
+    pub fn list_request<'a>(&'a self, org: &'a str) -> TeamsListRequest<'a> {
+        TeamsListRequest {
+            inner: self.inner.list_request(org),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::teams::Teams::list_all`].
+    pub fn list_all(&self, org: &str) -> ClientResult<crate::Response<Vec<crate::types::Team>>> {
+        self.runtime.block_on(self.inner.list_all(org))
+    }
+    /// The blocking version of [`crate::teams::Teams::create`].
+    pub fn create(
+        &self,
+        org: &str,
+        body: &crate::types::TeamsCreateRequest,
+    ) -> ClientResult<crate::Response<crate::types::FullTeam>> {
+        self.runtime.block_on(self.inner.create(org, body))
+    }
+    /// The blocking version of [`crate::teams::Teams::get_by_name`].
+    pub fn get_by_name(
+        &self,
+        org: &str,
+        team_slug: &str,
+    ) -> ClientResult<crate::Response<crate::types::FullTeam>> {
+        self.runtime
+            .block_on(self.inner.get_by_name(org, team_slug))
+    }
+    /// The blocking version of [`crate::teams::Teams::delete_in_org`].
+    pub fn delete_in_org(&self, org: &str, team_slug: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete_in_org(org, team_slug))
+    }
+    /// The blocking version of [`crate::teams::Teams::update_in_org`].
+    pub fn update_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+        body: &crate::types::TeamsUpdateInOrgRequest,
+    ) -> ClientResult<crate::Response<crate::types::FullTeam>> {
+        self.runtime
+            .block_on(self.inner.update_in_org(org, team_slug, body))
+    }
+    /// The blocking version of [`crate::teams::Teams::list_discussions_in_org`].
+    pub fn list_discussions_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+        direction: crate::types::Order,
+        per_page: i64,
+        page: i64,
+        pinned: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::TeamDiscussion>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_discussions_in_org(org, team_slug, direction, per_page, page, pinned),
+        )
+    }
+    /// The blocking version of [`crate::teams::Teams::list_discussions_in_org_request`].
+    pub fn list_discussions_in_org_request<'a>(
+        &'a self,
+        org: &'a str,
+        team_slug: &'a str,
+    ) -> TeamsListDiscussionsInOrgRequest<'a> {
+        TeamsListDiscussionsInOrgRequest {
+            inner: self.inner.list_discussions_in_org_request(org, team_slug),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::teams::Teams::list_all_discussions_in_org`].
+    pub fn list_all_discussions_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+        direction: crate::types::Order,
+        pinned: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::TeamDiscussion>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_all_discussions_in_org(org, team_slug, direction, pinned),
+        )
+    }
+    /// The blocking version of [`crate::teams::Teams::create_discussion_in_org`].
+    pub fn create_discussion_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+        body: &crate::types::TeamsCreateDiscussionInOrgRequest,
+    ) -> ClientResult<crate::Response<crate::types::TeamDiscussion>> {
+        self.runtime
+            .block_on(self.inner.create_discussion_in_org(org, team_slug, body))
+    }
+    /// The blocking version of [`crate::teams::Teams::get_discussion_in_org`].
+    pub fn get_discussion_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+        discussion_number: i64,
+    ) -> ClientResult<crate::Response<crate::types::TeamDiscussion>> {
+        self.runtime.block_on(
+            self.inner
+                .get_discussion_in_org(org, team_slug, discussion_number),
+        )
+    }
+    /// The blocking version of [`crate::teams::Teams::delete_discussion_in_org`].
+    pub fn delete_discussion_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+        discussion_number: i64,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.delete_discussion_in_org(
+            org,
+            team_slug,
+            discussion_number,
+        ))
+    }
+    /// The blocking version of [`crate::teams::Teams::update_discussion_in_org`].
+    pub fn update_discussion_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+        discussion_number: i64,
+        body: &crate::types::TeamsUpdateDiscussionInOrgRequest,
+    ) -> ClientResult<crate::Response<crate::types::TeamDiscussion>> {
+        self.runtime.block_on(self.inner.update_discussion_in_org(
+            org,
+            team_slug,
+            discussion_number,
+            body,
+        ))
+    }
+    /// The blocking version of [`crate::teams::Teams::list_discussion_comments_in_org`].
+    pub fn list_discussion_comments_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+        discussion_number: i64,
+        direction: crate::types::Order,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::TeamDiscussionComment>>> {
+        self.runtime
+            .block_on(self.inner.list_discussion_comments_in_org(
+                org,
+                team_slug,
+                discussion_number,
+                direction,
+                per_page,
+                page,
+            ))
+    }
+    /// The blocking version of [`crate::teams::Teams::list_discussion_comments_in_org_request`].
+    pub fn list_discussion_comments_in_org_request<'a>(
+        &'a self,
+        org: &'a str,
+        team_slug: &'a str,
+        discussion_number: i64,
+    ) -> TeamsListDiscussionCommentsInOrgRequest<'a> {
+        TeamsListDiscussionCommentsInOrgRequest {
+            inner: self.inner.list_discussion_comments_in_org_request(
+                org,
+                team_slug,
+                discussion_number,
+            ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::teams::Teams::list_all_discussion_comments_in_org`].
+    pub fn list_all_discussion_comments_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+        discussion_number: i64,
+        direction: crate::types::Order,
+    ) -> ClientResult<crate::Response<Vec<crate::types::TeamDiscussionComment>>> {
+        self.runtime
+            .block_on(self.inner.list_all_discussion_comments_in_org(
+                org,
+                team_slug,
+                discussion_number,
+                direction,
+            ))
+    }
+    /// The blocking version of [`crate::teams::Teams::create_discussion_comment_in_org`].
+    pub fn create_discussion_comment_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+        discussion_number: i64,
+        body: &crate::types::PullsUpdateReviewRequest,
+    ) -> ClientResult<crate::Response<crate::types::TeamDiscussionComment>> {
+        self.runtime
+            .block_on(self.inner.create_discussion_comment_in_org(
+                org,
+                team_slug,
+                discussion_number,
+                body,
+            ))
+    }
+    /// The blocking version of [`crate::teams::Teams::get_discussion_comment_in_org`].
+    pub fn get_discussion_comment_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+        discussion_number: i64,
+        comment_number: i64,
+    ) -> ClientResult<crate::Response<crate::types::TeamDiscussionComment>> {
+        self.runtime
+            .block_on(self.inner.get_discussion_comment_in_org(
+                org,
+                team_slug,
+                discussion_number,
+                comment_number,
+            ))
+    }
+    /// The blocking version of [`crate::teams::Teams::delete_discussion_comment_in_org`].
+    pub fn delete_discussion_comment_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+        discussion_number: i64,
+        comment_number: i64,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete_discussion_comment_in_org(
+                org,
+                team_slug,
+                discussion_number,
+                comment_number,
+            ))
+    }
+    /// The blocking version of [`crate::teams::Teams::update_discussion_comment_in_org`].
+    pub fn update_discussion_comment_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+        discussion_number: i64,
+        comment_number: i64,
+        body: &crate::types::PullsUpdateReviewRequest,
+    ) -> ClientResult<crate::Response<crate::types::TeamDiscussionComment>> {
+        self.runtime
+            .block_on(self.inner.update_discussion_comment_in_org(
+                org,
+                team_slug,
+                discussion_number,
+                comment_number,
+                body,
+            ))
+    }
+    /// The blocking version of [`crate::teams::Teams::list_pending_invitations_in_org`].
+    pub fn list_pending_invitations_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::OrganizationInvitation>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_pending_invitations_in_org(org, team_slug, per_page, page),
+        )
+    }
+    /// The blocking version of [`crate::teams::Teams::list_pending_invitations_in_org_request`].
+    pub fn list_pending_invitations_in_org_request<'a>(
+        &'a self,
+        org: &'a str,
+        team_slug: &'a str,
+    ) -> TeamsListPendingInvitationsInOrgRequest<'a> {
+        TeamsListPendingInvitationsInOrgRequest {
+            inner: self
+                .inner
+                .list_pending_invitations_in_org_request(org, team_slug),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::teams::Teams::list_all_pending_invitations_in_org`].
+    pub fn list_all_pending_invitations_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::OrganizationInvitation>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_all_pending_invitations_in_org(org, team_slug),
+        )
+    }
+    /// The blocking version of [`crate::teams::Teams::list_members_in_org`].
+    pub fn list_members_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+        role: crate::types::TeamsListMembersInOrgRole,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::SimpleUser>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_members_in_org(org, team_slug, role, per_page, page),
+        )
+    }
+    /// The blocking version of [`crate::teams::Teams::list_members_in_org_request`].
+    pub fn list_members_in_org_request<'a>(
+        &'a self,
+        org: &'a str,
+        team_slug: &'a str,
+    ) -> TeamsListMembersInOrgRequest<'a> {
+        TeamsListMembersInOrgRequest {
+            inner: self.inner.list_members_in_org_request(org, team_slug),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::teams::Teams::list_all_members_in_org`].
+    pub fn list_all_members_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+        role: crate::types::TeamsListMembersInOrgRole,
+    ) -> ClientResult<crate::Response<Vec<crate::types::SimpleUser>>> {
+        self.runtime
+            .block_on(self.inner.list_all_members_in_org(org, team_slug, role))
+    }
+    /// The blocking version of [`crate::teams::Teams::get_membership_for_user_in_org`].
+    pub fn get_membership_for_user_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+        username: &str,
+    ) -> ClientResult<crate::Response<crate::types::TeamMembership>> {
+        self.runtime.block_on(
+            self.inner
+                .get_membership_for_user_in_org(org, team_slug, username),
+        )
+    }
+    /// The blocking version of [`crate::teams::Teams::add_or_update_membership_for_user_in_org`].
+    pub fn add_or_update_membership_for_user_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+        username: &str,
+        body: &crate::types::TeamsAddUpdateMembershipUserInOrgRequest,
+    ) -> ClientResult<crate::Response<crate::types::TeamMembership>> {
+        self.runtime.block_on(
+            self.inner
+                .add_or_update_membership_for_user_in_org(org, team_slug, username, body),
+        )
+    }
+    /// The blocking version of [`crate::teams::Teams::remove_membership_for_user_in_org`].
+    pub fn remove_membership_for_user_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+        username: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .remove_membership_for_user_in_org(org, team_slug, username),
+        )
+    }
+    /// The blocking version of [`crate::teams::Teams::list_projects_in_org`].
+    pub fn list_projects_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::TeamProject>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_projects_in_org(org, team_slug, per_page, page),
+        )
+    }
+    /// The blocking version of [`crate::teams::Teams::list_projects_in_org_request`].
+    pub fn list_projects_in_org_request<'a>(
+        &'a self,
+        org: &'a str,
+        team_slug: &'a str,
+    ) -> TeamsListProjectsInOrgRequest<'a> {
+        TeamsListProjectsInOrgRequest {
+            inner: self.inner.list_projects_in_org_request(org, team_slug),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::teams::Teams::list_all_projects_in_org`].
+    pub fn list_all_projects_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::TeamProject>>> {
+        self.runtime
+            .block_on(self.inner.list_all_projects_in_org(org, team_slug))
+    }
+    /// The blocking version of [`crate::teams::Teams::check_permissions_for_project_in_org`].
+    pub fn check_permissions_for_project_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+        project_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::TeamProject>> {
+        self.runtime.block_on(
+            self.inner
+                .check_permissions_for_project_in_org(org, team_slug, project_id),
+        )
+    }
+    /// The blocking version of [`crate::teams::Teams::add_or_update_project_permissions_in_org`].
+    pub fn add_or_update_project_permissions_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+        project_id: i64,
+        body: &crate::types::ProjectsAddCollaboratorRequest,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .add_or_update_project_permissions_in_org(org, team_slug, project_id, body),
+        )
+    }
+    /// The blocking version of [`crate::teams::Teams::remove_project_in_org`].
+    pub fn remove_project_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+        project_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.remove_project_in_org(org, team_slug, project_id))
+    }
+    /// The blocking version of [`crate::teams::Teams::list_repos_in_org`].
+    pub fn list_repos_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::MinimalRepository>>> {
+        self.runtime
+            .block_on(self.inner.list_repos_in_org(org, team_slug, per_page, page))
+    }
+    /// The blocking version of [`crate::teams::Teams::list_repos_in_org_request`].
+    pub fn list_repos_in_org_request<'a>(
+        &'a self,
+        org: &'a str,
+        team_slug: &'a str,
+    ) -> TeamsListReposInOrgRequest<'a> {
+        TeamsListReposInOrgRequest {
+            inner: self.inner.list_repos_in_org_request(org, team_slug),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::teams::Teams::list_all_repos_in_org`].
+    pub fn list_all_repos_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::MinimalRepository>>> {
+        self.runtime
+            .block_on(self.inner.list_all_repos_in_org(org, team_slug))
+    }
+    /// The blocking version of [`crate::teams::Teams::check_permissions_for_repo_in_org`].
+    pub fn check_permissions_for_repo_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+        owner: &str,
+        repo: &str,
+    ) -> ClientResult<crate::Response<crate::types::TeamRepository>> {
+        self.runtime.block_on(
+            self.inner
+                .check_permissions_for_repo_in_org(org, team_slug, owner, repo),
+        )
+    }
+    /// The blocking version of [`crate::teams::Teams::add_or_update_repo_permissions_in_org`].
+    pub fn add_or_update_repo_permissions_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::TeamsAddUpdateRepoPermissionsInOrgRequest,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .add_or_update_repo_permissions_in_org(org, team_slug, owner, repo, body),
+        )
+    }
+    /// The blocking version of [`crate::teams::Teams::remove_repo_in_org`].
+    pub fn remove_repo_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+        owner: &str,
+        repo: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.remove_repo_in_org(org, team_slug, owner, repo))
+    }
+    /// The blocking version of [`crate::teams::Teams::list_idp_groups_in_org`].
+    pub fn list_idp_groups_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+    ) -> ClientResult<crate::Response<crate::types::GroupMapping>> {
+        self.runtime
+            .block_on(self.inner.list_idp_groups_in_org(org, team_slug))
+    }
+    /// The blocking version of [`crate::teams::Teams::create_or_update_idp_group_connections_in_org`].
+    pub fn create_or_update_idp_group_connections_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+        body: &crate::types::TeamsCreateUpdateIdpGroupConnectionsInOrgRequest,
+    ) -> ClientResult<crate::Response<crate::types::GroupMapping>> {
+        self.runtime.block_on(
+            self.inner
+                .create_or_update_idp_group_connections_in_org(org, team_slug, body),
+        )
+    }
+    /// The blocking version of [`crate::teams::Teams::list_child_in_org`].
+    pub fn list_child_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Team>>> {
+        self.runtime
+            .block_on(self.inner.list_child_in_org(org, team_slug, per_page, page))
+    }
+    /// The blocking version of [`crate::teams::Teams::list_child_in_org_request`].
+    pub fn list_child_in_org_request<'a>(
+        &'a self,
+        org: &'a str,
+        team_slug: &'a str,
+    ) -> TeamsListChildInOrgRequest<'a> {
+        TeamsListChildInOrgRequest {
+            inner: self.inner.list_child_in_org_request(org, team_slug),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::teams::Teams::list_all_child_in_org`].
+    pub fn list_all_child_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Team>>> {
+        self.runtime
+            .block_on(self.inner.list_all_child_in_org(org, team_slug))
+    }
+    /// The blocking version of [`crate::teams::Teams::get_legacy`].
+    pub fn get_legacy(
+        &self,
+        team_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::FullTeam>> {
+        self.runtime.block_on(self.inner.get_legacy(team_id))
+    }
+    /// The blocking version of [`crate::teams::Teams::delete_legacy`].
+    pub fn delete_legacy(&self, team_id: i64) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.delete_legacy(team_id))
+    }
+    /// The blocking version of [`crate::teams::Teams::update_legacy`].
+    pub fn update_legacy(
+        &self,
+        team_id: i64,
+        body: &crate::types::TeamsUpdateInOrgRequest,
+    ) -> ClientResult<crate::Response<crate::types::FullTeam>> {
+        self.runtime
+            .block_on(self.inner.update_legacy(team_id, body))
+    }
+    /// The blocking version of [`crate::teams::Teams::list_discussions_legacy`].
+    pub fn list_discussions_legacy(
+        &self,
+        team_id: i64,
+        direction: crate::types::Order,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::TeamDiscussion>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_discussions_legacy(team_id, direction, per_page, page),
+        )
+    }
+    /// The blocking version of [`crate::teams::Teams::list_discussions_legacy_request`].
+    pub fn list_discussions_legacy_request(
+        &self,
+        team_id: i64,
+    ) -> TeamsListDiscussionsLegacyRequest<'_> {
+        TeamsListDiscussionsLegacyRequest {
+            inner: self.inner.list_discussions_legacy_request(team_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::teams::Teams::list_all_discussions_legacy`].
+    pub fn list_all_discussions_legacy(
+        &self,
+        team_id: i64,
+        direction: crate::types::Order,
+    ) -> ClientResult<crate::Response<Vec<crate::types::TeamDiscussion>>> {
+        self.runtime
+            .block_on(self.inner.list_all_discussions_legacy(team_id, direction))
+    }
+    /// The blocking version of [`crate::teams::Teams::create_discussion_legacy`].
+    pub fn create_discussion_legacy(
+        &self,
+        team_id: i64,
+        body: &crate::types::TeamsCreateDiscussionInOrgRequest,
+    ) -> ClientResult<crate::Response<crate::types::TeamDiscussion>> {
+        self.runtime
+            .block_on(self.inner.create_discussion_legacy(team_id, body))
+    }
+    /// The blocking version of [`crate::teams::Teams::get_discussion_legacy`].
+    pub fn get_discussion_legacy(
+        &self,
+        team_id: i64,
+        discussion_number: i64,
+    ) -> ClientResult<crate::Response<crate::types::TeamDiscussion>> {
+        self.runtime
+            .block_on(self.inner.get_discussion_legacy(team_id, discussion_number))
+    }
+    /// The blocking version of [`crate::teams::Teams::delete_discussion_legacy`].
+    pub fn delete_discussion_legacy(
+        &self,
+        team_id: i64,
+        discussion_number: i64,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .delete_discussion_legacy(team_id, discussion_number),
+        )
+    }
+    /// The blocking version of [`crate::teams::Teams::update_discussion_legacy`].
+    pub fn update_discussion_legacy(
+        &self,
+        team_id: i64,
+        discussion_number: i64,
+        body: &crate::types::TeamsUpdateDiscussionInOrgRequest,
+    ) -> ClientResult<crate::Response<crate::types::TeamDiscussion>> {
+        self.runtime.block_on(
+            self.inner
+                .update_discussion_legacy(team_id, discussion_number, body),
+        )
+    }
+    /// The blocking version of [`crate::teams::Teams::list_discussion_comments_legacy`].
+    pub fn list_discussion_comments_legacy(
+        &self,
+        team_id: i64,
+        discussion_number: i64,
+        direction: crate::types::Order,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::TeamDiscussionComment>>> {
+        self.runtime
+            .block_on(self.inner.list_discussion_comments_legacy(
+                team_id,
+                discussion_number,
+                direction,
+                per_page,
+                page,
+            ))
+    }
+    /// The blocking version of [`crate::teams::Teams::list_discussion_comments_legacy_request`].
+    pub fn list_discussion_comments_legacy_request(
+        &self,
+        team_id: i64,
+        discussion_number: i64,
+    ) -> TeamsListDiscussionCommentsLegacyRequest<'_> {
+        TeamsListDiscussionCommentsLegacyRequest {
+            inner: self
+                .inner
+                .list_discussion_comments_legacy_request(team_id, discussion_number),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::teams::Teams::list_all_discussion_comments_legacy`].
+    pub fn list_all_discussion_comments_legacy(
+        &self,
+        team_id: i64,
+        discussion_number: i64,
+        direction: crate::types::Order,
+    ) -> ClientResult<crate::Response<Vec<crate::types::TeamDiscussionComment>>> {
+        self.runtime
+            .block_on(self.inner.list_all_discussion_comments_legacy(
+                team_id,
+                discussion_number,
+                direction,
+            ))
+    }
+    /// The blocking version of [`crate::teams::Teams::create_discussion_comment_legacy`].
+    pub fn create_discussion_comment_legacy(
+        &self,
+        team_id: i64,
+        discussion_number: i64,
+        body: &crate::types::PullsUpdateReviewRequest,
+    ) -> ClientResult<crate::Response<crate::types::TeamDiscussionComment>> {
+        self.runtime
+            .block_on(
+                self.inner
+                    .create_discussion_comment_legacy(team_id, discussion_number, body),
+            )
+    }
+    /// The blocking version of [`crate::teams::Teams::get_discussion_comment_legacy`].
+    pub fn get_discussion_comment_legacy(
+        &self,
+        team_id: i64,
+        discussion_number: i64,
+        comment_number: i64,
+    ) -> ClientResult<crate::Response<crate::types::TeamDiscussionComment>> {
+        self.runtime
+            .block_on(self.inner.get_discussion_comment_legacy(
+                team_id,
+                discussion_number,
+                comment_number,
+            ))
+    }
+    /// The blocking version of [`crate::teams::Teams::delete_discussion_comment_legacy`].
+    pub fn delete_discussion_comment_legacy(
+        &self,
+        team_id: i64,
+        discussion_number: i64,
+        comment_number: i64,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete_discussion_comment_legacy(
+                team_id,
+                discussion_number,
+                comment_number,
+            ))
+    }
+    /// The blocking version of [`crate::teams::Teams::update_discussion_comment_legacy`].
+    pub fn update_discussion_comment_legacy(
+        &self,
+        team_id: i64,
+        discussion_number: i64,
+        comment_number: i64,
+        body: &crate::types::PullsUpdateReviewRequest,
+    ) -> ClientResult<crate::Response<crate::types::TeamDiscussionComment>> {
+        self.runtime
+            .block_on(self.inner.update_discussion_comment_legacy(
+                team_id,
+                discussion_number,
+                comment_number,
+                body,
+            ))
+    }
+    /// The blocking version of [`crate::teams::Teams::list_pending_invitations_legacy`].
+    pub fn list_pending_invitations_legacy(
+        &self,
+        team_id: i64,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::OrganizationInvitation>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_pending_invitations_legacy(team_id, per_page, page),
+        )
+    }
+    /// The blocking version of [`crate::teams::Teams::list_pending_invitations_legacy_request`].
+    pub fn list_pending_invitations_legacy_request(
+        &self,
+        team_id: i64,
+    ) -> TeamsListPendingInvitationsLegacyRequest<'_> {
+        TeamsListPendingInvitationsLegacyRequest {
+            inner: self.inner.list_pending_invitations_legacy_request(team_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::teams::Teams::list_all_pending_invitations_legacy`].
+    pub fn list_all_pending_invitations_legacy(
+        &self,
+        team_id: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::OrganizationInvitation>>> {
+        self.runtime
+            .block_on(self.inner.list_all_pending_invitations_legacy(team_id))
+    }
+    /// The blocking version of [`crate::teams::Teams::list_members_legacy`].
+    pub fn list_members_legacy(
+        &self,
+        team_id: i64,
+        role: crate::types::TeamsListMembersInOrgRole,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::SimpleUser>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_members_legacy(team_id, role, per_page, page),
+        )
+    }
+    /// The blocking version of [`crate::teams::Teams::list_members_legacy_request`].
+    pub fn list_members_legacy_request(&self, team_id: i64) -> TeamsListMembersLegacyRequest<'_> {
+        TeamsListMembersLegacyRequest {
+            inner: self.inner.list_members_legacy_request(team_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::teams::Teams::list_all_members_legacy`].
+    pub fn list_all_members_legacy(
+        &self,
+        team_id: i64,
+        role: crate::types::TeamsListMembersInOrgRole,
+    ) -> ClientResult<crate::Response<Vec<crate::types::SimpleUser>>> {
+        self.runtime
+            .block_on(self.inner.list_all_members_legacy(team_id, role))
+    }
+    /// The blocking version of [`crate::teams::Teams::get_member_legacy`].
+    pub fn get_member_legacy(
+        &self,
+        team_id: i64,
+        username: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.get_member_legacy(team_id, username))
+    }
+    /// The blocking version of [`crate::teams::Teams::add_member_legacy`].
+    pub fn add_member_legacy(
+        &self,
+        team_id: i64,
+        username: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.add_member_legacy(team_id, username))
+    }
+    /// The blocking version of [`crate::teams::Teams::remove_member_legacy`].
+    pub fn remove_member_legacy(
+        &self,
+        team_id: i64,
+        username: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.remove_member_legacy(team_id, username))
+    }
+    /// The blocking version of [`crate::teams::Teams::get_membership_for_user_legacy`].
+    pub fn get_membership_for_user_legacy(
+        &self,
+        team_id: i64,
+        username: &str,
+    ) -> ClientResult<crate::Response<crate::types::TeamMembership>> {
+        self.runtime
+            .block_on(self.inner.get_membership_for_user_legacy(team_id, username))
+    }
+    /// The blocking version of [`crate::teams::Teams::add_or_update_membership_for_user_legacy`].
+    pub fn add_or_update_membership_for_user_legacy(
+        &self,
+        team_id: i64,
+        username: &str,
+        body: &crate::types::TeamsAddUpdateMembershipUserInOrgRequest,
+    ) -> ClientResult<crate::Response<crate::types::TeamMembership>> {
+        self.runtime.block_on(
+            self.inner
+                .add_or_update_membership_for_user_legacy(team_id, username, body),
+        )
+    }
+    /// The blocking version of [`crate::teams::Teams::remove_membership_for_user_legacy`].
+    pub fn remove_membership_for_user_legacy(
+        &self,
+        team_id: i64,
+        username: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .remove_membership_for_user_legacy(team_id, username),
+        )
+    }
+    /// The blocking version of [`crate::teams::Teams::list_projects_legacy`].
+    pub fn list_projects_legacy(
+        &self,
+        team_id: i64,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::TeamProject>>> {
+        self.runtime
+            .block_on(self.inner.list_projects_legacy(team_id, per_page, page))
+    }
+    /// The blocking version of [`crate::teams::Teams::list_projects_legacy_request`].
+    pub fn list_projects_legacy_request(&self, team_id: i64) -> TeamsListProjectsLegacyRequest<'_> {
+        TeamsListProjectsLegacyRequest {
+            inner: self.inner.list_projects_legacy_request(team_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::teams::Teams::list_all_projects_legacy`].
+    pub fn list_all_projects_legacy(
+        &self,
+        team_id: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::TeamProject>>> {
+        self.runtime
+            .block_on(self.inner.list_all_projects_legacy(team_id))
+    }
+    /// The blocking version of [`crate::teams::Teams::check_permissions_for_project_legacy`].
+    pub fn check_permissions_for_project_legacy(
+        &self,
+        team_id: i64,
+        project_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::TeamProject>> {
+        self.runtime.block_on(
+            self.inner
+                .check_permissions_for_project_legacy(team_id, project_id),
+        )
+    }
+    /// The blocking version of [`crate::teams::Teams::add_or_update_project_permissions_legacy`].
+    pub fn add_or_update_project_permissions_legacy(
+        &self,
+        team_id: i64,
+        project_id: i64,
+        body: &crate::types::TeamsAddUpdateProjectPermissionsLegacyRequest,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .add_or_update_project_permissions_legacy(team_id, project_id, body),
+        )
+    }
+    /// The blocking version of [`crate::teams::Teams::remove_project_legacy`].
+    pub fn remove_project_legacy(
+        &self,
+        team_id: i64,
+        project_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.remove_project_legacy(team_id, project_id))
+    }
+    /// The blocking version of [`crate::teams::Teams::list_repos_legacy`].
+    pub fn list_repos_legacy(
+        &self,
+        team_id: i64,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::MinimalRepository>>> {
+        self.runtime
+            .block_on(self.inner.list_repos_legacy(team_id, per_page, page))
+    }
+    /// The blocking version of [`crate::teams::Teams::list_repos_legacy_request`].
+    pub fn list_repos_legacy_request(&self, team_id: i64) -> TeamsListReposLegacyRequest<'_> {
+        TeamsListReposLegacyRequest {
+            inner: self.inner.list_repos_legacy_request(team_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::teams::Teams::list_all_repos_legacy`].
+    pub fn list_all_repos_legacy(
+        &self,
+        team_id: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::MinimalRepository>>> {
+        self.runtime
+            .block_on(self.inner.list_all_repos_legacy(team_id))
+    }
+    /// The blocking version of [`crate::teams::Teams::check_permissions_for_repo_legacy`].
+    pub fn check_permissions_for_repo_legacy(
+        &self,
+        team_id: i64,
+        owner: &str,
+        repo: &str,
+    ) -> ClientResult<crate::Response<crate::types::TeamRepository>> {
+        self.runtime.block_on(
+            self.inner
+                .check_permissions_for_repo_legacy(team_id, owner, repo),
+        )
+    }
+    /// The blocking version of [`crate::teams::Teams::add_or_update_repo_permissions_legacy`].
+    pub fn add_or_update_repo_permissions_legacy(
+        &self,
+        team_id: i64,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::TeamsAddUpdateRepoPermissionsLegacyRequest,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .add_or_update_repo_permissions_legacy(team_id, owner, repo, body),
+        )
+    }
+    /// The blocking version of [`crate::teams::Teams::remove_repo_legacy`].
+    pub fn remove_repo_legacy(
+        &self,
+        team_id: i64,
+        owner: &str,
+        repo: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.remove_repo_legacy(team_id, owner, repo))
+    }
+    /// The blocking version of [`crate::teams::Teams::list_idp_groups_for_legacy`].
+    pub fn list_idp_groups_for_legacy(
+        &self,
+        team_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::GroupMapping>> {
+        self.runtime
+            .block_on(self.inner.list_idp_groups_for_legacy(team_id))
+    }
+    /// The blocking version of [`crate::teams::Teams::create_or_update_idp_group_connections_legacy`].
+    pub fn create_or_update_idp_group_connections_legacy(
+        &self,
+        team_id: i64,
+        body: &crate::types::TeamsCreateUpdateIdpGroupConnectionsLegacyRequest,
+    ) -> ClientResult<crate::Response<crate::types::GroupMapping>> {
+        self.runtime.block_on(
+            self.inner
+                .create_or_update_idp_group_connections_legacy(team_id, body),
+        )
+    }
+    /// The blocking version of [`crate::teams::Teams::list_child_legacy`].
+    pub fn list_child_legacy(
+        &self,
+        team_id: i64,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Team>>> {
+        self.runtime
+            .block_on(self.inner.list_child_legacy(team_id, per_page, page))
+    }
+    /// The blocking version of [`crate::teams::Teams::list_child_legacy_request`].
+    pub fn list_child_legacy_request(&self, team_id: i64) -> TeamsListChildLegacyRequest<'_> {
+        TeamsListChildLegacyRequest {
+            inner: self.inner.list_child_legacy_request(team_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::teams::Teams::list_all_child_legacy`].
+    pub fn list_all_child_legacy(
+        &self,
+        team_id: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Team>>> {
+        self.runtime
+            .block_on(self.inner.list_all_child_legacy(team_id))
+    }
+    /// The blocking version of [`crate::teams::Teams::list_for_authenticated_user`].
+    pub fn list_for_authenticated_user(
+        &self,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::FullTeam>>> {
+        self.runtime
+            .block_on(self.inner.list_for_authenticated_user(per_page, page))
+    }
+    /// The blocking version of [`crate::teams::Teams::list_for_authenticated_user_request`].
+    pub fn list_for_authenticated_user_request(&self) -> TeamsListForAuthenticatedUserRequest<'_> {
+        TeamsListForAuthenticatedUserRequest {
+            inner: self.inner.list_for_authenticated_user_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::teams::Teams::list_all_for_authenticated_user`].
+    pub fn list_all_for_authenticated_user(
+        &self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::FullTeam>>> {
+        self.runtime
+            .block_on(self.inner.list_all_for_authenticated_user())
+    }
+}
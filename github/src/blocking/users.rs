@@ -0,0 +1,807 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Users::list_emails_for_authenticated`], set with a builder.
+pub struct UsersListEmailsForAuthenticatedRequest<'a> {
+    inner: crate::users::UsersListEmailsForAuthenticatedRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> UsersListEmailsForAuthenticatedRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Email>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Users::list_followers_for_authenticated_user`], set with a builder.
+pub struct UsersListFollowersForAuthenticatedUserRequest<'a> {
+    inner: crate::users::UsersListFollowersForAuthenticatedUserRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> UsersListFollowersForAuthenticatedUserRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::SimpleUser>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Users::list_followed_by_authenticated`], set with a builder.
+pub struct UsersListFollowedByAuthenticatedRequest<'a> {
+    inner: crate::users::UsersListFollowedByAuthenticatedRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> UsersListFollowedByAuthenticatedRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::SimpleUser>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Users::list_gpg_keys_for_authenticated`], set with a builder.
+pub struct UsersListGpgKeysForAuthenticatedRequest<'a> {
+    inner: crate::users::UsersListGpgKeysForAuthenticatedRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> UsersListGpgKeysForAuthenticatedRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::GpgKey>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Users::list_public_ssh_keys_for_authenticated`], set with a builder.
+pub struct UsersListPublicSshKeysForAuthenticatedRequest<'a> {
+    inner: crate::users::UsersListPublicSshKeysForAuthenticatedRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> UsersListPublicSshKeysForAuthenticatedRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Key>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Users::list_public_emails_for_authenticated`], set with a builder.
+pub struct UsersListPublicEmailsForAuthenticatedRequest<'a> {
+    inner: crate::users::UsersListPublicEmailsForAuthenticatedRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> UsersListPublicEmailsForAuthenticatedRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Email>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Users::list`], set with a builder.
+pub struct UsersListRequest<'a> {
+    inner: crate::users::UsersListRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> UsersListRequest<'a> {
+    /// Sets the `since` query parameter.
+    pub fn since(mut self, since: i64) -> Self {
+        self.inner = self.inner.since(since);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::SimpleUser>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Users::list_followers_for_user`], set with a builder.
+pub struct UsersListFollowersForUserRequest<'a> {
+    inner: crate::users::UsersListFollowersForUserRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> UsersListFollowersForUserRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::SimpleUser>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Users::list_following_for_user`], set with a builder.
+pub struct UsersListFollowingForUserRequest<'a> {
+    inner: crate::users::UsersListFollowingForUserRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> UsersListFollowingForUserRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::SimpleUser>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Users::list_gpg_keys_for_user`], set with a builder.
+pub struct UsersListGpgKeysForUserRequest<'a> {
+    inner: crate::users::UsersListGpgKeysForUserRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> UsersListGpgKeysForUserRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::GpgKey>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Users::get_context_for_user`], set with a builder.
+pub struct UsersGetContextForUserRequest<'a> {
+    inner: crate::users::UsersGetContextForUserRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> UsersGetContextForUserRequest<'a> {
+    /// Sets the `subject_type` query parameter.
+    pub fn subject_type(mut self, subject_type: crate::types::SubjectType) -> Self {
+        self.inner = self.inner.subject_type(subject_type);
+        self
+    }
+
+    /// Sets the `subject_id` query parameter.
+    pub fn subject_id(mut self, subject_id: &'a str) -> Self {
+        self.inner = self.inner.subject_id(subject_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::Hovercard>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Users::list_public_keys_for_user`], set with a builder.
+pub struct UsersListPublicKeysForUserRequest<'a> {
+    inner: crate::users::UsersListPublicKeysForUserRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> UsersListPublicKeysForUserRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.inner = self.inner.per_page(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::KeySimple>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::users::Users`].
+pub struct Users {
+    inner: crate::users::Users,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Users {
+    pub(crate) fn new(inner: crate::users::Users, runtime: Arc<tokio::runtime::Runtime>) -> Self {
+        Users { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::users::Users::get_authenticated_public_user`].
+    pub fn get_authenticated_public_user(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::PublicUser>> {
+        self.runtime
+            .block_on(self.inner.get_authenticated_public_user())
+    }
+    /// The blocking version of [`crate::users::Users::get_authenticated_private_user`].
+    pub fn get_authenticated_private_user(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::PrivateUser>> {
+        self.runtime
+            .block_on(self.inner.get_authenticated_private_user())
+    }
+    /// The blocking version of [`crate::users::Users::get_authenticated`].
+    pub fn get_authenticated(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::UsersGetByUsernameResponseOneOf>> {
+        self.runtime.block_on(self.inner.get_authenticated())
+    }
+    /// The blocking version of [`crate::users::Users::update_authenticated`].
+    pub fn update_authenticated(
+        &self,
+        body: &crate::types::UsersUpdateAuthenticatedRequest,
+    ) -> ClientResult<crate::Response<crate::types::PrivateUser>> {
+        self.runtime.block_on(self.inner.update_authenticated(body))
+    }
+    /// The blocking version of [`crate::users::Users::list_blocked_by_authenticated`].
+    pub fn list_blocked_by_authenticated(
+        &self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::SimpleUser>>> {
+        self.runtime
+            .block_on(self.inner.list_blocked_by_authenticated())
+    }
+    /// The blocking version of [`crate::users::Users::list_all_blocked_by_authenticated`].
+    pub fn list_all_blocked_by_authenticated(
+        &self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::SimpleUser>>> {
+        self.runtime
+            .block_on(self.inner.list_all_blocked_by_authenticated())
+    }
+    /// The blocking version of [`crate::users::Users::check_blocked`].
+    pub fn check_blocked(&self, username: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.check_blocked(username))
+    }
+    /// The blocking version of [`crate::users::Users::block`].
+    pub fn block(&self, username: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.block(username))
+    }
+    /// The blocking version of [`crate::users::Users::unblock`].
+    pub fn unblock(&self, username: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.unblock(username))
+    }
+    /// The blocking version of [`crate::users::Users::set_primary_email_visibility_for_authenticated`].
+    pub fn set_primary_email_visibility_for_authenticated(
+        &self,
+        body: &crate::types::UsersSetPrimaryEmailVisibilityAuthenticatedRequest,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Email>>> {
+        self.runtime.block_on(
+            self.inner
+                .set_primary_email_visibility_for_authenticated(body),
+        )
+    }
+    /// The blocking version of [`crate::users::Users::list_emails_for_authenticated`].
+    pub fn list_emails_for_authenticated(
+        &self,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Email>>> {
+        self.runtime
+            .block_on(self.inner.list_emails_for_authenticated(per_page, page))
+    }
+    /// The blocking version of [`crate::users::Users::list_emails_for_authenticated_request`].
+    pub fn list_emails_for_authenticated_request(
+        &self,
+    ) -> UsersListEmailsForAuthenticatedRequest<'_> {
+        UsersListEmailsForAuthenticatedRequest {
+            inner: self.inner.list_emails_for_authenticated_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::users::Users::list_all_emails_for_authenticated`].
+    pub fn list_all_emails_for_authenticated(
+        &self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Email>>> {
+        self.runtime
+            .block_on(self.inner.list_all_emails_for_authenticated())
+    }
+    /// The blocking version of [`crate::users::Users::add_email_for_authenticated`].
+    pub fn add_email_for_authenticated(
+        &self,
+        body: &crate::types::UsersAddEmailAuthenticatedRequestOneOf,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Email>>> {
+        self.runtime
+            .block_on(self.inner.add_email_for_authenticated(body))
+    }
+    /// The blocking version of [`crate::users::Users::delete_email_for_authenticated`].
+    pub fn delete_email_for_authenticated(
+        &self,
+        body: &crate::types::UsersAddEmailAuthenticatedRequestOneOf,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete_email_for_authenticated(body))
+    }
+    /// The blocking version of [`crate::users::Users::list_followers_for_authenticated_user`].
+    pub fn list_followers_for_authenticated_user(
+        &self,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::SimpleUser>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_followers_for_authenticated_user(per_page, page),
+        )
+    }
+    /// The blocking version of [`crate::users::Users::list_followers_for_authenticated_user_request`].
+    pub fn list_followers_for_authenticated_user_request(
+        &self,
+    ) -> UsersListFollowersForAuthenticatedUserRequest<'_> {
+        UsersListFollowersForAuthenticatedUserRequest {
+            inner: self.inner.list_followers_for_authenticated_user_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::users::Users::list_all_followers_for_authenticated_user`].
+    pub fn list_all_followers_for_authenticated_user(
+        &self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::SimpleUser>>> {
+        self.runtime
+            .block_on(self.inner.list_all_followers_for_authenticated_user())
+    }
+    /// The blocking version of [`crate::users::Users::list_followed_by_authenticated`].
+    pub fn list_followed_by_authenticated(
+        &self,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::SimpleUser>>> {
+        self.runtime
+            .block_on(self.inner.list_followed_by_authenticated(per_page, page))
+    }
+    /// The blocking version of [`crate::users::Users::list_followed_by_authenticated_request`].
+    pub fn list_followed_by_authenticated_request(
+        &self,
+    ) -> UsersListFollowedByAuthenticatedRequest<'_> {
+        UsersListFollowedByAuthenticatedRequest {
+            inner: self.inner.list_followed_by_authenticated_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::users::Users::list_all_followed_by_authenticated`].
+    pub fn list_all_followed_by_authenticated(
+        &self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::SimpleUser>>> {
+        self.runtime
+            .block_on(self.inner.list_all_followed_by_authenticated())
+    }
+    /// The blocking version of [`crate::users::Users::check_person_is_followed_by_authenticated`].
+    pub fn check_person_is_followed_by_authenticated(
+        &self,
+        username: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .check_person_is_followed_by_authenticated(username),
+        )
+    }
+    /// The blocking version of [`crate::users::Users::follow`].
+    pub fn follow(&self, username: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.follow(username))
+    }
+    /// The blocking version of [`crate::users::Users::unfollow`].
+    pub fn unfollow(&self, username: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.unfollow(username))
+    }
+    /// The blocking version of [`crate::users::Users::list_gpg_keys_for_authenticated`].
+    pub fn list_gpg_keys_for_authenticated(
+        &self,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GpgKey>>> {
+        self.runtime
+            .block_on(self.inner.list_gpg_keys_for_authenticated(per_page, page))
+    }
+    /// The blocking version of [`crate::users::Users::list_gpg_keys_for_authenticated_request`].
+    pub fn list_gpg_keys_for_authenticated_request(
+        &self,
+    ) -> UsersListGpgKeysForAuthenticatedRequest<'_> {
+        UsersListGpgKeysForAuthenticatedRequest {
+            inner: self.inner.list_gpg_keys_for_authenticated_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::users::Users::list_all_gpg_keys_for_authenticated`].
+    pub fn list_all_gpg_keys_for_authenticated(
+        &self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GpgKey>>> {
+        self.runtime
+            .block_on(self.inner.list_all_gpg_keys_for_authenticated())
+    }
+    /// The blocking version of [`crate::users::Users::create_gpg_key_for_authenticated`].
+    pub fn create_gpg_key_for_authenticated(
+        &self,
+        body: &crate::types::UsersCreateGpgKeyAuthenticatedRequest,
+    ) -> ClientResult<crate::Response<crate::types::GpgKey>> {
+        self.runtime
+            .block_on(self.inner.create_gpg_key_for_authenticated(body))
+    }
+    /// The blocking version of [`crate::users::Users::get_gpg_key_for_authenticated`].
+    pub fn get_gpg_key_for_authenticated(
+        &self,
+        gpg_key_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::GpgKey>> {
+        self.runtime
+            .block_on(self.inner.get_gpg_key_for_authenticated(gpg_key_id))
+    }
+    /// The blocking version of [`crate::users::Users::delete_gpg_key_for_authenticated`].
+    pub fn delete_gpg_key_for_authenticated(
+        &self,
+        gpg_key_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete_gpg_key_for_authenticated(gpg_key_id))
+    }
+    /// The blocking version of [`crate::users::Users::list_public_ssh_keys_for_authenticated`].
+    pub fn list_public_ssh_keys_for_authenticated(
+        &self,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Key>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_public_ssh_keys_for_authenticated(per_page, page),
+        )
+    }
+    /// The blocking version of [`crate::users::Users::list_public_ssh_keys_for_authenticated_request`].
+    pub fn list_public_ssh_keys_for_authenticated_request(
+        &self,
+    ) -> UsersListPublicSshKeysForAuthenticatedRequest<'_> {
+        UsersListPublicSshKeysForAuthenticatedRequest {
+            inner: self.inner.list_public_ssh_keys_for_authenticated_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::users::Users::list_all_public_ssh_keys_for_authenticated`].
+    pub fn list_all_public_ssh_keys_for_authenticated(
+        &self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Key>>> {
+        self.runtime
+            .block_on(self.inner.list_all_public_ssh_keys_for_authenticated())
+    }
+    /// The blocking version of [`crate::users::Users::create_public_ssh_key_for_authenticated`].
+    pub fn create_public_ssh_key_for_authenticated(
+        &self,
+        body: &crate::types::UsersCreatePublicSshKeyAuthenticatedRequest,
+    ) -> ClientResult<crate::Response<crate::types::Key>> {
+        self.runtime
+            .block_on(self.inner.create_public_ssh_key_for_authenticated(body))
+    }
+    /// The blocking version of [`crate::users::Users::get_public_ssh_key_for_authenticated`].
+    pub fn get_public_ssh_key_for_authenticated(
+        &self,
+        key_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::Key>> {
+        self.runtime
+            .block_on(self.inner.get_public_ssh_key_for_authenticated(key_id))
+    }
+    /// The blocking version of [`crate::users::Users::delete_public_ssh_key_for_authenticated`].
+    pub fn delete_public_ssh_key_for_authenticated(
+        &self,
+        key_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete_public_ssh_key_for_authenticated(key_id))
+    }
+    /// The blocking version of [`crate::users::Users::list_public_emails_for_authenticated`].
+    pub fn list_public_emails_for_authenticated(
+        &self,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Email>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_public_emails_for_authenticated(per_page, page),
+        )
+    }
+    /// The blocking version of [`crate::users::Users::list_public_emails_for_authenticated_request`].
+    pub fn list_public_emails_for_authenticated_request(
+        &self,
+    ) -> UsersListPublicEmailsForAuthenticatedRequest<'_> {
+        UsersListPublicEmailsForAuthenticatedRequest {
+            inner: self.inner.list_public_emails_for_authenticated_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::users::Users::list_all_public_emails_for_authenticated`].
+    pub fn list_all_public_emails_for_authenticated(
+        &self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Email>>> {
+        self.runtime
+            .block_on(self.inner.list_all_public_emails_for_authenticated())
+    }
+    /// The blocking version of [`crate::users::Users::list`].
+    pub fn list(
+        &self,
+        since: i64,
+        per_page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::SimpleUser>>> {
+        self.runtime.block_on(self.inner.list(since, per_page))
+    }
+    /// The blocking version of [`crate::users::Users::list_request`].
+    pub fn list_request(&self) -> UsersListRequest<'_> {
+        UsersListRequest {
+            inner: self.inner.list_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::users::Users::list_all`].
+    pub fn list_all(
+        &self,
+        since: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::SimpleUser>>> {
+        self.runtime.block_on(self.inner.list_all(since))
+    }
+    /// The blocking version of [`crate::users::Users::get_by_username_public_user`].
+    pub fn get_by_username_public_user(
+        &self,
+        username: &str,
+    ) -> ClientResult<crate::Response<crate::types::PublicUser>> {
+        self.runtime
+            .block_on(self.inner.get_by_username_public_user(username))
+    }
+    /// The blocking version of [`crate::users::Users::get_by_username_private_user`].
+    pub fn get_by_username_private_user(
+        &self,
+        username: &str,
+    ) -> ClientResult<crate::Response<crate::types::PrivateUser>> {
+        self.runtime
+            .block_on(self.inner.get_by_username_private_user(username))
+    }
+    /// The blocking version of [`crate::users::Users::get_by_username`].
+    pub fn get_by_username(
+        &self,
+        username: &str,
+    ) -> ClientResult<crate::Response<crate::types::UsersGetByUsernameResponseOneOf>> {
+        self.runtime.block_on(self.inner.get_by_username(username))
+    }
+    /// The blocking version of [`crate::users::Users::list_followers_for_user`].
+    pub fn list_followers_for_user(
+        &self,
+        username: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::SimpleUser>>> {
+        self.runtime
+            .block_on(self.inner.list_followers_for_user(username, per_page, page))
+    }
+    /// The blocking version of [`crate::users::Users::list_followers_for_user_request`].
+    pub fn list_followers_for_user_request<'a>(
+        &'a self,
+        username: &'a str,
+    ) -> UsersListFollowersForUserRequest<'a> {
+        UsersListFollowersForUserRequest {
+            inner: self.inner.list_followers_for_user_request(username),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::users::Users::list_all_followers_for_user`].
+    pub fn list_all_followers_for_user(
+        &self,
+        username: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::SimpleUser>>> {
+        self.runtime
+            .block_on(self.inner.list_all_followers_for_user(username))
+    }
+    /// The blocking version of [`crate::users::Users::list_following_for_user`].
+    pub fn list_following_for_user(
+        &self,
+        username: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::SimpleUser>>> {
+        self.runtime
+            .block_on(self.inner.list_following_for_user(username, per_page, page))
+    }
+    /// The blocking version of [`crate::users::Users::list_following_for_user_request`].
+    pub fn list_following_for_user_request<'a>(
+        &'a self,
+        username: &'a str,
+    ) -> UsersListFollowingForUserRequest<'a> {
+        UsersListFollowingForUserRequest {
+            inner: self.inner.list_following_for_user_request(username),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::users::Users::list_all_following_for_user`].
+    pub fn list_all_following_for_user(
+        &self,
+        username: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::SimpleUser>>> {
+        self.runtime
+            .block_on(self.inner.list_all_following_for_user(username))
+    }
+    /// The blocking version of [`crate::users::Users::check_following_for_user`].
+    pub fn check_following_for_user(
+        &self,
+        username: &str,
+        target_user: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.check_following_for_user(username, target_user))
+    }
+    /// The blocking version of [`crate::users::Users::list_gpg_keys_for_user`].
+    pub fn list_gpg_keys_for_user(
+        &self,
+        username: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GpgKey>>> {
+        self.runtime
+            .block_on(self.inner.list_gpg_keys_for_user(username, per_page, page))
+    }
+    /// The blocking version of [`crate::users::Users::list_gpg_keys_for_user_request`].
+    pub fn list_gpg_keys_for_user_request<'a>(
+        &'a self,
+        username: &'a str,
+    ) -> UsersListGpgKeysForUserRequest<'a> {
+        UsersListGpgKeysForUserRequest {
+            inner: self.inner.list_gpg_keys_for_user_request(username),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::users::Users::list_all_gpg_keys_for_user`].
+    pub fn list_all_gpg_keys_for_user(
+        &self,
+        username: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GpgKey>>> {
+        self.runtime
+            .block_on(self.inner.list_all_gpg_keys_for_user(username))
+    }
+    /// The blocking version of [`crate::users::Users::get_context_for_user`].
+    pub fn get_context_for_user(
+        &self,
+        username: &str,
+        subject_type: crate::types::SubjectType,
+        subject_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Hovercard>> {
+        self.runtime.block_on(
+            self.inner
+                .get_context_for_user(username, subject_type, subject_id),
+        )
+    }
+    /// The blocking version of [`crate::users::Users::get_context_for_user_request`].
+    pub fn get_context_for_user_request<'a>(
+        &'a self,
+        username: &'a str,
+    ) -> UsersGetContextForUserRequest<'a> {
+        UsersGetContextForUserRequest {
+            inner: self.inner.get_context_for_user_request(username),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::users::Users::list_public_keys_for_user`].
+    pub fn list_public_keys_for_user(
+        &self,
+        username: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::KeySimple>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_public_keys_for_user(username, per_page, page),
+        )
+    }
+    /// The blocking version of [`crate::users::Users::list_public_keys_for_user_request`].
+    pub fn list_public_keys_for_user_request<'a>(
+        &'a self,
+        username: &'a str,
+    ) -> UsersListPublicKeysForUserRequest<'a> {
+        UsersListPublicKeysForUserRequest {
+            inner: self.inner.list_public_keys_for_user_request(username),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::users::Users::list_all_public_keys_for_user`].
+    pub fn list_all_public_keys_for_user(
+        &self,
+        username: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::KeySimple>>> {
+        self.runtime
+            .block_on(self.inner.list_all_public_keys_for_user(username))
+    }
+}
@@ -1,6 +1,367 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Checks::list_annotations`], set with a builder.
+pub struct ChecksListAnnotationsRequest<'a> {
+    client: &'a Checks,
+    owner: &'a str,
+    repo: &'a str,
+    check_run_id: i64,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> ChecksListAnnotationsRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::CheckAnnotation>>> {
+        let Self {
+            client,
+            owner,
+            repo,
+            check_run_id,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/repos/{}/{}/check-runs/{}/annotations?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&check_run_id.to_string()),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Checks::list_for_suite`], set with a builder.
+pub struct ChecksListForSuiteRequest<'a> {
+    client: &'a Checks,
+    owner: &'a str,
+    repo: &'a str,
+    check_suite_id: i64,
+    check_name: Option<&'a str>,
+    status: Option<crate::types::JobStatus>,
+    filter: Option<crate::types::ActionsListJobsWorkflowRunFilter>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> ChecksListForSuiteRequest<'a> {
+    /// Sets the `check_name` query parameter.
+    pub fn check_name(mut self, check_name: &'a str) -> Self {
+        self.check_name = Some(check_name);
+        self
+    }
+
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: crate::types::JobStatus) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Sets the `filter` query parameter.
+    pub fn filter(mut self, filter: crate::types::ActionsListJobsWorkflowRunFilter) -> Self {
+        self.filter = Some(filter);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::ChecksListRefResponse>> {
+        let Self {
+            client,
+            owner,
+            repo,
+            check_suite_id,
+            check_name,
+            status,
+            filter,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = check_name {
+            query_args.push(("check_name".to_string(), v.to_string()));
+        }
+        if let Some(v) = filter {
+            query_args.push(("filter".to_string(), v.to_string()));
+        }
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        if let Some(v) = status {
+            query_args.push(("status".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/repos/{}/{}/check-suites/{}/check-runs?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&check_suite_id.to_string()),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Checks::list_for_ref`], set with a builder.
+pub struct ChecksListForRefRequest<'a> {
+    client: &'a Checks,
+    owner: &'a str,
+    repo: &'a str,
+    ref_: &'a str,
+    check_name: Option<&'a str>,
+    status: Option<crate::types::JobStatus>,
+    filter: Option<crate::types::ActionsListJobsWorkflowRunFilter>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+    app_id: Option<i64>,
+}
+
+impl<'a> ChecksListForRefRequest<'a> {
+    /// Sets the `check_name` query parameter.
+    pub fn check_name(mut self, check_name: &'a str) -> Self {
+        self.check_name = Some(check_name);
+        self
+    }
+
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: crate::types::JobStatus) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Sets the `filter` query parameter.
+    pub fn filter(mut self, filter: crate::types::ActionsListJobsWorkflowRunFilter) -> Self {
+        self.filter = Some(filter);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sets the `app_id` query parameter.
+    pub fn app_id(mut self, app_id: i64) -> Self {
+        self.app_id = Some(app_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::ChecksListRefResponse>> {
+        let Self {
+            client,
+            owner,
+            repo,
+            ref_,
+            check_name,
+            status,
+            filter,
+            per_page,
+            page,
+            app_id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = app_id {
+            query_args.push(("app_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = check_name {
+            query_args.push(("check_name".to_string(), v.to_string()));
+        }
+        if let Some(v) = filter {
+            query_args.push(("filter".to_string(), v.to_string()));
+        }
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        if let Some(v) = status {
+            query_args.push(("status".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/repos/{}/{}/commits/{}/check-runs?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(ref_),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Checks::list_suites_for_ref`], set with a builder.
+pub struct ChecksListSuitesForRefRequest<'a> {
+    client: &'a Checks,
+    owner: &'a str,
+    repo: &'a str,
+    ref_: &'a str,
+    app_id: Option<i64>,
+    check_name: Option<&'a str>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> ChecksListSuitesForRefRequest<'a> {
+    /// Sets the `app_id` query parameter.
+    pub fn app_id(mut self, app_id: i64) -> Self {
+        self.app_id = Some(app_id);
+        self
+    }
+
+    /// Sets the `check_name` query parameter.
+    pub fn check_name(mut self, check_name: &'a str) -> Self {
+        self.check_name = Some(check_name);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::ChecksListSuitesRefResponse>> {
+        let Self {
+            client,
+            owner,
+            repo,
+            ref_,
+            app_id,
+            check_name,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = app_id {
+            query_args.push(("app_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = check_name {
+            query_args.push(("check_name".to_string(), v.to_string()));
+        }
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/repos/{}/{}/commits/{}/check-suites?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(ref_),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct Checks {
     pub client: Client,
 }
@@ -191,6 +552,32 @@ impl Checks {
             )
             .await
     }
+    /**
+     * List check run annotations.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/check-runs/{check_run_id}/annotations` endpoint.
+     *
+     * As opposed to `list_annotations`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Lists annotations for a check run using the annotation `id`. GitHub Apps must have the `checks:read` permission on a private repository or pull access to a public repository to get annotations for a check run. OAuth Apps and authenticated users must have the `repo` scope to get annotations for a check run in a private repository.
+     *
+     * FROM: <https://docs.github.com/rest/reference/checks#list-check-run-annotations>
+     */
+    pub fn list_annotations_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+        check_run_id: i64,
+    ) -> ChecksListAnnotationsRequest<'a> {
+        ChecksListAnnotationsRequest {
+            client: self,
+            owner,
+            repo,
+            check_run_id,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List check run annotations.
      *
@@ -227,6 +614,34 @@ impl Checks {
             )
             .await
     }
+    /**
+     * List check run annotations.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/check-runs/{check_run_id}/annotations` endpoint.
+     *
+     * As opposed to `list_all_annotations`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Lists annotations for a check run using the annotation `id`. GitHub Apps must have the `checks:read` permission on a private repository or pull access to a public repository to get annotations for a check run. OAuth Apps and authenticated users must have the `repo` scope to get annotations for a check run in a private repository.
+     *
+     * FROM: <https://docs.github.com/rest/reference/checks#list-check-run-annotations>
+     */
+    pub fn list_all_annotations_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        check_run_id: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::CheckAnnotation>> + Unpin + '_ {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/check-runs/{}/annotations",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&check_run_id.to_string()),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Create a check suite.
      *
@@ -419,6 +834,37 @@ impl Checks {
             )
             .await
     }
+    /**
+     * List check runs in a check suite.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/check-suites/{check_suite_id}/check-runs` endpoint.
+     *
+     * As opposed to `list_for_suite`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * **Note:** The Checks API only looks for pushes in the repository where the check suite or check run were created. Pushes to a branch in a forked repository are not detected and return an empty `pull_requests` array.
+     *
+     * Lists check runs for a check suite using its `id`. GitHub Apps must have the `checks:read` permission on a private repository or pull access to a public repository to get check runs. OAuth Apps and authenticated users must have the `repo` scope to get check runs in a private repository.
+     *
+     * FROM: <https://docs.github.com/rest/reference/checks#list-check-runs-in-a-check-suite>
+     */
+    pub fn list_for_suite_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+        check_suite_id: i64,
+    ) -> ChecksListForSuiteRequest<'a> {
+        ChecksListForSuiteRequest {
+            client: self,
+            owner,
+            repo,
+            check_suite_id,
+            check_name: None,
+            status: None,
+            filter: None,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * Rerequest a check suite.
      *
@@ -538,6 +984,38 @@ impl Checks {
             )
             .await
     }
+    /**
+     * List check runs for a Git reference.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/commits/{ref}/check-runs` endpoint.
+     *
+     * As opposed to `list_for_ref`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * **Note:** The Checks API only looks for pushes in the repository where the check suite or check run were created. Pushes to a branch in a forked repository are not detected and return an empty `pull_requests` array.
+     *
+     * Lists check runs for a commit ref. The `ref` can be a SHA, branch name, or a tag name. GitHub Apps must have the `checks:read` permission on a private repository or pull access to a public repository to get check runs. OAuth Apps and authenticated users must have the `repo` scope to get check runs in a private repository.
+     *
+     * FROM: <https://docs.github.com/rest/reference/checks#list-check-runs-for-a-git-reference>
+     */
+    pub fn list_for_ref_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+        ref_: &'a str,
+    ) -> ChecksListForRefRequest<'a> {
+        ChecksListForRefRequest {
+            client: self,
+            owner,
+            repo,
+            ref_,
+            check_name: None,
+            status: None,
+            filter: None,
+            per_page: None,
+            page: None,
+            app_id: None,
+        }
+    }
     /**
      * List check suites for a Git reference.
      *
@@ -603,4 +1081,34 @@ impl Checks {
             )
             .await
     }
+    /**
+     * List check suites for a Git reference.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/commits/{ref}/check-suites` endpoint.
+     *
+     * As opposed to `list_suites_for_ref`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * **Note:** The Checks API only looks for pushes in the repository where the check suite or check run were created. Pushes to a branch in a forked repository are not detected and return an empty `pull_requests` array and a `null` value for `head_branch`.
+     *
+     * Lists check suites for a commit `ref`. The `ref` can be a SHA, branch name, or a tag name. GitHub Apps must have the `checks:read` permission on a private repository or pull access to a public repository to list check suites. OAuth Apps and authenticated users must have the `repo` scope to get check suites in a private repository.
+     *
+     * FROM: <https://docs.github.com/rest/reference/checks#list-check-suites-for-a-git-reference>
+     */
+    pub fn list_suites_for_ref_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+        ref_: &'a str,
+    ) -> ChecksListSuitesForRefRequest<'a> {
+        ChecksListSuitesForRefRequest {
+            client: self,
+            owner,
+            repo,
+            ref_,
+            app_id: None,
+            check_name: None,
+            per_page: None,
+            page: None,
+        }
+    }
 }
@@ -1,6 +1,354 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`CodeScanning::list_alerts_for_repo`], set with a builder.
+pub struct CodeScanningListAlertsForRepoRequest<'a> {
+    client: &'a CodeScanning,
+    owner: &'a str,
+    repo: &'a str,
+    tool_name: Option<&'a str>,
+    tool_guid: Option<&'a str>,
+    page: Option<i64>,
+    per_page: Option<i64>,
+    ref_: Option<&'a str>,
+    state: Option<crate::types::CodeScanningAlertState>,
+}
+
+impl<'a> CodeScanningListAlertsForRepoRequest<'a> {
+    /// Sets the `tool_name` query parameter.
+    pub fn tool_name(mut self, tool_name: &'a str) -> Self {
+        self.tool_name = Some(tool_name);
+        self
+    }
+
+    /// Sets the `tool_guid` query parameter.
+    pub fn tool_guid(mut self, tool_guid: &'a str) -> Self {
+        self.tool_guid = Some(tool_guid);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `ref` query parameter.
+    pub fn ref_(mut self, ref_: &'a str) -> Self {
+        self.ref_ = Some(ref_);
+        self
+    }
+
+    /// Sets the `state` query parameter.
+    pub fn state(mut self, state: crate::types::CodeScanningAlertState) -> Self {
+        self.state = Some(state);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::CodeScanningAlertItems>>> {
+        let Self {
+            client,
+            owner,
+            repo,
+            tool_name,
+            tool_guid,
+            page,
+            per_page,
+            ref_,
+            state,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        if let Some(v) = ref_ {
+            query_args.push(("ref".to_string(), v.to_string()));
+        }
+        if let Some(v) = state {
+            query_args.push(("state".to_string(), v.to_string()));
+        }
+        if let Some(v) = tool_guid {
+            query_args.push(("tool_guid".to_string(), v.to_string()));
+        }
+        if let Some(v) = tool_name {
+            query_args.push(("tool_name".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/repos/{}/{}/code-scanning/alerts?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`CodeScanning::list_alert_instances`], set with a builder.
+pub struct CodeScanningListAlertInstancesRequest<'a> {
+    client: &'a CodeScanning,
+    owner: &'a str,
+    repo: &'a str,
+    alert_number: i64,
+    page: Option<i64>,
+    per_page: Option<i64>,
+    ref_: Option<&'a str>,
+}
+
+impl<'a> CodeScanningListAlertInstancesRequest<'a> {
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `ref` query parameter.
+    pub fn ref_(mut self, ref_: &'a str) -> Self {
+        self.ref_ = Some(ref_);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::CodeScanningAlertInstance>>> {
+        let Self {
+            client,
+            owner,
+            repo,
+            alert_number,
+            page,
+            per_page,
+            ref_,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        if let Some(v) = ref_ {
+            query_args.push(("ref".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/repos/{}/{}/code-scanning/alerts/{}/instances?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&alert_number.to_string()),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`CodeScanning::list_recent_analyses`], set with a builder.
+pub struct CodeScanningListRecentAnalysesRequest<'a> {
+    client: &'a CodeScanning,
+    owner: &'a str,
+    repo: &'a str,
+    tool_name: Option<&'a str>,
+    tool_guid: Option<&'a str>,
+    page: Option<i64>,
+    per_page: Option<i64>,
+    ref_: Option<&'a str>,
+    sarif_id: Option<&'a str>,
+}
+
+impl<'a> CodeScanningListRecentAnalysesRequest<'a> {
+    /// Sets the `tool_name` query parameter.
+    pub fn tool_name(mut self, tool_name: &'a str) -> Self {
+        self.tool_name = Some(tool_name);
+        self
+    }
+
+    /// Sets the `tool_guid` query parameter.
+    pub fn tool_guid(mut self, tool_guid: &'a str) -> Self {
+        self.tool_guid = Some(tool_guid);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `ref` query parameter.
+    pub fn ref_(mut self, ref_: &'a str) -> Self {
+        self.ref_ = Some(ref_);
+        self
+    }
+
+    /// Sets the `sarif_id` query parameter.
+    pub fn sarif_id(mut self, sarif_id: &'a str) -> Self {
+        self.sarif_id = Some(sarif_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::CodeScanningAnalysis>>> {
+        let Self {
+            client,
+            owner,
+            repo,
+            tool_name,
+            tool_guid,
+            page,
+            per_page,
+            ref_,
+            sarif_id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        if let Some(v) = ref_ {
+            query_args.push(("ref".to_string(), v.to_string()));
+        }
+        if let Some(v) = sarif_id {
+            query_args.push(("sarif_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = tool_guid {
+            query_args.push(("tool_guid".to_string(), v.to_string()));
+        }
+        if let Some(v) = tool_name {
+            query_args.push(("tool_name".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/repos/{}/{}/code-scanning/analyses?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`CodeScanning::delete_analysis`], set with a builder.
+pub struct CodeScanningDeleteAnalysisRequest<'a> {
+    client: &'a CodeScanning,
+    owner: &'a str,
+    repo: &'a str,
+    analysis_id: i64,
+    confirm_delete: Option<&'a str>,
+}
+
+impl<'a> CodeScanningDeleteAnalysisRequest<'a> {
+    /// Sets the `confirm_delete` query parameter.
+    pub fn confirm_delete(mut self, confirm_delete: &'a str) -> Self {
+        self.confirm_delete = Some(confirm_delete);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::AnalysisDeletion>> {
+        let Self {
+            client,
+            owner,
+            repo,
+            analysis_id,
+            confirm_delete,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = confirm_delete {
+            query_args.push(("confirm_delete".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/repos/{}/{}/code-scanning/analyses/{}?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&analysis_id.to_string()),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .delete(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct CodeScanning {
     pub client: Client,
 }
@@ -89,6 +437,42 @@ impl CodeScanning {
             )
             .await
     }
+    /**
+     * List code scanning alerts for a repository.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/code-scanning/alerts` endpoint.
+     *
+     * As opposed to `list_alerts_for_repo`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Lists all open code scanning alerts for the default branch (usually `main`
+     * or `master`). You must use an access token with the `security_events` scope to use
+     * this endpoint. GitHub Apps must have the `security_events` read permission to use
+     * this endpoint.
+     *
+     * The response includes a `most_recent_instance` object.
+     * This provides details of the most recent instance of this alert
+     * for the default branch or for the specified Git reference
+     * (if you used `ref` in the request).
+     *
+     * FROM: <https://docs.github.com/rest/reference/code-scanning#list-code-scanning-alerts-for-a-repository>
+     */
+    pub fn list_alerts_for_repo_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+    ) -> CodeScanningListAlertsForRepoRequest<'a> {
+        CodeScanningListAlertsForRepoRequest {
+            client: self,
+            owner,
+            repo,
+            tool_name: None,
+            tool_guid: None,
+            page: None,
+            per_page: None,
+            ref_: None,
+            state: None,
+        }
+    }
     /**
      * List code scanning alerts for a repository.
      *
@@ -150,6 +534,60 @@ impl CodeScanning {
             )
             .await
     }
+    /**
+     * List code scanning alerts for a repository.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/code-scanning/alerts` endpoint.
+     *
+     * As opposed to `list_all_alerts_for_repo`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Lists all open code scanning alerts for the default branch (usually `main`
+     * or `master`). You must use an access token with the `security_events` scope to use
+     * this endpoint. GitHub Apps must have the `security_events` read permission to use
+     * this endpoint.
+     *
+     * The response includes a `most_recent_instance` object.
+     * This provides details of the most recent instance of this alert
+     * for the default branch or for the specified Git reference
+     * (if you used `ref` in the request).
+     *
+     * FROM: <https://docs.github.com/rest/reference/code-scanning#list-code-scanning-alerts-for-a-repository>
+     */
+    pub fn list_all_alerts_for_repo_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        tool_name: &str,
+        tool_guid: &str,
+        ref_: &str,
+        state: crate::types::CodeScanningAlertState,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::CodeScanningAlertItems>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !ref_.is_empty() {
+            query_args.push(("ref".to_string(), ref_.to_string()));
+        }
+        if !state.to_string().is_empty() {
+            query_args.push(("state".to_string(), state.to_string()));
+        }
+        if !tool_guid.is_empty() {
+            query_args.push(("tool_guid".to_string(), tool_guid.to_string()));
+        }
+        if !tool_name.is_empty() {
+            query_args.push(("tool_name".to_string(), tool_name.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/code-scanning/alerts?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                query_
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Get a code scanning alert.
      *
@@ -292,6 +730,33 @@ impl CodeScanning {
             )
             .await
     }
+    /**
+     * List instances of a code scanning alert.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/code-scanning/alerts/{alert_number}/instances` endpoint.
+     *
+     * As opposed to `list_alert_instances`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Lists all instances of the specified code scanning alert. You must use an access token with the `security_events` scope to use this endpoint. GitHub Apps must have the `security_events` read permission to use this endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/code-scanning#list-instances-of-a-code-scanning-alert>
+     */
+    pub fn list_alert_instances_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+        alert_number: i64,
+    ) -> CodeScanningListAlertInstancesRequest<'a> {
+        CodeScanningListAlertInstancesRequest {
+            client: self,
+            owner,
+            repo,
+            alert_number,
+            page: None,
+            per_page: None,
+            ref_: None,
+        }
+    }
     /**
      * List instances of a code scanning alert.
      *
@@ -335,6 +800,42 @@ impl CodeScanning {
             )
             .await
     }
+    /**
+     * List instances of a code scanning alert.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/code-scanning/alerts/{alert_number}/instances` endpoint.
+     *
+     * As opposed to `list_all_alert_instances`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Lists all instances of the specified code scanning alert. You must use an access token with the `security_events` scope to use this endpoint. GitHub Apps must have the `security_events` read permission to use this endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/code-scanning#list-instances-of-a-code-scanning-alert>
+     */
+    pub fn list_all_alert_instances_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        alert_number: i64,
+        ref_: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::CodeScanningAlertInstance>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !ref_.is_empty() {
+            query_args.push(("ref".to_string(), ref_.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/code-scanning/alerts/{}/instances?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&alert_number.to_string()),
+                query_
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * List code scanning analyses for a repository.
      *
@@ -420,6 +921,49 @@ impl CodeScanning {
             )
             .await
     }
+    /**
+     * List code scanning analyses for a repository.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/code-scanning/analyses` endpoint.
+     *
+     * As opposed to `list_recent_analyses`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Lists the details of all code scanning analyses for a repository,
+     * starting with the most recent.
+     * The response is paginated and you can use the `page` and `per_page` parameters
+     * to list the analyses you're interested in.
+     * By default 30 analyses are listed per page.
+     *
+     * The `rules_count` field in the response give the number of rules
+     * that were run in the analysis.
+     * For very old analyses this data is not available,
+     * and `0` is returned in this field.
+     *
+     * You must use an access token with the `security_events` scope to use this endpoint.
+     * GitHub Apps must have the `security_events` read permission to use this endpoint.
+     *
+     * **Deprecation notice**:
+     * The `tool_name` field is deprecated and will, in future, not be included in the response for this endpoint. The example response reflects this change. The tool name can now be found inside the `tool` field.
+     *
+     * FROM: <https://docs.github.com/rest/reference/code-scanning#list-code-scanning-analyses-for-a-repository>
+     */
+    pub fn list_recent_analyses_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+    ) -> CodeScanningListRecentAnalysesRequest<'a> {
+        CodeScanningListRecentAnalysesRequest {
+            client: self,
+            owner,
+            repo,
+            tool_name: None,
+            tool_guid: None,
+            page: None,
+            per_page: None,
+            ref_: None,
+            sarif_id: None,
+        }
+    }
     /**
      * List code scanning analyses for a repository.
      *
@@ -488,6 +1032,67 @@ impl CodeScanning {
             )
             .await
     }
+    /**
+     * List code scanning analyses for a repository.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/code-scanning/analyses` endpoint.
+     *
+     * As opposed to `list_all_recent_analyses`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Lists the details of all code scanning analyses for a repository,
+     * starting with the most recent.
+     * The response is paginated and you can use the `page` and `per_page` parameters
+     * to list the analyses you're interested in.
+     * By default 30 analyses are listed per page.
+     *
+     * The `rules_count` field in the response give the number of rules
+     * that were run in the analysis.
+     * For very old analyses this data is not available,
+     * and `0` is returned in this field.
+     *
+     * You must use an access token with the `security_events` scope to use this endpoint.
+     * GitHub Apps must have the `security_events` read permission to use this endpoint.
+     *
+     * **Deprecation notice**:
+     * The `tool_name` field is deprecated and will, in future, not be included in the response for this endpoint. The example response reflects this change. The tool name can now be found inside the `tool` field.
+     *
+     * FROM: <https://docs.github.com/rest/reference/code-scanning#list-code-scanning-analyses-for-a-repository>
+     */
+    pub fn list_all_recent_analyses_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        tool_name: &str,
+        tool_guid: &str,
+        ref_: &str,
+        sarif_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::CodeScanningAnalysis>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !ref_.is_empty() {
+            query_args.push(("ref".to_string(), ref_.to_string()));
+        }
+        if !sarif_id.is_empty() {
+            query_args.push(("sarif_id".to_string(), sarif_id.to_string()));
+        }
+        if !tool_guid.is_empty() {
+            query_args.push(("tool_guid".to_string(), tool_guid.to_string()));
+        }
+        if !tool_name.is_empty() {
+            query_args.push(("tool_name".to_string(), tool_name.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/code-scanning/analyses?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                query_
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Get a code scanning analysis for a repository.
      *
@@ -659,6 +1264,94 @@ impl CodeScanning {
             )
             .await
     }
+    /**
+     * Delete a code scanning analysis from a repository.
+     *
+     * This function performs a `DELETE` to the `/repos/{owner}/{repo}/code-scanning/analyses/{analysis_id}` endpoint.
+     *
+     * As opposed to `delete_analysis`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Deletes a specified code scanning analysis from a repository. For
+     * private repositories, you must use an access token with the `repo` scope. For public repositories,
+     * you must use an access token with `public_repo` and `repo:security_events` scopes.
+     * GitHub Apps must have the `security_events` write permission to use this endpoint.
+     *
+     * You can delete one analysis at a time.
+     * To delete a series of analyses, start with the most recent analysis and work backwards.
+     * Conceptually, the process is similar to the undo function in a text editor.
+     *
+     * When you list the analyses for a repository,
+     * one or more will be identified as deletable in the response:
+     *
+     * ```
+     * "deletable": true
+     * ```
+     *
+     * An analysis is deletable when it's the most recent in a set of analyses.
+     * Typically, a repository will have multiple sets of analyses
+     * for each enabled code scanning tool,
+     * where a set is determined by a unique combination of analysis values:
+     *
+     * * `ref`
+     * * `tool`
+     * * `analysis_key`
+     * * `environment`
+     *
+     * If you attempt to delete an analysis that is not the most recent in a set,
+     * you'll get a 400 response with the message:
+     *
+     * ```
+     * Analysis specified is not deletable.
+     * ```
+     *
+     * The response from a successful `DELETE` operation provides you with
+     * two alternative URLs for deleting the next analysis in the set
+     * (see the example default response below).
+     * Use the `next_analysis_url` URL if you want to avoid accidentally deleting the final analysis
+     * in the set. This is a useful option if you want to preserve at least one analysis
+     * for the specified tool in your repository.
+     * Use the `confirm_delete_url` URL if you are content to remove all analyses for a tool.
+     * When you delete the last analysis in a set the value of `next_analysis_url` and `confirm_delete_url`
+     * in the 200 response is `null`.
+     *
+     * As an example of the deletion process,
+     * let's imagine that you added a workflow that configured a particular code scanning tool
+     * to analyze the code in a repository. This tool has added 15 analyses:
+     * 10 on the default branch, and another 5 on a topic branch.
+     * You therefore have two separate sets of analyses for this tool.
+     * You've now decided that you want to remove all of the analyses for the tool.
+     * To do this you must make 15 separate deletion requests.
+     * To start, you must find the deletable analysis for one of the sets,
+     * step through deleting the analyses in that set,
+     * and then repeat the process for the second set.
+     * The procedure therefore consists of a nested loop:
+     *
+     * **Outer loop**:
+     * * List the analyses for the repository, filtered by tool.
+     * * Parse this list to find a deletable analysis. If found:
+     *
+     *   **Inner loop**:
+     *   * Delete the identified analysis.
+     *   * Parse the response for the value of `confirm_delete_url` and, if found, use this in the next iteration.
+     *
+     * The above process assumes that you want to remove all trace of the tool's analyses from the GitHub user interface, for the specified repository, and it therefore uses the `confirm_delete_url` value. Alternatively, you could use the `next_analysis_url` value, which would leave the last analysis in each set undeleted to avoid removing a tool's analysis entirely.
+     *
+     * FROM: <https://docs.github.com/rest/reference/code-scanning#delete-a-code-scanning-analysis-from-a-repository>
+     */
+    pub fn delete_analysis_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+        analysis_id: i64,
+    ) -> CodeScanningDeleteAnalysisRequest<'a> {
+        CodeScanningDeleteAnalysisRequest {
+            client: self,
+            owner,
+            repo,
+            analysis_id,
+            confirm_delete: None,
+        }
+    }
     /**
      * Upload an analysis as SARIF data.
      *
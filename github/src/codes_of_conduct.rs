@@ -59,6 +59,23 @@ impl CodesOfConduct {
             )
             .await
     }
+    /**
+     * Get all codes of conduct.
+     *
+     * This function performs a `GET` to the `/codes_of_conduct` endpoint.
+     *
+     * As opposed to `get_all_all_codes_of_conduct`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/codes-of-conduct#get-all-codes-of-conduct>
+     */
+    pub fn get_all_all_codes_of_conduct_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::CodeOfConduct>> + Unpin + '_ {
+        let url = self.client.url("/codes_of_conduct", None);
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Get a code of conduct.
      *
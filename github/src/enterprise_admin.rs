@@ -1,6 +1,643 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`EnterpriseAdmin::list_selected_organizations_enabled_github_actions_enterprise`], set with a builder.
+pub struct EnterpriseAdminListSelectedOrganizationsEnabledGithubActionsEnterpriseRequest<'a> {
+    client: &'a EnterpriseAdmin,
+    enterprise: &'a str,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> EnterpriseAdminListSelectedOrganizationsEnabledGithubActionsEnterpriseRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<
+        crate::Response<crate::types::EnterpriseAdminListOrgAccessSelfHostedRunnerGroupInResponse>,
+    > {
+        let Self {
+            client,
+            enterprise,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/enterprises/{}/actions/permissions/organizations?{}",
+                crate::progenitor_support::encode_path(enterprise),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`EnterpriseAdmin::list_self_hosted_runner_groups_for_enterprise`], set with a builder.
+pub struct EnterpriseAdminListSelfHostedRunnerGroupsForEnterpriseRequest<'a> {
+    client: &'a EnterpriseAdmin,
+    enterprise: &'a str,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> EnterpriseAdminListSelfHostedRunnerGroupsForEnterpriseRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<
+        crate::Response<crate::types::EnterpriseAdminListSelfHostedRunnerGroupsResponse>,
+    > {
+        let Self {
+            client,
+            enterprise,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/enterprises/{}/actions/runner-groups?{}",
+                crate::progenitor_support::encode_path(enterprise),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`EnterpriseAdmin::list_org_access_to_self_hosted_runner_group_in_enterprise`], set with a builder.
+pub struct EnterpriseAdminListOrgAccessToSelfHostedRunnerGroupInEnterpriseRequest<'a> {
+    client: &'a EnterpriseAdmin,
+    enterprise: &'a str,
+    runner_group_id: i64,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> EnterpriseAdminListOrgAccessToSelfHostedRunnerGroupInEnterpriseRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<
+        crate::Response<crate::types::EnterpriseAdminListOrgAccessSelfHostedRunnerGroupInResponse>,
+    > {
+        let Self {
+            client,
+            enterprise,
+            runner_group_id,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/enterprises/{}/actions/runner-groups/{}/organizations?{}",
+                crate::progenitor_support::encode_path(enterprise),
+                crate::progenitor_support::encode_path(&runner_group_id.to_string()),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`EnterpriseAdmin::list_self_hosted_runners_in_group_for_enterprise`], set with a builder.
+pub struct EnterpriseAdminListSelfHostedRunnersInGroupForEnterpriseRequest<'a> {
+    client: &'a EnterpriseAdmin,
+    enterprise: &'a str,
+    runner_group_id: i64,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> EnterpriseAdminListSelfHostedRunnersInGroupForEnterpriseRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::ActionsListSelfHostedRunnersInGroupOrgResponse>>
+    {
+        let Self {
+            client,
+            enterprise,
+            runner_group_id,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/enterprises/{}/actions/runner-groups/{}/runners?{}",
+                crate::progenitor_support::encode_path(enterprise),
+                crate::progenitor_support::encode_path(&runner_group_id.to_string()),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`EnterpriseAdmin::list_self_hosted_runners_for_enterprise`], set with a builder.
+pub struct EnterpriseAdminListSelfHostedRunnersForEnterpriseRequest<'a> {
+    client: &'a EnterpriseAdmin,
+    enterprise: &'a str,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> EnterpriseAdminListSelfHostedRunnersForEnterpriseRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::EnterpriseAdminListSelfHostedRunnersResponse>>
+    {
+        let Self {
+            client,
+            enterprise,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/enterprises/{}/actions/runners?{}",
+                crate::progenitor_support::encode_path(enterprise),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`EnterpriseAdmin::get_audit_log`], set with a builder.
+pub struct EnterpriseAdminGetAuditLogRequest<'a> {
+    client: &'a EnterpriseAdmin,
+    enterprise: &'a str,
+    phrase: Option<&'a str>,
+    include: Option<crate::types::Include>,
+    after: Option<&'a str>,
+    before: Option<&'a str>,
+    order: Option<crate::types::Order>,
+    page: Option<i64>,
+    per_page: Option<i64>,
+}
+
+impl<'a> EnterpriseAdminGetAuditLogRequest<'a> {
+    /// Sets the `phrase` query parameter.
+    pub fn phrase(mut self, phrase: &'a str) -> Self {
+        self.phrase = Some(phrase);
+        self
+    }
+
+    /// Sets the `include` query parameter.
+    pub fn include(mut self, include: crate::types::Include) -> Self {
+        self.include = Some(include);
+        self
+    }
+
+    /// Sets the `after` query parameter.
+    pub fn after(mut self, after: &'a str) -> Self {
+        self.after = Some(after);
+        self
+    }
+
+    /// Sets the `before` query parameter.
+    pub fn before(mut self, before: &'a str) -> Self {
+        self.before = Some(before);
+        self
+    }
+
+    /// Sets the `order` query parameter.
+    pub fn order(mut self, order: crate::types::Order) -> Self {
+        self.order = Some(order);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::AuditLogEvent>>> {
+        let Self {
+            client,
+            enterprise,
+            phrase,
+            include,
+            after,
+            before,
+            order,
+            page,
+            per_page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = after {
+            query_args.push(("after".to_string(), v.to_string()));
+        }
+        if let Some(v) = before {
+            query_args.push(("before".to_string(), v.to_string()));
+        }
+        if let Some(v) = include {
+            query_args.push(("include".to_string(), v.to_string()));
+        }
+        if let Some(v) = order {
+            query_args.push(("order".to_string(), v.to_string()));
+        }
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        if let Some(v) = phrase {
+            query_args.push(("phrase".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/enterprises/{}/audit-log?{}",
+                crate::progenitor_support::encode_path(enterprise),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`EnterpriseAdmin::list_provisioned_groups_enterprise`], set with a builder.
+pub struct EnterpriseAdminListProvisionedGroupsEnterpriseRequest<'a> {
+    client: &'a EnterpriseAdmin,
+    enterprise: &'a str,
+    start_index: Option<i64>,
+    count: Option<i64>,
+    filter: Option<&'a str>,
+    excluded_attributes: Option<&'a str>,
+}
+
+impl<'a> EnterpriseAdminListProvisionedGroupsEnterpriseRequest<'a> {
+    /// Sets the `startIndex` query parameter.
+    pub fn start_index(mut self, start_index: i64) -> Self {
+        self.start_index = Some(start_index);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Sets the `filter` query parameter.
+    pub fn filter(mut self, filter: &'a str) -> Self {
+        self.filter = Some(filter);
+        self
+    }
+
+    /// Sets the `excludedAttributes` query parameter.
+    pub fn excluded_attributes(mut self, excluded_attributes: &'a str) -> Self {
+        self.excluded_attributes = Some(excluded_attributes);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::ScimGroupListEnterprise>> {
+        let Self {
+            client,
+            enterprise,
+            start_index,
+            count,
+            filter,
+            excluded_attributes,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = count {
+            query_args.push(("count".to_string(), v.to_string()));
+        }
+        if let Some(v) = excluded_attributes {
+            query_args.push(("excludedAttributes".to_string(), v.to_string()));
+        }
+        if let Some(v) = filter {
+            query_args.push(("filter".to_string(), v.to_string()));
+        }
+        if let Some(v) = start_index {
+            query_args.push(("startIndex".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/scim/v2/enterprises/{}/Groups?{}",
+                crate::progenitor_support::encode_path(enterprise),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`EnterpriseAdmin::get_provisioning_information_for_enterprise_group`], set with a builder.
+pub struct EnterpriseAdminGetProvisioningInformationForEnterpriseGroupRequest<'a> {
+    client: &'a EnterpriseAdmin,
+    enterprise: &'a str,
+    scim_group_id: &'a str,
+    excluded_attributes: Option<&'a str>,
+}
+
+impl<'a> EnterpriseAdminGetProvisioningInformationForEnterpriseGroupRequest<'a> {
+    /// Sets the `excludedAttributes` query parameter.
+    pub fn excluded_attributes(mut self, excluded_attributes: &'a str) -> Self {
+        self.excluded_attributes = Some(excluded_attributes);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::ScimEnterpriseGroup>> {
+        let Self {
+            client,
+            enterprise,
+            scim_group_id,
+            excluded_attributes,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = excluded_attributes {
+            query_args.push(("excludedAttributes".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/scim/v2/enterprises/{}/Groups/{}?{}",
+                crate::progenitor_support::encode_path(enterprise),
+                crate::progenitor_support::encode_path(scim_group_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`EnterpriseAdmin::list_provisioned_identities_enterprise`], set with a builder.
+pub struct EnterpriseAdminListProvisionedIdentitiesEnterpriseRequest<'a> {
+    client: &'a EnterpriseAdmin,
+    enterprise: &'a str,
+    start_index: Option<i64>,
+    count: Option<i64>,
+    filter: Option<&'a str>,
+}
+
+impl<'a> EnterpriseAdminListProvisionedIdentitiesEnterpriseRequest<'a> {
+    /// Sets the `startIndex` query parameter.
+    pub fn start_index(mut self, start_index: i64) -> Self {
+        self.start_index = Some(start_index);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Sets the `filter` query parameter.
+    pub fn filter(mut self, filter: &'a str) -> Self {
+        self.filter = Some(filter);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::ScimUserListEnterprise>> {
+        let Self {
+            client,
+            enterprise,
+            start_index,
+            count,
+            filter,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = count {
+            query_args.push(("count".to_string(), v.to_string()));
+        }
+        if let Some(v) = filter {
+            query_args.push(("filter".to_string(), v.to_string()));
+        }
+        if let Some(v) = start_index {
+            query_args.push(("startIndex".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/scim/v2/enterprises/{}/Users?{}",
+                crate::progenitor_support::encode_path(enterprise),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct EnterpriseAdmin {
     pub client: Client,
 }
@@ -135,6 +772,30 @@ impl EnterpriseAdmin {
             )
             .await
     }
+    /**
+     * List selected organizations enabled for GitHub Actions in an enterprise.
+     *
+     * This function performs a `GET` to the `/enterprises/{enterprise}/actions/permissions/organizations` endpoint.
+     *
+     * As opposed to `list_selected_organizations_enabled_github_actions_enterprise`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Lists the organizations that are selected to have GitHub Actions enabled in an enterprise. To use this endpoint, the enterprise permission policy for `enabled_organizations` must be configured to `selected`. For more information, see "[Set GitHub Actions permissions for an enterprise](#set-github-actions-permissions-for-an-enterprise)."
+     *
+     * You must authenticate using an access token with the `admin:enterprise` scope to use this endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/enterprise-admin#list-selected-organizations-enabled-for-github-actions-in-an-enterprise>
+     */
+    pub fn list_selected_organizations_enabled_github_actions_enterprise_request<'a>(
+        &'a self,
+        enterprise: &'a str,
+    ) -> EnterpriseAdminListSelectedOrganizationsEnabledGithubActionsEnterpriseRequest<'a> {
+        EnterpriseAdminListSelectedOrganizationsEnabledGithubActionsEnterpriseRequest {
+            client: self,
+            enterprise,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * Set selected organizations enabled for GitHub Actions in an enterprise.
      *
@@ -374,6 +1035,30 @@ impl EnterpriseAdmin {
             )
             .await
     }
+    /**
+     * List self-hosted runner groups for an enterprise.
+     *
+     * This function performs a `GET` to the `/enterprises/{enterprise}/actions/runner-groups` endpoint.
+     *
+     * As opposed to `list_self_hosted_runner_groups_for_enterprise`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Lists all self-hosted runner groups for an enterprise.
+     *
+     * You must authenticate using an access token with the `admin:enterprise` scope to use this endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/enterprise-admin#list-self-hosted-runner-groups-for-an-enterprise>
+     */
+    pub fn list_self_hosted_runner_groups_for_enterprise_request<'a>(
+        &'a self,
+        enterprise: &'a str,
+    ) -> EnterpriseAdminListSelfHostedRunnerGroupsForEnterpriseRequest<'a> {
+        EnterpriseAdminListSelfHostedRunnerGroupsForEnterpriseRequest {
+            client: self,
+            enterprise,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * Create a self-hosted runner group for an enterprise.
      *
@@ -583,6 +1268,32 @@ impl EnterpriseAdmin {
             )
             .await
     }
+    /**
+     * List organization access to a self-hosted runner group in an enterprise.
+     *
+     * This function performs a `GET` to the `/enterprises/{enterprise}/actions/runner-groups/{runner_group_id}/organizations` endpoint.
+     *
+     * As opposed to `list_org_access_to_self_hosted_runner_group_in_enterprise`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Lists the organizations with access to a self-hosted runner group.
+     *
+     * You must authenticate using an access token with the `admin:enterprise` scope to use this endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/enterprise-admin#list-organization-access-to-a-self-hosted-runner-group-in-a-enterprise>
+     */
+    pub fn list_org_access_to_self_hosted_runner_group_in_enterprise_request<'a>(
+        &'a self,
+        enterprise: &'a str,
+        runner_group_id: i64,
+    ) -> EnterpriseAdminListOrgAccessToSelfHostedRunnerGroupInEnterpriseRequest<'a> {
+        EnterpriseAdminListOrgAccessToSelfHostedRunnerGroupInEnterpriseRequest {
+            client: self,
+            enterprise,
+            runner_group_id,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * Set organization access for a self-hosted runner group in an enterprise.
      *
@@ -760,6 +1471,32 @@ impl EnterpriseAdmin {
             )
             .await
     }
+    /**
+     * List self-hosted runners in a group for an enterprise.
+     *
+     * This function performs a `GET` to the `/enterprises/{enterprise}/actions/runner-groups/{runner_group_id}/runners` endpoint.
+     *
+     * As opposed to `list_self_hosted_runners_in_group_for_enterprise`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Lists the self-hosted runners that are in a specific enterprise group.
+     *
+     * You must authenticate using an access token with the `admin:enterprise` scope to use this endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/enterprise-admin#list-self-hosted-runners-in-a-group-for-an-enterprise>
+     */
+    pub fn list_self_hosted_runners_in_group_for_enterprise_request<'a>(
+        &'a self,
+        enterprise: &'a str,
+        runner_group_id: i64,
+    ) -> EnterpriseAdminListSelfHostedRunnersInGroupForEnterpriseRequest<'a> {
+        EnterpriseAdminListSelfHostedRunnersInGroupForEnterpriseRequest {
+            client: self,
+            enterprise,
+            runner_group_id,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * Set self-hosted runners in a group for an enterprise.
      *
@@ -935,6 +1672,30 @@ impl EnterpriseAdmin {
             )
             .await
     }
+    /**
+     * List self-hosted runners for an enterprise.
+     *
+     * This function performs a `GET` to the `/enterprises/{enterprise}/actions/runners` endpoint.
+     *
+     * As opposed to `list_self_hosted_runners_for_enterprise`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Lists all self-hosted runners configured for an enterprise.
+     *
+     * You must authenticate using an access token with the `admin:enterprise` scope to use this endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/enterprise-admin#list-self-hosted-runners-for-an-enterprise>
+     */
+    pub fn list_self_hosted_runners_for_enterprise_request<'a>(
+        &'a self,
+        enterprise: &'a str,
+    ) -> EnterpriseAdminListSelfHostedRunnersForEnterpriseRequest<'a> {
+        EnterpriseAdminListSelfHostedRunnersForEnterpriseRequest {
+            client: self,
+            enterprise,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List runner applications for an enterprise.
      *
@@ -1005,6 +1766,33 @@ impl EnterpriseAdmin {
             )
             .await
     }
+    /**
+     * List runner applications for an enterprise.
+     *
+     * This function performs a `GET` to the `/enterprises/{enterprise}/actions/runners/downloads` endpoint.
+     *
+     * As opposed to `list_all_runner_applications_for_enterprise`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Lists binaries for the runner application that you can download and run.
+     *
+     * You must authenticate using an access token with the `admin:enterprise` scope to use this endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/enterprise-admin#list-runner-applications-for-an-enterprise>
+     */
+    pub fn list_all_runner_applications_for_enterprise_stream(
+        &self,
+        enterprise: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::RunnerApplication>> + Unpin + '_
+    {
+        let url = self.client.url(
+            &format!(
+                "/enterprises/{}/actions/runners/downloads",
+                crate::progenitor_support::encode_path(enterprise),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Create a registration token for an enterprise.
      *
@@ -1252,6 +2040,33 @@ impl EnterpriseAdmin {
             )
             .await
     }
+    /**
+     * Get the audit log for an enterprise.
+     *
+     * This function performs a `GET` to the `/enterprises/{enterprise}/audit-log` endpoint.
+     *
+     * As opposed to `get_audit_log`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Gets the audit log for an enterprise. To use this endpoint, you must be an enterprise admin, and you must use an access token with the `admin:enterprise` scope.
+     *
+     * FROM: <https://docs.github.com/rest/reference/enterprise-admin#get-the-audit-log-for-an-enterprise>
+     */
+    pub fn get_audit_log_request<'a>(
+        &'a self,
+        enterprise: &'a str,
+    ) -> EnterpriseAdminGetAuditLogRequest<'a> {
+        EnterpriseAdminGetAuditLogRequest {
+            client: self,
+            enterprise,
+            phrase: None,
+            include: None,
+            after: None,
+            before: None,
+            order: None,
+            page: None,
+            per_page: None,
+        }
+    }
     /**
      * Get the audit log for an enterprise.
      *
@@ -1307,6 +2122,53 @@ impl EnterpriseAdmin {
             )
             .await
     }
+    /**
+     * Get the audit log for an enterprise.
+     *
+     * This function performs a `GET` to the `/enterprises/{enterprise}/audit-log` endpoint.
+     *
+     * As opposed to `get_all_audit_log`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Gets the audit log for an enterprise. To use this endpoint, you must be an enterprise admin, and you must use an access token with the `admin:enterprise` scope.
+     *
+     * FROM: <https://docs.github.com/rest/reference/enterprise-admin#get-the-audit-log-for-an-enterprise>
+     */
+    pub fn get_all_audit_log_stream(
+        &self,
+        enterprise: &str,
+        phrase: &str,
+        include: crate::types::Include,
+        after: &str,
+        before: &str,
+        order: crate::types::Order,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::AuditLogEvent>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !after.is_empty() {
+            query_args.push(("after".to_string(), after.to_string()));
+        }
+        if !before.is_empty() {
+            query_args.push(("before".to_string(), before.to_string()));
+        }
+        if !include.to_string().is_empty() {
+            query_args.push(("include".to_string(), include.to_string()));
+        }
+        if !order.to_string().is_empty() {
+            query_args.push(("order".to_string(), order.to_string()));
+        }
+        if !phrase.is_empty() {
+            query_args.push(("phrase".to_string(), phrase.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/enterprises/{}/audit-log?{}",
+                crate::progenitor_support::encode_path(enterprise),
+                query_
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * List provisioned SCIM groups for an enterprise.
      *
@@ -1367,6 +2229,30 @@ impl EnterpriseAdmin {
             )
             .await
     }
+    /**
+     * List provisioned SCIM groups for an enterprise.
+     *
+     * This function performs a `GET` to the `/scim/v2/enterprises/{enterprise}/Groups` endpoint.
+     *
+     * As opposed to `list_provisioned_groups_enterprise`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * **Note:** The SCIM API endpoints for enterprise accounts are currently in beta and are subject to change.
+     *
+     * FROM: <https://docs.github.com/rest/reference/enterprise-admin#list-provisioned-scim-groups-for-an-enterprise>
+     */
+    pub fn list_provisioned_groups_enterprise_request<'a>(
+        &'a self,
+        enterprise: &'a str,
+    ) -> EnterpriseAdminListProvisionedGroupsEnterpriseRequest<'a> {
+        EnterpriseAdminListProvisionedGroupsEnterpriseRequest {
+            client: self,
+            enterprise,
+            start_index: None,
+            count: None,
+            filter: None,
+            excluded_attributes: None,
+        }
+    }
     /**
      * Provision a SCIM enterprise group and invite users.
      *
@@ -1452,6 +2338,29 @@ impl EnterpriseAdmin {
             )
             .await
     }
+    /**
+     * Get SCIM provisioning information for an enterprise group.
+     *
+     * This function performs a `GET` to the `/scim/v2/enterprises/{enterprise}/Groups/{scim_group_id}` endpoint.
+     *
+     * As opposed to `get_provisioning_information_for_enterprise_group`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * **Note:** The SCIM API endpoints for enterprise accounts are currently in beta and are subject to change.
+     *
+     * FROM: <https://docs.github.com/rest/reference/enterprise-admin#get-scim-provisioning-information-for-an-enterprise-group>
+     */
+    pub fn get_provisioning_information_for_enterprise_group_request<'a>(
+        &'a self,
+        enterprise: &'a str,
+        scim_group_id: &'a str,
+    ) -> EnterpriseAdminGetProvisioningInformationForEnterpriseGroupRequest<'a> {
+        EnterpriseAdminGetProvisioningInformationForEnterpriseGroupRequest {
+            client: self,
+            enterprise,
+            scim_group_id,
+            excluded_attributes: None,
+        }
+    }
     /**
      * Set SCIM information for a provisioned enterprise group.
      *
@@ -1638,6 +2547,46 @@ impl EnterpriseAdmin {
             )
             .await
     }
+    /**
+     * List SCIM provisioned identities for an enterprise.
+     *
+     * This function performs a `GET` to the `/scim/v2/enterprises/{enterprise}/Users` endpoint.
+     *
+     * As opposed to `list_provisioned_identities_enterprise`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * **Note:** The SCIM API endpoints for enterprise accounts are currently in beta and are subject to change.
+     *
+     * Retrieves a paginated list of all provisioned enterprise members, including pending invitations.
+     *
+     * When a user with a SAML-provisioned external identity leaves (or is removed from) an enterprise, the account's metadata is immediately removed. However, the returned list of user accounts might not always match the organization or enterprise member list you see on GitHub. This can happen in certain cases where an external identity associated with an organization will not match an organization member:
+     *   - When a user with a SCIM-provisioned external identity is removed from an enterprise, the account's metadata is preserved to allow the user to re-join the organization in the future.
+     *   - When inviting a user to join an organization, you can expect to see their external identity in the results before they accept the invitation, or if the invitation is cancelled (or never accepted).
+     *   - When a user is invited over SCIM, an external identity is created that matches with the invitee's email address. However, this identity is only linked to a user account when the user accepts the invitation by going through SAML SSO.
+     *
+     * The returned list of external identities can include an entry for a `null` user. These are unlinked SAML identities that are created when a user goes through the following Single Sign-On (SSO) process but does not sign in to their GitHub account after completing SSO:
+     *
+     * 1. The user is granted access by the IdP and is not a member of the GitHub enterprise.
+     *
+     * 1. The user attempts to access the GitHub enterprise and initiates the SAML SSO process, and is not currently signed in to their GitHub account.
+     *
+     * 1. After successfully authenticating with the SAML SSO IdP, the `null` external identity entry is created and the user is prompted to sign in to their GitHub account:
+     *    - If the user signs in, their GitHub account is linked to this entry.
+     *    - If the user does not sign in (or does not create a new account when prompted), they are not added to the GitHub enterprise, and the external identity `null` entry remains in place.
+     *
+     * FROM: <https://docs.github.com/rest/reference/enterprise-admin#list-scim-provisioned-identities-for-an-enterprise>
+     */
+    pub fn list_provisioned_identities_enterprise_request<'a>(
+        &'a self,
+        enterprise: &'a str,
+    ) -> EnterpriseAdminListProvisionedIdentitiesEnterpriseRequest<'a> {
+        EnterpriseAdminListProvisionedIdentitiesEnterpriseRequest {
+            client: self,
+            enterprise,
+            start_index: None,
+            count: None,
+            filter: None,
+        }
+    }
     /**
      * Provision and invite a SCIM enterprise user.
      *
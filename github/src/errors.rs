@@ -0,0 +1,127 @@
+//! The errors returned by the API.
+
+use std::fmt;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// An error returned by the GitHub API.
+///
+/// FROM: <https://docs.github.com/en/rest/overview/resources-in-the-rest-api#client-errors>
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default, JsonSchema)]
+pub struct ApiError {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub message: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub documentation_url: String,
+    /// The validation errors of a `422 Unprocessable Entity` response.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<ApiErrorDetail>,
+}
+
+/// A validation error of a resource sent to the GitHub API.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default, JsonSchema)]
+pub struct ApiErrorDetail {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub resource: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub field: String,
+    /// What is wrong with the field: `missing`, `missing_field`, `invalid`,
+    /// `already_exists`, `unprocessable` or `custom`.
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub code: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub message: String,
+}
+
+impl ApiError {
+    fn from_body(body: &[u8]) -> Option<Self> {
+        serde_json::from_slice::<Self>(body)
+            .ok()
+            .filter(|error| !error.message.is_empty())
+    }
+}
+
+impl fmt::Display for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)?;
+        for error in &self.errors {
+            if error.message.is_empty() {
+                write!(f, "; {}.{}: {}", error.resource, error.field, error.code)?;
+            } else {
+                write!(f, "; {}", error.message)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl crate::ClientError {
+    /// Turns an unsuccessful response into an error, parsing its body into an `ApiError`
+    /// when it holds one. Responses to requests that were rate limited become
+    /// `ClientError::RateLimited`, as are those forbidden once the
+    /// rate limit is used up. Those keep their `ApiError` as well.
+    pub(crate) fn from_response(
+        status: http::StatusCode,
+        headers: reqwest::header::HeaderMap,
+        body: &[u8],
+    ) -> Self {
+        let rate_limit = crate::RateLimitInfo::from_headers(&headers).unwrap_or_default();
+        if status == http::StatusCode::TOO_MANY_REQUESTS
+            || (status == http::StatusCode::FORBIDDEN && rate_limit.remaining == Some(0))
+        {
+            return crate::ClientError::RateLimited {
+                status,
+                duration: rate_limit.wait().map(|wait| wait.as_secs()),
+                info: rate_limit,
+                error: ApiError::from_body(body).map(Box::new),
+                headers,
+            };
+        }
+
+        if body.is_empty() {
+            return crate::ClientError::HttpError {
+                status,
+                headers,
+                error: "empty response".into(),
+            };
+        }
+
+        match ApiError::from_body(body) {
+            Some(error) => crate::ClientError::ApiError {
+                status,
+                headers,
+                error: Box::new(error),
+            },
+            None => crate::ClientError::HttpError {
+                status,
+                headers,
+                error: String::from_utf8_lossy(body).into(),
+            },
+        }
+    }
+}
@@ -1,6 +1,440 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Gists::list`], set with a builder.
+pub struct GistsListRequest<'a> {
+    client: &'a Gists,
+    since: Option<chrono::DateTime<chrono::Utc>>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> GistsListRequest<'a> {
+    /// Sets the `since` query parameter.
+    pub fn since(mut self, since: chrono::DateTime<chrono::Utc>) -> Self {
+        self.since = Some(since);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::BaseGist>>> {
+        let Self {
+            client,
+            since,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        if let Some(date) = since {
+            query_args.push(("since".to_string(), date.to_rfc3339()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(&format!("/gists?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Gists::list_public`], set with a builder.
+pub struct GistsListPublicRequest<'a> {
+    client: &'a Gists,
+    since: Option<chrono::DateTime<chrono::Utc>>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> GistsListPublicRequest<'a> {
+    /// Sets the `since` query parameter.
+    pub fn since(mut self, since: chrono::DateTime<chrono::Utc>) -> Self {
+        self.since = Some(since);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::BaseGist>>> {
+        let Self {
+            client,
+            since,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        if let Some(date) = since {
+            query_args.push(("since".to_string(), date.to_rfc3339()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/gists/public?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Gists::list_starred`], set with a builder.
+pub struct GistsListStarredRequest<'a> {
+    client: &'a Gists,
+    since: Option<chrono::DateTime<chrono::Utc>>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> GistsListStarredRequest<'a> {
+    /// Sets the `since` query parameter.
+    pub fn since(mut self, since: chrono::DateTime<chrono::Utc>) -> Self {
+        self.since = Some(since);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::BaseGist>>> {
+        let Self {
+            client,
+            since,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        if let Some(date) = since {
+            query_args.push(("since".to_string(), date.to_rfc3339()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/gists/starred?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Gists::list_comments`], set with a builder.
+pub struct GistsListCommentsRequest<'a> {
+    client: &'a Gists,
+    gist_id: &'a str,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> GistsListCommentsRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::GistComment>>> {
+        let Self {
+            client,
+            gist_id,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/gists/{}/comments?{}",
+                crate::progenitor_support::encode_path(gist_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Gists::list_commits`], set with a builder.
+pub struct GistsListCommitsRequest<'a> {
+    client: &'a Gists,
+    gist_id: &'a str,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> GistsListCommitsRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::GistCommit>>> {
+        let Self {
+            client,
+            gist_id,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/gists/{}/commits?{}",
+                crate::progenitor_support::encode_path(gist_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Gists::list_forks`], set with a builder.
+pub struct GistsListForksRequest<'a> {
+    client: &'a Gists,
+    gist_id: &'a str,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> GistsListForksRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::GistSimple>>> {
+        let Self {
+            client,
+            gist_id,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/gists/{}/forks?{}",
+                crate::progenitor_support::encode_path(gist_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Gists::list_for_user`], set with a builder.
+pub struct GistsListForUserRequest<'a> {
+    client: &'a Gists,
+    username: &'a str,
+    since: Option<chrono::DateTime<chrono::Utc>>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> GistsListForUserRequest<'a> {
+    /// Sets the `since` query parameter.
+    pub fn since(mut self, since: chrono::DateTime<chrono::Utc>) -> Self {
+        self.since = Some(since);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::BaseGist>>> {
+        let Self {
+            client,
+            username,
+            since,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        if let Some(date) = since {
+            query_args.push(("since".to_string(), date.to_rfc3339()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/users/{}/gists?{}",
+                crate::progenitor_support::encode_path(username),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct Gists {
     pub client: Client,
 }
@@ -54,6 +488,25 @@ impl Gists {
             )
             .await
     }
+    /**
+     * List gists for the authenticated user.
+     *
+     * This function performs a `GET` to the `/gists` endpoint.
+     *
+     * As opposed to `list`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Lists the authenticated user's gists or if called anonymously, this endpoint returns all public gists:
+     *
+     * FROM: <https://docs.github.com/rest/reference/gists#list-gists-for-the-authenticated-user>
+     */
+    pub fn list_request(&self) -> GistsListRequest<'_> {
+        GistsListRequest {
+            client: self,
+            since: None,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List gists for the authenticated user.
      *
@@ -85,6 +538,29 @@ impl Gists {
             )
             .await
     }
+    /**
+     * List gists for the authenticated user.
+     *
+     * This function performs a `GET` to the `/gists` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Lists the authenticated user's gists or if called anonymously, this endpoint returns all public gists:
+     *
+     * FROM: <https://docs.github.com/rest/reference/gists#list-gists-for-the-authenticated-user>
+     */
+    pub fn list_all_stream(
+        &self,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::BaseGist>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(date) = since {
+            query_args.push(("since".to_string(), date.to_rfc3339()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/gists?{}", query_), None);
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Create a gist.
      *
@@ -156,6 +632,27 @@ impl Gists {
             )
             .await
     }
+    /**
+     * List public gists.
+     *
+     * This function performs a `GET` to the `/gists/public` endpoint.
+     *
+     * As opposed to `list_public`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * List public gists sorted by most recently updated to least recently updated.
+     *
+     * Note: With [pagination](https://docs.github.com/rest/overview/resources-in-the-rest-api#pagination), you can fetch up to 3000 gists. For example, you can fetch 100 pages with 30 gists per page or 30 pages with 100 gists per page.
+     *
+     * FROM: <https://docs.github.com/rest/reference/gists#list-public-gists>
+     */
+    pub fn list_public_request(&self) -> GistsListPublicRequest<'_> {
+        GistsListPublicRequest {
+            client: self,
+            since: None,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List public gists.
      *
@@ -189,6 +686,31 @@ impl Gists {
             )
             .await
     }
+    /**
+     * List public gists.
+     *
+     * This function performs a `GET` to the `/gists/public` endpoint.
+     *
+     * As opposed to `list_all_public`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * List public gists sorted by most recently updated to least recently updated.
+     *
+     * Note: With [pagination](https://docs.github.com/rest/overview/resources-in-the-rest-api#pagination), you can fetch up to 3000 gists. For example, you can fetch 100 pages with 30 gists per page or 30 pages with 100 gists per page.
+     *
+     * FROM: <https://docs.github.com/rest/reference/gists#list-public-gists>
+     */
+    pub fn list_all_public_stream(
+        &self,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::BaseGist>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(date) = since {
+            query_args.push(("since".to_string(), date.to_rfc3339()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/gists/public?{}", query_), None);
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * List starred gists.
      *
@@ -232,6 +754,25 @@ impl Gists {
             )
             .await
     }
+    /**
+     * List starred gists.
+     *
+     * This function performs a `GET` to the `/gists/starred` endpoint.
+     *
+     * As opposed to `list_starred`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * List the authenticated user's starred gists:
+     *
+     * FROM: <https://docs.github.com/rest/reference/gists#list-starred-gists>
+     */
+    pub fn list_starred_request(&self) -> GistsListStarredRequest<'_> {
+        GistsListStarredRequest {
+            client: self,
+            since: None,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List starred gists.
      *
@@ -263,6 +804,29 @@ impl Gists {
             )
             .await
     }
+    /**
+     * List starred gists.
+     *
+     * This function performs a `GET` to the `/gists/starred` endpoint.
+     *
+     * As opposed to `list_all_starred`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * List the authenticated user's starred gists:
+     *
+     * FROM: <https://docs.github.com/rest/reference/gists#list-starred-gists>
+     */
+    pub fn list_all_starred_stream(
+        &self,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::BaseGist>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(date) = since {
+            query_args.push(("since".to_string(), date.to_rfc3339()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/gists/starred?{}", query_), None);
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Get a gist.
      *
@@ -401,6 +965,25 @@ impl Gists {
             )
             .await
     }
+    /**
+     * List gist comments.
+     *
+     * This function performs a `GET` to the `/gists/{gist_id}/comments` endpoint.
+     *
+     * As opposed to `list_comments`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/gists#list-gist-comments>
+     */
+    pub fn list_comments_request<'a>(&'a self, gist_id: &'a str) -> GistsListCommentsRequest<'a> {
+        GistsListCommentsRequest {
+            client: self,
+            gist_id,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List gist comments.
      *
@@ -433,6 +1016,30 @@ impl Gists {
             )
             .await
     }
+    /**
+     * List gist comments.
+     *
+     * This function performs a `GET` to the `/gists/{gist_id}/comments` endpoint.
+     *
+     * As opposed to `list_all_comments`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/gists#list-gist-comments>
+     */
+    pub fn list_all_comments_stream(
+        &self,
+        gist_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::GistComment>> + Unpin + '_ {
+        let url = self.client.url(
+            &format!(
+                "/gists/{}/comments",
+                crate::progenitor_support::encode_path(gist_id),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Create a gist comment.
      *
@@ -627,6 +1234,25 @@ impl Gists {
             )
             .await
     }
+    /**
+     * List gist commits.
+     *
+     * This function performs a `GET` to the `/gists/{gist_id}/commits` endpoint.
+     *
+     * As opposed to `list_commits`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/gists#list-gist-commits>
+     */
+    pub fn list_commits_request<'a>(&'a self, gist_id: &'a str) -> GistsListCommitsRequest<'a> {
+        GistsListCommitsRequest {
+            client: self,
+            gist_id,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List gist commits.
      *
@@ -659,6 +1285,30 @@ impl Gists {
             )
             .await
     }
+    /**
+     * List gist commits.
+     *
+     * This function performs a `GET` to the `/gists/{gist_id}/commits` endpoint.
+     *
+     * As opposed to `list_all_commits`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/gists#list-gist-commits>
+     */
+    pub fn list_all_commits_stream(
+        &self,
+        gist_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::GistCommit>> + Unpin + '_ {
+        let url = self.client.url(
+            &format!(
+                "/gists/{}/commits",
+                crate::progenitor_support::encode_path(gist_id),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * List gist forks.
      *
@@ -706,6 +1356,25 @@ impl Gists {
             )
             .await
     }
+    /**
+     * List gist forks.
+     *
+     * This function performs a `GET` to the `/gists/{gist_id}/forks` endpoint.
+     *
+     * As opposed to `list_forks`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/gists#list-gist-forks>
+     */
+    pub fn list_forks_request<'a>(&'a self, gist_id: &'a str) -> GistsListForksRequest<'a> {
+        GistsListForksRequest {
+            client: self,
+            gist_id,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List gist forks.
      *
@@ -738,6 +1407,30 @@ impl Gists {
             )
             .await
     }
+    /**
+     * List gist forks.
+     *
+     * This function performs a `GET` to the `/gists/{gist_id}/forks` endpoint.
+     *
+     * As opposed to `list_all_forks`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/gists#list-gist-forks>
+     */
+    pub fn list_all_forks_stream(
+        &self,
+        gist_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::GistSimple>> + Unpin + '_ {
+        let url = self.client.url(
+            &format!(
+                "/gists/{}/forks",
+                crate::progenitor_support::encode_path(gist_id),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Fork a gist.
      *
@@ -954,6 +1647,26 @@ impl Gists {
             )
             .await
     }
+    /**
+     * List gists for a user.
+     *
+     * This function performs a `GET` to the `/users/{username}/gists` endpoint.
+     *
+     * As opposed to `list_for_user`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Lists public gists for the specified user:
+     *
+     * FROM: <https://docs.github.com/rest/reference/gists#list-gists-for-a-user>
+     */
+    pub fn list_for_user_request<'a>(&'a self, username: &'a str) -> GistsListForUserRequest<'a> {
+        GistsListForUserRequest {
+            client: self,
+            username,
+            since: None,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List gists for a user.
      *
@@ -993,4 +1706,35 @@ impl Gists {
             )
             .await
     }
+    /**
+     * List gists for a user.
+     *
+     * This function performs a `GET` to the `/users/{username}/gists` endpoint.
+     *
+     * As opposed to `list_all_for_user`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Lists public gists for the specified user:
+     *
+     * FROM: <https://docs.github.com/rest/reference/gists#list-gists-for-a-user>
+     */
+    pub fn list_all_for_user_stream(
+        &self,
+        username: &str,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::BaseGist>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(date) = since {
+            query_args.push(("since".to_string(), date.to_rfc3339()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/users/{}/gists?{}",
+                crate::progenitor_support::encode_path(username),
+                query_
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
 }
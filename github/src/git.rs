@@ -1,6 +1,125 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Git::list_matching_refs`], set with a builder.
+pub struct GitListMatchingRefsRequest<'a> {
+    client: &'a Git,
+    owner: &'a str,
+    repo: &'a str,
+    ref_: &'a str,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> GitListMatchingRefsRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::GitRef>>> {
+        let Self {
+            client,
+            owner,
+            repo,
+            ref_,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/repos/{}/{}/git/matching-refs/{}?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(ref_),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Git::get_tree`], set with a builder.
+pub struct GitGetTreeRequest<'a> {
+    client: &'a Git,
+    owner: &'a str,
+    repo: &'a str,
+    tree_sha: &'a str,
+    recursive: Option<&'a str>,
+}
+
+impl<'a> GitGetTreeRequest<'a> {
+    /// Sets the `recursive` query parameter.
+    pub fn recursive(mut self, recursive: &'a str) -> Self {
+        self.recursive = Some(recursive);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::GitTreeData>> {
+        let Self {
+            client,
+            owner,
+            repo,
+            tree_sha,
+            recursive,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = recursive {
+            query_args.push(("recursive".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/repos/{}/{}/git/trees/{}?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(tree_sha),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct Git {
     pub client: Client,
 }
@@ -286,6 +405,38 @@ impl Git {
             )
             .await
     }
+    /**
+     * List matching references.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/git/matching-refs/{ref}` endpoint.
+     *
+     * As opposed to `list_matching_refs`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Returns an array of references from your Git database that match the supplied name. The `:ref` in the URL must be formatted as `heads/<branch name>` for branches and `tags/<tag name>` for tags. If the `:ref` doesn't exist in the repository, but existing refs start with `:ref`, they will be returned as an array.
+     *
+     * When you use this endpoint without providing a `:ref`, it will return an array of all the references from your Git database, including notes and stashes if they exist on the server. Anything in the namespace is returned, not just `heads` and `tags`.
+     *
+     * **Note:** You need to explicitly [request a pull request](https://docs.github.com/rest/reference/pulls#get-a-pull-request) to trigger a test merge commit, which checks the mergeability of pull requests. For more information, see "[Checking mergeability of pull requests](https://docs.github.com/rest/guides/getting-started-with-the-git-database-api#checking-mergeability-of-pull-requests)".
+     *
+     * If you request matching references for a branch named `feature` but the branch `feature` doesn't exist, the response can still include other matching head refs that start with the word `feature`, such as `featureA` and `featureB`.
+     *
+     * FROM: <https://docs.github.com/rest/reference/git#list-matching-references>
+     */
+    pub fn list_matching_refs_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+        ref_: &'a str,
+    ) -> GitListMatchingRefsRequest<'a> {
+        GitListMatchingRefsRequest {
+            client: self,
+            owner,
+            repo,
+            ref_,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List matching references.
      *
@@ -328,6 +479,40 @@ impl Git {
             )
             .await
     }
+    /**
+     * List matching references.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/git/matching-refs/{ref}` endpoint.
+     *
+     * As opposed to `list_all_matching_refs`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Returns an array of references from your Git database that match the supplied name. The `:ref` in the URL must be formatted as `heads/<branch name>` for branches and `tags/<tag name>` for tags. If the `:ref` doesn't exist in the repository, but existing refs start with `:ref`, they will be returned as an array.
+     *
+     * When you use this endpoint without providing a `:ref`, it will return an array of all the references from your Git database, including notes and stashes if they exist on the server. Anything in the namespace is returned, not just `heads` and `tags`.
+     *
+     * **Note:** You need to explicitly [request a pull request](https://docs.github.com/rest/reference/pulls#get-a-pull-request) to trigger a test merge commit, which checks the mergeability of pull requests. For more information, see "[Checking mergeability of pull requests](https://docs.github.com/rest/guides/getting-started-with-the-git-database-api#checking-mergeability-of-pull-requests)".
+     *
+     * If you request matching references for a branch named `feature` but the branch `feature` doesn't exist, the response can still include other matching head refs that start with the word `feature`, such as `featureA` and `featureB`.
+     *
+     * FROM: <https://docs.github.com/rest/reference/git#list-matching-references>
+     */
+    pub fn list_all_matching_refs_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        ref_: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::GitRef>> + Unpin + '_ {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/git/matching-refs/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(ref_),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Get a reference.
      *
@@ -713,4 +898,31 @@ impl Git {
             )
             .await
     }
+    /**
+     * Get a tree.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/git/trees/{tree_sha}` endpoint.
+     *
+     * As opposed to `get_tree`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Returns a single tree using the SHA1 value for that tree.
+     *
+     * If `truncated` is `true` in the response then the number of items in the `tree` array exceeded our maximum limit. If you need to fetch more items, use the non-recursive method of fetching trees, and fetch one sub-tree at a time.
+     *
+     * FROM: <https://docs.github.com/rest/reference/git#get-a-tree>
+     */
+    pub fn get_tree_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+        tree_sha: &'a str,
+    ) -> GitGetTreeRequest<'a> {
+        GitGetTreeRequest {
+            client: self,
+            owner,
+            repo,
+            tree_sha,
+            recursive: None,
+        }
+    }
 }
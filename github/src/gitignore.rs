@@ -55,6 +55,23 @@ impl Gitignore {
             )
             .await
     }
+    /**
+     * Get all gitignore templates.
+     *
+     * This function performs a `GET` to the `/gitignore/templates` endpoint.
+     *
+     * As opposed to `get_all_all_templates`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * List all templates available to pass as an option when [creating a repository](https://docs.github.com/rest/reference/repos#create-a-repository-for-the-authenticated-user).
+     *
+     * FROM: <https://docs.github.com/rest/reference/gitignore#get-all-gitignore-templates>
+     */
+    pub fn get_all_all_templates_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<String>> + Unpin + '_ {
+        let url = self.client.url("/gitignore/templates", None);
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Get a gitignore template.
      *
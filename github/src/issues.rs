@@ -1,6 +1,1280 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Issues::list`], set with a builder.
+pub struct IssuesListRequest<'a> {
+    client: &'a Issues,
+    filter: Option<crate::types::Filter>,
+    state: Option<crate::types::IssuesListState>,
+    labels: Option<&'a str>,
+    sort: Option<crate::types::IssuesListSort>,
+    direction: Option<crate::types::Order>,
+    since: Option<chrono::DateTime<chrono::Utc>>,
+    collab: Option<bool>,
+    orgs: Option<bool>,
+    owned: Option<bool>,
+    pulls: Option<bool>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> IssuesListRequest<'a> {
+    /// Sets the `filter` query parameter.
+    pub fn filter(mut self, filter: crate::types::Filter) -> Self {
+        self.filter = Some(filter);
+        self
+    }
+
+    /// Sets the `state` query parameter.
+    pub fn state(mut self, state: crate::types::IssuesListState) -> Self {
+        self.state = Some(state);
+        self
+    }
+
+    /// Sets the `labels` query parameter.
+    pub fn labels(mut self, labels: &'a str) -> Self {
+        self.labels = Some(labels);
+        self
+    }
+
+    /// Sets the `sort` query parameter.
+    pub fn sort(mut self, sort: crate::types::IssuesListSort) -> Self {
+        self.sort = Some(sort);
+        self
+    }
+
+    /// Sets the `direction` query parameter.
+    pub fn direction(mut self, direction: crate::types::Order) -> Self {
+        self.direction = Some(direction);
+        self
+    }
+
+    /// Sets the `since` query parameter.
+    pub fn since(mut self, since: chrono::DateTime<chrono::Utc>) -> Self {
+        self.since = Some(since);
+        self
+    }
+
+    /// Sets the `collab` query parameter.
+    pub fn collab(mut self, collab: bool) -> Self {
+        self.collab = Some(collab);
+        self
+    }
+
+    /// Sets the `orgs` query parameter.
+    pub fn orgs(mut self, orgs: bool) -> Self {
+        self.orgs = Some(orgs);
+        self
+    }
+
+    /// Sets the `owned` query parameter.
+    pub fn owned(mut self, owned: bool) -> Self {
+        self.owned = Some(owned);
+        self
+    }
+
+    /// Sets the `pulls` query parameter.
+    pub fn pulls(mut self, pulls: bool) -> Self {
+        self.pulls = Some(pulls);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Issue>>> {
+        let Self {
+            client,
+            filter,
+            state,
+            labels,
+            sort,
+            direction,
+            since,
+            collab,
+            orgs,
+            owned,
+            pulls,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = collab {
+            query_args.push(("collab".to_string(), v.to_string()));
+        }
+        if let Some(v) = direction {
+            query_args.push(("direction".to_string(), v.to_string()));
+        }
+        if let Some(v) = filter {
+            query_args.push(("filter".to_string(), v.to_string()));
+        }
+        if let Some(v) = labels {
+            query_args.push(("labels".to_string(), v.to_string()));
+        }
+        if let Some(v) = orgs {
+            query_args.push(("orgs".to_string(), v.to_string()));
+        }
+        if let Some(v) = owned {
+            query_args.push(("owned".to_string(), v.to_string()));
+        }
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        if let Some(v) = pulls {
+            query_args.push(("pulls".to_string(), v.to_string()));
+        }
+        if let Some(date) = since {
+            query_args.push(("since".to_string(), date.to_rfc3339()));
+        }
+        if let Some(v) = sort {
+            query_args.push(("sort".to_string(), v.to_string()));
+        }
+        if let Some(v) = state {
+            query_args.push(("state".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(&format!("/issues?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Issues::list_for_org`], set with a builder.
+pub struct IssuesListForOrgRequest<'a> {
+    client: &'a Issues,
+    org: &'a str,
+    filter: Option<crate::types::Filter>,
+    state: Option<crate::types::IssuesListState>,
+    labels: Option<&'a str>,
+    sort: Option<crate::types::IssuesListSort>,
+    direction: Option<crate::types::Order>,
+    since: Option<chrono::DateTime<chrono::Utc>>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> IssuesListForOrgRequest<'a> {
+    /// Sets the `filter` query parameter.
+    pub fn filter(mut self, filter: crate::types::Filter) -> Self {
+        self.filter = Some(filter);
+        self
+    }
+
+    /// Sets the `state` query parameter.
+    pub fn state(mut self, state: crate::types::IssuesListState) -> Self {
+        self.state = Some(state);
+        self
+    }
+
+    /// Sets the `labels` query parameter.
+    pub fn labels(mut self, labels: &'a str) -> Self {
+        self.labels = Some(labels);
+        self
+    }
+
+    /// Sets the `sort` query parameter.
+    pub fn sort(mut self, sort: crate::types::IssuesListSort) -> Self {
+        self.sort = Some(sort);
+        self
+    }
+
+    /// Sets the `direction` query parameter.
+    pub fn direction(mut self, direction: crate::types::Order) -> Self {
+        self.direction = Some(direction);
+        self
+    }
+
+    /// Sets the `since` query parameter.
+    pub fn since(mut self, since: chrono::DateTime<chrono::Utc>) -> Self {
+        self.since = Some(since);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Issue>>> {
+        let Self {
+            client,
+            org,
+            filter,
+            state,
+            labels,
+            sort,
+            direction,
+            since,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = direction {
+            query_args.push(("direction".to_string(), v.to_string()));
+        }
+        if let Some(v) = filter {
+            query_args.push(("filter".to_string(), v.to_string()));
+        }
+        if let Some(v) = labels {
+            query_args.push(("labels".to_string(), v.to_string()));
+        }
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        if let Some(date) = since {
+            query_args.push(("since".to_string(), date.to_rfc3339()));
+        }
+        if let Some(v) = sort {
+            query_args.push(("sort".to_string(), v.to_string()));
+        }
+        if let Some(v) = state {
+            query_args.push(("state".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/orgs/{}/issues?{}",
+                crate::progenitor_support::encode_path(org),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Issues::list_assignees`], set with a builder.
+pub struct IssuesListAssigneesRequest<'a> {
+    client: &'a Issues,
+    owner: &'a str,
+    repo: &'a str,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> IssuesListAssigneesRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::SimpleUser>>> {
+        let Self {
+            client,
+            owner,
+            repo,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/repos/{}/{}/assignees?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Issues::list_for_repo`], set with a builder.
+pub struct IssuesListForRepoRequest<'a> {
+    client: &'a Issues,
+    owner: &'a str,
+    repo: &'a str,
+    milestone: Option<&'a str>,
+    state: Option<crate::types::IssuesListState>,
+    assignee: Option<&'a str>,
+    creator: Option<&'a str>,
+    mentioned: Option<&'a str>,
+    labels: Option<&'a str>,
+    sort: Option<crate::types::IssuesListSort>,
+    direction: Option<crate::types::Order>,
+    since: Option<chrono::DateTime<chrono::Utc>>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> IssuesListForRepoRequest<'a> {
+    /// Sets the `milestone` query parameter.
+    pub fn milestone(mut self, milestone: &'a str) -> Self {
+        self.milestone = Some(milestone);
+        self
+    }
+
+    /// Sets the `state` query parameter.
+    pub fn state(mut self, state: crate::types::IssuesListState) -> Self {
+        self.state = Some(state);
+        self
+    }
+
+    /// Sets the `assignee` query parameter.
+    pub fn assignee(mut self, assignee: &'a str) -> Self {
+        self.assignee = Some(assignee);
+        self
+    }
+
+    /// Sets the `creator` query parameter.
+    pub fn creator(mut self, creator: &'a str) -> Self {
+        self.creator = Some(creator);
+        self
+    }
+
+    /// Sets the `mentioned` query parameter.
+    pub fn mentioned(mut self, mentioned: &'a str) -> Self {
+        self.mentioned = Some(mentioned);
+        self
+    }
+
+    /// Sets the `labels` query parameter.
+    pub fn labels(mut self, labels: &'a str) -> Self {
+        self.labels = Some(labels);
+        self
+    }
+
+    /// Sets the `sort` query parameter.
+    pub fn sort(mut self, sort: crate::types::IssuesListSort) -> Self {
+        self.sort = Some(sort);
+        self
+    }
+
+    /// Sets the `direction` query parameter.
+    pub fn direction(mut self, direction: crate::types::Order) -> Self {
+        self.direction = Some(direction);
+        self
+    }
+
+    /// Sets the `since` query parameter.
+    pub fn since(mut self, since: chrono::DateTime<chrono::Utc>) -> Self {
+        self.since = Some(since);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::IssueSimple>>> {
+        let Self {
+            client,
+            owner,
+            repo,
+            milestone,
+            state,
+            assignee,
+            creator,
+            mentioned,
+            labels,
+            sort,
+            direction,
+            since,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = assignee {
+            query_args.push(("assignee".to_string(), v.to_string()));
+        }
+        if let Some(v) = creator {
+            query_args.push(("creator".to_string(), v.to_string()));
+        }
+        if let Some(v) = direction {
+            query_args.push(("direction".to_string(), v.to_string()));
+        }
+        if let Some(v) = labels {
+            query_args.push(("labels".to_string(), v.to_string()));
+        }
+        if let Some(v) = mentioned {
+            query_args.push(("mentioned".to_string(), v.to_string()));
+        }
+        if let Some(v) = milestone {
+            query_args.push(("milestone".to_string(), v.to_string()));
+        }
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        if let Some(date) = since {
+            query_args.push(("since".to_string(), date.to_rfc3339()));
+        }
+        if let Some(v) = sort {
+            query_args.push(("sort".to_string(), v.to_string()));
+        }
+        if let Some(v) = state {
+            query_args.push(("state".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/repos/{}/{}/issues?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Issues::list_comments_for_repo`], set with a builder.
+pub struct IssuesListCommentsForRepoRequest<'a> {
+    client: &'a Issues,
+    owner: &'a str,
+    repo: &'a str,
+    sort: Option<crate::types::Sort>,
+    direction: Option<crate::types::Order>,
+    since: Option<chrono::DateTime<chrono::Utc>>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> IssuesListCommentsForRepoRequest<'a> {
+    /// Sets the `sort` query parameter.
+    pub fn sort(mut self, sort: crate::types::Sort) -> Self {
+        self.sort = Some(sort);
+        self
+    }
+
+    /// Sets the `direction` query parameter.
+    pub fn direction(mut self, direction: crate::types::Order) -> Self {
+        self.direction = Some(direction);
+        self
+    }
+
+    /// Sets the `since` query parameter.
+    pub fn since(mut self, since: chrono::DateTime<chrono::Utc>) -> Self {
+        self.since = Some(since);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::IssueComment>>> {
+        let Self {
+            client,
+            owner,
+            repo,
+            sort,
+            direction,
+            since,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = direction {
+            query_args.push(("direction".to_string(), v.to_string()));
+        }
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        if let Some(date) = since {
+            query_args.push(("since".to_string(), date.to_rfc3339()));
+        }
+        if let Some(v) = sort {
+            query_args.push(("sort".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/repos/{}/{}/issues/comments?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Issues::list_events_for_repo`], set with a builder.
+pub struct IssuesListEventsForRepoRequest<'a> {
+    client: &'a Issues,
+    owner: &'a str,
+    repo: &'a str,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> IssuesListEventsForRepoRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::IssueEvent>>> {
+        let Self {
+            client,
+            owner,
+            repo,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/repos/{}/{}/issues/events?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Issues::list_comments`], set with a builder.
+pub struct IssuesListCommentsRequest<'a> {
+    client: &'a Issues,
+    owner: &'a str,
+    repo: &'a str,
+    issue_number: i64,
+    since: Option<chrono::DateTime<chrono::Utc>>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> IssuesListCommentsRequest<'a> {
+    /// Sets the `since` query parameter.
+    pub fn since(mut self, since: chrono::DateTime<chrono::Utc>) -> Self {
+        self.since = Some(since);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::IssueComment>>> {
+        let Self {
+            client,
+            owner,
+            repo,
+            issue_number,
+            since,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        if let Some(date) = since {
+            query_args.push(("since".to_string(), date.to_rfc3339()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/repos/{}/{}/issues/{}/comments?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&issue_number.to_string()),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Issues::list_events`], set with a builder.
+pub struct IssuesListEventsRequest<'a> {
+    client: &'a Issues,
+    owner: &'a str,
+    repo: &'a str,
+    issue_number: i64,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> IssuesListEventsRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::IssueEventAnyOf>>> {
+        let Self {
+            client,
+            owner,
+            repo,
+            issue_number,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/repos/{}/{}/issues/{}/events?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&issue_number.to_string()),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Issues::list_labels_on_issue`], set with a builder.
+pub struct IssuesListLabelsOnIssueRequest<'a> {
+    client: &'a Issues,
+    owner: &'a str,
+    repo: &'a str,
+    issue_number: i64,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> IssuesListLabelsOnIssueRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Label>>> {
+        let Self {
+            client,
+            owner,
+            repo,
+            issue_number,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/repos/{}/{}/issues/{}/labels?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&issue_number.to_string()),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Issues::list_events_for_timeline`], set with a builder.
+pub struct IssuesListEventsForTimelineRequest<'a> {
+    client: &'a Issues,
+    owner: &'a str,
+    repo: &'a str,
+    issue_number: i64,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> IssuesListEventsForTimelineRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Data>>> {
+        let Self {
+            client,
+            owner,
+            repo,
+            issue_number,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/repos/{}/{}/issues/{}/timeline?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&issue_number.to_string()),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Issues::list_labels_for_repo`], set with a builder.
+pub struct IssuesListLabelsForRepoRequest<'a> {
+    client: &'a Issues,
+    owner: &'a str,
+    repo: &'a str,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> IssuesListLabelsForRepoRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Label>>> {
+        let Self {
+            client,
+            owner,
+            repo,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/repos/{}/{}/labels?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Issues::list_milestones`], set with a builder.
+pub struct IssuesListMilestonesRequest<'a> {
+    client: &'a Issues,
+    owner: &'a str,
+    repo: &'a str,
+    state: Option<crate::types::IssuesListState>,
+    sort: Option<crate::types::IssuesListMilestonesSort>,
+    direction: Option<crate::types::Order>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> IssuesListMilestonesRequest<'a> {
+    /// Sets the `state` query parameter.
+    pub fn state(mut self, state: crate::types::IssuesListState) -> Self {
+        self.state = Some(state);
+        self
+    }
+
+    /// Sets the `sort` query parameter.
+    pub fn sort(mut self, sort: crate::types::IssuesListMilestonesSort) -> Self {
+        self.sort = Some(sort);
+        self
+    }
+
+    /// Sets the `direction` query parameter.
+    pub fn direction(mut self, direction: crate::types::Order) -> Self {
+        self.direction = Some(direction);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Milestone>>> {
+        let Self {
+            client,
+            owner,
+            repo,
+            state,
+            sort,
+            direction,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = direction {
+            query_args.push(("direction".to_string(), v.to_string()));
+        }
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        if let Some(v) = sort {
+            query_args.push(("sort".to_string(), v.to_string()));
+        }
+        if let Some(v) = state {
+            query_args.push(("state".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/repos/{}/{}/milestones?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Issues::list_labels_for_milestone`], set with a builder.
+pub struct IssuesListLabelsForMilestoneRequest<'a> {
+    client: &'a Issues,
+    owner: &'a str,
+    repo: &'a str,
+    milestone_number: i64,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> IssuesListLabelsForMilestoneRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Label>>> {
+        let Self {
+            client,
+            owner,
+            repo,
+            milestone_number,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/repos/{}/{}/milestones/{}/labels?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&milestone_number.to_string()),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Issues::list_for_authenticated_user`], set with a builder.
+pub struct IssuesListForAuthenticatedUserRequest<'a> {
+    client: &'a Issues,
+    filter: Option<crate::types::Filter>,
+    state: Option<crate::types::IssuesListState>,
+    labels: Option<&'a str>,
+    sort: Option<crate::types::IssuesListSort>,
+    direction: Option<crate::types::Order>,
+    since: Option<chrono::DateTime<chrono::Utc>>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> IssuesListForAuthenticatedUserRequest<'a> {
+    /// Sets the `filter` query parameter.
+    pub fn filter(mut self, filter: crate::types::Filter) -> Self {
+        self.filter = Some(filter);
+        self
+    }
+
+    /// Sets the `state` query parameter.
+    pub fn state(mut self, state: crate::types::IssuesListState) -> Self {
+        self.state = Some(state);
+        self
+    }
+
+    /// Sets the `labels` query parameter.
+    pub fn labels(mut self, labels: &'a str) -> Self {
+        self.labels = Some(labels);
+        self
+    }
+
+    /// Sets the `sort` query parameter.
+    pub fn sort(mut self, sort: crate::types::IssuesListSort) -> Self {
+        self.sort = Some(sort);
+        self
+    }
+
+    /// Sets the `direction` query parameter.
+    pub fn direction(mut self, direction: crate::types::Order) -> Self {
+        self.direction = Some(direction);
+        self
+    }
+
+    /// Sets the `since` query parameter.
+    pub fn since(mut self, since: chrono::DateTime<chrono::Utc>) -> Self {
+        self.since = Some(since);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Issue>>> {
+        let Self {
+            client,
+            filter,
+            state,
+            labels,
+            sort,
+            direction,
+            since,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = direction {
+            query_args.push(("direction".to_string(), v.to_string()));
+        }
+        if let Some(v) = filter {
+            query_args.push(("filter".to_string(), v.to_string()));
+        }
+        if let Some(v) = labels {
+            query_args.push(("labels".to_string(), v.to_string()));
+        }
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        if let Some(date) = since {
+            query_args.push(("since".to_string(), date.to_rfc3339()));
+        }
+        if let Some(v) = sort {
+            query_args.push(("sort".to_string(), v.to_string()));
+        }
+        if let Some(v) = state {
+            query_args.push(("state".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(&format!("/user/issues?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct Issues {
     pub client: Client,
 }
@@ -114,6 +1388,42 @@ impl Issues {
             )
             .await
     }
+    /**
+     * List issues assigned to the authenticated user.
+     *
+     * This function performs a `GET` to the `/issues` endpoint.
+     *
+     * As opposed to `list`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * List issues assigned to the authenticated user across all visible repositories including owned repositories, member
+     * repositories, and organization repositories. You can use the `filter` query parameter to fetch issues that are not
+     * necessarily assigned to you.
+     *
+     *
+     * **Note**: GitHub's REST API v3 considers every pull request an issue, but not every issue is a pull request. For this
+     * reason, "Issues" endpoints may return both issues and pull requests in the response. You can identify pull requests by
+     * the `pull_request` key. Be aware that the `id` of a pull request returned from "Issues" endpoints will be an _issue id_. To find out the pull
+     * request id, use the "[List pull requests](https://docs.github.com/rest/reference/pulls#list-pull-requests)" endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#list-issues-assigned-to-the-authenticated-user>
+     */
+    pub fn list_request(&self) -> IssuesListRequest<'_> {
+        IssuesListRequest {
+            client: self,
+            filter: None,
+            state: None,
+            labels: None,
+            sort: None,
+            direction: None,
+            since: None,
+            collab: None,
+            orgs: None,
+            owned: None,
+            pulls: None,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List issues assigned to the authenticated user.
      *
@@ -189,6 +1499,73 @@ impl Issues {
             )
             .await
     }
+    /**
+     * List issues assigned to the authenticated user.
+     *
+     * This function performs a `GET` to the `/issues` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * List issues assigned to the authenticated user across all visible repositories including owned repositories, member
+     * repositories, and organization repositories. You can use the `filter` query parameter to fetch issues that are not
+     * necessarily assigned to you.
+     *
+     *
+     * **Note**: GitHub's REST API v3 considers every pull request an issue, but not every issue is a pull request. For this
+     * reason, "Issues" endpoints may return both issues and pull requests in the response. You can identify pull requests by
+     * the `pull_request` key. Be aware that the `id` of a pull request returned from "Issues" endpoints will be an _issue id_. To find out the pull
+     * request id, use the "[List pull requests](https://docs.github.com/rest/reference/pulls#list-pull-requests)" endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#list-issues-assigned-to-the-authenticated-user>
+     */
+    pub fn list_all_stream(
+        &self,
+        filter: crate::types::Filter,
+        state: crate::types::IssuesListState,
+        labels: &str,
+        sort: crate::types::IssuesListSort,
+        direction: crate::types::Order,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+        collab: bool,
+        orgs: bool,
+        owned: bool,
+        pulls: bool,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Issue>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if collab {
+            query_args.push(("collab".to_string(), collab.to_string()));
+        }
+        if !direction.to_string().is_empty() {
+            query_args.push(("direction".to_string(), direction.to_string()));
+        }
+        if !filter.to_string().is_empty() {
+            query_args.push(("filter".to_string(), filter.to_string()));
+        }
+        if !labels.is_empty() {
+            query_args.push(("labels".to_string(), labels.to_string()));
+        }
+        if orgs {
+            query_args.push(("orgs".to_string(), orgs.to_string()));
+        }
+        if owned {
+            query_args.push(("owned".to_string(), owned.to_string()));
+        }
+        if pulls {
+            query_args.push(("pulls".to_string(), pulls.to_string()));
+        }
+        if let Some(date) = since {
+            query_args.push(("since".to_string(), date.to_rfc3339()));
+        }
+        if !sort.to_string().is_empty() {
+            query_args.push(("sort".to_string(), sort.to_string()));
+        }
+        if !state.to_string().is_empty() {
+            query_args.push(("state".to_string(), state.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/issues?{}", query_), None);
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * List organization issues assigned to the authenticated user.
      *
@@ -278,6 +1655,36 @@ impl Issues {
             )
             .await
     }
+    /**
+     * List organization issues assigned to the authenticated user.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/issues` endpoint.
+     *
+     * As opposed to `list_for_org`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * List issues in an organization assigned to the authenticated user.
+     *
+     * **Note**: GitHub's REST API v3 considers every pull request an issue, but not every issue is a pull request. For this
+     * reason, "Issues" endpoints may return both issues and pull requests in the response. You can identify pull requests by
+     * the `pull_request` key. Be aware that the `id` of a pull request returned from "Issues" endpoints will be an _issue id_. To find out the pull
+     * request id, use the "[List pull requests](https://docs.github.com/rest/reference/pulls#list-pull-requests)" endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#list-organization-issues-assigned-to-the-authenticated-user>
+     */
+    pub fn list_for_org_request<'a>(&'a self, org: &'a str) -> IssuesListForOrgRequest<'a> {
+        IssuesListForOrgRequest {
+            client: self,
+            org,
+            filter: None,
+            state: None,
+            labels: None,
+            sort: None,
+            direction: None,
+            since: None,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List organization issues assigned to the authenticated user.
      *
@@ -342,6 +1749,62 @@ impl Issues {
             )
             .await
     }
+    /**
+     * List organization issues assigned to the authenticated user.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/issues` endpoint.
+     *
+     * As opposed to `list_all_for_org`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * List issues in an organization assigned to the authenticated user.
+     *
+     * **Note**: GitHub's REST API v3 considers every pull request an issue, but not every issue is a pull request. For this
+     * reason, "Issues" endpoints may return both issues and pull requests in the response. You can identify pull requests by
+     * the `pull_request` key. Be aware that the `id` of a pull request returned from "Issues" endpoints will be an _issue id_. To find out the pull
+     * request id, use the "[List pull requests](https://docs.github.com/rest/reference/pulls#list-pull-requests)" endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#list-organization-issues-assigned-to-the-authenticated-user>
+     */
+    pub fn list_all_for_org_stream(
+        &self,
+        org: &str,
+        filter: crate::types::Filter,
+        state: crate::types::IssuesListState,
+        labels: &str,
+        sort: crate::types::IssuesListSort,
+        direction: crate::types::Order,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Issue>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !direction.to_string().is_empty() {
+            query_args.push(("direction".to_string(), direction.to_string()));
+        }
+        if !filter.to_string().is_empty() {
+            query_args.push(("filter".to_string(), filter.to_string()));
+        }
+        if !labels.is_empty() {
+            query_args.push(("labels".to_string(), labels.to_string()));
+        }
+        if let Some(date) = since {
+            query_args.push(("since".to_string(), date.to_rfc3339()));
+        }
+        if !sort.to_string().is_empty() {
+            query_args.push(("sort".to_string(), sort.to_string()));
+        }
+        if !state.to_string().is_empty() {
+            query_args.push(("state".to_string(), state.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/orgs/{}/issues?{}",
+                crate::progenitor_support::encode_path(org),
+                query_
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * List assignees.
      *
@@ -392,6 +1855,30 @@ impl Issues {
             )
             .await
     }
+    /**
+     * List assignees.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/assignees` endpoint.
+     *
+     * As opposed to `list_assignees`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Lists the [available assignees](https://help.github.com/articles/assigning-issues-and-pull-requests-to-other-github-users/) for issues in a repository.
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#list-assignees>
+     */
+    pub fn list_assignees_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+    ) -> IssuesListAssigneesRequest<'a> {
+        IssuesListAssigneesRequest {
+            client: self,
+            owner,
+            repo,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List assignees.
      *
@@ -407,7 +1894,41 @@ impl Issues {
         &self,
         owner: &str,
         repo: &str,
-    ) -> ClientResult<crate::Response<Vec<crate::types::SimpleUser>>> {
+    ) -> ClientResult<crate::Response<Vec<crate::types::SimpleUser>>> {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/assignees",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            None,
+        );
+        self.client
+            .get_all_pages(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+    /**
+     * List assignees.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/assignees` endpoint.
+     *
+     * As opposed to `list_all_assignees`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Lists the [available assignees](https://help.github.com/articles/assigning-issues-and-pull-requests-to-other-github-users/) for issues in a repository.
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#list-assignees>
+     */
+    pub fn list_all_assignees_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::SimpleUser>> + Unpin + '_ {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/assignees",
@@ -416,15 +1937,7 @@ impl Issues {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(url)
     }
     /**
      * Check if a user can be assigned.
@@ -572,6 +2085,44 @@ impl Issues {
             )
             .await
     }
+    /**
+     * List repository issues.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/issues` endpoint.
+     *
+     * As opposed to `list_for_repo`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * List issues in a repository.
+     *
+     * **Note**: GitHub's REST API v3 considers every pull request an issue, but not every issue is a pull request. For this
+     * reason, "Issues" endpoints may return both issues and pull requests in the response. You can identify pull requests by
+     * the `pull_request` key. Be aware that the `id` of a pull request returned from "Issues" endpoints will be an _issue id_. To find out the pull
+     * request id, use the "[List pull requests](https://docs.github.com/rest/reference/pulls#list-pull-requests)" endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#list-repository-issues>
+     */
+    pub fn list_for_repo_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+    ) -> IssuesListForRepoRequest<'a> {
+        IssuesListForRepoRequest {
+            client: self,
+            owner,
+            repo,
+            milestone: None,
+            state: None,
+            assignee: None,
+            creator: None,
+            mentioned: None,
+            labels: None,
+            sort: None,
+            direction: None,
+            since: None,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List repository issues.
      *
@@ -650,6 +2201,76 @@ impl Issues {
             )
             .await
     }
+    /**
+     * List repository issues.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/issues` endpoint.
+     *
+     * As opposed to `list_all_for_repo`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * List issues in a repository.
+     *
+     * **Note**: GitHub's REST API v3 considers every pull request an issue, but not every issue is a pull request. For this
+     * reason, "Issues" endpoints may return both issues and pull requests in the response. You can identify pull requests by
+     * the `pull_request` key. Be aware that the `id` of a pull request returned from "Issues" endpoints will be an _issue id_. To find out the pull
+     * request id, use the "[List pull requests](https://docs.github.com/rest/reference/pulls#list-pull-requests)" endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#list-repository-issues>
+     */
+    pub fn list_all_for_repo_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        milestone: &str,
+        state: crate::types::IssuesListState,
+        assignee: &str,
+        creator: &str,
+        mentioned: &str,
+        labels: &str,
+        sort: crate::types::IssuesListSort,
+        direction: crate::types::Order,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::IssueSimple>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !assignee.is_empty() {
+            query_args.push(("assignee".to_string(), assignee.to_string()));
+        }
+        if !creator.is_empty() {
+            query_args.push(("creator".to_string(), creator.to_string()));
+        }
+        if !direction.to_string().is_empty() {
+            query_args.push(("direction".to_string(), direction.to_string()));
+        }
+        if !labels.is_empty() {
+            query_args.push(("labels".to_string(), labels.to_string()));
+        }
+        if !mentioned.is_empty() {
+            query_args.push(("mentioned".to_string(), mentioned.to_string()));
+        }
+        if !milestone.is_empty() {
+            query_args.push(("milestone".to_string(), milestone.to_string()));
+        }
+        if let Some(date) = since {
+            query_args.push(("since".to_string(), date.to_rfc3339()));
+        }
+        if !sort.to_string().is_empty() {
+            query_args.push(("sort".to_string(), sort.to_string()));
+        }
+        if !state.to_string().is_empty() {
+            query_args.push(("state".to_string(), state.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/issues?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                query_
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Create an issue.
      *
@@ -757,6 +2378,33 @@ impl Issues {
             )
             .await
     }
+    /**
+     * List issue comments for a repository.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/issues/comments` endpoint.
+     *
+     * As opposed to `list_comments_for_repo`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * By default, Issue Comments are ordered by ascending ID.
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#list-issue-comments-for-a-repository>
+     */
+    pub fn list_comments_for_repo_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+    ) -> IssuesListCommentsForRepoRequest<'a> {
+        IssuesListCommentsForRepoRequest {
+            client: self,
+            owner,
+            repo,
+            sort: None,
+            direction: None,
+            since: None,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List issue comments for a repository.
      *
@@ -806,6 +2454,47 @@ impl Issues {
             )
             .await
     }
+    /**
+     * List issue comments for a repository.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/issues/comments` endpoint.
+     *
+     * As opposed to `list_all_comments_for_repo`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * By default, Issue Comments are ordered by ascending ID.
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#list-issue-comments-for-a-repository>
+     */
+    pub fn list_all_comments_for_repo_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        sort: crate::types::Sort,
+        direction: crate::types::Order,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::IssueComment>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !direction.to_string().is_empty() {
+            query_args.push(("direction".to_string(), direction.to_string()));
+        }
+        if let Some(date) = since {
+            query_args.push(("since".to_string(), date.to_rfc3339()));
+        }
+        if !sort.to_string().is_empty() {
+            query_args.push(("sort".to_string(), sort.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/issues/comments?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                query_
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Get an issue comment.
      *
@@ -977,6 +2666,30 @@ impl Issues {
             )
             .await
     }
+    /**
+     * List issue events for a repository.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/issues/events` endpoint.
+     *
+     * As opposed to `list_events_for_repo`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#list-issue-events-for-a-repository>
+     */
+    pub fn list_events_for_repo_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+    ) -> IssuesListEventsForRepoRequest<'a> {
+        IssuesListEventsForRepoRequest {
+            client: self,
+            owner,
+            repo,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List issue events for a repository.
      *
@@ -1011,6 +2724,32 @@ impl Issues {
             )
             .await
     }
+    /**
+     * List issue events for a repository.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/issues/events` endpoint.
+     *
+     * As opposed to `list_all_events_for_repo`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#list-issue-events-for-a-repository>
+     */
+    pub fn list_all_events_for_repo_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::IssueEvent>> + Unpin + '_ {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/issues/events",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Get an issue event.
      *
@@ -1282,6 +3021,33 @@ impl Issues {
             )
             .await
     }
+    /**
+     * List issue comments.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/issues/{issue_number}/comments` endpoint.
+     *
+     * As opposed to `list_comments`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Issue Comments are ordered by ascending ID.
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#list-issue-comments>
+     */
+    pub fn list_comments_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+        issue_number: i64,
+    ) -> IssuesListCommentsRequest<'a> {
+        IssuesListCommentsRequest {
+            client: self,
+            owner,
+            repo,
+            issue_number,
+            since: None,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List issue comments.
      *
@@ -1325,6 +3091,41 @@ impl Issues {
             )
             .await
     }
+    /**
+     * List issue comments.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/issues/{issue_number}/comments` endpoint.
+     *
+     * As opposed to `list_all_comments`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Issue Comments are ordered by ascending ID.
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#list-issue-comments>
+     */
+    pub fn list_all_comments_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::IssueComment>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(date) = since {
+            query_args.push(("since".to_string(), date.to_rfc3339()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/issues/{}/comments?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&issue_number.to_string()),
+                query_
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Create an issue comment.
      *
@@ -1419,6 +3220,32 @@ impl Issues {
             )
             .await
     }
+    /**
+     * List issue events.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/issues/{issue_number}/events` endpoint.
+     *
+     * As opposed to `list_events`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#list-issue-events>
+     */
+    pub fn list_events_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+        issue_number: i64,
+    ) -> IssuesListEventsRequest<'a> {
+        IssuesListEventsRequest {
+            client: self,
+            owner,
+            repo,
+            issue_number,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List issue events.
      *
@@ -1455,6 +3282,34 @@ impl Issues {
             )
             .await
     }
+    /**
+     * List issue events.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/issues/{issue_number}/events` endpoint.
+     *
+     * As opposed to `list_all_events`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#list-issue-events>
+     */
+    pub fn list_all_events_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::IssueEventAnyOf>> + Unpin + '_ {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/issues/{}/events",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&issue_number.to_string()),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * List labels for an issue.
      *
@@ -1508,6 +3363,32 @@ impl Issues {
             )
             .await
     }
+    /**
+     * List labels for an issue.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/issues/{issue_number}/labels` endpoint.
+     *
+     * As opposed to `list_labels_on_issue`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#list-labels-for-an-issue>
+     */
+    pub fn list_labels_on_issue_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+        issue_number: i64,
+    ) -> IssuesListLabelsOnIssueRequest<'a> {
+        IssuesListLabelsOnIssueRequest {
+            client: self,
+            owner,
+            repo,
+            issue_number,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List labels for an issue.
      *
@@ -1544,6 +3425,34 @@ impl Issues {
             )
             .await
     }
+    /**
+     * List labels for an issue.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/issues/{issue_number}/labels` endpoint.
+     *
+     * As opposed to `list_all_labels_on_issue`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#list-labels-for-an-issue>
+     */
+    pub fn list_all_labels_on_issue_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Label>> + Unpin + '_ {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/issues/{}/labels",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&issue_number.to_string()),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Set labels for an issue.
      *
@@ -1845,6 +3754,32 @@ impl Issues {
             )
             .await
     }
+    /**
+     * List timeline events for an issue.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/issues/{issue_number}/timeline` endpoint.
+     *
+     * As opposed to `list_events_for_timeline`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#list-timeline-events-for-an-issue>
+     */
+    pub fn list_events_for_timeline_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+        issue_number: i64,
+    ) -> IssuesListEventsForTimelineRequest<'a> {
+        IssuesListEventsForTimelineRequest {
+            client: self,
+            owner,
+            repo,
+            issue_number,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List timeline events for an issue.
      *
@@ -1881,6 +3816,34 @@ impl Issues {
             )
             .await
     }
+    /**
+     * List timeline events for an issue.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/issues/{issue_number}/timeline` endpoint.
+     *
+     * As opposed to `list_all_events_for_timeline`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#list-timeline-events-for-an-issue>
+     */
+    pub fn list_all_events_for_timeline_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Data>> + Unpin + '_ {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/issues/{}/timeline",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&issue_number.to_string()),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * List labels for a repository.
      *
@@ -1931,6 +3894,30 @@ impl Issues {
             )
             .await
     }
+    /**
+     * List labels for a repository.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/labels` endpoint.
+     *
+     * As opposed to `list_labels_for_repo`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#list-labels-for-a-repository>
+     */
+    pub fn list_labels_for_repo_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+    ) -> IssuesListLabelsForRepoRequest<'a> {
+        IssuesListLabelsForRepoRequest {
+            client: self,
+            owner,
+            repo,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List labels for a repository.
      *
@@ -1965,6 +3952,32 @@ impl Issues {
             )
             .await
     }
+    /**
+     * List labels for a repository.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/labels` endpoint.
+     *
+     * As opposed to `list_all_labels_for_repo`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#list-labels-for-a-repository>
+     */
+    pub fn list_all_labels_for_repo_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Label>> + Unpin + '_ {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/labels",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Create a label.
      *
@@ -2191,6 +4204,33 @@ impl Issues {
             )
             .await
     }
+    /**
+     * List milestones.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/milestones` endpoint.
+     *
+     * As opposed to `list_milestones`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#list-milestones>
+     */
+    pub fn list_milestones_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+    ) -> IssuesListMilestonesRequest<'a> {
+        IssuesListMilestonesRequest {
+            client: self,
+            owner,
+            repo,
+            state: None,
+            sort: None,
+            direction: None,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List milestones.
      *
@@ -2240,6 +4280,47 @@ impl Issues {
             )
             .await
     }
+    /**
+     * List milestones.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/milestones` endpoint.
+     *
+     * As opposed to `list_all_milestones`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#list-milestones>
+     */
+    pub fn list_all_milestones_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        state: crate::types::IssuesListState,
+        sort: crate::types::IssuesListMilestonesSort,
+        direction: crate::types::Order,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Milestone>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !direction.to_string().is_empty() {
+            query_args.push(("direction".to_string(), direction.to_string()));
+        }
+        if !sort.to_string().is_empty() {
+            query_args.push(("sort".to_string(), sort.to_string()));
+        }
+        if !state.to_string().is_empty() {
+            query_args.push(("state".to_string(), state.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/milestones?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                query_
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Create a milestone.
      *
@@ -2452,6 +4533,32 @@ impl Issues {
             )
             .await
     }
+    /**
+     * List labels for issues in a milestone.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/milestones/{milestone_number}/labels` endpoint.
+     *
+     * As opposed to `list_labels_for_milestone`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#list-labels-for-issues-in-a-milestone>
+     */
+    pub fn list_labels_for_milestone_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+        milestone_number: i64,
+    ) -> IssuesListLabelsForMilestoneRequest<'a> {
+        IssuesListLabelsForMilestoneRequest {
+            client: self,
+            owner,
+            repo,
+            milestone_number,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List labels for issues in a milestone.
      *
@@ -2488,6 +4595,34 @@ impl Issues {
             )
             .await
     }
+    /**
+     * List labels for issues in a milestone.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/milestones/{milestone_number}/labels` endpoint.
+     *
+     * As opposed to `list_all_labels_for_milestone`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#list-labels-for-issues-in-a-milestone>
+     */
+    pub fn list_all_labels_for_milestone_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        milestone_number: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Label>> + Unpin + '_ {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/milestones/{}/labels",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&milestone_number.to_string()),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * List user account issues assigned to the authenticated user.
      *
@@ -2568,6 +4703,35 @@ impl Issues {
             )
             .await
     }
+    /**
+     * List user account issues assigned to the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/issues` endpoint.
+     *
+     * As opposed to `list_for_authenticated_user`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * List issues across owned and member repositories assigned to the authenticated user.
+     *
+     * **Note**: GitHub's REST API v3 considers every pull request an issue, but not every issue is a pull request. For this
+     * reason, "Issues" endpoints may return both issues and pull requests in the response. You can identify pull requests by
+     * the `pull_request` key. Be aware that the `id` of a pull request returned from "Issues" endpoints will be an _issue id_. To find out the pull
+     * request id, use the "[List pull requests](https://docs.github.com/rest/reference/pulls#list-pull-requests)" endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#list-user-account-issues-assigned-to-the-authenticated-user>
+     */
+    pub fn list_for_authenticated_user_request(&self) -> IssuesListForAuthenticatedUserRequest<'_> {
+        IssuesListForAuthenticatedUserRequest {
+            client: self,
+            filter: None,
+            state: None,
+            labels: None,
+            sort: None,
+            direction: None,
+            since: None,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List user account issues assigned to the authenticated user.
      *
@@ -2624,4 +4788,52 @@ impl Issues {
             )
             .await
     }
+    /**
+     * List user account issues assigned to the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/issues` endpoint.
+     *
+     * As opposed to `list_all_for_authenticated_user`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * List issues across owned and member repositories assigned to the authenticated user.
+     *
+     * **Note**: GitHub's REST API v3 considers every pull request an issue, but not every issue is a pull request. For this
+     * reason, "Issues" endpoints may return both issues and pull requests in the response. You can identify pull requests by
+     * the `pull_request` key. Be aware that the `id` of a pull request returned from "Issues" endpoints will be an _issue id_. To find out the pull
+     * request id, use the "[List pull requests](https://docs.github.com/rest/reference/pulls#list-pull-requests)" endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#list-user-account-issues-assigned-to-the-authenticated-user>
+     */
+    pub fn list_all_for_authenticated_user_stream(
+        &self,
+        filter: crate::types::Filter,
+        state: crate::types::IssuesListState,
+        labels: &str,
+        sort: crate::types::IssuesListSort,
+        direction: crate::types::Order,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Issue>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !direction.to_string().is_empty() {
+            query_args.push(("direction".to_string(), direction.to_string()));
+        }
+        if !filter.to_string().is_empty() {
+            query_args.push(("filter".to_string(), filter.to_string()));
+        }
+        if !labels.is_empty() {
+            query_args.push(("labels".to_string(), labels.to_string()));
+        }
+        if let Some(date) = since {
+            query_args.push(("since".to_string(), date.to_rfc3339()));
+        }
+        if !sort.to_string().is_empty() {
+            query_args.push(("sort".to_string(), sort.to_string()));
+        }
+        if !state.to_string().is_empty() {
+            query_args.push(("state".to_string(), state.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/user/issues?{}", query_), None);
+        self.client.get_all_pages_stream(url)
+    }
 }
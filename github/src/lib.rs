@@ -183,6 +183,9 @@ pub mod apps;
 pub mod auth;
 /// Monitor charges and usage from Actions and Packages.
 pub mod billing;
+#[cfg(feature = "blocking")]
+#[cfg_attr(docsrs, doc(cfg(feature = "blocking")))]
+pub mod blocking;
 /// Rich interactions with checks run by your integrations.
 pub mod checks;
 /// Retrieve code scanning alerts from a repository.
@@ -193,6 +196,7 @@ pub mod codes_of_conduct;
 pub mod emojis;
 /// Administer a GitHub enterprise.
 pub mod enterprise_admin;
+pub mod errors;
 /// View, modify your gists.
 pub mod gists;
 /// Raw Git functionality.
@@ -212,6 +216,7 @@ pub mod licenses;
 pub mod markdown;
 /// Endpoints that give information about the API.
 pub mod meta;
+pub mod middleware;
 /// Move projects to or from GitHub.
 pub mod migrations;
 /// Manage access of OAuth applications.
@@ -226,6 +231,7 @@ pub mod projects;
 pub mod pulls;
 /// Check your current rate limit status.
 pub mod rate_limit;
+pub mod ratelimit;
 /// Interact with reactions to various GitHub entities.
 pub mod reactions;
 /// Interact with GitHub Repos.
@@ -243,9 +249,12 @@ pub mod types;
 pub mod users;
 #[doc(hidden)]
 pub mod utils;
+pub mod webhooks;
 
 pub use reqwest::{header::HeaderMap, StatusCode};
 
+pub use crate::{errors::ApiError, middleware::ClientBuilder, ratelimit::RateLimitInfo};
+
 #[derive(Debug)]
 pub struct Response<T> {
     pub status: reqwest::StatusCode,
@@ -261,6 +270,11 @@ impl<T> Response<T> {
             body,
         }
     }
+
+    /// Returns the state of the rate limit of the API reported by the response, if any.
+    pub fn rate_limit(&self) -> Option<RateLimitInfo> {
+        RateLimitInfo::from_headers(&self.headers)
+    }
 }
 
 type ClientResult<T> = Result<T, ClientError>;
@@ -271,9 +285,6 @@ use thiserror::Error;
 #[derive(Debug, Error)]
 pub enum ClientError {
     // Github only
-    /// Ratelimited
-    #[error("Rate limited for the next {duration} seconds")]
-    RateLimited { duration: u64 },
     /// JWT errors from auth.rs
     #[error(transparent)]
     JsonWebTokenError(#[from] jsonwebtoken::errors::Error),
@@ -282,12 +293,33 @@ pub enum ClientError {
     #[error(transparent)]
     #[cfg(feature = "httpcache")]
     IoError(#[from] std::io::Error),
+    /// A listing stopped before its last page, as the API handed back a page token or
+    /// url it already did, or the page limits of the client were reached where the
+    /// listing cannot be returned without its last page
+    #[error("Listing stopped before its last page, at page token {page_token:?}")]
+    IncompletePages { page_token: String },
+    /// The API rate limited the request. Its body is parsed into an `ApiError` when it
+    /// holds one, and `duration` is how many seconds the API asked to wait, if it said.
+    #[error(
+        "Rate limited. Code: {status}{}",
+        .duration.map(|duration| format!(", retry in {} seconds", duration)).unwrap_or_default()
+    )]
+    RateLimited {
+        status: http::StatusCode,
+        headers: reqwest::header::HeaderMap,
+        duration: Option<u64>,
+        info: crate::RateLimitInfo,
+        error: Option<Box<crate::ApiError>>,
+    },
     /// URL Parsing Error
     #[error(transparent)]
     UrlParserError(#[from] url::ParseError),
     /// Serde JSON parsing error
     #[error(transparent)]
     SerdeJsonError(#[from] serde_json::Error),
+    /// Serde form body encoding error
+    #[error(transparent)]
+    SerdeUrlencodedError(#[from] serde_urlencoded::ser::Error),
     /// Errors returned by reqwest
     #[error(transparent)]
     ReqwestError(#[from] reqwest::Error),
@@ -297,6 +329,13 @@ pub enum ClientError {
     /// Errors returned by reqwest middleware
     #[error(transparent)]
     ReqwestMiddleWareError(#[from] reqwest_middleware::Error),
+    /// Error returned by the API, parsed from the body of the response
+    #[error("API Error. Code: {status}, message: {error}")]
+    ApiError {
+        status: http::StatusCode,
+        headers: reqwest::header::HeaderMap,
+        error: Box<crate::ApiError>,
+    },
     /// Generic HTTP Error
     #[error("HTTP Error. Code: {status}, message: {error}")]
     HttpError {
@@ -348,6 +387,7 @@ impl RootDefaultServer {
 pub struct Client {
     host: String,
     host_override: Option<String>,
+    page_limits: crate::utils::PageLimits,
     agent: String,
     client: reqwest_middleware::ClientWithMiddleware,
     credentials: Option<crate::auth::Credentials>,
@@ -361,20 +401,7 @@ impl Client {
         A: Into<String>,
         C: Into<Option<crate::auth::Credentials>>,
     {
-        let http = reqwest::Client::builder()
-            .redirect(reqwest::redirect::Policy::none())
-            .build()?;
-        let retry_policy =
-            reqwest_retry::policies::ExponentialBackoff::builder().build_with_max_retries(3);
-        let client = reqwest_middleware::ClientBuilder::new(http)
-            // Trace HTTP requests. See the tracing crate to make use of these traces.
-            .with(reqwest_tracing::TracingMiddleware::default())
-            // Retry failed requests.
-            .with(reqwest_conditional_middleware::ConditionalMiddleware::new(
-                reqwest_retry::RetryTransientMiddleware::new_with_policy(retry_policy),
-                |req: &reqwest::Request| req.try_clone().is_some(),
-            ))
-            .build();
+        let client = crate::ClientBuilder::default().build()?;
 
         #[cfg(feature = "httpcache")]
         {
@@ -405,6 +432,7 @@ impl Client {
         Self {
             host: RootDefaultServer::default().default_url().to_string(),
             host_override: None,
+            page_limits: Default::default(),
             agent: agent.into(),
             client: http,
             credentials: credentials.into(),
@@ -425,6 +453,7 @@ impl Client {
         Self {
             host: RootDefaultServer::default().default_url().to_string(),
             host_override: None,
+            page_limits: Default::default(),
             agent: agent.into(),
             client: http,
             credentials: credentials.into(),
@@ -450,6 +479,30 @@ impl Client {
         self.host_override.as_deref()
     }
 
+    /// Replaces the HTTP stack of the client with one built by `builder`, to configure
+    /// its retries, timeouts and middleware.
+    pub fn with_client_builder(
+        &mut self,
+        builder: crate::ClientBuilder,
+    ) -> ClientResult<&mut Self> {
+        self.client = builder.build()?;
+        Ok(self)
+    }
+
+    /// Stop walking the pages of a paginated endpoint after `max_pages` pages have been
+    /// fetched. The first page is always fetched, so a limit of zero behaves like one.
+    pub fn with_max_pages(&mut self, max_pages: usize) -> &mut Self {
+        self.page_limits.max_pages = Some(max_pages.max(1));
+        self
+    }
+
+    /// Stop walking the pages of a paginated endpoint once `max_items` items have been
+    /// returned.
+    pub fn with_max_items(&mut self, max_items: usize) -> &mut Self {
+        self.page_limits.max_items = Some(max_items);
+        self
+    }
+
     pub(crate) fn url(&self, path: &str, host: Option<&str>) -> String {
         format!(
             "{}{}",
@@ -532,6 +585,8 @@ impl Client {
                                     permissions: Default::default(),
                                     repositories: Default::default(),
                                     repository_ids: Default::default(),
+                                    #[cfg(feature = "extra-fields")]
+                                    extra: Default::default(),
                                 },
                             )
                             .await?;
@@ -612,11 +667,8 @@ impl Client {
 
         let response = req.send().await?;
 
-        #[cfg(not(feature = "httpcache"))]
-        let (remaining, reset) = crate::utils::get_header_values(response.headers());
-
         #[cfg(feature = "httpcache")]
-        let (remaining, reset, etag) = crate::utils::get_header_values(response.headers());
+        let (_, _, etag) = crate::utils::get_header_values(response.headers());
 
         let status = response.status();
         let headers = response.headers().clone();
@@ -695,33 +747,7 @@ impl Client {
                 }
             }
         } else {
-            let error = match (remaining, reset) {
-                (Some(remaining), Some(reset)) if remaining == 0 => {
-                    let now = std::time::SystemTime::now()
-                        .duration_since(std::time::UNIX_EPOCH)
-                        .unwrap()
-                        .as_secs();
-                    ClientError::RateLimited {
-                        duration: u64::from(reset).saturating_sub(now),
-                    }
-                }
-                _ => {
-                    if response_body.is_empty() {
-                        ClientError::HttpError {
-                            status,
-                            headers,
-                            error: "empty response".into(),
-                        }
-                    } else {
-                        ClientError::HttpError {
-                            status,
-                            headers,
-                            error: String::from_utf8_lossy(&response_body).into(),
-                        }
-                    }
-                }
-            };
-            Err(error)
+            Err(ClientError::from_response(status, headers, &response_body))
         }
     }
 
@@ -814,6 +840,37 @@ impl Client {
         .await
     }
 
+    /// Lazily walk the pages of a `Link` header paginated endpoint, yielding
+    /// each item as it is received.
+    fn get_all_pages_stream<'a, D>(
+        &'a self,
+        uri: String,
+    ) -> impl futures::Stream<Item = ClientResult<D>> + Unpin + 'a
+    where
+        D: serde::de::DeserializeOwned + 'static + Send + 'a,
+    {
+        use futures::TryStreamExt;
+
+        Box::pin(
+            futures::stream::try_unfold(
+                (Some(uri), crate::utils::Pages::new(self.page_limits)),
+                move |(uri, mut pages)| async move {
+                    let uri = match uri {
+                        Some(uri) if pages.visit(&uri)? => uri,
+                        _ => return Ok(None),
+                    };
+
+                    let (link, mut response) = self.get_pages::<D>(&uri).await?;
+                    let more = pages.keep(&mut response.body);
+                    let items = futures::stream::iter(response.body.into_iter().map(Ok));
+
+                    ClientResult::Ok(Some((items, (link.filter(|_| more).map(|l| l.0), pages))))
+                },
+            )
+            .try_flatten(),
+        )
+    }
+
     async fn post<D>(&self, uri: &str, message: Message) -> ClientResult<crate::Response<D>>
     where
         D: serde::de::DeserializeOwned + 'static + Send,
@@ -910,22 +967,31 @@ impl Client {
     }
 
     /// "unfold" paginated results of a vector of items
+    ///
+    /// Pages are followed until the server stops returning a next link, returns an
+    /// empty page or the client's page limits are reached. The walk fails with
+    /// `ClientError::IncompletePages` if the server points back at a page that was
+    /// already fetched.
     async fn unfold<D>(&self, uri: &str) -> ClientResult<crate::Response<Vec<D>>>
     where
         D: serde::de::DeserializeOwned + 'static + Send,
     {
+        let mut pages = crate::utils::Pages::new(self.page_limits);
+        pages.visit(uri)?;
+
         let mut global_items = Vec::new();
-        let (new_link, mut response) = self.get_pages(uri).await?;
-        let mut link = new_link;
-        while !response.body.is_empty() {
+        let (mut link, mut response) = self.get_pages(uri).await?;
+        loop {
+            let more = pages.keep(&mut response.body);
             global_items.append(&mut response.body);
             // We need to get the next link.
-            if let Some(url) = &link {
-                let url = reqwest::Url::parse(&url.0)?;
-                let (new_link, new_response) = self.get_pages_url(&url).await?;
-                link = new_link;
-                response = new_response;
-            }
+            let url = match link {
+                Some(url) if more && pages.visit(&url.0)? => reqwest::Url::parse(&url.0)?,
+                _ => break,
+            };
+            let (new_link, new_response) = self.get_pages_url(&url).await?;
+            link = new_link;
+            response = new_response;
         }
 
         Ok(Response::new(
@@ -1,6 +1,67 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Licenses::get_all_commonly_used`], set with a builder.
+pub struct LicensesGetAllCommonlyUsedRequest<'a> {
+    client: &'a Licenses,
+    featured: Option<bool>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> LicensesGetAllCommonlyUsedRequest<'a> {
+    /// Sets the `featured` query parameter.
+    pub fn featured(mut self, featured: bool) -> Self {
+        self.featured = Some(featured);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::LicenseSimple>>> {
+        let Self {
+            client,
+            featured,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = featured {
+            query_args.push(("featured".to_string(), v.to_string()));
+        }
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(&format!("/licenses?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct Licenses {
     pub client: Client,
 }
@@ -54,6 +115,25 @@ impl Licenses {
             )
             .await
     }
+    /**
+     * Get all commonly used licenses.
+     *
+     * This function performs a `GET` to the `/licenses` endpoint.
+     *
+     * As opposed to `get_all_commonly_used`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/licenses#get-all-commonly-used-licenses>
+     */
+    pub fn get_all_commonly_used_request(&self) -> LicensesGetAllCommonlyUsedRequest<'_> {
+        LicensesGetAllCommonlyUsedRequest {
+            client: self,
+            featured: None,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * Get all commonly used licenses.
      *
@@ -85,6 +165,29 @@ impl Licenses {
             )
             .await
     }
+    /**
+     * Get all commonly used licenses.
+     *
+     * This function performs a `GET` to the `/licenses` endpoint.
+     *
+     * As opposed to `get_all_all_commonly_used`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/licenses#get-all-commonly-used-licenses>
+     */
+    pub fn get_all_all_commonly_used_stream(
+        &self,
+        featured: bool,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::LicenseSimple>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if featured {
+            query_args.push(("featured".to_string(), featured.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/licenses?{}", query_), None);
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Get a license.
      *
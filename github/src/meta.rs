@@ -1,6 +1,42 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Meta::get_octocat`], set with a builder.
+pub struct MetaGetOctocatRequest<'a> {
+    client: &'a Meta,
+    s: Option<&'a str>,
+}
+
+impl<'a> MetaGetOctocatRequest<'a> {
+    /// Sets the `s` query parameter.
+    pub fn s(mut self, s: &'a str) -> Self {
+        self.s = Some(s);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<String>> {
+        let Self { client, s } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = s {
+            query_args.push(("s".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(&format!("/octocat?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct Meta {
     pub client: Client,
 }
@@ -85,6 +121,23 @@ impl Meta {
             )
             .await
     }
+    /**
+     * Get Octocat.
+     *
+     * This function performs a `GET` to the `/octocat` endpoint.
+     *
+     * As opposed to `get_octocat`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get the octocat as ASCII art
+     *
+     * FROM: <https://docs.github.com/rest/reference/meta#get-octocat>
+     */
+    pub fn get_octocat_request(&self) -> MetaGetOctocatRequest<'_> {
+        MetaGetOctocatRequest {
+            client: self,
+            s: None,
+        }
+    }
     /**
      * Get the Zen of GitHub.
      *
@@ -1,6 +1,399 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Migrations::list_for_org`], set with a builder.
+pub struct MigrationsListForOrgRequest<'a> {
+    client: &'a Migrations,
+    org: &'a str,
+    per_page: Option<i64>,
+    page: Option<i64>,
+    exclude: Option<&'a [String]>,
+}
+
+impl<'a> MigrationsListForOrgRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sets the `exclude` query parameter.
+    pub fn exclude(mut self, exclude: &'a [String]) -> Self {
+        self.exclude = Some(exclude);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Migration>>> {
+        let Self {
+            client,
+            org,
+            per_page,
+            page,
+            exclude,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = exclude {
+            query_args.push(("exclude".to_string(), v.join(" ")));
+        }
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/orgs/{}/migrations?{}",
+                crate::progenitor_support::encode_path(org),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Migrations::get_status_for_org`], set with a builder.
+pub struct MigrationsGetStatusForOrgRequest<'a> {
+    client: &'a Migrations,
+    org: &'a str,
+    migration_id: i64,
+    exclude: Option<&'a [String]>,
+}
+
+impl<'a> MigrationsGetStatusForOrgRequest<'a> {
+    /// Sets the `exclude` query parameter.
+    pub fn exclude(mut self, exclude: &'a [String]) -> Self {
+        self.exclude = Some(exclude);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::Migration>> {
+        let Self {
+            client,
+            org,
+            migration_id,
+            exclude,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = exclude {
+            query_args.push(("exclude".to_string(), v.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/orgs/{}/migrations/{}?{}",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(&migration_id.to_string()),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Migrations::list_repos_for_org`], set with a builder.
+pub struct MigrationsListReposForOrgRequest<'a> {
+    client: &'a Migrations,
+    org: &'a str,
+    migration_id: i64,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> MigrationsListReposForOrgRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::MinimalRepository>>> {
+        let Self {
+            client,
+            org,
+            migration_id,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/orgs/{}/migrations/{}/repositories?{}",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(&migration_id.to_string()),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Migrations::get_commit_authors`], set with a builder.
+pub struct MigrationsGetCommitAuthorsRequest<'a> {
+    client: &'a Migrations,
+    owner: &'a str,
+    repo: &'a str,
+    since: Option<i64>,
+}
+
+impl<'a> MigrationsGetCommitAuthorsRequest<'a> {
+    /// Sets the `since` query parameter.
+    pub fn since(mut self, since: i64) -> Self {
+        self.since = Some(since);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::PorterAuthor>>> {
+        let Self {
+            client,
+            owner,
+            repo,
+            since,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = since {
+            query_args.push(("since".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/repos/{}/{}/import/authors?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Migrations::list_for_authenticated_user`], set with a builder.
+pub struct MigrationsListForAuthenticatedUserRequest<'a> {
+    client: &'a Migrations,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> MigrationsListForAuthenticatedUserRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Migration>>> {
+        let Self {
+            client,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/user/migrations?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Migrations::get_status_for_authenticated_user`], set with a builder.
+pub struct MigrationsGetStatusForAuthenticatedUserRequest<'a> {
+    client: &'a Migrations,
+    migration_id: i64,
+    exclude: Option<&'a [String]>,
+}
+
+impl<'a> MigrationsGetStatusForAuthenticatedUserRequest<'a> {
+    /// Sets the `exclude` query parameter.
+    pub fn exclude(mut self, exclude: &'a [String]) -> Self {
+        self.exclude = Some(exclude);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::Migration>> {
+        let Self {
+            client,
+            migration_id,
+            exclude,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = exclude {
+            query_args.push(("exclude".to_string(), v.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/user/migrations/{}?{}",
+                crate::progenitor_support::encode_path(&migration_id.to_string()),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Migrations::list_repos_for_user`], set with a builder.
+pub struct MigrationsListReposForUserRequest<'a> {
+    client: &'a Migrations,
+    migration_id: i64,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> MigrationsListReposForUserRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::MinimalRepository>>> {
+        let Self {
+            client,
+            migration_id,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/user/migrations/{}/repositories?{}",
+                crate::progenitor_support::encode_path(&migration_id.to_string()),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct Migrations {
     pub client: Client,
 }
@@ -63,6 +456,26 @@ impl Migrations {
             )
             .await
     }
+    /**
+     * List organization migrations.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/migrations` endpoint.
+     *
+     * As opposed to `list_for_org`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Lists the most recent migrations.
+     *
+     * FROM: <https://docs.github.com/rest/reference/migrations#list-organization-migrations>
+     */
+    pub fn list_for_org_request<'a>(&'a self, org: &'a str) -> MigrationsListForOrgRequest<'a> {
+        MigrationsListForOrgRequest {
+            client: self,
+            org,
+            per_page: None,
+            page: None,
+            exclude: None,
+        }
+    }
     /**
      * List organization migrations.
      *
@@ -102,6 +515,37 @@ impl Migrations {
             )
             .await
     }
+    /**
+     * List organization migrations.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/migrations` endpoint.
+     *
+     * As opposed to `list_all_for_org`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Lists the most recent migrations.
+     *
+     * FROM: <https://docs.github.com/rest/reference/migrations#list-organization-migrations>
+     */
+    pub fn list_all_for_org_stream(
+        &self,
+        org: &str,
+        exclude: &[String],
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Migration>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !exclude.is_empty() {
+            query_args.push(("exclude".to_string(), exclude.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/orgs/{}/migrations?{}",
+                crate::progenitor_support::encode_path(org),
+                query_
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Start an organization migration.
      *
@@ -189,6 +633,36 @@ impl Migrations {
             )
             .await
     }
+    /**
+     * Get an organization migration status.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/migrations/{migration_id}` endpoint.
+     *
+     * As opposed to `get_status_for_org`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Fetches the status of a migration.
+     *
+     * The `state` of a migration can be one of the following values:
+     *
+     * *   `pending`, which means the migration hasn't started yet.
+     * *   `exporting`, which means the migration is in progress.
+     * *   `exported`, which means the migration finished successfully.
+     * *   `failed`, which means the migration failed.
+     *
+     * FROM: <https://docs.github.com/rest/reference/migrations#get-an-organization-migration-status>
+     */
+    pub fn get_status_for_org_request<'a>(
+        &'a self,
+        org: &'a str,
+        migration_id: i64,
+    ) -> MigrationsGetStatusForOrgRequest<'a> {
+        MigrationsGetStatusForOrgRequest {
+            client: self,
+            org,
+            migration_id,
+            exclude: None,
+        }
+    }
     /**
      * Download an organization migration archive.
      *
@@ -353,6 +827,30 @@ impl Migrations {
             )
             .await
     }
+    /**
+     * List repositories in an organization migration.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/migrations/{migration_id}/repositories` endpoint.
+     *
+     * As opposed to `list_repos_for_org`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * List all the repositories for this organization migration.
+     *
+     * FROM: <https://docs.github.com/rest/reference/migrations#list-repositories-in-an-organization-migration>
+     */
+    pub fn list_repos_for_org_request<'a>(
+        &'a self,
+        org: &'a str,
+        migration_id: i64,
+    ) -> MigrationsListReposForOrgRequest<'a> {
+        MigrationsListReposForOrgRequest {
+            client: self,
+            org,
+            migration_id,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List repositories in an organization migration.
      *
@@ -387,6 +885,33 @@ impl Migrations {
             )
             .await
     }
+    /**
+     * List repositories in an organization migration.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/migrations/{migration_id}/repositories` endpoint.
+     *
+     * As opposed to `list_all_repos_for_org`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * List all the repositories for this organization migration.
+     *
+     * FROM: <https://docs.github.com/rest/reference/migrations#list-repositories-in-an-organization-migration>
+     */
+    pub fn list_all_repos_for_org_stream(
+        &self,
+        org: &str,
+        migration_id: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::MinimalRepository>> + Unpin + '_
+    {
+        let url = self.client.url(
+            &format!(
+                "/orgs/{}/migrations/{}/repositories",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(&migration_id.to_string()),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Get an import status.
      *
@@ -618,6 +1143,31 @@ impl Migrations {
             )
             .await
     }
+    /**
+     * Get commit authors.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/import/authors` endpoint.
+     *
+     * As opposed to `get_commit_authors`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Each type of source control system represents authors in a different way. For example, a Git commit author has a display name and an email address, but a Subversion commit author just has a username. The GitHub Importer will make the author information valid, but the author might not be correct. For example, it will change the bare Subversion username `hubot` into something like `hubot <hubot@12341234-abab-fefe-8787-fedcba987654>`.
+     *
+     * This endpoint and the [Map a commit author](https://docs.github.com/rest/reference/migrations#map-a-commit-author) endpoint allow you to provide correct Git author information.
+     *
+     * FROM: <https://docs.github.com/rest/reference/migrations#get-commit-authors>
+     */
+    pub fn get_commit_authors_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+    ) -> MigrationsGetCommitAuthorsRequest<'a> {
+        MigrationsGetCommitAuthorsRequest {
+            client: self,
+            owner,
+            repo,
+            since: None,
+        }
+    }
     /**
      * Get commit authors.
      *
@@ -661,6 +1211,41 @@ impl Migrations {
             )
             .await
     }
+    /**
+     * Get commit authors.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/import/authors` endpoint.
+     *
+     * As opposed to `get_all_commit_authors`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Each type of source control system represents authors in a different way. For example, a Git commit author has a display name and an email address, but a Subversion commit author just has a username. The GitHub Importer will make the author information valid, but the author might not be correct. For example, it will change the bare Subversion username `hubot` into something like `hubot <hubot@12341234-abab-fefe-8787-fedcba987654>`.
+     *
+     * This endpoint and the [Map a commit author](https://docs.github.com/rest/reference/migrations#map-a-commit-author) endpoint allow you to provide correct Git author information.
+     *
+     * FROM: <https://docs.github.com/rest/reference/migrations#get-commit-authors>
+     */
+    pub fn get_all_commit_authors_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        since: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::PorterAuthor>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if since > 0 {
+            query_args.push(("since".to_string(), since.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/import/authors?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                query_
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Map a commit author.
      *
@@ -773,6 +1358,32 @@ impl Migrations {
             )
             .await
     }
+    /**
+     * Get large files.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/import/large_files` endpoint.
+     *
+     * As opposed to `get_all_large_files`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * List files larger than 100MB found during the import
+     *
+     * FROM: <https://docs.github.com/rest/reference/migrations#get-large-files>
+     */
+    pub fn get_all_large_files_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::PorterLargeFile>> + Unpin + '_ {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/import/large_files",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Update Git LFS preference.
      *
@@ -851,6 +1462,26 @@ impl Migrations {
             )
             .await
     }
+    /**
+     * List user migrations.
+     *
+     * This function performs a `GET` to the `/user/migrations` endpoint.
+     *
+     * As opposed to `list_for_authenticated_user`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Lists all migrations a user has started.
+     *
+     * FROM: <https://docs.github.com/rest/reference/migrations#list-user-migrations>
+     */
+    pub fn list_for_authenticated_user_request(
+        &self,
+    ) -> MigrationsListForAuthenticatedUserRequest<'_> {
+        MigrationsListForAuthenticatedUserRequest {
+            client: self,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List user migrations.
      *
@@ -876,6 +1507,23 @@ impl Migrations {
             )
             .await
     }
+    /**
+     * List user migrations.
+     *
+     * This function performs a `GET` to the `/user/migrations` endpoint.
+     *
+     * As opposed to `list_all_for_authenticated_user`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Lists all migrations a user has started.
+     *
+     * FROM: <https://docs.github.com/rest/reference/migrations#list-user-migrations>
+     */
+    pub fn list_all_for_authenticated_user_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Migration>> + Unpin + '_ {
+        let url = self.client.url("/user/migrations", None);
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Start a user migration.
      *
@@ -949,6 +1597,34 @@ impl Migrations {
             )
             .await
     }
+    /**
+     * Get a user migration status.
+     *
+     * This function performs a `GET` to the `/user/migrations/{migration_id}` endpoint.
+     *
+     * As opposed to `get_status_for_authenticated_user`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Fetches a single user migration. The response includes the `state` of the migration, which can be one of the following values:
+     *
+     * *   `pending` - the migration hasn't started yet.
+     * *   `exporting` - the migration is in progress.
+     * *   `exported` - the migration finished successfully.
+     * *   `failed` - the migration failed.
+     *
+     * Once the migration has been `exported` you can [download the migration archive](https://docs.github.com/rest/reference/migrations#download-a-user-migration-archive).
+     *
+     * FROM: <https://docs.github.com/rest/reference/migrations#get-a-user-migration-status>
+     */
+    pub fn get_status_for_authenticated_user_request(
+        &self,
+        migration_id: i64,
+    ) -> MigrationsGetStatusForAuthenticatedUserRequest<'_> {
+        MigrationsGetStatusForAuthenticatedUserRequest {
+            client: self,
+            migration_id,
+            exclude: None,
+        }
+    }
     /**
      * Download a user migration archive.
      *
@@ -1121,6 +1797,28 @@ impl Migrations {
             )
             .await
     }
+    /**
+     * List repositories for a user migration.
+     *
+     * This function performs a `GET` to the `/user/migrations/{migration_id}/repositories` endpoint.
+     *
+     * As opposed to `list_repos_for_user`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Lists all the repositories for this user migration.
+     *
+     * FROM: <https://docs.github.com/rest/reference/migrations#list-repositories-for-a-user-migration>
+     */
+    pub fn list_repos_for_user_request(
+        &self,
+        migration_id: i64,
+    ) -> MigrationsListReposForUserRequest<'_> {
+        MigrationsListReposForUserRequest {
+            client: self,
+            migration_id,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List repositories for a user migration.
      *
@@ -1153,4 +1851,29 @@ impl Migrations {
             )
             .await
     }
+    /**
+     * List repositories for a user migration.
+     *
+     * This function performs a `GET` to the `/user/migrations/{migration_id}/repositories` endpoint.
+     *
+     * As opposed to `list_all_repos_for_user`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Lists all the repositories for this user migration.
+     *
+     * FROM: <https://docs.github.com/rest/reference/migrations#list-repositories-for-a-user-migration>
+     */
+    pub fn list_all_repos_for_user_stream(
+        &self,
+        migration_id: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::MinimalRepository>> + Unpin + '_
+    {
+        let url = self.client.url(
+            &format!(
+                "/user/migrations/{}/repositories",
+                crate::progenitor_support::encode_path(&migration_id.to_string()),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
 }
@@ -1,6 +1,132 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`OauthAuthorizations::list_grants`], set with a builder.
+pub struct OauthAuthorizationsListGrantsRequest<'a> {
+    client: &'a OauthAuthorizations,
+    per_page: Option<i64>,
+    page: Option<i64>,
+    client_id: Option<&'a str>,
+}
+
+impl<'a> OauthAuthorizationsListGrantsRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sets the `client_id` query parameter.
+    pub fn client_id(mut self, client_id: &'a str) -> Self {
+        self.client_id = Some(client_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::ApplicationGrant>>> {
+        let Self {
+            client,
+            per_page,
+            page,
+            client_id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = client_id {
+            query_args.push(("client_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/applications/grants?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`OauthAuthorizations::list_authorizations`], set with a builder.
+pub struct OauthAuthorizationsListAuthorizationsRequest<'a> {
+    client: &'a OauthAuthorizations,
+    per_page: Option<i64>,
+    page: Option<i64>,
+    client_id: Option<&'a str>,
+}
+
+impl<'a> OauthAuthorizationsListAuthorizationsRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sets the `client_id` query parameter.
+    pub fn client_id(mut self, client_id: &'a str) -> Self {
+        self.client_id = Some(client_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Authorization>>> {
+        let Self {
+            client,
+            per_page,
+            page,
+            client_id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = client_id {
+            query_args.push(("client_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/authorizations?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct OauthAuthorizations {
     pub client: Client,
 }
@@ -58,6 +184,27 @@ impl OauthAuthorizations {
             )
             .await
     }
+    /**
+     * List your grants.
+     *
+     * This function performs a `GET` to the `/applications/grants` endpoint.
+     *
+     * As opposed to `list_grants`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * **Deprecation Notice:** GitHub will discontinue the [OAuth Authorizations API](https://docs.github.com/rest/reference/oauth-authorizations/), which is used by integrations to create personal access tokens and OAuth tokens, and you must now create these tokens using our [web application flow](https://docs.github.com/developers/apps/authorizing-oauth-apps#web-application-flow). The [OAuth Authorizations API](https://docs.github.com/rest/reference/oauth-authorizations) will be removed on November, 13, 2020. For more information, including scheduled brownouts, see the [blog post](https://developer.github.com/changes/2020-02-14-deprecating-oauth-auth-endpoint/).
+     *
+     * You can use this API to list the set of OAuth applications that have been granted access to your account. Unlike the [list your authorizations](https://docs.github.com/rest/reference/oauth-authorizations#list-your-authorizations) API, this API does not manage individual tokens. This API will return one entry for each OAuth application that has been granted access to your account, regardless of the number of tokens an application has generated for your user. The list of OAuth applications returned matches what is shown on [the application authorizations settings screen within GitHub](https://github.com/settings/applications#authorized). The `scopes` returned are the union of scopes authorized for the application. For example, if an application has one token with `repo` scope and another token with `user` scope, the grant will return `["repo", "user"]`.
+     *
+     * FROM: <https://docs.github.com/rest/reference/oauth-authorizations#list-your-grants>
+     */
+    pub fn list_grants_request(&self) -> OauthAuthorizationsListGrantsRequest<'_> {
+        OauthAuthorizationsListGrantsRequest {
+            client: self,
+            per_page: None,
+            page: None,
+            client_id: None,
+        }
+    }
     /**
      * List your grants.
      *
@@ -93,6 +240,34 @@ impl OauthAuthorizations {
             )
             .await
     }
+    /**
+     * List your grants.
+     *
+     * This function performs a `GET` to the `/applications/grants` endpoint.
+     *
+     * As opposed to `list_all_grants`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * **Deprecation Notice:** GitHub will discontinue the [OAuth Authorizations API](https://docs.github.com/rest/reference/oauth-authorizations/), which is used by integrations to create personal access tokens and OAuth tokens, and you must now create these tokens using our [web application flow](https://docs.github.com/developers/apps/authorizing-oauth-apps#web-application-flow). The [OAuth Authorizations API](https://docs.github.com/rest/reference/oauth-authorizations) will be removed on November, 13, 2020. For more information, including scheduled brownouts, see the [blog post](https://developer.github.com/changes/2020-02-14-deprecating-oauth-auth-endpoint/).
+     *
+     * You can use this API to list the set of OAuth applications that have been granted access to your account. Unlike the [list your authorizations](https://docs.github.com/rest/reference/oauth-authorizations#list-your-authorizations) API, this API does not manage individual tokens. This API will return one entry for each OAuth application that has been granted access to your account, regardless of the number of tokens an application has generated for your user. The list of OAuth applications returned matches what is shown on [the application authorizations settings screen within GitHub](https://github.com/settings/applications#authorized). The `scopes` returned are the union of scopes authorized for the application. For example, if an application has one token with `repo` scope and another token with `user` scope, the grant will return `["repo", "user"]`.
+     *
+     * FROM: <https://docs.github.com/rest/reference/oauth-authorizations#list-your-grants>
+     */
+    pub fn list_all_grants_stream(
+        &self,
+        client_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::ApplicationGrant>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !client_id.is_empty() {
+            query_args.push(("client_id".to_string(), client_id.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/applications/grants?{}", query_), None);
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Get a single grant.
      *
@@ -205,6 +380,25 @@ impl OauthAuthorizations {
             )
             .await
     }
+    /**
+     * List your authorizations.
+     *
+     * This function performs a `GET` to the `/authorizations` endpoint.
+     *
+     * As opposed to `list_authorizations`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * **Deprecation Notice:** GitHub will discontinue the [OAuth Authorizations API](https://docs.github.com/rest/reference/oauth-authorizations), which is used by integrations to create personal access tokens and OAuth tokens, and you must now create these tokens using our [web application flow](https://docs.github.com/apps/building-oauth-apps/authorizing-oauth-apps/#web-application-flow). The [OAuth Authorizations API](https://docs.github.com/rest/reference/oauth-authorizations) will be removed on November, 13, 2020. For more information, including scheduled brownouts, see the [blog post](https://developer.github.com/changes/2020-02-14-deprecating-oauth-auth-endpoint/).
+     *
+     * FROM: <https://docs.github.com/rest/reference/oauth-authorizations#list-your-authorizations>
+     */
+    pub fn list_authorizations_request(&self) -> OauthAuthorizationsListAuthorizationsRequest<'_> {
+        OauthAuthorizationsListAuthorizationsRequest {
+            client: self,
+            per_page: None,
+            page: None,
+            client_id: None,
+        }
+    }
     /**
      * List your authorizations.
      *
@@ -238,6 +432,31 @@ impl OauthAuthorizations {
             )
             .await
     }
+    /**
+     * List your authorizations.
+     *
+     * This function performs a `GET` to the `/authorizations` endpoint.
+     *
+     * As opposed to `list_all_authorizations`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * **Deprecation Notice:** GitHub will discontinue the [OAuth Authorizations API](https://docs.github.com/rest/reference/oauth-authorizations), which is used by integrations to create personal access tokens and OAuth tokens, and you must now create these tokens using our [web application flow](https://docs.github.com/apps/building-oauth-apps/authorizing-oauth-apps/#web-application-flow). The [OAuth Authorizations API](https://docs.github.com/rest/reference/oauth-authorizations) will be removed on November, 13, 2020. For more information, including scheduled brownouts, see the [blog post](https://developer.github.com/changes/2020-02-14-deprecating-oauth-auth-endpoint/).
+     *
+     * FROM: <https://docs.github.com/rest/reference/oauth-authorizations#list-your-authorizations>
+     */
+    pub fn list_all_authorizations_stream(
+        &self,
+        client_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Authorization>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !client_id.is_empty() {
+            query_args.push(("client_id".to_string(), client_id.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/authorizations?{}", query_), None);
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Create a new authorization.
      *
@@ -1,6 +1,926 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Orgs::list`], set with a builder.
+pub struct OrgsListRequest<'a> {
+    client: &'a Orgs,
+    since: Option<i64>,
+    per_page: Option<i64>,
+}
+
+impl<'a> OrgsListRequest<'a> {
+    /// Sets the `since` query parameter.
+    pub fn since(mut self, since: i64) -> Self {
+        self.since = Some(since);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::OrganizationSimple>>> {
+        let Self {
+            client,
+            since,
+            per_page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        if let Some(v) = since {
+            query_args.push(("since".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/organizations?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Orgs::get_audit_log`], set with a builder.
+pub struct OrgsGetAuditLogRequest<'a> {
+    client: &'a Orgs,
+    org: &'a str,
+    phrase: Option<&'a str>,
+    include: Option<crate::types::Include>,
+    after: Option<&'a str>,
+    before: Option<&'a str>,
+    order: Option<crate::types::Order>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> OrgsGetAuditLogRequest<'a> {
+    /// Sets the `phrase` query parameter.
+    pub fn phrase(mut self, phrase: &'a str) -> Self {
+        self.phrase = Some(phrase);
+        self
+    }
+
+    /// Sets the `include` query parameter.
+    pub fn include(mut self, include: crate::types::Include) -> Self {
+        self.include = Some(include);
+        self
+    }
+
+    /// Sets the `after` query parameter.
+    pub fn after(mut self, after: &'a str) -> Self {
+        self.after = Some(after);
+        self
+    }
+
+    /// Sets the `before` query parameter.
+    pub fn before(mut self, before: &'a str) -> Self {
+        self.before = Some(before);
+        self
+    }
+
+    /// Sets the `order` query parameter.
+    pub fn order(mut self, order: crate::types::Order) -> Self {
+        self.order = Some(order);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::AuditLogEvent>>> {
+        let Self {
+            client,
+            org,
+            phrase,
+            include,
+            after,
+            before,
+            order,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = after {
+            query_args.push(("after".to_string(), v.to_string()));
+        }
+        if let Some(v) = before {
+            query_args.push(("before".to_string(), v.to_string()));
+        }
+        if let Some(v) = include {
+            query_args.push(("include".to_string(), v.to_string()));
+        }
+        if let Some(v) = order {
+            query_args.push(("order".to_string(), v.to_string()));
+        }
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        if let Some(v) = phrase {
+            query_args.push(("phrase".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/orgs/{}/audit-log?{}",
+                crate::progenitor_support::encode_path(org),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Orgs::list_failed_invitations`], set with a builder.
+pub struct OrgsListFailedInvitationsRequest<'a> {
+    client: &'a Orgs,
+    org: &'a str,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> OrgsListFailedInvitationsRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::OrganizationInvitation>>> {
+        let Self {
+            client,
+            org,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/orgs/{}/failed_invitations?{}",
+                crate::progenitor_support::encode_path(org),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Orgs::list_webhooks`], set with a builder.
+pub struct OrgsListWebhooksRequest<'a> {
+    client: &'a Orgs,
+    org: &'a str,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> OrgsListWebhooksRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::OrgHook>>> {
+        let Self {
+            client,
+            org,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/orgs/{}/hooks?{}",
+                crate::progenitor_support::encode_path(org),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Orgs::list_webhook_deliveries`], set with a builder.
+pub struct OrgsListWebhookDeliveriesRequest<'a> {
+    client: &'a Orgs,
+    org: &'a str,
+    hook_id: i64,
+    per_page: Option<i64>,
+    cursor: Option<&'a str>,
+}
+
+impl<'a> OrgsListWebhookDeliveriesRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `cursor` query parameter.
+    pub fn cursor(mut self, cursor: &'a str) -> Self {
+        self.cursor = Some(cursor);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::HookDeliveryItem>>> {
+        let Self {
+            client,
+            org,
+            hook_id,
+            per_page,
+            cursor,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = cursor {
+            query_args.push(("cursor".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/orgs/{}/hooks/{}/deliveries?{}",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(&hook_id.to_string()),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Orgs::list_app_installations`], set with a builder.
+pub struct OrgsListAppInstallationsRequest<'a> {
+    client: &'a Orgs,
+    org: &'a str,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> OrgsListAppInstallationsRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::AppsListInstallationsResponse>> {
+        let Self {
+            client,
+            org,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/orgs/{}/installations?{}",
+                crate::progenitor_support::encode_path(org),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Orgs::list_pending_invitations`], set with a builder.
+pub struct OrgsListPendingInvitationsRequest<'a> {
+    client: &'a Orgs,
+    org: &'a str,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> OrgsListPendingInvitationsRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::OrganizationInvitation>>> {
+        let Self {
+            client,
+            org,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/orgs/{}/invitations?{}",
+                crate::progenitor_support::encode_path(org),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Orgs::list_invitation_teams`], set with a builder.
+pub struct OrgsListInvitationTeamsRequest<'a> {
+    client: &'a Orgs,
+    org: &'a str,
+    invitation_id: i64,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> OrgsListInvitationTeamsRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Team>>> {
+        let Self {
+            client,
+            org,
+            invitation_id,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/orgs/{}/invitations/{}/teams?{}",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(&invitation_id.to_string()),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Orgs::list_members`], set with a builder.
+pub struct OrgsListMembersRequest<'a> {
+    client: &'a Orgs,
+    org: &'a str,
+    filter: Option<crate::types::OrgsListMembersFilter>,
+    role: Option<crate::types::OrgsListMembersRole>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> OrgsListMembersRequest<'a> {
+    /// Sets the `filter` query parameter.
+    pub fn filter(mut self, filter: crate::types::OrgsListMembersFilter) -> Self {
+        self.filter = Some(filter);
+        self
+    }
+
+    /// Sets the `role` query parameter.
+    pub fn role(mut self, role: crate::types::OrgsListMembersRole) -> Self {
+        self.role = Some(role);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::SimpleUser>>> {
+        let Self {
+            client,
+            org,
+            filter,
+            role,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = filter {
+            query_args.push(("filter".to_string(), v.to_string()));
+        }
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        if let Some(v) = role {
+            query_args.push(("role".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/orgs/{}/members?{}",
+                crate::progenitor_support::encode_path(org),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Orgs::list_outside_collaborators`], set with a builder.
+pub struct OrgsListOutsideCollaboratorsRequest<'a> {
+    client: &'a Orgs,
+    org: &'a str,
+    filter: Option<crate::types::OrgsListMembersFilter>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> OrgsListOutsideCollaboratorsRequest<'a> {
+    /// Sets the `filter` query parameter.
+    pub fn filter(mut self, filter: crate::types::OrgsListMembersFilter) -> Self {
+        self.filter = Some(filter);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::SimpleUser>>> {
+        let Self {
+            client,
+            org,
+            filter,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = filter {
+            query_args.push(("filter".to_string(), v.to_string()));
+        }
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/orgs/{}/outside_collaborators?{}",
+                crate::progenitor_support::encode_path(org),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Orgs::list_public_members`], set with a builder.
+pub struct OrgsListPublicMembersRequest<'a> {
+    client: &'a Orgs,
+    org: &'a str,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> OrgsListPublicMembersRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::SimpleUser>>> {
+        let Self {
+            client,
+            org,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/orgs/{}/public_members?{}",
+                crate::progenitor_support::encode_path(org),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Orgs::list_memberships_for_authenticated_user`], set with a builder.
+pub struct OrgsListMembershipsForAuthenticatedUserRequest<'a> {
+    client: &'a Orgs,
+    state: Option<crate::types::OrgMembershipState>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> OrgsListMembershipsForAuthenticatedUserRequest<'a> {
+    /// Sets the `state` query parameter.
+    pub fn state(mut self, state: crate::types::OrgMembershipState) -> Self {
+        self.state = Some(state);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::OrgMembership>>> {
+        let Self {
+            client,
+            state,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        if let Some(v) = state {
+            query_args.push(("state".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/user/memberships/orgs?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Orgs::list_for_authenticated_user`], set with a builder.
+pub struct OrgsListForAuthenticatedUserRequest<'a> {
+    client: &'a Orgs,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> OrgsListForAuthenticatedUserRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::OrganizationSimple>>> {
+        let Self {
+            client,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(&format!("/user/orgs?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Orgs::list_for_user`], set with a builder.
+pub struct OrgsListForUserRequest<'a> {
+    client: &'a Orgs,
+    username: &'a str,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> OrgsListForUserRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::OrganizationSimple>>> {
+        let Self {
+            client,
+            username,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/users/{}/orgs?{}",
+                crate::progenitor_support::encode_path(username),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct Orgs {
     pub client: Client,
 }
@@ -51,6 +971,26 @@ impl Orgs {
             )
             .await
     }
+    /**
+     * List organizations.
+     *
+     * This function performs a `GET` to the `/organizations` endpoint.
+     *
+     * As opposed to `list`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Lists all organizations, in the order that they were created on GitHub.
+     *
+     * **Note:** Pagination is powered exclusively by the `since` parameter. Use the [Link header](https://docs.github.com/rest/overview/resources-in-the-rest-api#link-header) to get the URL for the next page of organizations.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#list-organizations>
+     */
+    pub fn list_request(&self) -> OrgsListRequest<'_> {
+        OrgsListRequest {
+            client: self,
+            since: None,
+            per_page: None,
+        }
+    }
     /**
      * List organizations.
      *
@@ -74,15 +1014,41 @@ impl Orgs {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/organizations?{}", query_), None);
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client
+            .get_all_pages(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+    /**
+     * List organizations.
+     *
+     * This function performs a `GET` to the `/organizations` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Lists all organizations, in the order that they were created on GitHub.
+     *
+     * **Note:** Pagination is powered exclusively by the `since` parameter. Use the [Link header](https://docs.github.com/rest/overview/resources-in-the-rest-api#link-header) to get the URL for the next page of organizations.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#list-organizations>
+     */
+    pub fn list_all_stream(
+        &self,
+        since: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::OrganizationSimple>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if since > 0 {
+            query_args.push(("since".to_string(), since.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/organizations?{}", query_), None);
+        self.client.get_all_pages_stream(url)
     }
     /**
      * Get an organization.
@@ -233,6 +1199,32 @@ impl Orgs {
             )
             .await
     }
+    /**
+     * Get the audit log for an organization.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/audit-log` endpoint.
+     *
+     * As opposed to `get_audit_log`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Gets the audit log for an organization. For more information, see "[Reviewing the audit log for your organization](https://docs.github.com/github/setting-up-and-managing-organizations-and-teams/reviewing-the-audit-log-for-your-organization)."
+     *
+     * To use this endpoint, you must be an organization owner, and you must use an access token with the `admin:org` scope. GitHub Apps must have the `organization_administration` read permission to use this endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#get-audit-log>
+     */
+    pub fn get_audit_log_request<'a>(&'a self, org: &'a str) -> OrgsGetAuditLogRequest<'a> {
+        OrgsGetAuditLogRequest {
+            client: self,
+            org,
+            phrase: None,
+            include: None,
+            after: None,
+            before: None,
+            order: None,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * Get the audit log for an organization.
      *
@@ -290,6 +1282,55 @@ impl Orgs {
             )
             .await
     }
+    /**
+     * Get the audit log for an organization.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/audit-log` endpoint.
+     *
+     * As opposed to `get_all_audit_log`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Gets the audit log for an organization. For more information, see "[Reviewing the audit log for your organization](https://docs.github.com/github/setting-up-and-managing-organizations-and-teams/reviewing-the-audit-log-for-your-organization)."
+     *
+     * To use this endpoint, you must be an organization owner, and you must use an access token with the `admin:org` scope. GitHub Apps must have the `organization_administration` read permission to use this endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#get-audit-log>
+     */
+    pub fn get_all_audit_log_stream(
+        &self,
+        org: &str,
+        phrase: &str,
+        include: crate::types::Include,
+        after: &str,
+        before: &str,
+        order: crate::types::Order,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::AuditLogEvent>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !after.is_empty() {
+            query_args.push(("after".to_string(), after.to_string()));
+        }
+        if !before.is_empty() {
+            query_args.push(("before".to_string(), before.to_string()));
+        }
+        if !include.to_string().is_empty() {
+            query_args.push(("include".to_string(), include.to_string()));
+        }
+        if !order.to_string().is_empty() {
+            query_args.push(("order".to_string(), order.to_string()));
+        }
+        if !phrase.is_empty() {
+            query_args.push(("phrase".to_string(), phrase.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/orgs/{}/audit-log?{}",
+                crate::progenitor_support::encode_path(org),
+                query_
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * List users blocked by an organization.
      *
@@ -356,6 +1397,30 @@ impl Orgs {
             )
             .await
     }
+    /**
+     * List users blocked by an organization.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/blocks` endpoint.
+     *
+     * As opposed to `list_all_blocked_users`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * List the users blocked by an organization.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#list-users-blocked-by-an-organization>
+     */
+    pub fn list_all_blocked_users_stream(
+        &self,
+        org: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::SimpleUser>> + Unpin + '_ {
+        let url = self.client.url(
+            &format!(
+                "/orgs/{}/blocks",
+                crate::progenitor_support::encode_path(org),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Check if a user is blocked by an organization.
      *
@@ -533,6 +1598,33 @@ impl Orgs {
             )
             .await
     }
+    /**
+     * List SAML SSO authorizations for an organization.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/credential-authorizations` endpoint.
+     *
+     * As opposed to `list_all_saml_sso_authorizations`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Listing and deleting credential authorizations is available to organizations with GitHub Enterprise Cloud. For more information, see [GitHub's products](https://help.github.com/github/getting-started-with-github/githubs-products).
+     *
+     * An authenticated organization owner with the `read:org` scope can list all credential authorizations for an organization that uses SAML single sign-on (SSO). The credentials are either personal access tokens or SSH keys that organization members have authorized for the organization. For more information, see [About authentication with SAML single sign-on](https://help.github.com/en/articles/about-authentication-with-saml-single-sign-on).
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#list-saml-sso-authorizations-for-an-organization>
+     */
+    pub fn list_all_saml_sso_authorizations_stream(
+        &self,
+        org: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::CredentialAuthorization>> + Unpin + '_
+    {
+        let url = self.client.url(
+            &format!(
+                "/orgs/{}/credential-authorizations",
+                crate::progenitor_support::encode_path(org),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Remove a SAML SSO authorization for an organization.
      *
@@ -619,6 +1711,28 @@ impl Orgs {
             )
             .await
     }
+    /**
+     * List failed organization invitations.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/failed_invitations` endpoint.
+     *
+     * As opposed to `list_failed_invitations`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * The return hash contains `failed_at` and `failed_reason` fields which represent the time at which the invitation failed and the reason for the failure.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#list-failed-organization-invitations>
+     */
+    pub fn list_failed_invitations_request<'a>(
+        &'a self,
+        org: &'a str,
+    ) -> OrgsListFailedInvitationsRequest<'a> {
+        OrgsListFailedInvitationsRequest {
+            client: self,
+            org,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List failed organization invitations.
      *
@@ -651,6 +1765,31 @@ impl Orgs {
             )
             .await
     }
+    /**
+     * List failed organization invitations.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/failed_invitations` endpoint.
+     *
+     * As opposed to `list_all_failed_invitations`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * The return hash contains `failed_at` and `failed_reason` fields which represent the time at which the invitation failed and the reason for the failure.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#list-failed-organization-invitations>
+     */
+    pub fn list_all_failed_invitations_stream(
+        &self,
+        org: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::OrganizationInvitation>> + Unpin + '_
+    {
+        let url = self.client.url(
+            &format!(
+                "/orgs/{}/failed_invitations",
+                crate::progenitor_support::encode_path(org),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * List organization webhooks.
      *
@@ -698,6 +1837,25 @@ impl Orgs {
             )
             .await
     }
+    /**
+     * List organization webhooks.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/hooks` endpoint.
+     *
+     * As opposed to `list_webhooks`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#list-organization-webhooks>
+     */
+    pub fn list_webhooks_request<'a>(&'a self, org: &'a str) -> OrgsListWebhooksRequest<'a> {
+        OrgsListWebhooksRequest {
+            client: self,
+            org,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List organization webhooks.
      *
@@ -730,6 +1888,30 @@ impl Orgs {
             )
             .await
     }
+    /**
+     * List organization webhooks.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/hooks` endpoint.
+     *
+     * As opposed to `list_all_webhooks`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#list-organization-webhooks>
+     */
+    pub fn list_all_webhooks_stream(
+        &self,
+        org: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::OrgHook>> + Unpin + '_ {
+        let url = self.client.url(
+            &format!(
+                "/orgs/{}/hooks",
+                crate::progenitor_support::encode_path(org),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Create an organization webhook.
      *
@@ -1006,6 +2188,30 @@ impl Orgs {
             )
             .await
     }
+    /**
+     * List deliveries for an organization webhook.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/hooks/{hook_id}/deliveries` endpoint.
+     *
+     * As opposed to `list_webhook_deliveries`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Returns a list of webhook deliveries for a webhook configured in an organization.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#list-deliveries-for-an-organization-webhook>
+     */
+    pub fn list_webhook_deliveries_request<'a>(
+        &'a self,
+        org: &'a str,
+        hook_id: i64,
+    ) -> OrgsListWebhookDeliveriesRequest<'a> {
+        OrgsListWebhookDeliveriesRequest {
+            client: self,
+            org,
+            hook_id,
+            per_page: None,
+            cursor: None,
+        }
+    }
     /**
      * List deliveries for an organization webhook.
      *
@@ -1047,6 +2253,40 @@ impl Orgs {
             )
             .await
     }
+    /**
+     * List deliveries for an organization webhook.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/hooks/{hook_id}/deliveries` endpoint.
+     *
+     * As opposed to `list_all_webhook_deliveries`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Returns a list of webhook deliveries for a webhook configured in an organization.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#list-deliveries-for-an-organization-webhook>
+     */
+    pub fn list_all_webhook_deliveries_stream(
+        &self,
+        org: &str,
+        hook_id: i64,
+        cursor: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::HookDeliveryItem>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !cursor.is_empty() {
+            query_args.push(("cursor".to_string(), cursor.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/orgs/{}/hooks/{}/deliveries?{}",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(&hook_id.to_string()),
+                query_
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Get a webhook delivery for an organization webhook.
      *
@@ -1207,6 +2447,28 @@ impl Orgs {
             )
             .await
     }
+    /**
+     * List app installations for an organization.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/installations` endpoint.
+     *
+     * As opposed to `list_app_installations`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Lists all GitHub Apps in an organization. The installation count includes all GitHub Apps installed on repositories in the organization. You must be an organization owner with `admin:read` scope to use this endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#list-app-installations-for-an-organization>
+     */
+    pub fn list_app_installations_request<'a>(
+        &'a self,
+        org: &'a str,
+    ) -> OrgsListAppInstallationsRequest<'a> {
+        OrgsListAppInstallationsRequest {
+            client: self,
+            org,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List pending organization invitations.
      *
@@ -1254,6 +2516,28 @@ impl Orgs {
             )
             .await
     }
+    /**
+     * List pending organization invitations.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/invitations` endpoint.
+     *
+     * As opposed to `list_pending_invitations`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * The return hash contains a `role` field which refers to the Organization Invitation role and will be one of the following values: `direct_member`, `admin`, `billing_manager`, `hiring_manager`, or `reinstate`. If the invitee is not a GitHub member, the `login` field in the return hash will be `null`.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#list-pending-organization-invitations>
+     */
+    pub fn list_pending_invitations_request<'a>(
+        &'a self,
+        org: &'a str,
+    ) -> OrgsListPendingInvitationsRequest<'a> {
+        OrgsListPendingInvitationsRequest {
+            client: self,
+            org,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List pending organization invitations.
      *
@@ -1286,6 +2570,31 @@ impl Orgs {
             )
             .await
     }
+    /**
+     * List pending organization invitations.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/invitations` endpoint.
+     *
+     * As opposed to `list_all_pending_invitations`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * The return hash contains a `role` field which refers to the Organization Invitation role and will be one of the following values: `direct_member`, `admin`, `billing_manager`, `hiring_manager`, or `reinstate`. If the invitee is not a GitHub member, the `login` field in the return hash will be `null`.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#list-pending-organization-invitations>
+     */
+    pub fn list_all_pending_invitations_stream(
+        &self,
+        org: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::OrganizationInvitation>> + Unpin + '_
+    {
+        let url = self.client.url(
+            &format!(
+                "/orgs/{}/invitations",
+                crate::progenitor_support::encode_path(org),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Create an organization invitation.
      *
@@ -1412,6 +2721,30 @@ impl Orgs {
             )
             .await
     }
+    /**
+     * List organization invitation teams.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/invitations/{invitation_id}/teams` endpoint.
+     *
+     * As opposed to `list_invitation_teams`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * List all teams associated with an invitation. In order to see invitations in an organization, the authenticated user must be an organization owner.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#list-organization-invitation-teams>
+     */
+    pub fn list_invitation_teams_request<'a>(
+        &'a self,
+        org: &'a str,
+        invitation_id: i64,
+    ) -> OrgsListInvitationTeamsRequest<'a> {
+        OrgsListInvitationTeamsRequest {
+            client: self,
+            org,
+            invitation_id,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List organization invitation teams.
      *
@@ -1446,6 +2779,32 @@ impl Orgs {
             )
             .await
     }
+    /**
+     * List organization invitation teams.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/invitations/{invitation_id}/teams` endpoint.
+     *
+     * As opposed to `list_all_invitation_teams`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * List all teams associated with an invitation. In order to see invitations in an organization, the authenticated user must be an organization owner.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#list-organization-invitation-teams>
+     */
+    pub fn list_all_invitation_teams_stream(
+        &self,
+        org: &str,
+        invitation_id: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Team>> + Unpin + '_ {
+        let url = self.client.url(
+            &format!(
+                "/orgs/{}/invitations/{}/teams",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(&invitation_id.to_string()),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * List organization members.
      *
@@ -1508,6 +2867,27 @@ impl Orgs {
             )
             .await
     }
+    /**
+     * List organization members.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/members` endpoint.
+     *
+     * As opposed to `list_members`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * List all users who are members of an organization. If the authenticated user is also a member of this organization then both concealed and public members will be returned.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#list-organization-members>
+     */
+    pub fn list_members_request<'a>(&'a self, org: &'a str) -> OrgsListMembersRequest<'a> {
+        OrgsListMembersRequest {
+            client: self,
+            org,
+            filter: None,
+            role: None,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List organization members.
      *
@@ -1551,6 +2931,41 @@ impl Orgs {
             )
             .await
     }
+    /**
+     * List organization members.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/members` endpoint.
+     *
+     * As opposed to `list_all_members`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * List all users who are members of an organization. If the authenticated user is also a member of this organization then both concealed and public members will be returned.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#list-organization-members>
+     */
+    pub fn list_all_members_stream(
+        &self,
+        org: &str,
+        filter: crate::types::OrgsListMembersFilter,
+        role: crate::types::OrgsListMembersRole,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::SimpleUser>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !filter.to_string().is_empty() {
+            query_args.push(("filter".to_string(), filter.to_string()));
+        }
+        if !role.to_string().is_empty() {
+            query_args.push(("role".to_string(), role.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/orgs/{}/members?{}",
+                crate::progenitor_support::encode_path(org),
+                query_
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Check organization membership for a user.
      *
@@ -1801,6 +3216,29 @@ impl Orgs {
             )
             .await
     }
+    /**
+     * List outside collaborators for an organization.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/outside_collaborators` endpoint.
+     *
+     * As opposed to `list_outside_collaborators`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * List all users who are outside collaborators of an organization.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#list-outside-collaborators-for-an-organization>
+     */
+    pub fn list_outside_collaborators_request<'a>(
+        &'a self,
+        org: &'a str,
+    ) -> OrgsListOutsideCollaboratorsRequest<'a> {
+        OrgsListOutsideCollaboratorsRequest {
+            client: self,
+            org,
+            filter: None,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List outside collaborators for an organization.
      *
@@ -1840,6 +3278,37 @@ impl Orgs {
             )
             .await
     }
+    /**
+     * List outside collaborators for an organization.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/outside_collaborators` endpoint.
+     *
+     * As opposed to `list_all_outside_collaborators`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * List all users who are outside collaborators of an organization.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#list-outside-collaborators-for-an-organization>
+     */
+    pub fn list_all_outside_collaborators_stream(
+        &self,
+        org: &str,
+        filter: crate::types::OrgsListMembersFilter,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::SimpleUser>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !filter.to_string().is_empty() {
+            query_args.push(("filter".to_string(), filter.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/orgs/{}/outside_collaborators?{}",
+                crate::progenitor_support::encode_path(org),
+                query_
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Convert an organization member to outside collaborator.
      *
@@ -1961,6 +3430,28 @@ impl Orgs {
             )
             .await
     }
+    /**
+     * List public organization members.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/public_members` endpoint.
+     *
+     * As opposed to `list_public_members`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Members of an organization can choose to have their membership publicized or not.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#list-public-organization-members>
+     */
+    pub fn list_public_members_request<'a>(
+        &'a self,
+        org: &'a str,
+    ) -> OrgsListPublicMembersRequest<'a> {
+        OrgsListPublicMembersRequest {
+            client: self,
+            org,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List public organization members.
      *
@@ -1993,6 +3484,30 @@ impl Orgs {
             )
             .await
     }
+    /**
+     * List public organization members.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/public_members` endpoint.
+     *
+     * As opposed to `list_all_public_members`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Members of an organization can choose to have their membership publicized or not.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#list-public-organization-members>
+     */
+    pub fn list_all_public_members_stream(
+        &self,
+        org: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::SimpleUser>> + Unpin + '_ {
+        let url = self.client.url(
+            &format!(
+                "/orgs/{}/public_members",
+                crate::progenitor_support::encode_path(org),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Check public organization membership for a user.
      *
@@ -2151,6 +3666,27 @@ impl Orgs {
             )
             .await
     }
+    /**
+     * List organization memberships for the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/memberships/orgs` endpoint.
+     *
+     * As opposed to `list_memberships_for_authenticated_user`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#list-organization-memberships-for-the-authenticated-user>
+     */
+    pub fn list_memberships_for_authenticated_user_request(
+        &self,
+    ) -> OrgsListMembershipsForAuthenticatedUserRequest<'_> {
+        OrgsListMembershipsForAuthenticatedUserRequest {
+            client: self,
+            state: None,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List organization memberships for the authenticated user.
      *
@@ -2184,6 +3720,31 @@ impl Orgs {
             )
             .await
     }
+    /**
+     * List organization memberships for the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/memberships/orgs` endpoint.
+     *
+     * As opposed to `list_all_memberships_for_authenticated_user`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#list-organization-memberships-for-the-authenticated-user>
+     */
+    pub fn list_all_memberships_for_authenticated_user_stream(
+        &self,
+        state: crate::types::OrgMembershipState,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::OrgMembership>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !state.to_string().is_empty() {
+            query_args.push(("state".to_string(), state.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/user/memberships/orgs?{}", query_), None);
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Get an organization membership for the authenticated user.
      *
@@ -2295,6 +3856,28 @@ impl Orgs {
             )
             .await
     }
+    /**
+     * List organizations for the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/orgs` endpoint.
+     *
+     * As opposed to `list_for_authenticated_user`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * List organizations for the authenticated user.
+     *
+     * **OAuth scope requirements**
+     *
+     * This only lists organizations that your authorization allows you to operate on in some way (e.g., you can list teams with `read:org` scope, you can publicize your organization membership with `user` scope, etc.). Therefore, this API requires at least `user` or `read:org` scope. OAuth requests with insufficient scope receive a `403 Forbidden` response.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#list-organizations-for-the-authenticated-user>
+     */
+    pub fn list_for_authenticated_user_request(&self) -> OrgsListForAuthenticatedUserRequest<'_> {
+        OrgsListForAuthenticatedUserRequest {
+            client: self,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List organizations for the authenticated user.
      *
@@ -2324,6 +3907,28 @@ impl Orgs {
             )
             .await
     }
+    /**
+     * List organizations for the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/orgs` endpoint.
+     *
+     * As opposed to `list_all_for_authenticated_user`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * List organizations for the authenticated user.
+     *
+     * **OAuth scope requirements**
+     *
+     * This only lists organizations that your authorization allows you to operate on in some way (e.g., you can list teams with `read:org` scope, you can publicize your organization membership with `user` scope, etc.). Therefore, this API requires at least `user` or `read:org` scope. OAuth requests with insufficient scope receive a `403 Forbidden` response.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#list-organizations-for-the-authenticated-user>
+     */
+    pub fn list_all_for_authenticated_user_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::OrganizationSimple>> + Unpin + '_
+    {
+        let url = self.client.url("/user/orgs", None);
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * List organizations for a user.
      *
@@ -2373,6 +3978,27 @@ impl Orgs {
             )
             .await
     }
+    /**
+     * List organizations for a user.
+     *
+     * This function performs a `GET` to the `/users/{username}/orgs` endpoint.
+     *
+     * As opposed to `list_for_user`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * List [public organization memberships](https://help.github.com/articles/publicizing-or-concealing-organization-membership) for the specified user.
+     *
+     * This method only lists _public_ memberships, regardless of authentication. If you need to fetch all of the organization memberships (public and private) for the authenticated user, use the [List organizations for the authenticated user](https://docs.github.com/rest/reference/orgs#list-organizations-for-the-authenticated-user) API instead.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#list-organizations-for-a-user>
+     */
+    pub fn list_for_user_request<'a>(&'a self, username: &'a str) -> OrgsListForUserRequest<'a> {
+        OrgsListForUserRequest {
+            client: self,
+            username,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List organizations for a user.
      *
@@ -2407,4 +4033,31 @@ impl Orgs {
             )
             .await
     }
+    /**
+     * List organizations for a user.
+     *
+     * This function performs a `GET` to the `/users/{username}/orgs` endpoint.
+     *
+     * As opposed to `list_all_for_user`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * List [public organization memberships](https://help.github.com/articles/publicizing-or-concealing-organization-membership) for the specified user.
+     *
+     * This method only lists _public_ memberships, regardless of authentication. If you need to fetch all of the organization memberships (public and private) for the authenticated user, use the [List organizations for the authenticated user](https://docs.github.com/rest/reference/orgs#list-organizations-for-the-authenticated-user) API instead.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#list-organizations-for-a-user>
+     */
+    pub fn list_all_for_user_stream(
+        &self,
+        username: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::OrganizationSimple>> + Unpin + '_
+    {
+        let url = self.client.url(
+            &format!(
+                "/users/{}/orgs",
+                crate::progenitor_support::encode_path(username),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
 }
@@ -1,6 +1,266 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Packages::restore_package_for_org`], set with a builder.
+pub struct PackagesRestorePackageForOrgRequest<'a> {
+    client: &'a Packages,
+    package_type: crate::types::PackageType,
+    package_name: &'a str,
+    org: &'a str,
+    token: Option<&'a str>,
+}
+
+impl<'a> PackagesRestorePackageForOrgRequest<'a> {
+    /// Sets the `token` query parameter.
+    pub fn token(mut self, token: &'a str) -> Self {
+        self.token = Some(token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            package_type,
+            package_name,
+            org,
+            token,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = token {
+            query_args.push(("token".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/orgs/{}/packages/{}/{}/restore?{}",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(&package_type.to_string()),
+                crate::progenitor_support::encode_path(package_name),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Packages::get_all_package_versions_for_package_owned_by_org`], set with a builder.
+pub struct PackagesGetAllPackageVersionsForPackageOwnedByOrgRequest<'a> {
+    client: &'a Packages,
+    package_type: crate::types::PackageType,
+    package_name: &'a str,
+    org: &'a str,
+    page: Option<i64>,
+    per_page: Option<i64>,
+    state: Option<crate::types::PackagesGetAllPackageVersionsOwnedByOrgState>,
+}
+
+impl<'a> PackagesGetAllPackageVersionsForPackageOwnedByOrgRequest<'a> {
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `state` query parameter.
+    pub fn state(
+        mut self,
+        state: crate::types::PackagesGetAllPackageVersionsOwnedByOrgState,
+    ) -> Self {
+        self.state = Some(state);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::PackageVersion>>> {
+        let Self {
+            client,
+            package_type,
+            package_name,
+            org,
+            page,
+            per_page,
+            state,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        if let Some(v) = state {
+            query_args.push(("state".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/orgs/{}/packages/{}/{}/versions?{}",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(&package_type.to_string()),
+                crate::progenitor_support::encode_path(package_name),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Packages::restore_package_for_authenticated_user`], set with a builder.
+pub struct PackagesRestorePackageForAuthenticatedUserRequest<'a> {
+    client: &'a Packages,
+    package_type: crate::types::PackageType,
+    package_name: &'a str,
+    token: Option<&'a str>,
+}
+
+impl<'a> PackagesRestorePackageForAuthenticatedUserRequest<'a> {
+    /// Sets the `token` query parameter.
+    pub fn token(mut self, token: &'a str) -> Self {
+        self.token = Some(token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            package_type,
+            package_name,
+            token,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = token {
+            query_args.push(("token".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/user/packages/{}/{}/restore?{}",
+                crate::progenitor_support::encode_path(&package_type.to_string()),
+                crate::progenitor_support::encode_path(package_name),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Packages::get_all_package_versions_for_package_owned_by_authenticated_user`], set with a builder.
+pub struct PackagesGetAllPackageVersionsForPackageOwnedByAuthenticatedUserRequest<'a> {
+    client: &'a Packages,
+    package_type: crate::types::PackageType,
+    package_name: &'a str,
+    page: Option<i64>,
+    per_page: Option<i64>,
+    state: Option<crate::types::PackagesGetAllPackageVersionsOwnedByOrgState>,
+}
+
+impl<'a> PackagesGetAllPackageVersionsForPackageOwnedByAuthenticatedUserRequest<'a> {
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `state` query parameter.
+    pub fn state(
+        mut self,
+        state: crate::types::PackagesGetAllPackageVersionsOwnedByOrgState,
+    ) -> Self {
+        self.state = Some(state);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::PackageVersion>>> {
+        let Self {
+            client,
+            package_type,
+            package_name,
+            page,
+            per_page,
+            state,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        if let Some(v) = state {
+            query_args.push(("state".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/user/packages/{}/{}/versions?{}",
+                crate::progenitor_support::encode_path(&package_type.to_string()),
+                crate::progenitor_support::encode_path(package_name),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct Packages {
     pub client: Client,
 }
@@ -154,6 +414,39 @@ impl Packages {
             )
             .await
     }
+    /**
+     * Restore a package for an organization.
+     *
+     * This function performs a `POST` to the `/orgs/{org}/packages/{package_type}/{package_name}/restore` endpoint.
+     *
+     * As opposed to `restore_package_for_org`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Restores an entire package in an organization.
+     *
+     * You can restore a deleted package under the following conditions:
+     *   - The package was deleted within the last 30 days.
+     *   - The same package namespace and version is still available and not reused for a new package. If the same package namespace is not available, you will not be able to restore your package. In this scenario, to restore the deleted package, you must delete the new package that uses the deleted package's namespace first.
+     *
+     * To use this endpoint, you must have admin permissions in the organization and authenticate using an access token with the `packages:read` and `packages:write` scopes. In addition:
+     * - If `package_type` is not `container`, your token must also include the `repo` scope.
+     * - If `package_type` is `container`, you must also have admin permissions to the container that you want to restore.
+     *
+     * FROM: <https://docs.github.com/rest/reference/packages#restore-a-package-for-an-organization>
+     */
+    pub fn restore_package_for_org_request<'a>(
+        &'a self,
+        package_type: crate::types::PackageType,
+        package_name: &'a str,
+        org: &'a str,
+    ) -> PackagesRestorePackageForOrgRequest<'a> {
+        PackagesRestorePackageForOrgRequest {
+            client: self,
+            package_type,
+            package_name,
+            org,
+            token: None,
+        }
+    }
     /**
      * Get all package versions for a package owned by an organization.
      *
@@ -215,6 +508,36 @@ impl Packages {
             )
             .await
     }
+    /**
+     * Get all package versions for a package owned by an organization.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/packages/{package_type}/{package_name}/versions` endpoint.
+     *
+     * As opposed to `get_all_package_versions_for_package_owned_by_org`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Returns all package versions for a package owned by an organization.
+     *
+     * To use this endpoint, you must authenticate using an access token with the `packages:read` scope.
+     * If `package_type` is not `container`, your token must also include the `repo` scope.
+     *
+     * FROM: <https://docs.github.com/rest/reference/packages#get-all-package-versions-for-a-package-owned-by-an-organization>
+     */
+    pub fn get_all_package_versions_for_package_owned_by_org_request<'a>(
+        &'a self,
+        package_type: crate::types::PackageType,
+        package_name: &'a str,
+        org: &'a str,
+    ) -> PackagesGetAllPackageVersionsForPackageOwnedByOrgRequest<'a> {
+        PackagesGetAllPackageVersionsForPackageOwnedByOrgRequest {
+            client: self,
+            package_type,
+            package_name,
+            org,
+            page: None,
+            per_page: None,
+            state: None,
+        }
+    }
     /**
      * Get all package versions for a package owned by an organization.
      *
@@ -261,6 +584,44 @@ impl Packages {
             )
             .await
     }
+    /**
+     * Get all package versions for a package owned by an organization.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/packages/{package_type}/{package_name}/versions` endpoint.
+     *
+     * As opposed to `get_all_all_package_versions_for_package_owned_by_org`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Returns all package versions for a package owned by an organization.
+     *
+     * To use this endpoint, you must authenticate using an access token with the `packages:read` scope.
+     * If `package_type` is not `container`, your token must also include the `repo` scope.
+     *
+     * FROM: <https://docs.github.com/rest/reference/packages#get-all-package-versions-for-a-package-owned-by-an-organization>
+     */
+    pub fn get_all_all_package_versions_for_package_owned_by_org_stream(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+        org: &str,
+        state: crate::types::PackagesGetAllPackageVersionsOwnedByOrgState,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::PackageVersion>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !state.to_string().is_empty() {
+            query_args.push(("state".to_string(), state.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/orgs/{}/packages/{}/{}/versions?{}",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(&package_type.to_string()),
+                crate::progenitor_support::encode_path(package_name),
+                query_
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Get a package version for an organization.
      *
@@ -536,6 +897,35 @@ impl Packages {
             )
             .await
     }
+    /**
+     * Restore a package for the authenticated user.
+     *
+     * This function performs a `POST` to the `/user/packages/{package_type}/{package_name}/restore` endpoint.
+     *
+     * As opposed to `restore_package_for_authenticated_user`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Restores a package owned by the authenticated user.
+     *
+     * You can restore a deleted package under the following conditions:
+     *   - The package was deleted within the last 30 days.
+     *   - The same package namespace and version is still available and not reused for a new package. If the same package namespace is not available, you will not be able to restore your package. In this scenario, to restore the deleted package, you must delete the new package that uses the deleted package's namespace first.
+     *
+     * To use this endpoint, you must authenticate using an access token with the `packages:read` and `packages:write` scopes. If `package_type` is not `container`, your token must also include the `repo` scope.
+     *
+     * FROM: <https://docs.github.com/rest/reference/packages#restore-a-package-for-the-authenticated-user>
+     */
+    pub fn restore_package_for_authenticated_user_request<'a>(
+        &'a self,
+        package_type: crate::types::PackageType,
+        package_name: &'a str,
+    ) -> PackagesRestorePackageForAuthenticatedUserRequest<'a> {
+        PackagesRestorePackageForAuthenticatedUserRequest {
+            client: self,
+            package_type,
+            package_name,
+            token: None,
+        }
+    }
     /**
      * Get all package versions for a package owned by the authenticated user.
      *
@@ -594,6 +984,34 @@ impl Packages {
             )
             .await
     }
+    /**
+     * Get all package versions for a package owned by the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/packages/{package_type}/{package_name}/versions` endpoint.
+     *
+     * As opposed to `get_all_package_versions_for_package_owned_by_authenticated_user`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Returns all package versions for a package owned by the authenticated user.
+     *
+     * To use this endpoint, you must authenticate using an access token with the `packages:read` scope.
+     * If `package_type` is not `container`, your token must also include the `repo` scope.
+     *
+     * FROM: <https://docs.github.com/rest/reference/packages#get-all-package-versions-for-a-package-owned-by-the-authenticated-user>
+     */
+    pub fn get_all_package_versions_for_package_owned_by_authenticated_user_request<'a>(
+        &'a self,
+        package_type: crate::types::PackageType,
+        package_name: &'a str,
+    ) -> PackagesGetAllPackageVersionsForPackageOwnedByAuthenticatedUserRequest<'a> {
+        PackagesGetAllPackageVersionsForPackageOwnedByAuthenticatedUserRequest {
+            client: self,
+            package_type,
+            package_name,
+            page: None,
+            per_page: None,
+            state: None,
+        }
+    }
     /**
      * Get all package versions for a package owned by the authenticated user.
      *
@@ -638,6 +1056,42 @@ impl Packages {
             )
             .await
     }
+    /**
+     * Get all package versions for a package owned by the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/packages/{package_type}/{package_name}/versions` endpoint.
+     *
+     * As opposed to `get_all_all_package_versions_for_package_owned_by_authenticated_user`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Returns all package versions for a package owned by the authenticated user.
+     *
+     * To use this endpoint, you must authenticate using an access token with the `packages:read` scope.
+     * If `package_type` is not `container`, your token must also include the `repo` scope.
+     *
+     * FROM: <https://docs.github.com/rest/reference/packages#get-all-package-versions-for-a-package-owned-by-the-authenticated-user>
+     */
+    pub fn get_all_all_package_versions_for_package_owned_by_authenticated_user_stream(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+        state: crate::types::PackagesGetAllPackageVersionsOwnedByOrgState,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::PackageVersion>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !state.to_string().is_empty() {
+            query_args.push(("state".to_string(), state.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/user/packages/{}/{}/versions?{}",
+                crate::progenitor_support::encode_path(&package_type.to_string()),
+                crate::progenitor_support::encode_path(package_name),
+                query_
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Get a package version for the authenticated user.
      *
@@ -895,6 +1349,37 @@ impl Packages {
             )
             .await
     }
+    /**
+     * Get all package versions for a package owned by a user.
+     *
+     * This function performs a `GET` to the `/users/{username}/packages/{package_type}/{package_name}/versions` endpoint.
+     *
+     * As opposed to `get_all_all_package_versions_for_package_owned_by_user`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Returns all package versions for a public package owned by a specified user.
+     *
+     * To use this endpoint, you must authenticate using an access token with the `packages:read` scope.
+     * If `package_type` is not `container`, your token must also include the `repo` scope.
+     *
+     * FROM: <https://docs.github.com/rest/reference/packages#get-all-package-versions-for-a-package-owned-by-a-user>
+     */
+    pub fn get_all_all_package_versions_for_package_owned_by_user_stream(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+        username: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::PackageVersion>> + Unpin + '_ {
+        let url = self.client.url(
+            &format!(
+                "/users/{}/packages/{}/{}/versions",
+                crate::progenitor_support::encode_path(username),
+                crate::progenitor_support::encode_path(&package_type.to_string()),
+                crate::progenitor_support::encode_path(package_name),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Get a package version for a user.
      *
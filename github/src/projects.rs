@@ -1,6 +1,418 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Projects::list_for_org`], set with a builder.
+pub struct ProjectsListForOrgRequest<'a> {
+    client: &'a Projects,
+    org: &'a str,
+    state: Option<crate::types::IssuesListState>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> ProjectsListForOrgRequest<'a> {
+    /// Sets the `state` query parameter.
+    pub fn state(mut self, state: crate::types::IssuesListState) -> Self {
+        self.state = Some(state);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Project>>> {
+        let Self {
+            client,
+            org,
+            state,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        if let Some(v) = state {
+            query_args.push(("state".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/orgs/{}/projects?{}",
+                crate::progenitor_support::encode_path(org),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Projects::list_cards`], set with a builder.
+pub struct ProjectsListCardsRequest<'a> {
+    client: &'a Projects,
+    column_id: i64,
+    archived_state: Option<crate::types::ArchivedState>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> ProjectsListCardsRequest<'a> {
+    /// Sets the `archived_state` query parameter.
+    pub fn archived_state(mut self, archived_state: crate::types::ArchivedState) -> Self {
+        self.archived_state = Some(archived_state);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::ProjectCard>>> {
+        let Self {
+            client,
+            column_id,
+            archived_state,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = archived_state {
+            query_args.push(("archived_state".to_string(), v.to_string()));
+        }
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/projects/columns/{}/cards?{}",
+                crate::progenitor_support::encode_path(&column_id.to_string()),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Projects::list_collaborators`], set with a builder.
+pub struct ProjectsListCollaboratorsRequest<'a> {
+    client: &'a Projects,
+    project_id: i64,
+    affiliation: Option<crate::types::Affiliation>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> ProjectsListCollaboratorsRequest<'a> {
+    /// Sets the `affiliation` query parameter.
+    pub fn affiliation(mut self, affiliation: crate::types::Affiliation) -> Self {
+        self.affiliation = Some(affiliation);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::SimpleUser>>> {
+        let Self {
+            client,
+            project_id,
+            affiliation,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = affiliation {
+            query_args.push(("affiliation".to_string(), v.to_string()));
+        }
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/projects/{}/collaborators?{}",
+                crate::progenitor_support::encode_path(&project_id.to_string()),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Projects::list_columns`], set with a builder.
+pub struct ProjectsListColumnsRequest<'a> {
+    client: &'a Projects,
+    project_id: i64,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> ProjectsListColumnsRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::ProjectColumn>>> {
+        let Self {
+            client,
+            project_id,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/projects/{}/columns?{}",
+                crate::progenitor_support::encode_path(&project_id.to_string()),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Projects::list_for_repo`], set with a builder.
+pub struct ProjectsListForRepoRequest<'a> {
+    client: &'a Projects,
+    owner: &'a str,
+    repo: &'a str,
+    state: Option<crate::types::IssuesListState>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> ProjectsListForRepoRequest<'a> {
+    /// Sets the `state` query parameter.
+    pub fn state(mut self, state: crate::types::IssuesListState) -> Self {
+        self.state = Some(state);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Project>>> {
+        let Self {
+            client,
+            owner,
+            repo,
+            state,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        if let Some(v) = state {
+            query_args.push(("state".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/repos/{}/{}/projects?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Projects::list_for_user`], set with a builder.
+pub struct ProjectsListForUserRequest<'a> {
+    client: &'a Projects,
+    username: &'a str,
+    state: Option<crate::types::IssuesListState>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> ProjectsListForUserRequest<'a> {
+    /// Sets the `state` query parameter.
+    pub fn state(mut self, state: crate::types::IssuesListState) -> Self {
+        self.state = Some(state);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Project>>> {
+        let Self {
+            client,
+            username,
+            state,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        if let Some(v) = state {
+            query_args.push(("state".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/users/{}/projects?{}",
+                crate::progenitor_support::encode_path(username),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct Projects {
     pub client: Client,
 }
@@ -63,6 +475,26 @@ impl Projects {
             )
             .await
     }
+    /**
+     * List organization projects.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/projects` endpoint.
+     *
+     * As opposed to `list_for_org`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Lists the projects in an organization. Returns a `404 Not Found` status if projects are disabled in the organization. If you do not have sufficient privileges to perform this action, a `401 Unauthorized` or `410 Gone` status is returned.
+     *
+     * FROM: <https://docs.github.com/rest/reference/projects#list-organization-projects>
+     */
+    pub fn list_for_org_request<'a>(&'a self, org: &'a str) -> ProjectsListForOrgRequest<'a> {
+        ProjectsListForOrgRequest {
+            client: self,
+            org,
+            state: None,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List organization projects.
      *
@@ -102,6 +534,37 @@ impl Projects {
             )
             .await
     }
+    /**
+     * List organization projects.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/projects` endpoint.
+     *
+     * As opposed to `list_all_for_org`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Lists the projects in an organization. Returns a `404 Not Found` status if projects are disabled in the organization. If you do not have sufficient privileges to perform this action, a `401 Unauthorized` or `410 Gone` status is returned.
+     *
+     * FROM: <https://docs.github.com/rest/reference/projects#list-organization-projects>
+     */
+    pub fn list_all_for_org_stream(
+        &self,
+        org: &str,
+        state: crate::types::IssuesListState,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Project>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !state.to_string().is_empty() {
+            query_args.push(("state".to_string(), state.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/orgs/{}/projects?{}",
+                crate::progenitor_support::encode_path(org),
+                query_
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Create an organization project.
      *
@@ -424,6 +887,26 @@ impl Projects {
             )
             .await
     }
+    /**
+     * List project cards.
+     *
+     * This function performs a `GET` to the `/projects/columns/{column_id}/cards` endpoint.
+     *
+     * As opposed to `list_cards`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/projects#list-project-cards>
+     */
+    pub fn list_cards_request(&self, column_id: i64) -> ProjectsListCardsRequest<'_> {
+        ProjectsListCardsRequest {
+            client: self,
+            column_id,
+            archived_state: None,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List project cards.
      *
@@ -463,6 +946,37 @@ impl Projects {
             )
             .await
     }
+    /**
+     * List project cards.
+     *
+     * This function performs a `GET` to the `/projects/columns/{column_id}/cards` endpoint.
+     *
+     * As opposed to `list_all_cards`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/projects#list-project-cards>
+     */
+    pub fn list_all_cards_stream(
+        &self,
+        column_id: i64,
+        archived_state: crate::types::ArchivedState,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::ProjectCard>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !archived_state.to_string().is_empty() {
+            query_args.push(("archived_state".to_string(), archived_state.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/projects/columns/{}/cards?{}",
+                crate::progenitor_support::encode_path(&column_id.to_string()),
+                query_
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Create a project card.
      *
@@ -688,6 +1202,29 @@ impl Projects {
             )
             .await
     }
+    /**
+     * List project collaborators.
+     *
+     * This function performs a `GET` to the `/projects/{project_id}/collaborators` endpoint.
+     *
+     * As opposed to `list_collaborators`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Lists the collaborators for an organization project. For a project, the list of collaborators includes outside collaborators, organization members that are direct collaborators, organization members with access through team memberships, organization members with access through default organization permissions, and organization owners. You must be an organization owner or a project `admin` to list collaborators.
+     *
+     * FROM: <https://docs.github.com/rest/reference/projects#list-project-collaborators>
+     */
+    pub fn list_collaborators_request(
+        &self,
+        project_id: i64,
+    ) -> ProjectsListCollaboratorsRequest<'_> {
+        ProjectsListCollaboratorsRequest {
+            client: self,
+            project_id,
+            affiliation: None,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List project collaborators.
      *
@@ -727,6 +1264,37 @@ impl Projects {
             )
             .await
     }
+    /**
+     * List project collaborators.
+     *
+     * This function performs a `GET` to the `/projects/{project_id}/collaborators` endpoint.
+     *
+     * As opposed to `list_all_collaborators`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Lists the collaborators for an organization project. For a project, the list of collaborators includes outside collaborators, organization members that are direct collaborators, organization members with access through team memberships, organization members with access through default organization permissions, and organization owners. You must be an organization owner or a project `admin` to list collaborators.
+     *
+     * FROM: <https://docs.github.com/rest/reference/projects#list-project-collaborators>
+     */
+    pub fn list_all_collaborators_stream(
+        &self,
+        project_id: i64,
+        affiliation: crate::types::Affiliation,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::SimpleUser>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !affiliation.to_string().is_empty() {
+            query_args.push(("affiliation".to_string(), affiliation.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/projects/{}/collaborators?{}",
+                crate::progenitor_support::encode_path(&project_id.to_string()),
+                query_
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Add project collaborator.
      *
@@ -886,6 +1454,25 @@ impl Projects {
             )
             .await
     }
+    /**
+     * List project columns.
+     *
+     * This function performs a `GET` to the `/projects/{project_id}/columns` endpoint.
+     *
+     * As opposed to `list_columns`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/projects#list-project-columns>
+     */
+    pub fn list_columns_request(&self, project_id: i64) -> ProjectsListColumnsRequest<'_> {
+        ProjectsListColumnsRequest {
+            client: self,
+            project_id,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List project columns.
      *
@@ -918,6 +1505,30 @@ impl Projects {
             )
             .await
     }
+    /**
+     * List project columns.
+     *
+     * This function performs a `GET` to the `/projects/{project_id}/columns` endpoint.
+     *
+     * As opposed to `list_all_columns`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/projects#list-project-columns>
+     */
+    pub fn list_all_columns_stream(
+        &self,
+        project_id: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::ProjectColumn>> + Unpin + '_ {
+        let url = self.client.url(
+            &format!(
+                "/projects/{}/columns",
+                crate::progenitor_support::encode_path(&project_id.to_string()),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Create a project column.
      *
@@ -1008,6 +1619,31 @@ impl Projects {
             )
             .await
     }
+    /**
+     * List repository projects.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/projects` endpoint.
+     *
+     * As opposed to `list_for_repo`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Lists the projects in a repository. Returns a `404 Not Found` status if projects are disabled in the repository. If you do not have sufficient privileges to perform this action, a `401 Unauthorized` or `410 Gone` status is returned.
+     *
+     * FROM: <https://docs.github.com/rest/reference/projects#list-repository-projects>
+     */
+    pub fn list_for_repo_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+    ) -> ProjectsListForRepoRequest<'a> {
+        ProjectsListForRepoRequest {
+            client: self,
+            owner,
+            repo,
+            state: None,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List repository projects.
      *
@@ -1049,6 +1685,39 @@ impl Projects {
             )
             .await
     }
+    /**
+     * List repository projects.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/projects` endpoint.
+     *
+     * As opposed to `list_all_for_repo`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Lists the projects in a repository. Returns a `404 Not Found` status if projects are disabled in the repository. If you do not have sufficient privileges to perform this action, a `401 Unauthorized` or `410 Gone` status is returned.
+     *
+     * FROM: <https://docs.github.com/rest/reference/projects#list-repository-projects>
+     */
+    pub fn list_all_for_repo_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        state: crate::types::IssuesListState,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Project>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !state.to_string().is_empty() {
+            query_args.push(("state".to_string(), state.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/projects?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                query_
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Create a repository project.
      *
@@ -1163,6 +1832,29 @@ impl Projects {
             )
             .await
     }
+    /**
+     * List user projects.
+     *
+     * This function performs a `GET` to the `/users/{username}/projects` endpoint.
+     *
+     * As opposed to `list_for_user`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/projects#list-user-projects>
+     */
+    pub fn list_for_user_request<'a>(
+        &'a self,
+        username: &'a str,
+    ) -> ProjectsListForUserRequest<'a> {
+        ProjectsListForUserRequest {
+            client: self,
+            username,
+            state: None,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List user projects.
      *
@@ -1202,4 +1894,35 @@ impl Projects {
             )
             .await
     }
+    /**
+     * List user projects.
+     *
+     * This function performs a `GET` to the `/users/{username}/projects` endpoint.
+     *
+     * As opposed to `list_all_for_user`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/projects#list-user-projects>
+     */
+    pub fn list_all_for_user_stream(
+        &self,
+        username: &str,
+        state: crate::types::IssuesListState,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Project>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !state.to_string().is_empty() {
+            query_args.push(("state".to_string(), state.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/users/{}/projects?{}",
+                crate::progenitor_support::encode_path(username),
+                query_
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
 }
@@ -1,6 +1,650 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Pulls::list`], set with a builder.
+pub struct PullsListRequest<'a> {
+    client: &'a Pulls,
+    owner: &'a str,
+    repo: &'a str,
+    state: Option<crate::types::IssuesListState>,
+    head: Option<&'a str>,
+    base: Option<&'a str>,
+    sort: Option<crate::types::PullsListSort>,
+    direction: Option<crate::types::Order>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> PullsListRequest<'a> {
+    /// Sets the `state` query parameter.
+    pub fn state(mut self, state: crate::types::IssuesListState) -> Self {
+        self.state = Some(state);
+        self
+    }
+
+    /// Sets the `head` query parameter.
+    pub fn head(mut self, head: &'a str) -> Self {
+        self.head = Some(head);
+        self
+    }
+
+    /// Sets the `base` query parameter.
+    pub fn base(mut self, base: &'a str) -> Self {
+        self.base = Some(base);
+        self
+    }
+
+    /// Sets the `sort` query parameter.
+    pub fn sort(mut self, sort: crate::types::PullsListSort) -> Self {
+        self.sort = Some(sort);
+        self
+    }
+
+    /// Sets the `direction` query parameter.
+    pub fn direction(mut self, direction: crate::types::Order) -> Self {
+        self.direction = Some(direction);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::PullRequestSimple>>> {
+        let Self {
+            client,
+            owner,
+            repo,
+            state,
+            head,
+            base,
+            sort,
+            direction,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = base {
+            query_args.push(("base".to_string(), v.to_string()));
+        }
+        if let Some(v) = direction {
+            query_args.push(("direction".to_string(), v.to_string()));
+        }
+        if let Some(v) = head {
+            query_args.push(("head".to_string(), v.to_string()));
+        }
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        if let Some(v) = sort {
+            query_args.push(("sort".to_string(), v.to_string()));
+        }
+        if let Some(v) = state {
+            query_args.push(("state".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/repos/{}/{}/pulls?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Pulls::list_review_comments_for_repo`], set with a builder.
+pub struct PullsListReviewCommentsForRepoRequest<'a> {
+    client: &'a Pulls,
+    owner: &'a str,
+    repo: &'a str,
+    sort: Option<crate::types::PullsListReviewCommentsRepoSort>,
+    direction: Option<crate::types::Order>,
+    since: Option<chrono::DateTime<chrono::Utc>>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> PullsListReviewCommentsForRepoRequest<'a> {
+    /// Sets the `sort` query parameter.
+    pub fn sort(mut self, sort: crate::types::PullsListReviewCommentsRepoSort) -> Self {
+        self.sort = Some(sort);
+        self
+    }
+
+    /// Sets the `direction` query parameter.
+    pub fn direction(mut self, direction: crate::types::Order) -> Self {
+        self.direction = Some(direction);
+        self
+    }
+
+    /// Sets the `since` query parameter.
+    pub fn since(mut self, since: chrono::DateTime<chrono::Utc>) -> Self {
+        self.since = Some(since);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::PullRequestReviewComment>>> {
+        let Self {
+            client,
+            owner,
+            repo,
+            sort,
+            direction,
+            since,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = direction {
+            query_args.push(("direction".to_string(), v.to_string()));
+        }
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        if let Some(date) = since {
+            query_args.push(("since".to_string(), date.to_rfc3339()));
+        }
+        if let Some(v) = sort {
+            query_args.push(("sort".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/repos/{}/{}/pulls/comments?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Pulls::list_review_comments`], set with a builder.
+pub struct PullsListReviewCommentsRequest<'a> {
+    client: &'a Pulls,
+    owner: &'a str,
+    repo: &'a str,
+    pull_number: i64,
+    sort: Option<crate::types::Sort>,
+    direction: Option<crate::types::Order>,
+    since: Option<chrono::DateTime<chrono::Utc>>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> PullsListReviewCommentsRequest<'a> {
+    /// Sets the `sort` query parameter.
+    pub fn sort(mut self, sort: crate::types::Sort) -> Self {
+        self.sort = Some(sort);
+        self
+    }
+
+    /// Sets the `direction` query parameter.
+    pub fn direction(mut self, direction: crate::types::Order) -> Self {
+        self.direction = Some(direction);
+        self
+    }
+
+    /// Sets the `since` query parameter.
+    pub fn since(mut self, since: chrono::DateTime<chrono::Utc>) -> Self {
+        self.since = Some(since);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::PullRequestReviewComment>>> {
+        let Self {
+            client,
+            owner,
+            repo,
+            pull_number,
+            sort,
+            direction,
+            since,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = direction {
+            query_args.push(("direction".to_string(), v.to_string()));
+        }
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        if let Some(date) = since {
+            query_args.push(("since".to_string(), date.to_rfc3339()));
+        }
+        if let Some(v) = sort {
+            query_args.push(("sort".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/repos/{}/{}/pulls/{}/comments?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&pull_number.to_string()),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Pulls::list_commits`], set with a builder.
+pub struct PullsListCommitsRequest<'a> {
+    client: &'a Pulls,
+    owner: &'a str,
+    repo: &'a str,
+    pull_number: i64,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> PullsListCommitsRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::CommitDataType>>> {
+        let Self {
+            client,
+            owner,
+            repo,
+            pull_number,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/repos/{}/{}/pulls/{}/commits?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&pull_number.to_string()),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Pulls::list_files`], set with a builder.
+pub struct PullsListFilesRequest<'a> {
+    client: &'a Pulls,
+    owner: &'a str,
+    repo: &'a str,
+    pull_number: i64,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> PullsListFilesRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::DiffEntry>>> {
+        let Self {
+            client,
+            owner,
+            repo,
+            pull_number,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/repos/{}/{}/pulls/{}/files?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&pull_number.to_string()),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Pulls::list_requested_reviewers`], set with a builder.
+pub struct PullsListRequestedReviewersRequest<'a> {
+    client: &'a Pulls,
+    owner: &'a str,
+    repo: &'a str,
+    pull_number: i64,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> PullsListRequestedReviewersRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::PullRequestReview>> {
+        let Self {
+            client,
+            owner,
+            repo,
+            pull_number,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/repos/{}/{}/pulls/{}/requested_reviewers?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&pull_number.to_string()),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Pulls::list_reviews`], set with a builder.
+pub struct PullsListReviewsRequest<'a> {
+    client: &'a Pulls,
+    owner: &'a str,
+    repo: &'a str,
+    pull_number: i64,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> PullsListReviewsRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::PullRequestReviewData>>> {
+        let Self {
+            client,
+            owner,
+            repo,
+            pull_number,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/repos/{}/{}/pulls/{}/reviews?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&pull_number.to_string()),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Pulls::list_comments_for_review`], set with a builder.
+pub struct PullsListCommentsForReviewRequest<'a> {
+    client: &'a Pulls,
+    owner: &'a str,
+    repo: &'a str,
+    pull_number: i64,
+    review_id: i64,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> PullsListCommentsForReviewRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::ReviewComment>>> {
+        let Self {
+            client,
+            owner,
+            repo,
+            pull_number,
+            review_id,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/repos/{}/{}/pulls/{}/reviews/{}/comments?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&pull_number.to_string()),
+                crate::progenitor_support::encode_path(&review_id.to_string()),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct Pulls {
     pub client: Client,
 }
@@ -88,6 +732,31 @@ impl Pulls {
             )
             .await
     }
+    /**
+     * List pull requests.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/pulls` endpoint.
+     *
+     * As opposed to `list`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Draft pull requests are available in public repositories with GitHub Free and GitHub Free for organizations, GitHub Pro, and legacy per-repository billing plans, and in public and private repositories with GitHub Team and GitHub Enterprise Cloud. For more information, see [GitHub's products](https://help.github.com/github/getting-started-with-github/githubs-products) in the GitHub Help documentation.
+     *
+     * FROM: <https://docs.github.com/rest/reference/pulls#list-pull-requests>
+     */
+    pub fn list_request<'a>(&'a self, owner: &'a str, repo: &'a str) -> PullsListRequest<'a> {
+        PullsListRequest {
+            client: self,
+            owner,
+            repo,
+            state: None,
+            head: None,
+            base: None,
+            sort: None,
+            direction: None,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List pull requests.
      *
@@ -145,6 +814,56 @@ impl Pulls {
             )
             .await
     }
+    /**
+     * List pull requests.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/pulls` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Draft pull requests are available in public repositories with GitHub Free and GitHub Free for organizations, GitHub Pro, and legacy per-repository billing plans, and in public and private repositories with GitHub Team and GitHub Enterprise Cloud. For more information, see [GitHub's products](https://help.github.com/github/getting-started-with-github/githubs-products) in the GitHub Help documentation.
+     *
+     * FROM: <https://docs.github.com/rest/reference/pulls#list-pull-requests>
+     */
+    pub fn list_all_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        state: crate::types::IssuesListState,
+        head: &str,
+        base: &str,
+        sort: crate::types::PullsListSort,
+        direction: crate::types::Order,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::PullRequestSimple>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !base.is_empty() {
+            query_args.push(("base".to_string(), base.to_string()));
+        }
+        if !direction.to_string().is_empty() {
+            query_args.push(("direction".to_string(), direction.to_string()));
+        }
+        if !head.is_empty() {
+            query_args.push(("head".to_string(), head.to_string()));
+        }
+        if !sort.to_string().is_empty() {
+            query_args.push(("sort".to_string(), sort.to_string()));
+        }
+        if !state.to_string().is_empty() {
+            query_args.push(("state".to_string(), state.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/pulls?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                query_
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Create a pull request.
      *
@@ -197,39 +916,115 @@ impl Pulls {
      * Lists review comments for all pull requests in a repository. By default, review comments are in ascending order by ID.
      *
      * FROM: <https://docs.github.com/rest/reference/pulls#list-review-comments-in-a-repository>
-     *
-     * **Parameters:**
-     *
-     * * `owner: &str`
-     * * `repo: &str`
-     * * `sort: crate::types::PullsListReviewCommentsRepoSort`
-     * * `direction: crate::types::Order` -- The order of audit log events. To list newest events first, specify `desc`. To list oldest events first, specify `asc`.
-     *  
-     *  The default is `desc`.
-     * * `since: chrono::DateTime<chrono::Utc>` -- Only show notifications updated after the given time. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     *
+     * **Parameters:**
+     *
+     * * `owner: &str`
+     * * `repo: &str`
+     * * `sort: crate::types::PullsListReviewCommentsRepoSort`
+     * * `direction: crate::types::Order` -- The order of audit log events. To list newest events first, specify `desc`. To list oldest events first, specify `asc`.
+     *  
+     *  The default is `desc`.
+     * * `since: chrono::DateTime<chrono::Utc>` -- Only show notifications updated after the given time. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
+     * * `per_page: i64` -- Results per page (max 100).
+     * * `page: i64` -- Page number of the results to fetch.
+     */
+    pub async fn list_review_comments_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+        sort: crate::types::PullsListReviewCommentsRepoSort,
+        direction: crate::types::Order,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::PullRequestReviewComment>>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !direction.to_string().is_empty() {
+            query_args.push(("direction".to_string(), direction.to_string()));
+        }
+        if page > 0 {
+            query_args.push(("page".to_string(), page.to_string()));
+        }
+        if per_page > 0 {
+            query_args.push(("per_page".to_string(), per_page.to_string()));
+        }
+        if let Some(date) = since {
+            query_args.push(("since".to_string(), date.to_rfc3339()));
+        }
+        if !sort.to_string().is_empty() {
+            query_args.push(("sort".to_string(), sort.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/pulls/comments?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                query_
+            ),
+            None,
+        );
+        self.client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+    /**
+     * List review comments in a repository.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/pulls/comments` endpoint.
+     *
+     * As opposed to `list_review_comments_for_repo`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Lists review comments for all pull requests in a repository. By default, review comments are in ascending order by ID.
+     *
+     * FROM: <https://docs.github.com/rest/reference/pulls#list-review-comments-in-a-repository>
+     */
+    pub fn list_review_comments_for_repo_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+    ) -> PullsListReviewCommentsForRepoRequest<'a> {
+        PullsListReviewCommentsForRepoRequest {
+            client: self,
+            owner,
+            repo,
+            sort: None,
+            direction: None,
+            since: None,
+            per_page: None,
+            page: None,
+        }
+    }
+    /**
+     * List review comments in a repository.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/pulls/comments` endpoint.
+     *
+     * As opposed to `list_review_comments_for_repo`, this function returns all the pages of the request at once.
+     *
+     * Lists review comments for all pull requests in a repository. By default, review comments are in ascending order by ID.
+     *
+     * FROM: <https://docs.github.com/rest/reference/pulls#list-review-comments-in-a-repository>
      */
-    pub async fn list_review_comments_for_repo(
+    pub async fn list_all_review_comments_for_repo(
         &self,
         owner: &str,
         repo: &str,
         sort: crate::types::PullsListReviewCommentsRepoSort,
         direction: crate::types::Order,
         since: Option<chrono::DateTime<chrono::Utc>>,
-        per_page: i64,
-        page: i64,
     ) -> ClientResult<crate::Response<Vec<crate::types::PullRequestReviewComment>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !direction.to_string().is_empty() {
             query_args.push(("direction".to_string(), direction.to_string()));
         }
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
-        }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
-        }
         if let Some(date) = since {
             query_args.push(("since".to_string(), date.to_rfc3339()));
         }
@@ -247,7 +1042,7 @@ impl Pulls {
             None,
         );
         self.client
-            .get(
+            .get_all_pages(
                 &url,
                 crate::Message {
                     body: None,
@@ -261,20 +1056,21 @@ impl Pulls {
      *
      * This function performs a `GET` to the `/repos/{owner}/{repo}/pulls/comments` endpoint.
      *
-     * As opposed to `list_review_comments_for_repo`, this function returns all the pages of the request at once.
+     * As opposed to `list_all_review_comments_for_repo`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
      *
      * Lists review comments for all pull requests in a repository. By default, review comments are in ascending order by ID.
      *
      * FROM: <https://docs.github.com/rest/reference/pulls#list-review-comments-in-a-repository>
      */
-    pub async fn list_all_review_comments_for_repo(
+    pub fn list_all_review_comments_for_repo_stream(
         &self,
         owner: &str,
         repo: &str,
         sort: crate::types::PullsListReviewCommentsRepoSort,
         direction: crate::types::Order,
         since: Option<chrono::DateTime<chrono::Utc>>,
-    ) -> ClientResult<crate::Response<Vec<crate::types::PullRequestReviewComment>>> {
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::PullRequestReviewComment>> + Unpin + '_
+    {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !direction.to_string().is_empty() {
             query_args.push(("direction".to_string(), direction.to_string()));
@@ -295,15 +1091,7 @@ impl Pulls {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(url)
     }
     /**
      * Get a review comment for a pull request.
@@ -593,6 +1381,35 @@ impl Pulls {
             )
             .await
     }
+    /**
+     * List review comments on a pull request.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/pulls/{pull_number}/comments` endpoint.
+     *
+     * As opposed to `list_review_comments`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Lists all review comments for a pull request. By default, review comments are in ascending order by ID.
+     *
+     * FROM: <https://docs.github.com/rest/reference/pulls#list-review-comments-on-a-pull-request>
+     */
+    pub fn list_review_comments_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+        pull_number: i64,
+    ) -> PullsListReviewCommentsRequest<'a> {
+        PullsListReviewCommentsRequest {
+            client: self,
+            owner,
+            repo,
+            pull_number,
+            sort: None,
+            direction: None,
+            since: None,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List review comments on a pull request.
      *
@@ -644,6 +1461,50 @@ impl Pulls {
             )
             .await
     }
+    /**
+     * List review comments on a pull request.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/pulls/{pull_number}/comments` endpoint.
+     *
+     * As opposed to `list_all_review_comments`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Lists all review comments for a pull request. By default, review comments are in ascending order by ID.
+     *
+     * FROM: <https://docs.github.com/rest/reference/pulls#list-review-comments-on-a-pull-request>
+     */
+    pub fn list_all_review_comments_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        sort: crate::types::Sort,
+        direction: crate::types::Order,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::PullRequestReviewComment>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !direction.to_string().is_empty() {
+            query_args.push(("direction".to_string(), direction.to_string()));
+        }
+        if let Some(date) = since {
+            query_args.push(("since".to_string(), date.to_rfc3339()));
+        }
+        if !sort.to_string().is_empty() {
+            query_args.push(("sort".to_string(), sort.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/pulls/{}/comments?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&pull_number.to_string()),
+                query_
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Create a review comment for a pull request.
      *
@@ -791,6 +1652,32 @@ impl Pulls {
             )
             .await
     }
+    /**
+     * List commits on a pull request.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/pulls/{pull_number}/commits` endpoint.
+     *
+     * As opposed to `list_commits`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Lists a maximum of 250 commits for a pull request. To receive a complete commit list for pull requests with more than 250 commits, use the [List commits](https://docs.github.com/rest/reference/repos#list-commits) endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/pulls#list-commits-on-a-pull-request>
+     */
+    pub fn list_commits_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+        pull_number: i64,
+    ) -> PullsListCommitsRequest<'a> {
+        PullsListCommitsRequest {
+            client: self,
+            owner,
+            repo,
+            pull_number,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List commits on a pull request.
      *
@@ -827,6 +1714,34 @@ impl Pulls {
             )
             .await
     }
+    /**
+     * List commits on a pull request.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/pulls/{pull_number}/commits` endpoint.
+     *
+     * As opposed to `list_all_commits`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Lists a maximum of 250 commits for a pull request. To receive a complete commit list for pull requests with more than 250 commits, use the [List commits](https://docs.github.com/rest/reference/repos#list-commits) endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/pulls#list-commits-on-a-pull-request>
+     */
+    pub fn list_all_commits_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::CommitDataType>> + Unpin + '_ {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/pulls/{}/commits",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&pull_number.to_string()),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * List pull requests files.
      *
@@ -880,6 +1795,32 @@ impl Pulls {
             )
             .await
     }
+    /**
+     * List pull requests files.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/pulls/{pull_number}/files` endpoint.
+     *
+     * As opposed to `list_files`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * **Note:** Responses include a maximum of 3000 files. The paginated response returns 30 files per page by default.
+     *
+     * FROM: <https://docs.github.com/rest/reference/pulls#list-pull-requests-files>
+     */
+    pub fn list_files_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+        pull_number: i64,
+    ) -> PullsListFilesRequest<'a> {
+        PullsListFilesRequest {
+            client: self,
+            owner,
+            repo,
+            pull_number,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List pull requests files.
      *
@@ -916,6 +1857,34 @@ impl Pulls {
             )
             .await
     }
+    /**
+     * List pull requests files.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/pulls/{pull_number}/files` endpoint.
+     *
+     * As opposed to `list_all_files`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * **Note:** Responses include a maximum of 3000 files. The paginated response returns 30 files per page by default.
+     *
+     * FROM: <https://docs.github.com/rest/reference/pulls#list-pull-requests-files>
+     */
+    pub fn list_all_files_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::DiffEntry>> + Unpin + '_ {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/pulls/{}/files",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&pull_number.to_string()),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Check if a pull request has been merged.
      *
@@ -1050,6 +2019,32 @@ impl Pulls {
             )
             .await
     }
+    /**
+     * List requested reviewers for a pull request.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/pulls/{pull_number}/requested_reviewers` endpoint.
+     *
+     * As opposed to `list_requested_reviewers`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/pulls#list-requested-reviewers-for-a-pull-request>
+     */
+    pub fn list_requested_reviewers_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+        pull_number: i64,
+    ) -> PullsListRequestedReviewersRequest<'a> {
+        PullsListRequestedReviewersRequest {
+            client: self,
+            owner,
+            repo,
+            pull_number,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * Request reviewers for a pull request.
      *
@@ -1185,6 +2180,32 @@ impl Pulls {
             )
             .await
     }
+    /**
+     * List reviews for a pull request.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/pulls/{pull_number}/reviews` endpoint.
+     *
+     * As opposed to `list_reviews`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * The list of reviews returns in chronological order.
+     *
+     * FROM: <https://docs.github.com/rest/reference/pulls#list-reviews-for-a-pull-request>
+     */
+    pub fn list_reviews_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+        pull_number: i64,
+    ) -> PullsListReviewsRequest<'a> {
+        PullsListReviewsRequest {
+            client: self,
+            owner,
+            repo,
+            pull_number,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List reviews for a pull request.
      *
@@ -1221,6 +2242,35 @@ impl Pulls {
             )
             .await
     }
+    /**
+     * List reviews for a pull request.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/pulls/{pull_number}/reviews` endpoint.
+     *
+     * As opposed to `list_all_reviews`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * The list of reviews returns in chronological order.
+     *
+     * FROM: <https://docs.github.com/rest/reference/pulls#list-reviews-for-a-pull-request>
+     */
+    pub fn list_all_reviews_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::PullRequestReviewData>> + Unpin + '_
+    {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/pulls/{}/reviews",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&pull_number.to_string()),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Create a review for a pull request.
      *
@@ -1454,6 +2504,34 @@ impl Pulls {
             )
             .await
     }
+    /**
+     * List comments for a pull request review.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/pulls/{pull_number}/reviews/{review_id}/comments` endpoint.
+     *
+     * As opposed to `list_comments_for_review`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * List comments for a specific pull request review.
+     *
+     * FROM: <https://docs.github.com/rest/reference/pulls#list-comments-for-a-pull-request-review>
+     */
+    pub fn list_comments_for_review_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+        pull_number: i64,
+        review_id: i64,
+    ) -> PullsListCommentsForReviewRequest<'a> {
+        PullsListCommentsForReviewRequest {
+            client: self,
+            owner,
+            repo,
+            pull_number,
+            review_id,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List comments for a pull request review.
      *
@@ -1492,6 +2570,36 @@ impl Pulls {
             )
             .await
     }
+    /**
+     * List comments for a pull request review.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/pulls/{pull_number}/reviews/{review_id}/comments` endpoint.
+     *
+     * As opposed to `list_all_comments_for_review`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * List comments for a specific pull request review.
+     *
+     * FROM: <https://docs.github.com/rest/reference/pulls#list-comments-for-a-pull-request-review>
+     */
+    pub fn list_all_comments_for_review_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        review_id: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::ReviewComment>> + Unpin + '_ {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/pulls/{}/reviews/{}/comments",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&pull_number.to_string()),
+                crate::progenitor_support::encode_path(&review_id.to_string()),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Dismiss a review for a pull request.
      *
@@ -1,6 +1,614 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Reactions::list_for_team_discussion_comment_in_org`], set with a builder.
+pub struct ReactionsListForTeamDiscussionCommentInOrgRequest<'a> {
+    client: &'a Reactions,
+    org: &'a str,
+    team_slug: &'a str,
+    discussion_number: i64,
+    comment_number: i64,
+    content: Option<crate::types::Content>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> ReactionsListForTeamDiscussionCommentInOrgRequest<'a> {
+    /// Sets the `content` query parameter.
+    pub fn content(mut self, content: crate::types::Content) -> Self {
+        self.content = Some(content);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Reaction>>> {
+        let Self {
+            client,
+            org,
+            team_slug,
+            discussion_number,
+            comment_number,
+            content,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = content {
+            query_args.push(("content".to_string(), v.to_string()));
+        }
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/orgs/{}/teams/{}/discussions/{}/comments/{}/reactions?{}",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(team_slug),
+                crate::progenitor_support::encode_path(&discussion_number.to_string()),
+                crate::progenitor_support::encode_path(&comment_number.to_string()),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Reactions::list_for_team_discussion_in_org`], set with a builder.
+pub struct ReactionsListForTeamDiscussionInOrgRequest<'a> {
+    client: &'a Reactions,
+    org: &'a str,
+    team_slug: &'a str,
+    discussion_number: i64,
+    content: Option<crate::types::Content>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> ReactionsListForTeamDiscussionInOrgRequest<'a> {
+    /// Sets the `content` query parameter.
+    pub fn content(mut self, content: crate::types::Content) -> Self {
+        self.content = Some(content);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Reaction>>> {
+        let Self {
+            client,
+            org,
+            team_slug,
+            discussion_number,
+            content,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = content {
+            query_args.push(("content".to_string(), v.to_string()));
+        }
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/orgs/{}/teams/{}/discussions/{}/reactions?{}",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(team_slug),
+                crate::progenitor_support::encode_path(&discussion_number.to_string()),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Reactions::list_for_commit_comment`], set with a builder.
+pub struct ReactionsListForCommitCommentRequest<'a> {
+    client: &'a Reactions,
+    owner: &'a str,
+    repo: &'a str,
+    comment_id: i64,
+    content: Option<crate::types::Content>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> ReactionsListForCommitCommentRequest<'a> {
+    /// Sets the `content` query parameter.
+    pub fn content(mut self, content: crate::types::Content) -> Self {
+        self.content = Some(content);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Reaction>>> {
+        let Self {
+            client,
+            owner,
+            repo,
+            comment_id,
+            content,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = content {
+            query_args.push(("content".to_string(), v.to_string()));
+        }
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/repos/{}/{}/comments/{}/reactions?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&comment_id.to_string()),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Reactions::list_for_issue_comment`], set with a builder.
+pub struct ReactionsListForIssueCommentRequest<'a> {
+    client: &'a Reactions,
+    owner: &'a str,
+    repo: &'a str,
+    comment_id: i64,
+    content: Option<crate::types::Content>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> ReactionsListForIssueCommentRequest<'a> {
+    /// Sets the `content` query parameter.
+    pub fn content(mut self, content: crate::types::Content) -> Self {
+        self.content = Some(content);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Reaction>>> {
+        let Self {
+            client,
+            owner,
+            repo,
+            comment_id,
+            content,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = content {
+            query_args.push(("content".to_string(), v.to_string()));
+        }
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/repos/{}/{}/issues/comments/{}/reactions?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&comment_id.to_string()),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Reactions::list_for_issue`], set with a builder.
+pub struct ReactionsListForIssueRequest<'a> {
+    client: &'a Reactions,
+    owner: &'a str,
+    repo: &'a str,
+    issue_number: i64,
+    content: Option<crate::types::Content>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> ReactionsListForIssueRequest<'a> {
+    /// Sets the `content` query parameter.
+    pub fn content(mut self, content: crate::types::Content) -> Self {
+        self.content = Some(content);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Reaction>>> {
+        let Self {
+            client,
+            owner,
+            repo,
+            issue_number,
+            content,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = content {
+            query_args.push(("content".to_string(), v.to_string()));
+        }
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/repos/{}/{}/issues/{}/reactions?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&issue_number.to_string()),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Reactions::list_for_pull_request_review_comment`], set with a builder.
+pub struct ReactionsListForPullRequestReviewCommentRequest<'a> {
+    client: &'a Reactions,
+    owner: &'a str,
+    repo: &'a str,
+    comment_id: i64,
+    content: Option<crate::types::Content>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> ReactionsListForPullRequestReviewCommentRequest<'a> {
+    /// Sets the `content` query parameter.
+    pub fn content(mut self, content: crate::types::Content) -> Self {
+        self.content = Some(content);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Reaction>>> {
+        let Self {
+            client,
+            owner,
+            repo,
+            comment_id,
+            content,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = content {
+            query_args.push(("content".to_string(), v.to_string()));
+        }
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/repos/{}/{}/pulls/comments/{}/reactions?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&comment_id.to_string()),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Reactions::list_for_team_discussion_comment_legacy`], set with a builder.
+pub struct ReactionsListForTeamDiscussionCommentLegacyRequest<'a> {
+    client: &'a Reactions,
+    team_id: i64,
+    discussion_number: i64,
+    comment_number: i64,
+    content: Option<crate::types::Content>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> ReactionsListForTeamDiscussionCommentLegacyRequest<'a> {
+    /// Sets the `content` query parameter.
+    pub fn content(mut self, content: crate::types::Content) -> Self {
+        self.content = Some(content);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Reaction>>> {
+        let Self {
+            client,
+            team_id,
+            discussion_number,
+            comment_number,
+            content,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = content {
+            query_args.push(("content".to_string(), v.to_string()));
+        }
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/teams/{}/discussions/{}/comments/{}/reactions?{}",
+                crate::progenitor_support::encode_path(&team_id.to_string()),
+                crate::progenitor_support::encode_path(&discussion_number.to_string()),
+                crate::progenitor_support::encode_path(&comment_number.to_string()),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Reactions::list_for_team_discussion_legacy`], set with a builder.
+pub struct ReactionsListForTeamDiscussionLegacyRequest<'a> {
+    client: &'a Reactions,
+    team_id: i64,
+    discussion_number: i64,
+    content: Option<crate::types::Content>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> ReactionsListForTeamDiscussionLegacyRequest<'a> {
+    /// Sets the `content` query parameter.
+    pub fn content(mut self, content: crate::types::Content) -> Self {
+        self.content = Some(content);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Reaction>>> {
+        let Self {
+            client,
+            team_id,
+            discussion_number,
+            content,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = content {
+            query_args.push(("content".to_string(), v.to_string()));
+        }
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/teams/{}/discussions/{}/reactions?{}",
+                crate::progenitor_support::encode_path(&team_id.to_string()),
+                crate::progenitor_support::encode_path(&discussion_number.to_string()),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct Reactions {
     pub client: Client,
 }
@@ -74,6 +682,37 @@ impl Reactions {
             )
             .await
     }
+    /**
+     * List reactions for a team discussion comment.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}/comments/{comment_number}/reactions` endpoint.
+     *
+     * As opposed to `list_for_team_discussion_comment_in_org`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * List the reactions to a [team discussion comment](https://docs.github.com/rest/reference/teams#discussion-comments/). OAuth access tokens require the `read:discussion` [scope](https://docs.github.com/apps/building-oauth-apps/understanding-scopes-for-oauth-apps/).
+     *
+     * **Note:** You can also specify a team by `org_id` and `team_id` using the route `GET /organizations/:org_id/team/:team_id/discussions/:discussion_number/comments/:comment_number/reactions`.
+     *
+     * FROM: <https://docs.github.com/rest/reference/reactions#list-reactions-for-a-team-discussion-comment>
+     */
+    pub fn list_for_team_discussion_comment_in_org_request<'a>(
+        &'a self,
+        org: &'a str,
+        team_slug: &'a str,
+        discussion_number: i64,
+        comment_number: i64,
+    ) -> ReactionsListForTeamDiscussionCommentInOrgRequest<'a> {
+        ReactionsListForTeamDiscussionCommentInOrgRequest {
+            client: self,
+            org,
+            team_slug,
+            discussion_number,
+            comment_number,
+            content: None,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List reactions for a team discussion comment.
      *
@@ -121,6 +760,45 @@ impl Reactions {
             )
             .await
     }
+    /**
+     * List reactions for a team discussion comment.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}/comments/{comment_number}/reactions` endpoint.
+     *
+     * As opposed to `list_all_for_team_discussion_comment_in_org`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * List the reactions to a [team discussion comment](https://docs.github.com/rest/reference/teams#discussion-comments/). OAuth access tokens require the `read:discussion` [scope](https://docs.github.com/apps/building-oauth-apps/understanding-scopes-for-oauth-apps/).
+     *
+     * **Note:** You can also specify a team by `org_id` and `team_id` using the route `GET /organizations/:org_id/team/:team_id/discussions/:discussion_number/comments/:comment_number/reactions`.
+     *
+     * FROM: <https://docs.github.com/rest/reference/reactions#list-reactions-for-a-team-discussion-comment>
+     */
+    pub fn list_all_for_team_discussion_comment_in_org_stream(
+        &self,
+        org: &str,
+        team_slug: &str,
+        discussion_number: i64,
+        comment_number: i64,
+        content: crate::types::Content,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Reaction>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !content.to_string().is_empty() {
+            query_args.push(("content".to_string(), content.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/orgs/{}/teams/{}/discussions/{}/comments/{}/reactions?{}",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(team_slug),
+                crate::progenitor_support::encode_path(&discussion_number.to_string()),
+                crate::progenitor_support::encode_path(&comment_number.to_string()),
+                query_
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Create reaction for a team discussion comment.
      *
@@ -275,6 +953,35 @@ impl Reactions {
             )
             .await
     }
+    /**
+     * List reactions for a team discussion.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}/reactions` endpoint.
+     *
+     * As opposed to `list_for_team_discussion_in_org`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * List the reactions to a [team discussion](https://docs.github.com/rest/reference/teams#discussions). OAuth access tokens require the `read:discussion` [scope](https://docs.github.com/apps/building-oauth-apps/understanding-scopes-for-oauth-apps/).
+     *
+     * **Note:** You can also specify a team by `org_id` and `team_id` using the route `GET /organizations/:org_id/team/:team_id/discussions/:discussion_number/reactions`.
+     *
+     * FROM: <https://docs.github.com/rest/reference/reactions#list-reactions-for-a-team-discussion>
+     */
+    pub fn list_for_team_discussion_in_org_request<'a>(
+        &'a self,
+        org: &'a str,
+        team_slug: &'a str,
+        discussion_number: i64,
+    ) -> ReactionsListForTeamDiscussionInOrgRequest<'a> {
+        ReactionsListForTeamDiscussionInOrgRequest {
+            client: self,
+            org,
+            team_slug,
+            discussion_number,
+            content: None,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List reactions for a team discussion.
      *
@@ -320,6 +1027,43 @@ impl Reactions {
             )
             .await
     }
+    /**
+     * List reactions for a team discussion.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}/reactions` endpoint.
+     *
+     * As opposed to `list_all_for_team_discussion_in_org`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * List the reactions to a [team discussion](https://docs.github.com/rest/reference/teams#discussions). OAuth access tokens require the `read:discussion` [scope](https://docs.github.com/apps/building-oauth-apps/understanding-scopes-for-oauth-apps/).
+     *
+     * **Note:** You can also specify a team by `org_id` and `team_id` using the route `GET /organizations/:org_id/team/:team_id/discussions/:discussion_number/reactions`.
+     *
+     * FROM: <https://docs.github.com/rest/reference/reactions#list-reactions-for-a-team-discussion>
+     */
+    pub fn list_all_for_team_discussion_in_org_stream(
+        &self,
+        org: &str,
+        team_slug: &str,
+        discussion_number: i64,
+        content: crate::types::Content,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Reaction>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !content.to_string().is_empty() {
+            query_args.push(("content".to_string(), content.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/orgs/{}/teams/{}/discussions/{}/reactions?{}",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(team_slug),
+                crate::progenitor_support::encode_path(&discussion_number.to_string()),
+                query_
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Create reaction for a team discussion.
      *
@@ -459,25 +1203,95 @@ impl Reactions {
      * * `per_page: i64` -- Results per page (max 100).
      * * `page: i64` -- Page number of the results to fetch.
      */
-    pub async fn list_for_commit_comment(
+    pub async fn list_for_commit_comment(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+        content: crate::types::Content,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Reaction>>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !content.to_string().is_empty() {
+            query_args.push(("content".to_string(), content.to_string()));
+        }
+        if page > 0 {
+            query_args.push(("page".to_string(), page.to_string()));
+        }
+        if per_page > 0 {
+            query_args.push(("per_page".to_string(), per_page.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/comments/{}/reactions?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&comment_id.to_string()),
+                query_
+            ),
+            None,
+        );
+        self.client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+    /**
+     * List reactions for a commit comment.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/comments/{comment_id}/reactions` endpoint.
+     *
+     * As opposed to `list_for_commit_comment`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * List the reactions to a [commit comment](https://docs.github.com/rest/reference/repos#comments).
+     *
+     * FROM: <https://docs.github.com/rest/reference/reactions#list-reactions-for-a-commit-comment>
+     */
+    pub fn list_for_commit_comment_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+        comment_id: i64,
+    ) -> ReactionsListForCommitCommentRequest<'a> {
+        ReactionsListForCommitCommentRequest {
+            client: self,
+            owner,
+            repo,
+            comment_id,
+            content: None,
+            per_page: None,
+            page: None,
+        }
+    }
+    /**
+     * List reactions for a commit comment.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/comments/{comment_id}/reactions` endpoint.
+     *
+     * As opposed to `list_for_commit_comment`, this function returns all the pages of the request at once.
+     *
+     * List the reactions to a [commit comment](https://docs.github.com/rest/reference/repos#comments).
+     *
+     * FROM: <https://docs.github.com/rest/reference/reactions#list-reactions-for-a-commit-comment>
+     */
+    pub async fn list_all_for_commit_comment(
         &self,
         owner: &str,
         repo: &str,
         comment_id: i64,
         content: crate::types::Content,
-        per_page: i64,
-        page: i64,
     ) -> ClientResult<crate::Response<Vec<crate::types::Reaction>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !content.to_string().is_empty() {
             query_args.push(("content".to_string(), content.to_string()));
         }
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
-        }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
-        }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
@@ -490,7 +1304,7 @@ impl Reactions {
             None,
         );
         self.client
-            .get(
+            .get_all_pages(
                 &url,
                 crate::Message {
                     body: None,
@@ -504,19 +1318,19 @@ impl Reactions {
      *
      * This function performs a `GET` to the `/repos/{owner}/{repo}/comments/{comment_id}/reactions` endpoint.
      *
-     * As opposed to `list_for_commit_comment`, this function returns all the pages of the request at once.
+     * As opposed to `list_all_for_commit_comment`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
      *
      * List the reactions to a [commit comment](https://docs.github.com/rest/reference/repos#comments).
      *
      * FROM: <https://docs.github.com/rest/reference/reactions#list-reactions-for-a-commit-comment>
      */
-    pub async fn list_all_for_commit_comment(
+    pub fn list_all_for_commit_comment_stream(
         &self,
         owner: &str,
         repo: &str,
         comment_id: i64,
         content: crate::types::Content,
-    ) -> ClientResult<crate::Response<Vec<crate::types::Reaction>>> {
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Reaction>> + Unpin + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !content.to_string().is_empty() {
             query_args.push(("content".to_string(), content.to_string()));
@@ -532,15 +1346,7 @@ impl Reactions {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(url)
     }
     /**
      * Create reaction for a commit comment.
@@ -686,6 +1492,33 @@ impl Reactions {
             )
             .await
     }
+    /**
+     * List reactions for an issue comment.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/issues/comments/{comment_id}/reactions` endpoint.
+     *
+     * As opposed to `list_for_issue_comment`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * List the reactions to an [issue comment](https://docs.github.com/rest/reference/issues#comments).
+     *
+     * FROM: <https://docs.github.com/rest/reference/reactions#list-reactions-for-an-issue-comment>
+     */
+    pub fn list_for_issue_comment_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+        comment_id: i64,
+    ) -> ReactionsListForIssueCommentRequest<'a> {
+        ReactionsListForIssueCommentRequest {
+            client: self,
+            owner,
+            repo,
+            comment_id,
+            content: None,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List reactions for an issue comment.
      *
@@ -729,6 +1562,41 @@ impl Reactions {
             )
             .await
     }
+    /**
+     * List reactions for an issue comment.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/issues/comments/{comment_id}/reactions` endpoint.
+     *
+     * As opposed to `list_all_for_issue_comment`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * List the reactions to an [issue comment](https://docs.github.com/rest/reference/issues#comments).
+     *
+     * FROM: <https://docs.github.com/rest/reference/reactions#list-reactions-for-an-issue-comment>
+     */
+    pub fn list_all_for_issue_comment_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+        content: crate::types::Content,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Reaction>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !content.to_string().is_empty() {
+            query_args.push(("content".to_string(), content.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/issues/comments/{}/reactions?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&comment_id.to_string()),
+                query_
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Create reaction for an issue comment.
      *
@@ -873,6 +1741,33 @@ impl Reactions {
             )
             .await
     }
+    /**
+     * List reactions for an issue.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/issues/{issue_number}/reactions` endpoint.
+     *
+     * As opposed to `list_for_issue`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * List the reactions to an [issue](https://docs.github.com/rest/reference/issues).
+     *
+     * FROM: <https://docs.github.com/rest/reference/reactions#list-reactions-for-an-issue>
+     */
+    pub fn list_for_issue_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+        issue_number: i64,
+    ) -> ReactionsListForIssueRequest<'a> {
+        ReactionsListForIssueRequest {
+            client: self,
+            owner,
+            repo,
+            issue_number,
+            content: None,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List reactions for an issue.
      *
@@ -916,6 +1811,41 @@ impl Reactions {
             )
             .await
     }
+    /**
+     * List reactions for an issue.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/issues/{issue_number}/reactions` endpoint.
+     *
+     * As opposed to `list_all_for_issue`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * List the reactions to an [issue](https://docs.github.com/rest/reference/issues).
+     *
+     * FROM: <https://docs.github.com/rest/reference/reactions#list-reactions-for-an-issue>
+     */
+    pub fn list_all_for_issue_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+        content: crate::types::Content,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Reaction>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !content.to_string().is_empty() {
+            query_args.push(("content".to_string(), content.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/issues/{}/reactions?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&issue_number.to_string()),
+                query_
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Create reaction for an issue.
      *
@@ -1060,6 +1990,33 @@ impl Reactions {
             )
             .await
     }
+    /**
+     * List reactions for a pull request review comment.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/pulls/comments/{comment_id}/reactions` endpoint.
+     *
+     * As opposed to `list_for_pull_request_review_comment`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * List the reactions to a [pull request review comment](https://docs.github.com/rest/reference/pulls#review-comments).
+     *
+     * FROM: <https://docs.github.com/rest/reference/reactions#list-reactions-for-a-pull-request-review-comment>
+     */
+    pub fn list_for_pull_request_review_comment_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+        comment_id: i64,
+    ) -> ReactionsListForPullRequestReviewCommentRequest<'a> {
+        ReactionsListForPullRequestReviewCommentRequest {
+            client: self,
+            owner,
+            repo,
+            comment_id,
+            content: None,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List reactions for a pull request review comment.
      *
@@ -1103,6 +2060,41 @@ impl Reactions {
             )
             .await
     }
+    /**
+     * List reactions for a pull request review comment.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/pulls/comments/{comment_id}/reactions` endpoint.
+     *
+     * As opposed to `list_all_for_pull_request_review_comment`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * List the reactions to a [pull request review comment](https://docs.github.com/rest/reference/pulls#review-comments).
+     *
+     * FROM: <https://docs.github.com/rest/reference/reactions#list-reactions-for-a-pull-request-review-comment>
+     */
+    pub fn list_all_for_pull_request_review_comment_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+        content: crate::types::Content,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Reaction>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !content.to_string().is_empty() {
+            query_args.push(("content".to_string(), content.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/pulls/comments/{}/reactions?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&comment_id.to_string()),
+                query_
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Create reaction for a pull request review comment.
      *
@@ -1290,6 +2282,35 @@ impl Reactions {
             )
             .await
     }
+    /**
+     * List reactions for a team discussion comment (Legacy).
+     *
+     * This function performs a `GET` to the `/teams/{team_id}/discussions/{discussion_number}/comments/{comment_number}/reactions` endpoint.
+     *
+     * As opposed to `list_for_team_discussion_comment_legacy`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * **Deprecation Notice:** This endpoint route is deprecated and will be removed from the Teams API. We recommend migrating your existing code to use the new [`List reactions for a team discussion comment`](https://docs.github.com/rest/reference/reactions#list-reactions-for-a-team-discussion-comment) endpoint.
+     *
+     * List the reactions to a [team discussion comment](https://docs.github.com/rest/reference/teams#discussion-comments). OAuth access tokens require the `read:discussion` [scope](https://docs.github.com/apps/building-oauth-apps/understanding-scopes-for-oauth-apps/).
+     *
+     * FROM: <https://docs.github.com/rest/reference/reactions/#list-reactions-for-a-team-discussion-comment-legacy>
+     */
+    pub fn list_for_team_discussion_comment_legacy_request(
+        &self,
+        team_id: i64,
+        discussion_number: i64,
+        comment_number: i64,
+    ) -> ReactionsListForTeamDiscussionCommentLegacyRequest<'_> {
+        ReactionsListForTeamDiscussionCommentLegacyRequest {
+            client: self,
+            team_id,
+            discussion_number,
+            comment_number,
+            content: None,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List reactions for a team discussion comment (Legacy).
      *
@@ -1335,6 +2356,43 @@ impl Reactions {
             )
             .await
     }
+    /**
+     * List reactions for a team discussion comment (Legacy).
+     *
+     * This function performs a `GET` to the `/teams/{team_id}/discussions/{discussion_number}/comments/{comment_number}/reactions` endpoint.
+     *
+     * As opposed to `list_all_for_team_discussion_comment_legacy`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * **Deprecation Notice:** This endpoint route is deprecated and will be removed from the Teams API. We recommend migrating your existing code to use the new [`List reactions for a team discussion comment`](https://docs.github.com/rest/reference/reactions#list-reactions-for-a-team-discussion-comment) endpoint.
+     *
+     * List the reactions to a [team discussion comment](https://docs.github.com/rest/reference/teams#discussion-comments). OAuth access tokens require the `read:discussion` [scope](https://docs.github.com/apps/building-oauth-apps/understanding-scopes-for-oauth-apps/).
+     *
+     * FROM: <https://docs.github.com/rest/reference/reactions/#list-reactions-for-a-team-discussion-comment-legacy>
+     */
+    pub fn list_all_for_team_discussion_comment_legacy_stream(
+        &self,
+        team_id: i64,
+        discussion_number: i64,
+        comment_number: i64,
+        content: crate::types::Content,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Reaction>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !content.to_string().is_empty() {
+            query_args.push(("content".to_string(), content.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/teams/{}/discussions/{}/comments/{}/reactions?{}",
+                crate::progenitor_support::encode_path(&team_id.to_string()),
+                crate::progenitor_support::encode_path(&discussion_number.to_string()),
+                crate::progenitor_support::encode_path(&comment_number.to_string()),
+                query_
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Create reaction for a team discussion comment (Legacy).
      *
@@ -1435,6 +2493,33 @@ impl Reactions {
             )
             .await
     }
+    /**
+     * List reactions for a team discussion (Legacy).
+     *
+     * This function performs a `GET` to the `/teams/{team_id}/discussions/{discussion_number}/reactions` endpoint.
+     *
+     * As opposed to `list_for_team_discussion_legacy`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * **Deprecation Notice:** This endpoint route is deprecated and will be removed from the Teams API. We recommend migrating your existing code to use the new [`List reactions for a team discussion`](https://docs.github.com/rest/reference/reactions#list-reactions-for-a-team-discussion) endpoint.
+     *
+     * List the reactions to a [team discussion](https://docs.github.com/rest/reference/teams#discussions). OAuth access tokens require the `read:discussion` [scope](https://docs.github.com/apps/building-oauth-apps/understanding-scopes-for-oauth-apps/).
+     *
+     * FROM: <https://docs.github.com/rest/reference/reactions/#list-reactions-for-a-team-discussion-legacy>
+     */
+    pub fn list_for_team_discussion_legacy_request(
+        &self,
+        team_id: i64,
+        discussion_number: i64,
+    ) -> ReactionsListForTeamDiscussionLegacyRequest<'_> {
+        ReactionsListForTeamDiscussionLegacyRequest {
+            client: self,
+            team_id,
+            discussion_number,
+            content: None,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List reactions for a team discussion (Legacy).
      *
@@ -1478,6 +2563,41 @@ impl Reactions {
             )
             .await
     }
+    /**
+     * List reactions for a team discussion (Legacy).
+     *
+     * This function performs a `GET` to the `/teams/{team_id}/discussions/{discussion_number}/reactions` endpoint.
+     *
+     * As opposed to `list_all_for_team_discussion_legacy`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * **Deprecation Notice:** This endpoint route is deprecated and will be removed from the Teams API. We recommend migrating your existing code to use the new [`List reactions for a team discussion`](https://docs.github.com/rest/reference/reactions#list-reactions-for-a-team-discussion) endpoint.
+     *
+     * List the reactions to a [team discussion](https://docs.github.com/rest/reference/teams#discussions). OAuth access tokens require the `read:discussion` [scope](https://docs.github.com/apps/building-oauth-apps/understanding-scopes-for-oauth-apps/).
+     *
+     * FROM: <https://docs.github.com/rest/reference/reactions/#list-reactions-for-a-team-discussion-legacy>
+     */
+    pub fn list_all_for_team_discussion_legacy_stream(
+        &self,
+        team_id: i64,
+        discussion_number: i64,
+        content: crate::types::Content,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Reaction>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !content.to_string().is_empty() {
+            query_args.push(("content".to_string(), content.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/teams/{}/discussions/{}/reactions?{}",
+                crate::progenitor_support::encode_path(&team_id.to_string()),
+                crate::progenitor_support::encode_path(&discussion_number.to_string()),
+                query_
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Create reaction for a team discussion (Legacy).
      *
@@ -1,12 +1,2388 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Repos::list_for_org`], set with a builder.
+pub struct ReposListForOrgRequest<'a> {
+    client: &'a Repos,
+    org: &'a str,
+    type_: Option<crate::types::ReposListOrgType>,
+    sort: Option<crate::types::ReposListOrgSort>,
+    direction: Option<crate::types::Order>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> ReposListForOrgRequest<'a> {
+    /// Sets the `type` query parameter.
+    pub fn type_(mut self, type_: crate::types::ReposListOrgType) -> Self {
+        self.type_ = Some(type_);
+        self
+    }
+
+    /// Sets the `sort` query parameter.
+    pub fn sort(mut self, sort: crate::types::ReposListOrgSort) -> Self {
+        self.sort = Some(sort);
+        self
+    }
+
+    /// Sets the `direction` query parameter.
+    pub fn direction(mut self, direction: crate::types::Order) -> Self {
+        self.direction = Some(direction);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::MinimalRepository>>> {
+        let Self {
+            client,
+            org,
+            type_,
+            sort,
+            direction,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = direction {
+            query_args.push(("direction".to_string(), v.to_string()));
+        }
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        if let Some(v) = sort {
+            query_args.push(("sort".to_string(), v.to_string()));
+        }
+        if let Some(v) = type_ {
+            query_args.push(("type".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/orgs/{}/repos?{}",
+                crate::progenitor_support::encode_path(org),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Repos::list_autolinks`], set with a builder.
+pub struct ReposListAutolinksRequest<'a> {
+    client: &'a Repos,
+    owner: &'a str,
+    repo: &'a str,
+    page: Option<i64>,
+}
+
+impl<'a> ReposListAutolinksRequest<'a> {
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Autolink>>> {
+        let Self {
+            client,
+            owner,
+            repo,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/repos/{}/{}/autolinks?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Repos::list_branches`], set with a builder.
+pub struct ReposListBranchesRequest<'a> {
+    client: &'a Repos,
+    owner: &'a str,
+    repo: &'a str,
+    protected: Option<bool>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> ReposListBranchesRequest<'a> {
+    /// Sets the `protected` query parameter.
+    pub fn protected(mut self, protected: bool) -> Self {
+        self.protected = Some(protected);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::ShortBranch>>> {
+        let Self {
+            client,
+            owner,
+            repo,
+            protected,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        if let Some(v) = protected {
+            query_args.push(("protected".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/repos/{}/{}/branches?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Repos::list_collaborators`], set with a builder.
+pub struct ReposListCollaboratorsRequest<'a> {
+    client: &'a Repos,
+    owner: &'a str,
+    repo: &'a str,
+    affiliation: Option<crate::types::Affiliation>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> ReposListCollaboratorsRequest<'a> {
+    /// Sets the `affiliation` query parameter.
+    pub fn affiliation(mut self, affiliation: crate::types::Affiliation) -> Self {
+        self.affiliation = Some(affiliation);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Collaborator>>> {
+        let Self {
+            client,
+            owner,
+            repo,
+            affiliation,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = affiliation {
+            query_args.push(("affiliation".to_string(), v.to_string()));
+        }
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/repos/{}/{}/collaborators?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Repos::list_commit_comments_for_repo`], set with a builder.
+pub struct ReposListCommitCommentsForRepoRequest<'a> {
+    client: &'a Repos,
+    owner: &'a str,
+    repo: &'a str,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> ReposListCommitCommentsForRepoRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::CommitComment>>> {
+        let Self {
+            client,
+            owner,
+            repo,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/repos/{}/{}/comments?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Repos::list_commits`], set with a builder.
+pub struct ReposListCommitsRequest<'a> {
+    client: &'a Repos,
+    owner: &'a str,
+    repo: &'a str,
+    sha: Option<&'a str>,
+    path: Option<&'a str>,
+    author: Option<&'a str>,
+    since: Option<chrono::DateTime<chrono::Utc>>,
+    until: Option<chrono::DateTime<chrono::Utc>>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> ReposListCommitsRequest<'a> {
+    /// Sets the `sha` query parameter.
+    pub fn sha(mut self, sha: &'a str) -> Self {
+        self.sha = Some(sha);
+        self
+    }
+
+    /// Sets the `path` query parameter.
+    pub fn path(mut self, path: &'a str) -> Self {
+        self.path = Some(path);
+        self
+    }
+
+    /// Sets the `author` query parameter.
+    pub fn author(mut self, author: &'a str) -> Self {
+        self.author = Some(author);
+        self
+    }
+
+    /// Sets the `since` query parameter.
+    pub fn since(mut self, since: chrono::DateTime<chrono::Utc>) -> Self {
+        self.since = Some(since);
+        self
+    }
+
+    /// Sets the `until` query parameter.
+    pub fn until(mut self, until: chrono::DateTime<chrono::Utc>) -> Self {
+        self.until = Some(until);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::CommitDataType>>> {
+        let Self {
+            client,
+            owner,
+            repo,
+            sha,
+            path,
+            author,
+            since,
+            until,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = author {
+            query_args.push(("author".to_string(), v.to_string()));
+        }
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = path {
+            query_args.push(("path".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        if let Some(v) = sha {
+            query_args.push(("sha".to_string(), v.to_string()));
+        }
+        if let Some(date) = since {
+            query_args.push(("since".to_string(), date.to_rfc3339()));
+        }
+        if let Some(date) = until {
+            query_args.push(("until".to_string(), date.to_rfc3339()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/repos/{}/{}/commits?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Repos::list_comments_for_commit`], set with a builder.
+pub struct ReposListCommentsForCommitRequest<'a> {
+    client: &'a Repos,
+    owner: &'a str,
+    repo: &'a str,
+    commit_sha: &'a str,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> ReposListCommentsForCommitRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::CommitComment>>> {
+        let Self {
+            client,
+            owner,
+            repo,
+            commit_sha,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/repos/{}/{}/commits/{}/comments?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(commit_sha),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Repos::list_pull_requests_associated_with_commit`], set with a builder.
+pub struct ReposListPullRequestsAssociatedWithCommitRequest<'a> {
+    client: &'a Repos,
+    owner: &'a str,
+    repo: &'a str,
+    commit_sha: &'a str,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> ReposListPullRequestsAssociatedWithCommitRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::PullRequestSimple>>> {
+        let Self {
+            client,
+            owner,
+            repo,
+            commit_sha,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/repos/{}/{}/commits/{}/pulls?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(commit_sha),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Repos::get_commit`], set with a builder.
+pub struct ReposGetCommitRequest<'a> {
+    client: &'a Repos,
+    owner: &'a str,
+    repo: &'a str,
+    page: Option<i64>,
+    per_page: Option<i64>,
+    ref_: &'a str,
+}
+
+impl<'a> ReposGetCommitRequest<'a> {
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::CommitDataType>> {
+        let Self {
+            client,
+            owner,
+            repo,
+            page,
+            per_page,
+            ref_,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/repos/{}/{}/commits/{}?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(ref_),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Repos::get_combined_status_for_ref`], set with a builder.
+pub struct ReposGetCombinedStatusForRefRequest<'a> {
+    client: &'a Repos,
+    owner: &'a str,
+    repo: &'a str,
+    ref_: &'a str,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> ReposGetCombinedStatusForRefRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::CombinedCommitStatus>> {
+        let Self {
+            client,
+            owner,
+            repo,
+            ref_,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/repos/{}/{}/commits/{}/status?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(ref_),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Repos::list_commit_statuses_for_ref`], set with a builder.
+pub struct ReposListCommitStatusesForRefRequest<'a> {
+    client: &'a Repos,
+    owner: &'a str,
+    repo: &'a str,
+    ref_: &'a str,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> ReposListCommitStatusesForRefRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::StatusData>>> {
+        let Self {
+            client,
+            owner,
+            repo,
+            ref_,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/repos/{}/{}/commits/{}/statuses?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(ref_),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Repos::compare_commits`], set with a builder.
+pub struct ReposCompareCommitsRequest<'a> {
+    client: &'a Repos,
+    owner: &'a str,
+    repo: &'a str,
+    page: Option<i64>,
+    per_page: Option<i64>,
+    basehead: &'a str,
+}
+
+impl<'a> ReposCompareCommitsRequest<'a> {
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::CommitComparison>> {
+        let Self {
+            client,
+            owner,
+            repo,
+            page,
+            per_page,
+            basehead,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/repos/{}/{}/compare/{}?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(basehead),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Repos::get_content`], set with a builder.
+pub struct ReposGetContentRequest<'a> {
+    client: &'a Repos,
+    owner: &'a str,
+    repo: &'a str,
+    path: &'a str,
+    ref_: Option<&'a str>,
+}
+
+impl<'a> ReposGetContentRequest<'a> {
+    /// Sets the `ref` query parameter.
+    pub fn ref_(mut self, ref_: &'a str) -> Self {
+        self.ref_ = Some(ref_);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::ReposGetContentResponseOneOf>> {
+        let Self {
+            client,
+            owner,
+            repo,
+            path,
+            ref_,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = ref_ {
+            query_args.push(("ref".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/repos/{}/{}/contents/{}?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(path),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Repos::list_contributors`], set with a builder.
+pub struct ReposListContributorsRequest<'a> {
+    client: &'a Repos,
+    owner: &'a str,
+    repo: &'a str,
+    anon: Option<&'a str>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> ReposListContributorsRequest<'a> {
+    /// Sets the `anon` query parameter.
+    pub fn anon(mut self, anon: &'a str) -> Self {
+        self.anon = Some(anon);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Contributor>>> {
+        let Self {
+            client,
+            owner,
+            repo,
+            anon,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = anon {
+            query_args.push(("anon".to_string(), v.to_string()));
+        }
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/repos/{}/{}/contributors?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Repos::list_deployments`], set with a builder.
+pub struct ReposListDeploymentsRequest<'a> {
+    client: &'a Repos,
+    owner: &'a str,
+    repo: &'a str,
+    sha: Option<&'a str>,
+    ref_: Option<&'a str>,
+    task: Option<&'a str>,
+    environment: Option<&'a str>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> ReposListDeploymentsRequest<'a> {
+    /// Sets the `sha` query parameter.
+    pub fn sha(mut self, sha: &'a str) -> Self {
+        self.sha = Some(sha);
+        self
+    }
+
+    /// Sets the `ref` query parameter.
+    pub fn ref_(mut self, ref_: &'a str) -> Self {
+        self.ref_ = Some(ref_);
+        self
+    }
+
+    /// Sets the `task` query parameter.
+    pub fn task(mut self, task: &'a str) -> Self {
+        self.task = Some(task);
+        self
+    }
+
+    /// Sets the `environment` query parameter.
+    pub fn environment(mut self, environment: &'a str) -> Self {
+        self.environment = Some(environment);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Deployment>>> {
+        let Self {
+            client,
+            owner,
+            repo,
+            sha,
+            ref_,
+            task,
+            environment,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = environment {
+            query_args.push(("environment".to_string(), v.to_string()));
+        }
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        if let Some(v) = ref_ {
+            query_args.push(("ref".to_string(), v.to_string()));
+        }
+        if let Some(v) = sha {
+            query_args.push(("sha".to_string(), v.to_string()));
+        }
+        if let Some(v) = task {
+            query_args.push(("task".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/repos/{}/{}/deployments?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Repos::list_deployment_statuses`], set with a builder.
+pub struct ReposListDeploymentStatusesRequest<'a> {
+    client: &'a Repos,
+    owner: &'a str,
+    repo: &'a str,
+    deployment_id: i64,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> ReposListDeploymentStatusesRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::DeploymentStatus>>> {
+        let Self {
+            client,
+            owner,
+            repo,
+            deployment_id,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/repos/{}/{}/deployments/{}/statuses?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&deployment_id.to_string()),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Repos::list_forks`], set with a builder.
+pub struct ReposListForksRequest<'a> {
+    client: &'a Repos,
+    owner: &'a str,
+    repo: &'a str,
+    sort: Option<crate::types::ReposListForksSort>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> ReposListForksRequest<'a> {
+    /// Sets the `sort` query parameter.
+    pub fn sort(mut self, sort: crate::types::ReposListForksSort) -> Self {
+        self.sort = Some(sort);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::MinimalRepository>>> {
+        let Self {
+            client,
+            owner,
+            repo,
+            sort,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        if let Some(v) = sort {
+            query_args.push(("sort".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/repos/{}/{}/forks?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Repos::list_webhooks`], set with a builder.
+pub struct ReposListWebhooksRequest<'a> {
+    client: &'a Repos,
+    owner: &'a str,
+    repo: &'a str,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> ReposListWebhooksRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Hook>>> {
+        let Self {
+            client,
+            owner,
+            repo,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/repos/{}/{}/hooks?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Repos::list_webhook_deliveries`], set with a builder.
+pub struct ReposListWebhookDeliveriesRequest<'a> {
+    client: &'a Repos,
+    owner: &'a str,
+    repo: &'a str,
+    hook_id: i64,
+    per_page: Option<i64>,
+    cursor: Option<&'a str>,
+}
+
+impl<'a> ReposListWebhookDeliveriesRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `cursor` query parameter.
+    pub fn cursor(mut self, cursor: &'a str) -> Self {
+        self.cursor = Some(cursor);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::HookDeliveryItem>>> {
+        let Self {
+            client,
+            owner,
+            repo,
+            hook_id,
+            per_page,
+            cursor,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = cursor {
+            query_args.push(("cursor".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/repos/{}/{}/hooks/{}/deliveries?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&hook_id.to_string()),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Repos::list_invitations`], set with a builder.
+pub struct ReposListInvitationsRequest<'a> {
+    client: &'a Repos,
+    owner: &'a str,
+    repo: &'a str,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> ReposListInvitationsRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::RepositoryInvitation>>> {
+        let Self {
+            client,
+            owner,
+            repo,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/repos/{}/{}/invitations?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Repos::list_deploy_keys`], set with a builder.
+pub struct ReposListDeployKeysRequest<'a> {
+    client: &'a Repos,
+    owner: &'a str,
+    repo: &'a str,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> ReposListDeployKeysRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::DeployKey>>> {
+        let Self {
+            client,
+            owner,
+            repo,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/repos/{}/{}/keys?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Repos::list_pages_builds`], set with a builder.
+pub struct ReposListPagesBuildsRequest<'a> {
+    client: &'a Repos,
+    owner: &'a str,
+    repo: &'a str,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> ReposListPagesBuildsRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::PageBuild>>> {
+        let Self {
+            client,
+            owner,
+            repo,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/repos/{}/{}/pages/builds?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Repos::get_readme`], set with a builder.
+pub struct ReposGetReadmeRequest<'a> {
+    client: &'a Repos,
+    owner: &'a str,
+    repo: &'a str,
+    ref_: Option<&'a str>,
+}
+
+impl<'a> ReposGetReadmeRequest<'a> {
+    /// Sets the `ref` query parameter.
+    pub fn ref_(mut self, ref_: &'a str) -> Self {
+        self.ref_ = Some(ref_);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::ContentFile>> {
+        let Self {
+            client,
+            owner,
+            repo,
+            ref_,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = ref_ {
+            query_args.push(("ref".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/repos/{}/{}/readme?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Repos::get_readme_in_directory`], set with a builder.
+pub struct ReposGetReadmeInDirectoryRequest<'a> {
+    client: &'a Repos,
+    owner: &'a str,
+    repo: &'a str,
+    dir: &'a str,
+    ref_: Option<&'a str>,
+}
+
+impl<'a> ReposGetReadmeInDirectoryRequest<'a> {
+    /// Sets the `ref` query parameter.
+    pub fn ref_(mut self, ref_: &'a str) -> Self {
+        self.ref_ = Some(ref_);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::ContentFile>> {
+        let Self {
+            client,
+            owner,
+            repo,
+            dir,
+            ref_,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = ref_ {
+            query_args.push(("ref".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/repos/{}/{}/readme/{}?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(dir),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Repos::list_releases`], set with a builder.
+pub struct ReposListReleasesRequest<'a> {
+    client: &'a Repos,
+    owner: &'a str,
+    repo: &'a str,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> ReposListReleasesRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Release>>> {
+        let Self {
+            client,
+            owner,
+            repo,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/repos/{}/{}/releases?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Repos::list_release_assets`], set with a builder.
+pub struct ReposListReleaseAssetsRequest<'a> {
+    client: &'a Repos,
+    owner: &'a str,
+    repo: &'a str,
+    release_id: i64,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> ReposListReleaseAssetsRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::ReleaseAsset>>> {
+        let Self {
+            client,
+            owner,
+            repo,
+            release_id,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/repos/{}/{}/releases/{}/assets?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&release_id.to_string()),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 #[derive(Debug, Default, Clone)]
 pub struct ReposUploadReleaseAssetDefaultServer {}
 
-impl ReposUploadReleaseAssetDefaultServer {
-    pub fn default_url(&self) -> &str {
-        "https://uploads.github.com"
+impl ReposUploadReleaseAssetDefaultServer {
+    pub fn default_url(&self) -> &str {
+        "https://uploads.github.com"
+    }
+}
+/// The parameters of [`Repos::list_tags`], set with a builder.
+pub struct ReposListTagsRequest<'a> {
+    client: &'a Repos,
+    owner: &'a str,
+    repo: &'a str,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> ReposListTagsRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Tag>>> {
+        let Self {
+            client,
+            owner,
+            repo,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/repos/{}/{}/tags?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Repos::list_teams`], set with a builder.
+pub struct ReposListTeamsRequest<'a> {
+    client: &'a Repos,
+    owner: &'a str,
+    repo: &'a str,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> ReposListTeamsRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Team>>> {
+        let Self {
+            client,
+            owner,
+            repo,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/repos/{}/{}/teams?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Repos::get_all_topics`], set with a builder.
+pub struct ReposGetAllTopicsRequest<'a> {
+    client: &'a Repos,
+    owner: &'a str,
+    repo: &'a str,
+    page: Option<i64>,
+    per_page: Option<i64>,
+}
+
+impl<'a> ReposGetAllTopicsRequest<'a> {
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::Topic>> {
+        let Self {
+            client,
+            owner,
+            repo,
+            page,
+            per_page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/repos/{}/{}/topics?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Repos::get_clones`], set with a builder.
+pub struct ReposGetClonesRequest<'a> {
+    client: &'a Repos,
+    owner: &'a str,
+    repo: &'a str,
+    per: Option<crate::types::Per>,
+}
+
+impl<'a> ReposGetClonesRequest<'a> {
+    /// Sets the `per` query parameter.
+    pub fn per(mut self, per: crate::types::Per) -> Self {
+        self.per = Some(per);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::CloneTraffic>> {
+        let Self {
+            client,
+            owner,
+            repo,
+            per,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = per {
+            query_args.push(("per".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/repos/{}/{}/traffic/clones?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Repos::get_views`], set with a builder.
+pub struct ReposGetViewsRequest<'a> {
+    client: &'a Repos,
+    owner: &'a str,
+    repo: &'a str,
+    per: Option<crate::types::Per>,
+}
+
+impl<'a> ReposGetViewsRequest<'a> {
+    /// Sets the `per` query parameter.
+    pub fn per(mut self, per: crate::types::Per) -> Self {
+        self.per = Some(per);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::ViewTraffic>> {
+        let Self {
+            client,
+            owner,
+            repo,
+            per,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = per {
+            query_args.push(("per".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/repos/{}/{}/traffic/views?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Repos::list_public`], set with a builder.
+pub struct ReposListPublicRequest<'a> {
+    client: &'a Repos,
+    since: Option<i64>,
+}
+
+impl<'a> ReposListPublicRequest<'a> {
+    /// Sets the `since` query parameter.
+    pub fn since(mut self, since: i64) -> Self {
+        self.since = Some(since);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::MinimalRepository>>> {
+        let Self { client, since } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = since {
+            query_args.push(("since".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/repositories?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Repos::list_for_authenticated_user`], set with a builder.
+pub struct ReposListForAuthenticatedUserRequest<'a> {
+    client: &'a Repos,
+    visibility: Option<crate::types::ReposListVisibility>,
+    affiliation: Option<&'a str>,
+    type_: Option<crate::types::ReposListType>,
+    sort: Option<crate::types::ReposListOrgSort>,
+    direction: Option<crate::types::Order>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+    since: Option<chrono::DateTime<chrono::Utc>>,
+    before: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+impl<'a> ReposListForAuthenticatedUserRequest<'a> {
+    /// Sets the `visibility` query parameter.
+    pub fn visibility(mut self, visibility: crate::types::ReposListVisibility) -> Self {
+        self.visibility = Some(visibility);
+        self
+    }
+
+    /// Sets the `affiliation` query parameter.
+    pub fn affiliation(mut self, affiliation: &'a str) -> Self {
+        self.affiliation = Some(affiliation);
+        self
+    }
+
+    /// Sets the `type` query parameter.
+    pub fn type_(mut self, type_: crate::types::ReposListType) -> Self {
+        self.type_ = Some(type_);
+        self
+    }
+
+    /// Sets the `sort` query parameter.
+    pub fn sort(mut self, sort: crate::types::ReposListOrgSort) -> Self {
+        self.sort = Some(sort);
+        self
+    }
+
+    /// Sets the `direction` query parameter.
+    pub fn direction(mut self, direction: crate::types::Order) -> Self {
+        self.direction = Some(direction);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sets the `since` query parameter.
+    pub fn since(mut self, since: chrono::DateTime<chrono::Utc>) -> Self {
+        self.since = Some(since);
+        self
+    }
+
+    /// Sets the `before` query parameter.
+    pub fn before(mut self, before: chrono::DateTime<chrono::Utc>) -> Self {
+        self.before = Some(before);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Repository>>> {
+        let Self {
+            client,
+            visibility,
+            affiliation,
+            type_,
+            sort,
+            direction,
+            per_page,
+            page,
+            since,
+            before,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = affiliation {
+            query_args.push(("affiliation".to_string(), v.to_string()));
+        }
+        if let Some(date) = before {
+            query_args.push(("before".to_string(), date.to_rfc3339()));
+        }
+        if let Some(v) = direction {
+            query_args.push(("direction".to_string(), v.to_string()));
+        }
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        if let Some(date) = since {
+            query_args.push(("since".to_string(), date.to_rfc3339()));
+        }
+        if let Some(v) = sort {
+            query_args.push(("sort".to_string(), v.to_string()));
+        }
+        if let Some(v) = type_ {
+            query_args.push(("type".to_string(), v.to_string()));
+        }
+        if let Some(v) = visibility {
+            query_args.push(("visibility".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(&format!("/user/repos?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Repos::list_invitations_for_authenticated_user`], set with a builder.
+pub struct ReposListInvitationsForAuthenticatedUserRequest<'a> {
+    client: &'a Repos,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> ReposListInvitationsForAuthenticatedUserRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::RepositoryInvitation>>> {
+        let Self {
+            client,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/user/repository_invitations?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Repos::list_for_user`], set with a builder.
+pub struct ReposListForUserRequest<'a> {
+    client: &'a Repos,
+    username: &'a str,
+    type_: Option<crate::types::ReposListUserType>,
+    sort: Option<crate::types::ReposListOrgSort>,
+    direction: Option<crate::types::Order>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> ReposListForUserRequest<'a> {
+    /// Sets the `type` query parameter.
+    pub fn type_(mut self, type_: crate::types::ReposListUserType) -> Self {
+        self.type_ = Some(type_);
+        self
+    }
+
+    /// Sets the `sort` query parameter.
+    pub fn sort(mut self, sort: crate::types::ReposListOrgSort) -> Self {
+        self.sort = Some(sort);
+        self
+    }
+
+    /// Sets the `direction` query parameter.
+    pub fn direction(mut self, direction: crate::types::Order) -> Self {
+        self.direction = Some(direction);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::MinimalRepository>>> {
+        let Self {
+            client,
+            username,
+            type_,
+            sort,
+            direction,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = direction {
+            query_args.push(("direction".to_string(), v.to_string()));
+        }
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        if let Some(v) = sort {
+            query_args.push(("sort".to_string(), v.to_string()));
+        }
+        if let Some(v) = type_ {
+            query_args.push(("type".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/users/{}/repos?{}",
+                crate::progenitor_support::encode_path(username),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
     }
 }
 
@@ -84,6 +2460,28 @@ impl Repos {
             )
             .await
     }
+    /**
+     * List organization repositories.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/repos` endpoint.
+     *
+     * As opposed to `list_for_org`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Lists repositories for the specified organization.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-organization-repositories>
+     */
+    pub fn list_for_org_request<'a>(&'a self, org: &'a str) -> ReposListForOrgRequest<'a> {
+        ReposListForOrgRequest {
+            client: self,
+            org,
+            type_: None,
+            sort: None,
+            direction: None,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List organization repositories.
      *
@@ -131,6 +2529,46 @@ impl Repos {
             )
             .await
     }
+    /**
+     * List organization repositories.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/repos` endpoint.
+     *
+     * As opposed to `list_all_for_org`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Lists repositories for the specified organization.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-organization-repositories>
+     */
+    pub fn list_all_for_org_stream(
+        &self,
+        org: &str,
+        type_: crate::types::ReposListOrgType,
+        sort: crate::types::ReposListOrgSort,
+        direction: crate::types::Order,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::MinimalRepository>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !direction.to_string().is_empty() {
+            query_args.push(("direction".to_string(), direction.to_string()));
+        }
+        if !sort.to_string().is_empty() {
+            query_args.push(("sort".to_string(), sort.to_string()));
+        }
+        if !type_.to_string().is_empty() {
+            query_args.push(("type".to_string(), type_.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/orgs/{}/repos?{}",
+                crate::progenitor_support::encode_path(org),
+                query_
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Create an organization repository.
      *
@@ -333,6 +2771,31 @@ impl Repos {
             )
             .await
     }
+    /**
+     * List all autolinks of a repository.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/autolinks` endpoint.
+     *
+     * As opposed to `list_autolinks`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * This returns a list of autolinks configured for the given repository.
+     *
+     * Information about autolinks are only available to repository administrators.
+     *
+     * FROM: <https://docs.github.com/v3/repos#list-autolinks>
+     */
+    pub fn list_autolinks_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+    ) -> ReposListAutolinksRequest<'a> {
+        ReposListAutolinksRequest {
+            client: self,
+            owner,
+            repo,
+            page: None,
+        }
+    }
     /**
      * List all autolinks of a repository.
      *
@@ -369,6 +2832,34 @@ impl Repos {
             )
             .await
     }
+    /**
+     * List all autolinks of a repository.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/autolinks` endpoint.
+     *
+     * As opposed to `list_all_autolinks`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * This returns a list of autolinks configured for the given repository.
+     *
+     * Information about autolinks are only available to repository administrators.
+     *
+     * FROM: <https://docs.github.com/v3/repos#list-autolinks>
+     */
+    pub fn list_all_autolinks_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Autolink>> + Unpin + '_ {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/autolinks",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Create an autolink reference for a repository.
      *
@@ -620,6 +3111,31 @@ impl Repos {
             )
             .await
     }
+    /**
+     * List branches.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/branches` endpoint.
+     *
+     * As opposed to `list_branches`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-branches>
+     */
+    pub fn list_branches_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+    ) -> ReposListBranchesRequest<'a> {
+        ReposListBranchesRequest {
+            client: self,
+            owner,
+            repo,
+            protected: None,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List branches.
      *
@@ -661,6 +3177,39 @@ impl Repos {
             )
             .await
     }
+    /**
+     * List branches.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/branches` endpoint.
+     *
+     * As opposed to `list_all_branches`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-branches>
+     */
+    pub fn list_all_branches_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        protected: bool,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::ShortBranch>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if protected {
+            query_args.push(("protected".to_string(), protected.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/branches?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                query_
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Get a branch.
      *
@@ -1404,6 +3953,34 @@ impl Repos {
             )
             .await
     }
+    /**
+     * Get all status check contexts.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/branches/{branch}/protection/required_status_checks/contexts` endpoint.
+     *
+     * As opposed to `get_all_all_status_check_contexts`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Protected branches are available in public repositories with GitHub Free and GitHub Free for organizations, and in public and private repositories with GitHub Pro, GitHub Team, GitHub Enterprise Cloud, and GitHub Enterprise Server. For more information, see [GitHub's products](https://help.github.com/github/getting-started-with-github/githubs-products) in the GitHub Help documentation.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#get-all-status-check-contexts>
+     */
+    pub fn get_all_all_status_check_contexts_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> impl futures::Stream<Item = ClientResult<String>> + Unpin + '_ {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/branches/{}/protection/required_status_checks/contexts",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(branch),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Set status check contexts.
      *
@@ -1693,6 +4270,36 @@ impl Repos {
             )
             .await
     }
+    /**
+     * Get apps with access to the protected branch.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/branches/{branch}/protection/restrictions/apps` endpoint.
+     *
+     * As opposed to `get_all_apps_with_access_to_protected_branch`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Protected branches are available in public repositories with GitHub Free and GitHub Free for organizations, and in public and private repositories with GitHub Pro, GitHub Team, GitHub Enterprise Cloud, and GitHub Enterprise Server. For more information, see [GitHub's products](https://help.github.com/github/getting-started-with-github/githubs-products) in the GitHub Help documentation.
+     *
+     * Lists the GitHub Apps that have push access to this branch. Only installed GitHub Apps with `write` access to the `contents` permission can be added as authorized actors on a protected branch.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-apps-with-access-to-the-protected-branch>
+     */
+    pub fn get_all_apps_with_access_to_protected_branch_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::GitHubApp>> + Unpin + '_ {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/branches/{}/protection/restrictions/apps",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(branch),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Set app access restrictions.
      *
@@ -1844,14 +4451,52 @@ impl Repos {
      * Lists the teams who have push access to this branch. The list includes child teams.
      *
      * FROM: <https://docs.github.com/rest/reference/repos#list-teams-with-access-to-the-protected-branch>
-     *
-     * **Parameters:**
-     *
-     * * `owner: &str`
-     * * `repo: &str`
-     * * `branch: &str` -- The name of the branch.
+     *
+     * **Parameters:**
+     *
+     * * `owner: &str`
+     * * `repo: &str`
+     * * `branch: &str` -- The name of the branch.
+     */
+    pub async fn get_teams_with_access_to_protected_branch(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Team>>> {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/branches/{}/protection/restrictions/teams",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(branch),
+            ),
+            None,
+        );
+        self.client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+    /**
+     * Get teams with access to the protected branch.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/branches/{branch}/protection/restrictions/teams` endpoint.
+     *
+     * As opposed to `get_teams_with_access_to_protected_branch`, this function returns all the pages of the request at once.
+     *
+     * Protected branches are available in public repositories with GitHub Free and GitHub Free for organizations, and in public and private repositories with GitHub Pro, GitHub Team, GitHub Enterprise Cloud, and GitHub Enterprise Server. For more information, see [GitHub's products](https://help.github.com/github/getting-started-with-github/githubs-products) in the GitHub Help documentation.
+     *
+     * Lists the teams who have push access to this branch. The list includes child teams.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-teams-with-access-to-the-protected-branch>
      */
-    pub async fn get_teams_with_access_to_protected_branch(
+    pub async fn get_all_teams_with_access_to_protected_branch(
         &self,
         owner: &str,
         repo: &str,
@@ -1867,7 +4512,7 @@ impl Repos {
             None,
         );
         self.client
-            .get(
+            .get_all_pages(
                 &url,
                 crate::Message {
                     body: None,
@@ -1881,7 +4526,7 @@ impl Repos {
      *
      * This function performs a `GET` to the `/repos/{owner}/{repo}/branches/{branch}/protection/restrictions/teams` endpoint.
      *
-     * As opposed to `get_teams_with_access_to_protected_branch`, this function returns all the pages of the request at once.
+     * As opposed to `get_all_teams_with_access_to_protected_branch`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
      *
      * Protected branches are available in public repositories with GitHub Free and GitHub Free for organizations, and in public and private repositories with GitHub Pro, GitHub Team, GitHub Enterprise Cloud, and GitHub Enterprise Server. For more information, see [GitHub's products](https://help.github.com/github/getting-started-with-github/githubs-products) in the GitHub Help documentation.
      *
@@ -1889,12 +4534,12 @@ impl Repos {
      *
      * FROM: <https://docs.github.com/rest/reference/repos#list-teams-with-access-to-the-protected-branch>
      */
-    pub async fn get_all_teams_with_access_to_protected_branch(
+    pub fn get_all_teams_with_access_to_protected_branch_stream(
         &self,
         owner: &str,
         repo: &str,
         branch: &str,
-    ) -> ClientResult<crate::Response<Vec<crate::types::Team>>> {
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Team>> + Unpin + '_ {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/branches/{}/protection/restrictions/teams",
@@ -1904,15 +4549,7 @@ impl Repos {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(url)
     }
     /**
      * Set team access restrictions.
@@ -2135,6 +4772,36 @@ impl Repos {
             )
             .await
     }
+    /**
+     * Get users with access to the protected branch.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/branches/{branch}/protection/restrictions/users` endpoint.
+     *
+     * As opposed to `get_all_users_with_access_to_protected_branch`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Protected branches are available in public repositories with GitHub Free and GitHub Free for organizations, and in public and private repositories with GitHub Pro, GitHub Team, GitHub Enterprise Cloud, and GitHub Enterprise Server. For more information, see [GitHub's products](https://help.github.com/github/getting-started-with-github/githubs-products) in the GitHub Help documentation.
+     *
+     * Lists the people who have push access to this branch.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-users-with-access-to-the-protected-branch>
+     */
+    pub fn get_all_users_with_access_to_protected_branch_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::SimpleUser>> + Unpin + '_ {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/branches/{}/protection/restrictions/users",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(branch),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Set user access restrictions.
      *
@@ -2391,6 +5058,33 @@ impl Repos {
             )
             .await
     }
+    /**
+     * List repository collaborators.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/collaborators` endpoint.
+     *
+     * As opposed to `list_collaborators`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * For organization-owned repositories, the list of collaborators includes outside collaborators, organization members that are direct collaborators, organization members with access through team memberships, organization members with access through default organization permissions, and organization owners.
+     *
+     * Team members will include the members of child teams.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-repository-collaborators>
+     */
+    pub fn list_collaborators_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+    ) -> ReposListCollaboratorsRequest<'a> {
+        ReposListCollaboratorsRequest {
+            client: self,
+            owner,
+            repo,
+            affiliation: None,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List repository collaborators.
      *
@@ -2434,6 +5128,41 @@ impl Repos {
             )
             .await
     }
+    /**
+     * List repository collaborators.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/collaborators` endpoint.
+     *
+     * As opposed to `list_all_collaborators`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * For organization-owned repositories, the list of collaborators includes outside collaborators, organization members that are direct collaborators, organization members with access through team memberships, organization members with access through default organization permissions, and organization owners.
+     *
+     * Team members will include the members of child teams.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-repository-collaborators>
+     */
+    pub fn list_all_collaborators_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        affiliation: crate::types::Affiliation,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Collaborator>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !affiliation.to_string().is_empty() {
+            query_args.push(("affiliation".to_string(), affiliation.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/collaborators?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                query_
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Check if a user is a repository collaborator.
      *
@@ -2659,6 +5388,32 @@ impl Repos {
             )
             .await
     }
+    /**
+     * List commit comments for a repository.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/comments` endpoint.
+     *
+     * As opposed to `list_commit_comments_for_repo`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Commit Comments use [these custom media types](https://docs.github.com/rest/reference/repos#custom-media-types). You can read more about the use of media types in the API [here](https://docs.github.com/rest/overview/media-types/).
+     *
+     * Comments are ordered by ascending ID.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-commit-comments-for-a-repository>
+     */
+    pub fn list_commit_comments_for_repo_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+    ) -> ReposListCommitCommentsForRepoRequest<'a> {
+        ReposListCommitCommentsForRepoRequest {
+            client: self,
+            owner,
+            repo,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List commit comments for a repository.
      *
@@ -2695,6 +5450,34 @@ impl Repos {
             )
             .await
     }
+    /**
+     * List commit comments for a repository.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/comments` endpoint.
+     *
+     * As opposed to `list_all_commit_comments_for_repo`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Commit Comments use [these custom media types](https://docs.github.com/rest/reference/repos#custom-media-types). You can read more about the use of media types in the API [here](https://docs.github.com/rest/overview/media-types/).
+     *
+     * Comments are ordered by ascending ID.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-commit-comments-for-a-repository>
+     */
+    pub fn list_all_commit_comments_for_repo_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::CommitComment>> + Unpin + '_ {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/comments",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Get a commit comment.
      *
@@ -2851,20 +5634,168 @@ impl Repos {
      * | `valid` | None of the above errors applied, so the signature is considered to be verified. |
      *
      * FROM: <https://docs.github.com/rest/reference/repos#list-commits>
-     *
-     * **Parameters:**
-     *
-     * * `owner: &str`
-     * * `repo: &str`
-     * * `sha: &str` -- SHA or branch to start listing commits from. Default: the repository’s default branch (usually `master`).
-     * * `path: &str` -- Only commits containing this file path will be returned.
-     * * `author: &str` -- GitHub login or email address by which to filter by commit author.
-     * * `since: chrono::DateTime<chrono::Utc>` -- Only show notifications updated after the given time. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
-     * * `until: chrono::DateTime<chrono::Utc>` -- Only commits before this date will be returned. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     *
+     * **Parameters:**
+     *
+     * * `owner: &str`
+     * * `repo: &str`
+     * * `sha: &str` -- SHA or branch to start listing commits from. Default: the repository’s default branch (usually `master`).
+     * * `path: &str` -- Only commits containing this file path will be returned.
+     * * `author: &str` -- GitHub login or email address by which to filter by commit author.
+     * * `since: chrono::DateTime<chrono::Utc>` -- Only show notifications updated after the given time. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
+     * * `until: chrono::DateTime<chrono::Utc>` -- Only commits before this date will be returned. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
+     * * `per_page: i64` -- Results per page (max 100).
+     * * `page: i64` -- Page number of the results to fetch.
+     */
+    pub async fn list_commits(
+        &self,
+        owner: &str,
+        repo: &str,
+        sha: &str,
+        path: &str,
+        author: &str,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+        until: Option<chrono::DateTime<chrono::Utc>>,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::CommitDataType>>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !author.is_empty() {
+            query_args.push(("author".to_string(), author.to_string()));
+        }
+        if page > 0 {
+            query_args.push(("page".to_string(), page.to_string()));
+        }
+        if !path.is_empty() {
+            query_args.push(("path".to_string(), path.to_string()));
+        }
+        if per_page > 0 {
+            query_args.push(("per_page".to_string(), per_page.to_string()));
+        }
+        if !sha.is_empty() {
+            query_args.push(("sha".to_string(), sha.to_string()));
+        }
+        if let Some(date) = since {
+            query_args.push(("since".to_string(), date.to_rfc3339()));
+        }
+        if let Some(date) = until {
+            query_args.push(("until".to_string(), date.to_rfc3339()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/commits?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                query_
+            ),
+            None,
+        );
+        self.client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+    /**
+     * List commits.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/commits` endpoint.
+     *
+     * As opposed to `list_commits`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * **Signature verification object**
+     *
+     * The response will include a `verification` object that describes the result of verifying the commit's signature. The following fields are included in the `verification` object:
+     *
+     * | Name | Type | Description |
+     * | ---- | ---- | ----------- |
+     * | `verified` | `boolean` | Indicates whether GitHub considers the signature in this commit to be verified. |
+     * | `reason` | `string` | The reason for verified value. Possible values and their meanings are enumerated in table below. |
+     * | `signature` | `string` | The signature that was extracted from the commit. |
+     * | `payload` | `string` | The value that was signed. |
+     *
+     * These are the possible values for `reason` in the `verification` object:
+     *
+     * | Value | Description |
+     * | ----- | ----------- |
+     * | `expired_key` | The key that made the signature is expired. |
+     * | `not_signing_key` | The "signing" flag is not among the usage flags in the GPG key that made the signature. |
+     * | `gpgverify_error` | There was an error communicating with the signature verification service. |
+     * | `gpgverify_unavailable` | The signature verification service is currently unavailable. |
+     * | `unsigned` | The object does not include a signature. |
+     * | `unknown_signature_type` | A non-PGP signature was found in the commit. |
+     * | `no_user` | No user was associated with the `committer` email address in the commit. |
+     * | `unverified_email` | The `committer` email address in the commit was associated with a user, but the email address is not verified on her/his account. |
+     * | `bad_email` | The `committer` email address in the commit is not included in the identities of the PGP key that made the signature. |
+     * | `unknown_key` | The key that made the signature has not been registered with any user's account. |
+     * | `malformed_signature` | There was an error parsing the signature. |
+     * | `invalid` | The signature could not be cryptographically verified using the key whose key-id was found in the signature. |
+     * | `valid` | None of the above errors applied, so the signature is considered to be verified. |
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-commits>
+     */
+    pub fn list_commits_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+    ) -> ReposListCommitsRequest<'a> {
+        ReposListCommitsRequest {
+            client: self,
+            owner,
+            repo,
+            sha: None,
+            path: None,
+            author: None,
+            since: None,
+            until: None,
+            per_page: None,
+            page: None,
+        }
+    }
+    /**
+     * List commits.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/commits` endpoint.
+     *
+     * As opposed to `list_commits`, this function returns all the pages of the request at once.
+     *
+     * **Signature verification object**
+     *
+     * The response will include a `verification` object that describes the result of verifying the commit's signature. The following fields are included in the `verification` object:
+     *
+     * | Name | Type | Description |
+     * | ---- | ---- | ----------- |
+     * | `verified` | `boolean` | Indicates whether GitHub considers the signature in this commit to be verified. |
+     * | `reason` | `string` | The reason for verified value. Possible values and their meanings are enumerated in table below. |
+     * | `signature` | `string` | The signature that was extracted from the commit. |
+     * | `payload` | `string` | The value that was signed. |
+     *
+     * These are the possible values for `reason` in the `verification` object:
+     *
+     * | Value | Description |
+     * | ----- | ----------- |
+     * | `expired_key` | The key that made the signature is expired. |
+     * | `not_signing_key` | The "signing" flag is not among the usage flags in the GPG key that made the signature. |
+     * | `gpgverify_error` | There was an error communicating with the signature verification service. |
+     * | `gpgverify_unavailable` | The signature verification service is currently unavailable. |
+     * | `unsigned` | The object does not include a signature. |
+     * | `unknown_signature_type` | A non-PGP signature was found in the commit. |
+     * | `no_user` | No user was associated with the `committer` email address in the commit. |
+     * | `unverified_email` | The `committer` email address in the commit was associated with a user, but the email address is not verified on her/his account. |
+     * | `bad_email` | The `committer` email address in the commit is not included in the identities of the PGP key that made the signature. |
+     * | `unknown_key` | The key that made the signature has not been registered with any user's account. |
+     * | `malformed_signature` | There was an error parsing the signature. |
+     * | `invalid` | The signature could not be cryptographically verified using the key whose key-id was found in the signature. |
+     * | `valid` | None of the above errors applied, so the signature is considered to be verified. |
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-commits>
      */
-    pub async fn list_commits(
+    pub async fn list_all_commits(
         &self,
         owner: &str,
         repo: &str,
@@ -2873,22 +5804,14 @@ impl Repos {
         author: &str,
         since: Option<chrono::DateTime<chrono::Utc>>,
         until: Option<chrono::DateTime<chrono::Utc>>,
-        per_page: i64,
-        page: i64,
     ) -> ClientResult<crate::Response<Vec<crate::types::CommitDataType>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !author.is_empty() {
             query_args.push(("author".to_string(), author.to_string()));
         }
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
-        }
         if !path.is_empty() {
             query_args.push(("path".to_string(), path.to_string()));
         }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
-        }
         if !sha.is_empty() {
             query_args.push(("sha".to_string(), sha.to_string()));
         }
@@ -2909,7 +5832,7 @@ impl Repos {
             None,
         );
         self.client
-            .get(
+            .get_all_pages(
                 &url,
                 crate::Message {
                     body: None,
@@ -2923,7 +5846,7 @@ impl Repos {
      *
      * This function performs a `GET` to the `/repos/{owner}/{repo}/commits` endpoint.
      *
-     * As opposed to `list_commits`, this function returns all the pages of the request at once.
+     * As opposed to `list_all_commits`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
      *
      * **Signature verification object**
      *
@@ -2956,7 +5879,7 @@ impl Repos {
      *
      * FROM: <https://docs.github.com/rest/reference/repos#list-commits>
      */
-    pub async fn list_all_commits(
+    pub fn list_all_commits_stream(
         &self,
         owner: &str,
         repo: &str,
@@ -2965,7 +5888,7 @@ impl Repos {
         author: &str,
         since: Option<chrono::DateTime<chrono::Utc>>,
         until: Option<chrono::DateTime<chrono::Utc>>,
-    ) -> ClientResult<crate::Response<Vec<crate::types::CommitDataType>>> {
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::CommitDataType>> + Unpin + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !author.is_empty() {
             query_args.push(("author".to_string(), author.to_string()));
@@ -2992,15 +5915,7 @@ impl Repos {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(url)
     }
     /**
      * List branches for HEAD commit.
@@ -3082,6 +5997,36 @@ impl Repos {
             )
             .await
     }
+    /**
+     * List branches for HEAD commit.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/commits/{commit_sha}/branches-where-head` endpoint.
+     *
+     * As opposed to `list_all_branches_for_head_commit`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Protected branches are available in public repositories with GitHub Free and GitHub Free for organizations, and in public and private repositories with GitHub Pro, GitHub Team, GitHub Enterprise Cloud, and GitHub Enterprise Server. For more information, see [GitHub's products](https://help.github.com/github/getting-started-with-github/githubs-products) in the GitHub Help documentation.
+     *
+     * Returns all branches where the given commit SHA is the HEAD, or latest commit for the branch.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-branches-for-head-commit>
+     */
+    pub fn list_all_branches_for_head_commit_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        commit_sha: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::BranchShort>> + Unpin + '_ {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/commits/{}/branches-where-head",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(commit_sha),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * List commit comments.
      *
@@ -3135,6 +6080,32 @@ impl Repos {
             )
             .await
     }
+    /**
+     * List commit comments.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/commits/{commit_sha}/comments` endpoint.
+     *
+     * As opposed to `list_comments_for_commit`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Use the `:commit_sha` to specify the commit that will have its comments listed.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-commit-comments>
+     */
+    pub fn list_comments_for_commit_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+        commit_sha: &'a str,
+    ) -> ReposListCommentsForCommitRequest<'a> {
+        ReposListCommentsForCommitRequest {
+            client: self,
+            owner,
+            repo,
+            commit_sha,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List commit comments.
      *
@@ -3171,6 +6142,34 @@ impl Repos {
             )
             .await
     }
+    /**
+     * List commit comments.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/commits/{commit_sha}/comments` endpoint.
+     *
+     * As opposed to `list_all_comments_for_commit`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Use the `:commit_sha` to specify the commit that will have its comments listed.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-commit-comments>
+     */
+    pub fn list_all_comments_for_commit_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        commit_sha: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::CommitComment>> + Unpin + '_ {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/commits/{}/comments",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(commit_sha),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Create a commit comment.
      *
@@ -3267,6 +6266,32 @@ impl Repos {
             )
             .await
     }
+    /**
+     * List pull requests associated with a commit.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/commits/{commit_sha}/pulls` endpoint.
+     *
+     * As opposed to `list_pull_requests_associated_with_commit`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Lists the merged pull request that introduced the commit to the repository. If the commit is not present in the default branch, additionally returns open pull requests associated with the commit. The results may include open and closed pull requests. Additional preview headers may be required to see certain details for associated pull requests, such as whether a pull request is in a draft state. For more information about previews that might affect this endpoint, see the [List pull requests](https://docs.github.com/rest/reference/pulls#list-pull-requests) endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-pull-requests-associated-with-a-commit>
+     */
+    pub fn list_pull_requests_associated_with_commit_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+        commit_sha: &'a str,
+    ) -> ReposListPullRequestsAssociatedWithCommitRequest<'a> {
+        ReposListPullRequestsAssociatedWithCommitRequest {
+            client: self,
+            owner,
+            repo,
+            commit_sha,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List pull requests associated with a commit.
      *
@@ -3303,6 +6328,35 @@ impl Repos {
             )
             .await
     }
+    /**
+     * List pull requests associated with a commit.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/commits/{commit_sha}/pulls` endpoint.
+     *
+     * As opposed to `list_all_pull_requests_associated_with_commit`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Lists the merged pull request that introduced the commit to the repository. If the commit is not present in the default branch, additionally returns open pull requests associated with the commit. The results may include open and closed pull requests. Additional preview headers may be required to see certain details for associated pull requests, such as whether a pull request is in a draft state. For more information about previews that might affect this endpoint, see the [List pull requests](https://docs.github.com/rest/reference/pulls#list-pull-requests) endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-pull-requests-associated-with-a-commit>
+     */
+    pub fn list_all_pull_requests_associated_with_commit_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        commit_sha: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::PullRequestSimple>> + Unpin + '_
+    {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/commits/{}/pulls",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(commit_sha),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Get a commit.
      *
@@ -3391,6 +6445,67 @@ impl Repos {
             )
             .await
     }
+    /**
+     * Get a commit.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/commits/{ref}` endpoint.
+     *
+     * As opposed to `get_commit`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Returns the contents of a single commit reference. You must have `read` access for the repository to use this endpoint.
+     *
+     * **Note:** If there are more than 300 files in the commit diff, the response will include pagination link headers for the remaining files, up to a limit of 3000 files. Each page contains the static commit information, and the only changes are to the file listing.
+     *
+     * You can pass the appropriate [media type](https://docs.github.com/rest/overview/media-types/#commits-commit-comparison-and-pull-requests) to  fetch `diff` and `patch` formats. Diffs with binary data will have no `patch` property.
+     *
+     * To return only the SHA-1 hash of the commit reference, you can provide the `sha` custom [media type](https://docs.github.com/rest/overview/media-types/#commits-commit-comparison-and-pull-requests) in the `Accept` header. You can use this endpoint to check if a remote reference's SHA-1 hash is the same as your local reference's SHA-1 hash by providing the local SHA-1 reference as the ETag.
+     *
+     * **Signature verification object**
+     *
+     * The response will include a `verification` object that describes the result of verifying the commit's signature. The following fields are included in the `verification` object:
+     *
+     * | Name | Type | Description |
+     * | ---- | ---- | ----------- |
+     * | `verified` | `boolean` | Indicates whether GitHub considers the signature in this commit to be verified. |
+     * | `reason` | `string` | The reason for verified value. Possible values and their meanings are enumerated in table below. |
+     * | `signature` | `string` | The signature that was extracted from the commit. |
+     * | `payload` | `string` | The value that was signed. |
+     *
+     * These are the possible values for `reason` in the `verification` object:
+     *
+     * | Value | Description |
+     * | ----- | ----------- |
+     * | `expired_key` | The key that made the signature is expired. |
+     * | `not_signing_key` | The "signing" flag is not among the usage flags in the GPG key that made the signature. |
+     * | `gpgverify_error` | There was an error communicating with the signature verification service. |
+     * | `gpgverify_unavailable` | The signature verification service is currently unavailable. |
+     * | `unsigned` | The object does not include a signature. |
+     * | `unknown_signature_type` | A non-PGP signature was found in the commit. |
+     * | `no_user` | No user was associated with the `committer` email address in the commit. |
+     * | `unverified_email` | The `committer` email address in the commit was associated with a user, but the email address is not verified on her/his account. |
+     * | `bad_email` | The `committer` email address in the commit is not included in the identities of the PGP key that made the signature. |
+     * | `unknown_key` | The key that made the signature has not been registered with any user's account. |
+     * | `malformed_signature` | There was an error parsing the signature. |
+     * | `invalid` | The signature could not be cryptographically verified using the key whose key-id was found in the signature. |
+     * | `valid` | None of the above errors applied, so the signature is considered to be verified. |
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#get-a-commit>
+     */
+    pub fn get_commit_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+        ref_: &'a str,
+    ) -> ReposGetCommitRequest<'a> {
+        ReposGetCommitRequest {
+            client: self,
+            owner,
+            repo,
+            page: None,
+            per_page: None,
+            ref_,
+        }
+    }
     /**
      * Get the combined status for a specific reference.
      *
@@ -3452,6 +6567,40 @@ impl Repos {
             )
             .await
     }
+    /**
+     * Get the combined status for a specific reference.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/commits/{ref}/status` endpoint.
+     *
+     * As opposed to `get_combined_status_for_ref`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Users with pull access in a repository can access a combined view of commit statuses for a given ref. The ref can be a SHA, a branch name, or a tag name.
+     *
+     * The most recent status for each context is returned, up to 100. This field [paginates](https://docs.github.com/rest/overview/resources-in-the-rest-api#pagination) if there are over 100 contexts.
+     *
+     * Additionally, a combined `state` is returned. The `state` is one of:
+     *
+     * *   **failure** if any of the contexts report as `error` or `failure`
+     * *   **pending** if there are no statuses or a context is `pending`
+     * *   **success** if the latest status for all contexts is `success`
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#get-the-combined-status-for-a-specific-reference>
+     */
+    pub fn get_combined_status_for_ref_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+        ref_: &'a str,
+    ) -> ReposGetCombinedStatusForRefRequest<'a> {
+        ReposGetCombinedStatusForRefRequest {
+            client: self,
+            owner,
+            repo,
+            ref_,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List commit statuses for a reference.
      *
@@ -3507,6 +6656,34 @@ impl Repos {
             )
             .await
     }
+    /**
+     * List commit statuses for a reference.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/commits/{ref}/statuses` endpoint.
+     *
+     * As opposed to `list_commit_statuses_for_ref`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Users with pull access in a repository can view commit statuses for a given ref. The ref can be a SHA, a branch name, or a tag name. Statuses are returned in reverse chronological order. The first status in the list will be the latest one.
+     *
+     * This resource is also available via a legacy route: `GET /repos/:owner/:repo/statuses/:ref`.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-commit-statuses-for-a-reference>
+     */
+    pub fn list_commit_statuses_for_ref_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+        ref_: &'a str,
+    ) -> ReposListCommitStatusesForRefRequest<'a> {
+        ReposListCommitStatusesForRefRequest {
+            client: self,
+            owner,
+            repo,
+            ref_,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List commit statuses for a reference.
      *
@@ -3545,6 +6722,36 @@ impl Repos {
             )
             .await
     }
+    /**
+     * List commit statuses for a reference.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/commits/{ref}/statuses` endpoint.
+     *
+     * As opposed to `list_all_commit_statuses_for_ref`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Users with pull access in a repository can view commit statuses for a given ref. The ref can be a SHA, a branch name, or a tag name. Statuses are returned in reverse chronological order. The first status in the list will be the latest one.
+     *
+     * This resource is also available via a legacy route: `GET /repos/:owner/:repo/statuses/:ref`.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-commit-statuses-for-a-reference>
+     */
+    pub fn list_all_commit_statuses_for_ref_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        ref_: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::StatusData>> + Unpin + '_ {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/commits/{}/statuses",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(ref_),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Get community profile metrics.
      *
@@ -3685,6 +6892,71 @@ impl Repos {
             )
             .await
     }
+    /**
+     * Compare two commits.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/compare/{basehead}` endpoint.
+     *
+     * As opposed to `compare_commits`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * The `basehead` param is comprised of two parts: `base` and `head`. Both must be branch names in `repo`. To compare branches across other repositories in the same network as `repo`, use the format `<USERNAME>:branch`.
+     *
+     * The response from the API is equivalent to running the `git log base..head` command; however, commits are returned in chronological order. Pass the appropriate [media type](https://docs.github.com/rest/overview/media-types/#commits-commit-comparison-and-pull-requests) to fetch diff and patch formats.
+     *
+     * The response also includes details on the files that were changed between the two commits. This includes the status of the change (for example, if a file was added, removed, modified, or renamed), and details of the change itself. For example, files with a `renamed` status have a `previous_filename` field showing the previous filename of the file, and files with a `modified` status have a `patch` field showing the changes made to the file.
+     *
+     * **Working with large comparisons**
+     *
+     * To process a response with a large number of commits, you can use (`per_page` or `page`) to paginate the results. When using paging, the list of changed files is only returned with page 1, but includes all changed files for the entire comparison. For more information on working with pagination, see "[Traversing with pagination](/rest/guides/traversing-with-pagination)."
+     *
+     * When calling this API without any paging parameters (`per_page` or `page`), the returned list is limited to 250 commits and the last commit in the list is the most recent of the entire comparison. When a paging parameter is specified, the first commit in the returned list of each page is the earliest.
+     *
+     * **Signature verification object**
+     *
+     * The response will include a `verification` object that describes the result of verifying the commit's signature. The following fields are included in the `verification` object:
+     *
+     * | Name | Type | Description |
+     * | ---- | ---- | ----------- |
+     * | `verified` | `boolean` | Indicates whether GitHub considers the signature in this commit to be verified. |
+     * | `reason` | `string` | The reason for verified value. Possible values and their meanings are enumerated in table below. |
+     * | `signature` | `string` | The signature that was extracted from the commit. |
+     * | `payload` | `string` | The value that was signed. |
+     *
+     * These are the possible values for `reason` in the `verification` object:
+     *
+     * | Value | Description |
+     * | ----- | ----------- |
+     * | `expired_key` | The key that made the signature is expired. |
+     * | `not_signing_key` | The "signing" flag is not among the usage flags in the GPG key that made the signature. |
+     * | `gpgverify_error` | There was an error communicating with the signature verification service. |
+     * | `gpgverify_unavailable` | The signature verification service is currently unavailable. |
+     * | `unsigned` | The object does not include a signature. |
+     * | `unknown_signature_type` | A non-PGP signature was found in the commit. |
+     * | `no_user` | No user was associated with the `committer` email address in the commit. |
+     * | `unverified_email` | The `committer` email address in the commit was associated with a user, but the email address is not verified on her/his account. |
+     * | `bad_email` | The `committer` email address in the commit is not included in the identities of the PGP key that made the signature. |
+     * | `unknown_key` | The key that made the signature has not been registered with any user's account. |
+     * | `malformed_signature` | There was an error parsing the signature. |
+     * | `invalid` | The signature could not be cryptographically verified using the key whose key-id was found in the signature. |
+     * | `valid` | None of the above errors applied, so the signature is considered to be verified. |
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#compare-two-commits>
+     */
+    pub fn compare_commits_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+        basehead: &'a str,
+    ) -> ReposCompareCommitsRequest<'a> {
+        ReposCompareCommitsRequest {
+            client: self,
+            owner,
+            repo,
+            page: None,
+            per_page: None,
+            basehead,
+        }
+    }
     /**
      * Get repository content.
      *
@@ -4080,6 +7352,62 @@ impl Repos {
             )
             .await
     }
+    /**
+     * Get repository content.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/contents/{path}` endpoint.
+     *
+     * As opposed to `get_content`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Gets the contents of a file or directory in a repository. Specify the file path or directory in `:path`. If you omit
+     * `:path`, you will receive the contents of the repository's root directory. See the description below regarding what the API response includes for directories.
+     *
+     * Files and symlinks support [a custom media type](https://docs.github.com/rest/reference/repos#custom-media-types) for
+     * retrieving the raw content or rendered HTML (when supported). All content types support [a custom media
+     * type](https://docs.github.com/rest/reference/repos#custom-media-types) to ensure the content is returned in a consistent
+     * object format.
+     *
+     * **Note**:
+     * *   To get a repository's contents recursively, you can [recursively get the tree](https://docs.github.com/rest/reference/git#trees).
+     * *   This API has an upper limit of 1,000 files for a directory. If you need to retrieve more files, use the [Git Trees
+     * API](https://docs.github.com/rest/reference/git#get-a-tree).
+     * *   This API supports files up to 1 megabyte in size.
+     *
+     * #### If the content is a directory
+     * The response will be an array of objects, one object for each item in the directory.
+     * When listing the contents of a directory, submodules have their "type" specified as "file". Logically, the value
+     * _should_ be "submodule". This behavior exists in API v3 [for backwards compatibility purposes](https://git.io/v1YCW).
+     * In the next major version of the API, the type will be returned as "submodule".
+     *
+     * #### If the content is a symlink
+     * If the requested `:path` points to a symlink, and the symlink's target is a normal file in the repository, then the
+     * API responds with the content of the file (in the format shown in the example. Otherwise, the API responds with an object
+     * describing the symlink itself.
+     *
+     * #### If the content is a submodule
+     * The `submodule_git_url` identifies the location of the submodule repository, and the `sha` identifies a specific
+     * commit within the submodule repository. Git uses the given URL when cloning the submodule repository, and checks out
+     * the submodule at that specific commit.
+     *
+     * If the submodule repository is not hosted on github.com, the Git URLs (`git_url` and `_links["git"]`) and the
+     * github.com URLs (`html_url` and `_links["html"]`) will have null values.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#get-repository-content>
+     */
+    pub fn get_content_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+        path: &'a str,
+    ) -> ReposGetContentRequest<'a> {
+        ReposGetContentRequest {
+            client: self,
+            owner,
+            repo,
+            path,
+            ref_: None,
+        }
+    }
     /**
      * Create or update file contents.
      *
@@ -4225,6 +7553,33 @@ impl Repos {
             )
             .await
     }
+    /**
+     * List repository contributors.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/contributors` endpoint.
+     *
+     * As opposed to `list_contributors`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Lists contributors to the specified repository and sorts them by the number of commits per contributor in descending order. This endpoint may return information that is a few hours old because the GitHub REST API v3 caches contributor data to improve performance.
+     *
+     * GitHub identifies contributors by author email address. This endpoint groups contribution counts by GitHub user, which includes all associated email addresses. To improve performance, only the first 500 author email addresses in the repository link to GitHub users. The rest will appear as anonymous contributors without associated GitHub user information.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-repository-contributors>
+     */
+    pub fn list_contributors_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+    ) -> ReposListContributorsRequest<'a> {
+        ReposListContributorsRequest {
+            client: self,
+            owner,
+            repo,
+            anon: None,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List repository contributors.
      *
@@ -4268,6 +7623,41 @@ impl Repos {
             )
             .await
     }
+    /**
+     * List repository contributors.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/contributors` endpoint.
+     *
+     * As opposed to `list_all_contributors`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Lists contributors to the specified repository and sorts them by the number of commits per contributor in descending order. This endpoint may return information that is a few hours old because the GitHub REST API v3 caches contributor data to improve performance.
+     *
+     * GitHub identifies contributors by author email address. This endpoint groups contribution counts by GitHub user, which includes all associated email addresses. To improve performance, only the first 500 author email addresses in the repository link to GitHub users. The rest will appear as anonymous contributors without associated GitHub user information.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-repository-contributors>
+     */
+    pub fn list_all_contributors_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        anon: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Contributor>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !anon.is_empty() {
+            query_args.push(("anon".to_string(), anon.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/contributors?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                query_
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * List deployments.
      *
@@ -4338,6 +7728,34 @@ impl Repos {
             )
             .await
     }
+    /**
+     * List deployments.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/deployments` endpoint.
+     *
+     * As opposed to `list_deployments`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Simple filtering of deployments is available via query parameters:
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-deployments>
+     */
+    pub fn list_deployments_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+    ) -> ReposListDeploymentsRequest<'a> {
+        ReposListDeploymentsRequest {
+            client: self,
+            owner,
+            repo,
+            sha: None,
+            ref_: None,
+            task: None,
+            environment: None,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List deployments.
      *
@@ -4391,6 +7809,51 @@ impl Repos {
             )
             .await
     }
+    /**
+     * List deployments.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/deployments` endpoint.
+     *
+     * As opposed to `list_all_deployments`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Simple filtering of deployments is available via query parameters:
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-deployments>
+     */
+    pub fn list_all_deployments_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        sha: &str,
+        ref_: &str,
+        task: &str,
+        environment: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Deployment>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !environment.is_empty() {
+            query_args.push(("environment".to_string(), environment.to_string()));
+        }
+        if !ref_.is_empty() {
+            query_args.push(("ref".to_string(), ref_.to_string()));
+        }
+        if !sha.is_empty() {
+            query_args.push(("sha".to_string(), sha.to_string()));
+        }
+        if !task.is_empty() {
+            query_args.push(("task".to_string(), task.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/deployments?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                query_
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Create a deployment.
      *
@@ -4585,26 +8048,88 @@ impl Repos {
         per_page: i64,
         page: i64,
     ) -> ClientResult<crate::Response<Vec<crate::types::DeploymentStatus>>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
-        }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if page > 0 {
+            query_args.push(("page".to_string(), page.to_string()));
+        }
+        if per_page > 0 {
+            query_args.push(("per_page".to_string(), per_page.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/deployments/{}/statuses?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&deployment_id.to_string()),
+                query_
+            ),
+            None,
+        );
+        self.client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+    /**
+     * List deployment statuses.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/deployments/{deployment_id}/statuses` endpoint.
+     *
+     * As opposed to `list_deployment_statuses`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Users with pull access can view deployment statuses for a deployment:
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-deployment-statuses>
+     */
+    pub fn list_deployment_statuses_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+        deployment_id: i64,
+    ) -> ReposListDeploymentStatusesRequest<'a> {
+        ReposListDeploymentStatusesRequest {
+            client: self,
+            owner,
+            repo,
+            deployment_id,
+            per_page: None,
+            page: None,
+        }
+    }
+    /**
+     * List deployment statuses.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/deployments/{deployment_id}/statuses` endpoint.
+     *
+     * As opposed to `list_deployment_statuses`, this function returns all the pages of the request at once.
+     *
+     * Users with pull access can view deployment statuses for a deployment:
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-deployment-statuses>
+     */
+    pub async fn list_all_deployment_statuses(
+        &self,
+        owner: &str,
+        repo: &str,
+        deployment_id: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::DeploymentStatus>>> {
         let url = self.client.url(
             &format!(
-                "/repos/{}/{}/deployments/{}/statuses?{}",
+                "/repos/{}/{}/deployments/{}/statuses",
                 crate::progenitor_support::encode_path(owner),
                 crate::progenitor_support::encode_path(repo),
                 crate::progenitor_support::encode_path(&deployment_id.to_string()),
-                query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_all_pages(
                 &url,
                 crate::Message {
                     body: None,
@@ -4618,18 +8143,19 @@ impl Repos {
      *
      * This function performs a `GET` to the `/repos/{owner}/{repo}/deployments/{deployment_id}/statuses` endpoint.
      *
-     * As opposed to `list_deployment_statuses`, this function returns all the pages of the request at once.
+     * As opposed to `list_all_deployment_statuses`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
      *
      * Users with pull access can view deployment statuses for a deployment:
      *
      * FROM: <https://docs.github.com/rest/reference/repos#list-deployment-statuses>
      */
-    pub async fn list_all_deployment_statuses(
+    pub fn list_all_deployment_statuses_stream(
         &self,
         owner: &str,
         repo: &str,
         deployment_id: i64,
-    ) -> ClientResult<crate::Response<Vec<crate::types::DeploymentStatus>>> {
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::DeploymentStatus>> + Unpin + '_
+    {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/deployments/{}/statuses",
@@ -4639,15 +8165,7 @@ impl Repos {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(url)
     }
     /**
      * Create a deployment status.
@@ -5003,6 +8521,31 @@ impl Repos {
             )
             .await
     }
+    /**
+     * List forks.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/forks` endpoint.
+     *
+     * As opposed to `list_forks`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-forks>
+     */
+    pub fn list_forks_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+    ) -> ReposListForksRequest<'a> {
+        ReposListForksRequest {
+            client: self,
+            owner,
+            repo,
+            sort: None,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List forks.
      *
@@ -5044,6 +8587,40 @@ impl Repos {
             )
             .await
     }
+    /**
+     * List forks.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/forks` endpoint.
+     *
+     * As opposed to `list_all_forks`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-forks>
+     */
+    pub fn list_all_forks_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        sort: crate::types::ReposListForksSort,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::MinimalRepository>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !sort.to_string().is_empty() {
+            query_args.push(("sort".to_string(), sort.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/forks?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                query_
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Create a fork.
      *
@@ -5134,6 +8711,30 @@ impl Repos {
             )
             .await
     }
+    /**
+     * List repository webhooks.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/hooks` endpoint.
+     *
+     * As opposed to `list_webhooks`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-repository-webhooks>
+     */
+    pub fn list_webhooks_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+    ) -> ReposListWebhooksRequest<'a> {
+        ReposListWebhooksRequest {
+            client: self,
+            owner,
+            repo,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List repository webhooks.
      *
@@ -5168,6 +8769,32 @@ impl Repos {
             )
             .await
     }
+    /**
+     * List repository webhooks.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/hooks` endpoint.
+     *
+     * As opposed to `list_all_webhooks`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-repository-webhooks>
+     */
+    pub fn list_all_webhooks_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Hook>> + Unpin + '_ {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/hooks",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Create a repository webhook.
      *
@@ -5466,6 +9093,32 @@ impl Repos {
             )
             .await
     }
+    /**
+     * List deliveries for a repository webhook.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/hooks/{hook_id}/deliveries` endpoint.
+     *
+     * As opposed to `list_webhook_deliveries`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Returns a list of webhook deliveries for a webhook configured in a repository.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-deliveries-for-a-repository-webhook>
+     */
+    pub fn list_webhook_deliveries_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+        hook_id: i64,
+    ) -> ReposListWebhookDeliveriesRequest<'a> {
+        ReposListWebhookDeliveriesRequest {
+            client: self,
+            owner,
+            repo,
+            hook_id,
+            per_page: None,
+            cursor: None,
+        }
+    }
     /**
      * List deliveries for a repository webhook.
      *
@@ -5509,6 +9162,42 @@ impl Repos {
             )
             .await
     }
+    /**
+     * List deliveries for a repository webhook.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/hooks/{hook_id}/deliveries` endpoint.
+     *
+     * As opposed to `list_all_webhook_deliveries`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Returns a list of webhook deliveries for a webhook configured in a repository.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-deliveries-for-a-repository-webhook>
+     */
+    pub fn list_all_webhook_deliveries_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        hook_id: i64,
+        cursor: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::HookDeliveryItem>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !cursor.is_empty() {
+            query_args.push(("cursor".to_string(), cursor.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/hooks/{}/deliveries?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&hook_id.to_string()),
+                query_
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Get a delivery for a repository webhook.
      *
@@ -5727,6 +9416,30 @@ impl Repos {
             )
             .await
     }
+    /**
+     * List repository invitations.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/invitations` endpoint.
+     *
+     * As opposed to `list_invitations`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * When authenticating as a user with admin rights to a repository, this endpoint will list all currently open repository invitations.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-repository-invitations>
+     */
+    pub fn list_invitations_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+    ) -> ReposListInvitationsRequest<'a> {
+        ReposListInvitationsRequest {
+            client: self,
+            owner,
+            repo,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List repository invitations.
      *
@@ -5761,6 +9474,33 @@ impl Repos {
             )
             .await
     }
+    /**
+     * List repository invitations.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/invitations` endpoint.
+     *
+     * As opposed to `list_all_invitations`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * When authenticating as a user with admin rights to a repository, this endpoint will list all currently open repository invitations.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-repository-invitations>
+     */
+    pub fn list_all_invitations_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::RepositoryInvitation>> + Unpin + '_
+    {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/invitations",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Delete a repository invitation.
      *
@@ -5892,6 +9632,30 @@ impl Repos {
             )
             .await
     }
+    /**
+     * List deploy keys.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/keys` endpoint.
+     *
+     * As opposed to `list_deploy_keys`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-deploy-keys>
+     */
+    pub fn list_deploy_keys_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+    ) -> ReposListDeployKeysRequest<'a> {
+        ReposListDeployKeysRequest {
+            client: self,
+            owner,
+            repo,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List deploy keys.
      *
@@ -5926,6 +9690,32 @@ impl Repos {
             )
             .await
     }
+    /**
+     * List deploy keys.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/keys` endpoint.
+     *
+     * As opposed to `list_all_deploy_keys`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-deploy-keys>
+     */
+    pub fn list_all_deploy_keys_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::DeployKey>> + Unpin + '_ {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/keys",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Create a deploy key.
      *
@@ -6319,6 +10109,30 @@ impl Repos {
             )
             .await
     }
+    /**
+     * List GitHub Pages builds.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/pages/builds` endpoint.
+     *
+     * As opposed to `list_pages_builds`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-github-pages-builds>
+     */
+    pub fn list_pages_builds_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+    ) -> ReposListPagesBuildsRequest<'a> {
+        ReposListPagesBuildsRequest {
+            client: self,
+            owner,
+            repo,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List GitHub Pages builds.
      *
@@ -6353,6 +10167,32 @@ impl Repos {
             )
             .await
     }
+    /**
+     * List GitHub Pages builds.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/pages/builds` endpoint.
+     *
+     * As opposed to `list_all_pages_builds`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-github-pages-builds>
+     */
+    pub fn list_all_pages_builds_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::PageBuild>> + Unpin + '_ {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/pages/builds",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Request a GitHub Pages build.
      *
@@ -6557,6 +10397,31 @@ impl Repos {
             )
             .await
     }
+    /**
+     * Get a repository README.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/readme` endpoint.
+     *
+     * As opposed to `get_readme`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Gets the preferred README for a repository.
+     *
+     * READMEs support [custom media types](https://docs.github.com/rest/reference/repos#custom-media-types) for retrieving the raw content or rendered HTML.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#get-a-repository-readme>
+     */
+    pub fn get_readme_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+    ) -> ReposGetReadmeRequest<'a> {
+        ReposGetReadmeRequest {
+            client: self,
+            owner,
+            repo,
+            ref_: None,
+        }
+    }
     /**
      * Get a repository README for a directory.
      *
@@ -6607,6 +10472,33 @@ impl Repos {
             )
             .await
     }
+    /**
+     * Get a repository README for a directory.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/readme/{dir}` endpoint.
+     *
+     * As opposed to `get_readme_in_directory`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Gets the README from a repository directory.
+     *
+     * READMEs support [custom media types](https://docs.github.com/rest/reference/repos#custom-media-types) for retrieving the raw content or rendered HTML.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#get-a-repository-directory-readme>
+     */
+    pub fn get_readme_in_directory_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+        dir: &'a str,
+    ) -> ReposGetReadmeInDirectoryRequest<'a> {
+        ReposGetReadmeInDirectoryRequest {
+            client: self,
+            owner,
+            repo,
+            dir,
+            ref_: None,
+        }
+    }
     /**
      * List releases.
      *
@@ -6659,6 +10551,32 @@ impl Repos {
             )
             .await
     }
+    /**
+     * List releases.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/releases` endpoint.
+     *
+     * As opposed to `list_releases`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * This returns a list of releases, which does not include regular Git tags that have not been associated with a release. To get a list of Git tags, use the [Repository Tags API](https://docs.github.com/rest/reference/repos#list-repository-tags).
+     *
+     * Information about published releases are available to everyone. Only users with push access will receive listings for draft releases.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-releases>
+     */
+    pub fn list_releases_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+    ) -> ReposListReleasesRequest<'a> {
+        ReposListReleasesRequest {
+            client: self,
+            owner,
+            repo,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List releases.
      *
@@ -6695,6 +10613,34 @@ impl Repos {
             )
             .await
     }
+    /**
+     * List releases.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/releases` endpoint.
+     *
+     * As opposed to `list_all_releases`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * This returns a list of releases, which does not include regular Git tags that have not been associated with a release. To get a list of Git tags, use the [Repository Tags API](https://docs.github.com/rest/reference/repos#list-repository-tags).
+     *
+     * Information about published releases are available to everyone. Only users with push access will receive listings for draft releases.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-releases>
+     */
+    pub fn list_all_releases_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Release>> + Unpin + '_ {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/releases",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Create a release.
      *
@@ -7109,6 +11055,32 @@ impl Repos {
             )
             .await
     }
+    /**
+     * List release assets.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/releases/{release_id}/assets` endpoint.
+     *
+     * As opposed to `list_release_assets`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-release-assets>
+     */
+    pub fn list_release_assets_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+        release_id: i64,
+    ) -> ReposListReleaseAssetsRequest<'a> {
+        ReposListReleaseAssetsRequest {
+            client: self,
+            owner,
+            repo,
+            release_id,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List release assets.
      *
@@ -7145,6 +11117,34 @@ impl Repos {
             )
             .await
     }
+    /**
+     * List release assets.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/releases/{release_id}/assets` endpoint.
+     *
+     * As opposed to `list_all_release_assets`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-release-assets>
+     */
+    pub fn list_all_release_assets_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        release_id: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::ReleaseAsset>> + Unpin + '_ {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/releases/{}/assets",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&release_id.to_string()),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Upload a release asset.
      *
@@ -7287,6 +11287,32 @@ impl Repos {
             )
             .await
     }
+    /**
+     * Get the weekly commit activity.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/stats/code_frequency` endpoint.
+     *
+     * As opposed to `get_all_code_frequency_stats`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Returns a weekly aggregate of the number of additions and deletions pushed to a repository.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#get-the-weekly-commit-activity>
+     */
+    pub fn get_all_code_frequency_stats_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> impl futures::Stream<Item = ClientResult<Vec<i64>>> + Unpin + '_ {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/stats/code_frequency",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Get the last year of commit activity.
      *
@@ -7358,6 +11384,32 @@ impl Repos {
             )
             .await
     }
+    /**
+     * Get the last year of commit activity.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/stats/commit_activity` endpoint.
+     *
+     * As opposed to `get_all_commit_activity_stats`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Returns the last year of commit activity grouped by week. The `days` array is a group of commits per day, starting on `Sunday`.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#get-the-last-year-of-commit-activity>
+     */
+    pub fn get_all_commit_activity_stats_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::CommitActivity>> + Unpin + '_ {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/stats/commit_activity",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Get all contributor commit activity.
      *
@@ -7441,6 +11493,39 @@ impl Repos {
             )
             .await
     }
+    /**
+     * Get all contributor commit activity.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/stats/contributors` endpoint.
+     *
+     * As opposed to `get_all_contributors_stats`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     *
+     * Returns the `total` number of commits authored by the contributor. In addition, the response includes a Weekly Hash (`weeks` array) with the following information:
+     *
+     * *   `w` - Start of the week, given as a [Unix timestamp](http://en.wikipedia.org/wiki/Unix_time).
+     * *   `a` - Number of additions
+     * *   `d` - Number of deletions
+     * *   `c` - Number of commits
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#get-all-contributor-commit-activity>
+     */
+    pub fn get_all_contributors_stats_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::ContributorActivity>> + Unpin + '_
+    {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/stats/contributors",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Get the weekly commit count.
      *
@@ -7563,6 +11648,38 @@ impl Repos {
             )
             .await
     }
+    /**
+     * Get the hourly commit count for each day.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/stats/punch_card` endpoint.
+     *
+     * As opposed to `get_all_punch_card_stats`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Each array contains the day number, hour number, and number of commits:
+     *
+     * *   `0-6`: Sunday - Saturday
+     * *   `0-23`: Hour of day
+     * *   Number of commits
+     *
+     * For example, `[2, 14, 25]` indicates that there were 25 total commits, during the 2:00pm hour on Tuesdays. All times are based on the time zone of individual commits.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#get-the-hourly-commit-count-for-each-day>
+     */
+    pub fn get_all_punch_card_stats_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> impl futures::Stream<Item = ClientResult<Vec<i64>>> + Unpin + '_ {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/stats/punch_card",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Create a commit status.
      *
@@ -7656,6 +11773,30 @@ impl Repos {
             )
             .await
     }
+    /**
+     * List repository tags.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/tags` endpoint.
+     *
+     * As opposed to `list_tags`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-repository-tags>
+     */
+    pub fn list_tags_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+    ) -> ReposListTagsRequest<'a> {
+        ReposListTagsRequest {
+            client: self,
+            owner,
+            repo,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List repository tags.
      *
@@ -7690,6 +11831,32 @@ impl Repos {
             )
             .await
     }
+    /**
+     * List repository tags.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/tags` endpoint.
+     *
+     * As opposed to `list_all_tags`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-repository-tags>
+     */
+    pub fn list_all_tags_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Tag>> + Unpin + '_ {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/tags",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Download a repository archive (tar).
      *
@@ -7783,6 +11950,30 @@ impl Repos {
             )
             .await
     }
+    /**
+     * List repository teams.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/teams` endpoint.
+     *
+     * As opposed to `list_teams`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-repository-teams>
+     */
+    pub fn list_teams_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+    ) -> ReposListTeamsRequest<'a> {
+        ReposListTeamsRequest {
+            client: self,
+            owner,
+            repo,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List repository teams.
      *
@@ -7817,6 +12008,32 @@ impl Repos {
             )
             .await
     }
+    /**
+     * List repository teams.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/teams` endpoint.
+     *
+     * As opposed to `list_all_teams`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-repository-teams>
+     */
+    pub fn list_all_teams_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Team>> + Unpin + '_ {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/teams",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Get all repository topics.
      *
@@ -7867,6 +12084,30 @@ impl Repos {
             )
             .await
     }
+    /**
+     * Get all repository topics.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/topics` endpoint.
+     *
+     * As opposed to `get_all_topics`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#get-all-repository-topics>
+     */
+    pub fn get_all_topics_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+    ) -> ReposGetAllTopicsRequest<'a> {
+        ReposGetAllTopicsRequest {
+            client: self,
+            owner,
+            repo,
+            page: None,
+            per_page: None,
+        }
+    }
     /**
      * Replace all repository topics.
      *
@@ -7950,6 +12191,29 @@ impl Repos {
             )
             .await
     }
+    /**
+     * Get repository clones.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/traffic/clones` endpoint.
+     *
+     * As opposed to `get_clones`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get the total number of clones and breakdown per day or week for the last 14 days. Timestamps are aligned to UTC midnight of the beginning of the day or week. Week begins on Monday.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#get-repository-clones>
+     */
+    pub fn get_clones_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+    ) -> ReposGetClonesRequest<'a> {
+        ReposGetClonesRequest {
+            client: self,
+            owner,
+            repo,
+            per: None,
+        }
+    }
     /**
      * Get top referral paths.
      *
@@ -8021,6 +12285,32 @@ impl Repos {
             )
             .await
     }
+    /**
+     * Get top referral paths.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/traffic/popular/paths` endpoint.
+     *
+     * As opposed to `get_all_top_paths`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Get the top 10 popular contents over the last 14 days.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#get-top-referral-paths>
+     */
+    pub fn get_all_top_paths_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::ContentTraffic>> + Unpin + '_ {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/traffic/popular/paths",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Get top referral sources.
      *
@@ -8092,6 +12382,32 @@ impl Repos {
             )
             .await
     }
+    /**
+     * Get top referral sources.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/traffic/popular/referrers` endpoint.
+     *
+     * As opposed to `get_all_top_referrers`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Get the top 10 referrers over the last 14 days.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#get-top-referral-sources>
+     */
+    pub fn get_all_top_referrers_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::ReferrerTraffic>> + Unpin + '_ {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/traffic/popular/referrers",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Get page views.
      *
@@ -8137,6 +12453,29 @@ impl Repos {
             )
             .await
     }
+    /**
+     * Get page views.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/traffic/views` endpoint.
+     *
+     * As opposed to `get_views`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get the total number of views and breakdown per day or week for the last 14 days. Timestamps are aligned to UTC midnight of the beginning of the day or week. Week begins on Monday.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#get-page-views>
+     */
+    pub fn get_views_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+    ) -> ReposGetViewsRequest<'a> {
+        ReposGetViewsRequest {
+            client: self,
+            owner,
+            repo,
+            per: None,
+        }
+    }
     /**
      * Transfer a repository.
      *
@@ -8411,6 +12750,27 @@ impl Repos {
             )
             .await
     }
+    /**
+     * List public repositories.
+     *
+     * This function performs a `GET` to the `/repositories` endpoint.
+     *
+     * As opposed to `list_public`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Lists all public repositories in the order that they were created.
+     *
+     * Note:
+     * - For GitHub Enterprise Server, this endpoint will only list repositories available to all users on the enterprise.
+     * - Pagination is powered exclusively by the `since` parameter. Use the [Link header](https://docs.github.com/rest/overview/resources-in-the-rest-api#link-header) to get the URL for the next page of repositories.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-public-repositories>
+     */
+    pub fn list_public_request(&self) -> ReposListPublicRequest<'_> {
+        ReposListPublicRequest {
+            client: self,
+            since: None,
+        }
+    }
     /**
      * List public repositories.
      *
@@ -8446,6 +12806,34 @@ impl Repos {
             )
             .await
     }
+    /**
+     * List public repositories.
+     *
+     * This function performs a `GET` to the `/repositories` endpoint.
+     *
+     * As opposed to `list_all_public`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Lists all public repositories in the order that they were created.
+     *
+     * Note:
+     * - For GitHub Enterprise Server, this endpoint will only list repositories available to all users on the enterprise.
+     * - Pagination is powered exclusively by the `since` parameter. Use the [Link header](https://docs.github.com/rest/overview/resources-in-the-rest-api#link-header) to get the URL for the next page of repositories.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-public-repositories>
+     */
+    pub fn list_all_public_stream(
+        &self,
+        since: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::MinimalRepository>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if since > 0 {
+            query_args.push(("since".to_string(), since.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/repositories?{}", query_), None);
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * List repositories for the authenticated user.
      *
@@ -8528,6 +12916,33 @@ impl Repos {
             )
             .await
     }
+    /**
+     * List repositories for the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/repos` endpoint.
+     *
+     * As opposed to `list_for_authenticated_user`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Lists repositories that the authenticated user has explicit permission (`:read`, `:write`, or `:admin`) to access.
+     *
+     * The authenticated user has explicit permission to access repositories they own, repositories where they are a collaborator, and repositories that they can access through an organization membership.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-repositories-for-the-authenticated-user>
+     */
+    pub fn list_for_authenticated_user_request(&self) -> ReposListForAuthenticatedUserRequest<'_> {
+        ReposListForAuthenticatedUserRequest {
+            client: self,
+            visibility: None,
+            affiliation: None,
+            type_: None,
+            sort: None,
+            direction: None,
+            per_page: None,
+            page: None,
+            since: None,
+            before: None,
+        }
+    }
     /**
      * List repositories for the authenticated user.
      *
@@ -8585,6 +13000,55 @@ impl Repos {
             )
             .await
     }
+    /**
+     * List repositories for the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/repos` endpoint.
+     *
+     * As opposed to `list_all_for_authenticated_user`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Lists repositories that the authenticated user has explicit permission (`:read`, `:write`, or `:admin`) to access.
+     *
+     * The authenticated user has explicit permission to access repositories they own, repositories where they are a collaborator, and repositories that they can access through an organization membership.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-repositories-for-the-authenticated-user>
+     */
+    pub fn list_all_for_authenticated_user_stream(
+        &self,
+        visibility: crate::types::ReposListVisibility,
+        affiliation: &str,
+        type_: crate::types::ReposListType,
+        sort: crate::types::ReposListOrgSort,
+        direction: crate::types::Order,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+        before: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Repository>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !affiliation.is_empty() {
+            query_args.push(("affiliation".to_string(), affiliation.to_string()));
+        }
+        if let Some(date) = before {
+            query_args.push(("before".to_string(), date.to_rfc3339()));
+        }
+        if !direction.to_string().is_empty() {
+            query_args.push(("direction".to_string(), direction.to_string()));
+        }
+        if let Some(date) = since {
+            query_args.push(("since".to_string(), date.to_rfc3339()));
+        }
+        if !sort.to_string().is_empty() {
+            query_args.push(("sort".to_string(), sort.to_string()));
+        }
+        if !type_.to_string().is_empty() {
+            query_args.push(("type".to_string(), type_.to_string()));
+        }
+        if !visibility.to_string().is_empty() {
+            query_args.push(("visibility".to_string(), visibility.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/user/repos?{}", query_), None);
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Create a repository for the authenticated user.
      *
@@ -8656,6 +13120,26 @@ impl Repos {
             )
             .await
     }
+    /**
+     * List repository invitations for the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/repository_invitations` endpoint.
+     *
+     * As opposed to `list_invitations_for_authenticated_user`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * When authenticating as a user, this endpoint will list all currently open repository invitations for that user.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-repository-invitations-for-the-authenticated-user>
+     */
+    pub fn list_invitations_for_authenticated_user_request(
+        &self,
+    ) -> ReposListInvitationsForAuthenticatedUserRequest<'_> {
+        ReposListInvitationsForAuthenticatedUserRequest {
+            client: self,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List repository invitations for the authenticated user.
      *
@@ -8681,6 +13165,24 @@ impl Repos {
             )
             .await
     }
+    /**
+     * List repository invitations for the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/repository_invitations` endpoint.
+     *
+     * As opposed to `list_all_invitations_for_authenticated_user`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * When authenticating as a user, this endpoint will list all currently open repository invitations for that user.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-repository-invitations-for-the-authenticated-user>
+     */
+    pub fn list_all_invitations_for_authenticated_user_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::RepositoryInvitation>> + Unpin + '_
+    {
+        let url = self.client.url("/user/repository_invitations", None);
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Decline a repository invitation.
      *
@@ -8810,6 +13312,28 @@ impl Repos {
             )
             .await
     }
+    /**
+     * List repositories for a user.
+     *
+     * This function performs a `GET` to the `/users/{username}/repos` endpoint.
+     *
+     * As opposed to `list_for_user`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Lists public repositories for the specified user. Note: For GitHub AE, this endpoint will list internal repositories for the specified user.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-repositories-for-a-user>
+     */
+    pub fn list_for_user_request<'a>(&'a self, username: &'a str) -> ReposListForUserRequest<'a> {
+        ReposListForUserRequest {
+            client: self,
+            username,
+            type_: None,
+            sort: None,
+            direction: None,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List repositories for a user.
      *
@@ -8857,4 +13381,44 @@ impl Repos {
             )
             .await
     }
+    /**
+     * List repositories for a user.
+     *
+     * This function performs a `GET` to the `/users/{username}/repos` endpoint.
+     *
+     * As opposed to `list_all_for_user`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Lists public repositories for the specified user. Note: For GitHub AE, this endpoint will list internal repositories for the specified user.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-repositories-for-a-user>
+     */
+    pub fn list_all_for_user_stream(
+        &self,
+        username: &str,
+        type_: crate::types::ReposListUserType,
+        sort: crate::types::ReposListOrgSort,
+        direction: crate::types::Order,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::MinimalRepository>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !direction.to_string().is_empty() {
+            query_args.push(("direction".to_string(), direction.to_string()));
+        }
+        if !sort.to_string().is_empty() {
+            query_args.push(("sort".to_string(), sort.to_string()));
+        }
+        if !type_.to_string().is_empty() {
+            query_args.push(("type".to_string(), type_.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/users/{}/repos?{}",
+                crate::progenitor_support::encode_path(username),
+                query_
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
 }
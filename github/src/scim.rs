@@ -1,6 +1,76 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Scim::list_provisioned_identities`], set with a builder.
+pub struct ScimListProvisionedIdentitiesRequest<'a> {
+    client: &'a Scim,
+    org: &'a str,
+    start_index: Option<i64>,
+    count: Option<i64>,
+    filter: Option<&'a str>,
+}
+
+impl<'a> ScimListProvisionedIdentitiesRequest<'a> {
+    /// Sets the `startIndex` query parameter.
+    pub fn start_index(mut self, start_index: i64) -> Self {
+        self.start_index = Some(start_index);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Sets the `filter` query parameter.
+    pub fn filter(mut self, filter: &'a str) -> Self {
+        self.filter = Some(filter);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::ScimUserList>> {
+        let Self {
+            client,
+            org,
+            start_index,
+            count,
+            filter,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = count {
+            query_args.push(("count".to_string(), v.to_string()));
+        }
+        if let Some(v) = filter {
+            query_args.push(("filter".to_string(), v.to_string()));
+        }
+        if let Some(v) = start_index {
+            query_args.push(("startIndex".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/scim/v2/organizations/{}/Users?{}",
+                crate::progenitor_support::encode_path(org),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct Scim {
     pub client: Client,
 }
@@ -84,6 +154,44 @@ impl Scim {
             )
             .await
     }
+    /**
+     * List SCIM provisioned identities.
+     *
+     * This function performs a `GET` to the `/scim/v2/organizations/{org}/Users` endpoint.
+     *
+     * As opposed to `list_provisioned_identities`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Retrieves a paginated list of all provisioned organization members, including pending invitations. If you provide the `filter` parameter, the resources for all matching provisions members are returned.
+     *
+     * When a user with a SAML-provisioned external identity leaves (or is removed from) an organization, the account's metadata is immediately removed. However, the returned list of user accounts might not always match the organization or enterprise member list you see on GitHub. This can happen in certain cases where an external identity associated with an organization will not match an organization member:
+     *   - When a user with a SCIM-provisioned external identity is removed from an organization, the account's metadata is preserved to allow the user to re-join the organization in the future.
+     *   - When inviting a user to join an organization, you can expect to see their external identity in the results before they accept the invitation, or if the invitation is cancelled (or never accepted).
+     *   - When a user is invited over SCIM, an external identity is created that matches with the invitee's email address. However, this identity is only linked to a user account when the user accepts the invitation by going through SAML SSO.
+     *
+     * The returned list of external identities can include an entry for a `null` user. These are unlinked SAML identities that are created when a user goes through the following Single Sign-On (SSO) process but does not sign in to their GitHub account after completing SSO:
+     *
+     * 1. The user is granted access by the IdP and is not a member of the GitHub organization.
+     *
+     * 1. The user attempts to access the GitHub organization and initiates the SAML SSO process, and is not currently signed in to their GitHub account.
+     *
+     * 1. After successfully authenticating with the SAML SSO IdP, the `null` external identity entry is created and the user is prompted to sign in to their GitHub account:
+     *    - If the user signs in, their GitHub account is linked to this entry.
+     *    - If the user does not sign in (or does not create a new account when prompted), they are not added to the GitHub organization, and the external identity `null` entry remains in place.
+     *
+     * FROM: <https://docs.github.com/rest/reference/scim#list-scim-provisioned-identities>
+     */
+    pub fn list_provisioned_identities_request<'a>(
+        &'a self,
+        org: &'a str,
+    ) -> ScimListProvisionedIdentitiesRequest<'a> {
+        ScimListProvisionedIdentitiesRequest {
+            client: self,
+            org,
+            start_index: None,
+            count: None,
+            filter: None,
+        }
+    }
     /**
      * Provision and invite a SCIM user.
      *
@@ -1,6 +1,542 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Search::code`], set with a builder.
+pub struct SearchCodeRequest<'a> {
+    client: &'a Search,
+    q: &'a str,
+    sort: Option<crate::types::SearchCodeSort>,
+    order: Option<crate::types::Order>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> SearchCodeRequest<'a> {
+    /// Sets the `sort` query parameter.
+    pub fn sort(mut self, sort: crate::types::SearchCodeSort) -> Self {
+        self.sort = Some(sort);
+        self
+    }
+
+    /// Sets the `order` query parameter.
+    pub fn order(mut self, order: crate::types::Order) -> Self {
+        self.order = Some(order);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::SearchCodeResponse>> {
+        let Self {
+            client,
+            q,
+            sort,
+            order,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = order {
+            query_args.push(("order".to_string(), v.to_string()));
+        }
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        if !q.is_empty() {
+            query_args.push(("q".to_string(), q.to_string()));
+        }
+        if let Some(v) = sort {
+            query_args.push(("sort".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(&format!("/search/code?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Search::commits`], set with a builder.
+pub struct SearchCommitsRequest<'a> {
+    client: &'a Search,
+    q: &'a str,
+    sort: Option<crate::types::SearchCommitsSort>,
+    order: Option<crate::types::Order>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> SearchCommitsRequest<'a> {
+    /// Sets the `sort` query parameter.
+    pub fn sort(mut self, sort: crate::types::SearchCommitsSort) -> Self {
+        self.sort = Some(sort);
+        self
+    }
+
+    /// Sets the `order` query parameter.
+    pub fn order(mut self, order: crate::types::Order) -> Self {
+        self.order = Some(order);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::SearchCommitsResponse>> {
+        let Self {
+            client,
+            q,
+            sort,
+            order,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = order {
+            query_args.push(("order".to_string(), v.to_string()));
+        }
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        if !q.is_empty() {
+            query_args.push(("q".to_string(), q.to_string()));
+        }
+        if let Some(v) = sort {
+            query_args.push(("sort".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/search/commits?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Search::issues_and_pull_requests`], set with a builder.
+pub struct SearchIssuesAndPullRequestsRequest<'a> {
+    client: &'a Search,
+    q: &'a str,
+    sort: Option<crate::types::SearchIssuesPullRequestsSort>,
+    order: Option<crate::types::Order>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> SearchIssuesAndPullRequestsRequest<'a> {
+    /// Sets the `sort` query parameter.
+    pub fn sort(mut self, sort: crate::types::SearchIssuesPullRequestsSort) -> Self {
+        self.sort = Some(sort);
+        self
+    }
+
+    /// Sets the `order` query parameter.
+    pub fn order(mut self, order: crate::types::Order) -> Self {
+        self.order = Some(order);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::SearchIssuesPullRequestsResponse>> {
+        let Self {
+            client,
+            q,
+            sort,
+            order,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = order {
+            query_args.push(("order".to_string(), v.to_string()));
+        }
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        if !q.is_empty() {
+            query_args.push(("q".to_string(), q.to_string()));
+        }
+        if let Some(v) = sort {
+            query_args.push(("sort".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/search/issues?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Search::labels`], set with a builder.
+pub struct SearchLabelsRequest<'a> {
+    client: &'a Search,
+    repository_id: i64,
+    q: &'a str,
+    sort: Option<crate::types::Sort>,
+    order: Option<crate::types::Order>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> SearchLabelsRequest<'a> {
+    /// Sets the `sort` query parameter.
+    pub fn sort(mut self, sort: crate::types::Sort) -> Self {
+        self.sort = Some(sort);
+        self
+    }
+
+    /// Sets the `order` query parameter.
+    pub fn order(mut self, order: crate::types::Order) -> Self {
+        self.order = Some(order);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::SearchLabelsResponse>> {
+        let Self {
+            client,
+            repository_id,
+            q,
+            sort,
+            order,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = order {
+            query_args.push(("order".to_string(), v.to_string()));
+        }
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        if !q.is_empty() {
+            query_args.push(("q".to_string(), q.to_string()));
+        }
+        if repository_id > 0 {
+            query_args.push(("repository_id".to_string(), repository_id.to_string()));
+        }
+        if let Some(v) = sort {
+            query_args.push(("sort".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/search/labels?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Search::repos`], set with a builder.
+pub struct SearchReposRequest<'a> {
+    client: &'a Search,
+    q: &'a str,
+    sort: Option<crate::types::SearchReposSort>,
+    order: Option<crate::types::Order>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> SearchReposRequest<'a> {
+    /// Sets the `sort` query parameter.
+    pub fn sort(mut self, sort: crate::types::SearchReposSort) -> Self {
+        self.sort = Some(sort);
+        self
+    }
+
+    /// Sets the `order` query parameter.
+    pub fn order(mut self, order: crate::types::Order) -> Self {
+        self.order = Some(order);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::SearchReposResponse>> {
+        let Self {
+            client,
+            q,
+            sort,
+            order,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = order {
+            query_args.push(("order".to_string(), v.to_string()));
+        }
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        if !q.is_empty() {
+            query_args.push(("q".to_string(), q.to_string()));
+        }
+        if let Some(v) = sort {
+            query_args.push(("sort".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/search/repositories?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Search::topics`], set with a builder.
+pub struct SearchTopicsRequest<'a> {
+    client: &'a Search,
+    q: &'a str,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> SearchTopicsRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::SearchTopicsResponse>> {
+        let Self {
+            client,
+            q,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        if !q.is_empty() {
+            query_args.push(("q".to_string(), q.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/search/topics?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Search::users`], set with a builder.
+pub struct SearchUsersRequest<'a> {
+    client: &'a Search,
+    q: &'a str,
+    sort: Option<crate::types::SearchUsersSort>,
+    order: Option<crate::types::Order>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> SearchUsersRequest<'a> {
+    /// Sets the `sort` query parameter.
+    pub fn sort(mut self, sort: crate::types::SearchUsersSort) -> Self {
+        self.sort = Some(sort);
+        self
+    }
+
+    /// Sets the `order` query parameter.
+    pub fn order(mut self, order: crate::types::Order) -> Self {
+        self.order = Some(order);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::SearchUsersResponse>> {
+        let Self {
+            client,
+            q,
+            sort,
+            order,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = order {
+            query_args.push(("order".to_string(), v.to_string()));
+        }
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        if !q.is_empty() {
+            query_args.push(("q".to_string(), q.to_string()));
+        }
+        if let Some(v) = sort {
+            query_args.push(("sort".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/search/users?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct Search {
     pub client: Client,
 }
@@ -81,6 +617,44 @@ impl Search {
             )
             .await
     }
+    /**
+     * Search code.
+     *
+     * This function performs a `GET` to the `/search/code` endpoint.
+     *
+     * As opposed to `code`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Searches for query terms inside of a file. This method returns up to 100 results [per page](https://docs.github.com/rest/overview/resources-in-the-rest-api#pagination).
+     *
+     * When searching for code, you can get text match metadata for the file **content** and file **path** fields when you pass the `text-match` media type. For more details about how to receive highlighted search results, see [Text match metadata](https://docs.github.com/rest/reference/search#text-match-metadata).
+     *
+     * For example, if you want to find the definition of the `addClass` function inside [jQuery](https://github.com/jquery/jquery) repository, your query would look something like this:
+     *
+     * `q=addClass+in:file+language:js+repo:jquery/jquery`
+     *
+     * This query searches for the keyword `addClass` within a file's contents. The query limits the search to files where the language is JavaScript in the `jquery/jquery` repository.
+     *
+     * #### Considerations for code search
+     *
+     * Due to the complexity of searching code, there are a few restrictions on how searches are performed:
+     *
+     * *   Only the _default branch_ is considered. In most cases, this will be the `master` branch.
+     * *   Only files smaller than 384 KB are searchable.
+     * *   You must always include at least one search term when searching source code. For example, searching for [`language:go`](https://github.com/search?utf8=%E2%9C%93&q=language%3Ago&type=Code) is not valid, while [`amazing
+     * language:go`](https://github.com/search?utf8=%E2%9C%93&q=amazing+language%3Ago&type=Code) is.
+     *
+     * FROM: <https://docs.github.com/rest/reference/search#search-code>
+     */
+    pub fn code_request<'a>(&'a self, q: &'a str) -> SearchCodeRequest<'a> {
+        SearchCodeRequest {
+            client: self,
+            q,
+            sort: None,
+            order: None,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * Search commits.
      *
@@ -143,6 +717,34 @@ impl Search {
             )
             .await
     }
+    /**
+     * Search commits.
+     *
+     * This function performs a `GET` to the `/search/commits` endpoint.
+     *
+     * As opposed to `commits`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Find commits via various criteria on the default branch (usually `master`). This method returns up to 100 results [per page](https://docs.github.com/rest/overview/resources-in-the-rest-api#pagination).
+     *
+     * When searching for commits, you can get text match metadata for the **message** field when you provide the `text-match` media type. For more details about how to receive highlighted search results, see [Text match
+     * metadata](https://docs.github.com/rest/reference/search#text-match-metadata).
+     *
+     * For example, if you want to find commits related to CSS in the [octocat/Spoon-Knife](https://github.com/octocat/Spoon-Knife) repository. Your query would look something like this:
+     *
+     * `q=repo:octocat/Spoon-Knife+css`
+     *
+     * FROM: <https://docs.github.com/rest/reference/search#search-commits>
+     */
+    pub fn commits_request<'a>(&'a self, q: &'a str) -> SearchCommitsRequest<'a> {
+        SearchCommitsRequest {
+            client: self,
+            q,
+            sort: None,
+            order: None,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * Search issues and pull requests.
      *
@@ -207,6 +809,41 @@ impl Search {
             )
             .await
     }
+    /**
+     * Search issues and pull requests.
+     *
+     * This function performs a `GET` to the `/search/issues` endpoint.
+     *
+     * As opposed to `issues_and_pull_requests`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Find issues by state and keyword. This method returns up to 100 results [per page](https://docs.github.com/rest/overview/resources-in-the-rest-api#pagination).
+     *
+     * When searching for issues, you can get text match metadata for the issue **title**, issue **body**, and issue **comment body** fields when you pass the `text-match` media type. For more details about how to receive highlighted
+     * search results, see [Text match metadata](https://docs.github.com/rest/reference/search#text-match-metadata).
+     *
+     * For example, if you want to find the oldest unresolved Python bugs on Windows. Your query might look something like this.
+     *
+     * `q=windows+label:bug+language:python+state:open&sort=created&order=asc`
+     *
+     * This query searches for the keyword `windows`, within any open issue that is labeled as `bug`. The search runs across repositories whose primary language is Python. The results are sorted by creation date in ascending order, which means the oldest issues appear first in the search results.
+     *
+     * **Note:** For [user-to-server](https://docs.github.com/developers/apps/identifying-and-authorizing-users-for-github-apps#user-to-server-requests) GitHub App requests, you can't retrieve a combination of issues and pull requests in a single query. Requests that don't include the `is:issue` or `is:pull-request` qualifier will receive an HTTP `422 Unprocessable Entity` response. To get results for both issues and pull requests, you must send separate queries for issues and pull requests. For more information about the `is` qualifier, see "[Searching only issues or pull requests](https://docs.github.com/github/searching-for-information-on-github/searching-issues-and-pull-requests#search-only-issues-or-pull-requests)."
+     *
+     * FROM: <https://docs.github.com/rest/reference/search#search-issues-and-pull-requests>
+     */
+    pub fn issues_and_pull_requests_request<'a>(
+        &'a self,
+        q: &'a str,
+    ) -> SearchIssuesAndPullRequestsRequest<'a> {
+        SearchIssuesAndPullRequestsRequest {
+            client: self,
+            q,
+            sort: None,
+            order: None,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * Search labels.
      *
@@ -273,6 +910,36 @@ impl Search {
             )
             .await
     }
+    /**
+     * Search labels.
+     *
+     * This function performs a `GET` to the `/search/labels` endpoint.
+     *
+     * As opposed to `labels`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Find labels in a repository with names or descriptions that match search keywords. Returns up to 100 results [per page](https://docs.github.com/rest/overview/resources-in-the-rest-api#pagination).
+     *
+     * When searching for labels, you can get text match metadata for the label **name** and **description** fields when you pass the `text-match` media type. For more details about how to receive highlighted search results, see [Text match metadata](https://docs.github.com/rest/reference/search#text-match-metadata).
+     *
+     * For example, if you want to find labels in the `linguist` repository that match `bug`, `defect`, or `enhancement`. Your query might look like this:
+     *
+     * `q=bug+defect+enhancement&repository_id=64778136`
+     *
+     * The labels that best match the query appear first in the search results.
+     *
+     * FROM: <https://docs.github.com/rest/reference/search#search-labels>
+     */
+    pub fn labels_request<'a>(&'a self, repository_id: i64, q: &'a str) -> SearchLabelsRequest<'a> {
+        SearchLabelsRequest {
+            client: self,
+            repository_id,
+            q,
+            sort: None,
+            order: None,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * Search repositories.
      *
@@ -340,6 +1007,39 @@ impl Search {
             )
             .await
     }
+    /**
+     * Search repositories.
+     *
+     * This function performs a `GET` to the `/search/repositories` endpoint.
+     *
+     * As opposed to `repos`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Find repositories via various criteria. This method returns up to 100 results [per page](https://docs.github.com/rest/overview/resources-in-the-rest-api#pagination).
+     *
+     * When searching for repositories, you can get text match metadata for the **name** and **description** fields when you pass the `text-match` media type. For more details about how to receive highlighted search results, see [Text match metadata](https://docs.github.com/rest/reference/search#text-match-metadata).
+     *
+     * For example, if you want to search for popular Tetris repositories written in assembly code, your query might look like this:
+     *
+     * `q=tetris+language:assembly&sort=stars&order=desc`
+     *
+     * This query searches for repositories with the word `tetris` in the name, the description, or the README. The results are limited to repositories where the primary language is assembly. The results are sorted by stars in descending order, so that the most popular repositories appear first in the search results.
+     *
+     * When you include the `mercy` preview header, you can also search for multiple topics by adding more `topic:` instances. For example, your query might look like this:
+     *
+     * `q=topic:ruby+topic:rails`
+     *
+     * FROM: <https://docs.github.com/rest/reference/search#search-repositories>
+     */
+    pub fn repos_request<'a>(&'a self, q: &'a str) -> SearchReposRequest<'a> {
+        SearchReposRequest {
+            client: self,
+            q,
+            sort: None,
+            order: None,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * Search topics.
      *
@@ -391,6 +1091,33 @@ impl Search {
             )
             .await
     }
+    /**
+     * Search topics.
+     *
+     * This function performs a `GET` to the `/search/topics` endpoint.
+     *
+     * As opposed to `topics`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Find topics via various criteria. Results are sorted by best match. This method returns up to 100 results [per page](https://docs.github.com/rest/overview/resources-in-the-rest-api#pagination). See "[Searching topics](https://help.github.com/articles/searching-topics/)" for a detailed list of qualifiers.
+     *
+     * When searching for topics, you can get text match metadata for the topic's **short\_description**, **description**, **name**, or **display\_name** field when you pass the `text-match` media type. For more details about how to receive highlighted search results, see [Text match metadata](https://docs.github.com/rest/reference/search#text-match-metadata).
+     *
+     * For example, if you want to search for topics related to Ruby that are featured on https://github.com/topics. Your query might look like this:
+     *
+     * `q=ruby+is:featured`
+     *
+     * This query searches for topics with the keyword `ruby` and limits the results to find only topics that are featured. The topics that are the best match for the query appear first in the search results.
+     *
+     * FROM: <https://docs.github.com/rest/reference/search#search-topics>
+     */
+    pub fn topics_request<'a>(&'a self, q: &'a str) -> SearchTopicsRequest<'a> {
+        SearchTopicsRequest {
+            client: self,
+            q,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * Search users.
      *
@@ -452,4 +1179,33 @@ impl Search {
             )
             .await
     }
+    /**
+     * Search users.
+     *
+     * This function performs a `GET` to the `/search/users` endpoint.
+     *
+     * As opposed to `users`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Find users via various criteria. This method returns up to 100 results [per page](https://docs.github.com/rest/overview/resources-in-the-rest-api#pagination).
+     *
+     * When searching for users, you can get text match metadata for the issue **login**, **email**, and **name** fields when you pass the `text-match` media type. For more details about highlighting search results, see [Text match metadata](https://docs.github.com/rest/reference/search#text-match-metadata). For more details about how to receive highlighted search results, see [Text match metadata](https://docs.github.com/rest/reference/search#text-match-metadata).
+     *
+     * For example, if you're looking for a list of popular users, you might try this query:
+     *
+     * `q=tom+repos:%3E42+followers:%3E1000`
+     *
+     * This query searches for users with the name `tom`. The results are restricted to users with more than 42 repositories and over 1,000 followers.
+     *
+     * FROM: <https://docs.github.com/rest/reference/search#search-users>
+     */
+    pub fn users_request<'a>(&'a self, q: &'a str) -> SearchUsersRequest<'a> {
+        SearchUsersRequest {
+            client: self,
+            q,
+            sort: None,
+            order: None,
+            per_page: None,
+            page: None,
+        }
+    }
 }
@@ -1,6 +1,92 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`SecretScanning::list_alerts_for_repo`], set with a builder.
+pub struct SecretScanningListAlertsForRepoRequest<'a> {
+    client: &'a SecretScanning,
+    owner: &'a str,
+    repo: &'a str,
+    state: Option<crate::types::SecretScanningAlertState>,
+    secret_type: Option<&'a str>,
+    page: Option<i64>,
+    per_page: Option<i64>,
+}
+
+impl<'a> SecretScanningListAlertsForRepoRequest<'a> {
+    /// Sets the `state` query parameter.
+    pub fn state(mut self, state: crate::types::SecretScanningAlertState) -> Self {
+        self.state = Some(state);
+        self
+    }
+
+    /// Sets the `secret_type` query parameter.
+    pub fn secret_type(mut self, secret_type: &'a str) -> Self {
+        self.secret_type = Some(secret_type);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::SecretScanningAlert>>> {
+        let Self {
+            client,
+            owner,
+            repo,
+            state,
+            secret_type,
+            page,
+            per_page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        if let Some(v) = secret_type {
+            query_args.push(("secret_type".to_string(), v.to_string()));
+        }
+        if let Some(v) = state {
+            query_args.push(("state".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/repos/{}/{}/secret-scanning/alerts?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct SecretScanning {
     pub client: Client,
 }
@@ -73,6 +159,34 @@ impl SecretScanning {
             )
             .await
     }
+    /**
+     * List secret scanning alerts for a repository.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/secret-scanning/alerts` endpoint.
+     *
+     * As opposed to `list_alerts_for_repo`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Lists all secret scanning alerts for a private repository, from newest to oldest. To use this endpoint, you must be an administrator for the repository or organization, and you must use an access token with the `repo` scope or `security_events` scope.
+     *
+     * GitHub Apps must have the `secret_scanning_alerts` read permission to use this endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/secret-scanning#list-secret-scanning-alerts-for-a-repository>
+     */
+    pub fn list_alerts_for_repo_request<'a>(
+        &'a self,
+        owner: &'a str,
+        repo: &'a str,
+    ) -> SecretScanningListAlertsForRepoRequest<'a> {
+        SecretScanningListAlertsForRepoRequest {
+            client: self,
+            owner,
+            repo,
+            state: None,
+            secret_type: None,
+            page: None,
+            per_page: None,
+        }
+    }
     /**
      * List secret scanning alerts for a repository.
      *
@@ -120,6 +234,46 @@ impl SecretScanning {
             )
             .await
     }
+    /**
+     * List secret scanning alerts for a repository.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/secret-scanning/alerts` endpoint.
+     *
+     * As opposed to `list_all_alerts_for_repo`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Lists all secret scanning alerts for a private repository, from newest to oldest. To use this endpoint, you must be an administrator for the repository or organization, and you must use an access token with the `repo` scope or `security_events` scope.
+     *
+     * GitHub Apps must have the `secret_scanning_alerts` read permission to use this endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/secret-scanning#list-secret-scanning-alerts-for-a-repository>
+     */
+    pub fn list_all_alerts_for_repo_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        state: crate::types::SecretScanningAlertState,
+        secret_type: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::SecretScanningAlert>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !secret_type.is_empty() {
+            query_args.push(("secret_type".to_string(), secret_type.to_string()));
+        }
+        if !state.to_string().is_empty() {
+            query_args.push(("state".to_string(), state.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/secret-scanning/alerts?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                query_
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Get a secret scanning alert.
      *
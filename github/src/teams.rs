@@ -1,6 +1,1112 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Teams::list_idp_groups_for_org`], set with a builder.
+pub struct TeamsListIdpGroupsForOrgRequest<'a> {
+    client: &'a Teams,
+    org: &'a str,
+    per_page: Option<i64>,
+    page: Option<&'a str>,
+}
+
+impl<'a> TeamsListIdpGroupsForOrgRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: &'a str) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::GroupMapping>> {
+        let Self {
+            client,
+            org,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/orgs/{}/team-sync/groups?{}",
+                crate::progenitor_support::encode_path(org),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Teams::list`], set with a builder.
+pub struct TeamsListRequest<'a> {
+    client: &'a Teams,
+    org: &'a str,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> TeamsListRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Team>>> {
+        let Self {
+            client,
+            org,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/orgs/{}/teams?{}",
+                crate::progenitor_support::encode_path(org),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Teams::list_discussions_in_org`], set with a builder.
+pub struct TeamsListDiscussionsInOrgRequest<'a> {
+    client: &'a Teams,
+    org: &'a str,
+    team_slug: &'a str,
+    direction: Option<crate::types::Order>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+    pinned: Option<&'a str>,
+}
+
+impl<'a> TeamsListDiscussionsInOrgRequest<'a> {
+    /// Sets the `direction` query parameter.
+    pub fn direction(mut self, direction: crate::types::Order) -> Self {
+        self.direction = Some(direction);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sets the `pinned` query parameter.
+    pub fn pinned(mut self, pinned: &'a str) -> Self {
+        self.pinned = Some(pinned);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::TeamDiscussion>>> {
+        let Self {
+            client,
+            org,
+            team_slug,
+            direction,
+            per_page,
+            page,
+            pinned,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = direction {
+            query_args.push(("direction".to_string(), v.to_string()));
+        }
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        if let Some(v) = pinned {
+            query_args.push(("pinned".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/orgs/{}/teams/{}/discussions?{}",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(team_slug),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Teams::list_discussion_comments_in_org`], set with a builder.
+pub struct TeamsListDiscussionCommentsInOrgRequest<'a> {
+    client: &'a Teams,
+    org: &'a str,
+    team_slug: &'a str,
+    discussion_number: i64,
+    direction: Option<crate::types::Order>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> TeamsListDiscussionCommentsInOrgRequest<'a> {
+    /// Sets the `direction` query parameter.
+    pub fn direction(mut self, direction: crate::types::Order) -> Self {
+        self.direction = Some(direction);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::TeamDiscussionComment>>> {
+        let Self {
+            client,
+            org,
+            team_slug,
+            discussion_number,
+            direction,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = direction {
+            query_args.push(("direction".to_string(), v.to_string()));
+        }
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/orgs/{}/teams/{}/discussions/{}/comments?{}",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(team_slug),
+                crate::progenitor_support::encode_path(&discussion_number.to_string()),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Teams::list_pending_invitations_in_org`], set with a builder.
+pub struct TeamsListPendingInvitationsInOrgRequest<'a> {
+    client: &'a Teams,
+    org: &'a str,
+    team_slug: &'a str,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> TeamsListPendingInvitationsInOrgRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::OrganizationInvitation>>> {
+        let Self {
+            client,
+            org,
+            team_slug,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/orgs/{}/teams/{}/invitations?{}",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(team_slug),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Teams::list_members_in_org`], set with a builder.
+pub struct TeamsListMembersInOrgRequest<'a> {
+    client: &'a Teams,
+    org: &'a str,
+    team_slug: &'a str,
+    role: Option<crate::types::TeamsListMembersInOrgRole>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> TeamsListMembersInOrgRequest<'a> {
+    /// Sets the `role` query parameter.
+    pub fn role(mut self, role: crate::types::TeamsListMembersInOrgRole) -> Self {
+        self.role = Some(role);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::SimpleUser>>> {
+        let Self {
+            client,
+            org,
+            team_slug,
+            role,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        if let Some(v) = role {
+            query_args.push(("role".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/orgs/{}/teams/{}/members?{}",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(team_slug),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Teams::list_projects_in_org`], set with a builder.
+pub struct TeamsListProjectsInOrgRequest<'a> {
+    client: &'a Teams,
+    org: &'a str,
+    team_slug: &'a str,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> TeamsListProjectsInOrgRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::TeamProject>>> {
+        let Self {
+            client,
+            org,
+            team_slug,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/orgs/{}/teams/{}/projects?{}",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(team_slug),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Teams::list_repos_in_org`], set with a builder.
+pub struct TeamsListReposInOrgRequest<'a> {
+    client: &'a Teams,
+    org: &'a str,
+    team_slug: &'a str,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> TeamsListReposInOrgRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::MinimalRepository>>> {
+        let Self {
+            client,
+            org,
+            team_slug,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/orgs/{}/teams/{}/repos?{}",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(team_slug),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Teams::list_child_in_org`], set with a builder.
+pub struct TeamsListChildInOrgRequest<'a> {
+    client: &'a Teams,
+    org: &'a str,
+    team_slug: &'a str,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> TeamsListChildInOrgRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Team>>> {
+        let Self {
+            client,
+            org,
+            team_slug,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/orgs/{}/teams/{}/teams?{}",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(team_slug),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Teams::list_discussions_legacy`], set with a builder.
+pub struct TeamsListDiscussionsLegacyRequest<'a> {
+    client: &'a Teams,
+    team_id: i64,
+    direction: Option<crate::types::Order>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> TeamsListDiscussionsLegacyRequest<'a> {
+    /// Sets the `direction` query parameter.
+    pub fn direction(mut self, direction: crate::types::Order) -> Self {
+        self.direction = Some(direction);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::TeamDiscussion>>> {
+        let Self {
+            client,
+            team_id,
+            direction,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = direction {
+            query_args.push(("direction".to_string(), v.to_string()));
+        }
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/teams/{}/discussions?{}",
+                crate::progenitor_support::encode_path(&team_id.to_string()),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Teams::list_discussion_comments_legacy`], set with a builder.
+pub struct TeamsListDiscussionCommentsLegacyRequest<'a> {
+    client: &'a Teams,
+    team_id: i64,
+    discussion_number: i64,
+    direction: Option<crate::types::Order>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> TeamsListDiscussionCommentsLegacyRequest<'a> {
+    /// Sets the `direction` query parameter.
+    pub fn direction(mut self, direction: crate::types::Order) -> Self {
+        self.direction = Some(direction);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::TeamDiscussionComment>>> {
+        let Self {
+            client,
+            team_id,
+            discussion_number,
+            direction,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = direction {
+            query_args.push(("direction".to_string(), v.to_string()));
+        }
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/teams/{}/discussions/{}/comments?{}",
+                crate::progenitor_support::encode_path(&team_id.to_string()),
+                crate::progenitor_support::encode_path(&discussion_number.to_string()),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Teams::list_pending_invitations_legacy`], set with a builder.
+pub struct TeamsListPendingInvitationsLegacyRequest<'a> {
+    client: &'a Teams,
+    team_id: i64,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> TeamsListPendingInvitationsLegacyRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::OrganizationInvitation>>> {
+        let Self {
+            client,
+            team_id,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/teams/{}/invitations?{}",
+                crate::progenitor_support::encode_path(&team_id.to_string()),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Teams::list_members_legacy`], set with a builder.
+pub struct TeamsListMembersLegacyRequest<'a> {
+    client: &'a Teams,
+    team_id: i64,
+    role: Option<crate::types::TeamsListMembersInOrgRole>,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> TeamsListMembersLegacyRequest<'a> {
+    /// Sets the `role` query parameter.
+    pub fn role(mut self, role: crate::types::TeamsListMembersInOrgRole) -> Self {
+        self.role = Some(role);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::SimpleUser>>> {
+        let Self {
+            client,
+            team_id,
+            role,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        if let Some(v) = role {
+            query_args.push(("role".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/teams/{}/members?{}",
+                crate::progenitor_support::encode_path(&team_id.to_string()),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Teams::list_projects_legacy`], set with a builder.
+pub struct TeamsListProjectsLegacyRequest<'a> {
+    client: &'a Teams,
+    team_id: i64,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> TeamsListProjectsLegacyRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::TeamProject>>> {
+        let Self {
+            client,
+            team_id,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/teams/{}/projects?{}",
+                crate::progenitor_support::encode_path(&team_id.to_string()),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Teams::list_repos_legacy`], set with a builder.
+pub struct TeamsListReposLegacyRequest<'a> {
+    client: &'a Teams,
+    team_id: i64,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> TeamsListReposLegacyRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::MinimalRepository>>> {
+        let Self {
+            client,
+            team_id,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/teams/{}/repos?{}",
+                crate::progenitor_support::encode_path(&team_id.to_string()),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Teams::list_child_legacy`], set with a builder.
+pub struct TeamsListChildLegacyRequest<'a> {
+    client: &'a Teams,
+    team_id: i64,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> TeamsListChildLegacyRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Team>>> {
+        let Self {
+            client,
+            team_id,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/teams/{}/teams?{}",
+                crate::progenitor_support::encode_path(&team_id.to_string()),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Teams::list_for_authenticated_user`], set with a builder.
+pub struct TeamsListForAuthenticatedUserRequest<'a> {
+    client: &'a Teams,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> TeamsListForAuthenticatedUserRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::FullTeam>>> {
+        let Self {
+            client,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(&format!("/user/teams?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct Teams {
     pub client: Client,
 }
@@ -62,6 +1168,32 @@ impl Teams {
             )
             .await
     }
+    /**
+     * List IdP groups for an organization.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/team-sync/groups` endpoint.
+     *
+     * As opposed to `list_idp_groups_for_org`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Team synchronization is available for organizations using GitHub Enterprise Cloud. For more information, see [GitHub's products](https://help.github.com/github/getting-started-with-github/githubs-products) in the GitHub Help documentation.
+     *
+     * List IdP groups available in an organization. You can limit your page results using the `per_page` parameter. GitHub generates a url-encoded `page` token using a cursor value for where the next page begins. For more information on cursor pagination, see "[Offset and Cursor Pagination explained](https://dev.to/jackmarchant/offset-and-cursor-pagination-explained-b89)."
+     *
+     * The `per_page` parameter provides pagination for a list of IdP groups the authenticated user can access in an organization. For example, if the user `octocat` wants to see two groups per page in `octo-org` via cURL, it would look like this:
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#list-idp-groups-for-an-organization>
+     */
+    pub fn list_idp_groups_for_org_request<'a>(
+        &'a self,
+        org: &'a str,
+    ) -> TeamsListIdpGroupsForOrgRequest<'a> {
+        TeamsListIdpGroupsForOrgRequest {
+            client: self,
+            org,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List teams.
      *
@@ -109,6 +1241,25 @@ impl Teams {
             )
             .await
     }
+    /**
+     * List teams.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/teams` endpoint.
+     *
+     * As opposed to `list`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Lists all teams in an organization that are visible to the authenticated user.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#list-teams>
+     */
+    pub fn list_request<'a>(&'a self, org: &'a str) -> TeamsListRequest<'a> {
+        TeamsListRequest {
+            client: self,
+            org,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List teams.
      *
@@ -141,6 +1292,30 @@ impl Teams {
             )
             .await
     }
+    /**
+     * List teams.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/teams` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Lists all teams in an organization that are visible to the authenticated user.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#list-teams>
+     */
+    pub fn list_all_stream(
+        &self,
+        org: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Team>> + Unpin + '_ {
+        let url = self.client.url(
+            &format!(
+                "/orgs/{}/teams",
+                crate::progenitor_support::encode_path(org),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Create a team.
      *
@@ -362,6 +1537,34 @@ impl Teams {
             )
             .await
     }
+    /**
+     * List discussions.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/teams/{team_slug}/discussions` endpoint.
+     *
+     * As opposed to `list_discussions_in_org`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * List all discussions on a team's page. OAuth access tokens require the `read:discussion` [scope](https://docs.github.com/apps/building-oauth-apps/understanding-scopes-for-oauth-apps/).
+     *
+     * **Note:** You can also specify a team by `org_id` and `team_id` using the route `GET /organizations/{org_id}/team/{team_id}/discussions`.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#list-discussions>
+     */
+    pub fn list_discussions_in_org_request<'a>(
+        &'a self,
+        org: &'a str,
+        team_slug: &'a str,
+    ) -> TeamsListDiscussionsInOrgRequest<'a> {
+        TeamsListDiscussionsInOrgRequest {
+            client: self,
+            org,
+            team_slug,
+            direction: None,
+            per_page: None,
+            page: None,
+            pinned: None,
+        }
+    }
     /**
      * List discussions.
      *
@@ -409,6 +1612,45 @@ impl Teams {
             )
             .await
     }
+    /**
+     * List discussions.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/teams/{team_slug}/discussions` endpoint.
+     *
+     * As opposed to `list_all_discussions_in_org`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * List all discussions on a team's page. OAuth access tokens require the `read:discussion` [scope](https://docs.github.com/apps/building-oauth-apps/understanding-scopes-for-oauth-apps/).
+     *
+     * **Note:** You can also specify a team by `org_id` and `team_id` using the route `GET /organizations/{org_id}/team/{team_id}/discussions`.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#list-discussions>
+     */
+    pub fn list_all_discussions_in_org_stream(
+        &self,
+        org: &str,
+        team_slug: &str,
+        direction: crate::types::Order,
+        pinned: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::TeamDiscussion>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !direction.to_string().is_empty() {
+            query_args.push(("direction".to_string(), direction.to_string()));
+        }
+        if !pinned.is_empty() {
+            query_args.push(("pinned".to_string(), pinned.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/orgs/{}/teams/{}/discussions?{}",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(team_slug),
+                query_
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Create a discussion.
      *
@@ -631,7 +1873,81 @@ impl Teams {
             None,
         );
         self.client
-            .get(
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+    /**
+     * List discussion comments.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}/comments` endpoint.
+     *
+     * As opposed to `list_discussion_comments_in_org`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * List all comments on a team discussion. OAuth access tokens require the `read:discussion` [scope](https://docs.github.com/apps/building-oauth-apps/understanding-scopes-for-oauth-apps/).
+     *
+     * **Note:** You can also specify a team by `org_id` and `team_id` using the route `GET /organizations/{org_id}/team/{team_id}/discussions/{discussion_number}/comments`.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#list-discussion-comments>
+     */
+    pub fn list_discussion_comments_in_org_request<'a>(
+        &'a self,
+        org: &'a str,
+        team_slug: &'a str,
+        discussion_number: i64,
+    ) -> TeamsListDiscussionCommentsInOrgRequest<'a> {
+        TeamsListDiscussionCommentsInOrgRequest {
+            client: self,
+            org,
+            team_slug,
+            discussion_number,
+            direction: None,
+            per_page: None,
+            page: None,
+        }
+    }
+    /**
+     * List discussion comments.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}/comments` endpoint.
+     *
+     * As opposed to `list_discussion_comments_in_org`, this function returns all the pages of the request at once.
+     *
+     * List all comments on a team discussion. OAuth access tokens require the `read:discussion` [scope](https://docs.github.com/apps/building-oauth-apps/understanding-scopes-for-oauth-apps/).
+     *
+     * **Note:** You can also specify a team by `org_id` and `team_id` using the route `GET /organizations/{org_id}/team/{team_id}/discussions/{discussion_number}/comments`.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#list-discussion-comments>
+     */
+    pub async fn list_all_discussion_comments_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+        discussion_number: i64,
+        direction: crate::types::Order,
+    ) -> ClientResult<crate::Response<Vec<crate::types::TeamDiscussionComment>>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !direction.to_string().is_empty() {
+            query_args.push(("direction".to_string(), direction.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/orgs/{}/teams/{}/discussions/{}/comments?{}",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(team_slug),
+                crate::progenitor_support::encode_path(&discussion_number.to_string()),
+                query_
+            ),
+            None,
+        );
+        self.client
+            .get_all_pages(
                 &url,
                 crate::Message {
                     body: None,
@@ -645,7 +1961,7 @@ impl Teams {
      *
      * This function performs a `GET` to the `/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}/comments` endpoint.
      *
-     * As opposed to `list_discussion_comments_in_org`, this function returns all the pages of the request at once.
+     * As opposed to `list_all_discussion_comments_in_org`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
      *
      * List all comments on a team discussion. OAuth access tokens require the `read:discussion` [scope](https://docs.github.com/apps/building-oauth-apps/understanding-scopes-for-oauth-apps/).
      *
@@ -653,13 +1969,14 @@ impl Teams {
      *
      * FROM: <https://docs.github.com/rest/reference/teams#list-discussion-comments>
      */
-    pub async fn list_all_discussion_comments_in_org(
+    pub fn list_all_discussion_comments_in_org_stream(
         &self,
         org: &str,
         team_slug: &str,
         discussion_number: i64,
         direction: crate::types::Order,
-    ) -> ClientResult<crate::Response<Vec<crate::types::TeamDiscussionComment>>> {
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::TeamDiscussionComment>> + Unpin + '_
+    {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !direction.to_string().is_empty() {
             query_args.push(("direction".to_string(), direction.to_string()));
@@ -675,15 +1992,7 @@ impl Teams {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(url)
     }
     /**
      * Create a discussion comment.
@@ -918,6 +2227,32 @@ impl Teams {
             )
             .await
     }
+    /**
+     * List pending team invitations.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/teams/{team_slug}/invitations` endpoint.
+     *
+     * As opposed to `list_pending_invitations_in_org`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * The return hash contains a `role` field which refers to the Organization Invitation role and will be one of the following values: `direct_member`, `admin`, `billing_manager`, `hiring_manager`, or `reinstate`. If the invitee is not a GitHub member, the `login` field in the return hash will be `null`.
+     *
+     * **Note:** You can also specify a team by `org_id` and `team_id` using the route `GET /organizations/{org_id}/team/{team_id}/invitations`.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#list-pending-team-invitations>
+     */
+    pub fn list_pending_invitations_in_org_request<'a>(
+        &'a self,
+        org: &'a str,
+        team_slug: &'a str,
+    ) -> TeamsListPendingInvitationsInOrgRequest<'a> {
+        TeamsListPendingInvitationsInOrgRequest {
+            client: self,
+            org,
+            team_slug,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List pending team invitations.
      *
@@ -954,6 +2289,35 @@ impl Teams {
             )
             .await
     }
+    /**
+     * List pending team invitations.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/teams/{team_slug}/invitations` endpoint.
+     *
+     * As opposed to `list_all_pending_invitations_in_org`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * The return hash contains a `role` field which refers to the Organization Invitation role and will be one of the following values: `direct_member`, `admin`, `billing_manager`, `hiring_manager`, or `reinstate`. If the invitee is not a GitHub member, the `login` field in the return hash will be `null`.
+     *
+     * **Note:** You can also specify a team by `org_id` and `team_id` using the route `GET /organizations/{org_id}/team/{team_id}/invitations`.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#list-pending-team-invitations>
+     */
+    pub fn list_all_pending_invitations_in_org_stream(
+        &self,
+        org: &str,
+        team_slug: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::OrganizationInvitation>> + Unpin + '_
+    {
+        let url = self.client.url(
+            &format!(
+                "/orgs/{}/teams/{}/invitations",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(team_slug),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * List team members.
      *
@@ -1014,6 +2378,33 @@ impl Teams {
             )
             .await
     }
+    /**
+     * List team members.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/teams/{team_slug}/members` endpoint.
+     *
+     * As opposed to `list_members_in_org`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Team members will include the members of child teams.
+     *
+     * To list members in a team, the team must be visible to the authenticated user.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#list-team-members>
+     */
+    pub fn list_members_in_org_request<'a>(
+        &'a self,
+        org: &'a str,
+        team_slug: &'a str,
+    ) -> TeamsListMembersInOrgRequest<'a> {
+        TeamsListMembersInOrgRequest {
+            client: self,
+            org,
+            team_slug,
+            role: None,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List team members.
      *
@@ -1057,6 +2448,41 @@ impl Teams {
             )
             .await
     }
+    /**
+     * List team members.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/teams/{team_slug}/members` endpoint.
+     *
+     * As opposed to `list_all_members_in_org`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Team members will include the members of child teams.
+     *
+     * To list members in a team, the team must be visible to the authenticated user.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#list-team-members>
+     */
+    pub fn list_all_members_in_org_stream(
+        &self,
+        org: &str,
+        team_slug: &str,
+        role: crate::types::TeamsListMembersInOrgRole,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::SimpleUser>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !role.to_string().is_empty() {
+            query_args.push(("role".to_string(), role.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/orgs/{}/teams/{}/members?{}",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(team_slug),
+                query_
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Get team membership for a user.
      *
@@ -1255,6 +2681,32 @@ impl Teams {
             )
             .await
     }
+    /**
+     * List team projects.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/teams/{team_slug}/projects` endpoint.
+     *
+     * As opposed to `list_projects_in_org`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Lists the organization projects for a team.
+     *
+     * **Note:** You can also specify a team by `org_id` and `team_id` using the route `GET /organizations/{org_id}/team/{team_id}/projects`.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#list-team-projects>
+     */
+    pub fn list_projects_in_org_request<'a>(
+        &'a self,
+        org: &'a str,
+        team_slug: &'a str,
+    ) -> TeamsListProjectsInOrgRequest<'a> {
+        TeamsListProjectsInOrgRequest {
+            client: self,
+            org,
+            team_slug,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List team projects.
      *
@@ -1291,6 +2743,34 @@ impl Teams {
             )
             .await
     }
+    /**
+     * List team projects.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/teams/{team_slug}/projects` endpoint.
+     *
+     * As opposed to `list_all_projects_in_org`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Lists the organization projects for a team.
+     *
+     * **Note:** You can also specify a team by `org_id` and `team_id` using the route `GET /organizations/{org_id}/team/{team_id}/projects`.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#list-team-projects>
+     */
+    pub fn list_all_projects_in_org_stream(
+        &self,
+        org: &str,
+        team_slug: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::TeamProject>> + Unpin + '_ {
+        let url = self.client.url(
+            &format!(
+                "/orgs/{}/teams/{}/projects",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(team_slug),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Check team permissions for a project.
      *
@@ -1470,6 +2950,32 @@ impl Teams {
             )
             .await
     }
+    /**
+     * List team repositories.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/teams/{team_slug}/repos` endpoint.
+     *
+     * As opposed to `list_repos_in_org`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Lists a team's repositories visible to the authenticated user.
+     *
+     * **Note:** You can also specify a team by `org_id` and `team_id` using the route `GET /organizations/{org_id}/team/{team_id}/repos`.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#list-team-repositories>
+     */
+    pub fn list_repos_in_org_request<'a>(
+        &'a self,
+        org: &'a str,
+        team_slug: &'a str,
+    ) -> TeamsListReposInOrgRequest<'a> {
+        TeamsListReposInOrgRequest {
+            client: self,
+            org,
+            team_slug,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List team repositories.
      *
@@ -1506,6 +3012,35 @@ impl Teams {
             )
             .await
     }
+    /**
+     * List team repositories.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/teams/{team_slug}/repos` endpoint.
+     *
+     * As opposed to `list_all_repos_in_org`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Lists a team's repositories visible to the authenticated user.
+     *
+     * **Note:** You can also specify a team by `org_id` and `team_id` using the route `GET /organizations/{org_id}/team/{team_id}/repos`.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#list-team-repositories>
+     */
+    pub fn list_all_repos_in_org_stream(
+        &self,
+        org: &str,
+        team_slug: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::MinimalRepository>> + Unpin + '_
+    {
+        let url = self.client.url(
+            &format!(
+                "/orgs/{}/teams/{}/repos",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(team_slug),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Check team permissions for a repository.
      *
@@ -1783,6 +3318,32 @@ impl Teams {
             )
             .await
     }
+    /**
+     * List child teams.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/teams/{team_slug}/teams` endpoint.
+     *
+     * As opposed to `list_child_in_org`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Lists the child teams of the team specified by `{team_slug}`.
+     *
+     * **Note:** You can also specify a team by `org_id` and `team_id` using the route `GET /organizations/{org_id}/team/{team_id}/teams`.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#list-child-teams>
+     */
+    pub fn list_child_in_org_request<'a>(
+        &'a self,
+        org: &'a str,
+        team_slug: &'a str,
+    ) -> TeamsListChildInOrgRequest<'a> {
+        TeamsListChildInOrgRequest {
+            client: self,
+            org,
+            team_slug,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List child teams.
      *
@@ -1819,6 +3380,34 @@ impl Teams {
             )
             .await
     }
+    /**
+     * List child teams.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/teams/{team_slug}/teams` endpoint.
+     *
+     * As opposed to `list_all_child_in_org`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Lists the child teams of the team specified by `{team_slug}`.
+     *
+     * **Note:** You can also specify a team by `org_id` and `team_id` using the route `GET /organizations/{org_id}/team/{team_id}/teams`.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#list-child-teams>
+     */
+    pub fn list_all_child_in_org_stream(
+        &self,
+        org: &str,
+        team_slug: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Team>> + Unpin + '_ {
+        let url = self.client.url(
+            &format!(
+                "/orgs/{}/teams/{}/teams",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(team_slug),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Get a team (Legacy).
      *
@@ -1983,6 +3572,31 @@ impl Teams {
             )
             .await
     }
+    /**
+     * List discussions (Legacy).
+     *
+     * This function performs a `GET` to the `/teams/{team_id}/discussions` endpoint.
+     *
+     * As opposed to `list_discussions_legacy`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * **Deprecation Notice:** This endpoint route is deprecated and will be removed from the Teams API. We recommend migrating your existing code to use the new [`List discussions`](https://docs.github.com/rest/reference/teams#list-discussions) endpoint.
+     *
+     * List all discussions on a team's page. OAuth access tokens require the `read:discussion` [scope](https://docs.github.com/apps/building-oauth-apps/understanding-scopes-for-oauth-apps/).
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#list-discussions-legacy>
+     */
+    pub fn list_discussions_legacy_request(
+        &self,
+        team_id: i64,
+    ) -> TeamsListDiscussionsLegacyRequest<'_> {
+        TeamsListDiscussionsLegacyRequest {
+            client: self,
+            team_id,
+            direction: None,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List discussions (Legacy).
      *
@@ -2024,6 +3638,39 @@ impl Teams {
             )
             .await
     }
+    /**
+     * List discussions (Legacy).
+     *
+     * This function performs a `GET` to the `/teams/{team_id}/discussions` endpoint.
+     *
+     * As opposed to `list_all_discussions_legacy`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * **Deprecation Notice:** This endpoint route is deprecated and will be removed from the Teams API. We recommend migrating your existing code to use the new [`List discussions`](https://docs.github.com/rest/reference/teams#list-discussions) endpoint.
+     *
+     * List all discussions on a team's page. OAuth access tokens require the `read:discussion` [scope](https://docs.github.com/apps/building-oauth-apps/understanding-scopes-for-oauth-apps/).
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#list-discussions-legacy>
+     */
+    pub fn list_all_discussions_legacy_stream(
+        &self,
+        team_id: i64,
+        direction: crate::types::Order,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::TeamDiscussion>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !direction.to_string().is_empty() {
+            query_args.push(("direction".to_string(), direction.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/teams/{}/discussions?{}",
+                crate::progenitor_support::encode_path(&team_id.to_string()),
+                query_
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Create a discussion (Legacy).
      *
@@ -2240,6 +3887,33 @@ impl Teams {
             )
             .await
     }
+    /**
+     * List discussion comments (Legacy).
+     *
+     * This function performs a `GET` to the `/teams/{team_id}/discussions/{discussion_number}/comments` endpoint.
+     *
+     * As opposed to `list_discussion_comments_legacy`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * **Deprecation Notice:** This endpoint route is deprecated and will be removed from the Teams API. We recommend migrating your existing code to use the new [List discussion comments](https://docs.github.com/rest/reference/teams#list-discussion-comments) endpoint.
+     *
+     * List all comments on a team discussion. OAuth access tokens require the `read:discussion` [scope](https://docs.github.com/apps/building-oauth-apps/understanding-scopes-for-oauth-apps/).
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#list-discussion-comments-legacy>
+     */
+    pub fn list_discussion_comments_legacy_request(
+        &self,
+        team_id: i64,
+        discussion_number: i64,
+    ) -> TeamsListDiscussionCommentsLegacyRequest<'_> {
+        TeamsListDiscussionCommentsLegacyRequest {
+            client: self,
+            team_id,
+            discussion_number,
+            direction: None,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List discussion comments (Legacy).
      *
@@ -2283,6 +3957,42 @@ impl Teams {
             )
             .await
     }
+    /**
+     * List discussion comments (Legacy).
+     *
+     * This function performs a `GET` to the `/teams/{team_id}/discussions/{discussion_number}/comments` endpoint.
+     *
+     * As opposed to `list_all_discussion_comments_legacy`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * **Deprecation Notice:** This endpoint route is deprecated and will be removed from the Teams API. We recommend migrating your existing code to use the new [List discussion comments](https://docs.github.com/rest/reference/teams#list-discussion-comments) endpoint.
+     *
+     * List all comments on a team discussion. OAuth access tokens require the `read:discussion` [scope](https://docs.github.com/apps/building-oauth-apps/understanding-scopes-for-oauth-apps/).
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#list-discussion-comments-legacy>
+     */
+    pub fn list_all_discussion_comments_legacy_stream(
+        &self,
+        team_id: i64,
+        discussion_number: i64,
+        direction: crate::types::Order,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::TeamDiscussionComment>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !direction.to_string().is_empty() {
+            query_args.push(("direction".to_string(), direction.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/teams/{}/discussions/{}/comments?{}",
+                crate::progenitor_support::encode_path(&team_id.to_string()),
+                crate::progenitor_support::encode_path(&discussion_number.to_string()),
+                query_
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Create a discussion comment (Legacy).
      *
@@ -2501,6 +4211,30 @@ impl Teams {
             )
             .await
     }
+    /**
+     * List pending team invitations (Legacy).
+     *
+     * This function performs a `GET` to the `/teams/{team_id}/invitations` endpoint.
+     *
+     * As opposed to `list_pending_invitations_legacy`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * **Deprecation Notice:** This endpoint route is deprecated and will be removed from the Teams API. We recommend migrating your existing code to use the new [`List pending team invitations`](https://docs.github.com/rest/reference/teams#list-pending-team-invitations) endpoint.
+     *
+     * The return hash contains a `role` field which refers to the Organization Invitation role and will be one of the following values: `direct_member`, `admin`, `billing_manager`, `hiring_manager`, or `reinstate`. If the invitee is not a GitHub member, the `login` field in the return hash will be `null`.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#list-pending-team-invitations-legacy>
+     */
+    pub fn list_pending_invitations_legacy_request(
+        &self,
+        team_id: i64,
+    ) -> TeamsListPendingInvitationsLegacyRequest<'_> {
+        TeamsListPendingInvitationsLegacyRequest {
+            client: self,
+            team_id,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List pending team invitations (Legacy).
      *
@@ -2535,6 +4269,33 @@ impl Teams {
             )
             .await
     }
+    /**
+     * List pending team invitations (Legacy).
+     *
+     * This function performs a `GET` to the `/teams/{team_id}/invitations` endpoint.
+     *
+     * As opposed to `list_all_pending_invitations_legacy`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * **Deprecation Notice:** This endpoint route is deprecated and will be removed from the Teams API. We recommend migrating your existing code to use the new [`List pending team invitations`](https://docs.github.com/rest/reference/teams#list-pending-team-invitations) endpoint.
+     *
+     * The return hash contains a `role` field which refers to the Organization Invitation role and will be one of the following values: `direct_member`, `admin`, `billing_manager`, `hiring_manager`, or `reinstate`. If the invitee is not a GitHub member, the `login` field in the return hash will be `null`.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#list-pending-team-invitations-legacy>
+     */
+    pub fn list_all_pending_invitations_legacy_stream(
+        &self,
+        team_id: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::OrganizationInvitation>> + Unpin + '_
+    {
+        let url = self.client.url(
+            &format!(
+                "/teams/{}/invitations",
+                crate::progenitor_support::encode_path(&team_id.to_string()),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * List team members (Legacy).
      *
@@ -2592,6 +4353,28 @@ impl Teams {
             )
             .await
     }
+    /**
+     * List team members (Legacy).
+     *
+     * This function performs a `GET` to the `/teams/{team_id}/members` endpoint.
+     *
+     * As opposed to `list_members_legacy`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * **Deprecation Notice:** This endpoint route is deprecated and will be removed from the Teams API. We recommend migrating your existing code to use the new [`List team members`](https://docs.github.com/rest/reference/teams#list-team-members) endpoint.
+     *
+     * Team members will include the members of child teams.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#list-team-members-legacy>
+     */
+    pub fn list_members_legacy_request(&self, team_id: i64) -> TeamsListMembersLegacyRequest<'_> {
+        TeamsListMembersLegacyRequest {
+            client: self,
+            team_id,
+            role: None,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List team members (Legacy).
      *
@@ -2633,6 +4416,39 @@ impl Teams {
             )
             .await
     }
+    /**
+     * List team members (Legacy).
+     *
+     * This function performs a `GET` to the `/teams/{team_id}/members` endpoint.
+     *
+     * As opposed to `list_all_members_legacy`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * **Deprecation Notice:** This endpoint route is deprecated and will be removed from the Teams API. We recommend migrating your existing code to use the new [`List team members`](https://docs.github.com/rest/reference/teams#list-team-members) endpoint.
+     *
+     * Team members will include the members of child teams.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#list-team-members-legacy>
+     */
+    pub fn list_all_members_legacy_stream(
+        &self,
+        team_id: i64,
+        role: crate::types::TeamsListMembersInOrgRole,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::SimpleUser>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !role.to_string().is_empty() {
+            query_args.push(("role".to_string(), role.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/teams/{}/members?{}",
+                crate::progenitor_support::encode_path(&team_id.to_string()),
+                query_
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Get team member (Legacy).
      *
@@ -2952,6 +4768,27 @@ impl Teams {
             )
             .await
     }
+    /**
+     * List team projects (Legacy).
+     *
+     * This function performs a `GET` to the `/teams/{team_id}/projects` endpoint.
+     *
+     * As opposed to `list_projects_legacy`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * **Deprecation Notice:** This endpoint route is deprecated and will be removed from the Teams API. We recommend migrating your existing code to use the new [`List team projects`](https://docs.github.com/rest/reference/teams#list-team-projects) endpoint.
+     *
+     * Lists the organization projects for a team.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams/#list-team-projects-legacy>
+     */
+    pub fn list_projects_legacy_request(&self, team_id: i64) -> TeamsListProjectsLegacyRequest<'_> {
+        TeamsListProjectsLegacyRequest {
+            client: self,
+            team_id,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List team projects (Legacy).
      *
@@ -2986,6 +4823,32 @@ impl Teams {
             )
             .await
     }
+    /**
+     * List team projects (Legacy).
+     *
+     * This function performs a `GET` to the `/teams/{team_id}/projects` endpoint.
+     *
+     * As opposed to `list_all_projects_legacy`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * **Deprecation Notice:** This endpoint route is deprecated and will be removed from the Teams API. We recommend migrating your existing code to use the new [`List team projects`](https://docs.github.com/rest/reference/teams#list-team-projects) endpoint.
+     *
+     * Lists the organization projects for a team.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams/#list-team-projects-legacy>
+     */
+    pub fn list_all_projects_legacy_stream(
+        &self,
+        team_id: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::TeamProject>> + Unpin + '_ {
+        let url = self.client.url(
+            &format!(
+                "/teams/{}/projects",
+                crate::progenitor_support::encode_path(&team_id.to_string()),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Check team permissions for a project (Legacy).
      *
@@ -3151,6 +5014,25 @@ impl Teams {
             )
             .await
     }
+    /**
+     * List team repositories (Legacy).
+     *
+     * This function performs a `GET` to the `/teams/{team_id}/repos` endpoint.
+     *
+     * As opposed to `list_repos_legacy`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * **Deprecation Notice:** This endpoint route is deprecated and will be removed from the Teams API. We recommend migrating your existing code to use the new [List team repositories](https://docs.github.com/rest/reference/teams#list-team-repositories) endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams/#list-team-repositories-legacy>
+     */
+    pub fn list_repos_legacy_request(&self, team_id: i64) -> TeamsListReposLegacyRequest<'_> {
+        TeamsListReposLegacyRequest {
+            client: self,
+            team_id,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List team repositories (Legacy).
      *
@@ -3183,6 +5065,31 @@ impl Teams {
             )
             .await
     }
+    /**
+     * List team repositories (Legacy).
+     *
+     * This function performs a `GET` to the `/teams/{team_id}/repos` endpoint.
+     *
+     * As opposed to `list_all_repos_legacy`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * **Deprecation Notice:** This endpoint route is deprecated and will be removed from the Teams API. We recommend migrating your existing code to use the new [List team repositories](https://docs.github.com/rest/reference/teams#list-team-repositories) endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams/#list-team-repositories-legacy>
+     */
+    pub fn list_all_repos_legacy_stream(
+        &self,
+        team_id: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::MinimalRepository>> + Unpin + '_
+    {
+        let url = self.client.url(
+            &format!(
+                "/teams/{}/repos",
+                crate::progenitor_support::encode_path(&team_id.to_string()),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Check team permissions for a repository (Legacy).
      *
@@ -3438,6 +5345,25 @@ impl Teams {
             )
             .await
     }
+    /**
+     * List child teams (Legacy).
+     *
+     * This function performs a `GET` to the `/teams/{team_id}/teams` endpoint.
+     *
+     * As opposed to `list_child_legacy`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * **Deprecation Notice:** This endpoint route is deprecated and will be removed from the Teams API. We recommend migrating your existing code to use the new [`List child teams`](https://docs.github.com/rest/reference/teams#list-child-teams) endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams/#list-child-teams-legacy>
+     */
+    pub fn list_child_legacy_request(&self, team_id: i64) -> TeamsListChildLegacyRequest<'_> {
+        TeamsListChildLegacyRequest {
+            client: self,
+            team_id,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List child teams (Legacy).
      *
@@ -3470,6 +5396,30 @@ impl Teams {
             )
             .await
     }
+    /**
+     * List child teams (Legacy).
+     *
+     * This function performs a `GET` to the `/teams/{team_id}/teams` endpoint.
+     *
+     * As opposed to `list_all_child_legacy`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * **Deprecation Notice:** This endpoint route is deprecated and will be removed from the Teams API. We recommend migrating your existing code to use the new [`List child teams`](https://docs.github.com/rest/reference/teams#list-child-teams) endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams/#list-child-teams-legacy>
+     */
+    pub fn list_all_child_legacy_stream(
+        &self,
+        team_id: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Team>> + Unpin + '_ {
+        let url = self.client.url(
+            &format!(
+                "/teams/{}/teams",
+                crate::progenitor_support::encode_path(&team_id.to_string()),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * List teams for the authenticated user.
      *
@@ -3508,6 +5458,24 @@ impl Teams {
             )
             .await
     }
+    /**
+     * List teams for the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/teams` endpoint.
+     *
+     * As opposed to `list_for_authenticated_user`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * List all of the teams across all of the organizations to which the authenticated user belongs. This method requires `user`, `repo`, or `read:org` [scope](https://docs.github.com/apps/building-oauth-apps/understanding-scopes-for-oauth-apps/) when authenticating via [OAuth](https://docs.github.com/apps/building-oauth-apps/).
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#list-teams-for-the-authenticated-user>
+     */
+    pub fn list_for_authenticated_user_request(&self) -> TeamsListForAuthenticatedUserRequest<'_> {
+        TeamsListForAuthenticatedUserRequest {
+            client: self,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List teams for the authenticated user.
      *
@@ -3533,4 +5501,21 @@ impl Teams {
             )
             .await
     }
+    /**
+     * List teams for the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/teams` endpoint.
+     *
+     * As opposed to `list_all_for_authenticated_user`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * List all of the teams across all of the organizations to which the authenticated user belongs. This method requires `user`, `repo`, or `read:org` [scope](https://docs.github.com/apps/building-oauth-apps/understanding-scopes-for-oauth-apps/) when authenticating via [OAuth](https://docs.github.com/apps/building-oauth-apps/).
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#list-teams-for-the-authenticated-user>
+     */
+    pub fn list_all_for_authenticated_user_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::FullTeam>> + Unpin + '_ {
+        let url = self.client.url("/user/teams", None);
+        self.client.get_all_pages_stream(url)
+    }
 }
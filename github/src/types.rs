@@ -131,6 +131,10 @@ pub struct SimpleUser {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The set of permissions for the GitHub app
@@ -166,6 +170,10 @@ pub struct Permissions {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub metadata: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// GitHub apps are a new way to extend GitHub. They can be installed directly on organizations and user accounts and granted access to specific repositories. They come with granular permissions and built-in webhooks. GitHub apps are first class actors within GitHub.
@@ -273,6 +281,10 @@ pub struct GitHubApp {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub webhook_secret: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Basic Error
@@ -302,6 +314,10 @@ pub struct BasicError {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Validation Error Simple
@@ -328,6 +344,10 @@ pub struct ValidationErrorSimple {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub message: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types:
@@ -410,6 +430,10 @@ pub struct WebhookConfig {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Delivery made by a webhook, without request and response information.
@@ -480,6 +504,10 @@ pub struct HookDeliveryItem {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub status_code: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Scim Error
@@ -528,6 +556,10 @@ pub struct ScimError {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub status: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types:
@@ -642,6 +674,10 @@ pub struct Errors {
     pub resource: String,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub value: Option<ValueOneOf>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Validation Error
@@ -668,10 +704,19 @@ pub struct ValidationError {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub message: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Data {}
+pub struct Data {
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Request {
@@ -685,6 +730,10 @@ pub struct Request {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub payload: Option<Data>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -700,6 +749,10 @@ pub struct Response {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub payload: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Delivery made by a webhook.
@@ -772,6 +825,10 @@ pub struct HookDelivery {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub status_code: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// An enterprise account
@@ -837,6 +894,10 @@ pub struct Enterprise {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub website_url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -851,8 +912,9 @@ pub enum Pages {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Pages {
@@ -861,7 +923,7 @@ impl std::fmt::Display for Pages {
             Pages::Read => "read",
             Pages::Write => "write",
             Pages::Noop => "",
-            Pages::FallthroughString => "*",
+            Pages::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -887,8 +949,9 @@ pub enum RepositoryProjects {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for RepositoryProjects {
@@ -898,7 +961,7 @@ impl std::fmt::Display for RepositoryProjects {
             RepositoryProjects::Read => "read",
             RepositoryProjects::Write => "write",
             RepositoryProjects::Noop => "",
-            RepositoryProjects::FallthroughString => "*",
+            RepositoryProjects::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -920,8 +983,9 @@ pub enum OrganizationPlan {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OrganizationPlan {
@@ -929,7 +993,7 @@ impl std::fmt::Display for OrganizationPlan {
         match self {
             OrganizationPlan::Read => "read",
             OrganizationPlan::Noop => "",
-            OrganizationPlan::FallthroughString => "*",
+            OrganizationPlan::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -951,8 +1015,9 @@ pub enum Workflows {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Workflows {
@@ -960,7 +1025,7 @@ impl std::fmt::Display for Workflows {
         match self {
             Workflows::Write => "write",
             Workflows::Noop => "",
-            Workflows::FallthroughString => "*",
+            Workflows::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1130,6 +1195,10 @@ pub struct AppPermissions {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub workflows: Option<Workflows>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types are flattened into one object:
@@ -1163,8 +1232,9 @@ pub enum RepositorySelection {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for RepositorySelection {
@@ -1173,7 +1243,7 @@ impl std::fmt::Display for RepositorySelection {
             RepositorySelection::All => "all",
             RepositorySelection::Selected => "selected",
             RepositorySelection::Noop => "",
-            RepositorySelection::FallthroughString => "*",
+            RepositorySelection::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1304,6 +1374,10 @@ pub struct Installation {
         deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// License Simple
@@ -1345,6 +1419,10 @@ pub struct LicenseSimple {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -1374,6 +1452,10 @@ pub struct RepositoryPermissions {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub triage: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -1486,6 +1568,10 @@ pub struct Users {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -1505,6 +1591,10 @@ pub struct RepositoryTemplatePermissions {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub push: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -1972,6 +2062,10 @@ pub struct TemplateRepository {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub watchers_count: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A git repository
@@ -2509,6 +2603,10 @@ pub struct Repository {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub watchers_count: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Authentication token for a GitHub App installed on a user or org.
@@ -2568,6 +2666,10 @@ pub struct InstallationToken {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub token: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -2590,6 +2692,10 @@ pub struct App {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The authorization associated with an OAuth Access.
@@ -2631,6 +2737,10 @@ pub struct ApplicationGrant {
     pub url: String,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub user: Option<SimpleUser>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -2672,6 +2782,10 @@ pub struct ScopedInstallation {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub single_file_paths: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The authorization for an OAuth app, GitHub App, or a Personal Access Token.
@@ -2751,6 +2865,10 @@ pub struct Authorization {
     pub url: String,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub user: Option<SimpleUser>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Code Of Conduct
@@ -2786,6 +2904,10 @@ pub struct CodeOfConduct {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -2802,8 +2924,9 @@ pub enum EnabledRepositories {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EnabledRepositories {
@@ -2813,7 +2936,7 @@ impl std::fmt::Display for EnabledRepositories {
             EnabledRepositories::None => "none",
             EnabledRepositories::Selected => "selected",
             EnabledRepositories::Noop => "",
-            EnabledRepositories::FallthroughString => "*",
+            EnabledRepositories::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2839,8 +2962,9 @@ pub enum AllowedActions {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AllowedActions {
@@ -2850,7 +2974,7 @@ impl std::fmt::Display for AllowedActions {
             AllowedActions::LocalOnly => "local_only",
             AllowedActions::Selected => "selected",
             AllowedActions::Noop => "",
-            AllowedActions::FallthroughString => "*",
+            AllowedActions::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2885,6 +3009,10 @@ pub struct ActionsEnterprisePermissions {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub selected_organizations_url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Organization Simple
@@ -2962,6 +3090,10 @@ pub struct OrganizationSimple {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -2985,6 +3117,10 @@ pub struct SelectedActions {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub verified_allowed: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -3029,6 +3165,10 @@ pub struct RunnerGroupsEnterprise {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub visibility: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -3043,8 +3183,9 @@ pub enum Type {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Type {
@@ -3053,7 +3194,7 @@ impl std::fmt::Display for Type {
             Type::Custom => "custom",
             Type::ReadOnly => "read-only",
             Type::Noop => "",
-            Type::FallthroughString => "*",
+            Type::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3084,6 +3225,10 @@ pub struct Labels {
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
     pub type_: Option<Type>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A self hosted runner
@@ -3124,6 +3269,10 @@ pub struct Runner {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub status: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Runner Application
@@ -3166,6 +3315,10 @@ pub struct RunnerApplication {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub temp_download_token: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Authentication Token
@@ -3208,6 +3361,10 @@ pub struct AuthenticationToken {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub token: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -3218,6 +3375,10 @@ pub struct ActorLocation {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub country_name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -3463,6 +3624,10 @@ pub struct AuditLogEvent {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub visibility: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -3488,6 +3653,10 @@ pub struct MinutesUsedBreakdown {
         rename = "WINDOWS"
     )]
     pub windows: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -3511,6 +3680,10 @@ pub struct ActionsBillingUsage {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_paid_minutes_used: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -3533,6 +3706,10 @@ pub struct PackagesBillingUsage {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_paid_gigabytes_bandwidth_used: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -3555,6 +3732,10 @@ pub struct CombinedBillingUsage {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub estimated_storage_for_month: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Actor
@@ -3596,6 +3777,10 @@ pub struct Actor {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Color-coded labels help you categorize and filter your issues (just like labels in Gmail).
@@ -3642,6 +3827,10 @@ pub struct Label {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -3654,8 +3843,9 @@ pub enum State {
     #[serde(rename = "open")]
     #[default]
     Open,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for State {
@@ -3663,7 +3853,7 @@ impl std::fmt::Display for State {
         match self {
             State::Closed => "closed",
             State::Open => "open",
-            State::FallthroughString => "*",
+            State::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3763,6 +3953,10 @@ pub struct Milestone {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -3789,8 +3983,9 @@ pub enum AuthorAssociation {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AuthorAssociation {
@@ -3805,7 +4000,7 @@ impl std::fmt::Display for AuthorAssociation {
             AuthorAssociation::None => "NONE",
             AuthorAssociation::Owner => "OWNER",
             AuthorAssociation::Noop => "",
-            AuthorAssociation::FallthroughString => "*",
+            AuthorAssociation::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3849,6 +4044,10 @@ pub struct PullRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Issue Simple
@@ -4013,6 +4212,10 @@ pub struct IssueSimple {
     pub url: String,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub user: Option<SimpleUser>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -4079,6 +4282,10 @@ pub struct ReactionRollup {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Comments provide a way for people to collaborate on an issue.
@@ -4157,6 +4364,10 @@ pub struct IssueComment {
     pub url: String,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub user: Option<SimpleUser>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -4179,6 +4390,10 @@ pub struct Repo {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -4219,6 +4434,10 @@ pub struct EventPayloadPages {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub title: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -4245,6 +4464,10 @@ pub struct Payload {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub pages: Vec<EventPayloadPages>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Event
@@ -4286,6 +4509,10 @@ pub struct Event {
         rename = "type"
     )]
     pub type_: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Hypermedia Link with Type
@@ -4304,6 +4531,10 @@ pub struct LinkWithType {
         rename = "type"
     )]
     pub type_: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -4347,6 +4578,10 @@ pub struct Links {
      * Hypermedia Link with Type
      */
     pub user: LinkWithType,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Feed
@@ -4405,6 +4640,10 @@ pub struct Feed {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub user_url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -4440,6 +4679,10 @@ pub struct Files {
         rename = "type"
     )]
     pub type_: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Base Gist
@@ -4559,6 +4802,10 @@ pub struct BaseGist {
     pub url: String,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub user: Option<SimpleUser>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -4587,6 +4834,10 @@ pub struct Plan {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub space: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Public User
@@ -4842,6 +5093,10 @@ pub struct PublicUser {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -4864,6 +5119,10 @@ pub struct Stats {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Gist History
@@ -4900,6 +5159,10 @@ pub struct GistHistory {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub version: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -4933,6 +5196,10 @@ pub struct Forks {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub user: Option<PublicUser>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Gist
@@ -5052,6 +5319,10 @@ pub struct Gist {
     pub url: String,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub user: Option<SimpleUser>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -5098,6 +5369,10 @@ pub struct FilesAdditionalProperties {
         rename = "type"
     )]
     pub type_: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Gist Simple
@@ -5230,6 +5505,10 @@ pub struct GistSimple {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub user: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A comment made to a gist.
@@ -5277,6 +5556,10 @@ pub struct GistComment {
     pub url: String,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub user: Option<SimpleUser>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Gist Commit
@@ -5303,6 +5586,10 @@ pub struct GistCommit {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub version: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Gitignore Template
@@ -5320,6 +5607,10 @@ pub struct GitignoreTemplate {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub source: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -5365,6 +5656,10 @@ pub struct LabelsData {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types:
@@ -5581,6 +5876,10 @@ pub struct Issue {
     pub url: String,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub user: Option<SimpleUser>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// License
@@ -5672,6 +5971,10 @@ pub struct LicenseData {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Marketplace Listing Plan
@@ -5757,6 +6060,10 @@ pub struct MarketplaceListingPlan {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub yearly_price_in_cents: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -5786,6 +6093,10 @@ pub struct MarketplacePendingChange {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub unit_count: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -5835,6 +6146,10 @@ pub struct MarketplacePurchase {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub updated_at: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Marketplace Purchase
@@ -5880,6 +6195,10 @@ pub struct MarketplacePurchaseData {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -5898,6 +6217,10 @@ pub struct SshKeyFingerprints {
         rename = "SHA256_RSA"
     )]
     pub sha256_rsa: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Api Overview
@@ -5994,6 +6317,10 @@ pub struct ApiOverview {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub web: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -6023,6 +6350,10 @@ pub struct MinimalRepositoryPermissions {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub triage: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -6057,6 +6388,10 @@ pub struct License {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Minimal Repository
@@ -6610,6 +6945,10 @@ pub struct MinimalRepository {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub watchers_count: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -6639,6 +6978,10 @@ pub struct Subject {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Thread
@@ -6690,6 +7033,10 @@ pub struct Thread {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Thread Subscription
@@ -6735,6 +7082,10 @@ pub struct ThreadSubscription {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -6769,6 +7120,10 @@ pub struct OrganizationFullPlan {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub space: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Organization Full
@@ -7081,6 +7436,10 @@ pub struct OrganizationFull {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -7106,6 +7465,10 @@ pub struct ActionsOrganizationPermissions {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub selected_repositories_url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -7160,6 +7523,10 @@ pub struct RunnerGroupsOrg {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub visibility: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -7176,8 +7543,9 @@ pub enum Visibility {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Visibility {
@@ -7187,7 +7555,7 @@ impl std::fmt::Display for Visibility {
             Visibility::Private => "private",
             Visibility::Selected => "selected",
             Visibility::Noop => "",
-            Visibility::FallthroughString => "*",
+            Visibility::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -7231,6 +7599,10 @@ pub struct OrganizationActionsSecret {
      */
     #[serde(default, skip_serializing_if = "Visibility::is_noop")]
     pub visibility: Visibility,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The public key used for setting Actions Secrets.
@@ -7275,6 +7647,10 @@ pub struct ActionsPublicKey {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Credential Authorization
@@ -7355,6 +7731,10 @@ pub struct CredentialAuthorization {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub token_last_eight: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Organization Invitation
@@ -7425,6 +7805,10 @@ pub struct OrganizationInvitation {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub team_count: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -7453,6 +7837,10 @@ pub struct Config {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Org Hook
@@ -7522,6 +7910,10 @@ pub struct OrgHook {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -7538,8 +7930,9 @@ pub enum InteractionGroup {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for InteractionGroup {
@@ -7549,7 +7942,7 @@ impl std::fmt::Display for InteractionGroup {
             InteractionGroup::ContributorsOnly => "contributors_only",
             InteractionGroup::ExistingUsers => "existing_users",
             InteractionGroup::Noop => "",
-            InteractionGroup::FallthroughString => "*",
+            InteractionGroup::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -7580,6 +7973,10 @@ pub struct InteractionLimits {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub origin: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -7600,8 +7997,9 @@ pub enum InteractionExpiry {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for InteractionExpiry {
@@ -7613,7 +8011,7 @@ impl std::fmt::Display for InteractionExpiry {
             InteractionExpiry::SixMonths => "six_months",
             InteractionExpiry::ThreeDays => "three_days",
             InteractionExpiry::Noop => "",
-            InteractionExpiry::FallthroughString => "*",
+            InteractionExpiry::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -7637,6 +8035,10 @@ pub struct InteractionLimit {
      * The type of GitHub user that can comment, open issues, or create pull requests while the interaction limit is in effect. Can be one of: `existing_users`, `contributors_only`, `collaborators_only`.
      */
     pub limit: InteractionGroup,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Groups of organization members that gives permissions on specified repositories.
@@ -7714,6 +8116,10 @@ pub struct TeamSimple {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -7743,6 +8149,10 @@ pub struct TeamPermissions {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub triage: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Groups of organization members that gives permissions on specified repositories.
@@ -7821,6 +8231,10 @@ pub struct Team {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -7835,8 +8249,9 @@ pub enum OrgMembershipState {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OrgMembershipState {
@@ -7845,7 +8260,7 @@ impl std::fmt::Display for OrgMembershipState {
             OrgMembershipState::Active => "active",
             OrgMembershipState::Pending => "pending",
             OrgMembershipState::Noop => "",
-            OrgMembershipState::FallthroughString => "*",
+            OrgMembershipState::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -7871,8 +8286,9 @@ pub enum Role {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Role {
@@ -7882,7 +8298,7 @@ impl std::fmt::Display for Role {
             Role::BillingManager => "billing_manager",
             Role::Member => "member",
             Role::Noop => "",
-            Role::FallthroughString => "*",
+            Role::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -7901,6 +8317,10 @@ pub struct OrgMembershipPermissions {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub can_create_repository: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Org Membership
@@ -7939,6 +8359,10 @@ pub struct OrgMembership {
     pub url: String,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub user: Option<SimpleUser>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A migration.
@@ -8019,6 +8443,10 @@ pub struct Migration {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -8038,8 +8466,9 @@ pub enum PackageType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PackageType {
@@ -8052,7 +8481,7 @@ impl std::fmt::Display for PackageType {
             PackageType::Nuget => "nuget",
             PackageType::Rubygems => "rubygems",
             PackageType::Noop => "",
-            PackageType::FallthroughString => "*",
+            PackageType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -8073,8 +8502,9 @@ pub enum PackageVisibility {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PackageVisibility {
@@ -8083,7 +8513,7 @@ impl std::fmt::Display for PackageVisibility {
             PackageVisibility::Private => "private",
             PackageVisibility::Public => "public",
             PackageVisibility::Noop => "",
-            PackageVisibility::FallthroughString => "*",
+            PackageVisibility::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -8148,6 +8578,10 @@ pub struct Package {
     pub version_count: i64,
     #[serde(default, skip_serializing_if = "PackageVisibility::is_noop")]
     pub visibility: PackageVisibility,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -8161,6 +8595,10 @@ pub struct Container {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub tags: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -8171,6 +8609,10 @@ pub struct Docker {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub tag: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -8181,6 +8623,10 @@ pub struct Metadata {
     pub docker: Option<Docker>,
     #[serde(default, skip_serializing_if = "PackageType::is_noop")]
     pub package_type: PackageType,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A version of a software package
@@ -8254,6 +8700,10 @@ pub struct PackageVersion {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -8272,8 +8722,9 @@ pub enum OrganizationPermission {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OrganizationPermission {
@@ -8284,7 +8735,7 @@ impl std::fmt::Display for OrganizationPermission {
             OrganizationPermission::Read => "read",
             OrganizationPermission::Write => "write",
             OrganizationPermission::Noop => "",
-            OrganizationPermission::FallthroughString => "*",
+            OrganizationPermission::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -8386,6 +8837,10 @@ pub struct Project {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -8420,6 +8875,10 @@ pub struct Groups {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub synced_at: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// External Groups to be mapped to a team for membership
@@ -8434,6 +8893,10 @@ pub struct GroupMapping {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub groups: Vec<Groups>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -8448,8 +8911,9 @@ pub enum Privacy {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Privacy {
@@ -8458,7 +8922,7 @@ impl std::fmt::Display for Privacy {
             Privacy::Closed => "closed",
             Privacy::Secret => "secret",
             Privacy::Noop => "",
-            Privacy::FallthroughString => "*",
+            Privacy::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -8574,6 +9038,10 @@ pub struct FullTeam {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A team discussion is a persistent record of a free-form conversation within a team.
@@ -8680,6 +9148,10 @@ pub struct TeamDiscussion {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A reply to a discussion within a team.
@@ -8758,6 +9230,10 @@ pub struct TeamDiscussionComment {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -8784,8 +9260,9 @@ pub enum Content {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Content {
@@ -8800,7 +9277,7 @@ impl std::fmt::Display for Content {
             Content::Laugh => "laugh",
             Content::Rocket => "rocket",
             Content::Noop => "",
-            Content::FallthroughString => "*",
+            Content::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -8840,6 +9317,10 @@ pub struct Reaction {
     pub node_id: String,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub user: Option<SimpleUser>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -8852,8 +9333,9 @@ pub enum TeamMembershipRole {
     #[serde(rename = "member")]
     #[default]
     Member,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for TeamMembershipRole {
@@ -8861,7 +9343,7 @@ impl std::fmt::Display for TeamMembershipRole {
         match self {
             TeamMembershipRole::Maintainer => "maintainer",
             TeamMembershipRole::Member => "member",
-            TeamMembershipRole::FallthroughString => "*",
+            TeamMembershipRole::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -8886,6 +9368,10 @@ pub struct TeamMembership {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -8905,6 +9391,10 @@ pub struct TeamProjectPermissions {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub write: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A team's access to a project.
@@ -9002,6 +9492,10 @@ pub struct TeamProject {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A team's access to a repository.
@@ -9531,6 +10025,10 @@ pub struct TeamRepository {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub watchers_count: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Project cards represent a scope of work.
@@ -9612,6 +10110,10 @@ pub struct ProjectCard {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Project columns contain cards of work.
@@ -9665,6 +10167,10 @@ pub struct ProjectColumn {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Repository Collaborator Permission
@@ -9678,6 +10184,10 @@ pub struct RepositoryCollaboratorPermission {
     pub permission: String,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub user: Option<SimpleUser>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -9706,6 +10216,10 @@ pub struct RateLimit {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub used: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -9720,6 +10234,10 @@ pub struct Resources {
     pub search: RateLimit,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub source_import: Option<RateLimit>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Rate Limit Overview
@@ -9727,6 +10245,10 @@ pub struct Resources {
 pub struct RateLimitOverview {
     pub rate: RateLimit,
     pub resources: Resources,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Code of Conduct Simple
@@ -9756,6 +10278,10 @@ pub struct CodeOfConductSimple {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -9775,6 +10301,10 @@ pub struct FullRepositoryPermissions {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub push: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -9786,8 +10316,9 @@ pub enum Status {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Status {
@@ -9796,7 +10327,7 @@ impl std::fmt::Display for Status {
             Status::Disabled => "disabled",
             Status::Enabled => "enabled",
             Status::Noop => "",
-            Status::FallthroughString => "*",
+            Status::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -9812,6 +10343,10 @@ impl Status {
 pub struct SecretScanning {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub status: Option<Status>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -9820,6 +10355,10 @@ pub struct SecurityAnalysis {
     pub advanced_security: Option<SecretScanning>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub secret_scanning: Option<SecretScanning>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Full Repository
@@ -10373,6 +10912,10 @@ pub struct FullRepository {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub watchers_count: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// An artifact
@@ -10437,6 +10980,10 @@ pub struct Artifact {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -10453,8 +11000,9 @@ pub enum JobStatus {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for JobStatus {
@@ -10464,7 +11012,7 @@ impl std::fmt::Display for JobStatus {
             JobStatus::InProgress => "in_progress",
             JobStatus::Queued => "queued",
             JobStatus::Noop => "",
-            JobStatus::FallthroughString => "*",
+            JobStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -10513,6 +11061,10 @@ pub struct Steps {
      */
     #[serde(default, skip_serializing_if = "JobStatus::is_noop")]
     pub status: JobStatus,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Information of a job execution in a workflow run
@@ -10604,6 +11156,10 @@ pub struct Job {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -10624,6 +11180,10 @@ pub struct ActionsRepositoryPermissions {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub selected_actions_url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -10643,6 +11203,10 @@ pub struct Head {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub sha: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -10667,6 +11231,10 @@ pub struct PullRequestMinimal {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -10683,6 +11251,10 @@ pub struct Author {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Simple Commit
@@ -10716,6 +11288,10 @@ pub struct SimpleCommit {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub tree_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// An invocation of a workflow
@@ -10887,6 +11463,10 @@ pub struct WorkflowRun {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub workflow_url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -10933,6 +11513,10 @@ pub struct Environments {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -10947,8 +11531,9 @@ pub enum EnvironmentApprovalState {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EnvironmentApprovalState {
@@ -10957,7 +11542,7 @@ impl std::fmt::Display for EnvironmentApprovalState {
             EnvironmentApprovalState::Approved => "approved",
             EnvironmentApprovalState::Rejected => "rejected",
             EnvironmentApprovalState::Noop => "",
-            EnvironmentApprovalState::FallthroughString => "*",
+            EnvironmentApprovalState::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -10997,6 +11582,10 @@ pub struct EnvironmentApproval {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub user: Option<SimpleUser>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -11011,8 +11600,9 @@ pub enum DeploymentReviewerType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DeploymentReviewerType {
@@ -11021,7 +11611,7 @@ impl std::fmt::Display for DeploymentReviewerType {
             DeploymentReviewerType::Team => "Team",
             DeploymentReviewerType::User => "User",
             DeploymentReviewerType::Noop => "",
-            DeploymentReviewerType::FallthroughString => "*",
+            DeploymentReviewerType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -11065,6 +11655,10 @@ pub struct Environment {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types are flattened into one object:
@@ -11095,6 +11689,10 @@ pub struct Reviewers {
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
     pub type_: Option<DeploymentReviewerType>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Details of a deployment that is waiting for protection rules to pass
@@ -11127,6 +11725,10 @@ pub struct PendingDeployment {
         deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub wait_timer_started_at: Option<chrono::DateTime<chrono::Utc>>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types:
@@ -11274,6 +11876,10 @@ pub struct Deployment {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -11290,6 +11896,10 @@ pub struct Macos {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_ms: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -11300,6 +11910,10 @@ pub struct Billable {
     pub ubuntu: Option<Macos>,
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "WINDOWS")]
     pub windows: Option<Macos>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Workflow Run Usage
@@ -11315,6 +11929,10 @@ pub struct WorkflowRunUsage {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub run_duration_ms: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Set secrets for GitHub Actions.
@@ -11338,6 +11956,10 @@ pub struct ActionsSecret {
         deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -11355,8 +11977,9 @@ pub enum WorkflowState {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for WorkflowState {
@@ -11368,7 +11991,7 @@ impl std::fmt::Display for WorkflowState {
             WorkflowState::DisabledInactivity => "disabled_inactivity",
             WorkflowState::DisabledManually => "disabled_manually",
             WorkflowState::Noop => "",
-            WorkflowState::FallthroughString => "*",
+            WorkflowState::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -11448,6 +12071,10 @@ pub struct Workflow {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -11458,6 +12085,10 @@ pub struct Windows {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_ms: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -11468,12 +12099,20 @@ pub struct WorkflowUsageBillable {
     pub ubuntu: Option<Windows>,
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "WINDOWS")]
     pub windows: Option<Windows>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Workflow Usage
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct WorkflowUsage {
     pub billable: WorkflowUsageBillable,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// An autolink reference.
@@ -11497,6 +12136,10 @@ pub struct Autolink {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url_template: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -11512,6 +12155,10 @@ pub struct EnforceAdmins {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -11549,6 +12196,10 @@ pub struct DismissalRestrictions {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub users_url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Protected Branch Pull Request Review
@@ -11584,6 +12235,10 @@ pub struct ProtectedBranchPullRequestReview {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -11660,6 +12315,10 @@ pub struct Teams {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -11802,6 +12461,10 @@ pub struct BranchRestrictionPolicyAppsOwner {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -11830,6 +12493,10 @@ pub struct BranchRestrictionPolicyAppsPermissions {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub single_file: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -11901,6 +12568,10 @@ pub struct Apps {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub updated_at: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Branch Restriction Policy
@@ -11948,6 +12619,10 @@ pub struct BranchRestrictionPolicy {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub users_url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -11984,6 +12659,10 @@ pub struct RequiredStatusChecks {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -11993,6 +12672,10 @@ pub struct AllowDeletions {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub enabled: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Branch Protection
@@ -12069,6 +12752,10 @@ pub struct BranchProtection {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -12085,6 +12772,10 @@ pub struct Tree {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Short Branch
@@ -12113,6 +12804,10 @@ pub struct ShortBranch {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub protection_url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -12135,6 +12830,10 @@ pub struct Tagger {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -12162,6 +12861,10 @@ pub struct Verification {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub verified: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -12191,6 +12894,10 @@ pub struct CommitData {
     pub url: String,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub verification: Option<Verification>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -12213,6 +12920,10 @@ pub struct Parents {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -12283,6 +12994,10 @@ pub struct CommitFiles {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub status: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Commit
@@ -12343,6 +13058,10 @@ pub struct CommitDataType {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -12360,6 +13079,10 @@ pub struct BranchWithProtectionLinks {
         rename = "self"
     )]
     pub self_: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Branch With Protection
@@ -12407,6 +13130,10 @@ pub struct BranchWithProtection {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub required_approving_review_count: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Status Check Policy
@@ -12438,6 +13165,10 @@ pub struct StatusCheckPolicy {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -12475,6 +13206,10 @@ pub struct ProtectedBranchRequiredPullRequestReviewsDismissalRestrictions {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub users_url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -12504,6 +13239,10 @@ pub struct RequiredPullRequestReviews {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -12513,6 +13252,10 @@ pub struct ProtectedBranchRequiredLinearHistory {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub enabled: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Branch protections protect branches
@@ -12569,6 +13312,10 @@ pub struct ProtectedBranch {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A deployment created as the result of an Actions check run from a workflow that references an environment
@@ -12658,6 +13405,10 @@ pub struct DeploymentSimple {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -12679,8 +13430,9 @@ pub enum Conclusion {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Conclusion {
@@ -12694,7 +13446,7 @@ impl std::fmt::Display for Conclusion {
             Conclusion::Success => "success",
             Conclusion::TimedOut => "timed_out",
             Conclusion::Noop => "",
-            Conclusion::FallthroughString => "*",
+            Conclusion::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -12738,6 +13490,10 @@ pub struct Output {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub title: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -12748,6 +13504,10 @@ pub struct CheckSuite {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub id: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A check performed on the code of a given code change
@@ -12836,6 +13596,10 @@ pub struct CheckRun {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Check Annotation
@@ -12901,6 +13665,10 @@ pub struct CheckAnnotation {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub title: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A suite of checks performed on the code of a given code change
@@ -12995,6 +13763,10 @@ pub struct CheckSuiteData {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -13010,6 +13782,10 @@ pub struct AutoTriggerChecks {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub setting: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -13020,6 +13796,10 @@ pub struct Preferences {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub auto_trigger_checks: Vec<AutoTriggerChecks>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Check suite configuration preferences for a repository.
@@ -13030,6 +13810,10 @@ pub struct CheckSuitePreference {
      * Minimal Repository
      */
     pub repository: MinimalRepository,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -13048,8 +13832,9 @@ pub enum CodeScanningAlertState {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for CodeScanningAlertState {
@@ -13060,7 +13845,7 @@ impl std::fmt::Display for CodeScanningAlertState {
             CodeScanningAlertState::Fixed => "fixed",
             CodeScanningAlertState::Open => "open",
             CodeScanningAlertState::Noop => "",
-            CodeScanningAlertState::FallthroughString => "*",
+            CodeScanningAlertState::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -13086,8 +13871,9 @@ pub enum CodeScanningAlertDismissedReason {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for CodeScanningAlertDismissedReason {
@@ -13097,7 +13883,7 @@ impl std::fmt::Display for CodeScanningAlertDismissedReason {
             CodeScanningAlertDismissedReason::UsedInTests => "used in tests",
             CodeScanningAlertDismissedReason::WonTFix => "won't fix",
             CodeScanningAlertDismissedReason::Noop => "",
-            CodeScanningAlertDismissedReason::FallthroughString => "*",
+            CodeScanningAlertDismissedReason::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -13125,8 +13911,9 @@ pub enum Severity {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Severity {
@@ -13137,7 +13924,7 @@ impl std::fmt::Display for Severity {
             Severity::Note => "note",
             Severity::Warning => "warning",
             Severity::Noop => "",
-            Severity::FallthroughString => "*",
+            Severity::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -13174,6 +13961,10 @@ pub struct CodeScanningAlertRuleSummary {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub severity: Option<Severity>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -13196,6 +13987,10 @@ pub struct CodeScanningAnalysisTool {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub version: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Describe a region within a file for the alert.
@@ -13243,6 +14038,10 @@ pub struct CodeScanningAlertLocation {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub start_line: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -13261,8 +14060,9 @@ pub enum CodeScanningAlertClassification {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for CodeScanningAlertClassification {
@@ -13273,7 +14073,7 @@ impl std::fmt::Display for CodeScanningAlertClassification {
             CodeScanningAlertClassification::Source => "source",
             CodeScanningAlertClassification::Test => "test",
             CodeScanningAlertClassification::Noop => "",
-            CodeScanningAlertClassification::FallthroughString => "*",
+            CodeScanningAlertClassification::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -13293,6 +14093,10 @@ pub struct Message {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub text: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -13350,6 +14154,10 @@ pub struct CodeScanningAlertInstance {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub state: Option<CodeScanningAlertState>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -13407,6 +14215,10 @@ pub struct CodeScanningAlertItems {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -13425,8 +14237,9 @@ pub enum SecuritySeverityLevel {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SecuritySeverityLevel {
@@ -13437,7 +14250,7 @@ impl std::fmt::Display for SecuritySeverityLevel {
             SecuritySeverityLevel::Low => "low",
             SecuritySeverityLevel::Medium => "medium",
             SecuritySeverityLevel::Noop => "",
-            SecuritySeverityLevel::FallthroughString => "*",
+            SecuritySeverityLevel::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -13500,6 +14313,10 @@ pub struct CodeScanningAlertRule {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub tags: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -13559,6 +14376,10 @@ pub struct CodeScanningAlert {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -13573,8 +14394,9 @@ pub enum CodeScanningAlertSetState {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for CodeScanningAlertSetState {
@@ -13583,7 +14405,7 @@ impl std::fmt::Display for CodeScanningAlertSetState {
             CodeScanningAlertSetState::Dismissed => "dismissed",
             CodeScanningAlertSetState::Open => "open",
             CodeScanningAlertSetState::Noop => "",
-            CodeScanningAlertSetState::FallthroughString => "*",
+            CodeScanningAlertSetState::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -13688,6 +14510,10 @@ pub struct CodeScanningAnalysis {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub warning: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Successful deletion of a code scanning analysis
@@ -13705,6 +14531,10 @@ pub struct AnalysisDeletion {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub next_analysis_url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -13721,6 +14551,10 @@ pub struct CodeScanningSarifsReceipt {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -13735,8 +14569,9 @@ pub enum ProcessingStatus {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ProcessingStatus {
@@ -13745,7 +14580,7 @@ impl std::fmt::Display for ProcessingStatus {
             ProcessingStatus::Complete => "complete",
             ProcessingStatus::Pending => "pending",
             ProcessingStatus::Noop => "",
-            ProcessingStatus::FallthroughString => "*",
+            ProcessingStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -13770,6 +14605,10 @@ pub struct CodeScanningSarifsStatus {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub processing_status: Option<ProcessingStatus>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Collaborator
@@ -13900,6 +14739,10 @@ pub struct Collaborator {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -13920,8 +14763,9 @@ pub enum RepositoryInvitationPermissions {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for RepositoryInvitationPermissions {
@@ -13933,7 +14777,7 @@ impl std::fmt::Display for RepositoryInvitationPermissions {
             RepositoryInvitationPermissions::Triage => "triage",
             RepositoryInvitationPermissions::Write => "write",
             RepositoryInvitationPermissions::Noop => "",
-            RepositoryInvitationPermissions::FallthroughString => "*",
+            RepositoryInvitationPermissions::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -14002,6 +14846,10 @@ pub struct RepositoryInvitation {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Commit Comment
@@ -14084,6 +14932,10 @@ pub struct CommitComment {
     pub url: String,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub user: Option<SimpleUser>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Branch Short
@@ -14101,6 +14953,10 @@ pub struct BranchShort {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub protected: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Hypermedia Link
@@ -14112,6 +14968,10 @@ pub struct Link {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub href: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -14128,8 +14988,9 @@ pub enum MergeMethod {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for MergeMethod {
@@ -14139,7 +15000,7 @@ impl std::fmt::Display for MergeMethod {
             MergeMethod::Rebase => "rebase",
             MergeMethod::Squash => "squash",
             MergeMethod::Noop => "",
-            MergeMethod::FallthroughString => "*",
+            MergeMethod::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -14176,6 +15037,10 @@ pub struct AutoMerge {
      */
     #[serde(default, skip_serializing_if = "MergeMethod::is_noop")]
     pub merge_method: MergeMethod,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -14206,6 +15071,10 @@ pub struct PullRequestSimpleHead {
     pub sha: String,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub user: Option<SimpleUser>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -14236,6 +15105,10 @@ pub struct Base {
     pub sha: String,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub user: Option<SimpleUser>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -14273,6 +15146,10 @@ pub struct PullRequestSimpleLinks {
      * Hypermedia Link
      */
     pub statuses: Link,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Pull Request Simple
@@ -14469,6 +15346,10 @@ pub struct PullRequestSimple {
     pub url: String,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub user: Option<SimpleUser>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -14538,6 +15419,10 @@ pub struct SimpleCommitStatus {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Combined Commit Status
@@ -14583,6 +15468,10 @@ pub struct CombinedCommitStatus {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The status of a commit.
@@ -14653,6 +15542,10 @@ pub struct StatusData {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -14669,6 +15562,10 @@ pub struct CommunityHealthFile {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -14690,6 +15587,10 @@ pub struct CommunityProfileFiles {
     pub pull_request_template: Option<CommunityHealthFile>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub readme: Option<CommunityHealthFile>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Community Profile
@@ -14728,6 +15629,10 @@ pub struct CommunityProfile {
         deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Diff Entry
@@ -14799,6 +15704,10 @@ pub struct DiffEntry {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub status: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -14814,8 +15723,9 @@ pub enum CommitComparisonStatus {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for CommitComparisonStatus {
@@ -14826,7 +15736,7 @@ impl std::fmt::Display for CommitComparisonStatus {
             CommitComparisonStatus::Diverged => "diverged",
             CommitComparisonStatus::Identical => "identical",
             CommitComparisonStatus::Noop => "",
-            CommitComparisonStatus::FallthroughString => "*",
+            CommitComparisonStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -14914,6 +15824,10 @@ pub struct CommitComparison {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Content Reference attachments allow you to provide context around URLs posted in comments
@@ -14943,6 +15857,10 @@ pub struct ContentReferenceAttachment {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub title: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -14966,6 +15884,10 @@ pub struct ContentTreeEntriesLinks {
         rename = "self"
     )]
     pub self_: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -15033,6 +15955,10 @@ pub struct Entries {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Content Tree
@@ -15104,6 +16030,10 @@ pub struct ContentTree {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Content File
@@ -15190,6 +16120,10 @@ pub struct ContentFile {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// An object describing a symlink
@@ -15258,6 +16192,10 @@ pub struct SymlinkContent {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// An object describing a symlink
@@ -15326,6 +16264,10 @@ pub struct ContentSubmodule {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -15387,6 +16329,10 @@ pub struct FileCommitContent {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -15414,6 +16360,10 @@ pub struct FileCommitVerification {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub verified: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -15462,6 +16412,10 @@ pub struct FileCommit {
     pub url: String,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub verification: Option<FileCommitVerification>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// File Commit
@@ -15470,6 +16424,10 @@ pub struct FileCommitData {
     pub commit: FileCommit,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub content: Option<FileCommitContent>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Contributor
@@ -15607,6 +16565,10 @@ pub struct Contributor {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -15631,8 +16593,9 @@ pub enum DeploymentStatusState {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DeploymentStatusState {
@@ -15646,7 +16609,7 @@ impl std::fmt::Display for DeploymentStatusState {
             DeploymentStatusState::Queued => "queued",
             DeploymentStatusState::Success => "success",
             DeploymentStatusState::Noop => "",
-            DeploymentStatusState::FallthroughString => "*",
+            DeploymentStatusState::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -15742,6 +16705,10 @@ pub struct DeploymentStatus {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The type of deployment branch policy for this environment. To allow all branches to deploy, set to `null`.
@@ -15757,6 +16724,10 @@ pub struct DeploymentBranchPolicy {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub protected_branches: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -15786,6 +16757,10 @@ pub struct ProtectionRules {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub wait_timer: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -15818,6 +16793,10 @@ pub struct ProtectionRulesData {
         rename = "type"
     )]
     pub type_: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -15841,6 +16820,10 @@ pub struct ProtectionRulesDataType {
         rename = "type"
     )]
     pub type_: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types are flattened into one object:
@@ -15918,6 +16901,10 @@ pub struct EnvironmentData {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Blob
@@ -15965,6 +16952,10 @@ pub struct Blob {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Identifying information for the git-user
@@ -15988,6 +16979,10 @@ pub struct Committer {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Low-level Git commit operations within a repository
@@ -16039,6 +17034,10 @@ pub struct GitCommit {
     )]
     pub url: String,
     pub verification: Verification,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -16062,6 +17061,10 @@ pub struct Object {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Git references within a repository
@@ -16087,6 +17090,10 @@ pub struct GitRef {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Metadata for a Git tag
@@ -16129,6 +17136,10 @@ pub struct GitTag {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub verification: Option<Verification>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -16170,6 +17181,10 @@ pub struct GitTree {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The hierarchy between files in a Git repository.
@@ -16201,6 +17216,10 @@ pub struct GitTreeData {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -16223,6 +17242,10 @@ pub struct HookResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub status: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -16283,6 +17306,10 @@ pub struct HookConfig {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Webhooks for repositories.
@@ -16359,6 +17386,10 @@ pub struct Hook {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -16391,15 +17422,14 @@ pub enum ImportStatus {
     Pushing,
     #[serde(rename = "setup")]
     Setup,
-    #[serde(rename = "unknown")]
-    Unknown,
     #[serde(rename = "waiting_to_push")]
     WaitingToPush,
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ImportStatus {
@@ -16419,10 +17449,9 @@ impl std::fmt::Display for ImportStatus {
             ImportStatus::None => "none",
             ImportStatus::Pushing => "pushing",
             ImportStatus::Setup => "setup",
-            ImportStatus::Unknown => "unknown",
             ImportStatus::WaitingToPush => "waiting_to_push",
             ImportStatus::Noop => "",
-            ImportStatus::FallthroughString => "*",
+            ImportStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -16454,6 +17483,10 @@ pub struct ProjectChoices {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub vcs: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A repository import from an external source.
@@ -16618,6 +17651,10 @@ pub struct Import {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub vcs_url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Porter Author
@@ -16665,6 +17702,10 @@ pub struct PorterAuthor {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Porter Large File
@@ -16694,6 +17735,10 @@ pub struct PorterLargeFile {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub size: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Issue Event Label
@@ -16711,6 +17756,10 @@ pub struct IssueEventLabel {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -16739,6 +17788,10 @@ pub struct DismissedReview {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub state: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Issue Event Milestone
@@ -16750,6 +17803,10 @@ pub struct IssueEventMilestone {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub title: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Issue Event Project Card
@@ -16791,6 +17848,10 @@ pub struct IssueEventProjectCard {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -16807,6 +17868,10 @@ pub struct Rename {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub to: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Issue Event
@@ -16912,6 +17977,10 @@ pub struct IssueEvent {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Labeled Issue Event
@@ -16973,6 +18042,10 @@ pub struct LabeledIssueEvent {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Assigned Issue Event
@@ -17040,6 +18113,10 @@ pub struct AssignedIssueEvent {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Milestoned Issue Event
@@ -17101,6 +18178,10 @@ pub struct MilestonedIssueEvent {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Renamed Issue Event
@@ -17159,6 +18240,10 @@ pub struct RenamedIssueEvent {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Review Requested Issue Event
@@ -17231,6 +18316,10 @@ pub struct ReviewRequestedIssueEvent {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Review Request Removed Issue Event
@@ -17303,6 +18392,10 @@ pub struct ReviewRequestRemovedIssueEvent {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Review Dismissed Issue Event
@@ -17361,6 +18454,10 @@ pub struct ReviewDismissedIssueEvent {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Locked Issue Event
@@ -17424,6 +18521,10 @@ pub struct LockedIssueEvent {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Added to Project Issue Event
@@ -17486,6 +18587,10 @@ pub struct AddedProjectIssueEvent {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Moved Column in Project Issue Event
@@ -17548,6 +18653,10 @@ pub struct MovedColumnInProjectIssueEvent {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Removed from Project Issue Event
@@ -17610,6 +18719,10 @@ pub struct RemovedFromProjectIssueEvent {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Converted Note to Issue Issue Event
@@ -17672,6 +18785,10 @@ pub struct ConvertedNoteIssueEvent {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types are flattened into one object:
@@ -17849,6 +18966,10 @@ pub struct TimelineCommentEvent {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub user: Option<SimpleUser>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -17865,6 +18986,10 @@ pub struct Source {
         rename = "type"
     )]
     pub type_: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Timeline Cross Referenced Event
@@ -17894,6 +19019,10 @@ pub struct TimelineCrossReferencedEvent {
         deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Timeline Committed Event
@@ -17951,6 +19080,10 @@ pub struct TimelineCommittedEvent {
     )]
     pub url: String,
     pub verification: Verification,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -17963,6 +19096,10 @@ pub struct TimelineReviewedEventLinks {
      * Hypermedia Link
      */
     pub pull_request: Link,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Timeline Reviewed Event
@@ -18048,6 +19185,10 @@ pub struct TimelineReviewedEvent {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub user: Option<SimpleUser>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -18065,6 +19206,10 @@ pub struct PullRequestReviewCommentLinks {
      */
     #[serde(rename = "self")]
     pub self_: Link,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -18077,8 +19222,9 @@ pub enum Side {
     #[serde(rename = "RIGHT")]
     #[default]
     Right,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Side {
@@ -18086,7 +19232,7 @@ impl std::fmt::Display for Side {
         match self {
             Side::Left => "LEFT",
             Side::Right => "RIGHT",
-            Side::FallthroughString => "*",
+            Side::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -18268,6 +19414,10 @@ pub struct PullRequestReviewComment {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub user: Option<SimpleUser>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Timeline Line Commented Event
@@ -18294,6 +19444,10 @@ pub struct TimelineLineCommentedEvent {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub node_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Timeline Commit Commented Event
@@ -18326,6 +19480,10 @@ pub struct TimelineCommitCommentedEvent {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub node_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Timeline Assigned Issue Event
@@ -18388,6 +19546,10 @@ pub struct TimelineAssignedIssueEvent {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// An SSH key granting access to a single repository.
@@ -18433,6 +19595,10 @@ pub struct DeployKey {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub verified: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// License Content
@@ -18509,6 +19675,10 @@ pub struct LicenseContent {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -18525,6 +19695,10 @@ pub struct PagesSourceHash {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub path: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -18556,8 +19730,9 @@ pub enum PagesHttpsCertificateState {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PagesHttpsCertificateState {
@@ -18576,7 +19751,7 @@ impl std::fmt::Display for PagesHttpsCertificateState {
             PagesHttpsCertificateState::New => "new",
             PagesHttpsCertificateState::Uploaded => "uploaded",
             PagesHttpsCertificateState::Noop => "",
-            PagesHttpsCertificateState::FallthroughString => "*",
+            PagesHttpsCertificateState::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -18613,6 +19788,10 @@ pub struct PagesHttpsCertificate {
     pub expires_at: Option<chrono::NaiveDate>,
     #[serde(default, skip_serializing_if = "PagesHttpsCertificateState::is_noop")]
     pub state: PagesHttpsCertificateState,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -18629,8 +19808,9 @@ pub enum PageStatus {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PageStatus {
@@ -18640,7 +19820,7 @@ impl std::fmt::Display for PageStatus {
             PageStatus::Built => "built",
             PageStatus::Errored => "errored",
             PageStatus::Noop => "",
-            PageStatus::FallthroughString => "*",
+            PageStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -18695,6 +19875,10 @@ pub struct Page {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -18705,6 +19889,10 @@ pub struct Error {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub message: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Page Build
@@ -18749,6 +19937,10 @@ pub struct PageBuild {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Page Build Status
@@ -18766,6 +19958,10 @@ pub struct PageBuildStatus {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -18916,6 +20112,10 @@ pub struct Domain {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub uri: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -19066,6 +20266,10 @@ pub struct AltDomain {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub uri: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Pages Health Check Status
@@ -19078,6 +20282,10 @@ pub struct PagesHealthCheck {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub domain: Option<Domain>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -19190,6 +20398,10 @@ pub struct User {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -19649,6 +20861,10 @@ pub struct PullRequestHeadRepo {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub watchers_count: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -19675,6 +20891,10 @@ pub struct PullRequestHead {
     )]
     pub sha: String,
     pub user: User,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -20137,6 +21357,10 @@ pub struct PullRequestBaseRepo {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub watchers_count: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -20163,6 +21387,10 @@ pub struct PullRequestBase {
     )]
     pub sha: String,
     pub user: User,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Pull requests let you tell others about changes you've pushed to a repository on GitHub. Once a pull request is sent, interested parties can review the set of changes, discuss potential modifications, and even push follow-up commits if necessary.
@@ -20422,6 +21650,10 @@ pub struct PullRequestData {
     pub url: String,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub user: Option<SimpleUser>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Pull Request Merge Result
@@ -20444,6 +21676,10 @@ pub struct PullRequestMergeResult {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub sha: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Pull Request Review Request
@@ -20464,6 +21700,10 @@ pub struct PullRequestReview {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub users: Vec<SimpleUser>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Pull Request Reviews are reviews on pull requests.
@@ -20540,6 +21780,10 @@ pub struct PullRequestReviewData {
     pub submitted_at: Option<chrono::DateTime<chrono::Utc>>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub user: Option<SimpleUser>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -20557,6 +21801,10 @@ pub struct ReviewCommentLinks {
      */
     #[serde(rename = "self")]
     pub self_: Link,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Legacy Review Comment
@@ -20732,6 +21980,10 @@ pub struct ReviewComment {
     pub url: String,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub user: Option<SimpleUser>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -20746,8 +21998,9 @@ pub enum ReleaseAssetState {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ReleaseAssetState {
@@ -20756,7 +22009,7 @@ impl std::fmt::Display for ReleaseAssetState {
             ReleaseAssetState::Open => "open",
             ReleaseAssetState::Uploaded => "uploaded",
             ReleaseAssetState::Noop => "",
-            ReleaseAssetState::FallthroughString => "*",
+            ReleaseAssetState::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -20844,6 +22097,10 @@ pub struct ReleaseAsset {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A release.
@@ -20986,6 +22243,10 @@ pub struct Release {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub zipball_url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -21000,8 +22261,9 @@ pub enum SecretScanningAlertState {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SecretScanningAlertState {
@@ -21010,7 +22272,7 @@ impl std::fmt::Display for SecretScanningAlertState {
             SecretScanningAlertState::Open => "open",
             SecretScanningAlertState::Resolved => "resolved",
             SecretScanningAlertState::Noop => "",
-            SecretScanningAlertState::FallthroughString => "*",
+            SecretScanningAlertState::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -21038,8 +22300,9 @@ pub enum SecretScanningAlertResolution {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SecretScanningAlertResolution {
@@ -21050,7 +22313,7 @@ impl std::fmt::Display for SecretScanningAlertResolution {
             SecretScanningAlertResolution::UsedInTests => "used_in_tests",
             SecretScanningAlertResolution::WontFix => "wont_fix",
             SecretScanningAlertResolution::Noop => "",
-            SecretScanningAlertResolution::FallthroughString => "*",
+            SecretScanningAlertResolution::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -21121,6 +22384,10 @@ pub struct SecretScanningAlert {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Stargazer
@@ -21134,6 +22401,10 @@ pub struct Stargazer {
     pub starred_at: Option<chrono::DateTime<chrono::Utc>>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub user: Option<SimpleUser>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Commit Activity
@@ -21160,6 +22431,10 @@ pub struct CommitActivity {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub week: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -21188,6 +22463,10 @@ pub struct Weeks {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub w: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Contributor Activity
@@ -21207,6 +22486,10 @@ pub struct ContributorActivity {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub weeks: Vec<Weeks>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -21229,6 +22512,10 @@ pub struct ParticipationStats {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub owner: Vec<i64>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Repository invitations let you manage who you collaborate with.
@@ -21268,6 +22555,10 @@ pub struct RepositorySubscription {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Tag
@@ -21298,6 +22589,10 @@ pub struct Tag {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub zipball_url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A topic aggregates entities that are related to a subject.
@@ -21312,6 +22607,10 @@ pub struct Topic {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub names: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -21334,6 +22633,10 @@ pub struct Traffic {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub uniques: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Clone Traffic
@@ -21357,6 +22660,10 @@ pub struct CloneTraffic {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub uniques: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Content Traffic
@@ -21386,6 +22693,10 @@ pub struct ContentTraffic {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub uniques: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Referrer Traffic
@@ -21409,6 +22720,10 @@ pub struct ReferrerTraffic {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub uniques: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// View Traffic
@@ -21432,6 +22747,10 @@ pub struct ViewTraffic {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub views: Vec<Traffic>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -21455,6 +22774,10 @@ pub struct Members {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub value: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -21485,6 +22808,10 @@ pub struct Meta {
         rename = "resourceType"
     )]
     pub resource_type: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -21526,6 +22853,10 @@ pub struct ScimEnterpriseGroup {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub schemas: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -21567,6 +22898,10 @@ pub struct ScimGroupListEnterprise {
         rename = "totalResults"
     )]
     pub total_results: f64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -21585,6 +22920,10 @@ pub struct Name {
         rename = "givenName"
     )]
     pub given_name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -21607,6 +22946,10 @@ pub struct Emails {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub value: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -21617,6 +22960,10 @@ pub struct ScimUserListEnterpriseResourcesGroups {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub value: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -21671,6 +23018,10 @@ pub struct ScimEnterpriseUser {
         rename = "userName"
     )]
     pub user_name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -21712,6 +23063,10 @@ pub struct ScimUserListEnterprise {
         rename = "totalResults"
     )]
     pub total_results: f64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -21736,6 +23091,10 @@ pub struct ScimUserName {
         rename = "givenName"
     )]
     pub given_name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -21751,6 +23110,10 @@ pub struct ScimUserEmails {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub value: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -21781,6 +23144,10 @@ pub struct ScimUserMeta {
         rename = "resourceType"
     )]
     pub resource_type: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -21794,8 +23161,9 @@ pub enum Op {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Op {
@@ -21805,7 +23173,7 @@ impl std::fmt::Display for Op {
             Op::Remove => "remove",
             Op::Replace => "replace",
             Op::Noop => "",
-            Op::FallthroughString => "*",
+            Op::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -21895,6 +23263,10 @@ pub struct Operations {
     pub path: String,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub value: Option<ScimUserOperationsValueOneOf>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// SCIM /Users provisioning endpoints
@@ -21979,6 +23351,10 @@ pub struct ScimUser {
         rename = "userName"
     )]
     pub user_name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// SCIM User List
@@ -22021,6 +23397,10 @@ pub struct ScimUserList {
         rename = "totalResults"
     )]
     pub total_results: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -22040,6 +23420,10 @@ pub struct Matches {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub text: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -22074,6 +23458,10 @@ pub struct SearchResultTextMatches {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub property: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Code Search Result Item
@@ -22167,6 +23555,10 @@ pub struct CodeSearchResultItem {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -22198,6 +23590,10 @@ pub struct CommitSearchResultItem {
     pub url: String,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub verification: Option<Verification>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Commit Search Result Item
@@ -22263,6 +23659,10 @@ pub struct CommitSearchResultItemData {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Issue Search Result Item
@@ -22450,6 +23850,10 @@ pub struct IssueSearchResultItem {
     pub url: String,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub user: Option<SimpleUser>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Label Search Result Item
@@ -22511,6 +23915,10 @@ pub struct LabelSearchResultItem {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Repo Search Result Item
@@ -23018,6 +24426,10 @@ pub struct RepoSearchResultItem {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub watchers_count: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -23046,12 +24458,20 @@ pub struct TopicRelation {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub topic_id: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Related {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub topic_relation: Option<TopicRelation>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Topic Search Result Item
@@ -23157,6 +24577,10 @@ pub struct TopicSearchResultItem {
         deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// User Search Result Item
@@ -23392,6 +24816,10 @@ pub struct UserSearchResultItem {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Private User
@@ -23651,6 +25079,10 @@ pub struct PrivateUser {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Email
@@ -23678,6 +25110,10 @@ pub struct Email {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub visibility: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -23693,6 +25129,10 @@ pub struct GpgKeyEmails {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub verified: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -23777,6 +25217,10 @@ pub struct Subkeys {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub subkeys: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A unique encryption key
@@ -23856,6 +25300,10 @@ pub struct GpgKey {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub subkeys: Vec<Subkeys>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Key
@@ -23901,6 +25349,10 @@ pub struct Key {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub verified: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -23948,6 +25400,10 @@ pub struct MarketplaceAccount {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// User Marketplace Purchase
@@ -23993,6 +25449,10 @@ pub struct UserMarketplacePurchase {
         deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Starred Repository
@@ -24009,6 +25469,10 @@ pub struct StarredRepository {
         deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub starred_at: Option<chrono::DateTime<chrono::Utc>>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -24025,6 +25489,10 @@ pub struct Contexts {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub octicon: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Hovercard
@@ -24036,6 +25504,10 @@ pub struct Hovercard {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub contexts: Vec<Contexts>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Key Simple
@@ -24053,6 +25525,10 @@ pub struct KeySimple {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub key: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -24075,8 +25551,9 @@ pub enum Include {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Include {
@@ -24086,7 +25563,7 @@ impl std::fmt::Display for Include {
             Include::Git => "git",
             Include::Web => "web",
             Include::Noop => "",
-            Include::FallthroughString => "*",
+            Include::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -24112,8 +25589,9 @@ pub enum Order {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Order {
@@ -24122,7 +25600,7 @@ impl std::fmt::Display for Order {
             Order::Asc => "asc",
             Order::Desc => "desc",
             Order::Noop => "",
-            Order::FallthroughString => "*",
+            Order::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -24144,8 +25622,9 @@ pub enum Sort {
     Created,
     #[serde(rename = "updated")]
     Updated,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Sort {
@@ -24153,7 +25632,7 @@ impl std::fmt::Display for Sort {
         match self {
             Sort::Created => "created",
             Sort::Updated => "updated",
-            Sort::FallthroughString => "*",
+            Sort::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -24193,8 +25672,9 @@ pub enum WorkflowRunStatus {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for WorkflowRunStatus {
@@ -24214,7 +25694,7 @@ impl std::fmt::Display for WorkflowRunStatus {
             WorkflowRunStatus::TimedOut => "timed_out",
             WorkflowRunStatus::Waiting => "waiting",
             WorkflowRunStatus::Noop => "",
-            WorkflowRunStatus::FallthroughString => "*",
+            WorkflowRunStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -24290,8 +25770,9 @@ pub enum Per {
     Day,
     #[serde(rename = "week")]
     Week,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Per {
@@ -24299,7 +25780,7 @@ impl std::fmt::Display for Per {
         match self {
             Per::Day => "day",
             Per::Week => "week",
-            Per::FallthroughString => "*",
+            Per::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -24319,6 +25800,10 @@ pub struct PullsMergeResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub message: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -24341,6 +25826,10 @@ pub struct ServiceUnavailableResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub message: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -24363,6 +25852,10 @@ pub struct Block {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub reason: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -24381,6 +25874,10 @@ pub struct ForbiddenGistResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub message: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -24583,6 +26080,10 @@ pub struct MetaRootResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub user_url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -24611,6 +26112,10 @@ pub struct AppsCreateFromManifestResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub webhook_secret: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types are flattened into one object:
@@ -24651,6 +26156,10 @@ pub struct AppsUpdateWebhookConfigAppRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -24678,6 +26187,10 @@ pub struct AppsCreateInstallationAccessTokenRequest {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub repository_ids: Vec<i64>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -24688,6 +26201,10 @@ pub struct AppsCheckTokenRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub access_token: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -24733,6 +26250,10 @@ pub struct AppsScopeTokenRequest {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub target_id: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -24776,6 +26297,10 @@ pub struct OauthAuthorizationsCreateAuthorizationRequest {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub scopes: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -24813,6 +26338,10 @@ pub struct OauthAuthorizationsGetCreateAuthorizationAppRequest {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub scopes: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -24844,6 +26373,10 @@ pub struct OauthAuthorizationsGetCreateAuthorizationAppFingerprintRequest {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub scopes: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -24893,6 +26426,10 @@ pub struct OauthAuthorizationsUpdateAuthorizationRequest {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub scopes: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -24906,6 +26443,10 @@ pub struct EnterpriseAdminSetGithubActionsPermissionsRequest {
      * The policy that controls the repositories in the organization that are allowed to run GitHub Actions. Can be one of: `all`, `none`, or `selected`.
      */
     pub enabled_organizations: EnabledRepositories,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -24922,6 +26463,10 @@ pub struct EnterpriseAdminListOrgAccessSelfHostedRunnerGroupInResponse {
         deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub total_count: f64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -24935,6 +26480,10 @@ pub struct EnterpriseAdminSetOrgAccessSelfHostedRunnerGroupInRequest {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub selected_organization_ids: Vec<i64>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -24951,6 +26500,10 @@ pub struct EnterpriseAdminListSelfHostedRunnerGroupsResponse {
         deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub total_count: f64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -24984,6 +26537,10 @@ pub struct EnterpriseAdminCreateSelfHostedRunnerGroupRequest {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub visibility: Option<RepositorySelection>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -24999,6 +26556,10 @@ pub struct EnterpriseAdminUpdateSelfHostedRunnerGroupRequest {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub visibility: Option<RepositorySelection>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -25015,6 +26576,10 @@ pub struct ActionsListSelfHostedRunnersInGroupOrgResponse {
         deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub total_count: f64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -25028,6 +26593,10 @@ pub struct ActionsSetSelfHostedRunnersInGroupOrgRequest {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub runners: Vec<i64>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -25044,6 +26613,10 @@ pub struct EnterpriseAdminListSelfHostedRunnersResponse {
         deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub total_count: f64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Names and content for the files that make up the gist
@@ -25055,6 +26628,10 @@ pub struct FilesAdditionalPropertiesData {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub content: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -25064,8 +26641,9 @@ pub enum Public {
     False,
     #[serde(rename = "true")]
     True,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Public {
@@ -25073,7 +26651,7 @@ impl std::fmt::Display for Public {
         match self {
             Public::False => "false",
             Public::True => "true",
-            Public::FallthroughString => "*",
+            Public::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -25135,6 +26713,10 @@ pub struct GistsCreateRequest {
     pub files: FilesAdditionalPropertiesData,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub public: Option<PublicOneOf>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Names of files to be updated
@@ -25152,6 +26734,10 @@ pub struct FilesAdditionalPropertiesDataType {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub filename: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -25164,6 +26750,10 @@ pub struct GistsUpdateRequest {
     pub description: String,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub files: Option<FilesAdditionalPropertiesDataType>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -25174,6 +26764,10 @@ pub struct PullsUpdateReviewRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub body: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -25196,6 +26790,10 @@ pub struct AppsListInstallationReposResponse {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_count: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -25221,8 +26819,9 @@ pub enum Filter {
     Repos,
     #[serde(rename = "subscribed")]
     Subscribed,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Filter {
@@ -25234,7 +26833,7 @@ impl std::fmt::Display for Filter {
             Filter::Mentioned => "mentioned",
             Filter::Repos => "repos",
             Filter::Subscribed => "subscribed",
-            Filter::FallthroughString => "*",
+            Filter::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -25252,8 +26851,9 @@ pub enum IssuesListState {
     #[serde(rename = "open")]
     #[default]
     Open,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for IssuesListState {
@@ -25262,7 +26862,7 @@ impl std::fmt::Display for IssuesListState {
             IssuesListState::All => "all",
             IssuesListState::Closed => "closed",
             IssuesListState::Open => "open",
-            IssuesListState::FallthroughString => "*",
+            IssuesListState::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -25280,8 +26880,9 @@ pub enum IssuesListSort {
     Created,
     #[serde(rename = "updated")]
     Updated,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for IssuesListSort {
@@ -25290,7 +26891,7 @@ impl std::fmt::Display for IssuesListSort {
             IssuesListSort::Comments => "comments",
             IssuesListSort::Created => "created",
             IssuesListSort::Updated => "updated",
-            IssuesListSort::FallthroughString => "*",
+            IssuesListSort::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -25306,8 +26907,9 @@ pub enum Mode {
     #[serde(rename = "markdown")]
     #[default]
     Markdown,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Mode {
@@ -25315,7 +26917,7 @@ impl std::fmt::Display for Mode {
         match self {
             Mode::Gfm => "gfm",
             Mode::Markdown => "markdown",
-            Mode::FallthroughString => "*",
+            Mode::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -25340,6 +26942,10 @@ pub struct MarkdownRenderRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub text: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -25352,12 +26958,20 @@ pub struct ActivityMarkNotificationsAsReadRequest {
     pub last_read_at: Option<chrono::DateTime<chrono::Utc>>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub read: Option<bool>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ActivitySetThreadSubscriptionRequest {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub ignored: Option<bool>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -25378,8 +26992,9 @@ pub enum MembersAllowedRepositoryCreationType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for MembersAllowedRepositoryCreationType {
@@ -25389,7 +27004,7 @@ impl std::fmt::Display for MembersAllowedRepositoryCreationType {
             MembersAllowedRepositoryCreationType::None => "none",
             MembersAllowedRepositoryCreationType::Private => "private",
             MembersAllowedRepositoryCreationType::Noop => "",
-            MembersAllowedRepositoryCreationType::FallthroughString => "*",
+            MembersAllowedRepositoryCreationType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -25483,6 +27098,10 @@ pub struct OrgsUpdateRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub twitter_username: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types:
@@ -25532,6 +27151,10 @@ pub struct ActionsSetGithubPermissionsOrganizationRequest {
      * The policy that controls the repositories in the organization that are allowed to run GitHub Actions. Can be one of: `all`, `none`, or `selected`.
      */
     pub enabled_repositories: EnabledRepositories,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -25548,6 +27171,10 @@ pub struct ActionsListSelectedRepositoriesEnabledGithubOrganizationResponse {
         deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub total_count: f64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -25561,6 +27188,10 @@ pub struct ActionsSetRepoAccessSelfHostedRunnerGroupInOrgRequest {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub selected_repository_ids: Vec<i64>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -25577,6 +27208,10 @@ pub struct ActionsListSelfHostedRunnerGroupsOrgResponse {
         deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub total_count: f64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -25610,6 +27245,10 @@ pub struct ActionsCreateSelfHostedRunnerGroupOrgRequest {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub visibility: Option<Visibility>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -25625,6 +27264,10 @@ pub struct ActionsUpdateSelfHostedRunnerGroupOrgRequest {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub visibility: Option<Visibility>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -25641,6 +27284,10 @@ pub struct ActionsListRepoAccessSelfHostedRunnerGroupInOrgResponse {
         deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub total_count: f64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -25657,6 +27304,10 @@ pub struct ActionsListSelfHostedRunnersOrgResponse {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_count: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -25673,6 +27324,10 @@ pub struct ActionsListOrgSecretsResponse {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_count: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -25703,6 +27358,10 @@ pub struct ActionsCreateUpdateOrgSecretRequest {
      */
     #[serde(default, skip_serializing_if = "Visibility::is_noop")]
     pub visibility: Visibility,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -25719,6 +27378,10 @@ pub struct ActionsListSelectedReposOrgSecretResponse {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_count: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -25732,6 +27395,10 @@ pub struct ActionsSetSelectedReposOrgSecretRequest {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub selected_repository_ids: Vec<i64>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Key/value pairs to provide settings for this webhook. [These are defined below](https://docs.github.com/rest/reference/orgs#create-hook-config-params).
@@ -25772,6 +27439,10 @@ pub struct OrgsCreateWebhookRequestConfig {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub username: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -25797,6 +27468,10 @@ pub struct OrgsCreateWebhookRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Key/value pairs to provide settings for this webhook. [These are defined below](https://docs.github.com/rest/reference/orgs#update-hook-config-params).
@@ -25825,6 +27500,10 @@ pub struct OrgsUpdateWebhookRequestConfig {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -25851,6 +27530,10 @@ pub struct OrgsUpdateWebhookRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -25867,6 +27550,10 @@ pub struct AppsListInstallationsResponse {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_count: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types are flattened into one object:
@@ -25900,8 +27587,9 @@ pub enum OrgsCreateInvitationRequestRole {
     #[serde(rename = "direct_member")]
     #[default]
     DirectMember,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OrgsCreateInvitationRequestRole {
@@ -25910,7 +27598,7 @@ impl std::fmt::Display for OrgsCreateInvitationRequestRole {
             OrgsCreateInvitationRequestRole::Admin => "admin",
             OrgsCreateInvitationRequestRole::BillingManager => "billing_manager",
             OrgsCreateInvitationRequestRole::DirectMember => "direct_member",
-            OrgsCreateInvitationRequestRole::FallthroughString => "*",
+            OrgsCreateInvitationRequestRole::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -25947,6 +27635,10 @@ pub struct OrgsCreateInvitationRequest {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub team_ids: Vec<i64>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -25961,8 +27653,9 @@ pub enum OrgsListMembersFilter {
     #[serde(rename = "all")]
     #[default]
     All,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OrgsListMembersFilter {
@@ -25970,7 +27663,7 @@ impl std::fmt::Display for OrgsListMembersFilter {
         match self {
             OrgsListMembersFilter::TwoFaDisabled => "2fa_disabled",
             OrgsListMembersFilter::All => "all",
-            OrgsListMembersFilter::FallthroughString => "*",
+            OrgsListMembersFilter::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -25991,8 +27684,9 @@ pub enum OrgsListMembersRole {
     All,
     #[serde(rename = "member")]
     Member,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OrgsListMembersRole {
@@ -26001,7 +27695,7 @@ impl std::fmt::Display for OrgsListMembersRole {
             OrgsListMembersRole::Admin => "admin",
             OrgsListMembersRole::All => "all",
             OrgsListMembersRole::Member => "member",
-            OrgsListMembersRole::FallthroughString => "*",
+            OrgsListMembersRole::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -26019,8 +27713,9 @@ pub enum OrgsSetMembershipUserRequestRole {
     #[serde(rename = "member")]
     #[default]
     Member,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OrgsSetMembershipUserRequestRole {
@@ -26028,7 +27723,7 @@ impl std::fmt::Display for OrgsSetMembershipUserRequestRole {
         match self {
             OrgsSetMembershipUserRequestRole::Admin => "admin",
             OrgsSetMembershipUserRequestRole::Member => "member",
-            OrgsSetMembershipUserRequestRole::FallthroughString => "*",
+            OrgsSetMembershipUserRequestRole::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -26043,6 +27738,10 @@ pub struct OrgsSetMembershipUserRequest {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub role: Option<OrgsSetMembershipUserRequestRole>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -26055,8 +27754,9 @@ pub enum Exclude {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Exclude {
@@ -26064,7 +27764,7 @@ impl std::fmt::Display for Exclude {
         match self {
             Exclude::Repositories => "repositories",
             Exclude::Noop => "",
-            Exclude::FallthroughString => "*",
+            Exclude::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -26100,6 +27800,10 @@ pub struct MigrationsStartRequest {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub repositories: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -26112,8 +27816,9 @@ pub enum PackagesGetAllPackageVersionsOwnedByOrgState {
     Active,
     #[serde(rename = "deleted")]
     Deleted,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PackagesGetAllPackageVersionsOwnedByOrgState {
@@ -26121,7 +27826,7 @@ impl std::fmt::Display for PackagesGetAllPackageVersionsOwnedByOrgState {
         match self {
             PackagesGetAllPackageVersionsOwnedByOrgState::Active => "active",
             PackagesGetAllPackageVersionsOwnedByOrgState::Deleted => "deleted",
-            PackagesGetAllPackageVersionsOwnedByOrgState::FallthroughString => "*",
+            PackagesGetAllPackageVersionsOwnedByOrgState::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -26141,6 +27846,10 @@ pub struct ProjectsCreateRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -26165,8 +27874,9 @@ pub enum ReposListOrgType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ReposListOrgType {
@@ -26180,7 +27890,7 @@ impl std::fmt::Display for ReposListOrgType {
             ReposListOrgType::Public => "public",
             ReposListOrgType::Sources => "sources",
             ReposListOrgType::Noop => "",
-            ReposListOrgType::FallthroughString => "*",
+            ReposListOrgType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -26206,8 +27916,9 @@ pub enum ReposListOrgSort {
     Pushed,
     #[serde(rename = "updated")]
     Updated,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ReposListOrgSort {
@@ -26217,7 +27928,7 @@ impl std::fmt::Display for ReposListOrgSort {
             ReposListOrgSort::FullName => "full_name",
             ReposListOrgSort::Pushed => "pushed",
             ReposListOrgSort::Updated => "updated",
-            ReposListOrgSort::FallthroughString => "*",
+            ReposListOrgSort::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -26240,8 +27951,9 @@ pub enum ReposCreateInOrgRequestVisibility {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ReposCreateInOrgRequestVisibility {
@@ -26252,7 +27964,7 @@ impl std::fmt::Display for ReposCreateInOrgRequestVisibility {
             ReposCreateInOrgRequestVisibility::Public => "public",
             ReposCreateInOrgRequestVisibility::Visibility => "visibility",
             ReposCreateInOrgRequestVisibility::Noop => "",
-            ReposCreateInOrgRequestVisibility::FallthroughString => "*",
+            ReposCreateInOrgRequestVisibility::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -26330,6 +28042,10 @@ pub struct ReposCreateInOrgRequest {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub visibility: Option<ReposCreateInOrgRequestVisibility>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -26347,8 +28063,9 @@ pub enum Permission {
     Pull,
     #[serde(rename = "push")]
     Push,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Permission {
@@ -26357,7 +28074,7 @@ impl std::fmt::Display for Permission {
             Permission::Admin => "admin",
             Permission::Pull => "pull",
             Permission::Push => "push",
-            Permission::FallthroughString => "*",
+            Permission::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -26414,6 +28131,10 @@ pub struct TeamsCreateRequest {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub repo_names: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -26449,6 +28170,10 @@ pub struct TeamsUpdateInOrgRequest {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub privacy: Option<Privacy>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -26467,6 +28192,10 @@ pub struct TeamsCreateDiscussionInOrgRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub title: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -26483,6 +28212,10 @@ pub struct TeamsUpdateDiscussionInOrgRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub title: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -26492,6 +28225,10 @@ pub struct ReactionsCreateIssueRequest {
      */
     #[serde(default, skip_serializing_if = "Content::is_noop")]
     pub content: Content,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -26509,8 +28246,9 @@ pub enum TeamsListMembersInOrgRole {
     Maintainer,
     #[serde(rename = "member")]
     Member,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for TeamsListMembersInOrgRole {
@@ -26519,7 +28257,7 @@ impl std::fmt::Display for TeamsListMembersInOrgRole {
             TeamsListMembersInOrgRole::All => "all",
             TeamsListMembersInOrgRole::Maintainer => "maintainer",
             TeamsListMembersInOrgRole::Member => "member",
-            TeamsListMembersInOrgRole::FallthroughString => "*",
+            TeamsListMembersInOrgRole::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -26532,12 +28270,20 @@ pub struct TeamsAddUpdateMembershipUserInOrgRequest {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub role: Option<TeamMembershipRole>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ProjectsAddCollaboratorRequest {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub permission: Option<RepositoryProjects>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -26565,8 +28311,9 @@ pub enum TeamsAddUpdateRepoPermissionsInOrgRequestPermission {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for TeamsAddUpdateRepoPermissionsInOrgRequestPermission {
@@ -26578,7 +28325,7 @@ impl std::fmt::Display for TeamsAddUpdateRepoPermissionsInOrgRequestPermission {
             TeamsAddUpdateRepoPermissionsInOrgRequestPermission::Push => "push",
             TeamsAddUpdateRepoPermissionsInOrgRequestPermission::Triage => "triage",
             TeamsAddUpdateRepoPermissionsInOrgRequestPermission::Noop => "",
-            TeamsAddUpdateRepoPermissionsInOrgRequestPermission::FallthroughString => "*",
+            TeamsAddUpdateRepoPermissionsInOrgRequestPermission::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -26607,6 +28354,10 @@ pub struct TeamsAddUpdateRepoPermissionsInOrgRequest {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub permission: Option<TeamsAddUpdateRepoPermissionsInOrgRequestPermission>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -26629,6 +28380,10 @@ pub struct TeamsCreateUpdateIdpGroupConnectionsInOrgRequestGroups {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub group_name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -26642,6 +28397,10 @@ pub struct TeamsCreateUpdateIdpGroupConnectionsInOrgRequest {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub groups: Vec<TeamsCreateUpdateIdpGroupConnectionsInOrgRequestGroups>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -26667,6 +28426,10 @@ pub struct ProjectsDeleteResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub message: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -26679,6 +28442,10 @@ pub struct ProjectsUpdateCardRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub note: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -26695,6 +28462,10 @@ pub struct ProjectsMoveCardRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub position: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -26723,6 +28494,10 @@ pub struct ProjectsMoveCardResponseErrors {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub resource: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -26745,6 +28520,10 @@ pub struct ProjectsMoveCardResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub message: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -26761,6 +28540,10 @@ pub struct ProjectsMoveCardResponseErrorsData {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub message: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -26789,6 +28572,10 @@ pub struct ProjectsCreateCardResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub message: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -26799,6 +28586,10 @@ pub struct ProjectsUpdateColumnRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -26813,8 +28604,9 @@ pub enum ArchivedState {
     #[serde(rename = "not_archived")]
     #[default]
     NotArchived,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ArchivedState {
@@ -26823,7 +28615,7 @@ impl std::fmt::Display for ArchivedState {
             ArchivedState::All => "all",
             ArchivedState::Archived => "archived",
             ArchivedState::NotArchived => "not_archived",
-            ArchivedState::FallthroughString => "*",
+            ArchivedState::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -26837,6 +28629,10 @@ pub struct ProjectsCreateCardRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub note: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -26853,6 +28649,10 @@ pub struct ProjectsCreateCardRequestData {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub content_type: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types:
@@ -26893,6 +28693,10 @@ pub struct ProjectsMoveColumnRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub position: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -26922,6 +28726,10 @@ pub struct ProjectsUpdateRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub state: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -26939,8 +28747,9 @@ pub enum Affiliation {
     Direct,
     #[serde(rename = "outside")]
     Outside,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Affiliation {
@@ -26949,7 +28758,7 @@ impl std::fmt::Display for Affiliation {
             Affiliation::All => "all",
             Affiliation::Direct => "direct",
             Affiliation::Outside => "outside",
-            Affiliation::FallthroughString => "*",
+            Affiliation::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -26964,6 +28773,10 @@ pub struct ReposUpdateRequestSecurityAnalysisAdvanced {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub status: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Specify which security and analysis features to enable or disable. For example, to enable GitHub Advanced Security, use this data in the body of the PATCH request: `{"security_and_analysis": {"advanced_security": {"status": "enabled"}}}`. If you have admin permissions for a private repository covered by an Advanced Security license, you can check which security and analysis features are currently enabled by using a `GET /repos/{owner}/{repo}` request.
@@ -26979,6 +28792,10 @@ pub struct ReposUpdateRequestSecurityAnalysis {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub secret_scanning: Option<ReposUpdateRequestSecurityAnalysisAdvanced>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -27040,6 +28857,10 @@ pub struct ReposUpdateRequest {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub visibility: Option<ReposCreateInOrgRequestVisibility>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -27056,6 +28877,10 @@ pub struct ActionsListArtifactsRepoResponse {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_count: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -27067,6 +28892,10 @@ pub struct ActionsSetGithubPermissionsRepositoryRequest {
     pub allowed_actions: Option<AllowedActions>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub enabled: Option<bool>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -27083,6 +28912,10 @@ pub struct ActionsListWorkflowRunsResponse {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub workflow_runs: Vec<WorkflowRun>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -27097,8 +28930,9 @@ pub enum ActionsListJobsWorkflowRunFilter {
     #[serde(rename = "latest")]
     #[default]
     Latest,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ActionsListJobsWorkflowRunFilter {
@@ -27106,7 +28940,7 @@ impl std::fmt::Display for ActionsListJobsWorkflowRunFilter {
         match self {
             ActionsListJobsWorkflowRunFilter::All => "all",
             ActionsListJobsWorkflowRunFilter::Latest => "latest",
-            ActionsListJobsWorkflowRunFilter::FallthroughString => "*",
+            ActionsListJobsWorkflowRunFilter::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -27126,6 +28960,10 @@ pub struct ActionsListJobsWorkflowRunResponse {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_count: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -27150,6 +28988,10 @@ pub struct ActionsReviewPendingDeploymentsRunRequest {
      */
     #[serde(default, skip_serializing_if = "EnvironmentApprovalState::is_noop")]
     pub state: EnvironmentApprovalState,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -27166,6 +29008,10 @@ pub struct ActionsListRepoSecretsResponse {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_count: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -27182,6 +29028,10 @@ pub struct ActionsCreateUpdateRepoSecretRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub key_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -27198,6 +29048,10 @@ pub struct ActionsListRepoWorkflowsResponse {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub workflows: Vec<Workflow>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -27215,6 +29069,10 @@ pub struct ActionsCreateWorkflowDispatchRequest {
         rename = "ref"
     )]
     pub ref_: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -27231,6 +29089,10 @@ pub struct ReposCreateAutolinkRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url_template: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Require status checks to pass before merging. Set to `null` to disable.
@@ -27250,6 +29112,10 @@ pub struct ReposUpdateBranchProtectionRequestRequiredStatusChecks {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub strict: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Specify which users and teams can dismiss pull request reviews. Pass an empty `dismissal_restrictions` object to disable. User and team `dismissal_restrictions` are only available for organization-owned repositories. Omit this parameter for personal repositories.
@@ -27273,6 +29139,10 @@ pub struct ReposUpdateBranchProtectionRequestRequiredPullReviewsDismissalRestric
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub users: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Require at least one approving review on a pull request, before merging. Set to `null` to disable.
@@ -27309,6 +29179,10 @@ pub struct ReposUpdateBranchProtectionRequestRequiredPullReviews {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub required_approving_review_count: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Restrict who can push to the protected branch. User, app, and team `restrictions` are only available for organization-owned repositories. Set to `null` to disable.
@@ -27341,6 +29215,10 @@ pub struct Restrictions {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub users: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -27371,6 +29249,10 @@ pub struct ReposUpdateBranchProtectionRequest {
      */
     #[serde(default)]
     pub restrictions: Option<Restrictions>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -27397,6 +29279,10 @@ pub struct ReposUpdatePullRequestReviewProtection {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub required_approving_review_count: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -27412,6 +29298,10 @@ pub struct ReposUpdateStatusCheckProtectionRequest {
     pub contexts: Vec<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub strict: Option<bool>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -27425,6 +29315,10 @@ pub struct ReposAddStatusCheckContextsRequest {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub contexts: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types:
@@ -27487,6 +29381,10 @@ pub struct ReposAddAppAccessRestrictionsRequest {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub apps: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types:
@@ -27550,6 +29448,10 @@ pub struct ReposAddTeamAccessRestrictionsRequest {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub teams: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types:
@@ -27613,6 +29515,10 @@ pub struct ReposAddUserAccessRestrictionsRequest {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub users: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types:
@@ -27673,6 +29579,10 @@ pub struct ReposRenameBranchRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub new_name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -27700,8 +29610,9 @@ pub enum ChecksCreateRequestConclusion {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ChecksCreateRequestConclusion {
@@ -27716,7 +29627,7 @@ impl std::fmt::Display for ChecksCreateRequestConclusion {
             ChecksCreateRequestConclusion::Success => "success",
             ChecksCreateRequestConclusion::TimedOut => "timed_out",
             ChecksCreateRequestConclusion::Noop => "",
-            ChecksCreateRequestConclusion::FallthroughString => "*",
+            ChecksCreateRequestConclusion::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -27742,8 +29653,9 @@ pub enum AnnotationLevel {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AnnotationLevel {
@@ -27753,7 +29665,7 @@ impl std::fmt::Display for AnnotationLevel {
             AnnotationLevel::Notice => "notice",
             AnnotationLevel::Warning => "warning",
             AnnotationLevel::Noop => "",
-            AnnotationLevel::FallthroughString => "*",
+            AnnotationLevel::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -27820,6 +29732,10 @@ pub struct Annotations {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub title: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -27842,6 +29758,10 @@ pub struct Images {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub image_url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Check runs can accept a variety of data in the `output` object, including a `title` and `summary` and can optionally provide descriptive details about the run. See the [`output` object](https://docs.github.com/rest/reference/checks#output-object) description.
@@ -27883,6 +29803,10 @@ pub struct ChecksCreateRequestOutput {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub title: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -27905,6 +29829,10 @@ pub struct ChecksCreateRequestActions {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub label: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -27970,6 +29898,10 @@ pub struct ChecksCreateRequest {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub status: Option<JobStatus>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Check runs can accept a variety of data in the `output` object, including a `title` and `summary` and can optionally provide descriptive details about the run. See the [`output` object](https://docs.github.com/rest/reference/checks#output-object-1) description.
@@ -28011,6 +29943,10 @@ pub struct ChecksUpdateRequestOutput {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub title: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -28070,6 +30006,10 @@ pub struct ChecksUpdateRequest {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub status: Option<JobStatus>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -28080,6 +30020,10 @@ pub struct ChecksCreateSuiteRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub head_sha: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -28096,6 +30040,10 @@ pub struct ChecksListRefResponse {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_count: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -28109,6 +30057,10 @@ pub struct CodeScanningUpdateAlertRequest {
      * Sets the state of the code scanning alert. Can be one of `open` or `dismissed`. You must provide `dismissed_reason` when you set the state to `dismissed`.
      */
     pub state: CodeScanningAlertSetState,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -28150,6 +30102,10 @@ pub struct CodeScanningUploadSarifRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub tool_name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -28172,6 +30128,10 @@ pub struct ReposAddCollaboratorRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub permissions: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -28200,6 +30160,10 @@ pub struct ReposCreateCommitCommentRequest {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub position: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -28216,6 +30180,10 @@ pub struct ChecksListSuitesRefResponse {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_count: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types:
@@ -28317,6 +30285,10 @@ pub struct ReposCreateUpdateFileContentsRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub sha: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -28343,6 +30315,10 @@ pub struct ReposDeleteFileRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub sha: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -28389,6 +30365,10 @@ pub struct ReposCreateDeploymentRequest {
     pub task: String,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub transient_environment: Option<bool>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -28405,8 +30385,9 @@ pub enum ReposCreateDeploymentStatusRequestEnvironment {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ReposCreateDeploymentStatusRequestEnvironment {
@@ -28416,7 +30397,7 @@ impl std::fmt::Display for ReposCreateDeploymentStatusRequestEnvironment {
             ReposCreateDeploymentStatusRequestEnvironment::Qa => "qa",
             ReposCreateDeploymentStatusRequestEnvironment::Staging => "staging",
             ReposCreateDeploymentStatusRequestEnvironment::Noop => "",
-            ReposCreateDeploymentStatusRequestEnvironment::FallthroughString => "*",
+            ReposCreateDeploymentStatusRequestEnvironment::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -28466,6 +30447,10 @@ pub struct ReposCreateDeploymentStatusRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub target_url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -28478,6 +30463,10 @@ pub struct ReposCreateDispatchEventRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub event_type: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -28494,6 +30483,10 @@ pub struct ReposGetAllEnvironmentsResponse {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_count: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -28509,6 +30502,10 @@ pub struct ReposCreateUpdateEnvironmentRequestReviewers {
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
     pub type_: Option<DeploymentReviewerType>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -28533,6 +30530,10 @@ pub struct ReposCreateUpdateEnvironmentRequest {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub wait_timer: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -28549,8 +30550,9 @@ pub enum ReposListForksSort {
     Stargazers,
     #[serde(rename = "watchers")]
     Watchers,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ReposListForksSort {
@@ -28560,7 +30562,7 @@ impl std::fmt::Display for ReposListForksSort {
             ReposListForksSort::Oldest => "oldest",
             ReposListForksSort::Stargazers => "stargazers",
             ReposListForksSort::Watchers => "watchers",
-            ReposListForksSort::FallthroughString => "*",
+            ReposListForksSort::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -28574,6 +30576,10 @@ pub struct ReposCreateForkRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub organization: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -28590,6 +30596,10 @@ pub struct GitCreateBlobRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub encoding: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Information about the author of the commit. By default, the `author` will be the authenticated user and the current date. See the `author` and `committer` object below for details.
@@ -28616,6 +30626,10 @@ pub struct GitCreateCommitRequestAuthor {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Information about the person who is making the commit. By default, `committer` will use the information set in `author`. See the `author` and `committer` object below for details.
@@ -28642,6 +30656,10 @@ pub struct GitCreateCommitRequestCommitter {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -28683,6 +30701,10 @@ pub struct GitCreateCommitRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub tree: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -28706,6 +30728,10 @@ pub struct GitCreateRefRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub sha: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -28718,6 +30744,10 @@ pub struct GitUpdateRefRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub sha: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -28734,8 +30764,9 @@ pub enum GitCreateTagRequestType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GitCreateTagRequestType {
@@ -28745,7 +30776,7 @@ impl std::fmt::Display for GitCreateTagRequestType {
             GitCreateTagRequestType::Commit => "commit",
             GitCreateTagRequestType::Tree => "tree",
             GitCreateTagRequestType::Noop => "",
-            GitCreateTagRequestType::FallthroughString => "*",
+            GitCreateTagRequestType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -28781,6 +30812,10 @@ pub struct GitCreateTagRequestTagger {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -28817,6 +30852,10 @@ pub struct GitCreateTagRequest {
         rename = "type"
     )]
     pub type_: GitCreateTagRequestType,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -28837,8 +30876,9 @@ pub enum GitCreateTreeRequestMode {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GitCreateTreeRequestMode {
@@ -28850,7 +30890,7 @@ impl std::fmt::Display for GitCreateTreeRequestMode {
             GitCreateTreeRequestMode::SymlinkPathBlob => "120000",
             GitCreateTreeRequestMode::SubmoduleCommit => "160000",
             GitCreateTreeRequestMode::Noop => "",
-            GitCreateTreeRequestMode::FallthroughString => "*",
+            GitCreateTreeRequestMode::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -28892,6 +30932,10 @@ pub struct GitCreateTreeRequest {
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
     pub type_: Option<GitCreateTagRequestType>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -28911,6 +30955,10 @@ pub struct GitCreateTreeRequestData {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub tree: Vec<GitCreateTreeRequest>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Key/value pairs to provide settings for this webhook. [These are defined below](https://docs.github.com/rest/reference/repos#create-hook-config-params).
@@ -28951,6 +30999,10 @@ pub struct ReposCreateWebhookRequestConfig {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -28971,6 +31023,10 @@ pub struct ReposCreateWebhookRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Key/value pairs to provide settings for this webhook. [These are defined below](https://docs.github.com/rest/reference/repos#create-hook-config-params).
@@ -29011,6 +31067,10 @@ pub struct ReposUpdateWebhookRequestConfig {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -29049,6 +31109,10 @@ pub struct ReposUpdateWebhookRequest {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub remove_events: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -29067,8 +31131,9 @@ pub enum Vcs {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Vcs {
@@ -29079,7 +31144,7 @@ impl std::fmt::Display for Vcs {
             Vcs::Subversion => "subversion",
             Vcs::Tfvc => "tfvc",
             Vcs::Noop => "",
-            Vcs::FallthroughString => "*",
+            Vcs::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -29122,6 +31187,10 @@ pub struct MigrationsStartImportRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub vcs_username: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -29150,6 +31219,10 @@ pub struct MigrationsUpdateImportRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub vcs_username: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -29164,8 +31237,9 @@ pub enum UseLfs {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for UseLfs {
@@ -29174,7 +31248,7 @@ impl std::fmt::Display for UseLfs {
             UseLfs::OptIn => "opt_in",
             UseLfs::OptOut => "opt_out",
             UseLfs::Noop => "",
-            UseLfs::FallthroughString => "*",
+            UseLfs::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -29193,6 +31267,10 @@ pub struct MigrationsSetLfsPreferenceRequest {
      */
     #[serde(default, skip_serializing_if = "UseLfs::is_noop")]
     pub use_lfs: UseLfs,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -29202,6 +31280,10 @@ pub struct ReposUpdateInvitationRequest {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub permissions: Option<RepositoryInvitationPermissions>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -29230,6 +31312,10 @@ pub struct LabelsDataType {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types:
@@ -29309,6 +31395,10 @@ pub struct IssuesCreateRequest {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub milestone: Option<TitleOneOf>,
     pub title: TitleOneOf,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -29352,6 +31442,10 @@ pub struct IssuesUpdateRequest {
     pub state: Option<State>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub title: Option<TitleOneOf>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -29365,6 +31459,10 @@ pub struct IssuesAddAssigneesRequest {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub assignees: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -29378,6 +31476,10 @@ pub struct IssuesAddLabelsRequest {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub labels: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -29388,6 +31490,10 @@ pub struct IssuesSetLabelsRequest {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub labels: Vec<ProjectsUpdateColumnRequest>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types:
@@ -29531,8 +31637,9 @@ pub enum LockReason {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for LockReason {
@@ -29543,7 +31650,7 @@ impl std::fmt::Display for LockReason {
             LockReason::Spam => "spam",
             LockReason::TooHeated => "too heated",
             LockReason::Noop => "",
-            LockReason::FallthroughString => "*",
+            LockReason::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -29559,6 +31666,10 @@ impl LockReason {
 pub struct IssuesLockRequest {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub lock_reason: Option<LockReason>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -29577,6 +31688,10 @@ pub struct ReposCreateDeployKeyRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub title: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -29599,6 +31714,10 @@ pub struct IssuesCreateLabelRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -29621,6 +31740,10 @@ pub struct IssuesUpdateLabelRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub new_name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -29643,6 +31766,10 @@ pub struct ReposMergeRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub head: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -29655,8 +31782,9 @@ pub enum IssuesListMilestonesSort {
     #[serde(rename = "due_on")]
     #[default]
     DueOn,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for IssuesListMilestonesSort {
@@ -29664,7 +31792,7 @@ impl std::fmt::Display for IssuesListMilestonesSort {
         match self {
             IssuesListMilestonesSort::Completeness => "completeness",
             IssuesListMilestonesSort::DueOn => "due_on",
-            IssuesListMilestonesSort::FallthroughString => "*",
+            IssuesListMilestonesSort::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -29695,6 +31823,10 @@ pub struct IssuesCreateMilestoneRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub title: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -29705,6 +31837,10 @@ pub struct ActivityMarkRepoNotificationsAsReadRequest {
         deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub last_read_at: Option<chrono::DateTime<chrono::Utc>>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -29721,6 +31857,10 @@ pub struct PullsUpdateBranchResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -29733,8 +31873,9 @@ pub enum Path {
     Root,
     #[serde(rename = "/docs")]
     Docs,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Path {
@@ -29742,7 +31883,7 @@ impl std::fmt::Display for Path {
         match self {
             Path::Root => "/",
             Path::Docs => "/docs",
-            Path::FallthroughString => "*",
+            Path::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -29762,6 +31903,10 @@ pub struct ReposCreatePagesSiteRequestSource {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub path: Option<Path>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The source branch and directory used to publish your Pages site.
@@ -29771,6 +31916,10 @@ pub struct ReposCreatePagesSiteRequest {
      * The source branch and directory used to publish your Pages site.
      */
     pub source: ReposCreatePagesSiteRequestSource,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -29787,8 +31936,9 @@ pub enum SourceData {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SourceData {
@@ -29798,7 +31948,7 @@ impl std::fmt::Display for SourceData {
             SourceData::Master => "master",
             SourceData::MasterDocs => "master /docs",
             SourceData::Noop => "",
-            SourceData::FallthroughString => "*",
+            SourceData::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -29824,6 +31974,10 @@ pub struct SourceDataType {
      */
     #[serde(default)]
     pub path: Path,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types are flattened into one object:
@@ -29859,6 +32013,10 @@ pub struct ReposUpdateInformationAboutPagesSiteRequest {
     pub public: Option<bool>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub source: Option<SourceAnyOf>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -29875,8 +32033,9 @@ pub enum PullsListSort {
     Popularity,
     #[serde(rename = "updated")]
     Updated,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PullsListSort {
@@ -29886,7 +32045,7 @@ impl std::fmt::Display for PullsListSort {
             PullsListSort::LongRunning => "long-running",
             PullsListSort::Popularity => "popularity",
             PullsListSort::Updated => "updated",
-            PullsListSort::FallthroughString => "*",
+            PullsListSort::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -29928,6 +32087,10 @@ pub struct PullsCreateRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub title: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -29941,8 +32104,9 @@ pub enum PullsListReviewCommentsRepoSort {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PullsListReviewCommentsRepoSort {
@@ -29952,7 +32116,7 @@ impl std::fmt::Display for PullsListReviewCommentsRepoSort {
             PullsListReviewCommentsRepoSort::CreatedAt => "created_at",
             PullsListReviewCommentsRepoSort::Updated => "updated",
             PullsListReviewCommentsRepoSort::Noop => "",
-            PullsListReviewCommentsRepoSort::FallthroughString => "*",
+            PullsListReviewCommentsRepoSort::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -29991,6 +32155,10 @@ pub struct PullsUpdateRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub title: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -30007,8 +32175,9 @@ pub enum PullsCreateReviewCommentRequestStartSide {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PullsCreateReviewCommentRequestStartSide {
@@ -30018,7 +32187,7 @@ impl std::fmt::Display for PullsCreateReviewCommentRequestStartSide {
             PullsCreateReviewCommentRequestStartSide::Right => "RIGHT",
             PullsCreateReviewCommentRequestStartSide::Side => "side",
             PullsCreateReviewCommentRequestStartSide::Noop => "",
-            PullsCreateReviewCommentRequestStartSide::FallthroughString => "*",
+            PullsCreateReviewCommentRequestStartSide::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -30084,6 +32253,10 @@ pub struct PullsCreateReviewCommentRequest {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub start_side: Option<PullsCreateReviewCommentRequestStartSide>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -30108,6 +32281,10 @@ pub struct PullsMergeRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub sha: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -30130,6 +32307,10 @@ pub struct PullsRequestReviewers {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub team_reviewers: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -30152,6 +32333,10 @@ pub struct PullsRemoveRequestedReviewersRequest {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub team_reviewers: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -30168,8 +32353,9 @@ pub enum PullsCreateReviewRequestEvent {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PullsCreateReviewRequestEvent {
@@ -30179,7 +32365,7 @@ impl std::fmt::Display for PullsCreateReviewRequestEvent {
             PullsCreateReviewRequestEvent::Comment => "COMMENT",
             PullsCreateReviewRequestEvent::RequestChanges => "REQUEST_CHANGES",
             PullsCreateReviewRequestEvent::Noop => "",
-            PullsCreateReviewRequestEvent::FallthroughString => "*",
+            PullsCreateReviewRequestEvent::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -30235,6 +32421,10 @@ pub struct Comments {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub start_side: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -30265,6 +32455,10 @@ pub struct PullsCreateReviewRequest {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub event: Option<PullsCreateReviewRequestEvent>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -30281,6 +32475,10 @@ pub struct PullsDismissReviewRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub message: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -30299,6 +32497,10 @@ pub struct PullsSubmitReviewRequest {
         skip_serializing_if = "PullsCreateReviewRequestEvent::is_noop"
     )]
     pub event: PullsCreateReviewRequestEvent,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -30309,6 +32511,10 @@ pub struct PullsUpdateBranchRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub expected_head_sha: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -30347,6 +32553,10 @@ pub struct ReposCreateReleaseRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub target_commitish: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -30369,6 +32579,10 @@ pub struct ReposUpdateReleaseAssetRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub state: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -30391,8 +32605,9 @@ pub enum ReactionsCreateReleaseRequestContent {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ReactionsCreateReleaseRequestContent {
@@ -30405,7 +32620,7 @@ impl std::fmt::Display for ReactionsCreateReleaseRequestContent {
             ReactionsCreateReleaseRequestContent::Laugh => "laugh",
             ReactionsCreateReleaseRequestContent::Rocket => "rocket",
             ReactionsCreateReleaseRequestContent::Noop => "",
-            ReactionsCreateReleaseRequestContent::FallthroughString => "*",
+            ReactionsCreateReleaseRequestContent::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -30427,6 +32642,10 @@ pub struct ReactionsCreateReleaseRequest {
         skip_serializing_if = "ReactionsCreateReleaseRequestContent::is_noop"
     )]
     pub content: ReactionsCreateReleaseRequestContent,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -30440,6 +32659,10 @@ pub struct SecretScanningUpdateAlertRequest {
      * Sets the state of the secret scanning alert. Can be either `open` or `resolved`. You must provide `resolution` when you set the state to `resolved`.
      */
     pub state: SecretScanningAlertState,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types are flattened into one object:
@@ -30471,8 +32694,9 @@ pub enum ReposCreateCommitStatusRequestState {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ReposCreateCommitStatusRequestState {
@@ -30483,7 +32707,7 @@ impl std::fmt::Display for ReposCreateCommitStatusRequestState {
             ReposCreateCommitStatusRequestState::Pending => "pending",
             ReposCreateCommitStatusRequestState::Success => "success",
             ReposCreateCommitStatusRequestState::Noop => "",
-            ReposCreateCommitStatusRequestState::FallthroughString => "*",
+            ReposCreateCommitStatusRequestState::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -30523,6 +32747,10 @@ pub struct ReposCreateCommitStatusRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub target_url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -30531,6 +32759,10 @@ pub struct ActivitySetRepoSubscriptionRequest {
     pub ignored: Option<bool>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub subscribed: Option<bool>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -30550,6 +32782,10 @@ pub struct ReposTransferRequest {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub team_ids: Vec<i64>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -30576,6 +32812,10 @@ pub struct ReposCreateUsingTemplateRequest {
     pub owner: String,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub private: Option<bool>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -30602,6 +32842,10 @@ pub struct EnterpriseAdminProvisionInviteGroupRequest {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub schemas: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -30615,8 +32859,9 @@ pub enum EnterpriseAdminUpdateAttributeGroupRequestOperationsOp {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EnterpriseAdminUpdateAttributeGroupRequestOperationsOp {
@@ -30626,7 +32871,7 @@ impl std::fmt::Display for EnterpriseAdminUpdateAttributeGroupRequestOperationsO
             EnterpriseAdminUpdateAttributeGroupRequestOperationsOp::Remove => "Remove",
             EnterpriseAdminUpdateAttributeGroupRequestOperationsOp::Replace => "Replace",
             EnterpriseAdminUpdateAttributeGroupRequestOperationsOp::Noop => "",
-            EnterpriseAdminUpdateAttributeGroupRequestOperationsOp::FallthroughString => "*",
+            EnterpriseAdminUpdateAttributeGroupRequestOperationsOp::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -30656,6 +32901,10 @@ pub struct EnterpriseAdminUpdateAttributeGroupRequestOperations {
     pub path: String,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub value: Option<ScimUserOperationsValueOneOf>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -30679,6 +32928,10 @@ pub struct EnterpriseAdminUpdateAttributeGroupRequest {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub schemas: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -30701,6 +32954,10 @@ pub struct EnterpriseAdminProvisionInviteUserRequestEmails {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub value: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -30737,6 +32994,10 @@ pub struct EnterpriseAdminProvisionInviteUserRequest {
         rename = "userName"
     )]
     pub user_name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -30760,6 +33021,10 @@ pub struct EnterpriseAdminUpdateAttributeUserRequest {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub schemas: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -30812,6 +33077,10 @@ pub struct ScimProvisionInviteUserRequest {
         rename = "userName"
     )]
     pub user_name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -30849,6 +33118,10 @@ pub struct Value {
         rename = "userName"
     )]
     pub user_name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types:
@@ -30934,6 +33207,10 @@ pub struct ScimUpdateAttributeUserRequestOperations {
     pub path: String,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub value: Option<ScimUpdateAttributeUserRequestOperationsValueOneOf>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -30957,6 +33234,10 @@ pub struct ScimUpdateAttributeUserRequest {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub schemas: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -30969,8 +33250,9 @@ pub enum SearchCodeSort {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SearchCodeSort {
@@ -30978,7 +33260,7 @@ impl std::fmt::Display for SearchCodeSort {
         match self {
             SearchCodeSort::Indexed => "indexed",
             SearchCodeSort::Noop => "",
-            SearchCodeSort::FallthroughString => "*",
+            SearchCodeSort::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -31009,6 +33291,10 @@ pub struct SearchCodeResponse {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_count: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -31023,8 +33309,9 @@ pub enum SearchCommitsSort {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SearchCommitsSort {
@@ -31033,7 +33320,7 @@ impl std::fmt::Display for SearchCommitsSort {
             SearchCommitsSort::AuthorDate => "author-date",
             SearchCommitsSort::CommitterDate => "committer-date",
             SearchCommitsSort::Noop => "",
-            SearchCommitsSort::FallthroughString => "*",
+            SearchCommitsSort::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -31064,6 +33351,10 @@ pub struct SearchCommitsResponse {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_count: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -31096,8 +33387,9 @@ pub enum SearchIssuesPullRequestsSort {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SearchIssuesPullRequestsSort {
@@ -31115,7 +33407,7 @@ impl std::fmt::Display for SearchIssuesPullRequestsSort {
             SearchIssuesPullRequestsSort::ReactionsThinkingFace => "reactions-thinking_face",
             SearchIssuesPullRequestsSort::Updated => "updated",
             SearchIssuesPullRequestsSort::Noop => "",
-            SearchIssuesPullRequestsSort::FallthroughString => "*",
+            SearchIssuesPullRequestsSort::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -31146,6 +33438,10 @@ pub struct SearchIssuesPullRequestsResponse {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_count: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -31167,6 +33463,10 @@ pub struct SearchLabelsResponse {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_count: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -31185,8 +33485,9 @@ pub enum SearchReposSort {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SearchReposSort {
@@ -31197,7 +33498,7 @@ impl std::fmt::Display for SearchReposSort {
             SearchReposSort::Stars => "stars",
             SearchReposSort::Updated => "updated",
             SearchReposSort::Noop => "",
-            SearchReposSort::FallthroughString => "*",
+            SearchReposSort::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -31228,6 +33529,10 @@ pub struct SearchReposResponse {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_count: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -31249,6 +33554,10 @@ pub struct SearchTopicsResponse {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_count: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -31265,8 +33574,9 @@ pub enum SearchUsersSort {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SearchUsersSort {
@@ -31276,7 +33586,7 @@ impl std::fmt::Display for SearchUsersSort {
             SearchUsersSort::Joined => "joined",
             SearchUsersSort::Repositories => "repositories",
             SearchUsersSort::Noop => "",
-            SearchUsersSort::FallthroughString => "*",
+            SearchUsersSort::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -31307,6 +33617,10 @@ pub struct SearchUsersResponse {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_count: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -31316,6 +33630,10 @@ pub struct TeamsAddUpdateProjectPermissionsLegacyRequest {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub permission: Option<RepositoryProjects>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -31328,6 +33646,10 @@ pub struct TeamsAddUpdateRepoPermissionsLegacyRequest {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub permission: Option<Permission>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -31368,6 +33690,10 @@ pub struct TeamsCreateUpdateIdpGroupConnectionsLegacyRequestGroups {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -31387,6 +33713,10 @@ pub struct TeamsCreateUpdateIdpGroupConnectionsLegacyRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub synced_at: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types:
@@ -31471,12 +33801,20 @@ pub struct UsersUpdateAuthenticatedRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub twitter_username: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UsersSetPrimaryEmailVisibilityAuthenticatedRequest {
     #[serde(default, skip_serializing_if = "PackageVisibility::is_noop")]
     pub visibility: PackageVisibility,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -31490,6 +33828,10 @@ pub struct UsersAddEmailAuthenticatedRequest {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub emails: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types:
@@ -31570,6 +33912,10 @@ pub struct UsersCreateGpgKeyAuthenticatedRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub armored_public_key: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -31586,6 +33932,10 @@ pub struct UsersCreatePublicSshKeyAuthenticatedRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub title: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -31598,8 +33948,9 @@ pub enum OrgsUpdateMembershipRequestState {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OrgsUpdateMembershipRequestState {
@@ -31607,7 +33958,7 @@ impl std::fmt::Display for OrgsUpdateMembershipRequestState {
         match self {
             OrgsUpdateMembershipRequestState::Active => "active",
             OrgsUpdateMembershipRequestState::Noop => "",
-            OrgsUpdateMembershipRequestState::FallthroughString => "*",
+            OrgsUpdateMembershipRequestState::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -31629,6 +33980,10 @@ pub struct OrgsUpdateMembershipRequest {
         skip_serializing_if = "OrgsUpdateMembershipRequestState::is_noop"
     )]
     pub state: OrgsUpdateMembershipRequestState,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -31643,8 +33998,9 @@ pub enum ReposListVisibility {
     Private,
     #[serde(rename = "public")]
     Public,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ReposListVisibility {
@@ -31653,7 +34009,7 @@ impl std::fmt::Display for ReposListVisibility {
             ReposListVisibility::All => "all",
             ReposListVisibility::Private => "private",
             ReposListVisibility::Public => "public",
-            ReposListVisibility::FallthroughString => "*",
+            ReposListVisibility::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -31677,8 +34033,9 @@ pub enum ReposListType {
     Private,
     #[serde(rename = "public")]
     Public,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ReposListType {
@@ -31689,7 +34046,7 @@ impl std::fmt::Display for ReposListType {
             ReposListType::Owner => "owner",
             ReposListType::Private => "private",
             ReposListType::Public => "public",
-            ReposListType::FallthroughString => "*",
+            ReposListType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -31757,6 +34114,10 @@ pub struct ReposCreateRequest {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub team_id: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -31775,8 +34136,9 @@ pub enum SubjectType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SubjectType {
@@ -31787,7 +34149,7 @@ impl std::fmt::Display for SubjectType {
             SubjectType::PullRequest => "pull_request",
             SubjectType::Repository => "repository",
             SubjectType::Noop => "",
-            SubjectType::FallthroughString => "*",
+            SubjectType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -31811,8 +34173,9 @@ pub enum ReposListUserType {
     #[serde(rename = "owner")]
     #[default]
     Owner,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ReposListUserType {
@@ -31821,7 +34184,7 @@ impl std::fmt::Display for ReposListUserType {
             ReposListUserType::All => "all",
             ReposListUserType::Member => "member",
             ReposListUserType::Owner => "owner",
-            ReposListUserType::FallthroughString => "*",
+            ReposListUserType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1,6 +1,659 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Users::list_emails_for_authenticated`], set with a builder.
+pub struct UsersListEmailsForAuthenticatedRequest<'a> {
+    client: &'a Users,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> UsersListEmailsForAuthenticatedRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Email>>> {
+        let Self {
+            client,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(&format!("/user/emails?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Users::list_followers_for_authenticated_user`], set with a builder.
+pub struct UsersListFollowersForAuthenticatedUserRequest<'a> {
+    client: &'a Users,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> UsersListFollowersForAuthenticatedUserRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::SimpleUser>>> {
+        let Self {
+            client,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/user/followers?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Users::list_followed_by_authenticated`], set with a builder.
+pub struct UsersListFollowedByAuthenticatedRequest<'a> {
+    client: &'a Users,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> UsersListFollowedByAuthenticatedRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::SimpleUser>>> {
+        let Self {
+            client,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/user/following?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Users::list_gpg_keys_for_authenticated`], set with a builder.
+pub struct UsersListGpgKeysForAuthenticatedRequest<'a> {
+    client: &'a Users,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> UsersListGpgKeysForAuthenticatedRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::GpgKey>>> {
+        let Self {
+            client,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/user/gpg_keys?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Users::list_public_ssh_keys_for_authenticated`], set with a builder.
+pub struct UsersListPublicSshKeysForAuthenticatedRequest<'a> {
+    client: &'a Users,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> UsersListPublicSshKeysForAuthenticatedRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Key>>> {
+        let Self {
+            client,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(&format!("/user/keys?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Users::list_public_emails_for_authenticated`], set with a builder.
+pub struct UsersListPublicEmailsForAuthenticatedRequest<'a> {
+    client: &'a Users,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> UsersListPublicEmailsForAuthenticatedRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Email>>> {
+        let Self {
+            client,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/user/public_emails?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Users::list`], set with a builder.
+pub struct UsersListRequest<'a> {
+    client: &'a Users,
+    since: Option<i64>,
+    per_page: Option<i64>,
+}
+
+impl<'a> UsersListRequest<'a> {
+    /// Sets the `since` query parameter.
+    pub fn since(mut self, since: i64) -> Self {
+        self.since = Some(since);
+        self
+    }
+
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::SimpleUser>>> {
+        let Self {
+            client,
+            since,
+            per_page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        if let Some(v) = since {
+            query_args.push(("since".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(&format!("/users?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Users::list_followers_for_user`], set with a builder.
+pub struct UsersListFollowersForUserRequest<'a> {
+    client: &'a Users,
+    username: &'a str,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> UsersListFollowersForUserRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::SimpleUser>>> {
+        let Self {
+            client,
+            username,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/users/{}/followers?{}",
+                crate::progenitor_support::encode_path(username),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Users::list_following_for_user`], set with a builder.
+pub struct UsersListFollowingForUserRequest<'a> {
+    client: &'a Users,
+    username: &'a str,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> UsersListFollowingForUserRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::SimpleUser>>> {
+        let Self {
+            client,
+            username,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/users/{}/following?{}",
+                crate::progenitor_support::encode_path(username),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Users::list_gpg_keys_for_user`], set with a builder.
+pub struct UsersListGpgKeysForUserRequest<'a> {
+    client: &'a Users,
+    username: &'a str,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> UsersListGpgKeysForUserRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::GpgKey>>> {
+        let Self {
+            client,
+            username,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/users/{}/gpg_keys?{}",
+                crate::progenitor_support::encode_path(username),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Users::get_context_for_user`], set with a builder.
+pub struct UsersGetContextForUserRequest<'a> {
+    client: &'a Users,
+    username: &'a str,
+    subject_type: Option<crate::types::SubjectType>,
+    subject_id: Option<&'a str>,
+}
+
+impl<'a> UsersGetContextForUserRequest<'a> {
+    /// Sets the `subject_type` query parameter.
+    pub fn subject_type(mut self, subject_type: crate::types::SubjectType) -> Self {
+        self.subject_type = Some(subject_type);
+        self
+    }
+
+    /// Sets the `subject_id` query parameter.
+    pub fn subject_id(mut self, subject_id: &'a str) -> Self {
+        self.subject_id = Some(subject_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::Hovercard>> {
+        let Self {
+            client,
+            username,
+            subject_type,
+            subject_id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = subject_id {
+            query_args.push(("subject_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = subject_type {
+            query_args.push(("subject_type".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/users/{}/hovercard?{}",
+                crate::progenitor_support::encode_path(username),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Users::list_public_keys_for_user`], set with a builder.
+pub struct UsersListPublicKeysForUserRequest<'a> {
+    client: &'a Users,
+    username: &'a str,
+    per_page: Option<i64>,
+    page: Option<i64>,
+}
+
+impl<'a> UsersListPublicKeysForUserRequest<'a> {
+    /// Sets the `per_page` query parameter.
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::KeySimple>>> {
+        let Self {
+            client,
+            username,
+            per_page,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per_page {
+            query_args.push(("per_page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/users/{}/keys?{}",
+                crate::progenitor_support::encode_path(username),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct Users {
     pub client: Client,
 }
@@ -158,6 +811,23 @@ impl Users {
             )
             .await
     }
+    /**
+     * List users blocked by the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/blocks` endpoint.
+     *
+     * As opposed to `list_all_blocked_by_authenticated`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * List the users you've blocked on your personal account.
+     *
+     * FROM: <https://docs.github.com/rest/reference/users#list-users-blocked-by-the-authenticated-user>
+     */
+    pub fn list_all_blocked_by_authenticated_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::SimpleUser>> + Unpin + '_ {
+        let url = self.client.url("/user/blocks", None);
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Check if a user is blocked by the authenticated user.
      *
@@ -313,6 +983,26 @@ impl Users {
             )
             .await
     }
+    /**
+     * List email addresses for the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/emails` endpoint.
+     *
+     * As opposed to `list_emails_for_authenticated`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Lists all of your email addresses, and specifies which one is visible to the public. This endpoint is accessible with the `user:email` scope.
+     *
+     * FROM: <https://docs.github.com/rest/reference/users#list-email-addresses-for-the-authenticated-user>
+     */
+    pub fn list_emails_for_authenticated_request(
+        &self,
+    ) -> UsersListEmailsForAuthenticatedRequest<'_> {
+        UsersListEmailsForAuthenticatedRequest {
+            client: self,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List email addresses for the authenticated user.
      *
@@ -338,6 +1028,23 @@ impl Users {
             )
             .await
     }
+    /**
+     * List email addresses for the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/emails` endpoint.
+     *
+     * As opposed to `list_all_emails_for_authenticated`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Lists all of your email addresses, and specifies which one is visible to the public. This endpoint is accessible with the `user:email` scope.
+     *
+     * FROM: <https://docs.github.com/rest/reference/users#list-email-addresses-for-the-authenticated-user>
+     */
+    pub fn list_all_emails_for_authenticated_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Email>> + Unpin + '_ {
+        let url = self.client.url("/user/emails", None);
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Add an email address for the authenticated user.
      *
@@ -426,6 +1133,26 @@ impl Users {
             )
             .await
     }
+    /**
+     * List followers of the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/followers` endpoint.
+     *
+     * As opposed to `list_followers_for_authenticated_user`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Lists the people following the authenticated user.
+     *
+     * FROM: <https://docs.github.com/rest/reference/users#list-followers-of-the-authenticated-user>
+     */
+    pub fn list_followers_for_authenticated_user_request(
+        &self,
+    ) -> UsersListFollowersForAuthenticatedUserRequest<'_> {
+        UsersListFollowersForAuthenticatedUserRequest {
+            client: self,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List followers of the authenticated user.
      *
@@ -451,6 +1178,23 @@ impl Users {
             )
             .await
     }
+    /**
+     * List followers of the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/followers` endpoint.
+     *
+     * As opposed to `list_all_followers_for_authenticated_user`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Lists the people following the authenticated user.
+     *
+     * FROM: <https://docs.github.com/rest/reference/users#list-followers-of-the-authenticated-user>
+     */
+    pub fn list_all_followers_for_authenticated_user_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::SimpleUser>> + Unpin + '_ {
+        let url = self.client.url("/user/followers", None);
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * List the people the authenticated user follows.
      *
@@ -491,6 +1235,26 @@ impl Users {
             )
             .await
     }
+    /**
+     * List the people the authenticated user follows.
+     *
+     * This function performs a `GET` to the `/user/following` endpoint.
+     *
+     * As opposed to `list_followed_by_authenticated`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Lists the people who the authenticated user follows.
+     *
+     * FROM: <https://docs.github.com/rest/reference/users#list-the-people-the-authenticated-user-follows>
+     */
+    pub fn list_followed_by_authenticated_request(
+        &self,
+    ) -> UsersListFollowedByAuthenticatedRequest<'_> {
+        UsersListFollowedByAuthenticatedRequest {
+            client: self,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List the people the authenticated user follows.
      *
@@ -516,6 +1280,23 @@ impl Users {
             )
             .await
     }
+    /**
+     * List the people the authenticated user follows.
+     *
+     * This function performs a `GET` to the `/user/following` endpoint.
+     *
+     * As opposed to `list_all_followed_by_authenticated`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Lists the people who the authenticated user follows.
+     *
+     * FROM: <https://docs.github.com/rest/reference/users#list-the-people-the-authenticated-user-follows>
+     */
+    pub fn list_all_followed_by_authenticated_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::SimpleUser>> + Unpin + '_ {
+        let url = self.client.url("/user/following", None);
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Check if a person is followed by the authenticated user.
      *
@@ -652,6 +1433,26 @@ impl Users {
             )
             .await
     }
+    /**
+     * List GPG keys for the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/gpg_keys` endpoint.
+     *
+     * As opposed to `list_gpg_keys_for_authenticated`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Lists the current user's GPG keys. Requires that you are authenticated via Basic Auth or via OAuth with at least `read:gpg_key` [scope](https://docs.github.com/apps/building-oauth-apps/understanding-scopes-for-oauth-apps/).
+     *
+     * FROM: <https://docs.github.com/rest/reference/users#list-gpg-keys-for-the-authenticated-user>
+     */
+    pub fn list_gpg_keys_for_authenticated_request(
+        &self,
+    ) -> UsersListGpgKeysForAuthenticatedRequest<'_> {
+        UsersListGpgKeysForAuthenticatedRequest {
+            client: self,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List GPG keys for the authenticated user.
      *
@@ -677,6 +1478,23 @@ impl Users {
             )
             .await
     }
+    /**
+     * List GPG keys for the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/gpg_keys` endpoint.
+     *
+     * As opposed to `list_all_gpg_keys_for_authenticated`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Lists the current user's GPG keys. Requires that you are authenticated via Basic Auth or via OAuth with at least `read:gpg_key` [scope](https://docs.github.com/apps/building-oauth-apps/understanding-scopes-for-oauth-apps/).
+     *
+     * FROM: <https://docs.github.com/rest/reference/users#list-gpg-keys-for-the-authenticated-user>
+     */
+    pub fn list_all_gpg_keys_for_authenticated_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::GpgKey>> + Unpin + '_ {
+        let url = self.client.url("/user/gpg_keys", None);
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Create a GPG key for the authenticated user.
      *
@@ -807,6 +1625,26 @@ impl Users {
             )
             .await
     }
+    /**
+     * List public SSH keys for the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/keys` endpoint.
+     *
+     * As opposed to `list_public_ssh_keys_for_authenticated`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Lists the public SSH keys for the authenticated user's GitHub account. Requires that you are authenticated via Basic Auth or via OAuth with at least `read:public_key` [scope](https://docs.github.com/apps/building-oauth-apps/understanding-scopes-for-oauth-apps/).
+     *
+     * FROM: <https://docs.github.com/rest/reference/users#list-public-ssh-keys-for-the-authenticated-user>
+     */
+    pub fn list_public_ssh_keys_for_authenticated_request(
+        &self,
+    ) -> UsersListPublicSshKeysForAuthenticatedRequest<'_> {
+        UsersListPublicSshKeysForAuthenticatedRequest {
+            client: self,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List public SSH keys for the authenticated user.
      *
@@ -832,6 +1670,23 @@ impl Users {
             )
             .await
     }
+    /**
+     * List public SSH keys for the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/keys` endpoint.
+     *
+     * As opposed to `list_all_public_ssh_keys_for_authenticated`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Lists the public SSH keys for the authenticated user's GitHub account. Requires that you are authenticated via Basic Auth or via OAuth with at least `read:public_key` [scope](https://docs.github.com/apps/building-oauth-apps/understanding-scopes-for-oauth-apps/).
+     *
+     * FROM: <https://docs.github.com/rest/reference/users#list-public-ssh-keys-for-the-authenticated-user>
+     */
+    pub fn list_all_public_ssh_keys_for_authenticated_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Key>> + Unpin + '_ {
+        let url = self.client.url("/user/keys", None);
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Create a public SSH key for the authenticated user.
      *
@@ -964,6 +1819,26 @@ impl Users {
             )
             .await
     }
+    /**
+     * List public email addresses for the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/public_emails` endpoint.
+     *
+     * As opposed to `list_public_emails_for_authenticated`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Lists your publicly visible email address, which you can set with the [Set primary email visibility for the authenticated user](https://docs.github.com/rest/reference/users#set-primary-email-visibility-for-the-authenticated-user) endpoint. This endpoint is accessible with the `user:email` scope.
+     *
+     * FROM: <https://docs.github.com/rest/reference/users#list-public-email-addresses-for-the-authenticated-user>
+     */
+    pub fn list_public_emails_for_authenticated_request(
+        &self,
+    ) -> UsersListPublicEmailsForAuthenticatedRequest<'_> {
+        UsersListPublicEmailsForAuthenticatedRequest {
+            client: self,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List public email addresses for the authenticated user.
      *
@@ -989,6 +1864,23 @@ impl Users {
             )
             .await
     }
+    /**
+     * List public email addresses for the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/public_emails` endpoint.
+     *
+     * As opposed to `list_all_public_emails_for_authenticated`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Lists your publicly visible email address, which you can set with the [Set primary email visibility for the authenticated user](https://docs.github.com/rest/reference/users#set-primary-email-visibility-for-the-authenticated-user) endpoint. This endpoint is accessible with the `user:email` scope.
+     *
+     * FROM: <https://docs.github.com/rest/reference/users#list-public-email-addresses-for-the-authenticated-user>
+     */
+    pub fn list_all_public_emails_for_authenticated_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Email>> + Unpin + '_ {
+        let url = self.client.url("/user/public_emails", None);
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * List users.
      *
@@ -1029,6 +1921,26 @@ impl Users {
             )
             .await
     }
+    /**
+     * List users.
+     *
+     * This function performs a `GET` to the `/users` endpoint.
+     *
+     * As opposed to `list`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Lists all users, in the order that they signed up on GitHub. This list includes personal user accounts and organization accounts.
+     *
+     * Note: Pagination is powered exclusively by the `since` parameter. Use the [Link header](https://docs.github.com/rest/overview/resources-in-the-rest-api#link-header) to get the URL for the next page of users.
+     *
+     * FROM: <https://docs.github.com/rest/reference/users#list-users>
+     */
+    pub fn list_request(&self) -> UsersListRequest<'_> {
+        UsersListRequest {
+            client: self,
+            since: None,
+            per_page: None,
+        }
+    }
     /**
      * List users.
      *
@@ -1062,6 +1974,31 @@ impl Users {
             )
             .await
     }
+    /**
+     * List users.
+     *
+     * This function performs a `GET` to the `/users` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Lists all users, in the order that they signed up on GitHub. This list includes personal user accounts and organization accounts.
+     *
+     * Note: Pagination is powered exclusively by the `since` parameter. Use the [Link header](https://docs.github.com/rest/overview/resources-in-the-rest-api#link-header) to get the URL for the next page of users.
+     *
+     * FROM: <https://docs.github.com/rest/reference/users#list-users>
+     */
+    pub fn list_all_stream(
+        &self,
+        since: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::SimpleUser>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if since > 0 {
+            query_args.push(("since".to_string(), since.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/users?{}", query_), None);
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Get a user.
      *
@@ -1229,6 +2166,28 @@ impl Users {
             )
             .await
     }
+    /**
+     * List followers of a user.
+     *
+     * This function performs a `GET` to the `/users/{username}/followers` endpoint.
+     *
+     * As opposed to `list_followers_for_user`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Lists the people following the specified user.
+     *
+     * FROM: <https://docs.github.com/rest/reference/users#list-followers-of-a-user>
+     */
+    pub fn list_followers_for_user_request<'a>(
+        &'a self,
+        username: &'a str,
+    ) -> UsersListFollowersForUserRequest<'a> {
+        UsersListFollowersForUserRequest {
+            client: self,
+            username,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List followers of a user.
      *
@@ -1261,6 +2220,30 @@ impl Users {
             )
             .await
     }
+    /**
+     * List followers of a user.
+     *
+     * This function performs a `GET` to the `/users/{username}/followers` endpoint.
+     *
+     * As opposed to `list_all_followers_for_user`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Lists the people following the specified user.
+     *
+     * FROM: <https://docs.github.com/rest/reference/users#list-followers-of-a-user>
+     */
+    pub fn list_all_followers_for_user_stream(
+        &self,
+        username: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::SimpleUser>> + Unpin + '_ {
+        let url = self.client.url(
+            &format!(
+                "/users/{}/followers",
+                crate::progenitor_support::encode_path(username),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * List the people a user follows.
      *
@@ -1308,6 +2291,28 @@ impl Users {
             )
             .await
     }
+    /**
+     * List the people a user follows.
+     *
+     * This function performs a `GET` to the `/users/{username}/following` endpoint.
+     *
+     * As opposed to `list_following_for_user`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Lists the people who the specified user follows.
+     *
+     * FROM: <https://docs.github.com/rest/reference/users#list-the-people-a-user-follows>
+     */
+    pub fn list_following_for_user_request<'a>(
+        &'a self,
+        username: &'a str,
+    ) -> UsersListFollowingForUserRequest<'a> {
+        UsersListFollowingForUserRequest {
+            client: self,
+            username,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List the people a user follows.
      *
@@ -1340,6 +2345,30 @@ impl Users {
             )
             .await
     }
+    /**
+     * List the people a user follows.
+     *
+     * This function performs a `GET` to the `/users/{username}/following` endpoint.
+     *
+     * As opposed to `list_all_following_for_user`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Lists the people who the specified user follows.
+     *
+     * FROM: <https://docs.github.com/rest/reference/users#list-the-people-a-user-follows>
+     */
+    pub fn list_all_following_for_user_stream(
+        &self,
+        username: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::SimpleUser>> + Unpin + '_ {
+        let url = self.client.url(
+            &format!(
+                "/users/{}/following",
+                crate::progenitor_support::encode_path(username),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Check if a user follows another user.
      *
@@ -1424,6 +2453,28 @@ impl Users {
             )
             .await
     }
+    /**
+     * List GPG keys for a user.
+     *
+     * This function performs a `GET` to the `/users/{username}/gpg_keys` endpoint.
+     *
+     * As opposed to `list_gpg_keys_for_user`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Lists the GPG keys for a user. This information is accessible by anyone.
+     *
+     * FROM: <https://docs.github.com/rest/reference/users#list-gpg-keys-for-a-user>
+     */
+    pub fn list_gpg_keys_for_user_request<'a>(
+        &'a self,
+        username: &'a str,
+    ) -> UsersListGpgKeysForUserRequest<'a> {
+        UsersListGpgKeysForUserRequest {
+            client: self,
+            username,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List GPG keys for a user.
      *
@@ -1456,6 +2507,30 @@ impl Users {
             )
             .await
     }
+    /**
+     * List GPG keys for a user.
+     *
+     * This function performs a `GET` to the `/users/{username}/gpg_keys` endpoint.
+     *
+     * As opposed to `list_all_gpg_keys_for_user`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Lists the GPG keys for a user. This information is accessible by anyone.
+     *
+     * FROM: <https://docs.github.com/rest/reference/users#list-gpg-keys-for-a-user>
+     */
+    pub fn list_all_gpg_keys_for_user_stream(
+        &self,
+        username: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::GpgKey>> + Unpin + '_ {
+        let url = self.client.url(
+            &format!(
+                "/users/{}/gpg_keys",
+                crate::progenitor_support::encode_path(username),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Get contextual information for a user.
      *
@@ -1510,6 +2585,35 @@ impl Users {
             )
             .await
     }
+    /**
+     * Get contextual information for a user.
+     *
+     * This function performs a `GET` to the `/users/{username}/hovercard` endpoint.
+     *
+     * As opposed to `get_context_for_user`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Provides hovercard information when authenticated through basic auth or OAuth with the `repo` scope. You can find out more about someone in relation to their pull requests, issues, repositories, and organizations.
+     *
+     * The `subject_type` and `subject_id` parameters provide context for the person's hovercard, which returns more information than without the parameters. For example, if you wanted to find out more about `octocat` who owns the `Spoon-Knife` repository via cURL, it would look like this:
+     *
+     * ```shell
+     *  curl -u username:token
+     *   https://api.github.com/users/octocat/hovercard?subject_type=repository&subject_id=1300192
+     * ```
+     *
+     * FROM: <https://docs.github.com/rest/reference/users#get-contextual-information-for-a-user>
+     */
+    pub fn get_context_for_user_request<'a>(
+        &'a self,
+        username: &'a str,
+    ) -> UsersGetContextForUserRequest<'a> {
+        UsersGetContextForUserRequest {
+            client: self,
+            username,
+            subject_type: None,
+            subject_id: None,
+        }
+    }
     /**
      * List public keys for a user.
      *
@@ -1557,6 +2661,28 @@ impl Users {
             )
             .await
     }
+    /**
+     * List public keys for a user.
+     *
+     * This function performs a `GET` to the `/users/{username}/keys` endpoint.
+     *
+     * As opposed to `list_public_keys_for_user`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Lists the _verified_ public SSH keys for a user. This is accessible by anyone.
+     *
+     * FROM: <https://docs.github.com/rest/reference/users#list-public-keys-for-a-user>
+     */
+    pub fn list_public_keys_for_user_request<'a>(
+        &'a self,
+        username: &'a str,
+    ) -> UsersListPublicKeysForUserRequest<'a> {
+        UsersListPublicKeysForUserRequest {
+            client: self,
+            username,
+            per_page: None,
+            page: None,
+        }
+    }
     /**
      * List public keys for a user.
      *
@@ -1589,4 +2715,28 @@ impl Users {
             )
             .await
     }
+    /**
+     * List public keys for a user.
+     *
+     * This function performs a `GET` to the `/users/{username}/keys` endpoint.
+     *
+     * As opposed to `list_all_public_keys_for_user`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Lists the _verified_ public SSH keys for a user. This is accessible by anyone.
+     *
+     * FROM: <https://docs.github.com/rest/reference/users#list-public-keys-for-a-user>
+     */
+    pub fn list_all_public_keys_for_user_stream(
+        &self,
+        username: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::KeySimple>> + Unpin + '_ {
+        let url = self.client.url(
+            &format!(
+                "/users/{}/keys",
+                crate::progenitor_support::encode_path(username),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
 }
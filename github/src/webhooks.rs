@@ -0,0 +1,433 @@
+//! For verifying and parsing the webhook events GitHub delivers to an endpoint.
+//!
+//! FROM: <https://docs.github.com/en/webhooks/using-webhooks/validating-webhook-deliveries>
+use hmac::{Hmac, Mac};
+use schemars::JsonSchema;
+use serde::{Deserialize, Deserializer, Serialize};
+use sha2::Sha256;
+
+use crate::types;
+
+/// The header GitHub signs webhook deliveries with.
+pub const SIGNATURE_HEADER: &str = "X-Hub-Signature-256";
+
+/// The header holding the name of the event of a webhook delivery.
+pub const EVENT_HEADER: &str = "X-GitHub-Event";
+
+/// The header holding the unique identifier of a webhook delivery.
+pub const DELIVERY_HEADER: &str = "X-GitHub-Delivery";
+
+/// Errors returned while verifying or parsing a webhook delivery.
+#[derive(Debug, thiserror::Error)]
+pub enum WebhookError {
+    /// A header the delivery needs is missing
+    #[error("missing {0} header")]
+    MissingHeader(&'static str),
+    /// The signature header is malformed
+    #[error("invalid X-Hub-Signature-256 header")]
+    InvalidHeader,
+    /// The signature does not match the payload
+    #[error("the signature does not match the payload")]
+    SignatureMismatch,
+    /// The payload does not match its event
+    #[error(transparent)]
+    SerdeJsonError(#[from] serde_json::Error),
+}
+
+/// The installation of the GitHub App a webhook was delivered for.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default, JsonSchema)]
+pub struct EventInstallation {
+    #[serde(default)]
+    pub id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub node_id: String,
+}
+
+/// Activity related to a check run.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct CheckRunEvent {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub action: String,
+    pub check_run: types::CheckRun,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub installation: Option<EventInstallation>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub organization: Option<types::OrganizationSimple>,
+    pub repository: types::Repository,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sender: Option<types::SimpleUser>,
+}
+
+/// Activity related to a check suite.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct CheckSuiteEvent {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub action: String,
+    pub check_suite: types::CheckSuite,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub installation: Option<EventInstallation>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub organization: Option<types::OrganizationSimple>,
+    pub repository: types::Repository,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sender: Option<types::SimpleUser>,
+}
+
+/// Activity related to the installation of a GitHub App.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct InstallationEvent {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub action: String,
+    pub installation: types::Installation,
+    /// The repositories the installation can access, for the `created` and `deleted`
+    /// actions.
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub repositories: Vec<serde_json::Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sender: Option<types::SimpleUser>,
+}
+
+/// Activity related to a comment on an issue or a pull request.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct IssueCommentEvent {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub action: String,
+    pub comment: types::IssueComment,
+    pub issue: types::Issue,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub installation: Option<EventInstallation>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub organization: Option<types::OrganizationSimple>,
+    pub repository: types::Repository,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sender: Option<types::SimpleUser>,
+}
+
+/// Activity related to an issue.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct IssuesEvent {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub action: String,
+    pub issue: types::Issue,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub installation: Option<EventInstallation>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub organization: Option<types::OrganizationSimple>,
+    pub repository: types::Repository,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sender: Option<types::SimpleUser>,
+}
+
+/// Sent when a webhook is created, to check it is set up correctly.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PingEvent {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub zen: String,
+    #[serde(default)]
+    pub hook_id: i64,
+    pub hook: types::Hook,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub organization: Option<types::OrganizationSimple>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub repository: Option<types::Repository>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sender: Option<types::SimpleUser>,
+}
+
+/// Activity related to a pull request.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PullRequestEvent {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub action: String,
+    #[serde(default)]
+    pub number: i64,
+    pub pull_request: types::PullRequestData,
+    /// The changes to the pull request, for the `edited` action.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub changes: Option<serde_json::Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub installation: Option<EventInstallation>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub organization: Option<types::OrganizationSimple>,
+    pub repository: types::Repository,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sender: Option<types::SimpleUser>,
+}
+
+/// One or more commits pushed to a branch, or a tag.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PushEvent {
+    #[serde(
+        rename = "ref",
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub ref_: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub before: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub after: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub base_ref: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub compare: String,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub created: bool,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub deleted: bool,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub forced: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub commits: Vec<types::SimpleCommit>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub head_commit: Option<types::SimpleCommit>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pusher: Option<types::Author>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub installation: Option<EventInstallation>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub organization: Option<types::OrganizationSimple>,
+    #[serde(deserialize_with = "deserialize_push_repository")]
+    pub repository: types::Repository,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sender: Option<types::SimpleUser>,
+}
+
+/// Activity related to a release.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReleaseEvent {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub action: String,
+    pub release: types::Release,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub installation: Option<EventInstallation>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub organization: Option<types::OrganizationSimple>,
+    pub repository: types::Repository,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sender: Option<types::SimpleUser>,
+}
+
+/// Activity related to a run of a GitHub Actions workflow.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct WorkflowRunEvent {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub action: String,
+    pub workflow_run: types::WorkflowRun,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub workflow: Option<types::Workflow>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub installation: Option<EventInstallation>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub organization: Option<types::OrganizationSimple>,
+    pub repository: types::Repository,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sender: Option<types::SimpleUser>,
+}
+
+/// A webhook event, by the name GitHub delivers it with.
+///
+/// Events without a variant of their own are kept as JSON.
+#[derive(Debug, Clone, PartialEq)]
+pub enum WebhookEvent {
+    CheckRun(Box<CheckRunEvent>),
+    CheckSuite(Box<CheckSuiteEvent>),
+    Installation(Box<InstallationEvent>),
+    IssueComment(Box<IssueCommentEvent>),
+    Issues(Box<IssuesEvent>),
+    Ping(Box<PingEvent>),
+    PullRequest(Box<PullRequestEvent>),
+    Push(Box<PushEvent>),
+    Release(Box<ReleaseEvent>),
+    WorkflowRun(Box<WorkflowRunEvent>),
+    Other {
+        event: String,
+        payload: serde_json::Value,
+    },
+}
+
+/// Verifies the webhook deliveries of a GitHub App or a repository or organization
+/// hook, with its secret.
+#[derive(Clone)]
+pub struct Webhook {
+    secret: String,
+}
+
+impl Webhook {
+    /// Create a new webhook verifier from the secret of the webhook.
+    pub fn new<S>(secret: S) -> Self
+    where
+        S: ToString,
+    {
+        Webhook {
+            secret: secret.to_string(),
+        }
+    }
+
+    fn mac(&self, payload: &[u8]) -> Hmac<Sha256> {
+        let mut mac = Hmac::<Sha256>::new_from_slice(self.secret.as_bytes())
+            .expect("HMAC can take a key of any size");
+        mac.update(payload);
+        mac
+    }
+
+    /// Returns the `X-Hub-Signature-256` header GitHub would deliver along with a
+    /// payload, to test webhook handlers.
+    pub fn sign(&self, payload: &[u8]) -> String {
+        let signature = self.mac(payload).finalize().into_bytes();
+        format!("sha256={}", hex::encode(signature))
+    }
+
+    /// Verifies the `X-Hub-Signature-256` header of a delivery against its raw body.
+    pub fn verify(&self, payload: &[u8], signature: &str) -> Result<(), WebhookError> {
+        let signature = signature
+            .trim()
+            .strip_prefix("sha256=")
+            .and_then(|signature| hex::decode(signature).ok())
+            .ok_or(WebhookError::InvalidHeader)?;
+
+        self.mac(payload)
+            .verify_slice(&signature)
+            .map_err(|_| WebhookError::SignatureMismatch)
+    }
+
+    /// Verifies a delivery and parses its body into an event, from its headers.
+    ///
+    /// GitHub sends the signature in `X-Hub-Signature-256` and the name of the event
+    /// in `X-GitHub-Event`, which are looked up by name in `headers`, be it a
+    /// `HeaderMap` or any other pairs of names and values.
+    pub fn construct_event<I, K, V>(
+        &self,
+        headers: I,
+        payload: &[u8],
+    ) -> Result<WebhookEvent, WebhookError>
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: AsRef<str>,
+        V: AsRef<[u8]>,
+    {
+        let [signature, event] =
+            crate::utils::find_headers(headers, [SIGNATURE_HEADER, EVENT_HEADER]);
+        let signature = signature.ok_or(WebhookError::MissingHeader(SIGNATURE_HEADER))?;
+        let event = event.ok_or(WebhookError::MissingHeader(EVENT_HEADER))?;
+
+        self.verify(payload, &signature)?;
+        parse_event(&event, payload)
+    }
+}
+
+/// Parses the body of a webhook delivery into an event, from the value of its
+/// `X-GitHub-Event` header, without verifying it.
+pub fn parse_event(event: &str, payload: &[u8]) -> Result<WebhookEvent, WebhookError> {
+    Ok(match event.trim() {
+        "check_run" => WebhookEvent::CheckRun(serde_json::from_slice(payload)?),
+        "check_suite" => WebhookEvent::CheckSuite(serde_json::from_slice(payload)?),
+        "installation" => WebhookEvent::Installation(serde_json::from_slice(payload)?),
+        "issue_comment" => WebhookEvent::IssueComment(serde_json::from_slice(payload)?),
+        "issues" => WebhookEvent::Issues(serde_json::from_slice(payload)?),
+        "ping" => WebhookEvent::Ping(serde_json::from_slice(payload)?),
+        "pull_request" => WebhookEvent::PullRequest(serde_json::from_slice(payload)?),
+        "push" => WebhookEvent::Push(serde_json::from_slice(payload)?),
+        "release" => WebhookEvent::Release(serde_json::from_slice(payload)?),
+        "workflow_run" => WebhookEvent::WorkflowRun(serde_json::from_slice(payload)?),
+        event => WebhookEvent::Other {
+            event: event.to_string(),
+            payload: serde_json::from_slice(payload)?,
+        },
+    })
+}
+
+/// The repository of a push event has its timestamps as seconds since the epoch,
+/// rather than in the RFC 3339 format of the REST API.
+fn deserialize_push_repository<'de, D>(deserializer: D) -> Result<types::Repository, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    use chrono::TimeZone;
+
+    let mut repository = serde_json::Value::deserialize(deserializer)?;
+    for field in ["created_at", "pushed_at", "updated_at"] {
+        let timestamp = repository.get(field).and_then(|value| value.as_i64());
+        if let Some(timestamp) = timestamp {
+            if let Some(date) = chrono::Utc.timestamp_opt(timestamp, 0).single() {
+                repository[field] = serde_json::Value::String(date.to_rfc3339());
+            }
+        }
+    }
+
+    serde_json::from_value(repository).map_err(serde::de::Error::custom)
+}
@@ -10,7 +10,8 @@ use wiremock::{
 
 use octorust::{
     auth::{Credentials, InstallationTokenGenerator, JWTCredentials},
-    types::InstallationToken,
+    types::{InstallationToken, JobStatus},
+    webhooks::{self, Webhook, WebhookError, WebhookEvent},
     Client, ClientError,
 };
 
@@ -68,6 +69,8 @@ fn empty_issue() -> octorust::types::Issue {
         updated_at: None,
         url: String::new(),
         user: None,
+        #[cfg(feature = "extra-fields")]
+        extra: Default::default(),
     }
 }
 
@@ -88,6 +91,8 @@ async fn test_follows_next_links_during_unfold() {
             repository_selection: Default::default(),
             single_file: Default::default(),
             single_file_paths: Default::default(),
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         });
 
     Mock::given(method("POST"))
@@ -186,6 +191,8 @@ async fn test_refreshes_installation_token_once() {
             repository_selection: Default::default(),
             single_file: Default::default(),
             single_file_paths: Default::default(),
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         });
     Mock::given(method("POST"))
         .and(path(format!(
@@ -245,6 +252,8 @@ async fn test_ratelimit_error() {
             repository_selection: Default::default(),
             single_file: Default::default(),
             single_file_paths: Default::default(),
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         });
     Mock::given(method("POST"))
         .and(path(format!(
@@ -265,7 +274,7 @@ async fn test_ratelimit_error() {
         .and(path("/zen"))
         .and(header("authorization", "token test-token"))
         .respond_with(
-            ResponseTemplate::new(400)
+            ResponseTemplate::new(403)
                 .append_header("x-ratelimit-remaining", "0")
                 .append_header("x-ratelimit-reset", format!("{}", now.as_secs()).as_str()),
         )
@@ -288,7 +297,11 @@ async fn test_ratelimit_error() {
 
     // Ensure the request failed.
     let err = result.expect_err("get zen should fail");
-    if let ClientError::RateLimited { duration } = err {
+    if let ClientError::RateLimited {
+        duration: Some(duration),
+        ..
+    } = err
+    {
         /*
         We should expect a duration of 60, but between the delay in the auth request and
         possible change of seconds between the computation of `now` and the actual call,
@@ -321,6 +334,8 @@ async fn test_does_not_follow_redirects() {
             repository_selection: Default::default(),
             single_file: Default::default(),
             single_file_paths: Default::default(),
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         });
 
     Mock::given(method("POST"))
@@ -380,3 +395,343 @@ async fn test_does_not_follow_redirects() {
         .unwrap()
         .ends_with("/fake-download-path"));
 }
+
+fn token_client(server: &MockServer) -> Client {
+    let mut client = Client::new(
+        concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION")),
+        Credentials::Token("test-token".to_owned()),
+    )
+    .expect("Client creation should succeed");
+    client.with_host_override(server.uri());
+    client
+}
+
+async fn mount_issues_page(server: &MockServer, page: &str, issues: usize, next: Option<&str>) {
+    let mut response = ResponseTemplate::new(200).set_body_json(vec![empty_issue(); issues]);
+    if let Some(next) = next {
+        response = response.append_header(
+            HeaderName::from_bytes("link".as_bytes().to_vec()).unwrap(),
+            HeaderValue::from_bytes(
+                format!("<{}/issues?page={next}>; rel=\"next\"", server.uri()).into_bytes(),
+            )
+            .unwrap(),
+        );
+    }
+
+    let mock = Mock::given(method("GET")).and(path("/issues".to_string()));
+    let mock = if page == "1" {
+        mock.and(query_param("filter", "all"))
+    } else {
+        mock.and(query_param("page", page))
+    };
+
+    mock.respond_with(response).mount(server).await;
+}
+
+async fn list_all_issues(client: &Client) -> Result<Vec<octorust::types::Issue>, ClientError> {
+    client
+        .issues()
+        .list_all(
+            octorust::types::Filter::All,
+            octorust::types::IssuesListState::All,
+            "",
+            octorust::types::IssuesListSort::Created,
+            octorust::types::Order::Asc,
+            None,
+            false,
+            false,
+            false,
+            false,
+        )
+        .await
+        .map(|response| response.body)
+}
+
+#[tokio::test]
+async fn test_unfold_fails_on_repeated_next_link() {
+    let server = MockServer::start().await;
+
+    // The second page keeps pointing back at itself.
+    mount_issues_page(&server, "1", 2, Some("2")).await;
+    mount_issues_page(&server, "2", 1, Some("2")).await;
+
+    let client = token_client(&server);
+    match list_all_issues(&client).await {
+        Err(ClientError::IncompletePages { page_token }) => {
+            assert!(page_token.ends_with("/issues?page=2"))
+        }
+        result => unreachable!("Expected incomplete pages, got {:?}", result),
+    }
+    assert_eq!(server.received_requests().await.unwrap().len(), 2);
+
+    mem::drop(server)
+}
+
+#[tokio::test]
+async fn test_unfold_stops_on_empty_page() {
+    let server = MockServer::start().await;
+
+    mount_issues_page(&server, "1", 2, Some("2")).await;
+    mount_issues_page(&server, "2", 0, Some("3")).await;
+    mount_issues_page(&server, "3", 1, None).await;
+
+    let client = token_client(&server);
+    let issues = list_all_issues(&client).await.unwrap();
+
+    assert_eq!(issues.len(), 2);
+    assert_eq!(server.received_requests().await.unwrap().len(), 2);
+
+    mem::drop(server)
+}
+
+#[tokio::test]
+async fn test_unfold_respects_page_limits() {
+    let server = MockServer::start().await;
+
+    mount_issues_page(&server, "1", 2, Some("2")).await;
+    mount_issues_page(&server, "2", 2, Some("3")).await;
+    mount_issues_page(&server, "3", 2, None).await;
+
+    let mut client = token_client(&server);
+    client.with_max_pages(2);
+    assert_eq!(list_all_issues(&client).await.unwrap().len(), 4);
+    assert_eq!(server.received_requests().await.unwrap().len(), 2);
+
+    server.reset().await;
+    mount_issues_page(&server, "1", 2, Some("2")).await;
+    mount_issues_page(&server, "2", 2, Some("3")).await;
+    mount_issues_page(&server, "3", 2, None).await;
+
+    let mut client = token_client(&server);
+    client.with_max_items(3);
+    assert_eq!(list_all_issues(&client).await.unwrap().len(), 3);
+    assert_eq!(server.received_requests().await.unwrap().len(), 2);
+
+    mem::drop(server)
+}
+
+#[tokio::test]
+async fn test_validation_error_is_parsed() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/zen"))
+        .respond_with(ResponseTemplate::new(422).set_body_json(serde_json::json!({
+            "message": "Validation Failed",
+            "errors": [{ "resource": "Issue", "field": "title", "code": "missing_field" }],
+            "documentation_url": "https://docs.github.com/rest"
+        })))
+        .mount(&server)
+        .await;
+
+    let client = token_client(&server);
+    let err = client
+        .meta()
+        .get_zen()
+        .await
+        .expect_err("get zen should fail");
+
+    mem::drop(server);
+
+    match err {
+        ClientError::ApiError { status, error, .. } => {
+            assert_eq!(status, 422);
+            assert_eq!(error.message, "Validation Failed");
+            assert_eq!(error.errors[0].code, "missing_field");
+            assert_eq!(
+                error.to_string(),
+                "Validation Failed; Issue.title: missing_field"
+            );
+        }
+        err => unreachable!("Expected API error, got {:?}", err),
+    }
+}
+
+#[tokio::test]
+async fn test_unparsed_error_body_is_kept() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/zen"))
+        .respond_with(ResponseTemplate::new(404).set_body_string("Not Found"))
+        .mount(&server)
+        .await;
+
+    let client = token_client(&server);
+    let err = client
+        .meta()
+        .get_zen()
+        .await
+        .expect_err("get zen should fail");
+
+    mem::drop(server);
+
+    match err {
+        ClientError::HttpError { status, error, .. } => {
+            assert_eq!(status, 404);
+            assert_eq!(error, "Not Found");
+        }
+        err => unreachable!("Expected HTTP error, got {:?}", err),
+    }
+}
+
+const WEBHOOK_SECRET: &str = "It's a Secret to Everybody";
+
+fn delivery(webhook: &Webhook, event: &str, payload: &[u8]) -> Vec<(String, String)> {
+    vec![
+        (
+            "X-GitHub-Delivery".to_string(),
+            "72d3162e-cc78-11e3-81ab-4c9367dc0958".to_string(),
+        ),
+        ("x-github-event".to_string(), event.to_string()),
+        ("X-Hub-Signature-256".to_string(), webhook.sign(payload)),
+    ]
+}
+
+#[test]
+fn test_webhook_signature_matches_github() {
+    // The example of the GitHub documentation.
+    let webhook = Webhook::new(WEBHOOK_SECRET);
+    assert_eq!(
+        webhook.sign(b"Hello, World!"),
+        "sha256=757107ea0eb2509fc211221cce984b8a37570b6d7586c22c46f4379c8b043e17"
+    );
+    assert!(webhook
+        .verify(
+            b"Hello, World!",
+            "sha256=757107ea0eb2509fc211221cce984b8a37570b6d7586c22c46f4379c8b043e17"
+        )
+        .is_ok());
+}
+
+#[test]
+fn test_webhook_rejects_bad_signatures() {
+    let payload = include_bytes!("fixtures/ping.json");
+    let webhook = Webhook::new(WEBHOOK_SECRET);
+
+    let signature = Webhook::new("another secret").sign(payload);
+    assert!(matches!(
+        webhook.verify(payload, &signature),
+        Err(WebhookError::SignatureMismatch)
+    ));
+
+    let signature = webhook.sign(payload);
+    assert!(matches!(
+        webhook.verify(payload, signature.trim_start_matches("sha256=")),
+        Err(WebhookError::InvalidHeader)
+    ));
+
+    let headers = vec![("X-GitHub-Event", "ping")];
+    assert!(matches!(
+        webhook.construct_event(headers, payload),
+        Err(WebhookError::MissingHeader("X-Hub-Signature-256"))
+    ));
+}
+
+#[test]
+fn test_webhook_constructs_push_event() {
+    let payload = include_bytes!("fixtures/push.json");
+    let webhook = Webhook::new(WEBHOOK_SECRET);
+
+    let event = webhook
+        .construct_event(delivery(&webhook, "push", payload), payload)
+        .unwrap();
+    let WebhookEvent::Push(push) = event else {
+        unreachable!("Expected a push, got {:?}", event);
+    };
+    assert_eq!(push.ref_, "refs/heads/main");
+    assert_eq!(push.commits.len(), 1);
+    assert_eq!(push.commits[0].message, "Update README.md");
+    assert_eq!(push.pusher.unwrap().name, "Codertocat");
+    assert_eq!(push.repository.full_name, "Codertocat/Hello-World");
+    assert_eq!(
+        push.repository.created_at.unwrap().to_rfc3339(),
+        "2019-05-15T15:19:25+00:00"
+    );
+}
+
+#[test]
+fn test_webhook_constructs_typed_events() {
+    let webhook = Webhook::new(WEBHOOK_SECRET);
+
+    let payload = include_bytes!("fixtures/pull_request.json");
+    let event = webhook
+        .construct_event(delivery(&webhook, "pull_request", payload), payload)
+        .unwrap();
+    let WebhookEvent::PullRequest(pull_request) = event else {
+        unreachable!("Expected a pull request, got {:?}", event);
+    };
+    assert_eq!(pull_request.action, "opened");
+    assert_eq!(pull_request.pull_request.number, 2);
+    assert_eq!(pull_request.pull_request.head.ref_, "changes");
+    assert_eq!(pull_request.installation.unwrap().id, 2311213);
+
+    let payload = include_bytes!("fixtures/check_run.json");
+    let event = webhooks::parse_event("check_run", payload).unwrap();
+    let WebhookEvent::CheckRun(check_run) = event else {
+        unreachable!("Expected a check run, got {:?}", event);
+    };
+    assert_eq!(check_run.action, "completed");
+    assert_eq!(check_run.check_run.name, "Octocoders-linter");
+
+    let payload = include_bytes!("fixtures/installation.json");
+    let event = webhooks::parse_event("installation", payload).unwrap();
+    let WebhookEvent::Installation(installation) = event else {
+        unreachable!("Expected an installation, got {:?}", event);
+    };
+    assert_eq!(installation.installation.app_slug, "octoapp");
+    assert_eq!(installation.repositories.len(), 1);
+
+    let payload = include_bytes!("fixtures/ping.json");
+    let event = webhooks::parse_event("ping", payload).unwrap();
+    let WebhookEvent::Ping(ping) = event else {
+        unreachable!("Expected a ping, got {:?}", event);
+    };
+    assert_eq!(ping.hook_id, 109948940);
+    assert_eq!(ping.hook.events, vec!["*"]);
+}
+
+#[test]
+fn test_webhook_keeps_other_events() {
+    let payload = br#"{"action": "created", "starred_at": "2019-05-15T15:20:40Z"}"#;
+    let event = webhooks::parse_event("star", payload).unwrap();
+
+    assert_eq!(
+        event,
+        WebhookEvent::Other {
+            event: "star".to_string(),
+            payload: serde_json::from_slice(payload).unwrap(),
+        }
+    );
+}
+
+#[test]
+fn test_enums_keep_unknown_values() {
+    let status: JobStatus = serde_json::from_str("\"waiting\"").unwrap();
+    assert_eq!(status, JobStatus::Unknown("waiting".to_string()));
+    assert_eq!(status.to_string(), "waiting");
+    assert_eq!(serde_json::to_string(&status).unwrap(), "\"waiting\"");
+
+    let status: JobStatus = serde_json::from_str("\"queued\"").unwrap();
+    assert_eq!(status, JobStatus::Queued);
+    let status: JobStatus = serde_json::from_str("\"\"").unwrap();
+    assert_eq!(status, JobStatus::Noop);
+}
+
+#[cfg(feature = "extra-fields")]
+#[test]
+fn test_types_keep_extra_fields() {
+    let json = serde_json::json!({
+        "login": "octocat",
+        "id": 1,
+        "user_view_type": "public"
+    });
+
+    let user: octorust::types::SimpleUser = serde_json::from_value(json).unwrap();
+    assert_eq!(user.login, "octocat");
+    assert_eq!(user.extra["user_view_type"], "public");
+    assert!(!user.extra.contains_key("login"));
+
+    let value = serde_json::to_value(&user).unwrap();
+    assert_eq!(value["user_view_type"], "public");
+}
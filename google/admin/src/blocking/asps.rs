@@ -0,0 +1,32 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::asps::Asps`].
+pub struct Asps {
+    inner: crate::asps::Asps,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Asps {
+    pub(crate) fn new(inner: crate::asps::Asps, runtime: Arc<tokio::runtime::Runtime>) -> Self {
+        Asps { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::asps::Asps::list`].
+    pub fn list(&self, user_key: &str) -> ClientResult<crate::Response<crate::types::Asps>> {
+        self.runtime.block_on(self.inner.list(user_key))
+    }
+    /// The blocking version of [`crate::asps::Asps::get`].
+    pub fn get(
+        &self,
+        user_key: &str,
+        code_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::Asp>> {
+        self.runtime.block_on(self.inner.get(user_key, code_id))
+    }
+    /// The blocking version of [`crate::asps::Asps::delete`].
+    pub fn delete(&self, user_key: &str, code_id: i64) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.delete(user_key, code_id))
+    }
+}
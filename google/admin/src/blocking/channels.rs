@@ -0,0 +1,23 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::channels::Channels`].
+pub struct Channels {
+    inner: crate::channels::Channels,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Channels {
+    pub(crate) fn new(
+        inner: crate::channels::Channels,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Channels { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::channels::Channels::admin_stop`].
+    pub fn admin_stop(&self, body: &crate::types::Channel) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.admin_stop(body))
+    }
+}
@@ -0,0 +1,267 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Chromeosdevices::list`], set with a builder.
+pub struct ChromeosdevicesListRequest<'a> {
+    inner: crate::chromeosdevices::ChromeosdevicesListRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ChromeosdevicesListRequest<'a> {
+    /// Sets the `maxResults` query parameter.
+    pub fn max_results(mut self, max_results: i64) -> Self {
+        self.inner = self.inner.max_results(max_results);
+        self
+    }
+
+    /// Sets the `orderBy` query parameter.
+    pub fn order_by(mut self, order_by: crate::types::OrderBy) -> Self {
+        self.inner = self.inner.order_by(order_by);
+        self
+    }
+
+    /// Sets the `orgUnitPath` query parameter.
+    pub fn org_unit_path(mut self, org_unit_path: &'a str) -> Self {
+        self.inner = self.inner.org_unit_path(org_unit_path);
+        self
+    }
+
+    /// Sets the `pageToken` query parameter.
+    pub fn page_token(mut self, page_token: &'a str) -> Self {
+        self.inner = self.inner.page_token(page_token);
+        self
+    }
+
+    /// Sets the `projection` query parameter.
+    pub fn projection(mut self, projection: crate::types::Projection) -> Self {
+        self.inner = self.inner.projection(projection);
+        self
+    }
+
+    /// Sets the `query` query parameter.
+    pub fn query(mut self, query: &'a str) -> Self {
+        self.inner = self.inner.query(query);
+        self
+    }
+
+    /// Sets the `sortOrder` query parameter.
+    pub fn sort_order(mut self, sort_order: crate::types::SortOrder) -> Self {
+        self.inner = self.inner.sort_order(sort_order);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::ChromeOsDevice>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Chromeosdevices::get`], set with a builder.
+pub struct ChromeosdevicesGetRequest<'a> {
+    inner: crate::chromeosdevices::ChromeosdevicesGetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ChromeosdevicesGetRequest<'a> {
+    /// Sets the `projection` query parameter.
+    pub fn projection(mut self, projection: crate::types::Projection) -> Self {
+        self.inner = self.inner.projection(projection);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::ChromeOsDevice>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Chromeosdevices::update`], set with a builder.
+pub struct ChromeosdevicesUpdateRequest<'a> {
+    inner: crate::chromeosdevices::ChromeosdevicesUpdateRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ChromeosdevicesUpdateRequest<'a> {
+    /// Sets the `projection` query parameter.
+    pub fn projection(mut self, projection: crate::types::Projection) -> Self {
+        self.inner = self.inner.projection(projection);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::ChromeOsDevice>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Chromeosdevices::patch`], set with a builder.
+pub struct ChromeosdevicesPatchRequest<'a> {
+    inner: crate::chromeosdevices::ChromeosdevicesPatchRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ChromeosdevicesPatchRequest<'a> {
+    /// Sets the `projection` query parameter.
+    pub fn projection(mut self, projection: crate::types::Projection) -> Self {
+        self.inner = self.inner.projection(projection);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::ChromeOsDevice>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::chromeosdevices::Chromeosdevices`].
+pub struct Chromeosdevices {
+    inner: crate::chromeosdevices::Chromeosdevices,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Chromeosdevices {
+    pub(crate) fn new(
+        inner: crate::chromeosdevices::Chromeosdevices,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Chromeosdevices { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::chromeosdevices::Chromeosdevices::list`].
+    pub fn list(
+        &self,
+        customer_id: &str,
+        max_results: i64,
+        order_by: crate::types::OrderBy,
+        org_unit_path: &str,
+        page_token: &str,
+        projection: crate::types::Projection,
+        query: &str,
+        sort_order: crate::types::SortOrder,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ChromeOsDevice>>> {
+        self.runtime.block_on(self.inner.list(
+            customer_id,
+            max_results,
+            order_by,
+            org_unit_path,
+            page_token,
+            projection,
+            query,
+            sort_order,
+        ))
+    }
+    /// The blocking version of [`crate::chromeosdevices::Chromeosdevices::list_request`].
+    pub fn list_request<'a>(&'a self, customer_id: &'a str) -> ChromeosdevicesListRequest<'a> {
+        ChromeosdevicesListRequest {
+            inner: self.inner.list_request(customer_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::chromeosdevices::Chromeosdevices::list_all`].
+    pub fn list_all(
+        &self,
+        customer_id: &str,
+        order_by: crate::types::OrderBy,
+        org_unit_path: &str,
+        projection: crate::types::Projection,
+        query: &str,
+        sort_order: crate::types::SortOrder,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ChromeOsDevice>>> {
+        self.runtime.block_on(self.inner.list_all(
+            customer_id,
+            order_by,
+            org_unit_path,
+            projection,
+            query,
+            sort_order,
+        ))
+    }
+    /// The blocking version of [`crate::chromeosdevices::Chromeosdevices::move_devices_ou`].
+    pub fn move_devices_ou(
+        &self,
+        customer_id: &str,
+        org_unit_path: &str,
+        body: &crate::types::ChromeOsMoveDevicesOu,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.move_devices_ou(customer_id, org_unit_path, body))
+    }
+    /// The blocking version of [`crate::chromeosdevices::Chromeosdevices::get`].
+    pub fn get(
+        &self,
+        customer_id: &str,
+        device_id: &str,
+        projection: crate::types::Projection,
+    ) -> ClientResult<crate::Response<crate::types::ChromeOsDevice>> {
+        self.runtime
+            .block_on(self.inner.get(customer_id, device_id, projection))
+    }
+    /// The blocking version of [`crate::chromeosdevices::Chromeosdevices::get_request`].
+    pub fn get_request<'a>(
+        &'a self,
+        customer_id: &'a str,
+        device_id: &'a str,
+    ) -> ChromeosdevicesGetRequest<'a> {
+        ChromeosdevicesGetRequest {
+            inner: self.inner.get_request(customer_id, device_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::chromeosdevices::Chromeosdevices::update`].
+    pub fn update(
+        &self,
+        customer_id: &str,
+        device_id: &str,
+        projection: crate::types::Projection,
+        body: &crate::types::ChromeOsDevice,
+    ) -> ClientResult<crate::Response<crate::types::ChromeOsDevice>> {
+        self.runtime
+            .block_on(self.inner.update(customer_id, device_id, projection, body))
+    }
+    /// The blocking version of [`crate::chromeosdevices::Chromeosdevices::update_request`].
+    pub fn update_request<'a>(
+        &'a self,
+        customer_id: &'a str,
+        device_id: &'a str,
+        body: &'a crate::types::ChromeOsDevice,
+    ) -> ChromeosdevicesUpdateRequest<'a> {
+        ChromeosdevicesUpdateRequest {
+            inner: self.inner.update_request(customer_id, device_id, body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::chromeosdevices::Chromeosdevices::patch`].
+    pub fn patch(
+        &self,
+        customer_id: &str,
+        device_id: &str,
+        projection: crate::types::Projection,
+        body: &crate::types::ChromeOsDevice,
+    ) -> ClientResult<crate::Response<crate::types::ChromeOsDevice>> {
+        self.runtime
+            .block_on(self.inner.patch(customer_id, device_id, projection, body))
+    }
+    /// The blocking version of [`crate::chromeosdevices::Chromeosdevices::patch_request`].
+    pub fn patch_request<'a>(
+        &'a self,
+        customer_id: &'a str,
+        device_id: &'a str,
+        body: &'a crate::types::ChromeOsDevice,
+    ) -> ChromeosdevicesPatchRequest<'a> {
+        ChromeosdevicesPatchRequest {
+            inner: self.inner.patch_request(customer_id, device_id, body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::chromeosdevices::Chromeosdevices::action`].
+    pub fn action(
+        &self,
+        customer_id: &str,
+        resource_id: &str,
+        body: &crate::types::ChromeOsDeviceAction,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.action(customer_id, resource_id, body))
+    }
+}
@@ -0,0 +1,47 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::customer::Customer`].
+pub struct Customer {
+    inner: crate::customer::Customer,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Customer {
+    pub(crate) fn new(
+        inner: crate::customer::Customer,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Customer { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::customer::Customer::admin_devices_chromeos_commands_get`].
+    pub fn admin_devices_chromeos_commands_get(
+        &self,
+        customer_id: &str,
+        device_id: &str,
+        command_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::DirectoryChromeosdevicesCommand>> {
+        self.runtime
+            .block_on(self.inner.admin_devices_chromeos_commands_get(
+                customer_id,
+                device_id,
+                command_id,
+            ))
+    }
+    /// The blocking version of [`crate::customer::Customer::admin_devices_chromeos_issue_command`].
+    pub fn admin_devices_chromeos_issue_command(
+        &self,
+        customer_id: &str,
+        device_id: &str,
+        body: &crate::types::DirectoryChromeosdevicesIssueCommandRequest,
+    ) -> ClientResult<crate::Response<crate::types::DirectoryChromeosdevicesIssueCommandResponse>>
+    {
+        self.runtime
+            .block_on(
+                self.inner
+                    .admin_devices_chromeos_issue_command(customer_id, device_id, body),
+            )
+    }
+}
@@ -0,0 +1,276 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Customers::admin_chrome_printers_patch`], set with a builder.
+pub struct CustomersAdminChromePrintersPatchRequest<'a> {
+    inner: crate::customers::CustomersAdminChromePrintersPatchRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> CustomersAdminChromePrintersPatchRequest<'a> {
+    /// Sets the `clearMask` query parameter.
+    pub fn clear_mask(mut self, clear_mask: &'a str) -> Self {
+        self.inner = self.inner.clear_mask(clear_mask);
+        self
+    }
+
+    /// Sets the `updateMask` query parameter.
+    pub fn update_mask(mut self, update_mask: &'a str) -> Self {
+        self.inner = self.inner.update_mask(update_mask);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::Printer>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Customers::admin_chrome_printers_list`], set with a builder.
+pub struct CustomersAdminChromePrintersListRequest<'a> {
+    inner: crate::customers::CustomersAdminChromePrintersListRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> CustomersAdminChromePrintersListRequest<'a> {
+    /// Sets the `filter` query parameter.
+    pub fn filter(mut self, filter: &'a str) -> Self {
+        self.inner = self.inner.filter(filter);
+        self
+    }
+
+    /// Sets the `orgUnitId` query parameter.
+    pub fn org_unit_id(mut self, org_unit_id: &'a str) -> Self {
+        self.inner = self.inner.org_unit_id(org_unit_id);
+        self
+    }
+
+    /// Sets the `pageSize` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Sets the `pageToken` query parameter.
+    pub fn page_token(mut self, page_token: &'a str) -> Self {
+        self.inner = self.inner.page_token(page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Printer>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Customers::admin_chrome_printers_list_printer_models`], set with a builder.
+pub struct CustomersAdminChromePrintersListPrinterModelsRequest<'a> {
+    inner: crate::customers::CustomersAdminChromePrintersListPrinterModelsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> CustomersAdminChromePrintersListPrinterModelsRequest<'a> {
+    /// Sets the `filter` query parameter.
+    pub fn filter(mut self, filter: &'a str) -> Self {
+        self.inner = self.inner.filter(filter);
+        self
+    }
+
+    /// Sets the `pageSize` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Sets the `pageToken` query parameter.
+    pub fn page_token(mut self, page_token: &'a str) -> Self {
+        self.inner = self.inner.page_token(page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::PrinterModel>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::customers::Customers`].
+pub struct Customers {
+    inner: crate::customers::Customers,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Customers {
+    pub(crate) fn new(
+        inner: crate::customers::Customers,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Customers { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::customers::Customers::get`].
+    pub fn get(&self, customer_key: &str) -> ClientResult<crate::Response<crate::types::Customer>> {
+        self.runtime.block_on(self.inner.get(customer_key))
+    }
+    /// The blocking version of [`crate::customers::Customers::update`].
+    pub fn update(
+        &self,
+        customer_key: &str,
+        body: &crate::types::Customer,
+    ) -> ClientResult<crate::Response<crate::types::Customer>> {
+        self.runtime.block_on(self.inner.update(customer_key, body))
+    }
+    /// The blocking version of [`crate::customers::Customers::patch`].
+    pub fn patch(
+        &self,
+        customer_key: &str,
+        body: &crate::types::Customer,
+    ) -> ClientResult<crate::Response<crate::types::Customer>> {
+        self.runtime.block_on(self.inner.patch(customer_key, body))
+    }
+    /// The blocking version of [`crate::customers::Customers::admin_chrome_printers_get`].
+    pub fn admin_chrome_printers_get(
+        &self,
+        name: &str,
+    ) -> ClientResult<crate::Response<crate::types::Printer>> {
+        self.runtime
+            .block_on(self.inner.admin_chrome_printers_get(name))
+    }
+    /// The blocking version of [`crate::customers::Customers::admin_chrome_printers_delete`].
+    pub fn admin_chrome_printers_delete(
+        &self,
+        name: &str,
+    ) -> ClientResult<crate::Response<crate::types::Empty>> {
+        self.runtime
+            .block_on(self.inner.admin_chrome_printers_delete(name))
+    }
+    /// The blocking version of [`crate::customers::Customers::admin_chrome_printers_patch`].
+    pub fn admin_chrome_printers_patch(
+        &self,
+        name: &str,
+        clear_mask: &str,
+        update_mask: &str,
+        body: &crate::types::Printer,
+    ) -> ClientResult<crate::Response<crate::types::Printer>> {
+        self.runtime
+            .block_on(
+                self.inner
+                    .admin_chrome_printers_patch(name, clear_mask, update_mask, body),
+            )
+    }
+    /// The blocking version of [`crate::customers::Customers::admin_chrome_printers_patch_request`].
+    pub fn admin_chrome_printers_patch_request<'a>(
+        &'a self,
+        name: &'a str,
+        body: &'a crate::types::Printer,
+    ) -> CustomersAdminChromePrintersPatchRequest<'a> {
+        CustomersAdminChromePrintersPatchRequest {
+            inner: self.inner.admin_chrome_printers_patch_request(name, body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::customers::Customers::admin_chrome_printers_list`].
+    pub fn admin_chrome_printers_list(
+        &self,
+        parent: &str,
+        filter: &str,
+        org_unit_id: &str,
+        page_size: i64,
+        page_token: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Printer>>> {
+        self.runtime.block_on(self.inner.admin_chrome_printers_list(
+            parent,
+            filter,
+            org_unit_id,
+            page_size,
+            page_token,
+        ))
+    }
+    /// The blocking version of [`crate::customers::Customers::admin_chrome_printers_list_request`].
+    pub fn admin_chrome_printers_list_request<'a>(
+        &'a self,
+        parent: &'a str,
+    ) -> CustomersAdminChromePrintersListRequest<'a> {
+        CustomersAdminChromePrintersListRequest {
+            inner: self.inner.admin_chrome_printers_list_request(parent),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::customers::Customers::admin_chrome_printers_list_all`].
+    pub fn admin_chrome_printers_list_all(
+        &self,
+        parent: &str,
+        filter: &str,
+        org_unit_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Printer>>> {
+        self.runtime
+            .block_on(
+                self.inner
+                    .admin_chrome_printers_list_all(parent, filter, org_unit_id),
+            )
+    }
+    /// The blocking version of [`crate::customers::Customers::admin_chrome_printers_create`].
+    pub fn admin_chrome_printers_create(
+        &self,
+        parent: &str,
+        body: &crate::types::Printer,
+    ) -> ClientResult<crate::Response<crate::types::Printer>> {
+        self.runtime
+            .block_on(self.inner.admin_chrome_printers_create(parent, body))
+    }
+    /// The blocking version of [`crate::customers::Customers::admin_chrome_printers_batch_create`].
+    pub fn admin_chrome_printers_batch_create(
+        &self,
+        parent: &str,
+        body: &crate::types::BatchCreatePrintersRequest,
+    ) -> ClientResult<crate::Response<crate::types::BatchCreatePrintersResponse>> {
+        self.runtime
+            .block_on(self.inner.admin_chrome_printers_batch_create(parent, body))
+    }
+    /// The blocking version of [`crate::customers::Customers::admin_chrome_printers_batch_delete`].
+    pub fn admin_chrome_printers_batch_delete(
+        &self,
+        parent: &str,
+        body: &crate::types::BatchDeletePrintersRequest,
+    ) -> ClientResult<crate::Response<crate::types::BatchDeletePrintersResponse>> {
+        self.runtime
+            .block_on(self.inner.admin_chrome_printers_batch_delete(parent, body))
+    }
+    /// The blocking version of [`crate::customers::Customers::admin_chrome_printers_list_printer_models`].
+    pub fn admin_chrome_printers_list_printer_models(
+        &self,
+        parent: &str,
+        filter: &str,
+        page_size: i64,
+        page_token: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::PrinterModel>>> {
+        self.runtime.block_on(
+            self.inner
+                .admin_chrome_printers_list_printer_models(parent, filter, page_size, page_token),
+        )
+    }
+    /// The blocking version of [`crate::customers::Customers::admin_chrome_printers_list_printer_models_request`].
+    pub fn admin_chrome_printers_list_printer_models_request<'a>(
+        &'a self,
+        parent: &'a str,
+    ) -> CustomersAdminChromePrintersListPrinterModelsRequest<'a> {
+        CustomersAdminChromePrintersListPrinterModelsRequest {
+            inner: self
+                .inner
+                .admin_chrome_printers_list_printer_models_request(parent),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::customers::Customers::admin_chrome_printers_list_all_printer_models`].
+    pub fn admin_chrome_printers_list_all_printer_models(
+        &self,
+        parent: &str,
+        filter: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::PrinterModel>>> {
+        self.runtime.block_on(
+            self.inner
+                .admin_chrome_printers_list_all_printer_models(parent, filter),
+        )
+    }
+}
@@ -0,0 +1,80 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`DomainAliases::list`], set with a builder.
+pub struct DomainAliasesListRequest<'a> {
+    inner: crate::domain_aliases::DomainAliasesListRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> DomainAliasesListRequest<'a> {
+    /// Sets the `parentDomainName` query parameter.
+    pub fn parent_domain_name(mut self, parent_domain_name: &'a str) -> Self {
+        self.inner = self.inner.parent_domain_name(parent_domain_name);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::DomainAliases>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::domain_aliases::DomainAliases`].
+pub struct DomainAliases {
+    inner: crate::domain_aliases::DomainAliases,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl DomainAliases {
+    pub(crate) fn new(
+        inner: crate::domain_aliases::DomainAliases,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        DomainAliases { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::domain_aliases::DomainAliases::list`].
+    pub fn list(
+        &self,
+        customer: &str,
+        parent_domain_name: &str,
+    ) -> ClientResult<crate::Response<crate::types::DomainAliases>> {
+        self.runtime
+            .block_on(self.inner.list(customer, parent_domain_name))
+    }
+    /// The blocking version of [`crate::domain_aliases::DomainAliases::list_request`].
+    pub fn list_request<'a>(&'a self, customer: &'a str) -> DomainAliasesListRequest<'a> {
+        DomainAliasesListRequest {
+            inner: self.inner.list_request(customer),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::domain_aliases::DomainAliases::insert`].
+    pub fn insert(
+        &self,
+        customer: &str,
+        body: &crate::types::DomainAlias,
+    ) -> ClientResult<crate::Response<crate::types::DomainAlias>> {
+        self.runtime.block_on(self.inner.insert(customer, body))
+    }
+    /// The blocking version of [`crate::domain_aliases::DomainAliases::get`].
+    pub fn get(
+        &self,
+        customer: &str,
+        domain_alias_name: &str,
+    ) -> ClientResult<crate::Response<crate::types::DomainAlias>> {
+        self.runtime
+            .block_on(self.inner.get(customer, domain_alias_name))
+    }
+    /// The blocking version of [`crate::domain_aliases::DomainAliases::delete`].
+    pub fn delete(
+        &self,
+        customer: &str,
+        domain_alias_name: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete(customer, domain_alias_name))
+    }
+}
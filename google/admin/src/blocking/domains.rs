@@ -0,0 +1,44 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::domains::Domains`].
+pub struct Domains {
+    inner: crate::domains::Domains,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Domains {
+    pub(crate) fn new(
+        inner: crate::domains::Domains,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Domains { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::domains::Domains::list`].
+    pub fn list(&self, customer: &str) -> ClientResult<crate::Response<crate::types::Domains2>> {
+        self.runtime.block_on(self.inner.list(customer))
+    }
+    /// The blocking version of [`crate::domains::Domains::insert`].
+    pub fn insert(
+        &self,
+        customer: &str,
+        body: &crate::types::Domains,
+    ) -> ClientResult<crate::Response<crate::types::Domains>> {
+        self.runtime.block_on(self.inner.insert(customer, body))
+    }
+    /// The blocking version of [`crate::domains::Domains::get`].
+    pub fn get(
+        &self,
+        customer: &str,
+        domain_name: &str,
+    ) -> ClientResult<crate::Response<crate::types::Domains>> {
+        self.runtime.block_on(self.inner.get(customer, domain_name))
+    }
+    /// The blocking version of [`crate::domains::Domains::delete`].
+    pub fn delete(&self, customer: &str, domain_name: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete(customer, domain_name))
+    }
+}
@@ -0,0 +1,178 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Groups::list`], set with a builder.
+pub struct GroupsListRequest<'a> {
+    inner: crate::groups::GroupsListRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> GroupsListRequest<'a> {
+    /// Sets the `customer` query parameter.
+    pub fn customer(mut self, customer: &'a str) -> Self {
+        self.inner = self.inner.customer(customer);
+        self
+    }
+
+    /// Sets the `domain` query parameter.
+    pub fn domain(mut self, domain: &'a str) -> Self {
+        self.inner = self.inner.domain(domain);
+        self
+    }
+
+    /// Sets the `maxResults` query parameter.
+    pub fn max_results(mut self, max_results: i64) -> Self {
+        self.inner = self.inner.max_results(max_results);
+        self
+    }
+
+    /// Sets the `orderBy` query parameter.
+    pub fn order_by(mut self, order_by: crate::types::DirectoryGroupsListOrderBy) -> Self {
+        self.inner = self.inner.order_by(order_by);
+        self
+    }
+
+    /// Sets the `pageToken` query parameter.
+    pub fn page_token(mut self, page_token: &'a str) -> Self {
+        self.inner = self.inner.page_token(page_token);
+        self
+    }
+
+    /// Sets the `query` query parameter.
+    pub fn query(mut self, query: &'a str) -> Self {
+        self.inner = self.inner.query(query);
+        self
+    }
+
+    /// Sets the `sortOrder` query parameter.
+    pub fn sort_order(mut self, sort_order: crate::types::SortOrder) -> Self {
+        self.inner = self.inner.sort_order(sort_order);
+        self
+    }
+
+    /// Sets the `userKey` query parameter.
+    pub fn user_key(mut self, user_key: &'a str) -> Self {
+        self.inner = self.inner.user_key(user_key);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Group>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::groups::Groups`].
+pub struct Groups {
+    inner: crate::groups::Groups,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Groups {
+    pub(crate) fn new(inner: crate::groups::Groups, runtime: Arc<tokio::runtime::Runtime>) -> Self {
+        Groups { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::groups::Groups::list`].
+    pub fn list(
+        &self,
+        customer: &str,
+        domain: &str,
+        max_results: i64,
+        order_by: crate::types::DirectoryGroupsListOrderBy,
+        page_token: &str,
+        query: &str,
+        sort_order: crate::types::SortOrder,
+        user_key: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Group>>> {
+        self.runtime.block_on(self.inner.list(
+            customer,
+            domain,
+            max_results,
+            order_by,
+            page_token,
+            query,
+            sort_order,
+            user_key,
+        ))
+    }
+    /// The blocking version of [`crate::groups::Groups::list_request`].
+    pub fn list_request(&self) -> GroupsListRequest<'_> {
+        GroupsListRequest {
+            inner: self.inner.list_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::groups::Groups::list_all`].
+    pub fn list_all(
+        &self,
+        customer: &str,
+        domain: &str,
+        order_by: crate::types::DirectoryGroupsListOrderBy,
+        query: &str,
+        sort_order: crate::types::SortOrder,
+        user_key: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Group>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_all(customer, domain, order_by, query, sort_order, user_key),
+        )
+    }
+    /// The blocking version of [`crate::groups::Groups::insert`].
+    pub fn insert(
+        &self,
+        body: &crate::types::Group,
+    ) -> ClientResult<crate::Response<crate::types::Group>> {
+        self.runtime.block_on(self.inner.insert(body))
+    }
+    /// The blocking version of [`crate::groups::Groups::get`].
+    pub fn get(&self, group_key: &str) -> ClientResult<crate::Response<crate::types::Group>> {
+        self.runtime.block_on(self.inner.get(group_key))
+    }
+    /// The blocking version of [`crate::groups::Groups::update`].
+    pub fn update(
+        &self,
+        group_key: &str,
+        body: &crate::types::Group,
+    ) -> ClientResult<crate::Response<crate::types::Group>> {
+        self.runtime.block_on(self.inner.update(group_key, body))
+    }
+    /// The blocking version of [`crate::groups::Groups::delete`].
+    pub fn delete(&self, group_key: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.delete(group_key))
+    }
+    /// The blocking version of [`crate::groups::Groups::patch`].
+    pub fn patch(
+        &self,
+        group_key: &str,
+        body: &crate::types::Group,
+    ) -> ClientResult<crate::Response<crate::types::Group>> {
+        self.runtime.block_on(self.inner.patch(group_key, body))
+    }
+    /// The blocking version of [`crate::groups::Groups::aliases_list`].
+    pub fn aliases_list(
+        &self,
+        group_key: &str,
+    ) -> ClientResult<crate::Response<crate::types::Aliases>> {
+        self.runtime.block_on(self.inner.aliases_list(group_key))
+    }
+    /// The blocking version of [`crate::groups::Groups::aliases_insert`].
+    pub fn aliases_insert(
+        &self,
+        group_key: &str,
+        body: &crate::types::Alias,
+    ) -> ClientResult<crate::Response<crate::types::Alias>> {
+        self.runtime
+            .block_on(self.inner.aliases_insert(group_key, body))
+    }
+    /// The blocking version of [`crate::groups::Groups::aliases_delete`].
+    pub fn aliases_delete(
+        &self,
+        group_key: &str,
+        alias: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.aliases_delete(group_key, alias))
+    }
+}
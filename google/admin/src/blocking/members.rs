@@ -0,0 +1,143 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Members::list`], set with a builder.
+pub struct MembersListRequest<'a> {
+    inner: crate::members::MembersListRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> MembersListRequest<'a> {
+    /// Sets the `includeDerivedMembership` query parameter.
+    pub fn include_derived_membership(mut self, include_derived_membership: bool) -> Self {
+        self.inner = self
+            .inner
+            .include_derived_membership(include_derived_membership);
+        self
+    }
+
+    /// Sets the `maxResults` query parameter.
+    pub fn max_results(mut self, max_results: i64) -> Self {
+        self.inner = self.inner.max_results(max_results);
+        self
+    }
+
+    /// Sets the `pageToken` query parameter.
+    pub fn page_token(mut self, page_token: &'a str) -> Self {
+        self.inner = self.inner.page_token(page_token);
+        self
+    }
+
+    /// Sets the `roles` query parameter.
+    pub fn roles(mut self, roles: &'a str) -> Self {
+        self.inner = self.inner.roles(roles);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Member>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::members::Members`].
+pub struct Members {
+    inner: crate::members::Members,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Members {
+    pub(crate) fn new(
+        inner: crate::members::Members,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Members { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::members::Members::has`].
+    pub fn has(
+        &self,
+        group_key: &str,
+        member_key: &str,
+    ) -> ClientResult<crate::Response<crate::types::MembersHasMember>> {
+        self.runtime.block_on(self.inner.has(group_key, member_key))
+    }
+    /// The blocking version of [`crate::members::Members::list`].
+    pub fn list(
+        &self,
+        group_key: &str,
+        include_derived_membership: bool,
+        max_results: i64,
+        page_token: &str,
+        roles: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Member>>> {
+        self.runtime.block_on(self.inner.list(
+            group_key,
+            include_derived_membership,
+            max_results,
+            page_token,
+            roles,
+        ))
+    }
+    /// The blocking version of [`crate::members::Members::list_request`].
+    pub fn list_request<'a>(&'a self, group_key: &'a str) -> MembersListRequest<'a> {
+        MembersListRequest {
+            inner: self.inner.list_request(group_key),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::members::Members::list_all`].
+    pub fn list_all(
+        &self,
+        group_key: &str,
+        include_derived_membership: bool,
+        roles: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Member>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_all(group_key, include_derived_membership, roles),
+        )
+    }
+    /// The blocking version of [`crate::members::Members::insert`].
+    pub fn insert(
+        &self,
+        group_key: &str,
+        body: &crate::types::Member,
+    ) -> ClientResult<crate::Response<crate::types::Member>> {
+        self.runtime.block_on(self.inner.insert(group_key, body))
+    }
+    /// The blocking version of [`crate::members::Members::get`].
+    pub fn get(
+        &self,
+        group_key: &str,
+        member_key: &str,
+    ) -> ClientResult<crate::Response<crate::types::Member>> {
+        self.runtime.block_on(self.inner.get(group_key, member_key))
+    }
+    /// The blocking version of [`crate::members::Members::update`].
+    pub fn update(
+        &self,
+        group_key: &str,
+        member_key: &str,
+        body: &crate::types::Member,
+    ) -> ClientResult<crate::Response<crate::types::Member>> {
+        self.runtime
+            .block_on(self.inner.update(group_key, member_key, body))
+    }
+    /// The blocking version of [`crate::members::Members::delete`].
+    pub fn delete(&self, group_key: &str, member_key: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete(group_key, member_key))
+    }
+    /// The blocking version of [`crate::members::Members::patch`].
+    pub fn patch(
+        &self,
+        group_key: &str,
+        member_key: &str,
+        body: &crate::types::Member,
+    ) -> ClientResult<crate::Response<crate::types::Member>> {
+        self.runtime
+            .block_on(self.inner.patch(group_key, member_key, body))
+    }
+}
@@ -0,0 +1,172 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Mobiledevices::list`], set with a builder.
+pub struct MobiledevicesListRequest<'a> {
+    inner: crate::mobiledevices::MobiledevicesListRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> MobiledevicesListRequest<'a> {
+    /// Sets the `maxResults` query parameter.
+    pub fn max_results(mut self, max_results: i64) -> Self {
+        self.inner = self.inner.max_results(max_results);
+        self
+    }
+
+    /// Sets the `orderBy` query parameter.
+    pub fn order_by(mut self, order_by: crate::types::DirectoryMobiledevicesListOrderBy) -> Self {
+        self.inner = self.inner.order_by(order_by);
+        self
+    }
+
+    /// Sets the `pageToken` query parameter.
+    pub fn page_token(mut self, page_token: &'a str) -> Self {
+        self.inner = self.inner.page_token(page_token);
+        self
+    }
+
+    /// Sets the `projection` query parameter.
+    pub fn projection(mut self, projection: crate::types::Projection) -> Self {
+        self.inner = self.inner.projection(projection);
+        self
+    }
+
+    /// Sets the `query` query parameter.
+    pub fn query(mut self, query: &'a str) -> Self {
+        self.inner = self.inner.query(query);
+        self
+    }
+
+    /// Sets the `sortOrder` query parameter.
+    pub fn sort_order(mut self, sort_order: crate::types::SortOrder) -> Self {
+        self.inner = self.inner.sort_order(sort_order);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::MobileDevice>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Mobiledevices::get`], set with a builder.
+pub struct MobiledevicesGetRequest<'a> {
+    inner: crate::mobiledevices::MobiledevicesGetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> MobiledevicesGetRequest<'a> {
+    /// Sets the `projection` query parameter.
+    pub fn projection(mut self, projection: crate::types::Projection) -> Self {
+        self.inner = self.inner.projection(projection);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::MobileDevice>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::mobiledevices::Mobiledevices`].
+pub struct Mobiledevices {
+    inner: crate::mobiledevices::Mobiledevices,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Mobiledevices {
+    pub(crate) fn new(
+        inner: crate::mobiledevices::Mobiledevices,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Mobiledevices { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::mobiledevices::Mobiledevices::list`].
+    pub fn list(
+        &self,
+        customer_id: &str,
+        max_results: i64,
+        order_by: crate::types::DirectoryMobiledevicesListOrderBy,
+        page_token: &str,
+        projection: crate::types::Projection,
+        query: &str,
+        sort_order: crate::types::SortOrder,
+    ) -> ClientResult<crate::Response<Vec<crate::types::MobileDevice>>> {
+        self.runtime.block_on(self.inner.list(
+            customer_id,
+            max_results,
+            order_by,
+            page_token,
+            projection,
+            query,
+            sort_order,
+        ))
+    }
+    /// The blocking version of [`crate::mobiledevices::Mobiledevices::list_request`].
+    pub fn list_request<'a>(&'a self, customer_id: &'a str) -> MobiledevicesListRequest<'a> {
+        MobiledevicesListRequest {
+            inner: self.inner.list_request(customer_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::mobiledevices::Mobiledevices::list_all`].
+    pub fn list_all(
+        &self,
+        customer_id: &str,
+        order_by: crate::types::DirectoryMobiledevicesListOrderBy,
+        projection: crate::types::Projection,
+        query: &str,
+        sort_order: crate::types::SortOrder,
+    ) -> ClientResult<crate::Response<Vec<crate::types::MobileDevice>>> {
+        self.runtime.block_on(self.inner.list_all(
+            customer_id,
+            order_by,
+            projection,
+            query,
+            sort_order,
+        ))
+    }
+    /// The blocking version of [`crate::mobiledevices::Mobiledevices::get`].
+    pub fn get(
+        &self,
+        customer_id: &str,
+        resource_id: &str,
+        projection: crate::types::Projection,
+    ) -> ClientResult<crate::Response<crate::types::MobileDevice>> {
+        self.runtime
+            .block_on(self.inner.get(customer_id, resource_id, projection))
+    }
+    /// The blocking version of [`crate::mobiledevices::Mobiledevices::get_request`].
+    pub fn get_request<'a>(
+        &'a self,
+        customer_id: &'a str,
+        resource_id: &'a str,
+    ) -> MobiledevicesGetRequest<'a> {
+        MobiledevicesGetRequest {
+            inner: self.inner.get_request(customer_id, resource_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::mobiledevices::Mobiledevices::delete`].
+    pub fn delete(
+        &self,
+        customer_id: &str,
+        resource_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete(customer_id, resource_id))
+    }
+    /// The blocking version of [`crate::mobiledevices::Mobiledevices::action`].
+    pub fn action(
+        &self,
+        customer_id: &str,
+        resource_id: &str,
+        body: &crate::types::MobileDeviceAction,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.action(customer_id, resource_id, body))
+    }
+}
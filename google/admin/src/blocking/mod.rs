@@ -0,0 +1,166 @@
+//! A blocking client, for the programs that are not async.
+//!
+//! It has the functions of the async [`Client`](crate::Client), which it runs on a
+//! runtime of its own, so they must not be called from within an async runtime. The
+//! `*_stream` functions are left out, the `*_all` ones returning all the pages at once.
+
+use std::{future::Future, sync::Arc};
+
+pub mod asps;
+pub mod channels;
+pub mod chromeosdevices;
+pub mod customer;
+pub mod customers;
+pub mod domain_aliases;
+pub mod domains;
+pub mod groups;
+pub mod members;
+pub mod mobiledevices;
+pub mod orgunits;
+pub mod privileges;
+pub mod resources;
+pub mod role_assignments;
+pub mod roles;
+pub mod schemas;
+pub mod tokens;
+pub mod two_step_verification;
+pub mod users;
+pub mod verification_codes;
+
+/// A blocking client, sending its requests with an async [`Client`](crate::Client).
+#[derive(Clone)]
+pub struct Client {
+    inner: crate::Client,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Client {
+    /// Wraps an async client, creating the runtime its requests run on.
+    pub fn new(client: crate::Client) -> std::io::Result<Self> {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()?;
+
+        Ok(Client {
+            inner: client,
+            runtime: Arc::new(runtime),
+        })
+    }
+
+    /// Returns the async client the requests are sent with.
+    pub fn inner(&self) -> &crate::Client {
+        &self.inner
+    }
+
+    /// Runs a future to completion on the runtime of the client, for the async
+    /// functions that have no blocking version.
+    pub fn block_on<F: Future>(&self, future: F) -> F::Output {
+        self.runtime.block_on(future)
+    }
+
+    /// The blocking version of [`crate::Client::asps`].
+    pub fn asps(&self) -> asps::Asps {
+        asps::Asps::new(self.inner.asps(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::channels`].
+    pub fn channels(&self) -> channels::Channels {
+        channels::Channels::new(self.inner.channels(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::chromeosdevices`].
+    pub fn chromeosdevices(&self) -> chromeosdevices::Chromeosdevices {
+        chromeosdevices::Chromeosdevices::new(self.inner.chromeosdevices(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::customer`].
+    pub fn customer(&self) -> customer::Customer {
+        customer::Customer::new(self.inner.customer(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::customers`].
+    pub fn customers(&self) -> customers::Customers {
+        customers::Customers::new(self.inner.customers(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::domain_aliases`].
+    pub fn domain_aliases(&self) -> domain_aliases::DomainAliases {
+        domain_aliases::DomainAliases::new(self.inner.domain_aliases(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::domains`].
+    pub fn domains(&self) -> domains::Domains {
+        domains::Domains::new(self.inner.domains(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::groups`].
+    pub fn groups(&self) -> groups::Groups {
+        groups::Groups::new(self.inner.groups(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::members`].
+    pub fn members(&self) -> members::Members {
+        members::Members::new(self.inner.members(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::mobiledevices`].
+    pub fn mobiledevices(&self) -> mobiledevices::Mobiledevices {
+        mobiledevices::Mobiledevices::new(self.inner.mobiledevices(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::orgunits`].
+    pub fn orgunits(&self) -> orgunits::Orgunits {
+        orgunits::Orgunits::new(self.inner.orgunits(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::privileges`].
+    pub fn privileges(&self) -> privileges::Privileges {
+        privileges::Privileges::new(self.inner.privileges(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::resources`].
+    pub fn resources(&self) -> resources::Resources {
+        resources::Resources::new(self.inner.resources(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::role_assignments`].
+    pub fn role_assignments(&self) -> role_assignments::RoleAssignments {
+        role_assignments::RoleAssignments::new(self.inner.role_assignments(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::roles`].
+    pub fn roles(&self) -> roles::Roles {
+        roles::Roles::new(self.inner.roles(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::schemas`].
+    pub fn schemas(&self) -> schemas::Schemas {
+        schemas::Schemas::new(self.inner.schemas(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::tokens`].
+    pub fn tokens(&self) -> tokens::Tokens {
+        tokens::Tokens::new(self.inner.tokens(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::two_step_verification`].
+    pub fn two_step_verification(&self) -> two_step_verification::TwoStepVerification {
+        two_step_verification::TwoStepVerification::new(
+            self.inner.two_step_verification(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::users`].
+    pub fn users(&self) -> users::Users {
+        users::Users::new(self.inner.users(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::verification_codes`].
+    pub fn verification_codes(&self) -> verification_codes::VerificationCodes {
+        verification_codes::VerificationCodes::new(
+            self.inner.verification_codes(),
+            self.runtime.clone(),
+        )
+    }
+}
@@ -0,0 +1,107 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Orgunits::list`], set with a builder.
+pub struct OrgunitsListRequest<'a> {
+    inner: crate::orgunits::OrgunitsListRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OrgunitsListRequest<'a> {
+    /// Sets the `orgUnitPath` query parameter.
+    pub fn org_unit_path(mut self, org_unit_path: &'a str) -> Self {
+        self.inner = self.inner.org_unit_path(org_unit_path);
+        self
+    }
+
+    /// Sets the `type` query parameter.
+    pub fn type_(mut self, type_: crate::types::DirectoryOrgunitsListType) -> Self {
+        self.inner = self.inner.type_(type_);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::OrgUnits>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::orgunits::Orgunits`].
+pub struct Orgunits {
+    inner: crate::orgunits::Orgunits,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Orgunits {
+    pub(crate) fn new(
+        inner: crate::orgunits::Orgunits,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Orgunits { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::orgunits::Orgunits::list`].
+    pub fn list(
+        &self,
+        customer_id: &str,
+        org_unit_path: &str,
+        type_: crate::types::DirectoryOrgunitsListType,
+    ) -> ClientResult<crate::Response<crate::types::OrgUnits>> {
+        self.runtime
+            .block_on(self.inner.list(customer_id, org_unit_path, type_))
+    }
+    /// The blocking version of [`crate::orgunits::Orgunits::list_request`].
+    pub fn list_request<'a>(&'a self, customer_id: &'a str) -> OrgunitsListRequest<'a> {
+        OrgunitsListRequest {
+            inner: self.inner.list_request(customer_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::orgunits::Orgunits::insert`].
+    pub fn insert(
+        &self,
+        customer_id: &str,
+        body: &crate::types::OrgUnit,
+    ) -> ClientResult<crate::Response<crate::types::OrgUnit>> {
+        self.runtime.block_on(self.inner.insert(customer_id, body))
+    }
+    /// The blocking version of [`crate::orgunits::Orgunits::get`].
+    pub fn get(
+        &self,
+        customer_id: &str,
+        org_unit_path: &str,
+    ) -> ClientResult<crate::Response<crate::types::OrgUnit>> {
+        self.runtime
+            .block_on(self.inner.get(customer_id, org_unit_path))
+    }
+    /// The blocking version of [`crate::orgunits::Orgunits::update`].
+    pub fn update(
+        &self,
+        customer_id: &str,
+        org_unit_path: &str,
+        body: &crate::types::OrgUnit,
+    ) -> ClientResult<crate::Response<crate::types::OrgUnit>> {
+        self.runtime
+            .block_on(self.inner.update(customer_id, org_unit_path, body))
+    }
+    /// The blocking version of [`crate::orgunits::Orgunits::delete`].
+    pub fn delete(
+        &self,
+        customer_id: &str,
+        org_unit_path: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete(customer_id, org_unit_path))
+    }
+    /// The blocking version of [`crate::orgunits::Orgunits::patch`].
+    pub fn patch(
+        &self,
+        customer_id: &str,
+        org_unit_path: &str,
+        body: &crate::types::OrgUnit,
+    ) -> ClientResult<crate::Response<crate::types::OrgUnit>> {
+        self.runtime
+            .block_on(self.inner.patch(customer_id, org_unit_path, body))
+    }
+}
@@ -0,0 +1,23 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::privileges::Privileges`].
+pub struct Privileges {
+    inner: crate::privileges::Privileges,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Privileges {
+    pub(crate) fn new(
+        inner: crate::privileges::Privileges,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Privileges { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::privileges::Privileges::list`].
+    pub fn list(&self, customer: &str) -> ClientResult<crate::Response<crate::types::Privileges>> {
+        self.runtime.block_on(self.inner.list(customer))
+    }
+}
@@ -0,0 +1,472 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Resources::buildings_list`], set with a builder.
+pub struct ResourcesBuildingsListRequest<'a> {
+    inner: crate::resources::ResourcesBuildingsListRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ResourcesBuildingsListRequest<'a> {
+    /// Sets the `maxResults` query parameter.
+    pub fn max_results(mut self, max_results: i64) -> Self {
+        self.inner = self.inner.max_results(max_results);
+        self
+    }
+
+    /// Sets the `pageToken` query parameter.
+    pub fn page_token(mut self, page_token: &'a str) -> Self {
+        self.inner = self.inner.page_token(page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Building>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Resources::buildings_insert`], set with a builder.
+pub struct ResourcesBuildingsInsertRequest<'a> {
+    inner: crate::resources::ResourcesBuildingsInsertRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ResourcesBuildingsInsertRequest<'a> {
+    /// Sets the `coordinatesSource` query parameter.
+    pub fn coordinates_source(
+        mut self,
+        coordinates_source: crate::types::CoordinatesSource,
+    ) -> Self {
+        self.inner = self.inner.coordinates_source(coordinates_source);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::Building>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Resources::buildings_update`], set with a builder.
+pub struct ResourcesBuildingsUpdateRequest<'a> {
+    inner: crate::resources::ResourcesBuildingsUpdateRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ResourcesBuildingsUpdateRequest<'a> {
+    /// Sets the `coordinatesSource` query parameter.
+    pub fn coordinates_source(
+        mut self,
+        coordinates_source: crate::types::CoordinatesSource,
+    ) -> Self {
+        self.inner = self.inner.coordinates_source(coordinates_source);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::Building>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Resources::buildings_patch`], set with a builder.
+pub struct ResourcesBuildingsPatchRequest<'a> {
+    inner: crate::resources::ResourcesBuildingsPatchRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ResourcesBuildingsPatchRequest<'a> {
+    /// Sets the `coordinatesSource` query parameter.
+    pub fn coordinates_source(
+        mut self,
+        coordinates_source: crate::types::CoordinatesSource,
+    ) -> Self {
+        self.inner = self.inner.coordinates_source(coordinates_source);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::Building>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Resources::calendars_list`], set with a builder.
+pub struct ResourcesCalendarsListRequest<'a> {
+    inner: crate::resources::ResourcesCalendarsListRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ResourcesCalendarsListRequest<'a> {
+    /// Sets the `maxResults` query parameter.
+    pub fn max_results(mut self, max_results: i64) -> Self {
+        self.inner = self.inner.max_results(max_results);
+        self
+    }
+
+    /// Sets the `orderBy` query parameter.
+    pub fn order_by(mut self, order_by: &'a str) -> Self {
+        self.inner = self.inner.order_by(order_by);
+        self
+    }
+
+    /// Sets the `pageToken` query parameter.
+    pub fn page_token(mut self, page_token: &'a str) -> Self {
+        self.inner = self.inner.page_token(page_token);
+        self
+    }
+
+    /// Sets the `query` query parameter.
+    pub fn query(mut self, query: &'a str) -> Self {
+        self.inner = self.inner.query(query);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::CalendarResource>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Resources::features_list`], set with a builder.
+pub struct ResourcesFeaturesListRequest<'a> {
+    inner: crate::resources::ResourcesFeaturesListRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ResourcesFeaturesListRequest<'a> {
+    /// Sets the `maxResults` query parameter.
+    pub fn max_results(mut self, max_results: i64) -> Self {
+        self.inner = self.inner.max_results(max_results);
+        self
+    }
+
+    /// Sets the `pageToken` query parameter.
+    pub fn page_token(mut self, page_token: &'a str) -> Self {
+        self.inner = self.inner.page_token(page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Feature>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::resources::Resources`].
+pub struct Resources {
+    inner: crate::resources::Resources,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Resources {
+    pub(crate) fn new(
+        inner: crate::resources::Resources,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Resources { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::resources::Resources::buildings_list`].
+    pub fn buildings_list(
+        &self,
+        customer: &str,
+        max_results: i64,
+        page_token: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Building>>> {
+        self.runtime
+            .block_on(self.inner.buildings_list(customer, max_results, page_token))
+    }
+    /// The blocking version of [`crate::resources::Resources::buildings_list_request`].
+    pub fn buildings_list_request<'a>(
+        &'a self,
+        customer: &'a str,
+    ) -> ResourcesBuildingsListRequest<'a> {
+        ResourcesBuildingsListRequest {
+            inner: self.inner.buildings_list_request(customer),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::resources::Resources::buildings_list_all`].
+    pub fn buildings_list_all(
+        &self,
+        customer: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Building>>> {
+        self.runtime
+            .block_on(self.inner.buildings_list_all(customer))
+    }
+    /// The blocking version of [`crate::resources::Resources::buildings_insert`].
+    pub fn buildings_insert(
+        &self,
+        customer: &str,
+        coordinates_source: crate::types::CoordinatesSource,
+        body: &crate::types::Building,
+    ) -> ClientResult<crate::Response<crate::types::Building>> {
+        self.runtime.block_on(
+            self.inner
+                .buildings_insert(customer, coordinates_source, body),
+        )
+    }
+    /// The blocking version of [`crate::resources::Resources::buildings_insert_request`].
+    pub fn buildings_insert_request<'a>(
+        &'a self,
+        customer: &'a str,
+        body: &'a crate::types::Building,
+    ) -> ResourcesBuildingsInsertRequest<'a> {
+        ResourcesBuildingsInsertRequest {
+            inner: self.inner.buildings_insert_request(customer, body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::resources::Resources::buildings_get`].
+    pub fn buildings_get(
+        &self,
+        customer: &str,
+        building_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Building>> {
+        self.runtime
+            .block_on(self.inner.buildings_get(customer, building_id))
+    }
+    /// The blocking version of [`crate::resources::Resources::buildings_update`].
+    pub fn buildings_update(
+        &self,
+        customer: &str,
+        building_id: &str,
+        coordinates_source: crate::types::CoordinatesSource,
+        body: &crate::types::Building,
+    ) -> ClientResult<crate::Response<crate::types::Building>> {
+        self.runtime.block_on(self.inner.buildings_update(
+            customer,
+            building_id,
+            coordinates_source,
+            body,
+        ))
+    }
+    /// The blocking version of [`crate::resources::Resources::buildings_update_request`].
+    pub fn buildings_update_request<'a>(
+        &'a self,
+        customer: &'a str,
+        building_id: &'a str,
+        body: &'a crate::types::Building,
+    ) -> ResourcesBuildingsUpdateRequest<'a> {
+        ResourcesBuildingsUpdateRequest {
+            inner: self
+                .inner
+                .buildings_update_request(customer, building_id, body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::resources::Resources::buildings_delete`].
+    pub fn buildings_delete(
+        &self,
+        customer: &str,
+        building_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.buildings_delete(customer, building_id))
+    }
+    /// The blocking version of [`crate::resources::Resources::buildings_patch`].
+    pub fn buildings_patch(
+        &self,
+        customer: &str,
+        building_id: &str,
+        coordinates_source: crate::types::CoordinatesSource,
+        body: &crate::types::Building,
+    ) -> ClientResult<crate::Response<crate::types::Building>> {
+        self.runtime.block_on(self.inner.buildings_patch(
+            customer,
+            building_id,
+            coordinates_source,
+            body,
+        ))
+    }
+    /// The blocking version of [`crate::resources::Resources::buildings_patch_request`].
+    pub fn buildings_patch_request<'a>(
+        &'a self,
+        customer: &'a str,
+        building_id: &'a str,
+        body: &'a crate::types::Building,
+    ) -> ResourcesBuildingsPatchRequest<'a> {
+        ResourcesBuildingsPatchRequest {
+            inner: self
+                .inner
+                .buildings_patch_request(customer, building_id, body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::resources::Resources::calendars_list`].
+    pub fn calendars_list(
+        &self,
+        customer: &str,
+        max_results: i64,
+        order_by: &str,
+        page_token: &str,
+        query: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::CalendarResource>>> {
+        self.runtime.block_on(self.inner.calendars_list(
+            customer,
+            max_results,
+            order_by,
+            page_token,
+            query,
+        ))
+    }
+    /// The blocking version of [`crate::resources::Resources::calendars_list_request`].
+    pub fn calendars_list_request<'a>(
+        &'a self,
+        customer: &'a str,
+    ) -> ResourcesCalendarsListRequest<'a> {
+        ResourcesCalendarsListRequest {
+            inner: self.inner.calendars_list_request(customer),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::resources::Resources::calendars_list_all`].
+    pub fn calendars_list_all(
+        &self,
+        customer: &str,
+        order_by: &str,
+        query: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::CalendarResource>>> {
+        self.runtime
+            .block_on(self.inner.calendars_list_all(customer, order_by, query))
+    }
+    /// The blocking version of [`crate::resources::Resources::calendars_insert`].
+    pub fn calendars_insert(
+        &self,
+        customer: &str,
+        body: &crate::types::CalendarResource,
+    ) -> ClientResult<crate::Response<crate::types::CalendarResource>> {
+        self.runtime
+            .block_on(self.inner.calendars_insert(customer, body))
+    }
+    /// The blocking version of [`crate::resources::Resources::calendars_get`].
+    pub fn calendars_get(
+        &self,
+        customer: &str,
+        calendar_resource_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::CalendarResource>> {
+        self.runtime
+            .block_on(self.inner.calendars_get(customer, calendar_resource_id))
+    }
+    /// The blocking version of [`crate::resources::Resources::calendars_update`].
+    pub fn calendars_update(
+        &self,
+        customer: &str,
+        calendar_resource_id: &str,
+        body: &crate::types::CalendarResource,
+    ) -> ClientResult<crate::Response<crate::types::CalendarResource>> {
+        self.runtime.block_on(
+            self.inner
+                .calendars_update(customer, calendar_resource_id, body),
+        )
+    }
+    /// The blocking version of [`crate::resources::Resources::calendars_delete`].
+    pub fn calendars_delete(
+        &self,
+        customer: &str,
+        calendar_resource_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.calendars_delete(customer, calendar_resource_id))
+    }
+    /// The blocking version of [`crate::resources::Resources::calendars_patch`].
+    pub fn calendars_patch(
+        &self,
+        customer: &str,
+        calendar_resource_id: &str,
+        body: &crate::types::CalendarResource,
+    ) -> ClientResult<crate::Response<crate::types::CalendarResource>> {
+        self.runtime.block_on(
+            self.inner
+                .calendars_patch(customer, calendar_resource_id, body),
+        )
+    }
+    /// The blocking version of [`crate::resources::Resources::features_list`].
+    pub fn features_list(
+        &self,
+        customer: &str,
+        max_results: i64,
+        page_token: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Feature>>> {
+        self.runtime
+            .block_on(self.inner.features_list(customer, max_results, page_token))
+    }
+    /// The blocking version of [`crate::resources::Resources::features_list_request`].
+    pub fn features_list_request<'a>(
+        &'a self,
+        customer: &'a str,
+    ) -> ResourcesFeaturesListRequest<'a> {
+        ResourcesFeaturesListRequest {
+            inner: self.inner.features_list_request(customer),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::resources::Resources::features_list_all`].
+    pub fn features_list_all(
+        &self,
+        customer: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Feature>>> {
+        self.runtime
+            .block_on(self.inner.features_list_all(customer))
+    }
+    /// The blocking version of [`crate::resources::Resources::features_insert`].
+    pub fn features_insert(
+        &self,
+        customer: &str,
+        body: &crate::types::Feature,
+    ) -> ClientResult<crate::Response<crate::types::Feature>> {
+        self.runtime
+            .block_on(self.inner.features_insert(customer, body))
+    }
+    /// The blocking version of [`crate::resources::Resources::features_get`].
+    pub fn features_get(
+        &self,
+        customer: &str,
+        feature_key: &str,
+    ) -> ClientResult<crate::Response<crate::types::Feature>> {
+        self.runtime
+            .block_on(self.inner.features_get(customer, feature_key))
+    }
+    /// The blocking version of [`crate::resources::Resources::features_update`].
+    pub fn features_update(
+        &self,
+        customer: &str,
+        feature_key: &str,
+        body: &crate::types::Feature,
+    ) -> ClientResult<crate::Response<crate::types::Feature>> {
+        self.runtime
+            .block_on(self.inner.features_update(customer, feature_key, body))
+    }
+    /// The blocking version of [`crate::resources::Resources::features_delete`].
+    pub fn features_delete(
+        &self,
+        customer: &str,
+        feature_key: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.features_delete(customer, feature_key))
+    }
+    /// The blocking version of [`crate::resources::Resources::features_patch`].
+    pub fn features_patch(
+        &self,
+        customer: &str,
+        feature_key: &str,
+        body: &crate::types::Feature,
+    ) -> ClientResult<crate::Response<crate::types::Feature>> {
+        self.runtime
+            .block_on(self.inner.features_patch(customer, feature_key, body))
+    }
+    /// The blocking version of [`crate::resources::Resources::features_rename`].
+    pub fn features_rename(
+        &self,
+        customer: &str,
+        old_name: &str,
+        body: &crate::types::FeatureRename,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.features_rename(customer, old_name, body))
+    }
+}
@@ -0,0 +1,113 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`RoleAssignments::list`], set with a builder.
+pub struct RoleAssignmentsListRequest<'a> {
+    inner: crate::role_assignments::RoleAssignmentsListRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> RoleAssignmentsListRequest<'a> {
+    /// Sets the `maxResults` query parameter.
+    pub fn max_results(mut self, max_results: i64) -> Self {
+        self.inner = self.inner.max_results(max_results);
+        self
+    }
+
+    /// Sets the `pageToken` query parameter.
+    pub fn page_token(mut self, page_token: &'a str) -> Self {
+        self.inner = self.inner.page_token(page_token);
+        self
+    }
+
+    /// Sets the `roleId` query parameter.
+    pub fn role_id(mut self, role_id: &'a str) -> Self {
+        self.inner = self.inner.role_id(role_id);
+        self
+    }
+
+    /// Sets the `userKey` query parameter.
+    pub fn user_key(mut self, user_key: &'a str) -> Self {
+        self.inner = self.inner.user_key(user_key);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::RoleAssignment>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::role_assignments::RoleAssignments`].
+pub struct RoleAssignments {
+    inner: crate::role_assignments::RoleAssignments,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl RoleAssignments {
+    pub(crate) fn new(
+        inner: crate::role_assignments::RoleAssignments,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        RoleAssignments { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::role_assignments::RoleAssignments::list`].
+    pub fn list(
+        &self,
+        customer: &str,
+        max_results: i64,
+        page_token: &str,
+        role_id: &str,
+        user_key: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::RoleAssignment>>> {
+        self.runtime.block_on(
+            self.inner
+                .list(customer, max_results, page_token, role_id, user_key),
+        )
+    }
+    /// The blocking version of [`crate::role_assignments::RoleAssignments::list_request`].
+    pub fn list_request<'a>(&'a self, customer: &'a str) -> RoleAssignmentsListRequest<'a> {
+        RoleAssignmentsListRequest {
+            inner: self.inner.list_request(customer),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::role_assignments::RoleAssignments::list_all`].
+    pub fn list_all(
+        &self,
+        customer: &str,
+        role_id: &str,
+        user_key: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::RoleAssignment>>> {
+        self.runtime
+            .block_on(self.inner.list_all(customer, role_id, user_key))
+    }
+    /// The blocking version of [`crate::role_assignments::RoleAssignments::insert`].
+    pub fn insert(
+        &self,
+        customer: &str,
+        body: &crate::types::RoleAssignment,
+    ) -> ClientResult<crate::Response<crate::types::RoleAssignment>> {
+        self.runtime.block_on(self.inner.insert(customer, body))
+    }
+    /// The blocking version of [`crate::role_assignments::RoleAssignments::get`].
+    pub fn get(
+        &self,
+        customer: &str,
+        role_assignment_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::RoleAssignment>> {
+        self.runtime
+            .block_on(self.inner.get(customer, role_assignment_id))
+    }
+    /// The blocking version of [`crate::role_assignments::RoleAssignments::delete`].
+    pub fn delete(
+        &self,
+        customer: &str,
+        role_assignment_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete(customer, role_assignment_id))
+    }
+}
@@ -0,0 +1,105 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Roles::list`], set with a builder.
+pub struct RolesListRequest<'a> {
+    inner: crate::roles::RolesListRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> RolesListRequest<'a> {
+    /// Sets the `maxResults` query parameter.
+    pub fn max_results(mut self, max_results: i64) -> Self {
+        self.inner = self.inner.max_results(max_results);
+        self
+    }
+
+    /// Sets the `pageToken` query parameter.
+    pub fn page_token(mut self, page_token: &'a str) -> Self {
+        self.inner = self.inner.page_token(page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Role>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::roles::Roles`].
+pub struct Roles {
+    inner: crate::roles::Roles,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Roles {
+    pub(crate) fn new(inner: crate::roles::Roles, runtime: Arc<tokio::runtime::Runtime>) -> Self {
+        Roles { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::roles::Roles::list`].
+    pub fn list(
+        &self,
+        customer: &str,
+        max_results: i64,
+        page_token: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Role>>> {
+        self.runtime
+            .block_on(self.inner.list(customer, max_results, page_token))
+    }
+    /// The blocking version of [`crate::roles::Roles::list_request`].
+    pub fn list_request<'a>(&'a self, customer: &'a str) -> RolesListRequest<'a> {
+        RolesListRequest {
+            inner: self.inner.list_request(customer),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::roles::Roles::list_all`].
+    pub fn list_all(
+        &self,
+        customer: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Role>>> {
+        self.runtime.block_on(self.inner.list_all(customer))
+    }
+    /// The blocking version of [`crate::roles::Roles::insert`].
+    pub fn insert(
+        &self,
+        customer: &str,
+        body: &crate::types::Role,
+    ) -> ClientResult<crate::Response<crate::types::Role>> {
+        self.runtime.block_on(self.inner.insert(customer, body))
+    }
+    /// The blocking version of [`crate::roles::Roles::get`].
+    pub fn get(
+        &self,
+        customer: &str,
+        role_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Role>> {
+        self.runtime.block_on(self.inner.get(customer, role_id))
+    }
+    /// The blocking version of [`crate::roles::Roles::update`].
+    pub fn update(
+        &self,
+        customer: &str,
+        role_id: &str,
+        body: &crate::types::Role,
+    ) -> ClientResult<crate::Response<crate::types::Role>> {
+        self.runtime
+            .block_on(self.inner.update(customer, role_id, body))
+    }
+    /// The blocking version of [`crate::roles::Roles::delete`].
+    pub fn delete(&self, customer: &str, role_id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.delete(customer, role_id))
+    }
+    /// The blocking version of [`crate::roles::Roles::patch`].
+    pub fn patch(
+        &self,
+        customer: &str,
+        role_id: &str,
+        body: &crate::types::Role,
+    ) -> ClientResult<crate::Response<crate::types::Role>> {
+        self.runtime
+            .block_on(self.inner.patch(customer, role_id, body))
+    }
+}
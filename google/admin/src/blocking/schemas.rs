@@ -0,0 +1,65 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::schemas::Schemas`].
+pub struct Schemas {
+    inner: crate::schemas::Schemas,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Schemas {
+    pub(crate) fn new(
+        inner: crate::schemas::Schemas,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Schemas { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::schemas::Schemas::list`].
+    pub fn list(&self, customer_id: &str) -> ClientResult<crate::Response<crate::types::Schemas>> {
+        self.runtime.block_on(self.inner.list(customer_id))
+    }
+    /// The blocking version of [`crate::schemas::Schemas::insert`].
+    pub fn insert(
+        &self,
+        customer_id: &str,
+        body: &crate::types::Schema,
+    ) -> ClientResult<crate::Response<crate::types::Schema>> {
+        self.runtime.block_on(self.inner.insert(customer_id, body))
+    }
+    /// The blocking version of [`crate::schemas::Schemas::get`].
+    pub fn get(
+        &self,
+        customer_id: &str,
+        schema_key: &str,
+    ) -> ClientResult<crate::Response<crate::types::Schema>> {
+        self.runtime
+            .block_on(self.inner.get(customer_id, schema_key))
+    }
+    /// The blocking version of [`crate::schemas::Schemas::update`].
+    pub fn update(
+        &self,
+        customer_id: &str,
+        schema_key: &str,
+        body: &crate::types::Schema,
+    ) -> ClientResult<crate::Response<crate::types::Schema>> {
+        self.runtime
+            .block_on(self.inner.update(customer_id, schema_key, body))
+    }
+    /// The blocking version of [`crate::schemas::Schemas::delete`].
+    pub fn delete(&self, customer_id: &str, schema_key: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete(customer_id, schema_key))
+    }
+    /// The blocking version of [`crate::schemas::Schemas::patch`].
+    pub fn patch(
+        &self,
+        customer_id: &str,
+        schema_key: &str,
+        body: &crate::types::Schema,
+    ) -> ClientResult<crate::Response<crate::types::Schema>> {
+        self.runtime
+            .block_on(self.inner.patch(customer_id, schema_key, body))
+    }
+}
@@ -0,0 +1,33 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::tokens::Tokens`].
+pub struct Tokens {
+    inner: crate::tokens::Tokens,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Tokens {
+    pub(crate) fn new(inner: crate::tokens::Tokens, runtime: Arc<tokio::runtime::Runtime>) -> Self {
+        Tokens { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::tokens::Tokens::list`].
+    pub fn list(&self, user_key: &str) -> ClientResult<crate::Response<crate::types::Tokens>> {
+        self.runtime.block_on(self.inner.list(user_key))
+    }
+    /// The blocking version of [`crate::tokens::Tokens::get`].
+    pub fn get(
+        &self,
+        user_key: &str,
+        client_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Token>> {
+        self.runtime.block_on(self.inner.get(user_key, client_id))
+    }
+    /// The blocking version of [`crate::tokens::Tokens::delete`].
+    pub fn delete(&self, user_key: &str, client_id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete(user_key, client_id))
+    }
+}
@@ -0,0 +1,23 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::two_step_verification::TwoStepVerification`].
+pub struct TwoStepVerification {
+    inner: crate::two_step_verification::TwoStepVerification,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl TwoStepVerification {
+    pub(crate) fn new(
+        inner: crate::two_step_verification::TwoStepVerification,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        TwoStepVerification { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::two_step_verification::TwoStepVerification::turn_off`].
+    pub fn turn_off(&self, user_key: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.turn_off(user_key))
+    }
+}
@@ -0,0 +1,480 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Users::list`], set with a builder.
+pub struct UsersListRequest<'a> {
+    inner: crate::users::UsersListRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> UsersListRequest<'a> {
+    /// Sets the `customer` query parameter.
+    pub fn customer(mut self, customer: &'a str) -> Self {
+        self.inner = self.inner.customer(customer);
+        self
+    }
+
+    /// Sets the `domain` query parameter.
+    pub fn domain(mut self, domain: &'a str) -> Self {
+        self.inner = self.inner.domain(domain);
+        self
+    }
+
+    /// Sets the `event` query parameter.
+    pub fn event(mut self, event: crate::types::Event) -> Self {
+        self.inner = self.inner.event(event);
+        self
+    }
+
+    /// Sets the `maxResults` query parameter.
+    pub fn max_results(mut self, max_results: i64) -> Self {
+        self.inner = self.inner.max_results(max_results);
+        self
+    }
+
+    /// Sets the `orderBy` query parameter.
+    pub fn order_by(mut self, order_by: crate::types::DirectoryUsersListOrderBy) -> Self {
+        self.inner = self.inner.order_by(order_by);
+        self
+    }
+
+    /// Sets the `pageToken` query parameter.
+    pub fn page_token(mut self, page_token: &'a str) -> Self {
+        self.inner = self.inner.page_token(page_token);
+        self
+    }
+
+    /// Sets the `projection` query parameter.
+    pub fn projection(mut self, projection: crate::types::DirectoryUsersListProjection) -> Self {
+        self.inner = self.inner.projection(projection);
+        self
+    }
+
+    /// Sets the `query` query parameter.
+    pub fn query(mut self, query: &'a str) -> Self {
+        self.inner = self.inner.query(query);
+        self
+    }
+
+    /// Sets the `showDeleted` query parameter.
+    pub fn show_deleted(mut self, show_deleted: &'a str) -> Self {
+        self.inner = self.inner.show_deleted(show_deleted);
+        self
+    }
+
+    /// Sets the `sortOrder` query parameter.
+    pub fn sort_order(mut self, sort_order: crate::types::SortOrder) -> Self {
+        self.inner = self.inner.sort_order(sort_order);
+        self
+    }
+
+    /// Sets the `viewType` query parameter.
+    pub fn view_type(mut self, view_type: crate::types::ViewType) -> Self {
+        self.inner = self.inner.view_type(view_type);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::User>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Users::watch`], set with a builder.
+pub struct UsersWatchRequest<'a> {
+    inner: crate::users::UsersWatchRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> UsersWatchRequest<'a> {
+    /// Sets the `customer` query parameter.
+    pub fn customer(mut self, customer: &'a str) -> Self {
+        self.inner = self.inner.customer(customer);
+        self
+    }
+
+    /// Sets the `domain` query parameter.
+    pub fn domain(mut self, domain: &'a str) -> Self {
+        self.inner = self.inner.domain(domain);
+        self
+    }
+
+    /// Sets the `event` query parameter.
+    pub fn event(mut self, event: crate::types::Event) -> Self {
+        self.inner = self.inner.event(event);
+        self
+    }
+
+    /// Sets the `maxResults` query parameter.
+    pub fn max_results(mut self, max_results: i64) -> Self {
+        self.inner = self.inner.max_results(max_results);
+        self
+    }
+
+    /// Sets the `orderBy` query parameter.
+    pub fn order_by(mut self, order_by: crate::types::DirectoryUsersListOrderBy) -> Self {
+        self.inner = self.inner.order_by(order_by);
+        self
+    }
+
+    /// Sets the `pageToken` query parameter.
+    pub fn page_token(mut self, page_token: &'a str) -> Self {
+        self.inner = self.inner.page_token(page_token);
+        self
+    }
+
+    /// Sets the `projection` query parameter.
+    pub fn projection(mut self, projection: crate::types::DirectoryUsersListProjection) -> Self {
+        self.inner = self.inner.projection(projection);
+        self
+    }
+
+    /// Sets the `query` query parameter.
+    pub fn query(mut self, query: &'a str) -> Self {
+        self.inner = self.inner.query(query);
+        self
+    }
+
+    /// Sets the `showDeleted` query parameter.
+    pub fn show_deleted(mut self, show_deleted: &'a str) -> Self {
+        self.inner = self.inner.show_deleted(show_deleted);
+        self
+    }
+
+    /// Sets the `sortOrder` query parameter.
+    pub fn sort_order(mut self, sort_order: crate::types::SortOrder) -> Self {
+        self.inner = self.inner.sort_order(sort_order);
+        self
+    }
+
+    /// Sets the `viewType` query parameter.
+    pub fn view_type(mut self, view_type: crate::types::ViewType) -> Self {
+        self.inner = self.inner.view_type(view_type);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::Channel>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Users::get`], set with a builder.
+pub struct UsersGetRequest<'a> {
+    inner: crate::users::UsersGetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> UsersGetRequest<'a> {
+    /// Sets the `projection` query parameter.
+    pub fn projection(mut self, projection: crate::types::DirectoryUsersListProjection) -> Self {
+        self.inner = self.inner.projection(projection);
+        self
+    }
+
+    /// Sets the `viewType` query parameter.
+    pub fn view_type(mut self, view_type: crate::types::ViewType) -> Self {
+        self.inner = self.inner.view_type(view_type);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::User>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Users::aliases_list`], set with a builder.
+pub struct UsersAliasesListRequest<'a> {
+    inner: crate::users::UsersAliasesListRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> UsersAliasesListRequest<'a> {
+    /// Sets the `event` query parameter.
+    pub fn event(mut self, event: crate::types::DirectoryUsersAliasesListEvent) -> Self {
+        self.inner = self.inner.event(event);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::Aliases>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Users::aliases_watch`], set with a builder.
+pub struct UsersAliasesWatchRequest<'a> {
+    inner: crate::users::UsersAliasesWatchRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> UsersAliasesWatchRequest<'a> {
+    /// Sets the `event` query parameter.
+    pub fn event(mut self, event: crate::types::DirectoryUsersAliasesListEvent) -> Self {
+        self.inner = self.inner.event(event);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::Channel>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::users::Users`].
+pub struct Users {
+    inner: crate::users::Users,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Users {
+    pub(crate) fn new(inner: crate::users::Users, runtime: Arc<tokio::runtime::Runtime>) -> Self {
+        Users { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::users::Users::list`].
+    pub fn list(
+        &self,
+        customer: &str,
+        domain: &str,
+        event: crate::types::Event,
+        max_results: i64,
+        order_by: crate::types::DirectoryUsersListOrderBy,
+        page_token: &str,
+        projection: crate::types::DirectoryUsersListProjection,
+        query: &str,
+        show_deleted: &str,
+        sort_order: crate::types::SortOrder,
+        view_type: crate::types::ViewType,
+    ) -> ClientResult<crate::Response<Vec<crate::types::User>>> {
+        self.runtime.block_on(self.inner.list(
+            customer,
+            domain,
+            event,
+            max_results,
+            order_by,
+            page_token,
+            projection,
+            query,
+            show_deleted,
+            sort_order,
+            view_type,
+        ))
+    }
+    /// The blocking version of [`crate::users::Users::list_request`].
+    pub fn list_request(&self) -> UsersListRequest<'_> {
+        UsersListRequest {
+            inner: self.inner.list_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::users::Users::list_all`].
+    pub fn list_all(
+        &self,
+        customer: &str,
+        domain: &str,
+        event: crate::types::Event,
+        order_by: crate::types::DirectoryUsersListOrderBy,
+        projection: crate::types::DirectoryUsersListProjection,
+        query: &str,
+        show_deleted: &str,
+        sort_order: crate::types::SortOrder,
+        view_type: crate::types::ViewType,
+    ) -> ClientResult<crate::Response<Vec<crate::types::User>>> {
+        self.runtime.block_on(self.inner.list_all(
+            customer,
+            domain,
+            event,
+            order_by,
+            projection,
+            query,
+            show_deleted,
+            sort_order,
+            view_type,
+        ))
+    }
+    /// The blocking version of [`crate::users::Users::insert`].
+    pub fn insert(
+        &self,
+        body: &crate::types::User,
+    ) -> ClientResult<crate::Response<crate::types::User>> {
+        self.runtime.block_on(self.inner.insert(body))
+    }
+    /// The blocking version of [`crate::users::Users::watch`].
+    pub fn watch(
+        &self,
+        customer: &str,
+        domain: &str,
+        event: crate::types::Event,
+        max_results: i64,
+        order_by: crate::types::DirectoryUsersListOrderBy,
+        page_token: &str,
+        projection: crate::types::DirectoryUsersListProjection,
+        query: &str,
+        show_deleted: &str,
+        sort_order: crate::types::SortOrder,
+        view_type: crate::types::ViewType,
+        body: &crate::types::Channel,
+    ) -> ClientResult<crate::Response<crate::types::Channel>> {
+        self.runtime.block_on(self.inner.watch(
+            customer,
+            domain,
+            event,
+            max_results,
+            order_by,
+            page_token,
+            projection,
+            query,
+            show_deleted,
+            sort_order,
+            view_type,
+            body,
+        ))
+    }
+    /// The blocking version of [`crate::users::Users::watch_request`].
+    pub fn watch_request<'a>(&'a self, body: &'a crate::types::Channel) -> UsersWatchRequest<'a> {
+        UsersWatchRequest {
+            inner: self.inner.watch_request(body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::users::Users::get`].
+    pub fn get(
+        &self,
+        user_key: &str,
+        projection: crate::types::DirectoryUsersListProjection,
+        view_type: crate::types::ViewType,
+    ) -> ClientResult<crate::Response<crate::types::User>> {
+        self.runtime
+            .block_on(self.inner.get(user_key, projection, view_type))
+    }
+    /// The blocking version of [`crate::users::Users::get_request`].
+    pub fn get_request<'a>(&'a self, user_key: &'a str) -> UsersGetRequest<'a> {
+        UsersGetRequest {
+            inner: self.inner.get_request(user_key),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::users::Users::update`].
+    pub fn update(
+        &self,
+        user_key: &str,
+        body: &crate::types::User,
+    ) -> ClientResult<crate::Response<crate::types::User>> {
+        self.runtime.block_on(self.inner.update(user_key, body))
+    }
+    /// The blocking version of [`crate::users::Users::delete`].
+    pub fn delete(&self, user_key: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.delete(user_key))
+    }
+    /// The blocking version of [`crate::users::Users::patch`].
+    pub fn patch(
+        &self,
+        user_key: &str,
+        body: &crate::types::User,
+    ) -> ClientResult<crate::Response<crate::types::User>> {
+        self.runtime.block_on(self.inner.patch(user_key, body))
+    }
+    /// The blocking version of [`crate::users::Users::aliases_list`].
+    pub fn aliases_list(
+        &self,
+        user_key: &str,
+        event: crate::types::DirectoryUsersAliasesListEvent,
+    ) -> ClientResult<crate::Response<crate::types::Aliases>> {
+        self.runtime
+            .block_on(self.inner.aliases_list(user_key, event))
+    }
+    /// The blocking version of [`crate::users::Users::aliases_list_request`].
+    pub fn aliases_list_request<'a>(&'a self, user_key: &'a str) -> UsersAliasesListRequest<'a> {
+        UsersAliasesListRequest {
+            inner: self.inner.aliases_list_request(user_key),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::users::Users::aliases_insert`].
+    pub fn aliases_insert(
+        &self,
+        user_key: &str,
+        body: &crate::types::Alias,
+    ) -> ClientResult<crate::Response<crate::types::Alias>> {
+        self.runtime
+            .block_on(self.inner.aliases_insert(user_key, body))
+    }
+    /// The blocking version of [`crate::users::Users::aliases_watch`].
+    pub fn aliases_watch(
+        &self,
+        user_key: &str,
+        event: crate::types::DirectoryUsersAliasesListEvent,
+        body: &crate::types::Channel,
+    ) -> ClientResult<crate::Response<crate::types::Channel>> {
+        self.runtime
+            .block_on(self.inner.aliases_watch(user_key, event, body))
+    }
+    /// The blocking version of [`crate::users::Users::aliases_watch_request`].
+    pub fn aliases_watch_request<'a>(
+        &'a self,
+        user_key: &'a str,
+        body: &'a crate::types::Channel,
+    ) -> UsersAliasesWatchRequest<'a> {
+        UsersAliasesWatchRequest {
+            inner: self.inner.aliases_watch_request(user_key, body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::users::Users::aliases_delete`].
+    pub fn aliases_delete(&self, user_key: &str, alias: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.aliases_delete(user_key, alias))
+    }
+    /// The blocking version of [`crate::users::Users::make_admin`].
+    pub fn make_admin(
+        &self,
+        user_key: &str,
+        body: &crate::types::UserMakeAdmin,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.make_admin(user_key, body))
+    }
+    /// The blocking version of [`crate::users::Users::photos_get`].
+    pub fn photos_get(
+        &self,
+        user_key: &str,
+    ) -> ClientResult<crate::Response<crate::types::UserPhoto>> {
+        self.runtime.block_on(self.inner.photos_get(user_key))
+    }
+    /// The blocking version of [`crate::users::Users::photos_update`].
+    pub fn photos_update(
+        &self,
+        user_key: &str,
+        body: &crate::types::UserPhoto,
+    ) -> ClientResult<crate::Response<crate::types::UserPhoto>> {
+        self.runtime
+            .block_on(self.inner.photos_update(user_key, body))
+    }
+    /// The blocking version of [`crate::users::Users::photos_delete`].
+    pub fn photos_delete(&self, user_key: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.photos_delete(user_key))
+    }
+    /// The blocking version of [`crate::users::Users::photos_patch`].
+    pub fn photos_patch(
+        &self,
+        user_key: &str,
+        body: &crate::types::UserPhoto,
+    ) -> ClientResult<crate::Response<crate::types::UserPhoto>> {
+        self.runtime
+            .block_on(self.inner.photos_patch(user_key, body))
+    }
+    /// The blocking version of [`crate::users::Users::sign_out`].
+    pub fn sign_out(&self, user_key: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.sign_out(user_key))
+    }
+    /// The blocking version of [`crate::users::Users::undelete`].
+    pub fn undelete(
+        &self,
+        user_key: &str,
+        body: &crate::types::UserUndelete,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.undelete(user_key, body))
+    }
+}
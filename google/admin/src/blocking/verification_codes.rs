@@ -0,0 +1,34 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::verification_codes::VerificationCodes`].
+pub struct VerificationCodes {
+    inner: crate::verification_codes::VerificationCodes,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl VerificationCodes {
+    pub(crate) fn new(
+        inner: crate::verification_codes::VerificationCodes,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        VerificationCodes { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::verification_codes::VerificationCodes::list`].
+    pub fn list(
+        &self,
+        user_key: &str,
+    ) -> ClientResult<crate::Response<crate::types::VerificationCodes>> {
+        self.runtime.block_on(self.inner.list(user_key))
+    }
+    /// The blocking version of [`crate::verification_codes::VerificationCodes::generate`].
+    pub fn generate(&self, user_key: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.generate(user_key))
+    }
+    /// The blocking version of [`crate::verification_codes::VerificationCodes::invalidate`].
+    pub fn invalidate(&self, user_key: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.invalidate(user_key))
+    }
+}
@@ -1,6 +1,284 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Chromeosdevices::list`], set with a builder.
+pub struct ChromeosdevicesListRequest<'a> {
+    client: &'a Chromeosdevices,
+    customer_id: &'a str,
+    max_results: Option<i64>,
+    order_by: Option<crate::types::OrderBy>,
+    org_unit_path: Option<&'a str>,
+    page_token: Option<&'a str>,
+    projection: Option<crate::types::Projection>,
+    query: Option<&'a str>,
+    sort_order: Option<crate::types::SortOrder>,
+}
+
+impl<'a> ChromeosdevicesListRequest<'a> {
+    /// Sets the `maxResults` query parameter.
+    pub fn max_results(mut self, max_results: i64) -> Self {
+        self.max_results = Some(max_results);
+        self
+    }
+
+    /// Sets the `orderBy` query parameter.
+    pub fn order_by(mut self, order_by: crate::types::OrderBy) -> Self {
+        self.order_by = Some(order_by);
+        self
+    }
+
+    /// Sets the `orgUnitPath` query parameter.
+    pub fn org_unit_path(mut self, org_unit_path: &'a str) -> Self {
+        self.org_unit_path = Some(org_unit_path);
+        self
+    }
+
+    /// Sets the `pageToken` query parameter.
+    pub fn page_token(mut self, page_token: &'a str) -> Self {
+        self.page_token = Some(page_token);
+        self
+    }
+
+    /// Sets the `projection` query parameter.
+    pub fn projection(mut self, projection: crate::types::Projection) -> Self {
+        self.projection = Some(projection);
+        self
+    }
+
+    /// Sets the `query` query parameter.
+    pub fn query(mut self, query: &'a str) -> Self {
+        self.query = Some(query);
+        self
+    }
+
+    /// Sets the `sortOrder` query parameter.
+    pub fn sort_order(mut self, sort_order: crate::types::SortOrder) -> Self {
+        self.sort_order = Some(sort_order);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::ChromeOsDevice>>> {
+        let Self {
+            client,
+            customer_id,
+            max_results,
+            order_by,
+            org_unit_path,
+            page_token,
+            projection,
+            query,
+            sort_order,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = max_results {
+            query_args.push(("maxResults".to_string(), v.to_string()));
+        }
+        if let Some(v) = order_by {
+            query_args.push(("orderBy".to_string(), v.to_string()));
+        }
+        if let Some(v) = org_unit_path {
+            query_args.push(("orgUnitPath".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_token {
+            query_args.push(("pageToken".to_string(), v.to_string()));
+        }
+        if let Some(v) = projection {
+            query_args.push(("projection".to_string(), v.to_string()));
+        }
+        if let Some(v) = query {
+            query_args.push(("query".to_string(), v.to_string()));
+        }
+        if let Some(v) = sort_order {
+            query_args.push(("sortOrder".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/directory/v1/customer/{}/devices/chromeos?{}",
+                crate::progenitor_support::encode_path(customer_id),
+                query_
+            ),
+            None,
+        );
+
+        let resp: crate::Response<crate::types::ChromeOsDevices> = client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await?;
+
+        // Return our response data.
+        Ok(crate::Response::new(
+            resp.status,
+            resp.headers,
+            resp.body.chromeosdevices.to_vec(),
+        ))
+    }
+}
+
+/// The parameters of [`Chromeosdevices::get`], set with a builder.
+pub struct ChromeosdevicesGetRequest<'a> {
+    client: &'a Chromeosdevices,
+    customer_id: &'a str,
+    device_id: &'a str,
+    projection: Option<crate::types::Projection>,
+}
+
+impl<'a> ChromeosdevicesGetRequest<'a> {
+    /// Sets the `projection` query parameter.
+    pub fn projection(mut self, projection: crate::types::Projection) -> Self {
+        self.projection = Some(projection);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::ChromeOsDevice>> {
+        let Self {
+            client,
+            customer_id,
+            device_id,
+            projection,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = projection {
+            query_args.push(("projection".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/directory/v1/customer/{}/devices/chromeos/{}?{}",
+                crate::progenitor_support::encode_path(customer_id),
+                crate::progenitor_support::encode_path(device_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Chromeosdevices::update`], set with a builder.
+pub struct ChromeosdevicesUpdateRequest<'a> {
+    client: &'a Chromeosdevices,
+    customer_id: &'a str,
+    device_id: &'a str,
+    projection: Option<crate::types::Projection>,
+    body: &'a crate::types::ChromeOsDevice,
+}
+
+impl<'a> ChromeosdevicesUpdateRequest<'a> {
+    /// Sets the `projection` query parameter.
+    pub fn projection(mut self, projection: crate::types::Projection) -> Self {
+        self.projection = Some(projection);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::ChromeOsDevice>> {
+        let Self {
+            client,
+            customer_id,
+            device_id,
+            projection,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = projection {
+            query_args.push(("projection".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/directory/v1/customer/{}/devices/chromeos/{}?{}",
+                crate::progenitor_support::encode_path(customer_id),
+                crate::progenitor_support::encode_path(device_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .put(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Chromeosdevices::patch`], set with a builder.
+pub struct ChromeosdevicesPatchRequest<'a> {
+    client: &'a Chromeosdevices,
+    customer_id: &'a str,
+    device_id: &'a str,
+    projection: Option<crate::types::Projection>,
+    body: &'a crate::types::ChromeOsDevice,
+}
+
+impl<'a> ChromeosdevicesPatchRequest<'a> {
+    /// Sets the `projection` query parameter.
+    pub fn projection(mut self, projection: crate::types::Projection) -> Self {
+        self.projection = Some(projection);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::ChromeOsDevice>> {
+        let Self {
+            client,
+            customer_id,
+            device_id,
+            projection,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = projection {
+            query_args.push(("projection".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/directory/v1/customer/{}/devices/chromeos/{}?{}",
+                crate::progenitor_support::encode_path(customer_id),
+                crate::progenitor_support::encode_path(device_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .patch(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
 pub struct Chromeosdevices {
     pub client: Client,
 }
@@ -87,6 +365,26 @@ impl Chromeosdevices {
             resp.body.chromeosdevices.to_vec(),
         ))
     }
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/customer/{customerId}/devices/chromeos` endpoint.
+     *
+     * As opposed to `list`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Retrieves a paginated list of Chrome OS devices within an account.
+     */
+    pub fn list_request<'a>(&'a self, customer_id: &'a str) -> ChromeosdevicesListRequest<'a> {
+        ChromeosdevicesListRequest {
+            client: self,
+            customer_id,
+            max_results: None,
+            order_by: None,
+            org_unit_path: None,
+            page_token: None,
+            projection: None,
+            query: None,
+            sort_order: None,
+        }
+    }
     /**
      * This function performs a `GET` to the `/admin/directory/v1/customer/{customerId}/devices/chromeos` endpoint.
      *
@@ -128,11 +426,11 @@ impl Chromeosdevices {
             ),
             None,
         );
-        let crate::Response::<crate::types::ChromeOsDevices> {
-            mut status,
-            mut headers,
-            mut body,
-        } = self
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let mut pages = crate::utils::Pages::new(self.client.page_limits);
+        pages.visit(&url)?;
+
+        let mut response: crate::Response<crate::types::ChromeOsDevices> = self
             .client
             .get(
                 &url,
@@ -142,55 +440,97 @@ impl Chromeosdevices {
                 },
             )
             .await?;
-
-        let mut chromeosdevices = body.chromeosdevices;
-        let mut page = body.next_page_token;
+        let mut chromeosdevices = Vec::new();
 
         // Paginate if we should.
-        while !page.is_empty() {
-            if !url.contains('?') {
-                crate::Response::<crate::types::ChromeOsDevices> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}?pageToken={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
+        loop {
+            let page = if response.body.next_page_token.is_empty() {
+                None
             } else {
-                crate::Response::<crate::types::ChromeOsDevices> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}&pageToken={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
-
-            chromeosdevices.append(&mut body.chromeosdevices);
-
-            if !body.next_page_token.is_empty() && body.next_page_token != page {
-                page = body.next_page_token.to_string();
-            } else {
-                page = "".to_string();
-            }
+                Some(format!(
+                    "{}{}pageToken={}",
+                    url, sep, response.body.next_page_token
+                ))
+            };
+            let more = pages.keep(&mut response.body.chromeosdevices);
+            chromeosdevices.append(&mut response.body.chromeosdevices);
+
+            let next = match page {
+                Some(next) if more && pages.visit(&next)? => next,
+                _ => break,
+            };
+            response = self
+                .client
+                .get(
+                    &next,
+                    crate::Message {
+                        body: None,
+                        content_type: None,
+                    },
+                )
+                .await?;
         }
 
         // Return our response data.
-        Ok(crate::Response::new(status, headers, chromeosdevices))
+        Ok(crate::Response::new(
+            response.status,
+            response.headers,
+            chromeosdevices,
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/customer/{customerId}/devices/chromeos` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Retrieves a paginated list of Chrome OS devices within an account.
+     */
+    pub fn list_all_stream(
+        &self,
+        customer_id: &str,
+        order_by: crate::types::OrderBy,
+        org_unit_path: &str,
+        projection: crate::types::Projection,
+        query: &str,
+        sort_order: crate::types::SortOrder,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::ChromeOsDevice>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !order_by.to_string().is_empty() {
+            query_args.push(("orderBy".to_string(), order_by.to_string()));
+        }
+        if !org_unit_path.is_empty() {
+            query_args.push(("orgUnitPath".to_string(), org_unit_path.to_string()));
+        }
+        if !projection.to_string().is_empty() {
+            query_args.push(("projection".to_string(), projection.to_string()));
+        }
+        if !query.is_empty() {
+            query_args.push(("query".to_string(), query.to_string()));
+        }
+        if !sort_order.to_string().is_empty() {
+            query_args.push(("sortOrder".to_string(), sort_order.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/admin/directory/v1/customer/{}/devices/chromeos?{}",
+                crate::progenitor_support::encode_path(customer_id),
+                query_
+            ),
+            None,
+        );
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let next = format!("{}{}pageToken=", url, sep);
+        self.client
+            .get_pages_stream(url, move |body: crate::types::ChromeOsDevices| {
+                let page = if body.next_page_token.is_empty() {
+                    None
+                } else {
+                    Some(format!("{}{}", next, body.next_page_token))
+                };
+
+                (body.chromeosdevices, page)
+            })
     }
     /**
      * This function performs a `POST` to the `/admin/directory/v1/customer/{customerId}/devices/chromeos/moveDevicesToOu` endpoint.
@@ -272,6 +612,25 @@ impl Chromeosdevices {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/customer/{customerId}/devices/chromeos/{deviceId}` endpoint.
+     *
+     * As opposed to `get`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Retrieves a Chrome OS device's properties.
+     */
+    pub fn get_request<'a>(
+        &'a self,
+        customer_id: &'a str,
+        device_id: &'a str,
+    ) -> ChromeosdevicesGetRequest<'a> {
+        ChromeosdevicesGetRequest {
+            client: self,
+            customer_id,
+            device_id,
+            projection: None,
+        }
+    }
     /**
      * This function performs a `PUT` to the `/admin/directory/v1/customer/{customerId}/devices/chromeos/{deviceId}` endpoint.
      *
@@ -314,6 +673,27 @@ impl Chromeosdevices {
             )
             .await
     }
+    /**
+     * This function performs a `PUT` to the `/admin/directory/v1/customer/{customerId}/devices/chromeos/{deviceId}` endpoint.
+     *
+     * As opposed to `update`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Updates a device's updatable properties, such as `annotatedUser`, `annotatedLocation`, `notes`, `orgUnitPath`, or `annotatedAssetId`.
+     */
+    pub fn update_request<'a>(
+        &'a self,
+        customer_id: &'a str,
+        device_id: &'a str,
+        body: &'a crate::types::ChromeOsDevice,
+    ) -> ChromeosdevicesUpdateRequest<'a> {
+        ChromeosdevicesUpdateRequest {
+            client: self,
+            customer_id,
+            device_id,
+            projection: None,
+            body,
+        }
+    }
     /**
      * This function performs a `PATCH` to the `/admin/directory/v1/customer/{customerId}/devices/chromeos/{deviceId}` endpoint.
      *
@@ -356,6 +736,27 @@ impl Chromeosdevices {
             )
             .await
     }
+    /**
+     * This function performs a `PATCH` to the `/admin/directory/v1/customer/{customerId}/devices/chromeos/{deviceId}` endpoint.
+     *
+     * As opposed to `patch`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Updates a device's updatable properties, such as `annotatedUser`, `annotatedLocation`, `notes`, `orgUnitPath`, or `annotatedAssetId`. This method supports [patch semantics](/admin-sdk/directory/v1/guides/performance#patch).
+     */
+    pub fn patch_request<'a>(
+        &'a self,
+        customer_id: &'a str,
+        device_id: &'a str,
+        body: &'a crate::types::ChromeOsDevice,
+    ) -> ChromeosdevicesPatchRequest<'a> {
+        ChromeosdevicesPatchRequest {
+            client: self,
+            customer_id,
+            device_id,
+            projection: None,
+            body,
+        }
+    }
     /**
      * This function performs a `POST` to the `/admin/directory/v1/customer/{customerId}/devices/chromeos/{resourceId}/action` endpoint.
      *
@@ -1,6 +1,232 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Customers::admin_chrome_printers_patch`], set with a builder.
+pub struct CustomersAdminChromePrintersPatchRequest<'a> {
+    client: &'a Customers,
+    name: &'a str,
+    clear_mask: Option<&'a str>,
+    update_mask: Option<&'a str>,
+    body: &'a crate::types::Printer,
+}
+
+impl<'a> CustomersAdminChromePrintersPatchRequest<'a> {
+    /// Sets the `clearMask` query parameter.
+    pub fn clear_mask(mut self, clear_mask: &'a str) -> Self {
+        self.clear_mask = Some(clear_mask);
+        self
+    }
+
+    /// Sets the `updateMask` query parameter.
+    pub fn update_mask(mut self, update_mask: &'a str) -> Self {
+        self.update_mask = Some(update_mask);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::Printer>> {
+        let Self {
+            client,
+            name,
+            clear_mask,
+            update_mask,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = clear_mask {
+            query_args.push(("clearMask".to_string(), v.to_string()));
+        }
+        if let Some(v) = update_mask {
+            query_args.push(("updateMask".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/directory/v1/{}?{}",
+                crate::progenitor_support::encode_path(name),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .patch(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Customers::admin_chrome_printers_list`], set with a builder.
+pub struct CustomersAdminChromePrintersListRequest<'a> {
+    client: &'a Customers,
+    parent: &'a str,
+    filter: Option<&'a str>,
+    org_unit_id: Option<&'a str>,
+    page_size: Option<i64>,
+    page_token: Option<&'a str>,
+}
+
+impl<'a> CustomersAdminChromePrintersListRequest<'a> {
+    /// Sets the `filter` query parameter.
+    pub fn filter(mut self, filter: &'a str) -> Self {
+        self.filter = Some(filter);
+        self
+    }
+
+    /// Sets the `orgUnitId` query parameter.
+    pub fn org_unit_id(mut self, org_unit_id: &'a str) -> Self {
+        self.org_unit_id = Some(org_unit_id);
+        self
+    }
+
+    /// Sets the `pageSize` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Sets the `pageToken` query parameter.
+    pub fn page_token(mut self, page_token: &'a str) -> Self {
+        self.page_token = Some(page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Printer>>> {
+        let Self {
+            client,
+            parent,
+            filter,
+            org_unit_id,
+            page_size,
+            page_token,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = filter {
+            query_args.push(("filter".to_string(), v.to_string()));
+        }
+        if let Some(v) = org_unit_id {
+            query_args.push(("orgUnitId".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_size {
+            query_args.push(("pageSize".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_token {
+            query_args.push(("pageToken".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/directory/v1/{}/chrome/printers?{}",
+                crate::progenitor_support::encode_path(parent),
+                query_
+            ),
+            None,
+        );
+
+        let resp: crate::Response<crate::types::ListPrintersResponse> = client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await?;
+
+        // Return our response data.
+        Ok(crate::Response::new(
+            resp.status,
+            resp.headers,
+            resp.body.printers.to_vec(),
+        ))
+    }
+}
+
+/// The parameters of [`Customers::admin_chrome_printers_list_printer_models`], set with a builder.
+pub struct CustomersAdminChromePrintersListPrinterModelsRequest<'a> {
+    client: &'a Customers,
+    parent: &'a str,
+    filter: Option<&'a str>,
+    page_size: Option<i64>,
+    page_token: Option<&'a str>,
+}
+
+impl<'a> CustomersAdminChromePrintersListPrinterModelsRequest<'a> {
+    /// Sets the `filter` query parameter.
+    pub fn filter(mut self, filter: &'a str) -> Self {
+        self.filter = Some(filter);
+        self
+    }
+
+    /// Sets the `pageSize` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Sets the `pageToken` query parameter.
+    pub fn page_token(mut self, page_token: &'a str) -> Self {
+        self.page_token = Some(page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::PrinterModel>>> {
+        let Self {
+            client,
+            parent,
+            filter,
+            page_size,
+            page_token,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = filter {
+            query_args.push(("filter".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_size {
+            query_args.push(("pageSize".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_token {
+            query_args.push(("pageToken".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/directory/v1/{}/chrome/printers:listPrinterModels?{}",
+                crate::progenitor_support::encode_path(parent),
+                query_
+            ),
+            None,
+        );
+
+        let resp: crate::Response<crate::types::ListPrinterModelsResponse> = client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await?;
+
+        // Return our response data.
+        Ok(crate::Response::new(
+            resp.status,
+            resp.headers,
+            resp.body.printer_models.to_vec(),
+        ))
+    }
+}
+
 pub struct Customers {
     pub client: Client,
 }
@@ -207,6 +433,26 @@ impl Customers {
             )
             .await
     }
+    /**
+     * This function performs a `PATCH` to the `/admin/directory/v1/{name}` endpoint.
+     *
+     * As opposed to `admin_chrome_printers_patch`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Updates a `Printer` resource.
+     */
+    pub fn admin_chrome_printers_patch_request<'a>(
+        &'a self,
+        name: &'a str,
+        body: &'a crate::types::Printer,
+    ) -> CustomersAdminChromePrintersPatchRequest<'a> {
+        CustomersAdminChromePrintersPatchRequest {
+            client: self,
+            name,
+            clear_mask: None,
+            update_mask: None,
+            body,
+        }
+    }
     /**
      * This function performs a `GET` to the `/admin/directory/v1/{parent}/chrome/printers` endpoint.
      *
@@ -268,6 +514,26 @@ impl Customers {
             resp.body.printers.to_vec(),
         ))
     }
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/{parent}/chrome/printers` endpoint.
+     *
+     * As opposed to `admin_chrome_printers_list`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * List printers configs.
+     */
+    pub fn admin_chrome_printers_list_request<'a>(
+        &'a self,
+        parent: &'a str,
+    ) -> CustomersAdminChromePrintersListRequest<'a> {
+        CustomersAdminChromePrintersListRequest {
+            client: self,
+            parent,
+            filter: None,
+            org_unit_id: None,
+            page_size: None,
+            page_token: None,
+        }
+    }
     /**
      * This function performs a `GET` to the `/admin/directory/v1/{parent}/chrome/printers` endpoint.
      *
@@ -297,11 +563,11 @@ impl Customers {
             ),
             None,
         );
-        let crate::Response::<crate::types::ListPrintersResponse> {
-            mut status,
-            mut headers,
-            mut body,
-        } = self
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let mut pages = crate::utils::Pages::new(self.client.page_limits);
+        pages.visit(&url)?;
+
+        let mut response: crate::Response<crate::types::ListPrintersResponse> = self
             .client
             .get(
                 &url,
@@ -311,55 +577,85 @@ impl Customers {
                 },
             )
             .await?;
-
-        let mut printers = body.printers;
-        let mut page = body.next_page_token;
+        let mut printers = Vec::new();
 
         // Paginate if we should.
-        while !page.is_empty() {
-            if !url.contains('?') {
-                crate::Response::<crate::types::ListPrintersResponse> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}?pageToken={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            } else {
-                crate::Response::<crate::types::ListPrintersResponse> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}&pageToken={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
-
-            printers.append(&mut body.printers);
-
-            if !body.next_page_token.is_empty() && body.next_page_token != page {
-                page = body.next_page_token.to_string();
+        loop {
+            let page = if response.body.next_page_token.is_empty() {
+                None
             } else {
-                page = "".to_string();
-            }
+                Some(format!(
+                    "{}{}pageToken={}",
+                    url, sep, response.body.next_page_token
+                ))
+            };
+            let more = pages.keep(&mut response.body.printers);
+            printers.append(&mut response.body.printers);
+
+            let next = match page {
+                Some(next) if more && pages.visit(&next)? => next,
+                _ => break,
+            };
+            response = self
+                .client
+                .get(
+                    &next,
+                    crate::Message {
+                        body: None,
+                        content_type: None,
+                    },
+                )
+                .await?;
         }
 
         // Return our response data.
-        Ok(crate::Response::new(status, headers, printers))
+        Ok(crate::Response::new(
+            response.status,
+            response.headers,
+            printers,
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/{parent}/chrome/printers` endpoint.
+     *
+     * As opposed to `admin_chrome_printers_list_all`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * List printers configs.
+     */
+    pub fn admin_chrome_printers_list_all_stream(
+        &self,
+        parent: &str,
+        filter: &str,
+        org_unit_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Printer>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !filter.is_empty() {
+            query_args.push(("filter".to_string(), filter.to_string()));
+        }
+        if !org_unit_id.is_empty() {
+            query_args.push(("orgUnitId".to_string(), org_unit_id.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/admin/directory/v1/{}/chrome/printers?{}",
+                crate::progenitor_support::encode_path(parent),
+                query_
+            ),
+            None,
+        );
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let next = format!("{}{}pageToken=", url, sep);
+        self.client
+            .get_pages_stream(url, move |body: crate::types::ListPrintersResponse| {
+                let page = if body.next_page_token.is_empty() {
+                    None
+                } else {
+                    Some(format!("{}{}", next, body.next_page_token))
+                };
+
+                (body.printers, page)
+            })
     }
     /**
      * This function performs a `POST` to the `/admin/directory/v1/{parent}/chrome/printers` endpoint.
@@ -510,6 +806,25 @@ impl Customers {
             resp.body.printer_models.to_vec(),
         ))
     }
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/{parent}/chrome/printers:listPrinterModels` endpoint.
+     *
+     * As opposed to `admin_chrome_printers_list_printer_models`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Lists the supported printer models.
+     */
+    pub fn admin_chrome_printers_list_printer_models_request<'a>(
+        &'a self,
+        parent: &'a str,
+    ) -> CustomersAdminChromePrintersListPrinterModelsRequest<'a> {
+        CustomersAdminChromePrintersListPrinterModelsRequest {
+            client: self,
+            parent,
+            filter: None,
+            page_size: None,
+            page_token: None,
+        }
+    }
     /**
      * This function performs a `GET` to the `/admin/directory/v1/{parent}/chrome/printers:listPrinterModels` endpoint.
      *
@@ -535,11 +850,11 @@ impl Customers {
             ),
             None,
         );
-        let crate::Response::<crate::types::ListPrinterModelsResponse> {
-            mut status,
-            mut headers,
-            mut body,
-        } = self
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let mut pages = crate::utils::Pages::new(self.client.page_limits);
+        pages.visit(&url)?;
+
+        let mut response: crate::Response<crate::types::ListPrinterModelsResponse> = self
             .client
             .get(
                 &url,
@@ -549,54 +864,80 @@ impl Customers {
                 },
             )
             .await?;
-
-        let mut printer_models = body.printer_models;
-        let mut page = body.next_page_token;
+        let mut printer_models = Vec::new();
 
         // Paginate if we should.
-        while !page.is_empty() {
-            if !url.contains('?') {
-                crate::Response::<crate::types::ListPrinterModelsResponse> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}?pageToken={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            } else {
-                crate::Response::<crate::types::ListPrinterModelsResponse> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}&pageToken={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
-
-            printer_models.append(&mut body.printer_models);
-
-            if !body.next_page_token.is_empty() && body.next_page_token != page {
-                page = body.next_page_token.to_string();
+        loop {
+            let page = if response.body.next_page_token.is_empty() {
+                None
             } else {
-                page = "".to_string();
-            }
+                Some(format!(
+                    "{}{}pageToken={}",
+                    url, sep, response.body.next_page_token
+                ))
+            };
+            let more = pages.keep(&mut response.body.printer_models);
+            printer_models.append(&mut response.body.printer_models);
+
+            let next = match page {
+                Some(next) if more && pages.visit(&next)? => next,
+                _ => break,
+            };
+            response = self
+                .client
+                .get(
+                    &next,
+                    crate::Message {
+                        body: None,
+                        content_type: None,
+                    },
+                )
+                .await?;
         }
 
         // Return our response data.
-        Ok(crate::Response::new(status, headers, printer_models))
+        Ok(crate::Response::new(
+            response.status,
+            response.headers,
+            printer_models,
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/{parent}/chrome/printers:listPrinterModels` endpoint.
+     *
+     * As opposed to `admin_chrome_printers_list_all_printer_models`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Lists the supported printer models.
+     */
+    pub fn admin_chrome_printers_list_all_printer_models_stream(
+        &self,
+        parent: &str,
+        filter: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::PrinterModel>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !filter.is_empty() {
+            query_args.push(("filter".to_string(), filter.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/admin/directory/v1/{}/chrome/printers:listPrinterModels?{}",
+                crate::progenitor_support::encode_path(parent),
+                query_
+            ),
+            None,
+        );
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let next = format!("{}{}pageToken=", url, sep);
+        self.client
+            .get_pages_stream(url, move |body: crate::types::ListPrinterModelsResponse| {
+                let page = if body.next_page_token.is_empty() {
+                    None
+                } else {
+                    Some(format!("{}{}", next, body.next_page_token))
+                };
+
+                (body.printer_models, page)
+            })
     }
 }
@@ -1,6 +1,54 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`DomainAliases::list`], set with a builder.
+pub struct DomainAliasesListRequest<'a> {
+    client: &'a DomainAliases,
+    customer: &'a str,
+    parent_domain_name: Option<&'a str>,
+}
+
+impl<'a> DomainAliasesListRequest<'a> {
+    /// Sets the `parentDomainName` query parameter.
+    pub fn parent_domain_name(mut self, parent_domain_name: &'a str) -> Self {
+        self.parent_domain_name = Some(parent_domain_name);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::DomainAliases>> {
+        let Self {
+            client,
+            customer,
+            parent_domain_name,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = parent_domain_name {
+            query_args.push(("parentDomainName".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/directory/v1/customer/{}/domainaliases?{}",
+                crate::progenitor_support::encode_path(customer),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct DomainAliases {
     pub client: Client,
 }
@@ -52,6 +100,20 @@ impl DomainAliases {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/customer/{customer}/domainaliases` endpoint.
+     *
+     * As opposed to `list`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Lists the domain aliases of the customer.
+     */
+    pub fn list_request<'a>(&'a self, customer: &'a str) -> DomainAliasesListRequest<'a> {
+        DomainAliasesListRequest {
+            client: self,
+            customer,
+            parent_domain_name: None,
+        }
+    }
     /**
      * This function performs a `POST` to the `/admin/directory/v1/customer/{customer}/domainaliases` endpoint.
      *
@@ -1,6 +1,131 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Groups::list`], set with a builder.
+pub struct GroupsListRequest<'a> {
+    client: &'a Groups,
+    customer: Option<&'a str>,
+    domain: Option<&'a str>,
+    max_results: Option<i64>,
+    order_by: Option<crate::types::DirectoryGroupsListOrderBy>,
+    page_token: Option<&'a str>,
+    query: Option<&'a str>,
+    sort_order: Option<crate::types::SortOrder>,
+    user_key: Option<&'a str>,
+}
+
+impl<'a> GroupsListRequest<'a> {
+    /// Sets the `customer` query parameter.
+    pub fn customer(mut self, customer: &'a str) -> Self {
+        self.customer = Some(customer);
+        self
+    }
+
+    /// Sets the `domain` query parameter.
+    pub fn domain(mut self, domain: &'a str) -> Self {
+        self.domain = Some(domain);
+        self
+    }
+
+    /// Sets the `maxResults` query parameter.
+    pub fn max_results(mut self, max_results: i64) -> Self {
+        self.max_results = Some(max_results);
+        self
+    }
+
+    /// Sets the `orderBy` query parameter.
+    pub fn order_by(mut self, order_by: crate::types::DirectoryGroupsListOrderBy) -> Self {
+        self.order_by = Some(order_by);
+        self
+    }
+
+    /// Sets the `pageToken` query parameter.
+    pub fn page_token(mut self, page_token: &'a str) -> Self {
+        self.page_token = Some(page_token);
+        self
+    }
+
+    /// Sets the `query` query parameter.
+    pub fn query(mut self, query: &'a str) -> Self {
+        self.query = Some(query);
+        self
+    }
+
+    /// Sets the `sortOrder` query parameter.
+    pub fn sort_order(mut self, sort_order: crate::types::SortOrder) -> Self {
+        self.sort_order = Some(sort_order);
+        self
+    }
+
+    /// Sets the `userKey` query parameter.
+    pub fn user_key(mut self, user_key: &'a str) -> Self {
+        self.user_key = Some(user_key);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Group>>> {
+        let Self {
+            client,
+            customer,
+            domain,
+            max_results,
+            order_by,
+            page_token,
+            query,
+            sort_order,
+            user_key,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = customer {
+            query_args.push(("customer".to_string(), v.to_string()));
+        }
+        if let Some(v) = domain {
+            query_args.push(("domain".to_string(), v.to_string()));
+        }
+        if let Some(v) = max_results {
+            query_args.push(("maxResults".to_string(), v.to_string()));
+        }
+        if let Some(v) = order_by {
+            query_args.push(("orderBy".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_token {
+            query_args.push(("pageToken".to_string(), v.to_string()));
+        }
+        if let Some(v) = query {
+            query_args.push(("query".to_string(), v.to_string()));
+        }
+        if let Some(v) = sort_order {
+            query_args.push(("sortOrder".to_string(), v.to_string()));
+        }
+        if let Some(v) = user_key {
+            query_args.push(("userKey".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/admin/directory/v1/groups?{}", query_), None);
+
+        let resp: crate::Response<crate::types::Groups> = client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await?;
+
+        // Return our response data.
+        Ok(crate::Response::new(
+            resp.status,
+            resp.headers,
+            resp.body.groups.to_vec(),
+        ))
+    }
+}
+
 pub struct Groups {
     pub client: Client,
 }
@@ -85,6 +210,26 @@ impl Groups {
             resp.body.groups.to_vec(),
         ))
     }
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/groups` endpoint.
+     *
+     * As opposed to `list`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Retrieves all groups of a domain or of a user given a userKey (paginated).
+     */
+    pub fn list_request(&self) -> GroupsListRequest<'_> {
+        GroupsListRequest {
+            client: self,
+            customer: None,
+            domain: None,
+            max_results: None,
+            order_by: None,
+            page_token: None,
+            query: None,
+            sort_order: None,
+            user_key: None,
+        }
+    }
     /**
      * This function performs a `GET` to the `/admin/directory/v1/groups` endpoint.
      *
@@ -124,11 +269,11 @@ impl Groups {
         let url = self
             .client
             .url(&format!("/admin/directory/v1/groups?{}", query_), None);
-        let crate::Response::<crate::types::Groups> {
-            mut status,
-            mut headers,
-            mut body,
-        } = self
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let mut pages = crate::utils::Pages::new(self.client.page_limits);
+        pages.visit(&url)?;
+
+        let mut response: crate::Response<crate::types::Groups> = self
             .client
             .get(
                 &url,
@@ -138,55 +283,95 @@ impl Groups {
                 },
             )
             .await?;
-
-        let mut groups = body.groups;
-        let mut page = body.next_page_token;
+        let mut groups = Vec::new();
 
         // Paginate if we should.
-        while !page.is_empty() {
-            if !url.contains('?') {
-                crate::Response::<crate::types::Groups> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}?pageToken={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
+        loop {
+            let page = if response.body.next_page_token.is_empty() {
+                None
             } else {
-                crate::Response::<crate::types::Groups> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}&pageToken={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
-
-            groups.append(&mut body.groups);
+                Some(format!(
+                    "{}{}pageToken={}",
+                    url, sep, response.body.next_page_token
+                ))
+            };
+            let more = pages.keep(&mut response.body.groups);
+            groups.append(&mut response.body.groups);
 
-            if !body.next_page_token.is_empty() && body.next_page_token != page {
-                page = body.next_page_token.to_string();
-            } else {
-                page = "".to_string();
-            }
+            let next = match page {
+                Some(next) if more && pages.visit(&next)? => next,
+                _ => break,
+            };
+            response = self
+                .client
+                .get(
+                    &next,
+                    crate::Message {
+                        body: None,
+                        content_type: None,
+                    },
+                )
+                .await?;
         }
 
         // Return our response data.
-        Ok(crate::Response::new(status, headers, groups))
+        Ok(crate::Response::new(
+            response.status,
+            response.headers,
+            groups,
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/groups` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Retrieves all groups of a domain or of a user given a userKey (paginated).
+     */
+    pub fn list_all_stream(
+        &self,
+        customer: &str,
+        domain: &str,
+        order_by: crate::types::DirectoryGroupsListOrderBy,
+        query: &str,
+        sort_order: crate::types::SortOrder,
+        user_key: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Group>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !customer.is_empty() {
+            query_args.push(("customer".to_string(), customer.to_string()));
+        }
+        if !domain.is_empty() {
+            query_args.push(("domain".to_string(), domain.to_string()));
+        }
+        if !order_by.to_string().is_empty() {
+            query_args.push(("orderBy".to_string(), order_by.to_string()));
+        }
+        if !query.is_empty() {
+            query_args.push(("query".to_string(), query.to_string()));
+        }
+        if !sort_order.to_string().is_empty() {
+            query_args.push(("sortOrder".to_string(), sort_order.to_string()));
+        }
+        if !user_key.is_empty() {
+            query_args.push(("userKey".to_string(), user_key.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/admin/directory/v1/groups?{}", query_), None);
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let next = format!("{}{}pageToken=", url, sep);
+        self.client
+            .get_pages_stream(url, move |body: crate::types::Groups| {
+                let page = if body.next_page_token.is_empty() {
+                    None
+                } else {
+                    Some(format!("{}{}", next, body.next_page_token))
+                };
+
+                (body.groups, page)
+            })
     }
     /**
      * This function performs a `POST` to the `/admin/directory/v1/groups` endpoint.
@@ -1,6 +1,94 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Members::list`], set with a builder.
+pub struct MembersListRequest<'a> {
+    client: &'a Members,
+    group_key: &'a str,
+    include_derived_membership: Option<bool>,
+    max_results: Option<i64>,
+    page_token: Option<&'a str>,
+    roles: Option<&'a str>,
+}
+
+impl<'a> MembersListRequest<'a> {
+    /// Sets the `includeDerivedMembership` query parameter.
+    pub fn include_derived_membership(mut self, include_derived_membership: bool) -> Self {
+        self.include_derived_membership = Some(include_derived_membership);
+        self
+    }
+
+    /// Sets the `maxResults` query parameter.
+    pub fn max_results(mut self, max_results: i64) -> Self {
+        self.max_results = Some(max_results);
+        self
+    }
+
+    /// Sets the `pageToken` query parameter.
+    pub fn page_token(mut self, page_token: &'a str) -> Self {
+        self.page_token = Some(page_token);
+        self
+    }
+
+    /// Sets the `roles` query parameter.
+    pub fn roles(mut self, roles: &'a str) -> Self {
+        self.roles = Some(roles);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Member>>> {
+        let Self {
+            client,
+            group_key,
+            include_derived_membership,
+            max_results,
+            page_token,
+            roles,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = include_derived_membership {
+            query_args.push(("includeDerivedMembership".to_string(), v.to_string()));
+        }
+        if let Some(v) = max_results {
+            query_args.push(("maxResults".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_token {
+            query_args.push(("pageToken".to_string(), v.to_string()));
+        }
+        if let Some(v) = roles {
+            query_args.push(("roles".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/directory/v1/groups/{}/members?{}",
+                crate::progenitor_support::encode_path(group_key),
+                query_
+            ),
+            None,
+        );
+
+        let resp: crate::Response<crate::types::Members> = client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await?;
+
+        // Return our response data.
+        Ok(crate::Response::new(
+            resp.status,
+            resp.headers,
+            resp.body.members.to_vec(),
+        ))
+    }
+}
+
 pub struct Members {
     pub client: Client,
 }
@@ -108,6 +196,23 @@ impl Members {
             resp.body.members.to_vec(),
         ))
     }
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/groups/{groupKey}/members` endpoint.
+     *
+     * As opposed to `list`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Retrieves a paginated list of all members in a group.
+     */
+    pub fn list_request<'a>(&'a self, group_key: &'a str) -> MembersListRequest<'a> {
+        MembersListRequest {
+            client: self,
+            group_key,
+            include_derived_membership: None,
+            max_results: None,
+            page_token: None,
+            roles: None,
+        }
+    }
     /**
      * This function performs a `GET` to the `/admin/directory/v1/groups/{groupKey}/members` endpoint.
      *
@@ -140,11 +245,11 @@ impl Members {
             ),
             None,
         );
-        let crate::Response::<crate::types::Members> {
-            mut status,
-            mut headers,
-            mut body,
-        } = self
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let mut pages = crate::utils::Pages::new(self.client.page_limits);
+        pages.visit(&url)?;
+
+        let mut response: crate::Response<crate::types::Members> = self
             .client
             .get(
                 &url,
@@ -154,55 +259,88 @@ impl Members {
                 },
             )
             .await?;
-
-        let mut members = body.members;
-        let mut page = body.next_page_token;
+        let mut members = Vec::new();
 
         // Paginate if we should.
-        while !page.is_empty() {
-            if !url.contains('?') {
-                crate::Response::<crate::types::Members> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}?pageToken={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
+        loop {
+            let page = if response.body.next_page_token.is_empty() {
+                None
             } else {
-                crate::Response::<crate::types::Members> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}&pageToken={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
-
-            members.append(&mut body.members);
+                Some(format!(
+                    "{}{}pageToken={}",
+                    url, sep, response.body.next_page_token
+                ))
+            };
+            let more = pages.keep(&mut response.body.members);
+            members.append(&mut response.body.members);
 
-            if !body.next_page_token.is_empty() && body.next_page_token != page {
-                page = body.next_page_token.to_string();
-            } else {
-                page = "".to_string();
-            }
+            let next = match page {
+                Some(next) if more && pages.visit(&next)? => next,
+                _ => break,
+            };
+            response = self
+                .client
+                .get(
+                    &next,
+                    crate::Message {
+                        body: None,
+                        content_type: None,
+                    },
+                )
+                .await?;
         }
 
         // Return our response data.
-        Ok(crate::Response::new(status, headers, members))
+        Ok(crate::Response::new(
+            response.status,
+            response.headers,
+            members,
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/groups/{groupKey}/members` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Retrieves a paginated list of all members in a group.
+     */
+    pub fn list_all_stream(
+        &self,
+        group_key: &str,
+        include_derived_membership: bool,
+        roles: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Member>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if include_derived_membership {
+            query_args.push((
+                "includeDerivedMembership".to_string(),
+                include_derived_membership.to_string(),
+            ));
+        }
+        if !roles.is_empty() {
+            query_args.push(("roles".to_string(), roles.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/admin/directory/v1/groups/{}/members?{}",
+                crate::progenitor_support::encode_path(group_key),
+                query_
+            ),
+            None,
+        );
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let next = format!("{}{}pageToken=", url, sep);
+        self.client
+            .get_pages_stream(url, move |body: crate::types::Members| {
+                let page = if body.next_page_token.is_empty() {
+                    None
+                } else {
+                    Some(format!("{}{}", next, body.next_page_token))
+                };
+
+                (body.members, page)
+            })
     }
     /**
      * This function performs a `POST` to the `/admin/directory/v1/groups/{groupKey}/members` endpoint.
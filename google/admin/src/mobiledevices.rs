@@ -1,6 +1,167 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Mobiledevices::list`], set with a builder.
+pub struct MobiledevicesListRequest<'a> {
+    client: &'a Mobiledevices,
+    customer_id: &'a str,
+    max_results: Option<i64>,
+    order_by: Option<crate::types::DirectoryMobiledevicesListOrderBy>,
+    page_token: Option<&'a str>,
+    projection: Option<crate::types::Projection>,
+    query: Option<&'a str>,
+    sort_order: Option<crate::types::SortOrder>,
+}
+
+impl<'a> MobiledevicesListRequest<'a> {
+    /// Sets the `maxResults` query parameter.
+    pub fn max_results(mut self, max_results: i64) -> Self {
+        self.max_results = Some(max_results);
+        self
+    }
+
+    /// Sets the `orderBy` query parameter.
+    pub fn order_by(mut self, order_by: crate::types::DirectoryMobiledevicesListOrderBy) -> Self {
+        self.order_by = Some(order_by);
+        self
+    }
+
+    /// Sets the `pageToken` query parameter.
+    pub fn page_token(mut self, page_token: &'a str) -> Self {
+        self.page_token = Some(page_token);
+        self
+    }
+
+    /// Sets the `projection` query parameter.
+    pub fn projection(mut self, projection: crate::types::Projection) -> Self {
+        self.projection = Some(projection);
+        self
+    }
+
+    /// Sets the `query` query parameter.
+    pub fn query(mut self, query: &'a str) -> Self {
+        self.query = Some(query);
+        self
+    }
+
+    /// Sets the `sortOrder` query parameter.
+    pub fn sort_order(mut self, sort_order: crate::types::SortOrder) -> Self {
+        self.sort_order = Some(sort_order);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::MobileDevice>>> {
+        let Self {
+            client,
+            customer_id,
+            max_results,
+            order_by,
+            page_token,
+            projection,
+            query,
+            sort_order,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = max_results {
+            query_args.push(("maxResults".to_string(), v.to_string()));
+        }
+        if let Some(v) = order_by {
+            query_args.push(("orderBy".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_token {
+            query_args.push(("pageToken".to_string(), v.to_string()));
+        }
+        if let Some(v) = projection {
+            query_args.push(("projection".to_string(), v.to_string()));
+        }
+        if let Some(v) = query {
+            query_args.push(("query".to_string(), v.to_string()));
+        }
+        if let Some(v) = sort_order {
+            query_args.push(("sortOrder".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/directory/v1/customer/{}/devices/mobile?{}",
+                crate::progenitor_support::encode_path(customer_id),
+                query_
+            ),
+            None,
+        );
+
+        let resp: crate::Response<crate::types::MobileDevices> = client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await?;
+
+        // Return our response data.
+        Ok(crate::Response::new(
+            resp.status,
+            resp.headers,
+            resp.body.mobiledevices.to_vec(),
+        ))
+    }
+}
+
+/// The parameters of [`Mobiledevices::get`], set with a builder.
+pub struct MobiledevicesGetRequest<'a> {
+    client: &'a Mobiledevices,
+    customer_id: &'a str,
+    resource_id: &'a str,
+    projection: Option<crate::types::Projection>,
+}
+
+impl<'a> MobiledevicesGetRequest<'a> {
+    /// Sets the `projection` query parameter.
+    pub fn projection(mut self, projection: crate::types::Projection) -> Self {
+        self.projection = Some(projection);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::MobileDevice>> {
+        let Self {
+            client,
+            customer_id,
+            resource_id,
+            projection,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = projection {
+            query_args.push(("projection".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/directory/v1/customer/{}/devices/mobile/{}?{}",
+                crate::progenitor_support::encode_path(customer_id),
+                crate::progenitor_support::encode_path(resource_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct Mobiledevices {
     pub client: Client,
 }
@@ -82,6 +243,25 @@ impl Mobiledevices {
             resp.body.mobiledevices.to_vec(),
         ))
     }
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/customer/{customerId}/devices/mobile` endpoint.
+     *
+     * As opposed to `list`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Retrieves a paginated list of all mobile devices for an account.
+     */
+    pub fn list_request<'a>(&'a self, customer_id: &'a str) -> MobiledevicesListRequest<'a> {
+        MobiledevicesListRequest {
+            client: self,
+            customer_id,
+            max_results: None,
+            order_by: None,
+            page_token: None,
+            projection: None,
+            query: None,
+            sort_order: None,
+        }
+    }
     /**
      * This function performs a `GET` to the `/admin/directory/v1/customer/{customerId}/devices/mobile` endpoint.
      *
@@ -119,11 +299,11 @@ impl Mobiledevices {
             ),
             None,
         );
-        let crate::Response::<crate::types::MobileDevices> {
-            mut status,
-            mut headers,
-            mut body,
-        } = self
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let mut pages = crate::utils::Pages::new(self.client.page_limits);
+        pages.visit(&url)?;
+
+        let mut response: crate::Response<crate::types::MobileDevices> = self
             .client
             .get(
                 &url,
@@ -133,55 +313,93 @@ impl Mobiledevices {
                 },
             )
             .await?;
-
-        let mut mobiledevices = body.mobiledevices;
-        let mut page = body.next_page_token;
+        let mut mobiledevices = Vec::new();
 
         // Paginate if we should.
-        while !page.is_empty() {
-            if !url.contains('?') {
-                crate::Response::<crate::types::MobileDevices> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}?pageToken={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
+        loop {
+            let page = if response.body.next_page_token.is_empty() {
+                None
             } else {
-                crate::Response::<crate::types::MobileDevices> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}&pageToken={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
-
-            mobiledevices.append(&mut body.mobiledevices);
+                Some(format!(
+                    "{}{}pageToken={}",
+                    url, sep, response.body.next_page_token
+                ))
+            };
+            let more = pages.keep(&mut response.body.mobiledevices);
+            mobiledevices.append(&mut response.body.mobiledevices);
 
-            if !body.next_page_token.is_empty() && body.next_page_token != page {
-                page = body.next_page_token.to_string();
-            } else {
-                page = "".to_string();
-            }
+            let next = match page {
+                Some(next) if more && pages.visit(&next)? => next,
+                _ => break,
+            };
+            response = self
+                .client
+                .get(
+                    &next,
+                    crate::Message {
+                        body: None,
+                        content_type: None,
+                    },
+                )
+                .await?;
         }
 
         // Return our response data.
-        Ok(crate::Response::new(status, headers, mobiledevices))
+        Ok(crate::Response::new(
+            response.status,
+            response.headers,
+            mobiledevices,
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/customer/{customerId}/devices/mobile` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Retrieves a paginated list of all mobile devices for an account.
+     */
+    pub fn list_all_stream(
+        &self,
+        customer_id: &str,
+        order_by: crate::types::DirectoryMobiledevicesListOrderBy,
+        projection: crate::types::Projection,
+        query: &str,
+        sort_order: crate::types::SortOrder,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::MobileDevice>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !order_by.to_string().is_empty() {
+            query_args.push(("orderBy".to_string(), order_by.to_string()));
+        }
+        if !projection.to_string().is_empty() {
+            query_args.push(("projection".to_string(), projection.to_string()));
+        }
+        if !query.is_empty() {
+            query_args.push(("query".to_string(), query.to_string()));
+        }
+        if !sort_order.to_string().is_empty() {
+            query_args.push(("sortOrder".to_string(), sort_order.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/admin/directory/v1/customer/{}/devices/mobile?{}",
+                crate::progenitor_support::encode_path(customer_id),
+                query_
+            ),
+            None,
+        );
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let next = format!("{}{}pageToken=", url, sep);
+        self.client
+            .get_pages_stream(url, move |body: crate::types::MobileDevices| {
+                let page = if body.next_page_token.is_empty() {
+                    None
+                } else {
+                    Some(format!("{}{}", next, body.next_page_token))
+                };
+
+                (body.mobiledevices, page)
+            })
     }
     /**
      * This function performs a `GET` to the `/admin/directory/v1/customer/{customerId}/devices/mobile/{resourceId}` endpoint.
@@ -224,6 +442,25 @@ impl Mobiledevices {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/customer/{customerId}/devices/mobile/{resourceId}` endpoint.
+     *
+     * As opposed to `get`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Retrieves a mobile device's properties.
+     */
+    pub fn get_request<'a>(
+        &'a self,
+        customer_id: &'a str,
+        resource_id: &'a str,
+    ) -> MobiledevicesGetRequest<'a> {
+        MobiledevicesGetRequest {
+            client: self,
+            customer_id,
+            resource_id,
+            projection: None,
+        }
+    }
     /**
      * This function performs a `DELETE` to the `/admin/directory/v1/customer/{customerId}/devices/mobile/{resourceId}` endpoint.
      *
@@ -1,6 +1,65 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Orgunits::list`], set with a builder.
+pub struct OrgunitsListRequest<'a> {
+    client: &'a Orgunits,
+    customer_id: &'a str,
+    org_unit_path: Option<&'a str>,
+    type_: Option<crate::types::DirectoryOrgunitsListType>,
+}
+
+impl<'a> OrgunitsListRequest<'a> {
+    /// Sets the `orgUnitPath` query parameter.
+    pub fn org_unit_path(mut self, org_unit_path: &'a str) -> Self {
+        self.org_unit_path = Some(org_unit_path);
+        self
+    }
+
+    /// Sets the `type` query parameter.
+    pub fn type_(mut self, type_: crate::types::DirectoryOrgunitsListType) -> Self {
+        self.type_ = Some(type_);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::OrgUnits>> {
+        let Self {
+            client,
+            customer_id,
+            org_unit_path,
+            type_,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = org_unit_path {
+            query_args.push(("orgUnitPath".to_string(), v.to_string()));
+        }
+        if let Some(v) = type_ {
+            query_args.push(("type".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/directory/v1/customer/{}/orgunits?{}",
+                crate::progenitor_support::encode_path(customer_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct Orgunits {
     pub client: Client,
 }
@@ -54,6 +113,21 @@ impl Orgunits {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/customer/{customerId}/orgunits` endpoint.
+     *
+     * As opposed to `list`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Retrieves a list of all organizational units for an account.
+     */
+    pub fn list_request<'a>(&'a self, customer_id: &'a str) -> OrgunitsListRequest<'a> {
+        OrgunitsListRequest {
+            client: self,
+            customer_id,
+            org_unit_path: None,
+            type_: None,
+        }
+    }
     /**
      * This function performs a `POST` to the `/admin/directory/v1/customer/{customerId}/orgunits` endpoint.
      *
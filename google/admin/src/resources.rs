@@ -1,6 +1,391 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Resources::buildings_list`], set with a builder.
+pub struct ResourcesBuildingsListRequest<'a> {
+    client: &'a Resources,
+    customer: &'a str,
+    max_results: Option<i64>,
+    page_token: Option<&'a str>,
+}
+
+impl<'a> ResourcesBuildingsListRequest<'a> {
+    /// Sets the `maxResults` query parameter.
+    pub fn max_results(mut self, max_results: i64) -> Self {
+        self.max_results = Some(max_results);
+        self
+    }
+
+    /// Sets the `pageToken` query parameter.
+    pub fn page_token(mut self, page_token: &'a str) -> Self {
+        self.page_token = Some(page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Building>>> {
+        let Self {
+            client,
+            customer,
+            max_results,
+            page_token,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = max_results {
+            query_args.push(("maxResults".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_token {
+            query_args.push(("pageToken".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/directory/v1/customer/{}/resources/buildings?{}",
+                crate::progenitor_support::encode_path(customer),
+                query_
+            ),
+            None,
+        );
+
+        let resp: crate::Response<crate::types::Buildings> = client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await?;
+
+        // Return our response data.
+        Ok(crate::Response::new(
+            resp.status,
+            resp.headers,
+            resp.body.buildings.to_vec(),
+        ))
+    }
+}
+
+/// The parameters of [`Resources::buildings_insert`], set with a builder.
+pub struct ResourcesBuildingsInsertRequest<'a> {
+    client: &'a Resources,
+    customer: &'a str,
+    coordinates_source: Option<crate::types::CoordinatesSource>,
+    body: &'a crate::types::Building,
+}
+
+impl<'a> ResourcesBuildingsInsertRequest<'a> {
+    /// Sets the `coordinatesSource` query parameter.
+    pub fn coordinates_source(
+        mut self,
+        coordinates_source: crate::types::CoordinatesSource,
+    ) -> Self {
+        self.coordinates_source = Some(coordinates_source);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::Building>> {
+        let Self {
+            client,
+            customer,
+            coordinates_source,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = coordinates_source {
+            query_args.push(("coordinatesSource".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/directory/v1/customer/{}/resources/buildings?{}",
+                crate::progenitor_support::encode_path(customer),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Resources::buildings_update`], set with a builder.
+pub struct ResourcesBuildingsUpdateRequest<'a> {
+    client: &'a Resources,
+    customer: &'a str,
+    building_id: &'a str,
+    coordinates_source: Option<crate::types::CoordinatesSource>,
+    body: &'a crate::types::Building,
+}
+
+impl<'a> ResourcesBuildingsUpdateRequest<'a> {
+    /// Sets the `coordinatesSource` query parameter.
+    pub fn coordinates_source(
+        mut self,
+        coordinates_source: crate::types::CoordinatesSource,
+    ) -> Self {
+        self.coordinates_source = Some(coordinates_source);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::Building>> {
+        let Self {
+            client,
+            customer,
+            building_id,
+            coordinates_source,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = coordinates_source {
+            query_args.push(("coordinatesSource".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/directory/v1/customer/{}/resources/buildings/{}?{}",
+                crate::progenitor_support::encode_path(customer),
+                crate::progenitor_support::encode_path(building_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .put(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Resources::buildings_patch`], set with a builder.
+pub struct ResourcesBuildingsPatchRequest<'a> {
+    client: &'a Resources,
+    customer: &'a str,
+    building_id: &'a str,
+    coordinates_source: Option<crate::types::CoordinatesSource>,
+    body: &'a crate::types::Building,
+}
+
+impl<'a> ResourcesBuildingsPatchRequest<'a> {
+    /// Sets the `coordinatesSource` query parameter.
+    pub fn coordinates_source(
+        mut self,
+        coordinates_source: crate::types::CoordinatesSource,
+    ) -> Self {
+        self.coordinates_source = Some(coordinates_source);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::Building>> {
+        let Self {
+            client,
+            customer,
+            building_id,
+            coordinates_source,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = coordinates_source {
+            query_args.push(("coordinatesSource".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/directory/v1/customer/{}/resources/buildings/{}?{}",
+                crate::progenitor_support::encode_path(customer),
+                crate::progenitor_support::encode_path(building_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .patch(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Resources::calendars_list`], set with a builder.
+pub struct ResourcesCalendarsListRequest<'a> {
+    client: &'a Resources,
+    customer: &'a str,
+    max_results: Option<i64>,
+    order_by: Option<&'a str>,
+    page_token: Option<&'a str>,
+    query: Option<&'a str>,
+}
+
+impl<'a> ResourcesCalendarsListRequest<'a> {
+    /// Sets the `maxResults` query parameter.
+    pub fn max_results(mut self, max_results: i64) -> Self {
+        self.max_results = Some(max_results);
+        self
+    }
+
+    /// Sets the `orderBy` query parameter.
+    pub fn order_by(mut self, order_by: &'a str) -> Self {
+        self.order_by = Some(order_by);
+        self
+    }
+
+    /// Sets the `pageToken` query parameter.
+    pub fn page_token(mut self, page_token: &'a str) -> Self {
+        self.page_token = Some(page_token);
+        self
+    }
+
+    /// Sets the `query` query parameter.
+    pub fn query(mut self, query: &'a str) -> Self {
+        self.query = Some(query);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::CalendarResource>>> {
+        let Self {
+            client,
+            customer,
+            max_results,
+            order_by,
+            page_token,
+            query,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = max_results {
+            query_args.push(("maxResults".to_string(), v.to_string()));
+        }
+        if let Some(v) = order_by {
+            query_args.push(("orderBy".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_token {
+            query_args.push(("pageToken".to_string(), v.to_string()));
+        }
+        if let Some(v) = query {
+            query_args.push(("query".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/directory/v1/customer/{}/resources/calendars?{}",
+                crate::progenitor_support::encode_path(customer),
+                query_
+            ),
+            None,
+        );
+
+        let resp: crate::Response<crate::types::CalendarResources> = client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await?;
+
+        // Return our response data.
+        Ok(crate::Response::new(
+            resp.status,
+            resp.headers,
+            resp.body.items.to_vec(),
+        ))
+    }
+}
+
+/// The parameters of [`Resources::features_list`], set with a builder.
+pub struct ResourcesFeaturesListRequest<'a> {
+    client: &'a Resources,
+    customer: &'a str,
+    max_results: Option<i64>,
+    page_token: Option<&'a str>,
+}
+
+impl<'a> ResourcesFeaturesListRequest<'a> {
+    /// Sets the `maxResults` query parameter.
+    pub fn max_results(mut self, max_results: i64) -> Self {
+        self.max_results = Some(max_results);
+        self
+    }
+
+    /// Sets the `pageToken` query parameter.
+    pub fn page_token(mut self, page_token: &'a str) -> Self {
+        self.page_token = Some(page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Feature>>> {
+        let Self {
+            client,
+            customer,
+            max_results,
+            page_token,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = max_results {
+            query_args.push(("maxResults".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_token {
+            query_args.push(("pageToken".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/directory/v1/customer/{}/resources/features?{}",
+                crate::progenitor_support::encode_path(customer),
+                query_
+            ),
+            None,
+        );
+
+        let resp: crate::Response<crate::types::Features> = client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await?;
+
+        // Return our response data.
+        Ok(crate::Response::new(
+            resp.status,
+            resp.headers,
+            resp.body.features.to_vec(),
+        ))
+    }
+}
+
 pub struct Resources {
     pub client: Client,
 }
@@ -62,6 +447,24 @@ impl Resources {
             resp.body.buildings.to_vec(),
         ))
     }
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/customer/{customer}/resources/buildings` endpoint.
+     *
+     * As opposed to `buildings_list`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Retrieves a list of buildings for an account.
+     */
+    pub fn buildings_list_request<'a>(
+        &'a self,
+        customer: &'a str,
+    ) -> ResourcesBuildingsListRequest<'a> {
+        ResourcesBuildingsListRequest {
+            client: self,
+            customer,
+            max_results: None,
+            page_token: None,
+        }
+    }
     /**
      * This function performs a `GET` to the `/admin/directory/v1/customer/{customer}/resources/buildings` endpoint.
      *
@@ -80,11 +483,11 @@ impl Resources {
             ),
             None,
         );
-        let crate::Response::<crate::types::Buildings> {
-            mut status,
-            mut headers,
-            mut body,
-        } = self
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let mut pages = crate::utils::Pages::new(self.client.page_limits);
+        pages.visit(&url)?;
+
+        let mut response: crate::Response<crate::types::Buildings> = self
             .client
             .get(
                 &url,
@@ -94,55 +497,74 @@ impl Resources {
                 },
             )
             .await?;
-
-        let mut buildings = body.buildings;
-        let mut page = body.next_page_token;
+        let mut buildings = Vec::new();
 
         // Paginate if we should.
-        while !page.is_empty() {
-            if !url.contains('?') {
-                crate::Response::<crate::types::Buildings> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}?pageToken={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            } else {
-                crate::Response::<crate::types::Buildings> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}&pageToken={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
-
-            buildings.append(&mut body.buildings);
-
-            if !body.next_page_token.is_empty() && body.next_page_token != page {
-                page = body.next_page_token.to_string();
+        loop {
+            let page = if response.body.next_page_token.is_empty() {
+                None
             } else {
-                page = "".to_string();
-            }
+                Some(format!(
+                    "{}{}pageToken={}",
+                    url, sep, response.body.next_page_token
+                ))
+            };
+            let more = pages.keep(&mut response.body.buildings);
+            buildings.append(&mut response.body.buildings);
+
+            let next = match page {
+                Some(next) if more && pages.visit(&next)? => next,
+                _ => break,
+            };
+            response = self
+                .client
+                .get(
+                    &next,
+                    crate::Message {
+                        body: None,
+                        content_type: None,
+                    },
+                )
+                .await?;
         }
 
         // Return our response data.
-        Ok(crate::Response::new(status, headers, buildings))
+        Ok(crate::Response::new(
+            response.status,
+            response.headers,
+            buildings,
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/customer/{customer}/resources/buildings` endpoint.
+     *
+     * As opposed to `buildings_list_all`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Retrieves a list of buildings for an account.
+     */
+    pub fn buildings_list_all_stream(
+        &self,
+        customer: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Building>> + Unpin + '_ {
+        let url = self.client.url(
+            &format!(
+                "/admin/directory/v1/customer/{}/resources/buildings",
+                crate::progenitor_support::encode_path(customer),
+            ),
+            None,
+        );
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let next = format!("{}{}pageToken=", url, sep);
+        self.client
+            .get_pages_stream(url, move |body: crate::types::Buildings| {
+                let page = if body.next_page_token.is_empty() {
+                    None
+                } else {
+                    Some(format!("{}{}", next, body.next_page_token))
+                };
+
+                (body.buildings, page)
+            })
     }
     /**
      * This function performs a `POST` to the `/admin/directory/v1/customer/{customer}/resources/buildings` endpoint.
@@ -186,6 +608,25 @@ impl Resources {
             )
             .await
     }
+    /**
+     * This function performs a `POST` to the `/admin/directory/v1/customer/{customer}/resources/buildings` endpoint.
+     *
+     * As opposed to `buildings_insert`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Inserts a building.
+     */
+    pub fn buildings_insert_request<'a>(
+        &'a self,
+        customer: &'a str,
+        body: &'a crate::types::Building,
+    ) -> ResourcesBuildingsInsertRequest<'a> {
+        ResourcesBuildingsInsertRequest {
+            client: self,
+            customer,
+            coordinates_source: None,
+            body,
+        }
+    }
     /**
      * This function performs a `GET` to the `/admin/directory/v1/customer/{customer}/resources/buildings/{buildingId}` endpoint.
      *
@@ -264,6 +705,27 @@ impl Resources {
             )
             .await
     }
+    /**
+     * This function performs a `PUT` to the `/admin/directory/v1/customer/{customer}/resources/buildings/{buildingId}` endpoint.
+     *
+     * As opposed to `buildings_update`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Updates a building.
+     */
+    pub fn buildings_update_request<'a>(
+        &'a self,
+        customer: &'a str,
+        building_id: &'a str,
+        body: &'a crate::types::Building,
+    ) -> ResourcesBuildingsUpdateRequest<'a> {
+        ResourcesBuildingsUpdateRequest {
+            client: self,
+            customer,
+            building_id,
+            coordinates_source: None,
+            body,
+        }
+    }
     /**
      * This function performs a `DELETE` to the `/admin/directory/v1/customer/{customer}/resources/buildings/{buildingId}` endpoint.
      *
@@ -342,6 +804,27 @@ impl Resources {
             )
             .await
     }
+    /**
+     * This function performs a `PATCH` to the `/admin/directory/v1/customer/{customer}/resources/buildings/{buildingId}` endpoint.
+     *
+     * As opposed to `buildings_patch`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Patches a building.
+     */
+    pub fn buildings_patch_request<'a>(
+        &'a self,
+        customer: &'a str,
+        building_id: &'a str,
+        body: &'a crate::types::Building,
+    ) -> ResourcesBuildingsPatchRequest<'a> {
+        ResourcesBuildingsPatchRequest {
+            client: self,
+            customer,
+            building_id,
+            coordinates_source: None,
+            body,
+        }
+    }
     /**
      * This function performs a `GET` to the `/admin/directory/v1/customer/{customer}/resources/calendars` endpoint.
      *
@@ -403,6 +886,26 @@ impl Resources {
             resp.body.items.to_vec(),
         ))
     }
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/customer/{customer}/resources/calendars` endpoint.
+     *
+     * As opposed to `calendars_list`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Retrieves a list of calendar resources for an account.
+     */
+    pub fn calendars_list_request<'a>(
+        &'a self,
+        customer: &'a str,
+    ) -> ResourcesCalendarsListRequest<'a> {
+        ResourcesCalendarsListRequest {
+            client: self,
+            customer,
+            max_results: None,
+            order_by: None,
+            page_token: None,
+            query: None,
+        }
+    }
     /**
      * This function performs a `GET` to the `/admin/directory/v1/customer/{customer}/resources/calendars` endpoint.
      *
@@ -432,11 +935,11 @@ impl Resources {
             ),
             None,
         );
-        let crate::Response::<crate::types::CalendarResources> {
-            mut status,
-            mut headers,
-            mut body,
-        } = self
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let mut pages = crate::utils::Pages::new(self.client.page_limits);
+        pages.visit(&url)?;
+
+        let mut response: crate::Response<crate::types::CalendarResources> = self
             .client
             .get(
                 &url,
@@ -446,55 +949,86 @@ impl Resources {
                 },
             )
             .await?;
-
-        let mut items = body.items;
-        let mut page = body.next_page_token;
+        let mut items = Vec::new();
 
         // Paginate if we should.
-        while !page.is_empty() {
-            if !url.contains('?') {
-                crate::Response::<crate::types::CalendarResources> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}?pageToken={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            } else {
-                crate::Response::<crate::types::CalendarResources> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}&pageToken={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
-
-            items.append(&mut body.items);
-
-            if !body.next_page_token.is_empty() && body.next_page_token != page {
-                page = body.next_page_token.to_string();
+        loop {
+            let page = if response.body.next_page_token.is_empty() {
+                None
             } else {
-                page = "".to_string();
-            }
+                Some(format!(
+                    "{}{}pageToken={}",
+                    url, sep, response.body.next_page_token
+                ))
+            };
+            let more = pages.keep(&mut response.body.items);
+            items.append(&mut response.body.items);
+
+            let next = match page {
+                Some(next) if more && pages.visit(&next)? => next,
+                _ => break,
+            };
+            response = self
+                .client
+                .get(
+                    &next,
+                    crate::Message {
+                        body: None,
+                        content_type: None,
+                    },
+                )
+                .await?;
         }
 
         // Return our response data.
-        Ok(crate::Response::new(status, headers, items))
+        Ok(crate::Response::new(
+            response.status,
+            response.headers,
+            items,
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/customer/{customer}/resources/calendars` endpoint.
+     *
+     * As opposed to `calendars_list_all`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Retrieves a list of calendar resources for an account.
+     */
+    pub fn calendars_list_all_stream(
+        &self,
+        customer: &str,
+        order_by: &str,
+        query: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::CalendarResource>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !order_by.is_empty() {
+            query_args.push(("orderBy".to_string(), order_by.to_string()));
+        }
+        if !query.is_empty() {
+            query_args.push(("query".to_string(), query.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/admin/directory/v1/customer/{}/resources/calendars?{}",
+                crate::progenitor_support::encode_path(customer),
+                query_
+            ),
+            None,
+        );
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let next = format!("{}{}pageToken=", url, sep);
+        self.client
+            .get_pages_stream(url, move |body: crate::types::CalendarResources| {
+                let page = if body.next_page_token.is_empty() {
+                    None
+                } else {
+                    Some(format!("{}{}", next, body.next_page_token))
+                };
+
+                (body.items, page)
+            })
     }
     /**
      * This function performs a `POST` to the `/admin/directory/v1/customer/{customer}/resources/calendars` endpoint.
@@ -712,6 +1246,24 @@ impl Resources {
             resp.body.features.to_vec(),
         ))
     }
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/customer/{customer}/resources/features` endpoint.
+     *
+     * As opposed to `features_list`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Retrieves a list of features for an account.
+     */
+    pub fn features_list_request<'a>(
+        &'a self,
+        customer: &'a str,
+    ) -> ResourcesFeaturesListRequest<'a> {
+        ResourcesFeaturesListRequest {
+            client: self,
+            customer,
+            max_results: None,
+            page_token: None,
+        }
+    }
     /**
      * This function performs a `GET` to the `/admin/directory/v1/customer/{customer}/resources/features` endpoint.
      *
@@ -730,11 +1282,11 @@ impl Resources {
             ),
             None,
         );
-        let crate::Response::<crate::types::Features> {
-            mut status,
-            mut headers,
-            mut body,
-        } = self
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let mut pages = crate::utils::Pages::new(self.client.page_limits);
+        pages.visit(&url)?;
+
+        let mut response: crate::Response<crate::types::Features> = self
             .client
             .get(
                 &url,
@@ -744,55 +1296,74 @@ impl Resources {
                 },
             )
             .await?;
-
-        let mut features = body.features;
-        let mut page = body.next_page_token;
+        let mut features = Vec::new();
 
         // Paginate if we should.
-        while !page.is_empty() {
-            if !url.contains('?') {
-                crate::Response::<crate::types::Features> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}?pageToken={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
+        loop {
+            let page = if response.body.next_page_token.is_empty() {
+                None
             } else {
-                crate::Response::<crate::types::Features> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}&pageToken={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
-
-            features.append(&mut body.features);
-
-            if !body.next_page_token.is_empty() && body.next_page_token != page {
-                page = body.next_page_token.to_string();
-            } else {
-                page = "".to_string();
-            }
+                Some(format!(
+                    "{}{}pageToken={}",
+                    url, sep, response.body.next_page_token
+                ))
+            };
+            let more = pages.keep(&mut response.body.features);
+            features.append(&mut response.body.features);
+
+            let next = match page {
+                Some(next) if more && pages.visit(&next)? => next,
+                _ => break,
+            };
+            response = self
+                .client
+                .get(
+                    &next,
+                    crate::Message {
+                        body: None,
+                        content_type: None,
+                    },
+                )
+                .await?;
         }
 
         // Return our response data.
-        Ok(crate::Response::new(status, headers, features))
+        Ok(crate::Response::new(
+            response.status,
+            response.headers,
+            features,
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/customer/{customer}/resources/features` endpoint.
+     *
+     * As opposed to `features_list_all`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Retrieves a list of features for an account.
+     */
+    pub fn features_list_all_stream(
+        &self,
+        customer: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Feature>> + Unpin + '_ {
+        let url = self.client.url(
+            &format!(
+                "/admin/directory/v1/customer/{}/resources/features",
+                crate::progenitor_support::encode_path(customer),
+            ),
+            None,
+        );
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let next = format!("{}{}pageToken=", url, sep);
+        self.client
+            .get_pages_stream(url, move |body: crate::types::Features| {
+                let page = if body.next_page_token.is_empty() {
+                    None
+                } else {
+                    Some(format!("{}{}", next, body.next_page_token))
+                };
+
+                (body.features, page)
+            })
     }
     /**
      * This function performs a `POST` to the `/admin/directory/v1/customer/{customer}/resources/features` endpoint.
@@ -1,6 +1,94 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`RoleAssignments::list`], set with a builder.
+pub struct RoleAssignmentsListRequest<'a> {
+    client: &'a RoleAssignments,
+    customer: &'a str,
+    max_results: Option<i64>,
+    page_token: Option<&'a str>,
+    role_id: Option<&'a str>,
+    user_key: Option<&'a str>,
+}
+
+impl<'a> RoleAssignmentsListRequest<'a> {
+    /// Sets the `maxResults` query parameter.
+    pub fn max_results(mut self, max_results: i64) -> Self {
+        self.max_results = Some(max_results);
+        self
+    }
+
+    /// Sets the `pageToken` query parameter.
+    pub fn page_token(mut self, page_token: &'a str) -> Self {
+        self.page_token = Some(page_token);
+        self
+    }
+
+    /// Sets the `roleId` query parameter.
+    pub fn role_id(mut self, role_id: &'a str) -> Self {
+        self.role_id = Some(role_id);
+        self
+    }
+
+    /// Sets the `userKey` query parameter.
+    pub fn user_key(mut self, user_key: &'a str) -> Self {
+        self.user_key = Some(user_key);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::RoleAssignment>>> {
+        let Self {
+            client,
+            customer,
+            max_results,
+            page_token,
+            role_id,
+            user_key,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = max_results {
+            query_args.push(("maxResults".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_token {
+            query_args.push(("pageToken".to_string(), v.to_string()));
+        }
+        if let Some(v) = role_id {
+            query_args.push(("roleId".to_string(), v.to_string()));
+        }
+        if let Some(v) = user_key {
+            query_args.push(("userKey".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/directory/v1/customer/{}/roleassignments?{}",
+                crate::progenitor_support::encode_path(customer),
+                query_
+            ),
+            None,
+        );
+
+        let resp: crate::Response<crate::types::RoleAssignments> = client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await?;
+
+        // Return our response data.
+        Ok(crate::Response::new(
+            resp.status,
+            resp.headers,
+            resp.body.items.to_vec(),
+        ))
+    }
+}
+
 pub struct RoleAssignments {
     pub client: Client,
 }
@@ -72,6 +160,23 @@ impl RoleAssignments {
             resp.body.items.to_vec(),
         ))
     }
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/customer/{customer}/roleassignments` endpoint.
+     *
+     * As opposed to `list`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Retrieves a paginated list of all roleAssignments.
+     */
+    pub fn list_request<'a>(&'a self, customer: &'a str) -> RoleAssignmentsListRequest<'a> {
+        RoleAssignmentsListRequest {
+            client: self,
+            customer,
+            max_results: None,
+            page_token: None,
+            role_id: None,
+            user_key: None,
+        }
+    }
     /**
      * This function performs a `GET` to the `/admin/directory/v1/customer/{customer}/roleassignments` endpoint.
      *
@@ -101,11 +206,11 @@ impl RoleAssignments {
             ),
             None,
         );
-        let crate::Response::<crate::types::RoleAssignments> {
-            mut status,
-            mut headers,
-            mut body,
-        } = self
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let mut pages = crate::utils::Pages::new(self.client.page_limits);
+        pages.visit(&url)?;
+
+        let mut response: crate::Response<crate::types::RoleAssignments> = self
             .client
             .get(
                 &url,
@@ -115,55 +220,85 @@ impl RoleAssignments {
                 },
             )
             .await?;
-
-        let mut items = body.items;
-        let mut page = body.next_page_token;
+        let mut items = Vec::new();
 
         // Paginate if we should.
-        while !page.is_empty() {
-            if !url.contains('?') {
-                crate::Response::<crate::types::RoleAssignments> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}?pageToken={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
+        loop {
+            let page = if response.body.next_page_token.is_empty() {
+                None
             } else {
-                crate::Response::<crate::types::RoleAssignments> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}&pageToken={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
-
-            items.append(&mut body.items);
+                Some(format!(
+                    "{}{}pageToken={}",
+                    url, sep, response.body.next_page_token
+                ))
+            };
+            let more = pages.keep(&mut response.body.items);
+            items.append(&mut response.body.items);
 
-            if !body.next_page_token.is_empty() && body.next_page_token != page {
-                page = body.next_page_token.to_string();
-            } else {
-                page = "".to_string();
-            }
+            let next = match page {
+                Some(next) if more && pages.visit(&next)? => next,
+                _ => break,
+            };
+            response = self
+                .client
+                .get(
+                    &next,
+                    crate::Message {
+                        body: None,
+                        content_type: None,
+                    },
+                )
+                .await?;
         }
 
         // Return our response data.
-        Ok(crate::Response::new(status, headers, items))
+        Ok(crate::Response::new(
+            response.status,
+            response.headers,
+            items,
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/customer/{customer}/roleassignments` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Retrieves a paginated list of all roleAssignments.
+     */
+    pub fn list_all_stream(
+        &self,
+        customer: &str,
+        role_id: &str,
+        user_key: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::RoleAssignment>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !role_id.is_empty() {
+            query_args.push(("roleId".to_string(), role_id.to_string()));
+        }
+        if !user_key.is_empty() {
+            query_args.push(("userKey".to_string(), user_key.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/admin/directory/v1/customer/{}/roleassignments?{}",
+                crate::progenitor_support::encode_path(customer),
+                query_
+            ),
+            None,
+        );
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let next = format!("{}{}pageToken=", url, sep);
+        self.client
+            .get_pages_stream(url, move |body: crate::types::RoleAssignments| {
+                let page = if body.next_page_token.is_empty() {
+                    None
+                } else {
+                    Some(format!("{}{}", next, body.next_page_token))
+                };
+
+                (body.items, page)
+            })
     }
     /**
      * This function performs a `POST` to the `/admin/directory/v1/customer/{customer}/roleassignments` endpoint.
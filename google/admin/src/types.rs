@@ -51,6 +51,10 @@ pub struct Alias {
         rename = "primaryEmail"
     )]
     pub primary_email: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// JSON response template to list aliases in Directory API.
@@ -83,6 +87,10 @@ pub struct Aliases {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub kind: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// An application-specific password (ASP) is used with applications that do not accept a verification code when logging into the application on certain devices. The ASP access code is used instead of the login and password you commonly use when accessing an application through a browser. For more information about ASPs and how to create one, see the [help center](https://support.google.com/a/answer/2537800#asp).
@@ -155,6 +163,10 @@ pub struct Asp {
         rename = "userKey"
     )]
     pub user_key: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -180,6 +192,10 @@ pub struct Asps {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub kind: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -198,8 +214,9 @@ pub enum Severity {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Severity {
@@ -210,7 +227,7 @@ impl std::fmt::Display for Severity {
             Severity::SeverityUnspecified => "SEVERITY_UNSPECIFIED",
             Severity::SeverityWarning => "SEVERITY_WARNING",
             Severity::Noop => "",
-            Severity::FallthroughString => "*",
+            Severity::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -250,6 +267,10 @@ pub struct AuxiliaryMessage {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub severity: Option<Severity>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Request for adding new printers in batch.
@@ -264,6 +285,10 @@ pub struct BatchCreatePrintersRequest {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub requests: Vec<CreatePrinterRequest>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Response for adding new printers in batch.
@@ -287,6 +312,10 @@ pub struct BatchCreatePrintersResponse {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub printers: Vec<Printer>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Request for deleting existing printers in batch.
@@ -302,6 +331,10 @@ pub struct BatchDeletePrintersRequest {
         rename = "printerIds"
     )]
     pub printer_ids: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Response for deleting existing printers in batch.
@@ -327,6 +360,10 @@ pub struct BatchDeletePrintersResponse {
         rename = "printerIds"
     )]
     pub printer_ids: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Public API: Resources.buildings
@@ -399,6 +436,10 @@ pub struct Building {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub kind: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Public API: Resources.buildings
@@ -472,6 +513,10 @@ pub struct BuildingAddress {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub sublocality: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Public API: Resources.buildings
@@ -495,6 +540,10 @@ pub struct BuildingCoordinates {
         deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub longitude: f64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Public API: Resources.buildings
@@ -537,6 +586,10 @@ pub struct Buildings {
         rename = "nextPageToken"
     )]
     pub next_page_token: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Public API: Resources.calendars
@@ -688,6 +741,10 @@ pub struct CalendarResource {
         rename = "userVisibleDescription"
     )]
     pub user_visible_description: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Public API: Resources.calendars
@@ -730,6 +787,10 @@ pub struct CalendarResources {
         rename = "nextPageToken"
     )]
     pub next_page_token: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// An notification channel used to watch for resource changes.
@@ -827,6 +888,10 @@ pub struct Channel {
         rename = "type"
     )]
     pub type_: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -850,6 +915,10 @@ pub struct ActiveTimeRanges {
         deserialize_with = "crate::utils::date_format::deserialize"
     )]
     pub date: Option<chrono::NaiveDate>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -869,6 +938,10 @@ pub struct CpuTemperatureInfo {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub temperature: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -900,6 +973,10 @@ pub struct CpuStatusReports {
         rename = "reportTime"
     )]
     pub report_time: Option<chrono::DateTime<chrono::Utc>>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -934,6 +1011,10 @@ pub struct DeviceFiles {
         rename = "type"
     )]
     pub type_: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -965,6 +1046,10 @@ pub struct VolumeInfo {
         rename = "volumeId"
     )]
     pub volume_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -979,6 +1064,10 @@ pub struct DiskVolumeReports {
         rename = "volumeInfo"
     )]
     pub volume_info: Vec<VolumeInfo>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Information for an ip address.
@@ -1004,6 +1093,10 @@ pub struct LastKnownNetwork {
         rename = "wanIpAddress"
     )]
     pub wan_ip_address: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// List of recent device users, in descending order, by last login time.
@@ -1028,6 +1121,10 @@ pub struct RecentUsers {
         rename = "type"
     )]
     pub type_: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -1049,6 +1146,10 @@ pub struct SystemRamFreeReports {
         rename = "systemRamFreeInfo"
     )]
     pub system_ram_free_info: Vec<i64>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Trusted Platform Module (TPM) (Read-only)
@@ -1112,6 +1213,10 @@ pub struct TpmVersionInfo {
         rename = "vendorSpecific"
     )]
     pub vendor_specific: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Google Chrome devices run on the [Chrome OS](https://support.google.com/chromeos). For more information about common API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-chrome-devices).
@@ -1479,6 +1584,10 @@ pub struct ChromeOsDevice {
         rename = "willAutoRenew"
     )]
     pub will_auto_renew: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -1496,6 +1605,10 @@ pub struct ChromeOsDeviceAction {
         rename = "deprovisionReason"
     )]
     pub deprovision_reason: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -1528,6 +1641,10 @@ pub struct ChromeOsDevices {
         rename = "nextPageToken"
     )]
     pub next_page_token: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -1539,6 +1656,10 @@ pub struct ChromeOsMoveDevicesOu {
         rename = "deviceIds"
     )]
     pub device_ids: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Request for adding a new printer.
@@ -1558,6 +1679,10 @@ pub struct CreatePrinterRequest {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub printer: Option<Printer>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -1623,6 +1748,10 @@ pub struct Customer {
         rename = "postalAddress"
     )]
     pub postal_address: Option<CustomerPostalAddress>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -1688,6 +1817,10 @@ pub struct CustomerPostalAddress {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub region: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -1712,8 +1845,9 @@ pub enum State {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for State {
@@ -1727,7 +1861,7 @@ impl std::fmt::Display for State {
             State::SentToClient => "SENT_TO_CLIENT",
             State::StateUnspecified => "STATE_UNSPECIFIED",
             State::Noop => "",
-            State::FallthroughString => "*",
+            State::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1759,8 +1893,9 @@ pub enum Type {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Type {
@@ -1773,7 +1908,7 @@ impl std::fmt::Display for Type {
             Type::TakeAScreenshot => "TAKE_A_SCREENSHOT",
             Type::WipeUsers => "WIPE_USERS",
             Type::Noop => "",
-            Type::FallthroughString => "*",
+            Type::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1846,6 +1981,10 @@ pub struct DirectoryChromeosdevicesCommand {
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
     pub type_: Option<Type>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -1864,8 +2003,9 @@ pub enum Result {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Result {
@@ -1876,7 +2016,7 @@ impl std::fmt::Display for Result {
             Result::Ignored => "IGNORED",
             Result::Success => "SUCCESS",
             Result::Noop => "",
-            Result::FallthroughString => "*",
+            Result::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1916,6 +2056,10 @@ pub struct DirectoryChromeosdevicesCommandResult {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub result: Option<Result>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A request for issuing a command.
@@ -1939,6 +2083,10 @@ pub struct DirectoryChromeosdevicesIssueCommandRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub payload: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A response for issuing a command.
@@ -1954,6 +2102,10 @@ pub struct DirectoryChromeosdevicesIssueCommandResponse {
         rename = "commandId"
     )]
     pub command_id: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -2002,6 +2154,10 @@ pub struct DomainAlias {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub verified: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -2028,6 +2184,10 @@ pub struct DomainAliases {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub kind: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -2088,6 +2248,10 @@ pub struct Domains {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub verified: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -2113,11 +2277,20 @@ pub struct Domains2 {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub kind: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A generic empty message that you can re-use to avoid defining duplicated empty messages in your APIs. A typical example is to use it as the request or the response type of an API method. For instance: service Foo { rpc Bar(google.protobuf.Empty) returns (google.protobuf.Empty); } The JSON representation for `Empty` is empty JSON object `{}`.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Empty {}
+pub struct Empty {
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
 
 /**
  * Canonical code for why the update failed to apply.
@@ -2156,13 +2329,12 @@ pub enum ErrorCode {
     Unavailable,
     #[serde(rename = "UNIMPLEMENTED")]
     Unimplemented,
-    #[serde(rename = "UNKNOWN")]
-    Unknown,
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ErrorCode {
@@ -2184,9 +2356,8 @@ impl std::fmt::Display for ErrorCode {
             ErrorCode::Unauthenticated => "UNAUTHENTICATED",
             ErrorCode::Unavailable => "UNAVAILABLE",
             ErrorCode::Unimplemented => "UNIMPLEMENTED",
-            ErrorCode::Unknown => "UNKNOWN",
             ErrorCode::Noop => "",
-            ErrorCode::FallthroughString => "*",
+            ErrorCode::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2231,6 +2402,10 @@ pub struct FailureInfo {
         rename = "printerId"
     )]
     pub printer_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// JSON template for Feature object in Directory API.
@@ -2263,6 +2438,10 @@ pub struct Feature {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// JSON template for a feature instance.
@@ -2273,6 +2452,10 @@ pub struct FeatureInstance {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub feature: Option<Feature>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -2284,6 +2467,10 @@ pub struct FeatureRename {
         rename = "newName"
     )]
     pub new_name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Public API: Resources.features
@@ -2326,6 +2513,10 @@ pub struct Features {
         rename = "nextPageToken"
     )]
     pub next_page_token: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Google Groups provide your users the ability to send messages to groups of people using the group's email address. For more information about common tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-groups).
@@ -2423,6 +2614,10 @@ pub struct Group {
         rename = "nonEditableAliases"
     )]
     pub non_editable_aliases: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -2455,6 +2650,10 @@ pub struct Groups {
         rename = "nextPageToken"
     )]
     pub next_page_token: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Response for listing allowed printer models.
@@ -2480,6 +2679,10 @@ pub struct ListPrinterModelsResponse {
         rename = "printerModels"
     )]
     pub printer_models: Vec<PrinterModel>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Response for listing printers.
@@ -2504,6 +2707,10 @@ pub struct ListPrintersResponse {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub printers: Vec<Printer>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A Google Groups member can be a user or another group. This member can be inside or outside of your account's domains. For more information about common group member tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-group-members).
@@ -2582,6 +2789,10 @@ pub struct Member {
         rename = "type"
     )]
     pub type_: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -2614,6 +2825,10 @@ pub struct Members {
         rename = "nextPageToken"
     )]
     pub next_page_token: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// JSON template for Has Member response in Directory API.
@@ -2628,6 +2843,10 @@ pub struct MembersHasMember {
         rename = "isMember"
     )]
     pub is_member: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -2669,6 +2888,10 @@ pub struct Applications {
         rename = "versionName"
     )]
     pub version_name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Google Workspace Mobile Management includes Android, [Google Sync](https://support.google.com/a/answer/135937), and iOS devices. For more information about common group mobile device API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-mobile-devices.html).
@@ -3055,6 +3278,10 @@ pub struct MobileDevice {
         rename = "wifiMacAddress"
     )]
     pub wifi_mac_address: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -3065,6 +3292,10 @@ pub struct MobileDeviceAction {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub action: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -3097,6 +3328,10 @@ pub struct MobileDevices {
         rename = "nextPageToken"
     )]
     pub next_page_token: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Managing your account's organizational units allows you to configure your users' access to services and custom settings. For more information about common organizational unit tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-org-units.html).
@@ -3187,6 +3422,10 @@ pub struct OrgUnit {
         rename = "parentOrgUnitPath"
     )]
     pub parent_org_unit_path: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -3213,6 +3452,10 @@ pub struct OrgUnits {
         rename = "organizationUnits"
     )]
     pub organization_units: Vec<OrgUnit>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Printer configuration.
@@ -3313,6 +3556,10 @@ pub struct Printer {
         rename = "useDriverlessConfig"
     )]
     pub use_driverless_config: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Printer manufacturer and model
@@ -3347,6 +3594,10 @@ pub struct PrinterModel {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub manufacturer: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -3403,6 +3654,10 @@ pub struct Privilege {
         rename = "serviceName"
     )]
     pub service_name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -3428,6 +3683,10 @@ pub struct Privileges {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub kind: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -3446,6 +3705,10 @@ pub struct RolePrivileges {
         rename = "serviceId"
     )]
     pub service_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -3514,6 +3777,10 @@ pub struct Role {
         rename = "rolePrivileges"
     )]
     pub role_privileges: Vec<RolePrivileges>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Defines an assignment of a role.
@@ -3587,6 +3854,10 @@ pub struct RoleAssignment {
         rename = "scopeType"
     )]
     pub scope_type: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -3619,6 +3890,10 @@ pub struct RoleAssignments {
         rename = "nextPageToken"
     )]
     pub next_page_token: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -3651,6 +3926,10 @@ pub struct Roles {
         rename = "nextPageToken"
     )]
     pub next_page_token: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The type of API resource. For Schema resources, this is always `admin#directory#schema`.
@@ -3713,6 +3992,10 @@ pub struct Schema {
         rename = "schemaName"
     )]
     pub schema_name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Indexing spec for a numeric field. By default, only exact match queries will be supported for numeric fields. Setting the `numericIndexingSpec` allows range queries to be supported.
@@ -3738,6 +4021,10 @@ pub struct NumericIndexingSpec {
         rename = "minValue"
     )]
     pub min_value: f64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// You can use schemas to add custom fields to user profiles. You can use these fields to store information such as the projects your users work on, their physical locations, their hire dates, or whatever else fits your business needs. For more information, see [Custom User Fields](/admin-sdk/directory/v1/guides/manage-schemas).
@@ -3837,6 +4124,10 @@ pub struct SchemaFieldSpec {
         rename = "readAccessType"
     )]
     pub read_access_type: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// JSON response template for List Schema operation in Directory API.
@@ -3869,6 +4160,10 @@ pub struct Schemas {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub schemas: Vec<Schema>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// JSON template for token resource in Directory API.
@@ -3948,6 +4243,10 @@ pub struct Token {
         rename = "userKey"
     )]
     pub user_key: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// JSON response template for List tokens operation in Directory API.
@@ -3980,6 +4279,10 @@ pub struct Tokens {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub kind: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -4025,6 +4328,10 @@ pub struct Ims {
         rename = "type"
     )]
     pub type_: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The Directory API allows you to create and manage your account's users, user aliases, and user Gmail chat profile photos. For more information about common tasks, see the [User Accounts Developer's Guide](/admin-sdk/directory/v1/guides/manage-users.html) and the [User Aliases Developer's Guide](/admin-sdk/directory/v1/guides/manage-user-aliases.html).
@@ -4425,6 +4732,10 @@ pub struct User {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub websites: Option<serde_json::Value>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// JSON template for About (notes) of a user in Directory API.
@@ -4449,6 +4760,10 @@ pub struct UserAbout {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub value: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// JSON template for address.
@@ -4577,6 +4892,10 @@ pub struct UserAddress {
         rename = "type"
     )]
     pub type_: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// JSON template for an email.
@@ -4619,6 +4938,10 @@ pub struct UserEmail {
         rename = "type"
     )]
     pub type_: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// JSON template for an externalId entry.
@@ -4653,6 +4976,10 @@ pub struct UserExternalId {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub value: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -4678,6 +5005,10 @@ pub struct UserGender {
         rename = "type"
     )]
     pub type_: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// JSON template for instant messenger of an user.
@@ -4739,6 +5070,10 @@ pub struct UserIm {
         rename = "type"
     )]
     pub type_: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// JSON template for a keyword entry.
@@ -4773,6 +5108,10 @@ pub struct UserKeyword {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub value: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// JSON template for a language entry.
@@ -4798,6 +5137,10 @@ pub struct UserLanguage {
         rename = "languageCode"
     )]
     pub language_code: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// JSON template for a location entry.
@@ -4872,6 +5215,10 @@ pub struct UserLocation {
         rename = "type"
     )]
     pub type_: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -4884,6 +5231,10 @@ pub struct UserMakeAdmin {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub status: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -4909,6 +5260,10 @@ pub struct UserName {
         rename = "givenName"
     )]
     pub given_name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// JSON template for an organization entry.
@@ -5025,6 +5380,10 @@ pub struct UserOrganization {
         rename = "type"
     )]
     pub type_: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// JSON template for a phone entry.
@@ -5067,6 +5426,10 @@ pub struct UserPhone {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub value: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -5126,6 +5489,10 @@ pub struct UserPhoto {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub width: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// JSON template for a POSIX account entry.
@@ -5216,6 +5583,10 @@ pub struct UserPosixAccount {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub username: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// JSON template for a relation entry.
@@ -5250,6 +5621,10 @@ pub struct UserRelation {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub value: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// JSON template for a POSIX account entry.
@@ -5283,6 +5658,10 @@ pub struct UserSshPublicKey {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub key: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -5294,6 +5673,10 @@ pub struct UserUndelete {
         rename = "orgUnitPath"
     )]
     pub org_unit_path: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// JSON template for a website entry.
@@ -5336,6 +5719,10 @@ pub struct UserWebsite {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub value: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -5374,6 +5761,10 @@ pub struct Users {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub users: Vec<User>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The Directory API allows you to view, generate, and invalidate backup verification codes for a user.
@@ -5417,6 +5808,10 @@ pub struct VerificationCode {
         rename = "verificationCode"
     )]
     pub verification_code: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// JSON response template for List verification codes operation in Directory API.
@@ -5449,6 +5844,10 @@ pub struct VerificationCodes {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub kind: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -5463,8 +5862,9 @@ pub enum Xgafv {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Xgafv {
@@ -5473,7 +5873,7 @@ impl std::fmt::Display for Xgafv {
             Xgafv::One => "1",
             Xgafv::Two => "2",
             Xgafv::Noop => "",
-            Xgafv::FallthroughString => "*",
+            Xgafv::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -5499,8 +5899,9 @@ pub enum Alt {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Alt {
@@ -5510,7 +5911,7 @@ impl std::fmt::Display for Alt {
             Alt::Media => "media",
             Alt::Proto => "proto",
             Alt::Noop => "",
-            Alt::FallthroughString => "*",
+            Alt::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -5544,8 +5945,9 @@ pub enum OrderBy {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OrderBy {
@@ -5559,7 +5961,7 @@ impl std::fmt::Display for OrderBy {
             OrderBy::Status => "status",
             OrderBy::SupportEndDate => "supportEndDate",
             OrderBy::Noop => "",
-            OrderBy::FallthroughString => "*",
+            OrderBy::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -5583,8 +5985,9 @@ pub enum Projection {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Projection {
@@ -5593,7 +5996,7 @@ impl std::fmt::Display for Projection {
             Projection::Basic => "BASIC",
             Projection::Full => "FULL",
             Projection::Noop => "",
-            Projection::FallthroughString => "*",
+            Projection::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -5617,8 +6020,9 @@ pub enum SortOrder {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SortOrder {
@@ -5627,7 +6031,7 @@ impl std::fmt::Display for SortOrder {
             SortOrder::Ascending => "ASCENDING",
             SortOrder::Descending => "DESCENDING",
             SortOrder::Noop => "",
-            SortOrder::FallthroughString => "*",
+            SortOrder::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -5663,8 +6067,9 @@ pub enum DirectoryMobiledevicesListOrderBy {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DirectoryMobiledevicesListOrderBy {
@@ -5679,7 +6084,7 @@ impl std::fmt::Display for DirectoryMobiledevicesListOrderBy {
             DirectoryMobiledevicesListOrderBy::Status => "status",
             DirectoryMobiledevicesListOrderBy::Type => "type",
             DirectoryMobiledevicesListOrderBy::Noop => "",
-            DirectoryMobiledevicesListOrderBy::FallthroughString => "*",
+            DirectoryMobiledevicesListOrderBy::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -5703,8 +6108,9 @@ pub enum DirectoryOrgunitsListType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DirectoryOrgunitsListType {
@@ -5713,7 +6119,7 @@ impl std::fmt::Display for DirectoryOrgunitsListType {
             DirectoryOrgunitsListType::All => "all",
             DirectoryOrgunitsListType::Children => "children",
             DirectoryOrgunitsListType::Noop => "",
-            DirectoryOrgunitsListType::FallthroughString => "*",
+            DirectoryOrgunitsListType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -5739,8 +6145,9 @@ pub enum CoordinatesSource {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for CoordinatesSource {
@@ -5750,7 +6157,7 @@ impl std::fmt::Display for CoordinatesSource {
             CoordinatesSource::ResolvedFromAddress => "RESOLVED_FROM_ADDRESS",
             CoordinatesSource::SourceUnspecified => "SOURCE_UNSPECIFIED",
             CoordinatesSource::Noop => "",
-            CoordinatesSource::FallthroughString => "*",
+            CoordinatesSource::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -5772,8 +6179,9 @@ pub enum DirectoryGroupsListOrderBy {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DirectoryGroupsListOrderBy {
@@ -5781,7 +6189,7 @@ impl std::fmt::Display for DirectoryGroupsListOrderBy {
         match self {
             DirectoryGroupsListOrderBy::Email => "email",
             DirectoryGroupsListOrderBy::Noop => "",
-            DirectoryGroupsListOrderBy::FallthroughString => "*",
+            DirectoryGroupsListOrderBy::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -5811,8 +6219,9 @@ pub enum Event {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Event {
@@ -5824,7 +6233,7 @@ impl std::fmt::Display for Event {
             Event::Undelete => "undelete",
             Event::Update => "update",
             Event::Noop => "",
-            Event::FallthroughString => "*",
+            Event::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -5850,8 +6259,9 @@ pub enum DirectoryUsersListOrderBy {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DirectoryUsersListOrderBy {
@@ -5861,7 +6271,7 @@ impl std::fmt::Display for DirectoryUsersListOrderBy {
             DirectoryUsersListOrderBy::FamilyName => "familyName",
             DirectoryUsersListOrderBy::GivenName => "givenName",
             DirectoryUsersListOrderBy::Noop => "",
-            DirectoryUsersListOrderBy::FallthroughString => "*",
+            DirectoryUsersListOrderBy::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -5887,8 +6297,9 @@ pub enum DirectoryUsersListProjection {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DirectoryUsersListProjection {
@@ -5898,7 +6309,7 @@ impl std::fmt::Display for DirectoryUsersListProjection {
             DirectoryUsersListProjection::Custom => "custom",
             DirectoryUsersListProjection::Full => "full",
             DirectoryUsersListProjection::Noop => "",
-            DirectoryUsersListProjection::FallthroughString => "*",
+            DirectoryUsersListProjection::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -5922,8 +6333,9 @@ pub enum ViewType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ViewType {
@@ -5932,7 +6344,7 @@ impl std::fmt::Display for ViewType {
             ViewType::AdminView => "admin_view",
             ViewType::DomainPublic => "domain_public",
             ViewType::Noop => "",
-            ViewType::FallthroughString => "*",
+            ViewType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -5956,8 +6368,9 @@ pub enum DirectoryUsersAliasesListEvent {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DirectoryUsersAliasesListEvent {
@@ -5966,7 +6379,7 @@ impl std::fmt::Display for DirectoryUsersAliasesListEvent {
             DirectoryUsersAliasesListEvent::Add => "add",
             DirectoryUsersAliasesListEvent::Delete => "delete",
             DirectoryUsersAliasesListEvent::Noop => "",
-            DirectoryUsersAliasesListEvent::FallthroughString => "*",
+            DirectoryUsersAliasesListEvent::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
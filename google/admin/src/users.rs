@@ -1,6 +1,474 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Users::list`], set with a builder.
+pub struct UsersListRequest<'a> {
+    client: &'a Users,
+    customer: Option<&'a str>,
+    domain: Option<&'a str>,
+    event: Option<crate::types::Event>,
+    max_results: Option<i64>,
+    order_by: Option<crate::types::DirectoryUsersListOrderBy>,
+    page_token: Option<&'a str>,
+    projection: Option<crate::types::DirectoryUsersListProjection>,
+    query: Option<&'a str>,
+    show_deleted: Option<&'a str>,
+    sort_order: Option<crate::types::SortOrder>,
+    view_type: Option<crate::types::ViewType>,
+}
+
+impl<'a> UsersListRequest<'a> {
+    /// Sets the `customer` query parameter.
+    pub fn customer(mut self, customer: &'a str) -> Self {
+        self.customer = Some(customer);
+        self
+    }
+
+    /// Sets the `domain` query parameter.
+    pub fn domain(mut self, domain: &'a str) -> Self {
+        self.domain = Some(domain);
+        self
+    }
+
+    /// Sets the `event` query parameter.
+    pub fn event(mut self, event: crate::types::Event) -> Self {
+        self.event = Some(event);
+        self
+    }
+
+    /// Sets the `maxResults` query parameter.
+    pub fn max_results(mut self, max_results: i64) -> Self {
+        self.max_results = Some(max_results);
+        self
+    }
+
+    /// Sets the `orderBy` query parameter.
+    pub fn order_by(mut self, order_by: crate::types::DirectoryUsersListOrderBy) -> Self {
+        self.order_by = Some(order_by);
+        self
+    }
+
+    /// Sets the `pageToken` query parameter.
+    pub fn page_token(mut self, page_token: &'a str) -> Self {
+        self.page_token = Some(page_token);
+        self
+    }
+
+    /// Sets the `projection` query parameter.
+    pub fn projection(mut self, projection: crate::types::DirectoryUsersListProjection) -> Self {
+        self.projection = Some(projection);
+        self
+    }
+
+    /// Sets the `query` query parameter.
+    pub fn query(mut self, query: &'a str) -> Self {
+        self.query = Some(query);
+        self
+    }
+
+    /// Sets the `showDeleted` query parameter.
+    pub fn show_deleted(mut self, show_deleted: &'a str) -> Self {
+        self.show_deleted = Some(show_deleted);
+        self
+    }
+
+    /// Sets the `sortOrder` query parameter.
+    pub fn sort_order(mut self, sort_order: crate::types::SortOrder) -> Self {
+        self.sort_order = Some(sort_order);
+        self
+    }
+
+    /// Sets the `viewType` query parameter.
+    pub fn view_type(mut self, view_type: crate::types::ViewType) -> Self {
+        self.view_type = Some(view_type);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::User>>> {
+        let Self {
+            client,
+            customer,
+            domain,
+            event,
+            max_results,
+            order_by,
+            page_token,
+            projection,
+            query,
+            show_deleted,
+            sort_order,
+            view_type,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = customer {
+            query_args.push(("customer".to_string(), v.to_string()));
+        }
+        if let Some(v) = domain {
+            query_args.push(("domain".to_string(), v.to_string()));
+        }
+        if let Some(v) = event {
+            query_args.push(("event".to_string(), v.to_string()));
+        }
+        if let Some(v) = max_results {
+            query_args.push(("maxResults".to_string(), v.to_string()));
+        }
+        if let Some(v) = order_by {
+            query_args.push(("orderBy".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_token {
+            query_args.push(("pageToken".to_string(), v.to_string()));
+        }
+        if let Some(v) = projection {
+            query_args.push(("projection".to_string(), v.to_string()));
+        }
+        if let Some(v) = query {
+            query_args.push(("query".to_string(), v.to_string()));
+        }
+        if let Some(v) = show_deleted {
+            query_args.push(("showDeleted".to_string(), v.to_string()));
+        }
+        if let Some(v) = sort_order {
+            query_args.push(("sortOrder".to_string(), v.to_string()));
+        }
+        if let Some(v) = view_type {
+            query_args.push(("viewType".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/admin/directory/v1/users?{}", query_), None);
+
+        let resp: crate::Response<crate::types::Users> = client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await?;
+
+        // Return our response data.
+        Ok(crate::Response::new(
+            resp.status,
+            resp.headers,
+            resp.body.users.to_vec(),
+        ))
+    }
+}
+
+/// The parameters of [`Users::watch`], set with a builder.
+pub struct UsersWatchRequest<'a> {
+    client: &'a Users,
+    customer: Option<&'a str>,
+    domain: Option<&'a str>,
+    event: Option<crate::types::Event>,
+    max_results: Option<i64>,
+    order_by: Option<crate::types::DirectoryUsersListOrderBy>,
+    page_token: Option<&'a str>,
+    projection: Option<crate::types::DirectoryUsersListProjection>,
+    query: Option<&'a str>,
+    show_deleted: Option<&'a str>,
+    sort_order: Option<crate::types::SortOrder>,
+    view_type: Option<crate::types::ViewType>,
+    body: &'a crate::types::Channel,
+}
+
+impl<'a> UsersWatchRequest<'a> {
+    /// Sets the `customer` query parameter.
+    pub fn customer(mut self, customer: &'a str) -> Self {
+        self.customer = Some(customer);
+        self
+    }
+
+    /// Sets the `domain` query parameter.
+    pub fn domain(mut self, domain: &'a str) -> Self {
+        self.domain = Some(domain);
+        self
+    }
+
+    /// Sets the `event` query parameter.
+    pub fn event(mut self, event: crate::types::Event) -> Self {
+        self.event = Some(event);
+        self
+    }
+
+    /// Sets the `maxResults` query parameter.
+    pub fn max_results(mut self, max_results: i64) -> Self {
+        self.max_results = Some(max_results);
+        self
+    }
+
+    /// Sets the `orderBy` query parameter.
+    pub fn order_by(mut self, order_by: crate::types::DirectoryUsersListOrderBy) -> Self {
+        self.order_by = Some(order_by);
+        self
+    }
+
+    /// Sets the `pageToken` query parameter.
+    pub fn page_token(mut self, page_token: &'a str) -> Self {
+        self.page_token = Some(page_token);
+        self
+    }
+
+    /// Sets the `projection` query parameter.
+    pub fn projection(mut self, projection: crate::types::DirectoryUsersListProjection) -> Self {
+        self.projection = Some(projection);
+        self
+    }
+
+    /// Sets the `query` query parameter.
+    pub fn query(mut self, query: &'a str) -> Self {
+        self.query = Some(query);
+        self
+    }
+
+    /// Sets the `showDeleted` query parameter.
+    pub fn show_deleted(mut self, show_deleted: &'a str) -> Self {
+        self.show_deleted = Some(show_deleted);
+        self
+    }
+
+    /// Sets the `sortOrder` query parameter.
+    pub fn sort_order(mut self, sort_order: crate::types::SortOrder) -> Self {
+        self.sort_order = Some(sort_order);
+        self
+    }
+
+    /// Sets the `viewType` query parameter.
+    pub fn view_type(mut self, view_type: crate::types::ViewType) -> Self {
+        self.view_type = Some(view_type);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::Channel>> {
+        let Self {
+            client,
+            customer,
+            domain,
+            event,
+            max_results,
+            order_by,
+            page_token,
+            projection,
+            query,
+            show_deleted,
+            sort_order,
+            view_type,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = customer {
+            query_args.push(("customer".to_string(), v.to_string()));
+        }
+        if let Some(v) = domain {
+            query_args.push(("domain".to_string(), v.to_string()));
+        }
+        if let Some(v) = event {
+            query_args.push(("event".to_string(), v.to_string()));
+        }
+        if let Some(v) = max_results {
+            query_args.push(("maxResults".to_string(), v.to_string()));
+        }
+        if let Some(v) = order_by {
+            query_args.push(("orderBy".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_token {
+            query_args.push(("pageToken".to_string(), v.to_string()));
+        }
+        if let Some(v) = projection {
+            query_args.push(("projection".to_string(), v.to_string()));
+        }
+        if let Some(v) = query {
+            query_args.push(("query".to_string(), v.to_string()));
+        }
+        if let Some(v) = show_deleted {
+            query_args.push(("showDeleted".to_string(), v.to_string()));
+        }
+        if let Some(v) = sort_order {
+            query_args.push(("sortOrder".to_string(), v.to_string()));
+        }
+        if let Some(v) = view_type {
+            query_args.push(("viewType".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/admin/directory/v1/users/watch?{}", query_), None);
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Users::get`], set with a builder.
+pub struct UsersGetRequest<'a> {
+    client: &'a Users,
+    user_key: &'a str,
+    projection: Option<crate::types::DirectoryUsersListProjection>,
+    view_type: Option<crate::types::ViewType>,
+}
+
+impl<'a> UsersGetRequest<'a> {
+    /// Sets the `projection` query parameter.
+    pub fn projection(mut self, projection: crate::types::DirectoryUsersListProjection) -> Self {
+        self.projection = Some(projection);
+        self
+    }
+
+    /// Sets the `viewType` query parameter.
+    pub fn view_type(mut self, view_type: crate::types::ViewType) -> Self {
+        self.view_type = Some(view_type);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::User>> {
+        let Self {
+            client,
+            user_key,
+            projection,
+            view_type,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = projection {
+            query_args.push(("projection".to_string(), v.to_string()));
+        }
+        if let Some(v) = view_type {
+            query_args.push(("viewType".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/directory/v1/users/{}?{}",
+                crate::progenitor_support::encode_path(user_key),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Users::aliases_list`], set with a builder.
+pub struct UsersAliasesListRequest<'a> {
+    client: &'a Users,
+    user_key: &'a str,
+    event: Option<crate::types::DirectoryUsersAliasesListEvent>,
+}
+
+impl<'a> UsersAliasesListRequest<'a> {
+    /// Sets the `event` query parameter.
+    pub fn event(mut self, event: crate::types::DirectoryUsersAliasesListEvent) -> Self {
+        self.event = Some(event);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::Aliases>> {
+        let Self {
+            client,
+            user_key,
+            event,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = event {
+            query_args.push(("event".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/directory/v1/users/{}/aliases?{}",
+                crate::progenitor_support::encode_path(user_key),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Users::aliases_watch`], set with a builder.
+pub struct UsersAliasesWatchRequest<'a> {
+    client: &'a Users,
+    user_key: &'a str,
+    event: Option<crate::types::DirectoryUsersAliasesListEvent>,
+    body: &'a crate::types::Channel,
+}
+
+impl<'a> UsersAliasesWatchRequest<'a> {
+    /// Sets the `event` query parameter.
+    pub fn event(mut self, event: crate::types::DirectoryUsersAliasesListEvent) -> Self {
+        self.event = Some(event);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::Channel>> {
+        let Self {
+            client,
+            user_key,
+            event,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = event {
+            query_args.push(("event".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/directory/v1/users/{}/aliases/watch?{}",
+                crate::progenitor_support::encode_path(user_key),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
 pub struct Users {
     pub client: Client,
 }
@@ -101,6 +569,29 @@ impl Users {
             resp.body.users.to_vec(),
         ))
     }
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/users` endpoint.
+     *
+     * As opposed to `list`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Retrieves a paginated list of either deleted users or all users in a domain.
+     */
+    pub fn list_request(&self) -> UsersListRequest<'_> {
+        UsersListRequest {
+            client: self,
+            customer: None,
+            domain: None,
+            event: None,
+            max_results: None,
+            order_by: None,
+            page_token: None,
+            projection: None,
+            query: None,
+            show_deleted: None,
+            sort_order: None,
+            view_type: None,
+        }
+    }
     /**
      * This function performs a `GET` to the `/admin/directory/v1/users` endpoint.
      *
@@ -152,11 +643,11 @@ impl Users {
         let url = self
             .client
             .url(&format!("/admin/directory/v1/users?{}", query_), None);
-        let crate::Response::<crate::types::Users> {
-            mut status,
-            mut headers,
-            mut body,
-        } = self
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let mut pages = crate::utils::Pages::new(self.client.page_limits);
+        pages.visit(&url)?;
+
+        let mut response: crate::Response<crate::types::Users> = self
             .client
             .get(
                 &url,
@@ -166,55 +657,107 @@ impl Users {
                 },
             )
             .await?;
-
-        let mut users = body.users;
-        let mut page = body.next_page_token;
+        let mut users = Vec::new();
 
         // Paginate if we should.
-        while !page.is_empty() {
-            if !url.contains('?') {
-                crate::Response::<crate::types::Users> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}?pageToken={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            } else {
-                crate::Response::<crate::types::Users> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}&pageToken={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
-
-            users.append(&mut body.users);
-
-            if !body.next_page_token.is_empty() && body.next_page_token != page {
-                page = body.next_page_token.to_string();
+        loop {
+            let page = if response.body.next_page_token.is_empty() {
+                None
             } else {
-                page = "".to_string();
-            }
+                Some(format!(
+                    "{}{}pageToken={}",
+                    url, sep, response.body.next_page_token
+                ))
+            };
+            let more = pages.keep(&mut response.body.users);
+            users.append(&mut response.body.users);
+
+            let next = match page {
+                Some(next) if more && pages.visit(&next)? => next,
+                _ => break,
+            };
+            response = self
+                .client
+                .get(
+                    &next,
+                    crate::Message {
+                        body: None,
+                        content_type: None,
+                    },
+                )
+                .await?;
         }
 
         // Return our response data.
-        Ok(crate::Response::new(status, headers, users))
+        Ok(crate::Response::new(
+            response.status,
+            response.headers,
+            users,
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/users` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Retrieves a paginated list of either deleted users or all users in a domain.
+     */
+    pub fn list_all_stream(
+        &self,
+        customer: &str,
+        domain: &str,
+        event: crate::types::Event,
+        order_by: crate::types::DirectoryUsersListOrderBy,
+        projection: crate::types::DirectoryUsersListProjection,
+        query: &str,
+        show_deleted: &str,
+        sort_order: crate::types::SortOrder,
+        view_type: crate::types::ViewType,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::User>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !customer.is_empty() {
+            query_args.push(("customer".to_string(), customer.to_string()));
+        }
+        if !domain.is_empty() {
+            query_args.push(("domain".to_string(), domain.to_string()));
+        }
+        if !event.to_string().is_empty() {
+            query_args.push(("event".to_string(), event.to_string()));
+        }
+        if !order_by.to_string().is_empty() {
+            query_args.push(("orderBy".to_string(), order_by.to_string()));
+        }
+        if !projection.to_string().is_empty() {
+            query_args.push(("projection".to_string(), projection.to_string()));
+        }
+        if !query.is_empty() {
+            query_args.push(("query".to_string(), query.to_string()));
+        }
+        if !show_deleted.is_empty() {
+            query_args.push(("showDeleted".to_string(), show_deleted.to_string()));
+        }
+        if !sort_order.to_string().is_empty() {
+            query_args.push(("sortOrder".to_string(), sort_order.to_string()));
+        }
+        if !view_type.to_string().is_empty() {
+            query_args.push(("viewType".to_string(), view_type.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/admin/directory/v1/users?{}", query_), None);
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let next = format!("{}{}pageToken=", url, sep);
+        self.client
+            .get_pages_stream(url, move |body: crate::types::Users| {
+                let page = if body.next_page_token.is_empty() {
+                    None
+                } else {
+                    Some(format!("{}{}", next, body.next_page_token))
+                };
+
+                (body.users, page)
+            })
     }
     /**
      * This function performs a `POST` to the `/admin/directory/v1/users` endpoint.
@@ -319,6 +862,30 @@ impl Users {
             )
             .await
     }
+    /**
+     * This function performs a `POST` to the `/admin/directory/v1/users/watch` endpoint.
+     *
+     * As opposed to `watch`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Watches for changes in users list.
+     */
+    pub fn watch_request<'a>(&'a self, body: &'a crate::types::Channel) -> UsersWatchRequest<'a> {
+        UsersWatchRequest {
+            client: self,
+            customer: None,
+            domain: None,
+            event: None,
+            max_results: None,
+            order_by: None,
+            page_token: None,
+            projection: None,
+            query: None,
+            show_deleted: None,
+            sort_order: None,
+            view_type: None,
+            body,
+        }
+    }
     /**
      * This function performs a `GET` to the `/admin/directory/v1/users/{userKey}` endpoint.
      *
@@ -363,6 +930,21 @@ impl Users {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/users/{userKey}` endpoint.
+     *
+     * As opposed to `get`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Retrieves a user.
+     */
+    pub fn get_request<'a>(&'a self, user_key: &'a str) -> UsersGetRequest<'a> {
+        UsersGetRequest {
+            client: self,
+            user_key,
+            projection: None,
+            view_type: None,
+        }
+    }
     /**
      * This function performs a `PUT` to the `/admin/directory/v1/users/{userKey}` endpoint.
      *
@@ -490,6 +1072,20 @@ impl Users {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/users/{userKey}/aliases` endpoint.
+     *
+     * As opposed to `aliases_list`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Lists all aliases for a user.
+     */
+    pub fn aliases_list_request<'a>(&'a self, user_key: &'a str) -> UsersAliasesListRequest<'a> {
+        UsersAliasesListRequest {
+            client: self,
+            user_key,
+            event: None,
+        }
+    }
     /**
      * This function performs a `POST` to the `/admin/directory/v1/users/{userKey}/aliases` endpoint.
      *
@@ -560,6 +1156,25 @@ impl Users {
             )
             .await
     }
+    /**
+     * This function performs a `POST` to the `/admin/directory/v1/users/{userKey}/aliases/watch` endpoint.
+     *
+     * As opposed to `aliases_watch`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Watches for changes in users list.
+     */
+    pub fn aliases_watch_request<'a>(
+        &'a self,
+        user_key: &'a str,
+        body: &'a crate::types::Channel,
+    ) -> UsersAliasesWatchRequest<'a> {
+        UsersAliasesWatchRequest {
+            client: self,
+            user_key,
+            event: None,
+            body,
+        }
+    }
     /**
      * This function performs a `DELETE` to the `/admin/directory/v1/users/{userKey}/aliases/{alias}` endpoint.
      *
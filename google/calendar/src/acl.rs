@@ -1,6 +1,311 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Acl::list`], set with a builder.
+pub struct AclListRequest<'a> {
+    client: &'a Acl,
+    calendar_id: &'a str,
+    max_results: Option<i64>,
+    page_token: Option<&'a str>,
+    show_deleted: Option<bool>,
+}
+
+impl<'a> AclListRequest<'a> {
+    /// Sets the `maxResults` query parameter.
+    pub fn max_results(mut self, max_results: i64) -> Self {
+        self.max_results = Some(max_results);
+        self
+    }
+
+    /// Sets the `pageToken` query parameter.
+    pub fn page_token(mut self, page_token: &'a str) -> Self {
+        self.page_token = Some(page_token);
+        self
+    }
+
+    /// Sets the `showDeleted` query parameter.
+    pub fn show_deleted(mut self, show_deleted: bool) -> Self {
+        self.show_deleted = Some(show_deleted);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::AclRule>>> {
+        let Self {
+            client,
+            calendar_id,
+            max_results,
+            page_token,
+            show_deleted,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = max_results {
+            query_args.push(("maxResults".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_token {
+            query_args.push(("pageToken".to_string(), v.to_string()));
+        }
+        if let Some(v) = show_deleted {
+            query_args.push(("showDeleted".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/calendars/{}/acl?{}",
+                crate::progenitor_support::encode_path(calendar_id),
+                query_
+            ),
+            None,
+        );
+
+        let resp: crate::Response<crate::types::Acl> = client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await?;
+
+        // Return our response data.
+        Ok(crate::Response::new(
+            resp.status,
+            resp.headers,
+            resp.body.items.to_vec(),
+        ))
+    }
+}
+
+/// The parameters of [`Acl::insert`], set with a builder.
+pub struct AclInsertRequest<'a> {
+    client: &'a Acl,
+    calendar_id: &'a str,
+    send_notifications: Option<bool>,
+    body: &'a crate::types::AclRule,
+}
+
+impl<'a> AclInsertRequest<'a> {
+    /// Sets the `sendNotifications` query parameter.
+    pub fn send_notifications(mut self, send_notifications: bool) -> Self {
+        self.send_notifications = Some(send_notifications);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::AclRule>> {
+        let Self {
+            client,
+            calendar_id,
+            send_notifications,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = send_notifications {
+            query_args.push(("sendNotifications".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/calendars/{}/acl?{}",
+                crate::progenitor_support::encode_path(calendar_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Acl::watch`], set with a builder.
+pub struct AclWatchRequest<'a> {
+    client: &'a Acl,
+    calendar_id: &'a str,
+    max_results: Option<i64>,
+    page_token: Option<&'a str>,
+    show_deleted: Option<bool>,
+    body: &'a crate::types::Channel,
+}
+
+impl<'a> AclWatchRequest<'a> {
+    /// Sets the `maxResults` query parameter.
+    pub fn max_results(mut self, max_results: i64) -> Self {
+        self.max_results = Some(max_results);
+        self
+    }
+
+    /// Sets the `pageToken` query parameter.
+    pub fn page_token(mut self, page_token: &'a str) -> Self {
+        self.page_token = Some(page_token);
+        self
+    }
+
+    /// Sets the `showDeleted` query parameter.
+    pub fn show_deleted(mut self, show_deleted: bool) -> Self {
+        self.show_deleted = Some(show_deleted);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::Channel>> {
+        let Self {
+            client,
+            calendar_id,
+            max_results,
+            page_token,
+            show_deleted,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = max_results {
+            query_args.push(("maxResults".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_token {
+            query_args.push(("pageToken".to_string(), v.to_string()));
+        }
+        if let Some(v) = show_deleted {
+            query_args.push(("showDeleted".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/calendars/{}/acl/watch?{}",
+                crate::progenitor_support::encode_path(calendar_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Acl::update`], set with a builder.
+pub struct AclUpdateRequest<'a> {
+    client: &'a Acl,
+    calendar_id: &'a str,
+    rule_id: &'a str,
+    send_notifications: Option<bool>,
+    body: &'a crate::types::AclRule,
+}
+
+impl<'a> AclUpdateRequest<'a> {
+    /// Sets the `sendNotifications` query parameter.
+    pub fn send_notifications(mut self, send_notifications: bool) -> Self {
+        self.send_notifications = Some(send_notifications);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::AclRule>> {
+        let Self {
+            client,
+            calendar_id,
+            rule_id,
+            send_notifications,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = send_notifications {
+            query_args.push(("sendNotifications".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/calendars/{}/acl/{}?{}",
+                crate::progenitor_support::encode_path(calendar_id),
+                crate::progenitor_support::encode_path(rule_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .put(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Acl::patch`], set with a builder.
+pub struct AclPatchRequest<'a> {
+    client: &'a Acl,
+    calendar_id: &'a str,
+    rule_id: &'a str,
+    send_notifications: Option<bool>,
+    body: &'a crate::types::AclRule,
+}
+
+impl<'a> AclPatchRequest<'a> {
+    /// Sets the `sendNotifications` query parameter.
+    pub fn send_notifications(mut self, send_notifications: bool) -> Self {
+        self.send_notifications = Some(send_notifications);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::AclRule>> {
+        let Self {
+            client,
+            calendar_id,
+            rule_id,
+            send_notifications,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = send_notifications {
+            query_args.push(("sendNotifications".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/calendars/{}/acl/{}?{}",
+                crate::progenitor_support::encode_path(calendar_id),
+                crate::progenitor_support::encode_path(rule_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .patch(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
 pub struct Acl {
     pub client: Client,
 }
@@ -71,6 +376,22 @@ impl Acl {
             resp.body.items.to_vec(),
         ))
     }
+    /**
+     * This function performs a `GET` to the `/calendars/{calendarId}/acl` endpoint.
+     *
+     * As opposed to `list`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Returns the rules in the access control list for the calendar.
+     */
+    pub fn list_request<'a>(&'a self, calendar_id: &'a str) -> AclListRequest<'a> {
+        AclListRequest {
+            client: self,
+            calendar_id,
+            max_results: None,
+            page_token: None,
+            show_deleted: None,
+        }
+    }
     /**
      * This function performs a `GET` to the `/calendars/{calendarId}/acl` endpoint.
      *
@@ -96,11 +417,11 @@ impl Acl {
             ),
             None,
         );
-        let crate::Response::<crate::types::Acl> {
-            mut status,
-            mut headers,
-            mut body,
-        } = self
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let mut pages = crate::utils::Pages::new(self.client.page_limits);
+        pages.visit(&url)?;
+
+        let mut response: crate::Response<crate::types::Acl> = self
             .client
             .get(
                 &url,
@@ -110,55 +431,81 @@ impl Acl {
                 },
             )
             .await?;
-
-        let mut items = body.items;
-        let mut page = body.next_page_token;
+        let mut items = Vec::new();
 
         // Paginate if we should.
-        while !page.is_empty() {
-            if !url.contains('?') {
-                crate::Response::<crate::types::Acl> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}?pageToken={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
+        loop {
+            let page = if response.body.next_page_token.is_empty() {
+                None
             } else {
-                crate::Response::<crate::types::Acl> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}&pageToken={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
-
-            items.append(&mut body.items);
-
-            if !body.next_page_token.is_empty() && body.next_page_token != page {
-                page = body.next_page_token.to_string();
-            } else {
-                page = "".to_string();
-            }
+                Some(format!(
+                    "{}{}pageToken={}",
+                    url, sep, response.body.next_page_token
+                ))
+            };
+            let more = pages.keep(&mut response.body.items);
+            items.append(&mut response.body.items);
+
+            let next = match page {
+                Some(next) if more && pages.visit(&next)? => next,
+                _ => break,
+            };
+            response = self
+                .client
+                .get(
+                    &next,
+                    crate::Message {
+                        body: None,
+                        content_type: None,
+                    },
+                )
+                .await?;
         }
 
         // Return our response data.
-        Ok(crate::Response::new(status, headers, items))
+        Ok(crate::Response::new(
+            response.status,
+            response.headers,
+            items,
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/calendars/{calendarId}/acl` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Returns the rules in the access control list for the calendar.
+     */
+    pub fn list_all_stream(
+        &self,
+        calendar_id: &str,
+        show_deleted: bool,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::AclRule>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if show_deleted {
+            query_args.push(("showDeleted".to_string(), show_deleted.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/calendars/{}/acl?{}",
+                crate::progenitor_support::encode_path(calendar_id),
+                query_
+            ),
+            None,
+        );
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let next = format!("{}{}pageToken=", url, sep);
+        self.client
+            .get_pages_stream(url, move |body: crate::types::Acl| {
+                let page = if body.next_page_token.is_empty() {
+                    None
+                } else {
+                    Some(format!("{}{}", next, body.next_page_token))
+                };
+
+                (body.items, page)
+            })
     }
     /**
      * This function performs a `POST` to the `/calendars/{calendarId}/acl` endpoint.
@@ -202,6 +549,25 @@ impl Acl {
             )
             .await
     }
+    /**
+     * This function performs a `POST` to the `/calendars/{calendarId}/acl` endpoint.
+     *
+     * As opposed to `insert`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Creates an access control rule.
+     */
+    pub fn insert_request<'a>(
+        &'a self,
+        calendar_id: &'a str,
+        body: &'a crate::types::AclRule,
+    ) -> AclInsertRequest<'a> {
+        AclInsertRequest {
+            client: self,
+            calendar_id,
+            send_notifications: None,
+            body,
+        }
+    }
     /**
      * This function performs a `POST` to the `/calendars/{calendarId}/acl/watch` endpoint.
      *
@@ -255,6 +621,27 @@ impl Acl {
             )
             .await
     }
+    /**
+     * This function performs a `POST` to the `/calendars/{calendarId}/acl/watch` endpoint.
+     *
+     * As opposed to `watch`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Watch for changes to ACL resources.
+     */
+    pub fn watch_request<'a>(
+        &'a self,
+        calendar_id: &'a str,
+        body: &'a crate::types::Channel,
+    ) -> AclWatchRequest<'a> {
+        AclWatchRequest {
+            client: self,
+            calendar_id,
+            max_results: None,
+            page_token: None,
+            show_deleted: None,
+            body,
+        }
+    }
     /**
      * This function performs a `GET` to the `/calendars/{calendarId}/acl/{ruleId}` endpoint.
      *
@@ -333,6 +720,27 @@ impl Acl {
             )
             .await
     }
+    /**
+     * This function performs a `PUT` to the `/calendars/{calendarId}/acl/{ruleId}` endpoint.
+     *
+     * As opposed to `update`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Updates an access control rule.
+     */
+    pub fn update_request<'a>(
+        &'a self,
+        calendar_id: &'a str,
+        rule_id: &'a str,
+        body: &'a crate::types::AclRule,
+    ) -> AclUpdateRequest<'a> {
+        AclUpdateRequest {
+            client: self,
+            calendar_id,
+            rule_id,
+            send_notifications: None,
+            body,
+        }
+    }
     /**
      * This function performs a `DELETE` to the `/calendars/{calendarId}/acl/{ruleId}` endpoint.
      *
@@ -411,4 +819,25 @@ impl Acl {
             )
             .await
     }
+    /**
+     * This function performs a `PATCH` to the `/calendars/{calendarId}/acl/{ruleId}` endpoint.
+     *
+     * As opposed to `patch`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Updates an access control rule. This method supports patch semantics.
+     */
+    pub fn patch_request<'a>(
+        &'a self,
+        calendar_id: &'a str,
+        rule_id: &'a str,
+        body: &'a crate::types::AclRule,
+    ) -> AclPatchRequest<'a> {
+        AclPatchRequest {
+            client: self,
+            calendar_id,
+            rule_id,
+            send_notifications: None,
+            body,
+        }
+    }
 }
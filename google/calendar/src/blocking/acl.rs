@@ -0,0 +1,276 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Acl::list`], set with a builder.
+pub struct AclListRequest<'a> {
+    inner: crate::acl::AclListRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> AclListRequest<'a> {
+    /// Sets the `maxResults` query parameter.
+    pub fn max_results(mut self, max_results: i64) -> Self {
+        self.inner = self.inner.max_results(max_results);
+        self
+    }
+
+    /// Sets the `pageToken` query parameter.
+    pub fn page_token(mut self, page_token: &'a str) -> Self {
+        self.inner = self.inner.page_token(page_token);
+        self
+    }
+
+    /// Sets the `showDeleted` query parameter.
+    pub fn show_deleted(mut self, show_deleted: bool) -> Self {
+        self.inner = self.inner.show_deleted(show_deleted);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::AclRule>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Acl::insert`], set with a builder.
+pub struct AclInsertRequest<'a> {
+    inner: crate::acl::AclInsertRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> AclInsertRequest<'a> {
+    /// Sets the `sendNotifications` query parameter.
+    pub fn send_notifications(mut self, send_notifications: bool) -> Self {
+        self.inner = self.inner.send_notifications(send_notifications);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::AclRule>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Acl::watch`], set with a builder.
+pub struct AclWatchRequest<'a> {
+    inner: crate::acl::AclWatchRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> AclWatchRequest<'a> {
+    /// Sets the `maxResults` query parameter.
+    pub fn max_results(mut self, max_results: i64) -> Self {
+        self.inner = self.inner.max_results(max_results);
+        self
+    }
+
+    /// Sets the `pageToken` query parameter.
+    pub fn page_token(mut self, page_token: &'a str) -> Self {
+        self.inner = self.inner.page_token(page_token);
+        self
+    }
+
+    /// Sets the `showDeleted` query parameter.
+    pub fn show_deleted(mut self, show_deleted: bool) -> Self {
+        self.inner = self.inner.show_deleted(show_deleted);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::Channel>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Acl::update`], set with a builder.
+pub struct AclUpdateRequest<'a> {
+    inner: crate::acl::AclUpdateRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> AclUpdateRequest<'a> {
+    /// Sets the `sendNotifications` query parameter.
+    pub fn send_notifications(mut self, send_notifications: bool) -> Self {
+        self.inner = self.inner.send_notifications(send_notifications);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::AclRule>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Acl::patch`], set with a builder.
+pub struct AclPatchRequest<'a> {
+    inner: crate::acl::AclPatchRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> AclPatchRequest<'a> {
+    /// Sets the `sendNotifications` query parameter.
+    pub fn send_notifications(mut self, send_notifications: bool) -> Self {
+        self.inner = self.inner.send_notifications(send_notifications);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::AclRule>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::acl::Acl`].
+pub struct Acl {
+    inner: crate::acl::Acl,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Acl {
+    pub(crate) fn new(inner: crate::acl::Acl, runtime: Arc<tokio::runtime::Runtime>) -> Self {
+        Acl { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::acl::Acl::list`].
+    pub fn list(
+        &self,
+        calendar_id: &str,
+        max_results: i64,
+        page_token: &str,
+        show_deleted: bool,
+    ) -> ClientResult<crate::Response<Vec<crate::types::AclRule>>> {
+        self.runtime.block_on(
+            self.inner
+                .list(calendar_id, max_results, page_token, show_deleted),
+        )
+    }
+    /// The blocking version of [`crate::acl::Acl::list_request`].
+    pub fn list_request<'a>(&'a self, calendar_id: &'a str) -> AclListRequest<'a> {
+        AclListRequest {
+            inner: self.inner.list_request(calendar_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::acl::Acl::list_all`].
+    pub fn list_all(
+        &self,
+        calendar_id: &str,
+        show_deleted: bool,
+    ) -> ClientResult<crate::Response<Vec<crate::types::AclRule>>> {
+        self.runtime
+            .block_on(self.inner.list_all(calendar_id, show_deleted))
+    }
+    /// The blocking version of [`crate::acl::Acl::insert`].
+    pub fn insert(
+        &self,
+        calendar_id: &str,
+        send_notifications: bool,
+        body: &crate::types::AclRule,
+    ) -> ClientResult<crate::Response<crate::types::AclRule>> {
+        self.runtime
+            .block_on(self.inner.insert(calendar_id, send_notifications, body))
+    }
+    /// The blocking version of [`crate::acl::Acl::insert_request`].
+    pub fn insert_request<'a>(
+        &'a self,
+        calendar_id: &'a str,
+        body: &'a crate::types::AclRule,
+    ) -> AclInsertRequest<'a> {
+        AclInsertRequest {
+            inner: self.inner.insert_request(calendar_id, body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::acl::Acl::watch`].
+    pub fn watch(
+        &self,
+        calendar_id: &str,
+        max_results: i64,
+        page_token: &str,
+        show_deleted: bool,
+        body: &crate::types::Channel,
+    ) -> ClientResult<crate::Response<crate::types::Channel>> {
+        self.runtime.block_on(self.inner.watch(
+            calendar_id,
+            max_results,
+            page_token,
+            show_deleted,
+            body,
+        ))
+    }
+    /// The blocking version of [`crate::acl::Acl::watch_request`].
+    pub fn watch_request<'a>(
+        &'a self,
+        calendar_id: &'a str,
+        body: &'a crate::types::Channel,
+    ) -> AclWatchRequest<'a> {
+        AclWatchRequest {
+            inner: self.inner.watch_request(calendar_id, body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::acl::Acl::get`].
+    pub fn get(
+        &self,
+        calendar_id: &str,
+        rule_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::AclRule>> {
+        self.runtime.block_on(self.inner.get(calendar_id, rule_id))
+    }
+    /// The blocking version of [`crate::acl::Acl::update`].
+    pub fn update(
+        &self,
+        calendar_id: &str,
+        rule_id: &str,
+        send_notifications: bool,
+        body: &crate::types::AclRule,
+    ) -> ClientResult<crate::Response<crate::types::AclRule>> {
+        self.runtime.block_on(
+            self.inner
+                .update(calendar_id, rule_id, send_notifications, body),
+        )
+    }
+    /// The blocking version of [`crate::acl::Acl::update_request`].
+    pub fn update_request<'a>(
+        &'a self,
+        calendar_id: &'a str,
+        rule_id: &'a str,
+        body: &'a crate::types::AclRule,
+    ) -> AclUpdateRequest<'a> {
+        AclUpdateRequest {
+            inner: self.inner.update_request(calendar_id, rule_id, body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::acl::Acl::delete`].
+    pub fn delete(&self, calendar_id: &str, rule_id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete(calendar_id, rule_id))
+    }
+    /// The blocking version of [`crate::acl::Acl::patch`].
+    pub fn patch(
+        &self,
+        calendar_id: &str,
+        rule_id: &str,
+        send_notifications: bool,
+        body: &crate::types::AclRule,
+    ) -> ClientResult<crate::Response<crate::types::AclRule>> {
+        self.runtime.block_on(
+            self.inner
+                .patch(calendar_id, rule_id, send_notifications, body),
+        )
+    }
+    /// The blocking version of [`crate::acl::Acl::patch_request`].
+    pub fn patch_request<'a>(
+        &'a self,
+        calendar_id: &'a str,
+        rule_id: &'a str,
+        body: &'a crate::types::AclRule,
+    ) -> AclPatchRequest<'a> {
+        AclPatchRequest {
+            inner: self.inner.patch_request(calendar_id, rule_id, body),
+            runtime: &self.runtime,
+        }
+    }
+}
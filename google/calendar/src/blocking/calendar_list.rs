@@ -0,0 +1,299 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`CalendarList::list`], set with a builder.
+pub struct CalendarListListRequest<'a> {
+    inner: crate::calendar_list::CalendarListListRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> CalendarListListRequest<'a> {
+    /// Sets the `maxResults` query parameter.
+    pub fn max_results(mut self, max_results: i64) -> Self {
+        self.inner = self.inner.max_results(max_results);
+        self
+    }
+
+    /// Sets the `minAccessRole` query parameter.
+    pub fn min_access_role(mut self, min_access_role: crate::types::MinAccessRole) -> Self {
+        self.inner = self.inner.min_access_role(min_access_role);
+        self
+    }
+
+    /// Sets the `pageToken` query parameter.
+    pub fn page_token(mut self, page_token: &'a str) -> Self {
+        self.inner = self.inner.page_token(page_token);
+        self
+    }
+
+    /// Sets the `showDeleted` query parameter.
+    pub fn show_deleted(mut self, show_deleted: bool) -> Self {
+        self.inner = self.inner.show_deleted(show_deleted);
+        self
+    }
+
+    /// Sets the `showHidden` query parameter.
+    pub fn show_hidden(mut self, show_hidden: bool) -> Self {
+        self.inner = self.inner.show_hidden(show_hidden);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::CalendarListEntry>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`CalendarList::list_insert`], set with a builder.
+pub struct CalendarListListInsertRequest<'a> {
+    inner: crate::calendar_list::CalendarListListInsertRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> CalendarListListInsertRequest<'a> {
+    /// Sets the `colorRgbFormat` query parameter.
+    pub fn color_rgb_format(mut self, color_rgb_format: bool) -> Self {
+        self.inner = self.inner.color_rgb_format(color_rgb_format);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::CalendarListEntry>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`CalendarList::list_watch`], set with a builder.
+pub struct CalendarListListWatchRequest<'a> {
+    inner: crate::calendar_list::CalendarListListWatchRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> CalendarListListWatchRequest<'a> {
+    /// Sets the `maxResults` query parameter.
+    pub fn max_results(mut self, max_results: i64) -> Self {
+        self.inner = self.inner.max_results(max_results);
+        self
+    }
+
+    /// Sets the `minAccessRole` query parameter.
+    pub fn min_access_role(mut self, min_access_role: crate::types::MinAccessRole) -> Self {
+        self.inner = self.inner.min_access_role(min_access_role);
+        self
+    }
+
+    /// Sets the `pageToken` query parameter.
+    pub fn page_token(mut self, page_token: &'a str) -> Self {
+        self.inner = self.inner.page_token(page_token);
+        self
+    }
+
+    /// Sets the `showDeleted` query parameter.
+    pub fn show_deleted(mut self, show_deleted: bool) -> Self {
+        self.inner = self.inner.show_deleted(show_deleted);
+        self
+    }
+
+    /// Sets the `showHidden` query parameter.
+    pub fn show_hidden(mut self, show_hidden: bool) -> Self {
+        self.inner = self.inner.show_hidden(show_hidden);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::Channel>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`CalendarList::list_update`], set with a builder.
+pub struct CalendarListListUpdateRequest<'a> {
+    inner: crate::calendar_list::CalendarListListUpdateRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> CalendarListListUpdateRequest<'a> {
+    /// Sets the `colorRgbFormat` query parameter.
+    pub fn color_rgb_format(mut self, color_rgb_format: bool) -> Self {
+        self.inner = self.inner.color_rgb_format(color_rgb_format);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::CalendarListEntry>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`CalendarList::list_patch`], set with a builder.
+pub struct CalendarListListPatchRequest<'a> {
+    inner: crate::calendar_list::CalendarListListPatchRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> CalendarListListPatchRequest<'a> {
+    /// Sets the `colorRgbFormat` query parameter.
+    pub fn color_rgb_format(mut self, color_rgb_format: bool) -> Self {
+        self.inner = self.inner.color_rgb_format(color_rgb_format);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::CalendarListEntry>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::calendar_list::CalendarList`].
+pub struct CalendarList {
+    inner: crate::calendar_list::CalendarList,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl CalendarList {
+    pub(crate) fn new(
+        inner: crate::calendar_list::CalendarList,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        CalendarList { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::calendar_list::CalendarList::list`].
+    pub fn list(
+        &self,
+        max_results: i64,
+        min_access_role: crate::types::MinAccessRole,
+        page_token: &str,
+        show_deleted: bool,
+        show_hidden: bool,
+    ) -> ClientResult<crate::Response<Vec<crate::types::CalendarListEntry>>> {
+        self.runtime.block_on(self.inner.list(
+            max_results,
+            min_access_role,
+            page_token,
+            show_deleted,
+            show_hidden,
+        ))
+    }
+    /// The blocking version of [`crate::calendar_list::CalendarList::list_request`].
+    pub fn list_request(&self) -> CalendarListListRequest<'_> {
+        CalendarListListRequest {
+            inner: self.inner.list_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::calendar_list::CalendarList::list_all`].
+    pub fn list_all(
+        &self,
+        min_access_role: crate::types::MinAccessRole,
+        show_deleted: bool,
+        show_hidden: bool,
+    ) -> ClientResult<crate::Response<Vec<crate::types::CalendarListEntry>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_all(min_access_role, show_deleted, show_hidden),
+        )
+    }
+    /// The blocking version of [`crate::calendar_list::CalendarList::list_insert`].
+    pub fn list_insert(
+        &self,
+        color_rgb_format: bool,
+        body: &crate::types::CalendarListEntry,
+    ) -> ClientResult<crate::Response<crate::types::CalendarListEntry>> {
+        self.runtime
+            .block_on(self.inner.list_insert(color_rgb_format, body))
+    }
+    /// The blocking version of [`crate::calendar_list::CalendarList::list_insert_request`].
+    pub fn list_insert_request<'a>(
+        &'a self,
+        body: &'a crate::types::CalendarListEntry,
+    ) -> CalendarListListInsertRequest<'a> {
+        CalendarListListInsertRequest {
+            inner: self.inner.list_insert_request(body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::calendar_list::CalendarList::list_watch`].
+    pub fn list_watch(
+        &self,
+        max_results: i64,
+        min_access_role: crate::types::MinAccessRole,
+        page_token: &str,
+        show_deleted: bool,
+        show_hidden: bool,
+        body: &crate::types::Channel,
+    ) -> ClientResult<crate::Response<crate::types::Channel>> {
+        self.runtime.block_on(self.inner.list_watch(
+            max_results,
+            min_access_role,
+            page_token,
+            show_deleted,
+            show_hidden,
+            body,
+        ))
+    }
+    /// The blocking version of [`crate::calendar_list::CalendarList::list_watch_request`].
+    pub fn list_watch_request<'a>(
+        &'a self,
+        body: &'a crate::types::Channel,
+    ) -> CalendarListListWatchRequest<'a> {
+        CalendarListListWatchRequest {
+            inner: self.inner.list_watch_request(body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::calendar_list::CalendarList::list_get`].
+    pub fn list_get(
+        &self,
+        calendar_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::CalendarListEntry>> {
+        self.runtime.block_on(self.inner.list_get(calendar_id))
+    }
+    /// The blocking version of [`crate::calendar_list::CalendarList::list_update`].
+    pub fn list_update(
+        &self,
+        calendar_id: &str,
+        color_rgb_format: bool,
+        body: &crate::types::CalendarListEntry,
+    ) -> ClientResult<crate::Response<crate::types::CalendarListEntry>> {
+        self.runtime
+            .block_on(self.inner.list_update(calendar_id, color_rgb_format, body))
+    }
+    /// The blocking version of [`crate::calendar_list::CalendarList::list_update_request`].
+    pub fn list_update_request<'a>(
+        &'a self,
+        calendar_id: &'a str,
+        body: &'a crate::types::CalendarListEntry,
+    ) -> CalendarListListUpdateRequest<'a> {
+        CalendarListListUpdateRequest {
+            inner: self.inner.list_update_request(calendar_id, body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::calendar_list::CalendarList::list_delete`].
+    pub fn list_delete(&self, calendar_id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.list_delete(calendar_id))
+    }
+    /// The blocking version of [`crate::calendar_list::CalendarList::list_patch`].
+    pub fn list_patch(
+        &self,
+        calendar_id: &str,
+        color_rgb_format: bool,
+        body: &crate::types::CalendarListEntry,
+    ) -> ClientResult<crate::Response<crate::types::CalendarListEntry>> {
+        self.runtime
+            .block_on(self.inner.list_patch(calendar_id, color_rgb_format, body))
+    }
+    /// The blocking version of [`crate::calendar_list::CalendarList::list_patch_request`].
+    pub fn list_patch_request<'a>(
+        &'a self,
+        calendar_id: &'a str,
+        body: &'a crate::types::CalendarListEntry,
+    ) -> CalendarListListPatchRequest<'a> {
+        CalendarListListPatchRequest {
+            inner: self.inner.list_patch_request(calendar_id, body),
+            runtime: &self.runtime,
+        }
+    }
+}
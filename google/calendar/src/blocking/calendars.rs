@@ -0,0 +1,54 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::calendars::Calendars`].
+pub struct Calendars {
+    inner: crate::calendars::Calendars,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Calendars {
+    pub(crate) fn new(
+        inner: crate::calendars::Calendars,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Calendars { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::calendars::Calendars::insert`].
+    pub fn insert(
+        &self,
+        body: &crate::types::Calendar,
+    ) -> ClientResult<crate::Response<crate::types::Calendar>> {
+        self.runtime.block_on(self.inner.insert(body))
+    }
+    /// The blocking version of [`crate::calendars::Calendars::get`].
+    pub fn get(&self, calendar_id: &str) -> ClientResult<crate::Response<crate::types::Calendar>> {
+        self.runtime.block_on(self.inner.get(calendar_id))
+    }
+    /// The blocking version of [`crate::calendars::Calendars::update`].
+    pub fn update(
+        &self,
+        calendar_id: &str,
+        body: &crate::types::Calendar,
+    ) -> ClientResult<crate::Response<crate::types::Calendar>> {
+        self.runtime.block_on(self.inner.update(calendar_id, body))
+    }
+    /// The blocking version of [`crate::calendars::Calendars::delete`].
+    pub fn delete(&self, calendar_id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.delete(calendar_id))
+    }
+    /// The blocking version of [`crate::calendars::Calendars::patch`].
+    pub fn patch(
+        &self,
+        calendar_id: &str,
+        body: &crate::types::Calendar,
+    ) -> ClientResult<crate::Response<crate::types::Calendar>> {
+        self.runtime.block_on(self.inner.patch(calendar_id, body))
+    }
+    /// The blocking version of [`crate::calendars::Calendars::clear`].
+    pub fn clear(&self, calendar_id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.clear(calendar_id))
+    }
+}
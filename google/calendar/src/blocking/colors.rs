@@ -0,0 +1,20 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::colors::Colors`].
+pub struct Colors {
+    inner: crate::colors::Colors,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Colors {
+    pub(crate) fn new(inner: crate::colors::Colors, runtime: Arc<tokio::runtime::Runtime>) -> Self {
+        Colors { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::colors::Colors::get`].
+    pub fn get(&self) -> ClientResult<crate::Response<crate::types::Colors>> {
+        self.runtime.block_on(self.inner.get())
+    }
+}
@@ -0,0 +1,972 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Events::list`], set with a builder.
+pub struct EventsListRequest<'a> {
+    inner: crate::events::EventsListRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> EventsListRequest<'a> {
+    /// Sets the `iCalUID` query parameter.
+    pub fn i_cal_uid(mut self, i_cal_uid: &'a str) -> Self {
+        self.inner = self.inner.i_cal_uid(i_cal_uid);
+        self
+    }
+
+    /// Sets the `maxAttendees` query parameter.
+    pub fn max_attendees(mut self, max_attendees: i64) -> Self {
+        self.inner = self.inner.max_attendees(max_attendees);
+        self
+    }
+
+    /// Sets the `maxResults` query parameter.
+    pub fn max_results(mut self, max_results: i64) -> Self {
+        self.inner = self.inner.max_results(max_results);
+        self
+    }
+
+    /// Sets the `orderBy` query parameter.
+    pub fn order_by(mut self, order_by: crate::types::OrderBy) -> Self {
+        self.inner = self.inner.order_by(order_by);
+        self
+    }
+
+    /// Sets the `pageToken` query parameter.
+    pub fn page_token(mut self, page_token: &'a str) -> Self {
+        self.inner = self.inner.page_token(page_token);
+        self
+    }
+
+    /// Sets the `privateExtendedProperty` query parameter.
+    pub fn private_extended_property(mut self, private_extended_property: &'a [String]) -> Self {
+        self.inner = self
+            .inner
+            .private_extended_property(private_extended_property);
+        self
+    }
+
+    /// Sets the `q` query parameter.
+    pub fn q(mut self, q: &'a str) -> Self {
+        self.inner = self.inner.q(q);
+        self
+    }
+
+    /// Sets the `sharedExtendedProperty` query parameter.
+    pub fn shared_extended_property(mut self, shared_extended_property: &'a [String]) -> Self {
+        self.inner = self
+            .inner
+            .shared_extended_property(shared_extended_property);
+        self
+    }
+
+    /// Sets the `showDeleted` query parameter.
+    pub fn show_deleted(mut self, show_deleted: bool) -> Self {
+        self.inner = self.inner.show_deleted(show_deleted);
+        self
+    }
+
+    /// Sets the `showHiddenInvitations` query parameter.
+    pub fn show_hidden_invitations(mut self, show_hidden_invitations: bool) -> Self {
+        self.inner = self.inner.show_hidden_invitations(show_hidden_invitations);
+        self
+    }
+
+    /// Sets the `singleEvents` query parameter.
+    pub fn single_events(mut self, single_events: bool) -> Self {
+        self.inner = self.inner.single_events(single_events);
+        self
+    }
+
+    /// Sets the `timeMax` query parameter.
+    pub fn time_max(mut self, time_max: &'a str) -> Self {
+        self.inner = self.inner.time_max(time_max);
+        self
+    }
+
+    /// Sets the `timeMin` query parameter.
+    pub fn time_min(mut self, time_min: &'a str) -> Self {
+        self.inner = self.inner.time_min(time_min);
+        self
+    }
+
+    /// Sets the `timeZone` query parameter.
+    pub fn time_zone(mut self, time_zone: &'a str) -> Self {
+        self.inner = self.inner.time_zone(time_zone);
+        self
+    }
+
+    /// Sets the `updatedMin` query parameter.
+    pub fn updated_min(mut self, updated_min: &'a str) -> Self {
+        self.inner = self.inner.updated_min(updated_min);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Event>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Events::insert`], set with a builder.
+pub struct EventsInsertRequest<'a> {
+    inner: crate::events::EventsInsertRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> EventsInsertRequest<'a> {
+    /// Sets the `conferenceDataVersion` query parameter.
+    pub fn conference_data_version(mut self, conference_data_version: u64) -> Self {
+        self.inner = self.inner.conference_data_version(conference_data_version);
+        self
+    }
+
+    /// Sets the `maxAttendees` query parameter.
+    pub fn max_attendees(mut self, max_attendees: i64) -> Self {
+        self.inner = self.inner.max_attendees(max_attendees);
+        self
+    }
+
+    /// Sets the `sendNotifications` query parameter.
+    pub fn send_notifications(mut self, send_notifications: bool) -> Self {
+        self.inner = self.inner.send_notifications(send_notifications);
+        self
+    }
+
+    /// Sets the `sendUpdates` query parameter.
+    pub fn send_updates(mut self, send_updates: crate::types::SendUpdates) -> Self {
+        self.inner = self.inner.send_updates(send_updates);
+        self
+    }
+
+    /// Sets the `supportsAttachments` query parameter.
+    pub fn supports_attachments(mut self, supports_attachments: bool) -> Self {
+        self.inner = self.inner.supports_attachments(supports_attachments);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::Event>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Events::import`], set with a builder.
+pub struct EventsImportRequest<'a> {
+    inner: crate::events::EventsImportRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> EventsImportRequest<'a> {
+    /// Sets the `conferenceDataVersion` query parameter.
+    pub fn conference_data_version(mut self, conference_data_version: u64) -> Self {
+        self.inner = self.inner.conference_data_version(conference_data_version);
+        self
+    }
+
+    /// Sets the `supportsAttachments` query parameter.
+    pub fn supports_attachments(mut self, supports_attachments: bool) -> Self {
+        self.inner = self.inner.supports_attachments(supports_attachments);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::Event>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Events::quick_add`], set with a builder.
+pub struct EventsQuickAddRequest<'a> {
+    inner: crate::events::EventsQuickAddRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> EventsQuickAddRequest<'a> {
+    /// Sets the `sendNotifications` query parameter.
+    pub fn send_notifications(mut self, send_notifications: bool) -> Self {
+        self.inner = self.inner.send_notifications(send_notifications);
+        self
+    }
+
+    /// Sets the `sendUpdates` query parameter.
+    pub fn send_updates(mut self, send_updates: crate::types::SendUpdates) -> Self {
+        self.inner = self.inner.send_updates(send_updates);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::Event>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Events::watch`], set with a builder.
+pub struct EventsWatchRequest<'a> {
+    inner: crate::events::EventsWatchRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> EventsWatchRequest<'a> {
+    /// Sets the `iCalUID` query parameter.
+    pub fn i_cal_uid(mut self, i_cal_uid: &'a str) -> Self {
+        self.inner = self.inner.i_cal_uid(i_cal_uid);
+        self
+    }
+
+    /// Sets the `maxAttendees` query parameter.
+    pub fn max_attendees(mut self, max_attendees: i64) -> Self {
+        self.inner = self.inner.max_attendees(max_attendees);
+        self
+    }
+
+    /// Sets the `maxResults` query parameter.
+    pub fn max_results(mut self, max_results: i64) -> Self {
+        self.inner = self.inner.max_results(max_results);
+        self
+    }
+
+    /// Sets the `orderBy` query parameter.
+    pub fn order_by(mut self, order_by: crate::types::OrderBy) -> Self {
+        self.inner = self.inner.order_by(order_by);
+        self
+    }
+
+    /// Sets the `pageToken` query parameter.
+    pub fn page_token(mut self, page_token: &'a str) -> Self {
+        self.inner = self.inner.page_token(page_token);
+        self
+    }
+
+    /// Sets the `privateExtendedProperty` query parameter.
+    pub fn private_extended_property(mut self, private_extended_property: &'a [String]) -> Self {
+        self.inner = self
+            .inner
+            .private_extended_property(private_extended_property);
+        self
+    }
+
+    /// Sets the `q` query parameter.
+    pub fn q(mut self, q: &'a str) -> Self {
+        self.inner = self.inner.q(q);
+        self
+    }
+
+    /// Sets the `sharedExtendedProperty` query parameter.
+    pub fn shared_extended_property(mut self, shared_extended_property: &'a [String]) -> Self {
+        self.inner = self
+            .inner
+            .shared_extended_property(shared_extended_property);
+        self
+    }
+
+    /// Sets the `showDeleted` query parameter.
+    pub fn show_deleted(mut self, show_deleted: bool) -> Self {
+        self.inner = self.inner.show_deleted(show_deleted);
+        self
+    }
+
+    /// Sets the `showHiddenInvitations` query parameter.
+    pub fn show_hidden_invitations(mut self, show_hidden_invitations: bool) -> Self {
+        self.inner = self.inner.show_hidden_invitations(show_hidden_invitations);
+        self
+    }
+
+    /// Sets the `singleEvents` query parameter.
+    pub fn single_events(mut self, single_events: bool) -> Self {
+        self.inner = self.inner.single_events(single_events);
+        self
+    }
+
+    /// Sets the `timeMax` query parameter.
+    pub fn time_max(mut self, time_max: &'a str) -> Self {
+        self.inner = self.inner.time_max(time_max);
+        self
+    }
+
+    /// Sets the `timeMin` query parameter.
+    pub fn time_min(mut self, time_min: &'a str) -> Self {
+        self.inner = self.inner.time_min(time_min);
+        self
+    }
+
+    /// Sets the `timeZone` query parameter.
+    pub fn time_zone(mut self, time_zone: &'a str) -> Self {
+        self.inner = self.inner.time_zone(time_zone);
+        self
+    }
+
+    /// Sets the `updatedMin` query parameter.
+    pub fn updated_min(mut self, updated_min: &'a str) -> Self {
+        self.inner = self.inner.updated_min(updated_min);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::Channel>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Events::get`], set with a builder.
+pub struct EventsGetRequest<'a> {
+    inner: crate::events::EventsGetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> EventsGetRequest<'a> {
+    /// Sets the `maxAttendees` query parameter.
+    pub fn max_attendees(mut self, max_attendees: i64) -> Self {
+        self.inner = self.inner.max_attendees(max_attendees);
+        self
+    }
+
+    /// Sets the `timeZone` query parameter.
+    pub fn time_zone(mut self, time_zone: &'a str) -> Self {
+        self.inner = self.inner.time_zone(time_zone);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::Event>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Events::update`], set with a builder.
+pub struct EventsUpdateRequest<'a> {
+    inner: crate::events::EventsUpdateRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> EventsUpdateRequest<'a> {
+    /// Sets the `conferenceDataVersion` query parameter.
+    pub fn conference_data_version(mut self, conference_data_version: u64) -> Self {
+        self.inner = self.inner.conference_data_version(conference_data_version);
+        self
+    }
+
+    /// Sets the `maxAttendees` query parameter.
+    pub fn max_attendees(mut self, max_attendees: i64) -> Self {
+        self.inner = self.inner.max_attendees(max_attendees);
+        self
+    }
+
+    /// Sets the `sendNotifications` query parameter.
+    pub fn send_notifications(mut self, send_notifications: bool) -> Self {
+        self.inner = self.inner.send_notifications(send_notifications);
+        self
+    }
+
+    /// Sets the `sendUpdates` query parameter.
+    pub fn send_updates(mut self, send_updates: crate::types::SendUpdates) -> Self {
+        self.inner = self.inner.send_updates(send_updates);
+        self
+    }
+
+    /// Sets the `supportsAttachments` query parameter.
+    pub fn supports_attachments(mut self, supports_attachments: bool) -> Self {
+        self.inner = self.inner.supports_attachments(supports_attachments);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::Event>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Events::delete`], set with a builder.
+pub struct EventsDeleteRequest<'a> {
+    inner: crate::events::EventsDeleteRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> EventsDeleteRequest<'a> {
+    /// Sets the `sendNotifications` query parameter.
+    pub fn send_notifications(mut self, send_notifications: bool) -> Self {
+        self.inner = self.inner.send_notifications(send_notifications);
+        self
+    }
+
+    /// Sets the `sendUpdates` query parameter.
+    pub fn send_updates(mut self, send_updates: crate::types::SendUpdates) -> Self {
+        self.inner = self.inner.send_updates(send_updates);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Events::patch`], set with a builder.
+pub struct EventsPatchRequest<'a> {
+    inner: crate::events::EventsPatchRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> EventsPatchRequest<'a> {
+    /// Sets the `conferenceDataVersion` query parameter.
+    pub fn conference_data_version(mut self, conference_data_version: u64) -> Self {
+        self.inner = self.inner.conference_data_version(conference_data_version);
+        self
+    }
+
+    /// Sets the `maxAttendees` query parameter.
+    pub fn max_attendees(mut self, max_attendees: i64) -> Self {
+        self.inner = self.inner.max_attendees(max_attendees);
+        self
+    }
+
+    /// Sets the `sendNotifications` query parameter.
+    pub fn send_notifications(mut self, send_notifications: bool) -> Self {
+        self.inner = self.inner.send_notifications(send_notifications);
+        self
+    }
+
+    /// Sets the `sendUpdates` query parameter.
+    pub fn send_updates(mut self, send_updates: crate::types::SendUpdates) -> Self {
+        self.inner = self.inner.send_updates(send_updates);
+        self
+    }
+
+    /// Sets the `supportsAttachments` query parameter.
+    pub fn supports_attachments(mut self, supports_attachments: bool) -> Self {
+        self.inner = self.inner.supports_attachments(supports_attachments);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::Event>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Events::instances`], set with a builder.
+pub struct EventsInstancesRequest<'a> {
+    inner: crate::events::EventsInstancesRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> EventsInstancesRequest<'a> {
+    /// Sets the `maxAttendees` query parameter.
+    pub fn max_attendees(mut self, max_attendees: i64) -> Self {
+        self.inner = self.inner.max_attendees(max_attendees);
+        self
+    }
+
+    /// Sets the `maxResults` query parameter.
+    pub fn max_results(mut self, max_results: i64) -> Self {
+        self.inner = self.inner.max_results(max_results);
+        self
+    }
+
+    /// Sets the `originalStart` query parameter.
+    pub fn original_start(mut self, original_start: &'a str) -> Self {
+        self.inner = self.inner.original_start(original_start);
+        self
+    }
+
+    /// Sets the `pageToken` query parameter.
+    pub fn page_token(mut self, page_token: &'a str) -> Self {
+        self.inner = self.inner.page_token(page_token);
+        self
+    }
+
+    /// Sets the `showDeleted` query parameter.
+    pub fn show_deleted(mut self, show_deleted: bool) -> Self {
+        self.inner = self.inner.show_deleted(show_deleted);
+        self
+    }
+
+    /// Sets the `timeMax` query parameter.
+    pub fn time_max(mut self, time_max: &'a str) -> Self {
+        self.inner = self.inner.time_max(time_max);
+        self
+    }
+
+    /// Sets the `timeMin` query parameter.
+    pub fn time_min(mut self, time_min: &'a str) -> Self {
+        self.inner = self.inner.time_min(time_min);
+        self
+    }
+
+    /// Sets the `timeZone` query parameter.
+    pub fn time_zone(mut self, time_zone: &'a str) -> Self {
+        self.inner = self.inner.time_zone(time_zone);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Event>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Events::mv`], set with a builder.
+pub struct EventsMvRequest<'a> {
+    inner: crate::events::EventsMvRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> EventsMvRequest<'a> {
+    /// Sets the `sendNotifications` query parameter.
+    pub fn send_notifications(mut self, send_notifications: bool) -> Self {
+        self.inner = self.inner.send_notifications(send_notifications);
+        self
+    }
+
+    /// Sets the `sendUpdates` query parameter.
+    pub fn send_updates(mut self, send_updates: crate::types::SendUpdates) -> Self {
+        self.inner = self.inner.send_updates(send_updates);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::Event>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::events::Events`].
+pub struct Events {
+    inner: crate::events::Events,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Events {
+    pub(crate) fn new(inner: crate::events::Events, runtime: Arc<tokio::runtime::Runtime>) -> Self {
+        Events { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::events::Events::list`].
+    pub fn list(
+        &self,
+        calendar_id: &str,
+        i_cal_uid: &str,
+        max_attendees: i64,
+        max_results: i64,
+        order_by: crate::types::OrderBy,
+        page_token: &str,
+        private_extended_property: &[String],
+        q: &str,
+        shared_extended_property: &[String],
+        show_deleted: bool,
+        show_hidden_invitations: bool,
+        single_events: bool,
+        time_max: &str,
+        time_min: &str,
+        time_zone: &str,
+        updated_min: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Event>>> {
+        self.runtime.block_on(self.inner.list(
+            calendar_id,
+            i_cal_uid,
+            max_attendees,
+            max_results,
+            order_by,
+            page_token,
+            private_extended_property,
+            q,
+            shared_extended_property,
+            show_deleted,
+            show_hidden_invitations,
+            single_events,
+            time_max,
+            time_min,
+            time_zone,
+            updated_min,
+        ))
+    }
+    /// The blocking version of [`crate::events::Events::list_request`].
+    pub fn list_request<'a>(&'a self, calendar_id: &'a str) -> EventsListRequest<'a> {
+        EventsListRequest {
+            inner: self.inner.list_request(calendar_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::events::Events::list_all`].
+    pub fn list_all(
+        &self,
+        calendar_id: &str,
+        i_cal_uid: &str,
+        max_attendees: i64,
+        order_by: crate::types::OrderBy,
+        private_extended_property: &[String],
+        q: &str,
+        shared_extended_property: &[String],
+        show_deleted: bool,
+        show_hidden_invitations: bool,
+        single_events: bool,
+        time_max: &str,
+        time_min: &str,
+        time_zone: &str,
+        updated_min: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Event>>> {
+        self.runtime.block_on(self.inner.list_all(
+            calendar_id,
+            i_cal_uid,
+            max_attendees,
+            order_by,
+            private_extended_property,
+            q,
+            shared_extended_property,
+            show_deleted,
+            show_hidden_invitations,
+            single_events,
+            time_max,
+            time_min,
+            time_zone,
+            updated_min,
+        ))
+    }
+    /// The blocking version of [`crate::events::Events::insert`].
+    pub fn insert(
+        &self,
+        calendar_id: &str,
+        conference_data_version: u64,
+        max_attendees: i64,
+        send_notifications: bool,
+        send_updates: crate::types::SendUpdates,
+        supports_attachments: bool,
+        body: &crate::types::Event,
+    ) -> ClientResult<crate::Response<crate::types::Event>> {
+        self.runtime.block_on(self.inner.insert(
+            calendar_id,
+            conference_data_version,
+            max_attendees,
+            send_notifications,
+            send_updates,
+            supports_attachments,
+            body,
+        ))
+    }
+    /// The blocking version of [`crate::events::Events::insert_request`].
+    pub fn insert_request<'a>(
+        &'a self,
+        calendar_id: &'a str,
+        body: &'a crate::types::Event,
+    ) -> EventsInsertRequest<'a> {
+        EventsInsertRequest {
+            inner: self.inner.insert_request(calendar_id, body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::events::Events::import`].
+    pub fn import(
+        &self,
+        calendar_id: &str,
+        conference_data_version: u64,
+        supports_attachments: bool,
+        body: &crate::types::Event,
+    ) -> ClientResult<crate::Response<crate::types::Event>> {
+        self.runtime.block_on(self.inner.import(
+            calendar_id,
+            conference_data_version,
+            supports_attachments,
+            body,
+        ))
+    }
+    /// The blocking version of [`crate::events::Events::import_request`].
+    pub fn import_request<'a>(
+        &'a self,
+        calendar_id: &'a str,
+        body: &'a crate::types::Event,
+    ) -> EventsImportRequest<'a> {
+        EventsImportRequest {
+            inner: self.inner.import_request(calendar_id, body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::events::Events::quick_add`].
+    pub fn quick_add(
+        &self,
+        calendar_id: &str,
+        text: &str,
+        send_notifications: bool,
+        send_updates: crate::types::SendUpdates,
+    ) -> ClientResult<crate::Response<crate::types::Event>> {
+        self.runtime.block_on(self.inner.quick_add(
+            calendar_id,
+            text,
+            send_notifications,
+            send_updates,
+        ))
+    }
+    /// The blocking version of [`crate::events::Events::quick_add_request`].
+    pub fn quick_add_request<'a>(
+        &'a self,
+        calendar_id: &'a str,
+        text: &'a str,
+    ) -> EventsQuickAddRequest<'a> {
+        EventsQuickAddRequest {
+            inner: self.inner.quick_add_request(calendar_id, text),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::events::Events::watch`].
+    pub fn watch(
+        &self,
+        calendar_id: &str,
+        i_cal_uid: &str,
+        max_attendees: i64,
+        max_results: i64,
+        order_by: crate::types::OrderBy,
+        page_token: &str,
+        private_extended_property: &[String],
+        q: &str,
+        shared_extended_property: &[String],
+        show_deleted: bool,
+        show_hidden_invitations: bool,
+        single_events: bool,
+        time_max: &str,
+        time_min: &str,
+        time_zone: &str,
+        updated_min: &str,
+        body: &crate::types::Channel,
+    ) -> ClientResult<crate::Response<crate::types::Channel>> {
+        self.runtime.block_on(self.inner.watch(
+            calendar_id,
+            i_cal_uid,
+            max_attendees,
+            max_results,
+            order_by,
+            page_token,
+            private_extended_property,
+            q,
+            shared_extended_property,
+            show_deleted,
+            show_hidden_invitations,
+            single_events,
+            time_max,
+            time_min,
+            time_zone,
+            updated_min,
+            body,
+        ))
+    }
+    /// The blocking version of [`crate::events::Events::watch_request`].
+    pub fn watch_request<'a>(
+        &'a self,
+        calendar_id: &'a str,
+        body: &'a crate::types::Channel,
+    ) -> EventsWatchRequest<'a> {
+        EventsWatchRequest {
+            inner: self.inner.watch_request(calendar_id, body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::events::Events::get`].
+    pub fn get(
+        &self,
+        calendar_id: &str,
+        event_id: &str,
+        max_attendees: i64,
+        time_zone: &str,
+    ) -> ClientResult<crate::Response<crate::types::Event>> {
+        self.runtime.block_on(
+            self.inner
+                .get(calendar_id, event_id, max_attendees, time_zone),
+        )
+    }
+    /// The blocking version of [`crate::events::Events::get_request`].
+    pub fn get_request<'a>(
+        &'a self,
+        calendar_id: &'a str,
+        event_id: &'a str,
+    ) -> EventsGetRequest<'a> {
+        EventsGetRequest {
+            inner: self.inner.get_request(calendar_id, event_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::events::Events::update`].
+    pub fn update(
+        &self,
+        calendar_id: &str,
+        event_id: &str,
+        conference_data_version: u64,
+        max_attendees: i64,
+        send_notifications: bool,
+        send_updates: crate::types::SendUpdates,
+        supports_attachments: bool,
+        body: &crate::types::Event,
+    ) -> ClientResult<crate::Response<crate::types::Event>> {
+        self.runtime.block_on(self.inner.update(
+            calendar_id,
+            event_id,
+            conference_data_version,
+            max_attendees,
+            send_notifications,
+            send_updates,
+            supports_attachments,
+            body,
+        ))
+    }
+    /// The blocking version of [`crate::events::Events::update_request`].
+    pub fn update_request<'a>(
+        &'a self,
+        calendar_id: &'a str,
+        event_id: &'a str,
+        body: &'a crate::types::Event,
+    ) -> EventsUpdateRequest<'a> {
+        EventsUpdateRequest {
+            inner: self.inner.update_request(calendar_id, event_id, body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::events::Events::delete`].
+    pub fn delete(
+        &self,
+        calendar_id: &str,
+        event_id: &str,
+        send_notifications: bool,
+        send_updates: crate::types::SendUpdates,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.delete(
+            calendar_id,
+            event_id,
+            send_notifications,
+            send_updates,
+        ))
+    }
+    /// The blocking version of [`crate::events::Events::delete_request`].
+    pub fn delete_request<'a>(
+        &'a self,
+        calendar_id: &'a str,
+        event_id: &'a str,
+    ) -> EventsDeleteRequest<'a> {
+        EventsDeleteRequest {
+            inner: self.inner.delete_request(calendar_id, event_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::events::Events::patch`].
+    pub fn patch(
+        &self,
+        calendar_id: &str,
+        event_id: &str,
+        conference_data_version: u64,
+        max_attendees: i64,
+        send_notifications: bool,
+        send_updates: crate::types::SendUpdates,
+        supports_attachments: bool,
+        body: &crate::types::Event,
+    ) -> ClientResult<crate::Response<crate::types::Event>> {
+        self.runtime.block_on(self.inner.patch(
+            calendar_id,
+            event_id,
+            conference_data_version,
+            max_attendees,
+            send_notifications,
+            send_updates,
+            supports_attachments,
+            body,
+        ))
+    }
+    /// The blocking version of [`crate::events::Events::patch_request`].
+    pub fn patch_request<'a>(
+        &'a self,
+        calendar_id: &'a str,
+        event_id: &'a str,
+        body: &'a crate::types::Event,
+    ) -> EventsPatchRequest<'a> {
+        EventsPatchRequest {
+            inner: self.inner.patch_request(calendar_id, event_id, body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::events::Events::instances`].
+    pub fn instances(
+        &self,
+        calendar_id: &str,
+        event_id: &str,
+        max_attendees: i64,
+        max_results: i64,
+        original_start: &str,
+        page_token: &str,
+        show_deleted: bool,
+        time_max: &str,
+        time_min: &str,
+        time_zone: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Event>>> {
+        self.runtime.block_on(self.inner.instances(
+            calendar_id,
+            event_id,
+            max_attendees,
+            max_results,
+            original_start,
+            page_token,
+            show_deleted,
+            time_max,
+            time_min,
+            time_zone,
+        ))
+    }
+    /// The blocking version of [`crate::events::Events::instances_request`].
+    pub fn instances_request<'a>(
+        &'a self,
+        calendar_id: &'a str,
+        event_id: &'a str,
+    ) -> EventsInstancesRequest<'a> {
+        EventsInstancesRequest {
+            inner: self.inner.instances_request(calendar_id, event_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::events::Events::get_all_instances`].
+    pub fn get_all_instances(
+        &self,
+        calendar_id: &str,
+        event_id: &str,
+        max_attendees: i64,
+        original_start: &str,
+        show_deleted: bool,
+        time_max: &str,
+        time_min: &str,
+        time_zone: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Event>>> {
+        self.runtime.block_on(self.inner.get_all_instances(
+            calendar_id,
+            event_id,
+            max_attendees,
+            original_start,
+            show_deleted,
+            time_max,
+            time_min,
+            time_zone,
+        ))
+    }
+    /// The blocking version of [`crate::events::Events::mv`].
+    pub fn mv(
+        &self,
+        calendar_id: &str,
+        event_id: &str,
+        destination: &str,
+        send_notifications: bool,
+        send_updates: crate::types::SendUpdates,
+    ) -> ClientResult<crate::Response<crate::types::Event>> {
+        self.runtime.block_on(self.inner.mv(
+            calendar_id,
+            event_id,
+            destination,
+            send_notifications,
+            send_updates,
+        ))
+    }
+    /// The blocking version of [`crate::events::Events::mv_request`].
+    pub fn mv_request<'a>(
+        &'a self,
+        calendar_id: &'a str,
+        event_id: &'a str,
+        destination: &'a str,
+    ) -> EventsMvRequest<'a> {
+        EventsMvRequest {
+            inner: self.inner.mv_request(calendar_id, event_id, destination),
+            runtime: &self.runtime,
+        }
+    }
+}
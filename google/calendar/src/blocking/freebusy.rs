@@ -0,0 +1,26 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::freebusy::Freebusy`].
+pub struct Freebusy {
+    inner: crate::freebusy::Freebusy,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Freebusy {
+    pub(crate) fn new(
+        inner: crate::freebusy::Freebusy,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Freebusy { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::freebusy::Freebusy::query`].
+    pub fn query(
+        &self,
+        body: &crate::types::FreeBusyRequest,
+    ) -> ClientResult<crate::Response<crate::types::FreeBusyResponse>> {
+        self.runtime.block_on(self.inner.query(body))
+    }
+}
@@ -0,0 +1,88 @@
+//! A blocking client, for the programs that are not async.
+//!
+//! It has the functions of the async [`Client`](crate::Client), which it runs on a
+//! runtime of its own, so they must not be called from within an async runtime. The
+//! `*_stream` functions are left out, the `*_all` ones returning all the pages at once.
+
+use std::{future::Future, sync::Arc};
+
+pub mod acl;
+pub mod calendar_list;
+pub mod calendars;
+pub mod channels;
+pub mod colors;
+pub mod events;
+pub mod freebusy;
+pub mod settings;
+
+/// A blocking client, sending its requests with an async [`Client`](crate::Client).
+#[derive(Clone)]
+pub struct Client {
+    inner: crate::Client,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Client {
+    /// Wraps an async client, creating the runtime its requests run on.
+    pub fn new(client: crate::Client) -> std::io::Result<Self> {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()?;
+
+        Ok(Client {
+            inner: client,
+            runtime: Arc::new(runtime),
+        })
+    }
+
+    /// Returns the async client the requests are sent with.
+    pub fn inner(&self) -> &crate::Client {
+        &self.inner
+    }
+
+    /// Runs a future to completion on the runtime of the client, for the async
+    /// functions that have no blocking version.
+    pub fn block_on<F: Future>(&self, future: F) -> F::Output {
+        self.runtime.block_on(future)
+    }
+
+    /// The blocking version of [`crate::Client::acl`].
+    pub fn acl(&self) -> acl::Acl {
+        acl::Acl::new(self.inner.acl(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::calendar_list`].
+    pub fn calendar_list(&self) -> calendar_list::CalendarList {
+        calendar_list::CalendarList::new(self.inner.calendar_list(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::calendars`].
+    pub fn calendars(&self) -> calendars::Calendars {
+        calendars::Calendars::new(self.inner.calendars(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::channels`].
+    pub fn channels(&self) -> channels::Channels {
+        channels::Channels::new(self.inner.channels(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::colors`].
+    pub fn colors(&self) -> colors::Colors {
+        colors::Colors::new(self.inner.colors(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::events`].
+    pub fn events(&self) -> events::Events {
+        events::Events::new(self.inner.events(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::freebusy`].
+    pub fn freebusy(&self) -> freebusy::Freebusy {
+        freebusy::Freebusy::new(self.inner.freebusy(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::settings`].
+    pub fn settings(&self) -> settings::Settings {
+        settings::Settings::new(self.inner.settings(), self.runtime.clone())
+    }
+}
@@ -0,0 +1,113 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Settings::list`], set with a builder.
+pub struct SettingsListRequest<'a> {
+    inner: crate::settings::SettingsListRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> SettingsListRequest<'a> {
+    /// Sets the `maxResults` query parameter.
+    pub fn max_results(mut self, max_results: i64) -> Self {
+        self.inner = self.inner.max_results(max_results);
+        self
+    }
+
+    /// Sets the `pageToken` query parameter.
+    pub fn page_token(mut self, page_token: &'a str) -> Self {
+        self.inner = self.inner.page_token(page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Setting>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Settings::watch`], set with a builder.
+pub struct SettingsWatchRequest<'a> {
+    inner: crate::settings::SettingsWatchRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> SettingsWatchRequest<'a> {
+    /// Sets the `maxResults` query parameter.
+    pub fn max_results(mut self, max_results: i64) -> Self {
+        self.inner = self.inner.max_results(max_results);
+        self
+    }
+
+    /// Sets the `pageToken` query parameter.
+    pub fn page_token(mut self, page_token: &'a str) -> Self {
+        self.inner = self.inner.page_token(page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::Channel>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::settings::Settings`].
+pub struct Settings {
+    inner: crate::settings::Settings,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Settings {
+    pub(crate) fn new(
+        inner: crate::settings::Settings,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Settings { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::settings::Settings::list`].
+    pub fn list(
+        &self,
+        max_results: i64,
+        page_token: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Setting>>> {
+        self.runtime
+            .block_on(self.inner.list(max_results, page_token))
+    }
+    /// The blocking version of [`crate::settings::Settings::list_request`].
+    pub fn list_request(&self) -> SettingsListRequest<'_> {
+        SettingsListRequest {
+            inner: self.inner.list_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::settings::Settings::list_all`].
+    pub fn list_all(&self) -> ClientResult<crate::Response<Vec<crate::types::Setting>>> {
+        self.runtime.block_on(self.inner.list_all())
+    }
+    /// The blocking version of [`crate::settings::Settings::watch`].
+    pub fn watch(
+        &self,
+        max_results: i64,
+        page_token: &str,
+        body: &crate::types::Channel,
+    ) -> ClientResult<crate::Response<crate::types::Channel>> {
+        self.runtime
+            .block_on(self.inner.watch(max_results, page_token, body))
+    }
+    /// The blocking version of [`crate::settings::Settings::watch_request`].
+    pub fn watch_request<'a>(
+        &'a self,
+        body: &'a crate::types::Channel,
+    ) -> SettingsWatchRequest<'a> {
+        SettingsWatchRequest {
+            inner: self.inner.watch_request(body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::settings::Settings::get`].
+    pub fn get(&self, setting: &str) -> ClientResult<crate::Response<crate::types::Setting>> {
+        self.runtime.block_on(self.inner.get(setting))
+    }
+}
@@ -1,6 +1,328 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`CalendarList::list`], set with a builder.
+pub struct CalendarListListRequest<'a> {
+    client: &'a CalendarList,
+    max_results: Option<i64>,
+    min_access_role: Option<crate::types::MinAccessRole>,
+    page_token: Option<&'a str>,
+    show_deleted: Option<bool>,
+    show_hidden: Option<bool>,
+}
+
+impl<'a> CalendarListListRequest<'a> {
+    /// Sets the `maxResults` query parameter.
+    pub fn max_results(mut self, max_results: i64) -> Self {
+        self.max_results = Some(max_results);
+        self
+    }
+
+    /// Sets the `minAccessRole` query parameter.
+    pub fn min_access_role(mut self, min_access_role: crate::types::MinAccessRole) -> Self {
+        self.min_access_role = Some(min_access_role);
+        self
+    }
+
+    /// Sets the `pageToken` query parameter.
+    pub fn page_token(mut self, page_token: &'a str) -> Self {
+        self.page_token = Some(page_token);
+        self
+    }
+
+    /// Sets the `showDeleted` query parameter.
+    pub fn show_deleted(mut self, show_deleted: bool) -> Self {
+        self.show_deleted = Some(show_deleted);
+        self
+    }
+
+    /// Sets the `showHidden` query parameter.
+    pub fn show_hidden(mut self, show_hidden: bool) -> Self {
+        self.show_hidden = Some(show_hidden);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::CalendarListEntry>>> {
+        let Self {
+            client,
+            max_results,
+            min_access_role,
+            page_token,
+            show_deleted,
+            show_hidden,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = max_results {
+            query_args.push(("maxResults".to_string(), v.to_string()));
+        }
+        if let Some(v) = min_access_role {
+            query_args.push(("minAccessRole".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_token {
+            query_args.push(("pageToken".to_string(), v.to_string()));
+        }
+        if let Some(v) = show_deleted {
+            query_args.push(("showDeleted".to_string(), v.to_string()));
+        }
+        if let Some(v) = show_hidden {
+            query_args.push(("showHidden".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/users/me/calendarList?{}", query_), None);
+
+        let resp: crate::Response<crate::types::CalendarList> = client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await?;
+
+        // Return our response data.
+        Ok(crate::Response::new(
+            resp.status,
+            resp.headers,
+            resp.body.items.to_vec(),
+        ))
+    }
+}
+
+/// The parameters of [`CalendarList::list_insert`], set with a builder.
+pub struct CalendarListListInsertRequest<'a> {
+    client: &'a CalendarList,
+    color_rgb_format: Option<bool>,
+    body: &'a crate::types::CalendarListEntry,
+}
+
+impl<'a> CalendarListListInsertRequest<'a> {
+    /// Sets the `colorRgbFormat` query parameter.
+    pub fn color_rgb_format(mut self, color_rgb_format: bool) -> Self {
+        self.color_rgb_format = Some(color_rgb_format);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::CalendarListEntry>> {
+        let Self {
+            client,
+            color_rgb_format,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = color_rgb_format {
+            query_args.push(("colorRgbFormat".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/users/me/calendarList?{}", query_), None);
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`CalendarList::list_watch`], set with a builder.
+pub struct CalendarListListWatchRequest<'a> {
+    client: &'a CalendarList,
+    max_results: Option<i64>,
+    min_access_role: Option<crate::types::MinAccessRole>,
+    page_token: Option<&'a str>,
+    show_deleted: Option<bool>,
+    show_hidden: Option<bool>,
+    body: &'a crate::types::Channel,
+}
+
+impl<'a> CalendarListListWatchRequest<'a> {
+    /// Sets the `maxResults` query parameter.
+    pub fn max_results(mut self, max_results: i64) -> Self {
+        self.max_results = Some(max_results);
+        self
+    }
+
+    /// Sets the `minAccessRole` query parameter.
+    pub fn min_access_role(mut self, min_access_role: crate::types::MinAccessRole) -> Self {
+        self.min_access_role = Some(min_access_role);
+        self
+    }
+
+    /// Sets the `pageToken` query parameter.
+    pub fn page_token(mut self, page_token: &'a str) -> Self {
+        self.page_token = Some(page_token);
+        self
+    }
+
+    /// Sets the `showDeleted` query parameter.
+    pub fn show_deleted(mut self, show_deleted: bool) -> Self {
+        self.show_deleted = Some(show_deleted);
+        self
+    }
+
+    /// Sets the `showHidden` query parameter.
+    pub fn show_hidden(mut self, show_hidden: bool) -> Self {
+        self.show_hidden = Some(show_hidden);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::Channel>> {
+        let Self {
+            client,
+            max_results,
+            min_access_role,
+            page_token,
+            show_deleted,
+            show_hidden,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = max_results {
+            query_args.push(("maxResults".to_string(), v.to_string()));
+        }
+        if let Some(v) = min_access_role {
+            query_args.push(("minAccessRole".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_token {
+            query_args.push(("pageToken".to_string(), v.to_string()));
+        }
+        if let Some(v) = show_deleted {
+            query_args.push(("showDeleted".to_string(), v.to_string()));
+        }
+        if let Some(v) = show_hidden {
+            query_args.push(("showHidden".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/users/me/calendarList/watch?{}", query_), None);
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`CalendarList::list_update`], set with a builder.
+pub struct CalendarListListUpdateRequest<'a> {
+    client: &'a CalendarList,
+    calendar_id: &'a str,
+    color_rgb_format: Option<bool>,
+    body: &'a crate::types::CalendarListEntry,
+}
+
+impl<'a> CalendarListListUpdateRequest<'a> {
+    /// Sets the `colorRgbFormat` query parameter.
+    pub fn color_rgb_format(mut self, color_rgb_format: bool) -> Self {
+        self.color_rgb_format = Some(color_rgb_format);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::CalendarListEntry>> {
+        let Self {
+            client,
+            calendar_id,
+            color_rgb_format,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = color_rgb_format {
+            query_args.push(("colorRgbFormat".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/users/me/calendarList/{}?{}",
+                crate::progenitor_support::encode_path(calendar_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .put(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`CalendarList::list_patch`], set with a builder.
+pub struct CalendarListListPatchRequest<'a> {
+    client: &'a CalendarList,
+    calendar_id: &'a str,
+    color_rgb_format: Option<bool>,
+    body: &'a crate::types::CalendarListEntry,
+}
+
+impl<'a> CalendarListListPatchRequest<'a> {
+    /// Sets the `colorRgbFormat` query parameter.
+    pub fn color_rgb_format(mut self, color_rgb_format: bool) -> Self {
+        self.color_rgb_format = Some(color_rgb_format);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::CalendarListEntry>> {
+        let Self {
+            client,
+            calendar_id,
+            color_rgb_format,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = color_rgb_format {
+            query_args.push(("colorRgbFormat".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/users/me/calendarList/{}?{}",
+                crate::progenitor_support::encode_path(calendar_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .patch(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
 pub struct CalendarList {
     pub client: Client,
 }
@@ -75,6 +397,23 @@ impl CalendarList {
             resp.body.items.to_vec(),
         ))
     }
+    /**
+     * This function performs a `GET` to the `/users/me/calendarList` endpoint.
+     *
+     * As opposed to `list`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Returns the calendars on the user's calendar list.
+     */
+    pub fn list_request(&self) -> CalendarListListRequest<'_> {
+        CalendarListListRequest {
+            client: self,
+            max_results: None,
+            min_access_role: None,
+            page_token: None,
+            show_deleted: None,
+            show_hidden: None,
+        }
+    }
     /**
      * This function performs a `GET` to the `/users/me/calendarList` endpoint.
      *
@@ -102,11 +441,11 @@ impl CalendarList {
         let url = self
             .client
             .url(&format!("/users/me/calendarList?{}", query_), None);
-        let crate::Response::<crate::types::CalendarList> {
-            mut status,
-            mut headers,
-            mut body,
-        } = self
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let mut pages = crate::utils::Pages::new(self.client.page_limits);
+        pages.visit(&url)?;
+
+        let mut response: crate::Response<crate::types::CalendarList> = self
             .client
             .get(
                 &url,
@@ -116,55 +455,84 @@ impl CalendarList {
                 },
             )
             .await?;
-
-        let mut items = body.items;
-        let mut page = body.next_page_token;
+        let mut items = Vec::new();
 
         // Paginate if we should.
-        while !page.is_empty() {
-            if !url.contains('?') {
-                crate::Response::<crate::types::CalendarList> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}?pageToken={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
+        loop {
+            let page = if response.body.next_page_token.is_empty() {
+                None
             } else {
-                crate::Response::<crate::types::CalendarList> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}&pageToken={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
-
-            items.append(&mut body.items);
-
-            if !body.next_page_token.is_empty() && body.next_page_token != page {
-                page = body.next_page_token.to_string();
-            } else {
-                page = "".to_string();
-            }
+                Some(format!(
+                    "{}{}pageToken={}",
+                    url, sep, response.body.next_page_token
+                ))
+            };
+            let more = pages.keep(&mut response.body.items);
+            items.append(&mut response.body.items);
+
+            let next = match page {
+                Some(next) if more && pages.visit(&next)? => next,
+                _ => break,
+            };
+            response = self
+                .client
+                .get(
+                    &next,
+                    crate::Message {
+                        body: None,
+                        content_type: None,
+                    },
+                )
+                .await?;
         }
 
         // Return our response data.
-        Ok(crate::Response::new(status, headers, items))
+        Ok(crate::Response::new(
+            response.status,
+            response.headers,
+            items,
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/users/me/calendarList` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Returns the calendars on the user's calendar list.
+     */
+    pub fn list_all_stream(
+        &self,
+        min_access_role: crate::types::MinAccessRole,
+        show_deleted: bool,
+        show_hidden: bool,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::CalendarListEntry>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !min_access_role.to_string().is_empty() {
+            query_args.push(("minAccessRole".to_string(), min_access_role.to_string()));
+        }
+        if show_deleted {
+            query_args.push(("showDeleted".to_string(), show_deleted.to_string()));
+        }
+        if show_hidden {
+            query_args.push(("showHidden".to_string(), show_hidden.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/users/me/calendarList?{}", query_), None);
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let next = format!("{}{}pageToken=", url, sep);
+        self.client
+            .get_pages_stream(url, move |body: crate::types::CalendarList| {
+                let page = if body.next_page_token.is_empty() {
+                    None
+                } else {
+                    Some(format!("{}{}", next, body.next_page_token))
+                };
+
+                (body.items, page)
+            })
     }
     /**
      * This function performs a `POST` to the `/users/me/calendarList` endpoint.
@@ -198,6 +566,23 @@ impl CalendarList {
             )
             .await
     }
+    /**
+     * This function performs a `POST` to the `/users/me/calendarList` endpoint.
+     *
+     * As opposed to `list_insert`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Inserts an existing calendar into the user's calendar list.
+     */
+    pub fn list_insert_request<'a>(
+        &'a self,
+        body: &'a crate::types::CalendarListEntry,
+    ) -> CalendarListListInsertRequest<'a> {
+        CalendarListListInsertRequest {
+            client: self,
+            color_rgb_format: None,
+            body,
+        }
+    }
     /**
      * This function performs a `POST` to the `/users/me/calendarList/watch` endpoint.
      *
@@ -255,6 +640,27 @@ impl CalendarList {
             )
             .await
     }
+    /**
+     * This function performs a `POST` to the `/users/me/calendarList/watch` endpoint.
+     *
+     * As opposed to `list_watch`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Watch for changes to CalendarList resources.
+     */
+    pub fn list_watch_request<'a>(
+        &'a self,
+        body: &'a crate::types::Channel,
+    ) -> CalendarListListWatchRequest<'a> {
+        CalendarListListWatchRequest {
+            client: self,
+            max_results: None,
+            min_access_role: None,
+            page_token: None,
+            show_deleted: None,
+            show_hidden: None,
+            body,
+        }
+    }
     /**
      * This function performs a `GET` to the `/users/me/calendarList/{calendarId}` endpoint.
      *
@@ -324,6 +730,25 @@ impl CalendarList {
             )
             .await
     }
+    /**
+     * This function performs a `PUT` to the `/users/me/calendarList/{calendarId}` endpoint.
+     *
+     * As opposed to `list_update`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Updates an existing calendar on the user's calendar list.
+     */
+    pub fn list_update_request<'a>(
+        &'a self,
+        calendar_id: &'a str,
+        body: &'a crate::types::CalendarListEntry,
+    ) -> CalendarListListUpdateRequest<'a> {
+        CalendarListListUpdateRequest {
+            client: self,
+            calendar_id,
+            color_rgb_format: None,
+            body,
+        }
+    }
     /**
      * This function performs a `DELETE` to the `/users/me/calendarList/{calendarId}` endpoint.
      *
@@ -390,4 +815,23 @@ impl CalendarList {
             )
             .await
     }
+    /**
+     * This function performs a `PATCH` to the `/users/me/calendarList/{calendarId}` endpoint.
+     *
+     * As opposed to `list_patch`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Updates an existing calendar on the user's calendar list. This method supports patch semantics.
+     */
+    pub fn list_patch_request<'a>(
+        &'a self,
+        calendar_id: &'a str,
+        body: &'a crate::types::CalendarListEntry,
+    ) -> CalendarListListPatchRequest<'a> {
+        CalendarListListPatchRequest {
+            client: self,
+            calendar_id,
+            color_rgb_format: None,
+            body,
+        }
+    }
 }
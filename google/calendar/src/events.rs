@@ -1,6 +1,1158 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Events::list`], set with a builder.
+pub struct EventsListRequest<'a> {
+    client: &'a Events,
+    calendar_id: &'a str,
+    i_cal_uid: Option<&'a str>,
+    max_attendees: Option<i64>,
+    max_results: Option<i64>,
+    order_by: Option<crate::types::OrderBy>,
+    page_token: Option<&'a str>,
+    private_extended_property: Option<&'a [String]>,
+    q: Option<&'a str>,
+    shared_extended_property: Option<&'a [String]>,
+    show_deleted: Option<bool>,
+    show_hidden_invitations: Option<bool>,
+    single_events: Option<bool>,
+    time_max: Option<&'a str>,
+    time_min: Option<&'a str>,
+    time_zone: Option<&'a str>,
+    updated_min: Option<&'a str>,
+}
+
+impl<'a> EventsListRequest<'a> {
+    /// Sets the `iCalUID` query parameter.
+    pub fn i_cal_uid(mut self, i_cal_uid: &'a str) -> Self {
+        self.i_cal_uid = Some(i_cal_uid);
+        self
+    }
+
+    /// Sets the `maxAttendees` query parameter.
+    pub fn max_attendees(mut self, max_attendees: i64) -> Self {
+        self.max_attendees = Some(max_attendees);
+        self
+    }
+
+    /// Sets the `maxResults` query parameter.
+    pub fn max_results(mut self, max_results: i64) -> Self {
+        self.max_results = Some(max_results);
+        self
+    }
+
+    /// Sets the `orderBy` query parameter.
+    pub fn order_by(mut self, order_by: crate::types::OrderBy) -> Self {
+        self.order_by = Some(order_by);
+        self
+    }
+
+    /// Sets the `pageToken` query parameter.
+    pub fn page_token(mut self, page_token: &'a str) -> Self {
+        self.page_token = Some(page_token);
+        self
+    }
+
+    /// Sets the `privateExtendedProperty` query parameter.
+    pub fn private_extended_property(mut self, private_extended_property: &'a [String]) -> Self {
+        self.private_extended_property = Some(private_extended_property);
+        self
+    }
+
+    /// Sets the `q` query parameter.
+    pub fn q(mut self, q: &'a str) -> Self {
+        self.q = Some(q);
+        self
+    }
+
+    /// Sets the `sharedExtendedProperty` query parameter.
+    pub fn shared_extended_property(mut self, shared_extended_property: &'a [String]) -> Self {
+        self.shared_extended_property = Some(shared_extended_property);
+        self
+    }
+
+    /// Sets the `showDeleted` query parameter.
+    pub fn show_deleted(mut self, show_deleted: bool) -> Self {
+        self.show_deleted = Some(show_deleted);
+        self
+    }
+
+    /// Sets the `showHiddenInvitations` query parameter.
+    pub fn show_hidden_invitations(mut self, show_hidden_invitations: bool) -> Self {
+        self.show_hidden_invitations = Some(show_hidden_invitations);
+        self
+    }
+
+    /// Sets the `singleEvents` query parameter.
+    pub fn single_events(mut self, single_events: bool) -> Self {
+        self.single_events = Some(single_events);
+        self
+    }
+
+    /// Sets the `timeMax` query parameter.
+    pub fn time_max(mut self, time_max: &'a str) -> Self {
+        self.time_max = Some(time_max);
+        self
+    }
+
+    /// Sets the `timeMin` query parameter.
+    pub fn time_min(mut self, time_min: &'a str) -> Self {
+        self.time_min = Some(time_min);
+        self
+    }
+
+    /// Sets the `timeZone` query parameter.
+    pub fn time_zone(mut self, time_zone: &'a str) -> Self {
+        self.time_zone = Some(time_zone);
+        self
+    }
+
+    /// Sets the `updatedMin` query parameter.
+    pub fn updated_min(mut self, updated_min: &'a str) -> Self {
+        self.updated_min = Some(updated_min);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Event>>> {
+        let Self {
+            client,
+            calendar_id,
+            i_cal_uid,
+            max_attendees,
+            max_results,
+            order_by,
+            page_token,
+            private_extended_property,
+            q,
+            shared_extended_property,
+            show_deleted,
+            show_hidden_invitations,
+            single_events,
+            time_max,
+            time_min,
+            time_zone,
+            updated_min,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = i_cal_uid {
+            query_args.push(("iCalUID".to_string(), v.to_string()));
+        }
+        if let Some(v) = max_attendees {
+            query_args.push(("maxAttendees".to_string(), v.to_string()));
+        }
+        if let Some(v) = max_results {
+            query_args.push(("maxResults".to_string(), v.to_string()));
+        }
+        if let Some(v) = order_by {
+            query_args.push(("orderBy".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_token {
+            query_args.push(("pageToken".to_string(), v.to_string()));
+        }
+        if let Some(v) = private_extended_property {
+            query_args.push(("privateExtendedProperty".to_string(), v.join(" ")));
+        }
+        if let Some(v) = q {
+            query_args.push(("q".to_string(), v.to_string()));
+        }
+        if let Some(v) = shared_extended_property {
+            query_args.push(("sharedExtendedProperty".to_string(), v.join(" ")));
+        }
+        if let Some(v) = show_deleted {
+            query_args.push(("showDeleted".to_string(), v.to_string()));
+        }
+        if let Some(v) = show_hidden_invitations {
+            query_args.push(("showHiddenInvitations".to_string(), v.to_string()));
+        }
+        if let Some(v) = single_events {
+            query_args.push(("singleEvents".to_string(), v.to_string()));
+        }
+        if let Some(v) = time_max {
+            query_args.push(("timeMax".to_string(), v.to_string()));
+        }
+        if let Some(v) = time_min {
+            query_args.push(("timeMin".to_string(), v.to_string()));
+        }
+        if let Some(v) = time_zone {
+            query_args.push(("timeZone".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_min {
+            query_args.push(("updatedMin".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/calendars/{}/events?{}",
+                crate::progenitor_support::encode_path(calendar_id),
+                query_
+            ),
+            None,
+        );
+
+        let resp: crate::Response<crate::types::Events> = client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await?;
+
+        // Return our response data.
+        Ok(crate::Response::new(
+            resp.status,
+            resp.headers,
+            resp.body.items.to_vec(),
+        ))
+    }
+}
+
+/// The parameters of [`Events::insert`], set with a builder.
+pub struct EventsInsertRequest<'a> {
+    client: &'a Events,
+    calendar_id: &'a str,
+    conference_data_version: Option<u64>,
+    max_attendees: Option<i64>,
+    send_notifications: Option<bool>,
+    send_updates: Option<crate::types::SendUpdates>,
+    supports_attachments: Option<bool>,
+    body: &'a crate::types::Event,
+}
+
+impl<'a> EventsInsertRequest<'a> {
+    /// Sets the `conferenceDataVersion` query parameter.
+    pub fn conference_data_version(mut self, conference_data_version: u64) -> Self {
+        self.conference_data_version = Some(conference_data_version);
+        self
+    }
+
+    /// Sets the `maxAttendees` query parameter.
+    pub fn max_attendees(mut self, max_attendees: i64) -> Self {
+        self.max_attendees = Some(max_attendees);
+        self
+    }
+
+    /// Sets the `sendNotifications` query parameter.
+    pub fn send_notifications(mut self, send_notifications: bool) -> Self {
+        self.send_notifications = Some(send_notifications);
+        self
+    }
+
+    /// Sets the `sendUpdates` query parameter.
+    pub fn send_updates(mut self, send_updates: crate::types::SendUpdates) -> Self {
+        self.send_updates = Some(send_updates);
+        self
+    }
+
+    /// Sets the `supportsAttachments` query parameter.
+    pub fn supports_attachments(mut self, supports_attachments: bool) -> Self {
+        self.supports_attachments = Some(supports_attachments);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::Event>> {
+        let Self {
+            client,
+            calendar_id,
+            conference_data_version,
+            max_attendees,
+            send_notifications,
+            send_updates,
+            supports_attachments,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = conference_data_version {
+            query_args.push(("conferenceDataVersion".to_string(), v.to_string()));
+        }
+        if let Some(v) = max_attendees {
+            query_args.push(("maxAttendees".to_string(), v.to_string()));
+        }
+        if let Some(v) = send_notifications {
+            query_args.push(("sendNotifications".to_string(), v.to_string()));
+        }
+        if let Some(v) = send_updates {
+            query_args.push(("sendUpdates".to_string(), v.to_string()));
+        }
+        if let Some(v) = supports_attachments {
+            query_args.push(("supportsAttachments".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/calendars/{}/events?{}",
+                crate::progenitor_support::encode_path(calendar_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Events::import`], set with a builder.
+pub struct EventsImportRequest<'a> {
+    client: &'a Events,
+    calendar_id: &'a str,
+    conference_data_version: Option<u64>,
+    supports_attachments: Option<bool>,
+    body: &'a crate::types::Event,
+}
+
+impl<'a> EventsImportRequest<'a> {
+    /// Sets the `conferenceDataVersion` query parameter.
+    pub fn conference_data_version(mut self, conference_data_version: u64) -> Self {
+        self.conference_data_version = Some(conference_data_version);
+        self
+    }
+
+    /// Sets the `supportsAttachments` query parameter.
+    pub fn supports_attachments(mut self, supports_attachments: bool) -> Self {
+        self.supports_attachments = Some(supports_attachments);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::Event>> {
+        let Self {
+            client,
+            calendar_id,
+            conference_data_version,
+            supports_attachments,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = conference_data_version {
+            query_args.push(("conferenceDataVersion".to_string(), v.to_string()));
+        }
+        if let Some(v) = supports_attachments {
+            query_args.push(("supportsAttachments".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/calendars/{}/events/import?{}",
+                crate::progenitor_support::encode_path(calendar_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Events::quick_add`], set with a builder.
+pub struct EventsQuickAddRequest<'a> {
+    client: &'a Events,
+    calendar_id: &'a str,
+    text: &'a str,
+    send_notifications: Option<bool>,
+    send_updates: Option<crate::types::SendUpdates>,
+}
+
+impl<'a> EventsQuickAddRequest<'a> {
+    /// Sets the `sendNotifications` query parameter.
+    pub fn send_notifications(mut self, send_notifications: bool) -> Self {
+        self.send_notifications = Some(send_notifications);
+        self
+    }
+
+    /// Sets the `sendUpdates` query parameter.
+    pub fn send_updates(mut self, send_updates: crate::types::SendUpdates) -> Self {
+        self.send_updates = Some(send_updates);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::Event>> {
+        let Self {
+            client,
+            calendar_id,
+            text,
+            send_notifications,
+            send_updates,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = send_notifications {
+            query_args.push(("sendNotifications".to_string(), v.to_string()));
+        }
+        if let Some(v) = send_updates {
+            query_args.push(("sendUpdates".to_string(), v.to_string()));
+        }
+        if !text.is_empty() {
+            query_args.push(("text".to_string(), text.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/calendars/{}/events/quickAdd?{}",
+                crate::progenitor_support::encode_path(calendar_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Events::watch`], set with a builder.
+pub struct EventsWatchRequest<'a> {
+    client: &'a Events,
+    calendar_id: &'a str,
+    i_cal_uid: Option<&'a str>,
+    max_attendees: Option<i64>,
+    max_results: Option<i64>,
+    order_by: Option<crate::types::OrderBy>,
+    page_token: Option<&'a str>,
+    private_extended_property: Option<&'a [String]>,
+    q: Option<&'a str>,
+    shared_extended_property: Option<&'a [String]>,
+    show_deleted: Option<bool>,
+    show_hidden_invitations: Option<bool>,
+    single_events: Option<bool>,
+    time_max: Option<&'a str>,
+    time_min: Option<&'a str>,
+    time_zone: Option<&'a str>,
+    updated_min: Option<&'a str>,
+    body: &'a crate::types::Channel,
+}
+
+impl<'a> EventsWatchRequest<'a> {
+    /// Sets the `iCalUID` query parameter.
+    pub fn i_cal_uid(mut self, i_cal_uid: &'a str) -> Self {
+        self.i_cal_uid = Some(i_cal_uid);
+        self
+    }
+
+    /// Sets the `maxAttendees` query parameter.
+    pub fn max_attendees(mut self, max_attendees: i64) -> Self {
+        self.max_attendees = Some(max_attendees);
+        self
+    }
+
+    /// Sets the `maxResults` query parameter.
+    pub fn max_results(mut self, max_results: i64) -> Self {
+        self.max_results = Some(max_results);
+        self
+    }
+
+    /// Sets the `orderBy` query parameter.
+    pub fn order_by(mut self, order_by: crate::types::OrderBy) -> Self {
+        self.order_by = Some(order_by);
+        self
+    }
+
+    /// Sets the `pageToken` query parameter.
+    pub fn page_token(mut self, page_token: &'a str) -> Self {
+        self.page_token = Some(page_token);
+        self
+    }
+
+    /// Sets the `privateExtendedProperty` query parameter.
+    pub fn private_extended_property(mut self, private_extended_property: &'a [String]) -> Self {
+        self.private_extended_property = Some(private_extended_property);
+        self
+    }
+
+    /// Sets the `q` query parameter.
+    pub fn q(mut self, q: &'a str) -> Self {
+        self.q = Some(q);
+        self
+    }
+
+    /// Sets the `sharedExtendedProperty` query parameter.
+    pub fn shared_extended_property(mut self, shared_extended_property: &'a [String]) -> Self {
+        self.shared_extended_property = Some(shared_extended_property);
+        self
+    }
+
+    /// Sets the `showDeleted` query parameter.
+    pub fn show_deleted(mut self, show_deleted: bool) -> Self {
+        self.show_deleted = Some(show_deleted);
+        self
+    }
+
+    /// Sets the `showHiddenInvitations` query parameter.
+    pub fn show_hidden_invitations(mut self, show_hidden_invitations: bool) -> Self {
+        self.show_hidden_invitations = Some(show_hidden_invitations);
+        self
+    }
+
+    /// Sets the `singleEvents` query parameter.
+    pub fn single_events(mut self, single_events: bool) -> Self {
+        self.single_events = Some(single_events);
+        self
+    }
+
+    /// Sets the `timeMax` query parameter.
+    pub fn time_max(mut self, time_max: &'a str) -> Self {
+        self.time_max = Some(time_max);
+        self
+    }
+
+    /// Sets the `timeMin` query parameter.
+    pub fn time_min(mut self, time_min: &'a str) -> Self {
+        self.time_min = Some(time_min);
+        self
+    }
+
+    /// Sets the `timeZone` query parameter.
+    pub fn time_zone(mut self, time_zone: &'a str) -> Self {
+        self.time_zone = Some(time_zone);
+        self
+    }
+
+    /// Sets the `updatedMin` query parameter.
+    pub fn updated_min(mut self, updated_min: &'a str) -> Self {
+        self.updated_min = Some(updated_min);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::Channel>> {
+        let Self {
+            client,
+            calendar_id,
+            i_cal_uid,
+            max_attendees,
+            max_results,
+            order_by,
+            page_token,
+            private_extended_property,
+            q,
+            shared_extended_property,
+            show_deleted,
+            show_hidden_invitations,
+            single_events,
+            time_max,
+            time_min,
+            time_zone,
+            updated_min,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = i_cal_uid {
+            query_args.push(("iCalUID".to_string(), v.to_string()));
+        }
+        if let Some(v) = max_attendees {
+            query_args.push(("maxAttendees".to_string(), v.to_string()));
+        }
+        if let Some(v) = max_results {
+            query_args.push(("maxResults".to_string(), v.to_string()));
+        }
+        if let Some(v) = order_by {
+            query_args.push(("orderBy".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_token {
+            query_args.push(("pageToken".to_string(), v.to_string()));
+        }
+        if let Some(v) = private_extended_property {
+            query_args.push(("privateExtendedProperty".to_string(), v.join(" ")));
+        }
+        if let Some(v) = q {
+            query_args.push(("q".to_string(), v.to_string()));
+        }
+        if let Some(v) = shared_extended_property {
+            query_args.push(("sharedExtendedProperty".to_string(), v.join(" ")));
+        }
+        if let Some(v) = show_deleted {
+            query_args.push(("showDeleted".to_string(), v.to_string()));
+        }
+        if let Some(v) = show_hidden_invitations {
+            query_args.push(("showHiddenInvitations".to_string(), v.to_string()));
+        }
+        if let Some(v) = single_events {
+            query_args.push(("singleEvents".to_string(), v.to_string()));
+        }
+        if let Some(v) = time_max {
+            query_args.push(("timeMax".to_string(), v.to_string()));
+        }
+        if let Some(v) = time_min {
+            query_args.push(("timeMin".to_string(), v.to_string()));
+        }
+        if let Some(v) = time_zone {
+            query_args.push(("timeZone".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_min {
+            query_args.push(("updatedMin".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/calendars/{}/events/watch?{}",
+                crate::progenitor_support::encode_path(calendar_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Events::get`], set with a builder.
+pub struct EventsGetRequest<'a> {
+    client: &'a Events,
+    calendar_id: &'a str,
+    event_id: &'a str,
+    max_attendees: Option<i64>,
+    time_zone: Option<&'a str>,
+}
+
+impl<'a> EventsGetRequest<'a> {
+    /// Sets the `maxAttendees` query parameter.
+    pub fn max_attendees(mut self, max_attendees: i64) -> Self {
+        self.max_attendees = Some(max_attendees);
+        self
+    }
+
+    /// Sets the `timeZone` query parameter.
+    pub fn time_zone(mut self, time_zone: &'a str) -> Self {
+        self.time_zone = Some(time_zone);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::Event>> {
+        let Self {
+            client,
+            calendar_id,
+            event_id,
+            max_attendees,
+            time_zone,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = max_attendees {
+            query_args.push(("maxAttendees".to_string(), v.to_string()));
+        }
+        if let Some(v) = time_zone {
+            query_args.push(("timeZone".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/calendars/{}/events/{}?{}",
+                crate::progenitor_support::encode_path(calendar_id),
+                crate::progenitor_support::encode_path(event_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Events::update`], set with a builder.
+pub struct EventsUpdateRequest<'a> {
+    client: &'a Events,
+    calendar_id: &'a str,
+    event_id: &'a str,
+    conference_data_version: Option<u64>,
+    max_attendees: Option<i64>,
+    send_notifications: Option<bool>,
+    send_updates: Option<crate::types::SendUpdates>,
+    supports_attachments: Option<bool>,
+    body: &'a crate::types::Event,
+}
+
+impl<'a> EventsUpdateRequest<'a> {
+    /// Sets the `conferenceDataVersion` query parameter.
+    pub fn conference_data_version(mut self, conference_data_version: u64) -> Self {
+        self.conference_data_version = Some(conference_data_version);
+        self
+    }
+
+    /// Sets the `maxAttendees` query parameter.
+    pub fn max_attendees(mut self, max_attendees: i64) -> Self {
+        self.max_attendees = Some(max_attendees);
+        self
+    }
+
+    /// Sets the `sendNotifications` query parameter.
+    pub fn send_notifications(mut self, send_notifications: bool) -> Self {
+        self.send_notifications = Some(send_notifications);
+        self
+    }
+
+    /// Sets the `sendUpdates` query parameter.
+    pub fn send_updates(mut self, send_updates: crate::types::SendUpdates) -> Self {
+        self.send_updates = Some(send_updates);
+        self
+    }
+
+    /// Sets the `supportsAttachments` query parameter.
+    pub fn supports_attachments(mut self, supports_attachments: bool) -> Self {
+        self.supports_attachments = Some(supports_attachments);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::Event>> {
+        let Self {
+            client,
+            calendar_id,
+            event_id,
+            conference_data_version,
+            max_attendees,
+            send_notifications,
+            send_updates,
+            supports_attachments,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = conference_data_version {
+            query_args.push(("conferenceDataVersion".to_string(), v.to_string()));
+        }
+        if let Some(v) = max_attendees {
+            query_args.push(("maxAttendees".to_string(), v.to_string()));
+        }
+        if let Some(v) = send_notifications {
+            query_args.push(("sendNotifications".to_string(), v.to_string()));
+        }
+        if let Some(v) = send_updates {
+            query_args.push(("sendUpdates".to_string(), v.to_string()));
+        }
+        if let Some(v) = supports_attachments {
+            query_args.push(("supportsAttachments".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/calendars/{}/events/{}?{}",
+                crate::progenitor_support::encode_path(calendar_id),
+                crate::progenitor_support::encode_path(event_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .put(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Events::delete`], set with a builder.
+pub struct EventsDeleteRequest<'a> {
+    client: &'a Events,
+    calendar_id: &'a str,
+    event_id: &'a str,
+    send_notifications: Option<bool>,
+    send_updates: Option<crate::types::SendUpdates>,
+}
+
+impl<'a> EventsDeleteRequest<'a> {
+    /// Sets the `sendNotifications` query parameter.
+    pub fn send_notifications(mut self, send_notifications: bool) -> Self {
+        self.send_notifications = Some(send_notifications);
+        self
+    }
+
+    /// Sets the `sendUpdates` query parameter.
+    pub fn send_updates(mut self, send_updates: crate::types::SendUpdates) -> Self {
+        self.send_updates = Some(send_updates);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            calendar_id,
+            event_id,
+            send_notifications,
+            send_updates,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = send_notifications {
+            query_args.push(("sendNotifications".to_string(), v.to_string()));
+        }
+        if let Some(v) = send_updates {
+            query_args.push(("sendUpdates".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/calendars/{}/events/{}?{}",
+                crate::progenitor_support::encode_path(calendar_id),
+                crate::progenitor_support::encode_path(event_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .delete(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Events::patch`], set with a builder.
+pub struct EventsPatchRequest<'a> {
+    client: &'a Events,
+    calendar_id: &'a str,
+    event_id: &'a str,
+    conference_data_version: Option<u64>,
+    max_attendees: Option<i64>,
+    send_notifications: Option<bool>,
+    send_updates: Option<crate::types::SendUpdates>,
+    supports_attachments: Option<bool>,
+    body: &'a crate::types::Event,
+}
+
+impl<'a> EventsPatchRequest<'a> {
+    /// Sets the `conferenceDataVersion` query parameter.
+    pub fn conference_data_version(mut self, conference_data_version: u64) -> Self {
+        self.conference_data_version = Some(conference_data_version);
+        self
+    }
+
+    /// Sets the `maxAttendees` query parameter.
+    pub fn max_attendees(mut self, max_attendees: i64) -> Self {
+        self.max_attendees = Some(max_attendees);
+        self
+    }
+
+    /// Sets the `sendNotifications` query parameter.
+    pub fn send_notifications(mut self, send_notifications: bool) -> Self {
+        self.send_notifications = Some(send_notifications);
+        self
+    }
+
+    /// Sets the `sendUpdates` query parameter.
+    pub fn send_updates(mut self, send_updates: crate::types::SendUpdates) -> Self {
+        self.send_updates = Some(send_updates);
+        self
+    }
+
+    /// Sets the `supportsAttachments` query parameter.
+    pub fn supports_attachments(mut self, supports_attachments: bool) -> Self {
+        self.supports_attachments = Some(supports_attachments);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::Event>> {
+        let Self {
+            client,
+            calendar_id,
+            event_id,
+            conference_data_version,
+            max_attendees,
+            send_notifications,
+            send_updates,
+            supports_attachments,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = conference_data_version {
+            query_args.push(("conferenceDataVersion".to_string(), v.to_string()));
+        }
+        if let Some(v) = max_attendees {
+            query_args.push(("maxAttendees".to_string(), v.to_string()));
+        }
+        if let Some(v) = send_notifications {
+            query_args.push(("sendNotifications".to_string(), v.to_string()));
+        }
+        if let Some(v) = send_updates {
+            query_args.push(("sendUpdates".to_string(), v.to_string()));
+        }
+        if let Some(v) = supports_attachments {
+            query_args.push(("supportsAttachments".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/calendars/{}/events/{}?{}",
+                crate::progenitor_support::encode_path(calendar_id),
+                crate::progenitor_support::encode_path(event_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .patch(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Events::instances`], set with a builder.
+pub struct EventsInstancesRequest<'a> {
+    client: &'a Events,
+    calendar_id: &'a str,
+    event_id: &'a str,
+    max_attendees: Option<i64>,
+    max_results: Option<i64>,
+    original_start: Option<&'a str>,
+    page_token: Option<&'a str>,
+    show_deleted: Option<bool>,
+    time_max: Option<&'a str>,
+    time_min: Option<&'a str>,
+    time_zone: Option<&'a str>,
+}
+
+impl<'a> EventsInstancesRequest<'a> {
+    /// Sets the `maxAttendees` query parameter.
+    pub fn max_attendees(mut self, max_attendees: i64) -> Self {
+        self.max_attendees = Some(max_attendees);
+        self
+    }
+
+    /// Sets the `maxResults` query parameter.
+    pub fn max_results(mut self, max_results: i64) -> Self {
+        self.max_results = Some(max_results);
+        self
+    }
+
+    /// Sets the `originalStart` query parameter.
+    pub fn original_start(mut self, original_start: &'a str) -> Self {
+        self.original_start = Some(original_start);
+        self
+    }
+
+    /// Sets the `pageToken` query parameter.
+    pub fn page_token(mut self, page_token: &'a str) -> Self {
+        self.page_token = Some(page_token);
+        self
+    }
+
+    /// Sets the `showDeleted` query parameter.
+    pub fn show_deleted(mut self, show_deleted: bool) -> Self {
+        self.show_deleted = Some(show_deleted);
+        self
+    }
+
+    /// Sets the `timeMax` query parameter.
+    pub fn time_max(mut self, time_max: &'a str) -> Self {
+        self.time_max = Some(time_max);
+        self
+    }
+
+    /// Sets the `timeMin` query parameter.
+    pub fn time_min(mut self, time_min: &'a str) -> Self {
+        self.time_min = Some(time_min);
+        self
+    }
+
+    /// Sets the `timeZone` query parameter.
+    pub fn time_zone(mut self, time_zone: &'a str) -> Self {
+        self.time_zone = Some(time_zone);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Event>>> {
+        let Self {
+            client,
+            calendar_id,
+            event_id,
+            max_attendees,
+            max_results,
+            original_start,
+            page_token,
+            show_deleted,
+            time_max,
+            time_min,
+            time_zone,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = max_attendees {
+            query_args.push(("maxAttendees".to_string(), v.to_string()));
+        }
+        if let Some(v) = max_results {
+            query_args.push(("maxResults".to_string(), v.to_string()));
+        }
+        if let Some(v) = original_start {
+            query_args.push(("originalStart".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_token {
+            query_args.push(("pageToken".to_string(), v.to_string()));
+        }
+        if let Some(v) = show_deleted {
+            query_args.push(("showDeleted".to_string(), v.to_string()));
+        }
+        if let Some(v) = time_max {
+            query_args.push(("timeMax".to_string(), v.to_string()));
+        }
+        if let Some(v) = time_min {
+            query_args.push(("timeMin".to_string(), v.to_string()));
+        }
+        if let Some(v) = time_zone {
+            query_args.push(("timeZone".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/calendars/{}/events/{}/instances?{}",
+                crate::progenitor_support::encode_path(calendar_id),
+                crate::progenitor_support::encode_path(event_id),
+                query_
+            ),
+            None,
+        );
+
+        let resp: crate::Response<crate::types::Events> = client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await?;
+
+        // Return our response data.
+        Ok(crate::Response::new(
+            resp.status,
+            resp.headers,
+            resp.body.items.to_vec(),
+        ))
+    }
+}
+
+/// The parameters of [`Events::mv`], set with a builder.
+pub struct EventsMvRequest<'a> {
+    client: &'a Events,
+    calendar_id: &'a str,
+    event_id: &'a str,
+    destination: &'a str,
+    send_notifications: Option<bool>,
+    send_updates: Option<crate::types::SendUpdates>,
+}
+
+impl<'a> EventsMvRequest<'a> {
+    /// Sets the `sendNotifications` query parameter.
+    pub fn send_notifications(mut self, send_notifications: bool) -> Self {
+        self.send_notifications = Some(send_notifications);
+        self
+    }
+
+    /// Sets the `sendUpdates` query parameter.
+    pub fn send_updates(mut self, send_updates: crate::types::SendUpdates) -> Self {
+        self.send_updates = Some(send_updates);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::Event>> {
+        let Self {
+            client,
+            calendar_id,
+            event_id,
+            destination,
+            send_notifications,
+            send_updates,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !destination.is_empty() {
+            query_args.push(("destination".to_string(), destination.to_string()));
+        }
+        if let Some(v) = send_notifications {
+            query_args.push(("sendNotifications".to_string(), v.to_string()));
+        }
+        if let Some(v) = send_updates {
+            query_args.push(("sendUpdates".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/calendars/{}/events/{}/move?{}",
+                crate::progenitor_support::encode_path(calendar_id),
+                crate::progenitor_support::encode_path(event_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct Events {
     pub client: Client,
 }
@@ -151,6 +1303,34 @@ impl Events {
             resp.body.items.to_vec(),
         ))
     }
+    /**
+     * This function performs a `GET` to the `/calendars/{calendarId}/events` endpoint.
+     *
+     * As opposed to `list`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Returns events on the specified calendar.
+     */
+    pub fn list_request<'a>(&'a self, calendar_id: &'a str) -> EventsListRequest<'a> {
+        EventsListRequest {
+            client: self,
+            calendar_id,
+            i_cal_uid: None,
+            max_attendees: None,
+            max_results: None,
+            order_by: None,
+            page_token: None,
+            private_extended_property: None,
+            q: None,
+            shared_extended_property: None,
+            show_deleted: None,
+            show_hidden_invitations: None,
+            single_events: None,
+            time_max: None,
+            time_min: None,
+            time_zone: None,
+            updated_min: None,
+        }
+    }
     /**
      * This function performs a `GET` to the `/calendars/{calendarId}/events` endpoint.
      *
@@ -233,11 +1413,11 @@ impl Events {
             ),
             None,
         );
-        let crate::Response::<crate::types::Events> {
-            mut status,
-            mut headers,
-            mut body,
-        } = self
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let mut pages = crate::utils::Pages::new(self.client.page_limits);
+        pages.visit(&url)?;
+
+        let mut response: crate::Response<crate::types::Events> = self
             .client
             .get(
                 &url,
@@ -247,55 +1427,138 @@ impl Events {
                 },
             )
             .await?;
-
-        let mut items = body.items;
-        let mut page = body.next_page_token;
+        let mut items = Vec::new();
 
         // Paginate if we should.
-        while !page.is_empty() {
-            if !url.contains('?') {
-                crate::Response::<crate::types::Events> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}?pageToken={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            } else {
-                crate::Response::<crate::types::Events> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}&pageToken={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
-
-            items.append(&mut body.items);
-
-            if !body.next_page_token.is_empty() && body.next_page_token != page {
-                page = body.next_page_token.to_string();
+        loop {
+            let page = if response.body.next_page_token.is_empty() {
+                None
             } else {
-                page = "".to_string();
-            }
+                Some(format!(
+                    "{}{}pageToken={}",
+                    url, sep, response.body.next_page_token
+                ))
+            };
+            let more = pages.keep(&mut response.body.items);
+            items.append(&mut response.body.items);
+
+            let next = match page {
+                Some(next) if more && pages.visit(&next)? => next,
+                _ => break,
+            };
+            response = self
+                .client
+                .get(
+                    &next,
+                    crate::Message {
+                        body: None,
+                        content_type: None,
+                    },
+                )
+                .await?;
         }
 
         // Return our response data.
-        Ok(crate::Response::new(status, headers, items))
+        Ok(crate::Response::new(
+            response.status,
+            response.headers,
+            items,
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/calendars/{calendarId}/events` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Returns events on the specified calendar.
+     */
+    pub fn list_all_stream(
+        &self,
+        calendar_id: &str,
+        i_cal_uid: &str,
+        max_attendees: i64,
+        order_by: crate::types::OrderBy,
+        private_extended_property: &[String],
+        q: &str,
+        shared_extended_property: &[String],
+        show_deleted: bool,
+        show_hidden_invitations: bool,
+        single_events: bool,
+        time_max: &str,
+        time_min: &str,
+        time_zone: &str,
+        updated_min: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Event>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !i_cal_uid.is_empty() {
+            query_args.push(("iCalUID".to_string(), i_cal_uid.to_string()));
+        }
+        if max_attendees > 0 {
+            query_args.push(("maxAttendees".to_string(), max_attendees.to_string()));
+        }
+        if !order_by.to_string().is_empty() {
+            query_args.push(("orderBy".to_string(), order_by.to_string()));
+        }
+        if !private_extended_property.is_empty() {
+            query_args.push((
+                "privateExtendedProperty".to_string(),
+                private_extended_property.join(" "),
+            ));
+        }
+        if !q.is_empty() {
+            query_args.push(("q".to_string(), q.to_string()));
+        }
+        if !shared_extended_property.is_empty() {
+            query_args.push((
+                "sharedExtendedProperty".to_string(),
+                shared_extended_property.join(" "),
+            ));
+        }
+        if show_deleted {
+            query_args.push(("showDeleted".to_string(), show_deleted.to_string()));
+        }
+        if show_hidden_invitations {
+            query_args.push((
+                "showHiddenInvitations".to_string(),
+                show_hidden_invitations.to_string(),
+            ));
+        }
+        if single_events {
+            query_args.push(("singleEvents".to_string(), single_events.to_string()));
+        }
+        if !time_max.is_empty() {
+            query_args.push(("timeMax".to_string(), time_max.to_string()));
+        }
+        if !time_min.is_empty() {
+            query_args.push(("timeMin".to_string(), time_min.to_string()));
+        }
+        if !time_zone.is_empty() {
+            query_args.push(("timeZone".to_string(), time_zone.to_string()));
+        }
+        if !updated_min.is_empty() {
+            query_args.push(("updatedMin".to_string(), updated_min.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/calendars/{}/events?{}",
+                crate::progenitor_support::encode_path(calendar_id),
+                query_
+            ),
+            None,
+        );
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let next = format!("{}{}pageToken=", url, sep);
+        self.client
+            .get_pages_stream(url, move |body: crate::types::Events| {
+                let page = if body.next_page_token.is_empty() {
+                    None
+                } else {
+                    Some(format!("{}{}", next, body.next_page_token))
+                };
+
+                (body.items, page)
+            })
     }
     /**
      * This function performs a `POST` to the `/calendars/{calendarId}/events` endpoint.
@@ -367,6 +1630,29 @@ impl Events {
             )
             .await
     }
+    /**
+     * This function performs a `POST` to the `/calendars/{calendarId}/events` endpoint.
+     *
+     * As opposed to `insert`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Creates an event.
+     */
+    pub fn insert_request<'a>(
+        &'a self,
+        calendar_id: &'a str,
+        body: &'a crate::types::Event,
+    ) -> EventsInsertRequest<'a> {
+        EventsInsertRequest {
+            client: self,
+            calendar_id,
+            conference_data_version: None,
+            max_attendees: None,
+            send_notifications: None,
+            send_updates: None,
+            supports_attachments: None,
+            body,
+        }
+    }
     /**
      * This function performs a `POST` to the `/calendars/{calendarId}/events/import` endpoint.
      *
@@ -417,6 +1703,26 @@ impl Events {
             )
             .await
     }
+    /**
+     * This function performs a `POST` to the `/calendars/{calendarId}/events/import` endpoint.
+     *
+     * As opposed to `import`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Imports an event. This operation is used to add a private copy of an existing event to a calendar.
+     */
+    pub fn import_request<'a>(
+        &'a self,
+        calendar_id: &'a str,
+        body: &'a crate::types::Event,
+    ) -> EventsImportRequest<'a> {
+        EventsImportRequest {
+            client: self,
+            calendar_id,
+            conference_data_version: None,
+            supports_attachments: None,
+            body,
+        }
+    }
     /**
      * This function performs a `POST` to the `/calendars/{calendarId}/events/quickAdd` endpoint.
      *
@@ -470,6 +1776,26 @@ impl Events {
             )
             .await
     }
+    /**
+     * This function performs a `POST` to the `/calendars/{calendarId}/events/quickAdd` endpoint.
+     *
+     * As opposed to `quick_add`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Creates an event based on a simple text string.
+     */
+    pub fn quick_add_request<'a>(
+        &'a self,
+        calendar_id: &'a str,
+        text: &'a str,
+    ) -> EventsQuickAddRequest<'a> {
+        EventsQuickAddRequest {
+            client: self,
+            calendar_id,
+            text,
+            send_notifications: None,
+            send_updates: None,
+        }
+    }
     /**
      * This function performs a `POST` to the `/calendars/{calendarId}/events/watch` endpoint.
      *
@@ -603,6 +1929,39 @@ impl Events {
             )
             .await
     }
+    /**
+     * This function performs a `POST` to the `/calendars/{calendarId}/events/watch` endpoint.
+     *
+     * As opposed to `watch`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Watch for changes to Events resources.
+     */
+    pub fn watch_request<'a>(
+        &'a self,
+        calendar_id: &'a str,
+        body: &'a crate::types::Channel,
+    ) -> EventsWatchRequest<'a> {
+        EventsWatchRequest {
+            client: self,
+            calendar_id,
+            i_cal_uid: None,
+            max_attendees: None,
+            max_results: None,
+            order_by: None,
+            page_token: None,
+            private_extended_property: None,
+            q: None,
+            shared_extended_property: None,
+            show_deleted: None,
+            show_hidden_invitations: None,
+            single_events: None,
+            time_max: None,
+            time_min: None,
+            time_zone: None,
+            updated_min: None,
+            body,
+        }
+    }
     /**
      * This function performs a `GET` to the `/calendars/{calendarId}/events/{eventId}` endpoint.
      *
@@ -650,6 +2009,26 @@ impl Events {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/calendars/{calendarId}/events/{eventId}` endpoint.
+     *
+     * As opposed to `get`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Returns an event.
+     */
+    pub fn get_request<'a>(
+        &'a self,
+        calendar_id: &'a str,
+        event_id: &'a str,
+    ) -> EventsGetRequest<'a> {
+        EventsGetRequest {
+            client: self,
+            calendar_id,
+            event_id,
+            max_attendees: None,
+            time_zone: None,
+        }
+    }
     /**
      * This function performs a `PUT` to the `/calendars/{calendarId}/events/{eventId}` endpoint.
      *
@@ -724,6 +2103,31 @@ impl Events {
             )
             .await
     }
+    /**
+     * This function performs a `PUT` to the `/calendars/{calendarId}/events/{eventId}` endpoint.
+     *
+     * As opposed to `update`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Updates an event.
+     */
+    pub fn update_request<'a>(
+        &'a self,
+        calendar_id: &'a str,
+        event_id: &'a str,
+        body: &'a crate::types::Event,
+    ) -> EventsUpdateRequest<'a> {
+        EventsUpdateRequest {
+            client: self,
+            calendar_id,
+            event_id,
+            conference_data_version: None,
+            max_attendees: None,
+            send_notifications: None,
+            send_updates: None,
+            supports_attachments: None,
+            body,
+        }
+    }
     /**
      * This function performs a `DELETE` to the `/calendars/{calendarId}/events/{eventId}` endpoint.
      *
@@ -775,6 +2179,26 @@ impl Events {
             )
             .await
     }
+    /**
+     * This function performs a `DELETE` to the `/calendars/{calendarId}/events/{eventId}` endpoint.
+     *
+     * As opposed to `delete`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Deletes an event.
+     */
+    pub fn delete_request<'a>(
+        &'a self,
+        calendar_id: &'a str,
+        event_id: &'a str,
+    ) -> EventsDeleteRequest<'a> {
+        EventsDeleteRequest {
+            client: self,
+            calendar_id,
+            event_id,
+            send_notifications: None,
+            send_updates: None,
+        }
+    }
     /**
      * This function performs a `PATCH` to the `/calendars/{calendarId}/events/{eventId}` endpoint.
      *
@@ -849,6 +2273,31 @@ impl Events {
             )
             .await
     }
+    /**
+     * This function performs a `PATCH` to the `/calendars/{calendarId}/events/{eventId}` endpoint.
+     *
+     * As opposed to `patch`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Updates an event. This method supports patch semantics.
+     */
+    pub fn patch_request<'a>(
+        &'a self,
+        calendar_id: &'a str,
+        event_id: &'a str,
+        body: &'a crate::types::Event,
+    ) -> EventsPatchRequest<'a> {
+        EventsPatchRequest {
+            client: self,
+            calendar_id,
+            event_id,
+            conference_data_version: None,
+            max_attendees: None,
+            send_notifications: None,
+            send_updates: None,
+            supports_attachments: None,
+            body,
+        }
+    }
     /**
      * This function performs a `GET` to the `/calendars/{calendarId}/events/{eventId}/instances` endpoint.
      *
@@ -934,6 +2383,32 @@ impl Events {
             resp.body.items.to_vec(),
         ))
     }
+    /**
+     * This function performs a `GET` to the `/calendars/{calendarId}/events/{eventId}/instances` endpoint.
+     *
+     * As opposed to `instances`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Returns instances of the specified recurring event.
+     */
+    pub fn instances_request<'a>(
+        &'a self,
+        calendar_id: &'a str,
+        event_id: &'a str,
+    ) -> EventsInstancesRequest<'a> {
+        EventsInstancesRequest {
+            client: self,
+            calendar_id,
+            event_id,
+            max_attendees: None,
+            max_results: None,
+            original_start: None,
+            page_token: None,
+            show_deleted: None,
+            time_max: None,
+            time_min: None,
+            time_zone: None,
+        }
+    }
     /**
      * This function performs a `GET` to the `/calendars/{calendarId}/events/{eventId}/instances` endpoint.
      *
@@ -981,11 +2456,11 @@ impl Events {
             ),
             None,
         );
-        let crate::Response::<crate::types::Events> {
-            mut status,
-            mut headers,
-            mut body,
-        } = self
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let mut pages = crate::utils::Pages::new(self.client.page_limits);
+        pages.visit(&url)?;
+
+        let mut response: crate::Response<crate::types::Events> = self
             .client
             .get(
                 &url,
@@ -995,55 +2470,103 @@ impl Events {
                 },
             )
             .await?;
-
-        let mut items = body.items;
-        let mut page = body.next_page_token;
+        let mut items = Vec::new();
 
         // Paginate if we should.
-        while !page.is_empty() {
-            if !url.contains('?') {
-                crate::Response::<crate::types::Events> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}?pageToken={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            } else {
-                crate::Response::<crate::types::Events> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}&pageToken={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
-
-            items.append(&mut body.items);
-
-            if !body.next_page_token.is_empty() && body.next_page_token != page {
-                page = body.next_page_token.to_string();
+        loop {
+            let page = if response.body.next_page_token.is_empty() {
+                None
             } else {
-                page = "".to_string();
-            }
+                Some(format!(
+                    "{}{}pageToken={}",
+                    url, sep, response.body.next_page_token
+                ))
+            };
+            let more = pages.keep(&mut response.body.items);
+            items.append(&mut response.body.items);
+
+            let next = match page {
+                Some(next) if more && pages.visit(&next)? => next,
+                _ => break,
+            };
+            response = self
+                .client
+                .get(
+                    &next,
+                    crate::Message {
+                        body: None,
+                        content_type: None,
+                    },
+                )
+                .await?;
         }
 
         // Return our response data.
-        Ok(crate::Response::new(status, headers, items))
+        Ok(crate::Response::new(
+            response.status,
+            response.headers,
+            items,
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/calendars/{calendarId}/events/{eventId}/instances` endpoint.
+     *
+     * As opposed to `get_all_instances`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Returns instances of the specified recurring event.
+     */
+    pub fn get_all_instances_stream(
+        &self,
+        calendar_id: &str,
+        event_id: &str,
+        max_attendees: i64,
+        original_start: &str,
+        show_deleted: bool,
+        time_max: &str,
+        time_min: &str,
+        time_zone: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Event>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if max_attendees > 0 {
+            query_args.push(("maxAttendees".to_string(), max_attendees.to_string()));
+        }
+        if !original_start.is_empty() {
+            query_args.push(("originalStart".to_string(), original_start.to_string()));
+        }
+        if show_deleted {
+            query_args.push(("showDeleted".to_string(), show_deleted.to_string()));
+        }
+        if !time_max.is_empty() {
+            query_args.push(("timeMax".to_string(), time_max.to_string()));
+        }
+        if !time_min.is_empty() {
+            query_args.push(("timeMin".to_string(), time_min.to_string()));
+        }
+        if !time_zone.is_empty() {
+            query_args.push(("timeZone".to_string(), time_zone.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/calendars/{}/events/{}/instances?{}",
+                crate::progenitor_support::encode_path(calendar_id),
+                crate::progenitor_support::encode_path(event_id),
+                query_
+            ),
+            None,
+        );
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let next = format!("{}{}pageToken=", url, sep);
+        self.client
+            .get_pages_stream(url, move |body: crate::types::Events| {
+                let page = if body.next_page_token.is_empty() {
+                    None
+                } else {
+                    Some(format!("{}{}", next, body.next_page_token))
+                };
+
+                (body.items, page)
+            })
     }
     /**
      * This function performs a `POST` to the `/calendars/{calendarId}/events/{eventId}/move` endpoint.
@@ -1101,4 +2624,26 @@ impl Events {
             )
             .await
     }
+    /**
+     * This function performs a `POST` to the `/calendars/{calendarId}/events/{eventId}/move` endpoint.
+     *
+     * As opposed to `mv`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Moves an event to another calendar, i.e. changes an event's organizer.
+     */
+    pub fn mv_request<'a>(
+        &'a self,
+        calendar_id: &'a str,
+        event_id: &'a str,
+        destination: &'a str,
+    ) -> EventsMvRequest<'a> {
+        EventsMvRequest {
+            client: self,
+            calendar_id,
+            event_id,
+            destination,
+            send_notifications: None,
+            send_updates: None,
+        }
+    }
 }
@@ -111,19 +111,30 @@
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
 pub mod acl;
+#[cfg(feature = "blocking")]
+#[cfg_attr(docsrs, doc(cfg(feature = "blocking")))]
+pub mod blocking;
 pub mod calendar_list;
 pub mod calendars;
 pub mod channels;
 pub mod colors;
+pub mod errors;
 pub mod events;
 pub mod freebusy;
+pub mod middleware;
+pub mod push_notifications;
+pub mod ratelimit;
+pub mod service_account;
 pub mod settings;
+pub mod sync;
 pub mod types;
 #[doc(hidden)]
 pub mod utils;
 
 pub use reqwest::{header::HeaderMap, StatusCode};
 
+pub use crate::{errors::ApiError, middleware::ClientBuilder, ratelimit::RateLimitInfo};
+
 #[derive(Debug)]
 pub struct Response<T> {
     pub status: reqwest::StatusCode,
@@ -139,6 +150,11 @@ impl<T> Response<T> {
             body,
         }
     }
+
+    /// Returns the state of the rate limit of the API reported by the response, if any.
+    pub fn rate_limit(&self) -> Option<RateLimitInfo> {
+        RateLimitInfo::from_headers(&self.headers)
+    }
 }
 
 type ClientResult<T> = Result<T, ClientError>;
@@ -155,12 +171,36 @@ pub enum ClientError {
     /// utf8 convertion error
     #[error(transparent)]
     FromUtf8Error(#[from] std::string::FromUtf8Error),
+    /// JWT errors from service_account.rs
+    #[error(transparent)]
+    JsonWebTokenError(#[from] jsonwebtoken::errors::Error),
+    /// A listing stopped before its last page, as the API handed back a page token or
+    /// url it already did, or the page limits of the client were reached where the
+    /// listing cannot be returned without its last page
+    #[error("Listing stopped before its last page, at page token {page_token:?}")]
+    IncompletePages { page_token: String },
+    /// The API rate limited the request. Its body is parsed into an `ApiError` when it
+    /// holds one, and `duration` is how many seconds the API asked to wait, if it said.
+    #[error(
+        "Rate limited. Code: {status}{}",
+        .duration.map(|duration| format!(", retry in {} seconds", duration)).unwrap_or_default()
+    )]
+    RateLimited {
+        status: http::StatusCode,
+        headers: reqwest::header::HeaderMap,
+        duration: Option<u64>,
+        info: crate::RateLimitInfo,
+        error: Option<Box<crate::ApiError>>,
+    },
     /// URL Parsing Error
     #[error(transparent)]
     UrlParserError(#[from] url::ParseError),
     /// Serde JSON parsing error
     #[error(transparent)]
     SerdeJsonError(#[from] serde_json::Error),
+    /// Serde form body encoding error
+    #[error(transparent)]
+    SerdeUrlencodedError(#[from] serde_urlencoded::ser::Error),
     /// Errors returned by reqwest
     #[error(transparent)]
     ReqwestError(#[from] reqwest::Error),
@@ -170,6 +210,13 @@ pub enum ClientError {
     /// Errors returned by reqwest middleware
     #[error(transparent)]
     ReqwestMiddleWareError(#[from] reqwest_middleware::Error),
+    /// Error returned by the API, parsed from the body of the response
+    #[error("API Error. Code: {status}, message: {error}")]
+    ApiError {
+        status: http::StatusCode,
+        headers: reqwest::header::HeaderMap,
+        error: Box<crate::ApiError>,
+    },
     /// Generic HTTP Error
     #[error("HTTP Error. Code: {status}, message: {error}")]
     HttpError {
@@ -231,11 +278,13 @@ impl RootDefaultServer {
 pub struct Client {
     host: String,
     host_override: Option<String>,
+    page_limits: crate::utils::PageLimits,
     token: Arc<RwLock<InnerToken>>,
     client_id: String,
     client_secret: String,
     redirect_uri: String,
 
+    service_account: Option<Arc<crate::service_account::ServiceAccount>>,
     auto_refresh: bool,
     client: reqwest_middleware::ClientWithMiddleware,
 }
@@ -310,29 +359,15 @@ impl Client {
         T: ToString,
         Q: ToString,
     {
-        // Retry up to 3 times with increasing intervals between attempts.
-        let retry_policy =
-            reqwest_retry::policies::ExponentialBackoff::builder().build_with_max_retries(3);
-        let client = reqwest::Client::builder()
-            .redirect(reqwest::redirect::Policy::none())
-            .build();
+        let client = crate::ClientBuilder::default().build();
         match client {
-            Ok(c) => {
-                let client = reqwest_middleware::ClientBuilder::new(c)
-                    // Trace HTTP requests. See the tracing crate to make use of these traces.
-                    .with(reqwest_tracing::TracingMiddleware::default())
-                    // Retry failed requests.
-                    .with(reqwest_conditional_middleware::ConditionalMiddleware::new(
-                        reqwest_retry::RetryTransientMiddleware::new_with_policy(retry_policy),
-                        |req: &reqwest::Request| req.try_clone().is_some(),
-                    ))
-                    .build();
-
+            Ok(client) => {
                 let host = RootDefaultServer::default().default_url().to_string();
 
                 Client {
                     host,
                     host_override: None,
+                    page_limits: Default::default(),
                     client_id: client_id.to_string(),
                     client_secret: client_secret.to_string(),
                     redirect_uri: redirect_uri.to_string(),
@@ -342,6 +377,7 @@ impl Client {
                         expires_at: None,
                     })),
 
+                    service_account: None,
                     auto_refresh: false,
                     client,
                 }
@@ -429,6 +465,30 @@ impl Client {
         self.host_override.as_deref()
     }
 
+    /// Replaces the HTTP stack of the client with one built by `builder`, to configure
+    /// its retries, timeouts and middleware.
+    pub fn with_client_builder(
+        &mut self,
+        builder: crate::ClientBuilder,
+    ) -> ClientResult<&mut Self> {
+        self.client = builder.build()?;
+        Ok(self)
+    }
+
+    /// Stop walking the pages of a paginated endpoint after `max_pages` pages have been
+    /// fetched. The first page is always fetched, so a limit of zero behaves like one.
+    pub fn with_max_pages(&mut self, max_pages: usize) -> &mut Self {
+        self.page_limits.max_pages = Some(max_pages.max(1));
+        self
+    }
+
+    /// Stop walking the pages of a paginated endpoint once `max_items` items have been
+    /// returned.
+    pub fn with_max_items(&mut self, max_items: usize) -> &mut Self {
+        self.page_limits.max_items = Some(max_items);
+        self
+    }
+
     pub(crate) fn url(&self, path: &str, host: Option<&str>) -> String {
         format!(
             "{}{}",
@@ -461,29 +521,16 @@ impl Client {
         let secret = yup_oauth2::parse_application_secret(decoded_google_key)
             .expect("failed to read from google credential env var");
 
-        let client = reqwest::Client::builder()
-            .redirect(reqwest::redirect::Policy::none())
-            .build();
-        let retry_policy =
-            reqwest_retry::policies::ExponentialBackoff::builder().build_with_max_retries(3);
+        let client = crate::ClientBuilder::default().build();
 
         match client {
-            Ok(c) => {
-                let client = reqwest_middleware::ClientBuilder::new(c)
-                    // Trace HTTP requests. See the tracing crate to make use of these traces.
-                    .with(reqwest_tracing::TracingMiddleware::default())
-                    // Retry failed requests.
-                    .with(reqwest_conditional_middleware::ConditionalMiddleware::new(
-                        reqwest_retry::RetryTransientMiddleware::new_with_policy(retry_policy),
-                        |req: &reqwest::Request| req.try_clone().is_some(),
-                    ))
-                    .build();
-
+            Ok(client) => {
                 let host = RootDefaultServer::default().default_url().to_string();
 
                 Client {
                     host,
                     host_override: None,
+                    page_limits: Default::default(),
                     client_id: secret.client_id.to_string(),
                     client_secret: secret.client_secret.to_string(),
                     redirect_uri: secret.redirect_uris[0].to_string(),
@@ -492,6 +539,7 @@ impl Client {
                         refresh_token: refresh_token.to_string(),
                         expires_at: None,
                     })),
+                    service_account: None,
                     auto_refresh: false,
                     client,
                 }
@@ -521,6 +569,10 @@ impl Client {
     /// Refresh an access token from a refresh token. Client must have a refresh token
     /// for this to work.
     pub async fn refresh_access_token(&self) -> ClientResult<AccessToken> {
+        if let Some(service_account) = &self.service_account {
+            return self.refresh_service_account_token(service_account).await;
+        }
+
         let response = {
             let refresh_token = &self.token.read().await.refresh_token;
 
@@ -717,21 +769,7 @@ impl Client {
             };
             Ok(crate::Response::new(status, headers, parsed_response))
         } else {
-            let error = if response_body.is_empty() {
-                ClientError::HttpError {
-                    status,
-                    headers,
-                    error: "empty response".into(),
-                }
-            } else {
-                ClientError::HttpError {
-                    status,
-                    headers,
-                    error: String::from_utf8_lossy(&response_body).into(),
-                }
-            };
-
-            Err(error)
+            Err(ClientError::from_response(status, headers, &response_body))
         }
     }
 
@@ -770,20 +808,7 @@ impl Client {
             };
             Ok((link, crate::Response::new(status, headers, parsed_response)))
         } else {
-            let error = if response_body.is_empty() {
-                ClientError::HttpError {
-                    status,
-                    headers,
-                    error: "empty response".into(),
-                }
-            } else {
-                ClientError::HttpError {
-                    status,
-                    headers,
-                    error: String::from_utf8_lossy(&response_body).into(),
-                }
-            };
-            Err(error)
+            Err(ClientError::from_response(status, headers, &response_body))
         }
     }
 
@@ -837,21 +862,7 @@ impl Client {
             };
             Ok(crate::Response::new(status, headers, parsed_response))
         } else {
-            let error = if response_body.is_empty() {
-                ClientError::HttpError {
-                    status,
-                    headers,
-                    error: "empty response".into(),
-                }
-            } else {
-                ClientError::HttpError {
-                    status,
-                    headers,
-                    error: String::from_utf8_lossy(&response_body).into(),
-                }
-            };
-
-            Err(error)
+            Err(ClientError::from_response(status, headers, &response_body))
         }
     }
 
@@ -904,21 +915,7 @@ impl Client {
             };
             Ok(crate::Response::new(status, headers, parsed_response))
         } else {
-            let error = if response_body.is_empty() {
-                ClientError::HttpError {
-                    status,
-                    headers,
-                    error: "empty response".into(),
-                }
-            } else {
-                ClientError::HttpError {
-                    status,
-                    headers,
-                    error: String::from_utf8_lossy(&response_body).into(),
-                }
-            };
-
-            Err(error)
+            Err(ClientError::from_response(status, headers, &response_body))
         }
     }
 
@@ -988,21 +985,7 @@ impl Client {
             };
             Ok(crate::Response::new(status, headers, parsed_response))
         } else {
-            let error = if response_body.is_empty() {
-                ClientError::HttpError {
-                    status,
-                    headers,
-                    error: "empty response".into(),
-                }
-            } else {
-                ClientError::HttpError {
-                    status,
-                    headers,
-                    error: String::from_utf8_lossy(&response_body).into(),
-                }
-            };
-
-            Err(error)
+            Err(ClientError::from_response(status, headers, &response_body))
         }
     }
 
@@ -1036,24 +1019,104 @@ impl Client {
         self.unfold(uri).await
     }
 
+    /// Lazily walk the pages of a `Link` header paginated endpoint, yielding
+    /// each item as it is received.
+    #[allow(dead_code)]
+    fn get_all_pages_stream<'a, D>(
+        &'a self,
+        uri: String,
+    ) -> impl futures::Stream<Item = ClientResult<D>> + Unpin + 'a
+    where
+        D: serde::de::DeserializeOwned + 'static + Send + 'a,
+    {
+        use futures::TryStreamExt;
+
+        Box::pin(
+            futures::stream::try_unfold(
+                (Some(uri), crate::utils::Pages::new(self.page_limits)),
+                move |(uri, mut pages)| async move {
+                    let uri = match uri {
+                        Some(uri) if pages.visit(&uri)? => uri,
+                        _ => return Ok(None),
+                    };
+
+                    let (link, mut response) = self.get_pages::<D>(&uri).await?;
+                    let more = pages.keep(&mut response.body);
+                    let items = futures::stream::iter(response.body.into_iter().map(Ok));
+
+                    ClientResult::Ok(Some((items, (link.filter(|_| more).map(|l| l.0), pages))))
+                },
+            )
+            .try_flatten(),
+        )
+    }
+
+    /// Lazily walk the pages of an endpoint that paginates through its response
+    /// body, yielding each item as it is received.
+    ///
+    /// `next` splits every page into its items and the url of the page that
+    /// follows it, if there is one.
+    #[allow(dead_code)]
+    fn get_pages_stream<'a, R, D, F>(
+        &'a self,
+        uri: String,
+        next: F,
+    ) -> impl futures::Stream<Item = ClientResult<D>> + Unpin + 'a
+    where
+        R: serde::de::DeserializeOwned + 'static + Send,
+        D: 'a,
+        F: FnMut(R) -> (Vec<D>, Option<String>) + 'a,
+    {
+        use futures::TryStreamExt;
+
+        Box::pin(
+            futures::stream::try_unfold(
+                (Some(uri), next, crate::utils::Pages::new(self.page_limits)),
+                move |(uri, mut next, mut pages)| async move {
+                    let uri = match uri {
+                        Some(uri) if pages.visit(&uri)? => uri,
+                        _ => return Ok(None),
+                    };
+
+                    let response: crate::Response<R> = self.get(&uri, Message::default()).await?;
+                    let (mut items, uri) = next(response.body);
+                    let more = pages.keep(&mut items);
+                    let items = futures::stream::iter(items.into_iter().map(Ok));
+
+                    ClientResult::Ok(Some((items, (uri.filter(|_| more), next, pages))))
+                },
+            )
+            .try_flatten(),
+        )
+    }
+
     /// "unfold" paginated results of a vector of items
+    ///
+    /// Pages are followed until the server stops returning a next link, returns an
+    /// empty page or the client's page limits are reached. The walk fails with
+    /// `ClientError::IncompletePages` if the server points back at a page that was
+    /// already fetched.
     #[allow(dead_code)]
     async fn unfold<D>(&self, uri: &str) -> ClientResult<crate::Response<Vec<D>>>
     where
         D: serde::de::DeserializeOwned + 'static + Send,
     {
+        let mut pages = crate::utils::Pages::new(self.page_limits);
+        pages.visit(uri)?;
+
         let mut global_items = Vec::new();
-        let (new_link, mut response) = self.get_pages(uri).await?;
-        let mut link = new_link;
-        while !response.body.is_empty() {
+        let (mut link, mut response) = self.get_pages(uri).await?;
+        loop {
+            let more = pages.keep(&mut response.body);
             global_items.append(&mut response.body);
             // We need to get the next link.
-            if let Some(url) = &link {
-                let url = reqwest::Url::parse(&url.0)?;
-                let (new_link, new_response) = self.get_pages_url(&url).await?;
-                link = new_link;
-                response = new_response;
-            }
+            let url = match link {
+                Some(url) if more && pages.visit(&url.0)? => reqwest::Url::parse(&url.0)?,
+                _ => break,
+            };
+            let (new_link, new_response) = self.get_pages_url(&url).await?;
+            link = new_link;
+            response = new_response;
         }
 
         Ok(Response::new(
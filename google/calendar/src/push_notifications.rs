@@ -0,0 +1,293 @@
+//! For receiving the push notifications of the channels created with `watch`, and
+//! syncing the changes they announce with a `SyncSession`.
+//!
+//! FROM: <https://developers.google.com/calendar/api/guides/push>
+
+use std::time::Duration;
+
+use crate::ClientResult;
+
+/// The header holding the id of the channel a notification was sent for.
+pub const CHANNEL_ID_HEADER: &str = "X-Goog-Channel-ID";
+
+/// The header holding the token the channel was created with.
+pub const CHANNEL_TOKEN_HEADER: &str = "X-Goog-Channel-Token";
+
+/// The header holding when the channel expires.
+pub const CHANNEL_EXPIRATION_HEADER: &str = "X-Goog-Channel-Expiration";
+
+/// The header holding the state of the watched resource.
+pub const RESOURCE_STATE_HEADER: &str = "X-Goog-Resource-State";
+
+/// The header holding the id of the watched resource.
+pub const RESOURCE_ID_HEADER: &str = "X-Goog-Resource-ID";
+
+/// The header holding the URI of the watched resource.
+pub const RESOURCE_URI_HEADER: &str = "X-Goog-Resource-URI";
+
+/// The header holding the number of the notification among those of the channel.
+pub const MESSAGE_NUMBER_HEADER: &str = "X-Goog-Message-Number";
+
+/// The header holding what changed about the watched resource.
+pub const CHANGED_HEADER: &str = "X-Goog-Changed";
+
+/// Errors returned while parsing or verifying a notification.
+#[derive(Debug, thiserror::Error)]
+pub enum NotificationError {
+    /// A header the notification needs is missing
+    #[error("missing {0} header")]
+    MissingHeader(&'static str),
+    /// A header of the notification is malformed
+    #[error("invalid {0} header")]
+    InvalidHeader(&'static str),
+    /// The token of the notification is not the one of the channel
+    #[error("the channel token does not match")]
+    TokenMismatch,
+}
+
+/// The state of the watched resource, as of a notification.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ResourceState {
+    /// The channel was just created; nothing changed yet.
+    Sync,
+    /// The resource exists, and changed.
+    Exists,
+    /// The resource was deleted.
+    NotExists,
+    Add,
+    Remove,
+    Update,
+    Trash,
+    Untrash,
+    Change,
+    Other(String),
+}
+
+impl ResourceState {
+    /// Returns whether the resource changed, and has to be synced.
+    pub fn has_changes(&self) -> bool {
+        !matches!(self, ResourceState::Sync)
+    }
+}
+
+impl From<&str> for ResourceState {
+    fn from(s: &str) -> Self {
+        match s.trim() {
+            "sync" => ResourceState::Sync,
+            "exists" => ResourceState::Exists,
+            "not_exists" => ResourceState::NotExists,
+            "add" => ResourceState::Add,
+            "remove" => ResourceState::Remove,
+            "update" => ResourceState::Update,
+            "trash" => ResourceState::Trash,
+            "untrash" => ResourceState::Untrash,
+            "change" => ResourceState::Change,
+            state => ResourceState::Other(state.to_string()),
+        }
+    }
+}
+
+/// A push notification of a channel.
+///
+/// Notifications only tell that the resource changed; the changes themselves are
+/// listed by `sync`, from the token stored by the previous sync.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Notification {
+    pub channel_id: String,
+    pub channel_token: String,
+    pub channel_expiration: Option<chrono::DateTime<chrono::Utc>>,
+    pub resource_state: ResourceState,
+    pub resource_id: String,
+    pub resource_uri: String,
+    pub message_number: u64,
+    /// What changed about the resource, like `content` or `permissions`.
+    pub changed: Vec<String>,
+}
+
+impl Notification {
+    /// Parses a notification from the headers of its request, without verifying its
+    /// token.
+    ///
+    /// Google sends everything about a notification in its `X-Goog-*` headers, the
+    /// body of the request being empty.
+    pub fn from_headers<I, K, V>(headers: I) -> Result<Self, NotificationError>
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: AsRef<str>,
+        V: AsRef<[u8]>,
+    {
+        let [id, token, expiration, state, resource_id, uri, number, changed] =
+            crate::utils::find_headers(
+                headers,
+                [
+                    CHANNEL_ID_HEADER,
+                    CHANNEL_TOKEN_HEADER,
+                    CHANNEL_EXPIRATION_HEADER,
+                    RESOURCE_STATE_HEADER,
+                    RESOURCE_ID_HEADER,
+                    RESOURCE_URI_HEADER,
+                    MESSAGE_NUMBER_HEADER,
+                    CHANGED_HEADER,
+                ],
+            )
+            .map(|value| value.map(|value| value.trim().to_string()));
+        let required = |value: Option<String>, name: &'static str| {
+            value.ok_or(NotificationError::MissingHeader(name))
+        };
+
+        let channel_expiration = match expiration {
+            Some(expiration) => Some(
+                chrono::DateTime::parse_from_rfc2822(&expiration)
+                    .map_err(|_| NotificationError::InvalidHeader(CHANNEL_EXPIRATION_HEADER))?
+                    .with_timezone(&chrono::Utc),
+            ),
+            None => None,
+        };
+        let message_number = match number {
+            Some(number) => number
+                .parse()
+                .map_err(|_| NotificationError::InvalidHeader(MESSAGE_NUMBER_HEADER))?,
+            None => 0,
+        };
+
+        Ok(Notification {
+            channel_id: required(id, CHANNEL_ID_HEADER)?,
+            channel_token: token.unwrap_or_default(),
+            channel_expiration,
+            resource_state: ResourceState::from(required(state, RESOURCE_STATE_HEADER)?.as_str()),
+            resource_id: required(resource_id, RESOURCE_ID_HEADER)?,
+            resource_uri: uri.unwrap_or_default(),
+            message_number,
+            changed: changed
+                .map(|changed| {
+                    changed
+                        .split(',')
+                        .map(|change| change.trim().to_string())
+                        .filter(|change| !change.is_empty())
+                        .collect()
+                })
+                .unwrap_or_default(),
+        })
+    }
+
+    /// Syncs the changes the notification announces with `session`.
+    ///
+    /// Yields nothing for the `sync` notification sent as the channel is created, as
+    /// nothing changed yet.
+    pub fn sync<'a, S: crate::sync::TokenStore>(
+        &self,
+        session: &'a crate::sync::SyncSession<S>,
+    ) -> impl futures::Stream<
+        Item = Result<crate::sync::SyncChange<crate::types::Event>, crate::sync::SyncError>,
+    > + Unpin
+           + 'a {
+        use futures::StreamExt;
+
+        let changes = self.resource_state.has_changes().then(|| session.sync());
+        futures::stream::iter(changes).flatten()
+    }
+
+    /// Verifies the token of the notification against the one the channel was created
+    /// with.
+    pub fn verify_token(&self, token: &str) -> Result<(), NotificationError> {
+        let (a, b) = (self.channel_token.as_bytes(), token.as_bytes());
+        // Compared without returning early, not to leak how much of the token matches.
+        let matches =
+            a.len() == b.len() && a.iter().zip(b).fold(0, |diff, (a, b)| diff | (a ^ b)) == 0;
+        if matches {
+            Ok(())
+        } else {
+            Err(NotificationError::TokenMismatch)
+        }
+    }
+}
+
+/// Receives the notifications of channels created with a token.
+#[derive(Clone)]
+pub struct Webhook {
+    token: String,
+}
+
+impl Webhook {
+    /// Create a new receiver from the token the channels were created with.
+    pub fn new<S>(token: S) -> Self
+    where
+        S: ToString,
+    {
+        Webhook {
+            token: token.to_string(),
+        }
+    }
+
+    /// Parses a notification from the headers of its request, and verifies its token.
+    pub fn construct_notification<I, K, V>(
+        &self,
+        headers: I,
+    ) -> Result<Notification, NotificationError>
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: AsRef<str>,
+        V: AsRef<[u8]>,
+    {
+        let notification = Notification::from_headers(headers)?;
+        notification.verify_token(&self.token)?;
+        Ok(notification)
+    }
+}
+
+impl crate::types::Channel {
+    /// Returns when the channel expires, if it does.
+    pub fn expires_at(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        use chrono::TimeZone;
+
+        if self.expiration <= 0 {
+            return None;
+        }
+        chrono::Utc.timestamp_millis_opt(self.expiration).single()
+    }
+
+    /// Returns whether the channel expires within `margin`, and should be renewed.
+    pub fn expires_within(&self, margin: Duration) -> bool {
+        match self.expires_at() {
+            Some(expires_at) => match (expires_at - chrono::Utc::now()).to_std() {
+                Ok(left) => left <= margin,
+                // Already expired.
+                Err(_) => true,
+            },
+            None => false,
+        }
+    }
+
+    /// Returns the body of a new channel, with a new id, to watch the same resource
+    /// at the same address once this one expires.
+    pub fn renewal(&self) -> Self {
+        crate::types::Channel {
+            id: uuid::Uuid::new_v4().to_string(),
+            expiration: 0,
+            resource_id: String::new(),
+            resource_uri: String::new(),
+            ..self.clone()
+        }
+    }
+}
+
+impl crate::channels::Channels {
+    /// Replaces a channel with a new one before it expires: watches the resource with
+    /// the `renewal` of the channel, then stops the channel.
+    ///
+    /// Channels cannot be extended. The new channel is created by `watch`, with the
+    /// body it is given, as the parameters of the watched resource are its own.
+    pub async fn renew<F, Fut>(
+        &self,
+        channel: &crate::types::Channel,
+        watch: F,
+    ) -> ClientResult<crate::Response<crate::types::Channel>>
+    where
+        F: FnOnce(crate::types::Channel) -> Fut,
+        Fut: std::future::Future<Output = ClientResult<crate::Response<crate::types::Channel>>>,
+    {
+        let renewed = watch(channel.renewal()).await?;
+        self.stop(channel).await?;
+        Ok(renewed)
+    }
+}
@@ -1,6 +1,119 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Settings::list`], set with a builder.
+pub struct SettingsListRequest<'a> {
+    client: &'a Settings,
+    max_results: Option<i64>,
+    page_token: Option<&'a str>,
+}
+
+impl<'a> SettingsListRequest<'a> {
+    /// Sets the `maxResults` query parameter.
+    pub fn max_results(mut self, max_results: i64) -> Self {
+        self.max_results = Some(max_results);
+        self
+    }
+
+    /// Sets the `pageToken` query parameter.
+    pub fn page_token(mut self, page_token: &'a str) -> Self {
+        self.page_token = Some(page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Setting>>> {
+        let Self {
+            client,
+            max_results,
+            page_token,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = max_results {
+            query_args.push(("maxResults".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_token {
+            query_args.push(("pageToken".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/users/me/settings?{}", query_), None);
+
+        let resp: crate::Response<crate::types::Settings> = client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await?;
+
+        // Return our response data.
+        Ok(crate::Response::new(
+            resp.status,
+            resp.headers,
+            resp.body.items.to_vec(),
+        ))
+    }
+}
+
+/// The parameters of [`Settings::watch`], set with a builder.
+pub struct SettingsWatchRequest<'a> {
+    client: &'a Settings,
+    max_results: Option<i64>,
+    page_token: Option<&'a str>,
+    body: &'a crate::types::Channel,
+}
+
+impl<'a> SettingsWatchRequest<'a> {
+    /// Sets the `maxResults` query parameter.
+    pub fn max_results(mut self, max_results: i64) -> Self {
+        self.max_results = Some(max_results);
+        self
+    }
+
+    /// Sets the `pageToken` query parameter.
+    pub fn page_token(mut self, page_token: &'a str) -> Self {
+        self.page_token = Some(page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::Channel>> {
+        let Self {
+            client,
+            max_results,
+            page_token,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = max_results {
+            query_args.push(("maxResults".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_token {
+            query_args.push(("pageToken".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/users/me/settings/watch?{}", query_), None);
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
 pub struct Settings {
     pub client: Client,
 }
@@ -59,6 +172,20 @@ impl Settings {
             resp.body.items.to_vec(),
         ))
     }
+    /**
+     * This function performs a `GET` to the `/users/me/settings` endpoint.
+     *
+     * As opposed to `list`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Returns all user settings for the authenticated user.
+     */
+    pub fn list_request(&self) -> SettingsListRequest<'_> {
+        SettingsListRequest {
+            client: self,
+            max_results: None,
+            page_token: None,
+        }
+    }
     /**
      * This function performs a `GET` to the `/users/me/settings` endpoint.
      *
@@ -68,11 +195,11 @@ impl Settings {
      */
     pub async fn list_all(&self) -> ClientResult<crate::Response<Vec<crate::types::Setting>>> {
         let url = self.client.url("/users/me/settings", None);
-        let crate::Response::<crate::types::Settings> {
-            mut status,
-            mut headers,
-            mut body,
-        } = self
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let mut pages = crate::utils::Pages::new(self.client.page_limits);
+        pages.visit(&url)?;
+
+        let mut response: crate::Response<crate::types::Settings> = self
             .client
             .get(
                 &url,
@@ -82,55 +209,67 @@ impl Settings {
                 },
             )
             .await?;
-
-        let mut items = body.items;
-        let mut page = body.next_page_token;
+        let mut items = Vec::new();
 
         // Paginate if we should.
-        while !page.is_empty() {
-            if !url.contains('?') {
-                crate::Response::<crate::types::Settings> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}?pageToken={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            } else {
-                crate::Response::<crate::types::Settings> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}&pageToken={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
-
-            items.append(&mut body.items);
-
-            if !body.next_page_token.is_empty() && body.next_page_token != page {
-                page = body.next_page_token.to_string();
+        loop {
+            let page = if response.body.next_page_token.is_empty() {
+                None
             } else {
-                page = "".to_string();
-            }
+                Some(format!(
+                    "{}{}pageToken={}",
+                    url, sep, response.body.next_page_token
+                ))
+            };
+            let more = pages.keep(&mut response.body.items);
+            items.append(&mut response.body.items);
+
+            let next = match page {
+                Some(next) if more && pages.visit(&next)? => next,
+                _ => break,
+            };
+            response = self
+                .client
+                .get(
+                    &next,
+                    crate::Message {
+                        body: None,
+                        content_type: None,
+                    },
+                )
+                .await?;
         }
 
         // Return our response data.
-        Ok(crate::Response::new(status, headers, items))
+        Ok(crate::Response::new(
+            response.status,
+            response.headers,
+            items,
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/users/me/settings` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Returns all user settings for the authenticated user.
+     */
+    pub fn list_all_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Setting>> + Unpin + '_ {
+        let url = self.client.url("/users/me/settings", None);
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let next = format!("{}{}pageToken=", url, sep);
+        self.client
+            .get_pages_stream(url, move |body: crate::types::Settings| {
+                let page = if body.next_page_token.is_empty() {
+                    None
+                } else {
+                    Some(format!("{}{}", next, body.next_page_token))
+                };
+
+                (body.items, page)
+            })
     }
     /**
      * This function performs a `POST` to the `/users/me/settings/watch` endpoint.
@@ -173,6 +312,24 @@ impl Settings {
             )
             .await
     }
+    /**
+     * This function performs a `POST` to the `/users/me/settings/watch` endpoint.
+     *
+     * As opposed to `watch`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Watch for changes to Settings resources.
+     */
+    pub fn watch_request<'a>(
+        &'a self,
+        body: &'a crate::types::Channel,
+    ) -> SettingsWatchRequest<'a> {
+        SettingsWatchRequest {
+            client: self,
+            max_results: None,
+            page_token: None,
+            body,
+        }
+    }
     /**
      * This function performs a `GET` to the `/users/me/settings/{setting}` endpoint.
      *
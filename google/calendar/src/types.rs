@@ -51,6 +51,10 @@ pub struct Acl {
         rename = "nextSyncToken"
     )]
     pub next_sync_token: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The extent to which calendar access is granted by this ACL rule.
@@ -75,6 +79,10 @@ pub struct Scope {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub value: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -120,6 +128,10 @@ pub struct AclRule {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub scope: Option<Scope>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -194,6 +206,10 @@ pub struct Calendar {
         rename = "timeZone"
     )]
     pub time_zone: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -245,6 +261,10 @@ pub struct CalendarList {
         rename = "nextSyncToken"
     )]
     pub next_sync_token: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The notifications that the authenticated user is receiving for this calendar.
@@ -259,6 +279,10 @@ pub struct NotificationSettings {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub notifications: Vec<CalendarNotification>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -434,6 +458,10 @@ pub struct CalendarListEntry {
         rename = "timeZone"
     )]
     pub time_zone: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -457,6 +485,10 @@ pub struct CalendarNotification {
         rename = "type"
     )]
     pub type_: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -553,6 +585,10 @@ pub struct Channel {
         rename = "type"
     )]
     pub type_: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -575,6 +611,10 @@ pub struct ColorDefinition {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub foreground: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -608,6 +648,10 @@ pub struct Colors {
         serialize_with = "crate::utils::google_calendar_date_time_format::serialize"
     )]
     pub updated: Option<chrono::DateTime<chrono::Utc>>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -666,6 +710,10 @@ pub struct ConferenceData {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub signature: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -676,11 +724,20 @@ pub struct ConferenceParameters {
         rename = "addOnParameters"
     )]
     pub add_on_parameters: Option<ConferenceParametersAddOn>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Properties that are shared between copies of the event on other attendees' calendars.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Shared {}
+pub struct Shared {
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ConferenceParametersAddOn {
@@ -689,6 +746,10 @@ pub struct ConferenceParametersAddOn {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub parameters: Option<Shared>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -707,6 +768,10 @@ pub struct ConferenceProperties {
         rename = "allowedConferenceSolutionTypes"
     )]
     pub allowed_conference_solution_types: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -721,6 +786,10 @@ pub struct ConferenceRequestStatus {
         rename = "statusCode"
     )]
     pub status_code: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -746,6 +815,10 @@ pub struct ConferenceSolution {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -760,6 +833,10 @@ pub struct ConferenceSolutionKey {
         rename = "type"
     )]
     pub type_: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -782,6 +859,10 @@ pub struct CreateConferenceRequest {
     pub request_id: String,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub status: Option<ConferenceRequestStatus>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -885,6 +966,10 @@ pub struct EntryPoint {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub uri: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -907,6 +992,10 @@ pub struct Error {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub reason: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The creator of the event. Read-only.
@@ -949,6 +1038,10 @@ pub struct Creator {
         rename = "self"
     )]
     pub self_: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Extended properties of the event.
@@ -964,6 +1057,10 @@ pub struct ExtendedProperties {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub shared: Option<Shared>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A gadget that extends this event. Gadgets are deprecated; this structure is instead only used for returning birthday calendar metadata.
@@ -1043,6 +1140,10 @@ pub struct Gadget {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub width: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The organizer of the event. If the organizer is also an attendee, this is indicated with a separate entry in attendees with the organizer field set to True. To change the organizer, use the move operation. Read-only, except when importing an event.
@@ -1085,6 +1186,10 @@ pub struct Organizer {
         rename = "self"
     )]
     pub self_: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Information about the event's reminders for the authenticated user.
@@ -1108,6 +1213,10 @@ pub struct Reminders {
         rename = "useDefault"
     )]
     pub use_default: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Source from which the event was created. For example, a web page, an email message or any document identifiable by an URL with HTTP or HTTPS scheme. Can only be seen or modified by the creator of the event.
@@ -1131,6 +1240,10 @@ pub struct Source {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -1459,6 +1572,10 @@ pub struct Event {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub visibility: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -1512,6 +1629,10 @@ pub struct EventAttachment {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub title: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -1606,6 +1727,10 @@ pub struct EventAttendee {
         rename = "self"
     )]
     pub self_: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -1640,6 +1765,10 @@ pub struct EventDateTime {
         rename = "timeZone"
     )]
     pub time_zone: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -1662,6 +1791,10 @@ pub struct EventReminder {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub minutes: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -1771,6 +1904,10 @@ pub struct Events {
         serialize_with = "crate::utils::google_calendar_date_time_format::serialize"
     )]
     pub updated: Option<chrono::DateTime<chrono::Utc>>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -1793,6 +1930,10 @@ pub struct FreeBusyCalendar {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub errors: Vec<Error>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -1819,6 +1960,10 @@ pub struct FreeBusyGroup {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub errors: Vec<Error>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -1884,6 +2029,10 @@ pub struct FreeBusyRequest {
         rename = "timeZone"
     )]
     pub time_zone: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -1897,6 +2046,10 @@ pub struct FreeBusyRequestItem {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -1942,6 +2095,10 @@ pub struct FreeBusyResponse {
         rename = "timeMin"
     )]
     pub time_min: Option<chrono::DateTime<chrono::Utc>>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -1982,6 +2139,10 @@ pub struct Setting {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub value: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -2033,6 +2194,10 @@ pub struct Settings {
         rename = "nextSyncToken"
     )]
     pub next_sync_token: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -2057,6 +2222,10 @@ pub struct TimePeriod {
         serialize_with = "crate::utils::google_calendar_date_time_format::serialize"
     )]
     pub start: Option<chrono::DateTime<chrono::Utc>>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -2069,8 +2238,9 @@ pub enum Alt {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Alt {
@@ -2078,7 +2248,7 @@ impl std::fmt::Display for Alt {
         match self {
             Alt::Json => "json",
             Alt::Noop => "",
-            Alt::FallthroughString => "*",
+            Alt::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2102,8 +2272,9 @@ pub enum OrderBy {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OrderBy {
@@ -2112,7 +2283,7 @@ impl std::fmt::Display for OrderBy {
             OrderBy::StartTime => "startTime",
             OrderBy::Updated => "updated",
             OrderBy::Noop => "",
-            OrderBy::FallthroughString => "*",
+            OrderBy::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2138,8 +2309,9 @@ pub enum SendUpdates {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SendUpdates {
@@ -2149,7 +2321,7 @@ impl std::fmt::Display for SendUpdates {
             SendUpdates::ExternalOnly => "externalOnly",
             SendUpdates::None => "none",
             SendUpdates::Noop => "",
-            SendUpdates::FallthroughString => "*",
+            SendUpdates::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2177,8 +2349,9 @@ pub enum MinAccessRole {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for MinAccessRole {
@@ -2189,7 +2362,7 @@ impl std::fmt::Display for MinAccessRole {
             MinAccessRole::Reader => "reader",
             MinAccessRole::Writer => "writer",
             MinAccessRole::Noop => "",
-            MinAccessRole::FallthroughString => "*",
+            MinAccessRole::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -11,6 +11,68 @@ pub fn next_link(l: &LinkMap) -> Option<NextLink> {
         .map(NextLink)
 }
 
+/// Caps on how much of a paginated endpoint is fetched when walking all of its pages.
+/// By default every page is fetched.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct PageLimits {
+    /// The maximum number of pages to request.
+    pub max_pages: Option<usize>,
+    /// The maximum number of items to return.
+    pub max_items: Option<usize>,
+}
+
+/// Tracks the pages walked by a paginator, so it stops once one of the `PageLimits`
+/// is reached, and fails once the server points it back at a page it already fetched.
+pub(crate) struct Pages {
+    limits: PageLimits,
+    seen: std::collections::HashSet<String>,
+    items: usize,
+}
+
+impl Pages {
+    pub(crate) fn new(limits: PageLimits) -> Self {
+        Pages {
+            limits,
+            seen: Default::default(),
+            items: 0,
+        }
+    }
+
+    /// Records a request for the page at `page`, a url or a page token. Returns `false`
+    /// if the page should not be fetched as the page limit was reached.
+    ///
+    /// Fails with `ClientError::IncompletePages` if the page was already visited, as
+    /// following it would loop forever and stopping would return a truncated listing.
+    pub(crate) fn visit(&mut self, page: &str) -> crate::ClientResult<bool> {
+        if self
+            .limits
+            .max_pages
+            .is_some_and(|max| self.seen.len() >= max)
+        {
+            return Ok(false);
+        }
+
+        if self.seen.insert(page.to_string()) {
+            Ok(true)
+        } else {
+            Err(crate::ClientError::IncompletePages {
+                page_token: page.to_string(),
+            })
+        }
+    }
+
+    /// Drops the items of a page that go over the item limit. Returns `false` once the
+    /// limit is reached, or the page was empty, and no further pages should be fetched.
+    pub(crate) fn keep<D>(&mut self, items: &mut Vec<D>) -> bool {
+        if let Some(max) = self.limits.max_items {
+            items.truncate(max.saturating_sub(self.items));
+        }
+        self.items += items.len();
+
+        !items.is_empty() && self.limits.max_items.is_none_or(|max| self.items < max)
+    }
+}
+
 pub mod date_format {
     use chrono::NaiveDate;
     use serde::{self, Deserialize, Deserializer};
@@ -633,6 +695,54 @@ pub mod deserialize_null_vector {
     }
 }
 
+/// Returns the values of the headers with the given names, matched regardless of
+/// case, out of headers given as pairs of names and values, like those of a
+/// `HeaderMap`. Only the first value of a header is kept.
+pub(crate) fn find_headers<I, K, V, const N: usize>(
+    headers: I,
+    names: [&str; N],
+) -> [Option<String>; N]
+where
+    I: IntoIterator<Item = (K, V)>,
+    K: AsRef<str>,
+    V: AsRef<[u8]>,
+{
+    let mut values = std::array::from_fn(|_| None);
+    for (name, value) in headers {
+        let name = name.as_ref();
+        if let Some(i) = names.iter().position(|n| n.eq_ignore_ascii_case(name)) {
+            values[i].get_or_insert_with(|| String::from_utf8_lossy(value.as_ref()).to_string());
+        }
+    }
+    values
+}
+
+/// Starts tracking the pages of a listing, from its first page at `page_token`.
+pub(crate) fn pages_from(limits: PageLimits, page_token: &str) -> Pages {
+    let mut pages = Pages::new(limits);
+    // The first page cannot have been visited already.
+    let _ = pages.visit(page_token);
+    pages
+}
+
+/// Returns the token of the page to fetch next, or `None` after the last page.
+///
+/// Fails once the API hands back a token it already did, not to loop forever, or
+/// the page limits are reached, as the listing would be incomplete: the token that
+/// comes with its last page, to list the changes that happen next, would be missing.
+pub(crate) fn next_page_token(
+    pages: &mut Pages,
+    token: String,
+) -> crate::ClientResult<Option<String>> {
+    if token.is_empty() {
+        return Ok(None);
+    }
+    if !pages.visit(&token)? {
+        return Err(crate::ClientError::IncompletePages { page_token: token });
+    }
+    Ok(Some(token))
+}
+
 #[cfg(test)]
 mod tests {
     use super::next_link;
@@ -647,3 +757,87 @@ mod tests {
         assert_eq!("https://next-link", next);
     }
 }
+
+#[cfg(test)]
+mod google_tests {
+    use wiremock::{
+        matchers::{method, path, query_param},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    async fn mount_items_page(server: &MockServer, page: u32, next: Option<u32>) {
+        let mut response = ResponseTemplate::new(200).set_body_json(vec![page]);
+        if let Some(next) = next {
+            response = response.insert_header(
+                "link",
+                format!("<{}/items?page={}>; rel=\"next\"", server.uri(), next).as_str(),
+            );
+        }
+
+        Mock::given(method("GET"))
+            .and(path("/items"))
+            .and(query_param("page", page.to_string()))
+            .respond_with(response)
+            .mount(server)
+            .await;
+    }
+
+    fn pages_client(server: &MockServer) -> crate::Client {
+        let mut client =
+            crate::Client::new("client_id", "client_secret", "redirect_uri", "token", "");
+        client.with_host_override(server.uri());
+        client
+    }
+
+    #[tokio::test]
+    async fn test_get_all_pages_respects_page_limits() {
+        let server = MockServer::start().await;
+        mount_items_page(&server, 1, Some(2)).await;
+        mount_items_page(&server, 2, Some(3)).await;
+        mount_items_page(&server, 3, None).await;
+
+        let mut client = pages_client(&server);
+        let url = client.url("/items?page=1", None);
+        let items: Vec<u32> = client
+            .get_all_pages(&url, crate::Message::default())
+            .await
+            .unwrap()
+            .body;
+        assert_eq!(items, vec![1, 2, 3]);
+
+        server.reset().await;
+        mount_items_page(&server, 1, Some(2)).await;
+        mount_items_page(&server, 2, Some(3)).await;
+        mount_items_page(&server, 3, None).await;
+
+        client.with_max_pages(2);
+        let items: Vec<u32> = client
+            .get_all_pages(&url, crate::Message::default())
+            .await
+            .unwrap()
+            .body;
+        assert_eq!(items, vec![1, 2]);
+        assert_eq!(server.received_requests().await.unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_get_all_pages_fails_on_repeated_next_link() {
+        let server = MockServer::start().await;
+        // The second page keeps pointing back at itself.
+        mount_items_page(&server, 1, Some(2)).await;
+        mount_items_page(&server, 2, Some(2)).await;
+
+        let client = pages_client(&server);
+        let url = client.url("/items?page=1", None);
+        match client
+            .get_all_pages::<u32>(&url, crate::Message::default())
+            .await
+        {
+            Err(crate::ClientError::IncompletePages { page_token }) => {
+                assert!(page_token.ends_with("/items?page=2"))
+            }
+            result => unreachable!("Expected incomplete pages, got {:?}", result),
+        }
+        assert_eq!(server.received_requests().await.unwrap().len(), 2);
+    }
+}
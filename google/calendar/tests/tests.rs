@@ -1 +1,364 @@
+use std::{mem, time::Duration};
 
+use futures::TryStreamExt;
+
+use wiremock::{
+    matchers::{method, path, query_param, query_param_is_missing},
+    Mock, MockServer, ResponseTemplate,
+};
+
+use google_calendar::{
+    push_notifications::{Notification, NotificationError, ResourceState, Webhook},
+    sync::{MemoryTokenStore, SyncChange, SyncError, SyncSession},
+    types::Channel,
+    Client, ClientError,
+};
+
+#[test]
+fn test_webhook_constructs_notification() {
+    let webhook = Webhook::new("tok3n");
+    let headers = [
+        ("x-goog-channel-id", "01234567-89ab-cdef-0123456789ab"),
+        ("x-goog-channel-token", "tok3n"),
+        ("x-goog-channel-expiration", "Tue, 19 Nov 2013 01:13:52 GMT"),
+        ("x-goog-resource-id", "ret08u3rv24htgh289g"),
+        (
+            "x-goog-resource-uri",
+            "https://www.googleapis.com/calendar/v3/calendars/primary/events",
+        ),
+        ("x-goog-resource-state", "exists"),
+        ("x-goog-message-number", "10"),
+    ];
+
+    let notification = webhook.construct_notification(headers).unwrap();
+    assert_eq!(notification.channel_id, "01234567-89ab-cdef-0123456789ab");
+    assert_eq!(notification.resource_state, ResourceState::Exists);
+    assert!(notification.resource_state.has_changes());
+    assert_eq!(notification.message_number, 10);
+    assert_eq!(
+        notification.channel_expiration.unwrap().timestamp(),
+        1384823632
+    );
+
+    assert!(matches!(
+        Webhook::new("other").construct_notification(headers),
+        Err(NotificationError::TokenMismatch)
+    ));
+    assert!(matches!(
+        Notification::from_headers(headers[1..].iter().copied()),
+        Err(NotificationError::MissingHeader(_))
+    ));
+}
+
+#[test]
+fn test_channel_renewal() {
+    let channel = Channel {
+        id: "old".to_string(),
+        address: "https://example.com/notifications".to_string(),
+        token: "tok3n".to_string(),
+        type_: "web_hook".to_string(),
+        resource_id: "ret08u3rv24htgh289g".to_string(),
+        expiration: (chrono::Utc::now() + chrono::Duration::minutes(30)).timestamp_millis(),
+        kind: "api#channel".to_string(),
+        params: String::new(),
+        payload: false,
+        resource_uri: String::new(),
+        #[cfg(feature = "extra-fields")]
+        extra: Default::default(),
+    };
+    assert!(channel.expires_within(Duration::from_secs(3600)));
+    assert!(!channel.expires_within(Duration::from_secs(60)));
+
+    let renewal = channel.renewal();
+    assert_ne!(renewal.id, channel.id);
+    assert_eq!(renewal.address, channel.address);
+    assert_eq!(renewal.token, channel.token);
+    assert_eq!(renewal.expiration, 0);
+    assert!(renewal.resource_id.is_empty());
+    assert!(!renewal.expires_within(Duration::from_secs(3600)));
+}
+
+#[tokio::test]
+async fn test_notification_syncs_changes_through_pages() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/calendars/primary/events"))
+        .and(query_param("syncToken", "sync1"))
+        .and(query_param("pageToken", "page2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "items": [{ "id": "2", "status": "cancelled" }],
+            "nextSyncToken": "sync2"
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/calendars/primary/events"))
+        .and(query_param("syncToken", "sync1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "items": [{ "id": "1", "status": "confirmed" }],
+            "nextPageToken": "page2"
+        })))
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut client = Client::new("client_id", "client_secret", "redirect_uri", "token", "");
+    client.with_host_override(server.uri());
+
+    let session = SyncSession::new(client, "primary", MemoryTokenStore::new("sync1"));
+    let headers = |state| {
+        [
+            ("X-Goog-Channel-ID", "01234567-89ab-cdef-0123456789ab"),
+            ("X-Goog-Resource-ID", "ret08u3rv24htgh289g"),
+            ("X-Goog-Resource-State", state),
+        ]
+    };
+
+    // Nothing changed yet as the channel is created.
+    let created = Notification::from_headers(headers("sync")).unwrap();
+    assert!(created
+        .sync(&session)
+        .try_collect::<Vec<_>>()
+        .await
+        .unwrap()
+        .is_empty());
+
+    let notification = Notification::from_headers(headers("exists")).unwrap();
+    let changes = notification
+        .sync(&session)
+        .try_collect::<Vec<_>>()
+        .await
+        .unwrap();
+    assert_eq!(changes.len(), 2);
+    assert!(matches!(&changes[0], SyncChange::Changed(event) if event.id == "1"));
+    assert_eq!(changes[1], SyncChange::Deleted("2".to_string()));
+    assert_eq!(session.store().token(), Some("sync2".to_string()));
+
+    mem::drop(server)
+}
+
+#[tokio::test]
+async fn test_sync_session_lists_changes_and_stores_token() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/calendars/primary/events"))
+        .and(query_param("syncToken", "sync1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "items": [
+                { "id": "1", "status": "confirmed" },
+                { "id": "2", "status": "cancelled" }
+            ],
+            "nextSyncToken": "sync2"
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut client = Client::new("client_id", "client_secret", "redirect_uri", "token", "");
+    client.with_host_override(server.uri());
+
+    let session = SyncSession::new(client, "primary", MemoryTokenStore::new("sync1"));
+    let changes = session.sync().try_collect::<Vec<_>>().await.unwrap();
+
+    assert_eq!(changes.len(), 2);
+    assert!(matches!(&changes[0], SyncChange::Changed(event) if event.id == "1"));
+    assert_eq!(changes[1], SyncChange::Deleted("2".to_string()));
+    assert_eq!(session.store().token(), Some("sync2".to_string()));
+
+    mem::drop(server)
+}
+
+#[tokio::test]
+async fn test_sync_session_restarts_on_gone() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/calendars/primary/events"))
+        .and(query_param("syncToken", "expired"))
+        .respond_with(ResponseTemplate::new(410).set_body_json(serde_json::json!({
+            "error": { "code": 410, "message": "Sync token is no longer valid." }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/calendars/primary/events"))
+        .and(query_param("pageToken", "page2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "items": [{ "id": "2", "status": "confirmed" }],
+            "nextSyncToken": "fresh"
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/calendars/primary/events"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "items": [{ "id": "1", "status": "confirmed" }],
+            "nextPageToken": "page2"
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut client = Client::new("client_id", "client_secret", "redirect_uri", "token", "");
+    client.with_host_override(server.uri());
+
+    let session = SyncSession::new(client, "primary", MemoryTokenStore::new("expired"));
+    let changes = session.sync().try_collect::<Vec<_>>().await.unwrap();
+
+    assert_eq!(changes.len(), 3);
+    assert_eq!(changes[0], SyncChange::Reset);
+    assert!(matches!(&changes[1], SyncChange::Added(event) if event.id == "1"));
+    assert!(matches!(&changes[2], SyncChange::Added(event) if event.id == "2"));
+    assert_eq!(session.store().token(), Some("fresh".to_string()));
+
+    mem::drop(server)
+}
+
+#[tokio::test]
+async fn test_sync_session_fails_on_repeated_page_token() {
+    let server = MockServer::start().await;
+
+    // The second page hands back the token it was requested with.
+    Mock::given(method("GET"))
+        .and(path("/calendars/primary/events"))
+        .and(query_param("syncToken", "sync1"))
+        .and(query_param("pageToken", "page2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "items": [{ "id": "2", "status": "confirmed" }],
+            "nextPageToken": "page2"
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/calendars/primary/events"))
+        .and(query_param("syncToken", "sync1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "items": [{ "id": "1", "status": "confirmed" }],
+            "nextPageToken": "page2"
+        })))
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut client = Client::new("client_id", "client_secret", "redirect_uri", "token", "");
+    client.with_host_override(server.uri());
+
+    let session = SyncSession::new(client.clone(), "primary", MemoryTokenStore::new("sync1"));
+    match session.sync().try_collect::<Vec<_>>().await {
+        Err(SyncError::ClientError(err)) => assert!(matches!(
+            *err,
+            ClientError::IncompletePages { ref page_token } if page_token == "page2"
+        )),
+        result => unreachable!("Expected incomplete pages, got {:?}", result),
+    }
+    // Without the sync token of the last page, the stored one is kept.
+    assert_eq!(session.store().token(), Some("sync1".to_string()));
+
+    // The page limits of the client cut the listing short just the same.
+    server.reset().await;
+    Mock::given(method("GET"))
+        .and(path("/calendars/primary/events"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "items": [{ "id": "1", "status": "confirmed" }],
+            "nextPageToken": "page2"
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    client.with_max_pages(1);
+    let session = SyncSession::new(client, "primary", MemoryTokenStore::new("sync1"));
+    assert!(matches!(
+        session.sync().try_collect::<Vec<_>>().await,
+        Err(SyncError::ClientError(err)) if matches!(*err, ClientError::IncompletePages { .. })
+    ));
+
+    mem::drop(server)
+}
+
+#[tokio::test]
+async fn test_list_all_keeps_query_when_following_page_tokens() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/calendars/primary/acl"))
+        .and(query_param("showDeleted", "true"))
+        .and(query_param("pageToken", "p2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "items": [{ "id": "user:b@example.com", "role": "reader" }]
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/calendars/primary/acl"))
+        .and(query_param("showDeleted", "true"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "items": [{ "id": "user:a@example.com", "role": "owner" }],
+            "nextPageToken": "p2"
+        })))
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut client = Client::new("client_id", "client_secret", "redirect_uri", "token", "");
+    client.with_host_override(server.uri());
+
+    let rules = client.acl().list_all("primary", true).await.unwrap().body;
+    let ids = rules
+        .iter()
+        .map(|rule| rule.id.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(ids, vec!["user:a@example.com", "user:b@example.com"]);
+
+    mem::drop(server)
+}
+
+#[tokio::test]
+async fn test_insert_request_sends_only_the_parameters_set() {
+    let server = MockServer::start().await;
+
+    // The conference data version is optional, so it stays out when it was not set.
+    Mock::given(method("POST"))
+        .and(path("/calendars/primary/events"))
+        .and(query_param("maxAttendees", "5"))
+        .and(query_param_is_missing("conferenceDataVersion"))
+        .and(query_param_is_missing("sendUpdates"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": "e1",
+            "summary": "Standup"
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut client = Client::new("client_id", "client_secret", "redirect_uri", "token", "");
+    client.with_host_override(server.uri());
+
+    let event = serde_json::from_value(serde_json::json!({ "summary": "Standup" })).unwrap();
+    let created = client
+        .events()
+        .insert_request("primary", &event)
+        .max_attendees(5)
+        .send()
+        .await
+        .unwrap()
+        .body;
+    assert_eq!(created.id, "e1");
+
+    mem::drop(server)
+}
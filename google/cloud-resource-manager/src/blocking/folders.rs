@@ -0,0 +1,218 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Folders::list`], set with a builder.
+pub struct FoldersListRequest<'a> {
+    inner: crate::folders::FoldersListRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> FoldersListRequest<'a> {
+    /// Sets the `pageSize` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Sets the `pageToken` query parameter.
+    pub fn page_token(mut self, page_token: &'a str) -> Self {
+        self.inner = self.inner.page_token(page_token);
+        self
+    }
+
+    /// Sets the `parent` query parameter.
+    pub fn parent(mut self, parent: &'a str) -> Self {
+        self.inner = self.inner.parent(parent);
+        self
+    }
+
+    /// Sets the `showDeleted` query parameter.
+    pub fn show_deleted(mut self, show_deleted: bool) -> Self {
+        self.inner = self.inner.show_deleted(show_deleted);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Folder>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Folders::create`], set with a builder.
+pub struct FoldersCreateRequest<'a> {
+    inner: crate::folders::FoldersCreateRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> FoldersCreateRequest<'a> {
+    /// Sets the `parent` query parameter.
+    pub fn parent(mut self, parent: &'a str) -> Self {
+        self.inner = self.inner.parent(parent);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::Operation>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Folders::patch`], set with a builder.
+pub struct FoldersPatchRequest<'a> {
+    inner: crate::folders::FoldersPatchRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> FoldersPatchRequest<'a> {
+    /// Sets the `updateMask` query parameter.
+    pub fn update_mask(mut self, update_mask: &'a str) -> Self {
+        self.inner = self.inner.update_mask(update_mask);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::Folder>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::folders::Folders`].
+pub struct Folders {
+    inner: crate::folders::Folders,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Folders {
+    pub(crate) fn new(
+        inner: crate::folders::Folders,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Folders { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::folders::Folders::list`].
+    pub fn list(
+        &self,
+        page_size: i64,
+        page_token: &str,
+        parent: &str,
+        show_deleted: bool,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Folder>>> {
+        self.runtime
+            .block_on(self.inner.list(page_size, page_token, parent, show_deleted))
+    }
+    /// The blocking version of [`crate::folders::Folders::list_request`].
+    pub fn list_request(&self) -> FoldersListRequest<'_> {
+        FoldersListRequest {
+            inner: self.inner.list_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::folders::Folders::list_all`].
+    pub fn list_all(
+        &self,
+        parent: &str,
+        show_deleted: bool,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Folder>>> {
+        self.runtime
+            .block_on(self.inner.list_all(parent, show_deleted))
+    }
+    /// The blocking version of [`crate::folders::Folders::create`].
+    pub fn create(
+        &self,
+        parent: &str,
+        body: &crate::types::Folder,
+    ) -> ClientResult<crate::Response<crate::types::Operation>> {
+        self.runtime.block_on(self.inner.create(parent, body))
+    }
+    /// The blocking version of [`crate::folders::Folders::create_request`].
+    pub fn create_request<'a>(
+        &'a self,
+        body: &'a crate::types::Folder,
+    ) -> FoldersCreateRequest<'a> {
+        FoldersCreateRequest {
+            inner: self.inner.create_request(body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::folders::Folders::search`].
+    pub fn search(
+        &self,
+        body: &crate::types::SearchFoldersRequest,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Folder>>> {
+        self.runtime.block_on(self.inner.search(body))
+    }
+    /// The blocking version of [`crate::folders::Folders::get`].
+    pub fn get(&self, name: &str) -> ClientResult<crate::Response<crate::types::Folder>> {
+        self.runtime.block_on(self.inner.get(name))
+    }
+    /// The blocking version of [`crate::folders::Folders::delete`].
+    pub fn delete(&self, name: &str) -> ClientResult<crate::Response<crate::types::Folder>> {
+        self.runtime.block_on(self.inner.delete(name))
+    }
+    /// The blocking version of [`crate::folders::Folders::patch`].
+    pub fn patch(
+        &self,
+        name: &str,
+        update_mask: &str,
+        body: &crate::types::Folder,
+    ) -> ClientResult<crate::Response<crate::types::Folder>> {
+        self.runtime
+            .block_on(self.inner.patch(name, update_mask, body))
+    }
+    /// The blocking version of [`crate::folders::Folders::patch_request`].
+    pub fn patch_request<'a>(
+        &'a self,
+        name: &'a str,
+        body: &'a crate::types::Folder,
+    ) -> FoldersPatchRequest<'a> {
+        FoldersPatchRequest {
+            inner: self.inner.patch_request(name, body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::folders::Folders::mv`].
+    pub fn mv(
+        &self,
+        name: &str,
+        body: &crate::types::MoveFolderRequest,
+    ) -> ClientResult<crate::Response<crate::types::Operation>> {
+        self.runtime.block_on(self.inner.mv(name, body))
+    }
+    /// The blocking version of [`crate::folders::Folders::undelete`].
+    pub fn undelete(
+        &self,
+        name: &str,
+        body: &crate::types::MoveProjectMetadata,
+    ) -> ClientResult<crate::Response<crate::types::Folder>> {
+        self.runtime.block_on(self.inner.undelete(name, body))
+    }
+    /// The blocking version of [`crate::folders::Folders::get_iam_policy`].
+    pub fn get_iam_policy(
+        &self,
+        resource: &str,
+        body: &crate::types::GetIamPolicyRequest,
+    ) -> ClientResult<crate::Response<crate::types::Policy>> {
+        self.runtime
+            .block_on(self.inner.get_iam_policy(resource, body))
+    }
+    /// The blocking version of [`crate::folders::Folders::set_iam_policy`].
+    pub fn set_iam_policy(
+        &self,
+        resource: &str,
+        body: &crate::types::SetIamPolicyRequest,
+    ) -> ClientResult<crate::Response<crate::types::Policy>> {
+        self.runtime
+            .block_on(self.inner.set_iam_policy(resource, body))
+    }
+    /// The blocking version of [`crate::folders::Folders::test_iam_permissions`].
+    pub fn test_iam_permissions(
+        &self,
+        resource: &str,
+        body: &crate::types::TestIamPermissionsRequest,
+    ) -> ClientResult<crate::Response<crate::types::TestIamPermissionsResponse>> {
+        self.runtime
+            .block_on(self.inner.test_iam_permissions(resource, body))
+    }
+}
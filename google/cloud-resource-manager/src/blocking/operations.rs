@@ -0,0 +1,23 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::operations::Operations`].
+pub struct Operations {
+    inner: crate::operations::Operations,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Operations {
+    pub(crate) fn new(
+        inner: crate::operations::Operations,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Operations { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::operations::Operations::get`].
+    pub fn get(&self, name: &str) -> ClientResult<crate::Response<crate::types::Operation>> {
+        self.runtime.block_on(self.inner.get(name))
+    }
+}
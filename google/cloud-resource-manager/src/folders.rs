@@ -1,6 +1,176 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Folders::list`], set with a builder.
+pub struct FoldersListRequest<'a> {
+    client: &'a Folders,
+    page_size: Option<i64>,
+    page_token: Option<&'a str>,
+    parent: Option<&'a str>,
+    show_deleted: Option<bool>,
+}
+
+impl<'a> FoldersListRequest<'a> {
+    /// Sets the `pageSize` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Sets the `pageToken` query parameter.
+    pub fn page_token(mut self, page_token: &'a str) -> Self {
+        self.page_token = Some(page_token);
+        self
+    }
+
+    /// Sets the `parent` query parameter.
+    pub fn parent(mut self, parent: &'a str) -> Self {
+        self.parent = Some(parent);
+        self
+    }
+
+    /// Sets the `showDeleted` query parameter.
+    pub fn show_deleted(mut self, show_deleted: bool) -> Self {
+        self.show_deleted = Some(show_deleted);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Folder>>> {
+        let Self {
+            client,
+            page_size,
+            page_token,
+            parent,
+            show_deleted,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page_size {
+            query_args.push(("pageSize".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_token {
+            query_args.push(("pageToken".to_string(), v.to_string()));
+        }
+        if let Some(v) = parent {
+            query_args.push(("parent".to_string(), v.to_string()));
+        }
+        if let Some(v) = show_deleted {
+            query_args.push(("showDeleted".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(&format!("/v2/folders?{}", query_), None);
+
+        let resp: crate::Response<crate::types::ListFoldersResponse> = client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await?;
+
+        // Return our response data.
+        Ok(crate::Response::new(
+            resp.status,
+            resp.headers,
+            resp.body.folders.to_vec(),
+        ))
+    }
+}
+
+/// The parameters of [`Folders::create`], set with a builder.
+pub struct FoldersCreateRequest<'a> {
+    client: &'a Folders,
+    parent: Option<&'a str>,
+    body: &'a crate::types::Folder,
+}
+
+impl<'a> FoldersCreateRequest<'a> {
+    /// Sets the `parent` query parameter.
+    pub fn parent(mut self, parent: &'a str) -> Self {
+        self.parent = Some(parent);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::Operation>> {
+        let Self {
+            client,
+            parent,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = parent {
+            query_args.push(("parent".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(&format!("/v2/folders?{}", query_), None);
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Folders::patch`], set with a builder.
+pub struct FoldersPatchRequest<'a> {
+    client: &'a Folders,
+    name: &'a str,
+    update_mask: Option<&'a str>,
+    body: &'a crate::types::Folder,
+}
+
+impl<'a> FoldersPatchRequest<'a> {
+    /// Sets the `updateMask` query parameter.
+    pub fn update_mask(mut self, update_mask: &'a str) -> Self {
+        self.update_mask = Some(update_mask);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::Folder>> {
+        let Self {
+            client,
+            name,
+            update_mask,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = update_mask {
+            query_args.push(("updateMask".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v2/{}?{}",
+                crate::progenitor_support::encode_path(name),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .patch(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
 pub struct Folders {
     pub client: Client,
 }
@@ -63,6 +233,22 @@ impl Folders {
             resp.body.folders.to_vec(),
         ))
     }
+    /**
+     * This function performs a `GET` to the `/v2/folders` endpoint.
+     *
+     * As opposed to `list`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Lists the Folders that are direct descendants of supplied parent resource. List provides a strongly consistent view of the Folders underneath the specified parent resource. List returns Folders sorted based upon the (ascending) lexical ordering of their display_name. The caller must have `resourcemanager.folders.list` permission on the identified parent.
+     */
+    pub fn list_request(&self) -> FoldersListRequest<'_> {
+        FoldersListRequest {
+            client: self,
+            page_size: None,
+            page_token: None,
+            parent: None,
+            show_deleted: None,
+        }
+    }
     /**
      * This function performs a `GET` to the `/v2/folders` endpoint.
      *
@@ -84,11 +270,11 @@ impl Folders {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/v2/folders?{}", query_), None);
-        let crate::Response::<crate::types::ListFoldersResponse> {
-            mut status,
-            mut headers,
-            mut body,
-        } = self
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let mut pages = crate::utils::Pages::new(self.client.page_limits);
+        pages.visit(&url)?;
+
+        let mut response: crate::Response<crate::types::ListFoldersResponse> = self
             .client
             .get(
                 &url,
@@ -98,55 +284,77 @@ impl Folders {
                 },
             )
             .await?;
-
-        let mut folders = body.folders;
-        let mut page = body.next_page_token;
+        let mut folders = Vec::new();
 
         // Paginate if we should.
-        while !page.is_empty() {
-            if !url.contains('?') {
-                crate::Response::<crate::types::ListFoldersResponse> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}?pageToken={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
+        loop {
+            let page = if response.body.next_page_token.is_empty() {
+                None
             } else {
-                crate::Response::<crate::types::ListFoldersResponse> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}&pageToken={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
+                Some(format!(
+                    "{}{}pageToken={}",
+                    url, sep, response.body.next_page_token
+                ))
+            };
+            let more = pages.keep(&mut response.body.folders);
+            folders.append(&mut response.body.folders);
 
-            folders.append(&mut body.folders);
-
-            if !body.next_page_token.is_empty() && body.next_page_token != page {
-                page = body.next_page_token.to_string();
-            } else {
-                page = "".to_string();
-            }
+            let next = match page {
+                Some(next) if more && pages.visit(&next)? => next,
+                _ => break,
+            };
+            response = self
+                .client
+                .get(
+                    &next,
+                    crate::Message {
+                        body: None,
+                        content_type: None,
+                    },
+                )
+                .await?;
         }
 
         // Return our response data.
-        Ok(crate::Response::new(status, headers, folders))
+        Ok(crate::Response::new(
+            response.status,
+            response.headers,
+            folders,
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v2/folders` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Lists the Folders that are direct descendants of supplied parent resource. List provides a strongly consistent view of the Folders underneath the specified parent resource. List returns Folders sorted based upon the (ascending) lexical ordering of their display_name. The caller must have `resourcemanager.folders.list` permission on the identified parent.
+     */
+    pub fn list_all_stream(
+        &self,
+        parent: &str,
+        show_deleted: bool,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Folder>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !parent.is_empty() {
+            query_args.push(("parent".to_string(), parent.to_string()));
+        }
+        if show_deleted {
+            query_args.push(("showDeleted".to_string(), show_deleted.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/v2/folders?{}", query_), None);
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let next = format!("{}{}pageToken=", url, sep);
+        self.client
+            .get_pages_stream(url, move |body: crate::types::ListFoldersResponse| {
+                let page = if body.next_page_token.is_empty() {
+                    None
+                } else {
+                    Some(format!("{}{}", next, body.next_page_token))
+                };
+
+                (body.folders, page)
+            })
     }
     /**
      * This function performs a `POST` to the `/v2/folders` endpoint.
@@ -178,6 +386,23 @@ impl Folders {
             )
             .await
     }
+    /**
+     * This function performs a `POST` to the `/v2/folders` endpoint.
+     *
+     * As opposed to `create`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Creates a Folder in the resource hierarchy. Returns an Operation which can be used to track the progress of the folder creation workflow. Upon success the Operation.response field will be populated with the created Folder. In order to succeed, the addition of this new Folder must not violate the Folder naming, height or fanout constraints. + The Folder's display_name must be distinct from all other Folders that share its parent. + The addition of the Folder must not cause the active Folder hierarchy to exceed a height of 10. Note, the full active + deleted Folder hierarchy is allowed to reach a height of 20; this provides additional headroom when moving folders that contain deleted folders. + The addition of the Folder must not cause the total number of Folders under its parent to exceed 300. If the operation fails due to a folder constraint violation, some errors may be returned by the CreateFolder request, with status code FAILED_PRECONDITION and an error description. Other folder constraint violations will be communicated in the Operation, with the specific PreconditionFailure returned via the details list in the Operation.error field. The caller must have `resourcemanager.folders.create` permission on the identified parent.
+     */
+    pub fn create_request<'a>(
+        &'a self,
+        body: &'a crate::types::Folder,
+    ) -> FoldersCreateRequest<'a> {
+        FoldersCreateRequest {
+            client: self,
+            parent: None,
+            body,
+        }
+    }
     /**
      * This function performs a `POST` to the `/v2/folders:search` endpoint.
      *
@@ -293,6 +518,25 @@ impl Folders {
             )
             .await
     }
+    /**
+     * This function performs a `PATCH` to the `/v2/{name}` endpoint.
+     *
+     * As opposed to `patch`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Updates a Folder, changing its display_name. Changes to the folder display_name will be rejected if they violate either the display_name formatting rules or naming constraints described in the CreateFolder documentation. The Folder's display name must start and end with a letter or digit, may contain letters, digits, spaces, hyphens and underscores and can be between 3 and 30 characters. This is captured by the regular expression: `\p{L}\p{N}{1,28}[\p{L}\p{N}]`. The caller must have `resourcemanager.folders.update` permission on the identified folder. If the update fails due to the unique name constraint then a PreconditionFailure explaining this violation will be returned in the Status.details field.
+     */
+    pub fn patch_request<'a>(
+        &'a self,
+        name: &'a str,
+        body: &'a crate::types::Folder,
+    ) -> FoldersPatchRequest<'a> {
+        FoldersPatchRequest {
+            client: self,
+            name,
+            update_mask: None,
+            body,
+        }
+    }
     /**
      * This function performs a `POST` to the `/v2/{name}:move` endpoint.
      *
@@ -0,0 +1,271 @@
+//! The HTTP stack the client sends its requests through.
+
+use std::{sync::Arc, time::Duration};
+
+use retry_policies::{policies::ExponentialBackoff, RetryDecision, RetryPolicy};
+
+/// Configures the HTTP stack of a `Client`: how failed requests are retried, request
+/// timeouts and any additional `reqwest_middleware` layers.
+///
+/// Requests are traced and retried by default, up to 3 times with exponential backoff,
+/// when they time out, fail to connect or get back a `408`, `429` or `5xx` response.
+#[derive(Clone)]
+pub struct ClientBuilder {
+    max_retries: u32,
+    min_retry_interval: Duration,
+    max_retry_interval: Duration,
+    retryable_statuses: Vec<http::StatusCode>,
+    respect_retry_after: bool,
+    wait_on_rate_limit: bool,
+    timeout: Option<Duration>,
+    connect_timeout: Option<Duration>,
+    middleware: Vec<Arc<dyn reqwest_middleware::Middleware>>,
+}
+
+impl Default for ClientBuilder {
+    fn default() -> Self {
+        ClientBuilder {
+            max_retries: 3,
+            min_retry_interval: Duration::from_secs(1),
+            max_retry_interval: Duration::from_secs(30 * 60),
+            retryable_statuses: [408, 429]
+                .into_iter()
+                .chain(500..600)
+                .filter_map(|status| http::StatusCode::from_u16(status).ok())
+                .collect(),
+            respect_retry_after: true,
+            wait_on_rate_limit: false,
+            timeout: None,
+            connect_timeout: None,
+            middleware: Vec::new(),
+        }
+    }
+}
+
+impl ClientBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets how many times a failed request is retried. Zero disables retries.
+    pub fn max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Sets the minimum and maximum time to wait between two attempts of a request.
+    /// Defaults to between 1 second and 30 minutes.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `min` is greater than `max`.
+    pub fn retry_bounds(mut self, min: Duration, max: Duration) -> Self {
+        assert!(
+            min <= max,
+            "the minimum retry interval must not be greater than the maximum"
+        );
+        self.min_retry_interval = min;
+        self.max_retry_interval = max;
+        self
+    }
+
+    /// Sets the response statuses that cause a request to be retried.
+    pub fn retryable_statuses<I>(mut self, statuses: I) -> Self
+    where
+        I: IntoIterator<Item = http::StatusCode>,
+    {
+        self.retryable_statuses = statuses.into_iter().collect();
+        self
+    }
+
+    /// Sets whether the `Retry-After` header of a retryable response decides how long to
+    /// wait before the next attempt, rather than the backoff. Enabled by default.
+    ///
+    /// Responses asking to wait for longer than the maximum retry interval are returned
+    /// to the caller instead of being retried.
+    pub fn respect_retry_after(mut self, respect_retry_after: bool) -> Self {
+        self.respect_retry_after = respect_retry_after;
+        self
+    }
+
+    /// Sets whether requests wait for the rate limit the API last reported to reset once
+    /// it is exhausted, rather than being sent only to be rejected. Disabled by default.
+    ///
+    /// Requests that would have to wait for longer than the maximum retry interval are
+    /// sent right away.
+    pub fn wait_on_rate_limit(mut self, wait_on_rate_limit: bool) -> Self {
+        self.wait_on_rate_limit = wait_on_rate_limit;
+        self
+    }
+
+    /// Sets the timeout of every attempt of a request, from connecting until the
+    /// response body has been read.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the timeout for connecting to the server.
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Adds a middleware layer to the stack. Layers run in the order they are added, on
+    /// every attempt of a request, after the tracing, retry and rate limit layers.
+    pub fn with<M>(mut self, middleware: M) -> Self
+    where
+        M: reqwest_middleware::Middleware,
+    {
+        self.middleware.push(Arc::new(middleware));
+        self
+    }
+
+    /// Builds the HTTP client.
+    pub fn build(self) -> reqwest::Result<reqwest_middleware::ClientWithMiddleware> {
+        let mut http = reqwest::Client::builder().redirect(reqwest::redirect::Policy::none());
+        if let Some(timeout) = self.timeout {
+            http = http.timeout(timeout);
+        }
+        if let Some(timeout) = self.connect_timeout {
+            http = http.connect_timeout(timeout);
+        }
+
+        let retry = Retry {
+            policy: ExponentialBackoff::builder()
+                .retry_bounds(self.min_retry_interval, self.max_retry_interval)
+                .build_with_max_retries(self.max_retries),
+            retryable_statuses: self.retryable_statuses,
+            respect_retry_after: self.respect_retry_after,
+        };
+
+        let mut client = reqwest_middleware::ClientBuilder::new(http.build()?)
+            // Trace HTTP requests. See the tracing crate to make use of these traces.
+            .with(reqwest_tracing::TracingMiddleware::default())
+            // Retry failed requests.
+            .with(retry);
+        if self.wait_on_rate_limit {
+            client = client.with(crate::ratelimit::RateLimiter {
+                max_wait: self.max_retry_interval,
+                until: Default::default(),
+            });
+        }
+        for middleware in self.middleware {
+            client = client.with_arc(middleware);
+        }
+
+        Ok(client.build())
+    }
+}
+
+struct Retry {
+    policy: ExponentialBackoff,
+    retryable_statuses: Vec<http::StatusCode>,
+    respect_retry_after: bool,
+}
+
+#[async_trait::async_trait]
+impl reqwest_middleware::Middleware for Retry {
+    async fn handle(
+        &self,
+        req: reqwest::Request,
+        extensions: &mut task_local_extensions::Extensions,
+        next: reqwest_middleware::Next<'_>,
+    ) -> reqwest_middleware::Result<reqwest::Response> {
+        let mut retries = 0;
+        loop {
+            // Requests with a streaming body can not be sent again, so they only get the
+            // one attempt.
+            let attempt = match req.try_clone() {
+                Some(attempt) => attempt,
+                None => return next.run(req, extensions).await,
+            };
+
+            let result = next.clone().run(attempt, extensions).await;
+            match self.wait(&result, retries) {
+                Some(wait) => tokio::time::sleep(wait).await,
+                None => return result,
+            }
+            retries += 1;
+        }
+    }
+}
+
+impl Retry {
+    /// Returns how long to wait before retrying a request, or `None` if it should not be
+    /// retried.
+    fn wait(
+        &self,
+        result: &reqwest_middleware::Result<reqwest::Response>,
+        retries: u32,
+    ) -> Option<Duration> {
+        let retry_after = match result {
+            Ok(response) if self.retryable_statuses.contains(&response.status()) => {
+                if self.respect_retry_after {
+                    retry_after(response.headers())
+                } else {
+                    None
+                }
+            }
+            Err(reqwest_middleware::Error::Reqwest(e)) if e.is_timeout() || e.is_connect() => None,
+            _ => return None,
+        };
+
+        let backoff = match self.policy.should_retry(retries) {
+            RetryDecision::Retry { execute_after } => (execute_after - chrono::Utc::now())
+                .to_std()
+                .unwrap_or_default(),
+            RetryDecision::DoNotRetry => return None,
+        };
+
+        match retry_after {
+            Some(wait) if wait > self.policy.max_retry_interval => None,
+            Some(wait) => Some(wait),
+            None => Some(backoff),
+        }
+    }
+}
+
+/// Parses a `Retry-After` header, which holds either a number of seconds or a date.
+pub(crate) fn retry_after(headers: &reqwest::header::HeaderMap) -> Option<Duration> {
+    let value = headers
+        .get(reqwest::header::RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+
+    let date = chrono::DateTime::parse_from_rfc2822(value).ok()?;
+    Some(
+        (date.with_timezone(&chrono::Utc) - chrono::Utc::now())
+            .to_std()
+            .unwrap_or_default(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::retry_after;
+
+    #[test]
+    fn test_retry_after() {
+        let mut headers = reqwest::header::HeaderMap::new();
+        assert_eq!(retry_after(&headers), None);
+
+        headers.insert(reqwest::header::RETRY_AFTER, "120".parse().unwrap());
+        assert_eq!(retry_after(&headers), Some(Duration::from_secs(120)));
+
+        headers.insert(
+            reqwest::header::RETRY_AFTER,
+            "Wed, 21 Oct 2015 07:28:00 GMT".parse().unwrap(),
+        );
+        assert_eq!(retry_after(&headers), Some(Duration::ZERO));
+
+        headers.insert(reqwest::header::RETRY_AFTER, "soon".parse().unwrap());
+        assert_eq!(retry_after(&headers), None);
+    }
+}
@@ -24,6 +24,10 @@ pub struct AuditConfig {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub service: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -42,8 +46,9 @@ pub enum LogType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for LogType {
@@ -54,7 +59,7 @@ impl std::fmt::Display for LogType {
             LogType::DataWrite => "DATA_WRITE",
             LogType::LogTypeUnspecified => "LOG_TYPE_UNSPECIFIED",
             LogType::Noop => "",
-            LogType::FallthroughString => "*",
+            LogType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -84,6 +89,10 @@ pub struct AuditLogConfig {
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "logType")]
     pub log_type: Option<LogType>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Associates `members` with a `role`.
@@ -112,6 +121,10 @@ pub struct Binding {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub role: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -128,8 +141,9 @@ pub enum OperationType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OperationType {
@@ -139,7 +153,7 @@ impl std::fmt::Display for OperationType {
             OperationType::Move => "MOVE",
             OperationType::OperationTypeUnspecified => "OPERATION_TYPE_UNSPECIFIED",
             OperationType::Noop => "",
-            OperationType::FallthroughString => "*",
+            OperationType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -193,6 +207,10 @@ pub struct FolderOperation {
         rename = "sourceParent"
     )]
     pub source_parent: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Metadata pertaining to the Folder creation process.
@@ -217,6 +235,10 @@ pub struct CreateFolderMetadata {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub parent: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A status object which is used as the `metadata` field for the Operation returned by CreateProject. It provides insight for when significant phases of Project creation have completed.
@@ -248,11 +270,20 @@ pub struct CreateProjectMetadata {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub ready: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A status object which is used as the `metadata` field for the Operation returned by MoveProject.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct MoveProjectMetadata {}
+pub struct MoveProjectMetadata {
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
 
 /// Represents a textual expression in the Common Expression Language (CEL) syntax. CEL is a C-like expression language. The syntax and semantics of CEL are documented at https://github.com/google/cel-spec. Example (Comparison): title: "Summary size limit" description: "Determines if a summary is less than 100 chars" expression: "document.summary.size() < 100" Example (Equality): title: "Requestor is owner" description: "Determines if requestor is the document owner" expression: "document.owner == request.auth.claims.email" Example (Logic): title: "Public documents" description: "Determine whether the document should be publicly visible" expression: "document.type != 'private' && document.type != 'internal'" Example (Data Manipulation): title: "Notification string" description: "Create a notification string with a timestamp." expression: "'New message received at ' + string(document.create_time)" The exact variables and functions that may be referenced within an expression are determined by the service that evaluates it. See the service documentation for additional information.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -293,6 +324,10 @@ pub struct Expr {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub title: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -309,8 +344,9 @@ pub enum LifecycleState {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for LifecycleState {
@@ -320,7 +356,7 @@ impl std::fmt::Display for LifecycleState {
             LifecycleState::DeleteRequested => "DELETE_REQUESTED",
             LifecycleState::LifecycleStateUnspecified => "LIFECYCLE_STATE_UNSPECIFIED",
             LifecycleState::Noop => "",
-            LifecycleState::FallthroughString => "*",
+            LifecycleState::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -382,6 +418,10 @@ pub struct Folder {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub parent: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -412,8 +452,9 @@ pub enum ErrorMessageId {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ErrorMessageId {
@@ -432,7 +473,7 @@ impl std::fmt::Display for ErrorMessageId {
             ErrorMessageId::ParentDeletedViolation => "PARENT_DELETED_VIOLATION",
             ErrorMessageId::ResourceDeletedViolation => "RESOURCE_DELETED_VIOLATION",
             ErrorMessageId::Noop => "",
-            ErrorMessageId::FallthroughString => "*",
+            ErrorMessageId::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -456,6 +497,10 @@ pub struct FolderOperationError {
         rename = "errorMessageId"
     )]
     pub error_message_id: Option<ErrorMessageId>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Request message for `GetIamPolicy` method.
@@ -466,6 +511,10 @@ pub struct GetIamPolicyRequest {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub options: Option<GetPolicyOptions>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Encapsulates settings provided to GetIamPolicy.
@@ -481,6 +530,10 @@ pub struct GetPolicyOptions {
         rename = "requestedPolicyVersion"
     )]
     pub requested_policy_version: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The ListFolders response message.
@@ -505,6 +558,10 @@ pub struct ListFoldersResponse {
         rename = "nextPageToken"
     )]
     pub next_page_token: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Metadata pertaining to the folder move process.
@@ -540,6 +597,10 @@ pub struct MoveFolderMetadata {
         rename = "sourceParent"
     )]
     pub source_parent: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The MoveFolder request message.
@@ -555,6 +616,10 @@ pub struct MoveFolderRequest {
         rename = "destinationParent"
     )]
     pub destination_parent: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// This resource represents a long-running operation that is the result of a network API call.
@@ -592,6 +657,10 @@ pub struct Operation {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub response: Option<serde_json::Value>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// An Identity and Access Management (IAM) policy, which specifies access controls for Google Cloud resources. A `Policy` is a collection of `bindings`. A `binding` binds one or more `members` to a single `role`. Members can be user accounts, service accounts, Google groups, and domains (such as G Suite). A `role` is a named list of permissions; each `role` can be an IAM predefined role or a user-created custom role. For some types of Google Cloud resources, a `binding` can also specify a `condition`, which is a logical expression that allows access to a resource only if the expression evaluates to `true`. A condition can add constraints based on attributes of the request, the resource, or both. To learn which resources support conditions in their IAM policies, see the [IAM documentation](https://cloud.google.com/iam/help/conditions/resource-policies). **JSON example:** { "bindings": [ { "role": "roles/resourcemanager.organizationAdmin", "members": [ "user:mike@example.com", "group:admins@example.com", "domain:google.com", "serviceAccount:my-project-id@appspot.gserviceaccount.com" ] }, { "role": "roles/resourcemanager.organizationViewer", "members": [ "user:eve@example.com" ], "condition": { "title": "expirable access", "description": "Does not grant access after Sep 2020", "expression": "request.time < timestamp('2020-10-01T00:00:00.000Z')", } } ], "etag": "BwWWja0YfJA=", "version": 3 } **YAML example:** bindings: - members: - user:mike@example.com - group:admins@example.com - domain:google.com - serviceAccount:my-project-id@appspot.gserviceaccount.com role: roles/resourcemanager.organizationAdmin - members: - user:eve@example.com role: roles/resourcemanager.organizationViewer condition: title: expirable access description: Does not grant access after Sep 2020 expression: request.time < timestamp('2020-10-01T00:00:00.000Z') etag: BwWWja0YfJA= version: 3 For a description of IAM and its features, see the [IAM documentation](https://cloud.google.com/iam/docs/).
@@ -630,6 +699,10 @@ pub struct Policy {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub version: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The request message for searching folders.
@@ -664,6 +737,10 @@ pub struct SearchFoldersRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub query: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The response message for searching folders.
@@ -688,6 +765,10 @@ pub struct SearchFoldersResponse {
         rename = "nextPageToken"
     )]
     pub next_page_token: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Request message for `SetIamPolicy` method.
@@ -708,6 +789,10 @@ pub struct SetIamPolicyRequest {
         rename = "updateMask"
     )]
     pub update_mask: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The `Status` type defines a logical error model that is suitable for different programming environments, including REST APIs and RPC APIs. It is used by [gRPC](https://github.com/grpc). Each `Status` message contains three pieces of data: error code, error message, and error details. You can find out more about this error model and how to work with it in the [API Design Guide](https://cloud.google.com/apis/design/errors).
@@ -740,6 +825,10 @@ pub struct Status {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub message: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Request message for `TestIamPermissions` method.
@@ -754,6 +843,10 @@ pub struct TestIamPermissionsRequest {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub permissions: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Response message for `TestIamPermissions` method.
@@ -768,6 +861,10 @@ pub struct TestIamPermissionsResponse {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub permissions: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -782,8 +879,9 @@ pub enum Xgafv {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Xgafv {
@@ -792,7 +890,7 @@ impl std::fmt::Display for Xgafv {
             Xgafv::One => "1",
             Xgafv::Two => "2",
             Xgafv::Noop => "",
-            Xgafv::FallthroughString => "*",
+            Xgafv::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -818,8 +916,9 @@ pub enum Alt {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Alt {
@@ -829,7 +928,7 @@ impl std::fmt::Display for Alt {
             Alt::Media => "media",
             Alt::Proto => "proto",
             Alt::Noop => "",
-            Alt::FallthroughString => "*",
+            Alt::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
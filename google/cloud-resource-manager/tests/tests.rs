@@ -1 +1,61 @@
+use std::mem;
 
+use wiremock::{
+    matchers::{method, path, query_param, query_param_is_missing},
+    Mock, MockServer, ResponseTemplate,
+};
+
+use google_cloud_resource_manager::Client;
+
+async fn mount_folders_page(server: &MockServer, token: Option<&str>, folder: &str, next: &str) {
+    let mock = Mock::given(method("GET"))
+        .and(path("/v2/folders"))
+        .and(query_param("parent", "organizations/123"));
+    let mock = match token {
+        Some(token) => mock.and(query_param("pageToken", token)),
+        None => mock.and(query_param_is_missing("pageToken")),
+    };
+
+    mock.respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+        "folders": [{ "name": folder, "parent": "organizations/123" }],
+        "nextPageToken": next
+    })))
+    .mount(server)
+    .await;
+}
+
+#[tokio::test]
+async fn test_list_all_respects_max_pages() {
+    let server = MockServer::start().await;
+
+    mount_folders_page(&server, None, "folders/1", "p2").await;
+    mount_folders_page(&server, Some("p2"), "folders/2", "p3").await;
+    mount_folders_page(&server, Some("p3"), "folders/3", "").await;
+
+    let mut client = Client::new("client_id", "client_secret", "redirect_uri", "token", "");
+    client.with_host_override(server.uri());
+
+    let folders = client
+        .folders()
+        .list_all("organizations/123", false)
+        .await
+        .unwrap()
+        .body;
+    assert_eq!(folders.len(), 3);
+
+    client.with_max_pages(2);
+    let folders = client
+        .folders()
+        .list_all("organizations/123", false)
+        .await
+        .unwrap()
+        .body;
+    let names = folders
+        .iter()
+        .map(|folder| folder.name.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(names, vec!["folders/1", "folders/2"]);
+    assert_eq!(server.received_requests().await.unwrap().len(), 5);
+
+    mem::drop(server)
+}
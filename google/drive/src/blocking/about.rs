@@ -0,0 +1,20 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::about::About`].
+pub struct About {
+    inner: crate::about::About,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl About {
+    pub(crate) fn new(inner: crate::about::About, runtime: Arc<tokio::runtime::Runtime>) -> Self {
+        About { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::about::About::get`].
+    pub fn get(&self) -> ClientResult<crate::Response<crate::types::About>> {
+        self.runtime.block_on(self.inner.get())
+    }
+}
@@ -0,0 +1,375 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Changes::list`], set with a builder.
+pub struct ChangesListRequest<'a> {
+    inner: crate::changes::ChangesListRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ChangesListRequest<'a> {
+    /// Sets the `driveId` query parameter.
+    pub fn drive_id(mut self, drive_id: &'a str) -> Self {
+        self.inner = self.inner.drive_id(drive_id);
+        self
+    }
+
+    /// Sets the `includeCorpusRemovals` query parameter.
+    pub fn include_corpus_removals(mut self, include_corpus_removals: bool) -> Self {
+        self.inner = self.inner.include_corpus_removals(include_corpus_removals);
+        self
+    }
+
+    /// Sets the `includeItemsFromAllDrives` query parameter.
+    pub fn include_items_from_all_drives(mut self, include_items_from_all_drives: bool) -> Self {
+        self.inner = self
+            .inner
+            .include_items_from_all_drives(include_items_from_all_drives);
+        self
+    }
+
+    /// Sets the `includePermissionsForView` query parameter.
+    pub fn include_permissions_for_view(mut self, include_permissions_for_view: &'a str) -> Self {
+        self.inner = self
+            .inner
+            .include_permissions_for_view(include_permissions_for_view);
+        self
+    }
+
+    /// Sets the `includeRemoved` query parameter.
+    pub fn include_removed(mut self, include_removed: bool) -> Self {
+        self.inner = self.inner.include_removed(include_removed);
+        self
+    }
+
+    /// Sets the `includeTeamDriveItems` query parameter.
+    pub fn include_team_drive_items(mut self, include_team_drive_items: bool) -> Self {
+        self.inner = self
+            .inner
+            .include_team_drive_items(include_team_drive_items);
+        self
+    }
+
+    /// Sets the `pageSize` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Sets the `restrictToMyDrive` query parameter.
+    pub fn restrict_to_my_drive(mut self, restrict_to_my_drive: bool) -> Self {
+        self.inner = self.inner.restrict_to_my_drive(restrict_to_my_drive);
+        self
+    }
+
+    /// Sets the `spaces` query parameter.
+    pub fn spaces(mut self, spaces: &'a str) -> Self {
+        self.inner = self.inner.spaces(spaces);
+        self
+    }
+
+    /// Sets the `supportsAllDrives` query parameter.
+    pub fn supports_all_drives(mut self, supports_all_drives: bool) -> Self {
+        self.inner = self.inner.supports_all_drives(supports_all_drives);
+        self
+    }
+
+    /// Sets the `supportsTeamDrives` query parameter.
+    pub fn supports_team_drives(mut self, supports_team_drives: bool) -> Self {
+        self.inner = self.inner.supports_team_drives(supports_team_drives);
+        self
+    }
+
+    /// Sets the `teamDriveId` query parameter.
+    pub fn team_drive_id(mut self, team_drive_id: &'a str) -> Self {
+        self.inner = self.inner.team_drive_id(team_drive_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Change>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Changes::get_start_page_token`], set with a builder.
+pub struct ChangesGetStartPageTokenRequest<'a> {
+    inner: crate::changes::ChangesGetStartPageTokenRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ChangesGetStartPageTokenRequest<'a> {
+    /// Sets the `driveId` query parameter.
+    pub fn drive_id(mut self, drive_id: &'a str) -> Self {
+        self.inner = self.inner.drive_id(drive_id);
+        self
+    }
+
+    /// Sets the `supportsAllDrives` query parameter.
+    pub fn supports_all_drives(mut self, supports_all_drives: bool) -> Self {
+        self.inner = self.inner.supports_all_drives(supports_all_drives);
+        self
+    }
+
+    /// Sets the `supportsTeamDrives` query parameter.
+    pub fn supports_team_drives(mut self, supports_team_drives: bool) -> Self {
+        self.inner = self.inner.supports_team_drives(supports_team_drives);
+        self
+    }
+
+    /// Sets the `teamDriveId` query parameter.
+    pub fn team_drive_id(mut self, team_drive_id: &'a str) -> Self {
+        self.inner = self.inner.team_drive_id(team_drive_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::StartPageToken>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Changes::watch`], set with a builder.
+pub struct ChangesWatchRequest<'a> {
+    inner: crate::changes::ChangesWatchRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ChangesWatchRequest<'a> {
+    /// Sets the `driveId` query parameter.
+    pub fn drive_id(mut self, drive_id: &'a str) -> Self {
+        self.inner = self.inner.drive_id(drive_id);
+        self
+    }
+
+    /// Sets the `includeCorpusRemovals` query parameter.
+    pub fn include_corpus_removals(mut self, include_corpus_removals: bool) -> Self {
+        self.inner = self.inner.include_corpus_removals(include_corpus_removals);
+        self
+    }
+
+    /// Sets the `includeItemsFromAllDrives` query parameter.
+    pub fn include_items_from_all_drives(mut self, include_items_from_all_drives: bool) -> Self {
+        self.inner = self
+            .inner
+            .include_items_from_all_drives(include_items_from_all_drives);
+        self
+    }
+
+    /// Sets the `includePermissionsForView` query parameter.
+    pub fn include_permissions_for_view(mut self, include_permissions_for_view: &'a str) -> Self {
+        self.inner = self
+            .inner
+            .include_permissions_for_view(include_permissions_for_view);
+        self
+    }
+
+    /// Sets the `includeRemoved` query parameter.
+    pub fn include_removed(mut self, include_removed: bool) -> Self {
+        self.inner = self.inner.include_removed(include_removed);
+        self
+    }
+
+    /// Sets the `includeTeamDriveItems` query parameter.
+    pub fn include_team_drive_items(mut self, include_team_drive_items: bool) -> Self {
+        self.inner = self
+            .inner
+            .include_team_drive_items(include_team_drive_items);
+        self
+    }
+
+    /// Sets the `pageSize` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Sets the `restrictToMyDrive` query parameter.
+    pub fn restrict_to_my_drive(mut self, restrict_to_my_drive: bool) -> Self {
+        self.inner = self.inner.restrict_to_my_drive(restrict_to_my_drive);
+        self
+    }
+
+    /// Sets the `spaces` query parameter.
+    pub fn spaces(mut self, spaces: &'a str) -> Self {
+        self.inner = self.inner.spaces(spaces);
+        self
+    }
+
+    /// Sets the `supportsAllDrives` query parameter.
+    pub fn supports_all_drives(mut self, supports_all_drives: bool) -> Self {
+        self.inner = self.inner.supports_all_drives(supports_all_drives);
+        self
+    }
+
+    /// Sets the `supportsTeamDrives` query parameter.
+    pub fn supports_team_drives(mut self, supports_team_drives: bool) -> Self {
+        self.inner = self.inner.supports_team_drives(supports_team_drives);
+        self
+    }
+
+    /// Sets the `teamDriveId` query parameter.
+    pub fn team_drive_id(mut self, team_drive_id: &'a str) -> Self {
+        self.inner = self.inner.team_drive_id(team_drive_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::Channel>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::changes::Changes`].
+pub struct Changes {
+    inner: crate::changes::Changes,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Changes {
+    pub(crate) fn new(
+        inner: crate::changes::Changes,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Changes { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::changes::Changes::list`].
+    pub fn list(
+        &self,
+        page_token: &str,
+        drive_id: &str,
+        include_corpus_removals: bool,
+        include_items_from_all_drives: bool,
+        include_permissions_for_view: &str,
+        include_removed: bool,
+        include_team_drive_items: bool,
+        page_size: i64,
+        restrict_to_my_drive: bool,
+        spaces: &str,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+        team_drive_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Change>>> {
+        self.runtime.block_on(self.inner.list(
+            page_token,
+            drive_id,
+            include_corpus_removals,
+            include_items_from_all_drives,
+            include_permissions_for_view,
+            include_removed,
+            include_team_drive_items,
+            page_size,
+            restrict_to_my_drive,
+            spaces,
+            supports_all_drives,
+            supports_team_drives,
+            team_drive_id,
+        ))
+    }
+    /// The blocking version of [`crate::changes::Changes::list_request`].
+    pub fn list_request<'a>(&'a self, page_token: &'a str) -> ChangesListRequest<'a> {
+        ChangesListRequest {
+            inner: self.inner.list_request(page_token),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::changes::Changes::list_all`].
+    pub fn list_all(
+        &self,
+        drive_id: &str,
+        include_corpus_removals: bool,
+        include_items_from_all_drives: bool,
+        include_permissions_for_view: &str,
+        include_removed: bool,
+        include_team_drive_items: bool,
+        restrict_to_my_drive: bool,
+        spaces: &str,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+        team_drive_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Change>>> {
+        self.runtime.block_on(self.inner.list_all(
+            drive_id,
+            include_corpus_removals,
+            include_items_from_all_drives,
+            include_permissions_for_view,
+            include_removed,
+            include_team_drive_items,
+            restrict_to_my_drive,
+            spaces,
+            supports_all_drives,
+            supports_team_drives,
+            team_drive_id,
+        ))
+    }
+    /// The blocking version of [`crate::changes::Changes::get_start_page_token`].
+    pub fn get_start_page_token(
+        &self,
+        drive_id: &str,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+        team_drive_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::StartPageToken>> {
+        self.runtime.block_on(self.inner.get_start_page_token(
+            drive_id,
+            supports_all_drives,
+            supports_team_drives,
+            team_drive_id,
+        ))
+    }
+    /// The blocking version of [`crate::changes::Changes::get_start_page_token_request`].
+    pub fn get_start_page_token_request(&self) -> ChangesGetStartPageTokenRequest<'_> {
+        ChangesGetStartPageTokenRequest {
+            inner: self.inner.get_start_page_token_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::changes::Changes::watch`].
+    pub fn watch(
+        &self,
+        page_token: &str,
+        drive_id: &str,
+        include_corpus_removals: bool,
+        include_items_from_all_drives: bool,
+        include_permissions_for_view: &str,
+        include_removed: bool,
+        include_team_drive_items: bool,
+        page_size: i64,
+        restrict_to_my_drive: bool,
+        spaces: &str,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+        team_drive_id: &str,
+        body: &crate::types::Channel,
+    ) -> ClientResult<crate::Response<crate::types::Channel>> {
+        self.runtime.block_on(self.inner.watch(
+            page_token,
+            drive_id,
+            include_corpus_removals,
+            include_items_from_all_drives,
+            include_permissions_for_view,
+            include_removed,
+            include_team_drive_items,
+            page_size,
+            restrict_to_my_drive,
+            spaces,
+            supports_all_drives,
+            supports_team_drives,
+            team_drive_id,
+            body,
+        ))
+    }
+    /// The blocking version of [`crate::changes::Changes::watch_request`].
+    pub fn watch_request<'a>(
+        &'a self,
+        page_token: &'a str,
+        body: &'a crate::types::Channel,
+    ) -> ChangesWatchRequest<'a> {
+        ChangesWatchRequest {
+            inner: self.inner.watch_request(page_token, body),
+            runtime: &self.runtime,
+        }
+    }
+}
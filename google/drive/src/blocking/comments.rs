@@ -0,0 +1,155 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Comments::list`], set with a builder.
+pub struct CommentsListRequest<'a> {
+    inner: crate::comments::CommentsListRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> CommentsListRequest<'a> {
+    /// Sets the `includeDeleted` query parameter.
+    pub fn include_deleted(mut self, include_deleted: bool) -> Self {
+        self.inner = self.inner.include_deleted(include_deleted);
+        self
+    }
+
+    /// Sets the `pageSize` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Sets the `pageToken` query parameter.
+    pub fn page_token(mut self, page_token: &'a str) -> Self {
+        self.inner = self.inner.page_token(page_token);
+        self
+    }
+
+    /// Sets the `startModifiedTime` query parameter.
+    pub fn start_modified_time(mut self, start_modified_time: &'a str) -> Self {
+        self.inner = self.inner.start_modified_time(start_modified_time);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Comment>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Comments::get`], set with a builder.
+pub struct CommentsGetRequest<'a> {
+    inner: crate::comments::CommentsGetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> CommentsGetRequest<'a> {
+    /// Sets the `includeDeleted` query parameter.
+    pub fn include_deleted(mut self, include_deleted: bool) -> Self {
+        self.inner = self.inner.include_deleted(include_deleted);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::Comment>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::comments::Comments`].
+pub struct Comments {
+    inner: crate::comments::Comments,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Comments {
+    pub(crate) fn new(
+        inner: crate::comments::Comments,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Comments { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::comments::Comments::list`].
+    pub fn list(
+        &self,
+        file_id: &str,
+        include_deleted: bool,
+        page_size: i64,
+        page_token: &str,
+        start_modified_time: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Comment>>> {
+        self.runtime.block_on(self.inner.list(
+            file_id,
+            include_deleted,
+            page_size,
+            page_token,
+            start_modified_time,
+        ))
+    }
+    /// The blocking version of [`crate::comments::Comments::list_request`].
+    pub fn list_request<'a>(&'a self, file_id: &'a str) -> CommentsListRequest<'a> {
+        CommentsListRequest {
+            inner: self.inner.list_request(file_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::comments::Comments::list_all`].
+    pub fn list_all(
+        &self,
+        file_id: &str,
+        include_deleted: bool,
+        start_modified_time: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Comment>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_all(file_id, include_deleted, start_modified_time),
+        )
+    }
+    /// The blocking version of [`crate::comments::Comments::create`].
+    pub fn create(
+        &self,
+        file_id: &str,
+        body: &crate::types::Comment,
+    ) -> ClientResult<crate::Response<crate::types::Comment>> {
+        self.runtime.block_on(self.inner.create(file_id, body))
+    }
+    /// The blocking version of [`crate::comments::Comments::get`].
+    pub fn get(
+        &self,
+        file_id: &str,
+        comment_id: &str,
+        include_deleted: bool,
+    ) -> ClientResult<crate::Response<crate::types::Comment>> {
+        self.runtime
+            .block_on(self.inner.get(file_id, comment_id, include_deleted))
+    }
+    /// The blocking version of [`crate::comments::Comments::get_request`].
+    pub fn get_request<'a>(
+        &'a self,
+        file_id: &'a str,
+        comment_id: &'a str,
+    ) -> CommentsGetRequest<'a> {
+        CommentsGetRequest {
+            inner: self.inner.get_request(file_id, comment_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::comments::Comments::delete`].
+    pub fn delete(&self, file_id: &str, comment_id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete(file_id, comment_id))
+    }
+    /// The blocking version of [`crate::comments::Comments::update`].
+    pub fn update(
+        &self,
+        file_id: &str,
+        comment_id: &str,
+        body: &crate::types::Comment,
+    ) -> ClientResult<crate::Response<crate::types::Comment>> {
+        self.runtime
+            .block_on(self.inner.update(file_id, comment_id, body))
+    }
+}
@@ -0,0 +1,177 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Drives::list`], set with a builder.
+pub struct DrivesListRequest<'a> {
+    inner: crate::drives::DrivesListRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> DrivesListRequest<'a> {
+    /// Sets the `pageSize` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Sets the `pageToken` query parameter.
+    pub fn page_token(mut self, page_token: &'a str) -> Self {
+        self.inner = self.inner.page_token(page_token);
+        self
+    }
+
+    /// Sets the `q` query parameter.
+    pub fn q(mut self, q: &'a str) -> Self {
+        self.inner = self.inner.q(q);
+        self
+    }
+
+    /// Sets the `useDomainAdminAccess` query parameter.
+    pub fn use_domain_admin_access(mut self, use_domain_admin_access: bool) -> Self {
+        self.inner = self.inner.use_domain_admin_access(use_domain_admin_access);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Drive>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Drives::get`], set with a builder.
+pub struct DrivesGetRequest<'a> {
+    inner: crate::drives::DrivesGetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> DrivesGetRequest<'a> {
+    /// Sets the `useDomainAdminAccess` query parameter.
+    pub fn use_domain_admin_access(mut self, use_domain_admin_access: bool) -> Self {
+        self.inner = self.inner.use_domain_admin_access(use_domain_admin_access);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::Drive>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Drives::update`], set with a builder.
+pub struct DrivesUpdateRequest<'a> {
+    inner: crate::drives::DrivesUpdateRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> DrivesUpdateRequest<'a> {
+    /// Sets the `useDomainAdminAccess` query parameter.
+    pub fn use_domain_admin_access(mut self, use_domain_admin_access: bool) -> Self {
+        self.inner = self.inner.use_domain_admin_access(use_domain_admin_access);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::Drive>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::drives::Drives`].
+pub struct Drives {
+    inner: crate::drives::Drives,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Drives {
+    pub(crate) fn new(inner: crate::drives::Drives, runtime: Arc<tokio::runtime::Runtime>) -> Self {
+        Drives { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::drives::Drives::list`].
+    pub fn list(
+        &self,
+        page_size: i64,
+        page_token: &str,
+        q: &str,
+        use_domain_admin_access: bool,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Drive>>> {
+        self.runtime.block_on(
+            self.inner
+                .list(page_size, page_token, q, use_domain_admin_access),
+        )
+    }
+    /// The blocking version of [`crate::drives::Drives::list_request`].
+    pub fn list_request(&self) -> DrivesListRequest<'_> {
+        DrivesListRequest {
+            inner: self.inner.list_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::drives::Drives::list_all`].
+    pub fn list_all(
+        &self,
+        q: &str,
+        use_domain_admin_access: bool,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Drive>>> {
+        self.runtime
+            .block_on(self.inner.list_all(q, use_domain_admin_access))
+    }
+    /// The blocking version of [`crate::drives::Drives::create`].
+    pub fn create(
+        &self,
+        request_id: &str,
+        body: &crate::types::Drive,
+    ) -> ClientResult<crate::Response<crate::types::Drive>> {
+        self.runtime.block_on(self.inner.create(request_id, body))
+    }
+    /// The blocking version of [`crate::drives::Drives::get`].
+    pub fn get(
+        &self,
+        drive_id: &str,
+        use_domain_admin_access: bool,
+    ) -> ClientResult<crate::Response<crate::types::Drive>> {
+        self.runtime
+            .block_on(self.inner.get(drive_id, use_domain_admin_access))
+    }
+    /// The blocking version of [`crate::drives::Drives::get_request`].
+    pub fn get_request<'a>(&'a self, drive_id: &'a str) -> DrivesGetRequest<'a> {
+        DrivesGetRequest {
+            inner: self.inner.get_request(drive_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::drives::Drives::delete`].
+    pub fn delete(&self, drive_id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.delete(drive_id))
+    }
+    /// The blocking version of [`crate::drives::Drives::update`].
+    pub fn update(
+        &self,
+        drive_id: &str,
+        use_domain_admin_access: bool,
+        body: &crate::types::Drive,
+    ) -> ClientResult<crate::Response<crate::types::Drive>> {
+        self.runtime
+            .block_on(self.inner.update(drive_id, use_domain_admin_access, body))
+    }
+    /// The blocking version of [`crate::drives::Drives::update_request`].
+    pub fn update_request<'a>(
+        &'a self,
+        drive_id: &'a str,
+        body: &'a crate::types::Drive,
+    ) -> DrivesUpdateRequest<'a> {
+        DrivesUpdateRequest {
+            inner: self.inner.update_request(drive_id, body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::drives::Drives::hide`].
+    pub fn hide(&self, drive_id: &str) -> ClientResult<crate::Response<crate::types::Drive>> {
+        self.runtime.block_on(self.inner.hide(drive_id))
+    }
+    /// The blocking version of [`crate::drives::Drives::unhide`].
+    pub fn unhide(&self, drive_id: &str) -> ClientResult<crate::Response<crate::types::Drive>> {
+        self.runtime.block_on(self.inner.unhide(drive_id))
+    }
+}
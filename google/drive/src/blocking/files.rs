@@ -0,0 +1,695 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Files::list`], set with a builder.
+pub struct FilesListRequest<'a> {
+    inner: crate::files::FilesListRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> FilesListRequest<'a> {
+    /// Sets the `corpora` query parameter.
+    pub fn corpora(mut self, corpora: &'a str) -> Self {
+        self.inner = self.inner.corpora(corpora);
+        self
+    }
+
+    /// Sets the `driveId` query parameter.
+    pub fn drive_id(mut self, drive_id: &'a str) -> Self {
+        self.inner = self.inner.drive_id(drive_id);
+        self
+    }
+
+    /// Sets the `includeItemsFromAllDrives` query parameter.
+    pub fn include_items_from_all_drives(mut self, include_items_from_all_drives: bool) -> Self {
+        self.inner = self
+            .inner
+            .include_items_from_all_drives(include_items_from_all_drives);
+        self
+    }
+
+    /// Sets the `includePermissionsForView` query parameter.
+    pub fn include_permissions_for_view(mut self, include_permissions_for_view: &'a str) -> Self {
+        self.inner = self
+            .inner
+            .include_permissions_for_view(include_permissions_for_view);
+        self
+    }
+
+    /// Sets the `includeTeamDriveItems` query parameter.
+    pub fn include_team_drive_items(mut self, include_team_drive_items: bool) -> Self {
+        self.inner = self
+            .inner
+            .include_team_drive_items(include_team_drive_items);
+        self
+    }
+
+    /// Sets the `orderBy` query parameter.
+    pub fn order_by(mut self, order_by: &'a str) -> Self {
+        self.inner = self.inner.order_by(order_by);
+        self
+    }
+
+    /// Sets the `pageSize` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Sets the `pageToken` query parameter.
+    pub fn page_token(mut self, page_token: &'a str) -> Self {
+        self.inner = self.inner.page_token(page_token);
+        self
+    }
+
+    /// Sets the `q` query parameter.
+    pub fn q(mut self, q: &'a str) -> Self {
+        self.inner = self.inner.q(q);
+        self
+    }
+
+    /// Sets the `spaces` query parameter.
+    pub fn spaces(mut self, spaces: &'a str) -> Self {
+        self.inner = self.inner.spaces(spaces);
+        self
+    }
+
+    /// Sets the `supportsAllDrives` query parameter.
+    pub fn supports_all_drives(mut self, supports_all_drives: bool) -> Self {
+        self.inner = self.inner.supports_all_drives(supports_all_drives);
+        self
+    }
+
+    /// Sets the `supportsTeamDrives` query parameter.
+    pub fn supports_team_drives(mut self, supports_team_drives: bool) -> Self {
+        self.inner = self.inner.supports_team_drives(supports_team_drives);
+        self
+    }
+
+    /// Sets the `teamDriveId` query parameter.
+    pub fn team_drive_id(mut self, team_drive_id: &'a str) -> Self {
+        self.inner = self.inner.team_drive_id(team_drive_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::File>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Files::create`], set with a builder.
+pub struct FilesCreateRequest<'a> {
+    inner: crate::files::FilesCreateRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> FilesCreateRequest<'a> {
+    /// Sets the `ignoreDefaultVisibility` query parameter.
+    pub fn ignore_default_visibility(mut self, ignore_default_visibility: bool) -> Self {
+        self.inner = self
+            .inner
+            .ignore_default_visibility(ignore_default_visibility);
+        self
+    }
+
+    /// Sets the `includePermissionsForView` query parameter.
+    pub fn include_permissions_for_view(mut self, include_permissions_for_view: &'a str) -> Self {
+        self.inner = self
+            .inner
+            .include_permissions_for_view(include_permissions_for_view);
+        self
+    }
+
+    /// Sets the `keepRevisionForever` query parameter.
+    pub fn keep_revision_forever(mut self, keep_revision_forever: bool) -> Self {
+        self.inner = self.inner.keep_revision_forever(keep_revision_forever);
+        self
+    }
+
+    /// Sets the `ocrLanguage` query parameter.
+    pub fn ocr_language(mut self, ocr_language: &'a str) -> Self {
+        self.inner = self.inner.ocr_language(ocr_language);
+        self
+    }
+
+    /// Sets the `supportsAllDrives` query parameter.
+    pub fn supports_all_drives(mut self, supports_all_drives: bool) -> Self {
+        self.inner = self.inner.supports_all_drives(supports_all_drives);
+        self
+    }
+
+    /// Sets the `supportsTeamDrives` query parameter.
+    pub fn supports_team_drives(mut self, supports_team_drives: bool) -> Self {
+        self.inner = self.inner.supports_team_drives(supports_team_drives);
+        self
+    }
+
+    /// Sets the `useContentAsIndexableText` query parameter.
+    pub fn use_content_as_indexable_text(mut self, use_content_as_indexable_text: bool) -> Self {
+        self.inner = self
+            .inner
+            .use_content_as_indexable_text(use_content_as_indexable_text);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::File>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Files::generate_id`], set with a builder.
+pub struct FilesGenerateIdRequest<'a> {
+    inner: crate::files::FilesGenerateIdRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> FilesGenerateIdRequest<'a> {
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.inner = self.inner.count(count);
+        self
+    }
+
+    /// Sets the `space` query parameter.
+    pub fn space(mut self, space: &'a str) -> Self {
+        self.inner = self.inner.space(space);
+        self
+    }
+
+    /// Sets the `type` query parameter.
+    pub fn type_(mut self, type_: &'a str) -> Self {
+        self.inner = self.inner.type_(type_);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::GeneratedIds>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Files::get`], set with a builder.
+pub struct FilesGetRequest<'a> {
+    inner: crate::files::FilesGetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> FilesGetRequest<'a> {
+    /// Sets the `acknowledgeAbuse` query parameter.
+    pub fn acknowledge_abuse(mut self, acknowledge_abuse: bool) -> Self {
+        self.inner = self.inner.acknowledge_abuse(acknowledge_abuse);
+        self
+    }
+
+    /// Sets the `includePermissionsForView` query parameter.
+    pub fn include_permissions_for_view(mut self, include_permissions_for_view: &'a str) -> Self {
+        self.inner = self
+            .inner
+            .include_permissions_for_view(include_permissions_for_view);
+        self
+    }
+
+    /// Sets the `supportsAllDrives` query parameter.
+    pub fn supports_all_drives(mut self, supports_all_drives: bool) -> Self {
+        self.inner = self.inner.supports_all_drives(supports_all_drives);
+        self
+    }
+
+    /// Sets the `supportsTeamDrives` query parameter.
+    pub fn supports_team_drives(mut self, supports_team_drives: bool) -> Self {
+        self.inner = self.inner.supports_team_drives(supports_team_drives);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::File>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Files::delete`], set with a builder.
+pub struct FilesDeleteRequest<'a> {
+    inner: crate::files::FilesDeleteRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> FilesDeleteRequest<'a> {
+    /// Sets the `supportsAllDrives` query parameter.
+    pub fn supports_all_drives(mut self, supports_all_drives: bool) -> Self {
+        self.inner = self.inner.supports_all_drives(supports_all_drives);
+        self
+    }
+
+    /// Sets the `supportsTeamDrives` query parameter.
+    pub fn supports_team_drives(mut self, supports_team_drives: bool) -> Self {
+        self.inner = self.inner.supports_team_drives(supports_team_drives);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Files::update`], set with a builder.
+pub struct FilesUpdateRequest<'a> {
+    inner: crate::files::FilesUpdateRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> FilesUpdateRequest<'a> {
+    /// Sets the `addParents` query parameter.
+    pub fn add_parents(mut self, add_parents: &'a str) -> Self {
+        self.inner = self.inner.add_parents(add_parents);
+        self
+    }
+
+    /// Sets the `includePermissionsForView` query parameter.
+    pub fn include_permissions_for_view(mut self, include_permissions_for_view: &'a str) -> Self {
+        self.inner = self
+            .inner
+            .include_permissions_for_view(include_permissions_for_view);
+        self
+    }
+
+    /// Sets the `keepRevisionForever` query parameter.
+    pub fn keep_revision_forever(mut self, keep_revision_forever: bool) -> Self {
+        self.inner = self.inner.keep_revision_forever(keep_revision_forever);
+        self
+    }
+
+    /// Sets the `ocrLanguage` query parameter.
+    pub fn ocr_language(mut self, ocr_language: &'a str) -> Self {
+        self.inner = self.inner.ocr_language(ocr_language);
+        self
+    }
+
+    /// Sets the `removeParents` query parameter.
+    pub fn remove_parents(mut self, remove_parents: &'a str) -> Self {
+        self.inner = self.inner.remove_parents(remove_parents);
+        self
+    }
+
+    /// Sets the `supportsAllDrives` query parameter.
+    pub fn supports_all_drives(mut self, supports_all_drives: bool) -> Self {
+        self.inner = self.inner.supports_all_drives(supports_all_drives);
+        self
+    }
+
+    /// Sets the `supportsTeamDrives` query parameter.
+    pub fn supports_team_drives(mut self, supports_team_drives: bool) -> Self {
+        self.inner = self.inner.supports_team_drives(supports_team_drives);
+        self
+    }
+
+    /// Sets the `useContentAsIndexableText` query parameter.
+    pub fn use_content_as_indexable_text(mut self, use_content_as_indexable_text: bool) -> Self {
+        self.inner = self
+            .inner
+            .use_content_as_indexable_text(use_content_as_indexable_text);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::File>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Files::copy`], set with a builder.
+pub struct FilesCopyRequest<'a> {
+    inner: crate::files::FilesCopyRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> FilesCopyRequest<'a> {
+    /// Sets the `ignoreDefaultVisibility` query parameter.
+    pub fn ignore_default_visibility(mut self, ignore_default_visibility: bool) -> Self {
+        self.inner = self
+            .inner
+            .ignore_default_visibility(ignore_default_visibility);
+        self
+    }
+
+    /// Sets the `includePermissionsForView` query parameter.
+    pub fn include_permissions_for_view(mut self, include_permissions_for_view: &'a str) -> Self {
+        self.inner = self
+            .inner
+            .include_permissions_for_view(include_permissions_for_view);
+        self
+    }
+
+    /// Sets the `keepRevisionForever` query parameter.
+    pub fn keep_revision_forever(mut self, keep_revision_forever: bool) -> Self {
+        self.inner = self.inner.keep_revision_forever(keep_revision_forever);
+        self
+    }
+
+    /// Sets the `ocrLanguage` query parameter.
+    pub fn ocr_language(mut self, ocr_language: &'a str) -> Self {
+        self.inner = self.inner.ocr_language(ocr_language);
+        self
+    }
+
+    /// Sets the `supportsAllDrives` query parameter.
+    pub fn supports_all_drives(mut self, supports_all_drives: bool) -> Self {
+        self.inner = self.inner.supports_all_drives(supports_all_drives);
+        self
+    }
+
+    /// Sets the `supportsTeamDrives` query parameter.
+    pub fn supports_team_drives(mut self, supports_team_drives: bool) -> Self {
+        self.inner = self.inner.supports_team_drives(supports_team_drives);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::File>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Files::watch`], set with a builder.
+pub struct FilesWatchRequest<'a> {
+    inner: crate::files::FilesWatchRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> FilesWatchRequest<'a> {
+    /// Sets the `acknowledgeAbuse` query parameter.
+    pub fn acknowledge_abuse(mut self, acknowledge_abuse: bool) -> Self {
+        self.inner = self.inner.acknowledge_abuse(acknowledge_abuse);
+        self
+    }
+
+    /// Sets the `includePermissionsForView` query parameter.
+    pub fn include_permissions_for_view(mut self, include_permissions_for_view: &'a str) -> Self {
+        self.inner = self
+            .inner
+            .include_permissions_for_view(include_permissions_for_view);
+        self
+    }
+
+    /// Sets the `supportsAllDrives` query parameter.
+    pub fn supports_all_drives(mut self, supports_all_drives: bool) -> Self {
+        self.inner = self.inner.supports_all_drives(supports_all_drives);
+        self
+    }
+
+    /// Sets the `supportsTeamDrives` query parameter.
+    pub fn supports_team_drives(mut self, supports_team_drives: bool) -> Self {
+        self.inner = self.inner.supports_team_drives(supports_team_drives);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::Channel>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::files::Files`].
+pub struct Files {
+    inner: crate::files::Files,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Files {
+    pub(crate) fn new(inner: crate::files::Files, runtime: Arc<tokio::runtime::Runtime>) -> Self {
+        Files { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::files::Files::list`].
+    pub fn list(
+        &self,
+        corpora: &str,
+        drive_id: &str,
+        include_items_from_all_drives: bool,
+        include_permissions_for_view: &str,
+        include_team_drive_items: bool,
+        order_by: &str,
+        page_size: i64,
+        page_token: &str,
+        q: &str,
+        spaces: &str,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+        team_drive_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::File>>> {
+        self.runtime.block_on(self.inner.list(
+            corpora,
+            drive_id,
+            include_items_from_all_drives,
+            include_permissions_for_view,
+            include_team_drive_items,
+            order_by,
+            page_size,
+            page_token,
+            q,
+            spaces,
+            supports_all_drives,
+            supports_team_drives,
+            team_drive_id,
+        ))
+    }
+    /// The blocking version of [`crate::files::Files::list_request`].
+    pub fn list_request(&self) -> FilesListRequest<'_> {
+        FilesListRequest {
+            inner: self.inner.list_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::files::Files::list_all`].
+    pub fn list_all(
+        &self,
+        corpora: &str,
+        drive_id: &str,
+        include_items_from_all_drives: bool,
+        include_permissions_for_view: &str,
+        include_team_drive_items: bool,
+        order_by: &str,
+        q: &str,
+        spaces: &str,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+        team_drive_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::File>>> {
+        self.runtime.block_on(self.inner.list_all(
+            corpora,
+            drive_id,
+            include_items_from_all_drives,
+            include_permissions_for_view,
+            include_team_drive_items,
+            order_by,
+            q,
+            spaces,
+            supports_all_drives,
+            supports_team_drives,
+            team_drive_id,
+        ))
+    }
+    /// The blocking version of [`crate::files::Files::create`].
+    pub fn create(
+        &self,
+        ignore_default_visibility: bool,
+        include_permissions_for_view: &str,
+        keep_revision_forever: bool,
+        ocr_language: &str,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+        use_content_as_indexable_text: bool,
+        body: &crate::types::File,
+    ) -> ClientResult<crate::Response<crate::types::File>> {
+        self.runtime.block_on(self.inner.create(
+            ignore_default_visibility,
+            include_permissions_for_view,
+            keep_revision_forever,
+            ocr_language,
+            supports_all_drives,
+            supports_team_drives,
+            use_content_as_indexable_text,
+            body,
+        ))
+    }
+    /// The blocking version of [`crate::files::Files::create_request`].
+    pub fn create_request<'a>(&'a self, body: &'a crate::types::File) -> FilesCreateRequest<'a> {
+        FilesCreateRequest {
+            inner: self.inner.create_request(body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::files::Files::generate_id`].
+    pub fn generate_id(
+        &self,
+        count: i64,
+        space: &str,
+        type_: &str,
+    ) -> ClientResult<crate::Response<crate::types::GeneratedIds>> {
+        self.runtime
+            .block_on(self.inner.generate_id(count, space, type_))
+    }
+    /// The blocking version of [`crate::files::Files::generate_id_request`].
+    pub fn generate_id_request(&self) -> FilesGenerateIdRequest<'_> {
+        FilesGenerateIdRequest {
+            inner: self.inner.generate_id_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::files::Files::empty_trash`].
+    pub fn empty_trash(&self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.empty_trash())
+    }
+    /// The blocking version of [`crate::files::Files::get`].
+    pub fn get(
+        &self,
+        file_id: &str,
+        acknowledge_abuse: bool,
+        include_permissions_for_view: &str,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+    ) -> ClientResult<crate::Response<crate::types::File>> {
+        self.runtime.block_on(self.inner.get(
+            file_id,
+            acknowledge_abuse,
+            include_permissions_for_view,
+            supports_all_drives,
+            supports_team_drives,
+        ))
+    }
+    /// The blocking version of [`crate::files::Files::get_request`].
+    pub fn get_request<'a>(&'a self, file_id: &'a str) -> FilesGetRequest<'a> {
+        FilesGetRequest {
+            inner: self.inner.get_request(file_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::files::Files::delete`].
+    pub fn delete(
+        &self,
+        file_id: &str,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .delete(file_id, supports_all_drives, supports_team_drives),
+        )
+    }
+    /// The blocking version of [`crate::files::Files::delete_request`].
+    pub fn delete_request<'a>(&'a self, file_id: &'a str) -> FilesDeleteRequest<'a> {
+        FilesDeleteRequest {
+            inner: self.inner.delete_request(file_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::files::Files::update`].
+    pub fn update(
+        &self,
+        file_id: &str,
+        add_parents: &str,
+        include_permissions_for_view: &str,
+        keep_revision_forever: bool,
+        ocr_language: &str,
+        remove_parents: &str,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+        use_content_as_indexable_text: bool,
+        body: &crate::types::File,
+    ) -> ClientResult<crate::Response<crate::types::File>> {
+        self.runtime.block_on(self.inner.update(
+            file_id,
+            add_parents,
+            include_permissions_for_view,
+            keep_revision_forever,
+            ocr_language,
+            remove_parents,
+            supports_all_drives,
+            supports_team_drives,
+            use_content_as_indexable_text,
+            body,
+        ))
+    }
+    /// The blocking version of [`crate::files::Files::update_request`].
+    pub fn update_request<'a>(
+        &'a self,
+        file_id: &'a str,
+        body: &'a crate::types::File,
+    ) -> FilesUpdateRequest<'a> {
+        FilesUpdateRequest {
+            inner: self.inner.update_request(file_id, body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::files::Files::copy`].
+    pub fn copy(
+        &self,
+        file_id: &str,
+        ignore_default_visibility: bool,
+        include_permissions_for_view: &str,
+        keep_revision_forever: bool,
+        ocr_language: &str,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+        body: &crate::types::File,
+    ) -> ClientResult<crate::Response<crate::types::File>> {
+        self.runtime.block_on(self.inner.copy(
+            file_id,
+            ignore_default_visibility,
+            include_permissions_for_view,
+            keep_revision_forever,
+            ocr_language,
+            supports_all_drives,
+            supports_team_drives,
+            body,
+        ))
+    }
+    /// The blocking version of [`crate::files::Files::copy_request`].
+    pub fn copy_request<'a>(
+        &'a self,
+        file_id: &'a str,
+        body: &'a crate::types::File,
+    ) -> FilesCopyRequest<'a> {
+        FilesCopyRequest {
+            inner: self.inner.copy_request(file_id, body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::files::Files::export`].
+    pub fn export(&self, file_id: &str, mime_type: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.export(file_id, mime_type))
+    }
+    /// The blocking version of [`crate::files::Files::watch`].
+    pub fn watch(
+        &self,
+        file_id: &str,
+        acknowledge_abuse: bool,
+        include_permissions_for_view: &str,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+        body: &crate::types::Channel,
+    ) -> ClientResult<crate::Response<crate::types::Channel>> {
+        self.runtime.block_on(self.inner.watch(
+            file_id,
+            acknowledge_abuse,
+            include_permissions_for_view,
+            supports_all_drives,
+            supports_team_drives,
+            body,
+        ))
+    }
+    /// The blocking version of [`crate::files::Files::watch_request`].
+    pub fn watch_request<'a>(
+        &'a self,
+        file_id: &'a str,
+        body: &'a crate::types::Channel,
+    ) -> FilesWatchRequest<'a> {
+        FilesWatchRequest {
+            inner: self.inner.watch_request(file_id, body),
+            runtime: &self.runtime,
+        }
+    }
+}
@@ -0,0 +1,100 @@
+//! A blocking client, for the programs that are not async.
+//!
+//! It has the functions of the async [`Client`](crate::Client), which it runs on a
+//! runtime of its own, so they must not be called from within an async runtime. The
+//! `*_stream` functions are left out, the `*_all` ones returning all the pages at once.
+
+use std::{future::Future, sync::Arc};
+
+pub mod about;
+pub mod changes;
+pub mod channels;
+pub mod comments;
+pub mod drives;
+pub mod files;
+pub mod permissions;
+pub mod replies;
+pub mod revisions;
+pub mod teamdrives;
+
+/// A blocking client, sending its requests with an async [`Client`](crate::Client).
+#[derive(Clone)]
+pub struct Client {
+    inner: crate::Client,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Client {
+    /// Wraps an async client, creating the runtime its requests run on.
+    pub fn new(client: crate::Client) -> std::io::Result<Self> {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()?;
+
+        Ok(Client {
+            inner: client,
+            runtime: Arc::new(runtime),
+        })
+    }
+
+    /// Returns the async client the requests are sent with.
+    pub fn inner(&self) -> &crate::Client {
+        &self.inner
+    }
+
+    /// Runs a future to completion on the runtime of the client, for the async
+    /// functions that have no blocking version.
+    pub fn block_on<F: Future>(&self, future: F) -> F::Output {
+        self.runtime.block_on(future)
+    }
+
+    /// The blocking version of [`crate::Client::about`].
+    pub fn about(&self) -> about::About {
+        about::About::new(self.inner.about(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::changes`].
+    pub fn changes(&self) -> changes::Changes {
+        changes::Changes::new(self.inner.changes(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::channels`].
+    pub fn channels(&self) -> channels::Channels {
+        channels::Channels::new(self.inner.channels(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::comments`].
+    pub fn comments(&self) -> comments::Comments {
+        comments::Comments::new(self.inner.comments(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::drives`].
+    pub fn drives(&self) -> drives::Drives {
+        drives::Drives::new(self.inner.drives(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::files`].
+    pub fn files(&self) -> files::Files {
+        files::Files::new(self.inner.files(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::permissions`].
+    pub fn permissions(&self) -> permissions::Permissions {
+        permissions::Permissions::new(self.inner.permissions(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::replies`].
+    pub fn replies(&self) -> replies::Replies {
+        replies::Replies::new(self.inner.replies(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::revisions`].
+    pub fn revisions(&self) -> revisions::Revisions {
+        revisions::Revisions::new(self.inner.revisions(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::teamdrives`].
+    pub fn teamdrives(&self) -> teamdrives::Teamdrives {
+        teamdrives::Teamdrives::new(self.inner.teamdrives(), self.runtime.clone())
+    }
+}
@@ -0,0 +1,402 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Permissions::list`], set with a builder.
+pub struct PermissionsListRequest<'a> {
+    inner: crate::permissions::PermissionsListRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> PermissionsListRequest<'a> {
+    /// Sets the `includePermissionsForView` query parameter.
+    pub fn include_permissions_for_view(mut self, include_permissions_for_view: &'a str) -> Self {
+        self.inner = self
+            .inner
+            .include_permissions_for_view(include_permissions_for_view);
+        self
+    }
+
+    /// Sets the `pageSize` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Sets the `pageToken` query parameter.
+    pub fn page_token(mut self, page_token: &'a str) -> Self {
+        self.inner = self.inner.page_token(page_token);
+        self
+    }
+
+    /// Sets the `supportsAllDrives` query parameter.
+    pub fn supports_all_drives(mut self, supports_all_drives: bool) -> Self {
+        self.inner = self.inner.supports_all_drives(supports_all_drives);
+        self
+    }
+
+    /// Sets the `supportsTeamDrives` query parameter.
+    pub fn supports_team_drives(mut self, supports_team_drives: bool) -> Self {
+        self.inner = self.inner.supports_team_drives(supports_team_drives);
+        self
+    }
+
+    /// Sets the `useDomainAdminAccess` query parameter.
+    pub fn use_domain_admin_access(mut self, use_domain_admin_access: bool) -> Self {
+        self.inner = self.inner.use_domain_admin_access(use_domain_admin_access);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Permission>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Permissions::create`], set with a builder.
+pub struct PermissionsCreateRequest<'a> {
+    inner: crate::permissions::PermissionsCreateRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> PermissionsCreateRequest<'a> {
+    /// Sets the `emailMessage` query parameter.
+    pub fn email_message(mut self, email_message: &'a str) -> Self {
+        self.inner = self.inner.email_message(email_message);
+        self
+    }
+
+    /// Sets the `moveToNewOwnersRoot` query parameter.
+    pub fn move_to_new_owners_root(mut self, move_to_new_owners_root: bool) -> Self {
+        self.inner = self.inner.move_to_new_owners_root(move_to_new_owners_root);
+        self
+    }
+
+    /// Sets the `sendNotificationEmail` query parameter.
+    pub fn send_notification_email(mut self, send_notification_email: bool) -> Self {
+        self.inner = self.inner.send_notification_email(send_notification_email);
+        self
+    }
+
+    /// Sets the `supportsAllDrives` query parameter.
+    pub fn supports_all_drives(mut self, supports_all_drives: bool) -> Self {
+        self.inner = self.inner.supports_all_drives(supports_all_drives);
+        self
+    }
+
+    /// Sets the `supportsTeamDrives` query parameter.
+    pub fn supports_team_drives(mut self, supports_team_drives: bool) -> Self {
+        self.inner = self.inner.supports_team_drives(supports_team_drives);
+        self
+    }
+
+    /// Sets the `transferOwnership` query parameter.
+    pub fn transfer_ownership(mut self, transfer_ownership: bool) -> Self {
+        self.inner = self.inner.transfer_ownership(transfer_ownership);
+        self
+    }
+
+    /// Sets the `useDomainAdminAccess` query parameter.
+    pub fn use_domain_admin_access(mut self, use_domain_admin_access: bool) -> Self {
+        self.inner = self.inner.use_domain_admin_access(use_domain_admin_access);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::Permission>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Permissions::get`], set with a builder.
+pub struct PermissionsGetRequest<'a> {
+    inner: crate::permissions::PermissionsGetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> PermissionsGetRequest<'a> {
+    /// Sets the `supportsAllDrives` query parameter.
+    pub fn supports_all_drives(mut self, supports_all_drives: bool) -> Self {
+        self.inner = self.inner.supports_all_drives(supports_all_drives);
+        self
+    }
+
+    /// Sets the `supportsTeamDrives` query parameter.
+    pub fn supports_team_drives(mut self, supports_team_drives: bool) -> Self {
+        self.inner = self.inner.supports_team_drives(supports_team_drives);
+        self
+    }
+
+    /// Sets the `useDomainAdminAccess` query parameter.
+    pub fn use_domain_admin_access(mut self, use_domain_admin_access: bool) -> Self {
+        self.inner = self.inner.use_domain_admin_access(use_domain_admin_access);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::Permission>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Permissions::delete`], set with a builder.
+pub struct PermissionsDeleteRequest<'a> {
+    inner: crate::permissions::PermissionsDeleteRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> PermissionsDeleteRequest<'a> {
+    /// Sets the `supportsAllDrives` query parameter.
+    pub fn supports_all_drives(mut self, supports_all_drives: bool) -> Self {
+        self.inner = self.inner.supports_all_drives(supports_all_drives);
+        self
+    }
+
+    /// Sets the `supportsTeamDrives` query parameter.
+    pub fn supports_team_drives(mut self, supports_team_drives: bool) -> Self {
+        self.inner = self.inner.supports_team_drives(supports_team_drives);
+        self
+    }
+
+    /// Sets the `useDomainAdminAccess` query parameter.
+    pub fn use_domain_admin_access(mut self, use_domain_admin_access: bool) -> Self {
+        self.inner = self.inner.use_domain_admin_access(use_domain_admin_access);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Permissions::update`], set with a builder.
+pub struct PermissionsUpdateRequest<'a> {
+    inner: crate::permissions::PermissionsUpdateRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> PermissionsUpdateRequest<'a> {
+    /// Sets the `removeExpiration` query parameter.
+    pub fn remove_expiration(mut self, remove_expiration: bool) -> Self {
+        self.inner = self.inner.remove_expiration(remove_expiration);
+        self
+    }
+
+    /// Sets the `supportsAllDrives` query parameter.
+    pub fn supports_all_drives(mut self, supports_all_drives: bool) -> Self {
+        self.inner = self.inner.supports_all_drives(supports_all_drives);
+        self
+    }
+
+    /// Sets the `supportsTeamDrives` query parameter.
+    pub fn supports_team_drives(mut self, supports_team_drives: bool) -> Self {
+        self.inner = self.inner.supports_team_drives(supports_team_drives);
+        self
+    }
+
+    /// Sets the `transferOwnership` query parameter.
+    pub fn transfer_ownership(mut self, transfer_ownership: bool) -> Self {
+        self.inner = self.inner.transfer_ownership(transfer_ownership);
+        self
+    }
+
+    /// Sets the `useDomainAdminAccess` query parameter.
+    pub fn use_domain_admin_access(mut self, use_domain_admin_access: bool) -> Self {
+        self.inner = self.inner.use_domain_admin_access(use_domain_admin_access);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::Permission>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::permissions::Permissions`].
+pub struct Permissions {
+    inner: crate::permissions::Permissions,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Permissions {
+    pub(crate) fn new(
+        inner: crate::permissions::Permissions,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Permissions { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::permissions::Permissions::list`].
+    pub fn list(
+        &self,
+        file_id: &str,
+        include_permissions_for_view: &str,
+        page_size: i64,
+        page_token: &str,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+        use_domain_admin_access: bool,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Permission>>> {
+        self.runtime.block_on(self.inner.list(
+            file_id,
+            include_permissions_for_view,
+            page_size,
+            page_token,
+            supports_all_drives,
+            supports_team_drives,
+            use_domain_admin_access,
+        ))
+    }
+    /// The blocking version of [`crate::permissions::Permissions::list_request`].
+    pub fn list_request<'a>(&'a self, file_id: &'a str) -> PermissionsListRequest<'a> {
+        PermissionsListRequest {
+            inner: self.inner.list_request(file_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::permissions::Permissions::list_all`].
+    pub fn list_all(
+        &self,
+        file_id: &str,
+        include_permissions_for_view: &str,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+        use_domain_admin_access: bool,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Permission>>> {
+        self.runtime.block_on(self.inner.list_all(
+            file_id,
+            include_permissions_for_view,
+            supports_all_drives,
+            supports_team_drives,
+            use_domain_admin_access,
+        ))
+    }
+    /// The blocking version of [`crate::permissions::Permissions::create`].
+    pub fn create(
+        &self,
+        file_id: &str,
+        email_message: &str,
+        move_to_new_owners_root: bool,
+        send_notification_email: bool,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+        transfer_ownership: bool,
+        use_domain_admin_access: bool,
+        body: &crate::types::Permission,
+    ) -> ClientResult<crate::Response<crate::types::Permission>> {
+        self.runtime.block_on(self.inner.create(
+            file_id,
+            email_message,
+            move_to_new_owners_root,
+            send_notification_email,
+            supports_all_drives,
+            supports_team_drives,
+            transfer_ownership,
+            use_domain_admin_access,
+            body,
+        ))
+    }
+    /// The blocking version of [`crate::permissions::Permissions::create_request`].
+    pub fn create_request<'a>(
+        &'a self,
+        file_id: &'a str,
+        body: &'a crate::types::Permission,
+    ) -> PermissionsCreateRequest<'a> {
+        PermissionsCreateRequest {
+            inner: self.inner.create_request(file_id, body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::permissions::Permissions::get`].
+    pub fn get(
+        &self,
+        file_id: &str,
+        permission_id: &str,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+        use_domain_admin_access: bool,
+    ) -> ClientResult<crate::Response<crate::types::Permission>> {
+        self.runtime.block_on(self.inner.get(
+            file_id,
+            permission_id,
+            supports_all_drives,
+            supports_team_drives,
+            use_domain_admin_access,
+        ))
+    }
+    /// The blocking version of [`crate::permissions::Permissions::get_request`].
+    pub fn get_request<'a>(
+        &'a self,
+        file_id: &'a str,
+        permission_id: &'a str,
+    ) -> PermissionsGetRequest<'a> {
+        PermissionsGetRequest {
+            inner: self.inner.get_request(file_id, permission_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::permissions::Permissions::delete`].
+    pub fn delete(
+        &self,
+        file_id: &str,
+        permission_id: &str,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+        use_domain_admin_access: bool,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.delete(
+            file_id,
+            permission_id,
+            supports_all_drives,
+            supports_team_drives,
+            use_domain_admin_access,
+        ))
+    }
+    /// The blocking version of [`crate::permissions::Permissions::delete_request`].
+    pub fn delete_request<'a>(
+        &'a self,
+        file_id: &'a str,
+        permission_id: &'a str,
+    ) -> PermissionsDeleteRequest<'a> {
+        PermissionsDeleteRequest {
+            inner: self.inner.delete_request(file_id, permission_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::permissions::Permissions::update`].
+    pub fn update(
+        &self,
+        file_id: &str,
+        permission_id: &str,
+        remove_expiration: bool,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+        transfer_ownership: bool,
+        use_domain_admin_access: bool,
+        body: &crate::types::Permission,
+    ) -> ClientResult<crate::Response<crate::types::Permission>> {
+        self.runtime.block_on(self.inner.update(
+            file_id,
+            permission_id,
+            remove_expiration,
+            supports_all_drives,
+            supports_team_drives,
+            transfer_ownership,
+            use_domain_admin_access,
+            body,
+        ))
+    }
+    /// The blocking version of [`crate::permissions::Permissions::update_request`].
+    pub fn update_request<'a>(
+        &'a self,
+        file_id: &'a str,
+        permission_id: &'a str,
+        body: &'a crate::types::Permission,
+    ) -> PermissionsUpdateRequest<'a> {
+        PermissionsUpdateRequest {
+            inner: self.inner.update_request(file_id, permission_id, body),
+            runtime: &self.runtime,
+        }
+    }
+}
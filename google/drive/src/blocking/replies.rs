@@ -0,0 +1,163 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Replies::list`], set with a builder.
+pub struct RepliesListRequest<'a> {
+    inner: crate::replies::RepliesListRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> RepliesListRequest<'a> {
+    /// Sets the `includeDeleted` query parameter.
+    pub fn include_deleted(mut self, include_deleted: bool) -> Self {
+        self.inner = self.inner.include_deleted(include_deleted);
+        self
+    }
+
+    /// Sets the `pageSize` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Sets the `pageToken` query parameter.
+    pub fn page_token(mut self, page_token: &'a str) -> Self {
+        self.inner = self.inner.page_token(page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Reply>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Replies::get`], set with a builder.
+pub struct RepliesGetRequest<'a> {
+    inner: crate::replies::RepliesGetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> RepliesGetRequest<'a> {
+    /// Sets the `includeDeleted` query parameter.
+    pub fn include_deleted(mut self, include_deleted: bool) -> Self {
+        self.inner = self.inner.include_deleted(include_deleted);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::Reply>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::replies::Replies`].
+pub struct Replies {
+    inner: crate::replies::Replies,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Replies {
+    pub(crate) fn new(
+        inner: crate::replies::Replies,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Replies { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::replies::Replies::list`].
+    pub fn list(
+        &self,
+        file_id: &str,
+        comment_id: &str,
+        include_deleted: bool,
+        page_size: i64,
+        page_token: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Reply>>> {
+        self.runtime.block_on(self.inner.list(
+            file_id,
+            comment_id,
+            include_deleted,
+            page_size,
+            page_token,
+        ))
+    }
+    /// The blocking version of [`crate::replies::Replies::list_request`].
+    pub fn list_request<'a>(
+        &'a self,
+        file_id: &'a str,
+        comment_id: &'a str,
+    ) -> RepliesListRequest<'a> {
+        RepliesListRequest {
+            inner: self.inner.list_request(file_id, comment_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::replies::Replies::list_all`].
+    pub fn list_all(
+        &self,
+        file_id: &str,
+        comment_id: &str,
+        include_deleted: bool,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Reply>>> {
+        self.runtime
+            .block_on(self.inner.list_all(file_id, comment_id, include_deleted))
+    }
+    /// The blocking version of [`crate::replies::Replies::create`].
+    pub fn create(
+        &self,
+        file_id: &str,
+        comment_id: &str,
+        body: &crate::types::Reply,
+    ) -> ClientResult<crate::Response<crate::types::Reply>> {
+        self.runtime
+            .block_on(self.inner.create(file_id, comment_id, body))
+    }
+    /// The blocking version of [`crate::replies::Replies::get`].
+    pub fn get(
+        &self,
+        file_id: &str,
+        comment_id: &str,
+        reply_id: &str,
+        include_deleted: bool,
+    ) -> ClientResult<crate::Response<crate::types::Reply>> {
+        self.runtime.block_on(
+            self.inner
+                .get(file_id, comment_id, reply_id, include_deleted),
+        )
+    }
+    /// The blocking version of [`crate::replies::Replies::get_request`].
+    pub fn get_request<'a>(
+        &'a self,
+        file_id: &'a str,
+        comment_id: &'a str,
+        reply_id: &'a str,
+    ) -> RepliesGetRequest<'a> {
+        RepliesGetRequest {
+            inner: self.inner.get_request(file_id, comment_id, reply_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::replies::Replies::delete`].
+    pub fn delete(
+        &self,
+        file_id: &str,
+        comment_id: &str,
+        reply_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete(file_id, comment_id, reply_id))
+    }
+    /// The blocking version of [`crate::replies::Replies::update`].
+    pub fn update(
+        &self,
+        file_id: &str,
+        comment_id: &str,
+        reply_id: &str,
+        body: &crate::types::Reply,
+    ) -> ClientResult<crate::Response<crate::types::Reply>> {
+        self.runtime
+            .block_on(self.inner.update(file_id, comment_id, reply_id, body))
+    }
+}
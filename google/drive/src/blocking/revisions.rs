@@ -0,0 +1,123 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Revisions::list`], set with a builder.
+pub struct RevisionsListRequest<'a> {
+    inner: crate::revisions::RevisionsListRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> RevisionsListRequest<'a> {
+    /// Sets the `pageSize` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Sets the `pageToken` query parameter.
+    pub fn page_token(mut self, page_token: &'a str) -> Self {
+        self.inner = self.inner.page_token(page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Revision>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Revisions::get`], set with a builder.
+pub struct RevisionsGetRequest<'a> {
+    inner: crate::revisions::RevisionsGetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> RevisionsGetRequest<'a> {
+    /// Sets the `acknowledgeAbuse` query parameter.
+    pub fn acknowledge_abuse(mut self, acknowledge_abuse: bool) -> Self {
+        self.inner = self.inner.acknowledge_abuse(acknowledge_abuse);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::Revision>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::revisions::Revisions`].
+pub struct Revisions {
+    inner: crate::revisions::Revisions,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Revisions {
+    pub(crate) fn new(
+        inner: crate::revisions::Revisions,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Revisions { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::revisions::Revisions::list`].
+    pub fn list(
+        &self,
+        file_id: &str,
+        page_size: i64,
+        page_token: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Revision>>> {
+        self.runtime
+            .block_on(self.inner.list(file_id, page_size, page_token))
+    }
+    /// The blocking version of [`crate::revisions::Revisions::list_request`].
+    pub fn list_request<'a>(&'a self, file_id: &'a str) -> RevisionsListRequest<'a> {
+        RevisionsListRequest {
+            inner: self.inner.list_request(file_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::revisions::Revisions::list_all`].
+    pub fn list_all(
+        &self,
+        file_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Revision>>> {
+        self.runtime.block_on(self.inner.list_all(file_id))
+    }
+    /// The blocking version of [`crate::revisions::Revisions::get`].
+    pub fn get(
+        &self,
+        file_id: &str,
+        revision_id: &str,
+        acknowledge_abuse: bool,
+    ) -> ClientResult<crate::Response<crate::types::Revision>> {
+        self.runtime
+            .block_on(self.inner.get(file_id, revision_id, acknowledge_abuse))
+    }
+    /// The blocking version of [`crate::revisions::Revisions::get_request`].
+    pub fn get_request<'a>(
+        &'a self,
+        file_id: &'a str,
+        revision_id: &'a str,
+    ) -> RevisionsGetRequest<'a> {
+        RevisionsGetRequest {
+            inner: self.inner.get_request(file_id, revision_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::revisions::Revisions::delete`].
+    pub fn delete(&self, file_id: &str, revision_id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete(file_id, revision_id))
+    }
+    /// The blocking version of [`crate::revisions::Revisions::update`].
+    pub fn update(
+        &self,
+        file_id: &str,
+        revision_id: &str,
+        body: &crate::types::Revision,
+    ) -> ClientResult<crate::Response<crate::types::Revision>> {
+        self.runtime
+            .block_on(self.inner.update(file_id, revision_id, body))
+    }
+}
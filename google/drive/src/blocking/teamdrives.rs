@@ -0,0 +1,174 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Teamdrives::list`], set with a builder.
+pub struct TeamdrivesListRequest<'a> {
+    inner: crate::teamdrives::TeamdrivesListRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> TeamdrivesListRequest<'a> {
+    /// Sets the `pageSize` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Sets the `pageToken` query parameter.
+    pub fn page_token(mut self, page_token: &'a str) -> Self {
+        self.inner = self.inner.page_token(page_token);
+        self
+    }
+
+    /// Sets the `q` query parameter.
+    pub fn q(mut self, q: &'a str) -> Self {
+        self.inner = self.inner.q(q);
+        self
+    }
+
+    /// Sets the `useDomainAdminAccess` query parameter.
+    pub fn use_domain_admin_access(mut self, use_domain_admin_access: bool) -> Self {
+        self.inner = self.inner.use_domain_admin_access(use_domain_admin_access);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::TeamDrive>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Teamdrives::get`], set with a builder.
+pub struct TeamdrivesGetRequest<'a> {
+    inner: crate::teamdrives::TeamdrivesGetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> TeamdrivesGetRequest<'a> {
+    /// Sets the `useDomainAdminAccess` query parameter.
+    pub fn use_domain_admin_access(mut self, use_domain_admin_access: bool) -> Self {
+        self.inner = self.inner.use_domain_admin_access(use_domain_admin_access);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::TeamDrive>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Teamdrives::update`], set with a builder.
+pub struct TeamdrivesUpdateRequest<'a> {
+    inner: crate::teamdrives::TeamdrivesUpdateRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> TeamdrivesUpdateRequest<'a> {
+    /// Sets the `useDomainAdminAccess` query parameter.
+    pub fn use_domain_admin_access(mut self, use_domain_admin_access: bool) -> Self {
+        self.inner = self.inner.use_domain_admin_access(use_domain_admin_access);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::TeamDrive>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::teamdrives::Teamdrives`].
+pub struct Teamdrives {
+    inner: crate::teamdrives::Teamdrives,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Teamdrives {
+    pub(crate) fn new(
+        inner: crate::teamdrives::Teamdrives,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Teamdrives { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::teamdrives::Teamdrives::list`].
+    pub fn list(
+        &self,
+        page_size: i64,
+        page_token: &str,
+        q: &str,
+        use_domain_admin_access: bool,
+    ) -> ClientResult<crate::Response<Vec<crate::types::TeamDrive>>> {
+        self.runtime.block_on(
+            self.inner
+                .list(page_size, page_token, q, use_domain_admin_access),
+        )
+    }
+    /// The blocking version of [`crate::teamdrives::Teamdrives::list_request`].
+    pub fn list_request(&self) -> TeamdrivesListRequest<'_> {
+        TeamdrivesListRequest {
+            inner: self.inner.list_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::teamdrives::Teamdrives::list_all`].
+    pub fn list_all(
+        &self,
+        q: &str,
+        use_domain_admin_access: bool,
+    ) -> ClientResult<crate::Response<Vec<crate::types::TeamDrive>>> {
+        self.runtime
+            .block_on(self.inner.list_all(q, use_domain_admin_access))
+    }
+    /// The blocking version of [`crate::teamdrives::Teamdrives::create`].
+    pub fn create(
+        &self,
+        request_id: &str,
+        body: &crate::types::TeamDrive,
+    ) -> ClientResult<crate::Response<crate::types::TeamDrive>> {
+        self.runtime.block_on(self.inner.create(request_id, body))
+    }
+    /// The blocking version of [`crate::teamdrives::Teamdrives::get`].
+    pub fn get(
+        &self,
+        team_drive_id: &str,
+        use_domain_admin_access: bool,
+    ) -> ClientResult<crate::Response<crate::types::TeamDrive>> {
+        self.runtime
+            .block_on(self.inner.get(team_drive_id, use_domain_admin_access))
+    }
+    /// The blocking version of [`crate::teamdrives::Teamdrives::get_request`].
+    pub fn get_request<'a>(&'a self, team_drive_id: &'a str) -> TeamdrivesGetRequest<'a> {
+        TeamdrivesGetRequest {
+            inner: self.inner.get_request(team_drive_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::teamdrives::Teamdrives::delete`].
+    pub fn delete(&self, team_drive_id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.delete(team_drive_id))
+    }
+    /// The blocking version of [`crate::teamdrives::Teamdrives::update`].
+    pub fn update(
+        &self,
+        team_drive_id: &str,
+        use_domain_admin_access: bool,
+        body: &crate::types::TeamDrive,
+    ) -> ClientResult<crate::Response<crate::types::TeamDrive>> {
+        self.runtime.block_on(
+            self.inner
+                .update(team_drive_id, use_domain_admin_access, body),
+        )
+    }
+    /// The blocking version of [`crate::teamdrives::Teamdrives::update_request`].
+    pub fn update_request<'a>(
+        &'a self,
+        team_drive_id: &'a str,
+        body: &'a crate::types::TeamDrive,
+    ) -> TeamdrivesUpdateRequest<'a> {
+        TeamdrivesUpdateRequest {
+            inner: self.inner.update_request(team_drive_id, body),
+            runtime: &self.runtime,
+        }
+    }
+}
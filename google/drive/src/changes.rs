@@ -1,6 +1,421 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Changes::list`], set with a builder.
+pub struct ChangesListRequest<'a> {
+    client: &'a Changes,
+    page_token: &'a str,
+    drive_id: Option<&'a str>,
+    include_corpus_removals: Option<bool>,
+    include_items_from_all_drives: Option<bool>,
+    include_permissions_for_view: Option<&'a str>,
+    include_removed: Option<bool>,
+    include_team_drive_items: Option<bool>,
+    page_size: Option<i64>,
+    restrict_to_my_drive: Option<bool>,
+    spaces: Option<&'a str>,
+    supports_all_drives: Option<bool>,
+    supports_team_drives: Option<bool>,
+    team_drive_id: Option<&'a str>,
+}
+
+impl<'a> ChangesListRequest<'a> {
+    /// Sets the `driveId` query parameter.
+    pub fn drive_id(mut self, drive_id: &'a str) -> Self {
+        self.drive_id = Some(drive_id);
+        self
+    }
+
+    /// Sets the `includeCorpusRemovals` query parameter.
+    pub fn include_corpus_removals(mut self, include_corpus_removals: bool) -> Self {
+        self.include_corpus_removals = Some(include_corpus_removals);
+        self
+    }
+
+    /// Sets the `includeItemsFromAllDrives` query parameter.
+    pub fn include_items_from_all_drives(mut self, include_items_from_all_drives: bool) -> Self {
+        self.include_items_from_all_drives = Some(include_items_from_all_drives);
+        self
+    }
+
+    /// Sets the `includePermissionsForView` query parameter.
+    pub fn include_permissions_for_view(mut self, include_permissions_for_view: &'a str) -> Self {
+        self.include_permissions_for_view = Some(include_permissions_for_view);
+        self
+    }
+
+    /// Sets the `includeRemoved` query parameter.
+    pub fn include_removed(mut self, include_removed: bool) -> Self {
+        self.include_removed = Some(include_removed);
+        self
+    }
+
+    /// Sets the `includeTeamDriveItems` query parameter.
+    pub fn include_team_drive_items(mut self, include_team_drive_items: bool) -> Self {
+        self.include_team_drive_items = Some(include_team_drive_items);
+        self
+    }
+
+    /// Sets the `pageSize` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Sets the `restrictToMyDrive` query parameter.
+    pub fn restrict_to_my_drive(mut self, restrict_to_my_drive: bool) -> Self {
+        self.restrict_to_my_drive = Some(restrict_to_my_drive);
+        self
+    }
+
+    /// Sets the `spaces` query parameter.
+    pub fn spaces(mut self, spaces: &'a str) -> Self {
+        self.spaces = Some(spaces);
+        self
+    }
+
+    /// Sets the `supportsAllDrives` query parameter.
+    pub fn supports_all_drives(mut self, supports_all_drives: bool) -> Self {
+        self.supports_all_drives = Some(supports_all_drives);
+        self
+    }
+
+    /// Sets the `supportsTeamDrives` query parameter.
+    pub fn supports_team_drives(mut self, supports_team_drives: bool) -> Self {
+        self.supports_team_drives = Some(supports_team_drives);
+        self
+    }
+
+    /// Sets the `teamDriveId` query parameter.
+    pub fn team_drive_id(mut self, team_drive_id: &'a str) -> Self {
+        self.team_drive_id = Some(team_drive_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Change>>> {
+        let Self {
+            client,
+            page_token,
+            drive_id,
+            include_corpus_removals,
+            include_items_from_all_drives,
+            include_permissions_for_view,
+            include_removed,
+            include_team_drive_items,
+            page_size,
+            restrict_to_my_drive,
+            spaces,
+            supports_all_drives,
+            supports_team_drives,
+            team_drive_id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = drive_id {
+            query_args.push(("driveId".to_string(), v.to_string()));
+        }
+        if let Some(v) = include_corpus_removals {
+            query_args.push(("includeCorpusRemovals".to_string(), v.to_string()));
+        }
+        if let Some(v) = include_items_from_all_drives {
+            query_args.push(("includeItemsFromAllDrives".to_string(), v.to_string()));
+        }
+        if let Some(v) = include_permissions_for_view {
+            query_args.push(("includePermissionsForView".to_string(), v.to_string()));
+        }
+        if let Some(v) = include_removed {
+            query_args.push(("includeRemoved".to_string(), v.to_string()));
+        }
+        if let Some(v) = include_team_drive_items {
+            query_args.push(("includeTeamDriveItems".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_size {
+            query_args.push(("pageSize".to_string(), v.to_string()));
+        }
+        if !page_token.is_empty() {
+            query_args.push(("pageToken".to_string(), page_token.to_string()));
+        }
+        if let Some(v) = restrict_to_my_drive {
+            query_args.push(("restrictToMyDrive".to_string(), v.to_string()));
+        }
+        if let Some(v) = spaces {
+            query_args.push(("spaces".to_string(), v.to_string()));
+        }
+        if let Some(v) = supports_all_drives {
+            query_args.push(("supportsAllDrives".to_string(), v.to_string()));
+        }
+        if let Some(v) = supports_team_drives {
+            query_args.push(("supportsTeamDrives".to_string(), v.to_string()));
+        }
+        if let Some(v) = team_drive_id {
+            query_args.push(("teamDriveId".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(&format!("/changes?{}", query_), None);
+
+        let resp: crate::Response<crate::types::ChangeList> = client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await?;
+
+        // Return our response data.
+        Ok(crate::Response::new(
+            resp.status,
+            resp.headers,
+            resp.body.changes.to_vec(),
+        ))
+    }
+}
+
+/// The parameters of [`Changes::get_start_page_token`], set with a builder.
+pub struct ChangesGetStartPageTokenRequest<'a> {
+    client: &'a Changes,
+    drive_id: Option<&'a str>,
+    supports_all_drives: Option<bool>,
+    supports_team_drives: Option<bool>,
+    team_drive_id: Option<&'a str>,
+}
+
+impl<'a> ChangesGetStartPageTokenRequest<'a> {
+    /// Sets the `driveId` query parameter.
+    pub fn drive_id(mut self, drive_id: &'a str) -> Self {
+        self.drive_id = Some(drive_id);
+        self
+    }
+
+    /// Sets the `supportsAllDrives` query parameter.
+    pub fn supports_all_drives(mut self, supports_all_drives: bool) -> Self {
+        self.supports_all_drives = Some(supports_all_drives);
+        self
+    }
+
+    /// Sets the `supportsTeamDrives` query parameter.
+    pub fn supports_team_drives(mut self, supports_team_drives: bool) -> Self {
+        self.supports_team_drives = Some(supports_team_drives);
+        self
+    }
+
+    /// Sets the `teamDriveId` query parameter.
+    pub fn team_drive_id(mut self, team_drive_id: &'a str) -> Self {
+        self.team_drive_id = Some(team_drive_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::StartPageToken>> {
+        let Self {
+            client,
+            drive_id,
+            supports_all_drives,
+            supports_team_drives,
+            team_drive_id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = drive_id {
+            query_args.push(("driveId".to_string(), v.to_string()));
+        }
+        if let Some(v) = supports_all_drives {
+            query_args.push(("supportsAllDrives".to_string(), v.to_string()));
+        }
+        if let Some(v) = supports_team_drives {
+            query_args.push(("supportsTeamDrives".to_string(), v.to_string()));
+        }
+        if let Some(v) = team_drive_id {
+            query_args.push(("teamDriveId".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/changes/startPageToken?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Changes::watch`], set with a builder.
+pub struct ChangesWatchRequest<'a> {
+    client: &'a Changes,
+    page_token: &'a str,
+    drive_id: Option<&'a str>,
+    include_corpus_removals: Option<bool>,
+    include_items_from_all_drives: Option<bool>,
+    include_permissions_for_view: Option<&'a str>,
+    include_removed: Option<bool>,
+    include_team_drive_items: Option<bool>,
+    page_size: Option<i64>,
+    restrict_to_my_drive: Option<bool>,
+    spaces: Option<&'a str>,
+    supports_all_drives: Option<bool>,
+    supports_team_drives: Option<bool>,
+    team_drive_id: Option<&'a str>,
+    body: &'a crate::types::Channel,
+}
+
+impl<'a> ChangesWatchRequest<'a> {
+    /// Sets the `driveId` query parameter.
+    pub fn drive_id(mut self, drive_id: &'a str) -> Self {
+        self.drive_id = Some(drive_id);
+        self
+    }
+
+    /// Sets the `includeCorpusRemovals` query parameter.
+    pub fn include_corpus_removals(mut self, include_corpus_removals: bool) -> Self {
+        self.include_corpus_removals = Some(include_corpus_removals);
+        self
+    }
+
+    /// Sets the `includeItemsFromAllDrives` query parameter.
+    pub fn include_items_from_all_drives(mut self, include_items_from_all_drives: bool) -> Self {
+        self.include_items_from_all_drives = Some(include_items_from_all_drives);
+        self
+    }
+
+    /// Sets the `includePermissionsForView` query parameter.
+    pub fn include_permissions_for_view(mut self, include_permissions_for_view: &'a str) -> Self {
+        self.include_permissions_for_view = Some(include_permissions_for_view);
+        self
+    }
+
+    /// Sets the `includeRemoved` query parameter.
+    pub fn include_removed(mut self, include_removed: bool) -> Self {
+        self.include_removed = Some(include_removed);
+        self
+    }
+
+    /// Sets the `includeTeamDriveItems` query parameter.
+    pub fn include_team_drive_items(mut self, include_team_drive_items: bool) -> Self {
+        self.include_team_drive_items = Some(include_team_drive_items);
+        self
+    }
+
+    /// Sets the `pageSize` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Sets the `restrictToMyDrive` query parameter.
+    pub fn restrict_to_my_drive(mut self, restrict_to_my_drive: bool) -> Self {
+        self.restrict_to_my_drive = Some(restrict_to_my_drive);
+        self
+    }
+
+    /// Sets the `spaces` query parameter.
+    pub fn spaces(mut self, spaces: &'a str) -> Self {
+        self.spaces = Some(spaces);
+        self
+    }
+
+    /// Sets the `supportsAllDrives` query parameter.
+    pub fn supports_all_drives(mut self, supports_all_drives: bool) -> Self {
+        self.supports_all_drives = Some(supports_all_drives);
+        self
+    }
+
+    /// Sets the `supportsTeamDrives` query parameter.
+    pub fn supports_team_drives(mut self, supports_team_drives: bool) -> Self {
+        self.supports_team_drives = Some(supports_team_drives);
+        self
+    }
+
+    /// Sets the `teamDriveId` query parameter.
+    pub fn team_drive_id(mut self, team_drive_id: &'a str) -> Self {
+        self.team_drive_id = Some(team_drive_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::Channel>> {
+        let Self {
+            client,
+            page_token,
+            drive_id,
+            include_corpus_removals,
+            include_items_from_all_drives,
+            include_permissions_for_view,
+            include_removed,
+            include_team_drive_items,
+            page_size,
+            restrict_to_my_drive,
+            spaces,
+            supports_all_drives,
+            supports_team_drives,
+            team_drive_id,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = drive_id {
+            query_args.push(("driveId".to_string(), v.to_string()));
+        }
+        if let Some(v) = include_corpus_removals {
+            query_args.push(("includeCorpusRemovals".to_string(), v.to_string()));
+        }
+        if let Some(v) = include_items_from_all_drives {
+            query_args.push(("includeItemsFromAllDrives".to_string(), v.to_string()));
+        }
+        if let Some(v) = include_permissions_for_view {
+            query_args.push(("includePermissionsForView".to_string(), v.to_string()));
+        }
+        if let Some(v) = include_removed {
+            query_args.push(("includeRemoved".to_string(), v.to_string()));
+        }
+        if let Some(v) = include_team_drive_items {
+            query_args.push(("includeTeamDriveItems".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_size {
+            query_args.push(("pageSize".to_string(), v.to_string()));
+        }
+        if !page_token.is_empty() {
+            query_args.push(("pageToken".to_string(), page_token.to_string()));
+        }
+        if let Some(v) = restrict_to_my_drive {
+            query_args.push(("restrictToMyDrive".to_string(), v.to_string()));
+        }
+        if let Some(v) = spaces {
+            query_args.push(("spaces".to_string(), v.to_string()));
+        }
+        if let Some(v) = supports_all_drives {
+            query_args.push(("supportsAllDrives".to_string(), v.to_string()));
+        }
+        if let Some(v) = supports_team_drives {
+            query_args.push(("supportsTeamDrives".to_string(), v.to_string()));
+        }
+        if let Some(v) = team_drive_id {
+            query_args.push(("teamDriveId".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/changes/watch?{}", query_), None);
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
 pub struct Changes {
     pub client: Client,
 }
@@ -129,6 +544,31 @@ impl Changes {
             resp.body.changes.to_vec(),
         ))
     }
+    /**
+     * This function performs a `GET` to the `/changes` endpoint.
+     *
+     * As opposed to `list`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Lists the changes for a user or shared drive.
+     */
+    pub fn list_request<'a>(&'a self, page_token: &'a str) -> ChangesListRequest<'a> {
+        ChangesListRequest {
+            client: self,
+            page_token,
+            drive_id: None,
+            include_corpus_removals: None,
+            include_items_from_all_drives: None,
+            include_permissions_for_view: None,
+            include_removed: None,
+            include_team_drive_items: None,
+            page_size: None,
+            restrict_to_my_drive: None,
+            spaces: None,
+            supports_all_drives: None,
+            supports_team_drives: None,
+            team_drive_id: None,
+        }
+    }
     /**
      * This function performs a `GET` to the `/changes` endpoint.
      *
@@ -207,11 +647,11 @@ impl Changes {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/changes?{}", query_), None);
-        let crate::Response::<crate::types::ChangeList> {
-            mut status,
-            mut headers,
-            mut body,
-        } = self
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let mut pages = crate::utils::Pages::new(self.client.page_limits);
+        pages.visit(&url)?;
+
+        let mut response: crate::Response<crate::types::ChangeList> = self
             .client
             .get(
                 &url,
@@ -221,55 +661,134 @@ impl Changes {
                 },
             )
             .await?;
-
-        let mut changes = body.changes;
-        let mut page = body.next_page_token;
+        let mut changes = Vec::new();
 
         // Paginate if we should.
-        while !page.is_empty() {
-            if !url.contains('?') {
-                crate::Response::<crate::types::ChangeList> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}?pageToken={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
+        loop {
+            let page = if response.body.next_page_token.is_empty() {
+                None
             } else {
-                crate::Response::<crate::types::ChangeList> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}&pageToken={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
-
-            changes.append(&mut body.changes);
-
-            if !body.next_page_token.is_empty() && body.next_page_token != page {
-                page = body.next_page_token.to_string();
-            } else {
-                page = "".to_string();
-            }
+                Some(format!(
+                    "{}{}pageToken={}",
+                    url, sep, response.body.next_page_token
+                ))
+            };
+            let more = pages.keep(&mut response.body.changes);
+            changes.append(&mut response.body.changes);
+
+            let next = match page {
+                Some(next) if more && pages.visit(&next)? => next,
+                _ => break,
+            };
+            response = self
+                .client
+                .get(
+                    &next,
+                    crate::Message {
+                        body: None,
+                        content_type: None,
+                    },
+                )
+                .await?;
         }
 
         // Return our response data.
-        Ok(crate::Response::new(status, headers, changes))
+        Ok(crate::Response::new(
+            response.status,
+            response.headers,
+            changes,
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/changes` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Lists the changes for a user or shared drive.
+     */
+    pub fn list_all_stream(
+        &self,
+        drive_id: &str,
+        include_corpus_removals: bool,
+        include_items_from_all_drives: bool,
+        include_permissions_for_view: &str,
+        include_removed: bool,
+        include_team_drive_items: bool,
+        restrict_to_my_drive: bool,
+        spaces: &str,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+        team_drive_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Change>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !drive_id.is_empty() {
+            query_args.push(("driveId".to_string(), drive_id.to_string()));
+        }
+        if include_corpus_removals {
+            query_args.push((
+                "includeCorpusRemovals".to_string(),
+                include_corpus_removals.to_string(),
+            ));
+        }
+        if include_items_from_all_drives {
+            query_args.push((
+                "includeItemsFromAllDrives".to_string(),
+                include_items_from_all_drives.to_string(),
+            ));
+        }
+        if !include_permissions_for_view.is_empty() {
+            query_args.push((
+                "includePermissionsForView".to_string(),
+                include_permissions_for_view.to_string(),
+            ));
+        }
+        if include_removed {
+            query_args.push(("includeRemoved".to_string(), include_removed.to_string()));
+        }
+        if include_team_drive_items {
+            query_args.push((
+                "includeTeamDriveItems".to_string(),
+                include_team_drive_items.to_string(),
+            ));
+        }
+        if restrict_to_my_drive {
+            query_args.push((
+                "restrictToMyDrive".to_string(),
+                restrict_to_my_drive.to_string(),
+            ));
+        }
+        if !spaces.is_empty() {
+            query_args.push(("spaces".to_string(), spaces.to_string()));
+        }
+        if supports_all_drives {
+            query_args.push((
+                "supportsAllDrives".to_string(),
+                supports_all_drives.to_string(),
+            ));
+        }
+        if supports_team_drives {
+            query_args.push((
+                "supportsTeamDrives".to_string(),
+                supports_team_drives.to_string(),
+            ));
+        }
+        if !team_drive_id.is_empty() {
+            query_args.push(("teamDriveId".to_string(), team_drive_id.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/changes?{}", query_), None);
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let next = format!("{}{}pageToken=", url, sep);
+        self.client
+            .get_pages_stream(url, move |body: crate::types::ChangeList| {
+                let page = if body.next_page_token.is_empty() {
+                    None
+                } else {
+                    Some(format!("{}{}", next, body.next_page_token))
+                };
+
+                (body.changes, page)
+            })
     }
     /**
      * This function performs a `GET` to the `/changes/startPageToken` endpoint.
@@ -323,6 +842,22 @@ impl Changes {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/changes/startPageToken` endpoint.
+     *
+     * As opposed to `get_start_page_token`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Gets the starting pageToken for listing future changes.
+     */
+    pub fn get_start_page_token_request(&self) -> ChangesGetStartPageTokenRequest<'_> {
+        ChangesGetStartPageTokenRequest {
+            client: self,
+            drive_id: None,
+            supports_all_drives: None,
+            supports_team_drives: None,
+            team_drive_id: None,
+        }
+    }
     /**
      * This function performs a `POST` to the `/changes/watch` endpoint.
      *
@@ -434,4 +969,34 @@ impl Changes {
             )
             .await
     }
+    /**
+     * This function performs a `POST` to the `/changes/watch` endpoint.
+     *
+     * As opposed to `watch`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Subscribes to changes for a user.
+     */
+    pub fn watch_request<'a>(
+        &'a self,
+        page_token: &'a str,
+        body: &'a crate::types::Channel,
+    ) -> ChangesWatchRequest<'a> {
+        ChangesWatchRequest {
+            client: self,
+            page_token,
+            drive_id: None,
+            include_corpus_removals: None,
+            include_items_from_all_drives: None,
+            include_permissions_for_view: None,
+            include_removed: None,
+            include_team_drive_items: None,
+            page_size: None,
+            restrict_to_my_drive: None,
+            spaces: None,
+            supports_all_drives: None,
+            supports_team_drives: None,
+            team_drive_id: None,
+            body,
+        }
+    }
 }
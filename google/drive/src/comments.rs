@@ -1,6 +1,145 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Comments::list`], set with a builder.
+pub struct CommentsListRequest<'a> {
+    client: &'a Comments,
+    file_id: &'a str,
+    include_deleted: Option<bool>,
+    page_size: Option<i64>,
+    page_token: Option<&'a str>,
+    start_modified_time: Option<&'a str>,
+}
+
+impl<'a> CommentsListRequest<'a> {
+    /// Sets the `includeDeleted` query parameter.
+    pub fn include_deleted(mut self, include_deleted: bool) -> Self {
+        self.include_deleted = Some(include_deleted);
+        self
+    }
+
+    /// Sets the `pageSize` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Sets the `pageToken` query parameter.
+    pub fn page_token(mut self, page_token: &'a str) -> Self {
+        self.page_token = Some(page_token);
+        self
+    }
+
+    /// Sets the `startModifiedTime` query parameter.
+    pub fn start_modified_time(mut self, start_modified_time: &'a str) -> Self {
+        self.start_modified_time = Some(start_modified_time);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Comment>>> {
+        let Self {
+            client,
+            file_id,
+            include_deleted,
+            page_size,
+            page_token,
+            start_modified_time,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = include_deleted {
+            query_args.push(("includeDeleted".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_size {
+            query_args.push(("pageSize".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_token {
+            query_args.push(("pageToken".to_string(), v.to_string()));
+        }
+        if let Some(v) = start_modified_time {
+            query_args.push(("startModifiedTime".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/files/{}/comments?{}",
+                crate::progenitor_support::encode_path(file_id),
+                query_
+            ),
+            None,
+        );
+
+        let resp: crate::Response<crate::types::CommentList> = client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await?;
+
+        // Return our response data.
+        Ok(crate::Response::new(
+            resp.status,
+            resp.headers,
+            resp.body.comments.to_vec(),
+        ))
+    }
+}
+
+/// The parameters of [`Comments::get`], set with a builder.
+pub struct CommentsGetRequest<'a> {
+    client: &'a Comments,
+    file_id: &'a str,
+    comment_id: &'a str,
+    include_deleted: Option<bool>,
+}
+
+impl<'a> CommentsGetRequest<'a> {
+    /// Sets the `includeDeleted` query parameter.
+    pub fn include_deleted(mut self, include_deleted: bool) -> Self {
+        self.include_deleted = Some(include_deleted);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::Comment>> {
+        let Self {
+            client,
+            file_id,
+            comment_id,
+            include_deleted,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = include_deleted {
+            query_args.push(("includeDeleted".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/files/{}/comments/{}?{}",
+                crate::progenitor_support::encode_path(file_id),
+                crate::progenitor_support::encode_path(comment_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct Comments {
     pub client: Client,
 }
@@ -75,6 +214,23 @@ impl Comments {
             resp.body.comments.to_vec(),
         ))
     }
+    /**
+     * This function performs a `GET` to the `/files/{fileId}/comments` endpoint.
+     *
+     * As opposed to `list`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Lists a file's comments.
+     */
+    pub fn list_request<'a>(&'a self, file_id: &'a str) -> CommentsListRequest<'a> {
+        CommentsListRequest {
+            client: self,
+            file_id,
+            include_deleted: None,
+            page_size: None,
+            page_token: None,
+            start_modified_time: None,
+        }
+    }
     /**
      * This function performs a `GET` to the `/files/{fileId}/comments` endpoint.
      *
@@ -107,11 +263,11 @@ impl Comments {
             ),
             None,
         );
-        let crate::Response::<crate::types::CommentList> {
-            mut status,
-            mut headers,
-            mut body,
-        } = self
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let mut pages = crate::utils::Pages::new(self.client.page_limits);
+        pages.visit(&url)?;
+
+        let mut response: crate::Response<crate::types::CommentList> = self
             .client
             .get(
                 &url,
@@ -121,55 +277,88 @@ impl Comments {
                 },
             )
             .await?;
-
-        let mut comments = body.comments;
-        let mut page = body.next_page_token;
+        let mut comments = Vec::new();
 
         // Paginate if we should.
-        while !page.is_empty() {
-            if !url.contains('?') {
-                crate::Response::<crate::types::CommentList> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}?pageToken={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
+        loop {
+            let page = if response.body.next_page_token.is_empty() {
+                None
             } else {
-                crate::Response::<crate::types::CommentList> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}&pageToken={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
+                Some(format!(
+                    "{}{}pageToken={}",
+                    url, sep, response.body.next_page_token
+                ))
+            };
+            let more = pages.keep(&mut response.body.comments);
+            comments.append(&mut response.body.comments);
 
-            comments.append(&mut body.comments);
-
-            if !body.next_page_token.is_empty() && body.next_page_token != page {
-                page = body.next_page_token.to_string();
-            } else {
-                page = "".to_string();
-            }
+            let next = match page {
+                Some(next) if more && pages.visit(&next)? => next,
+                _ => break,
+            };
+            response = self
+                .client
+                .get(
+                    &next,
+                    crate::Message {
+                        body: None,
+                        content_type: None,
+                    },
+                )
+                .await?;
         }
 
         // Return our response data.
-        Ok(crate::Response::new(status, headers, comments))
+        Ok(crate::Response::new(
+            response.status,
+            response.headers,
+            comments,
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/files/{fileId}/comments` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Lists a file's comments.
+     */
+    pub fn list_all_stream(
+        &self,
+        file_id: &str,
+        include_deleted: bool,
+        start_modified_time: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Comment>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if include_deleted {
+            query_args.push(("includeDeleted".to_string(), include_deleted.to_string()));
+        }
+        if !start_modified_time.is_empty() {
+            query_args.push((
+                "startModifiedTime".to_string(),
+                start_modified_time.to_string(),
+            ));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/files/{}/comments?{}",
+                crate::progenitor_support::encode_path(file_id),
+                query_
+            ),
+            None,
+        );
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let next = format!("{}{}pageToken=", url, sep);
+        self.client
+            .get_pages_stream(url, move |body: crate::types::CommentList| {
+                let page = if body.next_page_token.is_empty() {
+                    None
+                } else {
+                    Some(format!("{}{}", next, body.next_page_token))
+                };
+
+                (body.comments, page)
+            })
     }
     /**
      * This function performs a `POST` to the `/files/{fileId}/comments` endpoint.
@@ -243,6 +432,25 @@ impl Comments {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/files/{fileId}/comments/{commentId}` endpoint.
+     *
+     * As opposed to `get`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Gets a comment by ID.
+     */
+    pub fn get_request<'a>(
+        &'a self,
+        file_id: &'a str,
+        comment_id: &'a str,
+    ) -> CommentsGetRequest<'a> {
+        CommentsGetRequest {
+            client: self,
+            file_id,
+            comment_id,
+            include_deleted: None,
+        }
+    }
     /**
      * This function performs a `DELETE` to the `/files/{fileId}/comments/{commentId}` endpoint.
      *
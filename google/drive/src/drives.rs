@@ -1,6 +1,183 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Drives::list`], set with a builder.
+pub struct DrivesListRequest<'a> {
+    client: &'a Drives,
+    page_size: Option<i64>,
+    page_token: Option<&'a str>,
+    q: Option<&'a str>,
+    use_domain_admin_access: Option<bool>,
+}
+
+impl<'a> DrivesListRequest<'a> {
+    /// Sets the `pageSize` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Sets the `pageToken` query parameter.
+    pub fn page_token(mut self, page_token: &'a str) -> Self {
+        self.page_token = Some(page_token);
+        self
+    }
+
+    /// Sets the `q` query parameter.
+    pub fn q(mut self, q: &'a str) -> Self {
+        self.q = Some(q);
+        self
+    }
+
+    /// Sets the `useDomainAdminAccess` query parameter.
+    pub fn use_domain_admin_access(mut self, use_domain_admin_access: bool) -> Self {
+        self.use_domain_admin_access = Some(use_domain_admin_access);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Drive>>> {
+        let Self {
+            client,
+            page_size,
+            page_token,
+            q,
+            use_domain_admin_access,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page_size {
+            query_args.push(("pageSize".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_token {
+            query_args.push(("pageToken".to_string(), v.to_string()));
+        }
+        if let Some(v) = q {
+            query_args.push(("q".to_string(), v.to_string()));
+        }
+        if let Some(v) = use_domain_admin_access {
+            query_args.push(("useDomainAdminAccess".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(&format!("/drives?{}", query_), None);
+
+        let resp: crate::Response<crate::types::DriveList> = client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await?;
+
+        // Return our response data.
+        Ok(crate::Response::new(
+            resp.status,
+            resp.headers,
+            resp.body.drives.to_vec(),
+        ))
+    }
+}
+
+/// The parameters of [`Drives::get`], set with a builder.
+pub struct DrivesGetRequest<'a> {
+    client: &'a Drives,
+    drive_id: &'a str,
+    use_domain_admin_access: Option<bool>,
+}
+
+impl<'a> DrivesGetRequest<'a> {
+    /// Sets the `useDomainAdminAccess` query parameter.
+    pub fn use_domain_admin_access(mut self, use_domain_admin_access: bool) -> Self {
+        self.use_domain_admin_access = Some(use_domain_admin_access);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::Drive>> {
+        let Self {
+            client,
+            drive_id,
+            use_domain_admin_access,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = use_domain_admin_access {
+            query_args.push(("useDomainAdminAccess".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/drives/{}?{}",
+                crate::progenitor_support::encode_path(drive_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Drives::update`], set with a builder.
+pub struct DrivesUpdateRequest<'a> {
+    client: &'a Drives,
+    drive_id: &'a str,
+    use_domain_admin_access: Option<bool>,
+    body: &'a crate::types::Drive,
+}
+
+impl<'a> DrivesUpdateRequest<'a> {
+    /// Sets the `useDomainAdminAccess` query parameter.
+    pub fn use_domain_admin_access(mut self, use_domain_admin_access: bool) -> Self {
+        self.use_domain_admin_access = Some(use_domain_admin_access);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::Drive>> {
+        let Self {
+            client,
+            drive_id,
+            use_domain_admin_access,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = use_domain_admin_access {
+            query_args.push(("useDomainAdminAccess".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/drives/{}?{}",
+                crate::progenitor_support::encode_path(drive_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .patch(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
 pub struct Drives {
     pub client: Client,
 }
@@ -66,6 +243,22 @@ impl Drives {
             resp.body.drives.to_vec(),
         ))
     }
+    /**
+     * This function performs a `GET` to the `/drives` endpoint.
+     *
+     * As opposed to `list`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Lists the user's shared drives.
+     */
+    pub fn list_request(&self) -> DrivesListRequest<'_> {
+        DrivesListRequest {
+            client: self,
+            page_size: None,
+            page_token: None,
+            q: None,
+            use_domain_admin_access: None,
+        }
+    }
     /**
      * This function performs a `GET` to the `/drives` endpoint.
      *
@@ -90,11 +283,11 @@ impl Drives {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/drives?{}", query_), None);
-        let crate::Response::<crate::types::DriveList> {
-            mut status,
-            mut headers,
-            mut body,
-        } = self
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let mut pages = crate::utils::Pages::new(self.client.page_limits);
+        pages.visit(&url)?;
+
+        let mut response: crate::Response<crate::types::DriveList> = self
             .client
             .get(
                 &url,
@@ -104,55 +297,80 @@ impl Drives {
                 },
             )
             .await?;
-
-        let mut drives = body.drives;
-        let mut page = body.next_page_token;
+        let mut drives = Vec::new();
 
         // Paginate if we should.
-        while !page.is_empty() {
-            if !url.contains('?') {
-                crate::Response::<crate::types::DriveList> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}?pageToken={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
+        loop {
+            let page = if response.body.next_page_token.is_empty() {
+                None
             } else {
-                crate::Response::<crate::types::DriveList> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}&pageToken={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
-
-            drives.append(&mut body.drives);
+                Some(format!(
+                    "{}{}pageToken={}",
+                    url, sep, response.body.next_page_token
+                ))
+            };
+            let more = pages.keep(&mut response.body.drives);
+            drives.append(&mut response.body.drives);
 
-            if !body.next_page_token.is_empty() && body.next_page_token != page {
-                page = body.next_page_token.to_string();
-            } else {
-                page = "".to_string();
-            }
+            let next = match page {
+                Some(next) if more && pages.visit(&next)? => next,
+                _ => break,
+            };
+            response = self
+                .client
+                .get(
+                    &next,
+                    crate::Message {
+                        body: None,
+                        content_type: None,
+                    },
+                )
+                .await?;
         }
 
         // Return our response data.
-        Ok(crate::Response::new(status, headers, drives))
+        Ok(crate::Response::new(
+            response.status,
+            response.headers,
+            drives,
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/drives` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Lists the user's shared drives.
+     */
+    pub fn list_all_stream(
+        &self,
+        q: &str,
+        use_domain_admin_access: bool,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Drive>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !q.is_empty() {
+            query_args.push(("q".to_string(), q.to_string()));
+        }
+        if use_domain_admin_access {
+            query_args.push((
+                "useDomainAdminAccess".to_string(),
+                use_domain_admin_access.to_string(),
+            ));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/drives?{}", query_), None);
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let next = format!("{}{}pageToken=", url, sep);
+        self.client
+            .get_pages_stream(url, move |body: crate::types::DriveList| {
+                let page = if body.next_page_token.is_empty() {
+                    None
+                } else {
+                    Some(format!("{}{}", next, body.next_page_token))
+                };
+
+                (body.drives, page)
+            })
     }
     /**
      * This function performs a `POST` to the `/drives` endpoint.
@@ -225,6 +443,20 @@ impl Drives {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/drives/{driveId}` endpoint.
+     *
+     * As opposed to `get`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Gets a shared drive's metadata by ID.
+     */
+    pub fn get_request<'a>(&'a self, drive_id: &'a str) -> DrivesGetRequest<'a> {
+        DrivesGetRequest {
+            client: self,
+            drive_id,
+            use_domain_admin_access: None,
+        }
+    }
     /**
      * This function performs a `DELETE` to the `/drives/{driveId}` endpoint.
      *
@@ -294,6 +526,25 @@ impl Drives {
             )
             .await
     }
+    /**
+     * This function performs a `PATCH` to the `/drives/{driveId}` endpoint.
+     *
+     * As opposed to `update`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Updates the metadate for a shared drive.
+     */
+    pub fn update_request<'a>(
+        &'a self,
+        drive_id: &'a str,
+        body: &'a crate::types::Drive,
+    ) -> DrivesUpdateRequest<'a> {
+        DrivesUpdateRequest {
+            client: self,
+            drive_id,
+            use_domain_admin_access: None,
+            body,
+        }
+    }
     /**
      * This function performs a `POST` to the `/drives/{driveId}/hide` endpoint.
      *
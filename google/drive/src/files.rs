@@ -1,6 +1,809 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Files::list`], set with a builder.
+pub struct FilesListRequest<'a> {
+    client: &'a Files,
+    corpora: Option<&'a str>,
+    drive_id: Option<&'a str>,
+    include_items_from_all_drives: Option<bool>,
+    include_permissions_for_view: Option<&'a str>,
+    include_team_drive_items: Option<bool>,
+    order_by: Option<&'a str>,
+    page_size: Option<i64>,
+    page_token: Option<&'a str>,
+    q: Option<&'a str>,
+    spaces: Option<&'a str>,
+    supports_all_drives: Option<bool>,
+    supports_team_drives: Option<bool>,
+    team_drive_id: Option<&'a str>,
+}
+
+impl<'a> FilesListRequest<'a> {
+    /// Sets the `corpora` query parameter.
+    pub fn corpora(mut self, corpora: &'a str) -> Self {
+        self.corpora = Some(corpora);
+        self
+    }
+
+    /// Sets the `driveId` query parameter.
+    pub fn drive_id(mut self, drive_id: &'a str) -> Self {
+        self.drive_id = Some(drive_id);
+        self
+    }
+
+    /// Sets the `includeItemsFromAllDrives` query parameter.
+    pub fn include_items_from_all_drives(mut self, include_items_from_all_drives: bool) -> Self {
+        self.include_items_from_all_drives = Some(include_items_from_all_drives);
+        self
+    }
+
+    /// Sets the `includePermissionsForView` query parameter.
+    pub fn include_permissions_for_view(mut self, include_permissions_for_view: &'a str) -> Self {
+        self.include_permissions_for_view = Some(include_permissions_for_view);
+        self
+    }
+
+    /// Sets the `includeTeamDriveItems` query parameter.
+    pub fn include_team_drive_items(mut self, include_team_drive_items: bool) -> Self {
+        self.include_team_drive_items = Some(include_team_drive_items);
+        self
+    }
+
+    /// Sets the `orderBy` query parameter.
+    pub fn order_by(mut self, order_by: &'a str) -> Self {
+        self.order_by = Some(order_by);
+        self
+    }
+
+    /// Sets the `pageSize` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Sets the `pageToken` query parameter.
+    pub fn page_token(mut self, page_token: &'a str) -> Self {
+        self.page_token = Some(page_token);
+        self
+    }
+
+    /// Sets the `q` query parameter.
+    pub fn q(mut self, q: &'a str) -> Self {
+        self.q = Some(q);
+        self
+    }
+
+    /// Sets the `spaces` query parameter.
+    pub fn spaces(mut self, spaces: &'a str) -> Self {
+        self.spaces = Some(spaces);
+        self
+    }
+
+    /// Sets the `supportsAllDrives` query parameter.
+    pub fn supports_all_drives(mut self, supports_all_drives: bool) -> Self {
+        self.supports_all_drives = Some(supports_all_drives);
+        self
+    }
+
+    /// Sets the `supportsTeamDrives` query parameter.
+    pub fn supports_team_drives(mut self, supports_team_drives: bool) -> Self {
+        self.supports_team_drives = Some(supports_team_drives);
+        self
+    }
+
+    /// Sets the `teamDriveId` query parameter.
+    pub fn team_drive_id(mut self, team_drive_id: &'a str) -> Self {
+        self.team_drive_id = Some(team_drive_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::File>>> {
+        let Self {
+            client,
+            corpora,
+            drive_id,
+            include_items_from_all_drives,
+            include_permissions_for_view,
+            include_team_drive_items,
+            order_by,
+            page_size,
+            page_token,
+            q,
+            spaces,
+            supports_all_drives,
+            supports_team_drives,
+            team_drive_id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = corpora {
+            query_args.push(("corpora".to_string(), v.to_string()));
+        }
+        if let Some(v) = drive_id {
+            query_args.push(("driveId".to_string(), v.to_string()));
+        }
+        if let Some(v) = include_items_from_all_drives {
+            query_args.push(("includeItemsFromAllDrives".to_string(), v.to_string()));
+        }
+        if let Some(v) = include_permissions_for_view {
+            query_args.push(("includePermissionsForView".to_string(), v.to_string()));
+        }
+        if let Some(v) = include_team_drive_items {
+            query_args.push(("includeTeamDriveItems".to_string(), v.to_string()));
+        }
+        if let Some(v) = order_by {
+            query_args.push(("orderBy".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_size {
+            query_args.push(("pageSize".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_token {
+            query_args.push(("pageToken".to_string(), v.to_string()));
+        }
+        if let Some(v) = q {
+            query_args.push(("q".to_string(), v.to_string()));
+        }
+        if let Some(v) = spaces {
+            query_args.push(("spaces".to_string(), v.to_string()));
+        }
+        if let Some(v) = supports_all_drives {
+            query_args.push(("supportsAllDrives".to_string(), v.to_string()));
+        }
+        if let Some(v) = supports_team_drives {
+            query_args.push(("supportsTeamDrives".to_string(), v.to_string()));
+        }
+        if let Some(v) = team_drive_id {
+            query_args.push(("teamDriveId".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(&format!("/files?{}", query_), None);
+
+        let resp: crate::Response<crate::types::FileList> = client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await?;
+
+        // Return our response data.
+        Ok(crate::Response::new(
+            resp.status,
+            resp.headers,
+            resp.body.files.to_vec(),
+        ))
+    }
+}
+
+/// The parameters of [`Files::create`], set with a builder.
+pub struct FilesCreateRequest<'a> {
+    client: &'a Files,
+    ignore_default_visibility: Option<bool>,
+    include_permissions_for_view: Option<&'a str>,
+    keep_revision_forever: Option<bool>,
+    ocr_language: Option<&'a str>,
+    supports_all_drives: Option<bool>,
+    supports_team_drives: Option<bool>,
+    use_content_as_indexable_text: Option<bool>,
+    body: &'a crate::types::File,
+}
+
+impl<'a> FilesCreateRequest<'a> {
+    /// Sets the `ignoreDefaultVisibility` query parameter.
+    pub fn ignore_default_visibility(mut self, ignore_default_visibility: bool) -> Self {
+        self.ignore_default_visibility = Some(ignore_default_visibility);
+        self
+    }
+
+    /// Sets the `includePermissionsForView` query parameter.
+    pub fn include_permissions_for_view(mut self, include_permissions_for_view: &'a str) -> Self {
+        self.include_permissions_for_view = Some(include_permissions_for_view);
+        self
+    }
+
+    /// Sets the `keepRevisionForever` query parameter.
+    pub fn keep_revision_forever(mut self, keep_revision_forever: bool) -> Self {
+        self.keep_revision_forever = Some(keep_revision_forever);
+        self
+    }
+
+    /// Sets the `ocrLanguage` query parameter.
+    pub fn ocr_language(mut self, ocr_language: &'a str) -> Self {
+        self.ocr_language = Some(ocr_language);
+        self
+    }
+
+    /// Sets the `supportsAllDrives` query parameter.
+    pub fn supports_all_drives(mut self, supports_all_drives: bool) -> Self {
+        self.supports_all_drives = Some(supports_all_drives);
+        self
+    }
+
+    /// Sets the `supportsTeamDrives` query parameter.
+    pub fn supports_team_drives(mut self, supports_team_drives: bool) -> Self {
+        self.supports_team_drives = Some(supports_team_drives);
+        self
+    }
+
+    /// Sets the `useContentAsIndexableText` query parameter.
+    pub fn use_content_as_indexable_text(mut self, use_content_as_indexable_text: bool) -> Self {
+        self.use_content_as_indexable_text = Some(use_content_as_indexable_text);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::File>> {
+        let Self {
+            client,
+            ignore_default_visibility,
+            include_permissions_for_view,
+            keep_revision_forever,
+            ocr_language,
+            supports_all_drives,
+            supports_team_drives,
+            use_content_as_indexable_text,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = ignore_default_visibility {
+            query_args.push(("ignoreDefaultVisibility".to_string(), v.to_string()));
+        }
+        if let Some(v) = include_permissions_for_view {
+            query_args.push(("includePermissionsForView".to_string(), v.to_string()));
+        }
+        if let Some(v) = keep_revision_forever {
+            query_args.push(("keepRevisionForever".to_string(), v.to_string()));
+        }
+        if let Some(v) = ocr_language {
+            query_args.push(("ocrLanguage".to_string(), v.to_string()));
+        }
+        if let Some(v) = supports_all_drives {
+            query_args.push(("supportsAllDrives".to_string(), v.to_string()));
+        }
+        if let Some(v) = supports_team_drives {
+            query_args.push(("supportsTeamDrives".to_string(), v.to_string()));
+        }
+        if let Some(v) = use_content_as_indexable_text {
+            query_args.push(("useContentAsIndexableText".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(&format!("/files?{}", query_), None);
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/octet-stream".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Files::generate_id`], set with a builder.
+pub struct FilesGenerateIdRequest<'a> {
+    client: &'a Files,
+    count: Option<i64>,
+    space: Option<&'a str>,
+    type_: Option<&'a str>,
+}
+
+impl<'a> FilesGenerateIdRequest<'a> {
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Sets the `space` query parameter.
+    pub fn space(mut self, space: &'a str) -> Self {
+        self.space = Some(space);
+        self
+    }
+
+    /// Sets the `type` query parameter.
+    pub fn type_(mut self, type_: &'a str) -> Self {
+        self.type_ = Some(type_);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::GeneratedIds>> {
+        let Self {
+            client,
+            count,
+            space,
+            type_,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = count {
+            query_args.push(("count".to_string(), v.to_string()));
+        }
+        if let Some(v) = space {
+            query_args.push(("space".to_string(), v.to_string()));
+        }
+        if let Some(v) = type_ {
+            query_args.push(("type".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/files/generateIds?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Files::get`], set with a builder.
+pub struct FilesGetRequest<'a> {
+    client: &'a Files,
+    file_id: &'a str,
+    acknowledge_abuse: Option<bool>,
+    include_permissions_for_view: Option<&'a str>,
+    supports_all_drives: Option<bool>,
+    supports_team_drives: Option<bool>,
+}
+
+impl<'a> FilesGetRequest<'a> {
+    /// Sets the `acknowledgeAbuse` query parameter.
+    pub fn acknowledge_abuse(mut self, acknowledge_abuse: bool) -> Self {
+        self.acknowledge_abuse = Some(acknowledge_abuse);
+        self
+    }
+
+    /// Sets the `includePermissionsForView` query parameter.
+    pub fn include_permissions_for_view(mut self, include_permissions_for_view: &'a str) -> Self {
+        self.include_permissions_for_view = Some(include_permissions_for_view);
+        self
+    }
+
+    /// Sets the `supportsAllDrives` query parameter.
+    pub fn supports_all_drives(mut self, supports_all_drives: bool) -> Self {
+        self.supports_all_drives = Some(supports_all_drives);
+        self
+    }
+
+    /// Sets the `supportsTeamDrives` query parameter.
+    pub fn supports_team_drives(mut self, supports_team_drives: bool) -> Self {
+        self.supports_team_drives = Some(supports_team_drives);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::File>> {
+        let Self {
+            client,
+            file_id,
+            acknowledge_abuse,
+            include_permissions_for_view,
+            supports_all_drives,
+            supports_team_drives,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = acknowledge_abuse {
+            query_args.push(("acknowledgeAbuse".to_string(), v.to_string()));
+        }
+        if let Some(v) = include_permissions_for_view {
+            query_args.push(("includePermissionsForView".to_string(), v.to_string()));
+        }
+        if let Some(v) = supports_all_drives {
+            query_args.push(("supportsAllDrives".to_string(), v.to_string()));
+        }
+        if let Some(v) = supports_team_drives {
+            query_args.push(("supportsTeamDrives".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/files/{}?{}",
+                crate::progenitor_support::encode_path(file_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Files::delete`], set with a builder.
+pub struct FilesDeleteRequest<'a> {
+    client: &'a Files,
+    file_id: &'a str,
+    supports_all_drives: Option<bool>,
+    supports_team_drives: Option<bool>,
+}
+
+impl<'a> FilesDeleteRequest<'a> {
+    /// Sets the `supportsAllDrives` query parameter.
+    pub fn supports_all_drives(mut self, supports_all_drives: bool) -> Self {
+        self.supports_all_drives = Some(supports_all_drives);
+        self
+    }
+
+    /// Sets the `supportsTeamDrives` query parameter.
+    pub fn supports_team_drives(mut self, supports_team_drives: bool) -> Self {
+        self.supports_team_drives = Some(supports_team_drives);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            file_id,
+            supports_all_drives,
+            supports_team_drives,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = supports_all_drives {
+            query_args.push(("supportsAllDrives".to_string(), v.to_string()));
+        }
+        if let Some(v) = supports_team_drives {
+            query_args.push(("supportsTeamDrives".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/files/{}?{}",
+                crate::progenitor_support::encode_path(file_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .delete(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Files::update`], set with a builder.
+pub struct FilesUpdateRequest<'a> {
+    client: &'a Files,
+    file_id: &'a str,
+    add_parents: Option<&'a str>,
+    include_permissions_for_view: Option<&'a str>,
+    keep_revision_forever: Option<bool>,
+    ocr_language: Option<&'a str>,
+    remove_parents: Option<&'a str>,
+    supports_all_drives: Option<bool>,
+    supports_team_drives: Option<bool>,
+    use_content_as_indexable_text: Option<bool>,
+    body: &'a crate::types::File,
+}
+
+impl<'a> FilesUpdateRequest<'a> {
+    /// Sets the `addParents` query parameter.
+    pub fn add_parents(mut self, add_parents: &'a str) -> Self {
+        self.add_parents = Some(add_parents);
+        self
+    }
+
+    /// Sets the `includePermissionsForView` query parameter.
+    pub fn include_permissions_for_view(mut self, include_permissions_for_view: &'a str) -> Self {
+        self.include_permissions_for_view = Some(include_permissions_for_view);
+        self
+    }
+
+    /// Sets the `keepRevisionForever` query parameter.
+    pub fn keep_revision_forever(mut self, keep_revision_forever: bool) -> Self {
+        self.keep_revision_forever = Some(keep_revision_forever);
+        self
+    }
+
+    /// Sets the `ocrLanguage` query parameter.
+    pub fn ocr_language(mut self, ocr_language: &'a str) -> Self {
+        self.ocr_language = Some(ocr_language);
+        self
+    }
+
+    /// Sets the `removeParents` query parameter.
+    pub fn remove_parents(mut self, remove_parents: &'a str) -> Self {
+        self.remove_parents = Some(remove_parents);
+        self
+    }
+
+    /// Sets the `supportsAllDrives` query parameter.
+    pub fn supports_all_drives(mut self, supports_all_drives: bool) -> Self {
+        self.supports_all_drives = Some(supports_all_drives);
+        self
+    }
+
+    /// Sets the `supportsTeamDrives` query parameter.
+    pub fn supports_team_drives(mut self, supports_team_drives: bool) -> Self {
+        self.supports_team_drives = Some(supports_team_drives);
+        self
+    }
+
+    /// Sets the `useContentAsIndexableText` query parameter.
+    pub fn use_content_as_indexable_text(mut self, use_content_as_indexable_text: bool) -> Self {
+        self.use_content_as_indexable_text = Some(use_content_as_indexable_text);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::File>> {
+        let Self {
+            client,
+            file_id,
+            add_parents,
+            include_permissions_for_view,
+            keep_revision_forever,
+            ocr_language,
+            remove_parents,
+            supports_all_drives,
+            supports_team_drives,
+            use_content_as_indexable_text,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = add_parents {
+            query_args.push(("addParents".to_string(), v.to_string()));
+        }
+        if let Some(v) = include_permissions_for_view {
+            query_args.push(("includePermissionsForView".to_string(), v.to_string()));
+        }
+        if let Some(v) = keep_revision_forever {
+            query_args.push(("keepRevisionForever".to_string(), v.to_string()));
+        }
+        if let Some(v) = ocr_language {
+            query_args.push(("ocrLanguage".to_string(), v.to_string()));
+        }
+        if let Some(v) = remove_parents {
+            query_args.push(("removeParents".to_string(), v.to_string()));
+        }
+        if let Some(v) = supports_all_drives {
+            query_args.push(("supportsAllDrives".to_string(), v.to_string()));
+        }
+        if let Some(v) = supports_team_drives {
+            query_args.push(("supportsTeamDrives".to_string(), v.to_string()));
+        }
+        if let Some(v) = use_content_as_indexable_text {
+            query_args.push(("useContentAsIndexableText".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/files/{}?{}",
+                crate::progenitor_support::encode_path(file_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .patch(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/octet-stream".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Files::copy`], set with a builder.
+pub struct FilesCopyRequest<'a> {
+    client: &'a Files,
+    file_id: &'a str,
+    ignore_default_visibility: Option<bool>,
+    include_permissions_for_view: Option<&'a str>,
+    keep_revision_forever: Option<bool>,
+    ocr_language: Option<&'a str>,
+    supports_all_drives: Option<bool>,
+    supports_team_drives: Option<bool>,
+    body: &'a crate::types::File,
+}
+
+impl<'a> FilesCopyRequest<'a> {
+    /// Sets the `ignoreDefaultVisibility` query parameter.
+    pub fn ignore_default_visibility(mut self, ignore_default_visibility: bool) -> Self {
+        self.ignore_default_visibility = Some(ignore_default_visibility);
+        self
+    }
+
+    /// Sets the `includePermissionsForView` query parameter.
+    pub fn include_permissions_for_view(mut self, include_permissions_for_view: &'a str) -> Self {
+        self.include_permissions_for_view = Some(include_permissions_for_view);
+        self
+    }
+
+    /// Sets the `keepRevisionForever` query parameter.
+    pub fn keep_revision_forever(mut self, keep_revision_forever: bool) -> Self {
+        self.keep_revision_forever = Some(keep_revision_forever);
+        self
+    }
+
+    /// Sets the `ocrLanguage` query parameter.
+    pub fn ocr_language(mut self, ocr_language: &'a str) -> Self {
+        self.ocr_language = Some(ocr_language);
+        self
+    }
+
+    /// Sets the `supportsAllDrives` query parameter.
+    pub fn supports_all_drives(mut self, supports_all_drives: bool) -> Self {
+        self.supports_all_drives = Some(supports_all_drives);
+        self
+    }
+
+    /// Sets the `supportsTeamDrives` query parameter.
+    pub fn supports_team_drives(mut self, supports_team_drives: bool) -> Self {
+        self.supports_team_drives = Some(supports_team_drives);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::File>> {
+        let Self {
+            client,
+            file_id,
+            ignore_default_visibility,
+            include_permissions_for_view,
+            keep_revision_forever,
+            ocr_language,
+            supports_all_drives,
+            supports_team_drives,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = ignore_default_visibility {
+            query_args.push(("ignoreDefaultVisibility".to_string(), v.to_string()));
+        }
+        if let Some(v) = include_permissions_for_view {
+            query_args.push(("includePermissionsForView".to_string(), v.to_string()));
+        }
+        if let Some(v) = keep_revision_forever {
+            query_args.push(("keepRevisionForever".to_string(), v.to_string()));
+        }
+        if let Some(v) = ocr_language {
+            query_args.push(("ocrLanguage".to_string(), v.to_string()));
+        }
+        if let Some(v) = supports_all_drives {
+            query_args.push(("supportsAllDrives".to_string(), v.to_string()));
+        }
+        if let Some(v) = supports_team_drives {
+            query_args.push(("supportsTeamDrives".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/files/{}/copy?{}",
+                crate::progenitor_support::encode_path(file_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Files::watch`], set with a builder.
+pub struct FilesWatchRequest<'a> {
+    client: &'a Files,
+    file_id: &'a str,
+    acknowledge_abuse: Option<bool>,
+    include_permissions_for_view: Option<&'a str>,
+    supports_all_drives: Option<bool>,
+    supports_team_drives: Option<bool>,
+    body: &'a crate::types::Channel,
+}
+
+impl<'a> FilesWatchRequest<'a> {
+    /// Sets the `acknowledgeAbuse` query parameter.
+    pub fn acknowledge_abuse(mut self, acknowledge_abuse: bool) -> Self {
+        self.acknowledge_abuse = Some(acknowledge_abuse);
+        self
+    }
+
+    /// Sets the `includePermissionsForView` query parameter.
+    pub fn include_permissions_for_view(mut self, include_permissions_for_view: &'a str) -> Self {
+        self.include_permissions_for_view = Some(include_permissions_for_view);
+        self
+    }
+
+    /// Sets the `supportsAllDrives` query parameter.
+    pub fn supports_all_drives(mut self, supports_all_drives: bool) -> Self {
+        self.supports_all_drives = Some(supports_all_drives);
+        self
+    }
+
+    /// Sets the `supportsTeamDrives` query parameter.
+    pub fn supports_team_drives(mut self, supports_team_drives: bool) -> Self {
+        self.supports_team_drives = Some(supports_team_drives);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::Channel>> {
+        let Self {
+            client,
+            file_id,
+            acknowledge_abuse,
+            include_permissions_for_view,
+            supports_all_drives,
+            supports_team_drives,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = acknowledge_abuse {
+            query_args.push(("acknowledgeAbuse".to_string(), v.to_string()));
+        }
+        if let Some(v) = include_permissions_for_view {
+            query_args.push(("includePermissionsForView".to_string(), v.to_string()));
+        }
+        if let Some(v) = supports_all_drives {
+            query_args.push(("supportsAllDrives".to_string(), v.to_string()));
+        }
+        if let Some(v) = supports_team_drives {
+            query_args.push(("supportsTeamDrives".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/files/{}/watch?{}",
+                crate::progenitor_support::encode_path(file_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
 pub struct Files {
     pub client: Client,
 }
@@ -124,6 +927,31 @@ impl Files {
             resp.body.files.to_vec(),
         ))
     }
+    /**
+     * This function performs a `GET` to the `/files` endpoint.
+     *
+     * As opposed to `list`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Lists or searches files.
+     */
+    pub fn list_request(&self) -> FilesListRequest<'_> {
+        FilesListRequest {
+            client: self,
+            corpora: None,
+            drive_id: None,
+            include_items_from_all_drives: None,
+            include_permissions_for_view: None,
+            include_team_drive_items: None,
+            order_by: None,
+            page_size: None,
+            page_token: None,
+            q: None,
+            spaces: None,
+            supports_all_drives: None,
+            supports_team_drives: None,
+            team_drive_id: None,
+        }
+    }
     /**
      * This function performs a `GET` to the `/files` endpoint.
      *
@@ -196,11 +1024,11 @@ impl Files {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/files?{}", query_), None);
-        let crate::Response::<crate::types::FileList> {
-            mut status,
-            mut headers,
-            mut body,
-        } = self
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let mut pages = crate::utils::Pages::new(self.client.page_limits);
+        pages.visit(&url)?;
+
+        let mut response: crate::Response<crate::types::FileList> = self
             .client
             .get(
                 &url,
@@ -210,55 +1038,128 @@ impl Files {
                 },
             )
             .await?;
-
-        let mut files = body.files;
-        let mut page = body.next_page_token;
+        let mut files = Vec::new();
 
         // Paginate if we should.
-        while !page.is_empty() {
-            if !url.contains('?') {
-                crate::Response::<crate::types::FileList> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}?pageToken={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
+        loop {
+            let page = if response.body.next_page_token.is_empty() {
+                None
             } else {
-                crate::Response::<crate::types::FileList> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}&pageToken={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
-
-            files.append(&mut body.files);
-
-            if !body.next_page_token.is_empty() && body.next_page_token != page {
-                page = body.next_page_token.to_string();
-            } else {
-                page = "".to_string();
-            }
+                Some(format!(
+                    "{}{}pageToken={}",
+                    url, sep, response.body.next_page_token
+                ))
+            };
+            let more = pages.keep(&mut response.body.files);
+            files.append(&mut response.body.files);
+
+            let next = match page {
+                Some(next) if more && pages.visit(&next)? => next,
+                _ => break,
+            };
+            response = self
+                .client
+                .get(
+                    &next,
+                    crate::Message {
+                        body: None,
+                        content_type: None,
+                    },
+                )
+                .await?;
         }
 
         // Return our response data.
-        Ok(crate::Response::new(status, headers, files))
+        Ok(crate::Response::new(
+            response.status,
+            response.headers,
+            files,
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/files` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Lists or searches files.
+     */
+    pub fn list_all_stream(
+        &self,
+        corpora: &str,
+        drive_id: &str,
+        include_items_from_all_drives: bool,
+        include_permissions_for_view: &str,
+        include_team_drive_items: bool,
+        order_by: &str,
+        q: &str,
+        spaces: &str,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+        team_drive_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::File>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !corpora.is_empty() {
+            query_args.push(("corpora".to_string(), corpora.to_string()));
+        }
+        if !drive_id.is_empty() {
+            query_args.push(("driveId".to_string(), drive_id.to_string()));
+        }
+        if include_items_from_all_drives {
+            query_args.push((
+                "includeItemsFromAllDrives".to_string(),
+                include_items_from_all_drives.to_string(),
+            ));
+        }
+        if !include_permissions_for_view.is_empty() {
+            query_args.push((
+                "includePermissionsForView".to_string(),
+                include_permissions_for_view.to_string(),
+            ));
+        }
+        if include_team_drive_items {
+            query_args.push((
+                "includeTeamDriveItems".to_string(),
+                include_team_drive_items.to_string(),
+            ));
+        }
+        if !order_by.is_empty() {
+            query_args.push(("orderBy".to_string(), order_by.to_string()));
+        }
+        if !q.is_empty() {
+            query_args.push(("q".to_string(), q.to_string()));
+        }
+        if !spaces.is_empty() {
+            query_args.push(("spaces".to_string(), spaces.to_string()));
+        }
+        if supports_all_drives {
+            query_args.push((
+                "supportsAllDrives".to_string(),
+                supports_all_drives.to_string(),
+            ));
+        }
+        if supports_team_drives {
+            query_args.push((
+                "supportsTeamDrives".to_string(),
+                supports_team_drives.to_string(),
+            ));
+        }
+        if !team_drive_id.is_empty() {
+            query_args.push(("teamDriveId".to_string(), team_drive_id.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/files?{}", query_), None);
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let next = format!("{}{}pageToken=", url, sep);
+        self.client
+            .get_pages_stream(url, move |body: crate::types::FileList| {
+                let page = if body.next_page_token.is_empty() {
+                    None
+                } else {
+                    Some(format!("{}{}", next, body.next_page_token))
+                };
+
+                (body.files, page)
+            })
     }
     /**
      * This function performs a `POST` to the `/files` endpoint.
@@ -339,6 +1240,26 @@ impl Files {
             )
             .await
     }
+    /**
+     * This function performs a `POST` to the `/files` endpoint.
+     *
+     * As opposed to `create`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Creates a new file.
+     */
+    pub fn create_request<'a>(&'a self, body: &'a crate::types::File) -> FilesCreateRequest<'a> {
+        FilesCreateRequest {
+            client: self,
+            ignore_default_visibility: None,
+            include_permissions_for_view: None,
+            keep_revision_forever: None,
+            ocr_language: None,
+            supports_all_drives: None,
+            supports_team_drives: None,
+            use_content_as_indexable_text: None,
+            body,
+        }
+    }
     /**
      * This function performs a `GET` to the `/files/generateIds` endpoint.
      *
@@ -380,6 +1301,21 @@ impl Files {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/files/generateIds` endpoint.
+     *
+     * As opposed to `generate_id`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Generates a set of file IDs which can be provided in create or copy requests.
+     */
+    pub fn generate_id_request(&self) -> FilesGenerateIdRequest<'_> {
+        FilesGenerateIdRequest {
+            client: self,
+            count: None,
+            space: None,
+            type_: None,
+        }
+    }
     /**
      * This function performs a `DELETE` to the `/files/trash` endpoint.
      *
@@ -466,6 +1402,23 @@ impl Files {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/files/{fileId}` endpoint.
+     *
+     * As opposed to `get`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Gets a file's metadata or content by ID.
+     */
+    pub fn get_request<'a>(&'a self, file_id: &'a str) -> FilesGetRequest<'a> {
+        FilesGetRequest {
+            client: self,
+            file_id,
+            acknowledge_abuse: None,
+            include_permissions_for_view: None,
+            supports_all_drives: None,
+            supports_team_drives: None,
+        }
+    }
     /**
      * This function performs a `DELETE` to the `/files/{fileId}` endpoint.
      *
@@ -516,6 +1469,21 @@ impl Files {
             )
             .await
     }
+    /**
+     * This function performs a `DELETE` to the `/files/{fileId}` endpoint.
+     *
+     * As opposed to `delete`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Permanently deletes a file owned by the user without moving it to the trash. If the file belongs to a shared drive the user must be an organizer on the parent. If the target is a folder, all descendants owned by the user are also deleted.
+     */
+    pub fn delete_request<'a>(&'a self, file_id: &'a str) -> FilesDeleteRequest<'a> {
+        FilesDeleteRequest {
+            client: self,
+            file_id,
+            supports_all_drives: None,
+            supports_team_drives: None,
+        }
+    }
     /**
      * This function performs a `PATCH` to the `/files/{fileId}` endpoint.
      *
@@ -606,6 +1574,32 @@ impl Files {
             )
             .await
     }
+    /**
+     * This function performs a `PATCH` to the `/files/{fileId}` endpoint.
+     *
+     * As opposed to `update`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Updates a file's metadata and/or content. This method supports patch semantics.
+     */
+    pub fn update_request<'a>(
+        &'a self,
+        file_id: &'a str,
+        body: &'a crate::types::File,
+    ) -> FilesUpdateRequest<'a> {
+        FilesUpdateRequest {
+            client: self,
+            file_id,
+            add_parents: None,
+            include_permissions_for_view: None,
+            keep_revision_forever: None,
+            ocr_language: None,
+            remove_parents: None,
+            supports_all_drives: None,
+            supports_team_drives: None,
+            use_content_as_indexable_text: None,
+            body,
+        }
+    }
     /**
      * This function performs a `POST` to the `/files/{fileId}/copy` endpoint.
      *
@@ -686,6 +1680,30 @@ impl Files {
             )
             .await
     }
+    /**
+     * This function performs a `POST` to the `/files/{fileId}/copy` endpoint.
+     *
+     * As opposed to `copy`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Creates a copy of a file and applies any requested updates with patch semantics. Folders cannot be copied.
+     */
+    pub fn copy_request<'a>(
+        &'a self,
+        file_id: &'a str,
+        body: &'a crate::types::File,
+    ) -> FilesCopyRequest<'a> {
+        FilesCopyRequest {
+            client: self,
+            file_id,
+            ignore_default_visibility: None,
+            include_permissions_for_view: None,
+            keep_revision_forever: None,
+            ocr_language: None,
+            supports_all_drives: None,
+            supports_team_drives: None,
+            body,
+        }
+    }
     /**
      * This function performs a `GET` to the `/files/{fileId}/export` endpoint.
      *
@@ -790,4 +1808,26 @@ impl Files {
             )
             .await
     }
+    /**
+     * This function performs a `POST` to the `/files/{fileId}/watch` endpoint.
+     *
+     * As opposed to `watch`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Subscribes to changes to a file
+     */
+    pub fn watch_request<'a>(
+        &'a self,
+        file_id: &'a str,
+        body: &'a crate::types::Channel,
+    ) -> FilesWatchRequest<'a> {
+        FilesWatchRequest {
+            client: self,
+            file_id,
+            acknowledge_abuse: None,
+            include_permissions_for_view: None,
+            supports_all_drives: None,
+            supports_team_drives: None,
+            body,
+        }
+    }
 }
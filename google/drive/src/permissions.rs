@@ -1,6 +1,475 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Permissions::list`], set with a builder.
+pub struct PermissionsListRequest<'a> {
+    client: &'a Permissions,
+    file_id: &'a str,
+    include_permissions_for_view: Option<&'a str>,
+    page_size: Option<i64>,
+    page_token: Option<&'a str>,
+    supports_all_drives: Option<bool>,
+    supports_team_drives: Option<bool>,
+    use_domain_admin_access: Option<bool>,
+}
+
+impl<'a> PermissionsListRequest<'a> {
+    /// Sets the `includePermissionsForView` query parameter.
+    pub fn include_permissions_for_view(mut self, include_permissions_for_view: &'a str) -> Self {
+        self.include_permissions_for_view = Some(include_permissions_for_view);
+        self
+    }
+
+    /// Sets the `pageSize` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Sets the `pageToken` query parameter.
+    pub fn page_token(mut self, page_token: &'a str) -> Self {
+        self.page_token = Some(page_token);
+        self
+    }
+
+    /// Sets the `supportsAllDrives` query parameter.
+    pub fn supports_all_drives(mut self, supports_all_drives: bool) -> Self {
+        self.supports_all_drives = Some(supports_all_drives);
+        self
+    }
+
+    /// Sets the `supportsTeamDrives` query parameter.
+    pub fn supports_team_drives(mut self, supports_team_drives: bool) -> Self {
+        self.supports_team_drives = Some(supports_team_drives);
+        self
+    }
+
+    /// Sets the `useDomainAdminAccess` query parameter.
+    pub fn use_domain_admin_access(mut self, use_domain_admin_access: bool) -> Self {
+        self.use_domain_admin_access = Some(use_domain_admin_access);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Permission>>> {
+        let Self {
+            client,
+            file_id,
+            include_permissions_for_view,
+            page_size,
+            page_token,
+            supports_all_drives,
+            supports_team_drives,
+            use_domain_admin_access,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = include_permissions_for_view {
+            query_args.push(("includePermissionsForView".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_size {
+            query_args.push(("pageSize".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_token {
+            query_args.push(("pageToken".to_string(), v.to_string()));
+        }
+        if let Some(v) = supports_all_drives {
+            query_args.push(("supportsAllDrives".to_string(), v.to_string()));
+        }
+        if let Some(v) = supports_team_drives {
+            query_args.push(("supportsTeamDrives".to_string(), v.to_string()));
+        }
+        if let Some(v) = use_domain_admin_access {
+            query_args.push(("useDomainAdminAccess".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/files/{}/permissions?{}",
+                crate::progenitor_support::encode_path(file_id),
+                query_
+            ),
+            None,
+        );
+
+        let resp: crate::Response<crate::types::PermissionList> = client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await?;
+
+        // Return our response data.
+        Ok(crate::Response::new(
+            resp.status,
+            resp.headers,
+            resp.body.permissions.to_vec(),
+        ))
+    }
+}
+
+/// The parameters of [`Permissions::create`], set with a builder.
+pub struct PermissionsCreateRequest<'a> {
+    client: &'a Permissions,
+    file_id: &'a str,
+    email_message: Option<&'a str>,
+    move_to_new_owners_root: Option<bool>,
+    send_notification_email: Option<bool>,
+    supports_all_drives: Option<bool>,
+    supports_team_drives: Option<bool>,
+    transfer_ownership: Option<bool>,
+    use_domain_admin_access: Option<bool>,
+    body: &'a crate::types::Permission,
+}
+
+impl<'a> PermissionsCreateRequest<'a> {
+    /// Sets the `emailMessage` query parameter.
+    pub fn email_message(mut self, email_message: &'a str) -> Self {
+        self.email_message = Some(email_message);
+        self
+    }
+
+    /// Sets the `moveToNewOwnersRoot` query parameter.
+    pub fn move_to_new_owners_root(mut self, move_to_new_owners_root: bool) -> Self {
+        self.move_to_new_owners_root = Some(move_to_new_owners_root);
+        self
+    }
+
+    /// Sets the `sendNotificationEmail` query parameter.
+    pub fn send_notification_email(mut self, send_notification_email: bool) -> Self {
+        self.send_notification_email = Some(send_notification_email);
+        self
+    }
+
+    /// Sets the `supportsAllDrives` query parameter.
+    pub fn supports_all_drives(mut self, supports_all_drives: bool) -> Self {
+        self.supports_all_drives = Some(supports_all_drives);
+        self
+    }
+
+    /// Sets the `supportsTeamDrives` query parameter.
+    pub fn supports_team_drives(mut self, supports_team_drives: bool) -> Self {
+        self.supports_team_drives = Some(supports_team_drives);
+        self
+    }
+
+    /// Sets the `transferOwnership` query parameter.
+    pub fn transfer_ownership(mut self, transfer_ownership: bool) -> Self {
+        self.transfer_ownership = Some(transfer_ownership);
+        self
+    }
+
+    /// Sets the `useDomainAdminAccess` query parameter.
+    pub fn use_domain_admin_access(mut self, use_domain_admin_access: bool) -> Self {
+        self.use_domain_admin_access = Some(use_domain_admin_access);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::Permission>> {
+        let Self {
+            client,
+            file_id,
+            email_message,
+            move_to_new_owners_root,
+            send_notification_email,
+            supports_all_drives,
+            supports_team_drives,
+            transfer_ownership,
+            use_domain_admin_access,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = email_message {
+            query_args.push(("emailMessage".to_string(), v.to_string()));
+        }
+        if let Some(v) = move_to_new_owners_root {
+            query_args.push(("moveToNewOwnersRoot".to_string(), v.to_string()));
+        }
+        if let Some(v) = send_notification_email {
+            query_args.push(("sendNotificationEmail".to_string(), v.to_string()));
+        }
+        if let Some(v) = supports_all_drives {
+            query_args.push(("supportsAllDrives".to_string(), v.to_string()));
+        }
+        if let Some(v) = supports_team_drives {
+            query_args.push(("supportsTeamDrives".to_string(), v.to_string()));
+        }
+        if let Some(v) = transfer_ownership {
+            query_args.push(("transferOwnership".to_string(), v.to_string()));
+        }
+        if let Some(v) = use_domain_admin_access {
+            query_args.push(("useDomainAdminAccess".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/files/{}/permissions?{}",
+                crate::progenitor_support::encode_path(file_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Permissions::get`], set with a builder.
+pub struct PermissionsGetRequest<'a> {
+    client: &'a Permissions,
+    file_id: &'a str,
+    permission_id: &'a str,
+    supports_all_drives: Option<bool>,
+    supports_team_drives: Option<bool>,
+    use_domain_admin_access: Option<bool>,
+}
+
+impl<'a> PermissionsGetRequest<'a> {
+    /// Sets the `supportsAllDrives` query parameter.
+    pub fn supports_all_drives(mut self, supports_all_drives: bool) -> Self {
+        self.supports_all_drives = Some(supports_all_drives);
+        self
+    }
+
+    /// Sets the `supportsTeamDrives` query parameter.
+    pub fn supports_team_drives(mut self, supports_team_drives: bool) -> Self {
+        self.supports_team_drives = Some(supports_team_drives);
+        self
+    }
+
+    /// Sets the `useDomainAdminAccess` query parameter.
+    pub fn use_domain_admin_access(mut self, use_domain_admin_access: bool) -> Self {
+        self.use_domain_admin_access = Some(use_domain_admin_access);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::Permission>> {
+        let Self {
+            client,
+            file_id,
+            permission_id,
+            supports_all_drives,
+            supports_team_drives,
+            use_domain_admin_access,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = supports_all_drives {
+            query_args.push(("supportsAllDrives".to_string(), v.to_string()));
+        }
+        if let Some(v) = supports_team_drives {
+            query_args.push(("supportsTeamDrives".to_string(), v.to_string()));
+        }
+        if let Some(v) = use_domain_admin_access {
+            query_args.push(("useDomainAdminAccess".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/files/{}/permissions/{}?{}",
+                crate::progenitor_support::encode_path(file_id),
+                crate::progenitor_support::encode_path(permission_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Permissions::delete`], set with a builder.
+pub struct PermissionsDeleteRequest<'a> {
+    client: &'a Permissions,
+    file_id: &'a str,
+    permission_id: &'a str,
+    supports_all_drives: Option<bool>,
+    supports_team_drives: Option<bool>,
+    use_domain_admin_access: Option<bool>,
+}
+
+impl<'a> PermissionsDeleteRequest<'a> {
+    /// Sets the `supportsAllDrives` query parameter.
+    pub fn supports_all_drives(mut self, supports_all_drives: bool) -> Self {
+        self.supports_all_drives = Some(supports_all_drives);
+        self
+    }
+
+    /// Sets the `supportsTeamDrives` query parameter.
+    pub fn supports_team_drives(mut self, supports_team_drives: bool) -> Self {
+        self.supports_team_drives = Some(supports_team_drives);
+        self
+    }
+
+    /// Sets the `useDomainAdminAccess` query parameter.
+    pub fn use_domain_admin_access(mut self, use_domain_admin_access: bool) -> Self {
+        self.use_domain_admin_access = Some(use_domain_admin_access);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            file_id,
+            permission_id,
+            supports_all_drives,
+            supports_team_drives,
+            use_domain_admin_access,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = supports_all_drives {
+            query_args.push(("supportsAllDrives".to_string(), v.to_string()));
+        }
+        if let Some(v) = supports_team_drives {
+            query_args.push(("supportsTeamDrives".to_string(), v.to_string()));
+        }
+        if let Some(v) = use_domain_admin_access {
+            query_args.push(("useDomainAdminAccess".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/files/{}/permissions/{}?{}",
+                crate::progenitor_support::encode_path(file_id),
+                crate::progenitor_support::encode_path(permission_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .delete(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Permissions::update`], set with a builder.
+pub struct PermissionsUpdateRequest<'a> {
+    client: &'a Permissions,
+    file_id: &'a str,
+    permission_id: &'a str,
+    remove_expiration: Option<bool>,
+    supports_all_drives: Option<bool>,
+    supports_team_drives: Option<bool>,
+    transfer_ownership: Option<bool>,
+    use_domain_admin_access: Option<bool>,
+    body: &'a crate::types::Permission,
+}
+
+impl<'a> PermissionsUpdateRequest<'a> {
+    /// Sets the `removeExpiration` query parameter.
+    pub fn remove_expiration(mut self, remove_expiration: bool) -> Self {
+        self.remove_expiration = Some(remove_expiration);
+        self
+    }
+
+    /// Sets the `supportsAllDrives` query parameter.
+    pub fn supports_all_drives(mut self, supports_all_drives: bool) -> Self {
+        self.supports_all_drives = Some(supports_all_drives);
+        self
+    }
+
+    /// Sets the `supportsTeamDrives` query parameter.
+    pub fn supports_team_drives(mut self, supports_team_drives: bool) -> Self {
+        self.supports_team_drives = Some(supports_team_drives);
+        self
+    }
+
+    /// Sets the `transferOwnership` query parameter.
+    pub fn transfer_ownership(mut self, transfer_ownership: bool) -> Self {
+        self.transfer_ownership = Some(transfer_ownership);
+        self
+    }
+
+    /// Sets the `useDomainAdminAccess` query parameter.
+    pub fn use_domain_admin_access(mut self, use_domain_admin_access: bool) -> Self {
+        self.use_domain_admin_access = Some(use_domain_admin_access);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::Permission>> {
+        let Self {
+            client,
+            file_id,
+            permission_id,
+            remove_expiration,
+            supports_all_drives,
+            supports_team_drives,
+            transfer_ownership,
+            use_domain_admin_access,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = remove_expiration {
+            query_args.push(("removeExpiration".to_string(), v.to_string()));
+        }
+        if let Some(v) = supports_all_drives {
+            query_args.push(("supportsAllDrives".to_string(), v.to_string()));
+        }
+        if let Some(v) = supports_team_drives {
+            query_args.push(("supportsTeamDrives".to_string(), v.to_string()));
+        }
+        if let Some(v) = transfer_ownership {
+            query_args.push(("transferOwnership".to_string(), v.to_string()));
+        }
+        if let Some(v) = use_domain_admin_access {
+            query_args.push(("useDomainAdminAccess".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/files/{}/permissions/{}?{}",
+                crate::progenitor_support::encode_path(file_id),
+                crate::progenitor_support::encode_path(permission_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .patch(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
 pub struct Permissions {
     pub client: Client,
 }
@@ -94,6 +563,25 @@ impl Permissions {
             resp.body.permissions.to_vec(),
         ))
     }
+    /**
+     * This function performs a `GET` to the `/files/{fileId}/permissions` endpoint.
+     *
+     * As opposed to `list`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Lists a file's or shared drive's permissions.
+     */
+    pub fn list_request<'a>(&'a self, file_id: &'a str) -> PermissionsListRequest<'a> {
+        PermissionsListRequest {
+            client: self,
+            file_id,
+            include_permissions_for_view: None,
+            page_size: None,
+            page_token: None,
+            supports_all_drives: None,
+            supports_team_drives: None,
+            use_domain_admin_access: None,
+        }
+    }
     /**
      * This function performs a `GET` to the `/files/{fileId}/permissions` endpoint.
      *
@@ -143,11 +631,11 @@ impl Permissions {
             ),
             None,
         );
-        let crate::Response::<crate::types::PermissionList> {
-            mut status,
-            mut headers,
-            mut body,
-        } = self
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let mut pages = crate::utils::Pages::new(self.client.page_limits);
+        pages.visit(&url)?;
+
+        let mut response: crate::Response<crate::types::PermissionList> = self
             .client
             .get(
                 &url,
@@ -157,55 +645,105 @@ impl Permissions {
                 },
             )
             .await?;
-
-        let mut permissions = body.permissions;
-        let mut page = body.next_page_token;
+        let mut permissions = Vec::new();
 
         // Paginate if we should.
-        while !page.is_empty() {
-            if !url.contains('?') {
-                crate::Response::<crate::types::PermissionList> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}?pageToken={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
+        loop {
+            let page = if response.body.next_page_token.is_empty() {
+                None
             } else {
-                crate::Response::<crate::types::PermissionList> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}&pageToken={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
-
-            permissions.append(&mut body.permissions);
-
-            if !body.next_page_token.is_empty() && body.next_page_token != page {
-                page = body.next_page_token.to_string();
-            } else {
-                page = "".to_string();
-            }
+                Some(format!(
+                    "{}{}pageToken={}",
+                    url, sep, response.body.next_page_token
+                ))
+            };
+            let more = pages.keep(&mut response.body.permissions);
+            permissions.append(&mut response.body.permissions);
+
+            let next = match page {
+                Some(next) if more && pages.visit(&next)? => next,
+                _ => break,
+            };
+            response = self
+                .client
+                .get(
+                    &next,
+                    crate::Message {
+                        body: None,
+                        content_type: None,
+                    },
+                )
+                .await?;
         }
 
         // Return our response data.
-        Ok(crate::Response::new(status, headers, permissions))
+        Ok(crate::Response::new(
+            response.status,
+            response.headers,
+            permissions,
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/files/{fileId}/permissions` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Lists a file's or shared drive's permissions.
+     */
+    pub fn list_all_stream(
+        &self,
+        file_id: &str,
+        include_permissions_for_view: &str,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+        use_domain_admin_access: bool,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Permission>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !include_permissions_for_view.is_empty() {
+            query_args.push((
+                "includePermissionsForView".to_string(),
+                include_permissions_for_view.to_string(),
+            ));
+        }
+        if supports_all_drives {
+            query_args.push((
+                "supportsAllDrives".to_string(),
+                supports_all_drives.to_string(),
+            ));
+        }
+        if supports_team_drives {
+            query_args.push((
+                "supportsTeamDrives".to_string(),
+                supports_team_drives.to_string(),
+            ));
+        }
+        if use_domain_admin_access {
+            query_args.push((
+                "useDomainAdminAccess".to_string(),
+                use_domain_admin_access.to_string(),
+            ));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/files/{}/permissions?{}",
+                crate::progenitor_support::encode_path(file_id),
+                query_
+            ),
+            None,
+        );
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let next = format!("{}{}pageToken=", url, sep);
+        self.client
+            .get_pages_stream(url, move |body: crate::types::PermissionList| {
+                let page = if body.next_page_token.is_empty() {
+                    None
+                } else {
+                    Some(format!("{}{}", next, body.next_page_token))
+                };
+
+                (body.permissions, page)
+            })
     }
     /**
      * This function performs a `POST` to the `/files/{fileId}/permissions` endpoint.
@@ -293,6 +831,31 @@ impl Permissions {
             )
             .await
     }
+    /**
+     * This function performs a `POST` to the `/files/{fileId}/permissions` endpoint.
+     *
+     * As opposed to `create`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Creates a permission for a file or shared drive.
+     */
+    pub fn create_request<'a>(
+        &'a self,
+        file_id: &'a str,
+        body: &'a crate::types::Permission,
+    ) -> PermissionsCreateRequest<'a> {
+        PermissionsCreateRequest {
+            client: self,
+            file_id,
+            email_message: None,
+            move_to_new_owners_root: None,
+            send_notification_email: None,
+            supports_all_drives: None,
+            supports_team_drives: None,
+            transfer_ownership: None,
+            use_domain_admin_access: None,
+            body,
+        }
+    }
     /**
      * This function performs a `GET` to the `/files/{fileId}/permissions/{permissionId}` endpoint.
      *
@@ -353,6 +916,27 @@ impl Permissions {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/files/{fileId}/permissions/{permissionId}` endpoint.
+     *
+     * As opposed to `get`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Gets a permission by ID.
+     */
+    pub fn get_request<'a>(
+        &'a self,
+        file_id: &'a str,
+        permission_id: &'a str,
+    ) -> PermissionsGetRequest<'a> {
+        PermissionsGetRequest {
+            client: self,
+            file_id,
+            permission_id,
+            supports_all_drives: None,
+            supports_team_drives: None,
+            use_domain_admin_access: None,
+        }
+    }
     /**
      * This function performs a `DELETE` to the `/files/{fileId}/permissions/{permissionId}` endpoint.
      *
@@ -413,6 +997,27 @@ impl Permissions {
             )
             .await
     }
+    /**
+     * This function performs a `DELETE` to the `/files/{fileId}/permissions/{permissionId}` endpoint.
+     *
+     * As opposed to `delete`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Deletes a permission.
+     */
+    pub fn delete_request<'a>(
+        &'a self,
+        file_id: &'a str,
+        permission_id: &'a str,
+    ) -> PermissionsDeleteRequest<'a> {
+        PermissionsDeleteRequest {
+            client: self,
+            file_id,
+            permission_id,
+            supports_all_drives: None,
+            supports_team_drives: None,
+            use_domain_admin_access: None,
+        }
+    }
     /**
      * This function performs a `PATCH` to the `/files/{fileId}/permissions/{permissionId}` endpoint.
      *
@@ -490,4 +1095,29 @@ impl Permissions {
             )
             .await
     }
+    /**
+     * This function performs a `PATCH` to the `/files/{fileId}/permissions/{permissionId}` endpoint.
+     *
+     * As opposed to `update`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Updates a permission with patch semantics.
+     */
+    pub fn update_request<'a>(
+        &'a self,
+        file_id: &'a str,
+        permission_id: &'a str,
+        body: &'a crate::types::Permission,
+    ) -> PermissionsUpdateRequest<'a> {
+        PermissionsUpdateRequest {
+            client: self,
+            file_id,
+            permission_id,
+            remove_expiration: None,
+            supports_all_drives: None,
+            supports_team_drives: None,
+            transfer_ownership: None,
+            use_domain_admin_access: None,
+            body,
+        }
+    }
 }
@@ -1,6 +1,140 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Replies::list`], set with a builder.
+pub struct RepliesListRequest<'a> {
+    client: &'a Replies,
+    file_id: &'a str,
+    comment_id: &'a str,
+    include_deleted: Option<bool>,
+    page_size: Option<i64>,
+    page_token: Option<&'a str>,
+}
+
+impl<'a> RepliesListRequest<'a> {
+    /// Sets the `includeDeleted` query parameter.
+    pub fn include_deleted(mut self, include_deleted: bool) -> Self {
+        self.include_deleted = Some(include_deleted);
+        self
+    }
+
+    /// Sets the `pageSize` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Sets the `pageToken` query parameter.
+    pub fn page_token(mut self, page_token: &'a str) -> Self {
+        self.page_token = Some(page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Reply>>> {
+        let Self {
+            client,
+            file_id,
+            comment_id,
+            include_deleted,
+            page_size,
+            page_token,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = include_deleted {
+            query_args.push(("includeDeleted".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_size {
+            query_args.push(("pageSize".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_token {
+            query_args.push(("pageToken".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/files/{}/comments/{}/replies?{}",
+                crate::progenitor_support::encode_path(file_id),
+                crate::progenitor_support::encode_path(comment_id),
+                query_
+            ),
+            None,
+        );
+
+        let resp: crate::Response<crate::types::ReplyList> = client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await?;
+
+        // Return our response data.
+        Ok(crate::Response::new(
+            resp.status,
+            resp.headers,
+            resp.body.replies.to_vec(),
+        ))
+    }
+}
+
+/// The parameters of [`Replies::get`], set with a builder.
+pub struct RepliesGetRequest<'a> {
+    client: &'a Replies,
+    file_id: &'a str,
+    comment_id: &'a str,
+    reply_id: &'a str,
+    include_deleted: Option<bool>,
+}
+
+impl<'a> RepliesGetRequest<'a> {
+    /// Sets the `includeDeleted` query parameter.
+    pub fn include_deleted(mut self, include_deleted: bool) -> Self {
+        self.include_deleted = Some(include_deleted);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::Reply>> {
+        let Self {
+            client,
+            file_id,
+            comment_id,
+            reply_id,
+            include_deleted,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = include_deleted {
+            query_args.push(("includeDeleted".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/files/{}/comments/{}/replies/{}?{}",
+                crate::progenitor_support::encode_path(file_id),
+                crate::progenitor_support::encode_path(comment_id),
+                crate::progenitor_support::encode_path(reply_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct Replies {
     pub client: Client,
 }
@@ -70,6 +204,27 @@ impl Replies {
             resp.body.replies.to_vec(),
         ))
     }
+    /**
+     * This function performs a `GET` to the `/files/{fileId}/comments/{commentId}/replies` endpoint.
+     *
+     * As opposed to `list`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Lists a comment's replies.
+     */
+    pub fn list_request<'a>(
+        &'a self,
+        file_id: &'a str,
+        comment_id: &'a str,
+    ) -> RepliesListRequest<'a> {
+        RepliesListRequest {
+            client: self,
+            file_id,
+            comment_id,
+            include_deleted: None,
+            page_size: None,
+            page_token: None,
+        }
+    }
     /**
      * This function performs a `GET` to the `/files/{fileId}/comments/{commentId}/replies` endpoint.
      *
@@ -97,11 +252,11 @@ impl Replies {
             ),
             None,
         );
-        let crate::Response::<crate::types::ReplyList> {
-            mut status,
-            mut headers,
-            mut body,
-        } = self
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let mut pages = crate::utils::Pages::new(self.client.page_limits);
+        pages.visit(&url)?;
+
+        let mut response: crate::Response<crate::types::ReplyList> = self
             .client
             .get(
                 &url,
@@ -111,55 +266,83 @@ impl Replies {
                 },
             )
             .await?;
-
-        let mut replies = body.replies;
-        let mut page = body.next_page_token;
+        let mut replies = Vec::new();
 
         // Paginate if we should.
-        while !page.is_empty() {
-            if !url.contains('?') {
-                crate::Response::<crate::types::ReplyList> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}?pageToken={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
+        loop {
+            let page = if response.body.next_page_token.is_empty() {
+                None
             } else {
-                crate::Response::<crate::types::ReplyList> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}&pageToken={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
-
-            replies.append(&mut body.replies);
+                Some(format!(
+                    "{}{}pageToken={}",
+                    url, sep, response.body.next_page_token
+                ))
+            };
+            let more = pages.keep(&mut response.body.replies);
+            replies.append(&mut response.body.replies);
 
-            if !body.next_page_token.is_empty() && body.next_page_token != page {
-                page = body.next_page_token.to_string();
-            } else {
-                page = "".to_string();
-            }
+            let next = match page {
+                Some(next) if more && pages.visit(&next)? => next,
+                _ => break,
+            };
+            response = self
+                .client
+                .get(
+                    &next,
+                    crate::Message {
+                        body: None,
+                        content_type: None,
+                    },
+                )
+                .await?;
         }
 
         // Return our response data.
-        Ok(crate::Response::new(status, headers, replies))
+        Ok(crate::Response::new(
+            response.status,
+            response.headers,
+            replies,
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/files/{fileId}/comments/{commentId}/replies` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Lists a comment's replies.
+     */
+    pub fn list_all_stream(
+        &self,
+        file_id: &str,
+        comment_id: &str,
+        include_deleted: bool,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Reply>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if include_deleted {
+            query_args.push(("includeDeleted".to_string(), include_deleted.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/files/{}/comments/{}/replies?{}",
+                crate::progenitor_support::encode_path(file_id),
+                crate::progenitor_support::encode_path(comment_id),
+                query_
+            ),
+            None,
+        );
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let next = format!("{}{}pageToken=", url, sep);
+        self.client
+            .get_pages_stream(url, move |body: crate::types::ReplyList| {
+                let page = if body.next_page_token.is_empty() {
+                    None
+                } else {
+                    Some(format!("{}{}", next, body.next_page_token))
+                };
+
+                (body.replies, page)
+            })
     }
     /**
      * This function performs a `POST` to the `/files/{fileId}/comments/{commentId}/replies` endpoint.
@@ -239,6 +422,27 @@ impl Replies {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/files/{fileId}/comments/{commentId}/replies/{replyId}` endpoint.
+     *
+     * As opposed to `get`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Gets a reply by ID.
+     */
+    pub fn get_request<'a>(
+        &'a self,
+        file_id: &'a str,
+        comment_id: &'a str,
+        reply_id: &'a str,
+    ) -> RepliesGetRequest<'a> {
+        RepliesGetRequest {
+            client: self,
+            file_id,
+            comment_id,
+            reply_id,
+            include_deleted: None,
+        }
+    }
     /**
      * This function performs a `DELETE` to the `/files/{fileId}/comments/{commentId}/replies/{replyId}` endpoint.
      *
@@ -1,6 +1,123 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Revisions::list`], set with a builder.
+pub struct RevisionsListRequest<'a> {
+    client: &'a Revisions,
+    file_id: &'a str,
+    page_size: Option<i64>,
+    page_token: Option<&'a str>,
+}
+
+impl<'a> RevisionsListRequest<'a> {
+    /// Sets the `pageSize` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Sets the `pageToken` query parameter.
+    pub fn page_token(mut self, page_token: &'a str) -> Self {
+        self.page_token = Some(page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Revision>>> {
+        let Self {
+            client,
+            file_id,
+            page_size,
+            page_token,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page_size {
+            query_args.push(("pageSize".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_token {
+            query_args.push(("pageToken".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/files/{}/revisions?{}",
+                crate::progenitor_support::encode_path(file_id),
+                query_
+            ),
+            None,
+        );
+
+        let resp: crate::Response<crate::types::RevisionList> = client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await?;
+
+        // Return our response data.
+        Ok(crate::Response::new(
+            resp.status,
+            resp.headers,
+            resp.body.revisions.to_vec(),
+        ))
+    }
+}
+
+/// The parameters of [`Revisions::get`], set with a builder.
+pub struct RevisionsGetRequest<'a> {
+    client: &'a Revisions,
+    file_id: &'a str,
+    revision_id: &'a str,
+    acknowledge_abuse: Option<bool>,
+}
+
+impl<'a> RevisionsGetRequest<'a> {
+    /// Sets the `acknowledgeAbuse` query parameter.
+    pub fn acknowledge_abuse(mut self, acknowledge_abuse: bool) -> Self {
+        self.acknowledge_abuse = Some(acknowledge_abuse);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::Revision>> {
+        let Self {
+            client,
+            file_id,
+            revision_id,
+            acknowledge_abuse,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = acknowledge_abuse {
+            query_args.push(("acknowledgeAbuse".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/files/{}/revisions/{}?{}",
+                crate::progenitor_support::encode_path(file_id),
+                crate::progenitor_support::encode_path(revision_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct Revisions {
     pub client: Client,
 }
@@ -62,6 +179,21 @@ impl Revisions {
             resp.body.revisions.to_vec(),
         ))
     }
+    /**
+     * This function performs a `GET` to the `/files/{fileId}/revisions` endpoint.
+     *
+     * As opposed to `list`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Lists a file's revisions.
+     */
+    pub fn list_request<'a>(&'a self, file_id: &'a str) -> RevisionsListRequest<'a> {
+        RevisionsListRequest {
+            client: self,
+            file_id,
+            page_size: None,
+            page_token: None,
+        }
+    }
     /**
      * This function performs a `GET` to the `/files/{fileId}/revisions` endpoint.
      *
@@ -80,11 +212,11 @@ impl Revisions {
             ),
             None,
         );
-        let crate::Response::<crate::types::RevisionList> {
-            mut status,
-            mut headers,
-            mut body,
-        } = self
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let mut pages = crate::utils::Pages::new(self.client.page_limits);
+        pages.visit(&url)?;
+
+        let mut response: crate::Response<crate::types::RevisionList> = self
             .client
             .get(
                 &url,
@@ -94,55 +226,74 @@ impl Revisions {
                 },
             )
             .await?;
-
-        let mut revisions = body.revisions;
-        let mut page = body.next_page_token;
+        let mut revisions = Vec::new();
 
         // Paginate if we should.
-        while !page.is_empty() {
-            if !url.contains('?') {
-                crate::Response::<crate::types::RevisionList> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}?pageToken={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
+        loop {
+            let page = if response.body.next_page_token.is_empty() {
+                None
             } else {
-                crate::Response::<crate::types::RevisionList> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}&pageToken={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
-
-            revisions.append(&mut body.revisions);
+                Some(format!(
+                    "{}{}pageToken={}",
+                    url, sep, response.body.next_page_token
+                ))
+            };
+            let more = pages.keep(&mut response.body.revisions);
+            revisions.append(&mut response.body.revisions);
 
-            if !body.next_page_token.is_empty() && body.next_page_token != page {
-                page = body.next_page_token.to_string();
-            } else {
-                page = "".to_string();
-            }
+            let next = match page {
+                Some(next) if more && pages.visit(&next)? => next,
+                _ => break,
+            };
+            response = self
+                .client
+                .get(
+                    &next,
+                    crate::Message {
+                        body: None,
+                        content_type: None,
+                    },
+                )
+                .await?;
         }
 
         // Return our response data.
-        Ok(crate::Response::new(status, headers, revisions))
+        Ok(crate::Response::new(
+            response.status,
+            response.headers,
+            revisions,
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/files/{fileId}/revisions` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Lists a file's revisions.
+     */
+    pub fn list_all_stream(
+        &self,
+        file_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Revision>> + Unpin + '_ {
+        let url = self.client.url(
+            &format!(
+                "/files/{}/revisions",
+                crate::progenitor_support::encode_path(file_id),
+            ),
+            None,
+        );
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let next = format!("{}{}pageToken=", url, sep);
+        self.client
+            .get_pages_stream(url, move |body: crate::types::RevisionList| {
+                let page = if body.next_page_token.is_empty() {
+                    None
+                } else {
+                    Some(format!("{}{}", next, body.next_page_token))
+                };
+
+                (body.revisions, page)
+            })
     }
     /**
      * This function performs a `GET` to the `/files/{fileId}/revisions/{revisionId}` endpoint.
@@ -188,6 +339,25 @@ impl Revisions {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/files/{fileId}/revisions/{revisionId}` endpoint.
+     *
+     * As opposed to `get`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Gets a revision's metadata or content by ID.
+     */
+    pub fn get_request<'a>(
+        &'a self,
+        file_id: &'a str,
+        revision_id: &'a str,
+    ) -> RevisionsGetRequest<'a> {
+        RevisionsGetRequest {
+            client: self,
+            file_id,
+            revision_id,
+            acknowledge_abuse: None,
+        }
+    }
     /**
      * This function performs a `DELETE` to the `/files/{fileId}/revisions/{revisionId}` endpoint.
      *
@@ -0,0 +1,367 @@
+//! For syncing a local copy of the files of a user incrementally, from a token stored between
+//! syncs.
+//!
+//! FROM: <https://developers.google.com/drive/api/guides/manage-changes>
+
+use std::sync::{Arc, Mutex};
+
+use crate::{
+    utils::{next_page_token, pages_from, Pages},
+    ClientError,
+};
+
+/// Errors returned while syncing.
+#[derive(Debug, thiserror::Error)]
+pub enum SyncError {
+    /// The API rejected a request
+    #[error(transparent)]
+    ClientError(Box<ClientError>),
+    /// The token store failed to load, save or clear the token
+    #[error("token store error: {0}")]
+    StoreError(Box<dyn std::error::Error + Send + Sync>),
+}
+
+impl From<ClientError> for SyncError {
+    fn from(err: ClientError) -> Self {
+        SyncError::ClientError(Box::new(err))
+    }
+}
+
+/// Persists the token of a sync session between syncs, like in a database.
+#[async_trait::async_trait]
+pub trait TokenStore: Send + Sync {
+    /// Returns the stored token, or `None` before the first sync.
+    async fn load(&self) -> Result<Option<String>, Box<dyn std::error::Error + Send + Sync>>;
+
+    /// Stores the token to sync from next.
+    async fn save(&self, token: &str) -> Result<(), Box<dyn std::error::Error + Send + Sync>>;
+
+    /// Forgets the stored token, once it was invalidated.
+    async fn clear(&self) -> Result<(), Box<dyn std::error::Error + Send + Sync>>;
+}
+
+/// Keeps the token of a sync session in memory, for as long as the process runs.
+#[derive(Debug, Clone, Default)]
+pub struct MemoryTokenStore {
+    token: Arc<Mutex<Option<String>>>,
+}
+
+impl MemoryTokenStore {
+    /// Create a new store, holding `token` if it is not empty.
+    pub fn new<S>(token: S) -> Self
+    where
+        S: ToString,
+    {
+        let token = token.to_string();
+        MemoryTokenStore {
+            token: Arc::new(Mutex::new(Some(token).filter(|token| !token.is_empty()))),
+        }
+    }
+
+    /// Returns the stored token, if any.
+    pub fn token(&self) -> Option<String> {
+        self.token.lock().unwrap().clone()
+    }
+}
+
+#[async_trait::async_trait]
+impl TokenStore for MemoryTokenStore {
+    async fn load(&self) -> Result<Option<String>, Box<dyn std::error::Error + Send + Sync>> {
+        Ok(self.token())
+    }
+
+    async fn save(&self, token: &str) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        *self.token.lock().unwrap() = Some(token.to_string());
+        Ok(())
+    }
+
+    async fn clear(&self) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        *self.token.lock().unwrap() = None;
+        Ok(())
+    }
+}
+
+/// A change to sync, as yielded by a sync session.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SyncChange<T> {
+    /// The item was listed by a full sync, made without a stored token.
+    Added(T),
+    /// The item was added or changed since the previous sync.
+    Changed(T),
+    /// The item with this id was deleted since the previous sync.
+    Deleted(String),
+    /// The stored token was invalidated, and the sync restarts from scratch: the
+    /// items synced so far have to be dropped, as all of them are listed again as
+    /// `Added`.
+    Reset,
+}
+
+/// Returns whether the API rejected a token as expired, with `410 Gone`.
+fn is_gone(err: &ClientError) -> bool {
+    match err {
+        ClientError::ApiError { status, .. } | ClientError::HttpError { status, .. } => {
+            *status == http::StatusCode::GONE
+        }
+        _ => false,
+    }
+}
+
+enum Step {
+    Start,
+    Files {
+        start_token: String,
+        page_token: String,
+        pages: Pages,
+    },
+    Changes {
+        page_token: String,
+        pages: Pages,
+    },
+    Save(String),
+}
+
+/// Which files a sync session syncs. By default only those of the user's My Drive
+/// are, leaving out shared drives.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct SyncOptions {
+    /// The shared drive to sync the files of, instead of those of the user.
+    pub drive_id: String,
+    /// Whether the files of shared drives are synced too.
+    pub include_items_from_all_drives: bool,
+    /// Whether the application supports shared drives, which syncing their files
+    /// needs.
+    pub supports_all_drives: bool,
+    /// The number of files or changes to fetch per page, or the default of the API
+    /// when 0.
+    pub page_size: i64,
+}
+
+impl SyncOptions {
+    /// Options syncing the files of shared drives as well as those of the user's My
+    /// Drive.
+    pub fn all_drives() -> Self {
+        SyncOptions {
+            include_items_from_all_drives: true,
+            supports_all_drives: true,
+            ..Default::default()
+        }
+    }
+
+    fn query_args(&self) -> Vec<(String, String)> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !self.drive_id.is_empty() {
+            query_args.push(("driveId".to_string(), self.drive_id.to_string()));
+        }
+        if self.include_items_from_all_drives {
+            query_args.push(("includeItemsFromAllDrives".to_string(), "true".to_string()));
+        }
+        if self.supports_all_drives {
+            query_args.push(("supportsAllDrives".to_string(), "true".to_string()));
+        }
+        if self.page_size > 0 {
+            query_args.push(("pageSize".to_string(), self.page_size.to_string()));
+        }
+        query_args
+    }
+}
+
+/// Syncs the files of a user, from the page token of the previous sync.
+pub struct SyncSession<S> {
+    client: crate::Client,
+    store: S,
+    options: SyncOptions,
+}
+
+impl<S: TokenStore> SyncSession<S> {
+    /// Create a new session syncing the files of the user, with the token kept in
+    /// `store`.
+    pub fn new(client: crate::Client, store: S) -> Self {
+        SyncSession {
+            client,
+            store,
+            options: Default::default(),
+        }
+    }
+
+    /// Sets which files the session syncs.
+    ///
+    /// The stored token is only valid for the options it was stored with, so they
+    /// should not change between syncs.
+    pub fn with_options(&mut self, options: SyncOptions) -> &mut Self {
+        self.options = options;
+        self
+    }
+
+    /// Returns the store of the session.
+    pub fn store(&self) -> &S {
+        &self.store
+    }
+
+    /// Lists the files that changed since the previous sync, or all of them without a
+    /// stored token.
+    ///
+    /// The token is only stored once the stream is exhausted, so that an interrupted
+    /// sync, or one failing as its pages loop or the page limits of the client are
+    /// reached, starts over from the previous one. Files removed from the view of the
+    /// user are yielded as `Deleted`, while the changes to shared drives themselves
+    /// are skipped.
+    pub fn sync(
+        &self,
+    ) -> impl futures::Stream<Item = Result<SyncChange<crate::types::File>, SyncError>> + Unpin + '_
+    {
+        use futures::TryStreamExt;
+
+        Box::pin(
+            futures::stream::try_unfold(Step::Start, move |step| async move {
+                let step = match step {
+                    Step::Start => {
+                        match self.store.load().await.map_err(SyncError::StoreError)? {
+                            Some(token) if !token.is_empty() => Step::Changes {
+                                pages: pages_from(self.client.page_limits, &token),
+                                page_token: token,
+                            },
+                            // The start token is taken before listing the files, not to
+                            // miss the changes made while they are listed.
+                            _ => Step::Files {
+                                start_token: self.start_page_token().await?,
+                                page_token: String::new(),
+                                pages: pages_from(self.client.page_limits, ""),
+                            },
+                        }
+                    }
+                    Step::Save(token) => {
+                        if !token.is_empty() {
+                            self.store
+                                .save(&token)
+                                .await
+                                .map_err(SyncError::StoreError)?;
+                        }
+                        return Ok::<_, SyncError>(None);
+                    }
+                    step => step,
+                };
+
+                let (changes, step) = match step {
+                    Step::Files {
+                        start_token,
+                        page_token,
+                        mut pages,
+                    } => {
+                        let page = self.files_page(&page_token).await?;
+                        let changes = page
+                            .files
+                            .into_iter()
+                            .map(|file| Ok(SyncChange::Added(file)))
+                            .collect::<Vec<_>>();
+                        let step = match next_page_token(&mut pages, page.next_page_token)? {
+                            Some(page_token) => Step::Files {
+                                start_token,
+                                page_token,
+                                pages,
+                            },
+                            None => Step::Save(start_token),
+                        };
+                        (changes, step)
+                    }
+                    Step::Changes {
+                        page_token,
+                        mut pages,
+                    } => match self.changes_page(&page_token).await {
+                        Err(err) if is_gone(&err) => {
+                            self.store.clear().await.map_err(SyncError::StoreError)?;
+                            let step = Step::Files {
+                                start_token: self.start_page_token().await?,
+                                page_token: String::new(),
+                                pages: pages_from(self.client.page_limits, ""),
+                            };
+                            (vec![Ok(SyncChange::Reset)], step)
+                        }
+                        page => {
+                            let page = page?.body;
+                            let changes = page
+                                .changes
+                                .into_iter()
+                                .filter_map(|change| {
+                                    if change.removed == Some(true) {
+                                        Some(Ok(SyncChange::Deleted(change.file_id)))
+                                    } else {
+                                        change.file.map(|file| Ok(SyncChange::Changed(file)))
+                                    }
+                                })
+                                .collect::<Vec<_>>();
+                            let step = match next_page_token(&mut pages, page.next_page_token)? {
+                                Some(page_token) => Step::Changes { page_token, pages },
+                                None => Step::Save(page.new_start_page_token),
+                            };
+                            (changes, step)
+                        }
+                    },
+                    Step::Start | Step::Save(_) => unreachable!(),
+                };
+
+                Ok(Some((futures::stream::iter(changes), step)))
+            })
+            .try_flatten(),
+        )
+    }
+
+    async fn start_page_token(&self) -> crate::ClientResult<String> {
+        let resp = self
+            .client
+            .changes()
+            .get_start_page_token(
+                &self.options.drive_id,
+                self.options.supports_all_drives,
+                false,
+                "",
+            )
+            .await?;
+        Ok(resp.body.start_page_token)
+    }
+
+    async fn files_page(&self, page_token: &str) -> crate::ClientResult<crate::types::FileList> {
+        let mut query_args = self.options.query_args();
+        if !self.options.drive_id.is_empty() {
+            query_args.push(("corpora".to_string(), "drive".to_string()));
+        } else if self.options.include_items_from_all_drives {
+            query_args.push(("corpora".to_string(), "allDrives".to_string()));
+        }
+        if !page_token.is_empty() {
+            query_args.push(("pageToken".to_string(), page_token.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/files?{}", query_), None);
+        let resp: crate::Response<crate::types::FileList> = self
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await?;
+        Ok(resp.body)
+    }
+
+    /// Fetches one page of the changes to the files since the sync that returned
+    /// `page_token`.
+    async fn changes_page(
+        &self,
+        page_token: &str,
+    ) -> crate::ClientResult<crate::Response<crate::types::ChangeList>> {
+        let mut query_args = self.options.query_args();
+        query_args.push(("pageToken".to_string(), page_token.to_string()));
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/changes?{}", query_), None);
+        self.client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
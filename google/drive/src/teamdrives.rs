@@ -1,6 +1,183 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Teamdrives::list`], set with a builder.
+pub struct TeamdrivesListRequest<'a> {
+    client: &'a Teamdrives,
+    page_size: Option<i64>,
+    page_token: Option<&'a str>,
+    q: Option<&'a str>,
+    use_domain_admin_access: Option<bool>,
+}
+
+impl<'a> TeamdrivesListRequest<'a> {
+    /// Sets the `pageSize` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Sets the `pageToken` query parameter.
+    pub fn page_token(mut self, page_token: &'a str) -> Self {
+        self.page_token = Some(page_token);
+        self
+    }
+
+    /// Sets the `q` query parameter.
+    pub fn q(mut self, q: &'a str) -> Self {
+        self.q = Some(q);
+        self
+    }
+
+    /// Sets the `useDomainAdminAccess` query parameter.
+    pub fn use_domain_admin_access(mut self, use_domain_admin_access: bool) -> Self {
+        self.use_domain_admin_access = Some(use_domain_admin_access);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::TeamDrive>>> {
+        let Self {
+            client,
+            page_size,
+            page_token,
+            q,
+            use_domain_admin_access,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page_size {
+            query_args.push(("pageSize".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_token {
+            query_args.push(("pageToken".to_string(), v.to_string()));
+        }
+        if let Some(v) = q {
+            query_args.push(("q".to_string(), v.to_string()));
+        }
+        if let Some(v) = use_domain_admin_access {
+            query_args.push(("useDomainAdminAccess".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(&format!("/teamdrives?{}", query_), None);
+
+        let resp: crate::Response<crate::types::TeamDriveList> = client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await?;
+
+        // Return our response data.
+        Ok(crate::Response::new(
+            resp.status,
+            resp.headers,
+            resp.body.team_drives.to_vec(),
+        ))
+    }
+}
+
+/// The parameters of [`Teamdrives::get`], set with a builder.
+pub struct TeamdrivesGetRequest<'a> {
+    client: &'a Teamdrives,
+    team_drive_id: &'a str,
+    use_domain_admin_access: Option<bool>,
+}
+
+impl<'a> TeamdrivesGetRequest<'a> {
+    /// Sets the `useDomainAdminAccess` query parameter.
+    pub fn use_domain_admin_access(mut self, use_domain_admin_access: bool) -> Self {
+        self.use_domain_admin_access = Some(use_domain_admin_access);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::TeamDrive>> {
+        let Self {
+            client,
+            team_drive_id,
+            use_domain_admin_access,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = use_domain_admin_access {
+            query_args.push(("useDomainAdminAccess".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/teamdrives/{}?{}",
+                crate::progenitor_support::encode_path(team_drive_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Teamdrives::update`], set with a builder.
+pub struct TeamdrivesUpdateRequest<'a> {
+    client: &'a Teamdrives,
+    team_drive_id: &'a str,
+    use_domain_admin_access: Option<bool>,
+    body: &'a crate::types::TeamDrive,
+}
+
+impl<'a> TeamdrivesUpdateRequest<'a> {
+    /// Sets the `useDomainAdminAccess` query parameter.
+    pub fn use_domain_admin_access(mut self, use_domain_admin_access: bool) -> Self {
+        self.use_domain_admin_access = Some(use_domain_admin_access);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::TeamDrive>> {
+        let Self {
+            client,
+            team_drive_id,
+            use_domain_admin_access,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = use_domain_admin_access {
+            query_args.push(("useDomainAdminAccess".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/teamdrives/{}?{}",
+                crate::progenitor_support::encode_path(team_drive_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .patch(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
 pub struct Teamdrives {
     pub client: Client,
 }
@@ -66,6 +243,22 @@ impl Teamdrives {
             resp.body.team_drives.to_vec(),
         ))
     }
+    /**
+     * This function performs a `GET` to the `/teamdrives` endpoint.
+     *
+     * As opposed to `list`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Deprecated use drives.list instead.
+     */
+    pub fn list_request(&self) -> TeamdrivesListRequest<'_> {
+        TeamdrivesListRequest {
+            client: self,
+            page_size: None,
+            page_token: None,
+            q: None,
+            use_domain_admin_access: None,
+        }
+    }
     /**
      * This function performs a `GET` to the `/teamdrives` endpoint.
      *
@@ -90,11 +283,11 @@ impl Teamdrives {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/teamdrives?{}", query_), None);
-        let crate::Response::<crate::types::TeamDriveList> {
-            mut status,
-            mut headers,
-            mut body,
-        } = self
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let mut pages = crate::utils::Pages::new(self.client.page_limits);
+        pages.visit(&url)?;
+
+        let mut response: crate::Response<crate::types::TeamDriveList> = self
             .client
             .get(
                 &url,
@@ -104,55 +297,80 @@ impl Teamdrives {
                 },
             )
             .await?;
-
-        let mut team_drives = body.team_drives;
-        let mut page = body.next_page_token;
+        let mut team_drives = Vec::new();
 
         // Paginate if we should.
-        while !page.is_empty() {
-            if !url.contains('?') {
-                crate::Response::<crate::types::TeamDriveList> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}?pageToken={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
+        loop {
+            let page = if response.body.next_page_token.is_empty() {
+                None
             } else {
-                crate::Response::<crate::types::TeamDriveList> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}&pageToken={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
-
-            team_drives.append(&mut body.team_drives);
+                Some(format!(
+                    "{}{}pageToken={}",
+                    url, sep, response.body.next_page_token
+                ))
+            };
+            let more = pages.keep(&mut response.body.team_drives);
+            team_drives.append(&mut response.body.team_drives);
 
-            if !body.next_page_token.is_empty() && body.next_page_token != page {
-                page = body.next_page_token.to_string();
-            } else {
-                page = "".to_string();
-            }
+            let next = match page {
+                Some(next) if more && pages.visit(&next)? => next,
+                _ => break,
+            };
+            response = self
+                .client
+                .get(
+                    &next,
+                    crate::Message {
+                        body: None,
+                        content_type: None,
+                    },
+                )
+                .await?;
         }
 
         // Return our response data.
-        Ok(crate::Response::new(status, headers, team_drives))
+        Ok(crate::Response::new(
+            response.status,
+            response.headers,
+            team_drives,
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/teamdrives` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Deprecated use drives.list instead.
+     */
+    pub fn list_all_stream(
+        &self,
+        q: &str,
+        use_domain_admin_access: bool,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::TeamDrive>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !q.is_empty() {
+            query_args.push(("q".to_string(), q.to_string()));
+        }
+        if use_domain_admin_access {
+            query_args.push((
+                "useDomainAdminAccess".to_string(),
+                use_domain_admin_access.to_string(),
+            ));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/teamdrives?{}", query_), None);
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let next = format!("{}{}pageToken=", url, sep);
+        self.client
+            .get_pages_stream(url, move |body: crate::types::TeamDriveList| {
+                let page = if body.next_page_token.is_empty() {
+                    None
+                } else {
+                    Some(format!("{}{}", next, body.next_page_token))
+                };
+
+                (body.team_drives, page)
+            })
     }
     /**
      * This function performs a `POST` to the `/teamdrives` endpoint.
@@ -225,6 +443,20 @@ impl Teamdrives {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/teamdrives/{teamDriveId}` endpoint.
+     *
+     * As opposed to `get`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Deprecated use drives.get instead.
+     */
+    pub fn get_request<'a>(&'a self, team_drive_id: &'a str) -> TeamdrivesGetRequest<'a> {
+        TeamdrivesGetRequest {
+            client: self,
+            team_drive_id,
+            use_domain_admin_access: None,
+        }
+    }
     /**
      * This function performs a `DELETE` to the `/teamdrives/{teamDriveId}` endpoint.
      *
@@ -294,4 +526,23 @@ impl Teamdrives {
             )
             .await
     }
+    /**
+     * This function performs a `PATCH` to the `/teamdrives/{teamDriveId}` endpoint.
+     *
+     * As opposed to `update`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Deprecated use drives.update instead
+     */
+    pub fn update_request<'a>(
+        &'a self,
+        team_drive_id: &'a str,
+        body: &'a crate::types::TeamDrive,
+    ) -> TeamdrivesUpdateRequest<'a> {
+        TeamdrivesUpdateRequest {
+            client: self,
+            team_drive_id,
+            use_domain_admin_access: None,
+            body,
+        }
+    }
 }
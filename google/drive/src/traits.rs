@@ -95,6 +95,8 @@ impl PermissionOps for crate::permissions::Permissions {
             team_drive_permission_details: Default::default(),
             type_: type_.to_string(),
             view: String::new(),
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
         };
 
         // Create the permission and return it.
@@ -33,6 +33,10 @@ pub struct DriveThemes {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The user's storage quota limits and usage. All fields are measured in bytes.
@@ -76,6 +80,10 @@ pub struct StorageQuota {
         rename = "usageInDriveTrash"
     )]
     pub usage_in_drive_trash: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Information about the user, the user's Drive, and system capabilities.
@@ -201,6 +209,10 @@ pub struct About {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub user: Option<User>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A change to a file or shared drive.
@@ -294,6 +306,10 @@ pub struct Change {
         rename = "type"
     )]
     pub type_: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A list of changes for a user.
@@ -337,6 +353,10 @@ pub struct ChangeList {
         rename = "nextPageToken"
     )]
     pub next_page_token: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// An notification channel used to watch for resource changes.
@@ -431,6 +451,10 @@ pub struct Channel {
         rename = "type"
     )]
     pub type_: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The file content to which the comment refers, typically within the anchor region. For a text file, for example, this would be the text at the location of the comment.
@@ -455,6 +479,10 @@ pub struct QuotedFileContent {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub value: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A comment on a file.
@@ -559,6 +587,10 @@ pub struct Comment {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub resolved: Option<bool>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A list of comments on a file.
@@ -592,6 +624,10 @@ pub struct CommentList {
         rename = "nextPageToken"
     )]
     pub next_page_token: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A restriction for accessing the content of the file.
@@ -640,6 +676,10 @@ pub struct ContentRestriction {
         rename = "type"
     )]
     pub type_: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// An image file and cropping parameters from which a background image for this shared drive is set. This is a write only field; it can only be set on drive.drives.update requests that don't set themeId. When specified, all fields of the backgroundImageFile must be set.
@@ -683,6 +723,10 @@ pub struct BackgroundImageFile {
         rename = "yCoordinate"
     )]
     pub y_coordinate: f64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Capabilities the current user has on this shared drive.
@@ -834,6 +878,10 @@ pub struct Capabilities {
         rename = "canTrashChildren"
     )]
     pub can_trash_children: Option<bool>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A set of restrictions that apply to this shared drive or items inside this shared drive.
@@ -875,6 +923,10 @@ pub struct Restrictions {
         rename = "driveMembersOnly"
     )]
     pub drive_members_only: Option<bool>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Representation of a shared drive.
@@ -971,6 +1023,10 @@ pub struct Drive {
         rename = "themeId"
     )]
     pub theme_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A list of shared drives.
@@ -1004,6 +1060,10 @@ pub struct DriveList {
         rename = "nextPageToken"
     )]
     pub next_page_token: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Capabilities the current user has on this file. Each capability corresponds to a fine-grained action that a user may take.
@@ -1300,6 +1360,10 @@ pub struct FileCapabilities {
         rename = "canUntrash"
     )]
     pub can_untrash: Option<bool>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A thumbnail for the file. This will only be used if Google Drive cannot generate a standard thumbnail.
@@ -1320,6 +1384,10 @@ pub struct Thumbnail {
         rename = "mimeType"
     )]
     pub mime_type: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Additional information about the content of the file. These fields are never populated in responses.
@@ -1340,6 +1408,10 @@ pub struct ContentHints {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub thumbnail: Option<Thumbnail>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Geographic location information stored in the image.
@@ -1372,6 +1444,10 @@ pub struct Location {
         deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub longitude: f64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Additional metadata about image media, if available.
@@ -1570,6 +1646,10 @@ pub struct ImageMediaMetadata {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub width: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Contains details about the link URLs that clients are using to refer to this item.
@@ -1593,6 +1673,10 @@ pub struct LinkShareMetadata {
         rename = "securityUpdateEnabled"
     )]
     pub security_update_enabled: Option<bool>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Shortcut file details. Only populated for shortcut files, which have the mimeType field set to application/vnd.google-apps.shortcut.
@@ -1628,6 +1712,10 @@ pub struct ShortcutDetails {
         rename = "targetResourceKey"
     )]
     pub target_resource_key: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Additional metadata about video media. This may not be available immediately upon upload.
@@ -1661,6 +1749,10 @@ pub struct VideoMediaMetadata {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub width: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The metadata for a file.
@@ -2203,6 +2295,10 @@ pub struct File {
         rename = "writersCanShare"
     )]
     pub writers_can_share: Option<bool>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A list of files.
@@ -2245,6 +2341,10 @@ pub struct FileList {
         rename = "nextPageToken"
     )]
     pub next_page_token: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A list of generated file IDs which can be provided in create requests.
@@ -2277,6 +2377,10 @@ pub struct GeneratedIds {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub space: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -2315,6 +2419,10 @@ pub struct PermissionDetails {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub role: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -2353,6 +2461,10 @@ pub struct TeamDrivePermissionDetails {
         rename = "teamDrivePermissionType"
     )]
     pub team_drive_permission_type: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A permission for a file. A permission grants a user, group, domain or the world access to a file or a folder hierarchy.
@@ -2487,6 +2599,10 @@ pub struct Permission {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub view: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A list of permissions for a file.
@@ -2520,6 +2636,10 @@ pub struct PermissionList {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub permissions: Vec<Permission>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A reply to a comment on a file.
@@ -2601,6 +2721,10 @@ pub struct Reply {
         rename = "modifiedTime"
     )]
     pub modified_time: Option<chrono::DateTime<chrono::Utc>>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A list of replies to a comment on a file.
@@ -2634,6 +2758,10 @@ pub struct ReplyList {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub replies: Vec<Reply>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The metadata for a revision to a file.
@@ -2767,6 +2895,10 @@ pub struct Revision {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub size: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A list of revisions of a file.
@@ -2800,6 +2932,10 @@ pub struct RevisionList {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub revisions: Vec<Revision>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -2823,6 +2959,10 @@ pub struct StartPageToken {
         rename = "startPageToken"
     )]
     pub start_page_token: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// An image file and cropping parameters from which a background image for this Team Drive is set. This is a write only field; it can only be set on drive.teamdrives.update requests that don't set themeId. When specified, all fields of the backgroundImageFile must be set.
@@ -2866,6 +3006,10 @@ pub struct TeamDriveBackgroundImageFile {
         rename = "yCoordinate"
     )]
     pub y_coordinate: f64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Capabilities the current user has on this Team Drive.
@@ -3026,6 +3170,10 @@ pub struct TeamDriveCapabilities {
         rename = "canTrashChildren"
     )]
     pub can_trash_children: Option<bool>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A set of restrictions that apply to this Team Drive or items inside this Team Drive.
@@ -3067,6 +3215,10 @@ pub struct TeamDriveRestrictions {
         rename = "teamMembersOnly"
     )]
     pub team_members_only: Option<bool>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Deprecated: use the drive collection instead.
@@ -3158,6 +3310,10 @@ pub struct TeamDrive {
         rename = "themeId"
     )]
     pub theme_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A list of Team Drives.
@@ -3192,6 +3348,10 @@ pub struct TeamDriveList {
         rename = "teamDrives"
     )]
     pub team_drives: Vec<TeamDrive>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Information about a Drive user.
@@ -3251,6 +3411,10 @@ pub struct User {
         rename = "photoLink"
     )]
     pub photo_link: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -3263,8 +3427,9 @@ pub enum Alt {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Alt {
@@ -3272,7 +3437,7 @@ impl std::fmt::Display for Alt {
         match self {
             Alt::Json => "json",
             Alt::Noop => "",
-            Alt::FallthroughString => "*",
+            Alt::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3296,8 +3461,9 @@ pub enum Corpus {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Corpus {
@@ -3306,7 +3472,7 @@ impl std::fmt::Display for Corpus {
             Corpus::Domain => "domain",
             Corpus::User => "user",
             Corpus::Noop => "",
-            Corpus::FallthroughString => "*",
+            Corpus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
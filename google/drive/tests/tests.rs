@@ -1 +1,468 @@
+use std::mem;
 
+use futures::{StreamExt, TryStreamExt};
+use wiremock::{
+    matchers::{method, path, query_param, query_param_is_missing},
+    Mock, MockServer, ResponseTemplate,
+};
+
+use google_drive::{
+    push_notifications::{NotificationError, ResourceState, Webhook},
+    sync::{MemoryTokenStore, SyncChange, SyncError, SyncOptions, SyncSession},
+    Client, ClientError,
+};
+
+#[tokio::test]
+async fn test_stream_fails_on_repeated_page_token() {
+    let server = MockServer::start().await;
+
+    // The second page hands back the token it was requested with.
+    Mock::given(method("GET"))
+        .and(path("/drives"))
+        .and(query_param("pageToken", "next"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "drives": [{ "id": "2" }],
+            "nextPageToken": "next"
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/drives"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "drives": [{ "id": "1" }],
+            "nextPageToken": "next"
+        })))
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut client = Client::new("client_id", "client_secret", "redirect_uri", "token", "");
+    client.with_host_override(server.uri());
+
+    let drives = client.drives();
+    let mut ids = drives.list_all_stream("", false).collect::<Vec<_>>().await;
+
+    assert!(matches!(
+        ids.pop(),
+        Some(Err(ClientError::IncompletePages { page_token })) if page_token.ends_with("pageToken=next")
+    ));
+    let ids = ids
+        .into_iter()
+        .map(|drive| drive.unwrap().id)
+        .collect::<Vec<_>>();
+    assert_eq!(ids, vec!["1".to_string(), "2".to_string()]);
+
+    mem::drop(server)
+}
+
+#[tokio::test]
+async fn test_list_all_fails_on_page_token_cycle() {
+    let server = MockServer::start().await;
+
+    // The tokens go A, B and then back to A.
+    for (token, id, next) in [
+        (None, "1", "A"),
+        (Some("A"), "2", "B"),
+        (Some("B"), "3", "A"),
+    ] {
+        let mock = Mock::given(method("GET")).and(path("/drives"));
+        let mock = match token {
+            Some(token) => mock.and(query_param("pageToken", token)),
+            None => mock.and(query_param_is_missing("pageToken")),
+        };
+        mock.respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "drives": [{ "id": id }],
+            "nextPageToken": next
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    }
+
+    let mut client = Client::new("client_id", "client_secret", "redirect_uri", "token", "");
+    client.with_host_override(server.uri());
+
+    match client.drives().list_all("", false).await {
+        Err(ClientError::IncompletePages { page_token }) => {
+            assert!(page_token.ends_with("pageToken=A"))
+        }
+        result => unreachable!("Expected incomplete pages, got {:?}", result),
+    }
+
+    mem::drop(server)
+}
+
+#[tokio::test]
+async fn test_list_request_sends_only_the_parameters_set() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/files"))
+        .and(query_param("q", "name = 'budget'"))
+        .and(query_param("pageSize", "10"))
+        .and(query_param_is_missing("corpora"))
+        .and(query_param_is_missing("pageToken"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "files": [{ "id": "1", "name": "budget" }]
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut client = Client::new("client_id", "client_secret", "redirect_uri", "token", "");
+    client.with_host_override(server.uri());
+
+    let files = client
+        .files()
+        .list_request()
+        .q("name = 'budget'")
+        .page_size(10)
+        .send()
+        .await
+        .unwrap()
+        .body;
+    assert_eq!(files.len(), 1);
+    assert_eq!(files[0].id, "1");
+
+    mem::drop(server)
+}
+
+#[tokio::test]
+async fn test_create_request_sends_only_the_parameters_set() {
+    let server = MockServer::start().await;
+
+    // Only whether to send a notification email was set.
+    Mock::given(method("POST"))
+        .and(path("/files/1/permissions"))
+        .and(query_param("sendNotificationEmail", "false"))
+        .and(query_param_is_missing("emailMessage"))
+        .and(query_param_is_missing("moveToNewOwnersRoot"))
+        .and(query_param_is_missing("supportsAllDrives"))
+        .and(query_param_is_missing("transferOwnership"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": "p1",
+            "role": "reader",
+            "type": "user"
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut client = Client::new("client_id", "client_secret", "redirect_uri", "token", "");
+    client.with_host_override(server.uri());
+
+    let permission = serde_json::from_value(serde_json::json!({
+        "emailAddress": "a@example.com",
+        "role": "reader",
+        "type": "user"
+    }))
+    .unwrap();
+    let created = client
+        .permissions()
+        .create_request("1", &permission)
+        .send_notification_email(false)
+        .send()
+        .await
+        .unwrap()
+        .body;
+    assert_eq!(created.id, "p1");
+
+    mem::drop(server)
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn test_blocking_client_sends_requests() {
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+    let server = runtime.block_on(MockServer::start());
+
+    runtime.block_on(
+        Mock::given(method("GET"))
+            .and(path("/files"))
+            .and(query_param("q", "name = 'budget'"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "files": [{ "id": "1", "name": "budget" }]
+            })))
+            .expect(1)
+            .mount(&server),
+    );
+    runtime.block_on(
+        Mock::given(method("GET"))
+            .and(path("/drives"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "drives": [{ "id": "2" }]
+            })))
+            .expect(1)
+            .mount(&server),
+    );
+
+    let mut client = Client::new("client_id", "client_secret", "redirect_uri", "token", "");
+    client.with_host_override(server.uri());
+    let client = google_drive::blocking::Client::new(client).unwrap();
+
+    let files = client
+        .files()
+        .list_request()
+        .q("name = 'budget'")
+        .send()
+        .unwrap()
+        .body;
+    assert_eq!(files[0].id, "1");
+
+    let drives = client.drives().list_all("", false).unwrap().body;
+    assert_eq!(drives[0].id, "2");
+
+    mem::drop(server)
+}
+
+#[test]
+fn test_webhook_constructs_notification() {
+    let headers = [
+        ("X-Goog-Channel-ID", "4ba78bf0-6a47-11e2-bcfd-0800200c9a66"),
+        ("X-Goog-Channel-Token", "tok3n"),
+        ("X-Goog-Resource-ID", "ret08u3rv24htgh289g"),
+        ("X-Goog-Resource-State", "update"),
+        ("X-Goog-Changed", "content, properties"),
+        ("X-Goog-Message-Number", "480896"),
+    ];
+
+    let notification = Webhook::new("tok3n")
+        .construct_notification(headers)
+        .unwrap();
+    assert_eq!(notification.resource_state, ResourceState::Update);
+    assert_eq!(notification.changed, vec!["content", "properties"]);
+    assert_eq!(notification.message_number, 480896);
+    assert_eq!(notification.channel_expiration, None);
+
+    assert!(matches!(
+        Webhook::new("tok3n").construct_notification([("X-Goog-Message-Number", "one")]),
+        Err(NotificationError::InvalidHeader(_))
+    ));
+}
+
+#[tokio::test]
+async fn test_notification_syncs_changes_through_pages() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/changes"))
+        .and(query_param("pageToken", "100"))
+        .and(query_param_is_missing("supportsAllDrives"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "changes": [{ "fileId": "a", "changeType": "file", "file": { "id": "a" } }],
+            "nextPageToken": "101"
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/changes"))
+        .and(query_param("pageToken", "101"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "changes": [{ "fileId": "b", "changeType": "file", "removed": true }],
+            "newStartPageToken": "102"
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut client = Client::new("client_id", "client_secret", "redirect_uri", "token", "");
+    client.with_host_override(server.uri());
+
+    let session = SyncSession::new(client, MemoryTokenStore::new("100"));
+    let notification = Webhook::new("tok3n")
+        .construct_notification([
+            ("X-Goog-Channel-ID", "4ba78bf0-6a47-11e2-bcfd-0800200c9a66"),
+            ("X-Goog-Channel-Token", "tok3n"),
+            ("X-Goog-Resource-ID", "ret08u3rv24htgh289g"),
+            ("X-Goog-Resource-State", "change"),
+        ])
+        .unwrap();
+    let changes = notification
+        .sync(&session)
+        .try_collect::<Vec<_>>()
+        .await
+        .unwrap();
+    assert_eq!(changes.len(), 2);
+    assert!(matches!(&changes[0], SyncChange::Changed(file) if file.id == "a"));
+    assert_eq!(changes[1], SyncChange::Deleted("b".to_string()));
+    assert_eq!(session.store().token(), Some("102".to_string()));
+
+    mem::drop(server)
+}
+
+#[tokio::test]
+async fn test_sync_session_of_all_drives() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/changes/startPageToken"))
+        .and(query_param("supportsAllDrives", "true"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "startPageToken": "100"
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/files"))
+        .and(query_param("corpora", "allDrives"))
+        .and(query_param("includeItemsFromAllDrives", "true"))
+        .and(query_param("supportsAllDrives", "true"))
+        .and(query_param("pageSize", "50"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "files": [{ "id": "a", "driveId": "d1" }]
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/changes"))
+        .and(query_param("pageToken", "100"))
+        .and(query_param("includeItemsFromAllDrives", "true"))
+        .and(query_param("supportsAllDrives", "true"))
+        .and(query_param("pageSize", "50"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "changes": [
+                { "fileId": "b", "changeType": "file", "driveId": "d1", "file": { "id": "b" } }
+            ],
+            "newStartPageToken": "101"
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut client = Client::new("client_id", "client_secret", "redirect_uri", "token", "");
+    client.with_host_override(server.uri());
+
+    let mut session = SyncSession::new(client, MemoryTokenStore::default());
+    session.with_options(SyncOptions {
+        page_size: 50,
+        ..SyncOptions::all_drives()
+    });
+    let added = session.sync().try_collect::<Vec<_>>().await.unwrap();
+    assert!(matches!(&added[..], [SyncChange::Added(file)] if file.id == "a"));
+
+    let changed = session.sync().try_collect::<Vec<_>>().await.unwrap();
+    assert!(matches!(&changed[..], [SyncChange::Changed(file)] if file.id == "b"));
+    assert_eq!(session.store().token(), Some("101".to_string()));
+
+    mem::drop(server)
+}
+
+#[tokio::test]
+async fn test_sync_session_fails_on_repeated_page_token() {
+    let server = MockServer::start().await;
+
+    // The second page hands back the token it was requested with.
+    Mock::given(method("GET"))
+        .and(path("/changes"))
+        .and(query_param("pageToken", "101"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "changes": [{ "fileId": "b", "changeType": "file" }],
+            "nextPageToken": "101"
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/changes"))
+        .and(query_param("pageToken", "100"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "changes": [{ "fileId": "a", "changeType": "file" }],
+            "nextPageToken": "101"
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut client = Client::new("client_id", "client_secret", "redirect_uri", "token", "");
+    client.with_host_override(server.uri());
+
+    // Storing the token would skip the changes of the pages left.
+    let session = SyncSession::new(client, MemoryTokenStore::new("100"));
+    match session.sync().try_collect::<Vec<_>>().await {
+        Err(SyncError::ClientError(err)) => assert!(matches!(
+            *err,
+            ClientError::IncompletePages { ref page_token } if page_token == "101"
+        )),
+        result => unreachable!("Expected incomplete pages, got {:?}", result),
+    }
+    assert_eq!(session.store().token(), Some("100".to_string()));
+
+    mem::drop(server)
+}
+
+#[tokio::test]
+async fn test_sync_session_lists_files_then_changes() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/changes/startPageToken"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "startPageToken": "100"
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/files"))
+        .and(query_param("pageToken", "next"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "files": [{ "id": "b" }]
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/files"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "files": [{ "id": "a" }],
+            "nextPageToken": "next"
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/changes"))
+        .and(query_param("pageToken", "100"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "changes": [
+                { "fileId": "a", "changeType": "file", "removed": true },
+                { "fileId": "c", "changeType": "file", "file": { "id": "c" } },
+                { "driveId": "d", "changeType": "drive" }
+            ],
+            "newStartPageToken": "101"
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut client = Client::new("client_id", "client_secret", "redirect_uri", "token", "");
+    client.with_host_override(server.uri());
+
+    let session = SyncSession::new(client, MemoryTokenStore::default());
+    let added = session.sync().try_collect::<Vec<_>>().await.unwrap();
+    assert_eq!(added.len(), 2);
+    assert!(matches!(&added[0], SyncChange::Added(file) if file.id == "a"));
+    assert!(matches!(&added[1], SyncChange::Added(file) if file.id == "b"));
+    assert_eq!(session.store().token(), Some("100".to_string()));
+
+    let changed = session.sync().try_collect::<Vec<_>>().await.unwrap();
+    assert_eq!(changed.len(), 2);
+    assert_eq!(changed[0], SyncChange::Deleted("a".to_string()));
+    assert!(matches!(&changed[1], SyncChange::Changed(file) if file.id == "c"));
+    assert_eq!(session.store().token(), Some("101".to_string()));
+
+    mem::drop(server)
+}
@@ -0,0 +1,130 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Groups::get`], set with a builder.
+pub struct GroupsGetRequest<'a> {
+    inner: crate::groups::GroupsGetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> GroupsGetRequest<'a> {
+    /// Sets the `alt` query parameter.
+    pub fn alt(mut self, alt: crate::types::Alt) -> Self {
+        self.inner = self.inner.alt(alt);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::Groups>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Groups::update`], set with a builder.
+pub struct GroupsUpdateRequest<'a> {
+    inner: crate::groups::GroupsUpdateRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> GroupsUpdateRequest<'a> {
+    /// Sets the `alt` query parameter.
+    pub fn alt(mut self, alt: crate::types::Alt) -> Self {
+        self.inner = self.inner.alt(alt);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::Groups>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Groups::patch`], set with a builder.
+pub struct GroupsPatchRequest<'a> {
+    inner: crate::groups::GroupsPatchRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> GroupsPatchRequest<'a> {
+    /// Sets the `alt` query parameter.
+    pub fn alt(mut self, alt: crate::types::Alt) -> Self {
+        self.inner = self.inner.alt(alt);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::Groups>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::groups::Groups`].
+pub struct Groups {
+    inner: crate::groups::Groups,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Groups {
+    pub(crate) fn new(inner: crate::groups::Groups, runtime: Arc<tokio::runtime::Runtime>) -> Self {
+        Groups { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::groups::Groups::get`].
+    pub fn get(
+        &self,
+        alt: crate::types::Alt,
+        group_unique_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Groups>> {
+        self.runtime.block_on(self.inner.get(alt, group_unique_id))
+    }
+    /// The blocking version of [`crate::groups::Groups::get_request`].
+    pub fn get_request<'a>(&'a self, group_unique_id: &'a str) -> GroupsGetRequest<'a> {
+        GroupsGetRequest {
+            inner: self.inner.get_request(group_unique_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::groups::Groups::update`].
+    pub fn update(
+        &self,
+        alt: crate::types::Alt,
+        group_unique_id: &str,
+        body: &crate::types::Groups,
+    ) -> ClientResult<crate::Response<crate::types::Groups>> {
+        self.runtime
+            .block_on(self.inner.update(alt, group_unique_id, body))
+    }
+    /// The blocking version of [`crate::groups::Groups::update_request`].
+    pub fn update_request<'a>(
+        &'a self,
+        group_unique_id: &'a str,
+        body: &'a crate::types::Groups,
+    ) -> GroupsUpdateRequest<'a> {
+        GroupsUpdateRequest {
+            inner: self.inner.update_request(group_unique_id, body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::groups::Groups::patch`].
+    pub fn patch(
+        &self,
+        alt: crate::types::Alt,
+        group_unique_id: &str,
+        body: &crate::types::Groups,
+    ) -> ClientResult<crate::Response<crate::types::Groups>> {
+        self.runtime
+            .block_on(self.inner.patch(alt, group_unique_id, body))
+    }
+    /// The blocking version of [`crate::groups::Groups::patch_request`].
+    pub fn patch_request<'a>(
+        &'a self,
+        group_unique_id: &'a str,
+        body: &'a crate::types::Groups,
+    ) -> GroupsPatchRequest<'a> {
+        GroupsPatchRequest {
+            inner: self.inner.patch_request(group_unique_id, body),
+            runtime: &self.runtime,
+        }
+    }
+}
@@ -1,6 +1,154 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Groups::get`], set with a builder.
+pub struct GroupsGetRequest<'a> {
+    client: &'a Groups,
+    alt: Option<crate::types::Alt>,
+    group_unique_id: &'a str,
+}
+
+impl<'a> GroupsGetRequest<'a> {
+    /// Sets the `alt` query parameter.
+    pub fn alt(mut self, alt: crate::types::Alt) -> Self {
+        self.alt = Some(alt);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::Groups>> {
+        let Self {
+            client,
+            alt,
+            group_unique_id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = alt {
+            query_args.push(("alt".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/{}?{}",
+                crate::progenitor_support::encode_path(group_unique_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Groups::update`], set with a builder.
+pub struct GroupsUpdateRequest<'a> {
+    client: &'a Groups,
+    alt: Option<crate::types::Alt>,
+    group_unique_id: &'a str,
+    body: &'a crate::types::Groups,
+}
+
+impl<'a> GroupsUpdateRequest<'a> {
+    /// Sets the `alt` query parameter.
+    pub fn alt(mut self, alt: crate::types::Alt) -> Self {
+        self.alt = Some(alt);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::Groups>> {
+        let Self {
+            client,
+            alt,
+            group_unique_id,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = alt {
+            query_args.push(("alt".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/{}?{}",
+                crate::progenitor_support::encode_path(group_unique_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .put(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Groups::patch`], set with a builder.
+pub struct GroupsPatchRequest<'a> {
+    client: &'a Groups,
+    alt: Option<crate::types::Alt>,
+    group_unique_id: &'a str,
+    body: &'a crate::types::Groups,
+}
+
+impl<'a> GroupsPatchRequest<'a> {
+    /// Sets the `alt` query parameter.
+    pub fn alt(mut self, alt: crate::types::Alt) -> Self {
+        self.alt = Some(alt);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::Groups>> {
+        let Self {
+            client,
+            alt,
+            group_unique_id,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = alt {
+            query_args.push(("alt".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/{}?{}",
+                crate::progenitor_support::encode_path(group_unique_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .patch(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
 pub struct Groups {
     pub client: Client,
 }
@@ -50,6 +198,20 @@ impl Groups {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/{groupUniqueId}` endpoint.
+     *
+     * As opposed to `get`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Gets one resource by id.
+     */
+    pub fn get_request<'a>(&'a self, group_unique_id: &'a str) -> GroupsGetRequest<'a> {
+        GroupsGetRequest {
+            client: self,
+            alt: None,
+            group_unique_id,
+        }
+    }
     /**
      * This function performs a `PUT` to the `/{groupUniqueId}` endpoint.
      *
@@ -90,6 +252,25 @@ impl Groups {
             )
             .await
     }
+    /**
+     * This function performs a `PUT` to the `/{groupUniqueId}` endpoint.
+     *
+     * As opposed to `update`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Updates an existing resource.
+     */
+    pub fn update_request<'a>(
+        &'a self,
+        group_unique_id: &'a str,
+        body: &'a crate::types::Groups,
+    ) -> GroupsUpdateRequest<'a> {
+        GroupsUpdateRequest {
+            client: self,
+            alt: None,
+            group_unique_id,
+            body,
+        }
+    }
     /**
      * This function performs a `PATCH` to the `/{groupUniqueId}` endpoint.
      *
@@ -130,4 +311,23 @@ impl Groups {
             )
             .await
     }
+    /**
+     * This function performs a `PATCH` to the `/{groupUniqueId}` endpoint.
+     *
+     * As opposed to `patch`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Updates an existing resource. This method supports patch semantics.
+     */
+    pub fn patch_request<'a>(
+        &'a self,
+        group_unique_id: &'a str,
+        body: &'a crate::types::Groups,
+    ) -> GroupsPatchRequest<'a> {
+        GroupsPatchRequest {
+            client: self,
+            alt: None,
+            group_unique_id,
+            body,
+        }
+    }
 }
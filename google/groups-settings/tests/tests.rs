@@ -1 +1,12 @@
+use google_groups_settings::utils;
 
+#[test]
+fn test_next_link_is_none_on_the_last_page() {
+    let link = parse_link_header::parse(
+        "<https://www.googleapis.com/groups/v1/groups?page=1>; rel=\"first\", \
+         <https://www.googleapis.com/groups/v1/groups?page=4>; rel=\"prev\"",
+    )
+    .unwrap();
+
+    assert!(utils::next_link(&link).is_none());
+}
@@ -0,0 +1,497 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Spreadsheets::get`], set with a builder.
+pub struct SpreadsheetsGetRequest<'a> {
+    inner: crate::spreadsheets::SpreadsheetsGetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> SpreadsheetsGetRequest<'a> {
+    /// Sets the `includeGridData` query parameter.
+    pub fn include_grid_data(mut self, include_grid_data: bool) -> Self {
+        self.inner = self.inner.include_grid_data(include_grid_data);
+        self
+    }
+
+    /// Sets the `ranges` query parameter.
+    pub fn ranges(mut self, ranges: &'a [String]) -> Self {
+        self.inner = self.inner.ranges(ranges);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::Spreadsheet>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Spreadsheets::values_get`], set with a builder.
+pub struct SpreadsheetsValuesGetRequest<'a> {
+    inner: crate::spreadsheets::SpreadsheetsValuesGetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> SpreadsheetsValuesGetRequest<'a> {
+    /// Sets the `dateTimeRenderOption` query parameter.
+    pub fn date_time_render_option(
+        mut self,
+        date_time_render_option: crate::types::DateTimeRenderOption,
+    ) -> Self {
+        self.inner = self.inner.date_time_render_option(date_time_render_option);
+        self
+    }
+
+    /// Sets the `majorDimension` query parameter.
+    pub fn major_dimension(mut self, major_dimension: crate::types::Dimension) -> Self {
+        self.inner = self.inner.major_dimension(major_dimension);
+        self
+    }
+
+    /// Sets the `valueRenderOption` query parameter.
+    pub fn value_render_option(
+        mut self,
+        value_render_option: crate::types::ValueRenderOption,
+    ) -> Self {
+        self.inner = self.inner.value_render_option(value_render_option);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::ValueRange>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Spreadsheets::values_update`], set with a builder.
+pub struct SpreadsheetsValuesUpdateRequest<'a> {
+    inner: crate::spreadsheets::SpreadsheetsValuesUpdateRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> SpreadsheetsValuesUpdateRequest<'a> {
+    /// Sets the `includeValuesInResponse` query parameter.
+    pub fn include_values_in_response(mut self, include_values_in_response: bool) -> Self {
+        self.inner = self
+            .inner
+            .include_values_in_response(include_values_in_response);
+        self
+    }
+
+    /// Sets the `responseDateTimeRenderOption` query parameter.
+    pub fn response_date_time_render_option(
+        mut self,
+        response_date_time_render_option: crate::types::DateTimeRenderOption,
+    ) -> Self {
+        self.inner = self
+            .inner
+            .response_date_time_render_option(response_date_time_render_option);
+        self
+    }
+
+    /// Sets the `responseValueRenderOption` query parameter.
+    pub fn response_value_render_option(
+        mut self,
+        response_value_render_option: crate::types::ValueRenderOption,
+    ) -> Self {
+        self.inner = self
+            .inner
+            .response_value_render_option(response_value_render_option);
+        self
+    }
+
+    /// Sets the `valueInputOption` query parameter.
+    pub fn value_input_option(
+        mut self,
+        value_input_option: crate::types::ValueInputOption,
+    ) -> Self {
+        self.inner = self.inner.value_input_option(value_input_option);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::UpdateValuesResponse>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Spreadsheets::values_append`], set with a builder.
+pub struct SpreadsheetsValuesAppendRequest<'a> {
+    inner: crate::spreadsheets::SpreadsheetsValuesAppendRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> SpreadsheetsValuesAppendRequest<'a> {
+    /// Sets the `includeValuesInResponse` query parameter.
+    pub fn include_values_in_response(mut self, include_values_in_response: bool) -> Self {
+        self.inner = self
+            .inner
+            .include_values_in_response(include_values_in_response);
+        self
+    }
+
+    /// Sets the `insertDataOption` query parameter.
+    pub fn insert_data_option(
+        mut self,
+        insert_data_option: crate::types::InsertDataOption,
+    ) -> Self {
+        self.inner = self.inner.insert_data_option(insert_data_option);
+        self
+    }
+
+    /// Sets the `responseDateTimeRenderOption` query parameter.
+    pub fn response_date_time_render_option(
+        mut self,
+        response_date_time_render_option: crate::types::DateTimeRenderOption,
+    ) -> Self {
+        self.inner = self
+            .inner
+            .response_date_time_render_option(response_date_time_render_option);
+        self
+    }
+
+    /// Sets the `responseValueRenderOption` query parameter.
+    pub fn response_value_render_option(
+        mut self,
+        response_value_render_option: crate::types::ValueRenderOption,
+    ) -> Self {
+        self.inner = self
+            .inner
+            .response_value_render_option(response_value_render_option);
+        self
+    }
+
+    /// Sets the `valueInputOption` query parameter.
+    pub fn value_input_option(
+        mut self,
+        value_input_option: crate::types::ValueInputOption,
+    ) -> Self {
+        self.inner = self.inner.value_input_option(value_input_option);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::AppendValuesResponse>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Spreadsheets::values_batch_get`], set with a builder.
+pub struct SpreadsheetsValuesBatchGetRequest<'a> {
+    inner: crate::spreadsheets::SpreadsheetsValuesBatchGetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> SpreadsheetsValuesBatchGetRequest<'a> {
+    /// Sets the `dateTimeRenderOption` query parameter.
+    pub fn date_time_render_option(
+        mut self,
+        date_time_render_option: crate::types::DateTimeRenderOption,
+    ) -> Self {
+        self.inner = self.inner.date_time_render_option(date_time_render_option);
+        self
+    }
+
+    /// Sets the `majorDimension` query parameter.
+    pub fn major_dimension(mut self, major_dimension: crate::types::Dimension) -> Self {
+        self.inner = self.inner.major_dimension(major_dimension);
+        self
+    }
+
+    /// Sets the `ranges` query parameter.
+    pub fn ranges(mut self, ranges: &'a [String]) -> Self {
+        self.inner = self.inner.ranges(ranges);
+        self
+    }
+
+    /// Sets the `valueRenderOption` query parameter.
+    pub fn value_render_option(
+        mut self,
+        value_render_option: crate::types::ValueRenderOption,
+    ) -> Self {
+        self.inner = self.inner.value_render_option(value_render_option);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::BatchGetValuesResponse>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::spreadsheets::Spreadsheets`].
+pub struct Spreadsheets {
+    inner: crate::spreadsheets::Spreadsheets,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Spreadsheets {
+    pub(crate) fn new(
+        inner: crate::spreadsheets::Spreadsheets,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Spreadsheets { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::spreadsheets::Spreadsheets::create`].
+    pub fn create(
+        &self,
+        body: &crate::types::Spreadsheet,
+    ) -> ClientResult<crate::Response<crate::types::Spreadsheet>> {
+        self.runtime.block_on(self.inner.create(body))
+    }
+    /// The blocking version of [`crate::spreadsheets::Spreadsheets::get`].
+    pub fn get(
+        &self,
+        spreadsheet_id: &str,
+        include_grid_data: bool,
+        ranges: &[String],
+    ) -> ClientResult<crate::Response<crate::types::Spreadsheet>> {
+        self.runtime
+            .block_on(self.inner.get(spreadsheet_id, include_grid_data, ranges))
+    }
+    /// The blocking version of [`crate::spreadsheets::Spreadsheets::get_request`].
+    pub fn get_request<'a>(&'a self, spreadsheet_id: &'a str) -> SpreadsheetsGetRequest<'a> {
+        SpreadsheetsGetRequest {
+            inner: self.inner.get_request(spreadsheet_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::spreadsheets::Spreadsheets::developer_metadata_get`].
+    pub fn developer_metadata_get(
+        &self,
+        spreadsheet_id: &str,
+        metadata_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::DeveloperMetadata>> {
+        self.runtime.block_on(
+            self.inner
+                .developer_metadata_get(spreadsheet_id, metadata_id),
+        )
+    }
+    /// The blocking version of [`crate::spreadsheets::Spreadsheets::developer_metadata_search`].
+    pub fn developer_metadata_search(
+        &self,
+        spreadsheet_id: &str,
+        body: &crate::types::SearchDeveloperMetadataRequest,
+    ) -> ClientResult<crate::Response<crate::types::SearchDeveloperMetadataResponse>> {
+        self.runtime
+            .block_on(self.inner.developer_metadata_search(spreadsheet_id, body))
+    }
+    /// The blocking version of [`crate::spreadsheets::Spreadsheets::sheets_copy`].
+    pub fn sheets_copy(
+        &self,
+        spreadsheet_id: &str,
+        sheet_id: i64,
+        body: &crate::types::CopySheetAnotherSpreadsheetRequest,
+    ) -> ClientResult<crate::Response<crate::types::SheetProperties>> {
+        self.runtime
+            .block_on(self.inner.sheets_copy(spreadsheet_id, sheet_id, body))
+    }
+    /// The blocking version of [`crate::spreadsheets::Spreadsheets::values_get`].
+    pub fn values_get(
+        &self,
+        spreadsheet_id: &str,
+        range: &str,
+        date_time_render_option: crate::types::DateTimeRenderOption,
+        major_dimension: crate::types::Dimension,
+        value_render_option: crate::types::ValueRenderOption,
+    ) -> ClientResult<crate::Response<crate::types::ValueRange>> {
+        self.runtime.block_on(self.inner.values_get(
+            spreadsheet_id,
+            range,
+            date_time_render_option,
+            major_dimension,
+            value_render_option,
+        ))
+    }
+    /// The blocking version of [`crate::spreadsheets::Spreadsheets::values_get_request`].
+    pub fn values_get_request<'a>(
+        &'a self,
+        spreadsheet_id: &'a str,
+        range: &'a str,
+    ) -> SpreadsheetsValuesGetRequest<'a> {
+        SpreadsheetsValuesGetRequest {
+            inner: self.inner.values_get_request(spreadsheet_id, range),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::spreadsheets::Spreadsheets::values_update`].
+    pub fn values_update(
+        &self,
+        spreadsheet_id: &str,
+        range: &str,
+        include_values_in_response: bool,
+        response_date_time_render_option: crate::types::DateTimeRenderOption,
+        response_value_render_option: crate::types::ValueRenderOption,
+        value_input_option: crate::types::ValueInputOption,
+        body: &crate::types::ValueRange,
+    ) -> ClientResult<crate::Response<crate::types::UpdateValuesResponse>> {
+        self.runtime.block_on(self.inner.values_update(
+            spreadsheet_id,
+            range,
+            include_values_in_response,
+            response_date_time_render_option,
+            response_value_render_option,
+            value_input_option,
+            body,
+        ))
+    }
+    /// The blocking version of [`crate::spreadsheets::Spreadsheets::values_update_request`].
+    pub fn values_update_request<'a>(
+        &'a self,
+        spreadsheet_id: &'a str,
+        range: &'a str,
+        body: &'a crate::types::ValueRange,
+    ) -> SpreadsheetsValuesUpdateRequest<'a> {
+        SpreadsheetsValuesUpdateRequest {
+            inner: self
+                .inner
+                .values_update_request(spreadsheet_id, range, body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::spreadsheets::Spreadsheets::values_append`].
+    pub fn values_append(
+        &self,
+        spreadsheet_id: &str,
+        range: &str,
+        include_values_in_response: bool,
+        insert_data_option: crate::types::InsertDataOption,
+        response_date_time_render_option: crate::types::DateTimeRenderOption,
+        response_value_render_option: crate::types::ValueRenderOption,
+        value_input_option: crate::types::ValueInputOption,
+        body: &crate::types::ValueRange,
+    ) -> ClientResult<crate::Response<crate::types::AppendValuesResponse>> {
+        self.runtime.block_on(self.inner.values_append(
+            spreadsheet_id,
+            range,
+            include_values_in_response,
+            insert_data_option,
+            response_date_time_render_option,
+            response_value_render_option,
+            value_input_option,
+            body,
+        ))
+    }
+    /// The blocking version of [`crate::spreadsheets::Spreadsheets::values_append_request`].
+    pub fn values_append_request<'a>(
+        &'a self,
+        spreadsheet_id: &'a str,
+        range: &'a str,
+        body: &'a crate::types::ValueRange,
+    ) -> SpreadsheetsValuesAppendRequest<'a> {
+        SpreadsheetsValuesAppendRequest {
+            inner: self
+                .inner
+                .values_append_request(spreadsheet_id, range, body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::spreadsheets::Spreadsheets::values_clear`].
+    pub fn values_clear(
+        &self,
+        spreadsheet_id: &str,
+        range: &str,
+        body: &crate::types::ClearValuesRequest,
+    ) -> ClientResult<crate::Response<crate::types::ClearValuesResponse>> {
+        self.runtime
+            .block_on(self.inner.values_clear(spreadsheet_id, range, body))
+    }
+    /// The blocking version of [`crate::spreadsheets::Spreadsheets::values_batch_clear`].
+    pub fn values_batch_clear(
+        &self,
+        spreadsheet_id: &str,
+        body: &crate::types::BatchClearValuesRequest,
+    ) -> ClientResult<crate::Response<crate::types::BatchClearValuesResponse>> {
+        self.runtime
+            .block_on(self.inner.values_batch_clear(spreadsheet_id, body))
+    }
+    /// The blocking version of [`crate::spreadsheets::Spreadsheets::values_batch_clear_data_filter`].
+    pub fn values_batch_clear_data_filter(
+        &self,
+        spreadsheet_id: &str,
+        body: &crate::types::BatchClearValuesByDataFilterRequest,
+    ) -> ClientResult<crate::Response<crate::types::BatchClearValuesByDataFilterResponse>> {
+        self.runtime.block_on(
+            self.inner
+                .values_batch_clear_data_filter(spreadsheet_id, body),
+        )
+    }
+    /// The blocking version of [`crate::spreadsheets::Spreadsheets::values_batch_get`].
+    pub fn values_batch_get(
+        &self,
+        spreadsheet_id: &str,
+        date_time_render_option: crate::types::DateTimeRenderOption,
+        major_dimension: crate::types::Dimension,
+        ranges: &[String],
+        value_render_option: crate::types::ValueRenderOption,
+    ) -> ClientResult<crate::Response<crate::types::BatchGetValuesResponse>> {
+        self.runtime.block_on(self.inner.values_batch_get(
+            spreadsheet_id,
+            date_time_render_option,
+            major_dimension,
+            ranges,
+            value_render_option,
+        ))
+    }
+    /// The blocking version of [`crate::spreadsheets::Spreadsheets::values_batch_get_request`].
+    pub fn values_batch_get_request<'a>(
+        &'a self,
+        spreadsheet_id: &'a str,
+    ) -> SpreadsheetsValuesBatchGetRequest<'a> {
+        SpreadsheetsValuesBatchGetRequest {
+            inner: self.inner.values_batch_get_request(spreadsheet_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::spreadsheets::Spreadsheets::values_batch_get_data_filter`].
+    pub fn values_batch_get_data_filter(
+        &self,
+        spreadsheet_id: &str,
+        body: &crate::types::BatchGetValuesByDataFilterRequest,
+    ) -> ClientResult<crate::Response<crate::types::BatchGetValuesByDataFilterResponse>> {
+        self.runtime.block_on(
+            self.inner
+                .values_batch_get_data_filter(spreadsheet_id, body),
+        )
+    }
+    /// The blocking version of [`crate::spreadsheets::Spreadsheets::values_batch_update`].
+    pub fn values_batch_update(
+        &self,
+        spreadsheet_id: &str,
+        body: &crate::types::BatchUpdateValuesRequest,
+    ) -> ClientResult<crate::Response<crate::types::BatchUpdateValuesResponse>> {
+        self.runtime
+            .block_on(self.inner.values_batch_update(spreadsheet_id, body))
+    }
+    /// The blocking version of [`crate::spreadsheets::Spreadsheets::values_batch_update_data_filter`].
+    pub fn values_batch_update_data_filter(
+        &self,
+        spreadsheet_id: &str,
+        body: &crate::types::BatchUpdateValuesByDataFilterRequest,
+    ) -> ClientResult<crate::Response<crate::types::BatchUpdateValuesByDataFilterResponse>> {
+        self.runtime.block_on(
+            self.inner
+                .values_batch_update_data_filter(spreadsheet_id, body),
+        )
+    }
+    /// The blocking version of [`crate::spreadsheets::Spreadsheets::batch_update`].
+    pub fn batch_update(
+        &self,
+        spreadsheet_id: &str,
+        body: &crate::types::BatchUpdateSpreadsheetRequest,
+    ) -> ClientResult<crate::Response<crate::types::BatchUpdateSpreadsheetResponse>> {
+        self.runtime
+            .block_on(self.inner.batch_update(spreadsheet_id, body))
+    }
+    /// The blocking version of [`crate::spreadsheets::Spreadsheets::get_data_filter`].
+    pub fn get_data_filter(
+        &self,
+        spreadsheet_id: &str,
+        body: &crate::types::GetSpreadsheetByDataFilterRequest,
+    ) -> ClientResult<crate::Response<crate::types::Spreadsheet>> {
+        self.runtime
+            .block_on(self.inner.get_data_filter(spreadsheet_id, body))
+    }
+}
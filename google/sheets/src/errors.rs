@@ -0,0 +1,139 @@
+//! The errors returned by the API.
+
+use std::fmt;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// An error returned by a Google API.
+///
+/// FROM: <https://cloud.google.com/apis/design/errors>
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default, JsonSchema)]
+pub struct ApiError {
+    /// The HTTP status code of the error.
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub code: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub message: String,
+    /// The canonical error code, such as `NOT_FOUND` or `PERMISSION_DENIED`.
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub status: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<ApiErrorDetail>,
+}
+
+/// One of the errors behind an error returned by a Google API.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default, JsonSchema)]
+pub struct ApiErrorDetail {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub domain: String,
+    /// Why the request failed, such as `notFound` or `rateLimitExceeded`.
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub reason: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub message: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub location: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "locationType"
+    )]
+    pub location_type: String,
+}
+
+impl ApiError {
+    fn from_body(body: &[u8]) -> Option<Self> {
+        #[derive(Deserialize)]
+        struct Envelope {
+            error: ApiError,
+        }
+
+        serde_json::from_slice::<Envelope>(body)
+            .ok()
+            .map(|envelope| envelope.error)
+    }
+}
+
+impl fmt::Display for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.errors.first() {
+            Some(error) if !error.reason.is_empty() => {
+                write!(f, "{} ({})", self.message, error.reason)
+            }
+            _ => write!(f, "{}", self.message),
+        }
+    }
+}
+
+impl crate::ClientError {
+    /// Turns an unsuccessful response into an error, parsing its body into an `ApiError`
+    /// when it holds one. Responses to requests that were rate limited become
+    /// `ClientError::RateLimited`. Those keep their `ApiError` as well.
+    pub(crate) fn from_response(
+        status: http::StatusCode,
+        headers: reqwest::header::HeaderMap,
+        body: &[u8],
+    ) -> Self {
+        let rate_limit = crate::RateLimitInfo::from_headers(&headers).unwrap_or_default();
+        if status == http::StatusCode::TOO_MANY_REQUESTS {
+            return crate::ClientError::RateLimited {
+                status,
+                duration: rate_limit.wait().map(|wait| wait.as_secs()),
+                info: rate_limit,
+                error: ApiError::from_body(body).map(Box::new),
+                headers,
+            };
+        }
+
+        if body.is_empty() {
+            return crate::ClientError::HttpError {
+                status,
+                headers,
+                error: "empty response".into(),
+            };
+        }
+
+        match ApiError::from_body(body) {
+            Some(error) => crate::ClientError::ApiError {
+                status,
+                headers,
+                error: Box::new(error),
+            },
+            None => crate::ClientError::HttpError {
+                status,
+                headers,
+                error: String::from_utf8_lossy(body).into(),
+            },
+        }
+    }
+}
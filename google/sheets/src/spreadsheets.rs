@@ -1,6 +1,435 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Spreadsheets::get`], set with a builder.
+pub struct SpreadsheetsGetRequest<'a> {
+    client: &'a Spreadsheets,
+    spreadsheet_id: &'a str,
+    include_grid_data: Option<bool>,
+    ranges: Option<&'a [String]>,
+}
+
+impl<'a> SpreadsheetsGetRequest<'a> {
+    /// Sets the `includeGridData` query parameter.
+    pub fn include_grid_data(mut self, include_grid_data: bool) -> Self {
+        self.include_grid_data = Some(include_grid_data);
+        self
+    }
+
+    /// Sets the `ranges` query parameter.
+    pub fn ranges(mut self, ranges: &'a [String]) -> Self {
+        self.ranges = Some(ranges);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::Spreadsheet>> {
+        let Self {
+            client,
+            spreadsheet_id,
+            include_grid_data,
+            ranges,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = include_grid_data {
+            query_args.push(("includeGridData".to_string(), v.to_string()));
+        }
+        if let Some(v) = ranges {
+            query_args.push(("ranges".to_string(), v.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v4/spreadsheets/{}?{}",
+                crate::progenitor_support::encode_path(spreadsheet_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Spreadsheets::values_get`], set with a builder.
+pub struct SpreadsheetsValuesGetRequest<'a> {
+    client: &'a Spreadsheets,
+    spreadsheet_id: &'a str,
+    range: &'a str,
+    date_time_render_option: Option<crate::types::DateTimeRenderOption>,
+    major_dimension: Option<crate::types::Dimension>,
+    value_render_option: Option<crate::types::ValueRenderOption>,
+}
+
+impl<'a> SpreadsheetsValuesGetRequest<'a> {
+    /// Sets the `dateTimeRenderOption` query parameter.
+    pub fn date_time_render_option(
+        mut self,
+        date_time_render_option: crate::types::DateTimeRenderOption,
+    ) -> Self {
+        self.date_time_render_option = Some(date_time_render_option);
+        self
+    }
+
+    /// Sets the `majorDimension` query parameter.
+    pub fn major_dimension(mut self, major_dimension: crate::types::Dimension) -> Self {
+        self.major_dimension = Some(major_dimension);
+        self
+    }
+
+    /// Sets the `valueRenderOption` query parameter.
+    pub fn value_render_option(
+        mut self,
+        value_render_option: crate::types::ValueRenderOption,
+    ) -> Self {
+        self.value_render_option = Some(value_render_option);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::ValueRange>> {
+        let Self {
+            client,
+            spreadsheet_id,
+            range,
+            date_time_render_option,
+            major_dimension,
+            value_render_option,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = date_time_render_option {
+            query_args.push(("dateTimeRenderOption".to_string(), v.to_string()));
+        }
+        if let Some(v) = major_dimension {
+            query_args.push(("majorDimension".to_string(), v.to_string()));
+        }
+        if let Some(v) = value_render_option {
+            query_args.push(("valueRenderOption".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v4/spreadsheets/{}/values/{}?{}",
+                crate::progenitor_support::encode_path(spreadsheet_id),
+                crate::progenitor_support::encode_path(range),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Spreadsheets::values_update`], set with a builder.
+pub struct SpreadsheetsValuesUpdateRequest<'a> {
+    client: &'a Spreadsheets,
+    spreadsheet_id: &'a str,
+    range: &'a str,
+    include_values_in_response: Option<bool>,
+    response_date_time_render_option: Option<crate::types::DateTimeRenderOption>,
+    response_value_render_option: Option<crate::types::ValueRenderOption>,
+    value_input_option: Option<crate::types::ValueInputOption>,
+    body: &'a crate::types::ValueRange,
+}
+
+impl<'a> SpreadsheetsValuesUpdateRequest<'a> {
+    /// Sets the `includeValuesInResponse` query parameter.
+    pub fn include_values_in_response(mut self, include_values_in_response: bool) -> Self {
+        self.include_values_in_response = Some(include_values_in_response);
+        self
+    }
+
+    /// Sets the `responseDateTimeRenderOption` query parameter.
+    pub fn response_date_time_render_option(
+        mut self,
+        response_date_time_render_option: crate::types::DateTimeRenderOption,
+    ) -> Self {
+        self.response_date_time_render_option = Some(response_date_time_render_option);
+        self
+    }
+
+    /// Sets the `responseValueRenderOption` query parameter.
+    pub fn response_value_render_option(
+        mut self,
+        response_value_render_option: crate::types::ValueRenderOption,
+    ) -> Self {
+        self.response_value_render_option = Some(response_value_render_option);
+        self
+    }
+
+    /// Sets the `valueInputOption` query parameter.
+    pub fn value_input_option(
+        mut self,
+        value_input_option: crate::types::ValueInputOption,
+    ) -> Self {
+        self.value_input_option = Some(value_input_option);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::UpdateValuesResponse>> {
+        let Self {
+            client,
+            spreadsheet_id,
+            range,
+            include_values_in_response,
+            response_date_time_render_option,
+            response_value_render_option,
+            value_input_option,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = include_values_in_response {
+            query_args.push(("includeValuesInResponse".to_string(), v.to_string()));
+        }
+        if let Some(v) = response_date_time_render_option {
+            query_args.push(("responseDateTimeRenderOption".to_string(), v.to_string()));
+        }
+        if let Some(v) = response_value_render_option {
+            query_args.push(("responseValueRenderOption".to_string(), v.to_string()));
+        }
+        if let Some(v) = value_input_option {
+            query_args.push(("valueInputOption".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v4/spreadsheets/{}/values/{}?{}",
+                crate::progenitor_support::encode_path(spreadsheet_id),
+                crate::progenitor_support::encode_path(range),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .put(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Spreadsheets::values_append`], set with a builder.
+pub struct SpreadsheetsValuesAppendRequest<'a> {
+    client: &'a Spreadsheets,
+    spreadsheet_id: &'a str,
+    range: &'a str,
+    include_values_in_response: Option<bool>,
+    insert_data_option: Option<crate::types::InsertDataOption>,
+    response_date_time_render_option: Option<crate::types::DateTimeRenderOption>,
+    response_value_render_option: Option<crate::types::ValueRenderOption>,
+    value_input_option: Option<crate::types::ValueInputOption>,
+    body: &'a crate::types::ValueRange,
+}
+
+impl<'a> SpreadsheetsValuesAppendRequest<'a> {
+    /// Sets the `includeValuesInResponse` query parameter.
+    pub fn include_values_in_response(mut self, include_values_in_response: bool) -> Self {
+        self.include_values_in_response = Some(include_values_in_response);
+        self
+    }
+
+    /// Sets the `insertDataOption` query parameter.
+    pub fn insert_data_option(
+        mut self,
+        insert_data_option: crate::types::InsertDataOption,
+    ) -> Self {
+        self.insert_data_option = Some(insert_data_option);
+        self
+    }
+
+    /// Sets the `responseDateTimeRenderOption` query parameter.
+    pub fn response_date_time_render_option(
+        mut self,
+        response_date_time_render_option: crate::types::DateTimeRenderOption,
+    ) -> Self {
+        self.response_date_time_render_option = Some(response_date_time_render_option);
+        self
+    }
+
+    /// Sets the `responseValueRenderOption` query parameter.
+    pub fn response_value_render_option(
+        mut self,
+        response_value_render_option: crate::types::ValueRenderOption,
+    ) -> Self {
+        self.response_value_render_option = Some(response_value_render_option);
+        self
+    }
+
+    /// Sets the `valueInputOption` query parameter.
+    pub fn value_input_option(
+        mut self,
+        value_input_option: crate::types::ValueInputOption,
+    ) -> Self {
+        self.value_input_option = Some(value_input_option);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::AppendValuesResponse>> {
+        let Self {
+            client,
+            spreadsheet_id,
+            range,
+            include_values_in_response,
+            insert_data_option,
+            response_date_time_render_option,
+            response_value_render_option,
+            value_input_option,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = include_values_in_response {
+            query_args.push(("includeValuesInResponse".to_string(), v.to_string()));
+        }
+        if let Some(v) = insert_data_option {
+            query_args.push(("insertDataOption".to_string(), v.to_string()));
+        }
+        if let Some(v) = response_date_time_render_option {
+            query_args.push(("responseDateTimeRenderOption".to_string(), v.to_string()));
+        }
+        if let Some(v) = response_value_render_option {
+            query_args.push(("responseValueRenderOption".to_string(), v.to_string()));
+        }
+        if let Some(v) = value_input_option {
+            query_args.push(("valueInputOption".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v4/spreadsheets/{}/values/{}:append?{}",
+                crate::progenitor_support::encode_path(spreadsheet_id),
+                crate::progenitor_support::encode_path(range),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Spreadsheets::values_batch_get`], set with a builder.
+pub struct SpreadsheetsValuesBatchGetRequest<'a> {
+    client: &'a Spreadsheets,
+    spreadsheet_id: &'a str,
+    date_time_render_option: Option<crate::types::DateTimeRenderOption>,
+    major_dimension: Option<crate::types::Dimension>,
+    ranges: Option<&'a [String]>,
+    value_render_option: Option<crate::types::ValueRenderOption>,
+}
+
+impl<'a> SpreadsheetsValuesBatchGetRequest<'a> {
+    /// Sets the `dateTimeRenderOption` query parameter.
+    pub fn date_time_render_option(
+        mut self,
+        date_time_render_option: crate::types::DateTimeRenderOption,
+    ) -> Self {
+        self.date_time_render_option = Some(date_time_render_option);
+        self
+    }
+
+    /// Sets the `majorDimension` query parameter.
+    pub fn major_dimension(mut self, major_dimension: crate::types::Dimension) -> Self {
+        self.major_dimension = Some(major_dimension);
+        self
+    }
+
+    /// Sets the `ranges` query parameter.
+    pub fn ranges(mut self, ranges: &'a [String]) -> Self {
+        self.ranges = Some(ranges);
+        self
+    }
+
+    /// Sets the `valueRenderOption` query parameter.
+    pub fn value_render_option(
+        mut self,
+        value_render_option: crate::types::ValueRenderOption,
+    ) -> Self {
+        self.value_render_option = Some(value_render_option);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::BatchGetValuesResponse>> {
+        let Self {
+            client,
+            spreadsheet_id,
+            date_time_render_option,
+            major_dimension,
+            ranges,
+            value_render_option,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = date_time_render_option {
+            query_args.push(("dateTimeRenderOption".to_string(), v.to_string()));
+        }
+        if let Some(v) = major_dimension {
+            query_args.push(("majorDimension".to_string(), v.to_string()));
+        }
+        if let Some(v) = ranges {
+            query_args.push(("ranges".to_string(), v.join(" ")));
+        }
+        if let Some(v) = value_render_option {
+            query_args.push(("valueRenderOption".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v4/spreadsheets/{}/values:batchGet?{}",
+                crate::progenitor_support::encode_path(spreadsheet_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct Spreadsheets {
     pub client: Client,
 }
@@ -74,6 +503,21 @@ impl Spreadsheets {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/v4/spreadsheets/{spreadsheetId}` endpoint.
+     *
+     * As opposed to `get`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Returns the spreadsheet at the given ID. The caller must specify the spreadsheet ID. By default, data within grids will not be returned. You can include grid data one of two ways: * Specify a field mask listing your desired fields using the `fields` URL parameter in HTTP * Set the includeGridData URL parameter to true. If a field mask is set, the `includeGridData` parameter is ignored For large spreadsheets, it is recommended to retrieve only the specific fields of the spreadsheet that you want. To retrieve only subsets of the spreadsheet, use the ranges URL parameter. Multiple ranges can be specified. Limiting the range will return only the portions of the spreadsheet that intersect the requested ranges. Ranges are specified using A1 notation.
+     */
+    pub fn get_request<'a>(&'a self, spreadsheet_id: &'a str) -> SpreadsheetsGetRequest<'a> {
+        SpreadsheetsGetRequest {
+            client: self,
+            spreadsheet_id,
+            include_grid_data: None,
+            ranges: None,
+        }
+    }
     /**
      * This function performs a `GET` to the `/v4/spreadsheets/{spreadsheetId}/developerMetadata/{metadataId}` endpoint.
      *
@@ -229,6 +673,27 @@ impl Spreadsheets {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/v4/spreadsheets/{spreadsheetId}/values/{range}` endpoint.
+     *
+     * As opposed to `values_get`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Returns a range of values from a spreadsheet. The caller must specify the spreadsheet ID and a range.
+     */
+    pub fn values_get_request<'a>(
+        &'a self,
+        spreadsheet_id: &'a str,
+        range: &'a str,
+    ) -> SpreadsheetsValuesGetRequest<'a> {
+        SpreadsheetsValuesGetRequest {
+            client: self,
+            spreadsheet_id,
+            range,
+            date_time_render_option: None,
+            major_dimension: None,
+            value_render_option: None,
+        }
+    }
     /**
      * This function performs a `PUT` to the `/v4/spreadsheets/{spreadsheetId}/values/{range}` endpoint.
      *
@@ -298,6 +763,30 @@ impl Spreadsheets {
             )
             .await
     }
+    /**
+     * This function performs a `PUT` to the `/v4/spreadsheets/{spreadsheetId}/values/{range}` endpoint.
+     *
+     * As opposed to `values_update`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Sets values in a range of a spreadsheet. The caller must specify the spreadsheet ID, range, and a valueInputOption.
+     */
+    pub fn values_update_request<'a>(
+        &'a self,
+        spreadsheet_id: &'a str,
+        range: &'a str,
+        body: &'a crate::types::ValueRange,
+    ) -> SpreadsheetsValuesUpdateRequest<'a> {
+        SpreadsheetsValuesUpdateRequest {
+            client: self,
+            spreadsheet_id,
+            range,
+            include_values_in_response: None,
+            response_date_time_render_option: None,
+            response_value_render_option: None,
+            value_input_option: None,
+            body,
+        }
+    }
     /**
      * This function performs a `POST` to the `/v4/spreadsheets/{spreadsheetId}/values/{range}:append` endpoint.
      *
@@ -375,6 +864,31 @@ impl Spreadsheets {
             )
             .await
     }
+    /**
+     * This function performs a `POST` to the `/v4/spreadsheets/{spreadsheetId}/values/{range}:append` endpoint.
+     *
+     * As opposed to `values_append`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Appends values to a spreadsheet. The input range is used to search for existing data and find a "table" within that range. Values will be appended to the next row of the table, starting with the first column of the table. See the [guide](/sheets/api/guides/values#appending_values) and [sample code](/sheets/api/samples/writing#append_values) for specific details of how tables are detected and data is appended. The caller must specify the spreadsheet ID, range, and a valueInputOption. The `valueInputOption` only controls how the input data will be added to the sheet (column-wise or row-wise), it does not influence what cell the data starts being written to.
+     */
+    pub fn values_append_request<'a>(
+        &'a self,
+        spreadsheet_id: &'a str,
+        range: &'a str,
+        body: &'a crate::types::ValueRange,
+    ) -> SpreadsheetsValuesAppendRequest<'a> {
+        SpreadsheetsValuesAppendRequest {
+            client: self,
+            spreadsheet_id,
+            range,
+            include_values_in_response: None,
+            insert_data_option: None,
+            response_date_time_render_option: None,
+            response_value_render_option: None,
+            value_input_option: None,
+            body,
+        }
+    }
     /**
      * This function performs a `POST` to the `/v4/spreadsheets/{spreadsheetId}/values/{range}:clear` endpoint.
      *
@@ -530,6 +1044,26 @@ impl Spreadsheets {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/v4/spreadsheets/{spreadsheetId}/values:batchGet` endpoint.
+     *
+     * As opposed to `values_batch_get`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Returns one or more ranges of values from a spreadsheet. The caller must specify the spreadsheet ID and one or more ranges.
+     */
+    pub fn values_batch_get_request<'a>(
+        &'a self,
+        spreadsheet_id: &'a str,
+    ) -> SpreadsheetsValuesBatchGetRequest<'a> {
+        SpreadsheetsValuesBatchGetRequest {
+            client: self,
+            spreadsheet_id,
+            date_time_render_option: None,
+            major_dimension: None,
+            ranges: None,
+            value_render_option: None,
+        }
+    }
     /**
      * This function performs a `POST` to the `/v4/spreadsheets/{spreadsheetId}/values:batchGetByDataFilter` endpoint.
      *
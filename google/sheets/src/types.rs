@@ -14,6 +14,10 @@ pub struct AddBandingRequest {
         rename = "bandedRange"
     )]
     pub banded_range: Option<BandedRange>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The result of adding a banded range.
@@ -28,6 +32,10 @@ pub struct AddBandingResponse {
         rename = "bandedRange"
     )]
     pub banded_range: Option<BandedRange>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Adds a chart to a sheet in the spreadsheet.
@@ -38,6 +46,10 @@ pub struct AddChartRequest {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub chart: Option<EmbeddedChart>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The result of adding a chart to a spreadsheet.
@@ -48,6 +60,10 @@ pub struct AddChartResponse {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub chart: Option<EmbeddedChart>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Adds a new conditional format rule at the given index. All subsequent rules' indexes are incremented.
@@ -67,6 +83,10 @@ pub struct AddConditionalFormatRuleRequest {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub rule: Option<ConditionalFormatRule>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Adds a data source. After the data source is added successfully, an associated DATA_SOURCE sheet is created and an execution is triggered to refresh the sheet to read data from the data source. The request requires an additional `bigquery.readonly` OAuth scope.
@@ -81,6 +101,10 @@ pub struct AddDataSourceRequest {
         rename = "dataSource"
     )]
     pub data_source: Option<DataSource>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The result of adding a data source.
@@ -104,6 +128,10 @@ pub struct AddDataSourceResponse {
         rename = "dataSource"
     )]
     pub data_source: Option<DataSource>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Creates a group over the specified range. If the requested range is a superset of the range of an existing group G, then the depth of G is incremented and this new group G' has the depth of that group. For example, a group [C:D, depth 1] + [B:E] results in groups [B:E, depth 1] and [C:D, depth 2]. If the requested range is a subset of the range of an existing group G, then the depth of the new group G' becomes one greater than the depth of G. For example, a group [B:E, depth 1] + [C:D] results in groups [B:E, depth 1] and [C:D, depth 2]. If the requested range starts before and ends within, or starts within and ends after, the range of an existing group G, then the range of the existing group G becomes the union of the ranges, and the new group G' has depth one greater than the depth of G and range as the intersection of the ranges. For example, a group [B:D, depth 1] + [C:E] results in groups [B:E, depth 1] and [C:D, depth 2].
@@ -114,6 +142,10 @@ pub struct AddDimensionGroupRequest {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub range: Option<DimensionRange>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The result of adding a group.
@@ -129,6 +161,10 @@ pub struct AddDimensionGroupResponse {
         rename = "dimensionGroups"
     )]
     pub dimension_groups: Vec<DimensionGroup>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Adds a filter view.
@@ -139,6 +175,10 @@ pub struct AddFilterViewRequest {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub filter: Option<FilterView>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The result of adding a filter view.
@@ -149,6 +189,10 @@ pub struct AddFilterViewResponse {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub filter: Option<FilterView>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Adds a named range to the spreadsheet.
@@ -163,6 +207,10 @@ pub struct AddNamedRangeRequest {
         rename = "namedRange"
     )]
     pub named_range: Option<NamedRange>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The result of adding a named range.
@@ -177,6 +225,10 @@ pub struct AddNamedRangeResponse {
         rename = "namedRange"
     )]
     pub named_range: Option<NamedRange>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Adds a new protected range.
@@ -191,6 +243,10 @@ pub struct AddProtectedRangeRequest {
         rename = "protectedRange"
     )]
     pub protected_range: Option<ProtectedRange>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The result of adding a new protected range.
@@ -205,6 +261,10 @@ pub struct AddProtectedRangeResponse {
         rename = "protectedRange"
     )]
     pub protected_range: Option<ProtectedRange>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Adds a new sheet. When a sheet is added at a given index, all subsequent sheets' indexes are incremented. To add an object sheet, use AddChartRequest instead and specify EmbeddedObjectPosition.sheetId or EmbeddedObjectPosition.newSheet.
@@ -215,6 +275,10 @@ pub struct AddSheetRequest {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub properties: Option<SheetProperties>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The result of adding a sheet.
@@ -225,6 +289,10 @@ pub struct AddSheetResponse {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub properties: Option<SheetProperties>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Adds a slicer to a sheet in the spreadsheet.
@@ -235,6 +303,10 @@ pub struct AddSlicerRequest {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub slicer: Option<Slicer>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The result of adding a slicer to a spreadsheet.
@@ -245,6 +317,10 @@ pub struct AddSlicerResponse {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub slicer: Option<Slicer>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Adds new cells after the last row with data in a sheet, inserting new rows into the sheet if necessary.
@@ -278,6 +354,10 @@ pub struct AppendCellsRequest {
         rename = "sheetId"
     )]
     pub sheet_id: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -294,8 +374,9 @@ pub enum Dimension {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Dimension {
@@ -305,7 +386,7 @@ impl std::fmt::Display for Dimension {
             Dimension::DimensionUnspecified => "DIMENSION_UNSPECIFIED",
             Dimension::Rows => "ROWS",
             Dimension::Noop => "",
-            Dimension::FallthroughString => "*",
+            Dimension::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -344,6 +425,10 @@ pub struct AppendDimensionRequest {
         rename = "sheetId"
     )]
     pub sheet_id: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The response when updating a range of values in a spreadsheet.
@@ -374,6 +459,10 @@ pub struct AppendValuesResponse {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub updates: Option<UpdateValuesResponse>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Fills in more data based on existing data.
@@ -402,6 +491,10 @@ pub struct AutoFillRequest {
         rename = "useAlternateSeries"
     )]
     pub use_alternate_series: Option<bool>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Automatically resizes one or more dimensions based on the contents of the cells in that dimension.
@@ -421,6 +514,10 @@ pub struct AutoResizeDimensionsRequest {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub dimensions: Option<DimensionRange>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A banded (alternating colors) range in a sheet.
@@ -459,6 +556,10 @@ pub struct BandedRange {
         rename = "rowProperties"
     )]
     pub row_properties: Option<BandingProperties>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Properties referring a single dimension (either row or column). If both BandedRange.row_properties and BandedRange.column_properties are set, the fill colors are applied to cells according to the following rules: * header_color and footer_color take priority over band colors. * first_band_color takes priority over second_band_color. * row_properties takes priority over column_properties. For example, the first row color takes priority over the first column color, but the first column color takes priority over the second row color. Similarly, the row header takes priority over the column header in the top left cell, but the column header takes priority over the first row color if the row header is not set.
@@ -536,6 +637,10 @@ pub struct BandingProperties {
         rename = "secondBandColorStyle"
     )]
     pub second_band_color_style: Option<ColorStyle>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -552,8 +657,9 @@ pub enum ComparisonType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ComparisonType {
@@ -563,7 +669,7 @@ impl std::fmt::Display for ComparisonType {
             ComparisonType::ComparisonTypeUndefined => "COMPARISON_TYPE_UNDEFINED",
             ComparisonType::PercentageDifference => "PERCENTAGE_DIFFERENCE",
             ComparisonType::Noop => "",
-            ComparisonType::FallthroughString => "*",
+            ComparisonType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -646,6 +752,10 @@ pub struct BaselineValueFormat {
         rename = "textFormat"
     )]
     pub text_format: Option<TextFormat>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -664,8 +774,9 @@ pub enum Position {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Position {
@@ -676,7 +787,7 @@ impl std::fmt::Display for Position {
             Position::LeftAxis => "LEFT_AXIS",
             Position::RightAxis => "RIGHT_AXIS",
             Position::Noop => "",
-            Position::FallthroughString => "*",
+            Position::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -728,6 +839,10 @@ pub struct BasicChartAxis {
         rename = "viewWindowOptions"
     )]
     pub view_window_options: Option<ChartAxisViewWindowOptions>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The domain of a chart. For example, if charting stock prices over time, this would be the date.
@@ -746,6 +861,10 @@ pub struct BasicChartDomain {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub reversed: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -772,8 +891,9 @@ pub enum Type {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Type {
@@ -788,7 +908,7 @@ impl std::fmt::Display for Type {
             Type::Scatter => "SCATTER",
             Type::SteppedArea => "STEPPED_AREA",
             Type::Noop => "",
-            Type::FallthroughString => "*",
+            Type::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -865,6 +985,10 @@ pub struct BasicChartSeries {
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
     pub type_: Option<Type>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -881,8 +1005,9 @@ pub enum CompareMode {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for CompareMode {
@@ -892,7 +1017,7 @@ impl std::fmt::Display for CompareMode {
             CompareMode::Category => "CATEGORY",
             CompareMode::Datum => "DATUM",
             CompareMode::Noop => "",
-            CompareMode::FallthroughString => "*",
+            CompareMode::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -924,8 +1049,9 @@ pub enum LegendPosition {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for LegendPosition {
@@ -940,7 +1066,7 @@ impl std::fmt::Display for LegendPosition {
             LegendPosition::RightLegend => "RIGHT_LEGEND",
             LegendPosition::TopLegend => "TOP_LEGEND",
             LegendPosition::Noop => "",
-            LegendPosition::FallthroughString => "*",
+            LegendPosition::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -968,8 +1094,9 @@ pub enum StackedType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for StackedType {
@@ -980,7 +1107,7 @@ impl std::fmt::Display for StackedType {
             StackedType::PercentStacked => "PERCENT_STACKED",
             StackedType::Stacked => "STACKED",
             StackedType::Noop => "",
-            StackedType::FallthroughString => "*",
+            StackedType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1100,6 +1227,10 @@ pub struct BasicChartSpec {
         rename = "totalDataLabel"
     )]
     pub total_data_label: Option<DataLabel>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The default filter associated with a sheet.
@@ -1135,6 +1266,10 @@ pub struct BasicFilter {
         rename = "sortSpecs"
     )]
     pub sort_specs: Vec<SortSpec>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Style override settings for a single series data point.
@@ -1172,6 +1307,10 @@ pub struct BasicSeriesDataPointStyleOverride {
         rename = "pointStyle"
     )]
     pub point_style: Option<PointStyle>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The request for clearing more than one range selected by a DataFilter in a spreadsheet.
@@ -1187,6 +1326,10 @@ pub struct BatchClearValuesByDataFilterRequest {
         rename = "dataFilters"
     )]
     pub data_filters: Vec<DataFilter>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The response when clearing a range of values selected with DataFilters in a spreadsheet.
@@ -1212,6 +1355,10 @@ pub struct BatchClearValuesByDataFilterResponse {
         rename = "spreadsheetId"
     )]
     pub spreadsheet_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The request for clearing more than one range of values in a spreadsheet.
@@ -1226,6 +1373,10 @@ pub struct BatchClearValuesRequest {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub ranges: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The response when clearing a range of values in a spreadsheet.
@@ -1251,6 +1402,10 @@ pub struct BatchClearValuesResponse {
         rename = "spreadsheetId"
     )]
     pub spreadsheet_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -1265,8 +1420,9 @@ pub enum DateTimeRenderOption {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DateTimeRenderOption {
@@ -1275,7 +1431,7 @@ impl std::fmt::Display for DateTimeRenderOption {
             DateTimeRenderOption::FormattedString => "FORMATTED_STRING",
             DateTimeRenderOption::SerialNumber => "SERIAL_NUMBER",
             DateTimeRenderOption::Noop => "",
-            DateTimeRenderOption::FallthroughString => "*",
+            DateTimeRenderOption::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1301,8 +1457,9 @@ pub enum ValueRenderOption {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ValueRenderOption {
@@ -1312,7 +1469,7 @@ impl std::fmt::Display for ValueRenderOption {
             ValueRenderOption::Formula => "FORMULA",
             ValueRenderOption::UnformattedValue => "UNFORMATTED_VALUE",
             ValueRenderOption::Noop => "",
-            ValueRenderOption::FallthroughString => "*",
+            ValueRenderOption::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1364,6 +1521,10 @@ pub struct BatchGetValuesByDataFilterRequest {
         rename = "valueRenderOption"
     )]
     pub value_render_option: Option<ValueRenderOption>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The response when retrieving more than one range of values in a spreadsheet selected by DataFilters.
@@ -1389,6 +1550,10 @@ pub struct BatchGetValuesByDataFilterResponse {
         rename = "valueRanges"
     )]
     pub value_ranges: Vec<MatchedValueRange>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The response when retrieving more than one range of values in a spreadsheet.
@@ -1414,6 +1579,10 @@ pub struct BatchGetValuesResponse {
         rename = "valueRanges"
     )]
     pub value_ranges: Vec<ValueRange>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The request for updating any aspect of a spreadsheet.
@@ -1456,6 +1625,10 @@ pub struct BatchUpdateSpreadsheetRequest {
         rename = "responseRanges"
     )]
     pub response_ranges: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The reply for batch updating a spreadsheet.
@@ -1489,6 +1662,10 @@ pub struct BatchUpdateSpreadsheetResponse {
         rename = "updatedSpreadsheet"
     )]
     pub updated_spreadsheet: Option<Spreadsheet>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -1505,8 +1682,9 @@ pub enum ValueInputOption {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ValueInputOption {
@@ -1516,7 +1694,7 @@ impl std::fmt::Display for ValueInputOption {
             ValueInputOption::Raw => "RAW",
             ValueInputOption::UserEntered => "USER_ENTERED",
             ValueInputOption::Noop => "",
-            ValueInputOption::FallthroughString => "*",
+            ValueInputOption::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1576,6 +1754,10 @@ pub struct BatchUpdateValuesByDataFilterRequest {
         rename = "valueInputOption"
     )]
     pub value_input_option: Option<ValueInputOption>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The response when updating a range of values in a spreadsheet.
@@ -1640,6 +1822,10 @@ pub struct BatchUpdateValuesByDataFilterResponse {
         rename = "totalUpdatedSheets"
     )]
     pub total_updated_sheets: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The request for updating more than one range of values in a spreadsheet.
@@ -1690,6 +1876,10 @@ pub struct BatchUpdateValuesRequest {
         rename = "valueInputOption"
     )]
     pub value_input_option: Option<ValueInputOption>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The response when updating a range of values in a spreadsheet.
@@ -1754,6 +1944,10 @@ pub struct BatchUpdateValuesResponse {
         rename = "totalUpdatedSheets"
     )]
     pub total_updated_sheets: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The specification of a BigQuery data source that's connected to a sheet.
@@ -1779,6 +1973,10 @@ pub struct BigQueryDataSourceSpec {
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "tableSpec")]
     pub table_spec: Option<BigQueryTableSpec>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Specifies a custom BigQuery query.
@@ -1794,6 +1992,10 @@ pub struct BigQuerySpec {
         rename = "rawQuery"
     )]
     pub raw_query: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Specifies a BigQuery table definition. Only [native tables](https://cloud.google.com/bigquery/docs/tables-intro) is allowed.
@@ -1829,6 +2031,10 @@ pub struct BigQueryTableSpec {
         rename = "tableProjectId"
     )]
     pub table_project_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -1903,8 +2109,9 @@ pub enum BooleanConditionType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for BooleanConditionType {
@@ -1943,7 +2150,7 @@ impl std::fmt::Display for BooleanConditionType {
             BooleanConditionType::TextNotEq => "TEXT_NOT_EQ",
             BooleanConditionType::TextStartsWith => "TEXT_STARTS_WITH",
             BooleanConditionType::Noop => "",
-            BooleanConditionType::FallthroughString => "*",
+            BooleanConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1972,6 +2179,10 @@ pub struct BooleanCondition {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub values: Vec<ConditionValue>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A rule that may or may not match, depending on the condition.
@@ -1987,6 +2198,10 @@ pub struct BooleanRule {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub format: Option<CellFormat>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -2013,8 +2228,9 @@ pub enum Style {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Style {
@@ -2029,7 +2245,7 @@ impl std::fmt::Display for Style {
             Style::SolidThick => "SOLID_THICK",
             Style::StyleUnspecified => "STYLE_UNSPECIFIED",
             Style::Noop => "",
-            Style::FallthroughString => "*",
+            Style::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2072,6 +2288,10 @@ pub struct Border {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub width: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The borders of the cell.
@@ -2097,6 +2317,10 @@ pub struct Borders {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub top: Option<Border>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -2121,8 +2345,9 @@ pub enum BubbleChartSpecLegendPosition {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for BubbleChartSpecLegendPosition {
@@ -2138,7 +2363,7 @@ impl std::fmt::Display for BubbleChartSpecLegendPosition {
             BubbleChartSpecLegendPosition::RightLegend => "RIGHT_LEGEND",
             BubbleChartSpecLegendPosition::TopLegend => "TOP_LEGEND",
             BubbleChartSpecLegendPosition::Noop => "",
-            BubbleChartSpecLegendPosition::FallthroughString => "*",
+            BubbleChartSpecLegendPosition::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2252,6 +2477,10 @@ pub struct BubbleChartSpec {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub series: Option<ChartData>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A candlestick chart.
@@ -2271,6 +2500,10 @@ pub struct CandlestickChartSpec {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub domain: Option<CandlestickDomain>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The Candlestick chart data, each containing the low, open, close, and high values for a series.
@@ -2308,6 +2541,10 @@ pub struct CandlestickData {
         rename = "openSeries"
     )]
     pub open_series: Option<CandlestickSeries>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The domain of a CandlestickChart.
@@ -2326,6 +2563,10 @@ pub struct CandlestickDomain {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub reversed: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The series of a CandlestickData.
@@ -2336,6 +2577,10 @@ pub struct CandlestickSeries {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub data: Option<ChartData>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Data about a specific cell.
@@ -2451,6 +2696,10 @@ pub struct CellData {
         rename = "userEnteredValue"
     )]
     pub user_entered_value: Option<ExtendedValue>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -2469,8 +2718,9 @@ pub enum HorizontalAlignment {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for HorizontalAlignment {
@@ -2481,7 +2731,7 @@ impl std::fmt::Display for HorizontalAlignment {
             HorizontalAlignment::Left => "LEFT",
             HorizontalAlignment::Right => "RIGHT",
             HorizontalAlignment::Noop => "",
-            HorizontalAlignment::FallthroughString => "*",
+            HorizontalAlignment::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2507,8 +2757,9 @@ pub enum HyperlinkDisplayType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for HyperlinkDisplayType {
@@ -2520,7 +2771,7 @@ impl std::fmt::Display for HyperlinkDisplayType {
             HyperlinkDisplayType::Linked => "LINKED",
             HyperlinkDisplayType::PlainText => "PLAIN_TEXT",
             HyperlinkDisplayType::Noop => "",
-            HyperlinkDisplayType::FallthroughString => "*",
+            HyperlinkDisplayType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2546,8 +2797,9 @@ pub enum TextDirection {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for TextDirection {
@@ -2557,7 +2809,7 @@ impl std::fmt::Display for TextDirection {
             TextDirection::RightToLeft => "RIGHT_TO_LEFT",
             TextDirection::TextDirectionUnspecified => "TEXT_DIRECTION_UNSPECIFIED",
             TextDirection::Noop => "",
-            TextDirection::FallthroughString => "*",
+            TextDirection::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2585,8 +2837,9 @@ pub enum VerticalAlignment {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for VerticalAlignment {
@@ -2597,7 +2850,7 @@ impl std::fmt::Display for VerticalAlignment {
             VerticalAlignment::Top => "TOP",
             VerticalAlignment::VerticalAlignUnspecified => "VERTICAL_ALIGN_UNSPECIFIED",
             VerticalAlignment::Noop => "",
-            VerticalAlignment::FallthroughString => "*",
+            VerticalAlignment::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2627,8 +2880,9 @@ pub enum WrapStrategy {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for WrapStrategy {
@@ -2640,7 +2894,7 @@ impl std::fmt::Display for WrapStrategy {
             WrapStrategy::Wrap => "WRAP",
             WrapStrategy::WrapStrategyUnspecified => "WRAP_STRATEGY_UNSPECIFIED",
             WrapStrategy::Noop => "",
-            WrapStrategy::FallthroughString => "*",
+            WrapStrategy::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2755,6 +3009,10 @@ pub struct CellFormat {
         rename = "wrapStrategy"
     )]
     pub wrap_strategy: Option<WrapStrategy>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -2773,8 +3031,9 @@ pub enum ViewWindowMode {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ViewWindowMode {
@@ -2785,7 +3044,7 @@ impl std::fmt::Display for ViewWindowMode {
             ViewWindowMode::Pretty => "PRETTY",
             ViewWindowMode::ViewWindowModeUnsupported => "VIEW_WINDOW_MODE_UNSUPPORTED",
             ViewWindowMode::Noop => "",
-            ViewWindowMode::FallthroughString => "*",
+            ViewWindowMode::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2829,6 +3088,10 @@ pub struct ChartAxisViewWindowOptions {
         rename = "viewWindowMode"
     )]
     pub view_window_mode: Option<ViewWindowMode>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Custom number formatting options for chart attributes.
@@ -2852,6 +3115,10 @@ pub struct ChartCustomNumberFormatOptions {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub suffix: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -2876,8 +3143,9 @@ pub enum AggregateType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AggregateType {
@@ -2891,7 +3159,7 @@ impl std::fmt::Display for AggregateType {
             AggregateType::Min => "MIN",
             AggregateType::Sum => "SUM",
             AggregateType::Noop => "",
-            AggregateType::FallthroughString => "*",
+            AggregateType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2938,6 +3206,10 @@ pub struct ChartData {
         rename = "sourceRange"
     )]
     pub source_range: Option<ChartSourceRange>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -2980,8 +3252,9 @@ pub enum ChartDateTimeRuleType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ChartDateTimeRuleType {
@@ -3006,7 +3279,7 @@ impl std::fmt::Display for ChartDateTimeRuleType {
             ChartDateTimeRuleType::YearMonthDay => "YEAR_MONTH_DAY",
             ChartDateTimeRuleType::YearQuarter => "YEAR_QUARTER",
             ChartDateTimeRuleType::Noop => "",
-            ChartDateTimeRuleType::FallthroughString => "*",
+            ChartDateTimeRuleType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3026,6 +3299,10 @@ pub struct ChartDateTimeRule {
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
     pub type_: Option<ChartDateTimeRuleType>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// An optional setting on the ChartData of the domain of a data source chart that defines buckets for the values in the domain rather than breaking out each individual value. For example, when plotting a data source chart, you can specify a histogram rule on the domain (it should only contain numeric values), grouping its values into buckets. Any values of a chart series that fall into the same bucket are aggregated based on the aggregate_type.
@@ -3049,6 +3326,10 @@ pub struct ChartGroupRule {
         rename = "histogramRule"
     )]
     pub histogram_rule: Option<ChartHistogramRule>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Allows you to organize numeric values in a source data column into buckets of constant size.
@@ -3084,6 +3365,10 @@ pub struct ChartHistogramRule {
         rename = "minValue"
     )]
     pub min_value: f64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Source ranges for a chart.
@@ -3098,6 +3383,10 @@ pub struct ChartSourceRange {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub sources: Vec<GridRange>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -3118,8 +3407,9 @@ pub enum HiddenDimensionStrategy {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for HiddenDimensionStrategy {
@@ -3133,7 +3423,7 @@ impl std::fmt::Display for HiddenDimensionStrategy {
             HiddenDimensionStrategy::SkipHiddenRows => "SKIP_HIDDEN_ROWS",
             HiddenDimensionStrategy::SkipHiddenRowsAndColumns => "SKIP_HIDDEN_ROWS_AND_COLUMNS",
             HiddenDimensionStrategy::Noop => "",
-            HiddenDimensionStrategy::FallthroughString => "*",
+            HiddenDimensionStrategy::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3359,6 +3649,10 @@ pub struct ChartSpec {
         rename = "waterfallChart"
     )]
     pub waterfall_chart: Option<WaterfallChartSpec>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Clears the basic filter, if any exists on the sheet.
@@ -3374,11 +3668,20 @@ pub struct ClearBasicFilterRequest {
         rename = "sheetId"
     )]
     pub sheet_id: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The request for clearing a range of values in a spreadsheet.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ClearValuesRequest {}
+pub struct ClearValuesRequest {
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
 
 /// The response when clearing a range of values in a spreadsheet.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -3403,6 +3706,10 @@ pub struct ClearValuesResponse {
         rename = "spreadsheetId"
     )]
     pub spreadsheet_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Represents a color in the RGBA color space. This representation is designed for simplicity of conversion to/from color representations in various languages over compactness. For example, the fields of this representation can be trivially provided to the constructor of `java.awt.Color` in Java; it can also be trivially provided to UIColor's `+colorWithRed:green:blue:alpha` method in iOS; and, with just a little work, it can be easily formatted into a CSS `rgba()` string in JavaScript. This reference page doesn't carry information about the absolute color space that should be used to interpret the RGB value (e.g. sRGB, Adobe RGB, DCI-P3, BT.2020, etc.). By default, applications should assume the sRGB color space. When color equality needs to be decided, implementations, unless documented otherwise, treat two colors as equal if all their red, green, blue, and alpha values each differ by at most 1e-5. Example (Java): import com.google.type.Color; // ... public static java.awt.Color fromProto(Color protocolor) { float alpha = protocolor.hasAlpha() ? protocolor.getAlpha().getValue() : 1.0; return new java.awt.Color( protocolor.getRed(), protocolor.getGreen(), protocolor.getBlue(), alpha); } public static Color toProto(java.awt.Color color) { float red = (float) color.getRed(); float green = (float) color.getGreen(); float blue = (float) color.getBlue(); float denominator = 255.0; Color.Builder resultBuilder = Color .newBuilder() .setRed(red / denominator) .setGreen(green / denominator) .setBlue(blue / denominator); int alpha = color.getAlpha(); if (alpha != 255) { result.setAlpha( FloatValue .newBuilder() .setValue(((float) alpha) / denominator) .build()); } return resultBuilder.build(); } // ... Example (iOS / Obj-C): // ... static UIColor* fromProto(Color* protocolor) { float red = [protocolor red]; float green = [protocolor green]; float blue = [protocolor blue]; FloatValue* alpha_wrapper = [protocolor alpha]; float alpha = 1.0; if (alpha_wrapper != nil) { alpha = [alpha_wrapper value]; } return [UIColor colorWithRed:red green:green blue:blue alpha:alpha]; } static Color* toProto(UIColor* color) { CGFloat red, green, blue, alpha; if (![color getRed:&red green:&green blue:&blue alpha:&alpha]) { return nil; } Color* result = [[Color alloc] init]; [result setRed:red]; [result setGreen:green]; [result setBlue:blue]; if (alpha <= 0.9999) { [result setAlpha:floatWrapperWithValue(alpha)]; } [result autorelease]; return result; } // ... Example (JavaScript): // ... var protoToCssColor = function(rgb_color) { var redFrac = rgb_color.red || 0.0; var greenFrac = rgb_color.green || 0.0; var blueFrac = rgb_color.blue || 0.0; var red = Math.floor(redFrac * 255); var green = Math.floor(greenFrac * 255); var blue = Math.floor(blueFrac * 255); if (!('alpha' in rgb_color)) { return rgbToCssColor(red, green, blue); } var alphaFrac = rgb_color.alpha.value || 0.0; var rgbParams = [red, green, blue].join(','); return ['rgba(', rgbParams, ',', alphaFrac, ')'].join(''); }; var rgbToCssColor = function(red, green, blue) { var rgbNumber = new Number((red << 16) | (green << 8) | blue); var hexString = rgbNumber.toString(16); var missingZeros = 6 - hexString.length; var resultBuilder = ['#']; for (var i = 0; i < missingZeros; i++) { resultBuilder.push('0'); } resultBuilder.push(hexString); return resultBuilder.join(''); }; // ...
@@ -3444,6 +3751,10 @@ pub struct Color {
         deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub red: f64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -3474,8 +3785,9 @@ pub enum ColorType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ColorType {
@@ -3492,7 +3804,7 @@ impl std::fmt::Display for ColorType {
             ColorType::Text => "TEXT",
             ColorType::ThemeColorTypeUnspecified => "THEME_COLOR_TYPE_UNSPECIFIED",
             ColorType::Noop => "",
-            ColorType::FallthroughString => "*",
+            ColorType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3521,6 +3833,10 @@ pub struct ColorStyle {
         rename = "themeColor"
     )]
     pub theme_color: Option<ColorType>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -3545,8 +3861,9 @@ pub enum RelativeDate {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for RelativeDate {
@@ -3560,7 +3877,7 @@ impl std::fmt::Display for RelativeDate {
             RelativeDate::Tomorrow => "TOMORROW",
             RelativeDate::Yesterday => "YESTERDAY",
             RelativeDate::Noop => "",
-            RelativeDate::FallthroughString => "*",
+            RelativeDate::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3594,6 +3911,10 @@ pub struct ConditionValue {
         rename = "userEnteredValue"
     )]
     pub user_entered_value: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A rule describing a conditional format.
@@ -3626,6 +3947,10 @@ pub struct ConditionalFormatRule {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub ranges: Vec<GridRange>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -3640,8 +3965,9 @@ pub enum PasteOrientation {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PasteOrientation {
@@ -3650,7 +3976,7 @@ impl std::fmt::Display for PasteOrientation {
             PasteOrientation::Normal => "NORMAL",
             PasteOrientation::Transpose => "TRANSPOSE",
             PasteOrientation::Noop => "",
-            PasteOrientation::FallthroughString => "*",
+            PasteOrientation::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3684,8 +4010,9 @@ pub enum PasteType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PasteType {
@@ -3699,7 +4026,7 @@ impl std::fmt::Display for PasteType {
             PasteType::PasteNoBorders => "PASTE_NO_BORDERS",
             PasteType::PasteValues => "PASTE_VALUES",
             PasteType::Noop => "",
-            PasteType::FallthroughString => "*",
+            PasteType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3738,6 +4065,10 @@ pub struct CopyPasteRequest {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub source: Option<GridRange>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The request to copy a sheet across spreadsheets.
@@ -3753,6 +4084,10 @@ pub struct CopySheetAnotherSpreadsheetRequest {
         rename = "destinationSpreadsheetId"
     )]
     pub destination_spreadsheet_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A request to create developer metadata.
@@ -3767,6 +4102,10 @@ pub struct CreateDeveloperMetadataRequest {
         rename = "developerMetadata"
     )]
     pub developer_metadata: Option<DeveloperMetadata>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The response from creating developer metadata.
@@ -3781,6 +4120,10 @@ pub struct CreateDeveloperMetadataResponse {
         rename = "developerMetadata"
     )]
     pub developer_metadata: Option<DeveloperMetadata>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Moves data from the source to the destination.
@@ -3801,6 +4144,10 @@ pub struct CutPasteRequest {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub source: Option<GridRange>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -3847,8 +4194,9 @@ pub enum ErrorCode {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ErrorCode {
@@ -3873,7 +4221,7 @@ impl std::fmt::Display for ErrorCode {
             ErrorCode::TooManyRows => "TOO_MANY_ROWS",
             ErrorCode::UnsupportedDataType => "UNSUPPORTED_DATA_TYPE",
             ErrorCode::Noop => "",
-            ErrorCode::FallthroughString => "*",
+            ErrorCode::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3903,8 +4251,9 @@ pub enum State {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for State {
@@ -3916,7 +4265,7 @@ impl std::fmt::Display for State {
             State::Running => "RUNNING",
             State::Succeeded => "SUCCEEDED",
             State::Noop => "",
-            State::FallthroughString => "*",
+            State::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3961,6 +4310,10 @@ pub struct DataExecutionStatus {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub state: Option<State>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Filter that describes what data should be selected or returned from a request.
@@ -3990,6 +4343,10 @@ pub struct DataFilter {
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "gridRange")]
     pub grid_range: Option<GridRange>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A range of values whose location is specified by a DataFilter.
@@ -4022,6 +4379,10 @@ pub struct DataFilterValueRange {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub values: Vec<Vec<String>>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -4050,8 +4411,9 @@ pub enum Placement {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Placement {
@@ -4067,7 +4429,7 @@ impl std::fmt::Display for Placement {
             Placement::OutsideEnd => "OUTSIDE_END",
             Placement::Right => "RIGHT",
             Placement::Noop => "",
-            Placement::FallthroughString => "*",
+            Placement::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -4095,8 +4457,9 @@ pub enum DataLabelType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DataLabelType {
@@ -4107,7 +4470,7 @@ impl std::fmt::Display for DataLabelType {
             DataLabelType::DataLabelTypeUnspecified => "DATA_LABEL_TYPE_UNSPECIFIED",
             DataLabelType::None => "NONE",
             DataLabelType::Noop => "",
-            DataLabelType::FallthroughString => "*",
+            DataLabelType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -4150,6 +4513,10 @@ pub struct DataLabel {
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
     pub type_: Option<DataLabelType>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Information about an external data source in the spreadsheet.
@@ -4190,6 +4557,10 @@ pub struct DataSource {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub spec: Option<DataSourceSpec>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Properties of a data source chart.
@@ -4214,6 +4585,10 @@ pub struct DataSourceChartProperties {
         rename = "dataSourceId"
     )]
     pub data_source_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A column in a data source.
@@ -4233,6 +4608,10 @@ pub struct DataSourceColumn {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub reference: Option<DataSourceColumnReference>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// An unique identifier that references a data source column.
@@ -4247,6 +4626,10 @@ pub struct DataSourceColumnReference {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A data source formula.
@@ -4271,6 +4654,10 @@ pub struct DataSourceFormula {
         rename = "dataSourceId"
     )]
     pub data_source_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Reference to a data source object.
@@ -4323,6 +4710,10 @@ pub struct DataSourceObjectReference {
         rename = "sheetId"
     )]
     pub sheet_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A list of references to data source objects.
@@ -4337,6 +4728,10 @@ pub struct DataSourceObjectReferences {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub references: Vec<DataSourceObjectReference>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A parameter in a data source's query. The parameter allows the user to pass in values from the spreadsheet into a query.
@@ -4366,6 +4761,10 @@ pub struct DataSourceParameter {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub range: Option<GridRange>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A schedule for data to refresh every day in a given time interval.
@@ -4376,6 +4775,10 @@ pub struct DataSourceRefreshDailySchedule {
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "startTime")]
     pub start_time: Option<TimeOfDay>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A monthly schedule for data to refresh on specific days in the month in a given time interval.
@@ -4396,6 +4799,10 @@ pub struct DataSourceRefreshMonthlySchedule {
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "startTime")]
     pub start_time: Option<TimeOfDay>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -4410,8 +4817,9 @@ pub enum RefreshScope {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for RefreshScope {
@@ -4422,7 +4830,7 @@ impl std::fmt::Display for RefreshScope {
                 "DATA_SOURCE_REFRESH_SCOPE_UNSPECIFIED"
             }
             RefreshScope::Noop => "",
-            RefreshScope::FallthroughString => "*",
+            RefreshScope::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -4486,6 +4894,10 @@ pub struct DataSourceRefreshSchedule {
         rename = "weeklySchedule"
     )]
     pub weekly_schedule: Option<DataSourceRefreshWeeklySchedule>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -4509,8 +4921,9 @@ pub enum DaysOfWeek {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DaysOfWeek {
@@ -4525,7 +4938,7 @@ impl std::fmt::Display for DaysOfWeek {
             DaysOfWeek::Tuesday => "TUESDAY",
             DaysOfWeek::Wednesday => "WEDNESDAY",
             DaysOfWeek::Noop => "",
-            DaysOfWeek::FallthroughString => "*",
+            DaysOfWeek::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -4555,6 +4968,10 @@ pub struct DataSourceRefreshWeeklySchedule {
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "startTime")]
     pub start_time: Option<TimeOfDay>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A range along a single dimension on a DATA_SOURCE sheet.
@@ -4580,6 +4997,10 @@ pub struct DataSourceSheetDimensionRange {
         rename = "sheetId"
     )]
     pub sheet_id: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Additional properties of a DATA_SOURCE sheet.
@@ -4613,6 +5034,10 @@ pub struct DataSourceSheetProperties {
         rename = "dataSourceId"
     )]
     pub data_source_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// This specifies the details of the data source. For example, for BigQuery, this specifies information about the BigQuery source.
@@ -4632,6 +5057,10 @@ pub struct DataSourceSpec {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub parameters: Vec<DataSourceParameter>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -4648,8 +5077,9 @@ pub enum ColumnSelectionType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ColumnSelectionType {
@@ -4661,7 +5091,7 @@ impl std::fmt::Display for ColumnSelectionType {
             ColumnSelectionType::Selected => "SELECTED",
             ColumnSelectionType::SyncAll => "SYNC_ALL",
             ColumnSelectionType::Noop => "",
-            ColumnSelectionType::FallthroughString => "*",
+            ColumnSelectionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -4743,6 +5173,10 @@ pub struct DataSourceTable {
         rename = "sortSpecs"
     )]
     pub sort_specs: Vec<SortSpec>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A data validation rule.
@@ -4780,6 +5214,10 @@ pub struct DataValidationRule {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub strict: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -4822,8 +5260,9 @@ pub enum DateTimeRuleType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DateTimeRuleType {
@@ -4846,7 +5285,7 @@ impl std::fmt::Display for DateTimeRuleType {
             DateTimeRuleType::YearMonthDay => "YEAR_MONTH_DAY",
             DateTimeRuleType::YearQuarter => "YEAR_QUARTER",
             DateTimeRuleType::Noop => "",
-            DateTimeRuleType::FallthroughString => "*",
+            DateTimeRuleType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -4866,6 +5305,10 @@ pub struct DateTimeRule {
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
     pub type_: Option<DateTimeRuleType>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Removes the banded range with the given ID from the spreadsheet.
@@ -4881,6 +5324,10 @@ pub struct DeleteBandingRequest {
         rename = "bandedRangeId"
     )]
     pub banded_range_id: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Deletes a conditional format rule at the given index. All subsequent rules' indexes are decremented.
@@ -4905,6 +5352,10 @@ pub struct DeleteConditionalFormatRuleRequest {
         rename = "sheetId"
     )]
     pub sheet_id: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The result of deleting a conditional format rule.
@@ -4915,6 +5366,10 @@ pub struct DeleteConditionalFormatRuleResponse {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub rule: Option<ConditionalFormatRule>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Deletes a data source. The request also deletes the associated data source sheet, and unlinks all associated data source objects.
@@ -4930,6 +5385,10 @@ pub struct DeleteDataSourceRequest {
         rename = "dataSourceId"
     )]
     pub data_source_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A request to delete developer metadata.
@@ -4944,6 +5403,10 @@ pub struct DeleteDeveloperMetadataRequest {
         rename = "dataFilter"
     )]
     pub data_filter: Option<DataFilter>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The response from deleting developer metadata.
@@ -4959,6 +5422,10 @@ pub struct DeleteDeveloperMetadataResponse {
         rename = "deletedDeveloperMetadata"
     )]
     pub deleted_developer_metadata: Vec<DeveloperMetadata>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Deletes a group over the specified range by decrementing the depth of the dimensions in the range. For example, assume the sheet has a depth-1 group over B:E and a depth-2 group over C:D. Deleting a group over D:E leaves the sheet with a depth-1 group over B:D and a depth-2 group over C:C.
@@ -4969,6 +5436,10 @@ pub struct DeleteDimensionGroupRequest {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub range: Option<DimensionRange>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The result of deleting a group.
@@ -4984,6 +5455,10 @@ pub struct DeleteDimensionGroupResponse {
         rename = "dimensionGroups"
     )]
     pub dimension_groups: Vec<DimensionGroup>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Deletes the dimensions from the sheet.
@@ -4994,6 +5469,10 @@ pub struct DeleteDimensionRequest {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub range: Option<DimensionRange>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Removes rows within this range that contain values in the specified columns that are duplicates of values in any previous row. Rows with identical values but different letter cases, formatting, or formulas are considered to be duplicates. This request also removes duplicate rows hidden from view (for example, due to a filter). When removing duplicates, the first instance of each duplicate row scanning from the top downwards is kept in the resulting range. Content outside of the specified range isn't removed, and rows considered duplicates do not have to be adjacent to each other in the range.
@@ -5014,6 +5493,10 @@ pub struct DeleteDuplicatesRequest {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub range: Option<GridRange>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The result of removing duplicates in a range.
@@ -5029,6 +5512,10 @@ pub struct DeleteDuplicatesResponse {
         rename = "duplicatesRemovedCount"
     )]
     pub duplicates_removed_count: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Deletes the embedded object with the given ID.
@@ -5044,6 +5531,10 @@ pub struct DeleteEmbeddedObjectRequest {
         rename = "objectId"
     )]
     pub object_id: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Deletes a particular filter view.
@@ -5059,6 +5550,10 @@ pub struct DeleteFilterViewRequest {
         rename = "filterId"
     )]
     pub filter_id: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Removes the named range with the given ID from the spreadsheet.
@@ -5074,6 +5569,10 @@ pub struct DeleteNamedRangeRequest {
         rename = "namedRangeId"
     )]
     pub named_range_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Deletes the protected range with the given ID.
@@ -5089,6 +5588,10 @@ pub struct DeleteProtectedRangeRequest {
         rename = "protectedRangeId"
     )]
     pub protected_range_id: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Deletes a range of cells, shifting other cells into the deleted area.
@@ -5108,6 +5611,10 @@ pub struct DeleteRangeRequest {
         rename = "shiftDimension"
     )]
     pub shift_dimension: Option<Dimension>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Deletes the requested sheet.
@@ -5123,6 +5630,10 @@ pub struct DeleteSheetRequest {
         rename = "sheetId"
     )]
     pub sheet_id: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -5139,8 +5650,9 @@ pub enum Visibility {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Visibility {
@@ -5152,7 +5664,7 @@ impl std::fmt::Display for Visibility {
             Visibility::Document => "DOCUMENT",
             Visibility::Project => "PROJECT",
             Visibility::Noop => "",
-            Visibility::FallthroughString => "*",
+            Visibility::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -5207,6 +5719,10 @@ pub struct DeveloperMetadata {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub visibility: Option<Visibility>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -5227,8 +5743,9 @@ pub enum LocationType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for LocationType {
@@ -5242,7 +5759,7 @@ impl std::fmt::Display for LocationType {
             LocationType::Sheet => "SHEET",
             LocationType::Spreadsheet => "SPREADSHEET",
             LocationType::Noop => "",
-            LocationType::FallthroughString => "*",
+            LocationType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -5293,6 +5810,10 @@ pub struct DeveloperMetadataLocation {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub spreadsheet: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -5309,8 +5830,9 @@ pub enum LocationMatchingStrategy {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for LocationMatchingStrategy {
@@ -5322,7 +5844,7 @@ impl std::fmt::Display for LocationMatchingStrategy {
             LocationMatchingStrategy::ExactLocation => "EXACT_LOCATION",
             LocationMatchingStrategy::IntersectingLocation => "INTERSECTING_LOCATION",
             LocationMatchingStrategy::Noop => "",
-            LocationMatchingStrategy::FallthroughString => "*",
+            LocationMatchingStrategy::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -5399,6 +5921,10 @@ pub struct DeveloperMetadataLookup {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub visibility: Option<Visibility>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A group over an interval of rows or columns on a sheet, which can contain or be contained within other groups. A group can be collapsed or expanded as a unit on the sheet.
@@ -5426,6 +5952,10 @@ pub struct DimensionGroup {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub range: Option<DimensionRange>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Properties about a dimension.
@@ -5478,6 +6008,10 @@ pub struct DimensionProperties {
         rename = "pixelSize"
     )]
     pub pixel_size: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A range along a single dimension on a sheet. All indexes are zero-based. Indexes are half open: the start index is inclusive and the end index is exclusive. Missing indexes indicate the range is unbounded on that side.
@@ -5518,6 +6052,10 @@ pub struct DimensionRange {
         rename = "startIndex"
     )]
     pub start_index: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Duplicates a particular filter view.
@@ -5533,6 +6071,10 @@ pub struct DuplicateFilterViewRequest {
         rename = "filterId"
     )]
     pub filter_id: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The result of a filter view being duplicated.
@@ -5543,6 +6085,10 @@ pub struct DuplicateFilterViewResponse {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub filter: Option<FilterView>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Duplicates the contents of a sheet.
@@ -5588,6 +6134,10 @@ pub struct DuplicateSheetRequest {
         rename = "sourceSheetId"
     )]
     pub source_sheet_id: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The result of duplicating a sheet.
@@ -5598,6 +6148,10 @@ pub struct DuplicateSheetResponse {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub properties: Option<SheetProperties>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The editors of a protected range.
@@ -5630,6 +6184,10 @@ pub struct Editors {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub users: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A chart embedded in a sheet.
@@ -5660,6 +6218,10 @@ pub struct EmbeddedChart {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub spec: Option<ChartSpec>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A border along an embedded object.
@@ -5679,6 +6241,10 @@ pub struct EmbeddedObjectBorder {
         rename = "colorStyle"
     )]
     pub color_style: Option<ColorStyle>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The position of an embedded object such as a chart.
@@ -5712,6 +6278,10 @@ pub struct EmbeddedObjectPosition {
         rename = "sheetId"
     )]
     pub sheet_id: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -5742,8 +6312,9 @@ pub enum ErrorValueType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ErrorValueType {
@@ -5760,7 +6331,7 @@ impl std::fmt::Display for ErrorValueType {
             ErrorValueType::Ref => "REF",
             ErrorValueType::Value => "VALUE",
             ErrorValueType::Noop => "",
-            ErrorValueType::FallthroughString => "*",
+            ErrorValueType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -5789,6 +6360,10 @@ pub struct ErrorValue {
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
     pub type_: Option<ErrorValueType>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The kinds of value that a cell in a spreadsheet can have.
@@ -5842,6 +6417,10 @@ pub struct ExtendedValue {
         rename = "stringValue"
     )]
     pub string_value: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Criteria for showing/hiding rows in a filter or filter view.
@@ -5898,6 +6477,10 @@ pub struct FilterCriteria {
         rename = "visibleForegroundColorStyle"
     )]
     pub visible_foreground_color_style: Option<ColorStyle>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The filter criteria associated with a specific column.
@@ -5931,6 +6514,10 @@ pub struct FilterSpec {
         rename = "filterCriteria"
     )]
     pub filter_criteria: Option<FilterCriteria>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A filter view.
@@ -5995,6 +6582,10 @@ pub struct FilterView {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub title: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Finds and replaces data in cells over a range, sheet, or all sheets.
@@ -6070,6 +6661,10 @@ pub struct FindReplaceRequest {
         rename = "sheetId"
     )]
     pub sheet_id: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The result of the find/replace.
@@ -6125,6 +6720,10 @@ pub struct FindReplaceResponse {
         rename = "valuesChanged"
     )]
     pub values_changed: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The request for retrieving a Spreadsheet.
@@ -6149,6 +6748,10 @@ pub struct GetSpreadsheetByDataFilterRequest {
         rename = "includeGridData"
     )]
     pub include_grid_data: Option<bool>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A rule that applies a gradient color scale format, based on the interpolation points listed. The format of a cell will vary based on its contents as compared to the values of the interpolation points.
@@ -6169,6 +6772,10 @@ pub struct GradientRule {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub minpoint: Option<InterpolationPoint>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A coordinate in a sheet. All indexes are zero-based.
@@ -6204,6 +6811,10 @@ pub struct GridCoordinate {
         rename = "sheetId"
     )]
     pub sheet_id: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Data in the grid, as well as metadata about the dimensions.
@@ -6259,6 +6870,10 @@ pub struct GridData {
         rename = "startRow"
     )]
     pub start_row: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Properties of a grid.
@@ -6331,6 +6946,10 @@ pub struct GridProperties {
         rename = "rowGroupControlAfter"
     )]
     pub row_group_control_after: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A range on a sheet. All indexes are zero-based. Indexes are half open, i.e. the start index is inclusive and the end index is exclusive -- [start_index, end_index). Missing indexes indicate the range is unbounded on that side. For example, if `"Sheet1"` is sheet ID 0, then: `Sheet1!A1:A1 == sheet_id: 0, start_row_index: 0, end_row_index: 1, start_column_index: 0, end_column_index: 1` `Sheet1!A3:B4 == sheet_id: 0, start_row_index: 2, end_row_index: 4, start_column_index: 0, end_column_index: 2` `Sheet1!A:B == sheet_id: 0, start_column_index: 0, end_column_index: 2` `Sheet1!A5:B == sheet_id: 0, start_row_index: 4, start_column_index: 0, end_column_index: 2` `Sheet1 == sheet_id:0` The start index must always be less than or equal to the end index. If the start index equals the end index, then the range is empty. Empty ranges are typically not meaningful and are usually rendered in the UI as `#REF!`.
@@ -6386,6 +7005,10 @@ pub struct GridRange {
         rename = "startRowIndex"
     )]
     pub start_row_index: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -6410,8 +7033,9 @@ pub enum HistogramChartSpecLegendPosition {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for HistogramChartSpecLegendPosition {
@@ -6427,7 +7051,7 @@ impl std::fmt::Display for HistogramChartSpecLegendPosition {
             HistogramChartSpecLegendPosition::RightLegend => "RIGHT_LEGEND",
             HistogramChartSpecLegendPosition::TopLegend => "TOP_LEGEND",
             HistogramChartSpecLegendPosition::Noop => "",
-            HistogramChartSpecLegendPosition::FallthroughString => "*",
+            HistogramChartSpecLegendPosition::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -6489,6 +7113,10 @@ pub struct HistogramChartSpec {
         rename = "showItemDividers"
     )]
     pub show_item_dividers: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Allows you to organize the numeric values in a source data column into buckets of a constant size. All values from HistogramRule.start to HistogramRule.end are placed into groups of size HistogramRule.interval. In addition, all values below HistogramRule.start are placed in one group, and all values above HistogramRule.end are placed in another. Only HistogramRule.interval is required, though if HistogramRule.start and HistogramRule.end are both provided, HistogramRule.start must be less than HistogramRule.end. For example, a pivot table showing average purchase amount by age that has 50+ rows: +-----+-------------------+ | Age | AVERAGE of Amount | +-----+-------------------+ | 16 | $27.13 | | 17 | $5.24 | | 18 | $20.15 | ... +-----+-------------------+ could be turned into a pivot table that looks like the one below by applying a histogram group rule with a HistogramRule.start of 25, an HistogramRule.interval of 20, and an HistogramRule.end of 65. +-------------+-------------------+ | Grouped Age | AVERAGE of Amount | +-------------+-------------------+ | < 25 | $19.34 | | 25-45 | $31.43 | | 45-65 | $35.87 | | > 65 | $27.55 | +-------------+-------------------+ | Grand Total | $29.12 | +-------------+-------------------+
@@ -6521,6 +7149,10 @@ pub struct HistogramRule {
         deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub start: f64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A histogram series containing the series color and data.
@@ -6545,6 +7177,10 @@ pub struct HistogramSeries {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub data: Option<ChartData>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Inserts rows or columns in a sheet at a particular index.
@@ -6564,6 +7200,10 @@ pub struct InsertDimensionRequest {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub range: Option<DimensionRange>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Inserts cells into a range, shifting the existing cells over or down.
@@ -6583,6 +7223,10 @@ pub struct InsertRangeRequest {
         rename = "shiftDimension"
     )]
     pub shift_dimension: Option<Dimension>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -6605,8 +7249,9 @@ pub enum InterpolationPointType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for InterpolationPointType {
@@ -6621,7 +7266,7 @@ impl std::fmt::Display for InterpolationPointType {
             InterpolationPointType::Percent => "PERCENT",
             InterpolationPointType::Percentile => "PERCENTILE",
             InterpolationPointType::Noop => "",
-            InterpolationPointType::FallthroughString => "*",
+            InterpolationPointType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -6664,6 +7309,10 @@ pub struct InterpolationPoint {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub value: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Represents a time interval, encoded as a Timestamp start (inclusive) and a Timestamp end (exclusive). The start must be less than or equal to the end. When the start equals the end, the interval is empty (matches no time). When both start and end are unspecified, the interval matches any time.
@@ -6689,6 +7338,10 @@ pub struct Interval {
         rename = "startTime"
     )]
     pub start_time: Option<chrono::DateTime<chrono::Utc>>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Settings to control how circular dependencies are resolved with iterative calculation.
@@ -6714,6 +7367,10 @@ pub struct IterativeCalculationSettings {
         rename = "maxIterations"
     )]
     pub max_iterations: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Formatting options for key value.
@@ -6733,6 +7390,10 @@ pub struct KeyValueFormat {
         rename = "textFormat"
     )]
     pub text_format: Option<TextFormat>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -6761,8 +7422,9 @@ pub enum LineStyleType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for LineStyleType {
@@ -6778,7 +7440,7 @@ impl std::fmt::Display for LineStyleType {
             LineStyleType::MediumDashedDotted => "MEDIUM_DASHED_DOTTED",
             LineStyleType::Solid => "SOLID",
             LineStyleType::Noop => "",
-            LineStyleType::FallthroughString => "*",
+            LineStyleType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -6807,6 +7469,10 @@ pub struct LineStyle {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub width: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// An external or local reference.
@@ -6821,6 +7487,10 @@ pub struct Link {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub uri: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Allows you to manually organize the values in a source data column into buckets with names of your choosing. For example, a pivot table that aggregates population by state: +-------+-------------------+ | State | SUM of Population | +-------+-------------------+ | AK | 0.7 | | AL | 4.8 | | AR | 2.9 | ... +-------+-------------------+ could be turned into a pivot table that aggregates population by time zone by providing a list of groups (for example, groupName = 'Central', items = ['AL', 'AR', 'IA', ...]) to a manual group rule. Note that a similar effect could be achieved by adding a time zone column to the source data and adjusting the pivot table. +-----------+-------------------+ | Time Zone | SUM of Population | +-----------+-------------------+ | Central | 106.3 | | Eastern | 151.9 | | Mountain | 17.4 | ... +-----------+-------------------+
@@ -6835,6 +7505,10 @@ pub struct ManualRule {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub groups: Vec<ManualRuleGroup>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A group name and a list of items from the source data that should be placed in the group with this name.
@@ -6854,6 +7528,10 @@ pub struct ManualRuleGroup {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub items: Vec<ExtendedValue>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A developer metadata entry and the data filters specified in the original request that matched it.
@@ -6878,6 +7556,10 @@ pub struct MatchedDeveloperMetadata {
         rename = "developerMetadata"
     )]
     pub developer_metadata: Option<DeveloperMetadata>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A value range that was matched by one or more data filers.
@@ -6902,6 +7584,10 @@ pub struct MatchedValueRange {
         rename = "valueRange"
     )]
     pub value_range: Option<ValueRange>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -6918,8 +7604,9 @@ pub enum MergeType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for MergeType {
@@ -6929,7 +7616,7 @@ impl std::fmt::Display for MergeType {
             MergeType::MergeColumns => "MERGE_COLUMNS",
             MergeType::MergeRows => "MERGE_ROWS",
             MergeType::Noop => "",
-            MergeType::FallthroughString => "*",
+            MergeType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -6954,6 +7641,10 @@ pub struct MergeCellsRequest {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub range: Option<GridRange>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Moves one or more rows or columns.
@@ -6974,6 +7665,10 @@ pub struct MoveDimensionRequest {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub source: Option<DimensionRange>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A named range.
@@ -7003,6 +7698,10 @@ pub struct NamedRange {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub range: Option<GridRange>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -7031,8 +7730,9 @@ pub enum NumberFormatType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for NumberFormatType {
@@ -7048,7 +7748,7 @@ impl std::fmt::Display for NumberFormatType {
             NumberFormatType::Text => "TEXT",
             NumberFormatType::Time => "TIME",
             NumberFormatType::Noop => "",
-            NumberFormatType::FallthroughString => "*",
+            NumberFormatType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -7077,6 +7777,10 @@ pub struct NumberFormat {
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
     pub type_: Option<NumberFormatType>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -7095,8 +7799,9 @@ pub enum NodeSize {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for NodeSize {
@@ -7107,7 +7812,7 @@ impl std::fmt::Display for NodeSize {
             NodeSize::OrgChartLabelSizeUnspecified => "ORG_CHART_LABEL_SIZE_UNSPECIFIED",
             NodeSize::Small => "SMALL",
             NodeSize::Noop => "",
-            NodeSize::FallthroughString => "*",
+            NodeSize::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -7178,6 +7883,10 @@ pub struct OrgChartSpec {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub tooltips: Option<ChartData>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The location an object is overlaid on top of a grid.
@@ -7232,6 +7941,10 @@ pub struct OverlayPosition {
         rename = "widthPixels"
     )]
     pub width_pixels: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The amount of padding around the cell, in pixels. When updating padding, every field must be specified.
@@ -7273,6 +7986,10 @@ pub struct Padding {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub top: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Inserts data into the spreadsheet starting at the specified coordinate.
@@ -7311,6 +8028,10 @@ pub struct PasteDataRequest {
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
     pub type_: Option<PasteType>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -7335,8 +8056,9 @@ pub enum PieChartSpecLegendPosition {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PieChartSpecLegendPosition {
@@ -7352,7 +8074,7 @@ impl std::fmt::Display for PieChartSpecLegendPosition {
             PieChartSpecLegendPosition::RightLegend => "RIGHT_LEGEND",
             PieChartSpecLegendPosition::TopLegend => "TOP_LEGEND",
             PieChartSpecLegendPosition::Noop => "",
-            PieChartSpecLegendPosition::FallthroughString => "*",
+            PieChartSpecLegendPosition::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -7405,6 +8127,10 @@ pub struct PieChartSpec {
         rename = "threeDimensional"
     )]
     pub three_dimensional: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Criteria for showing/hiding rows in a pivot table.
@@ -7434,6 +8160,10 @@ pub struct PivotFilterCriteria {
         rename = "visibleValues"
     )]
     pub visible_values: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The pivot table filter criteria associated with a specific source column offset.
@@ -7467,6 +8197,10 @@ pub struct PivotFilterSpec {
         rename = "filterCriteria"
     )]
     pub filter_criteria: Option<PivotFilterCriteria>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -7483,8 +8217,9 @@ pub enum SortOrder {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SortOrder {
@@ -7494,7 +8229,7 @@ impl std::fmt::Display for SortOrder {
             SortOrder::Descending => "DESCENDING",
             SortOrder::SortOrderUnspecified => "SORT_ORDER_UNSPECIFIED",
             SortOrder::Noop => "",
-            SortOrder::FallthroughString => "*",
+            SortOrder::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -7593,6 +8328,10 @@ pub struct PivotGroup {
         rename = "valueMetadata"
     )]
     pub value_metadata: Vec<PivotGroupValueMetadata>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The count limit on rows or columns in the pivot group.
@@ -7618,6 +8357,10 @@ pub struct PivotGroupLimit {
         rename = "countLimit"
     )]
     pub count_limit: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// An optional setting on a PivotGroup that defines buckets for the values in the source data column rather than breaking out each individual value. Only one PivotGroup with a group rule may be added for each column in the source data, though on any given column you may add both a PivotGroup that has a rule and a PivotGroup that does not.
@@ -7650,6 +8393,10 @@ pub struct PivotGroupRule {
         rename = "manualRule"
     )]
     pub manual_rule: Option<ManualRule>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Information about which values in a pivot group should be used for sorting.
@@ -7674,6 +8421,10 @@ pub struct PivotGroupSortValueBucket {
         rename = "valuesIndex"
     )]
     pub values_index: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Metadata about a value in a pivot grouping.
@@ -7692,6 +8443,10 @@ pub struct PivotGroupValueMetadata {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub value: Option<ExtendedValue>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -7706,8 +8461,9 @@ pub enum ValueLayout {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ValueLayout {
@@ -7716,7 +8472,7 @@ impl std::fmt::Display for ValueLayout {
             ValueLayout::Horizontal => "HORIZONTAL",
             ValueLayout::Vertical => "VERTICAL",
             ValueLayout::Noop => "",
-            ValueLayout::FallthroughString => "*",
+            ValueLayout::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -7806,6 +8562,10 @@ pub struct PivotTable {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub values: Vec<PivotValue>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -7824,8 +8584,9 @@ pub enum CalculatedDisplayType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for CalculatedDisplayType {
@@ -7838,7 +8599,7 @@ impl std::fmt::Display for CalculatedDisplayType {
                 "PIVOT_VALUE_CALCULATED_DISPLAY_TYPE_UNSPECIFIED"
             }
             CalculatedDisplayType::Noop => "",
-            CalculatedDisplayType::FallthroughString => "*",
+            CalculatedDisplayType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -7888,8 +8649,9 @@ pub enum SummarizeFunction {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SummarizeFunction {
@@ -7913,7 +8675,7 @@ impl std::fmt::Display for SummarizeFunction {
             SummarizeFunction::Var => "VAR",
             SummarizeFunction::Varp => "VARP",
             SummarizeFunction::Noop => "",
-            SummarizeFunction::FallthroughString => "*",
+            SummarizeFunction::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -7983,6 +8745,10 @@ pub struct PivotValue {
         rename = "summarizeFunction"
     )]
     pub summarize_function: Option<SummarizeFunction>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -8011,8 +8777,9 @@ pub enum Shape {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Shape {
@@ -8028,7 +8795,7 @@ impl std::fmt::Display for Shape {
             Shape::Triangle => "TRIANGLE",
             Shape::XMark => "X_MARK",
             Shape::Noop => "",
-            Shape::FallthroughString => "*",
+            Shape::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -8057,6 +8824,10 @@ pub struct PointStyle {
         deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub size: f64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A protected range.
@@ -8129,6 +8900,10 @@ pub struct ProtectedRange {
         rename = "warningOnly"
     )]
     pub warning_only: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Randomizes the order of the rows in a range.
@@ -8139,6 +8914,10 @@ pub struct RandomizeRangeRequest {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub range: Option<GridRange>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The execution status of refreshing one data source object.
@@ -8158,6 +8937,10 @@ pub struct RefreshDataSourceObjectExecutionStatus {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub reference: Option<DataSourceObjectReference>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Refreshes one or multiple data source objects in the spreadsheet by the specified references. The request requires an additional `bigquery.readonly` OAuth scope. If there are multiple refresh requests referencing the same data source objects in one batch, only the last refresh request is processed, and all those requests will have the same response accordingly.
@@ -8188,6 +8971,10 @@ pub struct RefreshDataSourceRequest {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub references: Option<DataSourceObjectReferences>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The response from refreshing one or multiple data source objects.
@@ -8202,6 +8989,10 @@ pub struct RefreshDataSourceResponse {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub statuses: Vec<RefreshDataSourceObjectExecutionStatus>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Updates all cells in the range to the values in the given Cell object. Only the fields listed in the fields field are updated; others are unchanged. If writing a cell with a formula, the formula's ranges will automatically increment for each field in the range. For example, if writing a cell with formula `=A1` into range B2:C4, B2 would be `=A1`, B3 would be `=A2`, B4 would be `=A3`, C2 would be `=B1`, C3 would be `=B2`, C4 would be `=B3`. To keep the formula's ranges static, use the `$` indicator. For example, use the formula `=$A$1` to prevent both the row and the column from incrementing.
@@ -8226,6 +9017,10 @@ pub struct RepeatCellRequest {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub range: Option<GridRange>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A single kind of update to apply to a spreadsheet.
@@ -8784,6 +9579,10 @@ pub struct Request {
         rename = "updateSpreadsheetProperties"
     )]
     pub update_spreadsheet_properties: Option<UpdateSpreadsheetPropertiesRequest>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A single response from an update.
@@ -8984,6 +9783,10 @@ pub struct Response {
         rename = "updateEmbeddedObjectPosition"
     )]
     pub update_embedded_object_position: Option<UpdateEmbeddedObjectPositionResponse>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Data about each cell in a row.
@@ -8998,6 +9801,10 @@ pub struct RowData {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub values: Vec<CellData>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -9014,8 +9821,9 @@ pub enum NumberFormatSource {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for NumberFormatSource {
@@ -9027,7 +9835,7 @@ impl std::fmt::Display for NumberFormatSource {
             NumberFormatSource::Custom => "CUSTOM",
             NumberFormatSource::FromData => "FROM_DATA",
             NumberFormatSource::Noop => "",
-            NumberFormatSource::FallthroughString => "*",
+            NumberFormatSource::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -9115,6 +9923,10 @@ pub struct ScorecardChartSpec {
         rename = "scaleFactor"
     )]
     pub scale_factor: f64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A request to retrieve all developer metadata matching the set of specified criteria.
@@ -9130,6 +9942,10 @@ pub struct SearchDeveloperMetadataRequest {
         rename = "dataFilters"
     )]
     pub data_filters: Vec<DataFilter>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A reply to a developer metadata search request.
@@ -9145,6 +9961,10 @@ pub struct SearchDeveloperMetadataResponse {
         rename = "matchedDeveloperMetadata"
     )]
     pub matched_developer_metadata: Vec<MatchedDeveloperMetadata>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Sets the basic filter associated with a sheet.
@@ -9155,6 +9975,10 @@ pub struct SetBasicFilterRequest {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub filter: Option<BasicFilter>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Sets a data validation rule to every cell in the range. To clear validation in a range, call this with no rule specified.
@@ -9170,6 +9994,10 @@ pub struct SetDataValidationRequest {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub rule: Option<DataValidationRule>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A sheet in a spreadsheet.
@@ -9295,6 +10123,10 @@ pub struct Sheet {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub slicers: Vec<Slicer>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -9313,8 +10145,9 @@ pub enum SheetType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SheetType {
@@ -9325,7 +10158,7 @@ impl std::fmt::Display for SheetType {
             SheetType::Object => "OBJECT",
             SheetType::SheetTypeUnspecified => "SHEET_TYPE_UNSPECIFIED",
             SheetType::Noop => "",
-            SheetType::FallthroughString => "*",
+            SheetType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -9422,6 +10255,10 @@ pub struct SheetProperties {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub title: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A slicer in a sheet.
@@ -9447,6 +10284,10 @@ pub struct Slicer {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub spec: Option<SlicerSpec>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The specifications of a slicer.
@@ -9530,6 +10371,10 @@ pub struct SlicerSpec {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub title: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Sorts data in rows based on a sort order per column.
@@ -9550,6 +10395,10 @@ pub struct SortRangeRequest {
         rename = "sortSpecs"
     )]
     pub sort_specs: Vec<SortSpec>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A sort order associated with a specific column or row.
@@ -9615,6 +10464,10 @@ pub struct SortSpec {
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "sortOrder")]
     pub sort_order: Option<SortOrder>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A combination of a source range and how to extend that source.
@@ -9640,6 +10493,10 @@ pub struct SourceDestination {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub source: Option<GridRange>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Resource that represents a spreadsheet.
@@ -9719,6 +10576,10 @@ pub struct Spreadsheet {
         rename = "spreadsheetUrl"
     )]
     pub spreadsheet_url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -9737,8 +10598,9 @@ pub enum AutoRecalc {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AutoRecalc {
@@ -9749,7 +10611,7 @@ impl std::fmt::Display for AutoRecalc {
             AutoRecalc::OnChange => "ON_CHANGE",
             AutoRecalc::RecalculationIntervalUnspecified => "RECALCULATION_INTERVAL_UNSPECIFIED",
             AutoRecalc::Noop => "",
-            AutoRecalc::FallthroughString => "*",
+            AutoRecalc::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -9828,6 +10690,10 @@ pub struct SpreadsheetProperties {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub title: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Represents spreadsheet theme
@@ -9853,6 +10719,10 @@ pub struct SpreadsheetTheme {
         rename = "themeColors"
     )]
     pub theme_colors: Vec<ThemeColorPair>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The format of a run of text in a cell. Absent values indicate that the field isn't specified.
@@ -9933,6 +10803,10 @@ pub struct TextFormat {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub underline: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A run of a text format. The format of this run continues until the start index of the next run. When updating, all fields must be set.
@@ -9953,6 +10827,10 @@ pub struct TextFormatRun {
         rename = "startIndex"
     )]
     pub start_index: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Position settings for text.
@@ -9967,6 +10845,10 @@ pub struct TextPosition {
         rename = "horizontalAlignment"
     )]
     pub horizontal_alignment: Option<HorizontalAlignment>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The rotation applied to text in a cell.
@@ -9989,6 +10871,10 @@ pub struct TextRotation {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub vertical: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -10013,8 +10899,9 @@ pub enum DelimiterType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DelimiterType {
@@ -10028,7 +10915,7 @@ impl std::fmt::Display for DelimiterType {
             DelimiterType::Semicolon => "SEMICOLON",
             DelimiterType::Space => "SPACE",
             DelimiterType::Noop => "",
-            DelimiterType::FallthroughString => "*",
+            DelimiterType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -10066,6 +10953,10 @@ pub struct TextColumnsRequest {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub source: Option<GridRange>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A pair mapping a spreadsheet theme color type to the concrete color it represents.
@@ -10081,6 +10972,10 @@ pub struct ThemeColorPair {
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "colorType")]
     pub color_type: Option<ColorType>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Represents a time of day. The date and time zone are either not significant or are specified elsewhere. An API may choose to allow leap seconds. Related types are google.type.Date and `google.protobuf.Timestamp`.
@@ -10122,6 +11017,10 @@ pub struct TimeOfDay {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub seconds: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A color scale for a treemap chart.
@@ -10199,6 +11098,10 @@ pub struct TreemapChartColorScale {
         rename = "noDataColorStyle"
     )]
     pub no_data_color_style: Option<ColorStyle>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A Treemap chart.
@@ -10312,6 +11215,10 @@ pub struct TreemapChartSpec {
         rename = "textFormat"
     )]
     pub text_format: Option<TextFormat>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Trims the whitespace (such as spaces, tabs, or new lines) in every cell in the specified range. This request removes all whitespace from the start and end of each cell's text, and reduces any subsequence of remaining whitespace characters to a single space. If the resulting trimmed text starts with a '+' or '=' character, the text remains as a string value and isn't interpreted as a formula.
@@ -10322,6 +11229,10 @@ pub struct TrimWhitespaceRequest {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub range: Option<GridRange>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The result of trimming whitespace in cells.
@@ -10337,6 +11248,10 @@ pub struct TrimWhitespaceResponse {
         rename = "cellsChangedCount"
     )]
     pub cells_changed_count: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Unmerges cells in the given range.
@@ -10347,6 +11262,10 @@ pub struct UnmergeCellsRequest {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub range: Option<GridRange>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Updates properties of the supplied banded range.
@@ -10370,6 +11289,10 @@ pub struct UpdateBandingRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub fields: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Updates the borders of a range. If a field is not set in the request, that means the border remains as-is. For example, with two subsequent UpdateBordersRequest: 1. range: A1:A5 `{ top: RED, bottom: WHITE }` 2. range: A1:A5 `{ left: BLUE }` That would result in A1:A5 having a borders of `{ top: RED, bottom: WHITE, left: BLUE }`. If you want to clear a border, explicitly set the style to NONE.
@@ -10418,6 +11341,10 @@ pub struct UpdateBordersRequest {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub top: Option<Border>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Updates all cells in a range with new data.
@@ -10451,6 +11378,10 @@ pub struct UpdateCellsRequest {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub start: Option<GridCoordinate>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Updates a chart's specifications. (This does not move or resize a chart. To move or resize a chart, use UpdateEmbeddedObjectPositionRequest.)
@@ -10471,6 +11402,10 @@ pub struct UpdateChartSpecRequest {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub spec: Option<ChartSpec>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Updates a conditional format rule at the given index, or moves a conditional format rule to another index.
@@ -10510,6 +11445,10 @@ pub struct UpdateConditionalFormatRuleRequest {
         rename = "sheetId"
     )]
     pub sheet_id: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The result of updating a conditional format rule.
@@ -10545,6 +11484,10 @@ pub struct UpdateConditionalFormatRuleResponse {
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "oldRule")]
     pub old_rule: Option<ConditionalFormatRule>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Updates a data source. After the data source is updated successfully, an execution is triggered to refresh the associated DATA_SOURCE sheet to read data from the updated data source. The request requires an additional `bigquery.readonly` OAuth scope.
@@ -10568,6 +11511,10 @@ pub struct UpdateDataSourceRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub fields: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The response from updating data source.
@@ -10591,6 +11538,10 @@ pub struct UpdateDataSourceResponse {
         rename = "dataSource"
     )]
     pub data_source: Option<DataSource>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A request to update properties of developer metadata. Updates the properties of the developer metadata selected by the filters to the values provided in the DeveloperMetadata resource. Callers must specify the properties they wish to update in the fields parameter, as well as specify at least one DataFilter matching the metadata they wish to update.
@@ -10624,6 +11575,10 @@ pub struct UpdateDeveloperMetadataRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub fields: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The response from updating developer metadata.
@@ -10639,6 +11594,10 @@ pub struct UpdateDeveloperMetadataResponse {
         rename = "developerMetadata"
     )]
     pub developer_metadata: Vec<DeveloperMetadata>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Updates the state of the specified group.
@@ -10662,6 +11621,10 @@ pub struct UpdateDimensionGroupRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub fields: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Updates properties of dimensions within the specified range.
@@ -10695,6 +11658,10 @@ pub struct UpdateDimensionPropertiesRequest {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub range: Option<DimensionRange>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Updates an embedded object's border property.
@@ -10724,6 +11691,10 @@ pub struct UpdateEmbeddedObjectBorderRequest {
         rename = "objectId"
     )]
     pub object_id: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Update an embedded object's position (such as a moving or resizing a chart or image).
@@ -10757,6 +11728,10 @@ pub struct UpdateEmbeddedObjectPositionRequest {
         rename = "objectId"
     )]
     pub object_id: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The result of updating an embedded object's position.
@@ -10767,6 +11742,10 @@ pub struct UpdateEmbeddedObjectPositionResponse {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub position: Option<EmbeddedObjectPosition>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Updates properties of the filter view.
@@ -10786,6 +11765,10 @@ pub struct UpdateFilterViewRequest {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub filter: Option<FilterView>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Updates properties of the named range with the specified namedRangeId.
@@ -10809,6 +11792,10 @@ pub struct UpdateNamedRangeRequest {
         rename = "namedRange"
     )]
     pub named_range: Option<NamedRange>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Updates an existing protected range with the specified protectedRangeId.
@@ -10832,6 +11819,10 @@ pub struct UpdateProtectedRangeRequest {
         rename = "protectedRange"
     )]
     pub protected_range: Option<ProtectedRange>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Updates properties of the sheet with the specified sheetId.
@@ -10851,6 +11842,10 @@ pub struct UpdateSheetPropertiesRequest {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub properties: Option<SheetProperties>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Updates a slicer's specifications. (This does not move or resize a slicer. To move or resize a slicer use UpdateEmbeddedObjectPositionRequest.
@@ -10880,6 +11875,10 @@ pub struct UpdateSlicerSpecRequest {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub spec: Option<SlicerSpec>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Updates properties of a spreadsheet.
@@ -10899,6 +11898,10 @@ pub struct UpdateSpreadsheetPropertiesRequest {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub properties: Option<SpreadsheetProperties>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The response when updating a range of values by a data filter in a spreadsheet.
@@ -10962,6 +11965,10 @@ pub struct UpdateValuesByDataFilterResponse {
         rename = "updatedRows"
     )]
     pub updated_rows: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The response when updating a range of values in a spreadsheet.
@@ -11026,6 +12033,10 @@ pub struct UpdateValuesResponse {
         rename = "updatedRows"
     )]
     pub updated_rows: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Data within a range of the spreadsheet.
@@ -11058,6 +12069,10 @@ pub struct ValueRange {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub values: Vec<Vec<String>>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Styles for a waterfall chart column.
@@ -11086,6 +12101,10 @@ pub struct WaterfallChartColumnStyle {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub label: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A custom subtotal column for a waterfall chart series.
@@ -11119,6 +12138,10 @@ pub struct WaterfallChartCustomSubtotal {
         rename = "subtotalIndex"
     )]
     pub subtotal_index: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The domain of a waterfall chart.
@@ -11137,6 +12160,10 @@ pub struct WaterfallChartDomain {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub reversed: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A single series of data for a waterfall chart.
@@ -11198,6 +12225,10 @@ pub struct WaterfallChartSeries {
         rename = "subtotalColumnsStyle"
     )]
     pub subtotal_columns_style: Option<WaterfallChartColumnStyle>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -11214,8 +12245,9 @@ pub enum WaterfallChartSpecStackedType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for WaterfallChartSpecStackedType {
@@ -11227,7 +12259,7 @@ impl std::fmt::Display for WaterfallChartSpecStackedType {
                 "WATERFALL_STACKED_TYPE_UNSPECIFIED"
             }
             WaterfallChartSpecStackedType::Noop => "",
-            WaterfallChartSpecStackedType::FallthroughString => "*",
+            WaterfallChartSpecStackedType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -11301,6 +12333,10 @@ pub struct WaterfallChartSpec {
         rename = "totalDataLabel"
     )]
     pub total_data_label: Option<DataLabel>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -11315,8 +12351,9 @@ pub enum Xgafv {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Xgafv {
@@ -11325,7 +12362,7 @@ impl std::fmt::Display for Xgafv {
             Xgafv::One => "1",
             Xgafv::Two => "2",
             Xgafv::Noop => "",
-            Xgafv::FallthroughString => "*",
+            Xgafv::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -11351,8 +12388,9 @@ pub enum Alt {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Alt {
@@ -11362,7 +12400,7 @@ impl std::fmt::Display for Alt {
             Alt::Media => "media",
             Alt::Proto => "proto",
             Alt::Noop => "",
-            Alt::FallthroughString => "*",
+            Alt::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -11386,8 +12424,9 @@ pub enum InsertDataOption {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for InsertDataOption {
@@ -11396,7 +12435,7 @@ impl std::fmt::Display for InsertDataOption {
             InsertDataOption::InsertRows => "INSERT_ROWS",
             InsertDataOption::Overwrite => "OVERWRITE",
             InsertDataOption::Noop => "",
-            InsertDataOption::FallthroughString => "*",
+            InsertDataOption::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1 +1,16 @@
+use sheets::utils;
 
+#[test]
+fn test_next_link_picks_the_next_relation() {
+    let link = parse_link_header::parse(
+        "<https://sheets.googleapis.com/v4/spreadsheets?page=1>; rel=\"prev\", \
+         <https://sheets.googleapis.com/v4/spreadsheets?page=3>; rel=\"next\", \
+         <https://sheets.googleapis.com/v4/spreadsheets?page=9>; rel=\"last\"",
+    )
+    .unwrap();
+
+    assert_eq!(
+        utils::next_link(&link).unwrap().0,
+        "https://sheets.googleapis.com/v4/spreadsheets?page=3"
+    );
+}
@@ -73,6 +73,30 @@ impl AdminsBeta {
             )
             .await
     }
+    /**
+     * Get all the admins at a company.
+     *
+     * This function performs a `GET` to the `/v1/companies/{company_id}/admins` endpoint.
+     *
+     * As opposed to `get_all_company_admins`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * This endpoint is in beta and intended for **[Gusto Embedded Payroll](https://gusto.com/embedded-payroll)** customers. Please [apply for early access](https://gusto-embedded-payroll.typeform.com/to/iomAQIj3?utm_source=docs) if you’d like to learn more and use it for production. Note, this endpoint will require you to enter a different agreement with Gusto.
+     *
+     * Returns a list of all the admins at a company
+     */
+    pub fn get_all_company_admins_stream(
+        &self,
+        company_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Admin>> + Unpin + '_ {
+        let url = self.client.url(
+            &format!(
+                "/v1/companies/{}/admins",
+                crate::progenitor_support::encode_path(company_id),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Create an admin for the company.
      *
@@ -59,6 +59,24 @@ impl Benefits {
             )
             .await
     }
+    /**
+     * Get all benefits supported by Gusto.
+     *
+     * This function performs a `GET` to the `/v1/benefits` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Returns all benefits supported by Gusto.
+     *
+     * The benefit object in Gusto contains high level information about a particular benefit type and its tax considerations. When companies choose to offer a benefit, they are creating a Company Benefit object associated with a particular benefit.
+     */
+    pub fn get_all_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::SupportedBenefit>> + Unpin + '_
+    {
+        let url = self.client.url("/v1/benefits", None);
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Get a supported benefit by ID.
      *
@@ -151,6 +169,30 @@ impl Benefits {
             )
             .await
     }
+    /**
+     * Get benefits for a company.
+     *
+     * This function performs a `GET` to the `/v1/companies/{company_id}/company_benefits` endpoint.
+     *
+     * As opposed to `get_all_company`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Company benefits represent the benefits that a company is offering to employees. This ties together a particular supported benefit with the company-specific information for the offering of that benefit.
+     *
+     * Note that company benefits can be deactivated only when no employees are enrolled.
+     */
+    pub fn get_all_company_stream(
+        &self,
+        company_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::CompanyBenefit>> + Unpin + '_ {
+        let url = self.client.url(
+            &format!(
+                "/v1/companies/{}/company_benefits",
+                crate::progenitor_support::encode_path(company_id),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Create a company benefit.
      *
@@ -305,6 +347,30 @@ impl Benefits {
             )
             .await
     }
+    /**
+     * Get an employee's benefits.
+     *
+     * This function performs a `GET` to the `/v1/employees/{employee_id}/employee_benefits` endpoint.
+     *
+     * As opposed to `get_all_employee`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Employee benefits represent an employee enrolled in a particular company benefit. It includes information specific to that employee’s enrollment.
+     *
+     * Returns an array of all employee benefits for this employee
+     */
+    pub fn get_all_employee_stream(
+        &self,
+        employee_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::EmployeeBenefit>> + Unpin + '_ {
+        let url = self.client.url(
+            &format!(
+                "/v1/employees/{}/employee_benefits",
+                crate::progenitor_support::encode_path(employee_id),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Create an employee benefit.
      *
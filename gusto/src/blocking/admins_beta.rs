@@ -0,0 +1,44 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::admins_beta::AdminsBeta`].
+pub struct AdminsBeta {
+    inner: crate::admins_beta::AdminsBeta,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl AdminsBeta {
+    pub(crate) fn new(
+        inner: crate::admins_beta::AdminsBeta,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        AdminsBeta { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::admins_beta::AdminsBeta::get_company_admins`].
+    pub fn get_company_admins(
+        &self,
+        company_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Admin>>> {
+        self.runtime
+            .block_on(self.inner.get_company_admins(company_id))
+    }
+    /// The blocking version of [`crate::admins_beta::AdminsBeta::get_all_company_admins`].
+    pub fn get_all_company_admins(
+        &self,
+        company_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Admin>>> {
+        self.runtime
+            .block_on(self.inner.get_all_company_admins(company_id))
+    }
+    /// The blocking version of [`crate::admins_beta::AdminsBeta::post_company_admin`].
+    pub fn post_company_admin(
+        &self,
+        company_id: &str,
+        body: &crate::types::PostCompanyAdminsRequest,
+    ) -> ClientResult<crate::Response<crate::types::Admin>> {
+        self.runtime
+            .block_on(self.inner.post_company_admin(company_id, body))
+    }
+}
@@ -0,0 +1,132 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::benefits::Benefits`].
+pub struct Benefits {
+    inner: crate::benefits::Benefits,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Benefits {
+    pub(crate) fn new(
+        inner: crate::benefits::Benefits,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Benefits { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::benefits::Benefits::get_page`].
+    pub fn get_page(&self) -> ClientResult<crate::Response<Vec<crate::types::SupportedBenefit>>> {
+        self.runtime.block_on(self.inner.get_page())
+    }
+    /// The blocking version of [`crate::benefits::Benefits::get_all`].
+    pub fn get_all(&self) -> ClientResult<crate::Response<Vec<crate::types::SupportedBenefit>>> {
+        self.runtime.block_on(self.inner.get_all())
+    }
+    /// The blocking version of [`crate::benefits::Benefits::get`].
+    pub fn get(
+        &self,
+        benefit_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::SupportedBenefit>> {
+        self.runtime.block_on(self.inner.get(benefit_id))
+    }
+    /// The blocking version of [`crate::benefits::Benefits::get_company`].
+    pub fn get_company(
+        &self,
+        company_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::CompanyBenefit>>> {
+        self.runtime.block_on(self.inner.get_company(company_id))
+    }
+    /// The blocking version of [`crate::benefits::Benefits::get_all_company`].
+    pub fn get_all_company(
+        &self,
+        company_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::CompanyBenefit>>> {
+        self.runtime
+            .block_on(self.inner.get_all_company(company_id))
+    }
+    /// The blocking version of [`crate::benefits::Benefits::post_company`].
+    pub fn post_company(
+        &self,
+        company_id: &str,
+        body: &crate::types::PostCompanyBenefitsRequest,
+    ) -> ClientResult<crate::Response<crate::types::CompanyBenefit>> {
+        self.runtime
+            .block_on(self.inner.post_company(company_id, body))
+    }
+    /// The blocking version of [`crate::benefits::Benefits::get_company_benefits`].
+    pub fn get_company_benefits(
+        &self,
+        company_benefit_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::CompanyBenefit>> {
+        self.runtime
+            .block_on(self.inner.get_company_benefits(company_benefit_id))
+    }
+    /// The blocking version of [`crate::benefits::Benefits::put_company`].
+    pub fn put_company(
+        &self,
+        company_benefit_id: &str,
+        body: &crate::types::PutCompanyBenefitRequest,
+    ) -> ClientResult<crate::Response<crate::types::CompanyBenefit>> {
+        self.runtime
+            .block_on(self.inner.put_company(company_benefit_id, body))
+    }
+    /// The blocking version of [`crate::benefits::Benefits::get_employee`].
+    pub fn get_employee(
+        &self,
+        employee_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::EmployeeBenefit>>> {
+        self.runtime.block_on(self.inner.get_employee(employee_id))
+    }
+    /// The blocking version of [`crate::benefits::Benefits::get_all_employee`].
+    pub fn get_all_employee(
+        &self,
+        employee_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::EmployeeBenefit>>> {
+        self.runtime
+            .block_on(self.inner.get_all_employee(employee_id))
+    }
+    /// The blocking version of [`crate::benefits::Benefits::post_employee`].
+    pub fn post_employee(
+        &self,
+        employee_id: &str,
+        body: &crate::types::PostEmployeeBenefitsRequest,
+    ) -> ClientResult<crate::Response<crate::types::EmployeeBenefit>> {
+        self.runtime
+            .block_on(self.inner.post_employee(employee_id, body))
+    }
+    /// The blocking version of [`crate::benefits::Benefits::post_employee_ytd_amounts_from_different_company`].
+    pub fn post_employee_ytd_amounts_from_different_company(
+        &self,
+        employee_id: &str,
+        body: &crate::types::PostEmployeeYtdBenefitAmountsFromDifferentCompanyRequest,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .post_employee_ytd_amounts_from_different_company(employee_id, body),
+        )
+    }
+    /// The blocking version of [`crate::benefits::Benefits::get_employee_benefits`].
+    pub fn get_employee_benefits(
+        &self,
+        employee_benefit_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::EmployeeBenefit>> {
+        self.runtime
+            .block_on(self.inner.get_employee_benefits(employee_benefit_id))
+    }
+    /// The blocking version of [`crate::benefits::Benefits::put_employee`].
+    pub fn put_employee(
+        &self,
+        employee_benefit_id: &str,
+        body: &crate::types::PutEmployeeBenefitRequest,
+    ) -> ClientResult<crate::Response<crate::types::EmployeeBenefit>> {
+        self.runtime
+            .block_on(self.inner.put_employee(employee_benefit_id, body))
+    }
+    /// The blocking version of [`crate::benefits::Benefits::delete_employee`].
+    pub fn delete_employee(&self, employee_benefit_id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete_employee(employee_benefit_id))
+    }
+}
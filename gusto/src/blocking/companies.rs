@@ -0,0 +1,40 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::companies::Companies`].
+pub struct Companies {
+    inner: crate::companies::Companies,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Companies {
+    pub(crate) fn new(
+        inner: crate::companies::Companies,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Companies { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::companies::Companies::get`].
+    pub fn get(
+        &self,
+        company_id_or_uuid: &str,
+    ) -> ClientResult<crate::Response<crate::types::Company>> {
+        self.runtime.block_on(self.inner.get(company_id_or_uuid))
+    }
+    /// The blocking version of [`crate::companies::Companies::post_partner_managed`].
+    pub fn post_partner_managed(
+        &self,
+        body: &crate::types::PostPartnerManagedCompaniesRequest,
+    ) -> ClientResult<crate::Response<crate::types::PostPartnerManagedCompaniesResponse>> {
+        self.runtime.block_on(self.inner.post_partner_managed(body))
+    }
+    /// The blocking version of [`crate::companies::Companies::post_provision`].
+    pub fn post_provision(
+        &self,
+        body: &crate::types::PostProvisionRequest,
+    ) -> ClientResult<crate::Response<crate::types::PostProvisionResponse>> {
+        self.runtime.block_on(self.inner.post_provision(body))
+    }
+}
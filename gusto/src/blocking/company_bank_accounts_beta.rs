@@ -0,0 +1,60 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::company_bank_accounts_beta::CompanyBankAccountsBeta`].
+pub struct CompanyBankAccountsBeta {
+    inner: crate::company_bank_accounts_beta::CompanyBankAccountsBeta,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl CompanyBankAccountsBeta {
+    pub(crate) fn new(
+        inner: crate::company_bank_accounts_beta::CompanyBankAccountsBeta,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        CompanyBankAccountsBeta { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::company_bank_accounts_beta::CompanyBankAccountsBeta::get_company_bank_accounts`].
+    pub fn get_company_bank_accounts(
+        &self,
+        company_id_or_uuid: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::CompanyBankAccount>>> {
+        self.runtime
+            .block_on(self.inner.get_company_bank_accounts(company_id_or_uuid))
+    }
+    /// The blocking version of [`crate::company_bank_accounts_beta::CompanyBankAccountsBeta::get_all_company_bank_accounts`].
+    pub fn get_all_company_bank_accounts(
+        &self,
+        company_id_or_uuid: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::CompanyBankAccount>>> {
+        self.runtime
+            .block_on(self.inner.get_all_company_bank_accounts(company_id_or_uuid))
+    }
+    /// The blocking version of [`crate::company_bank_accounts_beta::CompanyBankAccountsBeta::post_company_bank_account`].
+    pub fn post_company_bank_account(
+        &self,
+        company_id_or_uuid: &str,
+        body: &crate::types::PostCompanyBankAccountsRequest,
+    ) -> ClientResult<crate::Response<crate::types::CompanyBankAccount>> {
+        self.runtime.block_on(
+            self.inner
+                .post_company_bank_account(company_id_or_uuid, body),
+        )
+    }
+    /// The blocking version of [`crate::company_bank_accounts_beta::CompanyBankAccountsBeta::put_company_bank_accounts_verify`].
+    pub fn put_company_bank_accounts_verify(
+        &self,
+        company_id_or_uuid: &str,
+        bank_account_uuid: &str,
+        body: &crate::types::PutCompanyBankAccountsVerifyRequest,
+    ) -> ClientResult<crate::Response<crate::types::CompanyBankAccount>> {
+        self.runtime
+            .block_on(self.inner.put_company_bank_accounts_verify(
+                company_id_or_uuid,
+                bank_account_uuid,
+                body,
+            ))
+    }
+}
@@ -0,0 +1,48 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::compensations::Compensations`].
+pub struct Compensations {
+    inner: crate::compensations::Compensations,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Compensations {
+    pub(crate) fn new(
+        inner: crate::compensations::Compensations,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Compensations { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::compensations::Compensations::get`].
+    pub fn get(
+        &self,
+        compensation_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Compensation>> {
+        self.runtime.block_on(self.inner.get(compensation_id))
+    }
+    /// The blocking version of [`crate::compensations::Compensations::put`].
+    pub fn put(
+        &self,
+        compensation_id: &str,
+        body: &crate::types::PutCompensationRequest,
+    ) -> ClientResult<crate::Response<crate::types::Compensation>> {
+        self.runtime.block_on(self.inner.put(compensation_id, body))
+    }
+    /// The blocking version of [`crate::compensations::Compensations::get_job`].
+    pub fn get_job(
+        &self,
+        job_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Compensation>>> {
+        self.runtime.block_on(self.inner.get_job(job_id))
+    }
+    /// The blocking version of [`crate::compensations::Compensations::get_all_job`].
+    pub fn get_all_job(
+        &self,
+        job_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Compensation>>> {
+        self.runtime.block_on(self.inner.get_all_job(job_id))
+    }
+}
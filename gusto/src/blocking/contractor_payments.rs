@@ -0,0 +1,158 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`ContractorPayments::get_company`], set with a builder.
+pub struct ContractorPaymentsGetCompanyRequest<'a> {
+    inner: crate::contractor_payments::ContractorPaymentsGetCompanyRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ContractorPaymentsGetCompanyRequest<'a> {
+    /// Sets the `start_date` query parameter.
+    pub fn start_date(mut self, start_date: &'a str) -> Self {
+        self.inner = self.inner.start_date(start_date);
+        self
+    }
+
+    /// Sets the `end_date` query parameter.
+    pub fn end_date(mut self, end_date: &'a str) -> Self {
+        self.inner = self.inner.end_date(end_date);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::ContractorPaymentSummary>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`ContractorPayments::post_company`], set with a builder.
+pub struct ContractorPaymentsPostCompanyRequest<'a> {
+    inner: crate::contractor_payments::ContractorPaymentsPostCompanyRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ContractorPaymentsPostCompanyRequest<'a> {
+    /// Sets the `wage` query parameter.
+    pub fn wage(mut self, wage: f64) -> Self {
+        self.inner = self.inner.wage(wage);
+        self
+    }
+
+    /// Sets the `hours` query parameter.
+    pub fn hours(mut self, hours: f64) -> Self {
+        self.inner = self.inner.hours(hours);
+        self
+    }
+
+    /// Sets the `bonus` query parameter.
+    pub fn bonus(mut self, bonus: f64) -> Self {
+        self.inner = self.inner.bonus(bonus);
+        self
+    }
+
+    /// Sets the `reimbursement` query parameter.
+    pub fn reimbursement(mut self, reimbursement: f64) -> Self {
+        self.inner = self.inner.reimbursement(reimbursement);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::ContractorPayment>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::contractor_payments::ContractorPayments`].
+pub struct ContractorPayments {
+    inner: crate::contractor_payments::ContractorPayments,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl ContractorPayments {
+    pub(crate) fn new(
+        inner: crate::contractor_payments::ContractorPayments,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        ContractorPayments { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::contractor_payments::ContractorPayments::get_company`].
+    pub fn get_company(
+        &self,
+        company_id: &str,
+        start_date: &str,
+        end_date: &str,
+    ) -> ClientResult<crate::Response<crate::types::ContractorPaymentSummary>> {
+        self.runtime
+            .block_on(self.inner.get_company(company_id, start_date, end_date))
+    }
+    /// The blocking version of [`crate::contractor_payments::ContractorPayments::get_company_request`].
+    pub fn get_company_request<'a>(
+        &'a self,
+        company_id: &'a str,
+    ) -> ContractorPaymentsGetCompanyRequest<'a> {
+        ContractorPaymentsGetCompanyRequest {
+            inner: self.inner.get_company_request(company_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::contractor_payments::ContractorPayments::post_company`].
+    pub fn post_company(
+        &self,
+        company_id: &str,
+        date: &str,
+        contractor_id: f64,
+        wage: f64,
+        hours: f64,
+        bonus: f64,
+        reimbursement: f64,
+    ) -> ClientResult<crate::Response<crate::types::ContractorPayment>> {
+        self.runtime.block_on(self.inner.post_company(
+            company_id,
+            date,
+            contractor_id,
+            wage,
+            hours,
+            bonus,
+            reimbursement,
+        ))
+    }
+    /// The blocking version of [`crate::contractor_payments::ContractorPayments::post_company_request`].
+    pub fn post_company_request<'a>(
+        &'a self,
+        company_id: &'a str,
+        date: &'a str,
+        contractor_id: f64,
+    ) -> ContractorPaymentsPostCompanyRequest<'a> {
+        ContractorPaymentsPostCompanyRequest {
+            inner: self
+                .inner
+                .post_company_request(company_id, date, contractor_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::contractor_payments::ContractorPayments::get_company_contractor_payments`].
+    pub fn get_company_contractor_payments(
+        &self,
+        company_id: &str,
+        contractor_payment_id_or_uuid: &str,
+    ) -> ClientResult<crate::Response<crate::types::ContractorPayment>> {
+        self.runtime.block_on(
+            self.inner
+                .get_company_contractor_payments(company_id, contractor_payment_id_or_uuid),
+        )
+    }
+    /// The blocking version of [`crate::contractor_payments::ContractorPayments::delete_company`].
+    pub fn delete_company(
+        &self,
+        company_id: &str,
+        contractor_payment_id_or_uuid: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .delete_company(company_id, contractor_payment_id_or_uuid),
+        )
+    }
+}
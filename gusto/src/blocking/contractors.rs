@@ -0,0 +1,60 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::contractors::Contractors`].
+pub struct Contractors {
+    inner: crate::contractors::Contractors,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Contractors {
+    pub(crate) fn new(
+        inner: crate::contractors::Contractors,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Contractors { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::contractors::Contractors::get`].
+    pub fn get(
+        &self,
+        contractor_id_or_uuid: &str,
+    ) -> ClientResult<crate::Response<crate::types::Contractor>> {
+        self.runtime.block_on(self.inner.get(contractor_id_or_uuid))
+    }
+    /// The blocking version of [`crate::contractors::Contractors::put`].
+    pub fn put(
+        &self,
+        contractor_id_or_uuid: &str,
+        body: &crate::types::PutComntractorRequest,
+    ) -> ClientResult<crate::Response<crate::types::Contractor>> {
+        self.runtime
+            .block_on(self.inner.put(contractor_id_or_uuid, body))
+    }
+    /// The blocking version of [`crate::contractors::Contractors::get_company`].
+    pub fn get_company(
+        &self,
+        company_id_or_uuid: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Contractor>>> {
+        self.runtime
+            .block_on(self.inner.get_company(company_id_or_uuid))
+    }
+    /// The blocking version of [`crate::contractors::Contractors::get_all_company`].
+    pub fn get_all_company(
+        &self,
+        company_id_or_uuid: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Contractor>>> {
+        self.runtime
+            .block_on(self.inner.get_all_company(company_id_or_uuid))
+    }
+    /// The blocking version of [`crate::contractors::Contractors::post_company`].
+    pub fn post_company(
+        &self,
+        company_id_or_uuid: &str,
+        body: &crate::types::PostCompanyContractorsRequest,
+    ) -> ClientResult<crate::Response<crate::types::Contractor>> {
+        self.runtime
+            .block_on(self.inner.post_company(company_id_or_uuid, body))
+    }
+}
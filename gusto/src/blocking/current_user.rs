@@ -0,0 +1,23 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::current_user::CurrentUser`].
+pub struct CurrentUser {
+    inner: crate::current_user::CurrentUser,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl CurrentUser {
+    pub(crate) fn new(
+        inner: crate::current_user::CurrentUser,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        CurrentUser { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::current_user::CurrentUser::get_me`].
+    pub fn get_me(&self) -> ClientResult<crate::Response<crate::types::CurrentUser>> {
+        self.runtime.block_on(self.inner.get_me())
+    }
+}
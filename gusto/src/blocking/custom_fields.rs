@@ -0,0 +1,33 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::custom_fields::CustomFields`].
+pub struct CustomFields {
+    inner: crate::custom_fields::CustomFields,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl CustomFields {
+    pub(crate) fn new(
+        inner: crate::custom_fields::CustomFields,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        CustomFields { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::custom_fields::CustomFields::get_employee`].
+    pub fn get_employee(
+        &self,
+        employee_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::GetEmployeeCustomFieldsResponse>> {
+        self.runtime.block_on(self.inner.get_employee(employee_id))
+    }
+    /// The blocking version of [`crate::custom_fields::CustomFields::get_company`].
+    pub fn get_company(
+        &self,
+        company_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::GetCompanyCustomFieldsResponse>> {
+        self.runtime.block_on(self.inner.get_company(company_id))
+    }
+}
@@ -0,0 +1,58 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::earning_type::EarningType`].
+pub struct EarningType {
+    inner: crate::earning_type::EarningType,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl EarningType {
+    pub(crate) fn new(
+        inner: crate::earning_type::EarningType,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        EarningType { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::earning_type::EarningType::get_company`].
+    pub fn get_company(
+        &self,
+        company_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::EarningTypeListResponse>> {
+        self.runtime.block_on(self.inner.get_company(company_id))
+    }
+    /// The blocking version of [`crate::earning_type::EarningType::post_company`].
+    pub fn post_company(
+        &self,
+        company_id: &str,
+        body: &crate::types::PostCompanyEarningTypesRequest,
+    ) -> ClientResult<crate::Response<crate::types::EarningType>> {
+        self.runtime
+            .block_on(self.inner.post_company(company_id, body))
+    }
+    /// The blocking version of [`crate::earning_type::EarningType::put_company_type`].
+    pub fn put_company_type(
+        &self,
+        company_id: &str,
+        earning_type_uuid: &str,
+        body: &crate::types::PutCompanyEarningTypeRequest,
+    ) -> ClientResult<crate::Response<crate::types::EarningType>> {
+        self.runtime.block_on(
+            self.inner
+                .put_company_type(company_id, earning_type_uuid, body),
+        )
+    }
+    /// The blocking version of [`crate::earning_type::EarningType::delete_company_type`].
+    pub fn delete_company_type(
+        &self,
+        company_id: &str,
+        earning_type_uuid: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .delete_company_type(company_id, earning_type_uuid),
+        )
+    }
+}
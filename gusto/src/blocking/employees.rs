@@ -0,0 +1,165 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Employees::get`], set with a builder.
+pub struct EmployeesGetRequest<'a> {
+    inner: crate::employees::EmployeesGetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> EmployeesGetRequest<'a> {
+    /// Sets the `include` query parameter.
+    pub fn include(mut self, include: &'a [String]) -> Self {
+        self.inner = self.inner.include(include);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::Employee>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Employees::get_company`], set with a builder.
+pub struct EmployeesGetCompanyRequest<'a> {
+    inner: crate::employees::EmployeesGetCompanyRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> EmployeesGetCompanyRequest<'a> {
+    /// Sets the `terminated` query parameter.
+    pub fn terminated(mut self, terminated: bool) -> Self {
+        self.inner = self.inner.terminated(terminated);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: f64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sets the `per` query parameter.
+    pub fn per(mut self, per: f64) -> Self {
+        self.inner = self.inner.per(per);
+        self
+    }
+
+    /// Sets the `include` query parameter.
+    pub fn include(mut self, include: &'a [String]) -> Self {
+        self.inner = self.inner.include(include);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Employee>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::employees::Employees`].
+pub struct Employees {
+    inner: crate::employees::Employees,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Employees {
+    pub(crate) fn new(
+        inner: crate::employees::Employees,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Employees { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::employees::Employees::get`].
+    pub fn get(
+        &self,
+        employee_id_or_uuid: &str,
+        include: &[String],
+    ) -> ClientResult<crate::Response<crate::types::Employee>> {
+        self.runtime
+            .block_on(self.inner.get(employee_id_or_uuid, include))
+    }
+    /// The blocking version of [`crate::employees::Employees::get_request`].
+    pub fn get_request<'a>(&'a self, employee_id_or_uuid: &'a str) -> EmployeesGetRequest<'a> {
+        EmployeesGetRequest {
+            inner: self.inner.get_request(employee_id_or_uuid),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::employees::Employees::put`].
+    pub fn put(
+        &self,
+        employee_id_or_uuid: &str,
+        body: &crate::types::PutEmployeesRequest,
+    ) -> ClientResult<crate::Response<crate::types::Employee>> {
+        self.runtime
+            .block_on(self.inner.put(employee_id_or_uuid, body))
+    }
+    /// The blocking version of [`crate::employees::Employees::get_company`].
+    pub fn get_company(
+        &self,
+        company_id_or_uuid: &str,
+        terminated: bool,
+        page: f64,
+        per: f64,
+        include: &[String],
+    ) -> ClientResult<crate::Response<Vec<crate::types::Employee>>> {
+        self.runtime.block_on(self.inner.get_company(
+            company_id_or_uuid,
+            terminated,
+            page,
+            per,
+            include,
+        ))
+    }
+    /// The blocking version of [`crate::employees::Employees::get_company_request`].
+    pub fn get_company_request<'a>(
+        &'a self,
+        company_id_or_uuid: &'a str,
+    ) -> EmployeesGetCompanyRequest<'a> {
+        EmployeesGetCompanyRequest {
+            inner: self.inner.get_company_request(company_id_or_uuid),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::employees::Employees::get_all_company`].
+    pub fn get_all_company(
+        &self,
+        company_id_or_uuid: &str,
+        terminated: bool,
+        include: &[String],
+    ) -> ClientResult<crate::Response<Vec<crate::types::Employee>>> {
+        self.runtime.block_on(
+            self.inner
+                .get_all_company(company_id_or_uuid, terminated, include),
+        )
+    }
+    /// The blocking version of [`crate::employees::Employees::post`].
+    pub fn post(
+        &self,
+        company_id_or_uuid: &str,
+        body: &crate::types::PostEmployeesRequest,
+    ) -> ClientResult<crate::Response<crate::types::Employee>> {
+        self.runtime
+            .block_on(self.inner.post(company_id_or_uuid, body))
+    }
+    /// The blocking version of [`crate::employees::Employees::get_home_address`].
+    pub fn get_home_address(
+        &self,
+        employee_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Location>> {
+        self.runtime
+            .block_on(self.inner.get_home_address(employee_id))
+    }
+    /// The blocking version of [`crate::employees::Employees::put_home_address`].
+    pub fn put_home_address(
+        &self,
+        employee_id: &str,
+        body: &crate::types::PutEmployeeHomeAddressRequest,
+    ) -> ClientResult<crate::Response<crate::types::Location>> {
+        self.runtime
+            .block_on(self.inner.put_home_address(employee_id, body))
+    }
+}
@@ -0,0 +1,58 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::garnishments::Garnishments`].
+pub struct Garnishments {
+    inner: crate::garnishments::Garnishments,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Garnishments {
+    pub(crate) fn new(
+        inner: crate::garnishments::Garnishments,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Garnishments { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::garnishments::Garnishments::get_employee`].
+    pub fn get_employee(
+        &self,
+        employee_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Garnishment>>> {
+        self.runtime.block_on(self.inner.get_employee(employee_id))
+    }
+    /// The blocking version of [`crate::garnishments::Garnishments::get_all_employee`].
+    pub fn get_all_employee(
+        &self,
+        employee_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Garnishment>>> {
+        self.runtime
+            .block_on(self.inner.get_all_employee(employee_id))
+    }
+    /// The blocking version of [`crate::garnishments::Garnishments::post_employee`].
+    pub fn post_employee(
+        &self,
+        employee_id: &str,
+        body: &crate::types::PostEmployeeGarnishmentsRequest,
+    ) -> ClientResult<crate::Response<crate::types::Garnishment>> {
+        self.runtime
+            .block_on(self.inner.post_employee(employee_id, body))
+    }
+    /// The blocking version of [`crate::garnishments::Garnishments::get`].
+    pub fn get(
+        &self,
+        garnishment_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Garnishment>> {
+        self.runtime.block_on(self.inner.get(garnishment_id))
+    }
+    /// The blocking version of [`crate::garnishments::Garnishments::put`].
+    pub fn put(
+        &self,
+        garnishment_id: &str,
+        body: &crate::types::PutGarnishmentRequest,
+    ) -> ClientResult<crate::Response<crate::types::Garnishment>> {
+        self.runtime.block_on(self.inner.put(garnishment_id, body))
+    }
+}
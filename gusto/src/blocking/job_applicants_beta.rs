@@ -0,0 +1,80 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::job_applicants_beta::JobApplicantsBeta`].
+pub struct JobApplicantsBeta {
+    inner: crate::job_applicants_beta::JobApplicantsBeta,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl JobApplicantsBeta {
+    pub(crate) fn new(
+        inner: crate::job_applicants_beta::JobApplicantsBeta,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        JobApplicantsBeta { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::job_applicants_beta::JobApplicantsBeta::get_company_job_applicants`].
+    pub fn get_company_job_applicants(
+        &self,
+        company_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::JobApplicant>>> {
+        self.runtime
+            .block_on(self.inner.get_company_job_applicants(company_id))
+    }
+    /// The blocking version of [`crate::job_applicants_beta::JobApplicantsBeta::get_all_company_job_applicants`].
+    pub fn get_all_company_job_applicants(
+        &self,
+        company_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::JobApplicant>>> {
+        self.runtime
+            .block_on(self.inner.get_all_company_job_applicants(company_id))
+    }
+    /// The blocking version of [`crate::job_applicants_beta::JobApplicantsBeta::post_company_job_applicant`].
+    pub fn post_company_job_applicant(
+        &self,
+        company_id: &str,
+        body: &crate::types::PostCompanyJobApplicantsRequest,
+    ) -> ClientResult<crate::Response<crate::types::JobApplicant>> {
+        self.runtime
+            .block_on(self.inner.post_company_job_applicant(company_id, body))
+    }
+    /// The blocking version of [`crate::job_applicants_beta::JobApplicantsBeta::get_company_job_applicants_applicant`].
+    pub fn get_company_job_applicants_applicant(
+        &self,
+        company_id: &str,
+        job_applicant_uuid: &str,
+    ) -> ClientResult<crate::Response<crate::types::JobApplicant>> {
+        self.runtime.block_on(
+            self.inner
+                .get_company_job_applicants_applicant(company_id, job_applicant_uuid),
+        )
+    }
+    /// The blocking version of [`crate::job_applicants_beta::JobApplicantsBeta::put_company_job_applicants_applicant`].
+    pub fn put_company_job_applicants_applicant(
+        &self,
+        company_id: &str,
+        job_applicant_uuid: &str,
+        body: &crate::types::PutCompanyJobApplicantRequest,
+    ) -> ClientResult<crate::Response<crate::types::JobApplicant>> {
+        self.runtime
+            .block_on(self.inner.put_company_job_applicants_applicant(
+                company_id,
+                job_applicant_uuid,
+                body,
+            ))
+    }
+    /// The blocking version of [`crate::job_applicants_beta::JobApplicantsBeta::delete_company_job_applicants_applicant`].
+    pub fn delete_company_job_applicants_applicant(
+        &self,
+        company_id: &str,
+        job_applicant_uuid: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .delete_company_job_applicants_applicant(company_id, job_applicant_uuid),
+        )
+    }
+}
@@ -0,0 +1,64 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::jobs::Jobs`].
+pub struct Jobs {
+    inner: crate::jobs::Jobs,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Jobs {
+    pub(crate) fn new(inner: crate::jobs::Jobs, runtime: Arc<tokio::runtime::Runtime>) -> Self {
+        Jobs { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::jobs::Jobs::get`].
+    pub fn get(&self, job_id: &str) -> ClientResult<crate::Response<crate::types::Job>> {
+        self.runtime.block_on(self.inner.get(job_id))
+    }
+    /// The blocking version of [`crate::jobs::Jobs::put`].
+    pub fn put(
+        &self,
+        job_id: &str,
+        body: &crate::types::PutJobRequest,
+    ) -> ClientResult<crate::Response<crate::types::Job>> {
+        self.runtime.block_on(self.inner.put(job_id, body))
+    }
+    /// The blocking version of [`crate::jobs::Jobs::delete`].
+    pub fn delete(&self, job_id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.delete(job_id))
+    }
+    /// The blocking version of [`crate::jobs::Jobs::get_employee`].
+    pub fn get_employee(
+        &self,
+        employee_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Job>>> {
+        self.runtime.block_on(self.inner.get_employee(employee_id))
+    }
+    /// The blocking version of [`crate::jobs::Jobs::get_all_employee`].
+    pub fn get_all_employee(
+        &self,
+        employee_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Job>>> {
+        self.runtime
+            .block_on(self.inner.get_all_employee(employee_id))
+    }
+    /// The blocking version of [`crate::jobs::Jobs::post`].
+    pub fn post(
+        &self,
+        employee_id: &str,
+        body: &crate::types::PostJobRequest,
+    ) -> ClientResult<crate::Response<crate::types::Job>> {
+        self.runtime.block_on(self.inner.post(employee_id, body))
+    }
+    /// The blocking version of [`crate::jobs::Jobs::post_compensation`].
+    pub fn post_compensation(
+        &self,
+        job_id: &str,
+        body: &crate::types::PostJobCompensationsRequest,
+    ) -> ClientResult<crate::Response<crate::types::Compensation>> {
+        self.runtime
+            .block_on(self.inner.post_compensation(job_id, body))
+    }
+}
@@ -0,0 +1,56 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::locations::Locations`].
+pub struct Locations {
+    inner: crate::locations::Locations,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Locations {
+    pub(crate) fn new(
+        inner: crate::locations::Locations,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Locations { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::locations::Locations::get_company`].
+    pub fn get_company(
+        &self,
+        company_id_or_uuid: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Location>>> {
+        self.runtime
+            .block_on(self.inner.get_company(company_id_or_uuid))
+    }
+    /// The blocking version of [`crate::locations::Locations::get_all_company`].
+    pub fn get_all_company(
+        &self,
+        company_id_or_uuid: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Location>>> {
+        self.runtime
+            .block_on(self.inner.get_all_company(company_id_or_uuid))
+    }
+    /// The blocking version of [`crate::locations::Locations::post_company`].
+    pub fn post_company(
+        &self,
+        company_id_or_uuid: &str,
+        body: &crate::types::PostCompanyLocationsRequest,
+    ) -> ClientResult<crate::Response<crate::types::Location>> {
+        self.runtime
+            .block_on(self.inner.post_company(company_id_or_uuid, body))
+    }
+    /// The blocking version of [`crate::locations::Locations::get`].
+    pub fn get(&self, location_id: &str) -> ClientResult<crate::Response<crate::types::Location>> {
+        self.runtime.block_on(self.inner.get(location_id))
+    }
+    /// The blocking version of [`crate::locations::Locations::put`].
+    pub fn put(
+        &self,
+        location_id: &str,
+        body: &crate::types::PutLocationRequest,
+    ) -> ClientResult<crate::Response<crate::types::Location>> {
+        self.runtime.block_on(self.inner.put(location_id, body))
+    }
+}
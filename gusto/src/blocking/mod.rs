@@ -0,0 +1,168 @@
+//! A blocking client, for the programs that are not async.
+//!
+//! It has the functions of the async [`Client`](crate::Client), which it runs on a
+//! runtime of its own, so they must not be called from within an async runtime. The
+//! `*_stream` functions are left out, the `*_all` ones returning all the pages at once.
+
+use std::{future::Future, sync::Arc};
+
+pub mod admins_beta;
+pub mod benefits;
+pub mod companies;
+pub mod company_bank_accounts_beta;
+pub mod compensations;
+pub mod contractor_payments;
+pub mod contractors;
+pub mod current_user;
+pub mod custom_fields;
+pub mod earning_type;
+pub mod employees;
+pub mod garnishments;
+pub mod job_applicants_beta;
+pub mod jobs;
+pub mod locations;
+pub mod pay_schedules;
+pub mod payroll;
+pub mod terminations;
+pub mod time_off_requests;
+
+/// A blocking client, sending its requests with an async [`Client`](crate::Client).
+#[derive(Clone)]
+pub struct Client {
+    inner: crate::Client,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Client {
+    /// Wraps an async client, creating the runtime its requests run on.
+    pub fn new(client: crate::Client) -> std::io::Result<Self> {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()?;
+
+        Ok(Client {
+            inner: client,
+            runtime: Arc::new(runtime),
+        })
+    }
+
+    /// Returns the async client the requests are sent with.
+    pub fn inner(&self) -> &crate::Client {
+        &self.inner
+    }
+
+    /// Runs a future to completion on the runtime of the client, for the async
+    /// functions that have no blocking version.
+    pub fn block_on<F: Future>(&self, future: F) -> F::Output {
+        self.runtime.block_on(future)
+    }
+
+    /// The blocking version of [`crate::Client::admins_beta`].
+    pub fn admins_beta(&self) -> admins_beta::AdminsBeta {
+        admins_beta::AdminsBeta::new(self.inner.admins_beta(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::benefits`].
+    pub fn benefits(&self) -> benefits::Benefits {
+        benefits::Benefits::new(self.inner.benefits(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::companies`].
+    pub fn companies(&self) -> companies::Companies {
+        companies::Companies::new(self.inner.companies(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::company_bank_accounts_beta`].
+    pub fn company_bank_accounts_beta(
+        &self,
+    ) -> company_bank_accounts_beta::CompanyBankAccountsBeta {
+        company_bank_accounts_beta::CompanyBankAccountsBeta::new(
+            self.inner.company_bank_accounts_beta(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::compensations`].
+    pub fn compensations(&self) -> compensations::Compensations {
+        compensations::Compensations::new(self.inner.compensations(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::contractor_payments`].
+    pub fn contractor_payments(&self) -> contractor_payments::ContractorPayments {
+        contractor_payments::ContractorPayments::new(
+            self.inner.contractor_payments(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::contractors`].
+    pub fn contractors(&self) -> contractors::Contractors {
+        contractors::Contractors::new(self.inner.contractors(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::current_user`].
+    pub fn current_user(&self) -> current_user::CurrentUser {
+        current_user::CurrentUser::new(self.inner.current_user(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::custom_fields`].
+    pub fn custom_fields(&self) -> custom_fields::CustomFields {
+        custom_fields::CustomFields::new(self.inner.custom_fields(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::earning_type`].
+    pub fn earning_type(&self) -> earning_type::EarningType {
+        earning_type::EarningType::new(self.inner.earning_type(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::employees`].
+    pub fn employees(&self) -> employees::Employees {
+        employees::Employees::new(self.inner.employees(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::garnishments`].
+    pub fn garnishments(&self) -> garnishments::Garnishments {
+        garnishments::Garnishments::new(self.inner.garnishments(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::job_applicants_beta`].
+    pub fn job_applicants_beta(&self) -> job_applicants_beta::JobApplicantsBeta {
+        job_applicants_beta::JobApplicantsBeta::new(
+            self.inner.job_applicants_beta(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::jobs`].
+    pub fn jobs(&self) -> jobs::Jobs {
+        jobs::Jobs::new(self.inner.jobs(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::locations`].
+    pub fn locations(&self) -> locations::Locations {
+        locations::Locations::new(self.inner.locations(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::pay_schedules`].
+    pub fn pay_schedules(&self) -> pay_schedules::PaySchedules {
+        pay_schedules::PaySchedules::new(self.inner.pay_schedules(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::payroll`].
+    pub fn payroll(&self) -> payroll::Payroll {
+        payroll::Payroll::new(self.inner.payroll(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::terminations`].
+    pub fn terminations(&self) -> terminations::Terminations {
+        terminations::Terminations::new(self.inner.terminations(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::time_off_requests`].
+    pub fn time_off_requests(&self) -> time_off_requests::TimeOffRequests {
+        time_off_requests::TimeOffRequests::new(
+            self.inner.time_off_requests(),
+            self.runtime.clone(),
+        )
+    }
+}
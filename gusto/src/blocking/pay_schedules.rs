@@ -0,0 +1,58 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::pay_schedules::PaySchedules`].
+pub struct PaySchedules {
+    inner: crate::pay_schedules::PaySchedules,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl PaySchedules {
+    pub(crate) fn new(
+        inner: crate::pay_schedules::PaySchedules,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        PaySchedules { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::pay_schedules::PaySchedules::get_company`].
+    pub fn get_company(
+        &self,
+        company_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::PaySchedule>>> {
+        self.runtime.block_on(self.inner.get_company(company_id))
+    }
+    /// The blocking version of [`crate::pay_schedules::PaySchedules::get_all_company`].
+    pub fn get_all_company(
+        &self,
+        company_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::PaySchedule>>> {
+        self.runtime
+            .block_on(self.inner.get_all_company(company_id))
+    }
+    /// The blocking version of [`crate::pay_schedules::PaySchedules::get_company_schedule`].
+    pub fn get_company_schedule(
+        &self,
+        company_id_or_uuid: &str,
+        pay_schedule_id_or_uuid: &str,
+    ) -> ClientResult<crate::Response<crate::types::PaySchedule>> {
+        self.runtime.block_on(
+            self.inner
+                .get_company_schedule(company_id_or_uuid, pay_schedule_id_or_uuid),
+        )
+    }
+    /// The blocking version of [`crate::pay_schedules::PaySchedules::put_company_schedule`].
+    pub fn put_company_schedule(
+        &self,
+        company_id_or_uuid: &str,
+        pay_schedule_id_or_uuid: &str,
+        body: &crate::types::PutCompanyPaySchedulesScheduleRequest,
+    ) -> ClientResult<crate::Response<crate::types::PaySchedule>> {
+        self.runtime.block_on(self.inner.put_company_schedule(
+            company_id_or_uuid,
+            pay_schedule_id_or_uuid,
+            body,
+        ))
+    }
+}
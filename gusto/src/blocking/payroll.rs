@@ -0,0 +1,299 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Payroll::get_company_pay_periods`], set with a builder.
+pub struct PayrollGetCompanyPayPeriodsRequest<'a> {
+    inner: crate::payroll::PayrollGetCompanyPayPeriodsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> PayrollGetCompanyPayPeriodsRequest<'a> {
+    /// Sets the `start_date` query parameter.
+    pub fn start_date(mut self, start_date: &'a str) -> Self {
+        self.inner = self.inner.start_date(start_date);
+        self
+    }
+
+    /// Sets the `end_date` query parameter.
+    pub fn end_date(mut self, end_date: &'a str) -> Self {
+        self.inner = self.inner.end_date(end_date);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::PayPeriod>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Payroll::get_company`], set with a builder.
+pub struct PayrollGetCompanyRequest<'a> {
+    inner: crate::payroll::PayrollGetCompanyRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> PayrollGetCompanyRequest<'a> {
+    /// Sets the `processed` query parameter.
+    pub fn processed(mut self, processed: bool) -> Self {
+        self.inner = self.inner.processed(processed);
+        self
+    }
+
+    /// Sets the `include_off_cycle` query parameter.
+    pub fn include_off_cycle(mut self, include_off_cycle: bool) -> Self {
+        self.inner = self.inner.include_off_cycle(include_off_cycle);
+        self
+    }
+
+    /// Sets the `include` query parameter.
+    pub fn include(mut self, include: &'a [String]) -> Self {
+        self.inner = self.inner.include(include);
+        self
+    }
+
+    /// Sets the `start_date` query parameter.
+    pub fn start_date(mut self, start_date: &'a str) -> Self {
+        self.inner = self.inner.start_date(start_date);
+        self
+    }
+
+    /// Sets the `end_date` query parameter.
+    pub fn end_date(mut self, end_date: &'a str) -> Self {
+        self.inner = self.inner.end_date(end_date);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::PayrollData>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Payroll::get_company_payroll`], set with a builder.
+pub struct PayrollGetCompanyPayrollRequest<'a> {
+    inner: crate::payroll::PayrollGetCompanyPayrollRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> PayrollGetCompanyPayrollRequest<'a> {
+    /// Sets the `include` query parameter.
+    pub fn include(mut self, include: crate::types::GetCompanyPayrollsInclude) -> Self {
+        self.inner = self.inner.include(include);
+        self
+    }
+
+    /// Sets the `show_calculation` query parameter.
+    pub fn show_calculation(mut self, show_calculation: &'a str) -> Self {
+        self.inner = self.inner.show_calculation(show_calculation);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::PayrollData>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::payroll::Payroll`].
+pub struct Payroll {
+    inner: crate::payroll::Payroll,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Payroll {
+    pub(crate) fn new(
+        inner: crate::payroll::Payroll,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Payroll { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::payroll::Payroll::get_company_pay_periods`].
+    pub fn get_company_pay_periods(
+        &self,
+        company_id_or_uuid: &str,
+        start_date: &str,
+        end_date: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::PayPeriod>>> {
+        self.runtime.block_on(self.inner.get_company_pay_periods(
+            company_id_or_uuid,
+            start_date,
+            end_date,
+        ))
+    }
+    /// The blocking version of [`crate::payroll::Payroll::get_company_pay_periods_request`].
+    pub fn get_company_pay_periods_request<'a>(
+        &'a self,
+        company_id_or_uuid: &'a str,
+    ) -> PayrollGetCompanyPayPeriodsRequest<'a> {
+        PayrollGetCompanyPayPeriodsRequest {
+            inner: self
+                .inner
+                .get_company_pay_periods_request(company_id_or_uuid),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::payroll::Payroll::get_all_company_pay_periods`].
+    pub fn get_all_company_pay_periods(
+        &self,
+        company_id_or_uuid: &str,
+        start_date: &str,
+        end_date: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::PayPeriod>>> {
+        self.runtime
+            .block_on(self.inner.get_all_company_pay_periods(
+                company_id_or_uuid,
+                start_date,
+                end_date,
+            ))
+    }
+    /// The blocking version of [`crate::payroll::Payroll::get_company`].
+    pub fn get_company(
+        &self,
+        company_id_or_uuid: &str,
+        processed: bool,
+        include_off_cycle: bool,
+        include: &[String],
+        start_date: &str,
+        end_date: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::PayrollData>>> {
+        self.runtime.block_on(self.inner.get_company(
+            company_id_or_uuid,
+            processed,
+            include_off_cycle,
+            include,
+            start_date,
+            end_date,
+        ))
+    }
+    /// The blocking version of [`crate::payroll::Payroll::get_company_request`].
+    pub fn get_company_request<'a>(
+        &'a self,
+        company_id_or_uuid: &'a str,
+    ) -> PayrollGetCompanyRequest<'a> {
+        PayrollGetCompanyRequest {
+            inner: self.inner.get_company_request(company_id_or_uuid),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::payroll::Payroll::get_all_company`].
+    pub fn get_all_company(
+        &self,
+        company_id_or_uuid: &str,
+        processed: bool,
+        include_off_cycle: bool,
+        include: &[String],
+        start_date: &str,
+        end_date: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::PayrollData>>> {
+        self.runtime.block_on(self.inner.get_all_company(
+            company_id_or_uuid,
+            processed,
+            include_off_cycle,
+            include,
+            start_date,
+            end_date,
+        ))
+    }
+    /// The blocking version of [`crate::payroll::Payroll::post_company`].
+    pub fn post_company(
+        &self,
+        company_id_or_uuid: &str,
+        body: &crate::types::PostCompanyPayrollsRequest,
+    ) -> ClientResult<crate::Response<crate::types::PayrollData>> {
+        self.runtime
+            .block_on(self.inner.post_company(company_id_or_uuid, body))
+    }
+    /// The blocking version of [`crate::payroll::Payroll::get_company_payroll`].
+    pub fn get_company_payroll(
+        &self,
+        company_id_or_uuid: &str,
+        payroll_id_or_uuid: &str,
+        include: crate::types::GetCompanyPayrollsInclude,
+        show_calculation: &str,
+    ) -> ClientResult<crate::Response<crate::types::PayrollData>> {
+        self.runtime.block_on(self.inner.get_company_payroll(
+            company_id_or_uuid,
+            payroll_id_or_uuid,
+            include,
+            show_calculation,
+        ))
+    }
+    /// The blocking version of [`crate::payroll::Payroll::get_company_payroll_request`].
+    pub fn get_company_payroll_request<'a>(
+        &'a self,
+        company_id_or_uuid: &'a str,
+        payroll_id_or_uuid: &'a str,
+    ) -> PayrollGetCompanyPayrollRequest<'a> {
+        PayrollGetCompanyPayrollRequest {
+            inner: self
+                .inner
+                .get_company_payroll_request(company_id_or_uuid, payroll_id_or_uuid),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::payroll::Payroll::put_company`].
+    pub fn put_company(
+        &self,
+        company_id_or_uuid: &str,
+        payroll_id_or_uuid: &str,
+        body: &crate::types::PutCompanyPayrollsRequest,
+    ) -> ClientResult<crate::Response<crate::types::PayrollData>> {
+        self.runtime.block_on(
+            self.inner
+                .put_company(company_id_or_uuid, payroll_id_or_uuid, body),
+        )
+    }
+    /// The blocking version of [`crate::payroll::Payroll::put_company_pay_period_start_date_end`].
+    pub fn put_company_pay_period_start_date_end(
+        &self,
+        company_id_or_uuid: &str,
+        pay_period_start_date: &str,
+        pay_period_end_date: &str,
+        body: &crate::types::PutCompanyPayrollsRequest,
+    ) -> ClientResult<crate::Response<crate::types::PayrollData>> {
+        self.runtime
+            .block_on(self.inner.put_company_pay_period_start_date_end(
+                company_id_or_uuid,
+                pay_period_start_date,
+                pay_period_end_date,
+                body,
+            ))
+    }
+    /// The blocking version of [`crate::payroll::Payroll::put_company_calculate`].
+    pub fn put_company_calculate(
+        &self,
+        company_id: &str,
+        payroll_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.put_company_calculate(company_id, payroll_id))
+    }
+    /// The blocking version of [`crate::payroll::Payroll::put_company_submit`].
+    pub fn put_company_submit(
+        &self,
+        company_id: &str,
+        payroll_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.put_company_submit(company_id, payroll_id))
+    }
+    /// The blocking version of [`crate::payroll::Payroll::put_company_cancel`].
+    pub fn put_company_cancel(
+        &self,
+        company_id: &str,
+        payroll_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::PayrollData>> {
+        self.runtime
+            .block_on(self.inner.put_company_cancel(company_id, payroll_id))
+    }
+    /// The blocking version of [`crate::payroll::Payroll::get_company_or_reversals`].
+    pub fn get_company_or_reversals(
+        &self,
+        company_id_or_uuid: &str,
+    ) -> ClientResult<crate::Response<crate::types::GetCompanyPayrollReversalsResponse>> {
+        self.runtime
+            .block_on(self.inner.get_company_or_reversals(company_id_or_uuid))
+    }
+}
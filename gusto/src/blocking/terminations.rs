@@ -0,0 +1,43 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::terminations::Terminations`].
+pub struct Terminations {
+    inner: crate::terminations::Terminations,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Terminations {
+    pub(crate) fn new(
+        inner: crate::terminations::Terminations,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Terminations { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::terminations::Terminations::get_employee`].
+    pub fn get_employee(
+        &self,
+        employee_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Termination>>> {
+        self.runtime.block_on(self.inner.get_employee(employee_id))
+    }
+    /// The blocking version of [`crate::terminations::Terminations::get_all_employee`].
+    pub fn get_all_employee(
+        &self,
+        employee_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Termination>>> {
+        self.runtime
+            .block_on(self.inner.get_all_employee(employee_id))
+    }
+    /// The blocking version of [`crate::terminations::Terminations::post_employee`].
+    pub fn post_employee(
+        &self,
+        employee_id: &str,
+        body: &crate::types::PostEmployeeTerminationsRequest,
+    ) -> ClientResult<crate::Response<crate::types::Termination>> {
+        self.runtime
+            .block_on(self.inner.post_employee(employee_id, body))
+    }
+}
@@ -0,0 +1,85 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`TimeOffRequests::get_company`], set with a builder.
+pub struct TimeOffRequestsGetCompanyRequest<'a> {
+    inner: crate::time_off_requests::TimeOffRequestsGetCompanyRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> TimeOffRequestsGetCompanyRequest<'a> {
+    /// Sets the `start_date` query parameter.
+    pub fn start_date(mut self, start_date: &'a str) -> Self {
+        self.inner = self.inner.start_date(start_date);
+        self
+    }
+
+    /// Sets the `end_date` query parameter.
+    pub fn end_date(mut self, end_date: &'a str) -> Self {
+        self.inner = self.inner.end_date(end_date);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::TimeOffRequest>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::time_off_requests::TimeOffRequests`].
+pub struct TimeOffRequests {
+    inner: crate::time_off_requests::TimeOffRequests,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl TimeOffRequests {
+    pub(crate) fn new(
+        inner: crate::time_off_requests::TimeOffRequests,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        TimeOffRequests { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::time_off_requests::TimeOffRequests::get_company`].
+    pub fn get_company(
+        &self,
+        company_id: &str,
+        start_date: &str,
+        end_date: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::TimeOffRequest>>> {
+        self.runtime
+            .block_on(self.inner.get_company(company_id, start_date, end_date))
+    }
+    /// The blocking version of [`crate::time_off_requests::TimeOffRequests::get_company_request`].
+    pub fn get_company_request<'a>(
+        &'a self,
+        company_id: &'a str,
+    ) -> TimeOffRequestsGetCompanyRequest<'a> {
+        TimeOffRequestsGetCompanyRequest {
+            inner: self.inner.get_company_request(company_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::time_off_requests::TimeOffRequests::get_all_company`].
+    pub fn get_all_company(
+        &self,
+        company_id: &str,
+        start_date: &str,
+        end_date: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::TimeOffRequest>>> {
+        self.runtime
+            .block_on(self.inner.get_all_company(company_id, start_date, end_date))
+    }
+    /// The blocking version of [`crate::time_off_requests::TimeOffRequests::get_company_request_time_off_requests`].
+    pub fn get_company_request_time_off_requests(
+        &self,
+        company_id: &str,
+        time_off_request_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::TimeOffRequest>> {
+        self.runtime.block_on(
+            self.inner
+                .get_company_request_time_off_requests(company_id, time_off_request_id),
+        )
+    }
+}
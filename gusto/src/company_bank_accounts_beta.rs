@@ -73,6 +73,31 @@ impl CompanyBankAccountsBeta {
             )
             .await
     }
+    /**
+     * Get all company bank accounts.
+     *
+     * This function performs a `GET` to the `/v1/companies/{company_id_or_uuid}/bank_accounts` endpoint.
+     *
+     * As opposed to `get_all_company_bank_accounts`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * This endpoint is in beta and intended for **[Gusto Embedded Payroll](https://gusto.com/embedded-payroll)** customers. Please [apply for early access](https://gusto-embedded-payroll.typeform.com/to/iomAQIj3?utm_source=docs) if you’d like to learn more and use it for production. Note, this endpoint will require you to enter a different agreement with Gusto.
+     *
+     * Returns all company bank accounts
+     */
+    pub fn get_all_company_bank_accounts_stream(
+        &self,
+        company_id_or_uuid: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::CompanyBankAccount>> + Unpin + '_
+    {
+        let url = self.client.url(
+            &format!(
+                "/v1/companies/{}/bank_accounts",
+                crate::progenitor_support::encode_path(company_id_or_uuid),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Create a company bank account.
      *
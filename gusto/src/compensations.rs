@@ -139,4 +139,30 @@ impl Compensations {
             )
             .await
     }
+    /**
+     * Get compensations for a job.
+     *
+     * This function performs a `GET` to the `/v1/jobs/{job_id}/compensations` endpoint.
+     *
+     * As opposed to `get_all_job`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Compensations contain information on how much is paid out for a job. Jobs may have many compensations, but only one that is active. The current compensation is the one with the most recent `effective_date`.
+     *
+     * Note: Currently, jobs are arbitrarily limited to a single compensation as multiple compensations per job are not yet available in Gusto. The API is architected as if multiple compensations may exist, so integrations should integrate under the same assumption. The only exception is that creating a compensation with the same `job_id` as another will fail with a relevant error.
+     *
+     * Use the `flsa_status` to determine if an employee is elibgle for overtime.
+     */
+    pub fn get_all_job_stream(
+        &self,
+        job_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Compensation>> + Unpin + '_ {
+        let url = self.client.url(
+            &format!(
+                "/v1/jobs/{}/compensations",
+                crate::progenitor_support::encode_path(job_id),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
 }
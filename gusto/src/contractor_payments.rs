@@ -1,6 +1,158 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`ContractorPayments::get_company`], set with a builder.
+pub struct ContractorPaymentsGetCompanyRequest<'a> {
+    client: &'a ContractorPayments,
+    company_id: &'a str,
+    start_date: Option<&'a str>,
+    end_date: Option<&'a str>,
+}
+
+impl<'a> ContractorPaymentsGetCompanyRequest<'a> {
+    /// Sets the `start_date` query parameter.
+    pub fn start_date(mut self, start_date: &'a str) -> Self {
+        self.start_date = Some(start_date);
+        self
+    }
+
+    /// Sets the `end_date` query parameter.
+    pub fn end_date(mut self, end_date: &'a str) -> Self {
+        self.end_date = Some(end_date);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::ContractorPaymentSummary>> {
+        let Self {
+            client,
+            company_id,
+            start_date,
+            end_date,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = end_date {
+            query_args.push(("end_date".to_string(), v.to_string()));
+        }
+        if let Some(v) = start_date {
+            query_args.push(("start_date".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v1/companies/{}/contractor_payments?{}",
+                crate::progenitor_support::encode_path(company_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`ContractorPayments::post_company`], set with a builder.
+pub struct ContractorPaymentsPostCompanyRequest<'a> {
+    client: &'a ContractorPayments,
+    company_id: &'a str,
+    date: &'a str,
+    contractor_id: f64,
+    wage: Option<f64>,
+    hours: Option<f64>,
+    bonus: Option<f64>,
+    reimbursement: Option<f64>,
+}
+
+impl<'a> ContractorPaymentsPostCompanyRequest<'a> {
+    /// Sets the `wage` query parameter.
+    pub fn wage(mut self, wage: f64) -> Self {
+        self.wage = Some(wage);
+        self
+    }
+
+    /// Sets the `hours` query parameter.
+    pub fn hours(mut self, hours: f64) -> Self {
+        self.hours = Some(hours);
+        self
+    }
+
+    /// Sets the `bonus` query parameter.
+    pub fn bonus(mut self, bonus: f64) -> Self {
+        self.bonus = Some(bonus);
+        self
+    }
+
+    /// Sets the `reimbursement` query parameter.
+    pub fn reimbursement(mut self, reimbursement: f64) -> Self {
+        self.reimbursement = Some(reimbursement);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::ContractorPayment>> {
+        let Self {
+            client,
+            company_id,
+            date,
+            contractor_id,
+            wage,
+            hours,
+            bonus,
+            reimbursement,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = bonus {
+            query_args.push(("bonus".to_string(), v.to_string()));
+        }
+        if !contractor_id.to_string().is_empty() {
+            query_args.push(("contractor_id".to_string(), contractor_id.to_string()));
+        }
+        if !date.is_empty() {
+            query_args.push(("date".to_string(), date.to_string()));
+        }
+        if let Some(v) = hours {
+            query_args.push(("hours".to_string(), v.to_string()));
+        }
+        if let Some(v) = reimbursement {
+            query_args.push(("reimbursement".to_string(), v.to_string()));
+        }
+        if let Some(v) = wage {
+            query_args.push(("wage".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v1/companies/{}/contractor_payments?{}",
+                crate::progenitor_support::encode_path(company_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct ContractorPayments {
     pub client: Client,
 }
@@ -55,6 +207,26 @@ impl ContractorPayments {
             )
             .await
     }
+    /**
+     * Get contractor payments for a company.
+     *
+     * This function performs a `GET` to the `/v1/companies/{company_id}/contractor_payments` endpoint.
+     *
+     * As opposed to `get_company`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Returns an object containing individual contractor payments, within a given time period, including totals.
+     */
+    pub fn get_company_request<'a>(
+        &'a self,
+        company_id: &'a str,
+    ) -> ContractorPaymentsGetCompanyRequest<'a> {
+        ContractorPaymentsGetCompanyRequest {
+            client: self,
+            company_id,
+            start_date: None,
+            end_date: None,
+        }
+    }
     /**
      * Create a contractor payment (Beta).
      *
@@ -121,6 +293,34 @@ impl ContractorPayments {
             )
             .await
     }
+    /**
+     * Create a contractor payment (Beta).
+     *
+     * This function performs a `POST` to the `/v1/companies/{company_id}/contractor_payments` endpoint.
+     *
+     * As opposed to `post_company`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Returns an object containing individual contractor payments, within a given time period, including totals.
+     *
+     * This endpoint is in beta and intended for **[Gusto Embedded Payroll](https://gusto.com/embedded-payroll)** customers. Please [apply for early access](https://gusto-embedded-payroll.typeform.com/to/iomAQIj3?utm_source=docs) if you’d like to learn more and use it for production. Note, this endpoint will require you to enter a different agreement with Gusto.
+     */
+    pub fn post_company_request<'a>(
+        &'a self,
+        company_id: &'a str,
+        date: &'a str,
+        contractor_id: f64,
+    ) -> ContractorPaymentsPostCompanyRequest<'a> {
+        ContractorPaymentsPostCompanyRequest {
+            client: self,
+            company_id,
+            date,
+            contractor_id,
+            wage: None,
+            hours: None,
+            bonus: None,
+            reimbursement: None,
+        }
+    }
     /**
      * Get a single contractor payment.
      *
@@ -126,6 +126,28 @@ impl Contractors {
             )
             .await
     }
+    /**
+     * Get contractors of a company.
+     *
+     * This function performs a `GET` to the `/v1/companies/{company_id_or_uuid}/contractors` endpoint.
+     *
+     * As opposed to `get_all_company`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Get all contractors, active and inactive, individual and business, for a company.
+     */
+    pub fn get_all_company_stream(
+        &self,
+        company_id_or_uuid: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Contractor>> + Unpin + '_ {
+        let url = self.client.url(
+            &format!(
+                "/v1/companies/{}/contractors",
+                crate::progenitor_support::encode_path(company_id_or_uuid),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Create a contractor.
      *
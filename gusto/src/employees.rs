@@ -1,6 +1,135 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Employees::get`], set with a builder.
+pub struct EmployeesGetRequest<'a> {
+    client: &'a Employees,
+    employee_id_or_uuid: &'a str,
+    include: Option<&'a [String]>,
+}
+
+impl<'a> EmployeesGetRequest<'a> {
+    /// Sets the `include` query parameter.
+    pub fn include(mut self, include: &'a [String]) -> Self {
+        self.include = Some(include);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::Employee>> {
+        let Self {
+            client,
+            employee_id_or_uuid,
+            include,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = include {
+            query_args.push(("include".to_string(), v.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v1/employees/{}?{}",
+                crate::progenitor_support::encode_path(employee_id_or_uuid),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Employees::get_company`], set with a builder.
+pub struct EmployeesGetCompanyRequest<'a> {
+    client: &'a Employees,
+    company_id_or_uuid: &'a str,
+    terminated: Option<bool>,
+    page: Option<f64>,
+    per: Option<f64>,
+    include: Option<&'a [String]>,
+}
+
+impl<'a> EmployeesGetCompanyRequest<'a> {
+    /// Sets the `terminated` query parameter.
+    pub fn terminated(mut self, terminated: bool) -> Self {
+        self.terminated = Some(terminated);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: f64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sets the `per` query parameter.
+    pub fn per(mut self, per: f64) -> Self {
+        self.per = Some(per);
+        self
+    }
+
+    /// Sets the `include` query parameter.
+    pub fn include(mut self, include: &'a [String]) -> Self {
+        self.include = Some(include);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Employee>>> {
+        let Self {
+            client,
+            company_id_or_uuid,
+            terminated,
+            page,
+            per,
+            include,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = include {
+            query_args.push(("include".to_string(), v.join(" ")));
+        }
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = per {
+            query_args.push(("per".to_string(), v.to_string()));
+        }
+        if let Some(v) = terminated {
+            query_args.push(("terminated".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v1/companies/{}/employees?{}",
+                crate::progenitor_support::encode_path(company_id_or_uuid),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
 pub struct Employees {
     pub client: Client,
 }
@@ -50,6 +179,22 @@ impl Employees {
             )
             .await
     }
+    /**
+     * Get an employee.
+     *
+     * This function performs a `GET` to the `/v1/employees/{employee_id_or_uuid}` endpoint.
+     *
+     * As opposed to `get`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get an employee.
+     */
+    pub fn get_request<'a>(&'a self, employee_id_or_uuid: &'a str) -> EmployeesGetRequest<'a> {
+        EmployeesGetRequest {
+            client: self,
+            employee_id_or_uuid,
+            include: None,
+        }
+    }
     /**
      * Update an employee.
      *
@@ -133,6 +278,28 @@ impl Employees {
             )
             .await
     }
+    /**
+     * Get employees of a company.
+     *
+     * This function performs a `GET` to the `/v1/companies/{company_id_or_uuid}/employees` endpoint.
+     *
+     * As opposed to `get_company`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get all of the employees, onboarding, active and terminated, for a given company.
+     */
+    pub fn get_company_request<'a>(
+        &'a self,
+        company_id_or_uuid: &'a str,
+    ) -> EmployeesGetCompanyRequest<'a> {
+        EmployeesGetCompanyRequest {
+            client: self,
+            company_id_or_uuid,
+            terminated: None,
+            page: None,
+            per: None,
+            include: None,
+        }
+    }
     /**
      * Get employees of a company.
      *
@@ -174,6 +341,39 @@ impl Employees {
             )
             .await
     }
+    /**
+     * Get employees of a company.
+     *
+     * This function performs a `GET` to the `/v1/companies/{company_id_or_uuid}/employees` endpoint.
+     *
+     * As opposed to `get_all_company`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Get all of the employees, onboarding, active and terminated, for a given company.
+     */
+    pub fn get_all_company_stream(
+        &self,
+        company_id_or_uuid: &str,
+        terminated: bool,
+        include: &[String],
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Employee>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !include.is_empty() {
+            query_args.push(("include".to_string(), include.join(" ")));
+        }
+        if terminated {
+            query_args.push(("terminated".to_string(), terminated.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/v1/companies/{}/employees?{}",
+                crate::progenitor_support::encode_path(company_id_or_uuid),
+                query_
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Create an employee.
      *
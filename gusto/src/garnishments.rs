@@ -69,6 +69,28 @@ impl Garnishments {
             )
             .await
     }
+    /**
+     * Get garnishments for an employee.
+     *
+     * This function performs a `GET` to the `/v1/employees/{employee_id}/garnishments` endpoint.
+     *
+     * As opposed to `get_all_employee`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Garnishments, or employee deductions, are fixed amounts or percentages deducted from an employee’s pay. They can be deducted a specific number of times or on a recurring basis. Garnishments can also have maximum deductions on a yearly or per-pay-period bases. Common uses for garnishments are court-ordered payments for child support or back taxes. Some companies provide loans to their employees that are repaid via garnishments.
+     */
+    pub fn get_all_employee_stream(
+        &self,
+        employee_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Garnishment>> + Unpin + '_ {
+        let url = self.client.url(
+            &format!(
+                "/v1/employees/{}/garnishments",
+                crate::progenitor_support::encode_path(employee_id),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Create a garnishment.
      *
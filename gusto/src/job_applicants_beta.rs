@@ -73,6 +73,30 @@ impl JobApplicantsBeta {
             )
             .await
     }
+    /**
+     * Get all job applicants for a company.
+     *
+     * This function performs a `GET` to the `/v1/companies/{company_id}/job_applicants` endpoint.
+     *
+     * As opposed to `get_all_company_job_applicants`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * *This endpoint is in beta - we will be making breaking changes based on developer feedback.
+     *
+     * Returns all job applicants for a company.
+     */
+    pub fn get_all_company_job_applicants_stream(
+        &self,
+        company_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::JobApplicant>> + Unpin + '_ {
+        let url = self.client.url(
+            &format!(
+                "/v1/companies/{}/job_applicants",
+                crate::progenitor_support::encode_path(company_id),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Create a job applicant.
      *
@@ -148,6 +148,28 @@ impl Jobs {
             )
             .await
     }
+    /**
+     * Get jobs for an employee.
+     *
+     * This function performs a `GET` to the `/v1/employees/{employee_id}/jobs` endpoint.
+     *
+     * As opposed to `get_all_employee`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Get all of the jobs that an employee holds.
+     */
+    pub fn get_all_employee_stream(
+        &self,
+        employee_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Job>> + Unpin + '_ {
+        let url = self.client.url(
+            &format!(
+                "/v1/employees/{}/jobs",
+                crate::progenitor_support::encode_path(employee_id),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Create a job.
      *
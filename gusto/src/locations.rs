@@ -73,6 +73,30 @@ impl Locations {
             )
             .await
     }
+    /**
+     * Get company locations.
+     *
+     * This function performs a `GET` to the `/v1/companies/{company_id_or_uuid}/locations` endpoint.
+     *
+     * As opposed to `get_all_company`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Company locations represent all addresses associated with a company. These can be filing addesses, mailing addresses, and/or work locations; one address may serve multiple, or all, purposes.
+     *
+     * Since all company locations are subsets of locations, retrieving or updating an individual record should be done via the locations endpoints.
+     */
+    pub fn get_all_company_stream(
+        &self,
+        company_id_or_uuid: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Location>> + Unpin + '_ {
+        let url = self.client.url(
+            &format!(
+                "/v1/companies/{}/locations",
+                crate::progenitor_support::encode_path(company_id_or_uuid),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Create a company location.
      *
@@ -69,6 +69,28 @@ impl PaySchedules {
             )
             .await
     }
+    /**
+     * Get the pay schedules for a company.
+     *
+     * This function performs a `GET` to the `/v1/companies/{company_id}/pay_schedules` endpoint.
+     *
+     * As opposed to `get_all_company`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * The pay schedule object in Gusto captures the details of when employees work and when they should be paid. A company can have multiple pay schedules.
+     */
+    pub fn get_all_company_stream(
+        &self,
+        company_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::PaySchedule>> + Unpin + '_ {
+        let url = self.client.url(
+            &format!(
+                "/v1/companies/{}/pay_schedules",
+                crate::progenitor_support::encode_path(company_id),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Get a pay schedule.
      *
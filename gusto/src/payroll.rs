@@ -1,6 +1,219 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Payroll::get_company_pay_periods`], set with a builder.
+pub struct PayrollGetCompanyPayPeriodsRequest<'a> {
+    client: &'a Payroll,
+    company_id_or_uuid: &'a str,
+    start_date: Option<&'a str>,
+    end_date: Option<&'a str>,
+}
+
+impl<'a> PayrollGetCompanyPayPeriodsRequest<'a> {
+    /// Sets the `start_date` query parameter.
+    pub fn start_date(mut self, start_date: &'a str) -> Self {
+        self.start_date = Some(start_date);
+        self
+    }
+
+    /// Sets the `end_date` query parameter.
+    pub fn end_date(mut self, end_date: &'a str) -> Self {
+        self.end_date = Some(end_date);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::PayPeriod>>> {
+        let Self {
+            client,
+            company_id_or_uuid,
+            start_date,
+            end_date,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = end_date {
+            query_args.push(("end_date".to_string(), v.to_string()));
+        }
+        if let Some(v) = start_date {
+            query_args.push(("start_date".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v1/companies/{}/pay_periods?{}",
+                crate::progenitor_support::encode_path(company_id_or_uuid),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Payroll::get_company`], set with a builder.
+pub struct PayrollGetCompanyRequest<'a> {
+    client: &'a Payroll,
+    company_id_or_uuid: &'a str,
+    processed: Option<bool>,
+    include_off_cycle: Option<bool>,
+    include: Option<&'a [String]>,
+    start_date: Option<&'a str>,
+    end_date: Option<&'a str>,
+}
+
+impl<'a> PayrollGetCompanyRequest<'a> {
+    /// Sets the `processed` query parameter.
+    pub fn processed(mut self, processed: bool) -> Self {
+        self.processed = Some(processed);
+        self
+    }
+
+    /// Sets the `include_off_cycle` query parameter.
+    pub fn include_off_cycle(mut self, include_off_cycle: bool) -> Self {
+        self.include_off_cycle = Some(include_off_cycle);
+        self
+    }
+
+    /// Sets the `include` query parameter.
+    pub fn include(mut self, include: &'a [String]) -> Self {
+        self.include = Some(include);
+        self
+    }
+
+    /// Sets the `start_date` query parameter.
+    pub fn start_date(mut self, start_date: &'a str) -> Self {
+        self.start_date = Some(start_date);
+        self
+    }
+
+    /// Sets the `end_date` query parameter.
+    pub fn end_date(mut self, end_date: &'a str) -> Self {
+        self.end_date = Some(end_date);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::PayrollData>>> {
+        let Self {
+            client,
+            company_id_or_uuid,
+            processed,
+            include_off_cycle,
+            include,
+            start_date,
+            end_date,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = end_date {
+            query_args.push(("end_date".to_string(), v.to_string()));
+        }
+        if let Some(v) = include {
+            query_args.push(("include".to_string(), v.join(" ")));
+        }
+        if let Some(v) = include_off_cycle {
+            query_args.push(("include_off_cycle".to_string(), v.to_string()));
+        }
+        if let Some(v) = processed {
+            query_args.push(("processed".to_string(), v.to_string()));
+        }
+        if let Some(v) = start_date {
+            query_args.push(("start_date".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v1/companies/{}/payrolls?{}",
+                crate::progenitor_support::encode_path(company_id_or_uuid),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Payroll::get_company_payroll`], set with a builder.
+pub struct PayrollGetCompanyPayrollRequest<'a> {
+    client: &'a Payroll,
+    company_id_or_uuid: &'a str,
+    payroll_id_or_uuid: &'a str,
+    include: Option<crate::types::GetCompanyPayrollsInclude>,
+    show_calculation: Option<&'a str>,
+}
+
+impl<'a> PayrollGetCompanyPayrollRequest<'a> {
+    /// Sets the `include` query parameter.
+    pub fn include(mut self, include: crate::types::GetCompanyPayrollsInclude) -> Self {
+        self.include = Some(include);
+        self
+    }
+
+    /// Sets the `show_calculation` query parameter.
+    pub fn show_calculation(mut self, show_calculation: &'a str) -> Self {
+        self.show_calculation = Some(show_calculation);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::PayrollData>> {
+        let Self {
+            client,
+            company_id_or_uuid,
+            payroll_id_or_uuid,
+            include,
+            show_calculation,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = include {
+            query_args.push(("include".to_string(), v.to_string()));
+        }
+        if let Some(v) = show_calculation {
+            query_args.push(("show_calculation".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v1/companies/{}/payrolls/{}?{}",
+                crate::progenitor_support::encode_path(company_id_or_uuid),
+                crate::progenitor_support::encode_path(payroll_id_or_uuid),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct Payroll {
     pub client: Client,
 }
@@ -58,6 +271,29 @@ impl Payroll {
             )
             .await
     }
+    /**
+     * Get pay periods for a company.
+     *
+     * This function performs a `GET` to the `/v1/companies/{company_id_or_uuid}/pay_periods` endpoint.
+     *
+     * As opposed to `get_company_pay_periods`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Pay periods are the foundation of payroll. Compensation, time & attendance, taxes, and expense reports all rely on when they happened. To begin submitting information for a given payroll, we need to agree on the time period.
+     *
+     *
+     * By default, this endpoint returns every current and past pay period for a company. Since companies can process payroll as often as every week, there can be up to 53 pay periods a year. If a company has been running payroll with Gusto for five years, this endpoint could return up to 265 pay periods. Use the `start_date` and `end_date` parameters to reduce the scope of the response.
+     */
+    pub fn get_company_pay_periods_request<'a>(
+        &'a self,
+        company_id_or_uuid: &'a str,
+    ) -> PayrollGetCompanyPayPeriodsRequest<'a> {
+        PayrollGetCompanyPayPeriodsRequest {
+            client: self,
+            company_id_or_uuid,
+            start_date: None,
+            end_date: None,
+        }
+    }
     /**
      * Get pay periods for a company.
      *
@@ -102,6 +338,42 @@ impl Payroll {
             )
             .await
     }
+    /**
+     * Get pay periods for a company.
+     *
+     * This function performs a `GET` to the `/v1/companies/{company_id_or_uuid}/pay_periods` endpoint.
+     *
+     * As opposed to `get_all_company_pay_periods`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Pay periods are the foundation of payroll. Compensation, time & attendance, taxes, and expense reports all rely on when they happened. To begin submitting information for a given payroll, we need to agree on the time period.
+     *
+     *
+     * By default, this endpoint returns every current and past pay period for a company. Since companies can process payroll as often as every week, there can be up to 53 pay periods a year. If a company has been running payroll with Gusto for five years, this endpoint could return up to 265 pay periods. Use the `start_date` and `end_date` parameters to reduce the scope of the response.
+     */
+    pub fn get_all_company_pay_periods_stream(
+        &self,
+        company_id_or_uuid: &str,
+        start_date: &str,
+        end_date: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::PayPeriod>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !end_date.is_empty() {
+            query_args.push(("end_date".to_string(), end_date.to_string()));
+        }
+        if !start_date.is_empty() {
+            query_args.push(("start_date".to_string(), start_date.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/v1/companies/{}/pay_periods?{}",
+                crate::progenitor_support::encode_path(company_id_or_uuid),
+                query_
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Get all payrolls for a company.
      *
@@ -169,6 +441,34 @@ impl Payroll {
             )
             .await
     }
+    /**
+     * Get all payrolls for a company.
+     *
+     * This function performs a `GET` to the `/v1/companies/{company_id_or_uuid}/payrolls` endpoint.
+     *
+     * As opposed to `get_company`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Returns all payrolls, current and past for a company.
+     *
+     * Notes:
+     * * Hour and dollar amounts are returned as string representations of numeric decimals.
+     * * Hours are represented to the thousands place; dollar amounts are represented to the cent.
+     * * Every eligible compensation is returned for each employee. If no data has yet be inserted for a given field, it defaults to “0.00” (for fixed amounts) or “0.000” (for hours ).
+     */
+    pub fn get_company_request<'a>(
+        &'a self,
+        company_id_or_uuid: &'a str,
+    ) -> PayrollGetCompanyRequest<'a> {
+        PayrollGetCompanyRequest {
+            client: self,
+            company_id_or_uuid,
+            processed: None,
+            include_off_cycle: None,
+            include: None,
+            start_date: None,
+            end_date: None,
+        }
+    }
     /**
      * Get all payrolls for a company.
      *
@@ -230,6 +530,59 @@ impl Payroll {
             )
             .await
     }
+    /**
+     * Get all payrolls for a company.
+     *
+     * This function performs a `GET` to the `/v1/companies/{company_id_or_uuid}/payrolls` endpoint.
+     *
+     * As opposed to `get_all_company`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Returns all payrolls, current and past for a company.
+     *
+     * Notes:
+     * * Hour and dollar amounts are returned as string representations of numeric decimals.
+     * * Hours are represented to the thousands place; dollar amounts are represented to the cent.
+     * * Every eligible compensation is returned for each employee. If no data has yet be inserted for a given field, it defaults to “0.00” (for fixed amounts) or “0.000” (for hours ).
+     */
+    pub fn get_all_company_stream(
+        &self,
+        company_id_or_uuid: &str,
+        processed: bool,
+        include_off_cycle: bool,
+        include: &[String],
+        start_date: &str,
+        end_date: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::PayrollData>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !end_date.is_empty() {
+            query_args.push(("end_date".to_string(), end_date.to_string()));
+        }
+        if !include.is_empty() {
+            query_args.push(("include".to_string(), include.join(" ")));
+        }
+        if include_off_cycle {
+            query_args.push((
+                "include_off_cycle".to_string(),
+                include_off_cycle.to_string(),
+            ));
+        }
+        if processed {
+            query_args.push(("processed".to_string(), processed.to_string()));
+        }
+        if !start_date.is_empty() {
+            query_args.push(("start_date".to_string(), start_date.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/v1/companies/{}/payrolls?{}",
+                crate::progenitor_support::encode_path(company_id_or_uuid),
+                query_
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Create an Off-Cycle Payroll (Beta).
      *
@@ -312,6 +665,33 @@ impl Payroll {
             )
             .await
     }
+    /**
+     * Get a single payroll.
+     *
+     * This function performs a `GET` to the `/v1/companies/{company_id_or_uuid}/payrolls/{payroll_id_or_uuid}` endpoint.
+     *
+     * As opposed to `get_company_payroll`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Returns a payroll.
+     *
+     * Notes:
+     * * Hour and dollar amounts are returned as string representations of numeric decimals.
+     * * Hours are represented to the thousands place; dollar amounts are represented to the cent.
+     * * Every eligible compensation is returned for each employee. If no data has yet be inserted for a given field, it defaults to “0.00” (for fixed amounts) or “0.000” (for hours ).
+     */
+    pub fn get_company_payroll_request<'a>(
+        &'a self,
+        company_id_or_uuid: &'a str,
+        payroll_id_or_uuid: &'a str,
+    ) -> PayrollGetCompanyPayrollRequest<'a> {
+        PayrollGetCompanyPayrollRequest {
+            client: self,
+            company_id_or_uuid,
+            payroll_id_or_uuid,
+            include: None,
+            show_calculation: None,
+        }
+    }
     /**
      * Update a payroll by ID.
      *
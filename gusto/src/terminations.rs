@@ -73,6 +73,30 @@ impl Terminations {
             )
             .await
     }
+    /**
+     * Get terminations for an employee.
+     *
+     * This function performs a `GET` to the `/v1/employees/{employee_id}/terminations` endpoint.
+     *
+     * As opposed to `get_all_employee`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Terminations are created whenever an employee is scheduled to leave the company. The only things required are an effective date (their last day of work) and whether they should receive their wages in a one-off termination payroll or with the rest of the company.
+     *
+     * Note that some states require employees to receive their final wages within 24 hours (unless they consent otherwise,) in which case running a one-off payroll may be the only option.
+     */
+    pub fn get_all_employee_stream(
+        &self,
+        employee_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Termination>> + Unpin + '_ {
+        let url = self.client.url(
+            &format!(
+                "/v1/employees/{}/terminations",
+                crate::progenitor_support::encode_path(employee_id),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Create an employee termination.
      *
@@ -1,6 +1,65 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`TimeOffRequests::get_company`], set with a builder.
+pub struct TimeOffRequestsGetCompanyRequest<'a> {
+    client: &'a TimeOffRequests,
+    company_id: &'a str,
+    start_date: Option<&'a str>,
+    end_date: Option<&'a str>,
+}
+
+impl<'a> TimeOffRequestsGetCompanyRequest<'a> {
+    /// Sets the `start_date` query parameter.
+    pub fn start_date(mut self, start_date: &'a str) -> Self {
+        self.start_date = Some(start_date);
+        self
+    }
+
+    /// Sets the `end_date` query parameter.
+    pub fn end_date(mut self, end_date: &'a str) -> Self {
+        self.end_date = Some(end_date);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::TimeOffRequest>>> {
+        let Self {
+            client,
+            company_id,
+            start_date,
+            end_date,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = end_date {
+            query_args.push(("end_date".to_string(), v.to_string()));
+        }
+        if let Some(v) = start_date {
+            query_args.push(("start_date".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v1/companies/{}/time_off_requests?{}",
+                crate::progenitor_support::encode_path(company_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct TimeOffRequests {
     pub client: Client,
 }
@@ -72,6 +131,43 @@ impl TimeOffRequests {
             )
             .await
     }
+    /**
+     * Get time off requests for a company.
+     *
+     * This function performs a `GET` to the `/v1/companies/{company_id}/time_off_requests` endpoint.
+     *
+     * As opposed to `get_company`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get all time off requests, past and present, for a company.
+     *
+     * In order to reduce the number of time off requests returned in a single response, or to retrieve time off requests from a time period of interest, you may use the `start_date` and `end_date` parameters.
+     *
+     * You may provide both or either parameters to scope the returned data. For example:
+     *
+     * `?start_date='2019-01-01'`
+     *
+     * Returns all time off requests where the request start date is equal to or after January 1, 2019.
+     *
+     * `?end_date='2019-01-01'`
+     *
+     * Returns all time off requests where the request end date is equal to or before January 1, 2019.
+     *
+     * `?start_date='2019-05-01'&end_date='2019-08-31'`
+     *
+     * Returns all time off requests where the request start date is equal to or after May 1, 2019 and the request end date is equal to or before August 31, 2019.
+     *
+     */
+    pub fn get_company_request<'a>(
+        &'a self,
+        company_id: &'a str,
+    ) -> TimeOffRequestsGetCompanyRequest<'a> {
+        TimeOffRequestsGetCompanyRequest {
+            client: self,
+            company_id,
+            start_date: None,
+            end_date: None,
+        }
+    }
     /**
      * Get time off requests for a company.
      *
@@ -130,6 +226,56 @@ impl TimeOffRequests {
             )
             .await
     }
+    /**
+     * Get time off requests for a company.
+     *
+     * This function performs a `GET` to the `/v1/companies/{company_id}/time_off_requests` endpoint.
+     *
+     * As opposed to `get_all_company`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Get all time off requests, past and present, for a company.
+     *
+     * In order to reduce the number of time off requests returned in a single response, or to retrieve time off requests from a time period of interest, you may use the `start_date` and `end_date` parameters.
+     *
+     * You may provide both or either parameters to scope the returned data. For example:
+     *
+     * `?start_date='2019-01-01'`
+     *
+     * Returns all time off requests where the request start date is equal to or after January 1, 2019.
+     *
+     * `?end_date='2019-01-01'`
+     *
+     * Returns all time off requests where the request end date is equal to or before January 1, 2019.
+     *
+     * `?start_date='2019-05-01'&end_date='2019-08-31'`
+     *
+     * Returns all time off requests where the request start date is equal to or after May 1, 2019 and the request end date is equal to or before August 31, 2019.
+     *
+     */
+    pub fn get_all_company_stream(
+        &self,
+        company_id: &str,
+        start_date: &str,
+        end_date: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::TimeOffRequest>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !end_date.is_empty() {
+            query_args.push(("end_date".to_string(), end_date.to_string()));
+        }
+        if !start_date.is_empty() {
+            query_args.push(("start_date".to_string(), start_date.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/v1/companies/{}/time_off_requests?{}",
+                crate::progenitor_support::encode_path(company_id),
+                query_
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Get a specific time off request.
      *
@@ -137,7 +283,7 @@ impl TimeOffRequests {
      *
      * Details of a single time off request
      */
-    pub async fn get_company_request(
+    pub async fn get_company_request_time_off_requests(
         &self,
         company_id: &str,
         time_off_request_id: &str,
@@ -228,6 +228,10 @@ pub struct Employee {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub work_email: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The representation of an address in Gusto.
@@ -356,6 +360,10 @@ pub struct Location {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub zip: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The representation of paid time off in Gusto.
@@ -423,6 +431,10 @@ pub struct PaidTimeOff {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub paid_at_termination: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Garnishments, or employee deductions, are fixed amounts or percentages deducted from an employee’s pay. They can be deducted a specific number of times or on a recurring basis. Garnishments can also have maximum deductions on a yearly or per-pay-period bases. Common uses for garnishments are court-ordered payments for child support or back taxes. Some companies provide loans to their employees that are repaid via garnishments.
@@ -532,6 +544,10 @@ pub struct Garnishment {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub version: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The representation of a termination in Gusto.
@@ -586,6 +602,10 @@ pub struct Termination {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub version: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -606,8 +626,9 @@ pub enum PaymentUnit {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PaymentUnit {
@@ -619,7 +640,7 @@ impl std::fmt::Display for PaymentUnit {
             PaymentUnit::Week => "Week",
             PaymentUnit::Year => "Year",
             PaymentUnit::Noop => "",
-            PaymentUnit::FallthroughString => "*",
+            PaymentUnit::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -647,8 +668,9 @@ pub enum FlsaStatus {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for FlsaStatus {
@@ -659,7 +681,7 @@ impl std::fmt::Display for FlsaStatus {
             FlsaStatus::Owner => "Owner",
             FlsaStatus::SalariedNonexempt => "Salaried Nonexempt",
             FlsaStatus::Noop => "",
-            FlsaStatus::FallthroughString => "*",
+            FlsaStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -726,6 +748,10 @@ pub struct Compensation {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub version: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -801,6 +827,10 @@ pub struct JobLocation {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub zip: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The representation of a job in Gusto.
@@ -906,6 +936,10 @@ pub struct Job {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub version: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The representation of an admin user in Gusto.
@@ -938,6 +972,10 @@ pub struct Admin {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub last_name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -972,8 +1010,9 @@ pub enum EntityType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EntityType {
@@ -992,7 +1031,7 @@ impl std::fmt::Display for EntityType {
             EntityType::SoleProprietor => "Sole proprietor",
             EntityType::Trusteeship => "Trusteeship",
             EntityType::Noop => "",
-            EntityType::FallthroughString => "*",
+            EntityType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1022,8 +1061,9 @@ pub enum Tier {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Tier {
@@ -1035,7 +1075,7 @@ impl std::fmt::Display for Tier {
             Tier::ContractorOnly => "contractor_only",
             Tier::Core => "core",
             Tier::Noop => "",
-            Tier::FallthroughString => "*",
+            Tier::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1061,8 +1101,9 @@ pub enum CompanyStatus {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for CompanyStatus {
@@ -1072,7 +1113,7 @@ impl std::fmt::Display for CompanyStatus {
             CompanyStatus::NotApproved => "Not Approved",
             CompanyStatus::Suspended => "Suspended",
             CompanyStatus::Noop => "",
-            CompanyStatus::FallthroughString => "*",
+            CompanyStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1098,6 +1139,10 @@ pub struct Hourly {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -1108,6 +1153,10 @@ pub struct Fixed {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The available company-wide compensation rates for the company.
@@ -1140,6 +1189,10 @@ pub struct Compensations {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub paid_time_off: Vec<Fixed>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -1180,6 +1233,10 @@ pub struct HomeAddress {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub zip: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The primary signatory of the company.
@@ -1235,6 +1292,10 @@ pub struct PrimarySignatory {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub phone: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The primary payroll admin of the company.
@@ -1276,6 +1337,10 @@ pub struct PrimaryPayrollAdmin {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub phone: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The representation of a company in Gusto.
@@ -1373,6 +1438,10 @@ pub struct Company {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub uuid: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -1387,8 +1456,9 @@ pub enum WageType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for WageType {
@@ -1397,7 +1467,7 @@ impl std::fmt::Display for WageType {
             WageType::Fixed => "Fixed",
             WageType::Hourly => "Hourly",
             WageType::Noop => "",
-            WageType::FallthroughString => "*",
+            WageType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1421,8 +1491,9 @@ pub enum Type {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Type {
@@ -1431,7 +1502,7 @@ impl std::fmt::Display for Type {
             Type::Business => "Business",
             Type::Individual => "Individual",
             Type::Noop => "",
-            Type::FallthroughString => "*",
+            Type::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1500,6 +1571,10 @@ pub struct Address {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub zip: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The representation of a contractor (individual or business) in Gusto.
@@ -1618,6 +1693,10 @@ pub struct Contractor {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub wage_type: Option<WageType>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -1636,8 +1715,9 @@ pub enum PaymentMethod {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PaymentMethod {
@@ -1648,7 +1728,7 @@ impl std::fmt::Display for PaymentMethod {
             PaymentMethod::DirectDeposit => "Direct Deposit",
             PaymentMethod::HistoricalPayment => "Historical Payment",
             PaymentMethod::Noop => "",
-            PaymentMethod::FallthroughString => "*",
+            PaymentMethod::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1754,6 +1834,10 @@ pub struct ContractorPayment {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub wage_type: Option<WageType>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The wage and reimbursement totals for all contractor payments within a given time period.
@@ -1777,6 +1861,10 @@ pub struct Total {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub wages: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -1818,6 +1906,10 @@ pub struct ContractorPayments {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub wage_total: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The representation of the summary of contractor payments for a given company in a given time period.
@@ -1837,6 +1929,10 @@ pub struct ContractorPaymentSummary {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub total: Option<Total>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -1853,8 +1949,9 @@ pub enum Status {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Status {
@@ -1864,7 +1961,7 @@ impl std::fmt::Display for Status {
             Status::Denied => "denied",
             Status::Pending => "pending",
             Status::Noop => "",
-            Status::FallthroughString => "*",
+            Status::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1888,8 +1985,9 @@ pub enum RequestType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for RequestType {
@@ -1898,7 +1996,7 @@ impl std::fmt::Display for RequestType {
             RequestType::Sick => "sick",
             RequestType::Vacation => "vacation",
             RequestType::Noop => "",
-            RequestType::FallthroughString => "*",
+            RequestType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1912,7 +2010,12 @@ impl RequestType {
 
 /// An object that represents the days in the time off request. The keys of the object are the dates, formatted as a YYYY-MM-DD string. The values of the object are the number of hours requested off for each day, formatted as a string representation of a numeric decimal to the thousands place.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Days {}
+pub struct Days {
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
 
 ///
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -1935,6 +2038,10 @@ pub struct TimeOffRequestEmployee {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -1958,6 +2065,10 @@ pub struct Initiator {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// This value will be null if the request has not been approved.
@@ -1981,6 +2092,10 @@ pub struct Approver {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The representation of a time off request.
@@ -2043,6 +2158,10 @@ pub struct TimeOffRequest {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub status: Option<Status>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -2071,6 +2190,10 @@ pub struct Companies {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub trade_name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -2081,6 +2204,10 @@ pub struct PayrollAdmin {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub companies: Vec<Companies>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// An object containing each of the user's permissions.
@@ -2091,6 +2218,10 @@ pub struct Roles {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub payroll_admin: Option<PayrollAdmin>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -2119,6 +2250,10 @@ pub struct CurrentUser {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub roles: Option<Roles>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -2134,8 +2269,9 @@ pub enum Frequency {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Frequency {
@@ -2146,7 +2282,7 @@ impl std::fmt::Display for Frequency {
             Frequency::Monthly => "Monthly",
             Frequency::TwicePerMonth => "Twice per month",
             Frequency::Noop => "",
-            Frequency::FallthroughString => "*",
+            Frequency::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2225,6 +2361,10 @@ pub struct PaySchedule {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub uuid: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -2239,8 +2379,9 @@ pub enum AccountType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AccountType {
@@ -2249,7 +2390,7 @@ impl std::fmt::Display for AccountType {
             AccountType::Checking => "Checking",
             AccountType::Savings => "Savings",
             AccountType::Noop => "",
-            AccountType::FallthroughString => "*",
+            AccountType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2279,8 +2420,9 @@ pub enum VerificationStatus {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for VerificationStatus {
@@ -2290,7 +2432,7 @@ impl std::fmt::Display for VerificationStatus {
             VerificationStatus::ReadyForVerification => "ready_for_verification",
             VerificationStatus::Verified => "verified",
             VerificationStatus::Noop => "",
-            VerificationStatus::FallthroughString => "*",
+            VerificationStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2351,6 +2493,10 @@ pub struct CompanyBankAccount {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub verification_status: Option<VerificationStatus>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -2431,6 +2577,10 @@ pub struct SupportedBenefit {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub yearly_limit: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The representation of a company benefit.
@@ -2514,6 +2664,10 @@ pub struct CompanyBenefit {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub version: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -2537,6 +2691,10 @@ pub struct EarningType {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub uuid: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -2551,8 +2709,9 @@ pub enum DeductionReducesTaxableIncome {
     #[serde(rename = "unset")]
     #[default]
     Unset,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DeductionReducesTaxableIncome {
@@ -2563,7 +2722,7 @@ impl std::fmt::Display for DeductionReducesTaxableIncome {
             }
             DeductionReducesTaxableIncome::ReducesTaxableIncome => "reduces_taxable_income",
             DeductionReducesTaxableIncome::Unset => "unset",
-            DeductionReducesTaxableIncome::FallthroughString => "*",
+            DeductionReducesTaxableIncome::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2708,6 +2867,10 @@ pub struct EmployeeBenefit {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub version: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -2724,6 +2887,10 @@ pub struct EligibleEmployees {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub job_ids: Vec<f64>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Information about the payroll for the pay period.
@@ -2746,6 +2913,10 @@ pub struct Payroll {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub processed: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The representation of a pay period.
@@ -2795,6 +2966,10 @@ pub struct PayPeriod {
         deserialize_with = "crate::utils::date_format::deserialize"
     )]
     pub start_date: Option<chrono::NaiveDate>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -2823,6 +2998,10 @@ pub struct PayrollPayPeriod {
         deserialize_with = "crate::utils::date_format::deserialize"
     )]
     pub start_date: Option<chrono::NaiveDate>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The subtotals for the payroll.
@@ -3008,6 +3187,10 @@ pub struct Totals {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub tax_debit: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -3022,8 +3205,9 @@ pub enum PayrollEmployeeCompensationsPaymentMethod {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PayrollEmployeeCompensationsPaymentMethod {
@@ -3032,7 +3216,7 @@ impl std::fmt::Display for PayrollEmployeeCompensationsPaymentMethod {
             PayrollEmployeeCompensationsPaymentMethod::Check => "Check",
             PayrollEmployeeCompensationsPaymentMethod::DirectDeposit => "Direct Deposit",
             PayrollEmployeeCompensationsPaymentMethod::Noop => "",
-            PayrollEmployeeCompensationsPaymentMethod::FallthroughString => "*",
+            PayrollEmployeeCompensationsPaymentMethod::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3073,6 +3257,10 @@ pub struct FixedCompensations {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -3113,6 +3301,10 @@ pub struct HourlyCompensations {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -3135,6 +3327,10 @@ pub struct PayrollEmployeeCompensationsPaidTimeOff {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -3162,6 +3358,10 @@ pub struct Benefits {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -3178,6 +3378,10 @@ pub struct Deductions {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -3208,6 +3412,10 @@ pub struct Taxes {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -3298,6 +3506,10 @@ pub struct EmployeeCompensations {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub taxes: Vec<Taxes>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -3405,6 +3617,10 @@ pub struct PayrollData {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub version: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -3422,8 +3638,9 @@ pub enum CustomFieldType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for CustomFieldType {
@@ -3435,7 +3652,7 @@ impl std::fmt::Display for CustomFieldType {
             CustomFieldType::Radio => "radio",
             CustomFieldType::Text => "text",
             CustomFieldType::Noop => "",
-            CustomFieldType::FallthroughString => "*",
+            CustomFieldType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3506,6 +3723,10 @@ pub struct EmployeeCustomField {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub value: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A custom field on a company
@@ -3549,6 +3770,10 @@ pub struct CompanyCustomField {
     pub selection_options: Vec<String>,
     #[serde(rename = "type")]
     pub type_: CustomFieldType,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -3562,8 +3787,9 @@ pub enum GustoPersonType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GustoPersonType {
@@ -3573,7 +3799,7 @@ impl std::fmt::Display for GustoPersonType {
             GustoPersonType::Contractor => "Contractor",
             GustoPersonType::Employee => "Employee",
             GustoPersonType::Noop => "",
-            GustoPersonType::FallthroughString => "*",
+            GustoPersonType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3692,6 +3918,10 @@ pub struct JobApplicant {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub uuid: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -3737,6 +3967,10 @@ pub struct FederalTaxDetails {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub version: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -3777,6 +4011,10 @@ pub struct PostEmployeeYtdBenefitAmountsFromDifferentCompanyRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub ytd_employee_deduction_amount: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -3799,6 +4037,10 @@ pub struct EarningTypeListResponse {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub default: Vec<EarningType>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -3808,8 +4050,9 @@ pub enum Include {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Include {
@@ -3817,7 +4060,7 @@ impl std::fmt::Display for Include {
         match self {
             Include::CustomFields => "custom_fields",
             Include::Noop => "",
-            Include::FallthroughString => "*",
+            Include::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3897,6 +4140,10 @@ pub struct PutEmployeesRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub version: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -3953,6 +4200,10 @@ pub struct PostEmployeesRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub ssn: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -3991,6 +4242,10 @@ pub struct PutJobRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub version: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -4020,6 +4275,10 @@ pub struct PostJobRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub title: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -4098,6 +4357,10 @@ pub struct PostCompanyLocationsRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub zip: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -4176,6 +4439,10 @@ pub struct PutLocationRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub zip: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -4255,6 +4522,10 @@ pub struct PutComntractorRequest {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub wage_type: Option<WageType>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -4335,6 +4606,10 @@ pub struct PostCompanyContractorsRequest {
      */
     #[serde(default, skip_serializing_if = "WageType::is_noop")]
     pub wage_type: WageType,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -4368,6 +4643,10 @@ pub struct PutCompensationRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub version: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -4398,6 +4677,10 @@ pub struct PostJobCompensationsRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub rate: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -4468,6 +4751,10 @@ pub struct PostEmployeeGarnishmentsRequest {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub times: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -4546,6 +4833,10 @@ pub struct PutGarnishmentRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub version: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -4562,6 +4853,10 @@ pub struct PostEmployeeTerminationsRequest {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub run_termination_payroll: Option<bool>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -4621,6 +4916,10 @@ pub struct PutEmployeeHomeAddressRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub zip: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -4640,6 +4939,10 @@ pub struct PutCompanyPaySchedulesScheduleRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub version: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -4668,6 +4971,10 @@ pub struct PostCompanyBankAccountsRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub routing_number: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -4691,6 +4998,10 @@ pub struct PutCompanyBankAccountsVerifyRequest {
         deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub deposit_2: f64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -4733,6 +5044,10 @@ pub struct PostCompanyBenefitsRequest {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub responsible_for_employer_taxes: Option<bool>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -4761,6 +5076,10 @@ pub struct PutCompanyBenefitRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub version: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -4774,6 +5093,10 @@ pub struct PostCompanyEarningTypesRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -4787,6 +5110,10 @@ pub struct PutCompanyEarningTypeRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -4889,6 +5216,10 @@ pub struct PostEmployeeBenefitsRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub limit_option: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -4990,6 +5321,10 @@ pub struct PutEmployeeBenefitRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub version: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -5003,8 +5338,9 @@ pub enum GetCompanyPayrollsInclude {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GetCompanyPayrollsInclude {
@@ -5014,7 +5350,7 @@ impl std::fmt::Display for GetCompanyPayrollsInclude {
             GetCompanyPayrollsInclude::Deductions => "deductions",
             GetCompanyPayrollsInclude::Taxes => "taxes",
             GetCompanyPayrollsInclude::Noop => "",
-            GetCompanyPayrollsInclude::FallthroughString => "*",
+            GetCompanyPayrollsInclude::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -5035,8 +5371,9 @@ pub enum OffCycleReason {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OffCycleReason {
@@ -5045,7 +5382,7 @@ impl std::fmt::Display for OffCycleReason {
             OffCycleReason::Bonus => "Bonus",
             OffCycleReason::Correction => "Correction",
             OffCycleReason::Noop => "",
-            OffCycleReason::FallthroughString => "*",
+            OffCycleReason::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -5094,6 +5431,10 @@ pub struct PostCompanyPayrollsRequest {
         deserialize_with = "crate::utils::date_format::deserialize"
     )]
     pub start_date: Option<chrono::NaiveDate>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// An array of fixed compensations for the employee. Fixed compensations include tips, bonuses, and one time reimbursements.
@@ -5126,6 +5467,10 @@ pub struct PutCompanyPayrollsRequestEmployeeCompensationsFixed {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// An array of hourly compensations for the employee. Hourly compensations include regular, overtime, and double overtime hours.
@@ -5158,6 +5503,10 @@ pub struct PutCompanyPayrollsRequestEmployeeCompensationsHourly {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -5199,6 +5548,10 @@ pub struct PutCompanyPayrollsRequestEmployeeCompensations {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub paid_time_off: Vec<PayrollEmployeeCompensationsPaidTimeOff>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -5218,6 +5571,10 @@ pub struct PutCompanyPayrollsRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub version: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Information for the user who will be the primary payroll administrator for the new company.
@@ -5259,6 +5616,10 @@ pub struct User {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub phone: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -5290,6 +5651,10 @@ pub struct PostPartnerManagedCompaniesRequestCompany {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub trade_name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -5300,6 +5665,10 @@ pub struct PostPartnerManagedCompaniesRequest {
      * Information for the user who will be the primary payroll administrator for the new company.
      */
     pub user: User,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -5332,6 +5701,10 @@ pub struct PostPartnerManagedCompaniesResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub refresh_token: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -5399,6 +5772,10 @@ pub struct Addresses {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub zip: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -5457,6 +5834,10 @@ pub struct PostProvisionRequestCompany {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub trade_name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -5467,6 +5848,10 @@ pub struct PostProvisionRequest {
      * Information for the user who will be the primary payroll administrator for the new company.
      */
     pub user: User,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -5481,6 +5866,10 @@ pub struct PostProvisionResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub account_claim_url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -5494,6 +5883,10 @@ pub struct GetEmployeeCustomFieldsResponse {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub custom_fields: Vec<EmployeeCustomField>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -5504,6 +5897,10 @@ pub struct GetCompanyCustomFieldsResponse {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub custom_fields: Vec<CompanyCustomField>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -5518,8 +5915,9 @@ pub enum OnboardingPersonType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OnboardingPersonType {
@@ -5528,7 +5926,7 @@ impl std::fmt::Display for OnboardingPersonType {
             OnboardingPersonType::Contractor => "Contractor",
             OnboardingPersonType::Employee => "Employee",
             OnboardingPersonType::Noop => "",
-            OnboardingPersonType::FallthroughString => "*",
+            OnboardingPersonType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -5610,6 +6008,10 @@ pub struct PostCompanyJobApplicantsRequest {
         deserialize_with = "crate::utils::date_format::deserialize"
     )]
     pub start_date: Option<chrono::NaiveDate>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -5682,6 +6084,10 @@ pub struct PutCompanyJobApplicantRequest {
         deserialize_with = "crate::utils::date_format::deserialize"
     )]
     pub start_date: Option<chrono::NaiveDate>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -5737,6 +6143,10 @@ pub struct GetCompanyPayrollReversalsResponse {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub reversed_payroll_id: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -5769,6 +6179,10 @@ pub struct PostCompanyAdminsRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub last_name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -5834,6 +6248,10 @@ pub struct GetCompanyFederalTaxDetailsResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub version: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -5888,4 +6306,8 @@ pub struct PutCompanyFederalTaxDetailsRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub version: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
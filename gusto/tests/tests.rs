@@ -1,3 +1,12 @@
+use std::mem;
+
+use wiremock::{
+    matchers::{method, path, query_param, query_param_is_missing},
+    Mock, MockServer, ResponseTemplate,
+};
+
+use gusto_api::{Client, RootDemoServer};
+
 const EMPLOYEE: &str = r#"{
   "id": 7757869432666662,
   "uuid": "4b3f930f-82cd-48a8-b797-798686e12e5e",
@@ -135,12 +144,74 @@ fn test_deserialize_employee() {
             .unwrap()
             .clone()
             .to_string(),
-        // TODO: fix this better.
-        //"Commission Only Exempt".to_string()
-        "*".to_string()
+        "Commission Only Exempt".to_string()
     );
     assert_eq!(
         first_compensation.payment_unit.as_ref().unwrap().clone(),
         gusto_api::types::PaymentUnit::Year
     );
 }
+
+async fn mount_admins_page(server: &MockServer, page: u64, last: u64) {
+    let admins = format!("{}/v1/companies/1/admins", server.uri());
+    let mut links = vec![format!("<{admins}?page={last}&per=1>; rel=\"last\"")];
+    if page < last {
+        links.push(format!("<{admins}?page={}&per=1>; rel=\"next\"", page + 1));
+    }
+
+    let mock = Mock::given(method("GET")).and(path("/v1/companies/1/admins"));
+    let mock = if page == 1 {
+        mock.and(query_param_is_missing("page"))
+    } else {
+        mock.and(query_param("page", page.to_string()))
+            .and(query_param("per", "1"))
+    };
+    mock.respond_with(
+        ResponseTemplate::new(200)
+            .insert_header("link", links.join(", ").as_str())
+            .set_body_json(serde_json::json!([{ "email": format!("admin{page}@example.com") }])),
+    )
+    .mount(server)
+    .await;
+}
+
+#[tokio::test]
+async fn test_get_all_respects_max_pages() {
+    let server = MockServer::start().await;
+
+    for page in 1..=3 {
+        mount_admins_page(&server, page, 3).await;
+    }
+
+    let mut client = Client::new(
+        "client_id",
+        "client_secret",
+        "redirect_uri",
+        "token",
+        "",
+        RootDemoServer::default(),
+    );
+    client.with_host_override(server.uri());
+
+    let admins = client.admins_beta();
+    assert_eq!(
+        admins.get_all_company_admins("1").await.unwrap().body.len(),
+        3
+    );
+
+    client.with_max_pages(2);
+    let admins = client
+        .admins_beta()
+        .get_all_company_admins("1")
+        .await
+        .unwrap()
+        .body;
+    let emails = admins
+        .iter()
+        .map(|admin| admin.email.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(emails, vec!["admin1@example.com", "admin2@example.com"]);
+    assert_eq!(server.received_requests().await.unwrap().len(), 5);
+
+    mem::drop(server)
+}
@@ -1,6 +1,59 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`ActivityFeed::get_chimp_chatter`], set with a builder.
+pub struct ActivityFeedGetChimpChatterRequest<'a> {
+    client: &'a ActivityFeed,
+    count: Option<i64>,
+    offset: Option<i64>,
+}
+
+impl<'a> ActivityFeedGetChimpChatterRequest<'a> {
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::GetActivityFeedChimpChatterResponse>> {
+        let Self {
+            client,
+            count,
+            offset,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = count {
+            query_args.push(("count".to_string(), v.to_string()));
+        }
+        if let Some(v) = offset {
+            query_args.push(("offset".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/activity-feed/chimp-chatter?{}", query_), None);
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct ActivityFeed {
     pub client: Client,
 }
@@ -49,4 +102,20 @@ impl ActivityFeed {
             )
             .await
     }
+    /**
+     * Get latest chimp chatter.
+     *
+     * This function performs a `GET` to the `/activity-feed/chimp-chatter` endpoint.
+     *
+     * As opposed to `get_chimp_chatter`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Return the Chimp Chatter for this account ordered by most recent.
+     */
+    pub fn get_chimp_chatter_request(&self) -> ActivityFeedGetChimpChatterRequest<'_> {
+        ActivityFeedGetChimpChatterRequest {
+            client: self,
+            count: None,
+            offset: None,
+        }
+    }
 }
@@ -1,6 +1,139 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`AuthorizedApps::get`], set with a builder.
+pub struct AuthorizedAppsGetRequest<'a> {
+    client: &'a AuthorizedApps,
+    fields: Option<&'a [String]>,
+    exclude_fields: Option<&'a [String]>,
+    count: Option<i64>,
+    offset: Option<i64>,
+}
+
+impl<'a> AuthorizedAppsGetRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::GetAuthorizedAppsResponse>> {
+        let Self {
+            client,
+            fields,
+            exclude_fields,
+            count,
+            offset,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = count {
+            query_args.push(("count".to_string(), v.to_string()));
+        }
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = offset {
+            query_args.push(("offset".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/authorized-apps?{}", query_), None);
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`AuthorizedApps::get_authorized_apps`], set with a builder.
+pub struct AuthorizedAppsGetAuthorizedAppsRequest<'a> {
+    client: &'a AuthorizedApps,
+    fields: Option<&'a [String]>,
+    exclude_fields: Option<&'a [String]>,
+    app_id: &'a str,
+}
+
+impl<'a> AuthorizedAppsGetAuthorizedAppsRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::Apps>> {
+        let Self {
+            client,
+            fields,
+            exclude_fields,
+            app_id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/authorized-apps/{}?{}",
+                crate::progenitor_support::encode_path(app_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct AuthorizedApps {
     pub client: Client,
 }
@@ -59,6 +192,24 @@ impl AuthorizedApps {
             )
             .await
     }
+    /**
+     * List authorized apps.
+     *
+     * This function performs a `GET` to the `/authorized-apps` endpoint.
+     *
+     * As opposed to `get`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get a list of an account's registered, connected applications.
+     */
+    pub fn get_request(&self) -> AuthorizedAppsGetRequest<'_> {
+        AuthorizedAppsGetRequest {
+            client: self,
+            fields: None,
+            exclude_fields: None,
+            count: None,
+            offset: None,
+        }
+    }
     /**
      * Get authorized app info.
      *
@@ -104,4 +255,24 @@ impl AuthorizedApps {
             )
             .await
     }
+    /**
+     * Get authorized app info.
+     *
+     * This function performs a `GET` to the `/authorized-apps/{app_id}` endpoint.
+     *
+     * As opposed to `get_authorized_apps`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get information about a specific authorized application.
+     */
+    pub fn get_authorized_apps_request<'a>(
+        &'a self,
+        app_id: &'a str,
+    ) -> AuthorizedAppsGetAuthorizedAppsRequest<'a> {
+        AuthorizedAppsGetAuthorizedAppsRequest {
+            client: self,
+            fields: None,
+            exclude_fields: None,
+            app_id,
+        }
+    }
 }
@@ -1,6 +1,190 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Automations::get`], set with a builder.
+pub struct AutomationsGetRequest<'a> {
+    client: &'a Automations,
+    count: Option<i64>,
+    offset: Option<i64>,
+    fields: Option<&'a [String]>,
+    exclude_fields: Option<&'a [String]>,
+    before_create_time: Option<chrono::DateTime<chrono::Utc>>,
+    since_create_time: Option<chrono::DateTime<chrono::Utc>>,
+    before_start_time: Option<chrono::DateTime<chrono::Utc>>,
+    since_start_time: Option<chrono::DateTime<chrono::Utc>>,
+    status: Option<crate::types::Status>,
+}
+
+impl<'a> AutomationsGetRequest<'a> {
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sets the `before_create_time` query parameter.
+    pub fn before_create_time(mut self, before_create_time: chrono::DateTime<chrono::Utc>) -> Self {
+        self.before_create_time = Some(before_create_time);
+        self
+    }
+
+    /// Sets the `since_create_time` query parameter.
+    pub fn since_create_time(mut self, since_create_time: chrono::DateTime<chrono::Utc>) -> Self {
+        self.since_create_time = Some(since_create_time);
+        self
+    }
+
+    /// Sets the `before_start_time` query parameter.
+    pub fn before_start_time(mut self, before_start_time: chrono::DateTime<chrono::Utc>) -> Self {
+        self.before_start_time = Some(before_start_time);
+        self
+    }
+
+    /// Sets the `since_start_time` query parameter.
+    pub fn since_start_time(mut self, since_start_time: chrono::DateTime<chrono::Utc>) -> Self {
+        self.since_start_time = Some(since_start_time);
+        self
+    }
+
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: crate::types::Status) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::GetAutomationsResponse>> {
+        let Self {
+            client,
+            count,
+            offset,
+            fields,
+            exclude_fields,
+            before_create_time,
+            since_create_time,
+            before_start_time,
+            since_start_time,
+            status,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(date) = before_create_time {
+            query_args.push(("before_create_time".to_string(), date.to_rfc3339()));
+        }
+        if let Some(date) = before_start_time {
+            query_args.push(("before_start_time".to_string(), date.to_rfc3339()));
+        }
+        if let Some(v) = count {
+            query_args.push(("count".to_string(), v.to_string()));
+        }
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = offset {
+            query_args.push(("offset".to_string(), v.to_string()));
+        }
+        if let Some(date) = since_create_time {
+            query_args.push(("since_create_time".to_string(), date.to_rfc3339()));
+        }
+        if let Some(date) = since_start_time {
+            query_args.push(("since_start_time".to_string(), date.to_rfc3339()));
+        }
+        if let Some(v) = status {
+            query_args.push(("status".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(&format!("/automations?{}", query_), None);
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Automations::get_automations`], set with a builder.
+pub struct AutomationsGetAutomationsRequest<'a> {
+    client: &'a Automations,
+    fields: Option<&'a [String]>,
+    exclude_fields: Option<&'a [String]>,
+    workflow_id: &'a str,
+}
+
+impl<'a> AutomationsGetAutomationsRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::Automations>> {
+        let Self {
+            client,
+            fields,
+            exclude_fields,
+            workflow_id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/automations/{}?{}",
+                crate::progenitor_support::encode_path(workflow_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct Automations {
     pub client: Client,
 }
@@ -82,6 +266,29 @@ impl Automations {
             )
             .await
     }
+    /**
+     * List automations.
+     *
+     * This function performs a `GET` to the `/automations` endpoint.
+     *
+     * As opposed to `get`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get a summary of an account's classic automations.
+     */
+    pub fn get_request(&self) -> AutomationsGetRequest<'_> {
+        AutomationsGetRequest {
+            client: self,
+            count: None,
+            offset: None,
+            fields: None,
+            exclude_fields: None,
+            before_create_time: None,
+            since_create_time: None,
+            before_start_time: None,
+            since_start_time: None,
+            status: None,
+        }
+    }
     /**
      * Add automation.
      *
@@ -149,6 +356,26 @@ impl Automations {
             )
             .await
     }
+    /**
+     * Get automation info.
+     *
+     * This function performs a `GET` to the `/automations/{workflow_id}` endpoint.
+     *
+     * As opposed to `get_automations`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get a summary of an individual classic automation workflow's settings and content. The `trigger_settings` object returns information for the first email in the workflow.
+     */
+    pub fn get_automations_request<'a>(
+        &'a self,
+        workflow_id: &'a str,
+    ) -> AutomationsGetAutomationsRequest<'a> {
+        AutomationsGetAutomationsRequest {
+            client: self,
+            fields: None,
+            exclude_fields: None,
+            workflow_id,
+        }
+    }
     /**
      * Pause automation emails.
      *
@@ -1,6 +1,137 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`BatchWebhooks::get`], set with a builder.
+pub struct BatchWebhooksGetRequest<'a> {
+    client: &'a BatchWebhooks,
+    fields: Option<&'a [String]>,
+    exclude_fields: Option<&'a [String]>,
+    count: Option<i64>,
+    offset: Option<i64>,
+}
+
+impl<'a> BatchWebhooksGetRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::BatchWebhooks>> {
+        let Self {
+            client,
+            fields,
+            exclude_fields,
+            count,
+            offset,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = count {
+            query_args.push(("count".to_string(), v.to_string()));
+        }
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = offset {
+            query_args.push(("offset".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/batch-webhooks?{}", query_), None);
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`BatchWebhooks::get_batch_webhooks`], set with a builder.
+pub struct BatchWebhooksGetBatchWebhooksRequest<'a> {
+    client: &'a BatchWebhooks,
+    fields: Option<&'a [String]>,
+    exclude_fields: Option<&'a [String]>,
+    batch_webhook_id: &'a str,
+}
+
+impl<'a> BatchWebhooksGetBatchWebhooksRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::Webhooks>> {
+        let Self {
+            client,
+            fields,
+            exclude_fields,
+            batch_webhook_id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/batch-webhooks/{}?{}",
+                crate::progenitor_support::encode_path(batch_webhook_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct BatchWebhooks {
     pub client: Client,
 }
@@ -59,6 +190,24 @@ impl BatchWebhooks {
             )
             .await
     }
+    /**
+     * List batch webhooks.
+     *
+     * This function performs a `GET` to the `/batch-webhooks` endpoint.
+     *
+     * As opposed to `get`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get all webhooks that have been configured for batches.
+     */
+    pub fn get_request(&self) -> BatchWebhooksGetRequest<'_> {
+        BatchWebhooksGetRequest {
+            client: self,
+            fields: None,
+            exclude_fields: None,
+            count: None,
+            offset: None,
+        }
+    }
     /**
      * Add batch webhook.
      *
@@ -126,6 +275,26 @@ impl BatchWebhooks {
             )
             .await
     }
+    /**
+     * Get batch webhook info.
+     *
+     * This function performs a `GET` to the `/batch-webhooks/{batch_webhook_id}` endpoint.
+     *
+     * As opposed to `get_batch_webhooks`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get information about a specific batch webhook.
+     */
+    pub fn get_batch_webhooks_request<'a>(
+        &'a self,
+        batch_webhook_id: &'a str,
+    ) -> BatchWebhooksGetBatchWebhooksRequest<'a> {
+        BatchWebhooksGetBatchWebhooksRequest {
+            client: self,
+            fields: None,
+            exclude_fields: None,
+            batch_webhook_id,
+        }
+    }
     /**
      * Delete batch webhook.
      *
@@ -1,6 +1,135 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Batches::get`], set with a builder.
+pub struct BatchesGetRequest<'a> {
+    client: &'a Batches,
+    fields: Option<&'a [String]>,
+    exclude_fields: Option<&'a [String]>,
+    count: Option<i64>,
+    offset: Option<i64>,
+}
+
+impl<'a> BatchesGetRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::BatchOperations>> {
+        let Self {
+            client,
+            fields,
+            exclude_fields,
+            count,
+            offset,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = count {
+            query_args.push(("count".to_string(), v.to_string()));
+        }
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = offset {
+            query_args.push(("offset".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(&format!("/batches?{}", query_), None);
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Batches::get_batches`], set with a builder.
+pub struct BatchesGetBatchesRequest<'a> {
+    client: &'a Batches,
+    fields: Option<&'a [String]>,
+    exclude_fields: Option<&'a [String]>,
+    batch_id: &'a str,
+}
+
+impl<'a> BatchesGetBatchesRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::Batch>> {
+        let Self {
+            client,
+            fields,
+            exclude_fields,
+            batch_id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/batches/{}?{}",
+                crate::progenitor_support::encode_path(batch_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct Batches {
     pub client: Client,
 }
@@ -57,6 +186,24 @@ impl Batches {
             )
             .await
     }
+    /**
+     * List batch requests.
+     *
+     * This function performs a `GET` to the `/batches` endpoint.
+     *
+     * As opposed to `get`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get a summary of batch requests that have been made.
+     */
+    pub fn get_request(&self) -> BatchesGetRequest<'_> {
+        BatchesGetRequest {
+            client: self,
+            fields: None,
+            exclude_fields: None,
+            count: None,
+            offset: None,
+        }
+    }
     /**
      * Start batch operation.
      *
@@ -124,6 +271,23 @@ impl Batches {
             )
             .await
     }
+    /**
+     * Get batch operation status.
+     *
+     * This function performs a `GET` to the `/batches/{batch_id}` endpoint.
+     *
+     * As opposed to `get_batches`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get the status of a batch request.
+     */
+    pub fn get_batches_request<'a>(&'a self, batch_id: &'a str) -> BatchesGetBatchesRequest<'a> {
+        BatchesGetBatchesRequest {
+            client: self,
+            fields: None,
+            exclude_fields: None,
+            batch_id,
+        }
+    }
     /**
      * Delete batch request.
      *
@@ -0,0 +1,62 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`ActivityFeed::get_chimp_chatter`], set with a builder.
+pub struct ActivityFeedGetChimpChatterRequest<'a> {
+    inner: crate::activity_feed::ActivityFeedGetChimpChatterRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ActivityFeedGetChimpChatterRequest<'a> {
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.inner = self.inner.count(count);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.inner = self.inner.offset(offset);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::GetActivityFeedChimpChatterResponse>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::activity_feed::ActivityFeed`].
+pub struct ActivityFeed {
+    inner: crate::activity_feed::ActivityFeed,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl ActivityFeed {
+    pub(crate) fn new(
+        inner: crate::activity_feed::ActivityFeed,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        ActivityFeed { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::activity_feed::ActivityFeed::get_chimp_chatter`].
+    pub fn get_chimp_chatter(
+        &self,
+        count: i64,
+        offset: i64,
+    ) -> ClientResult<crate::Response<crate::types::GetActivityFeedChimpChatterResponse>> {
+        self.runtime
+            .block_on(self.inner.get_chimp_chatter(count, offset))
+    }
+    /// The blocking version of [`crate::activity_feed::ActivityFeed::get_chimp_chatter_request`].
+    pub fn get_chimp_chatter_request(&self) -> ActivityFeedGetChimpChatterRequest<'_> {
+        ActivityFeedGetChimpChatterRequest {
+            inner: self.inner.get_chimp_chatter_request(),
+            runtime: &self.runtime,
+        }
+    }
+}
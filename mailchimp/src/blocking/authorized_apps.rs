@@ -0,0 +1,121 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`AuthorizedApps::get`], set with a builder.
+pub struct AuthorizedAppsGetRequest<'a> {
+    inner: crate::authorized_apps::AuthorizedAppsGetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> AuthorizedAppsGetRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.inner = self.inner.count(count);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.inner = self.inner.offset(offset);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::GetAuthorizedAppsResponse>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`AuthorizedApps::get_authorized_apps`], set with a builder.
+pub struct AuthorizedAppsGetAuthorizedAppsRequest<'a> {
+    inner: crate::authorized_apps::AuthorizedAppsGetAuthorizedAppsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> AuthorizedAppsGetAuthorizedAppsRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::Apps>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::authorized_apps::AuthorizedApps`].
+pub struct AuthorizedApps {
+    inner: crate::authorized_apps::AuthorizedApps,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl AuthorizedApps {
+    pub(crate) fn new(
+        inner: crate::authorized_apps::AuthorizedApps,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        AuthorizedApps { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::authorized_apps::AuthorizedApps::get`].
+    pub fn get(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+    ) -> ClientResult<crate::Response<crate::types::GetAuthorizedAppsResponse>> {
+        self.runtime
+            .block_on(self.inner.get(fields, exclude_fields, count, offset))
+    }
+    /// The blocking version of [`crate::authorized_apps::AuthorizedApps::get_request`].
+    pub fn get_request(&self) -> AuthorizedAppsGetRequest<'_> {
+        AuthorizedAppsGetRequest {
+            inner: self.inner.get_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::authorized_apps::AuthorizedApps::get_authorized_apps`].
+    pub fn get_authorized_apps(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        app_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Apps>> {
+        self.runtime.block_on(
+            self.inner
+                .get_authorized_apps(fields, exclude_fields, app_id),
+        )
+    }
+    /// The blocking version of [`crate::authorized_apps::AuthorizedApps::get_authorized_apps_request`].
+    pub fn get_authorized_apps_request<'a>(
+        &'a self,
+        app_id: &'a str,
+    ) -> AuthorizedAppsGetAuthorizedAppsRequest<'a> {
+        AuthorizedAppsGetAuthorizedAppsRequest {
+            inner: self.inner.get_authorized_apps_request(app_id),
+            runtime: &self.runtime,
+        }
+    }
+}
@@ -0,0 +1,316 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Automations::get`], set with a builder.
+pub struct AutomationsGetRequest<'a> {
+    inner: crate::automations::AutomationsGetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> AutomationsGetRequest<'a> {
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.inner = self.inner.count(count);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.inner = self.inner.offset(offset);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sets the `before_create_time` query parameter.
+    pub fn before_create_time(mut self, before_create_time: chrono::DateTime<chrono::Utc>) -> Self {
+        self.inner = self.inner.before_create_time(before_create_time);
+        self
+    }
+
+    /// Sets the `since_create_time` query parameter.
+    pub fn since_create_time(mut self, since_create_time: chrono::DateTime<chrono::Utc>) -> Self {
+        self.inner = self.inner.since_create_time(since_create_time);
+        self
+    }
+
+    /// Sets the `before_start_time` query parameter.
+    pub fn before_start_time(mut self, before_start_time: chrono::DateTime<chrono::Utc>) -> Self {
+        self.inner = self.inner.before_start_time(before_start_time);
+        self
+    }
+
+    /// Sets the `since_start_time` query parameter.
+    pub fn since_start_time(mut self, since_start_time: chrono::DateTime<chrono::Utc>) -> Self {
+        self.inner = self.inner.since_start_time(since_start_time);
+        self
+    }
+
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: crate::types::Status) -> Self {
+        self.inner = self.inner.status(status);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::GetAutomationsResponse>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Automations::get_automations`], set with a builder.
+pub struct AutomationsGetAutomationsRequest<'a> {
+    inner: crate::automations::AutomationsGetAutomationsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> AutomationsGetAutomationsRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::Automations>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::automations::Automations`].
+pub struct Automations {
+    inner: crate::automations::Automations,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Automations {
+    pub(crate) fn new(
+        inner: crate::automations::Automations,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Automations { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::automations::Automations::get`].
+    pub fn get(
+        &self,
+        count: i64,
+        offset: i64,
+        fields: &[String],
+        exclude_fields: &[String],
+        before_create_time: Option<chrono::DateTime<chrono::Utc>>,
+        since_create_time: Option<chrono::DateTime<chrono::Utc>>,
+        before_start_time: Option<chrono::DateTime<chrono::Utc>>,
+        since_start_time: Option<chrono::DateTime<chrono::Utc>>,
+        status: crate::types::Status,
+    ) -> ClientResult<crate::Response<crate::types::GetAutomationsResponse>> {
+        self.runtime.block_on(self.inner.get(
+            count,
+            offset,
+            fields,
+            exclude_fields,
+            before_create_time,
+            since_create_time,
+            before_start_time,
+            since_start_time,
+            status,
+        ))
+    }
+    /// The blocking version of [`crate::automations::Automations::get_request`].
+    pub fn get_request(&self) -> AutomationsGetRequest<'_> {
+        AutomationsGetRequest {
+            inner: self.inner.get_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::automations::Automations::post`].
+    pub fn post(
+        &self,
+        body: &crate::types::AutomationWorkflow,
+    ) -> ClientResult<crate::Response<crate::types::Automations>> {
+        self.runtime.block_on(self.inner.post(body))
+    }
+    /// The blocking version of [`crate::automations::Automations::get_automations`].
+    pub fn get_automations(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        workflow_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Automations>> {
+        self.runtime.block_on(
+            self.inner
+                .get_automations(fields, exclude_fields, workflow_id),
+        )
+    }
+    /// The blocking version of [`crate::automations::Automations::get_automations_request`].
+    pub fn get_automations_request<'a>(
+        &'a self,
+        workflow_id: &'a str,
+    ) -> AutomationsGetAutomationsRequest<'a> {
+        AutomationsGetAutomationsRequest {
+            inner: self.inner.get_automations_request(workflow_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::automations::Automations::post_actions_pause_all_email`].
+    pub fn post_actions_pause_all_email(
+        &self,
+        workflow_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.post_actions_pause_all_email(workflow_id))
+    }
+    /// The blocking version of [`crate::automations::Automations::post_actions_start_all_email`].
+    pub fn post_actions_start_all_email(
+        &self,
+        workflow_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.post_actions_start_all_email(workflow_id))
+    }
+    /// The blocking version of [`crate::automations::Automations::archive`].
+    pub fn archive(&self, workflow_id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.archive(workflow_id))
+    }
+    /// The blocking version of [`crate::automations::Automations::get_email`].
+    pub fn get_email(
+        &self,
+        workflow_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::AutomationEmails>> {
+        self.runtime.block_on(self.inner.get_email(workflow_id))
+    }
+    /// The blocking version of [`crate::automations::Automations::get_email_automations`].
+    pub fn get_email_automations(
+        &self,
+        workflow_id: &str,
+        workflow_email_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Emails>> {
+        self.runtime.block_on(
+            self.inner
+                .get_email_automations(workflow_id, workflow_email_id),
+        )
+    }
+    /// The blocking version of [`crate::automations::Automations::delete_emails`].
+    pub fn delete_emails(
+        &self,
+        workflow_id: &str,
+        workflow_email_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete_emails(workflow_id, workflow_email_id))
+    }
+    /// The blocking version of [`crate::automations::Automations::patch_email_workflow`].
+    pub fn patch_email_workflow(
+        &self,
+        workflow_id: &str,
+        workflow_email_id: &str,
+        body: &crate::types::UpdateInformationAboutASpecificWorkflowEmail,
+    ) -> ClientResult<crate::Response<crate::types::Emails>> {
+        self.runtime.block_on(
+            self.inner
+                .patch_email_workflow(workflow_id, workflow_email_id, body),
+        )
+    }
+    /// The blocking version of [`crate::automations::Automations::get_emails_queue`].
+    pub fn get_emails_queue(
+        &self,
+        workflow_id: &str,
+        workflow_email_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::GetAutomationsEmailsQueueResponse>> {
+        self.runtime
+            .block_on(self.inner.get_emails_queue(workflow_id, workflow_email_id))
+    }
+    /// The blocking version of [`crate::automations::Automations::post_emails_queue`].
+    pub fn post_emails_queue(
+        &self,
+        workflow_id: &str,
+        workflow_email_id: &str,
+        body: &crate::types::SubscriberInAutomationQueue,
+    ) -> ClientResult<crate::Response<crate::types::SubscriberInAutomationQueueData>> {
+        self.runtime.block_on(
+            self.inner
+                .post_emails_queue(workflow_id, workflow_email_id, body),
+        )
+    }
+    /// The blocking version of [`crate::automations::Automations::get_emails_queue_automations`].
+    pub fn get_emails_queue_automations(
+        &self,
+        workflow_id: &str,
+        workflow_email_id: &str,
+        subscriber_hash: &str,
+    ) -> ClientResult<crate::Response<crate::types::SubscriberInAutomationQueueData>> {
+        self.runtime
+            .block_on(self.inner.get_emails_queue_automations(
+                workflow_id,
+                workflow_email_id,
+                subscriber_hash,
+            ))
+    }
+    /// The blocking version of [`crate::automations::Automations::post_emails_actions_pause`].
+    pub fn post_emails_actions_pause(
+        &self,
+        workflow_id: &str,
+        workflow_email_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .post_emails_actions_pause(workflow_id, workflow_email_id),
+        )
+    }
+    /// The blocking version of [`crate::automations::Automations::post_emails_actions_start`].
+    pub fn post_emails_actions_start(
+        &self,
+        workflow_id: &str,
+        workflow_email_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .post_emails_actions_start(workflow_id, workflow_email_id),
+        )
+    }
+    /// The blocking version of [`crate::automations::Automations::get_removed_subscriber`].
+    pub fn get_removed_subscriber(
+        &self,
+        workflow_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::RemovedSubscribers>> {
+        self.runtime
+            .block_on(self.inner.get_removed_subscriber(workflow_id))
+    }
+    /// The blocking version of [`crate::automations::Automations::post_removed_subscriber`].
+    pub fn post_removed_subscriber(
+        &self,
+        workflow_id: &str,
+        body: &crate::types::SubscriberInAutomationQueue,
+    ) -> ClientResult<crate::Response<crate::types::Subscribers>> {
+        self.runtime
+            .block_on(self.inner.post_removed_subscriber(workflow_id, body))
+    }
+    /// The blocking version of [`crate::automations::Automations::get_removed_subscriber_automations`].
+    pub fn get_removed_subscriber_automations(
+        &self,
+        workflow_id: &str,
+        subscriber_hash: &str,
+    ) -> ClientResult<crate::Response<crate::types::Subscribers>> {
+        self.runtime.block_on(
+            self.inner
+                .get_removed_subscriber_automations(workflow_id, subscriber_hash),
+        )
+    }
+}
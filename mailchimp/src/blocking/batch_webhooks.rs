@@ -0,0 +1,142 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`BatchWebhooks::get`], set with a builder.
+pub struct BatchWebhooksGetRequest<'a> {
+    inner: crate::batch_webhooks::BatchWebhooksGetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> BatchWebhooksGetRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.inner = self.inner.count(count);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.inner = self.inner.offset(offset);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::BatchWebhooks>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`BatchWebhooks::get_batch_webhooks`], set with a builder.
+pub struct BatchWebhooksGetBatchWebhooksRequest<'a> {
+    inner: crate::batch_webhooks::BatchWebhooksGetBatchWebhooksRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> BatchWebhooksGetBatchWebhooksRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::Webhooks>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::batch_webhooks::BatchWebhooks`].
+pub struct BatchWebhooks {
+    inner: crate::batch_webhooks::BatchWebhooks,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl BatchWebhooks {
+    pub(crate) fn new(
+        inner: crate::batch_webhooks::BatchWebhooks,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        BatchWebhooks { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::batch_webhooks::BatchWebhooks::get`].
+    pub fn get(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+    ) -> ClientResult<crate::Response<crate::types::BatchWebhooks>> {
+        self.runtime
+            .block_on(self.inner.get(fields, exclude_fields, count, offset))
+    }
+    /// The blocking version of [`crate::batch_webhooks::BatchWebhooks::get_request`].
+    pub fn get_request(&self) -> BatchWebhooksGetRequest<'_> {
+        BatchWebhooksGetRequest {
+            inner: self.inner.get_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::batch_webhooks::BatchWebhooks::post`].
+    pub fn post(
+        &self,
+        body: &crate::types::BatchWebhook,
+    ) -> ClientResult<crate::Response<crate::types::Webhooks>> {
+        self.runtime.block_on(self.inner.post(body))
+    }
+    /// The blocking version of [`crate::batch_webhooks::BatchWebhooks::get_batch_webhooks`].
+    pub fn get_batch_webhooks(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        batch_webhook_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Webhooks>> {
+        self.runtime.block_on(self.inner.get_batch_webhooks(
+            fields,
+            exclude_fields,
+            batch_webhook_id,
+        ))
+    }
+    /// The blocking version of [`crate::batch_webhooks::BatchWebhooks::get_batch_webhooks_request`].
+    pub fn get_batch_webhooks_request<'a>(
+        &'a self,
+        batch_webhook_id: &'a str,
+    ) -> BatchWebhooksGetBatchWebhooksRequest<'a> {
+        BatchWebhooksGetBatchWebhooksRequest {
+            inner: self.inner.get_batch_webhooks_request(batch_webhook_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::batch_webhooks::BatchWebhooks::delete`].
+    pub fn delete(&self, batch_webhook_id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.delete(batch_webhook_id))
+    }
+    /// The blocking version of [`crate::batch_webhooks::BatchWebhooks::patch`].
+    pub fn patch(
+        &self,
+        batch_webhook_id: &str,
+        body: &crate::types::BatchWebhook,
+    ) -> ClientResult<crate::Response<crate::types::Webhooks>> {
+        self.runtime
+            .block_on(self.inner.patch(batch_webhook_id, body))
+    }
+}
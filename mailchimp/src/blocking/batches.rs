@@ -0,0 +1,127 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Batches::get`], set with a builder.
+pub struct BatchesGetRequest<'a> {
+    inner: crate::batches::BatchesGetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> BatchesGetRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.inner = self.inner.count(count);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.inner = self.inner.offset(offset);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::BatchOperations>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Batches::get_batches`], set with a builder.
+pub struct BatchesGetBatchesRequest<'a> {
+    inner: crate::batches::BatchesGetBatchesRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> BatchesGetBatchesRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::Batch>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::batches::Batches`].
+pub struct Batches {
+    inner: crate::batches::Batches,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Batches {
+    pub(crate) fn new(
+        inner: crate::batches::Batches,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Batches { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::batches::Batches::get`].
+    pub fn get(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+    ) -> ClientResult<crate::Response<crate::types::BatchOperations>> {
+        self.runtime
+            .block_on(self.inner.get(fields, exclude_fields, count, offset))
+    }
+    /// The blocking version of [`crate::batches::Batches::get_request`].
+    pub fn get_request(&self) -> BatchesGetRequest<'_> {
+        BatchesGetRequest {
+            inner: self.inner.get_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::batches::Batches::post`].
+    pub fn post(
+        &self,
+        body: &crate::types::PostBatchesRequest,
+    ) -> ClientResult<crate::Response<crate::types::Batch>> {
+        self.runtime.block_on(self.inner.post(body))
+    }
+    /// The blocking version of [`crate::batches::Batches::get_batches`].
+    pub fn get_batches(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        batch_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Batch>> {
+        self.runtime
+            .block_on(self.inner.get_batches(fields, exclude_fields, batch_id))
+    }
+    /// The blocking version of [`crate::batches::Batches::get_batches_request`].
+    pub fn get_batches_request<'a>(&'a self, batch_id: &'a str) -> BatchesGetBatchesRequest<'a> {
+        BatchesGetBatchesRequest {
+            inner: self.inner.get_batches_request(batch_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::batches::Batches::delete`].
+    pub fn delete(&self, batch_id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.delete(batch_id))
+    }
+}
@@ -0,0 +1,140 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`CampaignFolders::get`], set with a builder.
+pub struct CampaignFoldersGetRequest<'a> {
+    inner: crate::campaign_folders::CampaignFoldersGetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> CampaignFoldersGetRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.inner = self.inner.count(count);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.inner = self.inner.offset(offset);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::CampaignFolders>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`CampaignFolders::get_campaign_folders`], set with a builder.
+pub struct CampaignFoldersGetCampaignFoldersRequest<'a> {
+    inner: crate::campaign_folders::CampaignFoldersGetCampaignFoldersRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> CampaignFoldersGetCampaignFoldersRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::CampaignFolder>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::campaign_folders::CampaignFolders`].
+pub struct CampaignFolders {
+    inner: crate::campaign_folders::CampaignFolders,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl CampaignFolders {
+    pub(crate) fn new(
+        inner: crate::campaign_folders::CampaignFolders,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        CampaignFolders { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::campaign_folders::CampaignFolders::get`].
+    pub fn get(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+    ) -> ClientResult<crate::Response<crate::types::CampaignFolders>> {
+        self.runtime
+            .block_on(self.inner.get(fields, exclude_fields, count, offset))
+    }
+    /// The blocking version of [`crate::campaign_folders::CampaignFolders::get_request`].
+    pub fn get_request(&self) -> CampaignFoldersGetRequest<'_> {
+        CampaignFoldersGetRequest {
+            inner: self.inner.get_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::campaign_folders::CampaignFolders::post`].
+    pub fn post(
+        &self,
+        body: &crate::types::GalleryFolder,
+    ) -> ClientResult<crate::Response<crate::types::CampaignFolder>> {
+        self.runtime.block_on(self.inner.post(body))
+    }
+    /// The blocking version of [`crate::campaign_folders::CampaignFolders::get_campaign_folders`].
+    pub fn get_campaign_folders(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        folder_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::CampaignFolder>> {
+        self.runtime.block_on(
+            self.inner
+                .get_campaign_folders(fields, exclude_fields, folder_id),
+        )
+    }
+    /// The blocking version of [`crate::campaign_folders::CampaignFolders::get_campaign_folders_request`].
+    pub fn get_campaign_folders_request<'a>(
+        &'a self,
+        folder_id: &'a str,
+    ) -> CampaignFoldersGetCampaignFoldersRequest<'a> {
+        CampaignFoldersGetCampaignFoldersRequest {
+            inner: self.inner.get_campaign_folders_request(folder_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::campaign_folders::CampaignFolders::delete`].
+    pub fn delete(&self, folder_id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.delete(folder_id))
+    }
+    /// The blocking version of [`crate::campaign_folders::CampaignFolders::patch`].
+    pub fn patch(
+        &self,
+        folder_id: &str,
+        body: &crate::types::GalleryFolder,
+    ) -> ClientResult<crate::Response<crate::types::CampaignFolder>> {
+        self.runtime.block_on(self.inner.patch(folder_id, body))
+    }
+}
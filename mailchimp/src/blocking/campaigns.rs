@@ -0,0 +1,518 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Campaigns::get`], set with a builder.
+pub struct CampaignsGetRequest<'a> {
+    inner: crate::campaigns::CampaignsGetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> CampaignsGetRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.inner = self.inner.count(count);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.inner = self.inner.offset(offset);
+        self
+    }
+
+    /// Sets the `type` query parameter.
+    pub fn type_(mut self, type_: crate::types::CampaignType) -> Self {
+        self.inner = self.inner.type_(type_);
+        self
+    }
+
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: crate::types::GetCampaignsStatus) -> Self {
+        self.inner = self.inner.status(status);
+        self
+    }
+
+    /// Sets the `before_send_time` query parameter.
+    pub fn before_send_time(mut self, before_send_time: chrono::DateTime<chrono::Utc>) -> Self {
+        self.inner = self.inner.before_send_time(before_send_time);
+        self
+    }
+
+    /// Sets the `since_send_time` query parameter.
+    pub fn since_send_time(mut self, since_send_time: chrono::DateTime<chrono::Utc>) -> Self {
+        self.inner = self.inner.since_send_time(since_send_time);
+        self
+    }
+
+    /// Sets the `before_create_time` query parameter.
+    pub fn before_create_time(mut self, before_create_time: chrono::DateTime<chrono::Utc>) -> Self {
+        self.inner = self.inner.before_create_time(before_create_time);
+        self
+    }
+
+    /// Sets the `since_create_time` query parameter.
+    pub fn since_create_time(mut self, since_create_time: chrono::DateTime<chrono::Utc>) -> Self {
+        self.inner = self.inner.since_create_time(since_create_time);
+        self
+    }
+
+    /// Sets the `list_id` query parameter.
+    pub fn list_id(mut self, list_id: &'a str) -> Self {
+        self.inner = self.inner.list_id(list_id);
+        self
+    }
+
+    /// Sets the `folder_id` query parameter.
+    pub fn folder_id(mut self, folder_id: &'a str) -> Self {
+        self.inner = self.inner.folder_id(folder_id);
+        self
+    }
+
+    /// Sets the `member_id` query parameter.
+    pub fn member_id(mut self, member_id: &'a str) -> Self {
+        self.inner = self.inner.member_id(member_id);
+        self
+    }
+
+    /// Sets the `sort_field` query parameter.
+    pub fn sort_field(mut self, sort_field: crate::types::SortField) -> Self {
+        self.inner = self.inner.sort_field(sort_field);
+        self
+    }
+
+    /// Sets the `sort_dir` query parameter.
+    pub fn sort_dir(mut self, sort_dir: crate::types::SortDir) -> Self {
+        self.inner = self.inner.sort_dir(sort_dir);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::GetCampaignsResponse>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Campaigns::get_campaigns`], set with a builder.
+pub struct CampaignsGetCampaignsRequest<'a> {
+    inner: crate::campaigns::CampaignsGetCampaignsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> CampaignsGetCampaignsRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::Campaign>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Campaigns::get_content`], set with a builder.
+pub struct CampaignsGetContentRequest<'a> {
+    inner: crate::campaigns::CampaignsGetContentRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> CampaignsGetContentRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::CampaignContent>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Campaigns::get_feedback`], set with a builder.
+pub struct CampaignsGetFeedbackRequest<'a> {
+    inner: crate::campaigns::CampaignsGetFeedbackRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> CampaignsGetFeedbackRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::CampaignReports>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Campaigns::get_feedback_campaigns`], set with a builder.
+pub struct CampaignsGetFeedbackCampaignsRequest<'a> {
+    inner: crate::campaigns::CampaignsGetFeedbackCampaignsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> CampaignsGetFeedbackCampaignsRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::CampaignFeedbackData>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Campaigns::get_send_checklist`], set with a builder.
+pub struct CampaignsGetSendChecklistRequest<'a> {
+    inner: crate::campaigns::CampaignsGetSendChecklistRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> CampaignsGetSendChecklistRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::SendChecklist>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::campaigns::Campaigns`].
+pub struct Campaigns {
+    inner: crate::campaigns::Campaigns,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Campaigns {
+    pub(crate) fn new(
+        inner: crate::campaigns::Campaigns,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Campaigns { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::campaigns::Campaigns::get`].
+    pub fn get(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        type_: crate::types::CampaignType,
+        status: crate::types::GetCampaignsStatus,
+        before_send_time: Option<chrono::DateTime<chrono::Utc>>,
+        since_send_time: Option<chrono::DateTime<chrono::Utc>>,
+        before_create_time: Option<chrono::DateTime<chrono::Utc>>,
+        since_create_time: Option<chrono::DateTime<chrono::Utc>>,
+        list_id: &str,
+        folder_id: &str,
+        member_id: &str,
+        sort_field: crate::types::SortField,
+        sort_dir: crate::types::SortDir,
+    ) -> ClientResult<crate::Response<crate::types::GetCampaignsResponse>> {
+        self.runtime.block_on(self.inner.get(
+            fields,
+            exclude_fields,
+            count,
+            offset,
+            type_,
+            status,
+            before_send_time,
+            since_send_time,
+            before_create_time,
+            since_create_time,
+            list_id,
+            folder_id,
+            member_id,
+            sort_field,
+            sort_dir,
+        ))
+    }
+    /// The blocking version of [`crate::campaigns::Campaigns::get_request`].
+    pub fn get_request(&self) -> CampaignsGetRequest<'_> {
+        CampaignsGetRequest {
+            inner: self.inner.get_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::campaigns::Campaigns::post`].
+    pub fn post(
+        &self,
+        body: &crate::types::CreatedCampaign,
+    ) -> ClientResult<crate::Response<crate::types::Campaign>> {
+        self.runtime.block_on(self.inner.post(body))
+    }
+    /// The blocking version of [`crate::campaigns::Campaigns::get_campaigns`].
+    pub fn get_campaigns(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        campaign_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Campaign>> {
+        self.runtime.block_on(
+            self.inner
+                .get_campaigns(fields, exclude_fields, campaign_id),
+        )
+    }
+    /// The blocking version of [`crate::campaigns::Campaigns::get_campaigns_request`].
+    pub fn get_campaigns_request<'a>(
+        &'a self,
+        campaign_id: &'a str,
+    ) -> CampaignsGetCampaignsRequest<'a> {
+        CampaignsGetCampaignsRequest {
+            inner: self.inner.get_campaigns_request(campaign_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::campaigns::Campaigns::delete`].
+    pub fn delete(&self, campaign_id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.delete(campaign_id))
+    }
+    /// The blocking version of [`crate::campaigns::Campaigns::patch`].
+    pub fn patch(
+        &self,
+        campaign_id: &str,
+        body: &crate::types::CampaignData,
+    ) -> ClientResult<crate::Response<crate::types::Campaign>> {
+        self.runtime.block_on(self.inner.patch(campaign_id, body))
+    }
+    /// The blocking version of [`crate::campaigns::Campaigns::post_actions_cancel_send`].
+    pub fn post_actions_cancel_send(&self, campaign_id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.post_actions_cancel_send(campaign_id))
+    }
+    /// The blocking version of [`crate::campaigns::Campaigns::post_actions_replicate`].
+    pub fn post_actions_replicate(
+        &self,
+        campaign_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::CampaignDataType>> {
+        self.runtime
+            .block_on(self.inner.post_actions_replicate(campaign_id))
+    }
+    /// The blocking version of [`crate::campaigns::Campaigns::post_actions_send`].
+    pub fn post_actions_send(&self, campaign_id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.post_actions_send(campaign_id))
+    }
+    /// The blocking version of [`crate::campaigns::Campaigns::post_actions_schedule`].
+    pub fn post_actions_schedule(
+        &self,
+        campaign_id: &str,
+        body: &crate::types::PostCampaignsActionsScheduleRequest,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.post_actions_schedule(campaign_id, body))
+    }
+    /// The blocking version of [`crate::campaigns::Campaigns::post_actions_unschedule`].
+    pub fn post_actions_unschedule(&self, campaign_id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.post_actions_unschedule(campaign_id))
+    }
+    /// The blocking version of [`crate::campaigns::Campaigns::post_actions_test`].
+    pub fn post_actions_test(
+        &self,
+        campaign_id: &str,
+        body: &crate::types::PostCampaignsActionsTestRequest,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.post_actions_test(campaign_id, body))
+    }
+    /// The blocking version of [`crate::campaigns::Campaigns::post_actions_pause`].
+    pub fn post_actions_pause(&self, campaign_id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.post_actions_pause(campaign_id))
+    }
+    /// The blocking version of [`crate::campaigns::Campaigns::post_actions_resume`].
+    pub fn post_actions_resume(&self, campaign_id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.post_actions_resume(campaign_id))
+    }
+    /// The blocking version of [`crate::campaigns::Campaigns::post_actions_create_resend`].
+    pub fn post_actions_create_resend(
+        &self,
+        campaign_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::CampaignDataType>> {
+        self.runtime
+            .block_on(self.inner.post_actions_create_resend(campaign_id))
+    }
+    /// The blocking version of [`crate::campaigns::Campaigns::get_content`].
+    pub fn get_content(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        campaign_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::CampaignContent>> {
+        self.runtime
+            .block_on(self.inner.get_content(fields, exclude_fields, campaign_id))
+    }
+    /// The blocking version of [`crate::campaigns::Campaigns::get_content_request`].
+    pub fn get_content_request<'a>(
+        &'a self,
+        campaign_id: &'a str,
+    ) -> CampaignsGetContentRequest<'a> {
+        CampaignsGetContentRequest {
+            inner: self.inner.get_content_request(campaign_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::campaigns::Campaigns::put_content`].
+    pub fn put_content(
+        &self,
+        campaign_id: &str,
+        body: &crate::types::CampaignContentData,
+    ) -> ClientResult<crate::Response<crate::types::CampaignContent>> {
+        self.runtime
+            .block_on(self.inner.put_content(campaign_id, body))
+    }
+    /// The blocking version of [`crate::campaigns::Campaigns::get_feedback`].
+    pub fn get_feedback(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        campaign_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::CampaignReports>> {
+        self.runtime
+            .block_on(self.inner.get_feedback(fields, exclude_fields, campaign_id))
+    }
+    /// The blocking version of [`crate::campaigns::Campaigns::get_feedback_request`].
+    pub fn get_feedback_request<'a>(
+        &'a self,
+        campaign_id: &'a str,
+    ) -> CampaignsGetFeedbackRequest<'a> {
+        CampaignsGetFeedbackRequest {
+            inner: self.inner.get_feedback_request(campaign_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::campaigns::Campaigns::post_feedback`].
+    pub fn post_feedback(
+        &self,
+        campaign_id: &str,
+        body: &crate::types::CampaignFeedback,
+    ) -> ClientResult<crate::Response<crate::types::CampaignFeedbackData>> {
+        self.runtime
+            .block_on(self.inner.post_feedback(campaign_id, body))
+    }
+    /// The blocking version of [`crate::campaigns::Campaigns::get_feedback_campaigns`].
+    pub fn get_feedback_campaigns(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        campaign_id: &str,
+        feedback_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::CampaignFeedbackData>> {
+        self.runtime.block_on(self.inner.get_feedback_campaigns(
+            fields,
+            exclude_fields,
+            campaign_id,
+            feedback_id,
+        ))
+    }
+    /// The blocking version of [`crate::campaigns::Campaigns::get_feedback_campaigns_request`].
+    pub fn get_feedback_campaigns_request<'a>(
+        &'a self,
+        campaign_id: &'a str,
+        feedback_id: &'a str,
+    ) -> CampaignsGetFeedbackCampaignsRequest<'a> {
+        CampaignsGetFeedbackCampaignsRequest {
+            inner: self
+                .inner
+                .get_feedback_campaigns_request(campaign_id, feedback_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::campaigns::Campaigns::delete_feedback`].
+    pub fn delete_feedback(
+        &self,
+        campaign_id: &str,
+        feedback_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete_feedback(campaign_id, feedback_id))
+    }
+    /// The blocking version of [`crate::campaigns::Campaigns::patch_feedback`].
+    pub fn patch_feedback(
+        &self,
+        campaign_id: &str,
+        feedback_id: &str,
+        body: &crate::types::CampaignFeedbackDataType,
+    ) -> ClientResult<crate::Response<crate::types::CampaignFeedbackData>> {
+        self.runtime
+            .block_on(self.inner.patch_feedback(campaign_id, feedback_id, body))
+    }
+    /// The blocking version of [`crate::campaigns::Campaigns::get_send_checklist`].
+    pub fn get_send_checklist(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        campaign_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::SendChecklist>> {
+        self.runtime.block_on(
+            self.inner
+                .get_send_checklist(fields, exclude_fields, campaign_id),
+        )
+    }
+    /// The blocking version of [`crate::campaigns::Campaigns::get_send_checklist_request`].
+    pub fn get_send_checklist_request<'a>(
+        &'a self,
+        campaign_id: &'a str,
+    ) -> CampaignsGetSendChecklistRequest<'a> {
+        CampaignsGetSendChecklistRequest {
+            inner: self.inner.get_send_checklist_request(campaign_id),
+            runtime: &self.runtime,
+        }
+    }
+}
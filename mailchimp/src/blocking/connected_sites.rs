@@ -0,0 +1,143 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`ConnectedSites::get`], set with a builder.
+pub struct ConnectedSitesGetRequest<'a> {
+    inner: crate::connected_sites::ConnectedSitesGetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ConnectedSitesGetRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.inner = self.inner.count(count);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.inner = self.inner.offset(offset);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::ConnectedSites>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`ConnectedSites::get_connected_sites`], set with a builder.
+pub struct ConnectedSitesGetConnectedSitesRequest<'a> {
+    inner: crate::connected_sites::ConnectedSitesGetConnectedSitesRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ConnectedSitesGetConnectedSitesRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::Sites>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::connected_sites::ConnectedSites`].
+pub struct ConnectedSites {
+    inner: crate::connected_sites::ConnectedSites,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl ConnectedSites {
+    pub(crate) fn new(
+        inner: crate::connected_sites::ConnectedSites,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        ConnectedSites { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::connected_sites::ConnectedSites::get`].
+    pub fn get(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+    ) -> ClientResult<crate::Response<crate::types::ConnectedSites>> {
+        self.runtime
+            .block_on(self.inner.get(fields, exclude_fields, count, offset))
+    }
+    /// The blocking version of [`crate::connected_sites::ConnectedSites::get_request`].
+    pub fn get_request(&self) -> ConnectedSitesGetRequest<'_> {
+        ConnectedSitesGetRequest {
+            inner: self.inner.get_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::connected_sites::ConnectedSites::post`].
+    pub fn post(
+        &self,
+        body: &crate::types::ConnectedSite,
+    ) -> ClientResult<crate::Response<crate::types::Sites>> {
+        self.runtime.block_on(self.inner.post(body))
+    }
+    /// The blocking version of [`crate::connected_sites::ConnectedSites::get_connected_sites`].
+    pub fn get_connected_sites(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        connected_site_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Sites>> {
+        self.runtime.block_on(self.inner.get_connected_sites(
+            fields,
+            exclude_fields,
+            connected_site_id,
+        ))
+    }
+    /// The blocking version of [`crate::connected_sites::ConnectedSites::get_connected_sites_request`].
+    pub fn get_connected_sites_request<'a>(
+        &'a self,
+        connected_site_id: &'a str,
+    ) -> ConnectedSitesGetConnectedSitesRequest<'a> {
+        ConnectedSitesGetConnectedSitesRequest {
+            inner: self.inner.get_connected_sites_request(connected_site_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::connected_sites::ConnectedSites::delete`].
+    pub fn delete(&self, connected_site_id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.delete(connected_site_id))
+    }
+    /// The blocking version of [`crate::connected_sites::ConnectedSites::post_actions_verify_script_installation`].
+    pub fn post_actions_verify_script_installation(
+        &self,
+        connected_site_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .post_actions_verify_script_installation(connected_site_id),
+        )
+    }
+}
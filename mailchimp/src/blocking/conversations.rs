@@ -0,0 +1,276 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Conversations::get`], set with a builder.
+pub struct ConversationsGetRequest<'a> {
+    inner: crate::conversations::ConversationsGetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ConversationsGetRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.inner = self.inner.count(count);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.inner = self.inner.offset(offset);
+        self
+    }
+
+    /// Sets the `has_unread_messages` query parameter.
+    pub fn has_unread_messages(mut self, has_unread_messages: crate::types::IsRead) -> Self {
+        self.inner = self.inner.has_unread_messages(has_unread_messages);
+        self
+    }
+
+    /// Sets the `list_id` query parameter.
+    pub fn list_id(mut self, list_id: &'a str) -> Self {
+        self.inner = self.inner.list_id(list_id);
+        self
+    }
+
+    /// Sets the `campaign_id` query parameter.
+    pub fn campaign_id(mut self, campaign_id: &'a str) -> Self {
+        self.inner = self.inner.campaign_id(campaign_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::TrackedConversations>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Conversations::get_conversations`], set with a builder.
+pub struct ConversationsGetConversationsRequest<'a> {
+    inner: crate::conversations::ConversationsGetConversationsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ConversationsGetConversationsRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::Conversation>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Conversations::get_message`], set with a builder.
+pub struct ConversationsGetMessageRequest<'a> {
+    inner: crate::conversations::ConversationsGetMessageRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ConversationsGetMessageRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sets the `is_read` query parameter.
+    pub fn is_read(mut self, is_read: crate::types::IsRead) -> Self {
+        self.inner = self.inner.is_read(is_read);
+        self
+    }
+
+    /// Sets the `before_timestamp` query parameter.
+    pub fn before_timestamp(mut self, before_timestamp: chrono::DateTime<chrono::Utc>) -> Self {
+        self.inner = self.inner.before_timestamp(before_timestamp);
+        self
+    }
+
+    /// Sets the `since_timestamp` query parameter.
+    pub fn since_timestamp(mut self, since_timestamp: chrono::DateTime<chrono::Utc>) -> Self {
+        self.inner = self.inner.since_timestamp(since_timestamp);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::CollectionOfConversationMessages>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Conversations::get_message_conversations`], set with a builder.
+pub struct ConversationsGetMessageConversationsRequest<'a> {
+    inner: crate::conversations::ConversationsGetMessageConversationsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ConversationsGetMessageConversationsRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::ConversationMessage>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::conversations::Conversations`].
+pub struct Conversations {
+    inner: crate::conversations::Conversations,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Conversations {
+    pub(crate) fn new(
+        inner: crate::conversations::Conversations,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Conversations { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::conversations::Conversations::get`].
+    pub fn get(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        has_unread_messages: crate::types::IsRead,
+        list_id: &str,
+        campaign_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::TrackedConversations>> {
+        self.runtime.block_on(self.inner.get(
+            fields,
+            exclude_fields,
+            count,
+            offset,
+            has_unread_messages,
+            list_id,
+            campaign_id,
+        ))
+    }
+    /// The blocking version of [`crate::conversations::Conversations::get_request`].
+    pub fn get_request(&self) -> ConversationsGetRequest<'_> {
+        ConversationsGetRequest {
+            inner: self.inner.get_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::conversations::Conversations::get_conversations`].
+    pub fn get_conversations(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        conversation_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Conversation>> {
+        self.runtime.block_on(
+            self.inner
+                .get_conversations(fields, exclude_fields, conversation_id),
+        )
+    }
+    /// The blocking version of [`crate::conversations::Conversations::get_conversations_request`].
+    pub fn get_conversations_request<'a>(
+        &'a self,
+        conversation_id: &'a str,
+    ) -> ConversationsGetConversationsRequest<'a> {
+        ConversationsGetConversationsRequest {
+            inner: self.inner.get_conversations_request(conversation_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::conversations::Conversations::get_message`].
+    pub fn get_message(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        conversation_id: &str,
+        is_read: crate::types::IsRead,
+        before_timestamp: Option<chrono::DateTime<chrono::Utc>>,
+        since_timestamp: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> ClientResult<crate::Response<crate::types::CollectionOfConversationMessages>> {
+        self.runtime.block_on(self.inner.get_message(
+            fields,
+            exclude_fields,
+            conversation_id,
+            is_read,
+            before_timestamp,
+            since_timestamp,
+        ))
+    }
+    /// The blocking version of [`crate::conversations::Conversations::get_message_request`].
+    pub fn get_message_request<'a>(
+        &'a self,
+        conversation_id: &'a str,
+    ) -> ConversationsGetMessageRequest<'a> {
+        ConversationsGetMessageRequest {
+            inner: self.inner.get_message_request(conversation_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::conversations::Conversations::get_message_conversations`].
+    pub fn get_message_conversations(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        conversation_id: &str,
+        message_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ConversationMessage>> {
+        self.runtime.block_on(self.inner.get_message_conversations(
+            fields,
+            exclude_fields,
+            conversation_id,
+            message_id,
+        ))
+    }
+    /// The blocking version of [`crate::conversations::Conversations::get_message_conversations_request`].
+    pub fn get_message_conversations_request<'a>(
+        &'a self,
+        conversation_id: &'a str,
+        message_id: &'a str,
+    ) -> ConversationsGetMessageConversationsRequest<'a> {
+        ConversationsGetMessageConversationsRequest {
+            inner: self
+                .inner
+                .get_message_conversations_request(conversation_id, message_id),
+            runtime: &self.runtime,
+        }
+    }
+}
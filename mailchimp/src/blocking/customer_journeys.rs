@@ -0,0 +1,31 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::customer_journeys::CustomerJourneys`].
+pub struct CustomerJourneys {
+    inner: crate::customer_journeys::CustomerJourneys,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl CustomerJourneys {
+    pub(crate) fn new(
+        inner: crate::customer_journeys::CustomerJourneys,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        CustomerJourneys { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::customer_journeys::CustomerJourneys::post_steps_actions_trigger`].
+    pub fn post_steps_actions_trigger(
+        &self,
+        journey_id: i64,
+        step_id: i64,
+        body: &crate::types::SubscriberInAutomationQueue,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .post_steps_actions_trigger(journey_id, step_id, body),
+        )
+    }
+}
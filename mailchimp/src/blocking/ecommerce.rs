@@ -0,0 +1,1842 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Ecommerce::get_order`], set with a builder.
+pub struct EcommerceGetOrderRequest<'a> {
+    inner: crate::ecommerce::EcommerceGetOrderRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> EcommerceGetOrderRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.inner = self.inner.count(count);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.inner = self.inner.offset(offset);
+        self
+    }
+
+    /// Sets the `campaign_id` query parameter.
+    pub fn campaign_id(mut self, campaign_id: &'a str) -> Self {
+        self.inner = self.inner.campaign_id(campaign_id);
+        self
+    }
+
+    /// Sets the `outreach_id` query parameter.
+    pub fn outreach_id(mut self, outreach_id: &'a str) -> Self {
+        self.inner = self.inner.outreach_id(outreach_id);
+        self
+    }
+
+    /// Sets the `customer_id` query parameter.
+    pub fn customer_id(mut self, customer_id: &'a str) -> Self {
+        self.inner = self.inner.customer_id(customer_id);
+        self
+    }
+
+    /// Sets the `has_outreach` query parameter.
+    pub fn has_outreach(mut self, has_outreach: bool) -> Self {
+        self.inner = self.inner.has_outreach(has_outreach);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::OrdersData>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Ecommerce::get_store`], set with a builder.
+pub struct EcommerceGetStoreRequest<'a> {
+    inner: crate::ecommerce::EcommerceGetStoreRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> EcommerceGetStoreRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.inner = self.inner.count(count);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.inner = self.inner.offset(offset);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::ECommerceStores>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Ecommerce::get_store_ecommerce`], set with a builder.
+pub struct EcommerceGetStoreEcommerceRequest<'a> {
+    inner: crate::ecommerce::EcommerceGetStoreEcommerceRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> EcommerceGetStoreEcommerceRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::Stores>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Ecommerce::get_stores_cart`], set with a builder.
+pub struct EcommerceGetStoresCartRequest<'a> {
+    inner: crate::ecommerce::EcommerceGetStoresCartRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> EcommerceGetStoresCartRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.inner = self.inner.count(count);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.inner = self.inner.offset(offset);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::CartsData>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Ecommerce::get_stores_cart_ecommerce`], set with a builder.
+pub struct EcommerceGetStoresCartEcommerceRequest<'a> {
+    inner: crate::ecommerce::EcommerceGetStoresCartEcommerceRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> EcommerceGetStoresCartEcommerceRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::Carts>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Ecommerce::get_stores_carts_line`], set with a builder.
+pub struct EcommerceGetStoresCartsLineRequest<'a> {
+    inner: crate::ecommerce::EcommerceGetStoresCartsLineRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> EcommerceGetStoresCartsLineRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.inner = self.inner.count(count);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.inner = self.inner.offset(offset);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::CartLines>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Ecommerce::get_stores_carts_line_ecommerce`], set with a builder.
+pub struct EcommerceGetStoresCartsLineEcommerceRequest<'a> {
+    inner: crate::ecommerce::EcommerceGetStoresCartsLineEcommerceRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> EcommerceGetStoresCartsLineEcommerceRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::ECommerceCartLineItem>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Ecommerce::get_stores_customer`], set with a builder.
+pub struct EcommerceGetStoresCustomerRequest<'a> {
+    inner: crate::ecommerce::EcommerceGetStoresCustomerRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> EcommerceGetStoresCustomerRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.inner = self.inner.count(count);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.inner = self.inner.offset(offset);
+        self
+    }
+
+    /// Sets the `email_address` query parameter.
+    pub fn email_address(mut self, email_address: &'a str) -> Self {
+        self.inner = self.inner.email_address(email_address);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::Customers>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Ecommerce::get_stores_customer_ecommerce`], set with a builder.
+pub struct EcommerceGetStoresCustomerEcommerceRequest<'a> {
+    inner: crate::ecommerce::EcommerceGetStoresCustomerEcommerceRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> EcommerceGetStoresCustomerEcommerceRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::Customer>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Ecommerce::get_stores_promorule`], set with a builder.
+pub struct EcommerceGetStoresPromoruleRequest<'a> {
+    inner: crate::ecommerce::EcommerceGetStoresPromoruleRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> EcommerceGetStoresPromoruleRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.inner = self.inner.count(count);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.inner = self.inner.offset(offset);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::PromoRulesData>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Ecommerce::get_stores_promorule_ecommerce`], set with a builder.
+pub struct EcommerceGetStoresPromoruleEcommerceRequest<'a> {
+    inner: crate::ecommerce::EcommerceGetStoresPromoruleEcommerceRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> EcommerceGetStoresPromoruleEcommerceRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::PromoRules>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Ecommerce::get_stores_promocode`], set with a builder.
+pub struct EcommerceGetStoresPromocodeRequest<'a> {
+    inner: crate::ecommerce::EcommerceGetStoresPromocodeRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> EcommerceGetStoresPromocodeRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.inner = self.inner.count(count);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.inner = self.inner.offset(offset);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::PromoCodesData>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Ecommerce::get_stores_promocode_ecommerce`], set with a builder.
+pub struct EcommerceGetStoresPromocodeEcommerceRequest<'a> {
+    inner: crate::ecommerce::EcommerceGetStoresPromocodeEcommerceRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> EcommerceGetStoresPromocodeEcommerceRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::PromoCodes>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Ecommerce::get_stores_order`], set with a builder.
+pub struct EcommerceGetStoresOrderRequest<'a> {
+    inner: crate::ecommerce::EcommerceGetStoresOrderRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> EcommerceGetStoresOrderRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.inner = self.inner.count(count);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.inner = self.inner.offset(offset);
+        self
+    }
+
+    /// Sets the `customer_id` query parameter.
+    pub fn customer_id(mut self, customer_id: &'a str) -> Self {
+        self.inner = self.inner.customer_id(customer_id);
+        self
+    }
+
+    /// Sets the `has_outreach` query parameter.
+    pub fn has_outreach(mut self, has_outreach: bool) -> Self {
+        self.inner = self.inner.has_outreach(has_outreach);
+        self
+    }
+
+    /// Sets the `campaign_id` query parameter.
+    pub fn campaign_id(mut self, campaign_id: &'a str) -> Self {
+        self.inner = self.inner.campaign_id(campaign_id);
+        self
+    }
+
+    /// Sets the `outreach_id` query parameter.
+    pub fn outreach_id(mut self, outreach_id: &'a str) -> Self {
+        self.inner = self.inner.outreach_id(outreach_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::OrdersDataType>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Ecommerce::get_stores_order_ecommerce`], set with a builder.
+pub struct EcommerceGetStoresOrderEcommerceRequest<'a> {
+    inner: crate::ecommerce::EcommerceGetStoresOrderEcommerceRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> EcommerceGetStoresOrderEcommerceRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::Orders>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Ecommerce::get_stores_orders_line`], set with a builder.
+pub struct EcommerceGetStoresOrdersLineRequest<'a> {
+    inner: crate::ecommerce::EcommerceGetStoresOrdersLineRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> EcommerceGetStoresOrdersLineRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.inner = self.inner.count(count);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.inner = self.inner.offset(offset);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::OrderLines>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Ecommerce::get_stores_orders_line_ecommerce`], set with a builder.
+pub struct EcommerceGetStoresOrdersLineEcommerceRequest<'a> {
+    inner: crate::ecommerce::EcommerceGetStoresOrdersLineEcommerceRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> EcommerceGetStoresOrdersLineEcommerceRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::Lines>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Ecommerce::get_stores_product`], set with a builder.
+pub struct EcommerceGetStoresProductRequest<'a> {
+    inner: crate::ecommerce::EcommerceGetStoresProductRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> EcommerceGetStoresProductRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.inner = self.inner.count(count);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.inner = self.inner.offset(offset);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::ProductsData>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Ecommerce::get_stores_product_ecommerce`], set with a builder.
+pub struct EcommerceGetStoresProductEcommerceRequest<'a> {
+    inner: crate::ecommerce::EcommerceGetStoresProductEcommerceRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> EcommerceGetStoresProductEcommerceRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::ECommerceProduct>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Ecommerce::get_stores_products_variant`], set with a builder.
+pub struct EcommerceGetStoresProductsVariantRequest<'a> {
+    inner: crate::ecommerce::EcommerceGetStoresProductsVariantRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> EcommerceGetStoresProductsVariantRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.inner = self.inner.count(count);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.inner = self.inner.offset(offset);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::EcommerceProductVariants>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Ecommerce::get_stores_products_variant_ecommerce`], set with a builder.
+pub struct EcommerceGetStoresProductsVariantEcommerceRequest<'a> {
+    inner: crate::ecommerce::EcommerceGetStoresProductsVariantEcommerceRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> EcommerceGetStoresProductsVariantEcommerceRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::Variants>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Ecommerce::get_stores_products_image`], set with a builder.
+pub struct EcommerceGetStoresProductsImageRequest<'a> {
+    inner: crate::ecommerce::EcommerceGetStoresProductsImageRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> EcommerceGetStoresProductsImageRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.inner = self.inner.count(count);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.inner = self.inner.offset(offset);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::EcommerceProductImages>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Ecommerce::get_stores_products_image_ecommerce`], set with a builder.
+pub struct EcommerceGetStoresProductsImageEcommerceRequest<'a> {
+    inner: crate::ecommerce::EcommerceGetStoresProductsImageEcommerceRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> EcommerceGetStoresProductsImageEcommerceRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::Images>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::ecommerce::Ecommerce`].
+pub struct Ecommerce {
+    inner: crate::ecommerce::Ecommerce,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Ecommerce {
+    pub(crate) fn new(
+        inner: crate::ecommerce::Ecommerce,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Ecommerce { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::ecommerce::Ecommerce::get_order`].
+    pub fn get_order(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        campaign_id: &str,
+        outreach_id: &str,
+        customer_id: &str,
+        has_outreach: bool,
+    ) -> ClientResult<crate::Response<crate::types::OrdersData>> {
+        self.runtime.block_on(self.inner.get_order(
+            fields,
+            exclude_fields,
+            count,
+            offset,
+            campaign_id,
+            outreach_id,
+            customer_id,
+            has_outreach,
+        ))
+    }
+    /// The blocking version of [`crate::ecommerce::Ecommerce::get_order_request`].
+    pub fn get_order_request(&self) -> EcommerceGetOrderRequest<'_> {
+        EcommerceGetOrderRequest {
+            inner: self.inner.get_order_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::ecommerce::Ecommerce::get_store`].
+    pub fn get_store(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+    ) -> ClientResult<crate::Response<crate::types::ECommerceStores>> {
+        self.runtime
+            .block_on(self.inner.get_store(fields, exclude_fields, count, offset))
+    }
+    /// The blocking version of [`crate::ecommerce::Ecommerce::get_store_request`].
+    pub fn get_store_request(&self) -> EcommerceGetStoreRequest<'_> {
+        EcommerceGetStoreRequest {
+            inner: self.inner.get_store_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::ecommerce::Ecommerce::post_store`].
+    pub fn post_store(
+        &self,
+        body: &crate::types::ECommerceStore,
+    ) -> ClientResult<crate::Response<crate::types::Stores>> {
+        self.runtime.block_on(self.inner.post_store(body))
+    }
+    /// The blocking version of [`crate::ecommerce::Ecommerce::get_store_ecommerce`].
+    pub fn get_store_ecommerce(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        store_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Stores>> {
+        self.runtime.block_on(
+            self.inner
+                .get_store_ecommerce(fields, exclude_fields, store_id),
+        )
+    }
+    /// The blocking version of [`crate::ecommerce::Ecommerce::get_store_ecommerce_request`].
+    pub fn get_store_ecommerce_request<'a>(
+        &'a self,
+        store_id: &'a str,
+    ) -> EcommerceGetStoreEcommerceRequest<'a> {
+        EcommerceGetStoreEcommerceRequest {
+            inner: self.inner.get_store_ecommerce_request(store_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::ecommerce::Ecommerce::delete_stores`].
+    pub fn delete_stores(&self, store_id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.delete_stores(store_id))
+    }
+    /// The blocking version of [`crate::ecommerce::Ecommerce::patch_stores`].
+    pub fn patch_stores(
+        &self,
+        store_id: &str,
+        body: &crate::types::ECommerceStoreData,
+    ) -> ClientResult<crate::Response<crate::types::Stores>> {
+        self.runtime
+            .block_on(self.inner.patch_stores(store_id, body))
+    }
+    /// The blocking version of [`crate::ecommerce::Ecommerce::get_stores_cart`].
+    pub fn get_stores_cart(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        store_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::CartsData>> {
+        self.runtime.block_on(self.inner.get_stores_cart(
+            fields,
+            exclude_fields,
+            count,
+            offset,
+            store_id,
+        ))
+    }
+    /// The blocking version of [`crate::ecommerce::Ecommerce::get_stores_cart_request`].
+    pub fn get_stores_cart_request<'a>(
+        &'a self,
+        store_id: &'a str,
+    ) -> EcommerceGetStoresCartRequest<'a> {
+        EcommerceGetStoresCartRequest {
+            inner: self.inner.get_stores_cart_request(store_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::ecommerce::Ecommerce::post_stores_cart`].
+    pub fn post_stores_cart(
+        &self,
+        store_id: &str,
+        body: &crate::types::ECommerceCart,
+    ) -> ClientResult<crate::Response<crate::types::Carts>> {
+        self.runtime
+            .block_on(self.inner.post_stores_cart(store_id, body))
+    }
+    /// The blocking version of [`crate::ecommerce::Ecommerce::get_stores_cart_ecommerce`].
+    pub fn get_stores_cart_ecommerce(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        store_id: &str,
+        cart_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Carts>> {
+        self.runtime.block_on(self.inner.get_stores_cart_ecommerce(
+            fields,
+            exclude_fields,
+            store_id,
+            cart_id,
+        ))
+    }
+    /// The blocking version of [`crate::ecommerce::Ecommerce::get_stores_cart_ecommerce_request`].
+    pub fn get_stores_cart_ecommerce_request<'a>(
+        &'a self,
+        store_id: &'a str,
+        cart_id: &'a str,
+    ) -> EcommerceGetStoresCartEcommerceRequest<'a> {
+        EcommerceGetStoresCartEcommerceRequest {
+            inner: self
+                .inner
+                .get_stores_cart_ecommerce_request(store_id, cart_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::ecommerce::Ecommerce::delete_stores_carts`].
+    pub fn delete_stores_carts(
+        &self,
+        store_id: &str,
+        cart_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete_stores_carts(store_id, cart_id))
+    }
+    /// The blocking version of [`crate::ecommerce::Ecommerce::patch_stores_carts`].
+    pub fn patch_stores_carts(
+        &self,
+        store_id: &str,
+        cart_id: &str,
+        body: &crate::types::ECommerceCartData,
+    ) -> ClientResult<crate::Response<crate::types::Carts>> {
+        self.runtime
+            .block_on(self.inner.patch_stores_carts(store_id, cart_id, body))
+    }
+    /// The blocking version of [`crate::ecommerce::Ecommerce::get_stores_carts_line`].
+    pub fn get_stores_carts_line(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        store_id: &str,
+        cart_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::CartLines>> {
+        self.runtime.block_on(self.inner.get_stores_carts_line(
+            fields,
+            exclude_fields,
+            count,
+            offset,
+            store_id,
+            cart_id,
+        ))
+    }
+    /// The blocking version of [`crate::ecommerce::Ecommerce::get_stores_carts_line_request`].
+    pub fn get_stores_carts_line_request<'a>(
+        &'a self,
+        store_id: &'a str,
+        cart_id: &'a str,
+    ) -> EcommerceGetStoresCartsLineRequest<'a> {
+        EcommerceGetStoresCartsLineRequest {
+            inner: self.inner.get_stores_carts_line_request(store_id, cart_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::ecommerce::Ecommerce::post_stores_carts_line`].
+    pub fn post_stores_carts_line(
+        &self,
+        store_id: &str,
+        cart_id: &str,
+        body: &crate::types::ECommerceCartLineItemData,
+    ) -> ClientResult<crate::Response<crate::types::ECommerceCartLineItem>> {
+        self.runtime
+            .block_on(self.inner.post_stores_carts_line(store_id, cart_id, body))
+    }
+    /// The blocking version of [`crate::ecommerce::Ecommerce::get_stores_carts_line_ecommerce`].
+    pub fn get_stores_carts_line_ecommerce(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        store_id: &str,
+        cart_id: &str,
+        line_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ECommerceCartLineItem>> {
+        self.runtime
+            .block_on(self.inner.get_stores_carts_line_ecommerce(
+                fields,
+                exclude_fields,
+                store_id,
+                cart_id,
+                line_id,
+            ))
+    }
+    /// The blocking version of [`crate::ecommerce::Ecommerce::get_stores_carts_line_ecommerce_request`].
+    pub fn get_stores_carts_line_ecommerce_request<'a>(
+        &'a self,
+        store_id: &'a str,
+        cart_id: &'a str,
+        line_id: &'a str,
+    ) -> EcommerceGetStoresCartsLineEcommerceRequest<'a> {
+        EcommerceGetStoresCartsLineEcommerceRequest {
+            inner: self
+                .inner
+                .get_stores_carts_line_ecommerce_request(store_id, cart_id, line_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::ecommerce::Ecommerce::delete_stores_carts_lines`].
+    pub fn delete_stores_carts_lines(
+        &self,
+        store_id: &str,
+        cart_id: &str,
+        line_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .delete_stores_carts_lines(store_id, cart_id, line_id),
+        )
+    }
+    /// The blocking version of [`crate::ecommerce::Ecommerce::patch_stores_carts_lines`].
+    pub fn patch_stores_carts_lines(
+        &self,
+        store_id: &str,
+        cart_id: &str,
+        line_id: &str,
+        body: &crate::types::ECommerceCartLineItemDataType,
+    ) -> ClientResult<crate::Response<crate::types::ECommerceCartLineItem>> {
+        self.runtime.block_on(
+            self.inner
+                .patch_stores_carts_lines(store_id, cart_id, line_id, body),
+        )
+    }
+    /// The blocking version of [`crate::ecommerce::Ecommerce::get_stores_customer`].
+    pub fn get_stores_customer(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        store_id: &str,
+        email_address: &str,
+    ) -> ClientResult<crate::Response<crate::types::Customers>> {
+        self.runtime.block_on(self.inner.get_stores_customer(
+            fields,
+            exclude_fields,
+            count,
+            offset,
+            store_id,
+            email_address,
+        ))
+    }
+    /// The blocking version of [`crate::ecommerce::Ecommerce::get_stores_customer_request`].
+    pub fn get_stores_customer_request<'a>(
+        &'a self,
+        store_id: &'a str,
+    ) -> EcommerceGetStoresCustomerRequest<'a> {
+        EcommerceGetStoresCustomerRequest {
+            inner: self.inner.get_stores_customer_request(store_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::ecommerce::Ecommerce::post_stores_customer`].
+    pub fn post_stores_customer(
+        &self,
+        store_id: &str,
+        body: &crate::types::ECommerceCustomerData,
+    ) -> ClientResult<crate::Response<crate::types::Customer>> {
+        self.runtime
+            .block_on(self.inner.post_stores_customer(store_id, body))
+    }
+    /// The blocking version of [`crate::ecommerce::Ecommerce::get_stores_customer_ecommerce`].
+    pub fn get_stores_customer_ecommerce(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        store_id: &str,
+        customer_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Customer>> {
+        self.runtime
+            .block_on(self.inner.get_stores_customer_ecommerce(
+                fields,
+                exclude_fields,
+                store_id,
+                customer_id,
+            ))
+    }
+    /// The blocking version of [`crate::ecommerce::Ecommerce::get_stores_customer_ecommerce_request`].
+    pub fn get_stores_customer_ecommerce_request<'a>(
+        &'a self,
+        store_id: &'a str,
+        customer_id: &'a str,
+    ) -> EcommerceGetStoresCustomerEcommerceRequest<'a> {
+        EcommerceGetStoresCustomerEcommerceRequest {
+            inner: self
+                .inner
+                .get_stores_customer_ecommerce_request(store_id, customer_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::ecommerce::Ecommerce::put_stores_customers`].
+    pub fn put_stores_customers(
+        &self,
+        store_id: &str,
+        customer_id: &str,
+        body: &crate::types::ECommerceCustomerDataType,
+    ) -> ClientResult<crate::Response<crate::types::Customer>> {
+        self.runtime
+            .block_on(self.inner.put_stores_customers(store_id, customer_id, body))
+    }
+    /// The blocking version of [`crate::ecommerce::Ecommerce::delete_stores_customers`].
+    pub fn delete_stores_customers(
+        &self,
+        store_id: &str,
+        customer_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete_stores_customers(store_id, customer_id))
+    }
+    /// The blocking version of [`crate::ecommerce::Ecommerce::patch_stores_customers`].
+    pub fn patch_stores_customers(
+        &self,
+        store_id: &str,
+        customer_id: &str,
+        body: &crate::types::ECommerceCartCustomer,
+    ) -> ClientResult<crate::Response<crate::types::Customer>> {
+        self.runtime.block_on(
+            self.inner
+                .patch_stores_customers(store_id, customer_id, body),
+        )
+    }
+    /// The blocking version of [`crate::ecommerce::Ecommerce::get_stores_promorule`].
+    pub fn get_stores_promorule(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        store_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::PromoRulesData>> {
+        self.runtime.block_on(self.inner.get_stores_promorule(
+            fields,
+            exclude_fields,
+            count,
+            offset,
+            store_id,
+        ))
+    }
+    /// The blocking version of [`crate::ecommerce::Ecommerce::get_stores_promorule_request`].
+    pub fn get_stores_promorule_request<'a>(
+        &'a self,
+        store_id: &'a str,
+    ) -> EcommerceGetStoresPromoruleRequest<'a> {
+        EcommerceGetStoresPromoruleRequest {
+            inner: self.inner.get_stores_promorule_request(store_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::ecommerce::Ecommerce::post_stores_promorule`].
+    pub fn post_stores_promorule(
+        &self,
+        store_id: &str,
+        body: &crate::types::ECommercePromoRule,
+    ) -> ClientResult<crate::Response<crate::types::PromoRules>> {
+        self.runtime
+            .block_on(self.inner.post_stores_promorule(store_id, body))
+    }
+    /// The blocking version of [`crate::ecommerce::Ecommerce::get_stores_promorule_ecommerce`].
+    pub fn get_stores_promorule_ecommerce(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        store_id: &str,
+        promo_rule_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::PromoRules>> {
+        self.runtime
+            .block_on(self.inner.get_stores_promorule_ecommerce(
+                fields,
+                exclude_fields,
+                store_id,
+                promo_rule_id,
+            ))
+    }
+    /// The blocking version of [`crate::ecommerce::Ecommerce::get_stores_promorule_ecommerce_request`].
+    pub fn get_stores_promorule_ecommerce_request<'a>(
+        &'a self,
+        store_id: &'a str,
+        promo_rule_id: &'a str,
+    ) -> EcommerceGetStoresPromoruleEcommerceRequest<'a> {
+        EcommerceGetStoresPromoruleEcommerceRequest {
+            inner: self
+                .inner
+                .get_stores_promorule_ecommerce_request(store_id, promo_rule_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::ecommerce::Ecommerce::delete_stores_promorules`].
+    pub fn delete_stores_promorules(
+        &self,
+        store_id: &str,
+        promo_rule_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete_stores_promorules(store_id, promo_rule_id))
+    }
+    /// The blocking version of [`crate::ecommerce::Ecommerce::patch_stores_promorules`].
+    pub fn patch_stores_promorules(
+        &self,
+        store_id: &str,
+        promo_rule_id: &str,
+        body: &crate::types::ECommercePromoRuleData,
+    ) -> ClientResult<crate::Response<crate::types::PromoRules>> {
+        self.runtime.block_on(
+            self.inner
+                .patch_stores_promorules(store_id, promo_rule_id, body),
+        )
+    }
+    /// The blocking version of [`crate::ecommerce::Ecommerce::get_stores_promocode`].
+    pub fn get_stores_promocode(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        promo_rule_id: &str,
+        store_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::PromoCodesData>> {
+        self.runtime.block_on(self.inner.get_stores_promocode(
+            fields,
+            exclude_fields,
+            count,
+            offset,
+            promo_rule_id,
+            store_id,
+        ))
+    }
+    /// The blocking version of [`crate::ecommerce::Ecommerce::get_stores_promocode_request`].
+    pub fn get_stores_promocode_request<'a>(
+        &'a self,
+        promo_rule_id: &'a str,
+        store_id: &'a str,
+    ) -> EcommerceGetStoresPromocodeRequest<'a> {
+        EcommerceGetStoresPromocodeRequest {
+            inner: self
+                .inner
+                .get_stores_promocode_request(promo_rule_id, store_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::ecommerce::Ecommerce::post_stores_promocode`].
+    pub fn post_stores_promocode(
+        &self,
+        store_id: &str,
+        promo_rule_id: &str,
+        body: &crate::types::ECommercePromoCode,
+    ) -> ClientResult<crate::Response<crate::types::PromoCodes>> {
+        self.runtime.block_on(
+            self.inner
+                .post_stores_promocode(store_id, promo_rule_id, body),
+        )
+    }
+    /// The blocking version of [`crate::ecommerce::Ecommerce::get_stores_promocode_ecommerce`].
+    pub fn get_stores_promocode_ecommerce(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        store_id: &str,
+        promo_rule_id: &str,
+        promo_code_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::PromoCodes>> {
+        self.runtime
+            .block_on(self.inner.get_stores_promocode_ecommerce(
+                fields,
+                exclude_fields,
+                store_id,
+                promo_rule_id,
+                promo_code_id,
+            ))
+    }
+    /// The blocking version of [`crate::ecommerce::Ecommerce::get_stores_promocode_ecommerce_request`].
+    pub fn get_stores_promocode_ecommerce_request<'a>(
+        &'a self,
+        store_id: &'a str,
+        promo_rule_id: &'a str,
+        promo_code_id: &'a str,
+    ) -> EcommerceGetStoresPromocodeEcommerceRequest<'a> {
+        EcommerceGetStoresPromocodeEcommerceRequest {
+            inner: self.inner.get_stores_promocode_ecommerce_request(
+                store_id,
+                promo_rule_id,
+                promo_code_id,
+            ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::ecommerce::Ecommerce::delete_stores_promocodes`].
+    pub fn delete_stores_promocodes(
+        &self,
+        store_id: &str,
+        promo_rule_id: &str,
+        promo_code_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.delete_stores_promocodes(
+            store_id,
+            promo_rule_id,
+            promo_code_id,
+        ))
+    }
+    /// The blocking version of [`crate::ecommerce::Ecommerce::patch_stores_promocodes`].
+    pub fn patch_stores_promocodes(
+        &self,
+        store_id: &str,
+        promo_rule_id: &str,
+        promo_code_id: &str,
+        body: &crate::types::ECommercePromoCodeData,
+    ) -> ClientResult<crate::Response<crate::types::PromoCodes>> {
+        self.runtime.block_on(self.inner.patch_stores_promocodes(
+            store_id,
+            promo_rule_id,
+            promo_code_id,
+            body,
+        ))
+    }
+    /// The blocking version of [`crate::ecommerce::Ecommerce::get_stores_order`].
+    pub fn get_stores_order(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        store_id: &str,
+        customer_id: &str,
+        has_outreach: bool,
+        campaign_id: &str,
+        outreach_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::OrdersDataType>> {
+        self.runtime.block_on(self.inner.get_stores_order(
+            fields,
+            exclude_fields,
+            count,
+            offset,
+            store_id,
+            customer_id,
+            has_outreach,
+            campaign_id,
+            outreach_id,
+        ))
+    }
+    /// The blocking version of [`crate::ecommerce::Ecommerce::get_stores_order_request`].
+    pub fn get_stores_order_request<'a>(
+        &'a self,
+        store_id: &'a str,
+    ) -> EcommerceGetStoresOrderRequest<'a> {
+        EcommerceGetStoresOrderRequest {
+            inner: self.inner.get_stores_order_request(store_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::ecommerce::Ecommerce::post_stores_order`].
+    pub fn post_stores_order(
+        &self,
+        store_id: &str,
+        body: &crate::types::ECommerceOrder,
+    ) -> ClientResult<crate::Response<crate::types::Orders>> {
+        self.runtime
+            .block_on(self.inner.post_stores_order(store_id, body))
+    }
+    /// The blocking version of [`crate::ecommerce::Ecommerce::get_stores_order_ecommerce`].
+    pub fn get_stores_order_ecommerce(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        store_id: &str,
+        order_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Orders>> {
+        self.runtime.block_on(self.inner.get_stores_order_ecommerce(
+            fields,
+            exclude_fields,
+            store_id,
+            order_id,
+        ))
+    }
+    /// The blocking version of [`crate::ecommerce::Ecommerce::get_stores_order_ecommerce_request`].
+    pub fn get_stores_order_ecommerce_request<'a>(
+        &'a self,
+        store_id: &'a str,
+        order_id: &'a str,
+    ) -> EcommerceGetStoresOrderEcommerceRequest<'a> {
+        EcommerceGetStoresOrderEcommerceRequest {
+            inner: self
+                .inner
+                .get_stores_order_ecommerce_request(store_id, order_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::ecommerce::Ecommerce::delete_stores_orders`].
+    pub fn delete_stores_orders(
+        &self,
+        store_id: &str,
+        order_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete_stores_orders(store_id, order_id))
+    }
+    /// The blocking version of [`crate::ecommerce::Ecommerce::patch_stores_orders`].
+    pub fn patch_stores_orders(
+        &self,
+        store_id: &str,
+        order_id: &str,
+        body: &crate::types::ECommerceOrderData,
+    ) -> ClientResult<crate::Response<crate::types::Orders>> {
+        self.runtime
+            .block_on(self.inner.patch_stores_orders(store_id, order_id, body))
+    }
+    /// The blocking version of [`crate::ecommerce::Ecommerce::get_stores_orders_line`].
+    pub fn get_stores_orders_line(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        store_id: &str,
+        order_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::OrderLines>> {
+        self.runtime.block_on(self.inner.get_stores_orders_line(
+            fields,
+            exclude_fields,
+            count,
+            offset,
+            store_id,
+            order_id,
+        ))
+    }
+    /// The blocking version of [`crate::ecommerce::Ecommerce::get_stores_orders_line_request`].
+    pub fn get_stores_orders_line_request<'a>(
+        &'a self,
+        store_id: &'a str,
+        order_id: &'a str,
+    ) -> EcommerceGetStoresOrdersLineRequest<'a> {
+        EcommerceGetStoresOrdersLineRequest {
+            inner: self
+                .inner
+                .get_stores_orders_line_request(store_id, order_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::ecommerce::Ecommerce::post_stores_orders_line`].
+    pub fn post_stores_orders_line(
+        &self,
+        store_id: &str,
+        order_id: &str,
+        body: &crate::types::ECommerceOrderLineItem,
+    ) -> ClientResult<crate::Response<crate::types::Lines>> {
+        self.runtime
+            .block_on(self.inner.post_stores_orders_line(store_id, order_id, body))
+    }
+    /// The blocking version of [`crate::ecommerce::Ecommerce::get_stores_orders_line_ecommerce`].
+    pub fn get_stores_orders_line_ecommerce(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        store_id: &str,
+        order_id: &str,
+        line_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Lines>> {
+        self.runtime
+            .block_on(self.inner.get_stores_orders_line_ecommerce(
+                fields,
+                exclude_fields,
+                store_id,
+                order_id,
+                line_id,
+            ))
+    }
+    /// The blocking version of [`crate::ecommerce::Ecommerce::get_stores_orders_line_ecommerce_request`].
+    pub fn get_stores_orders_line_ecommerce_request<'a>(
+        &'a self,
+        store_id: &'a str,
+        order_id: &'a str,
+        line_id: &'a str,
+    ) -> EcommerceGetStoresOrdersLineEcommerceRequest<'a> {
+        EcommerceGetStoresOrdersLineEcommerceRequest {
+            inner: self
+                .inner
+                .get_stores_orders_line_ecommerce_request(store_id, order_id, line_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::ecommerce::Ecommerce::delete_stores_orders_lines`].
+    pub fn delete_stores_orders_lines(
+        &self,
+        store_id: &str,
+        order_id: &str,
+        line_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .delete_stores_orders_lines(store_id, order_id, line_id),
+        )
+    }
+    /// The blocking version of [`crate::ecommerce::Ecommerce::patch_stores_orders_lines`].
+    pub fn patch_stores_orders_lines(
+        &self,
+        store_id: &str,
+        order_id: &str,
+        line_id: &str,
+        body: &crate::types::ECommerceOrderLineItemData,
+    ) -> ClientResult<crate::Response<crate::types::Lines>> {
+        self.runtime.block_on(
+            self.inner
+                .patch_stores_orders_lines(store_id, order_id, line_id, body),
+        )
+    }
+    /// The blocking version of [`crate::ecommerce::Ecommerce::get_stores_product`].
+    pub fn get_stores_product(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        store_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ProductsData>> {
+        self.runtime.block_on(self.inner.get_stores_product(
+            fields,
+            exclude_fields,
+            count,
+            offset,
+            store_id,
+        ))
+    }
+    /// The blocking version of [`crate::ecommerce::Ecommerce::get_stores_product_request`].
+    pub fn get_stores_product_request<'a>(
+        &'a self,
+        store_id: &'a str,
+    ) -> EcommerceGetStoresProductRequest<'a> {
+        EcommerceGetStoresProductRequest {
+            inner: self.inner.get_stores_product_request(store_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::ecommerce::Ecommerce::post_stores_product`].
+    pub fn post_stores_product(
+        &self,
+        store_id: &str,
+        body: &crate::types::ECommerceProductData,
+    ) -> ClientResult<crate::Response<crate::types::ECommerceProduct>> {
+        self.runtime
+            .block_on(self.inner.post_stores_product(store_id, body))
+    }
+    /// The blocking version of [`crate::ecommerce::Ecommerce::get_stores_product_ecommerce`].
+    pub fn get_stores_product_ecommerce(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        store_id: &str,
+        product_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ECommerceProduct>> {
+        self.runtime
+            .block_on(self.inner.get_stores_product_ecommerce(
+                fields,
+                exclude_fields,
+                store_id,
+                product_id,
+            ))
+    }
+    /// The blocking version of [`crate::ecommerce::Ecommerce::get_stores_product_ecommerce_request`].
+    pub fn get_stores_product_ecommerce_request<'a>(
+        &'a self,
+        store_id: &'a str,
+        product_id: &'a str,
+    ) -> EcommerceGetStoresProductEcommerceRequest<'a> {
+        EcommerceGetStoresProductEcommerceRequest {
+            inner: self
+                .inner
+                .get_stores_product_ecommerce_request(store_id, product_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::ecommerce::Ecommerce::delete_stores_products`].
+    pub fn delete_stores_products(
+        &self,
+        store_id: &str,
+        product_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete_stores_products(store_id, product_id))
+    }
+    /// The blocking version of [`crate::ecommerce::Ecommerce::patch_stores_products`].
+    pub fn patch_stores_products(
+        &self,
+        store_id: &str,
+        product_id: &str,
+        body: &crate::types::ECommerceProductDataType,
+    ) -> ClientResult<crate::Response<crate::types::ECommerceProduct>> {
+        self.runtime
+            .block_on(self.inner.patch_stores_products(store_id, product_id, body))
+    }
+    /// The blocking version of [`crate::ecommerce::Ecommerce::get_stores_products_variant`].
+    pub fn get_stores_products_variant(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        store_id: &str,
+        product_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::EcommerceProductVariants>> {
+        self.runtime
+            .block_on(self.inner.get_stores_products_variant(
+                fields,
+                exclude_fields,
+                count,
+                offset,
+                store_id,
+                product_id,
+            ))
+    }
+    /// The blocking version of [`crate::ecommerce::Ecommerce::get_stores_products_variant_request`].
+    pub fn get_stores_products_variant_request<'a>(
+        &'a self,
+        store_id: &'a str,
+        product_id: &'a str,
+    ) -> EcommerceGetStoresProductsVariantRequest<'a> {
+        EcommerceGetStoresProductsVariantRequest {
+            inner: self
+                .inner
+                .get_stores_products_variant_request(store_id, product_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::ecommerce::Ecommerce::post_stores_products_variant`].
+    pub fn post_stores_products_variant(
+        &self,
+        store_id: &str,
+        product_id: &str,
+        body: &crate::types::ECommerceProductVariant,
+    ) -> ClientResult<crate::Response<crate::types::Variants>> {
+        self.runtime.block_on(
+            self.inner
+                .post_stores_products_variant(store_id, product_id, body),
+        )
+    }
+    /// The blocking version of [`crate::ecommerce::Ecommerce::get_stores_products_variant_ecommerce`].
+    pub fn get_stores_products_variant_ecommerce(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        store_id: &str,
+        product_id: &str,
+        variant_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Variants>> {
+        self.runtime
+            .block_on(self.inner.get_stores_products_variant_ecommerce(
+                fields,
+                exclude_fields,
+                store_id,
+                product_id,
+                variant_id,
+            ))
+    }
+    /// The blocking version of [`crate::ecommerce::Ecommerce::get_stores_products_variant_ecommerce_request`].
+    pub fn get_stores_products_variant_ecommerce_request<'a>(
+        &'a self,
+        store_id: &'a str,
+        product_id: &'a str,
+        variant_id: &'a str,
+    ) -> EcommerceGetStoresProductsVariantEcommerceRequest<'a> {
+        EcommerceGetStoresProductsVariantEcommerceRequest {
+            inner: self
+                .inner
+                .get_stores_products_variant_ecommerce_request(store_id, product_id, variant_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::ecommerce::Ecommerce::put_stores_products_variants`].
+    pub fn put_stores_products_variants(
+        &self,
+        store_id: &str,
+        product_id: &str,
+        variant_id: &str,
+        body: &crate::types::ECommerceProductVariant,
+    ) -> ClientResult<crate::Response<crate::types::Variants>> {
+        self.runtime.block_on(
+            self.inner
+                .put_stores_products_variants(store_id, product_id, variant_id, body),
+        )
+    }
+    /// The blocking version of [`crate::ecommerce::Ecommerce::delete_stores_products_variants`].
+    pub fn delete_stores_products_variants(
+        &self,
+        store_id: &str,
+        product_id: &str,
+        variant_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .delete_stores_products_variants(store_id, product_id, variant_id),
+        )
+    }
+    /// The blocking version of [`crate::ecommerce::Ecommerce::patch_stores_products_variants`].
+    pub fn patch_stores_products_variants(
+        &self,
+        store_id: &str,
+        product_id: &str,
+        variant_id: &str,
+        body: &crate::types::ECommerceProductVariantData,
+    ) -> ClientResult<crate::Response<crate::types::Variants>> {
+        self.runtime.block_on(
+            self.inner
+                .patch_stores_products_variants(store_id, product_id, variant_id, body),
+        )
+    }
+    /// The blocking version of [`crate::ecommerce::Ecommerce::get_stores_products_image`].
+    pub fn get_stores_products_image(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        store_id: &str,
+        product_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::EcommerceProductImages>> {
+        self.runtime.block_on(self.inner.get_stores_products_image(
+            fields,
+            exclude_fields,
+            count,
+            offset,
+            store_id,
+            product_id,
+        ))
+    }
+    /// The blocking version of [`crate::ecommerce::Ecommerce::get_stores_products_image_request`].
+    pub fn get_stores_products_image_request<'a>(
+        &'a self,
+        store_id: &'a str,
+        product_id: &'a str,
+    ) -> EcommerceGetStoresProductsImageRequest<'a> {
+        EcommerceGetStoresProductsImageRequest {
+            inner: self
+                .inner
+                .get_stores_products_image_request(store_id, product_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::ecommerce::Ecommerce::post_stores_products_image`].
+    pub fn post_stores_products_image(
+        &self,
+        store_id: &str,
+        product_id: &str,
+        body: &crate::types::ECommerceProductImage,
+    ) -> ClientResult<crate::Response<crate::types::Images>> {
+        self.runtime.block_on(
+            self.inner
+                .post_stores_products_image(store_id, product_id, body),
+        )
+    }
+    /// The blocking version of [`crate::ecommerce::Ecommerce::get_stores_products_image_ecommerce`].
+    pub fn get_stores_products_image_ecommerce(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        store_id: &str,
+        product_id: &str,
+        image_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Images>> {
+        self.runtime
+            .block_on(self.inner.get_stores_products_image_ecommerce(
+                fields,
+                exclude_fields,
+                store_id,
+                product_id,
+                image_id,
+            ))
+    }
+    /// The blocking version of [`crate::ecommerce::Ecommerce::get_stores_products_image_ecommerce_request`].
+    pub fn get_stores_products_image_ecommerce_request<'a>(
+        &'a self,
+        store_id: &'a str,
+        product_id: &'a str,
+        image_id: &'a str,
+    ) -> EcommerceGetStoresProductsImageEcommerceRequest<'a> {
+        EcommerceGetStoresProductsImageEcommerceRequest {
+            inner: self
+                .inner
+                .get_stores_products_image_ecommerce_request(store_id, product_id, image_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::ecommerce::Ecommerce::delete_stores_products_images`].
+    pub fn delete_stores_products_images(
+        &self,
+        store_id: &str,
+        product_id: &str,
+        image_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .delete_stores_products_images(store_id, product_id, image_id),
+        )
+    }
+    /// The blocking version of [`crate::ecommerce::Ecommerce::patch_stores_products_images`].
+    pub fn patch_stores_products_images(
+        &self,
+        store_id: &str,
+        product_id: &str,
+        image_id: &str,
+        body: &crate::types::ECommerceProductImageData,
+    ) -> ClientResult<crate::Response<crate::types::Images>> {
+        self.runtime.block_on(
+            self.inner
+                .patch_stores_products_images(store_id, product_id, image_id, body),
+        )
+    }
+}
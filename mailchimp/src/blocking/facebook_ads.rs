@@ -0,0 +1,136 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`FacebookAds::get_all`], set with a builder.
+pub struct FacebookAdsGetAllRequest<'a> {
+    inner: crate::facebook_ads::FacebookAdsGetAllRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> FacebookAdsGetAllRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.inner = self.inner.count(count);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.inner = self.inner.offset(offset);
+        self
+    }
+
+    /// Sets the `sort_field` query parameter.
+    pub fn sort_field(mut self, sort_field: crate::types::GetAllFacebookAdsSortField) -> Self {
+        self.inner = self.inner.sort_field(sort_field);
+        self
+    }
+
+    /// Sets the `sort_dir` query parameter.
+    pub fn sort_dir(mut self, sort_dir: crate::types::SortDir) -> Self {
+        self.inner = self.inner.sort_dir(sort_dir);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::GetAllFacebookAdsResponse>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`FacebookAds::get`], set with a builder.
+pub struct FacebookAdsGetRequest<'a> {
+    inner: crate::facebook_ads::FacebookAdsGetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> FacebookAdsGetRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::FacebookAdsAllOf>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::facebook_ads::FacebookAds`].
+pub struct FacebookAds {
+    inner: crate::facebook_ads::FacebookAds,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl FacebookAds {
+    pub(crate) fn new(
+        inner: crate::facebook_ads::FacebookAds,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        FacebookAds { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::facebook_ads::FacebookAds::get_all`].
+    pub fn get_all(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        sort_field: crate::types::GetAllFacebookAdsSortField,
+        sort_dir: crate::types::SortDir,
+    ) -> ClientResult<crate::Response<crate::types::GetAllFacebookAdsResponse>> {
+        self.runtime.block_on(self.inner.get_all(
+            fields,
+            exclude_fields,
+            count,
+            offset,
+            sort_field,
+            sort_dir,
+        ))
+    }
+    /// The blocking version of [`crate::facebook_ads::FacebookAds::get_all_request`].
+    pub fn get_all_request(&self) -> FacebookAdsGetAllRequest<'_> {
+        FacebookAdsGetAllRequest {
+            inner: self.inner.get_all_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::facebook_ads::FacebookAds::get`].
+    pub fn get(
+        &self,
+        fields: &[String],
+        outreach_id: &str,
+        exclude_fields: &[String],
+    ) -> ClientResult<crate::Response<crate::types::FacebookAdsAllOf>> {
+        self.runtime
+            .block_on(self.inner.get(fields, outreach_id, exclude_fields))
+    }
+    /// The blocking version of [`crate::facebook_ads::FacebookAds::get_request`].
+    pub fn get_request<'a>(&'a self, outreach_id: &'a str) -> FacebookAdsGetRequest<'a> {
+        FacebookAdsGetRequest {
+            inner: self.inner.get_request(outreach_id),
+            runtime: &self.runtime,
+        }
+    }
+}
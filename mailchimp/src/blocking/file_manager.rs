@@ -0,0 +1,344 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`FileManager::get_file`], set with a builder.
+pub struct FileManagerGetFileRequest<'a> {
+    inner: crate::file_manager::FileManagerGetFileRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> FileManagerGetFileRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.inner = self.inner.count(count);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.inner = self.inner.offset(offset);
+        self
+    }
+
+    /// Sets the `type` query parameter.
+    pub fn type_(mut self, type_: &'a str) -> Self {
+        self.inner = self.inner.type_(type_);
+        self
+    }
+
+    /// Sets the `created_by` query parameter.
+    pub fn created_by(mut self, created_by: &'a str) -> Self {
+        self.inner = self.inner.created_by(created_by);
+        self
+    }
+
+    /// Sets the `before_created_at` query parameter.
+    pub fn before_created_at(mut self, before_created_at: &'a str) -> Self {
+        self.inner = self.inner.before_created_at(before_created_at);
+        self
+    }
+
+    /// Sets the `since_created_at` query parameter.
+    pub fn since_created_at(mut self, since_created_at: &'a str) -> Self {
+        self.inner = self.inner.since_created_at(since_created_at);
+        self
+    }
+
+    /// Sets the `sort_field` query parameter.
+    pub fn sort_field(mut self, sort_field: crate::types::GetFileManagerFilesSortField) -> Self {
+        self.inner = self.inner.sort_field(sort_field);
+        self
+    }
+
+    /// Sets the `sort_dir` query parameter.
+    pub fn sort_dir(mut self, sort_dir: crate::types::SortDir) -> Self {
+        self.inner = self.inner.sort_dir(sort_dir);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::FileManager>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`FileManager::get_file_file_manager`], set with a builder.
+pub struct FileManagerGetFileFileManagerRequest<'a> {
+    inner: crate::file_manager::FileManagerGetFileFileManagerRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> FileManagerGetFileFileManagerRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::Files>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`FileManager::get_folder`], set with a builder.
+pub struct FileManagerGetFolderRequest<'a> {
+    inner: crate::file_manager::FileManagerGetFolderRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> FileManagerGetFolderRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.inner = self.inner.count(count);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.inner = self.inner.offset(offset);
+        self
+    }
+
+    /// Sets the `created_by` query parameter.
+    pub fn created_by(mut self, created_by: &'a str) -> Self {
+        self.inner = self.inner.created_by(created_by);
+        self
+    }
+
+    /// Sets the `before_created_at` query parameter.
+    pub fn before_created_at(mut self, before_created_at: &'a str) -> Self {
+        self.inner = self.inner.before_created_at(before_created_at);
+        self
+    }
+
+    /// Sets the `since_created_at` query parameter.
+    pub fn since_created_at(mut self, since_created_at: &'a str) -> Self {
+        self.inner = self.inner.since_created_at(since_created_at);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::FileManagerFolders>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`FileManager::get_folder_file_manager`], set with a builder.
+pub struct FileManagerGetFolderFileManagerRequest<'a> {
+    inner: crate::file_manager::FileManagerGetFolderFileManagerRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> FileManagerGetFolderFileManagerRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::FileManagerFoldersGalleryFolder>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::file_manager::FileManager`].
+pub struct FileManager {
+    inner: crate::file_manager::FileManager,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl FileManager {
+    pub(crate) fn new(
+        inner: crate::file_manager::FileManager,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        FileManager { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::file_manager::FileManager::get_file`].
+    pub fn get_file(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        type_: &str,
+        created_by: &str,
+        before_created_at: &str,
+        since_created_at: &str,
+        sort_field: crate::types::GetFileManagerFilesSortField,
+        sort_dir: crate::types::SortDir,
+    ) -> ClientResult<crate::Response<crate::types::FileManager>> {
+        self.runtime.block_on(self.inner.get_file(
+            fields,
+            exclude_fields,
+            count,
+            offset,
+            type_,
+            created_by,
+            before_created_at,
+            since_created_at,
+            sort_field,
+            sort_dir,
+        ))
+    }
+    /// The blocking version of [`crate::file_manager::FileManager::get_file_request`].
+    pub fn get_file_request(&self) -> FileManagerGetFileRequest<'_> {
+        FileManagerGetFileRequest {
+            inner: self.inner.get_file_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::file_manager::FileManager::post`].
+    pub fn post(
+        &self,
+        body: &crate::types::GalleryFile,
+    ) -> ClientResult<crate::Response<crate::types::Files>> {
+        self.runtime.block_on(self.inner.post(body))
+    }
+    /// The blocking version of [`crate::file_manager::FileManager::get_file_file_manager`].
+    pub fn get_file_file_manager(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        file_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Files>> {
+        self.runtime.block_on(
+            self.inner
+                .get_file_file_manager(fields, exclude_fields, file_id),
+        )
+    }
+    /// The blocking version of [`crate::file_manager::FileManager::get_file_file_manager_request`].
+    pub fn get_file_file_manager_request<'a>(
+        &'a self,
+        file_id: &'a str,
+    ) -> FileManagerGetFileFileManagerRequest<'a> {
+        FileManagerGetFileFileManagerRequest {
+            inner: self.inner.get_file_file_manager_request(file_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::file_manager::FileManager::delete_files`].
+    pub fn delete_files(&self, file_id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.delete_files(file_id))
+    }
+    /// The blocking version of [`crate::file_manager::FileManager::patch_files`].
+    pub fn patch_files(
+        &self,
+        file_id: &str,
+        body: &crate::types::GalleryFileData,
+    ) -> ClientResult<crate::Response<crate::types::Files>> {
+        self.runtime.block_on(self.inner.patch_files(file_id, body))
+    }
+    /// The blocking version of [`crate::file_manager::FileManager::get_folder`].
+    pub fn get_folder(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        created_by: &str,
+        before_created_at: &str,
+        since_created_at: &str,
+    ) -> ClientResult<crate::Response<crate::types::FileManagerFolders>> {
+        self.runtime.block_on(self.inner.get_folder(
+            fields,
+            exclude_fields,
+            count,
+            offset,
+            created_by,
+            before_created_at,
+            since_created_at,
+        ))
+    }
+    /// The blocking version of [`crate::file_manager::FileManager::get_folder_request`].
+    pub fn get_folder_request(&self) -> FileManagerGetFolderRequest<'_> {
+        FileManagerGetFolderRequest {
+            inner: self.inner.get_folder_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::file_manager::FileManager::post_folder`].
+    pub fn post_folder(
+        &self,
+        body: &crate::types::GalleryFolder,
+    ) -> ClientResult<crate::Response<crate::types::FileManagerFoldersGalleryFolder>> {
+        self.runtime.block_on(self.inner.post_folder(body))
+    }
+    /// The blocking version of [`crate::file_manager::FileManager::get_folder_file_manager`].
+    pub fn get_folder_file_manager(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        folder_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::FileManagerFoldersGalleryFolder>> {
+        self.runtime.block_on(
+            self.inner
+                .get_folder_file_manager(fields, exclude_fields, folder_id),
+        )
+    }
+    /// The blocking version of [`crate::file_manager::FileManager::get_folder_file_manager_request`].
+    pub fn get_folder_file_manager_request<'a>(
+        &'a self,
+        folder_id: &'a str,
+    ) -> FileManagerGetFolderFileManagerRequest<'a> {
+        FileManagerGetFolderFileManagerRequest {
+            inner: self.inner.get_folder_file_manager_request(folder_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::file_manager::FileManager::delete_folders`].
+    pub fn delete_folders(&self, folder_id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.delete_folders(folder_id))
+    }
+    /// The blocking version of [`crate::file_manager::FileManager::patch_folders`].
+    pub fn patch_folders(
+        &self,
+        folder_id: &str,
+        body: &crate::types::GalleryFolder,
+    ) -> ClientResult<crate::Response<crate::types::FileManagerFoldersGalleryFolder>> {
+        self.runtime
+            .block_on(self.inner.patch_folders(folder_id, body))
+    }
+}
@@ -0,0 +1,236 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`LandingPages::get_all`], set with a builder.
+pub struct LandingPagesGetAllRequest<'a> {
+    inner: crate::landing_pages::LandingPagesGetAllRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> LandingPagesGetAllRequest<'a> {
+    /// Sets the `sort_dir` query parameter.
+    pub fn sort_dir(mut self, sort_dir: crate::types::SortDir) -> Self {
+        self.inner = self.inner.sort_dir(sort_dir);
+        self
+    }
+
+    /// Sets the `sort_field` query parameter.
+    pub fn sort_field(mut self, sort_field: crate::types::GetAllLandingPagesSortField) -> Self {
+        self.inner = self.inner.sort_field(sort_field);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.inner = self.inner.count(count);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::GetAllLandingPagesResponse>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`LandingPages::post_all`], set with a builder.
+pub struct LandingPagesPostAllRequest<'a> {
+    inner: crate::landing_pages::LandingPagesPostAllRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> LandingPagesPostAllRequest<'a> {
+    /// Sets the `use_default_list` query parameter.
+    pub fn use_default_list(mut self, use_default_list: bool) -> Self {
+        self.inner = self.inner.use_default_list(use_default_list);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::LandingPage>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`LandingPages::get`], set with a builder.
+pub struct LandingPagesGetRequest<'a> {
+    inner: crate::landing_pages::LandingPagesGetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> LandingPagesGetRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::LandingPage>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`LandingPages::get_content`], set with a builder.
+pub struct LandingPagesGetContentRequest<'a> {
+    inner: crate::landing_pages::LandingPagesGetContentRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> LandingPagesGetContentRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::LandingPageContent>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::landing_pages::LandingPages`].
+pub struct LandingPages {
+    inner: crate::landing_pages::LandingPages,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl LandingPages {
+    pub(crate) fn new(
+        inner: crate::landing_pages::LandingPages,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        LandingPages { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::landing_pages::LandingPages::get_all`].
+    pub fn get_all(
+        &self,
+        sort_dir: crate::types::SortDir,
+        sort_field: crate::types::GetAllLandingPagesSortField,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+    ) -> ClientResult<crate::Response<crate::types::GetAllLandingPagesResponse>> {
+        self.runtime.block_on(self.inner.get_all(
+            sort_dir,
+            sort_field,
+            fields,
+            exclude_fields,
+            count,
+        ))
+    }
+    /// The blocking version of [`crate::landing_pages::LandingPages::get_all_request`].
+    pub fn get_all_request(&self) -> LandingPagesGetAllRequest<'_> {
+        LandingPagesGetAllRequest {
+            inner: self.inner.get_all_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::landing_pages::LandingPages::post_all`].
+    pub fn post_all(
+        &self,
+        use_default_list: bool,
+        body: &crate::types::LandingPageData,
+    ) -> ClientResult<crate::Response<crate::types::LandingPage>> {
+        self.runtime
+            .block_on(self.inner.post_all(use_default_list, body))
+    }
+    /// The blocking version of [`crate::landing_pages::LandingPages::post_all_request`].
+    pub fn post_all_request<'a>(
+        &'a self,
+        body: &'a crate::types::LandingPageData,
+    ) -> LandingPagesPostAllRequest<'a> {
+        LandingPagesPostAllRequest {
+            inner: self.inner.post_all_request(body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::landing_pages::LandingPages::get`].
+    pub fn get(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        page_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::LandingPage>> {
+        self.runtime
+            .block_on(self.inner.get(fields, exclude_fields, page_id))
+    }
+    /// The blocking version of [`crate::landing_pages::LandingPages::get_request`].
+    pub fn get_request<'a>(&'a self, page_id: &'a str) -> LandingPagesGetRequest<'a> {
+        LandingPagesGetRequest {
+            inner: self.inner.get_request(page_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::landing_pages::LandingPages::delete`].
+    pub fn delete(&self, page_id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.delete(page_id))
+    }
+    /// The blocking version of [`crate::landing_pages::LandingPages::patch`].
+    pub fn patch(
+        &self,
+        page_id: &str,
+        body: &crate::types::LandingPageDataType,
+    ) -> ClientResult<crate::Response<crate::types::LandingPage>> {
+        self.runtime.block_on(self.inner.patch(page_id, body))
+    }
+    /// The blocking version of [`crate::landing_pages::LandingPages::post_actions_publish`].
+    pub fn post_actions_publish(
+        &self,
+        page_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::LandingPage>> {
+        self.runtime
+            .block_on(self.inner.post_actions_publish(page_id))
+    }
+    /// The blocking version of [`crate::landing_pages::LandingPages::post_actions_unpublish`].
+    pub fn post_actions_unpublish(&self, page_id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.post_actions_unpublish(page_id))
+    }
+    /// The blocking version of [`crate::landing_pages::LandingPages::get_content`].
+    pub fn get_content(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        page_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::LandingPageContent>> {
+        self.runtime
+            .block_on(self.inner.get_content(fields, exclude_fields, page_id))
+    }
+    /// The blocking version of [`crate::landing_pages::LandingPages::get_content_request`].
+    pub fn get_content_request<'a>(
+        &'a self,
+        page_id: &'a str,
+    ) -> LandingPagesGetContentRequest<'a> {
+        LandingPagesGetContentRequest {
+            inner: self.inner.get_content_request(page_id),
+            runtime: &self.runtime,
+        }
+    }
+}
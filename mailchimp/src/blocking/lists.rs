@@ -0,0 +1,2525 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Lists::get`], set with a builder.
+pub struct ListsGetRequest<'a> {
+    inner: crate::lists::ListsGetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ListsGetRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.inner = self.inner.count(count);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.inner = self.inner.offset(offset);
+        self
+    }
+
+    /// Sets the `before_date_created` query parameter.
+    pub fn before_date_created(mut self, before_date_created: &'a str) -> Self {
+        self.inner = self.inner.before_date_created(before_date_created);
+        self
+    }
+
+    /// Sets the `since_date_created` query parameter.
+    pub fn since_date_created(mut self, since_date_created: &'a str) -> Self {
+        self.inner = self.inner.since_date_created(since_date_created);
+        self
+    }
+
+    /// Sets the `before_campaign_last_sent` query parameter.
+    pub fn before_campaign_last_sent(mut self, before_campaign_last_sent: &'a str) -> Self {
+        self.inner = self
+            .inner
+            .before_campaign_last_sent(before_campaign_last_sent);
+        self
+    }
+
+    /// Sets the `since_campaign_last_sent` query parameter.
+    pub fn since_campaign_last_sent(mut self, since_campaign_last_sent: &'a str) -> Self {
+        self.inner = self
+            .inner
+            .since_campaign_last_sent(since_campaign_last_sent);
+        self
+    }
+
+    /// Sets the `email` query parameter.
+    pub fn email(mut self, email: &'a str) -> Self {
+        self.inner = self.inner.email(email);
+        self
+    }
+
+    /// Sets the `sort_field` query parameter.
+    pub fn sort_field(mut self, sort_field: crate::types::GetListsSortField) -> Self {
+        self.inner = self.inner.sort_field(sort_field);
+        self
+    }
+
+    /// Sets the `sort_dir` query parameter.
+    pub fn sort_dir(mut self, sort_dir: crate::types::SortDir) -> Self {
+        self.inner = self.inner.sort_dir(sort_dir);
+        self
+    }
+
+    /// Sets the `has_ecommerce_store` query parameter.
+    pub fn has_ecommerce_store(mut self, has_ecommerce_store: bool) -> Self {
+        self.inner = self.inner.has_ecommerce_store(has_ecommerce_store);
+        self
+    }
+
+    /// Sets the `include_total_contacts` query parameter.
+    pub fn include_total_contacts(mut self, include_total_contacts: bool) -> Self {
+        self.inner = self.inner.include_total_contacts(include_total_contacts);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::SubscriberLists>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Lists::get_lists`], set with a builder.
+pub struct ListsGetListsRequest<'a> {
+    inner: crate::lists::ListsGetListsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ListsGetListsRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sets the `include_total_contacts` query parameter.
+    pub fn include_total_contacts(mut self, include_total_contacts: bool) -> Self {
+        self.inner = self.inner.include_total_contacts(include_total_contacts);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::Lists>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Lists::post_lists`], set with a builder.
+pub struct ListsPostListsRequest<'a> {
+    inner: crate::lists::ListsPostListsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ListsPostListsRequest<'a> {
+    /// Sets the `skip_merge_validation` query parameter.
+    pub fn skip_merge_validation(mut self, skip_merge_validation: bool) -> Self {
+        self.inner = self.inner.skip_merge_validation(skip_merge_validation);
+        self
+    }
+
+    /// Sets the `skip_duplicate_check` query parameter.
+    pub fn skip_duplicate_check(mut self, skip_duplicate_check: bool) -> Self {
+        self.inner = self.inner.skip_duplicate_check(skip_duplicate_check);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::BatchUpdateListMembers>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Lists::get_abuse_report`], set with a builder.
+pub struct ListsGetAbuseReportRequest<'a> {
+    inner: crate::lists::ListsGetAbuseReportRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ListsGetAbuseReportRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.inner = self.inner.count(count);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.inner = self.inner.offset(offset);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::AbuseComplaints>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Lists::get_abuse_report_lists`], set with a builder.
+pub struct ListsGetAbuseReportListsRequest<'a> {
+    inner: crate::lists::ListsGetAbuseReportListsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ListsGetAbuseReportListsRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.inner = self.inner.count(count);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.inner = self.inner.offset(offset);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::AbuseReports>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Lists::get_activity`], set with a builder.
+pub struct ListsGetActivityRequest<'a> {
+    inner: crate::lists::ListsGetActivityRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ListsGetActivityRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::ListActivity>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Lists::get_client`], set with a builder.
+pub struct ListsGetClientRequest<'a> {
+    inner: crate::lists::ListsGetClientRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ListsGetClientRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::EmailClients>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Lists::get_growth_history`], set with a builder.
+pub struct ListsGetGrowthHistoryRequest<'a> {
+    inner: crate::lists::ListsGetGrowthHistoryRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ListsGetGrowthHistoryRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.inner = self.inner.count(count);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.inner = self.inner.offset(offset);
+        self
+    }
+
+    /// Sets the `sort_field` query parameter.
+    pub fn sort_field(mut self, sort_field: crate::types::GetListsGrowthHistorySortField) -> Self {
+        self.inner = self.inner.sort_field(sort_field);
+        self
+    }
+
+    /// Sets the `sort_dir` query parameter.
+    pub fn sort_dir(mut self, sort_dir: crate::types::SortDir) -> Self {
+        self.inner = self.inner.sort_dir(sort_dir);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::GrowthHistory>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Lists::get_growth_history_lists`], set with a builder.
+pub struct ListsGetGrowthHistoryListsRequest<'a> {
+    inner: crate::lists::ListsGetGrowthHistoryListsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ListsGetGrowthHistoryListsRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::History>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Lists::get_interest_categorie`], set with a builder.
+pub struct ListsGetInterestCategorieRequest<'a> {
+    inner: crate::lists::ListsGetInterestCategorieRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ListsGetInterestCategorieRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.inner = self.inner.count(count);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.inner = self.inner.offset(offset);
+        self
+    }
+
+    /// Sets the `type` query parameter.
+    pub fn type_(mut self, type_: &'a str) -> Self {
+        self.inner = self.inner.type_(type_);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::InterestGroupings>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Lists::get_interest_categorie_lists`], set with a builder.
+pub struct ListsGetInterestCategorieListsRequest<'a> {
+    inner: crate::lists::ListsGetInterestCategorieListsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ListsGetInterestCategorieListsRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::Categories>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Lists::get_interest_categories_interest`], set with a builder.
+pub struct ListsGetInterestCategoriesInterestRequest<'a> {
+    inner: crate::lists::ListsGetInterestCategoriesInterestRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ListsGetInterestCategoriesInterestRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.inner = self.inner.count(count);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.inner = self.inner.offset(offset);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::InterestsData>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Lists::get_interest_categories_interest_lists`], set with a builder.
+pub struct ListsGetInterestCategoriesInterestListsRequest<'a> {
+    inner: crate::lists::ListsGetInterestCategoriesInterestListsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ListsGetInterestCategoriesInterestListsRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::InterestsInterest>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Lists::preview_segment`], set with a builder.
+pub struct ListsPreviewSegmentRequest<'a> {
+    inner: crate::lists::ListsPreviewSegmentRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ListsPreviewSegmentRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.inner = self.inner.count(count);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.inner = self.inner.offset(offset);
+        self
+    }
+
+    /// Sets the `type` query parameter.
+    pub fn type_(mut self, type_: &'a str) -> Self {
+        self.inner = self.inner.type_(type_);
+        self
+    }
+
+    /// Sets the `since_created_at` query parameter.
+    pub fn since_created_at(mut self, since_created_at: &'a str) -> Self {
+        self.inner = self.inner.since_created_at(since_created_at);
+        self
+    }
+
+    /// Sets the `before_created_at` query parameter.
+    pub fn before_created_at(mut self, before_created_at: &'a str) -> Self {
+        self.inner = self.inner.before_created_at(before_created_at);
+        self
+    }
+
+    /// Sets the `include_cleaned` query parameter.
+    pub fn include_cleaned(mut self, include_cleaned: bool) -> Self {
+        self.inner = self.inner.include_cleaned(include_cleaned);
+        self
+    }
+
+    /// Sets the `include_transactional` query parameter.
+    pub fn include_transactional(mut self, include_transactional: bool) -> Self {
+        self.inner = self.inner.include_transactional(include_transactional);
+        self
+    }
+
+    /// Sets the `include_unsubscribed` query parameter.
+    pub fn include_unsubscribed(mut self, include_unsubscribed: bool) -> Self {
+        self.inner = self.inner.include_unsubscribed(include_unsubscribed);
+        self
+    }
+
+    /// Sets the `since_updated_at` query parameter.
+    pub fn since_updated_at(mut self, since_updated_at: &'a str) -> Self {
+        self.inner = self.inner.since_updated_at(since_updated_at);
+        self
+    }
+
+    /// Sets the `before_updated_at` query parameter.
+    pub fn before_updated_at(mut self, before_updated_at: &'a str) -> Self {
+        self.inner = self.inner.before_updated_at(before_updated_at);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::CollectionOfSegments>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Lists::get_segment`], set with a builder.
+pub struct ListsGetSegmentRequest<'a> {
+    inner: crate::lists::ListsGetSegmentRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ListsGetSegmentRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sets the `include_cleaned` query parameter.
+    pub fn include_cleaned(mut self, include_cleaned: bool) -> Self {
+        self.inner = self.inner.include_cleaned(include_cleaned);
+        self
+    }
+
+    /// Sets the `include_transactional` query parameter.
+    pub fn include_transactional(mut self, include_transactional: bool) -> Self {
+        self.inner = self.inner.include_transactional(include_transactional);
+        self
+    }
+
+    /// Sets the `include_unsubscribed` query parameter.
+    pub fn include_unsubscribed(mut self, include_unsubscribed: bool) -> Self {
+        self.inner = self.inner.include_unsubscribed(include_unsubscribed);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::Segments>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Lists::get_segments_member`], set with a builder.
+pub struct ListsGetSegmentsMemberRequest<'a> {
+    inner: crate::lists::ListsGetSegmentsMemberRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ListsGetSegmentsMemberRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.inner = self.inner.count(count);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.inner = self.inner.offset(offset);
+        self
+    }
+
+    /// Sets the `include_cleaned` query parameter.
+    pub fn include_cleaned(mut self, include_cleaned: bool) -> Self {
+        self.inner = self.inner.include_cleaned(include_cleaned);
+        self
+    }
+
+    /// Sets the `include_transactional` query parameter.
+    pub fn include_transactional(mut self, include_transactional: bool) -> Self {
+        self.inner = self.inner.include_transactional(include_transactional);
+        self
+    }
+
+    /// Sets the `include_unsubscribed` query parameter.
+    pub fn include_unsubscribed(mut self, include_unsubscribed: bool) -> Self {
+        self.inner = self.inner.include_unsubscribed(include_unsubscribed);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::SegmentMembers>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Lists::search_tags_name`], set with a builder.
+pub struct ListsSearchTagsNameRequest<'a> {
+    inner: crate::lists::ListsSearchTagsNameRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ListsSearchTagsNameRequest<'a> {
+    /// Sets the `name` query parameter.
+    pub fn name(mut self, name: &'a str) -> Self {
+        self.inner = self.inner.name(name);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::TagSearchResults>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Lists::get_member`], set with a builder.
+pub struct ListsGetMemberRequest<'a> {
+    inner: crate::lists::ListsGetMemberRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ListsGetMemberRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.inner = self.inner.count(count);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.inner = self.inner.offset(offset);
+        self
+    }
+
+    /// Sets the `email_type` query parameter.
+    pub fn email_type(mut self, email_type: &'a str) -> Self {
+        self.inner = self.inner.email_type(email_type);
+        self
+    }
+
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: crate::types::GetListsMembersStatus) -> Self {
+        self.inner = self.inner.status(status);
+        self
+    }
+
+    /// Sets the `since_timestamp_opt` query parameter.
+    pub fn since_timestamp_opt(mut self, since_timestamp_opt: &'a str) -> Self {
+        self.inner = self.inner.since_timestamp_opt(since_timestamp_opt);
+        self
+    }
+
+    /// Sets the `before_timestamp_opt` query parameter.
+    pub fn before_timestamp_opt(mut self, before_timestamp_opt: &'a str) -> Self {
+        self.inner = self.inner.before_timestamp_opt(before_timestamp_opt);
+        self
+    }
+
+    /// Sets the `since_last_changed` query parameter.
+    pub fn since_last_changed(mut self, since_last_changed: &'a str) -> Self {
+        self.inner = self.inner.since_last_changed(since_last_changed);
+        self
+    }
+
+    /// Sets the `before_last_changed` query parameter.
+    pub fn before_last_changed(mut self, before_last_changed: &'a str) -> Self {
+        self.inner = self.inner.before_last_changed(before_last_changed);
+        self
+    }
+
+    /// Sets the `unique_email_id` query parameter.
+    pub fn unique_email_id(mut self, unique_email_id: &'a str) -> Self {
+        self.inner = self.inner.unique_email_id(unique_email_id);
+        self
+    }
+
+    /// Sets the `vip_only` query parameter.
+    pub fn vip_only(mut self, vip_only: bool) -> Self {
+        self.inner = self.inner.vip_only(vip_only);
+        self
+    }
+
+    /// Sets the `interest_category_id` query parameter.
+    pub fn interest_category_id(mut self, interest_category_id: &'a str) -> Self {
+        self.inner = self.inner.interest_category_id(interest_category_id);
+        self
+    }
+
+    /// Sets the `interest_ids` query parameter.
+    pub fn interest_ids(mut self, interest_ids: &'a str) -> Self {
+        self.inner = self.inner.interest_ids(interest_ids);
+        self
+    }
+
+    /// Sets the `interest_match` query parameter.
+    pub fn interest_match(mut self, interest_match: crate::types::InterestMatch) -> Self {
+        self.inner = self.inner.interest_match(interest_match);
+        self
+    }
+
+    /// Sets the `sort_field` query parameter.
+    pub fn sort_field(mut self, sort_field: crate::types::GetListsMembersSortField) -> Self {
+        self.inner = self.inner.sort_field(sort_field);
+        self
+    }
+
+    /// Sets the `sort_dir` query parameter.
+    pub fn sort_dir(mut self, sort_dir: crate::types::SortDir) -> Self {
+        self.inner = self.inner.sort_dir(sort_dir);
+        self
+    }
+
+    /// Sets the `since_last_campaign` query parameter.
+    pub fn since_last_campaign(mut self, since_last_campaign: bool) -> Self {
+        self.inner = self.inner.since_last_campaign(since_last_campaign);
+        self
+    }
+
+    /// Sets the `unsubscribed_since` query parameter.
+    pub fn unsubscribed_since(mut self, unsubscribed_since: &'a str) -> Self {
+        self.inner = self.inner.unsubscribed_since(unsubscribed_since);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::ListMembersDataType>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Lists::post_member`], set with a builder.
+pub struct ListsPostMemberRequest<'a> {
+    inner: crate::lists::ListsPostMemberRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ListsPostMemberRequest<'a> {
+    /// Sets the `skip_merge_validation` query parameter.
+    pub fn skip_merge_validation(mut self, skip_merge_validation: bool) -> Self {
+        self.inner = self.inner.skip_merge_validation(skip_merge_validation);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::ListMembersData>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Lists::get_member_lists`], set with a builder.
+pub struct ListsGetMemberListsRequest<'a> {
+    inner: crate::lists::ListsGetMemberListsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ListsGetMemberListsRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::ListMembersData>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Lists::put_members`], set with a builder.
+pub struct ListsPutMembersRequest<'a> {
+    inner: crate::lists::ListsPutMembersRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ListsPutMembersRequest<'a> {
+    /// Sets the `skip_merge_validation` query parameter.
+    pub fn skip_merge_validation(mut self, skip_merge_validation: bool) -> Self {
+        self.inner = self.inner.skip_merge_validation(skip_merge_validation);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::ListMembersData>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Lists::patch_members`], set with a builder.
+pub struct ListsPatchMembersRequest<'a> {
+    inner: crate::lists::ListsPatchMembersRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ListsPatchMembersRequest<'a> {
+    /// Sets the `skip_merge_validation` query parameter.
+    pub fn skip_merge_validation(mut self, skip_merge_validation: bool) -> Self {
+        self.inner = self.inner.skip_merge_validation(skip_merge_validation);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::ListMembersData>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Lists::get_members_activity`], set with a builder.
+pub struct ListsGetMembersActivityRequest<'a> {
+    inner: crate::lists::ListsGetMembersActivityRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ListsGetMembersActivityRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sets the `action` query parameter.
+    pub fn action(mut self, action: &'a [String]) -> Self {
+        self.inner = self.inner.action(action);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::MemberActivityEvents>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Lists::get_members_activity_feed`], set with a builder.
+pub struct ListsGetMembersActivityFeedRequest<'a> {
+    inner: crate::lists::ListsGetMembersActivityFeedRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ListsGetMembersActivityFeedRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.inner = self.inner.count(count);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.inner = self.inner.offset(offset);
+        self
+    }
+
+    /// Sets the `activity_filters` query parameter.
+    pub fn activity_filters(mut self, activity_filters: &'a [String]) -> Self {
+        self.inner = self.inner.activity_filters(activity_filters);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::MemberActivityEventsData>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Lists::get_member_tag`], set with a builder.
+pub struct ListsGetMemberTagRequest<'a> {
+    inner: crate::lists::ListsGetMemberTagRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ListsGetMemberTagRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.inner = self.inner.count(count);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.inner = self.inner.offset(offset);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::CollectionOfTags>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Lists::get_members_event`], set with a builder.
+pub struct ListsGetMembersEventRequest<'a> {
+    inner: crate::lists::ListsGetMembersEventRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ListsGetMembersEventRequest<'a> {
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.inner = self.inner.count(count);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.inner = self.inner.offset(offset);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::CollectionOfEvents>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Lists::get_members_goal`], set with a builder.
+pub struct ListsGetMembersGoalRequest<'a> {
+    inner: crate::lists::ListsGetMembersGoalRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ListsGetMembersGoalRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::CollectionOfMemberActivityEvents>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Lists::get_members_note`], set with a builder.
+pub struct ListsGetMembersNoteRequest<'a> {
+    inner: crate::lists::ListsGetMembersNoteRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ListsGetMembersNoteRequest<'a> {
+    /// Sets the `sort_field` query parameter.
+    pub fn sort_field(mut self, sort_field: crate::types::GetListsMembersNotesSortField) -> Self {
+        self.inner = self.inner.sort_field(sort_field);
+        self
+    }
+
+    /// Sets the `sort_dir` query parameter.
+    pub fn sort_dir(mut self, sort_dir: crate::types::SortDir) -> Self {
+        self.inner = self.inner.sort_dir(sort_dir);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.inner = self.inner.count(count);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.inner = self.inner.offset(offset);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::CollectionOfNotes>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Lists::get_members_note_lists`], set with a builder.
+pub struct ListsGetMembersNoteListsRequest<'a> {
+    inner: crate::lists::ListsGetMembersNoteListsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ListsGetMembersNoteListsRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::CollectionOfNotesMember>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Lists::get_merge_field`], set with a builder.
+pub struct ListsGetMergeFieldRequest<'a> {
+    inner: crate::lists::ListsGetMergeFieldRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ListsGetMergeFieldRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.inner = self.inner.count(count);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.inner = self.inner.offset(offset);
+        self
+    }
+
+    /// Sets the `type` query parameter.
+    pub fn type_(mut self, type_: &'a str) -> Self {
+        self.inner = self.inner.type_(type_);
+        self
+    }
+
+    /// Sets the `required` query parameter.
+    pub fn required(mut self, required: bool) -> Self {
+        self.inner = self.inner.required(required);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::CollectionOfMergeFields>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Lists::get_merge_field_lists`], set with a builder.
+pub struct ListsGetMergeFieldListsRequest<'a> {
+    inner: crate::lists::ListsGetMergeFieldListsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ListsGetMergeFieldListsRequest<'a> {
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::MergeField>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Lists::get_location`], set with a builder.
+pub struct ListsGetLocationRequest<'a> {
+    inner: crate::lists::ListsGetLocationRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ListsGetLocationRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::ListLocations>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::lists::Lists`].
+pub struct Lists {
+    inner: crate::lists::Lists,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Lists {
+    pub(crate) fn new(inner: crate::lists::Lists, runtime: Arc<tokio::runtime::Runtime>) -> Self {
+        Lists { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::lists::Lists::get`].
+    pub fn get(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        before_date_created: &str,
+        since_date_created: &str,
+        before_campaign_last_sent: &str,
+        since_campaign_last_sent: &str,
+        email: &str,
+        sort_field: crate::types::GetListsSortField,
+        sort_dir: crate::types::SortDir,
+        has_ecommerce_store: bool,
+        include_total_contacts: bool,
+    ) -> ClientResult<crate::Response<crate::types::SubscriberLists>> {
+        self.runtime.block_on(self.inner.get(
+            fields,
+            exclude_fields,
+            count,
+            offset,
+            before_date_created,
+            since_date_created,
+            before_campaign_last_sent,
+            since_campaign_last_sent,
+            email,
+            sort_field,
+            sort_dir,
+            has_ecommerce_store,
+            include_total_contacts,
+        ))
+    }
+    /// The blocking version of [`crate::lists::Lists::get_request`].
+    pub fn get_request(&self) -> ListsGetRequest<'_> {
+        ListsGetRequest {
+            inner: self.inner.get_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::lists::Lists::post`].
+    pub fn post(
+        &self,
+        body: &crate::types::SubscriberList,
+    ) -> ClientResult<crate::Response<crate::types::Lists>> {
+        self.runtime.block_on(self.inner.post(body))
+    }
+    /// The blocking version of [`crate::lists::Lists::get_lists`].
+    pub fn get_lists(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        list_id: &str,
+        include_total_contacts: bool,
+    ) -> ClientResult<crate::Response<crate::types::Lists>> {
+        self.runtime.block_on(self.inner.get_lists(
+            fields,
+            exclude_fields,
+            list_id,
+            include_total_contacts,
+        ))
+    }
+    /// The blocking version of [`crate::lists::Lists::get_lists_request`].
+    pub fn get_lists_request<'a>(&'a self, list_id: &'a str) -> ListsGetListsRequest<'a> {
+        ListsGetListsRequest {
+            inner: self.inner.get_lists_request(list_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::lists::Lists::post_lists`].
+    pub fn post_lists(
+        &self,
+        list_id: &str,
+        skip_merge_validation: bool,
+        skip_duplicate_check: bool,
+        body: &crate::types::MembersSubscribeUnsubscribeFromAListInBatch,
+    ) -> ClientResult<crate::Response<crate::types::BatchUpdateListMembers>> {
+        self.runtime.block_on(self.inner.post_lists(
+            list_id,
+            skip_merge_validation,
+            skip_duplicate_check,
+            body,
+        ))
+    }
+    /// The blocking version of [`crate::lists::Lists::post_lists_request`].
+    pub fn post_lists_request<'a>(
+        &'a self,
+        list_id: &'a str,
+        body: &'a crate::types::MembersSubscribeUnsubscribeFromAListInBatch,
+    ) -> ListsPostListsRequest<'a> {
+        ListsPostListsRequest {
+            inner: self.inner.post_lists_request(list_id, body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::lists::Lists::delete`].
+    pub fn delete(&self, list_id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.delete(list_id))
+    }
+    /// The blocking version of [`crate::lists::Lists::patch`].
+    pub fn patch(
+        &self,
+        list_id: &str,
+        body: &crate::types::SubscriberListData,
+    ) -> ClientResult<crate::Response<crate::types::Lists>> {
+        self.runtime.block_on(self.inner.patch(list_id, body))
+    }
+    /// The blocking version of [`crate::lists::Lists::get_abuse_report`].
+    pub fn get_abuse_report(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        list_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::AbuseComplaints>> {
+        self.runtime.block_on(self.inner.get_abuse_report(
+            fields,
+            exclude_fields,
+            count,
+            offset,
+            list_id,
+        ))
+    }
+    /// The blocking version of [`crate::lists::Lists::get_abuse_report_request`].
+    pub fn get_abuse_report_request<'a>(
+        &'a self,
+        list_id: &'a str,
+    ) -> ListsGetAbuseReportRequest<'a> {
+        ListsGetAbuseReportRequest {
+            inner: self.inner.get_abuse_report_request(list_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::lists::Lists::get_abuse_report_lists`].
+    pub fn get_abuse_report_lists(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        list_id: &str,
+        report_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::AbuseReports>> {
+        self.runtime.block_on(self.inner.get_abuse_report_lists(
+            fields,
+            exclude_fields,
+            count,
+            offset,
+            list_id,
+            report_id,
+        ))
+    }
+    /// The blocking version of [`crate::lists::Lists::get_abuse_report_lists_request`].
+    pub fn get_abuse_report_lists_request<'a>(
+        &'a self,
+        list_id: &'a str,
+        report_id: &'a str,
+    ) -> ListsGetAbuseReportListsRequest<'a> {
+        ListsGetAbuseReportListsRequest {
+            inner: self
+                .inner
+                .get_abuse_report_lists_request(list_id, report_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::lists::Lists::get_activity`].
+    pub fn get_activity(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        list_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ListActivity>> {
+        self.runtime
+            .block_on(self.inner.get_activity(fields, exclude_fields, list_id))
+    }
+    /// The blocking version of [`crate::lists::Lists::get_activity_request`].
+    pub fn get_activity_request<'a>(&'a self, list_id: &'a str) -> ListsGetActivityRequest<'a> {
+        ListsGetActivityRequest {
+            inner: self.inner.get_activity_request(list_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::lists::Lists::get_client`].
+    pub fn get_client(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        list_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::EmailClients>> {
+        self.runtime
+            .block_on(self.inner.get_client(fields, exclude_fields, list_id))
+    }
+    /// The blocking version of [`crate::lists::Lists::get_client_request`].
+    pub fn get_client_request<'a>(&'a self, list_id: &'a str) -> ListsGetClientRequest<'a> {
+        ListsGetClientRequest {
+            inner: self.inner.get_client_request(list_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::lists::Lists::get_growth_history`].
+    pub fn get_growth_history(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        list_id: &str,
+        sort_field: crate::types::GetListsGrowthHistorySortField,
+        sort_dir: crate::types::SortDir,
+    ) -> ClientResult<crate::Response<crate::types::GrowthHistory>> {
+        self.runtime.block_on(self.inner.get_growth_history(
+            fields,
+            exclude_fields,
+            count,
+            offset,
+            list_id,
+            sort_field,
+            sort_dir,
+        ))
+    }
+    /// The blocking version of [`crate::lists::Lists::get_growth_history_request`].
+    pub fn get_growth_history_request<'a>(
+        &'a self,
+        list_id: &'a str,
+    ) -> ListsGetGrowthHistoryRequest<'a> {
+        ListsGetGrowthHistoryRequest {
+            inner: self.inner.get_growth_history_request(list_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::lists::Lists::get_growth_history_lists`].
+    pub fn get_growth_history_lists(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        list_id: &str,
+        month: &str,
+    ) -> ClientResult<crate::Response<crate::types::History>> {
+        self.runtime.block_on(self.inner.get_growth_history_lists(
+            fields,
+            exclude_fields,
+            list_id,
+            month,
+        ))
+    }
+    /// The blocking version of [`crate::lists::Lists::get_growth_history_lists_request`].
+    pub fn get_growth_history_lists_request<'a>(
+        &'a self,
+        list_id: &'a str,
+        month: &'a str,
+    ) -> ListsGetGrowthHistoryListsRequest<'a> {
+        ListsGetGrowthHistoryListsRequest {
+            inner: self.inner.get_growth_history_lists_request(list_id, month),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::lists::Lists::get_interest_categorie`].
+    pub fn get_interest_categorie(
+        &self,
+        list_id: &str,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        type_: &str,
+    ) -> ClientResult<crate::Response<crate::types::InterestGroupings>> {
+        self.runtime.block_on(self.inner.get_interest_categorie(
+            list_id,
+            fields,
+            exclude_fields,
+            count,
+            offset,
+            type_,
+        ))
+    }
+    /// The blocking version of [`crate::lists::Lists::get_interest_categorie_request`].
+    pub fn get_interest_categorie_request<'a>(
+        &'a self,
+        list_id: &'a str,
+    ) -> ListsGetInterestCategorieRequest<'a> {
+        ListsGetInterestCategorieRequest {
+            inner: self.inner.get_interest_categorie_request(list_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::lists::Lists::post_interest_categorie`].
+    pub fn post_interest_categorie(
+        &self,
+        list_id: &str,
+        body: &crate::types::InterestCategory,
+    ) -> ClientResult<crate::Response<crate::types::Categories>> {
+        self.runtime
+            .block_on(self.inner.post_interest_categorie(list_id, body))
+    }
+    /// The blocking version of [`crate::lists::Lists::get_interest_categorie_lists`].
+    pub fn get_interest_categorie_lists(
+        &self,
+        list_id: &str,
+        interest_category_id: &str,
+        fields: &[String],
+        exclude_fields: &[String],
+    ) -> ClientResult<crate::Response<crate::types::Categories>> {
+        self.runtime
+            .block_on(self.inner.get_interest_categorie_lists(
+                list_id,
+                interest_category_id,
+                fields,
+                exclude_fields,
+            ))
+    }
+    /// The blocking version of [`crate::lists::Lists::get_interest_categorie_lists_request`].
+    pub fn get_interest_categorie_lists_request<'a>(
+        &'a self,
+        list_id: &'a str,
+        interest_category_id: &'a str,
+    ) -> ListsGetInterestCategorieListsRequest<'a> {
+        ListsGetInterestCategorieListsRequest {
+            inner: self
+                .inner
+                .get_interest_categorie_lists_request(list_id, interest_category_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::lists::Lists::delete_interest_categories`].
+    pub fn delete_interest_categories(
+        &self,
+        list_id: &str,
+        interest_category_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .delete_interest_categories(list_id, interest_category_id),
+        )
+    }
+    /// The blocking version of [`crate::lists::Lists::patch_interest_categories`].
+    pub fn patch_interest_categories(
+        &self,
+        list_id: &str,
+        interest_category_id: &str,
+        body: &crate::types::InterestCategory,
+    ) -> ClientResult<crate::Response<crate::types::Categories>> {
+        self.runtime.block_on(self.inner.patch_interest_categories(
+            list_id,
+            interest_category_id,
+            body,
+        ))
+    }
+    /// The blocking version of [`crate::lists::Lists::get_interest_categories_interest`].
+    pub fn get_interest_categories_interest(
+        &self,
+        list_id: &str,
+        interest_category_id: &str,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+    ) -> ClientResult<crate::Response<crate::types::InterestsData>> {
+        self.runtime
+            .block_on(self.inner.get_interest_categories_interest(
+                list_id,
+                interest_category_id,
+                fields,
+                exclude_fields,
+                count,
+                offset,
+            ))
+    }
+    /// The blocking version of [`crate::lists::Lists::get_interest_categories_interest_request`].
+    pub fn get_interest_categories_interest_request<'a>(
+        &'a self,
+        list_id: &'a str,
+        interest_category_id: &'a str,
+    ) -> ListsGetInterestCategoriesInterestRequest<'a> {
+        ListsGetInterestCategoriesInterestRequest {
+            inner: self
+                .inner
+                .get_interest_categories_interest_request(list_id, interest_category_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::lists::Lists::post_interest_categories`].
+    pub fn post_interest_categories(
+        &self,
+        list_id: &str,
+        interest_category_id: &str,
+        body: &crate::types::Interest,
+    ) -> ClientResult<crate::Response<crate::types::InterestsInterest>> {
+        self.runtime.block_on(self.inner.post_interest_categories(
+            list_id,
+            interest_category_id,
+            body,
+        ))
+    }
+    /// The blocking version of [`crate::lists::Lists::get_interest_categories_interest_lists`].
+    pub fn get_interest_categories_interest_lists(
+        &self,
+        list_id: &str,
+        interest_category_id: &str,
+        interest_id: &str,
+        fields: &[String],
+        exclude_fields: &[String],
+    ) -> ClientResult<crate::Response<crate::types::InterestsInterest>> {
+        self.runtime
+            .block_on(self.inner.get_interest_categories_interest_lists(
+                list_id,
+                interest_category_id,
+                interest_id,
+                fields,
+                exclude_fields,
+            ))
+    }
+    /// The blocking version of [`crate::lists::Lists::get_interest_categories_interest_lists_request`].
+    pub fn get_interest_categories_interest_lists_request<'a>(
+        &'a self,
+        list_id: &'a str,
+        interest_category_id: &'a str,
+        interest_id: &'a str,
+    ) -> ListsGetInterestCategoriesInterestListsRequest<'a> {
+        ListsGetInterestCategoriesInterestListsRequest {
+            inner: self.inner.get_interest_categories_interest_lists_request(
+                list_id,
+                interest_category_id,
+                interest_id,
+            ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::lists::Lists::delete_interest_categories_interests`].
+    pub fn delete_interest_categories_interests(
+        &self,
+        list_id: &str,
+        interest_category_id: &str,
+        interest_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete_interest_categories_interests(
+                list_id,
+                interest_category_id,
+                interest_id,
+            ))
+    }
+    /// The blocking version of [`crate::lists::Lists::patch_interest_categories_interests`].
+    pub fn patch_interest_categories_interests(
+        &self,
+        list_id: &str,
+        interest_category_id: &str,
+        interest_id: &str,
+        body: &crate::types::Interest,
+    ) -> ClientResult<crate::Response<crate::types::InterestsInterest>> {
+        self.runtime
+            .block_on(self.inner.patch_interest_categories_interests(
+                list_id,
+                interest_category_id,
+                interest_id,
+                body,
+            ))
+    }
+    /// The blocking version of [`crate::lists::Lists::preview_segment`].
+    pub fn preview_segment(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        list_id: &str,
+        type_: &str,
+        since_created_at: &str,
+        before_created_at: &str,
+        include_cleaned: bool,
+        include_transactional: bool,
+        include_unsubscribed: bool,
+        since_updated_at: &str,
+        before_updated_at: &str,
+    ) -> ClientResult<crate::Response<crate::types::CollectionOfSegments>> {
+        self.runtime.block_on(self.inner.preview_segment(
+            fields,
+            exclude_fields,
+            count,
+            offset,
+            list_id,
+            type_,
+            since_created_at,
+            before_created_at,
+            include_cleaned,
+            include_transactional,
+            include_unsubscribed,
+            since_updated_at,
+            before_updated_at,
+        ))
+    }
+    /// The blocking version of [`crate::lists::Lists::preview_segment_request`].
+    pub fn preview_segment_request<'a>(
+        &'a self,
+        list_id: &'a str,
+    ) -> ListsPreviewSegmentRequest<'a> {
+        ListsPreviewSegmentRequest {
+            inner: self.inner.preview_segment_request(list_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::lists::Lists::post_segment`].
+    pub fn post_segment(
+        &self,
+        list_id: &str,
+        body: &crate::types::ListData,
+    ) -> ClientResult<crate::Response<crate::types::Segments>> {
+        self.runtime
+            .block_on(self.inner.post_segment(list_id, body))
+    }
+    /// The blocking version of [`crate::lists::Lists::get_segment`].
+    pub fn get_segment(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        list_id: &str,
+        segment_id: &str,
+        include_cleaned: bool,
+        include_transactional: bool,
+        include_unsubscribed: bool,
+    ) -> ClientResult<crate::Response<crate::types::Segments>> {
+        self.runtime.block_on(self.inner.get_segment(
+            fields,
+            exclude_fields,
+            list_id,
+            segment_id,
+            include_cleaned,
+            include_transactional,
+            include_unsubscribed,
+        ))
+    }
+    /// The blocking version of [`crate::lists::Lists::get_segment_request`].
+    pub fn get_segment_request<'a>(
+        &'a self,
+        list_id: &'a str,
+        segment_id: &'a str,
+    ) -> ListsGetSegmentRequest<'a> {
+        ListsGetSegmentRequest {
+            inner: self.inner.get_segment_request(list_id, segment_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::lists::Lists::post_segment_lists`].
+    pub fn post_segment_lists(
+        &self,
+        list_id: &str,
+        segment_id: &str,
+        body: &crate::types::MembersAddRemoveFromAStaticSegment,
+    ) -> ClientResult<crate::Response<crate::types::BatchAddRemoveListMembersFromStaticSegment>>
+    {
+        self.runtime
+            .block_on(self.inner.post_segment_lists(list_id, segment_id, body))
+    }
+    /// The blocking version of [`crate::lists::Lists::delete_segments`].
+    pub fn delete_segments(
+        &self,
+        list_id: &str,
+        segment_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete_segments(list_id, segment_id))
+    }
+    /// The blocking version of [`crate::lists::Lists::patch_segments`].
+    pub fn patch_segments(
+        &self,
+        list_id: &str,
+        segment_id: &str,
+        body: &crate::types::ListDataType,
+    ) -> ClientResult<crate::Response<crate::types::Segments>> {
+        self.runtime
+            .block_on(self.inner.patch_segments(list_id, segment_id, body))
+    }
+    /// The blocking version of [`crate::lists::Lists::get_segments_member`].
+    pub fn get_segments_member(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        list_id: &str,
+        segment_id: &str,
+        include_cleaned: bool,
+        include_transactional: bool,
+        include_unsubscribed: bool,
+    ) -> ClientResult<crate::Response<crate::types::SegmentMembers>> {
+        self.runtime.block_on(self.inner.get_segments_member(
+            fields,
+            exclude_fields,
+            count,
+            offset,
+            list_id,
+            segment_id,
+            include_cleaned,
+            include_transactional,
+            include_unsubscribed,
+        ))
+    }
+    /// The blocking version of [`crate::lists::Lists::get_segments_member_request`].
+    pub fn get_segments_member_request<'a>(
+        &'a self,
+        list_id: &'a str,
+        segment_id: &'a str,
+    ) -> ListsGetSegmentsMemberRequest<'a> {
+        ListsGetSegmentsMemberRequest {
+            inner: self.inner.get_segments_member_request(list_id, segment_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::lists::Lists::post_segments_member`].
+    pub fn post_segments_member(
+        &self,
+        list_id: &str,
+        segment_id: &str,
+        body: &crate::types::SubscriberInAutomationQueue,
+    ) -> ClientResult<crate::Response<crate::types::ListMembers>> {
+        self.runtime
+            .block_on(self.inner.post_segments_member(list_id, segment_id, body))
+    }
+    /// The blocking version of [`crate::lists::Lists::delete_segments_members`].
+    pub fn delete_segments_members(
+        &self,
+        list_id: &str,
+        segment_id: &str,
+        subscriber_hash: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.delete_segments_members(
+            list_id,
+            segment_id,
+            subscriber_hash,
+        ))
+    }
+    /// The blocking version of [`crate::lists::Lists::search_tags_name`].
+    pub fn search_tags_name(
+        &self,
+        list_id: &str,
+        name: &str,
+    ) -> ClientResult<crate::Response<crate::types::TagSearchResults>> {
+        self.runtime
+            .block_on(self.inner.search_tags_name(list_id, name))
+    }
+    /// The blocking version of [`crate::lists::Lists::search_tags_name_request`].
+    pub fn search_tags_name_request<'a>(
+        &'a self,
+        list_id: &'a str,
+    ) -> ListsSearchTagsNameRequest<'a> {
+        ListsSearchTagsNameRequest {
+            inner: self.inner.search_tags_name_request(list_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::lists::Lists::get_member`].
+    pub fn get_member(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        list_id: &str,
+        email_type: &str,
+        status: crate::types::GetListsMembersStatus,
+        since_timestamp_opt: &str,
+        before_timestamp_opt: &str,
+        since_last_changed: &str,
+        before_last_changed: &str,
+        unique_email_id: &str,
+        vip_only: bool,
+        interest_category_id: &str,
+        interest_ids: &str,
+        interest_match: crate::types::InterestMatch,
+        sort_field: crate::types::GetListsMembersSortField,
+        sort_dir: crate::types::SortDir,
+        since_last_campaign: bool,
+        unsubscribed_since: &str,
+    ) -> ClientResult<crate::Response<crate::types::ListMembersDataType>> {
+        self.runtime.block_on(self.inner.get_member(
+            fields,
+            exclude_fields,
+            count,
+            offset,
+            list_id,
+            email_type,
+            status,
+            since_timestamp_opt,
+            before_timestamp_opt,
+            since_last_changed,
+            before_last_changed,
+            unique_email_id,
+            vip_only,
+            interest_category_id,
+            interest_ids,
+            interest_match,
+            sort_field,
+            sort_dir,
+            since_last_campaign,
+            unsubscribed_since,
+        ))
+    }
+    /// The blocking version of [`crate::lists::Lists::get_member_request`].
+    pub fn get_member_request<'a>(&'a self, list_id: &'a str) -> ListsGetMemberRequest<'a> {
+        ListsGetMemberRequest {
+            inner: self.inner.get_member_request(list_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::lists::Lists::post_member`].
+    pub fn post_member(
+        &self,
+        list_id: &str,
+        skip_merge_validation: bool,
+        body: &crate::types::AddListMembers,
+    ) -> ClientResult<crate::Response<crate::types::ListMembersData>> {
+        self.runtime
+            .block_on(self.inner.post_member(list_id, skip_merge_validation, body))
+    }
+    /// The blocking version of [`crate::lists::Lists::post_member_request`].
+    pub fn post_member_request<'a>(
+        &'a self,
+        list_id: &'a str,
+        body: &'a crate::types::AddListMembers,
+    ) -> ListsPostMemberRequest<'a> {
+        ListsPostMemberRequest {
+            inner: self.inner.post_member_request(list_id, body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::lists::Lists::get_member_lists`].
+    pub fn get_member_lists(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        list_id: &str,
+        subscriber_hash: &str,
+    ) -> ClientResult<crate::Response<crate::types::ListMembersData>> {
+        self.runtime.block_on(self.inner.get_member_lists(
+            fields,
+            exclude_fields,
+            list_id,
+            subscriber_hash,
+        ))
+    }
+    /// The blocking version of [`crate::lists::Lists::get_member_lists_request`].
+    pub fn get_member_lists_request<'a>(
+        &'a self,
+        list_id: &'a str,
+        subscriber_hash: &'a str,
+    ) -> ListsGetMemberListsRequest<'a> {
+        ListsGetMemberListsRequest {
+            inner: self
+                .inner
+                .get_member_lists_request(list_id, subscriber_hash),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::lists::Lists::put_members`].
+    pub fn put_members(
+        &self,
+        list_id: &str,
+        subscriber_hash: &str,
+        skip_merge_validation: bool,
+        body: &crate::types::AddListMembersData,
+    ) -> ClientResult<crate::Response<crate::types::ListMembersData>> {
+        self.runtime.block_on(self.inner.put_members(
+            list_id,
+            subscriber_hash,
+            skip_merge_validation,
+            body,
+        ))
+    }
+    /// The blocking version of [`crate::lists::Lists::put_members_request`].
+    pub fn put_members_request<'a>(
+        &'a self,
+        list_id: &'a str,
+        subscriber_hash: &'a str,
+        body: &'a crate::types::AddListMembersData,
+    ) -> ListsPutMembersRequest<'a> {
+        ListsPutMembersRequest {
+            inner: self
+                .inner
+                .put_members_request(list_id, subscriber_hash, body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::lists::Lists::delete_members`].
+    pub fn delete_members(
+        &self,
+        list_id: &str,
+        subscriber_hash: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete_members(list_id, subscriber_hash))
+    }
+    /// The blocking version of [`crate::lists::Lists::patch_members`].
+    pub fn patch_members(
+        &self,
+        list_id: &str,
+        subscriber_hash: &str,
+        skip_merge_validation: bool,
+        body: &crate::types::AddListMembersDataType,
+    ) -> ClientResult<crate::Response<crate::types::ListMembersData>> {
+        self.runtime.block_on(self.inner.patch_members(
+            list_id,
+            subscriber_hash,
+            skip_merge_validation,
+            body,
+        ))
+    }
+    /// The blocking version of [`crate::lists::Lists::patch_members_request`].
+    pub fn patch_members_request<'a>(
+        &'a self,
+        list_id: &'a str,
+        subscriber_hash: &'a str,
+        body: &'a crate::types::AddListMembersDataType,
+    ) -> ListsPatchMembersRequest<'a> {
+        ListsPatchMembersRequest {
+            inner: self
+                .inner
+                .patch_members_request(list_id, subscriber_hash, body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::lists::Lists::get_members_activity`].
+    pub fn get_members_activity(
+        &self,
+        list_id: &str,
+        subscriber_hash: &str,
+        fields: &[String],
+        exclude_fields: &[String],
+        action: &[String],
+    ) -> ClientResult<crate::Response<crate::types::MemberActivityEvents>> {
+        self.runtime.block_on(self.inner.get_members_activity(
+            list_id,
+            subscriber_hash,
+            fields,
+            exclude_fields,
+            action,
+        ))
+    }
+    /// The blocking version of [`crate::lists::Lists::get_members_activity_request`].
+    pub fn get_members_activity_request<'a>(
+        &'a self,
+        list_id: &'a str,
+        subscriber_hash: &'a str,
+    ) -> ListsGetMembersActivityRequest<'a> {
+        ListsGetMembersActivityRequest {
+            inner: self
+                .inner
+                .get_members_activity_request(list_id, subscriber_hash),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::lists::Lists::get_members_activity_feed`].
+    pub fn get_members_activity_feed(
+        &self,
+        list_id: &str,
+        subscriber_hash: &str,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        activity_filters: &[String],
+    ) -> ClientResult<crate::Response<crate::types::MemberActivityEventsData>> {
+        self.runtime.block_on(self.inner.get_members_activity_feed(
+            list_id,
+            subscriber_hash,
+            fields,
+            exclude_fields,
+            count,
+            offset,
+            activity_filters,
+        ))
+    }
+    /// The blocking version of [`crate::lists::Lists::get_members_activity_feed_request`].
+    pub fn get_members_activity_feed_request<'a>(
+        &'a self,
+        list_id: &'a str,
+        subscriber_hash: &'a str,
+    ) -> ListsGetMembersActivityFeedRequest<'a> {
+        ListsGetMembersActivityFeedRequest {
+            inner: self
+                .inner
+                .get_members_activity_feed_request(list_id, subscriber_hash),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::lists::Lists::get_member_tag`].
+    pub fn get_member_tag(
+        &self,
+        list_id: &str,
+        subscriber_hash: &str,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+    ) -> ClientResult<crate::Response<crate::types::CollectionOfTags>> {
+        self.runtime.block_on(self.inner.get_member_tag(
+            list_id,
+            subscriber_hash,
+            fields,
+            exclude_fields,
+            count,
+            offset,
+        ))
+    }
+    /// The blocking version of [`crate::lists::Lists::get_member_tag_request`].
+    pub fn get_member_tag_request<'a>(
+        &'a self,
+        list_id: &'a str,
+        subscriber_hash: &'a str,
+    ) -> ListsGetMemberTagRequest<'a> {
+        ListsGetMemberTagRequest {
+            inner: self.inner.get_member_tag_request(list_id, subscriber_hash),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::lists::Lists::post_member_tag`].
+    pub fn post_member_tag(
+        &self,
+        list_id: &str,
+        subscriber_hash: &str,
+        body: &crate::types::MemberTags,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.post_member_tag(list_id, subscriber_hash, body))
+    }
+    /// The blocking version of [`crate::lists::Lists::get_members_event`].
+    pub fn get_members_event(
+        &self,
+        list_id: &str,
+        subscriber_hash: &str,
+        count: i64,
+        offset: i64,
+        fields: &[String],
+        exclude_fields: &[String],
+    ) -> ClientResult<crate::Response<crate::types::CollectionOfEvents>> {
+        self.runtime.block_on(self.inner.get_members_event(
+            list_id,
+            subscriber_hash,
+            count,
+            offset,
+            fields,
+            exclude_fields,
+        ))
+    }
+    /// The blocking version of [`crate::lists::Lists::get_members_event_request`].
+    pub fn get_members_event_request<'a>(
+        &'a self,
+        list_id: &'a str,
+        subscriber_hash: &'a str,
+    ) -> ListsGetMembersEventRequest<'a> {
+        ListsGetMembersEventRequest {
+            inner: self
+                .inner
+                .get_members_event_request(list_id, subscriber_hash),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::lists::Lists::post_member_event`].
+    pub fn post_member_event(
+        &self,
+        list_id: &str,
+        subscriber_hash: &str,
+        body: &crate::types::EventsData,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.post_member_event(list_id, subscriber_hash, body))
+    }
+    /// The blocking version of [`crate::lists::Lists::get_members_goal`].
+    pub fn get_members_goal(
+        &self,
+        list_id: &str,
+        subscriber_hash: &str,
+        fields: &[String],
+        exclude_fields: &[String],
+    ) -> ClientResult<crate::Response<crate::types::CollectionOfMemberActivityEvents>> {
+        self.runtime.block_on(self.inner.get_members_goal(
+            list_id,
+            subscriber_hash,
+            fields,
+            exclude_fields,
+        ))
+    }
+    /// The blocking version of [`crate::lists::Lists::get_members_goal_request`].
+    pub fn get_members_goal_request<'a>(
+        &'a self,
+        list_id: &'a str,
+        subscriber_hash: &'a str,
+    ) -> ListsGetMembersGoalRequest<'a> {
+        ListsGetMembersGoalRequest {
+            inner: self
+                .inner
+                .get_members_goal_request(list_id, subscriber_hash),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::lists::Lists::get_members_note`].
+    pub fn get_members_note(
+        &self,
+        list_id: &str,
+        subscriber_hash: &str,
+        sort_field: crate::types::GetListsMembersNotesSortField,
+        sort_dir: crate::types::SortDir,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+    ) -> ClientResult<crate::Response<crate::types::CollectionOfNotes>> {
+        self.runtime.block_on(self.inner.get_members_note(
+            list_id,
+            subscriber_hash,
+            sort_field,
+            sort_dir,
+            fields,
+            exclude_fields,
+            count,
+            offset,
+        ))
+    }
+    /// The blocking version of [`crate::lists::Lists::get_members_note_request`].
+    pub fn get_members_note_request<'a>(
+        &'a self,
+        list_id: &'a str,
+        subscriber_hash: &'a str,
+    ) -> ListsGetMembersNoteRequest<'a> {
+        ListsGetMembersNoteRequest {
+            inner: self
+                .inner
+                .get_members_note_request(list_id, subscriber_hash),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::lists::Lists::post_members_note`].
+    pub fn post_members_note(
+        &self,
+        list_id: &str,
+        subscriber_hash: &str,
+        body: &crate::types::MemberNotes,
+    ) -> ClientResult<crate::Response<crate::types::CollectionOfNotesMember>> {
+        self.runtime
+            .block_on(self.inner.post_members_note(list_id, subscriber_hash, body))
+    }
+    /// The blocking version of [`crate::lists::Lists::get_members_note_lists`].
+    pub fn get_members_note_lists(
+        &self,
+        list_id: &str,
+        subscriber_hash: &str,
+        note_id: &str,
+        fields: &[String],
+        exclude_fields: &[String],
+    ) -> ClientResult<crate::Response<crate::types::CollectionOfNotesMember>> {
+        self.runtime.block_on(self.inner.get_members_note_lists(
+            list_id,
+            subscriber_hash,
+            note_id,
+            fields,
+            exclude_fields,
+        ))
+    }
+    /// The blocking version of [`crate::lists::Lists::get_members_note_lists_request`].
+    pub fn get_members_note_lists_request<'a>(
+        &'a self,
+        list_id: &'a str,
+        subscriber_hash: &'a str,
+        note_id: &'a str,
+    ) -> ListsGetMembersNoteListsRequest<'a> {
+        ListsGetMembersNoteListsRequest {
+            inner: self
+                .inner
+                .get_members_note_lists_request(list_id, subscriber_hash, note_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::lists::Lists::delete_members_notes`].
+    pub fn delete_members_notes(
+        &self,
+        list_id: &str,
+        subscriber_hash: &str,
+        note_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .delete_members_notes(list_id, subscriber_hash, note_id),
+        )
+    }
+    /// The blocking version of [`crate::lists::Lists::patch_members_notes`].
+    pub fn patch_members_notes(
+        &self,
+        list_id: &str,
+        subscriber_hash: &str,
+        note_id: &str,
+        body: &crate::types::MemberNotes,
+    ) -> ClientResult<crate::Response<crate::types::CollectionOfNotesMember>> {
+        self.runtime.block_on(self.inner.patch_members_notes(
+            list_id,
+            subscriber_hash,
+            note_id,
+            body,
+        ))
+    }
+    /// The blocking version of [`crate::lists::Lists::post_members_hash_actions_delete_permanent`].
+    pub fn post_members_hash_actions_delete_permanent(
+        &self,
+        list_id: &str,
+        subscriber_hash: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .post_members_hash_actions_delete_permanent(list_id, subscriber_hash),
+        )
+    }
+    /// The blocking version of [`crate::lists::Lists::get_merge_field`].
+    pub fn get_merge_field(
+        &self,
+        list_id: &str,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        type_: &str,
+        required: bool,
+    ) -> ClientResult<crate::Response<crate::types::CollectionOfMergeFields>> {
+        self.runtime.block_on(self.inner.get_merge_field(
+            list_id,
+            fields,
+            exclude_fields,
+            count,
+            offset,
+            type_,
+            required,
+        ))
+    }
+    /// The blocking version of [`crate::lists::Lists::get_merge_field_request`].
+    pub fn get_merge_field_request<'a>(
+        &'a self,
+        list_id: &'a str,
+    ) -> ListsGetMergeFieldRequest<'a> {
+        ListsGetMergeFieldRequest {
+            inner: self.inner.get_merge_field_request(list_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::lists::Lists::post_merge_field`].
+    pub fn post_merge_field(
+        &self,
+        list_id: &str,
+        body: &crate::types::MergeFieldData,
+    ) -> ClientResult<crate::Response<crate::types::MergeField>> {
+        self.runtime
+            .block_on(self.inner.post_merge_field(list_id, body))
+    }
+    /// The blocking version of [`crate::lists::Lists::get_merge_field_lists`].
+    pub fn get_merge_field_lists(
+        &self,
+        list_id: &str,
+        merge_id: &str,
+        exclude_fields: &[String],
+        fields: &[String],
+    ) -> ClientResult<crate::Response<crate::types::MergeField>> {
+        self.runtime.block_on(self.inner.get_merge_field_lists(
+            list_id,
+            merge_id,
+            exclude_fields,
+            fields,
+        ))
+    }
+    /// The blocking version of [`crate::lists::Lists::get_merge_field_lists_request`].
+    pub fn get_merge_field_lists_request<'a>(
+        &'a self,
+        list_id: &'a str,
+        merge_id: &'a str,
+    ) -> ListsGetMergeFieldListsRequest<'a> {
+        ListsGetMergeFieldListsRequest {
+            inner: self.inner.get_merge_field_lists_request(list_id, merge_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::lists::Lists::delete_merge_fields`].
+    pub fn delete_merge_fields(
+        &self,
+        list_id: &str,
+        merge_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete_merge_fields(list_id, merge_id))
+    }
+    /// The blocking version of [`crate::lists::Lists::patch_merge_fields`].
+    pub fn patch_merge_fields(
+        &self,
+        list_id: &str,
+        merge_id: &str,
+        body: &crate::types::MergeFieldDataType,
+    ) -> ClientResult<crate::Response<crate::types::MergeField>> {
+        self.runtime
+            .block_on(self.inner.patch_merge_fields(list_id, merge_id, body))
+    }
+    /// The blocking version of [`crate::lists::Lists::get_webhook`].
+    pub fn get_webhook(
+        &self,
+        list_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ListWebhooksData>> {
+        self.runtime.block_on(self.inner.get_webhook(list_id))
+    }
+    /// The blocking version of [`crate::lists::Lists::post_webhook`].
+    pub fn post_webhook(
+        &self,
+        list_id: &str,
+        body: &crate::types::AddWebhook,
+    ) -> ClientResult<crate::Response<crate::types::ListWebhooks>> {
+        self.runtime
+            .block_on(self.inner.post_webhook(list_id, body))
+    }
+    /// The blocking version of [`crate::lists::Lists::get_webhook_lists`].
+    pub fn get_webhook_lists(
+        &self,
+        list_id: &str,
+        webhook_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ListWebhooks>> {
+        self.runtime
+            .block_on(self.inner.get_webhook_lists(list_id, webhook_id))
+    }
+    /// The blocking version of [`crate::lists::Lists::delete_webhooks`].
+    pub fn delete_webhooks(
+        &self,
+        list_id: &str,
+        webhook_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete_webhooks(list_id, webhook_id))
+    }
+    /// The blocking version of [`crate::lists::Lists::patch_webhooks`].
+    pub fn patch_webhooks(
+        &self,
+        list_id: &str,
+        webhook_id: &str,
+        body: &crate::types::AddWebhook,
+    ) -> ClientResult<crate::Response<crate::types::ListWebhooks>> {
+        self.runtime
+            .block_on(self.inner.patch_webhooks(list_id, webhook_id, body))
+    }
+    /// The blocking version of [`crate::lists::Lists::get_signup_form`].
+    pub fn get_signup_form(
+        &self,
+        list_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ListSignupForms>> {
+        self.runtime.block_on(self.inner.get_signup_form(list_id))
+    }
+    /// The blocking version of [`crate::lists::Lists::post_signup_form`].
+    pub fn post_signup_form(
+        &self,
+        list_id: &str,
+        body: &crate::types::SignupFormData,
+    ) -> ClientResult<crate::Response<crate::types::SignupForm>> {
+        self.runtime
+            .block_on(self.inner.post_signup_form(list_id, body))
+    }
+    /// The blocking version of [`crate::lists::Lists::get_location`].
+    pub fn get_location(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        list_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ListLocations>> {
+        self.runtime
+            .block_on(self.inner.get_location(fields, exclude_fields, list_id))
+    }
+    /// The blocking version of [`crate::lists::Lists::get_location_request`].
+    pub fn get_location_request<'a>(&'a self, list_id: &'a str) -> ListsGetLocationRequest<'a> {
+        ListsGetLocationRequest {
+            inner: self.inner.get_location_request(list_id),
+            runtime: &self.runtime,
+        }
+    }
+}
@@ -0,0 +1,187 @@
+//! A blocking client, for the programs that are not async.
+//!
+//! It has the functions of the async [`Client`](crate::Client), which it runs on a
+//! runtime of its own, so they must not be called from within an async runtime. The
+//! `*_stream` functions are left out, the `*_all` ones returning all the pages at once.
+
+use std::{future::Future, sync::Arc};
+
+pub mod activity_feed;
+pub mod authorized_apps;
+pub mod automations;
+pub mod batch_webhooks;
+pub mod batches;
+pub mod campaign_folders;
+pub mod campaigns;
+pub mod connected_sites;
+pub mod conversations;
+pub mod customer_journeys;
+pub mod ecommerce;
+pub mod facebook_ads;
+pub mod file_manager;
+pub mod landing_pages;
+pub mod lists;
+pub mod ping;
+pub mod reporting;
+pub mod reports;
+pub mod root;
+pub mod search_campaigns;
+pub mod search_members;
+pub mod template_folders;
+pub mod templates;
+pub mod verified_domains;
+
+/// A blocking client, sending its requests with an async [`Client`](crate::Client).
+#[derive(Clone)]
+pub struct Client {
+    inner: crate::Client,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Client {
+    /// Wraps an async client, creating the runtime its requests run on.
+    pub fn new(client: crate::Client) -> std::io::Result<Self> {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()?;
+
+        Ok(Client {
+            inner: client,
+            runtime: Arc::new(runtime),
+        })
+    }
+
+    /// Returns the async client the requests are sent with.
+    pub fn inner(&self) -> &crate::Client {
+        &self.inner
+    }
+
+    /// Runs a future to completion on the runtime of the client, for the async
+    /// functions that have no blocking version.
+    pub fn block_on<F: Future>(&self, future: F) -> F::Output {
+        self.runtime.block_on(future)
+    }
+
+    /// The blocking version of [`crate::Client::activity_feed`].
+    pub fn activity_feed(&self) -> activity_feed::ActivityFeed {
+        activity_feed::ActivityFeed::new(self.inner.activity_feed(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::authorized_apps`].
+    pub fn authorized_apps(&self) -> authorized_apps::AuthorizedApps {
+        authorized_apps::AuthorizedApps::new(self.inner.authorized_apps(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::automations`].
+    pub fn automations(&self) -> automations::Automations {
+        automations::Automations::new(self.inner.automations(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::batch_webhooks`].
+    pub fn batch_webhooks(&self) -> batch_webhooks::BatchWebhooks {
+        batch_webhooks::BatchWebhooks::new(self.inner.batch_webhooks(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::batches`].
+    pub fn batches(&self) -> batches::Batches {
+        batches::Batches::new(self.inner.batches(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::campaign_folders`].
+    pub fn campaign_folders(&self) -> campaign_folders::CampaignFolders {
+        campaign_folders::CampaignFolders::new(self.inner.campaign_folders(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::campaigns`].
+    pub fn campaigns(&self) -> campaigns::Campaigns {
+        campaigns::Campaigns::new(self.inner.campaigns(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::connected_sites`].
+    pub fn connected_sites(&self) -> connected_sites::ConnectedSites {
+        connected_sites::ConnectedSites::new(self.inner.connected_sites(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::conversations`].
+    pub fn conversations(&self) -> conversations::Conversations {
+        conversations::Conversations::new(self.inner.conversations(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::customer_journeys`].
+    pub fn customer_journeys(&self) -> customer_journeys::CustomerJourneys {
+        customer_journeys::CustomerJourneys::new(
+            self.inner.customer_journeys(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::ecommerce`].
+    pub fn ecommerce(&self) -> ecommerce::Ecommerce {
+        ecommerce::Ecommerce::new(self.inner.ecommerce(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::facebook_ads`].
+    pub fn facebook_ads(&self) -> facebook_ads::FacebookAds {
+        facebook_ads::FacebookAds::new(self.inner.facebook_ads(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::file_manager`].
+    pub fn file_manager(&self) -> file_manager::FileManager {
+        file_manager::FileManager::new(self.inner.file_manager(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::landing_pages`].
+    pub fn landing_pages(&self) -> landing_pages::LandingPages {
+        landing_pages::LandingPages::new(self.inner.landing_pages(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::lists`].
+    pub fn lists(&self) -> lists::Lists {
+        lists::Lists::new(self.inner.lists(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::ping`].
+    pub fn ping(&self) -> ping::Ping {
+        ping::Ping::new(self.inner.ping(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::reporting`].
+    pub fn reporting(&self) -> reporting::Reporting {
+        reporting::Reporting::new(self.inner.reporting(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::reports`].
+    pub fn reports(&self) -> reports::Reports {
+        reports::Reports::new(self.inner.reports(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::root`].
+    pub fn root(&self) -> root::Root {
+        root::Root::new(self.inner.root(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::search_campaigns`].
+    pub fn search_campaigns(&self) -> search_campaigns::SearchCampaigns {
+        search_campaigns::SearchCampaigns::new(self.inner.search_campaigns(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::search_members`].
+    pub fn search_members(&self) -> search_members::SearchMembers {
+        search_members::SearchMembers::new(self.inner.search_members(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::template_folders`].
+    pub fn template_folders(&self) -> template_folders::TemplateFolders {
+        template_folders::TemplateFolders::new(self.inner.template_folders(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::templates`].
+    pub fn templates(&self) -> templates::Templates {
+        templates::Templates::new(self.inner.templates(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::verified_domains`].
+    pub fn verified_domains(&self) -> verified_domains::VerifiedDomains {
+        verified_domains::VerifiedDomains::new(self.inner.verified_domains(), self.runtime.clone())
+    }
+}
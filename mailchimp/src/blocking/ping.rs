@@ -0,0 +1,20 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::ping::Ping`].
+pub struct Ping {
+    inner: crate::ping::Ping,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Ping {
+    pub(crate) fn new(inner: crate::ping::Ping, runtime: Arc<tokio::runtime::Runtime>) -> Self {
+        Ping { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::ping::Ping::get`].
+    pub fn get(&self) -> ClientResult<crate::Response<crate::types::ApiHealthStatus>> {
+        self.runtime.block_on(self.inner.get())
+    }
+}
@@ -0,0 +1,333 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Reporting::get_facebook_ads`], set with a builder.
+pub struct ReportingGetFacebookAdsRequest<'a> {
+    inner: crate::reporting::ReportingGetFacebookAdsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ReportingGetFacebookAdsRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.inner = self.inner.count(count);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.inner = self.inner.offset(offset);
+        self
+    }
+
+    /// Sets the `sort_field` query parameter.
+    pub fn sort_field(mut self, sort_field: crate::types::GetAllFacebookAdsSortField) -> Self {
+        self.inner = self.inner.sort_field(sort_field);
+        self
+    }
+
+    /// Sets the `sort_dir` query parameter.
+    pub fn sort_dir(mut self, sort_dir: crate::types::SortDir) -> Self {
+        self.inner = self.inner.sort_dir(sort_dir);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::GetReportingFacebookAdsResponse>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Reporting::get_facebook_ad`], set with a builder.
+pub struct ReportingGetFacebookAdRequest<'a> {
+    inner: crate::reporting::ReportingGetFacebookAdRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ReportingGetFacebookAdRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::GetReportingFacebookAdsResponseAllOf>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Reporting::get_facebook_ads_ecommerce_product_activity`], set with a builder.
+pub struct ReportingGetFacebookAdsEcommerceProductActivityRequest<'a> {
+    inner: crate::reporting::ReportingGetFacebookAdsEcommerceProductActivityRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ReportingGetFacebookAdsEcommerceProductActivityRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.inner = self.inner.count(count);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.inner = self.inner.offset(offset);
+        self
+    }
+
+    /// Sets the `sort_field` query parameter.
+    pub fn sort_field(
+        mut self,
+        sort_field: crate::types::GetReportsEcommerceProductActivitySortField,
+    ) -> Self {
+        self.inner = self.inner.sort_field(sort_field);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::GetReportsEcommerceProductActivityResponse>>
+    {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Reporting::get_landing_page`], set with a builder.
+pub struct ReportingGetLandingPageRequest<'a> {
+    inner: crate::reporting::ReportingGetLandingPageRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ReportingGetLandingPageRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::LandingPages>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Reporting::get_landing_pages`], set with a builder.
+pub struct ReportingGetLandingPagesRequest<'a> {
+    inner: crate::reporting::ReportingGetLandingPagesRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ReportingGetLandingPagesRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.inner = self.inner.count(count);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.inner = self.inner.offset(offset);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::GetReportingLandingPagesResponse>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::reporting::Reporting`].
+pub struct Reporting {
+    inner: crate::reporting::Reporting,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Reporting {
+    pub(crate) fn new(
+        inner: crate::reporting::Reporting,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Reporting { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::reporting::Reporting::get_facebook_ads`].
+    pub fn get_facebook_ads(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        sort_field: crate::types::GetAllFacebookAdsSortField,
+        sort_dir: crate::types::SortDir,
+    ) -> ClientResult<crate::Response<crate::types::GetReportingFacebookAdsResponse>> {
+        self.runtime.block_on(self.inner.get_facebook_ads(
+            fields,
+            exclude_fields,
+            count,
+            offset,
+            sort_field,
+            sort_dir,
+        ))
+    }
+    /// The blocking version of [`crate::reporting::Reporting::get_facebook_ads_request`].
+    pub fn get_facebook_ads_request(&self) -> ReportingGetFacebookAdsRequest<'_> {
+        ReportingGetFacebookAdsRequest {
+            inner: self.inner.get_facebook_ads_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::reporting::Reporting::get_facebook_ad`].
+    pub fn get_facebook_ad(
+        &self,
+        fields: &[String],
+        outreach_id: &str,
+        exclude_fields: &[String],
+    ) -> ClientResult<crate::Response<crate::types::GetReportingFacebookAdsResponseAllOf>> {
+        self.runtime.block_on(
+            self.inner
+                .get_facebook_ad(fields, outreach_id, exclude_fields),
+        )
+    }
+    /// The blocking version of [`crate::reporting::Reporting::get_facebook_ad_request`].
+    pub fn get_facebook_ad_request<'a>(
+        &'a self,
+        outreach_id: &'a str,
+    ) -> ReportingGetFacebookAdRequest<'a> {
+        ReportingGetFacebookAdRequest {
+            inner: self.inner.get_facebook_ad_request(outreach_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::reporting::Reporting::get_facebook_ads_ecommerce_product_activity`].
+    pub fn get_facebook_ads_ecommerce_product_activity(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        outreach_id: &str,
+        sort_field: crate::types::GetReportsEcommerceProductActivitySortField,
+    ) -> ClientResult<crate::Response<crate::types::GetReportsEcommerceProductActivityResponse>>
+    {
+        self.runtime
+            .block_on(self.inner.get_facebook_ads_ecommerce_product_activity(
+                fields,
+                exclude_fields,
+                count,
+                offset,
+                outreach_id,
+                sort_field,
+            ))
+    }
+    /// The blocking version of [`crate::reporting::Reporting::get_facebook_ads_ecommerce_product_activity_request`].
+    pub fn get_facebook_ads_ecommerce_product_activity_request<'a>(
+        &'a self,
+        outreach_id: &'a str,
+    ) -> ReportingGetFacebookAdsEcommerceProductActivityRequest<'a> {
+        ReportingGetFacebookAdsEcommerceProductActivityRequest {
+            inner: self
+                .inner
+                .get_facebook_ads_ecommerce_product_activity_request(outreach_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::reporting::Reporting::get_landing_page`].
+    pub fn get_landing_page(
+        &self,
+        fields: &[String],
+        outreach_id: &str,
+        exclude_fields: &[String],
+    ) -> ClientResult<crate::Response<crate::types::LandingPages>> {
+        self.runtime.block_on(
+            self.inner
+                .get_landing_page(fields, outreach_id, exclude_fields),
+        )
+    }
+    /// The blocking version of [`crate::reporting::Reporting::get_landing_page_request`].
+    pub fn get_landing_page_request<'a>(
+        &'a self,
+        outreach_id: &'a str,
+    ) -> ReportingGetLandingPageRequest<'a> {
+        ReportingGetLandingPageRequest {
+            inner: self.inner.get_landing_page_request(outreach_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::reporting::Reporting::get_landing_pages`].
+    pub fn get_landing_pages(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+    ) -> ClientResult<crate::Response<crate::types::GetReportingLandingPagesResponse>> {
+        self.runtime.block_on(
+            self.inner
+                .get_landing_pages(fields, exclude_fields, count, offset),
+        )
+    }
+    /// The blocking version of [`crate::reporting::Reporting::get_landing_pages_request`].
+    pub fn get_landing_pages_request(&self) -> ReportingGetLandingPagesRequest<'_> {
+        ReportingGetLandingPagesRequest {
+            inner: self.inner.get_landing_pages_request(),
+            runtime: &self.runtime,
+        }
+    }
+}
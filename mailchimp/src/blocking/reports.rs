@@ -0,0 +1,1291 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Reports::get`], set with a builder.
+pub struct ReportsGetRequest<'a> {
+    inner: crate::reports::ReportsGetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ReportsGetRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.inner = self.inner.count(count);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.inner = self.inner.offset(offset);
+        self
+    }
+
+    /// Sets the `type` query parameter.
+    pub fn type_(mut self, type_: crate::types::CampaignType) -> Self {
+        self.inner = self.inner.type_(type_);
+        self
+    }
+
+    /// Sets the `before_send_time` query parameter.
+    pub fn before_send_time(mut self, before_send_time: chrono::DateTime<chrono::Utc>) -> Self {
+        self.inner = self.inner.before_send_time(before_send_time);
+        self
+    }
+
+    /// Sets the `since_send_time` query parameter.
+    pub fn since_send_time(mut self, since_send_time: chrono::DateTime<chrono::Utc>) -> Self {
+        self.inner = self.inner.since_send_time(since_send_time);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::CampaignReportsData>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Reports::get_reports`], set with a builder.
+pub struct ReportsGetReportsRequest<'a> {
+    inner: crate::reports::ReportsGetReportsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ReportsGetReportsRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::Reports>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Reports::get_abuse`], set with a builder.
+pub struct ReportsGetAbuseRequest<'a> {
+    inner: crate::reports::ReportsGetAbuseRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ReportsGetAbuseRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::AbuseComplaintsData>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Reports::get_abuse_reports`], set with a builder.
+pub struct ReportsGetAbuseReportsRequest<'a> {
+    inner: crate::reports::ReportsGetAbuseReportsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ReportsGetAbuseReportsRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::AbuseComplaint>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Reports::get_advice`], set with a builder.
+pub struct ReportsGetAdviceRequest<'a> {
+    inner: crate::reports::ReportsGetAdviceRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ReportsGetAdviceRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::CampaignAdviceReport>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Reports::get_click_detail`], set with a builder.
+pub struct ReportsGetClickDetailRequest<'a> {
+    inner: crate::reports::ReportsGetClickDetailRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ReportsGetClickDetailRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.inner = self.inner.count(count);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.inner = self.inner.offset(offset);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::ClickDetailReport>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Reports::get_click_detail_reports`], set with a builder.
+pub struct ReportsGetClickDetailReportsRequest<'a> {
+    inner: crate::reports::ReportsGetClickDetailReportsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ReportsGetClickDetailReportsRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::UrlsClicked>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Reports::get_click_details_member`], set with a builder.
+pub struct ReportsGetClickDetailsMemberRequest<'a> {
+    inner: crate::reports::ReportsGetClickDetailsMemberRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ReportsGetClickDetailsMemberRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.inner = self.inner.count(count);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.inner = self.inner.offset(offset);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::ClickDetailMembers>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Reports::get_click_details_member_reports`], set with a builder.
+pub struct ReportsGetClickDetailsMemberReportsRequest<'a> {
+    inner: crate::reports::ReportsGetClickDetailsMemberReportsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ReportsGetClickDetailsMemberReportsRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::ClickDetailMember>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Reports::get_open_detail`], set with a builder.
+pub struct ReportsGetOpenDetailRequest<'a> {
+    inner: crate::reports::ReportsGetOpenDetailRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ReportsGetOpenDetailRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.inner = self.inner.count(count);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.inner = self.inner.offset(offset);
+        self
+    }
+
+    /// Sets the `since` query parameter.
+    pub fn since(mut self, since: &'a str) -> Self {
+        self.inner = self.inner.since(since);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::OpenDetailReport>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Reports::get_open_details_member`], set with a builder.
+pub struct ReportsGetOpenDetailsMemberRequest<'a> {
+    inner: crate::reports::ReportsGetOpenDetailsMemberRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ReportsGetOpenDetailsMemberRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::OpenActivity>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Reports::get_domain_performance`], set with a builder.
+pub struct ReportsGetDomainPerformanceRequest<'a> {
+    inner: crate::reports::ReportsGetDomainPerformanceRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ReportsGetDomainPerformanceRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::DomainPerformance>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Reports::get_eepurl`], set with a builder.
+pub struct ReportsGetEepurlRequest<'a> {
+    inner: crate::reports::ReportsGetEepurlRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ReportsGetEepurlRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::EepurlActivity>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Reports::get_email_activity`], set with a builder.
+pub struct ReportsGetEmailActivityRequest<'a> {
+    inner: crate::reports::ReportsGetEmailActivityRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ReportsGetEmailActivityRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.inner = self.inner.count(count);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.inner = self.inner.offset(offset);
+        self
+    }
+
+    /// Sets the `since` query parameter.
+    pub fn since(mut self, since: &'a str) -> Self {
+        self.inner = self.inner.since(since);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::EmailActivityData>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Reports::get_email_activity_reports`], set with a builder.
+pub struct ReportsGetEmailActivityReportsRequest<'a> {
+    inner: crate::reports::ReportsGetEmailActivityReportsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ReportsGetEmailActivityReportsRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sets the `since` query parameter.
+    pub fn since(mut self, since: &'a str) -> Self {
+        self.inner = self.inner.since(since);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::EmailActivity>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Reports::get_location`], set with a builder.
+pub struct ReportsGetLocationRequest<'a> {
+    inner: crate::reports::ReportsGetLocationRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ReportsGetLocationRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.inner = self.inner.count(count);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.inner = self.inner.offset(offset);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::OpenLocationsData>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Reports::get_sent`], set with a builder.
+pub struct ReportsGetSentRequest<'a> {
+    inner: crate::reports::ReportsGetSentRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ReportsGetSentRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.inner = self.inner.count(count);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.inner = self.inner.offset(offset);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::SentData>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Reports::get_sent_reports`], set with a builder.
+pub struct ReportsGetSentReportsRequest<'a> {
+    inner: crate::reports::ReportsGetSentReportsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ReportsGetSentReportsRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::SentTo>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Reports::get_sub`], set with a builder.
+pub struct ReportsGetSubRequest<'a> {
+    inner: crate::reports::ReportsGetSubRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ReportsGetSubRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::CampaignSubReports>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Reports::get_unsubscribed`], set with a builder.
+pub struct ReportsGetUnsubscribedRequest<'a> {
+    inner: crate::reports::ReportsGetUnsubscribedRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ReportsGetUnsubscribedRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.inner = self.inner.count(count);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.inner = self.inner.offset(offset);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::UnsubscribesData>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Reports::get_unsubscribed_reports`], set with a builder.
+pub struct ReportsGetUnsubscribedReportsRequest<'a> {
+    inner: crate::reports::ReportsGetUnsubscribedReportsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ReportsGetUnsubscribedReportsRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::Unsubscribes>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Reports::get_ecommerce_product_activity`], set with a builder.
+pub struct ReportsGetEcommerceProductActivityRequest<'a> {
+    inner: crate::reports::ReportsGetEcommerceProductActivityRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ReportsGetEcommerceProductActivityRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.inner = self.inner.count(count);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.inner = self.inner.offset(offset);
+        self
+    }
+
+    /// Sets the `sort_field` query parameter.
+    pub fn sort_field(
+        mut self,
+        sort_field: crate::types::GetReportsEcommerceProductActivitySortField,
+    ) -> Self {
+        self.inner = self.inner.sort_field(sort_field);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::GetReportsEcommerceProductActivityResponse>>
+    {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::reports::Reports`].
+pub struct Reports {
+    inner: crate::reports::Reports,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Reports {
+    pub(crate) fn new(
+        inner: crate::reports::Reports,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Reports { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::reports::Reports::get`].
+    pub fn get(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        type_: crate::types::CampaignType,
+        before_send_time: Option<chrono::DateTime<chrono::Utc>>,
+        since_send_time: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> ClientResult<crate::Response<crate::types::CampaignReportsData>> {
+        self.runtime.block_on(self.inner.get(
+            fields,
+            exclude_fields,
+            count,
+            offset,
+            type_,
+            before_send_time,
+            since_send_time,
+        ))
+    }
+    /// The blocking version of [`crate::reports::Reports::get_request`].
+    pub fn get_request(&self) -> ReportsGetRequest<'_> {
+        ReportsGetRequest {
+            inner: self.inner.get_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::reports::Reports::get_reports`].
+    pub fn get_reports(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        campaign_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Reports>> {
+        self.runtime
+            .block_on(self.inner.get_reports(fields, exclude_fields, campaign_id))
+    }
+    /// The blocking version of [`crate::reports::Reports::get_reports_request`].
+    pub fn get_reports_request<'a>(&'a self, campaign_id: &'a str) -> ReportsGetReportsRequest<'a> {
+        ReportsGetReportsRequest {
+            inner: self.inner.get_reports_request(campaign_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::reports::Reports::get_abuse`].
+    pub fn get_abuse(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        campaign_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::AbuseComplaintsData>> {
+        self.runtime
+            .block_on(self.inner.get_abuse(fields, exclude_fields, campaign_id))
+    }
+    /// The blocking version of [`crate::reports::Reports::get_abuse_request`].
+    pub fn get_abuse_request<'a>(&'a self, campaign_id: &'a str) -> ReportsGetAbuseRequest<'a> {
+        ReportsGetAbuseRequest {
+            inner: self.inner.get_abuse_request(campaign_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::reports::Reports::get_abuse_reports`].
+    pub fn get_abuse_reports(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        campaign_id: &str,
+        report_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::AbuseComplaint>> {
+        self.runtime.block_on(self.inner.get_abuse_reports(
+            fields,
+            exclude_fields,
+            campaign_id,
+            report_id,
+        ))
+    }
+    /// The blocking version of [`crate::reports::Reports::get_abuse_reports_request`].
+    pub fn get_abuse_reports_request<'a>(
+        &'a self,
+        campaign_id: &'a str,
+        report_id: &'a str,
+    ) -> ReportsGetAbuseReportsRequest<'a> {
+        ReportsGetAbuseReportsRequest {
+            inner: self.inner.get_abuse_reports_request(campaign_id, report_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::reports::Reports::get_advice`].
+    pub fn get_advice(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        campaign_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::CampaignAdviceReport>> {
+        self.runtime
+            .block_on(self.inner.get_advice(fields, exclude_fields, campaign_id))
+    }
+    /// The blocking version of [`crate::reports::Reports::get_advice_request`].
+    pub fn get_advice_request<'a>(&'a self, campaign_id: &'a str) -> ReportsGetAdviceRequest<'a> {
+        ReportsGetAdviceRequest {
+            inner: self.inner.get_advice_request(campaign_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::reports::Reports::get_click_detail`].
+    pub fn get_click_detail(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        campaign_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ClickDetailReport>> {
+        self.runtime.block_on(self.inner.get_click_detail(
+            fields,
+            exclude_fields,
+            count,
+            offset,
+            campaign_id,
+        ))
+    }
+    /// The blocking version of [`crate::reports::Reports::get_click_detail_request`].
+    pub fn get_click_detail_request<'a>(
+        &'a self,
+        campaign_id: &'a str,
+    ) -> ReportsGetClickDetailRequest<'a> {
+        ReportsGetClickDetailRequest {
+            inner: self.inner.get_click_detail_request(campaign_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::reports::Reports::get_click_detail_reports`].
+    pub fn get_click_detail_reports(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        campaign_id: &str,
+        link_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::UrlsClicked>> {
+        self.runtime.block_on(self.inner.get_click_detail_reports(
+            fields,
+            exclude_fields,
+            campaign_id,
+            link_id,
+        ))
+    }
+    /// The blocking version of [`crate::reports::Reports::get_click_detail_reports_request`].
+    pub fn get_click_detail_reports_request<'a>(
+        &'a self,
+        campaign_id: &'a str,
+        link_id: &'a str,
+    ) -> ReportsGetClickDetailReportsRequest<'a> {
+        ReportsGetClickDetailReportsRequest {
+            inner: self
+                .inner
+                .get_click_detail_reports_request(campaign_id, link_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::reports::Reports::get_click_details_member`].
+    pub fn get_click_details_member(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        campaign_id: &str,
+        link_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ClickDetailMembers>> {
+        self.runtime.block_on(self.inner.get_click_details_member(
+            fields,
+            exclude_fields,
+            count,
+            offset,
+            campaign_id,
+            link_id,
+        ))
+    }
+    /// The blocking version of [`crate::reports::Reports::get_click_details_member_request`].
+    pub fn get_click_details_member_request<'a>(
+        &'a self,
+        campaign_id: &'a str,
+        link_id: &'a str,
+    ) -> ReportsGetClickDetailsMemberRequest<'a> {
+        ReportsGetClickDetailsMemberRequest {
+            inner: self
+                .inner
+                .get_click_details_member_request(campaign_id, link_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::reports::Reports::get_click_details_member_reports`].
+    pub fn get_click_details_member_reports(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        campaign_id: &str,
+        link_id: &str,
+        subscriber_hash: &str,
+    ) -> ClientResult<crate::Response<crate::types::ClickDetailMember>> {
+        self.runtime
+            .block_on(self.inner.get_click_details_member_reports(
+                fields,
+                exclude_fields,
+                campaign_id,
+                link_id,
+                subscriber_hash,
+            ))
+    }
+    /// The blocking version of [`crate::reports::Reports::get_click_details_member_reports_request`].
+    pub fn get_click_details_member_reports_request<'a>(
+        &'a self,
+        campaign_id: &'a str,
+        link_id: &'a str,
+        subscriber_hash: &'a str,
+    ) -> ReportsGetClickDetailsMemberReportsRequest<'a> {
+        ReportsGetClickDetailsMemberReportsRequest {
+            inner: self.inner.get_click_details_member_reports_request(
+                campaign_id,
+                link_id,
+                subscriber_hash,
+            ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::reports::Reports::get_open_detail`].
+    pub fn get_open_detail(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        campaign_id: &str,
+        since: &str,
+    ) -> ClientResult<crate::Response<crate::types::OpenDetailReport>> {
+        self.runtime.block_on(self.inner.get_open_detail(
+            fields,
+            exclude_fields,
+            count,
+            offset,
+            campaign_id,
+            since,
+        ))
+    }
+    /// The blocking version of [`crate::reports::Reports::get_open_detail_request`].
+    pub fn get_open_detail_request<'a>(
+        &'a self,
+        campaign_id: &'a str,
+    ) -> ReportsGetOpenDetailRequest<'a> {
+        ReportsGetOpenDetailRequest {
+            inner: self.inner.get_open_detail_request(campaign_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::reports::Reports::get_open_details_member`].
+    pub fn get_open_details_member(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        campaign_id: &str,
+        subscriber_hash: &str,
+    ) -> ClientResult<crate::Response<crate::types::OpenActivity>> {
+        self.runtime.block_on(self.inner.get_open_details_member(
+            fields,
+            exclude_fields,
+            campaign_id,
+            subscriber_hash,
+        ))
+    }
+    /// The blocking version of [`crate::reports::Reports::get_open_details_member_request`].
+    pub fn get_open_details_member_request<'a>(
+        &'a self,
+        campaign_id: &'a str,
+        subscriber_hash: &'a str,
+    ) -> ReportsGetOpenDetailsMemberRequest<'a> {
+        ReportsGetOpenDetailsMemberRequest {
+            inner: self
+                .inner
+                .get_open_details_member_request(campaign_id, subscriber_hash),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::reports::Reports::get_domain_performance`].
+    pub fn get_domain_performance(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        campaign_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::DomainPerformance>> {
+        self.runtime.block_on(self.inner.get_domain_performance(
+            fields,
+            exclude_fields,
+            campaign_id,
+        ))
+    }
+    /// The blocking version of [`crate::reports::Reports::get_domain_performance_request`].
+    pub fn get_domain_performance_request<'a>(
+        &'a self,
+        campaign_id: &'a str,
+    ) -> ReportsGetDomainPerformanceRequest<'a> {
+        ReportsGetDomainPerformanceRequest {
+            inner: self.inner.get_domain_performance_request(campaign_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::reports::Reports::get_eepurl`].
+    pub fn get_eepurl(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        campaign_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::EepurlActivity>> {
+        self.runtime
+            .block_on(self.inner.get_eepurl(fields, exclude_fields, campaign_id))
+    }
+    /// The blocking version of [`crate::reports::Reports::get_eepurl_request`].
+    pub fn get_eepurl_request<'a>(&'a self, campaign_id: &'a str) -> ReportsGetEepurlRequest<'a> {
+        ReportsGetEepurlRequest {
+            inner: self.inner.get_eepurl_request(campaign_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::reports::Reports::get_email_activity`].
+    pub fn get_email_activity(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        campaign_id: &str,
+        since: &str,
+    ) -> ClientResult<crate::Response<crate::types::EmailActivityData>> {
+        self.runtime.block_on(self.inner.get_email_activity(
+            fields,
+            exclude_fields,
+            count,
+            offset,
+            campaign_id,
+            since,
+        ))
+    }
+    /// The blocking version of [`crate::reports::Reports::get_email_activity_request`].
+    pub fn get_email_activity_request<'a>(
+        &'a self,
+        campaign_id: &'a str,
+    ) -> ReportsGetEmailActivityRequest<'a> {
+        ReportsGetEmailActivityRequest {
+            inner: self.inner.get_email_activity_request(campaign_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::reports::Reports::get_email_activity_reports`].
+    pub fn get_email_activity_reports(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        campaign_id: &str,
+        subscriber_hash: &str,
+        since: &str,
+    ) -> ClientResult<crate::Response<crate::types::EmailActivity>> {
+        self.runtime.block_on(self.inner.get_email_activity_reports(
+            fields,
+            exclude_fields,
+            campaign_id,
+            subscriber_hash,
+            since,
+        ))
+    }
+    /// The blocking version of [`crate::reports::Reports::get_email_activity_reports_request`].
+    pub fn get_email_activity_reports_request<'a>(
+        &'a self,
+        campaign_id: &'a str,
+        subscriber_hash: &'a str,
+    ) -> ReportsGetEmailActivityReportsRequest<'a> {
+        ReportsGetEmailActivityReportsRequest {
+            inner: self
+                .inner
+                .get_email_activity_reports_request(campaign_id, subscriber_hash),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::reports::Reports::get_location`].
+    pub fn get_location(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        campaign_id: &str,
+        count: i64,
+        offset: i64,
+    ) -> ClientResult<crate::Response<crate::types::OpenLocationsData>> {
+        self.runtime.block_on(self.inner.get_location(
+            fields,
+            exclude_fields,
+            campaign_id,
+            count,
+            offset,
+        ))
+    }
+    /// The blocking version of [`crate::reports::Reports::get_location_request`].
+    pub fn get_location_request<'a>(
+        &'a self,
+        campaign_id: &'a str,
+    ) -> ReportsGetLocationRequest<'a> {
+        ReportsGetLocationRequest {
+            inner: self.inner.get_location_request(campaign_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::reports::Reports::get_sent`].
+    pub fn get_sent(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        campaign_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::SentData>> {
+        self.runtime.block_on(self.inner.get_sent(
+            fields,
+            exclude_fields,
+            count,
+            offset,
+            campaign_id,
+        ))
+    }
+    /// The blocking version of [`crate::reports::Reports::get_sent_request`].
+    pub fn get_sent_request<'a>(&'a self, campaign_id: &'a str) -> ReportsGetSentRequest<'a> {
+        ReportsGetSentRequest {
+            inner: self.inner.get_sent_request(campaign_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::reports::Reports::get_sent_reports`].
+    pub fn get_sent_reports(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        campaign_id: &str,
+        subscriber_hash: &str,
+    ) -> ClientResult<crate::Response<crate::types::SentTo>> {
+        self.runtime.block_on(self.inner.get_sent_reports(
+            fields,
+            exclude_fields,
+            campaign_id,
+            subscriber_hash,
+        ))
+    }
+    /// The blocking version of [`crate::reports::Reports::get_sent_reports_request`].
+    pub fn get_sent_reports_request<'a>(
+        &'a self,
+        campaign_id: &'a str,
+        subscriber_hash: &'a str,
+    ) -> ReportsGetSentReportsRequest<'a> {
+        ReportsGetSentReportsRequest {
+            inner: self
+                .inner
+                .get_sent_reports_request(campaign_id, subscriber_hash),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::reports::Reports::get_sub`].
+    pub fn get_sub(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        campaign_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::CampaignSubReports>> {
+        self.runtime
+            .block_on(self.inner.get_sub(fields, exclude_fields, campaign_id))
+    }
+    /// The blocking version of [`crate::reports::Reports::get_sub_request`].
+    pub fn get_sub_request<'a>(&'a self, campaign_id: &'a str) -> ReportsGetSubRequest<'a> {
+        ReportsGetSubRequest {
+            inner: self.inner.get_sub_request(campaign_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::reports::Reports::get_unsubscribed`].
+    pub fn get_unsubscribed(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        campaign_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::UnsubscribesData>> {
+        self.runtime.block_on(self.inner.get_unsubscribed(
+            fields,
+            exclude_fields,
+            count,
+            offset,
+            campaign_id,
+        ))
+    }
+    /// The blocking version of [`crate::reports::Reports::get_unsubscribed_request`].
+    pub fn get_unsubscribed_request<'a>(
+        &'a self,
+        campaign_id: &'a str,
+    ) -> ReportsGetUnsubscribedRequest<'a> {
+        ReportsGetUnsubscribedRequest {
+            inner: self.inner.get_unsubscribed_request(campaign_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::reports::Reports::get_unsubscribed_reports`].
+    pub fn get_unsubscribed_reports(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        campaign_id: &str,
+        subscriber_hash: &str,
+    ) -> ClientResult<crate::Response<crate::types::Unsubscribes>> {
+        self.runtime.block_on(self.inner.get_unsubscribed_reports(
+            fields,
+            exclude_fields,
+            campaign_id,
+            subscriber_hash,
+        ))
+    }
+    /// The blocking version of [`crate::reports::Reports::get_unsubscribed_reports_request`].
+    pub fn get_unsubscribed_reports_request<'a>(
+        &'a self,
+        campaign_id: &'a str,
+        subscriber_hash: &'a str,
+    ) -> ReportsGetUnsubscribedReportsRequest<'a> {
+        ReportsGetUnsubscribedReportsRequest {
+            inner: self
+                .inner
+                .get_unsubscribed_reports_request(campaign_id, subscriber_hash),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::reports::Reports::get_ecommerce_product_activity`].
+    pub fn get_ecommerce_product_activity(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        campaign_id: &str,
+        sort_field: crate::types::GetReportsEcommerceProductActivitySortField,
+    ) -> ClientResult<crate::Response<crate::types::GetReportsEcommerceProductActivityResponse>>
+    {
+        self.runtime
+            .block_on(self.inner.get_ecommerce_product_activity(
+                fields,
+                exclude_fields,
+                count,
+                offset,
+                campaign_id,
+                sort_field,
+            ))
+    }
+    /// The blocking version of [`crate::reports::Reports::get_ecommerce_product_activity_request`].
+    pub fn get_ecommerce_product_activity_request<'a>(
+        &'a self,
+        campaign_id: &'a str,
+    ) -> ReportsGetEcommerceProductActivityRequest<'a> {
+        ReportsGetEcommerceProductActivityRequest {
+            inner: self
+                .inner
+                .get_ecommerce_product_activity_request(campaign_id),
+            runtime: &self.runtime,
+        }
+    }
+}
@@ -0,0 +1,57 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Root::get`], set with a builder.
+pub struct RootGetRequest<'a> {
+    inner: crate::root::RootGetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> RootGetRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::ApiRoot>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::root::Root`].
+pub struct Root {
+    inner: crate::root::Root,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Root {
+    pub(crate) fn new(inner: crate::root::Root, runtime: Arc<tokio::runtime::Runtime>) -> Self {
+        Root { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::root::Root::get`].
+    pub fn get(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+    ) -> ClientResult<crate::Response<crate::types::ApiRoot>> {
+        self.runtime
+            .block_on(self.inner.get(fields, exclude_fields))
+    }
+    /// The blocking version of [`crate::root::Root::get_request`].
+    pub fn get_request(&self) -> RootGetRequest<'_> {
+        RootGetRequest {
+            inner: self.inner.get_request(),
+            runtime: &self.runtime,
+        }
+    }
+}
@@ -0,0 +1,67 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`SearchCampaigns::get`], set with a builder.
+pub struct SearchCampaignsGetRequest<'a> {
+    inner: crate::search_campaigns::SearchCampaignsGetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> SearchCampaignsGetRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sets the `query` query parameter.
+    pub fn query(mut self, query: &'a str) -> Self {
+        self.inner = self.inner.query(query);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::Campaigns>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::search_campaigns::SearchCampaigns`].
+pub struct SearchCampaigns {
+    inner: crate::search_campaigns::SearchCampaigns,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl SearchCampaigns {
+    pub(crate) fn new(
+        inner: crate::search_campaigns::SearchCampaigns,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        SearchCampaigns { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::search_campaigns::SearchCampaigns::get`].
+    pub fn get(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        query: &str,
+    ) -> ClientResult<crate::Response<crate::types::Campaigns>> {
+        self.runtime
+            .block_on(self.inner.get(fields, exclude_fields, query))
+    }
+    /// The blocking version of [`crate::search_campaigns::SearchCampaigns::get_request`].
+    pub fn get_request(&self) -> SearchCampaignsGetRequest<'_> {
+        SearchCampaignsGetRequest {
+            inner: self.inner.get_request(),
+            runtime: &self.runtime,
+        }
+    }
+}
@@ -0,0 +1,74 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`SearchMembers::get`], set with a builder.
+pub struct SearchMembersGetRequest<'a> {
+    inner: crate::search_members::SearchMembersGetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> SearchMembersGetRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sets the `query` query parameter.
+    pub fn query(mut self, query: &'a str) -> Self {
+        self.inner = self.inner.query(query);
+        self
+    }
+
+    /// Sets the `list_id` query parameter.
+    pub fn list_id(mut self, list_id: &'a str) -> Self {
+        self.inner = self.inner.list_id(list_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::MembersData>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::search_members::SearchMembers`].
+pub struct SearchMembers {
+    inner: crate::search_members::SearchMembers,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl SearchMembers {
+    pub(crate) fn new(
+        inner: crate::search_members::SearchMembers,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        SearchMembers { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::search_members::SearchMembers::get`].
+    pub fn get(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        query: &str,
+        list_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::MembersData>> {
+        self.runtime
+            .block_on(self.inner.get(fields, exclude_fields, query, list_id))
+    }
+    /// The blocking version of [`crate::search_members::SearchMembers::get_request`].
+    pub fn get_request(&self) -> SearchMembersGetRequest<'_> {
+        SearchMembersGetRequest {
+            inner: self.inner.get_request(),
+            runtime: &self.runtime,
+        }
+    }
+}
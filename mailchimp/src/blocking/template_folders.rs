@@ -0,0 +1,140 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`TemplateFolders::get`], set with a builder.
+pub struct TemplateFoldersGetRequest<'a> {
+    inner: crate::template_folders::TemplateFoldersGetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> TemplateFoldersGetRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.inner = self.inner.count(count);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.inner = self.inner.offset(offset);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::TemplateFolders>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`TemplateFolders::get_template_folders`], set with a builder.
+pub struct TemplateFoldersGetTemplateFoldersRequest<'a> {
+    inner: crate::template_folders::TemplateFoldersGetTemplateFoldersRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> TemplateFoldersGetTemplateFoldersRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::Folders>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::template_folders::TemplateFolders`].
+pub struct TemplateFolders {
+    inner: crate::template_folders::TemplateFolders,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl TemplateFolders {
+    pub(crate) fn new(
+        inner: crate::template_folders::TemplateFolders,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        TemplateFolders { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::template_folders::TemplateFolders::get`].
+    pub fn get(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+    ) -> ClientResult<crate::Response<crate::types::TemplateFolders>> {
+        self.runtime
+            .block_on(self.inner.get(fields, exclude_fields, count, offset))
+    }
+    /// The blocking version of [`crate::template_folders::TemplateFolders::get_request`].
+    pub fn get_request(&self) -> TemplateFoldersGetRequest<'_> {
+        TemplateFoldersGetRequest {
+            inner: self.inner.get_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::template_folders::TemplateFolders::post`].
+    pub fn post(
+        &self,
+        body: &crate::types::GalleryFolder,
+    ) -> ClientResult<crate::Response<crate::types::Folders>> {
+        self.runtime.block_on(self.inner.post(body))
+    }
+    /// The blocking version of [`crate::template_folders::TemplateFolders::get_template_folders`].
+    pub fn get_template_folders(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        folder_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Folders>> {
+        self.runtime.block_on(
+            self.inner
+                .get_template_folders(fields, exclude_fields, folder_id),
+        )
+    }
+    /// The blocking version of [`crate::template_folders::TemplateFolders::get_template_folders_request`].
+    pub fn get_template_folders_request<'a>(
+        &'a self,
+        folder_id: &'a str,
+    ) -> TemplateFoldersGetTemplateFoldersRequest<'a> {
+        TemplateFoldersGetTemplateFoldersRequest {
+            inner: self.inner.get_template_folders_request(folder_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::template_folders::TemplateFolders::delete`].
+    pub fn delete(&self, folder_id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.delete(folder_id))
+    }
+    /// The blocking version of [`crate::template_folders::TemplateFolders::patch`].
+    pub fn patch(
+        &self,
+        folder_id: &str,
+        body: &crate::types::GalleryFolder,
+    ) -> ClientResult<crate::Response<crate::types::Folders>> {
+        self.runtime.block_on(self.inner.patch(folder_id, body))
+    }
+}
@@ -0,0 +1,255 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Templates::get`], set with a builder.
+pub struct TemplatesGetRequest<'a> {
+    inner: crate::templates::TemplatesGetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> TemplatesGetRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.inner = self.inner.count(count);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.inner = self.inner.offset(offset);
+        self
+    }
+
+    /// Sets the `created_by` query parameter.
+    pub fn created_by(mut self, created_by: &'a str) -> Self {
+        self.inner = self.inner.created_by(created_by);
+        self
+    }
+
+    /// Sets the `since_date_created` query parameter.
+    pub fn since_date_created(mut self, since_date_created: &'a str) -> Self {
+        self.inner = self.inner.since_date_created(since_date_created);
+        self
+    }
+
+    /// Sets the `before_date_created` query parameter.
+    pub fn before_date_created(mut self, before_date_created: &'a str) -> Self {
+        self.inner = self.inner.before_date_created(before_date_created);
+        self
+    }
+
+    /// Sets the `type` query parameter.
+    pub fn type_(mut self, type_: &'a str) -> Self {
+        self.inner = self.inner.type_(type_);
+        self
+    }
+
+    /// Sets the `category` query parameter.
+    pub fn category(mut self, category: &'a str) -> Self {
+        self.inner = self.inner.category(category);
+        self
+    }
+
+    /// Sets the `folder_id` query parameter.
+    pub fn folder_id(mut self, folder_id: &'a str) -> Self {
+        self.inner = self.inner.folder_id(folder_id);
+        self
+    }
+
+    /// Sets the `sort_field` query parameter.
+    pub fn sort_field(mut self, sort_field: crate::types::GetTemplatesSortField) -> Self {
+        self.inner = self.inner.sort_field(sort_field);
+        self
+    }
+
+    /// Sets the `sort_dir` query parameter.
+    pub fn sort_dir(mut self, sort_dir: crate::types::SortDir) -> Self {
+        self.inner = self.inner.sort_dir(sort_dir);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::TemplatesData>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Templates::get_templates`], set with a builder.
+pub struct TemplatesGetTemplatesRequest<'a> {
+    inner: crate::templates::TemplatesGetTemplatesRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> TemplatesGetTemplatesRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::Templates>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Templates::get_default_content`], set with a builder.
+pub struct TemplatesGetDefaultContentRequest<'a> {
+    inner: crate::templates::TemplatesGetDefaultContentRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> TemplatesGetDefaultContentRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.inner = self.inner.exclude_fields(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::TemplateDefaultContent>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::templates::Templates`].
+pub struct Templates {
+    inner: crate::templates::Templates,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Templates {
+    pub(crate) fn new(
+        inner: crate::templates::Templates,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Templates { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::templates::Templates::get`].
+    pub fn get(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        created_by: &str,
+        since_date_created: &str,
+        before_date_created: &str,
+        type_: &str,
+        category: &str,
+        folder_id: &str,
+        sort_field: crate::types::GetTemplatesSortField,
+        sort_dir: crate::types::SortDir,
+    ) -> ClientResult<crate::Response<crate::types::TemplatesData>> {
+        self.runtime.block_on(self.inner.get(
+            fields,
+            exclude_fields,
+            count,
+            offset,
+            created_by,
+            since_date_created,
+            before_date_created,
+            type_,
+            category,
+            folder_id,
+            sort_field,
+            sort_dir,
+        ))
+    }
+    /// The blocking version of [`crate::templates::Templates::get_request`].
+    pub fn get_request(&self) -> TemplatesGetRequest<'_> {
+        TemplatesGetRequest {
+            inner: self.inner.get_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::templates::Templates::post`].
+    pub fn post(
+        &self,
+        body: &crate::types::TemplateInstance,
+    ) -> ClientResult<crate::Response<crate::types::Templates>> {
+        self.runtime.block_on(self.inner.post(body))
+    }
+    /// The blocking version of [`crate::templates::Templates::get_templates`].
+    pub fn get_templates(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        template_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Templates>> {
+        self.runtime.block_on(
+            self.inner
+                .get_templates(fields, exclude_fields, template_id),
+        )
+    }
+    /// The blocking version of [`crate::templates::Templates::get_templates_request`].
+    pub fn get_templates_request<'a>(
+        &'a self,
+        template_id: &'a str,
+    ) -> TemplatesGetTemplatesRequest<'a> {
+        TemplatesGetTemplatesRequest {
+            inner: self.inner.get_templates_request(template_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::templates::Templates::delete`].
+    pub fn delete(&self, template_id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.delete(template_id))
+    }
+    /// The blocking version of [`crate::templates::Templates::patch`].
+    pub fn patch(
+        &self,
+        template_id: &str,
+        body: &crate::types::TemplateInstance,
+    ) -> ClientResult<crate::Response<crate::types::Templates>> {
+        self.runtime.block_on(self.inner.patch(template_id, body))
+    }
+    /// The blocking version of [`crate::templates::Templates::get_default_content`].
+    pub fn get_default_content(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        template_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::TemplateDefaultContent>> {
+        self.runtime.block_on(
+            self.inner
+                .get_default_content(fields, exclude_fields, template_id),
+        )
+    }
+    /// The blocking version of [`crate::templates::Templates::get_default_content_request`].
+    pub fn get_default_content_request<'a>(
+        &'a self,
+        template_id: &'a str,
+    ) -> TemplatesGetDefaultContentRequest<'a> {
+        TemplatesGetDefaultContentRequest {
+            inner: self.inner.get_default_content_request(template_id),
+            runtime: &self.runtime,
+        }
+    }
+}
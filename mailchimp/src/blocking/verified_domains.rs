@@ -0,0 +1,52 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::verified_domains::VerifiedDomains`].
+pub struct VerifiedDomains {
+    inner: crate::verified_domains::VerifiedDomains,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl VerifiedDomains {
+    pub(crate) fn new(
+        inner: crate::verified_domains::VerifiedDomains,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        VerifiedDomains { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::verified_domains::VerifiedDomains::get`].
+    pub fn get(
+        &self,
+        domain_name: &str,
+    ) -> ClientResult<crate::Response<crate::types::VerifiedDomains>> {
+        self.runtime.block_on(self.inner.get(domain_name))
+    }
+    /// The blocking version of [`crate::verified_domains::VerifiedDomains::delete`].
+    pub fn delete(&self, domain_name: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.delete(domain_name))
+    }
+    /// The blocking version of [`crate::verified_domains::VerifiedDomains::verify_domain`].
+    pub fn verify_domain(
+        &self,
+        domain_name: &str,
+        body: &crate::types::VerifyADomainSending,
+    ) -> ClientResult<crate::Response<crate::types::VerifiedDomains>> {
+        self.runtime
+            .block_on(self.inner.verify_domain(domain_name, body))
+    }
+    /// The blocking version of [`crate::verified_domains::VerifiedDomains::get_verified_domains`].
+    pub fn get_verified_domains(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::VerifiedDomainsData>> {
+        self.runtime.block_on(self.inner.get_verified_domains())
+    }
+    /// The blocking version of [`crate::verified_domains::VerifiedDomains::create`].
+    pub fn create(
+        &self,
+        body: &crate::types::VerifiedDomainsDataType,
+    ) -> ClientResult<crate::Response<crate::types::VerifiedDomains>> {
+        self.runtime.block_on(self.inner.create(body))
+    }
+}
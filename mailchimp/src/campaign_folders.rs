@@ -1,6 +1,137 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`CampaignFolders::get`], set with a builder.
+pub struct CampaignFoldersGetRequest<'a> {
+    client: &'a CampaignFolders,
+    fields: Option<&'a [String]>,
+    exclude_fields: Option<&'a [String]>,
+    count: Option<i64>,
+    offset: Option<i64>,
+}
+
+impl<'a> CampaignFoldersGetRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::CampaignFolders>> {
+        let Self {
+            client,
+            fields,
+            exclude_fields,
+            count,
+            offset,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = count {
+            query_args.push(("count".to_string(), v.to_string()));
+        }
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = offset {
+            query_args.push(("offset".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/campaign-folders?{}", query_), None);
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`CampaignFolders::get_campaign_folders`], set with a builder.
+pub struct CampaignFoldersGetCampaignFoldersRequest<'a> {
+    client: &'a CampaignFolders,
+    fields: Option<&'a [String]>,
+    exclude_fields: Option<&'a [String]>,
+    folder_id: &'a str,
+}
+
+impl<'a> CampaignFoldersGetCampaignFoldersRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::CampaignFolder>> {
+        let Self {
+            client,
+            fields,
+            exclude_fields,
+            folder_id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/campaign-folders/{}?{}",
+                crate::progenitor_support::encode_path(folder_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct CampaignFolders {
     pub client: Client,
 }
@@ -59,6 +190,24 @@ impl CampaignFolders {
             )
             .await
     }
+    /**
+     * List campaign folders.
+     *
+     * This function performs a `GET` to the `/campaign-folders` endpoint.
+     *
+     * As opposed to `get`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get all folders used to organize campaigns.
+     */
+    pub fn get_request(&self) -> CampaignFoldersGetRequest<'_> {
+        CampaignFoldersGetRequest {
+            client: self,
+            fields: None,
+            exclude_fields: None,
+            count: None,
+            offset: None,
+        }
+    }
     /**
      * Add campaign folder.
      *
@@ -126,6 +275,26 @@ impl CampaignFolders {
             )
             .await
     }
+    /**
+     * Get campaign folder.
+     *
+     * This function performs a `GET` to the `/campaign-folders/{folder_id}` endpoint.
+     *
+     * As opposed to `get_campaign_folders`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get information about a specific folder used to organize campaigns.
+     */
+    pub fn get_campaign_folders_request<'a>(
+        &'a self,
+        folder_id: &'a str,
+    ) -> CampaignFoldersGetCampaignFoldersRequest<'a> {
+        CampaignFoldersGetCampaignFoldersRequest {
+            client: self,
+            fields: None,
+            exclude_fields: None,
+            folder_id,
+        }
+    }
     /**
      * Delete campaign folder.
      *
@@ -1,6 +1,491 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Campaigns::get`], set with a builder.
+pub struct CampaignsGetRequest<'a> {
+    client: &'a Campaigns,
+    fields: Option<&'a [String]>,
+    exclude_fields: Option<&'a [String]>,
+    count: Option<i64>,
+    offset: Option<i64>,
+    type_: Option<crate::types::CampaignType>,
+    status: Option<crate::types::GetCampaignsStatus>,
+    before_send_time: Option<chrono::DateTime<chrono::Utc>>,
+    since_send_time: Option<chrono::DateTime<chrono::Utc>>,
+    before_create_time: Option<chrono::DateTime<chrono::Utc>>,
+    since_create_time: Option<chrono::DateTime<chrono::Utc>>,
+    list_id: Option<&'a str>,
+    folder_id: Option<&'a str>,
+    member_id: Option<&'a str>,
+    sort_field: Option<crate::types::SortField>,
+    sort_dir: Option<crate::types::SortDir>,
+}
+
+impl<'a> CampaignsGetRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Sets the `type` query parameter.
+    pub fn type_(mut self, type_: crate::types::CampaignType) -> Self {
+        self.type_ = Some(type_);
+        self
+    }
+
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: crate::types::GetCampaignsStatus) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Sets the `before_send_time` query parameter.
+    pub fn before_send_time(mut self, before_send_time: chrono::DateTime<chrono::Utc>) -> Self {
+        self.before_send_time = Some(before_send_time);
+        self
+    }
+
+    /// Sets the `since_send_time` query parameter.
+    pub fn since_send_time(mut self, since_send_time: chrono::DateTime<chrono::Utc>) -> Self {
+        self.since_send_time = Some(since_send_time);
+        self
+    }
+
+    /// Sets the `before_create_time` query parameter.
+    pub fn before_create_time(mut self, before_create_time: chrono::DateTime<chrono::Utc>) -> Self {
+        self.before_create_time = Some(before_create_time);
+        self
+    }
+
+    /// Sets the `since_create_time` query parameter.
+    pub fn since_create_time(mut self, since_create_time: chrono::DateTime<chrono::Utc>) -> Self {
+        self.since_create_time = Some(since_create_time);
+        self
+    }
+
+    /// Sets the `list_id` query parameter.
+    pub fn list_id(mut self, list_id: &'a str) -> Self {
+        self.list_id = Some(list_id);
+        self
+    }
+
+    /// Sets the `folder_id` query parameter.
+    pub fn folder_id(mut self, folder_id: &'a str) -> Self {
+        self.folder_id = Some(folder_id);
+        self
+    }
+
+    /// Sets the `member_id` query parameter.
+    pub fn member_id(mut self, member_id: &'a str) -> Self {
+        self.member_id = Some(member_id);
+        self
+    }
+
+    /// Sets the `sort_field` query parameter.
+    pub fn sort_field(mut self, sort_field: crate::types::SortField) -> Self {
+        self.sort_field = Some(sort_field);
+        self
+    }
+
+    /// Sets the `sort_dir` query parameter.
+    pub fn sort_dir(mut self, sort_dir: crate::types::SortDir) -> Self {
+        self.sort_dir = Some(sort_dir);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::GetCampaignsResponse>> {
+        let Self {
+            client,
+            fields,
+            exclude_fields,
+            count,
+            offset,
+            type_,
+            status,
+            before_send_time,
+            since_send_time,
+            before_create_time,
+            since_create_time,
+            list_id,
+            folder_id,
+            member_id,
+            sort_field,
+            sort_dir,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(date) = before_create_time {
+            query_args.push(("before_create_time".to_string(), date.to_rfc3339()));
+        }
+        if let Some(date) = before_send_time {
+            query_args.push(("before_send_time".to_string(), date.to_rfc3339()));
+        }
+        if let Some(v) = count {
+            query_args.push(("count".to_string(), v.to_string()));
+        }
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = folder_id {
+            query_args.push(("folder_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = list_id {
+            query_args.push(("list_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = member_id {
+            query_args.push(("member_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = offset {
+            query_args.push(("offset".to_string(), v.to_string()));
+        }
+        if let Some(date) = since_create_time {
+            query_args.push(("since_create_time".to_string(), date.to_rfc3339()));
+        }
+        if let Some(date) = since_send_time {
+            query_args.push(("since_send_time".to_string(), date.to_rfc3339()));
+        }
+        if let Some(v) = sort_dir {
+            query_args.push(("sort_dir".to_string(), v.to_string()));
+        }
+        if let Some(v) = sort_field {
+            query_args.push(("sort_field".to_string(), v.to_string()));
+        }
+        if let Some(v) = status {
+            query_args.push(("status".to_string(), v.to_string()));
+        }
+        if let Some(v) = type_ {
+            query_args.push(("type".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(&format!("/campaigns?{}", query_), None);
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Campaigns::get_campaigns`], set with a builder.
+pub struct CampaignsGetCampaignsRequest<'a> {
+    client: &'a Campaigns,
+    fields: Option<&'a [String]>,
+    exclude_fields: Option<&'a [String]>,
+    campaign_id: &'a str,
+}
+
+impl<'a> CampaignsGetCampaignsRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::Campaign>> {
+        let Self {
+            client,
+            fields,
+            exclude_fields,
+            campaign_id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/campaigns/{}?{}",
+                crate::progenitor_support::encode_path(campaign_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Campaigns::get_content`], set with a builder.
+pub struct CampaignsGetContentRequest<'a> {
+    client: &'a Campaigns,
+    fields: Option<&'a [String]>,
+    exclude_fields: Option<&'a [String]>,
+    campaign_id: &'a str,
+}
+
+impl<'a> CampaignsGetContentRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::CampaignContent>> {
+        let Self {
+            client,
+            fields,
+            exclude_fields,
+            campaign_id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/campaigns/{}/content?{}",
+                crate::progenitor_support::encode_path(campaign_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Campaigns::get_feedback`], set with a builder.
+pub struct CampaignsGetFeedbackRequest<'a> {
+    client: &'a Campaigns,
+    fields: Option<&'a [String]>,
+    exclude_fields: Option<&'a [String]>,
+    campaign_id: &'a str,
+}
+
+impl<'a> CampaignsGetFeedbackRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::CampaignReports>> {
+        let Self {
+            client,
+            fields,
+            exclude_fields,
+            campaign_id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/campaigns/{}/feedback?{}",
+                crate::progenitor_support::encode_path(campaign_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Campaigns::get_feedback_campaigns`], set with a builder.
+pub struct CampaignsGetFeedbackCampaignsRequest<'a> {
+    client: &'a Campaigns,
+    fields: Option<&'a [String]>,
+    exclude_fields: Option<&'a [String]>,
+    campaign_id: &'a str,
+    feedback_id: &'a str,
+}
+
+impl<'a> CampaignsGetFeedbackCampaignsRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::CampaignFeedbackData>> {
+        let Self {
+            client,
+            fields,
+            exclude_fields,
+            campaign_id,
+            feedback_id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/campaigns/{}/feedback/{}?{}",
+                crate::progenitor_support::encode_path(campaign_id),
+                crate::progenitor_support::encode_path(feedback_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Campaigns::get_send_checklist`], set with a builder.
+pub struct CampaignsGetSendChecklistRequest<'a> {
+    client: &'a Campaigns,
+    fields: Option<&'a [String]>,
+    exclude_fields: Option<&'a [String]>,
+    campaign_id: &'a str,
+}
+
+impl<'a> CampaignsGetSendChecklistRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::SendChecklist>> {
+        let Self {
+            client,
+            fields,
+            exclude_fields,
+            campaign_id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/campaigns/{}/send-checklist?{}",
+                crate::progenitor_support::encode_path(campaign_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct Campaigns {
     pub client: Client,
 }
@@ -112,6 +597,35 @@ impl Campaigns {
             )
             .await
     }
+    /**
+     * List campaigns.
+     *
+     * This function performs a `GET` to the `/campaigns` endpoint.
+     *
+     * As opposed to `get`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get all campaigns in an account.
+     */
+    pub fn get_request(&self) -> CampaignsGetRequest<'_> {
+        CampaignsGetRequest {
+            client: self,
+            fields: None,
+            exclude_fields: None,
+            count: None,
+            offset: None,
+            type_: None,
+            status: None,
+            before_send_time: None,
+            since_send_time: None,
+            before_create_time: None,
+            since_create_time: None,
+            list_id: None,
+            folder_id: None,
+            member_id: None,
+            sort_field: None,
+            sort_dir: None,
+        }
+    }
     /**
      * Add campaign.
      *
@@ -179,6 +693,26 @@ impl Campaigns {
             )
             .await
     }
+    /**
+     * Get campaign info.
+     *
+     * This function performs a `GET` to the `/campaigns/{campaign_id}` endpoint.
+     *
+     * As opposed to `get_campaigns`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get information about a specific campaign.
+     */
+    pub fn get_campaigns_request<'a>(
+        &'a self,
+        campaign_id: &'a str,
+    ) -> CampaignsGetCampaignsRequest<'a> {
+        CampaignsGetCampaignsRequest {
+            client: self,
+            fields: None,
+            exclude_fields: None,
+            campaign_id,
+        }
+    }
     /**
      * Delete campaign.
      *
@@ -570,6 +1104,26 @@ impl Campaigns {
             )
             .await
     }
+    /**
+     * Get campaign content.
+     *
+     * This function performs a `GET` to the `/campaigns/{campaign_id}/content` endpoint.
+     *
+     * As opposed to `get_content`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get the the HTML and plain-text content for a campaign.
+     */
+    pub fn get_content_request<'a>(
+        &'a self,
+        campaign_id: &'a str,
+    ) -> CampaignsGetContentRequest<'a> {
+        CampaignsGetContentRequest {
+            client: self,
+            fields: None,
+            exclude_fields: None,
+            campaign_id,
+        }
+    }
     /**
      * Set campaign content.
      *
@@ -648,6 +1202,26 @@ impl Campaigns {
             )
             .await
     }
+    /**
+     * List campaign feedback.
+     *
+     * This function performs a `GET` to the `/campaigns/{campaign_id}/feedback` endpoint.
+     *
+     * As opposed to `get_feedback`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get team feedback while you're working together on a Mailchimp campaign.
+     */
+    pub fn get_feedback_request<'a>(
+        &'a self,
+        campaign_id: &'a str,
+    ) -> CampaignsGetFeedbackRequest<'a> {
+        CampaignsGetFeedbackRequest {
+            client: self,
+            fields: None,
+            exclude_fields: None,
+            campaign_id,
+        }
+    }
     /**
      * Add campaign feedback.
      *
@@ -729,6 +1303,28 @@ impl Campaigns {
             )
             .await
     }
+    /**
+     * Get campaign feedback message.
+     *
+     * This function performs a `GET` to the `/campaigns/{campaign_id}/feedback/{feedback_id}` endpoint.
+     *
+     * As opposed to `get_feedback_campaigns`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get a specific feedback message from a campaign.
+     */
+    pub fn get_feedback_campaigns_request<'a>(
+        &'a self,
+        campaign_id: &'a str,
+        feedback_id: &'a str,
+    ) -> CampaignsGetFeedbackCampaignsRequest<'a> {
+        CampaignsGetFeedbackCampaignsRequest {
+            client: self,
+            fields: None,
+            exclude_fields: None,
+            campaign_id,
+            feedback_id,
+        }
+    }
     /**
      * Delete campaign feedback message.
      *
@@ -845,4 +1441,24 @@ impl Campaigns {
             )
             .await
     }
+    /**
+     * Get campaign send checklist.
+     *
+     * This function performs a `GET` to the `/campaigns/{campaign_id}/send-checklist` endpoint.
+     *
+     * As opposed to `get_send_checklist`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Review the send checklist for a campaign, and resolve any issues before sending.
+     */
+    pub fn get_send_checklist_request<'a>(
+        &'a self,
+        campaign_id: &'a str,
+    ) -> CampaignsGetSendChecklistRequest<'a> {
+        CampaignsGetSendChecklistRequest {
+            client: self,
+            fields: None,
+            exclude_fields: None,
+            campaign_id,
+        }
+    }
 }
@@ -1,6 +1,137 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`ConnectedSites::get`], set with a builder.
+pub struct ConnectedSitesGetRequest<'a> {
+    client: &'a ConnectedSites,
+    fields: Option<&'a [String]>,
+    exclude_fields: Option<&'a [String]>,
+    count: Option<i64>,
+    offset: Option<i64>,
+}
+
+impl<'a> ConnectedSitesGetRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::ConnectedSites>> {
+        let Self {
+            client,
+            fields,
+            exclude_fields,
+            count,
+            offset,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = count {
+            query_args.push(("count".to_string(), v.to_string()));
+        }
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = offset {
+            query_args.push(("offset".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/connected-sites?{}", query_), None);
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`ConnectedSites::get_connected_sites`], set with a builder.
+pub struct ConnectedSitesGetConnectedSitesRequest<'a> {
+    client: &'a ConnectedSites,
+    fields: Option<&'a [String]>,
+    exclude_fields: Option<&'a [String]>,
+    connected_site_id: &'a str,
+}
+
+impl<'a> ConnectedSitesGetConnectedSitesRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::Sites>> {
+        let Self {
+            client,
+            fields,
+            exclude_fields,
+            connected_site_id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/connected-sites/{}?{}",
+                crate::progenitor_support::encode_path(connected_site_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct ConnectedSites {
     pub client: Client,
 }
@@ -59,6 +190,24 @@ impl ConnectedSites {
             )
             .await
     }
+    /**
+     * List connected sites.
+     *
+     * This function performs a `GET` to the `/connected-sites` endpoint.
+     *
+     * As opposed to `get`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get all connected sites in an account.
+     */
+    pub fn get_request(&self) -> ConnectedSitesGetRequest<'_> {
+        ConnectedSitesGetRequest {
+            client: self,
+            fields: None,
+            exclude_fields: None,
+            count: None,
+            offset: None,
+        }
+    }
     /**
      * Add connected site.
      *
@@ -126,6 +275,26 @@ impl ConnectedSites {
             )
             .await
     }
+    /**
+     * Get connected site.
+     *
+     * This function performs a `GET` to the `/connected-sites/{connected_site_id}` endpoint.
+     *
+     * As opposed to `get_connected_sites`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get information about a specific connected site.
+     */
+    pub fn get_connected_sites_request<'a>(
+        &'a self,
+        connected_site_id: &'a str,
+    ) -> ConnectedSitesGetConnectedSitesRequest<'a> {
+        ConnectedSitesGetConnectedSitesRequest {
+            client: self,
+            fields: None,
+            exclude_fields: None,
+            connected_site_id,
+        }
+    }
     /**
      * Delete connected site.
      *
@@ -1,6 +1,324 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Conversations::get`], set with a builder.
+pub struct ConversationsGetRequest<'a> {
+    client: &'a Conversations,
+    fields: Option<&'a [String]>,
+    exclude_fields: Option<&'a [String]>,
+    count: Option<i64>,
+    offset: Option<i64>,
+    has_unread_messages: Option<crate::types::IsRead>,
+    list_id: Option<&'a str>,
+    campaign_id: Option<&'a str>,
+}
+
+impl<'a> ConversationsGetRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Sets the `has_unread_messages` query parameter.
+    pub fn has_unread_messages(mut self, has_unread_messages: crate::types::IsRead) -> Self {
+        self.has_unread_messages = Some(has_unread_messages);
+        self
+    }
+
+    /// Sets the `list_id` query parameter.
+    pub fn list_id(mut self, list_id: &'a str) -> Self {
+        self.list_id = Some(list_id);
+        self
+    }
+
+    /// Sets the `campaign_id` query parameter.
+    pub fn campaign_id(mut self, campaign_id: &'a str) -> Self {
+        self.campaign_id = Some(campaign_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::TrackedConversations>> {
+        let Self {
+            client,
+            fields,
+            exclude_fields,
+            count,
+            offset,
+            has_unread_messages,
+            list_id,
+            campaign_id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = campaign_id {
+            query_args.push(("campaign_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = count {
+            query_args.push(("count".to_string(), v.to_string()));
+        }
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = has_unread_messages {
+            query_args.push(("has_unread_messages".to_string(), v.to_string()));
+        }
+        if let Some(v) = list_id {
+            query_args.push(("list_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = offset {
+            query_args.push(("offset".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/conversations?{}", query_), None);
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Conversations::get_conversations`], set with a builder.
+pub struct ConversationsGetConversationsRequest<'a> {
+    client: &'a Conversations,
+    fields: Option<&'a [String]>,
+    exclude_fields: Option<&'a [String]>,
+    conversation_id: &'a str,
+}
+
+impl<'a> ConversationsGetConversationsRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::Conversation>> {
+        let Self {
+            client,
+            fields,
+            exclude_fields,
+            conversation_id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/conversations/{}?{}",
+                crate::progenitor_support::encode_path(conversation_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Conversations::get_message`], set with a builder.
+pub struct ConversationsGetMessageRequest<'a> {
+    client: &'a Conversations,
+    fields: Option<&'a [String]>,
+    exclude_fields: Option<&'a [String]>,
+    conversation_id: &'a str,
+    is_read: Option<crate::types::IsRead>,
+    before_timestamp: Option<chrono::DateTime<chrono::Utc>>,
+    since_timestamp: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+impl<'a> ConversationsGetMessageRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sets the `is_read` query parameter.
+    pub fn is_read(mut self, is_read: crate::types::IsRead) -> Self {
+        self.is_read = Some(is_read);
+        self
+    }
+
+    /// Sets the `before_timestamp` query parameter.
+    pub fn before_timestamp(mut self, before_timestamp: chrono::DateTime<chrono::Utc>) -> Self {
+        self.before_timestamp = Some(before_timestamp);
+        self
+    }
+
+    /// Sets the `since_timestamp` query parameter.
+    pub fn since_timestamp(mut self, since_timestamp: chrono::DateTime<chrono::Utc>) -> Self {
+        self.since_timestamp = Some(since_timestamp);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::CollectionOfConversationMessages>> {
+        let Self {
+            client,
+            fields,
+            exclude_fields,
+            conversation_id,
+            is_read,
+            before_timestamp,
+            since_timestamp,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(date) = before_timestamp {
+            query_args.push(("before_timestamp".to_string(), date.to_rfc3339()));
+        }
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = is_read {
+            query_args.push(("is_read".to_string(), v.to_string()));
+        }
+        if let Some(date) = since_timestamp {
+            query_args.push(("since_timestamp".to_string(), date.to_rfc3339()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/conversations/{}/messages?{}",
+                crate::progenitor_support::encode_path(conversation_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Conversations::get_message_conversations`], set with a builder.
+pub struct ConversationsGetMessageConversationsRequest<'a> {
+    client: &'a Conversations,
+    fields: Option<&'a [String]>,
+    exclude_fields: Option<&'a [String]>,
+    conversation_id: &'a str,
+    message_id: &'a str,
+}
+
+impl<'a> ConversationsGetMessageConversationsRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::ConversationMessage>> {
+        let Self {
+            client,
+            fields,
+            exclude_fields,
+            conversation_id,
+            message_id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/conversations/{}/messages/{}?{}",
+                crate::progenitor_support::encode_path(conversation_id),
+                crate::progenitor_support::encode_path(message_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct Conversations {
     pub client: Client,
 }
@@ -75,6 +393,27 @@ impl Conversations {
             )
             .await
     }
+    /**
+     * List conversations.
+     *
+     * This function performs a `GET` to the `/conversations` endpoint.
+     *
+     * As opposed to `get`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get a list of conversations for the account. Conversations has been deprecated in favor of Inbox and these endpoints don't include Inbox data. Past Conversations are still available via this endpoint, but new campaign replies and other Inbox messages aren’t available using this endpoint.
+     */
+    pub fn get_request(&self) -> ConversationsGetRequest<'_> {
+        ConversationsGetRequest {
+            client: self,
+            fields: None,
+            exclude_fields: None,
+            count: None,
+            offset: None,
+            has_unread_messages: None,
+            list_id: None,
+            campaign_id: None,
+        }
+    }
     /**
      * Get conversation.
      *
@@ -120,6 +459,26 @@ impl Conversations {
             )
             .await
     }
+    /**
+     * Get conversation.
+     *
+     * This function performs a `GET` to the `/conversations/{conversation_id}` endpoint.
+     *
+     * As opposed to `get_conversations`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get details about an individual conversation. Conversations has been deprecated in favor of Inbox and these endpoints don't include Inbox data. Past Conversations are still available via this endpoint, but new campaign replies and other Inbox messages aren’t available using this endpoint.
+     */
+    pub fn get_conversations_request<'a>(
+        &'a self,
+        conversation_id: &'a str,
+    ) -> ConversationsGetConversationsRequest<'a> {
+        ConversationsGetConversationsRequest {
+            client: self,
+            fields: None,
+            exclude_fields: None,
+            conversation_id,
+        }
+    }
     /**
      * List messages.
      *
@@ -180,6 +539,29 @@ impl Conversations {
             )
             .await
     }
+    /**
+     * List messages.
+     *
+     * This function performs a `GET` to the `/conversations/{conversation_id}/messages` endpoint.
+     *
+     * As opposed to `get_message`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get messages from a specific conversation. Conversations has been deprecated in favor of Inbox and these endpoints don't include Inbox data. Past Conversations are still available via this endpoint, but new campaign replies and other Inbox messages aren’t available using this endpoint.
+     */
+    pub fn get_message_request<'a>(
+        &'a self,
+        conversation_id: &'a str,
+    ) -> ConversationsGetMessageRequest<'a> {
+        ConversationsGetMessageRequest {
+            client: self,
+            fields: None,
+            exclude_fields: None,
+            conversation_id,
+            is_read: None,
+            before_timestamp: None,
+            since_timestamp: None,
+        }
+    }
     /**
      * Get message.
      *
@@ -228,4 +610,26 @@ impl Conversations {
             )
             .await
     }
+    /**
+     * Get message.
+     *
+     * This function performs a `GET` to the `/conversations/{conversation_id}/messages/{message_id}` endpoint.
+     *
+     * As opposed to `get_message_conversations`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get an individual message in a conversation. Conversations has been deprecated in favor of Inbox and these endpoints don't include Inbox data. Past Conversations are still available via this endpoint, but new campaign replies and other Inbox messages aren’t available using this endpoint.
+     */
+    pub fn get_message_conversations_request<'a>(
+        &'a self,
+        conversation_id: &'a str,
+        message_id: &'a str,
+    ) -> ConversationsGetMessageConversationsRequest<'a> {
+        ConversationsGetMessageConversationsRequest {
+            client: self,
+            fields: None,
+            exclude_fields: None,
+            conversation_id,
+            message_id,
+        }
+    }
 }
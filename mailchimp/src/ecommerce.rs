@@ -1,6 +1,1751 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Ecommerce::get_order`], set with a builder.
+pub struct EcommerceGetOrderRequest<'a> {
+    client: &'a Ecommerce,
+    fields: Option<&'a [String]>,
+    exclude_fields: Option<&'a [String]>,
+    count: Option<i64>,
+    offset: Option<i64>,
+    campaign_id: Option<&'a str>,
+    outreach_id: Option<&'a str>,
+    customer_id: Option<&'a str>,
+    has_outreach: Option<bool>,
+}
+
+impl<'a> EcommerceGetOrderRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Sets the `campaign_id` query parameter.
+    pub fn campaign_id(mut self, campaign_id: &'a str) -> Self {
+        self.campaign_id = Some(campaign_id);
+        self
+    }
+
+    /// Sets the `outreach_id` query parameter.
+    pub fn outreach_id(mut self, outreach_id: &'a str) -> Self {
+        self.outreach_id = Some(outreach_id);
+        self
+    }
+
+    /// Sets the `customer_id` query parameter.
+    pub fn customer_id(mut self, customer_id: &'a str) -> Self {
+        self.customer_id = Some(customer_id);
+        self
+    }
+
+    /// Sets the `has_outreach` query parameter.
+    pub fn has_outreach(mut self, has_outreach: bool) -> Self {
+        self.has_outreach = Some(has_outreach);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::OrdersData>> {
+        let Self {
+            client,
+            fields,
+            exclude_fields,
+            count,
+            offset,
+            campaign_id,
+            outreach_id,
+            customer_id,
+            has_outreach,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = campaign_id {
+            query_args.push(("campaign_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = count {
+            query_args.push(("count".to_string(), v.to_string()));
+        }
+        if let Some(v) = customer_id {
+            query_args.push(("customer_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = has_outreach {
+            query_args.push(("has_outreach".to_string(), v.to_string()));
+        }
+        if let Some(v) = offset {
+            query_args.push(("offset".to_string(), v.to_string()));
+        }
+        if let Some(v) = outreach_id {
+            query_args.push(("outreach_id".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/ecommerce/orders?{}", query_), None);
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Ecommerce::get_store`], set with a builder.
+pub struct EcommerceGetStoreRequest<'a> {
+    client: &'a Ecommerce,
+    fields: Option<&'a [String]>,
+    exclude_fields: Option<&'a [String]>,
+    count: Option<i64>,
+    offset: Option<i64>,
+}
+
+impl<'a> EcommerceGetStoreRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::ECommerceStores>> {
+        let Self {
+            client,
+            fields,
+            exclude_fields,
+            count,
+            offset,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = count {
+            query_args.push(("count".to_string(), v.to_string()));
+        }
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = offset {
+            query_args.push(("offset".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/ecommerce/stores?{}", query_), None);
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Ecommerce::get_store_ecommerce`], set with a builder.
+pub struct EcommerceGetStoreEcommerceRequest<'a> {
+    client: &'a Ecommerce,
+    fields: Option<&'a [String]>,
+    exclude_fields: Option<&'a [String]>,
+    store_id: &'a str,
+}
+
+impl<'a> EcommerceGetStoreEcommerceRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::Stores>> {
+        let Self {
+            client,
+            fields,
+            exclude_fields,
+            store_id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/ecommerce/stores/{}?{}",
+                crate::progenitor_support::encode_path(store_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Ecommerce::get_stores_cart`], set with a builder.
+pub struct EcommerceGetStoresCartRequest<'a> {
+    client: &'a Ecommerce,
+    fields: Option<&'a [String]>,
+    exclude_fields: Option<&'a [String]>,
+    count: Option<i64>,
+    offset: Option<i64>,
+    store_id: &'a str,
+}
+
+impl<'a> EcommerceGetStoresCartRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::CartsData>> {
+        let Self {
+            client,
+            fields,
+            exclude_fields,
+            count,
+            offset,
+            store_id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = count {
+            query_args.push(("count".to_string(), v.to_string()));
+        }
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = offset {
+            query_args.push(("offset".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/ecommerce/stores/{}/carts?{}",
+                crate::progenitor_support::encode_path(store_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Ecommerce::get_stores_cart_ecommerce`], set with a builder.
+pub struct EcommerceGetStoresCartEcommerceRequest<'a> {
+    client: &'a Ecommerce,
+    fields: Option<&'a [String]>,
+    exclude_fields: Option<&'a [String]>,
+    store_id: &'a str,
+    cart_id: &'a str,
+}
+
+impl<'a> EcommerceGetStoresCartEcommerceRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::Carts>> {
+        let Self {
+            client,
+            fields,
+            exclude_fields,
+            store_id,
+            cart_id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/ecommerce/stores/{}/carts/{}?{}",
+                crate::progenitor_support::encode_path(store_id),
+                crate::progenitor_support::encode_path(cart_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Ecommerce::get_stores_carts_line`], set with a builder.
+pub struct EcommerceGetStoresCartsLineRequest<'a> {
+    client: &'a Ecommerce,
+    fields: Option<&'a [String]>,
+    exclude_fields: Option<&'a [String]>,
+    count: Option<i64>,
+    offset: Option<i64>,
+    store_id: &'a str,
+    cart_id: &'a str,
+}
+
+impl<'a> EcommerceGetStoresCartsLineRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::CartLines>> {
+        let Self {
+            client,
+            fields,
+            exclude_fields,
+            count,
+            offset,
+            store_id,
+            cart_id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = count {
+            query_args.push(("count".to_string(), v.to_string()));
+        }
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = offset {
+            query_args.push(("offset".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/ecommerce/stores/{}/carts/{}/lines?{}",
+                crate::progenitor_support::encode_path(store_id),
+                crate::progenitor_support::encode_path(cart_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Ecommerce::get_stores_carts_line_ecommerce`], set with a builder.
+pub struct EcommerceGetStoresCartsLineEcommerceRequest<'a> {
+    client: &'a Ecommerce,
+    fields: Option<&'a [String]>,
+    exclude_fields: Option<&'a [String]>,
+    store_id: &'a str,
+    cart_id: &'a str,
+    line_id: &'a str,
+}
+
+impl<'a> EcommerceGetStoresCartsLineEcommerceRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::ECommerceCartLineItem>> {
+        let Self {
+            client,
+            fields,
+            exclude_fields,
+            store_id,
+            cart_id,
+            line_id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/ecommerce/stores/{}/carts/{}/lines/{}?{}",
+                crate::progenitor_support::encode_path(store_id),
+                crate::progenitor_support::encode_path(cart_id),
+                crate::progenitor_support::encode_path(line_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Ecommerce::get_stores_customer`], set with a builder.
+pub struct EcommerceGetStoresCustomerRequest<'a> {
+    client: &'a Ecommerce,
+    fields: Option<&'a [String]>,
+    exclude_fields: Option<&'a [String]>,
+    count: Option<i64>,
+    offset: Option<i64>,
+    store_id: &'a str,
+    email_address: Option<&'a str>,
+}
+
+impl<'a> EcommerceGetStoresCustomerRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Sets the `email_address` query parameter.
+    pub fn email_address(mut self, email_address: &'a str) -> Self {
+        self.email_address = Some(email_address);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::Customers>> {
+        let Self {
+            client,
+            fields,
+            exclude_fields,
+            count,
+            offset,
+            store_id,
+            email_address,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = count {
+            query_args.push(("count".to_string(), v.to_string()));
+        }
+        if let Some(v) = email_address {
+            query_args.push(("email_address".to_string(), v.to_string()));
+        }
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = offset {
+            query_args.push(("offset".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/ecommerce/stores/{}/customers?{}",
+                crate::progenitor_support::encode_path(store_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Ecommerce::get_stores_customer_ecommerce`], set with a builder.
+pub struct EcommerceGetStoresCustomerEcommerceRequest<'a> {
+    client: &'a Ecommerce,
+    fields: Option<&'a [String]>,
+    exclude_fields: Option<&'a [String]>,
+    store_id: &'a str,
+    customer_id: &'a str,
+}
+
+impl<'a> EcommerceGetStoresCustomerEcommerceRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::Customer>> {
+        let Self {
+            client,
+            fields,
+            exclude_fields,
+            store_id,
+            customer_id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/ecommerce/stores/{}/customers/{}?{}",
+                crate::progenitor_support::encode_path(store_id),
+                crate::progenitor_support::encode_path(customer_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Ecommerce::get_stores_promorule`], set with a builder.
+pub struct EcommerceGetStoresPromoruleRequest<'a> {
+    client: &'a Ecommerce,
+    fields: Option<&'a [String]>,
+    exclude_fields: Option<&'a [String]>,
+    count: Option<i64>,
+    offset: Option<i64>,
+    store_id: &'a str,
+}
+
+impl<'a> EcommerceGetStoresPromoruleRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::PromoRulesData>> {
+        let Self {
+            client,
+            fields,
+            exclude_fields,
+            count,
+            offset,
+            store_id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = count {
+            query_args.push(("count".to_string(), v.to_string()));
+        }
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = offset {
+            query_args.push(("offset".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/ecommerce/stores/{}/promo-rules?{}",
+                crate::progenitor_support::encode_path(store_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Ecommerce::get_stores_promorule_ecommerce`], set with a builder.
+pub struct EcommerceGetStoresPromoruleEcommerceRequest<'a> {
+    client: &'a Ecommerce,
+    fields: Option<&'a [String]>,
+    exclude_fields: Option<&'a [String]>,
+    store_id: &'a str,
+    promo_rule_id: &'a str,
+}
+
+impl<'a> EcommerceGetStoresPromoruleEcommerceRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::PromoRules>> {
+        let Self {
+            client,
+            fields,
+            exclude_fields,
+            store_id,
+            promo_rule_id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/ecommerce/stores/{}/promo-rules/{}?{}",
+                crate::progenitor_support::encode_path(store_id),
+                crate::progenitor_support::encode_path(promo_rule_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Ecommerce::get_stores_promocode`], set with a builder.
+pub struct EcommerceGetStoresPromocodeRequest<'a> {
+    client: &'a Ecommerce,
+    fields: Option<&'a [String]>,
+    exclude_fields: Option<&'a [String]>,
+    count: Option<i64>,
+    offset: Option<i64>,
+    promo_rule_id: &'a str,
+    store_id: &'a str,
+}
+
+impl<'a> EcommerceGetStoresPromocodeRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::PromoCodesData>> {
+        let Self {
+            client,
+            fields,
+            exclude_fields,
+            count,
+            offset,
+            promo_rule_id,
+            store_id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = count {
+            query_args.push(("count".to_string(), v.to_string()));
+        }
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = offset {
+            query_args.push(("offset".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/ecommerce/stores/{}/promo-rules/{}/promo-codes?{}",
+                crate::progenitor_support::encode_path(store_id),
+                crate::progenitor_support::encode_path(promo_rule_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Ecommerce::get_stores_promocode_ecommerce`], set with a builder.
+pub struct EcommerceGetStoresPromocodeEcommerceRequest<'a> {
+    client: &'a Ecommerce,
+    fields: Option<&'a [String]>,
+    exclude_fields: Option<&'a [String]>,
+    store_id: &'a str,
+    promo_rule_id: &'a str,
+    promo_code_id: &'a str,
+}
+
+impl<'a> EcommerceGetStoresPromocodeEcommerceRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::PromoCodes>> {
+        let Self {
+            client,
+            fields,
+            exclude_fields,
+            store_id,
+            promo_rule_id,
+            promo_code_id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/ecommerce/stores/{}/promo-rules/{}/promo-codes/{}?{}",
+                crate::progenitor_support::encode_path(store_id),
+                crate::progenitor_support::encode_path(promo_rule_id),
+                crate::progenitor_support::encode_path(promo_code_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Ecommerce::get_stores_order`], set with a builder.
+pub struct EcommerceGetStoresOrderRequest<'a> {
+    client: &'a Ecommerce,
+    fields: Option<&'a [String]>,
+    exclude_fields: Option<&'a [String]>,
+    count: Option<i64>,
+    offset: Option<i64>,
+    store_id: &'a str,
+    customer_id: Option<&'a str>,
+    has_outreach: Option<bool>,
+    campaign_id: Option<&'a str>,
+    outreach_id: Option<&'a str>,
+}
+
+impl<'a> EcommerceGetStoresOrderRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Sets the `customer_id` query parameter.
+    pub fn customer_id(mut self, customer_id: &'a str) -> Self {
+        self.customer_id = Some(customer_id);
+        self
+    }
+
+    /// Sets the `has_outreach` query parameter.
+    pub fn has_outreach(mut self, has_outreach: bool) -> Self {
+        self.has_outreach = Some(has_outreach);
+        self
+    }
+
+    /// Sets the `campaign_id` query parameter.
+    pub fn campaign_id(mut self, campaign_id: &'a str) -> Self {
+        self.campaign_id = Some(campaign_id);
+        self
+    }
+
+    /// Sets the `outreach_id` query parameter.
+    pub fn outreach_id(mut self, outreach_id: &'a str) -> Self {
+        self.outreach_id = Some(outreach_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::OrdersDataType>> {
+        let Self {
+            client,
+            fields,
+            exclude_fields,
+            count,
+            offset,
+            store_id,
+            customer_id,
+            has_outreach,
+            campaign_id,
+            outreach_id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = campaign_id {
+            query_args.push(("campaign_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = count {
+            query_args.push(("count".to_string(), v.to_string()));
+        }
+        if let Some(v) = customer_id {
+            query_args.push(("customer_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = has_outreach {
+            query_args.push(("has_outreach".to_string(), v.to_string()));
+        }
+        if let Some(v) = offset {
+            query_args.push(("offset".to_string(), v.to_string()));
+        }
+        if let Some(v) = outreach_id {
+            query_args.push(("outreach_id".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/ecommerce/stores/{}/orders?{}",
+                crate::progenitor_support::encode_path(store_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Ecommerce::get_stores_order_ecommerce`], set with a builder.
+pub struct EcommerceGetStoresOrderEcommerceRequest<'a> {
+    client: &'a Ecommerce,
+    fields: Option<&'a [String]>,
+    exclude_fields: Option<&'a [String]>,
+    store_id: &'a str,
+    order_id: &'a str,
+}
+
+impl<'a> EcommerceGetStoresOrderEcommerceRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::Orders>> {
+        let Self {
+            client,
+            fields,
+            exclude_fields,
+            store_id,
+            order_id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/ecommerce/stores/{}/orders/{}?{}",
+                crate::progenitor_support::encode_path(store_id),
+                crate::progenitor_support::encode_path(order_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Ecommerce::get_stores_orders_line`], set with a builder.
+pub struct EcommerceGetStoresOrdersLineRequest<'a> {
+    client: &'a Ecommerce,
+    fields: Option<&'a [String]>,
+    exclude_fields: Option<&'a [String]>,
+    count: Option<i64>,
+    offset: Option<i64>,
+    store_id: &'a str,
+    order_id: &'a str,
+}
+
+impl<'a> EcommerceGetStoresOrdersLineRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::OrderLines>> {
+        let Self {
+            client,
+            fields,
+            exclude_fields,
+            count,
+            offset,
+            store_id,
+            order_id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = count {
+            query_args.push(("count".to_string(), v.to_string()));
+        }
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = offset {
+            query_args.push(("offset".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/ecommerce/stores/{}/orders/{}/lines?{}",
+                crate::progenitor_support::encode_path(store_id),
+                crate::progenitor_support::encode_path(order_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Ecommerce::get_stores_orders_line_ecommerce`], set with a builder.
+pub struct EcommerceGetStoresOrdersLineEcommerceRequest<'a> {
+    client: &'a Ecommerce,
+    fields: Option<&'a [String]>,
+    exclude_fields: Option<&'a [String]>,
+    store_id: &'a str,
+    order_id: &'a str,
+    line_id: &'a str,
+}
+
+impl<'a> EcommerceGetStoresOrdersLineEcommerceRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::Lines>> {
+        let Self {
+            client,
+            fields,
+            exclude_fields,
+            store_id,
+            order_id,
+            line_id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/ecommerce/stores/{}/orders/{}/lines/{}?{}",
+                crate::progenitor_support::encode_path(store_id),
+                crate::progenitor_support::encode_path(order_id),
+                crate::progenitor_support::encode_path(line_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Ecommerce::get_stores_product`], set with a builder.
+pub struct EcommerceGetStoresProductRequest<'a> {
+    client: &'a Ecommerce,
+    fields: Option<&'a [String]>,
+    exclude_fields: Option<&'a [String]>,
+    count: Option<i64>,
+    offset: Option<i64>,
+    store_id: &'a str,
+}
+
+impl<'a> EcommerceGetStoresProductRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::ProductsData>> {
+        let Self {
+            client,
+            fields,
+            exclude_fields,
+            count,
+            offset,
+            store_id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = count {
+            query_args.push(("count".to_string(), v.to_string()));
+        }
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = offset {
+            query_args.push(("offset".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/ecommerce/stores/{}/products?{}",
+                crate::progenitor_support::encode_path(store_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Ecommerce::get_stores_product_ecommerce`], set with a builder.
+pub struct EcommerceGetStoresProductEcommerceRequest<'a> {
+    client: &'a Ecommerce,
+    fields: Option<&'a [String]>,
+    exclude_fields: Option<&'a [String]>,
+    store_id: &'a str,
+    product_id: &'a str,
+}
+
+impl<'a> EcommerceGetStoresProductEcommerceRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::ECommerceProduct>> {
+        let Self {
+            client,
+            fields,
+            exclude_fields,
+            store_id,
+            product_id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/ecommerce/stores/{}/products/{}?{}",
+                crate::progenitor_support::encode_path(store_id),
+                crate::progenitor_support::encode_path(product_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Ecommerce::get_stores_products_variant`], set with a builder.
+pub struct EcommerceGetStoresProductsVariantRequest<'a> {
+    client: &'a Ecommerce,
+    fields: Option<&'a [String]>,
+    exclude_fields: Option<&'a [String]>,
+    count: Option<i64>,
+    offset: Option<i64>,
+    store_id: &'a str,
+    product_id: &'a str,
+}
+
+impl<'a> EcommerceGetStoresProductsVariantRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::EcommerceProductVariants>> {
+        let Self {
+            client,
+            fields,
+            exclude_fields,
+            count,
+            offset,
+            store_id,
+            product_id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = count {
+            query_args.push(("count".to_string(), v.to_string()));
+        }
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = offset {
+            query_args.push(("offset".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/ecommerce/stores/{}/products/{}/variants?{}",
+                crate::progenitor_support::encode_path(store_id),
+                crate::progenitor_support::encode_path(product_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Ecommerce::get_stores_products_variant_ecommerce`], set with a builder.
+pub struct EcommerceGetStoresProductsVariantEcommerceRequest<'a> {
+    client: &'a Ecommerce,
+    fields: Option<&'a [String]>,
+    exclude_fields: Option<&'a [String]>,
+    store_id: &'a str,
+    product_id: &'a str,
+    variant_id: &'a str,
+}
+
+impl<'a> EcommerceGetStoresProductsVariantEcommerceRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::Variants>> {
+        let Self {
+            client,
+            fields,
+            exclude_fields,
+            store_id,
+            product_id,
+            variant_id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/ecommerce/stores/{}/products/{}/variants/{}?{}",
+                crate::progenitor_support::encode_path(store_id),
+                crate::progenitor_support::encode_path(product_id),
+                crate::progenitor_support::encode_path(variant_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Ecommerce::get_stores_products_image`], set with a builder.
+pub struct EcommerceGetStoresProductsImageRequest<'a> {
+    client: &'a Ecommerce,
+    fields: Option<&'a [String]>,
+    exclude_fields: Option<&'a [String]>,
+    count: Option<i64>,
+    offset: Option<i64>,
+    store_id: &'a str,
+    product_id: &'a str,
+}
+
+impl<'a> EcommerceGetStoresProductsImageRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::EcommerceProductImages>> {
+        let Self {
+            client,
+            fields,
+            exclude_fields,
+            count,
+            offset,
+            store_id,
+            product_id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = count {
+            query_args.push(("count".to_string(), v.to_string()));
+        }
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = offset {
+            query_args.push(("offset".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/ecommerce/stores/{}/products/{}/images?{}",
+                crate::progenitor_support::encode_path(store_id),
+                crate::progenitor_support::encode_path(product_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Ecommerce::get_stores_products_image_ecommerce`], set with a builder.
+pub struct EcommerceGetStoresProductsImageEcommerceRequest<'a> {
+    client: &'a Ecommerce,
+    fields: Option<&'a [String]>,
+    exclude_fields: Option<&'a [String]>,
+    store_id: &'a str,
+    product_id: &'a str,
+    image_id: &'a str,
+}
+
+impl<'a> EcommerceGetStoresProductsImageEcommerceRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::Images>> {
+        let Self {
+            client,
+            fields,
+            exclude_fields,
+            store_id,
+            product_id,
+            image_id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/ecommerce/stores/{}/products/{}/images/{}?{}",
+                crate::progenitor_support::encode_path(store_id),
+                crate::progenitor_support::encode_path(product_id),
+                crate::progenitor_support::encode_path(image_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct Ecommerce {
     pub client: Client,
 }
@@ -79,6 +1824,28 @@ impl Ecommerce {
             )
             .await
     }
+    /**
+     * List account orders.
+     *
+     * This function performs a `GET` to the `/ecommerce/orders` endpoint.
+     *
+     * As opposed to `get_order`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get information about an account's orders.
+     */
+    pub fn get_order_request(&self) -> EcommerceGetOrderRequest<'_> {
+        EcommerceGetOrderRequest {
+            client: self,
+            fields: None,
+            exclude_fields: None,
+            count: None,
+            offset: None,
+            campaign_id: None,
+            outreach_id: None,
+            customer_id: None,
+            has_outreach: None,
+        }
+    }
     /**
      * List stores.
      *
@@ -127,6 +1894,24 @@ impl Ecommerce {
             )
             .await
     }
+    /**
+     * List stores.
+     *
+     * This function performs a `GET` to the `/ecommerce/stores` endpoint.
+     *
+     * As opposed to `get_store`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get information about all stores in the account.
+     */
+    pub fn get_store_request(&self) -> EcommerceGetStoreRequest<'_> {
+        EcommerceGetStoreRequest {
+            client: self,
+            fields: None,
+            exclude_fields: None,
+            count: None,
+            offset: None,
+        }
+    }
     /**
      * Add store.
      *
@@ -194,6 +1979,26 @@ impl Ecommerce {
             )
             .await
     }
+    /**
+     * Get store info.
+     *
+     * This function performs a `GET` to the `/ecommerce/stores/{store_id}` endpoint.
+     *
+     * As opposed to `get_store_ecommerce`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get information about a specific store.
+     */
+    pub fn get_store_ecommerce_request<'a>(
+        &'a self,
+        store_id: &'a str,
+    ) -> EcommerceGetStoreEcommerceRequest<'a> {
+        EcommerceGetStoreEcommerceRequest {
+            client: self,
+            fields: None,
+            exclude_fields: None,
+            store_id,
+        }
+    }
     /**
      * Delete store.
      *
@@ -311,6 +2116,28 @@ impl Ecommerce {
             )
             .await
     }
+    /**
+     * List carts.
+     *
+     * This function performs a `GET` to the `/ecommerce/stores/{store_id}/carts` endpoint.
+     *
+     * As opposed to `get_stores_cart`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get information about a store's carts.
+     */
+    pub fn get_stores_cart_request<'a>(
+        &'a self,
+        store_id: &'a str,
+    ) -> EcommerceGetStoresCartRequest<'a> {
+        EcommerceGetStoresCartRequest {
+            client: self,
+            fields: None,
+            exclude_fields: None,
+            count: None,
+            offset: None,
+            store_id,
+        }
+    }
     /**
      * Add cart.
      *
@@ -392,6 +2219,28 @@ impl Ecommerce {
             )
             .await
     }
+    /**
+     * Get cart info.
+     *
+     * This function performs a `GET` to the `/ecommerce/stores/{store_id}/carts/{cart_id}` endpoint.
+     *
+     * As opposed to `get_stores_cart_ecommerce`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get information about a specific cart.
+     */
+    pub fn get_stores_cart_ecommerce_request<'a>(
+        &'a self,
+        store_id: &'a str,
+        cart_id: &'a str,
+    ) -> EcommerceGetStoresCartEcommerceRequest<'a> {
+        EcommerceGetStoresCartEcommerceRequest {
+            client: self,
+            fields: None,
+            exclude_fields: None,
+            store_id,
+            cart_id,
+        }
+    }
     /**
      * Delete cart.
      *
@@ -521,6 +2370,30 @@ impl Ecommerce {
             )
             .await
     }
+    /**
+     * List cart line items.
+     *
+     * This function performs a `GET` to the `/ecommerce/stores/{store_id}/carts/{cart_id}/lines` endpoint.
+     *
+     * As opposed to `get_stores_carts_line`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get information about a cart's line items.
+     */
+    pub fn get_stores_carts_line_request<'a>(
+        &'a self,
+        store_id: &'a str,
+        cart_id: &'a str,
+    ) -> EcommerceGetStoresCartsLineRequest<'a> {
+        EcommerceGetStoresCartsLineRequest {
+            client: self,
+            fields: None,
+            exclude_fields: None,
+            count: None,
+            offset: None,
+            store_id,
+            cart_id,
+        }
+    }
     /**
      * Add cart line item.
      *
@@ -608,6 +2481,30 @@ impl Ecommerce {
             )
             .await
     }
+    /**
+     * Get cart line item.
+     *
+     * This function performs a `GET` to the `/ecommerce/stores/{store_id}/carts/{cart_id}/lines/{line_id}` endpoint.
+     *
+     * As opposed to `get_stores_carts_line_ecommerce`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get information about a specific cart line item.
+     */
+    pub fn get_stores_carts_line_ecommerce_request<'a>(
+        &'a self,
+        store_id: &'a str,
+        cart_id: &'a str,
+        line_id: &'a str,
+    ) -> EcommerceGetStoresCartsLineEcommerceRequest<'a> {
+        EcommerceGetStoresCartsLineEcommerceRequest {
+            client: self,
+            fields: None,
+            exclude_fields: None,
+            store_id,
+            cart_id,
+            line_id,
+        }
+    }
     /**
      * Delete cart line item.
      *
@@ -745,6 +2642,29 @@ impl Ecommerce {
             )
             .await
     }
+    /**
+     * List customers.
+     *
+     * This function performs a `GET` to the `/ecommerce/stores/{store_id}/customers` endpoint.
+     *
+     * As opposed to `get_stores_customer`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get information about a store's customers.
+     */
+    pub fn get_stores_customer_request<'a>(
+        &'a self,
+        store_id: &'a str,
+    ) -> EcommerceGetStoresCustomerRequest<'a> {
+        EcommerceGetStoresCustomerRequest {
+            client: self,
+            fields: None,
+            exclude_fields: None,
+            count: None,
+            offset: None,
+            store_id,
+            email_address: None,
+        }
+    }
     /**
      * Add customer.
      *
@@ -826,6 +2746,28 @@ impl Ecommerce {
             )
             .await
     }
+    /**
+     * Get customer info.
+     *
+     * This function performs a `GET` to the `/ecommerce/stores/{store_id}/customers/{customer_id}` endpoint.
+     *
+     * As opposed to `get_stores_customer_ecommerce`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get information about a specific customer.
+     */
+    pub fn get_stores_customer_ecommerce_request<'a>(
+        &'a self,
+        store_id: &'a str,
+        customer_id: &'a str,
+    ) -> EcommerceGetStoresCustomerEcommerceRequest<'a> {
+        EcommerceGetStoresCustomerEcommerceRequest {
+            client: self,
+            fields: None,
+            exclude_fields: None,
+            store_id,
+            customer_id,
+        }
+    }
     /**
      * Add or update customer.
      *
@@ -988,6 +2930,28 @@ impl Ecommerce {
             )
             .await
     }
+    /**
+     * List promo rules.
+     *
+     * This function performs a `GET` to the `/ecommerce/stores/{store_id}/promo-rules` endpoint.
+     *
+     * As opposed to `get_stores_promorule`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get information about a store's promo rules.
+     */
+    pub fn get_stores_promorule_request<'a>(
+        &'a self,
+        store_id: &'a str,
+    ) -> EcommerceGetStoresPromoruleRequest<'a> {
+        EcommerceGetStoresPromoruleRequest {
+            client: self,
+            fields: None,
+            exclude_fields: None,
+            count: None,
+            offset: None,
+            store_id,
+        }
+    }
     /**
      * Add promo rule.
      *
@@ -1069,6 +3033,28 @@ impl Ecommerce {
             )
             .await
     }
+    /**
+     * Get promo rule.
+     *
+     * This function performs a `GET` to the `/ecommerce/stores/{store_id}/promo-rules/{promo_rule_id}` endpoint.
+     *
+     * As opposed to `get_stores_promorule_ecommerce`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get information about a specific promo rule.
+     */
+    pub fn get_stores_promorule_ecommerce_request<'a>(
+        &'a self,
+        store_id: &'a str,
+        promo_rule_id: &'a str,
+    ) -> EcommerceGetStoresPromoruleEcommerceRequest<'a> {
+        EcommerceGetStoresPromoruleEcommerceRequest {
+            client: self,
+            fields: None,
+            exclude_fields: None,
+            store_id,
+            promo_rule_id,
+        }
+    }
     /**
      * Delete promo rule.
      *
@@ -1198,6 +3184,30 @@ impl Ecommerce {
             )
             .await
     }
+    /**
+     * List promo codes.
+     *
+     * This function performs a `GET` to the `/ecommerce/stores/{store_id}/promo-rules/{promo_rule_id}/promo-codes` endpoint.
+     *
+     * As opposed to `get_stores_promocode`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get information about a store's promo codes.
+     */
+    pub fn get_stores_promocode_request<'a>(
+        &'a self,
+        promo_rule_id: &'a str,
+        store_id: &'a str,
+    ) -> EcommerceGetStoresPromocodeRequest<'a> {
+        EcommerceGetStoresPromocodeRequest {
+            client: self,
+            fields: None,
+            exclude_fields: None,
+            count: None,
+            offset: None,
+            promo_rule_id,
+            store_id,
+        }
+    }
     /**
      * Add promo code.
      *
@@ -1285,6 +3295,30 @@ impl Ecommerce {
             )
             .await
     }
+    /**
+     * Get promo code.
+     *
+     * This function performs a `GET` to the `/ecommerce/stores/{store_id}/promo-rules/{promo_rule_id}/promo-codes/{promo_code_id}` endpoint.
+     *
+     * As opposed to `get_stores_promocode_ecommerce`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get information about a specific promo code.
+     */
+    pub fn get_stores_promocode_ecommerce_request<'a>(
+        &'a self,
+        store_id: &'a str,
+        promo_rule_id: &'a str,
+        promo_code_id: &'a str,
+    ) -> EcommerceGetStoresPromocodeEcommerceRequest<'a> {
+        EcommerceGetStoresPromocodeEcommerceRequest {
+            client: self,
+            fields: None,
+            exclude_fields: None,
+            store_id,
+            promo_rule_id,
+            promo_code_id,
+        }
+    }
     /**
      * Delete promo code.
      *
@@ -1437,6 +3471,32 @@ impl Ecommerce {
             )
             .await
     }
+    /**
+     * List orders.
+     *
+     * This function performs a `GET` to the `/ecommerce/stores/{store_id}/orders` endpoint.
+     *
+     * As opposed to `get_stores_order`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get information about a store's orders.
+     */
+    pub fn get_stores_order_request<'a>(
+        &'a self,
+        store_id: &'a str,
+    ) -> EcommerceGetStoresOrderRequest<'a> {
+        EcommerceGetStoresOrderRequest {
+            client: self,
+            fields: None,
+            exclude_fields: None,
+            count: None,
+            offset: None,
+            store_id,
+            customer_id: None,
+            has_outreach: None,
+            campaign_id: None,
+            outreach_id: None,
+        }
+    }
     /**
      * Add order.
      *
@@ -1518,6 +3578,28 @@ impl Ecommerce {
             )
             .await
     }
+    /**
+     * Get order info.
+     *
+     * This function performs a `GET` to the `/ecommerce/stores/{store_id}/orders/{order_id}` endpoint.
+     *
+     * As opposed to `get_stores_order_ecommerce`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get information about a specific order.
+     */
+    pub fn get_stores_order_ecommerce_request<'a>(
+        &'a self,
+        store_id: &'a str,
+        order_id: &'a str,
+    ) -> EcommerceGetStoresOrderEcommerceRequest<'a> {
+        EcommerceGetStoresOrderEcommerceRequest {
+            client: self,
+            fields: None,
+            exclude_fields: None,
+            store_id,
+            order_id,
+        }
+    }
     /**
      * Delete order.
      *
@@ -1647,6 +3729,30 @@ impl Ecommerce {
             )
             .await
     }
+    /**
+     * List order line items.
+     *
+     * This function performs a `GET` to the `/ecommerce/stores/{store_id}/orders/{order_id}/lines` endpoint.
+     *
+     * As opposed to `get_stores_orders_line`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get information about an order's line items.
+     */
+    pub fn get_stores_orders_line_request<'a>(
+        &'a self,
+        store_id: &'a str,
+        order_id: &'a str,
+    ) -> EcommerceGetStoresOrdersLineRequest<'a> {
+        EcommerceGetStoresOrdersLineRequest {
+            client: self,
+            fields: None,
+            exclude_fields: None,
+            count: None,
+            offset: None,
+            store_id,
+            order_id,
+        }
+    }
     /**
      * Add order line item.
      *
@@ -1734,6 +3840,30 @@ impl Ecommerce {
             )
             .await
     }
+    /**
+     * Get order line item.
+     *
+     * This function performs a `GET` to the `/ecommerce/stores/{store_id}/orders/{order_id}/lines/{line_id}` endpoint.
+     *
+     * As opposed to `get_stores_orders_line_ecommerce`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get information about a specific order line item.
+     */
+    pub fn get_stores_orders_line_ecommerce_request<'a>(
+        &'a self,
+        store_id: &'a str,
+        order_id: &'a str,
+        line_id: &'a str,
+    ) -> EcommerceGetStoresOrdersLineEcommerceRequest<'a> {
+        EcommerceGetStoresOrdersLineEcommerceRequest {
+            client: self,
+            fields: None,
+            exclude_fields: None,
+            store_id,
+            order_id,
+            line_id,
+        }
+    }
     /**
      * Delete order line item.
      *
@@ -1866,6 +3996,28 @@ impl Ecommerce {
             )
             .await
     }
+    /**
+     * List product.
+     *
+     * This function performs a `GET` to the `/ecommerce/stores/{store_id}/products` endpoint.
+     *
+     * As opposed to `get_stores_product`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get information about a store's products.
+     */
+    pub fn get_stores_product_request<'a>(
+        &'a self,
+        store_id: &'a str,
+    ) -> EcommerceGetStoresProductRequest<'a> {
+        EcommerceGetStoresProductRequest {
+            client: self,
+            fields: None,
+            exclude_fields: None,
+            count: None,
+            offset: None,
+            store_id,
+        }
+    }
     /**
      * Add product.
      *
@@ -1947,6 +4099,28 @@ impl Ecommerce {
             )
             .await
     }
+    /**
+     * Get product info.
+     *
+     * This function performs a `GET` to the `/ecommerce/stores/{store_id}/products/{product_id}` endpoint.
+     *
+     * As opposed to `get_stores_product_ecommerce`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get information about a specific product.
+     */
+    pub fn get_stores_product_ecommerce_request<'a>(
+        &'a self,
+        store_id: &'a str,
+        product_id: &'a str,
+    ) -> EcommerceGetStoresProductEcommerceRequest<'a> {
+        EcommerceGetStoresProductEcommerceRequest {
+            client: self,
+            fields: None,
+            exclude_fields: None,
+            store_id,
+            product_id,
+        }
+    }
     /**
      * Delete product.
      *
@@ -2076,6 +4250,30 @@ impl Ecommerce {
             )
             .await
     }
+    /**
+     * List product variants.
+     *
+     * This function performs a `GET` to the `/ecommerce/stores/{store_id}/products/{product_id}/variants` endpoint.
+     *
+     * As opposed to `get_stores_products_variant`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get information about a product's variants.
+     */
+    pub fn get_stores_products_variant_request<'a>(
+        &'a self,
+        store_id: &'a str,
+        product_id: &'a str,
+    ) -> EcommerceGetStoresProductsVariantRequest<'a> {
+        EcommerceGetStoresProductsVariantRequest {
+            client: self,
+            fields: None,
+            exclude_fields: None,
+            count: None,
+            offset: None,
+            store_id,
+            product_id,
+        }
+    }
     /**
      * Add product variant.
      *
@@ -2163,6 +4361,30 @@ impl Ecommerce {
             )
             .await
     }
+    /**
+     * Get product variant info.
+     *
+     * This function performs a `GET` to the `/ecommerce/stores/{store_id}/products/{product_id}/variants/{variant_id}` endpoint.
+     *
+     * As opposed to `get_stores_products_variant_ecommerce`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get information about a specific product variant.
+     */
+    pub fn get_stores_products_variant_ecommerce_request<'a>(
+        &'a self,
+        store_id: &'a str,
+        product_id: &'a str,
+        variant_id: &'a str,
+    ) -> EcommerceGetStoresProductsVariantEcommerceRequest<'a> {
+        EcommerceGetStoresProductsVariantEcommerceRequest {
+            client: self,
+            fields: None,
+            exclude_fields: None,
+            store_id,
+            product_id,
+            variant_id,
+        }
+    }
     /**
      * Add or update product variant.
      *
@@ -2337,6 +4559,30 @@ impl Ecommerce {
             )
             .await
     }
+    /**
+     * List product images.
+     *
+     * This function performs a `GET` to the `/ecommerce/stores/{store_id}/products/{product_id}/images` endpoint.
+     *
+     * As opposed to `get_stores_products_image`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get information about a product's images.
+     */
+    pub fn get_stores_products_image_request<'a>(
+        &'a self,
+        store_id: &'a str,
+        product_id: &'a str,
+    ) -> EcommerceGetStoresProductsImageRequest<'a> {
+        EcommerceGetStoresProductsImageRequest {
+            client: self,
+            fields: None,
+            exclude_fields: None,
+            count: None,
+            offset: None,
+            store_id,
+            product_id,
+        }
+    }
     /**
      * Add product image.
      *
@@ -2424,6 +4670,30 @@ impl Ecommerce {
             )
             .await
     }
+    /**
+     * Get product image info.
+     *
+     * This function performs a `GET` to the `/ecommerce/stores/{store_id}/products/{product_id}/images/{image_id}` endpoint.
+     *
+     * As opposed to `get_stores_products_image_ecommerce`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get information about a specific product image.
+     */
+    pub fn get_stores_products_image_ecommerce_request<'a>(
+        &'a self,
+        store_id: &'a str,
+        product_id: &'a str,
+        image_id: &'a str,
+    ) -> EcommerceGetStoresProductsImageEcommerceRequest<'a> {
+        EcommerceGetStoresProductsImageEcommerceRequest {
+            client: self,
+            fields: None,
+            exclude_fields: None,
+            store_id,
+            product_id,
+            image_id,
+        }
+    }
     /**
      * Delete product image.
      *
@@ -0,0 +1,106 @@
+//! The errors returned by the API.
+
+use std::fmt;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// An error returned by the API, as problem details.
+///
+/// FROM: <https://www.rfc-editor.org/rfc/rfc7807>
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default, JsonSchema)]
+pub struct ApiError {
+    /// A URI identifying the type of problem.
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "type"
+    )]
+    pub type_: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub title: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub status: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub detail: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub instance: String,
+}
+
+impl ApiError {
+    fn from_body(body: &[u8]) -> Option<Self> {
+        serde_json::from_slice::<Self>(body)
+            .ok()
+            .filter(|error| !error.title.is_empty())
+    }
+}
+
+impl fmt::Display for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.detail.is_empty() {
+            write!(f, "{}", self.title)
+        } else {
+            write!(f, "{}: {}", self.title, self.detail)
+        }
+    }
+}
+
+impl crate::ClientError {
+    /// Turns an unsuccessful response into an error, parsing its body into an `ApiError`
+    /// when it holds one. Responses to requests that were rate limited become
+    /// `ClientError::RateLimited`. Those keep their `ApiError` as well.
+    pub(crate) fn from_response(
+        status: http::StatusCode,
+        headers: reqwest::header::HeaderMap,
+        body: &[u8],
+    ) -> Self {
+        let rate_limit = crate::RateLimitInfo::from_headers(&headers).unwrap_or_default();
+        if status == http::StatusCode::TOO_MANY_REQUESTS {
+            return crate::ClientError::RateLimited {
+                status,
+                duration: rate_limit.wait().map(|wait| wait.as_secs()),
+                info: rate_limit,
+                error: ApiError::from_body(body).map(Box::new),
+                headers,
+            };
+        }
+
+        if body.is_empty() {
+            return crate::ClientError::HttpError {
+                status,
+                headers,
+                error: "empty response".into(),
+            };
+        }
+
+        match ApiError::from_body(body) {
+            Some(error) => crate::ClientError::ApiError {
+                status,
+                headers,
+                error: Box::new(error),
+            },
+            None => crate::ClientError::HttpError {
+                status,
+                headers,
+                error: String::from_utf8_lossy(body).into(),
+            },
+        }
+    }
+}
@@ -1,6 +1,161 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`FacebookAds::get_all`], set with a builder.
+pub struct FacebookAdsGetAllRequest<'a> {
+    client: &'a FacebookAds,
+    fields: Option<&'a [String]>,
+    exclude_fields: Option<&'a [String]>,
+    count: Option<i64>,
+    offset: Option<i64>,
+    sort_field: Option<crate::types::GetAllFacebookAdsSortField>,
+    sort_dir: Option<crate::types::SortDir>,
+}
+
+impl<'a> FacebookAdsGetAllRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Sets the `sort_field` query parameter.
+    pub fn sort_field(mut self, sort_field: crate::types::GetAllFacebookAdsSortField) -> Self {
+        self.sort_field = Some(sort_field);
+        self
+    }
+
+    /// Sets the `sort_dir` query parameter.
+    pub fn sort_dir(mut self, sort_dir: crate::types::SortDir) -> Self {
+        self.sort_dir = Some(sort_dir);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::GetAllFacebookAdsResponse>> {
+        let Self {
+            client,
+            fields,
+            exclude_fields,
+            count,
+            offset,
+            sort_field,
+            sort_dir,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = count {
+            query_args.push(("count".to_string(), v.to_string()));
+        }
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = offset {
+            query_args.push(("offset".to_string(), v.to_string()));
+        }
+        if let Some(v) = sort_dir {
+            query_args.push(("sort_dir".to_string(), v.to_string()));
+        }
+        if let Some(v) = sort_field {
+            query_args.push(("sort_field".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/facebook-ads?{}", query_), None);
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`FacebookAds::get`], set with a builder.
+pub struct FacebookAdsGetRequest<'a> {
+    client: &'a FacebookAds,
+    fields: Option<&'a [String]>,
+    outreach_id: &'a str,
+    exclude_fields: Option<&'a [String]>,
+}
+
+impl<'a> FacebookAdsGetRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::FacebookAdsAllOf>> {
+        let Self {
+            client,
+            fields,
+            outreach_id,
+            exclude_fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/facebook-ads/{}?{}",
+                crate::progenitor_support::encode_path(outreach_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct FacebookAds {
     pub client: Client,
 }
@@ -67,6 +222,26 @@ impl FacebookAds {
             )
             .await
     }
+    /**
+     * List facebook ads.
+     *
+     * This function performs a `GET` to the `/facebook-ads` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get list of Facebook ads.
+     */
+    pub fn get_all_request(&self) -> FacebookAdsGetAllRequest<'_> {
+        FacebookAdsGetAllRequest {
+            client: self,
+            fields: None,
+            exclude_fields: None,
+            count: None,
+            offset: None,
+            sort_field: None,
+            sort_dir: None,
+        }
+    }
     /**
      * Get facebook ad info.
      *
@@ -112,4 +287,21 @@ impl FacebookAds {
             )
             .await
     }
+    /**
+     * Get facebook ad info.
+     *
+     * This function performs a `GET` to the `/facebook-ads/{outreach_id}` endpoint.
+     *
+     * As opposed to `get`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get details of a Facebook ad.
+     */
+    pub fn get_request<'a>(&'a self, outreach_id: &'a str) -> FacebookAdsGetRequest<'a> {
+        FacebookAdsGetRequest {
+            client: self,
+            fields: None,
+            outreach_id,
+            exclude_fields: None,
+        }
+    }
 }
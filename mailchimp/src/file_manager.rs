@@ -1,6 +1,369 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`FileManager::get_file`], set with a builder.
+pub struct FileManagerGetFileRequest<'a> {
+    client: &'a FileManager,
+    fields: Option<&'a [String]>,
+    exclude_fields: Option<&'a [String]>,
+    count: Option<i64>,
+    offset: Option<i64>,
+    type_: Option<&'a str>,
+    created_by: Option<&'a str>,
+    before_created_at: Option<&'a str>,
+    since_created_at: Option<&'a str>,
+    sort_field: Option<crate::types::GetFileManagerFilesSortField>,
+    sort_dir: Option<crate::types::SortDir>,
+}
+
+impl<'a> FileManagerGetFileRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Sets the `type` query parameter.
+    pub fn type_(mut self, type_: &'a str) -> Self {
+        self.type_ = Some(type_);
+        self
+    }
+
+    /// Sets the `created_by` query parameter.
+    pub fn created_by(mut self, created_by: &'a str) -> Self {
+        self.created_by = Some(created_by);
+        self
+    }
+
+    /// Sets the `before_created_at` query parameter.
+    pub fn before_created_at(mut self, before_created_at: &'a str) -> Self {
+        self.before_created_at = Some(before_created_at);
+        self
+    }
+
+    /// Sets the `since_created_at` query parameter.
+    pub fn since_created_at(mut self, since_created_at: &'a str) -> Self {
+        self.since_created_at = Some(since_created_at);
+        self
+    }
+
+    /// Sets the `sort_field` query parameter.
+    pub fn sort_field(mut self, sort_field: crate::types::GetFileManagerFilesSortField) -> Self {
+        self.sort_field = Some(sort_field);
+        self
+    }
+
+    /// Sets the `sort_dir` query parameter.
+    pub fn sort_dir(mut self, sort_dir: crate::types::SortDir) -> Self {
+        self.sort_dir = Some(sort_dir);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::FileManager>> {
+        let Self {
+            client,
+            fields,
+            exclude_fields,
+            count,
+            offset,
+            type_,
+            created_by,
+            before_created_at,
+            since_created_at,
+            sort_field,
+            sort_dir,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = before_created_at {
+            query_args.push(("before_created_at".to_string(), v.to_string()));
+        }
+        if let Some(v) = count {
+            query_args.push(("count".to_string(), v.to_string()));
+        }
+        if let Some(v) = created_by {
+            query_args.push(("created_by".to_string(), v.to_string()));
+        }
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = offset {
+            query_args.push(("offset".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_created_at {
+            query_args.push(("since_created_at".to_string(), v.to_string()));
+        }
+        if let Some(v) = sort_dir {
+            query_args.push(("sort_dir".to_string(), v.to_string()));
+        }
+        if let Some(v) = sort_field {
+            query_args.push(("sort_field".to_string(), v.to_string()));
+        }
+        if let Some(v) = type_ {
+            query_args.push(("type".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/file-manager/files?{}", query_), None);
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`FileManager::get_file_file_manager`], set with a builder.
+pub struct FileManagerGetFileFileManagerRequest<'a> {
+    client: &'a FileManager,
+    fields: Option<&'a [String]>,
+    exclude_fields: Option<&'a [String]>,
+    file_id: &'a str,
+}
+
+impl<'a> FileManagerGetFileFileManagerRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::Files>> {
+        let Self {
+            client,
+            fields,
+            exclude_fields,
+            file_id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/file-manager/files/{}?{}",
+                crate::progenitor_support::encode_path(file_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`FileManager::get_folder`], set with a builder.
+pub struct FileManagerGetFolderRequest<'a> {
+    client: &'a FileManager,
+    fields: Option<&'a [String]>,
+    exclude_fields: Option<&'a [String]>,
+    count: Option<i64>,
+    offset: Option<i64>,
+    created_by: Option<&'a str>,
+    before_created_at: Option<&'a str>,
+    since_created_at: Option<&'a str>,
+}
+
+impl<'a> FileManagerGetFolderRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Sets the `created_by` query parameter.
+    pub fn created_by(mut self, created_by: &'a str) -> Self {
+        self.created_by = Some(created_by);
+        self
+    }
+
+    /// Sets the `before_created_at` query parameter.
+    pub fn before_created_at(mut self, before_created_at: &'a str) -> Self {
+        self.before_created_at = Some(before_created_at);
+        self
+    }
+
+    /// Sets the `since_created_at` query parameter.
+    pub fn since_created_at(mut self, since_created_at: &'a str) -> Self {
+        self.since_created_at = Some(since_created_at);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::FileManagerFolders>> {
+        let Self {
+            client,
+            fields,
+            exclude_fields,
+            count,
+            offset,
+            created_by,
+            before_created_at,
+            since_created_at,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = before_created_at {
+            query_args.push(("before_created_at".to_string(), v.to_string()));
+        }
+        if let Some(v) = count {
+            query_args.push(("count".to_string(), v.to_string()));
+        }
+        if let Some(v) = created_by {
+            query_args.push(("created_by".to_string(), v.to_string()));
+        }
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = offset {
+            query_args.push(("offset".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_created_at {
+            query_args.push(("since_created_at".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/file-manager/folders?{}", query_), None);
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`FileManager::get_folder_file_manager`], set with a builder.
+pub struct FileManagerGetFolderFileManagerRequest<'a> {
+    client: &'a FileManager,
+    fields: Option<&'a [String]>,
+    exclude_fields: Option<&'a [String]>,
+    folder_id: &'a str,
+}
+
+impl<'a> FileManagerGetFolderFileManagerRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::FileManagerFoldersGalleryFolder>> {
+        let Self {
+            client,
+            fields,
+            exclude_fields,
+            folder_id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/file-manager/folders/{}?{}",
+                crate::progenitor_support::encode_path(folder_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct FileManager {
     pub client: Client,
 }
@@ -92,6 +455,30 @@ impl FileManager {
             )
             .await
     }
+    /**
+     * List stored files.
+     *
+     * This function performs a `GET` to the `/file-manager/files` endpoint.
+     *
+     * As opposed to `get_file`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get a list of available images and files stored in the File Manager for the account.
+     */
+    pub fn get_file_request(&self) -> FileManagerGetFileRequest<'_> {
+        FileManagerGetFileRequest {
+            client: self,
+            fields: None,
+            exclude_fields: None,
+            count: None,
+            offset: None,
+            type_: None,
+            created_by: None,
+            before_created_at: None,
+            since_created_at: None,
+            sort_field: None,
+            sort_dir: None,
+        }
+    }
     /**
      * Add file.
      *
@@ -159,6 +546,26 @@ impl FileManager {
             )
             .await
     }
+    /**
+     * Get file.
+     *
+     * This function performs a `GET` to the `/file-manager/files/{file_id}` endpoint.
+     *
+     * As opposed to `get_file_file_manager`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get information about a specific file in the File Manager.
+     */
+    pub fn get_file_file_manager_request<'a>(
+        &'a self,
+        file_id: &'a str,
+    ) -> FileManagerGetFileFileManagerRequest<'a> {
+        FileManagerGetFileFileManagerRequest {
+            client: self,
+            fields: None,
+            exclude_fields: None,
+            file_id,
+        }
+    }
     /**
      * Delete file.
      *
@@ -287,6 +694,27 @@ impl FileManager {
             )
             .await
     }
+    /**
+     * List folders.
+     *
+     * This function performs a `GET` to the `/file-manager/folders` endpoint.
+     *
+     * As opposed to `get_folder`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get a list of all folders in the File Manager.
+     */
+    pub fn get_folder_request(&self) -> FileManagerGetFolderRequest<'_> {
+        FileManagerGetFolderRequest {
+            client: self,
+            fields: None,
+            exclude_fields: None,
+            count: None,
+            offset: None,
+            created_by: None,
+            before_created_at: None,
+            since_created_at: None,
+        }
+    }
     /**
      * Add folder.
      *
@@ -354,6 +782,26 @@ impl FileManager {
             )
             .await
     }
+    /**
+     * Get folder.
+     *
+     * This function performs a `GET` to the `/file-manager/folders/{folder_id}` endpoint.
+     *
+     * As opposed to `get_folder_file_manager`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get information about a specific folder in the File Manager.
+     */
+    pub fn get_folder_file_manager_request<'a>(
+        &'a self,
+        folder_id: &'a str,
+    ) -> FileManagerGetFolderFileManagerRequest<'a> {
+        FileManagerGetFolderFileManagerRequest {
+            client: self,
+            fields: None,
+            exclude_fields: None,
+            folder_id,
+        }
+    }
     /**
      * Delete folder.
      *
@@ -1,6 +1,250 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`LandingPages::get_all`], set with a builder.
+pub struct LandingPagesGetAllRequest<'a> {
+    client: &'a LandingPages,
+    sort_dir: Option<crate::types::SortDir>,
+    sort_field: Option<crate::types::GetAllLandingPagesSortField>,
+    fields: Option<&'a [String]>,
+    exclude_fields: Option<&'a [String]>,
+    count: Option<i64>,
+}
+
+impl<'a> LandingPagesGetAllRequest<'a> {
+    /// Sets the `sort_dir` query parameter.
+    pub fn sort_dir(mut self, sort_dir: crate::types::SortDir) -> Self {
+        self.sort_dir = Some(sort_dir);
+        self
+    }
+
+    /// Sets the `sort_field` query parameter.
+    pub fn sort_field(mut self, sort_field: crate::types::GetAllLandingPagesSortField) -> Self {
+        self.sort_field = Some(sort_field);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::GetAllLandingPagesResponse>> {
+        let Self {
+            client,
+            sort_dir,
+            sort_field,
+            fields,
+            exclude_fields,
+            count,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = count {
+            query_args.push(("count".to_string(), v.to_string()));
+        }
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = sort_dir {
+            query_args.push(("sort_dir".to_string(), v.to_string()));
+        }
+        if let Some(v) = sort_field {
+            query_args.push(("sort_field".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/landing-pages?{}", query_), None);
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`LandingPages::post_all`], set with a builder.
+pub struct LandingPagesPostAllRequest<'a> {
+    client: &'a LandingPages,
+    use_default_list: Option<bool>,
+    body: &'a crate::types::LandingPageData,
+}
+
+impl<'a> LandingPagesPostAllRequest<'a> {
+    /// Sets the `use_default_list` query parameter.
+    pub fn use_default_list(mut self, use_default_list: bool) -> Self {
+        self.use_default_list = Some(use_default_list);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::LandingPage>> {
+        let Self {
+            client,
+            use_default_list,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = use_default_list {
+            query_args.push(("use_default_list".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/landing-pages?{}", query_), None);
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`LandingPages::get`], set with a builder.
+pub struct LandingPagesGetRequest<'a> {
+    client: &'a LandingPages,
+    fields: Option<&'a [String]>,
+    exclude_fields: Option<&'a [String]>,
+    page_id: &'a str,
+}
+
+impl<'a> LandingPagesGetRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::LandingPage>> {
+        let Self {
+            client,
+            fields,
+            exclude_fields,
+            page_id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/landing-pages/{}?{}",
+                crate::progenitor_support::encode_path(page_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`LandingPages::get_content`], set with a builder.
+pub struct LandingPagesGetContentRequest<'a> {
+    client: &'a LandingPages,
+    fields: Option<&'a [String]>,
+    exclude_fields: Option<&'a [String]>,
+    page_id: &'a str,
+}
+
+impl<'a> LandingPagesGetContentRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::LandingPageContent>> {
+        let Self {
+            client,
+            fields,
+            exclude_fields,
+            page_id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/landing-pages/{}/content?{}",
+                crate::progenitor_support::encode_path(page_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct LandingPages {
     pub client: Client,
 }
@@ -62,6 +306,25 @@ impl LandingPages {
             )
             .await
     }
+    /**
+     * List landing pages.
+     *
+     * This function performs a `GET` to the `/landing-pages` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get all landing pages.
+     */
+    pub fn get_all_request(&self) -> LandingPagesGetAllRequest<'_> {
+        LandingPagesGetAllRequest {
+            client: self,
+            sort_dir: None,
+            sort_field: None,
+            fields: None,
+            exclude_fields: None,
+            count: None,
+        }
+    }
     /**
      * Add landing page.
      *
@@ -94,6 +357,25 @@ impl LandingPages {
             )
             .await
     }
+    /**
+     * Add landing page.
+     *
+     * This function performs a `POST` to the `/landing-pages` endpoint.
+     *
+     * As opposed to `post_all`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Create a new Mailchimp landing page.
+     */
+    pub fn post_all_request<'a>(
+        &'a self,
+        body: &'a crate::types::LandingPageData,
+    ) -> LandingPagesPostAllRequest<'a> {
+        LandingPagesPostAllRequest {
+            client: self,
+            use_default_list: None,
+            body,
+        }
+    }
     /**
      * Get landing page info.
      *
@@ -139,6 +421,23 @@ impl LandingPages {
             )
             .await
     }
+    /**
+     * Get landing page info.
+     *
+     * This function performs a `GET` to the `/landing-pages/{page_id}` endpoint.
+     *
+     * As opposed to `get`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get information about a specific page.
+     */
+    pub fn get_request<'a>(&'a self, page_id: &'a str) -> LandingPagesGetRequest<'a> {
+        LandingPagesGetRequest {
+            client: self,
+            fields: None,
+            exclude_fields: None,
+            page_id,
+        }
+    }
     /**
      * Delete landing page.
      *
@@ -307,4 +606,24 @@ impl LandingPages {
             )
             .await
     }
+    /**
+     * Get landing page content.
+     *
+     * This function performs a `GET` to the `/landing-pages/{page_id}/content` endpoint.
+     *
+     * As opposed to `get_content`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get the the HTML for your landing page.
+     */
+    pub fn get_content_request<'a>(
+        &'a self,
+        page_id: &'a str,
+    ) -> LandingPagesGetContentRequest<'a> {
+        LandingPagesGetContentRequest {
+            client: self,
+            fields: None,
+            exclude_fields: None,
+            page_id,
+        }
+    }
 }
@@ -1,6 +1,2721 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Lists::get`], set with a builder.
+pub struct ListsGetRequest<'a> {
+    client: &'a Lists,
+    fields: Option<&'a [String]>,
+    exclude_fields: Option<&'a [String]>,
+    count: Option<i64>,
+    offset: Option<i64>,
+    before_date_created: Option<&'a str>,
+    since_date_created: Option<&'a str>,
+    before_campaign_last_sent: Option<&'a str>,
+    since_campaign_last_sent: Option<&'a str>,
+    email: Option<&'a str>,
+    sort_field: Option<crate::types::GetListsSortField>,
+    sort_dir: Option<crate::types::SortDir>,
+    has_ecommerce_store: Option<bool>,
+    include_total_contacts: Option<bool>,
+}
+
+impl<'a> ListsGetRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Sets the `before_date_created` query parameter.
+    pub fn before_date_created(mut self, before_date_created: &'a str) -> Self {
+        self.before_date_created = Some(before_date_created);
+        self
+    }
+
+    /// Sets the `since_date_created` query parameter.
+    pub fn since_date_created(mut self, since_date_created: &'a str) -> Self {
+        self.since_date_created = Some(since_date_created);
+        self
+    }
+
+    /// Sets the `before_campaign_last_sent` query parameter.
+    pub fn before_campaign_last_sent(mut self, before_campaign_last_sent: &'a str) -> Self {
+        self.before_campaign_last_sent = Some(before_campaign_last_sent);
+        self
+    }
+
+    /// Sets the `since_campaign_last_sent` query parameter.
+    pub fn since_campaign_last_sent(mut self, since_campaign_last_sent: &'a str) -> Self {
+        self.since_campaign_last_sent = Some(since_campaign_last_sent);
+        self
+    }
+
+    /// Sets the `email` query parameter.
+    pub fn email(mut self, email: &'a str) -> Self {
+        self.email = Some(email);
+        self
+    }
+
+    /// Sets the `sort_field` query parameter.
+    pub fn sort_field(mut self, sort_field: crate::types::GetListsSortField) -> Self {
+        self.sort_field = Some(sort_field);
+        self
+    }
+
+    /// Sets the `sort_dir` query parameter.
+    pub fn sort_dir(mut self, sort_dir: crate::types::SortDir) -> Self {
+        self.sort_dir = Some(sort_dir);
+        self
+    }
+
+    /// Sets the `has_ecommerce_store` query parameter.
+    pub fn has_ecommerce_store(mut self, has_ecommerce_store: bool) -> Self {
+        self.has_ecommerce_store = Some(has_ecommerce_store);
+        self
+    }
+
+    /// Sets the `include_total_contacts` query parameter.
+    pub fn include_total_contacts(mut self, include_total_contacts: bool) -> Self {
+        self.include_total_contacts = Some(include_total_contacts);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::SubscriberLists>> {
+        let Self {
+            client,
+            fields,
+            exclude_fields,
+            count,
+            offset,
+            before_date_created,
+            since_date_created,
+            before_campaign_last_sent,
+            since_campaign_last_sent,
+            email,
+            sort_field,
+            sort_dir,
+            has_ecommerce_store,
+            include_total_contacts,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = before_campaign_last_sent {
+            query_args.push(("before_campaign_last_sent".to_string(), v.to_string()));
+        }
+        if let Some(v) = before_date_created {
+            query_args.push(("before_date_created".to_string(), v.to_string()));
+        }
+        if let Some(v) = count {
+            query_args.push(("count".to_string(), v.to_string()));
+        }
+        if let Some(v) = email {
+            query_args.push(("email".to_string(), v.to_string()));
+        }
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = has_ecommerce_store {
+            query_args.push(("has_ecommerce_store".to_string(), v.to_string()));
+        }
+        if let Some(v) = include_total_contacts {
+            query_args.push(("include_total_contacts".to_string(), v.to_string()));
+        }
+        if let Some(v) = offset {
+            query_args.push(("offset".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_campaign_last_sent {
+            query_args.push(("since_campaign_last_sent".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_date_created {
+            query_args.push(("since_date_created".to_string(), v.to_string()));
+        }
+        if let Some(v) = sort_dir {
+            query_args.push(("sort_dir".to_string(), v.to_string()));
+        }
+        if let Some(v) = sort_field {
+            query_args.push(("sort_field".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(&format!("/lists?{}", query_), None);
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Lists::get_lists`], set with a builder.
+pub struct ListsGetListsRequest<'a> {
+    client: &'a Lists,
+    fields: Option<&'a [String]>,
+    exclude_fields: Option<&'a [String]>,
+    list_id: &'a str,
+    include_total_contacts: Option<bool>,
+}
+
+impl<'a> ListsGetListsRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sets the `include_total_contacts` query parameter.
+    pub fn include_total_contacts(mut self, include_total_contacts: bool) -> Self {
+        self.include_total_contacts = Some(include_total_contacts);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::Lists>> {
+        let Self {
+            client,
+            fields,
+            exclude_fields,
+            list_id,
+            include_total_contacts,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = include_total_contacts {
+            query_args.push(("include_total_contacts".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/lists/{}?{}",
+                crate::progenitor_support::encode_path(list_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Lists::post_lists`], set with a builder.
+pub struct ListsPostListsRequest<'a> {
+    client: &'a Lists,
+    list_id: &'a str,
+    skip_merge_validation: Option<bool>,
+    skip_duplicate_check: Option<bool>,
+    body: &'a crate::types::MembersSubscribeUnsubscribeFromAListInBatch,
+}
+
+impl<'a> ListsPostListsRequest<'a> {
+    /// Sets the `skip_merge_validation` query parameter.
+    pub fn skip_merge_validation(mut self, skip_merge_validation: bool) -> Self {
+        self.skip_merge_validation = Some(skip_merge_validation);
+        self
+    }
+
+    /// Sets the `skip_duplicate_check` query parameter.
+    pub fn skip_duplicate_check(mut self, skip_duplicate_check: bool) -> Self {
+        self.skip_duplicate_check = Some(skip_duplicate_check);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::BatchUpdateListMembers>> {
+        let Self {
+            client,
+            list_id,
+            skip_merge_validation,
+            skip_duplicate_check,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = skip_duplicate_check {
+            query_args.push(("skip_duplicate_check".to_string(), v.to_string()));
+        }
+        if let Some(v) = skip_merge_validation {
+            query_args.push(("skip_merge_validation".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/lists/{}?{}",
+                crate::progenitor_support::encode_path(list_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Lists::get_abuse_report`], set with a builder.
+pub struct ListsGetAbuseReportRequest<'a> {
+    client: &'a Lists,
+    fields: Option<&'a [String]>,
+    exclude_fields: Option<&'a [String]>,
+    count: Option<i64>,
+    offset: Option<i64>,
+    list_id: &'a str,
+}
+
+impl<'a> ListsGetAbuseReportRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::AbuseComplaints>> {
+        let Self {
+            client,
+            fields,
+            exclude_fields,
+            count,
+            offset,
+            list_id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = count {
+            query_args.push(("count".to_string(), v.to_string()));
+        }
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = offset {
+            query_args.push(("offset".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/lists/{}/abuse-reports?{}",
+                crate::progenitor_support::encode_path(list_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Lists::get_abuse_report_lists`], set with a builder.
+pub struct ListsGetAbuseReportListsRequest<'a> {
+    client: &'a Lists,
+    fields: Option<&'a [String]>,
+    exclude_fields: Option<&'a [String]>,
+    count: Option<i64>,
+    offset: Option<i64>,
+    list_id: &'a str,
+    report_id: &'a str,
+}
+
+impl<'a> ListsGetAbuseReportListsRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::AbuseReports>> {
+        let Self {
+            client,
+            fields,
+            exclude_fields,
+            count,
+            offset,
+            list_id,
+            report_id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = count {
+            query_args.push(("count".to_string(), v.to_string()));
+        }
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = offset {
+            query_args.push(("offset".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/lists/{}/abuse-reports/{}?{}",
+                crate::progenitor_support::encode_path(list_id),
+                crate::progenitor_support::encode_path(report_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Lists::get_activity`], set with a builder.
+pub struct ListsGetActivityRequest<'a> {
+    client: &'a Lists,
+    fields: Option<&'a [String]>,
+    exclude_fields: Option<&'a [String]>,
+    list_id: &'a str,
+}
+
+impl<'a> ListsGetActivityRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::ListActivity>> {
+        let Self {
+            client,
+            fields,
+            exclude_fields,
+            list_id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/lists/{}/activity?{}",
+                crate::progenitor_support::encode_path(list_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Lists::get_client`], set with a builder.
+pub struct ListsGetClientRequest<'a> {
+    client: &'a Lists,
+    fields: Option<&'a [String]>,
+    exclude_fields: Option<&'a [String]>,
+    list_id: &'a str,
+}
+
+impl<'a> ListsGetClientRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::EmailClients>> {
+        let Self {
+            client,
+            fields,
+            exclude_fields,
+            list_id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/lists/{}/clients?{}",
+                crate::progenitor_support::encode_path(list_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Lists::get_growth_history`], set with a builder.
+pub struct ListsGetGrowthHistoryRequest<'a> {
+    client: &'a Lists,
+    fields: Option<&'a [String]>,
+    exclude_fields: Option<&'a [String]>,
+    count: Option<i64>,
+    offset: Option<i64>,
+    list_id: &'a str,
+    sort_field: Option<crate::types::GetListsGrowthHistorySortField>,
+    sort_dir: Option<crate::types::SortDir>,
+}
+
+impl<'a> ListsGetGrowthHistoryRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Sets the `sort_field` query parameter.
+    pub fn sort_field(mut self, sort_field: crate::types::GetListsGrowthHistorySortField) -> Self {
+        self.sort_field = Some(sort_field);
+        self
+    }
+
+    /// Sets the `sort_dir` query parameter.
+    pub fn sort_dir(mut self, sort_dir: crate::types::SortDir) -> Self {
+        self.sort_dir = Some(sort_dir);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::GrowthHistory>> {
+        let Self {
+            client,
+            fields,
+            exclude_fields,
+            count,
+            offset,
+            list_id,
+            sort_field,
+            sort_dir,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = count {
+            query_args.push(("count".to_string(), v.to_string()));
+        }
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = offset {
+            query_args.push(("offset".to_string(), v.to_string()));
+        }
+        if let Some(v) = sort_dir {
+            query_args.push(("sort_dir".to_string(), v.to_string()));
+        }
+        if let Some(v) = sort_field {
+            query_args.push(("sort_field".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/lists/{}/growth-history?{}",
+                crate::progenitor_support::encode_path(list_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Lists::get_growth_history_lists`], set with a builder.
+pub struct ListsGetGrowthHistoryListsRequest<'a> {
+    client: &'a Lists,
+    fields: Option<&'a [String]>,
+    exclude_fields: Option<&'a [String]>,
+    list_id: &'a str,
+    month: &'a str,
+}
+
+impl<'a> ListsGetGrowthHistoryListsRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::History>> {
+        let Self {
+            client,
+            fields,
+            exclude_fields,
+            list_id,
+            month,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/lists/{}/growth-history/{}?{}",
+                crate::progenitor_support::encode_path(list_id),
+                crate::progenitor_support::encode_path(month),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Lists::get_interest_categorie`], set with a builder.
+pub struct ListsGetInterestCategorieRequest<'a> {
+    client: &'a Lists,
+    list_id: &'a str,
+    fields: Option<&'a [String]>,
+    exclude_fields: Option<&'a [String]>,
+    count: Option<i64>,
+    offset: Option<i64>,
+    type_: Option<&'a str>,
+}
+
+impl<'a> ListsGetInterestCategorieRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Sets the `type` query parameter.
+    pub fn type_(mut self, type_: &'a str) -> Self {
+        self.type_ = Some(type_);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::InterestGroupings>> {
+        let Self {
+            client,
+            list_id,
+            fields,
+            exclude_fields,
+            count,
+            offset,
+            type_,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = count {
+            query_args.push(("count".to_string(), v.to_string()));
+        }
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = offset {
+            query_args.push(("offset".to_string(), v.to_string()));
+        }
+        if let Some(v) = type_ {
+            query_args.push(("type".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/lists/{}/interest-categories?{}",
+                crate::progenitor_support::encode_path(list_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Lists::get_interest_categorie_lists`], set with a builder.
+pub struct ListsGetInterestCategorieListsRequest<'a> {
+    client: &'a Lists,
+    list_id: &'a str,
+    interest_category_id: &'a str,
+    fields: Option<&'a [String]>,
+    exclude_fields: Option<&'a [String]>,
+}
+
+impl<'a> ListsGetInterestCategorieListsRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::Categories>> {
+        let Self {
+            client,
+            list_id,
+            interest_category_id,
+            fields,
+            exclude_fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/lists/{}/interest-categories/{}?{}",
+                crate::progenitor_support::encode_path(list_id),
+                crate::progenitor_support::encode_path(interest_category_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Lists::get_interest_categories_interest`], set with a builder.
+pub struct ListsGetInterestCategoriesInterestRequest<'a> {
+    client: &'a Lists,
+    list_id: &'a str,
+    interest_category_id: &'a str,
+    fields: Option<&'a [String]>,
+    exclude_fields: Option<&'a [String]>,
+    count: Option<i64>,
+    offset: Option<i64>,
+}
+
+impl<'a> ListsGetInterestCategoriesInterestRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::InterestsData>> {
+        let Self {
+            client,
+            list_id,
+            interest_category_id,
+            fields,
+            exclude_fields,
+            count,
+            offset,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = count {
+            query_args.push(("count".to_string(), v.to_string()));
+        }
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = offset {
+            query_args.push(("offset".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/lists/{}/interest-categories/{}/interests?{}",
+                crate::progenitor_support::encode_path(list_id),
+                crate::progenitor_support::encode_path(interest_category_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Lists::get_interest_categories_interest_lists`], set with a builder.
+pub struct ListsGetInterestCategoriesInterestListsRequest<'a> {
+    client: &'a Lists,
+    list_id: &'a str,
+    interest_category_id: &'a str,
+    interest_id: &'a str,
+    fields: Option<&'a [String]>,
+    exclude_fields: Option<&'a [String]>,
+}
+
+impl<'a> ListsGetInterestCategoriesInterestListsRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::InterestsInterest>> {
+        let Self {
+            client,
+            list_id,
+            interest_category_id,
+            interest_id,
+            fields,
+            exclude_fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/lists/{}/interest-categories/{}/interests/{}?{}",
+                crate::progenitor_support::encode_path(list_id),
+                crate::progenitor_support::encode_path(interest_category_id),
+                crate::progenitor_support::encode_path(interest_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Lists::preview_segment`], set with a builder.
+pub struct ListsPreviewSegmentRequest<'a> {
+    client: &'a Lists,
+    fields: Option<&'a [String]>,
+    exclude_fields: Option<&'a [String]>,
+    count: Option<i64>,
+    offset: Option<i64>,
+    list_id: &'a str,
+    type_: Option<&'a str>,
+    since_created_at: Option<&'a str>,
+    before_created_at: Option<&'a str>,
+    include_cleaned: Option<bool>,
+    include_transactional: Option<bool>,
+    include_unsubscribed: Option<bool>,
+    since_updated_at: Option<&'a str>,
+    before_updated_at: Option<&'a str>,
+}
+
+impl<'a> ListsPreviewSegmentRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Sets the `type` query parameter.
+    pub fn type_(mut self, type_: &'a str) -> Self {
+        self.type_ = Some(type_);
+        self
+    }
+
+    /// Sets the `since_created_at` query parameter.
+    pub fn since_created_at(mut self, since_created_at: &'a str) -> Self {
+        self.since_created_at = Some(since_created_at);
+        self
+    }
+
+    /// Sets the `before_created_at` query parameter.
+    pub fn before_created_at(mut self, before_created_at: &'a str) -> Self {
+        self.before_created_at = Some(before_created_at);
+        self
+    }
+
+    /// Sets the `include_cleaned` query parameter.
+    pub fn include_cleaned(mut self, include_cleaned: bool) -> Self {
+        self.include_cleaned = Some(include_cleaned);
+        self
+    }
+
+    /// Sets the `include_transactional` query parameter.
+    pub fn include_transactional(mut self, include_transactional: bool) -> Self {
+        self.include_transactional = Some(include_transactional);
+        self
+    }
+
+    /// Sets the `include_unsubscribed` query parameter.
+    pub fn include_unsubscribed(mut self, include_unsubscribed: bool) -> Self {
+        self.include_unsubscribed = Some(include_unsubscribed);
+        self
+    }
+
+    /// Sets the `since_updated_at` query parameter.
+    pub fn since_updated_at(mut self, since_updated_at: &'a str) -> Self {
+        self.since_updated_at = Some(since_updated_at);
+        self
+    }
+
+    /// Sets the `before_updated_at` query parameter.
+    pub fn before_updated_at(mut self, before_updated_at: &'a str) -> Self {
+        self.before_updated_at = Some(before_updated_at);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::CollectionOfSegments>> {
+        let Self {
+            client,
+            fields,
+            exclude_fields,
+            count,
+            offset,
+            list_id,
+            type_,
+            since_created_at,
+            before_created_at,
+            include_cleaned,
+            include_transactional,
+            include_unsubscribed,
+            since_updated_at,
+            before_updated_at,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = before_created_at {
+            query_args.push(("before_created_at".to_string(), v.to_string()));
+        }
+        if let Some(v) = before_updated_at {
+            query_args.push(("before_updated_at".to_string(), v.to_string()));
+        }
+        if let Some(v) = count {
+            query_args.push(("count".to_string(), v.to_string()));
+        }
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = include_cleaned {
+            query_args.push(("include_cleaned".to_string(), v.to_string()));
+        }
+        if let Some(v) = include_transactional {
+            query_args.push(("include_transactional".to_string(), v.to_string()));
+        }
+        if let Some(v) = include_unsubscribed {
+            query_args.push(("include_unsubscribed".to_string(), v.to_string()));
+        }
+        if let Some(v) = offset {
+            query_args.push(("offset".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_created_at {
+            query_args.push(("since_created_at".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_updated_at {
+            query_args.push(("since_updated_at".to_string(), v.to_string()));
+        }
+        if let Some(v) = type_ {
+            query_args.push(("type".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/lists/{}/segments?{}",
+                crate::progenitor_support::encode_path(list_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Lists::get_segment`], set with a builder.
+pub struct ListsGetSegmentRequest<'a> {
+    client: &'a Lists,
+    fields: Option<&'a [String]>,
+    exclude_fields: Option<&'a [String]>,
+    list_id: &'a str,
+    segment_id: &'a str,
+    include_cleaned: Option<bool>,
+    include_transactional: Option<bool>,
+    include_unsubscribed: Option<bool>,
+}
+
+impl<'a> ListsGetSegmentRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sets the `include_cleaned` query parameter.
+    pub fn include_cleaned(mut self, include_cleaned: bool) -> Self {
+        self.include_cleaned = Some(include_cleaned);
+        self
+    }
+
+    /// Sets the `include_transactional` query parameter.
+    pub fn include_transactional(mut self, include_transactional: bool) -> Self {
+        self.include_transactional = Some(include_transactional);
+        self
+    }
+
+    /// Sets the `include_unsubscribed` query parameter.
+    pub fn include_unsubscribed(mut self, include_unsubscribed: bool) -> Self {
+        self.include_unsubscribed = Some(include_unsubscribed);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::Segments>> {
+        let Self {
+            client,
+            fields,
+            exclude_fields,
+            list_id,
+            segment_id,
+            include_cleaned,
+            include_transactional,
+            include_unsubscribed,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = include_cleaned {
+            query_args.push(("include_cleaned".to_string(), v.to_string()));
+        }
+        if let Some(v) = include_transactional {
+            query_args.push(("include_transactional".to_string(), v.to_string()));
+        }
+        if let Some(v) = include_unsubscribed {
+            query_args.push(("include_unsubscribed".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/lists/{}/segments/{}?{}",
+                crate::progenitor_support::encode_path(list_id),
+                crate::progenitor_support::encode_path(segment_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Lists::get_segments_member`], set with a builder.
+pub struct ListsGetSegmentsMemberRequest<'a> {
+    client: &'a Lists,
+    fields: Option<&'a [String]>,
+    exclude_fields: Option<&'a [String]>,
+    count: Option<i64>,
+    offset: Option<i64>,
+    list_id: &'a str,
+    segment_id: &'a str,
+    include_cleaned: Option<bool>,
+    include_transactional: Option<bool>,
+    include_unsubscribed: Option<bool>,
+}
+
+impl<'a> ListsGetSegmentsMemberRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Sets the `include_cleaned` query parameter.
+    pub fn include_cleaned(mut self, include_cleaned: bool) -> Self {
+        self.include_cleaned = Some(include_cleaned);
+        self
+    }
+
+    /// Sets the `include_transactional` query parameter.
+    pub fn include_transactional(mut self, include_transactional: bool) -> Self {
+        self.include_transactional = Some(include_transactional);
+        self
+    }
+
+    /// Sets the `include_unsubscribed` query parameter.
+    pub fn include_unsubscribed(mut self, include_unsubscribed: bool) -> Self {
+        self.include_unsubscribed = Some(include_unsubscribed);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::SegmentMembers>> {
+        let Self {
+            client,
+            fields,
+            exclude_fields,
+            count,
+            offset,
+            list_id,
+            segment_id,
+            include_cleaned,
+            include_transactional,
+            include_unsubscribed,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = count {
+            query_args.push(("count".to_string(), v.to_string()));
+        }
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = include_cleaned {
+            query_args.push(("include_cleaned".to_string(), v.to_string()));
+        }
+        if let Some(v) = include_transactional {
+            query_args.push(("include_transactional".to_string(), v.to_string()));
+        }
+        if let Some(v) = include_unsubscribed {
+            query_args.push(("include_unsubscribed".to_string(), v.to_string()));
+        }
+        if let Some(v) = offset {
+            query_args.push(("offset".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/lists/{}/segments/{}/members?{}",
+                crate::progenitor_support::encode_path(list_id),
+                crate::progenitor_support::encode_path(segment_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Lists::search_tags_name`], set with a builder.
+pub struct ListsSearchTagsNameRequest<'a> {
+    client: &'a Lists,
+    list_id: &'a str,
+    name: Option<&'a str>,
+}
+
+impl<'a> ListsSearchTagsNameRequest<'a> {
+    /// Sets the `name` query parameter.
+    pub fn name(mut self, name: &'a str) -> Self {
+        self.name = Some(name);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::TagSearchResults>> {
+        let Self {
+            client,
+            list_id,
+            name,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = name {
+            query_args.push(("name".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/lists/{}/tag-search?{}",
+                crate::progenitor_support::encode_path(list_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Lists::get_member`], set with a builder.
+pub struct ListsGetMemberRequest<'a> {
+    client: &'a Lists,
+    fields: Option<&'a [String]>,
+    exclude_fields: Option<&'a [String]>,
+    count: Option<i64>,
+    offset: Option<i64>,
+    list_id: &'a str,
+    email_type: Option<&'a str>,
+    status: Option<crate::types::GetListsMembersStatus>,
+    since_timestamp_opt: Option<&'a str>,
+    before_timestamp_opt: Option<&'a str>,
+    since_last_changed: Option<&'a str>,
+    before_last_changed: Option<&'a str>,
+    unique_email_id: Option<&'a str>,
+    vip_only: Option<bool>,
+    interest_category_id: Option<&'a str>,
+    interest_ids: Option<&'a str>,
+    interest_match: Option<crate::types::InterestMatch>,
+    sort_field: Option<crate::types::GetListsMembersSortField>,
+    sort_dir: Option<crate::types::SortDir>,
+    since_last_campaign: Option<bool>,
+    unsubscribed_since: Option<&'a str>,
+}
+
+impl<'a> ListsGetMemberRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Sets the `email_type` query parameter.
+    pub fn email_type(mut self, email_type: &'a str) -> Self {
+        self.email_type = Some(email_type);
+        self
+    }
+
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: crate::types::GetListsMembersStatus) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Sets the `since_timestamp_opt` query parameter.
+    pub fn since_timestamp_opt(mut self, since_timestamp_opt: &'a str) -> Self {
+        self.since_timestamp_opt = Some(since_timestamp_opt);
+        self
+    }
+
+    /// Sets the `before_timestamp_opt` query parameter.
+    pub fn before_timestamp_opt(mut self, before_timestamp_opt: &'a str) -> Self {
+        self.before_timestamp_opt = Some(before_timestamp_opt);
+        self
+    }
+
+    /// Sets the `since_last_changed` query parameter.
+    pub fn since_last_changed(mut self, since_last_changed: &'a str) -> Self {
+        self.since_last_changed = Some(since_last_changed);
+        self
+    }
+
+    /// Sets the `before_last_changed` query parameter.
+    pub fn before_last_changed(mut self, before_last_changed: &'a str) -> Self {
+        self.before_last_changed = Some(before_last_changed);
+        self
+    }
+
+    /// Sets the `unique_email_id` query parameter.
+    pub fn unique_email_id(mut self, unique_email_id: &'a str) -> Self {
+        self.unique_email_id = Some(unique_email_id);
+        self
+    }
+
+    /// Sets the `vip_only` query parameter.
+    pub fn vip_only(mut self, vip_only: bool) -> Self {
+        self.vip_only = Some(vip_only);
+        self
+    }
+
+    /// Sets the `interest_category_id` query parameter.
+    pub fn interest_category_id(mut self, interest_category_id: &'a str) -> Self {
+        self.interest_category_id = Some(interest_category_id);
+        self
+    }
+
+    /// Sets the `interest_ids` query parameter.
+    pub fn interest_ids(mut self, interest_ids: &'a str) -> Self {
+        self.interest_ids = Some(interest_ids);
+        self
+    }
+
+    /// Sets the `interest_match` query parameter.
+    pub fn interest_match(mut self, interest_match: crate::types::InterestMatch) -> Self {
+        self.interest_match = Some(interest_match);
+        self
+    }
+
+    /// Sets the `sort_field` query parameter.
+    pub fn sort_field(mut self, sort_field: crate::types::GetListsMembersSortField) -> Self {
+        self.sort_field = Some(sort_field);
+        self
+    }
+
+    /// Sets the `sort_dir` query parameter.
+    pub fn sort_dir(mut self, sort_dir: crate::types::SortDir) -> Self {
+        self.sort_dir = Some(sort_dir);
+        self
+    }
+
+    /// Sets the `since_last_campaign` query parameter.
+    pub fn since_last_campaign(mut self, since_last_campaign: bool) -> Self {
+        self.since_last_campaign = Some(since_last_campaign);
+        self
+    }
+
+    /// Sets the `unsubscribed_since` query parameter.
+    pub fn unsubscribed_since(mut self, unsubscribed_since: &'a str) -> Self {
+        self.unsubscribed_since = Some(unsubscribed_since);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::ListMembersDataType>> {
+        let Self {
+            client,
+            fields,
+            exclude_fields,
+            count,
+            offset,
+            list_id,
+            email_type,
+            status,
+            since_timestamp_opt,
+            before_timestamp_opt,
+            since_last_changed,
+            before_last_changed,
+            unique_email_id,
+            vip_only,
+            interest_category_id,
+            interest_ids,
+            interest_match,
+            sort_field,
+            sort_dir,
+            since_last_campaign,
+            unsubscribed_since,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = before_last_changed {
+            query_args.push(("before_last_changed".to_string(), v.to_string()));
+        }
+        if let Some(v) = before_timestamp_opt {
+            query_args.push(("before_timestamp_opt".to_string(), v.to_string()));
+        }
+        if let Some(v) = count {
+            query_args.push(("count".to_string(), v.to_string()));
+        }
+        if let Some(v) = email_type {
+            query_args.push(("email_type".to_string(), v.to_string()));
+        }
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = interest_category_id {
+            query_args.push(("interest_category_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = interest_ids {
+            query_args.push(("interest_ids".to_string(), v.to_string()));
+        }
+        if let Some(v) = interest_match {
+            query_args.push(("interest_match".to_string(), v.to_string()));
+        }
+        if let Some(v) = offset {
+            query_args.push(("offset".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_last_campaign {
+            query_args.push(("since_last_campaign".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_last_changed {
+            query_args.push(("since_last_changed".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_timestamp_opt {
+            query_args.push(("since_timestamp_opt".to_string(), v.to_string()));
+        }
+        if let Some(v) = sort_dir {
+            query_args.push(("sort_dir".to_string(), v.to_string()));
+        }
+        if let Some(v) = sort_field {
+            query_args.push(("sort_field".to_string(), v.to_string()));
+        }
+        if let Some(v) = status {
+            query_args.push(("status".to_string(), v.to_string()));
+        }
+        if let Some(v) = unique_email_id {
+            query_args.push(("unique_email_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = unsubscribed_since {
+            query_args.push(("unsubscribed_since".to_string(), v.to_string()));
+        }
+        if let Some(v) = vip_only {
+            query_args.push(("vip_only".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/lists/{}/members?{}",
+                crate::progenitor_support::encode_path(list_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Lists::post_member`], set with a builder.
+pub struct ListsPostMemberRequest<'a> {
+    client: &'a Lists,
+    list_id: &'a str,
+    skip_merge_validation: Option<bool>,
+    body: &'a crate::types::AddListMembers,
+}
+
+impl<'a> ListsPostMemberRequest<'a> {
+    /// Sets the `skip_merge_validation` query parameter.
+    pub fn skip_merge_validation(mut self, skip_merge_validation: bool) -> Self {
+        self.skip_merge_validation = Some(skip_merge_validation);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::ListMembersData>> {
+        let Self {
+            client,
+            list_id,
+            skip_merge_validation,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = skip_merge_validation {
+            query_args.push(("skip_merge_validation".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/lists/{}/members?{}",
+                crate::progenitor_support::encode_path(list_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Lists::get_member_lists`], set with a builder.
+pub struct ListsGetMemberListsRequest<'a> {
+    client: &'a Lists,
+    fields: Option<&'a [String]>,
+    exclude_fields: Option<&'a [String]>,
+    list_id: &'a str,
+    subscriber_hash: &'a str,
+}
+
+impl<'a> ListsGetMemberListsRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::ListMembersData>> {
+        let Self {
+            client,
+            fields,
+            exclude_fields,
+            list_id,
+            subscriber_hash,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/lists/{}/members/{}?{}",
+                crate::progenitor_support::encode_path(list_id),
+                crate::progenitor_support::encode_path(subscriber_hash),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Lists::put_members`], set with a builder.
+pub struct ListsPutMembersRequest<'a> {
+    client: &'a Lists,
+    list_id: &'a str,
+    subscriber_hash: &'a str,
+    skip_merge_validation: Option<bool>,
+    body: &'a crate::types::AddListMembersData,
+}
+
+impl<'a> ListsPutMembersRequest<'a> {
+    /// Sets the `skip_merge_validation` query parameter.
+    pub fn skip_merge_validation(mut self, skip_merge_validation: bool) -> Self {
+        self.skip_merge_validation = Some(skip_merge_validation);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::ListMembersData>> {
+        let Self {
+            client,
+            list_id,
+            subscriber_hash,
+            skip_merge_validation,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = skip_merge_validation {
+            query_args.push(("skip_merge_validation".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/lists/{}/members/{}?{}",
+                crate::progenitor_support::encode_path(list_id),
+                crate::progenitor_support::encode_path(subscriber_hash),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .put(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Lists::patch_members`], set with a builder.
+pub struct ListsPatchMembersRequest<'a> {
+    client: &'a Lists,
+    list_id: &'a str,
+    subscriber_hash: &'a str,
+    skip_merge_validation: Option<bool>,
+    body: &'a crate::types::AddListMembersDataType,
+}
+
+impl<'a> ListsPatchMembersRequest<'a> {
+    /// Sets the `skip_merge_validation` query parameter.
+    pub fn skip_merge_validation(mut self, skip_merge_validation: bool) -> Self {
+        self.skip_merge_validation = Some(skip_merge_validation);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::ListMembersData>> {
+        let Self {
+            client,
+            list_id,
+            subscriber_hash,
+            skip_merge_validation,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = skip_merge_validation {
+            query_args.push(("skip_merge_validation".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/lists/{}/members/{}?{}",
+                crate::progenitor_support::encode_path(list_id),
+                crate::progenitor_support::encode_path(subscriber_hash),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .patch(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Lists::get_members_activity`], set with a builder.
+pub struct ListsGetMembersActivityRequest<'a> {
+    client: &'a Lists,
+    list_id: &'a str,
+    subscriber_hash: &'a str,
+    fields: Option<&'a [String]>,
+    exclude_fields: Option<&'a [String]>,
+    action: Option<&'a [String]>,
+}
+
+impl<'a> ListsGetMembersActivityRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sets the `action` query parameter.
+    pub fn action(mut self, action: &'a [String]) -> Self {
+        self.action = Some(action);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::MemberActivityEvents>> {
+        let Self {
+            client,
+            list_id,
+            subscriber_hash,
+            fields,
+            exclude_fields,
+            action,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = action {
+            query_args.push(("action".to_string(), v.join(" ")));
+        }
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/lists/{}/members/{}/activity?{}",
+                crate::progenitor_support::encode_path(list_id),
+                crate::progenitor_support::encode_path(subscriber_hash),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Lists::get_members_activity_feed`], set with a builder.
+pub struct ListsGetMembersActivityFeedRequest<'a> {
+    client: &'a Lists,
+    list_id: &'a str,
+    subscriber_hash: &'a str,
+    fields: Option<&'a [String]>,
+    exclude_fields: Option<&'a [String]>,
+    count: Option<i64>,
+    offset: Option<i64>,
+    activity_filters: Option<&'a [String]>,
+}
+
+impl<'a> ListsGetMembersActivityFeedRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Sets the `activity_filters` query parameter.
+    pub fn activity_filters(mut self, activity_filters: &'a [String]) -> Self {
+        self.activity_filters = Some(activity_filters);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::MemberActivityEventsData>> {
+        let Self {
+            client,
+            list_id,
+            subscriber_hash,
+            fields,
+            exclude_fields,
+            count,
+            offset,
+            activity_filters,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = activity_filters {
+            query_args.push(("activity_filters".to_string(), v.join(" ")));
+        }
+        if let Some(v) = count {
+            query_args.push(("count".to_string(), v.to_string()));
+        }
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = offset {
+            query_args.push(("offset".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/lists/{}/members/{}/activity-feed?{}",
+                crate::progenitor_support::encode_path(list_id),
+                crate::progenitor_support::encode_path(subscriber_hash),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Lists::get_member_tag`], set with a builder.
+pub struct ListsGetMemberTagRequest<'a> {
+    client: &'a Lists,
+    list_id: &'a str,
+    subscriber_hash: &'a str,
+    fields: Option<&'a [String]>,
+    exclude_fields: Option<&'a [String]>,
+    count: Option<i64>,
+    offset: Option<i64>,
+}
+
+impl<'a> ListsGetMemberTagRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::CollectionOfTags>> {
+        let Self {
+            client,
+            list_id,
+            subscriber_hash,
+            fields,
+            exclude_fields,
+            count,
+            offset,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = count {
+            query_args.push(("count".to_string(), v.to_string()));
+        }
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = offset {
+            query_args.push(("offset".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/lists/{}/members/{}/tags?{}",
+                crate::progenitor_support::encode_path(list_id),
+                crate::progenitor_support::encode_path(subscriber_hash),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Lists::get_members_event`], set with a builder.
+pub struct ListsGetMembersEventRequest<'a> {
+    client: &'a Lists,
+    list_id: &'a str,
+    subscriber_hash: &'a str,
+    count: Option<i64>,
+    offset: Option<i64>,
+    fields: Option<&'a [String]>,
+    exclude_fields: Option<&'a [String]>,
+}
+
+impl<'a> ListsGetMembersEventRequest<'a> {
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::CollectionOfEvents>> {
+        let Self {
+            client,
+            list_id,
+            subscriber_hash,
+            count,
+            offset,
+            fields,
+            exclude_fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = count {
+            query_args.push(("count".to_string(), v.to_string()));
+        }
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = offset {
+            query_args.push(("offset".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/lists/{}/members/{}/events?{}",
+                crate::progenitor_support::encode_path(list_id),
+                crate::progenitor_support::encode_path(subscriber_hash),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Lists::get_members_goal`], set with a builder.
+pub struct ListsGetMembersGoalRequest<'a> {
+    client: &'a Lists,
+    list_id: &'a str,
+    subscriber_hash: &'a str,
+    fields: Option<&'a [String]>,
+    exclude_fields: Option<&'a [String]>,
+}
+
+impl<'a> ListsGetMembersGoalRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::CollectionOfMemberActivityEvents>> {
+        let Self {
+            client,
+            list_id,
+            subscriber_hash,
+            fields,
+            exclude_fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/lists/{}/members/{}/goals?{}",
+                crate::progenitor_support::encode_path(list_id),
+                crate::progenitor_support::encode_path(subscriber_hash),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Lists::get_members_note`], set with a builder.
+pub struct ListsGetMembersNoteRequest<'a> {
+    client: &'a Lists,
+    list_id: &'a str,
+    subscriber_hash: &'a str,
+    sort_field: Option<crate::types::GetListsMembersNotesSortField>,
+    sort_dir: Option<crate::types::SortDir>,
+    fields: Option<&'a [String]>,
+    exclude_fields: Option<&'a [String]>,
+    count: Option<i64>,
+    offset: Option<i64>,
+}
+
+impl<'a> ListsGetMembersNoteRequest<'a> {
+    /// Sets the `sort_field` query parameter.
+    pub fn sort_field(mut self, sort_field: crate::types::GetListsMembersNotesSortField) -> Self {
+        self.sort_field = Some(sort_field);
+        self
+    }
+
+    /// Sets the `sort_dir` query parameter.
+    pub fn sort_dir(mut self, sort_dir: crate::types::SortDir) -> Self {
+        self.sort_dir = Some(sort_dir);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::CollectionOfNotes>> {
+        let Self {
+            client,
+            list_id,
+            subscriber_hash,
+            sort_field,
+            sort_dir,
+            fields,
+            exclude_fields,
+            count,
+            offset,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = count {
+            query_args.push(("count".to_string(), v.to_string()));
+        }
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = offset {
+            query_args.push(("offset".to_string(), v.to_string()));
+        }
+        if let Some(v) = sort_dir {
+            query_args.push(("sort_dir".to_string(), v.to_string()));
+        }
+        if let Some(v) = sort_field {
+            query_args.push(("sort_field".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/lists/{}/members/{}/notes?{}",
+                crate::progenitor_support::encode_path(list_id),
+                crate::progenitor_support::encode_path(subscriber_hash),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Lists::get_members_note_lists`], set with a builder.
+pub struct ListsGetMembersNoteListsRequest<'a> {
+    client: &'a Lists,
+    list_id: &'a str,
+    subscriber_hash: &'a str,
+    note_id: &'a str,
+    fields: Option<&'a [String]>,
+    exclude_fields: Option<&'a [String]>,
+}
+
+impl<'a> ListsGetMembersNoteListsRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::CollectionOfNotesMember>> {
+        let Self {
+            client,
+            list_id,
+            subscriber_hash,
+            note_id,
+            fields,
+            exclude_fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/lists/{}/members/{}/notes/{}?{}",
+                crate::progenitor_support::encode_path(list_id),
+                crate::progenitor_support::encode_path(subscriber_hash),
+                crate::progenitor_support::encode_path(note_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Lists::get_merge_field`], set with a builder.
+pub struct ListsGetMergeFieldRequest<'a> {
+    client: &'a Lists,
+    list_id: &'a str,
+    fields: Option<&'a [String]>,
+    exclude_fields: Option<&'a [String]>,
+    count: Option<i64>,
+    offset: Option<i64>,
+    type_: Option<&'a str>,
+    required: Option<bool>,
+}
+
+impl<'a> ListsGetMergeFieldRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Sets the `type` query parameter.
+    pub fn type_(mut self, type_: &'a str) -> Self {
+        self.type_ = Some(type_);
+        self
+    }
+
+    /// Sets the `required` query parameter.
+    pub fn required(mut self, required: bool) -> Self {
+        self.required = Some(required);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::CollectionOfMergeFields>> {
+        let Self {
+            client,
+            list_id,
+            fields,
+            exclude_fields,
+            count,
+            offset,
+            type_,
+            required,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = count {
+            query_args.push(("count".to_string(), v.to_string()));
+        }
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = offset {
+            query_args.push(("offset".to_string(), v.to_string()));
+        }
+        if let Some(v) = required {
+            query_args.push(("required".to_string(), v.to_string()));
+        }
+        if let Some(v) = type_ {
+            query_args.push(("type".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/lists/{}/merge-fields?{}",
+                crate::progenitor_support::encode_path(list_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Lists::get_merge_field_lists`], set with a builder.
+pub struct ListsGetMergeFieldListsRequest<'a> {
+    client: &'a Lists,
+    list_id: &'a str,
+    merge_id: &'a str,
+    exclude_fields: Option<&'a [String]>,
+    fields: Option<&'a [String]>,
+}
+
+impl<'a> ListsGetMergeFieldListsRequest<'a> {
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::MergeField>> {
+        let Self {
+            client,
+            list_id,
+            merge_id,
+            exclude_fields,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/lists/{}/merge-fields/{}?{}",
+                crate::progenitor_support::encode_path(list_id),
+                crate::progenitor_support::encode_path(merge_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Lists::get_location`], set with a builder.
+pub struct ListsGetLocationRequest<'a> {
+    client: &'a Lists,
+    fields: Option<&'a [String]>,
+    exclude_fields: Option<&'a [String]>,
+    list_id: &'a str,
+}
+
+impl<'a> ListsGetLocationRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::ListLocations>> {
+        let Self {
+            client,
+            fields,
+            exclude_fields,
+            list_id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/lists/{}/locations?{}",
+                crate::progenitor_support::encode_path(list_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct Lists {
     pub client: Client,
 }
@@ -120,6 +2835,33 @@ impl Lists {
             )
             .await
     }
+    /**
+     * Get lists info.
+     *
+     * This function performs a `GET` to the `/lists` endpoint.
+     *
+     * As opposed to `get`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get information about all lists in the account.
+     */
+    pub fn get_request(&self) -> ListsGetRequest<'_> {
+        ListsGetRequest {
+            client: self,
+            fields: None,
+            exclude_fields: None,
+            count: None,
+            offset: None,
+            before_date_created: None,
+            since_date_created: None,
+            before_campaign_last_sent: None,
+            since_campaign_last_sent: None,
+            email: None,
+            sort_field: None,
+            sort_dir: None,
+            has_ecommerce_store: None,
+            include_total_contacts: None,
+        }
+    }
     /**
      * Add list.
      *
@@ -195,6 +2937,24 @@ impl Lists {
             )
             .await
     }
+    /**
+     * Get list info.
+     *
+     * This function performs a `GET` to the `/lists/{list_id}` endpoint.
+     *
+     * As opposed to `get_lists`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get information about a specific list in your Mailchimp account. Results include list members who have signed up but haven't confirmed their subscription yet and unsubscribed or cleaned.
+     */
+    pub fn get_lists_request<'a>(&'a self, list_id: &'a str) -> ListsGetListsRequest<'a> {
+        ListsGetListsRequest {
+            client: self,
+            fields: None,
+            exclude_fields: None,
+            list_id,
+            include_total_contacts: None,
+        }
+    }
     /**
      * Batch subscribe or unsubscribe.
      *
@@ -247,6 +3007,28 @@ impl Lists {
             )
             .await
     }
+    /**
+     * Batch subscribe or unsubscribe.
+     *
+     * This function performs a `POST` to the `/lists/{list_id}` endpoint.
+     *
+     * As opposed to `post_lists`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Batch subscribe or unsubscribe list members.
+     */
+    pub fn post_lists_request<'a>(
+        &'a self,
+        list_id: &'a str,
+        body: &'a crate::types::MembersSubscribeUnsubscribeFromAListInBatch,
+    ) -> ListsPostListsRequest<'a> {
+        ListsPostListsRequest {
+            client: self,
+            list_id,
+            skip_merge_validation: None,
+            skip_duplicate_check: None,
+            body,
+        }
+    }
     /**
      * Delete list.
      *
@@ -358,6 +3140,28 @@ impl Lists {
             )
             .await
     }
+    /**
+     * List abuse reports.
+     *
+     * This function performs a `GET` to the `/lists/{list_id}/abuse-reports` endpoint.
+     *
+     * As opposed to `get_abuse_report`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get all abuse reports for a specific list.
+     */
+    pub fn get_abuse_report_request<'a>(
+        &'a self,
+        list_id: &'a str,
+    ) -> ListsGetAbuseReportRequest<'a> {
+        ListsGetAbuseReportRequest {
+            client: self,
+            fields: None,
+            exclude_fields: None,
+            count: None,
+            offset: None,
+            list_id,
+        }
+    }
     /**
      * Get abuse report.
      *
@@ -416,6 +3220,30 @@ impl Lists {
             )
             .await
     }
+    /**
+     * Get abuse report.
+     *
+     * This function performs a `GET` to the `/lists/{list_id}/abuse-reports/{report_id}` endpoint.
+     *
+     * As opposed to `get_abuse_report_lists`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get details about a specific abuse report.
+     */
+    pub fn get_abuse_report_lists_request<'a>(
+        &'a self,
+        list_id: &'a str,
+        report_id: &'a str,
+    ) -> ListsGetAbuseReportListsRequest<'a> {
+        ListsGetAbuseReportListsRequest {
+            client: self,
+            fields: None,
+            exclude_fields: None,
+            count: None,
+            offset: None,
+            list_id,
+            report_id,
+        }
+    }
     /**
      * List recent activity.
      *
@@ -461,6 +3289,23 @@ impl Lists {
             )
             .await
     }
+    /**
+     * List recent activity.
+     *
+     * This function performs a `GET` to the `/lists/{list_id}/activity` endpoint.
+     *
+     * As opposed to `get_activity`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get up to the previous 180 days of daily detailed aggregated activity stats for a list, not including Automation activity.
+     */
+    pub fn get_activity_request<'a>(&'a self, list_id: &'a str) -> ListsGetActivityRequest<'a> {
+        ListsGetActivityRequest {
+            client: self,
+            fields: None,
+            exclude_fields: None,
+            list_id,
+        }
+    }
     /**
      * List top email clients.
      *
@@ -506,6 +3351,23 @@ impl Lists {
             )
             .await
     }
+    /**
+     * List top email clients.
+     *
+     * This function performs a `GET` to the `/lists/{list_id}/clients` endpoint.
+     *
+     * As opposed to `get_client`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get a list of the top email clients based on user-agent strings.
+     */
+    pub fn get_client_request<'a>(&'a self, list_id: &'a str) -> ListsGetClientRequest<'a> {
+        ListsGetClientRequest {
+            client: self,
+            fields: None,
+            exclude_fields: None,
+            list_id,
+        }
+    }
     /**
      * List growth history data.
      *
@@ -571,6 +3433,30 @@ impl Lists {
             )
             .await
     }
+    /**
+     * List growth history data.
+     *
+     * This function performs a `GET` to the `/lists/{list_id}/growth-history` endpoint.
+     *
+     * As opposed to `get_growth_history`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get a month-by-month summary of a specific list's growth activity.
+     */
+    pub fn get_growth_history_request<'a>(
+        &'a self,
+        list_id: &'a str,
+    ) -> ListsGetGrowthHistoryRequest<'a> {
+        ListsGetGrowthHistoryRequest {
+            client: self,
+            fields: None,
+            exclude_fields: None,
+            count: None,
+            offset: None,
+            list_id,
+            sort_field: None,
+            sort_dir: None,
+        }
+    }
     /**
      * Get growth history by month.
      *
@@ -619,6 +3505,28 @@ impl Lists {
             )
             .await
     }
+    /**
+     * Get growth history by month.
+     *
+     * This function performs a `GET` to the `/lists/{list_id}/growth-history/{month}` endpoint.
+     *
+     * As opposed to `get_growth_history_lists`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get a summary of a specific list's growth activity for a specific month and year.
+     */
+    pub fn get_growth_history_lists_request<'a>(
+        &'a self,
+        list_id: &'a str,
+        month: &'a str,
+    ) -> ListsGetGrowthHistoryListsRequest<'a> {
+        ListsGetGrowthHistoryListsRequest {
+            client: self,
+            fields: None,
+            exclude_fields: None,
+            list_id,
+            month,
+        }
+    }
     /**
      * List interest categories.
      *
@@ -679,6 +3587,29 @@ impl Lists {
             )
             .await
     }
+    /**
+     * List interest categories.
+     *
+     * This function performs a `GET` to the `/lists/{list_id}/interest-categories` endpoint.
+     *
+     * As opposed to `get_interest_categorie`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get information about a list's interest categories.
+     */
+    pub fn get_interest_categorie_request<'a>(
+        &'a self,
+        list_id: &'a str,
+    ) -> ListsGetInterestCategorieRequest<'a> {
+        ListsGetInterestCategorieRequest {
+            client: self,
+            list_id,
+            fields: None,
+            exclude_fields: None,
+            count: None,
+            offset: None,
+            type_: None,
+        }
+    }
     /**
      * Add interest category.
      *
@@ -760,6 +3691,28 @@ impl Lists {
             )
             .await
     }
+    /**
+     * Get interest category info.
+     *
+     * This function performs a `GET` to the `/lists/{list_id}/interest-categories/{interest_category_id}` endpoint.
+     *
+     * As opposed to `get_interest_categorie_lists`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get information about a specific interest category.
+     */
+    pub fn get_interest_categorie_lists_request<'a>(
+        &'a self,
+        list_id: &'a str,
+        interest_category_id: &'a str,
+    ) -> ListsGetInterestCategorieListsRequest<'a> {
+        ListsGetInterestCategorieListsRequest {
+            client: self,
+            list_id,
+            interest_category_id,
+            fields: None,
+            exclude_fields: None,
+        }
+    }
     /**
      * Delete interest category.
      *
@@ -889,6 +3842,30 @@ impl Lists {
             )
             .await
     }
+    /**
+     * List interests in category.
+     *
+     * This function performs a `GET` to the `/lists/{list_id}/interest-categories/{interest_category_id}/interests` endpoint.
+     *
+     * As opposed to `get_interest_categories_interest`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get a list of this category's interests.
+     */
+    pub fn get_interest_categories_interest_request<'a>(
+        &'a self,
+        list_id: &'a str,
+        interest_category_id: &'a str,
+    ) -> ListsGetInterestCategoriesInterestRequest<'a> {
+        ListsGetInterestCategoriesInterestRequest {
+            client: self,
+            list_id,
+            interest_category_id,
+            fields: None,
+            exclude_fields: None,
+            count: None,
+            offset: None,
+        }
+    }
     /**
      * Add interest in category.
      *
@@ -976,6 +3953,30 @@ impl Lists {
             )
             .await
     }
+    /**
+     * Get interest in category.
+     *
+     * This function performs a `GET` to the `/lists/{list_id}/interest-categories/{interest_category_id}/interests/{interest_id}` endpoint.
+     *
+     * As opposed to `get_interest_categories_interest_lists`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get interests or 'group names' for a specific category.
+     */
+    pub fn get_interest_categories_interest_lists_request<'a>(
+        &'a self,
+        list_id: &'a str,
+        interest_category_id: &'a str,
+        interest_id: &'a str,
+    ) -> ListsGetInterestCategoriesInterestListsRequest<'a> {
+        ListsGetInterestCategoriesInterestListsRequest {
+            client: self,
+            list_id,
+            interest_category_id,
+            interest_id,
+            fields: None,
+            exclude_fields: None,
+        }
+    }
     /**
      * Delete interest in category.
      *
@@ -1160,6 +4161,36 @@ impl Lists {
             )
             .await
     }
+    /**
+     * List segments.
+     *
+     * This function performs a `GET` to the `/lists/{list_id}/segments` endpoint.
+     *
+     * As opposed to `preview_segment`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get information about all available segments for a specific list.
+     */
+    pub fn preview_segment_request<'a>(
+        &'a self,
+        list_id: &'a str,
+    ) -> ListsPreviewSegmentRequest<'a> {
+        ListsPreviewSegmentRequest {
+            client: self,
+            fields: None,
+            exclude_fields: None,
+            count: None,
+            offset: None,
+            list_id,
+            type_: None,
+            since_created_at: None,
+            before_created_at: None,
+            include_cleaned: None,
+            include_transactional: None,
+            include_unsubscribed: None,
+            since_updated_at: None,
+            before_updated_at: None,
+        }
+    }
     /**
      * Add segment.
      *
@@ -1262,6 +4293,31 @@ impl Lists {
             )
             .await
     }
+    /**
+     * Get segment info.
+     *
+     * This function performs a `GET` to the `/lists/{list_id}/segments/{segment_id}` endpoint.
+     *
+     * As opposed to `get_segment`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get information about a specific segment.
+     */
+    pub fn get_segment_request<'a>(
+        &'a self,
+        list_id: &'a str,
+        segment_id: &'a str,
+    ) -> ListsGetSegmentRequest<'a> {
+        ListsGetSegmentRequest {
+            client: self,
+            fields: None,
+            exclude_fields: None,
+            list_id,
+            segment_id,
+            include_cleaned: None,
+            include_transactional: None,
+            include_unsubscribed: None,
+        }
+    }
     /**
      * Batch add or remove members.
      *
@@ -1449,6 +4505,33 @@ impl Lists {
             )
             .await
     }
+    /**
+     * List members in segment.
+     *
+     * This function performs a `GET` to the `/lists/{list_id}/segments/{segment_id}/members` endpoint.
+     *
+     * As opposed to `get_segments_member`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get information about members in a saved segment.
+     */
+    pub fn get_segments_member_request<'a>(
+        &'a self,
+        list_id: &'a str,
+        segment_id: &'a str,
+    ) -> ListsGetSegmentsMemberRequest<'a> {
+        ListsGetSegmentsMemberRequest {
+            client: self,
+            fields: None,
+            exclude_fields: None,
+            count: None,
+            offset: None,
+            list_id,
+            segment_id,
+            include_cleaned: None,
+            include_transactional: None,
+            include_unsubscribed: None,
+        }
+    }
     /**
      * Add member to segment.
      *
@@ -1563,6 +4646,25 @@ impl Lists {
             )
             .await
     }
+    /**
+     * Search for tags on a list by name.
+     *
+     * This function performs a `GET` to the `/lists/{list_id}/tag-search` endpoint.
+     *
+     * As opposed to `search_tags_name`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Search for tags on a list by name. If no name is provided, will return all tags on the list.
+     */
+    pub fn search_tags_name_request<'a>(
+        &'a self,
+        list_id: &'a str,
+    ) -> ListsSearchTagsNameRequest<'a> {
+        ListsSearchTagsNameRequest {
+            client: self,
+            list_id,
+            name: None,
+        }
+    }
     /**
      * List members info.
      *
@@ -1714,6 +4816,40 @@ impl Lists {
             )
             .await
     }
+    /**
+     * List members info.
+     *
+     * This function performs a `GET` to the `/lists/{list_id}/members` endpoint.
+     *
+     * As opposed to `get_member`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get information about members in a specific Mailchimp list.
+     */
+    pub fn get_member_request<'a>(&'a self, list_id: &'a str) -> ListsGetMemberRequest<'a> {
+        ListsGetMemberRequest {
+            client: self,
+            fields: None,
+            exclude_fields: None,
+            count: None,
+            offset: None,
+            list_id,
+            email_type: None,
+            status: None,
+            since_timestamp_opt: None,
+            before_timestamp_opt: None,
+            since_last_changed: None,
+            before_last_changed: None,
+            unique_email_id: None,
+            vip_only: None,
+            interest_category_id: None,
+            interest_ids: None,
+            interest_match: None,
+            sort_field: None,
+            sort_dir: None,
+            since_last_campaign: None,
+            unsubscribed_since: None,
+        }
+    }
     /**
      * Add member to list.
      *
@@ -1758,6 +4894,27 @@ impl Lists {
             )
             .await
     }
+    /**
+     * Add member to list.
+     *
+     * This function performs a `POST` to the `/lists/{list_id}/members` endpoint.
+     *
+     * As opposed to `post_member`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Add a new member to the list.
+     */
+    pub fn post_member_request<'a>(
+        &'a self,
+        list_id: &'a str,
+        body: &'a crate::types::AddListMembers,
+    ) -> ListsPostMemberRequest<'a> {
+        ListsPostMemberRequest {
+            client: self,
+            list_id,
+            skip_merge_validation: None,
+            body,
+        }
+    }
     /**
      * Get member info.
      *
@@ -1806,6 +4963,28 @@ impl Lists {
             )
             .await
     }
+    /**
+     * Get member info.
+     *
+     * This function performs a `GET` to the `/lists/{list_id}/members/{subscriber_hash}` endpoint.
+     *
+     * As opposed to `get_member_lists`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get information about a specific list member, including a currently subscribed, unsubscribed, or bounced member.
+     */
+    pub fn get_member_lists_request<'a>(
+        &'a self,
+        list_id: &'a str,
+        subscriber_hash: &'a str,
+    ) -> ListsGetMemberListsRequest<'a> {
+        ListsGetMemberListsRequest {
+            client: self,
+            fields: None,
+            exclude_fields: None,
+            list_id,
+            subscriber_hash,
+        }
+    }
     /**
      * Add or update list member.
      *
@@ -1853,6 +5032,29 @@ impl Lists {
             )
             .await
     }
+    /**
+     * Add or update list member.
+     *
+     * This function performs a `PUT` to the `/lists/{list_id}/members/{subscriber_hash}` endpoint.
+     *
+     * As opposed to `put_members`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Add or update a list member.
+     */
+    pub fn put_members_request<'a>(
+        &'a self,
+        list_id: &'a str,
+        subscriber_hash: &'a str,
+        body: &'a crate::types::AddListMembersData,
+    ) -> ListsPutMembersRequest<'a> {
+        ListsPutMembersRequest {
+            client: self,
+            list_id,
+            subscriber_hash,
+            skip_merge_validation: None,
+            body,
+        }
+    }
     /**
      * Archive list member.
      *
@@ -1935,6 +5137,29 @@ impl Lists {
             )
             .await
     }
+    /**
+     * Update list member.
+     *
+     * This function performs a `PATCH` to the `/lists/{list_id}/members/{subscriber_hash}` endpoint.
+     *
+     * As opposed to `patch_members`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Update information for a specific list member.
+     */
+    pub fn patch_members_request<'a>(
+        &'a self,
+        list_id: &'a str,
+        subscriber_hash: &'a str,
+        body: &'a crate::types::AddListMembersDataType,
+    ) -> ListsPatchMembersRequest<'a> {
+        ListsPatchMembersRequest {
+            client: self,
+            list_id,
+            subscriber_hash,
+            skip_merge_validation: None,
+            body,
+        }
+    }
     /**
      * View recent activity 50.
      *
@@ -1988,6 +5213,29 @@ impl Lists {
             )
             .await
     }
+    /**
+     * View recent activity 50.
+     *
+     * This function performs a `GET` to the `/lists/{list_id}/members/{subscriber_hash}/activity` endpoint.
+     *
+     * As opposed to `get_members_activity`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get the last 50 events of a member's activity on a specific list, including opens, clicks, and unsubscribes.
+     */
+    pub fn get_members_activity_request<'a>(
+        &'a self,
+        list_id: &'a str,
+        subscriber_hash: &'a str,
+    ) -> ListsGetMembersActivityRequest<'a> {
+        ListsGetMembersActivityRequest {
+            client: self,
+            list_id,
+            subscriber_hash,
+            fields: None,
+            exclude_fields: None,
+            action: None,
+        }
+    }
     /**
      * View recent activity.
      *
@@ -2051,6 +5299,31 @@ impl Lists {
             )
             .await
     }
+    /**
+     * View recent activity.
+     *
+     * This function performs a `GET` to the `/lists/{list_id}/members/{subscriber_hash}/activity-feed` endpoint.
+     *
+     * As opposed to `get_members_activity_feed`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get a member's activity on a specific list, including opens, clicks, and unsubscribes.
+     */
+    pub fn get_members_activity_feed_request<'a>(
+        &'a self,
+        list_id: &'a str,
+        subscriber_hash: &'a str,
+    ) -> ListsGetMembersActivityFeedRequest<'a> {
+        ListsGetMembersActivityFeedRequest {
+            client: self,
+            list_id,
+            subscriber_hash,
+            fields: None,
+            exclude_fields: None,
+            count: None,
+            offset: None,
+            activity_filters: None,
+        }
+    }
     /**
      * List member tags.
      *
@@ -2109,6 +5382,30 @@ impl Lists {
             )
             .await
     }
+    /**
+     * List member tags.
+     *
+     * This function performs a `GET` to the `/lists/{list_id}/members/{subscriber_hash}/tags` endpoint.
+     *
+     * As opposed to `get_member_tag`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get the tags on a list member.
+     */
+    pub fn get_member_tag_request<'a>(
+        &'a self,
+        list_id: &'a str,
+        subscriber_hash: &'a str,
+    ) -> ListsGetMemberTagRequest<'a> {
+        ListsGetMemberTagRequest {
+            client: self,
+            list_id,
+            subscriber_hash,
+            fields: None,
+            exclude_fields: None,
+            count: None,
+            offset: None,
+        }
+    }
     /**
      * Add or remove member tags.
      *
@@ -2203,6 +5500,30 @@ impl Lists {
             )
             .await
     }
+    /**
+     * List member events.
+     *
+     * This function performs a `GET` to the `/lists/{list_id}/members/{subscriber_hash}/events` endpoint.
+     *
+     * As opposed to `get_members_event`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get events for a contact.
+     */
+    pub fn get_members_event_request<'a>(
+        &'a self,
+        list_id: &'a str,
+        subscriber_hash: &'a str,
+    ) -> ListsGetMembersEventRequest<'a> {
+        ListsGetMembersEventRequest {
+            client: self,
+            list_id,
+            subscriber_hash,
+            count: None,
+            offset: None,
+            fields: None,
+            exclude_fields: None,
+        }
+    }
     /**
      * Add event.
      *
@@ -2287,6 +5608,28 @@ impl Lists {
             )
             .await
     }
+    /**
+     * List member goal events.
+     *
+     * This function performs a `GET` to the `/lists/{list_id}/members/{subscriber_hash}/goals` endpoint.
+     *
+     * As opposed to `get_members_goal`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get the last 50 Goal events for a member on a specific list.
+     */
+    pub fn get_members_goal_request<'a>(
+        &'a self,
+        list_id: &'a str,
+        subscriber_hash: &'a str,
+    ) -> ListsGetMembersGoalRequest<'a> {
+        ListsGetMembersGoalRequest {
+            client: self,
+            list_id,
+            subscriber_hash,
+            fields: None,
+            exclude_fields: None,
+        }
+    }
     /**
      * List recent member notes.
      *
@@ -2355,6 +5698,32 @@ impl Lists {
             )
             .await
     }
+    /**
+     * List recent member notes.
+     *
+     * This function performs a `GET` to the `/lists/{list_id}/members/{subscriber_hash}/notes` endpoint.
+     *
+     * As opposed to `get_members_note`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get recent notes for a specific list member.
+     */
+    pub fn get_members_note_request<'a>(
+        &'a self,
+        list_id: &'a str,
+        subscriber_hash: &'a str,
+    ) -> ListsGetMembersNoteRequest<'a> {
+        ListsGetMembersNoteRequest {
+            client: self,
+            list_id,
+            subscriber_hash,
+            sort_field: None,
+            sort_dir: None,
+            fields: None,
+            exclude_fields: None,
+            count: None,
+            offset: None,
+        }
+    }
     /**
      * Add member note.
      *
@@ -2442,6 +5811,30 @@ impl Lists {
             )
             .await
     }
+    /**
+     * Get member note.
+     *
+     * This function performs a `GET` to the `/lists/{list_id}/members/{subscriber_hash}/notes/{note_id}` endpoint.
+     *
+     * As opposed to `get_members_note_lists`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get a specific note for a specific list member.
+     */
+    pub fn get_members_note_lists_request<'a>(
+        &'a self,
+        list_id: &'a str,
+        subscriber_hash: &'a str,
+        note_id: &'a str,
+    ) -> ListsGetMembersNoteListsRequest<'a> {
+        ListsGetMembersNoteListsRequest {
+            client: self,
+            list_id,
+            subscriber_hash,
+            note_id,
+            fields: None,
+            exclude_fields: None,
+        }
+    }
     /**
      * Delete note.
      *
@@ -2619,6 +6012,30 @@ impl Lists {
             )
             .await
     }
+    /**
+     * List merge fields.
+     *
+     * This function performs a `GET` to the `/lists/{list_id}/merge-fields` endpoint.
+     *
+     * As opposed to `get_merge_field`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get a list of all merge fields ([audience fields](https://mailchimp.com/help/getting-started-with-merge-tags/)) for a list.
+     */
+    pub fn get_merge_field_request<'a>(
+        &'a self,
+        list_id: &'a str,
+    ) -> ListsGetMergeFieldRequest<'a> {
+        ListsGetMergeFieldRequest {
+            client: self,
+            list_id,
+            fields: None,
+            exclude_fields: None,
+            count: None,
+            offset: None,
+            type_: None,
+            required: None,
+        }
+    }
     /**
      * Add merge field.
      *
@@ -2700,6 +6117,28 @@ impl Lists {
             )
             .await
     }
+    /**
+     * Get merge field.
+     *
+     * This function performs a `GET` to the `/lists/{list_id}/merge-fields/{merge_id}` endpoint.
+     *
+     * As opposed to `get_merge_field_lists`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get information about a specific merge field ([audience field](https://mailchimp.com/help/getting-started-with-merge-tags/)) in a list.
+     */
+    pub fn get_merge_field_lists_request<'a>(
+        &'a self,
+        list_id: &'a str,
+        merge_id: &'a str,
+    ) -> ListsGetMergeFieldListsRequest<'a> {
+        ListsGetMergeFieldListsRequest {
+            client: self,
+            list_id,
+            merge_id,
+            exclude_fields: None,
+            fields: None,
+        }
+    }
     /**
      * Delete merge field.
      *
@@ -3052,4 +6491,21 @@ impl Lists {
             )
             .await
     }
+    /**
+     * List locations.
+     *
+     * This function performs a `GET` to the `/lists/{list_id}/locations` endpoint.
+     *
+     * As opposed to `get_location`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get the locations (countries) that the list's subscribers have been tagged to based on geocoding their IP address.
+     */
+    pub fn get_location_request<'a>(&'a self, list_id: &'a str) -> ListsGetLocationRequest<'a> {
+        ListsGetLocationRequest {
+            client: self,
+            fields: None,
+            exclude_fields: None,
+            list_id,
+        }
+    }
 }
@@ -1,6 +1,393 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Reporting::get_facebook_ads`], set with a builder.
+pub struct ReportingGetFacebookAdsRequest<'a> {
+    client: &'a Reporting,
+    fields: Option<&'a [String]>,
+    exclude_fields: Option<&'a [String]>,
+    count: Option<i64>,
+    offset: Option<i64>,
+    sort_field: Option<crate::types::GetAllFacebookAdsSortField>,
+    sort_dir: Option<crate::types::SortDir>,
+}
+
+impl<'a> ReportingGetFacebookAdsRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Sets the `sort_field` query parameter.
+    pub fn sort_field(mut self, sort_field: crate::types::GetAllFacebookAdsSortField) -> Self {
+        self.sort_field = Some(sort_field);
+        self
+    }
+
+    /// Sets the `sort_dir` query parameter.
+    pub fn sort_dir(mut self, sort_dir: crate::types::SortDir) -> Self {
+        self.sort_dir = Some(sort_dir);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::GetReportingFacebookAdsResponse>> {
+        let Self {
+            client,
+            fields,
+            exclude_fields,
+            count,
+            offset,
+            sort_field,
+            sort_dir,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = count {
+            query_args.push(("count".to_string(), v.to_string()));
+        }
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = offset {
+            query_args.push(("offset".to_string(), v.to_string()));
+        }
+        if let Some(v) = sort_dir {
+            query_args.push(("sort_dir".to_string(), v.to_string()));
+        }
+        if let Some(v) = sort_field {
+            query_args.push(("sort_field".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/reporting/facebook-ads?{}", query_), None);
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Reporting::get_facebook_ad`], set with a builder.
+pub struct ReportingGetFacebookAdRequest<'a> {
+    client: &'a Reporting,
+    fields: Option<&'a [String]>,
+    outreach_id: &'a str,
+    exclude_fields: Option<&'a [String]>,
+}
+
+impl<'a> ReportingGetFacebookAdRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::GetReportingFacebookAdsResponseAllOf>> {
+        let Self {
+            client,
+            fields,
+            outreach_id,
+            exclude_fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/reporting/facebook-ads/{}?{}",
+                crate::progenitor_support::encode_path(outreach_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Reporting::get_facebook_ads_ecommerce_product_activity`], set with a builder.
+pub struct ReportingGetFacebookAdsEcommerceProductActivityRequest<'a> {
+    client: &'a Reporting,
+    fields: Option<&'a [String]>,
+    exclude_fields: Option<&'a [String]>,
+    count: Option<i64>,
+    offset: Option<i64>,
+    outreach_id: &'a str,
+    sort_field: Option<crate::types::GetReportsEcommerceProductActivitySortField>,
+}
+
+impl<'a> ReportingGetFacebookAdsEcommerceProductActivityRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Sets the `sort_field` query parameter.
+    pub fn sort_field(
+        mut self,
+        sort_field: crate::types::GetReportsEcommerceProductActivitySortField,
+    ) -> Self {
+        self.sort_field = Some(sort_field);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::GetReportsEcommerceProductActivityResponse>>
+    {
+        let Self {
+            client,
+            fields,
+            exclude_fields,
+            count,
+            offset,
+            outreach_id,
+            sort_field,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = count {
+            query_args.push(("count".to_string(), v.to_string()));
+        }
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = offset {
+            query_args.push(("offset".to_string(), v.to_string()));
+        }
+        if let Some(v) = sort_field {
+            query_args.push(("sort_field".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/reporting/facebook-ads/{}/ecommerce-product-activity?{}",
+                crate::progenitor_support::encode_path(outreach_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Reporting::get_landing_page`], set with a builder.
+pub struct ReportingGetLandingPageRequest<'a> {
+    client: &'a Reporting,
+    fields: Option<&'a [String]>,
+    outreach_id: &'a str,
+    exclude_fields: Option<&'a [String]>,
+}
+
+impl<'a> ReportingGetLandingPageRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::LandingPages>> {
+        let Self {
+            client,
+            fields,
+            outreach_id,
+            exclude_fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/reporting/landing-pages/{}?{}",
+                crate::progenitor_support::encode_path(outreach_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Reporting::get_landing_pages`], set with a builder.
+pub struct ReportingGetLandingPagesRequest<'a> {
+    client: &'a Reporting,
+    fields: Option<&'a [String]>,
+    exclude_fields: Option<&'a [String]>,
+    count: Option<i64>,
+    offset: Option<i64>,
+}
+
+impl<'a> ReportingGetLandingPagesRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::GetReportingLandingPagesResponse>> {
+        let Self {
+            client,
+            fields,
+            exclude_fields,
+            count,
+            offset,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = count {
+            query_args.push(("count".to_string(), v.to_string()));
+        }
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = offset {
+            query_args.push(("offset".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/reporting/landing-pages?{}", query_), None);
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct Reporting {
     pub client: Client,
 }
@@ -69,6 +456,26 @@ impl Reporting {
             )
             .await
     }
+    /**
+     * List facebook ads reports.
+     *
+     * This function performs a `GET` to the `/reporting/facebook-ads` endpoint.
+     *
+     * As opposed to `get_facebook_ads`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get reports of Facebook ads.
+     */
+    pub fn get_facebook_ads_request(&self) -> ReportingGetFacebookAdsRequest<'_> {
+        ReportingGetFacebookAdsRequest {
+            client: self,
+            fields: None,
+            exclude_fields: None,
+            count: None,
+            offset: None,
+            sort_field: None,
+            sort_dir: None,
+        }
+    }
     /**
      * Get facebook ad report.
      *
@@ -114,6 +521,26 @@ impl Reporting {
             )
             .await
     }
+    /**
+     * Get facebook ad report.
+     *
+     * This function performs a `GET` to the `/reporting/facebook-ads/{outreach_id}` endpoint.
+     *
+     * As opposed to `get_facebook_ad`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get report of a Facebook ad.
+     */
+    pub fn get_facebook_ad_request<'a>(
+        &'a self,
+        outreach_id: &'a str,
+    ) -> ReportingGetFacebookAdRequest<'a> {
+        ReportingGetFacebookAdRequest {
+            client: self,
+            fields: None,
+            outreach_id,
+            exclude_fields: None,
+        }
+    }
     /**
      * List facebook ecommerce report.
      *
@@ -175,6 +602,29 @@ impl Reporting {
             )
             .await
     }
+    /**
+     * List facebook ecommerce report.
+     *
+     * This function performs a `GET` to the `/reporting/facebook-ads/{outreach_id}/ecommerce-product-activity` endpoint.
+     *
+     * As opposed to `get_facebook_ads_ecommerce_product_activity`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get breakdown of product activity for an outreach.
+     */
+    pub fn get_facebook_ads_ecommerce_product_activity_request<'a>(
+        &'a self,
+        outreach_id: &'a str,
+    ) -> ReportingGetFacebookAdsEcommerceProductActivityRequest<'a> {
+        ReportingGetFacebookAdsEcommerceProductActivityRequest {
+            client: self,
+            fields: None,
+            exclude_fields: None,
+            count: None,
+            offset: None,
+            outreach_id,
+            sort_field: None,
+        }
+    }
     /**
      * Get landing page report.
      *
@@ -220,6 +670,26 @@ impl Reporting {
             )
             .await
     }
+    /**
+     * Get landing page report.
+     *
+     * This function performs a `GET` to the `/reporting/landing-pages/{outreach_id}` endpoint.
+     *
+     * As opposed to `get_landing_page`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get report of a landing page.
+     */
+    pub fn get_landing_page_request<'a>(
+        &'a self,
+        outreach_id: &'a str,
+    ) -> ReportingGetLandingPageRequest<'a> {
+        ReportingGetLandingPageRequest {
+            client: self,
+            fields: None,
+            outreach_id,
+            exclude_fields: None,
+        }
+    }
     /**
      * List landing pages reports.
      *
@@ -268,4 +738,22 @@ impl Reporting {
             )
             .await
     }
+    /**
+     * List landing pages reports.
+     *
+     * This function performs a `GET` to the `/reporting/landing-pages` endpoint.
+     *
+     * As opposed to `get_landing_pages`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get reports of landing pages.
+     */
+    pub fn get_landing_pages_request(&self) -> ReportingGetLandingPagesRequest<'_> {
+        ReportingGetLandingPagesRequest {
+            client: self,
+            fields: None,
+            exclude_fields: None,
+            count: None,
+            offset: None,
+        }
+    }
 }
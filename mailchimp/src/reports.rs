@@ -1,6 +1,1581 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Reports::get`], set with a builder.
+pub struct ReportsGetRequest<'a> {
+    client: &'a Reports,
+    fields: Option<&'a [String]>,
+    exclude_fields: Option<&'a [String]>,
+    count: Option<i64>,
+    offset: Option<i64>,
+    type_: Option<crate::types::CampaignType>,
+    before_send_time: Option<chrono::DateTime<chrono::Utc>>,
+    since_send_time: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+impl<'a> ReportsGetRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Sets the `type` query parameter.
+    pub fn type_(mut self, type_: crate::types::CampaignType) -> Self {
+        self.type_ = Some(type_);
+        self
+    }
+
+    /// Sets the `before_send_time` query parameter.
+    pub fn before_send_time(mut self, before_send_time: chrono::DateTime<chrono::Utc>) -> Self {
+        self.before_send_time = Some(before_send_time);
+        self
+    }
+
+    /// Sets the `since_send_time` query parameter.
+    pub fn since_send_time(mut self, since_send_time: chrono::DateTime<chrono::Utc>) -> Self {
+        self.since_send_time = Some(since_send_time);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::CampaignReportsData>> {
+        let Self {
+            client,
+            fields,
+            exclude_fields,
+            count,
+            offset,
+            type_,
+            before_send_time,
+            since_send_time,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(date) = before_send_time {
+            query_args.push(("before_send_time".to_string(), date.to_rfc3339()));
+        }
+        if let Some(v) = count {
+            query_args.push(("count".to_string(), v.to_string()));
+        }
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = offset {
+            query_args.push(("offset".to_string(), v.to_string()));
+        }
+        if let Some(date) = since_send_time {
+            query_args.push(("since_send_time".to_string(), date.to_rfc3339()));
+        }
+        if let Some(v) = type_ {
+            query_args.push(("type".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(&format!("/reports?{}", query_), None);
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Reports::get_reports`], set with a builder.
+pub struct ReportsGetReportsRequest<'a> {
+    client: &'a Reports,
+    fields: Option<&'a [String]>,
+    exclude_fields: Option<&'a [String]>,
+    campaign_id: &'a str,
+}
+
+impl<'a> ReportsGetReportsRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::Reports>> {
+        let Self {
+            client,
+            fields,
+            exclude_fields,
+            campaign_id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/reports/{}?{}",
+                crate::progenitor_support::encode_path(campaign_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Reports::get_abuse`], set with a builder.
+pub struct ReportsGetAbuseRequest<'a> {
+    client: &'a Reports,
+    fields: Option<&'a [String]>,
+    exclude_fields: Option<&'a [String]>,
+    campaign_id: &'a str,
+}
+
+impl<'a> ReportsGetAbuseRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::AbuseComplaintsData>> {
+        let Self {
+            client,
+            fields,
+            exclude_fields,
+            campaign_id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/reports/{}/abuse-reports?{}",
+                crate::progenitor_support::encode_path(campaign_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Reports::get_abuse_reports`], set with a builder.
+pub struct ReportsGetAbuseReportsRequest<'a> {
+    client: &'a Reports,
+    fields: Option<&'a [String]>,
+    exclude_fields: Option<&'a [String]>,
+    campaign_id: &'a str,
+    report_id: &'a str,
+}
+
+impl<'a> ReportsGetAbuseReportsRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::AbuseComplaint>> {
+        let Self {
+            client,
+            fields,
+            exclude_fields,
+            campaign_id,
+            report_id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/reports/{}/abuse-reports/{}?{}",
+                crate::progenitor_support::encode_path(campaign_id),
+                crate::progenitor_support::encode_path(report_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Reports::get_advice`], set with a builder.
+pub struct ReportsGetAdviceRequest<'a> {
+    client: &'a Reports,
+    fields: Option<&'a [String]>,
+    exclude_fields: Option<&'a [String]>,
+    campaign_id: &'a str,
+}
+
+impl<'a> ReportsGetAdviceRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::CampaignAdviceReport>> {
+        let Self {
+            client,
+            fields,
+            exclude_fields,
+            campaign_id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/reports/{}/advice?{}",
+                crate::progenitor_support::encode_path(campaign_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Reports::get_click_detail`], set with a builder.
+pub struct ReportsGetClickDetailRequest<'a> {
+    client: &'a Reports,
+    fields: Option<&'a [String]>,
+    exclude_fields: Option<&'a [String]>,
+    count: Option<i64>,
+    offset: Option<i64>,
+    campaign_id: &'a str,
+}
+
+impl<'a> ReportsGetClickDetailRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::ClickDetailReport>> {
+        let Self {
+            client,
+            fields,
+            exclude_fields,
+            count,
+            offset,
+            campaign_id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = count {
+            query_args.push(("count".to_string(), v.to_string()));
+        }
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = offset {
+            query_args.push(("offset".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/reports/{}/click-details?{}",
+                crate::progenitor_support::encode_path(campaign_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Reports::get_click_detail_reports`], set with a builder.
+pub struct ReportsGetClickDetailReportsRequest<'a> {
+    client: &'a Reports,
+    fields: Option<&'a [String]>,
+    exclude_fields: Option<&'a [String]>,
+    campaign_id: &'a str,
+    link_id: &'a str,
+}
+
+impl<'a> ReportsGetClickDetailReportsRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::UrlsClicked>> {
+        let Self {
+            client,
+            fields,
+            exclude_fields,
+            campaign_id,
+            link_id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/reports/{}/click-details/{}?{}",
+                crate::progenitor_support::encode_path(campaign_id),
+                crate::progenitor_support::encode_path(link_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Reports::get_click_details_member`], set with a builder.
+pub struct ReportsGetClickDetailsMemberRequest<'a> {
+    client: &'a Reports,
+    fields: Option<&'a [String]>,
+    exclude_fields: Option<&'a [String]>,
+    count: Option<i64>,
+    offset: Option<i64>,
+    campaign_id: &'a str,
+    link_id: &'a str,
+}
+
+impl<'a> ReportsGetClickDetailsMemberRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::ClickDetailMembers>> {
+        let Self {
+            client,
+            fields,
+            exclude_fields,
+            count,
+            offset,
+            campaign_id,
+            link_id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = count {
+            query_args.push(("count".to_string(), v.to_string()));
+        }
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = offset {
+            query_args.push(("offset".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/reports/{}/click-details/{}/members?{}",
+                crate::progenitor_support::encode_path(campaign_id),
+                crate::progenitor_support::encode_path(link_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Reports::get_click_details_member_reports`], set with a builder.
+pub struct ReportsGetClickDetailsMemberReportsRequest<'a> {
+    client: &'a Reports,
+    fields: Option<&'a [String]>,
+    exclude_fields: Option<&'a [String]>,
+    campaign_id: &'a str,
+    link_id: &'a str,
+    subscriber_hash: &'a str,
+}
+
+impl<'a> ReportsGetClickDetailsMemberReportsRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::ClickDetailMember>> {
+        let Self {
+            client,
+            fields,
+            exclude_fields,
+            campaign_id,
+            link_id,
+            subscriber_hash,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/reports/{}/click-details/{}/members/{}?{}",
+                crate::progenitor_support::encode_path(campaign_id),
+                crate::progenitor_support::encode_path(link_id),
+                crate::progenitor_support::encode_path(subscriber_hash),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Reports::get_open_detail`], set with a builder.
+pub struct ReportsGetOpenDetailRequest<'a> {
+    client: &'a Reports,
+    fields: Option<&'a [String]>,
+    exclude_fields: Option<&'a [String]>,
+    count: Option<i64>,
+    offset: Option<i64>,
+    campaign_id: &'a str,
+    since: Option<&'a str>,
+}
+
+impl<'a> ReportsGetOpenDetailRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Sets the `since` query parameter.
+    pub fn since(mut self, since: &'a str) -> Self {
+        self.since = Some(since);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::OpenDetailReport>> {
+        let Self {
+            client,
+            fields,
+            exclude_fields,
+            count,
+            offset,
+            campaign_id,
+            since,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = count {
+            query_args.push(("count".to_string(), v.to_string()));
+        }
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = offset {
+            query_args.push(("offset".to_string(), v.to_string()));
+        }
+        if let Some(v) = since {
+            query_args.push(("since".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/reports/{}/open-details?{}",
+                crate::progenitor_support::encode_path(campaign_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Reports::get_open_details_member`], set with a builder.
+pub struct ReportsGetOpenDetailsMemberRequest<'a> {
+    client: &'a Reports,
+    fields: Option<&'a [String]>,
+    exclude_fields: Option<&'a [String]>,
+    campaign_id: &'a str,
+    subscriber_hash: &'a str,
+}
+
+impl<'a> ReportsGetOpenDetailsMemberRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::OpenActivity>> {
+        let Self {
+            client,
+            fields,
+            exclude_fields,
+            campaign_id,
+            subscriber_hash,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/reports/{}/open-details/{}?{}",
+                crate::progenitor_support::encode_path(campaign_id),
+                crate::progenitor_support::encode_path(subscriber_hash),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Reports::get_domain_performance`], set with a builder.
+pub struct ReportsGetDomainPerformanceRequest<'a> {
+    client: &'a Reports,
+    fields: Option<&'a [String]>,
+    exclude_fields: Option<&'a [String]>,
+    campaign_id: &'a str,
+}
+
+impl<'a> ReportsGetDomainPerformanceRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::DomainPerformance>> {
+        let Self {
+            client,
+            fields,
+            exclude_fields,
+            campaign_id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/reports/{}/domain-performance?{}",
+                crate::progenitor_support::encode_path(campaign_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Reports::get_eepurl`], set with a builder.
+pub struct ReportsGetEepurlRequest<'a> {
+    client: &'a Reports,
+    fields: Option<&'a [String]>,
+    exclude_fields: Option<&'a [String]>,
+    campaign_id: &'a str,
+}
+
+impl<'a> ReportsGetEepurlRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::EepurlActivity>> {
+        let Self {
+            client,
+            fields,
+            exclude_fields,
+            campaign_id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/reports/{}/eepurl?{}",
+                crate::progenitor_support::encode_path(campaign_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Reports::get_email_activity`], set with a builder.
+pub struct ReportsGetEmailActivityRequest<'a> {
+    client: &'a Reports,
+    fields: Option<&'a [String]>,
+    exclude_fields: Option<&'a [String]>,
+    count: Option<i64>,
+    offset: Option<i64>,
+    campaign_id: &'a str,
+    since: Option<&'a str>,
+}
+
+impl<'a> ReportsGetEmailActivityRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Sets the `since` query parameter.
+    pub fn since(mut self, since: &'a str) -> Self {
+        self.since = Some(since);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::EmailActivityData>> {
+        let Self {
+            client,
+            fields,
+            exclude_fields,
+            count,
+            offset,
+            campaign_id,
+            since,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = count {
+            query_args.push(("count".to_string(), v.to_string()));
+        }
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = offset {
+            query_args.push(("offset".to_string(), v.to_string()));
+        }
+        if let Some(v) = since {
+            query_args.push(("since".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/reports/{}/email-activity?{}",
+                crate::progenitor_support::encode_path(campaign_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Reports::get_email_activity_reports`], set with a builder.
+pub struct ReportsGetEmailActivityReportsRequest<'a> {
+    client: &'a Reports,
+    fields: Option<&'a [String]>,
+    exclude_fields: Option<&'a [String]>,
+    campaign_id: &'a str,
+    subscriber_hash: &'a str,
+    since: Option<&'a str>,
+}
+
+impl<'a> ReportsGetEmailActivityReportsRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sets the `since` query parameter.
+    pub fn since(mut self, since: &'a str) -> Self {
+        self.since = Some(since);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::EmailActivity>> {
+        let Self {
+            client,
+            fields,
+            exclude_fields,
+            campaign_id,
+            subscriber_hash,
+            since,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = since {
+            query_args.push(("since".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/reports/{}/email-activity/{}?{}",
+                crate::progenitor_support::encode_path(campaign_id),
+                crate::progenitor_support::encode_path(subscriber_hash),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Reports::get_location`], set with a builder.
+pub struct ReportsGetLocationRequest<'a> {
+    client: &'a Reports,
+    fields: Option<&'a [String]>,
+    exclude_fields: Option<&'a [String]>,
+    campaign_id: &'a str,
+    count: Option<i64>,
+    offset: Option<i64>,
+}
+
+impl<'a> ReportsGetLocationRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::OpenLocationsData>> {
+        let Self {
+            client,
+            fields,
+            exclude_fields,
+            campaign_id,
+            count,
+            offset,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = count {
+            query_args.push(("count".to_string(), v.to_string()));
+        }
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = offset {
+            query_args.push(("offset".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/reports/{}/locations?{}",
+                crate::progenitor_support::encode_path(campaign_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Reports::get_sent`], set with a builder.
+pub struct ReportsGetSentRequest<'a> {
+    client: &'a Reports,
+    fields: Option<&'a [String]>,
+    exclude_fields: Option<&'a [String]>,
+    count: Option<i64>,
+    offset: Option<i64>,
+    campaign_id: &'a str,
+}
+
+impl<'a> ReportsGetSentRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::SentData>> {
+        let Self {
+            client,
+            fields,
+            exclude_fields,
+            count,
+            offset,
+            campaign_id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = count {
+            query_args.push(("count".to_string(), v.to_string()));
+        }
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = offset {
+            query_args.push(("offset".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/reports/{}/sent-to?{}",
+                crate::progenitor_support::encode_path(campaign_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Reports::get_sent_reports`], set with a builder.
+pub struct ReportsGetSentReportsRequest<'a> {
+    client: &'a Reports,
+    fields: Option<&'a [String]>,
+    exclude_fields: Option<&'a [String]>,
+    campaign_id: &'a str,
+    subscriber_hash: &'a str,
+}
+
+impl<'a> ReportsGetSentReportsRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::SentTo>> {
+        let Self {
+            client,
+            fields,
+            exclude_fields,
+            campaign_id,
+            subscriber_hash,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/reports/{}/sent-to/{}?{}",
+                crate::progenitor_support::encode_path(campaign_id),
+                crate::progenitor_support::encode_path(subscriber_hash),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Reports::get_sub`], set with a builder.
+pub struct ReportsGetSubRequest<'a> {
+    client: &'a Reports,
+    fields: Option<&'a [String]>,
+    exclude_fields: Option<&'a [String]>,
+    campaign_id: &'a str,
+}
+
+impl<'a> ReportsGetSubRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::CampaignSubReports>> {
+        let Self {
+            client,
+            fields,
+            exclude_fields,
+            campaign_id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/reports/{}/sub-reports?{}",
+                crate::progenitor_support::encode_path(campaign_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Reports::get_unsubscribed`], set with a builder.
+pub struct ReportsGetUnsubscribedRequest<'a> {
+    client: &'a Reports,
+    fields: Option<&'a [String]>,
+    exclude_fields: Option<&'a [String]>,
+    count: Option<i64>,
+    offset: Option<i64>,
+    campaign_id: &'a str,
+}
+
+impl<'a> ReportsGetUnsubscribedRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::UnsubscribesData>> {
+        let Self {
+            client,
+            fields,
+            exclude_fields,
+            count,
+            offset,
+            campaign_id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = count {
+            query_args.push(("count".to_string(), v.to_string()));
+        }
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = offset {
+            query_args.push(("offset".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/reports/{}/unsubscribed?{}",
+                crate::progenitor_support::encode_path(campaign_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Reports::get_unsubscribed_reports`], set with a builder.
+pub struct ReportsGetUnsubscribedReportsRequest<'a> {
+    client: &'a Reports,
+    fields: Option<&'a [String]>,
+    exclude_fields: Option<&'a [String]>,
+    campaign_id: &'a str,
+    subscriber_hash: &'a str,
+}
+
+impl<'a> ReportsGetUnsubscribedReportsRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::Unsubscribes>> {
+        let Self {
+            client,
+            fields,
+            exclude_fields,
+            campaign_id,
+            subscriber_hash,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/reports/{}/unsubscribed/{}?{}",
+                crate::progenitor_support::encode_path(campaign_id),
+                crate::progenitor_support::encode_path(subscriber_hash),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Reports::get_ecommerce_product_activity`], set with a builder.
+pub struct ReportsGetEcommerceProductActivityRequest<'a> {
+    client: &'a Reports,
+    fields: Option<&'a [String]>,
+    exclude_fields: Option<&'a [String]>,
+    count: Option<i64>,
+    offset: Option<i64>,
+    campaign_id: &'a str,
+    sort_field: Option<crate::types::GetReportsEcommerceProductActivitySortField>,
+}
+
+impl<'a> ReportsGetEcommerceProductActivityRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Sets the `sort_field` query parameter.
+    pub fn sort_field(
+        mut self,
+        sort_field: crate::types::GetReportsEcommerceProductActivitySortField,
+    ) -> Self {
+        self.sort_field = Some(sort_field);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::GetReportsEcommerceProductActivityResponse>>
+    {
+        let Self {
+            client,
+            fields,
+            exclude_fields,
+            count,
+            offset,
+            campaign_id,
+            sort_field,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = count {
+            query_args.push(("count".to_string(), v.to_string()));
+        }
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = offset {
+            query_args.push(("offset".to_string(), v.to_string()));
+        }
+        if let Some(v) = sort_field {
+            query_args.push(("sort_field".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/reports/{}/ecommerce-product-activity?{}",
+                crate::progenitor_support::encode_path(campaign_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct Reports {
     pub client: Client,
 }
@@ -72,6 +1647,27 @@ impl Reports {
             )
             .await
     }
+    /**
+     * List campaign reports.
+     *
+     * This function performs a `GET` to the `/reports` endpoint.
+     *
+     * As opposed to `get`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get campaign reports.
+     */
+    pub fn get_request(&self) -> ReportsGetRequest<'_> {
+        ReportsGetRequest {
+            client: self,
+            fields: None,
+            exclude_fields: None,
+            count: None,
+            offset: None,
+            type_: None,
+            before_send_time: None,
+            since_send_time: None,
+        }
+    }
     /**
      * Get campaign report.
      *
@@ -117,6 +1713,23 @@ impl Reports {
             )
             .await
     }
+    /**
+     * Get campaign report.
+     *
+     * This function performs a `GET` to the `/reports/{campaign_id}` endpoint.
+     *
+     * As opposed to `get_reports`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get report details for a specific sent campaign.
+     */
+    pub fn get_reports_request<'a>(&'a self, campaign_id: &'a str) -> ReportsGetReportsRequest<'a> {
+        ReportsGetReportsRequest {
+            client: self,
+            fields: None,
+            exclude_fields: None,
+            campaign_id,
+        }
+    }
     /**
      * List abuse reports.
      *
@@ -162,6 +1775,23 @@ impl Reports {
             )
             .await
     }
+    /**
+     * List abuse reports.
+     *
+     * This function performs a `GET` to the `/reports/{campaign_id}/abuse-reports` endpoint.
+     *
+     * As opposed to `get_abuse`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get a list of abuse complaints for a specific campaign.
+     */
+    pub fn get_abuse_request<'a>(&'a self, campaign_id: &'a str) -> ReportsGetAbuseRequest<'a> {
+        ReportsGetAbuseRequest {
+            client: self,
+            fields: None,
+            exclude_fields: None,
+            campaign_id,
+        }
+    }
     /**
      * Get abuse report.
      *
@@ -210,6 +1840,28 @@ impl Reports {
             )
             .await
     }
+    /**
+     * Get abuse report.
+     *
+     * This function performs a `GET` to the `/reports/{campaign_id}/abuse-reports/{report_id}` endpoint.
+     *
+     * As opposed to `get_abuse_reports`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get information about a specific abuse report for a campaign.
+     */
+    pub fn get_abuse_reports_request<'a>(
+        &'a self,
+        campaign_id: &'a str,
+        report_id: &'a str,
+    ) -> ReportsGetAbuseReportsRequest<'a> {
+        ReportsGetAbuseReportsRequest {
+            client: self,
+            fields: None,
+            exclude_fields: None,
+            campaign_id,
+            report_id,
+        }
+    }
     /**
      * List campaign feedback.
      *
@@ -255,6 +1907,23 @@ impl Reports {
             )
             .await
     }
+    /**
+     * List campaign feedback.
+     *
+     * This function performs a `GET` to the `/reports/{campaign_id}/advice` endpoint.
+     *
+     * As opposed to `get_advice`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get feedback based on a campaign's statistics. Advice feedback is based on campaign stats like opens, clicks, unsubscribes, bounces, and more.
+     */
+    pub fn get_advice_request<'a>(&'a self, campaign_id: &'a str) -> ReportsGetAdviceRequest<'a> {
+        ReportsGetAdviceRequest {
+            client: self,
+            fields: None,
+            exclude_fields: None,
+            campaign_id,
+        }
+    }
     /**
      * List campaign details.
      *
@@ -310,6 +1979,28 @@ impl Reports {
             )
             .await
     }
+    /**
+     * List campaign details.
+     *
+     * This function performs a `GET` to the `/reports/{campaign_id}/click-details` endpoint.
+     *
+     * As opposed to `get_click_detail`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get information about clicks on specific links in your Mailchimp campaigns.
+     */
+    pub fn get_click_detail_request<'a>(
+        &'a self,
+        campaign_id: &'a str,
+    ) -> ReportsGetClickDetailRequest<'a> {
+        ReportsGetClickDetailRequest {
+            client: self,
+            fields: None,
+            exclude_fields: None,
+            count: None,
+            offset: None,
+            campaign_id,
+        }
+    }
     /**
      * Get campaign link details.
      *
@@ -358,6 +2049,28 @@ impl Reports {
             )
             .await
     }
+    /**
+     * Get campaign link details.
+     *
+     * This function performs a `GET` to the `/reports/{campaign_id}/click-details/{link_id}` endpoint.
+     *
+     * As opposed to `get_click_detail_reports`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get click details for a specific link in a campaign.
+     */
+    pub fn get_click_detail_reports_request<'a>(
+        &'a self,
+        campaign_id: &'a str,
+        link_id: &'a str,
+    ) -> ReportsGetClickDetailReportsRequest<'a> {
+        ReportsGetClickDetailReportsRequest {
+            client: self,
+            fields: None,
+            exclude_fields: None,
+            campaign_id,
+            link_id,
+        }
+    }
     /**
      * List clicked link subscribers.
      *
@@ -416,6 +2129,30 @@ impl Reports {
             )
             .await
     }
+    /**
+     * List clicked link subscribers.
+     *
+     * This function performs a `GET` to the `/reports/{campaign_id}/click-details/{link_id}/members` endpoint.
+     *
+     * As opposed to `get_click_details_member`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get information about list members who clicked on a specific link in a campaign.
+     */
+    pub fn get_click_details_member_request<'a>(
+        &'a self,
+        campaign_id: &'a str,
+        link_id: &'a str,
+    ) -> ReportsGetClickDetailsMemberRequest<'a> {
+        ReportsGetClickDetailsMemberRequest {
+            client: self,
+            fields: None,
+            exclude_fields: None,
+            count: None,
+            offset: None,
+            campaign_id,
+            link_id,
+        }
+    }
     /**
      * Get clicked link subscriber.
      *
@@ -467,6 +2204,30 @@ impl Reports {
             )
             .await
     }
+    /**
+     * Get clicked link subscriber.
+     *
+     * This function performs a `GET` to the `/reports/{campaign_id}/click-details/{link_id}/members/{subscriber_hash}` endpoint.
+     *
+     * As opposed to `get_click_details_member_reports`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get information about a specific subscriber who clicked a link in a specific campaign.
+     */
+    pub fn get_click_details_member_reports_request<'a>(
+        &'a self,
+        campaign_id: &'a str,
+        link_id: &'a str,
+        subscriber_hash: &'a str,
+    ) -> ReportsGetClickDetailsMemberReportsRequest<'a> {
+        ReportsGetClickDetailsMemberReportsRequest {
+            client: self,
+            fields: None,
+            exclude_fields: None,
+            campaign_id,
+            link_id,
+            subscriber_hash,
+        }
+    }
     /**
      * List campaign open details.
      *
@@ -527,6 +2288,29 @@ impl Reports {
             )
             .await
     }
+    /**
+     * List campaign open details.
+     *
+     * This function performs a `GET` to the `/reports/{campaign_id}/open-details` endpoint.
+     *
+     * As opposed to `get_open_detail`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get detailed information about any campaign emails that were opened by a list member.
+     */
+    pub fn get_open_detail_request<'a>(
+        &'a self,
+        campaign_id: &'a str,
+    ) -> ReportsGetOpenDetailRequest<'a> {
+        ReportsGetOpenDetailRequest {
+            client: self,
+            fields: None,
+            exclude_fields: None,
+            count: None,
+            offset: None,
+            campaign_id,
+            since: None,
+        }
+    }
     /**
      * Get opened campaign subscriber.
      *
@@ -575,6 +2359,28 @@ impl Reports {
             )
             .await
     }
+    /**
+     * Get opened campaign subscriber.
+     *
+     * This function performs a `GET` to the `/reports/{campaign_id}/open-details/{subscriber_hash}` endpoint.
+     *
+     * As opposed to `get_open_details_member`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get information about a specific subscriber who opened a campaign.
+     */
+    pub fn get_open_details_member_request<'a>(
+        &'a self,
+        campaign_id: &'a str,
+        subscriber_hash: &'a str,
+    ) -> ReportsGetOpenDetailsMemberRequest<'a> {
+        ReportsGetOpenDetailsMemberRequest {
+            client: self,
+            fields: None,
+            exclude_fields: None,
+            campaign_id,
+            subscriber_hash,
+        }
+    }
     /**
      * List domain performance stats.
      *
@@ -620,6 +2426,26 @@ impl Reports {
             )
             .await
     }
+    /**
+     * List domain performance stats.
+     *
+     * This function performs a `GET` to the `/reports/{campaign_id}/domain-performance` endpoint.
+     *
+     * As opposed to `get_domain_performance`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get statistics for the top-performing email domains in a campaign.
+     */
+    pub fn get_domain_performance_request<'a>(
+        &'a self,
+        campaign_id: &'a str,
+    ) -> ReportsGetDomainPerformanceRequest<'a> {
+        ReportsGetDomainPerformanceRequest {
+            client: self,
+            fields: None,
+            exclude_fields: None,
+            campaign_id,
+        }
+    }
     /**
      * List EepURL activity.
      *
@@ -665,6 +2491,23 @@ impl Reports {
             )
             .await
     }
+    /**
+     * List EepURL activity.
+     *
+     * This function performs a `GET` to the `/reports/{campaign_id}/eepurl` endpoint.
+     *
+     * As opposed to `get_eepurl`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get a summary of social activity for the campaign, tracked by EepURL.
+     */
+    pub fn get_eepurl_request<'a>(&'a self, campaign_id: &'a str) -> ReportsGetEepurlRequest<'a> {
+        ReportsGetEepurlRequest {
+            client: self,
+            fields: None,
+            exclude_fields: None,
+            campaign_id,
+        }
+    }
     /**
      * List email activity.
      *
@@ -725,6 +2568,29 @@ impl Reports {
             )
             .await
     }
+    /**
+     * List email activity.
+     *
+     * This function performs a `GET` to the `/reports/{campaign_id}/email-activity` endpoint.
+     *
+     * As opposed to `get_email_activity`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get a list of member's subscriber activity in a specific campaign.
+     */
+    pub fn get_email_activity_request<'a>(
+        &'a self,
+        campaign_id: &'a str,
+    ) -> ReportsGetEmailActivityRequest<'a> {
+        ReportsGetEmailActivityRequest {
+            client: self,
+            fields: None,
+            exclude_fields: None,
+            count: None,
+            offset: None,
+            campaign_id,
+            since: None,
+        }
+    }
     /**
      * Get subscriber email activity.
      *
@@ -778,6 +2644,29 @@ impl Reports {
             )
             .await
     }
+    /**
+     * Get subscriber email activity.
+     *
+     * This function performs a `GET` to the `/reports/{campaign_id}/email-activity/{subscriber_hash}` endpoint.
+     *
+     * As opposed to `get_email_activity_reports`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get a specific list member's activity in a campaign including opens, clicks, and bounces.
+     */
+    pub fn get_email_activity_reports_request<'a>(
+        &'a self,
+        campaign_id: &'a str,
+        subscriber_hash: &'a str,
+    ) -> ReportsGetEmailActivityReportsRequest<'a> {
+        ReportsGetEmailActivityReportsRequest {
+            client: self,
+            fields: None,
+            exclude_fields: None,
+            campaign_id,
+            subscriber_hash,
+            since: None,
+        }
+    }
     /**
      * List top open activities.
      *
@@ -833,6 +2722,28 @@ impl Reports {
             )
             .await
     }
+    /**
+     * List top open activities.
+     *
+     * This function performs a `GET` to the `/reports/{campaign_id}/locations` endpoint.
+     *
+     * As opposed to `get_location`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get top open locations for a specific campaign.
+     */
+    pub fn get_location_request<'a>(
+        &'a self,
+        campaign_id: &'a str,
+    ) -> ReportsGetLocationRequest<'a> {
+        ReportsGetLocationRequest {
+            client: self,
+            fields: None,
+            exclude_fields: None,
+            campaign_id,
+            count: None,
+            offset: None,
+        }
+    }
     /**
      * List campaign recipients.
      *
@@ -888,6 +2799,25 @@ impl Reports {
             )
             .await
     }
+    /**
+     * List campaign recipients.
+     *
+     * This function performs a `GET` to the `/reports/{campaign_id}/sent-to` endpoint.
+     *
+     * As opposed to `get_sent`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get information about campaign recipients.
+     */
+    pub fn get_sent_request<'a>(&'a self, campaign_id: &'a str) -> ReportsGetSentRequest<'a> {
+        ReportsGetSentRequest {
+            client: self,
+            fields: None,
+            exclude_fields: None,
+            count: None,
+            offset: None,
+            campaign_id,
+        }
+    }
     /**
      * Get campaign recipient info.
      *
@@ -936,6 +2866,28 @@ impl Reports {
             )
             .await
     }
+    /**
+     * Get campaign recipient info.
+     *
+     * This function performs a `GET` to the `/reports/{campaign_id}/sent-to/{subscriber_hash}` endpoint.
+     *
+     * As opposed to `get_sent_reports`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get information about a specific campaign recipient.
+     */
+    pub fn get_sent_reports_request<'a>(
+        &'a self,
+        campaign_id: &'a str,
+        subscriber_hash: &'a str,
+    ) -> ReportsGetSentReportsRequest<'a> {
+        ReportsGetSentReportsRequest {
+            client: self,
+            fields: None,
+            exclude_fields: None,
+            campaign_id,
+            subscriber_hash,
+        }
+    }
     /**
      * List child campaign reports.
      *
@@ -981,6 +2933,23 @@ impl Reports {
             )
             .await
     }
+    /**
+     * List child campaign reports.
+     *
+     * This function performs a `GET` to the `/reports/{campaign_id}/sub-reports` endpoint.
+     *
+     * As opposed to `get_sub`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get a list of reports with child campaigns for a specific parent campaign.
+     */
+    pub fn get_sub_request<'a>(&'a self, campaign_id: &'a str) -> ReportsGetSubRequest<'a> {
+        ReportsGetSubRequest {
+            client: self,
+            fields: None,
+            exclude_fields: None,
+            campaign_id,
+        }
+    }
     /**
      * List unsubscribed members.
      *
@@ -1036,6 +3005,28 @@ impl Reports {
             )
             .await
     }
+    /**
+     * List unsubscribed members.
+     *
+     * This function performs a `GET` to the `/reports/{campaign_id}/unsubscribed` endpoint.
+     *
+     * As opposed to `get_unsubscribed`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get information about members who have unsubscribed from a specific campaign.
+     */
+    pub fn get_unsubscribed_request<'a>(
+        &'a self,
+        campaign_id: &'a str,
+    ) -> ReportsGetUnsubscribedRequest<'a> {
+        ReportsGetUnsubscribedRequest {
+            client: self,
+            fields: None,
+            exclude_fields: None,
+            count: None,
+            offset: None,
+            campaign_id,
+        }
+    }
     /**
      * Get unsubscribed member.
      *
@@ -1084,6 +3075,28 @@ impl Reports {
             )
             .await
     }
+    /**
+     * Get unsubscribed member.
+     *
+     * This function performs a `GET` to the `/reports/{campaign_id}/unsubscribed/{subscriber_hash}` endpoint.
+     *
+     * As opposed to `get_unsubscribed_reports`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get information about a specific list member who unsubscribed from a campaign.
+     */
+    pub fn get_unsubscribed_reports_request<'a>(
+        &'a self,
+        campaign_id: &'a str,
+        subscriber_hash: &'a str,
+    ) -> ReportsGetUnsubscribedReportsRequest<'a> {
+        ReportsGetUnsubscribedReportsRequest {
+            client: self,
+            fields: None,
+            exclude_fields: None,
+            campaign_id,
+            subscriber_hash,
+        }
+    }
     /**
      * List campaign product activity.
      *
@@ -1145,4 +3158,27 @@ impl Reports {
             )
             .await
     }
+    /**
+     * List campaign product activity.
+     *
+     * This function performs a `GET` to the `/reports/{campaign_id}/ecommerce-product-activity` endpoint.
+     *
+     * As opposed to `get_ecommerce_product_activity`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get breakdown of product activity for a campaign
+     */
+    pub fn get_ecommerce_product_activity_request<'a>(
+        &'a self,
+        campaign_id: &'a str,
+    ) -> ReportsGetEcommerceProductActivityRequest<'a> {
+        ReportsGetEcommerceProductActivityRequest {
+            client: self,
+            fields: None,
+            exclude_fields: None,
+            count: None,
+            offset: None,
+            campaign_id,
+            sort_field: None,
+        }
+    }
 }
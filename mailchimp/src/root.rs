@@ -1,6 +1,55 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Root::get`], set with a builder.
+pub struct RootGetRequest<'a> {
+    client: &'a Root,
+    fields: Option<&'a [String]>,
+    exclude_fields: Option<&'a [String]>,
+}
+
+impl<'a> RootGetRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::ApiRoot>> {
+        let Self {
+            client,
+            fields,
+            exclude_fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(&format!("?{}", query_), None);
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct Root {
     pub client: Client,
 }
@@ -47,4 +96,20 @@ impl Root {
             )
             .await
     }
+    /**
+     * List api root resources.
+     *
+     * This function performs a `GET` to the `/` endpoint.
+     *
+     * As opposed to `get`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get links to all other resources available in the API.
+     */
+    pub fn get_request(&self) -> RootGetRequest<'_> {
+        RootGetRequest {
+            client: self,
+            fields: None,
+            exclude_fields: None,
+        }
+    }
 }
@@ -1,6 +1,68 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`SearchCampaigns::get`], set with a builder.
+pub struct SearchCampaignsGetRequest<'a> {
+    client: &'a SearchCampaigns,
+    fields: Option<&'a [String]>,
+    exclude_fields: Option<&'a [String]>,
+    query: Option<&'a str>,
+}
+
+impl<'a> SearchCampaignsGetRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sets the `query` query parameter.
+    pub fn query(mut self, query: &'a str) -> Self {
+        self.query = Some(query);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::Campaigns>> {
+        let Self {
+            client,
+            fields,
+            exclude_fields,
+            query,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = query {
+            query_args.push(("query".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/search-campaigns?{}", query_), None);
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct SearchCampaigns {
     pub client: Client,
 }
@@ -54,4 +116,21 @@ impl SearchCampaigns {
             )
             .await
     }
+    /**
+     * Search campaigns.
+     *
+     * This function performs a `GET` to the `/search-campaigns` endpoint.
+     *
+     * As opposed to `get`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Search all campaigns for the specified query terms.
+     */
+    pub fn get_request(&self) -> SearchCampaignsGetRequest<'_> {
+        SearchCampaignsGetRequest {
+            client: self,
+            fields: None,
+            exclude_fields: None,
+            query: None,
+        }
+    }
 }
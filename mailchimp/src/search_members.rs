@@ -1,6 +1,79 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`SearchMembers::get`], set with a builder.
+pub struct SearchMembersGetRequest<'a> {
+    client: &'a SearchMembers,
+    fields: Option<&'a [String]>,
+    exclude_fields: Option<&'a [String]>,
+    query: Option<&'a str>,
+    list_id: Option<&'a str>,
+}
+
+impl<'a> SearchMembersGetRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sets the `query` query parameter.
+    pub fn query(mut self, query: &'a str) -> Self {
+        self.query = Some(query);
+        self
+    }
+
+    /// Sets the `list_id` query parameter.
+    pub fn list_id(mut self, list_id: &'a str) -> Self {
+        self.list_id = Some(list_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::MembersData>> {
+        let Self {
+            client,
+            fields,
+            exclude_fields,
+            query,
+            list_id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = list_id {
+            query_args.push(("list_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = query {
+            query_args.push(("query".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/search-members?{}", query_), None);
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct SearchMembers {
     pub client: Client,
 }
@@ -59,4 +132,22 @@ impl SearchMembers {
             )
             .await
     }
+    /**
+     * Search members.
+     *
+     * This function performs a `GET` to the `/search-members` endpoint.
+     *
+     * As opposed to `get`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Search for list members. This search can be restricted to a specific list, or can be used to search across all lists in an account.
+     */
+    pub fn get_request(&self) -> SearchMembersGetRequest<'_> {
+        SearchMembersGetRequest {
+            client: self,
+            fields: None,
+            exclude_fields: None,
+            query: None,
+            list_id: None,
+        }
+    }
 }
@@ -1,6 +1,137 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`TemplateFolders::get`], set with a builder.
+pub struct TemplateFoldersGetRequest<'a> {
+    client: &'a TemplateFolders,
+    fields: Option<&'a [String]>,
+    exclude_fields: Option<&'a [String]>,
+    count: Option<i64>,
+    offset: Option<i64>,
+}
+
+impl<'a> TemplateFoldersGetRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::TemplateFolders>> {
+        let Self {
+            client,
+            fields,
+            exclude_fields,
+            count,
+            offset,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = count {
+            query_args.push(("count".to_string(), v.to_string()));
+        }
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = offset {
+            query_args.push(("offset".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/template-folders?{}", query_), None);
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`TemplateFolders::get_template_folders`], set with a builder.
+pub struct TemplateFoldersGetTemplateFoldersRequest<'a> {
+    client: &'a TemplateFolders,
+    fields: Option<&'a [String]>,
+    exclude_fields: Option<&'a [String]>,
+    folder_id: &'a str,
+}
+
+impl<'a> TemplateFoldersGetTemplateFoldersRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::Folders>> {
+        let Self {
+            client,
+            fields,
+            exclude_fields,
+            folder_id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/template-folders/{}?{}",
+                crate::progenitor_support::encode_path(folder_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct TemplateFolders {
     pub client: Client,
 }
@@ -59,6 +190,24 @@ impl TemplateFolders {
             )
             .await
     }
+    /**
+     * List template folders.
+     *
+     * This function performs a `GET` to the `/template-folders` endpoint.
+     *
+     * As opposed to `get`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get all folders used to organize templates.
+     */
+    pub fn get_request(&self) -> TemplateFoldersGetRequest<'_> {
+        TemplateFoldersGetRequest {
+            client: self,
+            fields: None,
+            exclude_fields: None,
+            count: None,
+            offset: None,
+        }
+    }
     /**
      * Add template folder.
      *
@@ -126,6 +275,26 @@ impl TemplateFolders {
             )
             .await
     }
+    /**
+     * Get template folder.
+     *
+     * This function performs a `GET` to the `/template-folders/{folder_id}` endpoint.
+     *
+     * As opposed to `get_template_folders`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get information about a specific folder used to organize templates.
+     */
+    pub fn get_template_folders_request<'a>(
+        &'a self,
+        folder_id: &'a str,
+    ) -> TemplateFoldersGetTemplateFoldersRequest<'a> {
+        TemplateFoldersGetTemplateFoldersRequest {
+            client: self,
+            fields: None,
+            exclude_fields: None,
+            folder_id,
+        }
+    }
     /**
      * Delete template folder.
      *
@@ -1,6 +1,281 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Templates::get`], set with a builder.
+pub struct TemplatesGetRequest<'a> {
+    client: &'a Templates,
+    fields: Option<&'a [String]>,
+    exclude_fields: Option<&'a [String]>,
+    count: Option<i64>,
+    offset: Option<i64>,
+    created_by: Option<&'a str>,
+    since_date_created: Option<&'a str>,
+    before_date_created: Option<&'a str>,
+    type_: Option<&'a str>,
+    category: Option<&'a str>,
+    folder_id: Option<&'a str>,
+    sort_field: Option<crate::types::GetTemplatesSortField>,
+    sort_dir: Option<crate::types::SortDir>,
+}
+
+impl<'a> TemplatesGetRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Sets the `created_by` query parameter.
+    pub fn created_by(mut self, created_by: &'a str) -> Self {
+        self.created_by = Some(created_by);
+        self
+    }
+
+    /// Sets the `since_date_created` query parameter.
+    pub fn since_date_created(mut self, since_date_created: &'a str) -> Self {
+        self.since_date_created = Some(since_date_created);
+        self
+    }
+
+    /// Sets the `before_date_created` query parameter.
+    pub fn before_date_created(mut self, before_date_created: &'a str) -> Self {
+        self.before_date_created = Some(before_date_created);
+        self
+    }
+
+    /// Sets the `type` query parameter.
+    pub fn type_(mut self, type_: &'a str) -> Self {
+        self.type_ = Some(type_);
+        self
+    }
+
+    /// Sets the `category` query parameter.
+    pub fn category(mut self, category: &'a str) -> Self {
+        self.category = Some(category);
+        self
+    }
+
+    /// Sets the `folder_id` query parameter.
+    pub fn folder_id(mut self, folder_id: &'a str) -> Self {
+        self.folder_id = Some(folder_id);
+        self
+    }
+
+    /// Sets the `sort_field` query parameter.
+    pub fn sort_field(mut self, sort_field: crate::types::GetTemplatesSortField) -> Self {
+        self.sort_field = Some(sort_field);
+        self
+    }
+
+    /// Sets the `sort_dir` query parameter.
+    pub fn sort_dir(mut self, sort_dir: crate::types::SortDir) -> Self {
+        self.sort_dir = Some(sort_dir);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::TemplatesData>> {
+        let Self {
+            client,
+            fields,
+            exclude_fields,
+            count,
+            offset,
+            created_by,
+            since_date_created,
+            before_date_created,
+            type_,
+            category,
+            folder_id,
+            sort_field,
+            sort_dir,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = before_date_created {
+            query_args.push(("before_date_created".to_string(), v.to_string()));
+        }
+        if let Some(v) = category {
+            query_args.push(("category".to_string(), v.to_string()));
+        }
+        if let Some(v) = count {
+            query_args.push(("count".to_string(), v.to_string()));
+        }
+        if let Some(v) = created_by {
+            query_args.push(("created_by".to_string(), v.to_string()));
+        }
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = folder_id {
+            query_args.push(("folder_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = offset {
+            query_args.push(("offset".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_date_created {
+            query_args.push(("since_date_created".to_string(), v.to_string()));
+        }
+        if let Some(v) = sort_dir {
+            query_args.push(("sort_dir".to_string(), v.to_string()));
+        }
+        if let Some(v) = sort_field {
+            query_args.push(("sort_field".to_string(), v.to_string()));
+        }
+        if let Some(v) = type_ {
+            query_args.push(("type".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(&format!("/templates?{}", query_), None);
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Templates::get_templates`], set with a builder.
+pub struct TemplatesGetTemplatesRequest<'a> {
+    client: &'a Templates,
+    fields: Option<&'a [String]>,
+    exclude_fields: Option<&'a [String]>,
+    template_id: &'a str,
+}
+
+impl<'a> TemplatesGetTemplatesRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::Templates>> {
+        let Self {
+            client,
+            fields,
+            exclude_fields,
+            template_id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/templates/{}?{}",
+                crate::progenitor_support::encode_path(template_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Templates::get_default_content`], set with a builder.
+pub struct TemplatesGetDefaultContentRequest<'a> {
+    client: &'a Templates,
+    fields: Option<&'a [String]>,
+    exclude_fields: Option<&'a [String]>,
+    template_id: &'a str,
+}
+
+impl<'a> TemplatesGetDefaultContentRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a [String]) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `exclude_fields` query parameter.
+    pub fn exclude_fields(mut self, exclude_fields: &'a [String]) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::TemplateDefaultContent>> {
+        let Self {
+            client,
+            fields,
+            exclude_fields,
+            template_id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = exclude_fields {
+            query_args.push(("exclude_fields".to_string(), v.join(" ")));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/templates/{}/default-content?{}",
+                crate::progenitor_support::encode_path(template_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct Templates {
     pub client: Client,
 }
@@ -103,6 +378,32 @@ impl Templates {
             )
             .await
     }
+    /**
+     * List templates.
+     *
+     * This function performs a `GET` to the `/templates` endpoint.
+     *
+     * As opposed to `get`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get a list of an account's available templates.
+     */
+    pub fn get_request(&self) -> TemplatesGetRequest<'_> {
+        TemplatesGetRequest {
+            client: self,
+            fields: None,
+            exclude_fields: None,
+            count: None,
+            offset: None,
+            created_by: None,
+            since_date_created: None,
+            before_date_created: None,
+            type_: None,
+            category: None,
+            folder_id: None,
+            sort_field: None,
+            sort_dir: None,
+        }
+    }
     /**
      * Add template.
      *
@@ -170,6 +471,26 @@ impl Templates {
             )
             .await
     }
+    /**
+     * Get template info.
+     *
+     * This function performs a `GET` to the `/templates/{template_id}` endpoint.
+     *
+     * As opposed to `get_templates`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get information about a specific template.
+     */
+    pub fn get_templates_request<'a>(
+        &'a self,
+        template_id: &'a str,
+    ) -> TemplatesGetTemplatesRequest<'a> {
+        TemplatesGetTemplatesRequest {
+            client: self,
+            fields: None,
+            exclude_fields: None,
+            template_id,
+        }
+    }
     /**
      * Delete template.
      *
@@ -277,4 +598,24 @@ impl Templates {
             )
             .await
     }
+    /**
+     * View default content.
+     *
+     * This function performs a `GET` to the `/templates/{template_id}/default-content` endpoint.
+     *
+     * As opposed to `get_default_content`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get the sections that you can edit in a template, including each section's default content.
+     */
+    pub fn get_default_content_request<'a>(
+        &'a self,
+        template_id: &'a str,
+    ) -> TemplatesGetDefaultContentRequest<'a> {
+        TemplatesGetDefaultContentRequest {
+            client: self,
+            fields: None,
+            exclude_fields: None,
+            template_id,
+        }
+    }
 }
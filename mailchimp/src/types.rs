@@ -14,6 +14,10 @@ pub struct GalleryFolder {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Information about a specific template.
@@ -46,6 +50,10 @@ pub struct TemplateInstance {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A specific note for a specific member.
@@ -60,6 +68,10 @@ pub struct MemberNotes {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub note: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Assign subscribers to interests to group them together. Interests are referred to as 'group names' in the Mailchimp application.
@@ -83,6 +95,10 @@ pub struct Interest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -101,8 +117,9 @@ pub enum Type {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Type {
@@ -113,7 +130,7 @@ impl std::fmt::Display for Type {
             Type::Hidden => "hidden",
             Type::Radio => "radio",
             Type::Noop => "",
-            Type::FallthroughString => "*",
+            Type::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -151,6 +168,10 @@ pub struct InterestCategory {
      */
     #[serde(default, skip_serializing_if = "Type::is_noop", rename = "type")]
     pub type_: Type,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The events that can trigger the webhook and whether they are enabled.
@@ -204,6 +225,10 @@ pub struct Events {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub upemail: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The possible sources of any events that can trigger the webhook and whether they are enabled.
@@ -233,6 +258,10 @@ pub struct Sources {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub user: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Configure a webhook for the given list.
@@ -257,6 +286,10 @@ pub struct AddWebhook {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Information about subscribers in an Automation email queue.
@@ -271,6 +304,10 @@ pub struct SubscriberInAutomationQueue {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub email_address: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Information about a specific product variant.
@@ -357,6 +394,10 @@ pub struct ECommerceProductVariant {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub visibility: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -373,8 +414,9 @@ pub enum PricingPlanType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PricingPlanType {
@@ -384,7 +426,7 @@ impl std::fmt::Display for PricingPlanType {
             PricingPlanType::Monthly => "monthly",
             PricingPlanType::PayAsYouGo => "pay_as_you_go",
             PricingPlanType::Noop => "",
-            PricingPlanType::FallthroughString => "*",
+            PricingPlanType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -464,6 +506,10 @@ pub struct Contact {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub zip: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The [average campaign statistics](https://mailchimp.com/resources/research/email-marketing-benchmarks/?utm_source=mc-api&utm_medium=docs&utm_campaign=apidocs) for all campaigns in the account's specified industry.
@@ -496,6 +542,10 @@ pub struct IndustryStats {
         deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub open_rate: f64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -520,8 +570,9 @@ pub enum Method {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Method {
@@ -535,7 +586,7 @@ impl std::fmt::Display for Method {
             Method::Post => "POST",
             Method::Put => "PUT",
             Method::Noop => "",
-            Method::FallthroughString => "*",
+            Method::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -592,6 +643,10 @@ pub struct Links {
         rename = "targetSchema"
     )]
     pub target_schema: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The API root resource links to all other resources available in the API.
@@ -765,6 +820,10 @@ pub struct ApiRoot {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub username: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -787,8 +846,9 @@ pub enum GetActivityFeedChimpChatterResponseType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GetActivityFeedChimpChatterResponseType {
@@ -805,7 +865,7 @@ impl std::fmt::Display for GetActivityFeedChimpChatterResponseType {
             GetActivityFeedChimpChatterResponseType::ListsProfileUpdates => "lists:profile-updates",
             GetActivityFeedChimpChatterResponseType::ListsUnsubscribes => "lists:unsubscribes",
             GetActivityFeedChimpChatterResponseType::Noop => "",
-            GetActivityFeedChimpChatterResponseType::FallthroughString => "*",
+            GetActivityFeedChimpChatterResponseType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -879,6 +939,10 @@ pub struct ChimpChatter {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// An array of Chimp Chatter messages. There's a maximum of 200 messages present for an account.
@@ -912,6 +976,10 @@ pub struct GetActivityFeedChimpChatterResponse {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_items: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// An authorized app.
@@ -963,6 +1031,10 @@ pub struct Apps {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub users: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// An array of objects, each representing an authorized application.
@@ -996,6 +1068,10 @@ pub struct GetAuthorizedAppsResponse {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_items: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -1012,8 +1088,9 @@ pub enum Status {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Status {
@@ -1023,7 +1100,7 @@ impl std::fmt::Display for Status {
             Status::Save => "save",
             Status::Sending => "sending",
             Status::Noop => "",
-            Status::FallthroughString => "*",
+            Status::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1047,8 +1124,9 @@ pub enum Match {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Match {
@@ -1057,7 +1135,7 @@ impl std::fmt::Display for Match {
             Match::All => "all",
             Match::Any => "any",
             Match::Noop => "",
-            Match::FallthroughString => "*",
+            Match::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1076,8 +1154,9 @@ pub enum ConditionType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ConditionType {
@@ -1085,7 +1164,7 @@ impl std::fmt::Display for ConditionType {
         match self {
             ConditionType::Aim => "Aim",
             ConditionType::Noop => "",
-            ConditionType::FallthroughString => "*",
+            ConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1107,8 +1186,9 @@ pub enum Field {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Field {
@@ -1116,7 +1196,7 @@ impl std::fmt::Display for Field {
         match self {
             Field::Aim => "aim",
             Field::Noop => "",
-            Field::FallthroughString => "*",
+            Field::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1148,8 +1228,9 @@ pub enum Op {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Op {
@@ -1162,7 +1243,7 @@ impl std::fmt::Display for Op {
             Op::Open => "open",
             Op::Sent => "sent",
             Op::Noop => "",
-            Op::FallthroughString => "*",
+            Op::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1201,6 +1282,10 @@ pub struct Conditions {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub value: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -1210,8 +1295,9 @@ pub enum AutomationSegmentConditionType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AutomationSegmentConditionType {
@@ -1219,7 +1305,7 @@ impl std::fmt::Display for AutomationSegmentConditionType {
         match self {
             AutomationSegmentConditionType::Automation => "Automation",
             AutomationSegmentConditionType::Noop => "",
-            AutomationSegmentConditionType::FallthroughString => "*",
+            AutomationSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1241,8 +1327,9 @@ pub enum SegmentField {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SegmentField {
@@ -1250,7 +1337,7 @@ impl std::fmt::Display for SegmentField {
         match self {
             SegmentField::Automation => "automation",
             SegmentField::Noop => "",
-            SegmentField::FallthroughString => "*",
+            SegmentField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1278,8 +1365,9 @@ pub enum SegmentOperator {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SegmentOperator {
@@ -1290,7 +1378,7 @@ impl std::fmt::Display for SegmentOperator {
             SegmentOperator::NotStarted => "not_started",
             SegmentOperator::Started => "started",
             SegmentOperator::Noop => "",
-            SegmentOperator::FallthroughString => "*",
+            SegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1329,6 +1417,10 @@ pub struct AutomationSegment {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub value: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -1338,8 +1430,9 @@ pub enum PollActivitySegmentConditionType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PollActivitySegmentConditionType {
@@ -1347,7 +1440,7 @@ impl std::fmt::Display for PollActivitySegmentConditionType {
         match self {
             PollActivitySegmentConditionType::CampaignPoll => "CampaignPoll",
             PollActivitySegmentConditionType::Noop => "",
-            PollActivitySegmentConditionType::FallthroughString => "*",
+            PollActivitySegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1369,8 +1462,9 @@ pub enum PollActivitySegmentField {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PollActivitySegmentField {
@@ -1378,7 +1472,7 @@ impl std::fmt::Display for PollActivitySegmentField {
         match self {
             PollActivitySegmentField::Poll => "poll",
             PollActivitySegmentField::Noop => "",
-            PollActivitySegmentField::FallthroughString => "*",
+            PollActivitySegmentField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1402,8 +1496,9 @@ pub enum PollActivitySegmentOperator {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PollActivitySegmentOperator {
@@ -1412,7 +1507,7 @@ impl std::fmt::Display for PollActivitySegmentOperator {
             PollActivitySegmentOperator::Member => "member",
             PollActivitySegmentOperator::Notmember => "notmember",
             PollActivitySegmentOperator::Noop => "",
-            PollActivitySegmentOperator::FallthroughString => "*",
+            PollActivitySegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1451,6 +1546,10 @@ pub struct PollActivitySegment {
         deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub value: f64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -1460,8 +1559,9 @@ pub enum ConversationSegmentConditionType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ConversationSegmentConditionType {
@@ -1469,7 +1569,7 @@ impl std::fmt::Display for ConversationSegmentConditionType {
         match self {
             ConversationSegmentConditionType::Conversation => "Conversation",
             ConversationSegmentConditionType::Noop => "",
-            ConversationSegmentConditionType::FallthroughString => "*",
+            ConversationSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1491,8 +1591,9 @@ pub enum ConversationSegmentField {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ConversationSegmentField {
@@ -1500,7 +1601,7 @@ impl std::fmt::Display for ConversationSegmentField {
         match self {
             ConversationSegmentField::Conversation => "conversation",
             ConversationSegmentField::Noop => "",
-            ConversationSegmentField::FallthroughString => "*",
+            ConversationSegmentField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1539,6 +1640,10 @@ pub struct ConversationSegment {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub value: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -1548,8 +1653,9 @@ pub enum DateSegmentConditionType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DateSegmentConditionType {
@@ -1557,7 +1663,7 @@ impl std::fmt::Display for DateSegmentConditionType {
         match self {
             DateSegmentConditionType::Date => "Date",
             DateSegmentConditionType::Noop => "",
-            DateSegmentConditionType::FallthroughString => "*",
+            DateSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1583,8 +1689,9 @@ pub enum DateSegmentField {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DateSegmentField {
@@ -1594,7 +1701,7 @@ impl std::fmt::Display for DateSegmentField {
             DateSegmentField::InfoChanged => "info_changed",
             DateSegmentField::TimestampOpt => "timestamp_opt",
             DateSegmentField::Noop => "",
-            DateSegmentField::FallthroughString => "*",
+            DateSegmentField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1630,8 +1737,9 @@ pub enum DateSegmentOperator {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DateSegmentOperator {
@@ -1646,7 +1754,7 @@ impl std::fmt::Display for DateSegmentOperator {
             DateSegmentOperator::Notwithin => "notwithin",
             DateSegmentOperator::Within => "within",
             DateSegmentOperator::Noop => "",
-            DateSegmentOperator::FallthroughString => "*",
+            DateSegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1694,6 +1802,10 @@ pub struct DateSegment {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub value: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra_fields: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -1703,8 +1815,9 @@ pub enum EmailClientSegmentConditionType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EmailClientSegmentConditionType {
@@ -1712,7 +1825,7 @@ impl std::fmt::Display for EmailClientSegmentConditionType {
         match self {
             EmailClientSegmentConditionType::EmailClient => "EmailClient",
             EmailClientSegmentConditionType::Noop => "",
-            EmailClientSegmentConditionType::FallthroughString => "*",
+            EmailClientSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1734,8 +1847,9 @@ pub enum EmailClientSegmentField {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EmailClientSegmentField {
@@ -1743,7 +1857,7 @@ impl std::fmt::Display for EmailClientSegmentField {
         match self {
             EmailClientSegmentField::EmailClient => "email_client",
             EmailClientSegmentField::Noop => "",
-            EmailClientSegmentField::FallthroughString => "*",
+            EmailClientSegmentField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1767,8 +1881,9 @@ pub enum EmailClientSegmentOperator {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EmailClientSegmentOperator {
@@ -1777,7 +1892,7 @@ impl std::fmt::Display for EmailClientSegmentOperator {
             EmailClientSegmentOperator::ClientIs => "client_is",
             EmailClientSegmentOperator::ClientNot => "client_not",
             EmailClientSegmentOperator::Noop => "",
-            EmailClientSegmentOperator::FallthroughString => "*",
+            EmailClientSegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1816,6 +1931,10 @@ pub struct EmailClientSegment {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub value: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -1825,8 +1944,9 @@ pub enum LanguageSegmentConditionType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for LanguageSegmentConditionType {
@@ -1834,7 +1954,7 @@ impl std::fmt::Display for LanguageSegmentConditionType {
         match self {
             LanguageSegmentConditionType::Language => "Language",
             LanguageSegmentConditionType::Noop => "",
-            LanguageSegmentConditionType::FallthroughString => "*",
+            LanguageSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1856,8 +1976,9 @@ pub enum LanguageSegmentField {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for LanguageSegmentField {
@@ -1865,7 +1986,7 @@ impl std::fmt::Display for LanguageSegmentField {
         match self {
             LanguageSegmentField::Language => "language",
             LanguageSegmentField::Noop => "",
-            LanguageSegmentField::FallthroughString => "*",
+            LanguageSegmentField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1889,8 +2010,9 @@ pub enum LanguageSegmentOperator {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for LanguageSegmentOperator {
@@ -1899,7 +2021,7 @@ impl std::fmt::Display for LanguageSegmentOperator {
             LanguageSegmentOperator::Is => "is",
             LanguageSegmentOperator::Not => "not",
             LanguageSegmentOperator::Noop => "",
-            LanguageSegmentOperator::FallthroughString => "*",
+            LanguageSegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1938,6 +2060,10 @@ pub struct LanguageSegment {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub value: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -1947,8 +2073,9 @@ pub enum MemberRatingSegmentConditionType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for MemberRatingSegmentConditionType {
@@ -1956,7 +2083,7 @@ impl std::fmt::Display for MemberRatingSegmentConditionType {
         match self {
             MemberRatingSegmentConditionType::MemberRating => "MemberRating",
             MemberRatingSegmentConditionType::Noop => "",
-            MemberRatingSegmentConditionType::FallthroughString => "*",
+            MemberRatingSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1978,8 +2105,9 @@ pub enum MemberRatingSegmentField {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for MemberRatingSegmentField {
@@ -1987,7 +2115,7 @@ impl std::fmt::Display for MemberRatingSegmentField {
         match self {
             MemberRatingSegmentField::Rating => "rating",
             MemberRatingSegmentField::Noop => "",
-            MemberRatingSegmentField::FallthroughString => "*",
+            MemberRatingSegmentField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2015,8 +2143,9 @@ pub enum MemberRatingSegmentOperator {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for MemberRatingSegmentOperator {
@@ -2027,7 +2156,7 @@ impl std::fmt::Display for MemberRatingSegmentOperator {
             MemberRatingSegmentOperator::Less => "less",
             MemberRatingSegmentOperator::Not => "not",
             MemberRatingSegmentOperator::Noop => "",
-            MemberRatingSegmentOperator::FallthroughString => "*",
+            MemberRatingSegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2066,6 +2195,10 @@ pub struct MemberRatingSegment {
         deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub value: f64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -2075,8 +2208,9 @@ pub enum SignupSourceSegmentType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SignupSourceSegmentType {
@@ -2084,7 +2218,7 @@ impl std::fmt::Display for SignupSourceSegmentType {
         match self {
             SignupSourceSegmentType::SignupSource => "SignupSource",
             SignupSourceSegmentType::Noop => "",
-            SignupSourceSegmentType::FallthroughString => "*",
+            SignupSourceSegmentType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2103,8 +2237,9 @@ pub enum SignupSourceSegmentField {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SignupSourceSegmentField {
@@ -2112,7 +2247,7 @@ impl std::fmt::Display for SignupSourceSegmentField {
         match self {
             SignupSourceSegmentField::Source => "source",
             SignupSourceSegmentField::Noop => "",
-            SignupSourceSegmentField::FallthroughString => "*",
+            SignupSourceSegmentField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2136,8 +2271,9 @@ pub enum SignupSourceSegmentOperator {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SignupSourceSegmentOperator {
@@ -2146,7 +2282,7 @@ impl std::fmt::Display for SignupSourceSegmentOperator {
             SignupSourceSegmentOperator::SourceIs => "source_is",
             SignupSourceSegmentOperator::SourceNot => "source_not",
             SignupSourceSegmentOperator::Noop => "",
-            SignupSourceSegmentOperator::FallthroughString => "*",
+            SignupSourceSegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2179,6 +2315,10 @@ pub struct SignupSourceSegment {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub value: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -2188,8 +2328,9 @@ pub enum SurveyMonkeySegmentConditionType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SurveyMonkeySegmentConditionType {
@@ -2197,7 +2338,7 @@ impl std::fmt::Display for SurveyMonkeySegmentConditionType {
         match self {
             SurveyMonkeySegmentConditionType::SurveyMonkey => "SurveyMonkey",
             SurveyMonkeySegmentConditionType::Noop => "",
-            SurveyMonkeySegmentConditionType::FallthroughString => "*",
+            SurveyMonkeySegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2219,8 +2360,9 @@ pub enum SurveyMonkeySegmentField {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SurveyMonkeySegmentField {
@@ -2228,7 +2370,7 @@ impl std::fmt::Display for SurveyMonkeySegmentField {
         match self {
             SurveyMonkeySegmentField::SurveyMonkey => "survey_monkey",
             SurveyMonkeySegmentField::Noop => "",
-            SurveyMonkeySegmentField::FallthroughString => "*",
+            SurveyMonkeySegmentField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2267,6 +2409,10 @@ pub struct SurveyMonkeySegment {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub value: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -2276,8 +2422,9 @@ pub enum VipSegmentConditionType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for VipSegmentConditionType {
@@ -2285,7 +2432,7 @@ impl std::fmt::Display for VipSegmentConditionType {
         match self {
             VipSegmentConditionType::Vip => "VIP",
             VipSegmentConditionType::Noop => "",
-            VipSegmentConditionType::FallthroughString => "*",
+            VipSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2307,8 +2454,9 @@ pub enum VipSegmentField {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for VipSegmentField {
@@ -2316,7 +2464,7 @@ impl std::fmt::Display for VipSegmentField {
         match self {
             VipSegmentField::Gmonkey => "gmonkey",
             VipSegmentField::Noop => "",
-            VipSegmentField::FallthroughString => "*",
+            VipSegmentField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2346,6 +2494,10 @@ pub struct VipSegment {
      */
     #[serde(default, skip_serializing_if = "PollActivitySegmentOperator::is_noop")]
     pub op: PollActivitySegmentOperator,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -2355,8 +2507,9 @@ pub enum InterestsSegmentConditionType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for InterestsSegmentConditionType {
@@ -2364,7 +2517,7 @@ impl std::fmt::Display for InterestsSegmentConditionType {
         match self {
             InterestsSegmentConditionType::Interests => "Interests",
             InterestsSegmentConditionType::Noop => "",
-            InterestsSegmentConditionType::FallthroughString => "*",
+            InterestsSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2390,8 +2543,9 @@ pub enum InterestsSegmentOperator {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for InterestsSegmentOperator {
@@ -2401,7 +2555,7 @@ impl std::fmt::Display for InterestsSegmentOperator {
             InterestsSegmentOperator::Interestcontainsall => "interestcontainsall",
             InterestsSegmentOperator::Interestnotcontains => "interestnotcontains",
             InterestsSegmentOperator::Noop => "",
-            InterestsSegmentOperator::FallthroughString => "*",
+            InterestsSegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2444,6 +2598,10 @@ pub struct InterestsSegment {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub value: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -2453,8 +2611,9 @@ pub enum EcommerceCategorySegmentConditionType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EcommerceCategorySegmentConditionType {
@@ -2462,7 +2621,7 @@ impl std::fmt::Display for EcommerceCategorySegmentConditionType {
         match self {
             EcommerceCategorySegmentConditionType::EcommCategory => "EcommCategory",
             EcommerceCategorySegmentConditionType::Noop => "",
-            EcommerceCategorySegmentConditionType::FallthroughString => "*",
+            EcommerceCategorySegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2486,8 +2645,9 @@ pub enum EcommerceCategorySegmentField {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EcommerceCategorySegmentField {
@@ -2496,7 +2656,7 @@ impl std::fmt::Display for EcommerceCategorySegmentField {
             EcommerceCategorySegmentField::EcommCat => "ecomm_cat",
             EcommerceCategorySegmentField::EcommProd => "ecomm_prod",
             EcommerceCategorySegmentField::Noop => "",
-            EcommerceCategorySegmentField::FallthroughString => "*",
+            EcommerceCategorySegmentField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2528,8 +2688,9 @@ pub enum EcommerceCategorySegmentOperator {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EcommerceCategorySegmentOperator {
@@ -2542,7 +2703,7 @@ impl std::fmt::Display for EcommerceCategorySegmentOperator {
             EcommerceCategorySegmentOperator::Notcontain => "notcontain",
             EcommerceCategorySegmentOperator::Starts => "starts",
             EcommerceCategorySegmentOperator::Noop => "",
-            EcommerceCategorySegmentOperator::FallthroughString => "*",
+            EcommerceCategorySegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2581,6 +2742,10 @@ pub struct EcommerceCategorySegment {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub value: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -2590,8 +2755,9 @@ pub enum EcommerceNumberSegmentConditionType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EcommerceNumberSegmentConditionType {
@@ -2599,7 +2765,7 @@ impl std::fmt::Display for EcommerceNumberSegmentConditionType {
         match self {
             EcommerceNumberSegmentConditionType::EcommNumber => "EcommNumber",
             EcommerceNumberSegmentConditionType::Noop => "",
-            EcommerceNumberSegmentConditionType::FallthroughString => "*",
+            EcommerceNumberSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2627,8 +2793,9 @@ pub enum EcommerceNumberSegmentField {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EcommerceNumberSegmentField {
@@ -2639,7 +2806,7 @@ impl std::fmt::Display for EcommerceNumberSegmentField {
             EcommerceNumberSegmentField::EcommProdAll => "ecomm_prod_all",
             EcommerceNumberSegmentField::EcommSpentAvg => "ecomm_spent_avg",
             EcommerceNumberSegmentField::Noop => "",
-            EcommerceNumberSegmentField::FallthroughString => "*",
+            EcommerceNumberSegmentField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2678,6 +2845,10 @@ pub struct EcommerceNumberSegment {
         deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub value: f64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -2687,8 +2858,9 @@ pub enum EcommercePurchasedSegmentConditionType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EcommercePurchasedSegmentConditionType {
@@ -2696,7 +2868,7 @@ impl std::fmt::Display for EcommercePurchasedSegmentConditionType {
         match self {
             EcommercePurchasedSegmentConditionType::EcommPurchased => "EcommPurchased",
             EcommercePurchasedSegmentConditionType::Noop => "",
-            EcommercePurchasedSegmentConditionType::FallthroughString => "*",
+            EcommercePurchasedSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2718,8 +2890,9 @@ pub enum EcommercePurchasedSegmentField {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EcommercePurchasedSegmentField {
@@ -2727,7 +2900,7 @@ impl std::fmt::Display for EcommercePurchasedSegmentField {
         match self {
             EcommercePurchasedSegmentField::EcommPurchased => "ecomm_purchased",
             EcommercePurchasedSegmentField::Noop => "",
-            EcommercePurchasedSegmentField::FallthroughString => "*",
+            EcommercePurchasedSegmentField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2757,6 +2930,10 @@ pub struct EcommercePurchasedSegment {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub op: Option<PollActivitySegmentOperator>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -2766,8 +2943,9 @@ pub enum EcommerceSpentSegmentConditionType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EcommerceSpentSegmentConditionType {
@@ -2775,7 +2953,7 @@ impl std::fmt::Display for EcommerceSpentSegmentConditionType {
         match self {
             EcommerceSpentSegmentConditionType::EcommSpent => "EcommSpent",
             EcommerceSpentSegmentConditionType::Noop => "",
-            EcommerceSpentSegmentConditionType::FallthroughString => "*",
+            EcommerceSpentSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2799,8 +2977,9 @@ pub enum EcommerceSpentSegmentField {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EcommerceSpentSegmentField {
@@ -2809,7 +2988,7 @@ impl std::fmt::Display for EcommerceSpentSegmentField {
             EcommerceSpentSegmentField::EcommSpentAll => "ecomm_spent_all",
             EcommerceSpentSegmentField::EcommSpentOne => "ecomm_spent_one",
             EcommerceSpentSegmentField::Noop => "",
-            EcommerceSpentSegmentField::FallthroughString => "*",
+            EcommerceSpentSegmentField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2833,8 +3012,9 @@ pub enum EcommerceSpentSegmentOperator {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EcommerceSpentSegmentOperator {
@@ -2843,7 +3023,7 @@ impl std::fmt::Display for EcommerceSpentSegmentOperator {
             EcommerceSpentSegmentOperator::Greater => "greater",
             EcommerceSpentSegmentOperator::Less => "less",
             EcommerceSpentSegmentOperator::Noop => "",
-            EcommerceSpentSegmentOperator::FallthroughString => "*",
+            EcommerceSpentSegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2882,6 +3062,10 @@ pub struct EcommerceSpentSegment {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub value: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -2891,8 +3075,9 @@ pub enum EcommercePurchasedStoreSegmentConditionType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EcommercePurchasedStoreSegmentConditionType {
@@ -2900,7 +3085,7 @@ impl std::fmt::Display for EcommercePurchasedStoreSegmentConditionType {
         match self {
             EcommercePurchasedStoreSegmentConditionType::EcommStore => "EcommStore",
             EcommercePurchasedStoreSegmentConditionType::Noop => "",
-            EcommercePurchasedStoreSegmentConditionType::FallthroughString => "*",
+            EcommercePurchasedStoreSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2922,8 +3107,9 @@ pub enum EcommercePurchasedStoreSegmentField {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EcommercePurchasedStoreSegmentField {
@@ -2931,7 +3117,7 @@ impl std::fmt::Display for EcommercePurchasedStoreSegmentField {
         match self {
             EcommercePurchasedStoreSegmentField::EcommStore => "ecomm_store",
             EcommercePurchasedStoreSegmentField::Noop => "",
-            EcommercePurchasedStoreSegmentField::FallthroughString => "*",
+            EcommercePurchasedStoreSegmentField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2970,6 +3156,10 @@ pub struct EcommercePurchasedStoreSegment {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub value: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -2979,8 +3169,9 @@ pub enum GoalActivitySegmentConditionType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GoalActivitySegmentConditionType {
@@ -2988,7 +3179,7 @@ impl std::fmt::Display for GoalActivitySegmentConditionType {
         match self {
             GoalActivitySegmentConditionType::GoalActivity => "GoalActivity",
             GoalActivitySegmentConditionType::Noop => "",
-            GoalActivitySegmentConditionType::FallthroughString => "*",
+            GoalActivitySegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3010,8 +3201,9 @@ pub enum GoalActivitySegmentField {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GoalActivitySegmentField {
@@ -3019,7 +3211,7 @@ impl std::fmt::Display for GoalActivitySegmentField {
         match self {
             GoalActivitySegmentField::Goal => "goal",
             GoalActivitySegmentField::Noop => "",
-            GoalActivitySegmentField::FallthroughString => "*",
+            GoalActivitySegmentField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3051,8 +3243,9 @@ pub enum GoalActivitySegmentOperator {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GoalActivitySegmentOperator {
@@ -3065,7 +3258,7 @@ impl std::fmt::Display for GoalActivitySegmentOperator {
             GoalActivitySegmentOperator::Is => "is",
             GoalActivitySegmentOperator::Starts => "starts",
             GoalActivitySegmentOperator::Noop => "",
-            GoalActivitySegmentOperator::FallthroughString => "*",
+            GoalActivitySegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3104,6 +3297,10 @@ pub struct GoalActivitySegment {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub value: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -3113,8 +3310,9 @@ pub enum GoalTimestampSegmentConditionType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GoalTimestampSegmentConditionType {
@@ -3122,7 +3320,7 @@ impl std::fmt::Display for GoalTimestampSegmentConditionType {
         match self {
             GoalTimestampSegmentConditionType::GoalTimestamp => "GoalTimestamp",
             GoalTimestampSegmentConditionType::Noop => "",
-            GoalTimestampSegmentConditionType::FallthroughString => "*",
+            GoalTimestampSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3144,8 +3342,9 @@ pub enum GoalTimestampSegmentField {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GoalTimestampSegmentField {
@@ -3153,7 +3352,7 @@ impl std::fmt::Display for GoalTimestampSegmentField {
         match self {
             GoalTimestampSegmentField::GoalLastVisited => "goal_last_visited",
             GoalTimestampSegmentField::Noop => "",
-            GoalTimestampSegmentField::FallthroughString => "*",
+            GoalTimestampSegmentField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3179,8 +3378,9 @@ pub enum GoalTimestampSegmentOperator {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GoalTimestampSegmentOperator {
@@ -3190,7 +3390,7 @@ impl std::fmt::Display for GoalTimestampSegmentOperator {
             GoalTimestampSegmentOperator::Is => "is",
             GoalTimestampSegmentOperator::Less => "less",
             GoalTimestampSegmentOperator::Noop => "",
-            GoalTimestampSegmentOperator::FallthroughString => "*",
+            GoalTimestampSegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3229,6 +3429,10 @@ pub struct GoalTimestampSegment {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub value: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -3238,8 +3442,9 @@ pub enum SimilarSubscribersSegmentMemberConditionType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SimilarSubscribersSegmentMemberConditionType {
@@ -3247,7 +3452,7 @@ impl std::fmt::Display for SimilarSubscribersSegmentMemberConditionType {
         match self {
             SimilarSubscribersSegmentMemberConditionType::FuzzySegment => "FuzzySegment",
             SimilarSubscribersSegmentMemberConditionType::Noop => "",
-            SimilarSubscribersSegmentMemberConditionType::FallthroughString => "*",
+            SimilarSubscribersSegmentMemberConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3269,8 +3474,9 @@ pub enum SimilarSubscribersSegmentMemberField {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SimilarSubscribersSegmentMemberField {
@@ -3278,7 +3484,7 @@ impl std::fmt::Display for SimilarSubscribersSegmentMemberField {
         match self {
             SimilarSubscribersSegmentMemberField::FuzzySegment => "fuzzy_segment",
             SimilarSubscribersSegmentMemberField::Noop => "",
-            SimilarSubscribersSegmentMemberField::FallthroughString => "*",
+            SimilarSubscribersSegmentMemberField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3302,8 +3508,9 @@ pub enum SimilarSubscribersSegmentMemberOperator {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SimilarSubscribersSegmentMemberOperator {
@@ -3312,7 +3519,7 @@ impl std::fmt::Display for SimilarSubscribersSegmentMemberOperator {
             SimilarSubscribersSegmentMemberOperator::FuzzyIs => "fuzzy_is",
             SimilarSubscribersSegmentMemberOperator::FuzzyNot => "fuzzy_not",
             SimilarSubscribersSegmentMemberOperator::Noop => "",
-            SimilarSubscribersSegmentMemberOperator::FallthroughString => "*",
+            SimilarSubscribersSegmentMemberOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3357,6 +3564,10 @@ pub struct SimilarSubscribersSegmentMember {
         deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub value: f64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -3366,8 +3577,9 @@ pub enum StaticSegmentMemberConditionType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for StaticSegmentMemberConditionType {
@@ -3375,7 +3587,7 @@ impl std::fmt::Display for StaticSegmentMemberConditionType {
         match self {
             StaticSegmentMemberConditionType::StaticSegment => "StaticSegment",
             StaticSegmentMemberConditionType::Noop => "",
-            StaticSegmentMemberConditionType::FallthroughString => "*",
+            StaticSegmentMemberConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3397,8 +3609,9 @@ pub enum StaticSegmentMemberField {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for StaticSegmentMemberField {
@@ -3406,7 +3619,7 @@ impl std::fmt::Display for StaticSegmentMemberField {
         match self {
             StaticSegmentMemberField::StaticSegment => "static_segment",
             StaticSegmentMemberField::Noop => "",
-            StaticSegmentMemberField::FallthroughString => "*",
+            StaticSegmentMemberField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3430,8 +3643,9 @@ pub enum StaticSegmentMemberOperator {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for StaticSegmentMemberOperator {
@@ -3440,7 +3654,7 @@ impl std::fmt::Display for StaticSegmentMemberOperator {
             StaticSegmentMemberOperator::StaticIs => "static_is",
             StaticSegmentMemberOperator::StaticNot => "static_not",
             StaticSegmentMemberOperator::Noop => "",
-            StaticSegmentMemberOperator::FallthroughString => "*",
+            StaticSegmentMemberOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3479,6 +3693,10 @@ pub struct StaticSegmentMember {
         deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub value: f64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -3488,8 +3706,9 @@ pub enum LocationBasedSegmentConditionType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for LocationBasedSegmentConditionType {
@@ -3497,7 +3716,7 @@ impl std::fmt::Display for LocationBasedSegmentConditionType {
         match self {
             LocationBasedSegmentConditionType::IpGeoCountryState => "IPGeoCountryState",
             LocationBasedSegmentConditionType::Noop => "",
-            LocationBasedSegmentConditionType::FallthroughString => "*",
+            LocationBasedSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3519,8 +3738,9 @@ pub enum LocationBasedSegmentField {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for LocationBasedSegmentField {
@@ -3528,7 +3748,7 @@ impl std::fmt::Display for LocationBasedSegmentField {
         match self {
             LocationBasedSegmentField::Ipgeo => "ipgeo",
             LocationBasedSegmentField::Noop => "",
-            LocationBasedSegmentField::FallthroughString => "*",
+            LocationBasedSegmentField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3556,8 +3776,9 @@ pub enum LocationBasedSegmentOperator {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for LocationBasedSegmentOperator {
@@ -3568,7 +3789,7 @@ impl std::fmt::Display for LocationBasedSegmentOperator {
             LocationBasedSegmentOperator::Ipgeonotstate => "ipgeonotstate",
             LocationBasedSegmentOperator::Ipgeostate => "ipgeostate",
             LocationBasedSegmentOperator::Noop => "",
-            LocationBasedSegmentOperator::FallthroughString => "*",
+            LocationBasedSegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3607,6 +3828,10 @@ pub struct LocationBasedSegment {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub value: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -3616,8 +3841,9 @@ pub enum GeolocationSegmentConditionType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GeolocationSegmentConditionType {
@@ -3625,7 +3851,7 @@ impl std::fmt::Display for GeolocationSegmentConditionType {
         match self {
             GeolocationSegmentConditionType::IpGeoIn => "IPGeoIn",
             GeolocationSegmentConditionType::Noop => "",
-            GeolocationSegmentConditionType::FallthroughString => "*",
+            GeolocationSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3649,8 +3875,9 @@ pub enum GeolocationSegmentOperator {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GeolocationSegmentOperator {
@@ -3659,7 +3886,7 @@ impl std::fmt::Display for GeolocationSegmentOperator {
             GeolocationSegmentOperator::Ipgeoin => "ipgeoin",
             GeolocationSegmentOperator::Ipgeonotin => "ipgeonotin",
             GeolocationSegmentOperator::Noop => "",
-            GeolocationSegmentOperator::FallthroughString => "*",
+            GeolocationSegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3725,6 +3952,10 @@ pub struct GeolocationSegment {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub value: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -3734,8 +3965,9 @@ pub enum UsZipCodeSegmentConditionType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for UsZipCodeSegmentConditionType {
@@ -3743,7 +3975,7 @@ impl std::fmt::Display for UsZipCodeSegmentConditionType {
         match self {
             UsZipCodeSegmentConditionType::IpGeoInZip => "IPGeoInZip",
             UsZipCodeSegmentConditionType::Noop => "",
-            UsZipCodeSegmentConditionType::FallthroughString => "*",
+            UsZipCodeSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3765,8 +3997,9 @@ pub enum UsZipCodeSegmentOperator {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for UsZipCodeSegmentOperator {
@@ -3774,7 +4007,7 @@ impl std::fmt::Display for UsZipCodeSegmentOperator {
         match self {
             UsZipCodeSegmentOperator::Ipgeoinzip => "ipgeoinzip",
             UsZipCodeSegmentOperator::Noop => "",
-            UsZipCodeSegmentOperator::FallthroughString => "*",
+            UsZipCodeSegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3822,6 +4055,10 @@ pub struct UsZipCodeSegment {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub value: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra_fields: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -3831,8 +4068,9 @@ pub enum UnknownLocationBasedSegmentConditionType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for UnknownLocationBasedSegmentConditionType {
@@ -3840,7 +4078,7 @@ impl std::fmt::Display for UnknownLocationBasedSegmentConditionType {
         match self {
             UnknownLocationBasedSegmentConditionType::IpGeoUnknown => "IPGeoUnknown",
             UnknownLocationBasedSegmentConditionType::Noop => "",
-            UnknownLocationBasedSegmentConditionType::FallthroughString => "*",
+            UnknownLocationBasedSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3862,8 +4100,9 @@ pub enum UnknownLocationBasedSegmentOperator {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for UnknownLocationBasedSegmentOperator {
@@ -3871,7 +4110,7 @@ impl std::fmt::Display for UnknownLocationBasedSegmentOperator {
         match self {
             UnknownLocationBasedSegmentOperator::Ipgeounknown => "ipgeounknown",
             UnknownLocationBasedSegmentOperator::Noop => "",
-            UnknownLocationBasedSegmentOperator::FallthroughString => "*",
+            UnknownLocationBasedSegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3904,6 +4143,10 @@ pub struct UnknownLocationBasedSegment {
         skip_serializing_if = "UnknownLocationBasedSegmentOperator::is_noop"
     )]
     pub op: UnknownLocationBasedSegmentOperator,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -3913,8 +4156,9 @@ pub enum ZipCodeLocationBasedSegmentConditionType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ZipCodeLocationBasedSegmentConditionType {
@@ -3922,7 +4166,7 @@ impl std::fmt::Display for ZipCodeLocationBasedSegmentConditionType {
         match self {
             ZipCodeLocationBasedSegmentConditionType::IpGeoZip => "IPGeoZip",
             ZipCodeLocationBasedSegmentConditionType::Noop => "",
-            ZipCodeLocationBasedSegmentConditionType::FallthroughString => "*",
+            ZipCodeLocationBasedSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3946,8 +4190,9 @@ pub enum ZipCodeLocationBasedSegmentOperator {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ZipCodeLocationBasedSegmentOperator {
@@ -3956,7 +4201,7 @@ impl std::fmt::Display for ZipCodeLocationBasedSegmentOperator {
             ZipCodeLocationBasedSegmentOperator::Ipgeoiszip => "ipgeoiszip",
             ZipCodeLocationBasedSegmentOperator::Ipgeonotzip => "ipgeonotzip",
             ZipCodeLocationBasedSegmentOperator::Noop => "",
-            ZipCodeLocationBasedSegmentOperator::FallthroughString => "*",
+            ZipCodeLocationBasedSegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3998,6 +4243,10 @@ pub struct ZipCodeLocationBasedSegment {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub value: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -4007,8 +4256,9 @@ pub enum SocialProfilesAgeSegmentConditionType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SocialProfilesAgeSegmentConditionType {
@@ -4016,7 +4266,7 @@ impl std::fmt::Display for SocialProfilesAgeSegmentConditionType {
         match self {
             SocialProfilesAgeSegmentConditionType::SocialAge => "SocialAge",
             SocialProfilesAgeSegmentConditionType::Noop => "",
-            SocialProfilesAgeSegmentConditionType::FallthroughString => "*",
+            SocialProfilesAgeSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -4038,8 +4288,9 @@ pub enum SocialProfilesAgeSegmentField {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SocialProfilesAgeSegmentField {
@@ -4047,7 +4298,7 @@ impl std::fmt::Display for SocialProfilesAgeSegmentField {
         match self {
             SocialProfilesAgeSegmentField::SocialAge => "social_age",
             SocialProfilesAgeSegmentField::Noop => "",
-            SocialProfilesAgeSegmentField::FallthroughString => "*",
+            SocialProfilesAgeSegmentField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -4075,8 +4326,9 @@ pub enum Value {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Value {
@@ -4087,7 +4339,7 @@ impl std::fmt::Display for Value {
             Value::ThreeThousandFiveHundredAndFiftyFour => "35-54",
             Value::FiftyFive => "55+",
             Value::Noop => "",
-            Value::FallthroughString => "*",
+            Value::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -4125,6 +4377,10 @@ pub struct SocialProfilesAgeSegment {
      */
     #[serde(default, skip_serializing_if = "Value::is_noop")]
     pub value: Value,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -4134,8 +4390,9 @@ pub enum SocialProfilesGenderSegmentConditionType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SocialProfilesGenderSegmentConditionType {
@@ -4143,7 +4400,7 @@ impl std::fmt::Display for SocialProfilesGenderSegmentConditionType {
         match self {
             SocialProfilesGenderSegmentConditionType::SocialGender => "SocialGender",
             SocialProfilesGenderSegmentConditionType::Noop => "",
-            SocialProfilesGenderSegmentConditionType::FallthroughString => "*",
+            SocialProfilesGenderSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -4165,8 +4422,9 @@ pub enum SocialProfilesGenderSegmentField {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SocialProfilesGenderSegmentField {
@@ -4174,7 +4432,7 @@ impl std::fmt::Display for SocialProfilesGenderSegmentField {
         match self {
             SocialProfilesGenderSegmentField::SocialGender => "social_gender",
             SocialProfilesGenderSegmentField::Noop => "",
-            SocialProfilesGenderSegmentField::FallthroughString => "*",
+            SocialProfilesGenderSegmentField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -4198,8 +4456,9 @@ pub enum SocialProfilesGenderSegmentOperator {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SocialProfilesGenderSegmentOperator {
@@ -4208,7 +4467,7 @@ impl std::fmt::Display for SocialProfilesGenderSegmentOperator {
             SocialProfilesGenderSegmentOperator::Female => "female",
             SocialProfilesGenderSegmentOperator::Male => "male",
             SocialProfilesGenderSegmentOperator::Noop => "",
-            SocialProfilesGenderSegmentOperator::FallthroughString => "*",
+            SocialProfilesGenderSegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -4249,6 +4508,10 @@ pub struct SocialProfilesGenderSegment {
         skip_serializing_if = "SocialProfilesGenderSegmentOperator::is_noop"
     )]
     pub value: SocialProfilesGenderSegmentOperator,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -4258,8 +4521,9 @@ pub enum SocialProfilesInfluenceSegmentConditionType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SocialProfilesInfluenceSegmentConditionType {
@@ -4267,7 +4531,7 @@ impl std::fmt::Display for SocialProfilesInfluenceSegmentConditionType {
         match self {
             SocialProfilesInfluenceSegmentConditionType::SocialInfluence => "SocialInfluence",
             SocialProfilesInfluenceSegmentConditionType::Noop => "",
-            SocialProfilesInfluenceSegmentConditionType::FallthroughString => "*",
+            SocialProfilesInfluenceSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -4289,8 +4553,9 @@ pub enum SocialProfilesInfluenceSegmentField {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SocialProfilesInfluenceSegmentField {
@@ -4298,7 +4563,7 @@ impl std::fmt::Display for SocialProfilesInfluenceSegmentField {
         match self {
             SocialProfilesInfluenceSegmentField::SocialInfluence => "social_influence",
             SocialProfilesInfluenceSegmentField::Noop => "",
-            SocialProfilesInfluenceSegmentField::FallthroughString => "*",
+            SocialProfilesInfluenceSegmentField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -4340,6 +4605,10 @@ pub struct SocialProfilesInfluenceSegment {
         deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub value: f64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -4349,8 +4618,9 @@ pub enum SocialProfilesNetworkSegmentConditionType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SocialProfilesNetworkSegmentConditionType {
@@ -4358,7 +4628,7 @@ impl std::fmt::Display for SocialProfilesNetworkSegmentConditionType {
         match self {
             SocialProfilesNetworkSegmentConditionType::SocialNetworkMember => "SocialNetworkMember",
             SocialProfilesNetworkSegmentConditionType::Noop => "",
-            SocialProfilesNetworkSegmentConditionType::FallthroughString => "*",
+            SocialProfilesNetworkSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -4380,8 +4650,9 @@ pub enum SocialProfilesNetworkSegmentField {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SocialProfilesNetworkSegmentField {
@@ -4389,7 +4660,7 @@ impl std::fmt::Display for SocialProfilesNetworkSegmentField {
         match self {
             SocialProfilesNetworkSegmentField::SocialNetwork => "social_network",
             SocialProfilesNetworkSegmentField::Noop => "",
-            SocialProfilesNetworkSegmentField::FallthroughString => "*",
+            SocialProfilesNetworkSegmentField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -4431,8 +4702,9 @@ pub enum SocialProfilesNetworkSegmentOperator {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SocialProfilesNetworkSegmentOperator {
@@ -4450,7 +4722,7 @@ impl std::fmt::Display for SocialProfilesNetworkSegmentOperator {
             SocialProfilesNetworkSegmentOperator::Yelp => "yelp",
             SocialProfilesNetworkSegmentOperator::Youtube => "youtube",
             SocialProfilesNetworkSegmentOperator::Noop => "",
-            SocialProfilesNetworkSegmentOperator::FallthroughString => "*",
+            SocialProfilesNetworkSegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -4491,6 +4763,10 @@ pub struct SocialProfilesNetworkSegment {
         skip_serializing_if = "SocialProfilesNetworkSegmentOperator::is_noop"
     )]
     pub value: SocialProfilesNetworkSegmentOperator,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -4500,8 +4776,9 @@ pub enum SocialProfilesNetworkFollowSegmentConditionType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SocialProfilesNetworkFollowSegmentConditionType {
@@ -4511,7 +4788,7 @@ impl std::fmt::Display for SocialProfilesNetworkFollowSegmentConditionType {
                 "SocialNetworkFollow"
             }
             SocialProfilesNetworkFollowSegmentConditionType::Noop => "",
-            SocialProfilesNetworkFollowSegmentConditionType::FallthroughString => "*",
+            SocialProfilesNetworkFollowSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -4535,8 +4812,9 @@ pub enum SocialProfilesNetworkFollowSegmentOperator {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SocialProfilesNetworkFollowSegmentOperator {
@@ -4545,7 +4823,7 @@ impl std::fmt::Display for SocialProfilesNetworkFollowSegmentOperator {
             SocialProfilesNetworkFollowSegmentOperator::Follow => "follow",
             SocialProfilesNetworkFollowSegmentOperator::Notfollow => "notfollow",
             SocialProfilesNetworkFollowSegmentOperator::Noop => "",
-            SocialProfilesNetworkFollowSegmentOperator::FallthroughString => "*",
+            SocialProfilesNetworkFollowSegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -4567,8 +4845,9 @@ pub enum SocialProfilesNetworkFollowSegmentOperatorData {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SocialProfilesNetworkFollowSegmentOperatorData {
@@ -4576,7 +4855,7 @@ impl std::fmt::Display for SocialProfilesNetworkFollowSegmentOperatorData {
         match self {
             SocialProfilesNetworkFollowSegmentOperatorData::TwitterFollow => "twitter_follow",
             SocialProfilesNetworkFollowSegmentOperatorData::Noop => "",
-            SocialProfilesNetworkFollowSegmentOperatorData::FallthroughString => "*",
+            SocialProfilesNetworkFollowSegmentOperatorData::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -4620,6 +4899,10 @@ pub struct SocialProfilesNetworkFollowSegment {
         skip_serializing_if = "SocialProfilesNetworkFollowSegmentOperatorData::is_noop"
     )]
     pub value: SocialProfilesNetworkFollowSegmentOperatorData,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -4629,8 +4912,9 @@ pub enum AddressMergeFieldSegmentConditionType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AddressMergeFieldSegmentConditionType {
@@ -4638,7 +4922,7 @@ impl std::fmt::Display for AddressMergeFieldSegmentConditionType {
         match self {
             AddressMergeFieldSegmentConditionType::AddressMerge => "AddressMerge",
             AddressMergeFieldSegmentConditionType::Noop => "",
-            AddressMergeFieldSegmentConditionType::FallthroughString => "*",
+            AddressMergeFieldSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -4666,8 +4950,9 @@ pub enum AddressMergeFieldSegmentOperator {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AddressMergeFieldSegmentOperator {
@@ -4678,7 +4963,7 @@ impl std::fmt::Display for AddressMergeFieldSegmentOperator {
             AddressMergeFieldSegmentOperator::Contains => "contains",
             AddressMergeFieldSegmentOperator::Notcontain => "notcontain",
             AddressMergeFieldSegmentOperator::Noop => "",
-            AddressMergeFieldSegmentOperator::FallthroughString => "*",
+            AddressMergeFieldSegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -4724,6 +5009,10 @@ pub struct AddressMergeFieldSegment {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub value: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -4733,8 +5022,9 @@ pub enum AddressZipMergeFieldSegmentConditionType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AddressZipMergeFieldSegmentConditionType {
@@ -4742,7 +5032,7 @@ impl std::fmt::Display for AddressZipMergeFieldSegmentConditionType {
         match self {
             AddressZipMergeFieldSegmentConditionType::ZipMerge => "ZipMerge",
             AddressZipMergeFieldSegmentConditionType::Noop => "",
-            AddressZipMergeFieldSegmentConditionType::FallthroughString => "*",
+            AddressZipMergeFieldSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -4764,8 +5054,9 @@ pub enum AddressZipMergeFieldSegmentOperator {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AddressZipMergeFieldSegmentOperator {
@@ -4773,7 +5064,7 @@ impl std::fmt::Display for AddressZipMergeFieldSegmentOperator {
         match self {
             AddressZipMergeFieldSegmentOperator::Geoin => "geoin",
             AddressZipMergeFieldSegmentOperator::Noop => "",
-            AddressZipMergeFieldSegmentOperator::FallthroughString => "*",
+            AddressZipMergeFieldSegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -4828,6 +5119,10 @@ pub struct AddressZipMergeFieldSegment {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub value: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra_fields: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -4837,8 +5132,9 @@ pub enum BirthdayMergeFieldSegmentConditionType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for BirthdayMergeFieldSegmentConditionType {
@@ -4846,7 +5142,7 @@ impl std::fmt::Display for BirthdayMergeFieldSegmentConditionType {
         match self {
             BirthdayMergeFieldSegmentConditionType::BirthdayMerge => "BirthdayMerge",
             BirthdayMergeFieldSegmentConditionType::Noop => "",
-            BirthdayMergeFieldSegmentConditionType::FallthroughString => "*",
+            BirthdayMergeFieldSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -4874,8 +5170,9 @@ pub enum BirthdayMergeFieldSegmentOperator {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for BirthdayMergeFieldSegmentOperator {
@@ -4886,7 +5183,7 @@ impl std::fmt::Display for BirthdayMergeFieldSegmentOperator {
             BirthdayMergeFieldSegmentOperator::Is => "is",
             BirthdayMergeFieldSegmentOperator::Not => "not",
             BirthdayMergeFieldSegmentOperator::Noop => "",
-            BirthdayMergeFieldSegmentOperator::FallthroughString => "*",
+            BirthdayMergeFieldSegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -4932,6 +5229,10 @@ pub struct BirthdayMergeFieldSegment {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub value: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -4941,8 +5242,9 @@ pub enum DateMergeFieldSegmentConditionType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DateMergeFieldSegmentConditionType {
@@ -4950,7 +5252,7 @@ impl std::fmt::Display for DateMergeFieldSegmentConditionType {
         match self {
             DateMergeFieldSegmentConditionType::DateMerge => "DateMerge",
             DateMergeFieldSegmentConditionType::Noop => "",
-            DateMergeFieldSegmentConditionType::FallthroughString => "*",
+            DateMergeFieldSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -4982,8 +5284,9 @@ pub enum DateMergeFieldSegmentOperator {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DateMergeFieldSegmentOperator {
@@ -4996,7 +5299,7 @@ impl std::fmt::Display for DateMergeFieldSegmentOperator {
             DateMergeFieldSegmentOperator::Less => "less",
             DateMergeFieldSegmentOperator::Not => "not",
             DateMergeFieldSegmentOperator::Noop => "",
-            DateMergeFieldSegmentOperator::FallthroughString => "*",
+            DateMergeFieldSegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -5042,6 +5345,10 @@ pub struct DateMergeFieldSegment {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub value: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -5051,8 +5358,9 @@ pub enum DropdownRadioMergeFieldSegmentConditionType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DropdownRadioMergeFieldSegmentConditionType {
@@ -5060,7 +5368,7 @@ impl std::fmt::Display for DropdownRadioMergeFieldSegmentConditionType {
         match self {
             DropdownRadioMergeFieldSegmentConditionType::SelectMerge => "SelectMerge",
             DropdownRadioMergeFieldSegmentConditionType::Noop => "",
-            DropdownRadioMergeFieldSegmentConditionType::FallthroughString => "*",
+            DropdownRadioMergeFieldSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -5092,8 +5400,9 @@ pub enum DropdownRadioMergeFieldSegmentOperator {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DropdownRadioMergeFieldSegmentOperator {
@@ -5106,7 +5415,7 @@ impl std::fmt::Display for DropdownRadioMergeFieldSegmentOperator {
             DropdownRadioMergeFieldSegmentOperator::Not => "not",
             DropdownRadioMergeFieldSegmentOperator::Notcontain => "notcontain",
             DropdownRadioMergeFieldSegmentOperator::Noop => "",
-            DropdownRadioMergeFieldSegmentOperator::FallthroughString => "*",
+            DropdownRadioMergeFieldSegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -5152,6 +5461,10 @@ pub struct DropdownRadioMergeFieldSegment {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub value: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -5161,8 +5474,9 @@ pub enum TextNumberMergeFieldSegmentConditionType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for TextNumberMergeFieldSegmentConditionType {
@@ -5170,7 +5484,7 @@ impl std::fmt::Display for TextNumberMergeFieldSegmentConditionType {
         match self {
             TextNumberMergeFieldSegmentConditionType::TextMerge => "TextMerge",
             TextNumberMergeFieldSegmentConditionType::Noop => "",
-            TextNumberMergeFieldSegmentConditionType::FallthroughString => "*",
+            TextNumberMergeFieldSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -5210,8 +5524,9 @@ pub enum TextNumberMergeFieldSegmentOperator {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for TextNumberMergeFieldSegmentOperator {
@@ -5228,7 +5543,7 @@ impl std::fmt::Display for TextNumberMergeFieldSegmentOperator {
             TextNumberMergeFieldSegmentOperator::Notcontain => "notcontain",
             TextNumberMergeFieldSegmentOperator::Starts => "starts",
             TextNumberMergeFieldSegmentOperator::Noop => "",
-            TextNumberMergeFieldSegmentOperator::FallthroughString => "*",
+            TextNumberMergeFieldSegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -5274,6 +5589,10 @@ pub struct TextNumberMergeFieldSegment {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub value: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -5283,8 +5602,9 @@ pub enum EmailSegmentConditionType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EmailSegmentConditionType {
@@ -5292,7 +5612,7 @@ impl std::fmt::Display for EmailSegmentConditionType {
         match self {
             EmailSegmentConditionType::EmailAddress => "EmailAddress",
             EmailSegmentConditionType::Noop => "",
-            EmailSegmentConditionType::FallthroughString => "*",
+            EmailSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -5316,8 +5636,9 @@ pub enum EmailSegmentField {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EmailSegmentField {
@@ -5326,7 +5647,7 @@ impl std::fmt::Display for EmailSegmentField {
             EmailSegmentField::Email => "EMAIL",
             EmailSegmentField::Merge0 => "merge0",
             EmailSegmentField::Noop => "",
-            EmailSegmentField::FallthroughString => "*",
+            EmailSegmentField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -5362,8 +5683,9 @@ pub enum EmailSegmentOperator {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EmailSegmentOperator {
@@ -5378,7 +5700,7 @@ impl std::fmt::Display for EmailSegmentOperator {
             EmailSegmentOperator::Notcontain => "notcontain",
             EmailSegmentOperator::Starts => "starts",
             EmailSegmentOperator::Noop => "",
-            EmailSegmentOperator::FallthroughString => "*",
+            EmailSegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -5417,6 +5739,10 @@ pub struct EmailSegment {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub value: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -5426,8 +5752,9 @@ pub enum PredictedGenderSegmentConditionType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PredictedGenderSegmentConditionType {
@@ -5435,7 +5762,7 @@ impl std::fmt::Display for PredictedGenderSegmentConditionType {
         match self {
             PredictedGenderSegmentConditionType::PredictedGender => "PredictedGender",
             PredictedGenderSegmentConditionType::Noop => "",
-            PredictedGenderSegmentConditionType::FallthroughString => "*",
+            PredictedGenderSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -5457,8 +5784,9 @@ pub enum PredictedGenderSegmentField {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PredictedGenderSegmentField {
@@ -5466,7 +5794,7 @@ impl std::fmt::Display for PredictedGenderSegmentField {
         match self {
             PredictedGenderSegmentField::PredictedGender => "predicted_gender",
             PredictedGenderSegmentField::Noop => "",
-            PredictedGenderSegmentField::FallthroughString => "*",
+            PredictedGenderSegmentField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -5504,6 +5832,10 @@ pub struct PredictedGenderSegment {
         skip_serializing_if = "SocialProfilesGenderSegmentOperator::is_noop"
     )]
     pub value: SocialProfilesGenderSegmentOperator,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -5513,8 +5845,9 @@ pub enum PredictedAgeSegmentConditionType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PredictedAgeSegmentConditionType {
@@ -5522,7 +5855,7 @@ impl std::fmt::Display for PredictedAgeSegmentConditionType {
         match self {
             PredictedAgeSegmentConditionType::PredictedAge => "PredictedAge",
             PredictedAgeSegmentConditionType::Noop => "",
-            PredictedAgeSegmentConditionType::FallthroughString => "*",
+            PredictedAgeSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -5544,8 +5877,9 @@ pub enum PredictedAgeSegmentField {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PredictedAgeSegmentField {
@@ -5553,7 +5887,7 @@ impl std::fmt::Display for PredictedAgeSegmentField {
         match self {
             PredictedAgeSegmentField::PredictedAgeRange => "predicted_age_range",
             PredictedAgeSegmentField::Noop => "",
-            PredictedAgeSegmentField::FallthroughString => "*",
+            PredictedAgeSegmentField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -5575,8 +5909,9 @@ pub enum PredictedAgeSegmentOperator {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PredictedAgeSegmentOperator {
@@ -5584,7 +5919,7 @@ impl std::fmt::Display for PredictedAgeSegmentOperator {
         match self {
             PredictedAgeSegmentOperator::Is => "is",
             PredictedAgeSegmentOperator::Noop => "",
-            PredictedAgeSegmentOperator::FallthroughString => "*",
+            PredictedAgeSegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -5616,8 +5951,9 @@ pub enum PredictedAgeSegmentOperatorData {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PredictedAgeSegmentOperatorData {
@@ -5630,7 +5966,7 @@ impl std::fmt::Display for PredictedAgeSegmentOperatorData {
             PredictedAgeSegmentOperatorData::FiveThousandFiveHundredAndSixtyFour => "55-64",
             PredictedAgeSegmentOperatorData::SixtyFive => "65+",
             PredictedAgeSegmentOperatorData::Noop => "",
-            PredictedAgeSegmentOperatorData::FallthroughString => "*",
+            PredictedAgeSegmentOperatorData::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -5668,6 +6004,10 @@ pub struct PredictedAgeSegment {
         skip_serializing_if = "PredictedAgeSegmentOperatorData::is_noop"
     )]
     pub value: PredictedAgeSegmentOperatorData,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -5677,8 +6017,9 @@ pub enum NewSubscribersPrebuiltSegmentConditionType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for NewSubscribersPrebuiltSegmentConditionType {
@@ -5686,7 +6027,7 @@ impl std::fmt::Display for NewSubscribersPrebuiltSegmentConditionType {
         match self {
             NewSubscribersPrebuiltSegmentConditionType::NewSubscribers => "NewSubscribers",
             NewSubscribersPrebuiltSegmentConditionType::Noop => "",
-            NewSubscribersPrebuiltSegmentConditionType::FallthroughString => "*",
+            NewSubscribersPrebuiltSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -5708,8 +6049,9 @@ pub enum NewSubscribersPrebuiltSegmentField {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for NewSubscribersPrebuiltSegmentField {
@@ -5717,7 +6059,7 @@ impl std::fmt::Display for NewSubscribersPrebuiltSegmentField {
         match self {
             NewSubscribersPrebuiltSegmentField::TimestampOpt => "timestamp_opt",
             NewSubscribersPrebuiltSegmentField::Noop => "",
-            NewSubscribersPrebuiltSegmentField::FallthroughString => "*",
+            NewSubscribersPrebuiltSegmentField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -5739,8 +6081,9 @@ pub enum NewSubscribersPrebuiltSegmentOperator {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for NewSubscribersPrebuiltSegmentOperator {
@@ -5748,7 +6091,7 @@ impl std::fmt::Display for NewSubscribersPrebuiltSegmentOperator {
         match self {
             NewSubscribersPrebuiltSegmentOperator::DateWithin => "date_within",
             NewSubscribersPrebuiltSegmentOperator::Noop => "",
-            NewSubscribersPrebuiltSegmentOperator::FallthroughString => "*",
+            NewSubscribersPrebuiltSegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -5787,6 +6130,10 @@ pub struct NewSubscribersPrebuiltSegment {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub value: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types:
@@ -6321,6 +6668,10 @@ pub struct SegmentOpts {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub saved_segment_id: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// List settings for the Automation.
@@ -6366,6 +6717,10 @@ pub struct List {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub store_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The settings for the Automation workflow.
@@ -6439,6 +6794,10 @@ pub struct Settings {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub use_conversation: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Deprecated
@@ -6460,6 +6819,10 @@ pub struct Salesforce {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub notes: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Deprecated
@@ -6473,6 +6836,10 @@ pub struct Capsule {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub notes: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The tracking options for the Automation.
@@ -6547,6 +6914,10 @@ pub struct Tracking {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub text_clicks: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -6591,8 +6962,9 @@ pub enum WorkflowType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for WorkflowType {
@@ -6616,7 +6988,7 @@ impl std::fmt::Display for WorkflowType {
             WorkflowType::VisitUrl => "visitUrl",
             WorkflowType::WelcomeSeries => "welcomeSeries",
             WorkflowType::Noop => "",
-            WorkflowType::FallthroughString => "*",
+            WorkflowType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -6647,8 +7019,9 @@ pub enum Days {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Days {
@@ -6662,7 +7035,7 @@ impl std::fmt::Display for Days {
             Days::Tuesday => "tuesday",
             Days::Wednesday => "wednesday",
             Days::Noop => "",
-            Days::FallthroughString => "*",
+            Days::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -6688,8 +7061,9 @@ pub enum EmailSendTimeSettings {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EmailSendTimeSettings {
@@ -6699,7 +7073,7 @@ impl std::fmt::Display for EmailSendTimeSettings {
             EmailSendTimeSettings::SendAt => "send_at",
             EmailSendTimeSettings::SendBetween => "send_between",
             EmailSendTimeSettings::Noop => "",
-            EmailSendTimeSettings::FallthroughString => "*",
+            EmailSendTimeSettings::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -6723,6 +7097,10 @@ pub struct Hours {
         rename = "type"
     )]
     pub type_: EmailSendTimeSettings,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A workflow's runtime settings for an Automation.
@@ -6742,6 +7120,10 @@ pub struct Runtime {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub hours: Option<Hours>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Available triggers for Automation workflows.
@@ -6775,6 +7157,10 @@ pub struct TriggerSettings {
      */
     #[serde(default, skip_serializing_if = "WorkflowType::is_noop")]
     pub workflow_type: WorkflowType,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A summary of opens and clicks for sent campaigns.
@@ -6834,6 +7220,10 @@ pub struct ReportSummary {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub unique_opens: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A summary of an individual Automation workflow's settings and content.
@@ -6915,6 +7305,10 @@ pub struct Automations {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub trigger_settings: Option<TriggerSettings>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// An array of objects, each representing an Automation workflow.
@@ -6948,6 +7342,10 @@ pub struct GetAutomationsResponse {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_items: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// List settings for the Automation.
@@ -6971,6 +7369,10 @@ pub struct Recipients {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub store_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The settings for the Automation workflow.
@@ -6994,6 +7396,10 @@ pub struct AutomationCampaignSettings {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub reply_to: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Trigger settings for the Automation.
@@ -7008,6 +7414,10 @@ pub struct AutomationTrigger {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub workflow_type: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A summary of an individual Automation workflow's settings and content.
@@ -7026,6 +7436,10 @@ pub struct AutomationWorkflow {
      * Trigger settings for the Automation.
      */
     pub trigger_settings: AutomationTrigger,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -7044,8 +7458,9 @@ pub enum DelayType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DelayType {
@@ -7056,7 +7471,7 @@ impl std::fmt::Display for DelayType {
             DelayType::Now => "now",
             DelayType::Week => "week",
             DelayType::Noop => "",
-            DelayType::FallthroughString => "*",
+            DelayType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -7080,8 +7495,9 @@ pub enum Direction {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Direction {
@@ -7090,7 +7506,7 @@ impl std::fmt::Display for Direction {
             Direction::After => "after",
             Direction::Before => "before",
             Direction::Noop => "",
-            Direction::FallthroughString => "*",
+            Direction::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -7176,8 +7592,9 @@ pub enum Action {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Action {
@@ -7217,7 +7634,7 @@ impl std::fmt::Display for Action {
             Action::Signup => "signup",
             Action::TagAdd => "tag_add",
             Action::Noop => "",
-            Action::FallthroughString => "*",
+            Action::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -7274,6 +7691,10 @@ pub struct Delay {
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
     pub type_: Option<DelayType>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// An object representing all segmentation options. This object should contain a `saved_segment_id` to use an existing segment, or you can create a new segment by including both `match` and `conditions` options.
@@ -7311,6 +7732,10 @@ pub struct SegmentOptions {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub saved_segment_id: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// List settings for the campaign.
@@ -7365,6 +7790,10 @@ pub struct AutomationEmailsList {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub segment_text: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Settings for the campaign including the email subject, from name, and from email address.
@@ -7481,6 +7910,10 @@ pub struct CampaignSettings {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub title: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The tracking options for a campaign.
@@ -7555,6 +7988,10 @@ pub struct CampaignTrackingOptions {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub text_clicks: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The preview for the campaign, rendered by social networks like Facebook and Twitter. [Learn more](https://mailchimp.com/help/enable-and-customize-social-cards/).
@@ -7587,6 +8024,10 @@ pub struct SocialCard {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub title: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// For sent campaigns, a summary of opens and clicks.
@@ -7646,6 +8087,10 @@ pub struct CampaignReportSummary {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub unique_opens: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A summary of an individual Automation workflow email.
@@ -7807,6 +8252,10 @@ pub struct Emails {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub workflow_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A summary of the emails in an Automation workflow.
@@ -7840,6 +8289,10 @@ pub struct AutomationEmails {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_items: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Settings for the campaign including the email subject, from name, and from email address.
@@ -7890,6 +8343,10 @@ pub struct UpdateInformationAboutASpecificWorkflowEmailCampaignSettings {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub title: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -7902,8 +8359,9 @@ pub enum DelayDirection {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DelayDirection {
@@ -7911,7 +8369,7 @@ impl std::fmt::Display for DelayDirection {
         match self {
             DelayDirection::After => "after",
             DelayDirection::Noop => "",
-            DelayDirection::FallthroughString => "*",
+            DelayDirection::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -7937,8 +8395,9 @@ pub enum DelayAction {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DelayAction {
@@ -7948,7 +8407,7 @@ impl std::fmt::Display for DelayAction {
             DelayAction::EcommAbandonedCart => "ecomm_abandoned_cart",
             DelayAction::Signup => "signup",
             DelayAction::Noop => "",
-            DelayAction::FallthroughString => "*",
+            DelayAction::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -7987,6 +8446,10 @@ pub struct AutomationDelay {
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
     pub type_: Option<DelayType>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Update information about an individual Automation workflow email.
@@ -8002,6 +8465,10 @@ pub struct UpdateInformationAboutASpecificWorkflowEmail {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub settings: Option<UpdateInformationAboutASpecificWorkflowEmailCampaignSettings>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Information about subscribers in an Automation email queue.
@@ -8071,6 +8538,10 @@ pub struct Queue {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub workflow_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// An automation workflow
@@ -8122,6 +8593,10 @@ pub struct GetAutomationsEmailsQueueResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub workflow_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Information about subscribers in an Automation email queue.
@@ -8199,6 +8674,10 @@ pub struct SubscriberInAutomationQueueData {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub workflow_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A summary of a subscriber removed from an Automation workflow.
@@ -8250,6 +8729,10 @@ pub struct Subscribers {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub workflow_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A summary of the subscribers who were removed from an Automation workflow.
@@ -8292,6 +8775,10 @@ pub struct RemovedSubscribers {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub workflow_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -8312,8 +8799,9 @@ pub enum BatchOperationsStatus {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for BatchOperationsStatus {
@@ -8325,7 +8813,7 @@ impl std::fmt::Display for BatchOperationsStatus {
             BatchOperationsStatus::Preprocessing => "preprocessing",
             BatchOperationsStatus::Started => "started",
             BatchOperationsStatus::Noop => "",
-            BatchOperationsStatus::FallthroughString => "*",
+            BatchOperationsStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -8418,6 +8906,10 @@ pub struct Batch {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_operations: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A summary of batch requests that have been made.
@@ -8451,6 +8943,10 @@ pub struct BatchOperations {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_items: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -8471,8 +8967,9 @@ pub enum HttpMethod {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for HttpMethod {
@@ -8484,7 +8981,7 @@ impl std::fmt::Display for HttpMethod {
             HttpMethod::Post => "POST",
             HttpMethod::Put => "PUT",
             HttpMethod::Noop => "",
-            HttpMethod::FallthroughString => "*",
+            HttpMethod::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -8498,7 +8995,12 @@ impl HttpMethod {
 
 /// Any request query parameters. Example parameters: {"count":10, "offset":0}
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Params {}
+pub struct Params {
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Operations {
@@ -8530,6 +9032,10 @@ pub struct Operations {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub path: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -8543,6 +9049,10 @@ pub struct PostBatchesRequest {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub operations: Vec<Operations>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A webhook configured for batch status updates.
@@ -8576,6 +9086,10 @@ pub struct Webhooks {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Manage webhooks for batch requests.
@@ -8609,6 +9123,10 @@ pub struct BatchWebhooks {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub webhooks: Vec<Webhooks>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Add a new Batch Webook.
@@ -8623,6 +9141,10 @@ pub struct BatchWebhook {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A folder used to organize templates.
@@ -8665,6 +9187,10 @@ pub struct Folders {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A list of template folders
@@ -8698,6 +9224,10 @@ pub struct TemplateFolders {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_items: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A folder used to organize campaigns.
@@ -8740,6 +9270,10 @@ pub struct CampaignFolder {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A list of campaign folders
@@ -8773,6 +9307,10 @@ pub struct CampaignFolders {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_items: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -8793,8 +9331,9 @@ pub enum CampaignType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for CampaignType {
@@ -8806,7 +9345,7 @@ impl std::fmt::Display for CampaignType {
             CampaignType::Rss => "rss",
             CampaignType::Variate => "variate",
             CampaignType::Noop => "",
-            CampaignType::FallthroughString => "*",
+            CampaignType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -8836,8 +9375,9 @@ pub enum GetCampaignsStatus {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GetCampaignsStatus {
@@ -8849,7 +9389,7 @@ impl std::fmt::Display for GetCampaignsStatus {
             GetCampaignsStatus::Sending => "sending",
             GetCampaignsStatus::Sent => "sent",
             GetCampaignsStatus::Noop => "",
-            GetCampaignsStatus::FallthroughString => "*",
+            GetCampaignsStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -8873,8 +9413,9 @@ pub enum SortField {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SortField {
@@ -8883,7 +9424,7 @@ impl std::fmt::Display for SortField {
             SortField::CreateTime => "create_time",
             SortField::SendTime => "send_time",
             SortField::Noop => "",
-            SortField::FallthroughString => "*",
+            SortField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -8907,8 +9448,9 @@ pub enum SortDir {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SortDir {
@@ -8917,7 +9459,7 @@ impl std::fmt::Display for SortDir {
             SortDir::Asc => "ASC",
             SortDir::Desc => "DESC",
             SortDir::Noop => "",
-            SortDir::FallthroughString => "*",
+            SortDir::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -8953,8 +9495,9 @@ pub enum CampaignStatus {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for CampaignStatus {
@@ -8969,7 +9512,7 @@ impl std::fmt::Display for CampaignStatus {
             CampaignStatus::Sending => "sending",
             CampaignStatus::Sent => "sent",
             CampaignStatus::Noop => "",
-            CampaignStatus::FallthroughString => "*",
+            CampaignStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -8997,8 +9540,9 @@ pub enum ContentType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ContentType {
@@ -9009,7 +9553,7 @@ impl std::fmt::Display for ContentType {
             ContentType::Template => "template",
             ContentType::Url => "url",
             ContentType::Noop => "",
-            ContentType::FallthroughString => "*",
+            ContentType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -9169,6 +9713,10 @@ pub struct GetCampaignsResponseCampaignSettings {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub use_conversation: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -9187,8 +9735,9 @@ pub enum WinnerCriteria {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for WinnerCriteria {
@@ -9199,7 +9748,7 @@ impl std::fmt::Display for WinnerCriteria {
             WinnerCriteria::Opens => "opens",
             WinnerCriteria::TotalRevenue => "total_revenue",
             WinnerCriteria::Noop => "",
-            WinnerCriteria::FallthroughString => "*",
+            WinnerCriteria::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -9276,6 +9825,10 @@ pub struct Combinations {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub subject_line: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The settings specific to A/B test campaigns.
@@ -9376,6 +9929,10 @@ pub struct ABTestOptions {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub winning_combination_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -9392,8 +9949,9 @@ pub enum Frequency {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Frequency {
@@ -9403,7 +9961,7 @@ impl std::fmt::Display for Frequency {
             Frequency::Monthly => "monthly",
             Frequency::Weekly => "weekly",
             Frequency::Noop => "",
-            Frequency::FallthroughString => "*",
+            Frequency::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -9474,6 +10032,10 @@ pub struct DailySend {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub wednesday: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The schedule for sending the RSS Campaign.
@@ -9507,6 +10069,10 @@ pub struct Schedule {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub weekly_send_day: Option<Days>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// [RSS](https://mailchimp.com/help/share-your-blog-posts-with-mailchimp/) options for a campaign.
@@ -9548,6 +10114,10 @@ pub struct RssOpts {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub schedule: Option<Schedule>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -9564,8 +10134,9 @@ pub enum SplitTest {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SplitTest {
@@ -9575,7 +10146,7 @@ impl std::fmt::Display for SplitTest {
             SplitTest::Schedule => "schedule",
             SplitTest::Subject => "subject",
             SplitTest::Noop => "",
-            SplitTest::FallthroughString => "*",
+            SplitTest::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -9601,8 +10172,9 @@ pub enum PickWinner {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PickWinner {
@@ -9612,7 +10184,7 @@ impl std::fmt::Display for PickWinner {
             PickWinner::Manual => "manual",
             PickWinner::Opens => "opens",
             PickWinner::Noop => "",
-            PickWinner::FallthroughString => "*",
+            PickWinner::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -9636,8 +10208,9 @@ pub enum WaitTime {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for WaitTime {
@@ -9646,7 +10219,7 @@ impl std::fmt::Display for WaitTime {
             WaitTime::Days => "days",
             WaitTime::Hours => "hours",
             WaitTime::Noop => "",
-            WaitTime::FallthroughString => "*",
+            WaitTime::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -9775,6 +10348,10 @@ pub struct AbSplitOpts {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub wait_units: Option<WaitTime>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// E-Commerce stats for a campaign.
@@ -9807,6 +10384,10 @@ pub struct Ecommerce {
         deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub total_spent: f64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// For sent campaigns, a summary of opens, clicks, and e-commerce data.
@@ -9871,6 +10452,10 @@ pub struct GetCampaignsResponseCampaignReportSummary {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub unique_opens: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -9889,8 +10474,9 @@ pub enum CampaignDeliveryStatus {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for CampaignDeliveryStatus {
@@ -9901,7 +10487,7 @@ impl std::fmt::Display for CampaignDeliveryStatus {
             CampaignDeliveryStatus::Delivered => "delivered",
             CampaignDeliveryStatus::Delivering => "delivering",
             CampaignDeliveryStatus::Noop => "",
-            CampaignDeliveryStatus::FallthroughString => "*",
+            CampaignDeliveryStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -9955,6 +10541,10 @@ pub struct DeliveryStatus {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub status: Option<CampaignDeliveryStatus>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A summary of an individual campaign's settings and content.
@@ -10118,6 +10708,10 @@ pub struct Campaign {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub web_id: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// An array of campaigns.
@@ -10151,6 +10745,10 @@ pub struct GetCampaignsResponse {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_items: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// An object representing all segmentation options. This object should contain a `saved_segment_id` to use an existing segment, or you can create a new segment by including both `match` and `conditions` options.
@@ -10179,6 +10777,10 @@ pub struct CreatedCampaignListSegmentOptions {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub saved_segment_id: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// List settings for the campaign.
@@ -10198,6 +10800,10 @@ pub struct CreatedCampaignList {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub segment_opts: Option<CreatedCampaignListSegmentOptions>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The settings for your campaign, including subject, from name, reply-to address, and more.
@@ -10332,6 +10938,10 @@ pub struct CreatedCampaignSettings {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub use_conversation: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The settings specific to A/B test campaigns.
@@ -10396,6 +11006,10 @@ pub struct VariateSettings {
      */
     #[serde(default, skip_serializing_if = "WinnerCriteria::is_noop")]
     pub winner_criteria: WinnerCriteria,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// [RSS](https://mailchimp.com/help/share-your-blog-posts-with-mailchimp/) options, specific to an RSS campaign.
@@ -10428,6 +11042,10 @@ pub struct RssOptions {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub schedule: Option<Schedule>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -10442,8 +11060,9 @@ pub enum CreatedCampaignContentType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for CreatedCampaignContentType {
@@ -10452,7 +11071,7 @@ impl std::fmt::Display for CreatedCampaignContentType {
             CreatedCampaignContentType::Multichannel => "multichannel",
             CreatedCampaignContentType::Template => "template",
             CreatedCampaignContentType::Noop => "",
-            CreatedCampaignContentType::FallthroughString => "*",
+            CreatedCampaignContentType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -10511,6 +11130,10 @@ pub struct CreatedCampaign {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub variate_settings: Option<VariateSettings>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// List settings for the campaign.
@@ -10530,6 +11153,10 @@ pub struct CampaignList {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub segment_opts: Option<SegmentOptions>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The settings for your campaign, including subject, from name, reply-to address, and more.
@@ -10664,6 +11291,10 @@ pub struct CampaignSettingsData {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub use_conversation: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// [RSS](https://mailchimp.com/help/share-your-blog-posts-with-mailchimp/) options for a campaign.
@@ -10696,6 +11327,10 @@ pub struct CampaignRssOptions {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub schedule: Option<Schedule>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A summary of an individual campaign's settings and content.
@@ -10730,6 +11365,10 @@ pub struct CampaignData {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub variate_settings: Option<VariateSettings>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// List settings for the campaign.
@@ -10776,6 +11415,10 @@ pub struct CampaignListData {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub segment_text: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The settings specific to A/B test campaigns.
@@ -10876,6 +11519,10 @@ pub struct CampaignABTestOptions {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub winning_combination_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// [RSS](https://mailchimp.com/help/share-your-blog-posts-with-mailchimp/) options for a campaign.
@@ -10917,6 +11564,10 @@ pub struct CampaignRssOptionsData {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub schedule: Option<Schedule>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// For sent campaigns, a summary of opens and clicks.
@@ -10981,6 +11632,10 @@ pub struct CampaignReportSummaryData {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub unique_opens: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A summary of an individual campaign's settings and content.
@@ -11152,6 +11807,10 @@ pub struct CampaignDataType {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub web_id: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Choose whether the campaign should use [Batch Delivery](https://mailchimp.com/help/schedule-batch-delivery/). Cannot be set to `true` for campaigns using [Timewarp](https://mailchimp.com/help/use-timewarp/).
@@ -11175,6 +11834,10 @@ pub struct BatchDelivery {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub batch_delay: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -11198,6 +11861,10 @@ pub struct PostCampaignsActionsScheduleRequest {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub timewarp: Option<bool>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -11212,8 +11879,9 @@ pub enum SendType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SendType {
@@ -11222,7 +11890,7 @@ impl std::fmt::Display for SendType {
             SendType::Html => "html",
             SendType::Plaintext => "plaintext",
             SendType::Noop => "",
-            SendType::FallthroughString => "*",
+            SendType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -11250,6 +11918,10 @@ pub struct PostCampaignsActionsTestRequest {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub test_emails: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -11281,6 +11953,10 @@ pub struct VariateContents {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub plain_text: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The HTML and plain-text content for a campaign.
@@ -11332,6 +12008,10 @@ pub struct CampaignContent {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub variate_contents: Vec<VariateContents>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Use this template to generate the HTML content of the campaign
@@ -11351,6 +12031,10 @@ pub struct Template {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub sections: Option<Params>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -11373,8 +12057,9 @@ pub enum ArchiveType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ArchiveType {
@@ -11387,7 +12072,7 @@ impl std::fmt::Display for ArchiveType {
             ArchiveType::Tgz => "tgz",
             ArchiveType::Zip => "zip",
             ArchiveType::Noop => "",
-            ArchiveType::FallthroughString => "*",
+            ArchiveType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -11416,6 +12101,10 @@ pub struct Archive {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub archive_type: Option<ArchiveType>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Use this template to generate the HTML content for the campaign.
@@ -11435,6 +12124,10 @@ pub struct TemplateContent {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub sections: Option<Params>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -11485,6 +12178,10 @@ pub struct CampaignContentVariateContents {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The HTML and plain-text content for a campaign
@@ -11536,6 +12233,10 @@ pub struct CampaignContentData {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub variate_contents: Vec<CampaignContentVariateContents>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -11558,8 +12259,9 @@ pub enum Source {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Source {
@@ -11572,7 +12274,7 @@ impl std::fmt::Display for Source {
             Source::Sms => "sms",
             Source::Web => "web",
             Source::Noop => "",
-            Source::FallthroughString => "*",
+            Source::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -11682,6 +12384,10 @@ pub struct Feedback {
         deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A summary of the comment feedback for a specific campaign.
@@ -11724,6 +12430,10 @@ pub struct CampaignReports {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_items: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A specific feedback message from a specific campaign.
@@ -11755,6 +12465,10 @@ pub struct CampaignFeedback {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub message: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A specific feedback message from a specific campaign.
@@ -11855,6 +12569,10 @@ pub struct CampaignFeedbackData {
         deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A specific feedback message from a specific campaign.
@@ -11886,6 +12604,10 @@ pub struct CampaignFeedbackDataType {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub message: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -11902,8 +12624,9 @@ pub enum SendChecklistItemsType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SendChecklistItemsType {
@@ -11913,7 +12636,7 @@ impl std::fmt::Display for SendChecklistItemsType {
             SendChecklistItemsType::Success => "success",
             SendChecklistItemsType::Warning => "warning",
             SendChecklistItemsType::Noop => "",
-            SendChecklistItemsType::FallthroughString => "*",
+            SendChecklistItemsType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -11959,6 +12682,10 @@ pub struct Items {
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
     pub type_: Option<SendChecklistItemsType>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The send checklist for the campaign.
@@ -11991,6 +12718,10 @@ pub struct SendChecklist {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub items: Vec<Items>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The script used to connect your site with Mailchimp.
@@ -12014,6 +12745,10 @@ pub struct Script {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Information about a specific connected site.
@@ -12088,6 +12823,10 @@ pub struct Sites {
         deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A collection of connected sites in the account.
@@ -12121,6 +12860,10 @@ pub struct ConnectedSites {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_items: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Information about a specific connected site.
@@ -12144,6 +12887,10 @@ pub struct ConnectedSite {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub foreign_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -12158,8 +12905,9 @@ pub enum IsRead {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for IsRead {
@@ -12168,7 +12916,7 @@ impl std::fmt::Display for IsRead {
             IsRead::False => "false",
             IsRead::True => "true",
             IsRead::Noop => "",
-            IsRead::FallthroughString => "*",
+            IsRead::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -12236,6 +12984,10 @@ pub struct LastMessage {
         deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub timestamp: Option<chrono::DateTime<chrono::Utc>>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Details about an individual conversation. Conversation tracking is a feature available to paid accounts that lets you view replies to your campaigns in your Mailchimp account.
@@ -12328,6 +13080,10 @@ pub struct Conversation {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub unread_messages: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A collection of this account's tracked conversations.
@@ -12361,6 +13117,10 @@ pub struct TrackedConversations {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_items: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// An individual message in a conversation. Conversation tracking is a feature available to paid accounts that lets you view replies to your campaigns in your Mailchimp account.
@@ -12456,6 +13216,10 @@ pub struct ConversationMessage {
         deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub timestamp: Option<chrono::DateTime<chrono::Utc>>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Messages from a specific conversation.
@@ -12498,6 +13262,10 @@ pub struct CollectionOfConversationMessages {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_items: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -12510,8 +13278,9 @@ pub enum GetFileManagerFilesSortField {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GetFileManagerFilesSortField {
@@ -12519,7 +13288,7 @@ impl std::fmt::Display for GetFileManagerFilesSortField {
         match self {
             GetFileManagerFilesSortField::AddedDate => "added_date",
             GetFileManagerFilesSortField::Noop => "",
-            GetFileManagerFilesSortField::FallthroughString => "*",
+            GetFileManagerFilesSortField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -12543,8 +13312,9 @@ pub enum FileType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for FileType {
@@ -12553,7 +13323,7 @@ impl std::fmt::Display for FileType {
             FileType::File => "file",
             FileType::Image => "image",
             FileType::Noop => "",
-            FileType::FallthroughString => "*",
+            FileType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -12673,6 +13443,10 @@ pub struct Files {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub width: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A list of available images and files stored in the File Manager for the account.
@@ -12715,6 +13489,10 @@ pub struct FileManager {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_items: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// An individual file listed in the File Manager.
@@ -12747,6 +13525,10 @@ pub struct GalleryFile {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// An individual file listed in the File Manager.
@@ -12770,6 +13552,10 @@ pub struct GalleryFileData {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// An individual folder listed in the File Manager.
@@ -12830,6 +13616,10 @@ pub struct FileManagerFoldersGalleryFolder {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A list of all folders in the File Manager.
@@ -12863,6 +13653,10 @@ pub struct FileManagerFolders {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_items: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -12875,8 +13669,9 @@ pub enum GetListsSortField {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GetListsSortField {
@@ -12884,7 +13679,7 @@ impl std::fmt::Display for GetListsSortField {
         match self {
             GetListsSortField::DateCreated => "date_created",
             GetListsSortField::Noop => "",
-            GetListsSortField::FallthroughString => "*",
+            GetListsSortField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -12973,6 +13768,10 @@ pub struct ListContact {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub zip: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// [Default values for campaigns](https://mailchimp.com/help/edit-your-emails-subject-preview-text-from-name-or-from-email-address/) created for this list.
@@ -13014,6 +13813,10 @@ pub struct CampaignDefaults {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub subject: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -13028,8 +13831,9 @@ pub enum Visibility {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Visibility {
@@ -13038,7 +13842,7 @@ impl std::fmt::Display for Visibility {
             Visibility::Prv => "prv",
             Visibility::Pub => "pub",
             Visibility::Noop => "",
-            Visibility::FallthroughString => "*",
+            Visibility::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -13206,6 +14010,10 @@ pub struct Stats {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub unsubscribe_count_since_send: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Information about a specific list.
@@ -13389,6 +14197,10 @@ pub struct Lists {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub web_id: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Do particular authorization constraints around this collection limit creation of new instances?
@@ -13420,6 +14232,10 @@ pub struct Constraints {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub may_create: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A collection of subscriber lists for this account. Lists contain subscribers who have opted-in to receive correspondence from you or your organization.
@@ -13458,6 +14274,10 @@ pub struct SubscriberLists {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_items: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// [Contact information displayed in campaign footers](https://mailchimp.com/help/about-campaign-footers/) to comply with international spam laws.
@@ -13537,6 +14357,10 @@ pub struct SubscriberListContact {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub zip: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// [Default values for campaigns](https://mailchimp.com/help/edit-your-emails-subject-preview-text-from-name-or-from-email-address/) created for this list.
@@ -13578,6 +14402,10 @@ pub struct SubscriberListCampaignDefaults {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub subject: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Information about a specific list.
@@ -13659,6 +14487,10 @@ pub struct SubscriberList {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub use_archive_bar: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -13677,8 +14509,9 @@ pub enum MembersSubscribeUnsubscribeFromAListInBatchStatus {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for MembersSubscribeUnsubscribeFromAListInBatchStatus {
@@ -13689,7 +14522,7 @@ impl std::fmt::Display for MembersSubscribeUnsubscribeFromAListInBatchStatus {
             MembersSubscribeUnsubscribeFromAListInBatchStatus::Subscribed => "subscribed",
             MembersSubscribeUnsubscribeFromAListInBatchStatus::Unsubscribed => "unsubscribed",
             MembersSubscribeUnsubscribeFromAListInBatchStatus::Noop => "",
-            MembersSubscribeUnsubscribeFromAListInBatchStatus::FallthroughString => "*",
+            MembersSubscribeUnsubscribeFromAListInBatchStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -13725,6 +14558,10 @@ pub struct Location {
         deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub longitude: f64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Individuals who are currently or have been previously subscribed to this list, including members who have bounced or unsubscribed.
@@ -13824,6 +14661,10 @@ pub struct Members {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub vip: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Members to subscribe to or unsubscribe from a list.
@@ -13846,6 +14687,10 @@ pub struct MembersSubscribeUnsubscribeFromAListInBatch {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub update_existing: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -13866,8 +14711,9 @@ pub enum StatusIfNew {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for StatusIfNew {
@@ -13879,7 +14725,7 @@ impl std::fmt::Display for StatusIfNew {
             StatusIfNew::Transactional => "transactional",
             StatusIfNew::Unsubscribed => "unsubscribed",
             StatusIfNew::Noop => "",
-            StatusIfNew::FallthroughString => "*",
+            StatusIfNew::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -13912,6 +14758,10 @@ pub struct SubscriberStats {
         deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub avg_open_rate: f64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Subscriber location information.
@@ -13971,6 +14821,10 @@ pub struct BatchUpdateListMembersNewLocation {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub timezone: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The most recent Note added about this member.
@@ -14012,6 +14866,10 @@ pub struct Notes {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub note_id: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -14034,6 +14892,10 @@ pub struct Tags {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Individuals who are currently or have been previously subscribed to this list, including members who have bounced or unsubscribed.
@@ -14225,6 +15087,10 @@ pub struct NewMembers {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub vip: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -14239,8 +15105,9 @@ pub enum ErrorCode {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ErrorCode {
@@ -14249,7 +15116,7 @@ impl std::fmt::Display for ErrorCode {
             ErrorCode::ErrorContactExists => "ERROR_CONTACT_EXISTS",
             ErrorCode::ErrorGeneric => "ERROR_GENERIC",
             ErrorCode::Noop => "",
-            ErrorCode::FallthroughString => "*",
+            ErrorCode::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -14286,6 +15153,10 @@ pub struct Errors {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub error_code: Option<ErrorCode>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Batch update list members.
@@ -14355,6 +15226,10 @@ pub struct BatchUpdateListMembers {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub updated_members: Vec<NewMembers>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// [Contact information displayed in campaign footers](https://mailchimp.com/help/about-campaign-footers/) to comply with international spam laws.
@@ -14434,6 +15309,10 @@ pub struct SubscriberListContactData {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub zip: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Information about a specific list.
@@ -14515,6 +15394,10 @@ pub struct SubscriberListData {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub use_archive_bar: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Details of abuse complaints for a specific list. An abuse complaint occurs when your recipient clicks to 'report spam' in their email program.
@@ -14597,6 +15480,10 @@ pub struct AbuseReports {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub vip: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A collection of abuse complaints for a specific list. An abuse complaint occurs when your recipient clicks to 'report spam' in their email program.
@@ -14639,6 +15526,10 @@ pub struct AbuseComplaints {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_items: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// One day's worth of list activity. Doesn't include Automation activity.
@@ -14744,6 +15635,10 @@ pub struct Activity {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub unsubs: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Up to the previous 180 days of daily detailed aggregated activity stats for a specific list. Does not include AutoResponder or Automation activity.
@@ -14786,6 +15681,10 @@ pub struct ListActivity {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_items: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The email client.
@@ -14809,6 +15708,10 @@ pub struct Clients {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub members: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The top email clients based on user-agent strings.
@@ -14851,6 +15754,10 @@ pub struct EmailClients {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_items: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -14863,8 +15770,9 @@ pub enum GetListsGrowthHistorySortField {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GetListsGrowthHistorySortField {
@@ -14872,7 +15780,7 @@ impl std::fmt::Display for GetListsGrowthHistorySortField {
         match self {
             GetListsGrowthHistorySortField::Month => "month",
             GetListsGrowthHistorySortField::Noop => "",
-            GetListsGrowthHistorySortField::FallthroughString => "*",
+            GetListsGrowthHistorySortField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -15005,6 +15913,10 @@ pub struct History {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub unsubscribed: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A month-by-month summary of a specific list's growth activity.
@@ -15047,6 +15959,10 @@ pub struct GrowthHistory {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_items: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Interest categories organize interests, which are used to group subscribers based on their preferences. These correspond to Group Titles the application.
@@ -15103,6 +16019,10 @@ pub struct Categories {
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
     pub type_: Option<Type>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Information about this list's interest categories.
@@ -15145,6 +16065,10 @@ pub struct InterestGroupings {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_items: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Assign subscribers to interests to group them together. Interests are referred to as 'group names' in the Mailchimp application.
@@ -15214,6 +16138,10 @@ pub struct InterestsInterest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub subscriber_count: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A list of this category's interests
@@ -15265,6 +16193,10 @@ pub struct InterestsData {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_items: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -15281,8 +16213,9 @@ pub enum CollectionOfSegmentsType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for CollectionOfSegmentsType {
@@ -15292,7 +16225,7 @@ impl std::fmt::Display for CollectionOfSegmentsType {
             CollectionOfSegmentsType::Saved => "saved",
             CollectionOfSegmentsType::Static => "static",
             CollectionOfSegmentsType::Noop => "",
-            CollectionOfSegmentsType::FallthroughString => "*",
+            CollectionOfSegmentsType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -15321,6 +16254,10 @@ pub struct Options {
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "match")]
     pub match_: Option<Match>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Information about a specific segment.
@@ -15400,6 +16337,10 @@ pub struct Segments {
         deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A list of available segments.
@@ -15442,6 +16383,10 @@ pub struct CollectionOfSegments {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_items: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The [conditions of the segment](https://mailchimp.com/help/save-and-manage-segments/). Static and fuzzy segments don't have conditions.
@@ -15461,6 +16406,10 @@ pub struct ListConditions {
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "match")]
     pub match_: Option<Match>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Information about a specific list segment.
@@ -15489,6 +16438,10 @@ pub struct ListData {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub static_segment: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Members to add/remove to/from a static segment
@@ -15512,6 +16465,10 @@ pub struct MembersAddRemoveFromAStaticSegment {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub members_to_remove: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -15534,6 +16491,10 @@ pub struct BatchAddRemoveListMembersFromStaticSegmentErrors {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub error: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Batch add/remove List members to/from static segment
@@ -15603,6 +16564,10 @@ pub struct BatchAddRemoveListMembersFromStaticSegment {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_removed: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The [conditions of the segment](https://mailchimp.com/help/save-and-manage-segments/). Static and fuzzy segments don't have conditions.
@@ -15622,6 +16587,10 @@ pub struct ListConditionsData {
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "match")]
     pub match_: Option<Match>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Information about a specific list segment.
@@ -15650,6 +16619,10 @@ pub struct ListDataType {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub static_segment: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Individuals who are currently or have been previously subscribed to this list, including members who have bounced or unsubscribed.
@@ -15823,6 +16796,10 @@ pub struct ListMembers {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub vip: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// View members in a specific list segment.
@@ -15856,6 +16833,10 @@ pub struct SegmentMembers {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_items: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A list of tags matching the input query.
@@ -15879,6 +16860,10 @@ pub struct TagSearchResults {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_items: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -15901,8 +16886,9 @@ pub enum GetListsMembersStatus {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GetListsMembersStatus {
@@ -15915,7 +16901,7 @@ impl std::fmt::Display for GetListsMembersStatus {
             GetListsMembersStatus::Transactional => "transactional",
             GetListsMembersStatus::Unsubscribed => "unsubscribed",
             GetListsMembersStatus::Noop => "",
-            GetListsMembersStatus::FallthroughString => "*",
+            GetListsMembersStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -15941,8 +16927,9 @@ pub enum InterestMatch {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for InterestMatch {
@@ -15952,7 +16939,7 @@ impl std::fmt::Display for InterestMatch {
             InterestMatch::Any => "any",
             InterestMatch::None => "none",
             InterestMatch::Noop => "",
-            InterestMatch::FallthroughString => "*",
+            InterestMatch::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -15978,8 +16965,9 @@ pub enum GetListsMembersSortField {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GetListsMembersSortField {
@@ -15989,7 +16977,7 @@ impl std::fmt::Display for GetListsMembersSortField {
             GetListsMembersSortField::TimestampOpt => "timestamp_opt",
             GetListsMembersSortField::TimestampSignup => "timestamp_signup",
             GetListsMembersSortField::Noop => "",
-            GetListsMembersSortField::FallthroughString => "*",
+            GetListsMembersSortField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -16031,6 +17019,10 @@ pub struct EcommerceData {
         deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub total_revenue: f64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Open and click rates for this subscriber.
@@ -16059,6 +17051,10 @@ pub struct ListMembersSubscriberStats {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub ecommerce_data: Option<EcommerceData>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A single marketing permission a subscriber has either opted-in to or opted-out of.
@@ -16090,6 +17086,10 @@ pub struct MarketingPermission {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub text: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Individuals who are currently or have been previously subscribed to this list, including members who have bounced or unsubscribed.
@@ -16326,6 +17326,10 @@ pub struct ListMembersData {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub web_id: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Manage members of a specific Mailchimp list, including currently subscribed, unsubscribed, and bounced members.
@@ -16368,6 +17372,10 @@ pub struct ListMembersDataType {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_items: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A single marketing permission a subscriber has either opted-in to or opted-out of.
@@ -16390,6 +17398,10 @@ pub struct MarketingPermissions {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub marketing_permission_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Individuals who are currently or have been previously subscribed to this list, including members who have bounced or unsubscribed.
@@ -16507,6 +17519,10 @@ pub struct AddListMembers {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub vip: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Individuals who are currently or have been previously subscribed to this list, including members who have bounced or unsubscribed.
@@ -16620,6 +17636,10 @@ pub struct AddListMembersData {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub vip: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Individuals who are currently or have been previously subscribed to this list, including members who have bounced or unsubscribed.
@@ -16728,6 +17748,10 @@ pub struct AddListMembersDataType {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub vip: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Member activity events.
@@ -16797,6 +17821,10 @@ pub struct MemberActivity {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The last 50 member events for a list.
@@ -16848,6 +17876,10 @@ pub struct MemberActivityEvents {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_items: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -16860,8 +17892,9 @@ pub enum ActivityType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ActivityType {
@@ -16869,7 +17902,7 @@ impl std::fmt::Display for ActivityType {
         match self {
             ActivityType::Open => "open",
             ActivityType::Noop => "",
-            ActivityType::FallthroughString => "*",
+            ActivityType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -16916,6 +17949,10 @@ pub struct EmailOpens {
         deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub created_at_timestamp: Option<chrono::DateTime<chrono::Utc>>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -16928,8 +17965,9 @@ pub enum EmailClicksActivityType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EmailClicksActivityType {
@@ -16937,7 +17975,7 @@ impl std::fmt::Display for EmailClicksActivityType {
         match self {
             EmailClicksActivityType::Click => "click",
             EmailClicksActivityType::Noop => "",
-            EmailClicksActivityType::FallthroughString => "*",
+            EmailClicksActivityType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -16993,6 +18031,10 @@ pub struct EmailClicks {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub link_clicked: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -17005,8 +18047,9 @@ pub enum EmailBouncedActivityType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EmailBouncedActivityType {
@@ -17014,7 +18057,7 @@ impl std::fmt::Display for EmailBouncedActivityType {
         match self {
             EmailBouncedActivityType::Bounce => "bounce",
             EmailBouncedActivityType::Noop => "",
-            EmailBouncedActivityType::FallthroughString => "*",
+            EmailBouncedActivityType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -17038,8 +18081,9 @@ pub enum BounceType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for BounceType {
@@ -17048,7 +18092,7 @@ impl std::fmt::Display for BounceType {
             BounceType::Hard => "hard",
             BounceType::Soft => "soft",
             BounceType::Noop => "",
-            BounceType::FallthroughString => "*",
+            BounceType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -17108,6 +18152,10 @@ pub struct EmailBounced {
         deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub created_at_timestamp: Option<chrono::DateTime<chrono::Utc>>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -17120,8 +18168,9 @@ pub enum ListUnsubscribedActivityType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ListUnsubscribedActivityType {
@@ -17129,7 +18178,7 @@ impl std::fmt::Display for ListUnsubscribedActivityType {
         match self {
             ListUnsubscribedActivityType::Unsub => "unsub",
             ListUnsubscribedActivityType::Noop => "",
-            ListUnsubscribedActivityType::FallthroughString => "*",
+            ListUnsubscribedActivityType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -17193,6 +18242,10 @@ pub struct ListUnsubscribed {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub unsubscribe_reason: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -17205,8 +18258,9 @@ pub enum EmailSentActivityType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EmailSentActivityType {
@@ -17214,7 +18268,7 @@ impl std::fmt::Display for EmailSentActivityType {
         match self {
             EmailSentActivityType::Sent => "sent",
             EmailSentActivityType::Noop => "",
-            EmailSentActivityType::FallthroughString => "*",
+            EmailSentActivityType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -17261,6 +18315,10 @@ pub struct EmailSent {
         deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub created_at_timestamp: Option<chrono::DateTime<chrono::Utc>>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Activity feed item representing an individual reply in a conversation.
@@ -17359,6 +18417,10 @@ pub struct EmailConversation {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub thread_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -17371,8 +18433,9 @@ pub enum NoteActivityType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for NoteActivityType {
@@ -17380,7 +18443,7 @@ impl std::fmt::Display for NoteActivityType {
         match self {
             NoteActivityType::Note => "note",
             NoteActivityType::Noop => "",
-            NoteActivityType::FallthroughString => "*",
+            NoteActivityType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -17454,6 +18517,10 @@ pub struct Note {
         deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub updated_at_timestamp: Option<chrono::DateTime<chrono::Utc>>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -17466,8 +18533,9 @@ pub enum MarketingPermissionActivityType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for MarketingPermissionActivityType {
@@ -17475,7 +18543,7 @@ impl std::fmt::Display for MarketingPermissionActivityType {
         match self {
             MarketingPermissionActivityType::MarketingPermission => "marketing_permission",
             MarketingPermissionActivityType::Noop => "",
-            MarketingPermissionActivityType::FallthroughString => "*",
+            MarketingPermissionActivityType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -17530,6 +18598,10 @@ pub struct MarketingPermissionData {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub updated_by: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -17542,8 +18614,9 @@ pub enum PostcardSentActivityType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PostcardSentActivityType {
@@ -17551,7 +18624,7 @@ impl std::fmt::Display for PostcardSentActivityType {
         match self {
             PostcardSentActivityType::PostcardSent => "postcard_sent",
             PostcardSentActivityType::Noop => "",
-            PostcardSentActivityType::FallthroughString => "*",
+            PostcardSentActivityType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -17598,6 +18671,10 @@ pub struct PostcardSent {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub outreach_title: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -17610,8 +18687,9 @@ pub enum SquatterSignupActivityType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SquatterSignupActivityType {
@@ -17619,7 +18697,7 @@ impl std::fmt::Display for SquatterSignupActivityType {
         match self {
             SquatterSignupActivityType::SquatterSignup => "squatter_signup",
             SquatterSignupActivityType::Noop => "",
-            SquatterSignupActivityType::FallthroughString => "*",
+            SquatterSignupActivityType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -17666,6 +18744,10 @@ pub struct SquatterSignup {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub outreach_title: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -17678,8 +18760,9 @@ pub enum WebsiteSignupActivityType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for WebsiteSignupActivityType {
@@ -17687,7 +18770,7 @@ impl std::fmt::Display for WebsiteSignupActivityType {
         match self {
             WebsiteSignupActivityType::WebsiteSignup => "website_signup",
             WebsiteSignupActivityType::Noop => "",
-            WebsiteSignupActivityType::FallthroughString => "*",
+            WebsiteSignupActivityType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -17734,6 +18817,10 @@ pub struct WebsiteSignup {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub outreach_title: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -17746,8 +18833,9 @@ pub enum LandingPageSignupActivityType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for LandingPageSignupActivityType {
@@ -17755,7 +18843,7 @@ impl std::fmt::Display for LandingPageSignupActivityType {
         match self {
             LandingPageSignupActivityType::LandingPageSignup => "landing_page_signup",
             LandingPageSignupActivityType::Noop => "",
-            LandingPageSignupActivityType::FallthroughString => "*",
+            LandingPageSignupActivityType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -17802,6 +18890,10 @@ pub struct LandingPageSignup {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub outreach_title: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -17814,8 +18906,9 @@ pub enum EcommerceSignupActivityType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EcommerceSignupActivityType {
@@ -17823,7 +18916,7 @@ impl std::fmt::Display for EcommerceSignupActivityType {
         match self {
             EcommerceSignupActivityType::EcommerceSignup => "ecommerce_signup",
             EcommerceSignupActivityType::Noop => "",
-            EcommerceSignupActivityType::FallthroughString => "*",
+            EcommerceSignupActivityType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -17861,6 +18954,10 @@ pub struct EcommerceSignup {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub store_name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -17873,8 +18970,9 @@ pub enum GenericSignupActivityType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GenericSignupActivityType {
@@ -17882,7 +18980,7 @@ impl std::fmt::Display for GenericSignupActivityType {
         match self {
             GenericSignupActivityType::GenericSignup => "generic_signup",
             GenericSignupActivityType::Noop => "",
-            GenericSignupActivityType::FallthroughString => "*",
+            GenericSignupActivityType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -17920,6 +19018,10 @@ pub struct GenericSignup {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub signup_category: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -17932,8 +19034,9 @@ pub enum EcommerceOrderActivityType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EcommerceOrderActivityType {
@@ -17941,7 +19044,7 @@ impl std::fmt::Display for EcommerceOrderActivityType {
         match self {
             EcommerceOrderActivityType::Order => "order",
             EcommerceOrderActivityType::Noop => "",
-            EcommerceOrderActivityType::FallthroughString => "*",
+            EcommerceOrderActivityType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -18047,6 +19150,10 @@ pub struct Lines {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub quantity: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Activity feed item that represents an order.
@@ -18138,6 +19245,10 @@ pub struct EcommerceOrder {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub store_name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -18150,8 +19261,9 @@ pub enum ContactActivityEventType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ContactActivityEventType {
@@ -18159,7 +19271,7 @@ impl std::fmt::Display for ContactActivityEventType {
         match self {
             ContactActivityEventType::Event => "event",
             ContactActivityEventType::Noop => "",
-            ContactActivityEventType::FallthroughString => "*",
+            ContactActivityEventType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -18206,6 +19318,10 @@ pub struct ContactActivityEvent {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub event_properties: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -18218,8 +19334,9 @@ pub enum SurveyResponseActivityType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SurveyResponseActivityType {
@@ -18227,7 +19344,7 @@ impl std::fmt::Display for SurveyResponseActivityType {
         match self {
             SurveyResponseActivityType::SurveyResponse => "survey_response",
             SurveyResponseActivityType::Noop => "",
-            SurveyResponseActivityType::FallthroughString => "*",
+            SurveyResponseActivityType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -18274,6 +19391,10 @@ pub struct SurveyResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub survey_title: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types:
@@ -18532,6 +19653,10 @@ pub struct MemberActivityEventsData {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub list_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A list of tags assigned to a list member.
@@ -18565,6 +19690,10 @@ pub struct CollectionOfTags {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_items: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -18579,8 +19708,9 @@ pub enum MemberTagsTagStatus {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for MemberTagsTagStatus {
@@ -18589,7 +19719,7 @@ impl std::fmt::Display for MemberTagsTagStatus {
             MemberTagsTagStatus::Active => "active",
             MemberTagsTagStatus::Inactive => "inactive",
             MemberTagsTagStatus::Noop => "",
-            MemberTagsTagStatus::FallthroughString => "*",
+            MemberTagsTagStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -18618,6 +19748,10 @@ pub struct MemberTag {
      */
     #[serde(default, skip_serializing_if = "MemberTagsTagStatus::is_noop")]
     pub status: MemberTagsTagStatus,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A list of tags assigned to a list member.
@@ -18640,6 +19774,10 @@ pub struct MemberTags {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub tags: Vec<MemberTag>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A specific event for a contact.
@@ -18672,6 +19810,10 @@ pub struct Event {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub properties: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A collection of events for a given contact
@@ -18705,6 +19847,10 @@ pub struct CollectionOfEvents {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_items: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A new event for a specific list member
@@ -18745,6 +19891,10 @@ pub struct EventsData {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub properties: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A single instance of a goal activity.
@@ -18786,6 +19936,10 @@ pub struct Goal {
         deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub last_visited_at: Option<chrono::DateTime<chrono::Utc>>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The last 50 Goal events for a member on a specific list.
@@ -18837,6 +19991,10 @@ pub struct CollectionOfMemberActivityEvents {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_items: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -18853,8 +20011,9 @@ pub enum GetListsMembersNotesSortField {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GetListsMembersNotesSortField {
@@ -18864,7 +20023,7 @@ impl std::fmt::Display for GetListsMembersNotesSortField {
             GetListsMembersNotesSortField::NoteId => "note_id",
             GetListsMembersNotesSortField::UpdatedAt => "updated_at",
             GetListsMembersNotesSortField::Noop => "",
-            GetListsMembersNotesSortField::FallthroughString => "*",
+            GetListsMembersNotesSortField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -18952,6 +20111,10 @@ pub struct CollectionOfNotesMember {
         deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The last 10 notes for a specific list member, based on date created.
@@ -19003,6 +20166,10 @@ pub struct CollectionOfNotes {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_items: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -19035,8 +20202,9 @@ pub enum MergeFieldType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for MergeFieldType {
@@ -19054,7 +20222,7 @@ impl std::fmt::Display for MergeFieldType {
             MergeFieldType::Url => "url",
             MergeFieldType::Zip => "zip",
             MergeFieldType::Noop => "",
-            MergeFieldType::FallthroughString => "*",
+            MergeFieldType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -19114,6 +20282,10 @@ pub struct MergeFieldOptions {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub size: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A merge field ([audience field](https://mailchimp.com/help/getting-started-with-merge-tags/)) for a list.
@@ -19218,6 +20390,10 @@ pub struct MergeField {
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
     pub type_: Option<MergeFieldType>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The merge fields ([audience fields](https://mailchimp.com/help/getting-started-with-merge-tags/)) for an audience.
@@ -19260,6 +20436,10 @@ pub struct CollectionOfMergeFields {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_items: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A merge field ([audience field](https://mailchimp.com/help/getting-started-with-merge-tags/)) for a list.
@@ -19340,6 +20520,10 @@ pub struct MergeFieldData {
         rename = "type"
     )]
     pub type_: MergeFieldType,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Extra options for some merge field types.
@@ -19381,6 +20565,10 @@ pub struct MergeFieldOptionsData {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub phone_format: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A merge field ([audience field](https://mailchimp.com/help/getting-started-with-merge-tags/)) for a list.
@@ -19452,6 +20640,10 @@ pub struct MergeFieldDataType {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub tag: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The events that can trigger the webhook and whether they are enabled.
@@ -19505,6 +20697,10 @@ pub struct ListWebhooksEvents {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub upemail: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The possible sources of any events that can trigger the webhook and whether they are enabled.
@@ -19534,6 +20730,10 @@ pub struct ListWebhooksSources {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub user: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Webhook configured for the given list.
@@ -19586,6 +20786,10 @@ pub struct ListWebhooks {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Manage webhooks for a specific list.
@@ -19628,6 +20832,10 @@ pub struct ListWebhooksData {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub webhooks: Vec<ListWebhooks>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -19646,8 +20854,9 @@ pub enum ImageAlign {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ImageAlign {
@@ -19658,7 +20867,7 @@ impl std::fmt::Display for ImageAlign {
             ImageAlign::None => "none",
             ImageAlign::Right => "right",
             ImageAlign::Noop => "",
-            ImageAlign::FallthroughString => "*",
+            ImageAlign::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -19696,8 +20905,9 @@ pub enum ImageBorderStyle {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ImageBorderStyle {
@@ -19713,7 +20923,7 @@ impl std::fmt::Display for ImageBorderStyle {
             ImageBorderStyle::Ridge => "ridge",
             ImageBorderStyle::Solid => "solid",
             ImageBorderStyle::Noop => "",
-            ImageBorderStyle::FallthroughString => "*",
+            ImageBorderStyle::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -19737,8 +20947,9 @@ pub enum ImageTarget {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ImageTarget {
@@ -19747,7 +20958,7 @@ impl std::fmt::Display for ImageTarget {
             ImageTarget::Blank => "_blank",
             ImageTarget::Null => "null",
             ImageTarget::Noop => "",
-            ImageTarget::FallthroughString => "*",
+            ImageTarget::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -19849,6 +21060,10 @@ pub struct Header {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub text: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -19865,8 +21080,9 @@ pub enum Section {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Section {
@@ -19876,7 +21092,7 @@ impl std::fmt::Display for Section {
             Section::SignupThankYouTitle => "signup_thank_you_title",
             Section::UnsubMessage => "unsub_message",
             Section::Noop => "",
-            Section::FallthroughString => "*",
+            Section::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -19905,6 +21121,10 @@ pub struct Contents {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub value: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -19943,8 +21163,9 @@ pub enum Selector {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Selector {
@@ -19965,7 +21186,7 @@ impl std::fmt::Display for Selector {
             Selector::PageHeader => "page_header",
             Selector::PageOuterWrapper => "page_outer_wrapper",
             Selector::Noop => "",
-            Selector::FallthroughString => "*",
+            Selector::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -19998,6 +21219,10 @@ pub struct AnOptionSignupFormStyles {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub value: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Collection of Element style for List Signup Forms.
@@ -20017,6 +21242,10 @@ pub struct Styles {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub selector: Option<Selector>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// List signup form.
@@ -20073,6 +21302,10 @@ pub struct SignupForm {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub styles: Vec<Styles>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// List Signup Forms.
@@ -20115,6 +21348,10 @@ pub struct ListSignupForms {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_items: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// List signup form.
@@ -20143,6 +21380,10 @@ pub struct SignupFormData {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub styles: Vec<Styles>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -20171,6 +21412,10 @@ pub struct Locations {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A summary of List's locations.
@@ -20213,6 +21458,10 @@ pub struct ListLocations {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_items: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -20227,8 +21476,9 @@ pub enum GetAllLandingPagesSortField {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GetAllLandingPagesSortField {
@@ -20237,7 +21487,7 @@ impl std::fmt::Display for GetAllLandingPagesSortField {
             GetAllLandingPagesSortField::CreatedAt => "created_at",
             GetAllLandingPagesSortField::UpdatedAt => "updated_at",
             GetAllLandingPagesSortField::Noop => "",
-            GetAllLandingPagesSortField::FallthroughString => "*",
+            GetAllLandingPagesSortField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -20263,8 +21513,9 @@ pub enum LandingPageStatus {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for LandingPageStatus {
@@ -20274,7 +21525,7 @@ impl std::fmt::Display for LandingPageStatus {
             LandingPageStatus::Published => "published",
             LandingPageStatus::Unpublished => "unpublished",
             LandingPageStatus::Noop => "",
-            LandingPageStatus::FallthroughString => "*",
+            LandingPageStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -20305,6 +21556,10 @@ pub struct TrackingSettings {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub track_with_mailchimp: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A summary of an individual landing page's settings and content.
@@ -20456,6 +21711,10 @@ pub struct LandingPage {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub web_id: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A collection of landing pages.
@@ -20489,6 +21748,10 @@ pub struct GetAllLandingPagesResponse {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_items: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -20503,8 +21766,9 @@ pub enum LandingPageTemplateType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for LandingPageTemplateType {
@@ -20513,7 +21777,7 @@ impl std::fmt::Display for LandingPageTemplateType {
             LandingPageTemplateType::Product => "product",
             LandingPageTemplateType::Signup => "signup",
             LandingPageTemplateType::Noop => "",
-            LandingPageTemplateType::FallthroughString => "*",
+            LandingPageTemplateType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -20592,6 +21856,10 @@ pub struct LandingPageData {
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
     pub type_: Option<LandingPageTemplateType>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A summary of an individual page's properties.
@@ -20647,6 +21915,10 @@ pub struct LandingPageDataType {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub tracking: Option<TrackingSettings>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The HTML content for a landing page.
@@ -20680,6 +21952,10 @@ pub struct LandingPageContent {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub json: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// An object describing the bounce summary for the campaign.
@@ -20712,6 +21988,10 @@ pub struct Bounces {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub syntax_errors: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// An object describing the forwards and forward activity for the campaign.
@@ -20735,6 +22015,10 @@ pub struct Forwards {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub forwards_opens: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// An object describing the open activity for the campaign.
@@ -20776,6 +22060,10 @@ pub struct Opens {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub unique_opens: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// An object describing the click activity for the campaign.
@@ -20826,6 +22114,10 @@ pub struct Clicks {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub unique_subscriber_clicks: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// An object describing campaign engagement on Facebook.
@@ -20858,6 +22150,10 @@ pub struct FacebookLikes {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub unique_likes: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The average campaign statistics for your industry.
@@ -20927,6 +22223,10 @@ pub struct CampaignReportsIndustryStats {
         deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub unsub_rate: f64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The average campaign statistics for your list. This won't be present if we haven't calculated it yet for this list.
@@ -20968,6 +22268,10 @@ pub struct ListStats {
         deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub unsub_rate: f64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Stats for Campaign A.
@@ -21054,6 +22358,10 @@ pub struct A {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub unsubs: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Stats for Campaign B.
@@ -21140,6 +22448,10 @@ pub struct B {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub unsubs: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// General stats about different groups of an A/B Split campaign. Does not return information about Multivariate Campaigns.
@@ -21155,6 +22467,10 @@ pub struct AbSplit {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub b: Option<B>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -21231,6 +22547,10 @@ pub struct Timewarp {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub unique_opens: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -21271,6 +22591,10 @@ pub struct Timeseries {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub unique_opens: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The url and password for the [VIP report](https://mailchimp.com/help/share-a-campaign-report/).
@@ -21294,6 +22618,10 @@ pub struct ShareReport {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub share_url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// E-Commerce stats for a campaign.
@@ -21335,6 +22663,10 @@ pub struct ECommerceReport {
         deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub total_spent: f64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Report details about a sent campaign.
@@ -21540,6 +22872,10 @@ pub struct Reports {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub unsubscribed: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A list of reports containing campaigns marked as Sent.
@@ -21573,6 +22909,10 @@ pub struct CampaignReportsData {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_items: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Details of abuse complaints for a specific list. An abuse complaint occurs when your recipient clicks to 'report spam' in their email program.
@@ -21663,6 +23003,10 @@ pub struct AbuseComplaint {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub vip: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A list of abuse complaints for a specific list.
@@ -21705,6 +23049,10 @@ pub struct AbuseComplaintsData {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_items: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -21721,8 +23069,9 @@ pub enum AdviceType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AdviceType {
@@ -21732,7 +23081,7 @@ impl std::fmt::Display for AdviceType {
             AdviceType::Neutral => "neutral",
             AdviceType::Positive => "positive",
             AdviceType::Noop => "",
-            AdviceType::FallthroughString => "*",
+            AdviceType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -21771,6 +23120,10 @@ pub struct Advice {
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
     pub type_: Option<AdviceType>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A list of feedback based on a campaign's statistics.
@@ -21813,6 +23166,10 @@ pub struct CampaignAdviceReport {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_items: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Stats for Group A.
@@ -21854,6 +23211,10 @@ pub struct GroupA {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub unique_clicks_a: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Stats for Group B.
@@ -21895,6 +23256,10 @@ pub struct GroupB {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub unique_clicks_b: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A breakdown of clicks by different groups of an A/B Split campaign. Does not return information about Multivariate Campaigns.
@@ -21910,6 +23275,10 @@ pub struct ABSplit {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub b: Option<GroupB>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A report of links clicked in a specific campaign.
@@ -22002,6 +23371,10 @@ pub struct UrlsClicked {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A list of URLs and unique IDs included in HTML and plain-text versions of a campaign.
@@ -22044,6 +23417,10 @@ pub struct ClickDetailReport {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub urls_clicked: Vec<UrlsClicked>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A subscriber who clicked a specific URL in a specific campaign.
@@ -22143,6 +23520,10 @@ pub struct ClickDetailMember {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub vip: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A collection of members who clicked on a specific link within a campaign.
@@ -22185,6 +23566,10 @@ pub struct ClickDetailMembers {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_items: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A summary of the interaction with the campaign.
@@ -22199,6 +23584,10 @@ pub struct OpenDetailReportActivityMember {
         deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub timestamp: Option<chrono::DateTime<chrono::Utc>>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A list of a member's opens activity in a specific campaign.
@@ -22298,6 +23687,10 @@ pub struct OpenActivity {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub vip: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A detailed report of any campaign emails that were opened by a list member.
@@ -22349,6 +23742,10 @@ pub struct OpenDetailReport {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_opens: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A single email domain's performance
@@ -22462,6 +23859,10 @@ pub struct Domains {
         deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub unsubs_pct: f64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Statistics for the top-performing email domains in a campaign.
@@ -22513,6 +23914,10 @@ pub struct DomainPerformance {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_sent: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// An individual tweet.
@@ -22562,6 +23967,10 @@ pub struct Statuses {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub status_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A summary of Twitter activity for a campaign.
@@ -22612,6 +24021,10 @@ pub struct Twitter {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub tweets: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// An individual click location.
@@ -22635,6 +24048,10 @@ pub struct EepurlActivityClickSummaryLocation {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub region: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A summary of the click-throughs on the campaign's URL.
@@ -22676,6 +24093,10 @@ pub struct ClickSummary {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub locations: Vec<EepurlActivityClickSummaryLocation>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A single instance of a campaign referral.
@@ -22717,6 +24138,10 @@ pub struct Referrer {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub referrer: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A summary of social activity for the campaign, tracked by EepURL.
@@ -22778,6 +24203,10 @@ pub struct EepurlActivity {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub twitter: Option<Twitter>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A summary of the interaction with the campaign.
@@ -22829,6 +24258,10 @@ pub struct EmailActivityMember {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A list of a member's subscriber activity in a specific campaign, including opens, clicks, and bounces.
@@ -22897,6 +24330,10 @@ pub struct EmailActivity {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub list_is_active: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A list of member's subscriber activity in a specific campaign.
@@ -22939,6 +24376,10 @@ pub struct EmailActivityData {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_items: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -22979,6 +24420,10 @@ pub struct OpenLocations {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub region_name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Top open locations for a specific campaign.
@@ -23021,6 +24466,10 @@ pub struct OpenLocationsData {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_items: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A subscriber's status for a specific campaign.
@@ -23138,6 +24587,10 @@ pub struct SentTo {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub vip: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A list of subscribers who were sent a specific campaign.
@@ -23180,6 +24633,10 @@ pub struct SentData {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_items: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A list of reports containing child campaigns for a specific campaign.
@@ -23222,6 +24679,10 @@ pub struct CampaignSubReports {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_items: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A member who unsubscribed from a specific campaign.
@@ -23312,6 +24773,10 @@ pub struct Unsubscribes {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub vip: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A list of members who have unsubscribed from a specific campaign.
@@ -23354,6 +24819,10 @@ pub struct UnsubscribesData {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub unsubscribes: Vec<Unsubscribes>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -23370,8 +24839,9 @@ pub enum GetReportsEcommerceProductActivitySortField {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GetReportsEcommerceProductActivitySortField {
@@ -23381,7 +24851,7 @@ impl std::fmt::Display for GetReportsEcommerceProductActivitySortField {
             GetReportsEcommerceProductActivitySortField::TotalPurchased => "total_purchased",
             GetReportsEcommerceProductActivitySortField::TotalRevenue => "total_revenue",
             GetReportsEcommerceProductActivitySortField::Noop => "",
-            GetReportsEcommerceProductActivitySortField::FallthroughString => "*",
+            GetReportsEcommerceProductActivitySortField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -23467,6 +24937,10 @@ pub struct Products {
         deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub total_revenue: f64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A collection of ecommerce products.
@@ -23500,6 +24974,10 @@ pub struct GetReportsEcommerceProductActivityResponse {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_items: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -23516,8 +24994,9 @@ pub enum GetTemplatesSortField {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GetTemplatesSortField {
@@ -23527,7 +25006,7 @@ impl std::fmt::Display for GetTemplatesSortField {
             GetTemplatesSortField::DateEdited => "date_edited",
             GetTemplatesSortField::Name => "name",
             GetTemplatesSortField::Noop => "",
-            GetTemplatesSortField::FallthroughString => "*",
+            GetTemplatesSortField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -23676,6 +25155,10 @@ pub struct Templates {
         rename = "type"
     )]
     pub type_: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A list an account's available templates.
@@ -23709,6 +25192,10 @@ pub struct TemplatesData {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_items: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Default content for a template.
@@ -23729,6 +25216,10 @@ pub struct TemplateDefaultContent {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub sections: Option<serde_json::Value>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The customer's address.
@@ -23808,6 +25299,10 @@ pub struct Address {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub province_code: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Information about a specific customer.
@@ -23917,6 +25412,10 @@ pub struct Customer {
         deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -23929,8 +25428,9 @@ pub enum TrackingCode {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for TrackingCode {
@@ -23938,7 +25438,7 @@ impl std::fmt::Display for TrackingCode {
         match self {
             TrackingCode::Prec => "prec",
             TrackingCode::Noop => "",
-            TrackingCode::FallthroughString => "*",
+            TrackingCode::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -24072,6 +25572,10 @@ pub struct ShippingAddress {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub province_code: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The billing address for the order.
@@ -24196,6 +25700,10 @@ pub struct BillingAddress {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub province_code: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -24210,8 +25718,9 @@ pub enum OrdersPromosType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OrdersPromosType {
@@ -24220,7 +25729,7 @@ impl std::fmt::Display for OrdersPromosType {
             OrdersPromosType::Fixed => "fixed",
             OrdersPromosType::Percentage => "percentage",
             OrdersPromosType::Noop => "",
-            OrdersPromosType::FallthroughString => "*",
+            OrdersPromosType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -24257,6 +25766,10 @@ pub struct Promos {
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
     pub type_: Option<OrdersPromosType>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The outreach associated with this order. For example, an email campaign or Facebook ad.
@@ -24299,6 +25812,10 @@ pub struct Outreach {
         rename = "type"
     )]
     pub type_: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Information about a specific order.
@@ -24492,6 +26009,10 @@ pub struct Orders {
         deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub updated_at_foreign: Option<chrono::DateTime<chrono::Utc>>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A collection of orders in an account.
@@ -24525,6 +26046,10 @@ pub struct OrdersData {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_items: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The store address.
@@ -24622,6 +26147,10 @@ pub struct ECommerceStoresAddress {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub province_code: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The Connected Site associated with the store.
@@ -24641,6 +26170,10 @@ pub struct ECommerceStoresConnectedSite {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub site_script: Option<Script>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// abandonedCart automation details.
@@ -24668,6 +26201,10 @@ pub struct AbandonedCart {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub status: Option<Status>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// abandonedBrowse automation details. abandonedBrowse is also known as Product Retargeting Email or Retarget Site Visitors on the web.
@@ -24695,6 +26232,10 @@ pub struct AbandonedBrowse {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub status: Option<Status>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Details for the automations attached to this store.
@@ -24710,6 +26251,10 @@ pub struct ECommerceStoresAutomations {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub abandoned_cart: Option<AbandonedCart>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// An individual store in an account.
@@ -24873,6 +26418,10 @@ pub struct Stores {
         deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A collection of stores in the account.
@@ -24906,6 +26455,10 @@ pub struct ECommerceStores {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_items: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// An individual store in an account.
@@ -25023,6 +26576,10 @@ pub struct ECommerceStore {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub timezone: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// An individual store in an account.
@@ -25122,6 +26679,10 @@ pub struct ECommerceStoreData {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub timezone: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Information about a specific cart line item.
@@ -25200,6 +26761,10 @@ pub struct ECommerceCartLineItem {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub quantity: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Information about a specific cart.
@@ -25301,6 +26866,10 @@ pub struct Carts {
         deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A collection of a store's carts.
@@ -25343,6 +26912,10 @@ pub struct CartsData {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_items: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Information about a specific customer. For existing customers include only the `id` parameter in the `customer` object body.
@@ -25406,6 +26979,10 @@ pub struct ECommerceCustomer {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub opt_in_status: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Information about a specific cart line item.
@@ -25456,6 +27033,10 @@ pub struct ECommerceCartLineItemData {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub quantity: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Information about a specific cart.
@@ -25528,6 +27109,10 @@ pub struct ECommerceCart {
         deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub tax_total: f64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Information about a specific customer. Orders for existing customers should include only the `id` parameter in the `customer` object body.
@@ -25573,6 +27158,10 @@ pub struct ECommerceCartCustomer {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub opt_in_status: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Information about a specific cart line item.
@@ -25614,6 +27203,10 @@ pub struct ECommerceCartLineItemDataType {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub quantity: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Information about a specific cart.
@@ -25678,6 +27271,10 @@ pub struct ECommerceCartData {
         deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub tax_total: f64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A collection of a cart's line items.
@@ -25729,6 +27326,10 @@ pub struct CartLines {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_items: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A collection of the store's customers.
@@ -25771,6 +27372,10 @@ pub struct Customers {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_items: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Information about a specific customer.
@@ -25834,6 +27439,10 @@ pub struct ECommerceCustomerData {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub opt_in_status: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Information about a specific customer. Orders for existing customers should include only the `id` parameter in the `customer` object body.
@@ -25897,6 +27506,10 @@ pub struct ECommerceCustomerDataType {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub opt_in_status: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -25913,8 +27526,9 @@ pub enum Target {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Target {
@@ -25924,7 +27538,7 @@ impl std::fmt::Display for Target {
             Target::Shipping => "shipping",
             Target::Total => "total",
             Target::Noop => "",
-            Target::FallthroughString => "*",
+            Target::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -26039,6 +27653,10 @@ pub struct PromoRules {
         deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub updated_at_foreign: Option<chrono::DateTime<chrono::Utc>>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A collection of the store's promo rules.
@@ -26081,6 +27699,10 @@ pub struct PromoRulesData {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_items: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Information about an Ecommerce Store's specific Promo Rule.
@@ -26180,6 +27802,10 @@ pub struct ECommercePromoRule {
         deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub updated_at_foreign: Option<chrono::DateTime<chrono::Utc>>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Information about an Ecommerce Store's specific Promo Rule.
@@ -26266,6 +27892,10 @@ pub struct ECommercePromoRuleData {
         deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub updated_at_foreign: Option<chrono::DateTime<chrono::Utc>>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Information about an Ecommerce Store's specific Promo Code
@@ -26343,6 +27973,10 @@ pub struct PromoCodes {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub usage_count: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A collection of the store's promo codes.
@@ -26385,6 +28019,10 @@ pub struct PromoCodesData {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_items: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Information about an Ecommerce Store's specific Promo Code.
@@ -26452,6 +28090,10 @@ pub struct ECommercePromoCode {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub usage_count: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Information about an Ecommerce Store's specific Promo Code.
@@ -26510,6 +28152,10 @@ pub struct ECommercePromoCodeData {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub usage_count: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A collection of orders in a store.
@@ -26552,6 +28198,10 @@ pub struct OrdersDataType {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_items: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -26583,6 +28233,10 @@ pub struct ECommerceOrderPromos {
         rename = "type"
     )]
     pub type_: OrdersPromosType,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Information about a specific order line.
@@ -26642,6 +28296,10 @@ pub struct ECommerceOrderLineItem {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub quantity: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The outreach associated with this order. For example, an email campaign or Facebook ad.
@@ -26656,6 +28314,10 @@ pub struct ECommerceOrderOutreach {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Information about a specific order.
@@ -26829,6 +28491,10 @@ pub struct ECommerceOrder {
         deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub updated_at_foreign: Option<chrono::DateTime<chrono::Utc>>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Information about a specific order line.
@@ -26879,6 +28545,10 @@ pub struct ECommerceOrderLineItemData {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub quantity: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Information about a specific order.
@@ -27044,6 +28714,10 @@ pub struct ECommerceOrderData {
         deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub updated_at_foreign: Option<chrono::DateTime<chrono::Utc>>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A collection of an order's line items.
@@ -27095,6 +28769,10 @@ pub struct OrderLines {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_items: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Information about a specific product variant.
@@ -27209,6 +28887,10 @@ pub struct Variants {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub visibility: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Information about a specific product image.
@@ -27251,6 +28933,10 @@ pub struct Images {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub variant_ids: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Information about a specific product.
@@ -27375,6 +29061,10 @@ pub struct ECommerceProduct {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub vendor: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A collection of a store's products.
@@ -27417,6 +29107,10 @@ pub struct ProductsData {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_items: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Information about a specific product image.
@@ -27449,6 +29143,10 @@ pub struct ECommerceProductImage {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub variant_ids: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Information about a specific product.
@@ -27554,6 +29252,10 @@ pub struct ECommerceProductData {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub vendor: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Information about a specific product variant.
@@ -27631,6 +29333,10 @@ pub struct ECommerceProductVariantData {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub visibility: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Information about a specific product image.
@@ -27663,6 +29369,10 @@ pub struct ECommerceProductImageData {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub variant_ids: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Information about a specific product.
@@ -27759,6 +29469,10 @@ pub struct ECommerceProductDataType {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub vendor: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A collection of a product's variants.
@@ -27810,6 +29524,10 @@ pub struct EcommerceProductVariants {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub variants: Vec<Variants>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A collection of a product's images.
@@ -27861,6 +29579,10 @@ pub struct EcommerceProductImages {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_items: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -27879,6 +29601,10 @@ pub struct Results {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub snippet: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Campaigns and Snippets found for given search term.
@@ -27912,6 +29638,10 @@ pub struct Campaigns {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_items: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Exact matches of the provided search query.
@@ -27935,6 +29665,10 @@ pub struct ExactMatches {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_items: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Partial matches of the provided search query.
@@ -27958,6 +29692,10 @@ pub struct FullSearch {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_items: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Members found for given search term
@@ -27983,6 +29721,10 @@ pub struct MembersData {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub full_search: Option<FullSearch>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// API health status.
@@ -27997,6 +29739,10 @@ pub struct ApiHealthStatus {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub health_status: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -28013,8 +29759,9 @@ pub enum GetAllFacebookAdsSortField {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GetAllFacebookAdsSortField {
@@ -28024,7 +29771,7 @@ impl std::fmt::Display for GetAllFacebookAdsSortField {
             GetAllFacebookAdsSortField::EndTime => "end_time",
             GetAllFacebookAdsSortField::UpdatedAt => "updated_at",
             GetAllFacebookAdsSortField::Noop => "",
-            GetAllFacebookAdsSortField::FallthroughString => "*",
+            GetAllFacebookAdsSortField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -28072,8 +29819,9 @@ pub enum OutreachType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OutreachType {
@@ -28094,7 +29842,7 @@ impl std::fmt::Display for OutreachType {
             OutreachType::Variate => "variate",
             OutreachType::Website => "website",
             OutreachType::Noop => "",
-            OutreachType::FallthroughString => "*",
+            OutreachType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -28148,8 +29896,9 @@ pub enum OutreachStatus {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OutreachStatus {
@@ -28173,7 +29922,7 @@ impl std::fmt::Display for OutreachStatus {
             OutreachStatus::Somepaused => "somepaused",
             OutreachStatus::Unpublished => "unpublished",
             OutreachStatus::Noop => "",
-            OutreachStatus::FallthroughString => "*",
+            OutreachStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -28214,6 +29963,10 @@ pub struct FacebookAdsReportSummaryEcommerce {
         deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub total_revenue: f64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -28346,6 +30099,10 @@ pub struct FacebookAdsReportSummary {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub visits: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// List settings for the outreach
@@ -28400,6 +30157,10 @@ pub struct FacebookAdsList {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub segment_text: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -28518,6 +30279,10 @@ pub struct FacebookAds {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub web_id: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -28565,6 +30330,10 @@ pub struct FacebookAdsData {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub was_canceled_by_facebook: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Channel settings
@@ -28594,6 +30363,10 @@ pub struct Channel {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub ig_placement_feed: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Check if this ad is connected to a facebook page
@@ -28635,6 +30408,10 @@ pub struct FacebookAdsFeedback {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub content: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Connected Site
@@ -28667,6 +30444,10 @@ pub struct Site {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -28683,8 +30464,9 @@ pub enum FacebookAdsAudienceType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for FacebookAdsAudienceType {
@@ -28694,7 +30476,7 @@ impl std::fmt::Display for FacebookAdsAudienceType {
             FacebookAdsAudienceType::InterestBasedAudience => "Interest-based Audience",
             FacebookAdsAudienceType::LookalikeAudience => "Lookalike Audience",
             FacebookAdsAudienceType::Noop => "",
-            FacebookAdsAudienceType::FallthroughString => "*",
+            FacebookAdsAudienceType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -28718,8 +30500,9 @@ pub enum SourceType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SourceType {
@@ -28728,7 +30511,7 @@ impl std::fmt::Display for SourceType {
             SourceType::Facebook => "facebook",
             SourceType::List => "list",
             SourceType::Noop => "",
-            SourceType::FallthroughString => "*",
+            SourceType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -28787,6 +30570,10 @@ pub struct EmailSource {
         rename = "type"
     )]
     pub type_: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -28827,6 +30614,10 @@ pub struct FacebookAdsAudienceTargetingSpecsLocations {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub zips: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -28840,6 +30631,10 @@ pub struct FacebookAdsAudienceTargetingSpecsInterests {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -28879,6 +30674,10 @@ pub struct TargetingSpecs {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub min_age: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Audience settings
@@ -28921,6 +30720,10 @@ pub struct Audience {
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
     pub type_: Option<FacebookAdsAudienceType>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -28952,6 +30755,10 @@ pub struct Budget {
         deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub total_amount: f64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -29001,6 +30808,10 @@ pub struct Attachments {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -29065,6 +30876,10 @@ pub struct Content {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub title: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A facebook ad.
@@ -29124,6 +30939,10 @@ pub struct FacebookAdsDataType {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub site: Option<Site>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -29138,6 +30957,10 @@ pub struct GetFacebookAdsResponse {
         rename = "_links"
     )]
     pub links: Vec<Links>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types are flattened into one object:
@@ -29193,6 +31016,10 @@ pub struct GetAllFacebookAdsResponse {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_items: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -29215,6 +31042,10 @@ pub struct FacebookAdsReportSummaryEcommerceData {
         deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub total_revenue: f64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -29237,6 +31068,10 @@ pub struct CostPerClick {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub currency_code: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -29259,6 +31094,10 @@ pub struct ExtendedAt {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub timezone: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Report summary of facebook ad
@@ -29405,6 +31244,10 @@ pub struct FacebookAdsReportSummaryData {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub unique_clicks: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -29427,6 +31270,10 @@ pub struct FacebookAdsAudienceActivityClicks {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub date: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -29449,6 +31296,10 @@ pub struct Impressions {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub impressions: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -29471,6 +31322,10 @@ pub struct Revenue {
         deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub revenue: f64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -29493,6 +31348,10 @@ pub struct AudienceActivity {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub revenue: Vec<Revenue>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -29516,6 +31375,10 @@ pub struct FacebookAdsDataTypeLinksObject {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub report_summary: Option<FacebookAdsReportSummaryData>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types are flattened into one object:
@@ -29568,6 +31431,10 @@ pub struct GetReportingFacebookAdsResponse {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_items: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -29590,6 +31457,10 @@ pub struct Visits {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub val: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The clicks and visits data from the last seven days.
@@ -29622,6 +31493,10 @@ pub struct DailyStats {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub visits: Vec<Visits>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The clicks and visits data from the last five weeks.
@@ -29654,6 +31529,10 @@ pub struct WeeklyStats {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub visits: Vec<Visits>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -29668,6 +31547,10 @@ pub struct LandingPageReportTimeseries {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub weekly_stats: Option<WeeklyStats>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -29708,6 +31591,10 @@ pub struct LandingPageReportEcommerce {
         deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub total_revenue: f64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -29724,6 +31611,10 @@ pub struct Tag {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub tag_name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A summary of an individual landing page's settings and content.
@@ -29893,6 +31784,10 @@ pub struct LandingPages {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub web_id: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A collection of landing pages.
@@ -29926,6 +31821,10 @@ pub struct GetReportingLandingPagesResponse {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_items: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The verified domains currently on the account.
@@ -29974,6 +31873,10 @@ pub struct VerifiedDomains {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub verified: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Submit a response to the verification challenge and verify a domain for sending.
@@ -29988,6 +31891,10 @@ pub struct VerifyADomainSending {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub code: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The verified domains currently on the account.
@@ -30011,6 +31918,10 @@ pub struct VerifiedDomainsData {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_items: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The verified domains currently on the account.
@@ -30025,4 +31936,8 @@ pub struct VerifiedDomainsDataType {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub verification_email: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
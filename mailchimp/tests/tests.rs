@@ -1 +1,64 @@
+use std::mem;
 
+use wiremock::{
+    matchers::{method, path, query_param, query_param_is_missing},
+    Mock, MockServer, ResponseTemplate,
+};
+
+use mailchimp_api::{
+    types::{GetAllFacebookAdsSortField, SortDir},
+    Client,
+};
+
+#[tokio::test]
+async fn test_get_all_pages_with_count_and_offset() {
+    let server = MockServer::start().await;
+
+    for (offset, ids) in [(None, ["1", "2"].as_slice()), (Some("2"), ["3"].as_slice())] {
+        let mock = Mock::given(method("GET"))
+            .and(path("/facebook-ads"))
+            .and(query_param("count", "2"));
+        let mock = match offset {
+            Some(offset) => mock.and(query_param("offset", offset)),
+            None => mock.and(query_param_is_missing("offset")),
+        };
+        mock.respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "facebook_ads": ids
+                .iter()
+                .map(|id| serde_json::json!({ "id": id }))
+                .collect::<Vec<_>>(),
+            "total_items": 3
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    }
+
+    let mut client = Client::new("client_id", "client_secret", "redirect_uri", "token", "");
+    client.with_host_override(server.uri());
+
+    // Mailchimp pages through `offset` until `total_items` have been read.
+    let mut ids = Vec::new();
+    loop {
+        let page = client
+            .facebook_ads()
+            .get_all(
+                &[],
+                &[],
+                2,
+                ids.len() as i64,
+                GetAllFacebookAdsSortField::Noop,
+                SortDir::Noop,
+            )
+            .await
+            .unwrap()
+            .body;
+        ids.extend(page.facebook_ads.into_iter().map(|ad| ad.facebook_ads.id));
+        if ids.len() as i64 >= page.total_items {
+            break;
+        }
+    }
+    assert_eq!(ids, vec!["1", "2", "3"]);
+
+    mem::drop(server)
+}
@@ -1,6 +1,802 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Applications::list`], set with a builder.
+pub struct ApplicationsListRequest<'a> {
+    client: &'a Applications,
+    q: Option<&'a str>,
+    after: Option<&'a str>,
+    limit: Option<i64>,
+    filter: Option<&'a str>,
+    expand: Option<&'a str>,
+    include_non_deleted: Option<bool>,
+}
+
+impl<'a> ApplicationsListRequest<'a> {
+    /// Sets the `q` query parameter.
+    pub fn q(mut self, q: &'a str) -> Self {
+        self.q = Some(q);
+        self
+    }
+
+    /// Sets the `after` query parameter.
+    pub fn after(mut self, after: &'a str) -> Self {
+        self.after = Some(after);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `filter` query parameter.
+    pub fn filter(mut self, filter: &'a str) -> Self {
+        self.filter = Some(filter);
+        self
+    }
+
+    /// Sets the `expand` query parameter.
+    pub fn expand(mut self, expand: &'a str) -> Self {
+        self.expand = Some(expand);
+        self
+    }
+
+    /// Sets the `includeNonDeleted` query parameter.
+    pub fn include_non_deleted(mut self, include_non_deleted: bool) -> Self {
+        self.include_non_deleted = Some(include_non_deleted);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Application>>> {
+        let Self {
+            client,
+            q,
+            after,
+            limit,
+            filter,
+            expand,
+            include_non_deleted,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = after {
+            query_args.push(("after".to_string(), v.to_string()));
+        }
+        if let Some(v) = expand {
+            query_args.push(("expand".to_string(), v.to_string()));
+        }
+        if let Some(v) = filter {
+            query_args.push(("filter".to_string(), v.to_string()));
+        }
+        if let Some(v) = include_non_deleted {
+            query_args.push(("includeNonDeleted".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = q {
+            query_args.push(("q".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(&format!("/api/v1/apps?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Applications::create`], set with a builder.
+pub struct ApplicationsCreateRequest<'a> {
+    client: &'a Applications,
+    activate: Option<bool>,
+    body: &'a crate::types::Application,
+}
+
+impl<'a> ApplicationsCreateRequest<'a> {
+    /// Sets the `activate` query parameter.
+    pub fn activate(mut self, activate: bool) -> Self {
+        self.activate = Some(activate);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::Application>> {
+        let Self {
+            client,
+            activate,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = activate {
+            query_args.push(("activate".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(&format!("/api/v1/apps?{}", query_), None);
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Applications::get`], set with a builder.
+pub struct ApplicationsGetRequest<'a> {
+    client: &'a Applications,
+    app_id: &'a str,
+    expand: Option<&'a str>,
+}
+
+impl<'a> ApplicationsGetRequest<'a> {
+    /// Sets the `expand` query parameter.
+    pub fn expand(mut self, expand: &'a str) -> Self {
+        self.expand = Some(expand);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::Application>> {
+        let Self {
+            client,
+            app_id,
+            expand,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = expand {
+            query_args.push(("expand".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/api/v1/apps/{}?{}",
+                crate::progenitor_support::encode_path(app_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Applications::generate_key`], set with a builder.
+pub struct ApplicationsGenerateKeyRequest<'a> {
+    client: &'a Applications,
+    app_id: &'a str,
+    validity_years: Option<i64>,
+}
+
+impl<'a> ApplicationsGenerateKeyRequest<'a> {
+    /// Sets the `validityYears` query parameter.
+    pub fn validity_years(mut self, validity_years: i64) -> Self {
+        self.validity_years = Some(validity_years);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::JsonWebKey>> {
+        let Self {
+            client,
+            app_id,
+            validity_years,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = validity_years {
+            query_args.push(("validityYears".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/api/v1/apps/{}/credentials/keys/generate?{}",
+                crate::progenitor_support::encode_path(app_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Applications::list_scope_consent_grants`], set with a builder.
+pub struct ApplicationsListScopeConsentGrantsRequest<'a> {
+    client: &'a Applications,
+    app_id: &'a str,
+    expand: Option<&'a str>,
+}
+
+impl<'a> ApplicationsListScopeConsentGrantsRequest<'a> {
+    /// Sets the `expand` query parameter.
+    pub fn expand(mut self, expand: &'a str) -> Self {
+        self.expand = Some(expand);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::OAuth2ScopeConsentGrant>>> {
+        let Self {
+            client,
+            app_id,
+            expand,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = expand {
+            query_args.push(("expand".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/api/v1/apps/{}/grants?{}",
+                crate::progenitor_support::encode_path(app_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Applications::get_scope_consent_grant`], set with a builder.
+pub struct ApplicationsGetScopeConsentGrantRequest<'a> {
+    client: &'a Applications,
+    app_id: &'a str,
+    grant_id: &'a str,
+    expand: Option<&'a str>,
+}
+
+impl<'a> ApplicationsGetScopeConsentGrantRequest<'a> {
+    /// Sets the `expand` query parameter.
+    pub fn expand(mut self, expand: &'a str) -> Self {
+        self.expand = Some(expand);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::OAuth2ScopeConsentGrant>> {
+        let Self {
+            client,
+            app_id,
+            grant_id,
+            expand,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = expand {
+            query_args.push(("expand".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/api/v1/apps/{}/grants/{}?{}",
+                crate::progenitor_support::encode_path(app_id),
+                crate::progenitor_support::encode_path(grant_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Applications::list_group_assignments`], set with a builder.
+pub struct ApplicationsListGroupAssignmentsRequest<'a> {
+    client: &'a Applications,
+    app_id: &'a str,
+    q: Option<&'a str>,
+    after: Option<&'a str>,
+    limit: Option<i64>,
+    expand: Option<&'a str>,
+}
+
+impl<'a> ApplicationsListGroupAssignmentsRequest<'a> {
+    /// Sets the `q` query parameter.
+    pub fn q(mut self, q: &'a str) -> Self {
+        self.q = Some(q);
+        self
+    }
+
+    /// Sets the `after` query parameter.
+    pub fn after(mut self, after: &'a str) -> Self {
+        self.after = Some(after);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `expand` query parameter.
+    pub fn expand(mut self, expand: &'a str) -> Self {
+        self.expand = Some(expand);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ApplicationGroupAssignment>>> {
+        let Self {
+            client,
+            app_id,
+            q,
+            after,
+            limit,
+            expand,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = after {
+            query_args.push(("after".to_string(), v.to_string()));
+        }
+        if let Some(v) = expand {
+            query_args.push(("expand".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = q {
+            query_args.push(("q".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/api/v1/apps/{}/groups?{}",
+                crate::progenitor_support::encode_path(app_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Applications::get_group_assignment`], set with a builder.
+pub struct ApplicationsGetGroupAssignmentRequest<'a> {
+    client: &'a Applications,
+    app_id: &'a str,
+    group_id: &'a str,
+    expand: Option<&'a str>,
+}
+
+impl<'a> ApplicationsGetGroupAssignmentRequest<'a> {
+    /// Sets the `expand` query parameter.
+    pub fn expand(mut self, expand: &'a str) -> Self {
+        self.expand = Some(expand);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::ApplicationGroupAssignment>> {
+        let Self {
+            client,
+            app_id,
+            group_id,
+            expand,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = expand {
+            query_args.push(("expand".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/api/v1/apps/{}/groups/{}?{}",
+                crate::progenitor_support::encode_path(app_id),
+                crate::progenitor_support::encode_path(group_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Applications::list_o_auth_2_tokens_fors`], set with a builder.
+pub struct ApplicationsListOAuth2TokensForsRequest<'a> {
+    client: &'a Applications,
+    app_id: &'a str,
+    expand: Option<&'a str>,
+    after: Option<&'a str>,
+    limit: Option<i64>,
+}
+
+impl<'a> ApplicationsListOAuth2TokensForsRequest<'a> {
+    /// Sets the `expand` query parameter.
+    pub fn expand(mut self, expand: &'a str) -> Self {
+        self.expand = Some(expand);
+        self
+    }
+
+    /// Sets the `after` query parameter.
+    pub fn after(mut self, after: &'a str) -> Self {
+        self.after = Some(after);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::OAuth2Token>>> {
+        let Self {
+            client,
+            app_id,
+            expand,
+            after,
+            limit,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = after {
+            query_args.push(("after".to_string(), v.to_string()));
+        }
+        if let Some(v) = expand {
+            query_args.push(("expand".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/api/v1/apps/{}/tokens?{}",
+                crate::progenitor_support::encode_path(app_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Applications::get_o_auth_2_token_for`], set with a builder.
+pub struct ApplicationsGetOAuth2TokenForRequest<'a> {
+    client: &'a Applications,
+    app_id: &'a str,
+    token_id: &'a str,
+    expand: Option<&'a str>,
+}
+
+impl<'a> ApplicationsGetOAuth2TokenForRequest<'a> {
+    /// Sets the `expand` query parameter.
+    pub fn expand(mut self, expand: &'a str) -> Self {
+        self.expand = Some(expand);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::OAuth2Token>> {
+        let Self {
+            client,
+            app_id,
+            token_id,
+            expand,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = expand {
+            query_args.push(("expand".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/api/v1/apps/{}/tokens/{}?{}",
+                crate::progenitor_support::encode_path(app_id),
+                crate::progenitor_support::encode_path(token_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Applications::list_users`], set with a builder.
+pub struct ApplicationsListUsersRequest<'a> {
+    client: &'a Applications,
+    app_id: &'a str,
+    q: Option<&'a str>,
+    query_scope: Option<&'a str>,
+    after: Option<&'a str>,
+    limit: Option<i64>,
+    filter: Option<&'a str>,
+    expand: Option<&'a str>,
+}
+
+impl<'a> ApplicationsListUsersRequest<'a> {
+    /// Sets the `q` query parameter.
+    pub fn q(mut self, q: &'a str) -> Self {
+        self.q = Some(q);
+        self
+    }
+
+    /// Sets the `query_scope` query parameter.
+    pub fn query_scope(mut self, query_scope: &'a str) -> Self {
+        self.query_scope = Some(query_scope);
+        self
+    }
+
+    /// Sets the `after` query parameter.
+    pub fn after(mut self, after: &'a str) -> Self {
+        self.after = Some(after);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `filter` query parameter.
+    pub fn filter(mut self, filter: &'a str) -> Self {
+        self.filter = Some(filter);
+        self
+    }
+
+    /// Sets the `expand` query parameter.
+    pub fn expand(mut self, expand: &'a str) -> Self {
+        self.expand = Some(expand);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::AppUser>>> {
+        let Self {
+            client,
+            app_id,
+            q,
+            query_scope,
+            after,
+            limit,
+            filter,
+            expand,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = after {
+            query_args.push(("after".to_string(), v.to_string()));
+        }
+        if let Some(v) = expand {
+            query_args.push(("expand".to_string(), v.to_string()));
+        }
+        if let Some(v) = filter {
+            query_args.push(("filter".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = q {
+            query_args.push(("q".to_string(), v.to_string()));
+        }
+        if let Some(v) = query_scope {
+            query_args.push(("query_scope".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/api/v1/apps/{}/users?{}",
+                crate::progenitor_support::encode_path(app_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Applications::get_user`], set with a builder.
+pub struct ApplicationsGetUserRequest<'a> {
+    client: &'a Applications,
+    app_id: &'a str,
+    user_id: &'a str,
+    expand: Option<&'a str>,
+}
+
+impl<'a> ApplicationsGetUserRequest<'a> {
+    /// Sets the `expand` query parameter.
+    pub fn expand(mut self, expand: &'a str) -> Self {
+        self.expand = Some(expand);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::AppUser>> {
+        let Self {
+            client,
+            app_id,
+            user_id,
+            expand,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = expand {
+            query_args.push(("expand".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/api/v1/apps/{}/users/{}?{}",
+                crate::progenitor_support::encode_path(app_id),
+                crate::progenitor_support::encode_path(user_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Applications::delete_user`], set with a builder.
+pub struct ApplicationsDeleteUserRequest<'a> {
+    client: &'a Applications,
+    app_id: &'a str,
+    user_id: &'a str,
+    send_email: Option<bool>,
+}
+
+impl<'a> ApplicationsDeleteUserRequest<'a> {
+    /// Sets the `sendEmail` query parameter.
+    pub fn send_email(mut self, send_email: bool) -> Self {
+        self.send_email = Some(send_email);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            app_id,
+            user_id,
+            send_email,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = send_email {
+            query_args.push(("sendEmail".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/api/v1/apps/{}/users/{}?{}",
+                crate::progenitor_support::encode_path(app_id),
+                crate::progenitor_support::encode_path(user_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .delete(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct Applications {
     pub client: Client,
 }
@@ -70,6 +866,26 @@ impl Applications {
             )
             .await
     }
+    /**
+     * List Applications.
+     *
+     * This function performs a `GET` to the `/api/v1/apps` endpoint.
+     *
+     * As opposed to `list`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Enumerates apps added to your organization with pagination. A subset of apps can be returned that match a supported filter expression or query.
+     */
+    pub fn list_request(&self) -> ApplicationsListRequest<'_> {
+        ApplicationsListRequest {
+            client: self,
+            q: None,
+            after: None,
+            limit: None,
+            filter: None,
+            expand: None,
+            include_non_deleted: None,
+        }
+    }
     /**
      * List Applications.
      *
@@ -114,6 +930,42 @@ impl Applications {
             )
             .await
     }
+    /**
+     * List Applications.
+     *
+     * This function performs a `GET` to the `/api/v1/apps` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Enumerates apps added to your organization with pagination. A subset of apps can be returned that match a supported filter expression or query.
+     */
+    pub fn list_all_stream(
+        &self,
+        q: &str,
+        filter: &str,
+        expand: &str,
+        include_non_deleted: bool,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Application>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !expand.is_empty() {
+            query_args.push(("expand".to_string(), expand.to_string()));
+        }
+        if !filter.is_empty() {
+            query_args.push(("filter".to_string(), filter.to_string()));
+        }
+        if include_non_deleted {
+            query_args.push((
+                "includeNonDeleted".to_string(),
+                include_non_deleted.to_string(),
+            ));
+        }
+        if !q.is_empty() {
+            query_args.push(("q".to_string(), q.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/api/v1/apps?{}", query_), None);
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Add Application.
      *
@@ -147,6 +999,25 @@ impl Applications {
             )
             .await
     }
+    /**
+     * Add Application.
+     *
+     * This function performs a `POST` to the `/api/v1/apps` endpoint.
+     *
+     * As opposed to `create`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Adds a new application to your Okta organization.
+     */
+    pub fn create_request<'a>(
+        &'a self,
+        body: &'a crate::types::Application,
+    ) -> ApplicationsCreateRequest<'a> {
+        ApplicationsCreateRequest {
+            client: self,
+            activate: None,
+            body,
+        }
+    }
     /**
      * Get Application.
      *
@@ -187,6 +1058,22 @@ impl Applications {
             )
             .await
     }
+    /**
+     * Get Application.
+     *
+     * This function performs a `GET` to the `/api/v1/apps/{appId}` endpoint.
+     *
+     * As opposed to `get`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Fetches an application from your Okta organization by `id`.
+     */
+    pub fn get_request<'a>(&'a self, app_id: &'a str) -> ApplicationsGetRequest<'a> {
+        ApplicationsGetRequest {
+            client: self,
+            app_id,
+            expand: None,
+        }
+    }
     /**
      * Update Application.
      *
@@ -311,6 +1198,28 @@ impl Applications {
             )
             .await
     }
+    /**
+     * List Certificate Signing Requests for Application.
+     *
+     * This function performs a `GET` to the `/api/v1/apps/{appId}/credentials/csrs` endpoint.
+     *
+     * As opposed to `list_all_csrs_fors`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Enumerates Certificate Signing Requests for an application
+     */
+    pub fn list_all_csrs_fors_stream(
+        &self,
+        app_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Csr>> + Unpin + '_ {
+        let url = self.client.url(
+            &format!(
+                "/api/v1/apps/{}/credentials/csrs",
+                crate::progenitor_support::encode_path(app_id),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Generate Certificate Signing Request for Application.
      *
@@ -499,6 +1408,28 @@ impl Applications {
             )
             .await
     }
+    /**
+     * List Key Credentials for Application.
+     *
+     * This function performs a `GET` to the `/api/v1/apps/{appId}/credentials/keys` endpoint.
+     *
+     * As opposed to `list_all_keys`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Enumerates key credentials for an application
+     */
+    pub fn list_all_keys_stream(
+        &self,
+        app_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::JsonWebKey>> + Unpin + '_ {
+        let url = self.client.url(
+            &format!(
+                "/api/v1/apps/{}/credentials/keys",
+                crate::progenitor_support::encode_path(app_id),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * This function performs a `POST` to the `/api/v1/apps/{appId}/credentials/keys/generate` endpoint.
      *
@@ -537,6 +1468,23 @@ impl Applications {
             )
             .await
     }
+    /**
+     * This function performs a `POST` to the `/api/v1/apps/{appId}/credentials/keys/generate` endpoint.
+     *
+     * As opposed to `generate_key`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Generates a new X.509 certificate for an application key credential
+     */
+    pub fn generate_key_request<'a>(
+        &'a self,
+        app_id: &'a str,
+    ) -> ApplicationsGenerateKeyRequest<'a> {
+        ApplicationsGenerateKeyRequest {
+            client: self,
+            app_id,
+            validity_years: None,
+        }
+    }
     /**
      * Get Key Credential for Application.
      *
@@ -653,6 +1601,23 @@ impl Applications {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/apps/{appId}/grants` endpoint.
+     *
+     * As opposed to `list_scope_consent_grants`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Lists all scope consent grants for the application
+     */
+    pub fn list_scope_consent_grants_request<'a>(
+        &'a self,
+        app_id: &'a str,
+    ) -> ApplicationsListScopeConsentGrantsRequest<'a> {
+        ApplicationsListScopeConsentGrantsRequest {
+            client: self,
+            app_id,
+            expand: None,
+        }
+    }
     /**
      * This function performs a `GET` to the `/api/v1/apps/{appId}/grants` endpoint.
      *
@@ -688,6 +1653,34 @@ impl Applications {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/apps/{appId}/grants` endpoint.
+     *
+     * As opposed to `list_all_scope_consent_grants`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Lists all scope consent grants for the application
+     */
+    pub fn list_all_scope_consent_grants_stream(
+        &self,
+        app_id: &str,
+        expand: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::OAuth2ScopeConsentGrant>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !expand.is_empty() {
+            query_args.push(("expand".to_string(), expand.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/api/v1/apps/{}/grants?{}",
+                crate::progenitor_support::encode_path(app_id),
+                query_
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * This function performs a `POST` to the `/api/v1/apps/{appId}/grants` endpoint.
      *
@@ -760,6 +1753,25 @@ impl Applications {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/apps/{appId}/grants/{grantId}` endpoint.
+     *
+     * As opposed to `get_scope_consent_grant`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Fetches a single scope consent grant for the application
+     */
+    pub fn get_scope_consent_grant_request<'a>(
+        &'a self,
+        app_id: &'a str,
+        grant_id: &'a str,
+    ) -> ApplicationsGetScopeConsentGrantRequest<'a> {
+        ApplicationsGetScopeConsentGrantRequest {
+            client: self,
+            app_id,
+            grant_id,
+            expand: None,
+        }
+    }
     /**
      * This function performs a `DELETE` to the `/api/v1/apps/{appId}/grants/{grantId}` endpoint.
      *
@@ -848,6 +1860,28 @@ impl Applications {
             )
             .await
     }
+    /**
+     * List Groups Assigned to Application.
+     *
+     * This function performs a `GET` to the `/api/v1/apps/{appId}/groups` endpoint.
+     *
+     * As opposed to `list_group_assignments`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Enumerates group assignments for an application.
+     */
+    pub fn list_group_assignments_request<'a>(
+        &'a self,
+        app_id: &'a str,
+    ) -> ApplicationsListGroupAssignmentsRequest<'a> {
+        ApplicationsListGroupAssignmentsRequest {
+            client: self,
+            app_id,
+            q: None,
+            after: None,
+            limit: None,
+            expand: None,
+        }
+    }
     /**
      * List Groups Assigned to Application.
      *
@@ -889,6 +1923,40 @@ impl Applications {
             )
             .await
     }
+    /**
+     * List Groups Assigned to Application.
+     *
+     * This function performs a `GET` to the `/api/v1/apps/{appId}/groups` endpoint.
+     *
+     * As opposed to `list_all_group_assignments`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Enumerates group assignments for an application.
+     */
+    pub fn list_all_group_assignments_stream(
+        &self,
+        app_id: &str,
+        q: &str,
+        expand: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::ApplicationGroupAssignment>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !expand.is_empty() {
+            query_args.push(("expand".to_string(), expand.to_string()));
+        }
+        if !q.is_empty() {
+            query_args.push(("q".to_string(), q.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/api/v1/apps/{}/groups?{}",
+                crate::progenitor_support::encode_path(app_id),
+                query_
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Get Assigned Group for Application.
      *
@@ -932,6 +2000,27 @@ impl Applications {
             )
             .await
     }
+    /**
+     * Get Assigned Group for Application.
+     *
+     * This function performs a `GET` to the `/api/v1/apps/{appId}/groups/{groupId}` endpoint.
+     *
+     * As opposed to `get_group_assignment`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Fetches an application group assignment
+     */
+    pub fn get_group_assignment_request<'a>(
+        &'a self,
+        app_id: &'a str,
+        group_id: &'a str,
+    ) -> ApplicationsGetGroupAssignmentRequest<'a> {
+        ApplicationsGetGroupAssignmentRequest {
+            client: self,
+            app_id,
+            group_id,
+            expand: None,
+        }
+    }
     /**
      * Assign Group to Application.
      *
@@ -1109,6 +2198,25 @@ impl Applications {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/apps/{appId}/tokens` endpoint.
+     *
+     * As opposed to `list_o_auth_2_tokens_fors`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Lists all tokens for the application
+     */
+    pub fn list_o_auth_2_tokens_fors_request<'a>(
+        &'a self,
+        app_id: &'a str,
+    ) -> ApplicationsListOAuth2TokensForsRequest<'a> {
+        ApplicationsListOAuth2TokensForsRequest {
+            client: self,
+            app_id,
+            expand: None,
+            after: None,
+            limit: None,
+        }
+    }
     /**
      * This function performs a `GET` to the `/api/v1/apps/{appId}/tokens` endpoint.
      *
@@ -1144,6 +2252,33 @@ impl Applications {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/apps/{appId}/tokens` endpoint.
+     *
+     * As opposed to `list_all_o_auth_2_tokens_fors`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Lists all tokens for the application
+     */
+    pub fn list_all_o_auth_2_tokens_fors_stream(
+        &self,
+        app_id: &str,
+        expand: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::OAuth2Token>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !expand.is_empty() {
+            query_args.push(("expand".to_string(), expand.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/api/v1/apps/{}/tokens?{}",
+                crate::progenitor_support::encode_path(app_id),
+                query_
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * This function performs a `DELETE` to the `/api/v1/apps/{appId}/tokens` endpoint.
      *
@@ -1215,6 +2350,25 @@ impl Applications {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/apps/{appId}/tokens/{tokenId}` endpoint.
+     *
+     * As opposed to `get_o_auth_2_token_for`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Gets a token for the specified application
+     */
+    pub fn get_o_auth_2_token_for_request<'a>(
+        &'a self,
+        app_id: &'a str,
+        token_id: &'a str,
+    ) -> ApplicationsGetOAuth2TokenForRequest<'a> {
+        ApplicationsGetOAuth2TokenForRequest {
+            client: self,
+            app_id,
+            token_id,
+            expand: None,
+        }
+    }
     /**
      * This function performs a `DELETE` to the `/api/v1/apps/{appId}/tokens/{tokenId}` endpoint.
      *
@@ -1313,6 +2467,27 @@ impl Applications {
             )
             .await
     }
+    /**
+     * List Users Assigned to Application.
+     *
+     * This function performs a `GET` to the `/api/v1/apps/{appId}/users` endpoint.
+     *
+     * As opposed to `list_users`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Enumerates all assigned [application users](#application-user-model) for an application.
+     */
+    pub fn list_users_request<'a>(&'a self, app_id: &'a str) -> ApplicationsListUsersRequest<'a> {
+        ApplicationsListUsersRequest {
+            client: self,
+            app_id,
+            q: None,
+            query_scope: None,
+            after: None,
+            limit: None,
+            filter: None,
+            expand: None,
+        }
+    }
     /**
      * List Users Assigned to Application.
      *
@@ -1362,6 +2537,47 @@ impl Applications {
             )
             .await
     }
+    /**
+     * List Users Assigned to Application.
+     *
+     * This function performs a `GET` to the `/api/v1/apps/{appId}/users` endpoint.
+     *
+     * As opposed to `list_all_users`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Enumerates all assigned [application users](#application-user-model) for an application.
+     */
+    pub fn list_all_users_stream(
+        &self,
+        app_id: &str,
+        q: &str,
+        query_scope: &str,
+        filter: &str,
+        expand: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::AppUser>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !expand.is_empty() {
+            query_args.push(("expand".to_string(), expand.to_string()));
+        }
+        if !filter.is_empty() {
+            query_args.push(("filter".to_string(), filter.to_string()));
+        }
+        if !q.is_empty() {
+            query_args.push(("q".to_string(), q.to_string()));
+        }
+        if !query_scope.is_empty() {
+            query_args.push(("query_scope".to_string(), query_scope.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/api/v1/apps/{}/users?{}",
+                crate::progenitor_support::encode_path(app_id),
+                query_
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Assign User to Application for SSO & Provisioning.
      *
@@ -1438,6 +2654,27 @@ impl Applications {
             )
             .await
     }
+    /**
+     * Get Assigned User for Application.
+     *
+     * This function performs a `GET` to the `/api/v1/apps/{appId}/users/{userId}` endpoint.
+     *
+     * As opposed to `get_user`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Fetches a specific user assignment for application by `id`.
+     */
+    pub fn get_user_request<'a>(
+        &'a self,
+        app_id: &'a str,
+        user_id: &'a str,
+    ) -> ApplicationsGetUserRequest<'a> {
+        ApplicationsGetUserRequest {
+            client: self,
+            app_id,
+            user_id,
+            expand: None,
+        }
+    }
     /**
      * Update Application Profile for Assigned User.
      *
@@ -1517,4 +2754,25 @@ impl Applications {
             )
             .await
     }
+    /**
+     * Remove User from Application.
+     *
+     * This function performs a `DELETE` to the `/api/v1/apps/{appId}/users/{userId}` endpoint.
+     *
+     * As opposed to `delete_user`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Removes an assignment for a user from an application.
+     */
+    pub fn delete_user_request<'a>(
+        &'a self,
+        app_id: &'a str,
+        user_id: &'a str,
+    ) -> ApplicationsDeleteUserRequest<'a> {
+        ApplicationsDeleteUserRequest {
+            client: self,
+            app_id,
+            user_id,
+            send_email: None,
+        }
+    }
 }
@@ -1,6 +1,281 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`AuthorizationServers::list`], set with a builder.
+pub struct AuthorizationServersListRequest<'a> {
+    client: &'a AuthorizationServers,
+    q: Option<&'a str>,
+    limit: Option<&'a str>,
+    after: Option<&'a str>,
+}
+
+impl<'a> AuthorizationServersListRequest<'a> {
+    /// Sets the `q` query parameter.
+    pub fn q(mut self, q: &'a str) -> Self {
+        self.q = Some(q);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `after` query parameter.
+    pub fn after(mut self, after: &'a str) -> Self {
+        self.after = Some(after);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::AuthorizationServer>>> {
+        let Self {
+            client,
+            q,
+            limit,
+            after,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = after {
+            query_args.push(("after".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = q {
+            query_args.push(("q".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/api/v1/authorizationServers?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`AuthorizationServers::list_refresh_tokens_for_and_clients`], set with a builder.
+pub struct AuthorizationServersListRefreshTokensForAndClientsRequest<'a> {
+    client: &'a AuthorizationServers,
+    auth_server_id: &'a str,
+    client_id: &'a str,
+    expand: Option<&'a str>,
+    after: Option<&'a str>,
+    limit: Option<i64>,
+}
+
+impl<'a> AuthorizationServersListRefreshTokensForAndClientsRequest<'a> {
+    /// Sets the `expand` query parameter.
+    pub fn expand(mut self, expand: &'a str) -> Self {
+        self.expand = Some(expand);
+        self
+    }
+
+    /// Sets the `after` query parameter.
+    pub fn after(mut self, after: &'a str) -> Self {
+        self.after = Some(after);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::OAuth2RefreshToken>>> {
+        let Self {
+            client,
+            auth_server_id,
+            client_id,
+            expand,
+            after,
+            limit,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = after {
+            query_args.push(("after".to_string(), v.to_string()));
+        }
+        if let Some(v) = expand {
+            query_args.push(("expand".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/api/v1/authorizationServers/{}/clients/{}/tokens?{}",
+                crate::progenitor_support::encode_path(auth_server_id),
+                crate::progenitor_support::encode_path(client_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`AuthorizationServers::get_refresh_token_for_and_client`], set with a builder.
+pub struct AuthorizationServersGetRefreshTokenForAndClientRequest<'a> {
+    client: &'a AuthorizationServers,
+    auth_server_id: &'a str,
+    client_id: &'a str,
+    token_id: &'a str,
+    expand: Option<&'a str>,
+}
+
+impl<'a> AuthorizationServersGetRefreshTokenForAndClientRequest<'a> {
+    /// Sets the `expand` query parameter.
+    pub fn expand(mut self, expand: &'a str) -> Self {
+        self.expand = Some(expand);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::OAuth2RefreshToken>> {
+        let Self {
+            client,
+            auth_server_id,
+            client_id,
+            token_id,
+            expand,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = expand {
+            query_args.push(("expand".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/api/v1/authorizationServers/{}/clients/{}/tokens/{}?{}",
+                crate::progenitor_support::encode_path(auth_server_id),
+                crate::progenitor_support::encode_path(client_id),
+                crate::progenitor_support::encode_path(token_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`AuthorizationServers::list_o_auth_2_scopes`], set with a builder.
+pub struct AuthorizationServersListOAuth2ScopesRequest<'a> {
+    client: &'a AuthorizationServers,
+    auth_server_id: &'a str,
+    q: Option<&'a str>,
+    filter: Option<&'a str>,
+    cursor: Option<&'a str>,
+    limit: Option<i64>,
+}
+
+impl<'a> AuthorizationServersListOAuth2ScopesRequest<'a> {
+    /// Sets the `q` query parameter.
+    pub fn q(mut self, q: &'a str) -> Self {
+        self.q = Some(q);
+        self
+    }
+
+    /// Sets the `filter` query parameter.
+    pub fn filter(mut self, filter: &'a str) -> Self {
+        self.filter = Some(filter);
+        self
+    }
+
+    /// Sets the `cursor` query parameter.
+    pub fn cursor(mut self, cursor: &'a str) -> Self {
+        self.cursor = Some(cursor);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::OAuth2Scope>>> {
+        let Self {
+            client,
+            auth_server_id,
+            q,
+            filter,
+            cursor,
+            limit,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = cursor {
+            query_args.push(("cursor".to_string(), v.to_string()));
+        }
+        if let Some(v) = filter {
+            query_args.push(("filter".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = q {
+            query_args.push(("q".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/api/v1/authorizationServers/{}/scopes?{}",
+                crate::progenitor_support::encode_path(auth_server_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct AuthorizationServers {
     pub client: Client,
 }
@@ -52,6 +327,21 @@ impl AuthorizationServers {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/authorizationServers` endpoint.
+     *
+     * As opposed to `list`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Success
+     */
+    pub fn list_request(&self) -> AuthorizationServersListRequest<'_> {
+        AuthorizationServersListRequest {
+            client: self,
+            q: None,
+            limit: None,
+            after: None,
+        }
+    }
     /**
      * This function performs a `GET` to the `/api/v1/authorizationServers` endpoint.
      *
@@ -81,6 +371,28 @@ impl AuthorizationServers {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/authorizationServers` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Success
+     */
+    pub fn list_all_stream(
+        &self,
+        q: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::AuthorizationServer>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !q.is_empty() {
+            query_args.push(("q".to_string(), q.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/api/v1/authorizationServers?{}", query_), None);
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * This function performs a `POST` to the `/api/v1/authorizationServers` endpoint.
      *
@@ -247,6 +559,26 @@ impl AuthorizationServers {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/authorizationServers/{authServerId}/claims` endpoint.
+     *
+     * As opposed to `list_all_o_auth_2_claims`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Success
+     */
+    pub fn list_all_o_auth_2_claims_stream(
+        &self,
+        auth_server_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::OAuth2Claim>> + Unpin + '_ {
+        let url = self.client.url(
+            &format!(
+                "/api/v1/authorizationServers/{}/claims",
+                crate::progenitor_support::encode_path(auth_server_id),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * This function performs a `POST` to the `/api/v1/authorizationServers/{authServerId}/claims` endpoint.
      *
@@ -436,6 +768,26 @@ impl AuthorizationServers {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/authorizationServers/{authServerId}/clients` endpoint.
+     *
+     * As opposed to `list_all_o_auth_2_clients_fors`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Success
+     */
+    pub fn list_all_o_auth_2_clients_fors_stream(
+        &self,
+        auth_server_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::OAuth2Client>> + Unpin + '_ {
+        let url = self.client.url(
+            &format!(
+                "/api/v1/authorizationServers/{}/clients",
+                crate::progenitor_support::encode_path(auth_server_id),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * This function performs a `GET` to the `/api/v1/authorizationServers/{authServerId}/clients/{clientId}/tokens` endpoint.
      *
@@ -487,6 +839,27 @@ impl AuthorizationServers {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/authorizationServers/{authServerId}/clients/{clientId}/tokens` endpoint.
+     *
+     * As opposed to `list_refresh_tokens_for_and_clients`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Success
+     */
+    pub fn list_refresh_tokens_for_and_clients_request<'a>(
+        &'a self,
+        auth_server_id: &'a str,
+        client_id: &'a str,
+    ) -> AuthorizationServersListRefreshTokensForAndClientsRequest<'a> {
+        AuthorizationServersListRefreshTokensForAndClientsRequest {
+            client: self,
+            auth_server_id,
+            client_id,
+            expand: None,
+            after: None,
+            limit: None,
+        }
+    }
     /**
      * This function performs a `GET` to the `/api/v1/authorizationServers/{authServerId}/clients/{clientId}/tokens` endpoint.
      *
@@ -524,6 +897,36 @@ impl AuthorizationServers {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/authorizationServers/{authServerId}/clients/{clientId}/tokens` endpoint.
+     *
+     * As opposed to `list_all_refresh_tokens_for_and_clients`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Success
+     */
+    pub fn list_all_refresh_tokens_for_and_clients_stream(
+        &self,
+        auth_server_id: &str,
+        client_id: &str,
+        expand: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::OAuth2RefreshToken>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !expand.is_empty() {
+            query_args.push(("expand".to_string(), expand.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/api/v1/authorizationServers/{}/clients/{}/tokens?{}",
+                crate::progenitor_support::encode_path(auth_server_id),
+                crate::progenitor_support::encode_path(client_id),
+                query_
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * This function performs a `DELETE` to the `/api/v1/authorizationServers/{authServerId}/clients/{clientId}/tokens` endpoint.
      *
@@ -601,6 +1004,27 @@ impl AuthorizationServers {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/authorizationServers/{authServerId}/clients/{clientId}/tokens/{tokenId}` endpoint.
+     *
+     * As opposed to `get_refresh_token_for_and_client`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Success
+     */
+    pub fn get_refresh_token_for_and_client_request<'a>(
+        &'a self,
+        auth_server_id: &'a str,
+        client_id: &'a str,
+        token_id: &'a str,
+    ) -> AuthorizationServersGetRefreshTokenForAndClientRequest<'a> {
+        AuthorizationServersGetRefreshTokenForAndClientRequest {
+            client: self,
+            auth_server_id,
+            client_id,
+            token_id,
+            expand: None,
+        }
+    }
     /**
      * This function performs a `DELETE` to the `/api/v1/authorizationServers/{authServerId}/clients/{clientId}/tokens/{tokenId}` endpoint.
      *
@@ -695,6 +1119,26 @@ impl AuthorizationServers {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/authorizationServers/{authServerId}/credentials/keys` endpoint.
+     *
+     * As opposed to `list_all_keys`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Success
+     */
+    pub fn list_all_keys_stream(
+        &self,
+        auth_server_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::JsonWebKey>> + Unpin + '_ {
+        let url = self.client.url(
+            &format!(
+                "/api/v1/authorizationServers/{}/credentials/keys",
+                crate::progenitor_support::encode_path(auth_server_id),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * This function performs a `POST` to the `/api/v1/authorizationServers/{authServerId}/credentials/lifecycle/keyRotate` endpoint.
      *
@@ -838,6 +1282,27 @@ impl AuthorizationServers {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/authorizationServers/{authServerId}/policies` endpoint.
+     *
+     * As opposed to `list_all_policies`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Success
+     */
+    pub fn list_all_policies_stream(
+        &self,
+        auth_server_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::AuthorizationServerPolicy>> + Unpin + '_
+    {
+        let url = self.client.url(
+            &format!(
+                "/api/v1/authorizationServers/{}/policies",
+                crate::progenitor_support::encode_path(auth_server_id),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * This function performs a `POST` to the `/api/v1/authorizationServers/{authServerId}/policies` endpoint.
      *
@@ -1098,6 +1563,30 @@ impl AuthorizationServers {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/authorizationServers/{authServerId}/policies/{policyId}/rules` endpoint.
+     *
+     * As opposed to `list_all_policy_rules`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Enumerates all policy rules for the specified Custom Authorization Server and Policy.
+     */
+    pub fn list_all_policy_rules_stream(
+        &self,
+        policy_id: &str,
+        auth_server_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::AuthorizationServerPolicyRule>>
+           + Unpin
+           + '_ {
+        let url = self.client.url(
+            &format!(
+                "/api/v1/authorizationServers/{}/policies/{}/rules",
+                crate::progenitor_support::encode_path(auth_server_id),
+                crate::progenitor_support::encode_path(policy_id),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * This function performs a `POST` to the `/api/v1/authorizationServers/{authServerId}/policies/{policyId}/rules` endpoint.
      *
@@ -1366,6 +1855,26 @@ impl AuthorizationServers {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/authorizationServers/{authServerId}/scopes` endpoint.
+     *
+     * As opposed to `list_o_auth_2_scopes`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Success
+     */
+    pub fn list_o_auth_2_scopes_request<'a>(
+        &'a self,
+        auth_server_id: &'a str,
+    ) -> AuthorizationServersListOAuth2ScopesRequest<'a> {
+        AuthorizationServersListOAuth2ScopesRequest {
+            client: self,
+            auth_server_id,
+            q: None,
+            filter: None,
+            cursor: None,
+            limit: None,
+        }
+    }
     /**
      * This function performs a `GET` to the `/api/v1/authorizationServers/{authServerId}/scopes` endpoint.
      *
@@ -1409,6 +1918,41 @@ impl AuthorizationServers {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/authorizationServers/{authServerId}/scopes` endpoint.
+     *
+     * As opposed to `list_all_o_auth_2_scopes`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Success
+     */
+    pub fn list_all_o_auth_2_scopes_stream(
+        &self,
+        auth_server_id: &str,
+        q: &str,
+        filter: &str,
+        cursor: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::OAuth2Scope>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !cursor.is_empty() {
+            query_args.push(("cursor".to_string(), cursor.to_string()));
+        }
+        if !filter.is_empty() {
+            query_args.push(("filter".to_string(), filter.to_string()));
+        }
+        if !q.is_empty() {
+            query_args.push(("q".to_string(), q.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/api/v1/authorizationServers/{}/scopes?{}",
+                crate::progenitor_support::encode_path(auth_server_id),
+                query_
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * This function performs a `POST` to the `/api/v1/authorizationServers/{authServerId}/scopes` endpoint.
      *
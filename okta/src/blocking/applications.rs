@@ -0,0 +1,853 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Applications::list`], set with a builder.
+pub struct ApplicationsListRequest<'a> {
+    inner: crate::applications::ApplicationsListRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ApplicationsListRequest<'a> {
+    /// Sets the `q` query parameter.
+    pub fn q(mut self, q: &'a str) -> Self {
+        self.inner = self.inner.q(q);
+        self
+    }
+
+    /// Sets the `after` query parameter.
+    pub fn after(mut self, after: &'a str) -> Self {
+        self.inner = self.inner.after(after);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `filter` query parameter.
+    pub fn filter(mut self, filter: &'a str) -> Self {
+        self.inner = self.inner.filter(filter);
+        self
+    }
+
+    /// Sets the `expand` query parameter.
+    pub fn expand(mut self, expand: &'a str) -> Self {
+        self.inner = self.inner.expand(expand);
+        self
+    }
+
+    /// Sets the `includeNonDeleted` query parameter.
+    pub fn include_non_deleted(mut self, include_non_deleted: bool) -> Self {
+        self.inner = self.inner.include_non_deleted(include_non_deleted);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Application>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Applications::create`], set with a builder.
+pub struct ApplicationsCreateRequest<'a> {
+    inner: crate::applications::ApplicationsCreateRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ApplicationsCreateRequest<'a> {
+    /// Sets the `activate` query parameter.
+    pub fn activate(mut self, activate: bool) -> Self {
+        self.inner = self.inner.activate(activate);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::Application>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Applications::get`], set with a builder.
+pub struct ApplicationsGetRequest<'a> {
+    inner: crate::applications::ApplicationsGetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ApplicationsGetRequest<'a> {
+    /// Sets the `expand` query parameter.
+    pub fn expand(mut self, expand: &'a str) -> Self {
+        self.inner = self.inner.expand(expand);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::Application>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Applications::generate_key`], set with a builder.
+pub struct ApplicationsGenerateKeyRequest<'a> {
+    inner: crate::applications::ApplicationsGenerateKeyRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ApplicationsGenerateKeyRequest<'a> {
+    /// Sets the `validityYears` query parameter.
+    pub fn validity_years(mut self, validity_years: i64) -> Self {
+        self.inner = self.inner.validity_years(validity_years);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::JsonWebKey>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Applications::list_scope_consent_grants`], set with a builder.
+pub struct ApplicationsListScopeConsentGrantsRequest<'a> {
+    inner: crate::applications::ApplicationsListScopeConsentGrantsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ApplicationsListScopeConsentGrantsRequest<'a> {
+    /// Sets the `expand` query parameter.
+    pub fn expand(mut self, expand: &'a str) -> Self {
+        self.inner = self.inner.expand(expand);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::OAuth2ScopeConsentGrant>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Applications::get_scope_consent_grant`], set with a builder.
+pub struct ApplicationsGetScopeConsentGrantRequest<'a> {
+    inner: crate::applications::ApplicationsGetScopeConsentGrantRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ApplicationsGetScopeConsentGrantRequest<'a> {
+    /// Sets the `expand` query parameter.
+    pub fn expand(mut self, expand: &'a str) -> Self {
+        self.inner = self.inner.expand(expand);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::OAuth2ScopeConsentGrant>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Applications::list_group_assignments`], set with a builder.
+pub struct ApplicationsListGroupAssignmentsRequest<'a> {
+    inner: crate::applications::ApplicationsListGroupAssignmentsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ApplicationsListGroupAssignmentsRequest<'a> {
+    /// Sets the `q` query parameter.
+    pub fn q(mut self, q: &'a str) -> Self {
+        self.inner = self.inner.q(q);
+        self
+    }
+
+    /// Sets the `after` query parameter.
+    pub fn after(mut self, after: &'a str) -> Self {
+        self.inner = self.inner.after(after);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `expand` query parameter.
+    pub fn expand(mut self, expand: &'a str) -> Self {
+        self.inner = self.inner.expand(expand);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(
+        self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ApplicationGroupAssignment>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Applications::get_group_assignment`], set with a builder.
+pub struct ApplicationsGetGroupAssignmentRequest<'a> {
+    inner: crate::applications::ApplicationsGetGroupAssignmentRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ApplicationsGetGroupAssignmentRequest<'a> {
+    /// Sets the `expand` query parameter.
+    pub fn expand(mut self, expand: &'a str) -> Self {
+        self.inner = self.inner.expand(expand);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::ApplicationGroupAssignment>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Applications::list_o_auth_2_tokens_fors`], set with a builder.
+pub struct ApplicationsListOAuth2TokensForsRequest<'a> {
+    inner: crate::applications::ApplicationsListOAuth2TokensForsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ApplicationsListOAuth2TokensForsRequest<'a> {
+    /// Sets the `expand` query parameter.
+    pub fn expand(mut self, expand: &'a str) -> Self {
+        self.inner = self.inner.expand(expand);
+        self
+    }
+
+    /// Sets the `after` query parameter.
+    pub fn after(mut self, after: &'a str) -> Self {
+        self.inner = self.inner.after(after);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::OAuth2Token>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Applications::get_o_auth_2_token_for`], set with a builder.
+pub struct ApplicationsGetOAuth2TokenForRequest<'a> {
+    inner: crate::applications::ApplicationsGetOAuth2TokenForRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ApplicationsGetOAuth2TokenForRequest<'a> {
+    /// Sets the `expand` query parameter.
+    pub fn expand(mut self, expand: &'a str) -> Self {
+        self.inner = self.inner.expand(expand);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::OAuth2Token>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Applications::list_users`], set with a builder.
+pub struct ApplicationsListUsersRequest<'a> {
+    inner: crate::applications::ApplicationsListUsersRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ApplicationsListUsersRequest<'a> {
+    /// Sets the `q` query parameter.
+    pub fn q(mut self, q: &'a str) -> Self {
+        self.inner = self.inner.q(q);
+        self
+    }
+
+    /// Sets the `query_scope` query parameter.
+    pub fn query_scope(mut self, query_scope: &'a str) -> Self {
+        self.inner = self.inner.query_scope(query_scope);
+        self
+    }
+
+    /// Sets the `after` query parameter.
+    pub fn after(mut self, after: &'a str) -> Self {
+        self.inner = self.inner.after(after);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `filter` query parameter.
+    pub fn filter(mut self, filter: &'a str) -> Self {
+        self.inner = self.inner.filter(filter);
+        self
+    }
+
+    /// Sets the `expand` query parameter.
+    pub fn expand(mut self, expand: &'a str) -> Self {
+        self.inner = self.inner.expand(expand);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::AppUser>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Applications::get_user`], set with a builder.
+pub struct ApplicationsGetUserRequest<'a> {
+    inner: crate::applications::ApplicationsGetUserRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ApplicationsGetUserRequest<'a> {
+    /// Sets the `expand` query parameter.
+    pub fn expand(mut self, expand: &'a str) -> Self {
+        self.inner = self.inner.expand(expand);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::AppUser>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Applications::delete_user`], set with a builder.
+pub struct ApplicationsDeleteUserRequest<'a> {
+    inner: crate::applications::ApplicationsDeleteUserRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ApplicationsDeleteUserRequest<'a> {
+    /// Sets the `sendEmail` query parameter.
+    pub fn send_email(mut self, send_email: bool) -> Self {
+        self.inner = self.inner.send_email(send_email);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::applications::Applications`].
+pub struct Applications {
+    inner: crate::applications::Applications,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Applications {
+    pub(crate) fn new(
+        inner: crate::applications::Applications,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Applications { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::applications::Applications::list`].
+    pub fn list(
+        &self,
+        q: &str,
+        after: &str,
+        limit: i64,
+        filter: &str,
+        expand: &str,
+        include_non_deleted: bool,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Application>>> {
+        self.runtime.block_on(
+            self.inner
+                .list(q, after, limit, filter, expand, include_non_deleted),
+        )
+    }
+    /// The blocking version of [`crate::applications::Applications::list_request`].
+    pub fn list_request(&self) -> ApplicationsListRequest<'_> {
+        ApplicationsListRequest {
+            inner: self.inner.list_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::applications::Applications::list_all`].
+    pub fn list_all(
+        &self,
+        q: &str,
+        filter: &str,
+        expand: &str,
+        include_non_deleted: bool,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Application>>> {
+        self.runtime
+            .block_on(self.inner.list_all(q, filter, expand, include_non_deleted))
+    }
+    /// The blocking version of [`crate::applications::Applications::create`].
+    pub fn create(
+        &self,
+        activate: bool,
+        body: &crate::types::Application,
+    ) -> ClientResult<crate::Response<crate::types::Application>> {
+        self.runtime.block_on(self.inner.create(activate, body))
+    }
+    /// The blocking version of [`crate::applications::Applications::create_request`].
+    pub fn create_request<'a>(
+        &'a self,
+        body: &'a crate::types::Application,
+    ) -> ApplicationsCreateRequest<'a> {
+        ApplicationsCreateRequest {
+            inner: self.inner.create_request(body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::applications::Applications::get`].
+    pub fn get(
+        &self,
+        app_id: &str,
+        expand: &str,
+    ) -> ClientResult<crate::Response<crate::types::Application>> {
+        self.runtime.block_on(self.inner.get(app_id, expand))
+    }
+    /// The blocking version of [`crate::applications::Applications::get_request`].
+    pub fn get_request<'a>(&'a self, app_id: &'a str) -> ApplicationsGetRequest<'a> {
+        ApplicationsGetRequest {
+            inner: self.inner.get_request(app_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::applications::Applications::update`].
+    pub fn update(
+        &self,
+        app_id: &str,
+        body: &crate::types::Application,
+    ) -> ClientResult<crate::Response<crate::types::Application>> {
+        self.runtime.block_on(self.inner.update(app_id, body))
+    }
+    /// The blocking version of [`crate::applications::Applications::delete`].
+    pub fn delete(&self, app_id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.delete(app_id))
+    }
+    /// The blocking version of [`crate::applications::Applications::list_csrs_fors`].
+    pub fn list_csrs_fors(
+        &self,
+        app_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Csr>>> {
+        self.runtime.block_on(self.inner.list_csrs_fors(app_id))
+    }
+    /// The blocking version of [`crate::applications::Applications::list_all_csrs_fors`].
+    pub fn list_all_csrs_fors(
+        &self,
+        app_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Csr>>> {
+        self.runtime.block_on(self.inner.list_all_csrs_fors(app_id))
+    }
+    /// The blocking version of [`crate::applications::Applications::generate_csr_for`].
+    pub fn generate_csr_for(
+        &self,
+        app_id: &str,
+        body: &crate::types::CsrMetadata,
+    ) -> ClientResult<crate::Response<crate::types::Csr>> {
+        self.runtime
+            .block_on(self.inner.generate_csr_for(app_id, body))
+    }
+    /// The blocking version of [`crate::applications::Applications::get_csr_for`].
+    pub fn get_csr_for(
+        &self,
+        app_id: &str,
+        csr_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Csr>> {
+        self.runtime
+            .block_on(self.inner.get_csr_for(app_id, csr_id))
+    }
+    /// The blocking version of [`crate::applications::Applications::revoke_csr_from`].
+    pub fn revoke_csr_from(&self, app_id: &str, csr_id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.revoke_csr_from(app_id, csr_id))
+    }
+    /// The blocking version of [`crate::applications::Applications::post_app_credentials_csr_lifecycle_publish`].
+    pub fn post_app_credentials_csr_lifecycle_publish(
+        &self,
+        app_id: &str,
+        csr_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::JsonWebKey>> {
+        self.runtime.block_on(
+            self.inner
+                .post_app_credentials_csr_lifecycle_publish(app_id, csr_id),
+        )
+    }
+    /// The blocking version of [`crate::applications::Applications::list_keys`].
+    pub fn list_keys(
+        &self,
+        app_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::JsonWebKey>>> {
+        self.runtime.block_on(self.inner.list_keys(app_id))
+    }
+    /// The blocking version of [`crate::applications::Applications::list_all_keys`].
+    pub fn list_all_keys(
+        &self,
+        app_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::JsonWebKey>>> {
+        self.runtime.block_on(self.inner.list_all_keys(app_id))
+    }
+    /// The blocking version of [`crate::applications::Applications::generate_key`].
+    pub fn generate_key(
+        &self,
+        app_id: &str,
+        validity_years: i64,
+    ) -> ClientResult<crate::Response<crate::types::JsonWebKey>> {
+        self.runtime
+            .block_on(self.inner.generate_key(app_id, validity_years))
+    }
+    /// The blocking version of [`crate::applications::Applications::generate_key_request`].
+    pub fn generate_key_request<'a>(
+        &'a self,
+        app_id: &'a str,
+    ) -> ApplicationsGenerateKeyRequest<'a> {
+        ApplicationsGenerateKeyRequest {
+            inner: self.inner.generate_key_request(app_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::applications::Applications::get_key`].
+    pub fn get_key(
+        &self,
+        app_id: &str,
+        key_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::JsonWebKey>> {
+        self.runtime.block_on(self.inner.get_key(app_id, key_id))
+    }
+    /// The blocking version of [`crate::applications::Applications::clone_key`].
+    pub fn clone_key(
+        &self,
+        app_id: &str,
+        key_id: &str,
+        target_aid: &str,
+    ) -> ClientResult<crate::Response<crate::types::JsonWebKey>> {
+        self.runtime
+            .block_on(self.inner.clone_key(app_id, key_id, target_aid))
+    }
+    /// The blocking version of [`crate::applications::Applications::list_scope_consent_grants`].
+    pub fn list_scope_consent_grants(
+        &self,
+        app_id: &str,
+        expand: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::OAuth2ScopeConsentGrant>>> {
+        self.runtime
+            .block_on(self.inner.list_scope_consent_grants(app_id, expand))
+    }
+    /// The blocking version of [`crate::applications::Applications::list_scope_consent_grants_request`].
+    pub fn list_scope_consent_grants_request<'a>(
+        &'a self,
+        app_id: &'a str,
+    ) -> ApplicationsListScopeConsentGrantsRequest<'a> {
+        ApplicationsListScopeConsentGrantsRequest {
+            inner: self.inner.list_scope_consent_grants_request(app_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::applications::Applications::list_all_scope_consent_grants`].
+    pub fn list_all_scope_consent_grants(
+        &self,
+        app_id: &str,
+        expand: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::OAuth2ScopeConsentGrant>>> {
+        self.runtime
+            .block_on(self.inner.list_all_scope_consent_grants(app_id, expand))
+    }
+    /// The blocking version of [`crate::applications::Applications::grant_consent_scope`].
+    pub fn grant_consent_scope(
+        &self,
+        app_id: &str,
+        body: &crate::types::OAuth2ScopeConsentGrant,
+    ) -> ClientResult<crate::Response<crate::types::OAuth2ScopeConsentGrant>> {
+        self.runtime
+            .block_on(self.inner.grant_consent_scope(app_id, body))
+    }
+    /// The blocking version of [`crate::applications::Applications::get_scope_consent_grant`].
+    pub fn get_scope_consent_grant(
+        &self,
+        app_id: &str,
+        grant_id: &str,
+        expand: &str,
+    ) -> ClientResult<crate::Response<crate::types::OAuth2ScopeConsentGrant>> {
+        self.runtime
+            .block_on(self.inner.get_scope_consent_grant(app_id, grant_id, expand))
+    }
+    /// The blocking version of [`crate::applications::Applications::get_scope_consent_grant_request`].
+    pub fn get_scope_consent_grant_request<'a>(
+        &'a self,
+        app_id: &'a str,
+        grant_id: &'a str,
+    ) -> ApplicationsGetScopeConsentGrantRequest<'a> {
+        ApplicationsGetScopeConsentGrantRequest {
+            inner: self.inner.get_scope_consent_grant_request(app_id, grant_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::applications::Applications::revoke_scope_consent_grant`].
+    pub fn revoke_scope_consent_grant(
+        &self,
+        app_id: &str,
+        grant_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.revoke_scope_consent_grant(app_id, grant_id))
+    }
+    /// The blocking version of [`crate::applications::Applications::list_group_assignments`].
+    pub fn list_group_assignments(
+        &self,
+        app_id: &str,
+        q: &str,
+        after: &str,
+        limit: i64,
+        expand: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ApplicationGroupAssignment>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_group_assignments(app_id, q, after, limit, expand),
+        )
+    }
+    /// The blocking version of [`crate::applications::Applications::list_group_assignments_request`].
+    pub fn list_group_assignments_request<'a>(
+        &'a self,
+        app_id: &'a str,
+    ) -> ApplicationsListGroupAssignmentsRequest<'a> {
+        ApplicationsListGroupAssignmentsRequest {
+            inner: self.inner.list_group_assignments_request(app_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::applications::Applications::list_all_group_assignments`].
+    pub fn list_all_group_assignments(
+        &self,
+        app_id: &str,
+        q: &str,
+        expand: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ApplicationGroupAssignment>>> {
+        self.runtime
+            .block_on(self.inner.list_all_group_assignments(app_id, q, expand))
+    }
+    /// The blocking version of [`crate::applications::Applications::get_group_assignment`].
+    pub fn get_group_assignment(
+        &self,
+        app_id: &str,
+        group_id: &str,
+        expand: &str,
+    ) -> ClientResult<crate::Response<crate::types::ApplicationGroupAssignment>> {
+        self.runtime
+            .block_on(self.inner.get_group_assignment(app_id, group_id, expand))
+    }
+    /// The blocking version of [`crate::applications::Applications::get_group_assignment_request`].
+    pub fn get_group_assignment_request<'a>(
+        &'a self,
+        app_id: &'a str,
+        group_id: &'a str,
+    ) -> ApplicationsGetGroupAssignmentRequest<'a> {
+        ApplicationsGetGroupAssignmentRequest {
+            inner: self.inner.get_group_assignment_request(app_id, group_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::applications::Applications::create_group_assignment`].
+    pub fn create_group_assignment(
+        &self,
+        app_id: &str,
+        group_id: &str,
+        body: &crate::types::ApplicationGroupAssignment,
+    ) -> ClientResult<crate::Response<crate::types::ApplicationGroupAssignment>> {
+        self.runtime
+            .block_on(self.inner.create_group_assignment(app_id, group_id, body))
+    }
+    /// The blocking version of [`crate::applications::Applications::delete_group_assignment`].
+    pub fn delete_group_assignment(
+        &self,
+        app_id: &str,
+        group_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete_group_assignment(app_id, group_id))
+    }
+    /// The blocking version of [`crate::applications::Applications::activate`].
+    pub fn activate(&self, app_id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.activate(app_id))
+    }
+    /// The blocking version of [`crate::applications::Applications::deactivate`].
+    pub fn deactivate(&self, app_id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.deactivate(app_id))
+    }
+    /// The blocking version of [`crate::applications::Applications::list_o_auth_2_tokens_fors`].
+    pub fn list_o_auth_2_tokens_fors(
+        &self,
+        app_id: &str,
+        expand: &str,
+        after: &str,
+        limit: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::OAuth2Token>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_o_auth_2_tokens_fors(app_id, expand, after, limit),
+        )
+    }
+    /// The blocking version of [`crate::applications::Applications::list_o_auth_2_tokens_fors_request`].
+    pub fn list_o_auth_2_tokens_fors_request<'a>(
+        &'a self,
+        app_id: &'a str,
+    ) -> ApplicationsListOAuth2TokensForsRequest<'a> {
+        ApplicationsListOAuth2TokensForsRequest {
+            inner: self.inner.list_o_auth_2_tokens_fors_request(app_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::applications::Applications::list_all_o_auth_2_tokens_fors`].
+    pub fn list_all_o_auth_2_tokens_fors(
+        &self,
+        app_id: &str,
+        expand: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::OAuth2Token>>> {
+        self.runtime
+            .block_on(self.inner.list_all_o_auth_2_tokens_fors(app_id, expand))
+    }
+    /// The blocking version of [`crate::applications::Applications::revoke_o_auth_2_tokens_for`].
+    pub fn revoke_o_auth_2_tokens_for(&self, app_id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.revoke_o_auth_2_tokens_for(app_id))
+    }
+    /// The blocking version of [`crate::applications::Applications::get_o_auth_2_token_for`].
+    pub fn get_o_auth_2_token_for(
+        &self,
+        app_id: &str,
+        token_id: &str,
+        expand: &str,
+    ) -> ClientResult<crate::Response<crate::types::OAuth2Token>> {
+        self.runtime
+            .block_on(self.inner.get_o_auth_2_token_for(app_id, token_id, expand))
+    }
+    /// The blocking version of [`crate::applications::Applications::get_o_auth_2_token_for_request`].
+    pub fn get_o_auth_2_token_for_request<'a>(
+        &'a self,
+        app_id: &'a str,
+        token_id: &'a str,
+    ) -> ApplicationsGetOAuth2TokenForRequest<'a> {
+        ApplicationsGetOAuth2TokenForRequest {
+            inner: self.inner.get_o_auth_2_token_for_request(app_id, token_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::applications::Applications::revoke_o_auth_2_token_for`].
+    pub fn revoke_o_auth_2_token_for(
+        &self,
+        app_id: &str,
+        token_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.revoke_o_auth_2_token_for(app_id, token_id))
+    }
+    /// The blocking version of [`crate::applications::Applications::list_users`].
+    pub fn list_users(
+        &self,
+        app_id: &str,
+        q: &str,
+        query_scope: &str,
+        after: &str,
+        limit: i64,
+        filter: &str,
+        expand: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::AppUser>>> {
+        self.runtime.block_on(self.inner.list_users(
+            app_id,
+            q,
+            query_scope,
+            after,
+            limit,
+            filter,
+            expand,
+        ))
+    }
+    /// The blocking version of [`crate::applications::Applications::list_users_request`].
+    pub fn list_users_request<'a>(&'a self, app_id: &'a str) -> ApplicationsListUsersRequest<'a> {
+        ApplicationsListUsersRequest {
+            inner: self.inner.list_users_request(app_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::applications::Applications::list_all_users`].
+    pub fn list_all_users(
+        &self,
+        app_id: &str,
+        q: &str,
+        query_scope: &str,
+        filter: &str,
+        expand: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::AppUser>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_all_users(app_id, q, query_scope, filter, expand),
+        )
+    }
+    /// The blocking version of [`crate::applications::Applications::assign_user`].
+    pub fn assign_user(
+        &self,
+        app_id: &str,
+        body: &crate::types::AppUser,
+    ) -> ClientResult<crate::Response<crate::types::AppUser>> {
+        self.runtime.block_on(self.inner.assign_user(app_id, body))
+    }
+    /// The blocking version of [`crate::applications::Applications::get_user`].
+    pub fn get_user(
+        &self,
+        app_id: &str,
+        user_id: &str,
+        expand: &str,
+    ) -> ClientResult<crate::Response<crate::types::AppUser>> {
+        self.runtime
+            .block_on(self.inner.get_user(app_id, user_id, expand))
+    }
+    /// The blocking version of [`crate::applications::Applications::get_user_request`].
+    pub fn get_user_request<'a>(
+        &'a self,
+        app_id: &'a str,
+        user_id: &'a str,
+    ) -> ApplicationsGetUserRequest<'a> {
+        ApplicationsGetUserRequest {
+            inner: self.inner.get_user_request(app_id, user_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::applications::Applications::update_user`].
+    pub fn update_user(
+        &self,
+        app_id: &str,
+        user_id: &str,
+        body: &crate::types::AppUser,
+    ) -> ClientResult<crate::Response<crate::types::AppUser>> {
+        self.runtime
+            .block_on(self.inner.update_user(app_id, user_id, body))
+    }
+    /// The blocking version of [`crate::applications::Applications::delete_user`].
+    pub fn delete_user(
+        &self,
+        app_id: &str,
+        user_id: &str,
+        send_email: bool,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete_user(app_id, user_id, send_email))
+    }
+    /// The blocking version of [`crate::applications::Applications::delete_user_request`].
+    pub fn delete_user_request<'a>(
+        &'a self,
+        app_id: &'a str,
+        user_id: &'a str,
+    ) -> ApplicationsDeleteUserRequest<'a> {
+        ApplicationsDeleteUserRequest {
+            inner: self.inner.delete_user_request(app_id, user_id),
+            runtime: &self.runtime,
+        }
+    }
+}
@@ -0,0 +1,638 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`AuthorizationServers::list`], set with a builder.
+pub struct AuthorizationServersListRequest<'a> {
+    inner: crate::authorization_servers::AuthorizationServersListRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> AuthorizationServersListRequest<'a> {
+    /// Sets the `q` query parameter.
+    pub fn q(mut self, q: &'a str) -> Self {
+        self.inner = self.inner.q(q);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `after` query parameter.
+    pub fn after(mut self, after: &'a str) -> Self {
+        self.inner = self.inner.after(after);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::AuthorizationServer>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`AuthorizationServers::list_refresh_tokens_for_and_clients`], set with a builder.
+pub struct AuthorizationServersListRefreshTokensForAndClientsRequest<'a> {
+    inner:
+        crate::authorization_servers::AuthorizationServersListRefreshTokensForAndClientsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> AuthorizationServersListRefreshTokensForAndClientsRequest<'a> {
+    /// Sets the `expand` query parameter.
+    pub fn expand(mut self, expand: &'a str) -> Self {
+        self.inner = self.inner.expand(expand);
+        self
+    }
+
+    /// Sets the `after` query parameter.
+    pub fn after(mut self, after: &'a str) -> Self {
+        self.inner = self.inner.after(after);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::OAuth2RefreshToken>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`AuthorizationServers::get_refresh_token_for_and_client`], set with a builder.
+pub struct AuthorizationServersGetRefreshTokenForAndClientRequest<'a> {
+    inner: crate::authorization_servers::AuthorizationServersGetRefreshTokenForAndClientRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> AuthorizationServersGetRefreshTokenForAndClientRequest<'a> {
+    /// Sets the `expand` query parameter.
+    pub fn expand(mut self, expand: &'a str) -> Self {
+        self.inner = self.inner.expand(expand);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::OAuth2RefreshToken>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`AuthorizationServers::list_o_auth_2_scopes`], set with a builder.
+pub struct AuthorizationServersListOAuth2ScopesRequest<'a> {
+    inner: crate::authorization_servers::AuthorizationServersListOAuth2ScopesRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> AuthorizationServersListOAuth2ScopesRequest<'a> {
+    /// Sets the `q` query parameter.
+    pub fn q(mut self, q: &'a str) -> Self {
+        self.inner = self.inner.q(q);
+        self
+    }
+
+    /// Sets the `filter` query parameter.
+    pub fn filter(mut self, filter: &'a str) -> Self {
+        self.inner = self.inner.filter(filter);
+        self
+    }
+
+    /// Sets the `cursor` query parameter.
+    pub fn cursor(mut self, cursor: &'a str) -> Self {
+        self.inner = self.inner.cursor(cursor);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::OAuth2Scope>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::authorization_servers::AuthorizationServers`].
+pub struct AuthorizationServers {
+    inner: crate::authorization_servers::AuthorizationServers,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl AuthorizationServers {
+    pub(crate) fn new(
+        inner: crate::authorization_servers::AuthorizationServers,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        AuthorizationServers { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::authorization_servers::AuthorizationServers::list`].
+    pub fn list(
+        &self,
+        q: &str,
+        limit: &str,
+        after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::AuthorizationServer>>> {
+        self.runtime.block_on(self.inner.list(q, limit, after))
+    }
+    /// The blocking version of [`crate::authorization_servers::AuthorizationServers::list_request`].
+    pub fn list_request(&self) -> AuthorizationServersListRequest<'_> {
+        AuthorizationServersListRequest {
+            inner: self.inner.list_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::authorization_servers::AuthorizationServers::list_all`].
+    pub fn list_all(
+        &self,
+        q: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::AuthorizationServer>>> {
+        self.runtime.block_on(self.inner.list_all(q))
+    }
+    /// The blocking version of [`crate::authorization_servers::AuthorizationServers::create`].
+    pub fn create(
+        &self,
+        body: &crate::types::AuthorizationServer,
+    ) -> ClientResult<crate::Response<crate::types::AuthorizationServer>> {
+        self.runtime.block_on(self.inner.create(body))
+    }
+    /// The blocking version of [`crate::authorization_servers::AuthorizationServers::get`].
+    pub fn get(
+        &self,
+        auth_server_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::AuthorizationServer>> {
+        self.runtime.block_on(self.inner.get(auth_server_id))
+    }
+    /// The blocking version of [`crate::authorization_servers::AuthorizationServers::update`].
+    pub fn update(
+        &self,
+        auth_server_id: &str,
+        body: &crate::types::AuthorizationServer,
+    ) -> ClientResult<crate::Response<crate::types::AuthorizationServer>> {
+        self.runtime
+            .block_on(self.inner.update(auth_server_id, body))
+    }
+    /// The blocking version of [`crate::authorization_servers::AuthorizationServers::delete`].
+    pub fn delete(&self, auth_server_id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.delete(auth_server_id))
+    }
+    /// The blocking version of [`crate::authorization_servers::AuthorizationServers::list_o_auth_2_claims`].
+    pub fn list_o_auth_2_claims(
+        &self,
+        auth_server_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::OAuth2Claim>>> {
+        self.runtime
+            .block_on(self.inner.list_o_auth_2_claims(auth_server_id))
+    }
+    /// The blocking version of [`crate::authorization_servers::AuthorizationServers::list_all_o_auth_2_claims`].
+    pub fn list_all_o_auth_2_claims(
+        &self,
+        auth_server_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::OAuth2Claim>>> {
+        self.runtime
+            .block_on(self.inner.list_all_o_auth_2_claims(auth_server_id))
+    }
+    /// The blocking version of [`crate::authorization_servers::AuthorizationServers::create_o_auth_2_claim`].
+    pub fn create_o_auth_2_claim(
+        &self,
+        auth_server_id: &str,
+        body: &crate::types::OAuth2Claim,
+    ) -> ClientResult<crate::Response<crate::types::OAuth2Claim>> {
+        self.runtime
+            .block_on(self.inner.create_o_auth_2_claim(auth_server_id, body))
+    }
+    /// The blocking version of [`crate::authorization_servers::AuthorizationServers::get_o_auth_2_claim`].
+    pub fn get_o_auth_2_claim(
+        &self,
+        auth_server_id: &str,
+        claim_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::OAuth2Claim>> {
+        self.runtime
+            .block_on(self.inner.get_o_auth_2_claim(auth_server_id, claim_id))
+    }
+    /// The blocking version of [`crate::authorization_servers::AuthorizationServers::update_o_auth_2_claim`].
+    pub fn update_o_auth_2_claim(
+        &self,
+        auth_server_id: &str,
+        claim_id: &str,
+        body: &crate::types::OAuth2Claim,
+    ) -> ClientResult<crate::Response<crate::types::OAuth2Claim>> {
+        self.runtime.block_on(
+            self.inner
+                .update_o_auth_2_claim(auth_server_id, claim_id, body),
+        )
+    }
+    /// The blocking version of [`crate::authorization_servers::AuthorizationServers::delete_o_auth_2_claim`].
+    pub fn delete_o_auth_2_claim(
+        &self,
+        auth_server_id: &str,
+        claim_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete_o_auth_2_claim(auth_server_id, claim_id))
+    }
+    /// The blocking version of [`crate::authorization_servers::AuthorizationServers::list_o_auth_2_clients_fors`].
+    pub fn list_o_auth_2_clients_fors(
+        &self,
+        auth_server_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::OAuth2Client>>> {
+        self.runtime
+            .block_on(self.inner.list_o_auth_2_clients_fors(auth_server_id))
+    }
+    /// The blocking version of [`crate::authorization_servers::AuthorizationServers::list_all_o_auth_2_clients_fors`].
+    pub fn list_all_o_auth_2_clients_fors(
+        &self,
+        auth_server_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::OAuth2Client>>> {
+        self.runtime
+            .block_on(self.inner.list_all_o_auth_2_clients_fors(auth_server_id))
+    }
+    /// The blocking version of [`crate::authorization_servers::AuthorizationServers::list_refresh_tokens_for_and_clients`].
+    pub fn list_refresh_tokens_for_and_clients(
+        &self,
+        auth_server_id: &str,
+        client_id: &str,
+        expand: &str,
+        after: &str,
+        limit: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::OAuth2RefreshToken>>> {
+        self.runtime
+            .block_on(self.inner.list_refresh_tokens_for_and_clients(
+                auth_server_id,
+                client_id,
+                expand,
+                after,
+                limit,
+            ))
+    }
+    /// The blocking version of [`crate::authorization_servers::AuthorizationServers::list_refresh_tokens_for_and_clients_request`].
+    pub fn list_refresh_tokens_for_and_clients_request<'a>(
+        &'a self,
+        auth_server_id: &'a str,
+        client_id: &'a str,
+    ) -> AuthorizationServersListRefreshTokensForAndClientsRequest<'a> {
+        AuthorizationServersListRefreshTokensForAndClientsRequest {
+            inner: self
+                .inner
+                .list_refresh_tokens_for_and_clients_request(auth_server_id, client_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::authorization_servers::AuthorizationServers::list_all_refresh_tokens_for_and_clients`].
+    pub fn list_all_refresh_tokens_for_and_clients(
+        &self,
+        auth_server_id: &str,
+        client_id: &str,
+        expand: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::OAuth2RefreshToken>>> {
+        self.runtime
+            .block_on(self.inner.list_all_refresh_tokens_for_and_clients(
+                auth_server_id,
+                client_id,
+                expand,
+            ))
+    }
+    /// The blocking version of [`crate::authorization_servers::AuthorizationServers::revoke_refresh_tokens_for_and_client`].
+    pub fn revoke_refresh_tokens_for_and_client(
+        &self,
+        auth_server_id: &str,
+        client_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .revoke_refresh_tokens_for_and_client(auth_server_id, client_id),
+        )
+    }
+    /// The blocking version of [`crate::authorization_servers::AuthorizationServers::get_refresh_token_for_and_client`].
+    pub fn get_refresh_token_for_and_client(
+        &self,
+        auth_server_id: &str,
+        client_id: &str,
+        token_id: &str,
+        expand: &str,
+    ) -> ClientResult<crate::Response<crate::types::OAuth2RefreshToken>> {
+        self.runtime
+            .block_on(self.inner.get_refresh_token_for_and_client(
+                auth_server_id,
+                client_id,
+                token_id,
+                expand,
+            ))
+    }
+    /// The blocking version of [`crate::authorization_servers::AuthorizationServers::get_refresh_token_for_and_client_request`].
+    pub fn get_refresh_token_for_and_client_request<'a>(
+        &'a self,
+        auth_server_id: &'a str,
+        client_id: &'a str,
+        token_id: &'a str,
+    ) -> AuthorizationServersGetRefreshTokenForAndClientRequest<'a> {
+        AuthorizationServersGetRefreshTokenForAndClientRequest {
+            inner: self.inner.get_refresh_token_for_and_client_request(
+                auth_server_id,
+                client_id,
+                token_id,
+            ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::authorization_servers::AuthorizationServers::revoke_refresh_token_for_and_client`].
+    pub fn revoke_refresh_token_for_and_client(
+        &self,
+        auth_server_id: &str,
+        client_id: &str,
+        token_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.revoke_refresh_token_for_and_client(
+                auth_server_id,
+                client_id,
+                token_id,
+            ))
+    }
+    /// The blocking version of [`crate::authorization_servers::AuthorizationServers::list_keys`].
+    pub fn list_keys(
+        &self,
+        auth_server_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::JsonWebKey>>> {
+        self.runtime.block_on(self.inner.list_keys(auth_server_id))
+    }
+    /// The blocking version of [`crate::authorization_servers::AuthorizationServers::list_all_keys`].
+    pub fn list_all_keys(
+        &self,
+        auth_server_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::JsonWebKey>>> {
+        self.runtime
+            .block_on(self.inner.list_all_keys(auth_server_id))
+    }
+    /// The blocking version of [`crate::authorization_servers::AuthorizationServers::rotate_keys`].
+    pub fn rotate_keys(
+        &self,
+        auth_server_id: &str,
+        body: &crate::types::JwkUse,
+    ) -> ClientResult<crate::Response<Vec<crate::types::JsonWebKey>>> {
+        self.runtime
+            .block_on(self.inner.rotate_keys(auth_server_id, body))
+    }
+    /// The blocking version of [`crate::authorization_servers::AuthorizationServers::activate`].
+    pub fn activate(&self, auth_server_id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.activate(auth_server_id))
+    }
+    /// The blocking version of [`crate::authorization_servers::AuthorizationServers::deactivate`].
+    pub fn deactivate(&self, auth_server_id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.deactivate(auth_server_id))
+    }
+    /// The blocking version of [`crate::authorization_servers::AuthorizationServers::list_policies`].
+    pub fn list_policies(
+        &self,
+        auth_server_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::AuthorizationServerPolicy>>> {
+        self.runtime
+            .block_on(self.inner.list_policies(auth_server_id))
+    }
+    /// The blocking version of [`crate::authorization_servers::AuthorizationServers::list_all_policies`].
+    pub fn list_all_policies(
+        &self,
+        auth_server_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::AuthorizationServerPolicy>>> {
+        self.runtime
+            .block_on(self.inner.list_all_policies(auth_server_id))
+    }
+    /// The blocking version of [`crate::authorization_servers::AuthorizationServers::create_policy`].
+    pub fn create_policy(
+        &self,
+        auth_server_id: &str,
+        body: &crate::types::AuthorizationServerPolicy,
+    ) -> ClientResult<crate::Response<crate::types::AuthorizationServerPolicy>> {
+        self.runtime
+            .block_on(self.inner.create_policy(auth_server_id, body))
+    }
+    /// The blocking version of [`crate::authorization_servers::AuthorizationServers::get_policy`].
+    pub fn get_policy(
+        &self,
+        auth_server_id: &str,
+        policy_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::AuthorizationServerPolicy>> {
+        self.runtime
+            .block_on(self.inner.get_policy(auth_server_id, policy_id))
+    }
+    /// The blocking version of [`crate::authorization_servers::AuthorizationServers::update_policy`].
+    pub fn update_policy(
+        &self,
+        auth_server_id: &str,
+        policy_id: &str,
+        body: &crate::types::AuthorizationServerPolicy,
+    ) -> ClientResult<crate::Response<crate::types::AuthorizationServerPolicy>> {
+        self.runtime
+            .block_on(self.inner.update_policy(auth_server_id, policy_id, body))
+    }
+    /// The blocking version of [`crate::authorization_servers::AuthorizationServers::delete_policy`].
+    pub fn delete_policy(
+        &self,
+        auth_server_id: &str,
+        policy_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete_policy(auth_server_id, policy_id))
+    }
+    /// The blocking version of [`crate::authorization_servers::AuthorizationServers::activate_policy`].
+    pub fn activate_policy(
+        &self,
+        auth_server_id: &str,
+        policy_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.activate_policy(auth_server_id, policy_id))
+    }
+    /// The blocking version of [`crate::authorization_servers::AuthorizationServers::deactivate_policy`].
+    pub fn deactivate_policy(
+        &self,
+        auth_server_id: &str,
+        policy_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deactivate_policy(auth_server_id, policy_id))
+    }
+    /// The blocking version of [`crate::authorization_servers::AuthorizationServers::list_policy_rules`].
+    pub fn list_policy_rules(
+        &self,
+        policy_id: &str,
+        auth_server_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::AuthorizationServerPolicyRule>>> {
+        self.runtime
+            .block_on(self.inner.list_policy_rules(policy_id, auth_server_id))
+    }
+    /// The blocking version of [`crate::authorization_servers::AuthorizationServers::list_all_policy_rules`].
+    pub fn list_all_policy_rules(
+        &self,
+        policy_id: &str,
+        auth_server_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::AuthorizationServerPolicyRule>>> {
+        self.runtime
+            .block_on(self.inner.list_all_policy_rules(policy_id, auth_server_id))
+    }
+    /// The blocking version of [`crate::authorization_servers::AuthorizationServers::create_policy_rule`].
+    pub fn create_policy_rule(
+        &self,
+        policy_id: &str,
+        auth_server_id: &str,
+        body: &crate::types::AuthorizationServerPolicyRule,
+    ) -> ClientResult<crate::Response<crate::types::AuthorizationServerPolicyRule>> {
+        self.runtime.block_on(
+            self.inner
+                .create_policy_rule(policy_id, auth_server_id, body),
+        )
+    }
+    /// The blocking version of [`crate::authorization_servers::AuthorizationServers::get_policy_rule`].
+    pub fn get_policy_rule(
+        &self,
+        policy_id: &str,
+        auth_server_id: &str,
+        rule_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::AuthorizationServerPolicyRule>> {
+        self.runtime.block_on(
+            self.inner
+                .get_policy_rule(policy_id, auth_server_id, rule_id),
+        )
+    }
+    /// The blocking version of [`crate::authorization_servers::AuthorizationServers::update_policy_rule`].
+    pub fn update_policy_rule(
+        &self,
+        policy_id: &str,
+        auth_server_id: &str,
+        rule_id: &str,
+        body: &crate::types::AuthorizationServerPolicyRule,
+    ) -> ClientResult<crate::Response<crate::types::AuthorizationServerPolicyRule>> {
+        self.runtime.block_on(self.inner.update_policy_rule(
+            policy_id,
+            auth_server_id,
+            rule_id,
+            body,
+        ))
+    }
+    /// The blocking version of [`crate::authorization_servers::AuthorizationServers::delete_policy_rule`].
+    pub fn delete_policy_rule(
+        &self,
+        policy_id: &str,
+        auth_server_id: &str,
+        rule_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .delete_policy_rule(policy_id, auth_server_id, rule_id),
+        )
+    }
+    /// The blocking version of [`crate::authorization_servers::AuthorizationServers::activate_policy_rule`].
+    pub fn activate_policy_rule(
+        &self,
+        auth_server_id: &str,
+        policy_id: &str,
+        rule_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .activate_policy_rule(auth_server_id, policy_id, rule_id),
+        )
+    }
+    /// The blocking version of [`crate::authorization_servers::AuthorizationServers::deactivate_policy_rule`].
+    pub fn deactivate_policy_rule(
+        &self,
+        auth_server_id: &str,
+        policy_id: &str,
+        rule_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deactivate_policy_rule(auth_server_id, policy_id, rule_id),
+        )
+    }
+    /// The blocking version of [`crate::authorization_servers::AuthorizationServers::list_o_auth_2_scopes`].
+    pub fn list_o_auth_2_scopes(
+        &self,
+        auth_server_id: &str,
+        q: &str,
+        filter: &str,
+        cursor: &str,
+        limit: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::OAuth2Scope>>> {
+        self.runtime.block_on(self.inner.list_o_auth_2_scopes(
+            auth_server_id,
+            q,
+            filter,
+            cursor,
+            limit,
+        ))
+    }
+    /// The blocking version of [`crate::authorization_servers::AuthorizationServers::list_o_auth_2_scopes_request`].
+    pub fn list_o_auth_2_scopes_request<'a>(
+        &'a self,
+        auth_server_id: &'a str,
+    ) -> AuthorizationServersListOAuth2ScopesRequest<'a> {
+        AuthorizationServersListOAuth2ScopesRequest {
+            inner: self.inner.list_o_auth_2_scopes_request(auth_server_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::authorization_servers::AuthorizationServers::list_all_o_auth_2_scopes`].
+    pub fn list_all_o_auth_2_scopes(
+        &self,
+        auth_server_id: &str,
+        q: &str,
+        filter: &str,
+        cursor: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::OAuth2Scope>>> {
+        self.runtime.block_on(self.inner.list_all_o_auth_2_scopes(
+            auth_server_id,
+            q,
+            filter,
+            cursor,
+        ))
+    }
+    /// The blocking version of [`crate::authorization_servers::AuthorizationServers::create_o_auth_2_scope`].
+    pub fn create_o_auth_2_scope(
+        &self,
+        auth_server_id: &str,
+        body: &crate::types::OAuth2Scope,
+    ) -> ClientResult<crate::Response<crate::types::OAuth2Scope>> {
+        self.runtime
+            .block_on(self.inner.create_o_auth_2_scope(auth_server_id, body))
+    }
+    /// The blocking version of [`crate::authorization_servers::AuthorizationServers::get_o_auth_2_scope`].
+    pub fn get_o_auth_2_scope(
+        &self,
+        auth_server_id: &str,
+        scope_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::OAuth2Scope>> {
+        self.runtime
+            .block_on(self.inner.get_o_auth_2_scope(auth_server_id, scope_id))
+    }
+    /// The blocking version of [`crate::authorization_servers::AuthorizationServers::update_o_auth_2_scope`].
+    pub fn update_o_auth_2_scope(
+        &self,
+        auth_server_id: &str,
+        scope_id: &str,
+        body: &crate::types::OAuth2Scope,
+    ) -> ClientResult<crate::Response<crate::types::OAuth2Scope>> {
+        self.runtime.block_on(
+            self.inner
+                .update_o_auth_2_scope(auth_server_id, scope_id, body),
+        )
+    }
+    /// The blocking version of [`crate::authorization_servers::AuthorizationServers::delete_o_auth_2_scope`].
+    pub fn delete_o_auth_2_scope(
+        &self,
+        auth_server_id: &str,
+        scope_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete_o_auth_2_scope(auth_server_id, scope_id))
+    }
+}
@@ -0,0 +1,51 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::domains::Domains`].
+pub struct Domains {
+    inner: crate::domains::Domains,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Domains {
+    pub(crate) fn new(
+        inner: crate::domains::Domains,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Domains { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::domains::Domains::list`].
+    pub fn list(&self) -> ClientResult<crate::Response<crate::types::DomainListResponse>> {
+        self.runtime.block_on(self.inner.list())
+    }
+    /// The blocking version of [`crate::domains::Domains::create`].
+    pub fn create(
+        &self,
+        body: &crate::types::Domain,
+    ) -> ClientResult<crate::Response<crate::types::Domain>> {
+        self.runtime.block_on(self.inner.create(body))
+    }
+    /// The blocking version of [`crate::domains::Domains::get`].
+    pub fn get(&self, domain_id: &str) -> ClientResult<crate::Response<crate::types::Domain>> {
+        self.runtime.block_on(self.inner.get(domain_id))
+    }
+    /// The blocking version of [`crate::domains::Domains::delete`].
+    pub fn delete(&self, domain_id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.delete(domain_id))
+    }
+    /// The blocking version of [`crate::domains::Domains::create_certificate`].
+    pub fn create_certificate(
+        &self,
+        domain_id: &str,
+        body: &crate::types::DomainCertificate,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.create_certificate(domain_id, body))
+    }
+    /// The blocking version of [`crate::domains::Domains::verify`].
+    pub fn verify(&self, domain_id: &str) -> ClientResult<crate::Response<crate::types::Domain>> {
+        self.runtime.block_on(self.inner.verify(domain_id))
+    }
+}
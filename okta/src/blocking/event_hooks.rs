@@ -0,0 +1,75 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::event_hooks::EventHooks`].
+pub struct EventHooks {
+    inner: crate::event_hooks::EventHooks,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl EventHooks {
+    pub(crate) fn new(
+        inner: crate::event_hooks::EventHooks,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        EventHooks { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::event_hooks::EventHooks::list`].
+    pub fn list(&self) -> ClientResult<crate::Response<Vec<crate::types::EventHook>>> {
+        self.runtime.block_on(self.inner.list())
+    }
+    /// The blocking version of [`crate::event_hooks::EventHooks::list_all`].
+    pub fn list_all(&self) -> ClientResult<crate::Response<Vec<crate::types::EventHook>>> {
+        self.runtime.block_on(self.inner.list_all())
+    }
+    /// The blocking version of [`crate::event_hooks::EventHooks::create`].
+    pub fn create(
+        &self,
+        body: &crate::types::EventHook,
+    ) -> ClientResult<crate::Response<crate::types::EventHook>> {
+        self.runtime.block_on(self.inner.create(body))
+    }
+    /// The blocking version of [`crate::event_hooks::EventHooks::get`].
+    pub fn get(
+        &self,
+        event_hook_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::EventHook>> {
+        self.runtime.block_on(self.inner.get(event_hook_id))
+    }
+    /// The blocking version of [`crate::event_hooks::EventHooks::update`].
+    pub fn update(
+        &self,
+        event_hook_id: &str,
+        body: &crate::types::EventHook,
+    ) -> ClientResult<crate::Response<crate::types::EventHook>> {
+        self.runtime
+            .block_on(self.inner.update(event_hook_id, body))
+    }
+    /// The blocking version of [`crate::event_hooks::EventHooks::delete`].
+    pub fn delete(&self, event_hook_id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.delete(event_hook_id))
+    }
+    /// The blocking version of [`crate::event_hooks::EventHooks::activate`].
+    pub fn activate(
+        &self,
+        event_hook_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::EventHook>> {
+        self.runtime.block_on(self.inner.activate(event_hook_id))
+    }
+    /// The blocking version of [`crate::event_hooks::EventHooks::deactivate`].
+    pub fn deactivate(
+        &self,
+        event_hook_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::EventHook>> {
+        self.runtime.block_on(self.inner.deactivate(event_hook_id))
+    }
+    /// The blocking version of [`crate::event_hooks::EventHooks::verify`].
+    pub fn verify(
+        &self,
+        event_hook_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::EventHook>> {
+        self.runtime.block_on(self.inner.verify(event_hook_id))
+    }
+}
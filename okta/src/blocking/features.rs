@@ -0,0 +1,103 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Features::update_lifecycle`], set with a builder.
+pub struct FeaturesUpdateLifecycleRequest<'a> {
+    inner: crate::features::FeaturesUpdateLifecycleRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> FeaturesUpdateLifecycleRequest<'a> {
+    /// Sets the `mode` query parameter.
+    pub fn mode(mut self, mode: &'a str) -> Self {
+        self.inner = self.inner.mode(mode);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::Feature>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::features::Features`].
+pub struct Features {
+    inner: crate::features::Features,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Features {
+    pub(crate) fn new(
+        inner: crate::features::Features,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Features { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::features::Features::list`].
+    pub fn list(&self) -> ClientResult<crate::Response<Vec<crate::types::Feature>>> {
+        self.runtime.block_on(self.inner.list())
+    }
+    /// The blocking version of [`crate::features::Features::list_all`].
+    pub fn list_all(&self) -> ClientResult<crate::Response<Vec<crate::types::Feature>>> {
+        self.runtime.block_on(self.inner.list_all())
+    }
+    /// The blocking version of [`crate::features::Features::get`].
+    pub fn get(&self, feature_id: &str) -> ClientResult<crate::Response<crate::types::Feature>> {
+        self.runtime.block_on(self.inner.get(feature_id))
+    }
+    /// The blocking version of [`crate::features::Features::list_dependencies`].
+    pub fn list_dependencies(
+        &self,
+        feature_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Feature>>> {
+        self.runtime
+            .block_on(self.inner.list_dependencies(feature_id))
+    }
+    /// The blocking version of [`crate::features::Features::list_all_dependencies`].
+    pub fn list_all_dependencies(
+        &self,
+        feature_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Feature>>> {
+        self.runtime
+            .block_on(self.inner.list_all_dependencies(feature_id))
+    }
+    /// The blocking version of [`crate::features::Features::list_dependents`].
+    pub fn list_dependents(
+        &self,
+        feature_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Feature>>> {
+        self.runtime
+            .block_on(self.inner.list_dependents(feature_id))
+    }
+    /// The blocking version of [`crate::features::Features::list_all_dependents`].
+    pub fn list_all_dependents(
+        &self,
+        feature_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Feature>>> {
+        self.runtime
+            .block_on(self.inner.list_all_dependents(feature_id))
+    }
+    /// The blocking version of [`crate::features::Features::update_lifecycle`].
+    pub fn update_lifecycle(
+        &self,
+        feature_id: &str,
+        lifecycle: &str,
+        mode: &str,
+    ) -> ClientResult<crate::Response<crate::types::Feature>> {
+        self.runtime
+            .block_on(self.inner.update_lifecycle(feature_id, lifecycle, mode))
+    }
+    /// The blocking version of [`crate::features::Features::update_lifecycle_request`].
+    pub fn update_lifecycle_request<'a>(
+        &'a self,
+        feature_id: &'a str,
+        lifecycle: &'a str,
+    ) -> FeaturesUpdateLifecycleRequest<'a> {
+        FeaturesUpdateLifecycleRequest {
+            inner: self.inner.update_lifecycle_request(feature_id, lifecycle),
+            runtime: &self.runtime,
+        }
+    }
+}
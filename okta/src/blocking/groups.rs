@@ -0,0 +1,702 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Groups::list`], set with a builder.
+pub struct GroupsListRequest<'a> {
+    inner: crate::groups::GroupsListRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> GroupsListRequest<'a> {
+    /// Sets the `q` query parameter.
+    pub fn q(mut self, q: &'a str) -> Self {
+        self.inner = self.inner.q(q);
+        self
+    }
+
+    /// Sets the `search` query parameter.
+    pub fn search(mut self, search: &'a str) -> Self {
+        self.inner = self.inner.search(search);
+        self
+    }
+
+    /// Sets the `after` query parameter.
+    pub fn after(mut self, after: &'a str) -> Self {
+        self.inner = self.inner.after(after);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `expand` query parameter.
+    pub fn expand(mut self, expand: &'a str) -> Self {
+        self.inner = self.inner.expand(expand);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Group>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Groups::list_rules`], set with a builder.
+pub struct GroupsListRulesRequest<'a> {
+    inner: crate::groups::GroupsListRulesRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> GroupsListRulesRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `after` query parameter.
+    pub fn after(mut self, after: &'a str) -> Self {
+        self.inner = self.inner.after(after);
+        self
+    }
+
+    /// Sets the `search` query parameter.
+    pub fn search(mut self, search: &'a str) -> Self {
+        self.inner = self.inner.search(search);
+        self
+    }
+
+    /// Sets the `expand` query parameter.
+    pub fn expand(mut self, expand: &'a str) -> Self {
+        self.inner = self.inner.expand(expand);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::GroupRule>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Groups::get_rule`], set with a builder.
+pub struct GroupsGetRuleRequest<'a> {
+    inner: crate::groups::GroupsGetRuleRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> GroupsGetRuleRequest<'a> {
+    /// Sets the `expand` query parameter.
+    pub fn expand(mut self, expand: &'a str) -> Self {
+        self.inner = self.inner.expand(expand);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::GroupRule>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Groups::delete_rule`], set with a builder.
+pub struct GroupsDeleteRuleRequest<'a> {
+    inner: crate::groups::GroupsDeleteRuleRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> GroupsDeleteRuleRequest<'a> {
+    /// Sets the `removeUsers` query parameter.
+    pub fn remove_users(mut self, remove_users: bool) -> Self {
+        self.inner = self.inner.remove_users(remove_users);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Groups::list_assigned_applications_fors`], set with a builder.
+pub struct GroupsListAssignedApplicationsForsRequest<'a> {
+    inner: crate::groups::GroupsListAssignedApplicationsForsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> GroupsListAssignedApplicationsForsRequest<'a> {
+    /// Sets the `after` query parameter.
+    pub fn after(mut self, after: &'a str) -> Self {
+        self.inner = self.inner.after(after);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Application>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Groups::list_assigned_roles`], set with a builder.
+pub struct GroupsListAssignedRolesRequest<'a> {
+    inner: crate::groups::GroupsListAssignedRolesRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> GroupsListAssignedRolesRequest<'a> {
+    /// Sets the `expand` query parameter.
+    pub fn expand(mut self, expand: &'a str) -> Self {
+        self.inner = self.inner.expand(expand);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Role>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Groups::assign_role`], set with a builder.
+pub struct GroupsAssignRoleRequest<'a> {
+    inner: crate::groups::GroupsAssignRoleRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> GroupsAssignRoleRequest<'a> {
+    /// Sets the `disableNotifications` query parameter.
+    pub fn disable_notifications(mut self, disable_notifications: &'a str) -> Self {
+        self.inner = self.inner.disable_notifications(disable_notifications);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::Role>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Groups::list_application_targets_for_administrator_roles`], set with a builder.
+pub struct GroupsListApplicationTargetsForAdministratorRolesRequest<'a> {
+    inner: crate::groups::GroupsListApplicationTargetsForAdministratorRolesRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> GroupsListApplicationTargetsForAdministratorRolesRequest<'a> {
+    /// Sets the `after` query parameter.
+    pub fn after(mut self, after: &'a str) -> Self {
+        self.inner = self.inner.after(after);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::CatalogApplication>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Groups::list_targets_for_roles`], set with a builder.
+pub struct GroupsListTargetsForRolesRequest<'a> {
+    inner: crate::groups::GroupsListTargetsForRolesRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> GroupsListTargetsForRolesRequest<'a> {
+    /// Sets the `after` query parameter.
+    pub fn after(mut self, after: &'a str) -> Self {
+        self.inner = self.inner.after(after);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Group>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Groups::list_users`], set with a builder.
+pub struct GroupsListUsersRequest<'a> {
+    inner: crate::groups::GroupsListUsersRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> GroupsListUsersRequest<'a> {
+    /// Sets the `after` query parameter.
+    pub fn after(mut self, after: &'a str) -> Self {
+        self.inner = self.inner.after(after);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::User>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::groups::Groups`].
+pub struct Groups {
+    inner: crate::groups::Groups,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Groups {
+    pub(crate) fn new(inner: crate::groups::Groups, runtime: Arc<tokio::runtime::Runtime>) -> Self {
+        Groups { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::groups::Groups::list`].
+    pub fn list(
+        &self,
+        q: &str,
+        search: &str,
+        after: &str,
+        limit: i64,
+        expand: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Group>>> {
+        self.runtime
+            .block_on(self.inner.list(q, search, after, limit, expand))
+    }
+    /// The blocking version of [`crate::groups::Groups::list_request`].
+    pub fn list_request(&self) -> GroupsListRequest<'_> {
+        GroupsListRequest {
+            inner: self.inner.list_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::groups::Groups::list_all`].
+    pub fn list_all(
+        &self,
+        q: &str,
+        search: &str,
+        expand: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Group>>> {
+        self.runtime
+            .block_on(self.inner.list_all(q, search, expand))
+    }
+    /// The blocking version of [`crate::groups::Groups::create`].
+    pub fn create(
+        &self,
+        body: &crate::types::Group,
+    ) -> ClientResult<crate::Response<crate::types::Group>> {
+        self.runtime.block_on(self.inner.create(body))
+    }
+    /// The blocking version of [`crate::groups::Groups::list_rules`].
+    pub fn list_rules(
+        &self,
+        limit: i64,
+        after: &str,
+        search: &str,
+        expand: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GroupRule>>> {
+        self.runtime
+            .block_on(self.inner.list_rules(limit, after, search, expand))
+    }
+    /// The blocking version of [`crate::groups::Groups::list_rules_request`].
+    pub fn list_rules_request(&self) -> GroupsListRulesRequest<'_> {
+        GroupsListRulesRequest {
+            inner: self.inner.list_rules_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::groups::Groups::list_all_rules`].
+    pub fn list_all_rules(
+        &self,
+        search: &str,
+        expand: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GroupRule>>> {
+        self.runtime
+            .block_on(self.inner.list_all_rules(search, expand))
+    }
+    /// The blocking version of [`crate::groups::Groups::create_rule`].
+    pub fn create_rule(
+        &self,
+        body: &crate::types::GroupRule,
+    ) -> ClientResult<crate::Response<crate::types::GroupRule>> {
+        self.runtime.block_on(self.inner.create_rule(body))
+    }
+    /// The blocking version of [`crate::groups::Groups::get_rule`].
+    pub fn get_rule(
+        &self,
+        rule_id: &str,
+        expand: &str,
+    ) -> ClientResult<crate::Response<crate::types::GroupRule>> {
+        self.runtime.block_on(self.inner.get_rule(rule_id, expand))
+    }
+    /// The blocking version of [`crate::groups::Groups::get_rule_request`].
+    pub fn get_rule_request<'a>(&'a self, rule_id: &'a str) -> GroupsGetRuleRequest<'a> {
+        GroupsGetRuleRequest {
+            inner: self.inner.get_rule_request(rule_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::groups::Groups::update_rule`].
+    pub fn update_rule(
+        &self,
+        rule_id: &str,
+        body: &crate::types::GroupRule,
+    ) -> ClientResult<crate::Response<crate::types::GroupRule>> {
+        self.runtime.block_on(self.inner.update_rule(rule_id, body))
+    }
+    /// The blocking version of [`crate::groups::Groups::delete_rule`].
+    pub fn delete_rule(
+        &self,
+        rule_id: &str,
+        remove_users: bool,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete_rule(rule_id, remove_users))
+    }
+    /// The blocking version of [`crate::groups::Groups::delete_rule_request`].
+    pub fn delete_rule_request<'a>(&'a self, rule_id: &'a str) -> GroupsDeleteRuleRequest<'a> {
+        GroupsDeleteRuleRequest {
+            inner: self.inner.delete_rule_request(rule_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::groups::Groups::activate_rule`].
+    pub fn activate_rule(&self, rule_id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.activate_rule(rule_id))
+    }
+    /// The blocking version of [`crate::groups::Groups::deactivate_rule`].
+    pub fn deactivate_rule(&self, rule_id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.deactivate_rule(rule_id))
+    }
+    /// The blocking version of [`crate::groups::Groups::get`].
+    pub fn get(&self, group_id: &str) -> ClientResult<crate::Response<crate::types::Group>> {
+        self.runtime.block_on(self.inner.get(group_id))
+    }
+    /// The blocking version of [`crate::groups::Groups::update`].
+    pub fn update(
+        &self,
+        group_id: &str,
+        body: &crate::types::Group,
+    ) -> ClientResult<crate::Response<crate::types::Group>> {
+        self.runtime.block_on(self.inner.update(group_id, body))
+    }
+    /// The blocking version of [`crate::groups::Groups::delete`].
+    pub fn delete(&self, group_id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.delete(group_id))
+    }
+    /// The blocking version of [`crate::groups::Groups::list_assigned_applications_fors`].
+    pub fn list_assigned_applications_fors(
+        &self,
+        group_id: &str,
+        after: &str,
+        limit: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Application>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_assigned_applications_fors(group_id, after, limit),
+        )
+    }
+    /// The blocking version of [`crate::groups::Groups::list_assigned_applications_fors_request`].
+    pub fn list_assigned_applications_fors_request<'a>(
+        &'a self,
+        group_id: &'a str,
+    ) -> GroupsListAssignedApplicationsForsRequest<'a> {
+        GroupsListAssignedApplicationsForsRequest {
+            inner: self.inner.list_assigned_applications_fors_request(group_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::groups::Groups::list_all_assigned_applications_fors`].
+    pub fn list_all_assigned_applications_fors(
+        &self,
+        group_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Application>>> {
+        self.runtime
+            .block_on(self.inner.list_all_assigned_applications_fors(group_id))
+    }
+    /// The blocking version of [`crate::groups::Groups::list_assigned_roles`].
+    pub fn list_assigned_roles(
+        &self,
+        group_id: &str,
+        expand: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Role>>> {
+        self.runtime
+            .block_on(self.inner.list_assigned_roles(group_id, expand))
+    }
+    /// The blocking version of [`crate::groups::Groups::list_assigned_roles_request`].
+    pub fn list_assigned_roles_request<'a>(
+        &'a self,
+        group_id: &'a str,
+    ) -> GroupsListAssignedRolesRequest<'a> {
+        GroupsListAssignedRolesRequest {
+            inner: self.inner.list_assigned_roles_request(group_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::groups::Groups::list_all_assigned_roles`].
+    pub fn list_all_assigned_roles(
+        &self,
+        group_id: &str,
+        expand: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Role>>> {
+        self.runtime
+            .block_on(self.inner.list_all_assigned_roles(group_id, expand))
+    }
+    /// The blocking version of [`crate::groups::Groups::assign_role`].
+    pub fn assign_role(
+        &self,
+        group_id: &str,
+        disable_notifications: &str,
+        body: &crate::types::AssignRoleRequest,
+    ) -> ClientResult<crate::Response<crate::types::Role>> {
+        self.runtime.block_on(
+            self.inner
+                .assign_role(group_id, disable_notifications, body),
+        )
+    }
+    /// The blocking version of [`crate::groups::Groups::assign_role_request`].
+    pub fn assign_role_request<'a>(
+        &'a self,
+        group_id: &'a str,
+        body: &'a crate::types::AssignRoleRequest,
+    ) -> GroupsAssignRoleRequest<'a> {
+        GroupsAssignRoleRequest {
+            inner: self.inner.assign_role_request(group_id, body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::groups::Groups::get_role`].
+    pub fn get_role(
+        &self,
+        group_id: &str,
+        role_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Role>> {
+        self.runtime
+            .block_on(self.inner.get_role(group_id, role_id))
+    }
+    /// The blocking version of [`crate::groups::Groups::remove_role_from`].
+    pub fn remove_role_from(
+        &self,
+        group_id: &str,
+        role_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.remove_role_from(group_id, role_id))
+    }
+    /// The blocking version of [`crate::groups::Groups::list_application_targets_for_administrator_roles`].
+    pub fn list_application_targets_for_administrator_roles(
+        &self,
+        group_id: &str,
+        role_id: &str,
+        after: &str,
+        limit: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::CatalogApplication>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_application_targets_for_administrator_roles(group_id, role_id, after, limit),
+        )
+    }
+    /// The blocking version of [`crate::groups::Groups::list_application_targets_for_administrator_roles_request`].
+    pub fn list_application_targets_for_administrator_roles_request<'a>(
+        &'a self,
+        group_id: &'a str,
+        role_id: &'a str,
+    ) -> GroupsListApplicationTargetsForAdministratorRolesRequest<'a> {
+        GroupsListApplicationTargetsForAdministratorRolesRequest {
+            inner: self
+                .inner
+                .list_application_targets_for_administrator_roles_request(group_id, role_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::groups::Groups::list_all_application_targets_for_administrator_roles`].
+    pub fn list_all_application_targets_for_administrator_roles(
+        &self,
+        group_id: &str,
+        role_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::CatalogApplication>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_all_application_targets_for_administrator_roles(group_id, role_id),
+        )
+    }
+    /// The blocking version of [`crate::groups::Groups::add_application_target_admin_role_given`].
+    pub fn add_application_target_admin_role_given(
+        &self,
+        group_id: &str,
+        role_id: &str,
+        app_name: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .add_application_target_admin_role_given(group_id, role_id, app_name),
+        )
+    }
+    /// The blocking version of [`crate::groups::Groups::remove_application_target_from_administrator_role_given`].
+    pub fn remove_application_target_from_administrator_role_given(
+        &self,
+        group_id: &str,
+        role_id: &str,
+        app_name: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .remove_application_target_from_administrator_role_given(
+                    group_id, role_id, app_name,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::groups::Groups::add_application_instance_target_app_admin_role_given`].
+    pub fn add_application_instance_target_app_admin_role_given(
+        &self,
+        group_id: &str,
+        role_id: &str,
+        app_name: &str,
+        application_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .add_application_instance_target_app_admin_role_given(
+                    group_id,
+                    role_id,
+                    app_name,
+                    application_id,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::groups::Groups::remove_application_target_from_administrator_role_given_groups`].
+    pub fn remove_application_target_from_administrator_role_given_groups(
+        &self,
+        group_id: &str,
+        role_id: &str,
+        app_name: &str,
+        application_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .remove_application_target_from_administrator_role_given_groups(
+                    group_id,
+                    role_id,
+                    app_name,
+                    application_id,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::groups::Groups::list_targets_for_roles`].
+    pub fn list_targets_for_roles(
+        &self,
+        group_id: &str,
+        role_id: &str,
+        after: &str,
+        limit: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Group>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_targets_for_roles(group_id, role_id, after, limit),
+        )
+    }
+    /// The blocking version of [`crate::groups::Groups::list_targets_for_roles_request`].
+    pub fn list_targets_for_roles_request<'a>(
+        &'a self,
+        group_id: &'a str,
+        role_id: &'a str,
+    ) -> GroupsListTargetsForRolesRequest<'a> {
+        GroupsListTargetsForRolesRequest {
+            inner: self.inner.list_targets_for_roles_request(group_id, role_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::groups::Groups::list_all_targets_for_roles`].
+    pub fn list_all_targets_for_roles(
+        &self,
+        group_id: &str,
+        role_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Group>>> {
+        self.runtime
+            .block_on(self.inner.list_all_targets_for_roles(group_id, role_id))
+    }
+    /// The blocking version of [`crate::groups::Groups::add_target_administrator_role_for`].
+    pub fn add_target_administrator_role_for(
+        &self,
+        group_id: &str,
+        role_id: &str,
+        target_group_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.add_target_administrator_role_for(
+                group_id,
+                role_id,
+                target_group_id,
+            ))
+    }
+    /// The blocking version of [`crate::groups::Groups::remove_target_from_administrator_role_given`].
+    pub fn remove_target_from_administrator_role_given(
+        &self,
+        group_id: &str,
+        role_id: &str,
+        target_group_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.remove_target_from_administrator_role_given(
+                group_id,
+                role_id,
+                target_group_id,
+            ))
+    }
+    /// The blocking version of [`crate::groups::Groups::list_users`].
+    pub fn list_users(
+        &self,
+        group_id: &str,
+        after: &str,
+        limit: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::User>>> {
+        self.runtime
+            .block_on(self.inner.list_users(group_id, after, limit))
+    }
+    /// The blocking version of [`crate::groups::Groups::list_users_request`].
+    pub fn list_users_request<'a>(&'a self, group_id: &'a str) -> GroupsListUsersRequest<'a> {
+        GroupsListUsersRequest {
+            inner: self.inner.list_users_request(group_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::groups::Groups::list_all_users`].
+    pub fn list_all_users(
+        &self,
+        group_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::User>>> {
+        self.runtime.block_on(self.inner.list_all_users(group_id))
+    }
+    /// The blocking version of [`crate::groups::Groups::add_user`].
+    pub fn add_user(&self, group_id: &str, user_id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.add_user(group_id, user_id))
+    }
+    /// The blocking version of [`crate::groups::Groups::remove_user_from`].
+    pub fn remove_user_from(
+        &self,
+        group_id: &str,
+        user_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.remove_user_from(group_id, user_id))
+    }
+}
@@ -0,0 +1,334 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`IdentityProviders::list`], set with a builder.
+pub struct IdentityProvidersListRequest<'a> {
+    inner: crate::identity_providers::IdentityProvidersListRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> IdentityProvidersListRequest<'a> {
+    /// Sets the `q` query parameter.
+    pub fn q(mut self, q: &'a str) -> Self {
+        self.inner = self.inner.q(q);
+        self
+    }
+
+    /// Sets the `after` query parameter.
+    pub fn after(mut self, after: &'a str) -> Self {
+        self.inner = self.inner.after(after);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `type` query parameter.
+    pub fn type_(mut self, type_: &'a str) -> Self {
+        self.inner = self.inner.type_(type_);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::IdentityProvider>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`IdentityProviders::list_keys`], set with a builder.
+pub struct IdentityProvidersListKeysRequest<'a> {
+    inner: crate::identity_providers::IdentityProvidersListKeysRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> IdentityProvidersListKeysRequest<'a> {
+    /// Sets the `after` query parameter.
+    pub fn after(mut self, after: &'a str) -> Self {
+        self.inner = self.inner.after(after);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::JsonWebKey>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::identity_providers::IdentityProviders`].
+pub struct IdentityProviders {
+    inner: crate::identity_providers::IdentityProviders,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl IdentityProviders {
+    pub(crate) fn new(
+        inner: crate::identity_providers::IdentityProviders,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        IdentityProviders { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::identity_providers::IdentityProviders::list`].
+    pub fn list(
+        &self,
+        q: &str,
+        after: &str,
+        limit: i64,
+        type_: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::IdentityProvider>>> {
+        self.runtime
+            .block_on(self.inner.list(q, after, limit, type_))
+    }
+    /// The blocking version of [`crate::identity_providers::IdentityProviders::list_request`].
+    pub fn list_request(&self) -> IdentityProvidersListRequest<'_> {
+        IdentityProvidersListRequest {
+            inner: self.inner.list_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::identity_providers::IdentityProviders::list_all`].
+    pub fn list_all(
+        &self,
+        q: &str,
+        type_: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::IdentityProvider>>> {
+        self.runtime.block_on(self.inner.list_all(q, type_))
+    }
+    /// The blocking version of [`crate::identity_providers::IdentityProviders::create`].
+    pub fn create(
+        &self,
+        body: &crate::types::IdentityProvider,
+    ) -> ClientResult<crate::Response<crate::types::IdentityProvider>> {
+        self.runtime.block_on(self.inner.create(body))
+    }
+    /// The blocking version of [`crate::identity_providers::IdentityProviders::list_keys`].
+    pub fn list_keys(
+        &self,
+        after: &str,
+        limit: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::JsonWebKey>>> {
+        self.runtime.block_on(self.inner.list_keys(after, limit))
+    }
+    /// The blocking version of [`crate::identity_providers::IdentityProviders::list_keys_request`].
+    pub fn list_keys_request(&self) -> IdentityProvidersListKeysRequest<'_> {
+        IdentityProvidersListKeysRequest {
+            inner: self.inner.list_keys_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::identity_providers::IdentityProviders::list_all_keys`].
+    pub fn list_all_keys(&self) -> ClientResult<crate::Response<Vec<crate::types::JsonWebKey>>> {
+        self.runtime.block_on(self.inner.list_all_keys())
+    }
+    /// The blocking version of [`crate::identity_providers::IdentityProviders::create_key`].
+    pub fn create_key(
+        &self,
+        body: &crate::types::JsonWebKey,
+    ) -> ClientResult<crate::Response<crate::types::JsonWebKey>> {
+        self.runtime.block_on(self.inner.create_key(body))
+    }
+    /// The blocking version of [`crate::identity_providers::IdentityProviders::get_key`].
+    pub fn get_key(&self, key_id: &str) -> ClientResult<crate::Response<crate::types::JsonWebKey>> {
+        self.runtime.block_on(self.inner.get_key(key_id))
+    }
+    /// The blocking version of [`crate::identity_providers::IdentityProviders::delete_key`].
+    pub fn delete_key(&self, key_id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.delete_key(key_id))
+    }
+    /// The blocking version of [`crate::identity_providers::IdentityProviders::get`].
+    pub fn get(
+        &self,
+        idp_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::IdentityProvider>> {
+        self.runtime.block_on(self.inner.get(idp_id))
+    }
+    /// The blocking version of [`crate::identity_providers::IdentityProviders::update`].
+    pub fn update(
+        &self,
+        idp_id: &str,
+        body: &crate::types::IdentityProvider,
+    ) -> ClientResult<crate::Response<crate::types::IdentityProvider>> {
+        self.runtime.block_on(self.inner.update(idp_id, body))
+    }
+    /// The blocking version of [`crate::identity_providers::IdentityProviders::delete`].
+    pub fn delete(&self, idp_id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.delete(idp_id))
+    }
+    /// The blocking version of [`crate::identity_providers::IdentityProviders::list_csrs_fors`].
+    pub fn list_csrs_fors(
+        &self,
+        idp_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Csr>>> {
+        self.runtime.block_on(self.inner.list_csrs_fors(idp_id))
+    }
+    /// The blocking version of [`crate::identity_providers::IdentityProviders::list_all_csrs_fors`].
+    pub fn list_all_csrs_fors(
+        &self,
+        idp_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Csr>>> {
+        self.runtime.block_on(self.inner.list_all_csrs_fors(idp_id))
+    }
+    /// The blocking version of [`crate::identity_providers::IdentityProviders::generate_csr_for`].
+    pub fn generate_csr_for(
+        &self,
+        idp_id: &str,
+        body: &crate::types::CsrMetadata,
+    ) -> ClientResult<crate::Response<crate::types::Csr>> {
+        self.runtime
+            .block_on(self.inner.generate_csr_for(idp_id, body))
+    }
+    /// The blocking version of [`crate::identity_providers::IdentityProviders::get_csr_for`].
+    pub fn get_csr_for(
+        &self,
+        idp_id: &str,
+        csr_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Csr>> {
+        self.runtime
+            .block_on(self.inner.get_csr_for(idp_id, csr_id))
+    }
+    /// The blocking version of [`crate::identity_providers::IdentityProviders::revoke_csr_for`].
+    pub fn revoke_csr_for(&self, idp_id: &str, csr_id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.revoke_csr_for(idp_id, csr_id))
+    }
+    /// The blocking version of [`crate::identity_providers::IdentityProviders::post_idp_credentials_csr_lifecycle_publish`].
+    pub fn post_idp_credentials_csr_lifecycle_publish(
+        &self,
+        idp_id: &str,
+        csr_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::JsonWebKey>> {
+        self.runtime.block_on(
+            self.inner
+                .post_idp_credentials_csr_lifecycle_publish(idp_id, csr_id),
+        )
+    }
+    /// The blocking version of [`crate::identity_providers::IdentityProviders::list_signing_keys`].
+    pub fn list_signing_keys(
+        &self,
+        idp_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::JsonWebKey>>> {
+        self.runtime.block_on(self.inner.list_signing_keys(idp_id))
+    }
+    /// The blocking version of [`crate::identity_providers::IdentityProviders::list_all_signing_keys`].
+    pub fn list_all_signing_keys(
+        &self,
+        idp_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::JsonWebKey>>> {
+        self.runtime
+            .block_on(self.inner.list_all_signing_keys(idp_id))
+    }
+    /// The blocking version of [`crate::identity_providers::IdentityProviders::generate_signing_key`].
+    pub fn generate_signing_key(
+        &self,
+        idp_id: &str,
+        validity_years: i64,
+    ) -> ClientResult<crate::Response<crate::types::JsonWebKey>> {
+        self.runtime
+            .block_on(self.inner.generate_signing_key(idp_id, validity_years))
+    }
+    /// The blocking version of [`crate::identity_providers::IdentityProviders::get_signing_key`].
+    pub fn get_signing_key(
+        &self,
+        idp_id: &str,
+        key_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::JsonWebKey>> {
+        self.runtime
+            .block_on(self.inner.get_signing_key(idp_id, key_id))
+    }
+    /// The blocking version of [`crate::identity_providers::IdentityProviders::clone_key`].
+    pub fn clone_key(
+        &self,
+        idp_id: &str,
+        key_id: &str,
+        target_idp_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::JsonWebKey>> {
+        self.runtime
+            .block_on(self.inner.clone_key(idp_id, key_id, target_idp_id))
+    }
+    /// The blocking version of [`crate::identity_providers::IdentityProviders::activate`].
+    pub fn activate(
+        &self,
+        idp_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::IdentityProvider>> {
+        self.runtime.block_on(self.inner.activate(idp_id))
+    }
+    /// The blocking version of [`crate::identity_providers::IdentityProviders::deactivate`].
+    pub fn deactivate(
+        &self,
+        idp_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::IdentityProvider>> {
+        self.runtime.block_on(self.inner.deactivate(idp_id))
+    }
+    /// The blocking version of [`crate::identity_providers::IdentityProviders::list_application_users`].
+    pub fn list_application_users(
+        &self,
+        idp_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::IdentityProviderApplicationUser>>> {
+        self.runtime
+            .block_on(self.inner.list_application_users(idp_id))
+    }
+    /// The blocking version of [`crate::identity_providers::IdentityProviders::list_all_application_users`].
+    pub fn list_all_application_users(
+        &self,
+        idp_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::IdentityProviderApplicationUser>>> {
+        self.runtime
+            .block_on(self.inner.list_all_application_users(idp_id))
+    }
+    /// The blocking version of [`crate::identity_providers::IdentityProviders::get_application_user`].
+    pub fn get_application_user(
+        &self,
+        idp_id: &str,
+        user_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::IdentityProviderApplicationUser>> {
+        self.runtime
+            .block_on(self.inner.get_application_user(idp_id, user_id))
+    }
+    /// The blocking version of [`crate::identity_providers::IdentityProviders::link_user`].
+    pub fn link_user(
+        &self,
+        idp_id: &str,
+        user_id: &str,
+        body: &crate::types::UserIdentityProviderLinkRequest,
+    ) -> ClientResult<crate::Response<crate::types::IdentityProviderApplicationUser>> {
+        self.runtime
+            .block_on(self.inner.link_user(idp_id, user_id, body))
+    }
+    /// The blocking version of [`crate::identity_providers::IdentityProviders::unlink_user_from`].
+    pub fn unlink_user_from(
+        &self,
+        idp_id: &str,
+        user_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.unlink_user_from(idp_id, user_id))
+    }
+    /// The blocking version of [`crate::identity_providers::IdentityProviders::list_social_auth_tokens`].
+    pub fn list_social_auth_tokens(
+        &self,
+        idp_id: &str,
+        user_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::SocialAuthToken>>> {
+        self.runtime
+            .block_on(self.inner.list_social_auth_tokens(idp_id, user_id))
+    }
+    /// The blocking version of [`crate::identity_providers::IdentityProviders::list_all_social_auth_tokens`].
+    pub fn list_all_social_auth_tokens(
+        &self,
+        idp_id: &str,
+        user_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::SocialAuthToken>>> {
+        self.runtime
+            .block_on(self.inner.list_all_social_auth_tokens(idp_id, user_id))
+    }
+}
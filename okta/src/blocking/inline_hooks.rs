@@ -0,0 +1,109 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`InlineHooks::list`], set with a builder.
+pub struct InlineHooksListRequest<'a> {
+    inner: crate::inline_hooks::InlineHooksListRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> InlineHooksListRequest<'a> {
+    /// Sets the `type` query parameter.
+    pub fn type_(mut self, type_: &'a str) -> Self {
+        self.inner = self.inner.type_(type_);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::InlineHook>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::inline_hooks::InlineHooks`].
+pub struct InlineHooks {
+    inner: crate::inline_hooks::InlineHooks,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl InlineHooks {
+    pub(crate) fn new(
+        inner: crate::inline_hooks::InlineHooks,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        InlineHooks { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::inline_hooks::InlineHooks::list`].
+    pub fn list(
+        &self,
+        type_: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::InlineHook>>> {
+        self.runtime.block_on(self.inner.list(type_))
+    }
+    /// The blocking version of [`crate::inline_hooks::InlineHooks::list_request`].
+    pub fn list_request(&self) -> InlineHooksListRequest<'_> {
+        InlineHooksListRequest {
+            inner: self.inner.list_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::inline_hooks::InlineHooks::list_all`].
+    pub fn list_all(
+        &self,
+        type_: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::InlineHook>>> {
+        self.runtime.block_on(self.inner.list_all(type_))
+    }
+    /// The blocking version of [`crate::inline_hooks::InlineHooks::create`].
+    pub fn create(
+        &self,
+        body: &crate::types::InlineHook,
+    ) -> ClientResult<crate::Response<crate::types::InlineHook>> {
+        self.runtime.block_on(self.inner.create(body))
+    }
+    /// The blocking version of [`crate::inline_hooks::InlineHooks::get`].
+    pub fn get(
+        &self,
+        inline_hook_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::InlineHook>> {
+        self.runtime.block_on(self.inner.get(inline_hook_id))
+    }
+    /// The blocking version of [`crate::inline_hooks::InlineHooks::update`].
+    pub fn update(
+        &self,
+        inline_hook_id: &str,
+        body: &crate::types::InlineHook,
+    ) -> ClientResult<crate::Response<crate::types::InlineHook>> {
+        self.runtime
+            .block_on(self.inner.update(inline_hook_id, body))
+    }
+    /// The blocking version of [`crate::inline_hooks::InlineHooks::delete`].
+    pub fn delete(&self, inline_hook_id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.delete(inline_hook_id))
+    }
+    /// The blocking version of [`crate::inline_hooks::InlineHooks::execute`].
+    pub fn execute(
+        &self,
+        inline_hook_id: &str,
+        body: &crate::types::Links,
+    ) -> ClientResult<crate::Response<crate::types::InlineHookResponse>> {
+        self.runtime
+            .block_on(self.inner.execute(inline_hook_id, body))
+    }
+    /// The blocking version of [`crate::inline_hooks::InlineHooks::activate`].
+    pub fn activate(
+        &self,
+        inline_hook_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::InlineHook>> {
+        self.runtime.block_on(self.inner.activate(inline_hook_id))
+    }
+    /// The blocking version of [`crate::inline_hooks::InlineHooks::deactivate`].
+    pub fn deactivate(
+        &self,
+        inline_hook_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::InlineHook>> {
+        self.runtime.block_on(self.inner.deactivate(inline_hook_id))
+    }
+}
@@ -0,0 +1,51 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::linked_objects::LinkedObjects`].
+pub struct LinkedObjects {
+    inner: crate::linked_objects::LinkedObjects,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl LinkedObjects {
+    pub(crate) fn new(
+        inner: crate::linked_objects::LinkedObjects,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        LinkedObjects { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::linked_objects::LinkedObjects::list_definitions`].
+    pub fn list_definitions(
+        &self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::LinkedObject>>> {
+        self.runtime.block_on(self.inner.list_definitions())
+    }
+    /// The blocking version of [`crate::linked_objects::LinkedObjects::list_all_definitions`].
+    pub fn list_all_definitions(
+        &self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::LinkedObject>>> {
+        self.runtime.block_on(self.inner.list_all_definitions())
+    }
+    /// The blocking version of [`crate::linked_objects::LinkedObjects::add_definition`].
+    pub fn add_definition(
+        &self,
+        body: &crate::types::LinkedObject,
+    ) -> ClientResult<crate::Response<crate::types::LinkedObject>> {
+        self.runtime.block_on(self.inner.add_definition(body))
+    }
+    /// The blocking version of [`crate::linked_objects::LinkedObjects::get_definition`].
+    pub fn get_definition(
+        &self,
+        linked_object_name: &str,
+    ) -> ClientResult<crate::Response<crate::types::LinkedObject>> {
+        self.runtime
+            .block_on(self.inner.get_definition(linked_object_name))
+    }
+    /// The blocking version of [`crate::linked_objects::LinkedObjects::delete_definition`].
+    pub fn delete_definition(&self, linked_object_name: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete_definition(linked_object_name))
+    }
+}
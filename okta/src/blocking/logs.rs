@@ -0,0 +1,106 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Logs::get_page`], set with a builder.
+pub struct LogsGetPageRequest<'a> {
+    inner: crate::logs::LogsGetPageRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> LogsGetPageRequest<'a> {
+    /// Sets the `since` query parameter.
+    pub fn since(mut self, since: chrono::DateTime<chrono::Utc>) -> Self {
+        self.inner = self.inner.since(since);
+        self
+    }
+
+    /// Sets the `until` query parameter.
+    pub fn until(mut self, until: chrono::DateTime<chrono::Utc>) -> Self {
+        self.inner = self.inner.until(until);
+        self
+    }
+
+    /// Sets the `filter` query parameter.
+    pub fn filter(mut self, filter: &'a str) -> Self {
+        self.inner = self.inner.filter(filter);
+        self
+    }
+
+    /// Sets the `q` query parameter.
+    pub fn q(mut self, q: &'a str) -> Self {
+        self.inner = self.inner.q(q);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `sortOrder` query parameter.
+    pub fn sort_order(mut self, sort_order: &'a str) -> Self {
+        self.inner = self.inner.sort_order(sort_order);
+        self
+    }
+
+    /// Sets the `after` query parameter.
+    pub fn after(mut self, after: &'a str) -> Self {
+        self.inner = self.inner.after(after);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::LogEvent>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::logs::Logs`].
+pub struct Logs {
+    inner: crate::logs::Logs,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Logs {
+    pub(crate) fn new(inner: crate::logs::Logs, runtime: Arc<tokio::runtime::Runtime>) -> Self {
+        Logs { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::logs::Logs::get_page`].
+    pub fn get_page(
+        &self,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+        until: Option<chrono::DateTime<chrono::Utc>>,
+        filter: &str,
+        q: &str,
+        limit: i64,
+        sort_order: &str,
+        after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::LogEvent>>> {
+        self.runtime.block_on(
+            self.inner
+                .get_page(since, until, filter, q, limit, sort_order, after),
+        )
+    }
+    /// The blocking version of [`crate::logs::Logs::get_page_request`].
+    pub fn get_page_request(&self) -> LogsGetPageRequest<'_> {
+        LogsGetPageRequest {
+            inner: self.inner.get_page_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::logs::Logs::get_all`].
+    pub fn get_all(
+        &self,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+        until: Option<chrono::DateTime<chrono::Utc>>,
+        filter: &str,
+        q: &str,
+        sort_order: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::LogEvent>>> {
+        self.runtime
+            .block_on(self.inner.get_all(since, until, filter, q, sort_order))
+    }
+}
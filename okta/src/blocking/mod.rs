@@ -0,0 +1,172 @@
+//! A blocking client, for the programs that are not async.
+//!
+//! It has the functions of the async [`Client`](crate::Client), which it runs on a
+//! runtime of its own, so they must not be called from within an async runtime. The
+//! `*_stream` functions are left out, the `*_all` ones returning all the pages at once.
+
+use std::{future::Future, sync::Arc};
+
+pub mod applications;
+pub mod authorization_servers;
+pub mod domains;
+pub mod event_hooks;
+pub mod features;
+pub mod groups;
+pub mod identity_providers;
+pub mod inline_hooks;
+pub mod linked_objects;
+pub mod logs;
+pub mod network_zones;
+pub mod policies;
+pub mod profile_mappings;
+pub mod sessions;
+pub mod templates;
+pub mod threat_insights;
+pub mod trusted_origins;
+pub mod user_factors;
+pub mod user_schemas;
+pub mod user_types;
+pub mod users;
+
+/// A blocking client, sending its requests with an async [`Client`](crate::Client).
+#[derive(Clone)]
+pub struct Client {
+    inner: crate::Client,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Client {
+    /// Wraps an async client, creating the runtime its requests run on.
+    pub fn new(client: crate::Client) -> std::io::Result<Self> {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()?;
+
+        Ok(Client {
+            inner: client,
+            runtime: Arc::new(runtime),
+        })
+    }
+
+    /// Returns the async client the requests are sent with.
+    pub fn inner(&self) -> &crate::Client {
+        &self.inner
+    }
+
+    /// Runs a future to completion on the runtime of the client, for the async
+    /// functions that have no blocking version.
+    pub fn block_on<F: Future>(&self, future: F) -> F::Output {
+        self.runtime.block_on(future)
+    }
+
+    /// The blocking version of [`crate::Client::applications`].
+    pub fn applications(&self) -> applications::Applications {
+        applications::Applications::new(self.inner.applications(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::authorization_servers`].
+    pub fn authorization_servers(&self) -> authorization_servers::AuthorizationServers {
+        authorization_servers::AuthorizationServers::new(
+            self.inner.authorization_servers(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::domains`].
+    pub fn domains(&self) -> domains::Domains {
+        domains::Domains::new(self.inner.domains(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::event_hooks`].
+    pub fn event_hooks(&self) -> event_hooks::EventHooks {
+        event_hooks::EventHooks::new(self.inner.event_hooks(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::features`].
+    pub fn features(&self) -> features::Features {
+        features::Features::new(self.inner.features(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::groups`].
+    pub fn groups(&self) -> groups::Groups {
+        groups::Groups::new(self.inner.groups(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::identity_providers`].
+    pub fn identity_providers(&self) -> identity_providers::IdentityProviders {
+        identity_providers::IdentityProviders::new(
+            self.inner.identity_providers(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::inline_hooks`].
+    pub fn inline_hooks(&self) -> inline_hooks::InlineHooks {
+        inline_hooks::InlineHooks::new(self.inner.inline_hooks(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::linked_objects`].
+    pub fn linked_objects(&self) -> linked_objects::LinkedObjects {
+        linked_objects::LinkedObjects::new(self.inner.linked_objects(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::logs`].
+    pub fn logs(&self) -> logs::Logs {
+        logs::Logs::new(self.inner.logs(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::network_zones`].
+    pub fn network_zones(&self) -> network_zones::NetworkZones {
+        network_zones::NetworkZones::new(self.inner.network_zones(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::policies`].
+    pub fn policies(&self) -> policies::Policies {
+        policies::Policies::new(self.inner.policies(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::profile_mappings`].
+    pub fn profile_mappings(&self) -> profile_mappings::ProfileMappings {
+        profile_mappings::ProfileMappings::new(self.inner.profile_mappings(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::sessions`].
+    pub fn sessions(&self) -> sessions::Sessions {
+        sessions::Sessions::new(self.inner.sessions(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::templates`].
+    pub fn templates(&self) -> templates::Templates {
+        templates::Templates::new(self.inner.templates(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::threat_insights`].
+    pub fn threat_insights(&self) -> threat_insights::ThreatInsights {
+        threat_insights::ThreatInsights::new(self.inner.threat_insights(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::trusted_origins`].
+    pub fn trusted_origins(&self) -> trusted_origins::TrustedOrigins {
+        trusted_origins::TrustedOrigins::new(self.inner.trusted_origins(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::user_factors`].
+    pub fn user_factors(&self) -> user_factors::UserFactors {
+        user_factors::UserFactors::new(self.inner.user_factors(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::user_schemas`].
+    pub fn user_schemas(&self) -> user_schemas::UserSchemas {
+        user_schemas::UserSchemas::new(self.inner.user_schemas(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::user_types`].
+    pub fn user_types(&self) -> user_types::UserTypes {
+        user_types::UserTypes::new(self.inner.user_types(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::users`].
+    pub fn users(&self) -> users::Users {
+        users::Users::new(self.inner.users(), self.runtime.clone())
+    }
+}
@@ -0,0 +1,110 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`NetworkZones::list`], set with a builder.
+pub struct NetworkZonesListRequest<'a> {
+    inner: crate::network_zones::NetworkZonesListRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> NetworkZonesListRequest<'a> {
+    /// Sets the `after` query parameter.
+    pub fn after(mut self, after: &'a str) -> Self {
+        self.inner = self.inner.after(after);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `filter` query parameter.
+    pub fn filter(mut self, filter: &'a str) -> Self {
+        self.inner = self.inner.filter(filter);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::NetworkZone>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::network_zones::NetworkZones`].
+pub struct NetworkZones {
+    inner: crate::network_zones::NetworkZones,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl NetworkZones {
+    pub(crate) fn new(
+        inner: crate::network_zones::NetworkZones,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        NetworkZones { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::network_zones::NetworkZones::list`].
+    pub fn list(
+        &self,
+        after: &str,
+        limit: i64,
+        filter: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::NetworkZone>>> {
+        self.runtime.block_on(self.inner.list(after, limit, filter))
+    }
+    /// The blocking version of [`crate::network_zones::NetworkZones::list_request`].
+    pub fn list_request(&self) -> NetworkZonesListRequest<'_> {
+        NetworkZonesListRequest {
+            inner: self.inner.list_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::network_zones::NetworkZones::list_all`].
+    pub fn list_all(
+        &self,
+        filter: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::NetworkZone>>> {
+        self.runtime.block_on(self.inner.list_all(filter))
+    }
+    /// The blocking version of [`crate::network_zones::NetworkZones::create`].
+    pub fn create(
+        &self,
+        body: &crate::types::NetworkZone,
+    ) -> ClientResult<crate::Response<crate::types::NetworkZone>> {
+        self.runtime.block_on(self.inner.create(body))
+    }
+    /// The blocking version of [`crate::network_zones::NetworkZones::get`].
+    pub fn get(&self, zone_id: &str) -> ClientResult<crate::Response<crate::types::NetworkZone>> {
+        self.runtime.block_on(self.inner.get(zone_id))
+    }
+    /// The blocking version of [`crate::network_zones::NetworkZones::update`].
+    pub fn update(
+        &self,
+        zone_id: &str,
+        body: &crate::types::NetworkZone,
+    ) -> ClientResult<crate::Response<crate::types::NetworkZone>> {
+        self.runtime.block_on(self.inner.update(zone_id, body))
+    }
+    /// The blocking version of [`crate::network_zones::NetworkZones::delete`].
+    pub fn delete(&self, zone_id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.delete(zone_id))
+    }
+    /// The blocking version of [`crate::network_zones::NetworkZones::activate`].
+    pub fn activate(
+        &self,
+        zone_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::NetworkZone>> {
+        self.runtime.block_on(self.inner.activate(zone_id))
+    }
+    /// The blocking version of [`crate::network_zones::NetworkZones::deactivate`].
+    pub fn deactivate(
+        &self,
+        zone_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::NetworkZone>> {
+        self.runtime.block_on(self.inner.deactivate(zone_id))
+    }
+}
@@ -0,0 +1,237 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Policies::list`], set with a builder.
+pub struct PoliciesListRequest<'a> {
+    inner: crate::policies::PoliciesListRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> PoliciesListRequest<'a> {
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: &'a str) -> Self {
+        self.inner = self.inner.status(status);
+        self
+    }
+
+    /// Sets the `expand` query parameter.
+    pub fn expand(mut self, expand: &'a str) -> Self {
+        self.inner = self.inner.expand(expand);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Policy>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Policies::create_policy`], set with a builder.
+pub struct PoliciesCreatePolicyRequest<'a> {
+    inner: crate::policies::PoliciesCreatePolicyRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> PoliciesCreatePolicyRequest<'a> {
+    /// Sets the `activate` query parameter.
+    pub fn activate(mut self, activate: bool) -> Self {
+        self.inner = self.inner.activate(activate);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::Policy>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Policies::get_policy`], set with a builder.
+pub struct PoliciesGetPolicyRequest<'a> {
+    inner: crate::policies::PoliciesGetPolicyRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> PoliciesGetPolicyRequest<'a> {
+    /// Sets the `expand` query parameter.
+    pub fn expand(mut self, expand: &'a str) -> Self {
+        self.inner = self.inner.expand(expand);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::Policy>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::policies::Policies`].
+pub struct Policies {
+    inner: crate::policies::Policies,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Policies {
+    pub(crate) fn new(
+        inner: crate::policies::Policies,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Policies { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::policies::Policies::list`].
+    pub fn list(
+        &self,
+        type_: &str,
+        status: &str,
+        expand: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Policy>>> {
+        self.runtime
+            .block_on(self.inner.list(type_, status, expand))
+    }
+    /// The blocking version of [`crate::policies::Policies::list_request`].
+    pub fn list_request<'a>(&'a self, type_: &'a str) -> PoliciesListRequest<'a> {
+        PoliciesListRequest {
+            inner: self.inner.list_request(type_),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::policies::Policies::list_all`].
+    pub fn list_all(
+        &self,
+        type_: &str,
+        status: &str,
+        expand: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Policy>>> {
+        self.runtime
+            .block_on(self.inner.list_all(type_, status, expand))
+    }
+    /// The blocking version of [`crate::policies::Policies::create_policy`].
+    pub fn create_policy(
+        &self,
+        activate: bool,
+        body: &crate::types::Policy,
+    ) -> ClientResult<crate::Response<crate::types::Policy>> {
+        self.runtime
+            .block_on(self.inner.create_policy(activate, body))
+    }
+    /// The blocking version of [`crate::policies::Policies::create_policy_request`].
+    pub fn create_policy_request<'a>(
+        &'a self,
+        body: &'a crate::types::Policy,
+    ) -> PoliciesCreatePolicyRequest<'a> {
+        PoliciesCreatePolicyRequest {
+            inner: self.inner.create_policy_request(body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::policies::Policies::get_policy`].
+    pub fn get_policy(
+        &self,
+        policy_id: &str,
+        expand: &str,
+    ) -> ClientResult<crate::Response<crate::types::Policy>> {
+        self.runtime
+            .block_on(self.inner.get_policy(policy_id, expand))
+    }
+    /// The blocking version of [`crate::policies::Policies::get_policy_request`].
+    pub fn get_policy_request<'a>(&'a self, policy_id: &'a str) -> PoliciesGetPolicyRequest<'a> {
+        PoliciesGetPolicyRequest {
+            inner: self.inner.get_policy_request(policy_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::policies::Policies::update_policy`].
+    pub fn update_policy(
+        &self,
+        policy_id: &str,
+        body: &crate::types::Policy,
+    ) -> ClientResult<crate::Response<crate::types::Policy>> {
+        self.runtime
+            .block_on(self.inner.update_policy(policy_id, body))
+    }
+    /// The blocking version of [`crate::policies::Policies::delete_policy`].
+    pub fn delete_policy(&self, policy_id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.delete_policy(policy_id))
+    }
+    /// The blocking version of [`crate::policies::Policies::activate_policy`].
+    pub fn activate_policy(&self, policy_id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.activate_policy(policy_id))
+    }
+    /// The blocking version of [`crate::policies::Policies::deactivate_policy`].
+    pub fn deactivate_policy(&self, policy_id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deactivate_policy(policy_id))
+    }
+    /// The blocking version of [`crate::policies::Policies::list_policy_rules`].
+    pub fn list_policy_rules(
+        &self,
+        policy_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::PolicyRule>>> {
+        self.runtime
+            .block_on(self.inner.list_policy_rules(policy_id))
+    }
+    /// The blocking version of [`crate::policies::Policies::list_all_policy_rules`].
+    pub fn list_all_policy_rules(
+        &self,
+        policy_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::PolicyRule>>> {
+        self.runtime
+            .block_on(self.inner.list_all_policy_rules(policy_id))
+    }
+    /// The blocking version of [`crate::policies::Policies::create_policy_rule`].
+    pub fn create_policy_rule(
+        &self,
+        policy_id: &str,
+        body: &crate::types::PolicyRule,
+    ) -> ClientResult<crate::Response<crate::types::PolicyRule>> {
+        self.runtime
+            .block_on(self.inner.create_policy_rule(policy_id, body))
+    }
+    /// The blocking version of [`crate::policies::Policies::get_policy_rule`].
+    pub fn get_policy_rule(
+        &self,
+        policy_id: &str,
+        rule_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::PolicyRule>> {
+        self.runtime
+            .block_on(self.inner.get_policy_rule(policy_id, rule_id))
+    }
+    /// The blocking version of [`crate::policies::Policies::update_policy_rule`].
+    pub fn update_policy_rule(
+        &self,
+        policy_id: &str,
+        rule_id: &str,
+        body: &crate::types::PolicyRule,
+    ) -> ClientResult<crate::Response<crate::types::PolicyRule>> {
+        self.runtime
+            .block_on(self.inner.update_policy_rule(policy_id, rule_id, body))
+    }
+    /// The blocking version of [`crate::policies::Policies::delete_policy_rule`].
+    pub fn delete_policy_rule(
+        &self,
+        policy_id: &str,
+        rule_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete_policy_rule(policy_id, rule_id))
+    }
+    /// The blocking version of [`crate::policies::Policies::activate_policy_rule`].
+    pub fn activate_policy_rule(
+        &self,
+        policy_id: &str,
+        rule_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.activate_policy_rule(policy_id, rule_id))
+    }
+    /// The blocking version of [`crate::policies::Policies::deactivate_policy_rule`].
+    pub fn deactivate_policy_rule(
+        &self,
+        policy_id: &str,
+        rule_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deactivate_policy_rule(policy_id, rule_id))
+    }
+}
@@ -0,0 +1,98 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`ProfileMappings::list`], set with a builder.
+pub struct ProfileMappingsListRequest<'a> {
+    inner: crate::profile_mappings::ProfileMappingsListRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProfileMappingsListRequest<'a> {
+    /// Sets the `after` query parameter.
+    pub fn after(mut self, after: &'a str) -> Self {
+        self.inner = self.inner.after(after);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `sourceId` query parameter.
+    pub fn source_id(mut self, source_id: &'a str) -> Self {
+        self.inner = self.inner.source_id(source_id);
+        self
+    }
+
+    /// Sets the `targetId` query parameter.
+    pub fn target_id(mut self, target_id: &'a str) -> Self {
+        self.inner = self.inner.target_id(target_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::ProfileMapping>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::profile_mappings::ProfileMappings`].
+pub struct ProfileMappings {
+    inner: crate::profile_mappings::ProfileMappings,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl ProfileMappings {
+    pub(crate) fn new(
+        inner: crate::profile_mappings::ProfileMappings,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        ProfileMappings { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::profile_mappings::ProfileMappings::list`].
+    pub fn list(
+        &self,
+        after: &str,
+        limit: i64,
+        source_id: &str,
+        target_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ProfileMapping>>> {
+        self.runtime
+            .block_on(self.inner.list(after, limit, source_id, target_id))
+    }
+    /// The blocking version of [`crate::profile_mappings::ProfileMappings::list_request`].
+    pub fn list_request(&self) -> ProfileMappingsListRequest<'_> {
+        ProfileMappingsListRequest {
+            inner: self.inner.list_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::profile_mappings::ProfileMappings::list_all`].
+    pub fn list_all(
+        &self,
+        source_id: &str,
+        target_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ProfileMapping>>> {
+        self.runtime
+            .block_on(self.inner.list_all(source_id, target_id))
+    }
+    /// The blocking version of [`crate::profile_mappings::ProfileMappings::get`].
+    pub fn get(
+        &self,
+        mapping_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ProfileMapping>> {
+        self.runtime.block_on(self.inner.get(mapping_id))
+    }
+    /// The blocking version of [`crate::profile_mappings::ProfileMappings::update`].
+    pub fn update(
+        &self,
+        mapping_id: &str,
+        body: &crate::types::ProfileMapping,
+    ) -> ClientResult<crate::Response<crate::types::ProfileMapping>> {
+        self.runtime.block_on(self.inner.update(mapping_id, body))
+    }
+}
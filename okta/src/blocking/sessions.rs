@@ -0,0 +1,41 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::sessions::Sessions`].
+pub struct Sessions {
+    inner: crate::sessions::Sessions,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Sessions {
+    pub(crate) fn new(
+        inner: crate::sessions::Sessions,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Sessions { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::sessions::Sessions::create`].
+    pub fn create(
+        &self,
+        body: &crate::types::CreateSessionRequest,
+    ) -> ClientResult<crate::Response<crate::types::Session>> {
+        self.runtime.block_on(self.inner.create(body))
+    }
+    /// The blocking version of [`crate::sessions::Sessions::get`].
+    pub fn get(&self, session_id: &str) -> ClientResult<crate::Response<crate::types::Session>> {
+        self.runtime.block_on(self.inner.get(session_id))
+    }
+    /// The blocking version of [`crate::sessions::Sessions::end`].
+    pub fn end(&self, session_id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.end(session_id))
+    }
+    /// The blocking version of [`crate::sessions::Sessions::refresh`].
+    pub fn refresh(
+        &self,
+        session_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Session>> {
+        self.runtime.block_on(self.inner.refresh(session_id))
+    }
+}
@@ -0,0 +1,96 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Templates::list_sms`], set with a builder.
+pub struct TemplatesListSmsRequest<'a> {
+    inner: crate::templates::TemplatesListSmsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> TemplatesListSmsRequest<'a> {
+    /// Sets the `templateType` query parameter.
+    pub fn template_type(mut self, template_type: &'a str) -> Self {
+        self.inner = self.inner.template_type(template_type);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::SmsTemplate>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::templates::Templates`].
+pub struct Templates {
+    inner: crate::templates::Templates,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Templates {
+    pub(crate) fn new(
+        inner: crate::templates::Templates,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Templates { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::templates::Templates::list_sms`].
+    pub fn list_sms(
+        &self,
+        template_type: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::SmsTemplate>>> {
+        self.runtime.block_on(self.inner.list_sms(template_type))
+    }
+    /// The blocking version of [`crate::templates::Templates::list_sms_request`].
+    pub fn list_sms_request(&self) -> TemplatesListSmsRequest<'_> {
+        TemplatesListSmsRequest {
+            inner: self.inner.list_sms_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::templates::Templates::list_all_sms`].
+    pub fn list_all_sms(
+        &self,
+        template_type: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::SmsTemplate>>> {
+        self.runtime
+            .block_on(self.inner.list_all_sms(template_type))
+    }
+    /// The blocking version of [`crate::templates::Templates::create_sms`].
+    pub fn create_sms(
+        &self,
+        body: &crate::types::SmsTemplate,
+    ) -> ClientResult<crate::Response<crate::types::SmsTemplate>> {
+        self.runtime.block_on(self.inner.create_sms(body))
+    }
+    /// The blocking version of [`crate::templates::Templates::get_sm`].
+    pub fn get_sm(
+        &self,
+        template_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::SmsTemplate>> {
+        self.runtime.block_on(self.inner.get_sm(template_id))
+    }
+    /// The blocking version of [`crate::templates::Templates::update_sms`].
+    pub fn update_sms(
+        &self,
+        template_id: &str,
+        body: &crate::types::SmsTemplate,
+    ) -> ClientResult<crate::Response<crate::types::SmsTemplate>> {
+        self.runtime
+            .block_on(self.inner.update_sms(template_id, body))
+    }
+    /// The blocking version of [`crate::templates::Templates::partial_update_sms`].
+    pub fn partial_update_sms(
+        &self,
+        template_id: &str,
+        body: &crate::types::SmsTemplate,
+    ) -> ClientResult<crate::Response<crate::types::SmsTemplate>> {
+        self.runtime
+            .block_on(self.inner.partial_update_sms(template_id, body))
+    }
+    /// The blocking version of [`crate::templates::Templates::delete_sms`].
+    pub fn delete_sms(&self, template_id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.delete_sms(template_id))
+    }
+}
@@ -0,0 +1,33 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::threat_insights::ThreatInsights`].
+pub struct ThreatInsights {
+    inner: crate::threat_insights::ThreatInsights,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl ThreatInsights {
+    pub(crate) fn new(
+        inner: crate::threat_insights::ThreatInsights,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        ThreatInsights { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::threat_insights::ThreatInsights::get_current_configuration`].
+    pub fn get_current_configuration(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::ThreatInsightConfiguration>> {
+        self.runtime
+            .block_on(self.inner.get_current_configuration())
+    }
+    /// The blocking version of [`crate::threat_insights::ThreatInsights::update_configuration`].
+    pub fn update_configuration(
+        &self,
+        body: &crate::types::ThreatInsightConfiguration,
+    ) -> ClientResult<crate::Response<crate::types::ThreatInsightConfiguration>> {
+        self.runtime.block_on(self.inner.update_configuration(body))
+    }
+}
@@ -0,0 +1,128 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`TrustedOrigins::list_origins`], set with a builder.
+pub struct TrustedOriginsListOriginsRequest<'a> {
+    inner: crate::trusted_origins::TrustedOriginsListOriginsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> TrustedOriginsListOriginsRequest<'a> {
+    /// Sets the `q` query parameter.
+    pub fn q(mut self, q: &'a str) -> Self {
+        self.inner = self.inner.q(q);
+        self
+    }
+
+    /// Sets the `filter` query parameter.
+    pub fn filter(mut self, filter: &'a str) -> Self {
+        self.inner = self.inner.filter(filter);
+        self
+    }
+
+    /// Sets the `after` query parameter.
+    pub fn after(mut self, after: &'a str) -> Self {
+        self.inner = self.inner.after(after);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::TrustedOrigin>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::trusted_origins::TrustedOrigins`].
+pub struct TrustedOrigins {
+    inner: crate::trusted_origins::TrustedOrigins,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl TrustedOrigins {
+    pub(crate) fn new(
+        inner: crate::trusted_origins::TrustedOrigins,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        TrustedOrigins { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::trusted_origins::TrustedOrigins::list_origins`].
+    pub fn list_origins(
+        &self,
+        q: &str,
+        filter: &str,
+        after: &str,
+        limit: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::TrustedOrigin>>> {
+        self.runtime
+            .block_on(self.inner.list_origins(q, filter, after, limit))
+    }
+    /// The blocking version of [`crate::trusted_origins::TrustedOrigins::list_origins_request`].
+    pub fn list_origins_request(&self) -> TrustedOriginsListOriginsRequest<'_> {
+        TrustedOriginsListOriginsRequest {
+            inner: self.inner.list_origins_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::trusted_origins::TrustedOrigins::list_all_origins`].
+    pub fn list_all_origins(
+        &self,
+        q: &str,
+        filter: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::TrustedOrigin>>> {
+        self.runtime
+            .block_on(self.inner.list_all_origins(q, filter))
+    }
+    /// The blocking version of [`crate::trusted_origins::TrustedOrigins::create_origin`].
+    pub fn create_origin(
+        &self,
+        body: &crate::types::TrustedOrigin,
+    ) -> ClientResult<crate::Response<crate::types::TrustedOrigin>> {
+        self.runtime.block_on(self.inner.create_origin(body))
+    }
+    /// The blocking version of [`crate::trusted_origins::TrustedOrigins::get_origin`].
+    pub fn get_origin(
+        &self,
+        trusted_origin_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::TrustedOrigin>> {
+        self.runtime
+            .block_on(self.inner.get_origin(trusted_origin_id))
+    }
+    /// The blocking version of [`crate::trusted_origins::TrustedOrigins::update_origin`].
+    pub fn update_origin(
+        &self,
+        trusted_origin_id: &str,
+        body: &crate::types::TrustedOrigin,
+    ) -> ClientResult<crate::Response<crate::types::TrustedOrigin>> {
+        self.runtime
+            .block_on(self.inner.update_origin(trusted_origin_id, body))
+    }
+    /// The blocking version of [`crate::trusted_origins::TrustedOrigins::delete_origin`].
+    pub fn delete_origin(&self, trusted_origin_id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete_origin(trusted_origin_id))
+    }
+    /// The blocking version of [`crate::trusted_origins::TrustedOrigins::activate_origin`].
+    pub fn activate_origin(
+        &self,
+        trusted_origin_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::TrustedOrigin>> {
+        self.runtime
+            .block_on(self.inner.activate_origin(trusted_origin_id))
+    }
+    /// The blocking version of [`crate::trusted_origins::TrustedOrigins::deactivate_origin`].
+    pub fn deactivate_origin(
+        &self,
+        trusted_origin_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::TrustedOrigin>> {
+        self.runtime
+            .block_on(self.inner.deactivate_origin(trusted_origin_id))
+    }
+}
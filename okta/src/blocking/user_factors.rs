@@ -0,0 +1,227 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`UserFactors::enroll_factor`], set with a builder.
+pub struct UserFactorsEnrollFactorRequest<'a> {
+    inner: crate::user_factors::UserFactorsEnrollFactorRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> UserFactorsEnrollFactorRequest<'a> {
+    /// Sets the `updatePhone` query parameter.
+    pub fn update_phone(mut self, update_phone: bool) -> Self {
+        self.inner = self.inner.update_phone(update_phone);
+        self
+    }
+
+    /// Sets the `templateId` query parameter.
+    pub fn template_id(mut self, template_id: &'a str) -> Self {
+        self.inner = self.inner.template_id(template_id);
+        self
+    }
+
+    /// Sets the `tokenLifetimeSeconds` query parameter.
+    pub fn token_lifetime_seconds(mut self, token_lifetime_seconds: i64) -> Self {
+        self.inner = self.inner.token_lifetime_seconds(token_lifetime_seconds);
+        self
+    }
+
+    /// Sets the `activate` query parameter.
+    pub fn activate(mut self, activate: bool) -> Self {
+        self.inner = self.inner.activate(activate);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::UserFactor>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`UserFactors::verify_factor`], set with a builder.
+pub struct UserFactorsVerifyFactorRequest<'a> {
+    inner: crate::user_factors::UserFactorsVerifyFactorRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> UserFactorsVerifyFactorRequest<'a> {
+    /// Sets the `templateId` query parameter.
+    pub fn template_id(mut self, template_id: &'a str) -> Self {
+        self.inner = self.inner.template_id(template_id);
+        self
+    }
+
+    /// Sets the `tokenLifetimeSeconds` query parameter.
+    pub fn token_lifetime_seconds(mut self, token_lifetime_seconds: i64) -> Self {
+        self.inner = self.inner.token_lifetime_seconds(token_lifetime_seconds);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::VerifyUserFactorResponse>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::user_factors::UserFactors`].
+pub struct UserFactors {
+    inner: crate::user_factors::UserFactors,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl UserFactors {
+    pub(crate) fn new(
+        inner: crate::user_factors::UserFactors,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        UserFactors { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::user_factors::UserFactors::list_factors`].
+    pub fn list_factors(
+        &self,
+        user_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::UserFactor>>> {
+        self.runtime.block_on(self.inner.list_factors(user_id))
+    }
+    /// The blocking version of [`crate::user_factors::UserFactors::list_all_factors`].
+    pub fn list_all_factors(
+        &self,
+        user_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::UserFactor>>> {
+        self.runtime.block_on(self.inner.list_all_factors(user_id))
+    }
+    /// The blocking version of [`crate::user_factors::UserFactors::enroll_factor`].
+    pub fn enroll_factor(
+        &self,
+        user_id: &str,
+        update_phone: bool,
+        template_id: &str,
+        token_lifetime_seconds: i64,
+        activate: bool,
+        body: &crate::types::UserFactor,
+    ) -> ClientResult<crate::Response<crate::types::UserFactor>> {
+        self.runtime.block_on(self.inner.enroll_factor(
+            user_id,
+            update_phone,
+            template_id,
+            token_lifetime_seconds,
+            activate,
+            body,
+        ))
+    }
+    /// The blocking version of [`crate::user_factors::UserFactors::enroll_factor_request`].
+    pub fn enroll_factor_request<'a>(
+        &'a self,
+        user_id: &'a str,
+        body: &'a crate::types::UserFactor,
+    ) -> UserFactorsEnrollFactorRequest<'a> {
+        UserFactorsEnrollFactorRequest {
+            inner: self.inner.enroll_factor_request(user_id, body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::user_factors::UserFactors::list_supported_factors`].
+    pub fn list_supported_factors(
+        &self,
+        user_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::UserFactor>>> {
+        self.runtime
+            .block_on(self.inner.list_supported_factors(user_id))
+    }
+    /// The blocking version of [`crate::user_factors::UserFactors::list_all_supported_factors`].
+    pub fn list_all_supported_factors(
+        &self,
+        user_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::UserFactor>>> {
+        self.runtime
+            .block_on(self.inner.list_all_supported_factors(user_id))
+    }
+    /// The blocking version of [`crate::user_factors::UserFactors::list_supported_security_questions`].
+    pub fn list_supported_security_questions(
+        &self,
+        user_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::SecurityQuestion>>> {
+        self.runtime
+            .block_on(self.inner.list_supported_security_questions(user_id))
+    }
+    /// The blocking version of [`crate::user_factors::UserFactors::list_all_supported_security_questions`].
+    pub fn list_all_supported_security_questions(
+        &self,
+        user_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::SecurityQuestion>>> {
+        self.runtime
+            .block_on(self.inner.list_all_supported_security_questions(user_id))
+    }
+    /// The blocking version of [`crate::user_factors::UserFactors::get_factor`].
+    pub fn get_factor(
+        &self,
+        user_id: &str,
+        factor_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::UserFactor>> {
+        self.runtime
+            .block_on(self.inner.get_factor(user_id, factor_id))
+    }
+    /// The blocking version of [`crate::user_factors::UserFactors::delete_factor`].
+    pub fn delete_factor(
+        &self,
+        user_id: &str,
+        factor_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete_factor(user_id, factor_id))
+    }
+    /// The blocking version of [`crate::user_factors::UserFactors::activate_factor`].
+    pub fn activate_factor(
+        &self,
+        user_id: &str,
+        factor_id: &str,
+        body: &crate::types::ActivateFactorRequest,
+    ) -> ClientResult<crate::Response<crate::types::UserFactor>> {
+        self.runtime
+            .block_on(self.inner.activate_factor(user_id, factor_id, body))
+    }
+    /// The blocking version of [`crate::user_factors::UserFactors::get_factor_transaction_status`].
+    pub fn get_factor_transaction_status(
+        &self,
+        user_id: &str,
+        factor_id: &str,
+        transaction_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::VerifyUserFactorResponse>> {
+        self.runtime
+            .block_on(
+                self.inner
+                    .get_factor_transaction_status(user_id, factor_id, transaction_id),
+            )
+    }
+    /// The blocking version of [`crate::user_factors::UserFactors::verify_factor`].
+    pub fn verify_factor(
+        &self,
+        user_id: &str,
+        factor_id: &str,
+        template_id: &str,
+        token_lifetime_seconds: i64,
+        body: &crate::types::VerifyFactorRequest,
+    ) -> ClientResult<crate::Response<crate::types::VerifyUserFactorResponse>> {
+        self.runtime.block_on(self.inner.verify_factor(
+            user_id,
+            factor_id,
+            template_id,
+            token_lifetime_seconds,
+            body,
+        ))
+    }
+    /// The blocking version of [`crate::user_factors::UserFactors::verify_factor_request`].
+    pub fn verify_factor_request<'a>(
+        &'a self,
+        user_id: &'a str,
+        factor_id: &'a str,
+        body: &'a crate::types::VerifyFactorRequest,
+    ) -> UserFactorsVerifyFactorRequest<'a> {
+        UserFactorsVerifyFactorRequest {
+            inner: self.inner.verify_factor_request(user_id, factor_id, body),
+            runtime: &self.runtime,
+        }
+    }
+}
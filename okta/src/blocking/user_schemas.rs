@@ -0,0 +1,51 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::user_schemas::UserSchemas`].
+pub struct UserSchemas {
+    inner: crate::user_schemas::UserSchemas,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl UserSchemas {
+    pub(crate) fn new(
+        inner: crate::user_schemas::UserSchemas,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        UserSchemas { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::user_schemas::UserSchemas::get_application`].
+    pub fn get_application(
+        &self,
+        app_instance_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::UserSchema>> {
+        self.runtime
+            .block_on(self.inner.get_application(app_instance_id))
+    }
+    /// The blocking version of [`crate::user_schemas::UserSchemas::update_application_user_profile`].
+    pub fn update_application_user_profile(
+        &self,
+        app_instance_id: &str,
+        body: &crate::types::UserSchema,
+    ) -> ClientResult<crate::Response<crate::types::UserSchema>> {
+        self.runtime.block_on(
+            self.inner
+                .update_application_user_profile(app_instance_id, body),
+        )
+    }
+    /// The blocking version of [`crate::user_schemas::UserSchemas::get`].
+    pub fn get(&self, schema_id: &str) -> ClientResult<crate::Response<crate::types::UserSchema>> {
+        self.runtime.block_on(self.inner.get(schema_id))
+    }
+    /// The blocking version of [`crate::user_schemas::UserSchemas::update_user_profile`].
+    pub fn update_user_profile(
+        &self,
+        schema_id: &str,
+        body: &crate::types::UserSchema,
+    ) -> ClientResult<crate::Response<crate::types::UserSchema>> {
+        self.runtime
+            .block_on(self.inner.update_user_profile(schema_id, body))
+    }
+}
@@ -0,0 +1,58 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::user_types::UserTypes`].
+pub struct UserTypes {
+    inner: crate::user_types::UserTypes,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl UserTypes {
+    pub(crate) fn new(
+        inner: crate::user_types::UserTypes,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        UserTypes { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::user_types::UserTypes::list`].
+    pub fn list(&self) -> ClientResult<crate::Response<Vec<crate::types::UserType>>> {
+        self.runtime.block_on(self.inner.list())
+    }
+    /// The blocking version of [`crate::user_types::UserTypes::list_all`].
+    pub fn list_all(&self) -> ClientResult<crate::Response<Vec<crate::types::UserType>>> {
+        self.runtime.block_on(self.inner.list_all())
+    }
+    /// The blocking version of [`crate::user_types::UserTypes::create`].
+    pub fn create(
+        &self,
+        body: &crate::types::UserType,
+    ) -> ClientResult<crate::Response<crate::types::UserType>> {
+        self.runtime.block_on(self.inner.create(body))
+    }
+    /// The blocking version of [`crate::user_types::UserTypes::get`].
+    pub fn get(&self, type_id: &str) -> ClientResult<crate::Response<crate::types::UserType>> {
+        self.runtime.block_on(self.inner.get(type_id))
+    }
+    /// The blocking version of [`crate::user_types::UserTypes::replace`].
+    pub fn replace(
+        &self,
+        type_id: &str,
+        body: &crate::types::UserType,
+    ) -> ClientResult<crate::Response<crate::types::UserType>> {
+        self.runtime.block_on(self.inner.replace(type_id, body))
+    }
+    /// The blocking version of [`crate::user_types::UserTypes::update`].
+    pub fn update(
+        &self,
+        type_id: &str,
+        body: &crate::types::UserType,
+    ) -> ClientResult<crate::Response<crate::types::UserType>> {
+        self.runtime.block_on(self.inner.update(type_id, body))
+    }
+    /// The blocking version of [`crate::user_types::UserTypes::delete`].
+    pub fn delete(&self, type_id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.delete(type_id))
+    }
+}
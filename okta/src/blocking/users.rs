@@ -0,0 +1,1291 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Users::list`], set with a builder.
+pub struct UsersListRequest<'a> {
+    inner: crate::users::UsersListRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> UsersListRequest<'a> {
+    /// Sets the `q` query parameter.
+    pub fn q(mut self, q: &'a str) -> Self {
+        self.inner = self.inner.q(q);
+        self
+    }
+
+    /// Sets the `after` query parameter.
+    pub fn after(mut self, after: &'a str) -> Self {
+        self.inner = self.inner.after(after);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `filter` query parameter.
+    pub fn filter(mut self, filter: &'a str) -> Self {
+        self.inner = self.inner.filter(filter);
+        self
+    }
+
+    /// Sets the `search` query parameter.
+    pub fn search(mut self, search: &'a str) -> Self {
+        self.inner = self.inner.search(search);
+        self
+    }
+
+    /// Sets the `sortBy` query parameter.
+    pub fn sort_by(mut self, sort_by: &'a str) -> Self {
+        self.inner = self.inner.sort_by(sort_by);
+        self
+    }
+
+    /// Sets the `sortOrder` query parameter.
+    pub fn sort_order(mut self, sort_order: &'a str) -> Self {
+        self.inner = self.inner.sort_order(sort_order);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::User>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Users::create`], set with a builder.
+pub struct UsersCreateRequest<'a> {
+    inner: crate::users::UsersCreateRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> UsersCreateRequest<'a> {
+    /// Sets the `activate` query parameter.
+    pub fn activate(mut self, activate: bool) -> Self {
+        self.inner = self.inner.activate(activate);
+        self
+    }
+
+    /// Sets the `provider` query parameter.
+    pub fn provider(mut self, provider: bool) -> Self {
+        self.inner = self.inner.provider(provider);
+        self
+    }
+
+    /// Sets the `nextLogin` query parameter.
+    pub fn next_login(mut self, next_login: &'a str) -> Self {
+        self.inner = self.inner.next_login(next_login);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::User>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Users::update`], set with a builder.
+pub struct UsersUpdateRequest<'a> {
+    inner: crate::users::UsersUpdateRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> UsersUpdateRequest<'a> {
+    /// Sets the `strict` query parameter.
+    pub fn strict(mut self, strict: bool) -> Self {
+        self.inner = self.inner.strict(strict);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::User>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Users::partial_update`], set with a builder.
+pub struct UsersPartialUpdateRequest<'a> {
+    inner: crate::users::UsersPartialUpdateRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> UsersPartialUpdateRequest<'a> {
+    /// Sets the `strict` query parameter.
+    pub fn strict(mut self, strict: bool) -> Self {
+        self.inner = self.inner.strict(strict);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::User>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Users::deactivate_or_delete`], set with a builder.
+pub struct UsersDeactivateOrDeleteRequest<'a> {
+    inner: crate::users::UsersDeactivateOrDeleteRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> UsersDeactivateOrDeleteRequest<'a> {
+    /// Sets the `sendEmail` query parameter.
+    pub fn send_email(mut self, send_email: bool) -> Self {
+        self.inner = self.inner.send_email(send_email);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Users::list_grants_for_and_clients`], set with a builder.
+pub struct UsersListGrantsForAndClientsRequest<'a> {
+    inner: crate::users::UsersListGrantsForAndClientsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> UsersListGrantsForAndClientsRequest<'a> {
+    /// Sets the `expand` query parameter.
+    pub fn expand(mut self, expand: &'a str) -> Self {
+        self.inner = self.inner.expand(expand);
+        self
+    }
+
+    /// Sets the `after` query parameter.
+    pub fn after(mut self, after: &'a str) -> Self {
+        self.inner = self.inner.after(after);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::OAuth2ScopeConsentGrant>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Users::list_refresh_tokens_for_and_clients`], set with a builder.
+pub struct UsersListRefreshTokensForAndClientsRequest<'a> {
+    inner: crate::users::UsersListRefreshTokensForAndClientsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> UsersListRefreshTokensForAndClientsRequest<'a> {
+    /// Sets the `expand` query parameter.
+    pub fn expand(mut self, expand: &'a str) -> Self {
+        self.inner = self.inner.expand(expand);
+        self
+    }
+
+    /// Sets the `after` query parameter.
+    pub fn after(mut self, after: &'a str) -> Self {
+        self.inner = self.inner.after(after);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::OAuth2RefreshToken>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Users::get_refresh_token_for_and_client`], set with a builder.
+pub struct UsersGetRefreshTokenForAndClientRequest<'a> {
+    inner: crate::users::UsersGetRefreshTokenForAndClientRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> UsersGetRefreshTokenForAndClientRequest<'a> {
+    /// Sets the `expand` query parameter.
+    pub fn expand(mut self, expand: &'a str) -> Self {
+        self.inner = self.inner.expand(expand);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `after` query parameter.
+    pub fn after(mut self, after: &'a str) -> Self {
+        self.inner = self.inner.after(after);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::OAuth2RefreshToken>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Users::change_password`], set with a builder.
+pub struct UsersChangePasswordRequest<'a> {
+    inner: crate::users::UsersChangePasswordRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> UsersChangePasswordRequest<'a> {
+    /// Sets the `strict` query parameter.
+    pub fn strict(mut self, strict: bool) -> Self {
+        self.inner = self.inner.strict(strict);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::UserCredentials>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Users::list_grants`], set with a builder.
+pub struct UsersListGrantsRequest<'a> {
+    inner: crate::users::UsersListGrantsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> UsersListGrantsRequest<'a> {
+    /// Sets the `scopeId` query parameter.
+    pub fn scope_id(mut self, scope_id: &'a str) -> Self {
+        self.inner = self.inner.scope_id(scope_id);
+        self
+    }
+
+    /// Sets the `expand` query parameter.
+    pub fn expand(mut self, expand: &'a str) -> Self {
+        self.inner = self.inner.expand(expand);
+        self
+    }
+
+    /// Sets the `after` query parameter.
+    pub fn after(mut self, after: &'a str) -> Self {
+        self.inner = self.inner.after(after);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::OAuth2ScopeConsentGrant>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Users::get_grant`], set with a builder.
+pub struct UsersGetGrantRequest<'a> {
+    inner: crate::users::UsersGetGrantRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> UsersGetGrantRequest<'a> {
+    /// Sets the `expand` query parameter.
+    pub fn expand(mut self, expand: &'a str) -> Self {
+        self.inner = self.inner.expand(expand);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::OAuth2ScopeConsentGrant>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Users::deactivate`], set with a builder.
+pub struct UsersDeactivateRequest<'a> {
+    inner: crate::users::UsersDeactivateRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> UsersDeactivateRequest<'a> {
+    /// Sets the `sendEmail` query parameter.
+    pub fn send_email(mut self, send_email: bool) -> Self {
+        self.inner = self.inner.send_email(send_email);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Users::reactivate`], set with a builder.
+pub struct UsersReactivateRequest<'a> {
+    inner: crate::users::UsersReactivateRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> UsersReactivateRequest<'a> {
+    /// Sets the `sendEmail` query parameter.
+    pub fn send_email(mut self, send_email: bool) -> Self {
+        self.inner = self.inner.send_email(send_email);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::UserActivationToken>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Users::get_linked_objects_fors`], set with a builder.
+pub struct UsersGetLinkedObjectsForsRequest<'a> {
+    inner: crate::users::UsersGetLinkedObjectsForsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> UsersGetLinkedObjectsForsRequest<'a> {
+    /// Sets the `after` query parameter.
+    pub fn after(mut self, after: &'a str) -> Self {
+        self.inner = self.inner.after(after);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Links>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Users::list_assigned_roles_fors`], set with a builder.
+pub struct UsersListAssignedRolesForsRequest<'a> {
+    inner: crate::users::UsersListAssignedRolesForsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> UsersListAssignedRolesForsRequest<'a> {
+    /// Sets the `expand` query parameter.
+    pub fn expand(mut self, expand: &'a str) -> Self {
+        self.inner = self.inner.expand(expand);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Role>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Users::assign_role`], set with a builder.
+pub struct UsersAssignRoleRequest<'a> {
+    inner: crate::users::UsersAssignRoleRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> UsersAssignRoleRequest<'a> {
+    /// Sets the `disableNotifications` query parameter.
+    pub fn disable_notifications(mut self, disable_notifications: &'a str) -> Self {
+        self.inner = self.inner.disable_notifications(disable_notifications);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::Role>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Users::list_application_targets_for_administrator_roles`], set with a builder.
+pub struct UsersListApplicationTargetsForAdministratorRolesRequest<'a> {
+    inner: crate::users::UsersListApplicationTargetsForAdministratorRolesRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> UsersListApplicationTargetsForAdministratorRolesRequest<'a> {
+    /// Sets the `after` query parameter.
+    pub fn after(mut self, after: &'a str) -> Self {
+        self.inner = self.inner.after(after);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::CatalogApplication>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Users::list_group_targets_for_roles`], set with a builder.
+pub struct UsersListGroupTargetsForRolesRequest<'a> {
+    inner: crate::users::UsersListGroupTargetsForRolesRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> UsersListGroupTargetsForRolesRequest<'a> {
+    /// Sets the `after` query parameter.
+    pub fn after(mut self, after: &'a str) -> Self {
+        self.inner = self.inner.after(after);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Group>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Users::clear_sessions`], set with a builder.
+pub struct UsersClearSessionsRequest<'a> {
+    inner: crate::users::UsersClearSessionsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> UsersClearSessionsRequest<'a> {
+    /// Sets the `oauthTokens` query parameter.
+    pub fn oauth_tokens(mut self, oauth_tokens: bool) -> Self {
+        self.inner = self.inner.oauth_tokens(oauth_tokens);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::users::Users`].
+pub struct Users {
+    inner: crate::users::Users,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Users {
+    pub(crate) fn new(inner: crate::users::Users, runtime: Arc<tokio::runtime::Runtime>) -> Self {
+        Users { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::users::Users::list`].
+    pub fn list(
+        &self,
+        q: &str,
+        after: &str,
+        limit: i64,
+        filter: &str,
+        search: &str,
+        sort_by: &str,
+        sort_order: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::User>>> {
+        self.runtime.block_on(
+            self.inner
+                .list(q, after, limit, filter, search, sort_by, sort_order),
+        )
+    }
+    /// The blocking version of [`crate::users::Users::list_request`].
+    pub fn list_request(&self) -> UsersListRequest<'_> {
+        UsersListRequest {
+            inner: self.inner.list_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::users::Users::list_all`].
+    pub fn list_all(
+        &self,
+        q: &str,
+        filter: &str,
+        search: &str,
+        sort_by: &str,
+        sort_order: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::User>>> {
+        self.runtime
+            .block_on(self.inner.list_all(q, filter, search, sort_by, sort_order))
+    }
+    /// The blocking version of [`crate::users::Users::create`].
+    pub fn create(
+        &self,
+        activate: bool,
+        provider: bool,
+        next_login: &str,
+        body: &crate::types::CreateUserRequest,
+    ) -> ClientResult<crate::Response<crate::types::User>> {
+        self.runtime
+            .block_on(self.inner.create(activate, provider, next_login, body))
+    }
+    /// The blocking version of [`crate::users::Users::create_request`].
+    pub fn create_request<'a>(
+        &'a self,
+        body: &'a crate::types::CreateUserRequest,
+    ) -> UsersCreateRequest<'a> {
+        UsersCreateRequest {
+            inner: self.inner.create_request(body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::users::Users::set_linked_object_for`].
+    pub fn set_linked_object_for(
+        &self,
+        associated_user_id: &str,
+        primary_relationship_name: &str,
+        primary_user_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.set_linked_object_for(
+            associated_user_id,
+            primary_relationship_name,
+            primary_user_id,
+        ))
+    }
+    /// The blocking version of [`crate::users::Users::get`].
+    pub fn get(&self, user_id: &str) -> ClientResult<crate::Response<crate::types::User>> {
+        self.runtime.block_on(self.inner.get(user_id))
+    }
+    /// The blocking version of [`crate::users::Users::update`].
+    pub fn update(
+        &self,
+        user_id: &str,
+        strict: bool,
+        body: &crate::types::User,
+    ) -> ClientResult<crate::Response<crate::types::User>> {
+        self.runtime
+            .block_on(self.inner.update(user_id, strict, body))
+    }
+    /// The blocking version of [`crate::users::Users::update_request`].
+    pub fn update_request<'a>(
+        &'a self,
+        user_id: &'a str,
+        body: &'a crate::types::User,
+    ) -> UsersUpdateRequest<'a> {
+        UsersUpdateRequest {
+            inner: self.inner.update_request(user_id, body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::users::Users::partial_update`].
+    pub fn partial_update(
+        &self,
+        user_id: &str,
+        strict: bool,
+        body: &crate::types::User,
+    ) -> ClientResult<crate::Response<crate::types::User>> {
+        self.runtime
+            .block_on(self.inner.partial_update(user_id, strict, body))
+    }
+    /// The blocking version of [`crate::users::Users::partial_update_request`].
+    pub fn partial_update_request<'a>(
+        &'a self,
+        user_id: &'a str,
+        body: &'a crate::types::User,
+    ) -> UsersPartialUpdateRequest<'a> {
+        UsersPartialUpdateRequest {
+            inner: self.inner.partial_update_request(user_id, body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::users::Users::deactivate_or_delete`].
+    pub fn deactivate_or_delete(
+        &self,
+        user_id: &str,
+        send_email: bool,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deactivate_or_delete(user_id, send_email))
+    }
+    /// The blocking version of [`crate::users::Users::deactivate_or_delete_request`].
+    pub fn deactivate_or_delete_request<'a>(
+        &'a self,
+        user_id: &'a str,
+    ) -> UsersDeactivateOrDeleteRequest<'a> {
+        UsersDeactivateOrDeleteRequest {
+            inner: self.inner.deactivate_or_delete_request(user_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::users::Users::list_app_links`].
+    pub fn list_app_links(
+        &self,
+        user_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::AppLink>>> {
+        self.runtime.block_on(self.inner.list_app_links(user_id))
+    }
+    /// The blocking version of [`crate::users::Users::list_all_app_links`].
+    pub fn list_all_app_links(
+        &self,
+        user_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::AppLink>>> {
+        self.runtime
+            .block_on(self.inner.list_all_app_links(user_id))
+    }
+    /// The blocking version of [`crate::users::Users::list_clients`].
+    pub fn list_clients(
+        &self,
+        user_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::OAuth2Client>>> {
+        self.runtime.block_on(self.inner.list_clients(user_id))
+    }
+    /// The blocking version of [`crate::users::Users::list_all_clients`].
+    pub fn list_all_clients(
+        &self,
+        user_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::OAuth2Client>>> {
+        self.runtime.block_on(self.inner.list_all_clients(user_id))
+    }
+    /// The blocking version of [`crate::users::Users::list_grants_for_and_clients`].
+    pub fn list_grants_for_and_clients(
+        &self,
+        user_id: &str,
+        client_id: &str,
+        expand: &str,
+        after: &str,
+        limit: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::OAuth2ScopeConsentGrant>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_grants_for_and_clients(user_id, client_id, expand, after, limit),
+        )
+    }
+    /// The blocking version of [`crate::users::Users::list_grants_for_and_clients_request`].
+    pub fn list_grants_for_and_clients_request<'a>(
+        &'a self,
+        user_id: &'a str,
+        client_id: &'a str,
+    ) -> UsersListGrantsForAndClientsRequest<'a> {
+        UsersListGrantsForAndClientsRequest {
+            inner: self
+                .inner
+                .list_grants_for_and_clients_request(user_id, client_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::users::Users::list_all_grants_for_and_clients`].
+    pub fn list_all_grants_for_and_clients(
+        &self,
+        user_id: &str,
+        client_id: &str,
+        expand: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::OAuth2ScopeConsentGrant>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_all_grants_for_and_clients(user_id, client_id, expand),
+        )
+    }
+    /// The blocking version of [`crate::users::Users::revoke_grants_for_and_client`].
+    pub fn revoke_grants_for_and_client(
+        &self,
+        user_id: &str,
+        client_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.revoke_grants_for_and_client(user_id, client_id))
+    }
+    /// The blocking version of [`crate::users::Users::list_refresh_tokens_for_and_clients`].
+    pub fn list_refresh_tokens_for_and_clients(
+        &self,
+        user_id: &str,
+        client_id: &str,
+        expand: &str,
+        after: &str,
+        limit: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::OAuth2RefreshToken>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_refresh_tokens_for_and_clients(user_id, client_id, expand, after, limit),
+        )
+    }
+    /// The blocking version of [`crate::users::Users::list_refresh_tokens_for_and_clients_request`].
+    pub fn list_refresh_tokens_for_and_clients_request<'a>(
+        &'a self,
+        user_id: &'a str,
+        client_id: &'a str,
+    ) -> UsersListRefreshTokensForAndClientsRequest<'a> {
+        UsersListRefreshTokensForAndClientsRequest {
+            inner: self
+                .inner
+                .list_refresh_tokens_for_and_clients_request(user_id, client_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::users::Users::list_all_refresh_tokens_for_and_clients`].
+    pub fn list_all_refresh_tokens_for_and_clients(
+        &self,
+        user_id: &str,
+        client_id: &str,
+        expand: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::OAuth2RefreshToken>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_all_refresh_tokens_for_and_clients(user_id, client_id, expand),
+        )
+    }
+    /// The blocking version of [`crate::users::Users::revoke_tokens_for_and_client`].
+    pub fn revoke_tokens_for_and_client(
+        &self,
+        user_id: &str,
+        client_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.revoke_tokens_for_and_client(user_id, client_id))
+    }
+    /// The blocking version of [`crate::users::Users::get_refresh_token_for_and_client`].
+    pub fn get_refresh_token_for_and_client(
+        &self,
+        user_id: &str,
+        client_id: &str,
+        token_id: &str,
+        expand: &str,
+        limit: i64,
+        after: &str,
+    ) -> ClientResult<crate::Response<crate::types::OAuth2RefreshToken>> {
+        self.runtime.block_on(
+            self.inner.get_refresh_token_for_and_client(
+                user_id, client_id, token_id, expand, limit, after,
+            ),
+        )
+    }
+    /// The blocking version of [`crate::users::Users::get_refresh_token_for_and_client_request`].
+    pub fn get_refresh_token_for_and_client_request<'a>(
+        &'a self,
+        user_id: &'a str,
+        client_id: &'a str,
+        token_id: &'a str,
+    ) -> UsersGetRefreshTokenForAndClientRequest<'a> {
+        UsersGetRefreshTokenForAndClientRequest {
+            inner: self
+                .inner
+                .get_refresh_token_for_and_client_request(user_id, client_id, token_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::users::Users::revoke_token_for_and_client`].
+    pub fn revoke_token_for_and_client(
+        &self,
+        user_id: &str,
+        client_id: &str,
+        token_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .revoke_token_for_and_client(user_id, client_id, token_id),
+        )
+    }
+    /// The blocking version of [`crate::users::Users::change_password`].
+    pub fn change_password(
+        &self,
+        user_id: &str,
+        strict: bool,
+        body: &crate::types::ChangePasswordRequest,
+    ) -> ClientResult<crate::Response<crate::types::UserCredentials>> {
+        self.runtime
+            .block_on(self.inner.change_password(user_id, strict, body))
+    }
+    /// The blocking version of [`crate::users::Users::change_password_request`].
+    pub fn change_password_request<'a>(
+        &'a self,
+        user_id: &'a str,
+        body: &'a crate::types::ChangePasswordRequest,
+    ) -> UsersChangePasswordRequest<'a> {
+        UsersChangePasswordRequest {
+            inner: self.inner.change_password_request(user_id, body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::users::Users::change_recovery_question`].
+    pub fn change_recovery_question(
+        &self,
+        user_id: &str,
+        body: &crate::types::UserCredentials,
+    ) -> ClientResult<crate::Response<crate::types::UserCredentials>> {
+        self.runtime
+            .block_on(self.inner.change_recovery_question(user_id, body))
+    }
+    /// The blocking version of [`crate::users::Users::post_credentials_forgot_password`].
+    pub fn post_credentials_forgot_password(
+        &self,
+        user_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ResetPasswordToken>> {
+        self.runtime
+            .block_on(self.inner.post_credentials_forgot_password(user_id))
+    }
+    /// The blocking version of [`crate::users::Users::list_grants`].
+    pub fn list_grants(
+        &self,
+        user_id: &str,
+        scope_id: &str,
+        expand: &str,
+        after: &str,
+        limit: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::OAuth2ScopeConsentGrant>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_grants(user_id, scope_id, expand, after, limit),
+        )
+    }
+    /// The blocking version of [`crate::users::Users::list_grants_request`].
+    pub fn list_grants_request<'a>(&'a self, user_id: &'a str) -> UsersListGrantsRequest<'a> {
+        UsersListGrantsRequest {
+            inner: self.inner.list_grants_request(user_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::users::Users::list_all_grants`].
+    pub fn list_all_grants(
+        &self,
+        user_id: &str,
+        scope_id: &str,
+        expand: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::OAuth2ScopeConsentGrant>>> {
+        self.runtime
+            .block_on(self.inner.list_all_grants(user_id, scope_id, expand))
+    }
+    /// The blocking version of [`crate::users::Users::revoke_grants`].
+    pub fn revoke_grants(&self, user_id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.revoke_grants(user_id))
+    }
+    /// The blocking version of [`crate::users::Users::get_grant`].
+    pub fn get_grant(
+        &self,
+        user_id: &str,
+        grant_id: &str,
+        expand: &str,
+    ) -> ClientResult<crate::Response<crate::types::OAuth2ScopeConsentGrant>> {
+        self.runtime
+            .block_on(self.inner.get_grant(user_id, grant_id, expand))
+    }
+    /// The blocking version of [`crate::users::Users::get_grant_request`].
+    pub fn get_grant_request<'a>(
+        &'a self,
+        user_id: &'a str,
+        grant_id: &'a str,
+    ) -> UsersGetGrantRequest<'a> {
+        UsersGetGrantRequest {
+            inner: self.inner.get_grant_request(user_id, grant_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::users::Users::revoke_grant`].
+    pub fn revoke_grant(&self, user_id: &str, grant_id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.revoke_grant(user_id, grant_id))
+    }
+    /// The blocking version of [`crate::users::Users::list_groups`].
+    pub fn list_groups(
+        &self,
+        user_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Group>>> {
+        self.runtime.block_on(self.inner.list_groups(user_id))
+    }
+    /// The blocking version of [`crate::users::Users::list_all_groups`].
+    pub fn list_all_groups(
+        &self,
+        user_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Group>>> {
+        self.runtime.block_on(self.inner.list_all_groups(user_id))
+    }
+    /// The blocking version of [`crate::users::Users::list_identity_providers`].
+    pub fn list_identity_providers(
+        &self,
+        user_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::IdentityProvider>>> {
+        self.runtime
+            .block_on(self.inner.list_identity_providers(user_id))
+    }
+    /// The blocking version of [`crate::users::Users::list_all_identity_providers`].
+    pub fn list_all_identity_providers(
+        &self,
+        user_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::IdentityProvider>>> {
+        self.runtime
+            .block_on(self.inner.list_all_identity_providers(user_id))
+    }
+    /// The blocking version of [`crate::users::Users::activate`].
+    pub fn activate(
+        &self,
+        user_id: &str,
+        send_email: bool,
+    ) -> ClientResult<crate::Response<crate::types::UserActivationToken>> {
+        self.runtime
+            .block_on(self.inner.activate(user_id, send_email))
+    }
+    /// The blocking version of [`crate::users::Users::deactivate`].
+    pub fn deactivate(&self, user_id: &str, send_email: bool) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deactivate(user_id, send_email))
+    }
+    /// The blocking version of [`crate::users::Users::deactivate_request`].
+    pub fn deactivate_request<'a>(&'a self, user_id: &'a str) -> UsersDeactivateRequest<'a> {
+        UsersDeactivateRequest {
+            inner: self.inner.deactivate_request(user_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::users::Users::expire_password`].
+    pub fn expire_password(
+        &self,
+        user_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::User>> {
+        self.runtime.block_on(self.inner.expire_password(user_id))
+    }
+    /// The blocking version of [`crate::users::Users::expire_password_and_get_temporary`].
+    pub fn expire_password_and_get_temporary(
+        &self,
+        user_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::TempPassword>> {
+        self.runtime
+            .block_on(self.inner.expire_password_and_get_temporary(user_id))
+    }
+    /// The blocking version of [`crate::users::Users::reactivate`].
+    pub fn reactivate(
+        &self,
+        user_id: &str,
+        send_email: bool,
+    ) -> ClientResult<crate::Response<crate::types::UserActivationToken>> {
+        self.runtime
+            .block_on(self.inner.reactivate(user_id, send_email))
+    }
+    /// The blocking version of [`crate::users::Users::reactivate_request`].
+    pub fn reactivate_request<'a>(&'a self, user_id: &'a str) -> UsersReactivateRequest<'a> {
+        UsersReactivateRequest {
+            inner: self.inner.reactivate_request(user_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::users::Users::reset_factors`].
+    pub fn reset_factors(&self, user_id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.reset_factors(user_id))
+    }
+    /// The blocking version of [`crate::users::Users::reset_password`].
+    pub fn reset_password(
+        &self,
+        user_id: &str,
+        send_email: bool,
+    ) -> ClientResult<crate::Response<crate::types::ResetPasswordToken>> {
+        self.runtime
+            .block_on(self.inner.reset_password(user_id, send_email))
+    }
+    /// The blocking version of [`crate::users::Users::suspend`].
+    pub fn suspend(&self, user_id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.suspend(user_id))
+    }
+    /// The blocking version of [`crate::users::Users::unlock`].
+    pub fn unlock(&self, user_id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.unlock(user_id))
+    }
+    /// The blocking version of [`crate::users::Users::unsuspend`].
+    pub fn unsuspend(&self, user_id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.unsuspend(user_id))
+    }
+    /// The blocking version of [`crate::users::Users::get_linked_objects_fors`].
+    pub fn get_linked_objects_fors(
+        &self,
+        user_id: &str,
+        relationship_name: &str,
+        after: &str,
+        limit: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Links>>> {
+        self.runtime.block_on(self.inner.get_linked_objects_fors(
+            user_id,
+            relationship_name,
+            after,
+            limit,
+        ))
+    }
+    /// The blocking version of [`crate::users::Users::get_linked_objects_fors_request`].
+    pub fn get_linked_objects_fors_request<'a>(
+        &'a self,
+        user_id: &'a str,
+        relationship_name: &'a str,
+    ) -> UsersGetLinkedObjectsForsRequest<'a> {
+        UsersGetLinkedObjectsForsRequest {
+            inner: self
+                .inner
+                .get_linked_objects_fors_request(user_id, relationship_name),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::users::Users::get_all_linked_objects_fors`].
+    pub fn get_all_linked_objects_fors(
+        &self,
+        user_id: &str,
+        relationship_name: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Links>>> {
+        self.runtime.block_on(
+            self.inner
+                .get_all_linked_objects_fors(user_id, relationship_name),
+        )
+    }
+    /// The blocking version of [`crate::users::Users::remove_linked_object_for`].
+    pub fn remove_linked_object_for(
+        &self,
+        user_id: &str,
+        relationship_name: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .remove_linked_object_for(user_id, relationship_name),
+        )
+    }
+    /// The blocking version of [`crate::users::Users::list_assigned_roles_fors`].
+    pub fn list_assigned_roles_fors(
+        &self,
+        user_id: &str,
+        expand: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Role>>> {
+        self.runtime
+            .block_on(self.inner.list_assigned_roles_fors(user_id, expand))
+    }
+    /// The blocking version of [`crate::users::Users::list_assigned_roles_fors_request`].
+    pub fn list_assigned_roles_fors_request<'a>(
+        &'a self,
+        user_id: &'a str,
+    ) -> UsersListAssignedRolesForsRequest<'a> {
+        UsersListAssignedRolesForsRequest {
+            inner: self.inner.list_assigned_roles_fors_request(user_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::users::Users::list_all_assigned_roles_fors`].
+    pub fn list_all_assigned_roles_fors(
+        &self,
+        user_id: &str,
+        expand: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Role>>> {
+        self.runtime
+            .block_on(self.inner.list_all_assigned_roles_fors(user_id, expand))
+    }
+    /// The blocking version of [`crate::users::Users::assign_role`].
+    pub fn assign_role(
+        &self,
+        user_id: &str,
+        disable_notifications: &str,
+        body: &crate::types::AssignRoleRequest,
+    ) -> ClientResult<crate::Response<crate::types::Role>> {
+        self.runtime
+            .block_on(self.inner.assign_role(user_id, disable_notifications, body))
+    }
+    /// The blocking version of [`crate::users::Users::assign_role_request`].
+    pub fn assign_role_request<'a>(
+        &'a self,
+        user_id: &'a str,
+        body: &'a crate::types::AssignRoleRequest,
+    ) -> UsersAssignRoleRequest<'a> {
+        UsersAssignRoleRequest {
+            inner: self.inner.assign_role_request(user_id, body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::users::Users::remove_role_from`].
+    pub fn remove_role_from(
+        &self,
+        user_id: &str,
+        role_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.remove_role_from(user_id, role_id))
+    }
+    /// The blocking version of [`crate::users::Users::list_application_targets_for_administrator_roles`].
+    pub fn list_application_targets_for_administrator_roles(
+        &self,
+        user_id: &str,
+        role_id: &str,
+        after: &str,
+        limit: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::CatalogApplication>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_application_targets_for_administrator_roles(user_id, role_id, after, limit),
+        )
+    }
+    /// The blocking version of [`crate::users::Users::list_application_targets_for_administrator_roles_request`].
+    pub fn list_application_targets_for_administrator_roles_request<'a>(
+        &'a self,
+        user_id: &'a str,
+        role_id: &'a str,
+    ) -> UsersListApplicationTargetsForAdministratorRolesRequest<'a> {
+        UsersListApplicationTargetsForAdministratorRolesRequest {
+            inner: self
+                .inner
+                .list_application_targets_for_administrator_roles_request(user_id, role_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::users::Users::list_all_application_targets_for_administrator_roles`].
+    pub fn list_all_application_targets_for_administrator_roles(
+        &self,
+        user_id: &str,
+        role_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::CatalogApplication>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_all_application_targets_for_administrator_roles(user_id, role_id),
+        )
+    }
+    /// The blocking version of [`crate::users::Users::add_all_apps_as_target_role`].
+    pub fn add_all_apps_as_target_role(
+        &self,
+        user_id: &str,
+        role_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.add_all_apps_as_target_role(user_id, role_id))
+    }
+    /// The blocking version of [`crate::users::Users::add_application_target_admin_role_for`].
+    pub fn add_application_target_admin_role_for(
+        &self,
+        user_id: &str,
+        role_id: &str,
+        app_name: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .add_application_target_admin_role_for(user_id, role_id, app_name),
+        )
+    }
+    /// The blocking version of [`crate::users::Users::remove_application_target_from_administrator_role_for`].
+    pub fn remove_application_target_from_administrator_role_for(
+        &self,
+        user_id: &str,
+        role_id: &str,
+        app_name: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .remove_application_target_from_administrator_role_for(user_id, role_id, app_name),
+        )
+    }
+    /// The blocking version of [`crate::users::Users::add_application_target_app_admin_role_for`].
+    pub fn add_application_target_app_admin_role_for(
+        &self,
+        user_id: &str,
+        role_id: &str,
+        app_name: &str,
+        application_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.add_application_target_app_admin_role_for(
+                user_id,
+                role_id,
+                app_name,
+                application_id,
+            ))
+    }
+    /// The blocking version of [`crate::users::Users::remove_application_target_from_administrator_role_for_users`].
+    pub fn remove_application_target_from_administrator_role_for_users(
+        &self,
+        user_id: &str,
+        role_id: &str,
+        app_name: &str,
+        application_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .remove_application_target_from_administrator_role_for_users(
+                    user_id,
+                    role_id,
+                    app_name,
+                    application_id,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::users::Users::list_group_targets_for_roles`].
+    pub fn list_group_targets_for_roles(
+        &self,
+        user_id: &str,
+        role_id: &str,
+        after: &str,
+        limit: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Group>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_group_targets_for_roles(user_id, role_id, after, limit),
+        )
+    }
+    /// The blocking version of [`crate::users::Users::list_group_targets_for_roles_request`].
+    pub fn list_group_targets_for_roles_request<'a>(
+        &'a self,
+        user_id: &'a str,
+        role_id: &'a str,
+    ) -> UsersListGroupTargetsForRolesRequest<'a> {
+        UsersListGroupTargetsForRolesRequest {
+            inner: self
+                .inner
+                .list_group_targets_for_roles_request(user_id, role_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::users::Users::list_all_group_targets_for_roles`].
+    pub fn list_all_group_targets_for_roles(
+        &self,
+        user_id: &str,
+        role_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Group>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_all_group_targets_for_roles(user_id, role_id),
+        )
+    }
+    /// The blocking version of [`crate::users::Users::add_group_target_role`].
+    pub fn add_group_target_role(
+        &self,
+        user_id: &str,
+        role_id: &str,
+        group_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.add_group_target_role(user_id, role_id, group_id))
+    }
+    /// The blocking version of [`crate::users::Users::remove_group_target_from_role`].
+    pub fn remove_group_target_from_role(
+        &self,
+        user_id: &str,
+        role_id: &str,
+        group_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .remove_group_target_from_role(user_id, role_id, group_id),
+        )
+    }
+    /// The blocking version of [`crate::users::Users::clear_sessions`].
+    pub fn clear_sessions(
+        &self,
+        user_id: &str,
+        oauth_tokens: bool,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.clear_sessions(user_id, oauth_tokens))
+    }
+    /// The blocking version of [`crate::users::Users::clear_sessions_request`].
+    pub fn clear_sessions_request<'a>(&'a self, user_id: &'a str) -> UsersClearSessionsRequest<'a> {
+        UsersClearSessionsRequest {
+            inner: self.inner.clear_sessions_request(user_id),
+            runtime: &self.runtime,
+        }
+    }
+}
@@ -47,6 +47,19 @@ impl EventHooks {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/eventHooks` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Success
+     */
+    pub fn list_all_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::EventHook>> + Unpin + '_ {
+        let url = self.client.url("/api/v1/eventHooks", None);
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * This function performs a `POST` to the `/api/v1/eventHooks` endpoint.
      *
@@ -1,6 +1,57 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Features::update_lifecycle`], set with a builder.
+pub struct FeaturesUpdateLifecycleRequest<'a> {
+    client: &'a Features,
+    feature_id: &'a str,
+    lifecycle: &'a str,
+    mode: Option<&'a str>,
+}
+
+impl<'a> FeaturesUpdateLifecycleRequest<'a> {
+    /// Sets the `mode` query parameter.
+    pub fn mode(mut self, mode: &'a str) -> Self {
+        self.mode = Some(mode);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::Feature>> {
+        let Self {
+            client,
+            feature_id,
+            lifecycle,
+            mode,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = mode {
+            query_args.push(("mode".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/api/v1/features/{}/{}?{}",
+                crate::progenitor_support::encode_path(feature_id),
+                crate::progenitor_support::encode_path(lifecycle),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct Features {
     pub client: Client,
 }
@@ -47,6 +98,19 @@ impl Features {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/features` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Success
+     */
+    pub fn list_all_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Feature>> + Unpin + '_ {
+        let url = self.client.url("/api/v1/features", None);
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * This function performs a `GET` to the `/api/v1/features/{featureId}` endpoint.
      *
@@ -135,6 +199,26 @@ impl Features {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/features/{featureId}/dependencies` endpoint.
+     *
+     * As opposed to `list_all_dependencies`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Success
+     */
+    pub fn list_all_dependencies_stream(
+        &self,
+        feature_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Feature>> + Unpin + '_ {
+        let url = self.client.url(
+            &format!(
+                "/api/v1/features/{}/dependencies",
+                crate::progenitor_support::encode_path(feature_id),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * This function performs a `GET` to the `/api/v1/features/{featureId}/dependents` endpoint.
      *
@@ -193,6 +277,26 @@ impl Features {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/features/{featureId}/dependents` endpoint.
+     *
+     * As opposed to `list_all_dependents`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Success
+     */
+    pub fn list_all_dependents_stream(
+        &self,
+        feature_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Feature>> + Unpin + '_ {
+        let url = self.client.url(
+            &format!(
+                "/api/v1/features/{}/dependents",
+                crate::progenitor_support::encode_path(feature_id),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * This function performs a `POST` to the `/api/v1/features/{featureId}/{lifecycle}` endpoint.
      *
@@ -234,4 +338,23 @@ impl Features {
             )
             .await
     }
+    /**
+     * This function performs a `POST` to the `/api/v1/features/{featureId}/{lifecycle}` endpoint.
+     *
+     * As opposed to `update_lifecycle`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Success
+     */
+    pub fn update_lifecycle_request<'a>(
+        &'a self,
+        feature_id: &'a str,
+        lifecycle: &'a str,
+    ) -> FeaturesUpdateLifecycleRequest<'a> {
+        FeaturesUpdateLifecycleRequest {
+            client: self,
+            feature_id,
+            lifecycle,
+            mode: None,
+        }
+    }
 }
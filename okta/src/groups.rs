@@ -1,6 +1,603 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Groups::list`], set with a builder.
+pub struct GroupsListRequest<'a> {
+    client: &'a Groups,
+    q: Option<&'a str>,
+    search: Option<&'a str>,
+    after: Option<&'a str>,
+    limit: Option<i64>,
+    expand: Option<&'a str>,
+}
+
+impl<'a> GroupsListRequest<'a> {
+    /// Sets the `q` query parameter.
+    pub fn q(mut self, q: &'a str) -> Self {
+        self.q = Some(q);
+        self
+    }
+
+    /// Sets the `search` query parameter.
+    pub fn search(mut self, search: &'a str) -> Self {
+        self.search = Some(search);
+        self
+    }
+
+    /// Sets the `after` query parameter.
+    pub fn after(mut self, after: &'a str) -> Self {
+        self.after = Some(after);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `expand` query parameter.
+    pub fn expand(mut self, expand: &'a str) -> Self {
+        self.expand = Some(expand);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Group>>> {
+        let Self {
+            client,
+            q,
+            search,
+            after,
+            limit,
+            expand,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = after {
+            query_args.push(("after".to_string(), v.to_string()));
+        }
+        if let Some(v) = expand {
+            query_args.push(("expand".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = q {
+            query_args.push(("q".to_string(), v.to_string()));
+        }
+        if let Some(v) = search {
+            query_args.push(("search".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/api/v1/groups?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Groups::list_rules`], set with a builder.
+pub struct GroupsListRulesRequest<'a> {
+    client: &'a Groups,
+    limit: Option<i64>,
+    after: Option<&'a str>,
+    search: Option<&'a str>,
+    expand: Option<&'a str>,
+}
+
+impl<'a> GroupsListRulesRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `after` query parameter.
+    pub fn after(mut self, after: &'a str) -> Self {
+        self.after = Some(after);
+        self
+    }
+
+    /// Sets the `search` query parameter.
+    pub fn search(mut self, search: &'a str) -> Self {
+        self.search = Some(search);
+        self
+    }
+
+    /// Sets the `expand` query parameter.
+    pub fn expand(mut self, expand: &'a str) -> Self {
+        self.expand = Some(expand);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::GroupRule>>> {
+        let Self {
+            client,
+            limit,
+            after,
+            search,
+            expand,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = after {
+            query_args.push(("after".to_string(), v.to_string()));
+        }
+        if let Some(v) = expand {
+            query_args.push(("expand".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = search {
+            query_args.push(("search".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/api/v1/groups/rules?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Groups::get_rule`], set with a builder.
+pub struct GroupsGetRuleRequest<'a> {
+    client: &'a Groups,
+    rule_id: &'a str,
+    expand: Option<&'a str>,
+}
+
+impl<'a> GroupsGetRuleRequest<'a> {
+    /// Sets the `expand` query parameter.
+    pub fn expand(mut self, expand: &'a str) -> Self {
+        self.expand = Some(expand);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::GroupRule>> {
+        let Self {
+            client,
+            rule_id,
+            expand,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = expand {
+            query_args.push(("expand".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/api/v1/groups/rules/{}?{}",
+                crate::progenitor_support::encode_path(rule_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Groups::delete_rule`], set with a builder.
+pub struct GroupsDeleteRuleRequest<'a> {
+    client: &'a Groups,
+    rule_id: &'a str,
+    remove_users: Option<bool>,
+}
+
+impl<'a> GroupsDeleteRuleRequest<'a> {
+    /// Sets the `removeUsers` query parameter.
+    pub fn remove_users(mut self, remove_users: bool) -> Self {
+        self.remove_users = Some(remove_users);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            rule_id,
+            remove_users,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = remove_users {
+            query_args.push(("removeUsers".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/api/v1/groups/rules/{}?{}",
+                crate::progenitor_support::encode_path(rule_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .delete(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Groups::list_assigned_applications_fors`], set with a builder.
+pub struct GroupsListAssignedApplicationsForsRequest<'a> {
+    client: &'a Groups,
+    group_id: &'a str,
+    after: Option<&'a str>,
+    limit: Option<i64>,
+}
+
+impl<'a> GroupsListAssignedApplicationsForsRequest<'a> {
+    /// Sets the `after` query parameter.
+    pub fn after(mut self, after: &'a str) -> Self {
+        self.after = Some(after);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Application>>> {
+        let Self {
+            client,
+            group_id,
+            after,
+            limit,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = after {
+            query_args.push(("after".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/api/v1/groups/{}/apps?{}",
+                crate::progenitor_support::encode_path(group_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Groups::list_assigned_roles`], set with a builder.
+pub struct GroupsListAssignedRolesRequest<'a> {
+    client: &'a Groups,
+    group_id: &'a str,
+    expand: Option<&'a str>,
+}
+
+impl<'a> GroupsListAssignedRolesRequest<'a> {
+    /// Sets the `expand` query parameter.
+    pub fn expand(mut self, expand: &'a str) -> Self {
+        self.expand = Some(expand);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Role>>> {
+        let Self {
+            client,
+            group_id,
+            expand,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = expand {
+            query_args.push(("expand".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/api/v1/groups/{}/roles?{}",
+                crate::progenitor_support::encode_path(group_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Groups::assign_role`], set with a builder.
+pub struct GroupsAssignRoleRequest<'a> {
+    client: &'a Groups,
+    group_id: &'a str,
+    disable_notifications: Option<&'a str>,
+    body: &'a crate::types::AssignRoleRequest,
+}
+
+impl<'a> GroupsAssignRoleRequest<'a> {
+    /// Sets the `disableNotifications` query parameter.
+    pub fn disable_notifications(mut self, disable_notifications: &'a str) -> Self {
+        self.disable_notifications = Some(disable_notifications);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::Role>> {
+        let Self {
+            client,
+            group_id,
+            disable_notifications,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = disable_notifications {
+            query_args.push(("disableNotifications".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/api/v1/groups/{}/roles?{}",
+                crate::progenitor_support::encode_path(group_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Groups::list_application_targets_for_administrator_roles`], set with a builder.
+pub struct GroupsListApplicationTargetsForAdministratorRolesRequest<'a> {
+    client: &'a Groups,
+    group_id: &'a str,
+    role_id: &'a str,
+    after: Option<&'a str>,
+    limit: Option<i64>,
+}
+
+impl<'a> GroupsListApplicationTargetsForAdministratorRolesRequest<'a> {
+    /// Sets the `after` query parameter.
+    pub fn after(mut self, after: &'a str) -> Self {
+        self.after = Some(after);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::CatalogApplication>>> {
+        let Self {
+            client,
+            group_id,
+            role_id,
+            after,
+            limit,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = after {
+            query_args.push(("after".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/api/v1/groups/{}/roles/{}/targets/catalog/apps?{}",
+                crate::progenitor_support::encode_path(group_id),
+                crate::progenitor_support::encode_path(role_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Groups::list_targets_for_roles`], set with a builder.
+pub struct GroupsListTargetsForRolesRequest<'a> {
+    client: &'a Groups,
+    group_id: &'a str,
+    role_id: &'a str,
+    after: Option<&'a str>,
+    limit: Option<i64>,
+}
+
+impl<'a> GroupsListTargetsForRolesRequest<'a> {
+    /// Sets the `after` query parameter.
+    pub fn after(mut self, after: &'a str) -> Self {
+        self.after = Some(after);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Group>>> {
+        let Self {
+            client,
+            group_id,
+            role_id,
+            after,
+            limit,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = after {
+            query_args.push(("after".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/api/v1/groups/{}/roles/{}/targets/groups?{}",
+                crate::progenitor_support::encode_path(group_id),
+                crate::progenitor_support::encode_path(role_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Groups::list_users`], set with a builder.
+pub struct GroupsListUsersRequest<'a> {
+    client: &'a Groups,
+    group_id: &'a str,
+    after: Option<&'a str>,
+    limit: Option<i64>,
+}
+
+impl<'a> GroupsListUsersRequest<'a> {
+    /// Sets the `after` query parameter.
+    pub fn after(mut self, after: &'a str) -> Self {
+        self.after = Some(after);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::User>>> {
+        let Self {
+            client,
+            group_id,
+            after,
+            limit,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = after {
+            query_args.push(("after".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/api/v1/groups/{}/users?{}",
+                crate::progenitor_support::encode_path(group_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct Groups {
     pub client: Client,
 }
@@ -62,6 +659,25 @@ impl Groups {
             )
             .await
     }
+    /**
+     * List Groups.
+     *
+     * This function performs a `GET` to the `/api/v1/groups` endpoint.
+     *
+     * As opposed to `list`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Enumerates groups in your organization with pagination. A subset of groups can be returned that match a supported filter expression or query.
+     */
+    pub fn list_request(&self) -> GroupsListRequest<'_> {
+        GroupsListRequest {
+            client: self,
+            q: None,
+            search: None,
+            after: None,
+            limit: None,
+            expand: None,
+        }
+    }
     /**
      * List Groups.
      *
@@ -99,6 +715,35 @@ impl Groups {
             )
             .await
     }
+    /**
+     * List Groups.
+     *
+     * This function performs a `GET` to the `/api/v1/groups` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Enumerates groups in your organization with pagination. A subset of groups can be returned that match a supported filter expression or query.
+     */
+    pub fn list_all_stream(
+        &self,
+        q: &str,
+        search: &str,
+        expand: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Group>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !expand.is_empty() {
+            query_args.push(("expand".to_string(), expand.to_string()));
+        }
+        if !q.is_empty() {
+            query_args.push(("q".to_string(), q.to_string()));
+        }
+        if !search.is_empty() {
+            query_args.push(("search".to_string(), search.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/api/v1/groups?{}", query_), None);
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Add Group.
      *
@@ -169,6 +814,24 @@ impl Groups {
             )
             .await
     }
+    /**
+     * List Group Rules.
+     *
+     * This function performs a `GET` to the `/api/v1/groups/rules` endpoint.
+     *
+     * As opposed to `list_rules`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Lists all group rules for your organization.
+     */
+    pub fn list_rules_request(&self) -> GroupsListRulesRequest<'_> {
+        GroupsListRulesRequest {
+            client: self,
+            limit: None,
+            after: None,
+            search: None,
+            expand: None,
+        }
+    }
     /**
      * List Group Rules.
      *
@@ -204,6 +867,33 @@ impl Groups {
             )
             .await
     }
+    /**
+     * List Group Rules.
+     *
+     * This function performs a `GET` to the `/api/v1/groups/rules` endpoint.
+     *
+     * As opposed to `list_all_rules`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Lists all group rules for your organization.
+     */
+    pub fn list_all_rules_stream(
+        &self,
+        search: &str,
+        expand: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::GroupRule>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !expand.is_empty() {
+            query_args.push(("expand".to_string(), expand.to_string()));
+        }
+        if !search.is_empty() {
+            query_args.push(("search".to_string(), search.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/api/v1/groups/rules?{}", query_), None);
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Create Group Rule.
      *
@@ -266,6 +956,22 @@ impl Groups {
             )
             .await
     }
+    /**
+     * Get Group Rule.
+     *
+     * This function performs a `GET` to the `/api/v1/groups/rules/{ruleId}` endpoint.
+     *
+     * As opposed to `get_rule`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Fetches a specific group rule by id from your organization
+     */
+    pub fn get_rule_request<'a>(&'a self, rule_id: &'a str) -> GroupsGetRuleRequest<'a> {
+        GroupsGetRuleRequest {
+            client: self,
+            rule_id,
+            expand: None,
+        }
+    }
     /**
      * This function performs a `PUT` to the `/api/v1/groups/rules/{ruleId}` endpoint.
      *
@@ -337,6 +1043,22 @@ impl Groups {
             )
             .await
     }
+    /**
+     * Delete a group Rule.
+     *
+     * This function performs a `DELETE` to the `/api/v1/groups/rules/{ruleId}` endpoint.
+     *
+     * As opposed to `delete_rule`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Removes a specific group rule by id from your organization
+     */
+    pub fn delete_rule_request<'a>(&'a self, rule_id: &'a str) -> GroupsDeleteRuleRequest<'a> {
+        GroupsDeleteRuleRequest {
+            client: self,
+            rule_id,
+            remove_users: None,
+        }
+    }
     /**
      * Activate a group Rule.
      *
@@ -531,6 +1253,26 @@ impl Groups {
             )
             .await
     }
+    /**
+     * List Assigned Applications.
+     *
+     * This function performs a `GET` to the `/api/v1/groups/{groupId}/apps` endpoint.
+     *
+     * As opposed to `list_assigned_applications_fors`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Enumerates all applications that are assigned to a group.
+     */
+    pub fn list_assigned_applications_fors_request<'a>(
+        &'a self,
+        group_id: &'a str,
+    ) -> GroupsListAssignedApplicationsForsRequest<'a> {
+        GroupsListAssignedApplicationsForsRequest {
+            client: self,
+            group_id,
+            after: None,
+            limit: None,
+        }
+    }
     /**
      * List Assigned Applications.
      *
@@ -561,6 +1303,28 @@ impl Groups {
             )
             .await
     }
+    /**
+     * List Assigned Applications.
+     *
+     * This function performs a `GET` to the `/api/v1/groups/{groupId}/apps` endpoint.
+     *
+     * As opposed to `list_all_assigned_applications_fors`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Enumerates all applications that are assigned to a group.
+     */
+    pub fn list_all_assigned_applications_fors_stream(
+        &self,
+        group_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Application>> + Unpin + '_ {
+        let url = self.client.url(
+            &format!(
+                "/api/v1/groups/{}/apps",
+                crate::progenitor_support::encode_path(group_id),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * This function performs a `GET` to the `/api/v1/groups/{groupId}/roles` endpoint.
      *
@@ -599,6 +1363,23 @@ impl Groups {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/groups/{groupId}/roles` endpoint.
+     *
+     * As opposed to `list_assigned_roles`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Success
+     */
+    pub fn list_assigned_roles_request<'a>(
+        &'a self,
+        group_id: &'a str,
+    ) -> GroupsListAssignedRolesRequest<'a> {
+        GroupsListAssignedRolesRequest {
+            client: self,
+            group_id,
+            expand: None,
+        }
+    }
     /**
      * This function performs a `GET` to the `/api/v1/groups/{groupId}/roles` endpoint.
      *
@@ -634,6 +1415,33 @@ impl Groups {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/groups/{groupId}/roles` endpoint.
+     *
+     * As opposed to `list_all_assigned_roles`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Success
+     */
+    pub fn list_all_assigned_roles_stream(
+        &self,
+        group_id: &str,
+        expand: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Role>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !expand.is_empty() {
+            query_args.push(("expand".to_string(), expand.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/api/v1/groups/{}/roles?{}",
+                crate::progenitor_support::encode_path(group_id),
+                query_
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * This function performs a `POST` to the `/api/v1/groups/{groupId}/roles` endpoint.
      *
@@ -676,6 +1484,25 @@ impl Groups {
             )
             .await
     }
+    /**
+     * This function performs a `POST` to the `/api/v1/groups/{groupId}/roles` endpoint.
+     *
+     * As opposed to `assign_role`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Assigns a Role to a Group
+     */
+    pub fn assign_role_request<'a>(
+        &'a self,
+        group_id: &'a str,
+        body: &'a crate::types::AssignRoleRequest,
+    ) -> GroupsAssignRoleRequest<'a> {
+        GroupsAssignRoleRequest {
+            client: self,
+            group_id,
+            disable_notifications: None,
+            body,
+        }
+    }
     /**
      * This function performs a `GET` to the `/api/v1/groups/{groupId}/roles/{roleId}` endpoint.
      *
@@ -788,6 +1615,26 @@ impl Groups {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/groups/{groupId}/roles/{roleId}/targets/catalog/apps` endpoint.
+     *
+     * As opposed to `list_application_targets_for_administrator_roles`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Lists all App targets for an `APP_ADMIN` Role assigned to a Group. This methods return list may include full Applications or Instances. The response for an instance will have an `ID` value, while Application will not have an ID.
+     */
+    pub fn list_application_targets_for_administrator_roles_request<'a>(
+        &'a self,
+        group_id: &'a str,
+        role_id: &'a str,
+    ) -> GroupsListApplicationTargetsForAdministratorRolesRequest<'a> {
+        GroupsListApplicationTargetsForAdministratorRolesRequest {
+            client: self,
+            group_id,
+            role_id,
+            after: None,
+            limit: None,
+        }
+    }
     /**
      * This function performs a `GET` to the `/api/v1/groups/{groupId}/roles/{roleId}/targets/catalog/apps` endpoint.
      *
@@ -818,6 +1665,29 @@ impl Groups {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/groups/{groupId}/roles/{roleId}/targets/catalog/apps` endpoint.
+     *
+     * As opposed to `list_all_application_targets_for_administrator_roles`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Lists all App targets for an `APP_ADMIN` Role assigned to a Group. This methods return list may include full Applications or Instances. The response for an instance will have an `ID` value, while Application will not have an ID.
+     */
+    pub fn list_all_application_targets_for_administrator_roles_stream(
+        &self,
+        group_id: &str,
+        role_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::CatalogApplication>> + Unpin + '_
+    {
+        let url = self.client.url(
+            &format!(
+                "/api/v1/groups/{}/roles/{}/targets/catalog/apps",
+                crate::progenitor_support::encode_path(group_id),
+                crate::progenitor_support::encode_path(role_id),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * This function performs a `PUT` to the `/api/v1/groups/{groupId}/roles/{roleId}/targets/catalog/apps/{appName}` endpoint.
      *
@@ -1018,6 +1888,26 @@ impl Groups {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/groups/{groupId}/roles/{roleId}/targets/groups` endpoint.
+     *
+     * As opposed to `list_targets_for_roles`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Success
+     */
+    pub fn list_targets_for_roles_request<'a>(
+        &'a self,
+        group_id: &'a str,
+        role_id: &'a str,
+    ) -> GroupsListTargetsForRolesRequest<'a> {
+        GroupsListTargetsForRolesRequest {
+            client: self,
+            group_id,
+            role_id,
+            after: None,
+            limit: None,
+        }
+    }
     /**
      * This function performs a `GET` to the `/api/v1/groups/{groupId}/roles/{roleId}/targets/groups` endpoint.
      *
@@ -1048,6 +1938,28 @@ impl Groups {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/groups/{groupId}/roles/{roleId}/targets/groups` endpoint.
+     *
+     * As opposed to `list_all_targets_for_roles`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Success
+     */
+    pub fn list_all_targets_for_roles_stream(
+        &self,
+        group_id: &str,
+        role_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Group>> + Unpin + '_ {
+        let url = self.client.url(
+            &format!(
+                "/api/v1/groups/{}/roles/{}/targets/groups",
+                crate::progenitor_support::encode_path(group_id),
+                crate::progenitor_support::encode_path(role_id),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * This function performs a `PUT` to the `/api/v1/groups/{groupId}/roles/{roleId}/targets/groups/{targetGroupId}` endpoint.
      *
@@ -1165,6 +2077,23 @@ impl Groups {
             )
             .await
     }
+    /**
+     * List Group Members.
+     *
+     * This function performs a `GET` to the `/api/v1/groups/{groupId}/users` endpoint.
+     *
+     * As opposed to `list_users`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Enumerates all users that are a member of a group.
+     */
+    pub fn list_users_request<'a>(&'a self, group_id: &'a str) -> GroupsListUsersRequest<'a> {
+        GroupsListUsersRequest {
+            client: self,
+            group_id,
+            after: None,
+            limit: None,
+        }
+    }
     /**
      * List Group Members.
      *
@@ -1195,6 +2124,28 @@ impl Groups {
             )
             .await
     }
+    /**
+     * List Group Members.
+     *
+     * This function performs a `GET` to the `/api/v1/groups/{groupId}/users` endpoint.
+     *
+     * As opposed to `list_all_users`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Enumerates all users that are a member of a group.
+     */
+    pub fn list_all_users_stream(
+        &self,
+        group_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::User>> + Unpin + '_ {
+        let url = self.client.url(
+            &format!(
+                "/api/v1/groups/{}/users",
+                crate::progenitor_support::encode_path(group_id),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Add User to Group.
      *
@@ -1,6 +1,130 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`IdentityProviders::list`], set with a builder.
+pub struct IdentityProvidersListRequest<'a> {
+    client: &'a IdentityProviders,
+    q: Option<&'a str>,
+    after: Option<&'a str>,
+    limit: Option<i64>,
+    type_: Option<&'a str>,
+}
+
+impl<'a> IdentityProvidersListRequest<'a> {
+    /// Sets the `q` query parameter.
+    pub fn q(mut self, q: &'a str) -> Self {
+        self.q = Some(q);
+        self
+    }
+
+    /// Sets the `after` query parameter.
+    pub fn after(mut self, after: &'a str) -> Self {
+        self.after = Some(after);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `type` query parameter.
+    pub fn type_(mut self, type_: &'a str) -> Self {
+        self.type_ = Some(type_);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::IdentityProvider>>> {
+        let Self {
+            client,
+            q,
+            after,
+            limit,
+            type_,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = after {
+            query_args.push(("after".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = q {
+            query_args.push(("q".to_string(), v.to_string()));
+        }
+        if let Some(v) = type_ {
+            query_args.push(("type".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(&format!("/api/v1/idps?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`IdentityProviders::list_keys`], set with a builder.
+pub struct IdentityProvidersListKeysRequest<'a> {
+    client: &'a IdentityProviders,
+    after: Option<&'a str>,
+    limit: Option<i64>,
+}
+
+impl<'a> IdentityProvidersListKeysRequest<'a> {
+    /// Sets the `after` query parameter.
+    pub fn after(mut self, after: &'a str) -> Self {
+        self.after = Some(after);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::JsonWebKey>>> {
+        let Self {
+            client,
+            after,
+            limit,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = after {
+            query_args.push(("after".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/api/v1/idps/credentials/keys?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct IdentityProviders {
     pub client: Client,
 }
@@ -57,6 +181,24 @@ impl IdentityProviders {
             )
             .await
     }
+    /**
+     * List Identity Providers.
+     *
+     * This function performs a `GET` to the `/api/v1/idps` endpoint.
+     *
+     * As opposed to `list`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Enumerates IdPs in your organization with pagination. A subset of IdPs can be returned that match a supported filter expression or query.
+     */
+    pub fn list_request(&self) -> IdentityProvidersListRequest<'_> {
+        IdentityProvidersListRequest {
+            client: self,
+            q: None,
+            after: None,
+            limit: None,
+            type_: None,
+        }
+    }
     /**
      * List Identity Providers.
      *
@@ -90,6 +232,32 @@ impl IdentityProviders {
             )
             .await
     }
+    /**
+     * List Identity Providers.
+     *
+     * This function performs a `GET` to the `/api/v1/idps` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Enumerates IdPs in your organization with pagination. A subset of IdPs can be returned that match a supported filter expression or query.
+     */
+    pub fn list_all_stream(
+        &self,
+        q: &str,
+        type_: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::IdentityProvider>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !q.is_empty() {
+            query_args.push(("q".to_string(), q.to_string()));
+        }
+        if !type_.is_empty() {
+            query_args.push(("type".to_string(), type_.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/api/v1/idps?{}", query_), None);
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Add Identity Provider.
      *
@@ -150,6 +318,22 @@ impl IdentityProviders {
             )
             .await
     }
+    /**
+     * List Keys.
+     *
+     * This function performs a `GET` to the `/api/v1/idps/credentials/keys` endpoint.
+     *
+     * As opposed to `list_keys`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Enumerates IdP key credentials.
+     */
+    pub fn list_keys_request(&self) -> IdentityProvidersListKeysRequest<'_> {
+        IdentityProvidersListKeysRequest {
+            client: self,
+            after: None,
+            limit: None,
+        }
+    }
     /**
      * List Keys.
      *
@@ -173,6 +357,21 @@ impl IdentityProviders {
             )
             .await
     }
+    /**
+     * List Keys.
+     *
+     * This function performs a `GET` to the `/api/v1/idps/credentials/keys` endpoint.
+     *
+     * As opposed to `list_all_keys`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Enumerates IdP key credentials.
+     */
+    pub fn list_all_keys_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::JsonWebKey>> + Unpin + '_ {
+        let url = self.client.url("/api/v1/idps/credentials/keys", None);
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Add X.509 Certificate Public Key.
      *
@@ -412,6 +611,28 @@ impl IdentityProviders {
             )
             .await
     }
+    /**
+     * List Certificate Signing Requests for IdP.
+     *
+     * This function performs a `GET` to the `/api/v1/idps/{idpId}/credentials/csrs` endpoint.
+     *
+     * As opposed to `list_all_csrs_fors`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Enumerates Certificate Signing Requests for an IdP
+     */
+    pub fn list_all_csrs_fors_stream(
+        &self,
+        idp_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Csr>> + Unpin + '_ {
+        let url = self.client.url(
+            &format!(
+                "/api/v1/idps/{}/credentials/csrs",
+                crate::progenitor_support::encode_path(idp_id),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Generate Certificate Signing Request for IdP.
      *
@@ -606,6 +827,28 @@ impl IdentityProviders {
             )
             .await
     }
+    /**
+     * List Signing Key Credentials for IdP.
+     *
+     * This function performs a `GET` to the `/api/v1/idps/{idpId}/credentials/keys` endpoint.
+     *
+     * As opposed to `list_all_signing_keys`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Enumerates signing key credentials for an IdP
+     */
+    pub fn list_all_signing_keys_stream(
+        &self,
+        idp_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::JsonWebKey>> + Unpin + '_ {
+        let url = self.client.url(
+            &format!(
+                "/api/v1/idps/{}/credentials/keys",
+                crate::progenitor_support::encode_path(idp_id),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Generate New IdP Signing Key Credential.
      *
@@ -850,6 +1093,30 @@ impl IdentityProviders {
             )
             .await
     }
+    /**
+     * Find Users.
+     *
+     * This function performs a `GET` to the `/api/v1/idps/{idpId}/users` endpoint.
+     *
+     * As opposed to `list_all_application_users`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Find all the users linked to an identity provider
+     */
+    pub fn list_all_application_users_stream(
+        &self,
+        idp_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::IdentityProviderApplicationUser>>
+           + Unpin
+           + '_ {
+        let url = self.client.url(
+            &format!(
+                "/api/v1/idps/{}/users",
+                crate::progenitor_support::encode_path(idp_id),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * This function performs a `GET` to the `/api/v1/idps/{idpId}/users/{userId}` endpoint.
      *
@@ -1021,4 +1288,28 @@ impl IdentityProviders {
             )
             .await
     }
+    /**
+     * Social Authentication Token Operation.
+     *
+     * This function performs a `GET` to the `/api/v1/idps/{idpId}/users/{userId}/credentials/tokens` endpoint.
+     *
+     * As opposed to `list_all_social_auth_tokens`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Fetches the tokens minted by the Social Authentication Provider when the user authenticates with Okta via Social Auth.
+     */
+    pub fn list_all_social_auth_tokens_stream(
+        &self,
+        idp_id: &str,
+        user_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::SocialAuthToken>> + Unpin + '_ {
+        let url = self.client.url(
+            &format!(
+                "/api/v1/idps/{}/users/{}/credentials/tokens",
+                crate::progenitor_support::encode_path(idp_id),
+                crate::progenitor_support::encode_path(user_id),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
 }
@@ -1,6 +1,44 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`InlineHooks::list`], set with a builder.
+pub struct InlineHooksListRequest<'a> {
+    client: &'a InlineHooks,
+    type_: Option<&'a str>,
+}
+
+impl<'a> InlineHooksListRequest<'a> {
+    /// Sets the `type` query parameter.
+    pub fn type_(mut self, type_: &'a str) -> Self {
+        self.type_ = Some(type_);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::InlineHook>>> {
+        let Self { client, type_ } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = type_ {
+            query_args.push(("type".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/api/v1/inlineHooks?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct InlineHooks {
     pub client: Client,
 }
@@ -42,6 +80,19 @@ impl InlineHooks {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/inlineHooks` endpoint.
+     *
+     * As opposed to `list`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Success
+     */
+    pub fn list_request(&self) -> InlineHooksListRequest<'_> {
+        InlineHooksListRequest {
+            client: self,
+            type_: None,
+        }
+    }
     /**
      * This function performs a `GET` to the `/api/v1/inlineHooks` endpoint.
      *
@@ -71,6 +122,27 @@ impl InlineHooks {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/inlineHooks` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Success
+     */
+    pub fn list_all_stream(
+        &self,
+        type_: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::InlineHook>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !type_.is_empty() {
+            query_args.push(("type".to_string(), type_.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/api/v1/inlineHooks?{}", query_), None);
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * This function performs a `POST` to the `/api/v1/inlineHooks` endpoint.
      *
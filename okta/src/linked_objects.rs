@@ -55,6 +55,21 @@ impl LinkedObjects {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/meta/schemas/user/linkedObjects` endpoint.
+     *
+     * As opposed to `list_all_definitions`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Success
+     */
+    pub fn list_all_definitions_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::LinkedObject>> + Unpin + '_ {
+        let url = self
+            .client
+            .url("/api/v1/meta/schemas/user/linkedObjects", None);
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * This function performs a `POST` to the `/api/v1/meta/schemas/user/linkedObjects` endpoint.
      *
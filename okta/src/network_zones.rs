@@ -1,6 +1,69 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`NetworkZones::list`], set with a builder.
+pub struct NetworkZonesListRequest<'a> {
+    client: &'a NetworkZones,
+    after: Option<&'a str>,
+    limit: Option<i64>,
+    filter: Option<&'a str>,
+}
+
+impl<'a> NetworkZonesListRequest<'a> {
+    /// Sets the `after` query parameter.
+    pub fn after(mut self, after: &'a str) -> Self {
+        self.after = Some(after);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `filter` query parameter.
+    pub fn filter(mut self, filter: &'a str) -> Self {
+        self.filter = Some(filter);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::NetworkZone>>> {
+        let Self {
+            client,
+            after,
+            limit,
+            filter,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = after {
+            query_args.push(("after".to_string(), v.to_string()));
+        }
+        if let Some(v) = filter {
+            query_args.push(("filter".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/api/v1/zones?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct NetworkZones {
     pub client: Client,
 }
@@ -52,6 +115,23 @@ impl NetworkZones {
             )
             .await
     }
+    /**
+     * List Network Zones.
+     *
+     * This function performs a `GET` to the `/api/v1/zones` endpoint.
+     *
+     * As opposed to `list`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Enumerates network zones added to your organization with pagination. A subset of zones can be returned that match a supported filter expression or query.
+     */
+    pub fn list_request(&self) -> NetworkZonesListRequest<'_> {
+        NetworkZonesListRequest {
+            client: self,
+            after: None,
+            limit: None,
+            filter: None,
+        }
+    }
     /**
      * List Network Zones.
      *
@@ -81,6 +161,27 @@ impl NetworkZones {
             )
             .await
     }
+    /**
+     * List Network Zones.
+     *
+     * This function performs a `GET` to the `/api/v1/zones` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Enumerates network zones added to your organization with pagination. A subset of zones can be returned that match a supported filter expression or query.
+     */
+    pub fn list_all_stream(
+        &self,
+        filter: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::NetworkZone>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !filter.is_empty() {
+            query_args.push(("filter".to_string(), filter.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/api/v1/zones?{}", query_), None);
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Add Network Zone.
      *
@@ -1,6 +1,154 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Policies::list`], set with a builder.
+pub struct PoliciesListRequest<'a> {
+    client: &'a Policies,
+    type_: &'a str,
+    status: Option<&'a str>,
+    expand: Option<&'a str>,
+}
+
+impl<'a> PoliciesListRequest<'a> {
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: &'a str) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Sets the `expand` query parameter.
+    pub fn expand(mut self, expand: &'a str) -> Self {
+        self.expand = Some(expand);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Policy>>> {
+        let Self {
+            client,
+            type_,
+            status,
+            expand,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = expand {
+            query_args.push(("expand".to_string(), v.to_string()));
+        }
+        if let Some(v) = status {
+            query_args.push(("status".to_string(), v.to_string()));
+        }
+        if !type_.is_empty() {
+            query_args.push(("type".to_string(), type_.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/api/v1/policies?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Policies::create_policy`], set with a builder.
+pub struct PoliciesCreatePolicyRequest<'a> {
+    client: &'a Policies,
+    activate: Option<bool>,
+    body: &'a crate::types::Policy,
+}
+
+impl<'a> PoliciesCreatePolicyRequest<'a> {
+    /// Sets the `activate` query parameter.
+    pub fn activate(mut self, activate: bool) -> Self {
+        self.activate = Some(activate);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::Policy>> {
+        let Self {
+            client,
+            activate,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = activate {
+            query_args.push(("activate".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/api/v1/policies?{}", query_), None);
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Policies::get_policy`], set with a builder.
+pub struct PoliciesGetPolicyRequest<'a> {
+    client: &'a Policies,
+    policy_id: &'a str,
+    expand: Option<&'a str>,
+}
+
+impl<'a> PoliciesGetPolicyRequest<'a> {
+    /// Sets the `expand` query parameter.
+    pub fn expand(mut self, expand: &'a str) -> Self {
+        self.expand = Some(expand);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::Policy>> {
+        let Self {
+            client,
+            policy_id,
+            expand,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = expand {
+            query_args.push(("expand".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/api/v1/policies/{}?{}",
+                crate::progenitor_support::encode_path(policy_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct Policies {
     pub client: Client,
 }
@@ -52,6 +200,21 @@ impl Policies {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/policies` endpoint.
+     *
+     * As opposed to `list`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Gets all policies with the specified type.
+     */
+    pub fn list_request<'a>(&'a self, type_: &'a str) -> PoliciesListRequest<'a> {
+        PoliciesListRequest {
+            client: self,
+            type_,
+            status: None,
+            expand: None,
+        }
+    }
     /**
      * This function performs a `GET` to the `/api/v1/policies` endpoint.
      *
@@ -89,6 +252,35 @@ impl Policies {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/policies` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Gets all policies with the specified type.
+     */
+    pub fn list_all_stream(
+        &self,
+        type_: &str,
+        status: &str,
+        expand: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Policy>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !expand.is_empty() {
+            query_args.push(("expand".to_string(), expand.to_string()));
+        }
+        if !status.is_empty() {
+            query_args.push(("status".to_string(), status.to_string()));
+        }
+        if !type_.is_empty() {
+            query_args.push(("type".to_string(), type_.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/api/v1/policies?{}", query_), None);
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * This function performs a `POST` to the `/api/v1/policies` endpoint.
      *
@@ -121,6 +313,23 @@ impl Policies {
             )
             .await
     }
+    /**
+     * This function performs a `POST` to the `/api/v1/policies` endpoint.
+     *
+     * As opposed to `create_policy`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Creates a policy.
+     */
+    pub fn create_policy_request<'a>(
+        &'a self,
+        body: &'a crate::types::Policy,
+    ) -> PoliciesCreatePolicyRequest<'a> {
+        PoliciesCreatePolicyRequest {
+            client: self,
+            activate: None,
+            body,
+        }
+    }
     /**
      * This function performs a `GET` to the `/api/v1/policies/{policyId}` endpoint.
      *
@@ -159,6 +368,20 @@ impl Policies {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/policies/{policyId}` endpoint.
+     *
+     * As opposed to `get_policy`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Gets a policy.
+     */
+    pub fn get_policy_request<'a>(&'a self, policy_id: &'a str) -> PoliciesGetPolicyRequest<'a> {
+        PoliciesGetPolicyRequest {
+            client: self,
+            policy_id,
+            expand: None,
+        }
+    }
     /**
      * This function performs a `PUT` to the `/api/v1/policies/{policyId}` endpoint.
      *
@@ -329,6 +552,26 @@ impl Policies {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/policies/{policyId}/rules` endpoint.
+     *
+     * As opposed to `list_all_policy_rules`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Enumerates all policy rules.
+     */
+    pub fn list_all_policy_rules_stream(
+        &self,
+        policy_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::PolicyRule>> + Unpin + '_ {
+        let url = self.client.url(
+            &format!(
+                "/api/v1/policies/{}/rules",
+                crate::progenitor_support::encode_path(policy_id),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * This function performs a `POST` to the `/api/v1/policies/{policyId}/rules` endpoint.
      *
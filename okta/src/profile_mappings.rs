@@ -1,6 +1,80 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`ProfileMappings::list`], set with a builder.
+pub struct ProfileMappingsListRequest<'a> {
+    client: &'a ProfileMappings,
+    after: Option<&'a str>,
+    limit: Option<i64>,
+    source_id: Option<&'a str>,
+    target_id: Option<&'a str>,
+}
+
+impl<'a> ProfileMappingsListRequest<'a> {
+    /// Sets the `after` query parameter.
+    pub fn after(mut self, after: &'a str) -> Self {
+        self.after = Some(after);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `sourceId` query parameter.
+    pub fn source_id(mut self, source_id: &'a str) -> Self {
+        self.source_id = Some(source_id);
+        self
+    }
+
+    /// Sets the `targetId` query parameter.
+    pub fn target_id(mut self, target_id: &'a str) -> Self {
+        self.target_id = Some(target_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::ProfileMapping>>> {
+        let Self {
+            client,
+            after,
+            limit,
+            source_id,
+            target_id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = after {
+            query_args.push(("after".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = source_id {
+            query_args.push(("sourceId".to_string(), v.to_string()));
+        }
+        if let Some(v) = target_id {
+            query_args.push(("targetId".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/api/v1/mappings?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct ProfileMappings {
     pub client: Client,
 }
@@ -57,6 +131,22 @@ impl ProfileMappings {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/mappings` endpoint.
+     *
+     * As opposed to `list`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Enumerates Profile Mappings in your organization with pagination.
+     */
+    pub fn list_request(&self) -> ProfileMappingsListRequest<'_> {
+        ProfileMappingsListRequest {
+            client: self,
+            after: None,
+            limit: None,
+            source_id: None,
+            target_id: None,
+        }
+    }
     /**
      * This function performs a `GET` to the `/api/v1/mappings` endpoint.
      *
@@ -90,6 +180,31 @@ impl ProfileMappings {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/mappings` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Enumerates Profile Mappings in your organization with pagination.
+     */
+    pub fn list_all_stream(
+        &self,
+        source_id: &str,
+        target_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::ProfileMapping>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !source_id.is_empty() {
+            query_args.push(("sourceId".to_string(), source_id.to_string()));
+        }
+        if !target_id.is_empty() {
+            query_args.push(("targetId".to_string(), target_id.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/api/v1/mappings?{}", query_), None);
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Get Profile Mapping.
      *
@@ -1,6 +1,47 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Templates::list_sms`], set with a builder.
+pub struct TemplatesListSmsRequest<'a> {
+    client: &'a Templates,
+    template_type: Option<&'a str>,
+}
+
+impl<'a> TemplatesListSmsRequest<'a> {
+    /// Sets the `templateType` query parameter.
+    pub fn template_type(mut self, template_type: &'a str) -> Self {
+        self.template_type = Some(template_type);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::SmsTemplate>>> {
+        let Self {
+            client,
+            template_type,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = template_type {
+            query_args.push(("templateType".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/api/v1/templates/sms?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct Templates {
     pub client: Client,
 }
@@ -44,6 +85,21 @@ impl Templates {
             )
             .await
     }
+    /**
+     * List SMS Templates.
+     *
+     * This function performs a `GET` to the `/api/v1/templates/sms` endpoint.
+     *
+     * As opposed to `list_sms`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Enumerates custom SMS templates in your organization. A subset of templates can be returned that match a template type.
+     */
+    pub fn list_sms_request(&self) -> TemplatesListSmsRequest<'_> {
+        TemplatesListSmsRequest {
+            client: self,
+            template_type: None,
+        }
+    }
     /**
      * List SMS Templates.
      *
@@ -75,6 +131,29 @@ impl Templates {
             )
             .await
     }
+    /**
+     * List SMS Templates.
+     *
+     * This function performs a `GET` to the `/api/v1/templates/sms` endpoint.
+     *
+     * As opposed to `list_all_sms`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Enumerates custom SMS templates in your organization. A subset of templates can be returned that match a template type.
+     */
+    pub fn list_all_sms_stream(
+        &self,
+        template_type: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::SmsTemplate>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !template_type.is_empty() {
+            query_args.push(("templateType".to_string(), template_type.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/api/v1/templates/sms?{}", query_), None);
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Add SMS Template.
      *
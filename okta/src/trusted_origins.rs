@@ -1,6 +1,80 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`TrustedOrigins::list_origins`], set with a builder.
+pub struct TrustedOriginsListOriginsRequest<'a> {
+    client: &'a TrustedOrigins,
+    q: Option<&'a str>,
+    filter: Option<&'a str>,
+    after: Option<&'a str>,
+    limit: Option<i64>,
+}
+
+impl<'a> TrustedOriginsListOriginsRequest<'a> {
+    /// Sets the `q` query parameter.
+    pub fn q(mut self, q: &'a str) -> Self {
+        self.q = Some(q);
+        self
+    }
+
+    /// Sets the `filter` query parameter.
+    pub fn filter(mut self, filter: &'a str) -> Self {
+        self.filter = Some(filter);
+        self
+    }
+
+    /// Sets the `after` query parameter.
+    pub fn after(mut self, after: &'a str) -> Self {
+        self.after = Some(after);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::TrustedOrigin>>> {
+        let Self {
+            client,
+            q,
+            filter,
+            after,
+            limit,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = after {
+            query_args.push(("after".to_string(), v.to_string()));
+        }
+        if let Some(v) = filter {
+            query_args.push(("filter".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = q {
+            query_args.push(("q".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/api/v1/trustedOrigins?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct TrustedOrigins {
     pub client: Client,
 }
@@ -57,6 +131,22 @@ impl TrustedOrigins {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/trustedOrigins` endpoint.
+     *
+     * As opposed to `list_origins`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Success
+     */
+    pub fn list_origins_request(&self) -> TrustedOriginsListOriginsRequest<'_> {
+        TrustedOriginsListOriginsRequest {
+            client: self,
+            q: None,
+            filter: None,
+            after: None,
+            limit: None,
+        }
+    }
     /**
      * This function performs a `GET` to the `/api/v1/trustedOrigins` endpoint.
      *
@@ -90,6 +180,31 @@ impl TrustedOrigins {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/trustedOrigins` endpoint.
+     *
+     * As opposed to `list_all_origins`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Success
+     */
+    pub fn list_all_origins_stream(
+        &self,
+        q: &str,
+        filter: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::TrustedOrigin>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !filter.is_empty() {
+            query_args.push(("filter".to_string(), filter.to_string()));
+        }
+        if !q.is_empty() {
+            query_args.push(("q".to_string(), q.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/api/v1/trustedOrigins?{}", query_), None);
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * This function performs a `POST` to the `/api/v1/trustedOrigins` endpoint.
      *
@@ -16,6 +16,10 @@ pub struct AcsEndpoint {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -54,6 +58,10 @@ pub struct ActivateFactorRequest {
         rename = "stateToken"
     )]
     pub state_token: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -65,8 +73,9 @@ pub enum Type {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Type {
@@ -75,7 +84,7 @@ impl std::fmt::Display for Type {
             Type::App => "APP",
             Type::AppType => "APP_TYPE",
             Type::Noop => "",
-            Type::FallthroughString => "*",
+            Type::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -103,6 +112,10 @@ pub struct AppInstanceConditionEvaluatorOr {
     pub name: String,
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
     pub type_: Option<Type>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -119,6 +132,10 @@ pub struct AppInstancePolicyRuleCondition {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub include: Vec<AppInstanceConditionEvaluatorOr>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -135,6 +152,10 @@ pub struct UserCondition {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub include: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -204,10 +225,19 @@ pub struct AppLink {
         rename = "sortOrder"
     )]
     pub sort_order: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Links {}
+pub struct Links {
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AppUser {
@@ -285,6 +315,10 @@ pub struct AppUser {
         rename = "syncState"
     )]
     pub sync_state: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -298,6 +332,10 @@ pub struct AppUserCredentials {
         rename = "userName"
     )]
     pub user_name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -308,6 +346,10 @@ pub struct AppUserPasswordCredential {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub value: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -321,8 +363,9 @@ pub enum Status {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Status {
@@ -332,7 +375,7 @@ impl std::fmt::Display for Status {
             Status::Deleted => "DELETED",
             Status::Inactive => "INACTIVE",
             Status::Noop => "",
-            Status::FallthroughString => "*",
+            Status::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -407,6 +450,10 @@ pub struct Application {
     pub status: Option<Status>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub visibility: Option<ApplicationVisibility>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -431,6 +478,10 @@ pub struct ApplicationAccessibility {
         rename = "selfService"
     )]
     pub self_service: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -443,6 +494,10 @@ pub struct ApplicationCredentials {
         rename = "userNameTemplate"
     )]
     pub user_name_template: Option<ApplicationCredentialsUsernameTemplate>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -467,6 +522,10 @@ pub struct ApplicationCredentialsOAuthClient {
     pub client_secret: String,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub token_endpoint_auth_method: Option<OAuthEndpointAuthenticationMethod>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -484,8 +543,9 @@ pub enum ApplicationCredentialsScheme {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ApplicationCredentialsScheme {
@@ -499,7 +559,7 @@ impl std::fmt::Display for ApplicationCredentialsScheme {
                 "SHARED_USERNAME_AND_PASSWORD"
             }
             ApplicationCredentialsScheme::Noop => "",
-            ApplicationCredentialsScheme::FallthroughString => "*",
+            ApplicationCredentialsScheme::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -542,6 +602,10 @@ pub struct ApplicationCredentialsSigning {
     pub rotation_mode: String,
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "use")]
     pub use_: Option<Use>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -551,8 +615,9 @@ pub enum Use {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Use {
@@ -560,7 +625,7 @@ impl std::fmt::Display for Use {
         match self {
             Use::Sig => "sig",
             Use::Noop => "",
-            Use::FallthroughString => "*",
+            Use::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -593,6 +658,10 @@ pub struct ApplicationCredentialsUsernameTemplate {
         rename = "type"
     )]
     pub type_: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -622,6 +691,10 @@ pub struct ApplicationGroupAssignment {
     pub priority: i64,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub profile: Option<Links>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -633,6 +706,10 @@ pub struct ApplicationLicensing {
         rename = "seatCount"
     )]
     pub seat_count: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -654,12 +731,20 @@ pub struct ApplicationSettings {
     pub inline_hook_id: String,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub notifications: Option<ApplicationSettingsNotifications>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ApplicationSettingsNotifications {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub vpn: Option<ApplicationSettingsNotificationsVpn>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -679,6 +764,10 @@ pub struct ApplicationSettingsNotificationsVpn {
     pub message: String,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub network: Option<ApplicationSettingsNotificationsVpnNetwork>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -701,6 +790,10 @@ pub struct ApplicationSettingsNotificationsVpnNetwork {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub include: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -726,8 +819,9 @@ pub enum ApplicationSignOnMode {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ApplicationSignOnMode {
@@ -743,7 +837,7 @@ impl std::fmt::Display for ApplicationSignOnMode {
             ApplicationSignOnMode::SecurePasswordStore => "SECURE_PASSWORD_STORE",
             ApplicationSignOnMode::WsFederation => "WS_FEDERATION",
             ApplicationSignOnMode::Noop => "",
-            ApplicationSignOnMode::FallthroughString => "*",
+            ApplicationSignOnMode::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -771,6 +865,10 @@ pub struct ApplicationVisibility {
     pub auto_submit_toolbar: bool,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub hide: Option<ApplicationVisibilityHide>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -786,12 +884,20 @@ pub struct ApplicationVisibilityHide {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub web: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AssignRoleRequest {
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
     pub type_: Option<RoleType>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -804,6 +910,10 @@ pub struct AuthenticationProvider {
     pub name: String,
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
     pub type_: Option<AuthenticationProviderType>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -823,8 +933,9 @@ pub enum AuthenticationProviderType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AuthenticationProviderType {
@@ -837,7 +948,7 @@ impl std::fmt::Display for AuthenticationProviderType {
             AuthenticationProviderType::Okta => "OKTA",
             AuthenticationProviderType::Social => "SOCIAL",
             AuthenticationProviderType::Noop => "",
-            AuthenticationProviderType::FallthroughString => "*",
+            AuthenticationProviderType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -858,8 +969,9 @@ pub enum IssuerMode {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for IssuerMode {
@@ -868,7 +980,7 @@ impl std::fmt::Display for IssuerMode {
             IssuerMode::CustomUrl => "CUSTOM_URL",
             IssuerMode::OrgUrl => "ORG_URL",
             IssuerMode::Noop => "",
-            IssuerMode::FallthroughString => "*",
+            IssuerMode::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -889,8 +1001,9 @@ pub enum RoleStatus {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for RoleStatus {
@@ -899,7 +1012,7 @@ impl std::fmt::Display for RoleStatus {
             RoleStatus::Active => "ACTIVE",
             RoleStatus::Inactive => "INACTIVE",
             RoleStatus::Noop => "",
-            RoleStatus::FallthroughString => "*",
+            RoleStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -968,12 +1081,20 @@ pub struct AuthorizationServer {
     pub name: String,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub status: Option<RoleStatus>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AuthorizationServerCredentials {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub signing: Option<AuthorizationServerCredentialsSigningConfig>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -985,8 +1106,9 @@ pub enum AuthorizationServerCredentialsRotationMode {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AuthorizationServerCredentialsRotationMode {
@@ -995,7 +1117,7 @@ impl std::fmt::Display for AuthorizationServerCredentialsRotationMode {
             AuthorizationServerCredentialsRotationMode::Auto => "AUTO",
             AuthorizationServerCredentialsRotationMode::Manual => "MANUAL",
             AuthorizationServerCredentialsRotationMode::Noop => "",
-            AuthorizationServerCredentialsRotationMode::FallthroughString => "*",
+            AuthorizationServerCredentialsRotationMode::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1037,6 +1159,10 @@ pub struct AuthorizationServerCredentialsSigningConfig {
     pub rotation_mode: Option<AuthorizationServerCredentialsRotationMode>,
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "use")]
     pub use_: Option<Use>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -1093,6 +1219,10 @@ pub struct AuthorizationServerPolicy {
     pub system: bool,
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
     pub type_: Option<PolicyType>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -1102,8 +1232,9 @@ pub enum AuthorizationServerPolicyRuleType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AuthorizationServerPolicyRuleType {
@@ -1111,7 +1242,7 @@ impl std::fmt::Display for AuthorizationServerPolicyRuleType {
         match self {
             AuthorizationServerPolicyRuleType::ResourceAccess => "RESOURCE_ACCESS",
             AuthorizationServerPolicyRuleType::Noop => "",
-            AuthorizationServerPolicyRuleType::FallthroughString => "*",
+            AuthorizationServerPolicyRuleType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1169,12 +1300,20 @@ pub struct AuthorizationServerPolicyRule {
     pub system: bool,
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
     pub type_: Option<AuthorizationServerPolicyRuleType>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AuthorizationServerPolicyRuleActions {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub token: Option<TokenAuthorizationServerPolicyRuleAction>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -1191,6 +1330,10 @@ pub struct AuthorizationServerPolicyRuleConditions {
     pub people: Option<PolicyPeopleCondition>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub scopes: Option<OAuth2ScopesMediationPolicyRuleCondition>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -1199,12 +1342,20 @@ pub struct AutoLoginApplication {
     pub credentials: Option<SchemeApplicationCredentials>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub settings: Option<AutoLoginApplicationSettings>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AutoLoginApplicationSettings {
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "signOn")]
     pub sign_on: Option<AutoLoginApplicationSettingsSignOn>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -1223,12 +1374,20 @@ pub struct AutoLoginApplicationSettingsSignOn {
         rename = "redirectUrl"
     )]
     pub redirect_url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct BasicApplicationSettings {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub app: Option<BasicApplicationSettingsData>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -1246,6 +1405,10 @@ pub struct BasicApplicationSettingsData {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -1256,6 +1419,10 @@ pub struct BasicAuthApplication {
     pub name: Option<serde_json::Value>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub settings: Option<BasicApplicationSettings>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -1268,6 +1435,10 @@ pub struct BeforeScheduledActionPolicyRuleCondition {
         rename = "lifecycleAction"
     )]
     pub lifecycle_action: Option<ScheduledUserLifecycleAction>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -1276,12 +1447,20 @@ pub struct BookmarkApplication {
     pub name: Option<serde_json::Value>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub settings: Option<BookmarkApplicationSettings>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct BookmarkApplicationSettings {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub app: Option<BookmarkApplicationSettingsData>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -1298,18 +1477,30 @@ pub struct BookmarkApplicationSettingsData {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct BrowserPluginApplication {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub credentials: Option<SchemeApplicationCredentials>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct CallUserFactor {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub profile: Option<CallUserFactorProfile>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -1328,6 +1519,10 @@ pub struct CallUserFactorProfile {
         rename = "phoneNumber"
     )]
     pub phone_number: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -1400,6 +1595,10 @@ pub struct CatalogApplication {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub website: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -1416,6 +1615,10 @@ pub struct ChangePasswordRequest {
         rename = "oldPassword"
     )]
     pub old_password: Option<PasswordCredential>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -1426,6 +1629,10 @@ pub struct ClientPolicyCondition {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub include: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -1436,6 +1643,10 @@ pub struct ContextPolicyRuleCondition {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub expression: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -1447,6 +1658,10 @@ pub struct CreateSessionRequest {
         rename = "sessionToken"
     )]
     pub session_token: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -1464,6 +1679,10 @@ pub struct CreateUserRequest {
     pub profile: Option<UserProfile>,
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
     pub type_: Option<UserType>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -1492,6 +1711,10 @@ pub struct Csr {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub kty: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -1504,6 +1727,10 @@ pub struct CsrMetadata {
         rename = "subjectAltNames"
     )]
     pub subject_alt_names: Option<CsrMetadataSubjectAltNames>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -1550,6 +1777,10 @@ pub struct CsrMetadataSubject {
         rename = "stateOrProvinceName"
     )]
     pub state_or_province_name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -1561,6 +1792,10 @@ pub struct CsrMetadataSubjectAltNames {
         rename = "dnsNames"
     )]
     pub dns_names: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -1574,6 +1809,10 @@ pub struct CustomHotpUserFactor {
     pub factor_profile_id: String,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub profile: Option<CustomHotpUserFactorProfile>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -1585,6 +1824,10 @@ pub struct CustomHotpUserFactorProfile {
         rename = "sharedSecret"
     )]
     pub shared_secret: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -1613,6 +1856,10 @@ pub struct DnsRecord {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub values: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -1624,8 +1871,9 @@ pub enum DnsRecordType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DnsRecordType {
@@ -1634,7 +1882,7 @@ impl std::fmt::Display for DnsRecordType {
             DnsRecordType::Cname => "CNAME",
             DnsRecordType::Txt => "TXT",
             DnsRecordType::Noop => "",
-            DnsRecordType::FallthroughString => "*",
+            DnsRecordType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1655,8 +1903,9 @@ pub enum TrustLevel {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for TrustLevel {
@@ -1665,7 +1914,7 @@ impl std::fmt::Display for TrustLevel {
             TrustLevel::Any => "ANY",
             TrustLevel::Trusted => "TRUSTED",
             TrustLevel::Noop => "",
-            TrustLevel::FallthroughString => "*",
+            TrustLevel::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1697,6 +1946,10 @@ pub struct DevicePolicyRuleCondition {
         rename = "trustLevel"
     )]
     pub trust_level: Option<TrustLevel>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -1710,8 +1963,9 @@ pub enum SupportedMdmFrameworks {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SupportedMdmFrameworks {
@@ -1721,7 +1975,7 @@ impl std::fmt::Display for SupportedMdmFrameworks {
             SupportedMdmFrameworks::Native => "NATIVE",
             SupportedMdmFrameworks::Safe => "SAFE",
             SupportedMdmFrameworks::Noop => "",
-            SupportedMdmFrameworks::FallthroughString => "*",
+            SupportedMdmFrameworks::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1746,8 +2000,9 @@ pub enum Types {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Types {
@@ -1758,7 +2013,7 @@ impl std::fmt::Display for Types {
             Types::Osx => "OSX",
             Types::Windows => "WINDOWS",
             Types::Noop => "",
-            Types::FallthroughString => "*",
+            Types::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1785,6 +2040,10 @@ pub struct DevicePolicyRuleConditionPlatform {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub types: Vec<Types>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -1826,6 +2085,10 @@ pub struct Domain {
         rename = "validationStatus"
     )]
     pub validation_status: Option<DomainValidationStatus>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -1852,6 +2115,10 @@ pub struct DomainCertificate {
     pub private_key: String,
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
     pub type_: Option<DomainCertificateType>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -1874,6 +2141,10 @@ pub struct DomainCertificateMetadata {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub subject: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -1883,8 +2154,9 @@ pub enum DomainCertificateSourceType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DomainCertificateSourceType {
@@ -1892,7 +2164,7 @@ impl std::fmt::Display for DomainCertificateSourceType {
         match self {
             DomainCertificateSourceType::Manual => "MANUAL",
             DomainCertificateSourceType::Noop => "",
-            DomainCertificateSourceType::FallthroughString => "*",
+            DomainCertificateSourceType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1911,8 +2183,9 @@ pub enum DomainCertificateType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DomainCertificateType {
@@ -1920,7 +2193,7 @@ impl std::fmt::Display for DomainCertificateType {
         match self {
             DomainCertificateType::Pem => "PEM",
             DomainCertificateType::Noop => "",
-            DomainCertificateType::FallthroughString => "*",
+            DomainCertificateType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1940,6 +2213,10 @@ pub struct DomainListResponse {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub domains: Vec<Domain>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -1955,8 +2232,9 @@ pub enum DomainValidationStatus {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DomainValidationStatus {
@@ -1967,7 +2245,7 @@ impl std::fmt::Display for DomainValidationStatus {
             DomainValidationStatus::NotStarted => "NOT_STARTED",
             DomainValidationStatus::Verified => "VERIFIED",
             DomainValidationStatus::Noop => "",
-            DomainValidationStatus::FallthroughString => "*",
+            DomainValidationStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1993,12 +2271,20 @@ pub struct Duration {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub unit: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct EmailUserFactor {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub profile: Option<EmailUserFactorProfile>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -2009,6 +2295,10 @@ pub struct EmailUserFactorProfile {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub email: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -2020,8 +2310,9 @@ pub enum EnabledStatus {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EnabledStatus {
@@ -2030,7 +2321,7 @@ impl std::fmt::Display for EnabledStatus {
             EnabledStatus::Disabled => "DISABLED",
             EnabledStatus::Enabled => "ENABLED",
             EnabledStatus::Noop => "",
-            EnabledStatus::FallthroughString => "*",
+            EnabledStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2051,8 +2342,9 @@ pub enum VerificationStatus {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for VerificationStatus {
@@ -2061,7 +2353,7 @@ impl std::fmt::Display for VerificationStatus {
             VerificationStatus::Unverified => "UNVERIFIED",
             VerificationStatus::Verified => "VERIFIED",
             VerificationStatus::Noop => "",
-            VerificationStatus::FallthroughString => "*",
+            VerificationStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2121,6 +2413,10 @@ pub struct EventHook {
         rename = "verificationStatus"
     )]
     pub verification_status: Option<VerificationStatus>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -2130,8 +2426,9 @@ pub enum EventHookChannelType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EventHookChannelType {
@@ -2139,7 +2436,7 @@ impl std::fmt::Display for EventHookChannelType {
         match self {
             EventHookChannelType::Http => "HTTP",
             EventHookChannelType::Noop => "",
-            EventHookChannelType::FallthroughString => "*",
+            EventHookChannelType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2163,6 +2460,10 @@ pub struct EventHookChannel {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub version: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -2185,6 +2486,10 @@ pub struct EventHookChannelConfig {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub uri: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -2203,6 +2508,10 @@ pub struct EventHookChannelConfigAuthScheme {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub value: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -2212,8 +2521,9 @@ pub enum EventHookChannelConfigAuthSchemeType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EventHookChannelConfigAuthSchemeType {
@@ -2221,7 +2531,7 @@ impl std::fmt::Display for EventHookChannelConfigAuthSchemeType {
         match self {
             EventHookChannelConfigAuthSchemeType::Header => "HEADER",
             EventHookChannelConfigAuthSchemeType::Noop => "",
-            EventHookChannelConfigAuthSchemeType::FallthroughString => "*",
+            EventHookChannelConfigAuthSchemeType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2247,6 +2557,10 @@ pub struct EventHookChannelConfigHeader {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub value: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -2258,8 +2572,9 @@ pub enum EventSubscriptionsType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EventSubscriptionsType {
@@ -2268,7 +2583,7 @@ impl std::fmt::Display for EventSubscriptionsType {
             EventSubscriptionsType::EventType => "EVENT_TYPE",
             EventSubscriptionsType::FlowEvent => "FLOW_EVENT",
             EventSubscriptionsType::Noop => "",
-            EventSubscriptionsType::FallthroughString => "*",
+            EventSubscriptionsType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2290,6 +2605,10 @@ pub struct EventSubscriptions {
     pub items: Vec<String>,
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
     pub type_: Option<EventSubscriptionsType>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -2313,8 +2632,9 @@ pub enum FactorProvider {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for FactorProvider {
@@ -2329,7 +2649,7 @@ impl std::fmt::Display for FactorProvider {
             FactorProvider::Symantec => "SYMANTEC",
             FactorProvider::Yubico => "YUBICO",
             FactorProvider::Noop => "",
-            FactorProvider::FallthroughString => "*",
+            FactorProvider::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2366,8 +2686,9 @@ pub enum FactorResultType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for FactorResultType {
@@ -2384,7 +2705,7 @@ impl std::fmt::Display for FactorResultType {
             FactorResultType::TimeWindowExceeded => "TIME_WINDOW_EXCEEDED",
             FactorResultType::Waiting => "WAITING",
             FactorResultType::Noop => "",
-            FactorResultType::FallthroughString => "*",
+            FactorResultType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2415,8 +2736,9 @@ pub enum FactorStatus {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for FactorStatus {
@@ -2430,7 +2752,7 @@ impl std::fmt::Display for FactorStatus {
             FactorStatus::NotSetup => "NOT_SETUP",
             FactorStatus::PendingActivation => "PENDING_ACTIVATION",
             FactorStatus::Noop => "",
-            FactorStatus::FallthroughString => "*",
+            FactorStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2473,8 +2795,9 @@ pub enum FactorType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for FactorType {
@@ -2494,7 +2817,7 @@ impl std::fmt::Display for FactorType {
             FactorType::Web => "web",
             FactorType::Webauthn => "webauthn",
             FactorType::Noop => "",
-            FactorType::FallthroughString => "*",
+            FactorType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2534,6 +2857,10 @@ pub struct Feature {
     pub status: Option<EnabledStatus>,
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
     pub type_: Option<FeatureType>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -2542,6 +2869,10 @@ pub struct FeatureStage {
     pub state: Option<FeatureStageState>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub value: Option<FeatureStageValue>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -2553,8 +2884,9 @@ pub enum FeatureStageState {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for FeatureStageState {
@@ -2563,7 +2895,7 @@ impl std::fmt::Display for FeatureStageState {
             FeatureStageState::Closed => "CLOSED",
             FeatureStageState::Open => "OPEN",
             FeatureStageState::Noop => "",
-            FeatureStageState::FallthroughString => "*",
+            FeatureStageState::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2584,8 +2916,9 @@ pub enum FeatureStageValue {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for FeatureStageValue {
@@ -2594,7 +2927,7 @@ impl std::fmt::Display for FeatureStageValue {
             FeatureStageValue::Beta => "BETA",
             FeatureStageValue::Ea => "EA",
             FeatureStageValue::Noop => "",
-            FeatureStageValue::FallthroughString => "*",
+            FeatureStageValue::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2613,8 +2946,9 @@ pub enum FeatureType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for FeatureType {
@@ -2622,7 +2956,7 @@ impl std::fmt::Display for FeatureType {
         match self {
             FeatureType::SelfService => "self-service",
             FeatureType::Noop => "",
-            FeatureType::FallthroughString => "*",
+            FeatureType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2643,6 +2977,10 @@ pub struct ResetPasswordToken {
         rename = "resetPasswordUrl"
     )]
     pub reset_password_url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -2688,6 +3026,10 @@ pub struct Group {
     pub profile: Option<GroupProfile>,
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
     pub type_: Option<GroupType>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -2704,6 +3046,10 @@ pub struct GroupProfile {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -2746,6 +3092,10 @@ pub struct GroupRule {
         rename = "type"
     )]
     pub type_: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -2756,6 +3106,10 @@ pub struct GroupRuleAction {
         rename = "assignUserToGroups"
     )]
     pub assign_user_to_groups: Option<GroupRuleAssignment>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -2764,6 +3118,10 @@ pub struct GroupRuleConditions {
     pub expression: Option<GroupRuleExpression>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub people: Option<GroupRulePeopleCondition>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -2781,6 +3139,10 @@ pub struct GroupRuleExpression {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub value: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -2792,6 +3154,10 @@ pub struct GroupRuleAssignment {
         rename = "groupIds"
     )]
     pub group_ids: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -2808,6 +3174,10 @@ pub struct GroupRuleCondition {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub include: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -2816,6 +3186,10 @@ pub struct GroupRulePeopleCondition {
     pub groups: Option<GroupRuleCondition>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub users: Option<GroupRuleCondition>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -2829,8 +3203,9 @@ pub enum GroupRuleStatus {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GroupRuleStatus {
@@ -2840,7 +3215,7 @@ impl std::fmt::Display for GroupRuleStatus {
             GroupRuleStatus::Inactive => "INACTIVE",
             GroupRuleStatus::Invalid => "INVALID",
             GroupRuleStatus::Noop => "",
-            GroupRuleStatus::FallthroughString => "*",
+            GroupRuleStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2863,8 +3238,9 @@ pub enum GroupType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GroupType {
@@ -2874,7 +3250,7 @@ impl std::fmt::Display for GroupType {
             GroupType::BuiltIn => "BUILT_IN",
             GroupType::OktaGroup => "OKTA_GROUP",
             GroupType::Noop => "",
-            GroupType::FallthroughString => "*",
+            GroupType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2890,6 +3266,10 @@ impl GroupType {
 pub struct HardwareUserFactor {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub profile: Option<WebUserFactorProfile>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -2901,6 +3281,10 @@ pub struct WebUserFactorProfile {
         rename = "credentialId"
     )]
     pub credential_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -2912,8 +3296,9 @@ pub enum IdentityProviderIssuerMode {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for IdentityProviderIssuerMode {
@@ -2922,7 +3307,7 @@ impl std::fmt::Display for IdentityProviderIssuerMode {
             IdentityProviderIssuerMode::CustomUrlDomain => "CUSTOM_URL_DOMAIN",
             IdentityProviderIssuerMode::OrgUrl => "ORG_URL",
             IdentityProviderIssuerMode::Noop => "",
-            IdentityProviderIssuerMode::FallthroughString => "*",
+            IdentityProviderIssuerMode::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2959,8 +3344,9 @@ pub enum IdentityProviderType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for IdentityProviderType {
@@ -2977,7 +3363,7 @@ impl std::fmt::Display for IdentityProviderType {
             IdentityProviderType::Saml2 => "SAML2",
             IdentityProviderType::X509 => "X509",
             IdentityProviderType::Noop => "",
-            IdentityProviderType::FallthroughString => "*",
+            IdentityProviderType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3032,6 +3418,10 @@ pub struct IdentityProvider {
     pub status: Option<RoleStatus>,
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
     pub type_: Option<IdentityProviderType>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -3068,6 +3458,10 @@ pub struct IdentityProviderApplicationUser {
     pub last_updated: String,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub profile: Option<Links>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -3078,6 +3472,10 @@ pub struct IdentityProviderCredentials {
     pub signing: Option<IdentityProviderCredentialsSigning>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub trust: Option<IdentityProviderCredentialsTrust>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -3094,6 +3492,10 @@ pub struct IdentityProviderCredentialsClient {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub client_secret: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -3104,6 +3506,10 @@ pub struct IdentityProviderCredentialsSigning {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub kid: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -3117,8 +3523,9 @@ pub enum Revocation {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Revocation {
@@ -3128,7 +3535,7 @@ impl std::fmt::Display for Revocation {
             Revocation::DeltaCrl => "DELTA_CRL",
             Revocation::Ocsp => "OCSP",
             Revocation::Noop => "",
-            Revocation::FallthroughString => "*",
+            Revocation::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3169,6 +3576,10 @@ pub struct IdentityProviderCredentialsTrust {
         rename = "revocationCacheLifetime"
     )]
     pub revocation_cache_lifetime: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -3190,6 +3601,10 @@ pub struct IdentityProviderPolicy {
     pub provisioning: Option<Provisioning>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub subject: Option<PolicySubject>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -3203,8 +3618,9 @@ pub enum Provider {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Provider {
@@ -3214,7 +3630,7 @@ impl std::fmt::Display for Provider {
             Provider::Okta => "OKTA",
             Provider::SpecificIdp => "SPECIFIC_IDP",
             Provider::Noop => "",
-            Provider::FallthroughString => "*",
+            Provider::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3237,6 +3653,10 @@ pub struct IdentityProviderPolicyRuleCondition {
     pub idp_ids: Vec<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub provider: Option<Provider>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -3280,6 +3700,10 @@ pub struct InlineHook {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub version: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -3294,6 +3718,10 @@ pub struct InlineHookChannel {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub version: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -3322,6 +3750,10 @@ pub struct InlineHookChannelConfig {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub uri: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -3345,6 +3777,10 @@ pub struct InlineHookChannelConfigAuthScheme {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub value: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -3361,6 +3797,10 @@ pub struct InlineHookChannelConfigHeaders {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub value: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -3371,6 +3811,10 @@ pub struct InlineHookResponse {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub commands: Vec<InlineHookResponseCommands>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -3393,6 +3837,10 @@ pub struct InlineHookResponseCommandValue {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub value: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -3410,6 +3858,10 @@ pub struct InlineHookResponseCommands {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub value: Vec<InlineHookResponseCommandValue>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -3427,8 +3879,9 @@ pub enum InlineHookType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for InlineHookType {
@@ -3442,7 +3895,7 @@ impl std::fmt::Display for InlineHookType {
             }
             InlineHookType::ComOktaUserPreRegistration => "com.okta.user.pre-registration",
             InlineHookType::Noop => "",
-            InlineHookType::FallthroughString => "*",
+            InlineHookType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3499,6 +3952,10 @@ pub struct IonField {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub visible: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -3558,6 +4015,10 @@ pub struct IonForm {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub value: Vec<IonField>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -3661,12 +4122,20 @@ pub struct JsonWebKey {
         rename = "x5u"
     )]
     pub x_5u: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct JwkUse {
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "use")]
     pub use_: Option<Use>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -3690,6 +4159,10 @@ pub struct LifecycleExpirationPolicyRuleCondition {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub unit: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -3700,6 +4173,10 @@ pub struct LinkedObject {
     pub associated: Option<LinkedObjectDetails>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub primary: Option<LinkedObjectDetails>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -3724,6 +4201,10 @@ pub struct LinkedObjectDetails {
     pub title: String,
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
     pub type_: Option<LinkedObjectDetailsType>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -3733,8 +4214,9 @@ pub enum LinkedObjectDetailsType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for LinkedObjectDetailsType {
@@ -3742,7 +4224,7 @@ impl std::fmt::Display for LinkedObjectDetailsType {
         match self {
             LinkedObjectDetailsType::User => "USER",
             LinkedObjectDetailsType::Noop => "",
-            LinkedObjectDetailsType::FallthroughString => "*",
+            LinkedObjectDetailsType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3785,6 +4267,10 @@ pub struct LogActor {
         rename = "type"
     )]
     pub type_: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -3829,6 +4315,10 @@ pub struct LogAuthenticationContext {
     pub interface: String,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub issuer: Option<LogIssuer>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -3848,8 +4338,9 @@ pub enum LogAuthenticationProvider {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for LogAuthenticationProvider {
@@ -3862,7 +4353,7 @@ impl std::fmt::Display for LogAuthenticationProvider {
             LogAuthenticationProvider::OktaAuthenticationProvider => "OKTA_AUTHENTICATION_PROVIDER",
             LogAuthenticationProvider::Social => "SOCIAL",
             LogAuthenticationProvider::Noop => "",
-            LogAuthenticationProvider::FallthroughString => "*",
+            LogAuthenticationProvider::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3909,6 +4400,10 @@ pub struct LogClient {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub zone: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -3930,8 +4425,9 @@ pub enum LogCredentialProvider {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for LogCredentialProvider {
@@ -3945,7 +4441,7 @@ impl std::fmt::Display for LogCredentialProvider {
             LogCredentialProvider::Symantec => "SYMANTEC",
             LogCredentialProvider::Yubikey => "YUBIKEY",
             LogCredentialProvider::Noop => "",
-            LogCredentialProvider::FallthroughString => "*",
+            LogCredentialProvider::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3978,8 +4474,9 @@ pub enum LogCredentialType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for LogCredentialType {
@@ -3994,7 +4491,7 @@ impl std::fmt::Display for LogCredentialType {
             LogCredentialType::Password => "PASSWORD",
             LogCredentialType::Sms => "SMS",
             LogCredentialType::Noop => "",
-            LogCredentialType::FallthroughString => "*",
+            LogCredentialType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -4010,6 +4507,10 @@ impl LogCredentialType {
 pub struct LogDebugContext {
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "debugData")]
     pub debug_data: Option<Links>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -4089,6 +4590,10 @@ pub struct LogEvent {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub version: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -4120,6 +4625,10 @@ pub struct LogGeographicalContext {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub state: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -4136,6 +4645,10 @@ pub struct LogGeolocation {
         deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub lon: f64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -4164,6 +4677,10 @@ pub struct LogIpAddress {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub version: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -4181,6 +4698,10 @@ pub struct LogIssuer {
         rename = "type"
     )]
     pub type_: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -4197,6 +4718,10 @@ pub struct LogOutcome {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub result: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -4208,6 +4733,10 @@ pub struct LogRequest {
         rename = "ipChain"
     )]
     pub ip_chain: Vec<LogIpAddress>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -4244,6 +4773,10 @@ pub struct LogSecurityContext {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub isp: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -4259,8 +4792,9 @@ pub enum LogSeverity {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for LogSeverity {
@@ -4271,7 +4805,7 @@ impl std::fmt::Display for LogSeverity {
             LogSeverity::Info => "INFO",
             LogSeverity::Warn => "WARN",
             LogSeverity::Noop => "",
-            LogSeverity::FallthroughString => "*",
+            LogSeverity::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -4318,6 +4852,10 @@ pub struct LogTarget {
         rename = "type"
     )]
     pub type_: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -4337,6 +4875,10 @@ pub struct LogTransaction {
         rename = "type"
     )]
     pub type_: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -4360,6 +4902,10 @@ pub struct LogUserAgent {
         rename = "rawUserAgent"
     )]
     pub raw_user_agent: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -4371,8 +4917,9 @@ pub enum Enrollment {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Enrollment {
@@ -4381,7 +4928,7 @@ impl std::fmt::Display for Enrollment {
             Enrollment::AnyOrNone => "ANY_OR_NONE",
             Enrollment::Omm => "OMM",
             Enrollment::Noop => "",
-            Enrollment::FallthroughString => "*",
+            Enrollment::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -4403,6 +4950,10 @@ pub struct MdmEnrollmentPolicyRuleCondition {
     pub block_non_safe_android: bool,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub enrollment: Option<Enrollment>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -4476,6 +5027,10 @@ pub struct NetworkZone {
     pub type_: Option<NetworkZoneType>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub usage: Option<NetworkZoneUsage>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -4488,6 +5043,10 @@ pub struct NetworkZoneAddress {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub value: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -4499,8 +5058,9 @@ pub enum NetworkZoneAddressType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for NetworkZoneAddressType {
@@ -4509,7 +5069,7 @@ impl std::fmt::Display for NetworkZoneAddressType {
             NetworkZoneAddressType::Cidr => "CIDR",
             NetworkZoneAddressType::Range => "RANGE",
             NetworkZoneAddressType::Noop => "",
-            NetworkZoneAddressType::FallthroughString => "*",
+            NetworkZoneAddressType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -4535,6 +5095,10 @@ pub struct NetworkZoneLocation {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub region: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -4546,8 +5110,9 @@ pub enum NetworkZoneType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for NetworkZoneType {
@@ -4556,7 +5121,7 @@ impl std::fmt::Display for NetworkZoneType {
             NetworkZoneType::Dynamic => "DYNAMIC",
             NetworkZoneType::Ip => "IP",
             NetworkZoneType::Noop => "",
-            NetworkZoneType::FallthroughString => "*",
+            NetworkZoneType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -4577,8 +5142,9 @@ pub enum NetworkZoneUsage {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for NetworkZoneUsage {
@@ -4587,7 +5153,7 @@ impl std::fmt::Display for NetworkZoneUsage {
             NetworkZoneUsage::Blocklist => "BLOCKLIST",
             NetworkZoneUsage::Policy => "POLICY",
             NetworkZoneUsage::Noop => "",
-            NetworkZoneUsage::FallthroughString => "*",
+            NetworkZoneUsage::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -4614,6 +5180,10 @@ pub struct OAuth2Actor {
         rename = "type"
     )]
     pub type_: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -4625,8 +5195,9 @@ pub enum ClaimType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ClaimType {
@@ -4635,7 +5206,7 @@ impl std::fmt::Display for ClaimType {
             ClaimType::Identity => "IDENTITY",
             ClaimType::Resource => "RESOURCE",
             ClaimType::Noop => "",
-            ClaimType::FallthroughString => "*",
+            ClaimType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -4660,8 +5231,9 @@ pub enum GroupFilterType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GroupFilterType {
@@ -4672,7 +5244,7 @@ impl std::fmt::Display for GroupFilterType {
             GroupFilterType::Regex => "REGEX",
             GroupFilterType::StartsWith => "STARTS_WITH",
             GroupFilterType::Noop => "",
-            GroupFilterType::FallthroughString => "*",
+            GroupFilterType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -4695,8 +5267,9 @@ pub enum ValueType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ValueType {
@@ -4706,7 +5279,7 @@ impl std::fmt::Display for ValueType {
             ValueType::Groups => "GROUPS",
             ValueType::System => "SYSTEM",
             ValueType::Noop => "",
-            ValueType::FallthroughString => "*",
+            ValueType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -4761,6 +5334,10 @@ pub struct OAuth2Claim {
     pub value: String,
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "valueType")]
     pub value_type: Option<ValueType>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -4771,6 +5348,10 @@ pub struct OAuth2ClaimConditions {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub scopes: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -4801,6 +5382,10 @@ pub struct OAuth2Client {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub logo_uri: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -4812,8 +5397,9 @@ pub enum OAuth2RefreshTokenStatus {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OAuth2RefreshTokenStatus {
@@ -4822,7 +5408,7 @@ impl std::fmt::Display for OAuth2RefreshTokenStatus {
             OAuth2RefreshTokenStatus::Active => "ACTIVE",
             OAuth2RefreshTokenStatus::Revoked => "REVOKED",
             OAuth2RefreshTokenStatus::Noop => "",
-            OAuth2RefreshTokenStatus::FallthroughString => "*",
+            OAuth2RefreshTokenStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -4896,6 +5482,10 @@ pub struct OAuth2RefreshToken {
         rename = "userId"
     )]
     pub user_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -4909,8 +5499,9 @@ pub enum Consent {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Consent {
@@ -4920,7 +5511,7 @@ impl std::fmt::Display for Consent {
             Consent::Implicit => "IMPLICIT",
             Consent::Required => "REQUIRED",
             Consent::Noop => "",
-            Consent::FallthroughString => "*",
+            Consent::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -4941,8 +5532,9 @@ pub enum MetadataPublish {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for MetadataPublish {
@@ -4951,7 +5543,7 @@ impl std::fmt::Display for MetadataPublish {
             MetadataPublish::AllClients => "ALL_CLIENTS",
             MetadataPublish::NoClients => "NO_CLIENTS",
             MetadataPublish::Noop => "",
-            MetadataPublish::FallthroughString => "*",
+            MetadataPublish::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -5008,6 +5600,10 @@ pub struct OAuth2Scope {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub system: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -5068,6 +5664,10 @@ pub struct OAuth2ScopeConsentGrant {
         rename = "userId"
     )]
     pub user_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -5079,8 +5679,9 @@ pub enum OAuth2ScopeConsentGrantSource {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OAuth2ScopeConsentGrantSource {
@@ -5089,7 +5690,7 @@ impl std::fmt::Display for OAuth2ScopeConsentGrantSource {
             OAuth2ScopeConsentGrantSource::Admin => "ADMIN",
             OAuth2ScopeConsentGrantSource::EndUser => "END_USER",
             OAuth2ScopeConsentGrantSource::Noop => "",
-            OAuth2ScopeConsentGrantSource::FallthroughString => "*",
+            OAuth2ScopeConsentGrantSource::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -5109,6 +5710,10 @@ pub struct OAuth2ScopesMediationPolicyRuleCondition {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub include: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -5171,6 +5776,10 @@ pub struct OAuth2Token {
         rename = "userId"
     )]
     pub user_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -5181,6 +5790,10 @@ pub struct OAuthApplicationCredentials {
         rename = "oauthClient"
     )]
     pub oauth_client: Option<ApplicationCredentialsOAuthClient>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -5198,8 +5811,9 @@ pub enum OAuthEndpointAuthenticationMethod {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OAuthEndpointAuthenticationMethod {
@@ -5211,7 +5825,7 @@ impl std::fmt::Display for OAuthEndpointAuthenticationMethod {
             OAuthEndpointAuthenticationMethod::None => "none",
             OAuthEndpointAuthenticationMethod::PrivateKeyJwt => "private_key_jwt",
             OAuthEndpointAuthenticationMethod::Noop => "",
-            OAuthEndpointAuthenticationMethod::FallthroughString => "*",
+            OAuthEndpointAuthenticationMethod::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -5238,8 +5852,9 @@ pub enum OAuthGrantType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OAuthGrantType {
@@ -5251,7 +5866,7 @@ impl std::fmt::Display for OAuthGrantType {
             OAuthGrantType::Password => "password",
             OAuthGrantType::RefreshToken => "refresh_token",
             OAuthGrantType::Noop => "",
-            OAuthGrantType::FallthroughString => "*",
+            OAuthGrantType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -5274,8 +5889,9 @@ pub enum OAuthResponseType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OAuthResponseType {
@@ -5285,7 +5901,7 @@ impl std::fmt::Display for OAuthResponseType {
             OAuthResponseType::IdToken => "id_token",
             OAuthResponseType::Token => "token",
             OAuthResponseType::Noop => "",
-            OAuthResponseType::FallthroughString => "*",
+            OAuthResponseType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -5301,12 +5917,20 @@ impl OAuthResponseType {
 pub struct OktaSignOnPolicy {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub conditions: Option<OktaSignOnPolicyConditions>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct OktaSignOnPolicyConditions {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub people: Option<PolicyPeopleCondition>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -5321,12 +5945,20 @@ pub struct OktaSignOnPolicyRule {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct OktaSignOnPolicyRuleActions {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub signon: Option<OktaSignOnPolicyRuleSignonActions>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -5341,6 +5973,10 @@ pub struct OktaSignOnPolicyRuleConditions {
     pub network: Option<PolicyNetworkCondition>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub people: Option<PolicyPeopleCondition>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -5352,8 +5988,9 @@ pub enum Access {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Access {
@@ -5362,7 +5999,7 @@ impl std::fmt::Display for Access {
             Access::Allow => "ALLOW",
             Access::Deny => "DENY",
             Access::Noop => "",
-            Access::FallthroughString => "*",
+            Access::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -5385,8 +6022,9 @@ pub enum FactorPromptMode {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for FactorPromptMode {
@@ -5396,7 +6034,7 @@ impl std::fmt::Display for FactorPromptMode {
             FactorPromptMode::Device => "DEVICE",
             FactorPromptMode::Session => "SESSION",
             FactorPromptMode::Noop => "",
-            FactorPromptMode::FallthroughString => "*",
+            FactorPromptMode::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -5439,6 +6077,10 @@ pub struct OktaSignOnPolicyRuleSignonActions {
     pub require_factor: bool,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub session: Option<OktaSignOnPolicyRuleSignonSessionActions>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -5463,6 +6105,10 @@ pub struct OktaSignOnPolicyRuleSignonSessionActions {
         rename = "usePersistentCookie"
     )]
     pub use_persistent_cookie: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -5473,6 +6119,10 @@ pub struct OpenConnectApplication {
     pub name: Option<serde_json::Value>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub settings: Option<OpenConnectApplicationSettings>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -5484,8 +6134,9 @@ pub enum OpenConnectApplicationConsentMethod {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OpenConnectApplicationConsentMethod {
@@ -5494,7 +6145,7 @@ impl std::fmt::Display for OpenConnectApplicationConsentMethod {
             OpenConnectApplicationConsentMethod::Required => "REQUIRED",
             OpenConnectApplicationConsentMethod::Trusted => "TRUSTED",
             OpenConnectApplicationConsentMethod::Noop => "",
-            OpenConnectApplicationConsentMethod::FallthroughString => "*",
+            OpenConnectApplicationConsentMethod::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -5520,6 +6171,10 @@ pub struct OpenConnectApplicationIdpInitiatedLogin {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub mode: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -5530,6 +6185,10 @@ pub struct OpenConnectApplicationSettings {
         rename = "oauthClient"
     )]
     pub oauth_client: Option<OpenConnectApplicationSettingsClient>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -5606,6 +6265,10 @@ pub struct OpenConnectApplicationSettingsClient {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub wildcard_redirect: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -5616,6 +6279,10 @@ pub struct OpenConnectApplicationSettingsClientKeys {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub keys: Vec<JsonWebKey>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -5628,6 +6295,10 @@ pub struct OpenConnectApplicationSettingsRefreshToken {
     pub leeway: i64,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub rotation_type: Option<OpenConnectRefreshTokenRotationType>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -5643,8 +6314,9 @@ pub enum OpenConnectApplicationType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OpenConnectApplicationType {
@@ -5655,7 +6327,7 @@ impl std::fmt::Display for OpenConnectApplicationType {
             OpenConnectApplicationType::Service => "service",
             OpenConnectApplicationType::Web => "web",
             OpenConnectApplicationType::Noop => "",
-            OpenConnectApplicationType::FallthroughString => "*",
+            OpenConnectApplicationType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -5676,8 +6348,9 @@ pub enum OpenConnectRefreshTokenRotationType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OpenConnectRefreshTokenRotationType {
@@ -5686,7 +6359,7 @@ impl std::fmt::Display for OpenConnectRefreshTokenRotationType {
             OpenConnectRefreshTokenRotationType::Rotate => "rotate",
             OpenConnectRefreshTokenRotationType::Static => "static",
             OpenConnectRefreshTokenRotationType::Noop => "",
-            OpenConnectRefreshTokenRotationType::FallthroughString => "*",
+            OpenConnectRefreshTokenRotationType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -5710,6 +6383,10 @@ pub struct PasswordCredential {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub value: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -5742,6 +6419,10 @@ pub struct PasswordCredentialHash {
         rename = "workFactor"
     )]
     pub work_factor: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -5759,8 +6440,9 @@ pub enum PasswordCredentialHashAlgorithm {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PasswordCredentialHashAlgorithm {
@@ -5772,7 +6454,7 @@ impl std::fmt::Display for PasswordCredentialHashAlgorithm {
             PasswordCredentialHashAlgorithm::Sha256 => "SHA-256",
             PasswordCredentialHashAlgorithm::Sha512 => "SHA-512",
             PasswordCredentialHashAlgorithm::Noop => "",
-            PasswordCredentialHashAlgorithm::FallthroughString => "*",
+            PasswordCredentialHashAlgorithm::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -5793,12 +6475,20 @@ pub struct PasswordCredentialHook {
         rename = "type"
     )]
     pub type_: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PasswordDictionary {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub common: Option<PasswordDictionaryCommon>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -5808,6 +6498,10 @@ pub struct PasswordDictionaryCommon {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub exclude: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -5816,6 +6510,10 @@ pub struct PasswordPolicy {
     pub conditions: Option<PasswordPolicyConditions>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub settings: Option<PasswordPolicySettingsData>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -5831,8 +6529,9 @@ pub enum PasswordPolicyAuthenticationProviderCondition {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PasswordPolicyAuthenticationProviderCondition {
@@ -5843,7 +6542,7 @@ impl std::fmt::Display for PasswordPolicyAuthenticationProviderCondition {
             PasswordPolicyAuthenticationProviderCondition::Ldap => "LDAP",
             PasswordPolicyAuthenticationProviderCondition::Okta => "OKTA",
             PasswordPolicyAuthenticationProviderCondition::Noop => "",
-            PasswordPolicyAuthenticationProviderCondition::FallthroughString => "*",
+            PasswordPolicyAuthenticationProviderCondition::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -5865,6 +6564,10 @@ pub struct PasswordPolicyAuthenticationProviderConditionData {
     pub include: Vec<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub provider: Option<PasswordPolicyAuthenticationProviderCondition>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -5877,12 +6580,20 @@ pub struct PasswordPolicyConditions {
     pub auth_provider: Option<PasswordPolicyAuthenticationProviderConditionData>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub people: Option<PolicyPeopleCondition>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PasswordPolicyDelegationSettings {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub options: Option<PasswordPolicyDelegationSettingsOptions>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -5893,6 +6604,10 @@ pub struct PasswordPolicyDelegationSettingsOptions {
         rename = "skipUnlock"
     )]
     pub skip_unlock: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -5903,6 +6618,10 @@ pub struct PasswordPolicySettings {
     pub complexity: Option<PasswordPolicySettingsComplexity>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub lockout: Option<PasswordPolicySettingsLockout>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -5935,6 +6654,10 @@ pub struct PasswordPolicySettingsAge {
         rename = "minAgeMinutes"
     )]
     pub min_age_minutes: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -5989,6 +6712,10 @@ pub struct PasswordPolicySettingsComplexity {
         rename = "minUpperCase"
     )]
     pub min_upper_case: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -6020,6 +6747,10 @@ pub struct PasswordPolicySettingsLockout {
         rename = "userLockoutNotificationChannels"
     )]
     pub user_lockout_notification_channels: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -6028,6 +6759,10 @@ pub struct PasswordPolicyRecoveryEmail {
     pub properties: Option<PasswordPolicyRecoveryEmailProperties>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub status: Option<RoleStatus>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -6038,6 +6773,10 @@ pub struct PasswordPolicyRecoveryEmailProperties {
         rename = "recoveryToken"
     )]
     pub recovery_token: Option<PasswordPolicyRecoveryEmailToken>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -6049,12 +6788,20 @@ pub struct PasswordPolicyRecoveryEmailToken {
         rename = "tokenLifetimeMinutes"
     )]
     pub token_lifetime_minutes: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PasswordPolicyRecoveryFactorSettings {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub status: Option<RoleStatus>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -6067,6 +6814,10 @@ pub struct PasswordPolicyRecoveryFactors {
     pub okta_sms: Option<PasswordPolicyRecoveryFactorSettings>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub recovery_question: Option<PasswordPolicyRecoveryQuestion>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -6075,6 +6826,10 @@ pub struct PasswordPolicyRecoveryQuestion {
     pub properties: Option<PasswordPolicyRecoveryQuestionProperties>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub status: Option<RoleStatus>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -6086,18 +6841,30 @@ pub struct PasswordPolicyRecoveryQuestionComplexity {
         rename = "minLength"
     )]
     pub min_length: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PasswordPolicyRecoveryQuestionProperties {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub complexity: Option<PasswordPolicyRecoveryQuestionComplexity>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PasswordPolicyRecoverySettings {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub factors: Option<PasswordPolicyRecoveryFactors>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -6112,12 +6879,20 @@ pub struct PasswordPolicyRule {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PasswordPolicyRuleAction {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub access: Option<Access>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -6140,6 +6915,10 @@ pub struct PasswordPolicyRuleActions {
         rename = "selfServiceUnlock"
     )]
     pub self_service_unlock: Option<PasswordPolicyRuleAction>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -6148,6 +6927,10 @@ pub struct PasswordPolicyRuleConditions {
     pub network: Option<PolicyNetworkCondition>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub people: Option<PolicyPeopleCondition>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -6158,6 +6941,10 @@ pub struct PasswordPolicySettingsData {
     pub password: Option<PasswordPolicySettings>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub recovery: Option<PasswordPolicyRecoverySettings>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -6173,8 +6960,9 @@ pub enum PlatformConditionEvaluatorType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PlatformConditionEvaluatorType {
@@ -6185,7 +6973,7 @@ impl std::fmt::Display for PlatformConditionEvaluatorType {
             PlatformConditionEvaluatorType::Mobile => "MOBILE",
             PlatformConditionEvaluatorType::Other => "OTHER",
             PlatformConditionEvaluatorType::Noop => "",
-            PlatformConditionEvaluatorType::FallthroughString => "*",
+            PlatformConditionEvaluatorType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -6203,6 +6991,10 @@ pub struct PlatformConditionEvaluator {
     pub os: Option<PlatformConditionEvaluatorOperatingSystem>,
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
     pub type_: Option<PlatformConditionEvaluatorType>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -6222,8 +7014,9 @@ pub enum PlatformConditionEvaluatorOperatingSystemType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PlatformConditionEvaluatorOperatingSystemType {
@@ -6236,7 +7029,7 @@ impl std::fmt::Display for PlatformConditionEvaluatorOperatingSystemType {
             PlatformConditionEvaluatorOperatingSystemType::Other => "OTHER",
             PlatformConditionEvaluatorOperatingSystemType::Windows => "WINDOWS",
             PlatformConditionEvaluatorOperatingSystemType::Noop => "",
-            PlatformConditionEvaluatorOperatingSystemType::FallthroughString => "*",
+            PlatformConditionEvaluatorOperatingSystemType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -6260,6 +7053,10 @@ pub struct PlatformConditionEvaluatorOperatingSystem {
     pub type_: Option<PlatformConditionEvaluatorOperatingSystemType>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub version: Option<PlatformConditionEvaluatorOperatingSystemVersion>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -6271,8 +7068,9 @@ pub enum MatchType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for MatchType {
@@ -6281,7 +7079,7 @@ impl std::fmt::Display for MatchType {
             MatchType::Expression => "EXPRESSION",
             MatchType::Semver => "SEMVER",
             MatchType::Noop => "",
-            MatchType::FallthroughString => "*",
+            MatchType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -6303,6 +7101,10 @@ pub struct PlatformConditionEvaluatorOperatingSystemVersion {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub value: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -6319,6 +7121,10 @@ pub struct PlatformPolicyRuleCondition {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub include: Vec<PlatformConditionEvaluator>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -6375,6 +7181,10 @@ pub struct Policy {
     pub system: bool,
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
     pub type_: Option<PolicyType>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -6386,8 +7196,9 @@ pub enum Action {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Action {
@@ -6396,7 +7207,7 @@ impl std::fmt::Display for Action {
             Action::Auto => "AUTO",
             Action::Disabled => "DISABLED",
             Action::Noop => "",
-            Action::FallthroughString => "*",
+            Action::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -6414,12 +7225,20 @@ pub struct PolicyAccountLink {
     pub action: Option<Action>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub filter: Option<PolicyAccountLinkFilter>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PolicyAccountLinkFilter {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub groups: Option<ClientPolicyCondition>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -6431,8 +7250,9 @@ pub enum Connection {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Connection {
@@ -6441,7 +7261,7 @@ impl std::fmt::Display for Connection {
             Connection::Anywhere => "ANYWHERE",
             Connection::Zone => "ZONE",
             Connection::Noop => "",
-            Connection::FallthroughString => "*",
+            Connection::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -6469,6 +7289,10 @@ pub struct PolicyNetworkCondition {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub include: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -6477,6 +7301,10 @@ pub struct PolicyPeopleCondition {
     pub groups: Option<UserCondition>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub users: Option<UserCondition>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -6488,8 +7316,9 @@ pub enum PolicyRuleType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PolicyRuleType {
@@ -6498,7 +7327,7 @@ impl std::fmt::Display for PolicyRuleType {
             PolicyRuleType::Password => "PASSWORD",
             PolicyRuleType::SignOn => "SIGN_ON",
             PolicyRuleType::Noop => "",
-            PolicyRuleType::FallthroughString => "*",
+            PolicyRuleType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -6556,6 +7385,10 @@ pub struct PolicyRule {
     pub system: bool,
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
     pub type_: Option<PolicyRuleType>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -6582,12 +7415,20 @@ pub struct PolicyRuleActions {
     pub self_service_unlock: Option<PasswordPolicyRuleAction>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub signon: Option<OktaSignOnPolicyRuleSignonActions>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PolicyRuleActionsEnroll {
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "self")]
     pub self_: Option<PolicyRuleActionsEnrollSelf>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -6601,8 +7442,9 @@ pub enum PolicyRuleActionsEnrollSelf {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PolicyRuleActionsEnrollSelf {
@@ -6612,7 +7454,7 @@ impl std::fmt::Display for PolicyRuleActionsEnrollSelf {
             PolicyRuleActionsEnrollSelf::Login => "LOGIN",
             PolicyRuleActionsEnrollSelf::Never => "NEVER",
             PolicyRuleActionsEnrollSelf::Noop => "",
-            PolicyRuleActionsEnrollSelf::FallthroughString => "*",
+            PolicyRuleActionsEnrollSelf::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -6633,8 +7475,9 @@ pub enum AuthType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AuthType {
@@ -6643,7 +7486,7 @@ impl std::fmt::Display for AuthType {
             AuthType::Any => "ANY",
             AuthType::Radius => "RADIUS",
             AuthType::Noop => "",
-            AuthType::FallthroughString => "*",
+            AuthType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -6659,6 +7502,10 @@ impl AuthType {
 pub struct PolicyRuleAuthContextCondition {
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "authType")]
     pub auth_type: Option<AuthType>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -6737,6 +7584,10 @@ pub struct PolicyRuleConditions {
     pub user_status: Option<UserStatusPolicyRuleCondition>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub users: Option<UserPolicyRuleCondition>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -6768,6 +7619,10 @@ pub struct PolicySubject {
         rename = "userNameTemplate"
     )]
     pub user_name_template: Option<PolicyUserNameTemplate>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -6783,8 +7638,9 @@ pub enum PolicySubjectMatchType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PolicySubjectMatchType {
@@ -6795,7 +7651,7 @@ impl std::fmt::Display for PolicySubjectMatchType {
             PolicySubjectMatchType::Username => "USERNAME",
             PolicySubjectMatchType::UsernameOrEmail => "USERNAME_OR_EMAIL",
             PolicySubjectMatchType::Noop => "",
-            PolicySubjectMatchType::FallthroughString => "*",
+            PolicySubjectMatchType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -6820,8 +7676,9 @@ pub enum PolicyType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PolicyType {
@@ -6832,7 +7689,7 @@ impl std::fmt::Display for PolicyType {
             PolicyType::OktaSignOn => "OKTA_SIGN_ON",
             PolicyType::Password => "PASSWORD",
             PolicyType::Noop => "",
-            PolicyType::FallthroughString => "*",
+            PolicyType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -6852,6 +7709,10 @@ pub struct PolicyUserNameTemplate {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub template: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -6870,6 +7731,10 @@ pub struct ProfileMapping {
     pub source: Option<ProfileMappingSource>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub target: Option<ProfileMappingSource>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -6886,6 +7751,10 @@ pub struct ProfileMappingProperty {
         rename = "pushStatus"
     )]
     pub push_status: Option<Links>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -6911,6 +7780,10 @@ pub struct ProfileMappingSource {
         rename = "type"
     )]
     pub type_: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -6926,8 +7799,9 @@ pub enum ProtocolType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ProtocolType {
@@ -6938,7 +7812,7 @@ impl std::fmt::Display for ProtocolType {
             ProtocolType::Oidc => "OIDC",
             ProtocolType::Saml2 => "SAML2",
             ProtocolType::Noop => "",
-            ProtocolType::FallthroughString => "*",
+            ProtocolType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -6976,12 +7850,20 @@ pub struct Protocol {
     pub settings: Option<ProtocolSettings>,
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
     pub type_: Option<ProtocolType>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ProtocolAlgorithmType {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub signature: Option<ProtocolAlgorithmTypeSignature>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -6999,8 +7881,9 @@ pub enum Scope {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Scope {
@@ -7012,7 +7895,7 @@ impl std::fmt::Display for Scope {
             Scope::Response => "RESPONSE",
             Scope::Token => "TOKEN",
             Scope::Noop => "",
-            Scope::FallthroughString => "*",
+            Scope::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -7034,6 +7917,10 @@ pub struct ProtocolAlgorithmTypeSignature {
     pub algorithm: String,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub scope: Option<Scope>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -7042,6 +7929,10 @@ pub struct ProtocolAlgorithms {
     pub request: Option<ProtocolAlgorithmType>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub response: Option<ProtocolAlgorithmType>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -7053,8 +7944,9 @@ pub enum Binding {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Binding {
@@ -7063,7 +7955,7 @@ impl std::fmt::Display for Binding {
             Binding::HttpPost => "HTTP-POST",
             Binding::HttpRedirect => "HTTP-REDIRECT",
             Binding::Noop => "",
-            Binding::FallthroughString => "*",
+            Binding::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -7084,8 +7976,9 @@ pub enum ProtocolEndpointType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ProtocolEndpointType {
@@ -7094,7 +7987,7 @@ impl std::fmt::Display for ProtocolEndpointType {
             ProtocolEndpointType::Instance => "INSTANCE",
             ProtocolEndpointType::Org => "ORG",
             ProtocolEndpointType::Noop => "",
-            ProtocolEndpointType::FallthroughString => "*",
+            ProtocolEndpointType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -7124,6 +8017,10 @@ pub struct ProtocolEndpoint {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -7144,12 +8041,20 @@ pub struct ProtocolEndpoints {
     pub token: Option<ProtocolEndpoint>,
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "userInfo")]
     pub user_info: Option<ProtocolEndpoint>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ProtocolRelayState {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub format: Option<ProtocolRelayStateFormat>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -7161,8 +8066,9 @@ pub enum ProtocolRelayStateFormat {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ProtocolRelayStateFormat {
@@ -7171,7 +8077,7 @@ impl std::fmt::Display for ProtocolRelayStateFormat {
             ProtocolRelayStateFormat::FromUrl => "FROM_URL",
             ProtocolRelayStateFormat::Opaque => "OPAQUE",
             ProtocolRelayStateFormat::Noop => "",
-            ProtocolRelayStateFormat::FallthroughString => "*",
+            ProtocolRelayStateFormat::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -7192,6 +8098,10 @@ pub struct ProtocolSettings {
         rename = "nameFormat"
     )]
     pub name_format: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -7205,8 +8115,9 @@ pub enum ProvisioningAction {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ProvisioningAction {
@@ -7216,7 +8127,7 @@ impl std::fmt::Display for ProvisioningAction {
             ProvisioningAction::Callout => "CALLOUT",
             ProvisioningAction::Disabled => "DISABLED",
             ProvisioningAction::Noop => "",
-            ProvisioningAction::FallthroughString => "*",
+            ProvisioningAction::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -7242,6 +8153,10 @@ pub struct Provisioning {
         rename = "profileMaster"
     )]
     pub profile_master: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -7250,6 +8165,10 @@ pub struct ProvisioningConditions {
     pub deprovisioned: Option<ProvisioningDeprovisionedCondition>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub suspended: Option<ProvisioningSuspendedCondition>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -7261,8 +8180,9 @@ pub enum ProvisioningDeprovisionedConditionAction {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ProvisioningDeprovisionedConditionAction {
@@ -7271,7 +8191,7 @@ impl std::fmt::Display for ProvisioningDeprovisionedConditionAction {
             ProvisioningDeprovisionedConditionAction::None => "NONE",
             ProvisioningDeprovisionedConditionAction::Reactivate => "REACTIVATE",
             ProvisioningDeprovisionedConditionAction::Noop => "",
-            ProvisioningDeprovisionedConditionAction::FallthroughString => "*",
+            ProvisioningDeprovisionedConditionAction::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -7287,6 +8207,10 @@ impl ProvisioningDeprovisionedConditionAction {
 pub struct ProvisioningDeprovisionedCondition {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub action: Option<ProvisioningDeprovisionedConditionAction>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -7302,8 +8226,9 @@ pub enum ProvisioningGroupsAction {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ProvisioningGroupsAction {
@@ -7314,7 +8239,7 @@ impl std::fmt::Display for ProvisioningGroupsAction {
             ProvisioningGroupsAction::None => "NONE",
             ProvisioningGroupsAction::Sync => "SYNC",
             ProvisioningGroupsAction::Noop => "",
-            ProvisioningGroupsAction::FallthroughString => "*",
+            ProvisioningGroupsAction::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -7349,6 +8274,10 @@ pub struct ProvisioningGroups {
         rename = "sourceAttributeName"
     )]
     pub source_attribute_name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -7360,8 +8289,9 @@ pub enum ProvisioningSuspendedConditionAction {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ProvisioningSuspendedConditionAction {
@@ -7370,7 +8300,7 @@ impl std::fmt::Display for ProvisioningSuspendedConditionAction {
             ProvisioningSuspendedConditionAction::None => "NONE",
             ProvisioningSuspendedConditionAction::Unsuspend => "UNSUSPEND",
             ProvisioningSuspendedConditionAction::Noop => "",
-            ProvisioningSuspendedConditionAction::FallthroughString => "*",
+            ProvisioningSuspendedConditionAction::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -7386,6 +8316,10 @@ impl ProvisioningSuspendedConditionAction {
 pub struct ProvisioningSuspendedCondition {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub action: Option<ProvisioningSuspendedConditionAction>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -7405,6 +8339,10 @@ pub struct PushUserFactor {
     pub factor_result: Option<FactorResultType>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub profile: Option<PushUserFactorProfile>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -7448,6 +8386,10 @@ pub struct PushUserFactorProfile {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub version: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -7464,6 +8406,10 @@ pub struct RecoveryQuestionCredential {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub question: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -7474,6 +8420,10 @@ pub struct RiskPolicyRuleCondition {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub behaviors: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -7484,6 +8434,10 @@ pub struct RiskScorePolicyRuleCondition {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub level: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -7533,6 +8487,10 @@ pub struct Role {
     pub status: Option<RoleStatus>,
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
     pub type_: Option<RoleType>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -7544,8 +8502,9 @@ pub enum RoleAssignmentType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for RoleAssignmentType {
@@ -7554,7 +8513,7 @@ impl std::fmt::Display for RoleAssignmentType {
             RoleAssignmentType::Group => "GROUP",
             RoleAssignmentType::User => "USER",
             RoleAssignmentType::Noop => "",
-            RoleAssignmentType::FallthroughString => "*",
+            RoleAssignmentType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -7589,8 +8548,9 @@ pub enum RoleType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for RoleType {
@@ -7606,7 +8566,7 @@ impl std::fmt::Display for RoleType {
             RoleType::SuperAdmin => "SUPER_ADMIN",
             RoleType::UserAdmin => "USER_ADMIN",
             RoleType::Noop => "",
-            RoleType::FallthroughString => "*",
+            RoleType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -7622,12 +8582,20 @@ impl RoleType {
 pub struct SamlApplication {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub settings: Option<SamlApplicationSettings>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct SamlApplicationSettings {
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "signOn")]
     pub sign_on: Option<SamlApplicationSettingsSignOn>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -7800,6 +8768,10 @@ pub struct SamlApplicationSettingsSignOn {
         rename = "subjectNameIdTemplate"
     )]
     pub subject_name_id_template: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -7843,6 +8815,10 @@ pub struct SamlAttributeStatement {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub values: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -7866,8 +8842,9 @@ pub enum Value {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Value {
@@ -7882,7 +8859,7 @@ impl std::fmt::Display for Value {
             Value::Pending => "PENDING",
             Value::Suspended => "SUSPENDED",
             Value::Noop => "",
-            Value::FallthroughString => "*",
+            Value::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -7898,6 +8875,10 @@ impl Value {
 pub struct ScheduledUserLifecycleAction {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub status: Option<Value>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -7921,6 +8902,10 @@ pub struct SchemeApplicationCredentials {
         rename = "userName"
     )]
     pub user_name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -7934,6 +8919,10 @@ pub struct ScopeData {
     pub string_value: String,
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
     pub type_: Option<ScopeType>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -7945,8 +8934,9 @@ pub enum ScopeType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ScopeType {
@@ -7955,7 +8945,7 @@ impl std::fmt::Display for ScopeType {
             ScopeType::Cors => "CORS",
             ScopeType::Redirect => "REDIRECT",
             ScopeType::Noop => "",
-            ScopeType::FallthroughString => "*",
+            ScopeType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -7975,12 +8965,20 @@ pub struct SecurePasswordStoreApplication {
     pub name: Option<serde_json::Value>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub settings: Option<SecurePasswordStoreApplicationSettings>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct SecurePasswordStoreApplicationSettings {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub app: Option<SecurePasswordStoreApplicationSettingsData>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -8047,6 +9045,10 @@ pub struct SecurePasswordStoreApplicationSettingsData {
         rename = "usernameField"
     )]
     pub username_field: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -8070,12 +9072,20 @@ pub struct SecurityQuestion {
         rename = "questionText"
     )]
     pub question_text: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct SecurityQuestionUserFactor {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub profile: Option<SecurityQuestion>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -8139,6 +9149,10 @@ pub struct Session {
         rename = "userId"
     )]
     pub user_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -8166,8 +9180,9 @@ pub enum SessionAuthenticationMethod {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SessionAuthenticationMethod {
@@ -8184,7 +9199,7 @@ impl std::fmt::Display for SessionAuthenticationMethod {
             SessionAuthenticationMethod::Swk => "swk",
             SessionAuthenticationMethod::Tel => "tel",
             SessionAuthenticationMethod::Noop => "",
-            SessionAuthenticationMethod::FallthroughString => "*",
+            SessionAuthenticationMethod::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -8206,6 +9221,10 @@ pub struct SessionIdentityProvider {
     pub id: String,
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
     pub type_: Option<SessionIdentityProviderType>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -8223,8 +9242,9 @@ pub enum SessionIdentityProviderType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SessionIdentityProviderType {
@@ -8236,7 +9256,7 @@ impl std::fmt::Display for SessionIdentityProviderType {
             SessionIdentityProviderType::Okta => "OKTA",
             SessionIdentityProviderType::Social => "SOCIAL",
             SessionIdentityProviderType::Noop => "",
-            SessionIdentityProviderType::FallthroughString => "*",
+            SessionIdentityProviderType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -8259,8 +9279,9 @@ pub enum SessionStatus {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SessionStatus {
@@ -8270,7 +9291,7 @@ impl std::fmt::Display for SessionStatus {
             SessionStatus::MfaEnroll => "MFA_ENROLL",
             SessionStatus::MfaRequired => "MFA_REQUIRED",
             SessionStatus::Noop => "",
-            SessionStatus::FallthroughString => "*",
+            SessionStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -8290,6 +9311,10 @@ pub struct SignOnInlineHook {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -8312,6 +9337,10 @@ pub struct SingleLogout {
         rename = "logoutUrl"
     )]
     pub logout_url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -8351,6 +9380,10 @@ pub struct SmsTemplate {
     pub translations: Option<Links>,
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
     pub type_: Option<SmsTemplateType>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -8360,8 +9393,9 @@ pub enum SmsTemplateType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SmsTemplateType {
@@ -8369,7 +9403,7 @@ impl std::fmt::Display for SmsTemplateType {
         match self {
             SmsTemplateType::SmsVerifyCode => "SMS_VERIFY_CODE",
             SmsTemplateType::Noop => "",
-            SmsTemplateType::FallthroughString => "*",
+            SmsTemplateType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -8385,6 +9419,10 @@ impl SmsTemplateType {
 pub struct SmsUserFactor {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub profile: Option<SmsUserFactorProfile>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -8396,6 +9434,10 @@ pub struct SmsUserFactorProfile {
         rename = "phoneNumber"
     )]
     pub phone_number: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -8407,8 +9449,9 @@ pub enum TokenType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for TokenType {
@@ -8417,7 +9460,7 @@ impl std::fmt::Display for TokenType {
             TokenType::Access => "ACCESS",
             TokenType::Refresh => "REFRESH",
             TokenType::Noop => "",
-            TokenType::FallthroughString => "*",
+            TokenType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -8465,6 +9508,10 @@ pub struct SocialAuthToken {
     pub token_auth_scheme: String,
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "tokenType")]
     pub token_type: Option<TokenType>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -8476,6 +9523,10 @@ pub struct SpCertificate {
         rename = "x5c"
     )]
     pub x_5c: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -8484,12 +9535,20 @@ pub struct SwaApplication {
     pub name: Option<serde_json::Value>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub settings: Option<SwaApplicationSettings>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct SwaApplicationSettings {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub app: Option<SwaApplicationSettingsData>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -8528,6 +9587,10 @@ pub struct SwaApplicationSettingsData {
         rename = "usernameField"
     )]
     pub username_field: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -8536,12 +9599,20 @@ pub struct SwaThreeFieldApplication {
     pub name: Option<serde_json::Value>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub settings: Option<SwaThreeFieldApplicationSettings>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct SwaThreeFieldApplicationSettings {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub app: Option<SwaThreeFieldApplicationSettingsData>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -8595,6 +9666,10 @@ pub struct SwaThreeFieldApplicationSettingsData {
         rename = "userNameSelector"
     )]
     pub user_name_selector: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -8606,6 +9681,10 @@ pub struct TempPassword {
         rename = "tempPassword"
     )]
     pub temp_password: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -8638,6 +9717,10 @@ pub struct ThreatInsightConfiguration {
         rename = "lastUpdated"
     )]
     pub last_updated: Option<chrono::DateTime<chrono::Utc>>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -8669,6 +9752,10 @@ pub struct TokenAuthorizationServerPolicyRuleAction {
         rename = "refreshTokenWindowMinutes"
     )]
     pub refresh_token_window_minutes: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -8679,18 +9766,30 @@ pub struct TokenAuthorizationServerPolicyRuleActionInlineHook {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct TokenUserFactor {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub profile: Option<WebUserFactorProfile>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct TotpUserFactor {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub profile: Option<WebUserFactorProfile>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -8754,12 +9853,20 @@ pub struct TrustedOrigin {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub status: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct U2FUserFactor {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub profile: Option<WebUserFactorProfile>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -8828,6 +9935,10 @@ pub struct User {
     pub transitioning_to_status: Option<UserStatus>,
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
     pub type_: Option<UserType>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -8846,6 +9957,10 @@ pub struct UserActivationToken {
         rename = "activationUrl"
     )]
     pub activation_url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -8856,6 +9971,10 @@ pub struct UserCredentials {
     pub provider: Option<AuthenticationProvider>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub recovery_question: Option<RecoveryQuestionCredential>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -8895,6 +10014,10 @@ pub struct UserFactor {
     pub status: Option<FactorStatus>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub verify: Option<VerifyFactorRequest>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -8912,8 +10035,9 @@ pub enum UserIdentifierConditionEvaluatorPatternMatchType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for UserIdentifierConditionEvaluatorPatternMatchType {
@@ -8925,7 +10049,7 @@ impl std::fmt::Display for UserIdentifierConditionEvaluatorPatternMatchType {
             UserIdentifierConditionEvaluatorPatternMatchType::StartsWith => "STARTS_WITH",
             UserIdentifierConditionEvaluatorPatternMatchType::Suffix => "SUFFIX",
             UserIdentifierConditionEvaluatorPatternMatchType::Noop => "",
-            UserIdentifierConditionEvaluatorPatternMatchType::FallthroughString => "*",
+            UserIdentifierConditionEvaluatorPatternMatchType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -8947,6 +10071,10 @@ pub struct UserIdentifierConditionEvaluatorPattern {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub value: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -8958,8 +10086,9 @@ pub enum UserIdentifierPolicyRuleConditionType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for UserIdentifierPolicyRuleConditionType {
@@ -8968,7 +10097,7 @@ impl std::fmt::Display for UserIdentifierPolicyRuleConditionType {
             UserIdentifierPolicyRuleConditionType::Attribute => "ATTRIBUTE",
             UserIdentifierPolicyRuleConditionType::Identifier => "IDENTIFIER",
             UserIdentifierPolicyRuleConditionType::Noop => "",
-            UserIdentifierPolicyRuleConditionType::FallthroughString => "*",
+            UserIdentifierPolicyRuleConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -8996,6 +10125,10 @@ pub struct UserIdentifierPolicyRuleCondition {
     pub patterns: Vec<UserIdentifierConditionEvaluatorPattern>,
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
     pub type_: Option<UserIdentifierPolicyRuleConditionType>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -9007,6 +10140,10 @@ pub struct UserIdentityProviderLinkRequest {
         rename = "externalId"
     )]
     pub external_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -9025,6 +10162,10 @@ pub struct UserLifecycleAttributePolicyRuleCondition {
         rename = "matchingValue"
     )]
     pub matching_value: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -9034,8 +10175,9 @@ pub enum UserNextLogin {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for UserNextLogin {
@@ -9043,7 +10185,7 @@ impl std::fmt::Display for UserNextLogin {
         match self {
             UserNextLogin::ChangePassword => "changePassword",
             UserNextLogin::Noop => "",
-            UserNextLogin::FallthroughString => "*",
+            UserNextLogin::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -9089,6 +10231,10 @@ pub struct UserPolicyRuleCondition {
         rename = "userLifecycleAttribute"
     )]
     pub user_lifecycle_attribute: Option<UserLifecycleAttributePolicyRuleCondition>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -9382,6 +10528,10 @@ pub struct UserProfile {
         rename = "zipCode"
     )]
     pub zip_code: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -9437,6 +10587,10 @@ pub struct UserSchema {
         rename = "type"
     )]
     pub type_: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -9534,6 +10688,10 @@ pub struct UserSchemaAttribute {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub unique: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -9551,6 +10709,10 @@ pub struct UserSchemaAttributeEnum {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub title: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -9576,6 +10738,10 @@ pub struct UserSchemaAttributeItems {
         rename = "type"
     )]
     pub type_: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -9588,6 +10754,10 @@ pub struct UserSchemaAttributeMaster {
     pub priority: Vec<UserSchemaAttributeMasterPriority>,
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
     pub type_: Option<UserSchemaAttributeMasterType>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -9605,6 +10775,10 @@ pub struct UserSchemaAttributeMasterPriority {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub value: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -9618,8 +10792,9 @@ pub enum UserSchemaAttributeMasterType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for UserSchemaAttributeMasterType {
@@ -9629,7 +10804,7 @@ impl std::fmt::Display for UserSchemaAttributeMasterType {
             UserSchemaAttributeMasterType::Override => "OVERRIDE",
             UserSchemaAttributeMasterType::ProfileMaster => "PROFILE_MASTER",
             UserSchemaAttributeMasterType::Noop => "",
-            UserSchemaAttributeMasterType::FallthroughString => "*",
+            UserSchemaAttributeMasterType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -9655,6 +10830,10 @@ pub struct UserSchemaAttributePermission {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub principal: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -9672,8 +10851,9 @@ pub enum UserSchemaAttributeType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for UserSchemaAttributeType {
@@ -9685,7 +10865,7 @@ impl std::fmt::Display for UserSchemaAttributeType {
             UserSchemaAttributeType::Number => "number",
             UserSchemaAttributeType::String => "string",
             UserSchemaAttributeType::Noop => "",
-            UserSchemaAttributeType::FallthroughString => "*",
+            UserSchemaAttributeType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -9720,6 +10900,10 @@ pub struct UserSchemaBase {
         rename = "type"
     )]
     pub type_: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -9842,6 +11026,10 @@ pub struct UserSchemaBaseProperties {
     pub user_type: Option<UserSchemaAttribute>,
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "zipCode")]
     pub zip_code: Option<UserSchemaAttribute>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -9850,12 +11038,20 @@ pub struct UserSchemaDefinitions {
     pub base: Option<UserSchemaBase>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub custom: Option<UserSchemaPublic>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UserSchemaProperties {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub profile: Option<UserSchemaPropertiesProfile>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -9867,6 +11063,10 @@ pub struct UserSchemaPropertiesProfile {
         rename = "allOf"
     )]
     pub all_of: Vec<UserSchemaPropertiesProfileItem>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -9878,6 +11078,10 @@ pub struct UserSchemaPropertiesProfileItem {
         rename = "$ref"
     )]
     pub ref_: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -9903,6 +11107,10 @@ pub struct UserSchemaPublic {
         rename = "type"
     )]
     pub type_: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -9926,8 +11134,9 @@ pub enum UserStatus {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for UserStatus {
@@ -9942,7 +11151,7 @@ impl std::fmt::Display for UserStatus {
             UserStatus::Staged => "STAGED",
             UserStatus::Suspended => "SUSPENDED",
             UserStatus::Noop => "",
-            UserStatus::FallthroughString => "*",
+            UserStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -9958,6 +11167,10 @@ impl UserStatus {
 pub struct UserStatusPolicyRuleCondition {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub value: Option<Value>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -10021,6 +11234,10 @@ pub struct UserType {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -10079,6 +11296,10 @@ pub struct VerifyFactorRequest {
         rename = "stateToken"
     )]
     pub state_token: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -10106,8 +11327,9 @@ pub enum FactorResult {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for FactorResult {
@@ -10124,7 +11346,7 @@ impl std::fmt::Display for FactorResult {
             FactorResult::TimeWindowExceeded => "TIME_WINDOW_EXCEEDED",
             FactorResult::Waiting => "WAITING",
             FactorResult::Noop => "",
-            FactorResult::FallthroughString => "*",
+            FactorResult::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -10162,12 +11384,20 @@ pub struct VerifyUserFactorResponse {
         rename = "factorResultMessage"
     )]
     pub factor_result_message: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct WebAuthnUserFactor {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub profile: Option<WebAuthnUserFactorProfile>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -10186,12 +11416,20 @@ pub struct WebAuthnUserFactorProfile {
         rename = "credentialId"
     )]
     pub credential_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct WebUserFactor {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub profile: Option<WebUserFactorProfile>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -10200,12 +11438,20 @@ pub struct WsFederationApplication {
     pub name: Option<serde_json::Value>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub settings: Option<WsFederationApplicationSettings>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct WsFederationApplicationSettings {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub app: Option<WsFederationApplicationSettingsData>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -10292,4 +11538,8 @@ pub struct WsFederationApplicationSettingsData {
         rename = "wReplyURL"
     )]
     pub w_reply_url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
@@ -1,6 +1,155 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`UserFactors::enroll_factor`], set with a builder.
+pub struct UserFactorsEnrollFactorRequest<'a> {
+    client: &'a UserFactors,
+    user_id: &'a str,
+    update_phone: Option<bool>,
+    template_id: Option<&'a str>,
+    token_lifetime_seconds: Option<i64>,
+    activate: Option<bool>,
+    body: &'a crate::types::UserFactor,
+}
+
+impl<'a> UserFactorsEnrollFactorRequest<'a> {
+    /// Sets the `updatePhone` query parameter.
+    pub fn update_phone(mut self, update_phone: bool) -> Self {
+        self.update_phone = Some(update_phone);
+        self
+    }
+
+    /// Sets the `templateId` query parameter.
+    pub fn template_id(mut self, template_id: &'a str) -> Self {
+        self.template_id = Some(template_id);
+        self
+    }
+
+    /// Sets the `tokenLifetimeSeconds` query parameter.
+    pub fn token_lifetime_seconds(mut self, token_lifetime_seconds: i64) -> Self {
+        self.token_lifetime_seconds = Some(token_lifetime_seconds);
+        self
+    }
+
+    /// Sets the `activate` query parameter.
+    pub fn activate(mut self, activate: bool) -> Self {
+        self.activate = Some(activate);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::UserFactor>> {
+        let Self {
+            client,
+            user_id,
+            update_phone,
+            template_id,
+            token_lifetime_seconds,
+            activate,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = activate {
+            query_args.push(("activate".to_string(), v.to_string()));
+        }
+        if let Some(v) = template_id {
+            query_args.push(("templateId".to_string(), v.to_string()));
+        }
+        if let Some(v) = token_lifetime_seconds {
+            query_args.push(("tokenLifetimeSeconds".to_string(), v.to_string()));
+        }
+        if let Some(v) = update_phone {
+            query_args.push(("updatePhone".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/api/v1/users/{}/factors?{}",
+                crate::progenitor_support::encode_path(user_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`UserFactors::verify_factor`], set with a builder.
+pub struct UserFactorsVerifyFactorRequest<'a> {
+    client: &'a UserFactors,
+    user_id: &'a str,
+    factor_id: &'a str,
+    template_id: Option<&'a str>,
+    token_lifetime_seconds: Option<i64>,
+    body: &'a crate::types::VerifyFactorRequest,
+}
+
+impl<'a> UserFactorsVerifyFactorRequest<'a> {
+    /// Sets the `templateId` query parameter.
+    pub fn template_id(mut self, template_id: &'a str) -> Self {
+        self.template_id = Some(template_id);
+        self
+    }
+
+    /// Sets the `tokenLifetimeSeconds` query parameter.
+    pub fn token_lifetime_seconds(mut self, token_lifetime_seconds: i64) -> Self {
+        self.token_lifetime_seconds = Some(token_lifetime_seconds);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::VerifyUserFactorResponse>> {
+        let Self {
+            client,
+            user_id,
+            factor_id,
+            template_id,
+            token_lifetime_seconds,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = template_id {
+            query_args.push(("templateId".to_string(), v.to_string()));
+        }
+        if let Some(v) = token_lifetime_seconds {
+            query_args.push(("tokenLifetimeSeconds".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/api/v1/users/{}/factors/{}/verify?{}",
+                crate::progenitor_support::encode_path(user_id),
+                crate::progenitor_support::encode_path(factor_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
 pub struct UserFactors {
     pub client: Client,
 }
@@ -69,6 +218,26 @@ impl UserFactors {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/users/{userId}/factors` endpoint.
+     *
+     * As opposed to `list_all_factors`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Enumerates all the enrolled factors for the specified user
+     */
+    pub fn list_all_factors_stream(
+        &self,
+        user_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::UserFactor>> + Unpin + '_ {
+        let url = self.client.url(
+            &format!(
+                "/api/v1/users/{}/factors",
+                crate::progenitor_support::encode_path(user_id),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Enroll Factor.
      *
@@ -128,6 +297,30 @@ impl UserFactors {
             )
             .await
     }
+    /**
+     * Enroll Factor.
+     *
+     * This function performs a `POST` to the `/api/v1/users/{userId}/factors` endpoint.
+     *
+     * As opposed to `enroll_factor`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Enrolls a user with a supported factor.
+     */
+    pub fn enroll_factor_request<'a>(
+        &'a self,
+        user_id: &'a str,
+        body: &'a crate::types::UserFactor,
+    ) -> UserFactorsEnrollFactorRequest<'a> {
+        UserFactorsEnrollFactorRequest {
+            client: self,
+            user_id,
+            update_phone: None,
+            template_id: None,
+            token_lifetime_seconds: None,
+            activate: None,
+            body,
+        }
+    }
     /**
      * This function performs a `GET` to the `/api/v1/users/{userId}/factors/catalog` endpoint.
      *
@@ -186,6 +379,26 @@ impl UserFactors {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/users/{userId}/factors/catalog` endpoint.
+     *
+     * As opposed to `list_all_supported_factors`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Enumerates all the supported factors that can be enrolled for the specified user
+     */
+    pub fn list_all_supported_factors_stream(
+        &self,
+        user_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::UserFactor>> + Unpin + '_ {
+        let url = self.client.url(
+            &format!(
+                "/api/v1/users/{}/factors/catalog",
+                crate::progenitor_support::encode_path(user_id),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * This function performs a `GET` to the `/api/v1/users/{userId}/factors/questions` endpoint.
      *
@@ -244,6 +457,27 @@ impl UserFactors {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/users/{userId}/factors/questions` endpoint.
+     *
+     * As opposed to `list_all_supported_security_questions`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Enumerates all available security questions for a user's `question` factor
+     */
+    pub fn list_all_supported_security_questions_stream(
+        &self,
+        user_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::SecurityQuestion>> + Unpin + '_
+    {
+        let url = self.client.url(
+            &format!(
+                "/api/v1/users/{}/factors/questions",
+                crate::progenitor_support::encode_path(user_id),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * This function performs a `GET` to the `/api/v1/users/{userId}/factors/{factorId}` endpoint.
      *
@@ -437,4 +671,28 @@ impl UserFactors {
             )
             .await
     }
+    /**
+     * Verify MFA Factor.
+     *
+     * This function performs a `POST` to the `/api/v1/users/{userId}/factors/{factorId}/verify` endpoint.
+     *
+     * As opposed to `verify_factor`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Verifies an OTP for a `token` or `token:hardware` factor
+     */
+    pub fn verify_factor_request<'a>(
+        &'a self,
+        user_id: &'a str,
+        factor_id: &'a str,
+        body: &'a crate::types::VerifyFactorRequest,
+    ) -> UserFactorsVerifyFactorRequest<'a> {
+        UserFactorsVerifyFactorRequest {
+            client: self,
+            user_id,
+            factor_id,
+            template_id: None,
+            token_lifetime_seconds: None,
+            body,
+        }
+    }
 }
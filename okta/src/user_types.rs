@@ -47,6 +47,19 @@ impl UserTypes {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/meta/types/user` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Fetches all User Types in your org
+     */
+    pub fn list_all_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::UserType>> + Unpin + '_ {
+        let url = self.client.url("/api/v1/meta/types/user", None);
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * This function performs a `POST` to the `/api/v1/meta/types/user` endpoint.
      *
@@ -1,6 +1,1168 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Users::list`], set with a builder.
+pub struct UsersListRequest<'a> {
+    client: &'a Users,
+    q: Option<&'a str>,
+    after: Option<&'a str>,
+    limit: Option<i64>,
+    filter: Option<&'a str>,
+    search: Option<&'a str>,
+    sort_by: Option<&'a str>,
+    sort_order: Option<&'a str>,
+}
+
+impl<'a> UsersListRequest<'a> {
+    /// Sets the `q` query parameter.
+    pub fn q(mut self, q: &'a str) -> Self {
+        self.q = Some(q);
+        self
+    }
+
+    /// Sets the `after` query parameter.
+    pub fn after(mut self, after: &'a str) -> Self {
+        self.after = Some(after);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `filter` query parameter.
+    pub fn filter(mut self, filter: &'a str) -> Self {
+        self.filter = Some(filter);
+        self
+    }
+
+    /// Sets the `search` query parameter.
+    pub fn search(mut self, search: &'a str) -> Self {
+        self.search = Some(search);
+        self
+    }
+
+    /// Sets the `sortBy` query parameter.
+    pub fn sort_by(mut self, sort_by: &'a str) -> Self {
+        self.sort_by = Some(sort_by);
+        self
+    }
+
+    /// Sets the `sortOrder` query parameter.
+    pub fn sort_order(mut self, sort_order: &'a str) -> Self {
+        self.sort_order = Some(sort_order);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::User>>> {
+        let Self {
+            client,
+            q,
+            after,
+            limit,
+            filter,
+            search,
+            sort_by,
+            sort_order,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = after {
+            query_args.push(("after".to_string(), v.to_string()));
+        }
+        if let Some(v) = filter {
+            query_args.push(("filter".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = q {
+            query_args.push(("q".to_string(), v.to_string()));
+        }
+        if let Some(v) = search {
+            query_args.push(("search".to_string(), v.to_string()));
+        }
+        if let Some(v) = sort_by {
+            query_args.push(("sortBy".to_string(), v.to_string()));
+        }
+        if let Some(v) = sort_order {
+            query_args.push(("sortOrder".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/api/v1/users?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Users::create`], set with a builder.
+pub struct UsersCreateRequest<'a> {
+    client: &'a Users,
+    activate: Option<bool>,
+    provider: Option<bool>,
+    next_login: Option<&'a str>,
+    body: &'a crate::types::CreateUserRequest,
+}
+
+impl<'a> UsersCreateRequest<'a> {
+    /// Sets the `activate` query parameter.
+    pub fn activate(mut self, activate: bool) -> Self {
+        self.activate = Some(activate);
+        self
+    }
+
+    /// Sets the `provider` query parameter.
+    pub fn provider(mut self, provider: bool) -> Self {
+        self.provider = Some(provider);
+        self
+    }
+
+    /// Sets the `nextLogin` query parameter.
+    pub fn next_login(mut self, next_login: &'a str) -> Self {
+        self.next_login = Some(next_login);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::User>> {
+        let Self {
+            client,
+            activate,
+            provider,
+            next_login,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = activate {
+            query_args.push(("activate".to_string(), v.to_string()));
+        }
+        if let Some(v) = next_login {
+            query_args.push(("nextLogin".to_string(), v.to_string()));
+        }
+        if let Some(v) = provider {
+            query_args.push(("provider".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/api/v1/users?{}", query_), None);
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Users::update`], set with a builder.
+pub struct UsersUpdateRequest<'a> {
+    client: &'a Users,
+    user_id: &'a str,
+    strict: Option<bool>,
+    body: &'a crate::types::User,
+}
+
+impl<'a> UsersUpdateRequest<'a> {
+    /// Sets the `strict` query parameter.
+    pub fn strict(mut self, strict: bool) -> Self {
+        self.strict = Some(strict);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::User>> {
+        let Self {
+            client,
+            user_id,
+            strict,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = strict {
+            query_args.push(("strict".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/api/v1/users/{}?{}",
+                crate::progenitor_support::encode_path(user_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .put(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Users::partial_update`], set with a builder.
+pub struct UsersPartialUpdateRequest<'a> {
+    client: &'a Users,
+    user_id: &'a str,
+    strict: Option<bool>,
+    body: &'a crate::types::User,
+}
+
+impl<'a> UsersPartialUpdateRequest<'a> {
+    /// Sets the `strict` query parameter.
+    pub fn strict(mut self, strict: bool) -> Self {
+        self.strict = Some(strict);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::User>> {
+        let Self {
+            client,
+            user_id,
+            strict,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = strict {
+            query_args.push(("strict".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/api/v1/users/{}?{}",
+                crate::progenitor_support::encode_path(user_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Users::deactivate_or_delete`], set with a builder.
+pub struct UsersDeactivateOrDeleteRequest<'a> {
+    client: &'a Users,
+    user_id: &'a str,
+    send_email: Option<bool>,
+}
+
+impl<'a> UsersDeactivateOrDeleteRequest<'a> {
+    /// Sets the `sendEmail` query parameter.
+    pub fn send_email(mut self, send_email: bool) -> Self {
+        self.send_email = Some(send_email);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            user_id,
+            send_email,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = send_email {
+            query_args.push(("sendEmail".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/api/v1/users/{}?{}",
+                crate::progenitor_support::encode_path(user_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .delete(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Users::list_grants_for_and_clients`], set with a builder.
+pub struct UsersListGrantsForAndClientsRequest<'a> {
+    client: &'a Users,
+    user_id: &'a str,
+    client_id: &'a str,
+    expand: Option<&'a str>,
+    after: Option<&'a str>,
+    limit: Option<i64>,
+}
+
+impl<'a> UsersListGrantsForAndClientsRequest<'a> {
+    /// Sets the `expand` query parameter.
+    pub fn expand(mut self, expand: &'a str) -> Self {
+        self.expand = Some(expand);
+        self
+    }
+
+    /// Sets the `after` query parameter.
+    pub fn after(mut self, after: &'a str) -> Self {
+        self.after = Some(after);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::OAuth2ScopeConsentGrant>>> {
+        let Self {
+            client,
+            user_id,
+            client_id,
+            expand,
+            after,
+            limit,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = after {
+            query_args.push(("after".to_string(), v.to_string()));
+        }
+        if let Some(v) = expand {
+            query_args.push(("expand".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/api/v1/users/{}/clients/{}/grants?{}",
+                crate::progenitor_support::encode_path(user_id),
+                crate::progenitor_support::encode_path(client_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Users::list_refresh_tokens_for_and_clients`], set with a builder.
+pub struct UsersListRefreshTokensForAndClientsRequest<'a> {
+    client: &'a Users,
+    user_id: &'a str,
+    client_id: &'a str,
+    expand: Option<&'a str>,
+    after: Option<&'a str>,
+    limit: Option<i64>,
+}
+
+impl<'a> UsersListRefreshTokensForAndClientsRequest<'a> {
+    /// Sets the `expand` query parameter.
+    pub fn expand(mut self, expand: &'a str) -> Self {
+        self.expand = Some(expand);
+        self
+    }
+
+    /// Sets the `after` query parameter.
+    pub fn after(mut self, after: &'a str) -> Self {
+        self.after = Some(after);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::OAuth2RefreshToken>>> {
+        let Self {
+            client,
+            user_id,
+            client_id,
+            expand,
+            after,
+            limit,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = after {
+            query_args.push(("after".to_string(), v.to_string()));
+        }
+        if let Some(v) = expand {
+            query_args.push(("expand".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/api/v1/users/{}/clients/{}/tokens?{}",
+                crate::progenitor_support::encode_path(user_id),
+                crate::progenitor_support::encode_path(client_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Users::get_refresh_token_for_and_client`], set with a builder.
+pub struct UsersGetRefreshTokenForAndClientRequest<'a> {
+    client: &'a Users,
+    user_id: &'a str,
+    client_id: &'a str,
+    token_id: &'a str,
+    expand: Option<&'a str>,
+    limit: Option<i64>,
+    after: Option<&'a str>,
+}
+
+impl<'a> UsersGetRefreshTokenForAndClientRequest<'a> {
+    /// Sets the `expand` query parameter.
+    pub fn expand(mut self, expand: &'a str) -> Self {
+        self.expand = Some(expand);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `after` query parameter.
+    pub fn after(mut self, after: &'a str) -> Self {
+        self.after = Some(after);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::OAuth2RefreshToken>> {
+        let Self {
+            client,
+            user_id,
+            client_id,
+            token_id,
+            expand,
+            limit,
+            after,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = after {
+            query_args.push(("after".to_string(), v.to_string()));
+        }
+        if let Some(v) = expand {
+            query_args.push(("expand".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/api/v1/users/{}/clients/{}/tokens/{}?{}",
+                crate::progenitor_support::encode_path(user_id),
+                crate::progenitor_support::encode_path(client_id),
+                crate::progenitor_support::encode_path(token_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Users::change_password`], set with a builder.
+pub struct UsersChangePasswordRequest<'a> {
+    client: &'a Users,
+    user_id: &'a str,
+    strict: Option<bool>,
+    body: &'a crate::types::ChangePasswordRequest,
+}
+
+impl<'a> UsersChangePasswordRequest<'a> {
+    /// Sets the `strict` query parameter.
+    pub fn strict(mut self, strict: bool) -> Self {
+        self.strict = Some(strict);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::UserCredentials>> {
+        let Self {
+            client,
+            user_id,
+            strict,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = strict {
+            query_args.push(("strict".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/api/v1/users/{}/credentials/change_password?{}",
+                crate::progenitor_support::encode_path(user_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Users::list_grants`], set with a builder.
+pub struct UsersListGrantsRequest<'a> {
+    client: &'a Users,
+    user_id: &'a str,
+    scope_id: Option<&'a str>,
+    expand: Option<&'a str>,
+    after: Option<&'a str>,
+    limit: Option<i64>,
+}
+
+impl<'a> UsersListGrantsRequest<'a> {
+    /// Sets the `scopeId` query parameter.
+    pub fn scope_id(mut self, scope_id: &'a str) -> Self {
+        self.scope_id = Some(scope_id);
+        self
+    }
+
+    /// Sets the `expand` query parameter.
+    pub fn expand(mut self, expand: &'a str) -> Self {
+        self.expand = Some(expand);
+        self
+    }
+
+    /// Sets the `after` query parameter.
+    pub fn after(mut self, after: &'a str) -> Self {
+        self.after = Some(after);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::OAuth2ScopeConsentGrant>>> {
+        let Self {
+            client,
+            user_id,
+            scope_id,
+            expand,
+            after,
+            limit,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = after {
+            query_args.push(("after".to_string(), v.to_string()));
+        }
+        if let Some(v) = expand {
+            query_args.push(("expand".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = scope_id {
+            query_args.push(("scopeId".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/api/v1/users/{}/grants?{}",
+                crate::progenitor_support::encode_path(user_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Users::get_grant`], set with a builder.
+pub struct UsersGetGrantRequest<'a> {
+    client: &'a Users,
+    user_id: &'a str,
+    grant_id: &'a str,
+    expand: Option<&'a str>,
+}
+
+impl<'a> UsersGetGrantRequest<'a> {
+    /// Sets the `expand` query parameter.
+    pub fn expand(mut self, expand: &'a str) -> Self {
+        self.expand = Some(expand);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::OAuth2ScopeConsentGrant>> {
+        let Self {
+            client,
+            user_id,
+            grant_id,
+            expand,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = expand {
+            query_args.push(("expand".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/api/v1/users/{}/grants/{}?{}",
+                crate::progenitor_support::encode_path(user_id),
+                crate::progenitor_support::encode_path(grant_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Users::deactivate`], set with a builder.
+pub struct UsersDeactivateRequest<'a> {
+    client: &'a Users,
+    user_id: &'a str,
+    send_email: Option<bool>,
+}
+
+impl<'a> UsersDeactivateRequest<'a> {
+    /// Sets the `sendEmail` query parameter.
+    pub fn send_email(mut self, send_email: bool) -> Self {
+        self.send_email = Some(send_email);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            user_id,
+            send_email,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = send_email {
+            query_args.push(("sendEmail".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/api/v1/users/{}/lifecycle/deactivate?{}",
+                crate::progenitor_support::encode_path(user_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Users::reactivate`], set with a builder.
+pub struct UsersReactivateRequest<'a> {
+    client: &'a Users,
+    user_id: &'a str,
+    send_email: Option<bool>,
+}
+
+impl<'a> UsersReactivateRequest<'a> {
+    /// Sets the `sendEmail` query parameter.
+    pub fn send_email(mut self, send_email: bool) -> Self {
+        self.send_email = Some(send_email);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::UserActivationToken>> {
+        let Self {
+            client,
+            user_id,
+            send_email,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = send_email {
+            query_args.push(("sendEmail".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/api/v1/users/{}/lifecycle/reactivate?{}",
+                crate::progenitor_support::encode_path(user_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Users::get_linked_objects_fors`], set with a builder.
+pub struct UsersGetLinkedObjectsForsRequest<'a> {
+    client: &'a Users,
+    user_id: &'a str,
+    relationship_name: &'a str,
+    after: Option<&'a str>,
+    limit: Option<i64>,
+}
+
+impl<'a> UsersGetLinkedObjectsForsRequest<'a> {
+    /// Sets the `after` query parameter.
+    pub fn after(mut self, after: &'a str) -> Self {
+        self.after = Some(after);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Links>>> {
+        let Self {
+            client,
+            user_id,
+            relationship_name,
+            after,
+            limit,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = after {
+            query_args.push(("after".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/api/v1/users/{}/linkedObjects/{}?{}",
+                crate::progenitor_support::encode_path(user_id),
+                crate::progenitor_support::encode_path(relationship_name),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Users::list_assigned_roles_fors`], set with a builder.
+pub struct UsersListAssignedRolesForsRequest<'a> {
+    client: &'a Users,
+    user_id: &'a str,
+    expand: Option<&'a str>,
+}
+
+impl<'a> UsersListAssignedRolesForsRequest<'a> {
+    /// Sets the `expand` query parameter.
+    pub fn expand(mut self, expand: &'a str) -> Self {
+        self.expand = Some(expand);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Role>>> {
+        let Self {
+            client,
+            user_id,
+            expand,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = expand {
+            query_args.push(("expand".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/api/v1/users/{}/roles?{}",
+                crate::progenitor_support::encode_path(user_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Users::assign_role`], set with a builder.
+pub struct UsersAssignRoleRequest<'a> {
+    client: &'a Users,
+    user_id: &'a str,
+    disable_notifications: Option<&'a str>,
+    body: &'a crate::types::AssignRoleRequest,
+}
+
+impl<'a> UsersAssignRoleRequest<'a> {
+    /// Sets the `disableNotifications` query parameter.
+    pub fn disable_notifications(mut self, disable_notifications: &'a str) -> Self {
+        self.disable_notifications = Some(disable_notifications);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::Role>> {
+        let Self {
+            client,
+            user_id,
+            disable_notifications,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = disable_notifications {
+            query_args.push(("disableNotifications".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/api/v1/users/{}/roles?{}",
+                crate::progenitor_support::encode_path(user_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Users::list_application_targets_for_administrator_roles`], set with a builder.
+pub struct UsersListApplicationTargetsForAdministratorRolesRequest<'a> {
+    client: &'a Users,
+    user_id: &'a str,
+    role_id: &'a str,
+    after: Option<&'a str>,
+    limit: Option<i64>,
+}
+
+impl<'a> UsersListApplicationTargetsForAdministratorRolesRequest<'a> {
+    /// Sets the `after` query parameter.
+    pub fn after(mut self, after: &'a str) -> Self {
+        self.after = Some(after);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::CatalogApplication>>> {
+        let Self {
+            client,
+            user_id,
+            role_id,
+            after,
+            limit,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = after {
+            query_args.push(("after".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/api/v1/users/{}/roles/{}/targets/catalog/apps?{}",
+                crate::progenitor_support::encode_path(user_id),
+                crate::progenitor_support::encode_path(role_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Users::list_group_targets_for_roles`], set with a builder.
+pub struct UsersListGroupTargetsForRolesRequest<'a> {
+    client: &'a Users,
+    user_id: &'a str,
+    role_id: &'a str,
+    after: Option<&'a str>,
+    limit: Option<i64>,
+}
+
+impl<'a> UsersListGroupTargetsForRolesRequest<'a> {
+    /// Sets the `after` query parameter.
+    pub fn after(mut self, after: &'a str) -> Self {
+        self.after = Some(after);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Group>>> {
+        let Self {
+            client,
+            user_id,
+            role_id,
+            after,
+            limit,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = after {
+            query_args.push(("after".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/api/v1/users/{}/roles/{}/targets/groups?{}",
+                crate::progenitor_support::encode_path(user_id),
+                crate::progenitor_support::encode_path(role_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Users::clear_sessions`], set with a builder.
+pub struct UsersClearSessionsRequest<'a> {
+    client: &'a Users,
+    user_id: &'a str,
+    oauth_tokens: Option<bool>,
+}
+
+impl<'a> UsersClearSessionsRequest<'a> {
+    /// Sets the `oauthTokens` query parameter.
+    pub fn oauth_tokens(mut self, oauth_tokens: bool) -> Self {
+        self.oauth_tokens = Some(oauth_tokens);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            user_id,
+            oauth_tokens,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = oauth_tokens {
+            query_args.push(("oauthTokens".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/api/v1/users/{}/sessions?{}",
+                crate::progenitor_support::encode_path(user_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .delete(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct Users {
     pub client: Client,
 }
@@ -72,6 +1234,27 @@ impl Users {
             )
             .await
     }
+    /**
+     * List Users.
+     *
+     * This function performs a `GET` to the `/api/v1/users` endpoint.
+     *
+     * As opposed to `list`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Lists users in your organization with pagination in most cases.  A subset of users can be returned that match a supported filter expression or search criteria.
+     */
+    pub fn list_request(&self) -> UsersListRequest<'_> {
+        UsersListRequest {
+            client: self,
+            q: None,
+            after: None,
+            limit: None,
+            filter: None,
+            search: None,
+            sort_by: None,
+            sort_order: None,
+        }
+    }
     /**
      * List Users.
      *
@@ -117,6 +1300,43 @@ impl Users {
             )
             .await
     }
+    /**
+     * List Users.
+     *
+     * This function performs a `GET` to the `/api/v1/users` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Lists users in your organization with pagination in most cases.  A subset of users can be returned that match a supported filter expression or search criteria.
+     */
+    pub fn list_all_stream(
+        &self,
+        q: &str,
+        filter: &str,
+        search: &str,
+        sort_by: &str,
+        sort_order: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::User>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !filter.is_empty() {
+            query_args.push(("filter".to_string(), filter.to_string()));
+        }
+        if !q.is_empty() {
+            query_args.push(("q".to_string(), q.to_string()));
+        }
+        if !search.is_empty() {
+            query_args.push(("search".to_string(), search.to_string()));
+        }
+        if !sort_by.is_empty() {
+            query_args.push(("sortBy".to_string(), sort_by.to_string()));
+        }
+        if !sort_order.is_empty() {
+            query_args.push(("sortOrder".to_string(), sort_order.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/api/v1/users?{}", query_), None);
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Create User.
      *
@@ -159,6 +1379,27 @@ impl Users {
             )
             .await
     }
+    /**
+     * Create User.
+     *
+     * This function performs a `POST` to the `/api/v1/users` endpoint.
+     *
+     * As opposed to `create`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Creates a new user in your Okta organization with or without credentials.
+     */
+    pub fn create_request<'a>(
+        &'a self,
+        body: &'a crate::types::CreateUserRequest,
+    ) -> UsersCreateRequest<'a> {
+        UsersCreateRequest {
+            client: self,
+            activate: None,
+            provider: None,
+            next_login: None,
+            body,
+        }
+    }
     /**
      * This function performs a `PUT` to the `/api/v1/users/{associatedUserId}/linkedObjects/{primaryRelationshipName}/{primaryUserId}` endpoint.
      *
@@ -263,6 +1504,27 @@ impl Users {
             )
             .await
     }
+    /**
+     * Update User.
+     *
+     * This function performs a `PUT` to the `/api/v1/users/{userId}` endpoint.
+     *
+     * As opposed to `update`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Update a user's profile and/or credentials using strict-update semantics.
+     */
+    pub fn update_request<'a>(
+        &'a self,
+        user_id: &'a str,
+        body: &'a crate::types::User,
+    ) -> UsersUpdateRequest<'a> {
+        UsersUpdateRequest {
+            client: self,
+            user_id,
+            strict: None,
+            body,
+        }
+    }
     /**
      * This function performs a `POST` to the `/api/v1/users/{userId}` endpoint.
      *
@@ -302,6 +1564,25 @@ impl Users {
             )
             .await
     }
+    /**
+     * This function performs a `POST` to the `/api/v1/users/{userId}` endpoint.
+     *
+     * As opposed to `partial_update`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Fetch a user by `id`, `login`, or `login shortname` if the short name is unambiguous.
+     */
+    pub fn partial_update_request<'a>(
+        &'a self,
+        user_id: &'a str,
+        body: &'a crate::types::User,
+    ) -> UsersPartialUpdateRequest<'a> {
+        UsersPartialUpdateRequest {
+            client: self,
+            user_id,
+            strict: None,
+            body,
+        }
+    }
     /**
      * Delete User.
      *
@@ -342,6 +1623,25 @@ impl Users {
             )
             .await
     }
+    /**
+     * Delete User.
+     *
+     * This function performs a `DELETE` to the `/api/v1/users/{userId}` endpoint.
+     *
+     * As opposed to `deactivate_or_delete`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Deletes a user permanently.  This operation can only be performed on users that have a `DEPROVISIONED` status.  **This action cannot be recovered!**
+     */
+    pub fn deactivate_or_delete_request<'a>(
+        &'a self,
+        user_id: &'a str,
+    ) -> UsersDeactivateOrDeleteRequest<'a> {
+        UsersDeactivateOrDeleteRequest {
+            client: self,
+            user_id,
+            send_email: None,
+        }
+    }
     /**
      * Get Assigned App Links.
      *
@@ -404,6 +1704,28 @@ impl Users {
             )
             .await
     }
+    /**
+     * Get Assigned App Links.
+     *
+     * This function performs a `GET` to the `/api/v1/users/{userId}/appLinks` endpoint.
+     *
+     * As opposed to `list_all_app_links`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Fetches appLinks for all direct or indirect (via group membership) assigned applications.
+     */
+    pub fn list_all_app_links_stream(
+        &self,
+        user_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::AppLink>> + Unpin + '_ {
+        let url = self.client.url(
+            &format!(
+                "/api/v1/users/{}/appLinks",
+                crate::progenitor_support::encode_path(user_id),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * This function performs a `GET` to the `/api/v1/users/{userId}/clients` endpoint.
      *
@@ -462,6 +1784,26 @@ impl Users {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/users/{userId}/clients` endpoint.
+     *
+     * As opposed to `list_all_clients`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Lists all client resources for which the specified user has grants or tokens.
+     */
+    pub fn list_all_clients_stream(
+        &self,
+        user_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::OAuth2Client>> + Unpin + '_ {
+        let url = self.client.url(
+            &format!(
+                "/api/v1/users/{}/clients",
+                crate::progenitor_support::encode_path(user_id),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * This function performs a `GET` to the `/api/v1/users/{userId}/clients/{clientId}/grants` endpoint.
      *
@@ -513,6 +1855,27 @@ impl Users {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/users/{userId}/clients/{clientId}/grants` endpoint.
+     *
+     * As opposed to `list_grants_for_and_clients`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Lists all grants for a specified user and client
+     */
+    pub fn list_grants_for_and_clients_request<'a>(
+        &'a self,
+        user_id: &'a str,
+        client_id: &'a str,
+    ) -> UsersListGrantsForAndClientsRequest<'a> {
+        UsersListGrantsForAndClientsRequest {
+            client: self,
+            user_id,
+            client_id,
+            expand: None,
+            after: None,
+            limit: None,
+        }
+    }
     /**
      * This function performs a `GET` to the `/api/v1/users/{userId}/clients/{clientId}/grants` endpoint.
      *
@@ -550,6 +1913,36 @@ impl Users {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/users/{userId}/clients/{clientId}/grants` endpoint.
+     *
+     * As opposed to `list_all_grants_for_and_clients`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Lists all grants for a specified user and client
+     */
+    pub fn list_all_grants_for_and_clients_stream(
+        &self,
+        user_id: &str,
+        client_id: &str,
+        expand: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::OAuth2ScopeConsentGrant>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !expand.is_empty() {
+            query_args.push(("expand".to_string(), expand.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/api/v1/users/{}/clients/{}/grants?{}",
+                crate::progenitor_support::encode_path(user_id),
+                crate::progenitor_support::encode_path(client_id),
+                query_
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * This function performs a `DELETE` to the `/api/v1/users/{userId}/clients/{clientId}/grants` endpoint.
      *
@@ -625,7 +2018,65 @@ impl Users {
             None,
         );
         self.client
-            .get(
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/users/{userId}/clients/{clientId}/tokens` endpoint.
+     *
+     * As opposed to `list_refresh_tokens_for_and_clients`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Lists all refresh tokens issued for the specified User and Client.
+     */
+    pub fn list_refresh_tokens_for_and_clients_request<'a>(
+        &'a self,
+        user_id: &'a str,
+        client_id: &'a str,
+    ) -> UsersListRefreshTokensForAndClientsRequest<'a> {
+        UsersListRefreshTokensForAndClientsRequest {
+            client: self,
+            user_id,
+            client_id,
+            expand: None,
+            after: None,
+            limit: None,
+        }
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/users/{userId}/clients/{clientId}/tokens` endpoint.
+     *
+     * As opposed to `list_refresh_tokens_for_and_client`, this function returns all the pages of the request at once.
+     *
+     * Lists all refresh tokens issued for the specified User and Client.
+     */
+    pub async fn list_all_refresh_tokens_for_and_clients(
+        &self,
+        user_id: &str,
+        client_id: &str,
+        expand: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::OAuth2RefreshToken>>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !expand.is_empty() {
+            query_args.push(("expand".to_string(), expand.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/api/v1/users/{}/clients/{}/tokens?{}",
+                crate::progenitor_support::encode_path(user_id),
+                crate::progenitor_support::encode_path(client_id),
+                query_
+            ),
+            None,
+        );
+        self.client
+            .get_all_pages(
                 &url,
                 crate::Message {
                     body: None,
@@ -637,16 +2088,17 @@ impl Users {
     /**
      * This function performs a `GET` to the `/api/v1/users/{userId}/clients/{clientId}/tokens` endpoint.
      *
-     * As opposed to `list_refresh_tokens_for_and_client`, this function returns all the pages of the request at once.
+     * As opposed to `list_all_refresh_tokens_for_and_clients`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
      *
      * Lists all refresh tokens issued for the specified User and Client.
      */
-    pub async fn list_all_refresh_tokens_for_and_clients(
+    pub fn list_all_refresh_tokens_for_and_clients_stream(
         &self,
         user_id: &str,
         client_id: &str,
         expand: &str,
-    ) -> ClientResult<crate::Response<Vec<crate::types::OAuth2RefreshToken>>> {
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::OAuth2RefreshToken>> + Unpin + '_
+    {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !expand.is_empty() {
             query_args.push(("expand".to_string(), expand.to_string()));
@@ -661,15 +2113,7 @@ impl Users {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(url)
     }
     /**
      * This function performs a `DELETE` to the `/api/v1/users/{userId}/clients/{clientId}/tokens` endpoint.
@@ -758,6 +2202,29 @@ impl Users {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/users/{userId}/clients/{clientId}/tokens/{tokenId}` endpoint.
+     *
+     * As opposed to `get_refresh_token_for_and_client`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Gets a refresh token issued for the specified User and Client.
+     */
+    pub fn get_refresh_token_for_and_client_request<'a>(
+        &'a self,
+        user_id: &'a str,
+        client_id: &'a str,
+        token_id: &'a str,
+    ) -> UsersGetRefreshTokenForAndClientRequest<'a> {
+        UsersGetRefreshTokenForAndClientRequest {
+            client: self,
+            user_id,
+            client_id,
+            token_id,
+            expand: None,
+            limit: None,
+            after: None,
+        }
+    }
     /**
      * This function performs a `DELETE` to the `/api/v1/users/{userId}/clients/{clientId}/tokens/{tokenId}` endpoint.
      *
@@ -835,6 +2302,27 @@ impl Users {
             )
             .await
     }
+    /**
+     * Change Password.
+     *
+     * This function performs a `POST` to the `/api/v1/users/{userId}/credentials/change_password` endpoint.
+     *
+     * As opposed to `change_password`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Changes a user's password by validating the user's current password. This operation can only be performed on users in `STAGED`, `ACTIVE`, `PASSWORD_EXPIRED`, or `RECOVERY` status that have a valid password credential
+     */
+    pub fn change_password_request<'a>(
+        &'a self,
+        user_id: &'a str,
+        body: &'a crate::types::ChangePasswordRequest,
+    ) -> UsersChangePasswordRequest<'a> {
+        UsersChangePasswordRequest {
+            client: self,
+            user_id,
+            strict: None,
+            body,
+        }
+    }
     /**
      * Change Recovery Question.
      *
@@ -951,6 +2439,23 @@ impl Users {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/users/{userId}/grants` endpoint.
+     *
+     * As opposed to `list_grants`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Lists all grants for the specified user
+     */
+    pub fn list_grants_request<'a>(&'a self, user_id: &'a str) -> UsersListGrantsRequest<'a> {
+        UsersListGrantsRequest {
+            client: self,
+            user_id,
+            scope_id: None,
+            expand: None,
+            after: None,
+            limit: None,
+        }
+    }
     /**
      * This function performs a `GET` to the `/api/v1/users/{userId}/grants` endpoint.
      *
@@ -990,6 +2495,38 @@ impl Users {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/users/{userId}/grants` endpoint.
+     *
+     * As opposed to `list_all_grants`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Lists all grants for the specified user
+     */
+    pub fn list_all_grants_stream(
+        &self,
+        user_id: &str,
+        scope_id: &str,
+        expand: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::OAuth2ScopeConsentGrant>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !expand.is_empty() {
+            query_args.push(("expand".to_string(), expand.to_string()));
+        }
+        if !scope_id.is_empty() {
+            query_args.push(("scopeId".to_string(), scope_id.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/api/v1/users/{}/grants?{}",
+                crate::progenitor_support::encode_path(user_id),
+                query_
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * This function performs a `DELETE` to the `/api/v1/users/{userId}/grants` endpoint.
      *
@@ -1058,6 +2595,25 @@ impl Users {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/users/{userId}/grants/{grantId}` endpoint.
+     *
+     * As opposed to `get_grant`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Gets a grant for the specified user
+     */
+    pub fn get_grant_request<'a>(
+        &'a self,
+        user_id: &'a str,
+        grant_id: &'a str,
+    ) -> UsersGetGrantRequest<'a> {
+        UsersGetGrantRequest {
+            client: self,
+            user_id,
+            grant_id,
+            expand: None,
+        }
+    }
     /**
      * This function performs a `DELETE` to the `/api/v1/users/{userId}/grants/{grantId}` endpoint.
      *
@@ -1153,6 +2709,28 @@ impl Users {
             )
             .await
     }
+    /**
+     * Get Member Groups.
+     *
+     * This function performs a `GET` to the `/api/v1/users/{userId}/groups` endpoint.
+     *
+     * As opposed to `list_all_groups`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Fetches the groups of which the user is a member.
+     */
+    pub fn list_all_groups_stream(
+        &self,
+        user_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Group>> + Unpin + '_ {
+        let url = self.client.url(
+            &format!(
+                "/api/v1/users/{}/groups",
+                crate::progenitor_support::encode_path(user_id),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Listing IdPs associated with a user.
      *
@@ -1215,6 +2793,29 @@ impl Users {
             )
             .await
     }
+    /**
+     * Listing IdPs associated with a user.
+     *
+     * This function performs a `GET` to the `/api/v1/users/{userId}/idps` endpoint.
+     *
+     * As opposed to `list_all_identity_providers`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Lists the IdPs associated with the user.
+     */
+    pub fn list_all_identity_providers_stream(
+        &self,
+        user_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::IdentityProvider>> + Unpin + '_
+    {
+        let url = self.client.url(
+            &format!(
+                "/api/v1/users/{}/idps",
+                crate::progenitor_support::encode_path(user_id),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Activate User.
      *
@@ -1295,6 +2896,22 @@ impl Users {
             )
             .await
     }
+    /**
+     * Deactivate User.
+     *
+     * This function performs a `POST` to the `/api/v1/users/{userId}/lifecycle/deactivate` endpoint.
+     *
+     * As opposed to `deactivate`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Deactivates a user.  This operation can only be performed on users that do not have a `DEPROVISIONED` status.  Deactivation of a user is an asynchronous operation.  The user will have the `transitioningToStatus` property with a value of `DEPROVISIONED` during deactivation to indicate that the user hasn't completed the asynchronous operation.  The user will have a status of `DEPROVISIONED` when the deactivation process is complete.
+     */
+    pub fn deactivate_request<'a>(&'a self, user_id: &'a str) -> UsersDeactivateRequest<'a> {
+        UsersDeactivateRequest {
+            client: self,
+            user_id,
+            send_email: None,
+        }
+    }
     /**
      * Expire Password.
      *
@@ -1399,6 +3016,22 @@ impl Users {
             )
             .await
     }
+    /**
+     * Reactivate User.
+     *
+     * This function performs a `POST` to the `/api/v1/users/{userId}/lifecycle/reactivate` endpoint.
+     *
+     * As opposed to `reactivate`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Reactivates a user.  This operation can only be performed on users with a `PROVISIONED` status.  This operation restarts the activation workflow if for some reason the user activation was not completed when using the activationToken from [Activate User](#activate-user).
+     */
+    pub fn reactivate_request<'a>(&'a self, user_id: &'a str) -> UsersReactivateRequest<'a> {
+        UsersReactivateRequest {
+            client: self,
+            user_id,
+            send_email: None,
+        }
+    }
     /**
      * Reset Factors.
      *
@@ -1601,6 +3234,26 @@ impl Users {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/users/{userId}/linkedObjects/{relationshipName}` endpoint.
+     *
+     * As opposed to `get_linked_objects_fors`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get linked objects for a user, relationshipName can be a primary or associated relationship name
+     */
+    pub fn get_linked_objects_fors_request<'a>(
+        &'a self,
+        user_id: &'a str,
+        relationship_name: &'a str,
+    ) -> UsersGetLinkedObjectsForsRequest<'a> {
+        UsersGetLinkedObjectsForsRequest {
+            client: self,
+            user_id,
+            relationship_name,
+            after: None,
+            limit: None,
+        }
+    }
     /**
      * This function performs a `GET` to the `/api/v1/users/{userId}/linkedObjects/{relationshipName}` endpoint.
      *
@@ -1631,6 +3284,28 @@ impl Users {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/users/{userId}/linkedObjects/{relationshipName}` endpoint.
+     *
+     * As opposed to `get_all_linked_objects_fors`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Get linked objects for a user, relationshipName can be a primary or associated relationship name
+     */
+    pub fn get_all_linked_objects_fors_stream(
+        &self,
+        user_id: &str,
+        relationship_name: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Links>> + Unpin + '_ {
+        let url = self.client.url(
+            &format!(
+                "/api/v1/users/{}/linkedObjects/{}",
+                crate::progenitor_support::encode_path(user_id),
+                crate::progenitor_support::encode_path(relationship_name),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * This function performs a `DELETE` to the `/api/v1/users/{userId}/linkedObjects/{relationshipName}` endpoint.
      *
@@ -1702,6 +3377,23 @@ impl Users {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/users/{userId}/roles` endpoint.
+     *
+     * As opposed to `list_assigned_roles_fors`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Lists all roles assigned to a user.
+     */
+    pub fn list_assigned_roles_fors_request<'a>(
+        &'a self,
+        user_id: &'a str,
+    ) -> UsersListAssignedRolesForsRequest<'a> {
+        UsersListAssignedRolesForsRequest {
+            client: self,
+            user_id,
+            expand: None,
+        }
+    }
     /**
      * This function performs a `GET` to the `/api/v1/users/{userId}/roles` endpoint.
      *
@@ -1737,6 +3429,33 @@ impl Users {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/users/{userId}/roles` endpoint.
+     *
+     * As opposed to `list_all_assigned_roles_fors`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Lists all roles assigned to a user.
+     */
+    pub fn list_all_assigned_roles_fors_stream(
+        &self,
+        user_id: &str,
+        expand: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Role>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !expand.is_empty() {
+            query_args.push(("expand".to_string(), expand.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/api/v1/users/{}/roles?{}",
+                crate::progenitor_support::encode_path(user_id),
+                query_
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * This function performs a `POST` to the `/api/v1/users/{userId}/roles` endpoint.
      *
@@ -1779,6 +3498,25 @@ impl Users {
             )
             .await
     }
+    /**
+     * This function performs a `POST` to the `/api/v1/users/{userId}/roles` endpoint.
+     *
+     * As opposed to `assign_role`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Assigns a role to a user.
+     */
+    pub fn assign_role_request<'a>(
+        &'a self,
+        user_id: &'a str,
+        body: &'a crate::types::AssignRoleRequest,
+    ) -> UsersAssignRoleRequest<'a> {
+        UsersAssignRoleRequest {
+            client: self,
+            user_id,
+            disable_notifications: None,
+            body,
+        }
+    }
     /**
      * This function performs a `DELETE` to the `/api/v1/users/{userId}/roles/{roleId}` endpoint.
      *
@@ -1858,6 +3596,26 @@ impl Users {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/users/{userId}/roles/{roleId}/targets/catalog/apps` endpoint.
+     *
+     * As opposed to `list_application_targets_for_administrator_roles`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Lists all App targets for an `APP_ADMIN` Role assigned to a User. This methods return list may include full Applications or Instances. The response for an instance will have an `ID` value, while Application will not have an ID.
+     */
+    pub fn list_application_targets_for_administrator_roles_request<'a>(
+        &'a self,
+        user_id: &'a str,
+        role_id: &'a str,
+    ) -> UsersListApplicationTargetsForAdministratorRolesRequest<'a> {
+        UsersListApplicationTargetsForAdministratorRolesRequest {
+            client: self,
+            user_id,
+            role_id,
+            after: None,
+            limit: None,
+        }
+    }
     /**
      * This function performs a `GET` to the `/api/v1/users/{userId}/roles/{roleId}/targets/catalog/apps` endpoint.
      *
@@ -1888,6 +3646,29 @@ impl Users {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/users/{userId}/roles/{roleId}/targets/catalog/apps` endpoint.
+     *
+     * As opposed to `list_all_application_targets_for_administrator_roles`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Lists all App targets for an `APP_ADMIN` Role assigned to a User. This methods return list may include full Applications or Instances. The response for an instance will have an `ID` value, while Application will not have an ID.
+     */
+    pub fn list_all_application_targets_for_administrator_roles_stream(
+        &self,
+        user_id: &str,
+        role_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::CatalogApplication>> + Unpin + '_
+    {
+        let url = self.client.url(
+            &format!(
+                "/api/v1/users/{}/roles/{}/targets/catalog/apps",
+                crate::progenitor_support::encode_path(user_id),
+                crate::progenitor_support::encode_path(role_id),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * This function performs a `PUT` to the `/api/v1/users/{userId}/roles/{roleId}/targets/catalog/apps` endpoint.
      *
@@ -2121,6 +3902,26 @@ impl Users {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/users/{userId}/roles/{roleId}/targets/groups` endpoint.
+     *
+     * As opposed to `list_group_targets_for_roles`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Success
+     */
+    pub fn list_group_targets_for_roles_request<'a>(
+        &'a self,
+        user_id: &'a str,
+        role_id: &'a str,
+    ) -> UsersListGroupTargetsForRolesRequest<'a> {
+        UsersListGroupTargetsForRolesRequest {
+            client: self,
+            user_id,
+            role_id,
+            after: None,
+            limit: None,
+        }
+    }
     /**
      * This function performs a `GET` to the `/api/v1/users/{userId}/roles/{roleId}/targets/groups` endpoint.
      *
@@ -2151,6 +3952,28 @@ impl Users {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/users/{userId}/roles/{roleId}/targets/groups` endpoint.
+     *
+     * As opposed to `list_all_group_targets_for_roles`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Success
+     */
+    pub fn list_all_group_targets_for_roles_stream(
+        &self,
+        user_id: &str,
+        role_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Group>> + Unpin + '_ {
+        let url = self.client.url(
+            &format!(
+                "/api/v1/users/{}/roles/{}/targets/groups",
+                crate::progenitor_support::encode_path(user_id),
+                crate::progenitor_support::encode_path(role_id),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * This function performs a `PUT` to the `/api/v1/users/{userId}/roles/{roleId}/targets/groups/{groupId}` endpoint.
      *
@@ -2261,4 +4084,18 @@ impl Users {
             )
             .await
     }
+    /**
+     * This function performs a `DELETE` to the `/api/v1/users/{userId}/sessions` endpoint.
+     *
+     * As opposed to `clear_sessions`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Removes all active identity provider sessions. This forces the user to authenticate on the next operation. Optionally revokes OpenID Connect and OAuth refresh and access tokens issued to the user.
+     */
+    pub fn clear_sessions_request<'a>(&'a self, user_id: &'a str) -> UsersClearSessionsRequest<'a> {
+        UsersClearSessionsRequest {
+            client: self,
+            user_id,
+            oauth_tokens: None,
+        }
+    }
 }
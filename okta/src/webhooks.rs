@@ -0,0 +1,554 @@
+//! For verifying and parsing the event hooks and inline hooks Okta sends to an
+//! endpoint, and building the commands inline hooks respond with.
+//!
+//! FROM: <https://developer.okta.com/docs/concepts/event-hooks/>
+//! FROM: <https://developer.okta.com/docs/concepts/inline-hooks/>
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::types;
+
+/// The header Okta sends the one-time verification challenge of an event hook in.
+pub const VERIFICATION_HEADER: &str = "x-okta-verification-challenge";
+
+/// The header hooks are authenticated with by default.
+pub const DEFAULT_AUTH_HEADER: &str = "Authorization";
+
+/// Errors returned while verifying or parsing a hook request.
+#[derive(Debug, thiserror::Error)]
+pub enum WebhookError {
+    /// A header the request needs is missing
+    #[error("missing {0} header")]
+    MissingHeader(&'static str),
+    /// The header the secret of the hook is sent in is missing. It is named by the
+    /// hook, with `Webhook::with_header`, so it is kept apart from `MissingHeader`.
+    #[error("missing {0} authorization header")]
+    MissingAuthHeader(String),
+    /// The authorization header does not match the secret of the hook
+    #[error("the authorization header does not match the secret")]
+    SecretMismatch,
+    /// The payload does not match the hook
+    #[error(transparent)]
+    SerdeJsonError(#[from] serde_json::Error),
+}
+
+/// The body of a request to an event hook or an inline hook.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default, JsonSchema)]
+pub struct HookRequest<T> {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "eventType"
+    )]
+    pub event_type: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "eventTypeVersion"
+    )]
+    pub event_type_version: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "cloudEventsVersion"
+    )]
+    pub cloud_events_version: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub source: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "eventId"
+    )]
+    pub event_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize",
+        rename = "eventTime"
+    )]
+    pub event_time: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "contentType"
+    )]
+    pub content_type: String,
+    pub data: T,
+}
+
+/// The body of a delivery of an event hook.
+pub type EventHookDelivery = HookRequest<EventHookData>;
+
+/// The events of a delivery of an event hook, as those of the System Log.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default, JsonSchema)]
+pub struct EventHookData {
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub events: Vec<types::LogEvent>,
+}
+
+/// The response to the one-time verification request of an event hook.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default, JsonSchema)]
+pub struct VerificationResponse {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub verification: String,
+}
+
+/// A request to an inline hook, by its type.
+///
+/// Types without a variant of their own are kept as JSON.
+#[derive(Debug, Clone, PartialEq)]
+pub enum InlineHookRequest {
+    Registration(Box<HookRequest<RegistrationHookData>>),
+    Saml(Box<HookRequest<SamlHookData>>),
+    Token(Box<HookRequest<TokenHookData>>),
+    Other(serde_json::Value),
+}
+
+/// The data of a request to a token inline hook, with the tokens about to be minted.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default, JsonSchema)]
+pub struct TokenHookData {
+    /// The request, protocol, session, user and policy the tokens are minted for.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub context: Option<serde_json::Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub identity: Option<HookToken>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub access: Option<HookToken>,
+}
+
+/// A token of a token inline hook request.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default, JsonSchema)]
+pub struct HookToken {
+    #[serde(default, skip_serializing_if = "serde_json::Map::is_empty")]
+    pub claims: serde_json::Map<String, serde_json::Value>,
+    /// The scopes granted to an access token.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scopes: Option<serde_json::Value>,
+    /// The lifetime of the token.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub token: Option<serde_json::Value>,
+}
+
+/// The data of a request to a registration inline hook, with the profile of the user
+/// signing up.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default, JsonSchema)]
+pub struct RegistrationHookData {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub context: Option<serde_json::Value>,
+    #[serde(
+        default,
+        skip_serializing_if = "serde_json::Map::is_empty",
+        rename = "userProfile"
+    )]
+    pub user_profile: serde_json::Map<String, serde_json::Value>,
+    /// What Okta does by default, `ALLOW` or `DENY`.
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub action: String,
+}
+
+/// The data of a request to a SAML inline hook, with the assertion about to be sent.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default, JsonSchema)]
+pub struct SamlHookData {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub context: Option<serde_json::Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub assertion: Option<SamlAssertion>,
+}
+
+/// The SAML assertion of a SAML inline hook request.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default, JsonSchema)]
+pub struct SamlAssertion {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subject: Option<serde_json::Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub authentication: Option<serde_json::Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub conditions: Option<serde_json::Value>,
+    #[serde(default, skip_serializing_if = "serde_json::Map::is_empty")]
+    pub claims: serde_json::Map<String, serde_json::Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lifetime: Option<serde_json::Value>,
+}
+
+/// The response of an inline hook, with the commands Okta should apply.
+///
+/// ```
+/// use okta::webhooks::{Command, CommandResponse, PatchOperation};
+///
+/// let response = CommandResponse::new().command(Command::patch_access(vec![
+///     PatchOperation::add("/claims/tier", "gold"),
+/// ]));
+/// ```
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default, JsonSchema)]
+pub struct CommandResponse {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub commands: Vec<Command>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<HookError>,
+    /// Extra information, written to the System Log events of the hook.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "debugContext"
+    )]
+    pub debug_context: Option<serde_json::Value>,
+}
+
+impl CommandResponse {
+    /// Create a new response, without commands.
+    pub fn new() -> Self {
+        CommandResponse::default()
+    }
+
+    /// Adds a command to the response.
+    pub fn command(mut self, command: Command) -> Self {
+        self.commands.push(command);
+        self
+    }
+
+    /// Sets the error of the response, which stops the flow the hook is part of.
+    pub fn error<S>(mut self, summary: S) -> Self
+    where
+        S: ToString,
+    {
+        self.error = Some(HookError {
+            error_summary: summary.to_string(),
+            error_causes: Vec::new(),
+        });
+        self
+    }
+
+    /// Sets extra information to write to the System Log events of the hook.
+    pub fn debug_context(mut self, debug_context: serde_json::Value) -> Self {
+        self.debug_context = Some(debug_context);
+        self
+    }
+}
+
+/// A command of an inline hook response.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default, JsonSchema)]
+pub struct Command {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "type"
+    )]
+    pub type_: String,
+    #[serde(default)]
+    pub value: serde_json::Value,
+}
+
+impl Command {
+    fn patch(type_: &str, operations: Vec<PatchOperation>) -> Self {
+        Command {
+            type_: type_.to_string(),
+            value: serde_json::to_value(operations).expect("patch operations are JSON"),
+        }
+    }
+
+    /// Patches the ID token of a token inline hook.
+    pub fn patch_identity(operations: Vec<PatchOperation>) -> Self {
+        Command::patch("com.okta.identity.patch", operations)
+    }
+
+    /// Patches the access token of a token inline hook.
+    pub fn patch_access(operations: Vec<PatchOperation>) -> Self {
+        Command::patch("com.okta.access.patch", operations)
+    }
+
+    /// Patches the assertion of a SAML inline hook.
+    pub fn patch_assertion(operations: Vec<PatchOperation>) -> Self {
+        Command::patch("com.okta.assertion.patch", operations)
+    }
+
+    /// Allows or denies the sign-up of a registration inline hook.
+    pub fn allow_registration(allow: bool) -> Self {
+        Command {
+            type_: "com.okta.action.update".to_string(),
+            value: serde_json::json!({
+                "registration": if allow { "ALLOW" } else { "DENY" },
+            }),
+        }
+    }
+
+    /// Updates attributes of the profile of the user signing up, in a registration
+    /// inline hook.
+    pub fn update_profile(profile: serde_json::Map<String, serde_json::Value>) -> Self {
+        Command {
+            type_: "com.okta.user.profile.update".to_string(),
+            value: serde_json::Value::Object(profile),
+        }
+    }
+}
+
+/// A JSON Patch operation, on a token or an assertion.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default, JsonSchema)]
+pub struct PatchOperation {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub op: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub path: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value: Option<serde_json::Value>,
+}
+
+impl PatchOperation {
+    fn new<V>(op: &str, path: &str, value: Option<V>) -> Self
+    where
+        V: Into<serde_json::Value>,
+    {
+        PatchOperation {
+            op: op.to_string(),
+            path: path.to_string(),
+            value: value.map(Into::into),
+        }
+    }
+
+    /// Adds a claim, or an element to an array with a `/-` path.
+    pub fn add<V>(path: &str, value: V) -> Self
+    where
+        V: Into<serde_json::Value>,
+    {
+        PatchOperation::new("add", path, Some(value))
+    }
+
+    /// Replaces an existing claim.
+    pub fn replace<V>(path: &str, value: V) -> Self
+    where
+        V: Into<serde_json::Value>,
+    {
+        PatchOperation::new("replace", path, Some(value))
+    }
+
+    /// Removes a claim.
+    pub fn remove(path: &str) -> Self {
+        PatchOperation::new::<serde_json::Value>("remove", path, None)
+    }
+}
+
+/// The error of an inline hook response.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default, JsonSchema)]
+pub struct HookError {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "errorSummary"
+    )]
+    pub error_summary: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
+        rename = "errorCauses"
+    )]
+    pub error_causes: Vec<HookErrorCause>,
+}
+
+/// A cause of the error of an inline hook response, like an invalid attribute of a
+/// registration.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default, JsonSchema)]
+pub struct HookErrorCause {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "errorSummary"
+    )]
+    pub error_summary: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub reason: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "locationType"
+    )]
+    pub location_type: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub location: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub domain: String,
+}
+
+/// Verifies the requests of a hook, with the secret of its authentication field.
+///
+/// Okta does not sign hook requests; it sends the secret the hook was registered
+/// with in a header instead.
+#[derive(Clone)]
+pub struct Webhook {
+    header: String,
+    secret: String,
+}
+
+impl Webhook {
+    /// Create a new verifier from the secret of the hook, sent in the `Authorization`
+    /// header.
+    pub fn new<S>(secret: S) -> Self
+    where
+        S: ToString,
+    {
+        Webhook {
+            header: DEFAULT_AUTH_HEADER.to_string(),
+            secret: secret.to_string(),
+        }
+    }
+
+    /// Sets the header the secret is sent in, the authentication field of the hook.
+    pub fn with_header<S>(&mut self, header: S) -> &mut Self
+    where
+        S: ToString,
+    {
+        self.header = header.to_string();
+        self
+    }
+
+    /// Verifies the authentication header of a request, from its headers.
+    ///
+    /// Okta sends the secret of the hook as is, in `Authorization` unless another
+    /// header was set with `with_header`.
+    pub fn verify<I, K, V>(&self, headers: I) -> Result<(), WebhookError>
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: AsRef<str>,
+        V: AsRef<[u8]>,
+    {
+        let [secret] = crate::utils::find_headers(headers, [self.header.as_str()]);
+        let secret =
+            secret.ok_or_else(|| WebhookError::MissingAuthHeader(self.header.to_string()))?;
+
+        if constant_time_eq(secret.as_bytes(), self.secret.as_bytes()) {
+            Ok(())
+        } else {
+            Err(WebhookError::SecretMismatch)
+        }
+    }
+
+    /// Verifies the one-time verification request Okta sends when an event hook is
+    /// registered, and returns the response echoing its challenge.
+    pub fn verification<I, K, V>(&self, headers: I) -> Result<VerificationResponse, WebhookError>
+    where
+        I: IntoIterator<Item = (K, V)> + Clone,
+        K: AsRef<str>,
+        V: AsRef<[u8]>,
+    {
+        self.verify(headers.clone())?;
+        let [challenge] = crate::utils::find_headers(headers, [VERIFICATION_HEADER]);
+        let challenge = challenge.ok_or(WebhookError::MissingHeader(VERIFICATION_HEADER))?;
+
+        Ok(verification_response(&challenge))
+    }
+
+    /// Verifies a delivery of an event hook and parses its body.
+    pub fn construct_event<I, K, V>(
+        &self,
+        headers: I,
+        payload: &[u8],
+    ) -> Result<EventHookDelivery, WebhookError>
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: AsRef<str>,
+        V: AsRef<[u8]>,
+    {
+        self.verify(headers)?;
+        parse_event(payload)
+    }
+
+    /// Verifies a request to an inline hook and parses its body.
+    pub fn construct_inline_hook<I, K, V>(
+        &self,
+        headers: I,
+        payload: &[u8],
+    ) -> Result<InlineHookRequest, WebhookError>
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: AsRef<str>,
+        V: AsRef<[u8]>,
+    {
+        self.verify(headers)?;
+        parse_inline_hook(payload)
+    }
+}
+
+/// Compares secrets without returning early, not to leak how much of one matches.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0, |diff, (a, b)| diff | (a ^ b)) == 0
+}
+
+/// Returns the response to the one-time verification request of an event hook, from
+/// the value of its `x-okta-verification-challenge` header.
+pub fn verification_response(challenge: &str) -> VerificationResponse {
+    VerificationResponse {
+        verification: challenge.trim().to_string(),
+    }
+}
+
+/// Parses the body of a delivery of an event hook, without verifying it.
+pub fn parse_event(payload: &[u8]) -> Result<EventHookDelivery, WebhookError> {
+    Ok(serde_json::from_slice(payload)?)
+}
+
+/// Parses the body of a request to an inline hook by its type, without verifying it.
+pub fn parse_inline_hook(payload: &[u8]) -> Result<InlineHookRequest, WebhookError> {
+    let request = serde_json::from_slice::<serde_json::Value>(payload)?;
+    let type_ = request
+        .get("eventType")
+        .and_then(|type_| serde_json::from_value(type_.clone()).ok())
+        .unwrap_or_else(|| types::InlineHookType::Unknown(String::new()));
+
+    Ok(match type_ {
+        types::InlineHookType::ComOktaOauth2TokensTransform => {
+            InlineHookRequest::Token(serde_json::from_value(request)?)
+        }
+        types::InlineHookType::ComOktaSamlTokensTransform => {
+            InlineHookRequest::Saml(serde_json::from_value(request)?)
+        }
+        types::InlineHookType::ComOktaUserPreRegistration => {
+            InlineHookRequest::Registration(serde_json::from_value(request)?)
+        }
+        _ => InlineHookRequest::Other(request),
+    })
+}
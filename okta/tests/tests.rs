@@ -1 +1,575 @@
+use std::{
+    mem,
+    time::{Duration, Instant, SystemTime},
+};
 
+use futures::StreamExt;
+use wiremock::{
+    matchers::{header, method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+use okta::{
+    webhooks::{
+        self, Command, CommandResponse, InlineHookRequest, PatchOperation, Webhook, WebhookError,
+    },
+    Client, ClientBuilder, ClientError, StatusCode,
+};
+
+#[tokio::test]
+async fn test_stream_follows_link_header() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v1/logs"))
+        .and(query_param("after", "1"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(serde_json::json!([{ "uuid": "2" }])),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v1/logs"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header(
+                    "link",
+                    format!("<{}/api/v1/logs?after=1>; rel=\"next\"", server.uri()).as_str(),
+                )
+                .set_body_json(serde_json::json!([{ "uuid": "1" }])),
+        )
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut client = Client::new("token");
+    client.with_host_override(server.uri());
+
+    let logs = client.logs();
+    let events = logs
+        .get_all_stream(None, None, "", "", "")
+        .map(|event| event.unwrap().uuid)
+        .collect::<Vec<_>>()
+        .await;
+
+    assert_eq!(events, vec!["1".to_string(), "2".to_string()]);
+
+    mem::drop(server)
+}
+
+async fn mount_logs_page(server: &MockServer, after: Option<&str>, uuid: &str, next: Option<&str>) {
+    let mut response =
+        ResponseTemplate::new(200).set_body_json(serde_json::json!([{ "uuid": uuid }]));
+    if let Some(next) = next {
+        response = response.insert_header(
+            "link",
+            format!(
+                "<{}/api/v1/logs?after={}>; rel=\"next\"",
+                server.uri(),
+                next
+            )
+            .as_str(),
+        );
+    }
+
+    // The first page matches any request, so it has to be mounted last.
+    let mock = Mock::given(method("GET")).and(path("/api/v1/logs"));
+    match after {
+        Some(after) => mock.and(query_param("after", after)).respond_with(response),
+        None => mock.respond_with(response).up_to_n_times(1),
+    }
+    .mount(server)
+    .await
+}
+
+#[tokio::test]
+async fn test_stream_fails_on_repeated_next_link() {
+    let server = MockServer::start().await;
+
+    mount_logs_page(&server, Some("1"), "2", Some("1")).await;
+    mount_logs_page(&server, None, "1", Some("1")).await;
+
+    let mut client = Client::new("token");
+    client.with_host_override(server.uri());
+
+    let logs = client.logs();
+    let mut events = logs
+        .get_all_stream(None, None, "", "", "")
+        .collect::<Vec<_>>()
+        .await;
+
+    assert!(matches!(
+        events.pop(),
+        Some(Err(ClientError::IncompletePages { page_token })) if page_token.ends_with("/api/v1/logs?after=1")
+    ));
+    let events = events
+        .into_iter()
+        .map(|event| event.unwrap().uuid)
+        .collect::<Vec<_>>();
+    assert_eq!(events, vec!["1".to_string(), "2".to_string()]);
+    assert_eq!(server.received_requests().await.unwrap().len(), 2);
+
+    mem::drop(server)
+}
+
+#[tokio::test]
+async fn test_get_all_respects_max_items() {
+    let server = MockServer::start().await;
+
+    mount_logs_page(&server, Some("1"), "2", Some("2")).await;
+    mount_logs_page(&server, Some("2"), "3", None).await;
+    mount_logs_page(&server, None, "1", Some("1")).await;
+
+    let mut client = Client::new("token");
+    client.with_host_override(server.uri()).with_max_items(2);
+
+    let events = client
+        .logs()
+        .get_all(None, None, "", "", "")
+        .await
+        .unwrap()
+        .body
+        .into_iter()
+        .map(|event| event.uuid)
+        .collect::<Vec<_>>();
+
+    assert_eq!(events, vec!["1".to_string(), "2".to_string()]);
+    assert_eq!(server.received_requests().await.unwrap().len(), 2);
+
+    mem::drop(server)
+}
+
+async fn get_all_logs(client: &Client) -> Result<Vec<String>, ClientError> {
+    let logs = client.logs().get_all(None, None, "", "", "").await?;
+    Ok(logs.body.into_iter().map(|event| event.uuid).collect())
+}
+
+#[tokio::test]
+async fn test_retry_honors_retry_after() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v1/logs"))
+        .respond_with(ResponseTemplate::new(429).insert_header("retry-after", "0"))
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v1/logs"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(serde_json::json!([{ "uuid": "1" }])),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    // The backoff alone would make the test wait for an hour.
+    let mut client = Client::new("token");
+    client
+        .with_host_override(server.uri())
+        .with_client_builder(
+            ClientBuilder::new().retry_bounds(Duration::from_secs(3600), Duration::from_secs(3600)),
+        )
+        .unwrap();
+
+    assert_eq!(get_all_logs(&client).await.unwrap(), vec!["1".to_string()]);
+
+    mem::drop(server)
+}
+
+#[tokio::test]
+async fn test_retryable_statuses_are_configurable() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v1/logs"))
+        .respond_with(ResponseTemplate::new(409))
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v1/logs"))
+        .respond_with(ResponseTemplate::new(503))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut client = Client::new("token");
+    client
+        .with_host_override(server.uri())
+        .with_client_builder(
+            ClientBuilder::new()
+                .retryable_statuses([StatusCode::CONFLICT])
+                .retry_bounds(Duration::from_millis(1), Duration::from_millis(10)),
+        )
+        .unwrap();
+
+    // The conflict is retried, the unavailable service that follows it is not.
+    assert!(get_all_logs(&client).await.is_err());
+
+    mem::drop(server)
+}
+
+struct RequestId;
+
+#[async_trait::async_trait]
+impl reqwest_middleware::Middleware for RequestId {
+    async fn handle(
+        &self,
+        mut req: reqwest::Request,
+        extensions: &mut task_local_extensions::Extensions,
+        next: reqwest_middleware::Next<'_>,
+    ) -> reqwest_middleware::Result<reqwest::Response> {
+        req.headers_mut().insert(
+            "x-request-id",
+            reqwest::header::HeaderValue::from_static("1"),
+        );
+        next.run(req, extensions).await
+    }
+}
+
+#[tokio::test]
+async fn test_client_builder_adds_middleware() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v1/logs"))
+        .and(header("x-request-id", "1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([])))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut client = Client::new("token");
+    client
+        .with_host_override(server.uri())
+        .with_client_builder(ClientBuilder::new().with(RequestId))
+        .unwrap();
+
+    assert!(get_all_logs(&client).await.unwrap().is_empty());
+
+    mem::drop(server)
+}
+
+#[tokio::test]
+async fn test_client_builder_timeout() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v1/logs"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_delay(Duration::from_secs(5))
+                .set_body_json(serde_json::json!([])),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut client = Client::new("token");
+    client
+        .with_host_override(server.uri())
+        .with_client_builder(
+            ClientBuilder::new()
+                .max_retries(0)
+                .timeout(Duration::from_millis(100)),
+        )
+        .unwrap();
+
+    assert!(get_all_logs(&client).await.is_err());
+
+    mem::drop(server)
+}
+
+#[tokio::test]
+async fn test_api_error_is_parsed() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v1/logs"))
+        .respond_with(ResponseTemplate::new(403).set_body_json(serde_json::json!({
+            "errorCode": "E0000006",
+            "errorSummary": "You do not have permission to perform the requested action",
+            "errorLink": "E0000006",
+            "errorId": "oae1",
+            "errorCauses": []
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut client = Client::new("token");
+    client.with_host_override(server.uri());
+
+    match get_all_logs(&client).await {
+        Err(ClientError::ApiError { status, error, .. }) => {
+            assert_eq!(status, StatusCode::FORBIDDEN);
+            assert_eq!(error.error_code, "E0000006");
+            assert_eq!(error.error_id, "oae1");
+        }
+        result => unreachable!("Expected API error, got {:?}", result),
+    }
+
+    mem::drop(server)
+}
+
+#[tokio::test]
+async fn test_response_reports_rate_limit() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v1/logs"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("x-rate-limit-limit", "600")
+                .insert_header("x-rate-limit-remaining", "599")
+                .insert_header("x-rate-limit-reset", "1609459200")
+                .set_body_json(serde_json::json!([])),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut client = Client::new("token");
+    client.with_host_override(server.uri());
+
+    let response = client.logs().get_all(None, None, "", "", "").await.unwrap();
+    let rate_limit = response.rate_limit().unwrap();
+    assert_eq!(rate_limit.limit, Some(600));
+    assert_eq!(rate_limit.remaining, Some(599));
+    assert_eq!(
+        rate_limit.reset,
+        Some(SystemTime::UNIX_EPOCH + Duration::from_secs(1609459200))
+    );
+    assert_eq!(rate_limit.wait(), None);
+
+    mem::drop(server)
+}
+
+#[tokio::test]
+async fn test_too_many_requests_is_rate_limited() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v1/logs"))
+        .respond_with(ResponseTemplate::new(429).insert_header("retry-after", "7"))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut client = Client::new("token");
+    client
+        .with_host_override(server.uri())
+        .with_client_builder(ClientBuilder::new().max_retries(0))
+        .unwrap();
+
+    match get_all_logs(&client).await {
+        Err(ClientError::RateLimited { duration, .. }) => assert_eq!(duration, Some(7)),
+        result => unreachable!("Expected rate limit error, got {:?}", result),
+    }
+
+    mem::drop(server)
+}
+
+#[tokio::test]
+async fn test_client_builder_waits_on_rate_limit() {
+    let server = MockServer::start().await;
+    let reset = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .unwrap()
+        .as_secs()
+        + 2;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v1/logs"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("x-rate-limit-remaining", "0")
+                .insert_header("x-rate-limit-reset", reset.to_string().as_str())
+                .set_body_json(serde_json::json!([])),
+        )
+        .expect(2)
+        .mount(&server)
+        .await;
+
+    let mut client = Client::new("token");
+    client
+        .with_host_override(server.uri())
+        .with_client_builder(ClientBuilder::new().wait_on_rate_limit(true))
+        .unwrap();
+
+    get_all_logs(&client).await.unwrap();
+    let start = Instant::now();
+    get_all_logs(&client).await.unwrap();
+
+    // The second request is held back until the rate limit resets.
+    assert!(start.elapsed() >= Duration::from_secs(1));
+
+    mem::drop(server)
+}
+
+#[test]
+fn test_webhook_answers_verification_challenge() {
+    let webhook = Webhook::new("s3cr3t");
+
+    let response = webhook
+        .verification([
+            ("authorization", "s3cr3t"),
+            (
+                "X-Okta-Verification-Challenge",
+                "j8RT5dUcs3bT3bB-Eug8kX1uV9b-o8cA",
+            ),
+        ])
+        .unwrap();
+    assert_eq!(
+        serde_json::to_value(&response).unwrap(),
+        serde_json::json!({ "verification": "j8RT5dUcs3bT3bB-Eug8kX1uV9b-o8cA" })
+    );
+
+    assert!(matches!(
+        webhook.verification([("Authorization", "s3cr3t")]),
+        Err(WebhookError::MissingHeader(webhooks::VERIFICATION_HEADER))
+    ));
+}
+
+#[test]
+fn test_webhook_checks_secret() {
+    let payload = include_bytes!("fixtures/event_hook.json");
+
+    let mut webhook = Webhook::new("s3cr3t");
+    assert!(matches!(
+        webhook.construct_event([("Authorization", "wrong")], payload),
+        Err(WebhookError::SecretMismatch)
+    ));
+    assert!(matches!(
+        webhook.construct_event([("Authorization", "s3cr3")], payload),
+        Err(WebhookError::SecretMismatch)
+    ));
+    assert!(matches!(
+        webhook.construct_event(Vec::<(&str, &str)>::new(), payload),
+        Err(WebhookError::MissingAuthHeader(header)) if header == "Authorization"
+    ));
+
+    webhook.with_header("X-Hook-Secret");
+    assert!(matches!(
+        webhook.construct_event([("Authorization", "s3cr3t")], payload),
+        Err(WebhookError::MissingAuthHeader(header)) if header == "X-Hook-Secret"
+    ));
+    assert!(webhook
+        .construct_event([("x-hook-secret", "s3cr3t")], payload)
+        .is_ok());
+}
+
+#[test]
+fn test_webhook_constructs_event_hook_delivery() {
+    let payload = include_bytes!("fixtures/event_hook.json");
+    let delivery = Webhook::new("s3cr3t")
+        .construct_event([("Authorization", "s3cr3t")], payload)
+        .unwrap();
+
+    assert_eq!(delivery.event_type, "com.okta.event_hook");
+    assert_eq!(delivery.event_id, "b5a188b9-5ece-4636-b041-482ffda96311");
+    assert!(delivery.event_time.is_some());
+
+    let event = &delivery.data.events[0];
+    assert_eq!(event.event_type, "user.session.start");
+    assert_eq!(event.legacy_event_type, "core.user_auth.login_success");
+    assert_eq!(
+        event.actor.as_ref().unwrap().alternate_id,
+        "jessie@example.com"
+    );
+    assert_eq!(event.outcome.as_ref().unwrap().result, "SUCCESS");
+}
+
+#[test]
+fn test_parse_inline_hook_by_type() {
+    let payload = include_bytes!("fixtures/token_inline_hook.json");
+    let InlineHookRequest::Token(request) = webhooks::parse_inline_hook(payload).unwrap() else {
+        panic!("expected a token inline hook");
+    };
+    let identity = request.data.identity.unwrap();
+    assert_eq!(identity.claims["email"], "jessie@example.com");
+    assert!(request.data.access.unwrap().scopes.is_some());
+
+    let payload = include_bytes!("fixtures/registration_inline_hook.json");
+    let InlineHookRequest::Registration(request) = webhooks::parse_inline_hook(payload).unwrap()
+    else {
+        panic!("expected a registration inline hook");
+    };
+    assert_eq!(request.data.action, "ALLOW");
+    assert_eq!(
+        request.data.user_profile["login"],
+        "rosario.jones@example.com"
+    );
+
+    let payload = include_bytes!("fixtures/saml_inline_hook.json");
+    let InlineHookRequest::Saml(request) = webhooks::parse_inline_hook(payload).unwrap() else {
+        panic!("expected a SAML inline hook");
+    };
+    assert!(request
+        .data
+        .assertion
+        .unwrap()
+        .claims
+        .contains_key("extPatientId"));
+
+    let payload = br#"{"eventType": "com.okta.import.transform", "data": {}}"#;
+    assert!(matches!(
+        webhooks::parse_inline_hook(payload).unwrap(),
+        InlineHookRequest::Other(_)
+    ));
+}
+
+#[test]
+fn test_command_response_serializes_commands() {
+    let response = CommandResponse::new()
+        .command(Command::patch_identity(vec![
+            PatchOperation::add("/claims/tier", "gold"),
+            PatchOperation::replace("/token/lifetime/expiration", 7200),
+        ]))
+        .command(Command::patch_access(vec![PatchOperation::remove(
+            "/claims/groups",
+        )]));
+    assert_eq!(
+        serde_json::to_value(&response).unwrap(),
+        serde_json::json!({
+            "commands": [
+                {
+                    "type": "com.okta.identity.patch",
+                    "value": [
+                        { "op": "add", "path": "/claims/tier", "value": "gold" },
+                        { "op": "replace", "path": "/token/lifetime/expiration", "value": 7200 },
+                    ],
+                },
+                {
+                    "type": "com.okta.access.patch",
+                    "value": [{ "op": "remove", "path": "/claims/groups" }],
+                },
+            ],
+        })
+    );
+
+    let mut profile = serde_json::Map::new();
+    profile.insert("department".to_string(), "Sales".into());
+    let response = CommandResponse::new()
+        .command(Command::allow_registration(false))
+        .command(Command::update_profile(profile))
+        .error("Registration is closed");
+    assert_eq!(
+        serde_json::to_value(&response).unwrap(),
+        serde_json::json!({
+            "commands": [
+                { "type": "com.okta.action.update", "value": { "registration": "DENY" } },
+                { "type": "com.okta.user.profile.update", "value": { "department": "Sales" } },
+            ],
+            "error": { "errorSummary": "Registration is closed" },
+        })
+    );
+}
@@ -0,0 +1,23 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::auths::Auths`].
+pub struct Auths {
+    inner: crate::auths::Auths,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Auths {
+    pub(crate) fn new(inner: crate::auths::Auths, runtime: Arc<tokio::runtime::Runtime>) -> Self {
+        Auths { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::auths::Auths::post_token`].
+    pub fn post_token(
+        &self,
+        body: &crate::types::PostTokenRequest,
+    ) -> ClientResult<crate::Response<crate::types::OAuth2Token>> {
+        self.runtime.block_on(self.inner.post_token(body))
+    }
+}
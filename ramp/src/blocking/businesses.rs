@@ -0,0 +1,30 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::businesses::Businesses`].
+pub struct Businesses {
+    inner: crate::businesses::Businesses,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Businesses {
+    pub(crate) fn new(
+        inner: crate::businesses::Businesses,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Businesses { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::businesses::Businesses::get_resources_busine`].
+    pub fn get_resources_busine(&self) -> ClientResult<crate::Response<crate::types::Business>> {
+        self.runtime.block_on(self.inner.get_resources_busine())
+    }
+    /// The blocking version of [`crate::businesses::Businesses::get_resources_business_current`].
+    pub fn get_resources_business_current(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::BusinessCurrentStatus>> {
+        self.runtime
+            .block_on(self.inner.get_resources_business_current())
+    }
+}
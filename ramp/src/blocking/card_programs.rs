@@ -0,0 +1,77 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`CardPrograms::get_page`], set with a builder.
+pub struct CardProgramsGetPageRequest<'a> {
+    inner: crate::card_programs::CardProgramsGetPageRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> CardProgramsGetPageRequest<'a> {
+    /// Sets the `start` query parameter.
+    pub fn start(mut self, start: &'a str) -> Self {
+        self.inner = self.inner.start(start);
+        self
+    }
+
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: f64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::CardProgram>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::card_programs::CardPrograms`].
+pub struct CardPrograms {
+    inner: crate::card_programs::CardPrograms,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl CardPrograms {
+    pub(crate) fn new(
+        inner: crate::card_programs::CardPrograms,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        CardPrograms { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::card_programs::CardPrograms::get_page`].
+    pub fn get_page(
+        &self,
+        start: &str,
+        page_size: f64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::CardProgram>>> {
+        self.runtime.block_on(self.inner.get_page(start, page_size))
+    }
+    /// The blocking version of [`crate::card_programs::CardPrograms::get_page_request`].
+    pub fn get_page_request(&self) -> CardProgramsGetPageRequest<'_> {
+        CardProgramsGetPageRequest {
+            inner: self.inner.get_page_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::card_programs::CardPrograms::get_all`].
+    pub fn get_all(&self) -> ClientResult<crate::Response<Vec<crate::types::CardProgram>>> {
+        self.runtime.block_on(self.inner.get_all())
+    }
+    /// The blocking version of [`crate::card_programs::CardPrograms::post_resources`].
+    pub fn post_resources(
+        &self,
+        body: &crate::types::PostResourcesCardProgramRequest,
+    ) -> ClientResult<crate::Response<crate::types::CardProgram>> {
+        self.runtime.block_on(self.inner.post_resources(body))
+    }
+    /// The blocking version of [`crate::card_programs::CardPrograms::get_program`].
+    pub fn get_program(
+        &self,
+        id: &str,
+    ) -> ClientResult<crate::Response<crate::types::CardProgram>> {
+        self.runtime.block_on(self.inner.get_program(id))
+    }
+}
@@ -0,0 +1,144 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Cards::get_page`], set with a builder.
+pub struct CardsGetPageRequest<'a> {
+    inner: crate::cards::CardsGetPageRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> CardsGetPageRequest<'a> {
+    /// Sets the `start` query parameter.
+    pub fn start(mut self, start: &'a str) -> Self {
+        self.inner = self.inner.start(start);
+        self
+    }
+
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: f64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Sets the `user_id` query parameter.
+    pub fn user_id(mut self, user_id: &'a str) -> Self {
+        self.inner = self.inner.user_id(user_id);
+        self
+    }
+
+    /// Sets the `card_program_id` query parameter.
+    pub fn card_program_id(mut self, card_program_id: &'a str) -> Self {
+        self.inner = self.inner.card_program_id(card_program_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Card>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::cards::Cards`].
+pub struct Cards {
+    inner: crate::cards::Cards,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Cards {
+    pub(crate) fn new(inner: crate::cards::Cards, runtime: Arc<tokio::runtime::Runtime>) -> Self {
+        Cards { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::cards::Cards::get_page`].
+    pub fn get_page(
+        &self,
+        start: &str,
+        page_size: f64,
+        user_id: &str,
+        card_program_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Card>>> {
+        self.runtime.block_on(
+            self.inner
+                .get_page(start, page_size, user_id, card_program_id),
+        )
+    }
+    /// The blocking version of [`crate::cards::Cards::get_page_request`].
+    pub fn get_page_request(&self) -> CardsGetPageRequest<'_> {
+        CardsGetPageRequest {
+            inner: self.inner.get_page_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::cards::Cards::get_all`].
+    pub fn get_all(
+        &self,
+        user_id: &str,
+        card_program_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Card>>> {
+        self.runtime
+            .block_on(self.inner.get_all(user_id, card_program_id))
+    }
+    /// The blocking version of [`crate::cards::Cards::get`].
+    pub fn get(&self, id: &str) -> ClientResult<crate::Response<crate::types::Card>> {
+        self.runtime.block_on(self.inner.get(id))
+    }
+    /// The blocking version of [`crate::cards::Cards::patch_resources`].
+    pub fn patch_resources(
+        &self,
+        id: &str,
+        body: &crate::types::PatchResourcesCardsCardRequest,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.patch_resources(id, body))
+    }
+    /// The blocking version of [`crate::cards::Cards::post_resources_physical`].
+    pub fn post_resources_physical(
+        &self,
+        body: &crate::types::PostResourcesCardPhysicalRequest,
+    ) -> ClientResult<crate::Response<crate::types::TaskResponse>> {
+        self.runtime
+            .block_on(self.inner.post_resources_physical(body))
+    }
+    /// The blocking version of [`crate::cards::Cards::post_resources_virtual`].
+    pub fn post_resources_virtual(
+        &self,
+        body: &crate::types::PostResourcesCardVirtualRequest,
+    ) -> ClientResult<crate::Response<crate::types::TaskResponse>> {
+        self.runtime
+            .block_on(self.inner.post_resources_virtual(body))
+    }
+    /// The blocking version of [`crate::cards::Cards::post_resources_termination`].
+    pub fn post_resources_termination(
+        &self,
+        id: &str,
+        body: &crate::types::PostResourcesCardsCardSuspensionRequest,
+    ) -> ClientResult<crate::Response<crate::types::TaskResponse>> {
+        self.runtime
+            .block_on(self.inner.post_resources_termination(id, body))
+    }
+    /// The blocking version of [`crate::cards::Cards::post_resources_suspension`].
+    pub fn post_resources_suspension(
+        &self,
+        id: &str,
+        body: &crate::types::PostResourcesCardsCardSuspensionRequest,
+    ) -> ClientResult<crate::Response<crate::types::TaskResponse>> {
+        self.runtime
+            .block_on(self.inner.post_resources_suspension(id, body))
+    }
+    /// The blocking version of [`crate::cards::Cards::post_resources_unsuspension`].
+    pub fn post_resources_unsuspension(
+        &self,
+        id: &str,
+        body: &crate::types::PostResourcesCardsCardSuspensionRequest,
+    ) -> ClientResult<crate::Response<crate::types::TaskResponse>> {
+        self.runtime
+            .block_on(self.inner.post_resources_unsuspension(id, body))
+    }
+    /// The blocking version of [`crate::cards::Cards::get_resources_deferred`].
+    pub fn get_resources_deferred(
+        &self,
+        id: &str,
+    ) -> ClientResult<crate::Response<crate::types::GetResourcesCardsDeferredResponse>> {
+        self.runtime.block_on(self.inner.get_resources_deferred(id))
+    }
+}
@@ -0,0 +1,70 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::custom_ids::CustomIds`].
+pub struct CustomIds {
+    inner: crate::custom_ids::CustomIds,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl CustomIds {
+    pub(crate) fn new(
+        inner: crate::custom_ids::CustomIds,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        CustomIds { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::custom_ids::CustomIds::get_custom_provider`].
+    pub fn get_custom_provider(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::GetCustomProviderResponse>> {
+        self.runtime.block_on(self.inner.get_custom_provider())
+    }
+    /// The blocking version of [`crate::custom_ids::CustomIds::postcustom_provider`].
+    pub fn postcustom_provider(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::PostcustomProviderResponse>> {
+        self.runtime.block_on(self.inner.postcustom_provider())
+    }
+    /// The blocking version of [`crate::custom_ids::CustomIds::post_custom_provider_application_link`].
+    pub fn post_custom_provider_application_link(
+        &self,
+        body: &crate::types::GetCustomProviderResponse,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.post_custom_provider_application_link(body))
+    }
+    /// The blocking version of [`crate::custom_ids::CustomIds::get_entity_type_custom_ramp`].
+    pub fn get_entity_type_custom_ramp(
+        &self,
+        entity_type: &str,
+        custom_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::GetEntityTypeCustomRampResponse>> {
+        self.runtime.block_on(
+            self.inner
+                .get_entity_type_custom_ramp(entity_type, custom_id),
+        )
+    }
+    /// The blocking version of [`crate::custom_ids::CustomIds::get_entity_type_ramp_custom`].
+    pub fn get_entity_type_ramp_custom(
+        &self,
+        entity_type: &str,
+        ramp_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::GetEntityTypeRampCustomResponse>> {
+        self.runtime
+            .block_on(self.inner.get_entity_type_ramp_custom(entity_type, ramp_id))
+    }
+    /// The blocking version of [`crate::custom_ids::CustomIds::post_custom_provider_entity_type_link`].
+    pub fn post_custom_provider_entity_type_link(
+        &self,
+        entity_type: &str,
+        body: &crate::types::PostCustomProviderEntityTypeLinkRequest,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .post_custom_provider_entity_type_link(entity_type, body),
+        )
+    }
+}
@@ -0,0 +1,82 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Departments::get_page`], set with a builder.
+pub struct DepartmentsGetPageRequest<'a> {
+    inner: crate::departments::DepartmentsGetPageRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> DepartmentsGetPageRequest<'a> {
+    /// Sets the `start` query parameter.
+    pub fn start(mut self, start: &'a str) -> Self {
+        self.inner = self.inner.start(start);
+        self
+    }
+
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: f64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Department>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::departments::Departments`].
+pub struct Departments {
+    inner: crate::departments::Departments,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Departments {
+    pub(crate) fn new(
+        inner: crate::departments::Departments,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Departments { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::departments::Departments::get_page`].
+    pub fn get_page(
+        &self,
+        start: &str,
+        page_size: f64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Department>>> {
+        self.runtime.block_on(self.inner.get_page(start, page_size))
+    }
+    /// The blocking version of [`crate::departments::Departments::get_page_request`].
+    pub fn get_page_request(&self) -> DepartmentsGetPageRequest<'_> {
+        DepartmentsGetPageRequest {
+            inner: self.inner.get_page_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::departments::Departments::get_all`].
+    pub fn get_all(&self) -> ClientResult<crate::Response<Vec<crate::types::Department>>> {
+        self.runtime.block_on(self.inner.get_all())
+    }
+    /// The blocking version of [`crate::departments::Departments::post`].
+    pub fn post(
+        &self,
+        body: &crate::types::PostLocationRequest,
+    ) -> ClientResult<crate::Response<crate::types::Department>> {
+        self.runtime.block_on(self.inner.post(body))
+    }
+    /// The blocking version of [`crate::departments::Departments::get`].
+    pub fn get(&self, id: &str) -> ClientResult<crate::Response<crate::types::Department>> {
+        self.runtime.block_on(self.inner.get(id))
+    }
+    /// The blocking version of [`crate::departments::Departments::patch`].
+    pub fn patch(
+        &self,
+        id: &str,
+        body: &crate::types::PostLocationRequest,
+    ) -> ClientResult<crate::Response<crate::types::Department>> {
+        self.runtime.block_on(self.inner.patch(id, body))
+    }
+}
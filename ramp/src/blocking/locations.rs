@@ -0,0 +1,82 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Locations::get_page`], set with a builder.
+pub struct LocationsGetPageRequest<'a> {
+    inner: crate::locations::LocationsGetPageRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> LocationsGetPageRequest<'a> {
+    /// Sets the `start` query parameter.
+    pub fn start(mut self, start: &'a str) -> Self {
+        self.inner = self.inner.start(start);
+        self
+    }
+
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: f64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Location>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::locations::Locations`].
+pub struct Locations {
+    inner: crate::locations::Locations,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Locations {
+    pub(crate) fn new(
+        inner: crate::locations::Locations,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Locations { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::locations::Locations::get_page`].
+    pub fn get_page(
+        &self,
+        start: &str,
+        page_size: f64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Location>>> {
+        self.runtime.block_on(self.inner.get_page(start, page_size))
+    }
+    /// The blocking version of [`crate::locations::Locations::get_page_request`].
+    pub fn get_page_request(&self) -> LocationsGetPageRequest<'_> {
+        LocationsGetPageRequest {
+            inner: self.inner.get_page_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::locations::Locations::get_all`].
+    pub fn get_all(&self) -> ClientResult<crate::Response<Vec<crate::types::Location>>> {
+        self.runtime.block_on(self.inner.get_all())
+    }
+    /// The blocking version of [`crate::locations::Locations::post`].
+    pub fn post(
+        &self,
+        body: &crate::types::PostLocationRequest,
+    ) -> ClientResult<crate::Response<crate::types::Location>> {
+        self.runtime.block_on(self.inner.post(body))
+    }
+    /// The blocking version of [`crate::locations::Locations::get`].
+    pub fn get(&self, id: &str) -> ClientResult<crate::Response<crate::types::Location>> {
+        self.runtime.block_on(self.inner.get(id))
+    }
+    /// The blocking version of [`crate::locations::Locations::patch`].
+    pub fn patch(
+        &self,
+        id: &str,
+        body: &crate::types::PostLocationRequest,
+    ) -> ClientResult<crate::Response<crate::types::Location>> {
+        self.runtime.block_on(self.inner.patch(id, body))
+    }
+}
@@ -0,0 +1,106 @@
+//! A blocking client, for the programs that are not async.
+//!
+//! It has the functions of the async [`Client`](crate::Client), which it runs on a
+//! runtime of its own, so they must not be called from within an async runtime. The
+//! `*_stream` functions are left out, the `*_all` ones returning all the pages at once.
+
+use std::{future::Future, sync::Arc};
+
+pub mod auths;
+pub mod businesses;
+pub mod card_programs;
+pub mod cards;
+pub mod custom_ids;
+pub mod departments;
+pub mod locations;
+pub mod receipts;
+pub mod reimbursements;
+pub mod transactions;
+pub mod users;
+
+/// A blocking client, sending its requests with an async [`Client`](crate::Client).
+#[derive(Clone)]
+pub struct Client {
+    inner: crate::Client,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Client {
+    /// Wraps an async client, creating the runtime its requests run on.
+    pub fn new(client: crate::Client) -> std::io::Result<Self> {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()?;
+
+        Ok(Client {
+            inner: client,
+            runtime: Arc::new(runtime),
+        })
+    }
+
+    /// Returns the async client the requests are sent with.
+    pub fn inner(&self) -> &crate::Client {
+        &self.inner
+    }
+
+    /// Runs a future to completion on the runtime of the client, for the async
+    /// functions that have no blocking version.
+    pub fn block_on<F: Future>(&self, future: F) -> F::Output {
+        self.runtime.block_on(future)
+    }
+
+    /// The blocking version of [`crate::Client::auths`].
+    pub fn auths(&self) -> auths::Auths {
+        auths::Auths::new(self.inner.auths(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::businesses`].
+    pub fn businesses(&self) -> businesses::Businesses {
+        businesses::Businesses::new(self.inner.businesses(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::card_programs`].
+    pub fn card_programs(&self) -> card_programs::CardPrograms {
+        card_programs::CardPrograms::new(self.inner.card_programs(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::cards`].
+    pub fn cards(&self) -> cards::Cards {
+        cards::Cards::new(self.inner.cards(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::custom_ids`].
+    pub fn custom_ids(&self) -> custom_ids::CustomIds {
+        custom_ids::CustomIds::new(self.inner.custom_ids(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::departments`].
+    pub fn departments(&self) -> departments::Departments {
+        departments::Departments::new(self.inner.departments(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::locations`].
+    pub fn locations(&self) -> locations::Locations {
+        locations::Locations::new(self.inner.locations(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::receipts`].
+    pub fn receipts(&self) -> receipts::Receipts {
+        receipts::Receipts::new(self.inner.receipts(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::reimbursements`].
+    pub fn reimbursements(&self) -> reimbursements::Reimbursements {
+        reimbursements::Reimbursements::new(self.inner.reimbursements(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::transactions`].
+    pub fn transactions(&self) -> transactions::Transactions {
+        transactions::Transactions::new(self.inner.transactions(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::users`].
+    pub fn users(&self) -> users::Users {
+        users::Users::new(self.inner.users(), self.runtime.clone())
+    }
+}
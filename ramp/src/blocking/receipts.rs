@@ -0,0 +1,111 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Receipts::get_page`], set with a builder.
+pub struct ReceiptsGetPageRequest<'a> {
+    inner: crate::receipts::ReceiptsGetPageRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ReceiptsGetPageRequest<'a> {
+    /// Sets the `from_date` query parameter.
+    pub fn from_date(mut self, from_date: chrono::DateTime<chrono::Utc>) -> Self {
+        self.inner = self.inner.from_date(from_date);
+        self
+    }
+
+    /// Sets the `to_date` query parameter.
+    pub fn to_date(mut self, to_date: chrono::DateTime<chrono::Utc>) -> Self {
+        self.inner = self.inner.to_date(to_date);
+        self
+    }
+
+    /// Sets the `created_after` query parameter.
+    pub fn created_after(mut self, created_after: chrono::DateTime<chrono::Utc>) -> Self {
+        self.inner = self.inner.created_after(created_after);
+        self
+    }
+
+    /// Sets the `created_before` query parameter.
+    pub fn created_before(mut self, created_before: chrono::DateTime<chrono::Utc>) -> Self {
+        self.inner = self.inner.created_before(created_before);
+        self
+    }
+
+    /// Sets the `start` query parameter.
+    pub fn start(mut self, start: &'a str) -> Self {
+        self.inner = self.inner.start(start);
+        self
+    }
+
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: f64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Receipt>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::receipts::Receipts`].
+pub struct Receipts {
+    inner: crate::receipts::Receipts,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Receipts {
+    pub(crate) fn new(
+        inner: crate::receipts::Receipts,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Receipts { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::receipts::Receipts::get_page`].
+    pub fn get_page(
+        &self,
+        from_date: Option<chrono::DateTime<chrono::Utc>>,
+        to_date: Option<chrono::DateTime<chrono::Utc>>,
+        created_after: Option<chrono::DateTime<chrono::Utc>>,
+        created_before: Option<chrono::DateTime<chrono::Utc>>,
+        start: &str,
+        page_size: f64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Receipt>>> {
+        self.runtime.block_on(self.inner.get_page(
+            from_date,
+            to_date,
+            created_after,
+            created_before,
+            start,
+            page_size,
+        ))
+    }
+    /// The blocking version of [`crate::receipts::Receipts::get_page_request`].
+    pub fn get_page_request(&self) -> ReceiptsGetPageRequest<'_> {
+        ReceiptsGetPageRequest {
+            inner: self.inner.get_page_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::receipts::Receipts::get_all`].
+    pub fn get_all(
+        &self,
+        from_date: Option<chrono::DateTime<chrono::Utc>>,
+        to_date: Option<chrono::DateTime<chrono::Utc>>,
+        created_after: Option<chrono::DateTime<chrono::Utc>>,
+        created_before: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Receipt>>> {
+        self.runtime.block_on(
+            self.inner
+                .get_all(from_date, to_date, created_after, created_before),
+        )
+    }
+    /// The blocking version of [`crate::receipts::Receipts::get`].
+    pub fn get(&self, id: &str) -> ClientResult<crate::Response<crate::types::Receipt>> {
+        self.runtime.block_on(self.inner.get(id))
+    }
+}
@@ -0,0 +1,67 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Reimbursements::get_page`], set with a builder.
+pub struct ReimbursementsGetPageRequest<'a> {
+    inner: crate::reimbursements::ReimbursementsGetPageRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ReimbursementsGetPageRequest<'a> {
+    /// Sets the `start` query parameter.
+    pub fn start(mut self, start: &'a str) -> Self {
+        self.inner = self.inner.start(start);
+        self
+    }
+
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: f64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Reimbursement>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::reimbursements::Reimbursements`].
+pub struct Reimbursements {
+    inner: crate::reimbursements::Reimbursements,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Reimbursements {
+    pub(crate) fn new(
+        inner: crate::reimbursements::Reimbursements,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Reimbursements { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::reimbursements::Reimbursements::get_page`].
+    pub fn get_page(
+        &self,
+        start: &str,
+        page_size: f64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Reimbursement>>> {
+        self.runtime.block_on(self.inner.get_page(start, page_size))
+    }
+    /// The blocking version of [`crate::reimbursements::Reimbursements::get_page_request`].
+    pub fn get_page_request(&self) -> ReimbursementsGetPageRequest<'_> {
+        ReimbursementsGetPageRequest {
+            inner: self.inner.get_page_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::reimbursements::Reimbursements::get_all`].
+    pub fn get_all(&self) -> ClientResult<crate::Response<Vec<crate::types::Reimbursement>>> {
+        self.runtime.block_on(self.inner.get_all())
+    }
+    /// The blocking version of [`crate::reimbursements::Reimbursements::get`].
+    pub fn get(&self, id: &str) -> ClientResult<crate::Response<crate::types::Reimbursement>> {
+        self.runtime.block_on(self.inner.get(id))
+    }
+}
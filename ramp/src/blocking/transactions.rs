@@ -0,0 +1,213 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Transactions::get_page`], set with a builder.
+pub struct TransactionsGetPageRequest<'a> {
+    inner: crate::transactions::TransactionsGetPageRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> TransactionsGetPageRequest<'a> {
+    /// Sets the `department_id` query parameter.
+    pub fn department_id(mut self, department_id: &'a str) -> Self {
+        self.inner = self.inner.department_id(department_id);
+        self
+    }
+
+    /// Sets the `location_id` query parameter.
+    pub fn location_id(mut self, location_id: &'a str) -> Self {
+        self.inner = self.inner.location_id(location_id);
+        self
+    }
+
+    /// Sets the `from_date` query parameter.
+    pub fn from_date(mut self, from_date: chrono::DateTime<chrono::Utc>) -> Self {
+        self.inner = self.inner.from_date(from_date);
+        self
+    }
+
+    /// Sets the `to_date` query parameter.
+    pub fn to_date(mut self, to_date: chrono::DateTime<chrono::Utc>) -> Self {
+        self.inner = self.inner.to_date(to_date);
+        self
+    }
+
+    /// Sets the `merchant_id` query parameter.
+    pub fn merchant_id(mut self, merchant_id: &'a str) -> Self {
+        self.inner = self.inner.merchant_id(merchant_id);
+        self
+    }
+
+    /// Sets the `sk_category_id` query parameter.
+    pub fn sk_category_id(mut self, sk_category_id: &'a str) -> Self {
+        self.inner = self.inner.sk_category_id(sk_category_id);
+        self
+    }
+
+    /// Sets the `order_by_date_desc` query parameter.
+    pub fn order_by_date_desc(mut self, order_by_date_desc: bool) -> Self {
+        self.inner = self.inner.order_by_date_desc(order_by_date_desc);
+        self
+    }
+
+    /// Sets the `order_by_date_asc` query parameter.
+    pub fn order_by_date_asc(mut self, order_by_date_asc: bool) -> Self {
+        self.inner = self.inner.order_by_date_asc(order_by_date_asc);
+        self
+    }
+
+    /// Sets the `order_by_amount_desc` query parameter.
+    pub fn order_by_amount_desc(mut self, order_by_amount_desc: bool) -> Self {
+        self.inner = self.inner.order_by_amount_desc(order_by_amount_desc);
+        self
+    }
+
+    /// Sets the `order_by_amount_asc` query parameter.
+    pub fn order_by_amount_asc(mut self, order_by_amount_asc: bool) -> Self {
+        self.inner = self.inner.order_by_amount_asc(order_by_amount_asc);
+        self
+    }
+
+    /// Sets the `state` query parameter.
+    pub fn state(mut self, state: &'a str) -> Self {
+        self.inner = self.inner.state(state);
+        self
+    }
+
+    /// Sets the `min_amount` query parameter.
+    pub fn min_amount(mut self, min_amount: f64) -> Self {
+        self.inner = self.inner.min_amount(min_amount);
+        self
+    }
+
+    /// Sets the `max_amount` query parameter.
+    pub fn max_amount(mut self, max_amount: f64) -> Self {
+        self.inner = self.inner.max_amount(max_amount);
+        self
+    }
+
+    /// Sets the `start` query parameter.
+    pub fn start(mut self, start: &'a str) -> Self {
+        self.inner = self.inner.start(start);
+        self
+    }
+
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: f64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Sets the `requires_memo` query parameter.
+    pub fn requires_memo(mut self, requires_memo: bool) -> Self {
+        self.inner = self.inner.requires_memo(requires_memo);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Data>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::transactions::Transactions`].
+pub struct Transactions {
+    inner: crate::transactions::Transactions,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Transactions {
+    pub(crate) fn new(
+        inner: crate::transactions::Transactions,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Transactions { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::transactions::Transactions::get_page`].
+    pub fn get_page(
+        &self,
+        department_id: &str,
+        location_id: &str,
+        from_date: Option<chrono::DateTime<chrono::Utc>>,
+        to_date: Option<chrono::DateTime<chrono::Utc>>,
+        merchant_id: &str,
+        sk_category_id: &str,
+        order_by_date_desc: bool,
+        order_by_date_asc: bool,
+        order_by_amount_desc: bool,
+        order_by_amount_asc: bool,
+        state: &str,
+        min_amount: f64,
+        max_amount: f64,
+        start: &str,
+        page_size: f64,
+        requires_memo: bool,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Data>>> {
+        self.runtime.block_on(self.inner.get_page(
+            department_id,
+            location_id,
+            from_date,
+            to_date,
+            merchant_id,
+            sk_category_id,
+            order_by_date_desc,
+            order_by_date_asc,
+            order_by_amount_desc,
+            order_by_amount_asc,
+            state,
+            min_amount,
+            max_amount,
+            start,
+            page_size,
+            requires_memo,
+        ))
+    }
+    /// The blocking version of [`crate::transactions::Transactions::get_page_request`].
+    pub fn get_page_request(&self) -> TransactionsGetPageRequest<'_> {
+        TransactionsGetPageRequest {
+            inner: self.inner.get_page_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::transactions::Transactions::get_all`].
+    pub fn get_all(
+        &self,
+        department_id: &str,
+        location_id: &str,
+        from_date: Option<chrono::DateTime<chrono::Utc>>,
+        to_date: Option<chrono::DateTime<chrono::Utc>>,
+        merchant_id: &str,
+        sk_category_id: &str,
+        order_by_date_desc: bool,
+        order_by_date_asc: bool,
+        order_by_amount_desc: bool,
+        order_by_amount_asc: bool,
+        state: &str,
+        min_amount: f64,
+        max_amount: f64,
+        requires_memo: bool,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Data>>> {
+        self.runtime.block_on(self.inner.get_all(
+            department_id,
+            location_id,
+            from_date,
+            to_date,
+            merchant_id,
+            sk_category_id,
+            order_by_date_desc,
+            order_by_date_asc,
+            order_by_amount_desc,
+            order_by_amount_asc,
+            state,
+            min_amount,
+            max_amount,
+            requires_memo,
+        ))
+    }
+    /// The blocking version of [`crate::transactions::Transactions::get_resource`].
+    pub fn get_resource(&self, id: &str) -> ClientResult<crate::Response<crate::types::Data>> {
+        self.runtime.block_on(self.inner.get_resource(id))
+    }
+}
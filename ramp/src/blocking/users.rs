@@ -0,0 +1,112 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Users::get_page`], set with a builder.
+pub struct UsersGetPageRequest<'a> {
+    inner: crate::users::UsersGetPageRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> UsersGetPageRequest<'a> {
+    /// Sets the `start` query parameter.
+    pub fn start(mut self, start: &'a str) -> Self {
+        self.inner = self.inner.start(start);
+        self
+    }
+
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: f64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Sets the `department_id` query parameter.
+    pub fn department_id(mut self, department_id: &'a str) -> Self {
+        self.inner = self.inner.department_id(department_id);
+        self
+    }
+
+    /// Sets the `location_id` query parameter.
+    pub fn location_id(mut self, location_id: &'a str) -> Self {
+        self.inner = self.inner.location_id(location_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::User>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::users::Users`].
+pub struct Users {
+    inner: crate::users::Users,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Users {
+    pub(crate) fn new(inner: crate::users::Users, runtime: Arc<tokio::runtime::Runtime>) -> Self {
+        Users { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::users::Users::get`].
+    pub fn get(&self, id: &str) -> ClientResult<crate::Response<crate::types::User>> {
+        self.runtime.block_on(self.inner.get(id))
+    }
+    /// The blocking version of [`crate::users::Users::delete`].
+    pub fn delete(&self, id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.delete(id))
+    }
+    /// The blocking version of [`crate::users::Users::patch`].
+    pub fn patch(
+        &self,
+        id: &str,
+        body: &crate::types::PatchUsersRequest,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.patch(id, body))
+    }
+    /// The blocking version of [`crate::users::Users::get_page`].
+    pub fn get_page(
+        &self,
+        start: &str,
+        page_size: f64,
+        department_id: &str,
+        location_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::User>>> {
+        self.runtime.block_on(
+            self.inner
+                .get_page(start, page_size, department_id, location_id),
+        )
+    }
+    /// The blocking version of [`crate::users::Users::get_page_request`].
+    pub fn get_page_request(&self) -> UsersGetPageRequest<'_> {
+        UsersGetPageRequest {
+            inner: self.inner.get_page_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::users::Users::get_all`].
+    pub fn get_all(
+        &self,
+        department_id: &str,
+        location_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::User>>> {
+        self.runtime
+            .block_on(self.inner.get_all(department_id, location_id))
+    }
+    /// The blocking version of [`crate::users::Users::post_deferred`].
+    pub fn post_deferred(
+        &self,
+        body: &crate::types::PostUsersDeferredRequest,
+    ) -> ClientResult<crate::Response<crate::types::User>> {
+        self.runtime.block_on(self.inner.post_deferred(body))
+    }
+    /// The blocking version of [`crate::users::Users::get_deferred_status`].
+    pub fn get_deferred_status(
+        &self,
+        id: &str,
+    ) -> ClientResult<crate::Response<crate::types::GetUsersDeferredStatusResponse>> {
+        self.runtime.block_on(self.inner.get_deferred_status(id))
+    }
+}
@@ -1,6 +1,85 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Cards::get_page`], set with a builder.
+pub struct CardsGetPageRequest<'a> {
+    client: &'a Cards,
+    start: Option<&'a str>,
+    page_size: Option<f64>,
+    user_id: Option<&'a str>,
+    card_program_id: Option<&'a str>,
+}
+
+impl<'a> CardsGetPageRequest<'a> {
+    /// Sets the `start` query parameter.
+    pub fn start(mut self, start: &'a str) -> Self {
+        self.start = Some(start);
+        self
+    }
+
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: f64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Sets the `user_id` query parameter.
+    pub fn user_id(mut self, user_id: &'a str) -> Self {
+        self.user_id = Some(user_id);
+        self
+    }
+
+    /// Sets the `card_program_id` query parameter.
+    pub fn card_program_id(mut self, card_program_id: &'a str) -> Self {
+        self.card_program_id = Some(card_program_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Card>>> {
+        let Self {
+            client,
+            start,
+            page_size,
+            user_id,
+            card_program_id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = card_program_id {
+            query_args.push(("card_program_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_size {
+            query_args.push(("page_size".to_string(), v.to_string()));
+        }
+        if let Some(v) = start {
+            query_args.push(("start".to_string(), v.to_string()));
+        }
+        if let Some(v) = user_id {
+            query_args.push(("user_id".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(&format!("/cards?{}", query_), None);
+
+        let resp: crate::Response<crate::types::GetCardsResponse> = client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await?;
+
+        // Return our response data.
+        Ok(crate::Response::new(
+            resp.status,
+            resp.headers,
+            resp.body.cards.to_vec(),
+        ))
+    }
+}
+
 pub struct Cards {
     pub client: Client,
 }
@@ -66,6 +145,24 @@ impl Cards {
             resp.body.cards.to_vec(),
         ))
     }
+    /**
+     * List cards.
+     *
+     * This function performs a `GET` to the `/cards` endpoint.
+     *
+     * As opposed to `get_page`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Retrieve all cards.
+     */
+    pub fn get_page_request(&self) -> CardsGetPageRequest<'_> {
+        CardsGetPageRequest {
+            client: self,
+            start: None,
+            page_size: None,
+            user_id: None,
+            card_program_id: None,
+        }
+    }
     /**
      * List cards.
      *
@@ -89,11 +186,11 @@ impl Cards {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/cards?{}", query_), None);
-        let crate::Response::<crate::types::GetCardsResponse> {
-            mut status,
-            mut headers,
-            body,
-        } = self
+
+        let mut pages = crate::utils::Pages::new(self.client.page_limits);
+        pages.visit(&url)?;
+
+        let mut response: crate::Response<crate::types::GetCardsResponse> = self
             .client
             .get(
                 &url,
@@ -103,16 +200,26 @@ impl Cards {
                 },
             )
             .await?;
-
-        let mut cards = body.cards;
-        let mut page = body.page.next.to_string();
+        let mut cards = Vec::new();
 
         // Paginate if we should.
-        while !page.is_empty() {
-            match self
+        loop {
+            let page = if response.body.page.next.is_empty() {
+                None
+            } else {
+                Some(response.body.page.next.to_string())
+            };
+            let more = pages.keep(&mut response.body.cards);
+            cards.append(&mut response.body.cards);
+
+            let next = match page {
+                Some(next) if more && pages.visit(&next)? => next,
+                _ => break,
+            };
+            response = match self
                 .client
-                .get::<crate::types::GetCardsResponse>(
-                    page.trim_start_matches(&self.client.host),
+                .get(
+                    &next,
                     crate::Message {
                         body: None,
                         content_type: None,
@@ -120,29 +227,60 @@ impl Cards {
                 )
                 .await
             {
-                Ok(mut resp) => {
-                    cards.append(&mut resp.body.cards);
-                    status = resp.status;
-                    headers = resp.headers;
-
-                    page = if body.page.next != page {
-                        body.page.next.to_string()
-                    } else {
-                        "".to_string()
-                    };
-                }
-                Err(e) => {
-                    if e.to_string().contains("404 Not Found") {
-                        page = "".to_string();
-                    } else {
-                        return Err(e);
-                    }
-                }
-            }
+                Ok(response) => response,
+                // Ramp answers the `next` url that follows its last page with a 404.
+                Err(crate::ClientError::ApiError {
+                    status: http::StatusCode::NOT_FOUND,
+                    ..
+                })
+                | Err(crate::ClientError::HttpError {
+                    status: http::StatusCode::NOT_FOUND,
+                    ..
+                }) => break,
+                Err(e) => return Err(e),
+            };
         }
 
         // Return our response data.
-        Ok(crate::Response::new(status, headers, cards))
+        Ok(crate::Response::new(
+            response.status,
+            response.headers,
+            cards,
+        ))
+    }
+    /**
+     * List cards.
+     *
+     * This function performs a `GET` to the `/cards` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Retrieve all cards.
+     */
+    pub fn get_all_stream(
+        &self,
+        user_id: &str,
+        card_program_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Card>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !card_program_id.is_empty() {
+            query_args.push(("card_program_id".to_string(), card_program_id.to_string()));
+        }
+        if !user_id.is_empty() {
+            query_args.push(("user_id".to_string(), user_id.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/cards?{}", query_), None);
+        self.client
+            .get_pages_stream(url, |body: crate::types::GetCardsResponse| {
+                let page = if body.page.next.is_empty() {
+                    None
+                } else {
+                    Some(body.page.next)
+                };
+
+                (body.cards, page)
+            })
     }
     /**
      * GET a card.
@@ -1,6 +1,63 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Locations::get_page`], set with a builder.
+pub struct LocationsGetPageRequest<'a> {
+    client: &'a Locations,
+    start: Option<&'a str>,
+    page_size: Option<f64>,
+}
+
+impl<'a> LocationsGetPageRequest<'a> {
+    /// Sets the `start` query parameter.
+    pub fn start(mut self, start: &'a str) -> Self {
+        self.start = Some(start);
+        self
+    }
+
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: f64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Location>>> {
+        let Self {
+            client,
+            start,
+            page_size,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page_size {
+            query_args.push(("page_size".to_string(), v.to_string()));
+        }
+        if let Some(v) = start {
+            query_args.push(("start".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(&format!("/locations?{}", query_), None);
+
+        let resp: crate::Response<crate::types::GetLocationResponse> = client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await?;
+
+        // Return our response data.
+        Ok(crate::Response::new(
+            resp.status,
+            resp.headers,
+            resp.body.data.to_vec(),
+        ))
+    }
+}
+
 pub struct Locations {
     pub client: Client,
 }
@@ -56,6 +113,22 @@ impl Locations {
             resp.body.data.to_vec(),
         ))
     }
+    /**
+     * List locations.
+     *
+     * This function performs a `GET` to the `/locations` endpoint.
+     *
+     * As opposed to `get_page`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Retrieves all locations for your business.
+     */
+    pub fn get_page_request(&self) -> LocationsGetPageRequest<'_> {
+        LocationsGetPageRequest {
+            client: self,
+            start: None,
+            page_size: None,
+        }
+    }
     /**
      * List locations.
      *
@@ -67,11 +140,10 @@ impl Locations {
      */
     pub async fn get_all(&self) -> ClientResult<crate::Response<Vec<crate::types::Location>>> {
         let url = self.client.url("/locations", None);
-        let crate::Response::<crate::types::GetLocationResponse> {
-            mut status,
-            mut headers,
-            body,
-        } = self
+        let mut pages = crate::utils::Pages::new(self.client.page_limits);
+        pages.visit(&url)?;
+
+        let mut response: crate::Response<crate::types::GetLocationResponse> = self
             .client
             .get(
                 &url,
@@ -81,16 +153,26 @@ impl Locations {
                 },
             )
             .await?;
-
-        let mut data = body.data;
-        let mut page = body.page.next.to_string();
+        let mut data = Vec::new();
 
         // Paginate if we should.
-        while !page.is_empty() {
-            match self
+        loop {
+            let page = if response.body.page.next.is_empty() {
+                None
+            } else {
+                Some(response.body.page.next.to_string())
+            };
+            let more = pages.keep(&mut response.body.data);
+            data.append(&mut response.body.data);
+
+            let next = match page {
+                Some(next) if more && pages.visit(&next)? => next,
+                _ => break,
+            };
+            response = match self
                 .client
-                .get::<crate::types::GetLocationResponse>(
-                    page.trim_start_matches(&self.client.host),
+                .get(
+                    &next,
                     crate::Message {
                         body: None,
                         content_type: None,
@@ -98,29 +180,50 @@ impl Locations {
                 )
                 .await
             {
-                Ok(mut resp) => {
-                    data.append(&mut resp.body.data);
-                    status = resp.status;
-                    headers = resp.headers;
-
-                    page = if body.page.next != page {
-                        body.page.next.to_string()
-                    } else {
-                        "".to_string()
-                    };
-                }
-                Err(e) => {
-                    if e.to_string().contains("404 Not Found") {
-                        page = "".to_string();
-                    } else {
-                        return Err(e);
-                    }
-                }
-            }
+                Ok(response) => response,
+                // Ramp answers the `next` url that follows its last page with a 404.
+                Err(crate::ClientError::ApiError {
+                    status: http::StatusCode::NOT_FOUND,
+                    ..
+                })
+                | Err(crate::ClientError::HttpError {
+                    status: http::StatusCode::NOT_FOUND,
+                    ..
+                }) => break,
+                Err(e) => return Err(e),
+            };
         }
 
         // Return our response data.
-        Ok(crate::Response::new(status, headers, data))
+        Ok(crate::Response::new(
+            response.status,
+            response.headers,
+            data,
+        ))
+    }
+    /**
+     * List locations.
+     *
+     * This function performs a `GET` to the `/locations` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Retrieves all locations for your business.
+     */
+    pub fn get_all_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Location>> + Unpin + '_ {
+        let url = self.client.url("/locations", None);
+        self.client
+            .get_pages_stream(url, |body: crate::types::GetLocationResponse| {
+                let page = if body.page.next.is_empty() {
+                    None
+                } else {
+                    Some(body.page.next)
+                };
+
+                (body.data, page)
+            })
     }
     /**
      * Create new location.
@@ -1,6 +1,107 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Receipts::get_page`], set with a builder.
+pub struct ReceiptsGetPageRequest<'a> {
+    client: &'a Receipts,
+    from_date: Option<chrono::DateTime<chrono::Utc>>,
+    to_date: Option<chrono::DateTime<chrono::Utc>>,
+    created_after: Option<chrono::DateTime<chrono::Utc>>,
+    created_before: Option<chrono::DateTime<chrono::Utc>>,
+    start: Option<&'a str>,
+    page_size: Option<f64>,
+}
+
+impl<'a> ReceiptsGetPageRequest<'a> {
+    /// Sets the `from_date` query parameter.
+    pub fn from_date(mut self, from_date: chrono::DateTime<chrono::Utc>) -> Self {
+        self.from_date = Some(from_date);
+        self
+    }
+
+    /// Sets the `to_date` query parameter.
+    pub fn to_date(mut self, to_date: chrono::DateTime<chrono::Utc>) -> Self {
+        self.to_date = Some(to_date);
+        self
+    }
+
+    /// Sets the `created_after` query parameter.
+    pub fn created_after(mut self, created_after: chrono::DateTime<chrono::Utc>) -> Self {
+        self.created_after = Some(created_after);
+        self
+    }
+
+    /// Sets the `created_before` query parameter.
+    pub fn created_before(mut self, created_before: chrono::DateTime<chrono::Utc>) -> Self {
+        self.created_before = Some(created_before);
+        self
+    }
+
+    /// Sets the `start` query parameter.
+    pub fn start(mut self, start: &'a str) -> Self {
+        self.start = Some(start);
+        self
+    }
+
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: f64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Receipt>>> {
+        let Self {
+            client,
+            from_date,
+            to_date,
+            created_after,
+            created_before,
+            start,
+            page_size,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(date) = created_after {
+            query_args.push(("created_after".to_string(), date.to_rfc3339()));
+        }
+        if let Some(date) = created_before {
+            query_args.push(("created_before".to_string(), date.to_rfc3339()));
+        }
+        if let Some(date) = from_date {
+            query_args.push(("from_date".to_string(), date.to_rfc3339()));
+        }
+        if let Some(v) = page_size {
+            query_args.push(("page_size".to_string(), v.to_string()));
+        }
+        if let Some(v) = start {
+            query_args.push(("start".to_string(), v.to_string()));
+        }
+        if let Some(date) = to_date {
+            query_args.push(("to_date".to_string(), date.to_rfc3339()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(&format!("/receipts?{}", query_), None);
+
+        let resp: crate::Response<crate::types::GetReceiptsResponse> = client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await?;
+
+        // Return our response data.
+        Ok(crate::Response::new(
+            resp.status,
+            resp.headers,
+            resp.body.data.to_vec(),
+        ))
+    }
+}
+
 pub struct Receipts {
     pub client: Client,
 }
@@ -75,6 +176,26 @@ impl Receipts {
             resp.body.data.to_vec(),
         ))
     }
+    /**
+     * List receipts.
+     *
+     * This function performs a `GET` to the `/receipts` endpoint.
+     *
+     * As opposed to `get_page`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Returns description of all receipts of a business.
+     */
+    pub fn get_page_request(&self) -> ReceiptsGetPageRequest<'_> {
+        ReceiptsGetPageRequest {
+            client: self,
+            from_date: None,
+            to_date: None,
+            created_after: None,
+            created_before: None,
+            start: None,
+            page_size: None,
+        }
+    }
     /**
      * List receipts.
      *
@@ -106,11 +227,11 @@ impl Receipts {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/receipts?{}", query_), None);
-        let crate::Response::<crate::types::GetReceiptsResponse> {
-            mut status,
-            mut headers,
-            body,
-        } = self
+
+        let mut pages = crate::utils::Pages::new(self.client.page_limits);
+        pages.visit(&url)?;
+
+        let mut response: crate::Response<crate::types::GetReceiptsResponse> = self
             .client
             .get(
                 &url,
@@ -120,16 +241,26 @@ impl Receipts {
                 },
             )
             .await?;
-
-        let mut data = body.data;
-        let mut page = body.page.next.to_string();
+        let mut data = Vec::new();
 
         // Paginate if we should.
-        while !page.is_empty() {
-            match self
+        loop {
+            let page = if response.body.page.next.is_empty() {
+                None
+            } else {
+                Some(response.body.page.next.to_string())
+            };
+            let more = pages.keep(&mut response.body.data);
+            data.append(&mut response.body.data);
+
+            let next = match page {
+                Some(next) if more && pages.visit(&next)? => next,
+                _ => break,
+            };
+            response = match self
                 .client
-                .get::<crate::types::GetReceiptsResponse>(
-                    page.trim_start_matches(&self.client.host),
+                .get(
+                    &next,
                     crate::Message {
                         body: None,
                         content_type: None,
@@ -137,29 +268,68 @@ impl Receipts {
                 )
                 .await
             {
-                Ok(mut resp) => {
-                    data.append(&mut resp.body.data);
-                    status = resp.status;
-                    headers = resp.headers;
-
-                    page = if body.page.next != page {
-                        body.page.next.to_string()
-                    } else {
-                        "".to_string()
-                    };
-                }
-                Err(e) => {
-                    if e.to_string().contains("404 Not Found") {
-                        page = "".to_string();
-                    } else {
-                        return Err(e);
-                    }
-                }
-            }
+                Ok(response) => response,
+                // Ramp answers the `next` url that follows its last page with a 404.
+                Err(crate::ClientError::ApiError {
+                    status: http::StatusCode::NOT_FOUND,
+                    ..
+                })
+                | Err(crate::ClientError::HttpError {
+                    status: http::StatusCode::NOT_FOUND,
+                    ..
+                }) => break,
+                Err(e) => return Err(e),
+            };
         }
 
         // Return our response data.
-        Ok(crate::Response::new(status, headers, data))
+        Ok(crate::Response::new(
+            response.status,
+            response.headers,
+            data,
+        ))
+    }
+    /**
+     * List receipts.
+     *
+     * This function performs a `GET` to the `/receipts` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Returns description of all receipts of a business.
+     */
+    pub fn get_all_stream(
+        &self,
+        from_date: Option<chrono::DateTime<chrono::Utc>>,
+        to_date: Option<chrono::DateTime<chrono::Utc>>,
+        created_after: Option<chrono::DateTime<chrono::Utc>>,
+        created_before: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Receipt>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(date) = created_after {
+            query_args.push(("created_after".to_string(), date.to_rfc3339()));
+        }
+        if let Some(date) = created_before {
+            query_args.push(("created_before".to_string(), date.to_rfc3339()));
+        }
+        if let Some(date) = from_date {
+            query_args.push(("from_date".to_string(), date.to_rfc3339()));
+        }
+        if let Some(date) = to_date {
+            query_args.push(("to_date".to_string(), date.to_rfc3339()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/receipts?{}", query_), None);
+        self.client
+            .get_pages_stream(url, |body: crate::types::GetReceiptsResponse| {
+                let page = if body.page.next.is_empty() {
+                    None
+                } else {
+                    Some(body.page.next)
+                };
+
+                (body.data, page)
+            })
     }
     /**
      * Get details for one receipt.
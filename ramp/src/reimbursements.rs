@@ -1,6 +1,65 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Reimbursements::get_page`], set with a builder.
+pub struct ReimbursementsGetPageRequest<'a> {
+    client: &'a Reimbursements,
+    start: Option<&'a str>,
+    page_size: Option<f64>,
+}
+
+impl<'a> ReimbursementsGetPageRequest<'a> {
+    /// Sets the `start` query parameter.
+    pub fn start(mut self, start: &'a str) -> Self {
+        self.start = Some(start);
+        self
+    }
+
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: f64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Reimbursement>>> {
+        let Self {
+            client,
+            start,
+            page_size,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page_size {
+            query_args.push(("page_size".to_string(), v.to_string()));
+        }
+        if let Some(v) = start {
+            query_args.push(("start".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/reimbursements?{}", query_), None);
+
+        let resp: crate::Response<crate::types::GetReimbursementsResponse> = client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await?;
+
+        // Return our response data.
+        Ok(crate::Response::new(
+            resp.status,
+            resp.headers,
+            resp.body.data.to_vec(),
+        ))
+    }
+}
+
 pub struct Reimbursements {
     pub client: Client,
 }
@@ -55,6 +114,20 @@ impl Reimbursements {
             resp.body.data.to_vec(),
         ))
     }
+    /**
+     * List Reimbursements.
+     *
+     * This function performs a `GET` to the `/reimbursements` endpoint.
+     *
+     * As opposed to `get_page`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     */
+    pub fn get_page_request(&self) -> ReimbursementsGetPageRequest<'_> {
+        ReimbursementsGetPageRequest {
+            client: self,
+            start: None,
+            page_size: None,
+        }
+    }
     /**
      * List Reimbursements.
      *
@@ -64,11 +137,10 @@ impl Reimbursements {
      */
     pub async fn get_all(&self) -> ClientResult<crate::Response<Vec<crate::types::Reimbursement>>> {
         let url = self.client.url("/reimbursements", None);
-        let crate::Response::<crate::types::GetReimbursementsResponse> {
-            mut status,
-            mut headers,
-            body,
-        } = self
+        let mut pages = crate::utils::Pages::new(self.client.page_limits);
+        pages.visit(&url)?;
+
+        let mut response: crate::Response<crate::types::GetReimbursementsResponse> = self
             .client
             .get(
                 &url,
@@ -78,16 +150,26 @@ impl Reimbursements {
                 },
             )
             .await?;
-
-        let mut data = body.data;
-        let mut page = body.page.next.to_string();
+        let mut data = Vec::new();
 
         // Paginate if we should.
-        while !page.is_empty() {
-            match self
+        loop {
+            let page = if response.body.page.next.is_empty() {
+                None
+            } else {
+                Some(response.body.page.next.to_string())
+            };
+            let more = pages.keep(&mut response.body.data);
+            data.append(&mut response.body.data);
+
+            let next = match page {
+                Some(next) if more && pages.visit(&next)? => next,
+                _ => break,
+            };
+            response = match self
                 .client
-                .get::<crate::types::GetReimbursementsResponse>(
-                    page.trim_start_matches(&self.client.host),
+                .get(
+                    &next,
                     crate::Message {
                         body: None,
                         content_type: None,
@@ -95,29 +177,48 @@ impl Reimbursements {
                 )
                 .await
             {
-                Ok(mut resp) => {
-                    data.append(&mut resp.body.data);
-                    status = resp.status;
-                    headers = resp.headers;
-
-                    page = if body.page.next != page {
-                        body.page.next.to_string()
-                    } else {
-                        "".to_string()
-                    };
-                }
-                Err(e) => {
-                    if e.to_string().contains("404 Not Found") {
-                        page = "".to_string();
-                    } else {
-                        return Err(e);
-                    }
-                }
-            }
+                Ok(response) => response,
+                // Ramp answers the `next` url that follows its last page with a 404.
+                Err(crate::ClientError::ApiError {
+                    status: http::StatusCode::NOT_FOUND,
+                    ..
+                })
+                | Err(crate::ClientError::HttpError {
+                    status: http::StatusCode::NOT_FOUND,
+                    ..
+                }) => break,
+                Err(e) => return Err(e),
+            };
         }
 
         // Return our response data.
-        Ok(crate::Response::new(status, headers, data))
+        Ok(crate::Response::new(
+            response.status,
+            response.headers,
+            data,
+        ))
+    }
+    /**
+     * List Reimbursements.
+     *
+     * This function performs a `GET` to the `/reimbursements` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     */
+    pub fn get_all_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Reimbursement>> + Unpin + '_ {
+        let url = self.client.url("/reimbursements", None);
+        self.client
+            .get_pages_stream(url, |body: crate::types::GetReimbursementsResponse| {
+                let page = if body.page.next.is_empty() {
+                    None
+                } else {
+                    Some(body.page.next)
+                };
+
+                (body.data, page)
+            })
     }
     /**
      * Get details for one reimbursement.
@@ -1,6 +1,219 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Transactions::get_page`], set with a builder.
+pub struct TransactionsGetPageRequest<'a> {
+    client: &'a Transactions,
+    department_id: Option<&'a str>,
+    location_id: Option<&'a str>,
+    from_date: Option<chrono::DateTime<chrono::Utc>>,
+    to_date: Option<chrono::DateTime<chrono::Utc>>,
+    merchant_id: Option<&'a str>,
+    sk_category_id: Option<&'a str>,
+    order_by_date_desc: Option<bool>,
+    order_by_date_asc: Option<bool>,
+    order_by_amount_desc: Option<bool>,
+    order_by_amount_asc: Option<bool>,
+    state: Option<&'a str>,
+    min_amount: Option<f64>,
+    max_amount: Option<f64>,
+    start: Option<&'a str>,
+    page_size: Option<f64>,
+    requires_memo: Option<bool>,
+}
+
+impl<'a> TransactionsGetPageRequest<'a> {
+    /// Sets the `department_id` query parameter.
+    pub fn department_id(mut self, department_id: &'a str) -> Self {
+        self.department_id = Some(department_id);
+        self
+    }
+
+    /// Sets the `location_id` query parameter.
+    pub fn location_id(mut self, location_id: &'a str) -> Self {
+        self.location_id = Some(location_id);
+        self
+    }
+
+    /// Sets the `from_date` query parameter.
+    pub fn from_date(mut self, from_date: chrono::DateTime<chrono::Utc>) -> Self {
+        self.from_date = Some(from_date);
+        self
+    }
+
+    /// Sets the `to_date` query parameter.
+    pub fn to_date(mut self, to_date: chrono::DateTime<chrono::Utc>) -> Self {
+        self.to_date = Some(to_date);
+        self
+    }
+
+    /// Sets the `merchant_id` query parameter.
+    pub fn merchant_id(mut self, merchant_id: &'a str) -> Self {
+        self.merchant_id = Some(merchant_id);
+        self
+    }
+
+    /// Sets the `sk_category_id` query parameter.
+    pub fn sk_category_id(mut self, sk_category_id: &'a str) -> Self {
+        self.sk_category_id = Some(sk_category_id);
+        self
+    }
+
+    /// Sets the `order_by_date_desc` query parameter.
+    pub fn order_by_date_desc(mut self, order_by_date_desc: bool) -> Self {
+        self.order_by_date_desc = Some(order_by_date_desc);
+        self
+    }
+
+    /// Sets the `order_by_date_asc` query parameter.
+    pub fn order_by_date_asc(mut self, order_by_date_asc: bool) -> Self {
+        self.order_by_date_asc = Some(order_by_date_asc);
+        self
+    }
+
+    /// Sets the `order_by_amount_desc` query parameter.
+    pub fn order_by_amount_desc(mut self, order_by_amount_desc: bool) -> Self {
+        self.order_by_amount_desc = Some(order_by_amount_desc);
+        self
+    }
+
+    /// Sets the `order_by_amount_asc` query parameter.
+    pub fn order_by_amount_asc(mut self, order_by_amount_asc: bool) -> Self {
+        self.order_by_amount_asc = Some(order_by_amount_asc);
+        self
+    }
+
+    /// Sets the `state` query parameter.
+    pub fn state(mut self, state: &'a str) -> Self {
+        self.state = Some(state);
+        self
+    }
+
+    /// Sets the `min_amount` query parameter.
+    pub fn min_amount(mut self, min_amount: f64) -> Self {
+        self.min_amount = Some(min_amount);
+        self
+    }
+
+    /// Sets the `max_amount` query parameter.
+    pub fn max_amount(mut self, max_amount: f64) -> Self {
+        self.max_amount = Some(max_amount);
+        self
+    }
+
+    /// Sets the `start` query parameter.
+    pub fn start(mut self, start: &'a str) -> Self {
+        self.start = Some(start);
+        self
+    }
+
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: f64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Sets the `requires_memo` query parameter.
+    pub fn requires_memo(mut self, requires_memo: bool) -> Self {
+        self.requires_memo = Some(requires_memo);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Data>>> {
+        let Self {
+            client,
+            department_id,
+            location_id,
+            from_date,
+            to_date,
+            merchant_id,
+            sk_category_id,
+            order_by_date_desc,
+            order_by_date_asc,
+            order_by_amount_desc,
+            order_by_amount_asc,
+            state,
+            min_amount,
+            max_amount,
+            start,
+            page_size,
+            requires_memo,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = department_id {
+            query_args.push(("department_id".to_string(), v.to_string()));
+        }
+        if let Some(date) = from_date {
+            query_args.push(("from_date".to_string(), date.to_rfc3339()));
+        }
+        if let Some(v) = location_id {
+            query_args.push(("location_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = max_amount {
+            query_args.push(("max_amount".to_string(), v.to_string()));
+        }
+        if let Some(v) = merchant_id {
+            query_args.push(("merchant_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = min_amount {
+            query_args.push(("min_amount".to_string(), v.to_string()));
+        }
+        if let Some(v) = order_by_amount_asc {
+            query_args.push(("order_by_amount_asc".to_string(), v.to_string()));
+        }
+        if let Some(v) = order_by_amount_desc {
+            query_args.push(("order_by_amount_desc".to_string(), v.to_string()));
+        }
+        if let Some(v) = order_by_date_asc {
+            query_args.push(("order_by_date_asc".to_string(), v.to_string()));
+        }
+        if let Some(v) = order_by_date_desc {
+            query_args.push(("order_by_date_desc".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_size {
+            query_args.push(("page_size".to_string(), v.to_string()));
+        }
+        if let Some(v) = requires_memo {
+            query_args.push(("requires_memo".to_string(), v.to_string()));
+        }
+        if let Some(v) = sk_category_id {
+            query_args.push(("sk_category_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = start {
+            query_args.push(("start".to_string(), v.to_string()));
+        }
+        if let Some(v) = state {
+            query_args.push(("state".to_string(), v.to_string()));
+        }
+        if let Some(date) = to_date {
+            query_args.push(("to_date".to_string(), date.to_rfc3339()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/transactions?{}", query_), None);
+
+        let resp: crate::Response<crate::types::GetTransactionResponse> = client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await?;
+
+        // Return our response data.
+        Ok(crate::Response::new(
+            resp.status,
+            resp.headers,
+            resp.body.data.to_vec(),
+        ))
+    }
+}
+
 pub struct Transactions {
     pub client: Client,
 }
@@ -138,6 +351,36 @@ impl Transactions {
             resp.body.data.to_vec(),
         ))
     }
+    /**
+     * List transactions.
+     *
+     * This function performs a `GET` to the `/transactions` endpoint.
+     *
+     * As opposed to `get_page`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Retrieves all transactions for the business. This endpoint supports filtering and ordering. NOTE: only one ordering param is supported.
+     */
+    pub fn get_page_request(&self) -> TransactionsGetPageRequest<'_> {
+        TransactionsGetPageRequest {
+            client: self,
+            department_id: None,
+            location_id: None,
+            from_date: None,
+            to_date: None,
+            merchant_id: None,
+            sk_category_id: None,
+            order_by_date_desc: None,
+            order_by_date_asc: None,
+            order_by_amount_desc: None,
+            order_by_amount_asc: None,
+            state: None,
+            min_amount: None,
+            max_amount: None,
+            start: None,
+            page_size: None,
+            requires_memo: None,
+        }
+    }
     /**
      * List transactions.
      *
@@ -221,11 +464,11 @@ impl Transactions {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/transactions?{}", query_), None);
-        let crate::Response::<crate::types::GetTransactionResponse> {
-            mut status,
-            mut headers,
-            body,
-        } = self
+
+        let mut pages = crate::utils::Pages::new(self.client.page_limits);
+        pages.visit(&url)?;
+
+        let mut response: crate::Response<crate::types::GetTransactionResponse> = self
             .client
             .get(
                 &url,
@@ -235,16 +478,26 @@ impl Transactions {
                 },
             )
             .await?;
-
-        let mut data = body.data;
-        let mut page = body.page.next.to_string();
+        let mut data = Vec::new();
 
         // Paginate if we should.
-        while !page.is_empty() {
-            match self
+        loop {
+            let page = if response.body.page.next.is_empty() {
+                None
+            } else {
+                Some(response.body.page.next.to_string())
+            };
+            let more = pages.keep(&mut response.body.data);
+            data.append(&mut response.body.data);
+
+            let next = match page {
+                Some(next) if more && pages.visit(&next)? => next,
+                _ => break,
+            };
+            response = match self
                 .client
-                .get::<crate::types::GetTransactionResponse>(
-                    page.trim_start_matches(&self.client.host),
+                .get(
+                    &next,
                     crate::Message {
                         body: None,
                         content_type: None,
@@ -252,29 +505,120 @@ impl Transactions {
                 )
                 .await
             {
-                Ok(mut resp) => {
-                    data.append(&mut resp.body.data);
-                    status = resp.status;
-                    headers = resp.headers;
-
-                    page = if body.page.next != page {
-                        body.page.next.to_string()
-                    } else {
-                        "".to_string()
-                    };
-                }
-                Err(e) => {
-                    if e.to_string().contains("404 Not Found") {
-                        page = "".to_string();
-                    } else {
-                        return Err(e);
-                    }
-                }
-            }
+                Ok(response) => response,
+                // Ramp answers the `next` url that follows its last page with a 404.
+                Err(crate::ClientError::ApiError {
+                    status: http::StatusCode::NOT_FOUND,
+                    ..
+                })
+                | Err(crate::ClientError::HttpError {
+                    status: http::StatusCode::NOT_FOUND,
+                    ..
+                }) => break,
+                Err(e) => return Err(e),
+            };
         }
 
         // Return our response data.
-        Ok(crate::Response::new(status, headers, data))
+        Ok(crate::Response::new(
+            response.status,
+            response.headers,
+            data,
+        ))
+    }
+    /**
+     * List transactions.
+     *
+     * This function performs a `GET` to the `/transactions` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Retrieves all transactions for the business. This endpoint supports filtering and ordering. NOTE: only one ordering param is supported.
+     */
+    pub fn get_all_stream(
+        &self,
+        department_id: &str,
+        location_id: &str,
+        from_date: Option<chrono::DateTime<chrono::Utc>>,
+        to_date: Option<chrono::DateTime<chrono::Utc>>,
+        merchant_id: &str,
+        sk_category_id: &str,
+        order_by_date_desc: bool,
+        order_by_date_asc: bool,
+        order_by_amount_desc: bool,
+        order_by_amount_asc: bool,
+        state: &str,
+        min_amount: f64,
+        max_amount: f64,
+        requires_memo: bool,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Data>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !department_id.is_empty() {
+            query_args.push(("department_id".to_string(), department_id.to_string()));
+        }
+        if let Some(date) = from_date {
+            query_args.push(("from_date".to_string(), date.to_rfc3339()));
+        }
+        if !location_id.is_empty() {
+            query_args.push(("location_id".to_string(), location_id.to_string()));
+        }
+        if !max_amount.to_string().is_empty() {
+            query_args.push(("max_amount".to_string(), max_amount.to_string()));
+        }
+        if !merchant_id.is_empty() {
+            query_args.push(("merchant_id".to_string(), merchant_id.to_string()));
+        }
+        if !min_amount.to_string().is_empty() {
+            query_args.push(("min_amount".to_string(), min_amount.to_string()));
+        }
+        if order_by_amount_asc {
+            query_args.push((
+                "order_by_amount_asc".to_string(),
+                order_by_amount_asc.to_string(),
+            ));
+        }
+        if order_by_amount_desc {
+            query_args.push((
+                "order_by_amount_desc".to_string(),
+                order_by_amount_desc.to_string(),
+            ));
+        }
+        if order_by_date_asc {
+            query_args.push((
+                "order_by_date_asc".to_string(),
+                order_by_date_asc.to_string(),
+            ));
+        }
+        if order_by_date_desc {
+            query_args.push((
+                "order_by_date_desc".to_string(),
+                order_by_date_desc.to_string(),
+            ));
+        }
+        if requires_memo {
+            query_args.push(("requires_memo".to_string(), requires_memo.to_string()));
+        }
+        if !sk_category_id.is_empty() {
+            query_args.push(("sk_category_id".to_string(), sk_category_id.to_string()));
+        }
+        if !state.is_empty() {
+            query_args.push(("state".to_string(), state.to_string()));
+        }
+        if let Some(date) = to_date {
+            query_args.push(("to_date".to_string(), date.to_rfc3339()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/transactions?{}", query_), None);
+        self.client
+            .get_pages_stream(url, |body: crate::types::GetTransactionResponse| {
+                let page = if body.page.next.is_empty() {
+                    None
+                } else {
+                    Some(body.page.next)
+                };
+
+                (body.data, page)
+            })
     }
     /**
      * GET a transaction.
@@ -11,6 +11,68 @@ pub fn next_link(l: &LinkMap) -> Option<NextLink> {
         .map(NextLink)
 }
 
+/// Caps on how much of a paginated endpoint is fetched when walking all of its pages.
+/// By default every page is fetched.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct PageLimits {
+    /// The maximum number of pages to request.
+    pub max_pages: Option<usize>,
+    /// The maximum number of items to return.
+    pub max_items: Option<usize>,
+}
+
+/// Tracks the pages walked by a paginator, so it stops once one of the `PageLimits`
+/// is reached, and fails once the server points it back at a page it already fetched.
+pub(crate) struct Pages {
+    limits: PageLimits,
+    seen: std::collections::HashSet<String>,
+    items: usize,
+}
+
+impl Pages {
+    pub(crate) fn new(limits: PageLimits) -> Self {
+        Pages {
+            limits,
+            seen: Default::default(),
+            items: 0,
+        }
+    }
+
+    /// Records a request for the page at `page`, a url or a page token. Returns `false`
+    /// if the page should not be fetched as the page limit was reached.
+    ///
+    /// Fails with `ClientError::IncompletePages` if the page was already visited, as
+    /// following it would loop forever and stopping would return a truncated listing.
+    pub(crate) fn visit(&mut self, page: &str) -> crate::ClientResult<bool> {
+        if self
+            .limits
+            .max_pages
+            .is_some_and(|max| self.seen.len() >= max)
+        {
+            return Ok(false);
+        }
+
+        if self.seen.insert(page.to_string()) {
+            Ok(true)
+        } else {
+            Err(crate::ClientError::IncompletePages {
+                page_token: page.to_string(),
+            })
+        }
+    }
+
+    /// Drops the items of a page that go over the item limit. Returns `false` once the
+    /// limit is reached, or the page was empty, and no further pages should be fetched.
+    pub(crate) fn keep<D>(&mut self, items: &mut Vec<D>) -> bool {
+        if let Some(max) = self.limits.max_items {
+            items.truncate(max.saturating_sub(self.items));
+        }
+        self.items += items.len();
+
+        !items.is_empty() && self.limits.max_items.is_none_or(|max| self.items < max)
+    }
+}
+
 pub mod date_format {
     use chrono::NaiveDate;
     use serde::{self, Deserialize, Deserializer};
@@ -633,6 +695,13 @@ pub mod deserialize_null_vector {
     }
 }
 
+impl Pages {
+    /// Returns whether a page after the first one was visited.
+    pub(crate) fn followed(&self) -> bool {
+        self.seen.len() > 1
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::next_link;
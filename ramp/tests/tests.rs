@@ -1,3 +1,13 @@
+use std::mem;
+
+use futures::TryStreamExt;
+use wiremock::{
+    matchers::{method, path, query_param, query_param_is_missing},
+    Mock, MockServer, ResponseTemplate,
+};
+
+use ramp_api::{Client, ClientError};
+
 const USER: &str = r#"{
   "business_id": "00316d69-a36f-4a24-883a-a0a25353686c",
   "department_id": "7e2617f5-0892-4658-8d76-86d500595eeb",
@@ -42,8 +52,171 @@ fn test_deserialize() {
         location_id: "".to_string(),
         phone: "5553245132".to_string(),
         role: ramp_api::types::Role::BusinessUser,
+        #[cfg(feature = "extra-fields")]
+        extra: Default::default(),
     };
     let s = serde_json::to_string_pretty(&t).unwrap();
     println!("string_user_request = {}", s);
     assert!(true, "{}", s.contains("BUSINESS_USER"));
 }
+
+async fn mount_card_programs_page(server: &MockServer, start: Option<&str>, next: Option<&str>) {
+    let mock = Mock::given(method("GET")).and(path("/card-programs"));
+    let (mock, id) = match start {
+        Some(start) => (mock.and(query_param("start", start)), start),
+        None => (mock.and(query_param_is_missing("start")), "1"),
+    };
+
+    // Ramp hands back the full url of the next page.
+    let next = next
+        .map(|next| format!("{}/card-programs?start={}", server.uri(), next))
+        .unwrap_or_default();
+    mock.respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+        "card-programs": [{ "id": id }],
+        "page": { "next": next }
+    })))
+    .mount(server)
+    .await;
+}
+
+fn pages_client(server: &MockServer) -> Client {
+    let mut client = Client::new("client_id", "client_secret", "redirect_uri", "token", "");
+    client.with_host_override(server.uri());
+    client
+}
+
+#[tokio::test]
+async fn test_get_all_follows_next_urls() {
+    let server = MockServer::start().await;
+
+    mount_card_programs_page(&server, None, Some("2")).await;
+    mount_card_programs_page(&server, Some("2"), Some("3")).await;
+    mount_card_programs_page(&server, Some("3"), None).await;
+
+    let client = pages_client(&server);
+    let programs = client.card_programs().get_all().await.unwrap().body;
+    let ids = programs
+        .iter()
+        .map(|program| program.id.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(ids, vec!["1", "2", "3"]);
+
+    // The second page points back at itself.
+    server.reset().await;
+    mount_card_programs_page(&server, None, Some("2")).await;
+    mount_card_programs_page(&server, Some("2"), Some("2")).await;
+
+    match client.card_programs().get_all().await {
+        Err(ClientError::IncompletePages { page_token }) => {
+            assert!(page_token.ends_with("/card-programs?start=2"))
+        }
+        result => unreachable!("Expected incomplete pages, got {:?}", result),
+    }
+    assert_eq!(server.received_requests().await.unwrap().len(), 2);
+
+    mem::drop(server)
+}
+
+#[tokio::test]
+async fn test_get_all_ends_on_not_found_next_url() {
+    let server = MockServer::start().await;
+
+    // Nothing answers the next url of the second page.
+    mount_card_programs_page(&server, None, Some("2")).await;
+    mount_card_programs_page(&server, Some("2"), Some("3")).await;
+
+    let client = pages_client(&server);
+    let programs = client.card_programs().get_all().await.unwrap().body;
+    let ids = programs
+        .iter()
+        .map(|program| program.id.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(ids, vec!["1", "2"]);
+    assert_eq!(server.received_requests().await.unwrap().len(), 3);
+
+    mem::drop(server)
+}
+
+#[tokio::test]
+async fn test_get_all_stream_ends_on_not_found_next_url() {
+    let server = MockServer::start().await;
+
+    // Nothing answers the next url of the second page.
+    mount_card_programs_page(&server, None, Some("2")).await;
+    mount_card_programs_page(&server, Some("2"), Some("3")).await;
+
+    let client = pages_client(&server);
+    let card_programs = client.card_programs();
+    let ids = card_programs
+        .get_all_stream()
+        .map_ok(|program| program.id)
+        .try_collect::<Vec<_>>()
+        .await
+        .unwrap();
+    assert_eq!(ids, vec!["1", "2"]);
+    assert_eq!(server.received_requests().await.unwrap().len(), 3);
+
+    // A first page that is not found is still an error.
+    server.reset().await;
+    let result = card_programs.get_all_stream().try_collect::<Vec<_>>().await;
+    assert!(result.is_err());
+
+    mem::drop(server)
+}
+
+#[tokio::test]
+async fn test_get_all_respects_max_pages() {
+    let server = MockServer::start().await;
+
+    mount_card_programs_page(&server, None, Some("2")).await;
+    mount_card_programs_page(&server, Some("2"), Some("3")).await;
+    mount_card_programs_page(&server, Some("3"), None).await;
+
+    let mut client = pages_client(&server);
+    client.with_max_pages(2);
+    let programs = client.card_programs().get_all().await.unwrap().body;
+    assert_eq!(programs.len(), 2);
+    assert_eq!(server.received_requests().await.unwrap().len(), 2);
+
+    mem::drop(server)
+}
+
+#[tokio::test]
+async fn test_get_page_request_sends_only_the_parameters_set() {
+    let server = MockServer::start().await;
+
+    // The amounts go out as set, while nothing else was.
+    Mock::given(method("GET"))
+        .and(path("/transactions"))
+        .and(query_param("min_amount", "0"))
+        .and(query_param("max_amount", "500"))
+        .and(query_param("page_size", "25"))
+        .and(query_param_is_missing("department_id"))
+        .and(query_param_is_missing("from_date"))
+        .and(query_param_is_missing("order_by_date_desc"))
+        .and(query_param_is_missing("state"))
+        .and(query_param_is_missing("start"))
+        .and(query_param_is_missing("requires_memo"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "data": [],
+            "page": {}
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = pages_client(&server);
+    let transactions = client
+        .transactions()
+        .get_page_request()
+        .min_amount(0.0)
+        .max_amount(500.0)
+        .page_size(25.0)
+        .send()
+        .await
+        .unwrap()
+        .body;
+    assert!(transactions.is_empty());
+
+    mem::drop(server)
+}
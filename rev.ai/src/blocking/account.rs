@@ -0,0 +1,23 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::account::Account`].
+pub struct Account {
+    inner: crate::account::Account,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Account {
+    pub(crate) fn new(
+        inner: crate::account::Account,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Account { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::account::Account::get`].
+    pub fn get(&self) -> ClientResult<crate::Response<crate::types::Account>> {
+        self.runtime.block_on(self.inner.get())
+    }
+}
@@ -0,0 +1,29 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::captions::Captions`].
+pub struct Captions {
+    inner: crate::captions::Captions,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Captions {
+    pub(crate) fn new(
+        inner: crate::captions::Captions,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Captions { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::captions::Captions::get`].
+    pub fn get(
+        &self,
+        id: &str,
+        accept: crate::types::Accept,
+        speaker_channel: i64,
+    ) -> ClientResult<crate::Response<String>> {
+        self.runtime
+            .block_on(self.inner.get(id, accept, speaker_channel))
+    }
+}
@@ -0,0 +1,80 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Jobs::get_list_of`], set with a builder.
+pub struct JobsGetListOfRequest<'a> {
+    inner: crate::jobs::JobsGetListOfRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> JobsGetListOfRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.inner = self.inner.starting_after(starting_after);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::JobAllOf>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::jobs::Jobs`].
+pub struct Jobs {
+    inner: crate::jobs::Jobs,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Jobs {
+    pub(crate) fn new(inner: crate::jobs::Jobs, runtime: Arc<tokio::runtime::Runtime>) -> Self {
+        Jobs { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::jobs::Jobs::get_list_of`].
+    pub fn get_list_of(
+        &self,
+        limit: i64,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::JobAllOf>>> {
+        self.runtime
+            .block_on(self.inner.get_list_of(limit, starting_after))
+    }
+    /// The blocking version of [`crate::jobs::Jobs::get_list_of_request`].
+    pub fn get_list_of_request(&self) -> JobsGetListOfRequest<'_> {
+        JobsGetListOfRequest {
+            inner: self.inner.get_list_of_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::jobs::Jobs::get_all_list_all_of`].
+    pub fn get_all_list_all_of(
+        &self,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::JobAllOf>>> {
+        self.runtime
+            .block_on(self.inner.get_all_list_all_of(starting_after))
+    }
+    /// The blocking version of [`crate::jobs::Jobs::submit_transcription`].
+    pub fn submit_transcription(
+        &self,
+        body: &crate::types::SubmitJobMediaUrlOptionsAllOf,
+    ) -> ClientResult<crate::Response<crate::types::JobAllOf>> {
+        self.runtime.block_on(self.inner.submit_transcription(body))
+    }
+    /// The blocking version of [`crate::jobs::Jobs::get`].
+    pub fn get(&self, id: &str) -> ClientResult<crate::Response<crate::types::JobAllOf>> {
+        self.runtime.block_on(self.inner.get(id))
+    }
+    /// The blocking version of [`crate::jobs::Jobs::delete`].
+    pub fn delete(&self, id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.delete(id))
+    }
+}
@@ -0,0 +1,64 @@
+//! A blocking client, for the programs that are not async.
+//!
+//! It has the functions of the async [`Client`](crate::Client), which it runs on a
+//! runtime of its own, so they must not be called from within an async runtime. The
+//! `*_stream` functions are left out, the `*_all` ones returning all the pages at once.
+
+use std::{future::Future, sync::Arc};
+
+pub mod account;
+pub mod captions;
+pub mod jobs;
+pub mod transcript;
+
+/// A blocking client, sending its requests with an async [`Client`](crate::Client).
+#[derive(Clone)]
+pub struct Client {
+    inner: crate::Client,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Client {
+    /// Wraps an async client, creating the runtime its requests run on.
+    pub fn new(client: crate::Client) -> std::io::Result<Self> {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()?;
+
+        Ok(Client {
+            inner: client,
+            runtime: Arc::new(runtime),
+        })
+    }
+
+    /// Returns the async client the requests are sent with.
+    pub fn inner(&self) -> &crate::Client {
+        &self.inner
+    }
+
+    /// Runs a future to completion on the runtime of the client, for the async
+    /// functions that have no blocking version.
+    pub fn block_on<F: Future>(&self, future: F) -> F::Output {
+        self.runtime.block_on(future)
+    }
+
+    /// The blocking version of [`crate::Client::account`].
+    pub fn account(&self) -> account::Account {
+        account::Account::new(self.inner.account(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::captions`].
+    pub fn captions(&self) -> captions::Captions {
+        captions::Captions::new(self.inner.captions(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::jobs`].
+    pub fn jobs(&self) -> jobs::Jobs {
+        jobs::Jobs::new(self.inner.jobs(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::transcript`].
+    pub fn transcript(&self) -> transcript::Transcript {
+        transcript::Transcript::new(self.inner.transcript(), self.runtime.clone())
+    }
+}
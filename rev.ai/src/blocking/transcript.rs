@@ -0,0 +1,27 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::transcript::Transcript`].
+pub struct Transcript {
+    inner: crate::transcript::Transcript,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Transcript {
+    pub(crate) fn new(
+        inner: crate::transcript::Transcript,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Transcript { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::transcript::Transcript::get`].
+    pub fn get(
+        &self,
+        id: &str,
+        accept: crate::types::AcceptTranscript,
+    ) -> ClientResult<crate::Response<String>> {
+        self.runtime.block_on(self.inner.get(id, accept))
+    }
+}
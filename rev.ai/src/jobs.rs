@@ -1,6 +1,56 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Jobs::get_list_of`], set with a builder.
+pub struct JobsGetListOfRequest<'a> {
+    client: &'a Jobs,
+    limit: Option<i64>,
+    starting_after: Option<&'a str>,
+}
+
+impl<'a> JobsGetListOfRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.starting_after = Some(starting_after);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::JobAllOf>>> {
+        let Self {
+            client,
+            limit,
+            starting_after,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = starting_after {
+            query_args.push(("starting_after".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(&format!("/jobs?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct Jobs {
     pub client: Client,
 }
@@ -47,6 +97,22 @@ impl Jobs {
             )
             .await
     }
+    /**
+     * Get List of Jobs.
+     *
+     * This function performs a `GET` to the `/jobs` endpoint.
+     *
+     * As opposed to `get_list_of`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Gets a list of transcription jobs submitted within the last 30 days in reverse chronological order up to the provided `limit` number of jobs per call. **Note:** Jobs older than 30 days will not be listed. Pagination is supported via passing the last job `id` from a previous call into `starting_after`.
+     */
+    pub fn get_list_of_request(&self) -> JobsGetListOfRequest<'_> {
+        JobsGetListOfRequest {
+            client: self,
+            limit: None,
+            starting_after: None,
+        }
+    }
     /**
      * Get List of Jobs.
      *
@@ -76,6 +142,27 @@ impl Jobs {
             )
             .await
     }
+    /**
+     * Get List of Jobs.
+     *
+     * This function performs a `GET` to the `/jobs` endpoint.
+     *
+     * As opposed to `get_all_list_all_of`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Gets a list of transcription jobs submitted within the last 30 days in reverse chronological order up to the provided `limit` number of jobs per call. **Note:** Jobs older than 30 days will not be listed. Pagination is supported via passing the last job `id` from a previous call into `starting_after`.
+     */
+    pub fn get_all_list_all_of_stream(
+        &self,
+        starting_after: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::JobAllOf>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !starting_after.is_empty() {
+            query_args.push(("starting_after".to_string(), starting_after.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/jobs?{}", query_), None);
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Submit Transcription Job.
      *
@@ -23,6 +23,10 @@ pub struct Account {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub email: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -36,6 +40,10 @@ pub struct DescriptionlessJobOptions {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub metadata: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -49,6 +57,10 @@ pub struct DescriptionlessJobOptionsData {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub callback_url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -116,8 +128,9 @@ pub enum Language {
     Sv,
     #[serde(rename = "tr")]
     Tr,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Language {
@@ -154,7 +167,7 @@ impl std::fmt::Display for Language {
             Language::Sl => "sl",
             Language::Sv => "sv",
             Language::Tr => "tr",
-            Language::FallthroughString => "*",
+            Language::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -220,6 +233,10 @@ pub struct DescriptionlessJobOptionsDataType {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub speaker_channels_count: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types are flattened into one object:
@@ -264,8 +281,9 @@ pub enum Failure {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Failure {
@@ -281,7 +299,7 @@ impl std::fmt::Display for Failure {
             Failure::InvoicingLimitExceeded => "invoicing_limit_exceeded",
             Failure::Transcription => "transcription",
             Failure::Noop => "",
-            Failure::FallthroughString => "*",
+            Failure::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -307,8 +325,9 @@ pub enum Status {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Status {
@@ -318,7 +337,7 @@ impl std::fmt::Display for Status {
             Status::InProgress => "in_progress",
             Status::Transcribed => "transcribed",
             Status::Noop => "",
-            Status::FallthroughString => "*",
+            Status::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -340,8 +359,9 @@ pub enum Type {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Type {
@@ -349,7 +369,7 @@ impl std::fmt::Display for Type {
         match self {
             Type::Async => "async",
             Type::Noop => "",
-            Type::FallthroughString => "*",
+            Type::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -539,6 +559,10 @@ pub struct Job {
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
     pub type_: Option<Type>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types are flattened into one object:
@@ -571,6 +595,10 @@ pub struct SubmitJobMediaUrlOptions {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub media_url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types are flattened into one object:
@@ -639,6 +667,10 @@ pub struct SubmitJobOptions {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub speaker_channels_count: Option<serde_json::Value>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Contains a collection of phrases. Custom vocabulary informs and biases the speech recognition to find those phrases (at the cost of slightly slower transcription).
@@ -658,6 +690,10 @@ pub struct CustomVocabularies {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub phrases: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -668,6 +704,10 @@ pub struct SubmitJobOptionsData {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub custom_vocabularies: Vec<CustomVocabularies>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types are flattened into one object:
@@ -698,13 +738,12 @@ pub enum TranscriptMonologuesElementsType {
     Punct,
     #[serde(rename = "text")]
     Text,
-    #[serde(rename = "unknown")]
-    Unknown,
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for TranscriptMonologuesElementsType {
@@ -712,9 +751,8 @@ impl std::fmt::Display for TranscriptMonologuesElementsType {
         match self {
             TranscriptMonologuesElementsType::Punct => "punct",
             TranscriptMonologuesElementsType::Text => "text",
-            TranscriptMonologuesElementsType::Unknown => "unknown",
             TranscriptMonologuesElementsType::Noop => "",
-            TranscriptMonologuesElementsType::FallthroughString => "*",
+            TranscriptMonologuesElementsType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -769,6 +807,10 @@ pub struct Elements {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub value: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -791,6 +833,10 @@ pub struct Monologues {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub speaker: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Rev.ai Transcript Model
@@ -815,6 +861,10 @@ pub struct Transcript {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub monologues: Vec<Monologues>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -829,8 +879,9 @@ pub enum Accept {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Accept {
@@ -839,7 +890,7 @@ impl std::fmt::Display for Accept {
             Accept::ApplicationXSubrip => "application/x-subrip",
             Accept::TextVtt => "text/vtt",
             Accept::Noop => "",
-            Accept::FallthroughString => "*",
+            Accept::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -863,8 +914,9 @@ pub enum AcceptTranscript {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AcceptTranscript {
@@ -875,7 +927,7 @@ impl std::fmt::Display for AcceptTranscript {
             }
             AcceptTranscript::TextPlain => "text/plain",
             AcceptTranscript::Noop => "",
-            AcceptTranscript::FallthroughString => "*",
+            AcceptTranscript::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
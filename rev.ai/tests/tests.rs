@@ -1 +1,67 @@
+use std::mem;
 
+use futures::StreamExt;
+use wiremock::{
+    matchers::{method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+use revai::{Client, ClientError};
+
+async fn mount_jobs_page(server: &MockServer, starting_after: &str, jobs: &[&str]) {
+    let last = jobs.last().unwrap();
+    Mock::given(method("GET"))
+        .and(path("/jobs"))
+        .and(query_param("starting_after", starting_after))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header(
+                    "link",
+                    format!(
+                        "<{}/jobs?starting_after={last}>; rel=\"next\"",
+                        server.uri()
+                    )
+                    .as_str(),
+                )
+                .set_body_json(
+                    jobs.iter()
+                        .map(|id| serde_json::json!({ "id": id }))
+                        .collect::<Vec<_>>(),
+                ),
+        )
+        .mount(server)
+        .await;
+}
+
+#[tokio::test]
+async fn test_stream_follows_starting_after_lazily() {
+    let server = MockServer::start().await;
+
+    // Every page links to the jobs after its last one.
+    mount_jobs_page(&server, "job0", &["job1", "job2"]).await;
+    mount_jobs_page(&server, "job2", &["job3", "job4"]).await;
+    mount_jobs_page(&server, "job4", &["job4"]).await;
+
+    let mut client = Client::new("token");
+    client.with_host_override(server.uri());
+    let jobs = client.jobs();
+
+    let ids = jobs
+        .get_all_list_all_of_stream("job0")
+        .take(2)
+        .map(|job| job.unwrap().job.id)
+        .collect::<Vec<_>>()
+        .await;
+    assert_eq!(ids, vec!["job1", "job2"]);
+    assert_eq!(server.received_requests().await.unwrap().len(), 1);
+
+    // The last page links back to itself.
+    match jobs.get_all_list_all_of("job0").await {
+        Err(ClientError::IncompletePages { page_token }) => {
+            assert!(page_token.ends_with("/jobs?starting_after=job4"))
+        }
+        result => unreachable!("Expected incomplete pages, got {:?}", result),
+    }
+
+    mem::drop(server)
+}
@@ -72,6 +72,28 @@ impl Alerts {
             )
             .await
     }
+    /**
+     * Retrieve all alerts.
+     *
+     * This function performs a `GET` to the `/alerts` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * **This endpoint allows you to retrieve all of your alerts.**
+     *
+     * Alerts allow you to specify an email address to receive notifications regarding your email usage or statistics.
+     * * Usage alerts allow you to set the threshold at which an alert will be sent.
+     * * Stats notifications allow you to set how frequently you would like to receive email statistics reports. For example, "daily", "weekly", or "monthly".
+     *
+     * For more information about alerts, please see our [Alerts documentation](https://sendgrid.com/docs/ui/account-and-settings/alerts/).
+     */
+    pub fn get_all_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::GetAlertsResponse>> + Unpin + '_
+    {
+        let url = self.client.url("/alerts", None);
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Create a new Alert.
      *
@@ -1,6 +1,42 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`ApiKeys::get`], set with a builder.
+pub struct ApiKeysGetRequest<'a> {
+    client: &'a ApiKeys,
+    limit: Option<i64>,
+}
+
+impl<'a> ApiKeysGetRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::GetApiKeysResponse>> {
+        let Self { client, limit } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(&format!("/api_keys?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct ApiKeys {
     pub client: Client,
 }
@@ -49,6 +85,27 @@ impl ApiKeys {
             )
             .await
     }
+    /**
+     * Retrieve all API Keys belonging to the authenticated user.
+     *
+     * This function performs a `GET` to the `/api_keys` endpoint.
+     *
+     * As opposed to `get`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * **This endpoint allows you to retrieve all API Keys that belong to the authenticated user.**
+     *
+     * A successful response from this API will include all available API keys' names and IDs.
+     *
+     * For security reasons, there is not a way to retrieve the key itself after it's created. If you lose your API key, you must create a new one. Only the "Create API keys" endpoint will return a key to you and only at the time of creation.
+     *
+     * An `api_key_id` can be used to update or delete the key, as well as retrieve the key's details, such as its scopes.
+     */
+    pub fn get_request(&self) -> ApiKeysGetRequest<'_> {
+        ApiKeysGetRequest {
+            client: self,
+            limit: None,
+        }
+    }
     /**
      * Create API keys.
      *
@@ -0,0 +1,50 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::alerts::Alerts`].
+pub struct Alerts {
+    inner: crate::alerts::Alerts,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Alerts {
+    pub(crate) fn new(inner: crate::alerts::Alerts, runtime: Arc<tokio::runtime::Runtime>) -> Self {
+        Alerts { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::alerts::Alerts::get_page`].
+    pub fn get_page(&self) -> ClientResult<crate::Response<Vec<crate::types::GetAlertsResponse>>> {
+        self.runtime.block_on(self.inner.get_page())
+    }
+    /// The blocking version of [`crate::alerts::Alerts::get_all`].
+    pub fn get_all(&self) -> ClientResult<crate::Response<Vec<crate::types::GetAlertsResponse>>> {
+        self.runtime.block_on(self.inner.get_all())
+    }
+    /// The blocking version of [`crate::alerts::Alerts::post`].
+    pub fn post(
+        &self,
+        body: &crate::types::PostAlertsRequest,
+    ) -> ClientResult<crate::Response<crate::types::PostAlertsResponse>> {
+        self.runtime.block_on(self.inner.post(body))
+    }
+    /// The blocking version of [`crate::alerts::Alerts::get`].
+    pub fn get(
+        &self,
+        alert_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::GetAlertsAlertResponse>> {
+        self.runtime.block_on(self.inner.get(alert_id))
+    }
+    /// The blocking version of [`crate::alerts::Alerts::delete`].
+    pub fn delete(&self, alert_id: i64) -> ClientResult<crate::Response<crate::types::Help>> {
+        self.runtime.block_on(self.inner.delete(alert_id))
+    }
+    /// The blocking version of [`crate::alerts::Alerts::patch`].
+    pub fn patch(
+        &self,
+        alert_id: i64,
+        body: &crate::types::PatchAlertsAlertRequest,
+    ) -> ClientResult<crate::Response<crate::types::GetAlertsAlertResponse>> {
+        self.runtime.block_on(self.inner.patch(alert_id, body))
+    }
+}
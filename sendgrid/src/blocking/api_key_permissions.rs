@@ -0,0 +1,23 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::api_key_permissions::ApiKeyPermissions`].
+pub struct ApiKeyPermissions {
+    inner: crate::api_key_permissions::ApiKeyPermissions,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl ApiKeyPermissions {
+    pub(crate) fn new(
+        inner: crate::api_key_permissions::ApiKeyPermissions,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        ApiKeyPermissions { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::api_key_permissions::ApiKeyPermissions::get_scopes`].
+    pub fn get_scopes(&self) -> ClientResult<crate::Response<crate::types::GetScopesResponse>> {
+        self.runtime.block_on(self.inner.get_scopes())
+    }
+}
@@ -0,0 +1,87 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`ApiKeys::get`], set with a builder.
+pub struct ApiKeysGetRequest<'a> {
+    inner: crate::api_keys::ApiKeysGetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ApiKeysGetRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::GetApiKeysResponse>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::api_keys::ApiKeys`].
+pub struct ApiKeys {
+    inner: crate::api_keys::ApiKeys,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl ApiKeys {
+    pub(crate) fn new(
+        inner: crate::api_keys::ApiKeys,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        ApiKeys { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::api_keys::ApiKeys::get`].
+    pub fn get(
+        &self,
+        limit: i64,
+    ) -> ClientResult<crate::Response<crate::types::GetApiKeysResponse>> {
+        self.runtime.block_on(self.inner.get(limit))
+    }
+    /// The blocking version of [`crate::api_keys::ApiKeys::get_request`].
+    pub fn get_request(&self) -> ApiKeysGetRequest<'_> {
+        ApiKeysGetRequest {
+            inner: self.inner.get_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::api_keys::ApiKeys::create`].
+    pub fn create(
+        &self,
+        body: &crate::types::CreateApiKeysRequest,
+    ) -> ClientResult<crate::Response<crate::types::CreateApiKeysResponse>> {
+        self.runtime.block_on(self.inner.create(body))
+    }
+    /// The blocking version of [`crate::api_keys::ApiKeys::get_key`].
+    pub fn get_key(
+        &self,
+        api_key_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::GetApiKeysKeyResponse>> {
+        self.runtime.block_on(self.inner.get_key(api_key_id))
+    }
+    /// The blocking version of [`crate::api_keys::ApiKeys::put_key`].
+    pub fn put_key(
+        &self,
+        api_key_id: &str,
+        body: &crate::types::PutApiKeysKeyRequest,
+    ) -> ClientResult<crate::Response<crate::types::ApiKeyNameScopesAllOf>> {
+        self.runtime.block_on(self.inner.put_key(api_key_id, body))
+    }
+    /// The blocking version of [`crate::api_keys::ApiKeys::delete_key`].
+    pub fn delete_key(&self, api_key_id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.delete_key(api_key_id))
+    }
+    /// The blocking version of [`crate::api_keys::ApiKeys::patch_key`].
+    pub fn patch_key(
+        &self,
+        api_key_id: &str,
+        body: &crate::types::IpPool,
+    ) -> ClientResult<crate::Response<crate::types::ApiKeyNameId>> {
+        self.runtime
+            .block_on(self.inner.patch_key(api_key_id, body))
+    }
+}
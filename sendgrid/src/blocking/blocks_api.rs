@@ -0,0 +1,120 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`BlocksApi::get_suppression_blocks`], set with a builder.
+pub struct BlocksApiGetSuppressionBlocksRequest<'a> {
+    inner: crate::blocks_api::BlocksApiGetSuppressionBlocksRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> BlocksApiGetSuppressionBlocksRequest<'a> {
+    /// Sets the `start_time` query parameter.
+    pub fn start_time(mut self, start_time: i64) -> Self {
+        self.inner = self.inner.start_time(start_time);
+        self
+    }
+
+    /// Sets the `end_time` query parameter.
+    pub fn end_time(mut self, end_time: i64) -> Self {
+        self.inner = self.inner.end_time(end_time);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.inner = self.inner.offset(offset);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::BlocksResponse>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::blocks_api::BlocksApi`].
+pub struct BlocksApi {
+    inner: crate::blocks_api::BlocksApi,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl BlocksApi {
+    pub(crate) fn new(
+        inner: crate::blocks_api::BlocksApi,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        BlocksApi { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::blocks_api::BlocksApi::get_suppression_blocks`].
+    pub fn get_suppression_blocks(
+        &self,
+        start_time: i64,
+        end_time: i64,
+        limit: i64,
+        offset: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::BlocksResponse>>> {
+        self.runtime.block_on(
+            self.inner
+                .get_suppression_blocks(start_time, end_time, limit, offset),
+        )
+    }
+    /// The blocking version of [`crate::blocks_api::BlocksApi::get_suppression_blocks_request`].
+    pub fn get_suppression_blocks_request(&self) -> BlocksApiGetSuppressionBlocksRequest<'_> {
+        BlocksApiGetSuppressionBlocksRequest {
+            inner: self.inner.get_suppression_blocks_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::blocks_api::BlocksApi::get_all_suppression_blocks`].
+    pub fn get_all_suppression_blocks(
+        &self,
+        start_time: i64,
+        end_time: i64,
+        offset: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::BlocksResponse>>> {
+        self.runtime.block_on(
+            self.inner
+                .get_all_suppression_blocks(start_time, end_time, offset),
+        )
+    }
+    /// The blocking version of [`crate::blocks_api::BlocksApi::delete_suppression_blocks`].
+    pub fn delete_suppression_blocks(
+        &self,
+        body: &crate::types::DeleteSuppressionBlocksRequest,
+    ) -> ClientResult<crate::Response<crate::types::Help>> {
+        self.runtime
+            .block_on(self.inner.delete_suppression_blocks(body))
+    }
+    /// The blocking version of [`crate::blocks_api::BlocksApi::get_suppression_blocks_email`].
+    pub fn get_suppression_blocks_email(
+        &self,
+        email: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::BlocksResponse>>> {
+        self.runtime
+            .block_on(self.inner.get_suppression_blocks_email(email))
+    }
+    /// The blocking version of [`crate::blocks_api::BlocksApi::get_all_suppression_blocks_email`].
+    pub fn get_all_suppression_blocks_email(
+        &self,
+        email: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::BlocksResponse>>> {
+        self.runtime
+            .block_on(self.inner.get_all_suppression_blocks_email(email))
+    }
+    /// The blocking version of [`crate::blocks_api::BlocksApi::delete_suppression_blocks_email`].
+    pub fn delete_suppression_blocks_email(
+        &self,
+        email: &str,
+    ) -> ClientResult<crate::Response<crate::types::Help>> {
+        self.runtime
+            .block_on(self.inner.delete_suppression_blocks_email(email))
+    }
+}
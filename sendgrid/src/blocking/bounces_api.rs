@@ -0,0 +1,106 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`BouncesApi::get_suppression_bounces`], set with a builder.
+pub struct BouncesApiGetSuppressionBouncesRequest<'a> {
+    inner: crate::bounces_api::BouncesApiGetSuppressionBouncesRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> BouncesApiGetSuppressionBouncesRequest<'a> {
+    /// Sets the `start_time` query parameter.
+    pub fn start_time(mut self, start_time: i64) -> Self {
+        self.inner = self.inner.start_time(start_time);
+        self
+    }
+
+    /// Sets the `end_time` query parameter.
+    pub fn end_time(mut self, end_time: i64) -> Self {
+        self.inner = self.inner.end_time(end_time);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::BounceResponse>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::bounces_api::BouncesApi`].
+pub struct BouncesApi {
+    inner: crate::bounces_api::BouncesApi,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl BouncesApi {
+    pub(crate) fn new(
+        inner: crate::bounces_api::BouncesApi,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        BouncesApi { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::bounces_api::BouncesApi::get_suppression_bounces`].
+    pub fn get_suppression_bounces(
+        &self,
+        start_time: i64,
+        end_time: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::BounceResponse>>> {
+        self.runtime
+            .block_on(self.inner.get_suppression_bounces(start_time, end_time))
+    }
+    /// The blocking version of [`crate::bounces_api::BouncesApi::get_suppression_bounces_request`].
+    pub fn get_suppression_bounces_request(&self) -> BouncesApiGetSuppressionBouncesRequest<'_> {
+        BouncesApiGetSuppressionBouncesRequest {
+            inner: self.inner.get_suppression_bounces_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::bounces_api::BouncesApi::get_all_suppression_bounces`].
+    pub fn get_all_suppression_bounces(
+        &self,
+        start_time: i64,
+        end_time: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::BounceResponse>>> {
+        self.runtime
+            .block_on(self.inner.get_all_suppression_bounces(start_time, end_time))
+    }
+    /// The blocking version of [`crate::bounces_api::BouncesApi::delete_suppression_bounces`].
+    pub fn delete_suppression_bounces(
+        &self,
+        body: &crate::types::DeleteSuppressionBouncesRequest,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete_suppression_bounces(body))
+    }
+    /// The blocking version of [`crate::bounces_api::BouncesApi::get_suppression_bounces_email`].
+    pub fn get_suppression_bounces_email(
+        &self,
+        email: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::BounceResponse>>> {
+        self.runtime
+            .block_on(self.inner.get_suppression_bounces_email(email))
+    }
+    /// The blocking version of [`crate::bounces_api::BouncesApi::get_all_suppression_bounces_email`].
+    pub fn get_all_suppression_bounces_email(
+        &self,
+        email: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::BounceResponse>>> {
+        self.runtime
+            .block_on(self.inner.get_all_suppression_bounces_email(email))
+    }
+    /// The blocking version of [`crate::bounces_api::BouncesApi::delete_suppression_bounces_email`].
+    pub fn delete_suppression_bounces_email(
+        &self,
+        email: &str,
+        email_address: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<crate::types::Help>> {
+        self.runtime
+            .block_on(
+                self.inner
+                    .delete_suppression_bounces_email(email, email_address, body),
+            )
+    }
+}
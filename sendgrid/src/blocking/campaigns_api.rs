@@ -0,0 +1,148 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`CampaignsApi::get_campaigns`], set with a builder.
+pub struct CampaignsApiGetCampaignsRequest<'a> {
+    inner: crate::campaigns_api::CampaignsApiGetCampaignsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> CampaignsApiGetCampaignsRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.inner = self.inner.offset(offset);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::GetCampaignsResponse>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::campaigns_api::CampaignsApi`].
+pub struct CampaignsApi {
+    inner: crate::campaigns_api::CampaignsApi,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl CampaignsApi {
+    pub(crate) fn new(
+        inner: crate::campaigns_api::CampaignsApi,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        CampaignsApi { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::campaigns_api::CampaignsApi::get_campaigns`].
+    pub fn get_campaigns(
+        &self,
+        limit: i64,
+        offset: i64,
+    ) -> ClientResult<crate::Response<crate::types::GetCampaignsResponse>> {
+        self.runtime
+            .block_on(self.inner.get_campaigns(limit, offset))
+    }
+    /// The blocking version of [`crate::campaigns_api::CampaignsApi::get_campaigns_request`].
+    pub fn get_campaigns_request(&self) -> CampaignsApiGetCampaignsRequest<'_> {
+        CampaignsApiGetCampaignsRequest {
+            inner: self.inner.get_campaigns_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::campaigns_api::CampaignsApi::post_campaign`].
+    pub fn post_campaign(
+        &self,
+        body: &crate::types::CampaignsRequest,
+    ) -> ClientResult<crate::Response<crate::types::CampaignResponseAllOf>> {
+        self.runtime.block_on(self.inner.post_campaign(body))
+    }
+    /// The blocking version of [`crate::campaigns_api::CampaignsApi::get_campaigns_campaign`].
+    pub fn get_campaigns_campaign(
+        &self,
+        campaign_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::GetCampaignsCampaignResponse>> {
+        self.runtime
+            .block_on(self.inner.get_campaigns_campaign(campaign_id))
+    }
+    /// The blocking version of [`crate::campaigns_api::CampaignsApi::delete_campaigns_campaign`].
+    pub fn delete_campaigns_campaign(&self, campaign_id: i64) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete_campaigns_campaign(campaign_id))
+    }
+    /// The blocking version of [`crate::campaigns_api::CampaignsApi::patch_campaigns_campaign`].
+    pub fn patch_campaigns_campaign(
+        &self,
+        campaign_id: i64,
+        body: &crate::types::UpdateACampaignRequest,
+    ) -> ClientResult<crate::Response<crate::types::CampaignResponseAllOf>> {
+        self.runtime
+            .block_on(self.inner.patch_campaigns_campaign(campaign_id, body))
+    }
+    /// The blocking version of [`crate::campaigns_api::CampaignsApi::post_campaigns_campaign_schedules_now`].
+    pub fn post_campaigns_campaign_schedules_now(
+        &self,
+        campaign_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::SendACampaignResponse>> {
+        self.runtime.block_on(
+            self.inner
+                .post_campaigns_campaign_schedules_now(campaign_id),
+        )
+    }
+    /// The blocking version of [`crate::campaigns_api::CampaignsApi::get_campaigns_campaign_schedule`].
+    pub fn get_campaigns_campaign_schedule(
+        &self,
+        campaign_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::ScheduleACampaignRequest>> {
+        self.runtime
+            .block_on(self.inner.get_campaigns_campaign_schedule(campaign_id))
+    }
+    /// The blocking version of [`crate::campaigns_api::CampaignsApi::post_campaigns_campaign_schedule`].
+    pub fn post_campaigns_campaign_schedule(
+        &self,
+        campaign_id: i64,
+        body: &crate::types::ScheduleACampaignRequest,
+    ) -> ClientResult<crate::Response<crate::types::ScheduleACampaignResponse>> {
+        self.runtime.block_on(
+            self.inner
+                .post_campaigns_campaign_schedule(campaign_id, body),
+        )
+    }
+    /// The blocking version of [`crate::campaigns_api::CampaignsApi::delete_campaigns_campaign_schedules`].
+    pub fn delete_campaigns_campaign_schedules(
+        &self,
+        campaign_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete_campaigns_campaign_schedules(campaign_id))
+    }
+    /// The blocking version of [`crate::campaigns_api::CampaignsApi::patch_campaigns_campaign_schedules`].
+    pub fn patch_campaigns_campaign_schedules(
+        &self,
+        campaign_id: i64,
+        body: &crate::types::ScheduleACampaignRequest,
+    ) -> ClientResult<crate::Response<crate::types::UpdateAScheduledCampaignResponse>> {
+        self.runtime.block_on(
+            self.inner
+                .patch_campaigns_campaign_schedules(campaign_id, body),
+        )
+    }
+    /// The blocking version of [`crate::campaigns_api::CampaignsApi::post_campaigns_campaign_schedules_test`].
+    pub fn post_campaigns_campaign_schedules_test(
+        &self,
+        campaign_id: i64,
+        body: &crate::types::SendATestCampaignRequest,
+    ) -> ClientResult<crate::Response<crate::types::SendATestCampaignRequest>> {
+        self.runtime.block_on(
+            self.inner
+                .post_campaigns_campaign_schedules_test(campaign_id, body),
+        )
+    }
+}
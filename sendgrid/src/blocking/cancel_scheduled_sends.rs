@@ -0,0 +1,84 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::cancel_scheduled_sends::CancelScheduledSends`].
+pub struct CancelScheduledSends {
+    inner: crate::cancel_scheduled_sends::CancelScheduledSends,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl CancelScheduledSends {
+    pub(crate) fn new(
+        inner: crate::cancel_scheduled_sends::CancelScheduledSends,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        CancelScheduledSends { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::cancel_scheduled_sends::CancelScheduledSends::post_mail_batch`].
+    pub fn post_mail_batch(&self) -> ClientResult<crate::Response<crate::types::MailBatchId>> {
+        self.runtime.block_on(self.inner.post_mail_batch())
+    }
+    /// The blocking version of [`crate::cancel_scheduled_sends::CancelScheduledSends::get_user_scheduled_sends`].
+    pub fn get_user_scheduled_sends(
+        &self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::UserScheduledSendStatusAllOf>>> {
+        self.runtime.block_on(self.inner.get_user_scheduled_sends())
+    }
+    /// The blocking version of [`crate::cancel_scheduled_sends::CancelScheduledSends::get_all_user_scheduled_sends`].
+    pub fn get_all_user_scheduled_sends(
+        &self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::UserScheduledSendStatusAllOf>>> {
+        self.runtime
+            .block_on(self.inner.get_all_user_scheduled_sends())
+    }
+    /// The blocking version of [`crate::cancel_scheduled_sends::CancelScheduledSends::post_user_scheduled_send`].
+    pub fn post_user_scheduled_send(
+        &self,
+        body: &crate::types::CancelPauseAScheduledSendRequest,
+    ) -> ClientResult<crate::Response<crate::types::UserScheduledSendStatusAllOf>> {
+        self.runtime
+            .block_on(self.inner.post_user_scheduled_send(body))
+    }
+    /// The blocking version of [`crate::cancel_scheduled_sends::CancelScheduledSends::get_mail_batch`].
+    pub fn get_mail_batch(
+        &self,
+        batch_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::MailBatchId>> {
+        self.runtime.block_on(self.inner.get_mail_batch(batch_id))
+    }
+    /// The blocking version of [`crate::cancel_scheduled_sends::CancelScheduledSends::get_user_scheduled_sends_batch`].
+    pub fn get_user_scheduled_sends_batch(
+        &self,
+        batch_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::UserScheduledSendStatusAllOf>>> {
+        self.runtime
+            .block_on(self.inner.get_user_scheduled_sends_batch(batch_id))
+    }
+    /// The blocking version of [`crate::cancel_scheduled_sends::CancelScheduledSends::get_all_user_scheduled_sends_batch`].
+    pub fn get_all_user_scheduled_sends_batch(
+        &self,
+        batch_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::UserScheduledSendStatusAllOf>>> {
+        self.runtime
+            .block_on(self.inner.get_all_user_scheduled_sends_batch(batch_id))
+    }
+    /// The blocking version of [`crate::cancel_scheduled_sends::CancelScheduledSends::delete_user_scheduled_sends_batch`].
+    pub fn delete_user_scheduled_sends_batch(
+        &self,
+        batch_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete_user_scheduled_sends_batch(batch_id))
+    }
+    /// The blocking version of [`crate::cancel_scheduled_sends::CancelScheduledSends::patch_user_scheduled_sends_batch`].
+    pub fn patch_user_scheduled_sends_batch(
+        &self,
+        batch_id: &str,
+        body: &crate::types::UserScheduledSendStatus,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.patch_user_scheduled_sends_batch(batch_id, body))
+    }
+}
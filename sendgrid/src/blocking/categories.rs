@@ -0,0 +1,245 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Categories::get_page`], set with a builder.
+pub struct CategoriesGetPageRequest<'a> {
+    inner: crate::categories::CategoriesGetPageRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> CategoriesGetPageRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `category` query parameter.
+    pub fn category(mut self, category: &'a str) -> Self {
+        self.inner = self.inner.category(category);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.inner = self.inner.offset(offset);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::GetCategoriesResponse>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Categories::get_stats_sum`], set with a builder.
+pub struct CategoriesGetStatsSumRequest<'a> {
+    inner: crate::categories::CategoriesGetStatsSumRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> CategoriesGetStatsSumRequest<'a> {
+    /// Sets the `sort_by_metric` query parameter.
+    pub fn sort_by_metric(mut self, sort_by_metric: &'a str) -> Self {
+        self.inner = self.inner.sort_by_metric(sort_by_metric);
+        self
+    }
+
+    /// Sets the `sort_by_direction` query parameter.
+    pub fn sort_by_direction(mut self, sort_by_direction: crate::types::SortByDirection) -> Self {
+        self.inner = self.inner.sort_by_direction(sort_by_direction);
+        self
+    }
+
+    /// Sets the `end_date` query parameter.
+    pub fn end_date(mut self, end_date: &'a str) -> Self {
+        self.inner = self.inner.end_date(end_date);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.inner = self.inner.offset(offset);
+        self
+    }
+
+    /// Sets the `aggregated_by` query parameter.
+    pub fn aggregated_by(
+        mut self,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+    ) -> Self {
+        self.inner = self.inner.aggregated_by(aggregated_by);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::CategoryStats>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Categories::get_stats`], set with a builder.
+pub struct CategoriesGetStatsRequest<'a> {
+    inner: crate::categories::CategoriesGetStatsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> CategoriesGetStatsRequest<'a> {
+    /// Sets the `end_date` query parameter.
+    pub fn end_date(mut self, end_date: &'a str) -> Self {
+        self.inner = self.inner.end_date(end_date);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.inner = self.inner.offset(offset);
+        self
+    }
+
+    /// Sets the `aggregated_by` query parameter.
+    pub fn aggregated_by(
+        mut self,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+    ) -> Self {
+        self.inner = self.inner.aggregated_by(aggregated_by);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::CategoryStats>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::categories::Categories`].
+pub struct Categories {
+    inner: crate::categories::Categories,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Categories {
+    pub(crate) fn new(
+        inner: crate::categories::Categories,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Categories { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::categories::Categories::get_page`].
+    pub fn get_page(
+        &self,
+        limit: i64,
+        category: &str,
+        offset: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GetCategoriesResponse>>> {
+        self.runtime
+            .block_on(self.inner.get_page(limit, category, offset))
+    }
+    /// The blocking version of [`crate::categories::Categories::get_page_request`].
+    pub fn get_page_request(&self) -> CategoriesGetPageRequest<'_> {
+        CategoriesGetPageRequest {
+            inner: self.inner.get_page_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::categories::Categories::get_all`].
+    pub fn get_all(
+        &self,
+        category: &str,
+        offset: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GetCategoriesResponse>>> {
+        self.runtime.block_on(self.inner.get_all(category, offset))
+    }
+    /// The blocking version of [`crate::categories::Categories::get_stats_sum`].
+    pub fn get_stats_sum(
+        &self,
+        sort_by_metric: &str,
+        sort_by_direction: crate::types::SortByDirection,
+        start_date: &str,
+        end_date: &str,
+        limit: i64,
+        offset: i64,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+    ) -> ClientResult<crate::Response<crate::types::CategoryStats>> {
+        self.runtime.block_on(self.inner.get_stats_sum(
+            sort_by_metric,
+            sort_by_direction,
+            start_date,
+            end_date,
+            limit,
+            offset,
+            aggregated_by,
+        ))
+    }
+    /// The blocking version of [`crate::categories::Categories::get_stats_sum_request`].
+    pub fn get_stats_sum_request<'a>(
+        &'a self,
+        start_date: &'a str,
+    ) -> CategoriesGetStatsSumRequest<'a> {
+        CategoriesGetStatsSumRequest {
+            inner: self.inner.get_stats_sum_request(start_date),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::categories::Categories::get_stats`].
+    pub fn get_stats(
+        &self,
+        start_date: &str,
+        end_date: &str,
+        categories: &str,
+        limit: i64,
+        offset: i64,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+    ) -> ClientResult<crate::Response<Vec<crate::types::CategoryStats>>> {
+        self.runtime.block_on(self.inner.get_stats(
+            start_date,
+            end_date,
+            categories,
+            limit,
+            offset,
+            aggregated_by,
+        ))
+    }
+    /// The blocking version of [`crate::categories::Categories::get_stats_request`].
+    pub fn get_stats_request<'a>(
+        &'a self,
+        start_date: &'a str,
+        categories: &'a str,
+    ) -> CategoriesGetStatsRequest<'a> {
+        CategoriesGetStatsRequest {
+            inner: self.inner.get_stats_request(start_date, categories),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::categories::Categories::get_all_stats`].
+    pub fn get_all_stats(
+        &self,
+        start_date: &str,
+        end_date: &str,
+        categories: &str,
+        offset: i64,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+    ) -> ClientResult<crate::Response<Vec<crate::types::CategoryStats>>> {
+        self.runtime.block_on(self.inner.get_all_stats(
+            start_date,
+            end_date,
+            categories,
+            offset,
+            aggregated_by,
+        ))
+    }
+}
@@ -0,0 +1,67 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::certificates::Certificates`].
+pub struct Certificates {
+    inner: crate::certificates::Certificates,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Certificates {
+    pub(crate) fn new(
+        inner: crate::certificates::Certificates,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Certificates { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::certificates::Certificates::post_sso`].
+    pub fn post_sso(
+        &self,
+        body: &crate::types::PostSsoCertificatesRequest,
+    ) -> ClientResult<crate::Response<crate::types::SsoCertificateBody>> {
+        self.runtime.block_on(self.inner.post_sso(body))
+    }
+    /// The blocking version of [`crate::certificates::Certificates::get_sso_integrations_integration`].
+    pub fn get_sso_integrations_integration(
+        &self,
+        integration_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::SsoCertificateBody>>> {
+        self.runtime
+            .block_on(self.inner.get_sso_integrations_integration(integration_id))
+    }
+    /// The blocking version of [`crate::certificates::Certificates::get_all_sso_integrations_integration`].
+    pub fn get_all_sso_integrations_integration(
+        &self,
+        integration_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::SsoCertificateBody>>> {
+        self.runtime.block_on(
+            self.inner
+                .get_all_sso_integrations_integration(integration_id),
+        )
+    }
+    /// The blocking version of [`crate::certificates::Certificates::get_sso_cert`].
+    pub fn get_sso_cert(
+        &self,
+        cert_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::SsoCertificateBody>> {
+        self.runtime.block_on(self.inner.get_sso_cert(cert_id))
+    }
+    /// The blocking version of [`crate::certificates::Certificates::delete_sso_cert`].
+    pub fn delete_sso_cert(
+        &self,
+        cert_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::SsoCertificateBody>> {
+        self.runtime.block_on(self.inner.delete_sso_cert(cert_id))
+    }
+    /// The blocking version of [`crate::certificates::Certificates::patch_sso_cert`].
+    pub fn patch_sso_cert(
+        &self,
+        cert_id: &str,
+        body: &crate::types::PatchSsoCertificatesCertRequest,
+    ) -> ClientResult<crate::Response<Vec<crate::types::SsoErrorResponse>>> {
+        self.runtime
+            .block_on(self.inner.patch_sso_cert(cert_id, body))
+    }
+}
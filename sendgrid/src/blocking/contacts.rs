@@ -0,0 +1,140 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Contacts::delete_mc`], set with a builder.
+pub struct ContactsDeleteMcRequest<'a> {
+    inner: crate::contacts::ContactsDeleteMcRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ContactsDeleteMcRequest<'a> {
+    /// Sets the `delete_all_contacts` query parameter.
+    pub fn delete_all_contacts(mut self, delete_all_contacts: &'a str) -> Self {
+        self.inner = self.inner.delete_all_contacts(delete_all_contacts);
+        self
+    }
+
+    /// Sets the `ids` query parameter.
+    pub fn ids(mut self, ids: &'a str) -> Self {
+        self.inner = self.inner.ids(ids);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::DeleteMcContactsResponse>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::contacts::Contacts`].
+pub struct Contacts {
+    inner: crate::contacts::Contacts,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Contacts {
+    pub(crate) fn new(
+        inner: crate::contacts::Contacts,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Contacts { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::contacts::Contacts::get_mc_contats`].
+    pub fn get_mc_contats(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::GetMcContatsResponse>> {
+        self.runtime.block_on(self.inner.get_mc_contats())
+    }
+    /// The blocking version of [`crate::contacts::Contacts::put_mc`].
+    pub fn put_mc(
+        &self,
+        body: &crate::types::PutMcContactsRequest,
+    ) -> ClientResult<crate::Response<crate::types::PutMcContactsResponse>> {
+        self.runtime.block_on(self.inner.put_mc(body))
+    }
+    /// The blocking version of [`crate::contacts::Contacts::delete_mc`].
+    pub fn delete_mc(
+        &self,
+        delete_all_contacts: &str,
+        ids: &str,
+    ) -> ClientResult<crate::Response<crate::types::DeleteMcContactsResponse>> {
+        self.runtime
+            .block_on(self.inner.delete_mc(delete_all_contacts, ids))
+    }
+    /// The blocking version of [`crate::contacts::Contacts::delete_mc_request`].
+    pub fn delete_mc_request(&self) -> ContactsDeleteMcRequest<'_> {
+        ContactsDeleteMcRequest {
+            inner: self.inner.delete_mc_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::contacts::Contacts::get_mc_count`].
+    pub fn get_mc_count(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::GetMcContactsCountResponse>> {
+        self.runtime.block_on(self.inner.get_mc_count())
+    }
+    /// The blocking version of [`crate::contacts::Contacts::get_marketing_exports`].
+    pub fn get_marketing_exports(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::GetMarketingContactsExportsResponse>> {
+        self.runtime.block_on(self.inner.get_marketing_exports())
+    }
+    /// The blocking version of [`crate::contacts::Contacts::post_mc_export`].
+    pub fn post_mc_export(
+        &self,
+        body: &crate::types::PostMcContactsExportsRequest,
+    ) -> ClientResult<crate::Response<crate::types::PostMcContactsExportsResponse>> {
+        self.runtime.block_on(self.inner.post_mc_export(body))
+    }
+    /// The blocking version of [`crate::contacts::Contacts::get_mc`].
+    pub fn get_mc(&self, id: &str) -> ClientResult<crate::Response<crate::types::ContactDetails3>> {
+        self.runtime.block_on(self.inner.get_mc(id))
+    }
+    /// The blocking version of [`crate::contacts::Contacts::post_mc_search`].
+    pub fn post_mc_search(
+        &self,
+        body: &crate::types::PostMcContactsSearchRequest,
+    ) -> ClientResult<crate::Response<crate::types::PostMcContactsSearchResponse>> {
+        self.runtime.block_on(self.inner.post_mc_search(body))
+    }
+    /// The blocking version of [`crate::contacts::Contacts::put_mc_imports`].
+    pub fn put_mc_imports(
+        &self,
+        body: &crate::types::PutMcContactsImportsRequest,
+    ) -> ClientResult<crate::Response<crate::types::PutMcContactsImportsResponse>> {
+        self.runtime.block_on(self.inner.put_mc_imports(body))
+    }
+    /// The blocking version of [`crate::contacts::Contacts::get_marketing_import`].
+    pub fn get_marketing_import(
+        &self,
+        id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ContactImport>> {
+        self.runtime.block_on(self.inner.get_marketing_import(id))
+    }
+    /// The blocking version of [`crate::contacts::Contacts::get_mc_export`].
+    pub fn get_mc_export(
+        &self,
+        id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ContactExport>> {
+        self.runtime.block_on(self.inner.get_mc_export(id))
+    }
+    /// The blocking version of [`crate::contacts::Contacts::post_marketing_batch`].
+    pub fn post_marketing_batch(
+        &self,
+        body: &crate::types::PostMarketingContactsBatchRequest,
+    ) -> ClientResult<crate::Response<crate::types::PostMarketingContactsBatchResponse>> {
+        self.runtime.block_on(self.inner.post_marketing_batch(body))
+    }
+    /// The blocking version of [`crate::contacts::Contacts::post_marketing_search_email`].
+    pub fn post_marketing_search_email(
+        &self,
+        body: &crate::types::PostMarketingContactsSearchEmailsRequest,
+    ) -> ClientResult<crate::Response<crate::types::PostMarketingContactsSearchEmailsResponse>>
+    {
+        self.runtime
+            .block_on(self.inner.post_marketing_search_email(body))
+    }
+}
@@ -0,0 +1,61 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::contacts_api_custom_fields::ContactsApiCustomFields`].
+pub struct ContactsApiCustomFields {
+    inner: crate::contacts_api_custom_fields::ContactsApiCustomFields,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl ContactsApiCustomFields {
+    pub(crate) fn new(
+        inner: crate::contacts_api_custom_fields::ContactsApiCustomFields,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        ContactsApiCustomFields { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::contacts_api_custom_fields::ContactsApiCustomFields::get_contactdb_custom_fields`].
+    pub fn get_contactdb_custom_fields(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::ListAllCustomFieldsResponse>> {
+        self.runtime
+            .block_on(self.inner.get_contactdb_custom_fields())
+    }
+    /// The blocking version of [`crate::contacts_api_custom_fields::ContactsApiCustomFields::post_contactdb_custom_field`].
+    pub fn post_contactdb_custom_field(
+        &self,
+        body: &crate::types::PostContactdbCustomFieldsRequest,
+    ) -> ClientResult<crate::Response<crate::types::ContactdbCustomFieldWithAllOf>> {
+        self.runtime
+            .block_on(self.inner.post_contactdb_custom_field(body))
+    }
+    /// The blocking version of [`crate::contacts_api_custom_fields::ContactsApiCustomFields::get_contactdb_custom_fields_field`].
+    pub fn get_contactdb_custom_fields_field(
+        &self,
+        custom_field_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::ContactdbCustomFieldWithAllOf>> {
+        self.runtime.block_on(
+            self.inner
+                .get_contactdb_custom_fields_field(custom_field_id),
+        )
+    }
+    /// The blocking version of [`crate::contacts_api_custom_fields::ContactsApiCustomFields::delete_contactdb_custom_fields_field`].
+    pub fn delete_contactdb_custom_fields_field(
+        &self,
+        custom_field_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::GlobalErrorResponseSchema>> {
+        self.runtime.block_on(
+            self.inner
+                .delete_contactdb_custom_fields_field(custom_field_id),
+        )
+    }
+    /// The blocking version of [`crate::contacts_api_custom_fields::ContactsApiCustomFields::get_contactdb_reserved_fields`].
+    pub fn get_contactdb_reserved_fields(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::GetContactdbReservedFieldsResponse>> {
+        self.runtime
+            .block_on(self.inner.get_contactdb_reserved_fields())
+    }
+}
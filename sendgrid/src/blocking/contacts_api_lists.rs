@@ -0,0 +1,186 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`ContactsApiLists::delete_contactdb_lists_list`], set with a builder.
+pub struct ContactsApiListsDeleteContactdbListsListRequest<'a> {
+    inner: crate::contacts_api_lists::ContactsApiListsDeleteContactdbListsListRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ContactsApiListsDeleteContactdbListsListRequest<'a> {
+    /// Sets the `delete_contacts` query parameter.
+    pub fn delete_contacts(mut self, delete_contacts: bool) -> Self {
+        self.inner = self.inner.delete_contacts(delete_contacts);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`ContactsApiLists::get_contactdb_lists_list_recipients`], set with a builder.
+pub struct ContactsApiListsGetContactdbListsListRecipientsRequest<'a> {
+    inner: crate::contacts_api_lists::ContactsApiListsGetContactdbListsListRecipientsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ContactsApiListsGetContactdbListsListRecipientsRequest<'a> {
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::GetContactdbRecipientsSearchResponse>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::contacts_api_lists::ContactsApiLists`].
+pub struct ContactsApiLists {
+    inner: crate::contacts_api_lists::ContactsApiLists,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl ContactsApiLists {
+    pub(crate) fn new(
+        inner: crate::contacts_api_lists::ContactsApiLists,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        ContactsApiLists { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::contacts_api_lists::ContactsApiLists::get_contactdb_lists`].
+    pub fn get_contactdb_lists(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::ListAllListsResponse>> {
+        self.runtime.block_on(self.inner.get_contactdb_lists())
+    }
+    /// The blocking version of [`crate::contacts_api_lists::ContactsApiLists::post_contactdb_list`].
+    pub fn post_contactdb_list(
+        &self,
+        body: &crate::types::IpPool,
+    ) -> ClientResult<crate::Response<crate::types::ContactdbList>> {
+        self.runtime.block_on(self.inner.post_contactdb_list(body))
+    }
+    /// The blocking version of [`crate::contacts_api_lists::ContactsApiLists::delete_contactdb_lists`].
+    pub fn delete_contactdb_lists(&self, body: &[i64]) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete_contactdb_lists(body))
+    }
+    /// The blocking version of [`crate::contacts_api_lists::ContactsApiLists::get_contactdb_lists_list`].
+    pub fn get_contactdb_lists_list(
+        &self,
+        list_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ContactdbList>> {
+        self.runtime
+            .block_on(self.inner.get_contactdb_lists_list(list_id))
+    }
+    /// The blocking version of [`crate::contacts_api_lists::ContactsApiLists::delete_contactdb_lists_list`].
+    pub fn delete_contactdb_lists_list(
+        &self,
+        list_id: &str,
+        delete_contacts: bool,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(
+                self.inner
+                    .delete_contactdb_lists_list(list_id, delete_contacts, body),
+            )
+    }
+    /// The blocking version of [`crate::contacts_api_lists::ContactsApiLists::delete_contactdb_lists_list_request`].
+    pub fn delete_contactdb_lists_list_request<'a>(
+        &'a self,
+        list_id: &'a str,
+        body: &'a serde_json::Value,
+    ) -> ContactsApiListsDeleteContactdbListsListRequest<'a> {
+        ContactsApiListsDeleteContactdbListsListRequest {
+            inner: self
+                .inner
+                .delete_contactdb_lists_list_request(list_id, body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::contacts_api_lists::ContactsApiLists::patch_contactdb_lists_list`].
+    pub fn patch_contactdb_lists_list(
+        &self,
+        list_id: &str,
+        body: &crate::types::IpPool,
+    ) -> ClientResult<crate::Response<crate::types::PatchContactdbListsListResponse>> {
+        self.runtime
+            .block_on(self.inner.patch_contactdb_lists_list(list_id, body))
+    }
+    /// The blocking version of [`crate::contacts_api_lists::ContactsApiLists::get_contactdb_lists_list_recipients`].
+    pub fn get_contactdb_lists_list_recipients(
+        &self,
+        list_id: i64,
+        page: i64,
+        page_size: i64,
+    ) -> ClientResult<crate::Response<crate::types::GetContactdbRecipientsSearchResponse>> {
+        self.runtime.block_on(
+            self.inner
+                .get_contactdb_lists_list_recipients(list_id, page, page_size),
+        )
+    }
+    /// The blocking version of [`crate::contacts_api_lists::ContactsApiLists::get_contactdb_lists_list_recipients_request`].
+    pub fn get_contactdb_lists_list_recipients_request(
+        &self,
+        list_id: i64,
+    ) -> ContactsApiListsGetContactdbListsListRecipientsRequest<'_> {
+        ContactsApiListsGetContactdbListsListRecipientsRequest {
+            inner: self
+                .inner
+                .get_contactdb_lists_list_recipients_request(list_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::contacts_api_lists::ContactsApiLists::post_contactdb_lists_list_recipient`].
+    pub fn post_contactdb_lists_list_recipient(
+        &self,
+        list_id: i64,
+        body: &[i64],
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .post_contactdb_lists_list_recipient(list_id, body),
+        )
+    }
+    /// The blocking version of [`crate::contacts_api_lists::ContactsApiLists::post_contactdb_lists_list_recipients_recipient`].
+    pub fn post_contactdb_lists_list_recipients_recipient(
+        &self,
+        list_id: i64,
+        recipient_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .post_contactdb_lists_list_recipients_recipient(list_id, recipient_id),
+        )
+    }
+    /// The blocking version of [`crate::contacts_api_lists::ContactsApiLists::delete_contactdb_lists_list_recipients_recipient`].
+    pub fn delete_contactdb_lists_list_recipients_recipient(
+        &self,
+        list_id: i64,
+        recipient_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete_contactdb_lists_list_recipients_recipient(
+                list_id,
+                recipient_id,
+                body,
+            ))
+    }
+}
@@ -0,0 +1,184 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`ContactsApiRecipients::get_contactdb_recipients`], set with a builder.
+pub struct ContactsApiRecipientsGetContactdbRecipientsRequest<'a> {
+    inner: crate::contacts_api_recipients::ContactsApiRecipientsGetContactdbRecipientsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ContactsApiRecipientsGetContactdbRecipientsRequest<'a> {
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::ListRecipientsResponse>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`ContactsApiRecipients::get_contactdb_recipients_search`], set with a builder.
+pub struct ContactsApiRecipientsGetContactdbRecipientsSearchRequest<'a> {
+    inner: crate::contacts_api_recipients::ContactsApiRecipientsGetContactdbRecipientsSearchRequest<
+        'a,
+    >,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ContactsApiRecipientsGetContactdbRecipientsSearchRequest<'a> {
+    /// Sets the `{field_name}` query parameter.
+    pub fn field_name(mut self, field_name: &'a str) -> Self {
+        self.inner = self.inner.field_name(field_name);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::GetContactdbRecipientsSearchResponse>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::contacts_api_recipients::ContactsApiRecipients`].
+pub struct ContactsApiRecipients {
+    inner: crate::contacts_api_recipients::ContactsApiRecipients,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl ContactsApiRecipients {
+    pub(crate) fn new(
+        inner: crate::contacts_api_recipients::ContactsApiRecipients,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        ContactsApiRecipients { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::contacts_api_recipients::ContactsApiRecipients::get_contactdb_recipients`].
+    pub fn get_contactdb_recipients(
+        &self,
+        page: i64,
+        page_size: i64,
+    ) -> ClientResult<crate::Response<crate::types::ListRecipientsResponse>> {
+        self.runtime
+            .block_on(self.inner.get_contactdb_recipients(page, page_size))
+    }
+    /// The blocking version of [`crate::contacts_api_recipients::ContactsApiRecipients::get_contactdb_recipients_request`].
+    pub fn get_contactdb_recipients_request(
+        &self,
+    ) -> ContactsApiRecipientsGetContactdbRecipientsRequest<'_> {
+        ContactsApiRecipientsGetContactdbRecipientsRequest {
+            inner: self.inner.get_contactdb_recipients_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::contacts_api_recipients::ContactsApiRecipients::post_contactdb_recipient`].
+    pub fn post_contactdb_recipient(
+        &self,
+        body: &[crate::types::PostContactdbRecipientsRequest],
+    ) -> ClientResult<crate::Response<crate::types::ContactDbRecipientResponse>> {
+        self.runtime
+            .block_on(self.inner.post_contactdb_recipient(body))
+    }
+    /// The blocking version of [`crate::contacts_api_recipients::ContactsApiRecipients::delete_contactdb_recipients`].
+    pub fn delete_contactdb_recipients(
+        &self,
+        body: &[String],
+    ) -> ClientResult<crate::Response<crate::types::Help>> {
+        self.runtime
+            .block_on(self.inner.delete_contactdb_recipients(body))
+    }
+    /// The blocking version of [`crate::contacts_api_recipients::ContactsApiRecipients::patch_contactdb_recipients`].
+    pub fn patch_contactdb_recipients(
+        &self,
+        body: &[crate::types::PatchContactdbRecipientsRequest],
+    ) -> ClientResult<crate::Response<crate::types::ContactDbRecipientResponse>> {
+        self.runtime
+            .block_on(self.inner.patch_contactdb_recipients(body))
+    }
+    /// The blocking version of [`crate::contacts_api_recipients::ContactsApiRecipients::get_contactdb_statu`].
+    pub fn get_contactdb_statu(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::GetContactdbStatusResponseData>> {
+        self.runtime.block_on(self.inner.get_contactdb_statu())
+    }
+    /// The blocking version of [`crate::contacts_api_recipients::ContactsApiRecipients::get_contactdb_recipients_recipient`].
+    pub fn get_contactdb_recipients_recipient(
+        &self,
+        recipient_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ContactdbRecipient>> {
+        self.runtime
+            .block_on(self.inner.get_contactdb_recipients_recipient(recipient_id))
+    }
+    /// The blocking version of [`crate::contacts_api_recipients::ContactsApiRecipients::delete_contactdb_recipients_recipient`].
+    pub fn delete_contactdb_recipients_recipient(
+        &self,
+        recipient_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Help>> {
+        self.runtime.block_on(
+            self.inner
+                .delete_contactdb_recipients_recipient(recipient_id),
+        )
+    }
+    /// The blocking version of [`crate::contacts_api_recipients::ContactsApiRecipients::get_contactdb_recipients_recipient_lists`].
+    pub fn get_contactdb_recipients_recipient_lists(
+        &self,
+        recipient_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::GetContactdbRecipientsRecipientListsResponse>>
+    {
+        self.runtime.block_on(
+            self.inner
+                .get_contactdb_recipients_recipient_lists(recipient_id),
+        )
+    }
+    /// The blocking version of [`crate::contacts_api_recipients::ContactsApiRecipients::get_contactdb_recipients_billable_count`].
+    pub fn get_contactdb_recipients_billable_count(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::ContactdbRecipientCount>> {
+        self.runtime
+            .block_on(self.inner.get_contactdb_recipients_billable_count())
+    }
+    /// The blocking version of [`crate::contacts_api_recipients::ContactsApiRecipients::get_contactdb_recipients_count`].
+    pub fn get_contactdb_recipients_count(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::ContactdbRecipientCount>> {
+        self.runtime
+            .block_on(self.inner.get_contactdb_recipients_count())
+    }
+    /// The blocking version of [`crate::contacts_api_recipients::ContactsApiRecipients::get_contactdb_recipients_search`].
+    pub fn get_contactdb_recipients_search(
+        &self,
+        field_name: &str,
+    ) -> ClientResult<crate::Response<crate::types::GetContactdbRecipientsSearchResponse>> {
+        self.runtime
+            .block_on(self.inner.get_contactdb_recipients_search(field_name))
+    }
+    /// The blocking version of [`crate::contacts_api_recipients::ContactsApiRecipients::get_contactdb_recipients_search_request`].
+    pub fn get_contactdb_recipients_search_request(
+        &self,
+    ) -> ContactsApiRecipientsGetContactdbRecipientsSearchRequest<'_> {
+        ContactsApiRecipientsGetContactdbRecipientsSearchRequest {
+            inner: self.inner.get_contactdb_recipients_search_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::contacts_api_recipients::ContactsApiRecipients::post_contactdb_recipients_search`].
+    pub fn post_contactdb_recipients_search(
+        &self,
+        body: &crate::types::PostContactdbRecipientsSearchRequest,
+    ) -> ClientResult<crate::Response<crate::types::PostContactdbRecipientsSearchResponseData>>
+    {
+        self.runtime
+            .block_on(self.inner.post_contactdb_recipients_search(body))
+    }
+}
@@ -0,0 +1,150 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`ContactsApiSegments::delete_contactdb_segments_segment`], set with a builder.
+pub struct ContactsApiSegmentsDeleteContactdbSegmentsSegmentRequest<'a> {
+    inner:
+        crate::contacts_api_segments::ContactsApiSegmentsDeleteContactdbSegmentsSegmentRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ContactsApiSegmentsDeleteContactdbSegmentsSegmentRequest<'a> {
+    /// Sets the `delete_contacts` query parameter.
+    pub fn delete_contacts(mut self, delete_contacts: bool) -> Self {
+        self.inner = self.inner.delete_contacts(delete_contacts);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`ContactsApiSegments::get_contactdb_segments_segment_recipients`], set with a builder.
+pub struct ContactsApiSegmentsGetContactdbSegmentsSegmentRecipientsRequest<'a> {
+            inner: crate::contacts_api_segments::ContactsApiSegmentsGetContactdbSegmentsSegmentRecipientsRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a> ContactsApiSegmentsGetContactdbSegmentsSegmentRecipientsRequest<'a> {
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::ListRecipientsOnASegmentResponse>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::contacts_api_segments::ContactsApiSegments`].
+pub struct ContactsApiSegments {
+    inner: crate::contacts_api_segments::ContactsApiSegments,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl ContactsApiSegments {
+    pub(crate) fn new(
+        inner: crate::contacts_api_segments::ContactsApiSegments,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        ContactsApiSegments { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::contacts_api_segments::ContactsApiSegments::get_contactdb_segments`].
+    pub fn get_contactdb_segments(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::ListAllSegmentsResponse>> {
+        self.runtime.block_on(self.inner.get_contactdb_segments())
+    }
+    /// The blocking version of [`crate::contacts_api_segments::ContactsApiSegments::post_contactdb_segment`].
+    pub fn post_contactdb_segment(
+        &self,
+        body: &crate::types::ContactdbSegments,
+    ) -> ClientResult<crate::Response<crate::types::ContactdbSegmentsWithAllOf>> {
+        self.runtime
+            .block_on(self.inner.post_contactdb_segment(body))
+    }
+    /// The blocking version of [`crate::contacts_api_segments::ContactsApiSegments::get_contactdb_segments_segment`].
+    pub fn get_contactdb_segments_segment(
+        &self,
+        segment_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ContactdbSegments>> {
+        self.runtime
+            .block_on(self.inner.get_contactdb_segments_segment(segment_id))
+    }
+    /// The blocking version of [`crate::contacts_api_segments::ContactsApiSegments::delete_contactdb_segments_segment`].
+    pub fn delete_contactdb_segments_segment(
+        &self,
+        segment_id: &str,
+        delete_contacts: bool,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete_contactdb_segments_segment(
+                segment_id,
+                delete_contacts,
+                body,
+            ))
+    }
+    /// The blocking version of [`crate::contacts_api_segments::ContactsApiSegments::delete_contactdb_segments_segment_request`].
+    pub fn delete_contactdb_segments_segment_request<'a>(
+        &'a self,
+        segment_id: &'a str,
+        body: &'a serde_json::Value,
+    ) -> ContactsApiSegmentsDeleteContactdbSegmentsSegmentRequest<'a> {
+        ContactsApiSegmentsDeleteContactdbSegmentsSegmentRequest {
+            inner: self
+                .inner
+                .delete_contactdb_segments_segment_request(segment_id, body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::contacts_api_segments::ContactsApiSegments::patch_contactdb_segments_segment`].
+    pub fn patch_contactdb_segments_segment(
+        &self,
+        segment_id: &str,
+        body: &crate::types::PatchContactdbSegmentsSegmentRequest,
+    ) -> ClientResult<crate::Response<crate::types::ContactdbSegments>> {
+        self.runtime.block_on(
+            self.inner
+                .patch_contactdb_segments_segment(segment_id, body),
+        )
+    }
+    /// The blocking version of [`crate::contacts_api_segments::ContactsApiSegments::get_contactdb_segments_segment_recipients`].
+    pub fn get_contactdb_segments_segment_recipients(
+        &self,
+        segment_id: i64,
+        page: i64,
+        page_size: i64,
+    ) -> ClientResult<crate::Response<crate::types::ListRecipientsOnASegmentResponse>> {
+        self.runtime.block_on(
+            self.inner
+                .get_contactdb_segments_segment_recipients(segment_id, page, page_size),
+        )
+    }
+    /// The blocking version of [`crate::contacts_api_segments::ContactsApiSegments::get_contactdb_segments_segment_recipients_request`].
+    pub fn get_contactdb_segments_segment_recipients_request(
+        &self,
+        segment_id: i64,
+    ) -> ContactsApiSegmentsGetContactdbSegmentsSegmentRecipientsRequest<'_> {
+        ContactsApiSegmentsGetContactdbSegmentsSegmentRecipientsRequest {
+            inner: self
+                .inner
+                .get_contactdb_segments_segment_recipients_request(segment_id),
+            runtime: &self.runtime,
+        }
+    }
+}
@@ -0,0 +1,61 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`CsvUiOnly::post_messages_download`], set with a builder.
+pub struct CsvUiOnlyPostMessagesDownloadRequest<'a> {
+    inner: crate::csv_ui_only::CsvUiOnlyPostMessagesDownloadRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> CsvUiOnlyPostMessagesDownloadRequest<'a> {
+    /// Sets the `query` query parameter.
+    pub fn query(mut self, query: &'a str) -> Self {
+        self.inner = self.inner.query(query);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::PostMessagesDownloadResponse>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::csv_ui_only::CsvUiOnly`].
+pub struct CsvUiOnly {
+    inner: crate::csv_ui_only::CsvUiOnly,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl CsvUiOnly {
+    pub(crate) fn new(
+        inner: crate::csv_ui_only::CsvUiOnly,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        CsvUiOnly { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::csv_ui_only::CsvUiOnly::post_messages_download`].
+    pub fn post_messages_download(
+        &self,
+        query: &str,
+    ) -> ClientResult<crate::Response<crate::types::PostMessagesDownloadResponse>> {
+        self.runtime
+            .block_on(self.inner.post_messages_download(query))
+    }
+    /// The blocking version of [`crate::csv_ui_only::CsvUiOnly::post_messages_download_request`].
+    pub fn post_messages_download_request(&self) -> CsvUiOnlyPostMessagesDownloadRequest<'_> {
+        CsvUiOnlyPostMessagesDownloadRequest {
+            inner: self.inner.post_messages_download_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::csv_ui_only::CsvUiOnly::get_messages_download`].
+    pub fn get_messages_download(
+        &self,
+        download_uuid: &str,
+    ) -> ClientResult<crate::Response<crate::types::GetMessagesDownloadResponse>> {
+        self.runtime
+            .block_on(self.inner.get_messages_download(download_uuid))
+    }
+}
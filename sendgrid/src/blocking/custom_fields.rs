@@ -0,0 +1,54 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::custom_fields::CustomFields`].
+pub struct CustomFields {
+    inner: crate::custom_fields::CustomFields,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl CustomFields {
+    pub(crate) fn new(
+        inner: crate::custom_fields::CustomFields,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        CustomFields { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::custom_fields::CustomFields::get_mc_field_definitions`].
+    pub fn get_mc_field_definitions(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::GetMcFieldDefinitionsResponse>> {
+        self.runtime.block_on(self.inner.get_mc_field_definitions())
+    }
+    /// The blocking version of [`crate::custom_fields::CustomFields::post_mc_field_definition`].
+    pub fn post_mc_field_definition(
+        &self,
+        body: &crate::types::PostMcFieldDefinitionsRequest,
+    ) -> ClientResult<crate::Response<crate::types::PostMcFieldDefinitionsResponseAllOf>> {
+        self.runtime
+            .block_on(self.inner.post_mc_field_definition(body))
+    }
+    /// The blocking version of [`crate::custom_fields::CustomFields::delete_mc_field_definitions_custom`].
+    pub fn delete_mc_field_definitions_custom(
+        &self,
+        custom_field_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .delete_mc_field_definitions_custom(custom_field_id),
+        )
+    }
+    /// The blocking version of [`crate::custom_fields::CustomFields::patch_mc_field_definitions_custom`].
+    pub fn patch_mc_field_definitions_custom(
+        &self,
+        custom_field_id: &str,
+        body: &crate::types::IpPool,
+    ) -> ClientResult<crate::Response<crate::types::PostMcFieldDefinitionsResponseAllOf>> {
+        self.runtime.block_on(
+            self.inner
+                .patch_mc_field_definitions_custom(custom_field_id, body),
+        )
+    }
+}
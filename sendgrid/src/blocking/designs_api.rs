@@ -0,0 +1,171 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`DesignsApi::list_designs`], set with a builder.
+pub struct DesignsApiListDesignsRequest<'a> {
+    inner: crate::designs_api::DesignsApiListDesignsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> DesignsApiListDesignsRequest<'a> {
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: u64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Sets the `page_token` query parameter.
+    pub fn page_token(mut self, page_token: &'a str) -> Self {
+        self.inner = self.inner.page_token(page_token);
+        self
+    }
+
+    /// Sets the `summary` query parameter.
+    pub fn summary(mut self, summary: bool) -> Self {
+        self.inner = self.inner.summary(summary);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::ListDesignsResponse>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`DesignsApi::list_sendgrid_pre_built_designs`], set with a builder.
+pub struct DesignsApiListSendgridPreBuiltDesignsRequest<'a> {
+    inner: crate::designs_api::DesignsApiListSendgridPreBuiltDesignsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> DesignsApiListSendgridPreBuiltDesignsRequest<'a> {
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: u64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Sets the `page_token` query parameter.
+    pub fn page_token(mut self, page_token: &'a str) -> Self {
+        self.inner = self.inner.page_token(page_token);
+        self
+    }
+
+    /// Sets the `summary` query parameter.
+    pub fn summary(mut self, summary: bool) -> Self {
+        self.inner = self.inner.summary(summary);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::ListDesignsResponse>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::designs_api::DesignsApi`].
+pub struct DesignsApi {
+    inner: crate::designs_api::DesignsApi,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl DesignsApi {
+    pub(crate) fn new(
+        inner: crate::designs_api::DesignsApi,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        DesignsApi { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::designs_api::DesignsApi::get_design`].
+    pub fn get_design(
+        &self,
+        id: &str,
+    ) -> ClientResult<crate::Response<crate::types::DesignOutputAllOf>> {
+        self.runtime.block_on(self.inner.get_design(id))
+    }
+    /// The blocking version of [`crate::designs_api::DesignsApi::post_duplicate_design`].
+    pub fn post_duplicate_design(
+        &self,
+        id: &str,
+        body: &crate::types::DesignDuplicateInput,
+    ) -> ClientResult<crate::Response<crate::types::DesignOutputAllOf>> {
+        self.runtime
+            .block_on(self.inner.post_duplicate_design(id, body))
+    }
+    /// The blocking version of [`crate::designs_api::DesignsApi::delete_design`].
+    pub fn delete_design(&self, id: &str) -> ClientResult<crate::Response<crate::types::Help>> {
+        self.runtime.block_on(self.inner.delete_design(id))
+    }
+    /// The blocking version of [`crate::designs_api::DesignsApi::put_design`].
+    pub fn put_design(
+        &self,
+        id: &str,
+        body: &crate::types::PutDesignRequest,
+    ) -> ClientResult<crate::Response<crate::types::DesignOutputAllOf>> {
+        self.runtime.block_on(self.inner.put_design(id, body))
+    }
+    /// The blocking version of [`crate::designs_api::DesignsApi::list_designs`].
+    pub fn list_designs(
+        &self,
+        page_size: u64,
+        page_token: &str,
+        summary: bool,
+    ) -> ClientResult<crate::Response<crate::types::ListDesignsResponse>> {
+        self.runtime
+            .block_on(self.inner.list_designs(page_size, page_token, summary))
+    }
+    /// The blocking version of [`crate::designs_api::DesignsApi::list_designs_request`].
+    pub fn list_designs_request(&self) -> DesignsApiListDesignsRequest<'_> {
+        DesignsApiListDesignsRequest {
+            inner: self.inner.list_designs_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::designs_api::DesignsApi::post_design`].
+    pub fn post_design(
+        &self,
+        body: &crate::types::DesignInputAllOf,
+    ) -> ClientResult<crate::Response<crate::types::DesignOutputAllOf>> {
+        self.runtime.block_on(self.inner.post_design(body))
+    }
+    /// The blocking version of [`crate::designs_api::DesignsApi::get_sendgrid_pre_built_design`].
+    pub fn get_sendgrid_pre_built_design(
+        &self,
+        id: &str,
+    ) -> ClientResult<crate::Response<crate::types::DesignOutputAllOf>> {
+        self.runtime
+            .block_on(self.inner.get_sendgrid_pre_built_design(id))
+    }
+    /// The blocking version of [`crate::designs_api::DesignsApi::post_sendgrid_pre_built_design`].
+    pub fn post_sendgrid_pre_built_design(
+        &self,
+        id: &str,
+        body: &crate::types::DesignDuplicateInput,
+    ) -> ClientResult<crate::Response<crate::types::DesignOutputAllOf>> {
+        self.runtime
+            .block_on(self.inner.post_sendgrid_pre_built_design(id, body))
+    }
+    /// The blocking version of [`crate::designs_api::DesignsApi::list_sendgrid_pre_built_designs`].
+    pub fn list_sendgrid_pre_built_designs(
+        &self,
+        page_size: u64,
+        page_token: &str,
+        summary: bool,
+    ) -> ClientResult<crate::Response<crate::types::ListDesignsResponse>> {
+        self.runtime.block_on(
+            self.inner
+                .list_sendgrid_pre_built_designs(page_size, page_token, summary),
+        )
+    }
+    /// The blocking version of [`crate::designs_api::DesignsApi::list_sendgrid_pre_built_designs_request`].
+    pub fn list_sendgrid_pre_built_designs_request(
+        &self,
+    ) -> DesignsApiListSendgridPreBuiltDesignsRequest<'_> {
+        DesignsApiListSendgridPreBuiltDesignsRequest {
+            inner: self.inner.list_sendgrid_pre_built_designs_request(),
+            runtime: &self.runtime,
+        }
+    }
+}
@@ -0,0 +1,273 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`DomainAuthentication::get_whitelabel_domains`], set with a builder.
+pub struct DomainAuthenticationGetWhitelabelDomainsRequest<'a> {
+    inner: crate::domain_authentication::DomainAuthenticationGetWhitelabelDomainsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> DomainAuthenticationGetWhitelabelDomainsRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.inner = self.inner.offset(offset);
+        self
+    }
+
+    /// Sets the `exclude_subusers` query parameter.
+    pub fn exclude_subusers(mut self, exclude_subusers: bool) -> Self {
+        self.inner = self.inner.exclude_subusers(exclude_subusers);
+        self
+    }
+
+    /// Sets the `username` query parameter.
+    pub fn username(mut self, username: &'a str) -> Self {
+        self.inner = self.inner.username(username);
+        self
+    }
+
+    /// Sets the `domain` query parameter.
+    pub fn domain(mut self, domain: &'a str) -> Self {
+        self.inner = self.inner.domain(domain);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(
+        self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::DomainAuthentication200ResponseAllOf>>>
+    {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`DomainAuthentication::get_whitelabel_domains_default`], set with a builder.
+pub struct DomainAuthenticationGetWhitelabelDomainsDefaultRequest<'a> {
+    inner: crate::domain_authentication::DomainAuthenticationGetWhitelabelDomainsDefaultRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> DomainAuthenticationGetWhitelabelDomainsDefaultRequest<'a> {
+    /// Sets the `domain` query parameter.
+    pub fn domain(mut self, domain: &'a str) -> Self {
+        self.inner = self.inner.domain(domain);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(
+        self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::DomainAuthentication200ResponseAllOf>>>
+    {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`DomainAuthentication::delete_whitelabel_domains_subuser`], set with a builder.
+pub struct DomainAuthenticationDeleteWhitelabelDomainsSubuserRequest<'a> {
+    inner:
+        crate::domain_authentication::DomainAuthenticationDeleteWhitelabelDomainsSubuserRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> DomainAuthenticationDeleteWhitelabelDomainsSubuserRequest<'a> {
+    /// Sets the `username` query parameter.
+    pub fn username(mut self, username: &'a str) -> Self {
+        self.inner = self.inner.username(username);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::Help>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::domain_authentication::DomainAuthentication`].
+pub struct DomainAuthentication {
+    inner: crate::domain_authentication::DomainAuthentication,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl DomainAuthentication {
+    pub(crate) fn new(
+        inner: crate::domain_authentication::DomainAuthentication,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        DomainAuthentication { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::domain_authentication::DomainAuthentication::get_whitelabel_domains`].
+    pub fn get_whitelabel_domains(
+        &self,
+        limit: i64,
+        offset: i64,
+        exclude_subusers: bool,
+        username: &str,
+        domain: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::DomainAuthentication200ResponseAllOf>>>
+    {
+        self.runtime.block_on(self.inner.get_whitelabel_domains(
+            limit,
+            offset,
+            exclude_subusers,
+            username,
+            domain,
+        ))
+    }
+    /// The blocking version of [`crate::domain_authentication::DomainAuthentication::get_whitelabel_domains_request`].
+    pub fn get_whitelabel_domains_request(
+        &self,
+    ) -> DomainAuthenticationGetWhitelabelDomainsRequest<'_> {
+        DomainAuthenticationGetWhitelabelDomainsRequest {
+            inner: self.inner.get_whitelabel_domains_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::domain_authentication::DomainAuthentication::get_all_whitelabel_domains`].
+    pub fn get_all_whitelabel_domains(
+        &self,
+        offset: i64,
+        exclude_subusers: bool,
+        username: &str,
+        domain: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::DomainAuthentication200ResponseAllOf>>>
+    {
+        self.runtime.block_on(self.inner.get_all_whitelabel_domains(
+            offset,
+            exclude_subusers,
+            username,
+            domain,
+        ))
+    }
+    /// The blocking version of [`crate::domain_authentication::DomainAuthentication::post_whitelabel_domain`].
+    pub fn post_whitelabel_domain(
+        &self,
+        body: &crate::types::PostWhitelabelDomainsRequest,
+    ) -> ClientResult<crate::Response<crate::types::AuthenticationDomain>> {
+        self.runtime
+            .block_on(self.inner.post_whitelabel_domain(body))
+    }
+    /// The blocking version of [`crate::domain_authentication::DomainAuthentication::get_whitelabel_domains_domain`].
+    pub fn get_whitelabel_domains_domain(
+        &self,
+        domain_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::AuthenticationDomain>> {
+        self.runtime
+            .block_on(self.inner.get_whitelabel_domains_domain(domain_id))
+    }
+    /// The blocking version of [`crate::domain_authentication::DomainAuthentication::delete_whitelabel_domains_domain`].
+    pub fn delete_whitelabel_domains_domain(
+        &self,
+        domain_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Help>> {
+        self.runtime
+            .block_on(self.inner.delete_whitelabel_domains_domain(domain_id))
+    }
+    /// The blocking version of [`crate::domain_authentication::DomainAuthentication::patch_whitelabel_domains_domain`].
+    pub fn patch_whitelabel_domains_domain(
+        &self,
+        domain_id: &str,
+        body: &crate::types::PatchWhitelabelDomainsDomainRequest,
+    ) -> ClientResult<crate::Response<Vec<crate::types::DomainAuthentication200ResponseAllOf>>>
+    {
+        self.runtime
+            .block_on(self.inner.patch_whitelabel_domains_domain(domain_id, body))
+    }
+    /// The blocking version of [`crate::domain_authentication::DomainAuthentication::get_whitelabel_domains_default`].
+    pub fn get_whitelabel_domains_default(
+        &self,
+        domain: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::DomainAuthentication200ResponseAllOf>>>
+    {
+        self.runtime
+            .block_on(self.inner.get_whitelabel_domains_default(domain))
+    }
+    /// The blocking version of [`crate::domain_authentication::DomainAuthentication::get_whitelabel_domains_default_request`].
+    pub fn get_whitelabel_domains_default_request(
+        &self,
+    ) -> DomainAuthenticationGetWhitelabelDomainsDefaultRequest<'_> {
+        DomainAuthenticationGetWhitelabelDomainsDefaultRequest {
+            inner: self.inner.get_whitelabel_domains_default_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::domain_authentication::DomainAuthentication::get_all_whitelabel_domains_default`].
+    pub fn get_all_whitelabel_domains_default(
+        &self,
+        domain: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::DomainAuthentication200ResponseAllOf>>>
+    {
+        self.runtime
+            .block_on(self.inner.get_all_whitelabel_domains_default(domain))
+    }
+    /// The blocking version of [`crate::domain_authentication::DomainAuthentication::post_whitelabel_domains_ip`].
+    pub fn post_whitelabel_domains_ip(
+        &self,
+        id: i64,
+        body: &crate::types::Ips,
+    ) -> ClientResult<crate::Response<crate::types::DomainAuthentication>> {
+        self.runtime
+            .block_on(self.inner.post_whitelabel_domains_ip(id, body))
+    }
+    /// The blocking version of [`crate::domain_authentication::DomainAuthentication::delete_whitelabel_domains_ips_ip`].
+    pub fn delete_whitelabel_domains_ips_ip(
+        &self,
+        id: i64,
+        ip: &str,
+    ) -> ClientResult<crate::Response<crate::types::DomainAuthentication>> {
+        self.runtime
+            .block_on(self.inner.delete_whitelabel_domains_ips_ip(id, ip))
+    }
+    /// The blocking version of [`crate::domain_authentication::DomainAuthentication::post_whitelabel_domains_validate`].
+    pub fn post_whitelabel_domains_validate(
+        &self,
+        id: i64,
+    ) -> ClientResult<crate::Response<crate::types::PostWhitelabelDomainsValidateResponse>> {
+        self.runtime
+            .block_on(self.inner.post_whitelabel_domains_validate(id))
+    }
+    /// The blocking version of [`crate::domain_authentication::DomainAuthentication::get_whitelabel_domains_subuser`].
+    pub fn get_whitelabel_domains_subuser(
+        &self,
+        username: &str,
+    ) -> ClientResult<crate::Response<crate::types::DomainAuthentication>> {
+        self.runtime
+            .block_on(self.inner.get_whitelabel_domains_subuser(username))
+    }
+    /// The blocking version of [`crate::domain_authentication::DomainAuthentication::delete_whitelabel_domains_subuser`].
+    pub fn delete_whitelabel_domains_subuser(
+        &self,
+        username: &str,
+    ) -> ClientResult<crate::Response<crate::types::Help>> {
+        self.runtime
+            .block_on(self.inner.delete_whitelabel_domains_subuser(username))
+    }
+    /// The blocking version of [`crate::domain_authentication::DomainAuthentication::delete_whitelabel_domains_subuser_request`].
+    pub fn delete_whitelabel_domains_subuser_request(
+        &self,
+    ) -> DomainAuthenticationDeleteWhitelabelDomainsSubuserRequest<'_> {
+        DomainAuthenticationDeleteWhitelabelDomainsSubuserRequest {
+            inner: self.inner.delete_whitelabel_domains_subuser_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::domain_authentication::DomainAuthentication::post_whitelabel_domains_domain_subuser`].
+    pub fn post_whitelabel_domains_domain_subuser(
+        &self,
+        domain_id: i64,
+        body: &crate::types::PutUserUsernameResponse,
+    ) -> ClientResult<crate::Response<crate::types::DomainAuthentication>> {
+        self.runtime.block_on(
+            self.inner
+                .post_whitelabel_domains_domain_subuser(domain_id, body),
+        )
+    }
+}
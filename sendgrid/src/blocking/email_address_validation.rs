@@ -0,0 +1,27 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::email_address_validation::EmailAddressValidation`].
+pub struct EmailAddressValidation {
+    inner: crate::email_address_validation::EmailAddressValidation,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl EmailAddressValidation {
+    pub(crate) fn new(
+        inner: crate::email_address_validation::EmailAddressValidation,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        EmailAddressValidation { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::email_address_validation::EmailAddressValidation::post_validations_email`].
+    pub fn post_validations_email(
+        &self,
+        body: &crate::types::PostValidationsEmailRequest,
+    ) -> ClientResult<crate::Response<crate::types::PostValidationsEmailResponse>> {
+        self.runtime
+            .block_on(self.inner.post_validations_email(body))
+    }
+}
@@ -0,0 +1,27 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::email_cname_records::EmailCnameRecords`].
+pub struct EmailCnameRecords {
+    inner: crate::email_cname_records::EmailCnameRecords,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl EmailCnameRecords {
+    pub(crate) fn new(
+        inner: crate::email_cname_records::EmailCnameRecords,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        EmailCnameRecords { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::email_cname_records::EmailCnameRecords::post_whitelabel_dns_email`].
+    pub fn post_whitelabel_dns_email(
+        &self,
+        body: &crate::types::PostWhitelabelDnsEmailRequest,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.post_whitelabel_dns_email(body))
+    }
+}
@@ -0,0 +1,122 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`InvalidEmailsApi::get_suppression_invalid_emails`], set with a builder.
+pub struct InvalidEmailsApiGetSuppressionInvalidEmailsRequest<'a> {
+    inner: crate::invalid_emails_api::InvalidEmailsApiGetSuppressionInvalidEmailsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> InvalidEmailsApiGetSuppressionInvalidEmailsRequest<'a> {
+    /// Sets the `start_time` query parameter.
+    pub fn start_time(mut self, start_time: i64) -> Self {
+        self.inner = self.inner.start_time(start_time);
+        self
+    }
+
+    /// Sets the `end_time` query parameter.
+    pub fn end_time(mut self, end_time: i64) -> Self {
+        self.inner = self.inner.end_time(end_time);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.inner = self.inner.offset(offset);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::InvalidEmail>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::invalid_emails_api::InvalidEmailsApi`].
+pub struct InvalidEmailsApi {
+    inner: crate::invalid_emails_api::InvalidEmailsApi,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl InvalidEmailsApi {
+    pub(crate) fn new(
+        inner: crate::invalid_emails_api::InvalidEmailsApi,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        InvalidEmailsApi { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::invalid_emails_api::InvalidEmailsApi::get_suppression_invalid_emails`].
+    pub fn get_suppression_invalid_emails(
+        &self,
+        start_time: i64,
+        end_time: i64,
+        limit: i64,
+        offset: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::InvalidEmail>>> {
+        self.runtime.block_on(
+            self.inner
+                .get_suppression_invalid_emails(start_time, end_time, limit, offset),
+        )
+    }
+    /// The blocking version of [`crate::invalid_emails_api::InvalidEmailsApi::get_suppression_invalid_emails_request`].
+    pub fn get_suppression_invalid_emails_request(
+        &self,
+    ) -> InvalidEmailsApiGetSuppressionInvalidEmailsRequest<'_> {
+        InvalidEmailsApiGetSuppressionInvalidEmailsRequest {
+            inner: self.inner.get_suppression_invalid_emails_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::invalid_emails_api::InvalidEmailsApi::get_all_suppression_invalid_emails`].
+    pub fn get_all_suppression_invalid_emails(
+        &self,
+        start_time: i64,
+        end_time: i64,
+        offset: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::InvalidEmail>>> {
+        self.runtime.block_on(
+            self.inner
+                .get_all_suppression_invalid_emails(start_time, end_time, offset),
+        )
+    }
+    /// The blocking version of [`crate::invalid_emails_api::InvalidEmailsApi::delete_suppression_invalid_emails`].
+    pub fn delete_suppression_invalid_emails(
+        &self,
+        body: &crate::types::DeleteSuppressionBlocksRequest,
+    ) -> ClientResult<crate::Response<crate::types::Help>> {
+        self.runtime
+            .block_on(self.inner.delete_suppression_invalid_emails(body))
+    }
+    /// The blocking version of [`crate::invalid_emails_api::InvalidEmailsApi::get_suppression_invalid_emails_email`].
+    pub fn get_suppression_invalid_emails_email(
+        &self,
+        email: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::InvalidEmail>>> {
+        self.runtime
+            .block_on(self.inner.get_suppression_invalid_emails_email(email))
+    }
+    /// The blocking version of [`crate::invalid_emails_api::InvalidEmailsApi::get_all_suppression_invalid_emails_email`].
+    pub fn get_all_suppression_invalid_emails_email(
+        &self,
+        email: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::InvalidEmail>>> {
+        self.runtime
+            .block_on(self.inner.get_all_suppression_invalid_emails_email(email))
+    }
+    /// The blocking version of [`crate::invalid_emails_api::InvalidEmailsApi::delete_suppression_invalid_emails_email`].
+    pub fn delete_suppression_invalid_emails_email(
+        &self,
+        email: &str,
+    ) -> ClientResult<crate::Response<crate::types::Help>> {
+        self.runtime
+            .block_on(self.inner.delete_suppression_invalid_emails_email(email))
+    }
+}
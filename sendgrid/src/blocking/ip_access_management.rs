@@ -0,0 +1,96 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`IpAccessManagement::get_access_settings_activity`], set with a builder.
+pub struct IpAccessManagementGetAccessSettingsActivityRequest<'a> {
+    inner: crate::ip_access_management::IpAccessManagementGetAccessSettingsActivityRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> IpAccessManagementGetAccessSettingsActivityRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::GetAccessSettingsActivityResponse>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::ip_access_management::IpAccessManagement`].
+pub struct IpAccessManagement {
+    inner: crate::ip_access_management::IpAccessManagement,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl IpAccessManagement {
+    pub(crate) fn new(
+        inner: crate::ip_access_management::IpAccessManagement,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        IpAccessManagement { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::ip_access_management::IpAccessManagement::get_access_settings_whitelist`].
+    pub fn get_access_settings_whitelist(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::IpAccessResponse>> {
+        self.runtime
+            .block_on(self.inner.get_access_settings_whitelist())
+    }
+    /// The blocking version of [`crate::ip_access_management::IpAccessManagement::post_access_settings_whitelist`].
+    pub fn post_access_settings_whitelist(
+        &self,
+        body: &crate::types::PostAccessSettingsWhitelistRequest,
+    ) -> ClientResult<crate::Response<crate::types::IpAccessResponse>> {
+        self.runtime
+            .block_on(self.inner.post_access_settings_whitelist(body))
+    }
+    /// The blocking version of [`crate::ip_access_management::IpAccessManagement::delete_access_settings_whitelist`].
+    pub fn delete_access_settings_whitelist(
+        &self,
+        body: &crate::types::DeleteAccessSettingsWhitelistRequest,
+    ) -> ClientResult<crate::Response<crate::types::Help>> {
+        self.runtime
+            .block_on(self.inner.delete_access_settings_whitelist(body))
+    }
+    /// The blocking version of [`crate::ip_access_management::IpAccessManagement::get_access_settings_activity`].
+    pub fn get_access_settings_activity(
+        &self,
+        limit: i64,
+    ) -> ClientResult<crate::Response<crate::types::GetAccessSettingsActivityResponse>> {
+        self.runtime
+            .block_on(self.inner.get_access_settings_activity(limit))
+    }
+    /// The blocking version of [`crate::ip_access_management::IpAccessManagement::get_access_settings_activity_request`].
+    pub fn get_access_settings_activity_request(
+        &self,
+    ) -> IpAccessManagementGetAccessSettingsActivityRequest<'_> {
+        IpAccessManagementGetAccessSettingsActivityRequest {
+            inner: self.inner.get_access_settings_activity_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::ip_access_management::IpAccessManagement::get_access_settings_whitelist_rule`].
+    pub fn get_access_settings_whitelist_rule(
+        &self,
+        rule_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::IpAccessResponse>> {
+        self.runtime
+            .block_on(self.inner.get_access_settings_whitelist_rule(rule_id))
+    }
+    /// The blocking version of [`crate::ip_access_management::IpAccessManagement::delete_access_settings_whitelist_rule`].
+    pub fn delete_access_settings_whitelist_rule(
+        &self,
+        rule_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Help>> {
+        self.runtime
+            .block_on(self.inner.delete_access_settings_whitelist_rule(rule_id))
+    }
+}
@@ -0,0 +1,144 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`IpAddresses::get_ips`], set with a builder.
+pub struct IpAddressesGetIpsRequest<'a> {
+    inner: crate::ip_addresses::IpAddressesGetIpsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> IpAddressesGetIpsRequest<'a> {
+    /// Sets the `ip` query parameter.
+    pub fn ip(mut self, ip: &'a str) -> Self {
+        self.inner = self.inner.ip(ip);
+        self
+    }
+
+    /// Sets the `exclude_whitelabels` query parameter.
+    pub fn exclude_whitelabels(mut self, exclude_whitelabels: bool) -> Self {
+        self.inner = self.inner.exclude_whitelabels(exclude_whitelabels);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.inner = self.inner.offset(offset);
+        self
+    }
+
+    /// Sets the `subuser` query parameter.
+    pub fn subuser(mut self, subuser: &'a str) -> Self {
+        self.inner = self.inner.subuser(subuser);
+        self
+    }
+
+    /// Sets the `sort_by_direction` query parameter.
+    pub fn sort_by_direction(mut self, sort_by_direction: crate::types::SortByDirection) -> Self {
+        self.inner = self.inner.sort_by_direction(sort_by_direction);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::GetIpsResponse>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::ip_addresses::IpAddresses`].
+pub struct IpAddresses {
+    inner: crate::ip_addresses::IpAddresses,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl IpAddresses {
+    pub(crate) fn new(
+        inner: crate::ip_addresses::IpAddresses,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        IpAddresses { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::ip_addresses::IpAddresses::get_ips`].
+    pub fn get_ips(
+        &self,
+        ip: &str,
+        exclude_whitelabels: bool,
+        limit: i64,
+        offset: i64,
+        subuser: &str,
+        sort_by_direction: crate::types::SortByDirection,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GetIpsResponse>>> {
+        self.runtime.block_on(self.inner.get_ips(
+            ip,
+            exclude_whitelabels,
+            limit,
+            offset,
+            subuser,
+            sort_by_direction,
+        ))
+    }
+    /// The blocking version of [`crate::ip_addresses::IpAddresses::get_ips_request`].
+    pub fn get_ips_request(&self) -> IpAddressesGetIpsRequest<'_> {
+        IpAddressesGetIpsRequest {
+            inner: self.inner.get_ips_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::ip_addresses::IpAddresses::get_all_ips`].
+    pub fn get_all_ips(
+        &self,
+        ip: &str,
+        exclude_whitelabels: bool,
+        offset: i64,
+        subuser: &str,
+        sort_by_direction: crate::types::SortByDirection,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GetIpsResponse>>> {
+        self.runtime.block_on(self.inner.get_all_ips(
+            ip,
+            exclude_whitelabels,
+            offset,
+            subuser,
+            sort_by_direction,
+        ))
+    }
+    /// The blocking version of [`crate::ip_addresses::IpAddresses::post_ip`].
+    pub fn post_ip(
+        &self,
+        body: &crate::types::PostIpsRequest,
+    ) -> ClientResult<crate::Response<crate::types::PostIpsResponseData>> {
+        self.runtime.block_on(self.inner.post_ip(body))
+    }
+    /// The blocking version of [`crate::ip_addresses::IpAddresses::get_ips_remaining`].
+    pub fn get_ips_remaining(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::GetIpsRemainingResponse>> {
+        self.runtime.block_on(self.inner.get_ips_remaining())
+    }
+    /// The blocking version of [`crate::ip_addresses::IpAddresses::get_ips_assigned`].
+    pub fn get_ips_assigned(
+        &self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GetIpsAssignedResponse>>> {
+        self.runtime.block_on(self.inner.get_ips_assigned())
+    }
+    /// The blocking version of [`crate::ip_addresses::IpAddresses::get_all_ips_assigned`].
+    pub fn get_all_ips_assigned(
+        &self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GetIpsAssignedResponse>>> {
+        self.runtime.block_on(self.inner.get_all_ips_assigned())
+    }
+    /// The blocking version of [`crate::ip_addresses::IpAddresses::get_ips_ip_address`].
+    pub fn get_ips_ip_address(
+        &self,
+        ip_address: &str,
+    ) -> ClientResult<crate::Response<crate::types::GetIpsIpAddressResponse>> {
+        self.runtime
+            .block_on(self.inner.get_ips_ip_address(ip_address))
+    }
+}
@@ -0,0 +1,81 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::ip_pools::IpPools`].
+pub struct IpPools {
+    inner: crate::ip_pools::IpPools,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl IpPools {
+    pub(crate) fn new(
+        inner: crate::ip_pools::IpPools,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        IpPools { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::ip_pools::IpPools::get_ips_pools`].
+    pub fn get_ips_pools(
+        &self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::IpPoolsPoolResp>>> {
+        self.runtime.block_on(self.inner.get_ips_pools())
+    }
+    /// The blocking version of [`crate::ip_pools::IpPools::get_all_ips_pools`].
+    pub fn get_all_ips_pools(
+        &self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::IpPoolsPoolResp>>> {
+        self.runtime.block_on(self.inner.get_all_ips_pools())
+    }
+    /// The blocking version of [`crate::ip_pools::IpPools::post_ips_pool`].
+    pub fn post_ips_pool(
+        &self,
+        body: &crate::types::IpPool,
+    ) -> ClientResult<crate::Response<crate::types::IpPoolsPoolResp>> {
+        self.runtime.block_on(self.inner.post_ips_pool(body))
+    }
+    /// The blocking version of [`crate::ip_pools::IpPools::post_ips_pools_pool_name_ip`].
+    pub fn post_ips_pools_pool_name_ip(
+        &self,
+        pool_name: &str,
+        body: &crate::types::PostIpsWarmupRequest,
+    ) -> ClientResult<crate::Response<crate::types::GetIpsAssignedResponse>> {
+        self.runtime
+            .block_on(self.inner.post_ips_pools_pool_name_ip(pool_name, body))
+    }
+    /// The blocking version of [`crate::ip_pools::IpPools::get_ips_pools_pool_name`].
+    pub fn get_ips_pools_pool_name(
+        &self,
+        pool_name: &str,
+    ) -> ClientResult<crate::Response<crate::types::GetIpsPoolsPoolNameResponse>> {
+        self.runtime
+            .block_on(self.inner.get_ips_pools_pool_name(pool_name))
+    }
+    /// The blocking version of [`crate::ip_pools::IpPools::put_ips_pools_pool_name`].
+    pub fn put_ips_pools_pool_name(
+        &self,
+        pool_name: &str,
+        body: &crate::types::PutIpsPoolsPoolNameRequest,
+    ) -> ClientResult<crate::Response<crate::types::IpPoolsPoolResp>> {
+        self.runtime
+            .block_on(self.inner.put_ips_pools_pool_name(pool_name, body))
+    }
+    /// The blocking version of [`crate::ip_pools::IpPools::delete_ips_pools_pool_name`].
+    pub fn delete_ips_pools_pool_name(
+        &self,
+        pool_name: &str,
+    ) -> ClientResult<crate::Response<crate::types::Help>> {
+        self.runtime
+            .block_on(self.inner.delete_ips_pools_pool_name(pool_name))
+    }
+    /// The blocking version of [`crate::ip_pools::IpPools::delete_ips_pools_pool_name_ip`].
+    pub fn delete_ips_pools_pool_name_ip(
+        &self,
+        pool_name: &str,
+        ip: &str,
+    ) -> ClientResult<crate::Response<crate::types::Help>> {
+        self.runtime
+            .block_on(self.inner.delete_ips_pools_pool_name_ip(pool_name, ip))
+    }
+}
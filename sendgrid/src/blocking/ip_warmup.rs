@@ -0,0 +1,62 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::ip_warmup::IpWarmup`].
+pub struct IpWarmup {
+    inner: crate::ip_warmup::IpWarmup,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl IpWarmup {
+    pub(crate) fn new(
+        inner: crate::ip_warmup::IpWarmup,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        IpWarmup { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::ip_warmup::IpWarmup::get_ips_warmup`].
+    pub fn get_ips_warmup(
+        &self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::IpWarmupResponse>>> {
+        self.runtime.block_on(self.inner.get_ips_warmup())
+    }
+    /// The blocking version of [`crate::ip_warmup::IpWarmup::get_all_ips_warmup`].
+    pub fn get_all_ips_warmup(
+        &self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::IpWarmupResponse>>> {
+        self.runtime.block_on(self.inner.get_all_ips_warmup())
+    }
+    /// The blocking version of [`crate::ip_warmup::IpWarmup::post_ips_warmup`].
+    pub fn post_ips_warmup(
+        &self,
+        body: &crate::types::PostIpsWarmupRequest,
+    ) -> ClientResult<crate::Response<Vec<crate::types::IpWarmupResponse>>> {
+        self.runtime.block_on(self.inner.post_ips_warmup(body))
+    }
+    /// The blocking version of [`crate::ip_warmup::IpWarmup::get_ips_warmup_ip_address`].
+    pub fn get_ips_warmup_ip_address(
+        &self,
+        ip_address: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::IpWarmupResponse>>> {
+        self.runtime
+            .block_on(self.inner.get_ips_warmup_ip_address(ip_address))
+    }
+    /// The blocking version of [`crate::ip_warmup::IpWarmup::get_all_ips_warmup_ip_address`].
+    pub fn get_all_ips_warmup_ip_address(
+        &self,
+        ip_address: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::IpWarmupResponse>>> {
+        self.runtime
+            .block_on(self.inner.get_all_ips_warmup_ip_address(ip_address))
+    }
+    /// The blocking version of [`crate::ip_warmup::IpWarmup::delete_ips_warmup_ip_address`].
+    pub fn delete_ips_warmup_ip_address(
+        &self,
+        ip_address: &str,
+    ) -> ClientResult<crate::Response<crate::types::Help>> {
+        self.runtime
+            .block_on(self.inner.delete_ips_warmup_ip_address(ip_address))
+    }
+}
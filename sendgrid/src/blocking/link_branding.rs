@@ -0,0 +1,160 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`LinkBranding::get_whitelabel_links`], set with a builder.
+pub struct LinkBrandingGetWhitelabelLinksRequest<'a> {
+    inner: crate::link_branding::LinkBrandingGetWhitelabelLinksRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> LinkBrandingGetWhitelabelLinksRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::LinkBranding200Response>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`LinkBranding::get_whitelabel_links_default`], set with a builder.
+pub struct LinkBrandingGetWhitelabelLinksDefaultRequest<'a> {
+    inner: crate::link_branding::LinkBrandingGetWhitelabelLinksDefaultRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> LinkBrandingGetWhitelabelLinksDefaultRequest<'a> {
+    /// Sets the `domain` query parameter.
+    pub fn domain(mut self, domain: &'a str) -> Self {
+        self.inner = self.inner.domain(domain);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::LinkBranding200Response>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::link_branding::LinkBranding`].
+pub struct LinkBranding {
+    inner: crate::link_branding::LinkBranding,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl LinkBranding {
+    pub(crate) fn new(
+        inner: crate::link_branding::LinkBranding,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        LinkBranding { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::link_branding::LinkBranding::get_whitelabel_links`].
+    pub fn get_whitelabel_links(
+        &self,
+        limit: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::LinkBranding200Response>>> {
+        self.runtime
+            .block_on(self.inner.get_whitelabel_links(limit))
+    }
+    /// The blocking version of [`crate::link_branding::LinkBranding::get_whitelabel_links_request`].
+    pub fn get_whitelabel_links_request(&self) -> LinkBrandingGetWhitelabelLinksRequest<'_> {
+        LinkBrandingGetWhitelabelLinksRequest {
+            inner: self.inner.get_whitelabel_links_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::link_branding::LinkBranding::get_all_whitelabel_links`].
+    pub fn get_all_whitelabel_links(
+        &self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::LinkBranding200Response>>> {
+        self.runtime.block_on(self.inner.get_all_whitelabel_links())
+    }
+    /// The blocking version of [`crate::link_branding::LinkBranding::post_whitelabel_link`].
+    pub fn post_whitelabel_link(
+        &self,
+        body: &crate::types::PostWhitelabelLinksRequest,
+    ) -> ClientResult<crate::Response<crate::types::LinkBranding200Response>> {
+        self.runtime.block_on(self.inner.post_whitelabel_link(body))
+    }
+    /// The blocking version of [`crate::link_branding::LinkBranding::post_whitelabel_links_validate`].
+    pub fn post_whitelabel_links_validate(
+        &self,
+        id: i64,
+    ) -> ClientResult<crate::Response<crate::types::PostWhitelabelLinksValidateResponse>> {
+        self.runtime
+            .block_on(self.inner.post_whitelabel_links_validate(id))
+    }
+    /// The blocking version of [`crate::link_branding::LinkBranding::post_whitelabel_links_link_subuser`].
+    pub fn post_whitelabel_links_link_subuser(
+        &self,
+        link_id: i64,
+        body: &crate::types::PostWhitelabelLinksLinkSubuserRequest,
+    ) -> ClientResult<crate::Response<crate::types::LinkBranding200Response>> {
+        self.runtime
+            .block_on(self.inner.post_whitelabel_links_link_subuser(link_id, body))
+    }
+    /// The blocking version of [`crate::link_branding::LinkBranding::get_whitelabel_links_link_branding`].
+    pub fn get_whitelabel_links_link_branding(
+        &self,
+        id: i64,
+    ) -> ClientResult<crate::Response<crate::types::LinkBranding200Response>> {
+        self.runtime
+            .block_on(self.inner.get_whitelabel_links_link_branding(id))
+    }
+    /// The blocking version of [`crate::link_branding::LinkBranding::delete_whitelabel_links`].
+    pub fn delete_whitelabel_links(
+        &self,
+        id: i64,
+    ) -> ClientResult<crate::Response<crate::types::Help>> {
+        self.runtime
+            .block_on(self.inner.delete_whitelabel_links(id))
+    }
+    /// The blocking version of [`crate::link_branding::LinkBranding::patch_whitelabel_links`].
+    pub fn patch_whitelabel_links(
+        &self,
+        id: i64,
+        body: &crate::types::PatchWhitelabelLinksRequest,
+    ) -> ClientResult<crate::Response<crate::types::LinkBranding200Response>> {
+        self.runtime
+            .block_on(self.inner.patch_whitelabel_links(id, body))
+    }
+    /// The blocking version of [`crate::link_branding::LinkBranding::get_whitelabel_links_default`].
+    pub fn get_whitelabel_links_default(
+        &self,
+        domain: &str,
+    ) -> ClientResult<crate::Response<crate::types::LinkBranding200Response>> {
+        self.runtime
+            .block_on(self.inner.get_whitelabel_links_default(domain))
+    }
+    /// The blocking version of [`crate::link_branding::LinkBranding::get_whitelabel_links_default_request`].
+    pub fn get_whitelabel_links_default_request(
+        &self,
+    ) -> LinkBrandingGetWhitelabelLinksDefaultRequest<'_> {
+        LinkBrandingGetWhitelabelLinksDefaultRequest {
+            inner: self.inner.get_whitelabel_links_default_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::link_branding::LinkBranding::get_whitelabel_links_subuser`].
+    pub fn get_whitelabel_links_subuser(
+        &self,
+        username: &str,
+    ) -> ClientResult<crate::Response<crate::types::LinkBranding200Response>> {
+        self.runtime
+            .block_on(self.inner.get_whitelabel_links_subuser(username))
+    }
+    /// The blocking version of [`crate::link_branding::LinkBranding::delete_whitelabel_links_subuser`].
+    pub fn delete_whitelabel_links_subuser(
+        &self,
+        username: &str,
+    ) -> ClientResult<crate::Response<crate::types::Help>> {
+        self.runtime
+            .block_on(self.inner.delete_whitelabel_links_subuser(username))
+    }
+}
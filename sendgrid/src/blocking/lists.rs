@@ -0,0 +1,158 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Lists::get_mc`], set with a builder.
+pub struct ListsGetMcRequest<'a> {
+    inner: crate::lists::ListsGetMcRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ListsGetMcRequest<'a> {
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: f64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Sets the `page_token` query parameter.
+    pub fn page_token(mut self, page_token: &'a str) -> Self {
+        self.inner = self.inner.page_token(page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::GetMcListsResponse>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Lists::get_mc_lists`], set with a builder.
+pub struct ListsGetMcListsRequest<'a> {
+    inner: crate::lists::ListsGetMcListsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ListsGetMcListsRequest<'a> {
+    /// Sets the `contact_sample` query parameter.
+    pub fn contact_sample(mut self, contact_sample: bool) -> Self {
+        self.inner = self.inner.contact_sample(contact_sample);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::GetMcListsResponseAllOf>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Lists::delete`], set with a builder.
+pub struct ListsDeleteRequest<'a> {
+    inner: crate::lists::ListsDeleteRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ListsDeleteRequest<'a> {
+    /// Sets the `delete_contacts` query parameter.
+    pub fn delete_contacts(mut self, delete_contacts: bool) -> Self {
+        self.inner = self.inner.delete_contacts(delete_contacts);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::DeleteListsResponse>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::lists::Lists`].
+pub struct Lists {
+    inner: crate::lists::Lists,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Lists {
+    pub(crate) fn new(inner: crate::lists::Lists, runtime: Arc<tokio::runtime::Runtime>) -> Self {
+        Lists { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::lists::Lists::get_mc`].
+    pub fn get_mc(
+        &self,
+        page_size: f64,
+        page_token: &str,
+    ) -> ClientResult<crate::Response<crate::types::GetMcListsResponse>> {
+        self.runtime
+            .block_on(self.inner.get_mc(page_size, page_token))
+    }
+    /// The blocking version of [`crate::lists::Lists::get_mc_request`].
+    pub fn get_mc_request(&self) -> ListsGetMcRequest<'_> {
+        ListsGetMcRequest {
+            inner: self.inner.get_mc_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::lists::Lists::post_mc`].
+    pub fn post_mc(
+        &self,
+        body: &crate::types::IpPool,
+    ) -> ClientResult<crate::Response<crate::types::List>> {
+        self.runtime.block_on(self.inner.post_mc(body))
+    }
+    /// The blocking version of [`crate::lists::Lists::get_mc_contacts_count`].
+    pub fn get_mc_contacts_count(
+        &self,
+        id: &str,
+    ) -> ClientResult<crate::Response<crate::types::GetMcListsContactsCountResponse>> {
+        self.runtime.block_on(self.inner.get_mc_contacts_count(id))
+    }
+    /// The blocking version of [`crate::lists::Lists::get_mc_lists`].
+    pub fn get_mc_lists(
+        &self,
+        id: &str,
+        contact_sample: bool,
+    ) -> ClientResult<crate::Response<crate::types::GetMcListsResponseAllOf>> {
+        self.runtime
+            .block_on(self.inner.get_mc_lists(id, contact_sample))
+    }
+    /// The blocking version of [`crate::lists::Lists::get_mc_lists_request`].
+    pub fn get_mc_lists_request<'a>(&'a self, id: &'a str) -> ListsGetMcListsRequest<'a> {
+        ListsGetMcListsRequest {
+            inner: self.inner.get_mc_lists_request(id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::lists::Lists::delete`].
+    pub fn delete(
+        &self,
+        id: &str,
+        delete_contacts: bool,
+    ) -> ClientResult<crate::Response<crate::types::DeleteListsResponse>> {
+        self.runtime
+            .block_on(self.inner.delete(id, delete_contacts))
+    }
+    /// The blocking version of [`crate::lists::Lists::delete_request`].
+    pub fn delete_request<'a>(&'a self, id: &'a str) -> ListsDeleteRequest<'a> {
+        ListsDeleteRequest {
+            inner: self.inner.delete_request(id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::lists::Lists::patch_mc`].
+    pub fn patch_mc(
+        &self,
+        id: &str,
+        body: &crate::types::PatchMcListsRequest,
+    ) -> ClientResult<crate::Response<crate::types::List>> {
+        self.runtime.block_on(self.inner.patch_mc(id, body))
+    }
+    /// The blocking version of [`crate::lists::Lists::delete_mc_contacts`].
+    pub fn delete_mc_contacts(
+        &self,
+        id: &str,
+        contact_ids: &str,
+    ) -> ClientResult<crate::Response<crate::types::DeleteMcListsContactsResponse>> {
+        self.runtime
+            .block_on(self.inner.delete_mc_contacts(id, contact_ids))
+    }
+}
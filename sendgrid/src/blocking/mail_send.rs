@@ -0,0 +1,26 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::mail_send::MailSend`].
+pub struct MailSend {
+    inner: crate::mail_send::MailSend,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl MailSend {
+    pub(crate) fn new(
+        inner: crate::mail_send::MailSend,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        MailSend { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::mail_send::MailSend::post`].
+    pub fn post(
+        &self,
+        body: &crate::types::PostMailSendRequest,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.post(body))
+    }
+}
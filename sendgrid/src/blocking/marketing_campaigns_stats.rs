@@ -0,0 +1,537 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`MarketingCampaignsStats::getall_automation_stats`], set with a builder.
+pub struct MarketingCampaignsStatsGetallAutomationStatsRequest<'a> {
+    inner:
+        crate::marketing_campaigns_stats::MarketingCampaignsStatsGetallAutomationStatsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> MarketingCampaignsStatsGetallAutomationStatsRequest<'a> {
+    /// Sets the `automation_ids` query parameter.
+    pub fn automation_ids(mut self, automation_ids: &'a [String]) -> Self {
+        self.inner = self.inner.automation_ids(automation_ids);
+        self
+    }
+
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Sets the `page_token` query parameter.
+    pub fn page_token(mut self, page_token: &'a str) -> Self {
+        self.inner = self.inner.page_token(page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::AutomationsResponse>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`MarketingCampaignsStats::get_automation_stat`], set with a builder.
+pub struct MarketingCampaignsStatsGetAutomationStatRequest<'a> {
+    inner: crate::marketing_campaigns_stats::MarketingCampaignsStatsGetAutomationStatRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> MarketingCampaignsStatsGetAutomationStatRequest<'a> {
+    /// Sets the `group_by` query parameter.
+    pub fn group_by(mut self, group_by: &'a [String]) -> Self {
+        self.inner = self.inner.group_by(group_by);
+        self
+    }
+
+    /// Sets the `step_ids` query parameter.
+    pub fn step_ids(mut self, step_ids: &'a [String]) -> Self {
+        self.inner = self.inner.step_ids(step_ids);
+        self
+    }
+
+    /// Sets the `aggregated_by` query parameter.
+    pub fn aggregated_by(mut self, aggregated_by: crate::types::AggregatedBy) -> Self {
+        self.inner = self.inner.aggregated_by(aggregated_by);
+        self
+    }
+
+    /// Sets the `start_date` query parameter.
+    pub fn start_date(mut self, start_date: chrono::NaiveDate) -> Self {
+        self.inner = self.inner.start_date(start_date);
+        self
+    }
+
+    /// Sets the `end_date` query parameter.
+    pub fn end_date(mut self, end_date: chrono::NaiveDate) -> Self {
+        self.inner = self.inner.end_date(end_date);
+        self
+    }
+
+    /// Sets the `timezone` query parameter.
+    pub fn timezone(mut self, timezone: &'a str) -> Self {
+        self.inner = self.inner.timezone(timezone);
+        self
+    }
+
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Sets the `page_token` query parameter.
+    pub fn page_token(mut self, page_token: &'a str) -> Self {
+        self.inner = self.inner.page_token(page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::AutomationsResponse>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`MarketingCampaignsStats::getall_singlesend_stats`], set with a builder.
+pub struct MarketingCampaignsStatsGetallSinglesendStatsRequest<'a> {
+    inner:
+        crate::marketing_campaigns_stats::MarketingCampaignsStatsGetallSinglesendStatsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> MarketingCampaignsStatsGetallSinglesendStatsRequest<'a> {
+    /// Sets the `singlesend_ids` query parameter.
+    pub fn singlesend_ids(mut self, singlesend_ids: &'a [String]) -> Self {
+        self.inner = self.inner.singlesend_ids(singlesend_ids);
+        self
+    }
+
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Sets the `page_token` query parameter.
+    pub fn page_token(mut self, page_token: &'a str) -> Self {
+        self.inner = self.inner.page_token(page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::SinglesendsResponse>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`MarketingCampaignsStats::get_singlesend_stat`], set with a builder.
+pub struct MarketingCampaignsStatsGetSinglesendStatRequest<'a> {
+    inner: crate::marketing_campaigns_stats::MarketingCampaignsStatsGetSinglesendStatRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> MarketingCampaignsStatsGetSinglesendStatRequest<'a> {
+    /// Sets the `aggregated_by` query parameter.
+    pub fn aggregated_by(mut self, aggregated_by: crate::types::AggregatedBy) -> Self {
+        self.inner = self.inner.aggregated_by(aggregated_by);
+        self
+    }
+
+    /// Sets the `start_date` query parameter.
+    pub fn start_date(mut self, start_date: chrono::NaiveDate) -> Self {
+        self.inner = self.inner.start_date(start_date);
+        self
+    }
+
+    /// Sets the `end_date` query parameter.
+    pub fn end_date(mut self, end_date: chrono::NaiveDate) -> Self {
+        self.inner = self.inner.end_date(end_date);
+        self
+    }
+
+    /// Sets the `timezone` query parameter.
+    pub fn timezone(mut self, timezone: &'a str) -> Self {
+        self.inner = self.inner.timezone(timezone);
+        self
+    }
+
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Sets the `page_token` query parameter.
+    pub fn page_token(mut self, page_token: &'a str) -> Self {
+        self.inner = self.inner.page_token(page_token);
+        self
+    }
+
+    /// Sets the `group_by` query parameter.
+    pub fn group_by(mut self, group_by: &'a [String]) -> Self {
+        self.inner = self.inner.group_by(group_by);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::SinglesendsResponse>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`MarketingCampaignsStats::get_automation_link_stat`], set with a builder.
+pub struct MarketingCampaignsStatsGetAutomationLinkStatRequest<'a> {
+    inner:
+        crate::marketing_campaigns_stats::MarketingCampaignsStatsGetAutomationLinkStatRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> MarketingCampaignsStatsGetAutomationLinkStatRequest<'a> {
+    /// Sets the `group_by` query parameter.
+    pub fn group_by(mut self, group_by: &'a [String]) -> Self {
+        self.inner = self.inner.group_by(group_by);
+        self
+    }
+
+    /// Sets the `step_ids` query parameter.
+    pub fn step_ids(mut self, step_ids: &'a [String]) -> Self {
+        self.inner = self.inner.step_ids(step_ids);
+        self
+    }
+
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Sets the `page_token` query parameter.
+    pub fn page_token(mut self, page_token: &'a str) -> Self {
+        self.inner = self.inner.page_token(page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::AutomationsLinkStatsResponse>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`MarketingCampaignsStats::get_singlesend_link_stat`], set with a builder.
+pub struct MarketingCampaignsStatsGetSinglesendLinkStatRequest<'a> {
+    inner:
+        crate::marketing_campaigns_stats::MarketingCampaignsStatsGetSinglesendLinkStatRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> MarketingCampaignsStatsGetSinglesendLinkStatRequest<'a> {
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Sets the `page_token` query parameter.
+    pub fn page_token(mut self, page_token: &'a str) -> Self {
+        self.inner = self.inner.page_token(page_token);
+        self
+    }
+
+    /// Sets the `group_by` query parameter.
+    pub fn group_by(mut self, group_by: &'a [String]) -> Self {
+        self.inner = self.inner.group_by(group_by);
+        self
+    }
+
+    /// Sets the `ab_variation_id` query parameter.
+    pub fn ab_variation_id(mut self, ab_variation_id: &'a str) -> Self {
+        self.inner = self.inner.ab_variation_id(ab_variation_id);
+        self
+    }
+
+    /// Sets the `ab_phase_id` query parameter.
+    pub fn ab_phase_id(mut self, ab_phase_id: crate::types::AbPhaseId) -> Self {
+        self.inner = self.inner.ab_phase_id(ab_phase_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::SinglesendsLinkStatsResponse>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`MarketingCampaignsStats::get_singlesend_stats_export`], set with a builder.
+pub struct MarketingCampaignsStatsGetSinglesendStatsExportRequest<'a> {
+    inner: crate::marketing_campaigns_stats::MarketingCampaignsStatsGetSinglesendStatsExportRequest<
+        'a,
+    >,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> MarketingCampaignsStatsGetSinglesendStatsExportRequest<'a> {
+    /// Sets the `ids` query parameter.
+    pub fn ids(mut self, ids: &'a [String]) -> Self {
+        self.inner = self.inner.ids(ids);
+        self
+    }
+
+    /// Sets the `timezone` query parameter.
+    pub fn timezone(mut self, timezone: &'a str) -> Self {
+        self.inner = self.inner.timezone(timezone);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<String>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`MarketingCampaignsStats::get_automations_stats_export`], set with a builder.
+pub struct MarketingCampaignsStatsGetAutomationsStatsExportRequest<'a> {
+    inner:
+        crate::marketing_campaigns_stats::MarketingCampaignsStatsGetAutomationsStatsExportRequest<
+            'a,
+        >,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> MarketingCampaignsStatsGetAutomationsStatsExportRequest<'a> {
+    /// Sets the `ids` query parameter.
+    pub fn ids(mut self, ids: &'a [String]) -> Self {
+        self.inner = self.inner.ids(ids);
+        self
+    }
+
+    /// Sets the `timezone` query parameter.
+    pub fn timezone(mut self, timezone: &'a str) -> Self {
+        self.inner = self.inner.timezone(timezone);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<String>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::marketing_campaigns_stats::MarketingCampaignsStats`].
+pub struct MarketingCampaignsStats {
+    inner: crate::marketing_campaigns_stats::MarketingCampaignsStats,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl MarketingCampaignsStats {
+    pub(crate) fn new(
+        inner: crate::marketing_campaigns_stats::MarketingCampaignsStats,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        MarketingCampaignsStats { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::marketing_campaigns_stats::MarketingCampaignsStats::getall_automation_stats`].
+    pub fn getall_automation_stats(
+        &self,
+        automation_ids: &[String],
+        page_size: i64,
+        page_token: &str,
+    ) -> ClientResult<crate::Response<crate::types::AutomationsResponse>> {
+        self.runtime.block_on(self.inner.getall_automation_stats(
+            automation_ids,
+            page_size,
+            page_token,
+        ))
+    }
+    /// The blocking version of [`crate::marketing_campaigns_stats::MarketingCampaignsStats::getall_automation_stats_request`].
+    pub fn getall_automation_stats_request(
+        &self,
+    ) -> MarketingCampaignsStatsGetallAutomationStatsRequest<'_> {
+        MarketingCampaignsStatsGetallAutomationStatsRequest {
+            inner: self.inner.getall_automation_stats_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::marketing_campaigns_stats::MarketingCampaignsStats::get_automation_stat`].
+    pub fn get_automation_stat(
+        &self,
+        id: &str,
+        group_by: &[String],
+        step_ids: &[String],
+        aggregated_by: crate::types::AggregatedBy,
+        start_date: chrono::NaiveDate,
+        end_date: chrono::NaiveDate,
+        timezone: &str,
+        page_size: i64,
+        page_token: &str,
+    ) -> ClientResult<crate::Response<crate::types::AutomationsResponse>> {
+        self.runtime.block_on(self.inner.get_automation_stat(
+            id,
+            group_by,
+            step_ids,
+            aggregated_by,
+            start_date,
+            end_date,
+            timezone,
+            page_size,
+            page_token,
+        ))
+    }
+    /// The blocking version of [`crate::marketing_campaigns_stats::MarketingCampaignsStats::get_automation_stat_request`].
+    pub fn get_automation_stat_request<'a>(
+        &'a self,
+        id: &'a str,
+    ) -> MarketingCampaignsStatsGetAutomationStatRequest<'a> {
+        MarketingCampaignsStatsGetAutomationStatRequest {
+            inner: self.inner.get_automation_stat_request(id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::marketing_campaigns_stats::MarketingCampaignsStats::getall_singlesend_stats`].
+    pub fn getall_singlesend_stats(
+        &self,
+        singlesend_ids: &[String],
+        page_size: i64,
+        page_token: &str,
+    ) -> ClientResult<crate::Response<crate::types::SinglesendsResponse>> {
+        self.runtime.block_on(self.inner.getall_singlesend_stats(
+            singlesend_ids,
+            page_size,
+            page_token,
+        ))
+    }
+    /// The blocking version of [`crate::marketing_campaigns_stats::MarketingCampaignsStats::getall_singlesend_stats_request`].
+    pub fn getall_singlesend_stats_request(
+        &self,
+    ) -> MarketingCampaignsStatsGetallSinglesendStatsRequest<'_> {
+        MarketingCampaignsStatsGetallSinglesendStatsRequest {
+            inner: self.inner.getall_singlesend_stats_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::marketing_campaigns_stats::MarketingCampaignsStats::get_singlesend_stat`].
+    pub fn get_singlesend_stat(
+        &self,
+        id: &str,
+        aggregated_by: crate::types::AggregatedBy,
+        start_date: chrono::NaiveDate,
+        end_date: chrono::NaiveDate,
+        timezone: &str,
+        page_size: i64,
+        page_token: &str,
+        group_by: &[String],
+    ) -> ClientResult<crate::Response<crate::types::SinglesendsResponse>> {
+        self.runtime.block_on(self.inner.get_singlesend_stat(
+            id,
+            aggregated_by,
+            start_date,
+            end_date,
+            timezone,
+            page_size,
+            page_token,
+            group_by,
+        ))
+    }
+    /// The blocking version of [`crate::marketing_campaigns_stats::MarketingCampaignsStats::get_singlesend_stat_request`].
+    pub fn get_singlesend_stat_request<'a>(
+        &'a self,
+        id: &'a str,
+    ) -> MarketingCampaignsStatsGetSinglesendStatRequest<'a> {
+        MarketingCampaignsStatsGetSinglesendStatRequest {
+            inner: self.inner.get_singlesend_stat_request(id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::marketing_campaigns_stats::MarketingCampaignsStats::get_automation_link_stat`].
+    pub fn get_automation_link_stat(
+        &self,
+        id: &str,
+        group_by: &[String],
+        step_ids: &[String],
+        page_size: i64,
+        page_token: &str,
+    ) -> ClientResult<crate::Response<crate::types::AutomationsLinkStatsResponse>> {
+        self.runtime.block_on(
+            self.inner
+                .get_automation_link_stat(id, group_by, step_ids, page_size, page_token),
+        )
+    }
+    /// The blocking version of [`crate::marketing_campaigns_stats::MarketingCampaignsStats::get_automation_link_stat_request`].
+    pub fn get_automation_link_stat_request<'a>(
+        &'a self,
+        id: &'a str,
+    ) -> MarketingCampaignsStatsGetAutomationLinkStatRequest<'a> {
+        MarketingCampaignsStatsGetAutomationLinkStatRequest {
+            inner: self.inner.get_automation_link_stat_request(id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::marketing_campaigns_stats::MarketingCampaignsStats::get_singlesend_link_stat`].
+    pub fn get_singlesend_link_stat(
+        &self,
+        id: &str,
+        page_size: i64,
+        page_token: &str,
+        group_by: &[String],
+        ab_variation_id: &str,
+        ab_phase_id: crate::types::AbPhaseId,
+    ) -> ClientResult<crate::Response<crate::types::SinglesendsLinkStatsResponse>> {
+        self.runtime.block_on(self.inner.get_singlesend_link_stat(
+            id,
+            page_size,
+            page_token,
+            group_by,
+            ab_variation_id,
+            ab_phase_id,
+        ))
+    }
+    /// The blocking version of [`crate::marketing_campaigns_stats::MarketingCampaignsStats::get_singlesend_link_stat_request`].
+    pub fn get_singlesend_link_stat_request<'a>(
+        &'a self,
+        id: &'a str,
+    ) -> MarketingCampaignsStatsGetSinglesendLinkStatRequest<'a> {
+        MarketingCampaignsStatsGetSinglesendLinkStatRequest {
+            inner: self.inner.get_singlesend_link_stat_request(id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::marketing_campaigns_stats::MarketingCampaignsStats::get_singlesend_stats_export`].
+    pub fn get_singlesend_stats_export(
+        &self,
+        ids: &[String],
+        timezone: &str,
+    ) -> ClientResult<crate::Response<String>> {
+        self.runtime
+            .block_on(self.inner.get_singlesend_stats_export(ids, timezone))
+    }
+    /// The blocking version of [`crate::marketing_campaigns_stats::MarketingCampaignsStats::get_singlesend_stats_export_request`].
+    pub fn get_singlesend_stats_export_request(
+        &self,
+    ) -> MarketingCampaignsStatsGetSinglesendStatsExportRequest<'_> {
+        MarketingCampaignsStatsGetSinglesendStatsExportRequest {
+            inner: self.inner.get_singlesend_stats_export_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::marketing_campaigns_stats::MarketingCampaignsStats::get_automations_stats_export`].
+    pub fn get_automations_stats_export(
+        &self,
+        ids: &[String],
+        timezone: &str,
+    ) -> ClientResult<crate::Response<String>> {
+        self.runtime
+            .block_on(self.inner.get_automations_stats_export(ids, timezone))
+    }
+    /// The blocking version of [`crate::marketing_campaigns_stats::MarketingCampaignsStats::get_automations_stats_export_request`].
+    pub fn get_automations_stats_export_request(
+        &self,
+    ) -> MarketingCampaignsStatsGetAutomationsStatsExportRequest<'_> {
+        MarketingCampaignsStatsGetAutomationsStatsExportRequest {
+            inner: self.inner.get_automations_stats_export_request(),
+            runtime: &self.runtime,
+        }
+    }
+}
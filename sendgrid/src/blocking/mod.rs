@@ -0,0 +1,475 @@
+//! A blocking client, for the programs that are not async.
+//!
+//! It has the functions of the async [`Client`](crate::Client), which it runs on a
+//! runtime of its own, so they must not be called from within an async runtime. The
+//! `*_stream` functions are left out, the `*_all` ones returning all the pages at once.
+
+use std::{future::Future, sync::Arc};
+
+pub mod alerts;
+pub mod api_key_permissions;
+pub mod api_keys;
+pub mod blocks_api;
+pub mod bounces_api;
+pub mod campaigns_api;
+pub mod cancel_scheduled_sends;
+pub mod categories;
+pub mod certificates;
+pub mod contacts;
+pub mod contacts_api_custom_fields;
+pub mod contacts_api_lists;
+pub mod contacts_api_recipients;
+pub mod contacts_api_segments;
+pub mod csv_ui_only;
+pub mod custom_fields;
+pub mod designs_api;
+pub mod domain_authentication;
+pub mod email_address_validation;
+pub mod email_cname_records;
+pub mod invalid_emails_api;
+pub mod ip_access_management;
+pub mod ip_addresses;
+pub mod ip_pools;
+pub mod ip_warmup;
+pub mod link_branding;
+pub mod lists;
+pub mod mail_send;
+pub mod marketing_campaigns_stats;
+pub mod query;
+pub mod reverse_dns;
+pub mod segmenting_contacts;
+pub mod segmenting_contacts_beta;
+pub mod send_test_email;
+pub mod sender_identities_api;
+pub mod sender_verification;
+pub mod senders;
+pub mod settings_enforced_tls;
+pub mod settings_inbound_parse;
+pub mod settings_mail;
+pub mod settings_partner;
+pub mod settings_tracking;
+pub mod single_sends;
+pub mod single_sign_on_settings;
+pub mod single_sign_on_teammates;
+pub mod spam_reports_api;
+pub mod stats;
+pub mod subuser_monitor_settings;
+pub mod subuser_statistics;
+pub mod subusers_api;
+pub mod suppressions;
+pub mod suppressions_global;
+pub mod suppressions_unsubscribe_groups;
+pub mod teammates;
+pub mod transactional_templates;
+pub mod transactional_templates_versions;
+pub mod users_api;
+pub mod webhooks;
+
+/// A blocking client, sending its requests with an async [`Client`](crate::Client).
+#[derive(Clone)]
+pub struct Client {
+    inner: crate::Client,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Client {
+    /// Wraps an async client, creating the runtime its requests run on.
+    pub fn new(client: crate::Client) -> std::io::Result<Self> {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()?;
+
+        Ok(Client {
+            inner: client,
+            runtime: Arc::new(runtime),
+        })
+    }
+
+    /// Returns the async client the requests are sent with.
+    pub fn inner(&self) -> &crate::Client {
+        &self.inner
+    }
+
+    /// Runs a future to completion on the runtime of the client, for the async
+    /// functions that have no blocking version.
+    pub fn block_on<F: Future>(&self, future: F) -> F::Output {
+        self.runtime.block_on(future)
+    }
+
+    /// The blocking version of [`crate::Client::alerts`].
+    pub fn alerts(&self) -> alerts::Alerts {
+        alerts::Alerts::new(self.inner.alerts(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::api_key_permissions`].
+    pub fn api_key_permissions(&self) -> api_key_permissions::ApiKeyPermissions {
+        api_key_permissions::ApiKeyPermissions::new(
+            self.inner.api_key_permissions(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::api_keys`].
+    pub fn api_keys(&self) -> api_keys::ApiKeys {
+        api_keys::ApiKeys::new(self.inner.api_keys(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::blocks_api`].
+    pub fn blocks_api(&self) -> blocks_api::BlocksApi {
+        blocks_api::BlocksApi::new(self.inner.blocks_api(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::bounces_api`].
+    pub fn bounces_api(&self) -> bounces_api::BouncesApi {
+        bounces_api::BouncesApi::new(self.inner.bounces_api(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::campaigns_api`].
+    pub fn campaigns_api(&self) -> campaigns_api::CampaignsApi {
+        campaigns_api::CampaignsApi::new(self.inner.campaigns_api(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::cancel_scheduled_sends`].
+    pub fn cancel_scheduled_sends(&self) -> cancel_scheduled_sends::CancelScheduledSends {
+        cancel_scheduled_sends::CancelScheduledSends::new(
+            self.inner.cancel_scheduled_sends(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::categories`].
+    pub fn categories(&self) -> categories::Categories {
+        categories::Categories::new(self.inner.categories(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::certificates`].
+    pub fn certificates(&self) -> certificates::Certificates {
+        certificates::Certificates::new(self.inner.certificates(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::contacts`].
+    pub fn contacts(&self) -> contacts::Contacts {
+        contacts::Contacts::new(self.inner.contacts(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::contacts_api_custom_fields`].
+    pub fn contacts_api_custom_fields(
+        &self,
+    ) -> contacts_api_custom_fields::ContactsApiCustomFields {
+        contacts_api_custom_fields::ContactsApiCustomFields::new(
+            self.inner.contacts_api_custom_fields(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::contacts_api_lists`].
+    pub fn contacts_api_lists(&self) -> contacts_api_lists::ContactsApiLists {
+        contacts_api_lists::ContactsApiLists::new(
+            self.inner.contacts_api_lists(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::contacts_api_recipients`].
+    pub fn contacts_api_recipients(&self) -> contacts_api_recipients::ContactsApiRecipients {
+        contacts_api_recipients::ContactsApiRecipients::new(
+            self.inner.contacts_api_recipients(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::contacts_api_segments`].
+    pub fn contacts_api_segments(&self) -> contacts_api_segments::ContactsApiSegments {
+        contacts_api_segments::ContactsApiSegments::new(
+            self.inner.contacts_api_segments(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::csv_ui_only`].
+    pub fn csv_ui_only(&self) -> csv_ui_only::CsvUiOnly {
+        csv_ui_only::CsvUiOnly::new(self.inner.csv_ui_only(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::custom_fields`].
+    pub fn custom_fields(&self) -> custom_fields::CustomFields {
+        custom_fields::CustomFields::new(self.inner.custom_fields(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::designs_api`].
+    pub fn designs_api(&self) -> designs_api::DesignsApi {
+        designs_api::DesignsApi::new(self.inner.designs_api(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::domain_authentication`].
+    pub fn domain_authentication(&self) -> domain_authentication::DomainAuthentication {
+        domain_authentication::DomainAuthentication::new(
+            self.inner.domain_authentication(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::email_address_validation`].
+    pub fn email_address_validation(&self) -> email_address_validation::EmailAddressValidation {
+        email_address_validation::EmailAddressValidation::new(
+            self.inner.email_address_validation(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::email_cname_records`].
+    pub fn email_cname_records(&self) -> email_cname_records::EmailCnameRecords {
+        email_cname_records::EmailCnameRecords::new(
+            self.inner.email_cname_records(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::invalid_emails_api`].
+    pub fn invalid_emails_api(&self) -> invalid_emails_api::InvalidEmailsApi {
+        invalid_emails_api::InvalidEmailsApi::new(
+            self.inner.invalid_emails_api(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::ip_access_management`].
+    pub fn ip_access_management(&self) -> ip_access_management::IpAccessManagement {
+        ip_access_management::IpAccessManagement::new(
+            self.inner.ip_access_management(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::ip_addresses`].
+    pub fn ip_addresses(&self) -> ip_addresses::IpAddresses {
+        ip_addresses::IpAddresses::new(self.inner.ip_addresses(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::ip_pools`].
+    pub fn ip_pools(&self) -> ip_pools::IpPools {
+        ip_pools::IpPools::new(self.inner.ip_pools(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::ip_warmup`].
+    pub fn ip_warmup(&self) -> ip_warmup::IpWarmup {
+        ip_warmup::IpWarmup::new(self.inner.ip_warmup(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::link_branding`].
+    pub fn link_branding(&self) -> link_branding::LinkBranding {
+        link_branding::LinkBranding::new(self.inner.link_branding(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::lists`].
+    pub fn lists(&self) -> lists::Lists {
+        lists::Lists::new(self.inner.lists(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::mail_send`].
+    pub fn mail_send(&self) -> mail_send::MailSend {
+        mail_send::MailSend::new(self.inner.mail_send(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::marketing_campaigns_stats`].
+    pub fn marketing_campaigns_stats(&self) -> marketing_campaigns_stats::MarketingCampaignsStats {
+        marketing_campaigns_stats::MarketingCampaignsStats::new(
+            self.inner.marketing_campaigns_stats(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::query`].
+    pub fn query(&self) -> query::Query {
+        query::Query::new(self.inner.query(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::reverse_dns`].
+    pub fn reverse_dns(&self) -> reverse_dns::ReverseDns {
+        reverse_dns::ReverseDns::new(self.inner.reverse_dns(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::segmenting_contacts`].
+    pub fn segmenting_contacts(&self) -> segmenting_contacts::SegmentingContacts {
+        segmenting_contacts::SegmentingContacts::new(
+            self.inner.segmenting_contacts(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::segmenting_contacts_beta`].
+    pub fn segmenting_contacts_beta(&self) -> segmenting_contacts_beta::SegmentingContactsBeta {
+        segmenting_contacts_beta::SegmentingContactsBeta::new(
+            self.inner.segmenting_contacts_beta(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::send_test_email`].
+    pub fn send_test_email(&self) -> send_test_email::SendTestEmail {
+        send_test_email::SendTestEmail::new(self.inner.send_test_email(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::sender_identities_api`].
+    pub fn sender_identities_api(&self) -> sender_identities_api::SenderIdentitiesApi {
+        sender_identities_api::SenderIdentitiesApi::new(
+            self.inner.sender_identities_api(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::sender_verification`].
+    pub fn sender_verification(&self) -> sender_verification::SenderVerification {
+        sender_verification::SenderVerification::new(
+            self.inner.sender_verification(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::senders`].
+    pub fn senders(&self) -> senders::Senders {
+        senders::Senders::new(self.inner.senders(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::settings_enforced_tls`].
+    pub fn settings_enforced_tls(&self) -> settings_enforced_tls::SettingsEnforcedTls {
+        settings_enforced_tls::SettingsEnforcedTls::new(
+            self.inner.settings_enforced_tls(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::settings_inbound_parse`].
+    pub fn settings_inbound_parse(&self) -> settings_inbound_parse::SettingsInboundParse {
+        settings_inbound_parse::SettingsInboundParse::new(
+            self.inner.settings_inbound_parse(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::settings_mail`].
+    pub fn settings_mail(&self) -> settings_mail::SettingsMail {
+        settings_mail::SettingsMail::new(self.inner.settings_mail(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::settings_partner`].
+    pub fn settings_partner(&self) -> settings_partner::SettingsPartner {
+        settings_partner::SettingsPartner::new(self.inner.settings_partner(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::settings_tracking`].
+    pub fn settings_tracking(&self) -> settings_tracking::SettingsTracking {
+        settings_tracking::SettingsTracking::new(
+            self.inner.settings_tracking(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::single_sends`].
+    pub fn single_sends(&self) -> single_sends::SingleSends {
+        single_sends::SingleSends::new(self.inner.single_sends(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::single_sign_on_settings`].
+    pub fn single_sign_on_settings(&self) -> single_sign_on_settings::SingleSignOnSettings {
+        single_sign_on_settings::SingleSignOnSettings::new(
+            self.inner.single_sign_on_settings(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::single_sign_on_teammates`].
+    pub fn single_sign_on_teammates(&self) -> single_sign_on_teammates::SingleSignOnTeammates {
+        single_sign_on_teammates::SingleSignOnTeammates::new(
+            self.inner.single_sign_on_teammates(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::spam_reports_api`].
+    pub fn spam_reports_api(&self) -> spam_reports_api::SpamReportsApi {
+        spam_reports_api::SpamReportsApi::new(self.inner.spam_reports_api(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::stats`].
+    pub fn stats(&self) -> stats::Stats {
+        stats::Stats::new(self.inner.stats(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::subuser_monitor_settings`].
+    pub fn subuser_monitor_settings(&self) -> subuser_monitor_settings::SubuserMonitorSettings {
+        subuser_monitor_settings::SubuserMonitorSettings::new(
+            self.inner.subuser_monitor_settings(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::subuser_statistics`].
+    pub fn subuser_statistics(&self) -> subuser_statistics::SubuserStatistics {
+        subuser_statistics::SubuserStatistics::new(
+            self.inner.subuser_statistics(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::subusers_api`].
+    pub fn subusers_api(&self) -> subusers_api::SubusersApi {
+        subusers_api::SubusersApi::new(self.inner.subusers_api(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::suppressions`].
+    pub fn suppressions(&self) -> suppressions::Suppressions {
+        suppressions::Suppressions::new(self.inner.suppressions(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::suppressions_global`].
+    pub fn suppressions_global(&self) -> suppressions_global::SuppressionsGlobal {
+        suppressions_global::SuppressionsGlobal::new(
+            self.inner.suppressions_global(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::suppressions_unsubscribe_groups`].
+    pub fn suppressions_unsubscribe_groups(
+        &self,
+    ) -> suppressions_unsubscribe_groups::SuppressionsUnsubscribeGroups {
+        suppressions_unsubscribe_groups::SuppressionsUnsubscribeGroups::new(
+            self.inner.suppressions_unsubscribe_groups(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::teammates`].
+    pub fn teammates(&self) -> teammates::Teammates {
+        teammates::Teammates::new(self.inner.teammates(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::transactional_templates`].
+    pub fn transactional_templates(&self) -> transactional_templates::TransactionalTemplates {
+        transactional_templates::TransactionalTemplates::new(
+            self.inner.transactional_templates(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::transactional_templates_versions`].
+    pub fn transactional_templates_versions(
+        &self,
+    ) -> transactional_templates_versions::TransactionalTemplatesVersions {
+        transactional_templates_versions::TransactionalTemplatesVersions::new(
+            self.inner.transactional_templates_versions(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::users_api`].
+    pub fn users_api(&self) -> users_api::UsersApi {
+        users_api::UsersApi::new(self.inner.users_api(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::webhooks`].
+    pub fn webhooks(&self) -> webhooks::Webhooks {
+        webhooks::Webhooks::new(self.inner.webhooks(), self.runtime.clone())
+    }
+}
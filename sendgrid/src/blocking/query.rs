@@ -0,0 +1,57 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Query::get_messages`], set with a builder.
+pub struct QueryGetMessagesRequest<'a> {
+    inner: crate::query::QueryGetMessagesRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> QueryGetMessagesRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: f64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::GetMessagesResponse>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::query::Query`].
+pub struct Query {
+    inner: crate::query::Query,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Query {
+    pub(crate) fn new(inner: crate::query::Query, runtime: Arc<tokio::runtime::Runtime>) -> Self {
+        Query { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::query::Query::get_messages`].
+    pub fn get_messages(
+        &self,
+        query: &str,
+        limit: f64,
+    ) -> ClientResult<crate::Response<crate::types::GetMessagesResponse>> {
+        self.runtime.block_on(self.inner.get_messages(query, limit))
+    }
+    /// The blocking version of [`crate::query::Query::get_messages_request`].
+    pub fn get_messages_request<'a>(&'a self, query: &'a str) -> QueryGetMessagesRequest<'a> {
+        QueryGetMessagesRequest {
+            inner: self.inner.get_messages_request(query),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::query::Query::get_messages_msg`].
+    pub fn get_messages_msg(
+        &self,
+        msg_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Message>> {
+        self.runtime.block_on(self.inner.get_messages_msg(msg_id))
+    }
+}
@@ -0,0 +1,105 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`ReverseDns::get_whitelabel_ips`], set with a builder.
+pub struct ReverseDnsGetWhitelabelIpsRequest<'a> {
+    inner: crate::reverse_dns::ReverseDnsGetWhitelabelIpsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ReverseDnsGetWhitelabelIpsRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.inner = self.inner.offset(offset);
+        self
+    }
+
+    /// Sets the `ip` query parameter.
+    pub fn ip(mut self, ip: &'a str) -> Self {
+        self.inner = self.inner.ip(ip);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::ReverseDns>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::reverse_dns::ReverseDns`].
+pub struct ReverseDns {
+    inner: crate::reverse_dns::ReverseDns,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl ReverseDns {
+    pub(crate) fn new(
+        inner: crate::reverse_dns::ReverseDns,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        ReverseDns { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::reverse_dns::ReverseDns::get_whitelabel_ips`].
+    pub fn get_whitelabel_ips(
+        &self,
+        limit: i64,
+        offset: i64,
+        ip: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ReverseDns>>> {
+        self.runtime
+            .block_on(self.inner.get_whitelabel_ips(limit, offset, ip))
+    }
+    /// The blocking version of [`crate::reverse_dns::ReverseDns::get_whitelabel_ips_request`].
+    pub fn get_whitelabel_ips_request(&self) -> ReverseDnsGetWhitelabelIpsRequest<'_> {
+        ReverseDnsGetWhitelabelIpsRequest {
+            inner: self.inner.get_whitelabel_ips_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::reverse_dns::ReverseDns::get_all_whitelabel_ips`].
+    pub fn get_all_whitelabel_ips(
+        &self,
+        offset: i64,
+        ip: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ReverseDns>>> {
+        self.runtime
+            .block_on(self.inner.get_all_whitelabel_ips(offset, ip))
+    }
+    /// The blocking version of [`crate::reverse_dns::ReverseDns::post_whitelabel_ip`].
+    pub fn post_whitelabel_ip(
+        &self,
+        body: &crate::types::PostWhitelabelIpsRequest,
+    ) -> ClientResult<crate::Response<crate::types::ReverseDns>> {
+        self.runtime.block_on(self.inner.post_whitelabel_ip(body))
+    }
+    /// The blocking version of [`crate::reverse_dns::ReverseDns::post_whitelabel_ips_validate`].
+    pub fn post_whitelabel_ips_validate(
+        &self,
+        id: &str,
+    ) -> ClientResult<crate::Response<crate::types::PostWhitelabelIpsValidateResponse>> {
+        self.runtime
+            .block_on(self.inner.post_whitelabel_ips_validate(id))
+    }
+    /// The blocking version of [`crate::reverse_dns::ReverseDns::get_whitelabel_ip`].
+    pub fn get_whitelabel_ip(
+        &self,
+        id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ReverseDns>> {
+        self.runtime.block_on(self.inner.get_whitelabel_ip(id))
+    }
+    /// The blocking version of [`crate::reverse_dns::ReverseDns::delete_whitelabel_ips`].
+    pub fn delete_whitelabel_ips(
+        &self,
+        id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Help>> {
+        self.runtime.block_on(self.inner.delete_whitelabel_ips(id))
+    }
+}
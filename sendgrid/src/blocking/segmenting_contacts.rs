@@ -0,0 +1,141 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`SegmentingContacts::get_marketing_segments`], set with a builder.
+pub struct SegmentingContactsGetMarketingSegmentsRequest<'a> {
+    inner: crate::segmenting_contacts::SegmentingContactsGetMarketingSegmentsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> SegmentingContactsGetMarketingSegmentsRequest<'a> {
+    /// Sets the `parent_list_ids` query parameter.
+    pub fn parent_list_ids(mut self, parent_list_ids: &'a str) -> Self {
+        self.inner = self.inner.parent_list_ids(parent_list_ids);
+        self
+    }
+
+    /// Sets the `no_parent_list_id` query parameter.
+    pub fn no_parent_list_id(mut self, no_parent_list_id: bool) -> Self {
+        self.inner = self.inner.no_parent_list_id(no_parent_list_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::GetMarketingSegmentsResponse>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`SegmentingContacts::get_marketing_segments_segment`], set with a builder.
+pub struct SegmentingContactsGetMarketingSegmentsSegmentRequest<'a> {
+    inner: crate::segmenting_contacts::SegmentingContactsGetMarketingSegmentsSegmentRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> SegmentingContactsGetMarketingSegmentsSegmentRequest<'a> {
+    /// Sets the `query_json` query parameter.
+    pub fn query_json(mut self, query_json: bool) -> Self {
+        self.inner = self.inner.query_json(query_json);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::FullSegmentAllOf>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::segmenting_contacts::SegmentingContacts`].
+pub struct SegmentingContacts {
+    inner: crate::segmenting_contacts::SegmentingContacts,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl SegmentingContacts {
+    pub(crate) fn new(
+        inner: crate::segmenting_contacts::SegmentingContacts,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        SegmentingContacts { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::segmenting_contacts::SegmentingContacts::get_marketing_segments`].
+    pub fn get_marketing_segments(
+        &self,
+        parent_list_ids: &str,
+        no_parent_list_id: bool,
+    ) -> ClientResult<crate::Response<crate::types::GetMarketingSegmentsResponse>> {
+        self.runtime.block_on(
+            self.inner
+                .get_marketing_segments(parent_list_ids, no_parent_list_id),
+        )
+    }
+    /// The blocking version of [`crate::segmenting_contacts::SegmentingContacts::get_marketing_segments_request`].
+    pub fn get_marketing_segments_request(
+        &self,
+    ) -> SegmentingContactsGetMarketingSegmentsRequest<'_> {
+        SegmentingContactsGetMarketingSegmentsRequest {
+            inner: self.inner.get_marketing_segments_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::segmenting_contacts::SegmentingContacts::post_marketing_segment`].
+    pub fn post_marketing_segment(
+        &self,
+        body: &crate::types::PostMarketingSegmentsRequestAllOf,
+    ) -> ClientResult<crate::Response<crate::types::FullSegmentAllOf>> {
+        self.runtime
+            .block_on(self.inner.post_marketing_segment(body))
+    }
+    /// The blocking version of [`crate::segmenting_contacts::SegmentingContacts::get_marketing_segments_segment`].
+    pub fn get_marketing_segments_segment(
+        &self,
+        segment_id: &str,
+        query_json: bool,
+    ) -> ClientResult<crate::Response<crate::types::FullSegmentAllOf>> {
+        self.runtime.block_on(
+            self.inner
+                .get_marketing_segments_segment(segment_id, query_json),
+        )
+    }
+    /// The blocking version of [`crate::segmenting_contacts::SegmentingContacts::get_marketing_segments_segment_request`].
+    pub fn get_marketing_segments_segment_request<'a>(
+        &'a self,
+        segment_id: &'a str,
+    ) -> SegmentingContactsGetMarketingSegmentsSegmentRequest<'a> {
+        SegmentingContactsGetMarketingSegmentsSegmentRequest {
+            inner: self
+                .inner
+                .get_marketing_segments_segment_request(segment_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::segmenting_contacts::SegmentingContacts::delete_marketing_segments_segment`].
+    pub fn delete_marketing_segments_segment(
+        &self,
+        segment_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Help>> {
+        self.runtime
+            .block_on(self.inner.delete_marketing_segments_segment(segment_id))
+    }
+    /// The blocking version of [`crate::segmenting_contacts::SegmentingContacts::patch_marketing_segments_segment`].
+    pub fn patch_marketing_segments_segment(
+        &self,
+        segment_id: &str,
+        body: &crate::types::SegmentWriteV2,
+    ) -> ClientResult<crate::Response<crate::types::FullSegmentAllOf>> {
+        self.runtime.block_on(
+            self.inner
+                .patch_marketing_segments_segment(segment_id, body),
+        )
+    }
+    /// The blocking version of [`crate::segmenting_contacts::SegmentingContacts::post_marketing_segments_delete`].
+    pub fn post_marketing_segments_delete(
+        &self,
+        body: &crate::types::PostMarketingSegmentsDeleteRequest,
+    ) -> ClientResult<crate::Response<crate::types::PostMarketingSegmentsDeleteResponse>> {
+        self.runtime
+            .block_on(self.inner.post_marketing_segments_delete(body))
+    }
+}
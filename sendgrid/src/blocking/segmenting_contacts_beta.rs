@@ -0,0 +1,119 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`SegmentingContactsBeta::get_segments`], set with a builder.
+pub struct SegmentingContactsBetaGetSegmentsRequest<'a> {
+    inner: crate::segmenting_contacts_beta::SegmentingContactsBetaGetSegmentsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> SegmentingContactsBetaGetSegmentsRequest<'a> {
+    /// Sets the `parent_list_ids` query parameter.
+    pub fn parent_list_ids(mut self, parent_list_ids: &'a str) -> Self {
+        self.inner = self.inner.parent_list_ids(parent_list_ids);
+        self
+    }
+
+    /// Sets the `no_parent_list_id` query parameter.
+    pub fn no_parent_list_id(mut self, no_parent_list_id: bool) -> Self {
+        self.inner = self.inner.no_parent_list_id(no_parent_list_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::AllSegmentsResponse>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`SegmentingContactsBeta::get_segments_segment`], set with a builder.
+pub struct SegmentingContactsBetaGetSegmentsSegmentRequest<'a> {
+    inner: crate::segmenting_contacts_beta::SegmentingContactsBetaGetSegmentsSegmentRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> SegmentingContactsBetaGetSegmentsSegmentRequest<'a> {
+    /// Sets the `contacts_sample` query parameter.
+    pub fn contacts_sample(mut self, contacts_sample: bool) -> Self {
+        self.inner = self.inner.contacts_sample(contacts_sample);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::SegmentResponse>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::segmenting_contacts_beta::SegmentingContactsBeta`].
+pub struct SegmentingContactsBeta {
+    inner: crate::segmenting_contacts_beta::SegmentingContactsBeta,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl SegmentingContactsBeta {
+    pub(crate) fn new(
+        inner: crate::segmenting_contacts_beta::SegmentingContactsBeta,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        SegmentingContactsBeta { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::segmenting_contacts_beta::SegmentingContactsBeta::get_segments`].
+    pub fn get_segments(
+        &self,
+        parent_list_ids: &str,
+        no_parent_list_id: bool,
+    ) -> ClientResult<crate::Response<crate::types::AllSegmentsResponse>> {
+        self.runtime
+            .block_on(self.inner.get_segments(parent_list_ids, no_parent_list_id))
+    }
+    /// The blocking version of [`crate::segmenting_contacts_beta::SegmentingContactsBeta::get_segments_request`].
+    pub fn get_segments_request(&self) -> SegmentingContactsBetaGetSegmentsRequest<'_> {
+        SegmentingContactsBetaGetSegmentsRequest {
+            inner: self.inner.get_segments_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::segmenting_contacts_beta::SegmentingContactsBeta::post_segment`].
+    pub fn post_segment(
+        &self,
+        body: &crate::types::SegmentWriteV2,
+    ) -> ClientResult<crate::Response<crate::types::SegmentResponse>> {
+        self.runtime.block_on(self.inner.post_segment(body))
+    }
+    /// The blocking version of [`crate::segmenting_contacts_beta::SegmentingContactsBeta::get_segments_segment`].
+    pub fn get_segments_segment(
+        &self,
+        segment_id: &str,
+        contacts_sample: bool,
+    ) -> ClientResult<crate::Response<crate::types::SegmentResponse>> {
+        self.runtime
+            .block_on(self.inner.get_segments_segment(segment_id, contacts_sample))
+    }
+    /// The blocking version of [`crate::segmenting_contacts_beta::SegmentingContactsBeta::get_segments_segment_request`].
+    pub fn get_segments_segment_request<'a>(
+        &'a self,
+        segment_id: &'a str,
+    ) -> SegmentingContactsBetaGetSegmentsSegmentRequest<'a> {
+        SegmentingContactsBetaGetSegmentsSegmentRequest {
+            inner: self.inner.get_segments_segment_request(segment_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::segmenting_contacts_beta::SegmentingContactsBeta::delete_segments_segment`].
+    pub fn delete_segments_segment(&self, segment_id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete_segments_segment(segment_id))
+    }
+    /// The blocking version of [`crate::segmenting_contacts_beta::SegmentingContactsBeta::patch_segments_segment`].
+    pub fn patch_segments_segment(
+        &self,
+        segment_id: &str,
+        body: &crate::types::SegmentUpdate,
+    ) -> ClientResult<crate::Response<crate::types::SegmentResponse>> {
+        self.runtime
+            .block_on(self.inner.patch_segments_segment(segment_id, body))
+    }
+}
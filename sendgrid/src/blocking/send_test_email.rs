@@ -0,0 +1,27 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::send_test_email::SendTestEmail`].
+pub struct SendTestEmail {
+    inner: crate::send_test_email::SendTestEmail,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl SendTestEmail {
+    pub(crate) fn new(
+        inner: crate::send_test_email::SendTestEmail,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        SendTestEmail { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::send_test_email::SendTestEmail::post_marketing_test_send_email`].
+    pub fn post_marketing_test_send_email(
+        &self,
+        body: &crate::types::PostMarketingTestSendEmailRequest,
+    ) -> ClientResult<crate::Response<crate::types::Help>> {
+        self.runtime
+            .block_on(self.inner.post_marketing_test_send_email(body))
+    }
+}
@@ -0,0 +1,65 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::sender_identities_api::SenderIdentitiesApi`].
+pub struct SenderIdentitiesApi {
+    inner: crate::sender_identities_api::SenderIdentitiesApi,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl SenderIdentitiesApi {
+    pub(crate) fn new(
+        inner: crate::sender_identities_api::SenderIdentitiesApi,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        SenderIdentitiesApi { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::sender_identities_api::SenderIdentitiesApi::get_senders`].
+    pub fn get_senders(&self) -> ClientResult<crate::Response<crate::types::GetSendersResponse>> {
+        self.runtime.block_on(self.inner.get_senders())
+    }
+    /// The blocking version of [`crate::sender_identities_api::SenderIdentitiesApi::post_sender`].
+    pub fn post_sender(
+        &self,
+        body: &crate::types::PostSendersRequestAllOf,
+    ) -> ClientResult<crate::Response<crate::types::SenderAllOf>> {
+        self.runtime.block_on(self.inner.post_sender(body))
+    }
+    /// The blocking version of [`crate::sender_identities_api::SenderIdentitiesApi::get_senders_sender`].
+    pub fn get_senders_sender(
+        &self,
+        sender_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::SenderAllOf>> {
+        self.runtime
+            .block_on(self.inner.get_senders_sender(sender_id))
+    }
+    /// The blocking version of [`crate::sender_identities_api::SenderIdentitiesApi::delete_senders_sender`].
+    pub fn delete_senders_sender(
+        &self,
+        sender_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::Help>> {
+        self.runtime
+            .block_on(self.inner.delete_senders_sender(sender_id))
+    }
+    /// The blocking version of [`crate::sender_identities_api::SenderIdentitiesApi::patch_senders_sender`].
+    pub fn patch_senders_sender(
+        &self,
+        sender_id: i64,
+        body: &crate::types::SenderRequest,
+    ) -> ClientResult<crate::Response<crate::types::SenderAllOf>> {
+        self.runtime
+            .block_on(self.inner.patch_senders_sender(sender_id, body))
+    }
+    /// The blocking version of [`crate::sender_identities_api::SenderIdentitiesApi::post_senders_sender_resend_verification`].
+    pub fn post_senders_sender_resend_verification(
+        &self,
+        sender_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::Help>> {
+        self.runtime.block_on(
+            self.inner
+                .post_senders_sender_resend_verification(sender_id),
+        )
+    }
+}
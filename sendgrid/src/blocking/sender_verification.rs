@@ -0,0 +1,121 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`SenderVerification::get_verified_senders`], set with a builder.
+pub struct SenderVerificationGetVerifiedSendersRequest<'a> {
+    inner: crate::sender_verification::SenderVerificationGetVerifiedSendersRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> SenderVerificationGetVerifiedSendersRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: f64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `lastSeenID` query parameter.
+    pub fn last_seen_id(mut self, last_seen_id: f64) -> Self {
+        self.inner = self.inner.last_seen_id(last_seen_id);
+        self
+    }
+
+    /// Sets the `id` query parameter.
+    pub fn id(mut self, id: i64) -> Self {
+        self.inner = self.inner.id(id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::GetVerifiedSendersResponse>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::sender_verification::SenderVerification`].
+pub struct SenderVerification {
+    inner: crate::sender_verification::SenderVerification,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl SenderVerification {
+    pub(crate) fn new(
+        inner: crate::sender_verification::SenderVerification,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        SenderVerification { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::sender_verification::SenderVerification::get_verified_senders_domains`].
+    pub fn get_verified_senders_domains(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::GetVerifiedSendersDomainsResponse>> {
+        self.runtime
+            .block_on(self.inner.get_verified_senders_domains())
+    }
+    /// The blocking version of [`crate::sender_verification::SenderVerification::get_verified_senders_steps_completed`].
+    pub fn get_verified_senders_steps_completed(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::GetVerifiedSendersStepsCompletedResponse>> {
+        self.runtime
+            .block_on(self.inner.get_verified_senders_steps_completed())
+    }
+    /// The blocking version of [`crate::sender_verification::SenderVerification::get_verified_senders`].
+    pub fn get_verified_senders(
+        &self,
+        limit: f64,
+        last_seen_id: f64,
+        id: i64,
+    ) -> ClientResult<crate::Response<crate::types::GetVerifiedSendersResponse>> {
+        self.runtime
+            .block_on(self.inner.get_verified_senders(limit, last_seen_id, id))
+    }
+    /// The blocking version of [`crate::sender_verification::SenderVerification::get_verified_senders_request`].
+    pub fn get_verified_senders_request(&self) -> SenderVerificationGetVerifiedSendersRequest<'_> {
+        SenderVerificationGetVerifiedSendersRequest {
+            inner: self.inner.get_verified_senders_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::sender_verification::SenderVerification::post_verified_sender`].
+    pub fn post_verified_sender(
+        &self,
+        body: &crate::types::VerifiedSenderRequestSchema,
+    ) -> ClientResult<crate::Response<crate::types::VerifiedSenderResponseSchema>> {
+        self.runtime.block_on(self.inner.post_verified_sender(body))
+    }
+    /// The blocking version of [`crate::sender_verification::SenderVerification::get_verified_senders_verify_token`].
+    pub fn get_verified_senders_verify_token(
+        &self,
+        token: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.get_verified_senders_verify_token(token))
+    }
+    /// The blocking version of [`crate::sender_verification::SenderVerification::delete_verified_senders`].
+    pub fn delete_verified_senders(
+        &self,
+        id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Help>> {
+        self.runtime
+            .block_on(self.inner.delete_verified_senders(id))
+    }
+    /// The blocking version of [`crate::sender_verification::SenderVerification::patch_verified_senders`].
+    pub fn patch_verified_senders(
+        &self,
+        id: &str,
+        body: &crate::types::VerifiedSenderRequestSchema,
+    ) -> ClientResult<crate::Response<crate::types::VerifiedSenderResponseSchema>> {
+        self.runtime
+            .block_on(self.inner.patch_verified_senders(id, body))
+    }
+    /// The blocking version of [`crate::sender_verification::SenderVerification::post_verified_senders_resend`].
+    pub fn post_verified_senders_resend(
+        &self,
+        id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Help>> {
+        self.runtime
+            .block_on(self.inner.post_verified_senders_resend(id))
+    }
+}
@@ -0,0 +1,26 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::senders::Senders`].
+pub struct Senders {
+    inner: crate::senders::Senders,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Senders {
+    pub(crate) fn new(
+        inner: crate::senders::Senders,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Senders { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::senders::Senders::post_marketing`].
+    pub fn post_marketing(
+        &self,
+        body: &crate::types::PostMarketingSendersRequest,
+    ) -> ClientResult<crate::Response<crate::types::SenderAllOf>> {
+        self.runtime.block_on(self.inner.post_marketing(body))
+    }
+}
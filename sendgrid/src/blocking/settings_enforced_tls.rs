@@ -0,0 +1,32 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::settings_enforced_tls::SettingsEnforcedTls`].
+pub struct SettingsEnforcedTls {
+    inner: crate::settings_enforced_tls::SettingsEnforcedTls,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl SettingsEnforcedTls {
+    pub(crate) fn new(
+        inner: crate::settings_enforced_tls::SettingsEnforcedTls,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        SettingsEnforcedTls { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::settings_enforced_tls::SettingsEnforcedTls::get_user`].
+    pub fn get_user(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::EnforcedTlsRequestResponse>> {
+        self.runtime.block_on(self.inner.get_user())
+    }
+    /// The blocking version of [`crate::settings_enforced_tls::SettingsEnforcedTls::patch_user`].
+    pub fn patch_user(
+        &self,
+        body: &crate::types::EnforcedTlsRequestResponse,
+    ) -> ClientResult<crate::Response<crate::types::EnforcedTlsRequestResponse>> {
+        self.runtime.block_on(self.inner.patch_user(body))
+    }
+}
@@ -0,0 +1,58 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::settings_inbound_parse::SettingsInboundParse`].
+pub struct SettingsInboundParse {
+    inner: crate::settings_inbound_parse::SettingsInboundParse,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl SettingsInboundParse {
+    pub(crate) fn new(
+        inner: crate::settings_inbound_parse::SettingsInboundParse,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        SettingsInboundParse { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::settings_inbound_parse::SettingsInboundParse::post_user_webhooks_parse_setting`].
+    pub fn post_user_webhooks_parse_setting(
+        &self,
+        body: &crate::types::ParseSetting,
+    ) -> ClientResult<crate::Response<crate::types::ParseSetting>> {
+        self.runtime
+            .block_on(self.inner.post_user_webhooks_parse_setting(body))
+    }
+    /// The blocking version of [`crate::settings_inbound_parse::SettingsInboundParse::get_user_webhooks_parse_settings_hostname`].
+    pub fn get_user_webhooks_parse_settings_hostname(
+        &self,
+        hostname: &str,
+    ) -> ClientResult<crate::Response<crate::types::ParseSetting>> {
+        self.runtime.block_on(
+            self.inner
+                .get_user_webhooks_parse_settings_hostname(hostname),
+        )
+    }
+    /// The blocking version of [`crate::settings_inbound_parse::SettingsInboundParse::delete_user_webhooks_parse_settings_hostname`].
+    pub fn delete_user_webhooks_parse_settings_hostname(
+        &self,
+        hostname: &str,
+    ) -> ClientResult<crate::Response<crate::types::Help>> {
+        self.runtime.block_on(
+            self.inner
+                .delete_user_webhooks_parse_settings_hostname(hostname),
+        )
+    }
+    /// The blocking version of [`crate::settings_inbound_parse::SettingsInboundParse::patch_user_webhooks_parse_settings_hostname`].
+    pub fn patch_user_webhooks_parse_settings_hostname(
+        &self,
+        hostname: &str,
+        body: &crate::types::ParseSetting,
+    ) -> ClientResult<crate::Response<crate::types::ParseSetting>> {
+        self.runtime.block_on(
+            self.inner
+                .patch_user_webhooks_parse_settings_hostname(hostname, body),
+        )
+    }
+}
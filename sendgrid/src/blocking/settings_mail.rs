@@ -0,0 +1,149 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`SettingsMail::get_mail_settings`], set with a builder.
+pub struct SettingsMailGetMailSettingsRequest<'a> {
+    inner: crate::settings_mail::SettingsMailGetMailSettingsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> SettingsMailGetMailSettingsRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.inner = self.inner.offset(offset);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::GetMailSettingsResponse>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::settings_mail::SettingsMail`].
+pub struct SettingsMail {
+    inner: crate::settings_mail::SettingsMail,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl SettingsMail {
+    pub(crate) fn new(
+        inner: crate::settings_mail::SettingsMail,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        SettingsMail { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::settings_mail::SettingsMail::get_mail_settings`].
+    pub fn get_mail_settings(
+        &self,
+        limit: i64,
+        offset: i64,
+    ) -> ClientResult<crate::Response<crate::types::GetMailSettingsResponse>> {
+        self.runtime
+            .block_on(self.inner.get_mail_settings(limit, offset))
+    }
+    /// The blocking version of [`crate::settings_mail::SettingsMail::get_mail_settings_request`].
+    pub fn get_mail_settings_request(&self) -> SettingsMailGetMailSettingsRequest<'_> {
+        SettingsMailGetMailSettingsRequest {
+            inner: self.inner.get_mail_settings_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::settings_mail::SettingsMail::get_mail_settings_address_whitelist`].
+    pub fn get_mail_settings_address_whitelist(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::MailSettingsAddressWhitelabel>> {
+        self.runtime
+            .block_on(self.inner.get_mail_settings_address_whitelist())
+    }
+    /// The blocking version of [`crate::settings_mail::SettingsMail::patch_mail_settings_address_whitelist`].
+    pub fn patch_mail_settings_address_whitelist(
+        &self,
+        body: &crate::types::PatchMailSettingsAddressWhitelistRequest,
+    ) -> ClientResult<crate::Response<crate::types::MailSettingsAddressWhitelabel>> {
+        self.runtime
+            .block_on(self.inner.patch_mail_settings_address_whitelist(body))
+    }
+    /// The blocking version of [`crate::settings_mail::SettingsMail::get_mail_settings_footer`].
+    pub fn get_mail_settings_footer(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::MailSettingsFooter>> {
+        self.runtime.block_on(self.inner.get_mail_settings_footer())
+    }
+    /// The blocking version of [`crate::settings_mail::SettingsMail::patch_mail_settings_footer`].
+    pub fn patch_mail_settings_footer(
+        &self,
+        body: &crate::types::MailSettingsFooter,
+    ) -> ClientResult<crate::Response<crate::types::MailSettingsFooter>> {
+        self.runtime
+            .block_on(self.inner.patch_mail_settings_footer(body))
+    }
+    /// The blocking version of [`crate::settings_mail::SettingsMail::get_mail_settings_forward_spam`].
+    pub fn get_mail_settings_forward_spam(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::MailSettingsForwardSpam>> {
+        self.runtime
+            .block_on(self.inner.get_mail_settings_forward_spam())
+    }
+    /// The blocking version of [`crate::settings_mail::SettingsMail::patch_mail_settings_forward_spam`].
+    pub fn patch_mail_settings_forward_spam(
+        &self,
+        body: &crate::types::MailSettingsForwardSpam,
+    ) -> ClientResult<crate::Response<crate::types::MailSettingsForwardSpam>> {
+        self.runtime
+            .block_on(self.inner.patch_mail_settings_forward_spam(body))
+    }
+    /// The blocking version of [`crate::settings_mail::SettingsMail::get_mail_settings_template`].
+    pub fn get_mail_settings_template(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::MailSettingsTemplate>> {
+        self.runtime
+            .block_on(self.inner.get_mail_settings_template())
+    }
+    /// The blocking version of [`crate::settings_mail::SettingsMail::patch_mail_settings_template`].
+    pub fn patch_mail_settings_template(
+        &self,
+        body: &crate::types::PatchMailSettingsTemplateRequest,
+    ) -> ClientResult<crate::Response<crate::types::PatchMailSettingsTemplateResponse>> {
+        self.runtime
+            .block_on(self.inner.patch_mail_settings_template(body))
+    }
+    /// The blocking version of [`crate::settings_mail::SettingsMail::get_mail_settings_bounce_purge`].
+    pub fn get_mail_settings_bounce_purge(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::MailSettingsBouncePurge>> {
+        self.runtime
+            .block_on(self.inner.get_mail_settings_bounce_purge())
+    }
+    /// The blocking version of [`crate::settings_mail::SettingsMail::patch_mail_settings_bounce_purge`].
+    pub fn patch_mail_settings_bounce_purge(
+        &self,
+        body: &crate::types::MailSettingsBouncePurge,
+    ) -> ClientResult<crate::Response<crate::types::MailSettingsBouncePurge>> {
+        self.runtime
+            .block_on(self.inner.patch_mail_settings_bounce_purge(body))
+    }
+    /// The blocking version of [`crate::settings_mail::SettingsMail::get_mail_settings_forward_bounce`].
+    pub fn get_mail_settings_forward_bounce(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::MailSettingsForwardBounce>> {
+        self.runtime
+            .block_on(self.inner.get_mail_settings_forward_bounce())
+    }
+    /// The blocking version of [`crate::settings_mail::SettingsMail::patch_mail_settings_forward_bounce`].
+    pub fn patch_mail_settings_forward_bounce(
+        &self,
+        body: &crate::types::MailSettingsForwardBounce,
+    ) -> ClientResult<crate::Response<crate::types::MailSettingsForwardBounce>> {
+        self.runtime
+            .block_on(self.inner.patch_mail_settings_forward_bounce(body))
+    }
+}
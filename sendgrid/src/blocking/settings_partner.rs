@@ -0,0 +1,75 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`SettingsPartner::get_partner_settings`], set with a builder.
+pub struct SettingsPartnerGetPartnerSettingsRequest<'a> {
+    inner: crate::settings_partner::SettingsPartnerGetPartnerSettingsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> SettingsPartnerGetPartnerSettingsRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.inner = self.inner.offset(offset);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::GetPartnerSettingsResponse>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::settings_partner::SettingsPartner`].
+pub struct SettingsPartner {
+    inner: crate::settings_partner::SettingsPartner,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl SettingsPartner {
+    pub(crate) fn new(
+        inner: crate::settings_partner::SettingsPartner,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        SettingsPartner { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::settings_partner::SettingsPartner::get_partner_settings_new_relic`].
+    pub fn get_partner_settings_new_relic(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::PartnerSettingsNewRelic>> {
+        self.runtime
+            .block_on(self.inner.get_partner_settings_new_relic())
+    }
+    /// The blocking version of [`crate::settings_partner::SettingsPartner::patch_partner_settings_new_relic`].
+    pub fn patch_partner_settings_new_relic(
+        &self,
+        body: &crate::types::PatchPartnerSettingsNewRelicRequest,
+    ) -> ClientResult<crate::Response<crate::types::PartnerSettingsNewRelic>> {
+        self.runtime
+            .block_on(self.inner.patch_partner_settings_new_relic(body))
+    }
+    /// The blocking version of [`crate::settings_partner::SettingsPartner::get_partner_settings`].
+    pub fn get_partner_settings(
+        &self,
+        limit: i64,
+        offset: i64,
+    ) -> ClientResult<crate::Response<crate::types::GetPartnerSettingsResponse>> {
+        self.runtime
+            .block_on(self.inner.get_partner_settings(limit, offset))
+    }
+    /// The blocking version of [`crate::settings_partner::SettingsPartner::get_partner_settings_request`].
+    pub fn get_partner_settings_request(&self) -> SettingsPartnerGetPartnerSettingsRequest<'_> {
+        SettingsPartnerGetPartnerSettingsRequest {
+            inner: self.inner.get_partner_settings_request(),
+            runtime: &self.runtime,
+        }
+    }
+}
@@ -0,0 +1,85 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::settings_tracking::SettingsTracking`].
+pub struct SettingsTracking {
+    inner: crate::settings_tracking::SettingsTracking,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl SettingsTracking {
+    pub(crate) fn new(
+        inner: crate::settings_tracking::SettingsTracking,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        SettingsTracking { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::settings_tracking::SettingsTracking::get_tracking_settings`].
+    pub fn get_tracking_settings(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::GetTrackingSettingsResponse>> {
+        self.runtime.block_on(self.inner.get_tracking_settings())
+    }
+    /// The blocking version of [`crate::settings_tracking::SettingsTracking::get_tracking_settings_click`].
+    pub fn get_tracking_settings_click(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::ClickTracking>> {
+        self.runtime
+            .block_on(self.inner.get_tracking_settings_click())
+    }
+    /// The blocking version of [`crate::settings_tracking::SettingsTracking::patch_tracking_settings_click`].
+    pub fn patch_tracking_settings_click(
+        &self,
+        body: &crate::types::PatchTrackingSettingsOpenRequest,
+    ) -> ClientResult<crate::Response<crate::types::ClickTracking>> {
+        self.runtime
+            .block_on(self.inner.patch_tracking_settings_click(body))
+    }
+    /// The blocking version of [`crate::settings_tracking::SettingsTracking::get_tracking_settings_google_analytic`].
+    pub fn get_tracking_settings_google_analytic(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::GoogleAnalyticsSettings>> {
+        self.runtime
+            .block_on(self.inner.get_tracking_settings_google_analytic())
+    }
+    /// The blocking version of [`crate::settings_tracking::SettingsTracking::patch_tracking_settings_google_analytics`].
+    pub fn patch_tracking_settings_google_analytics(
+        &self,
+        body: &crate::types::GoogleAnalyticsSettings,
+    ) -> ClientResult<crate::Response<crate::types::GoogleAnalyticsSettings>> {
+        self.runtime
+            .block_on(self.inner.patch_tracking_settings_google_analytics(body))
+    }
+    /// The blocking version of [`crate::settings_tracking::SettingsTracking::get_tracking_settings_open`].
+    pub fn get_tracking_settings_open(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::GetTrackingSettingsOpenResponse>> {
+        self.runtime
+            .block_on(self.inner.get_tracking_settings_open())
+    }
+    /// The blocking version of [`crate::settings_tracking::SettingsTracking::patch_tracking_settings_open`].
+    pub fn patch_tracking_settings_open(
+        &self,
+        body: &crate::types::PatchTrackingSettingsOpenRequest,
+    ) -> ClientResult<crate::Response<crate::types::GetTrackingSettingsOpenResponse>> {
+        self.runtime
+            .block_on(self.inner.patch_tracking_settings_open(body))
+    }
+    /// The blocking version of [`crate::settings_tracking::SettingsTracking::get_tracking_settings_subscription`].
+    pub fn get_tracking_settings_subscription(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::SubscriptionTrackingSettings>> {
+        self.runtime
+            .block_on(self.inner.get_tracking_settings_subscription())
+    }
+    /// The blocking version of [`crate::settings_tracking::SettingsTracking::patch_tracking_settings_subscription`].
+    pub fn patch_tracking_settings_subscription(
+        &self,
+        body: &crate::types::SubscriptionTrackingSettings,
+    ) -> ClientResult<crate::Response<crate::types::SubscriptionTrackingSettings>> {
+        self.runtime
+            .block_on(self.inner.patch_tracking_settings_subscription(body))
+    }
+}
@@ -0,0 +1,216 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`SingleSends::get_marketing_singlesends`], set with a builder.
+pub struct SingleSendsGetMarketingSinglesendsRequest<'a> {
+    inner: crate::single_sends::SingleSendsGetMarketingSinglesendsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> SingleSendsGetMarketingSinglesendsRequest<'a> {
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Sets the `page_token` query parameter.
+    pub fn page_token(mut self, page_token: &'a str) -> Self {
+        self.inner = self.inner.page_token(page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::GetMarketingSinglesendsResponse>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`SingleSends::delete_marketing_singlesends`], set with a builder.
+pub struct SingleSendsDeleteMarketingSinglesendsRequest<'a> {
+    inner: crate::single_sends::SingleSendsDeleteMarketingSinglesendsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> SingleSendsDeleteMarketingSinglesendsRequest<'a> {
+    /// Sets the `ids` query parameter.
+    pub fn ids(mut self, ids: &'a [String]) -> Self {
+        self.inner = self.inner.ids(ids);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`SingleSends::post_marketing_singlesends_search`], set with a builder.
+pub struct SingleSendsPostMarketingSinglesendsSearchRequest<'a> {
+    inner: crate::single_sends::SingleSendsPostMarketingSinglesendsSearchRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> SingleSendsPostMarketingSinglesendsSearchRequest<'a> {
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Sets the `page_token` query parameter.
+    pub fn page_token(mut self, page_token: &'a str) -> Self {
+        self.inner = self.inner.page_token(page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::GetMarketingSinglesendsResponse>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::single_sends::SingleSends`].
+pub struct SingleSends {
+    inner: crate::single_sends::SingleSends,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl SingleSends {
+    pub(crate) fn new(
+        inner: crate::single_sends::SingleSends,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        SingleSends { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::single_sends::SingleSends::get_marketing_singlesends`].
+    pub fn get_marketing_singlesends(
+        &self,
+        page_size: i64,
+        page_token: &str,
+    ) -> ClientResult<crate::Response<crate::types::GetMarketingSinglesendsResponse>> {
+        self.runtime
+            .block_on(self.inner.get_marketing_singlesends(page_size, page_token))
+    }
+    /// The blocking version of [`crate::single_sends::SingleSends::get_marketing_singlesends_request`].
+    pub fn get_marketing_singlesends_request(
+        &self,
+    ) -> SingleSendsGetMarketingSinglesendsRequest<'_> {
+        SingleSendsGetMarketingSinglesendsRequest {
+            inner: self.inner.get_marketing_singlesends_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::single_sends::SingleSends::post_marketing_singlesend`].
+    pub fn post_marketing_singlesend(
+        &self,
+        body: &crate::types::SinglesendRequest,
+    ) -> ClientResult<crate::Response<crate::types::SinglesendResponseAllOf>> {
+        self.runtime
+            .block_on(self.inner.post_marketing_singlesend(body))
+    }
+    /// The blocking version of [`crate::single_sends::SingleSends::delete_marketing_singlesends`].
+    pub fn delete_marketing_singlesends(
+        &self,
+        ids: &[String],
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete_marketing_singlesends(ids))
+    }
+    /// The blocking version of [`crate::single_sends::SingleSends::delete_marketing_singlesends_request`].
+    pub fn delete_marketing_singlesends_request(
+        &self,
+    ) -> SingleSendsDeleteMarketingSinglesendsRequest<'_> {
+        SingleSendsDeleteMarketingSinglesendsRequest {
+            inner: self.inner.delete_marketing_singlesends_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::single_sends::SingleSends::get_marketing_singlesend`].
+    pub fn get_marketing_singlesend(
+        &self,
+        id: &str,
+    ) -> ClientResult<crate::Response<crate::types::SinglesendResponseAllOf>> {
+        self.runtime
+            .block_on(self.inner.get_marketing_singlesend(id))
+    }
+    /// The blocking version of [`crate::single_sends::SingleSends::post_marketing_singlesend_single_sends`].
+    pub fn post_marketing_singlesend_single_sends(
+        &self,
+        id: &str,
+        body: &crate::types::PostMarketingSinglesendsRequest,
+    ) -> ClientResult<crate::Response<crate::types::SinglesendResponseAllOf>> {
+        self.runtime
+            .block_on(self.inner.post_marketing_singlesend_single_sends(id, body))
+    }
+    /// The blocking version of [`crate::single_sends::SingleSends::delete_marketing_singlesends_single_sends`].
+    pub fn delete_marketing_singlesends_single_sends(
+        &self,
+        id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete_marketing_singlesends_single_sends(id))
+    }
+    /// The blocking version of [`crate::single_sends::SingleSends::patch_marketing_singlesends`].
+    pub fn patch_marketing_singlesends(
+        &self,
+        id: &str,
+        body: &crate::types::SinglesendRequest,
+    ) -> ClientResult<crate::Response<crate::types::SinglesendResponseAllOf>> {
+        self.runtime
+            .block_on(self.inner.patch_marketing_singlesends(id, body))
+    }
+    /// The blocking version of [`crate::single_sends::SingleSends::post_marketing_singlesends_search`].
+    pub fn post_marketing_singlesends_search(
+        &self,
+        page_size: i64,
+        page_token: &str,
+        body: &crate::types::SinglesendSearch,
+    ) -> ClientResult<crate::Response<crate::types::GetMarketingSinglesendsResponse>> {
+        self.runtime.block_on(
+            self.inner
+                .post_marketing_singlesends_search(page_size, page_token, body),
+        )
+    }
+    /// The blocking version of [`crate::single_sends::SingleSends::post_marketing_singlesends_search_request`].
+    pub fn post_marketing_singlesends_search_request<'a>(
+        &'a self,
+        body: &'a crate::types::SinglesendSearch,
+    ) -> SingleSendsPostMarketingSinglesendsSearchRequest<'a> {
+        SingleSendsPostMarketingSinglesendsSearchRequest {
+            inner: self.inner.post_marketing_singlesends_search_request(body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::single_sends::SingleSends::put_marketing_singlesends_schedule`].
+    pub fn put_marketing_singlesends_schedule(
+        &self,
+        id: &str,
+        body: &crate::types::PutMarketingSinglesendsScheduleRequest,
+    ) -> ClientResult<crate::Response<crate::types::PutMarketingSinglesendsScheduleResponse>> {
+        self.runtime
+            .block_on(self.inner.put_marketing_singlesends_schedule(id, body))
+    }
+    /// The blocking version of [`crate::single_sends::SingleSends::delete_marketing_singlesends_schedule`].
+    pub fn delete_marketing_singlesends_schedule(
+        &self,
+        id: &str,
+    ) -> ClientResult<crate::Response<crate::types::SinglesendSchedule>> {
+        self.runtime
+            .block_on(self.inner.delete_marketing_singlesends_schedule(id))
+    }
+    /// The blocking version of [`crate::single_sends::SingleSends::get_marketing_singlesends_categories`].
+    pub fn get_marketing_singlesends_categories(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::GetMarketingSinglesendsCategoriesResponse>>
+    {
+        self.runtime
+            .block_on(self.inner.get_marketing_singlesends_categories())
+    }
+}
@@ -0,0 +1,152 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`SingleSignOnSettings::get_sso_integrations`], set with a builder.
+pub struct SingleSignOnSettingsGetSsoIntegrationsRequest<'a> {
+    inner: crate::single_sign_on_settings::SingleSignOnSettingsGetSsoIntegrationsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> SingleSignOnSettingsGetSsoIntegrationsRequest<'a> {
+    /// Sets the `si` query parameter.
+    pub fn si(mut self, si: bool) -> Self {
+        self.inner = self.inner.si(si);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::SsoIntegrationAllOf>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`SingleSignOnSettings::get_sso_integration`], set with a builder.
+pub struct SingleSignOnSettingsGetSsoIntegrationRequest<'a> {
+    inner: crate::single_sign_on_settings::SingleSignOnSettingsGetSsoIntegrationRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> SingleSignOnSettingsGetSsoIntegrationRequest<'a> {
+    /// Sets the `si` query parameter.
+    pub fn si(mut self, si: bool) -> Self {
+        self.inner = self.inner.si(si);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::SsoIntegrationAllOf>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`SingleSignOnSettings::patch_sso_integrations`], set with a builder.
+pub struct SingleSignOnSettingsPatchSsoIntegrationsRequest<'a> {
+    inner: crate::single_sign_on_settings::SingleSignOnSettingsPatchSsoIntegrationsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> SingleSignOnSettingsPatchSsoIntegrationsRequest<'a> {
+    /// Sets the `si` query parameter.
+    pub fn si(mut self, si: bool) -> Self {
+        self.inner = self.inner.si(si);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::SsoIntegrationAllOf>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::single_sign_on_settings::SingleSignOnSettings`].
+pub struct SingleSignOnSettings {
+    inner: crate::single_sign_on_settings::SingleSignOnSettings,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl SingleSignOnSettings {
+    pub(crate) fn new(
+        inner: crate::single_sign_on_settings::SingleSignOnSettings,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        SingleSignOnSettings { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::single_sign_on_settings::SingleSignOnSettings::get_sso_integrations`].
+    pub fn get_sso_integrations(
+        &self,
+        si: bool,
+    ) -> ClientResult<crate::Response<Vec<crate::types::SsoIntegrationAllOf>>> {
+        self.runtime.block_on(self.inner.get_sso_integrations(si))
+    }
+    /// The blocking version of [`crate::single_sign_on_settings::SingleSignOnSettings::get_sso_integrations_request`].
+    pub fn get_sso_integrations_request(
+        &self,
+    ) -> SingleSignOnSettingsGetSsoIntegrationsRequest<'_> {
+        SingleSignOnSettingsGetSsoIntegrationsRequest {
+            inner: self.inner.get_sso_integrations_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::single_sign_on_settings::SingleSignOnSettings::get_all_sso_integrations`].
+    pub fn get_all_sso_integrations(
+        &self,
+        si: bool,
+    ) -> ClientResult<crate::Response<Vec<crate::types::SsoIntegrationAllOf>>> {
+        self.runtime
+            .block_on(self.inner.get_all_sso_integrations(si))
+    }
+    /// The blocking version of [`crate::single_sign_on_settings::SingleSignOnSettings::post_sso_integration`].
+    pub fn post_sso_integration(
+        &self,
+        body: &crate::types::CreateIntegrationRequest,
+    ) -> ClientResult<crate::Response<crate::types::SsoIntegrationAllOf>> {
+        self.runtime.block_on(self.inner.post_sso_integration(body))
+    }
+    /// The blocking version of [`crate::single_sign_on_settings::SingleSignOnSettings::get_sso_integration`].
+    pub fn get_sso_integration(
+        &self,
+        id: &str,
+        si: bool,
+    ) -> ClientResult<crate::Response<crate::types::SsoIntegrationAllOf>> {
+        self.runtime
+            .block_on(self.inner.get_sso_integration(id, si))
+    }
+    /// The blocking version of [`crate::single_sign_on_settings::SingleSignOnSettings::get_sso_integration_request`].
+    pub fn get_sso_integration_request<'a>(
+        &'a self,
+        id: &'a str,
+    ) -> SingleSignOnSettingsGetSsoIntegrationRequest<'a> {
+        SingleSignOnSettingsGetSsoIntegrationRequest {
+            inner: self.inner.get_sso_integration_request(id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::single_sign_on_settings::SingleSignOnSettings::delete_sso_integrations`].
+    pub fn delete_sso_integrations(&self, id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete_sso_integrations(id))
+    }
+    /// The blocking version of [`crate::single_sign_on_settings::SingleSignOnSettings::patch_sso_integrations`].
+    pub fn patch_sso_integrations(
+        &self,
+        id: &str,
+        si: bool,
+        body: &crate::types::CreateIntegrationRequest,
+    ) -> ClientResult<crate::Response<crate::types::SsoIntegrationAllOf>> {
+        self.runtime
+            .block_on(self.inner.patch_sso_integrations(id, si, body))
+    }
+    /// The blocking version of [`crate::single_sign_on_settings::SingleSignOnSettings::patch_sso_integrations_request`].
+    pub fn patch_sso_integrations_request<'a>(
+        &'a self,
+        id: &'a str,
+        body: &'a crate::types::CreateIntegrationRequest,
+    ) -> SingleSignOnSettingsPatchSsoIntegrationsRequest<'a> {
+        SingleSignOnSettingsPatchSsoIntegrationsRequest {
+            inner: self.inner.patch_sso_integrations_request(id, body),
+            runtime: &self.runtime,
+        }
+    }
+}
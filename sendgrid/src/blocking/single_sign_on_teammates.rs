@@ -0,0 +1,35 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::single_sign_on_teammates::SingleSignOnTeammates`].
+pub struct SingleSignOnTeammates {
+    inner: crate::single_sign_on_teammates::SingleSignOnTeammates,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl SingleSignOnTeammates {
+    pub(crate) fn new(
+        inner: crate::single_sign_on_teammates::SingleSignOnTeammates,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        SingleSignOnTeammates { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::single_sign_on_teammates::SingleSignOnTeammates::post_sso_teammate`].
+    pub fn post_sso_teammate(
+        &self,
+        body: &crate::types::SsoTeammateRequestAllOf,
+    ) -> ClientResult<crate::Response<crate::types::SsoTeammateResponseAllOf>> {
+        self.runtime.block_on(self.inner.post_sso_teammate(body))
+    }
+    /// The blocking version of [`crate::single_sign_on_teammates::SingleSignOnTeammates::patch_sso_teammates_username`].
+    pub fn patch_sso_teammates_username(
+        &self,
+        username: &str,
+        body: &crate::types::PatchSsoTeammatesUsernameRequest,
+    ) -> ClientResult<crate::Response<crate::types::SsoTeammatesPatchResponseAllOf>> {
+        self.runtime
+            .block_on(self.inner.patch_sso_teammates_username(username, body))
+    }
+}
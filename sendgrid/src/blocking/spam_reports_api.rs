@@ -0,0 +1,122 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`SpamReportsApi::get_suppression_spam_reports`], set with a builder.
+pub struct SpamReportsApiGetSuppressionSpamReportsRequest<'a> {
+    inner: crate::spam_reports_api::SpamReportsApiGetSuppressionSpamReportsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> SpamReportsApiGetSuppressionSpamReportsRequest<'a> {
+    /// Sets the `start_time` query parameter.
+    pub fn start_time(mut self, start_time: i64) -> Self {
+        self.inner = self.inner.start_time(start_time);
+        self
+    }
+
+    /// Sets the `end_time` query parameter.
+    pub fn end_time(mut self, end_time: i64) -> Self {
+        self.inner = self.inner.end_time(end_time);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.inner = self.inner.offset(offset);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::SpamReportsResponse>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::spam_reports_api::SpamReportsApi`].
+pub struct SpamReportsApi {
+    inner: crate::spam_reports_api::SpamReportsApi,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl SpamReportsApi {
+    pub(crate) fn new(
+        inner: crate::spam_reports_api::SpamReportsApi,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        SpamReportsApi { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::spam_reports_api::SpamReportsApi::get_suppression_spam_reports`].
+    pub fn get_suppression_spam_reports(
+        &self,
+        start_time: i64,
+        end_time: i64,
+        limit: i64,
+        offset: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::SpamReportsResponse>>> {
+        self.runtime.block_on(
+            self.inner
+                .get_suppression_spam_reports(start_time, end_time, limit, offset),
+        )
+    }
+    /// The blocking version of [`crate::spam_reports_api::SpamReportsApi::get_suppression_spam_reports_request`].
+    pub fn get_suppression_spam_reports_request(
+        &self,
+    ) -> SpamReportsApiGetSuppressionSpamReportsRequest<'_> {
+        SpamReportsApiGetSuppressionSpamReportsRequest {
+            inner: self.inner.get_suppression_spam_reports_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::spam_reports_api::SpamReportsApi::get_all_suppression_spam_reports`].
+    pub fn get_all_suppression_spam_reports(
+        &self,
+        start_time: i64,
+        end_time: i64,
+        offset: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::SpamReportsResponse>>> {
+        self.runtime.block_on(
+            self.inner
+                .get_all_suppression_spam_reports(start_time, end_time, offset),
+        )
+    }
+    /// The blocking version of [`crate::spam_reports_api::SpamReportsApi::delete_suppression_spam_reports`].
+    pub fn delete_suppression_spam_reports(
+        &self,
+        body: &crate::types::DeleteSuppressionBlocksRequest,
+    ) -> ClientResult<crate::Response<crate::types::Help>> {
+        self.runtime
+            .block_on(self.inner.delete_suppression_spam_reports(body))
+    }
+    /// The blocking version of [`crate::spam_reports_api::SpamReportsApi::get_suppression_spam_reports_email`].
+    pub fn get_suppression_spam_reports_email(
+        &self,
+        email: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::SpamReportsResponse>>> {
+        self.runtime
+            .block_on(self.inner.get_suppression_spam_reports_email(email))
+    }
+    /// The blocking version of [`crate::spam_reports_api::SpamReportsApi::get_all_suppression_spam_reports_email`].
+    pub fn get_all_suppression_spam_reports_email(
+        &self,
+        email: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::SpamReportsResponse>>> {
+        self.runtime
+            .block_on(self.inner.get_all_suppression_spam_reports_email(email))
+    }
+    /// The blocking version of [`crate::spam_reports_api::SpamReportsApi::delete_suppression_spam_reports_email`].
+    pub fn delete_suppression_spam_reports_email(
+        &self,
+        email: &str,
+    ) -> ClientResult<crate::Response<crate::types::Help>> {
+        self.runtime
+            .block_on(self.inner.delete_suppression_spam_reports_email(email))
+    }
+}
@@ -0,0 +1,534 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Stats::get_page`], set with a builder.
+pub struct StatsGetPageRequest<'a> {
+    inner: crate::stats::StatsGetPageRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> StatsGetPageRequest<'a> {
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.inner = self.inner.offset(offset);
+        self
+    }
+
+    /// Sets the `aggregated_by` query parameter.
+    pub fn aggregated_by(
+        mut self,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+    ) -> Self {
+        self.inner = self.inner.aggregated_by(aggregated_by);
+        self
+    }
+
+    /// Sets the `end_date` query parameter.
+    pub fn end_date(mut self, end_date: &'a str) -> Self {
+        self.inner = self.inner.end_date(end_date);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::GetStatsResponseData>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Stats::get_geo`], set with a builder.
+pub struct StatsGetGeoRequest<'a> {
+    inner: crate::stats::StatsGetGeoRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> StatsGetGeoRequest<'a> {
+    /// Sets the `country` query parameter.
+    pub fn country(mut self, country: crate::types::Country) -> Self {
+        self.inner = self.inner.country(country);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.inner = self.inner.offset(offset);
+        self
+    }
+
+    /// Sets the `aggregated_by` query parameter.
+    pub fn aggregated_by(
+        mut self,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+    ) -> Self {
+        self.inner = self.inner.aggregated_by(aggregated_by);
+        self
+    }
+
+    /// Sets the `end_date` query parameter.
+    pub fn end_date(mut self, end_date: &'a str) -> Self {
+        self.inner = self.inner.end_date(end_date);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::GetGeoStatsResponseData>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Stats::get_devices`], set with a builder.
+pub struct StatsGetDevicesRequest<'a> {
+    inner: crate::stats::StatsGetDevicesRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> StatsGetDevicesRequest<'a> {
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.inner = self.inner.offset(offset);
+        self
+    }
+
+    /// Sets the `aggregated_by` query parameter.
+    pub fn aggregated_by(
+        mut self,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+    ) -> Self {
+        self.inner = self.inner.aggregated_by(aggregated_by);
+        self
+    }
+
+    /// Sets the `end_date` query parameter.
+    pub fn end_date(mut self, end_date: &'a str) -> Self {
+        self.inner = self.inner.end_date(end_date);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::GetClientsStatsResponse>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Stats::get_clients`], set with a builder.
+pub struct StatsGetClientsRequest<'a> {
+    inner: crate::stats::StatsGetClientsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> StatsGetClientsRequest<'a> {
+    /// Sets the `end_date` query parameter.
+    pub fn end_date(mut self, end_date: &'a str) -> Self {
+        self.inner = self.inner.end_date(end_date);
+        self
+    }
+
+    /// Sets the `aggregated_by` query parameter.
+    pub fn aggregated_by(
+        mut self,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+    ) -> Self {
+        self.inner = self.inner.aggregated_by(aggregated_by);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::GetClientsStatsResponse>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Stats::get_clients_client_type`], set with a builder.
+pub struct StatsGetClientsClientTypeRequest<'a> {
+    inner: crate::stats::StatsGetClientsClientTypeRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> StatsGetClientsClientTypeRequest<'a> {
+    /// Sets the `end_date` query parameter.
+    pub fn end_date(mut self, end_date: &'a str) -> Self {
+        self.inner = self.inner.end_date(end_date);
+        self
+    }
+
+    /// Sets the `aggregated_by` query parameter.
+    pub fn aggregated_by(
+        mut self,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+    ) -> Self {
+        self.inner = self.inner.aggregated_by(aggregated_by);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::GetClientsStatsResponse>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Stats::get_mailbox_providers`], set with a builder.
+pub struct StatsGetMailboxProvidersRequest<'a> {
+    inner: crate::stats::StatsGetMailboxProvidersRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> StatsGetMailboxProvidersRequest<'a> {
+    /// Sets the `mailbox_providers` query parameter.
+    pub fn mailbox_providers(mut self, mailbox_providers: &'a str) -> Self {
+        self.inner = self.inner.mailbox_providers(mailbox_providers);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.inner = self.inner.offset(offset);
+        self
+    }
+
+    /// Sets the `aggregated_by` query parameter.
+    pub fn aggregated_by(
+        mut self,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+    ) -> Self {
+        self.inner = self.inner.aggregated_by(aggregated_by);
+        self
+    }
+
+    /// Sets the `end_date` query parameter.
+    pub fn end_date(mut self, end_date: &'a str) -> Self {
+        self.inner = self.inner.end_date(end_date);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(
+        self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GetMailboxProvidersStatsResponseData>>>
+    {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Stats::get_browsers`], set with a builder.
+pub struct StatsGetBrowsersRequest<'a> {
+    inner: crate::stats::StatsGetBrowsersRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> StatsGetBrowsersRequest<'a> {
+    /// Sets the `browsers` query parameter.
+    pub fn browsers(mut self, browsers: &'a str) -> Self {
+        self.inner = self.inner.browsers(browsers);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.inner = self.inner.offset(offset);
+        self
+    }
+
+    /// Sets the `aggregated_by` query parameter.
+    pub fn aggregated_by(
+        mut self,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+    ) -> Self {
+        self.inner = self.inner.aggregated_by(aggregated_by);
+        self
+    }
+
+    /// Sets the `end_date` query parameter.
+    pub fn end_date(mut self, end_date: &'a str) -> Self {
+        self.inner = self.inner.end_date(end_date);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(
+        self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GetBrowsersStatsResponseData>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::stats::Stats`].
+pub struct Stats {
+    inner: crate::stats::Stats,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Stats {
+    pub(crate) fn new(inner: crate::stats::Stats, runtime: Arc<tokio::runtime::Runtime>) -> Self {
+        Stats { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::stats::Stats::get_page`].
+    pub fn get_page(
+        &self,
+        offset: i64,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+        start_date: &str,
+        end_date: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GetStatsResponseData>>> {
+        self.runtime.block_on(
+            self.inner
+                .get_page(offset, aggregated_by, start_date, end_date),
+        )
+    }
+    /// The blocking version of [`crate::stats::Stats::get_page_request`].
+    pub fn get_page_request<'a>(&'a self, start_date: &'a str) -> StatsGetPageRequest<'a> {
+        StatsGetPageRequest {
+            inner: self.inner.get_page_request(start_date),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::stats::Stats::get_all`].
+    pub fn get_all(
+        &self,
+        offset: i64,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+        start_date: &str,
+        end_date: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GetStatsResponseData>>> {
+        self.runtime.block_on(
+            self.inner
+                .get_all(offset, aggregated_by, start_date, end_date),
+        )
+    }
+    /// The blocking version of [`crate::stats::Stats::get_geo`].
+    pub fn get_geo(
+        &self,
+        country: crate::types::Country,
+        offset: i64,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+        start_date: &str,
+        end_date: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GetGeoStatsResponseData>>> {
+        self.runtime.block_on(self.inner.get_geo(
+            country,
+            offset,
+            aggregated_by,
+            start_date,
+            end_date,
+        ))
+    }
+    /// The blocking version of [`crate::stats::Stats::get_geo_request`].
+    pub fn get_geo_request<'a>(&'a self, start_date: &'a str) -> StatsGetGeoRequest<'a> {
+        StatsGetGeoRequest {
+            inner: self.inner.get_geo_request(start_date),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::stats::Stats::get_all_geo`].
+    pub fn get_all_geo(
+        &self,
+        country: crate::types::Country,
+        offset: i64,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+        start_date: &str,
+        end_date: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GetGeoStatsResponseData>>> {
+        self.runtime.block_on(self.inner.get_all_geo(
+            country,
+            offset,
+            aggregated_by,
+            start_date,
+            end_date,
+        ))
+    }
+    /// The blocking version of [`crate::stats::Stats::get_devices`].
+    pub fn get_devices(
+        &self,
+        offset: i64,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+        start_date: &str,
+        end_date: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GetClientsStatsResponse>>> {
+        self.runtime.block_on(
+            self.inner
+                .get_devices(offset, aggregated_by, start_date, end_date),
+        )
+    }
+    /// The blocking version of [`crate::stats::Stats::get_devices_request`].
+    pub fn get_devices_request<'a>(&'a self, start_date: &'a str) -> StatsGetDevicesRequest<'a> {
+        StatsGetDevicesRequest {
+            inner: self.inner.get_devices_request(start_date),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::stats::Stats::get_all_devices`].
+    pub fn get_all_devices(
+        &self,
+        offset: i64,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+        start_date: &str,
+        end_date: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GetClientsStatsResponse>>> {
+        self.runtime.block_on(self.inner.get_all_devices(
+            offset,
+            aggregated_by,
+            start_date,
+            end_date,
+        ))
+    }
+    /// The blocking version of [`crate::stats::Stats::get_clients`].
+    pub fn get_clients(
+        &self,
+        start_date: &str,
+        end_date: &str,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GetClientsStatsResponse>>> {
+        self.runtime
+            .block_on(self.inner.get_clients(start_date, end_date, aggregated_by))
+    }
+    /// The blocking version of [`crate::stats::Stats::get_clients_request`].
+    pub fn get_clients_request<'a>(&'a self, start_date: &'a str) -> StatsGetClientsRequest<'a> {
+        StatsGetClientsRequest {
+            inner: self.inner.get_clients_request(start_date),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::stats::Stats::get_all_clients`].
+    pub fn get_all_clients(
+        &self,
+        start_date: &str,
+        end_date: &str,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GetClientsStatsResponse>>> {
+        self.runtime.block_on(
+            self.inner
+                .get_all_clients(start_date, end_date, aggregated_by),
+        )
+    }
+    /// The blocking version of [`crate::stats::Stats::get_clients_client_type`].
+    pub fn get_clients_client_type(
+        &self,
+        client_type: crate::types::ClientType,
+        start_date: &str,
+        end_date: &str,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GetClientsStatsResponse>>> {
+        self.runtime.block_on(self.inner.get_clients_client_type(
+            client_type,
+            start_date,
+            end_date,
+            aggregated_by,
+        ))
+    }
+    /// The blocking version of [`crate::stats::Stats::get_clients_client_type_request`].
+    pub fn get_clients_client_type_request<'a>(
+        &'a self,
+        client_type: crate::types::ClientType,
+        start_date: &'a str,
+    ) -> StatsGetClientsClientTypeRequest<'a> {
+        StatsGetClientsClientTypeRequest {
+            inner: self
+                .inner
+                .get_clients_client_type_request(client_type, start_date),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::stats::Stats::get_all_clients_client_type`].
+    pub fn get_all_clients_client_type(
+        &self,
+        client_type: crate::types::ClientType,
+        start_date: &str,
+        end_date: &str,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GetClientsStatsResponse>>> {
+        self.runtime
+            .block_on(self.inner.get_all_clients_client_type(
+                client_type,
+                start_date,
+                end_date,
+                aggregated_by,
+            ))
+    }
+    /// The blocking version of [`crate::stats::Stats::get_mailbox_providers`].
+    pub fn get_mailbox_providers(
+        &self,
+        mailbox_providers: &str,
+        offset: i64,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+        start_date: &str,
+        end_date: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GetMailboxProvidersStatsResponseData>>>
+    {
+        self.runtime.block_on(self.inner.get_mailbox_providers(
+            mailbox_providers,
+            offset,
+            aggregated_by,
+            start_date,
+            end_date,
+        ))
+    }
+    /// The blocking version of [`crate::stats::Stats::get_mailbox_providers_request`].
+    pub fn get_mailbox_providers_request<'a>(
+        &'a self,
+        start_date: &'a str,
+    ) -> StatsGetMailboxProvidersRequest<'a> {
+        StatsGetMailboxProvidersRequest {
+            inner: self.inner.get_mailbox_providers_request(start_date),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::stats::Stats::get_all_mailbox_providers`].
+    pub fn get_all_mailbox_providers(
+        &self,
+        mailbox_providers: &str,
+        offset: i64,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+        start_date: &str,
+        end_date: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GetMailboxProvidersStatsResponseData>>>
+    {
+        self.runtime.block_on(self.inner.get_all_mailbox_providers(
+            mailbox_providers,
+            offset,
+            aggregated_by,
+            start_date,
+            end_date,
+        ))
+    }
+    /// The blocking version of [`crate::stats::Stats::get_browsers`].
+    pub fn get_browsers(
+        &self,
+        browsers: &str,
+        offset: i64,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+        start_date: &str,
+        end_date: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GetBrowsersStatsResponseData>>> {
+        self.runtime.block_on(self.inner.get_browsers(
+            browsers,
+            offset,
+            aggregated_by,
+            start_date,
+            end_date,
+        ))
+    }
+    /// The blocking version of [`crate::stats::Stats::get_browsers_request`].
+    pub fn get_browsers_request<'a>(&'a self, start_date: &'a str) -> StatsGetBrowsersRequest<'a> {
+        StatsGetBrowsersRequest {
+            inner: self.inner.get_browsers_request(start_date),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::stats::Stats::get_all_browsers`].
+    pub fn get_all_browsers(
+        &self,
+        browsers: &str,
+        offset: i64,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+        start_date: &str,
+        end_date: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GetBrowsersStatsResponseData>>> {
+        self.runtime.block_on(self.inner.get_all_browsers(
+            browsers,
+            offset,
+            aggregated_by,
+            start_date,
+            end_date,
+        ))
+    }
+}
@@ -0,0 +1,59 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::subuser_monitor_settings::SubuserMonitorSettings`].
+pub struct SubuserMonitorSettings {
+    inner: crate::subuser_monitor_settings::SubuserMonitorSettings,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl SubuserMonitorSettings {
+    pub(crate) fn new(
+        inner: crate::subuser_monitor_settings::SubuserMonitorSettings,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        SubuserMonitorSettings { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::subuser_monitor_settings::SubuserMonitorSettings::get_subusers_subuser_name_monitor`].
+    pub fn get_subusers_subuser_name_monitor(
+        &self,
+        subuser_name: &str,
+    ) -> ClientResult<crate::Response<crate::types::Monitor>> {
+        self.runtime
+            .block_on(self.inner.get_subusers_subuser_name_monitor(subuser_name))
+    }
+    /// The blocking version of [`crate::subuser_monitor_settings::SubuserMonitorSettings::put_subusers_subuser_name_monitor`].
+    pub fn put_subusers_subuser_name_monitor(
+        &self,
+        subuser_name: &str,
+        body: &crate::types::Monitor,
+    ) -> ClientResult<crate::Response<crate::types::Monitor>> {
+        self.runtime.block_on(
+            self.inner
+                .put_subusers_subuser_name_monitor(subuser_name, body),
+        )
+    }
+    /// The blocking version of [`crate::subuser_monitor_settings::SubuserMonitorSettings::post_subusers_subuser_name_monitor`].
+    pub fn post_subusers_subuser_name_monitor(
+        &self,
+        subuser_name: &str,
+        body: &crate::types::Monitor,
+    ) -> ClientResult<crate::Response<crate::types::Monitor>> {
+        self.runtime.block_on(
+            self.inner
+                .post_subusers_subuser_name_monitor(subuser_name, body),
+        )
+    }
+    /// The blocking version of [`crate::subuser_monitor_settings::SubuserMonitorSettings::delete_subusers_subuser_name_monitor`].
+    pub fn delete_subusers_subuser_name_monitor(
+        &self,
+        subuser_name: &str,
+    ) -> ClientResult<crate::Response<crate::types::Help>> {
+        self.runtime.block_on(
+            self.inner
+                .delete_subusers_subuser_name_monitor(subuser_name),
+        )
+    }
+}
@@ -0,0 +1,312 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`SubuserStatistics::get_subusers_subuser_name_stats_monthly`], set with a builder.
+pub struct SubuserStatisticsGetSubusersSubuserNameStatsMonthlyRequest<'a> {
+    inner:
+        crate::subuser_statistics::SubuserStatisticsGetSubusersSubuserNameStatsMonthlyRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> SubuserStatisticsGetSubusersSubuserNameStatsMonthlyRequest<'a> {
+    /// Sets the `sort_by_metric` query parameter.
+    pub fn sort_by_metric(mut self, sort_by_metric: &'a str) -> Self {
+        self.inner = self.inner.sort_by_metric(sort_by_metric);
+        self
+    }
+
+    /// Sets the `sort_by_direction` query parameter.
+    pub fn sort_by_direction(mut self, sort_by_direction: crate::types::SortByDirection) -> Self {
+        self.inner = self.inner.sort_by_direction(sort_by_direction);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.inner = self.inner.offset(offset);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::SubuserStatsData>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`SubuserStatistics::get_subusers_stats_monthly`], set with a builder.
+pub struct SubuserStatisticsGetSubusersStatsMonthlyRequest<'a> {
+    inner: crate::subuser_statistics::SubuserStatisticsGetSubusersStatsMonthlyRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> SubuserStatisticsGetSubusersStatsMonthlyRequest<'a> {
+    /// Sets the `subuser` query parameter.
+    pub fn subuser(mut self, subuser: &'a str) -> Self {
+        self.inner = self.inner.subuser(subuser);
+        self
+    }
+
+    /// Sets the `sort_by_metric` query parameter.
+    pub fn sort_by_metric(mut self, sort_by_metric: crate::types::SortByMetric) -> Self {
+        self.inner = self.inner.sort_by_metric(sort_by_metric);
+        self
+    }
+
+    /// Sets the `sort_by_direction` query parameter.
+    pub fn sort_by_direction(mut self, sort_by_direction: crate::types::SortByDirection) -> Self {
+        self.inner = self.inner.sort_by_direction(sort_by_direction);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.inner = self.inner.offset(offset);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::SubuserStatsData>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`SubuserStatistics::get_subusers_stats_sum`], set with a builder.
+pub struct SubuserStatisticsGetSubusersStatsSumRequest<'a> {
+    inner: crate::subuser_statistics::SubuserStatisticsGetSubusersStatsSumRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> SubuserStatisticsGetSubusersStatsSumRequest<'a> {
+    /// Sets the `sort_by_direction` query parameter.
+    pub fn sort_by_direction(mut self, sort_by_direction: crate::types::SortByDirection) -> Self {
+        self.inner = self.inner.sort_by_direction(sort_by_direction);
+        self
+    }
+
+    /// Sets the `end_date` query parameter.
+    pub fn end_date(mut self, end_date: &'a str) -> Self {
+        self.inner = self.inner.end_date(end_date);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.inner = self.inner.offset(offset);
+        self
+    }
+
+    /// Sets the `aggregated_by` query parameter.
+    pub fn aggregated_by(mut self, aggregated_by: &'a str) -> Self {
+        self.inner = self.inner.aggregated_by(aggregated_by);
+        self
+    }
+
+    /// Sets the `sort_by_metric` query parameter.
+    pub fn sort_by_metric(mut self, sort_by_metric: &'a str) -> Self {
+        self.inner = self.inner.sort_by_metric(sort_by_metric);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::CategoryStats>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`SubuserStatistics::get_subusers_stat`], set with a builder.
+pub struct SubuserStatisticsGetSubusersStatRequest<'a> {
+    inner: crate::subuser_statistics::SubuserStatisticsGetSubusersStatRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> SubuserStatisticsGetSubusersStatRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.inner = self.inner.offset(offset);
+        self
+    }
+
+    /// Sets the `aggregated_by` query parameter.
+    pub fn aggregated_by(
+        mut self,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+    ) -> Self {
+        self.inner = self.inner.aggregated_by(aggregated_by);
+        self
+    }
+
+    /// Sets the `end_date` query parameter.
+    pub fn end_date(mut self, end_date: &'a str) -> Self {
+        self.inner = self.inner.end_date(end_date);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::CategoryStats>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::subuser_statistics::SubuserStatistics`].
+pub struct SubuserStatistics {
+    inner: crate::subuser_statistics::SubuserStatistics,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl SubuserStatistics {
+    pub(crate) fn new(
+        inner: crate::subuser_statistics::SubuserStatistics,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        SubuserStatistics { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::subuser_statistics::SubuserStatistics::get_subusers_subuser_name_stats_monthly`].
+    pub fn get_subusers_subuser_name_stats_monthly(
+        &self,
+        subuser_name: &str,
+        date: &str,
+        sort_by_metric: &str,
+        sort_by_direction: crate::types::SortByDirection,
+        limit: i64,
+        offset: i64,
+    ) -> ClientResult<crate::Response<crate::types::SubuserStatsData>> {
+        self.runtime
+            .block_on(self.inner.get_subusers_subuser_name_stats_monthly(
+                subuser_name,
+                date,
+                sort_by_metric,
+                sort_by_direction,
+                limit,
+                offset,
+            ))
+    }
+    /// The blocking version of [`crate::subuser_statistics::SubuserStatistics::get_subusers_subuser_name_stats_monthly_request`].
+    pub fn get_subusers_subuser_name_stats_monthly_request<'a>(
+        &'a self,
+        subuser_name: &'a str,
+        date: &'a str,
+    ) -> SubuserStatisticsGetSubusersSubuserNameStatsMonthlyRequest<'a> {
+        SubuserStatisticsGetSubusersSubuserNameStatsMonthlyRequest {
+            inner: self
+                .inner
+                .get_subusers_subuser_name_stats_monthly_request(subuser_name, date),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::subuser_statistics::SubuserStatistics::get_subusers_stats_monthly`].
+    pub fn get_subusers_stats_monthly(
+        &self,
+        date: &str,
+        subuser: &str,
+        sort_by_metric: crate::types::SortByMetric,
+        sort_by_direction: crate::types::SortByDirection,
+        limit: i64,
+        offset: i64,
+    ) -> ClientResult<crate::Response<crate::types::SubuserStatsData>> {
+        self.runtime.block_on(self.inner.get_subusers_stats_monthly(
+            date,
+            subuser,
+            sort_by_metric,
+            sort_by_direction,
+            limit,
+            offset,
+        ))
+    }
+    /// The blocking version of [`crate::subuser_statistics::SubuserStatistics::get_subusers_stats_monthly_request`].
+    pub fn get_subusers_stats_monthly_request<'a>(
+        &'a self,
+        date: &'a str,
+    ) -> SubuserStatisticsGetSubusersStatsMonthlyRequest<'a> {
+        SubuserStatisticsGetSubusersStatsMonthlyRequest {
+            inner: self.inner.get_subusers_stats_monthly_request(date),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::subuser_statistics::SubuserStatistics::get_subusers_stats_sum`].
+    pub fn get_subusers_stats_sum(
+        &self,
+        sort_by_direction: crate::types::SortByDirection,
+        start_date: &str,
+        end_date: &str,
+        limit: i64,
+        offset: i64,
+        aggregated_by: &str,
+        sort_by_metric: &str,
+    ) -> ClientResult<crate::Response<crate::types::CategoryStats>> {
+        self.runtime.block_on(self.inner.get_subusers_stats_sum(
+            sort_by_direction,
+            start_date,
+            end_date,
+            limit,
+            offset,
+            aggregated_by,
+            sort_by_metric,
+        ))
+    }
+    /// The blocking version of [`crate::subuser_statistics::SubuserStatistics::get_subusers_stats_sum_request`].
+    pub fn get_subusers_stats_sum_request<'a>(
+        &'a self,
+        start_date: &'a str,
+    ) -> SubuserStatisticsGetSubusersStatsSumRequest<'a> {
+        SubuserStatisticsGetSubusersStatsSumRequest {
+            inner: self.inner.get_subusers_stats_sum_request(start_date),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::subuser_statistics::SubuserStatistics::get_subusers_stat`].
+    pub fn get_subusers_stat(
+        &self,
+        limit: i64,
+        offset: i64,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+        subusers: &str,
+        start_date: &str,
+        end_date: &str,
+    ) -> ClientResult<crate::Response<crate::types::CategoryStats>> {
+        self.runtime.block_on(self.inner.get_subusers_stat(
+            limit,
+            offset,
+            aggregated_by,
+            subusers,
+            start_date,
+            end_date,
+        ))
+    }
+    /// The blocking version of [`crate::subuser_statistics::SubuserStatistics::get_subusers_stat_request`].
+    pub fn get_subusers_stat_request<'a>(
+        &'a self,
+        subusers: &'a str,
+        start_date: &'a str,
+    ) -> SubuserStatisticsGetSubusersStatRequest<'a> {
+        SubuserStatisticsGetSubusersStatRequest {
+            inner: self.inner.get_subusers_stat_request(subusers, start_date),
+            runtime: &self.runtime,
+        }
+    }
+}
@@ -0,0 +1,153 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`SubusersApi::get_subusers`], set with a builder.
+pub struct SubusersApiGetSubusersRequest<'a> {
+    inner: crate::subusers_api::SubusersApiGetSubusersRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> SubusersApiGetSubusersRequest<'a> {
+    /// Sets the `username` query parameter.
+    pub fn username(mut self, username: &'a str) -> Self {
+        self.inner = self.inner.username(username);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.inner = self.inner.offset(offset);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Subuser>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`SubusersApi::get_subusers_reputations`], set with a builder.
+pub struct SubusersApiGetSubusersReputationsRequest<'a> {
+    inner: crate::subusers_api::SubusersApiGetSubusersReputationsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> SubusersApiGetSubusersReputationsRequest<'a> {
+    /// Sets the `usernames` query parameter.
+    pub fn usernames(mut self, usernames: &'a str) -> Self {
+        self.inner = self.inner.usernames(usernames);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(
+        self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GetSubusersReputationsResponse>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::subusers_api::SubusersApi`].
+pub struct SubusersApi {
+    inner: crate::subusers_api::SubusersApi,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl SubusersApi {
+    pub(crate) fn new(
+        inner: crate::subusers_api::SubusersApi,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        SubusersApi { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::subusers_api::SubusersApi::get_subusers`].
+    pub fn get_subusers(
+        &self,
+        username: &str,
+        limit: i64,
+        offset: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Subuser>>> {
+        self.runtime
+            .block_on(self.inner.get_subusers(username, limit, offset))
+    }
+    /// The blocking version of [`crate::subusers_api::SubusersApi::get_subusers_request`].
+    pub fn get_subusers_request(&self) -> SubusersApiGetSubusersRequest<'_> {
+        SubusersApiGetSubusersRequest {
+            inner: self.inner.get_subusers_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::subusers_api::SubusersApi::get_all_subusers`].
+    pub fn get_all_subusers(
+        &self,
+        username: &str,
+        offset: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Subuser>>> {
+        self.runtime
+            .block_on(self.inner.get_all_subusers(username, offset))
+    }
+    /// The blocking version of [`crate::subusers_api::SubusersApi::post_subuser`].
+    pub fn post_subuser(
+        &self,
+        body: &crate::types::PostSubusersRequest,
+    ) -> ClientResult<crate::Response<crate::types::SubuserPost>> {
+        self.runtime.block_on(self.inner.post_subuser(body))
+    }
+    /// The blocking version of [`crate::subusers_api::SubusersApi::delete_subusers_subuser_name`].
+    pub fn delete_subusers_subuser_name(
+        &self,
+        subuser_name: &str,
+    ) -> ClientResult<crate::Response<crate::types::Help>> {
+        self.runtime
+            .block_on(self.inner.delete_subusers_subuser_name(subuser_name))
+    }
+    /// The blocking version of [`crate::subusers_api::SubusersApi::patch_subusers_subuser_name`].
+    pub fn patch_subusers_subuser_name(
+        &self,
+        subuser_name: &str,
+        body: &crate::types::PatchSubusersSubuserNameRequest,
+    ) -> ClientResult<crate::Response<crate::types::Help>> {
+        self.runtime
+            .block_on(self.inner.patch_subusers_subuser_name(subuser_name, body))
+    }
+    /// The blocking version of [`crate::subusers_api::SubusersApi::get_subusers_reputations`].
+    pub fn get_subusers_reputations(
+        &self,
+        usernames: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GetSubusersReputationsResponse>>> {
+        self.runtime
+            .block_on(self.inner.get_subusers_reputations(usernames))
+    }
+    /// The blocking version of [`crate::subusers_api::SubusersApi::get_subusers_reputations_request`].
+    pub fn get_subusers_reputations_request(&self) -> SubusersApiGetSubusersReputationsRequest<'_> {
+        SubusersApiGetSubusersReputationsRequest {
+            inner: self.inner.get_subusers_reputations_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::subusers_api::SubusersApi::get_all_subusers_reputations`].
+    pub fn get_all_subusers_reputations(
+        &self,
+        usernames: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GetSubusersReputationsResponse>>> {
+        self.runtime
+            .block_on(self.inner.get_all_subusers_reputations(usernames))
+    }
+    /// The blocking version of [`crate::subusers_api::SubusersApi::put_subusers_subuser_name_ips`].
+    pub fn put_subusers_subuser_name_ips(
+        &self,
+        subuser_name: &str,
+        body: &[std::net::Ipv4Addr],
+    ) -> ClientResult<crate::Response<crate::types::PutSubusersSubuserNameIpsResponse>> {
+        self.runtime
+            .block_on(self.inner.put_subusers_subuser_name_ips(subuser_name, body))
+    }
+}
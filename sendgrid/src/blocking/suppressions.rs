@@ -0,0 +1,81 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::suppressions::Suppressions`].
+pub struct Suppressions {
+    inner: crate::suppressions::Suppressions,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Suppressions {
+    pub(crate) fn new(
+        inner: crate::suppressions::Suppressions,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Suppressions { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::suppressions::Suppressions::get_asm_groups_group`].
+    pub fn get_asm_groups_group(
+        &self,
+        group_id: &str,
+    ) -> ClientResult<crate::Response<Vec<String>>> {
+        self.runtime
+            .block_on(self.inner.get_asm_groups_group(group_id))
+    }
+    /// The blocking version of [`crate::suppressions::Suppressions::get_all_asm_groups_group`].
+    pub fn get_all_asm_groups_group(
+        &self,
+        group_id: &str,
+    ) -> ClientResult<crate::Response<Vec<String>>> {
+        self.runtime
+            .block_on(self.inner.get_all_asm_groups_group(group_id))
+    }
+    /// The blocking version of [`crate::suppressions::Suppressions::post_asm_groups_group`].
+    pub fn post_asm_groups_group(
+        &self,
+        group_id: &str,
+        body: &crate::types::SuppressionsRequestBody,
+    ) -> ClientResult<crate::Response<crate::types::PostAsmGroupsGroupSuppressionsResponse>> {
+        self.runtime
+            .block_on(self.inner.post_asm_groups_group(group_id, body))
+    }
+    /// The blocking version of [`crate::suppressions::Suppressions::post_asm_groups_group_search`].
+    pub fn post_asm_groups_group_search(
+        &self,
+        group_id: &str,
+        body: &crate::types::SuppressionsRequestBody,
+    ) -> ClientResult<crate::Response<Vec<String>>> {
+        self.runtime
+            .block_on(self.inner.post_asm_groups_group_search(group_id, body))
+    }
+    /// The blocking version of [`crate::suppressions::Suppressions::get_asm`].
+    pub fn get_asm(
+        &self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GetAsmSuppressionsResponse>>> {
+        self.runtime.block_on(self.inner.get_asm())
+    }
+    /// The blocking version of [`crate::suppressions::Suppressions::get_all_asm`].
+    pub fn get_all_asm(
+        &self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GetAsmSuppressionsResponse>>> {
+        self.runtime.block_on(self.inner.get_all_asm())
+    }
+    /// The blocking version of [`crate::suppressions::Suppressions::get_asm_email`].
+    pub fn get_asm_email(
+        &self,
+        email: &str,
+    ) -> ClientResult<crate::Response<crate::types::GetAsmSuppressionsEmailResponse>> {
+        self.runtime.block_on(self.inner.get_asm_email(email))
+    }
+    /// The blocking version of [`crate::suppressions::Suppressions::delete_asm_groups_group_email`].
+    pub fn delete_asm_groups_group_email(
+        &self,
+        group_id: &str,
+        email: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete_asm_groups_group_email(group_id, email))
+    }
+}
@@ -0,0 +1,113 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`SuppressionsGlobal::get_suppression_unsubscribes`], set with a builder.
+pub struct SuppressionsGlobalGetSuppressionUnsubscribesRequest<'a> {
+    inner: crate::suppressions_global::SuppressionsGlobalGetSuppressionUnsubscribesRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> SuppressionsGlobalGetSuppressionUnsubscribesRequest<'a> {
+    /// Sets the `start_time` query parameter.
+    pub fn start_time(mut self, start_time: i64) -> Self {
+        self.inner = self.inner.start_time(start_time);
+        self
+    }
+
+    /// Sets the `end_time` query parameter.
+    pub fn end_time(mut self, end_time: i64) -> Self {
+        self.inner = self.inner.end_time(end_time);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.inner = self.inner.offset(offset);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(
+        self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GetSuppressionUnsubscribesResponse>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::suppressions_global::SuppressionsGlobal`].
+pub struct SuppressionsGlobal {
+    inner: crate::suppressions_global::SuppressionsGlobal,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl SuppressionsGlobal {
+    pub(crate) fn new(
+        inner: crate::suppressions_global::SuppressionsGlobal,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        SuppressionsGlobal { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::suppressions_global::SuppressionsGlobal::post_asm`].
+    pub fn post_asm(
+        &self,
+        body: &crate::types::SuppressionsRequestBody,
+    ) -> ClientResult<crate::Response<crate::types::SuppressionsRequestBody>> {
+        self.runtime.block_on(self.inner.post_asm(body))
+    }
+    /// The blocking version of [`crate::suppressions_global::SuppressionsGlobal::get_suppression_unsubscribes`].
+    pub fn get_suppression_unsubscribes(
+        &self,
+        start_time: i64,
+        end_time: i64,
+        limit: i64,
+        offset: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GetSuppressionUnsubscribesResponse>>> {
+        self.runtime.block_on(
+            self.inner
+                .get_suppression_unsubscribes(start_time, end_time, limit, offset),
+        )
+    }
+    /// The blocking version of [`crate::suppressions_global::SuppressionsGlobal::get_suppression_unsubscribes_request`].
+    pub fn get_suppression_unsubscribes_request(
+        &self,
+    ) -> SuppressionsGlobalGetSuppressionUnsubscribesRequest<'_> {
+        SuppressionsGlobalGetSuppressionUnsubscribesRequest {
+            inner: self.inner.get_suppression_unsubscribes_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::suppressions_global::SuppressionsGlobal::get_all_suppression_unsubscribes`].
+    pub fn get_all_suppression_unsubscribes(
+        &self,
+        start_time: i64,
+        end_time: i64,
+        offset: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GetSuppressionUnsubscribesResponse>>> {
+        self.runtime.block_on(
+            self.inner
+                .get_all_suppression_unsubscribes(start_time, end_time, offset),
+        )
+    }
+    /// The blocking version of [`crate::suppressions_global::SuppressionsGlobal::get_asm_email`].
+    pub fn get_asm_email(
+        &self,
+        email: &str,
+    ) -> ClientResult<crate::Response<crate::types::RetrieveAGlobalSuppressionResponse>> {
+        self.runtime.block_on(self.inner.get_asm_email(email))
+    }
+    /// The blocking version of [`crate::suppressions_global::SuppressionsGlobal::delete_asm_email`].
+    pub fn delete_asm_email(
+        &self,
+        email: &str,
+    ) -> ClientResult<crate::Response<crate::types::Help>> {
+        self.runtime.block_on(self.inner.delete_asm_email(email))
+    }
+}
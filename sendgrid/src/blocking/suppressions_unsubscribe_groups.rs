@@ -0,0 +1,93 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`SuppressionsUnsubscribeGroups::get_asm_groups`], set with a builder.
+pub struct SuppressionsUnsubscribeGroupsGetAsmGroupsRequest<'a> {
+    inner: crate::suppressions_unsubscribe_groups::SuppressionsUnsubscribeGroupsGetAsmGroupsRequest<
+        'a,
+    >,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> SuppressionsUnsubscribeGroupsGetAsmGroupsRequest<'a> {
+    /// Sets the `id` query parameter.
+    pub fn id(mut self, id: i64) -> Self {
+        self.inner = self.inner.id(id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::SuppressionGroup>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::suppressions_unsubscribe_groups::SuppressionsUnsubscribeGroups`].
+pub struct SuppressionsUnsubscribeGroups {
+    inner: crate::suppressions_unsubscribe_groups::SuppressionsUnsubscribeGroups,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl SuppressionsUnsubscribeGroups {
+    pub(crate) fn new(
+        inner: crate::suppressions_unsubscribe_groups::SuppressionsUnsubscribeGroups,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        SuppressionsUnsubscribeGroups { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::suppressions_unsubscribe_groups::SuppressionsUnsubscribeGroups::get_asm_groups`].
+    pub fn get_asm_groups(
+        &self,
+        id: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::SuppressionGroup>>> {
+        self.runtime.block_on(self.inner.get_asm_groups(id))
+    }
+    /// The blocking version of [`crate::suppressions_unsubscribe_groups::SuppressionsUnsubscribeGroups::get_asm_groups_request`].
+    pub fn get_asm_groups_request(&self) -> SuppressionsUnsubscribeGroupsGetAsmGroupsRequest<'_> {
+        SuppressionsUnsubscribeGroupsGetAsmGroupsRequest {
+            inner: self.inner.get_asm_groups_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::suppressions_unsubscribe_groups::SuppressionsUnsubscribeGroups::get_all_asm_groups`].
+    pub fn get_all_asm_groups(
+        &self,
+        id: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::SuppressionGroup>>> {
+        self.runtime.block_on(self.inner.get_all_asm_groups(id))
+    }
+    /// The blocking version of [`crate::suppressions_unsubscribe_groups::SuppressionsUnsubscribeGroups::post_asm_group`].
+    pub fn post_asm_group(
+        &self,
+        body: &crate::types::SuppressionGroupRequestBase,
+    ) -> ClientResult<crate::Response<crate::types::PostAsmGroupsResponse>> {
+        self.runtime.block_on(self.inner.post_asm_group(body))
+    }
+    /// The blocking version of [`crate::suppressions_unsubscribe_groups::SuppressionsUnsubscribeGroups::get_asm_groups_group`].
+    pub fn get_asm_groups_group(
+        &self,
+        group_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::GetAsmGroupsGroupResponseAllOf>> {
+        self.runtime
+            .block_on(self.inner.get_asm_groups_group(group_id))
+    }
+    /// The blocking version of [`crate::suppressions_unsubscribe_groups::SuppressionsUnsubscribeGroups::delete_asm_groups_group`].
+    pub fn delete_asm_groups_group(
+        &self,
+        group_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Help>> {
+        self.runtime
+            .block_on(self.inner.delete_asm_groups_group(group_id))
+    }
+    /// The blocking version of [`crate::suppressions_unsubscribe_groups::SuppressionsUnsubscribeGroups::patch_asm_groups_group`].
+    pub fn patch_asm_groups_group(
+        &self,
+        group_id: &str,
+        body: &crate::types::SuppressionGroupRequestBase,
+    ) -> ClientResult<crate::Response<crate::types::SuppressionGroup>> {
+        self.runtime
+            .block_on(self.inner.patch_asm_groups_group(group_id, body))
+    }
+}
@@ -0,0 +1,175 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Teammates::get`], set with a builder.
+pub struct TeammatesGetRequest<'a> {
+    inner: crate::teammates::TeammatesGetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> TeammatesGetRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: u64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: u64) -> Self {
+        self.inner = self.inner.offset(offset);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::GetTeammatesResponse>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Teammates::get_scopes_requests`], set with a builder.
+pub struct TeammatesGetScopesRequestsRequest<'a> {
+    inner: crate::teammates::TeammatesGetScopesRequestsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> TeammatesGetScopesRequestsRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.inner = self.inner.offset(offset);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(
+        self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GetScopesRequestsResponse>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::teammates::Teammates`].
+pub struct Teammates {
+    inner: crate::teammates::Teammates,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Teammates {
+    pub(crate) fn new(
+        inner: crate::teammates::Teammates,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Teammates { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::teammates::Teammates::get`].
+    pub fn get(
+        &self,
+        limit: u64,
+        offset: u64,
+    ) -> ClientResult<crate::Response<crate::types::GetTeammatesResponse>> {
+        self.runtime.block_on(self.inner.get(limit, offset))
+    }
+    /// The blocking version of [`crate::teammates::Teammates::get_request`].
+    pub fn get_request(&self) -> TeammatesGetRequest<'_> {
+        TeammatesGetRequest {
+            inner: self.inner.get_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::teammates::Teammates::post`].
+    pub fn post(
+        &self,
+        body: &crate::types::PostTeammatesRequest,
+    ) -> ClientResult<crate::Response<crate::types::PostTeammatesResponse>> {
+        self.runtime.block_on(self.inner.post(body))
+    }
+    /// The blocking version of [`crate::teammates::Teammates::post_pending_token_resend`].
+    pub fn post_pending_token_resend(
+        &self,
+        token: &str,
+    ) -> ClientResult<crate::Response<crate::types::PostTeammatesResponse>> {
+        self.runtime
+            .block_on(self.inner.post_pending_token_resend(token))
+    }
+    /// The blocking version of [`crate::teammates::Teammates::get_scopes_requests`].
+    pub fn get_scopes_requests(
+        &self,
+        limit: i64,
+        offset: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GetScopesRequestsResponse>>> {
+        self.runtime
+            .block_on(self.inner.get_scopes_requests(limit, offset))
+    }
+    /// The blocking version of [`crate::teammates::Teammates::get_scopes_requests_request`].
+    pub fn get_scopes_requests_request(&self) -> TeammatesGetScopesRequestsRequest<'_> {
+        TeammatesGetScopesRequestsRequest {
+            inner: self.inner.get_scopes_requests_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::teammates::Teammates::get_all_scopes_requests`].
+    pub fn get_all_scopes_requests(
+        &self,
+        offset: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GetScopesRequestsResponse>>> {
+        self.runtime
+            .block_on(self.inner.get_all_scopes_requests(offset))
+    }
+    /// The blocking version of [`crate::teammates::Teammates::get_pending`].
+    pub fn get_pending(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::GetTeammatesPendingResponse>> {
+        self.runtime.block_on(self.inner.get_pending())
+    }
+    /// The blocking version of [`crate::teammates::Teammates::get_username`].
+    pub fn get_username(
+        &self,
+        username: &str,
+    ) -> ClientResult<crate::Response<crate::types::GetTeammatesUsernameResponse>> {
+        self.runtime.block_on(self.inner.get_username(username))
+    }
+    /// The blocking version of [`crate::teammates::Teammates::delete_username`].
+    pub fn delete_username(
+        &self,
+        username: &str,
+    ) -> ClientResult<crate::Response<crate::types::PostSendersResponse>> {
+        self.runtime.block_on(self.inner.delete_username(username))
+    }
+    /// The blocking version of [`crate::teammates::Teammates::patch_username`].
+    pub fn patch_username(
+        &self,
+        username: &str,
+        body: &crate::types::PatchTeammatesUsernameRequest,
+    ) -> ClientResult<crate::Response<crate::types::GetTeammatesUsernameResponse>> {
+        self.runtime
+            .block_on(self.inner.patch_username(username, body))
+    }
+    /// The blocking version of [`crate::teammates::Teammates::patch_scopes_requests_approve`].
+    pub fn patch_scopes_requests_approve(
+        &self,
+        request_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::PatchScopesRequestsApproveResponse>> {
+        self.runtime
+            .block_on(self.inner.patch_scopes_requests_approve(request_id))
+    }
+    /// The blocking version of [`crate::teammates::Teammates::delete_scopes_requests_request`].
+    pub fn delete_scopes_requests_request(
+        &self,
+        request_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete_scopes_requests_request(request_id))
+    }
+    /// The blocking version of [`crate::teammates::Teammates::delete_pending_token`].
+    pub fn delete_pending_token(&self, token: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete_pending_token(token))
+    }
+}
@@ -0,0 +1,105 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`TransactionalTemplates::get_templates`], set with a builder.
+pub struct TransactionalTemplatesGetTemplatesRequest<'a> {
+    inner: crate::transactional_templates::TransactionalTemplatesGetTemplatesRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> TransactionalTemplatesGetTemplatesRequest<'a> {
+    /// Sets the `generations` query parameter.
+    pub fn generations(mut self, generations: crate::types::Generations) -> Self {
+        self.inner = self.inner.generations(generations);
+        self
+    }
+
+    /// Sets the `page_token` query parameter.
+    pub fn page_token(mut self, page_token: &'a str) -> Self {
+        self.inner = self.inner.page_token(page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::GetTemplatesResponse>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::transactional_templates::TransactionalTemplates`].
+pub struct TransactionalTemplates {
+    inner: crate::transactional_templates::TransactionalTemplates,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl TransactionalTemplates {
+    pub(crate) fn new(
+        inner: crate::transactional_templates::TransactionalTemplates,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        TransactionalTemplates { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::transactional_templates::TransactionalTemplates::get_templates`].
+    pub fn get_templates(
+        &self,
+        generations: crate::types::Generations,
+        page_size: f64,
+        page_token: &str,
+    ) -> ClientResult<crate::Response<crate::types::GetTemplatesResponse>> {
+        self.runtime
+            .block_on(self.inner.get_templates(generations, page_size, page_token))
+    }
+    /// The blocking version of [`crate::transactional_templates::TransactionalTemplates::get_templates_request`].
+    pub fn get_templates_request(
+        &self,
+        page_size: f64,
+    ) -> TransactionalTemplatesGetTemplatesRequest<'_> {
+        TransactionalTemplatesGetTemplatesRequest {
+            inner: self.inner.get_templates_request(page_size),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::transactional_templates::TransactionalTemplates::post_template`].
+    pub fn post_template(
+        &self,
+        body: &crate::types::PostTemplatesRequest,
+    ) -> ClientResult<crate::Response<crate::types::TransactionalTemplateAllOf>> {
+        self.runtime.block_on(self.inner.post_template(body))
+    }
+    /// The blocking version of [`crate::transactional_templates::TransactionalTemplates::get_templates_template`].
+    pub fn get_templates_template(
+        &self,
+        template_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::TransactionalTemplateAllOf>> {
+        self.runtime
+            .block_on(self.inner.get_templates_template(template_id))
+    }
+    /// The blocking version of [`crate::transactional_templates::TransactionalTemplates::post_templates_template`].
+    pub fn post_templates_template(
+        &self,
+        template_id: &str,
+        body: &crate::types::PostTemplatesTemplateRequest,
+    ) -> ClientResult<crate::Response<crate::types::TransactionalTemplateAllOf>> {
+        self.runtime
+            .block_on(self.inner.post_templates_template(template_id, body))
+    }
+    /// The blocking version of [`crate::transactional_templates::TransactionalTemplates::delete_templates_template`].
+    pub fn delete_templates_template(
+        &self,
+        template_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Help>> {
+        self.runtime
+            .block_on(self.inner.delete_templates_template(template_id))
+    }
+    /// The blocking version of [`crate::transactional_templates::TransactionalTemplates::patch_templates_template`].
+    pub fn patch_templates_template(
+        &self,
+        template_id: &str,
+        body: &crate::types::PatchTemplatesTemplateRequest,
+    ) -> ClientResult<crate::Response<crate::types::TransactionalTemplateAllOf>> {
+        self.runtime
+            .block_on(self.inner.patch_templates_template(template_id, body))
+    }
+}
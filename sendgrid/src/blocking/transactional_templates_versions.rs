@@ -0,0 +1,77 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::transactional_templates_versions::TransactionalTemplatesVersions`].
+pub struct TransactionalTemplatesVersions {
+    inner: crate::transactional_templates_versions::TransactionalTemplatesVersions,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl TransactionalTemplatesVersions {
+    pub(crate) fn new(
+        inner: crate::transactional_templates_versions::TransactionalTemplatesVersions,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        TransactionalTemplatesVersions { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::transactional_templates_versions::TransactionalTemplatesVersions::post_templates_template_version`].
+    pub fn post_templates_template_version(
+        &self,
+        template_id: &str,
+        body: &crate::types::TransactionalTemplateVersionCreate,
+    ) -> ClientResult<crate::Response<crate::types::TransactionalTemplateVersionOutputAllOf>> {
+        self.runtime.block_on(
+            self.inner
+                .post_templates_template_version(template_id, body),
+        )
+    }
+    /// The blocking version of [`crate::transactional_templates_versions::TransactionalTemplatesVersions::post_templates_template_versions_version_activate`].
+    pub fn post_templates_template_versions_version_activate(
+        &self,
+        template_id: &str,
+        version_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::TransactionalTemplateVersionOutputAllOf>> {
+        self.runtime.block_on(
+            self.inner
+                .post_templates_template_versions_version_activate(template_id, version_id),
+        )
+    }
+    /// The blocking version of [`crate::transactional_templates_versions::TransactionalTemplatesVersions::get_templates_template_versions_version`].
+    pub fn get_templates_template_versions_version(
+        &self,
+        template_id: &str,
+        version_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::TransactionalTemplateVersionOutputAllOf>> {
+        self.runtime.block_on(
+            self.inner
+                .get_templates_template_versions_version(template_id, version_id),
+        )
+    }
+    /// The blocking version of [`crate::transactional_templates_versions::TransactionalTemplatesVersions::delete_templates_template_versions_version`].
+    pub fn delete_templates_template_versions_version(
+        &self,
+        template_id: &str,
+        version_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .delete_templates_template_versions_version(template_id, version_id),
+        )
+    }
+    /// The blocking version of [`crate::transactional_templates_versions::TransactionalTemplatesVersions::patch_templates_template_versions_version`].
+    pub fn patch_templates_template_versions_version(
+        &self,
+        template_id: &str,
+        version_id: &str,
+        body: &crate::types::TransactionalTemplateVersionCreate,
+    ) -> ClientResult<crate::Response<crate::types::TransactionalTemplateVersionOutputAllOf>> {
+        self.runtime
+            .block_on(self.inner.patch_templates_template_versions_version(
+                template_id,
+                version_id,
+                body,
+            ))
+    }
+}
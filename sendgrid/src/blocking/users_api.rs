@@ -0,0 +1,75 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::users_api::UsersApi`].
+pub struct UsersApi {
+    inner: crate::users_api::UsersApi,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl UsersApi {
+    pub(crate) fn new(
+        inner: crate::users_api::UsersApi,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        UsersApi { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::users_api::UsersApi::get_user_profile`].
+    pub fn get_user_profile(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::GetUserProfileResponse>> {
+        self.runtime.block_on(self.inner.get_user_profile())
+    }
+    /// The blocking version of [`crate::users_api::UsersApi::patch_user_profile`].
+    pub fn patch_user_profile(
+        &self,
+        body: &crate::types::UserProfile,
+    ) -> ClientResult<crate::Response<crate::types::UserProfile>> {
+        self.runtime.block_on(self.inner.patch_user_profile(body))
+    }
+    /// The blocking version of [`crate::users_api::UsersApi::get_user_account`].
+    pub fn get_user_account(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::GetUserAccountResponse>> {
+        self.runtime.block_on(self.inner.get_user_account())
+    }
+    /// The blocking version of [`crate::users_api::UsersApi::get_user_email`].
+    pub fn get_user_email(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::GetUserEmailResponse>> {
+        self.runtime.block_on(self.inner.get_user_email())
+    }
+    /// The blocking version of [`crate::users_api::UsersApi::put_user_email`].
+    pub fn put_user_email(
+        &self,
+        body: &crate::types::PutUserEmailRequest,
+    ) -> ClientResult<crate::Response<crate::types::GetUserEmailResponse>> {
+        self.runtime.block_on(self.inner.put_user_email(body))
+    }
+    /// The blocking version of [`crate::users_api::UsersApi::get_user_username`].
+    pub fn get_user_username(&self) -> ClientResult<crate::Response<crate::types::Users>> {
+        self.runtime.block_on(self.inner.get_user_username())
+    }
+    /// The blocking version of [`crate::users_api::UsersApi::put_user_username`].
+    pub fn put_user_username(
+        &self,
+        body: &crate::types::PutUserUsernameRequest,
+    ) -> ClientResult<crate::Response<crate::types::PutUserUsernameResponse>> {
+        self.runtime.block_on(self.inner.put_user_username(body))
+    }
+    /// The blocking version of [`crate::users_api::UsersApi::get_user_credits`].
+    pub fn get_user_credits(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::GetUserCreditsResponse>> {
+        self.runtime.block_on(self.inner.get_user_credits())
+    }
+    /// The blocking version of [`crate::users_api::UsersApi::put_user_password`].
+    pub fn put_user_password(
+        &self,
+        body: &crate::types::PutUserPasswordRequest,
+    ) -> ClientResult<crate::Response<crate::types::Help>> {
+        self.runtime.block_on(self.inner.put_user_password(body))
+    }
+}
@@ -0,0 +1,150 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Webhooks::get_user_parse_stats`], set with a builder.
+pub struct WebhooksGetUserParseStatsRequest<'a> {
+    inner: crate::webhooks::WebhooksGetUserParseStatsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> WebhooksGetUserParseStatsRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: &'a str) -> Self {
+        self.inner = self.inner.offset(offset);
+        self
+    }
+
+    /// Sets the `aggregated_by` query parameter.
+    pub fn aggregated_by(
+        mut self,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+    ) -> Self {
+        self.inner = self.inner.aggregated_by(aggregated_by);
+        self
+    }
+
+    /// Sets the `end_date` query parameter.
+    pub fn end_date(mut self, end_date: &'a str) -> Self {
+        self.inner = self.inner.end_date(end_date);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(
+        self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GetUserWebhooksParseStatsResponseData>>>
+    {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::webhooks::Webhooks`].
+pub struct Webhooks {
+    inner: crate::webhooks::Webhooks,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Webhooks {
+    pub(crate) fn new(
+        inner: crate::webhooks::Webhooks,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Webhooks { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::webhooks::Webhooks::get_user_event_settings`].
+    pub fn get_user_event_settings(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::WebhooksEventWebhookResponse>> {
+        self.runtime.block_on(self.inner.get_user_event_settings())
+    }
+    /// The blocking version of [`crate::webhooks::Webhooks::patch_user_event_settings`].
+    pub fn patch_user_event_settings(
+        &self,
+        body: &crate::types::WebhooksEventWebhookUpdateWithOAuthRequest,
+    ) -> ClientResult<crate::Response<crate::types::WebhooksEventWebhookResponse>> {
+        self.runtime
+            .block_on(self.inner.patch_user_event_settings(body))
+    }
+    /// The blocking version of [`crate::webhooks::Webhooks::get_user_parse_settings`].
+    pub fn get_user_parse_settings(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::GetUserWebhooksParseSettingsResponse>> {
+        self.runtime.block_on(self.inner.get_user_parse_settings())
+    }
+    /// The blocking version of [`crate::webhooks::Webhooks::get_user_parse_stats`].
+    pub fn get_user_parse_stats(
+        &self,
+        limit: &str,
+        offset: &str,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+        start_date: &str,
+        end_date: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GetUserWebhooksParseStatsResponseData>>>
+    {
+        self.runtime.block_on(self.inner.get_user_parse_stats(
+            limit,
+            offset,
+            aggregated_by,
+            start_date,
+            end_date,
+        ))
+    }
+    /// The blocking version of [`crate::webhooks::Webhooks::get_user_parse_stats_request`].
+    pub fn get_user_parse_stats_request<'a>(
+        &'a self,
+        start_date: &'a str,
+    ) -> WebhooksGetUserParseStatsRequest<'a> {
+        WebhooksGetUserParseStatsRequest {
+            inner: self.inner.get_user_parse_stats_request(start_date),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::webhooks::Webhooks::get_all_user_parse_stats`].
+    pub fn get_all_user_parse_stats(
+        &self,
+        offset: &str,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+        start_date: &str,
+        end_date: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GetUserWebhooksParseStatsResponseData>>>
+    {
+        self.runtime.block_on(self.inner.get_all_user_parse_stats(
+            offset,
+            aggregated_by,
+            start_date,
+            end_date,
+        ))
+    }
+    /// The blocking version of [`crate::webhooks::Webhooks::get_user_event_settings_signed`].
+    pub fn get_user_event_settings_signed(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::GetUserWebhooksEventSettingsSignedResponse>>
+    {
+        self.runtime
+            .block_on(self.inner.get_user_event_settings_signed())
+    }
+    /// The blocking version of [`crate::webhooks::Webhooks::patch_user_event_settings_signed`].
+    pub fn patch_user_event_settings_signed(
+        &self,
+        body: &crate::types::GetTrackingSettingsOpenResponse,
+    ) -> ClientResult<crate::Response<crate::types::GetUserWebhooksEventSettingsSignedResponse>>
+    {
+        self.runtime
+            .block_on(self.inner.patch_user_event_settings_signed(body))
+    }
+    /// The blocking version of [`crate::webhooks::Webhooks::post_user_event_test`].
+    pub fn post_user_event_test(
+        &self,
+        body: &crate::types::PostUserWebhooksEventTestRequest,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.post_user_event_test(body))
+    }
+}
@@ -1,6 +1,58 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`BouncesApi::get_suppression_bounces`], set with a builder.
+pub struct BouncesApiGetSuppressionBouncesRequest<'a> {
+    client: &'a BouncesApi,
+    start_time: Option<i64>,
+    end_time: Option<i64>,
+}
+
+impl<'a> BouncesApiGetSuppressionBouncesRequest<'a> {
+    /// Sets the `start_time` query parameter.
+    pub fn start_time(mut self, start_time: i64) -> Self {
+        self.start_time = Some(start_time);
+        self
+    }
+
+    /// Sets the `end_time` query parameter.
+    pub fn end_time(mut self, end_time: i64) -> Self {
+        self.end_time = Some(end_time);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::BounceResponse>>> {
+        let Self {
+            client,
+            start_time,
+            end_time,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = end_time {
+            query_args.push(("end_time".to_string(), v.to_string()));
+        }
+        if let Some(v) = start_time {
+            query_args.push(("start_time".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/suppression/bounces?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct BouncesApi {
     pub client: Client,
 }
@@ -51,6 +103,22 @@ impl BouncesApi {
             )
             .await
     }
+    /**
+     * Retrieve all bounces.
+     *
+     * This function performs a `GET` to the `/suppression/bounces` endpoint.
+     *
+     * As opposed to `get_suppression_bounces`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * **This endpoint allows you to retrieve all of your bounces.**
+     */
+    pub fn get_suppression_bounces_request(&self) -> BouncesApiGetSuppressionBouncesRequest<'_> {
+        BouncesApiGetSuppressionBouncesRequest {
+            client: self,
+            start_time: None,
+            end_time: None,
+        }
+    }
     /**
      * Retrieve all bounces.
      *
@@ -86,6 +154,33 @@ impl BouncesApi {
             )
             .await
     }
+    /**
+     * Retrieve all bounces.
+     *
+     * This function performs a `GET` to the `/suppression/bounces` endpoint.
+     *
+     * As opposed to `get_all_suppression_bounces`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * **This endpoint allows you to retrieve all of your bounces.**
+     */
+    pub fn get_all_suppression_bounces_stream(
+        &self,
+        start_time: i64,
+        end_time: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::BounceResponse>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if end_time > 0 {
+            query_args.push(("end_time".to_string(), end_time.to_string()));
+        }
+        if start_time > 0 {
+            query_args.push(("start_time".to_string(), start_time.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/suppression/bounces?{}", query_), None);
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Delete bounces.
      *
@@ -179,6 +274,28 @@ impl BouncesApi {
             )
             .await
     }
+    /**
+     * Retrieve a Bounce.
+     *
+     * This function performs a `GET` to the `/suppression/bounces/{email}` endpoint.
+     *
+     * As opposed to `get_all_suppression_bounces_email`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * **This endpoint allows you to retrieve a specific bounce by email address.**
+     */
+    pub fn get_all_suppression_bounces_email_stream(
+        &self,
+        email: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::BounceResponse>> + Unpin + '_ {
+        let url = self.client.url(
+            &format!(
+                "/suppression/bounces/{}",
+                crate::progenitor_support::encode_path(email),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Delete a bounce.
      *
@@ -1,6 +1,56 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`CampaignsApi::get_campaigns`], set with a builder.
+pub struct CampaignsApiGetCampaignsRequest<'a> {
+    client: &'a CampaignsApi,
+    limit: Option<i64>,
+    offset: Option<i64>,
+}
+
+impl<'a> CampaignsApiGetCampaignsRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::GetCampaignsResponse>> {
+        let Self {
+            client,
+            limit,
+            offset,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = offset {
+            query_args.push(("offset".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(&format!("/campaigns?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct CampaignsApi {
     pub client: Client,
 }
@@ -52,6 +102,26 @@ impl CampaignsApi {
             )
             .await
     }
+    /**
+     * Retrieve all Campaigns.
+     *
+     * This function performs a `GET` to the `/campaigns` endpoint.
+     *
+     * As opposed to `get_campaigns`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * **This endpoint allows you to retrieve a list of all of your campaigns.**
+     *
+     * Returns campaigns in reverse order they were created (newest first).
+     *
+     * Returns an empty array if no campaigns exist.
+     */
+    pub fn get_campaigns_request(&self) -> CampaignsApiGetCampaignsRequest<'_> {
+        CampaignsApiGetCampaignsRequest {
+            client: self,
+            limit: None,
+            offset: None,
+        }
+    }
     /**
      * Create a Campaign.
      *
@@ -92,6 +92,24 @@ impl CancelScheduledSends {
             )
             .await
     }
+    /**
+     * Retrieve all scheduled sends.
+     *
+     * This function performs a `GET` to the `/user/scheduled_sends` endpoint.
+     *
+     * As opposed to `get_all_user_scheduled_sends`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * **This endpoint allows you to retrieve all cancelled and paused scheduled send information.**
+     *
+     * This endpoint will return only the scheduled sends that are associated with a `batch_id`. If you have scheduled a send using the `/mail/send` endpoint and the `send_at` field but no `batch_id`, the send will be scheduled for delivery; however, it will not be returned by this endpoint. For this reason, you should assign a `batch_id` to any scheduled send you may need to pause or cancel in the future.
+     */
+    pub fn get_all_user_scheduled_sends_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::UserScheduledSendStatusAllOf>> + Unpin + '_
+    {
+        let url = self.client.url("/user/scheduled_sends", None);
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Cancel or pause a scheduled send.
      *
@@ -224,6 +242,29 @@ impl CancelScheduledSends {
             )
             .await
     }
+    /**
+     * Retrieve scheduled send.
+     *
+     * This function performs a `GET` to the `/user/scheduled_sends/{batch_id}` endpoint.
+     *
+     * As opposed to `get_all_user_scheduled_sends_batch`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * **This endpoint allows you to retrieve the cancel/paused scheduled send information for a specific `batch_id`.**
+     */
+    pub fn get_all_user_scheduled_sends_batch_stream(
+        &self,
+        batch_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::UserScheduledSendStatusAllOf>> + Unpin + '_
+    {
+        let url = self.client.url(
+            &format!(
+                "/user/scheduled_sends/{}",
+                crate::progenitor_support::encode_path(batch_id),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Delete a cancellation or pause from a scheduled send.
      *
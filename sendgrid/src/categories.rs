@@ -1,6 +1,260 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Categories::get_page`], set with a builder.
+pub struct CategoriesGetPageRequest<'a> {
+    client: &'a Categories,
+    limit: Option<i64>,
+    category: Option<&'a str>,
+    offset: Option<i64>,
+}
+
+impl<'a> CategoriesGetPageRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `category` query parameter.
+    pub fn category(mut self, category: &'a str) -> Self {
+        self.category = Some(category);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GetCategoriesResponse>>> {
+        let Self {
+            client,
+            limit,
+            category,
+            offset,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = category {
+            query_args.push(("category".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = offset {
+            query_args.push(("offset".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(&format!("/categories?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Categories::get_stats_sum`], set with a builder.
+pub struct CategoriesGetStatsSumRequest<'a> {
+    client: &'a Categories,
+    sort_by_metric: Option<&'a str>,
+    sort_by_direction: Option<crate::types::SortByDirection>,
+    start_date: &'a str,
+    end_date: Option<&'a str>,
+    limit: Option<i64>,
+    offset: Option<i64>,
+    aggregated_by: Option<crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy>,
+}
+
+impl<'a> CategoriesGetStatsSumRequest<'a> {
+    /// Sets the `sort_by_metric` query parameter.
+    pub fn sort_by_metric(mut self, sort_by_metric: &'a str) -> Self {
+        self.sort_by_metric = Some(sort_by_metric);
+        self
+    }
+
+    /// Sets the `sort_by_direction` query parameter.
+    pub fn sort_by_direction(mut self, sort_by_direction: crate::types::SortByDirection) -> Self {
+        self.sort_by_direction = Some(sort_by_direction);
+        self
+    }
+
+    /// Sets the `end_date` query parameter.
+    pub fn end_date(mut self, end_date: &'a str) -> Self {
+        self.end_date = Some(end_date);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Sets the `aggregated_by` query parameter.
+    pub fn aggregated_by(
+        mut self,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+    ) -> Self {
+        self.aggregated_by = Some(aggregated_by);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::CategoryStats>> {
+        let Self {
+            client,
+            sort_by_metric,
+            sort_by_direction,
+            start_date,
+            end_date,
+            limit,
+            offset,
+            aggregated_by,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = aggregated_by {
+            query_args.push(("aggregated_by".to_string(), v.to_string()));
+        }
+        if let Some(v) = end_date {
+            query_args.push(("end_date".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = offset {
+            query_args.push(("offset".to_string(), v.to_string()));
+        }
+        if let Some(v) = sort_by_direction {
+            query_args.push(("sort_by_direction".to_string(), v.to_string()));
+        }
+        if let Some(v) = sort_by_metric {
+            query_args.push(("sort_by_metric".to_string(), v.to_string()));
+        }
+        if !start_date.is_empty() {
+            query_args.push(("start_date".to_string(), start_date.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/categories/stats/sums?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Categories::get_stats`], set with a builder.
+pub struct CategoriesGetStatsRequest<'a> {
+    client: &'a Categories,
+    start_date: &'a str,
+    end_date: Option<&'a str>,
+    categories: &'a str,
+    limit: Option<i64>,
+    offset: Option<i64>,
+    aggregated_by: Option<crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy>,
+}
+
+impl<'a> CategoriesGetStatsRequest<'a> {
+    /// Sets the `end_date` query parameter.
+    pub fn end_date(mut self, end_date: &'a str) -> Self {
+        self.end_date = Some(end_date);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Sets the `aggregated_by` query parameter.
+    pub fn aggregated_by(
+        mut self,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+    ) -> Self {
+        self.aggregated_by = Some(aggregated_by);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::CategoryStats>>> {
+        let Self {
+            client,
+            start_date,
+            end_date,
+            categories,
+            limit,
+            offset,
+            aggregated_by,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = aggregated_by {
+            query_args.push(("aggregated_by".to_string(), v.to_string()));
+        }
+        if !categories.is_empty() {
+            query_args.push(("categories".to_string(), categories.to_string()));
+        }
+        if let Some(v) = end_date {
+            query_args.push(("end_date".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = offset {
+            query_args.push(("offset".to_string(), v.to_string()));
+        }
+        if !start_date.is_empty() {
+            query_args.push(("start_date".to_string(), start_date.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/categories/stats?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct Categories {
     pub client: Client,
 }
@@ -53,6 +307,23 @@ impl Categories {
             )
             .await
     }
+    /**
+     * Retrieve all categories.
+     *
+     * This function performs a `GET` to the `/categories` endpoint.
+     *
+     * As opposed to `get_page`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * **This endpoint allows you to retrieve a list of all of your categories.**
+     */
+    pub fn get_page_request(&self) -> CategoriesGetPageRequest<'_> {
+        CategoriesGetPageRequest {
+            client: self,
+            limit: None,
+            category: None,
+            offset: None,
+        }
+    }
     /**
      * Retrieve all categories.
      *
@@ -86,6 +357,32 @@ impl Categories {
             )
             .await
     }
+    /**
+     * Retrieve all categories.
+     *
+     * This function performs a `GET` to the `/categories` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * **This endpoint allows you to retrieve a list of all of your categories.**
+     */
+    pub fn get_all_stream(
+        &self,
+        category: &str,
+        offset: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::GetCategoriesResponse>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !category.is_empty() {
+            query_args.push(("category".to_string(), category.to_string()));
+        }
+        if offset > 0 {
+            query_args.push(("offset".to_string(), offset.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/categories?{}", query_), None);
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Retrieve sums of email stats for each category [Needs: Stats object defined, has category ID?].
      *
@@ -155,6 +452,32 @@ impl Categories {
             )
             .await
     }
+    /**
+     * Retrieve sums of email stats for each category [Needs: Stats object defined, has category ID?].
+     *
+     * This function performs a `GET` to the `/categories/stats/sums` endpoint.
+     *
+     * As opposed to `get_stats_sum`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * **This endpoint allows you to retrieve the total sum of each email statistic for every category over the given date range.**
+     *
+     * If you do not define any query parameters, this endpoint will return a sum for each category in groups of 10.
+     */
+    pub fn get_stats_sum_request<'a>(
+        &'a self,
+        start_date: &'a str,
+    ) -> CategoriesGetStatsSumRequest<'a> {
+        CategoriesGetStatsSumRequest {
+            client: self,
+            sort_by_metric: None,
+            sort_by_direction: None,
+            start_date,
+            end_date: None,
+            limit: None,
+            offset: None,
+            aggregated_by: None,
+        }
+    }
     /**
      * Retrieve Email Statistics for Categories.
      *
@@ -216,6 +539,32 @@ impl Categories {
             )
             .await
     }
+    /**
+     * Retrieve Email Statistics for Categories.
+     *
+     * This function performs a `GET` to the `/categories/stats` endpoint.
+     *
+     * As opposed to `get_stats`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * **This endpoint allows you to retrieve all of your email statistics for each of your categories.**
+     *
+     * If you do not define any query parameters, this endpoint will return a sum for each category in groups of 10.
+     */
+    pub fn get_stats_request<'a>(
+        &'a self,
+        start_date: &'a str,
+        categories: &'a str,
+    ) -> CategoriesGetStatsRequest<'a> {
+        CategoriesGetStatsRequest {
+            client: self,
+            start_date,
+            end_date: None,
+            categories,
+            limit: None,
+            offset: None,
+            aggregated_by: None,
+        }
+    }
     /**
      * Retrieve Email Statistics for Categories.
      *
@@ -265,4 +614,45 @@ impl Categories {
             )
             .await
     }
+    /**
+     * Retrieve Email Statistics for Categories.
+     *
+     * This function performs a `GET` to the `/categories/stats` endpoint.
+     *
+     * As opposed to `get_all_stats`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * **This endpoint allows you to retrieve all of your email statistics for each of your categories.**
+     *
+     * If you do not define any query parameters, this endpoint will return a sum for each category in groups of 10.
+     */
+    pub fn get_all_stats_stream(
+        &self,
+        start_date: &str,
+        end_date: &str,
+        categories: &str,
+        offset: i64,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::CategoryStats>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !aggregated_by.to_string().is_empty() {
+            query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
+        }
+        if !categories.is_empty() {
+            query_args.push(("categories".to_string(), categories.to_string()));
+        }
+        if !end_date.is_empty() {
+            query_args.push(("end_date".to_string(), end_date.to_string()));
+        }
+        if offset > 0 {
+            query_args.push(("offset".to_string(), offset.to_string()));
+        }
+        if !start_date.is_empty() {
+            query_args.push(("start_date".to_string(), start_date.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/categories/stats?{}", query_), None);
+        self.client.get_all_pages_stream(url)
+    }
 }
@@ -95,6 +95,31 @@ impl Certificates {
             )
             .await
     }
+    /**
+     * Get All SSO Certificates by Integration.
+     *
+     * This function performs a `GET` to the `/sso/integrations/{integration_id}/certificates` endpoint.
+     *
+     * As opposed to `get_all_sso_integrations_integration`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * **This endpoint allows you to retrieve all your IdP configurations by configuration ID.**
+     *
+     * The `integration_id` expected by this endpoint is the `id` returned in the response by the "Get All SSO Integrations" endpoint.
+     */
+    pub fn get_all_sso_integrations_integration_stream(
+        &self,
+        integration_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::SsoCertificateBody>> + Unpin + '_
+    {
+        let url = self.client.url(
+            &format!(
+                "/sso/integrations/{}/certificates",
+                crate::progenitor_support::encode_path(integration_id),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Get an SSO Certificate.
      *
@@ -1,6 +1,60 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Contacts::delete_mc`], set with a builder.
+pub struct ContactsDeleteMcRequest<'a> {
+    client: &'a Contacts,
+    delete_all_contacts: Option<&'a str>,
+    ids: Option<&'a str>,
+}
+
+impl<'a> ContactsDeleteMcRequest<'a> {
+    /// Sets the `delete_all_contacts` query parameter.
+    pub fn delete_all_contacts(mut self, delete_all_contacts: &'a str) -> Self {
+        self.delete_all_contacts = Some(delete_all_contacts);
+        self
+    }
+
+    /// Sets the `ids` query parameter.
+    pub fn ids(mut self, ids: &'a str) -> Self {
+        self.ids = Some(ids);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::DeleteMcContactsResponse>> {
+        let Self {
+            client,
+            delete_all_contacts,
+            ids,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = delete_all_contacts {
+            query_args.push(("delete_all_contacts".to_string(), v.to_string()));
+        }
+        if let Some(v) = ids {
+            query_args.push(("ids".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/marketing/contacts?{}", query_), None);
+
+        client
+            .client
+            .delete(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct Contacts {
     pub client: Client,
 }
@@ -119,6 +173,30 @@ impl Contacts {
             )
             .await
     }
+    /**
+     * Delete Contacts.
+     *
+     * This function performs a `DELETE` to the `/marketing/contacts` endpoint.
+     *
+     * As opposed to `delete_mc`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * **This endpoint can be used to delete one or more contacts**.
+     *
+     * The query parameter `ids` must set to a comma-separated list of contact IDs for bulk contact deletion.
+     *
+     * The query parameter `delete_all_contacts` must be set to `"true"` to delete **all** contacts.
+     *
+     * You must set either `ids` or `delete_all_contacts`.
+     *
+     * Deletion jobs are processed asynchronously.
+     */
+    pub fn delete_mc_request(&self) -> ContactsDeleteMcRequest<'_> {
+        ContactsDeleteMcRequest {
+            client: self,
+            delete_all_contacts: None,
+            ids: None,
+        }
+    }
     /**
      * Get Total Contact Count.
      *
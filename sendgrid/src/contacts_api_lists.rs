@@ -1,6 +1,117 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`ContactsApiLists::delete_contactdb_lists_list`], set with a builder.
+pub struct ContactsApiListsDeleteContactdbListsListRequest<'a> {
+    client: &'a ContactsApiLists,
+    list_id: &'a str,
+    delete_contacts: Option<bool>,
+    body: &'a serde_json::Value,
+}
+
+impl<'a> ContactsApiListsDeleteContactdbListsListRequest<'a> {
+    /// Sets the `delete_contacts` query parameter.
+    pub fn delete_contacts(mut self, delete_contacts: bool) -> Self {
+        self.delete_contacts = Some(delete_contacts);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            list_id,
+            delete_contacts,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = delete_contacts {
+            query_args.push(("delete_contacts".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/contactdb/lists/{}?{}",
+                crate::progenitor_support::encode_path(list_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .delete(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`ContactsApiLists::get_contactdb_lists_list_recipients`], set with a builder.
+pub struct ContactsApiListsGetContactdbListsListRecipientsRequest<'a> {
+    client: &'a ContactsApiLists,
+    list_id: i64,
+    page: Option<i64>,
+    page_size: Option<i64>,
+}
+
+impl<'a> ContactsApiListsGetContactdbListsListRecipientsRequest<'a> {
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::GetContactdbRecipientsSearchResponse>> {
+        let Self {
+            client,
+            list_id,
+            page,
+            page_size,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_size {
+            query_args.push(("page_size".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/contactdb/lists/{}/recipients?{}",
+                crate::progenitor_support::encode_path(&list_id.to_string()),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct ContactsApiLists {
     pub client: Client,
 }
@@ -159,6 +270,27 @@ impl ContactsApiLists {
             )
             .await
     }
+    /**
+     * Delete a List.
+     *
+     * This function performs a `DELETE` to the `/contactdb/lists/{list_id}` endpoint.
+     *
+     * As opposed to `delete_contactdb_lists_list`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * **This endpoint allows you to delete a specific recipient list with the given ID.**
+     */
+    pub fn delete_contactdb_lists_list_request<'a>(
+        &'a self,
+        list_id: &'a str,
+        body: &'a serde_json::Value,
+    ) -> ContactsApiListsDeleteContactdbListsListRequest<'a> {
+        ContactsApiListsDeleteContactdbListsListRequest {
+            client: self,
+            list_id,
+            delete_contacts: None,
+            body,
+        }
+    }
     /**
      * Update a List.
      *
@@ -239,6 +371,26 @@ impl ContactsApiLists {
             )
             .await
     }
+    /**
+     * Retrieve all recipients on a List.
+     *
+     * This function performs a `GET` to the `/contactdb/lists/{list_id}/recipients` endpoint.
+     *
+     * As opposed to `get_contactdb_lists_list_recipients`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * **This endpoint allows you to retrieve all recipients on the list with the given ID.**
+     */
+    pub fn get_contactdb_lists_list_recipients_request(
+        &self,
+        list_id: i64,
+    ) -> ContactsApiListsGetContactdbListsListRecipientsRequest<'_> {
+        ContactsApiListsGetContactdbListsListRecipientsRequest {
+            client: self,
+            list_id,
+            page: None,
+            page_size: None,
+        }
+    }
     /**
      * Add Multiple Recipients to a List.
      *
@@ -1,6 +1,98 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`ContactsApiRecipients::get_contactdb_recipients`], set with a builder.
+pub struct ContactsApiRecipientsGetContactdbRecipientsRequest<'a> {
+    client: &'a ContactsApiRecipients,
+    page: Option<i64>,
+    page_size: Option<i64>,
+}
+
+impl<'a> ContactsApiRecipientsGetContactdbRecipientsRequest<'a> {
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::ListRecipientsResponse>> {
+        let Self {
+            client,
+            page,
+            page_size,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_size {
+            query_args.push(("page_size".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/contactdb/recipients?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`ContactsApiRecipients::get_contactdb_recipients_search`], set with a builder.
+pub struct ContactsApiRecipientsGetContactdbRecipientsSearchRequest<'a> {
+    client: &'a ContactsApiRecipients,
+    field_name: Option<&'a str>,
+}
+
+impl<'a> ContactsApiRecipientsGetContactdbRecipientsSearchRequest<'a> {
+    /// Sets the `{field_name}` query parameter.
+    pub fn field_name(mut self, field_name: &'a str) -> Self {
+        self.field_name = Some(field_name);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::GetContactdbRecipientsSearchResponse>> {
+        let Self { client, field_name } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = field_name {
+            query_args.push(("{field_name}".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/contactdb/recipients/search?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct ContactsApiRecipients {
     pub client: Client,
 }
@@ -53,6 +145,27 @@ impl ContactsApiRecipients {
             )
             .await
     }
+    /**
+     * Retrieve recipients.
+     *
+     * This function performs a `GET` to the `/contactdb/recipients` endpoint.
+     *
+     * As opposed to `get_contactdb_recipients`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * **This endpoint allows you to retrieve all of your Marketing Campaigns recipients.**
+     *
+     * Batch deletion of a page makes it possible to receive an empty page of recipients before reaching the end of
+     * the list of recipients. To avoid this issue; iterate over pages until a 404 is retrieved.
+     */
+    pub fn get_contactdb_recipients_request(
+        &self,
+    ) -> ContactsApiRecipientsGetContactdbRecipientsRequest<'_> {
+        ContactsApiRecipientsGetContactdbRecipientsRequest {
+            client: self,
+            page: None,
+            page_size: None,
+        }
+    }
     /**
      * Add recipients.
      *
@@ -364,6 +477,32 @@ impl ContactsApiRecipients {
             )
             .await
     }
+    /**
+     * Search recipients.
+     *
+     * This function performs a `GET` to the `/contactdb/recipients/search` endpoint.
+     *
+     * As opposed to `get_contactdb_recipients_search`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * **This endpoint allows you to perform a search on all of your Marketing Campaigns recipients.**
+     *
+     * field_name:
+     *
+     * * is a variable that is substituted for your actual custom field name from your recipient.
+     * * Text fields must be url-encoded. Date fields are searchable only by unix timestamp (e.g. 2/2/2015 becomes 1422835200)
+     * * If field_name is a 'reserved' date field, such as created_at or updated_at, the system will internally convert
+     * your epoch time to a date range encompassing the entire day. For example, an epoch time of 1422835600 converts to
+     * Mon, 02 Feb 2015 00:06:40 GMT, but internally the system will search from Mon, 02 Feb 2015 00:00:00 GMT through
+     * Mon, 02 Feb 2015 23:59:59 GMT.
+     */
+    pub fn get_contactdb_recipients_search_request(
+        &self,
+    ) -> ContactsApiRecipientsGetContactdbRecipientsSearchRequest<'_> {
+        ContactsApiRecipientsGetContactdbRecipientsSearchRequest {
+            client: self,
+            field_name: None,
+        }
+    }
     /**
      * Search recipients.
      *
@@ -1,6 +1,117 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`ContactsApiSegments::delete_contactdb_segments_segment`], set with a builder.
+pub struct ContactsApiSegmentsDeleteContactdbSegmentsSegmentRequest<'a> {
+    client: &'a ContactsApiSegments,
+    segment_id: &'a str,
+    delete_contacts: Option<bool>,
+    body: &'a serde_json::Value,
+}
+
+impl<'a> ContactsApiSegmentsDeleteContactdbSegmentsSegmentRequest<'a> {
+    /// Sets the `delete_contacts` query parameter.
+    pub fn delete_contacts(mut self, delete_contacts: bool) -> Self {
+        self.delete_contacts = Some(delete_contacts);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            segment_id,
+            delete_contacts,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = delete_contacts {
+            query_args.push(("delete_contacts".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/contactdb/segments/{}?{}",
+                crate::progenitor_support::encode_path(segment_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .delete(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`ContactsApiSegments::get_contactdb_segments_segment_recipients`], set with a builder.
+pub struct ContactsApiSegmentsGetContactdbSegmentsSegmentRecipientsRequest<'a> {
+    client: &'a ContactsApiSegments,
+    segment_id: i64,
+    page: Option<i64>,
+    page_size: Option<i64>,
+}
+
+impl<'a> ContactsApiSegmentsGetContactdbSegmentsSegmentRecipientsRequest<'a> {
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::ListRecipientsOnASegmentResponse>> {
+        let Self {
+            client,
+            segment_id,
+            page,
+            page_size,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_size {
+            query_args.push(("page_size".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/contactdb/segments/{}/recipients?{}",
+                crate::progenitor_support::encode_path(&segment_id.to_string()),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct ContactsApiSegments {
     pub client: Client,
 }
@@ -177,6 +288,29 @@ impl ContactsApiSegments {
             )
             .await
     }
+    /**
+     * Delete a segment.
+     *
+     * This function performs a `DELETE` to the `/contactdb/segments/{segment_id}` endpoint.
+     *
+     * As opposed to `delete_contactdb_segments_segment`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * **This endpoint allows you to delete a segment from your recipients database.**
+     *
+     * You also have the option to delete all the contacts from your Marketing Campaigns recipient database who were in this segment.
+     */
+    pub fn delete_contactdb_segments_segment_request<'a>(
+        &'a self,
+        segment_id: &'a str,
+        body: &'a serde_json::Value,
+    ) -> ContactsApiSegmentsDeleteContactdbSegmentsSegmentRequest<'a> {
+        ContactsApiSegmentsDeleteContactdbSegmentsSegmentRequest {
+            client: self,
+            segment_id,
+            delete_contacts: None,
+            body,
+        }
+    }
     /**
      * Update a segment.
      *
@@ -256,4 +390,24 @@ impl ContactsApiSegments {
             )
             .await
     }
+    /**
+     * Retrieve recipients on a segment.
+     *
+     * This function performs a `GET` to the `/contactdb/segments/{segment_id}/recipients` endpoint.
+     *
+     * As opposed to `get_contactdb_segments_segment_recipients`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * **This endpoint allows you to retrieve all of the recipients in a segment with the given ID.**
+     */
+    pub fn get_contactdb_segments_segment_recipients_request(
+        &self,
+        segment_id: i64,
+    ) -> ContactsApiSegmentsGetContactdbSegmentsSegmentRecipientsRequest<'_> {
+        ContactsApiSegmentsGetContactdbSegmentsSegmentRecipientsRequest {
+            client: self,
+            segment_id,
+            page: None,
+            page_size: None,
+        }
+    }
 }
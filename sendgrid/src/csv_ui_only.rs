@@ -1,6 +1,46 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`CsvUiOnly::post_messages_download`], set with a builder.
+pub struct CsvUiOnlyPostMessagesDownloadRequest<'a> {
+    client: &'a CsvUiOnly,
+    query: Option<&'a str>,
+}
+
+impl<'a> CsvUiOnlyPostMessagesDownloadRequest<'a> {
+    /// Sets the `query` query parameter.
+    pub fn query(mut self, query: &'a str) -> Self {
+        self.query = Some(query);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::PostMessagesDownloadResponse>> {
+        let Self { client, query } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = query {
+            query_args.push(("query".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/messages/download?{}", query_), None);
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct CsvUiOnly {
     pub client: Client,
 }
@@ -51,6 +91,27 @@ impl CsvUiOnly {
             )
             .await
     }
+    /**
+     * Request CSV.
+     *
+     * This function performs a `POST` to the `/messages/download` endpoint.
+     *
+     * As opposed to `post_messages_download`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * This is BETA functionality. You may not have access, and we reserve the right to change functionality without notice.
+     *
+     * This request will kick off a backend process to generate a CSV file. Once generated, the worker will then send an email for the user download the file. The link will expire in 3 days.
+     *
+     * The CSV fill contain the last 1 million messages. This endpoint will be rate limited to 1 request every 12 hours (rate limit may change).
+     *
+     * This endpoint is similar to the GET Single Message endpoint - the only difference is that /download is added to indicate that this is a CSV download requests but the same query is used to determine what the CSV should contain.
+     */
+    pub fn post_messages_download_request(&self) -> CsvUiOnlyPostMessagesDownloadRequest<'_> {
+        CsvUiOnlyPostMessagesDownloadRequest {
+            client: self,
+            query: None,
+        }
+    }
     /**
      * Download CSV.
      *
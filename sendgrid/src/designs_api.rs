@@ -1,6 +1,130 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`DesignsApi::list_designs`], set with a builder.
+pub struct DesignsApiListDesignsRequest<'a> {
+    client: &'a DesignsApi,
+    page_size: Option<u64>,
+    page_token: Option<&'a str>,
+    summary: Option<bool>,
+}
+
+impl<'a> DesignsApiListDesignsRequest<'a> {
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: u64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Sets the `page_token` query parameter.
+    pub fn page_token(mut self, page_token: &'a str) -> Self {
+        self.page_token = Some(page_token);
+        self
+    }
+
+    /// Sets the `summary` query parameter.
+    pub fn summary(mut self, summary: bool) -> Self {
+        self.summary = Some(summary);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::ListDesignsResponse>> {
+        let Self {
+            client,
+            page_size,
+            page_token,
+            summary,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page_size {
+            query_args.push(("page_size".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_token {
+            query_args.push(("page_token".to_string(), v.to_string()));
+        }
+        if let Some(v) = summary {
+            query_args.push(("summary".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(&format!("/designs?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`DesignsApi::list_sendgrid_pre_built_designs`], set with a builder.
+pub struct DesignsApiListSendgridPreBuiltDesignsRequest<'a> {
+    client: &'a DesignsApi,
+    page_size: Option<u64>,
+    page_token: Option<&'a str>,
+    summary: Option<bool>,
+}
+
+impl<'a> DesignsApiListSendgridPreBuiltDesignsRequest<'a> {
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: u64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Sets the `page_token` query parameter.
+    pub fn page_token(mut self, page_token: &'a str) -> Self {
+        self.page_token = Some(page_token);
+        self
+    }
+
+    /// Sets the `summary` query parameter.
+    pub fn summary(mut self, summary: bool) -> Self {
+        self.summary = Some(summary);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::ListDesignsResponse>> {
+        let Self {
+            client,
+            page_size,
+            page_token,
+            summary,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page_size {
+            query_args.push(("page_size".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_token {
+            query_args.push(("page_token".to_string(), v.to_string()));
+        }
+        if let Some(v) = summary {
+            query_args.push(("summary".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/designs/pre-builts?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct DesignsApi {
     pub client: Client,
 }
@@ -181,6 +305,27 @@ impl DesignsApi {
             )
             .await
     }
+    /**
+     * List Designs.
+     *
+     * This function performs a `GET` to the `/designs` endpoint.
+     *
+     * As opposed to `list_designs`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * **This endpoint allows you to retrieve a list of designs already stored in your Design Library**.
+     *
+     * A GET request to `/designs` will return a list of your existing designs. This endpoint will not return the pre-built Twilio SendGrid designs. Pre-built designs can be retrieved using the `/designs/pre-builts` endpoint, which is detailed below.
+     *
+     * By default, you will receive 100 results per request; however, you can modify the number of results returned by passing an integer to the `page_size` query parameter.
+     */
+    pub fn list_designs_request(&self) -> DesignsApiListDesignsRequest<'_> {
+        DesignsApiListDesignsRequest {
+            client: self,
+            page_size: None,
+            page_token: None,
+            summary: None,
+        }
+    }
     /**
      * Create Design.
      *
@@ -324,4 +469,29 @@ impl DesignsApi {
             )
             .await
     }
+    /**
+     * List SendGrid Pre-built Designs.
+     *
+     * This function performs a `GET` to the `/designs/pre-builts` endpoint.
+     *
+     * As opposed to `list_sendgrid_pre_built_designs`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * **This endpoint allows you to retrieve a list of pre-built designs provided by Twilio SendGrid**.
+     *
+     * Unlike the `/designs` endpoint where *your* designs are stored, a GET request made to `designs/pre-builts` will retrieve a list of the pre-built Twilio SendGrid designs. This endpoint will not return the designs stored in your Design Library.
+     *
+     * By default, you will receive 100 results per request; however, you can modify the number of results returned by passing an integer to the `page_size` query parameter.
+     *
+     * This endpoint is useful for retrieving the IDs of Twilio SendGrid designs that you want to duplicate and modify.
+     */
+    pub fn list_sendgrid_pre_built_designs_request(
+        &self,
+    ) -> DesignsApiListSendgridPreBuiltDesignsRequest<'_> {
+        DesignsApiListSendgridPreBuiltDesignsRequest {
+            client: self,
+            page_size: None,
+            page_token: None,
+            summary: None,
+        }
+    }
 }
@@ -1,6 +1,173 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`DomainAuthentication::get_whitelabel_domains`], set with a builder.
+pub struct DomainAuthenticationGetWhitelabelDomainsRequest<'a> {
+    client: &'a DomainAuthentication,
+    limit: Option<i64>,
+    offset: Option<i64>,
+    exclude_subusers: Option<bool>,
+    username: Option<&'a str>,
+    domain: Option<&'a str>,
+}
+
+impl<'a> DomainAuthenticationGetWhitelabelDomainsRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Sets the `exclude_subusers` query parameter.
+    pub fn exclude_subusers(mut self, exclude_subusers: bool) -> Self {
+        self.exclude_subusers = Some(exclude_subusers);
+        self
+    }
+
+    /// Sets the `username` query parameter.
+    pub fn username(mut self, username: &'a str) -> Self {
+        self.username = Some(username);
+        self
+    }
+
+    /// Sets the `domain` query parameter.
+    pub fn domain(mut self, domain: &'a str) -> Self {
+        self.domain = Some(domain);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::DomainAuthentication200ResponseAllOf>>>
+    {
+        let Self {
+            client,
+            limit,
+            offset,
+            exclude_subusers,
+            username,
+            domain,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = domain {
+            query_args.push(("domain".to_string(), v.to_string()));
+        }
+        if let Some(v) = exclude_subusers {
+            query_args.push(("exclude_subusers".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = offset {
+            query_args.push(("offset".to_string(), v.to_string()));
+        }
+        if let Some(v) = username {
+            query_args.push(("username".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/whitelabel/domains?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`DomainAuthentication::get_whitelabel_domains_default`], set with a builder.
+pub struct DomainAuthenticationGetWhitelabelDomainsDefaultRequest<'a> {
+    client: &'a DomainAuthentication,
+    domain: Option<&'a str>,
+}
+
+impl<'a> DomainAuthenticationGetWhitelabelDomainsDefaultRequest<'a> {
+    /// Sets the `domain` query parameter.
+    pub fn domain(mut self, domain: &'a str) -> Self {
+        self.domain = Some(domain);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::DomainAuthentication200ResponseAllOf>>>
+    {
+        let Self { client, domain } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = domain {
+            query_args.push(("domain".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/whitelabel/domains/default?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`DomainAuthentication::delete_whitelabel_domains_subuser`], set with a builder.
+pub struct DomainAuthenticationDeleteWhitelabelDomainsSubuserRequest<'a> {
+    client: &'a DomainAuthentication,
+    username: Option<&'a str>,
+}
+
+impl<'a> DomainAuthenticationDeleteWhitelabelDomainsSubuserRequest<'a> {
+    /// Sets the `username` query parameter.
+    pub fn username(mut self, username: &'a str) -> Self {
+        self.username = Some(username);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::Help>> {
+        let Self { client, username } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = username {
+            query_args.push(("username".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/whitelabel/domains/subuser?{}", query_), None);
+
+        client
+            .client
+            .delete(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct DomainAuthentication {
     pub client: Client,
 }
@@ -66,6 +233,27 @@ impl DomainAuthentication {
             )
             .await
     }
+    /**
+     * List all authenticated domains.
+     *
+     * This function performs a `GET` to the `/whitelabel/domains` endpoint.
+     *
+     * As opposed to `get_whitelabel_domains`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * **This endpoint allows you to retrieve a list of all domains you have authenticated.**
+     */
+    pub fn get_whitelabel_domains_request(
+        &self,
+    ) -> DomainAuthenticationGetWhitelabelDomainsRequest<'_> {
+        DomainAuthenticationGetWhitelabelDomainsRequest {
+            client: self,
+            limit: None,
+            offset: None,
+            exclude_subusers: None,
+            username: None,
+            domain: None,
+        }
+    }
     /**
      * List all authenticated domains.
      *
@@ -110,6 +298,43 @@ impl DomainAuthentication {
             )
             .await
     }
+    /**
+     * List all authenticated domains.
+     *
+     * This function performs a `GET` to the `/whitelabel/domains` endpoint.
+     *
+     * As opposed to `get_all_whitelabel_domains`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * **This endpoint allows you to retrieve a list of all domains you have authenticated.**
+     */
+    pub fn get_all_whitelabel_domains_stream(
+        &self,
+        offset: i64,
+        exclude_subusers: bool,
+        username: &str,
+        domain: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::DomainAuthentication200ResponseAllOf>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !domain.is_empty() {
+            query_args.push(("domain".to_string(), domain.to_string()));
+        }
+        if exclude_subusers {
+            query_args.push(("exclude_subusers".to_string(), exclude_subusers.to_string()));
+        }
+        if offset > 0 {
+            query_args.push(("offset".to_string(), offset.to_string()));
+        }
+        if !username.is_empty() {
+            query_args.push(("username".to_string(), username.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/whitelabel/domains?{}", query_), None);
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Authenticate a domain.
      *
@@ -277,6 +502,27 @@ impl DomainAuthentication {
             )
             .await
     }
+    /**
+     * Get the default authentication.
+     *
+     * This function performs a `GET` to the `/whitelabel/domains/default` endpoint.
+     *
+     * As opposed to `get_whitelabel_domains_default`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * **This endpoint allows you to retrieve the default authentication for a domain.**
+     *
+     * When creating or updating a domain authentication, you can set the domain as a default. The default domain will be used to send all mail. If you have multiple authenticated domains, the authenticated domain matching the domain of the From address will be used, and the default will be overridden.
+     *
+     * This endpoint will return a default domain and its details only if a default is set. You are not required to set a default. If you do not set a default domain, this endpoint will return general information about your domain authentication status.
+     */
+    pub fn get_whitelabel_domains_default_request(
+        &self,
+    ) -> DomainAuthenticationGetWhitelabelDomainsDefaultRequest<'_> {
+        DomainAuthenticationGetWhitelabelDomainsDefaultRequest {
+            client: self,
+            domain: None,
+        }
+    }
     /**
      * Get the default authentication.
      *
@@ -313,6 +559,35 @@ impl DomainAuthentication {
             )
             .await
     }
+    /**
+     * Get the default authentication.
+     *
+     * This function performs a `GET` to the `/whitelabel/domains/default` endpoint.
+     *
+     * As opposed to `get_all_whitelabel_domains_default`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * **This endpoint allows you to retrieve the default authentication for a domain.**
+     *
+     * When creating or updating a domain authentication, you can set the domain as a default. The default domain will be used to send all mail. If you have multiple authenticated domains, the authenticated domain matching the domain of the From address will be used, and the default will be overridden.
+     *
+     * This endpoint will return a default domain and its details only if a default is set. You are not required to set a default. If you do not set a default domain, this endpoint will return general information about your domain authentication status.
+     */
+    pub fn get_all_whitelabel_domains_default_stream(
+        &self,
+        domain: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::DomainAuthentication200ResponseAllOf>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !domain.is_empty() {
+            query_args.push(("domain".to_string(), domain.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/whitelabel/domains/default?{}", query_), None);
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Add an IP to an authenticated domain.
      *
@@ -482,6 +757,25 @@ impl DomainAuthentication {
             )
             .await
     }
+    /**
+     * Disassociate an authenticated domain from a given user.
+     *
+     * This function performs a `DELETE` to the `/whitelabel/domains/subuser` endpoint.
+     *
+     * As opposed to `delete_whitelabel_domains_subuser`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * **This endpoint allows you to disassociate a specific authenticated domain from a subuser.**
+     *
+     * Authenticated domains can be associated with (i.e. assigned to) subusers from a parent account. This functionality allows subusers to send mail using their parent's domain authentication. To associate a authenticated domain with a subuser, the parent account must first authenticate and validate the domain. The the parent may then associate the authenticated domain via the subuser management tools.
+     */
+    pub fn delete_whitelabel_domains_subuser_request(
+        &self,
+    ) -> DomainAuthenticationDeleteWhitelabelDomainsSubuserRequest<'_> {
+        DomainAuthenticationDeleteWhitelabelDomainsSubuserRequest {
+            client: self,
+            username: None,
+        }
+    }
     /**
      * Associate a authenticated domain with a given user.
      *
@@ -0,0 +1,187 @@
+//! For decoding the emails SendGrid posts to the URL of an Inbound Parse host, as
+//! configured with `settings_inbound_parse()`.
+//!
+//! FROM: <https://docs.sendgrid.com/for-developers/parsing-email/setting-up-the-inbound-parse-webhook>
+use std::collections::HashMap;
+
+use serde::Deserialize;
+
+/// Errors returned while decoding an Inbound Parse post.
+#[derive(Debug, thiserror::Error)]
+pub enum InboundParseError {
+    /// The body is not `multipart/form-data`, or is malformed
+    #[error(transparent)]
+    MultipartError(#[from] multer::Error),
+    /// The `envelope`, `charsets` or `attachment-info` field is not valid JSON
+    #[error(transparent)]
+    SerdeJsonError(#[from] serde_json::Error),
+}
+
+/// An email received by an Inbound Parse host.
+///
+/// Text fields are decoded from the charset SendGrid reports for them. When the host
+/// is set to post the raw, full MIME message, it is in `raw` and `text`, `html` and
+/// `attachments` are left empty.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct InboundEmail {
+    /// The raw headers of the email.
+    pub headers: String,
+    pub from: String,
+    pub to: String,
+    pub cc: String,
+    pub subject: String,
+    pub text: String,
+    pub html: String,
+    /// The SMTP envelope of the email, with the addresses it was actually sent to and
+    /// from.
+    pub envelope: InboundEnvelope,
+    pub sender_ip: String,
+    pub dkim: String,
+    /// The result of the SPF check of the sender, from the `SPF` field.
+    pub spf: String,
+    pub spam_score: String,
+    pub spam_report: String,
+    /// The charset of each field, by its name.
+    pub charsets: HashMap<String, String>,
+    /// The attachments of the email, in the order they were posted.
+    pub attachments: Vec<InboundAttachment>,
+    /// The full MIME message, in raw mode.
+    pub raw: Option<bytes::Bytes>,
+    /// The fields without a dedicated member, by their name.
+    pub other: HashMap<String, String>,
+}
+
+/// The SMTP envelope of an inbound email.
+#[derive(Debug, Clone, PartialEq, Default, Deserialize)]
+pub struct InboundEnvelope {
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub to: Vec<String>,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub from: String,
+}
+
+/// An attachment of an inbound email.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct InboundAttachment {
+    /// The name of the field of the attachment, like `attachment1`.
+    pub field: String,
+    pub filename: String,
+    pub content_type: String,
+    /// The `Content-ID` of an inline attachment, that the HTML part refers to as
+    /// `cid:...`.
+    pub content_id: String,
+    pub data: bytes::Bytes,
+}
+
+#[derive(Deserialize, Default)]
+struct AttachmentInfo {
+    #[serde(default)]
+    filename: Option<String>,
+    #[serde(default, rename = "type")]
+    type_: Option<String>,
+    #[serde(default, rename = "content-id")]
+    content_id: Option<String>,
+}
+
+/// Decodes the body of an Inbound Parse post, from its `Content-Type` header.
+pub async fn parse_email<B>(content_type: &str, body: B) -> Result<InboundEmail, InboundParseError>
+where
+    B: Into<bytes::Bytes>,
+{
+    let boundary = multer::parse_boundary(content_type)?;
+    let body = body.into();
+    let mut multipart = multer::Multipart::new(
+        futures::stream::once(async move { Ok::<_, std::convert::Infallible>(body) }),
+        boundary,
+    );
+
+    // The charsets of the fields are only known once all of them are read.
+    let mut fields = Vec::new();
+    let mut files = Vec::new();
+    while let Some(field) = multipart.next_field().await? {
+        let name = field.name().unwrap_or_default().to_string();
+        match field.file_name().map(str::to_string) {
+            Some(filename) => {
+                let content_type = field
+                    .content_type()
+                    .map(|mime| mime.to_string())
+                    .unwrap_or_default();
+                let data = field.bytes().await?;
+                files.push(InboundAttachment {
+                    field: name,
+                    filename,
+                    content_type,
+                    content_id: String::new(),
+                    data,
+                });
+            }
+            None => fields.push((name, field.bytes().await?)),
+        }
+    }
+
+    let mut charsets = HashMap::new();
+    let mut attachment_info = HashMap::<String, AttachmentInfo>::new();
+    for (name, value) in &fields {
+        match name.as_str() {
+            "charsets" => charsets = serde_json::from_slice(value)?,
+            "attachment-info" => attachment_info = serde_json::from_slice(value)?,
+            _ => {}
+        }
+    }
+
+    let mut email = InboundEmail::default();
+    for (name, value) in fields {
+        let decoded = decode(&value, charsets.get(&name));
+        match name.as_str() {
+            "headers" => email.headers = decoded,
+            "from" => email.from = decoded,
+            "to" => email.to = decoded,
+            "cc" => email.cc = decoded,
+            "subject" => email.subject = decoded,
+            "text" => email.text = decoded,
+            "html" => email.html = decoded,
+            "envelope" => email.envelope = serde_json::from_slice(&value)?,
+            "sender_ip" => email.sender_ip = decoded,
+            "dkim" => email.dkim = decoded,
+            "SPF" => email.spf = decoded,
+            "spam_score" => email.spam_score = decoded,
+            "spam_report" => email.spam_report = decoded,
+            "email" => email.raw = Some(value),
+            // Described by the other fields already.
+            "charsets" | "attachment-info" | "attachments" | "content-ids" => {}
+            _ => {
+                email.other.insert(name, decoded);
+            }
+        }
+    }
+
+    for mut attachment in files {
+        if let Some(info) = attachment_info.remove(&attachment.field) {
+            if let Some(filename) = info.filename {
+                attachment.filename = filename;
+            }
+            if let Some(type_) = info.type_ {
+                attachment.content_type = type_;
+            }
+            attachment.content_id = info.content_id.unwrap_or_default();
+        }
+        email.attachments.push(attachment);
+    }
+    email.charsets = charsets;
+
+    Ok(email)
+}
+
+/// Decodes a field from its charset, falling back to UTF-8 for unknown ones.
+fn decode(value: &[u8], charset: Option<&String>) -> String {
+    let encoding = charset
+        .and_then(|charset| encoding_rs::Encoding::for_label(charset.trim().as_bytes()))
+        .unwrap_or(encoding_rs::UTF_8);
+    encoding.decode(value).0.into_owned()
+}
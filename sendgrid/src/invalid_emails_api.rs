@@ -1,6 +1,80 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`InvalidEmailsApi::get_suppression_invalid_emails`], set with a builder.
+pub struct InvalidEmailsApiGetSuppressionInvalidEmailsRequest<'a> {
+    client: &'a InvalidEmailsApi,
+    start_time: Option<i64>,
+    end_time: Option<i64>,
+    limit: Option<i64>,
+    offset: Option<i64>,
+}
+
+impl<'a> InvalidEmailsApiGetSuppressionInvalidEmailsRequest<'a> {
+    /// Sets the `start_time` query parameter.
+    pub fn start_time(mut self, start_time: i64) -> Self {
+        self.start_time = Some(start_time);
+        self
+    }
+
+    /// Sets the `end_time` query parameter.
+    pub fn end_time(mut self, end_time: i64) -> Self {
+        self.end_time = Some(end_time);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::InvalidEmail>>> {
+        let Self {
+            client,
+            start_time,
+            end_time,
+            limit,
+            offset,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = end_time {
+            query_args.push(("end_time".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = offset {
+            query_args.push(("offset".to_string(), v.to_string()));
+        }
+        if let Some(v) = start_time {
+            query_args.push(("start_time".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/suppression/invalid_emails?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct InvalidEmailsApi {
     pub client: Client,
 }
@@ -60,6 +134,26 @@ impl InvalidEmailsApi {
             )
             .await
     }
+    /**
+     * Retrieve all invalid emails.
+     *
+     * This function performs a `GET` to the `/suppression/invalid_emails` endpoint.
+     *
+     * As opposed to `get_suppression_invalid_emails`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * **This endpoint allows you to retrieve a list of all invalid email addresses.**
+     */
+    pub fn get_suppression_invalid_emails_request(
+        &self,
+    ) -> InvalidEmailsApiGetSuppressionInvalidEmailsRequest<'_> {
+        InvalidEmailsApiGetSuppressionInvalidEmailsRequest {
+            client: self,
+            start_time: None,
+            end_time: None,
+            limit: None,
+            offset: None,
+        }
+    }
     /**
      * Retrieve all invalid emails.
      *
@@ -99,6 +193,37 @@ impl InvalidEmailsApi {
             )
             .await
     }
+    /**
+     * Retrieve all invalid emails.
+     *
+     * This function performs a `GET` to the `/suppression/invalid_emails` endpoint.
+     *
+     * As opposed to `get_all_suppression_invalid_emails`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * **This endpoint allows you to retrieve a list of all invalid email addresses.**
+     */
+    pub fn get_all_suppression_invalid_emails_stream(
+        &self,
+        start_time: i64,
+        end_time: i64,
+        offset: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::InvalidEmail>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if end_time > 0 {
+            query_args.push(("end_time".to_string(), end_time.to_string()));
+        }
+        if offset > 0 {
+            query_args.push(("offset".to_string(), offset.to_string()));
+        }
+        if start_time > 0 {
+            query_args.push(("start_time".to_string(), start_time.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/suppression/invalid_emails?{}", query_), None);
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Delete invalid emails.
      *
@@ -192,6 +317,28 @@ impl InvalidEmailsApi {
             )
             .await
     }
+    /**
+     * Retrieve a specific invalid email.
+     *
+     * This function performs a `GET` to the `/suppression/invalid_emails/{email}` endpoint.
+     *
+     * As opposed to `get_all_suppression_invalid_emails_email`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * **This endpoint allows you to retrieve a specific invalid email addresses.**
+     */
+    pub fn get_all_suppression_invalid_emails_email_stream(
+        &self,
+        email: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::InvalidEmail>> + Unpin + '_ {
+        let url = self.client.url(
+            &format!(
+                "/suppression/invalid_emails/{}",
+                crate::progenitor_support::encode_path(email),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Delete a specific invalid email.
      *
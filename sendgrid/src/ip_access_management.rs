@@ -1,6 +1,46 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`IpAccessManagement::get_access_settings_activity`], set with a builder.
+pub struct IpAccessManagementGetAccessSettingsActivityRequest<'a> {
+    client: &'a IpAccessManagement,
+    limit: Option<i64>,
+}
+
+impl<'a> IpAccessManagementGetAccessSettingsActivityRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::GetAccessSettingsActivityResponse>> {
+        let Self { client, limit } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/access_settings/activity?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct IpAccessManagement {
     pub client: Client,
 }
@@ -130,6 +170,23 @@ impl IpAccessManagement {
             )
             .await
     }
+    /**
+     * Retrieve all recent access attempts.
+     *
+     * This function performs a `GET` to the `/access_settings/activity` endpoint.
+     *
+     * As opposed to `get_access_settings_activity`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * **This endpoint allows you to retrieve a list of all of the IP addresses that recently attempted to access your account either through the User Interface or the API.**
+     */
+    pub fn get_access_settings_activity_request(
+        &self,
+    ) -> IpAccessManagementGetAccessSettingsActivityRequest<'_> {
+        IpAccessManagementGetAccessSettingsActivityRequest {
+            client: self,
+            limit: None,
+        }
+    }
     /**
      * Retrieve a specific allowed IP.
      *
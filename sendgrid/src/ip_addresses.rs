@@ -1,6 +1,100 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`IpAddresses::get_ips`], set with a builder.
+pub struct IpAddressesGetIpsRequest<'a> {
+    client: &'a IpAddresses,
+    ip: Option<&'a str>,
+    exclude_whitelabels: Option<bool>,
+    limit: Option<i64>,
+    offset: Option<i64>,
+    subuser: Option<&'a str>,
+    sort_by_direction: Option<crate::types::SortByDirection>,
+}
+
+impl<'a> IpAddressesGetIpsRequest<'a> {
+    /// Sets the `ip` query parameter.
+    pub fn ip(mut self, ip: &'a str) -> Self {
+        self.ip = Some(ip);
+        self
+    }
+
+    /// Sets the `exclude_whitelabels` query parameter.
+    pub fn exclude_whitelabels(mut self, exclude_whitelabels: bool) -> Self {
+        self.exclude_whitelabels = Some(exclude_whitelabels);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Sets the `subuser` query parameter.
+    pub fn subuser(mut self, subuser: &'a str) -> Self {
+        self.subuser = Some(subuser);
+        self
+    }
+
+    /// Sets the `sort_by_direction` query parameter.
+    pub fn sort_by_direction(mut self, sort_by_direction: crate::types::SortByDirection) -> Self {
+        self.sort_by_direction = Some(sort_by_direction);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::GetIpsResponse>>> {
+        let Self {
+            client,
+            ip,
+            exclude_whitelabels,
+            limit,
+            offset,
+            subuser,
+            sort_by_direction,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = exclude_whitelabels {
+            query_args.push(("exclude_whitelabels".to_string(), v.to_string()));
+        }
+        if let Some(v) = ip {
+            query_args.push(("ip".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = offset {
+            query_args.push(("offset".to_string(), v.to_string()));
+        }
+        if let Some(v) = sort_by_direction {
+            query_args.push(("sort_by_direction".to_string(), v.to_string()));
+        }
+        if let Some(v) = subuser {
+            query_args.push(("subuser".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(&format!("/ips?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct IpAddresses {
     pub client: Client,
 }
@@ -77,6 +171,30 @@ impl IpAddresses {
             )
             .await
     }
+    /**
+     * Retrieve all IP addresses.
+     *
+     * This function performs a `GET` to the `/ips` endpoint.
+     *
+     * As opposed to `get_ips`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * **This endpoint allows you to retrieve a list of all assigned and unassigned IPs.**
+     *
+     * Response includes warm up status, pools, assigned subusers, and reverse DNS info. The start_date field corresponds to when warmup started for that IP.
+     *
+     * A single IP address or a range of IP addresses may be dedicated to an account in order to send email for multiple domains. The reputation of this IP is based on the aggregate performance of all the senders who use it.
+     */
+    pub fn get_ips_request(&self) -> IpAddressesGetIpsRequest<'_> {
+        IpAddressesGetIpsRequest {
+            client: self,
+            ip: None,
+            exclude_whitelabels: None,
+            limit: None,
+            offset: None,
+            subuser: None,
+            sort_by_direction: None,
+        }
+    }
     /**
      * Retrieve all IP addresses.
      *
@@ -132,6 +250,53 @@ impl IpAddresses {
             )
             .await
     }
+    /**
+     * Retrieve all IP addresses.
+     *
+     * This function performs a `GET` to the `/ips` endpoint.
+     *
+     * As opposed to `get_all_ips`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * **This endpoint allows you to retrieve a list of all assigned and unassigned IPs.**
+     *
+     * Response includes warm up status, pools, assigned subusers, and reverse DNS info. The start_date field corresponds to when warmup started for that IP.
+     *
+     * A single IP address or a range of IP addresses may be dedicated to an account in order to send email for multiple domains. The reputation of this IP is based on the aggregate performance of all the senders who use it.
+     */
+    pub fn get_all_ips_stream(
+        &self,
+        ip: &str,
+        exclude_whitelabels: bool,
+        offset: i64,
+        subuser: &str,
+        sort_by_direction: crate::types::SortByDirection,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::GetIpsResponse>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if exclude_whitelabels {
+            query_args.push((
+                "exclude_whitelabels".to_string(),
+                exclude_whitelabels.to_string(),
+            ));
+        }
+        if !ip.is_empty() {
+            query_args.push(("ip".to_string(), ip.to_string()));
+        }
+        if offset > 0 {
+            query_args.push(("offset".to_string(), offset.to_string()));
+        }
+        if !sort_by_direction.to_string().is_empty() {
+            query_args.push((
+                "sort_by_direction".to_string(),
+                sort_by_direction.to_string(),
+            ));
+        }
+        if !subuser.is_empty() {
+            query_args.push(("subuser".to_string(), subuser.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/ips?{}", query_), None);
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Add IPs.
      *
@@ -223,6 +388,24 @@ impl IpAddresses {
             )
             .await
     }
+    /**
+     * Retrieve all assigned IPs.
+     *
+     * This function performs a `GET` to the `/ips/assigned` endpoint.
+     *
+     * As opposed to `get_all_ips_assigned`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * **This endpoint allows you to retrieve only assigned IP addresses.**
+     *
+     * A single IP address or a range of IP addresses may be dedicated to an account in order to send email for multiple domains. The reputation of this IP is based on the aggregate performance of all the senders who use it.
+     */
+    pub fn get_all_ips_assigned_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::GetIpsAssignedResponse>> + Unpin + '_
+    {
+        let url = self.client.url("/ips/assigned", None);
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Retrieve all IP pools an IP address belongs to.
      *
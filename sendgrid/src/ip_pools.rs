@@ -55,6 +55,21 @@ impl IpPools {
             )
             .await
     }
+    /**
+     * Retrieve all IP pools.
+     *
+     * This function performs a `GET` to the `/ips/pools` endpoint.
+     *
+     * As opposed to `get_all_ips_pools`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * **This endpoint allows you to get all of your IP pools.**
+     */
+    pub fn get_all_ips_pools_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::IpPoolsPoolResp>> + Unpin + '_ {
+        let url = self.client.url("/ips/pools", None);
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Create an IP pool.
      *
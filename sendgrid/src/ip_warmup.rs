@@ -55,6 +55,22 @@ impl IpWarmup {
             )
             .await
     }
+    /**
+     * Retrieve all IPs currently in warmup.
+     *
+     * This function performs a `GET` to the `/ips/warmup` endpoint.
+     *
+     * As opposed to `get_all_ips_warmup`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * **This endpoint allows you to retrieve all of your IP addresses that are currently warming up.**
+     */
+    pub fn get_all_ips_warmup_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::IpWarmupResponse>> + Unpin + '_
+    {
+        let url = self.client.url("/ips/warmup", None);
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Start warming up an IP address.
      *
@@ -139,6 +155,31 @@ impl IpWarmup {
             )
             .await
     }
+    /**
+     * Retrieve the warmup status for a specific IP address.
+     *
+     * This function performs a `GET` to the `/ips/warmup/{ip_address}` endpoint.
+     *
+     * As opposed to `get_all_ips_warmup_ip_address`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * **This endpoint allows you to retrieve the warmup status for a specific IP address.**
+     *
+     * You can retrieve all of your warming IPs using the "Retrieve all IPs currently in warmup" endpoint.
+     */
+    pub fn get_all_ips_warmup_ip_address_stream(
+        &self,
+        ip_address: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::IpWarmupResponse>> + Unpin + '_
+    {
+        let url = self.client.url(
+            &format!(
+                "/ips/warmup/{}",
+                crate::progenitor_support::encode_path(ip_address),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Stop warming up an IP address.
      *
@@ -1,6 +1,86 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`LinkBranding::get_whitelabel_links`], set with a builder.
+pub struct LinkBrandingGetWhitelabelLinksRequest<'a> {
+    client: &'a LinkBranding,
+    limit: Option<i64>,
+}
+
+impl<'a> LinkBrandingGetWhitelabelLinksRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::LinkBranding200Response>>> {
+        let Self { client, limit } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/whitelabel/links?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`LinkBranding::get_whitelabel_links_default`], set with a builder.
+pub struct LinkBrandingGetWhitelabelLinksDefaultRequest<'a> {
+    client: &'a LinkBranding,
+    domain: Option<&'a str>,
+}
+
+impl<'a> LinkBrandingGetWhitelabelLinksDefaultRequest<'a> {
+    /// Sets the `domain` query parameter.
+    pub fn domain(mut self, domain: &'a str) -> Self {
+        self.domain = Some(domain);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::LinkBranding200Response>> {
+        let Self { client, domain } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = domain {
+            query_args.push(("domain".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/whitelabel/links/default?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct LinkBranding {
     pub client: Client,
 }
@@ -47,6 +127,23 @@ impl LinkBranding {
             )
             .await
     }
+    /**
+     * Retrieve all branded links.
+     *
+     * This function performs a `GET` to the `/whitelabel/links` endpoint.
+     *
+     * As opposed to `get_whitelabel_links`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * **This endpoint allows you to retrieve all branded links**.
+     *
+     * You can submit this request as one of your subusers if you include their ID in the `on-behalf-of` header in the request.
+     */
+    pub fn get_whitelabel_links_request(&self) -> LinkBrandingGetWhitelabelLinksRequest<'_> {
+        LinkBrandingGetWhitelabelLinksRequest {
+            client: self,
+            limit: None,
+        }
+    }
     /**
      * Retrieve all branded links.
      *
@@ -72,6 +169,24 @@ impl LinkBranding {
             )
             .await
     }
+    /**
+     * Retrieve all branded links.
+     *
+     * This function performs a `GET` to the `/whitelabel/links` endpoint.
+     *
+     * As opposed to `get_all_whitelabel_links`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * **This endpoint allows you to retrieve all branded links**.
+     *
+     * You can submit this request as one of your subusers if you include their ID in the `on-behalf-of` header in the request.
+     */
+    pub fn get_all_whitelabel_links_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::LinkBranding200Response>> + Unpin + '_
+    {
+        let url = self.client.url("/whitelabel/links", None);
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Create a branded link.
      *
@@ -314,6 +429,31 @@ impl LinkBranding {
             )
             .await
     }
+    /**
+     * Retrieve the default branded link.
+     *
+     * This function performs a `GET` to the `/whitelabel/links/default` endpoint.
+     *
+     * As opposed to `get_whitelabel_links_default`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * **This endpoint allows you to retrieve the default branded link.**
+     *
+     * The default branded link is the actual URL to be used when sending messages. If you have more than one branded link, the default is determined by the following order:
+     *
+     * * The validated branded link marked as `default` (set when you call the "Create a branded link" endpoint or by calling the "Update a branded link" endpoint on an existing link)
+     * * Legacy branded links (migrated from the whitelabel wizard)
+     * * Default SendGrid-branded links (i.e., `100.ct.sendgrid.net`)
+     *
+     * You can submit this request as one of your subusers if you include their ID in the `on-behalf-of` header in the request.
+     */
+    pub fn get_whitelabel_links_default_request(
+        &self,
+    ) -> LinkBrandingGetWhitelabelLinksDefaultRequest<'_> {
+        LinkBrandingGetWhitelabelLinksDefaultRequest {
+            client: self,
+            domain: None,
+        }
+    }
     /**
      * Retrieve a subuser's branded link.
      *
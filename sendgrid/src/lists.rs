@@ -1,6 +1,156 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Lists::get_mc`], set with a builder.
+pub struct ListsGetMcRequest<'a> {
+    client: &'a Lists,
+    page_size: Option<f64>,
+    page_token: Option<&'a str>,
+}
+
+impl<'a> ListsGetMcRequest<'a> {
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: f64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Sets the `page_token` query parameter.
+    pub fn page_token(mut self, page_token: &'a str) -> Self {
+        self.page_token = Some(page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::GetMcListsResponse>> {
+        let Self {
+            client,
+            page_size,
+            page_token,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page_size {
+            query_args.push(("page_size".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_token {
+            query_args.push(("page_token".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/marketing/lists?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Lists::get_mc_lists`], set with a builder.
+pub struct ListsGetMcListsRequest<'a> {
+    client: &'a Lists,
+    id: &'a str,
+    contact_sample: Option<bool>,
+}
+
+impl<'a> ListsGetMcListsRequest<'a> {
+    /// Sets the `contact_sample` query parameter.
+    pub fn contact_sample(mut self, contact_sample: bool) -> Self {
+        self.contact_sample = Some(contact_sample);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::GetMcListsResponseAllOf>> {
+        let Self {
+            client,
+            id,
+            contact_sample,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = contact_sample {
+            query_args.push(("contact_sample".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/marketing/lists/{}?{}",
+                crate::progenitor_support::encode_path(id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Lists::delete`], set with a builder.
+pub struct ListsDeleteRequest<'a> {
+    client: &'a Lists,
+    id: &'a str,
+    delete_contacts: Option<bool>,
+}
+
+impl<'a> ListsDeleteRequest<'a> {
+    /// Sets the `delete_contacts` query parameter.
+    pub fn delete_contacts(mut self, delete_contacts: bool) -> Self {
+        self.delete_contacts = Some(delete_contacts);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::DeleteListsResponse>> {
+        let Self {
+            client,
+            id,
+            delete_contacts,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = delete_contacts {
+            query_args.push(("delete_contacts".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/marketing/lists/{}?{}",
+                crate::progenitor_support::encode_path(id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .delete(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct Lists {
     pub client: Client,
 }
@@ -49,6 +199,22 @@ impl Lists {
             )
             .await
     }
+    /**
+     * Get All Lists.
+     *
+     * This function performs a `GET` to the `/marketing/lists` endpoint.
+     *
+     * As opposed to `get_mc`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * **This endpoint returns an array of all of your contact lists.**
+     */
+    pub fn get_mc_request(&self) -> ListsGetMcRequest<'_> {
+        ListsGetMcRequest {
+            client: self,
+            page_size: None,
+            page_token: None,
+        }
+    }
     /**
      * Create List.
      *
@@ -146,6 +312,26 @@ impl Lists {
             )
             .await
     }
+    /**
+     * Get a List by ID.
+     *
+     * This function performs a `GET` to the `/marketing/lists/{id}` endpoint.
+     *
+     * As opposed to `get_mc_lists`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * **This endpoint returns data about a specific list.**
+     *
+     * Setting the optional parameter `contact_sample=true` returns the `contact_sample` in the response body. Up to fifty of the most recent contacts uploaded or attached to a list will be returned, sorted alphabetically, by email address.
+     *
+     * The full contact count is also returned.
+     */
+    pub fn get_mc_lists_request<'a>(&'a self, id: &'a str) -> ListsGetMcListsRequest<'a> {
+        ListsGetMcListsRequest {
+            client: self,
+            id,
+            contact_sample: None,
+        }
+    }
     /**
      * Delete a list.
      *
@@ -187,6 +373,24 @@ impl Lists {
             )
             .await
     }
+    /**
+     * Delete a list.
+     *
+     * This function performs a `DELETE` to the `/marketing/lists/{id}` endpoint.
+     *
+     * As opposed to `delete`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * **This endpoint allows you to deletes a specific list.**
+     *
+     * Optionally, you can also delete contacts associated to the list. The query parameter, `delete_contacts=true`, will delete the list and start an asynchronous job to delete associated contacts.
+     */
+    pub fn delete_request<'a>(&'a self, id: &'a str) -> ListsDeleteRequest<'a> {
+        ListsDeleteRequest {
+            client: self,
+            id,
+            delete_contacts: None,
+        }
+    }
     /**
      * Update List.
      *
@@ -1,6 +1,654 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`MarketingCampaignsStats::getall_automation_stats`], set with a builder.
+pub struct MarketingCampaignsStatsGetallAutomationStatsRequest<'a> {
+    client: &'a MarketingCampaignsStats,
+    automation_ids: Option<&'a [String]>,
+    page_size: Option<i64>,
+    page_token: Option<&'a str>,
+}
+
+impl<'a> MarketingCampaignsStatsGetallAutomationStatsRequest<'a> {
+    /// Sets the `automation_ids` query parameter.
+    pub fn automation_ids(mut self, automation_ids: &'a [String]) -> Self {
+        self.automation_ids = Some(automation_ids);
+        self
+    }
+
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Sets the `page_token` query parameter.
+    pub fn page_token(mut self, page_token: &'a str) -> Self {
+        self.page_token = Some(page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::AutomationsResponse>> {
+        let Self {
+            client,
+            automation_ids,
+            page_size,
+            page_token,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = automation_ids {
+            query_args.push(("automation_ids".to_string(), v.join(" ")));
+        }
+        if let Some(v) = page_size {
+            query_args.push(("page_size".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_token {
+            query_args.push(("page_token".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/marketing/stats/automations?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`MarketingCampaignsStats::get_automation_stat`], set with a builder.
+pub struct MarketingCampaignsStatsGetAutomationStatRequest<'a> {
+    client: &'a MarketingCampaignsStats,
+    id: &'a str,
+    group_by: Option<&'a [String]>,
+    step_ids: Option<&'a [String]>,
+    aggregated_by: Option<crate::types::AggregatedBy>,
+    start_date: Option<chrono::NaiveDate>,
+    end_date: Option<chrono::NaiveDate>,
+    timezone: Option<&'a str>,
+    page_size: Option<i64>,
+    page_token: Option<&'a str>,
+}
+
+impl<'a> MarketingCampaignsStatsGetAutomationStatRequest<'a> {
+    /// Sets the `group_by` query parameter.
+    pub fn group_by(mut self, group_by: &'a [String]) -> Self {
+        self.group_by = Some(group_by);
+        self
+    }
+
+    /// Sets the `step_ids` query parameter.
+    pub fn step_ids(mut self, step_ids: &'a [String]) -> Self {
+        self.step_ids = Some(step_ids);
+        self
+    }
+
+    /// Sets the `aggregated_by` query parameter.
+    pub fn aggregated_by(mut self, aggregated_by: crate::types::AggregatedBy) -> Self {
+        self.aggregated_by = Some(aggregated_by);
+        self
+    }
+
+    /// Sets the `start_date` query parameter.
+    pub fn start_date(mut self, start_date: chrono::NaiveDate) -> Self {
+        self.start_date = Some(start_date);
+        self
+    }
+
+    /// Sets the `end_date` query parameter.
+    pub fn end_date(mut self, end_date: chrono::NaiveDate) -> Self {
+        self.end_date = Some(end_date);
+        self
+    }
+
+    /// Sets the `timezone` query parameter.
+    pub fn timezone(mut self, timezone: &'a str) -> Self {
+        self.timezone = Some(timezone);
+        self
+    }
+
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Sets the `page_token` query parameter.
+    pub fn page_token(mut self, page_token: &'a str) -> Self {
+        self.page_token = Some(page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::AutomationsResponse>> {
+        let Self {
+            client,
+            id,
+            group_by,
+            step_ids,
+            aggregated_by,
+            start_date,
+            end_date,
+            timezone,
+            page_size,
+            page_token,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = aggregated_by {
+            query_args.push(("aggregated_by".to_string(), v.to_string()));
+        }
+        if let Some(v) = end_date {
+            query_args.push(("end_date".to_string(), v.to_string()));
+        }
+        if let Some(v) = group_by {
+            query_args.push(("group_by".to_string(), v.join(" ")));
+        }
+        if let Some(v) = page_size {
+            query_args.push(("page_size".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_token {
+            query_args.push(("page_token".to_string(), v.to_string()));
+        }
+        if let Some(v) = start_date {
+            query_args.push(("start_date".to_string(), v.to_string()));
+        }
+        if let Some(v) = step_ids {
+            query_args.push(("step_ids".to_string(), v.join(" ")));
+        }
+        if let Some(v) = timezone {
+            query_args.push(("timezone".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/marketing/stats/automations/{}?{}",
+                crate::progenitor_support::encode_path(id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`MarketingCampaignsStats::getall_singlesend_stats`], set with a builder.
+pub struct MarketingCampaignsStatsGetallSinglesendStatsRequest<'a> {
+    client: &'a MarketingCampaignsStats,
+    singlesend_ids: Option<&'a [String]>,
+    page_size: Option<i64>,
+    page_token: Option<&'a str>,
+}
+
+impl<'a> MarketingCampaignsStatsGetallSinglesendStatsRequest<'a> {
+    /// Sets the `singlesend_ids` query parameter.
+    pub fn singlesend_ids(mut self, singlesend_ids: &'a [String]) -> Self {
+        self.singlesend_ids = Some(singlesend_ids);
+        self
+    }
+
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Sets the `page_token` query parameter.
+    pub fn page_token(mut self, page_token: &'a str) -> Self {
+        self.page_token = Some(page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::SinglesendsResponse>> {
+        let Self {
+            client,
+            singlesend_ids,
+            page_size,
+            page_token,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page_size {
+            query_args.push(("page_size".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_token {
+            query_args.push(("page_token".to_string(), v.to_string()));
+        }
+        if let Some(v) = singlesend_ids {
+            query_args.push(("singlesend_ids".to_string(), v.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/marketing/stats/singlesends?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`MarketingCampaignsStats::get_singlesend_stat`], set with a builder.
+pub struct MarketingCampaignsStatsGetSinglesendStatRequest<'a> {
+    client: &'a MarketingCampaignsStats,
+    id: &'a str,
+    aggregated_by: Option<crate::types::AggregatedBy>,
+    start_date: Option<chrono::NaiveDate>,
+    end_date: Option<chrono::NaiveDate>,
+    timezone: Option<&'a str>,
+    page_size: Option<i64>,
+    page_token: Option<&'a str>,
+    group_by: Option<&'a [String]>,
+}
+
+impl<'a> MarketingCampaignsStatsGetSinglesendStatRequest<'a> {
+    /// Sets the `aggregated_by` query parameter.
+    pub fn aggregated_by(mut self, aggregated_by: crate::types::AggregatedBy) -> Self {
+        self.aggregated_by = Some(aggregated_by);
+        self
+    }
+
+    /// Sets the `start_date` query parameter.
+    pub fn start_date(mut self, start_date: chrono::NaiveDate) -> Self {
+        self.start_date = Some(start_date);
+        self
+    }
+
+    /// Sets the `end_date` query parameter.
+    pub fn end_date(mut self, end_date: chrono::NaiveDate) -> Self {
+        self.end_date = Some(end_date);
+        self
+    }
+
+    /// Sets the `timezone` query parameter.
+    pub fn timezone(mut self, timezone: &'a str) -> Self {
+        self.timezone = Some(timezone);
+        self
+    }
+
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Sets the `page_token` query parameter.
+    pub fn page_token(mut self, page_token: &'a str) -> Self {
+        self.page_token = Some(page_token);
+        self
+    }
+
+    /// Sets the `group_by` query parameter.
+    pub fn group_by(mut self, group_by: &'a [String]) -> Self {
+        self.group_by = Some(group_by);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::SinglesendsResponse>> {
+        let Self {
+            client,
+            id,
+            aggregated_by,
+            start_date,
+            end_date,
+            timezone,
+            page_size,
+            page_token,
+            group_by,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = aggregated_by {
+            query_args.push(("aggregated_by".to_string(), v.to_string()));
+        }
+        if let Some(v) = end_date {
+            query_args.push(("end_date".to_string(), v.to_string()));
+        }
+        if let Some(v) = group_by {
+            query_args.push(("group_by".to_string(), v.join(" ")));
+        }
+        if let Some(v) = page_size {
+            query_args.push(("page_size".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_token {
+            query_args.push(("page_token".to_string(), v.to_string()));
+        }
+        if let Some(v) = start_date {
+            query_args.push(("start_date".to_string(), v.to_string()));
+        }
+        if let Some(v) = timezone {
+            query_args.push(("timezone".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/marketing/stats/singlesends/{}?{}",
+                crate::progenitor_support::encode_path(id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`MarketingCampaignsStats::get_automation_link_stat`], set with a builder.
+pub struct MarketingCampaignsStatsGetAutomationLinkStatRequest<'a> {
+    client: &'a MarketingCampaignsStats,
+    id: &'a str,
+    group_by: Option<&'a [String]>,
+    step_ids: Option<&'a [String]>,
+    page_size: Option<i64>,
+    page_token: Option<&'a str>,
+}
+
+impl<'a> MarketingCampaignsStatsGetAutomationLinkStatRequest<'a> {
+    /// Sets the `group_by` query parameter.
+    pub fn group_by(mut self, group_by: &'a [String]) -> Self {
+        self.group_by = Some(group_by);
+        self
+    }
+
+    /// Sets the `step_ids` query parameter.
+    pub fn step_ids(mut self, step_ids: &'a [String]) -> Self {
+        self.step_ids = Some(step_ids);
+        self
+    }
+
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Sets the `page_token` query parameter.
+    pub fn page_token(mut self, page_token: &'a str) -> Self {
+        self.page_token = Some(page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::AutomationsLinkStatsResponse>> {
+        let Self {
+            client,
+            id,
+            group_by,
+            step_ids,
+            page_size,
+            page_token,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = group_by {
+            query_args.push(("group_by".to_string(), v.join(" ")));
+        }
+        if let Some(v) = page_size {
+            query_args.push(("page_size".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_token {
+            query_args.push(("page_token".to_string(), v.to_string()));
+        }
+        if let Some(v) = step_ids {
+            query_args.push(("step_ids".to_string(), v.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/marketing/stats/automations/{}/links?{}",
+                crate::progenitor_support::encode_path(id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`MarketingCampaignsStats::get_singlesend_link_stat`], set with a builder.
+pub struct MarketingCampaignsStatsGetSinglesendLinkStatRequest<'a> {
+    client: &'a MarketingCampaignsStats,
+    id: &'a str,
+    page_size: Option<i64>,
+    page_token: Option<&'a str>,
+    group_by: Option<&'a [String]>,
+    ab_variation_id: Option<&'a str>,
+    ab_phase_id: Option<crate::types::AbPhaseId>,
+}
+
+impl<'a> MarketingCampaignsStatsGetSinglesendLinkStatRequest<'a> {
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Sets the `page_token` query parameter.
+    pub fn page_token(mut self, page_token: &'a str) -> Self {
+        self.page_token = Some(page_token);
+        self
+    }
+
+    /// Sets the `group_by` query parameter.
+    pub fn group_by(mut self, group_by: &'a [String]) -> Self {
+        self.group_by = Some(group_by);
+        self
+    }
+
+    /// Sets the `ab_variation_id` query parameter.
+    pub fn ab_variation_id(mut self, ab_variation_id: &'a str) -> Self {
+        self.ab_variation_id = Some(ab_variation_id);
+        self
+    }
+
+    /// Sets the `ab_phase_id` query parameter.
+    pub fn ab_phase_id(mut self, ab_phase_id: crate::types::AbPhaseId) -> Self {
+        self.ab_phase_id = Some(ab_phase_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::SinglesendsLinkStatsResponse>> {
+        let Self {
+            client,
+            id,
+            page_size,
+            page_token,
+            group_by,
+            ab_variation_id,
+            ab_phase_id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = ab_phase_id {
+            query_args.push(("ab_phase_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = ab_variation_id {
+            query_args.push(("ab_variation_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = group_by {
+            query_args.push(("group_by".to_string(), v.join(" ")));
+        }
+        if let Some(v) = page_size {
+            query_args.push(("page_size".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_token {
+            query_args.push(("page_token".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/marketing/stats/singlesends/{}/links?{}",
+                crate::progenitor_support::encode_path(id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`MarketingCampaignsStats::get_singlesend_stats_export`], set with a builder.
+pub struct MarketingCampaignsStatsGetSinglesendStatsExportRequest<'a> {
+    client: &'a MarketingCampaignsStats,
+    ids: Option<&'a [String]>,
+    timezone: Option<&'a str>,
+}
+
+impl<'a> MarketingCampaignsStatsGetSinglesendStatsExportRequest<'a> {
+    /// Sets the `ids` query parameter.
+    pub fn ids(mut self, ids: &'a [String]) -> Self {
+        self.ids = Some(ids);
+        self
+    }
+
+    /// Sets the `timezone` query parameter.
+    pub fn timezone(mut self, timezone: &'a str) -> Self {
+        self.timezone = Some(timezone);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<String>> {
+        let Self {
+            client,
+            ids,
+            timezone,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = ids {
+            query_args.push(("ids".to_string(), v.join(" ")));
+        }
+        if let Some(v) = timezone {
+            query_args.push(("timezone".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/marketing/stats/singlesends/export?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`MarketingCampaignsStats::get_automations_stats_export`], set with a builder.
+pub struct MarketingCampaignsStatsGetAutomationsStatsExportRequest<'a> {
+    client: &'a MarketingCampaignsStats,
+    ids: Option<&'a [String]>,
+    timezone: Option<&'a str>,
+}
+
+impl<'a> MarketingCampaignsStatsGetAutomationsStatsExportRequest<'a> {
+    /// Sets the `ids` query parameter.
+    pub fn ids(mut self, ids: &'a [String]) -> Self {
+        self.ids = Some(ids);
+        self
+    }
+
+    /// Sets the `timezone` query parameter.
+    pub fn timezone(mut self, timezone: &'a str) -> Self {
+        self.timezone = Some(timezone);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<String>> {
+        let Self {
+            client,
+            ids,
+            timezone,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = ids {
+            query_args.push(("ids".to_string(), v.join(" ")));
+        }
+        if let Some(v) = timezone {
+            query_args.push(("timezone".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/marketing/stats/automations/export?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct MarketingCampaignsStats {
     pub client: Client,
 }
@@ -60,6 +708,31 @@ impl MarketingCampaignsStats {
             )
             .await
     }
+    /**
+     * Get All Automation Stats.
+     *
+     * This function performs a `GET` to the `/marketing/stats/automations` endpoint.
+     *
+     * As opposed to `getall_automation_stats`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * **This endpoint allows you to retrieve stats for all your Automations.**
+     *
+     * By default, all of your Automations will be returned, but you can specify a selection by passing in a comma-separated list of Automation IDs as the value of the query string parameter `automation_ids`.
+     *
+     * Responses are paginated. You can limit the number of responses returned per batch using the `page_size` query string parameter. The default is 50, but you specify a value between 1 and 100.
+     *
+     * You can retrieve a specific page of responses with the `page_token` query string parameter.
+     */
+    pub fn getall_automation_stats_request(
+        &self,
+    ) -> MarketingCampaignsStatsGetallAutomationStatsRequest<'_> {
+        MarketingCampaignsStatsGetallAutomationStatsRequest {
+            client: self,
+            automation_ids: None,
+            page_size: None,
+            page_token: None,
+        }
+    }
     /**
      * Get Automation Stats by ID.
      *
@@ -138,6 +811,36 @@ impl MarketingCampaignsStats {
             )
             .await
     }
+    /**
+     * Get Automation Stats by ID.
+     *
+     * This function performs a `GET` to the `/marketing/stats/automations/{id}` endpoint.
+     *
+     * As opposed to `get_automation_stat`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * **This endpoint allows you to retrieve stats for a single Automation using its ID.**
+     *
+     * Multiple Automation IDs can be retrieved using the "Get All Automation Stats" endpoint. Once you have an ID, this endpoint will return detailed stats for the single automation specified.
+     *
+     * You may constrain the stats returned using the `start_date` and `end_date` query string parameters. You can also use the `group_by` and `aggregated_by` query string parameters to further refine the stats returned.
+     */
+    pub fn get_automation_stat_request<'a>(
+        &'a self,
+        id: &'a str,
+    ) -> MarketingCampaignsStatsGetAutomationStatRequest<'a> {
+        MarketingCampaignsStatsGetAutomationStatRequest {
+            client: self,
+            id,
+            group_by: None,
+            step_ids: None,
+            aggregated_by: None,
+            start_date: None,
+            end_date: None,
+            timezone: None,
+            page_size: None,
+            page_token: None,
+        }
+    }
     /**
      * Get All Single Sends Stats.
      *
@@ -187,6 +890,31 @@ impl MarketingCampaignsStats {
             )
             .await
     }
+    /**
+     * Get All Single Sends Stats.
+     *
+     * This function performs a `GET` to the `/marketing/stats/singlesends` endpoint.
+     *
+     * As opposed to `getall_singlesend_stats`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * **This endpoint allows you to retrieve stats for all your Single Sends.**
+     *
+     * By default, all of your Single Sends will be returned, but you can specify a selection by passing in a comma-separated list of Single Send IDs as the value of the query string parameter `singlesend_ids`.
+     *
+     * Responses are paginated. You can limit the number of responses returned per batch using the `page_size` query string parameter. The default is 50, but you specify a value between 1 and 100.
+     *
+     * You can retrieve a specific page of responses with the `page_token` query string parameter.
+     */
+    pub fn getall_singlesend_stats_request(
+        &self,
+    ) -> MarketingCampaignsStatsGetallSinglesendStatsRequest<'_> {
+        MarketingCampaignsStatsGetallSinglesendStatsRequest {
+            client: self,
+            singlesend_ids: None,
+            page_size: None,
+            page_token: None,
+        }
+    }
     /**
      * Get Single Send Stats by ID.
      *
@@ -260,6 +988,35 @@ impl MarketingCampaignsStats {
             )
             .await
     }
+    /**
+     * Get Single Send Stats by ID.
+     *
+     * This function performs a `GET` to the `/marketing/stats/singlesends/{id}` endpoint.
+     *
+     * As opposed to `get_singlesend_stat`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * **This endpoint allows you to retrieve stats for an individual Single Send using a Single Send ID.**
+     *
+     * Multiple Single Send IDs can be retrieved using the "Get All Single Sends Stats" endpoint. Once you have an ID, this endpoint will return detailed stats for the Single Send specified.
+     *
+     * You may constrain the stats returned using the `start_date` and `end_date` query string parameters. You can also use the `group_by` and `aggregated_by` query string parameters to further refine the stats returned.
+     */
+    pub fn get_singlesend_stat_request<'a>(
+        &'a self,
+        id: &'a str,
+    ) -> MarketingCampaignsStatsGetSinglesendStatRequest<'a> {
+        MarketingCampaignsStatsGetSinglesendStatRequest {
+            client: self,
+            id,
+            aggregated_by: None,
+            start_date: None,
+            end_date: None,
+            timezone: None,
+            page_size: None,
+            page_token: None,
+            group_by: None,
+        }
+    }
     /**
      * Get Automation Click Tracking Stats by ID.
      *
@@ -320,6 +1077,34 @@ impl MarketingCampaignsStats {
             )
             .await
     }
+    /**
+     * Get Automation Click Tracking Stats by ID.
+     *
+     * This function performs a `GET` to the `/marketing/stats/automations/{id}/links` endpoint.
+     *
+     * As opposed to `get_automation_link_stat`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * **This endpoint lets you retrieve click-tracking stats for a single Automation**.
+     *
+     * The stats returned list the URLs embedded in your Automation and the number of clicks each one received.
+     *
+     * Responses are paginated. You can limit the number of responses returned per batch using the `page_size` query string parameter. The default is 50, but you specify a value between 1 and 100.
+     *
+     * You can retrieve a specific page of responses with the `page_token` query string parameter.
+     */
+    pub fn get_automation_link_stat_request<'a>(
+        &'a self,
+        id: &'a str,
+    ) -> MarketingCampaignsStatsGetAutomationLinkStatRequest<'a> {
+        MarketingCampaignsStatsGetAutomationLinkStatRequest {
+            client: self,
+            id,
+            group_by: None,
+            step_ids: None,
+            page_size: None,
+            page_token: None,
+        }
+    }
     /**
      * Get Single Send Click Tracking Stats by ID.
      *
@@ -385,6 +1170,35 @@ impl MarketingCampaignsStats {
             )
             .await
     }
+    /**
+     * Get Single Send Click Tracking Stats by ID.
+     *
+     * This function performs a `GET` to the `/marketing/stats/singlesends/{id}/links` endpoint.
+     *
+     * As opposed to `get_singlesend_link_stat`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * **This endpoint lets you retrieve click-tracking stats for one Single Send**.
+     *
+     * The stats returned list the URLs embedded in the specified Single Send and the number of clicks each one received.
+     *
+     * Responses are paginated. You can limit the number of responses returned per batch using the `page_size` query string parameter. The default is 50, but you specify a value between 1 and 100.
+     *
+     * You can retrieve a specific page of responses with the `page_token` query string parameter.
+     */
+    pub fn get_singlesend_link_stat_request<'a>(
+        &'a self,
+        id: &'a str,
+    ) -> MarketingCampaignsStatsGetSinglesendLinkStatRequest<'a> {
+        MarketingCampaignsStatsGetSinglesendLinkStatRequest {
+            client: self,
+            id,
+            page_size: None,
+            page_token: None,
+            group_by: None,
+            ab_variation_id: None,
+            ab_phase_id: None,
+        }
+    }
     /**
      * Export Single Send Stats.
      *
@@ -428,6 +1242,28 @@ impl MarketingCampaignsStats {
             )
             .await
     }
+    /**
+     * Export Single Send Stats.
+     *
+     * This function performs a `GET` to the `/marketing/stats/singlesends/export` endpoint.
+     *
+     * As opposed to `get_singlesend_stats_export`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * **This endpoint allows you to export Single Send stats as .CSV data**.
+     *
+     * You can specify one Single Send or many: include as many Single Send IDs as you need, separating them with commas, as the value of the `ids` query string paramter.
+     *
+     * The data is returned as plain text response but in .CSV format, so your application making the call can present the information in whatever way is most appropriate, or just save the data as a .csv file.
+     */
+    pub fn get_singlesend_stats_export_request(
+        &self,
+    ) -> MarketingCampaignsStatsGetSinglesendStatsExportRequest<'_> {
+        MarketingCampaignsStatsGetSinglesendStatsExportRequest {
+            client: self,
+            ids: None,
+            timezone: None,
+        }
+    }
     /**
      * Export Automation Stats.
      *
@@ -471,4 +1307,26 @@ impl MarketingCampaignsStats {
             )
             .await
     }
+    /**
+     * Export Automation Stats.
+     *
+     * This function performs a `GET` to the `/marketing/stats/automations/export` endpoint.
+     *
+     * As opposed to `get_automations_stats_export`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * **This endpoint allows you to export Automation stats as CSV data**.
+     *
+     * You can specify one Automation or many: include as many Automation IDs as you need, separating them with commas, as the value of the `ids` query string paramter.
+     *
+     * The data is returned as plain text response but in CSV format, so your application making the call can present the information in whatever way is most appropriate, or just save the data as a `.csv` file.
+     */
+    pub fn get_automations_stats_export_request(
+        &self,
+    ) -> MarketingCampaignsStatsGetAutomationsStatsExportRequest<'_> {
+        MarketingCampaignsStatsGetAutomationsStatsExportRequest {
+            client: self,
+            ids: None,
+            timezone: None,
+        }
+    }
 }
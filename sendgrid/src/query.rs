@@ -1,6 +1,50 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Query::get_messages`], set with a builder.
+pub struct QueryGetMessagesRequest<'a> {
+    client: &'a Query,
+    query: &'a str,
+    limit: Option<f64>,
+}
+
+impl<'a> QueryGetMessagesRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: f64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::GetMessagesResponse>> {
+        let Self {
+            client,
+            query,
+            limit,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if !query.is_empty() {
+            query_args.push(("query".to_string(), query.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(&format!("/messages?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct Query {
     pub client: Client,
 }
@@ -89,6 +133,61 @@ impl Query {
             )
             .await
     }
+    /**
+     * Filter all messages.
+     *
+     * This function performs a `GET` to the `/messages` endpoint.
+     *
+     * As opposed to `get_messages`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * This is **BETA** functionality. You may not have access, and we reserve the right to change functionality without notice.
+     *
+     * Filter all messages to search your Email Activity. All queries need to be [URL encoded](https://meyerweb.com/eric/tools/dencoder/), and have this format:
+     *
+     * `query={query_type}="{query_content}"`
+     *
+     * encoded, this would look like this:
+     *
+     * `query=type%3D%22query_content%22`
+     *
+     * for example:
+     *
+     * Filter by a specific email - `query=to_email%3D%22example%40example.com%22`
+     *
+     * Filter by subject line - `query=subject%3d%22A%20Great%20Subject%22`
+     *
+     * **Full list of basic query types and examples:**
+     *
+     *
+     * | **Filter query**    | **Unencoded Example** (put this one into the try it out query - it'll automatically encode it for you) | **Encoded Example** (use this one in your code)                        |
+     * |-----------------|----------------------------------------------------------------------------------------------------------------------------|--------------------------------------------------------------------|
+     * | msg_id          | msg_id=“filter0307p1las1-16816-5A023E36-1.0”                                                                               | msg_id%3D%22filter0307p1las1-16816-5A023E36-1.0%22           |
+     * | from_email      | from_email=“testing@sendgrid.net”                                                                                          | from_email%3D%22testing%40sendgrid.net%22                    |
+     * | subject         | subject="This is a subject test"                                                                                      | subject%22This%20is%20a%20subject%20test%22                  |
+     * | to_email        | to_email="example@example.com"                                                                                       | to_email%3D%22example%40example.com%22                       |
+     * | status          |                                                                                                                            | status%22processed%22                                        |
+     * | template_id     |                                                                                                                            |                                                                    |
+     * | asm_group_id    |                                                                                                                            |                                                                    |
+     * | api_key_id      |                                                                                                                            |                                                                    |
+     * | events          | status="processed"                                                                                                   | status%3D%22processed%22                                     |
+     * | originating_ip  |                                                                                                                            |                                                                    |
+     * | categories      |                                                                                                                            |                                                                    |
+     * | unique_args     |                                                                                                                            |                                                                    |
+     * | outbound_ip     |                                                                                                                            |                                                                    |
+     * | last_event_time | last_event_time=“2017-11-07T23:13:58Z”                                                                               | last_event_time%3D%E2%80%9C2017-11-07T23%3A13%3A58Z%E2%80%9D |
+     * | clicks          | clicks="0"                                                                                                           | clicks%3D%220%22                                             |
+     *
+     * For information about building compound queries, and for the full query language functionality, see the [query language reference](https://docs.google.com/a/sendgrid.com/document/d/1fWoKTFNfg5UUsB6t9KuIcSo9CetKF_T0bGfWJ_gdPCs/edit?usp=sharing).
+     *
+     * Coming soon, example compound queries: limit + to email + date
+     */
+    pub fn get_messages_request<'a>(&'a self, query: &'a str) -> QueryGetMessagesRequest<'a> {
+        QueryGetMessagesRequest {
+            client: self,
+            query,
+            limit: None,
+        }
+    }
     /**
      * Filter messages by message ID.
      *
@@ -1,6 +1,69 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`ReverseDns::get_whitelabel_ips`], set with a builder.
+pub struct ReverseDnsGetWhitelabelIpsRequest<'a> {
+    client: &'a ReverseDns,
+    limit: Option<i64>,
+    offset: Option<i64>,
+    ip: Option<&'a str>,
+}
+
+impl<'a> ReverseDnsGetWhitelabelIpsRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Sets the `ip` query parameter.
+    pub fn ip(mut self, ip: &'a str) -> Self {
+        self.ip = Some(ip);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::ReverseDns>>> {
+        let Self {
+            client,
+            limit,
+            offset,
+            ip,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = ip {
+            query_args.push(("ip".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = offset {
+            query_args.push(("offset".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/whitelabel/ips?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct ReverseDns {
     pub client: Client,
 }
@@ -61,6 +124,29 @@ impl ReverseDns {
             )
             .await
     }
+    /**
+     * Retrieve all reverse DNS records.
+     *
+     * This function performs a `GET` to the `/whitelabel/ips` endpoint.
+     *
+     * As opposed to `get_whitelabel_ips`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * **This endpoint allows you to retrieve all of the Reverse DNS records created by this account.**
+     *
+     * You may include a search key by using the `ip` query string parameter. This enables you to perform a prefix search for a given IP segment (e.g., `?ip="192."`).
+     *
+     * Use the `limit` query string parameter to reduce the number of records returned. All records will be returned if you have fewer records than the specified limit.
+     *
+     * The `offset` query string parameter allows you to specify a non-zero index from which records will be returned. For example, if you have ten records, `?offset=5` will return the last five records (at indexes 5 through 9). The list starts at index zero.
+     */
+    pub fn get_whitelabel_ips_request(&self) -> ReverseDnsGetWhitelabelIpsRequest<'_> {
+        ReverseDnsGetWhitelabelIpsRequest {
+            client: self,
+            limit: None,
+            offset: None,
+            ip: None,
+        }
+    }
     /**
      * Retrieve all reverse DNS records.
      *
@@ -102,6 +188,39 @@ impl ReverseDns {
             )
             .await
     }
+    /**
+     * Retrieve all reverse DNS records.
+     *
+     * This function performs a `GET` to the `/whitelabel/ips` endpoint.
+     *
+     * As opposed to `get_all_whitelabel_ips`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * **This endpoint allows you to retrieve all of the Reverse DNS records created by this account.**
+     *
+     * You may include a search key by using the `ip` query string parameter. This enables you to perform a prefix search for a given IP segment (e.g., `?ip="192."`).
+     *
+     * Use the `limit` query string parameter to reduce the number of records returned. All records will be returned if you have fewer records than the specified limit.
+     *
+     * The `offset` query string parameter allows you to specify a non-zero index from which records will be returned. For example, if you have ten records, `?offset=5` will return the last five records (at indexes 5 through 9). The list starts at index zero.
+     */
+    pub fn get_all_whitelabel_ips_stream(
+        &self,
+        offset: i64,
+        ip: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::ReverseDns>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !ip.is_empty() {
+            query_args.push(("ip".to_string(), ip.to_string()));
+        }
+        if offset > 0 {
+            query_args.push(("offset".to_string(), offset.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/whitelabel/ips?{}", query_), None);
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Set up reverse DNS.
      *
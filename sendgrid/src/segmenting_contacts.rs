@@ -1,6 +1,108 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`SegmentingContacts::get_marketing_segments`], set with a builder.
+pub struct SegmentingContactsGetMarketingSegmentsRequest<'a> {
+    client: &'a SegmentingContacts,
+    parent_list_ids: Option<&'a str>,
+    no_parent_list_id: Option<bool>,
+}
+
+impl<'a> SegmentingContactsGetMarketingSegmentsRequest<'a> {
+    /// Sets the `parent_list_ids` query parameter.
+    pub fn parent_list_ids(mut self, parent_list_ids: &'a str) -> Self {
+        self.parent_list_ids = Some(parent_list_ids);
+        self
+    }
+
+    /// Sets the `no_parent_list_id` query parameter.
+    pub fn no_parent_list_id(mut self, no_parent_list_id: bool) -> Self {
+        self.no_parent_list_id = Some(no_parent_list_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::GetMarketingSegmentsResponse>> {
+        let Self {
+            client,
+            parent_list_ids,
+            no_parent_list_id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = no_parent_list_id {
+            query_args.push(("no_parent_list_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = parent_list_ids {
+            query_args.push(("parent_list_ids".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/marketing/segments?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`SegmentingContacts::get_marketing_segments_segment`], set with a builder.
+pub struct SegmentingContactsGetMarketingSegmentsSegmentRequest<'a> {
+    client: &'a SegmentingContacts,
+    segment_id: &'a str,
+    query_json: Option<bool>,
+}
+
+impl<'a> SegmentingContactsGetMarketingSegmentsSegmentRequest<'a> {
+    /// Sets the `query_json` query parameter.
+    pub fn query_json(mut self, query_json: bool) -> Self {
+        self.query_json = Some(query_json);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::FullSegmentAllOf>> {
+        let Self {
+            client,
+            segment_id,
+            query_json,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = query_json {
+            query_args.push(("query_json".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/marketing/segments/{}?{}",
+                crate::progenitor_support::encode_path(segment_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct SegmentingContacts {
     pub client: Client,
 }
@@ -61,6 +163,33 @@ impl SegmentingContacts {
             )
             .await
     }
+    /**
+     * Get List of Segments.
+     *
+     * This function performs a `GET` to the `/marketing/segments` endpoint.
+     *
+     * As opposed to `get_marketing_segments`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * **This endpoint allows you to retrieve a list of segments.**
+     *
+     * The query param `parent_list_ids` is treated as a filter.  Any match will be returned.  0 matches will return a response code of 200 with an empty `results` array.
+     *
+     * `parent_list_ids` | `no_parent_list_id` | `result`
+     * -----------------:|:--------------------:|:-------------
+     * empty | false | all segments
+     * values | false | segments filtered by list_ids
+     * values | true | segments filtered by list_ids and segments with no parent list_ids
+     * empty | true | segments with no parent list_ids
+     */
+    pub fn get_marketing_segments_request(
+        &self,
+    ) -> SegmentingContactsGetMarketingSegmentsRequest<'_> {
+        SegmentingContactsGetMarketingSegmentsRequest {
+            client: self,
+            parent_list_ids: None,
+            no_parent_list_id: None,
+        }
+    }
     /**
      * Create Segment.
      *
@@ -122,6 +251,25 @@ impl SegmentingContacts {
             )
             .await
     }
+    /**
+     * Get Segment by ID.
+     *
+     * This function performs a `GET` to the `/marketing/segments/{segment_id}` endpoint.
+     *
+     * As opposed to `get_marketing_segments_segment`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * **This endpoint allows you to retrieve a single segment by ID.**
+     */
+    pub fn get_marketing_segments_segment_request<'a>(
+        &'a self,
+        segment_id: &'a str,
+    ) -> SegmentingContactsGetMarketingSegmentsSegmentRequest<'a> {
+        SegmentingContactsGetMarketingSegmentsSegmentRequest {
+            client: self,
+            segment_id,
+            query_json: None,
+        }
+    }
     /**
      * Delete Segment.
      *
@@ -1,6 +1,106 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`SegmentingContactsBeta::get_segments`], set with a builder.
+pub struct SegmentingContactsBetaGetSegmentsRequest<'a> {
+    client: &'a SegmentingContactsBeta,
+    parent_list_ids: Option<&'a str>,
+    no_parent_list_id: Option<bool>,
+}
+
+impl<'a> SegmentingContactsBetaGetSegmentsRequest<'a> {
+    /// Sets the `parent_list_ids` query parameter.
+    pub fn parent_list_ids(mut self, parent_list_ids: &'a str) -> Self {
+        self.parent_list_ids = Some(parent_list_ids);
+        self
+    }
+
+    /// Sets the `no_parent_list_id` query parameter.
+    pub fn no_parent_list_id(mut self, no_parent_list_id: bool) -> Self {
+        self.no_parent_list_id = Some(no_parent_list_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::AllSegmentsResponse>> {
+        let Self {
+            client,
+            parent_list_ids,
+            no_parent_list_id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = no_parent_list_id {
+            query_args.push(("no_parent_list_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = parent_list_ids {
+            query_args.push(("parent_list_ids".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/marketing/segments/2.0?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`SegmentingContactsBeta::get_segments_segment`], set with a builder.
+pub struct SegmentingContactsBetaGetSegmentsSegmentRequest<'a> {
+    client: &'a SegmentingContactsBeta,
+    segment_id: &'a str,
+    contacts_sample: Option<bool>,
+}
+
+impl<'a> SegmentingContactsBetaGetSegmentsSegmentRequest<'a> {
+    /// Sets the `contacts_sample` query parameter.
+    pub fn contacts_sample(mut self, contacts_sample: bool) -> Self {
+        self.contacts_sample = Some(contacts_sample);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::SegmentResponse>> {
+        let Self {
+            client,
+            segment_id,
+            contacts_sample,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = contacts_sample {
+            query_args.push(("contacts_sample".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/marketing/segments/2.0/{}?{}",
+                crate::progenitor_support::encode_path(segment_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct SegmentingContactsBeta {
     pub client: Client,
 }
@@ -61,6 +161,31 @@ impl SegmentingContactsBeta {
             )
             .await
     }
+    /**
+     * Get List of Segments.
+     *
+     * This function performs a `GET` to the `/marketing/segments/2.0` endpoint.
+     *
+     * As opposed to `get_segments`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * **The Segmentation V2 API is currently in private beta. If you'd like to be added to the beta, please fill out this [form](https://docs.google.com/forms/d/e/1FAIpQLSd5zwC9dRk8lAp1oTWjdGc-aSY69flW_7wnutvKBhpUluSnfQ/viewform)**
+     *
+     * The query param `parent_list_ids` is treated as a filter.  Any match will be returned.  0 matches will return a response code of 200 with an empty `results` array.
+     *
+     * `parent_list_ids` | `no_parent_list_id` | `result`
+     * -----------------:|:--------------------:|:-------------
+     * empty | false | all segments
+     * values | false | segments filtered by list_ids
+     * values | true | segments filtered by list_ids and segments with no parent list_ids
+     * empty | true | segments with no parent list_ids
+     */
+    pub fn get_segments_request(&self) -> SegmentingContactsBetaGetSegmentsRequest<'_> {
+        SegmentingContactsBetaGetSegmentsRequest {
+            client: self,
+            parent_list_ids: None,
+            no_parent_list_id: None,
+        }
+    }
     /**
      * Create Segment.
      *
@@ -124,6 +249,25 @@ impl SegmentingContactsBeta {
             )
             .await
     }
+    /**
+     * Get Segment by ID.
+     *
+     * This function performs a `GET` to the `/marketing/segments/2.0/{segment_id}` endpoint.
+     *
+     * As opposed to `get_segments_segment`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * **The Segmentation V2 API is currently in private beta. If you'd like to be added to the beta, please fill out this [form](https://docs.google.com/forms/d/e/1FAIpQLSd5zwC9dRk8lAp1oTWjdGc-aSY69flW_7wnutvKBhpUluSnfQ/viewform)**
+     */
+    pub fn get_segments_segment_request<'a>(
+        &'a self,
+        segment_id: &'a str,
+    ) -> SegmentingContactsBetaGetSegmentsSegmentRequest<'a> {
+        SegmentingContactsBetaGetSegmentsSegmentRequest {
+            client: self,
+            segment_id,
+            contacts_sample: None,
+        }
+    }
     /**
      * Delete segment.
      *
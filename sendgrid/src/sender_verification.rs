@@ -1,6 +1,71 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`SenderVerification::get_verified_senders`], set with a builder.
+pub struct SenderVerificationGetVerifiedSendersRequest<'a> {
+    client: &'a SenderVerification,
+    limit: Option<f64>,
+    last_seen_id: Option<f64>,
+    id: Option<i64>,
+}
+
+impl<'a> SenderVerificationGetVerifiedSendersRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: f64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `lastSeenID` query parameter.
+    pub fn last_seen_id(mut self, last_seen_id: f64) -> Self {
+        self.last_seen_id = Some(last_seen_id);
+        self
+    }
+
+    /// Sets the `id` query parameter.
+    pub fn id(mut self, id: i64) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::GetVerifiedSendersResponse>> {
+        let Self {
+            client,
+            limit,
+            last_seen_id,
+            id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = id {
+            query_args.push(("id".to_string(), v.to_string()));
+        }
+        if let Some(v) = last_seen_id {
+            query_args.push(("lastSeenID".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/verified_senders?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct SenderVerification {
     pub client: Client,
 }
@@ -112,6 +177,31 @@ impl SenderVerification {
             )
             .await
     }
+    /**
+     * Get All Verified Senders.
+     *
+     * This function performs a `GET` to the `/verified_senders` endpoint.
+     *
+     * As opposed to `get_verified_senders`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * **This endpoint allows you to retrieve all the Sender Identities associated with an account.**
+     *
+     * This endpoint will return both verified and unverified senders.
+     *
+     * You can limit the number of results returned using the `limit`, `lastSeenID`, and `id` query string parameters.
+     *
+     * * `limit` allows you to specify an exact number of Sender Identities to return.
+     * * `lastSeenID` will return senders with an ID number occuring after the passed in ID. In other words, the `lastSeenID` provides a starting point from which SendGrid will iterate to find Sender Identities associated with your account.
+     * * `id` will return information about only the Sender Identity passed in the request.
+     */
+    pub fn get_verified_senders_request(&self) -> SenderVerificationGetVerifiedSendersRequest<'_> {
+        SenderVerificationGetVerifiedSendersRequest {
+            client: self,
+            limit: None,
+            last_seen_id: None,
+            id: None,
+        }
+    }
     /**
      * Create Verified Sender Request.
      *
@@ -1,6 +1,60 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`SettingsMail::get_mail_settings`], set with a builder.
+pub struct SettingsMailGetMailSettingsRequest<'a> {
+    client: &'a SettingsMail,
+    limit: Option<i64>,
+    offset: Option<i64>,
+}
+
+impl<'a> SettingsMailGetMailSettingsRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::GetMailSettingsResponse>> {
+        let Self {
+            client,
+            limit,
+            offset,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = offset {
+            query_args.push(("offset".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/mail_settings?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct SettingsMail {
     pub client: Client,
 }
@@ -50,6 +104,24 @@ impl SettingsMail {
             )
             .await
     }
+    /**
+     * Retrieve all mail settings.
+     *
+     * This function performs a `GET` to the `/mail_settings` endpoint.
+     *
+     * As opposed to `get_mail_settings`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * **This endpoint allows you to retrieve a list of all mail settings.**
+     *
+     * Each setting will be returned with an `enabled` status set to `true` or `false` and a short description that explains what the setting does.
+     */
+    pub fn get_mail_settings_request(&self) -> SettingsMailGetMailSettingsRequest<'_> {
+        SettingsMailGetMailSettingsRequest {
+            client: self,
+            limit: None,
+            offset: None,
+        }
+    }
     /**
      * Retrieve address whitelist mail settings.
      *
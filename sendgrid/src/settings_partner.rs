@@ -1,6 +1,60 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`SettingsPartner::get_partner_settings`], set with a builder.
+pub struct SettingsPartnerGetPartnerSettingsRequest<'a> {
+    client: &'a SettingsPartner,
+    limit: Option<i64>,
+    offset: Option<i64>,
+}
+
+impl<'a> SettingsPartnerGetPartnerSettingsRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::GetPartnerSettingsResponse>> {
+        let Self {
+            client,
+            limit,
+            offset,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = offset {
+            query_args.push(("offset".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/partner_settings?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct SettingsPartner {
     pub client: Client,
 }
@@ -111,4 +165,22 @@ impl SettingsPartner {
             )
             .await
     }
+    /**
+     * Returns a list of all partner settings.
+     *
+     * This function performs a `GET` to the `/partner_settings` endpoint.
+     *
+     * As opposed to `get_partner_settings`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * **This endpoint allows you to retrieve a list of all partner settings that you can enable.**
+     *
+     * Our partner settings allow you to integrate your SendGrid account with our partners to increase your SendGrid experience and functionality. For more information about our partners, and how you can begin integrating with them, please visit our [Partners documentation](https://sendgrid.com/docs/ui/account-and-settings/partners/).
+     */
+    pub fn get_partner_settings_request(&self) -> SettingsPartnerGetPartnerSettingsRequest<'_> {
+        SettingsPartnerGetPartnerSettingsRequest {
+            client: self,
+            limit: None,
+            offset: None,
+        }
+    }
 }
@@ -1,6 +1,154 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`SingleSends::get_marketing_singlesends`], set with a builder.
+pub struct SingleSendsGetMarketingSinglesendsRequest<'a> {
+    client: &'a SingleSends,
+    page_size: Option<i64>,
+    page_token: Option<&'a str>,
+}
+
+impl<'a> SingleSendsGetMarketingSinglesendsRequest<'a> {
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Sets the `page_token` query parameter.
+    pub fn page_token(mut self, page_token: &'a str) -> Self {
+        self.page_token = Some(page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::GetMarketingSinglesendsResponse>> {
+        let Self {
+            client,
+            page_size,
+            page_token,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page_size {
+            query_args.push(("page_size".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_token {
+            query_args.push(("page_token".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/marketing/singlesends?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`SingleSends::delete_marketing_singlesends`], set with a builder.
+pub struct SingleSendsDeleteMarketingSinglesendsRequest<'a> {
+    client: &'a SingleSends,
+    ids: Option<&'a [String]>,
+}
+
+impl<'a> SingleSendsDeleteMarketingSinglesendsRequest<'a> {
+    /// Sets the `ids` query parameter.
+    pub fn ids(mut self, ids: &'a [String]) -> Self {
+        self.ids = Some(ids);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self { client, ids } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = ids {
+            query_args.push(("ids".to_string(), v.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/marketing/singlesends?{}", query_), None);
+
+        client
+            .client
+            .delete(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`SingleSends::post_marketing_singlesends_search`], set with a builder.
+pub struct SingleSendsPostMarketingSinglesendsSearchRequest<'a> {
+    client: &'a SingleSends,
+    page_size: Option<i64>,
+    page_token: Option<&'a str>,
+    body: &'a crate::types::SinglesendSearch,
+}
+
+impl<'a> SingleSendsPostMarketingSinglesendsSearchRequest<'a> {
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Sets the `page_token` query parameter.
+    pub fn page_token(mut self, page_token: &'a str) -> Self {
+        self.page_token = Some(page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::GetMarketingSinglesendsResponse>> {
+        let Self {
+            client,
+            page_size,
+            page_token,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page_size {
+            query_args.push(("page_size".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_token {
+            query_args.push(("page_token".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/marketing/singlesends/search?{}", query_), None);
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
 pub struct SingleSends {
     pub client: Client,
 }
@@ -51,6 +199,26 @@ impl SingleSends {
             )
             .await
     }
+    /**
+     * Get All Single Sends.
+     *
+     * This function performs a `GET` to the `/marketing/singlesends` endpoint.
+     *
+     * As opposed to `get_marketing_singlesends`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * **This endpoint allows you to retrieve all your Single Sends.**
+     *
+     * Returns all of your Single Sends with condensed details about each, including the Single Sends' IDs. For more details about an individual Single Send, pass the Single Send's ID to the `/marketing/singlesends/{id}` endpoint.
+     */
+    pub fn get_marketing_singlesends_request(
+        &self,
+    ) -> SingleSendsGetMarketingSinglesendsRequest<'_> {
+        SingleSendsGetMarketingSinglesendsRequest {
+            client: self,
+            page_size: None,
+            page_token: None,
+        }
+    }
     /**
      * Create Single Send.
      *
@@ -112,6 +280,27 @@ impl SingleSends {
             )
             .await
     }
+    /**
+     * Bulk Delete Single Sends.
+     *
+     * This function performs a `DELETE` to the `/marketing/singlesends` endpoint.
+     *
+     * As opposed to `delete_marketing_singlesends`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * **This endpoint allows you to delete multiple Single Sends using an array of Single Sends IDs.**
+     *
+     * To first retrieve all your Single Sends' IDs, you can make a GET request to the `/marketing/singlensends` endpoint.
+     *
+     * Please note that a DELETE request is permanent, and your Single Sends will not be recoverable after deletion.
+     */
+    pub fn delete_marketing_singlesends_request(
+        &self,
+    ) -> SingleSendsDeleteMarketingSinglesendsRequest<'_> {
+        SingleSendsDeleteMarketingSinglesendsRequest {
+            client: self,
+            ids: None,
+        }
+    }
     /**
      * Get Single Send by ID.
      *
@@ -293,6 +482,42 @@ impl SingleSends {
             )
             .await
     }
+    /**
+     * Get Single Sends Search.
+     *
+     * This function performs a `POST` to the `/marketing/singlesends/search` endpoint.
+     *
+     * As opposed to `post_marketing_singlesends_search`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * **This endpoint allows you to search for Single Sends based on specified criteria.**
+     *
+     * You can search for Single Sends by passing a combination of values using the `name`, `status`, and `categories` request body fields.
+     *
+     * For example, if you want to search for all Single Sends that are "drafts" or "scheduled" and also associated with the category "shoes," your request body may look like the example below.
+     *
+     * ```javascript
+     * {
+     *   "status": [
+     *     "draft",
+     *     "scheduled"
+     *   ],
+     *   "categories": [
+     *     "shoes"
+     *   ],
+     * }
+     * ```
+     */
+    pub fn post_marketing_singlesends_search_request<'a>(
+        &'a self,
+        body: &'a crate::types::SinglesendSearch,
+    ) -> SingleSendsPostMarketingSinglesendsSearchRequest<'a> {
+        SingleSendsPostMarketingSinglesendsSearchRequest {
+            client: self,
+            page_size: None,
+            page_token: None,
+            body,
+        }
+    }
     /**
      * Schedule Single Send.
      *
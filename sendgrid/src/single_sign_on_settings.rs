@@ -1,6 +1,140 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`SingleSignOnSettings::get_sso_integrations`], set with a builder.
+pub struct SingleSignOnSettingsGetSsoIntegrationsRequest<'a> {
+    client: &'a SingleSignOnSettings,
+    si: Option<bool>,
+}
+
+impl<'a> SingleSignOnSettingsGetSsoIntegrationsRequest<'a> {
+    /// Sets the `si` query parameter.
+    pub fn si(mut self, si: bool) -> Self {
+        self.si = Some(si);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::SsoIntegrationAllOf>>> {
+        let Self { client, si } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = si {
+            query_args.push(("si".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/sso/integrations?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`SingleSignOnSettings::get_sso_integration`], set with a builder.
+pub struct SingleSignOnSettingsGetSsoIntegrationRequest<'a> {
+    client: &'a SingleSignOnSettings,
+    id: &'a str,
+    si: Option<bool>,
+}
+
+impl<'a> SingleSignOnSettingsGetSsoIntegrationRequest<'a> {
+    /// Sets the `si` query parameter.
+    pub fn si(mut self, si: bool) -> Self {
+        self.si = Some(si);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::SsoIntegrationAllOf>> {
+        let Self { client, id, si } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = si {
+            query_args.push(("si".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/sso/integrations/{}?{}",
+                crate::progenitor_support::encode_path(id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`SingleSignOnSettings::patch_sso_integrations`], set with a builder.
+pub struct SingleSignOnSettingsPatchSsoIntegrationsRequest<'a> {
+    client: &'a SingleSignOnSettings,
+    id: &'a str,
+    si: Option<bool>,
+    body: &'a crate::types::CreateIntegrationRequest,
+}
+
+impl<'a> SingleSignOnSettingsPatchSsoIntegrationsRequest<'a> {
+    /// Sets the `si` query parameter.
+    pub fn si(mut self, si: bool) -> Self {
+        self.si = Some(si);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::SsoIntegrationAllOf>> {
+        let Self {
+            client,
+            id,
+            si,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = si {
+            query_args.push(("si".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/sso/integrations/{}?{}",
+                crate::progenitor_support::encode_path(id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .patch(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct SingleSignOnSettings {
     pub client: Client,
 }
@@ -46,6 +180,25 @@ impl SingleSignOnSettings {
             )
             .await
     }
+    /**
+     * Get All SSO Integrations.
+     *
+     * This function performs a `GET` to the `/sso/integrations` endpoint.
+     *
+     * As opposed to `get_sso_integrations`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * **This endpoint allows you to retrieve all SSO integrations tied to your Twilio SendGrid account.**
+     *
+     * The IDs returned by this endpoint can be used by the APIs additional endpoints to modify your SSO integrations.
+     */
+    pub fn get_sso_integrations_request(
+        &self,
+    ) -> SingleSignOnSettingsGetSsoIntegrationsRequest<'_> {
+        SingleSignOnSettingsGetSsoIntegrationsRequest {
+            client: self,
+            si: None,
+        }
+    }
     /**
      * Get All SSO Integrations.
      *
@@ -79,6 +232,32 @@ impl SingleSignOnSettings {
             )
             .await
     }
+    /**
+     * Get All SSO Integrations.
+     *
+     * This function performs a `GET` to the `/sso/integrations` endpoint.
+     *
+     * As opposed to `get_all_sso_integrations`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * **This endpoint allows you to retrieve all SSO integrations tied to your Twilio SendGrid account.**
+     *
+     * The IDs returned by this endpoint can be used by the APIs additional endpoints to modify your SSO integrations.
+     */
+    pub fn get_all_sso_integrations_stream(
+        &self,
+        si: bool,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::SsoIntegrationAllOf>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if si {
+            query_args.push(("si".to_string(), si.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/sso/integrations?{}", query_), None);
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Create an SSO Integration.
      *
@@ -142,6 +321,27 @@ impl SingleSignOnSettings {
             )
             .await
     }
+    /**
+     * Get an SSO Integration.
+     *
+     * This function performs a `GET` to the `/sso/integrations/{id}` endpoint.
+     *
+     * As opposed to `get_sso_integration`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * **This endpoint allows you to retrieve an SSO integration by ID.**
+     *
+     * You can retrieve the IDs for your configurations from the response provided by the "Get All SSO Integrations" endpoint.
+     */
+    pub fn get_sso_integration_request<'a>(
+        &'a self,
+        id: &'a str,
+    ) -> SingleSignOnSettingsGetSsoIntegrationRequest<'a> {
+        SingleSignOnSettingsGetSsoIntegrationRequest {
+            client: self,
+            id,
+            si: None,
+        }
+    }
     /**
      * Delete an SSO Integration.
      *
@@ -211,4 +411,27 @@ impl SingleSignOnSettings {
             )
             .await
     }
+    /**
+     * Update an SSO Integration.
+     *
+     * This function performs a `PATCH` to the `/sso/integrations/{id}` endpoint.
+     *
+     * As opposed to `patch_sso_integrations`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * **This endpoint allows you to modify an exisiting SSO integration.**
+     *
+     * You can retrieve the IDs for your configurations from the response provided by the "Get All SSO Integrations" endpoint.
+     */
+    pub fn patch_sso_integrations_request<'a>(
+        &'a self,
+        id: &'a str,
+        body: &'a crate::types::CreateIntegrationRequest,
+    ) -> SingleSignOnSettingsPatchSsoIntegrationsRequest<'a> {
+        SingleSignOnSettingsPatchSsoIntegrationsRequest {
+            client: self,
+            id,
+            si: None,
+            body,
+        }
+    }
 }
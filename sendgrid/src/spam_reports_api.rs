@@ -1,6 +1,82 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`SpamReportsApi::get_suppression_spam_reports`], set with a builder.
+pub struct SpamReportsApiGetSuppressionSpamReportsRequest<'a> {
+    client: &'a SpamReportsApi,
+    start_time: Option<i64>,
+    end_time: Option<i64>,
+    limit: Option<i64>,
+    offset: Option<i64>,
+}
+
+impl<'a> SpamReportsApiGetSuppressionSpamReportsRequest<'a> {
+    /// Sets the `start_time` query parameter.
+    pub fn start_time(mut self, start_time: i64) -> Self {
+        self.start_time = Some(start_time);
+        self
+    }
+
+    /// Sets the `end_time` query parameter.
+    pub fn end_time(mut self, end_time: i64) -> Self {
+        self.end_time = Some(end_time);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::SpamReportsResponse>>> {
+        let Self {
+            client,
+            start_time,
+            end_time,
+            limit,
+            offset,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = end_time {
+            query_args.push(("end_time".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = offset {
+            query_args.push(("offset".to_string(), v.to_string()));
+        }
+        if let Some(v) = start_time {
+            query_args.push(("start_time".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/suppression/spam_reports?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct SpamReportsApi {
     pub client: Client,
 }
@@ -60,6 +136,26 @@ impl SpamReportsApi {
             )
             .await
     }
+    /**
+     * Retrieve all spam reports.
+     *
+     * This function performs a `GET` to the `/suppression/spam_reports` endpoint.
+     *
+     * As opposed to `get_suppression_spam_reports`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * **This endpoint allows you to retrieve all spam reports.**
+     */
+    pub fn get_suppression_spam_reports_request(
+        &self,
+    ) -> SpamReportsApiGetSuppressionSpamReportsRequest<'_> {
+        SpamReportsApiGetSuppressionSpamReportsRequest {
+            client: self,
+            start_time: None,
+            end_time: None,
+            limit: None,
+            offset: None,
+        }
+    }
     /**
      * Retrieve all spam reports.
      *
@@ -99,6 +195,38 @@ impl SpamReportsApi {
             )
             .await
     }
+    /**
+     * Retrieve all spam reports.
+     *
+     * This function performs a `GET` to the `/suppression/spam_reports` endpoint.
+     *
+     * As opposed to `get_all_suppression_spam_reports`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * **This endpoint allows you to retrieve all spam reports.**
+     */
+    pub fn get_all_suppression_spam_reports_stream(
+        &self,
+        start_time: i64,
+        end_time: i64,
+        offset: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::SpamReportsResponse>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if end_time > 0 {
+            query_args.push(("end_time".to_string(), end_time.to_string()));
+        }
+        if offset > 0 {
+            query_args.push(("offset".to_string(), offset.to_string()));
+        }
+        if start_time > 0 {
+            query_args.push(("start_time".to_string(), start_time.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/suppression/spam_reports?{}", query_), None);
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Delete spam reports.
      *
@@ -194,6 +322,29 @@ impl SpamReportsApi {
             )
             .await
     }
+    /**
+     * Retrieve a specific spam report.
+     *
+     * This function performs a `GET` to the `/suppression/spam_reports/{email}` endpoint.
+     *
+     * As opposed to `get_all_suppression_spam_reports_email`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * **This endpoint allows you to retrieve a specific spam report by email address.**
+     */
+    pub fn get_all_suppression_spam_reports_email_stream(
+        &self,
+        email: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::SpamReportsResponse>> + Unpin + '_
+    {
+        let url = self.client.url(
+            &format!(
+                "/suppression/spam_reports/{}",
+                crate::progenitor_support::encode_path(email),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Delete a specific spam report.
      *
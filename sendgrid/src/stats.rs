@@ -1,6 +1,532 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Stats::get_page`], set with a builder.
+pub struct StatsGetPageRequest<'a> {
+    client: &'a Stats,
+    offset: Option<i64>,
+    aggregated_by: Option<crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy>,
+    start_date: &'a str,
+    end_date: Option<&'a str>,
+}
+
+impl<'a> StatsGetPageRequest<'a> {
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Sets the `aggregated_by` query parameter.
+    pub fn aggregated_by(
+        mut self,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+    ) -> Self {
+        self.aggregated_by = Some(aggregated_by);
+        self
+    }
+
+    /// Sets the `end_date` query parameter.
+    pub fn end_date(mut self, end_date: &'a str) -> Self {
+        self.end_date = Some(end_date);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GetStatsResponseData>>> {
+        let Self {
+            client,
+            offset,
+            aggregated_by,
+            start_date,
+            end_date,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = aggregated_by {
+            query_args.push(("aggregated_by".to_string(), v.to_string()));
+        }
+        if let Some(v) = end_date {
+            query_args.push(("end_date".to_string(), v.to_string()));
+        }
+        if let Some(v) = offset {
+            query_args.push(("offset".to_string(), v.to_string()));
+        }
+        if !start_date.is_empty() {
+            query_args.push(("start_date".to_string(), start_date.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(&format!("/stats?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Stats::get_geo`], set with a builder.
+pub struct StatsGetGeoRequest<'a> {
+    client: &'a Stats,
+    country: Option<crate::types::Country>,
+    offset: Option<i64>,
+    aggregated_by: Option<crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy>,
+    start_date: &'a str,
+    end_date: Option<&'a str>,
+}
+
+impl<'a> StatsGetGeoRequest<'a> {
+    /// Sets the `country` query parameter.
+    pub fn country(mut self, country: crate::types::Country) -> Self {
+        self.country = Some(country);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Sets the `aggregated_by` query parameter.
+    pub fn aggregated_by(
+        mut self,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+    ) -> Self {
+        self.aggregated_by = Some(aggregated_by);
+        self
+    }
+
+    /// Sets the `end_date` query parameter.
+    pub fn end_date(mut self, end_date: &'a str) -> Self {
+        self.end_date = Some(end_date);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GetGeoStatsResponseData>>> {
+        let Self {
+            client,
+            country,
+            offset,
+            aggregated_by,
+            start_date,
+            end_date,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = aggregated_by {
+            query_args.push(("aggregated_by".to_string(), v.to_string()));
+        }
+        if let Some(v) = country {
+            query_args.push(("country".to_string(), v.to_string()));
+        }
+        if let Some(v) = end_date {
+            query_args.push(("end_date".to_string(), v.to_string()));
+        }
+        if let Some(v) = offset {
+            query_args.push(("offset".to_string(), v.to_string()));
+        }
+        if !start_date.is_empty() {
+            query_args.push(("start_date".to_string(), start_date.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(&format!("/geo/stats?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Stats::get_devices`], set with a builder.
+pub struct StatsGetDevicesRequest<'a> {
+    client: &'a Stats,
+    offset: Option<i64>,
+    aggregated_by: Option<crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy>,
+    start_date: &'a str,
+    end_date: Option<&'a str>,
+}
+
+impl<'a> StatsGetDevicesRequest<'a> {
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Sets the `aggregated_by` query parameter.
+    pub fn aggregated_by(
+        mut self,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+    ) -> Self {
+        self.aggregated_by = Some(aggregated_by);
+        self
+    }
+
+    /// Sets the `end_date` query parameter.
+    pub fn end_date(mut self, end_date: &'a str) -> Self {
+        self.end_date = Some(end_date);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GetClientsStatsResponse>>> {
+        let Self {
+            client,
+            offset,
+            aggregated_by,
+            start_date,
+            end_date,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = aggregated_by {
+            query_args.push(("aggregated_by".to_string(), v.to_string()));
+        }
+        if let Some(v) = end_date {
+            query_args.push(("end_date".to_string(), v.to_string()));
+        }
+        if let Some(v) = offset {
+            query_args.push(("offset".to_string(), v.to_string()));
+        }
+        if !start_date.is_empty() {
+            query_args.push(("start_date".to_string(), start_date.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/devices/stats?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Stats::get_clients`], set with a builder.
+pub struct StatsGetClientsRequest<'a> {
+    client: &'a Stats,
+    start_date: &'a str,
+    end_date: Option<&'a str>,
+    aggregated_by: Option<crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy>,
+}
+
+impl<'a> StatsGetClientsRequest<'a> {
+    /// Sets the `end_date` query parameter.
+    pub fn end_date(mut self, end_date: &'a str) -> Self {
+        self.end_date = Some(end_date);
+        self
+    }
+
+    /// Sets the `aggregated_by` query parameter.
+    pub fn aggregated_by(
+        mut self,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+    ) -> Self {
+        self.aggregated_by = Some(aggregated_by);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GetClientsStatsResponse>>> {
+        let Self {
+            client,
+            start_date,
+            end_date,
+            aggregated_by,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = aggregated_by {
+            query_args.push(("aggregated_by".to_string(), v.to_string()));
+        }
+        if let Some(v) = end_date {
+            query_args.push(("end_date".to_string(), v.to_string()));
+        }
+        if !start_date.is_empty() {
+            query_args.push(("start_date".to_string(), start_date.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/clients/stats?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Stats::get_clients_client_type`], set with a builder.
+pub struct StatsGetClientsClientTypeRequest<'a> {
+    client: &'a Stats,
+    client_type: crate::types::ClientType,
+    start_date: &'a str,
+    end_date: Option<&'a str>,
+    aggregated_by: Option<crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy>,
+}
+
+impl<'a> StatsGetClientsClientTypeRequest<'a> {
+    /// Sets the `end_date` query parameter.
+    pub fn end_date(mut self, end_date: &'a str) -> Self {
+        self.end_date = Some(end_date);
+        self
+    }
+
+    /// Sets the `aggregated_by` query parameter.
+    pub fn aggregated_by(
+        mut self,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+    ) -> Self {
+        self.aggregated_by = Some(aggregated_by);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GetClientsStatsResponse>>> {
+        let Self {
+            client,
+            client_type,
+            start_date,
+            end_date,
+            aggregated_by,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = aggregated_by {
+            query_args.push(("aggregated_by".to_string(), v.to_string()));
+        }
+        if let Some(v) = end_date {
+            query_args.push(("end_date".to_string(), v.to_string()));
+        }
+        if !start_date.is_empty() {
+            query_args.push(("start_date".to_string(), start_date.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/clients/{}/stats?{}",
+                crate::progenitor_support::encode_path(&client_type.to_string()),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Stats::get_mailbox_providers`], set with a builder.
+pub struct StatsGetMailboxProvidersRequest<'a> {
+    client: &'a Stats,
+    mailbox_providers: Option<&'a str>,
+    offset: Option<i64>,
+    aggregated_by: Option<crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy>,
+    start_date: &'a str,
+    end_date: Option<&'a str>,
+}
+
+impl<'a> StatsGetMailboxProvidersRequest<'a> {
+    /// Sets the `mailbox_providers` query parameter.
+    pub fn mailbox_providers(mut self, mailbox_providers: &'a str) -> Self {
+        self.mailbox_providers = Some(mailbox_providers);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Sets the `aggregated_by` query parameter.
+    pub fn aggregated_by(
+        mut self,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+    ) -> Self {
+        self.aggregated_by = Some(aggregated_by);
+        self
+    }
+
+    /// Sets the `end_date` query parameter.
+    pub fn end_date(mut self, end_date: &'a str) -> Self {
+        self.end_date = Some(end_date);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GetMailboxProvidersStatsResponseData>>>
+    {
+        let Self {
+            client,
+            mailbox_providers,
+            offset,
+            aggregated_by,
+            start_date,
+            end_date,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = aggregated_by {
+            query_args.push(("aggregated_by".to_string(), v.to_string()));
+        }
+        if let Some(v) = end_date {
+            query_args.push(("end_date".to_string(), v.to_string()));
+        }
+        if let Some(v) = mailbox_providers {
+            query_args.push(("mailbox_providers".to_string(), v.to_string()));
+        }
+        if let Some(v) = offset {
+            query_args.push(("offset".to_string(), v.to_string()));
+        }
+        if !start_date.is_empty() {
+            query_args.push(("start_date".to_string(), start_date.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/mailbox_providers/stats?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Stats::get_browsers`], set with a builder.
+pub struct StatsGetBrowsersRequest<'a> {
+    client: &'a Stats,
+    browsers: Option<&'a str>,
+    offset: Option<i64>,
+    aggregated_by: Option<crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy>,
+    start_date: &'a str,
+    end_date: Option<&'a str>,
+}
+
+impl<'a> StatsGetBrowsersRequest<'a> {
+    /// Sets the `browsers` query parameter.
+    pub fn browsers(mut self, browsers: &'a str) -> Self {
+        self.browsers = Some(browsers);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Sets the `aggregated_by` query parameter.
+    pub fn aggregated_by(
+        mut self,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+    ) -> Self {
+        self.aggregated_by = Some(aggregated_by);
+        self
+    }
+
+    /// Sets the `end_date` query parameter.
+    pub fn end_date(mut self, end_date: &'a str) -> Self {
+        self.end_date = Some(end_date);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GetBrowsersStatsResponseData>>> {
+        let Self {
+            client,
+            browsers,
+            offset,
+            aggregated_by,
+            start_date,
+            end_date,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = aggregated_by {
+            query_args.push(("aggregated_by".to_string(), v.to_string()));
+        }
+        if let Some(v) = browsers {
+            query_args.push(("browsers".to_string(), v.to_string()));
+        }
+        if let Some(v) = end_date {
+            query_args.push(("end_date".to_string(), v.to_string()));
+        }
+        if let Some(v) = offset {
+            query_args.push(("offset".to_string(), v.to_string()));
+        }
+        if !start_date.is_empty() {
+            query_args.push(("start_date".to_string(), start_date.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/browsers/stats?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct Stats {
     pub client: Client,
 }
@@ -61,6 +587,26 @@ impl Stats {
             )
             .await
     }
+    /**
+     * Retrieve global email statistics.
+     *
+     * This function performs a `GET` to the `/stats` endpoint.
+     *
+     * As opposed to `get_page`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * **This endpoint allows you to retrieve all of your global email statistics between a given date range.**
+     *
+     * Parent accounts will see aggregated stats for their account and all subuser accounts. Subuser accounts will only see their own stats.
+     */
+    pub fn get_page_request<'a>(&'a self, start_date: &'a str) -> StatsGetPageRequest<'a> {
+        StatsGetPageRequest {
+            client: self,
+            offset: None,
+            aggregated_by: None,
+            start_date,
+            end_date: None,
+        }
+    }
     /**
      * Retrieve global email statistics.
      *
@@ -104,6 +650,42 @@ impl Stats {
             )
             .await
     }
+    /**
+     * Retrieve global email statistics.
+     *
+     * This function performs a `GET` to the `/stats` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * **This endpoint allows you to retrieve all of your global email statistics between a given date range.**
+     *
+     * Parent accounts will see aggregated stats for their account and all subuser accounts. Subuser accounts will only see their own stats.
+     */
+    pub fn get_all_stream(
+        &self,
+        offset: i64,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+        start_date: &str,
+        end_date: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::GetStatsResponseData>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !aggregated_by.to_string().is_empty() {
+            query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
+        }
+        if !end_date.is_empty() {
+            query_args.push(("end_date".to_string(), end_date.to_string()));
+        }
+        if offset > 0 {
+            query_args.push(("offset".to_string(), offset.to_string()));
+        }
+        if !start_date.is_empty() {
+            query_args.push(("start_date".to_string(), start_date.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/stats?{}", query_), None);
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Retrieve email statistics by country and state/province.
      *
@@ -161,6 +743,29 @@ impl Stats {
             )
             .await
     }
+    /**
+     * Retrieve email statistics by country and state/province.
+     *
+     * This function performs a `GET` to the `/geo/stats` endpoint.
+     *
+     * As opposed to `get_geo`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * **This endpoint allows you to retrieve your email statistics segmented by country and state/province.**
+     *
+     * **We only store up to 7 days of email activity in our database.** By default, 500 items will be returned per request via the Advanced Stats API endpoints.
+     *
+     * Advanced Stats provide a more in-depth view of your email statistics and the actions taken by your recipients. You can segment these statistics by geographic location, device type, client type, browser, and mailbox provider. For more information about statistics, please see our [User Guide](https://sendgrid.com/docs/User_Guide/Statistics/index.html).
+     */
+    pub fn get_geo_request<'a>(&'a self, start_date: &'a str) -> StatsGetGeoRequest<'a> {
+        StatsGetGeoRequest {
+            client: self,
+            country: None,
+            offset: None,
+            aggregated_by: None,
+            start_date,
+            end_date: None,
+        }
+    }
     /**
      * Retrieve email statistics by country and state/province.
      *
@@ -210,11 +815,147 @@ impl Stats {
             )
             .await
     }
+    /**
+     * Retrieve email statistics by country and state/province.
+     *
+     * This function performs a `GET` to the `/geo/stats` endpoint.
+     *
+     * As opposed to `get_all_geo`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * **This endpoint allows you to retrieve your email statistics segmented by country and state/province.**
+     *
+     * **We only store up to 7 days of email activity in our database.** By default, 500 items will be returned per request via the Advanced Stats API endpoints.
+     *
+     * Advanced Stats provide a more in-depth view of your email statistics and the actions taken by your recipients. You can segment these statistics by geographic location, device type, client type, browser, and mailbox provider. For more information about statistics, please see our [User Guide](https://sendgrid.com/docs/User_Guide/Statistics/index.html).
+     */
+    pub fn get_all_geo_stream(
+        &self,
+        country: crate::types::Country,
+        offset: i64,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+        start_date: &str,
+        end_date: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::GetGeoStatsResponseData>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !aggregated_by.to_string().is_empty() {
+            query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
+        }
+        if !country.to_string().is_empty() {
+            query_args.push(("country".to_string(), country.to_string()));
+        }
+        if !end_date.is_empty() {
+            query_args.push(("end_date".to_string(), end_date.to_string()));
+        }
+        if offset > 0 {
+            query_args.push(("offset".to_string(), offset.to_string()));
+        }
+        if !start_date.is_empty() {
+            query_args.push(("start_date".to_string(), start_date.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/geo/stats?{}", query_), None);
+        self.client.get_all_pages_stream(url)
+    }
+    /**
+     * Retrieve email statistics by device type.
+     *
+     * This function performs a `GET` to the `/devices/stats` endpoint.
+     *
+     * **This endpoint allows you to retrieve your email statistics segmented by the device type.**
+     *
+     * **We only store up to 7 days of email activity in our database.** By default, 500 items will be returned per request via the Advanced Stats API endpoints.
+     *
+     * ## Available Device Types
+     * | **Device** | **Description** | **Example** |
+     * |---|---|---|
+     * | Desktop | Email software on desktop computer. | I.E., Outlook, Sparrow, or Apple Mail. |
+     * | Webmail |	A web-based email client. | I.E., Yahoo, Google, AOL, or Outlook.com. |
+     * | Phone | A smart phone. | iPhone, Android, Blackberry, etc.
+     * | Tablet | A tablet computer. | iPad, android based tablet, etc. |
+     * | Other | An unrecognized device. |
+     *
+     * Advanced Stats provide a more in-depth view of your email statistics and the actions taken by your recipients. You can segment these statistics by geographic location, device type, client type, browser, and mailbox provider. For more information about statistics, please see our [Statistics Overview](https://sendgrid.com/docs/ui/analytics-and-reporting/stats-overview/).
+     *
+     * **Parameters:**
+     *
+     * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
+     * * `offset: i64` -- The point in the list to begin retrieving results.
+     * * `offset: i64` -- The point in the list to begin retrieving results.
+     * * `aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy` -- How to group the statistics. Must be either "day", "week", or "month".
+     * * `start_date: &str` -- The starting date of the statistics to retrieve. Must follow format YYYY-MM-DD.
+     * * `end_date: &str` -- The end date of the statistics to retrieve. Defaults to today. Must follow format YYYY-MM-DD.
+     */
+    pub async fn get_devices(
+        &self,
+        offset: i64,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+        start_date: &str,
+        end_date: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GetClientsStatsResponse>>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !aggregated_by.to_string().is_empty() {
+            query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
+        }
+        if !end_date.is_empty() {
+            query_args.push(("end_date".to_string(), end_date.to_string()));
+        }
+        if offset > 0 {
+            query_args.push(("offset".to_string(), offset.to_string()));
+        }
+        if !start_date.is_empty() {
+            query_args.push(("start_date".to_string(), start_date.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/devices/stats?{}", query_), None);
+        self.client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+    /**
+     * Retrieve email statistics by device type.
+     *
+     * This function performs a `GET` to the `/devices/stats` endpoint.
+     *
+     * As opposed to `get_devices`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * **This endpoint allows you to retrieve your email statistics segmented by the device type.**
+     *
+     * **We only store up to 7 days of email activity in our database.** By default, 500 items will be returned per request via the Advanced Stats API endpoints.
+     *
+     * ## Available Device Types
+     * | **Device** | **Description** | **Example** |
+     * |---|---|---|
+     * | Desktop | Email software on desktop computer. | I.E., Outlook, Sparrow, or Apple Mail. |
+     * | Webmail |	A web-based email client. | I.E., Yahoo, Google, AOL, or Outlook.com. |
+     * | Phone | A smart phone. | iPhone, Android, Blackberry, etc.
+     * | Tablet | A tablet computer. | iPad, android based tablet, etc. |
+     * | Other | An unrecognized device. |
+     *
+     * Advanced Stats provide a more in-depth view of your email statistics and the actions taken by your recipients. You can segment these statistics by geographic location, device type, client type, browser, and mailbox provider. For more information about statistics, please see our [Statistics Overview](https://sendgrid.com/docs/ui/analytics-and-reporting/stats-overview/).
+     */
+    pub fn get_devices_request<'a>(&'a self, start_date: &'a str) -> StatsGetDevicesRequest<'a> {
+        StatsGetDevicesRequest {
+            client: self,
+            offset: None,
+            aggregated_by: None,
+            start_date,
+            end_date: None,
+        }
+    }
     /**
      * Retrieve email statistics by device type.
      *
      * This function performs a `GET` to the `/devices/stats` endpoint.
      *
+     * As opposed to `get_devices`, this function returns all the pages of the request at once.
+     *
      * **This endpoint allows you to retrieve your email statistics segmented by the device type.**
      *
      * **We only store up to 7 days of email activity in our database.** By default, 500 items will be returned per request via the Advanced Stats API endpoints.
@@ -229,17 +970,8 @@ impl Stats {
      * | Other | An unrecognized device. |
      *
      * Advanced Stats provide a more in-depth view of your email statistics and the actions taken by your recipients. You can segment these statistics by geographic location, device type, client type, browser, and mailbox provider. For more information about statistics, please see our [Statistics Overview](https://sendgrid.com/docs/ui/analytics-and-reporting/stats-overview/).
-     *
-     * **Parameters:**
-     *
-     * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
-     * * `offset: i64` -- The point in the list to begin retrieving results.
-     * * `offset: i64` -- The point in the list to begin retrieving results.
-     * * `aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy` -- How to group the statistics. Must be either "day", "week", or "month".
-     * * `start_date: &str` -- The starting date of the statistics to retrieve. Must follow format YYYY-MM-DD.
-     * * `end_date: &str` -- The end date of the statistics to retrieve. Defaults to today. Must follow format YYYY-MM-DD.
      */
-    pub async fn get_devices(
+    pub async fn get_all_devices(
         &self,
         offset: i64,
         aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
@@ -262,7 +994,7 @@ impl Stats {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/devices/stats?{}", query_), None);
         self.client
-            .get(
+            .get_all_pages(
                 &url,
                 crate::Message {
                     body: None,
@@ -276,7 +1008,7 @@ impl Stats {
      *
      * This function performs a `GET` to the `/devices/stats` endpoint.
      *
-     * As opposed to `get_devices`, this function returns all the pages of the request at once.
+     * As opposed to `get_all_devices`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
      *
      * **This endpoint allows you to retrieve your email statistics segmented by the device type.**
      *
@@ -293,13 +1025,14 @@ impl Stats {
      *
      * Advanced Stats provide a more in-depth view of your email statistics and the actions taken by your recipients. You can segment these statistics by geographic location, device type, client type, browser, and mailbox provider. For more information about statistics, please see our [Statistics Overview](https://sendgrid.com/docs/ui/analytics-and-reporting/stats-overview/).
      */
-    pub async fn get_all_devices(
+    pub fn get_all_devices_stream(
         &self,
         offset: i64,
         aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
         start_date: &str,
         end_date: &str,
-    ) -> ClientResult<crate::Response<Vec<crate::types::GetClientsStatsResponse>>> {
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::GetClientsStatsResponse>> + Unpin + '_
+    {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !aggregated_by.to_string().is_empty() {
             query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
@@ -315,15 +1048,7 @@ impl Stats {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/devices/stats?{}", query_), None);
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.client.get_all_pages_stream(url)
     }
     /**
      * Retrieve email statistics by client type.
@@ -371,6 +1096,27 @@ impl Stats {
             )
             .await
     }
+    /**
+     * Retrieve email statistics by client type.
+     *
+     * This function performs a `GET` to the `/clients/stats` endpoint.
+     *
+     * As opposed to `get_clients`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * **This endpoint allows you to retrieve your email statistics segmented by client type.**
+     *
+     * **We only store up to 7 days of email activity in our database.** By default, 500 items will be returned per request via the Advanced Stats API endpoints.
+     *
+     * Advanced Stats provide a more in-depth view of your email statistics and the actions taken by your recipients. You can segment these statistics by geographic location, device type, client type, browser, and mailbox provider. For more information about statistics, please see our [Statistics Overview](https://sendgrid.com/docs/ui/analytics-and-reporting/stats-overview/).
+     */
+    pub fn get_clients_request<'a>(&'a self, start_date: &'a str) -> StatsGetClientsRequest<'a> {
+        StatsGetClientsRequest {
+            client: self,
+            start_date,
+            end_date: None,
+            aggregated_by: None,
+        }
+    }
     /**
      * Retrieve email statistics by client type.
      *
@@ -412,6 +1158,40 @@ impl Stats {
             )
             .await
     }
+    /**
+     * Retrieve email statistics by client type.
+     *
+     * This function performs a `GET` to the `/clients/stats` endpoint.
+     *
+     * As opposed to `get_all_clients`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * **This endpoint allows you to retrieve your email statistics segmented by client type.**
+     *
+     * **We only store up to 7 days of email activity in our database.** By default, 500 items will be returned per request via the Advanced Stats API endpoints.
+     *
+     * Advanced Stats provide a more in-depth view of your email statistics and the actions taken by your recipients. You can segment these statistics by geographic location, device type, client type, browser, and mailbox provider. For more information about statistics, please see our [Statistics Overview](https://sendgrid.com/docs/ui/analytics-and-reporting/stats-overview/).
+     */
+    pub fn get_all_clients_stream(
+        &self,
+        start_date: &str,
+        end_date: &str,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::GetClientsStatsResponse>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !aggregated_by.to_string().is_empty() {
+            query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
+        }
+        if !end_date.is_empty() {
+            query_args.push(("end_date".to_string(), end_date.to_string()));
+        }
+        if !start_date.is_empty() {
+            query_args.push(("start_date".to_string(), start_date.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/clients/stats?{}", query_), None);
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Retrieve stats by a specific client type.
      *
@@ -472,6 +1252,38 @@ impl Stats {
             )
             .await
     }
+    /**
+     * Retrieve stats by a specific client type.
+     *
+     * This function performs a `GET` to the `/clients/{client_type}/stats` endpoint.
+     *
+     * As opposed to `get_clients_client_type`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * **This endpoint allows you to retrieve your email statistics segmented by a specific client type.**
+     *
+     * **We only store up to 7 days of email activity in our database.** By default, 500 items will be returned per request via the Advanced Stats API endpoints.
+     *
+     * ## Available Client Types
+     * - phone
+     * - tablet
+     * - webmail
+     * - desktop
+     *
+     * Advanced Stats provide a more in-depth view of your email statistics and the actions taken by your recipients. You can segment these statistics by geographic location, device type, client type, browser, and mailbox provider. For more information about statistics, please see our [Statistics Overview](https://sendgrid.com/docs/ui/analytics-and-reporting/stats-overview/).
+     */
+    pub fn get_clients_client_type_request<'a>(
+        &'a self,
+        client_type: crate::types::ClientType,
+        start_date: &'a str,
+    ) -> StatsGetClientsClientTypeRequest<'a> {
+        StatsGetClientsClientTypeRequest {
+            client: self,
+            client_type,
+            start_date,
+            end_date: None,
+            aggregated_by: None,
+        }
+    }
     /**
      * Retrieve stats by a specific client type.
      *
@@ -527,6 +1339,54 @@ impl Stats {
             )
             .await
     }
+    /**
+     * Retrieve stats by a specific client type.
+     *
+     * This function performs a `GET` to the `/clients/{client_type}/stats` endpoint.
+     *
+     * As opposed to `get_all_clients_client_type`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * **This endpoint allows you to retrieve your email statistics segmented by a specific client type.**
+     *
+     * **We only store up to 7 days of email activity in our database.** By default, 500 items will be returned per request via the Advanced Stats API endpoints.
+     *
+     * ## Available Client Types
+     * - phone
+     * - tablet
+     * - webmail
+     * - desktop
+     *
+     * Advanced Stats provide a more in-depth view of your email statistics and the actions taken by your recipients. You can segment these statistics by geographic location, device type, client type, browser, and mailbox provider. For more information about statistics, please see our [Statistics Overview](https://sendgrid.com/docs/ui/analytics-and-reporting/stats-overview/).
+     */
+    pub fn get_all_clients_client_type_stream(
+        &self,
+        client_type: crate::types::ClientType,
+        start_date: &str,
+        end_date: &str,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::GetClientsStatsResponse>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !aggregated_by.to_string().is_empty() {
+            query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
+        }
+        if !end_date.is_empty() {
+            query_args.push(("end_date".to_string(), end_date.to_string()));
+        }
+        if !start_date.is_empty() {
+            query_args.push(("start_date".to_string(), start_date.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/clients/{}/stats?{}",
+                crate::progenitor_support::encode_path(&client_type.to_string()),
+                query_
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Retrieve email statistics by mailbox provider.
      *
@@ -590,6 +1450,32 @@ impl Stats {
             )
             .await
     }
+    /**
+     * Retrieve email statistics by mailbox provider.
+     *
+     * This function performs a `GET` to the `/mailbox_providers/stats` endpoint.
+     *
+     * As opposed to `get_mailbox_providers`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * **This endpoint allows you to retrieve your email statistics segmented by recipient mailbox provider.**
+     *
+     * **We only store up to 7 days of email activity in our database.** By default, 500 items will be returned per request via the Advanced Stats API endpoints.
+     *
+     * Advanced Stats provide a more in-depth view of your email statistics and the actions taken by your recipients. You can segment these statistics by geographic location, device type, client type, browser, and mailbox provider. For more information about statistics, please see our [Statistics Overview](https://sendgrid.com/docs/ui/analytics-and-reporting/stats-overview/).
+     */
+    pub fn get_mailbox_providers_request<'a>(
+        &'a self,
+        start_date: &'a str,
+    ) -> StatsGetMailboxProvidersRequest<'a> {
+        StatsGetMailboxProvidersRequest {
+            client: self,
+            mailbox_providers: None,
+            offset: None,
+            aggregated_by: None,
+            start_date,
+            end_date: None,
+        }
+    }
     /**
      * Retrieve email statistics by mailbox provider.
      *
@@ -645,6 +1531,54 @@ impl Stats {
             )
             .await
     }
+    /**
+     * Retrieve email statistics by mailbox provider.
+     *
+     * This function performs a `GET` to the `/mailbox_providers/stats` endpoint.
+     *
+     * As opposed to `get_all_mailbox_providers`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * **This endpoint allows you to retrieve your email statistics segmented by recipient mailbox provider.**
+     *
+     * **We only store up to 7 days of email activity in our database.** By default, 500 items will be returned per request via the Advanced Stats API endpoints.
+     *
+     * Advanced Stats provide a more in-depth view of your email statistics and the actions taken by your recipients. You can segment these statistics by geographic location, device type, client type, browser, and mailbox provider. For more information about statistics, please see our [Statistics Overview](https://sendgrid.com/docs/ui/analytics-and-reporting/stats-overview/).
+     */
+    pub fn get_all_mailbox_providers_stream(
+        &self,
+        mailbox_providers: &str,
+        offset: i64,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+        start_date: &str,
+        end_date: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::GetMailboxProvidersStatsResponseData>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !aggregated_by.to_string().is_empty() {
+            query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
+        }
+        if !end_date.is_empty() {
+            query_args.push(("end_date".to_string(), end_date.to_string()));
+        }
+        if !mailbox_providers.is_empty() {
+            query_args.push((
+                "mailbox_providers".to_string(),
+                mailbox_providers.to_string(),
+            ));
+        }
+        if offset > 0 {
+            query_args.push(("offset".to_string(), offset.to_string()));
+        }
+        if !start_date.is_empty() {
+            query_args.push(("start_date".to_string(), start_date.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/mailbox_providers/stats?{}", query_), None);
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Retrieve email statistics by browser.
      *
@@ -704,6 +1638,29 @@ impl Stats {
             )
             .await
     }
+    /**
+     * Retrieve email statistics by browser.
+     *
+     * This function performs a `GET` to the `/browsers/stats` endpoint.
+     *
+     * As opposed to `get_browsers`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * **This endpoint allows you to retrieve your email statistics segmented by browser type.**
+     *
+     * **We only store up to 7 days of email activity in our database.** By default, 500 items will be returned per request via the Advanced Stats API endpoints.
+     *
+     * Advanced Stats provide a more in-depth view of your email statistics and the actions taken by your recipients. You can segment these statistics by geographic location, device type, client type, browser, and mailbox provider. For more information about statistics, please see our [Statistics Overview](https://sendgrid.com/docs/ui/analytics-and-reporting/stats-overview/).
+     */
+    pub fn get_browsers_request<'a>(&'a self, start_date: &'a str) -> StatsGetBrowsersRequest<'a> {
+        StatsGetBrowsersRequest {
+            client: self,
+            browsers: None,
+            offset: None,
+            aggregated_by: None,
+            start_date,
+            end_date: None,
+        }
+    }
     /**
      * Retrieve email statistics by browser.
      *
@@ -755,4 +1712,48 @@ impl Stats {
             )
             .await
     }
+    /**
+     * Retrieve email statistics by browser.
+     *
+     * This function performs a `GET` to the `/browsers/stats` endpoint.
+     *
+     * As opposed to `get_all_browsers`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * **This endpoint allows you to retrieve your email statistics segmented by browser type.**
+     *
+     * **We only store up to 7 days of email activity in our database.** By default, 500 items will be returned per request via the Advanced Stats API endpoints.
+     *
+     * Advanced Stats provide a more in-depth view of your email statistics and the actions taken by your recipients. You can segment these statistics by geographic location, device type, client type, browser, and mailbox provider. For more information about statistics, please see our [Statistics Overview](https://sendgrid.com/docs/ui/analytics-and-reporting/stats-overview/).
+     */
+    pub fn get_all_browsers_stream(
+        &self,
+        browsers: &str,
+        offset: i64,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+        start_date: &str,
+        end_date: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::GetBrowsersStatsResponseData>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !aggregated_by.to_string().is_empty() {
+            query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
+        }
+        if !browsers.is_empty() {
+            query_args.push(("browsers".to_string(), browsers.to_string()));
+        }
+        if !end_date.is_empty() {
+            query_args.push(("end_date".to_string(), end_date.to_string()));
+        }
+        if offset > 0 {
+            query_args.push(("offset".to_string(), offset.to_string()));
+        }
+        if !start_date.is_empty() {
+            query_args.push(("start_date".to_string(), start_date.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/browsers/stats?{}", query_), None);
+        self.client.get_all_pages_stream(url)
+    }
 }
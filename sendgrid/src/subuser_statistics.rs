@@ -1,6 +1,370 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`SubuserStatistics::get_subusers_subuser_name_stats_monthly`], set with a builder.
+pub struct SubuserStatisticsGetSubusersSubuserNameStatsMonthlyRequest<'a> {
+    client: &'a SubuserStatistics,
+    subuser_name: &'a str,
+    date: &'a str,
+    sort_by_metric: Option<&'a str>,
+    sort_by_direction: Option<crate::types::SortByDirection>,
+    limit: Option<i64>,
+    offset: Option<i64>,
+}
+
+impl<'a> SubuserStatisticsGetSubusersSubuserNameStatsMonthlyRequest<'a> {
+    /// Sets the `sort_by_metric` query parameter.
+    pub fn sort_by_metric(mut self, sort_by_metric: &'a str) -> Self {
+        self.sort_by_metric = Some(sort_by_metric);
+        self
+    }
+
+    /// Sets the `sort_by_direction` query parameter.
+    pub fn sort_by_direction(mut self, sort_by_direction: crate::types::SortByDirection) -> Self {
+        self.sort_by_direction = Some(sort_by_direction);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::SubuserStatsData>> {
+        let Self {
+            client,
+            subuser_name,
+            date,
+            sort_by_metric,
+            sort_by_direction,
+            limit,
+            offset,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !date.is_empty() {
+            query_args.push(("date".to_string(), date.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = offset {
+            query_args.push(("offset".to_string(), v.to_string()));
+        }
+        if let Some(v) = sort_by_direction {
+            query_args.push(("sort_by_direction".to_string(), v.to_string()));
+        }
+        if let Some(v) = sort_by_metric {
+            query_args.push(("sort_by_metric".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/subusers/{}/stats/monthly?{}",
+                crate::progenitor_support::encode_path(subuser_name),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`SubuserStatistics::get_subusers_stats_monthly`], set with a builder.
+pub struct SubuserStatisticsGetSubusersStatsMonthlyRequest<'a> {
+    client: &'a SubuserStatistics,
+    date: &'a str,
+    subuser: Option<&'a str>,
+    sort_by_metric: Option<crate::types::SortByMetric>,
+    sort_by_direction: Option<crate::types::SortByDirection>,
+    limit: Option<i64>,
+    offset: Option<i64>,
+}
+
+impl<'a> SubuserStatisticsGetSubusersStatsMonthlyRequest<'a> {
+    /// Sets the `subuser` query parameter.
+    pub fn subuser(mut self, subuser: &'a str) -> Self {
+        self.subuser = Some(subuser);
+        self
+    }
+
+    /// Sets the `sort_by_metric` query parameter.
+    pub fn sort_by_metric(mut self, sort_by_metric: crate::types::SortByMetric) -> Self {
+        self.sort_by_metric = Some(sort_by_metric);
+        self
+    }
+
+    /// Sets the `sort_by_direction` query parameter.
+    pub fn sort_by_direction(mut self, sort_by_direction: crate::types::SortByDirection) -> Self {
+        self.sort_by_direction = Some(sort_by_direction);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::SubuserStatsData>> {
+        let Self {
+            client,
+            date,
+            subuser,
+            sort_by_metric,
+            sort_by_direction,
+            limit,
+            offset,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !date.is_empty() {
+            query_args.push(("date".to_string(), date.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = offset {
+            query_args.push(("offset".to_string(), v.to_string()));
+        }
+        if let Some(v) = sort_by_direction {
+            query_args.push(("sort_by_direction".to_string(), v.to_string()));
+        }
+        if let Some(v) = sort_by_metric {
+            query_args.push(("sort_by_metric".to_string(), v.to_string()));
+        }
+        if let Some(v) = subuser {
+            query_args.push(("subuser".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/subusers/stats/monthly?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`SubuserStatistics::get_subusers_stats_sum`], set with a builder.
+pub struct SubuserStatisticsGetSubusersStatsSumRequest<'a> {
+    client: &'a SubuserStatistics,
+    sort_by_direction: Option<crate::types::SortByDirection>,
+    start_date: &'a str,
+    end_date: Option<&'a str>,
+    limit: Option<i64>,
+    offset: Option<i64>,
+    aggregated_by: Option<&'a str>,
+    sort_by_metric: Option<&'a str>,
+}
+
+impl<'a> SubuserStatisticsGetSubusersStatsSumRequest<'a> {
+    /// Sets the `sort_by_direction` query parameter.
+    pub fn sort_by_direction(mut self, sort_by_direction: crate::types::SortByDirection) -> Self {
+        self.sort_by_direction = Some(sort_by_direction);
+        self
+    }
+
+    /// Sets the `end_date` query parameter.
+    pub fn end_date(mut self, end_date: &'a str) -> Self {
+        self.end_date = Some(end_date);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Sets the `aggregated_by` query parameter.
+    pub fn aggregated_by(mut self, aggregated_by: &'a str) -> Self {
+        self.aggregated_by = Some(aggregated_by);
+        self
+    }
+
+    /// Sets the `sort_by_metric` query parameter.
+    pub fn sort_by_metric(mut self, sort_by_metric: &'a str) -> Self {
+        self.sort_by_metric = Some(sort_by_metric);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::CategoryStats>> {
+        let Self {
+            client,
+            sort_by_direction,
+            start_date,
+            end_date,
+            limit,
+            offset,
+            aggregated_by,
+            sort_by_metric,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = aggregated_by {
+            query_args.push(("aggregated_by".to_string(), v.to_string()));
+        }
+        if let Some(v) = end_date {
+            query_args.push(("end_date".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = offset {
+            query_args.push(("offset".to_string(), v.to_string()));
+        }
+        if let Some(v) = sort_by_direction {
+            query_args.push(("sort_by_direction".to_string(), v.to_string()));
+        }
+        if let Some(v) = sort_by_metric {
+            query_args.push(("sort_by_metric".to_string(), v.to_string()));
+        }
+        if !start_date.is_empty() {
+            query_args.push(("start_date".to_string(), start_date.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/subusers/stats/sums?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`SubuserStatistics::get_subusers_stat`], set with a builder.
+pub struct SubuserStatisticsGetSubusersStatRequest<'a> {
+    client: &'a SubuserStatistics,
+    limit: Option<i64>,
+    offset: Option<i64>,
+    aggregated_by: Option<crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy>,
+    subusers: &'a str,
+    start_date: &'a str,
+    end_date: Option<&'a str>,
+}
+
+impl<'a> SubuserStatisticsGetSubusersStatRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Sets the `aggregated_by` query parameter.
+    pub fn aggregated_by(
+        mut self,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+    ) -> Self {
+        self.aggregated_by = Some(aggregated_by);
+        self
+    }
+
+    /// Sets the `end_date` query parameter.
+    pub fn end_date(mut self, end_date: &'a str) -> Self {
+        self.end_date = Some(end_date);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::CategoryStats>> {
+        let Self {
+            client,
+            limit,
+            offset,
+            aggregated_by,
+            subusers,
+            start_date,
+            end_date,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = aggregated_by {
+            query_args.push(("aggregated_by".to_string(), v.to_string()));
+        }
+        if let Some(v) = end_date {
+            query_args.push(("end_date".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = offset {
+            query_args.push(("offset".to_string(), v.to_string()));
+        }
+        if !start_date.is_empty() {
+            query_args.push(("start_date".to_string(), start_date.to_string()));
+        }
+        if !subusers.is_empty() {
+            query_args.push(("subusers".to_string(), subusers.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/subusers/stats?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct SubuserStatistics {
     pub client: Client,
 }
@@ -76,6 +440,33 @@ impl SubuserStatistics {
             )
             .await
     }
+    /**
+     * Retrieve the monthly email statistics for a single subuser.
+     *
+     * This function performs a `GET` to the `/subusers/{subuser_name}/stats/monthly` endpoint.
+     *
+     * As opposed to `get_subusers_subuser_name_stats_monthly`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * **This endpoint allows you to retrive the monthly email statistics for a specific subuser.**
+     *
+     * When using the `sort_by_metric` to sort your stats by a specific metric, you can not sort by the following metrics:
+     * `bounce_drops`, `deferred`, `invalid_emails`, `processed`, `spam_report_drops`, `spam_reports`, or `unsubscribe_drops`.
+     */
+    pub fn get_subusers_subuser_name_stats_monthly_request<'a>(
+        &'a self,
+        subuser_name: &'a str,
+        date: &'a str,
+    ) -> SubuserStatisticsGetSubusersSubuserNameStatsMonthlyRequest<'a> {
+        SubuserStatisticsGetSubusersSubuserNameStatsMonthlyRequest {
+            client: self,
+            subuser_name,
+            date,
+            sort_by_metric: None,
+            sort_by_direction: None,
+            limit: None,
+            offset: None,
+        }
+    }
     /**
      * Retrieve monthly stats for all subusers.
      *
@@ -140,6 +531,32 @@ impl SubuserStatistics {
             )
             .await
     }
+    /**
+     * Retrieve monthly stats for all subusers.
+     *
+     * This function performs a `GET` to the `/subusers/stats/monthly` endpoint.
+     *
+     * As opposed to `get_subusers_stats_monthly`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * **This endpoint allows you to retrieve the monthly email statistics for all subusers over the given date range.**
+     *
+     * When using the `sort_by_metric` to sort your stats by a specific metric, you can not sort by the following metrics:
+     * `bounce_drops`, `deferred`, `invalid_emails`, `processed`, `spam_report_drops`, `spam_reports`, or `unsubscribe_drops`.
+     */
+    pub fn get_subusers_stats_monthly_request<'a>(
+        &'a self,
+        date: &'a str,
+    ) -> SubuserStatisticsGetSubusersStatsMonthlyRequest<'a> {
+        SubuserStatisticsGetSubusersStatsMonthlyRequest {
+            client: self,
+            date,
+            subuser: None,
+            sort_by_metric: None,
+            sort_by_direction: None,
+            limit: None,
+            offset: None,
+        }
+    }
     /**
      * Retrieve the totals for each email statistic metric for all subusers.
      *
@@ -206,6 +623,30 @@ impl SubuserStatistics {
             )
             .await
     }
+    /**
+     * Retrieve the totals for each email statistic metric for all subusers.
+     *
+     * This function performs a `GET` to the `/subusers/stats/sums` endpoint.
+     *
+     * As opposed to `get_subusers_stats_sum`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * **This endpoint allows you to retrieve the total sums of each email statistic metric for all subusers over the given date range.**
+     */
+    pub fn get_subusers_stats_sum_request<'a>(
+        &'a self,
+        start_date: &'a str,
+    ) -> SubuserStatisticsGetSubusersStatsSumRequest<'a> {
+        SubuserStatisticsGetSubusersStatsSumRequest {
+            client: self,
+            sort_by_direction: None,
+            start_date,
+            end_date: None,
+            limit: None,
+            offset: None,
+            aggregated_by: None,
+            sort_by_metric: None,
+        }
+    }
     /**
      * Retrieve email statistics for your subusers.
      *
@@ -266,4 +707,30 @@ impl SubuserStatistics {
             )
             .await
     }
+    /**
+     * Retrieve email statistics for your subusers.
+     *
+     * This function performs a `GET` to the `/subusers/stats` endpoint.
+     *
+     * As opposed to `get_subusers_stat`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * **This endpoint allows you to retrieve the email statistics for the given subusers.**
+     *
+     * You may retrieve statistics for up to 10 different subusers by including an additional _subusers_ parameter for each additional subuser.
+     */
+    pub fn get_subusers_stat_request<'a>(
+        &'a self,
+        subusers: &'a str,
+        start_date: &'a str,
+    ) -> SubuserStatisticsGetSubusersStatRequest<'a> {
+        SubuserStatisticsGetSubusersStatRequest {
+            client: self,
+            limit: None,
+            offset: None,
+            aggregated_by: None,
+            subusers,
+            start_date,
+            end_date: None,
+        }
+    }
 }
@@ -1,6 +1,107 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`SubusersApi::get_subusers`], set with a builder.
+pub struct SubusersApiGetSubusersRequest<'a> {
+    client: &'a SubusersApi,
+    username: Option<&'a str>,
+    limit: Option<i64>,
+    offset: Option<i64>,
+}
+
+impl<'a> SubusersApiGetSubusersRequest<'a> {
+    /// Sets the `username` query parameter.
+    pub fn username(mut self, username: &'a str) -> Self {
+        self.username = Some(username);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Subuser>>> {
+        let Self {
+            client,
+            username,
+            limit,
+            offset,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = offset {
+            query_args.push(("offset".to_string(), v.to_string()));
+        }
+        if let Some(v) = username {
+            query_args.push(("username".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(&format!("/subusers?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`SubusersApi::get_subusers_reputations`], set with a builder.
+pub struct SubusersApiGetSubusersReputationsRequest<'a> {
+    client: &'a SubusersApi,
+    usernames: Option<&'a str>,
+}
+
+impl<'a> SubusersApiGetSubusersReputationsRequest<'a> {
+    /// Sets the `usernames` query parameter.
+    pub fn usernames(mut self, usernames: &'a str) -> Self {
+        self.usernames = Some(usernames);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GetSubusersReputationsResponse>>> {
+        let Self { client, usernames } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = usernames {
+            query_args.push(("usernames".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/subusers/reputations?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct SubusersApi {
     pub client: Client,
 }
@@ -54,6 +155,25 @@ impl SubusersApi {
             )
             .await
     }
+    /**
+     * List all Subusers.
+     *
+     * This function performs a `GET` to the `/subusers` endpoint.
+     *
+     * As opposed to `get_subusers`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * **This endpoint allows you to retrieve a list of all of your subusers.**
+     *
+     * You can choose to retrieve specific subusers as well as limit the results that come back from the API.
+     */
+    pub fn get_subusers_request(&self) -> SubusersApiGetSubusersRequest<'_> {
+        SubusersApiGetSubusersRequest {
+            client: self,
+            username: None,
+            limit: None,
+            offset: None,
+        }
+    }
     /**
      * List all Subusers.
      *
@@ -89,6 +209,33 @@ impl SubusersApi {
             )
             .await
     }
+    /**
+     * List all Subusers.
+     *
+     * This function performs a `GET` to the `/subusers` endpoint.
+     *
+     * As opposed to `get_all_subusers`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * **This endpoint allows you to retrieve a list of all of your subusers.**
+     *
+     * You can choose to retrieve specific subusers as well as limit the results that come back from the API.
+     */
+    pub fn get_all_subusers_stream(
+        &self,
+        username: &str,
+        offset: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Subuser>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if offset > 0 {
+            query_args.push(("offset".to_string(), offset.to_string()));
+        }
+        if !username.is_empty() {
+            query_args.push(("username".to_string(), username.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/subusers?{}", query_), None);
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Create Subuser.
      *
@@ -205,6 +352,23 @@ impl SubusersApi {
             )
             .await
     }
+    /**
+     * Retrieve Subuser Reputations.
+     *
+     * This function performs a `GET` to the `/subusers/reputations` endpoint.
+     *
+     * As opposed to `get_subusers_reputations`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * **This endpoint allows you to request the reputations for your subusers.**
+     *
+     * Subuser sender reputations give a good idea how well a sender is doing with regards to how recipients and recipient servers react to the mail that is being received. When a bounce, spam report, or other negative action happens on a sent email, it will affect your sender rating.
+     */
+    pub fn get_subusers_reputations_request(&self) -> SubusersApiGetSubusersReputationsRequest<'_> {
+        SubusersApiGetSubusersReputationsRequest {
+            client: self,
+            usernames: None,
+        }
+    }
     /**
      * Retrieve Subuser Reputations.
      *
@@ -238,6 +402,33 @@ impl SubusersApi {
             )
             .await
     }
+    /**
+     * Retrieve Subuser Reputations.
+     *
+     * This function performs a `GET` to the `/subusers/reputations` endpoint.
+     *
+     * As opposed to `get_all_subusers_reputations`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * **This endpoint allows you to request the reputations for your subusers.**
+     *
+     * Subuser sender reputations give a good idea how well a sender is doing with regards to how recipients and recipient servers react to the mail that is being received. When a bounce, spam report, or other negative action happens on a sent email, it will affect your sender rating.
+     */
+    pub fn get_all_subusers_reputations_stream(
+        &self,
+        usernames: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::GetSubusersReputationsResponse>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !usernames.is_empty() {
+            query_args.push(("usernames".to_string(), usernames.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/subusers/reputations?{}", query_), None);
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Update IPs assigned to a subuser.
      *
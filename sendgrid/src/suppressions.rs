@@ -73,6 +73,28 @@ impl Suppressions {
             )
             .await
     }
+    /**
+     * Retrieve all suppressions for a suppression group.
+     *
+     * This function performs a `GET` to the `/asm/groups/{group_id}/suppressions` endpoint.
+     *
+     * As opposed to `get_all_asm_groups_group`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * **This endpoint allows you to retrieve all suppressed email addresses belonging to the given group.**
+     */
+    pub fn get_all_asm_groups_group_stream(
+        &self,
+        group_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<String>> + Unpin + '_ {
+        let url = self.client.url(
+            &format!(
+                "/asm/groups/{}/suppressions",
+                crate::progenitor_support::encode_path(group_id),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Add suppressions to a suppression group.
      *
@@ -191,6 +213,22 @@ impl Suppressions {
             )
             .await
     }
+    /**
+     * Retrieve all suppressions.
+     *
+     * This function performs a `GET` to the `/asm/suppressions` endpoint.
+     *
+     * As opposed to `get_all_asm`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * **This endpoint allows you to retrieve a list of all suppressions.**
+     */
+    pub fn get_all_asm_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::GetAsmSuppressionsResponse>> + Unpin + '_
+    {
+        let url = self.client.url("/asm/suppressions", None);
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Retrieve all suppression groups for an email address.
      *
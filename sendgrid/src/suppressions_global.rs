@@ -1,6 +1,82 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`SuppressionsGlobal::get_suppression_unsubscribes`], set with a builder.
+pub struct SuppressionsGlobalGetSuppressionUnsubscribesRequest<'a> {
+    client: &'a SuppressionsGlobal,
+    start_time: Option<i64>,
+    end_time: Option<i64>,
+    limit: Option<i64>,
+    offset: Option<i64>,
+}
+
+impl<'a> SuppressionsGlobalGetSuppressionUnsubscribesRequest<'a> {
+    /// Sets the `start_time` query parameter.
+    pub fn start_time(mut self, start_time: i64) -> Self {
+        self.start_time = Some(start_time);
+        self
+    }
+
+    /// Sets the `end_time` query parameter.
+    pub fn end_time(mut self, end_time: i64) -> Self {
+        self.end_time = Some(end_time);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GetSuppressionUnsubscribesResponse>>> {
+        let Self {
+            client,
+            start_time,
+            end_time,
+            limit,
+            offset,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = end_time {
+            query_args.push(("end_time".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = offset {
+            query_args.push(("offset".to_string(), v.to_string()));
+        }
+        if let Some(v) = start_time {
+            query_args.push(("start_time".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/suppression/unsubscribes?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct SuppressionsGlobal {
     pub client: Client,
 }
@@ -86,6 +162,26 @@ impl SuppressionsGlobal {
             )
             .await
     }
+    /**
+     * Retrieve all global suppressions.
+     *
+     * This function performs a `GET` to the `/suppression/unsubscribes` endpoint.
+     *
+     * As opposed to `get_suppression_unsubscribes`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * **This endpoint allows you to retrieve a list of all email address that are globally suppressed.**
+     */
+    pub fn get_suppression_unsubscribes_request(
+        &self,
+    ) -> SuppressionsGlobalGetSuppressionUnsubscribesRequest<'_> {
+        SuppressionsGlobalGetSuppressionUnsubscribesRequest {
+            client: self,
+            start_time: None,
+            end_time: None,
+            limit: None,
+            offset: None,
+        }
+    }
     /**
      * Retrieve all global suppressions.
      *
@@ -125,6 +221,39 @@ impl SuppressionsGlobal {
             )
             .await
     }
+    /**
+     * Retrieve all global suppressions.
+     *
+     * This function performs a `GET` to the `/suppression/unsubscribes` endpoint.
+     *
+     * As opposed to `get_all_suppression_unsubscribes`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * **This endpoint allows you to retrieve a list of all email address that are globally suppressed.**
+     */
+    pub fn get_all_suppression_unsubscribes_stream(
+        &self,
+        start_time: i64,
+        end_time: i64,
+        offset: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::GetSuppressionUnsubscribesResponse>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if end_time > 0 {
+            query_args.push(("end_time".to_string(), end_time.to_string()));
+        }
+        if offset > 0 {
+            query_args.push(("offset".to_string(), offset.to_string()));
+        }
+        if start_time > 0 {
+            query_args.push(("start_time".to_string(), start_time.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/suppression/unsubscribes?{}", query_), None);
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Retrieve a Global Suppression.
      *
@@ -1,6 +1,42 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`SuppressionsUnsubscribeGroups::get_asm_groups`], set with a builder.
+pub struct SuppressionsUnsubscribeGroupsGetAsmGroupsRequest<'a> {
+    client: &'a SuppressionsUnsubscribeGroups,
+    id: Option<i64>,
+}
+
+impl<'a> SuppressionsUnsubscribeGroupsGetAsmGroupsRequest<'a> {
+    /// Sets the `id` query parameter.
+    pub fn id(mut self, id: i64) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::SuppressionGroup>>> {
+        let Self { client, id } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = id {
+            query_args.push(("id".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(&format!("/asm/groups?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct SuppressionsUnsubscribeGroups {
     pub client: Client,
 }
@@ -45,6 +81,23 @@ impl SuppressionsUnsubscribeGroups {
             )
             .await
     }
+    /**
+     * Retrieve all suppression groups associated with the user.
+     *
+     * This function performs a `GET` to the `/asm/groups` endpoint.
+     *
+     * As opposed to `get_asm_groups`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * **This endpoint allows you to retrieve a list of all suppression groups created by this user.**
+     *
+     * This endpoint can also return information for multiple group IDs that you include in your request. To add a group ID to your request, simply append `?id=123456&id=123456`, with the appropriate group IDs.
+     */
+    pub fn get_asm_groups_request(&self) -> SuppressionsUnsubscribeGroupsGetAsmGroupsRequest<'_> {
+        SuppressionsUnsubscribeGroupsGetAsmGroupsRequest {
+            client: self,
+            id: None,
+        }
+    }
     /**
      * Retrieve all suppression groups associated with the user.
      *
@@ -76,6 +129,30 @@ impl SuppressionsUnsubscribeGroups {
             )
             .await
     }
+    /**
+     * Retrieve all suppression groups associated with the user.
+     *
+     * This function performs a `GET` to the `/asm/groups` endpoint.
+     *
+     * As opposed to `get_all_asm_groups`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * **This endpoint allows you to retrieve a list of all suppression groups created by this user.**
+     *
+     * This endpoint can also return information for multiple group IDs that you include in your request. To add a group ID to your request, simply append `?id=123456&id=123456`, with the appropriate group IDs.
+     */
+    pub fn get_all_asm_groups_stream(
+        &self,
+        id: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::SuppressionGroup>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if id > 0 {
+            query_args.push(("id".to_string(), id.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/asm/groups?{}", query_), None);
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Create a new suppression group.
      *
@@ -1,6 +1,110 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Teammates::get`], set with a builder.
+pub struct TeammatesGetRequest<'a> {
+    client: &'a Teammates,
+    limit: Option<u64>,
+    offset: Option<u64>,
+}
+
+impl<'a> TeammatesGetRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: u64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: u64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::GetTeammatesResponse>> {
+        let Self {
+            client,
+            limit,
+            offset,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = offset {
+            query_args.push(("offset".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(&format!("/teammates?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Teammates::get_scopes_requests`], set with a builder.
+pub struct TeammatesGetScopesRequestsRequest<'a> {
+    client: &'a Teammates,
+    limit: Option<i64>,
+    offset: Option<i64>,
+}
+
+impl<'a> TeammatesGetScopesRequestsRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GetScopesRequestsResponse>>> {
+        let Self {
+            client,
+            limit,
+            offset,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = offset {
+            query_args.push(("offset".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/scopes/requests?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct Teammates {
     pub client: Client,
 }
@@ -50,6 +154,24 @@ impl Teammates {
             )
             .await
     }
+    /**
+     * Retrieve all teammates.
+     *
+     * This function performs a `GET` to the `/teammates` endpoint.
+     *
+     * As opposed to `get`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * **This endpoint allows you to retrieve a list of all current Teammates.**
+     *
+     * You can limit the number of results returned using the `limit` query paramater. To return results from a specific Teammate, use the `offset` paramter. The Response Headers will include pagination info.
+     */
+    pub fn get_request(&self) -> TeammatesGetRequest<'_> {
+        TeammatesGetRequest {
+            client: self,
+            limit: None,
+            offset: None,
+        }
+    }
     /**
      * Invite teammate.
      *
@@ -154,6 +276,24 @@ impl Teammates {
             )
             .await
     }
+    /**
+     * Retrieve access requests.
+     *
+     * This function performs a `GET` to the `/scopes/requests` endpoint.
+     *
+     * As opposed to `get_scopes_requests`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * **This endpoint allows you to retrieve a list of all recent access requests.**
+     *
+     * The Response Header's `link` parameter will include pagination info.
+     */
+    pub fn get_scopes_requests_request(&self) -> TeammatesGetScopesRequestsRequest<'_> {
+        TeammatesGetScopesRequestsRequest {
+            client: self,
+            limit: None,
+            offset: None,
+        }
+    }
     /**
      * Retrieve access requests.
      *
@@ -187,6 +327,32 @@ impl Teammates {
             )
             .await
     }
+    /**
+     * Retrieve access requests.
+     *
+     * This function performs a `GET` to the `/scopes/requests` endpoint.
+     *
+     * As opposed to `get_all_scopes_requests`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * **This endpoint allows you to retrieve a list of all recent access requests.**
+     *
+     * The Response Header's `link` parameter will include pagination info.
+     */
+    pub fn get_all_scopes_requests_stream(
+        &self,
+        offset: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::GetScopesRequestsResponse>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if offset > 0 {
+            query_args.push(("offset".to_string(), offset.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/scopes/requests?{}", query_), None);
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Retrieve all pending teammates.
      *
@@ -37,10 +37,14 @@ impl MailOps for crate::mail_send::MailSend {
             from: crate::types::FromEmailObject {
                 email: from.to_string(),
                 name: String::new(),
+                #[cfg(feature = "extra-fields")]
+                extra: Default::default(),
             },
             content: vec![crate::types::Content {
                 value: message.to_string(),
                 type_: "text/plain".to_string(),
+                #[cfg(feature = "extra-fields")]
+                extra: Default::default(),
             }],
             ..Default::default()
         };
@@ -52,18 +56,24 @@ impl MailOps for crate::mail_send::MailSend {
             p.to.push(crate::types::ReplyTo {
                 email: to.to_string(),
                 name: String::new(),
+                #[cfg(feature = "extra-fields")]
+                extra: Default::default(),
             });
         }
         for cc in ccs {
             p.cc.push(crate::types::CcBccEmailObject {
                 email: cc.to_string(),
                 name: String::new(),
+                #[cfg(feature = "extra-fields")]
+                extra: Default::default(),
             });
         }
         for bcc in bccs {
             p.bcc.push(crate::types::CcBccEmailObject {
                 email: bcc.to_string(),
                 name: String::new(),
+                #[cfg(feature = "extra-fields")]
+                extra: Default::default(),
             });
         }
         mail.personalizations = vec![p];
@@ -87,11 +97,11 @@ impl MailOps for crate::mail_send::MailSend {
                 resp.headers().clone(),
                 (),
             )),
-            s => Err(ClientError::HttpError {
-                status: s,
-                headers: resp.headers().clone(),
-                error: "Posting to /mail/send".to_string(),
-            }),
+            status => {
+                let headers = resp.headers().clone();
+                let body = resp.bytes().await?;
+                Err(ClientError::from_response(status, headers, &body))
+            }
         }
     }
 }
@@ -1,6 +1,61 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`TransactionalTemplates::get_templates`], set with a builder.
+pub struct TransactionalTemplatesGetTemplatesRequest<'a> {
+    client: &'a TransactionalTemplates,
+    generations: Option<crate::types::Generations>,
+    page_size: f64,
+    page_token: Option<&'a str>,
+}
+
+impl<'a> TransactionalTemplatesGetTemplatesRequest<'a> {
+    /// Sets the `generations` query parameter.
+    pub fn generations(mut self, generations: crate::types::Generations) -> Self {
+        self.generations = Some(generations);
+        self
+    }
+
+    /// Sets the `page_token` query parameter.
+    pub fn page_token(mut self, page_token: &'a str) -> Self {
+        self.page_token = Some(page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::GetTemplatesResponse>> {
+        let Self {
+            client,
+            generations,
+            page_size,
+            page_token,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = generations {
+            query_args.push(("generations".to_string(), v.to_string()));
+        }
+        if !page_size.to_string().is_empty() {
+            query_args.push(("page_size".to_string(), page_size.to_string()));
+        }
+        if let Some(v) = page_token {
+            query_args.push(("page_token".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(&format!("/templates?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct TransactionalTemplates {
     pub client: Client,
 }
@@ -53,6 +108,26 @@ impl TransactionalTemplates {
             )
             .await
     }
+    /**
+     * Retrieve paged transactional templates.
+     *
+     * This function performs a `GET` to the `/templates` endpoint.
+     *
+     * As opposed to `get_templates`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * **This endpoint allows you to retrieve all transactional templates.**
+     */
+    pub fn get_templates_request(
+        &self,
+        page_size: f64,
+    ) -> TransactionalTemplatesGetTemplatesRequest<'_> {
+        TransactionalTemplatesGetTemplatesRequest {
+            client: self,
+            generations: None,
+            page_size,
+            page_token: None,
+        }
+    }
     /**
      * Create a transactional template.
      *
@@ -26,6 +26,10 @@ pub struct PartnerSettingsNewRelic {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub license_key: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -65,6 +69,10 @@ pub struct SubscriptionTrackingSettings {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -87,6 +95,10 @@ pub struct Errors {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub message: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -139,6 +151,10 @@ pub struct ContactDbRecipientResponse {
         deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub updated_count: f64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -158,6 +174,10 @@ pub struct CampaignResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub status: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types are flattened into one object:
@@ -188,8 +208,9 @@ pub enum Operator {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Operator {
@@ -201,7 +222,7 @@ impl std::fmt::Display for Operator {
             Operator::Lt => "lt",
             Operator::Ne => "ne",
             Operator::Noop => "",
-            Operator::FallthroughString => "*",
+            Operator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -222,8 +243,9 @@ pub enum AndOr {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AndOr {
@@ -232,7 +254,7 @@ impl std::fmt::Display for AndOr {
             AndOr::And => "and",
             AndOr::Or => "or",
             AndOr::Noop => "",
-            AndOr::FallthroughString => "*",
+            AndOr::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -268,6 +290,10 @@ pub struct ContactdbSegmentsConditions {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub value: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -296,11 +322,20 @@ pub struct BounceResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub status: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// helper text or docs for troubleshooting
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Help {}
+pub struct Help {
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Contacts {
@@ -366,6 +401,10 @@ pub struct Contacts {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub zip: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -385,6 +424,10 @@ pub struct Users {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub username: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The DKIM record for messages sent using this authenticated domain.
@@ -426,6 +469,10 @@ pub struct Dkim {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub valid: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -504,6 +551,10 @@ pub struct ReverseDns {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub valid: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -542,6 +593,10 @@ pub struct SenderId {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub verified: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types are flattened into one object:
@@ -577,8 +632,9 @@ pub enum Type {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Type {
@@ -588,7 +644,7 @@ impl std::fmt::Display for Type {
             Type::Number => "number",
             Type::Text => "text",
             Type::Noop => "",
-            Type::FallthroughString => "*",
+            Type::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -610,6 +666,10 @@ pub struct ContactdbCustomField {
     pub name: String,
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
     pub type_: Option<Type>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -649,6 +709,10 @@ pub struct Subuser {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub username: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -664,6 +728,10 @@ pub struct MailSettingsAddressWhitelabel {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub list: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -680,8 +748,9 @@ pub enum LinkBranding200ResponseDnsDomainCnameType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for LinkBranding200ResponseDnsDomainCnameType {
@@ -691,7 +760,7 @@ impl std::fmt::Display for LinkBranding200ResponseDnsDomainCnameType {
             LinkBranding200ResponseDnsDomainCnameType::Mx => "mx",
             LinkBranding200ResponseDnsDomainCnameType::Txt => "txt",
             LinkBranding200ResponseDnsDomainCnameType::Noop => "",
-            LinkBranding200ResponseDnsDomainCnameType::FallthroughString => "*",
+            LinkBranding200ResponseDnsDomainCnameType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -741,6 +810,10 @@ pub struct DomainCname {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub valid: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The DNS record generated to verify who created the link branding.
@@ -777,6 +850,10 @@ pub struct OwnerCname {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub valid: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The DNS records generated for this link branding.
@@ -791,6 +868,10 @@ pub struct Dns {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub owner_cname: Option<OwnerCname>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -859,6 +940,10 @@ pub struct LinkBranding200Response {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub valid: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -878,6 +963,10 @@ pub struct FromEmailObject {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -891,6 +980,10 @@ pub struct ApiKeyNameScopes {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub scopes: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types are flattened into one object:
@@ -938,6 +1031,10 @@ pub struct ContactdbSegments {
         deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub recipient_count: f64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -954,6 +1051,10 @@ pub struct ApiKeyNameId {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The individual events and their stats.
@@ -977,6 +1078,10 @@ pub struct AdvancedStatsOpens {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub unique_opens: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -992,6 +1097,10 @@ pub struct MailSettingsTemplate {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub html_content: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -1011,6 +1120,10 @@ pub struct IpWarmupResponse {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub start_date: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -1033,6 +1146,10 @@ pub struct Monitor {
         deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub frequency: f64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -1060,6 +1177,10 @@ pub struct GlobalErrorResponseSchemaErrors {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub message: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -1076,6 +1197,10 @@ pub struct GlobalErrorResponseSchema {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The individual events and their stats.
@@ -1153,6 +1278,10 @@ pub struct AdvancedStatsMailboxProvider {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub spam_reports: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types are flattened into one object:
@@ -1182,6 +1311,10 @@ pub struct ContactdbCustomFieldWithId {
         deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub id: f64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types are flattened into one object:
@@ -1208,6 +1341,10 @@ pub struct IpPool {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -1247,6 +1384,10 @@ pub struct GoogleAnalyticsSettings {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub utm_term: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -1368,6 +1509,10 @@ pub struct WebhooksEventWebhookResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -1439,6 +1584,10 @@ pub struct UserProfile {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub zip: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -1460,6 +1609,10 @@ pub struct MailSettingsFooter {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub plain_content: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -1560,6 +1713,10 @@ pub struct Metrics {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub unsubscribes: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -1585,6 +1742,10 @@ pub struct Stats {
         rename = "type"
     )]
     pub type_: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -1604,6 +1765,10 @@ pub struct CategoryStats {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub stats: Vec<Stats>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -1630,12 +1795,20 @@ pub struct ParseSetting {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct TransactionalTemplate {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub warning: Option<Warning>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types are flattened into one object:
@@ -1674,6 +1847,10 @@ pub struct ContactdbList {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub recipient_count: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -1718,6 +1895,10 @@ pub struct SuppressionGroup {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub unsubscribes: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -1739,6 +1920,10 @@ pub struct MailSettingsBouncePurge {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub soft_bounces: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -1749,6 +1934,10 @@ pub struct TransactionalTemplateVersionOutput {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub warnings: Vec<Warning>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types are flattened into one object:
@@ -1796,6 +1985,10 @@ pub struct Permissions {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub web: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -1808,6 +2001,10 @@ pub struct Credentials {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub username: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -1823,6 +2020,10 @@ pub struct MailSettingsForwardSpam {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub enabled: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -1837,8 +2038,9 @@ pub enum Editor {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Editor {
@@ -1847,7 +2049,7 @@ impl std::fmt::Display for Editor {
             Editor::Code => "code",
             Editor::Design => "design",
             Editor::Noop => "",
-            Editor::FallthroughString => "*",
+            Editor::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1938,6 +2140,10 @@ pub struct CampaignsRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub title: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -2038,6 +2244,10 @@ pub struct SubuserStatsMetrics {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub unsubscribes: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -2081,6 +2291,10 @@ pub struct SubuserStats {
         rename = "type"
     )]
     pub type_: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -2097,6 +2311,10 @@ pub struct SubuserStatsData {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub stats: Vec<SubuserStats>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -2111,8 +2329,9 @@ pub enum Status {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Status {
@@ -2121,7 +2340,7 @@ impl std::fmt::Display for Status {
             Status::Cancel => "cancel",
             Status::Pause => "pause",
             Status::Noop => "",
-            Status::FallthroughString => "*",
+            Status::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2141,6 +2360,10 @@ pub struct UserScheduledSendStatus {
      */
     #[serde(default, skip_serializing_if = "Status::is_noop")]
     pub status: Status,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types are flattened into one object:
@@ -2189,6 +2412,10 @@ pub struct ContactdbSegmentsWithId {
         deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub id: f64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types are flattened into one object:
@@ -2225,6 +2452,10 @@ pub struct AdvancedStatsClicks {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub unique_clicks: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -2319,6 +2550,10 @@ pub struct Recipients {
         deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub updated_at: f64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -2329,6 +2564,10 @@ pub struct ContactdbRecipient {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub recipients: Vec<Recipients>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -2344,6 +2583,10 @@ pub struct MailSettingsPatch {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub enabled: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -2359,6 +2602,10 @@ pub struct MailSettingsForwardBounce {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub enabled: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -2372,6 +2619,10 @@ pub struct MailBatchId {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub batch_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -2386,6 +2637,10 @@ pub struct CreditAllocation {
         rename = "type"
     )]
     pub type_: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -2431,6 +2686,10 @@ pub struct SubuserPost {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub username: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -2444,6 +2703,10 @@ pub struct ContactdbRecipientCount {
         deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub recipient_count: f64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -2457,6 +2720,10 @@ pub struct ContactdbCustomFieldWithValue {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub value: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types are flattened into one object:
@@ -2523,6 +2790,10 @@ pub struct TransactionalTemplateVersionCreate {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub test_data: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -2576,6 +2847,10 @@ pub struct TransactionalTemplatesVersionOutputLean {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub updated_at: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -2590,8 +2865,9 @@ pub enum Generation {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Generation {
@@ -2600,7 +2876,7 @@ impl std::fmt::Display for Generation {
             Generation::Dynamic => "dynamic",
             Generation::Legacy => "legacy",
             Generation::Noop => "",
-            Generation::FallthroughString => "*",
+            Generation::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2653,6 +2929,10 @@ pub struct TransactionalTemplatesTemplateLean {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub versions: Vec<TransactionalTemplatesVersionOutputLean>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -2757,6 +3037,10 @@ pub struct ContactDetails {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub updated_at: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Result map of the import job.
@@ -2816,6 +3100,10 @@ pub struct Results {
         deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub updated_count: f64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -2852,6 +3140,10 @@ pub struct ContactImport {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub status: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -2876,6 +3168,10 @@ pub struct CustomFields {
         rename = "custom_field_name2"
     )]
     pub custom_field_name_2: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -2972,6 +3268,10 @@ pub struct Contact {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub state_province_region: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -2984,6 +3284,10 @@ pub struct SingleContactRequest {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub list_ids: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -3000,8 +3304,9 @@ pub enum ContactExportStatus {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ContactExportStatus {
@@ -3011,7 +3316,7 @@ impl std::fmt::Display for ContactExportStatus {
             ContactExportStatus::Pending => "pending",
             ContactExportStatus::Ready => "ready",
             ContactExportStatus::Noop => "",
-            ContactExportStatus::FallthroughString => "*",
+            ContactExportStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3092,6 +3397,10 @@ pub struct ContactExport {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub urls: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -3152,6 +3461,10 @@ pub struct ContactSummary {
         deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub updated_at: f64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -3221,6 +3534,10 @@ pub struct ContactRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub state_province_region: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -3355,6 +3672,10 @@ pub struct ContactDetails2 {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub whatsapp: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -3366,6 +3687,10 @@ pub struct SelfMetadata {
         rename = "self"
     )]
     pub self_: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -3397,6 +3722,10 @@ pub struct Error {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub parameter: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -3413,6 +3742,10 @@ pub struct Link {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub rel: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -3442,6 +3775,10 @@ pub struct Metadata {
         rename = "self"
     )]
     pub self_: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -3464,6 +3801,10 @@ pub struct Webhook {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -3488,6 +3829,10 @@ pub struct List {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -3501,8 +3846,9 @@ pub enum FieldType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for FieldType {
@@ -3512,7 +3858,7 @@ impl std::fmt::Display for FieldType {
             FieldType::Number => "Number",
             FieldType::Text => "Text",
             FieldType::Noop => "",
-            FieldType::FallthroughString => "*",
+            FieldType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3545,6 +3891,10 @@ pub struct ReservedFieldDefinitionsResponse {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub read_only: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -3569,6 +3919,10 @@ pub struct CustomFieldDefinitionsResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -3591,6 +3945,10 @@ pub struct SegmentWrite {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub query_dsl: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -3646,6 +4004,10 @@ pub struct SegmentSummary {
         deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -3668,6 +4030,10 @@ pub struct L {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub v: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -3685,6 +4051,10 @@ pub struct SegmentQueryJsonContactsL {
     pub op: String,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub r: Option<L>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -3713,6 +4083,10 @@ pub struct SegmentQueryJsonContactsLR {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub v: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -3730,6 +4104,10 @@ pub struct R {
     pub op: String,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub r: Option<L>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -3747,6 +4125,10 @@ pub struct SegmentQueryJsonContactsLData {
     pub op: String,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub r: Option<R>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -3769,6 +4151,10 @@ pub struct SegmentQueryJsonContactsR {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub v: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -3786,6 +4172,10 @@ pub struct SegmentQueryJsonContactsRData {
     pub op: String,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub r: Option<SegmentQueryJsonContactsR>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -3803,12 +4193,20 @@ pub struct SegmentQueryJsonContacts {
     pub op: String,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub r: Option<SegmentQueryJsonContactsRData>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct SegmentQueryJson {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub contacts: Option<SegmentQueryJsonContacts>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The user may choose to create up to 120 custom fields or none at all. This is not a reserved field.
@@ -3834,6 +4232,10 @@ pub struct ContactResponseCustomFields {
         rename = "custom_field_name2"
     )]
     pub custom_field_name_2: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -3950,6 +4352,10 @@ pub struct ContactResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub state_province_region: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -3960,6 +4366,10 @@ pub struct TneSenderId {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub id: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Only verified sender identities can be used to send email.
@@ -3982,6 +4392,10 @@ pub struct Verified {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub status: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -4011,6 +4425,10 @@ pub struct TneSenderData {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub verified: Option<Verified>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types are flattened into one object:
@@ -4058,6 +4476,10 @@ pub struct ApiError {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub message: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -4068,6 +4490,10 @@ pub struct ApiErrors {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub errors: Vec<ApiError>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -4097,6 +4523,10 @@ pub struct MetadataType {
         rename = "self"
     )]
     pub self_: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -4119,6 +4549,10 @@ pub struct DesignInput {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub plain_content: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types are flattened into one object:
@@ -4182,6 +4616,10 @@ pub struct DesignOutputSummary {
         deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types are flattened into one object:
@@ -4210,6 +4648,10 @@ pub struct DesignDuplicateInput {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -4353,6 +4795,10 @@ pub struct ContactDetails3 {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub whatsapp: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -4363,6 +4809,10 @@ pub struct Warning {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub message: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -4394,6 +4844,10 @@ pub struct ErrorsData {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub parameter: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// If the request is incorrect, an array of errors will be returned.
@@ -4405,6 +4859,10 @@ pub struct ErrorsDataType {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub errors: Vec<ErrorsData>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -4419,8 +4877,9 @@ pub enum AbPhase {
     Send,
     #[serde(rename = "test")]
     Test,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AbPhase {
@@ -4429,7 +4888,7 @@ impl std::fmt::Display for AbPhase {
             AbPhase::All => "all",
             AbPhase::Send => "send",
             AbPhase::Test => "test",
-            AbPhase::FallthroughString => "*",
+            AbPhase::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -4471,6 +4930,10 @@ pub struct SinglesendsResponseResults {
     pub id: String,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub stats: Option<MetricsData>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -4483,6 +4946,10 @@ pub struct SinglesendsResponse {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub results: Vec<SinglesendsResponseResults>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -4516,6 +4983,10 @@ pub struct AutomationsResponseResults {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub step_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -4528,6 +4999,10 @@ pub struct AutomationsResponse {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub results: Vec<AutomationsResponseResults>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -4604,6 +5079,10 @@ pub struct MetricsData {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub unsubscribes: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -4617,8 +5096,9 @@ pub enum SinglesendSearchStatus {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SinglesendSearchStatus {
@@ -4628,7 +5108,7 @@ impl std::fmt::Display for SinglesendSearchStatus {
             SinglesendSearchStatus::Scheduled => "scheduled",
             SinglesendSearchStatus::Triggered => "triggered",
             SinglesendSearchStatus::Noop => "",
-            SinglesendSearchStatus::FallthroughString => "*",
+            SinglesendSearchStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -4660,6 +5140,10 @@ pub struct SinglesendSearch {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub status: Vec<SinglesendSearchStatus>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -4690,6 +5174,10 @@ pub struct SendTo {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub segment_ids: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -4773,6 +5261,10 @@ pub struct EmailConfig {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub suppression_group_id: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -4802,6 +5294,10 @@ pub struct SinglesendRequest {
     pub send_at: Option<chrono::DateTime<chrono::Utc>>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub send_to: Option<SendTo>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -4814,6 +5310,10 @@ pub struct SinglesendSchedule {
     pub send_at: Option<chrono::DateTime<chrono::Utc>>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub status: Option<SinglesendSearchStatus>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -4845,6 +5345,10 @@ pub struct Warnings {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub warning_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -4855,6 +5359,10 @@ pub struct SinglesendWarning {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub warnings: Vec<Warnings>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -4877,6 +5385,10 @@ pub struct ReplyTo {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -4968,6 +5480,10 @@ pub struct WebhooksEventWebhookUpdateWithOAuthRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -5053,6 +5569,10 @@ pub struct WebhooksEventWebhookRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -5072,6 +5592,10 @@ pub struct ReplyEmailObject {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -5106,6 +5630,10 @@ pub struct AutomationsLinkStatsResponseResults {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub url_location: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -5127,6 +5655,10 @@ pub struct AutomationsLinkStatsResponse {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_clicks: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -5156,6 +5688,10 @@ pub struct LinkTrackingMetadata {
         rename = "self"
     )]
     pub self_: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -5195,6 +5731,10 @@ pub struct SinglesendsLinkStatsResponseResults {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub url_location: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -5216,6 +5756,10 @@ pub struct SinglesendsLinkStatsResponse {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_clicks: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -5235,6 +5779,10 @@ pub struct Subusers {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub username: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -5251,6 +5799,10 @@ pub struct DomainAuthentication200Response {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub subusers: Vec<Subusers>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types are flattened into one object:
@@ -5278,8 +5830,9 @@ pub enum AbTestSummaryType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AbTestSummaryType {
@@ -5288,7 +5841,7 @@ impl std::fmt::Display for AbTestSummaryType {
             AbTestSummaryType::Content => "content",
             AbTestSummaryType::Subject => "subject",
             AbTestSummaryType::Noop => "",
-            AbTestSummaryType::FallthroughString => "*",
+            AbTestSummaryType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -5314,8 +5867,9 @@ pub enum WinnerCriteria {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for WinnerCriteria {
@@ -5325,7 +5879,7 @@ impl std::fmt::Display for WinnerCriteria {
             WinnerCriteria::Manual => "manual",
             WinnerCriteria::Open => "open",
             WinnerCriteria::Noop => "",
-            WinnerCriteria::FallthroughString => "*",
+            WinnerCriteria::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -5395,6 +5949,10 @@ pub struct AbTestSummary {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub winning_template_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -5456,6 +6014,10 @@ pub struct SinglesendResponseShort {
         deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -5475,6 +6037,10 @@ pub struct CcBccEmailObject {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -5555,6 +6121,10 @@ pub struct VerifiedSenderRequestSchema {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub zip: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -5642,6 +6212,10 @@ pub struct VerifiedSenderResponseSchema {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub zip: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -5673,6 +6247,10 @@ pub struct Result {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub updated_at: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -5683,6 +6261,10 @@ pub struct IpAccessResponse {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub result: Vec<Result>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -5759,6 +6341,10 @@ pub struct StatsAdvancedGlobal {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub unsubscribes: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types are flattened into one object:
@@ -5781,6 +6367,10 @@ pub struct StatsAdvancedBaseSchema {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub metrics: Option<Help>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -5803,6 +6393,10 @@ pub struct StatsAdvancedBaseSchemaData {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub stats: Vec<StatsAdvancedBaseSchema>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -5818,6 +6412,10 @@ pub struct FullSegment {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub query_json: Option<Help>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types are flattened into one object:
@@ -5856,6 +6454,10 @@ pub struct From {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -5917,6 +6519,10 @@ pub struct SendersRequestBody {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub zip: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -5931,6 +6537,10 @@ pub struct EnforcedTlsRequestResponse {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub require_valid_cert: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -5964,6 +6574,10 @@ pub struct SinglesendResponse {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub warnings: Vec<Warnings>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types are flattened into one object:
@@ -6007,6 +6621,10 @@ pub struct DesignCommonFields {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub subject: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types are flattened into one object:
@@ -6042,6 +6660,10 @@ pub struct InvalidEmail {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub reason: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -6058,8 +6680,9 @@ pub enum EmailActivityResponseCommonFieldsStatus {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EmailActivityResponseCommonFieldsStatus {
@@ -6069,7 +6692,7 @@ impl std::fmt::Display for EmailActivityResponseCommonFieldsStatus {
             EmailActivityResponseCommonFieldsStatus::NotDelivered => "not delivered",
             EmailActivityResponseCommonFieldsStatus::Processed => "processed",
             EmailActivityResponseCommonFieldsStatus::Noop => "",
-            EmailActivityResponseCommonFieldsStatus::FallthroughString => "*",
+            EmailActivityResponseCommonFieldsStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -6109,6 +6732,10 @@ pub struct EmailActivityResponseCommonFields {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub to_email: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -6122,6 +6749,10 @@ pub struct SuppressionsRequestBody {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub recipient_emails: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -6143,6 +6774,10 @@ pub struct SuppressionGroupRequestBase {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -6177,6 +6812,10 @@ pub struct SsoCertificateBody {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub public_certificate: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -6217,6 +6856,10 @@ pub struct SsoIntegration {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub single_signon_url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types are flattened into one object:
@@ -6277,6 +6920,10 @@ pub struct CreateIntegrationRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub signout_url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -6298,6 +6945,10 @@ pub struct SsoTeammateResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub username: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types are flattened into one object:
@@ -6324,6 +6975,10 @@ pub struct GetScopesResponse {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub scopes: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types are flattened into one object:
@@ -6353,8 +7008,9 @@ pub enum UserType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for UserType {
@@ -6364,7 +7020,7 @@ impl std::fmt::Display for UserType {
             UserType::Owner => "owner",
             UserType::Teammate => "teammate",
             UserType::Noop => "",
-            UserType::FallthroughString => "*",
+            UserType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -6483,6 +7139,10 @@ pub struct SsoTeammatesPatchResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub zip: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types are flattened into one object:
@@ -6527,6 +7187,10 @@ pub struct SsoErrorResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub message: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -6547,6 +7211,10 @@ pub struct ClickTracking {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub enabled: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -6588,6 +7256,10 @@ pub struct SsoTeammateCommonFields {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub last_name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -6616,6 +7288,10 @@ pub struct SpamReportsResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub ip: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -6653,6 +7329,10 @@ pub struct BlocksResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub status: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -6663,6 +7343,10 @@ pub struct IpPoolsPoolResp {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -6685,6 +7369,10 @@ pub struct SenderRequestFrom {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -6735,6 +7423,10 @@ pub struct SenderRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub zip: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Segment status indicates whether the segment's contacts will be updated periodically
@@ -6758,6 +7450,10 @@ pub struct SegmentStatusResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub query_validation: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -6846,6 +7542,10 @@ pub struct AllSegmentsResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub updated_at: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -6860,6 +7560,10 @@ pub struct SegmentSummaryV2 {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub results: Vec<SegmentSummary>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -6961,6 +7665,10 @@ pub struct SegmentResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub updated_at: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -6983,6 +7691,10 @@ pub struct ErrorsSeg {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub message: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// If the request is incorrect, an array of errors will be returned.
@@ -6994,6 +7706,10 @@ pub struct ErrorsSegData {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub errors: Vec<ErrorsSeg>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -7022,6 +7738,10 @@ pub struct SegmentWriteV2 {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub query_dsl: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -7038,6 +7758,10 @@ pub struct SegmentUpdate {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub query_dsl: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -7051,8 +7775,9 @@ pub enum AbbvMessageStatus {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AbbvMessageStatus {
@@ -7062,7 +7787,7 @@ impl std::fmt::Display for AbbvMessageStatus {
             AbbvMessageStatus::NotDelivered => "not_delivered",
             AbbvMessageStatus::Processed => "processed",
             AbbvMessageStatus::Noop => "",
-            AbbvMessageStatus::FallthroughString => "*",
+            AbbvMessageStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -7135,6 +7860,10 @@ pub struct Messages {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub to_email: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -7167,8 +7896,9 @@ pub enum EventName {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EventName {
@@ -7186,7 +7916,7 @@ impl std::fmt::Display for EventName {
             EventName::SpamReport => "spam_report",
             EventName::Unsubscribe => "unsubscribe",
             EventName::Noop => "",
-            EventName::FallthroughString => "*",
+            EventName::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -7212,8 +7942,9 @@ pub enum BounceType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for BounceType {
@@ -7223,7 +7954,7 @@ impl std::fmt::Display for BounceType {
             BounceType::Bounced => "bounced",
             BounceType::Expired => "expired",
             BounceType::Noop => "",
-            BounceType::FallthroughString => "*",
+            BounceType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -7295,6 +8026,10 @@ pub struct Event {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -7309,8 +8044,9 @@ pub enum OutboundIpType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OutboundIpType {
@@ -7319,7 +8055,7 @@ impl std::fmt::Display for OutboundIpType {
             OutboundIpType::Dedicated => "dedicated",
             OutboundIpType::Shared => "shared",
             OutboundIpType::Noop => "",
-            OutboundIpType::FallthroughString => "*",
+            OutboundIpType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -7450,6 +8186,10 @@ pub struct Message {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub unique_args: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The DNS records for this authenticated domain.
@@ -7471,6 +8211,10 @@ pub struct DomainAuthenticationDns {
      * The DKIM record for messages sent using this authenticated domain.
      */
     pub subdomain_spf: Dkim,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -7564,6 +8308,10 @@ pub struct DomainAuthentication {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub valid: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The DNS records used to authenticate the sending domain.
@@ -7583,6 +8331,10 @@ pub struct AuthenticationDomainDns {
      * The DKIM record for messages sent using this authenticated domain.
      */
     pub mail_cname: Dkim,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -7685,6 +8437,10 @@ pub struct AuthenticationDomain {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub valid: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -7697,8 +8453,9 @@ pub enum AggregatedBy {
     #[serde(rename = "total")]
     #[default]
     Total,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AggregatedBy {
@@ -7706,7 +8463,7 @@ impl std::fmt::Display for AggregatedBy {
         match self {
             AggregatedBy::Day => "day",
             AggregatedBy::Total => "total",
-            AggregatedBy::FallthroughString => "*",
+            AggregatedBy::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -7721,8 +8478,9 @@ pub enum GroupBy {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GroupBy {
@@ -7731,7 +8489,7 @@ impl std::fmt::Display for GroupBy {
             GroupBy::AbPhase => "ab_phase",
             GroupBy::AbVariation => "ab_variation",
             GroupBy::Noop => "",
-            GroupBy::FallthroughString => "*",
+            GroupBy::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -7750,8 +8508,9 @@ pub enum TraitAutomationQueryParamsGroupBy {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for TraitAutomationQueryParamsGroupBy {
@@ -7759,7 +8518,7 @@ impl std::fmt::Display for TraitAutomationQueryParamsGroupBy {
         match self {
             TraitAutomationQueryParamsGroupBy::StepId => "step_id",
             TraitAutomationQueryParamsGroupBy::Noop => "",
-            TraitAutomationQueryParamsGroupBy::FallthroughString => "*",
+            TraitAutomationQueryParamsGroupBy::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -7780,8 +8539,9 @@ pub enum AbPhaseId {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AbPhaseId {
@@ -7790,7 +8550,7 @@ impl std::fmt::Display for AbPhaseId {
             AbPhaseId::Send => "send",
             AbPhaseId::Test => "test",
             AbPhaseId::Noop => "",
-            AbPhaseId::FallthroughString => "*",
+            AbPhaseId::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -7816,8 +8576,9 @@ pub enum TraitStatsAdvancedBaseQueryStringsAggregatedBy {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for TraitStatsAdvancedBaseQueryStringsAggregatedBy {
@@ -7827,7 +8588,7 @@ impl std::fmt::Display for TraitStatsAdvancedBaseQueryStringsAggregatedBy {
             TraitStatsAdvancedBaseQueryStringsAggregatedBy::Month => "month",
             TraitStatsAdvancedBaseQueryStringsAggregatedBy::Week => "week",
             TraitStatsAdvancedBaseQueryStringsAggregatedBy::Noop => "",
-            TraitStatsAdvancedBaseQueryStringsAggregatedBy::FallthroughString => "*",
+            TraitStatsAdvancedBaseQueryStringsAggregatedBy::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -7843,6 +8604,10 @@ impl TraitStatsAdvancedBaseQueryStringsAggregatedBy {
 pub struct TraitPagination200Response {
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "_metadata")]
     pub metadata: Option<Metadata>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -7856,6 +8621,10 @@ pub struct TraitGlobalErrors500Response {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub message: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -7866,6 +8635,10 @@ pub struct GetMessagesMsgResponse {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub errors: Vec<TraitGlobalErrors500Response>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -7893,6 +8666,10 @@ pub struct TraitCancelScheduledSendsErrors400Response {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub message: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -7912,6 +8689,10 @@ pub struct TraitCancelScheduledSendsErrors400ResponseData {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -7952,6 +8733,10 @@ pub struct TraitMakoErrorResponse400Errors {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub parameter: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -7962,6 +8747,10 @@ pub struct TraitMakoErrorResponse400 {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub errors: Vec<TraitMakoErrorResponse400Errors>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -8027,6 +8816,10 @@ pub struct Personalizations {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub to: Vec<ReplyTo>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -8050,6 +8843,10 @@ pub struct Content {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub value: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -8062,8 +8859,9 @@ pub enum Disposition {
     Attachment,
     #[serde(rename = "inline")]
     Inline,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Disposition {
@@ -8071,7 +8869,7 @@ impl std::fmt::Display for Disposition {
         match self {
             Disposition::Attachment => "attachment",
             Disposition::Inline => "inline",
-            Disposition::FallthroughString => "*",
+            Disposition::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -8121,6 +8919,10 @@ pub struct Attachments {
         rename = "type"
     )]
     pub type_: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// An object allowing you to specify how to handle unsubscribes.
@@ -8141,6 +8943,10 @@ pub struct Asm {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub groups_to_display: Vec<i64>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Allows you to bypass all unsubscribe groups and suppressions to ensure that the email is delivered to every single recipient. This should only be used in emergencies when it is absolutely necessary that every recipient receives your email. This filter cannot be combined with any other bypass filters. See our [documentation](https://sendgrid.com/docs/ui/sending-email/index-suppressions/#bypass-suppressions) for more about bypass filters.
@@ -8154,6 +8960,10 @@ pub struct BypassListManagement {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub enable: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Allows you to bypass the spam report list to ensure that the email is delivered to recipients. Bounce and unsubscribe lists will still be checked; addresses on these other lists will not receive the message. This filter cannot be combined with the `bypass_list_management` filter. See our [documentation](https://sendgrid.com/docs/ui/sending-email/index-suppressions/#bypass-suppressions) for more about bypass filters.
@@ -8167,6 +8977,10 @@ pub struct BypassSpamManagement {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub enable: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Allows you to bypass the bounce list to ensure that the email is delivered to recipients. Spam report and unsubscribe lists will still be checked; addresses on these other lists will not receive the message. This filter cannot be combined with the `bypass_list_management` filter. See our [documentation](https://sendgrid.com/docs/ui/sending-email/index-suppressions/#bypass-suppressions) for more about bypass filters.
@@ -8180,6 +8994,10 @@ pub struct BypassBounceManagement {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub enable: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Allows you to bypass the global unsubscribe list to ensure that the email is delivered to recipients. Bounce and spam report lists will still be checked; addresses on these other lists will not receive the message. This filter applies only to global unsubscribes and will not bypass group unsubscribes. This filter cannot be combined with the `bypass_list_management` filter. See our [documentation](https://sendgrid.com/docs/ui/sending-email/index-suppressions/#bypass-suppressions) for more about bypass filters.
@@ -8193,6 +9011,10 @@ pub struct BypassUnsubscribeManagement {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub enable: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The default footer that you would like included on every email.
@@ -8224,6 +9046,10 @@ pub struct Footer {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub text: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Sandbox Mode allows you to send a test email to ensure that your request body is valid and formatted correctly.
@@ -8237,6 +9063,10 @@ pub struct SandboxMode {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub enable: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A collection of different mail settings that you can use to specify how you would like this email to be handled.
@@ -8272,6 +9102,10 @@ pub struct MailSettings {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub sandbox_mode: Option<SandboxMode>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Allows you to track if a recipient clicked a link in your email.
@@ -8293,6 +9127,10 @@ pub struct PostMailSendRequestTrackingSettingsClick {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub enable_text: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Allows you to track if the email was opened by including a single pixel image in the body of the content. When the pixel is loaded, Twilio SendGrid can log that the email was opened.
@@ -8315,6 +9153,10 @@ pub struct OpenTracking {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub substitution_tag: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Allows you to insert a subscription management link at the bottom of the text and HTML bodies of your email. If you would like to specify the location of the link within your email, you may use the `substitution_tag`.
@@ -8355,6 +9197,10 @@ pub struct SubscriptionTracking {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub text: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Allows you to enable tracking provided by Google Analytics.
@@ -8413,6 +9259,10 @@ pub struct Ganalytics {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub utm_term: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Settings to determine how you would like to track the metrics of how your recipients interact with your email.
@@ -8438,6 +9288,10 @@ pub struct TrackingSettings {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub subscription_tracking: Option<SubscriptionTracking>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -8522,6 +9376,10 @@ pub struct PostMailSendRequest {
     pub template_id: String,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub tracking_settings: Option<TrackingSettings>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -8540,6 +9398,10 @@ pub struct CancelPauseAScheduledSendRequest {
      */
     #[serde(default, skip_serializing_if = "Status::is_noop")]
     pub status: Status,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -8550,6 +9412,10 @@ pub struct GetApiKeysResponse {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub result: Vec<ApiKeyNameId>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -8569,6 +9435,10 @@ pub struct CreateApiKeysRequest {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub scopes: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -8609,6 +9479,10 @@ pub struct CreateApiKeysResponse {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub scopes: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -8619,6 +9493,10 @@ pub struct GetApiKeysKeyResponse {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub result: Vec<ApiKeyNameScopesAllOf>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -8638,6 +9516,10 @@ pub struct PutApiKeysKeyRequest {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub scopes: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -8653,6 +9535,10 @@ pub struct GetScopesResponseErrors {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub message: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -8666,6 +9552,10 @@ pub struct GetScopesResponseData {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub errors: Vec<GetScopesResponseErrors>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -8679,6 +9569,10 @@ pub struct Ips {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub ip: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -8692,6 +9586,10 @@ pub struct PostAccessSettingsWhitelistRequest {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub ips: Vec<Ips>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -8705,6 +9603,10 @@ pub struct DeleteAccessSettingsWhitelistRequest {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub ids: Vec<i64>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -8756,6 +9658,10 @@ pub struct GetAccessSettingsActivityResponseResult {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub location: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -8769,6 +9675,10 @@ pub struct GetAccessSettingsActivityResponse {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub result: Vec<GetAccessSettingsActivityResponseResult>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -8797,6 +9707,10 @@ pub struct PostSsoCertificatesRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub public_certificate: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -8815,6 +9729,10 @@ pub struct PatchSsoCertificatesCertRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub public_certificate: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -8839,6 +9757,10 @@ pub struct PatchSsoTeammatesUsernameRequest {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub scopes: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -8878,6 +9800,10 @@ pub struct GetMailSettingsResponseResult {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub title: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -8891,6 +9817,10 @@ pub struct GetMailSettingsResponse {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub result: Vec<GetMailSettingsResponseResult>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -8903,6 +9833,10 @@ pub struct PatchMailSettingsAddressWhitelistRequest {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub list: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -8915,6 +9849,10 @@ pub struct PatchMailSettingsTemplateRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub html_content: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -8936,6 +9874,10 @@ pub struct PatchMailSettingsTemplateResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub html_content: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -8950,6 +9892,10 @@ pub struct PatchPartnerSettingsNewRelicRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub license_key: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -8963,6 +9909,10 @@ pub struct GetPartnerSettingsResponse {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub result: Vec<GetMailSettingsResponseResult>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -9089,6 +10039,10 @@ pub struct GetTeammatesResponseResult {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub zip: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -9099,6 +10053,10 @@ pub struct GetTeammatesResponse {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub result: Vec<GetTeammatesResponseResult>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -9126,6 +10084,10 @@ pub struct PostTeammatesRequest {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub scopes: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -9165,6 +10127,10 @@ pub struct PostTeammatesResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub token: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -9187,6 +10153,10 @@ pub struct PostTeammatesResponseErrors {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub message: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -9197,6 +10167,10 @@ pub struct PostSendersResponse {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub errors: Vec<PostTeammatesResponseErrors>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -9252,6 +10226,10 @@ pub struct GetScopesRequestsResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub username: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -9297,6 +10275,10 @@ pub struct GetTeammatesPendingResponseResult {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub token: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -9307,6 +10289,10 @@ pub struct GetTeammatesPendingResponse {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub result: Vec<GetTeammatesPendingResponseResult>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -9442,6 +10428,10 @@ pub struct GetTeammatesUsernameResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub zip: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -9460,6 +10450,10 @@ pub struct PatchTeammatesUsernameRequest {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub scopes: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -9473,6 +10467,10 @@ pub struct PatchScopesRequestsApproveResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub scope_group_name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -9487,8 +10485,9 @@ pub enum GetAlertsResponseType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GetAlertsResponseType {
@@ -9497,7 +10496,7 @@ impl std::fmt::Display for GetAlertsResponseType {
             GetAlertsResponseType::StatsNotification => "stats_notification",
             GetAlertsResponseType::UsageLimit => "usage_limit",
             GetAlertsResponseType::Noop => "",
-            GetAlertsResponseType::FallthroughString => "*",
+            GetAlertsResponseType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -9562,6 +10561,10 @@ pub struct GetAlertsResponse {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub updated_at: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -9596,6 +10599,10 @@ pub struct PostAlertsRequest {
         rename = "type"
     )]
     pub type_: GetAlertsResponseType,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -9652,6 +10659,10 @@ pub struct PostAlertsResponse {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub updated_at: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -9666,8 +10677,9 @@ pub enum GetAlertsAlertResponseType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GetAlertsAlertResponseType {
@@ -9676,7 +10688,7 @@ impl std::fmt::Display for GetAlertsAlertResponseType {
             GetAlertsAlertResponseType::StatsNotification => "stats_notification",
             GetAlertsAlertResponseType::UsageAlert => "usage_alert",
             GetAlertsAlertResponseType::Noop => "",
-            GetAlertsAlertResponseType::FallthroughString => "*",
+            GetAlertsAlertResponseType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -9741,6 +10753,10 @@ pub struct GetAlertsAlertResponse {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub updated_at: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -9763,6 +10779,10 @@ pub struct PatchAlertsAlertRequest {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub percentage: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -9864,6 +10884,10 @@ pub struct GetUserProfileResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub zip: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -9878,8 +10902,9 @@ pub enum GetUserAccountResponseType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GetUserAccountResponseType {
@@ -9888,7 +10913,7 @@ impl std::fmt::Display for GetUserAccountResponseType {
             GetUserAccountResponseType::Free => "free",
             GetUserAccountResponseType::Paid => "paid",
             GetUserAccountResponseType::Noop => "",
-            GetUserAccountResponseType::FallthroughString => "*",
+            GetUserAccountResponseType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -9920,6 +10945,10 @@ pub struct GetUserAccountResponse {
         rename = "type"
     )]
     pub type_: GetUserAccountResponseType,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -9933,6 +10962,10 @@ pub struct GetUserEmailResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub email: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -9943,6 +10976,10 @@ pub struct PutUserEmailRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub email: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -9953,6 +10990,10 @@ pub struct PutUserUsernameRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub username: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -9966,6 +11007,10 @@ pub struct PutUserUsernameResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub username: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -10021,6 +11066,10 @@ pub struct GetUserCreditsResponse {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub used: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -10043,6 +11092,10 @@ pub struct PutUserPasswordRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub old_password: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -10083,12 +11136,20 @@ pub struct PostSubusersRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub username: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PatchSubusersSubuserNameRequest {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub disabled: Option<bool>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -10111,6 +11172,10 @@ pub struct GetSubusersReputationsResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub username: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -10124,6 +11189,10 @@ pub struct PutSubusersSubuserNameIpsResponse {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub ips: Vec<std::net::Ipv4Addr>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -10136,8 +11205,9 @@ pub enum SortByDirection {
     #[serde(rename = "desc")]
     #[default]
     Desc,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SortByDirection {
@@ -10145,7 +11215,7 @@ impl std::fmt::Display for SortByDirection {
         match self {
             SortByDirection::Asc => "asc",
             SortByDirection::Desc => "desc",
-            SortByDirection::FallthroughString => "*",
+            SortByDirection::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -10175,8 +11245,9 @@ pub enum SortByMetric {
     UniqueOpens,
     #[serde(rename = "unsubscribes")]
     Unsubscribes,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SortByMetric {
@@ -10191,7 +11262,7 @@ impl std::fmt::Display for SortByMetric {
             SortByMetric::UniqueClicks => "unique_clicks",
             SortByMetric::UniqueOpens => "unique_opens",
             SortByMetric::Unsubscribes => "unsubscribes",
-            SortByMetric::FallthroughString => "*",
+            SortByMetric::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -10216,6 +11287,10 @@ pub struct PostWhitelabelLinksRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub subdomain: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The DNS record generated for the sending domain used for this branded link.
@@ -10238,6 +11313,10 @@ pub struct PostWhitelabelLinksValidateResponseValidationResultsDomainCname {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub valid: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The individual validation results for each of the DNS records associated with this branded link.
@@ -10252,6 +11331,10 @@ pub struct ValidationResults {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub owner_cname: Option<PostWhitelabelLinksValidateResponseValidationResultsDomainCname>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -10274,6 +11357,10 @@ pub struct PostWhitelabelLinksValidateResponse {
      * The individual validation results for each of the DNS records associated with this branded link.
      */
     pub validation_results: ValidationResults,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -10287,6 +11374,10 @@ pub struct PostWhitelabelLinksValidateResponseErrors {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub message: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -10300,6 +11391,10 @@ pub struct PostMarketingSegmentsResponse {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub errors: Vec<PostWhitelabelLinksValidateResponseErrors>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -10310,12 +11405,20 @@ pub struct PostWhitelabelLinksLinkSubuserRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub username: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PatchWhitelabelLinksRequest {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub default: Option<bool>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -10326,6 +11429,10 @@ pub struct PostIpsWarmupRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub ip: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -10341,6 +11448,10 @@ pub struct PostIpsWarmupResponseErrors {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub message: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -10354,6 +11465,10 @@ pub struct PostIpsWarmupResponse {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub errors: Vec<PostIpsWarmupResponseErrors>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -10382,6 +11497,10 @@ pub struct PostWhitelabelIpsRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub subdomain: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The specific results of the validation.
@@ -10392,6 +11511,10 @@ pub struct PostWhitelabelIpsValidateResponseValidationResults {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub a_record: Option<PostWhitelabelLinksValidateResponseValidationResultsDomainCname>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -10414,6 +11537,10 @@ pub struct PostWhitelabelIpsValidateResponse {
      * The specific results of the validation.
      */
     pub validation_results: PostWhitelabelIpsValidateResponseValidationResults,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -10433,6 +11560,10 @@ pub struct PostValidationsEmailRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub source: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -10449,8 +11580,9 @@ pub enum Verdict {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Verdict {
@@ -10460,7 +11592,7 @@ impl std::fmt::Display for Verdict {
             Verdict::Risky => "Risky",
             Verdict::Valid => "Valid",
             Verdict::Noop => "",
-            Verdict::FallthroughString => "*",
+            Verdict::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -10499,6 +11631,10 @@ pub struct Domain {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub is_suspected_disposable_address: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Checks on the local part of the email address.
@@ -10512,6 +11648,10 @@ pub struct LocalPart {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub is_suspected_role_address: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Additional checks on the email address.
@@ -10533,6 +11673,10 @@ pub struct Additional {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub has_suspected_bounces: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Granular checks for email address validity.
@@ -10550,6 +11694,10 @@ pub struct Checks {
      * Checks on the local part of the email address.
      */
     pub local_part: LocalPart,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -10626,11 +11774,19 @@ pub struct PostValidationsEmailResponseResult {
      */
     #[serde(default, skip_serializing_if = "Verdict::is_noop")]
     pub verdict: Verdict,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostValidationsEmailResponse {
     pub result: PostValidationsEmailResponseResult,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -10662,6 +11818,10 @@ pub struct PostWhitelabelDnsEmailRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub message: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -10684,12 +11844,20 @@ pub struct PostWhitelabelDnsEmailResponseErrors {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub field: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostWhitelabelDnsEmailResponse {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub errors: Option<PostWhitelabelDnsEmailResponseErrors>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -10726,6 +11894,10 @@ pub struct GetIpsAssignedResponse {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub warmup: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -10748,6 +11920,10 @@ pub struct GetIpsPoolsPoolNameResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub pool_name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -10758,6 +11934,10 @@ pub struct PutIpsPoolsPoolNameRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -10771,6 +11951,10 @@ pub struct DeleteIpsPoolsPoolNameResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub error: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -10842,6 +12026,10 @@ pub struct GetIpsResponse {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub whitelabeled: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -10860,6 +12048,10 @@ pub struct PostIpsRequest {
     pub subusers: Vec<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub warmup: Option<bool>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -10882,6 +12074,10 @@ pub struct PostIpsResponse {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub subusers: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -10909,6 +12105,10 @@ pub struct PostIpsResponseData {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub warmup: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -10937,6 +12137,10 @@ pub struct GetIpsRemainingResponseResults {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub remaining: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -10947,6 +12151,10 @@ pub struct GetIpsRemainingResponse {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub results: Vec<GetIpsRemainingResponseResults>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -11009,6 +12217,10 @@ pub struct GetIpsIpAddressResponse {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub whitelabeled: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -11052,6 +12264,10 @@ pub struct PostWhitelabelDomainsRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub username: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -11060,6 +12276,10 @@ pub struct PatchWhitelabelDomainsDomainRequest {
     pub custom_spf: Option<bool>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub default: Option<bool>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The CNAME record for the authenticated domain.
@@ -11082,6 +12302,10 @@ pub struct MailCname {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub valid: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A DNS record for this authenticated domain.
@@ -11104,6 +12328,10 @@ pub struct Dkim1 {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub valid: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The SPF record for the authenticated domain.
@@ -11126,6 +12354,10 @@ pub struct Spf {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub valid: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The individual DNS records that are checked when validating, including the reason for any invalid DNS records.
@@ -11151,6 +12383,10 @@ pub struct PostWhitelabelDomainsValidateResponseValidationResults {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub spf: Option<Spf>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -11174,6 +12410,10 @@ pub struct PostWhitelabelDomainsValidateResponse {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub validation_results: Option<PostWhitelabelDomainsValidateResponseValidationResults>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -11187,6 +12427,10 @@ pub struct PostWhitelabelDomainsValidateResponseData {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub errors: Vec<PostWhitelabelLinksValidateResponseErrors>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -11209,11 +12453,19 @@ pub struct GetVerifiedSendersDomainsResponseResults {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub soft_failures: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetVerifiedSendersDomainsResponse {
     pub results: GetVerifiedSendersDomainsResponseResults,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -11234,12 +12486,20 @@ pub struct GetVerifiedSendersStepsCompletedResponseResults {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub sender_verified: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetVerifiedSendersStepsCompletedResponse {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub results: Option<GetVerifiedSendersStepsCompletedResponseResults>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -11250,6 +12510,10 @@ pub struct GetVerifiedSendersResponse {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub results: Vec<VerifiedSenderResponseSchema>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -11281,6 +12545,10 @@ pub struct PostVerifiedSendersResponseErrors {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub message: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -11291,6 +12559,10 @@ pub struct PostVerifiedSendersResponse {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub errors: Vec<PostVerifiedSendersResponseErrors>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -11313,6 +12585,10 @@ pub struct GetVerifiedSendersVerifyTokenResponseErrors {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub message: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -11323,6 +12599,10 @@ pub struct PatchVerifiedSendersResponse {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub errors: Vec<GetVerifiedSendersVerifyTokenResponseErrors>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -11333,6 +12613,10 @@ pub struct DeleteVerifiedSendersResponse {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub errors: Vec<GetVerifiedSendersVerifyTokenResponseErrors>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -11369,6 +12653,10 @@ pub struct PutDesignRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub subject: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -11381,6 +12669,10 @@ pub struct ListDesignsResponse {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub result: Vec<DesignOutputSummaryAllOf>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -11399,6 +12691,10 @@ pub struct GetMcContatsResponse {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub result: Vec<ContactDetails3>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -11409,6 +12705,10 @@ pub struct PostMcListsResponse {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub errors: Vec<Error>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -11431,6 +12731,10 @@ pub struct PutMcContactsRequest {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub list_ids: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -11444,6 +12748,10 @@ pub struct PutMcContactsResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub job_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The deletion job has been accepted and is being processed.
@@ -11453,6 +12761,10 @@ pub struct DeleteMcContactsResponse {
      * helper text or docs for troubleshooting
      */
     pub job_id: Help,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -11463,6 +12775,10 @@ pub struct DeleteMcContactsResponseData {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub errors: Vec<Help>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// `billable_breakdown` will only appear to the parent user in an account with subusers.
@@ -11482,6 +12798,10 @@ pub struct BillableBreakdown {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -11503,6 +12823,10 @@ pub struct GetMcContactsCountResponse {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub contact_count: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -11527,6 +12851,10 @@ pub struct Lists {
         rename = "Name"
     )]
     pub name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -11559,6 +12887,10 @@ pub struct GetMarketingContactsExportsResponseResultMetadata {
         rename = "self"
     )]
     pub self_: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -11649,6 +12981,10 @@ pub struct GetMarketingContactsExportsResponseResult {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub user_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -11661,6 +12997,10 @@ pub struct GetMarketingContactsExportsResponse {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub result: Vec<GetMarketingContactsExportsResponseResult>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -11683,6 +13023,10 @@ pub struct GetMarketingContactsExportsResponseErrors {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub message: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -11693,6 +13037,10 @@ pub struct GetMarketingContactsExportsResponseData {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub errors: Vec<GetMarketingContactsExportsResponseErrors>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -11705,6 +13053,10 @@ pub struct Notifications {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub email: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -11717,8 +13069,9 @@ pub enum FileType {
     Csv,
     #[serde(rename = "json")]
     Json,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for FileType {
@@ -11726,7 +13079,7 @@ impl std::fmt::Display for FileType {
         match self {
             FileType::Csv => "csv",
             FileType::Json => "json",
-            FileType::FallthroughString => "*",
+            FileType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -11765,6 +13118,10 @@ pub struct PostMcContactsExportsRequest {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub segment_ids: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -11780,6 +13137,10 @@ pub struct PostMcContactsExportsResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -11793,6 +13154,10 @@ pub struct PostMcContactsSearchRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub query: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -11814,6 +13179,10 @@ pub struct PostMcContactsSearchResponse {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub result: Vec<ContactDetails3>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -11826,8 +13195,9 @@ pub enum PutMcContactsImportsRequestFileType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PutMcContactsImportsRequestFileType {
@@ -11835,7 +13205,7 @@ impl std::fmt::Display for PutMcContactsImportsRequestFileType {
         match self {
             PutMcContactsImportsRequestFileType::Csv => "csv",
             PutMcContactsImportsRequestFileType::Noop => "",
-            PutMcContactsImportsRequestFileType::FallthroughString => "*",
+            PutMcContactsImportsRequestFileType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -11891,6 +13261,10 @@ pub struct PutMcContactsImportsRequest {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub list_ids: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -11913,6 +13287,10 @@ pub struct UploadHeaders {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub value: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -11944,6 +13322,10 @@ pub struct PutMcContactsImportsResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub upload_uri: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -11954,12 +13336,20 @@ pub struct PutMcContactsImportsResponseData {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub errors: Vec<Error>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetMarketingContactsImportsResponse {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub errors: Option<Error>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Array of IDs
@@ -11974,6 +13364,10 @@ pub struct PostMarketingContactsBatchRequest {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub ids: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -11984,6 +13378,10 @@ pub struct PostMarketingContactsBatchResponse {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub result: Vec<ContactDetails3>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -11998,6 +13396,10 @@ pub struct PostMarketingContactsSearchEmailsRequest {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub emails: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -12007,6 +13409,10 @@ pub struct PostMarketingContactsSearchEmailsResponse {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub result: Option<Help>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -12068,6 +13474,10 @@ pub struct PostMarketingSendersRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub zip: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -12080,6 +13490,10 @@ pub struct GetMcListsResponse {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub result: Vec<List>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -12096,12 +13510,20 @@ pub struct GetMcListsContactsCountResponse {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub contact_count: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetMcListsResponseData {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub contact_sample: Option<ContactDetails2>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types are flattened into one object:
@@ -12129,6 +13551,10 @@ pub struct DeleteListsResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub job_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -12139,6 +13565,10 @@ pub struct PatchMcListsRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The removal is accepted and processing.
@@ -12153,6 +13583,10 @@ pub struct DeleteMcListsContactsResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub job_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -12171,6 +13605,10 @@ pub struct GetMcFieldDefinitionsResponse {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub reserved_fields: Vec<ReservedFieldDefinitionsResponse>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -12186,12 +13624,20 @@ pub struct PostMcFieldDefinitionsRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostMcFieldDefinitionsResponse {
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "_metadata")]
     pub metadata: Option<MetadataType>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types are flattened into one object:
@@ -12215,6 +13661,10 @@ pub struct GetMarketingSegmentsResponse {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub results: Vec<SegmentSummary>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -12228,6 +13678,10 @@ pub struct PostMarketingSegmentsRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub parent_list_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types are flattened into one object:
@@ -12251,6 +13705,10 @@ pub struct PostMarketingSegmentsDeleteRequest {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub ids: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// resources in which segment is being used
@@ -12275,6 +13733,10 @@ pub struct Resources {
         rename = "type"
     )]
     pub type_: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -12302,6 +13764,10 @@ pub struct PostMarketingSegmentsDeleteResponseErrors {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub resources: Option<Resources>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -12312,6 +13778,10 @@ pub struct PostMarketingSegmentsDeleteResponse {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub errors: Vec<PostMarketingSegmentsDeleteResponseErrors>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -12324,6 +13794,10 @@ pub struct GetMarketingSinglesendsResponse {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub result: Vec<SinglesendResponseShort>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -12334,6 +13808,10 @@ pub struct PostMarketingSinglesendsResponse {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub errors: Vec<SsoErrorResponse>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -12347,6 +13825,10 @@ pub struct PostMarketingSinglesendsRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -12359,8 +13841,9 @@ pub enum SendAt {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SendAt {
@@ -12368,7 +13851,7 @@ impl std::fmt::Display for SendAt {
         match self {
             SendAt::Now => "now",
             SendAt::Noop => "",
-            SendAt::FallthroughString => "*",
+            SendAt::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -12387,6 +13870,10 @@ pub struct PutMarketingSinglesendsScheduleRequest {
      */
     #[serde(default, skip_serializing_if = "SendAt::is_noop")]
     pub send_at: SendAt,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -12396,8 +13883,9 @@ pub enum PutMarketingSinglesendsScheduleResponseStatus {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PutMarketingSinglesendsScheduleResponseStatus {
@@ -12405,7 +13893,7 @@ impl std::fmt::Display for PutMarketingSinglesendsScheduleResponseStatus {
         match self {
             PutMarketingSinglesendsScheduleResponseStatus::Scheduled => "scheduled",
             PutMarketingSinglesendsScheduleResponseStatus::Noop => "",
-            PutMarketingSinglesendsScheduleResponseStatus::FallthroughString => "*",
+            PutMarketingSinglesendsScheduleResponseStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -12427,6 +13915,10 @@ pub struct PutMarketingSinglesendsScheduleResponse {
     pub send_at: Option<chrono::DateTime<chrono::Utc>>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub status: Option<PutMarketingSinglesendsScheduleResponseStatus>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -12440,6 +13932,10 @@ pub struct GetMarketingSinglesendsCategoriesResponse {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub categories: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -12492,6 +13988,10 @@ pub struct PostMarketingTestSendEmailRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub version_id_override: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -12502,6 +14002,10 @@ pub struct GetSendersResponse {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub result: Vec<SenderAllOf>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -12516,6 +14020,10 @@ pub struct PostSendersRequest {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub reply_to: Option<Help>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types are flattened into one object:
@@ -12539,6 +14047,10 @@ pub struct ListAllListsResponse {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub lists: Vec<ContactdbList>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -12564,6 +14076,10 @@ pub struct PatchContactdbListsListResponse {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub recipient_count: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -12574,6 +14090,10 @@ pub struct GetContactdbRecipientsSearchResponse {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub recipients: Vec<ContactdbRecipient>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -12584,6 +14104,10 @@ pub struct ListRecipientsResponse {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub recipients: Vec<Help>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -12621,6 +14145,10 @@ pub struct PostContactdbRecipientsRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub last_name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -12652,6 +14180,10 @@ pub struct PatchContactdbRecipientsRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub last_name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -12674,6 +14206,10 @@ pub struct GetContactdbStatusResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub value: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -12684,6 +14220,10 @@ pub struct GetContactdbStatusResponseData {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub status: Vec<GetContactdbStatusResponse>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -12694,6 +14234,10 @@ pub struct GetContactdbRecipientsRecipientListsResponse {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub lists: Vec<ContactdbList>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -12713,6 +14257,10 @@ pub struct PostContactdbRecipientsSearchRequest {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub list_id: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types are flattened into one object:
@@ -12760,6 +14308,10 @@ pub struct PostContactdbRecipientsSearchResponseCustomFields {
     pub type_: String,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub value: Option<ValueAnyOf>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -12827,6 +14379,10 @@ pub struct PostContactdbRecipientsSearchResponse {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub updated_at: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -12843,6 +14399,10 @@ pub struct PostContactdbRecipientsSearchResponseData {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub recipients: Vec<PostContactdbRecipientsSearchResponse>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -12853,6 +14413,10 @@ pub struct ListAllCustomFieldsResponse {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub custom_fields: Vec<ContactdbCustomFieldWithAllOf>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -12870,6 +14434,10 @@ pub struct PostContactdbCustomFieldsRequest {
         rename = "type"
     )]
     pub type_: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -12893,6 +14461,10 @@ pub struct ReservedFields {
         rename = "type"
     )]
     pub type_: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -12903,6 +14475,10 @@ pub struct GetContactdbReservedFieldsResponse {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub reserved_fields: Vec<ReservedFields>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -12913,6 +14489,10 @@ pub struct ListAllSegmentsResponse {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub segments: Vec<ContactdbSegments>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -12938,6 +14518,10 @@ pub struct PatchContactdbSegmentsSegmentRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -12948,6 +14532,10 @@ pub struct ListRecipientsOnASegmentResponse {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub recipients: Vec<ContactdbRecipient>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -12961,6 +14549,10 @@ pub struct GetCategoriesResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub category: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -12971,6 +14563,10 @@ pub struct GetCategoriesResponseData {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub errors: Vec<ErrorsSeg>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -12981,6 +14577,10 @@ pub struct GetCampaignsResponse {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub result: Vec<CampaignResponseAllOf>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -13093,6 +14693,10 @@ pub struct GetCampaignsCampaignResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub title: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -13142,6 +14746,10 @@ pub struct UpdateACampaignRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub title: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -13161,6 +14769,10 @@ pub struct SendACampaignResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub status: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -13171,6 +14783,10 @@ pub struct ScheduleACampaignRequest {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub send_at: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -13183,8 +14799,9 @@ pub enum ScheduleACampaignResponseStatus {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ScheduleACampaignResponseStatus {
@@ -13192,7 +14809,7 @@ impl std::fmt::Display for ScheduleACampaignResponseStatus {
         match self {
             ScheduleACampaignResponseStatus::Scheduled => "Scheduled",
             ScheduleACampaignResponseStatus::Noop => "",
-            ScheduleACampaignResponseStatus::FallthroughString => "*",
+            ScheduleACampaignResponseStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -13226,6 +14843,10 @@ pub struct ScheduleACampaignResponse {
         skip_serializing_if = "ScheduleACampaignResponseStatus::is_noop"
     )]
     pub status: ScheduleACampaignResponseStatus,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -13251,6 +14872,10 @@ pub struct UpdateAScheduledCampaignResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub status: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -13264,6 +14889,10 @@ pub struct SendATestCampaignRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub to: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -13278,8 +14907,9 @@ pub enum Generations {
     Legacy,
     #[serde(rename = "legacy,dynamic")]
     LegacyDynamic,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Generations {
@@ -13288,7 +14918,7 @@ impl std::fmt::Display for Generations {
             Generations::Dynamic => "dynamic",
             Generations::Legacy => "legacy",
             Generations::LegacyDynamic => "legacy,dynamic",
-            Generations::FallthroughString => "*",
+            Generations::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -13307,6 +14937,10 @@ pub struct GetTemplatesResponse {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub result: Vec<TransactionalTemplatesTemplateLean>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -13329,6 +14963,10 @@ pub struct GetTemplatesResponseErrors {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub message: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -13339,6 +14977,10 @@ pub struct GetTemplatesResponseData {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub errors: Vec<GetTemplatesResponseErrors>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -13354,6 +14996,10 @@ pub struct PostTemplatesRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -13364,6 +15010,10 @@ pub struct PostTemplatesTemplateRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -13374,6 +15024,10 @@ pub struct PatchTemplatesTemplateRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -13387,6 +15041,10 @@ pub struct GetUserWebhooksParseSettingsResponse {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub result: Vec<ParseSetting>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -13400,12 +15058,20 @@ pub struct GetUserWebhooksParseStatsResponseMetrics {
         deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub received: f64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetUserWebhooksParseStatsResponse {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub metrics: Option<GetUserWebhooksParseStatsResponseMetrics>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -13428,6 +15094,10 @@ pub struct GetUserWebhooksParseStatsResponseData {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub stats: Vec<GetUserWebhooksParseStatsResponse>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -13441,6 +15111,10 @@ pub struct GetUserWebhooksEventSettingsSignedResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub public_key: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -13453,6 +15127,10 @@ pub struct GetTrackingSettingsOpenResponse {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub enabled: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -13475,6 +15153,10 @@ pub struct PatchUserWebhooksEventSettingsSignedResponseErrors {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub message: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -13485,6 +15167,10 @@ pub struct PatchUserWebhooksEventSettingsSignedResponse {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub errors: Vec<PatchUserWebhooksEventSettingsSignedResponseErrors>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -13513,6 +15199,10 @@ pub struct PostUserWebhooksEventTestRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -13523,6 +15213,10 @@ pub struct GetMessagesResponse {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub messages: Vec<Messages>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -13532,8 +15226,9 @@ pub enum PostMessagesDownloadResponseStatus {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PostMessagesDownloadResponseStatus {
@@ -13541,7 +15236,7 @@ impl std::fmt::Display for PostMessagesDownloadResponseStatus {
         match self {
             PostMessagesDownloadResponseStatus::Pending => "pending",
             PostMessagesDownloadResponseStatus::Noop => "",
-            PostMessagesDownloadResponseStatus::FallthroughString => "*",
+            PostMessagesDownloadResponseStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -13566,6 +15261,10 @@ pub struct PostMessagesDownloadResponse {
     pub message: String,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub status: Option<PostMessagesDownloadResponseStatus>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -13588,6 +15287,10 @@ pub struct GetMessagesDownloadResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub presigned_url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -13627,6 +15330,10 @@ pub struct GetTrackingSettingsResponseResult {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub title: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -13640,18 +15347,30 @@ pub struct GetTrackingSettingsResponse {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub result: Vec<GetTrackingSettingsResponseResult>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PatchTrackingSettingsOpenRequest {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub enabled: Option<bool>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetStatsResponse {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub metrics: Option<StatsAdvancedGlobalAllOf>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -13674,6 +15393,10 @@ pub struct GetStatsResponseData {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub stats: Vec<GetStatsResponse>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -13688,8 +15411,9 @@ pub enum Country {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Country {
@@ -13698,7 +15422,7 @@ impl std::fmt::Display for Country {
             Country::Ca => "CA",
             Country::Us => "US",
             Country::Noop => "",
-            Country::FallthroughString => "*",
+            Country::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -13733,6 +15457,10 @@ pub struct GetGeoStatsResponse {
         rename = "type"
     )]
     pub type_: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -13755,6 +15483,10 @@ pub struct GetGeoStatsResponseData {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub stats: Vec<GetGeoStatsResponse>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -13783,6 +15515,10 @@ pub struct GetDevicesStatsResponse {
         rename = "type"
     )]
     pub type_: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -13805,6 +15541,10 @@ pub struct GetClientsStatsResponse {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub stats: Vec<GetDevicesStatsResponse>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -13823,8 +15563,9 @@ pub enum ClientType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ClientType {
@@ -13835,7 +15576,7 @@ impl std::fmt::Display for ClientType {
             ClientType::Tablet => "tablet",
             ClientType::Webmail => "webmail",
             ClientType::Noop => "",
-            ClientType::FallthroughString => "*",
+            ClientType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -13870,6 +15611,10 @@ pub struct GetMailboxProvidersStatsResponse {
         rename = "type"
     )]
     pub type_: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -13892,6 +15637,10 @@ pub struct GetMailboxProvidersStatsResponseData {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub stats: Vec<GetMailboxProvidersStatsResponse>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -13920,6 +15669,10 @@ pub struct GetBrowsersStatsResponse {
         rename = "type"
     )]
     pub type_: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -13942,6 +15695,10 @@ pub struct GetBrowsersStatsResponseData {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub stats: Vec<GetBrowsersStatsResponse>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -13954,6 +15711,10 @@ pub struct DeleteSuppressionBouncesRequest {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub emails: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -13966,6 +15727,10 @@ pub struct DeleteSuppressionBlocksRequest {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub emails: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -13985,6 +15750,10 @@ pub struct GetSuppressionUnsubscribesResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub email: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -13998,6 +15767,10 @@ pub struct RetrieveAGlobalSuppressionResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub recipient_email: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -14034,6 +15807,10 @@ pub struct PostAsmGroupsResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -14059,6 +15836,10 @@ pub struct GetAsmGroupsGroupResponse {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub unsubscribes: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types are flattened into one object:
@@ -14085,6 +15866,10 @@ pub struct PostAsmGroupsGroupSuppressionsResponse {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub recipient_emails: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -14119,6 +15904,10 @@ pub struct GetAsmSuppressionsResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub group_name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -14163,6 +15952,10 @@ pub struct Suppressions {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub suppressed: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -14176,4 +15969,8 @@ pub struct GetAsmSuppressionsEmailResponse {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub suppressions: Vec<Suppressions>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
@@ -0,0 +1,374 @@
+//! For verifying and parsing the events SendGrid posts to the Event Webhook.
+//!
+//! FROM: <https://docs.sendgrid.com/for-developers/tracking-events/getting-started-event-webhook-security-features>
+use base64::{engine::general_purpose::STANDARD, Engine};
+use p256::{
+    ecdsa::{signature::Verifier, Signature, VerifyingKey},
+    pkcs8::DecodePublicKey,
+};
+use schemars::JsonSchema;
+use serde::{Deserialize, Deserializer, Serialize};
+
+/// The header SendGrid signs Event Webhook posts with.
+pub const SIGNATURE_HEADER: &str = "X-Twilio-Email-Event-Webhook-Signature";
+
+/// The header holding the timestamp that is signed along with the payload.
+pub const TIMESTAMP_HEADER: &str = "X-Twilio-Email-Event-Webhook-Timestamp";
+
+/// Errors returned while verifying or parsing an Event Webhook post.
+#[derive(Debug, thiserror::Error)]
+pub enum WebhookError {
+    /// The public key is not a base64 encoded P-256 key
+    #[error("invalid public key")]
+    InvalidPublicKey,
+    /// A header the post needs is missing
+    #[error("missing {0} header")]
+    MissingHeader(&'static str),
+    /// The signature is not a base64 encoded ECDSA signature
+    #[error("invalid signature")]
+    InvalidSignature,
+    /// The signature does not match the payload
+    #[error("the signature does not match the payload")]
+    SignatureMismatch,
+    /// The payload is not a list of events
+    #[error(transparent)]
+    SerdeJsonError(#[from] serde_json::Error),
+}
+
+/// An event of the Event Webhook, by its kind.
+///
+/// Events of the kinds without a variant of their own, like `processed` or
+/// `dropped`, are kept as JSON.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Event {
+    Bounce(Box<BounceEvent>),
+    Click(Box<ClickEvent>),
+    Delivered(Box<DeliveredEvent>),
+    Open(Box<OpenEvent>),
+    SpamReport(Box<EventInfo>),
+    Unsubscribe(Box<EventInfo>),
+    Other(serde_json::Value),
+}
+
+impl Event {
+    fn from_value(mut event: serde_json::Value) -> serde_json::Result<Self> {
+        // The kind is taken out, not to end up among the custom arguments.
+        let removed = event
+            .as_object_mut()
+            .and_then(|event| event.remove("event"));
+        let kind = removed.as_ref().and_then(|kind| kind.as_str());
+        Ok(match kind {
+            Some("bounce") => Event::Bounce(serde_json::from_value(event)?),
+            Some("click") => Event::Click(serde_json::from_value(event)?),
+            Some("delivered") => Event::Delivered(serde_json::from_value(event)?),
+            Some("open") => Event::Open(serde_json::from_value(event)?),
+            Some("spamreport") => Event::SpamReport(serde_json::from_value(event)?),
+            Some("unsubscribe") => Event::Unsubscribe(serde_json::from_value(event)?),
+            _ => {
+                if let (Some(object), Some(kind)) = (event.as_object_mut(), kind) {
+                    object.insert("event".to_string(), kind.into());
+                }
+                Event::Other(event)
+            }
+        })
+    }
+
+    /// Returns the fields every typed event has.
+    pub fn info(&self) -> Option<&EventInfo> {
+        match self {
+            Event::Bounce(event) => Some(&event.info),
+            Event::Click(event) => Some(&event.info),
+            Event::Delivered(event) => Some(&event.info),
+            Event::Open(event) => Some(&event.info),
+            Event::SpamReport(info) | Event::Unsubscribe(info) => Some(info),
+            Event::Other(_) => None,
+        }
+    }
+}
+
+/// The fields of every event, about the message it is for.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default, JsonSchema)]
+pub struct EventInfo {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub email: String,
+    /// When the event happened, in seconds since the epoch.
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub timestamp: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "smtp-id"
+    )]
+    pub smtp_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub sg_event_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub sg_message_id: String,
+    /// The categories of the message, sent either as one string or as a list.
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "deserialize_category"
+    )]
+    pub category: Vec<String>,
+    /// The custom arguments of the message, which SendGrid adds to its events.
+    #[serde(flatten)]
+    pub custom_args: serde_json::Map<String, serde_json::Value>,
+}
+
+/// A message was accepted by the receiving server.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default, JsonSchema)]
+pub struct DeliveredEvent {
+    /// The response of the receiving server.
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub response: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub ip: String,
+    /// Whether the message was sent over TLS, as `1` or `0`.
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub tls: i64,
+    #[serde(flatten)]
+    pub info: EventInfo,
+}
+
+/// A message was rejected by the receiving server.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default, JsonSchema)]
+pub struct BounceEvent {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub reason: String,
+    /// The SMTP status code of the bounce, like `5.0.0`.
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub status: String,
+    /// Either `bounce`, for a permanent failure, or `blocked`, for a temporary one.
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "type"
+    )]
+    pub type_: String,
+    /// Why the message bounced, like `Invalid Address` or `Reputation`.
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub bounce_classification: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub ip: String,
+    /// Whether the message was sent over TLS, as `1` or `0`.
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub tls: i64,
+    #[serde(flatten)]
+    pub info: EventInfo,
+}
+
+/// A message was opened.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default, JsonSchema)]
+pub struct OpenEvent {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub useragent: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub ip: String,
+    /// Whether the open was made by the mail client of the recipient, like Apple Mail
+    /// Privacy Protection does, rather than by the recipient.
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub sg_machine_open: bool,
+    #[serde(flatten)]
+    pub info: EventInfo,
+}
+
+/// A link of a message was clicked.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default, JsonSchema)]
+pub struct ClickEvent {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+    /// Which of the links to the URL was clicked, when the message has several.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url_offset: Option<UrlOffset>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub useragent: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub ip: String,
+    #[serde(flatten)]
+    pub info: EventInfo,
+}
+
+/// The position of a clicked link among the links to the same URL.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default, JsonSchema)]
+pub struct UrlOffset {
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub index: i64,
+    /// Whether the link is in the `html` or the `text` part of the message.
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "type"
+    )]
+    pub type_: String,
+}
+
+fn deserialize_category<'de, D>(deserializer: D) -> Result<Vec<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Category {
+        One(String),
+        Many(Vec<String>),
+    }
+
+    Ok(match Option::<Category>::deserialize(deserializer)? {
+        Some(Category::One(category)) => vec![category],
+        Some(Category::Many(categories)) => categories,
+        None => Vec::new(),
+    })
+}
+
+/// Verifies the Event Webhook posts of an account, with its verification key.
+#[derive(Clone)]
+pub struct Webhook {
+    key: VerifyingKey,
+}
+
+impl Webhook {
+    /// Create a new verifier from the public key of the signed Event Webhook, as
+    /// returned by `webhooks().get_user_event_settings_signed()`: a base64 encoded
+    /// DER key.
+    pub fn new(public_key: &str) -> Result<Self, WebhookError> {
+        let der = STANDARD
+            .decode(public_key.trim())
+            .map_err(|_| WebhookError::InvalidPublicKey)?;
+        let key =
+            VerifyingKey::from_public_key_der(&der).map_err(|_| WebhookError::InvalidPublicKey)?;
+
+        Ok(Webhook { key })
+    }
+
+    /// Verifies the `X-Twilio-Email-Event-Webhook-Signature` header of a post against
+    /// its raw body and its `X-Twilio-Email-Event-Webhook-Timestamp` header.
+    pub fn verify(
+        &self,
+        payload: &[u8],
+        signature: &str,
+        timestamp: &str,
+    ) -> Result<(), WebhookError> {
+        let signature = STANDARD
+            .decode(signature.trim())
+            .map_err(|_| WebhookError::InvalidSignature)?;
+        let signature =
+            Signature::from_der(&signature).map_err(|_| WebhookError::InvalidSignature)?;
+
+        let mut message = timestamp.trim().as_bytes().to_vec();
+        message.extend_from_slice(payload);
+
+        self.key
+            .verify(&message, &signature)
+            .map_err(|_| WebhookError::SignatureMismatch)
+    }
+
+    /// Verifies a post and parses its body into events, from its headers.
+    ///
+    /// The ECDSA signature comes in `X-Twilio-Email-Event-Webhook-Signature`, over the
+    /// `X-Twilio-Email-Event-Webhook-Timestamp` header followed by the body.
+    pub fn construct_events<I, K, V>(
+        &self,
+        headers: I,
+        payload: &[u8],
+    ) -> Result<Vec<Event>, WebhookError>
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: AsRef<str>,
+        V: AsRef<[u8]>,
+    {
+        let [signature, timestamp] =
+            crate::utils::find_headers(headers, [SIGNATURE_HEADER, TIMESTAMP_HEADER]);
+        let signature = signature.ok_or(WebhookError::MissingHeader(SIGNATURE_HEADER))?;
+        let timestamp = timestamp.ok_or(WebhookError::MissingHeader(TIMESTAMP_HEADER))?;
+
+        self.verify(payload, &signature, &timestamp)?;
+        parse_events(payload)
+    }
+}
+
+/// Parses the body of a post into its events, without verifying it.
+pub fn parse_events(payload: &[u8]) -> Result<Vec<Event>, WebhookError> {
+    let events = serde_json::from_slice::<Vec<serde_json::Value>>(payload)?;
+    Ok(events
+        .into_iter()
+        .map(Event::from_value)
+        .collect::<serde_json::Result<_>>()?)
+}
@@ -1,6 +1,91 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Webhooks::get_user_parse_stats`], set with a builder.
+pub struct WebhooksGetUserParseStatsRequest<'a> {
+    client: &'a Webhooks,
+    limit: Option<&'a str>,
+    offset: Option<&'a str>,
+    aggregated_by: Option<crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy>,
+    start_date: &'a str,
+    end_date: Option<&'a str>,
+}
+
+impl<'a> WebhooksGetUserParseStatsRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `offset` query parameter.
+    pub fn offset(mut self, offset: &'a str) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Sets the `aggregated_by` query parameter.
+    pub fn aggregated_by(
+        mut self,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+    ) -> Self {
+        self.aggregated_by = Some(aggregated_by);
+        self
+    }
+
+    /// Sets the `end_date` query parameter.
+    pub fn end_date(mut self, end_date: &'a str) -> Self {
+        self.end_date = Some(end_date);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GetUserWebhooksParseStatsResponseData>>>
+    {
+        let Self {
+            client,
+            limit,
+            offset,
+            aggregated_by,
+            start_date,
+            end_date,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = aggregated_by {
+            query_args.push(("aggregated_by".to_string(), v.to_string()));
+        }
+        if let Some(v) = end_date {
+            query_args.push(("end_date".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = offset {
+            query_args.push(("offset".to_string(), v.to_string()));
+        }
+        if !start_date.is_empty() {
+            query_args.push(("start_date".to_string(), start_date.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/user/webhooks/parse/stats?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct Webhooks {
     pub client: Client,
 }
@@ -158,6 +243,32 @@ impl Webhooks {
             )
             .await
     }
+    /**
+     * Retrieves Inbound Parse Webhook statistics.
+     *
+     * This function performs a `GET` to the `/user/webhooks/parse/stats` endpoint.
+     *
+     * As opposed to `get_user_parse_stats`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * **This endpoint allows you to retrieve the statistics for your Parse Webhook useage.**
+     *
+     * SendGrid's Inbound Parse Webhook allows you to parse the contents and attachments of incomming emails. The Parse API can then POST the parsed emails to a URL that you specify. The Inbound Parse Webhook cannot parse messages greater than 30MB in size, including all attachments.
+     *
+     * There are a number of pre-made integrations for the SendGrid Parse Webhook which make processing events easy. You can find these integrations in the [Library Index](https://sendgrid.com/docs/Integrate/libraries.html#-Webhook-Libraries).
+     */
+    pub fn get_user_parse_stats_request<'a>(
+        &'a self,
+        start_date: &'a str,
+    ) -> WebhooksGetUserParseStatsRequest<'a> {
+        WebhooksGetUserParseStatsRequest {
+            client: self,
+            limit: None,
+            offset: None,
+            aggregated_by: None,
+            start_date,
+            end_date: None,
+        }
+    }
     /**
      * Retrieves Inbound Parse Webhook statistics.
      *
@@ -206,6 +317,47 @@ impl Webhooks {
             )
             .await
     }
+    /**
+     * Retrieves Inbound Parse Webhook statistics.
+     *
+     * This function performs a `GET` to the `/user/webhooks/parse/stats` endpoint.
+     *
+     * As opposed to `get_all_user_parse_stats`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * **This endpoint allows you to retrieve the statistics for your Parse Webhook useage.**
+     *
+     * SendGrid's Inbound Parse Webhook allows you to parse the contents and attachments of incomming emails. The Parse API can then POST the parsed emails to a URL that you specify. The Inbound Parse Webhook cannot parse messages greater than 30MB in size, including all attachments.
+     *
+     * There are a number of pre-made integrations for the SendGrid Parse Webhook which make processing events easy. You can find these integrations in the [Library Index](https://sendgrid.com/docs/Integrate/libraries.html#-Webhook-Libraries).
+     */
+    pub fn get_all_user_parse_stats_stream(
+        &self,
+        offset: &str,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+        start_date: &str,
+        end_date: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::GetUserWebhooksParseStatsResponseData>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !aggregated_by.to_string().is_empty() {
+            query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
+        }
+        if !end_date.is_empty() {
+            query_args.push(("end_date".to_string(), end_date.to_string()));
+        }
+        if !offset.is_empty() {
+            query_args.push(("offset".to_string(), offset.to_string()));
+        }
+        if !start_date.is_empty() {
+            query_args.push(("start_date".to_string(), start_date.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/user/webhooks/parse/stats?{}", query_), None);
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Retrieve Signed Webhook Public Key.
      *
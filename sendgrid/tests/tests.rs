@@ -1,18 +1,28 @@
 use std::{mem, time::Duration};
 
 use wiremock::{
-    matchers::{method, path},
+    matchers::{method, path, query_param, query_param_is_missing},
     Mock, MockServer, ResponseTemplate,
 };
 
-use sendgrid_api::{types::GetScopesResponse, Client};
+use sendgrid_api::{
+    inbound_parse::{self, InboundParseError},
+    traits::MailOps,
+    types::GetScopesResponse,
+    webhook_events::{self, Event, Webhook, WebhookError},
+    Client, ClientError,
+};
 
 #[tokio::test]
 async fn test_uses_host_override() {
     let server = MockServer::start().await;
     let response = ResponseTemplate::new(200)
         .set_delay(Duration::from_secs(1))
-        .set_body_json(GetScopesResponse { scopes: vec![] });
+        .set_body_json(GetScopesResponse {
+            scopes: vec![],
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
+        });
 
     Mock::given(method("GET"))
         .and(path("/scopes".to_string()))
@@ -28,3 +38,271 @@ async fn test_uses_host_override() {
 
     mem::drop(server)
 }
+
+fn alerts_link(server: &MockServer, prev: &str, next: &str) -> String {
+    let alerts = format!("{}/alerts?page_size=1", server.uri());
+    format!(
+        "<{alerts}&page_token={prev}>; rel=\"prev\"; title=\"1\", \
+         <{alerts}&page_token={next}>; rel=\"next\"; title=\"2\", \
+         <{alerts}&page_token=t9>; rel=\"last\"; title=\"9\""
+    )
+}
+
+#[tokio::test]
+async fn test_get_all_fails_on_repeated_page_token() {
+    let server = MockServer::start().await;
+
+    // The second page's next link hands back the token it was requested with.
+    Mock::given(method("GET"))
+        .and(path("/alerts"))
+        .and(query_param("page_token", "t2"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("link", alerts_link(&server, "t1", "t2").as_str())
+                .set_body_json(serde_json::json!([{ "id": 2 }])),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/alerts"))
+        .and(query_param_is_missing("page_token"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("link", alerts_link(&server, "t1", "t2").as_str())
+                .set_body_json(serde_json::json!([{ "id": 1 }])),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut client = Client::new("token");
+    client.with_host_override(server.uri());
+
+    match client.alerts().get_all().await {
+        Err(ClientError::IncompletePages { page_token }) => {
+            assert!(page_token.ends_with("page_token=t2"))
+        }
+        result => unreachable!("Expected incomplete pages, got {:?}", result),
+    }
+
+    mem::drop(server)
+}
+
+#[tokio::test]
+async fn test_send_plain_text_parses_api_error() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/mail/send"))
+        .respond_with(ResponseTemplate::new(400).set_body_json(serde_json::json!({
+            "errors": [{
+                "field": "from.email",
+                "message": "The from email does not contain a valid address.",
+                "help": "http://sendgrid.com/docs/API_Reference/Web_API_v3/Mail/errors.html"
+            }]
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut client = Client::new("token");
+    client.with_host_override(server.uri());
+
+    let result = client
+        .mail_send()
+        .send_plain_text(
+            "subject",
+            "message",
+            &["to@example.com".to_string()],
+            &[],
+            &[],
+            "from",
+        )
+        .await;
+
+    match result {
+        Err(ClientError::ApiError { error, .. }) => {
+            assert_eq!(error.errors[0].field, "from.email");
+            assert_eq!(
+                error.to_string(),
+                "from.email: The from email does not contain a valid address."
+            );
+        }
+        result => unreachable!("Expected API error, got {:?}", result),
+    }
+
+    mem::drop(server)
+}
+
+#[tokio::test]
+async fn test_request_takes_required_query_parameter() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/categories/stats/sums"))
+        .and(query_param("start_date", "2024-01-01"))
+        .and(query_param("limit", "5"))
+        .and(query_param_is_missing("end_date"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "date": "2024-01-01",
+            "stats": []
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut client = Client::new("token");
+    client.with_host_override(server.uri());
+
+    let stats = client
+        .categories()
+        .get_stats_sum_request("2024-01-01")
+        .limit(5)
+        .send()
+        .await
+        .unwrap()
+        .body;
+    assert_eq!(stats.date, "2024-01-01");
+
+    mem::drop(server)
+}
+
+const EVENT_WEBHOOK_KEY: &str = "MFkwEwYHKoZIzj0CAQYIKoZIzj0DAQcDQgAEM0AVAqw8cQBj5BwCGT0R90HBj9gjawggtcSR1HpiGnex7P5hYl3b+f16kp7SLMZkaYBqQyG5QsD0d5XYS3Ww5Q==";
+const EVENT_WEBHOOK_SIGNATURE: &str = "MEUCIQDznWp1LpBoi2L9YF+kjGeGhjZAF6bKfovjs0XYnccKwgIgZdnSnx5/8pVWNYnzQWpQxJwBnbJONpG28U+tB12uDyA=";
+
+#[test]
+fn test_event_webhook_verifies_signature() {
+    let payload = include_bytes!("fixtures/events.json");
+    let webhook = Webhook::new(EVENT_WEBHOOK_KEY).unwrap();
+
+    webhook
+        .verify(payload, EVENT_WEBHOOK_SIGNATURE, "1700000010")
+        .unwrap();
+    assert!(matches!(
+        webhook.verify(payload, EVENT_WEBHOOK_SIGNATURE, "1700000011"),
+        Err(WebhookError::SignatureMismatch)
+    ));
+    assert!(matches!(
+        webhook.verify(&payload[1..], EVENT_WEBHOOK_SIGNATURE, "1700000010"),
+        Err(WebhookError::SignatureMismatch)
+    ));
+    assert!(matches!(
+        webhook.verify(payload, "c2lnbmF0dXJl", "1700000010"),
+        Err(WebhookError::InvalidSignature)
+    ));
+    assert!(matches!(
+        Webhook::new("a2V5"),
+        Err(WebhookError::InvalidPublicKey)
+    ));
+}
+
+#[test]
+fn test_event_webhook_constructs_events() {
+    let payload = include_bytes!("fixtures/events.json");
+    let events = Webhook::new(EVENT_WEBHOOK_KEY)
+        .unwrap()
+        .construct_events(
+            [
+                (
+                    "x-twilio-email-event-webhook-signature",
+                    EVENT_WEBHOOK_SIGNATURE,
+                ),
+                ("x-twilio-email-event-webhook-timestamp", "1700000010"),
+            ],
+            payload,
+        )
+        .unwrap();
+    assert_eq!(events.len(), 7);
+
+    let Event::Delivered(delivered) = &events[0] else {
+        panic!("expected a delivered event");
+    };
+    assert_eq!(delivered.response, "250 OK");
+    assert_eq!(delivered.tls, 1);
+    assert_eq!(delivered.info.category, vec!["cat facts"]);
+    assert_eq!(delivered.info.custom_args["order_id"], "10042");
+    assert!(!delivered.info.custom_args.contains_key("response"));
+
+    let Event::Bounce(bounce) = &events[1] else {
+        panic!("expected a bounce event");
+    };
+    assert_eq!(bounce.type_, "bounce");
+    assert_eq!(bounce.bounce_classification, "Invalid Address");
+    assert_eq!(bounce.info.email, "jordan@example.com");
+    assert_eq!(bounce.info.category, vec!["cat facts", "newsletter"]);
+    assert!(bounce.info.custom_args.is_empty());
+
+    assert!(matches!(&events[2], Event::Open(open) if !open.sg_machine_open));
+    let Event::Click(click) = &events[3] else {
+        panic!("expected a click event");
+    };
+    assert_eq!(click.url, "http://www.sendgrid.com/");
+    assert_eq!(click.url_offset.as_ref().unwrap().type_, "html");
+    assert!(matches!(&events[4], Event::SpamReport(_)));
+    assert_eq!(events[5].info().unwrap().timestamp, 1700000005);
+
+    let Event::Other(other) = &events[6] else {
+        panic!("expected an untyped event");
+    };
+    assert_eq!(other["event"], "group_unsubscribe");
+
+    assert!(matches!(
+        webhook_events::parse_events(b"{}"),
+        Err(WebhookError::SerdeJsonError(_))
+    ));
+}
+
+#[tokio::test]
+async fn test_inbound_parse_decodes_email() {
+    let body = include_bytes!("fixtures/inbound_parse.bin");
+    let email = inbound_parse::parse_email("multipart/form-data; boundary=xYzZY", &body[..])
+        .await
+        .unwrap();
+
+    assert_eq!(email.from, "Rosario Jones <rosario@example.com>");
+    assert_eq!(email.envelope.to, vec!["inbound@parse.example.com"]);
+    assert_eq!(email.envelope.from, "rosario@example.com");
+    assert_eq!(email.spf, "pass");
+    assert!(email.headers.contains("Subject: Café order"));
+    // Decoded from ISO-8859-1.
+    assert_eq!(email.subject, "Café order");
+    assert_eq!(email.text, "Voilà, the order.\n");
+    assert_eq!(email.charsets["html"], "iso-8859-1");
+    assert!(email.raw.is_none());
+
+    assert_eq!(email.attachments.len(), 2);
+    assert_eq!(email.attachments[0].filename, "order.csv");
+    assert_eq!(
+        &email.attachments[0].data[..],
+        b"sku,quantity\r\nTSHIRT-BLUE-M,2\r\n"
+    );
+    assert_eq!(email.attachments[1].field, "attachment2");
+    assert_eq!(email.attachments[1].content_type, "image/png");
+    assert_eq!(email.attachments[1].content_id, "ii_139db99fdb");
+    assert!(email.attachments[1].data.starts_with(b"\x89PNG"));
+}
+
+#[tokio::test]
+async fn test_inbound_parse_raw_mode() {
+    let raw = "From: rosario@example.com\r\nSubject: Hi\r\n\r\nHello\r\n";
+    let body = format!(
+        "--b\r\nContent-Disposition: form-data; name=\"email\"\r\n\r\n{}\r\n\
+         --b\r\nContent-Disposition: form-data; name=\"to\"\r\n\r\ninbound@parse.example.com\r\n\
+         --b--\r\n",
+        raw
+    );
+    let email = inbound_parse::parse_email("multipart/form-data; boundary=b", body)
+        .await
+        .unwrap();
+
+    assert_eq!(email.raw.as_deref(), Some(raw.as_bytes()));
+    assert_eq!(email.to, "inbound@parse.example.com");
+    assert!(email.attachments.is_empty());
+
+    assert!(matches!(
+        inbound_parse::parse_email("application/json", "{}").await,
+        Err(InboundParseError::MultipartError(_))
+    ));
+}
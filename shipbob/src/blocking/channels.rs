@@ -0,0 +1,27 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::channels::Channels`].
+pub struct Channels {
+    inner: crate::channels::Channels,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Channels {
+    pub(crate) fn new(
+        inner: crate::channels::Channels,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Channels { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::channels::Channels::get_page`].
+    pub fn get_page(&self) -> ClientResult<crate::Response<Vec<crate::types::Channel>>> {
+        self.runtime.block_on(self.inner.get_page())
+    }
+    /// The blocking version of [`crate::channels::Channels::get_all`].
+    pub fn get_all(&self) -> ClientResult<crate::Response<Vec<crate::types::Channel>>> {
+        self.runtime.block_on(self.inner.get_all())
+    }
+}
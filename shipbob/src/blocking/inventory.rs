@@ -0,0 +1,128 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Inventory::get_page`], set with a builder.
+pub struct InventoryGetPageRequest<'a> {
+    inner: crate::inventory::InventoryGetPageRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> InventoryGetPageRequest<'a> {
+    /// Sets the `Page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sets the `Limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `IsActive` query parameter.
+    pub fn is_active(mut self, is_active: bool) -> Self {
+        self.inner = self.inner.is_active(is_active);
+        self
+    }
+
+    /// Sets the `IsDigital` query parameter.
+    pub fn is_digital(mut self, is_digital: bool) -> Self {
+        self.inner = self.inner.is_digital(is_digital);
+        self
+    }
+
+    /// Sets the `IDs` query parameter.
+    pub fn ids(mut self, ids: &'a [String]) -> Self {
+        self.inner = self.inner.ids(ids);
+        self
+    }
+
+    /// Sets the `Sort` query parameter.
+    pub fn sort(mut self, sort: &'a str) -> Self {
+        self.inner = self.inner.sort(sort);
+        self
+    }
+
+    /// Sets the `Search` query parameter.
+    pub fn search(mut self, search: &'a str) -> Self {
+        self.inner = self.inner.search(search);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Inventory>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::inventory::Inventory`].
+pub struct Inventory {
+    inner: crate::inventory::Inventory,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Inventory {
+    pub(crate) fn new(
+        inner: crate::inventory::Inventory,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Inventory { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::inventory::Inventory::get`].
+    pub fn get(&self, inventory_id: i64) -> ClientResult<crate::Response<crate::types::Inventory>> {
+        self.runtime.block_on(self.inner.get(inventory_id))
+    }
+    /// The blocking version of [`crate::inventory::Inventory::get_page`].
+    pub fn get_page(
+        &self,
+        page: i64,
+        limit: i64,
+        is_active: bool,
+        is_digital: bool,
+        ids: &[String],
+        sort: &str,
+        search: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Inventory>>> {
+        self.runtime.block_on(
+            self.inner
+                .get_page(page, limit, is_active, is_digital, ids, sort, search),
+        )
+    }
+    /// The blocking version of [`crate::inventory::Inventory::get_page_request`].
+    pub fn get_page_request(&self) -> InventoryGetPageRequest<'_> {
+        InventoryGetPageRequest {
+            inner: self.inner.get_page_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::inventory::Inventory::get_all`].
+    pub fn get_all(
+        &self,
+        is_active: bool,
+        is_digital: bool,
+        ids: &[String],
+        sort: &str,
+        search: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Inventory>>> {
+        self.runtime
+            .block_on(self.inner.get_all(is_active, is_digital, ids, sort, search))
+    }
+    /// The blocking version of [`crate::inventory::Inventory::get_product`].
+    pub fn get_product(
+        &self,
+        product_id: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Inventory>>> {
+        self.runtime.block_on(self.inner.get_product(product_id))
+    }
+    /// The blocking version of [`crate::inventory::Inventory::get_all_product`].
+    pub fn get_all_product(
+        &self,
+        product_id: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Inventory>>> {
+        self.runtime
+            .block_on(self.inner.get_all_product(product_id))
+    }
+}
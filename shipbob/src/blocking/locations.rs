@@ -0,0 +1,85 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Locations::get_page`], set with a builder.
+pub struct LocationsGetPageRequest<'a> {
+    inner: crate::locations::LocationsGetPageRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> LocationsGetPageRequest<'a> {
+    /// Sets the `IncludeInactive` query parameter.
+    pub fn include_inactive(mut self, include_inactive: bool) -> Self {
+        self.inner = self.inner.include_inactive(include_inactive);
+        self
+    }
+
+    /// Sets the `ReceivingEnabled` query parameter.
+    pub fn receiving_enabled(mut self, receiving_enabled: bool) -> Self {
+        self.inner = self.inner.receiving_enabled(receiving_enabled);
+        self
+    }
+
+    /// Sets the `AccessGranted` query parameter.
+    pub fn access_granted(mut self, access_granted: bool) -> Self {
+        self.inner = self.inner.access_granted(access_granted);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(
+        self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::IntegrationsLocationInternalAllOf>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::locations::Locations`].
+pub struct Locations {
+    inner: crate::locations::Locations,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Locations {
+    pub(crate) fn new(
+        inner: crate::locations::Locations,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Locations { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::locations::Locations::get_page`].
+    pub fn get_page(
+        &self,
+        include_inactive: bool,
+        receiving_enabled: bool,
+        access_granted: bool,
+    ) -> ClientResult<crate::Response<Vec<crate::types::IntegrationsLocationInternalAllOf>>> {
+        self.runtime.block_on(self.inner.get_page(
+            include_inactive,
+            receiving_enabled,
+            access_granted,
+        ))
+    }
+    /// The blocking version of [`crate::locations::Locations::get_page_request`].
+    pub fn get_page_request(&self) -> LocationsGetPageRequest<'_> {
+        LocationsGetPageRequest {
+            inner: self.inner.get_page_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::locations::Locations::get_all`].
+    pub fn get_all(
+        &self,
+        include_inactive: bool,
+        receiving_enabled: bool,
+        access_granted: bool,
+    ) -> ClientResult<crate::Response<Vec<crate::types::IntegrationsLocationInternalAllOf>>> {
+        self.runtime.block_on(self.inner.get_all(
+            include_inactive,
+            receiving_enabled,
+            access_granted,
+        ))
+    }
+}
@@ -0,0 +1,88 @@
+//! A blocking client, for the programs that are not async.
+//!
+//! It has the functions of the async [`Client`](crate::Client), which it runs on a
+//! runtime of its own, so they must not be called from within an async runtime. The
+//! `*_stream` functions are left out, the `*_all` ones returning all the pages at once.
+
+use std::{future::Future, sync::Arc};
+
+pub mod channels;
+pub mod inventory;
+pub mod locations;
+pub mod orders;
+pub mod products;
+pub mod receiving;
+pub mod returns;
+pub mod webhooks;
+
+/// A blocking client, sending its requests with an async [`Client`](crate::Client).
+#[derive(Clone)]
+pub struct Client {
+    inner: crate::Client,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Client {
+    /// Wraps an async client, creating the runtime its requests run on.
+    pub fn new(client: crate::Client) -> std::io::Result<Self> {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()?;
+
+        Ok(Client {
+            inner: client,
+            runtime: Arc::new(runtime),
+        })
+    }
+
+    /// Returns the async client the requests are sent with.
+    pub fn inner(&self) -> &crate::Client {
+        &self.inner
+    }
+
+    /// Runs a future to completion on the runtime of the client, for the async
+    /// functions that have no blocking version.
+    pub fn block_on<F: Future>(&self, future: F) -> F::Output {
+        self.runtime.block_on(future)
+    }
+
+    /// The blocking version of [`crate::Client::channels`].
+    pub fn channels(&self) -> channels::Channels {
+        channels::Channels::new(self.inner.channels(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::inventory`].
+    pub fn inventory(&self) -> inventory::Inventory {
+        inventory::Inventory::new(self.inner.inventory(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::locations`].
+    pub fn locations(&self) -> locations::Locations {
+        locations::Locations::new(self.inner.locations(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::orders`].
+    pub fn orders(&self) -> orders::Orders {
+        orders::Orders::new(self.inner.orders(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::products`].
+    pub fn products(&self) -> products::Products {
+        products::Products::new(self.inner.products(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::receiving`].
+    pub fn receiving(&self) -> receiving::Receiving {
+        receiving::Receiving::new(self.inner.receiving(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::returns`].
+    pub fn returns(&self) -> returns::Returns {
+        returns::Returns::new(self.inner.returns(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::webhooks`].
+    pub fn webhooks(&self) -> webhooks::Webhooks {
+        webhooks::Webhooks::new(self.inner.webhooks(), self.runtime.clone())
+    }
+}
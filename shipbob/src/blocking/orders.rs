@@ -0,0 +1,372 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Orders::get_page`], set with a builder.
+pub struct OrdersGetPageRequest<'a> {
+    inner: crate::orders::OrdersGetPageRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OrdersGetPageRequest<'a> {
+    /// Sets the `Page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sets the `Limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `IDs` query parameter.
+    pub fn ids(mut self, ids: &'a [String]) -> Self {
+        self.inner = self.inner.ids(ids);
+        self
+    }
+
+    /// Sets the `ReferenceIds` query parameter.
+    pub fn reference_ids(mut self, reference_ids: &'a [String]) -> Self {
+        self.inner = self.inner.reference_ids(reference_ids);
+        self
+    }
+
+    /// Sets the `StartDate` query parameter.
+    pub fn start_date(mut self, start_date: chrono::DateTime<chrono::Utc>) -> Self {
+        self.inner = self.inner.start_date(start_date);
+        self
+    }
+
+    /// Sets the `EndDate` query parameter.
+    pub fn end_date(mut self, end_date: chrono::DateTime<chrono::Utc>) -> Self {
+        self.inner = self.inner.end_date(end_date);
+        self
+    }
+
+    /// Sets the `SortOrder` query parameter.
+    pub fn sort_order(mut self, sort_order: crate::types::SortOrder) -> Self {
+        self.inner = self.inner.sort_order(sort_order);
+        self
+    }
+
+    /// Sets the `HasTracking` query parameter.
+    pub fn has_tracking(mut self, has_tracking: bool) -> Self {
+        self.inner = self.inner.has_tracking(has_tracking);
+        self
+    }
+
+    /// Sets the `LastUpdateStartDate` query parameter.
+    pub fn last_update_start_date(
+        mut self,
+        last_update_start_date: chrono::DateTime<chrono::Utc>,
+    ) -> Self {
+        self.inner = self.inner.last_update_start_date(last_update_start_date);
+        self
+    }
+
+    /// Sets the `LastUpdateEndDate` query parameter.
+    pub fn last_update_end_date(
+        mut self,
+        last_update_end_date: chrono::DateTime<chrono::Utc>,
+    ) -> Self {
+        self.inner = self.inner.last_update_end_date(last_update_end_date);
+        self
+    }
+
+    /// Sets the `IsTrackingUploaded` query parameter.
+    pub fn is_tracking_uploaded(mut self, is_tracking_uploaded: bool) -> Self {
+        self.inner = self.inner.is_tracking_uploaded(is_tracking_uploaded);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Order>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Orders::get_shipping_method`], set with a builder.
+pub struct OrdersGetShippingMethodRequest<'a> {
+    inner: crate::orders::OrdersGetShippingMethodRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OrdersGetShippingMethodRequest<'a> {
+    /// Sets the `Page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sets the `Limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::OrdersShipMethodDetail>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::orders::Orders`].
+pub struct Orders {
+    inner: crate::orders::Orders,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Orders {
+    pub(crate) fn new(inner: crate::orders::Orders, runtime: Arc<tokio::runtime::Runtime>) -> Self {
+        Orders { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::orders::Orders::post_estimate`].
+    pub fn post_estimate(
+        &self,
+        body: &crate::types::OrdersEstimateFulfillmentRequestModel,
+    ) -> ClientResult<crate::Response<crate::types::OrdersEstimate>> {
+        self.runtime.block_on(self.inner.post_estimate(body))
+    }
+    /// The blocking version of [`crate::orders::Orders::get`].
+    pub fn get(&self, order_id: i64) -> ClientResult<crate::Response<crate::types::Order>> {
+        self.runtime.block_on(self.inner.get(order_id))
+    }
+    /// The blocking version of [`crate::orders::Orders::get_page`].
+    pub fn get_page(
+        &self,
+        page: i64,
+        limit: i64,
+        ids: &[String],
+        reference_ids: &[String],
+        start_date: Option<chrono::DateTime<chrono::Utc>>,
+        end_date: Option<chrono::DateTime<chrono::Utc>>,
+        sort_order: crate::types::SortOrder,
+        has_tracking: bool,
+        last_update_start_date: Option<chrono::DateTime<chrono::Utc>>,
+        last_update_end_date: Option<chrono::DateTime<chrono::Utc>>,
+        is_tracking_uploaded: bool,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Order>>> {
+        self.runtime.block_on(self.inner.get_page(
+            page,
+            limit,
+            ids,
+            reference_ids,
+            start_date,
+            end_date,
+            sort_order,
+            has_tracking,
+            last_update_start_date,
+            last_update_end_date,
+            is_tracking_uploaded,
+        ))
+    }
+    /// The blocking version of [`crate::orders::Orders::get_page_request`].
+    pub fn get_page_request(&self) -> OrdersGetPageRequest<'_> {
+        OrdersGetPageRequest {
+            inner: self.inner.get_page_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::orders::Orders::get_all`].
+    pub fn get_all(
+        &self,
+        ids: &[String],
+        reference_ids: &[String],
+        start_date: Option<chrono::DateTime<chrono::Utc>>,
+        end_date: Option<chrono::DateTime<chrono::Utc>>,
+        sort_order: crate::types::SortOrder,
+        has_tracking: bool,
+        last_update_start_date: Option<chrono::DateTime<chrono::Utc>>,
+        last_update_end_date: Option<chrono::DateTime<chrono::Utc>>,
+        is_tracking_uploaded: bool,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Order>>> {
+        self.runtime.block_on(self.inner.get_all(
+            ids,
+            reference_ids,
+            start_date,
+            end_date,
+            sort_order,
+            has_tracking,
+            last_update_start_date,
+            last_update_end_date,
+            is_tracking_uploaded,
+        ))
+    }
+    /// The blocking version of [`crate::orders::Orders::post`].
+    pub fn post(
+        &self,
+        body: &crate::types::OrdersCreateOrderModel,
+    ) -> ClientResult<crate::Response<crate::types::Order>> {
+        self.runtime.block_on(self.inner.post(body))
+    }
+    /// The blocking version of [`crate::orders::Orders::post_cancel`].
+    pub fn post_cancel(
+        &self,
+        order_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::OrdersCanceledOrder>> {
+        self.runtime.block_on(self.inner.post_cancel(order_id))
+    }
+    /// The blocking version of [`crate::orders::Orders::get_store_json`].
+    pub fn get_store_json(&self, order_id: i64) -> ClientResult<crate::Response<String>> {
+        self.runtime.block_on(self.inner.get_store_json(order_id))
+    }
+    /// The blocking version of [`crate::orders::Orders::post_store_json`].
+    pub fn post_store_json(
+        &self,
+        order_id: i64,
+        body: &crate::types::OrdersAddStoreOrderJsonModel,
+    ) -> ClientResult<crate::Response<String>> {
+        self.runtime
+            .block_on(self.inner.post_store_json(order_id, body))
+    }
+    /// The blocking version of [`crate::orders::Orders::get_shipment`].
+    pub fn get_shipment(
+        &self,
+        order_id: i64,
+        shipment_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::OrdersShipment>> {
+        self.runtime
+            .block_on(self.inner.get_shipment(order_id, shipment_id))
+    }
+    /// The blocking version of [`crate::orders::Orders::post_shipment_cancel`].
+    pub fn post_shipment_cancel(
+        &self,
+        shipment_id: i64,
+        order_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::OrdersShipment>> {
+        self.runtime
+            .block_on(self.inner.post_shipment_cancel(shipment_id, order_id))
+    }
+    /// The blocking version of [`crate::orders::Orders::get_shipment_timeline`].
+    pub fn get_shipment_timeline(
+        &self,
+        order_id: i64,
+        shipment_id: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::OrdersShipmentLog>>> {
+        self.runtime
+            .block_on(self.inner.get_shipment_timeline(order_id, shipment_id))
+    }
+    /// The blocking version of [`crate::orders::Orders::get_all_shipment_timeline`].
+    pub fn get_all_shipment_timeline(
+        &self,
+        order_id: i64,
+        shipment_id: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::OrdersShipmentLog>>> {
+        self.runtime
+            .block_on(self.inner.get_all_shipment_timeline(order_id, shipment_id))
+    }
+    /// The blocking version of [`crate::orders::Orders::get_shipments`].
+    pub fn get_shipments(
+        &self,
+        order_id: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::OrdersShipment>>> {
+        self.runtime.block_on(self.inner.get_shipments(order_id))
+    }
+    /// The blocking version of [`crate::orders::Orders::get_all_shipments`].
+    pub fn get_all_shipments(
+        &self,
+        order_id: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::OrdersShipment>>> {
+        self.runtime
+            .block_on(self.inner.get_all_shipments(order_id))
+    }
+    /// The blocking version of [`crate::orders::Orders::get_shipment_logs`].
+    pub fn get_shipment_logs(
+        &self,
+        order_id: i64,
+        shipment_id: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::OrdersShipmentLog>>> {
+        self.runtime
+            .block_on(self.inner.get_shipment_logs(order_id, shipment_id))
+    }
+    /// The blocking version of [`crate::orders::Orders::get_all_shipment_logs`].
+    pub fn get_all_shipment_logs(
+        &self,
+        order_id: i64,
+        shipment_id: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::OrdersShipmentLog>>> {
+        self.runtime
+            .block_on(self.inner.get_all_shipment_logs(order_id, shipment_id))
+    }
+    /// The blocking version of [`crate::orders::Orders::get_shipment_orders`].
+    pub fn get_shipment_orders(
+        &self,
+        shipment_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::OrdersShipment>> {
+        self.runtime
+            .block_on(self.inner.get_shipment_orders(shipment_id))
+    }
+    /// The blocking version of [`crate::orders::Orders::post_shipment_cancel_orders`].
+    pub fn post_shipment_cancel_orders(
+        &self,
+        shipment_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::OrdersShipment>> {
+        self.runtime
+            .block_on(self.inner.post_shipment_cancel_orders(shipment_id))
+    }
+    /// The blocking version of [`crate::orders::Orders::post_shipment_cancel_bulk`].
+    pub fn post_shipment_cancel_bulk(
+        &self,
+        body: &crate::types::OrdersCancelShipmentsModel,
+    ) -> ClientResult<crate::Response<crate::types::OrdersCanceledShipments>> {
+        self.runtime
+            .block_on(self.inner.post_shipment_cancel_bulk(body))
+    }
+    /// The blocking version of [`crate::orders::Orders::get_shipment_timeline_orders`].
+    pub fn get_shipment_timeline_orders(
+        &self,
+        shipment_id: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::OrdersShipmentLog>>> {
+        self.runtime
+            .block_on(self.inner.get_shipment_timeline_orders(shipment_id))
+    }
+    /// The blocking version of [`crate::orders::Orders::get_all_shipment_timeline_all`].
+    pub fn get_all_shipment_timeline_all(
+        &self,
+        shipment_id: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::OrdersShipmentLog>>> {
+        self.runtime
+            .block_on(self.inner.get_all_shipment_timeline_all(shipment_id))
+    }
+    /// The blocking version of [`crate::orders::Orders::get_shipment_logs_orders`].
+    pub fn get_shipment_logs_orders(
+        &self,
+        shipment_id: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::OrdersShipmentLog>>> {
+        self.runtime
+            .block_on(self.inner.get_shipment_logs_orders(shipment_id))
+    }
+    /// The blocking version of [`crate::orders::Orders::get_all_shipment_logs_all`].
+    pub fn get_all_shipment_logs_all(
+        &self,
+        shipment_id: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::OrdersShipmentLog>>> {
+        self.runtime
+            .block_on(self.inner.get_all_shipment_logs_all(shipment_id))
+    }
+    /// The blocking version of [`crate::orders::Orders::get_shipping_method`].
+    pub fn get_shipping_method(
+        &self,
+        page: i64,
+        limit: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::OrdersShipMethodDetail>>> {
+        self.runtime
+            .block_on(self.inner.get_shipping_method(page, limit))
+    }
+    /// The blocking version of [`crate::orders::Orders::get_shipping_method_request`].
+    pub fn get_shipping_method_request(&self) -> OrdersGetShippingMethodRequest<'_> {
+        OrdersGetShippingMethodRequest {
+            inner: self.inner.get_shipping_method_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::orders::Orders::get_all_shipping_method`].
+    pub fn get_all_shipping_method(
+        &self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::OrdersShipMethodDetail>>> {
+        self.runtime.block_on(self.inner.get_all_shipping_method())
+    }
+}
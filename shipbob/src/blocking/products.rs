@@ -0,0 +1,145 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Products::get_page`], set with a builder.
+pub struct ProductsGetPageRequest<'a> {
+    inner: crate::products::ProductsGetPageRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProductsGetPageRequest<'a> {
+    /// Sets the `Page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sets the `Limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `IDs` query parameter.
+    pub fn ids(mut self, ids: &'a [String]) -> Self {
+        self.inner = self.inner.ids(ids);
+        self
+    }
+
+    /// Sets the `ReferenceIds` query parameter.
+    pub fn reference_ids(mut self, reference_ids: &'a [String]) -> Self {
+        self.inner = self.inner.reference_ids(reference_ids);
+        self
+    }
+
+    /// Sets the `Search` query parameter.
+    pub fn search(mut self, search: &'a str) -> Self {
+        self.inner = self.inner.search(search);
+        self
+    }
+
+    /// Sets the `ActiveStatus` query parameter.
+    pub fn active_status(mut self, active_status: crate::types::ProductActiveStatus) -> Self {
+        self.inner = self.inner.active_status(active_status);
+        self
+    }
+
+    /// Sets the `BundleStatus` query parameter.
+    pub fn bundle_status(mut self, bundle_status: crate::types::ProductBundleStatus) -> Self {
+        self.inner = self.inner.bundle_status(bundle_status);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Product>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::products::Products`].
+pub struct Products {
+    inner: crate::products::Products,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Products {
+    pub(crate) fn new(
+        inner: crate::products::Products,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Products { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::products::Products::get_page`].
+    pub fn get_page(
+        &self,
+        page: i64,
+        limit: i64,
+        ids: &[String],
+        reference_ids: &[String],
+        search: &str,
+        active_status: crate::types::ProductActiveStatus,
+        bundle_status: crate::types::ProductBundleStatus,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Product>>> {
+        self.runtime.block_on(self.inner.get_page(
+            page,
+            limit,
+            ids,
+            reference_ids,
+            search,
+            active_status,
+            bundle_status,
+        ))
+    }
+    /// The blocking version of [`crate::products::Products::get_page_request`].
+    pub fn get_page_request(&self) -> ProductsGetPageRequest<'_> {
+        ProductsGetPageRequest {
+            inner: self.inner.get_page_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::products::Products::get_all`].
+    pub fn get_all(
+        &self,
+        ids: &[String],
+        reference_ids: &[String],
+        search: &str,
+        active_status: crate::types::ProductActiveStatus,
+        bundle_status: crate::types::ProductBundleStatus,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Product>>> {
+        self.runtime.block_on(self.inner.get_all(
+            ids,
+            reference_ids,
+            search,
+            active_status,
+            bundle_status,
+        ))
+    }
+    /// The blocking version of [`crate::products::Products::post`].
+    pub fn post(
+        &self,
+        body: &crate::types::ProductsCreateProductModel,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Product>>> {
+        self.runtime.block_on(self.inner.post(body))
+    }
+    /// The blocking version of [`crate::products::Products::get`].
+    pub fn get(&self, product_id: i64) -> ClientResult<crate::Response<crate::types::Product>> {
+        self.runtime.block_on(self.inner.get(product_id))
+    }
+    /// The blocking version of [`crate::products::Products::put`].
+    pub fn put(
+        &self,
+        product_id: i64,
+        body: &crate::types::ProductsUpdateProductModel,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Product>>> {
+        self.runtime.block_on(self.inner.put(product_id, body))
+    }
+    /// The blocking version of [`crate::products::Products::post_batch`].
+    pub fn post_batch(
+        &self,
+        body: &[crate::types::ProductsCreateProductModel],
+    ) -> ClientResult<crate::Response<Vec<crate::types::Product>>> {
+        self.runtime.block_on(self.inner.post_batch(body))
+    }
+}
@@ -0,0 +1,51 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::receiving::Receiving`].
+pub struct Receiving {
+    inner: crate::receiving::Receiving,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Receiving {
+    pub(crate) fn new(
+        inner: crate::receiving::Receiving,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Receiving { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::receiving::Receiving::get_fulfillment_center`].
+    pub fn get_fulfillment_center(
+        &self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ReceivingFulfillmentCenter>>> {
+        self.runtime.block_on(self.inner.get_fulfillment_center())
+    }
+    /// The blocking version of [`crate::receiving::Receiving::get_all_fulfillment_center`].
+    pub fn get_all_fulfillment_center(
+        &self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ReceivingFulfillmentCenter>>> {
+        self.runtime
+            .block_on(self.inner.get_all_fulfillment_center())
+    }
+    /// The blocking version of [`crate::receiving::Receiving::get`].
+    pub fn get(&self, id: i64) -> ClientResult<crate::Response<crate::types::ReceivingOrder>> {
+        self.runtime.block_on(self.inner.get(id))
+    }
+    /// The blocking version of [`crate::receiving::Receiving::get_label`].
+    pub fn get_label(&self, id: i64) -> ClientResult<crate::Response<bytes::Bytes>> {
+        self.runtime.block_on(self.inner.get_label(id))
+    }
+    /// The blocking version of [`crate::receiving::Receiving::post`].
+    pub fn post(
+        &self,
+        body: &crate::types::ReceivingCreateOrderModel,
+    ) -> ClientResult<crate::Response<crate::types::ReceivingOrder>> {
+        self.runtime.block_on(self.inner.post(body))
+    }
+    /// The blocking version of [`crate::receiving::Receiving::post_cancel`].
+    pub fn post_cancel(&self, id: i64) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.post_cancel(id))
+    }
+}
@@ -0,0 +1,199 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Returns::get_page`], set with a builder.
+pub struct ReturnsGetPageRequest<'a> {
+    inner: crate::returns::ReturnsGetPageRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ReturnsGetPageRequest<'a> {
+    /// Sets the `Page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sets the `Limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `SortOrder` query parameter.
+    pub fn sort_order(mut self, sort_order: crate::types::SortOrder) -> Self {
+        self.inner = self.inner.sort_order(sort_order);
+        self
+    }
+
+    /// Sets the `StartDate` query parameter.
+    pub fn start_date(mut self, start_date: chrono::DateTime<chrono::Utc>) -> Self {
+        self.inner = self.inner.start_date(start_date);
+        self
+    }
+
+    /// Sets the `EndDate` query parameter.
+    pub fn end_date(mut self, end_date: chrono::DateTime<chrono::Utc>) -> Self {
+        self.inner = self.inner.end_date(end_date);
+        self
+    }
+
+    /// Sets the `IDs` query parameter.
+    pub fn ids(mut self, ids: &'a [String]) -> Self {
+        self.inner = self.inner.ids(ids);
+        self
+    }
+
+    /// Sets the `ReferenceIds` query parameter.
+    pub fn reference_ids(mut self, reference_ids: &'a [String]) -> Self {
+        self.inner = self.inner.reference_ids(reference_ids);
+        self
+    }
+
+    /// Sets the `Status` query parameter.
+    pub fn status(mut self, status: &'a [String]) -> Self {
+        self.inner = self.inner.status(status);
+        self
+    }
+
+    /// Sets the `FulfillmentCenterIds` query parameter.
+    pub fn fulfillment_center_ids(mut self, fulfillment_center_ids: &'a [String]) -> Self {
+        self.inner = self.inner.fulfillment_center_ids(fulfillment_center_ids);
+        self
+    }
+
+    /// Sets the `TrackingNumbers` query parameter.
+    pub fn tracking_numbers(mut self, tracking_numbers: &'a [String]) -> Self {
+        self.inner = self.inner.tracking_numbers(tracking_numbers);
+        self
+    }
+
+    /// Sets the `OriginalShipmentIds` query parameter.
+    pub fn original_shipment_ids(mut self, original_shipment_ids: &'a [String]) -> Self {
+        self.inner = self.inner.original_shipment_ids(original_shipment_ids);
+        self
+    }
+
+    /// Sets the `InventoryIds` query parameter.
+    pub fn inventory_ids(mut self, inventory_ids: &'a [String]) -> Self {
+        self.inner = self.inner.inventory_ids(inventory_ids);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::ReturnOrder>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::returns::Returns`].
+pub struct Returns {
+    inner: crate::returns::Returns,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Returns {
+    pub(crate) fn new(
+        inner: crate::returns::Returns,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Returns { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::returns::Returns::get`].
+    pub fn get(&self, id: i64) -> ClientResult<crate::Response<crate::types::ReturnOrder>> {
+        self.runtime.block_on(self.inner.get(id))
+    }
+    /// The blocking version of [`crate::returns::Returns::put`].
+    pub fn put(
+        &self,
+        id: i64,
+        body: &crate::types::ReturnsCreateReturn,
+    ) -> ClientResult<crate::Response<crate::types::ReturnOrder>> {
+        self.runtime.block_on(self.inner.put(id, body))
+    }
+    /// The blocking version of [`crate::returns::Returns::get_page`].
+    pub fn get_page(
+        &self,
+        page: i64,
+        limit: i64,
+        sort_order: crate::types::SortOrder,
+        start_date: Option<chrono::DateTime<chrono::Utc>>,
+        end_date: Option<chrono::DateTime<chrono::Utc>>,
+        ids: &[String],
+        reference_ids: &[String],
+        status: &[String],
+        fulfillment_center_ids: &[String],
+        tracking_numbers: &[String],
+        original_shipment_ids: &[String],
+        inventory_ids: &[String],
+    ) -> ClientResult<crate::Response<Vec<crate::types::ReturnOrder>>> {
+        self.runtime.block_on(self.inner.get_page(
+            page,
+            limit,
+            sort_order,
+            start_date,
+            end_date,
+            ids,
+            reference_ids,
+            status,
+            fulfillment_center_ids,
+            tracking_numbers,
+            original_shipment_ids,
+            inventory_ids,
+        ))
+    }
+    /// The blocking version of [`crate::returns::Returns::get_page_request`].
+    pub fn get_page_request(&self) -> ReturnsGetPageRequest<'_> {
+        ReturnsGetPageRequest {
+            inner: self.inner.get_page_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::returns::Returns::get_all`].
+    pub fn get_all(
+        &self,
+        sort_order: crate::types::SortOrder,
+        start_date: Option<chrono::DateTime<chrono::Utc>>,
+        end_date: Option<chrono::DateTime<chrono::Utc>>,
+        ids: &[String],
+        reference_ids: &[String],
+        status: &[String],
+        fulfillment_center_ids: &[String],
+        tracking_numbers: &[String],
+        original_shipment_ids: &[String],
+        inventory_ids: &[String],
+    ) -> ClientResult<crate::Response<Vec<crate::types::ReturnOrder>>> {
+        self.runtime.block_on(self.inner.get_all(
+            sort_order,
+            start_date,
+            end_date,
+            ids,
+            reference_ids,
+            status,
+            fulfillment_center_ids,
+            tracking_numbers,
+            original_shipment_ids,
+            inventory_ids,
+        ))
+    }
+    /// The blocking version of [`crate::returns::Returns::post`].
+    pub fn post(
+        &self,
+        body: &crate::types::ReturnsCreateReturn,
+    ) -> ClientResult<crate::Response<crate::types::ReturnOrder>> {
+        self.runtime.block_on(self.inner.post(body))
+    }
+    /// The blocking version of [`crate::returns::Returns::post_cancel`].
+    pub fn post_cancel(&self, id: i64) -> ClientResult<crate::Response<crate::types::ReturnOrder>> {
+        self.runtime.block_on(self.inner.post_cancel(id))
+    }
+    /// The blocking version of [`crate::returns::Returns::get_status_history`].
+    pub fn get_status_history(
+        &self,
+        id: i64,
+    ) -> ClientResult<crate::Response<crate::types::ReturnOrderStatusHistory>> {
+        self.runtime.block_on(self.inner.get_status_history(id))
+    }
+}
@@ -0,0 +1,85 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Webhooks::get_page`], set with a builder.
+pub struct WebhooksGetPageRequest<'a> {
+    inner: crate::webhooks::WebhooksGetPageRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> WebhooksGetPageRequest<'a> {
+    /// Sets the `Topic` query parameter.
+    pub fn topic(mut self, topic: crate::types::WebhooksTopics) -> Self {
+        self.inner = self.inner.topic(topic);
+        self
+    }
+
+    /// Sets the `Page` query parameter.
+    pub fn page(mut self, page: u64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sets the `Limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Webhook>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::webhooks::Webhooks`].
+pub struct Webhooks {
+    inner: crate::webhooks::Webhooks,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Webhooks {
+    pub(crate) fn new(
+        inner: crate::webhooks::Webhooks,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Webhooks { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::webhooks::Webhooks::get_page`].
+    pub fn get_page(
+        &self,
+        topic: crate::types::WebhooksTopics,
+        page: u64,
+        limit: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Webhook>>> {
+        self.runtime
+            .block_on(self.inner.get_page(topic, page, limit))
+    }
+    /// The blocking version of [`crate::webhooks::Webhooks::get_page_request`].
+    pub fn get_page_request(&self) -> WebhooksGetPageRequest<'_> {
+        WebhooksGetPageRequest {
+            inner: self.inner.get_page_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::webhooks::Webhooks::get_all`].
+    pub fn get_all(
+        &self,
+        topic: crate::types::WebhooksTopics,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Webhook>>> {
+        self.runtime.block_on(self.inner.get_all(topic))
+    }
+    /// The blocking version of [`crate::webhooks::Webhooks::post`].
+    pub fn post(
+        &self,
+        body: &crate::types::WebhooksCreateWebhookSubscriptionModel,
+    ) -> ClientResult<crate::Response<crate::types::Webhook>> {
+        self.runtime.block_on(self.inner.post(body))
+    }
+    /// The blocking version of [`crate::webhooks::Webhooks::delete`].
+    pub fn delete(&self, id: i64) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.delete(id))
+    }
+}
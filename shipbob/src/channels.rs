@@ -47,4 +47,17 @@ impl Channels {
             )
             .await
     }
+    /**
+     * Get user-authorized channel info.
+     *
+     * This function performs a `GET` to the `/channel` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     */
+    pub fn get_all_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Channel>> + Unpin + '_ {
+        let url = self.client.url("/channel", None);
+        self.client.get_all_pages_stream(url)
+    }
 }
@@ -1,6 +1,111 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Inventory::get_page`], set with a builder.
+pub struct InventoryGetPageRequest<'a> {
+    client: &'a Inventory,
+    page: Option<i64>,
+    limit: Option<i64>,
+    is_active: Option<bool>,
+    is_digital: Option<bool>,
+    ids: Option<&'a [String]>,
+    sort: Option<&'a str>,
+    search: Option<&'a str>,
+}
+
+impl<'a> InventoryGetPageRequest<'a> {
+    /// Sets the `Page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sets the `Limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `IsActive` query parameter.
+    pub fn is_active(mut self, is_active: bool) -> Self {
+        self.is_active = Some(is_active);
+        self
+    }
+
+    /// Sets the `IsDigital` query parameter.
+    pub fn is_digital(mut self, is_digital: bool) -> Self {
+        self.is_digital = Some(is_digital);
+        self
+    }
+
+    /// Sets the `IDs` query parameter.
+    pub fn ids(mut self, ids: &'a [String]) -> Self {
+        self.ids = Some(ids);
+        self
+    }
+
+    /// Sets the `Sort` query parameter.
+    pub fn sort(mut self, sort: &'a str) -> Self {
+        self.sort = Some(sort);
+        self
+    }
+
+    /// Sets the `Search` query parameter.
+    pub fn search(mut self, search: &'a str) -> Self {
+        self.search = Some(search);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Inventory>>> {
+        let Self {
+            client,
+            page,
+            limit,
+            is_active,
+            is_digital,
+            ids,
+            sort,
+            search,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = ids {
+            query_args.push(("IDs".to_string(), v.join(" ")));
+        }
+        if let Some(v) = is_active {
+            query_args.push(("IsActive".to_string(), v.to_string()));
+        }
+        if let Some(v) = is_digital {
+            query_args.push(("IsDigital".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("Limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = page {
+            query_args.push(("Page".to_string(), v.to_string()));
+        }
+        if let Some(v) = search {
+            query_args.push(("Search".to_string(), v.to_string()));
+        }
+        if let Some(v) = sort {
+            query_args.push(("Sort".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(&format!("/inventory?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct Inventory {
     pub client: Client,
 }
@@ -106,6 +211,25 @@ impl Inventory {
             )
             .await
     }
+    /**
+     * List inventory items.
+     *
+     * This function performs a `GET` to the `/inventory` endpoint.
+     *
+     * As opposed to `get_page`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     */
+    pub fn get_page_request(&self) -> InventoryGetPageRequest<'_> {
+        InventoryGetPageRequest {
+            client: self,
+            page: None,
+            limit: None,
+            is_active: None,
+            is_digital: None,
+            ids: None,
+            sort: None,
+            search: None,
+        }
+    }
     /**
      * List inventory items.
      *
@@ -149,6 +273,41 @@ impl Inventory {
             )
             .await
     }
+    /**
+     * List inventory items.
+     *
+     * This function performs a `GET` to the `/inventory` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     */
+    pub fn get_all_stream(
+        &self,
+        is_active: bool,
+        is_digital: bool,
+        ids: &[String],
+        sort: &str,
+        search: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Inventory>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !ids.is_empty() {
+            query_args.push(("IDs".to_string(), ids.join(" ")));
+        }
+        if is_active {
+            query_args.push(("IsActive".to_string(), is_active.to_string()));
+        }
+        if is_digital {
+            query_args.push(("IsDigital".to_string(), is_digital.to_string()));
+        }
+        if !search.is_empty() {
+            query_args.push(("Search".to_string(), search.to_string()));
+        }
+        if !sort.is_empty() {
+            query_args.push(("Sort".to_string(), sort.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/inventory?{}", query_), None);
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Get a list of inventory items by product id.
      *
@@ -208,4 +367,24 @@ impl Inventory {
             )
             .await
     }
+    /**
+     * Get a list of inventory items by product id.
+     *
+     * This function performs a `GET` to the `/product/{productId}/inventory` endpoint.
+     *
+     * As opposed to `get_all_product`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     */
+    pub fn get_all_product_stream(
+        &self,
+        product_id: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Inventory>> + Unpin + '_ {
+        let url = self.client.url(
+            &format!(
+                "/product/{}/inventory",
+                crate::progenitor_support::encode_path(&product_id.to_string()),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
 }
@@ -1,6 +1,69 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Locations::get_page`], set with a builder.
+pub struct LocationsGetPageRequest<'a> {
+    client: &'a Locations,
+    include_inactive: Option<bool>,
+    receiving_enabled: Option<bool>,
+    access_granted: Option<bool>,
+}
+
+impl<'a> LocationsGetPageRequest<'a> {
+    /// Sets the `IncludeInactive` query parameter.
+    pub fn include_inactive(mut self, include_inactive: bool) -> Self {
+        self.include_inactive = Some(include_inactive);
+        self
+    }
+
+    /// Sets the `ReceivingEnabled` query parameter.
+    pub fn receiving_enabled(mut self, receiving_enabled: bool) -> Self {
+        self.receiving_enabled = Some(receiving_enabled);
+        self
+    }
+
+    /// Sets the `AccessGranted` query parameter.
+    pub fn access_granted(mut self, access_granted: bool) -> Self {
+        self.access_granted = Some(access_granted);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::IntegrationsLocationInternalAllOf>>> {
+        let Self {
+            client,
+            include_inactive,
+            receiving_enabled,
+            access_granted,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = access_granted {
+            query_args.push(("AccessGranted".to_string(), v.to_string()));
+        }
+        if let Some(v) = include_inactive {
+            query_args.push(("IncludeInactive".to_string(), v.to_string()));
+        }
+        if let Some(v) = receiving_enabled {
+            query_args.push(("ReceivingEnabled".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(&format!("/location?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct Locations {
     pub client: Client,
 }
@@ -53,6 +116,21 @@ impl Locations {
             )
             .await
     }
+    /**
+     * Get locations.
+     *
+     * This function performs a `GET` to the `/location` endpoint.
+     *
+     * As opposed to `get_page`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     */
+    pub fn get_page_request(&self) -> LocationsGetPageRequest<'_> {
+        LocationsGetPageRequest {
+            client: self,
+            include_inactive: None,
+            receiving_enabled: None,
+            access_granted: None,
+        }
+    }
     /**
      * Get locations.
      *
@@ -91,4 +169,36 @@ impl Locations {
             )
             .await
     }
+    /**
+     * Get locations.
+     *
+     * This function performs a `GET` to the `/location` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     */
+    pub fn get_all_stream(
+        &self,
+        include_inactive: bool,
+        receiving_enabled: bool,
+        access_granted: bool,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::IntegrationsLocationInternalAllOf>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if access_granted {
+            query_args.push(("AccessGranted".to_string(), access_granted.to_string()));
+        }
+        if include_inactive {
+            query_args.push(("IncludeInactive".to_string(), include_inactive.to_string()));
+        }
+        if receiving_enabled {
+            query_args.push((
+                "ReceivingEnabled".to_string(),
+                receiving_enabled.to_string(),
+            ));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/location?{}", query_), None);
+        self.client.get_all_pages_stream(url)
+    }
 }
@@ -1,6 +1,215 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Orders::get_page`], set with a builder.
+pub struct OrdersGetPageRequest<'a> {
+    client: &'a Orders,
+    page: Option<i64>,
+    limit: Option<i64>,
+    ids: Option<&'a [String]>,
+    reference_ids: Option<&'a [String]>,
+    start_date: Option<chrono::DateTime<chrono::Utc>>,
+    end_date: Option<chrono::DateTime<chrono::Utc>>,
+    sort_order: Option<crate::types::SortOrder>,
+    has_tracking: Option<bool>,
+    last_update_start_date: Option<chrono::DateTime<chrono::Utc>>,
+    last_update_end_date: Option<chrono::DateTime<chrono::Utc>>,
+    is_tracking_uploaded: Option<bool>,
+}
+
+impl<'a> OrdersGetPageRequest<'a> {
+    /// Sets the `Page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sets the `Limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `IDs` query parameter.
+    pub fn ids(mut self, ids: &'a [String]) -> Self {
+        self.ids = Some(ids);
+        self
+    }
+
+    /// Sets the `ReferenceIds` query parameter.
+    pub fn reference_ids(mut self, reference_ids: &'a [String]) -> Self {
+        self.reference_ids = Some(reference_ids);
+        self
+    }
+
+    /// Sets the `StartDate` query parameter.
+    pub fn start_date(mut self, start_date: chrono::DateTime<chrono::Utc>) -> Self {
+        self.start_date = Some(start_date);
+        self
+    }
+
+    /// Sets the `EndDate` query parameter.
+    pub fn end_date(mut self, end_date: chrono::DateTime<chrono::Utc>) -> Self {
+        self.end_date = Some(end_date);
+        self
+    }
+
+    /// Sets the `SortOrder` query parameter.
+    pub fn sort_order(mut self, sort_order: crate::types::SortOrder) -> Self {
+        self.sort_order = Some(sort_order);
+        self
+    }
+
+    /// Sets the `HasTracking` query parameter.
+    pub fn has_tracking(mut self, has_tracking: bool) -> Self {
+        self.has_tracking = Some(has_tracking);
+        self
+    }
+
+    /// Sets the `LastUpdateStartDate` query parameter.
+    pub fn last_update_start_date(
+        mut self,
+        last_update_start_date: chrono::DateTime<chrono::Utc>,
+    ) -> Self {
+        self.last_update_start_date = Some(last_update_start_date);
+        self
+    }
+
+    /// Sets the `LastUpdateEndDate` query parameter.
+    pub fn last_update_end_date(
+        mut self,
+        last_update_end_date: chrono::DateTime<chrono::Utc>,
+    ) -> Self {
+        self.last_update_end_date = Some(last_update_end_date);
+        self
+    }
+
+    /// Sets the `IsTrackingUploaded` query parameter.
+    pub fn is_tracking_uploaded(mut self, is_tracking_uploaded: bool) -> Self {
+        self.is_tracking_uploaded = Some(is_tracking_uploaded);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Order>>> {
+        let Self {
+            client,
+            page,
+            limit,
+            ids,
+            reference_ids,
+            start_date,
+            end_date,
+            sort_order,
+            has_tracking,
+            last_update_start_date,
+            last_update_end_date,
+            is_tracking_uploaded,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(date) = end_date {
+            query_args.push(("EndDate".to_string(), date.to_rfc3339()));
+        }
+        if let Some(v) = has_tracking {
+            query_args.push(("HasTracking".to_string(), v.to_string()));
+        }
+        if let Some(v) = ids {
+            query_args.push(("IDs".to_string(), v.join(" ")));
+        }
+        if let Some(v) = is_tracking_uploaded {
+            query_args.push(("IsTrackingUploaded".to_string(), v.to_string()));
+        }
+        if let Some(date) = last_update_end_date {
+            query_args.push(("LastUpdateEndDate".to_string(), date.to_rfc3339()));
+        }
+        if let Some(date) = last_update_start_date {
+            query_args.push(("LastUpdateStartDate".to_string(), date.to_rfc3339()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("Limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = page {
+            query_args.push(("Page".to_string(), v.to_string()));
+        }
+        if let Some(v) = reference_ids {
+            query_args.push(("ReferenceIds".to_string(), v.join(" ")));
+        }
+        if let Some(v) = sort_order {
+            query_args.push(("SortOrder".to_string(), v.to_string()));
+        }
+        if let Some(date) = start_date {
+            query_args.push(("StartDate".to_string(), date.to_rfc3339()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(&format!("/order?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Orders::get_shipping_method`], set with a builder.
+pub struct OrdersGetShippingMethodRequest<'a> {
+    client: &'a Orders,
+    page: Option<i64>,
+    limit: Option<i64>,
+}
+
+impl<'a> OrdersGetShippingMethodRequest<'a> {
+    /// Sets the `Page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sets the `Limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::OrdersShipMethodDetail>>> {
+        let Self {
+            client,
+            page,
+            limit,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = limit {
+            query_args.push(("Limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = page {
+            query_args.push(("Page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/shippingmethod?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct Orders {
     pub client: Client,
 }
@@ -153,6 +362,31 @@ impl Orders {
             )
             .await
     }
+    /**
+     * Get Orders.
+     *
+     * This function performs a `GET` to the `/order` endpoint.
+     *
+     * As opposed to `get_page`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * All parameters are AND filters
+     */
+    pub fn get_page_request(&self) -> OrdersGetPageRequest<'_> {
+        OrdersGetPageRequest {
+            client: self,
+            page: None,
+            limit: None,
+            ids: None,
+            reference_ids: None,
+            start_date: None,
+            end_date: None,
+            sort_order: None,
+            has_tracking: None,
+            last_update_start_date: None,
+            last_update_end_date: None,
+            is_tracking_uploaded: None,
+        }
+    }
     /**
      * Get Orders.
      *
@@ -217,6 +451,62 @@ impl Orders {
             )
             .await
     }
+    /**
+     * Get Orders.
+     *
+     * This function performs a `GET` to the `/order` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * All parameters are AND filters
+     */
+    pub fn get_all_stream(
+        &self,
+        ids: &[String],
+        reference_ids: &[String],
+        start_date: Option<chrono::DateTime<chrono::Utc>>,
+        end_date: Option<chrono::DateTime<chrono::Utc>>,
+        sort_order: crate::types::SortOrder,
+        has_tracking: bool,
+        last_update_start_date: Option<chrono::DateTime<chrono::Utc>>,
+        last_update_end_date: Option<chrono::DateTime<chrono::Utc>>,
+        is_tracking_uploaded: bool,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Order>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(date) = end_date {
+            query_args.push(("EndDate".to_string(), date.to_rfc3339()));
+        }
+        if has_tracking {
+            query_args.push(("HasTracking".to_string(), has_tracking.to_string()));
+        }
+        if !ids.is_empty() {
+            query_args.push(("IDs".to_string(), ids.join(" ")));
+        }
+        if is_tracking_uploaded {
+            query_args.push((
+                "IsTrackingUploaded".to_string(),
+                is_tracking_uploaded.to_string(),
+            ));
+        }
+        if let Some(date) = last_update_end_date {
+            query_args.push(("LastUpdateEndDate".to_string(), date.to_rfc3339()));
+        }
+        if let Some(date) = last_update_start_date {
+            query_args.push(("LastUpdateStartDate".to_string(), date.to_rfc3339()));
+        }
+        if !reference_ids.is_empty() {
+            query_args.push(("ReferenceIds".to_string(), reference_ids.join(" ")));
+        }
+        if !sort_order.to_string().is_empty() {
+            query_args.push(("SortOrder".to_string(), sort_order.to_string()));
+        }
+        if let Some(date) = start_date {
+            query_args.push(("StartDate".to_string(), date.to_rfc3339()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/order?{}", query_), None);
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Create Order.
      *
@@ -462,6 +752,29 @@ impl Orders {
             )
             .await
     }
+    /**
+     * Get one Shipment's status timeline by Order Id and Shipment Id.
+     *
+     * This function performs a `GET` to the `/order/{orderId}/shipment/{shipmentId}/timeline` endpoint.
+     *
+     * As opposed to `get_all_shipment_timeline`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     */
+    pub fn get_all_shipment_timeline_stream(
+        &self,
+        order_id: i64,
+        shipment_id: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::OrdersShipmentLog>> + Unpin + '_
+    {
+        let url = self.client.url(
+            &format!(
+                "/order/{}/shipment/{}/timeline",
+                crate::progenitor_support::encode_path(&order_id.to_string()),
+                crate::progenitor_support::encode_path(&shipment_id.to_string()),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Get all Shipments for Order.
      *
@@ -521,6 +834,26 @@ impl Orders {
             )
             .await
     }
+    /**
+     * Get all Shipments for Order.
+     *
+     * This function performs a `GET` to the `/order/{orderId}/shipment` endpoint.
+     *
+     * As opposed to `get_all_shipments`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     */
+    pub fn get_all_shipments_stream(
+        &self,
+        order_id: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::OrdersShipment>> + Unpin + '_ {
+        let url = self.client.url(
+            &format!(
+                "/order/{}/shipment",
+                crate::progenitor_support::encode_path(&order_id.to_string()),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Get logs for one Shipment by Order Id and Shipment Id.
      *
@@ -585,6 +918,29 @@ impl Orders {
             )
             .await
     }
+    /**
+     * Get logs for one Shipment by Order Id and Shipment Id.
+     *
+     * This function performs a `GET` to the `/order/{orderId}/shipment/{shipmentId}/logs` endpoint.
+     *
+     * As opposed to `get_all_shipment_logs`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     */
+    pub fn get_all_shipment_logs_stream(
+        &self,
+        order_id: i64,
+        shipment_id: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::OrdersShipmentLog>> + Unpin + '_
+    {
+        let url = self.client.url(
+            &format!(
+                "/order/{}/shipment/{}/logs",
+                crate::progenitor_support::encode_path(&order_id.to_string()),
+                crate::progenitor_support::encode_path(&shipment_id.to_string()),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Get one Shipment by Shipment Id.
      *
@@ -730,6 +1086,27 @@ impl Orders {
             )
             .await
     }
+    /**
+     * Get one Shipment's status timeline by Shipment Id.
+     *
+     * This function performs a `GET` to the `/shipment/{shipmentId}/timeline` endpoint.
+     *
+     * As opposed to `get_all_shipment_timeline_all`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     */
+    pub fn get_all_shipment_timeline_all_stream(
+        &self,
+        shipment_id: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::OrdersShipmentLog>> + Unpin + '_
+    {
+        let url = self.client.url(
+            &format!(
+                "/shipment/{}/timeline",
+                crate::progenitor_support::encode_path(&shipment_id.to_string()),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Get logs for one Shipment by Shipment Id.
      *
@@ -789,6 +1166,27 @@ impl Orders {
             )
             .await
     }
+    /**
+     * Get logs for one Shipment by Shipment Id.
+     *
+     * This function performs a `GET` to the `/shipment/{shipmentId}/logs` endpoint.
+     *
+     * As opposed to `get_all_shipment_logs_all`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     */
+    pub fn get_all_shipment_logs_all_stream(
+        &self,
+        shipment_id: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::OrdersShipmentLog>> + Unpin + '_
+    {
+        let url = self.client.url(
+            &format!(
+                "/shipment/{}/logs",
+                crate::progenitor_support::encode_path(&shipment_id.to_string()),
+            ),
+            None,
+        );
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Get shipping methods.
      *
@@ -827,6 +1225,22 @@ impl Orders {
             )
             .await
     }
+    /**
+     * Get shipping methods.
+     *
+     * This function performs a `GET` to the `/shippingmethod` endpoint.
+     *
+     * As opposed to `get_shipping_method`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get all merchants shipping methods
+     */
+    pub fn get_shipping_method_request(&self) -> OrdersGetShippingMethodRequest<'_> {
+        OrdersGetShippingMethodRequest {
+            client: self,
+            page: None,
+            limit: None,
+        }
+    }
     /**
      * Get shipping methods.
      *
@@ -850,4 +1264,20 @@ impl Orders {
             )
             .await
     }
+    /**
+     * Get shipping methods.
+     *
+     * This function performs a `GET` to the `/shippingmethod` endpoint.
+     *
+     * As opposed to `get_all_shipping_method`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Get all merchants shipping methods
+     */
+    pub fn get_all_shipping_method_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::OrdersShipMethodDetail>> + Unpin + '_
+    {
+        let url = self.client.url("/shippingmethod", None);
+        self.client.get_all_pages_stream(url)
+    }
 }
@@ -1,6 +1,111 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Products::get_page`], set with a builder.
+pub struct ProductsGetPageRequest<'a> {
+    client: &'a Products,
+    page: Option<i64>,
+    limit: Option<i64>,
+    ids: Option<&'a [String]>,
+    reference_ids: Option<&'a [String]>,
+    search: Option<&'a str>,
+    active_status: Option<crate::types::ProductActiveStatus>,
+    bundle_status: Option<crate::types::ProductBundleStatus>,
+}
+
+impl<'a> ProductsGetPageRequest<'a> {
+    /// Sets the `Page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sets the `Limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `IDs` query parameter.
+    pub fn ids(mut self, ids: &'a [String]) -> Self {
+        self.ids = Some(ids);
+        self
+    }
+
+    /// Sets the `ReferenceIds` query parameter.
+    pub fn reference_ids(mut self, reference_ids: &'a [String]) -> Self {
+        self.reference_ids = Some(reference_ids);
+        self
+    }
+
+    /// Sets the `Search` query parameter.
+    pub fn search(mut self, search: &'a str) -> Self {
+        self.search = Some(search);
+        self
+    }
+
+    /// Sets the `ActiveStatus` query parameter.
+    pub fn active_status(mut self, active_status: crate::types::ProductActiveStatus) -> Self {
+        self.active_status = Some(active_status);
+        self
+    }
+
+    /// Sets the `BundleStatus` query parameter.
+    pub fn bundle_status(mut self, bundle_status: crate::types::ProductBundleStatus) -> Self {
+        self.bundle_status = Some(bundle_status);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Product>>> {
+        let Self {
+            client,
+            page,
+            limit,
+            ids,
+            reference_ids,
+            search,
+            active_status,
+            bundle_status,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = active_status {
+            query_args.push(("ActiveStatus".to_string(), v.to_string()));
+        }
+        if let Some(v) = bundle_status {
+            query_args.push(("BundleStatus".to_string(), v.to_string()));
+        }
+        if let Some(v) = ids {
+            query_args.push(("IDs".to_string(), v.join(" ")));
+        }
+        if let Some(v) = limit {
+            query_args.push(("Limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = page {
+            query_args.push(("Page".to_string(), v.to_string()));
+        }
+        if let Some(v) = reference_ids {
+            query_args.push(("ReferenceIds".to_string(), v.join(" ")));
+        }
+        if let Some(v) = search {
+            query_args.push(("Search".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(&format!("/product?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct Products {
     pub client: Client,
 }
@@ -80,6 +185,25 @@ impl Products {
             )
             .await
     }
+    /**
+     * Get multiple products.
+     *
+     * This function performs a `GET` to the `/product` endpoint.
+     *
+     * As opposed to `get_page`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     */
+    pub fn get_page_request(&self) -> ProductsGetPageRequest<'_> {
+        ProductsGetPageRequest {
+            client: self,
+            page: None,
+            limit: None,
+            ids: None,
+            reference_ids: None,
+            search: None,
+            active_status: None,
+            bundle_status: None,
+        }
+    }
     /**
      * Get multiple products.
      *
@@ -123,6 +247,41 @@ impl Products {
             )
             .await
     }
+    /**
+     * Get multiple products.
+     *
+     * This function performs a `GET` to the `/product` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     */
+    pub fn get_all_stream(
+        &self,
+        ids: &[String],
+        reference_ids: &[String],
+        search: &str,
+        active_status: crate::types::ProductActiveStatus,
+        bundle_status: crate::types::ProductBundleStatus,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Product>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !active_status.to_string().is_empty() {
+            query_args.push(("ActiveStatus".to_string(), active_status.to_string()));
+        }
+        if !bundle_status.to_string().is_empty() {
+            query_args.push(("BundleStatus".to_string(), bundle_status.to_string()));
+        }
+        if !ids.is_empty() {
+            query_args.push(("IDs".to_string(), ids.join(" ")));
+        }
+        if !reference_ids.is_empty() {
+            query_args.push(("ReferenceIds".to_string(), reference_ids.join(" ")));
+        }
+        if !search.is_empty() {
+            query_args.push(("Search".to_string(), search.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/product?{}", query_), None);
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Add a single product to the store.
      *
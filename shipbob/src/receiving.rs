@@ -51,6 +51,20 @@ impl Receiving {
             )
             .await
     }
+    /**
+     * Get Fulfillment Centers.
+     *
+     * This function performs a `GET` to the `/fulfillmentCenter` endpoint.
+     *
+     * As opposed to `get_all_fulfillment_center`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     */
+    pub fn get_all_fulfillment_center_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::ReceivingFulfillmentCenter>> + Unpin + '_
+    {
+        let url = self.client.url("/fulfillmentCenter", None);
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Get Warehouse Receiving Order.
      *
@@ -1,6 +1,166 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Returns::get_page`], set with a builder.
+pub struct ReturnsGetPageRequest<'a> {
+    client: &'a Returns,
+    page: Option<i64>,
+    limit: Option<i64>,
+    sort_order: Option<crate::types::SortOrder>,
+    start_date: Option<chrono::DateTime<chrono::Utc>>,
+    end_date: Option<chrono::DateTime<chrono::Utc>>,
+    ids: Option<&'a [String]>,
+    reference_ids: Option<&'a [String]>,
+    status: Option<&'a [String]>,
+    fulfillment_center_ids: Option<&'a [String]>,
+    tracking_numbers: Option<&'a [String]>,
+    original_shipment_ids: Option<&'a [String]>,
+    inventory_ids: Option<&'a [String]>,
+}
+
+impl<'a> ReturnsGetPageRequest<'a> {
+    /// Sets the `Page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sets the `Limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `SortOrder` query parameter.
+    pub fn sort_order(mut self, sort_order: crate::types::SortOrder) -> Self {
+        self.sort_order = Some(sort_order);
+        self
+    }
+
+    /// Sets the `StartDate` query parameter.
+    pub fn start_date(mut self, start_date: chrono::DateTime<chrono::Utc>) -> Self {
+        self.start_date = Some(start_date);
+        self
+    }
+
+    /// Sets the `EndDate` query parameter.
+    pub fn end_date(mut self, end_date: chrono::DateTime<chrono::Utc>) -> Self {
+        self.end_date = Some(end_date);
+        self
+    }
+
+    /// Sets the `IDs` query parameter.
+    pub fn ids(mut self, ids: &'a [String]) -> Self {
+        self.ids = Some(ids);
+        self
+    }
+
+    /// Sets the `ReferenceIds` query parameter.
+    pub fn reference_ids(mut self, reference_ids: &'a [String]) -> Self {
+        self.reference_ids = Some(reference_ids);
+        self
+    }
+
+    /// Sets the `Status` query parameter.
+    pub fn status(mut self, status: &'a [String]) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Sets the `FulfillmentCenterIds` query parameter.
+    pub fn fulfillment_center_ids(mut self, fulfillment_center_ids: &'a [String]) -> Self {
+        self.fulfillment_center_ids = Some(fulfillment_center_ids);
+        self
+    }
+
+    /// Sets the `TrackingNumbers` query parameter.
+    pub fn tracking_numbers(mut self, tracking_numbers: &'a [String]) -> Self {
+        self.tracking_numbers = Some(tracking_numbers);
+        self
+    }
+
+    /// Sets the `OriginalShipmentIds` query parameter.
+    pub fn original_shipment_ids(mut self, original_shipment_ids: &'a [String]) -> Self {
+        self.original_shipment_ids = Some(original_shipment_ids);
+        self
+    }
+
+    /// Sets the `InventoryIds` query parameter.
+    pub fn inventory_ids(mut self, inventory_ids: &'a [String]) -> Self {
+        self.inventory_ids = Some(inventory_ids);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::ReturnOrder>>> {
+        let Self {
+            client,
+            page,
+            limit,
+            sort_order,
+            start_date,
+            end_date,
+            ids,
+            reference_ids,
+            status,
+            fulfillment_center_ids,
+            tracking_numbers,
+            original_shipment_ids,
+            inventory_ids,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(date) = end_date {
+            query_args.push(("EndDate".to_string(), date.to_rfc3339()));
+        }
+        if let Some(v) = fulfillment_center_ids {
+            query_args.push(("FulfillmentCenterIds".to_string(), v.join(" ")));
+        }
+        if let Some(v) = ids {
+            query_args.push(("IDs".to_string(), v.join(" ")));
+        }
+        if let Some(v) = inventory_ids {
+            query_args.push(("InventoryIds".to_string(), v.join(" ")));
+        }
+        if let Some(v) = limit {
+            query_args.push(("Limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = original_shipment_ids {
+            query_args.push(("OriginalShipmentIds".to_string(), v.join(" ")));
+        }
+        if let Some(v) = page {
+            query_args.push(("Page".to_string(), v.to_string()));
+        }
+        if let Some(v) = reference_ids {
+            query_args.push(("ReferenceIds".to_string(), v.join(" ")));
+        }
+        if let Some(v) = sort_order {
+            query_args.push(("SortOrder".to_string(), v.to_string()));
+        }
+        if let Some(date) = start_date {
+            query_args.push(("StartDate".to_string(), date.to_rfc3339()));
+        }
+        if let Some(v) = status {
+            query_args.push(("Status".to_string(), v.join(" ")));
+        }
+        if let Some(v) = tracking_numbers {
+            query_args.push(("TrackingNumbers".to_string(), v.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(&format!("/return?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct Returns {
     pub client: Client,
 }
@@ -162,6 +322,30 @@ impl Returns {
             )
             .await
     }
+    /**
+     * Get Return Orders.
+     *
+     * This function performs a `GET` to the `/return` endpoint.
+     *
+     * As opposed to `get_page`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     */
+    pub fn get_page_request(&self) -> ReturnsGetPageRequest<'_> {
+        ReturnsGetPageRequest {
+            client: self,
+            page: None,
+            limit: None,
+            sort_order: None,
+            start_date: None,
+            end_date: None,
+            ids: None,
+            reference_ids: None,
+            status: None,
+            fulfillment_center_ids: None,
+            tracking_numbers: None,
+            original_shipment_ids: None,
+            inventory_ids: None,
+        }
+    }
     /**
      * Get Return Orders.
      *
@@ -231,6 +415,67 @@ impl Returns {
             )
             .await
     }
+    /**
+     * Get Return Orders.
+     *
+     * This function performs a `GET` to the `/return` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     */
+    pub fn get_all_stream(
+        &self,
+        sort_order: crate::types::SortOrder,
+        start_date: Option<chrono::DateTime<chrono::Utc>>,
+        end_date: Option<chrono::DateTime<chrono::Utc>>,
+        ids: &[String],
+        reference_ids: &[String],
+        status: &[String],
+        fulfillment_center_ids: &[String],
+        tracking_numbers: &[String],
+        original_shipment_ids: &[String],
+        inventory_ids: &[String],
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::ReturnOrder>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(date) = end_date {
+            query_args.push(("EndDate".to_string(), date.to_rfc3339()));
+        }
+        if !fulfillment_center_ids.is_empty() {
+            query_args.push((
+                "FulfillmentCenterIds".to_string(),
+                fulfillment_center_ids.join(" "),
+            ));
+        }
+        if !ids.is_empty() {
+            query_args.push(("IDs".to_string(), ids.join(" ")));
+        }
+        if !inventory_ids.is_empty() {
+            query_args.push(("InventoryIds".to_string(), inventory_ids.join(" ")));
+        }
+        if !original_shipment_ids.is_empty() {
+            query_args.push((
+                "OriginalShipmentIds".to_string(),
+                original_shipment_ids.join(" "),
+            ));
+        }
+        if !reference_ids.is_empty() {
+            query_args.push(("ReferenceIds".to_string(), reference_ids.join(" ")));
+        }
+        if !sort_order.to_string().is_empty() {
+            query_args.push(("SortOrder".to_string(), sort_order.to_string()));
+        }
+        if let Some(date) = start_date {
+            query_args.push(("StartDate".to_string(), date.to_rfc3339()));
+        }
+        if !status.is_empty() {
+            query_args.push(("Status".to_string(), status.join(" ")));
+        }
+        if !tracking_numbers.is_empty() {
+            query_args.push(("TrackingNumbers".to_string(), tracking_numbers.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/return?{}", query_), None);
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Create Return Order.
      *
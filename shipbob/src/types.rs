@@ -40,6 +40,10 @@ pub struct Channel {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub scopes: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Information about an inventory item's dimensions
@@ -81,6 +85,10 @@ pub struct InventoryDimension {
         deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub width: f64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Break down of fulfillable quantity by fulfillment center
@@ -149,6 +157,10 @@ pub struct InventoryFulfillmentCenterQuantity {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub onhand_quantity: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Break down of fulfillable quantity by lot
@@ -226,6 +238,10 @@ pub struct InventoryLotQuantity {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub onhand_quantity: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -254,8 +270,9 @@ pub enum PackagingAttribute {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PackagingAttribute {
@@ -271,7 +288,7 @@ impl std::fmt::Display for PackagingAttribute {
             PackagingAttribute::Poster => "Poster",
             PackagingAttribute::Stackable => "Stackable",
             PackagingAttribute::Noop => "",
-            PackagingAttribute::FallthroughString => "*",
+            PackagingAttribute::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -436,6 +453,10 @@ pub struct Inventory {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_sellable_quantity: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -505,6 +526,10 @@ pub struct OrdersEstimationAddress {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub zip_code: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -536,6 +561,10 @@ pub struct OrdersEstimateProductInfoModel {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub reference_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -559,6 +588,10 @@ pub struct OrdersEstimateFulfillmentRequestModel {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub shipping_methods: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Information about a fulfillment center that a shipment can belong to
@@ -582,6 +615,10 @@ pub struct OrdersFulfillmentCenter {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -609,6 +646,10 @@ pub struct OrdersEstimateDetail {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub shipping_method: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -622,6 +663,10 @@ pub struct OrdersEstimate {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub estimates: Vec<OrdersEstimateDetail>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Created by channel metadata
@@ -645,6 +690,10 @@ pub struct OrdersChannelInfo {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -661,8 +710,9 @@ pub enum Type {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Type {
@@ -671,7 +721,7 @@ impl std::fmt::Display for Type {
             Type::MarkFor => "MarkFor",
             Type::ShipFrom => "ShipFrom",
             Type::Noop => "",
-            Type::FallthroughString => "*",
+            Type::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -758,6 +808,10 @@ pub struct OrdersRetailerProgramDataAddress {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub zip_code: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Information about the recipient of an order
@@ -794,6 +848,10 @@ pub struct OrdersRecipientInfo {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub phone_number: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -834,6 +892,10 @@ pub struct OrdersProductInfo {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub sku: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -856,6 +918,10 @@ pub struct OrdersTag {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub value: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Information about the recipient of a shipment
@@ -893,6 +959,10 @@ pub struct OrdersRecipient {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub phone_number: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -942,6 +1012,10 @@ pub struct OrdersStatusDetail {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Tracking information for a shipment
@@ -983,6 +1057,10 @@ pub struct OrdersTracking {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub tracking_url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Information about inventory belonging to a store product
@@ -1059,6 +1137,10 @@ pub struct OrdersInventory {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub serial_numbers: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Information about a store product belonging to a shipment
@@ -1109,6 +1191,10 @@ pub struct OrdersShipmentProduct {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub sku: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Measurements of a shipment
@@ -1150,6 +1236,10 @@ pub struct OrdersMeasurements {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub width_in: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -1180,8 +1270,9 @@ pub enum Status {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Status {
@@ -1198,7 +1289,7 @@ impl std::fmt::Display for Status {
             Status::Pending => "Pending",
             Status::Processing => "Processing",
             Status::Noop => "",
-            Status::FallthroughString => "*",
+            Status::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1233,13 +1324,12 @@ pub enum PackageMaterialType {
     PosterTube,
     #[serde(rename = "Undefined")]
     Undefined,
-    #[serde(rename = "Unknown")]
-    Unknown,
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PackageMaterialType {
@@ -1254,9 +1344,8 @@ impl std::fmt::Display for PackageMaterialType {
             PackageMaterialType::PolyMailer => "PolyMailer",
             PackageMaterialType::PosterTube => "PosterTube",
             PackageMaterialType::Undefined => "Undefined",
-            PackageMaterialType::Unknown => "Unknown",
             PackageMaterialType::Noop => "",
-            PackageMaterialType::FallthroughString => "*",
+            PackageMaterialType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1290,8 +1379,9 @@ pub enum EstimatedFulfillmentDateStatus {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EstimatedFulfillmentDateStatus {
@@ -1307,7 +1397,7 @@ impl std::fmt::Display for EstimatedFulfillmentDateStatus {
             EstimatedFulfillmentDateStatus::PendingOnTime => "PendingOnTime",
             EstimatedFulfillmentDateStatus::Unavailable => "Unavailable",
             EstimatedFulfillmentDateStatus::Noop => "",
-            EstimatedFulfillmentDateStatus::FallthroughString => "*",
+            EstimatedFulfillmentDateStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1490,6 +1580,10 @@ pub struct OrdersShipment {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub tracking: Option<OrdersTracking>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -1508,8 +1602,9 @@ pub enum CarrierType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for CarrierType {
@@ -1518,7 +1613,7 @@ impl std::fmt::Display for CarrierType {
             CarrierType::Freight => "Freight",
             CarrierType::Parcel => "Parcel",
             CarrierType::Noop => "",
-            CarrierType::FallthroughString => "*",
+            CarrierType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1550,8 +1645,9 @@ pub enum PaymentTerm {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PaymentTerm {
@@ -1561,7 +1657,7 @@ impl std::fmt::Display for PaymentTerm {
             PaymentTerm::Prepaid => "Prepaid",
             PaymentTerm::ThirdParty => "ThirdParty",
             PaymentTerm::Noop => "",
-            PaymentTerm::FallthroughString => "*",
+            PaymentTerm::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1596,6 +1692,10 @@ pub struct OrdersShippingTerms {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub payment_term: Option<PaymentTerm>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Contains properties that needs to be used for fulfilling B2B/Dropship orders.
@@ -1646,6 +1746,10 @@ pub struct OrdersRetailerProgramData {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub retailer_program_type: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -1668,8 +1772,9 @@ pub enum OrderStatus {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OrderStatus {
@@ -1682,7 +1787,7 @@ impl std::fmt::Display for OrderStatus {
             OrderStatus::PartiallyFulfilled => "PartiallyFulfilled",
             OrderStatus::Processing => "Processing",
             OrderStatus::Noop => "",
-            OrderStatus::FallthroughString => "*",
+            OrderStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1708,8 +1813,9 @@ pub enum OrderType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OrderType {
@@ -1719,7 +1825,7 @@ impl std::fmt::Display for OrderType {
             OrderType::Dtc => "DTC",
             OrderType::DropShip => "DropShip",
             OrderType::Noop => "",
-            OrderType::FallthroughString => "*",
+            OrderType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1853,6 +1959,10 @@ pub struct Order {
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
     pub type_: Option<OrderType>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -1875,6 +1985,10 @@ pub struct OrdersAddProductOrderByModel {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub quantity: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -1906,6 +2020,10 @@ pub struct OrdersAddProductOrderByReferenceModel {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub reference_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types:
@@ -2033,6 +2151,10 @@ pub struct OrdersCreateOrderModel {
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
     pub type_: Option<OrderType>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -2042,8 +2164,9 @@ pub enum Action {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Action {
@@ -2051,7 +2174,7 @@ impl std::fmt::Display for Action {
         match self {
             Action::Cancel => "Cancel",
             Action::Noop => "",
-            Action::FallthroughString => "*",
+            Action::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2097,6 +2220,10 @@ pub struct OrdersCanceledShipment {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub shipment_id: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -2113,8 +2240,9 @@ pub enum OrdersCanceledOrderStatus {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OrdersCanceledOrderStatus {
@@ -2124,7 +2252,7 @@ impl std::fmt::Display for OrdersCanceledOrderStatus {
             OrdersCanceledOrderStatus::PartialSuccess => "PartialSuccess",
             OrdersCanceledOrderStatus::Success => "Success",
             OrdersCanceledOrderStatus::Noop => "",
-            OrdersCanceledOrderStatus::FallthroughString => "*",
+            OrdersCanceledOrderStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2167,6 +2295,10 @@ pub struct OrdersCanceledOrder {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub status: Option<OrdersCanceledOrderStatus>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Model for adding a Store Order Json to a ShipBob Order.
@@ -2181,6 +2313,10 @@ pub struct OrdersAddStoreOrderJsonModel {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub order_json: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -2230,6 +2366,10 @@ pub struct OrdersShipmentLog {
         deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub timestamp: Option<chrono::DateTime<chrono::Utc>>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Model for cancel multiple shipments at once
@@ -2244,6 +2384,10 @@ pub struct OrdersCancelShipmentsModel {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub shipment_ids: Vec<i64>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -2258,6 +2402,10 @@ pub struct OrdersCanceledShipments {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub results: Vec<OrdersCanceledShipment>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -2280,6 +2428,10 @@ pub struct OrdersServiceLevelDetail {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -2320,6 +2472,10 @@ pub struct OrdersShipMethodDetail {
     pub name: String,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub service_level: Option<OrdersServiceLevelDetail>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -2333,8 +2489,9 @@ pub enum ProductActiveStatus {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ProductActiveStatus {
@@ -2344,7 +2501,7 @@ impl std::fmt::Display for ProductActiveStatus {
             ProductActiveStatus::Any => "Any",
             ProductActiveStatus::Inactive => "Inactive",
             ProductActiveStatus::Noop => "",
-            ProductActiveStatus::FallthroughString => "*",
+            ProductActiveStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2367,8 +2524,9 @@ pub enum ProductBundleStatus {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ProductBundleStatus {
@@ -2378,7 +2536,7 @@ impl std::fmt::Display for ProductBundleStatus {
             ProductBundleStatus::Bundle => "Bundle",
             ProductBundleStatus::NotBundle => "NotBundle",
             ProductBundleStatus::Noop => "",
-            ProductBundleStatus::FallthroughString => "*",
+            ProductBundleStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2411,6 +2569,10 @@ pub struct ProductsChannel {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The inventory that store products can resolve to when packing a shipment
@@ -2443,6 +2605,10 @@ pub struct ProductsInventoryItem {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub quantity: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Break down of quantities by fulfillment center
@@ -2493,6 +2659,10 @@ pub struct ProductsFulfillmentCenterQuantity {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub onhand_quantity: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -2630,6 +2800,10 @@ pub struct Product {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub upc: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The product to create
@@ -2698,6 +2872,10 @@ pub struct ProductsCreateProductModel {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub upc: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Updates to an existing product product
@@ -2757,6 +2935,10 @@ pub struct ProductsUpdateProductModel {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub upc: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Information about a fulfillment center
@@ -2863,6 +3045,10 @@ pub struct ReceivingFulfillmentCenter {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub zip_code: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -2884,8 +3070,9 @@ pub enum ReceivingStatus {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ReceivingStatus {
@@ -2899,7 +3086,7 @@ impl std::fmt::Display for ReceivingStatus {
             ReceivingStatus::PartiallyArrived => "PartiallyArrived",
             ReceivingStatus::Processing => "Processing",
             ReceivingStatus::Noop => "",
-            ReceivingStatus::FallthroughString => "*",
+            ReceivingStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2922,8 +3109,9 @@ pub enum ReceivingPackageType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ReceivingPackageType {
@@ -2933,7 +3121,7 @@ impl std::fmt::Display for ReceivingPackageType {
             ReceivingPackageType::Package => "Package",
             ReceivingPackageType::Pallet => "Pallet",
             ReceivingPackageType::Noop => "",
-            ReceivingPackageType::FallthroughString => "*",
+            ReceivingPackageType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2956,8 +3144,9 @@ pub enum ReceivingPackingType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ReceivingPackingType {
@@ -2967,7 +3156,7 @@ impl std::fmt::Display for ReceivingPackingType {
             ReceivingPackingType::MultipleSkuPerBox => "MultipleSkuPerBox",
             ReceivingPackingType::OneSkuPerBox => "OneSkuPerBox",
             ReceivingPackingType::Noop => "",
-            ReceivingPackingType::FallthroughString => "*",
+            ReceivingPackingType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2996,8 +3185,9 @@ pub enum ReceivingBoxStatus {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ReceivingBoxStatus {
@@ -3010,7 +3200,7 @@ impl std::fmt::Display for ReceivingBoxStatus {
             ReceivingBoxStatus::Counting => "Counting",
             ReceivingBoxStatus::Stowing => "Stowing",
             ReceivingBoxStatus::Noop => "",
-            ReceivingBoxStatus::FallthroughString => "*",
+            ReceivingBoxStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3070,6 +3260,10 @@ pub struct ReceivingBoxItem {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub received_quantity: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Information about a box shipment included in a receiving order
@@ -3134,6 +3328,10 @@ pub struct ReceivingBox {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub tracking_number: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Information about a receiving order
@@ -3213,10 +3411,19 @@ pub struct ReceivingOrder {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub status: Option<ReceivingStatus>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Extensions {}
+pub struct Extensions {
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct MicrosoftAspNetCoreMvcValidationProblemDetails {
@@ -3274,6 +3481,10 @@ pub struct MicrosoftAspNetCoreMvcValidationProblemDetails {
         rename = "type"
     )]
     pub type_: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Model containing information that assigns a receiving order to a fulfillment center
@@ -3288,6 +3499,10 @@ pub struct ReceivingAssignOrderFulfillmentCenterModel {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub id: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Information about an inventory item contained inside a receiving order box
@@ -3329,6 +3544,10 @@ pub struct ReceivingAddBoxItemModel {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub quantity: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Information about a box shipment to be added to a receiving order
@@ -3352,6 +3571,10 @@ pub struct ReceivingAddBoxOrderModel {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub tracking_number: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Information to create a new receiving order
@@ -3381,6 +3604,10 @@ pub struct ReceivingCreateOrderModel {
      */
     pub fulfillment_center: ReceivingAssignOrderFulfillmentCenterModel,
     pub package_type: ReceivingPackageType,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -3398,8 +3625,9 @@ pub enum ReturnStatus {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ReturnStatus {
@@ -3411,7 +3639,7 @@ impl std::fmt::Display for ReturnStatus {
             ReturnStatus::Completed => "Completed",
             ReturnStatus::Processing => "Processing",
             ReturnStatus::Noop => "",
-            ReturnStatus::FallthroughString => "*",
+            ReturnStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3434,8 +3662,9 @@ pub enum ReturnsTransactionLogSource {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ReturnsTransactionLogSource {
@@ -3445,7 +3674,7 @@ impl std::fmt::Display for ReturnsTransactionLogSource {
             ReturnsTransactionLogSource::ReturnProcessingFee => "ReturnProcessingFee",
             ReturnsTransactionLogSource::ReturnToSenderFee => "ReturnToSenderFee",
             ReturnsTransactionLogSource::Noop => "",
-            ReturnsTransactionLogSource::FallthroughString => "*",
+            ReturnsTransactionLogSource::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3470,6 +3699,10 @@ pub struct ReturnsTransaction {
     pub amount: f64,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub transaction_type: Option<ReturnsTransactionLogSource>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Information about a fulfillment center
@@ -3493,6 +3726,10 @@ pub struct ReturnsFulfillmentCenter {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -3508,8 +3745,9 @@ pub enum ReturnAction {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ReturnAction {
@@ -3520,7 +3758,7 @@ impl std::fmt::Display for ReturnAction {
             ReturnAction::Quarantine => "Quarantine",
             ReturnAction::Restock => "Restock",
             ReturnAction::Noop => "",
-            ReturnAction::FallthroughString => "*",
+            ReturnAction::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3541,8 +3779,9 @@ pub enum ReturnActionSource {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ReturnActionSource {
@@ -3551,7 +3790,7 @@ impl std::fmt::Display for ReturnActionSource {
             ReturnActionSource::InventoryDefault => "InventoryDefault",
             ReturnActionSource::Override => "Override",
             ReturnActionSource::Noop => "",
-            ReturnActionSource::FallthroughString => "*",
+            ReturnActionSource::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3578,6 +3817,10 @@ pub struct ReturnActionRequested {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub instructions: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -3602,6 +3845,10 @@ pub struct ReturnActionTaken {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub quantity_processed: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -3644,6 +3891,10 @@ pub struct ReturnsInventoryItem {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub quantity: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -3655,8 +3906,9 @@ pub enum ReturnType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ReturnType {
@@ -3665,7 +3917,7 @@ impl std::fmt::Display for ReturnType {
             ReturnType::Regular => "Regular",
             ReturnType::ReturnToSender => "ReturnToSender",
             ReturnType::Noop => "",
-            ReturnType::FallthroughString => "*",
+            ReturnType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3765,6 +4017,10 @@ pub struct ReturnOrder {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub transactions: Vec<ReturnsTransaction>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -3789,6 +4045,10 @@ pub struct ReturnInventory {
     pub quantity: i64,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub requested_action: Option<ReturnAction>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -3833,6 +4093,10 @@ pub struct ReturnsCreateReturn {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub tracking_number: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -3847,8 +4111,9 @@ pub enum SortOrder {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SortOrder {
@@ -3857,7 +4122,7 @@ impl std::fmt::Display for SortOrder {
             SortOrder::Newest => "Newest",
             SortOrder::Oldest => "Oldest",
             SortOrder::Noop => "",
-            SortOrder::FallthroughString => "*",
+            SortOrder::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3882,6 +4147,10 @@ pub struct ReturnOrderStatusHistory {
         deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub time_stamp: Option<chrono::DateTime<chrono::Utc>>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -3897,8 +4166,9 @@ pub enum WebhooksTopics {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for WebhooksTopics {
@@ -3909,7 +4179,7 @@ impl std::fmt::Display for WebhooksTopics {
             WebhooksTopics::ShipmentException => "shipment_exception",
             WebhooksTopics::ShipmentOnhold => "shipment_onhold",
             WebhooksTopics::Noop => "",
-            WebhooksTopics::FallthroughString => "*",
+            WebhooksTopics::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3952,6 +4222,10 @@ pub struct Webhook {
     pub subscription_url: String,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub topic: Option<WebhooksTopics>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -3966,6 +4240,10 @@ pub struct WebhooksCreateWebhookSubscriptionModel {
     )]
     pub subscription_url: String,
     pub topic: WebhooksTopics,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -4025,6 +4303,10 @@ pub struct MicrosoftAspNetCoreMvcProblemDetails {
         rename = "type"
     )]
     pub type_: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -4036,8 +4318,9 @@ pub enum IntegrationsLocationServiceTypeEnum {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for IntegrationsLocationServiceTypeEnum {
@@ -4046,7 +4329,7 @@ impl std::fmt::Display for IntegrationsLocationServiceTypeEnum {
             IntegrationsLocationServiceTypeEnum::Receiving => "Receiving",
             IntegrationsLocationServiceTypeEnum::Returns => "Returns",
             IntegrationsLocationServiceTypeEnum::Noop => "",
-            IntegrationsLocationServiceTypeEnum::FallthroughString => "*",
+            IntegrationsLocationServiceTypeEnum::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -4144,6 +4427,10 @@ pub struct IntegrationsLocationAddress {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub zip_code: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -4163,6 +4450,10 @@ pub struct IntegrationsLocationService {
     pub enabled: bool,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub service_type: Option<IntegrationsLocationServiceTypeEnum>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -4250,6 +4541,10 @@ pub struct IntegrationsLocation {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub timezone: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -4262,6 +4557,10 @@ pub struct IntegrationsLocationInternal {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub is_enabled_for_new_user: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types are flattened into one object:
@@ -0,0 +1,85 @@
+//! For parsing the payloads ShipBob sends to the webhooks subscribed to with
+//! `webhooks().post`, by their topic.
+//!
+//! FROM: <https://developer.shipbob.com/webhooks/>
+use crate::types;
+
+/// The header holding the topic of a webhook, like `order_shipped`.
+pub const TOPIC_HEADER: &str = "shipbob-topic";
+
+/// The header holding the id of the subscription a webhook was sent for.
+pub const SUBSCRIPTION_ID_HEADER: &str = "shipbob-subscription-id";
+
+/// Errors returned while parsing a webhook.
+#[derive(Debug, thiserror::Error)]
+pub enum WebhookError {
+    /// A header the webhook needs is missing
+    #[error("missing {0} header")]
+    MissingHeader(&'static str),
+    /// The payload does not match its topic
+    #[error(transparent)]
+    SerdeJsonError(#[from] serde_json::Error),
+}
+
+/// A webhook, by its topic.
+///
+/// Topics without a variant of their own are kept as JSON.
+#[derive(Debug, Clone, PartialEq)]
+pub enum WebhookEvent {
+    /// An order shipped, with its shipments.
+    OrderShipped(Box<types::Order>),
+    /// A shipment was delivered.
+    ShipmentDelivered(Box<types::OrdersShipment>),
+    /// A shipment ran into an exception, like missing inventory.
+    ShipmentException(Box<types::OrdersShipment>),
+    /// A shipment was put on hold, like for an invalid address.
+    ShipmentOnhold(Box<types::OrdersShipment>),
+    Other {
+        topic: String,
+        payload: serde_json::Value,
+    },
+}
+
+impl WebhookEvent {
+    /// Returns the topic of the webhook, as subscribed to.
+    pub fn topic(&self) -> types::WebhooksTopics {
+        match self {
+            WebhookEvent::OrderShipped(_) => types::WebhooksTopics::OrderShipped,
+            WebhookEvent::ShipmentDelivered(_) => types::WebhooksTopics::ShipmentDelivered,
+            WebhookEvent::ShipmentException(_) => types::WebhooksTopics::ShipmentException,
+            WebhookEvent::ShipmentOnhold(_) => types::WebhooksTopics::ShipmentOnhold,
+            WebhookEvent::Other { topic, .. } => types::WebhooksTopics::Unknown(topic.clone()),
+        }
+    }
+}
+
+/// Parses the body of a webhook, from its headers.
+///
+/// Only the `shipbob-topic` header is read, to tell which of the types of
+/// `WebhookEvent` the body has.
+pub fn construct_event<I, K, V>(headers: I, payload: &[u8]) -> Result<WebhookEvent, WebhookError>
+where
+    I: IntoIterator<Item = (K, V)>,
+    K: AsRef<str>,
+    V: AsRef<[u8]>,
+{
+    let [topic] = crate::utils::find_headers(headers, [TOPIC_HEADER]);
+    let topic = topic.ok_or(WebhookError::MissingHeader(TOPIC_HEADER))?;
+
+    parse_event(&topic, payload)
+}
+
+/// Parses the body of a webhook into an event, from the value of its `shipbob-topic`
+/// header.
+pub fn parse_event(topic: &str, payload: &[u8]) -> Result<WebhookEvent, WebhookError> {
+    Ok(match topic.trim() {
+        "order_shipped" => WebhookEvent::OrderShipped(serde_json::from_slice(payload)?),
+        "shipment_delivered" => WebhookEvent::ShipmentDelivered(serde_json::from_slice(payload)?),
+        "shipment_exception" => WebhookEvent::ShipmentException(serde_json::from_slice(payload)?),
+        "shipment_onhold" => WebhookEvent::ShipmentOnhold(serde_json::from_slice(payload)?),
+        topic => WebhookEvent::Other {
+            topic: topic.to_string(),
+            payload: serde_json::from_slice(payload)?,
+        },
+    })
+}
@@ -1,6 +1,67 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Webhooks::get_page`], set with a builder.
+pub struct WebhooksGetPageRequest<'a> {
+    client: &'a Webhooks,
+    topic: Option<crate::types::WebhooksTopics>,
+    page: Option<u64>,
+    limit: Option<i64>,
+}
+
+impl<'a> WebhooksGetPageRequest<'a> {
+    /// Sets the `Topic` query parameter.
+    pub fn topic(mut self, topic: crate::types::WebhooksTopics) -> Self {
+        self.topic = Some(topic);
+        self
+    }
+
+    /// Sets the `Page` query parameter.
+    pub fn page(mut self, page: u64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sets the `Limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Webhook>>> {
+        let Self {
+            client,
+            topic,
+            page,
+            limit,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = limit {
+            query_args.push(("Limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = page {
+            query_args.push(("Page".to_string(), v.to_string()));
+        }
+        if let Some(v) = topic {
+            query_args.push(("Topic".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(&format!("/webhook?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct Webhooks {
     pub client: Client,
 }
@@ -52,6 +113,23 @@ impl Webhooks {
             )
             .await
     }
+    /**
+     * Get Webhooks.
+     *
+     * This function performs a `GET` to the `/webhook` endpoint.
+     *
+     * As opposed to `get_page`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * All parameters are AND filters
+     */
+    pub fn get_page_request(&self) -> WebhooksGetPageRequest<'_> {
+        WebhooksGetPageRequest {
+            client: self,
+            topic: None,
+            page: None,
+            limit: None,
+        }
+    }
     /**
      * Get Webhooks.
      *
@@ -81,6 +159,27 @@ impl Webhooks {
             )
             .await
     }
+    /**
+     * Get Webhooks.
+     *
+     * This function performs a `GET` to the `/webhook` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * All parameters are AND filters
+     */
+    pub fn get_all_stream(
+        &self,
+        topic: crate::types::WebhooksTopics,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Webhook>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !topic.to_string().is_empty() {
+            query_args.push(("Topic".to_string(), topic.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/webhook?{}", query_), None);
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * Create a new webhook subscription.
      *
@@ -1 +1,167 @@
+use std::mem;
 
+use wiremock::{
+    matchers::{method, path, query_param, query_param_is_missing},
+    Mock, MockServer, ResponseTemplate,
+};
+
+use shipbob::{
+    types,
+    webhook_events::{self, WebhookError, WebhookEvent},
+    Client,
+};
+
+async fn mount_channels_page(server: &MockServer, page: &str, channels: &[i64]) {
+    let next = page.parse::<u64>().unwrap() + 1;
+    Mock::given(method("GET"))
+        .and(path("/channel"))
+        .and(query_param("Page", page))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header(
+                    "link",
+                    format!(
+                        "<{}/channel?Page={next}&Limit=2>; rel=\"next\"",
+                        server.uri()
+                    )
+                    .as_str(),
+                )
+                .set_body_json(
+                    channels
+                        .iter()
+                        .map(|id| serde_json::json!({ "id": id }))
+                        .collect::<Vec<_>>(),
+                ),
+        )
+        .mount(server)
+        .await;
+}
+
+#[tokio::test]
+async fn test_get_all_stops_on_empty_page() {
+    let server = MockServer::start().await;
+
+    // ShipBob keeps linking to the next page number past the last one.
+    Mock::given(method("GET"))
+        .and(path("/channel"))
+        .and(query_param_is_missing("Page"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header(
+                    "link",
+                    format!("<{}/channel?Page=2&Limit=2>; rel=\"next\"", server.uri()).as_str(),
+                )
+                .set_body_json(serde_json::json!([{ "id": 1 }, { "id": 2 }])),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+    mount_channels_page(&server, "2", &[3]).await;
+    mount_channels_page(&server, "3", &[]).await;
+
+    let mut client = Client::new("token");
+    client.with_host_override(server.uri());
+
+    let channels = client.channels().get_all().await.unwrap().body;
+    let ids = channels
+        .iter()
+        .map(|channel| channel.id)
+        .collect::<Vec<_>>();
+    assert_eq!(ids, vec![1, 2, 3]);
+    assert_eq!(server.received_requests().await.unwrap().len(), 3);
+
+    mem::drop(server)
+}
+
+#[test]
+fn test_construct_event_dispatches_on_topic() {
+    let payload = include_bytes!("fixtures/order_shipped.json");
+    let event = webhook_events::construct_event(
+        [
+            ("Shipbob-Topic", "order_shipped"),
+            ("Shipbob-Subscription-Id", "4411"),
+        ],
+        payload,
+    )
+    .unwrap();
+    assert_eq!(event.topic(), types::WebhooksTopics::OrderShipped);
+
+    let WebhookEvent::OrderShipped(order) = event else {
+        panic!("expected an order_shipped webhook");
+    };
+    assert_eq!(order.id, 104833461);
+    assert_eq!(order.status, Some(types::OrderStatus::Fulfilled));
+    let tracking = order.shipments[0].tracking.as_ref().unwrap();
+    assert_eq!(tracking.carrier, "USPS");
+    assert_eq!(tracking.tracking_number, "9400111899223100012345");
+
+    assert!(matches!(
+        webhook_events::construct_event(Vec::<(&str, &str)>::new(), payload),
+        Err(WebhookError::MissingHeader(_))
+    ));
+}
+
+#[test]
+fn test_construct_event_shipment_delivered() {
+    let payload = include_bytes!("fixtures/shipment_delivered.json");
+    let event = webhook_events::construct_event(
+        [
+            ("shipbob-topic", "shipment_delivered"),
+            ("shipbob-subscription-id", "4412"),
+        ],
+        payload,
+    )
+    .unwrap();
+    assert_eq!(event.topic(), types::WebhooksTopics::ShipmentDelivered);
+
+    let WebhookEvent::ShipmentDelivered(shipment) = event else {
+        panic!("expected a shipment_delivered webhook");
+    };
+    assert_eq!(shipment.id, 98210733);
+    assert_eq!(shipment.order_id, 104833461);
+    assert_eq!(shipment.status, Some(types::Status::Completed));
+    assert_eq!(
+        shipment.estimated_fulfillment_date_status,
+        Some(types::EstimatedFulfillmentDateStatus::FulfilledOnTime)
+    );
+    assert!(shipment.actual_fulfillment_date.is_some());
+    let tracking = shipment.tracking.as_ref().unwrap();
+    assert_eq!(tracking.carrier, "USPS");
+    assert_eq!(tracking.tracking_number, "9400111899223100012345");
+}
+
+#[test]
+fn test_parse_event_shipments() {
+    let payload = include_bytes!("fixtures/shipment_exception.json");
+    let WebhookEvent::ShipmentException(shipment) =
+        webhook_events::parse_event("shipment_exception", payload).unwrap()
+    else {
+        panic!("expected a shipment_exception webhook");
+    };
+    assert_eq!(shipment.order_id, 104833462);
+    assert_eq!(shipment.status, Some(types::Status::Exception));
+    assert_eq!(shipment.status_details[0].name, "OutOfStock");
+    assert!(shipment.tracking.is_none());
+
+    let payload = include_bytes!("fixtures/shipment_onhold.json");
+    let event = webhook_events::parse_event("shipment_onhold", payload).unwrap();
+    assert_eq!(event.topic(), types::WebhooksTopics::ShipmentOnhold);
+    let WebhookEvent::ShipmentOnhold(shipment) = event else {
+        panic!("expected a shipment_onhold webhook");
+    };
+    assert_eq!(shipment.order_id, 104833463);
+    assert_eq!(shipment.status, Some(types::Status::OnHold));
+    assert_eq!(shipment.status_details[0].name, "InvalidAddress");
+
+    assert!(matches!(
+        webhook_events::parse_event("order_shipped", b"\"shipped\""),
+        Err(WebhookError::SerdeJsonError(_))
+    ));
+
+    let event = webhook_events::parse_event("order_cancelled", b"{\"id\": 1}").unwrap();
+    assert_eq!(
+        event.topic(),
+        types::WebhooksTopics::Unknown("order_cancelled".to_string())
+    );
+    assert!(matches!(event, WebhookEvent::Other { topic, .. } if topic == "order_cancelled"));
+}
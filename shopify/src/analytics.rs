@@ -1,6 +1,1196 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Analytics::deprecated_202001_get_report`], set with a builder.
+pub struct AnalyticsDeprecated202001GetReportRequest<'a> {
+    client: &'a Analytics,
+    ids: Option<&'a str>,
+    limit: Option<&'a str>,
+    since_id: Option<&'a str>,
+    updated_at_min: Option<&'a str>,
+    updated_at_max: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> AnalyticsDeprecated202001GetReportRequest<'a> {
+    /// Sets the `ids` query parameter.
+    pub fn ids(mut self, ids: &'a str) -> Self {
+        self.ids = Some(ids);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.updated_at_min = Some(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.updated_at_max = Some(updated_at_max);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            ids,
+            limit,
+            since_id,
+            updated_at_min,
+            updated_at_max,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = ids {
+            query_args.push(("ids".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_max {
+            query_args.push(("updated_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_min {
+            query_args.push(("updated_at_min".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/admin/api/2020-01/reports.json?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Analytics::deprecated_202001_create_reports`], set with a builder.
+pub struct AnalyticsDeprecated202001CreateReportsRequest<'a> {
+    client: &'a Analytics,
+    name: Option<&'a str>,
+    shopify_ql: Option<&'a str>,
+    body: &'a serde_json::Value,
+}
+
+impl<'a> AnalyticsDeprecated202001CreateReportsRequest<'a> {
+    /// Sets the `name` query parameter.
+    pub fn name(mut self, name: &'a str) -> Self {
+        self.name = Some(name);
+        self
+    }
+
+    /// Sets the `shopify_ql` query parameter.
+    pub fn shopify_ql(mut self, shopify_ql: &'a str) -> Self {
+        self.shopify_ql = Some(shopify_ql);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            name,
+            shopify_ql,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = name {
+            query_args.push(("name".to_string(), v.to_string()));
+        }
+        if let Some(v) = shopify_ql {
+            query_args.push(("shopify_ql".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/admin/api/2020-01/reports.json?{}", query_), None);
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Analytics::deprecated_202001_get_reports_param_report`], set with a builder.
+pub struct AnalyticsDeprecated202001GetReportsParamReportRequest<'a> {
+    client: &'a Analytics,
+    report_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> AnalyticsDeprecated202001GetReportsParamReportRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            report_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-01/reports/{}/json?{}",
+                crate::progenitor_support::encode_path(report_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Analytics::deprecated_202004_get_report`], set with a builder.
+pub struct AnalyticsDeprecated202004GetReportRequest<'a> {
+    client: &'a Analytics,
+    ids: Option<&'a str>,
+    limit: Option<&'a str>,
+    since_id: Option<&'a str>,
+    updated_at_min: Option<&'a str>,
+    updated_at_max: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> AnalyticsDeprecated202004GetReportRequest<'a> {
+    /// Sets the `ids` query parameter.
+    pub fn ids(mut self, ids: &'a str) -> Self {
+        self.ids = Some(ids);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.updated_at_min = Some(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.updated_at_max = Some(updated_at_max);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            ids,
+            limit,
+            since_id,
+            updated_at_min,
+            updated_at_max,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = ids {
+            query_args.push(("ids".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_max {
+            query_args.push(("updated_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_min {
+            query_args.push(("updated_at_min".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/admin/api/2020-04/reports.json?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Analytics::deprecated_202004_create_reports`], set with a builder.
+pub struct AnalyticsDeprecated202004CreateReportsRequest<'a> {
+    client: &'a Analytics,
+    name: Option<&'a str>,
+    shopify_ql: Option<&'a str>,
+    body: &'a serde_json::Value,
+}
+
+impl<'a> AnalyticsDeprecated202004CreateReportsRequest<'a> {
+    /// Sets the `name` query parameter.
+    pub fn name(mut self, name: &'a str) -> Self {
+        self.name = Some(name);
+        self
+    }
+
+    /// Sets the `shopify_ql` query parameter.
+    pub fn shopify_ql(mut self, shopify_ql: &'a str) -> Self {
+        self.shopify_ql = Some(shopify_ql);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            name,
+            shopify_ql,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = name {
+            query_args.push(("name".to_string(), v.to_string()));
+        }
+        if let Some(v) = shopify_ql {
+            query_args.push(("shopify_ql".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/admin/api/2020-04/reports.json?{}", query_), None);
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Analytics::deprecated_202004_get_reports_param_report`], set with a builder.
+pub struct AnalyticsDeprecated202004GetReportsParamReportRequest<'a> {
+    client: &'a Analytics,
+    report_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> AnalyticsDeprecated202004GetReportsParamReportRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            report_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-04/reports/{}/json?{}",
+                crate::progenitor_support::encode_path(report_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Analytics::deprecated_202007_get_report`], set with a builder.
+pub struct AnalyticsDeprecated202007GetReportRequest<'a> {
+    client: &'a Analytics,
+    ids: Option<&'a str>,
+    limit: Option<&'a str>,
+    since_id: Option<&'a str>,
+    updated_at_min: Option<&'a str>,
+    updated_at_max: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> AnalyticsDeprecated202007GetReportRequest<'a> {
+    /// Sets the `ids` query parameter.
+    pub fn ids(mut self, ids: &'a str) -> Self {
+        self.ids = Some(ids);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.updated_at_min = Some(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.updated_at_max = Some(updated_at_max);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            ids,
+            limit,
+            since_id,
+            updated_at_min,
+            updated_at_max,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = ids {
+            query_args.push(("ids".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_max {
+            query_args.push(("updated_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_min {
+            query_args.push(("updated_at_min".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/admin/api/2020-07/reports.json?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Analytics::deprecated_202007_create_reports`], set with a builder.
+pub struct AnalyticsDeprecated202007CreateReportsRequest<'a> {
+    client: &'a Analytics,
+    name: Option<&'a str>,
+    shopify_ql: Option<&'a str>,
+    body: &'a serde_json::Value,
+}
+
+impl<'a> AnalyticsDeprecated202007CreateReportsRequest<'a> {
+    /// Sets the `name` query parameter.
+    pub fn name(mut self, name: &'a str) -> Self {
+        self.name = Some(name);
+        self
+    }
+
+    /// Sets the `shopify_ql` query parameter.
+    pub fn shopify_ql(mut self, shopify_ql: &'a str) -> Self {
+        self.shopify_ql = Some(shopify_ql);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            name,
+            shopify_ql,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = name {
+            query_args.push(("name".to_string(), v.to_string()));
+        }
+        if let Some(v) = shopify_ql {
+            query_args.push(("shopify_ql".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/admin/api/2020-07/reports.json?{}", query_), None);
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Analytics::deprecated_202007_get_reports_param_report`], set with a builder.
+pub struct AnalyticsDeprecated202007GetReportsParamReportRequest<'a> {
+    client: &'a Analytics,
+    report_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> AnalyticsDeprecated202007GetReportsParamReportRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            report_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-07/reports/{}/json?{}",
+                crate::progenitor_support::encode_path(report_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Analytics::get_report`], set with a builder.
+pub struct AnalyticsGetReportRequest<'a> {
+    client: &'a Analytics,
+    ids: Option<&'a str>,
+    limit: Option<&'a str>,
+    since_id: Option<&'a str>,
+    updated_at_min: Option<&'a str>,
+    updated_at_max: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> AnalyticsGetReportRequest<'a> {
+    /// Sets the `ids` query parameter.
+    pub fn ids(mut self, ids: &'a str) -> Self {
+        self.ids = Some(ids);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.updated_at_min = Some(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.updated_at_max = Some(updated_at_max);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            ids,
+            limit,
+            since_id,
+            updated_at_min,
+            updated_at_max,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = ids {
+            query_args.push(("ids".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_max {
+            query_args.push(("updated_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_min {
+            query_args.push(("updated_at_min".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/admin/api/2020-10/reports.json?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Analytics::create_reports`], set with a builder.
+pub struct AnalyticsCreateReportsRequest<'a> {
+    client: &'a Analytics,
+    name: Option<&'a str>,
+    shopify_ql: Option<&'a str>,
+    body: &'a serde_json::Value,
+}
+
+impl<'a> AnalyticsCreateReportsRequest<'a> {
+    /// Sets the `name` query parameter.
+    pub fn name(mut self, name: &'a str) -> Self {
+        self.name = Some(name);
+        self
+    }
+
+    /// Sets the `shopify_ql` query parameter.
+    pub fn shopify_ql(mut self, shopify_ql: &'a str) -> Self {
+        self.shopify_ql = Some(shopify_ql);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            name,
+            shopify_ql,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = name {
+            query_args.push(("name".to_string(), v.to_string()));
+        }
+        if let Some(v) = shopify_ql {
+            query_args.push(("shopify_ql".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/admin/api/2020-10/reports.json?{}", query_), None);
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Analytics::get_reports_param_report`], set with a builder.
+pub struct AnalyticsGetReportsParamReportRequest<'a> {
+    client: &'a Analytics,
+    report_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> AnalyticsGetReportsParamReportRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            report_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-10/reports/{}/json?{}",
+                crate::progenitor_support::encode_path(report_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Analytics::deprecated_202101_get_report`], set with a builder.
+pub struct AnalyticsDeprecated202101GetReportRequest<'a> {
+    client: &'a Analytics,
+    ids: Option<&'a str>,
+    limit: Option<&'a str>,
+    since_id: Option<&'a str>,
+    updated_at_min: Option<&'a str>,
+    updated_at_max: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> AnalyticsDeprecated202101GetReportRequest<'a> {
+    /// Sets the `ids` query parameter.
+    pub fn ids(mut self, ids: &'a str) -> Self {
+        self.ids = Some(ids);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.updated_at_min = Some(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.updated_at_max = Some(updated_at_max);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            ids,
+            limit,
+            since_id,
+            updated_at_min,
+            updated_at_max,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = ids {
+            query_args.push(("ids".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_max {
+            query_args.push(("updated_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_min {
+            query_args.push(("updated_at_min".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/admin/api/2021-01/reports.json?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Analytics::deprecated_202101_create_reports`], set with a builder.
+pub struct AnalyticsDeprecated202101CreateReportsRequest<'a> {
+    client: &'a Analytics,
+    name: Option<&'a str>,
+    shopify_ql: Option<&'a str>,
+    body: &'a serde_json::Value,
+}
+
+impl<'a> AnalyticsDeprecated202101CreateReportsRequest<'a> {
+    /// Sets the `name` query parameter.
+    pub fn name(mut self, name: &'a str) -> Self {
+        self.name = Some(name);
+        self
+    }
+
+    /// Sets the `shopify_ql` query parameter.
+    pub fn shopify_ql(mut self, shopify_ql: &'a str) -> Self {
+        self.shopify_ql = Some(shopify_ql);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            name,
+            shopify_ql,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = name {
+            query_args.push(("name".to_string(), v.to_string()));
+        }
+        if let Some(v) = shopify_ql {
+            query_args.push(("shopify_ql".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/admin/api/2021-01/reports.json?{}", query_), None);
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Analytics::deprecated_202101_get_reports_param_report`], set with a builder.
+pub struct AnalyticsDeprecated202101GetReportsParamReportRequest<'a> {
+    client: &'a Analytics,
+    report_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> AnalyticsDeprecated202101GetReportsParamReportRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            report_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2021-01/reports/{}/json?{}",
+                crate::progenitor_support::encode_path(report_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Analytics::deprecated_unstable_get_report`], set with a builder.
+pub struct AnalyticsDeprecatedUnstableGetReportRequest<'a> {
+    client: &'a Analytics,
+    ids: Option<&'a str>,
+    limit: Option<&'a str>,
+    since_id: Option<&'a str>,
+    updated_at_min: Option<&'a str>,
+    updated_at_max: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> AnalyticsDeprecatedUnstableGetReportRequest<'a> {
+    /// Sets the `ids` query parameter.
+    pub fn ids(mut self, ids: &'a str) -> Self {
+        self.ids = Some(ids);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.updated_at_min = Some(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.updated_at_max = Some(updated_at_max);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            ids,
+            limit,
+            since_id,
+            updated_at_min,
+            updated_at_max,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = ids {
+            query_args.push(("ids".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_max {
+            query_args.push(("updated_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_min {
+            query_args.push(("updated_at_min".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/unstable/reports.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Analytics::deprecated_unstable_create_reports`], set with a builder.
+pub struct AnalyticsDeprecatedUnstableCreateReportsRequest<'a> {
+    client: &'a Analytics,
+    name: Option<&'a str>,
+    shopify_ql: Option<&'a str>,
+    body: &'a serde_json::Value,
+}
+
+impl<'a> AnalyticsDeprecatedUnstableCreateReportsRequest<'a> {
+    /// Sets the `name` query parameter.
+    pub fn name(mut self, name: &'a str) -> Self {
+        self.name = Some(name);
+        self
+    }
+
+    /// Sets the `shopify_ql` query parameter.
+    pub fn shopify_ql(mut self, shopify_ql: &'a str) -> Self {
+        self.shopify_ql = Some(shopify_ql);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            name,
+            shopify_ql,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = name {
+            query_args.push(("name".to_string(), v.to_string()));
+        }
+        if let Some(v) = shopify_ql {
+            query_args.push(("shopify_ql".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/unstable/reports.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Analytics::deprecated_unstable_get_reports_param_report`], set with a builder.
+pub struct AnalyticsDeprecatedUnstableGetReportsParamReportRequest<'a> {
+    client: &'a Analytics,
+    report_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> AnalyticsDeprecatedUnstableGetReportsParamReportRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            report_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/unstable/reports/{}/json?{}",
+                crate::progenitor_support::encode_path(report_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct Analytics {
     pub client: Client,
 }
@@ -70,6 +1260,28 @@ impl Analytics {
             )
             .await
     }
+    /**
+     * Retrieves a list of reports. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-01/reports.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_get_report`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/analytics/report#index-2020-01
+     */
+    pub fn deprecated_202001_get_report_request(
+        &self,
+    ) -> AnalyticsDeprecated202001GetReportRequest<'_> {
+        AnalyticsDeprecated202001GetReportRequest {
+            client: self,
+            ids: None,
+            limit: None,
+            since_id: None,
+            updated_at_min: None,
+            updated_at_max: None,
+            fields: None,
+        }
+    }
     /**
      * Creates a new report.
      *
@@ -109,6 +1321,26 @@ impl Analytics {
             )
             .await
     }
+    /**
+     * Creates a new report.
+     *
+     * This function performs a `POST` to the `/admin/api/2020-01/reports.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_create_reports`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/analytics/report#create-2020-01
+     */
+    pub fn deprecated_202001_create_reports_request<'a>(
+        &'a self,
+        body: &'a serde_json::Value,
+    ) -> AnalyticsDeprecated202001CreateReportsRequest<'a> {
+        AnalyticsDeprecated202001CreateReportsRequest {
+            client: self,
+            name: None,
+            shopify_ql: None,
+            body,
+        }
+    }
     /**
      * Retrieves a single report created by your app.
      *
@@ -149,6 +1381,25 @@ impl Analytics {
             )
             .await
     }
+    /**
+     * Retrieves a single report created by your app.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-01/reports/{report_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_get_reports_param_report`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/analytics/report#show-2020-01
+     */
+    pub fn deprecated_202001_get_reports_param_report_request<'a>(
+        &'a self,
+        report_id: &'a str,
+    ) -> AnalyticsDeprecated202001GetReportsParamReportRequest<'a> {
+        AnalyticsDeprecated202001GetReportsParamReportRequest {
+            client: self,
+            report_id,
+            fields: None,
+        }
+    }
     /**
      * Updates a report.
      *
@@ -273,6 +1524,28 @@ impl Analytics {
             )
             .await
     }
+    /**
+     * Retrieves a list of reports. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-04/reports.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_get_report`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/analytics/report#index-2020-04
+     */
+    pub fn deprecated_202004_get_report_request(
+        &self,
+    ) -> AnalyticsDeprecated202004GetReportRequest<'_> {
+        AnalyticsDeprecated202004GetReportRequest {
+            client: self,
+            ids: None,
+            limit: None,
+            since_id: None,
+            updated_at_min: None,
+            updated_at_max: None,
+            fields: None,
+        }
+    }
     /**
      * Creates a new report.
      *
@@ -312,6 +1585,26 @@ impl Analytics {
             )
             .await
     }
+    /**
+     * Creates a new report.
+     *
+     * This function performs a `POST` to the `/admin/api/2020-04/reports.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_create_reports`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/analytics/report#create-2020-04
+     */
+    pub fn deprecated_202004_create_reports_request<'a>(
+        &'a self,
+        body: &'a serde_json::Value,
+    ) -> AnalyticsDeprecated202004CreateReportsRequest<'a> {
+        AnalyticsDeprecated202004CreateReportsRequest {
+            client: self,
+            name: None,
+            shopify_ql: None,
+            body,
+        }
+    }
     /**
      * Retrieves a single report created by your app.
      *
@@ -352,6 +1645,25 @@ impl Analytics {
             )
             .await
     }
+    /**
+     * Retrieves a single report created by your app.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-04/reports/{report_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_get_reports_param_report`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/analytics/report#show-2020-04
+     */
+    pub fn deprecated_202004_get_reports_param_report_request<'a>(
+        &'a self,
+        report_id: &'a str,
+    ) -> AnalyticsDeprecated202004GetReportsParamReportRequest<'a> {
+        AnalyticsDeprecated202004GetReportsParamReportRequest {
+            client: self,
+            report_id,
+            fields: None,
+        }
+    }
     /**
      * Updates a report.
      *
@@ -476,6 +1788,28 @@ impl Analytics {
             )
             .await
     }
+    /**
+     * Retrieves a list of reports. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-07/reports.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_get_report`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/analytics/report#index-2020-07
+     */
+    pub fn deprecated_202007_get_report_request(
+        &self,
+    ) -> AnalyticsDeprecated202007GetReportRequest<'_> {
+        AnalyticsDeprecated202007GetReportRequest {
+            client: self,
+            ids: None,
+            limit: None,
+            since_id: None,
+            updated_at_min: None,
+            updated_at_max: None,
+            fields: None,
+        }
+    }
     /**
      * Creates a new report.
      *
@@ -515,6 +1849,26 @@ impl Analytics {
             )
             .await
     }
+    /**
+     * Creates a new report.
+     *
+     * This function performs a `POST` to the `/admin/api/2020-07/reports.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_create_reports`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/analytics/report#create-2020-07
+     */
+    pub fn deprecated_202007_create_reports_request<'a>(
+        &'a self,
+        body: &'a serde_json::Value,
+    ) -> AnalyticsDeprecated202007CreateReportsRequest<'a> {
+        AnalyticsDeprecated202007CreateReportsRequest {
+            client: self,
+            name: None,
+            shopify_ql: None,
+            body,
+        }
+    }
     /**
      * Retrieves a single report created by your app.
      *
@@ -555,6 +1909,25 @@ impl Analytics {
             )
             .await
     }
+    /**
+     * Retrieves a single report created by your app.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-07/reports/{report_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_get_reports_param_report`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/analytics/report#show-2020-07
+     */
+    pub fn deprecated_202007_get_reports_param_report_request<'a>(
+        &'a self,
+        report_id: &'a str,
+    ) -> AnalyticsDeprecated202007GetReportsParamReportRequest<'a> {
+        AnalyticsDeprecated202007GetReportsParamReportRequest {
+            client: self,
+            report_id,
+            fields: None,
+        }
+    }
     /**
      * Updates a report.
      *
@@ -679,6 +2052,26 @@ impl Analytics {
             )
             .await
     }
+    /**
+     * Retrieves a list of reports. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-10/reports.json` endpoint.
+     *
+     * As opposed to `get_report`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/analytics/report#index-2020-10
+     */
+    pub fn get_report_request(&self) -> AnalyticsGetReportRequest<'_> {
+        AnalyticsGetReportRequest {
+            client: self,
+            ids: None,
+            limit: None,
+            since_id: None,
+            updated_at_min: None,
+            updated_at_max: None,
+            fields: None,
+        }
+    }
     /**
      * Creates a new report.
      *
@@ -718,6 +2111,26 @@ impl Analytics {
             )
             .await
     }
+    /**
+     * Creates a new report.
+     *
+     * This function performs a `POST` to the `/admin/api/2020-10/reports.json` endpoint.
+     *
+     * As opposed to `create_reports`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/analytics/report#create-2020-10
+     */
+    pub fn create_reports_request<'a>(
+        &'a self,
+        body: &'a serde_json::Value,
+    ) -> AnalyticsCreateReportsRequest<'a> {
+        AnalyticsCreateReportsRequest {
+            client: self,
+            name: None,
+            shopify_ql: None,
+            body,
+        }
+    }
     /**
      * Retrieves a single report created by your app.
      *
@@ -758,6 +2171,25 @@ impl Analytics {
             )
             .await
     }
+    /**
+     * Retrieves a single report created by your app.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-10/reports/{report_id}.json` endpoint.
+     *
+     * As opposed to `get_reports_param_report`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/analytics/report#show-2020-10
+     */
+    pub fn get_reports_param_report_request<'a>(
+        &'a self,
+        report_id: &'a str,
+    ) -> AnalyticsGetReportsParamReportRequest<'a> {
+        AnalyticsGetReportsParamReportRequest {
+            client: self,
+            report_id,
+            fields: None,
+        }
+    }
     /**
      * Updates a report.
      *
@@ -882,6 +2314,28 @@ impl Analytics {
             )
             .await
     }
+    /**
+     * Retrieves a list of reports. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2021-01/reports.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_get_report`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/analytics/report#index-2021-01
+     */
+    pub fn deprecated_202101_get_report_request(
+        &self,
+    ) -> AnalyticsDeprecated202101GetReportRequest<'_> {
+        AnalyticsDeprecated202101GetReportRequest {
+            client: self,
+            ids: None,
+            limit: None,
+            since_id: None,
+            updated_at_min: None,
+            updated_at_max: None,
+            fields: None,
+        }
+    }
     /**
      * Creates a new report.
      *
@@ -921,6 +2375,26 @@ impl Analytics {
             )
             .await
     }
+    /**
+     * Creates a new report.
+     *
+     * This function performs a `POST` to the `/admin/api/2021-01/reports.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_create_reports`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/analytics/report#create-2021-01
+     */
+    pub fn deprecated_202101_create_reports_request<'a>(
+        &'a self,
+        body: &'a serde_json::Value,
+    ) -> AnalyticsDeprecated202101CreateReportsRequest<'a> {
+        AnalyticsDeprecated202101CreateReportsRequest {
+            client: self,
+            name: None,
+            shopify_ql: None,
+            body,
+        }
+    }
     /**
      * Retrieves a single report created by your app.
      *
@@ -961,6 +2435,25 @@ impl Analytics {
             )
             .await
     }
+    /**
+     * Retrieves a single report created by your app.
+     *
+     * This function performs a `GET` to the `/admin/api/2021-01/reports/{report_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_get_reports_param_report`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/analytics/report#show-2021-01
+     */
+    pub fn deprecated_202101_get_reports_param_report_request<'a>(
+        &'a self,
+        report_id: &'a str,
+    ) -> AnalyticsDeprecated202101GetReportsParamReportRequest<'a> {
+        AnalyticsDeprecated202101GetReportsParamReportRequest {
+            client: self,
+            report_id,
+            fields: None,
+        }
+    }
     /**
      * Updates a report.
      *
@@ -1086,6 +2579,28 @@ impl Analytics {
             )
             .await
     }
+    /**
+     * Retrieves a list of reports. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/unstable/reports.json` endpoint.
+     *
+     * As opposed to `deprecated_unstable_get_report`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/analytics/report#index-unstable
+     */
+    pub fn deprecated_unstable_get_report_request(
+        &self,
+    ) -> AnalyticsDeprecatedUnstableGetReportRequest<'_> {
+        AnalyticsDeprecatedUnstableGetReportRequest {
+            client: self,
+            ids: None,
+            limit: None,
+            since_id: None,
+            updated_at_min: None,
+            updated_at_max: None,
+            fields: None,
+        }
+    }
     /**
      * Creates a new report.
      *
@@ -1126,6 +2641,26 @@ impl Analytics {
             )
             .await
     }
+    /**
+     * Creates a new report.
+     *
+     * This function performs a `POST` to the `/admin/api/unstable/reports.json` endpoint.
+     *
+     * As opposed to `deprecated_unstable_create_reports`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/analytics/report#create-unstable
+     */
+    pub fn deprecated_unstable_create_reports_request<'a>(
+        &'a self,
+        body: &'a serde_json::Value,
+    ) -> AnalyticsDeprecatedUnstableCreateReportsRequest<'a> {
+        AnalyticsDeprecatedUnstableCreateReportsRequest {
+            client: self,
+            name: None,
+            shopify_ql: None,
+            body,
+        }
+    }
     /**
      * Retrieves a single report created by your app.
      *
@@ -1166,6 +2701,25 @@ impl Analytics {
             )
             .await
     }
+    /**
+     * Retrieves a single report created by your app.
+     *
+     * This function performs a `GET` to the `/admin/api/unstable/reports/{report_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_unstable_get_reports_param_report`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/analytics/report#show-unstable
+     */
+    pub fn deprecated_unstable_get_reports_param_report_request<'a>(
+        &'a self,
+        report_id: &'a str,
+    ) -> AnalyticsDeprecatedUnstableGetReportsParamReportRequest<'a> {
+        AnalyticsDeprecatedUnstableGetReportsParamReportRequest {
+            client: self,
+            report_id,
+            fields: None,
+        }
+    }
     /**
      * Updates a report.
      *
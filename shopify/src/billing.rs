@@ -1,6 +1,2684 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Billing::deprecated_202001_get_application_charge`], set with a builder.
+pub struct BillingDeprecated202001GetApplicationChargeRequest<'a> {
+    client: &'a Billing,
+    since_id: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> BillingDeprecated202001GetApplicationChargeRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            since_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-01/application_charges.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Billing::deprecated_202001_get_application_charges_param_charge`], set with a builder.
+pub struct BillingDeprecated202001GetApplicationChargesParamChargeRequest<'a> {
+    client: &'a Billing,
+    application_charge_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> BillingDeprecated202001GetApplicationChargesParamChargeRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            application_charge_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-01/application_charges/{}/json?{}",
+                crate::progenitor_support::encode_path(application_charge_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Billing::deprecated_202004_get_application_charge`], set with a builder.
+pub struct BillingDeprecated202004GetApplicationChargeRequest<'a> {
+    client: &'a Billing,
+    since_id: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> BillingDeprecated202004GetApplicationChargeRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            since_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-04/application_charges.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Billing::deprecated_202004_get_application_charges_param_charge`], set with a builder.
+pub struct BillingDeprecated202004GetApplicationChargesParamChargeRequest<'a> {
+    client: &'a Billing,
+    application_charge_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> BillingDeprecated202004GetApplicationChargesParamChargeRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            application_charge_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-04/application_charges/{}/json?{}",
+                crate::progenitor_support::encode_path(application_charge_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Billing::deprecated_202007_get_application_charge`], set with a builder.
+pub struct BillingDeprecated202007GetApplicationChargeRequest<'a> {
+    client: &'a Billing,
+    since_id: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> BillingDeprecated202007GetApplicationChargeRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            since_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-07/application_charges.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Billing::deprecated_202007_get_application_charges_param_charge`], set with a builder.
+pub struct BillingDeprecated202007GetApplicationChargesParamChargeRequest<'a> {
+    client: &'a Billing,
+    application_charge_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> BillingDeprecated202007GetApplicationChargesParamChargeRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            application_charge_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-07/application_charges/{}/json?{}",
+                crate::progenitor_support::encode_path(application_charge_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Billing::get_application_charge`], set with a builder.
+pub struct BillingGetApplicationChargeRequest<'a> {
+    client: &'a Billing,
+    since_id: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> BillingGetApplicationChargeRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            since_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-10/application_charges.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Billing::get_application_charges_param_charge`], set with a builder.
+pub struct BillingGetApplicationChargesParamChargeRequest<'a> {
+    client: &'a Billing,
+    application_charge_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> BillingGetApplicationChargesParamChargeRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            application_charge_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-10/application_charges/{}/json?{}",
+                crate::progenitor_support::encode_path(application_charge_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Billing::deprecated_202101_get_application_charge`], set with a builder.
+pub struct BillingDeprecated202101GetApplicationChargeRequest<'a> {
+    client: &'a Billing,
+    since_id: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> BillingDeprecated202101GetApplicationChargeRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            since_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2021-01/application_charges.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Billing::deprecated_202101_get_application_charges_param_charge`], set with a builder.
+pub struct BillingDeprecated202101GetApplicationChargesParamChargeRequest<'a> {
+    client: &'a Billing,
+    application_charge_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> BillingDeprecated202101GetApplicationChargesParamChargeRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            application_charge_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2021-01/application_charges/{}/json?{}",
+                crate::progenitor_support::encode_path(application_charge_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Billing::deprecated_unstable_get_application_charge`], set with a builder.
+pub struct BillingDeprecatedUnstableGetApplicationChargeRequest<'a> {
+    client: &'a Billing,
+    since_id: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> BillingDeprecatedUnstableGetApplicationChargeRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            since_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/unstable/application_charges.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Billing::deprecated_unstable_get_application_charges_param_charge`], set with a builder.
+pub struct BillingDeprecatedUnstableGetApplicationChargesParamChargeRequest<'a> {
+    client: &'a Billing,
+    application_charge_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> BillingDeprecatedUnstableGetApplicationChargesParamChargeRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            application_charge_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/unstable/application_charges/{}/json?{}",
+                crate::progenitor_support::encode_path(application_charge_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Billing::deprecated_202001_get_application_credit`], set with a builder.
+pub struct BillingDeprecated202001GetApplicationCreditRequest<'a> {
+    client: &'a Billing,
+    fields: Option<&'a str>,
+}
+
+impl<'a> BillingDeprecated202001GetApplicationCreditRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self { client, fields } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-01/application_credits.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Billing::deprecated_202001_get_application_credits_param_credit`], set with a builder.
+pub struct BillingDeprecated202001GetApplicationCreditsParamCreditRequest<'a> {
+    client: &'a Billing,
+    application_credit_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> BillingDeprecated202001GetApplicationCreditsParamCreditRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            application_credit_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-01/application_credits/{}/json?{}",
+                crate::progenitor_support::encode_path(application_credit_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Billing::deprecated_202004_get_application_credit`], set with a builder.
+pub struct BillingDeprecated202004GetApplicationCreditRequest<'a> {
+    client: &'a Billing,
+    fields: Option<&'a str>,
+}
+
+impl<'a> BillingDeprecated202004GetApplicationCreditRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self { client, fields } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-04/application_credits.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Billing::deprecated_202004_get_application_credits_param_credit`], set with a builder.
+pub struct BillingDeprecated202004GetApplicationCreditsParamCreditRequest<'a> {
+    client: &'a Billing,
+    application_credit_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> BillingDeprecated202004GetApplicationCreditsParamCreditRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            application_credit_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-04/application_credits/{}/json?{}",
+                crate::progenitor_support::encode_path(application_credit_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Billing::deprecated_202007_get_application_credit`], set with a builder.
+pub struct BillingDeprecated202007GetApplicationCreditRequest<'a> {
+    client: &'a Billing,
+    fields: Option<&'a str>,
+}
+
+impl<'a> BillingDeprecated202007GetApplicationCreditRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self { client, fields } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-07/application_credits.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Billing::deprecated_202007_get_application_credits_param_credit`], set with a builder.
+pub struct BillingDeprecated202007GetApplicationCreditsParamCreditRequest<'a> {
+    client: &'a Billing,
+    application_credit_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> BillingDeprecated202007GetApplicationCreditsParamCreditRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            application_credit_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-07/application_credits/{}/json?{}",
+                crate::progenitor_support::encode_path(application_credit_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Billing::get_application_credit`], set with a builder.
+pub struct BillingGetApplicationCreditRequest<'a> {
+    client: &'a Billing,
+    fields: Option<&'a str>,
+}
+
+impl<'a> BillingGetApplicationCreditRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self { client, fields } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-10/application_credits.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Billing::get_application_credits_param_credit`], set with a builder.
+pub struct BillingGetApplicationCreditsParamCreditRequest<'a> {
+    client: &'a Billing,
+    application_credit_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> BillingGetApplicationCreditsParamCreditRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            application_credit_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-10/application_credits/{}/json?{}",
+                crate::progenitor_support::encode_path(application_credit_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Billing::deprecated_202101_get_application_credit`], set with a builder.
+pub struct BillingDeprecated202101GetApplicationCreditRequest<'a> {
+    client: &'a Billing,
+    fields: Option<&'a str>,
+}
+
+impl<'a> BillingDeprecated202101GetApplicationCreditRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self { client, fields } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2021-01/application_credits.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Billing::deprecated_202101_get_application_credits_param_credit`], set with a builder.
+pub struct BillingDeprecated202101GetApplicationCreditsParamCreditRequest<'a> {
+    client: &'a Billing,
+    application_credit_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> BillingDeprecated202101GetApplicationCreditsParamCreditRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            application_credit_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2021-01/application_credits/{}/json?{}",
+                crate::progenitor_support::encode_path(application_credit_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Billing::deprecated_unstable_get_application_credit`], set with a builder.
+pub struct BillingDeprecatedUnstableGetApplicationCreditRequest<'a> {
+    client: &'a Billing,
+    fields: Option<&'a str>,
+}
+
+impl<'a> BillingDeprecatedUnstableGetApplicationCreditRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self { client, fields } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/unstable/application_credits.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Billing::deprecated_unstable_get_application_credits_param_credit`], set with a builder.
+pub struct BillingDeprecatedUnstableGetApplicationCreditsParamCreditRequest<'a> {
+    client: &'a Billing,
+    application_credit_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> BillingDeprecatedUnstableGetApplicationCreditsParamCreditRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            application_credit_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/unstable/application_credits/{}/json?{}",
+                crate::progenitor_support::encode_path(application_credit_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Billing::deprecated_202001_get_recurring_application_charge`], set with a builder.
+pub struct BillingDeprecated202001GetRecurringApplicationChargeRequest<'a> {
+    client: &'a Billing,
+    since_id: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> BillingDeprecated202001GetRecurringApplicationChargeRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            since_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-01/recurring_application_charges.json?{}",
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Billing::deprecated_202001_get_recurring_application_charges_param_charge`], set with a builder.
+pub struct BillingDeprecated202001GetRecurringApplicationChargesParamChargeRequest<'a> {
+    client: &'a Billing,
+    recurring_application_charge_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> BillingDeprecated202001GetRecurringApplicationChargesParamChargeRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            recurring_application_charge_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-01/recurring_application_charges/{}/json?{}",
+                crate::progenitor_support::encode_path(recurring_application_charge_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Billing::deprecated_202001_update_recurring_application_charges_param_charge_customize`], set with a builder.
+pub struct BillingDeprecated202001UpdateRecurringApplicationChargesParamChargeCustomizeRequest<'a> {
+    client: &'a Billing,
+    recurring_application_charge_id: &'a str,
+    recurring_application_charge_capped_amount: Option<i64>,
+}
+
+impl<'a> BillingDeprecated202001UpdateRecurringApplicationChargesParamChargeCustomizeRequest<'a> {
+    /// Sets the `recurring_application_charge[capped_amount]` query parameter.
+    pub fn recurring_application_charge_capped_amount(
+        mut self,
+        recurring_application_charge_capped_amount: i64,
+    ) -> Self {
+        self.recurring_application_charge_capped_amount =
+            Some(recurring_application_charge_capped_amount);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            recurring_application_charge_id,
+            recurring_application_charge_capped_amount,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = recurring_application_charge_capped_amount {
+            query_args.push((
+                "recurring_application_charge[capped_amount]".to_string(),
+                v.to_string(),
+            ));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-01/recurring_application_charges/{}/customize.json?{}",
+                crate::progenitor_support::encode_path(recurring_application_charge_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .put(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Billing::deprecated_202004_get_recurring_application_charge`], set with a builder.
+pub struct BillingDeprecated202004GetRecurringApplicationChargeRequest<'a> {
+    client: &'a Billing,
+    since_id: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> BillingDeprecated202004GetRecurringApplicationChargeRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            since_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-04/recurring_application_charges.json?{}",
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Billing::deprecated_202004_get_recurring_application_charges_param_charge`], set with a builder.
+pub struct BillingDeprecated202004GetRecurringApplicationChargesParamChargeRequest<'a> {
+    client: &'a Billing,
+    recurring_application_charge_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> BillingDeprecated202004GetRecurringApplicationChargesParamChargeRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            recurring_application_charge_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-04/recurring_application_charges/{}/json?{}",
+                crate::progenitor_support::encode_path(recurring_application_charge_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Billing::deprecated_202004_update_recurring_application_charges_param_charge_customize`], set with a builder.
+pub struct BillingDeprecated202004UpdateRecurringApplicationChargesParamChargeCustomizeRequest<'a> {
+    client: &'a Billing,
+    recurring_application_charge_id: &'a str,
+    recurring_application_charge_capped_amount: Option<i64>,
+}
+
+impl<'a> BillingDeprecated202004UpdateRecurringApplicationChargesParamChargeCustomizeRequest<'a> {
+    /// Sets the `recurring_application_charge[capped_amount]` query parameter.
+    pub fn recurring_application_charge_capped_amount(
+        mut self,
+        recurring_application_charge_capped_amount: i64,
+    ) -> Self {
+        self.recurring_application_charge_capped_amount =
+            Some(recurring_application_charge_capped_amount);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            recurring_application_charge_id,
+            recurring_application_charge_capped_amount,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = recurring_application_charge_capped_amount {
+            query_args.push((
+                "recurring_application_charge[capped_amount]".to_string(),
+                v.to_string(),
+            ));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-04/recurring_application_charges/{}/customize.json?{}",
+                crate::progenitor_support::encode_path(recurring_application_charge_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .put(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Billing::deprecated_202007_get_recurring_application_charge`], set with a builder.
+pub struct BillingDeprecated202007GetRecurringApplicationChargeRequest<'a> {
+    client: &'a Billing,
+    since_id: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> BillingDeprecated202007GetRecurringApplicationChargeRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            since_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-07/recurring_application_charges.json?{}",
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Billing::deprecated_202007_get_recurring_application_charges_param_charge`], set with a builder.
+pub struct BillingDeprecated202007GetRecurringApplicationChargesParamChargeRequest<'a> {
+    client: &'a Billing,
+    recurring_application_charge_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> BillingDeprecated202007GetRecurringApplicationChargesParamChargeRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            recurring_application_charge_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-07/recurring_application_charges/{}/json?{}",
+                crate::progenitor_support::encode_path(recurring_application_charge_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Billing::deprecated_202007_update_recurring_application_charges_param_charge_customize`], set with a builder.
+pub struct BillingDeprecated202007UpdateRecurringApplicationChargesParamChargeCustomizeRequest<'a> {
+    client: &'a Billing,
+    recurring_application_charge_id: &'a str,
+    recurring_application_charge_capped_amount: Option<i64>,
+}
+
+impl<'a> BillingDeprecated202007UpdateRecurringApplicationChargesParamChargeCustomizeRequest<'a> {
+    /// Sets the `recurring_application_charge[capped_amount]` query parameter.
+    pub fn recurring_application_charge_capped_amount(
+        mut self,
+        recurring_application_charge_capped_amount: i64,
+    ) -> Self {
+        self.recurring_application_charge_capped_amount =
+            Some(recurring_application_charge_capped_amount);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            recurring_application_charge_id,
+            recurring_application_charge_capped_amount,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = recurring_application_charge_capped_amount {
+            query_args.push((
+                "recurring_application_charge[capped_amount]".to_string(),
+                v.to_string(),
+            ));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-07/recurring_application_charges/{}/customize.json?{}",
+                crate::progenitor_support::encode_path(recurring_application_charge_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .put(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Billing::get_recurring_application_charge`], set with a builder.
+pub struct BillingGetRecurringApplicationChargeRequest<'a> {
+    client: &'a Billing,
+    since_id: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> BillingGetRecurringApplicationChargeRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            since_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-10/recurring_application_charges.json?{}",
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Billing::get_recurring_application_charges_param_charge`], set with a builder.
+pub struct BillingGetRecurringApplicationChargesParamChargeRequest<'a> {
+    client: &'a Billing,
+    recurring_application_charge_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> BillingGetRecurringApplicationChargesParamChargeRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            recurring_application_charge_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-10/recurring_application_charges/{}/json?{}",
+                crate::progenitor_support::encode_path(recurring_application_charge_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Billing::update_recurring_application_charges_param_charge_customize`], set with a builder.
+pub struct BillingUpdateRecurringApplicationChargesParamChargeCustomizeRequest<'a> {
+    client: &'a Billing,
+    recurring_application_charge_id: &'a str,
+    recurring_application_charge_capped_amount: Option<i64>,
+}
+
+impl<'a> BillingUpdateRecurringApplicationChargesParamChargeCustomizeRequest<'a> {
+    /// Sets the `recurring_application_charge[capped_amount]` query parameter.
+    pub fn recurring_application_charge_capped_amount(
+        mut self,
+        recurring_application_charge_capped_amount: i64,
+    ) -> Self {
+        self.recurring_application_charge_capped_amount =
+            Some(recurring_application_charge_capped_amount);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            recurring_application_charge_id,
+            recurring_application_charge_capped_amount,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = recurring_application_charge_capped_amount {
+            query_args.push((
+                "recurring_application_charge[capped_amount]".to_string(),
+                v.to_string(),
+            ));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-10/recurring_application_charges/{}/customize.json?{}",
+                crate::progenitor_support::encode_path(recurring_application_charge_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .put(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Billing::deprecated_202101_get_recurring_application_charge`], set with a builder.
+pub struct BillingDeprecated202101GetRecurringApplicationChargeRequest<'a> {
+    client: &'a Billing,
+    since_id: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> BillingDeprecated202101GetRecurringApplicationChargeRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            since_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2021-01/recurring_application_charges.json?{}",
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Billing::deprecated_202101_get_recurring_application_charges_param_charge`], set with a builder.
+pub struct BillingDeprecated202101GetRecurringApplicationChargesParamChargeRequest<'a> {
+    client: &'a Billing,
+    recurring_application_charge_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> BillingDeprecated202101GetRecurringApplicationChargesParamChargeRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            recurring_application_charge_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2021-01/recurring_application_charges/{}/json?{}",
+                crate::progenitor_support::encode_path(recurring_application_charge_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Billing::deprecated_202101_update_recurring_application_charges_param_charge_customize`], set with a builder.
+pub struct BillingDeprecated202101UpdateRecurringApplicationChargesParamChargeCustomizeRequest<'a> {
+    client: &'a Billing,
+    recurring_application_charge_id: &'a str,
+    recurring_application_charge_capped_amount: Option<i64>,
+}
+
+impl<'a> BillingDeprecated202101UpdateRecurringApplicationChargesParamChargeCustomizeRequest<'a> {
+    /// Sets the `recurring_application_charge[capped_amount]` query parameter.
+    pub fn recurring_application_charge_capped_amount(
+        mut self,
+        recurring_application_charge_capped_amount: i64,
+    ) -> Self {
+        self.recurring_application_charge_capped_amount =
+            Some(recurring_application_charge_capped_amount);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            recurring_application_charge_id,
+            recurring_application_charge_capped_amount,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = recurring_application_charge_capped_amount {
+            query_args.push((
+                "recurring_application_charge[capped_amount]".to_string(),
+                v.to_string(),
+            ));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2021-01/recurring_application_charges/{}/customize.json?{}",
+                crate::progenitor_support::encode_path(recurring_application_charge_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .put(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Billing::deprecated_unstable_get_recurring_application_charge`], set with a builder.
+pub struct BillingDeprecatedUnstableGetRecurringApplicationChargeRequest<'a> {
+    client: &'a Billing,
+    since_id: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> BillingDeprecatedUnstableGetRecurringApplicationChargeRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            since_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/unstable/recurring_application_charges.json?{}",
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Billing::deprecated_unstable_get_recurring_application_charges_param_charge`], set with a builder.
+pub struct BillingDeprecatedUnstableGetRecurringApplicationChargesParamChargeRequest<'a> {
+    client: &'a Billing,
+    recurring_application_charge_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> BillingDeprecatedUnstableGetRecurringApplicationChargesParamChargeRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            recurring_application_charge_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/unstable/recurring_application_charges/{}/json?{}",
+                crate::progenitor_support::encode_path(recurring_application_charge_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Billing::deprecated_unstable_update_recurring_application_charges_param_charge_customize`], set with a builder.
+pub struct BillingDeprecatedUnstableUpdateRecurringApplicationChargesParamChargeCustomizeRequest<'a>
+{
+    client: &'a Billing,
+    recurring_application_charge_id: &'a str,
+    recurring_application_charge_capped_amount: Option<i64>,
+}
+
+impl<'a> BillingDeprecatedUnstableUpdateRecurringApplicationChargesParamChargeCustomizeRequest<'a> {
+    /// Sets the `recurring_application_charge[capped_amount]` query parameter.
+    pub fn recurring_application_charge_capped_amount(
+        mut self,
+        recurring_application_charge_capped_amount: i64,
+    ) -> Self {
+        self.recurring_application_charge_capped_amount =
+            Some(recurring_application_charge_capped_amount);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            recurring_application_charge_id,
+            recurring_application_charge_capped_amount,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = recurring_application_charge_capped_amount {
+            query_args.push((
+                "recurring_application_charge[capped_amount]".to_string(),
+                v.to_string(),
+            ));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/unstable/recurring_application_charges/{}/customize.json?{}",
+                crate::progenitor_support::encode_path(recurring_application_charge_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .put(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Billing::deprecated_202001_get_recurring_application_charges_param_charge_usage`], set with a builder.
+pub struct BillingDeprecated202001GetRecurringApplicationChargesParamChargeUsageRequest<'a> {
+    client: &'a Billing,
+    recurring_application_charge_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> BillingDeprecated202001GetRecurringApplicationChargesParamChargeUsageRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            recurring_application_charge_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-01/recurring_application_charges/{}/usage_charges.json?{}",
+                crate::progenitor_support::encode_path(recurring_application_charge_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Billing::deprecated_202001_get_recurring_application_charges_param_charge_usage_billing`], set with a builder.
+pub struct BillingDeprecated202001GetRecurringApplicationChargesParamChargeUsageBillingRequest<'a> {
+    client: &'a Billing,
+    recurring_application_charge_id: &'a str,
+    usage_charge_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> BillingDeprecated202001GetRecurringApplicationChargesParamChargeUsageBillingRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            recurring_application_charge_id,
+            usage_charge_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-01/recurring_application_charges/{}/usage_charges/{}/json?{}",
+                crate::progenitor_support::encode_path(recurring_application_charge_id),
+                crate::progenitor_support::encode_path(usage_charge_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Billing::deprecated_202004_get_recurring_application_charges_param_charge_usage`], set with a builder.
+pub struct BillingDeprecated202004GetRecurringApplicationChargesParamChargeUsageRequest<'a> {
+    client: &'a Billing,
+    recurring_application_charge_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> BillingDeprecated202004GetRecurringApplicationChargesParamChargeUsageRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            recurring_application_charge_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-04/recurring_application_charges/{}/usage_charges.json?{}",
+                crate::progenitor_support::encode_path(recurring_application_charge_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Billing::deprecated_202004_get_recurring_application_charges_param_charge_usage_billing`], set with a builder.
+pub struct BillingDeprecated202004GetRecurringApplicationChargesParamChargeUsageBillingRequest<'a> {
+    client: &'a Billing,
+    recurring_application_charge_id: &'a str,
+    usage_charge_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> BillingDeprecated202004GetRecurringApplicationChargesParamChargeUsageBillingRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            recurring_application_charge_id,
+            usage_charge_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-04/recurring_application_charges/{}/usage_charges/{}/json?{}",
+                crate::progenitor_support::encode_path(recurring_application_charge_id),
+                crate::progenitor_support::encode_path(usage_charge_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Billing::deprecated_202007_get_recurring_application_charges_param_charge_usage`], set with a builder.
+pub struct BillingDeprecated202007GetRecurringApplicationChargesParamChargeUsageRequest<'a> {
+    client: &'a Billing,
+    recurring_application_charge_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> BillingDeprecated202007GetRecurringApplicationChargesParamChargeUsageRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            recurring_application_charge_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-07/recurring_application_charges/{}/usage_charges.json?{}",
+                crate::progenitor_support::encode_path(recurring_application_charge_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Billing::deprecated_202007_get_recurring_application_charges_param_charge_usage_billing`], set with a builder.
+pub struct BillingDeprecated202007GetRecurringApplicationChargesParamChargeUsageBillingRequest<'a> {
+    client: &'a Billing,
+    recurring_application_charge_id: &'a str,
+    usage_charge_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> BillingDeprecated202007GetRecurringApplicationChargesParamChargeUsageBillingRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            recurring_application_charge_id,
+            usage_charge_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-07/recurring_application_charges/{}/usage_charges/{}/json?{}",
+                crate::progenitor_support::encode_path(recurring_application_charge_id),
+                crate::progenitor_support::encode_path(usage_charge_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Billing::get_recurring_application_charges_param_charge_usage`], set with a builder.
+pub struct BillingGetRecurringApplicationChargesParamChargeUsageRequest<'a> {
+    client: &'a Billing,
+    recurring_application_charge_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> BillingGetRecurringApplicationChargesParamChargeUsageRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            recurring_application_charge_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-10/recurring_application_charges/{}/usage_charges.json?{}",
+                crate::progenitor_support::encode_path(recurring_application_charge_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Billing::get_recurring_application_charges_param_charge_usage_billing`], set with a builder.
+pub struct BillingGetRecurringApplicationChargesParamChargeUsageBillingRequest<'a> {
+    client: &'a Billing,
+    recurring_application_charge_id: &'a str,
+    usage_charge_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> BillingGetRecurringApplicationChargesParamChargeUsageBillingRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            recurring_application_charge_id,
+            usage_charge_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-10/recurring_application_charges/{}/usage_charges/{}/json?{}",
+                crate::progenitor_support::encode_path(recurring_application_charge_id),
+                crate::progenitor_support::encode_path(usage_charge_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Billing::deprecated_202101_get_recurring_application_charges_param_charge_usage`], set with a builder.
+pub struct BillingDeprecated202101GetRecurringApplicationChargesParamChargeUsageRequest<'a> {
+    client: &'a Billing,
+    recurring_application_charge_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> BillingDeprecated202101GetRecurringApplicationChargesParamChargeUsageRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            recurring_application_charge_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2021-01/recurring_application_charges/{}/usage_charges.json?{}",
+                crate::progenitor_support::encode_path(recurring_application_charge_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Billing::deprecated_202101_get_recurring_application_charges_param_charge_usage_billing`], set with a builder.
+pub struct BillingDeprecated202101GetRecurringApplicationChargesParamChargeUsageBillingRequest<'a> {
+    client: &'a Billing,
+    recurring_application_charge_id: &'a str,
+    usage_charge_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> BillingDeprecated202101GetRecurringApplicationChargesParamChargeUsageBillingRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            recurring_application_charge_id,
+            usage_charge_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2021-01/recurring_application_charges/{}/usage_charges/{}/json?{}",
+                crate::progenitor_support::encode_path(recurring_application_charge_id),
+                crate::progenitor_support::encode_path(usage_charge_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Billing::deprecated_unstable_get_recurring_application_charges_param_charge_usage`], set with a builder.
+pub struct BillingDeprecatedUnstableGetRecurringApplicationChargesParamChargeUsageRequest<'a> {
+    client: &'a Billing,
+    recurring_application_charge_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> BillingDeprecatedUnstableGetRecurringApplicationChargesParamChargeUsageRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            recurring_application_charge_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/unstable/recurring_application_charges/{}/usage_charges.json?{}",
+                crate::progenitor_support::encode_path(recurring_application_charge_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Billing::deprecated_unstable_get_recurring_application_charges_param_charge_usage_billing`], set with a builder.
+pub struct BillingDeprecatedUnstableGetRecurringApplicationChargesParamChargeUsageBillingRequest<'a>
+{
+    client: &'a Billing,
+    recurring_application_charge_id: &'a str,
+    usage_charge_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> BillingDeprecatedUnstableGetRecurringApplicationChargesParamChargeUsageBillingRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            recurring_application_charge_id,
+            usage_charge_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/unstable/recurring_application_charges/{}/usage_charges/{}/json?{}",
+                crate::progenitor_support::encode_path(recurring_application_charge_id),
+                crate::progenitor_support::encode_path(usage_charge_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct Billing {
     pub client: Client,
 }
@@ -50,6 +2728,24 @@ impl Billing {
             )
             .await
     }
+    /**
+     * Retrieves a list of application charges.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-01/application_charges.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_get_application_charge`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/billing/applicationcharge#index-2020-01
+     */
+    pub fn deprecated_202001_get_application_charge_request(
+        &self,
+    ) -> BillingDeprecated202001GetApplicationChargeRequest<'_> {
+        BillingDeprecated202001GetApplicationChargeRequest {
+            client: self,
+            since_id: None,
+            fields: None,
+        }
+    }
     /**
      * Creates an application charge.
      *
@@ -114,6 +2810,25 @@ impl Billing {
             )
             .await
     }
+    /**
+     * Retrieves an application charge.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-01/application_charges/{application_charge_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_get_application_charges_param_charge`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/billing/applicationcharge#show-2020-01
+     */
+    pub fn deprecated_202001_get_application_charges_param_charge_request<'a>(
+        &'a self,
+        application_charge_id: &'a str,
+    ) -> BillingDeprecated202001GetApplicationChargesParamChargeRequest<'a> {
+        BillingDeprecated202001GetApplicationChargesParamChargeRequest {
+            client: self,
+            application_charge_id,
+            fields: None,
+        }
+    }
     /**
      * Caution
       This endpoint is no longer required and is deprecated as of
@@ -191,6 +2906,24 @@ impl Billing {
             )
             .await
     }
+    /**
+     * Retrieves a list of application charges.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-04/application_charges.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_get_application_charge`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/billing/applicationcharge#index-2020-04
+     */
+    pub fn deprecated_202004_get_application_charge_request(
+        &self,
+    ) -> BillingDeprecated202004GetApplicationChargeRequest<'_> {
+        BillingDeprecated202004GetApplicationChargeRequest {
+            client: self,
+            since_id: None,
+            fields: None,
+        }
+    }
     /**
      * Creates an application charge.
      *
@@ -255,6 +2988,25 @@ impl Billing {
             )
             .await
     }
+    /**
+     * Retrieves an application charge.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-04/application_charges/{application_charge_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_get_application_charges_param_charge`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/billing/applicationcharge#show-2020-04
+     */
+    pub fn deprecated_202004_get_application_charges_param_charge_request<'a>(
+        &'a self,
+        application_charge_id: &'a str,
+    ) -> BillingDeprecated202004GetApplicationChargesParamChargeRequest<'a> {
+        BillingDeprecated202004GetApplicationChargesParamChargeRequest {
+            client: self,
+            application_charge_id,
+            fields: None,
+        }
+    }
     /**
      * Caution
       This endpoint is no longer required and is deprecated as of
@@ -332,6 +3084,24 @@ impl Billing {
             )
             .await
     }
+    /**
+     * Retrieves a list of application charges.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-07/application_charges.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_get_application_charge`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/billing/applicationcharge#index-2020-07
+     */
+    pub fn deprecated_202007_get_application_charge_request(
+        &self,
+    ) -> BillingDeprecated202007GetApplicationChargeRequest<'_> {
+        BillingDeprecated202007GetApplicationChargeRequest {
+            client: self,
+            since_id: None,
+            fields: None,
+        }
+    }
     /**
      * Creates an application charge.
      *
@@ -396,6 +3166,25 @@ impl Billing {
             )
             .await
     }
+    /**
+     * Retrieves an application charge.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-07/application_charges/{application_charge_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_get_application_charges_param_charge`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/billing/applicationcharge#show-2020-07
+     */
+    pub fn deprecated_202007_get_application_charges_param_charge_request<'a>(
+        &'a self,
+        application_charge_id: &'a str,
+    ) -> BillingDeprecated202007GetApplicationChargesParamChargeRequest<'a> {
+        BillingDeprecated202007GetApplicationChargesParamChargeRequest {
+            client: self,
+            application_charge_id,
+            fields: None,
+        }
+    }
     /**
      * Caution
       This endpoint is no longer required and is deprecated as of
@@ -473,6 +3262,22 @@ impl Billing {
             )
             .await
     }
+    /**
+     * Retrieves a list of application charges.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-10/application_charges.json` endpoint.
+     *
+     * As opposed to `get_application_charge`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/billing/applicationcharge#index-2020-10
+     */
+    pub fn get_application_charge_request(&self) -> BillingGetApplicationChargeRequest<'_> {
+        BillingGetApplicationChargeRequest {
+            client: self,
+            since_id: None,
+            fields: None,
+        }
+    }
     /**
      * Creates an application charge.
      *
@@ -537,6 +3342,25 @@ impl Billing {
             )
             .await
     }
+    /**
+     * Retrieves an application charge.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-10/application_charges/{application_charge_id}.json` endpoint.
+     *
+     * As opposed to `get_application_charges_param_charge`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/billing/applicationcharge#show-2020-10
+     */
+    pub fn get_application_charges_param_charge_request<'a>(
+        &'a self,
+        application_charge_id: &'a str,
+    ) -> BillingGetApplicationChargesParamChargeRequest<'a> {
+        BillingGetApplicationChargesParamChargeRequest {
+            client: self,
+            application_charge_id,
+            fields: None,
+        }
+    }
     /**
      * Caution
       This endpoint is no longer required and is deprecated as of
@@ -614,6 +3438,24 @@ impl Billing {
             )
             .await
     }
+    /**
+     * Retrieves a list of application charges.
+     *
+     * This function performs a `GET` to the `/admin/api/2021-01/application_charges.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_get_application_charge`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/billing/applicationcharge#index-2021-01
+     */
+    pub fn deprecated_202101_get_application_charge_request(
+        &self,
+    ) -> BillingDeprecated202101GetApplicationChargeRequest<'_> {
+        BillingDeprecated202101GetApplicationChargeRequest {
+            client: self,
+            since_id: None,
+            fields: None,
+        }
+    }
     /**
      * Creates an application charge.
      *
@@ -678,6 +3520,25 @@ impl Billing {
             )
             .await
     }
+    /**
+     * Retrieves an application charge.
+     *
+     * This function performs a `GET` to the `/admin/api/2021-01/application_charges/{application_charge_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_get_application_charges_param_charge`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/billing/applicationcharge#show-2021-01
+     */
+    pub fn deprecated_202101_get_application_charges_param_charge_request<'a>(
+        &'a self,
+        application_charge_id: &'a str,
+    ) -> BillingDeprecated202101GetApplicationChargesParamChargeRequest<'a> {
+        BillingDeprecated202101GetApplicationChargesParamChargeRequest {
+            client: self,
+            application_charge_id,
+            fields: None,
+        }
+    }
     /**
      * Retrieves a list of application charges.
      *
@@ -717,6 +3578,24 @@ impl Billing {
             )
             .await
     }
+    /**
+     * Retrieves a list of application charges.
+     *
+     * This function performs a `GET` to the `/admin/api/unstable/application_charges.json` endpoint.
+     *
+     * As opposed to `deprecated_unstable_get_application_charge`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/billing/applicationcharge#index-unstable
+     */
+    pub fn deprecated_unstable_get_application_charge_request(
+        &self,
+    ) -> BillingDeprecatedUnstableGetApplicationChargeRequest<'_> {
+        BillingDeprecatedUnstableGetApplicationChargeRequest {
+            client: self,
+            since_id: None,
+            fields: None,
+        }
+    }
     /**
      * Creates an application charge.
      *
@@ -781,6 +3660,25 @@ impl Billing {
             )
             .await
     }
+    /**
+     * Retrieves an application charge.
+     *
+     * This function performs a `GET` to the `/admin/api/unstable/application_charges/{application_charge_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_unstable_get_application_charges_param_charge`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/billing/applicationcharge#show-unstable
+     */
+    pub fn deprecated_unstable_get_application_charges_param_charge_request<'a>(
+        &'a self,
+        application_charge_id: &'a str,
+    ) -> BillingDeprecatedUnstableGetApplicationChargesParamChargeRequest<'a> {
+        BillingDeprecatedUnstableGetApplicationChargesParamChargeRequest {
+            client: self,
+            application_charge_id,
+            fields: None,
+        }
+    }
     /**
      * Retrieves all application credits.
      *
@@ -815,6 +3713,23 @@ impl Billing {
             )
             .await
     }
+    /**
+     * Retrieves all application credits.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-01/application_credits.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_get_application_credit`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/billing/applicationcredit#index-2020-01
+     */
+    pub fn deprecated_202001_get_application_credit_request(
+        &self,
+    ) -> BillingDeprecated202001GetApplicationCreditRequest<'_> {
+        BillingDeprecated202001GetApplicationCreditRequest {
+            client: self,
+            fields: None,
+        }
+    }
     /**
      * Creates an application credit.
      *
@@ -879,6 +3794,25 @@ impl Billing {
             )
             .await
     }
+    /**
+     * Retrieves a single application credit.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-01/application_credits/{application_credit_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_get_application_credits_param_credit`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/billing/applicationcredit#show-2020-01
+     */
+    pub fn deprecated_202001_get_application_credits_param_credit_request<'a>(
+        &'a self,
+        application_credit_id: &'a str,
+    ) -> BillingDeprecated202001GetApplicationCreditsParamCreditRequest<'a> {
+        BillingDeprecated202001GetApplicationCreditsParamCreditRequest {
+            client: self,
+            application_credit_id,
+            fields: None,
+        }
+    }
     /**
      * Retrieves all application credits.
      *
@@ -913,6 +3847,23 @@ impl Billing {
             )
             .await
     }
+    /**
+     * Retrieves all application credits.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-04/application_credits.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_get_application_credit`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/billing/applicationcredit#index-2020-04
+     */
+    pub fn deprecated_202004_get_application_credit_request(
+        &self,
+    ) -> BillingDeprecated202004GetApplicationCreditRequest<'_> {
+        BillingDeprecated202004GetApplicationCreditRequest {
+            client: self,
+            fields: None,
+        }
+    }
     /**
      * Creates an application credit.
      *
@@ -977,6 +3928,25 @@ impl Billing {
             )
             .await
     }
+    /**
+     * Retrieves a single application credit.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-04/application_credits/{application_credit_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_get_application_credits_param_credit`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/billing/applicationcredit#show-2020-04
+     */
+    pub fn deprecated_202004_get_application_credits_param_credit_request<'a>(
+        &'a self,
+        application_credit_id: &'a str,
+    ) -> BillingDeprecated202004GetApplicationCreditsParamCreditRequest<'a> {
+        BillingDeprecated202004GetApplicationCreditsParamCreditRequest {
+            client: self,
+            application_credit_id,
+            fields: None,
+        }
+    }
     /**
      * Retrieves all application credits.
      *
@@ -1011,6 +3981,23 @@ impl Billing {
             )
             .await
     }
+    /**
+     * Retrieves all application credits.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-07/application_credits.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_get_application_credit`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/billing/applicationcredit#index-2020-07
+     */
+    pub fn deprecated_202007_get_application_credit_request(
+        &self,
+    ) -> BillingDeprecated202007GetApplicationCreditRequest<'_> {
+        BillingDeprecated202007GetApplicationCreditRequest {
+            client: self,
+            fields: None,
+        }
+    }
     /**
      * Creates an application credit.
      *
@@ -1075,6 +4062,25 @@ impl Billing {
             )
             .await
     }
+    /**
+     * Retrieves a single application credit.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-07/application_credits/{application_credit_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_get_application_credits_param_credit`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/billing/applicationcredit#show-2020-07
+     */
+    pub fn deprecated_202007_get_application_credits_param_credit_request<'a>(
+        &'a self,
+        application_credit_id: &'a str,
+    ) -> BillingDeprecated202007GetApplicationCreditsParamCreditRequest<'a> {
+        BillingDeprecated202007GetApplicationCreditsParamCreditRequest {
+            client: self,
+            application_credit_id,
+            fields: None,
+        }
+    }
     /**
      * Retrieves all application credits.
      *
@@ -1106,6 +4112,21 @@ impl Billing {
             )
             .await
     }
+    /**
+     * Retrieves all application credits.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-10/application_credits.json` endpoint.
+     *
+     * As opposed to `get_application_credit`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/billing/applicationcredit#index-2020-10
+     */
+    pub fn get_application_credit_request(&self) -> BillingGetApplicationCreditRequest<'_> {
+        BillingGetApplicationCreditRequest {
+            client: self,
+            fields: None,
+        }
+    }
     /**
      * Creates an application credit.
      *
@@ -1170,6 +4191,25 @@ impl Billing {
             )
             .await
     }
+    /**
+     * Retrieves a single application credit.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-10/application_credits/{application_credit_id}.json` endpoint.
+     *
+     * As opposed to `get_application_credits_param_credit`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/billing/applicationcredit#show-2020-10
+     */
+    pub fn get_application_credits_param_credit_request<'a>(
+        &'a self,
+        application_credit_id: &'a str,
+    ) -> BillingGetApplicationCreditsParamCreditRequest<'a> {
+        BillingGetApplicationCreditsParamCreditRequest {
+            client: self,
+            application_credit_id,
+            fields: None,
+        }
+    }
     /**
      * Retrieves all application credits.
      *
@@ -1204,6 +4244,23 @@ impl Billing {
             )
             .await
     }
+    /**
+     * Retrieves all application credits.
+     *
+     * This function performs a `GET` to the `/admin/api/2021-01/application_credits.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_get_application_credit`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/billing/applicationcredit#index-2021-01
+     */
+    pub fn deprecated_202101_get_application_credit_request(
+        &self,
+    ) -> BillingDeprecated202101GetApplicationCreditRequest<'_> {
+        BillingDeprecated202101GetApplicationCreditRequest {
+            client: self,
+            fields: None,
+        }
+    }
     /**
      * Creates an application credit.
      *
@@ -1268,6 +4325,25 @@ impl Billing {
             )
             .await
     }
+    /**
+     * Retrieves a single application credit.
+     *
+     * This function performs a `GET` to the `/admin/api/2021-01/application_credits/{application_credit_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_get_application_credits_param_credit`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/billing/applicationcredit#show-2021-01
+     */
+    pub fn deprecated_202101_get_application_credits_param_credit_request<'a>(
+        &'a self,
+        application_credit_id: &'a str,
+    ) -> BillingDeprecated202101GetApplicationCreditsParamCreditRequest<'a> {
+        BillingDeprecated202101GetApplicationCreditsParamCreditRequest {
+            client: self,
+            application_credit_id,
+            fields: None,
+        }
+    }
     /**
      * Retrieves all application credits.
      *
@@ -1302,6 +4378,23 @@ impl Billing {
             )
             .await
     }
+    /**
+     * Retrieves all application credits.
+     *
+     * This function performs a `GET` to the `/admin/api/unstable/application_credits.json` endpoint.
+     *
+     * As opposed to `deprecated_unstable_get_application_credit`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/billing/applicationcredit#index-unstable
+     */
+    pub fn deprecated_unstable_get_application_credit_request(
+        &self,
+    ) -> BillingDeprecatedUnstableGetApplicationCreditRequest<'_> {
+        BillingDeprecatedUnstableGetApplicationCreditRequest {
+            client: self,
+            fields: None,
+        }
+    }
     /**
      * Creates an application credit.
      *
@@ -1366,6 +4459,25 @@ impl Billing {
             )
             .await
     }
+    /**
+     * Retrieves a single application credit.
+     *
+     * This function performs a `GET` to the `/admin/api/unstable/application_credits/{application_credit_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_unstable_get_application_credits_param_credit`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/billing/applicationcredit#show-unstable
+     */
+    pub fn deprecated_unstable_get_application_credits_param_credit_request<'a>(
+        &'a self,
+        application_credit_id: &'a str,
+    ) -> BillingDeprecatedUnstableGetApplicationCreditsParamCreditRequest<'a> {
+        BillingDeprecatedUnstableGetApplicationCreditsParamCreditRequest {
+            client: self,
+            application_credit_id,
+            fields: None,
+        }
+    }
     /**
      * Retrieves a list of recurring application charges.
      *
@@ -1408,6 +4520,24 @@ impl Billing {
             )
             .await
     }
+    /**
+     * Retrieves a list of recurring application charges.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-01/recurring_application_charges.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_get_recurring_application_charge`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/billing/recurringapplicationcharge#index-2020-01
+     */
+    pub fn deprecated_202001_get_recurring_application_charge_request(
+        &self,
+    ) -> BillingDeprecated202001GetRecurringApplicationChargeRequest<'_> {
+        BillingDeprecated202001GetRecurringApplicationChargeRequest {
+            client: self,
+            since_id: None,
+            fields: None,
+        }
+    }
     /**
      * Creates a recurring application charge.
      *
@@ -1473,6 +4603,25 @@ impl Billing {
             )
             .await
     }
+    /**
+     * Retrieves a single charge.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-01/recurring_application_charges/{recurring_application_charge_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_get_recurring_application_charges_param_charge`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/billing/recurringapplicationcharge#show-2020-01
+     */
+    pub fn deprecated_202001_get_recurring_application_charges_param_charge_request<'a>(
+        &'a self,
+        recurring_application_charge_id: &'a str,
+    ) -> BillingDeprecated202001GetRecurringApplicationChargesParamChargeRequest<'a> {
+        BillingDeprecated202001GetRecurringApplicationChargesParamChargeRequest {
+            client: self,
+            recurring_application_charge_id,
+            fields: None,
+        }
+    }
     /**
      * Cancels a recurring application charge.
      *
@@ -1586,6 +4735,28 @@ impl Billing {
             )
             .await
     }
+    /**
+     * Updates the capped amount of an active recurring application charge.
+     *
+     * This function performs a `PUT` to the `/admin/api/2020-01/recurring_application_charges/{recurring_application_charge_id}/customize.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_update_recurring_application_charges_param_charge_customize`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/billing/recurringapplicationcharge#customize-2020-01
+     */
+    pub fn deprecated_202001_update_recurring_application_charges_param_charge_customize_request<
+        'a,
+    >(
+        &'a self,
+        recurring_application_charge_id: &'a str,
+    ) -> BillingDeprecated202001UpdateRecurringApplicationChargesParamChargeCustomizeRequest<'a>
+    {
+        BillingDeprecated202001UpdateRecurringApplicationChargesParamChargeCustomizeRequest {
+            client: self,
+            recurring_application_charge_id,
+            recurring_application_charge_capped_amount: None,
+        }
+    }
     /**
      * Retrieves a list of recurring application charges.
      *
@@ -1628,6 +4799,24 @@ impl Billing {
             )
             .await
     }
+    /**
+     * Retrieves a list of recurring application charges.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-04/recurring_application_charges.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_get_recurring_application_charge`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/billing/recurringapplicationcharge#index-2020-04
+     */
+    pub fn deprecated_202004_get_recurring_application_charge_request(
+        &self,
+    ) -> BillingDeprecated202004GetRecurringApplicationChargeRequest<'_> {
+        BillingDeprecated202004GetRecurringApplicationChargeRequest {
+            client: self,
+            since_id: None,
+            fields: None,
+        }
+    }
     /**
      * Creates a recurring application charge.
      *
@@ -1693,6 +4882,25 @@ impl Billing {
             )
             .await
     }
+    /**
+     * Retrieves a single charge.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-04/recurring_application_charges/{recurring_application_charge_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_get_recurring_application_charges_param_charge`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/billing/recurringapplicationcharge#show-2020-04
+     */
+    pub fn deprecated_202004_get_recurring_application_charges_param_charge_request<'a>(
+        &'a self,
+        recurring_application_charge_id: &'a str,
+    ) -> BillingDeprecated202004GetRecurringApplicationChargesParamChargeRequest<'a> {
+        BillingDeprecated202004GetRecurringApplicationChargesParamChargeRequest {
+            client: self,
+            recurring_application_charge_id,
+            fields: None,
+        }
+    }
     /**
      * Cancels a recurring application charge.
      *
@@ -1806,6 +5014,28 @@ impl Billing {
             )
             .await
     }
+    /**
+     * Updates the capped amount of an active recurring application charge.
+     *
+     * This function performs a `PUT` to the `/admin/api/2020-04/recurring_application_charges/{recurring_application_charge_id}/customize.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_update_recurring_application_charges_param_charge_customize`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/billing/recurringapplicationcharge#customize-2020-04
+     */
+    pub fn deprecated_202004_update_recurring_application_charges_param_charge_customize_request<
+        'a,
+    >(
+        &'a self,
+        recurring_application_charge_id: &'a str,
+    ) -> BillingDeprecated202004UpdateRecurringApplicationChargesParamChargeCustomizeRequest<'a>
+    {
+        BillingDeprecated202004UpdateRecurringApplicationChargesParamChargeCustomizeRequest {
+            client: self,
+            recurring_application_charge_id,
+            recurring_application_charge_capped_amount: None,
+        }
+    }
     /**
      * Retrieves a list of recurring application charges.
      *
@@ -1848,6 +5078,24 @@ impl Billing {
             )
             .await
     }
+    /**
+     * Retrieves a list of recurring application charges.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-07/recurring_application_charges.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_get_recurring_application_charge`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/billing/recurringapplicationcharge#index-2020-07
+     */
+    pub fn deprecated_202007_get_recurring_application_charge_request(
+        &self,
+    ) -> BillingDeprecated202007GetRecurringApplicationChargeRequest<'_> {
+        BillingDeprecated202007GetRecurringApplicationChargeRequest {
+            client: self,
+            since_id: None,
+            fields: None,
+        }
+    }
     /**
      * Creates a recurring application charge.
      *
@@ -1913,6 +5161,25 @@ impl Billing {
             )
             .await
     }
+    /**
+     * Retrieves a single charge.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-07/recurring_application_charges/{recurring_application_charge_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_get_recurring_application_charges_param_charge`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/billing/recurringapplicationcharge#show-2020-07
+     */
+    pub fn deprecated_202007_get_recurring_application_charges_param_charge_request<'a>(
+        &'a self,
+        recurring_application_charge_id: &'a str,
+    ) -> BillingDeprecated202007GetRecurringApplicationChargesParamChargeRequest<'a> {
+        BillingDeprecated202007GetRecurringApplicationChargesParamChargeRequest {
+            client: self,
+            recurring_application_charge_id,
+            fields: None,
+        }
+    }
     /**
      * Cancels a recurring application charge.
      *
@@ -2026,6 +5293,28 @@ impl Billing {
             )
             .await
     }
+    /**
+     * Updates the capped amount of an active recurring application charge.
+     *
+     * This function performs a `PUT` to the `/admin/api/2020-07/recurring_application_charges/{recurring_application_charge_id}/customize.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_update_recurring_application_charges_param_charge_customize`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/billing/recurringapplicationcharge#customize-2020-07
+     */
+    pub fn deprecated_202007_update_recurring_application_charges_param_charge_customize_request<
+        'a,
+    >(
+        &'a self,
+        recurring_application_charge_id: &'a str,
+    ) -> BillingDeprecated202007UpdateRecurringApplicationChargesParamChargeCustomizeRequest<'a>
+    {
+        BillingDeprecated202007UpdateRecurringApplicationChargesParamChargeCustomizeRequest {
+            client: self,
+            recurring_application_charge_id,
+            recurring_application_charge_capped_amount: None,
+        }
+    }
     /**
      * Retrieves a list of recurring application charges.
      *
@@ -2068,6 +5357,24 @@ impl Billing {
             )
             .await
     }
+    /**
+     * Retrieves a list of recurring application charges.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-10/recurring_application_charges.json` endpoint.
+     *
+     * As opposed to `get_recurring_application_charge`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/billing/recurringapplicationcharge#index-2020-10
+     */
+    pub fn get_recurring_application_charge_request(
+        &self,
+    ) -> BillingGetRecurringApplicationChargeRequest<'_> {
+        BillingGetRecurringApplicationChargeRequest {
+            client: self,
+            since_id: None,
+            fields: None,
+        }
+    }
     /**
      * Creates a recurring application charge.
      *
@@ -2133,6 +5440,25 @@ impl Billing {
             )
             .await
     }
+    /**
+     * Retrieves a single charge.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-10/recurring_application_charges/{recurring_application_charge_id}.json` endpoint.
+     *
+     * As opposed to `get_recurring_application_charges_param_charge`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/billing/recurringapplicationcharge#show-2020-10
+     */
+    pub fn get_recurring_application_charges_param_charge_request<'a>(
+        &'a self,
+        recurring_application_charge_id: &'a str,
+    ) -> BillingGetRecurringApplicationChargesParamChargeRequest<'a> {
+        BillingGetRecurringApplicationChargesParamChargeRequest {
+            client: self,
+            recurring_application_charge_id,
+            fields: None,
+        }
+    }
     /**
      * Cancels a recurring application charge.
      *
@@ -2246,6 +5572,25 @@ impl Billing {
             )
             .await
     }
+    /**
+     * Updates the capped amount of an active recurring application charge.
+     *
+     * This function performs a `PUT` to the `/admin/api/2020-10/recurring_application_charges/{recurring_application_charge_id}/customize.json` endpoint.
+     *
+     * As opposed to `update_recurring_application_charges_param_charge_customize`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/billing/recurringapplicationcharge#customize-2020-10
+     */
+    pub fn update_recurring_application_charges_param_charge_customize_request<'a>(
+        &'a self,
+        recurring_application_charge_id: &'a str,
+    ) -> BillingUpdateRecurringApplicationChargesParamChargeCustomizeRequest<'a> {
+        BillingUpdateRecurringApplicationChargesParamChargeCustomizeRequest {
+            client: self,
+            recurring_application_charge_id,
+            recurring_application_charge_capped_amount: None,
+        }
+    }
     /**
      * Retrieves a list of recurring application charges.
      *
@@ -2288,6 +5633,24 @@ impl Billing {
             )
             .await
     }
+    /**
+     * Retrieves a list of recurring application charges.
+     *
+     * This function performs a `GET` to the `/admin/api/2021-01/recurring_application_charges.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_get_recurring_application_charge`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/billing/recurringapplicationcharge#index-2021-01
+     */
+    pub fn deprecated_202101_get_recurring_application_charge_request(
+        &self,
+    ) -> BillingDeprecated202101GetRecurringApplicationChargeRequest<'_> {
+        BillingDeprecated202101GetRecurringApplicationChargeRequest {
+            client: self,
+            since_id: None,
+            fields: None,
+        }
+    }
     /**
      * Creates a recurring application charge.
      *
@@ -2353,6 +5716,25 @@ impl Billing {
             )
             .await
     }
+    /**
+     * Retrieves a single charge.
+     *
+     * This function performs a `GET` to the `/admin/api/2021-01/recurring_application_charges/{recurring_application_charge_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_get_recurring_application_charges_param_charge`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/billing/recurringapplicationcharge#show-2021-01
+     */
+    pub fn deprecated_202101_get_recurring_application_charges_param_charge_request<'a>(
+        &'a self,
+        recurring_application_charge_id: &'a str,
+    ) -> BillingDeprecated202101GetRecurringApplicationChargesParamChargeRequest<'a> {
+        BillingDeprecated202101GetRecurringApplicationChargesParamChargeRequest {
+            client: self,
+            recurring_application_charge_id,
+            fields: None,
+        }
+    }
     /**
      * Cancels a recurring application charge.
      *
@@ -2428,6 +5810,28 @@ impl Billing {
             )
             .await
     }
+    /**
+     * Updates the capped amount of an active recurring application charge.
+     *
+     * This function performs a `PUT` to the `/admin/api/2021-01/recurring_application_charges/{recurring_application_charge_id}/customize.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_update_recurring_application_charges_param_charge_customize`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/billing/recurringapplicationcharge#customize-2021-01
+     */
+    pub fn deprecated_202101_update_recurring_application_charges_param_charge_customize_request<
+        'a,
+    >(
+        &'a self,
+        recurring_application_charge_id: &'a str,
+    ) -> BillingDeprecated202101UpdateRecurringApplicationChargesParamChargeCustomizeRequest<'a>
+    {
+        BillingDeprecated202101UpdateRecurringApplicationChargesParamChargeCustomizeRequest {
+            client: self,
+            recurring_application_charge_id,
+            recurring_application_charge_capped_amount: None,
+        }
+    }
     /**
      * Retrieves a list of recurring application charges.
      *
@@ -2470,6 +5874,24 @@ impl Billing {
             )
             .await
     }
+    /**
+     * Retrieves a list of recurring application charges.
+     *
+     * This function performs a `GET` to the `/admin/api/unstable/recurring_application_charges.json` endpoint.
+     *
+     * As opposed to `deprecated_unstable_get_recurring_application_charge`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/billing/recurringapplicationcharge#index-unstable
+     */
+    pub fn deprecated_unstable_get_recurring_application_charge_request(
+        &self,
+    ) -> BillingDeprecatedUnstableGetRecurringApplicationChargeRequest<'_> {
+        BillingDeprecatedUnstableGetRecurringApplicationChargeRequest {
+            client: self,
+            since_id: None,
+            fields: None,
+        }
+    }
     /**
      * Creates a recurring application charge.
      *
@@ -2535,6 +5957,25 @@ impl Billing {
             )
             .await
     }
+    /**
+     * Retrieves a single charge.
+     *
+     * This function performs a `GET` to the `/admin/api/unstable/recurring_application_charges/{recurring_application_charge_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_unstable_get_recurring_application_charges_param_charge`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/billing/recurringapplicationcharge#show-unstable
+     */
+    pub fn deprecated_unstable_get_recurring_application_charges_param_charge_request<'a>(
+        &'a self,
+        recurring_application_charge_id: &'a str,
+    ) -> BillingDeprecatedUnstableGetRecurringApplicationChargesParamChargeRequest<'a> {
+        BillingDeprecatedUnstableGetRecurringApplicationChargesParamChargeRequest {
+            client: self,
+            recurring_application_charge_id,
+            fields: None,
+        }
+    }
     /**
      * Cancels a recurring application charge.
      *
@@ -2610,6 +6051,28 @@ impl Billing {
             )
             .await
     }
+    /**
+     * Updates the capped amount of an active recurring application charge.
+     *
+     * This function performs a `PUT` to the `/admin/api/unstable/recurring_application_charges/{recurring_application_charge_id}/customize.json` endpoint.
+     *
+     * As opposed to `deprecated_unstable_update_recurring_application_charges_param_charge_customize`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/billing/recurringapplicationcharge#customize-unstable
+     */
+    pub fn deprecated_unstable_update_recurring_application_charges_param_charge_customize_request<
+        'a,
+    >(
+        &'a self,
+        recurring_application_charge_id: &'a str,
+    ) -> BillingDeprecatedUnstableUpdateRecurringApplicationChargesParamChargeCustomizeRequest<'a>
+    {
+        BillingDeprecatedUnstableUpdateRecurringApplicationChargesParamChargeCustomizeRequest {
+            client: self,
+            recurring_application_charge_id,
+            recurring_application_charge_capped_amount: None,
+        }
+    }
     /**
      * Retrieves a list of usage charges.
      *
@@ -2650,6 +6113,25 @@ impl Billing {
             )
             .await
     }
+    /**
+     * Retrieves a list of usage charges.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-01/recurring_application_charges/{recurring_application_charge_id}/usage_charges.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_get_recurring_application_charges_param_charge_usage`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/billing/usagecharge#index-2020-01
+     */
+    pub fn deprecated_202001_get_recurring_application_charges_param_charge_usage_request<'a>(
+        &'a self,
+        recurring_application_charge_id: &'a str,
+    ) -> BillingDeprecated202001GetRecurringApplicationChargesParamChargeUsageRequest<'a> {
+        BillingDeprecated202001GetRecurringApplicationChargesParamChargeUsageRequest {
+            client: self,
+            recurring_application_charge_id,
+            fields: None,
+        }
+    }
     /**
      * Creates a usage charge.
      *
@@ -2726,6 +6208,30 @@ impl Billing {
             )
             .await
     }
+    /**
+     * Retrieves a single charge.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-01/recurring_application_charges/{recurring_application_charge_id}/usage_charges/{usage_charge_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_get_recurring_application_charges_param_charge_usage_billing`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/billing/usagecharge#show-2020-01
+     */
+    pub fn deprecated_202001_get_recurring_application_charges_param_charge_usage_billing_request<
+        'a,
+    >(
+        &'a self,
+        recurring_application_charge_id: &'a str,
+        usage_charge_id: &'a str,
+    ) -> BillingDeprecated202001GetRecurringApplicationChargesParamChargeUsageBillingRequest<'a>
+    {
+        BillingDeprecated202001GetRecurringApplicationChargesParamChargeUsageBillingRequest {
+            client: self,
+            recurring_application_charge_id,
+            usage_charge_id,
+            fields: None,
+        }
+    }
     /**
      * Retrieves a list of usage charges.
      *
@@ -2766,6 +6272,25 @@ impl Billing {
             )
             .await
     }
+    /**
+     * Retrieves a list of usage charges.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-04/recurring_application_charges/{recurring_application_charge_id}/usage_charges.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_get_recurring_application_charges_param_charge_usage`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/billing/usagecharge#index-2020-04
+     */
+    pub fn deprecated_202004_get_recurring_application_charges_param_charge_usage_request<'a>(
+        &'a self,
+        recurring_application_charge_id: &'a str,
+    ) -> BillingDeprecated202004GetRecurringApplicationChargesParamChargeUsageRequest<'a> {
+        BillingDeprecated202004GetRecurringApplicationChargesParamChargeUsageRequest {
+            client: self,
+            recurring_application_charge_id,
+            fields: None,
+        }
+    }
     /**
      * Creates a usage charge.
      *
@@ -2842,6 +6367,30 @@ impl Billing {
             )
             .await
     }
+    /**
+     * Retrieves a single charge.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-04/recurring_application_charges/{recurring_application_charge_id}/usage_charges/{usage_charge_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_get_recurring_application_charges_param_charge_usage_billing`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/billing/usagecharge#show-2020-04
+     */
+    pub fn deprecated_202004_get_recurring_application_charges_param_charge_usage_billing_request<
+        'a,
+    >(
+        &'a self,
+        recurring_application_charge_id: &'a str,
+        usage_charge_id: &'a str,
+    ) -> BillingDeprecated202004GetRecurringApplicationChargesParamChargeUsageBillingRequest<'a>
+    {
+        BillingDeprecated202004GetRecurringApplicationChargesParamChargeUsageBillingRequest {
+            client: self,
+            recurring_application_charge_id,
+            usage_charge_id,
+            fields: None,
+        }
+    }
     /**
      * Retrieves a list of usage charges.
      *
@@ -2882,6 +6431,25 @@ impl Billing {
             )
             .await
     }
+    /**
+     * Retrieves a list of usage charges.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-07/recurring_application_charges/{recurring_application_charge_id}/usage_charges.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_get_recurring_application_charges_param_charge_usage`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/billing/usagecharge#index-2020-07
+     */
+    pub fn deprecated_202007_get_recurring_application_charges_param_charge_usage_request<'a>(
+        &'a self,
+        recurring_application_charge_id: &'a str,
+    ) -> BillingDeprecated202007GetRecurringApplicationChargesParamChargeUsageRequest<'a> {
+        BillingDeprecated202007GetRecurringApplicationChargesParamChargeUsageRequest {
+            client: self,
+            recurring_application_charge_id,
+            fields: None,
+        }
+    }
     /**
      * Creates a usage charge.
      *
@@ -2958,6 +6526,30 @@ impl Billing {
             )
             .await
     }
+    /**
+     * Retrieves a single charge.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-07/recurring_application_charges/{recurring_application_charge_id}/usage_charges/{usage_charge_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_get_recurring_application_charges_param_charge_usage_billing`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/billing/usagecharge#show-2020-07
+     */
+    pub fn deprecated_202007_get_recurring_application_charges_param_charge_usage_billing_request<
+        'a,
+    >(
+        &'a self,
+        recurring_application_charge_id: &'a str,
+        usage_charge_id: &'a str,
+    ) -> BillingDeprecated202007GetRecurringApplicationChargesParamChargeUsageBillingRequest<'a>
+    {
+        BillingDeprecated202007GetRecurringApplicationChargesParamChargeUsageBillingRequest {
+            client: self,
+            recurring_application_charge_id,
+            usage_charge_id,
+            fields: None,
+        }
+    }
     /**
      * Retrieves a list of usage charges.
      *
@@ -2998,6 +6590,25 @@ impl Billing {
             )
             .await
     }
+    /**
+     * Retrieves a list of usage charges.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-10/recurring_application_charges/{recurring_application_charge_id}/usage_charges.json` endpoint.
+     *
+     * As opposed to `get_recurring_application_charges_param_charge_usage`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/billing/usagecharge#index-2020-10
+     */
+    pub fn get_recurring_application_charges_param_charge_usage_request<'a>(
+        &'a self,
+        recurring_application_charge_id: &'a str,
+    ) -> BillingGetRecurringApplicationChargesParamChargeUsageRequest<'a> {
+        BillingGetRecurringApplicationChargesParamChargeUsageRequest {
+            client: self,
+            recurring_application_charge_id,
+            fields: None,
+        }
+    }
     /**
      * Creates a usage charge.
      *
@@ -3074,6 +6685,27 @@ impl Billing {
             )
             .await
     }
+    /**
+     * Retrieves a single charge.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-10/recurring_application_charges/{recurring_application_charge_id}/usage_charges/{usage_charge_id}.json` endpoint.
+     *
+     * As opposed to `get_recurring_application_charges_param_charge_usage_billing`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/billing/usagecharge#show-2020-10
+     */
+    pub fn get_recurring_application_charges_param_charge_usage_billing_request<'a>(
+        &'a self,
+        recurring_application_charge_id: &'a str,
+        usage_charge_id: &'a str,
+    ) -> BillingGetRecurringApplicationChargesParamChargeUsageBillingRequest<'a> {
+        BillingGetRecurringApplicationChargesParamChargeUsageBillingRequest {
+            client: self,
+            recurring_application_charge_id,
+            usage_charge_id,
+            fields: None,
+        }
+    }
     /**
      * Retrieves a list of usage charges.
      *
@@ -3114,6 +6746,25 @@ impl Billing {
             )
             .await
     }
+    /**
+     * Retrieves a list of usage charges.
+     *
+     * This function performs a `GET` to the `/admin/api/2021-01/recurring_application_charges/{recurring_application_charge_id}/usage_charges.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_get_recurring_application_charges_param_charge_usage`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/billing/usagecharge#index-2021-01
+     */
+    pub fn deprecated_202101_get_recurring_application_charges_param_charge_usage_request<'a>(
+        &'a self,
+        recurring_application_charge_id: &'a str,
+    ) -> BillingDeprecated202101GetRecurringApplicationChargesParamChargeUsageRequest<'a> {
+        BillingDeprecated202101GetRecurringApplicationChargesParamChargeUsageRequest {
+            client: self,
+            recurring_application_charge_id,
+            fields: None,
+        }
+    }
     /**
      * Creates a usage charge.
      *
@@ -3190,6 +6841,30 @@ impl Billing {
             )
             .await
     }
+    /**
+     * Retrieves a single charge.
+     *
+     * This function performs a `GET` to the `/admin/api/2021-01/recurring_application_charges/{recurring_application_charge_id}/usage_charges/{usage_charge_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_get_recurring_application_charges_param_charge_usage_billing`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/billing/usagecharge#show-2021-01
+     */
+    pub fn deprecated_202101_get_recurring_application_charges_param_charge_usage_billing_request<
+        'a,
+    >(
+        &'a self,
+        recurring_application_charge_id: &'a str,
+        usage_charge_id: &'a str,
+    ) -> BillingDeprecated202101GetRecurringApplicationChargesParamChargeUsageBillingRequest<'a>
+    {
+        BillingDeprecated202101GetRecurringApplicationChargesParamChargeUsageBillingRequest {
+            client: self,
+            recurring_application_charge_id,
+            usage_charge_id,
+            fields: None,
+        }
+    }
     /**
      * Retrieves a list of usage charges.
      *
@@ -3230,6 +6905,25 @@ impl Billing {
             )
             .await
     }
+    /**
+     * Retrieves a list of usage charges.
+     *
+     * This function performs a `GET` to the `/admin/api/unstable/recurring_application_charges/{recurring_application_charge_id}/usage_charges.json` endpoint.
+     *
+     * As opposed to `deprecated_unstable_get_recurring_application_charges_param_charge_usage`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/billing/usagecharge#index-unstable
+     */
+    pub fn deprecated_unstable_get_recurring_application_charges_param_charge_usage_request<'a>(
+        &'a self,
+        recurring_application_charge_id: &'a str,
+    ) -> BillingDeprecatedUnstableGetRecurringApplicationChargesParamChargeUsageRequest<'a> {
+        BillingDeprecatedUnstableGetRecurringApplicationChargesParamChargeUsageRequest {
+            client: self,
+            recurring_application_charge_id,
+            fields: None,
+        }
+    }
     /**
      * Creates a usage charge.
      *
@@ -3306,4 +7000,28 @@ impl Billing {
             )
             .await
     }
+    /**
+     * Retrieves a single charge.
+     *
+     * This function performs a `GET` to the `/admin/api/unstable/recurring_application_charges/{recurring_application_charge_id}/usage_charges/{usage_charge_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_unstable_get_recurring_application_charges_param_charge_usage_billing`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/billing/usagecharge#show-unstable
+     */
+    pub fn deprecated_unstable_get_recurring_application_charges_param_charge_usage_billing_request<
+        'a,
+    >(
+        &'a self,
+        recurring_application_charge_id: &'a str,
+        usage_charge_id: &'a str,
+    ) -> BillingDeprecatedUnstableGetRecurringApplicationChargesParamChargeUsageBillingRequest<'a>
+    {
+        BillingDeprecatedUnstableGetRecurringApplicationChargesParamChargeUsageBillingRequest {
+            client: self,
+            recurring_application_charge_id,
+            usage_charge_id,
+            fields: None,
+        }
+    }
 }
@@ -0,0 +1,161 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::access::Access`].
+pub struct Access {
+    inner: crate::access::Access,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Access {
+    pub(crate) fn new(inner: crate::access::Access, runtime: Arc<tokio::runtime::Runtime>) -> Self {
+        Access { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::access::Access::get_admin_oauth_scope`].
+    pub fn get_admin_oauth_scope(&self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.get_admin_oauth_scope())
+    }
+    /// The blocking version of [`crate::access::Access::deprecated_202001_get_storefront_token`].
+    pub fn deprecated_202001_get_storefront_token(&self) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202001_get_storefront_token())
+    }
+    /// The blocking version of [`crate::access::Access::deprecated_202001_create_storefront_tokens`].
+    pub fn deprecated_202001_create_storefront_tokens(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202001_create_storefront_tokens(body))
+    }
+    /// The blocking version of [`crate::access::Access::deprecated_202001_delete_storefront_tokens_param_token`].
+    pub fn deprecated_202001_delete_storefront_tokens_param_token(
+        &self,
+        storefront_access_token_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_delete_storefront_tokens_param_token(storefront_access_token_id),
+        )
+    }
+    /// The blocking version of [`crate::access::Access::deprecated_202004_get_storefront_token`].
+    pub fn deprecated_202004_get_storefront_token(&self) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202004_get_storefront_token())
+    }
+    /// The blocking version of [`crate::access::Access::deprecated_202004_create_storefront_tokens`].
+    pub fn deprecated_202004_create_storefront_tokens(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202004_create_storefront_tokens(body))
+    }
+    /// The blocking version of [`crate::access::Access::deprecated_202004_delete_storefront_tokens_param_token`].
+    pub fn deprecated_202004_delete_storefront_tokens_param_token(
+        &self,
+        storefront_access_token_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_delete_storefront_tokens_param_token(storefront_access_token_id),
+        )
+    }
+    /// The blocking version of [`crate::access::Access::deprecated_202007_get_storefront_token`].
+    pub fn deprecated_202007_get_storefront_token(&self) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202007_get_storefront_token())
+    }
+    /// The blocking version of [`crate::access::Access::deprecated_202007_create_storefront_tokens`].
+    pub fn deprecated_202007_create_storefront_tokens(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202007_create_storefront_tokens(body))
+    }
+    /// The blocking version of [`crate::access::Access::deprecated_202007_delete_storefront_tokens_param_token`].
+    pub fn deprecated_202007_delete_storefront_tokens_param_token(
+        &self,
+        storefront_access_token_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_delete_storefront_tokens_param_token(storefront_access_token_id),
+        )
+    }
+    /// The blocking version of [`crate::access::Access::get_storefront_token`].
+    pub fn get_storefront_token(&self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.get_storefront_token())
+    }
+    /// The blocking version of [`crate::access::Access::create_storefront_tokens`].
+    pub fn create_storefront_tokens(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.create_storefront_tokens(body))
+    }
+    /// The blocking version of [`crate::access::Access::delete_storefront_tokens_param_token`].
+    pub fn delete_storefront_tokens_param_token(
+        &self,
+        storefront_access_token_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .delete_storefront_tokens_param_token(storefront_access_token_id),
+        )
+    }
+    /// The blocking version of [`crate::access::Access::deprecated_202101_get_storefront_token`].
+    pub fn deprecated_202101_get_storefront_token(&self) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202101_get_storefront_token())
+    }
+    /// The blocking version of [`crate::access::Access::deprecated_202101_create_storefront_tokens`].
+    pub fn deprecated_202101_create_storefront_tokens(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202101_create_storefront_tokens(body))
+    }
+    /// The blocking version of [`crate::access::Access::deprecated_202101_delete_storefront_tokens_param_token`].
+    pub fn deprecated_202101_delete_storefront_tokens_param_token(
+        &self,
+        storefront_access_token_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_delete_storefront_tokens_param_token(storefront_access_token_id),
+        )
+    }
+    /// The blocking version of [`crate::access::Access::deprecated_unstable_get_storefront_token`].
+    pub fn deprecated_unstable_get_storefront_token(&self) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_unstable_get_storefront_token())
+    }
+    /// The blocking version of [`crate::access::Access::deprecated_unstable_create_storefront_tokens`].
+    pub fn deprecated_unstable_create_storefront_tokens(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_create_storefront_tokens(body),
+        )
+    }
+    /// The blocking version of [`crate::access::Access::deprecated_unstable_delete_storefront_tokens_param_token`].
+    pub fn deprecated_unstable_delete_storefront_tokens_param_token(
+        &self,
+        storefront_access_token_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_delete_storefront_tokens_param_token(
+                    storefront_access_token_id,
+                ),
+        )
+    }
+}
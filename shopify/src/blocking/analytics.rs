@@ -0,0 +1,1134 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Analytics::deprecated_202001_get_report`], set with a builder.
+pub struct AnalyticsDeprecated202001GetReportRequest<'a> {
+    inner: crate::analytics::AnalyticsDeprecated202001GetReportRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> AnalyticsDeprecated202001GetReportRequest<'a> {
+    /// Sets the `ids` query parameter.
+    pub fn ids(mut self, ids: &'a str) -> Self {
+        self.inner = self.inner.ids(ids);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.inner = self.inner.updated_at_min(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.inner = self.inner.updated_at_max(updated_at_max);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Analytics::deprecated_202001_create_reports`], set with a builder.
+pub struct AnalyticsDeprecated202001CreateReportsRequest<'a> {
+    inner: crate::analytics::AnalyticsDeprecated202001CreateReportsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> AnalyticsDeprecated202001CreateReportsRequest<'a> {
+    /// Sets the `name` query parameter.
+    pub fn name(mut self, name: &'a str) -> Self {
+        self.inner = self.inner.name(name);
+        self
+    }
+
+    /// Sets the `shopify_ql` query parameter.
+    pub fn shopify_ql(mut self, shopify_ql: &'a str) -> Self {
+        self.inner = self.inner.shopify_ql(shopify_ql);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Analytics::deprecated_202001_get_reports_param_report`], set with a builder.
+pub struct AnalyticsDeprecated202001GetReportsParamReportRequest<'a> {
+    inner: crate::analytics::AnalyticsDeprecated202001GetReportsParamReportRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> AnalyticsDeprecated202001GetReportsParamReportRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Analytics::deprecated_202004_get_report`], set with a builder.
+pub struct AnalyticsDeprecated202004GetReportRequest<'a> {
+    inner: crate::analytics::AnalyticsDeprecated202004GetReportRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> AnalyticsDeprecated202004GetReportRequest<'a> {
+    /// Sets the `ids` query parameter.
+    pub fn ids(mut self, ids: &'a str) -> Self {
+        self.inner = self.inner.ids(ids);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.inner = self.inner.updated_at_min(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.inner = self.inner.updated_at_max(updated_at_max);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Analytics::deprecated_202004_create_reports`], set with a builder.
+pub struct AnalyticsDeprecated202004CreateReportsRequest<'a> {
+    inner: crate::analytics::AnalyticsDeprecated202004CreateReportsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> AnalyticsDeprecated202004CreateReportsRequest<'a> {
+    /// Sets the `name` query parameter.
+    pub fn name(mut self, name: &'a str) -> Self {
+        self.inner = self.inner.name(name);
+        self
+    }
+
+    /// Sets the `shopify_ql` query parameter.
+    pub fn shopify_ql(mut self, shopify_ql: &'a str) -> Self {
+        self.inner = self.inner.shopify_ql(shopify_ql);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Analytics::deprecated_202004_get_reports_param_report`], set with a builder.
+pub struct AnalyticsDeprecated202004GetReportsParamReportRequest<'a> {
+    inner: crate::analytics::AnalyticsDeprecated202004GetReportsParamReportRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> AnalyticsDeprecated202004GetReportsParamReportRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Analytics::deprecated_202007_get_report`], set with a builder.
+pub struct AnalyticsDeprecated202007GetReportRequest<'a> {
+    inner: crate::analytics::AnalyticsDeprecated202007GetReportRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> AnalyticsDeprecated202007GetReportRequest<'a> {
+    /// Sets the `ids` query parameter.
+    pub fn ids(mut self, ids: &'a str) -> Self {
+        self.inner = self.inner.ids(ids);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.inner = self.inner.updated_at_min(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.inner = self.inner.updated_at_max(updated_at_max);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Analytics::deprecated_202007_create_reports`], set with a builder.
+pub struct AnalyticsDeprecated202007CreateReportsRequest<'a> {
+    inner: crate::analytics::AnalyticsDeprecated202007CreateReportsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> AnalyticsDeprecated202007CreateReportsRequest<'a> {
+    /// Sets the `name` query parameter.
+    pub fn name(mut self, name: &'a str) -> Self {
+        self.inner = self.inner.name(name);
+        self
+    }
+
+    /// Sets the `shopify_ql` query parameter.
+    pub fn shopify_ql(mut self, shopify_ql: &'a str) -> Self {
+        self.inner = self.inner.shopify_ql(shopify_ql);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Analytics::deprecated_202007_get_reports_param_report`], set with a builder.
+pub struct AnalyticsDeprecated202007GetReportsParamReportRequest<'a> {
+    inner: crate::analytics::AnalyticsDeprecated202007GetReportsParamReportRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> AnalyticsDeprecated202007GetReportsParamReportRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Analytics::get_report`], set with a builder.
+pub struct AnalyticsGetReportRequest<'a> {
+    inner: crate::analytics::AnalyticsGetReportRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> AnalyticsGetReportRequest<'a> {
+    /// Sets the `ids` query parameter.
+    pub fn ids(mut self, ids: &'a str) -> Self {
+        self.inner = self.inner.ids(ids);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.inner = self.inner.updated_at_min(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.inner = self.inner.updated_at_max(updated_at_max);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Analytics::create_reports`], set with a builder.
+pub struct AnalyticsCreateReportsRequest<'a> {
+    inner: crate::analytics::AnalyticsCreateReportsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> AnalyticsCreateReportsRequest<'a> {
+    /// Sets the `name` query parameter.
+    pub fn name(mut self, name: &'a str) -> Self {
+        self.inner = self.inner.name(name);
+        self
+    }
+
+    /// Sets the `shopify_ql` query parameter.
+    pub fn shopify_ql(mut self, shopify_ql: &'a str) -> Self {
+        self.inner = self.inner.shopify_ql(shopify_ql);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Analytics::get_reports_param_report`], set with a builder.
+pub struct AnalyticsGetReportsParamReportRequest<'a> {
+    inner: crate::analytics::AnalyticsGetReportsParamReportRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> AnalyticsGetReportsParamReportRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Analytics::deprecated_202101_get_report`], set with a builder.
+pub struct AnalyticsDeprecated202101GetReportRequest<'a> {
+    inner: crate::analytics::AnalyticsDeprecated202101GetReportRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> AnalyticsDeprecated202101GetReportRequest<'a> {
+    /// Sets the `ids` query parameter.
+    pub fn ids(mut self, ids: &'a str) -> Self {
+        self.inner = self.inner.ids(ids);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.inner = self.inner.updated_at_min(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.inner = self.inner.updated_at_max(updated_at_max);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Analytics::deprecated_202101_create_reports`], set with a builder.
+pub struct AnalyticsDeprecated202101CreateReportsRequest<'a> {
+    inner: crate::analytics::AnalyticsDeprecated202101CreateReportsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> AnalyticsDeprecated202101CreateReportsRequest<'a> {
+    /// Sets the `name` query parameter.
+    pub fn name(mut self, name: &'a str) -> Self {
+        self.inner = self.inner.name(name);
+        self
+    }
+
+    /// Sets the `shopify_ql` query parameter.
+    pub fn shopify_ql(mut self, shopify_ql: &'a str) -> Self {
+        self.inner = self.inner.shopify_ql(shopify_ql);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Analytics::deprecated_202101_get_reports_param_report`], set with a builder.
+pub struct AnalyticsDeprecated202101GetReportsParamReportRequest<'a> {
+    inner: crate::analytics::AnalyticsDeprecated202101GetReportsParamReportRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> AnalyticsDeprecated202101GetReportsParamReportRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Analytics::deprecated_unstable_get_report`], set with a builder.
+pub struct AnalyticsDeprecatedUnstableGetReportRequest<'a> {
+    inner: crate::analytics::AnalyticsDeprecatedUnstableGetReportRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> AnalyticsDeprecatedUnstableGetReportRequest<'a> {
+    /// Sets the `ids` query parameter.
+    pub fn ids(mut self, ids: &'a str) -> Self {
+        self.inner = self.inner.ids(ids);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.inner = self.inner.updated_at_min(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.inner = self.inner.updated_at_max(updated_at_max);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Analytics::deprecated_unstable_create_reports`], set with a builder.
+pub struct AnalyticsDeprecatedUnstableCreateReportsRequest<'a> {
+    inner: crate::analytics::AnalyticsDeprecatedUnstableCreateReportsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> AnalyticsDeprecatedUnstableCreateReportsRequest<'a> {
+    /// Sets the `name` query parameter.
+    pub fn name(mut self, name: &'a str) -> Self {
+        self.inner = self.inner.name(name);
+        self
+    }
+
+    /// Sets the `shopify_ql` query parameter.
+    pub fn shopify_ql(mut self, shopify_ql: &'a str) -> Self {
+        self.inner = self.inner.shopify_ql(shopify_ql);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Analytics::deprecated_unstable_get_reports_param_report`], set with a builder.
+pub struct AnalyticsDeprecatedUnstableGetReportsParamReportRequest<'a> {
+    inner: crate::analytics::AnalyticsDeprecatedUnstableGetReportsParamReportRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> AnalyticsDeprecatedUnstableGetReportsParamReportRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::analytics::Analytics`].
+pub struct Analytics {
+    inner: crate::analytics::Analytics,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Analytics {
+    pub(crate) fn new(
+        inner: crate::analytics::Analytics,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Analytics { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::analytics::Analytics::deprecated_202001_get_report`].
+    pub fn deprecated_202001_get_report(
+        &self,
+        ids: &str,
+        limit: &str,
+        since_id: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202001_get_report(
+                ids,
+                limit,
+                since_id,
+                updated_at_min,
+                updated_at_max,
+                fields,
+            ))
+    }
+    /// The blocking version of [`crate::analytics::Analytics::deprecated_202001_get_report_request`].
+    pub fn deprecated_202001_get_report_request(
+        &self,
+    ) -> AnalyticsDeprecated202001GetReportRequest<'_> {
+        AnalyticsDeprecated202001GetReportRequest {
+            inner: self.inner.deprecated_202001_get_report_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::analytics::Analytics::deprecated_202001_create_reports`].
+    pub fn deprecated_202001_create_reports(
+        &self,
+        name: &str,
+        shopify_ql: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_create_reports(name, shopify_ql, body),
+        )
+    }
+    /// The blocking version of [`crate::analytics::Analytics::deprecated_202001_create_reports_request`].
+    pub fn deprecated_202001_create_reports_request<'a>(
+        &'a self,
+        body: &'a serde_json::Value,
+    ) -> AnalyticsDeprecated202001CreateReportsRequest<'a> {
+        AnalyticsDeprecated202001CreateReportsRequest {
+            inner: self.inner.deprecated_202001_create_reports_request(body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::analytics::Analytics::deprecated_202001_get_reports_param_report`].
+    pub fn deprecated_202001_get_reports_param_report(
+        &self,
+        report_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_get_reports_param_report(report_id, fields),
+        )
+    }
+    /// The blocking version of [`crate::analytics::Analytics::deprecated_202001_get_reports_param_report_request`].
+    pub fn deprecated_202001_get_reports_param_report_request<'a>(
+        &'a self,
+        report_id: &'a str,
+    ) -> AnalyticsDeprecated202001GetReportsParamReportRequest<'a> {
+        AnalyticsDeprecated202001GetReportsParamReportRequest {
+            inner: self
+                .inner
+                .deprecated_202001_get_reports_param_report_request(report_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::analytics::Analytics::deprecated_202001_update_reports_param_report`].
+    pub fn deprecated_202001_update_reports_param_report(
+        &self,
+        report_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_update_reports_param_report(report_id, body),
+        )
+    }
+    /// The blocking version of [`crate::analytics::Analytics::deprecated_202001_delete_reports_param_report`].
+    pub fn deprecated_202001_delete_reports_param_report(
+        &self,
+        report_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_delete_reports_param_report(report_id),
+        )
+    }
+    /// The blocking version of [`crate::analytics::Analytics::deprecated_202004_get_report`].
+    pub fn deprecated_202004_get_report(
+        &self,
+        ids: &str,
+        limit: &str,
+        since_id: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202004_get_report(
+                ids,
+                limit,
+                since_id,
+                updated_at_min,
+                updated_at_max,
+                fields,
+            ))
+    }
+    /// The blocking version of [`crate::analytics::Analytics::deprecated_202004_get_report_request`].
+    pub fn deprecated_202004_get_report_request(
+        &self,
+    ) -> AnalyticsDeprecated202004GetReportRequest<'_> {
+        AnalyticsDeprecated202004GetReportRequest {
+            inner: self.inner.deprecated_202004_get_report_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::analytics::Analytics::deprecated_202004_create_reports`].
+    pub fn deprecated_202004_create_reports(
+        &self,
+        name: &str,
+        shopify_ql: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_create_reports(name, shopify_ql, body),
+        )
+    }
+    /// The blocking version of [`crate::analytics::Analytics::deprecated_202004_create_reports_request`].
+    pub fn deprecated_202004_create_reports_request<'a>(
+        &'a self,
+        body: &'a serde_json::Value,
+    ) -> AnalyticsDeprecated202004CreateReportsRequest<'a> {
+        AnalyticsDeprecated202004CreateReportsRequest {
+            inner: self.inner.deprecated_202004_create_reports_request(body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::analytics::Analytics::deprecated_202004_get_reports_param_report`].
+    pub fn deprecated_202004_get_reports_param_report(
+        &self,
+        report_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_get_reports_param_report(report_id, fields),
+        )
+    }
+    /// The blocking version of [`crate::analytics::Analytics::deprecated_202004_get_reports_param_report_request`].
+    pub fn deprecated_202004_get_reports_param_report_request<'a>(
+        &'a self,
+        report_id: &'a str,
+    ) -> AnalyticsDeprecated202004GetReportsParamReportRequest<'a> {
+        AnalyticsDeprecated202004GetReportsParamReportRequest {
+            inner: self
+                .inner
+                .deprecated_202004_get_reports_param_report_request(report_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::analytics::Analytics::deprecated_202004_update_reports_param_report`].
+    pub fn deprecated_202004_update_reports_param_report(
+        &self,
+        report_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_update_reports_param_report(report_id, body),
+        )
+    }
+    /// The blocking version of [`crate::analytics::Analytics::deprecated_202004_delete_reports_param_report`].
+    pub fn deprecated_202004_delete_reports_param_report(
+        &self,
+        report_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_delete_reports_param_report(report_id),
+        )
+    }
+    /// The blocking version of [`crate::analytics::Analytics::deprecated_202007_get_report`].
+    pub fn deprecated_202007_get_report(
+        &self,
+        ids: &str,
+        limit: &str,
+        since_id: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202007_get_report(
+                ids,
+                limit,
+                since_id,
+                updated_at_min,
+                updated_at_max,
+                fields,
+            ))
+    }
+    /// The blocking version of [`crate::analytics::Analytics::deprecated_202007_get_report_request`].
+    pub fn deprecated_202007_get_report_request(
+        &self,
+    ) -> AnalyticsDeprecated202007GetReportRequest<'_> {
+        AnalyticsDeprecated202007GetReportRequest {
+            inner: self.inner.deprecated_202007_get_report_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::analytics::Analytics::deprecated_202007_create_reports`].
+    pub fn deprecated_202007_create_reports(
+        &self,
+        name: &str,
+        shopify_ql: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_create_reports(name, shopify_ql, body),
+        )
+    }
+    /// The blocking version of [`crate::analytics::Analytics::deprecated_202007_create_reports_request`].
+    pub fn deprecated_202007_create_reports_request<'a>(
+        &'a self,
+        body: &'a serde_json::Value,
+    ) -> AnalyticsDeprecated202007CreateReportsRequest<'a> {
+        AnalyticsDeprecated202007CreateReportsRequest {
+            inner: self.inner.deprecated_202007_create_reports_request(body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::analytics::Analytics::deprecated_202007_get_reports_param_report`].
+    pub fn deprecated_202007_get_reports_param_report(
+        &self,
+        report_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_get_reports_param_report(report_id, fields),
+        )
+    }
+    /// The blocking version of [`crate::analytics::Analytics::deprecated_202007_get_reports_param_report_request`].
+    pub fn deprecated_202007_get_reports_param_report_request<'a>(
+        &'a self,
+        report_id: &'a str,
+    ) -> AnalyticsDeprecated202007GetReportsParamReportRequest<'a> {
+        AnalyticsDeprecated202007GetReportsParamReportRequest {
+            inner: self
+                .inner
+                .deprecated_202007_get_reports_param_report_request(report_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::analytics::Analytics::deprecated_202007_update_reports_param_report`].
+    pub fn deprecated_202007_update_reports_param_report(
+        &self,
+        report_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_update_reports_param_report(report_id, body),
+        )
+    }
+    /// The blocking version of [`crate::analytics::Analytics::deprecated_202007_delete_reports_param_report`].
+    pub fn deprecated_202007_delete_reports_param_report(
+        &self,
+        report_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_delete_reports_param_report(report_id),
+        )
+    }
+    /// The blocking version of [`crate::analytics::Analytics::get_report`].
+    pub fn get_report(
+        &self,
+        ids: &str,
+        limit: &str,
+        since_id: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.get_report(
+            ids,
+            limit,
+            since_id,
+            updated_at_min,
+            updated_at_max,
+            fields,
+        ))
+    }
+    /// The blocking version of [`crate::analytics::Analytics::get_report_request`].
+    pub fn get_report_request(&self) -> AnalyticsGetReportRequest<'_> {
+        AnalyticsGetReportRequest {
+            inner: self.inner.get_report_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::analytics::Analytics::create_reports`].
+    pub fn create_reports(
+        &self,
+        name: &str,
+        shopify_ql: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.create_reports(name, shopify_ql, body))
+    }
+    /// The blocking version of [`crate::analytics::Analytics::create_reports_request`].
+    pub fn create_reports_request<'a>(
+        &'a self,
+        body: &'a serde_json::Value,
+    ) -> AnalyticsCreateReportsRequest<'a> {
+        AnalyticsCreateReportsRequest {
+            inner: self.inner.create_reports_request(body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::analytics::Analytics::get_reports_param_report`].
+    pub fn get_reports_param_report(
+        &self,
+        report_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.get_reports_param_report(report_id, fields))
+    }
+    /// The blocking version of [`crate::analytics::Analytics::get_reports_param_report_request`].
+    pub fn get_reports_param_report_request<'a>(
+        &'a self,
+        report_id: &'a str,
+    ) -> AnalyticsGetReportsParamReportRequest<'a> {
+        AnalyticsGetReportsParamReportRequest {
+            inner: self.inner.get_reports_param_report_request(report_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::analytics::Analytics::update_reports_param_report`].
+    pub fn update_reports_param_report(
+        &self,
+        report_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.update_reports_param_report(report_id, body))
+    }
+    /// The blocking version of [`crate::analytics::Analytics::delete_reports_param_report`].
+    pub fn delete_reports_param_report(
+        &self,
+        report_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete_reports_param_report(report_id))
+    }
+    /// The blocking version of [`crate::analytics::Analytics::deprecated_202101_get_report`].
+    pub fn deprecated_202101_get_report(
+        &self,
+        ids: &str,
+        limit: &str,
+        since_id: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202101_get_report(
+                ids,
+                limit,
+                since_id,
+                updated_at_min,
+                updated_at_max,
+                fields,
+            ))
+    }
+    /// The blocking version of [`crate::analytics::Analytics::deprecated_202101_get_report_request`].
+    pub fn deprecated_202101_get_report_request(
+        &self,
+    ) -> AnalyticsDeprecated202101GetReportRequest<'_> {
+        AnalyticsDeprecated202101GetReportRequest {
+            inner: self.inner.deprecated_202101_get_report_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::analytics::Analytics::deprecated_202101_create_reports`].
+    pub fn deprecated_202101_create_reports(
+        &self,
+        name: &str,
+        shopify_ql: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_create_reports(name, shopify_ql, body),
+        )
+    }
+    /// The blocking version of [`crate::analytics::Analytics::deprecated_202101_create_reports_request`].
+    pub fn deprecated_202101_create_reports_request<'a>(
+        &'a self,
+        body: &'a serde_json::Value,
+    ) -> AnalyticsDeprecated202101CreateReportsRequest<'a> {
+        AnalyticsDeprecated202101CreateReportsRequest {
+            inner: self.inner.deprecated_202101_create_reports_request(body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::analytics::Analytics::deprecated_202101_get_reports_param_report`].
+    pub fn deprecated_202101_get_reports_param_report(
+        &self,
+        report_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_get_reports_param_report(report_id, fields),
+        )
+    }
+    /// The blocking version of [`crate::analytics::Analytics::deprecated_202101_get_reports_param_report_request`].
+    pub fn deprecated_202101_get_reports_param_report_request<'a>(
+        &'a self,
+        report_id: &'a str,
+    ) -> AnalyticsDeprecated202101GetReportsParamReportRequest<'a> {
+        AnalyticsDeprecated202101GetReportsParamReportRequest {
+            inner: self
+                .inner
+                .deprecated_202101_get_reports_param_report_request(report_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::analytics::Analytics::deprecated_202101_update_reports_param_report`].
+    pub fn deprecated_202101_update_reports_param_report(
+        &self,
+        report_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_update_reports_param_report(report_id, body),
+        )
+    }
+    /// The blocking version of [`crate::analytics::Analytics::deprecated_202101_delete_reports_param_report`].
+    pub fn deprecated_202101_delete_reports_param_report(
+        &self,
+        report_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_delete_reports_param_report(report_id),
+        )
+    }
+    /// The blocking version of [`crate::analytics::Analytics::deprecated_unstable_get_report`].
+    pub fn deprecated_unstable_get_report(
+        &self,
+        ids: &str,
+        limit: &str,
+        since_id: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_unstable_get_report(
+                ids,
+                limit,
+                since_id,
+                updated_at_min,
+                updated_at_max,
+                fields,
+            ))
+    }
+    /// The blocking version of [`crate::analytics::Analytics::deprecated_unstable_get_report_request`].
+    pub fn deprecated_unstable_get_report_request(
+        &self,
+    ) -> AnalyticsDeprecatedUnstableGetReportRequest<'_> {
+        AnalyticsDeprecatedUnstableGetReportRequest {
+            inner: self.inner.deprecated_unstable_get_report_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::analytics::Analytics::deprecated_unstable_create_reports`].
+    pub fn deprecated_unstable_create_reports(
+        &self,
+        name: &str,
+        shopify_ql: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_create_reports(name, shopify_ql, body),
+        )
+    }
+    /// The blocking version of [`crate::analytics::Analytics::deprecated_unstable_create_reports_request`].
+    pub fn deprecated_unstable_create_reports_request<'a>(
+        &'a self,
+        body: &'a serde_json::Value,
+    ) -> AnalyticsDeprecatedUnstableCreateReportsRequest<'a> {
+        AnalyticsDeprecatedUnstableCreateReportsRequest {
+            inner: self.inner.deprecated_unstable_create_reports_request(body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::analytics::Analytics::deprecated_unstable_get_reports_param_report`].
+    pub fn deprecated_unstable_get_reports_param_report(
+        &self,
+        report_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_get_reports_param_report(report_id, fields),
+        )
+    }
+    /// The blocking version of [`crate::analytics::Analytics::deprecated_unstable_get_reports_param_report_request`].
+    pub fn deprecated_unstable_get_reports_param_report_request<'a>(
+        &'a self,
+        report_id: &'a str,
+    ) -> AnalyticsDeprecatedUnstableGetReportsParamReportRequest<'a> {
+        AnalyticsDeprecatedUnstableGetReportsParamReportRequest {
+            inner: self
+                .inner
+                .deprecated_unstable_get_reports_param_report_request(report_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::analytics::Analytics::deprecated_unstable_update_reports_param_report`].
+    pub fn deprecated_unstable_update_reports_param_report(
+        &self,
+        report_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_update_reports_param_report(report_id, body),
+        )
+    }
+    /// The blocking version of [`crate::analytics::Analytics::deprecated_unstable_delete_reports_param_report`].
+    pub fn deprecated_unstable_delete_reports_param_report(
+        &self,
+        report_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_delete_reports_param_report(report_id),
+        )
+    }
+}
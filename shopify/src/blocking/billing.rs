@@ -0,0 +1,2931 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Billing::deprecated_202001_get_application_charge`], set with a builder.
+pub struct BillingDeprecated202001GetApplicationChargeRequest<'a> {
+    inner: crate::billing::BillingDeprecated202001GetApplicationChargeRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> BillingDeprecated202001GetApplicationChargeRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Billing::deprecated_202001_get_application_charges_param_charge`], set with a builder.
+pub struct BillingDeprecated202001GetApplicationChargesParamChargeRequest<'a> {
+    inner: crate::billing::BillingDeprecated202001GetApplicationChargesParamChargeRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> BillingDeprecated202001GetApplicationChargesParamChargeRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Billing::deprecated_202004_get_application_charge`], set with a builder.
+pub struct BillingDeprecated202004GetApplicationChargeRequest<'a> {
+    inner: crate::billing::BillingDeprecated202004GetApplicationChargeRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> BillingDeprecated202004GetApplicationChargeRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Billing::deprecated_202004_get_application_charges_param_charge`], set with a builder.
+pub struct BillingDeprecated202004GetApplicationChargesParamChargeRequest<'a> {
+    inner: crate::billing::BillingDeprecated202004GetApplicationChargesParamChargeRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> BillingDeprecated202004GetApplicationChargesParamChargeRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Billing::deprecated_202007_get_application_charge`], set with a builder.
+pub struct BillingDeprecated202007GetApplicationChargeRequest<'a> {
+    inner: crate::billing::BillingDeprecated202007GetApplicationChargeRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> BillingDeprecated202007GetApplicationChargeRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Billing::deprecated_202007_get_application_charges_param_charge`], set with a builder.
+pub struct BillingDeprecated202007GetApplicationChargesParamChargeRequest<'a> {
+    inner: crate::billing::BillingDeprecated202007GetApplicationChargesParamChargeRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> BillingDeprecated202007GetApplicationChargesParamChargeRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Billing::get_application_charge`], set with a builder.
+pub struct BillingGetApplicationChargeRequest<'a> {
+    inner: crate::billing::BillingGetApplicationChargeRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> BillingGetApplicationChargeRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Billing::get_application_charges_param_charge`], set with a builder.
+pub struct BillingGetApplicationChargesParamChargeRequest<'a> {
+    inner: crate::billing::BillingGetApplicationChargesParamChargeRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> BillingGetApplicationChargesParamChargeRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Billing::deprecated_202101_get_application_charge`], set with a builder.
+pub struct BillingDeprecated202101GetApplicationChargeRequest<'a> {
+    inner: crate::billing::BillingDeprecated202101GetApplicationChargeRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> BillingDeprecated202101GetApplicationChargeRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Billing::deprecated_202101_get_application_charges_param_charge`], set with a builder.
+pub struct BillingDeprecated202101GetApplicationChargesParamChargeRequest<'a> {
+    inner: crate::billing::BillingDeprecated202101GetApplicationChargesParamChargeRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> BillingDeprecated202101GetApplicationChargesParamChargeRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Billing::deprecated_unstable_get_application_charge`], set with a builder.
+pub struct BillingDeprecatedUnstableGetApplicationChargeRequest<'a> {
+    inner: crate::billing::BillingDeprecatedUnstableGetApplicationChargeRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> BillingDeprecatedUnstableGetApplicationChargeRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Billing::deprecated_unstable_get_application_charges_param_charge`], set with a builder.
+pub struct BillingDeprecatedUnstableGetApplicationChargesParamChargeRequest<'a> {
+    inner: crate::billing::BillingDeprecatedUnstableGetApplicationChargesParamChargeRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> BillingDeprecatedUnstableGetApplicationChargesParamChargeRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Billing::deprecated_202001_get_application_credit`], set with a builder.
+pub struct BillingDeprecated202001GetApplicationCreditRequest<'a> {
+    inner: crate::billing::BillingDeprecated202001GetApplicationCreditRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> BillingDeprecated202001GetApplicationCreditRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Billing::deprecated_202001_get_application_credits_param_credit`], set with a builder.
+pub struct BillingDeprecated202001GetApplicationCreditsParamCreditRequest<'a> {
+    inner: crate::billing::BillingDeprecated202001GetApplicationCreditsParamCreditRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> BillingDeprecated202001GetApplicationCreditsParamCreditRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Billing::deprecated_202004_get_application_credit`], set with a builder.
+pub struct BillingDeprecated202004GetApplicationCreditRequest<'a> {
+    inner: crate::billing::BillingDeprecated202004GetApplicationCreditRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> BillingDeprecated202004GetApplicationCreditRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Billing::deprecated_202004_get_application_credits_param_credit`], set with a builder.
+pub struct BillingDeprecated202004GetApplicationCreditsParamCreditRequest<'a> {
+    inner: crate::billing::BillingDeprecated202004GetApplicationCreditsParamCreditRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> BillingDeprecated202004GetApplicationCreditsParamCreditRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Billing::deprecated_202007_get_application_credit`], set with a builder.
+pub struct BillingDeprecated202007GetApplicationCreditRequest<'a> {
+    inner: crate::billing::BillingDeprecated202007GetApplicationCreditRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> BillingDeprecated202007GetApplicationCreditRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Billing::deprecated_202007_get_application_credits_param_credit`], set with a builder.
+pub struct BillingDeprecated202007GetApplicationCreditsParamCreditRequest<'a> {
+    inner: crate::billing::BillingDeprecated202007GetApplicationCreditsParamCreditRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> BillingDeprecated202007GetApplicationCreditsParamCreditRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Billing::get_application_credit`], set with a builder.
+pub struct BillingGetApplicationCreditRequest<'a> {
+    inner: crate::billing::BillingGetApplicationCreditRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> BillingGetApplicationCreditRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Billing::get_application_credits_param_credit`], set with a builder.
+pub struct BillingGetApplicationCreditsParamCreditRequest<'a> {
+    inner: crate::billing::BillingGetApplicationCreditsParamCreditRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> BillingGetApplicationCreditsParamCreditRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Billing::deprecated_202101_get_application_credit`], set with a builder.
+pub struct BillingDeprecated202101GetApplicationCreditRequest<'a> {
+    inner: crate::billing::BillingDeprecated202101GetApplicationCreditRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> BillingDeprecated202101GetApplicationCreditRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Billing::deprecated_202101_get_application_credits_param_credit`], set with a builder.
+pub struct BillingDeprecated202101GetApplicationCreditsParamCreditRequest<'a> {
+    inner: crate::billing::BillingDeprecated202101GetApplicationCreditsParamCreditRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> BillingDeprecated202101GetApplicationCreditsParamCreditRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Billing::deprecated_unstable_get_application_credit`], set with a builder.
+pub struct BillingDeprecatedUnstableGetApplicationCreditRequest<'a> {
+    inner: crate::billing::BillingDeprecatedUnstableGetApplicationCreditRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> BillingDeprecatedUnstableGetApplicationCreditRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Billing::deprecated_unstable_get_application_credits_param_credit`], set with a builder.
+pub struct BillingDeprecatedUnstableGetApplicationCreditsParamCreditRequest<'a> {
+    inner: crate::billing::BillingDeprecatedUnstableGetApplicationCreditsParamCreditRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> BillingDeprecatedUnstableGetApplicationCreditsParamCreditRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Billing::deprecated_202001_get_recurring_application_charge`], set with a builder.
+pub struct BillingDeprecated202001GetRecurringApplicationChargeRequest<'a> {
+    inner: crate::billing::BillingDeprecated202001GetRecurringApplicationChargeRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> BillingDeprecated202001GetRecurringApplicationChargeRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Billing::deprecated_202001_get_recurring_application_charges_param_charge`], set with a builder.
+pub struct BillingDeprecated202001GetRecurringApplicationChargesParamChargeRequest<'a> {
+    inner:
+        crate::billing::BillingDeprecated202001GetRecurringApplicationChargesParamChargeRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> BillingDeprecated202001GetRecurringApplicationChargesParamChargeRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Billing::deprecated_202001_update_recurring_application_charges_param_charge_customize`], set with a builder.
+pub struct BillingDeprecated202001UpdateRecurringApplicationChargesParamChargeCustomizeRequest<'a> {
+            inner: crate::billing::BillingDeprecated202001UpdateRecurringApplicationChargesParamChargeCustomizeRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a> BillingDeprecated202001UpdateRecurringApplicationChargesParamChargeCustomizeRequest<'a> {
+    /// Sets the `recurring_application_charge[capped_amount]` query parameter.
+    pub fn recurring_application_charge_capped_amount(
+        mut self,
+        recurring_application_charge_capped_amount: i64,
+    ) -> Self {
+        self.inner = self
+            .inner
+            .recurring_application_charge_capped_amount(recurring_application_charge_capped_amount);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Billing::deprecated_202004_get_recurring_application_charge`], set with a builder.
+pub struct BillingDeprecated202004GetRecurringApplicationChargeRequest<'a> {
+    inner: crate::billing::BillingDeprecated202004GetRecurringApplicationChargeRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> BillingDeprecated202004GetRecurringApplicationChargeRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Billing::deprecated_202004_get_recurring_application_charges_param_charge`], set with a builder.
+pub struct BillingDeprecated202004GetRecurringApplicationChargesParamChargeRequest<'a> {
+    inner:
+        crate::billing::BillingDeprecated202004GetRecurringApplicationChargesParamChargeRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> BillingDeprecated202004GetRecurringApplicationChargesParamChargeRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Billing::deprecated_202004_update_recurring_application_charges_param_charge_customize`], set with a builder.
+pub struct BillingDeprecated202004UpdateRecurringApplicationChargesParamChargeCustomizeRequest<'a> {
+            inner: crate::billing::BillingDeprecated202004UpdateRecurringApplicationChargesParamChargeCustomizeRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a> BillingDeprecated202004UpdateRecurringApplicationChargesParamChargeCustomizeRequest<'a> {
+    /// Sets the `recurring_application_charge[capped_amount]` query parameter.
+    pub fn recurring_application_charge_capped_amount(
+        mut self,
+        recurring_application_charge_capped_amount: i64,
+    ) -> Self {
+        self.inner = self
+            .inner
+            .recurring_application_charge_capped_amount(recurring_application_charge_capped_amount);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Billing::deprecated_202007_get_recurring_application_charge`], set with a builder.
+pub struct BillingDeprecated202007GetRecurringApplicationChargeRequest<'a> {
+    inner: crate::billing::BillingDeprecated202007GetRecurringApplicationChargeRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> BillingDeprecated202007GetRecurringApplicationChargeRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Billing::deprecated_202007_get_recurring_application_charges_param_charge`], set with a builder.
+pub struct BillingDeprecated202007GetRecurringApplicationChargesParamChargeRequest<'a> {
+    inner:
+        crate::billing::BillingDeprecated202007GetRecurringApplicationChargesParamChargeRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> BillingDeprecated202007GetRecurringApplicationChargesParamChargeRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Billing::deprecated_202007_update_recurring_application_charges_param_charge_customize`], set with a builder.
+pub struct BillingDeprecated202007UpdateRecurringApplicationChargesParamChargeCustomizeRequest<'a> {
+            inner: crate::billing::BillingDeprecated202007UpdateRecurringApplicationChargesParamChargeCustomizeRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a> BillingDeprecated202007UpdateRecurringApplicationChargesParamChargeCustomizeRequest<'a> {
+    /// Sets the `recurring_application_charge[capped_amount]` query parameter.
+    pub fn recurring_application_charge_capped_amount(
+        mut self,
+        recurring_application_charge_capped_amount: i64,
+    ) -> Self {
+        self.inner = self
+            .inner
+            .recurring_application_charge_capped_amount(recurring_application_charge_capped_amount);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Billing::get_recurring_application_charge`], set with a builder.
+pub struct BillingGetRecurringApplicationChargeRequest<'a> {
+    inner: crate::billing::BillingGetRecurringApplicationChargeRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> BillingGetRecurringApplicationChargeRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Billing::get_recurring_application_charges_param_charge`], set with a builder.
+pub struct BillingGetRecurringApplicationChargesParamChargeRequest<'a> {
+    inner: crate::billing::BillingGetRecurringApplicationChargesParamChargeRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> BillingGetRecurringApplicationChargesParamChargeRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Billing::update_recurring_application_charges_param_charge_customize`], set with a builder.
+pub struct BillingUpdateRecurringApplicationChargesParamChargeCustomizeRequest<'a> {
+    inner: crate::billing::BillingUpdateRecurringApplicationChargesParamChargeCustomizeRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> BillingUpdateRecurringApplicationChargesParamChargeCustomizeRequest<'a> {
+    /// Sets the `recurring_application_charge[capped_amount]` query parameter.
+    pub fn recurring_application_charge_capped_amount(
+        mut self,
+        recurring_application_charge_capped_amount: i64,
+    ) -> Self {
+        self.inner = self
+            .inner
+            .recurring_application_charge_capped_amount(recurring_application_charge_capped_amount);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Billing::deprecated_202101_get_recurring_application_charge`], set with a builder.
+pub struct BillingDeprecated202101GetRecurringApplicationChargeRequest<'a> {
+    inner: crate::billing::BillingDeprecated202101GetRecurringApplicationChargeRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> BillingDeprecated202101GetRecurringApplicationChargeRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Billing::deprecated_202101_get_recurring_application_charges_param_charge`], set with a builder.
+pub struct BillingDeprecated202101GetRecurringApplicationChargesParamChargeRequest<'a> {
+    inner:
+        crate::billing::BillingDeprecated202101GetRecurringApplicationChargesParamChargeRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> BillingDeprecated202101GetRecurringApplicationChargesParamChargeRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Billing::deprecated_202101_update_recurring_application_charges_param_charge_customize`], set with a builder.
+pub struct BillingDeprecated202101UpdateRecurringApplicationChargesParamChargeCustomizeRequest<'a> {
+            inner: crate::billing::BillingDeprecated202101UpdateRecurringApplicationChargesParamChargeCustomizeRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a> BillingDeprecated202101UpdateRecurringApplicationChargesParamChargeCustomizeRequest<'a> {
+    /// Sets the `recurring_application_charge[capped_amount]` query parameter.
+    pub fn recurring_application_charge_capped_amount(
+        mut self,
+        recurring_application_charge_capped_amount: i64,
+    ) -> Self {
+        self.inner = self
+            .inner
+            .recurring_application_charge_capped_amount(recurring_application_charge_capped_amount);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Billing::deprecated_unstable_get_recurring_application_charge`], set with a builder.
+pub struct BillingDeprecatedUnstableGetRecurringApplicationChargeRequest<'a> {
+    inner: crate::billing::BillingDeprecatedUnstableGetRecurringApplicationChargeRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> BillingDeprecatedUnstableGetRecurringApplicationChargeRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Billing::deprecated_unstable_get_recurring_application_charges_param_charge`], set with a builder.
+pub struct BillingDeprecatedUnstableGetRecurringApplicationChargesParamChargeRequest<'a> {
+    inner:
+        crate::billing::BillingDeprecatedUnstableGetRecurringApplicationChargesParamChargeRequest<
+            'a,
+        >,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> BillingDeprecatedUnstableGetRecurringApplicationChargesParamChargeRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Billing::deprecated_unstable_update_recurring_application_charges_param_charge_customize`], set with a builder.
+pub struct BillingDeprecatedUnstableUpdateRecurringApplicationChargesParamChargeCustomizeRequest<'a> {
+            inner: crate::billing::BillingDeprecatedUnstableUpdateRecurringApplicationChargesParamChargeCustomizeRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a> BillingDeprecatedUnstableUpdateRecurringApplicationChargesParamChargeCustomizeRequest<'a> {
+    /// Sets the `recurring_application_charge[capped_amount]` query parameter.
+    pub fn recurring_application_charge_capped_amount(
+        mut self,
+        recurring_application_charge_capped_amount: i64,
+    ) -> Self {
+        self.inner = self
+            .inner
+            .recurring_application_charge_capped_amount(recurring_application_charge_capped_amount);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Billing::deprecated_202001_get_recurring_application_charges_param_charge_usage`], set with a builder.
+pub struct BillingDeprecated202001GetRecurringApplicationChargesParamChargeUsageRequest<'a> {
+            inner: crate::billing::BillingDeprecated202001GetRecurringApplicationChargesParamChargeUsageRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a> BillingDeprecated202001GetRecurringApplicationChargesParamChargeUsageRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Billing::deprecated_202001_get_recurring_application_charges_param_charge_usage_billing`], set with a builder.
+pub struct BillingDeprecated202001GetRecurringApplicationChargesParamChargeUsageBillingRequest<'a> {
+            inner: crate::billing::BillingDeprecated202001GetRecurringApplicationChargesParamChargeUsageBillingRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a> BillingDeprecated202001GetRecurringApplicationChargesParamChargeUsageBillingRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Billing::deprecated_202004_get_recurring_application_charges_param_charge_usage`], set with a builder.
+pub struct BillingDeprecated202004GetRecurringApplicationChargesParamChargeUsageRequest<'a> {
+            inner: crate::billing::BillingDeprecated202004GetRecurringApplicationChargesParamChargeUsageRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a> BillingDeprecated202004GetRecurringApplicationChargesParamChargeUsageRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Billing::deprecated_202004_get_recurring_application_charges_param_charge_usage_billing`], set with a builder.
+pub struct BillingDeprecated202004GetRecurringApplicationChargesParamChargeUsageBillingRequest<'a> {
+            inner: crate::billing::BillingDeprecated202004GetRecurringApplicationChargesParamChargeUsageBillingRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a> BillingDeprecated202004GetRecurringApplicationChargesParamChargeUsageBillingRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Billing::deprecated_202007_get_recurring_application_charges_param_charge_usage`], set with a builder.
+pub struct BillingDeprecated202007GetRecurringApplicationChargesParamChargeUsageRequest<'a> {
+            inner: crate::billing::BillingDeprecated202007GetRecurringApplicationChargesParamChargeUsageRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a> BillingDeprecated202007GetRecurringApplicationChargesParamChargeUsageRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Billing::deprecated_202007_get_recurring_application_charges_param_charge_usage_billing`], set with a builder.
+pub struct BillingDeprecated202007GetRecurringApplicationChargesParamChargeUsageBillingRequest<'a> {
+            inner: crate::billing::BillingDeprecated202007GetRecurringApplicationChargesParamChargeUsageBillingRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a> BillingDeprecated202007GetRecurringApplicationChargesParamChargeUsageBillingRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Billing::get_recurring_application_charges_param_charge_usage`], set with a builder.
+pub struct BillingGetRecurringApplicationChargesParamChargeUsageRequest<'a> {
+    inner: crate::billing::BillingGetRecurringApplicationChargesParamChargeUsageRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> BillingGetRecurringApplicationChargesParamChargeUsageRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Billing::get_recurring_application_charges_param_charge_usage_billing`], set with a builder.
+pub struct BillingGetRecurringApplicationChargesParamChargeUsageBillingRequest<'a> {
+    inner: crate::billing::BillingGetRecurringApplicationChargesParamChargeUsageBillingRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> BillingGetRecurringApplicationChargesParamChargeUsageBillingRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Billing::deprecated_202101_get_recurring_application_charges_param_charge_usage`], set with a builder.
+pub struct BillingDeprecated202101GetRecurringApplicationChargesParamChargeUsageRequest<'a> {
+            inner: crate::billing::BillingDeprecated202101GetRecurringApplicationChargesParamChargeUsageRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a> BillingDeprecated202101GetRecurringApplicationChargesParamChargeUsageRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Billing::deprecated_202101_get_recurring_application_charges_param_charge_usage_billing`], set with a builder.
+pub struct BillingDeprecated202101GetRecurringApplicationChargesParamChargeUsageBillingRequest<'a> {
+            inner: crate::billing::BillingDeprecated202101GetRecurringApplicationChargesParamChargeUsageBillingRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a> BillingDeprecated202101GetRecurringApplicationChargesParamChargeUsageBillingRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Billing::deprecated_unstable_get_recurring_application_charges_param_charge_usage`], set with a builder.
+pub struct BillingDeprecatedUnstableGetRecurringApplicationChargesParamChargeUsageRequest<'a> {
+            inner: crate::billing::BillingDeprecatedUnstableGetRecurringApplicationChargesParamChargeUsageRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a> BillingDeprecatedUnstableGetRecurringApplicationChargesParamChargeUsageRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Billing::deprecated_unstable_get_recurring_application_charges_param_charge_usage_billing`], set with a builder.
+pub struct BillingDeprecatedUnstableGetRecurringApplicationChargesParamChargeUsageBillingRequest<'a> {
+            inner: crate::billing::BillingDeprecatedUnstableGetRecurringApplicationChargesParamChargeUsageBillingRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a> BillingDeprecatedUnstableGetRecurringApplicationChargesParamChargeUsageBillingRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::billing::Billing`].
+pub struct Billing {
+    inner: crate::billing::Billing,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Billing {
+    pub(crate) fn new(
+        inner: crate::billing::Billing,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Billing { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::billing::Billing::deprecated_202001_get_application_charge`].
+    pub fn deprecated_202001_get_application_charge(
+        &self,
+        since_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_get_application_charge(since_id, fields),
+        )
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_202001_get_application_charge_request`].
+    pub fn deprecated_202001_get_application_charge_request(
+        &self,
+    ) -> BillingDeprecated202001GetApplicationChargeRequest<'_> {
+        BillingDeprecated202001GetApplicationChargeRequest {
+            inner: self
+                .inner
+                .deprecated_202001_get_application_charge_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_202001_create_application_charges`].
+    pub fn deprecated_202001_create_application_charges(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_create_application_charges(body),
+        )
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_202001_get_application_charges_param_charge`].
+    pub fn deprecated_202001_get_application_charges_param_charge(
+        &self,
+        application_charge_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_get_application_charges_param_charge(
+                    application_charge_id,
+                    fields,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_202001_get_application_charges_param_charge_request`].
+    pub fn deprecated_202001_get_application_charges_param_charge_request<'a>(
+        &'a self,
+        application_charge_id: &'a str,
+    ) -> BillingDeprecated202001GetApplicationChargesParamChargeRequest<'a> {
+        BillingDeprecated202001GetApplicationChargesParamChargeRequest {
+            inner: self
+                .inner
+                .deprecated_202001_get_application_charges_param_charge_request(
+                    application_charge_id,
+                ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_202001_create_application_charges_param_charge_activate`].
+    pub fn deprecated_202001_create_application_charges_param_charge_activate(
+        &self,
+        application_charge_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_create_application_charges_param_charge_activate(
+                    application_charge_id,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_202004_get_application_charge`].
+    pub fn deprecated_202004_get_application_charge(
+        &self,
+        since_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_get_application_charge(since_id, fields),
+        )
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_202004_get_application_charge_request`].
+    pub fn deprecated_202004_get_application_charge_request(
+        &self,
+    ) -> BillingDeprecated202004GetApplicationChargeRequest<'_> {
+        BillingDeprecated202004GetApplicationChargeRequest {
+            inner: self
+                .inner
+                .deprecated_202004_get_application_charge_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_202004_create_application_charges`].
+    pub fn deprecated_202004_create_application_charges(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_create_application_charges(body),
+        )
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_202004_get_application_charges_param_charge`].
+    pub fn deprecated_202004_get_application_charges_param_charge(
+        &self,
+        application_charge_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_get_application_charges_param_charge(
+                    application_charge_id,
+                    fields,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_202004_get_application_charges_param_charge_request`].
+    pub fn deprecated_202004_get_application_charges_param_charge_request<'a>(
+        &'a self,
+        application_charge_id: &'a str,
+    ) -> BillingDeprecated202004GetApplicationChargesParamChargeRequest<'a> {
+        BillingDeprecated202004GetApplicationChargesParamChargeRequest {
+            inner: self
+                .inner
+                .deprecated_202004_get_application_charges_param_charge_request(
+                    application_charge_id,
+                ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_202004_create_application_charges_param_charge_activate`].
+    pub fn deprecated_202004_create_application_charges_param_charge_activate(
+        &self,
+        application_charge_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_create_application_charges_param_charge_activate(
+                    application_charge_id,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_202007_get_application_charge`].
+    pub fn deprecated_202007_get_application_charge(
+        &self,
+        since_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_get_application_charge(since_id, fields),
+        )
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_202007_get_application_charge_request`].
+    pub fn deprecated_202007_get_application_charge_request(
+        &self,
+    ) -> BillingDeprecated202007GetApplicationChargeRequest<'_> {
+        BillingDeprecated202007GetApplicationChargeRequest {
+            inner: self
+                .inner
+                .deprecated_202007_get_application_charge_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_202007_create_application_charges`].
+    pub fn deprecated_202007_create_application_charges(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_create_application_charges(body),
+        )
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_202007_get_application_charges_param_charge`].
+    pub fn deprecated_202007_get_application_charges_param_charge(
+        &self,
+        application_charge_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_get_application_charges_param_charge(
+                    application_charge_id,
+                    fields,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_202007_get_application_charges_param_charge_request`].
+    pub fn deprecated_202007_get_application_charges_param_charge_request<'a>(
+        &'a self,
+        application_charge_id: &'a str,
+    ) -> BillingDeprecated202007GetApplicationChargesParamChargeRequest<'a> {
+        BillingDeprecated202007GetApplicationChargesParamChargeRequest {
+            inner: self
+                .inner
+                .deprecated_202007_get_application_charges_param_charge_request(
+                    application_charge_id,
+                ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_202007_create_application_charges_param_charge_activate`].
+    pub fn deprecated_202007_create_application_charges_param_charge_activate(
+        &self,
+        application_charge_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_create_application_charges_param_charge_activate(
+                    application_charge_id,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::billing::Billing::get_application_charge`].
+    pub fn get_application_charge(
+        &self,
+        since_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.get_application_charge(since_id, fields))
+    }
+    /// The blocking version of [`crate::billing::Billing::get_application_charge_request`].
+    pub fn get_application_charge_request(&self) -> BillingGetApplicationChargeRequest<'_> {
+        BillingGetApplicationChargeRequest {
+            inner: self.inner.get_application_charge_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::billing::Billing::create_application_charges`].
+    pub fn create_application_charges(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.create_application_charges(body))
+    }
+    /// The blocking version of [`crate::billing::Billing::get_application_charges_param_charge`].
+    pub fn get_application_charges_param_charge(
+        &self,
+        application_charge_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .get_application_charges_param_charge(application_charge_id, fields),
+        )
+    }
+    /// The blocking version of [`crate::billing::Billing::get_application_charges_param_charge_request`].
+    pub fn get_application_charges_param_charge_request<'a>(
+        &'a self,
+        application_charge_id: &'a str,
+    ) -> BillingGetApplicationChargesParamChargeRequest<'a> {
+        BillingGetApplicationChargesParamChargeRequest {
+            inner: self
+                .inner
+                .get_application_charges_param_charge_request(application_charge_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::billing::Billing::create_application_charges_param_charge_activate`].
+    pub fn create_application_charges_param_charge_activate(
+        &self,
+        application_charge_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .create_application_charges_param_charge_activate(application_charge_id, body),
+        )
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_202101_get_application_charge`].
+    pub fn deprecated_202101_get_application_charge(
+        &self,
+        since_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_get_application_charge(since_id, fields),
+        )
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_202101_get_application_charge_request`].
+    pub fn deprecated_202101_get_application_charge_request(
+        &self,
+    ) -> BillingDeprecated202101GetApplicationChargeRequest<'_> {
+        BillingDeprecated202101GetApplicationChargeRequest {
+            inner: self
+                .inner
+                .deprecated_202101_get_application_charge_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_202101_create_application_charges`].
+    pub fn deprecated_202101_create_application_charges(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_create_application_charges(body),
+        )
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_202101_get_application_charges_param_charge`].
+    pub fn deprecated_202101_get_application_charges_param_charge(
+        &self,
+        application_charge_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_get_application_charges_param_charge(
+                    application_charge_id,
+                    fields,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_202101_get_application_charges_param_charge_request`].
+    pub fn deprecated_202101_get_application_charges_param_charge_request<'a>(
+        &'a self,
+        application_charge_id: &'a str,
+    ) -> BillingDeprecated202101GetApplicationChargesParamChargeRequest<'a> {
+        BillingDeprecated202101GetApplicationChargesParamChargeRequest {
+            inner: self
+                .inner
+                .deprecated_202101_get_application_charges_param_charge_request(
+                    application_charge_id,
+                ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_unstable_get_application_charge`].
+    pub fn deprecated_unstable_get_application_charge(
+        &self,
+        since_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_get_application_charge(since_id, fields),
+        )
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_unstable_get_application_charge_request`].
+    pub fn deprecated_unstable_get_application_charge_request(
+        &self,
+    ) -> BillingDeprecatedUnstableGetApplicationChargeRequest<'_> {
+        BillingDeprecatedUnstableGetApplicationChargeRequest {
+            inner: self
+                .inner
+                .deprecated_unstable_get_application_charge_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_unstable_create_application_charges`].
+    pub fn deprecated_unstable_create_application_charges(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_create_application_charges(body),
+        )
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_unstable_get_application_charges_param_charge`].
+    pub fn deprecated_unstable_get_application_charges_param_charge(
+        &self,
+        application_charge_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_get_application_charges_param_charge(
+                    application_charge_id,
+                    fields,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_unstable_get_application_charges_param_charge_request`].
+    pub fn deprecated_unstable_get_application_charges_param_charge_request<'a>(
+        &'a self,
+        application_charge_id: &'a str,
+    ) -> BillingDeprecatedUnstableGetApplicationChargesParamChargeRequest<'a> {
+        BillingDeprecatedUnstableGetApplicationChargesParamChargeRequest {
+            inner: self
+                .inner
+                .deprecated_unstable_get_application_charges_param_charge_request(
+                    application_charge_id,
+                ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_202001_get_application_credit`].
+    pub fn deprecated_202001_get_application_credit(
+        &self,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202001_get_application_credit(fields))
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_202001_get_application_credit_request`].
+    pub fn deprecated_202001_get_application_credit_request(
+        &self,
+    ) -> BillingDeprecated202001GetApplicationCreditRequest<'_> {
+        BillingDeprecated202001GetApplicationCreditRequest {
+            inner: self
+                .inner
+                .deprecated_202001_get_application_credit_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_202001_create_application_credits`].
+    pub fn deprecated_202001_create_application_credits(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_create_application_credits(body),
+        )
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_202001_get_application_credits_param_credit`].
+    pub fn deprecated_202001_get_application_credits_param_credit(
+        &self,
+        application_credit_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_get_application_credits_param_credit(
+                    application_credit_id,
+                    fields,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_202001_get_application_credits_param_credit_request`].
+    pub fn deprecated_202001_get_application_credits_param_credit_request<'a>(
+        &'a self,
+        application_credit_id: &'a str,
+    ) -> BillingDeprecated202001GetApplicationCreditsParamCreditRequest<'a> {
+        BillingDeprecated202001GetApplicationCreditsParamCreditRequest {
+            inner: self
+                .inner
+                .deprecated_202001_get_application_credits_param_credit_request(
+                    application_credit_id,
+                ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_202004_get_application_credit`].
+    pub fn deprecated_202004_get_application_credit(
+        &self,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202004_get_application_credit(fields))
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_202004_get_application_credit_request`].
+    pub fn deprecated_202004_get_application_credit_request(
+        &self,
+    ) -> BillingDeprecated202004GetApplicationCreditRequest<'_> {
+        BillingDeprecated202004GetApplicationCreditRequest {
+            inner: self
+                .inner
+                .deprecated_202004_get_application_credit_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_202004_create_application_credits`].
+    pub fn deprecated_202004_create_application_credits(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_create_application_credits(body),
+        )
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_202004_get_application_credits_param_credit`].
+    pub fn deprecated_202004_get_application_credits_param_credit(
+        &self,
+        application_credit_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_get_application_credits_param_credit(
+                    application_credit_id,
+                    fields,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_202004_get_application_credits_param_credit_request`].
+    pub fn deprecated_202004_get_application_credits_param_credit_request<'a>(
+        &'a self,
+        application_credit_id: &'a str,
+    ) -> BillingDeprecated202004GetApplicationCreditsParamCreditRequest<'a> {
+        BillingDeprecated202004GetApplicationCreditsParamCreditRequest {
+            inner: self
+                .inner
+                .deprecated_202004_get_application_credits_param_credit_request(
+                    application_credit_id,
+                ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_202007_get_application_credit`].
+    pub fn deprecated_202007_get_application_credit(
+        &self,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202007_get_application_credit(fields))
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_202007_get_application_credit_request`].
+    pub fn deprecated_202007_get_application_credit_request(
+        &self,
+    ) -> BillingDeprecated202007GetApplicationCreditRequest<'_> {
+        BillingDeprecated202007GetApplicationCreditRequest {
+            inner: self
+                .inner
+                .deprecated_202007_get_application_credit_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_202007_create_application_credits`].
+    pub fn deprecated_202007_create_application_credits(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_create_application_credits(body),
+        )
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_202007_get_application_credits_param_credit`].
+    pub fn deprecated_202007_get_application_credits_param_credit(
+        &self,
+        application_credit_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_get_application_credits_param_credit(
+                    application_credit_id,
+                    fields,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_202007_get_application_credits_param_credit_request`].
+    pub fn deprecated_202007_get_application_credits_param_credit_request<'a>(
+        &'a self,
+        application_credit_id: &'a str,
+    ) -> BillingDeprecated202007GetApplicationCreditsParamCreditRequest<'a> {
+        BillingDeprecated202007GetApplicationCreditsParamCreditRequest {
+            inner: self
+                .inner
+                .deprecated_202007_get_application_credits_param_credit_request(
+                    application_credit_id,
+                ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::billing::Billing::get_application_credit`].
+    pub fn get_application_credit(&self, fields: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.get_application_credit(fields))
+    }
+    /// The blocking version of [`crate::billing::Billing::get_application_credit_request`].
+    pub fn get_application_credit_request(&self) -> BillingGetApplicationCreditRequest<'_> {
+        BillingGetApplicationCreditRequest {
+            inner: self.inner.get_application_credit_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::billing::Billing::create_application_credits`].
+    pub fn create_application_credits(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.create_application_credits(body))
+    }
+    /// The blocking version of [`crate::billing::Billing::get_application_credits_param_credit`].
+    pub fn get_application_credits_param_credit(
+        &self,
+        application_credit_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .get_application_credits_param_credit(application_credit_id, fields),
+        )
+    }
+    /// The blocking version of [`crate::billing::Billing::get_application_credits_param_credit_request`].
+    pub fn get_application_credits_param_credit_request<'a>(
+        &'a self,
+        application_credit_id: &'a str,
+    ) -> BillingGetApplicationCreditsParamCreditRequest<'a> {
+        BillingGetApplicationCreditsParamCreditRequest {
+            inner: self
+                .inner
+                .get_application_credits_param_credit_request(application_credit_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_202101_get_application_credit`].
+    pub fn deprecated_202101_get_application_credit(
+        &self,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202101_get_application_credit(fields))
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_202101_get_application_credit_request`].
+    pub fn deprecated_202101_get_application_credit_request(
+        &self,
+    ) -> BillingDeprecated202101GetApplicationCreditRequest<'_> {
+        BillingDeprecated202101GetApplicationCreditRequest {
+            inner: self
+                .inner
+                .deprecated_202101_get_application_credit_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_202101_create_application_credits`].
+    pub fn deprecated_202101_create_application_credits(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_create_application_credits(body),
+        )
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_202101_get_application_credits_param_credit`].
+    pub fn deprecated_202101_get_application_credits_param_credit(
+        &self,
+        application_credit_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_get_application_credits_param_credit(
+                    application_credit_id,
+                    fields,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_202101_get_application_credits_param_credit_request`].
+    pub fn deprecated_202101_get_application_credits_param_credit_request<'a>(
+        &'a self,
+        application_credit_id: &'a str,
+    ) -> BillingDeprecated202101GetApplicationCreditsParamCreditRequest<'a> {
+        BillingDeprecated202101GetApplicationCreditsParamCreditRequest {
+            inner: self
+                .inner
+                .deprecated_202101_get_application_credits_param_credit_request(
+                    application_credit_id,
+                ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_unstable_get_application_credit`].
+    pub fn deprecated_unstable_get_application_credit(
+        &self,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_get_application_credit(fields),
+        )
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_unstable_get_application_credit_request`].
+    pub fn deprecated_unstable_get_application_credit_request(
+        &self,
+    ) -> BillingDeprecatedUnstableGetApplicationCreditRequest<'_> {
+        BillingDeprecatedUnstableGetApplicationCreditRequest {
+            inner: self
+                .inner
+                .deprecated_unstable_get_application_credit_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_unstable_create_application_credits`].
+    pub fn deprecated_unstable_create_application_credits(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_create_application_credits(body),
+        )
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_unstable_get_application_credits_param_credit`].
+    pub fn deprecated_unstable_get_application_credits_param_credit(
+        &self,
+        application_credit_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_get_application_credits_param_credit(
+                    application_credit_id,
+                    fields,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_unstable_get_application_credits_param_credit_request`].
+    pub fn deprecated_unstable_get_application_credits_param_credit_request<'a>(
+        &'a self,
+        application_credit_id: &'a str,
+    ) -> BillingDeprecatedUnstableGetApplicationCreditsParamCreditRequest<'a> {
+        BillingDeprecatedUnstableGetApplicationCreditsParamCreditRequest {
+            inner: self
+                .inner
+                .deprecated_unstable_get_application_credits_param_credit_request(
+                    application_credit_id,
+                ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_202001_get_recurring_application_charge`].
+    pub fn deprecated_202001_get_recurring_application_charge(
+        &self,
+        since_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_get_recurring_application_charge(since_id, fields),
+        )
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_202001_get_recurring_application_charge_request`].
+    pub fn deprecated_202001_get_recurring_application_charge_request(
+        &self,
+    ) -> BillingDeprecated202001GetRecurringApplicationChargeRequest<'_> {
+        BillingDeprecated202001GetRecurringApplicationChargeRequest {
+            inner: self
+                .inner
+                .deprecated_202001_get_recurring_application_charge_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_202001_create_recurring_application_charges`].
+    pub fn deprecated_202001_create_recurring_application_charges(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_create_recurring_application_charges(body),
+        )
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_202001_get_recurring_application_charges_param_charge`].
+    pub fn deprecated_202001_get_recurring_application_charges_param_charge(
+        &self,
+        recurring_application_charge_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_get_recurring_application_charges_param_charge(
+                    recurring_application_charge_id,
+                    fields,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_202001_get_recurring_application_charges_param_charge_request`].
+    pub fn deprecated_202001_get_recurring_application_charges_param_charge_request<'a>(
+        &'a self,
+        recurring_application_charge_id: &'a str,
+    ) -> BillingDeprecated202001GetRecurringApplicationChargesParamChargeRequest<'a> {
+        BillingDeprecated202001GetRecurringApplicationChargesParamChargeRequest {
+            inner: self
+                .inner
+                .deprecated_202001_get_recurring_application_charges_param_charge_request(
+                    recurring_application_charge_id,
+                ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_202001_delete_recurring_application_charges_param_charge`].
+    pub fn deprecated_202001_delete_recurring_application_charges_param_charge(
+        &self,
+        recurring_application_charge_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_delete_recurring_application_charges_param_charge(
+                    recurring_application_charge_id,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_202001_create_recurring_application_charges_param_charge_activate`].
+    pub fn deprecated_202001_create_recurring_application_charges_param_charge_activate(
+        &self,
+        recurring_application_charge_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_create_recurring_application_charges_param_charge_activate(
+                    recurring_application_charge_id,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_202001_update_recurring_application_charges_param_charge_customize`].
+    pub fn deprecated_202001_update_recurring_application_charges_param_charge_customize(
+        &self,
+        recurring_application_charge_id: &str,
+        recurring_application_charge_capped_amount: i64,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_update_recurring_application_charges_param_charge_customize(
+                    recurring_application_charge_id,
+                    recurring_application_charge_capped_amount,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_202001_update_recurring_application_charges_param_charge_customize_request`].
+    pub fn deprecated_202001_update_recurring_application_charges_param_charge_customize_request<
+        'a,
+    >(
+        &'a self,
+        recurring_application_charge_id: &'a str,
+    ) -> BillingDeprecated202001UpdateRecurringApplicationChargesParamChargeCustomizeRequest<'a>
+    {
+        BillingDeprecated202001UpdateRecurringApplicationChargesParamChargeCustomizeRequest { inner: self.inner.deprecated_202001_update_recurring_application_charges_param_charge_customize_request(recurring_application_charge_id), runtime: &self.runtime }
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_202004_get_recurring_application_charge`].
+    pub fn deprecated_202004_get_recurring_application_charge(
+        &self,
+        since_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_get_recurring_application_charge(since_id, fields),
+        )
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_202004_get_recurring_application_charge_request`].
+    pub fn deprecated_202004_get_recurring_application_charge_request(
+        &self,
+    ) -> BillingDeprecated202004GetRecurringApplicationChargeRequest<'_> {
+        BillingDeprecated202004GetRecurringApplicationChargeRequest {
+            inner: self
+                .inner
+                .deprecated_202004_get_recurring_application_charge_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_202004_create_recurring_application_charges`].
+    pub fn deprecated_202004_create_recurring_application_charges(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_create_recurring_application_charges(body),
+        )
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_202004_get_recurring_application_charges_param_charge`].
+    pub fn deprecated_202004_get_recurring_application_charges_param_charge(
+        &self,
+        recurring_application_charge_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_get_recurring_application_charges_param_charge(
+                    recurring_application_charge_id,
+                    fields,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_202004_get_recurring_application_charges_param_charge_request`].
+    pub fn deprecated_202004_get_recurring_application_charges_param_charge_request<'a>(
+        &'a self,
+        recurring_application_charge_id: &'a str,
+    ) -> BillingDeprecated202004GetRecurringApplicationChargesParamChargeRequest<'a> {
+        BillingDeprecated202004GetRecurringApplicationChargesParamChargeRequest {
+            inner: self
+                .inner
+                .deprecated_202004_get_recurring_application_charges_param_charge_request(
+                    recurring_application_charge_id,
+                ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_202004_delete_recurring_application_charges_param_charge`].
+    pub fn deprecated_202004_delete_recurring_application_charges_param_charge(
+        &self,
+        recurring_application_charge_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_delete_recurring_application_charges_param_charge(
+                    recurring_application_charge_id,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_202004_create_recurring_application_charges_param_charge_activate`].
+    pub fn deprecated_202004_create_recurring_application_charges_param_charge_activate(
+        &self,
+        recurring_application_charge_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_create_recurring_application_charges_param_charge_activate(
+                    recurring_application_charge_id,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_202004_update_recurring_application_charges_param_charge_customize`].
+    pub fn deprecated_202004_update_recurring_application_charges_param_charge_customize(
+        &self,
+        recurring_application_charge_id: &str,
+        recurring_application_charge_capped_amount: i64,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_update_recurring_application_charges_param_charge_customize(
+                    recurring_application_charge_id,
+                    recurring_application_charge_capped_amount,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_202004_update_recurring_application_charges_param_charge_customize_request`].
+    pub fn deprecated_202004_update_recurring_application_charges_param_charge_customize_request<
+        'a,
+    >(
+        &'a self,
+        recurring_application_charge_id: &'a str,
+    ) -> BillingDeprecated202004UpdateRecurringApplicationChargesParamChargeCustomizeRequest<'a>
+    {
+        BillingDeprecated202004UpdateRecurringApplicationChargesParamChargeCustomizeRequest { inner: self.inner.deprecated_202004_update_recurring_application_charges_param_charge_customize_request(recurring_application_charge_id), runtime: &self.runtime }
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_202007_get_recurring_application_charge`].
+    pub fn deprecated_202007_get_recurring_application_charge(
+        &self,
+        since_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_get_recurring_application_charge(since_id, fields),
+        )
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_202007_get_recurring_application_charge_request`].
+    pub fn deprecated_202007_get_recurring_application_charge_request(
+        &self,
+    ) -> BillingDeprecated202007GetRecurringApplicationChargeRequest<'_> {
+        BillingDeprecated202007GetRecurringApplicationChargeRequest {
+            inner: self
+                .inner
+                .deprecated_202007_get_recurring_application_charge_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_202007_create_recurring_application_charges`].
+    pub fn deprecated_202007_create_recurring_application_charges(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_create_recurring_application_charges(body),
+        )
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_202007_get_recurring_application_charges_param_charge`].
+    pub fn deprecated_202007_get_recurring_application_charges_param_charge(
+        &self,
+        recurring_application_charge_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_get_recurring_application_charges_param_charge(
+                    recurring_application_charge_id,
+                    fields,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_202007_get_recurring_application_charges_param_charge_request`].
+    pub fn deprecated_202007_get_recurring_application_charges_param_charge_request<'a>(
+        &'a self,
+        recurring_application_charge_id: &'a str,
+    ) -> BillingDeprecated202007GetRecurringApplicationChargesParamChargeRequest<'a> {
+        BillingDeprecated202007GetRecurringApplicationChargesParamChargeRequest {
+            inner: self
+                .inner
+                .deprecated_202007_get_recurring_application_charges_param_charge_request(
+                    recurring_application_charge_id,
+                ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_202007_delete_recurring_application_charges_param_charge`].
+    pub fn deprecated_202007_delete_recurring_application_charges_param_charge(
+        &self,
+        recurring_application_charge_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_delete_recurring_application_charges_param_charge(
+                    recurring_application_charge_id,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_202007_create_recurring_application_charges_param_charge_activate`].
+    pub fn deprecated_202007_create_recurring_application_charges_param_charge_activate(
+        &self,
+        recurring_application_charge_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_create_recurring_application_charges_param_charge_activate(
+                    recurring_application_charge_id,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_202007_update_recurring_application_charges_param_charge_customize`].
+    pub fn deprecated_202007_update_recurring_application_charges_param_charge_customize(
+        &self,
+        recurring_application_charge_id: &str,
+        recurring_application_charge_capped_amount: i64,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_update_recurring_application_charges_param_charge_customize(
+                    recurring_application_charge_id,
+                    recurring_application_charge_capped_amount,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_202007_update_recurring_application_charges_param_charge_customize_request`].
+    pub fn deprecated_202007_update_recurring_application_charges_param_charge_customize_request<
+        'a,
+    >(
+        &'a self,
+        recurring_application_charge_id: &'a str,
+    ) -> BillingDeprecated202007UpdateRecurringApplicationChargesParamChargeCustomizeRequest<'a>
+    {
+        BillingDeprecated202007UpdateRecurringApplicationChargesParamChargeCustomizeRequest { inner: self.inner.deprecated_202007_update_recurring_application_charges_param_charge_customize_request(recurring_application_charge_id), runtime: &self.runtime }
+    }
+    /// The blocking version of [`crate::billing::Billing::get_recurring_application_charge`].
+    pub fn get_recurring_application_charge(
+        &self,
+        since_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .get_recurring_application_charge(since_id, fields),
+        )
+    }
+    /// The blocking version of [`crate::billing::Billing::get_recurring_application_charge_request`].
+    pub fn get_recurring_application_charge_request(
+        &self,
+    ) -> BillingGetRecurringApplicationChargeRequest<'_> {
+        BillingGetRecurringApplicationChargeRequest {
+            inner: self.inner.get_recurring_application_charge_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::billing::Billing::create_recurring_application_charges`].
+    pub fn create_recurring_application_charges(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.create_recurring_application_charges(body))
+    }
+    /// The blocking version of [`crate::billing::Billing::get_recurring_application_charges_param_charge`].
+    pub fn get_recurring_application_charges_param_charge(
+        &self,
+        recurring_application_charge_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.get_recurring_application_charges_param_charge(
+                recurring_application_charge_id,
+                fields,
+            ))
+    }
+    /// The blocking version of [`crate::billing::Billing::get_recurring_application_charges_param_charge_request`].
+    pub fn get_recurring_application_charges_param_charge_request<'a>(
+        &'a self,
+        recurring_application_charge_id: &'a str,
+    ) -> BillingGetRecurringApplicationChargesParamChargeRequest<'a> {
+        BillingGetRecurringApplicationChargesParamChargeRequest {
+            inner: self
+                .inner
+                .get_recurring_application_charges_param_charge_request(
+                    recurring_application_charge_id,
+                ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::billing::Billing::delete_recurring_application_charges_param_charge`].
+    pub fn delete_recurring_application_charges_param_charge(
+        &self,
+        recurring_application_charge_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .delete_recurring_application_charges_param_charge(recurring_application_charge_id),
+        )
+    }
+    /// The blocking version of [`crate::billing::Billing::create_recurring_application_charges_param_charge_activate`].
+    pub fn create_recurring_application_charges_param_charge_activate(
+        &self,
+        recurring_application_charge_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .create_recurring_application_charges_param_charge_activate(
+                    recurring_application_charge_id,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::billing::Billing::update_recurring_application_charges_param_charge_customize`].
+    pub fn update_recurring_application_charges_param_charge_customize(
+        &self,
+        recurring_application_charge_id: &str,
+        recurring_application_charge_capped_amount: i64,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .update_recurring_application_charges_param_charge_customize(
+                    recurring_application_charge_id,
+                    recurring_application_charge_capped_amount,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::billing::Billing::update_recurring_application_charges_param_charge_customize_request`].
+    pub fn update_recurring_application_charges_param_charge_customize_request<'a>(
+        &'a self,
+        recurring_application_charge_id: &'a str,
+    ) -> BillingUpdateRecurringApplicationChargesParamChargeCustomizeRequest<'a> {
+        BillingUpdateRecurringApplicationChargesParamChargeCustomizeRequest {
+            inner: self
+                .inner
+                .update_recurring_application_charges_param_charge_customize_request(
+                    recurring_application_charge_id,
+                ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_202101_get_recurring_application_charge`].
+    pub fn deprecated_202101_get_recurring_application_charge(
+        &self,
+        since_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_get_recurring_application_charge(since_id, fields),
+        )
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_202101_get_recurring_application_charge_request`].
+    pub fn deprecated_202101_get_recurring_application_charge_request(
+        &self,
+    ) -> BillingDeprecated202101GetRecurringApplicationChargeRequest<'_> {
+        BillingDeprecated202101GetRecurringApplicationChargeRequest {
+            inner: self
+                .inner
+                .deprecated_202101_get_recurring_application_charge_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_202101_create_recurring_application_charges`].
+    pub fn deprecated_202101_create_recurring_application_charges(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_create_recurring_application_charges(body),
+        )
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_202101_get_recurring_application_charges_param_charge`].
+    pub fn deprecated_202101_get_recurring_application_charges_param_charge(
+        &self,
+        recurring_application_charge_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_get_recurring_application_charges_param_charge(
+                    recurring_application_charge_id,
+                    fields,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_202101_get_recurring_application_charges_param_charge_request`].
+    pub fn deprecated_202101_get_recurring_application_charges_param_charge_request<'a>(
+        &'a self,
+        recurring_application_charge_id: &'a str,
+    ) -> BillingDeprecated202101GetRecurringApplicationChargesParamChargeRequest<'a> {
+        BillingDeprecated202101GetRecurringApplicationChargesParamChargeRequest {
+            inner: self
+                .inner
+                .deprecated_202101_get_recurring_application_charges_param_charge_request(
+                    recurring_application_charge_id,
+                ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_202101_delete_recurring_application_charges_param_charge`].
+    pub fn deprecated_202101_delete_recurring_application_charges_param_charge(
+        &self,
+        recurring_application_charge_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_delete_recurring_application_charges_param_charge(
+                    recurring_application_charge_id,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_202101_update_recurring_application_charges_param_charge_customize`].
+    pub fn deprecated_202101_update_recurring_application_charges_param_charge_customize(
+        &self,
+        recurring_application_charge_id: &str,
+        recurring_application_charge_capped_amount: i64,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_update_recurring_application_charges_param_charge_customize(
+                    recurring_application_charge_id,
+                    recurring_application_charge_capped_amount,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_202101_update_recurring_application_charges_param_charge_customize_request`].
+    pub fn deprecated_202101_update_recurring_application_charges_param_charge_customize_request<
+        'a,
+    >(
+        &'a self,
+        recurring_application_charge_id: &'a str,
+    ) -> BillingDeprecated202101UpdateRecurringApplicationChargesParamChargeCustomizeRequest<'a>
+    {
+        BillingDeprecated202101UpdateRecurringApplicationChargesParamChargeCustomizeRequest { inner: self.inner.deprecated_202101_update_recurring_application_charges_param_charge_customize_request(recurring_application_charge_id), runtime: &self.runtime }
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_unstable_get_recurring_application_charge`].
+    pub fn deprecated_unstable_get_recurring_application_charge(
+        &self,
+        since_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_get_recurring_application_charge(since_id, fields),
+        )
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_unstable_get_recurring_application_charge_request`].
+    pub fn deprecated_unstable_get_recurring_application_charge_request(
+        &self,
+    ) -> BillingDeprecatedUnstableGetRecurringApplicationChargeRequest<'_> {
+        BillingDeprecatedUnstableGetRecurringApplicationChargeRequest {
+            inner: self
+                .inner
+                .deprecated_unstable_get_recurring_application_charge_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_unstable_create_recurring_application_charges`].
+    pub fn deprecated_unstable_create_recurring_application_charges(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_create_recurring_application_charges(body),
+        )
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_unstable_get_recurring_application_charges_param_charge`].
+    pub fn deprecated_unstable_get_recurring_application_charges_param_charge(
+        &self,
+        recurring_application_charge_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_get_recurring_application_charges_param_charge(
+                    recurring_application_charge_id,
+                    fields,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_unstable_get_recurring_application_charges_param_charge_request`].
+    pub fn deprecated_unstable_get_recurring_application_charges_param_charge_request<'a>(
+        &'a self,
+        recurring_application_charge_id: &'a str,
+    ) -> BillingDeprecatedUnstableGetRecurringApplicationChargesParamChargeRequest<'a> {
+        BillingDeprecatedUnstableGetRecurringApplicationChargesParamChargeRequest {
+            inner: self
+                .inner
+                .deprecated_unstable_get_recurring_application_charges_param_charge_request(
+                    recurring_application_charge_id,
+                ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_unstable_delete_recurring_application_charges_param_charge`].
+    pub fn deprecated_unstable_delete_recurring_application_charges_param_charge(
+        &self,
+        recurring_application_charge_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_delete_recurring_application_charges_param_charge(
+                    recurring_application_charge_id,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_unstable_update_recurring_application_charges_param_charge_customize`].
+    pub fn deprecated_unstable_update_recurring_application_charges_param_charge_customize(
+        &self,
+        recurring_application_charge_id: &str,
+        recurring_application_charge_capped_amount: i64,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_update_recurring_application_charges_param_charge_customize(
+                    recurring_application_charge_id,
+                    recurring_application_charge_capped_amount,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_unstable_update_recurring_application_charges_param_charge_customize_request`].
+    pub fn deprecated_unstable_update_recurring_application_charges_param_charge_customize_request<
+        'a,
+    >(
+        &'a self,
+        recurring_application_charge_id: &'a str,
+    ) -> BillingDeprecatedUnstableUpdateRecurringApplicationChargesParamChargeCustomizeRequest<'a>
+    {
+        BillingDeprecatedUnstableUpdateRecurringApplicationChargesParamChargeCustomizeRequest { inner: self.inner.deprecated_unstable_update_recurring_application_charges_param_charge_customize_request(recurring_application_charge_id), runtime: &self.runtime }
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_202001_get_recurring_application_charges_param_charge_usage`].
+    pub fn deprecated_202001_get_recurring_application_charges_param_charge_usage(
+        &self,
+        recurring_application_charge_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_get_recurring_application_charges_param_charge_usage(
+                    recurring_application_charge_id,
+                    fields,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_202001_get_recurring_application_charges_param_charge_usage_request`].
+    pub fn deprecated_202001_get_recurring_application_charges_param_charge_usage_request<'a>(
+        &'a self,
+        recurring_application_charge_id: &'a str,
+    ) -> BillingDeprecated202001GetRecurringApplicationChargesParamChargeUsageRequest<'a> {
+        BillingDeprecated202001GetRecurringApplicationChargesParamChargeUsageRequest {
+            inner: self
+                .inner
+                .deprecated_202001_get_recurring_application_charges_param_charge_usage_request(
+                    recurring_application_charge_id,
+                ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_202001_create_recurring_application_charges_param_charge_usage`].
+    pub fn deprecated_202001_create_recurring_application_charges_param_charge_usage(
+        &self,
+        recurring_application_charge_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_create_recurring_application_charges_param_charge_usage(
+                    recurring_application_charge_id,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_202001_get_recurring_application_charges_param_charge_usage_billing`].
+    pub fn deprecated_202001_get_recurring_application_charges_param_charge_usage_billing(
+        &self,
+        recurring_application_charge_id: &str,
+        usage_charge_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_get_recurring_application_charges_param_charge_usage_billing(
+                    recurring_application_charge_id,
+                    usage_charge_id,
+                    fields,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_202001_get_recurring_application_charges_param_charge_usage_billing_request`].
+    pub fn deprecated_202001_get_recurring_application_charges_param_charge_usage_billing_request<
+        'a,
+    >(
+        &'a self,
+        recurring_application_charge_id: &'a str,
+        usage_charge_id: &'a str,
+    ) -> BillingDeprecated202001GetRecurringApplicationChargesParamChargeUsageBillingRequest<'a>
+    {
+        BillingDeprecated202001GetRecurringApplicationChargesParamChargeUsageBillingRequest { inner: self.inner.deprecated_202001_get_recurring_application_charges_param_charge_usage_billing_request(recurring_application_charge_id, usage_charge_id), runtime: &self.runtime }
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_202004_get_recurring_application_charges_param_charge_usage`].
+    pub fn deprecated_202004_get_recurring_application_charges_param_charge_usage(
+        &self,
+        recurring_application_charge_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_get_recurring_application_charges_param_charge_usage(
+                    recurring_application_charge_id,
+                    fields,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_202004_get_recurring_application_charges_param_charge_usage_request`].
+    pub fn deprecated_202004_get_recurring_application_charges_param_charge_usage_request<'a>(
+        &'a self,
+        recurring_application_charge_id: &'a str,
+    ) -> BillingDeprecated202004GetRecurringApplicationChargesParamChargeUsageRequest<'a> {
+        BillingDeprecated202004GetRecurringApplicationChargesParamChargeUsageRequest {
+            inner: self
+                .inner
+                .deprecated_202004_get_recurring_application_charges_param_charge_usage_request(
+                    recurring_application_charge_id,
+                ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_202004_create_recurring_application_charges_param_charge_usage`].
+    pub fn deprecated_202004_create_recurring_application_charges_param_charge_usage(
+        &self,
+        recurring_application_charge_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_create_recurring_application_charges_param_charge_usage(
+                    recurring_application_charge_id,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_202004_get_recurring_application_charges_param_charge_usage_billing`].
+    pub fn deprecated_202004_get_recurring_application_charges_param_charge_usage_billing(
+        &self,
+        recurring_application_charge_id: &str,
+        usage_charge_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_get_recurring_application_charges_param_charge_usage_billing(
+                    recurring_application_charge_id,
+                    usage_charge_id,
+                    fields,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_202004_get_recurring_application_charges_param_charge_usage_billing_request`].
+    pub fn deprecated_202004_get_recurring_application_charges_param_charge_usage_billing_request<
+        'a,
+    >(
+        &'a self,
+        recurring_application_charge_id: &'a str,
+        usage_charge_id: &'a str,
+    ) -> BillingDeprecated202004GetRecurringApplicationChargesParamChargeUsageBillingRequest<'a>
+    {
+        BillingDeprecated202004GetRecurringApplicationChargesParamChargeUsageBillingRequest { inner: self.inner.deprecated_202004_get_recurring_application_charges_param_charge_usage_billing_request(recurring_application_charge_id, usage_charge_id), runtime: &self.runtime }
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_202007_get_recurring_application_charges_param_charge_usage`].
+    pub fn deprecated_202007_get_recurring_application_charges_param_charge_usage(
+        &self,
+        recurring_application_charge_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_get_recurring_application_charges_param_charge_usage(
+                    recurring_application_charge_id,
+                    fields,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_202007_get_recurring_application_charges_param_charge_usage_request`].
+    pub fn deprecated_202007_get_recurring_application_charges_param_charge_usage_request<'a>(
+        &'a self,
+        recurring_application_charge_id: &'a str,
+    ) -> BillingDeprecated202007GetRecurringApplicationChargesParamChargeUsageRequest<'a> {
+        BillingDeprecated202007GetRecurringApplicationChargesParamChargeUsageRequest {
+            inner: self
+                .inner
+                .deprecated_202007_get_recurring_application_charges_param_charge_usage_request(
+                    recurring_application_charge_id,
+                ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_202007_create_recurring_application_charges_param_charge_usage`].
+    pub fn deprecated_202007_create_recurring_application_charges_param_charge_usage(
+        &self,
+        recurring_application_charge_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_create_recurring_application_charges_param_charge_usage(
+                    recurring_application_charge_id,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_202007_get_recurring_application_charges_param_charge_usage_billing`].
+    pub fn deprecated_202007_get_recurring_application_charges_param_charge_usage_billing(
+        &self,
+        recurring_application_charge_id: &str,
+        usage_charge_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_get_recurring_application_charges_param_charge_usage_billing(
+                    recurring_application_charge_id,
+                    usage_charge_id,
+                    fields,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_202007_get_recurring_application_charges_param_charge_usage_billing_request`].
+    pub fn deprecated_202007_get_recurring_application_charges_param_charge_usage_billing_request<
+        'a,
+    >(
+        &'a self,
+        recurring_application_charge_id: &'a str,
+        usage_charge_id: &'a str,
+    ) -> BillingDeprecated202007GetRecurringApplicationChargesParamChargeUsageBillingRequest<'a>
+    {
+        BillingDeprecated202007GetRecurringApplicationChargesParamChargeUsageBillingRequest { inner: self.inner.deprecated_202007_get_recurring_application_charges_param_charge_usage_billing_request(recurring_application_charge_id, usage_charge_id), runtime: &self.runtime }
+    }
+    /// The blocking version of [`crate::billing::Billing::get_recurring_application_charges_param_charge_usage`].
+    pub fn get_recurring_application_charges_param_charge_usage(
+        &self,
+        recurring_application_charge_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .get_recurring_application_charges_param_charge_usage(
+                    recurring_application_charge_id,
+                    fields,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::billing::Billing::get_recurring_application_charges_param_charge_usage_request`].
+    pub fn get_recurring_application_charges_param_charge_usage_request<'a>(
+        &'a self,
+        recurring_application_charge_id: &'a str,
+    ) -> BillingGetRecurringApplicationChargesParamChargeUsageRequest<'a> {
+        BillingGetRecurringApplicationChargesParamChargeUsageRequest {
+            inner: self
+                .inner
+                .get_recurring_application_charges_param_charge_usage_request(
+                    recurring_application_charge_id,
+                ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::billing::Billing::create_recurring_application_charges_param_charge_usage`].
+    pub fn create_recurring_application_charges_param_charge_usage(
+        &self,
+        recurring_application_charge_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .create_recurring_application_charges_param_charge_usage(
+                    recurring_application_charge_id,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::billing::Billing::get_recurring_application_charges_param_charge_usage_billing`].
+    pub fn get_recurring_application_charges_param_charge_usage_billing(
+        &self,
+        recurring_application_charge_id: &str,
+        usage_charge_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .get_recurring_application_charges_param_charge_usage_billing(
+                    recurring_application_charge_id,
+                    usage_charge_id,
+                    fields,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::billing::Billing::get_recurring_application_charges_param_charge_usage_billing_request`].
+    pub fn get_recurring_application_charges_param_charge_usage_billing_request<'a>(
+        &'a self,
+        recurring_application_charge_id: &'a str,
+        usage_charge_id: &'a str,
+    ) -> BillingGetRecurringApplicationChargesParamChargeUsageBillingRequest<'a> {
+        BillingGetRecurringApplicationChargesParamChargeUsageBillingRequest {
+            inner: self
+                .inner
+                .get_recurring_application_charges_param_charge_usage_billing_request(
+                    recurring_application_charge_id,
+                    usage_charge_id,
+                ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_202101_get_recurring_application_charges_param_charge_usage`].
+    pub fn deprecated_202101_get_recurring_application_charges_param_charge_usage(
+        &self,
+        recurring_application_charge_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_get_recurring_application_charges_param_charge_usage(
+                    recurring_application_charge_id,
+                    fields,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_202101_get_recurring_application_charges_param_charge_usage_request`].
+    pub fn deprecated_202101_get_recurring_application_charges_param_charge_usage_request<'a>(
+        &'a self,
+        recurring_application_charge_id: &'a str,
+    ) -> BillingDeprecated202101GetRecurringApplicationChargesParamChargeUsageRequest<'a> {
+        BillingDeprecated202101GetRecurringApplicationChargesParamChargeUsageRequest {
+            inner: self
+                .inner
+                .deprecated_202101_get_recurring_application_charges_param_charge_usage_request(
+                    recurring_application_charge_id,
+                ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_202101_create_recurring_application_charges_param_charge_usage`].
+    pub fn deprecated_202101_create_recurring_application_charges_param_charge_usage(
+        &self,
+        recurring_application_charge_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_create_recurring_application_charges_param_charge_usage(
+                    recurring_application_charge_id,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_202101_get_recurring_application_charges_param_charge_usage_billing`].
+    pub fn deprecated_202101_get_recurring_application_charges_param_charge_usage_billing(
+        &self,
+        recurring_application_charge_id: &str,
+        usage_charge_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_get_recurring_application_charges_param_charge_usage_billing(
+                    recurring_application_charge_id,
+                    usage_charge_id,
+                    fields,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_202101_get_recurring_application_charges_param_charge_usage_billing_request`].
+    pub fn deprecated_202101_get_recurring_application_charges_param_charge_usage_billing_request<
+        'a,
+    >(
+        &'a self,
+        recurring_application_charge_id: &'a str,
+        usage_charge_id: &'a str,
+    ) -> BillingDeprecated202101GetRecurringApplicationChargesParamChargeUsageBillingRequest<'a>
+    {
+        BillingDeprecated202101GetRecurringApplicationChargesParamChargeUsageBillingRequest { inner: self.inner.deprecated_202101_get_recurring_application_charges_param_charge_usage_billing_request(recurring_application_charge_id, usage_charge_id), runtime: &self.runtime }
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_unstable_get_recurring_application_charges_param_charge_usage`].
+    pub fn deprecated_unstable_get_recurring_application_charges_param_charge_usage(
+        &self,
+        recurring_application_charge_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_get_recurring_application_charges_param_charge_usage(
+                    recurring_application_charge_id,
+                    fields,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_unstable_get_recurring_application_charges_param_charge_usage_request`].
+    pub fn deprecated_unstable_get_recurring_application_charges_param_charge_usage_request<'a>(
+        &'a self,
+        recurring_application_charge_id: &'a str,
+    ) -> BillingDeprecatedUnstableGetRecurringApplicationChargesParamChargeUsageRequest<'a> {
+        BillingDeprecatedUnstableGetRecurringApplicationChargesParamChargeUsageRequest {
+            inner: self
+                .inner
+                .deprecated_unstable_get_recurring_application_charges_param_charge_usage_request(
+                    recurring_application_charge_id,
+                ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_unstable_create_recurring_application_charges_param_charge_usage`].
+    pub fn deprecated_unstable_create_recurring_application_charges_param_charge_usage(
+        &self,
+        recurring_application_charge_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_create_recurring_application_charges_param_charge_usage(
+                    recurring_application_charge_id,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_unstable_get_recurring_application_charges_param_charge_usage_billing`].
+    pub fn deprecated_unstable_get_recurring_application_charges_param_charge_usage_billing(
+        &self,
+        recurring_application_charge_id: &str,
+        usage_charge_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_get_recurring_application_charges_param_charge_usage_billing(
+                    recurring_application_charge_id,
+                    usage_charge_id,
+                    fields,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::billing::Billing::deprecated_unstable_get_recurring_application_charges_param_charge_usage_billing_request`].
+    pub fn deprecated_unstable_get_recurring_application_charges_param_charge_usage_billing_request<
+        'a,
+    >(
+        &'a self,
+        recurring_application_charge_id: &'a str,
+        usage_charge_id: &'a str,
+    ) -> BillingDeprecatedUnstableGetRecurringApplicationChargesParamChargeUsageBillingRequest<'a>
+    {
+        BillingDeprecatedUnstableGetRecurringApplicationChargesParamChargeUsageBillingRequest { inner: self.inner.deprecated_unstable_get_recurring_application_charges_param_charge_usage_billing_request(recurring_application_charge_id, usage_charge_id), runtime: &self.runtime }
+    }
+}
@@ -0,0 +1,3569 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Customers::deprecated_202001_get`], set with a builder.
+pub struct CustomersDeprecated202001GetRequest<'a> {
+    inner: crate::customers::CustomersDeprecated202001GetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> CustomersDeprecated202001GetRequest<'a> {
+    /// Sets the `ids` query parameter.
+    pub fn ids(mut self, ids: &'a str) -> Self {
+        self.inner = self.inner.ids(ids);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.inner = self.inner.created_at_min(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.inner = self.inner.created_at_max(created_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.inner = self.inner.updated_at_min(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.inner = self.inner.updated_at_max(updated_at_max);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Customers::deprecated_202001_get_search`], set with a builder.
+pub struct CustomersDeprecated202001GetSearchRequest<'a> {
+    inner: crate::customers::CustomersDeprecated202001GetSearchRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> CustomersDeprecated202001GetSearchRequest<'a> {
+    /// Sets the `order` query parameter.
+    pub fn order(mut self, order: &'a str) -> Self {
+        self.inner = self.inner.order(order);
+        self
+    }
+
+    /// Sets the `query` query parameter.
+    pub fn query(mut self, query: &'a str) -> Self {
+        self.inner = self.inner.query(query);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Customers::deprecated_202001_get_param`], set with a builder.
+pub struct CustomersDeprecated202001GetParamRequest<'a> {
+    inner: crate::customers::CustomersDeprecated202001GetParamRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> CustomersDeprecated202001GetParamRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Customers::deprecated_202004_get`], set with a builder.
+pub struct CustomersDeprecated202004GetRequest<'a> {
+    inner: crate::customers::CustomersDeprecated202004GetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> CustomersDeprecated202004GetRequest<'a> {
+    /// Sets the `ids` query parameter.
+    pub fn ids(mut self, ids: &'a str) -> Self {
+        self.inner = self.inner.ids(ids);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.inner = self.inner.created_at_min(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.inner = self.inner.created_at_max(created_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.inner = self.inner.updated_at_min(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.inner = self.inner.updated_at_max(updated_at_max);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Customers::deprecated_202004_get_search`], set with a builder.
+pub struct CustomersDeprecated202004GetSearchRequest<'a> {
+    inner: crate::customers::CustomersDeprecated202004GetSearchRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> CustomersDeprecated202004GetSearchRequest<'a> {
+    /// Sets the `order` query parameter.
+    pub fn order(mut self, order: &'a str) -> Self {
+        self.inner = self.inner.order(order);
+        self
+    }
+
+    /// Sets the `query` query parameter.
+    pub fn query(mut self, query: &'a str) -> Self {
+        self.inner = self.inner.query(query);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Customers::deprecated_202004_get_param`], set with a builder.
+pub struct CustomersDeprecated202004GetParamRequest<'a> {
+    inner: crate::customers::CustomersDeprecated202004GetParamRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> CustomersDeprecated202004GetParamRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Customers::deprecated_202007_get`], set with a builder.
+pub struct CustomersDeprecated202007GetRequest<'a> {
+    inner: crate::customers::CustomersDeprecated202007GetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> CustomersDeprecated202007GetRequest<'a> {
+    /// Sets the `ids` query parameter.
+    pub fn ids(mut self, ids: &'a str) -> Self {
+        self.inner = self.inner.ids(ids);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.inner = self.inner.created_at_min(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.inner = self.inner.created_at_max(created_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.inner = self.inner.updated_at_min(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.inner = self.inner.updated_at_max(updated_at_max);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Customers::deprecated_202007_get_search`], set with a builder.
+pub struct CustomersDeprecated202007GetSearchRequest<'a> {
+    inner: crate::customers::CustomersDeprecated202007GetSearchRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> CustomersDeprecated202007GetSearchRequest<'a> {
+    /// Sets the `order` query parameter.
+    pub fn order(mut self, order: &'a str) -> Self {
+        self.inner = self.inner.order(order);
+        self
+    }
+
+    /// Sets the `query` query parameter.
+    pub fn query(mut self, query: &'a str) -> Self {
+        self.inner = self.inner.query(query);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Customers::deprecated_202007_get_param`], set with a builder.
+pub struct CustomersDeprecated202007GetParamRequest<'a> {
+    inner: crate::customers::CustomersDeprecated202007GetParamRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> CustomersDeprecated202007GetParamRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Customers::get`], set with a builder.
+pub struct CustomersGetRequest<'a> {
+    inner: crate::customers::CustomersGetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> CustomersGetRequest<'a> {
+    /// Sets the `ids` query parameter.
+    pub fn ids(mut self, ids: &'a str) -> Self {
+        self.inner = self.inner.ids(ids);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.inner = self.inner.created_at_min(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.inner = self.inner.created_at_max(created_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.inner = self.inner.updated_at_min(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.inner = self.inner.updated_at_max(updated_at_max);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Customers::get_search`], set with a builder.
+pub struct CustomersGetSearchRequest<'a> {
+    inner: crate::customers::CustomersGetSearchRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> CustomersGetSearchRequest<'a> {
+    /// Sets the `order` query parameter.
+    pub fn order(mut self, order: &'a str) -> Self {
+        self.inner = self.inner.order(order);
+        self
+    }
+
+    /// Sets the `query` query parameter.
+    pub fn query(mut self, query: &'a str) -> Self {
+        self.inner = self.inner.query(query);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Customers::get_param`], set with a builder.
+pub struct CustomersGetParamRequest<'a> {
+    inner: crate::customers::CustomersGetParamRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> CustomersGetParamRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Customers::deprecated_202101_get`], set with a builder.
+pub struct CustomersDeprecated202101GetRequest<'a> {
+    inner: crate::customers::CustomersDeprecated202101GetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> CustomersDeprecated202101GetRequest<'a> {
+    /// Sets the `ids` query parameter.
+    pub fn ids(mut self, ids: &'a str) -> Self {
+        self.inner = self.inner.ids(ids);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.inner = self.inner.created_at_min(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.inner = self.inner.created_at_max(created_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.inner = self.inner.updated_at_min(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.inner = self.inner.updated_at_max(updated_at_max);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Customers::deprecated_202101_get_search`], set with a builder.
+pub struct CustomersDeprecated202101GetSearchRequest<'a> {
+    inner: crate::customers::CustomersDeprecated202101GetSearchRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> CustomersDeprecated202101GetSearchRequest<'a> {
+    /// Sets the `order` query parameter.
+    pub fn order(mut self, order: &'a str) -> Self {
+        self.inner = self.inner.order(order);
+        self
+    }
+
+    /// Sets the `query` query parameter.
+    pub fn query(mut self, query: &'a str) -> Self {
+        self.inner = self.inner.query(query);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Customers::deprecated_202101_get_param`], set with a builder.
+pub struct CustomersDeprecated202101GetParamRequest<'a> {
+    inner: crate::customers::CustomersDeprecated202101GetParamRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> CustomersDeprecated202101GetParamRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Customers::deprecated_unstable_get`], set with a builder.
+pub struct CustomersDeprecatedUnstableGetRequest<'a> {
+    inner: crate::customers::CustomersDeprecatedUnstableGetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> CustomersDeprecatedUnstableGetRequest<'a> {
+    /// Sets the `ids` query parameter.
+    pub fn ids(mut self, ids: &'a str) -> Self {
+        self.inner = self.inner.ids(ids);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.inner = self.inner.created_at_min(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.inner = self.inner.created_at_max(created_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.inner = self.inner.updated_at_min(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.inner = self.inner.updated_at_max(updated_at_max);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Customers::deprecated_unstable_get_search`], set with a builder.
+pub struct CustomersDeprecatedUnstableGetSearchRequest<'a> {
+    inner: crate::customers::CustomersDeprecatedUnstableGetSearchRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> CustomersDeprecatedUnstableGetSearchRequest<'a> {
+    /// Sets the `order` query parameter.
+    pub fn order(mut self, order: &'a str) -> Self {
+        self.inner = self.inner.order(order);
+        self
+    }
+
+    /// Sets the `query` query parameter.
+    pub fn query(mut self, query: &'a str) -> Self {
+        self.inner = self.inner.query(query);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Customers::deprecated_unstable_get_param`], set with a builder.
+pub struct CustomersDeprecatedUnstableGetParamRequest<'a> {
+    inner: crate::customers::CustomersDeprecatedUnstableGetParamRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> CustomersDeprecatedUnstableGetParamRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Customers::deprecated_202001_update_param_addresses_set`], set with a builder.
+pub struct CustomersDeprecated202001UpdateParamAddressesSetRequest<'a> {
+    inner: crate::customers::CustomersDeprecated202001UpdateParamAddressesSetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> CustomersDeprecated202001UpdateParamAddressesSetRequest<'a> {
+    /// Sets the `address_ids[]` query parameter.
+    pub fn address_ids(mut self, address_ids: i64) -> Self {
+        self.inner = self.inner.address_ids(address_ids);
+        self
+    }
+
+    /// Sets the `operation` query parameter.
+    pub fn operation(mut self, operation: &'a str) -> Self {
+        self.inner = self.inner.operation(operation);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Customers::deprecated_202004_update_param_addresses_set`], set with a builder.
+pub struct CustomersDeprecated202004UpdateParamAddressesSetRequest<'a> {
+    inner: crate::customers::CustomersDeprecated202004UpdateParamAddressesSetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> CustomersDeprecated202004UpdateParamAddressesSetRequest<'a> {
+    /// Sets the `address_ids[]` query parameter.
+    pub fn address_ids(mut self, address_ids: i64) -> Self {
+        self.inner = self.inner.address_ids(address_ids);
+        self
+    }
+
+    /// Sets the `operation` query parameter.
+    pub fn operation(mut self, operation: &'a str) -> Self {
+        self.inner = self.inner.operation(operation);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Customers::deprecated_202007_update_param_addresses_set`], set with a builder.
+pub struct CustomersDeprecated202007UpdateParamAddressesSetRequest<'a> {
+    inner: crate::customers::CustomersDeprecated202007UpdateParamAddressesSetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> CustomersDeprecated202007UpdateParamAddressesSetRequest<'a> {
+    /// Sets the `address_ids[]` query parameter.
+    pub fn address_ids(mut self, address_ids: i64) -> Self {
+        self.inner = self.inner.address_ids(address_ids);
+        self
+    }
+
+    /// Sets the `operation` query parameter.
+    pub fn operation(mut self, operation: &'a str) -> Self {
+        self.inner = self.inner.operation(operation);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Customers::update_param_addresses_set`], set with a builder.
+pub struct CustomersUpdateParamAddressesSetRequest<'a> {
+    inner: crate::customers::CustomersUpdateParamAddressesSetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> CustomersUpdateParamAddressesSetRequest<'a> {
+    /// Sets the `address_ids[]` query parameter.
+    pub fn address_ids(mut self, address_ids: i64) -> Self {
+        self.inner = self.inner.address_ids(address_ids);
+        self
+    }
+
+    /// Sets the `operation` query parameter.
+    pub fn operation(mut self, operation: &'a str) -> Self {
+        self.inner = self.inner.operation(operation);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Customers::deprecated_202101_update_param_addresses_set`], set with a builder.
+pub struct CustomersDeprecated202101UpdateParamAddressesSetRequest<'a> {
+    inner: crate::customers::CustomersDeprecated202101UpdateParamAddressesSetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> CustomersDeprecated202101UpdateParamAddressesSetRequest<'a> {
+    /// Sets the `address_ids[]` query parameter.
+    pub fn address_ids(mut self, address_ids: i64) -> Self {
+        self.inner = self.inner.address_ids(address_ids);
+        self
+    }
+
+    /// Sets the `operation` query parameter.
+    pub fn operation(mut self, operation: &'a str) -> Self {
+        self.inner = self.inner.operation(operation);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Customers::deprecated_unstable_update_param_addresses_set`], set with a builder.
+pub struct CustomersDeprecatedUnstableUpdateParamAddressesSetRequest<'a> {
+    inner: crate::customers::CustomersDeprecatedUnstableUpdateParamAddressesSetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> CustomersDeprecatedUnstableUpdateParamAddressesSetRequest<'a> {
+    /// Sets the `address_ids[]` query parameter.
+    pub fn address_ids(mut self, address_ids: i64) -> Self {
+        self.inner = self.inner.address_ids(address_ids);
+        self
+    }
+
+    /// Sets the `operation` query parameter.
+    pub fn operation(mut self, operation: &'a str) -> Self {
+        self.inner = self.inner.operation(operation);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Customers::deprecated_202001_get_saved_searche`], set with a builder.
+pub struct CustomersDeprecated202001GetSavedSearcheRequest<'a> {
+    inner: crate::customers::CustomersDeprecated202001GetSavedSearcheRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> CustomersDeprecated202001GetSavedSearcheRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Customers::deprecated_202001_get_saved_searches_count`], set with a builder.
+pub struct CustomersDeprecated202001GetSavedSearchesCountRequest<'a> {
+    inner: crate::customers::CustomersDeprecated202001GetSavedSearchesCountRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> CustomersDeprecated202001GetSavedSearchesCountRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Customers::deprecated_202001_get_saved_searches_param_search`], set with a builder.
+pub struct CustomersDeprecated202001GetSavedSearchesParamSearchRequest<'a> {
+    inner: crate::customers::CustomersDeprecated202001GetSavedSearchesParamSearchRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> CustomersDeprecated202001GetSavedSearchesParamSearchRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Customers::deprecated_202001_get_saved_searches_param_search_customers`], set with a builder.
+pub struct CustomersDeprecated202001GetSavedSearchesParamSearchCustomersRequest<'a> {
+    inner:
+        crate::customers::CustomersDeprecated202001GetSavedSearchesParamSearchCustomersRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> CustomersDeprecated202001GetSavedSearchesParamSearchCustomersRequest<'a> {
+    /// Sets the `order` query parameter.
+    pub fn order(mut self, order: &'a str) -> Self {
+        self.inner = self.inner.order(order);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Customers::deprecated_202004_get_saved_searche`], set with a builder.
+pub struct CustomersDeprecated202004GetSavedSearcheRequest<'a> {
+    inner: crate::customers::CustomersDeprecated202004GetSavedSearcheRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> CustomersDeprecated202004GetSavedSearcheRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Customers::deprecated_202004_get_saved_searches_count`], set with a builder.
+pub struct CustomersDeprecated202004GetSavedSearchesCountRequest<'a> {
+    inner: crate::customers::CustomersDeprecated202004GetSavedSearchesCountRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> CustomersDeprecated202004GetSavedSearchesCountRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Customers::deprecated_202004_get_saved_searches_param_search`], set with a builder.
+pub struct CustomersDeprecated202004GetSavedSearchesParamSearchRequest<'a> {
+    inner: crate::customers::CustomersDeprecated202004GetSavedSearchesParamSearchRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> CustomersDeprecated202004GetSavedSearchesParamSearchRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Customers::deprecated_202004_get_saved_searches_param_search_customers`], set with a builder.
+pub struct CustomersDeprecated202004GetSavedSearchesParamSearchCustomersRequest<'a> {
+    inner:
+        crate::customers::CustomersDeprecated202004GetSavedSearchesParamSearchCustomersRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> CustomersDeprecated202004GetSavedSearchesParamSearchCustomersRequest<'a> {
+    /// Sets the `order` query parameter.
+    pub fn order(mut self, order: &'a str) -> Self {
+        self.inner = self.inner.order(order);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Customers::deprecated_202007_get_saved_searche`], set with a builder.
+pub struct CustomersDeprecated202007GetSavedSearcheRequest<'a> {
+    inner: crate::customers::CustomersDeprecated202007GetSavedSearcheRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> CustomersDeprecated202007GetSavedSearcheRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Customers::deprecated_202007_get_saved_searches_count`], set with a builder.
+pub struct CustomersDeprecated202007GetSavedSearchesCountRequest<'a> {
+    inner: crate::customers::CustomersDeprecated202007GetSavedSearchesCountRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> CustomersDeprecated202007GetSavedSearchesCountRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Customers::deprecated_202007_get_saved_searches_param_search`], set with a builder.
+pub struct CustomersDeprecated202007GetSavedSearchesParamSearchRequest<'a> {
+    inner: crate::customers::CustomersDeprecated202007GetSavedSearchesParamSearchRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> CustomersDeprecated202007GetSavedSearchesParamSearchRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Customers::deprecated_202007_get_saved_searches_param_search_customers`], set with a builder.
+pub struct CustomersDeprecated202007GetSavedSearchesParamSearchCustomersRequest<'a> {
+    inner:
+        crate::customers::CustomersDeprecated202007GetSavedSearchesParamSearchCustomersRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> CustomersDeprecated202007GetSavedSearchesParamSearchCustomersRequest<'a> {
+    /// Sets the `order` query parameter.
+    pub fn order(mut self, order: &'a str) -> Self {
+        self.inner = self.inner.order(order);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Customers::get_saved_searche`], set with a builder.
+pub struct CustomersGetSavedSearcheRequest<'a> {
+    inner: crate::customers::CustomersGetSavedSearcheRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> CustomersGetSavedSearcheRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Customers::get_saved_searches_count`], set with a builder.
+pub struct CustomersGetSavedSearchesCountRequest<'a> {
+    inner: crate::customers::CustomersGetSavedSearchesCountRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> CustomersGetSavedSearchesCountRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Customers::get_saved_searches_param_search`], set with a builder.
+pub struct CustomersGetSavedSearchesParamSearchRequest<'a> {
+    inner: crate::customers::CustomersGetSavedSearchesParamSearchRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> CustomersGetSavedSearchesParamSearchRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Customers::get_saved_searches_param_search_customers`], set with a builder.
+pub struct CustomersGetSavedSearchesParamSearchCustomersRequest<'a> {
+    inner: crate::customers::CustomersGetSavedSearchesParamSearchCustomersRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> CustomersGetSavedSearchesParamSearchCustomersRequest<'a> {
+    /// Sets the `order` query parameter.
+    pub fn order(mut self, order: &'a str) -> Self {
+        self.inner = self.inner.order(order);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Customers::deprecated_202101_get_saved_searche`], set with a builder.
+pub struct CustomersDeprecated202101GetSavedSearcheRequest<'a> {
+    inner: crate::customers::CustomersDeprecated202101GetSavedSearcheRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> CustomersDeprecated202101GetSavedSearcheRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Customers::deprecated_202101_get_saved_searches_count`], set with a builder.
+pub struct CustomersDeprecated202101GetSavedSearchesCountRequest<'a> {
+    inner: crate::customers::CustomersDeprecated202101GetSavedSearchesCountRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> CustomersDeprecated202101GetSavedSearchesCountRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Customers::deprecated_202101_get_saved_searches_param_search`], set with a builder.
+pub struct CustomersDeprecated202101GetSavedSearchesParamSearchRequest<'a> {
+    inner: crate::customers::CustomersDeprecated202101GetSavedSearchesParamSearchRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> CustomersDeprecated202101GetSavedSearchesParamSearchRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Customers::deprecated_202101_get_saved_searches_param_search_customers`], set with a builder.
+pub struct CustomersDeprecated202101GetSavedSearchesParamSearchCustomersRequest<'a> {
+    inner:
+        crate::customers::CustomersDeprecated202101GetSavedSearchesParamSearchCustomersRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> CustomersDeprecated202101GetSavedSearchesParamSearchCustomersRequest<'a> {
+    /// Sets the `order` query parameter.
+    pub fn order(mut self, order: &'a str) -> Self {
+        self.inner = self.inner.order(order);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Customers::deprecated_unstable_get_saved_searche`], set with a builder.
+pub struct CustomersDeprecatedUnstableGetSavedSearcheRequest<'a> {
+    inner: crate::customers::CustomersDeprecatedUnstableGetSavedSearcheRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> CustomersDeprecatedUnstableGetSavedSearcheRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Customers::deprecated_unstable_get_saved_searches_count`], set with a builder.
+pub struct CustomersDeprecatedUnstableGetSavedSearchesCountRequest<'a> {
+    inner: crate::customers::CustomersDeprecatedUnstableGetSavedSearchesCountRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> CustomersDeprecatedUnstableGetSavedSearchesCountRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Customers::deprecated_unstable_get_saved_searches_param_search`], set with a builder.
+pub struct CustomersDeprecatedUnstableGetSavedSearchesParamSearchRequest<'a> {
+    inner: crate::customers::CustomersDeprecatedUnstableGetSavedSearchesParamSearchRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> CustomersDeprecatedUnstableGetSavedSearchesParamSearchRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Customers::deprecated_unstable_get_saved_searches_param_search_customers`], set with a builder.
+pub struct CustomersDeprecatedUnstableGetSavedSearchesParamSearchCustomersRequest<'a> {
+    inner: crate::customers::CustomersDeprecatedUnstableGetSavedSearchesParamSearchCustomersRequest<
+        'a,
+    >,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> CustomersDeprecatedUnstableGetSavedSearchesParamSearchCustomersRequest<'a> {
+    /// Sets the `order` query parameter.
+    pub fn order(mut self, order: &'a str) -> Self {
+        self.inner = self.inner.order(order);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::customers::Customers`].
+pub struct Customers {
+    inner: crate::customers::Customers,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Customers {
+    pub(crate) fn new(
+        inner: crate::customers::Customers,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Customers { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::customers::Customers::deprecated_202001_get`].
+    pub fn deprecated_202001_get(
+        &self,
+        ids: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        limit: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.deprecated_202001_get(
+            ids,
+            since_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            limit,
+            fields,
+        ))
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202001_get_request`].
+    pub fn deprecated_202001_get_request(&self) -> CustomersDeprecated202001GetRequest<'_> {
+        CustomersDeprecated202001GetRequest {
+            inner: self.inner.deprecated_202001_get_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202001_create`].
+    pub fn deprecated_202001_create(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202001_create(body))
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202001_get_search`].
+    pub fn deprecated_202001_get_search(
+        &self,
+        order: &str,
+        query: &str,
+        limit: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_get_search(order, query, limit, fields),
+        )
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202001_get_search_request`].
+    pub fn deprecated_202001_get_search_request(
+        &self,
+    ) -> CustomersDeprecated202001GetSearchRequest<'_> {
+        CustomersDeprecated202001GetSearchRequest {
+            inner: self.inner.deprecated_202001_get_search_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202001_get_param`].
+    pub fn deprecated_202001_get_param(
+        &self,
+        customer_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202001_get_param(customer_id, fields))
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202001_get_param_request`].
+    pub fn deprecated_202001_get_param_request<'a>(
+        &'a self,
+        customer_id: &'a str,
+    ) -> CustomersDeprecated202001GetParamRequest<'a> {
+        CustomersDeprecated202001GetParamRequest {
+            inner: self.inner.deprecated_202001_get_param_request(customer_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202001_update_param`].
+    pub fn deprecated_202001_update_param(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202001_update_param(customer_id, body))
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202001_delete_param`].
+    pub fn deprecated_202001_delete_param(
+        &self,
+        customer_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202001_delete_param(customer_id))
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202001_create_param_account_activation_url`].
+    pub fn deprecated_202001_create_param_account_activation_url(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_create_param_account_activation_url(customer_id, body),
+        )
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202001_create_param_send_invite`].
+    pub fn deprecated_202001_create_param_send_invite(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_create_param_send_invite(customer_id, body),
+        )
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202001_get_count`].
+    pub fn deprecated_202001_get_count(&self) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202001_get_count())
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202001_get_param_order`].
+    pub fn deprecated_202001_get_param_order(
+        &self,
+        customer_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202001_get_param_order(customer_id))
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202004_get`].
+    pub fn deprecated_202004_get(
+        &self,
+        ids: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        limit: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.deprecated_202004_get(
+            ids,
+            since_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            limit,
+            fields,
+        ))
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202004_get_request`].
+    pub fn deprecated_202004_get_request(&self) -> CustomersDeprecated202004GetRequest<'_> {
+        CustomersDeprecated202004GetRequest {
+            inner: self.inner.deprecated_202004_get_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202004_create`].
+    pub fn deprecated_202004_create(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202004_create(body))
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202004_get_search`].
+    pub fn deprecated_202004_get_search(
+        &self,
+        order: &str,
+        query: &str,
+        limit: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_get_search(order, query, limit, fields),
+        )
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202004_get_search_request`].
+    pub fn deprecated_202004_get_search_request(
+        &self,
+    ) -> CustomersDeprecated202004GetSearchRequest<'_> {
+        CustomersDeprecated202004GetSearchRequest {
+            inner: self.inner.deprecated_202004_get_search_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202004_get_param`].
+    pub fn deprecated_202004_get_param(
+        &self,
+        customer_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202004_get_param(customer_id, fields))
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202004_get_param_request`].
+    pub fn deprecated_202004_get_param_request<'a>(
+        &'a self,
+        customer_id: &'a str,
+    ) -> CustomersDeprecated202004GetParamRequest<'a> {
+        CustomersDeprecated202004GetParamRequest {
+            inner: self.inner.deprecated_202004_get_param_request(customer_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202004_update_param`].
+    pub fn deprecated_202004_update_param(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202004_update_param(customer_id, body))
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202004_delete_param`].
+    pub fn deprecated_202004_delete_param(
+        &self,
+        customer_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202004_delete_param(customer_id))
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202004_create_param_account_activation_url`].
+    pub fn deprecated_202004_create_param_account_activation_url(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_create_param_account_activation_url(customer_id, body),
+        )
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202004_create_param_send_invite`].
+    pub fn deprecated_202004_create_param_send_invite(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_create_param_send_invite(customer_id, body),
+        )
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202004_get_count`].
+    pub fn deprecated_202004_get_count(&self) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202004_get_count())
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202004_get_param_order`].
+    pub fn deprecated_202004_get_param_order(
+        &self,
+        customer_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202004_get_param_order(customer_id))
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202007_get`].
+    pub fn deprecated_202007_get(
+        &self,
+        ids: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        limit: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.deprecated_202007_get(
+            ids,
+            since_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            limit,
+            fields,
+        ))
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202007_get_request`].
+    pub fn deprecated_202007_get_request(&self) -> CustomersDeprecated202007GetRequest<'_> {
+        CustomersDeprecated202007GetRequest {
+            inner: self.inner.deprecated_202007_get_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202007_create`].
+    pub fn deprecated_202007_create(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202007_create(body))
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202007_get_search`].
+    pub fn deprecated_202007_get_search(
+        &self,
+        order: &str,
+        query: &str,
+        limit: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_get_search(order, query, limit, fields),
+        )
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202007_get_search_request`].
+    pub fn deprecated_202007_get_search_request(
+        &self,
+    ) -> CustomersDeprecated202007GetSearchRequest<'_> {
+        CustomersDeprecated202007GetSearchRequest {
+            inner: self.inner.deprecated_202007_get_search_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202007_get_param`].
+    pub fn deprecated_202007_get_param(
+        &self,
+        customer_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202007_get_param(customer_id, fields))
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202007_get_param_request`].
+    pub fn deprecated_202007_get_param_request<'a>(
+        &'a self,
+        customer_id: &'a str,
+    ) -> CustomersDeprecated202007GetParamRequest<'a> {
+        CustomersDeprecated202007GetParamRequest {
+            inner: self.inner.deprecated_202007_get_param_request(customer_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202007_update_param`].
+    pub fn deprecated_202007_update_param(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202007_update_param(customer_id, body))
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202007_delete_param`].
+    pub fn deprecated_202007_delete_param(
+        &self,
+        customer_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202007_delete_param(customer_id))
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202007_create_param_account_activation_url`].
+    pub fn deprecated_202007_create_param_account_activation_url(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_create_param_account_activation_url(customer_id, body),
+        )
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202007_create_param_send_invite`].
+    pub fn deprecated_202007_create_param_send_invite(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_create_param_send_invite(customer_id, body),
+        )
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202007_get_count`].
+    pub fn deprecated_202007_get_count(&self) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202007_get_count())
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202007_get_param_order`].
+    pub fn deprecated_202007_get_param_order(
+        &self,
+        customer_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202007_get_param_order(customer_id))
+    }
+    /// The blocking version of [`crate::customers::Customers::get`].
+    pub fn get(
+        &self,
+        ids: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        limit: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.get(
+            ids,
+            since_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            limit,
+            fields,
+        ))
+    }
+    /// The blocking version of [`crate::customers::Customers::get_request`].
+    pub fn get_request(&self) -> CustomersGetRequest<'_> {
+        CustomersGetRequest {
+            inner: self.inner.get_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::customers::Customers::create`].
+    pub fn create(&self, body: &serde_json::Value) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.create(body))
+    }
+    /// The blocking version of [`crate::customers::Customers::get_search`].
+    pub fn get_search(
+        &self,
+        order: &str,
+        query: &str,
+        limit: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.get_search(order, query, limit, fields))
+    }
+    /// The blocking version of [`crate::customers::Customers::get_search_request`].
+    pub fn get_search_request(&self) -> CustomersGetSearchRequest<'_> {
+        CustomersGetSearchRequest {
+            inner: self.inner.get_search_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::customers::Customers::get_param`].
+    pub fn get_param(&self, customer_id: &str, fields: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.get_param(customer_id, fields))
+    }
+    /// The blocking version of [`crate::customers::Customers::get_param_request`].
+    pub fn get_param_request<'a>(&'a self, customer_id: &'a str) -> CustomersGetParamRequest<'a> {
+        CustomersGetParamRequest {
+            inner: self.inner.get_param_request(customer_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::customers::Customers::update_param`].
+    pub fn update_param(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.update_param(customer_id, body))
+    }
+    /// The blocking version of [`crate::customers::Customers::delete_param`].
+    pub fn delete_param(&self, customer_id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.delete_param(customer_id))
+    }
+    /// The blocking version of [`crate::customers::Customers::create_param_account_activation_url`].
+    pub fn create_param_account_activation_url(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .create_param_account_activation_url(customer_id, body),
+        )
+    }
+    /// The blocking version of [`crate::customers::Customers::create_param_send_invite`].
+    pub fn create_param_send_invite(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.create_param_send_invite(customer_id, body))
+    }
+    /// The blocking version of [`crate::customers::Customers::get_count`].
+    pub fn get_count(&self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.get_count())
+    }
+    /// The blocking version of [`crate::customers::Customers::get_param_order`].
+    pub fn get_param_order(&self, customer_id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.get_param_order(customer_id))
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202101_get`].
+    pub fn deprecated_202101_get(
+        &self,
+        ids: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        limit: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.deprecated_202101_get(
+            ids,
+            since_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            limit,
+            fields,
+        ))
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202101_get_request`].
+    pub fn deprecated_202101_get_request(&self) -> CustomersDeprecated202101GetRequest<'_> {
+        CustomersDeprecated202101GetRequest {
+            inner: self.inner.deprecated_202101_get_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202101_create`].
+    pub fn deprecated_202101_create(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202101_create(body))
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202101_get_search`].
+    pub fn deprecated_202101_get_search(
+        &self,
+        order: &str,
+        query: &str,
+        limit: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_get_search(order, query, limit, fields),
+        )
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202101_get_search_request`].
+    pub fn deprecated_202101_get_search_request(
+        &self,
+    ) -> CustomersDeprecated202101GetSearchRequest<'_> {
+        CustomersDeprecated202101GetSearchRequest {
+            inner: self.inner.deprecated_202101_get_search_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202101_get_param`].
+    pub fn deprecated_202101_get_param(
+        &self,
+        customer_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202101_get_param(customer_id, fields))
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202101_get_param_request`].
+    pub fn deprecated_202101_get_param_request<'a>(
+        &'a self,
+        customer_id: &'a str,
+    ) -> CustomersDeprecated202101GetParamRequest<'a> {
+        CustomersDeprecated202101GetParamRequest {
+            inner: self.inner.deprecated_202101_get_param_request(customer_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202101_update_param`].
+    pub fn deprecated_202101_update_param(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202101_update_param(customer_id, body))
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202101_delete_param`].
+    pub fn deprecated_202101_delete_param(
+        &self,
+        customer_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202101_delete_param(customer_id))
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202101_create_param_account_activation_url`].
+    pub fn deprecated_202101_create_param_account_activation_url(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_create_param_account_activation_url(customer_id, body),
+        )
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202101_create_param_send_invite`].
+    pub fn deprecated_202101_create_param_send_invite(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_create_param_send_invite(customer_id, body),
+        )
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202101_get_count`].
+    pub fn deprecated_202101_get_count(&self) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202101_get_count())
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202101_get_param_order`].
+    pub fn deprecated_202101_get_param_order(
+        &self,
+        customer_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202101_get_param_order(customer_id))
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_unstable_get`].
+    pub fn deprecated_unstable_get(
+        &self,
+        ids: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        limit: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.deprecated_unstable_get(
+            ids,
+            since_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            limit,
+            fields,
+        ))
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_unstable_get_request`].
+    pub fn deprecated_unstable_get_request(&self) -> CustomersDeprecatedUnstableGetRequest<'_> {
+        CustomersDeprecatedUnstableGetRequest {
+            inner: self.inner.deprecated_unstable_get_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_unstable_create`].
+    pub fn deprecated_unstable_create(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_unstable_create(body))
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_unstable_get_search`].
+    pub fn deprecated_unstable_get_search(
+        &self,
+        order: &str,
+        query: &str,
+        limit: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_get_search(order, query, limit, fields),
+        )
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_unstable_get_search_request`].
+    pub fn deprecated_unstable_get_search_request(
+        &self,
+    ) -> CustomersDeprecatedUnstableGetSearchRequest<'_> {
+        CustomersDeprecatedUnstableGetSearchRequest {
+            inner: self.inner.deprecated_unstable_get_search_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_unstable_get_param`].
+    pub fn deprecated_unstable_get_param(
+        &self,
+        customer_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_get_param(customer_id, fields),
+        )
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_unstable_get_param_request`].
+    pub fn deprecated_unstable_get_param_request<'a>(
+        &'a self,
+        customer_id: &'a str,
+    ) -> CustomersDeprecatedUnstableGetParamRequest<'a> {
+        CustomersDeprecatedUnstableGetParamRequest {
+            inner: self
+                .inner
+                .deprecated_unstable_get_param_request(customer_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_unstable_update_param`].
+    pub fn deprecated_unstable_update_param(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_update_param(customer_id, body),
+        )
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_unstable_delete_param`].
+    pub fn deprecated_unstable_delete_param(
+        &self,
+        customer_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_unstable_delete_param(customer_id))
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_unstable_create_param_account_activation_url`].
+    pub fn deprecated_unstable_create_param_account_activation_url(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_create_param_account_activation_url(customer_id, body),
+        )
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_unstable_create_param_send_invite`].
+    pub fn deprecated_unstable_create_param_send_invite(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_create_param_send_invite(customer_id, body),
+        )
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_unstable_get_count`].
+    pub fn deprecated_unstable_get_count(&self) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_unstable_get_count())
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_unstable_get_param_order`].
+    pub fn deprecated_unstable_get_param_order(
+        &self,
+        customer_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_unstable_get_param_order(customer_id))
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202001_get_param_addresse`].
+    pub fn deprecated_202001_get_param_addresse(
+        &self,
+        customer_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202001_get_param_addresse(customer_id))
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202001_create_param_addresses`].
+    pub fn deprecated_202001_create_param_addresses(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_create_param_addresses(customer_id, body),
+        )
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202001_get_param_addresses_address`].
+    pub fn deprecated_202001_get_param_addresses_address(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_get_param_addresses_address(customer_id, address_id),
+        )
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202001_update_param_addresses_address`].
+    pub fn deprecated_202001_update_param_addresses_address(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202001_update_param_addresses_address(
+                customer_id,
+                address_id,
+                body,
+            ))
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202001_delete_param_addresses_address`].
+    pub fn deprecated_202001_delete_param_addresses_address(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_delete_param_addresses_address(customer_id, address_id),
+        )
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202001_update_param_addresses_set`].
+    pub fn deprecated_202001_update_param_addresses_set(
+        &self,
+        customer_id: &str,
+        address_ids: i64,
+        operation: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202001_update_param_addresses_set(
+                customer_id,
+                address_ids,
+                operation,
+            ))
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202001_update_param_addresses_set_request`].
+    pub fn deprecated_202001_update_param_addresses_set_request<'a>(
+        &'a self,
+        customer_id: &'a str,
+    ) -> CustomersDeprecated202001UpdateParamAddressesSetRequest<'a> {
+        CustomersDeprecated202001UpdateParamAddressesSetRequest {
+            inner: self
+                .inner
+                .deprecated_202001_update_param_addresses_set_request(customer_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202001_update_param_addresses_address_default`].
+    pub fn deprecated_202001_update_param_addresses_address_default(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_update_param_addresses_address_default(customer_id, address_id),
+        )
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202004_get_param_addresse`].
+    pub fn deprecated_202004_get_param_addresse(
+        &self,
+        customer_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202004_get_param_addresse(customer_id))
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202004_create_param_addresses`].
+    pub fn deprecated_202004_create_param_addresses(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_create_param_addresses(customer_id, body),
+        )
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202004_get_param_addresses_address`].
+    pub fn deprecated_202004_get_param_addresses_address(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_get_param_addresses_address(customer_id, address_id),
+        )
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202004_update_param_addresses_address`].
+    pub fn deprecated_202004_update_param_addresses_address(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202004_update_param_addresses_address(
+                customer_id,
+                address_id,
+                body,
+            ))
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202004_delete_param_addresses_address`].
+    pub fn deprecated_202004_delete_param_addresses_address(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_delete_param_addresses_address(customer_id, address_id),
+        )
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202004_update_param_addresses_set`].
+    pub fn deprecated_202004_update_param_addresses_set(
+        &self,
+        customer_id: &str,
+        address_ids: i64,
+        operation: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202004_update_param_addresses_set(
+                customer_id,
+                address_ids,
+                operation,
+            ))
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202004_update_param_addresses_set_request`].
+    pub fn deprecated_202004_update_param_addresses_set_request<'a>(
+        &'a self,
+        customer_id: &'a str,
+    ) -> CustomersDeprecated202004UpdateParamAddressesSetRequest<'a> {
+        CustomersDeprecated202004UpdateParamAddressesSetRequest {
+            inner: self
+                .inner
+                .deprecated_202004_update_param_addresses_set_request(customer_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202004_update_param_addresses_address_default`].
+    pub fn deprecated_202004_update_param_addresses_address_default(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_update_param_addresses_address_default(customer_id, address_id),
+        )
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202007_get_param_addresse`].
+    pub fn deprecated_202007_get_param_addresse(
+        &self,
+        customer_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202007_get_param_addresse(customer_id))
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202007_create_param_addresses`].
+    pub fn deprecated_202007_create_param_addresses(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_create_param_addresses(customer_id, body),
+        )
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202007_get_param_addresses_address`].
+    pub fn deprecated_202007_get_param_addresses_address(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_get_param_addresses_address(customer_id, address_id),
+        )
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202007_update_param_addresses_address`].
+    pub fn deprecated_202007_update_param_addresses_address(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202007_update_param_addresses_address(
+                customer_id,
+                address_id,
+                body,
+            ))
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202007_delete_param_addresses_address`].
+    pub fn deprecated_202007_delete_param_addresses_address(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_delete_param_addresses_address(customer_id, address_id),
+        )
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202007_update_param_addresses_set`].
+    pub fn deprecated_202007_update_param_addresses_set(
+        &self,
+        customer_id: &str,
+        address_ids: i64,
+        operation: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202007_update_param_addresses_set(
+                customer_id,
+                address_ids,
+                operation,
+            ))
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202007_update_param_addresses_set_request`].
+    pub fn deprecated_202007_update_param_addresses_set_request<'a>(
+        &'a self,
+        customer_id: &'a str,
+    ) -> CustomersDeprecated202007UpdateParamAddressesSetRequest<'a> {
+        CustomersDeprecated202007UpdateParamAddressesSetRequest {
+            inner: self
+                .inner
+                .deprecated_202007_update_param_addresses_set_request(customer_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202007_update_param_addresses_address_default`].
+    pub fn deprecated_202007_update_param_addresses_address_default(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_update_param_addresses_address_default(customer_id, address_id),
+        )
+    }
+    /// The blocking version of [`crate::customers::Customers::get_param_addresse`].
+    pub fn get_param_addresse(&self, customer_id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.get_param_addresse(customer_id))
+    }
+    /// The blocking version of [`crate::customers::Customers::create_param_addresses`].
+    pub fn create_param_addresses(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.create_param_addresses(customer_id, body))
+    }
+    /// The blocking version of [`crate::customers::Customers::get_param_addresses_address`].
+    pub fn get_param_addresses_address(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .get_param_addresses_address(customer_id, address_id),
+        )
+    }
+    /// The blocking version of [`crate::customers::Customers::update_param_addresses_address`].
+    pub fn update_param_addresses_address(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(
+                self.inner
+                    .update_param_addresses_address(customer_id, address_id, body),
+            )
+    }
+    /// The blocking version of [`crate::customers::Customers::delete_param_addresses_address`].
+    pub fn delete_param_addresses_address(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .delete_param_addresses_address(customer_id, address_id),
+        )
+    }
+    /// The blocking version of [`crate::customers::Customers::update_param_addresses_set`].
+    pub fn update_param_addresses_set(
+        &self,
+        customer_id: &str,
+        address_ids: i64,
+        operation: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.update_param_addresses_set(
+            customer_id,
+            address_ids,
+            operation,
+        ))
+    }
+    /// The blocking version of [`crate::customers::Customers::update_param_addresses_set_request`].
+    pub fn update_param_addresses_set_request<'a>(
+        &'a self,
+        customer_id: &'a str,
+    ) -> CustomersUpdateParamAddressesSetRequest<'a> {
+        CustomersUpdateParamAddressesSetRequest {
+            inner: self.inner.update_param_addresses_set_request(customer_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::customers::Customers::update_param_addresses_address_default`].
+    pub fn update_param_addresses_address_default(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .update_param_addresses_address_default(customer_id, address_id),
+        )
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202101_get_param_addresse`].
+    pub fn deprecated_202101_get_param_addresse(
+        &self,
+        customer_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202101_get_param_addresse(customer_id))
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202101_create_param_addresses`].
+    pub fn deprecated_202101_create_param_addresses(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_create_param_addresses(customer_id, body),
+        )
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202101_get_param_addresses_address`].
+    pub fn deprecated_202101_get_param_addresses_address(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_get_param_addresses_address(customer_id, address_id),
+        )
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202101_update_param_addresses_address`].
+    pub fn deprecated_202101_update_param_addresses_address(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202101_update_param_addresses_address(
+                customer_id,
+                address_id,
+                body,
+            ))
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202101_delete_param_addresses_address`].
+    pub fn deprecated_202101_delete_param_addresses_address(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_delete_param_addresses_address(customer_id, address_id),
+        )
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202101_update_param_addresses_set`].
+    pub fn deprecated_202101_update_param_addresses_set(
+        &self,
+        customer_id: &str,
+        address_ids: i64,
+        operation: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202101_update_param_addresses_set(
+                customer_id,
+                address_ids,
+                operation,
+            ))
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202101_update_param_addresses_set_request`].
+    pub fn deprecated_202101_update_param_addresses_set_request<'a>(
+        &'a self,
+        customer_id: &'a str,
+    ) -> CustomersDeprecated202101UpdateParamAddressesSetRequest<'a> {
+        CustomersDeprecated202101UpdateParamAddressesSetRequest {
+            inner: self
+                .inner
+                .deprecated_202101_update_param_addresses_set_request(customer_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202101_update_param_addresses_address_default`].
+    pub fn deprecated_202101_update_param_addresses_address_default(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_update_param_addresses_address_default(customer_id, address_id),
+        )
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_unstable_get_param_addresse`].
+    pub fn deprecated_unstable_get_param_addresse(
+        &self,
+        customer_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_get_param_addresse(customer_id),
+        )
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_unstable_create_param_addresses`].
+    pub fn deprecated_unstable_create_param_addresses(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_create_param_addresses(customer_id, body),
+        )
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_unstable_get_param_addresses_address`].
+    pub fn deprecated_unstable_get_param_addresses_address(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_get_param_addresses_address(customer_id, address_id),
+        )
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_unstable_update_param_addresses_address`].
+    pub fn deprecated_unstable_update_param_addresses_address(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_update_param_addresses_address(customer_id, address_id, body),
+        )
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_unstable_delete_param_addresses_address`].
+    pub fn deprecated_unstable_delete_param_addresses_address(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_delete_param_addresses_address(customer_id, address_id),
+        )
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_unstable_update_param_addresses_set`].
+    pub fn deprecated_unstable_update_param_addresses_set(
+        &self,
+        customer_id: &str,
+        address_ids: i64,
+        operation: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_unstable_update_param_addresses_set(
+                customer_id,
+                address_ids,
+                operation,
+            ))
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_unstable_update_param_addresses_set_request`].
+    pub fn deprecated_unstable_update_param_addresses_set_request<'a>(
+        &'a self,
+        customer_id: &'a str,
+    ) -> CustomersDeprecatedUnstableUpdateParamAddressesSetRequest<'a> {
+        CustomersDeprecatedUnstableUpdateParamAddressesSetRequest {
+            inner: self
+                .inner
+                .deprecated_unstable_update_param_addresses_set_request(customer_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_unstable_update_param_addresses_address_default`].
+    pub fn deprecated_unstable_update_param_addresses_address_default(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_update_param_addresses_address_default(
+                    customer_id,
+                    address_id,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202001_get_saved_searche`].
+    pub fn deprecated_202001_get_saved_searche(
+        &self,
+        limit: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_get_saved_searche(limit, since_id, fields),
+        )
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202001_get_saved_searche_request`].
+    pub fn deprecated_202001_get_saved_searche_request(
+        &self,
+    ) -> CustomersDeprecated202001GetSavedSearcheRequest<'_> {
+        CustomersDeprecated202001GetSavedSearcheRequest {
+            inner: self.inner.deprecated_202001_get_saved_searche_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202001_create_saved_searches`].
+    pub fn deprecated_202001_create_saved_searches(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202001_create_saved_searches(body))
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202001_get_saved_searches_count`].
+    pub fn deprecated_202001_get_saved_searches_count(
+        &self,
+        since_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_get_saved_searches_count(since_id),
+        )
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202001_get_saved_searches_count_request`].
+    pub fn deprecated_202001_get_saved_searches_count_request(
+        &self,
+    ) -> CustomersDeprecated202001GetSavedSearchesCountRequest<'_> {
+        CustomersDeprecated202001GetSavedSearchesCountRequest {
+            inner: self
+                .inner
+                .deprecated_202001_get_saved_searches_count_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202001_get_saved_searches_param_search`].
+    pub fn deprecated_202001_get_saved_searches_param_search(
+        &self,
+        customer_saved_search_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_get_saved_searches_param_search(
+                    customer_saved_search_id,
+                    fields,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202001_get_saved_searches_param_search_request`].
+    pub fn deprecated_202001_get_saved_searches_param_search_request<'a>(
+        &'a self,
+        customer_saved_search_id: &'a str,
+    ) -> CustomersDeprecated202001GetSavedSearchesParamSearchRequest<'a> {
+        CustomersDeprecated202001GetSavedSearchesParamSearchRequest {
+            inner: self
+                .inner
+                .deprecated_202001_get_saved_searches_param_search_request(
+                    customer_saved_search_id,
+                ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202001_update_saved_searches_param_search`].
+    pub fn deprecated_202001_update_saved_searches_param_search(
+        &self,
+        customer_saved_search_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_update_saved_searches_param_search(
+                    customer_saved_search_id,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202001_delete_saved_searches_param_search`].
+    pub fn deprecated_202001_delete_saved_searches_param_search(
+        &self,
+        customer_saved_search_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_delete_saved_searches_param_search(customer_saved_search_id),
+        )
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202001_get_saved_searches_param_search_customers`].
+    pub fn deprecated_202001_get_saved_searches_param_search_customers(
+        &self,
+        customer_saved_search_id: &str,
+        order: &str,
+        limit: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_get_saved_searches_param_search_customers(
+                    customer_saved_search_id,
+                    order,
+                    limit,
+                    fields,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202001_get_saved_searches_param_search_customers_request`].
+    pub fn deprecated_202001_get_saved_searches_param_search_customers_request<'a>(
+        &'a self,
+        customer_saved_search_id: &'a str,
+    ) -> CustomersDeprecated202001GetSavedSearchesParamSearchCustomersRequest<'a> {
+        CustomersDeprecated202001GetSavedSearchesParamSearchCustomersRequest {
+            inner: self
+                .inner
+                .deprecated_202001_get_saved_searches_param_search_customers_request(
+                    customer_saved_search_id,
+                ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202004_get_saved_searche`].
+    pub fn deprecated_202004_get_saved_searche(
+        &self,
+        limit: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_get_saved_searche(limit, since_id, fields),
+        )
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202004_get_saved_searche_request`].
+    pub fn deprecated_202004_get_saved_searche_request(
+        &self,
+    ) -> CustomersDeprecated202004GetSavedSearcheRequest<'_> {
+        CustomersDeprecated202004GetSavedSearcheRequest {
+            inner: self.inner.deprecated_202004_get_saved_searche_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202004_create_saved_searches`].
+    pub fn deprecated_202004_create_saved_searches(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202004_create_saved_searches(body))
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202004_get_saved_searches_count`].
+    pub fn deprecated_202004_get_saved_searches_count(
+        &self,
+        since_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_get_saved_searches_count(since_id),
+        )
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202004_get_saved_searches_count_request`].
+    pub fn deprecated_202004_get_saved_searches_count_request(
+        &self,
+    ) -> CustomersDeprecated202004GetSavedSearchesCountRequest<'_> {
+        CustomersDeprecated202004GetSavedSearchesCountRequest {
+            inner: self
+                .inner
+                .deprecated_202004_get_saved_searches_count_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202004_get_saved_searches_param_search`].
+    pub fn deprecated_202004_get_saved_searches_param_search(
+        &self,
+        customer_saved_search_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_get_saved_searches_param_search(
+                    customer_saved_search_id,
+                    fields,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202004_get_saved_searches_param_search_request`].
+    pub fn deprecated_202004_get_saved_searches_param_search_request<'a>(
+        &'a self,
+        customer_saved_search_id: &'a str,
+    ) -> CustomersDeprecated202004GetSavedSearchesParamSearchRequest<'a> {
+        CustomersDeprecated202004GetSavedSearchesParamSearchRequest {
+            inner: self
+                .inner
+                .deprecated_202004_get_saved_searches_param_search_request(
+                    customer_saved_search_id,
+                ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202004_update_saved_searches_param_search`].
+    pub fn deprecated_202004_update_saved_searches_param_search(
+        &self,
+        customer_saved_search_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_update_saved_searches_param_search(
+                    customer_saved_search_id,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202004_delete_saved_searches_param_search`].
+    pub fn deprecated_202004_delete_saved_searches_param_search(
+        &self,
+        customer_saved_search_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_delete_saved_searches_param_search(customer_saved_search_id),
+        )
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202004_get_saved_searches_param_search_customers`].
+    pub fn deprecated_202004_get_saved_searches_param_search_customers(
+        &self,
+        customer_saved_search_id: &str,
+        order: &str,
+        limit: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_get_saved_searches_param_search_customers(
+                    customer_saved_search_id,
+                    order,
+                    limit,
+                    fields,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202004_get_saved_searches_param_search_customers_request`].
+    pub fn deprecated_202004_get_saved_searches_param_search_customers_request<'a>(
+        &'a self,
+        customer_saved_search_id: &'a str,
+    ) -> CustomersDeprecated202004GetSavedSearchesParamSearchCustomersRequest<'a> {
+        CustomersDeprecated202004GetSavedSearchesParamSearchCustomersRequest {
+            inner: self
+                .inner
+                .deprecated_202004_get_saved_searches_param_search_customers_request(
+                    customer_saved_search_id,
+                ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202007_get_saved_searche`].
+    pub fn deprecated_202007_get_saved_searche(
+        &self,
+        limit: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_get_saved_searche(limit, since_id, fields),
+        )
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202007_get_saved_searche_request`].
+    pub fn deprecated_202007_get_saved_searche_request(
+        &self,
+    ) -> CustomersDeprecated202007GetSavedSearcheRequest<'_> {
+        CustomersDeprecated202007GetSavedSearcheRequest {
+            inner: self.inner.deprecated_202007_get_saved_searche_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202007_create_saved_searches`].
+    pub fn deprecated_202007_create_saved_searches(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202007_create_saved_searches(body))
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202007_get_saved_searches_count`].
+    pub fn deprecated_202007_get_saved_searches_count(
+        &self,
+        since_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_get_saved_searches_count(since_id),
+        )
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202007_get_saved_searches_count_request`].
+    pub fn deprecated_202007_get_saved_searches_count_request(
+        &self,
+    ) -> CustomersDeprecated202007GetSavedSearchesCountRequest<'_> {
+        CustomersDeprecated202007GetSavedSearchesCountRequest {
+            inner: self
+                .inner
+                .deprecated_202007_get_saved_searches_count_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202007_get_saved_searches_param_search`].
+    pub fn deprecated_202007_get_saved_searches_param_search(
+        &self,
+        customer_saved_search_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_get_saved_searches_param_search(
+                    customer_saved_search_id,
+                    fields,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202007_get_saved_searches_param_search_request`].
+    pub fn deprecated_202007_get_saved_searches_param_search_request<'a>(
+        &'a self,
+        customer_saved_search_id: &'a str,
+    ) -> CustomersDeprecated202007GetSavedSearchesParamSearchRequest<'a> {
+        CustomersDeprecated202007GetSavedSearchesParamSearchRequest {
+            inner: self
+                .inner
+                .deprecated_202007_get_saved_searches_param_search_request(
+                    customer_saved_search_id,
+                ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202007_update_saved_searches_param_search`].
+    pub fn deprecated_202007_update_saved_searches_param_search(
+        &self,
+        customer_saved_search_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_update_saved_searches_param_search(
+                    customer_saved_search_id,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202007_delete_saved_searches_param_search`].
+    pub fn deprecated_202007_delete_saved_searches_param_search(
+        &self,
+        customer_saved_search_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_delete_saved_searches_param_search(customer_saved_search_id),
+        )
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202007_get_saved_searches_param_search_customers`].
+    pub fn deprecated_202007_get_saved_searches_param_search_customers(
+        &self,
+        customer_saved_search_id: &str,
+        order: &str,
+        limit: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_get_saved_searches_param_search_customers(
+                    customer_saved_search_id,
+                    order,
+                    limit,
+                    fields,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202007_get_saved_searches_param_search_customers_request`].
+    pub fn deprecated_202007_get_saved_searches_param_search_customers_request<'a>(
+        &'a self,
+        customer_saved_search_id: &'a str,
+    ) -> CustomersDeprecated202007GetSavedSearchesParamSearchCustomersRequest<'a> {
+        CustomersDeprecated202007GetSavedSearchesParamSearchCustomersRequest {
+            inner: self
+                .inner
+                .deprecated_202007_get_saved_searches_param_search_customers_request(
+                    customer_saved_search_id,
+                ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::customers::Customers::get_saved_searche`].
+    pub fn get_saved_searche(
+        &self,
+        limit: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.get_saved_searche(limit, since_id, fields))
+    }
+    /// The blocking version of [`crate::customers::Customers::get_saved_searche_request`].
+    pub fn get_saved_searche_request(&self) -> CustomersGetSavedSearcheRequest<'_> {
+        CustomersGetSavedSearcheRequest {
+            inner: self.inner.get_saved_searche_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::customers::Customers::create_saved_searches`].
+    pub fn create_saved_searches(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.create_saved_searches(body))
+    }
+    /// The blocking version of [`crate::customers::Customers::get_saved_searches_count`].
+    pub fn get_saved_searches_count(&self, since_id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.get_saved_searches_count(since_id))
+    }
+    /// The blocking version of [`crate::customers::Customers::get_saved_searches_count_request`].
+    pub fn get_saved_searches_count_request(&self) -> CustomersGetSavedSearchesCountRequest<'_> {
+        CustomersGetSavedSearchesCountRequest {
+            inner: self.inner.get_saved_searches_count_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::customers::Customers::get_saved_searches_param_search`].
+    pub fn get_saved_searches_param_search(
+        &self,
+        customer_saved_search_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .get_saved_searches_param_search(customer_saved_search_id, fields),
+        )
+    }
+    /// The blocking version of [`crate::customers::Customers::get_saved_searches_param_search_request`].
+    pub fn get_saved_searches_param_search_request<'a>(
+        &'a self,
+        customer_saved_search_id: &'a str,
+    ) -> CustomersGetSavedSearchesParamSearchRequest<'a> {
+        CustomersGetSavedSearchesParamSearchRequest {
+            inner: self
+                .inner
+                .get_saved_searches_param_search_request(customer_saved_search_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::customers::Customers::update_saved_searches_param_search`].
+    pub fn update_saved_searches_param_search(
+        &self,
+        customer_saved_search_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .update_saved_searches_param_search(customer_saved_search_id, body),
+        )
+    }
+    /// The blocking version of [`crate::customers::Customers::delete_saved_searches_param_search`].
+    pub fn delete_saved_searches_param_search(
+        &self,
+        customer_saved_search_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .delete_saved_searches_param_search(customer_saved_search_id),
+        )
+    }
+    /// The blocking version of [`crate::customers::Customers::get_saved_searches_param_search_customers`].
+    pub fn get_saved_searches_param_search_customers(
+        &self,
+        customer_saved_search_id: &str,
+        order: &str,
+        limit: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.get_saved_searches_param_search_customers(
+                customer_saved_search_id,
+                order,
+                limit,
+                fields,
+            ))
+    }
+    /// The blocking version of [`crate::customers::Customers::get_saved_searches_param_search_customers_request`].
+    pub fn get_saved_searches_param_search_customers_request<'a>(
+        &'a self,
+        customer_saved_search_id: &'a str,
+    ) -> CustomersGetSavedSearchesParamSearchCustomersRequest<'a> {
+        CustomersGetSavedSearchesParamSearchCustomersRequest {
+            inner: self
+                .inner
+                .get_saved_searches_param_search_customers_request(customer_saved_search_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202101_get_saved_searche`].
+    pub fn deprecated_202101_get_saved_searche(
+        &self,
+        limit: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_get_saved_searche(limit, since_id, fields),
+        )
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202101_get_saved_searche_request`].
+    pub fn deprecated_202101_get_saved_searche_request(
+        &self,
+    ) -> CustomersDeprecated202101GetSavedSearcheRequest<'_> {
+        CustomersDeprecated202101GetSavedSearcheRequest {
+            inner: self.inner.deprecated_202101_get_saved_searche_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202101_create_saved_searches`].
+    pub fn deprecated_202101_create_saved_searches(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202101_create_saved_searches(body))
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202101_get_saved_searches_count`].
+    pub fn deprecated_202101_get_saved_searches_count(
+        &self,
+        since_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_get_saved_searches_count(since_id),
+        )
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202101_get_saved_searches_count_request`].
+    pub fn deprecated_202101_get_saved_searches_count_request(
+        &self,
+    ) -> CustomersDeprecated202101GetSavedSearchesCountRequest<'_> {
+        CustomersDeprecated202101GetSavedSearchesCountRequest {
+            inner: self
+                .inner
+                .deprecated_202101_get_saved_searches_count_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202101_get_saved_searches_param_search`].
+    pub fn deprecated_202101_get_saved_searches_param_search(
+        &self,
+        customer_saved_search_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_get_saved_searches_param_search(
+                    customer_saved_search_id,
+                    fields,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202101_get_saved_searches_param_search_request`].
+    pub fn deprecated_202101_get_saved_searches_param_search_request<'a>(
+        &'a self,
+        customer_saved_search_id: &'a str,
+    ) -> CustomersDeprecated202101GetSavedSearchesParamSearchRequest<'a> {
+        CustomersDeprecated202101GetSavedSearchesParamSearchRequest {
+            inner: self
+                .inner
+                .deprecated_202101_get_saved_searches_param_search_request(
+                    customer_saved_search_id,
+                ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202101_update_saved_searches_param_search`].
+    pub fn deprecated_202101_update_saved_searches_param_search(
+        &self,
+        customer_saved_search_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_update_saved_searches_param_search(
+                    customer_saved_search_id,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202101_delete_saved_searches_param_search`].
+    pub fn deprecated_202101_delete_saved_searches_param_search(
+        &self,
+        customer_saved_search_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_delete_saved_searches_param_search(customer_saved_search_id),
+        )
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202101_get_saved_searches_param_search_customers`].
+    pub fn deprecated_202101_get_saved_searches_param_search_customers(
+        &self,
+        customer_saved_search_id: &str,
+        order: &str,
+        limit: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_get_saved_searches_param_search_customers(
+                    customer_saved_search_id,
+                    order,
+                    limit,
+                    fields,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_202101_get_saved_searches_param_search_customers_request`].
+    pub fn deprecated_202101_get_saved_searches_param_search_customers_request<'a>(
+        &'a self,
+        customer_saved_search_id: &'a str,
+    ) -> CustomersDeprecated202101GetSavedSearchesParamSearchCustomersRequest<'a> {
+        CustomersDeprecated202101GetSavedSearchesParamSearchCustomersRequest {
+            inner: self
+                .inner
+                .deprecated_202101_get_saved_searches_param_search_customers_request(
+                    customer_saved_search_id,
+                ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_unstable_get_saved_searche`].
+    pub fn deprecated_unstable_get_saved_searche(
+        &self,
+        limit: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_get_saved_searche(limit, since_id, fields),
+        )
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_unstable_get_saved_searche_request`].
+    pub fn deprecated_unstable_get_saved_searche_request(
+        &self,
+    ) -> CustomersDeprecatedUnstableGetSavedSearcheRequest<'_> {
+        CustomersDeprecatedUnstableGetSavedSearcheRequest {
+            inner: self.inner.deprecated_unstable_get_saved_searche_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_unstable_create_saved_searches`].
+    pub fn deprecated_unstable_create_saved_searches(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_unstable_create_saved_searches(body))
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_unstable_get_saved_searches_count`].
+    pub fn deprecated_unstable_get_saved_searches_count(
+        &self,
+        since_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_get_saved_searches_count(since_id),
+        )
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_unstable_get_saved_searches_count_request`].
+    pub fn deprecated_unstable_get_saved_searches_count_request(
+        &self,
+    ) -> CustomersDeprecatedUnstableGetSavedSearchesCountRequest<'_> {
+        CustomersDeprecatedUnstableGetSavedSearchesCountRequest {
+            inner: self
+                .inner
+                .deprecated_unstable_get_saved_searches_count_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_unstable_get_saved_searches_param_search`].
+    pub fn deprecated_unstable_get_saved_searches_param_search(
+        &self,
+        customer_saved_search_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_get_saved_searches_param_search(
+                    customer_saved_search_id,
+                    fields,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_unstable_get_saved_searches_param_search_request`].
+    pub fn deprecated_unstable_get_saved_searches_param_search_request<'a>(
+        &'a self,
+        customer_saved_search_id: &'a str,
+    ) -> CustomersDeprecatedUnstableGetSavedSearchesParamSearchRequest<'a> {
+        CustomersDeprecatedUnstableGetSavedSearchesParamSearchRequest {
+            inner: self
+                .inner
+                .deprecated_unstable_get_saved_searches_param_search_request(
+                    customer_saved_search_id,
+                ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_unstable_update_saved_searches_param_search`].
+    pub fn deprecated_unstable_update_saved_searches_param_search(
+        &self,
+        customer_saved_search_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_update_saved_searches_param_search(
+                    customer_saved_search_id,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_unstable_delete_saved_searches_param_search`].
+    pub fn deprecated_unstable_delete_saved_searches_param_search(
+        &self,
+        customer_saved_search_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_delete_saved_searches_param_search(customer_saved_search_id),
+        )
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_unstable_get_saved_searches_param_search_customers`].
+    pub fn deprecated_unstable_get_saved_searches_param_search_customers(
+        &self,
+        customer_saved_search_id: &str,
+        order: &str,
+        limit: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_get_saved_searches_param_search_customers(
+                    customer_saved_search_id,
+                    order,
+                    limit,
+                    fields,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::customers::Customers::deprecated_unstable_get_saved_searches_param_search_customers_request`].
+    pub fn deprecated_unstable_get_saved_searches_param_search_customers_request<'a>(
+        &'a self,
+        customer_saved_search_id: &'a str,
+    ) -> CustomersDeprecatedUnstableGetSavedSearchesParamSearchCustomersRequest<'a> {
+        CustomersDeprecatedUnstableGetSavedSearchesParamSearchCustomersRequest {
+            inner: self
+                .inner
+                .deprecated_unstable_get_saved_searches_param_search_customers_request(
+                    customer_saved_search_id,
+                ),
+            runtime: &self.runtime,
+        }
+    }
+}
@@ -0,0 +1,29 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::deprecated_api_calls::DeprecatedApiCalls`].
+pub struct DeprecatedApiCalls {
+    inner: crate::deprecated_api_calls::DeprecatedApiCalls,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl DeprecatedApiCalls {
+    pub(crate) fn new(
+        inner: crate::deprecated_api_calls::DeprecatedApiCalls,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        DeprecatedApiCalls { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::deprecated_api_calls::DeprecatedApiCalls::deprecated_202101_get_call`].
+    pub fn deprecated_202101_get_call(&self) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202101_get_call())
+    }
+    /// The blocking version of [`crate::deprecated_api_calls::DeprecatedApiCalls::deprecated_unstable_get_call`].
+    pub fn deprecated_unstable_get_call(&self) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_unstable_get_call())
+    }
+}
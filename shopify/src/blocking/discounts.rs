@@ -0,0 +1,807 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Discounts::deprecated_202001_get_codes_lookup`], set with a builder.
+pub struct DiscountsDeprecated202001GetCodesLookupRequest<'a> {
+    inner: crate::discounts::DiscountsDeprecated202001GetCodesLookupRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> DiscountsDeprecated202001GetCodesLookupRequest<'a> {
+    /// Sets the `code` query parameter.
+    pub fn code(mut self, code: i64) -> Self {
+        self.inner = self.inner.code(code);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Discounts::deprecated_202004_get_codes_lookup`], set with a builder.
+pub struct DiscountsDeprecated202004GetCodesLookupRequest<'a> {
+    inner: crate::discounts::DiscountsDeprecated202004GetCodesLookupRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> DiscountsDeprecated202004GetCodesLookupRequest<'a> {
+    /// Sets the `code` query parameter.
+    pub fn code(mut self, code: i64) -> Self {
+        self.inner = self.inner.code(code);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Discounts::deprecated_202007_get_codes_lookup`], set with a builder.
+pub struct DiscountsDeprecated202007GetCodesLookupRequest<'a> {
+    inner: crate::discounts::DiscountsDeprecated202007GetCodesLookupRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> DiscountsDeprecated202007GetCodesLookupRequest<'a> {
+    /// Sets the `code` query parameter.
+    pub fn code(mut self, code: i64) -> Self {
+        self.inner = self.inner.code(code);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Discounts::get_codes_lookup`], set with a builder.
+pub struct DiscountsGetCodesLookupRequest<'a> {
+    inner: crate::discounts::DiscountsGetCodesLookupRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> DiscountsGetCodesLookupRequest<'a> {
+    /// Sets the `code` query parameter.
+    pub fn code(mut self, code: i64) -> Self {
+        self.inner = self.inner.code(code);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Discounts::deprecated_202101_get_codes_lookup`], set with a builder.
+pub struct DiscountsDeprecated202101GetCodesLookupRequest<'a> {
+    inner: crate::discounts::DiscountsDeprecated202101GetCodesLookupRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> DiscountsDeprecated202101GetCodesLookupRequest<'a> {
+    /// Sets the `code` query parameter.
+    pub fn code(mut self, code: i64) -> Self {
+        self.inner = self.inner.code(code);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Discounts::deprecated_unstable_get_codes_lookup`], set with a builder.
+pub struct DiscountsDeprecatedUnstableGetCodesLookupRequest<'a> {
+    inner: crate::discounts::DiscountsDeprecatedUnstableGetCodesLookupRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> DiscountsDeprecatedUnstableGetCodesLookupRequest<'a> {
+    /// Sets the `code` query parameter.
+    pub fn code(mut self, code: i64) -> Self {
+        self.inner = self.inner.code(code);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::discounts::Discounts`].
+pub struct Discounts {
+    inner: crate::discounts::Discounts,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Discounts {
+    pub(crate) fn new(
+        inner: crate::discounts::Discounts,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Discounts { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::discounts::Discounts::deprecated_202001_get_price_rules_param_rule_code`].
+    pub fn deprecated_202001_get_price_rules_param_rule_code(
+        &self,
+        price_rule_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_get_price_rules_param_rule_code(price_rule_id),
+        )
+    }
+    /// The blocking version of [`crate::discounts::Discounts::deprecated_202001_create_price_rules_param_rule_codes`].
+    pub fn deprecated_202001_create_price_rules_param_rule_codes(
+        &self,
+        price_rule_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_create_price_rules_param_rule_codes(price_rule_id, body),
+        )
+    }
+    /// The blocking version of [`crate::discounts::Discounts::deprecated_202001_get_price_rules_param_rule_codes_code`].
+    pub fn deprecated_202001_get_price_rules_param_rule_codes_code(
+        &self,
+        price_rule_id: &str,
+        discount_code_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_get_price_rules_param_rule_codes_code(
+                    price_rule_id,
+                    discount_code_id,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::discounts::Discounts::deprecated_202001_update_price_rules_param_rule_codes_code`].
+    pub fn deprecated_202001_update_price_rules_param_rule_codes_code(
+        &self,
+        price_rule_id: &str,
+        discount_code_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_update_price_rules_param_rule_codes_code(
+                    price_rule_id,
+                    discount_code_id,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::discounts::Discounts::deprecated_202001_delete_price_rules_param_rule_codes_code`].
+    pub fn deprecated_202001_delete_price_rules_param_rule_codes_code(
+        &self,
+        price_rule_id: &str,
+        discount_code_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_delete_price_rules_param_rule_codes_code(
+                    price_rule_id,
+                    discount_code_id,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::discounts::Discounts::deprecated_202001_get_codes_lookup`].
+    pub fn deprecated_202001_get_codes_lookup(
+        &self,
+        code: i64,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202001_get_codes_lookup(code))
+    }
+    /// The blocking version of [`crate::discounts::Discounts::deprecated_202001_get_codes_lookup_request`].
+    pub fn deprecated_202001_get_codes_lookup_request(
+        &self,
+    ) -> DiscountsDeprecated202001GetCodesLookupRequest<'_> {
+        DiscountsDeprecated202001GetCodesLookupRequest {
+            inner: self.inner.deprecated_202001_get_codes_lookup_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::discounts::Discounts::deprecated_202001_create_price_rules_param_rule_batch`].
+    pub fn deprecated_202001_create_price_rules_param_rule_batch(
+        &self,
+        price_rule_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_create_price_rules_param_rule_batch(price_rule_id, body),
+        )
+    }
+    /// The blocking version of [`crate::discounts::Discounts::deprecated_202001_get_price_rules_param_rule_batch`].
+    pub fn deprecated_202001_get_price_rules_param_rule_batch(
+        &self,
+        price_rule_id: &str,
+        batch_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_get_price_rules_param_rule_batch(price_rule_id, batch_id),
+        )
+    }
+    /// The blocking version of [`crate::discounts::Discounts::deprecated_202001_get_price_rules_param_rule_batch_code`].
+    pub fn deprecated_202001_get_price_rules_param_rule_batch_code(
+        &self,
+        price_rule_id: &str,
+        batch_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_get_price_rules_param_rule_batch_code(price_rule_id, batch_id),
+        )
+    }
+    /// The blocking version of [`crate::discounts::Discounts::deprecated_202004_get_price_rules_param_rule_code`].
+    pub fn deprecated_202004_get_price_rules_param_rule_code(
+        &self,
+        price_rule_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_get_price_rules_param_rule_code(price_rule_id),
+        )
+    }
+    /// The blocking version of [`crate::discounts::Discounts::deprecated_202004_create_price_rules_param_rule_codes`].
+    pub fn deprecated_202004_create_price_rules_param_rule_codes(
+        &self,
+        price_rule_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_create_price_rules_param_rule_codes(price_rule_id, body),
+        )
+    }
+    /// The blocking version of [`crate::discounts::Discounts::deprecated_202004_get_price_rules_param_rule_codes_code`].
+    pub fn deprecated_202004_get_price_rules_param_rule_codes_code(
+        &self,
+        price_rule_id: &str,
+        discount_code_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_get_price_rules_param_rule_codes_code(
+                    price_rule_id,
+                    discount_code_id,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::discounts::Discounts::deprecated_202004_update_price_rules_param_rule_codes_code`].
+    pub fn deprecated_202004_update_price_rules_param_rule_codes_code(
+        &self,
+        price_rule_id: &str,
+        discount_code_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_update_price_rules_param_rule_codes_code(
+                    price_rule_id,
+                    discount_code_id,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::discounts::Discounts::deprecated_202004_delete_price_rules_param_rule_codes_code`].
+    pub fn deprecated_202004_delete_price_rules_param_rule_codes_code(
+        &self,
+        price_rule_id: &str,
+        discount_code_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_delete_price_rules_param_rule_codes_code(
+                    price_rule_id,
+                    discount_code_id,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::discounts::Discounts::deprecated_202004_get_codes_lookup`].
+    pub fn deprecated_202004_get_codes_lookup(
+        &self,
+        code: i64,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202004_get_codes_lookup(code))
+    }
+    /// The blocking version of [`crate::discounts::Discounts::deprecated_202004_get_codes_lookup_request`].
+    pub fn deprecated_202004_get_codes_lookup_request(
+        &self,
+    ) -> DiscountsDeprecated202004GetCodesLookupRequest<'_> {
+        DiscountsDeprecated202004GetCodesLookupRequest {
+            inner: self.inner.deprecated_202004_get_codes_lookup_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::discounts::Discounts::deprecated_202004_create_price_rules_param_rule_batch`].
+    pub fn deprecated_202004_create_price_rules_param_rule_batch(
+        &self,
+        price_rule_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_create_price_rules_param_rule_batch(price_rule_id, body),
+        )
+    }
+    /// The blocking version of [`crate::discounts::Discounts::deprecated_202004_get_price_rules_param_rule_batch`].
+    pub fn deprecated_202004_get_price_rules_param_rule_batch(
+        &self,
+        price_rule_id: &str,
+        batch_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_get_price_rules_param_rule_batch(price_rule_id, batch_id),
+        )
+    }
+    /// The blocking version of [`crate::discounts::Discounts::deprecated_202004_get_price_rules_param_rule_batch_code`].
+    pub fn deprecated_202004_get_price_rules_param_rule_batch_code(
+        &self,
+        price_rule_id: &str,
+        batch_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_get_price_rules_param_rule_batch_code(price_rule_id, batch_id),
+        )
+    }
+    /// The blocking version of [`crate::discounts::Discounts::deprecated_202007_get_price_rules_param_rule_code`].
+    pub fn deprecated_202007_get_price_rules_param_rule_code(
+        &self,
+        price_rule_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_get_price_rules_param_rule_code(price_rule_id),
+        )
+    }
+    /// The blocking version of [`crate::discounts::Discounts::deprecated_202007_create_price_rules_param_rule_codes`].
+    pub fn deprecated_202007_create_price_rules_param_rule_codes(
+        &self,
+        price_rule_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_create_price_rules_param_rule_codes(price_rule_id, body),
+        )
+    }
+    /// The blocking version of [`crate::discounts::Discounts::deprecated_202007_get_price_rules_param_rule_codes_code`].
+    pub fn deprecated_202007_get_price_rules_param_rule_codes_code(
+        &self,
+        price_rule_id: &str,
+        discount_code_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_get_price_rules_param_rule_codes_code(
+                    price_rule_id,
+                    discount_code_id,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::discounts::Discounts::deprecated_202007_update_price_rules_param_rule_codes_code`].
+    pub fn deprecated_202007_update_price_rules_param_rule_codes_code(
+        &self,
+        price_rule_id: &str,
+        discount_code_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_update_price_rules_param_rule_codes_code(
+                    price_rule_id,
+                    discount_code_id,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::discounts::Discounts::deprecated_202007_delete_price_rules_param_rule_codes_code`].
+    pub fn deprecated_202007_delete_price_rules_param_rule_codes_code(
+        &self,
+        price_rule_id: &str,
+        discount_code_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_delete_price_rules_param_rule_codes_code(
+                    price_rule_id,
+                    discount_code_id,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::discounts::Discounts::deprecated_202007_get_codes_lookup`].
+    pub fn deprecated_202007_get_codes_lookup(
+        &self,
+        code: i64,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202007_get_codes_lookup(code))
+    }
+    /// The blocking version of [`crate::discounts::Discounts::deprecated_202007_get_codes_lookup_request`].
+    pub fn deprecated_202007_get_codes_lookup_request(
+        &self,
+    ) -> DiscountsDeprecated202007GetCodesLookupRequest<'_> {
+        DiscountsDeprecated202007GetCodesLookupRequest {
+            inner: self.inner.deprecated_202007_get_codes_lookup_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::discounts::Discounts::deprecated_202007_create_price_rules_param_rule_batch`].
+    pub fn deprecated_202007_create_price_rules_param_rule_batch(
+        &self,
+        price_rule_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_create_price_rules_param_rule_batch(price_rule_id, body),
+        )
+    }
+    /// The blocking version of [`crate::discounts::Discounts::deprecated_202007_get_price_rules_param_rule_batch`].
+    pub fn deprecated_202007_get_price_rules_param_rule_batch(
+        &self,
+        price_rule_id: &str,
+        batch_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_get_price_rules_param_rule_batch(price_rule_id, batch_id),
+        )
+    }
+    /// The blocking version of [`crate::discounts::Discounts::deprecated_202007_get_price_rules_param_rule_batch_code`].
+    pub fn deprecated_202007_get_price_rules_param_rule_batch_code(
+        &self,
+        price_rule_id: &str,
+        batch_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_get_price_rules_param_rule_batch_code(price_rule_id, batch_id),
+        )
+    }
+    /// The blocking version of [`crate::discounts::Discounts::get_price_rules_param_rule_code`].
+    pub fn get_price_rules_param_rule_code(
+        &self,
+        price_rule_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.get_price_rules_param_rule_code(price_rule_id))
+    }
+    /// The blocking version of [`crate::discounts::Discounts::create_price_rules_param_rule_codes`].
+    pub fn create_price_rules_param_rule_codes(
+        &self,
+        price_rule_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .create_price_rules_param_rule_codes(price_rule_id, body),
+        )
+    }
+    /// The blocking version of [`crate::discounts::Discounts::get_price_rules_param_rule_codes_code`].
+    pub fn get_price_rules_param_rule_codes_code(
+        &self,
+        price_rule_id: &str,
+        discount_code_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .get_price_rules_param_rule_codes_code(price_rule_id, discount_code_id),
+        )
+    }
+    /// The blocking version of [`crate::discounts::Discounts::update_price_rules_param_rule_codes_code`].
+    pub fn update_price_rules_param_rule_codes_code(
+        &self,
+        price_rule_id: &str,
+        discount_code_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.update_price_rules_param_rule_codes_code(
+                price_rule_id,
+                discount_code_id,
+                body,
+            ))
+    }
+    /// The blocking version of [`crate::discounts::Discounts::delete_price_rules_param_rule_codes_code`].
+    pub fn delete_price_rules_param_rule_codes_code(
+        &self,
+        price_rule_id: &str,
+        discount_code_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .delete_price_rules_param_rule_codes_code(price_rule_id, discount_code_id),
+        )
+    }
+    /// The blocking version of [`crate::discounts::Discounts::get_codes_lookup`].
+    pub fn get_codes_lookup(&self, code: i64) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.get_codes_lookup(code))
+    }
+    /// The blocking version of [`crate::discounts::Discounts::get_codes_lookup_request`].
+    pub fn get_codes_lookup_request(&self) -> DiscountsGetCodesLookupRequest<'_> {
+        DiscountsGetCodesLookupRequest {
+            inner: self.inner.get_codes_lookup_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::discounts::Discounts::create_price_rules_param_rule_batch`].
+    pub fn create_price_rules_param_rule_batch(
+        &self,
+        price_rule_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .create_price_rules_param_rule_batch(price_rule_id, body),
+        )
+    }
+    /// The blocking version of [`crate::discounts::Discounts::get_price_rules_param_rule_batch`].
+    pub fn get_price_rules_param_rule_batch(
+        &self,
+        price_rule_id: &str,
+        batch_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .get_price_rules_param_rule_batch(price_rule_id, batch_id),
+        )
+    }
+    /// The blocking version of [`crate::discounts::Discounts::get_price_rules_param_rule_batch_code`].
+    pub fn get_price_rules_param_rule_batch_code(
+        &self,
+        price_rule_id: &str,
+        batch_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .get_price_rules_param_rule_batch_code(price_rule_id, batch_id),
+        )
+    }
+    /// The blocking version of [`crate::discounts::Discounts::deprecated_202101_get_price_rules_param_rule_code`].
+    pub fn deprecated_202101_get_price_rules_param_rule_code(
+        &self,
+        price_rule_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_get_price_rules_param_rule_code(price_rule_id),
+        )
+    }
+    /// The blocking version of [`crate::discounts::Discounts::deprecated_202101_create_price_rules_param_rule_codes`].
+    pub fn deprecated_202101_create_price_rules_param_rule_codes(
+        &self,
+        price_rule_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_create_price_rules_param_rule_codes(price_rule_id, body),
+        )
+    }
+    /// The blocking version of [`crate::discounts::Discounts::deprecated_202101_get_price_rules_param_rule_codes_code`].
+    pub fn deprecated_202101_get_price_rules_param_rule_codes_code(
+        &self,
+        price_rule_id: &str,
+        discount_code_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_get_price_rules_param_rule_codes_code(
+                    price_rule_id,
+                    discount_code_id,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::discounts::Discounts::deprecated_202101_update_price_rules_param_rule_codes_code`].
+    pub fn deprecated_202101_update_price_rules_param_rule_codes_code(
+        &self,
+        price_rule_id: &str,
+        discount_code_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_update_price_rules_param_rule_codes_code(
+                    price_rule_id,
+                    discount_code_id,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::discounts::Discounts::deprecated_202101_delete_price_rules_param_rule_codes_code`].
+    pub fn deprecated_202101_delete_price_rules_param_rule_codes_code(
+        &self,
+        price_rule_id: &str,
+        discount_code_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_delete_price_rules_param_rule_codes_code(
+                    price_rule_id,
+                    discount_code_id,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::discounts::Discounts::deprecated_202101_get_codes_lookup`].
+    pub fn deprecated_202101_get_codes_lookup(
+        &self,
+        code: i64,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202101_get_codes_lookup(code))
+    }
+    /// The blocking version of [`crate::discounts::Discounts::deprecated_202101_get_codes_lookup_request`].
+    pub fn deprecated_202101_get_codes_lookup_request(
+        &self,
+    ) -> DiscountsDeprecated202101GetCodesLookupRequest<'_> {
+        DiscountsDeprecated202101GetCodesLookupRequest {
+            inner: self.inner.deprecated_202101_get_codes_lookup_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::discounts::Discounts::deprecated_202101_create_price_rules_param_rule_batch`].
+    pub fn deprecated_202101_create_price_rules_param_rule_batch(
+        &self,
+        price_rule_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_create_price_rules_param_rule_batch(price_rule_id, body),
+        )
+    }
+    /// The blocking version of [`crate::discounts::Discounts::deprecated_202101_get_price_rules_param_rule_batch`].
+    pub fn deprecated_202101_get_price_rules_param_rule_batch(
+        &self,
+        price_rule_id: &str,
+        batch_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_get_price_rules_param_rule_batch(price_rule_id, batch_id),
+        )
+    }
+    /// The blocking version of [`crate::discounts::Discounts::deprecated_202101_get_price_rules_param_rule_batch_code`].
+    pub fn deprecated_202101_get_price_rules_param_rule_batch_code(
+        &self,
+        price_rule_id: &str,
+        batch_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_get_price_rules_param_rule_batch_code(price_rule_id, batch_id),
+        )
+    }
+    /// The blocking version of [`crate::discounts::Discounts::deprecated_unstable_get_price_rules_param_rule_code`].
+    pub fn deprecated_unstable_get_price_rules_param_rule_code(
+        &self,
+        price_rule_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_get_price_rules_param_rule_code(price_rule_id),
+        )
+    }
+    /// The blocking version of [`crate::discounts::Discounts::deprecated_unstable_create_price_rules_param_rule_codes`].
+    pub fn deprecated_unstable_create_price_rules_param_rule_codes(
+        &self,
+        price_rule_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_create_price_rules_param_rule_codes(price_rule_id, body),
+        )
+    }
+    /// The blocking version of [`crate::discounts::Discounts::deprecated_unstable_get_price_rules_param_rule_codes_code`].
+    pub fn deprecated_unstable_get_price_rules_param_rule_codes_code(
+        &self,
+        price_rule_id: &str,
+        discount_code_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_get_price_rules_param_rule_codes_code(
+                    price_rule_id,
+                    discount_code_id,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::discounts::Discounts::deprecated_unstable_update_price_rules_param_rule_codes_code`].
+    pub fn deprecated_unstable_update_price_rules_param_rule_codes_code(
+        &self,
+        price_rule_id: &str,
+        discount_code_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_update_price_rules_param_rule_codes_code(
+                    price_rule_id,
+                    discount_code_id,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::discounts::Discounts::deprecated_unstable_delete_price_rules_param_rule_codes_code`].
+    pub fn deprecated_unstable_delete_price_rules_param_rule_codes_code(
+        &self,
+        price_rule_id: &str,
+        discount_code_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_delete_price_rules_param_rule_codes_code(
+                    price_rule_id,
+                    discount_code_id,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::discounts::Discounts::deprecated_unstable_get_codes_lookup`].
+    pub fn deprecated_unstable_get_codes_lookup(
+        &self,
+        code: i64,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_unstable_get_codes_lookup(code))
+    }
+    /// The blocking version of [`crate::discounts::Discounts::deprecated_unstable_get_codes_lookup_request`].
+    pub fn deprecated_unstable_get_codes_lookup_request(
+        &self,
+    ) -> DiscountsDeprecatedUnstableGetCodesLookupRequest<'_> {
+        DiscountsDeprecatedUnstableGetCodesLookupRequest {
+            inner: self.inner.deprecated_unstable_get_codes_lookup_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::discounts::Discounts::deprecated_unstable_create_price_rules_param_rule_batch`].
+    pub fn deprecated_unstable_create_price_rules_param_rule_batch(
+        &self,
+        price_rule_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_create_price_rules_param_rule_batch(price_rule_id, body),
+        )
+    }
+    /// The blocking version of [`crate::discounts::Discounts::deprecated_unstable_get_price_rules_param_rule_batch`].
+    pub fn deprecated_unstable_get_price_rules_param_rule_batch(
+        &self,
+        price_rule_id: &str,
+        batch_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_get_price_rules_param_rule_batch(price_rule_id, batch_id),
+        )
+    }
+    /// The blocking version of [`crate::discounts::Discounts::deprecated_unstable_get_price_rules_param_rule_batch_code`].
+    pub fn deprecated_unstable_get_price_rules_param_rule_batch_code(
+        &self,
+        price_rule_id: &str,
+        batch_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_get_price_rules_param_rule_batch_code(price_rule_id, batch_id),
+        )
+    }
+}
@@ -0,0 +1,2466 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Events::deprecated_202001_get`], set with a builder.
+pub struct EventsDeprecated202001GetRequest<'a> {
+    inner: crate::events::EventsDeprecated202001GetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> EventsDeprecated202001GetRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.inner = self.inner.created_at_min(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.inner = self.inner.created_at_max(created_at_max);
+        self
+    }
+
+    /// Sets the `filter` query parameter.
+    pub fn filter(mut self, filter: &'a str) -> Self {
+        self.inner = self.inner.filter(filter);
+        self
+    }
+
+    /// Sets the `verb` query parameter.
+    pub fn verb(mut self, verb: &'a str) -> Self {
+        self.inner = self.inner.verb(verb);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Events::deprecated_202001_get_param`], set with a builder.
+pub struct EventsDeprecated202001GetParamRequest<'a> {
+    inner: crate::events::EventsDeprecated202001GetParamRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> EventsDeprecated202001GetParamRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Events::deprecated_202001_get_count`], set with a builder.
+pub struct EventsDeprecated202001GetCountRequest<'a> {
+    inner: crate::events::EventsDeprecated202001GetCountRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> EventsDeprecated202001GetCountRequest<'a> {
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.inner = self.inner.created_at_min(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.inner = self.inner.created_at_max(created_at_max);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Events::deprecated_202004_get`], set with a builder.
+pub struct EventsDeprecated202004GetRequest<'a> {
+    inner: crate::events::EventsDeprecated202004GetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> EventsDeprecated202004GetRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.inner = self.inner.created_at_min(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.inner = self.inner.created_at_max(created_at_max);
+        self
+    }
+
+    /// Sets the `filter` query parameter.
+    pub fn filter(mut self, filter: &'a str) -> Self {
+        self.inner = self.inner.filter(filter);
+        self
+    }
+
+    /// Sets the `verb` query parameter.
+    pub fn verb(mut self, verb: &'a str) -> Self {
+        self.inner = self.inner.verb(verb);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Events::deprecated_202004_get_param`], set with a builder.
+pub struct EventsDeprecated202004GetParamRequest<'a> {
+    inner: crate::events::EventsDeprecated202004GetParamRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> EventsDeprecated202004GetParamRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Events::deprecated_202004_get_count`], set with a builder.
+pub struct EventsDeprecated202004GetCountRequest<'a> {
+    inner: crate::events::EventsDeprecated202004GetCountRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> EventsDeprecated202004GetCountRequest<'a> {
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.inner = self.inner.created_at_min(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.inner = self.inner.created_at_max(created_at_max);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Events::deprecated_202007_get`], set with a builder.
+pub struct EventsDeprecated202007GetRequest<'a> {
+    inner: crate::events::EventsDeprecated202007GetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> EventsDeprecated202007GetRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.inner = self.inner.created_at_min(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.inner = self.inner.created_at_max(created_at_max);
+        self
+    }
+
+    /// Sets the `filter` query parameter.
+    pub fn filter(mut self, filter: &'a str) -> Self {
+        self.inner = self.inner.filter(filter);
+        self
+    }
+
+    /// Sets the `verb` query parameter.
+    pub fn verb(mut self, verb: &'a str) -> Self {
+        self.inner = self.inner.verb(verb);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Events::deprecated_202007_get_param`], set with a builder.
+pub struct EventsDeprecated202007GetParamRequest<'a> {
+    inner: crate::events::EventsDeprecated202007GetParamRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> EventsDeprecated202007GetParamRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Events::deprecated_202007_get_count`], set with a builder.
+pub struct EventsDeprecated202007GetCountRequest<'a> {
+    inner: crate::events::EventsDeprecated202007GetCountRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> EventsDeprecated202007GetCountRequest<'a> {
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.inner = self.inner.created_at_min(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.inner = self.inner.created_at_max(created_at_max);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Events::get`], set with a builder.
+pub struct EventsGetRequest<'a> {
+    inner: crate::events::EventsGetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> EventsGetRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.inner = self.inner.created_at_min(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.inner = self.inner.created_at_max(created_at_max);
+        self
+    }
+
+    /// Sets the `filter` query parameter.
+    pub fn filter(mut self, filter: &'a str) -> Self {
+        self.inner = self.inner.filter(filter);
+        self
+    }
+
+    /// Sets the `verb` query parameter.
+    pub fn verb(mut self, verb: &'a str) -> Self {
+        self.inner = self.inner.verb(verb);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Events::get_param`], set with a builder.
+pub struct EventsGetParamRequest<'a> {
+    inner: crate::events::EventsGetParamRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> EventsGetParamRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Events::get_count`], set with a builder.
+pub struct EventsGetCountRequest<'a> {
+    inner: crate::events::EventsGetCountRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> EventsGetCountRequest<'a> {
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.inner = self.inner.created_at_min(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.inner = self.inner.created_at_max(created_at_max);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Events::deprecated_202101_get`], set with a builder.
+pub struct EventsDeprecated202101GetRequest<'a> {
+    inner: crate::events::EventsDeprecated202101GetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> EventsDeprecated202101GetRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.inner = self.inner.created_at_min(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.inner = self.inner.created_at_max(created_at_max);
+        self
+    }
+
+    /// Sets the `filter` query parameter.
+    pub fn filter(mut self, filter: &'a str) -> Self {
+        self.inner = self.inner.filter(filter);
+        self
+    }
+
+    /// Sets the `verb` query parameter.
+    pub fn verb(mut self, verb: &'a str) -> Self {
+        self.inner = self.inner.verb(verb);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Events::deprecated_202101_get_param`], set with a builder.
+pub struct EventsDeprecated202101GetParamRequest<'a> {
+    inner: crate::events::EventsDeprecated202101GetParamRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> EventsDeprecated202101GetParamRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Events::deprecated_202101_get_count`], set with a builder.
+pub struct EventsDeprecated202101GetCountRequest<'a> {
+    inner: crate::events::EventsDeprecated202101GetCountRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> EventsDeprecated202101GetCountRequest<'a> {
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.inner = self.inner.created_at_min(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.inner = self.inner.created_at_max(created_at_max);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Events::deprecated_unstable_get`], set with a builder.
+pub struct EventsDeprecatedUnstableGetRequest<'a> {
+    inner: crate::events::EventsDeprecatedUnstableGetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> EventsDeprecatedUnstableGetRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.inner = self.inner.created_at_min(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.inner = self.inner.created_at_max(created_at_max);
+        self
+    }
+
+    /// Sets the `filter` query parameter.
+    pub fn filter(mut self, filter: &'a str) -> Self {
+        self.inner = self.inner.filter(filter);
+        self
+    }
+
+    /// Sets the `verb` query parameter.
+    pub fn verb(mut self, verb: &'a str) -> Self {
+        self.inner = self.inner.verb(verb);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Events::deprecated_unstable_get_param`], set with a builder.
+pub struct EventsDeprecatedUnstableGetParamRequest<'a> {
+    inner: crate::events::EventsDeprecatedUnstableGetParamRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> EventsDeprecatedUnstableGetParamRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Events::deprecated_unstable_get_count`], set with a builder.
+pub struct EventsDeprecatedUnstableGetCountRequest<'a> {
+    inner: crate::events::EventsDeprecatedUnstableGetCountRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> EventsDeprecatedUnstableGetCountRequest<'a> {
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.inner = self.inner.created_at_min(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.inner = self.inner.created_at_max(created_at_max);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Events::deprecated_202001_get_webhook`], set with a builder.
+pub struct EventsDeprecated202001GetWebhookRequest<'a> {
+    inner: crate::events::EventsDeprecated202001GetWebhookRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> EventsDeprecated202001GetWebhookRequest<'a> {
+    /// Sets the `address` query parameter.
+    pub fn address(mut self, address: &'a str) -> Self {
+        self.inner = self.inner.address(address);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.inner = self.inner.created_at_max(created_at_max);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.inner = self.inner.created_at_min(created_at_min);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `topic` query parameter.
+    pub fn topic(mut self, topic: &'a str) -> Self {
+        self.inner = self.inner.topic(topic);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.inner = self.inner.updated_at_min(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.inner = self.inner.updated_at_max(updated_at_max);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Events::deprecated_202001_create_webhooks`], set with a builder.
+pub struct EventsDeprecated202001CreateWebhooksRequest<'a> {
+    inner: crate::events::EventsDeprecated202001CreateWebhooksRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> EventsDeprecated202001CreateWebhooksRequest<'a> {
+    /// Sets the `format` query parameter.
+    pub fn format(mut self, format: &'a str) -> Self {
+        self.inner = self.inner.format(format);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Events::deprecated_202001_get_webhooks_count`], set with a builder.
+pub struct EventsDeprecated202001GetWebhooksCountRequest<'a> {
+    inner: crate::events::EventsDeprecated202001GetWebhooksCountRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> EventsDeprecated202001GetWebhooksCountRequest<'a> {
+    /// Sets the `address` query parameter.
+    pub fn address(mut self, address: &'a str) -> Self {
+        self.inner = self.inner.address(address);
+        self
+    }
+
+    /// Sets the `topic` query parameter.
+    pub fn topic(mut self, topic: &'a str) -> Self {
+        self.inner = self.inner.topic(topic);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Events::deprecated_202001_get_webhooks_param_webhook`], set with a builder.
+pub struct EventsDeprecated202001GetWebhooksParamWebhookRequest<'a> {
+    inner: crate::events::EventsDeprecated202001GetWebhooksParamWebhookRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> EventsDeprecated202001GetWebhooksParamWebhookRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Events::deprecated_202004_get_webhook`], set with a builder.
+pub struct EventsDeprecated202004GetWebhookRequest<'a> {
+    inner: crate::events::EventsDeprecated202004GetWebhookRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> EventsDeprecated202004GetWebhookRequest<'a> {
+    /// Sets the `address` query parameter.
+    pub fn address(mut self, address: &'a str) -> Self {
+        self.inner = self.inner.address(address);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.inner = self.inner.created_at_max(created_at_max);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.inner = self.inner.created_at_min(created_at_min);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `topic` query parameter.
+    pub fn topic(mut self, topic: &'a str) -> Self {
+        self.inner = self.inner.topic(topic);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.inner = self.inner.updated_at_min(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.inner = self.inner.updated_at_max(updated_at_max);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Events::deprecated_202004_create_webhooks`], set with a builder.
+pub struct EventsDeprecated202004CreateWebhooksRequest<'a> {
+    inner: crate::events::EventsDeprecated202004CreateWebhooksRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> EventsDeprecated202004CreateWebhooksRequest<'a> {
+    /// Sets the `format` query parameter.
+    pub fn format(mut self, format: &'a str) -> Self {
+        self.inner = self.inner.format(format);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Events::deprecated_202004_get_webhooks_count`], set with a builder.
+pub struct EventsDeprecated202004GetWebhooksCountRequest<'a> {
+    inner: crate::events::EventsDeprecated202004GetWebhooksCountRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> EventsDeprecated202004GetWebhooksCountRequest<'a> {
+    /// Sets the `address` query parameter.
+    pub fn address(mut self, address: &'a str) -> Self {
+        self.inner = self.inner.address(address);
+        self
+    }
+
+    /// Sets the `topic` query parameter.
+    pub fn topic(mut self, topic: &'a str) -> Self {
+        self.inner = self.inner.topic(topic);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Events::deprecated_202004_get_webhooks_param_webhook`], set with a builder.
+pub struct EventsDeprecated202004GetWebhooksParamWebhookRequest<'a> {
+    inner: crate::events::EventsDeprecated202004GetWebhooksParamWebhookRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> EventsDeprecated202004GetWebhooksParamWebhookRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Events::deprecated_202007_get_webhook`], set with a builder.
+pub struct EventsDeprecated202007GetWebhookRequest<'a> {
+    inner: crate::events::EventsDeprecated202007GetWebhookRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> EventsDeprecated202007GetWebhookRequest<'a> {
+    /// Sets the `address` query parameter.
+    pub fn address(mut self, address: &'a str) -> Self {
+        self.inner = self.inner.address(address);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.inner = self.inner.created_at_max(created_at_max);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.inner = self.inner.created_at_min(created_at_min);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `topic` query parameter.
+    pub fn topic(mut self, topic: &'a str) -> Self {
+        self.inner = self.inner.topic(topic);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.inner = self.inner.updated_at_min(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.inner = self.inner.updated_at_max(updated_at_max);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Events::deprecated_202007_create_webhooks`], set with a builder.
+pub struct EventsDeprecated202007CreateWebhooksRequest<'a> {
+    inner: crate::events::EventsDeprecated202007CreateWebhooksRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> EventsDeprecated202007CreateWebhooksRequest<'a> {
+    /// Sets the `format` query parameter.
+    pub fn format(mut self, format: &'a str) -> Self {
+        self.inner = self.inner.format(format);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Events::deprecated_202007_get_webhooks_count`], set with a builder.
+pub struct EventsDeprecated202007GetWebhooksCountRequest<'a> {
+    inner: crate::events::EventsDeprecated202007GetWebhooksCountRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> EventsDeprecated202007GetWebhooksCountRequest<'a> {
+    /// Sets the `address` query parameter.
+    pub fn address(mut self, address: &'a str) -> Self {
+        self.inner = self.inner.address(address);
+        self
+    }
+
+    /// Sets the `topic` query parameter.
+    pub fn topic(mut self, topic: &'a str) -> Self {
+        self.inner = self.inner.topic(topic);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Events::deprecated_202007_get_webhooks_param_webhook`], set with a builder.
+pub struct EventsDeprecated202007GetWebhooksParamWebhookRequest<'a> {
+    inner: crate::events::EventsDeprecated202007GetWebhooksParamWebhookRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> EventsDeprecated202007GetWebhooksParamWebhookRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Events::get_webhook`], set with a builder.
+pub struct EventsGetWebhookRequest<'a> {
+    inner: crate::events::EventsGetWebhookRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> EventsGetWebhookRequest<'a> {
+    /// Sets the `address` query parameter.
+    pub fn address(mut self, address: &'a str) -> Self {
+        self.inner = self.inner.address(address);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.inner = self.inner.created_at_max(created_at_max);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.inner = self.inner.created_at_min(created_at_min);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `topic` query parameter.
+    pub fn topic(mut self, topic: &'a str) -> Self {
+        self.inner = self.inner.topic(topic);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.inner = self.inner.updated_at_min(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.inner = self.inner.updated_at_max(updated_at_max);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Events::create_webhooks`], set with a builder.
+pub struct EventsCreateWebhooksRequest<'a> {
+    inner: crate::events::EventsCreateWebhooksRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> EventsCreateWebhooksRequest<'a> {
+    /// Sets the `format` query parameter.
+    pub fn format(mut self, format: &'a str) -> Self {
+        self.inner = self.inner.format(format);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Events::get_webhooks_count`], set with a builder.
+pub struct EventsGetWebhooksCountRequest<'a> {
+    inner: crate::events::EventsGetWebhooksCountRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> EventsGetWebhooksCountRequest<'a> {
+    /// Sets the `address` query parameter.
+    pub fn address(mut self, address: &'a str) -> Self {
+        self.inner = self.inner.address(address);
+        self
+    }
+
+    /// Sets the `topic` query parameter.
+    pub fn topic(mut self, topic: &'a str) -> Self {
+        self.inner = self.inner.topic(topic);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Events::get_webhooks_param_webhook`], set with a builder.
+pub struct EventsGetWebhooksParamWebhookRequest<'a> {
+    inner: crate::events::EventsGetWebhooksParamWebhookRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> EventsGetWebhooksParamWebhookRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Events::deprecated_202101_get_webhook`], set with a builder.
+pub struct EventsDeprecated202101GetWebhookRequest<'a> {
+    inner: crate::events::EventsDeprecated202101GetWebhookRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> EventsDeprecated202101GetWebhookRequest<'a> {
+    /// Sets the `address` query parameter.
+    pub fn address(mut self, address: &'a str) -> Self {
+        self.inner = self.inner.address(address);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.inner = self.inner.created_at_max(created_at_max);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.inner = self.inner.created_at_min(created_at_min);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `topic` query parameter.
+    pub fn topic(mut self, topic: &'a str) -> Self {
+        self.inner = self.inner.topic(topic);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.inner = self.inner.updated_at_min(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.inner = self.inner.updated_at_max(updated_at_max);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Events::deprecated_202101_create_webhooks`], set with a builder.
+pub struct EventsDeprecated202101CreateWebhooksRequest<'a> {
+    inner: crate::events::EventsDeprecated202101CreateWebhooksRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> EventsDeprecated202101CreateWebhooksRequest<'a> {
+    /// Sets the `format` query parameter.
+    pub fn format(mut self, format: &'a str) -> Self {
+        self.inner = self.inner.format(format);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Events::deprecated_202101_get_webhooks_count`], set with a builder.
+pub struct EventsDeprecated202101GetWebhooksCountRequest<'a> {
+    inner: crate::events::EventsDeprecated202101GetWebhooksCountRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> EventsDeprecated202101GetWebhooksCountRequest<'a> {
+    /// Sets the `address` query parameter.
+    pub fn address(mut self, address: &'a str) -> Self {
+        self.inner = self.inner.address(address);
+        self
+    }
+
+    /// Sets the `topic` query parameter.
+    pub fn topic(mut self, topic: &'a str) -> Self {
+        self.inner = self.inner.topic(topic);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Events::deprecated_202101_get_webhooks_param_webhook`], set with a builder.
+pub struct EventsDeprecated202101GetWebhooksParamWebhookRequest<'a> {
+    inner: crate::events::EventsDeprecated202101GetWebhooksParamWebhookRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> EventsDeprecated202101GetWebhooksParamWebhookRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Events::deprecated_unstable_get_webhook`], set with a builder.
+pub struct EventsDeprecatedUnstableGetWebhookRequest<'a> {
+    inner: crate::events::EventsDeprecatedUnstableGetWebhookRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> EventsDeprecatedUnstableGetWebhookRequest<'a> {
+    /// Sets the `address` query parameter.
+    pub fn address(mut self, address: &'a str) -> Self {
+        self.inner = self.inner.address(address);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.inner = self.inner.created_at_max(created_at_max);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.inner = self.inner.created_at_min(created_at_min);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `topic` query parameter.
+    pub fn topic(mut self, topic: &'a str) -> Self {
+        self.inner = self.inner.topic(topic);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.inner = self.inner.updated_at_min(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.inner = self.inner.updated_at_max(updated_at_max);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Events::deprecated_unstable_create_webhooks`], set with a builder.
+pub struct EventsDeprecatedUnstableCreateWebhooksRequest<'a> {
+    inner: crate::events::EventsDeprecatedUnstableCreateWebhooksRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> EventsDeprecatedUnstableCreateWebhooksRequest<'a> {
+    /// Sets the `format` query parameter.
+    pub fn format(mut self, format: &'a str) -> Self {
+        self.inner = self.inner.format(format);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Events::deprecated_unstable_get_webhooks_count`], set with a builder.
+pub struct EventsDeprecatedUnstableGetWebhooksCountRequest<'a> {
+    inner: crate::events::EventsDeprecatedUnstableGetWebhooksCountRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> EventsDeprecatedUnstableGetWebhooksCountRequest<'a> {
+    /// Sets the `address` query parameter.
+    pub fn address(mut self, address: &'a str) -> Self {
+        self.inner = self.inner.address(address);
+        self
+    }
+
+    /// Sets the `topic` query parameter.
+    pub fn topic(mut self, topic: &'a str) -> Self {
+        self.inner = self.inner.topic(topic);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Events::deprecated_unstable_get_webhooks_param_webhook`], set with a builder.
+pub struct EventsDeprecatedUnstableGetWebhooksParamWebhookRequest<'a> {
+    inner: crate::events::EventsDeprecatedUnstableGetWebhooksParamWebhookRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> EventsDeprecatedUnstableGetWebhooksParamWebhookRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::events::Events`].
+pub struct Events {
+    inner: crate::events::Events,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Events {
+    pub(crate) fn new(inner: crate::events::Events, runtime: Arc<tokio::runtime::Runtime>) -> Self {
+        Events { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::events::Events::deprecated_202001_get`].
+    pub fn deprecated_202001_get(
+        &self,
+        limit: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        filter: &str,
+        verb: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.deprecated_202001_get(
+            limit,
+            since_id,
+            created_at_min,
+            created_at_max,
+            filter,
+            verb,
+            fields,
+        ))
+    }
+    /// The blocking version of [`crate::events::Events::deprecated_202001_get_request`].
+    pub fn deprecated_202001_get_request(&self) -> EventsDeprecated202001GetRequest<'_> {
+        EventsDeprecated202001GetRequest {
+            inner: self.inner.deprecated_202001_get_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::events::Events::deprecated_202001_get_param`].
+    pub fn deprecated_202001_get_param(
+        &self,
+        event_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202001_get_param(event_id, fields))
+    }
+    /// The blocking version of [`crate::events::Events::deprecated_202001_get_param_request`].
+    pub fn deprecated_202001_get_param_request<'a>(
+        &'a self,
+        event_id: &'a str,
+    ) -> EventsDeprecated202001GetParamRequest<'a> {
+        EventsDeprecated202001GetParamRequest {
+            inner: self.inner.deprecated_202001_get_param_request(event_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::events::Events::deprecated_202001_get_count`].
+    pub fn deprecated_202001_get_count(
+        &self,
+        created_at_min: &str,
+        created_at_max: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_get_count(created_at_min, created_at_max),
+        )
+    }
+    /// The blocking version of [`crate::events::Events::deprecated_202001_get_count_request`].
+    pub fn deprecated_202001_get_count_request(&self) -> EventsDeprecated202001GetCountRequest<'_> {
+        EventsDeprecated202001GetCountRequest {
+            inner: self.inner.deprecated_202001_get_count_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::events::Events::deprecated_202004_get`].
+    pub fn deprecated_202004_get(
+        &self,
+        limit: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        filter: &str,
+        verb: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.deprecated_202004_get(
+            limit,
+            since_id,
+            created_at_min,
+            created_at_max,
+            filter,
+            verb,
+            fields,
+        ))
+    }
+    /// The blocking version of [`crate::events::Events::deprecated_202004_get_request`].
+    pub fn deprecated_202004_get_request(&self) -> EventsDeprecated202004GetRequest<'_> {
+        EventsDeprecated202004GetRequest {
+            inner: self.inner.deprecated_202004_get_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::events::Events::deprecated_202004_get_param`].
+    pub fn deprecated_202004_get_param(
+        &self,
+        event_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202004_get_param(event_id, fields))
+    }
+    /// The blocking version of [`crate::events::Events::deprecated_202004_get_param_request`].
+    pub fn deprecated_202004_get_param_request<'a>(
+        &'a self,
+        event_id: &'a str,
+    ) -> EventsDeprecated202004GetParamRequest<'a> {
+        EventsDeprecated202004GetParamRequest {
+            inner: self.inner.deprecated_202004_get_param_request(event_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::events::Events::deprecated_202004_get_count`].
+    pub fn deprecated_202004_get_count(
+        &self,
+        created_at_min: &str,
+        created_at_max: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_get_count(created_at_min, created_at_max),
+        )
+    }
+    /// The blocking version of [`crate::events::Events::deprecated_202004_get_count_request`].
+    pub fn deprecated_202004_get_count_request(&self) -> EventsDeprecated202004GetCountRequest<'_> {
+        EventsDeprecated202004GetCountRequest {
+            inner: self.inner.deprecated_202004_get_count_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::events::Events::deprecated_202007_get`].
+    pub fn deprecated_202007_get(
+        &self,
+        limit: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        filter: &str,
+        verb: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.deprecated_202007_get(
+            limit,
+            since_id,
+            created_at_min,
+            created_at_max,
+            filter,
+            verb,
+            fields,
+        ))
+    }
+    /// The blocking version of [`crate::events::Events::deprecated_202007_get_request`].
+    pub fn deprecated_202007_get_request(&self) -> EventsDeprecated202007GetRequest<'_> {
+        EventsDeprecated202007GetRequest {
+            inner: self.inner.deprecated_202007_get_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::events::Events::deprecated_202007_get_param`].
+    pub fn deprecated_202007_get_param(
+        &self,
+        event_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202007_get_param(event_id, fields))
+    }
+    /// The blocking version of [`crate::events::Events::deprecated_202007_get_param_request`].
+    pub fn deprecated_202007_get_param_request<'a>(
+        &'a self,
+        event_id: &'a str,
+    ) -> EventsDeprecated202007GetParamRequest<'a> {
+        EventsDeprecated202007GetParamRequest {
+            inner: self.inner.deprecated_202007_get_param_request(event_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::events::Events::deprecated_202007_get_count`].
+    pub fn deprecated_202007_get_count(
+        &self,
+        created_at_min: &str,
+        created_at_max: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_get_count(created_at_min, created_at_max),
+        )
+    }
+    /// The blocking version of [`crate::events::Events::deprecated_202007_get_count_request`].
+    pub fn deprecated_202007_get_count_request(&self) -> EventsDeprecated202007GetCountRequest<'_> {
+        EventsDeprecated202007GetCountRequest {
+            inner: self.inner.deprecated_202007_get_count_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::events::Events::get`].
+    pub fn get(
+        &self,
+        limit: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        filter: &str,
+        verb: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.get(
+            limit,
+            since_id,
+            created_at_min,
+            created_at_max,
+            filter,
+            verb,
+            fields,
+        ))
+    }
+    /// The blocking version of [`crate::events::Events::get_request`].
+    pub fn get_request(&self) -> EventsGetRequest<'_> {
+        EventsGetRequest {
+            inner: self.inner.get_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::events::Events::get_param`].
+    pub fn get_param(&self, event_id: &str, fields: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.get_param(event_id, fields))
+    }
+    /// The blocking version of [`crate::events::Events::get_param_request`].
+    pub fn get_param_request<'a>(&'a self, event_id: &'a str) -> EventsGetParamRequest<'a> {
+        EventsGetParamRequest {
+            inner: self.inner.get_param_request(event_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::events::Events::get_count`].
+    pub fn get_count(
+        &self,
+        created_at_min: &str,
+        created_at_max: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.get_count(created_at_min, created_at_max))
+    }
+    /// The blocking version of [`crate::events::Events::get_count_request`].
+    pub fn get_count_request(&self) -> EventsGetCountRequest<'_> {
+        EventsGetCountRequest {
+            inner: self.inner.get_count_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::events::Events::deprecated_202101_get`].
+    pub fn deprecated_202101_get(
+        &self,
+        limit: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        filter: &str,
+        verb: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.deprecated_202101_get(
+            limit,
+            since_id,
+            created_at_min,
+            created_at_max,
+            filter,
+            verb,
+            fields,
+        ))
+    }
+    /// The blocking version of [`crate::events::Events::deprecated_202101_get_request`].
+    pub fn deprecated_202101_get_request(&self) -> EventsDeprecated202101GetRequest<'_> {
+        EventsDeprecated202101GetRequest {
+            inner: self.inner.deprecated_202101_get_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::events::Events::deprecated_202101_get_param`].
+    pub fn deprecated_202101_get_param(
+        &self,
+        event_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202101_get_param(event_id, fields))
+    }
+    /// The blocking version of [`crate::events::Events::deprecated_202101_get_param_request`].
+    pub fn deprecated_202101_get_param_request<'a>(
+        &'a self,
+        event_id: &'a str,
+    ) -> EventsDeprecated202101GetParamRequest<'a> {
+        EventsDeprecated202101GetParamRequest {
+            inner: self.inner.deprecated_202101_get_param_request(event_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::events::Events::deprecated_202101_get_count`].
+    pub fn deprecated_202101_get_count(
+        &self,
+        created_at_min: &str,
+        created_at_max: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_get_count(created_at_min, created_at_max),
+        )
+    }
+    /// The blocking version of [`crate::events::Events::deprecated_202101_get_count_request`].
+    pub fn deprecated_202101_get_count_request(&self) -> EventsDeprecated202101GetCountRequest<'_> {
+        EventsDeprecated202101GetCountRequest {
+            inner: self.inner.deprecated_202101_get_count_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::events::Events::deprecated_unstable_get`].
+    pub fn deprecated_unstable_get(
+        &self,
+        limit: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        filter: &str,
+        verb: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.deprecated_unstable_get(
+            limit,
+            since_id,
+            created_at_min,
+            created_at_max,
+            filter,
+            verb,
+            fields,
+        ))
+    }
+    /// The blocking version of [`crate::events::Events::deprecated_unstable_get_request`].
+    pub fn deprecated_unstable_get_request(&self) -> EventsDeprecatedUnstableGetRequest<'_> {
+        EventsDeprecatedUnstableGetRequest {
+            inner: self.inner.deprecated_unstable_get_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::events::Events::deprecated_unstable_get_param`].
+    pub fn deprecated_unstable_get_param(
+        &self,
+        event_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_unstable_get_param(event_id, fields))
+    }
+    /// The blocking version of [`crate::events::Events::deprecated_unstable_get_param_request`].
+    pub fn deprecated_unstable_get_param_request<'a>(
+        &'a self,
+        event_id: &'a str,
+    ) -> EventsDeprecatedUnstableGetParamRequest<'a> {
+        EventsDeprecatedUnstableGetParamRequest {
+            inner: self.inner.deprecated_unstable_get_param_request(event_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::events::Events::deprecated_unstable_get_count`].
+    pub fn deprecated_unstable_get_count(
+        &self,
+        created_at_min: &str,
+        created_at_max: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_get_count(created_at_min, created_at_max),
+        )
+    }
+    /// The blocking version of [`crate::events::Events::deprecated_unstable_get_count_request`].
+    pub fn deprecated_unstable_get_count_request(
+        &self,
+    ) -> EventsDeprecatedUnstableGetCountRequest<'_> {
+        EventsDeprecatedUnstableGetCountRequest {
+            inner: self.inner.deprecated_unstable_get_count_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::events::Events::deprecated_202001_get_webhook`].
+    pub fn deprecated_202001_get_webhook(
+        &self,
+        address: &str,
+        created_at_max: &str,
+        created_at_min: &str,
+        fields: &str,
+        limit: &str,
+        since_id: &str,
+        topic: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202001_get_webhook(
+                address,
+                created_at_max,
+                created_at_min,
+                fields,
+                limit,
+                since_id,
+                topic,
+                updated_at_min,
+                updated_at_max,
+            ))
+    }
+    /// The blocking version of [`crate::events::Events::deprecated_202001_get_webhook_request`].
+    pub fn deprecated_202001_get_webhook_request(
+        &self,
+    ) -> EventsDeprecated202001GetWebhookRequest<'_> {
+        EventsDeprecated202001GetWebhookRequest {
+            inner: self.inner.deprecated_202001_get_webhook_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::events::Events::deprecated_202001_create_webhooks`].
+    pub fn deprecated_202001_create_webhooks(
+        &self,
+        format: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202001_create_webhooks(format, body))
+    }
+    /// The blocking version of [`crate::events::Events::deprecated_202001_create_webhooks_request`].
+    pub fn deprecated_202001_create_webhooks_request<'a>(
+        &'a self,
+        body: &'a serde_json::Value,
+    ) -> EventsDeprecated202001CreateWebhooksRequest<'a> {
+        EventsDeprecated202001CreateWebhooksRequest {
+            inner: self.inner.deprecated_202001_create_webhooks_request(body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::events::Events::deprecated_202001_get_webhooks_count`].
+    pub fn deprecated_202001_get_webhooks_count(
+        &self,
+        address: &str,
+        topic: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_get_webhooks_count(address, topic),
+        )
+    }
+    /// The blocking version of [`crate::events::Events::deprecated_202001_get_webhooks_count_request`].
+    pub fn deprecated_202001_get_webhooks_count_request(
+        &self,
+    ) -> EventsDeprecated202001GetWebhooksCountRequest<'_> {
+        EventsDeprecated202001GetWebhooksCountRequest {
+            inner: self.inner.deprecated_202001_get_webhooks_count_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::events::Events::deprecated_202001_get_webhooks_param_webhook`].
+    pub fn deprecated_202001_get_webhooks_param_webhook(
+        &self,
+        webhook_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_get_webhooks_param_webhook(webhook_id, fields),
+        )
+    }
+    /// The blocking version of [`crate::events::Events::deprecated_202001_get_webhooks_param_webhook_request`].
+    pub fn deprecated_202001_get_webhooks_param_webhook_request<'a>(
+        &'a self,
+        webhook_id: &'a str,
+    ) -> EventsDeprecated202001GetWebhooksParamWebhookRequest<'a> {
+        EventsDeprecated202001GetWebhooksParamWebhookRequest {
+            inner: self
+                .inner
+                .deprecated_202001_get_webhooks_param_webhook_request(webhook_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::events::Events::deprecated_202001_update_webhooks_param_webhook`].
+    pub fn deprecated_202001_update_webhooks_param_webhook(
+        &self,
+        webhook_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_update_webhooks_param_webhook(webhook_id, body),
+        )
+    }
+    /// The blocking version of [`crate::events::Events::deprecated_202001_delete_webhooks_param_webhook`].
+    pub fn deprecated_202001_delete_webhooks_param_webhook(
+        &self,
+        webhook_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_delete_webhooks_param_webhook(webhook_id),
+        )
+    }
+    /// The blocking version of [`crate::events::Events::deprecated_202004_get_webhook`].
+    pub fn deprecated_202004_get_webhook(
+        &self,
+        address: &str,
+        created_at_max: &str,
+        created_at_min: &str,
+        fields: &str,
+        limit: &str,
+        since_id: &str,
+        topic: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202004_get_webhook(
+                address,
+                created_at_max,
+                created_at_min,
+                fields,
+                limit,
+                since_id,
+                topic,
+                updated_at_min,
+                updated_at_max,
+            ))
+    }
+    /// The blocking version of [`crate::events::Events::deprecated_202004_get_webhook_request`].
+    pub fn deprecated_202004_get_webhook_request(
+        &self,
+    ) -> EventsDeprecated202004GetWebhookRequest<'_> {
+        EventsDeprecated202004GetWebhookRequest {
+            inner: self.inner.deprecated_202004_get_webhook_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::events::Events::deprecated_202004_create_webhooks`].
+    pub fn deprecated_202004_create_webhooks(
+        &self,
+        format: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202004_create_webhooks(format, body))
+    }
+    /// The blocking version of [`crate::events::Events::deprecated_202004_create_webhooks_request`].
+    pub fn deprecated_202004_create_webhooks_request<'a>(
+        &'a self,
+        body: &'a serde_json::Value,
+    ) -> EventsDeprecated202004CreateWebhooksRequest<'a> {
+        EventsDeprecated202004CreateWebhooksRequest {
+            inner: self.inner.deprecated_202004_create_webhooks_request(body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::events::Events::deprecated_202004_get_webhooks_count`].
+    pub fn deprecated_202004_get_webhooks_count(
+        &self,
+        address: &str,
+        topic: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_get_webhooks_count(address, topic),
+        )
+    }
+    /// The blocking version of [`crate::events::Events::deprecated_202004_get_webhooks_count_request`].
+    pub fn deprecated_202004_get_webhooks_count_request(
+        &self,
+    ) -> EventsDeprecated202004GetWebhooksCountRequest<'_> {
+        EventsDeprecated202004GetWebhooksCountRequest {
+            inner: self.inner.deprecated_202004_get_webhooks_count_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::events::Events::deprecated_202004_get_webhooks_param_webhook`].
+    pub fn deprecated_202004_get_webhooks_param_webhook(
+        &self,
+        webhook_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_get_webhooks_param_webhook(webhook_id, fields),
+        )
+    }
+    /// The blocking version of [`crate::events::Events::deprecated_202004_get_webhooks_param_webhook_request`].
+    pub fn deprecated_202004_get_webhooks_param_webhook_request<'a>(
+        &'a self,
+        webhook_id: &'a str,
+    ) -> EventsDeprecated202004GetWebhooksParamWebhookRequest<'a> {
+        EventsDeprecated202004GetWebhooksParamWebhookRequest {
+            inner: self
+                .inner
+                .deprecated_202004_get_webhooks_param_webhook_request(webhook_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::events::Events::deprecated_202004_update_webhooks_param_webhook`].
+    pub fn deprecated_202004_update_webhooks_param_webhook(
+        &self,
+        webhook_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_update_webhooks_param_webhook(webhook_id, body),
+        )
+    }
+    /// The blocking version of [`crate::events::Events::deprecated_202004_delete_webhooks_param_webhook`].
+    pub fn deprecated_202004_delete_webhooks_param_webhook(
+        &self,
+        webhook_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_delete_webhooks_param_webhook(webhook_id),
+        )
+    }
+    /// The blocking version of [`crate::events::Events::deprecated_202007_get_webhook`].
+    pub fn deprecated_202007_get_webhook(
+        &self,
+        address: &str,
+        created_at_max: &str,
+        created_at_min: &str,
+        fields: &str,
+        limit: &str,
+        since_id: &str,
+        topic: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202007_get_webhook(
+                address,
+                created_at_max,
+                created_at_min,
+                fields,
+                limit,
+                since_id,
+                topic,
+                updated_at_min,
+                updated_at_max,
+            ))
+    }
+    /// The blocking version of [`crate::events::Events::deprecated_202007_get_webhook_request`].
+    pub fn deprecated_202007_get_webhook_request(
+        &self,
+    ) -> EventsDeprecated202007GetWebhookRequest<'_> {
+        EventsDeprecated202007GetWebhookRequest {
+            inner: self.inner.deprecated_202007_get_webhook_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::events::Events::deprecated_202007_create_webhooks`].
+    pub fn deprecated_202007_create_webhooks(
+        &self,
+        format: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202007_create_webhooks(format, body))
+    }
+    /// The blocking version of [`crate::events::Events::deprecated_202007_create_webhooks_request`].
+    pub fn deprecated_202007_create_webhooks_request<'a>(
+        &'a self,
+        body: &'a serde_json::Value,
+    ) -> EventsDeprecated202007CreateWebhooksRequest<'a> {
+        EventsDeprecated202007CreateWebhooksRequest {
+            inner: self.inner.deprecated_202007_create_webhooks_request(body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::events::Events::deprecated_202007_get_webhooks_count`].
+    pub fn deprecated_202007_get_webhooks_count(
+        &self,
+        address: &str,
+        topic: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_get_webhooks_count(address, topic),
+        )
+    }
+    /// The blocking version of [`crate::events::Events::deprecated_202007_get_webhooks_count_request`].
+    pub fn deprecated_202007_get_webhooks_count_request(
+        &self,
+    ) -> EventsDeprecated202007GetWebhooksCountRequest<'_> {
+        EventsDeprecated202007GetWebhooksCountRequest {
+            inner: self.inner.deprecated_202007_get_webhooks_count_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::events::Events::deprecated_202007_get_webhooks_param_webhook`].
+    pub fn deprecated_202007_get_webhooks_param_webhook(
+        &self,
+        webhook_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_get_webhooks_param_webhook(webhook_id, fields),
+        )
+    }
+    /// The blocking version of [`crate::events::Events::deprecated_202007_get_webhooks_param_webhook_request`].
+    pub fn deprecated_202007_get_webhooks_param_webhook_request<'a>(
+        &'a self,
+        webhook_id: &'a str,
+    ) -> EventsDeprecated202007GetWebhooksParamWebhookRequest<'a> {
+        EventsDeprecated202007GetWebhooksParamWebhookRequest {
+            inner: self
+                .inner
+                .deprecated_202007_get_webhooks_param_webhook_request(webhook_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::events::Events::deprecated_202007_update_webhooks_param_webhook`].
+    pub fn deprecated_202007_update_webhooks_param_webhook(
+        &self,
+        webhook_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_update_webhooks_param_webhook(webhook_id, body),
+        )
+    }
+    /// The blocking version of [`crate::events::Events::deprecated_202007_delete_webhooks_param_webhook`].
+    pub fn deprecated_202007_delete_webhooks_param_webhook(
+        &self,
+        webhook_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_delete_webhooks_param_webhook(webhook_id),
+        )
+    }
+    /// The blocking version of [`crate::events::Events::get_webhook`].
+    pub fn get_webhook(
+        &self,
+        address: &str,
+        created_at_max: &str,
+        created_at_min: &str,
+        fields: &str,
+        limit: &str,
+        since_id: &str,
+        topic: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.get_webhook(
+            address,
+            created_at_max,
+            created_at_min,
+            fields,
+            limit,
+            since_id,
+            topic,
+            updated_at_min,
+            updated_at_max,
+        ))
+    }
+    /// The blocking version of [`crate::events::Events::get_webhook_request`].
+    pub fn get_webhook_request(&self) -> EventsGetWebhookRequest<'_> {
+        EventsGetWebhookRequest {
+            inner: self.inner.get_webhook_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::events::Events::create_webhooks`].
+    pub fn create_webhooks(
+        &self,
+        format: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.create_webhooks(format, body))
+    }
+    /// The blocking version of [`crate::events::Events::create_webhooks_request`].
+    pub fn create_webhooks_request<'a>(
+        &'a self,
+        body: &'a serde_json::Value,
+    ) -> EventsCreateWebhooksRequest<'a> {
+        EventsCreateWebhooksRequest {
+            inner: self.inner.create_webhooks_request(body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::events::Events::get_webhooks_count`].
+    pub fn get_webhooks_count(
+        &self,
+        address: &str,
+        topic: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.get_webhooks_count(address, topic))
+    }
+    /// The blocking version of [`crate::events::Events::get_webhooks_count_request`].
+    pub fn get_webhooks_count_request(&self) -> EventsGetWebhooksCountRequest<'_> {
+        EventsGetWebhooksCountRequest {
+            inner: self.inner.get_webhooks_count_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::events::Events::get_webhooks_param_webhook`].
+    pub fn get_webhooks_param_webhook(
+        &self,
+        webhook_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.get_webhooks_param_webhook(webhook_id, fields))
+    }
+    /// The blocking version of [`crate::events::Events::get_webhooks_param_webhook_request`].
+    pub fn get_webhooks_param_webhook_request<'a>(
+        &'a self,
+        webhook_id: &'a str,
+    ) -> EventsGetWebhooksParamWebhookRequest<'a> {
+        EventsGetWebhooksParamWebhookRequest {
+            inner: self.inner.get_webhooks_param_webhook_request(webhook_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::events::Events::update_webhooks_param_webhook`].
+    pub fn update_webhooks_param_webhook(
+        &self,
+        webhook_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.update_webhooks_param_webhook(webhook_id, body))
+    }
+    /// The blocking version of [`crate::events::Events::delete_webhooks_param_webhook`].
+    pub fn delete_webhooks_param_webhook(
+        &self,
+        webhook_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete_webhooks_param_webhook(webhook_id))
+    }
+    /// The blocking version of [`crate::events::Events::deprecated_202101_get_webhook`].
+    pub fn deprecated_202101_get_webhook(
+        &self,
+        address: &str,
+        created_at_max: &str,
+        created_at_min: &str,
+        fields: &str,
+        limit: &str,
+        since_id: &str,
+        topic: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202101_get_webhook(
+                address,
+                created_at_max,
+                created_at_min,
+                fields,
+                limit,
+                since_id,
+                topic,
+                updated_at_min,
+                updated_at_max,
+            ))
+    }
+    /// The blocking version of [`crate::events::Events::deprecated_202101_get_webhook_request`].
+    pub fn deprecated_202101_get_webhook_request(
+        &self,
+    ) -> EventsDeprecated202101GetWebhookRequest<'_> {
+        EventsDeprecated202101GetWebhookRequest {
+            inner: self.inner.deprecated_202101_get_webhook_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::events::Events::deprecated_202101_create_webhooks`].
+    pub fn deprecated_202101_create_webhooks(
+        &self,
+        format: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202101_create_webhooks(format, body))
+    }
+    /// The blocking version of [`crate::events::Events::deprecated_202101_create_webhooks_request`].
+    pub fn deprecated_202101_create_webhooks_request<'a>(
+        &'a self,
+        body: &'a serde_json::Value,
+    ) -> EventsDeprecated202101CreateWebhooksRequest<'a> {
+        EventsDeprecated202101CreateWebhooksRequest {
+            inner: self.inner.deprecated_202101_create_webhooks_request(body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::events::Events::deprecated_202101_get_webhooks_count`].
+    pub fn deprecated_202101_get_webhooks_count(
+        &self,
+        address: &str,
+        topic: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_get_webhooks_count(address, topic),
+        )
+    }
+    /// The blocking version of [`crate::events::Events::deprecated_202101_get_webhooks_count_request`].
+    pub fn deprecated_202101_get_webhooks_count_request(
+        &self,
+    ) -> EventsDeprecated202101GetWebhooksCountRequest<'_> {
+        EventsDeprecated202101GetWebhooksCountRequest {
+            inner: self.inner.deprecated_202101_get_webhooks_count_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::events::Events::deprecated_202101_get_webhooks_param_webhook`].
+    pub fn deprecated_202101_get_webhooks_param_webhook(
+        &self,
+        webhook_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_get_webhooks_param_webhook(webhook_id, fields),
+        )
+    }
+    /// The blocking version of [`crate::events::Events::deprecated_202101_get_webhooks_param_webhook_request`].
+    pub fn deprecated_202101_get_webhooks_param_webhook_request<'a>(
+        &'a self,
+        webhook_id: &'a str,
+    ) -> EventsDeprecated202101GetWebhooksParamWebhookRequest<'a> {
+        EventsDeprecated202101GetWebhooksParamWebhookRequest {
+            inner: self
+                .inner
+                .deprecated_202101_get_webhooks_param_webhook_request(webhook_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::events::Events::deprecated_202101_update_webhooks_param_webhook`].
+    pub fn deprecated_202101_update_webhooks_param_webhook(
+        &self,
+        webhook_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_update_webhooks_param_webhook(webhook_id, body),
+        )
+    }
+    /// The blocking version of [`crate::events::Events::deprecated_202101_delete_webhooks_param_webhook`].
+    pub fn deprecated_202101_delete_webhooks_param_webhook(
+        &self,
+        webhook_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_delete_webhooks_param_webhook(webhook_id),
+        )
+    }
+    /// The blocking version of [`crate::events::Events::deprecated_unstable_get_webhook`].
+    pub fn deprecated_unstable_get_webhook(
+        &self,
+        address: &str,
+        created_at_max: &str,
+        created_at_min: &str,
+        fields: &str,
+        limit: &str,
+        since_id: &str,
+        topic: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_unstable_get_webhook(
+                address,
+                created_at_max,
+                created_at_min,
+                fields,
+                limit,
+                since_id,
+                topic,
+                updated_at_min,
+                updated_at_max,
+            ))
+    }
+    /// The blocking version of [`crate::events::Events::deprecated_unstable_get_webhook_request`].
+    pub fn deprecated_unstable_get_webhook_request(
+        &self,
+    ) -> EventsDeprecatedUnstableGetWebhookRequest<'_> {
+        EventsDeprecatedUnstableGetWebhookRequest {
+            inner: self.inner.deprecated_unstable_get_webhook_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::events::Events::deprecated_unstable_create_webhooks`].
+    pub fn deprecated_unstable_create_webhooks(
+        &self,
+        format: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_unstable_create_webhooks(format, body))
+    }
+    /// The blocking version of [`crate::events::Events::deprecated_unstable_create_webhooks_request`].
+    pub fn deprecated_unstable_create_webhooks_request<'a>(
+        &'a self,
+        body: &'a serde_json::Value,
+    ) -> EventsDeprecatedUnstableCreateWebhooksRequest<'a> {
+        EventsDeprecatedUnstableCreateWebhooksRequest {
+            inner: self.inner.deprecated_unstable_create_webhooks_request(body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::events::Events::deprecated_unstable_get_webhooks_count`].
+    pub fn deprecated_unstable_get_webhooks_count(
+        &self,
+        address: &str,
+        topic: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_get_webhooks_count(address, topic),
+        )
+    }
+    /// The blocking version of [`crate::events::Events::deprecated_unstable_get_webhooks_count_request`].
+    pub fn deprecated_unstable_get_webhooks_count_request(
+        &self,
+    ) -> EventsDeprecatedUnstableGetWebhooksCountRequest<'_> {
+        EventsDeprecatedUnstableGetWebhooksCountRequest {
+            inner: self.inner.deprecated_unstable_get_webhooks_count_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::events::Events::deprecated_unstable_get_webhooks_param_webhook`].
+    pub fn deprecated_unstable_get_webhooks_param_webhook(
+        &self,
+        webhook_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_get_webhooks_param_webhook(webhook_id, fields),
+        )
+    }
+    /// The blocking version of [`crate::events::Events::deprecated_unstable_get_webhooks_param_webhook_request`].
+    pub fn deprecated_unstable_get_webhooks_param_webhook_request<'a>(
+        &'a self,
+        webhook_id: &'a str,
+    ) -> EventsDeprecatedUnstableGetWebhooksParamWebhookRequest<'a> {
+        EventsDeprecatedUnstableGetWebhooksParamWebhookRequest {
+            inner: self
+                .inner
+                .deprecated_unstable_get_webhooks_param_webhook_request(webhook_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::events::Events::deprecated_unstable_update_webhooks_param_webhook`].
+    pub fn deprecated_unstable_update_webhooks_param_webhook(
+        &self,
+        webhook_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_update_webhooks_param_webhook(webhook_id, body),
+        )
+    }
+    /// The blocking version of [`crate::events::Events::deprecated_unstable_delete_webhooks_param_webhook`].
+    pub fn deprecated_unstable_delete_webhooks_param_webhook(
+        &self,
+        webhook_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_delete_webhooks_param_webhook(webhook_id),
+        )
+    }
+}
@@ -0,0 +1,2514 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Inventory::deprecated_202001_get_item`], set with a builder.
+pub struct InventoryDeprecated202001GetItemRequest<'a> {
+    inner: crate::inventory::InventoryDeprecated202001GetItemRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> InventoryDeprecated202001GetItemRequest<'a> {
+    /// Sets the `ids required` query parameter.
+    pub fn ids_required(mut self, ids_required: &'a str) -> Self {
+        self.inner = self.inner.ids_required(ids_required);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `ids` query parameter.
+    pub fn ids(mut self, ids: i64) -> Self {
+        self.inner = self.inner.ids(ids);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Inventory::deprecated_202004_get_item`], set with a builder.
+pub struct InventoryDeprecated202004GetItemRequest<'a> {
+    inner: crate::inventory::InventoryDeprecated202004GetItemRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> InventoryDeprecated202004GetItemRequest<'a> {
+    /// Sets the `ids required` query parameter.
+    pub fn ids_required(mut self, ids_required: &'a str) -> Self {
+        self.inner = self.inner.ids_required(ids_required);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `ids` query parameter.
+    pub fn ids(mut self, ids: i64) -> Self {
+        self.inner = self.inner.ids(ids);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Inventory::deprecated_202007_get_item`], set with a builder.
+pub struct InventoryDeprecated202007GetItemRequest<'a> {
+    inner: crate::inventory::InventoryDeprecated202007GetItemRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> InventoryDeprecated202007GetItemRequest<'a> {
+    /// Sets the `ids required` query parameter.
+    pub fn ids_required(mut self, ids_required: &'a str) -> Self {
+        self.inner = self.inner.ids_required(ids_required);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `ids` query parameter.
+    pub fn ids(mut self, ids: i64) -> Self {
+        self.inner = self.inner.ids(ids);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Inventory::get_item`], set with a builder.
+pub struct InventoryGetItemRequest<'a> {
+    inner: crate::inventory::InventoryGetItemRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> InventoryGetItemRequest<'a> {
+    /// Sets the `ids required` query parameter.
+    pub fn ids_required(mut self, ids_required: &'a str) -> Self {
+        self.inner = self.inner.ids_required(ids_required);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `ids` query parameter.
+    pub fn ids(mut self, ids: i64) -> Self {
+        self.inner = self.inner.ids(ids);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Inventory::deprecated_202101_get_item`], set with a builder.
+pub struct InventoryDeprecated202101GetItemRequest<'a> {
+    inner: crate::inventory::InventoryDeprecated202101GetItemRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> InventoryDeprecated202101GetItemRequest<'a> {
+    /// Sets the `ids required` query parameter.
+    pub fn ids_required(mut self, ids_required: &'a str) -> Self {
+        self.inner = self.inner.ids_required(ids_required);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `ids` query parameter.
+    pub fn ids(mut self, ids: i64) -> Self {
+        self.inner = self.inner.ids(ids);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Inventory::deprecated_unstable_get_item`], set with a builder.
+pub struct InventoryDeprecatedUnstableGetItemRequest<'a> {
+    inner: crate::inventory::InventoryDeprecatedUnstableGetItemRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> InventoryDeprecatedUnstableGetItemRequest<'a> {
+    /// Sets the `ids required` query parameter.
+    pub fn ids_required(mut self, ids_required: &'a str) -> Self {
+        self.inner = self.inner.ids_required(ids_required);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `ids` query parameter.
+    pub fn ids(mut self, ids: i64) -> Self {
+        self.inner = self.inner.ids(ids);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Inventory::deprecated_202001_get_level`], set with a builder.
+pub struct InventoryDeprecated202001GetLevelRequest<'a> {
+    inner: crate::inventory::InventoryDeprecated202001GetLevelRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> InventoryDeprecated202001GetLevelRequest<'a> {
+    /// Sets the `inventory_item_ids` query parameter.
+    pub fn inventory_item_ids(mut self, inventory_item_ids: &'a str) -> Self {
+        self.inner = self.inner.inventory_item_ids(inventory_item_ids);
+        self
+    }
+
+    /// Sets the `location_ids` query parameter.
+    pub fn location_ids(mut self, location_ids: &'a str) -> Self {
+        self.inner = self.inner.location_ids(location_ids);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.inner = self.inner.updated_at_min(updated_at_min);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Inventory::deprecated_202001_delete_levels`], set with a builder.
+pub struct InventoryDeprecated202001DeleteLevelsRequest<'a> {
+    inner: crate::inventory::InventoryDeprecated202001DeleteLevelsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> InventoryDeprecated202001DeleteLevelsRequest<'a> {
+    /// Sets the `inventory_item_id required` query parameter.
+    pub fn inventory_item_id_required(mut self, inventory_item_id_required: &'a str) -> Self {
+        self.inner = self
+            .inner
+            .inventory_item_id_required(inventory_item_id_required);
+        self
+    }
+
+    /// Sets the `location_id required` query parameter.
+    pub fn location_id_required(mut self, location_id_required: &'a str) -> Self {
+        self.inner = self.inner.location_id_required(location_id_required);
+        self
+    }
+
+    /// Sets the `inventory_item_id` query parameter.
+    pub fn inventory_item_id(mut self, inventory_item_id: i64) -> Self {
+        self.inner = self.inner.inventory_item_id(inventory_item_id);
+        self
+    }
+
+    /// Sets the `location_id` query parameter.
+    pub fn location_id(mut self, location_id: i64) -> Self {
+        self.inner = self.inner.location_id(location_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Inventory::deprecated_202001_create_levels_adjust`], set with a builder.
+pub struct InventoryDeprecated202001CreateLevelsAdjustRequest<'a> {
+    inner: crate::inventory::InventoryDeprecated202001CreateLevelsAdjustRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> InventoryDeprecated202001CreateLevelsAdjustRequest<'a> {
+    /// Sets the `inventory_item_id required` query parameter.
+    pub fn inventory_item_id_required(mut self, inventory_item_id_required: &'a str) -> Self {
+        self.inner = self
+            .inner
+            .inventory_item_id_required(inventory_item_id_required);
+        self
+    }
+
+    /// Sets the `location_id required` query parameter.
+    pub fn location_id_required(mut self, location_id_required: &'a str) -> Self {
+        self.inner = self.inner.location_id_required(location_id_required);
+        self
+    }
+
+    /// Sets the `available_adjustment required` query parameter.
+    pub fn available_adjustment_required(mut self, available_adjustment_required: &'a str) -> Self {
+        self.inner = self
+            .inner
+            .available_adjustment_required(available_adjustment_required);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Inventory::deprecated_202001_create_levels_connect`], set with a builder.
+pub struct InventoryDeprecated202001CreateLevelsConnectRequest<'a> {
+    inner: crate::inventory::InventoryDeprecated202001CreateLevelsConnectRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> InventoryDeprecated202001CreateLevelsConnectRequest<'a> {
+    /// Sets the `inventory_item_id required` query parameter.
+    pub fn inventory_item_id_required(mut self, inventory_item_id_required: &'a str) -> Self {
+        self.inner = self
+            .inner
+            .inventory_item_id_required(inventory_item_id_required);
+        self
+    }
+
+    /// Sets the `location_id required` query parameter.
+    pub fn location_id_required(mut self, location_id_required: &'a str) -> Self {
+        self.inner = self.inner.location_id_required(location_id_required);
+        self
+    }
+
+    /// Sets the `relocate_if_necessary` query parameter.
+    pub fn relocate_if_necessary(mut self, relocate_if_necessary: &'a str) -> Self {
+        self.inner = self.inner.relocate_if_necessary(relocate_if_necessary);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Inventory::deprecated_202001_create_levels_set`], set with a builder.
+pub struct InventoryDeprecated202001CreateLevelsSetRequest<'a> {
+    inner: crate::inventory::InventoryDeprecated202001CreateLevelsSetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> InventoryDeprecated202001CreateLevelsSetRequest<'a> {
+    /// Sets the `inventory_item_id required` query parameter.
+    pub fn inventory_item_id_required(mut self, inventory_item_id_required: &'a str) -> Self {
+        self.inner = self
+            .inner
+            .inventory_item_id_required(inventory_item_id_required);
+        self
+    }
+
+    /// Sets the `location_id required` query parameter.
+    pub fn location_id_required(mut self, location_id_required: &'a str) -> Self {
+        self.inner = self.inner.location_id_required(location_id_required);
+        self
+    }
+
+    /// Sets the `available required` query parameter.
+    pub fn available_required(mut self, available_required: &'a str) -> Self {
+        self.inner = self.inner.available_required(available_required);
+        self
+    }
+
+    /// Sets the `disconnect_if_necessary` query parameter.
+    pub fn disconnect_if_necessary(mut self, disconnect_if_necessary: &'a str) -> Self {
+        self.inner = self.inner.disconnect_if_necessary(disconnect_if_necessary);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Inventory::deprecated_202004_get_level`], set with a builder.
+pub struct InventoryDeprecated202004GetLevelRequest<'a> {
+    inner: crate::inventory::InventoryDeprecated202004GetLevelRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> InventoryDeprecated202004GetLevelRequest<'a> {
+    /// Sets the `inventory_item_ids` query parameter.
+    pub fn inventory_item_ids(mut self, inventory_item_ids: &'a str) -> Self {
+        self.inner = self.inner.inventory_item_ids(inventory_item_ids);
+        self
+    }
+
+    /// Sets the `location_ids` query parameter.
+    pub fn location_ids(mut self, location_ids: &'a str) -> Self {
+        self.inner = self.inner.location_ids(location_ids);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.inner = self.inner.updated_at_min(updated_at_min);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Inventory::deprecated_202004_delete_levels`], set with a builder.
+pub struct InventoryDeprecated202004DeleteLevelsRequest<'a> {
+    inner: crate::inventory::InventoryDeprecated202004DeleteLevelsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> InventoryDeprecated202004DeleteLevelsRequest<'a> {
+    /// Sets the `inventory_item_id required` query parameter.
+    pub fn inventory_item_id_required(mut self, inventory_item_id_required: &'a str) -> Self {
+        self.inner = self
+            .inner
+            .inventory_item_id_required(inventory_item_id_required);
+        self
+    }
+
+    /// Sets the `location_id required` query parameter.
+    pub fn location_id_required(mut self, location_id_required: &'a str) -> Self {
+        self.inner = self.inner.location_id_required(location_id_required);
+        self
+    }
+
+    /// Sets the `inventory_item_id` query parameter.
+    pub fn inventory_item_id(mut self, inventory_item_id: i64) -> Self {
+        self.inner = self.inner.inventory_item_id(inventory_item_id);
+        self
+    }
+
+    /// Sets the `location_id` query parameter.
+    pub fn location_id(mut self, location_id: i64) -> Self {
+        self.inner = self.inner.location_id(location_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Inventory::deprecated_202004_create_levels_adjust`], set with a builder.
+pub struct InventoryDeprecated202004CreateLevelsAdjustRequest<'a> {
+    inner: crate::inventory::InventoryDeprecated202004CreateLevelsAdjustRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> InventoryDeprecated202004CreateLevelsAdjustRequest<'a> {
+    /// Sets the `inventory_item_id required` query parameter.
+    pub fn inventory_item_id_required(mut self, inventory_item_id_required: &'a str) -> Self {
+        self.inner = self
+            .inner
+            .inventory_item_id_required(inventory_item_id_required);
+        self
+    }
+
+    /// Sets the `location_id required` query parameter.
+    pub fn location_id_required(mut self, location_id_required: &'a str) -> Self {
+        self.inner = self.inner.location_id_required(location_id_required);
+        self
+    }
+
+    /// Sets the `available_adjustment required` query parameter.
+    pub fn available_adjustment_required(mut self, available_adjustment_required: &'a str) -> Self {
+        self.inner = self
+            .inner
+            .available_adjustment_required(available_adjustment_required);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Inventory::deprecated_202004_create_levels_connect`], set with a builder.
+pub struct InventoryDeprecated202004CreateLevelsConnectRequest<'a> {
+    inner: crate::inventory::InventoryDeprecated202004CreateLevelsConnectRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> InventoryDeprecated202004CreateLevelsConnectRequest<'a> {
+    /// Sets the `inventory_item_id required` query parameter.
+    pub fn inventory_item_id_required(mut self, inventory_item_id_required: &'a str) -> Self {
+        self.inner = self
+            .inner
+            .inventory_item_id_required(inventory_item_id_required);
+        self
+    }
+
+    /// Sets the `location_id required` query parameter.
+    pub fn location_id_required(mut self, location_id_required: &'a str) -> Self {
+        self.inner = self.inner.location_id_required(location_id_required);
+        self
+    }
+
+    /// Sets the `relocate_if_necessary` query parameter.
+    pub fn relocate_if_necessary(mut self, relocate_if_necessary: &'a str) -> Self {
+        self.inner = self.inner.relocate_if_necessary(relocate_if_necessary);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Inventory::deprecated_202004_create_levels_set`], set with a builder.
+pub struct InventoryDeprecated202004CreateLevelsSetRequest<'a> {
+    inner: crate::inventory::InventoryDeprecated202004CreateLevelsSetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> InventoryDeprecated202004CreateLevelsSetRequest<'a> {
+    /// Sets the `inventory_item_id required` query parameter.
+    pub fn inventory_item_id_required(mut self, inventory_item_id_required: &'a str) -> Self {
+        self.inner = self
+            .inner
+            .inventory_item_id_required(inventory_item_id_required);
+        self
+    }
+
+    /// Sets the `location_id required` query parameter.
+    pub fn location_id_required(mut self, location_id_required: &'a str) -> Self {
+        self.inner = self.inner.location_id_required(location_id_required);
+        self
+    }
+
+    /// Sets the `available required` query parameter.
+    pub fn available_required(mut self, available_required: &'a str) -> Self {
+        self.inner = self.inner.available_required(available_required);
+        self
+    }
+
+    /// Sets the `disconnect_if_necessary` query parameter.
+    pub fn disconnect_if_necessary(mut self, disconnect_if_necessary: &'a str) -> Self {
+        self.inner = self.inner.disconnect_if_necessary(disconnect_if_necessary);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Inventory::deprecated_202007_get_level`], set with a builder.
+pub struct InventoryDeprecated202007GetLevelRequest<'a> {
+    inner: crate::inventory::InventoryDeprecated202007GetLevelRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> InventoryDeprecated202007GetLevelRequest<'a> {
+    /// Sets the `inventory_item_ids` query parameter.
+    pub fn inventory_item_ids(mut self, inventory_item_ids: &'a str) -> Self {
+        self.inner = self.inner.inventory_item_ids(inventory_item_ids);
+        self
+    }
+
+    /// Sets the `location_ids` query parameter.
+    pub fn location_ids(mut self, location_ids: &'a str) -> Self {
+        self.inner = self.inner.location_ids(location_ids);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.inner = self.inner.updated_at_min(updated_at_min);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Inventory::deprecated_202007_delete_levels`], set with a builder.
+pub struct InventoryDeprecated202007DeleteLevelsRequest<'a> {
+    inner: crate::inventory::InventoryDeprecated202007DeleteLevelsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> InventoryDeprecated202007DeleteLevelsRequest<'a> {
+    /// Sets the `inventory_item_id required` query parameter.
+    pub fn inventory_item_id_required(mut self, inventory_item_id_required: &'a str) -> Self {
+        self.inner = self
+            .inner
+            .inventory_item_id_required(inventory_item_id_required);
+        self
+    }
+
+    /// Sets the `location_id required` query parameter.
+    pub fn location_id_required(mut self, location_id_required: &'a str) -> Self {
+        self.inner = self.inner.location_id_required(location_id_required);
+        self
+    }
+
+    /// Sets the `inventory_item_id` query parameter.
+    pub fn inventory_item_id(mut self, inventory_item_id: i64) -> Self {
+        self.inner = self.inner.inventory_item_id(inventory_item_id);
+        self
+    }
+
+    /// Sets the `location_id` query parameter.
+    pub fn location_id(mut self, location_id: i64) -> Self {
+        self.inner = self.inner.location_id(location_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Inventory::deprecated_202007_create_levels_adjust`], set with a builder.
+pub struct InventoryDeprecated202007CreateLevelsAdjustRequest<'a> {
+    inner: crate::inventory::InventoryDeprecated202007CreateLevelsAdjustRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> InventoryDeprecated202007CreateLevelsAdjustRequest<'a> {
+    /// Sets the `inventory_item_id required` query parameter.
+    pub fn inventory_item_id_required(mut self, inventory_item_id_required: &'a str) -> Self {
+        self.inner = self
+            .inner
+            .inventory_item_id_required(inventory_item_id_required);
+        self
+    }
+
+    /// Sets the `location_id required` query parameter.
+    pub fn location_id_required(mut self, location_id_required: &'a str) -> Self {
+        self.inner = self.inner.location_id_required(location_id_required);
+        self
+    }
+
+    /// Sets the `available_adjustment required` query parameter.
+    pub fn available_adjustment_required(mut self, available_adjustment_required: &'a str) -> Self {
+        self.inner = self
+            .inner
+            .available_adjustment_required(available_adjustment_required);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Inventory::deprecated_202007_create_levels_connect`], set with a builder.
+pub struct InventoryDeprecated202007CreateLevelsConnectRequest<'a> {
+    inner: crate::inventory::InventoryDeprecated202007CreateLevelsConnectRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> InventoryDeprecated202007CreateLevelsConnectRequest<'a> {
+    /// Sets the `inventory_item_id required` query parameter.
+    pub fn inventory_item_id_required(mut self, inventory_item_id_required: &'a str) -> Self {
+        self.inner = self
+            .inner
+            .inventory_item_id_required(inventory_item_id_required);
+        self
+    }
+
+    /// Sets the `location_id required` query parameter.
+    pub fn location_id_required(mut self, location_id_required: &'a str) -> Self {
+        self.inner = self.inner.location_id_required(location_id_required);
+        self
+    }
+
+    /// Sets the `relocate_if_necessary` query parameter.
+    pub fn relocate_if_necessary(mut self, relocate_if_necessary: &'a str) -> Self {
+        self.inner = self.inner.relocate_if_necessary(relocate_if_necessary);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Inventory::deprecated_202007_create_levels_set`], set with a builder.
+pub struct InventoryDeprecated202007CreateLevelsSetRequest<'a> {
+    inner: crate::inventory::InventoryDeprecated202007CreateLevelsSetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> InventoryDeprecated202007CreateLevelsSetRequest<'a> {
+    /// Sets the `inventory_item_id required` query parameter.
+    pub fn inventory_item_id_required(mut self, inventory_item_id_required: &'a str) -> Self {
+        self.inner = self
+            .inner
+            .inventory_item_id_required(inventory_item_id_required);
+        self
+    }
+
+    /// Sets the `location_id required` query parameter.
+    pub fn location_id_required(mut self, location_id_required: &'a str) -> Self {
+        self.inner = self.inner.location_id_required(location_id_required);
+        self
+    }
+
+    /// Sets the `available required` query parameter.
+    pub fn available_required(mut self, available_required: &'a str) -> Self {
+        self.inner = self.inner.available_required(available_required);
+        self
+    }
+
+    /// Sets the `disconnect_if_necessary` query parameter.
+    pub fn disconnect_if_necessary(mut self, disconnect_if_necessary: &'a str) -> Self {
+        self.inner = self.inner.disconnect_if_necessary(disconnect_if_necessary);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Inventory::get_level`], set with a builder.
+pub struct InventoryGetLevelRequest<'a> {
+    inner: crate::inventory::InventoryGetLevelRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> InventoryGetLevelRequest<'a> {
+    /// Sets the `inventory_item_ids` query parameter.
+    pub fn inventory_item_ids(mut self, inventory_item_ids: &'a str) -> Self {
+        self.inner = self.inner.inventory_item_ids(inventory_item_ids);
+        self
+    }
+
+    /// Sets the `location_ids` query parameter.
+    pub fn location_ids(mut self, location_ids: &'a str) -> Self {
+        self.inner = self.inner.location_ids(location_ids);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.inner = self.inner.updated_at_min(updated_at_min);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Inventory::delete_levels`], set with a builder.
+pub struct InventoryDeleteLevelsRequest<'a> {
+    inner: crate::inventory::InventoryDeleteLevelsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> InventoryDeleteLevelsRequest<'a> {
+    /// Sets the `inventory_item_id required` query parameter.
+    pub fn inventory_item_id_required(mut self, inventory_item_id_required: &'a str) -> Self {
+        self.inner = self
+            .inner
+            .inventory_item_id_required(inventory_item_id_required);
+        self
+    }
+
+    /// Sets the `location_id required` query parameter.
+    pub fn location_id_required(mut self, location_id_required: &'a str) -> Self {
+        self.inner = self.inner.location_id_required(location_id_required);
+        self
+    }
+
+    /// Sets the `inventory_item_id` query parameter.
+    pub fn inventory_item_id(mut self, inventory_item_id: i64) -> Self {
+        self.inner = self.inner.inventory_item_id(inventory_item_id);
+        self
+    }
+
+    /// Sets the `location_id` query parameter.
+    pub fn location_id(mut self, location_id: i64) -> Self {
+        self.inner = self.inner.location_id(location_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Inventory::create_levels_adjust`], set with a builder.
+pub struct InventoryCreateLevelsAdjustRequest<'a> {
+    inner: crate::inventory::InventoryCreateLevelsAdjustRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> InventoryCreateLevelsAdjustRequest<'a> {
+    /// Sets the `inventory_item_id required` query parameter.
+    pub fn inventory_item_id_required(mut self, inventory_item_id_required: &'a str) -> Self {
+        self.inner = self
+            .inner
+            .inventory_item_id_required(inventory_item_id_required);
+        self
+    }
+
+    /// Sets the `location_id required` query parameter.
+    pub fn location_id_required(mut self, location_id_required: &'a str) -> Self {
+        self.inner = self.inner.location_id_required(location_id_required);
+        self
+    }
+
+    /// Sets the `available_adjustment required` query parameter.
+    pub fn available_adjustment_required(mut self, available_adjustment_required: &'a str) -> Self {
+        self.inner = self
+            .inner
+            .available_adjustment_required(available_adjustment_required);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Inventory::create_levels_connect`], set with a builder.
+pub struct InventoryCreateLevelsConnectRequest<'a> {
+    inner: crate::inventory::InventoryCreateLevelsConnectRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> InventoryCreateLevelsConnectRequest<'a> {
+    /// Sets the `inventory_item_id required` query parameter.
+    pub fn inventory_item_id_required(mut self, inventory_item_id_required: &'a str) -> Self {
+        self.inner = self
+            .inner
+            .inventory_item_id_required(inventory_item_id_required);
+        self
+    }
+
+    /// Sets the `location_id required` query parameter.
+    pub fn location_id_required(mut self, location_id_required: &'a str) -> Self {
+        self.inner = self.inner.location_id_required(location_id_required);
+        self
+    }
+
+    /// Sets the `relocate_if_necessary` query parameter.
+    pub fn relocate_if_necessary(mut self, relocate_if_necessary: &'a str) -> Self {
+        self.inner = self.inner.relocate_if_necessary(relocate_if_necessary);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Inventory::create_levels_set`], set with a builder.
+pub struct InventoryCreateLevelsSetRequest<'a> {
+    inner: crate::inventory::InventoryCreateLevelsSetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> InventoryCreateLevelsSetRequest<'a> {
+    /// Sets the `inventory_item_id required` query parameter.
+    pub fn inventory_item_id_required(mut self, inventory_item_id_required: &'a str) -> Self {
+        self.inner = self
+            .inner
+            .inventory_item_id_required(inventory_item_id_required);
+        self
+    }
+
+    /// Sets the `location_id required` query parameter.
+    pub fn location_id_required(mut self, location_id_required: &'a str) -> Self {
+        self.inner = self.inner.location_id_required(location_id_required);
+        self
+    }
+
+    /// Sets the `available required` query parameter.
+    pub fn available_required(mut self, available_required: &'a str) -> Self {
+        self.inner = self.inner.available_required(available_required);
+        self
+    }
+
+    /// Sets the `disconnect_if_necessary` query parameter.
+    pub fn disconnect_if_necessary(mut self, disconnect_if_necessary: &'a str) -> Self {
+        self.inner = self.inner.disconnect_if_necessary(disconnect_if_necessary);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Inventory::deprecated_202101_get_level`], set with a builder.
+pub struct InventoryDeprecated202101GetLevelRequest<'a> {
+    inner: crate::inventory::InventoryDeprecated202101GetLevelRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> InventoryDeprecated202101GetLevelRequest<'a> {
+    /// Sets the `inventory_item_ids` query parameter.
+    pub fn inventory_item_ids(mut self, inventory_item_ids: &'a str) -> Self {
+        self.inner = self.inner.inventory_item_ids(inventory_item_ids);
+        self
+    }
+
+    /// Sets the `location_ids` query parameter.
+    pub fn location_ids(mut self, location_ids: &'a str) -> Self {
+        self.inner = self.inner.location_ids(location_ids);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.inner = self.inner.updated_at_min(updated_at_min);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Inventory::deprecated_202101_delete_levels`], set with a builder.
+pub struct InventoryDeprecated202101DeleteLevelsRequest<'a> {
+    inner: crate::inventory::InventoryDeprecated202101DeleteLevelsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> InventoryDeprecated202101DeleteLevelsRequest<'a> {
+    /// Sets the `inventory_item_id required` query parameter.
+    pub fn inventory_item_id_required(mut self, inventory_item_id_required: &'a str) -> Self {
+        self.inner = self
+            .inner
+            .inventory_item_id_required(inventory_item_id_required);
+        self
+    }
+
+    /// Sets the `location_id required` query parameter.
+    pub fn location_id_required(mut self, location_id_required: &'a str) -> Self {
+        self.inner = self.inner.location_id_required(location_id_required);
+        self
+    }
+
+    /// Sets the `inventory_item_id` query parameter.
+    pub fn inventory_item_id(mut self, inventory_item_id: i64) -> Self {
+        self.inner = self.inner.inventory_item_id(inventory_item_id);
+        self
+    }
+
+    /// Sets the `location_id` query parameter.
+    pub fn location_id(mut self, location_id: i64) -> Self {
+        self.inner = self.inner.location_id(location_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Inventory::deprecated_202101_create_levels_adjust`], set with a builder.
+pub struct InventoryDeprecated202101CreateLevelsAdjustRequest<'a> {
+    inner: crate::inventory::InventoryDeprecated202101CreateLevelsAdjustRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> InventoryDeprecated202101CreateLevelsAdjustRequest<'a> {
+    /// Sets the `inventory_item_id required` query parameter.
+    pub fn inventory_item_id_required(mut self, inventory_item_id_required: &'a str) -> Self {
+        self.inner = self
+            .inner
+            .inventory_item_id_required(inventory_item_id_required);
+        self
+    }
+
+    /// Sets the `location_id required` query parameter.
+    pub fn location_id_required(mut self, location_id_required: &'a str) -> Self {
+        self.inner = self.inner.location_id_required(location_id_required);
+        self
+    }
+
+    /// Sets the `available_adjustment required` query parameter.
+    pub fn available_adjustment_required(mut self, available_adjustment_required: &'a str) -> Self {
+        self.inner = self
+            .inner
+            .available_adjustment_required(available_adjustment_required);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Inventory::deprecated_202101_create_levels_connect`], set with a builder.
+pub struct InventoryDeprecated202101CreateLevelsConnectRequest<'a> {
+    inner: crate::inventory::InventoryDeprecated202101CreateLevelsConnectRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> InventoryDeprecated202101CreateLevelsConnectRequest<'a> {
+    /// Sets the `inventory_item_id required` query parameter.
+    pub fn inventory_item_id_required(mut self, inventory_item_id_required: &'a str) -> Self {
+        self.inner = self
+            .inner
+            .inventory_item_id_required(inventory_item_id_required);
+        self
+    }
+
+    /// Sets the `location_id required` query parameter.
+    pub fn location_id_required(mut self, location_id_required: &'a str) -> Self {
+        self.inner = self.inner.location_id_required(location_id_required);
+        self
+    }
+
+    /// Sets the `relocate_if_necessary` query parameter.
+    pub fn relocate_if_necessary(mut self, relocate_if_necessary: &'a str) -> Self {
+        self.inner = self.inner.relocate_if_necessary(relocate_if_necessary);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Inventory::deprecated_202101_create_levels_set`], set with a builder.
+pub struct InventoryDeprecated202101CreateLevelsSetRequest<'a> {
+    inner: crate::inventory::InventoryDeprecated202101CreateLevelsSetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> InventoryDeprecated202101CreateLevelsSetRequest<'a> {
+    /// Sets the `inventory_item_id required` query parameter.
+    pub fn inventory_item_id_required(mut self, inventory_item_id_required: &'a str) -> Self {
+        self.inner = self
+            .inner
+            .inventory_item_id_required(inventory_item_id_required);
+        self
+    }
+
+    /// Sets the `location_id required` query parameter.
+    pub fn location_id_required(mut self, location_id_required: &'a str) -> Self {
+        self.inner = self.inner.location_id_required(location_id_required);
+        self
+    }
+
+    /// Sets the `available required` query parameter.
+    pub fn available_required(mut self, available_required: &'a str) -> Self {
+        self.inner = self.inner.available_required(available_required);
+        self
+    }
+
+    /// Sets the `disconnect_if_necessary` query parameter.
+    pub fn disconnect_if_necessary(mut self, disconnect_if_necessary: &'a str) -> Self {
+        self.inner = self.inner.disconnect_if_necessary(disconnect_if_necessary);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Inventory::deprecated_unstable_get_level`], set with a builder.
+pub struct InventoryDeprecatedUnstableGetLevelRequest<'a> {
+    inner: crate::inventory::InventoryDeprecatedUnstableGetLevelRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> InventoryDeprecatedUnstableGetLevelRequest<'a> {
+    /// Sets the `inventory_item_ids` query parameter.
+    pub fn inventory_item_ids(mut self, inventory_item_ids: &'a str) -> Self {
+        self.inner = self.inner.inventory_item_ids(inventory_item_ids);
+        self
+    }
+
+    /// Sets the `location_ids` query parameter.
+    pub fn location_ids(mut self, location_ids: &'a str) -> Self {
+        self.inner = self.inner.location_ids(location_ids);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.inner = self.inner.updated_at_min(updated_at_min);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Inventory::deprecated_unstable_delete_levels`], set with a builder.
+pub struct InventoryDeprecatedUnstableDeleteLevelsRequest<'a> {
+    inner: crate::inventory::InventoryDeprecatedUnstableDeleteLevelsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> InventoryDeprecatedUnstableDeleteLevelsRequest<'a> {
+    /// Sets the `inventory_item_id required` query parameter.
+    pub fn inventory_item_id_required(mut self, inventory_item_id_required: &'a str) -> Self {
+        self.inner = self
+            .inner
+            .inventory_item_id_required(inventory_item_id_required);
+        self
+    }
+
+    /// Sets the `location_id required` query parameter.
+    pub fn location_id_required(mut self, location_id_required: &'a str) -> Self {
+        self.inner = self.inner.location_id_required(location_id_required);
+        self
+    }
+
+    /// Sets the `inventory_item_id` query parameter.
+    pub fn inventory_item_id(mut self, inventory_item_id: i64) -> Self {
+        self.inner = self.inner.inventory_item_id(inventory_item_id);
+        self
+    }
+
+    /// Sets the `location_id` query parameter.
+    pub fn location_id(mut self, location_id: i64) -> Self {
+        self.inner = self.inner.location_id(location_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Inventory::deprecated_unstable_create_levels_adjust`], set with a builder.
+pub struct InventoryDeprecatedUnstableCreateLevelsAdjustRequest<'a> {
+    inner: crate::inventory::InventoryDeprecatedUnstableCreateLevelsAdjustRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> InventoryDeprecatedUnstableCreateLevelsAdjustRequest<'a> {
+    /// Sets the `inventory_item_id required` query parameter.
+    pub fn inventory_item_id_required(mut self, inventory_item_id_required: &'a str) -> Self {
+        self.inner = self
+            .inner
+            .inventory_item_id_required(inventory_item_id_required);
+        self
+    }
+
+    /// Sets the `location_id required` query parameter.
+    pub fn location_id_required(mut self, location_id_required: &'a str) -> Self {
+        self.inner = self.inner.location_id_required(location_id_required);
+        self
+    }
+
+    /// Sets the `available_adjustment required` query parameter.
+    pub fn available_adjustment_required(mut self, available_adjustment_required: &'a str) -> Self {
+        self.inner = self
+            .inner
+            .available_adjustment_required(available_adjustment_required);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Inventory::deprecated_unstable_create_levels_connect`], set with a builder.
+pub struct InventoryDeprecatedUnstableCreateLevelsConnectRequest<'a> {
+    inner: crate::inventory::InventoryDeprecatedUnstableCreateLevelsConnectRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> InventoryDeprecatedUnstableCreateLevelsConnectRequest<'a> {
+    /// Sets the `inventory_item_id required` query parameter.
+    pub fn inventory_item_id_required(mut self, inventory_item_id_required: &'a str) -> Self {
+        self.inner = self
+            .inner
+            .inventory_item_id_required(inventory_item_id_required);
+        self
+    }
+
+    /// Sets the `location_id required` query parameter.
+    pub fn location_id_required(mut self, location_id_required: &'a str) -> Self {
+        self.inner = self.inner.location_id_required(location_id_required);
+        self
+    }
+
+    /// Sets the `relocate_if_necessary` query parameter.
+    pub fn relocate_if_necessary(mut self, relocate_if_necessary: &'a str) -> Self {
+        self.inner = self.inner.relocate_if_necessary(relocate_if_necessary);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Inventory::deprecated_unstable_create_levels_set`], set with a builder.
+pub struct InventoryDeprecatedUnstableCreateLevelsSetRequest<'a> {
+    inner: crate::inventory::InventoryDeprecatedUnstableCreateLevelsSetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> InventoryDeprecatedUnstableCreateLevelsSetRequest<'a> {
+    /// Sets the `inventory_item_id required` query parameter.
+    pub fn inventory_item_id_required(mut self, inventory_item_id_required: &'a str) -> Self {
+        self.inner = self
+            .inner
+            .inventory_item_id_required(inventory_item_id_required);
+        self
+    }
+
+    /// Sets the `location_id required` query parameter.
+    pub fn location_id_required(mut self, location_id_required: &'a str) -> Self {
+        self.inner = self.inner.location_id_required(location_id_required);
+        self
+    }
+
+    /// Sets the `available required` query parameter.
+    pub fn available_required(mut self, available_required: &'a str) -> Self {
+        self.inner = self.inner.available_required(available_required);
+        self
+    }
+
+    /// Sets the `disconnect_if_necessary` query parameter.
+    pub fn disconnect_if_necessary(mut self, disconnect_if_necessary: &'a str) -> Self {
+        self.inner = self.inner.disconnect_if_necessary(disconnect_if_necessary);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::inventory::Inventory`].
+pub struct Inventory {
+    inner: crate::inventory::Inventory,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Inventory {
+    pub(crate) fn new(
+        inner: crate::inventory::Inventory,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Inventory { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::inventory::Inventory::deprecated_202001_get_item`].
+    pub fn deprecated_202001_get_item(
+        &self,
+        ids_required: &str,
+        limit: &str,
+        ids: i64,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_get_item(ids_required, limit, ids),
+        )
+    }
+    /// The blocking version of [`crate::inventory::Inventory::deprecated_202001_get_item_request`].
+    pub fn deprecated_202001_get_item_request(
+        &self,
+    ) -> InventoryDeprecated202001GetItemRequest<'_> {
+        InventoryDeprecated202001GetItemRequest {
+            inner: self.inner.deprecated_202001_get_item_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::inventory::Inventory::deprecated_202001_get_items_param_item`].
+    pub fn deprecated_202001_get_items_param_item(
+        &self,
+        inventory_item_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_get_items_param_item(inventory_item_id),
+        )
+    }
+    /// The blocking version of [`crate::inventory::Inventory::deprecated_202001_update_items_param_item`].
+    pub fn deprecated_202001_update_items_param_item(
+        &self,
+        inventory_item_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_update_items_param_item(inventory_item_id, body),
+        )
+    }
+    /// The blocking version of [`crate::inventory::Inventory::deprecated_202004_get_item`].
+    pub fn deprecated_202004_get_item(
+        &self,
+        ids_required: &str,
+        limit: &str,
+        ids: i64,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_get_item(ids_required, limit, ids),
+        )
+    }
+    /// The blocking version of [`crate::inventory::Inventory::deprecated_202004_get_item_request`].
+    pub fn deprecated_202004_get_item_request(
+        &self,
+    ) -> InventoryDeprecated202004GetItemRequest<'_> {
+        InventoryDeprecated202004GetItemRequest {
+            inner: self.inner.deprecated_202004_get_item_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::inventory::Inventory::deprecated_202004_get_items_param_item`].
+    pub fn deprecated_202004_get_items_param_item(
+        &self,
+        inventory_item_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_get_items_param_item(inventory_item_id),
+        )
+    }
+    /// The blocking version of [`crate::inventory::Inventory::deprecated_202004_update_items_param_item`].
+    pub fn deprecated_202004_update_items_param_item(
+        &self,
+        inventory_item_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_update_items_param_item(inventory_item_id, body),
+        )
+    }
+    /// The blocking version of [`crate::inventory::Inventory::deprecated_202007_get_item`].
+    pub fn deprecated_202007_get_item(
+        &self,
+        ids_required: &str,
+        limit: &str,
+        ids: i64,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_get_item(ids_required, limit, ids),
+        )
+    }
+    /// The blocking version of [`crate::inventory::Inventory::deprecated_202007_get_item_request`].
+    pub fn deprecated_202007_get_item_request(
+        &self,
+    ) -> InventoryDeprecated202007GetItemRequest<'_> {
+        InventoryDeprecated202007GetItemRequest {
+            inner: self.inner.deprecated_202007_get_item_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::inventory::Inventory::deprecated_202007_get_items_param_item`].
+    pub fn deprecated_202007_get_items_param_item(
+        &self,
+        inventory_item_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_get_items_param_item(inventory_item_id),
+        )
+    }
+    /// The blocking version of [`crate::inventory::Inventory::deprecated_202007_update_items_param_item`].
+    pub fn deprecated_202007_update_items_param_item(
+        &self,
+        inventory_item_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_update_items_param_item(inventory_item_id, body),
+        )
+    }
+    /// The blocking version of [`crate::inventory::Inventory::get_item`].
+    pub fn get_item(
+        &self,
+        ids_required: &str,
+        limit: &str,
+        ids: i64,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.get_item(ids_required, limit, ids))
+    }
+    /// The blocking version of [`crate::inventory::Inventory::get_item_request`].
+    pub fn get_item_request(&self) -> InventoryGetItemRequest<'_> {
+        InventoryGetItemRequest {
+            inner: self.inner.get_item_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::inventory::Inventory::get_items_param_item`].
+    pub fn get_items_param_item(
+        &self,
+        inventory_item_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.get_items_param_item(inventory_item_id))
+    }
+    /// The blocking version of [`crate::inventory::Inventory::update_items_param_item`].
+    pub fn update_items_param_item(
+        &self,
+        inventory_item_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.update_items_param_item(inventory_item_id, body))
+    }
+    /// The blocking version of [`crate::inventory::Inventory::deprecated_202101_get_item`].
+    pub fn deprecated_202101_get_item(
+        &self,
+        ids_required: &str,
+        limit: &str,
+        ids: i64,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_get_item(ids_required, limit, ids),
+        )
+    }
+    /// The blocking version of [`crate::inventory::Inventory::deprecated_202101_get_item_request`].
+    pub fn deprecated_202101_get_item_request(
+        &self,
+    ) -> InventoryDeprecated202101GetItemRequest<'_> {
+        InventoryDeprecated202101GetItemRequest {
+            inner: self.inner.deprecated_202101_get_item_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::inventory::Inventory::deprecated_202101_get_items_param_item`].
+    pub fn deprecated_202101_get_items_param_item(
+        &self,
+        inventory_item_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_get_items_param_item(inventory_item_id),
+        )
+    }
+    /// The blocking version of [`crate::inventory::Inventory::deprecated_202101_update_items_param_item`].
+    pub fn deprecated_202101_update_items_param_item(
+        &self,
+        inventory_item_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_update_items_param_item(inventory_item_id, body),
+        )
+    }
+    /// The blocking version of [`crate::inventory::Inventory::deprecated_unstable_get_item`].
+    pub fn deprecated_unstable_get_item(
+        &self,
+        ids_required: &str,
+        limit: &str,
+        ids: i64,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_get_item(ids_required, limit, ids),
+        )
+    }
+    /// The blocking version of [`crate::inventory::Inventory::deprecated_unstable_get_item_request`].
+    pub fn deprecated_unstable_get_item_request(
+        &self,
+    ) -> InventoryDeprecatedUnstableGetItemRequest<'_> {
+        InventoryDeprecatedUnstableGetItemRequest {
+            inner: self.inner.deprecated_unstable_get_item_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::inventory::Inventory::deprecated_unstable_get_items_param_item`].
+    pub fn deprecated_unstable_get_items_param_item(
+        &self,
+        inventory_item_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_get_items_param_item(inventory_item_id),
+        )
+    }
+    /// The blocking version of [`crate::inventory::Inventory::deprecated_unstable_update_items_param_item`].
+    pub fn deprecated_unstable_update_items_param_item(
+        &self,
+        inventory_item_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_update_items_param_item(inventory_item_id, body),
+        )
+    }
+    /// The blocking version of [`crate::inventory::Inventory::deprecated_202001_get_level`].
+    pub fn deprecated_202001_get_level(
+        &self,
+        inventory_item_ids: &str,
+        location_ids: &str,
+        limit: &str,
+        updated_at_min: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202001_get_level(
+                inventory_item_ids,
+                location_ids,
+                limit,
+                updated_at_min,
+            ))
+    }
+    /// The blocking version of [`crate::inventory::Inventory::deprecated_202001_get_level_request`].
+    pub fn deprecated_202001_get_level_request(
+        &self,
+    ) -> InventoryDeprecated202001GetLevelRequest<'_> {
+        InventoryDeprecated202001GetLevelRequest {
+            inner: self.inner.deprecated_202001_get_level_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::inventory::Inventory::deprecated_202001_delete_levels`].
+    pub fn deprecated_202001_delete_levels(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        inventory_item_id: i64,
+        location_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202001_delete_levels(
+                inventory_item_id_required,
+                location_id_required,
+                inventory_item_id,
+                location_id,
+            ))
+    }
+    /// The blocking version of [`crate::inventory::Inventory::deprecated_202001_delete_levels_request`].
+    pub fn deprecated_202001_delete_levels_request(
+        &self,
+    ) -> InventoryDeprecated202001DeleteLevelsRequest<'_> {
+        InventoryDeprecated202001DeleteLevelsRequest {
+            inner: self.inner.deprecated_202001_delete_levels_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::inventory::Inventory::deprecated_202001_create_levels_adjust`].
+    pub fn deprecated_202001_create_levels_adjust(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        available_adjustment_required: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202001_create_levels_adjust(
+                inventory_item_id_required,
+                location_id_required,
+                available_adjustment_required,
+                body,
+            ))
+    }
+    /// The blocking version of [`crate::inventory::Inventory::deprecated_202001_create_levels_adjust_request`].
+    pub fn deprecated_202001_create_levels_adjust_request<'a>(
+        &'a self,
+        body: &'a serde_json::Value,
+    ) -> InventoryDeprecated202001CreateLevelsAdjustRequest<'a> {
+        InventoryDeprecated202001CreateLevelsAdjustRequest {
+            inner: self
+                .inner
+                .deprecated_202001_create_levels_adjust_request(body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::inventory::Inventory::deprecated_202001_create_levels_connect`].
+    pub fn deprecated_202001_create_levels_connect(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        relocate_if_necessary: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202001_create_levels_connect(
+                inventory_item_id_required,
+                location_id_required,
+                relocate_if_necessary,
+                body,
+            ))
+    }
+    /// The blocking version of [`crate::inventory::Inventory::deprecated_202001_create_levels_connect_request`].
+    pub fn deprecated_202001_create_levels_connect_request<'a>(
+        &'a self,
+        body: &'a serde_json::Value,
+    ) -> InventoryDeprecated202001CreateLevelsConnectRequest<'a> {
+        InventoryDeprecated202001CreateLevelsConnectRequest {
+            inner: self
+                .inner
+                .deprecated_202001_create_levels_connect_request(body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::inventory::Inventory::deprecated_202001_create_levels_set`].
+    pub fn deprecated_202001_create_levels_set(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        available_required: &str,
+        disconnect_if_necessary: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202001_create_levels_set(
+                inventory_item_id_required,
+                location_id_required,
+                available_required,
+                disconnect_if_necessary,
+                body,
+            ))
+    }
+    /// The blocking version of [`crate::inventory::Inventory::deprecated_202001_create_levels_set_request`].
+    pub fn deprecated_202001_create_levels_set_request<'a>(
+        &'a self,
+        body: &'a serde_json::Value,
+    ) -> InventoryDeprecated202001CreateLevelsSetRequest<'a> {
+        InventoryDeprecated202001CreateLevelsSetRequest {
+            inner: self.inner.deprecated_202001_create_levels_set_request(body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::inventory::Inventory::deprecated_202004_get_level`].
+    pub fn deprecated_202004_get_level(
+        &self,
+        inventory_item_ids: &str,
+        location_ids: &str,
+        limit: &str,
+        updated_at_min: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202004_get_level(
+                inventory_item_ids,
+                location_ids,
+                limit,
+                updated_at_min,
+            ))
+    }
+    /// The blocking version of [`crate::inventory::Inventory::deprecated_202004_get_level_request`].
+    pub fn deprecated_202004_get_level_request(
+        &self,
+    ) -> InventoryDeprecated202004GetLevelRequest<'_> {
+        InventoryDeprecated202004GetLevelRequest {
+            inner: self.inner.deprecated_202004_get_level_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::inventory::Inventory::deprecated_202004_delete_levels`].
+    pub fn deprecated_202004_delete_levels(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        inventory_item_id: i64,
+        location_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202004_delete_levels(
+                inventory_item_id_required,
+                location_id_required,
+                inventory_item_id,
+                location_id,
+            ))
+    }
+    /// The blocking version of [`crate::inventory::Inventory::deprecated_202004_delete_levels_request`].
+    pub fn deprecated_202004_delete_levels_request(
+        &self,
+    ) -> InventoryDeprecated202004DeleteLevelsRequest<'_> {
+        InventoryDeprecated202004DeleteLevelsRequest {
+            inner: self.inner.deprecated_202004_delete_levels_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::inventory::Inventory::deprecated_202004_create_levels_adjust`].
+    pub fn deprecated_202004_create_levels_adjust(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        available_adjustment_required: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202004_create_levels_adjust(
+                inventory_item_id_required,
+                location_id_required,
+                available_adjustment_required,
+                body,
+            ))
+    }
+    /// The blocking version of [`crate::inventory::Inventory::deprecated_202004_create_levels_adjust_request`].
+    pub fn deprecated_202004_create_levels_adjust_request<'a>(
+        &'a self,
+        body: &'a serde_json::Value,
+    ) -> InventoryDeprecated202004CreateLevelsAdjustRequest<'a> {
+        InventoryDeprecated202004CreateLevelsAdjustRequest {
+            inner: self
+                .inner
+                .deprecated_202004_create_levels_adjust_request(body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::inventory::Inventory::deprecated_202004_create_levels_connect`].
+    pub fn deprecated_202004_create_levels_connect(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        relocate_if_necessary: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202004_create_levels_connect(
+                inventory_item_id_required,
+                location_id_required,
+                relocate_if_necessary,
+                body,
+            ))
+    }
+    /// The blocking version of [`crate::inventory::Inventory::deprecated_202004_create_levels_connect_request`].
+    pub fn deprecated_202004_create_levels_connect_request<'a>(
+        &'a self,
+        body: &'a serde_json::Value,
+    ) -> InventoryDeprecated202004CreateLevelsConnectRequest<'a> {
+        InventoryDeprecated202004CreateLevelsConnectRequest {
+            inner: self
+                .inner
+                .deprecated_202004_create_levels_connect_request(body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::inventory::Inventory::deprecated_202004_create_levels_set`].
+    pub fn deprecated_202004_create_levels_set(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        available_required: &str,
+        disconnect_if_necessary: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202004_create_levels_set(
+                inventory_item_id_required,
+                location_id_required,
+                available_required,
+                disconnect_if_necessary,
+                body,
+            ))
+    }
+    /// The blocking version of [`crate::inventory::Inventory::deprecated_202004_create_levels_set_request`].
+    pub fn deprecated_202004_create_levels_set_request<'a>(
+        &'a self,
+        body: &'a serde_json::Value,
+    ) -> InventoryDeprecated202004CreateLevelsSetRequest<'a> {
+        InventoryDeprecated202004CreateLevelsSetRequest {
+            inner: self.inner.deprecated_202004_create_levels_set_request(body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::inventory::Inventory::deprecated_202007_get_level`].
+    pub fn deprecated_202007_get_level(
+        &self,
+        inventory_item_ids: &str,
+        location_ids: &str,
+        limit: &str,
+        updated_at_min: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202007_get_level(
+                inventory_item_ids,
+                location_ids,
+                limit,
+                updated_at_min,
+            ))
+    }
+    /// The blocking version of [`crate::inventory::Inventory::deprecated_202007_get_level_request`].
+    pub fn deprecated_202007_get_level_request(
+        &self,
+    ) -> InventoryDeprecated202007GetLevelRequest<'_> {
+        InventoryDeprecated202007GetLevelRequest {
+            inner: self.inner.deprecated_202007_get_level_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::inventory::Inventory::deprecated_202007_delete_levels`].
+    pub fn deprecated_202007_delete_levels(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        inventory_item_id: i64,
+        location_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202007_delete_levels(
+                inventory_item_id_required,
+                location_id_required,
+                inventory_item_id,
+                location_id,
+            ))
+    }
+    /// The blocking version of [`crate::inventory::Inventory::deprecated_202007_delete_levels_request`].
+    pub fn deprecated_202007_delete_levels_request(
+        &self,
+    ) -> InventoryDeprecated202007DeleteLevelsRequest<'_> {
+        InventoryDeprecated202007DeleteLevelsRequest {
+            inner: self.inner.deprecated_202007_delete_levels_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::inventory::Inventory::deprecated_202007_create_levels_adjust`].
+    pub fn deprecated_202007_create_levels_adjust(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        available_adjustment_required: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202007_create_levels_adjust(
+                inventory_item_id_required,
+                location_id_required,
+                available_adjustment_required,
+                body,
+            ))
+    }
+    /// The blocking version of [`crate::inventory::Inventory::deprecated_202007_create_levels_adjust_request`].
+    pub fn deprecated_202007_create_levels_adjust_request<'a>(
+        &'a self,
+        body: &'a serde_json::Value,
+    ) -> InventoryDeprecated202007CreateLevelsAdjustRequest<'a> {
+        InventoryDeprecated202007CreateLevelsAdjustRequest {
+            inner: self
+                .inner
+                .deprecated_202007_create_levels_adjust_request(body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::inventory::Inventory::deprecated_202007_create_levels_connect`].
+    pub fn deprecated_202007_create_levels_connect(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        relocate_if_necessary: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202007_create_levels_connect(
+                inventory_item_id_required,
+                location_id_required,
+                relocate_if_necessary,
+                body,
+            ))
+    }
+    /// The blocking version of [`crate::inventory::Inventory::deprecated_202007_create_levels_connect_request`].
+    pub fn deprecated_202007_create_levels_connect_request<'a>(
+        &'a self,
+        body: &'a serde_json::Value,
+    ) -> InventoryDeprecated202007CreateLevelsConnectRequest<'a> {
+        InventoryDeprecated202007CreateLevelsConnectRequest {
+            inner: self
+                .inner
+                .deprecated_202007_create_levels_connect_request(body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::inventory::Inventory::deprecated_202007_create_levels_set`].
+    pub fn deprecated_202007_create_levels_set(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        available_required: &str,
+        disconnect_if_necessary: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202007_create_levels_set(
+                inventory_item_id_required,
+                location_id_required,
+                available_required,
+                disconnect_if_necessary,
+                body,
+            ))
+    }
+    /// The blocking version of [`crate::inventory::Inventory::deprecated_202007_create_levels_set_request`].
+    pub fn deprecated_202007_create_levels_set_request<'a>(
+        &'a self,
+        body: &'a serde_json::Value,
+    ) -> InventoryDeprecated202007CreateLevelsSetRequest<'a> {
+        InventoryDeprecated202007CreateLevelsSetRequest {
+            inner: self.inner.deprecated_202007_create_levels_set_request(body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::inventory::Inventory::get_level`].
+    pub fn get_level(
+        &self,
+        inventory_item_ids: &str,
+        location_ids: &str,
+        limit: &str,
+        updated_at_min: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.get_level(
+            inventory_item_ids,
+            location_ids,
+            limit,
+            updated_at_min,
+        ))
+    }
+    /// The blocking version of [`crate::inventory::Inventory::get_level_request`].
+    pub fn get_level_request(&self) -> InventoryGetLevelRequest<'_> {
+        InventoryGetLevelRequest {
+            inner: self.inner.get_level_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::inventory::Inventory::delete_levels`].
+    pub fn delete_levels(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        inventory_item_id: i64,
+        location_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.delete_levels(
+            inventory_item_id_required,
+            location_id_required,
+            inventory_item_id,
+            location_id,
+        ))
+    }
+    /// The blocking version of [`crate::inventory::Inventory::delete_levels_request`].
+    pub fn delete_levels_request(&self) -> InventoryDeleteLevelsRequest<'_> {
+        InventoryDeleteLevelsRequest {
+            inner: self.inner.delete_levels_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::inventory::Inventory::create_levels_adjust`].
+    pub fn create_levels_adjust(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        available_adjustment_required: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.create_levels_adjust(
+            inventory_item_id_required,
+            location_id_required,
+            available_adjustment_required,
+            body,
+        ))
+    }
+    /// The blocking version of [`crate::inventory::Inventory::create_levels_adjust_request`].
+    pub fn create_levels_adjust_request<'a>(
+        &'a self,
+        body: &'a serde_json::Value,
+    ) -> InventoryCreateLevelsAdjustRequest<'a> {
+        InventoryCreateLevelsAdjustRequest {
+            inner: self.inner.create_levels_adjust_request(body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::inventory::Inventory::create_levels_connect`].
+    pub fn create_levels_connect(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        relocate_if_necessary: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.create_levels_connect(
+            inventory_item_id_required,
+            location_id_required,
+            relocate_if_necessary,
+            body,
+        ))
+    }
+    /// The blocking version of [`crate::inventory::Inventory::create_levels_connect_request`].
+    pub fn create_levels_connect_request<'a>(
+        &'a self,
+        body: &'a serde_json::Value,
+    ) -> InventoryCreateLevelsConnectRequest<'a> {
+        InventoryCreateLevelsConnectRequest {
+            inner: self.inner.create_levels_connect_request(body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::inventory::Inventory::create_levels_set`].
+    pub fn create_levels_set(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        available_required: &str,
+        disconnect_if_necessary: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.create_levels_set(
+            inventory_item_id_required,
+            location_id_required,
+            available_required,
+            disconnect_if_necessary,
+            body,
+        ))
+    }
+    /// The blocking version of [`crate::inventory::Inventory::create_levels_set_request`].
+    pub fn create_levels_set_request<'a>(
+        &'a self,
+        body: &'a serde_json::Value,
+    ) -> InventoryCreateLevelsSetRequest<'a> {
+        InventoryCreateLevelsSetRequest {
+            inner: self.inner.create_levels_set_request(body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::inventory::Inventory::deprecated_202101_get_level`].
+    pub fn deprecated_202101_get_level(
+        &self,
+        inventory_item_ids: &str,
+        location_ids: &str,
+        limit: &str,
+        updated_at_min: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202101_get_level(
+                inventory_item_ids,
+                location_ids,
+                limit,
+                updated_at_min,
+            ))
+    }
+    /// The blocking version of [`crate::inventory::Inventory::deprecated_202101_get_level_request`].
+    pub fn deprecated_202101_get_level_request(
+        &self,
+    ) -> InventoryDeprecated202101GetLevelRequest<'_> {
+        InventoryDeprecated202101GetLevelRequest {
+            inner: self.inner.deprecated_202101_get_level_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::inventory::Inventory::deprecated_202101_delete_levels`].
+    pub fn deprecated_202101_delete_levels(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        inventory_item_id: i64,
+        location_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202101_delete_levels(
+                inventory_item_id_required,
+                location_id_required,
+                inventory_item_id,
+                location_id,
+            ))
+    }
+    /// The blocking version of [`crate::inventory::Inventory::deprecated_202101_delete_levels_request`].
+    pub fn deprecated_202101_delete_levels_request(
+        &self,
+    ) -> InventoryDeprecated202101DeleteLevelsRequest<'_> {
+        InventoryDeprecated202101DeleteLevelsRequest {
+            inner: self.inner.deprecated_202101_delete_levels_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::inventory::Inventory::deprecated_202101_create_levels_adjust`].
+    pub fn deprecated_202101_create_levels_adjust(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        available_adjustment_required: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202101_create_levels_adjust(
+                inventory_item_id_required,
+                location_id_required,
+                available_adjustment_required,
+                body,
+            ))
+    }
+    /// The blocking version of [`crate::inventory::Inventory::deprecated_202101_create_levels_adjust_request`].
+    pub fn deprecated_202101_create_levels_adjust_request<'a>(
+        &'a self,
+        body: &'a serde_json::Value,
+    ) -> InventoryDeprecated202101CreateLevelsAdjustRequest<'a> {
+        InventoryDeprecated202101CreateLevelsAdjustRequest {
+            inner: self
+                .inner
+                .deprecated_202101_create_levels_adjust_request(body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::inventory::Inventory::deprecated_202101_create_levels_connect`].
+    pub fn deprecated_202101_create_levels_connect(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        relocate_if_necessary: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202101_create_levels_connect(
+                inventory_item_id_required,
+                location_id_required,
+                relocate_if_necessary,
+                body,
+            ))
+    }
+    /// The blocking version of [`crate::inventory::Inventory::deprecated_202101_create_levels_connect_request`].
+    pub fn deprecated_202101_create_levels_connect_request<'a>(
+        &'a self,
+        body: &'a serde_json::Value,
+    ) -> InventoryDeprecated202101CreateLevelsConnectRequest<'a> {
+        InventoryDeprecated202101CreateLevelsConnectRequest {
+            inner: self
+                .inner
+                .deprecated_202101_create_levels_connect_request(body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::inventory::Inventory::deprecated_202101_create_levels_set`].
+    pub fn deprecated_202101_create_levels_set(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        available_required: &str,
+        disconnect_if_necessary: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202101_create_levels_set(
+                inventory_item_id_required,
+                location_id_required,
+                available_required,
+                disconnect_if_necessary,
+                body,
+            ))
+    }
+    /// The blocking version of [`crate::inventory::Inventory::deprecated_202101_create_levels_set_request`].
+    pub fn deprecated_202101_create_levels_set_request<'a>(
+        &'a self,
+        body: &'a serde_json::Value,
+    ) -> InventoryDeprecated202101CreateLevelsSetRequest<'a> {
+        InventoryDeprecated202101CreateLevelsSetRequest {
+            inner: self.inner.deprecated_202101_create_levels_set_request(body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::inventory::Inventory::deprecated_unstable_get_level`].
+    pub fn deprecated_unstable_get_level(
+        &self,
+        inventory_item_ids: &str,
+        location_ids: &str,
+        limit: &str,
+        updated_at_min: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_unstable_get_level(
+                inventory_item_ids,
+                location_ids,
+                limit,
+                updated_at_min,
+            ))
+    }
+    /// The blocking version of [`crate::inventory::Inventory::deprecated_unstable_get_level_request`].
+    pub fn deprecated_unstable_get_level_request(
+        &self,
+    ) -> InventoryDeprecatedUnstableGetLevelRequest<'_> {
+        InventoryDeprecatedUnstableGetLevelRequest {
+            inner: self.inner.deprecated_unstable_get_level_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::inventory::Inventory::deprecated_unstable_delete_levels`].
+    pub fn deprecated_unstable_delete_levels(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        inventory_item_id: i64,
+        location_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_unstable_delete_levels(
+                inventory_item_id_required,
+                location_id_required,
+                inventory_item_id,
+                location_id,
+            ))
+    }
+    /// The blocking version of [`crate::inventory::Inventory::deprecated_unstable_delete_levels_request`].
+    pub fn deprecated_unstable_delete_levels_request(
+        &self,
+    ) -> InventoryDeprecatedUnstableDeleteLevelsRequest<'_> {
+        InventoryDeprecatedUnstableDeleteLevelsRequest {
+            inner: self.inner.deprecated_unstable_delete_levels_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::inventory::Inventory::deprecated_unstable_create_levels_adjust`].
+    pub fn deprecated_unstable_create_levels_adjust(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        available_adjustment_required: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_unstable_create_levels_adjust(
+                inventory_item_id_required,
+                location_id_required,
+                available_adjustment_required,
+                body,
+            ))
+    }
+    /// The blocking version of [`crate::inventory::Inventory::deprecated_unstable_create_levels_adjust_request`].
+    pub fn deprecated_unstable_create_levels_adjust_request<'a>(
+        &'a self,
+        body: &'a serde_json::Value,
+    ) -> InventoryDeprecatedUnstableCreateLevelsAdjustRequest<'a> {
+        InventoryDeprecatedUnstableCreateLevelsAdjustRequest {
+            inner: self
+                .inner
+                .deprecated_unstable_create_levels_adjust_request(body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::inventory::Inventory::deprecated_unstable_create_levels_connect`].
+    pub fn deprecated_unstable_create_levels_connect(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        relocate_if_necessary: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_unstable_create_levels_connect(
+                inventory_item_id_required,
+                location_id_required,
+                relocate_if_necessary,
+                body,
+            ))
+    }
+    /// The blocking version of [`crate::inventory::Inventory::deprecated_unstable_create_levels_connect_request`].
+    pub fn deprecated_unstable_create_levels_connect_request<'a>(
+        &'a self,
+        body: &'a serde_json::Value,
+    ) -> InventoryDeprecatedUnstableCreateLevelsConnectRequest<'a> {
+        InventoryDeprecatedUnstableCreateLevelsConnectRequest {
+            inner: self
+                .inner
+                .deprecated_unstable_create_levels_connect_request(body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::inventory::Inventory::deprecated_unstable_create_levels_set`].
+    pub fn deprecated_unstable_create_levels_set(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        available_required: &str,
+        disconnect_if_necessary: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_unstable_create_levels_set(
+                inventory_item_id_required,
+                location_id_required,
+                available_required,
+                disconnect_if_necessary,
+                body,
+            ))
+    }
+    /// The blocking version of [`crate::inventory::Inventory::deprecated_unstable_create_levels_set_request`].
+    pub fn deprecated_unstable_create_levels_set_request<'a>(
+        &'a self,
+        body: &'a serde_json::Value,
+    ) -> InventoryDeprecatedUnstableCreateLevelsSetRequest<'a> {
+        InventoryDeprecatedUnstableCreateLevelsSetRequest {
+            inner: self
+                .inner
+                .deprecated_unstable_create_levels_set_request(body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::inventory::Inventory::deprecated_202001_get_location`].
+    pub fn deprecated_202001_get_location(&self) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202001_get_location())
+    }
+    /// The blocking version of [`crate::inventory::Inventory::deprecated_202001_get_locations_param_location`].
+    pub fn deprecated_202001_get_locations_param_location(
+        &self,
+        location_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_get_locations_param_location(location_id),
+        )
+    }
+    /// The blocking version of [`crate::inventory::Inventory::deprecated_202001_get_locations_count`].
+    pub fn deprecated_202001_get_locations_count(&self) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202001_get_locations_count())
+    }
+    /// The blocking version of [`crate::inventory::Inventory::deprecated_202001_get_locations_param_location_level`].
+    pub fn deprecated_202001_get_locations_param_location_level(
+        &self,
+        location_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_get_locations_param_location_level(location_id),
+        )
+    }
+    /// The blocking version of [`crate::inventory::Inventory::deprecated_202004_get_location`].
+    pub fn deprecated_202004_get_location(&self) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202004_get_location())
+    }
+    /// The blocking version of [`crate::inventory::Inventory::deprecated_202004_get_locations_param_location`].
+    pub fn deprecated_202004_get_locations_param_location(
+        &self,
+        location_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_get_locations_param_location(location_id),
+        )
+    }
+    /// The blocking version of [`crate::inventory::Inventory::deprecated_202004_get_locations_count`].
+    pub fn deprecated_202004_get_locations_count(&self) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202004_get_locations_count())
+    }
+    /// The blocking version of [`crate::inventory::Inventory::deprecated_202004_get_locations_param_location_level`].
+    pub fn deprecated_202004_get_locations_param_location_level(
+        &self,
+        location_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_get_locations_param_location_level(location_id),
+        )
+    }
+    /// The blocking version of [`crate::inventory::Inventory::deprecated_202007_get_location`].
+    pub fn deprecated_202007_get_location(&self) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202007_get_location())
+    }
+    /// The blocking version of [`crate::inventory::Inventory::deprecated_202007_get_locations_param_location`].
+    pub fn deprecated_202007_get_locations_param_location(
+        &self,
+        location_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_get_locations_param_location(location_id),
+        )
+    }
+    /// The blocking version of [`crate::inventory::Inventory::deprecated_202007_get_locations_count`].
+    pub fn deprecated_202007_get_locations_count(&self) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202007_get_locations_count())
+    }
+    /// The blocking version of [`crate::inventory::Inventory::deprecated_202007_get_locations_param_location_level`].
+    pub fn deprecated_202007_get_locations_param_location_level(
+        &self,
+        location_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_get_locations_param_location_level(location_id),
+        )
+    }
+    /// The blocking version of [`crate::inventory::Inventory::get_location`].
+    pub fn get_location(&self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.get_location())
+    }
+    /// The blocking version of [`crate::inventory::Inventory::get_locations_param_location`].
+    pub fn get_locations_param_location(
+        &self,
+        location_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.get_locations_param_location(location_id))
+    }
+    /// The blocking version of [`crate::inventory::Inventory::get_locations_count`].
+    pub fn get_locations_count(&self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.get_locations_count())
+    }
+    /// The blocking version of [`crate::inventory::Inventory::get_locations_param_location_level`].
+    pub fn get_locations_param_location_level(
+        &self,
+        location_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.get_locations_param_location_level(location_id))
+    }
+    /// The blocking version of [`crate::inventory::Inventory::deprecated_202101_get_location`].
+    pub fn deprecated_202101_get_location(&self) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202101_get_location())
+    }
+    /// The blocking version of [`crate::inventory::Inventory::deprecated_202101_get_locations_param_location`].
+    pub fn deprecated_202101_get_locations_param_location(
+        &self,
+        location_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_get_locations_param_location(location_id),
+        )
+    }
+    /// The blocking version of [`crate::inventory::Inventory::deprecated_202101_get_locations_count`].
+    pub fn deprecated_202101_get_locations_count(&self) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202101_get_locations_count())
+    }
+    /// The blocking version of [`crate::inventory::Inventory::deprecated_202101_get_locations_param_location_level`].
+    pub fn deprecated_202101_get_locations_param_location_level(
+        &self,
+        location_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_get_locations_param_location_level(location_id),
+        )
+    }
+    /// The blocking version of [`crate::inventory::Inventory::deprecated_unstable_get_location`].
+    pub fn deprecated_unstable_get_location(&self) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_unstable_get_location())
+    }
+    /// The blocking version of [`crate::inventory::Inventory::deprecated_unstable_get_locations_param_location`].
+    pub fn deprecated_unstable_get_locations_param_location(
+        &self,
+        location_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_get_locations_param_location(location_id),
+        )
+    }
+    /// The blocking version of [`crate::inventory::Inventory::deprecated_unstable_get_locations_count`].
+    pub fn deprecated_unstable_get_locations_count(&self) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_unstable_get_locations_count())
+    }
+    /// The blocking version of [`crate::inventory::Inventory::deprecated_unstable_get_locations_param_location_level`].
+    pub fn deprecated_unstable_get_locations_param_location_level(
+        &self,
+        location_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_get_locations_param_location_level(location_id),
+        )
+    }
+}
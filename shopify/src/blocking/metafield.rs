@@ -0,0 +1,693 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Metafield::deprecated_202001_get`], set with a builder.
+pub struct MetafieldDeprecated202001GetRequest<'a> {
+    inner: crate::metafield::MetafieldDeprecated202001GetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> MetafieldDeprecated202001GetRequest<'a> {
+    /// Sets the `metafield[owner_id]` query parameter.
+    pub fn metafield_owner_id(mut self, metafield_owner_id: i64) -> Self {
+        self.inner = self.inner.metafield_owner_id(metafield_owner_id);
+        self
+    }
+
+    /// Sets the `metafield[owner_resource]` query parameter.
+    pub fn metafield_owner_resource(mut self, metafield_owner_resource: &'a str) -> Self {
+        self.inner = self
+            .inner
+            .metafield_owner_resource(metafield_owner_resource);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Metafield::deprecated_202001_get_param`], set with a builder.
+pub struct MetafieldDeprecated202001GetParamRequest<'a> {
+    inner: crate::metafield::MetafieldDeprecated202001GetParamRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> MetafieldDeprecated202001GetParamRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Metafield::deprecated_202004_get`], set with a builder.
+pub struct MetafieldDeprecated202004GetRequest<'a> {
+    inner: crate::metafield::MetafieldDeprecated202004GetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> MetafieldDeprecated202004GetRequest<'a> {
+    /// Sets the `metafield[owner_id]` query parameter.
+    pub fn metafield_owner_id(mut self, metafield_owner_id: i64) -> Self {
+        self.inner = self.inner.metafield_owner_id(metafield_owner_id);
+        self
+    }
+
+    /// Sets the `metafield[owner_resource]` query parameter.
+    pub fn metafield_owner_resource(mut self, metafield_owner_resource: &'a str) -> Self {
+        self.inner = self
+            .inner
+            .metafield_owner_resource(metafield_owner_resource);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Metafield::deprecated_202004_get_param`], set with a builder.
+pub struct MetafieldDeprecated202004GetParamRequest<'a> {
+    inner: crate::metafield::MetafieldDeprecated202004GetParamRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> MetafieldDeprecated202004GetParamRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Metafield::deprecated_202007_get`], set with a builder.
+pub struct MetafieldDeprecated202007GetRequest<'a> {
+    inner: crate::metafield::MetafieldDeprecated202007GetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> MetafieldDeprecated202007GetRequest<'a> {
+    /// Sets the `metafield[owner_id]` query parameter.
+    pub fn metafield_owner_id(mut self, metafield_owner_id: i64) -> Self {
+        self.inner = self.inner.metafield_owner_id(metafield_owner_id);
+        self
+    }
+
+    /// Sets the `metafield[owner_resource]` query parameter.
+    pub fn metafield_owner_resource(mut self, metafield_owner_resource: &'a str) -> Self {
+        self.inner = self
+            .inner
+            .metafield_owner_resource(metafield_owner_resource);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Metafield::deprecated_202007_get_param`], set with a builder.
+pub struct MetafieldDeprecated202007GetParamRequest<'a> {
+    inner: crate::metafield::MetafieldDeprecated202007GetParamRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> MetafieldDeprecated202007GetParamRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Metafield::get`], set with a builder.
+pub struct MetafieldGetRequest<'a> {
+    inner: crate::metafield::MetafieldGetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> MetafieldGetRequest<'a> {
+    /// Sets the `metafield[owner_id]` query parameter.
+    pub fn metafield_owner_id(mut self, metafield_owner_id: i64) -> Self {
+        self.inner = self.inner.metafield_owner_id(metafield_owner_id);
+        self
+    }
+
+    /// Sets the `metafield[owner_resource]` query parameter.
+    pub fn metafield_owner_resource(mut self, metafield_owner_resource: &'a str) -> Self {
+        self.inner = self
+            .inner
+            .metafield_owner_resource(metafield_owner_resource);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Metafield::get_param`], set with a builder.
+pub struct MetafieldGetParamRequest<'a> {
+    inner: crate::metafield::MetafieldGetParamRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> MetafieldGetParamRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Metafield::deprecated_202101_get`], set with a builder.
+pub struct MetafieldDeprecated202101GetRequest<'a> {
+    inner: crate::metafield::MetafieldDeprecated202101GetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> MetafieldDeprecated202101GetRequest<'a> {
+    /// Sets the `metafield[owner_id]` query parameter.
+    pub fn metafield_owner_id(mut self, metafield_owner_id: i64) -> Self {
+        self.inner = self.inner.metafield_owner_id(metafield_owner_id);
+        self
+    }
+
+    /// Sets the `metafield[owner_resource]` query parameter.
+    pub fn metafield_owner_resource(mut self, metafield_owner_resource: &'a str) -> Self {
+        self.inner = self
+            .inner
+            .metafield_owner_resource(metafield_owner_resource);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Metafield::deprecated_202101_get_param`], set with a builder.
+pub struct MetafieldDeprecated202101GetParamRequest<'a> {
+    inner: crate::metafield::MetafieldDeprecated202101GetParamRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> MetafieldDeprecated202101GetParamRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Metafield::deprecated_unstable_get`], set with a builder.
+pub struct MetafieldDeprecatedUnstableGetRequest<'a> {
+    inner: crate::metafield::MetafieldDeprecatedUnstableGetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> MetafieldDeprecatedUnstableGetRequest<'a> {
+    /// Sets the `metafield[owner_id]` query parameter.
+    pub fn metafield_owner_id(mut self, metafield_owner_id: i64) -> Self {
+        self.inner = self.inner.metafield_owner_id(metafield_owner_id);
+        self
+    }
+
+    /// Sets the `metafield[owner_resource]` query parameter.
+    pub fn metafield_owner_resource(mut self, metafield_owner_resource: &'a str) -> Self {
+        self.inner = self
+            .inner
+            .metafield_owner_resource(metafield_owner_resource);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Metafield::deprecated_unstable_get_param`], set with a builder.
+pub struct MetafieldDeprecatedUnstableGetParamRequest<'a> {
+    inner: crate::metafield::MetafieldDeprecatedUnstableGetParamRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> MetafieldDeprecatedUnstableGetParamRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::metafield::Metafield`].
+pub struct Metafield {
+    inner: crate::metafield::Metafield,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Metafield {
+    pub(crate) fn new(
+        inner: crate::metafield::Metafield,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Metafield { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::metafield::Metafield::deprecated_202001_get`].
+    pub fn deprecated_202001_get(
+        &self,
+        metafield_owner_id: i64,
+        metafield_owner_resource: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_get(metafield_owner_id, metafield_owner_resource),
+        )
+    }
+    /// The blocking version of [`crate::metafield::Metafield::deprecated_202001_get_request`].
+    pub fn deprecated_202001_get_request(&self) -> MetafieldDeprecated202001GetRequest<'_> {
+        MetafieldDeprecated202001GetRequest {
+            inner: self.inner.deprecated_202001_get_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::metafield::Metafield::deprecated_202001_create`].
+    pub fn deprecated_202001_create(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202001_create(body))
+    }
+    /// The blocking version of [`crate::metafield::Metafield::deprecated_202001_get_count`].
+    pub fn deprecated_202001_get_count(&self) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202001_get_count())
+    }
+    /// The blocking version of [`crate::metafield::Metafield::deprecated_202001_get_param`].
+    pub fn deprecated_202001_get_param(
+        &self,
+        metafield_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202001_get_param(metafield_id, fields))
+    }
+    /// The blocking version of [`crate::metafield::Metafield::deprecated_202001_get_param_request`].
+    pub fn deprecated_202001_get_param_request<'a>(
+        &'a self,
+        metafield_id: &'a str,
+    ) -> MetafieldDeprecated202001GetParamRequest<'a> {
+        MetafieldDeprecated202001GetParamRequest {
+            inner: self.inner.deprecated_202001_get_param_request(metafield_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::metafield::Metafield::deprecated_202001_update_param`].
+    pub fn deprecated_202001_update_param(
+        &self,
+        metafield_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_update_param(metafield_id, body),
+        )
+    }
+    /// The blocking version of [`crate::metafield::Metafield::deprecated_202001_delete_param`].
+    pub fn deprecated_202001_delete_param(
+        &self,
+        metafield_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202001_delete_param(metafield_id))
+    }
+    /// The blocking version of [`crate::metafield::Metafield::deprecated_202004_get`].
+    pub fn deprecated_202004_get(
+        &self,
+        metafield_owner_id: i64,
+        metafield_owner_resource: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_get(metafield_owner_id, metafield_owner_resource),
+        )
+    }
+    /// The blocking version of [`crate::metafield::Metafield::deprecated_202004_get_request`].
+    pub fn deprecated_202004_get_request(&self) -> MetafieldDeprecated202004GetRequest<'_> {
+        MetafieldDeprecated202004GetRequest {
+            inner: self.inner.deprecated_202004_get_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::metafield::Metafield::deprecated_202004_create`].
+    pub fn deprecated_202004_create(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202004_create(body))
+    }
+    /// The blocking version of [`crate::metafield::Metafield::deprecated_202004_get_count`].
+    pub fn deprecated_202004_get_count(&self) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202004_get_count())
+    }
+    /// The blocking version of [`crate::metafield::Metafield::deprecated_202004_get_param`].
+    pub fn deprecated_202004_get_param(
+        &self,
+        metafield_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202004_get_param(metafield_id, fields))
+    }
+    /// The blocking version of [`crate::metafield::Metafield::deprecated_202004_get_param_request`].
+    pub fn deprecated_202004_get_param_request<'a>(
+        &'a self,
+        metafield_id: &'a str,
+    ) -> MetafieldDeprecated202004GetParamRequest<'a> {
+        MetafieldDeprecated202004GetParamRequest {
+            inner: self.inner.deprecated_202004_get_param_request(metafield_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::metafield::Metafield::deprecated_202004_update_param`].
+    pub fn deprecated_202004_update_param(
+        &self,
+        metafield_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_update_param(metafield_id, body),
+        )
+    }
+    /// The blocking version of [`crate::metafield::Metafield::deprecated_202004_delete_param`].
+    pub fn deprecated_202004_delete_param(
+        &self,
+        metafield_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202004_delete_param(metafield_id))
+    }
+    /// The blocking version of [`crate::metafield::Metafield::deprecated_202007_get`].
+    pub fn deprecated_202007_get(
+        &self,
+        metafield_owner_id: i64,
+        metafield_owner_resource: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_get(metafield_owner_id, metafield_owner_resource),
+        )
+    }
+    /// The blocking version of [`crate::metafield::Metafield::deprecated_202007_get_request`].
+    pub fn deprecated_202007_get_request(&self) -> MetafieldDeprecated202007GetRequest<'_> {
+        MetafieldDeprecated202007GetRequest {
+            inner: self.inner.deprecated_202007_get_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::metafield::Metafield::deprecated_202007_create`].
+    pub fn deprecated_202007_create(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202007_create(body))
+    }
+    /// The blocking version of [`crate::metafield::Metafield::deprecated_202007_get_count`].
+    pub fn deprecated_202007_get_count(&self) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202007_get_count())
+    }
+    /// The blocking version of [`crate::metafield::Metafield::deprecated_202007_get_param`].
+    pub fn deprecated_202007_get_param(
+        &self,
+        metafield_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202007_get_param(metafield_id, fields))
+    }
+    /// The blocking version of [`crate::metafield::Metafield::deprecated_202007_get_param_request`].
+    pub fn deprecated_202007_get_param_request<'a>(
+        &'a self,
+        metafield_id: &'a str,
+    ) -> MetafieldDeprecated202007GetParamRequest<'a> {
+        MetafieldDeprecated202007GetParamRequest {
+            inner: self.inner.deprecated_202007_get_param_request(metafield_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::metafield::Metafield::deprecated_202007_update_param`].
+    pub fn deprecated_202007_update_param(
+        &self,
+        metafield_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_update_param(metafield_id, body),
+        )
+    }
+    /// The blocking version of [`crate::metafield::Metafield::deprecated_202007_delete_param`].
+    pub fn deprecated_202007_delete_param(
+        &self,
+        metafield_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202007_delete_param(metafield_id))
+    }
+    /// The blocking version of [`crate::metafield::Metafield::get`].
+    pub fn get(
+        &self,
+        metafield_owner_id: i64,
+        metafield_owner_resource: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.get(metafield_owner_id, metafield_owner_resource))
+    }
+    /// The blocking version of [`crate::metafield::Metafield::get_request`].
+    pub fn get_request(&self) -> MetafieldGetRequest<'_> {
+        MetafieldGetRequest {
+            inner: self.inner.get_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::metafield::Metafield::create`].
+    pub fn create(&self, body: &serde_json::Value) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.create(body))
+    }
+    /// The blocking version of [`crate::metafield::Metafield::get_count`].
+    pub fn get_count(&self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.get_count())
+    }
+    /// The blocking version of [`crate::metafield::Metafield::get_param`].
+    pub fn get_param(&self, metafield_id: &str, fields: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.get_param(metafield_id, fields))
+    }
+    /// The blocking version of [`crate::metafield::Metafield::get_param_request`].
+    pub fn get_param_request<'a>(&'a self, metafield_id: &'a str) -> MetafieldGetParamRequest<'a> {
+        MetafieldGetParamRequest {
+            inner: self.inner.get_param_request(metafield_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::metafield::Metafield::update_param`].
+    pub fn update_param(
+        &self,
+        metafield_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.update_param(metafield_id, body))
+    }
+    /// The blocking version of [`crate::metafield::Metafield::delete_param`].
+    pub fn delete_param(&self, metafield_id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.delete_param(metafield_id))
+    }
+    /// The blocking version of [`crate::metafield::Metafield::deprecated_202101_get`].
+    pub fn deprecated_202101_get(
+        &self,
+        metafield_owner_id: i64,
+        metafield_owner_resource: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_get(metafield_owner_id, metafield_owner_resource),
+        )
+    }
+    /// The blocking version of [`crate::metafield::Metafield::deprecated_202101_get_request`].
+    pub fn deprecated_202101_get_request(&self) -> MetafieldDeprecated202101GetRequest<'_> {
+        MetafieldDeprecated202101GetRequest {
+            inner: self.inner.deprecated_202101_get_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::metafield::Metafield::deprecated_202101_create`].
+    pub fn deprecated_202101_create(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202101_create(body))
+    }
+    /// The blocking version of [`crate::metafield::Metafield::deprecated_202101_get_count`].
+    pub fn deprecated_202101_get_count(&self) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202101_get_count())
+    }
+    /// The blocking version of [`crate::metafield::Metafield::deprecated_202101_get_param`].
+    pub fn deprecated_202101_get_param(
+        &self,
+        metafield_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202101_get_param(metafield_id, fields))
+    }
+    /// The blocking version of [`crate::metafield::Metafield::deprecated_202101_get_param_request`].
+    pub fn deprecated_202101_get_param_request<'a>(
+        &'a self,
+        metafield_id: &'a str,
+    ) -> MetafieldDeprecated202101GetParamRequest<'a> {
+        MetafieldDeprecated202101GetParamRequest {
+            inner: self.inner.deprecated_202101_get_param_request(metafield_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::metafield::Metafield::deprecated_202101_update_param`].
+    pub fn deprecated_202101_update_param(
+        &self,
+        metafield_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_update_param(metafield_id, body),
+        )
+    }
+    /// The blocking version of [`crate::metafield::Metafield::deprecated_202101_delete_param`].
+    pub fn deprecated_202101_delete_param(
+        &self,
+        metafield_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202101_delete_param(metafield_id))
+    }
+    /// The blocking version of [`crate::metafield::Metafield::deprecated_unstable_get`].
+    pub fn deprecated_unstable_get(
+        &self,
+        metafield_owner_id: i64,
+        metafield_owner_resource: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_get(metafield_owner_id, metafield_owner_resource),
+        )
+    }
+    /// The blocking version of [`crate::metafield::Metafield::deprecated_unstable_get_request`].
+    pub fn deprecated_unstable_get_request(&self) -> MetafieldDeprecatedUnstableGetRequest<'_> {
+        MetafieldDeprecatedUnstableGetRequest {
+            inner: self.inner.deprecated_unstable_get_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::metafield::Metafield::deprecated_unstable_create`].
+    pub fn deprecated_unstable_create(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_unstable_create(body))
+    }
+    /// The blocking version of [`crate::metafield::Metafield::deprecated_unstable_get_count`].
+    pub fn deprecated_unstable_get_count(&self) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_unstable_get_count())
+    }
+    /// The blocking version of [`crate::metafield::Metafield::deprecated_unstable_get_param`].
+    pub fn deprecated_unstable_get_param(
+        &self,
+        metafield_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_get_param(metafield_id, fields),
+        )
+    }
+    /// The blocking version of [`crate::metafield::Metafield::deprecated_unstable_get_param_request`].
+    pub fn deprecated_unstable_get_param_request<'a>(
+        &'a self,
+        metafield_id: &'a str,
+    ) -> MetafieldDeprecatedUnstableGetParamRequest<'a> {
+        MetafieldDeprecatedUnstableGetParamRequest {
+            inner: self
+                .inner
+                .deprecated_unstable_get_param_request(metafield_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::metafield::Metafield::deprecated_unstable_update_param`].
+    pub fn deprecated_unstable_update_param(
+        &self,
+        metafield_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_update_param(metafield_id, body),
+        )
+    }
+    /// The blocking version of [`crate::metafield::Metafield::deprecated_unstable_delete_param`].
+    pub fn deprecated_unstable_delete_param(
+        &self,
+        metafield_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_unstable_delete_param(metafield_id))
+    }
+}
@@ -0,0 +1,157 @@
+//! A blocking client, for the programs that are not async.
+//!
+//! It has the functions of the async [`Client`](crate::Client), which it runs on a
+//! runtime of its own, so they must not be called from within an async runtime. The
+//! `*_stream` functions are left out, the `*_all` ones returning all the pages at once.
+
+use std::{future::Future, sync::Arc};
+
+pub mod access;
+pub mod analytics;
+pub mod billing;
+pub mod customers;
+pub mod deprecated_api_calls;
+pub mod discounts;
+pub mod events;
+pub mod inventory;
+pub mod metafield;
+pub mod online_store;
+pub mod orders;
+pub mod plus;
+pub mod products;
+pub mod sales_channels;
+pub mod shipping_and_fulfillment;
+pub mod shopify_payments;
+pub mod store_properties;
+pub mod tendertransaction;
+
+/// A blocking client, sending its requests with an async [`Client`](crate::Client).
+#[derive(Clone)]
+pub struct Client {
+    inner: crate::Client,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Client {
+    /// Wraps an async client, creating the runtime its requests run on.
+    pub fn new(client: crate::Client) -> std::io::Result<Self> {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()?;
+
+        Ok(Client {
+            inner: client,
+            runtime: Arc::new(runtime),
+        })
+    }
+
+    /// Returns the async client the requests are sent with.
+    pub fn inner(&self) -> &crate::Client {
+        &self.inner
+    }
+
+    /// Runs a future to completion on the runtime of the client, for the async
+    /// functions that have no blocking version.
+    pub fn block_on<F: Future>(&self, future: F) -> F::Output {
+        self.runtime.block_on(future)
+    }
+
+    /// The blocking version of [`crate::Client::access`].
+    pub fn access(&self) -> access::Access {
+        access::Access::new(self.inner.access(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::analytics`].
+    pub fn analytics(&self) -> analytics::Analytics {
+        analytics::Analytics::new(self.inner.analytics(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::billing`].
+    pub fn billing(&self) -> billing::Billing {
+        billing::Billing::new(self.inner.billing(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::customers`].
+    pub fn customers(&self) -> customers::Customers {
+        customers::Customers::new(self.inner.customers(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::deprecated_api_calls`].
+    pub fn deprecated_api_calls(&self) -> deprecated_api_calls::DeprecatedApiCalls {
+        deprecated_api_calls::DeprecatedApiCalls::new(
+            self.inner.deprecated_api_calls(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::discounts`].
+    pub fn discounts(&self) -> discounts::Discounts {
+        discounts::Discounts::new(self.inner.discounts(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::events`].
+    pub fn events(&self) -> events::Events {
+        events::Events::new(self.inner.events(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::inventory`].
+    pub fn inventory(&self) -> inventory::Inventory {
+        inventory::Inventory::new(self.inner.inventory(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::metafield`].
+    pub fn metafield(&self) -> metafield::Metafield {
+        metafield::Metafield::new(self.inner.metafield(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::online_store`].
+    pub fn online_store(&self) -> online_store::OnlineStore {
+        online_store::OnlineStore::new(self.inner.online_store(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::orders`].
+    pub fn orders(&self) -> orders::Orders {
+        orders::Orders::new(self.inner.orders(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::plus`].
+    pub fn plus(&self) -> plus::Plus {
+        plus::Plus::new(self.inner.plus(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::products`].
+    pub fn products(&self) -> products::Products {
+        products::Products::new(self.inner.products(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::sales_channels`].
+    pub fn sales_channels(&self) -> sales_channels::SalesChannels {
+        sales_channels::SalesChannels::new(self.inner.sales_channels(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::shipping_and_fulfillment`].
+    pub fn shipping_and_fulfillment(&self) -> shipping_and_fulfillment::ShippingAndFulfillment {
+        shipping_and_fulfillment::ShippingAndFulfillment::new(
+            self.inner.shipping_and_fulfillment(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::shopify_payments`].
+    pub fn shopify_payments(&self) -> shopify_payments::ShopifyPayments {
+        shopify_payments::ShopifyPayments::new(self.inner.shopify_payments(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::store_properties`].
+    pub fn store_properties(&self) -> store_properties::StoreProperties {
+        store_properties::StoreProperties::new(self.inner.store_properties(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::tendertransaction`].
+    pub fn tendertransaction(&self) -> tendertransaction::Tendertransaction {
+        tendertransaction::Tendertransaction::new(
+            self.inner.tendertransaction(),
+            self.runtime.clone(),
+        )
+    }
+}
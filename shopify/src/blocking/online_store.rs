@@ -0,0 +1,6439 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`OnlineStore::deprecated_202001_get_blogs_param_blog_article`], set with a builder.
+pub struct OnlineStoreDeprecated202001GetBlogsParamBlogArticleRequest<'a> {
+    inner: crate::online_store::OnlineStoreDeprecated202001GetBlogsParamBlogArticleRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OnlineStoreDeprecated202001GetBlogsParamBlogArticleRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.inner = self.inner.created_at_min(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.inner = self.inner.created_at_max(created_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.inner = self.inner.updated_at_min(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.inner = self.inner.updated_at_max(updated_at_max);
+        self
+    }
+
+    /// Sets the `published_at_min` query parameter.
+    pub fn published_at_min(mut self, published_at_min: &'a str) -> Self {
+        self.inner = self.inner.published_at_min(published_at_min);
+        self
+    }
+
+    /// Sets the `published_at_max` query parameter.
+    pub fn published_at_max(mut self, published_at_max: &'a str) -> Self {
+        self.inner = self.inner.published_at_max(published_at_max);
+        self
+    }
+
+    /// Sets the `published_status` query parameter.
+    pub fn published_status(mut self, published_status: &'a str) -> Self {
+        self.inner = self.inner.published_status(published_status);
+        self
+    }
+
+    /// Sets the `handle` query parameter.
+    pub fn handle(mut self, handle: &'a str) -> Self {
+        self.inner = self.inner.handle(handle);
+        self
+    }
+
+    /// Sets the `tag` query parameter.
+    pub fn tag(mut self, tag: &'a str) -> Self {
+        self.inner = self.inner.tag(tag);
+        self
+    }
+
+    /// Sets the `author` query parameter.
+    pub fn author(mut self, author: &'a str) -> Self {
+        self.inner = self.inner.author(author);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202001_get_blogs_param_blog_articles_count`], set with a builder.
+pub struct OnlineStoreDeprecated202001GetBlogsParamBlogArticlesCountRequest<'a> {
+    inner:
+        crate::online_store::OnlineStoreDeprecated202001GetBlogsParamBlogArticlesCountRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OnlineStoreDeprecated202001GetBlogsParamBlogArticlesCountRequest<'a> {
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.inner = self.inner.created_at_min(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.inner = self.inner.created_at_max(created_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.inner = self.inner.updated_at_min(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.inner = self.inner.updated_at_max(updated_at_max);
+        self
+    }
+
+    /// Sets the `published_at_min` query parameter.
+    pub fn published_at_min(mut self, published_at_min: &'a str) -> Self {
+        self.inner = self.inner.published_at_min(published_at_min);
+        self
+    }
+
+    /// Sets the `published_at_max` query parameter.
+    pub fn published_at_max(mut self, published_at_max: &'a str) -> Self {
+        self.inner = self.inner.published_at_max(published_at_max);
+        self
+    }
+
+    /// Sets the `published_status` query parameter.
+    pub fn published_status(mut self, published_status: &'a str) -> Self {
+        self.inner = self.inner.published_status(published_status);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202001_get_blogs_param_blog_articles_article`], set with a builder.
+pub struct OnlineStoreDeprecated202001GetBlogsParamBlogArticlesArticleRequest<'a> {
+    inner:
+        crate::online_store::OnlineStoreDeprecated202001GetBlogsParamBlogArticlesArticleRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OnlineStoreDeprecated202001GetBlogsParamBlogArticlesArticleRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202001_get_articles_tag`], set with a builder.
+pub struct OnlineStoreDeprecated202001GetArticlesTagRequest<'a> {
+    inner: crate::online_store::OnlineStoreDeprecated202001GetArticlesTagRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OnlineStoreDeprecated202001GetArticlesTagRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `popular` query parameter.
+    pub fn popular(mut self, popular: &'a str) -> Self {
+        self.inner = self.inner.popular(popular);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202004_get_blogs_param_blog_article`], set with a builder.
+pub struct OnlineStoreDeprecated202004GetBlogsParamBlogArticleRequest<'a> {
+    inner: crate::online_store::OnlineStoreDeprecated202004GetBlogsParamBlogArticleRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OnlineStoreDeprecated202004GetBlogsParamBlogArticleRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.inner = self.inner.created_at_min(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.inner = self.inner.created_at_max(created_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.inner = self.inner.updated_at_min(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.inner = self.inner.updated_at_max(updated_at_max);
+        self
+    }
+
+    /// Sets the `published_at_min` query parameter.
+    pub fn published_at_min(mut self, published_at_min: &'a str) -> Self {
+        self.inner = self.inner.published_at_min(published_at_min);
+        self
+    }
+
+    /// Sets the `published_at_max` query parameter.
+    pub fn published_at_max(mut self, published_at_max: &'a str) -> Self {
+        self.inner = self.inner.published_at_max(published_at_max);
+        self
+    }
+
+    /// Sets the `published_status` query parameter.
+    pub fn published_status(mut self, published_status: &'a str) -> Self {
+        self.inner = self.inner.published_status(published_status);
+        self
+    }
+
+    /// Sets the `handle` query parameter.
+    pub fn handle(mut self, handle: &'a str) -> Self {
+        self.inner = self.inner.handle(handle);
+        self
+    }
+
+    /// Sets the `tag` query parameter.
+    pub fn tag(mut self, tag: &'a str) -> Self {
+        self.inner = self.inner.tag(tag);
+        self
+    }
+
+    /// Sets the `author` query parameter.
+    pub fn author(mut self, author: &'a str) -> Self {
+        self.inner = self.inner.author(author);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202004_get_blogs_param_blog_articles_count`], set with a builder.
+pub struct OnlineStoreDeprecated202004GetBlogsParamBlogArticlesCountRequest<'a> {
+    inner:
+        crate::online_store::OnlineStoreDeprecated202004GetBlogsParamBlogArticlesCountRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OnlineStoreDeprecated202004GetBlogsParamBlogArticlesCountRequest<'a> {
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.inner = self.inner.created_at_min(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.inner = self.inner.created_at_max(created_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.inner = self.inner.updated_at_min(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.inner = self.inner.updated_at_max(updated_at_max);
+        self
+    }
+
+    /// Sets the `published_at_min` query parameter.
+    pub fn published_at_min(mut self, published_at_min: &'a str) -> Self {
+        self.inner = self.inner.published_at_min(published_at_min);
+        self
+    }
+
+    /// Sets the `published_at_max` query parameter.
+    pub fn published_at_max(mut self, published_at_max: &'a str) -> Self {
+        self.inner = self.inner.published_at_max(published_at_max);
+        self
+    }
+
+    /// Sets the `published_status` query parameter.
+    pub fn published_status(mut self, published_status: &'a str) -> Self {
+        self.inner = self.inner.published_status(published_status);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202004_get_blogs_param_blog_articles_article`], set with a builder.
+pub struct OnlineStoreDeprecated202004GetBlogsParamBlogArticlesArticleRequest<'a> {
+    inner:
+        crate::online_store::OnlineStoreDeprecated202004GetBlogsParamBlogArticlesArticleRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OnlineStoreDeprecated202004GetBlogsParamBlogArticlesArticleRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202004_get_articles_tag`], set with a builder.
+pub struct OnlineStoreDeprecated202004GetArticlesTagRequest<'a> {
+    inner: crate::online_store::OnlineStoreDeprecated202004GetArticlesTagRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OnlineStoreDeprecated202004GetArticlesTagRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `popular` query parameter.
+    pub fn popular(mut self, popular: &'a str) -> Self {
+        self.inner = self.inner.popular(popular);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202007_get_blogs_param_blog_article`], set with a builder.
+pub struct OnlineStoreDeprecated202007GetBlogsParamBlogArticleRequest<'a> {
+    inner: crate::online_store::OnlineStoreDeprecated202007GetBlogsParamBlogArticleRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OnlineStoreDeprecated202007GetBlogsParamBlogArticleRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.inner = self.inner.created_at_min(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.inner = self.inner.created_at_max(created_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.inner = self.inner.updated_at_min(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.inner = self.inner.updated_at_max(updated_at_max);
+        self
+    }
+
+    /// Sets the `published_at_min` query parameter.
+    pub fn published_at_min(mut self, published_at_min: &'a str) -> Self {
+        self.inner = self.inner.published_at_min(published_at_min);
+        self
+    }
+
+    /// Sets the `published_at_max` query parameter.
+    pub fn published_at_max(mut self, published_at_max: &'a str) -> Self {
+        self.inner = self.inner.published_at_max(published_at_max);
+        self
+    }
+
+    /// Sets the `published_status` query parameter.
+    pub fn published_status(mut self, published_status: &'a str) -> Self {
+        self.inner = self.inner.published_status(published_status);
+        self
+    }
+
+    /// Sets the `handle` query parameter.
+    pub fn handle(mut self, handle: &'a str) -> Self {
+        self.inner = self.inner.handle(handle);
+        self
+    }
+
+    /// Sets the `tag` query parameter.
+    pub fn tag(mut self, tag: &'a str) -> Self {
+        self.inner = self.inner.tag(tag);
+        self
+    }
+
+    /// Sets the `author` query parameter.
+    pub fn author(mut self, author: &'a str) -> Self {
+        self.inner = self.inner.author(author);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202007_get_blogs_param_blog_articles_count`], set with a builder.
+pub struct OnlineStoreDeprecated202007GetBlogsParamBlogArticlesCountRequest<'a> {
+    inner:
+        crate::online_store::OnlineStoreDeprecated202007GetBlogsParamBlogArticlesCountRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OnlineStoreDeprecated202007GetBlogsParamBlogArticlesCountRequest<'a> {
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.inner = self.inner.created_at_min(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.inner = self.inner.created_at_max(created_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.inner = self.inner.updated_at_min(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.inner = self.inner.updated_at_max(updated_at_max);
+        self
+    }
+
+    /// Sets the `published_at_min` query parameter.
+    pub fn published_at_min(mut self, published_at_min: &'a str) -> Self {
+        self.inner = self.inner.published_at_min(published_at_min);
+        self
+    }
+
+    /// Sets the `published_at_max` query parameter.
+    pub fn published_at_max(mut self, published_at_max: &'a str) -> Self {
+        self.inner = self.inner.published_at_max(published_at_max);
+        self
+    }
+
+    /// Sets the `published_status` query parameter.
+    pub fn published_status(mut self, published_status: &'a str) -> Self {
+        self.inner = self.inner.published_status(published_status);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202007_get_blogs_param_blog_articles_article`], set with a builder.
+pub struct OnlineStoreDeprecated202007GetBlogsParamBlogArticlesArticleRequest<'a> {
+    inner:
+        crate::online_store::OnlineStoreDeprecated202007GetBlogsParamBlogArticlesArticleRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OnlineStoreDeprecated202007GetBlogsParamBlogArticlesArticleRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202007_get_articles_tag`], set with a builder.
+pub struct OnlineStoreDeprecated202007GetArticlesTagRequest<'a> {
+    inner: crate::online_store::OnlineStoreDeprecated202007GetArticlesTagRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OnlineStoreDeprecated202007GetArticlesTagRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `popular` query parameter.
+    pub fn popular(mut self, popular: &'a str) -> Self {
+        self.inner = self.inner.popular(popular);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`OnlineStore::get_blogs_param_blog_article`], set with a builder.
+pub struct OnlineStoreGetBlogsParamBlogArticleRequest<'a> {
+    inner: crate::online_store::OnlineStoreGetBlogsParamBlogArticleRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OnlineStoreGetBlogsParamBlogArticleRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.inner = self.inner.created_at_min(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.inner = self.inner.created_at_max(created_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.inner = self.inner.updated_at_min(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.inner = self.inner.updated_at_max(updated_at_max);
+        self
+    }
+
+    /// Sets the `published_at_min` query parameter.
+    pub fn published_at_min(mut self, published_at_min: &'a str) -> Self {
+        self.inner = self.inner.published_at_min(published_at_min);
+        self
+    }
+
+    /// Sets the `published_at_max` query parameter.
+    pub fn published_at_max(mut self, published_at_max: &'a str) -> Self {
+        self.inner = self.inner.published_at_max(published_at_max);
+        self
+    }
+
+    /// Sets the `published_status` query parameter.
+    pub fn published_status(mut self, published_status: &'a str) -> Self {
+        self.inner = self.inner.published_status(published_status);
+        self
+    }
+
+    /// Sets the `handle` query parameter.
+    pub fn handle(mut self, handle: &'a str) -> Self {
+        self.inner = self.inner.handle(handle);
+        self
+    }
+
+    /// Sets the `tag` query parameter.
+    pub fn tag(mut self, tag: &'a str) -> Self {
+        self.inner = self.inner.tag(tag);
+        self
+    }
+
+    /// Sets the `author` query parameter.
+    pub fn author(mut self, author: &'a str) -> Self {
+        self.inner = self.inner.author(author);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`OnlineStore::get_blogs_param_blog_articles_count`], set with a builder.
+pub struct OnlineStoreGetBlogsParamBlogArticlesCountRequest<'a> {
+    inner: crate::online_store::OnlineStoreGetBlogsParamBlogArticlesCountRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OnlineStoreGetBlogsParamBlogArticlesCountRequest<'a> {
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.inner = self.inner.created_at_min(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.inner = self.inner.created_at_max(created_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.inner = self.inner.updated_at_min(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.inner = self.inner.updated_at_max(updated_at_max);
+        self
+    }
+
+    /// Sets the `published_at_min` query parameter.
+    pub fn published_at_min(mut self, published_at_min: &'a str) -> Self {
+        self.inner = self.inner.published_at_min(published_at_min);
+        self
+    }
+
+    /// Sets the `published_at_max` query parameter.
+    pub fn published_at_max(mut self, published_at_max: &'a str) -> Self {
+        self.inner = self.inner.published_at_max(published_at_max);
+        self
+    }
+
+    /// Sets the `published_status` query parameter.
+    pub fn published_status(mut self, published_status: &'a str) -> Self {
+        self.inner = self.inner.published_status(published_status);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`OnlineStore::get_blogs_param_blog_articles_article`], set with a builder.
+pub struct OnlineStoreGetBlogsParamBlogArticlesArticleRequest<'a> {
+    inner: crate::online_store::OnlineStoreGetBlogsParamBlogArticlesArticleRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OnlineStoreGetBlogsParamBlogArticlesArticleRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`OnlineStore::get_articles_tag`], set with a builder.
+pub struct OnlineStoreGetArticlesTagRequest<'a> {
+    inner: crate::online_store::OnlineStoreGetArticlesTagRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OnlineStoreGetArticlesTagRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `popular` query parameter.
+    pub fn popular(mut self, popular: &'a str) -> Self {
+        self.inner = self.inner.popular(popular);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202101_get_blogs_param_blog_article`], set with a builder.
+pub struct OnlineStoreDeprecated202101GetBlogsParamBlogArticleRequest<'a> {
+    inner: crate::online_store::OnlineStoreDeprecated202101GetBlogsParamBlogArticleRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OnlineStoreDeprecated202101GetBlogsParamBlogArticleRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.inner = self.inner.created_at_min(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.inner = self.inner.created_at_max(created_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.inner = self.inner.updated_at_min(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.inner = self.inner.updated_at_max(updated_at_max);
+        self
+    }
+
+    /// Sets the `published_at_min` query parameter.
+    pub fn published_at_min(mut self, published_at_min: &'a str) -> Self {
+        self.inner = self.inner.published_at_min(published_at_min);
+        self
+    }
+
+    /// Sets the `published_at_max` query parameter.
+    pub fn published_at_max(mut self, published_at_max: &'a str) -> Self {
+        self.inner = self.inner.published_at_max(published_at_max);
+        self
+    }
+
+    /// Sets the `published_status` query parameter.
+    pub fn published_status(mut self, published_status: &'a str) -> Self {
+        self.inner = self.inner.published_status(published_status);
+        self
+    }
+
+    /// Sets the `handle` query parameter.
+    pub fn handle(mut self, handle: &'a str) -> Self {
+        self.inner = self.inner.handle(handle);
+        self
+    }
+
+    /// Sets the `tag` query parameter.
+    pub fn tag(mut self, tag: &'a str) -> Self {
+        self.inner = self.inner.tag(tag);
+        self
+    }
+
+    /// Sets the `author` query parameter.
+    pub fn author(mut self, author: &'a str) -> Self {
+        self.inner = self.inner.author(author);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202101_get_blogs_param_blog_articles_count`], set with a builder.
+pub struct OnlineStoreDeprecated202101GetBlogsParamBlogArticlesCountRequest<'a> {
+    inner:
+        crate::online_store::OnlineStoreDeprecated202101GetBlogsParamBlogArticlesCountRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OnlineStoreDeprecated202101GetBlogsParamBlogArticlesCountRequest<'a> {
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.inner = self.inner.created_at_min(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.inner = self.inner.created_at_max(created_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.inner = self.inner.updated_at_min(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.inner = self.inner.updated_at_max(updated_at_max);
+        self
+    }
+
+    /// Sets the `published_at_min` query parameter.
+    pub fn published_at_min(mut self, published_at_min: &'a str) -> Self {
+        self.inner = self.inner.published_at_min(published_at_min);
+        self
+    }
+
+    /// Sets the `published_at_max` query parameter.
+    pub fn published_at_max(mut self, published_at_max: &'a str) -> Self {
+        self.inner = self.inner.published_at_max(published_at_max);
+        self
+    }
+
+    /// Sets the `published_status` query parameter.
+    pub fn published_status(mut self, published_status: &'a str) -> Self {
+        self.inner = self.inner.published_status(published_status);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202101_get_blogs_param_blog_articles_article`], set with a builder.
+pub struct OnlineStoreDeprecated202101GetBlogsParamBlogArticlesArticleRequest<'a> {
+    inner:
+        crate::online_store::OnlineStoreDeprecated202101GetBlogsParamBlogArticlesArticleRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OnlineStoreDeprecated202101GetBlogsParamBlogArticlesArticleRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202101_get_articles_tag`], set with a builder.
+pub struct OnlineStoreDeprecated202101GetArticlesTagRequest<'a> {
+    inner: crate::online_store::OnlineStoreDeprecated202101GetArticlesTagRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OnlineStoreDeprecated202101GetArticlesTagRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `popular` query parameter.
+    pub fn popular(mut self, popular: &'a str) -> Self {
+        self.inner = self.inner.popular(popular);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_unstable_get_blogs_param_blog_article`], set with a builder.
+pub struct OnlineStoreDeprecatedUnstableGetBlogsParamBlogArticleRequest<'a> {
+    inner: crate::online_store::OnlineStoreDeprecatedUnstableGetBlogsParamBlogArticleRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OnlineStoreDeprecatedUnstableGetBlogsParamBlogArticleRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.inner = self.inner.created_at_min(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.inner = self.inner.created_at_max(created_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.inner = self.inner.updated_at_min(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.inner = self.inner.updated_at_max(updated_at_max);
+        self
+    }
+
+    /// Sets the `published_at_min` query parameter.
+    pub fn published_at_min(mut self, published_at_min: &'a str) -> Self {
+        self.inner = self.inner.published_at_min(published_at_min);
+        self
+    }
+
+    /// Sets the `published_at_max` query parameter.
+    pub fn published_at_max(mut self, published_at_max: &'a str) -> Self {
+        self.inner = self.inner.published_at_max(published_at_max);
+        self
+    }
+
+    /// Sets the `published_status` query parameter.
+    pub fn published_status(mut self, published_status: &'a str) -> Self {
+        self.inner = self.inner.published_status(published_status);
+        self
+    }
+
+    /// Sets the `handle` query parameter.
+    pub fn handle(mut self, handle: &'a str) -> Self {
+        self.inner = self.inner.handle(handle);
+        self
+    }
+
+    /// Sets the `tag` query parameter.
+    pub fn tag(mut self, tag: &'a str) -> Self {
+        self.inner = self.inner.tag(tag);
+        self
+    }
+
+    /// Sets the `author` query parameter.
+    pub fn author(mut self, author: &'a str) -> Self {
+        self.inner = self.inner.author(author);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_unstable_get_blogs_param_blog_articles_count`], set with a builder.
+pub struct OnlineStoreDeprecatedUnstableGetBlogsParamBlogArticlesCountRequest<'a> {
+    inner:
+        crate::online_store::OnlineStoreDeprecatedUnstableGetBlogsParamBlogArticlesCountRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OnlineStoreDeprecatedUnstableGetBlogsParamBlogArticlesCountRequest<'a> {
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.inner = self.inner.created_at_min(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.inner = self.inner.created_at_max(created_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.inner = self.inner.updated_at_min(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.inner = self.inner.updated_at_max(updated_at_max);
+        self
+    }
+
+    /// Sets the `published_at_min` query parameter.
+    pub fn published_at_min(mut self, published_at_min: &'a str) -> Self {
+        self.inner = self.inner.published_at_min(published_at_min);
+        self
+    }
+
+    /// Sets the `published_at_max` query parameter.
+    pub fn published_at_max(mut self, published_at_max: &'a str) -> Self {
+        self.inner = self.inner.published_at_max(published_at_max);
+        self
+    }
+
+    /// Sets the `published_status` query parameter.
+    pub fn published_status(mut self, published_status: &'a str) -> Self {
+        self.inner = self.inner.published_status(published_status);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_unstable_get_blogs_param_blog_articles_article`], set with a builder.
+pub struct OnlineStoreDeprecatedUnstableGetBlogsParamBlogArticlesArticleRequest<'a> {
+    inner:
+        crate::online_store::OnlineStoreDeprecatedUnstableGetBlogsParamBlogArticlesArticleRequest<
+            'a,
+        >,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OnlineStoreDeprecatedUnstableGetBlogsParamBlogArticlesArticleRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_unstable_get_articles_tag`], set with a builder.
+pub struct OnlineStoreDeprecatedUnstableGetArticlesTagRequest<'a> {
+    inner: crate::online_store::OnlineStoreDeprecatedUnstableGetArticlesTagRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OnlineStoreDeprecatedUnstableGetArticlesTagRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `popular` query parameter.
+    pub fn popular(mut self, popular: &'a str) -> Self {
+        self.inner = self.inner.popular(popular);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202001_get_themes_param_theme_asset`], set with a builder.
+pub struct OnlineStoreDeprecated202001GetThemesParamThemeAssetRequest<'a> {
+    inner: crate::online_store::OnlineStoreDeprecated202001GetThemesParamThemeAssetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OnlineStoreDeprecated202001GetThemesParamThemeAssetRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `asset[key]` query parameter.
+    pub fn asset_key(mut self, asset_key: &'a str) -> Self {
+        self.inner = self.inner.asset_key(asset_key);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202001_update_themes_param_theme_assets`], set with a builder.
+pub struct OnlineStoreDeprecated202001UpdateThemesParamThemeAssetsRequest<'a> {
+    inner: crate::online_store::OnlineStoreDeprecated202001UpdateThemesParamThemeAssetsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OnlineStoreDeprecated202001UpdateThemesParamThemeAssetsRequest<'a> {
+    /// Sets the `src` query parameter.
+    pub fn src(mut self, src: &'a str) -> Self {
+        self.inner = self.inner.src(src);
+        self
+    }
+
+    /// Sets the `source_key` query parameter.
+    pub fn source_key(mut self, source_key: &'a str) -> Self {
+        self.inner = self.inner.source_key(source_key);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202001_delete_themes_param_theme_assets`], set with a builder.
+pub struct OnlineStoreDeprecated202001DeleteThemesParamThemeAssetsRequest<'a> {
+    inner: crate::online_store::OnlineStoreDeprecated202001DeleteThemesParamThemeAssetsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OnlineStoreDeprecated202001DeleteThemesParamThemeAssetsRequest<'a> {
+    /// Sets the `asset[key]` query parameter.
+    pub fn asset_key(mut self, asset_key: &'a str) -> Self {
+        self.inner = self.inner.asset_key(asset_key);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202004_get_themes_param_theme_asset`], set with a builder.
+pub struct OnlineStoreDeprecated202004GetThemesParamThemeAssetRequest<'a> {
+    inner: crate::online_store::OnlineStoreDeprecated202004GetThemesParamThemeAssetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OnlineStoreDeprecated202004GetThemesParamThemeAssetRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `asset[key]` query parameter.
+    pub fn asset_key(mut self, asset_key: &'a str) -> Self {
+        self.inner = self.inner.asset_key(asset_key);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202004_update_themes_param_theme_assets`], set with a builder.
+pub struct OnlineStoreDeprecated202004UpdateThemesParamThemeAssetsRequest<'a> {
+    inner: crate::online_store::OnlineStoreDeprecated202004UpdateThemesParamThemeAssetsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OnlineStoreDeprecated202004UpdateThemesParamThemeAssetsRequest<'a> {
+    /// Sets the `src` query parameter.
+    pub fn src(mut self, src: &'a str) -> Self {
+        self.inner = self.inner.src(src);
+        self
+    }
+
+    /// Sets the `source_key` query parameter.
+    pub fn source_key(mut self, source_key: &'a str) -> Self {
+        self.inner = self.inner.source_key(source_key);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202004_delete_themes_param_theme_assets`], set with a builder.
+pub struct OnlineStoreDeprecated202004DeleteThemesParamThemeAssetsRequest<'a> {
+    inner: crate::online_store::OnlineStoreDeprecated202004DeleteThemesParamThemeAssetsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OnlineStoreDeprecated202004DeleteThemesParamThemeAssetsRequest<'a> {
+    /// Sets the `asset[key]` query parameter.
+    pub fn asset_key(mut self, asset_key: &'a str) -> Self {
+        self.inner = self.inner.asset_key(asset_key);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202007_get_themes_param_theme_asset`], set with a builder.
+pub struct OnlineStoreDeprecated202007GetThemesParamThemeAssetRequest<'a> {
+    inner: crate::online_store::OnlineStoreDeprecated202007GetThemesParamThemeAssetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OnlineStoreDeprecated202007GetThemesParamThemeAssetRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `asset[key]` query parameter.
+    pub fn asset_key(mut self, asset_key: &'a str) -> Self {
+        self.inner = self.inner.asset_key(asset_key);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202007_update_themes_param_theme_assets`], set with a builder.
+pub struct OnlineStoreDeprecated202007UpdateThemesParamThemeAssetsRequest<'a> {
+    inner: crate::online_store::OnlineStoreDeprecated202007UpdateThemesParamThemeAssetsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OnlineStoreDeprecated202007UpdateThemesParamThemeAssetsRequest<'a> {
+    /// Sets the `src` query parameter.
+    pub fn src(mut self, src: &'a str) -> Self {
+        self.inner = self.inner.src(src);
+        self
+    }
+
+    /// Sets the `source_key` query parameter.
+    pub fn source_key(mut self, source_key: &'a str) -> Self {
+        self.inner = self.inner.source_key(source_key);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202007_delete_themes_param_theme_assets`], set with a builder.
+pub struct OnlineStoreDeprecated202007DeleteThemesParamThemeAssetsRequest<'a> {
+    inner: crate::online_store::OnlineStoreDeprecated202007DeleteThemesParamThemeAssetsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OnlineStoreDeprecated202007DeleteThemesParamThemeAssetsRequest<'a> {
+    /// Sets the `asset[key]` query parameter.
+    pub fn asset_key(mut self, asset_key: &'a str) -> Self {
+        self.inner = self.inner.asset_key(asset_key);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`OnlineStore::get_themes_param_theme_asset`], set with a builder.
+pub struct OnlineStoreGetThemesParamThemeAssetRequest<'a> {
+    inner: crate::online_store::OnlineStoreGetThemesParamThemeAssetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OnlineStoreGetThemesParamThemeAssetRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `asset[key]` query parameter.
+    pub fn asset_key(mut self, asset_key: &'a str) -> Self {
+        self.inner = self.inner.asset_key(asset_key);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`OnlineStore::update_themes_param_theme_assets`], set with a builder.
+pub struct OnlineStoreUpdateThemesParamThemeAssetsRequest<'a> {
+    inner: crate::online_store::OnlineStoreUpdateThemesParamThemeAssetsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OnlineStoreUpdateThemesParamThemeAssetsRequest<'a> {
+    /// Sets the `src` query parameter.
+    pub fn src(mut self, src: &'a str) -> Self {
+        self.inner = self.inner.src(src);
+        self
+    }
+
+    /// Sets the `source_key` query parameter.
+    pub fn source_key(mut self, source_key: &'a str) -> Self {
+        self.inner = self.inner.source_key(source_key);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`OnlineStore::delete_themes_param_theme_assets`], set with a builder.
+pub struct OnlineStoreDeleteThemesParamThemeAssetsRequest<'a> {
+    inner: crate::online_store::OnlineStoreDeleteThemesParamThemeAssetsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OnlineStoreDeleteThemesParamThemeAssetsRequest<'a> {
+    /// Sets the `asset[key]` query parameter.
+    pub fn asset_key(mut self, asset_key: &'a str) -> Self {
+        self.inner = self.inner.asset_key(asset_key);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202101_get_themes_param_theme_asset`], set with a builder.
+pub struct OnlineStoreDeprecated202101GetThemesParamThemeAssetRequest<'a> {
+    inner: crate::online_store::OnlineStoreDeprecated202101GetThemesParamThemeAssetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OnlineStoreDeprecated202101GetThemesParamThemeAssetRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `asset[key]` query parameter.
+    pub fn asset_key(mut self, asset_key: &'a str) -> Self {
+        self.inner = self.inner.asset_key(asset_key);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202101_update_themes_param_theme_assets`], set with a builder.
+pub struct OnlineStoreDeprecated202101UpdateThemesParamThemeAssetsRequest<'a> {
+    inner: crate::online_store::OnlineStoreDeprecated202101UpdateThemesParamThemeAssetsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OnlineStoreDeprecated202101UpdateThemesParamThemeAssetsRequest<'a> {
+    /// Sets the `src` query parameter.
+    pub fn src(mut self, src: &'a str) -> Self {
+        self.inner = self.inner.src(src);
+        self
+    }
+
+    /// Sets the `source_key` query parameter.
+    pub fn source_key(mut self, source_key: &'a str) -> Self {
+        self.inner = self.inner.source_key(source_key);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202101_delete_themes_param_theme_assets`], set with a builder.
+pub struct OnlineStoreDeprecated202101DeleteThemesParamThemeAssetsRequest<'a> {
+    inner: crate::online_store::OnlineStoreDeprecated202101DeleteThemesParamThemeAssetsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OnlineStoreDeprecated202101DeleteThemesParamThemeAssetsRequest<'a> {
+    /// Sets the `asset[key]` query parameter.
+    pub fn asset_key(mut self, asset_key: &'a str) -> Self {
+        self.inner = self.inner.asset_key(asset_key);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_unstable_get_themes_param_theme_asset`], set with a builder.
+pub struct OnlineStoreDeprecatedUnstableGetThemesParamThemeAssetRequest<'a> {
+    inner: crate::online_store::OnlineStoreDeprecatedUnstableGetThemesParamThemeAssetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OnlineStoreDeprecatedUnstableGetThemesParamThemeAssetRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `asset[key]` query parameter.
+    pub fn asset_key(mut self, asset_key: &'a str) -> Self {
+        self.inner = self.inner.asset_key(asset_key);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_unstable_update_themes_param_theme_assets`], set with a builder.
+pub struct OnlineStoreDeprecatedUnstableUpdateThemesParamThemeAssetsRequest<'a> {
+    inner:
+        crate::online_store::OnlineStoreDeprecatedUnstableUpdateThemesParamThemeAssetsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OnlineStoreDeprecatedUnstableUpdateThemesParamThemeAssetsRequest<'a> {
+    /// Sets the `src` query parameter.
+    pub fn src(mut self, src: &'a str) -> Self {
+        self.inner = self.inner.src(src);
+        self
+    }
+
+    /// Sets the `source_key` query parameter.
+    pub fn source_key(mut self, source_key: &'a str) -> Self {
+        self.inner = self.inner.source_key(source_key);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_unstable_delete_themes_param_theme_assets`], set with a builder.
+pub struct OnlineStoreDeprecatedUnstableDeleteThemesParamThemeAssetsRequest<'a> {
+    inner:
+        crate::online_store::OnlineStoreDeprecatedUnstableDeleteThemesParamThemeAssetsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OnlineStoreDeprecatedUnstableDeleteThemesParamThemeAssetsRequest<'a> {
+    /// Sets the `asset[key]` query parameter.
+    pub fn asset_key(mut self, asset_key: &'a str) -> Self {
+        self.inner = self.inner.asset_key(asset_key);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202001_get_blog`], set with a builder.
+pub struct OnlineStoreDeprecated202001GetBlogRequest<'a> {
+    inner: crate::online_store::OnlineStoreDeprecated202001GetBlogRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OnlineStoreDeprecated202001GetBlogRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `handle` query parameter.
+    pub fn handle(mut self, handle: &'a str) -> Self {
+        self.inner = self.inner.handle(handle);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202001_get_blogs_param_blog`], set with a builder.
+pub struct OnlineStoreDeprecated202001GetBlogsParamBlogRequest<'a> {
+    inner: crate::online_store::OnlineStoreDeprecated202001GetBlogsParamBlogRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OnlineStoreDeprecated202001GetBlogsParamBlogRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202001_get_comment`], set with a builder.
+pub struct OnlineStoreDeprecated202001GetCommentRequest<'a> {
+    inner: crate::online_store::OnlineStoreDeprecated202001GetCommentRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OnlineStoreDeprecated202001GetCommentRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.inner = self.inner.created_at_min(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.inner = self.inner.created_at_max(created_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.inner = self.inner.updated_at_min(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.inner = self.inner.updated_at_max(updated_at_max);
+        self
+    }
+
+    /// Sets the `published_at_min` query parameter.
+    pub fn published_at_min(mut self, published_at_min: &'a str) -> Self {
+        self.inner = self.inner.published_at_min(published_at_min);
+        self
+    }
+
+    /// Sets the `published_at_max` query parameter.
+    pub fn published_at_max(mut self, published_at_max: &'a str) -> Self {
+        self.inner = self.inner.published_at_max(published_at_max);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `published_status` query parameter.
+    pub fn published_status(mut self, published_status: &'a str) -> Self {
+        self.inner = self.inner.published_status(published_status);
+        self
+    }
+
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: &'a str) -> Self {
+        self.inner = self.inner.status(status);
+        self
+    }
+
+    /// Sets the `article_id` query parameter.
+    pub fn article_id(mut self, article_id: i64) -> Self {
+        self.inner = self.inner.article_id(article_id);
+        self
+    }
+
+    /// Sets the `blog_id` query parameter.
+    pub fn blog_id(mut self, blog_id: i64) -> Self {
+        self.inner = self.inner.blog_id(blog_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202001_get_comments_count`], set with a builder.
+pub struct OnlineStoreDeprecated202001GetCommentsCountRequest<'a> {
+    inner: crate::online_store::OnlineStoreDeprecated202001GetCommentsCountRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OnlineStoreDeprecated202001GetCommentsCountRequest<'a> {
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.inner = self.inner.created_at_min(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.inner = self.inner.created_at_max(created_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.inner = self.inner.updated_at_min(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.inner = self.inner.updated_at_max(updated_at_max);
+        self
+    }
+
+    /// Sets the `published_at_min` query parameter.
+    pub fn published_at_min(mut self, published_at_min: &'a str) -> Self {
+        self.inner = self.inner.published_at_min(published_at_min);
+        self
+    }
+
+    /// Sets the `published_at_max` query parameter.
+    pub fn published_at_max(mut self, published_at_max: &'a str) -> Self {
+        self.inner = self.inner.published_at_max(published_at_max);
+        self
+    }
+
+    /// Sets the `published_status` query parameter.
+    pub fn published_status(mut self, published_status: &'a str) -> Self {
+        self.inner = self.inner.published_status(published_status);
+        self
+    }
+
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: &'a str) -> Self {
+        self.inner = self.inner.status(status);
+        self
+    }
+
+    /// Sets the `article_id` query parameter.
+    pub fn article_id(mut self, article_id: i64) -> Self {
+        self.inner = self.inner.article_id(article_id);
+        self
+    }
+
+    /// Sets the `blog_id` query parameter.
+    pub fn blog_id(mut self, blog_id: i64) -> Self {
+        self.inner = self.inner.blog_id(blog_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202001_get_comments_param_comment`], set with a builder.
+pub struct OnlineStoreDeprecated202001GetCommentsParamCommentRequest<'a> {
+    inner: crate::online_store::OnlineStoreDeprecated202001GetCommentsParamCommentRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OnlineStoreDeprecated202001GetCommentsParamCommentRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202001_get_redirect`], set with a builder.
+pub struct OnlineStoreDeprecated202001GetRedirectRequest<'a> {
+    inner: crate::online_store::OnlineStoreDeprecated202001GetRedirectRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OnlineStoreDeprecated202001GetRedirectRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `path` query parameter.
+    pub fn path(mut self, path: &'a str) -> Self {
+        self.inner = self.inner.path(path);
+        self
+    }
+
+    /// Sets the `target` query parameter.
+    pub fn target(mut self, target: &'a str) -> Self {
+        self.inner = self.inner.target(target);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202001_get_redirects_count`], set with a builder.
+pub struct OnlineStoreDeprecated202001GetRedirectsCountRequest<'a> {
+    inner: crate::online_store::OnlineStoreDeprecated202001GetRedirectsCountRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OnlineStoreDeprecated202001GetRedirectsCountRequest<'a> {
+    /// Sets the `path` query parameter.
+    pub fn path(mut self, path: &'a str) -> Self {
+        self.inner = self.inner.path(path);
+        self
+    }
+
+    /// Sets the `target` query parameter.
+    pub fn target(mut self, target: &'a str) -> Self {
+        self.inner = self.inner.target(target);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202001_get_redirects_param_redirect`], set with a builder.
+pub struct OnlineStoreDeprecated202001GetRedirectsParamRedirectRequest<'a> {
+    inner: crate::online_store::OnlineStoreDeprecated202001GetRedirectsParamRedirectRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OnlineStoreDeprecated202001GetRedirectsParamRedirectRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202004_get_redirect`], set with a builder.
+pub struct OnlineStoreDeprecated202004GetRedirectRequest<'a> {
+    inner: crate::online_store::OnlineStoreDeprecated202004GetRedirectRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OnlineStoreDeprecated202004GetRedirectRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `path` query parameter.
+    pub fn path(mut self, path: &'a str) -> Self {
+        self.inner = self.inner.path(path);
+        self
+    }
+
+    /// Sets the `target` query parameter.
+    pub fn target(mut self, target: &'a str) -> Self {
+        self.inner = self.inner.target(target);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202004_get_redirects_count`], set with a builder.
+pub struct OnlineStoreDeprecated202004GetRedirectsCountRequest<'a> {
+    inner: crate::online_store::OnlineStoreDeprecated202004GetRedirectsCountRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OnlineStoreDeprecated202004GetRedirectsCountRequest<'a> {
+    /// Sets the `path` query parameter.
+    pub fn path(mut self, path: &'a str) -> Self {
+        self.inner = self.inner.path(path);
+        self
+    }
+
+    /// Sets the `target` query parameter.
+    pub fn target(mut self, target: &'a str) -> Self {
+        self.inner = self.inner.target(target);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202004_get_redirects_param_redirect`], set with a builder.
+pub struct OnlineStoreDeprecated202004GetRedirectsParamRedirectRequest<'a> {
+    inner: crate::online_store::OnlineStoreDeprecated202004GetRedirectsParamRedirectRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OnlineStoreDeprecated202004GetRedirectsParamRedirectRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202007_get_redirect`], set with a builder.
+pub struct OnlineStoreDeprecated202007GetRedirectRequest<'a> {
+    inner: crate::online_store::OnlineStoreDeprecated202007GetRedirectRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OnlineStoreDeprecated202007GetRedirectRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `path` query parameter.
+    pub fn path(mut self, path: &'a str) -> Self {
+        self.inner = self.inner.path(path);
+        self
+    }
+
+    /// Sets the `target` query parameter.
+    pub fn target(mut self, target: &'a str) -> Self {
+        self.inner = self.inner.target(target);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202007_get_redirects_count`], set with a builder.
+pub struct OnlineStoreDeprecated202007GetRedirectsCountRequest<'a> {
+    inner: crate::online_store::OnlineStoreDeprecated202007GetRedirectsCountRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OnlineStoreDeprecated202007GetRedirectsCountRequest<'a> {
+    /// Sets the `path` query parameter.
+    pub fn path(mut self, path: &'a str) -> Self {
+        self.inner = self.inner.path(path);
+        self
+    }
+
+    /// Sets the `target` query parameter.
+    pub fn target(mut self, target: &'a str) -> Self {
+        self.inner = self.inner.target(target);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202007_get_redirects_param_redirect`], set with a builder.
+pub struct OnlineStoreDeprecated202007GetRedirectsParamRedirectRequest<'a> {
+    inner: crate::online_store::OnlineStoreDeprecated202007GetRedirectsParamRedirectRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OnlineStoreDeprecated202007GetRedirectsParamRedirectRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`OnlineStore::get_redirect`], set with a builder.
+pub struct OnlineStoreGetRedirectRequest<'a> {
+    inner: crate::online_store::OnlineStoreGetRedirectRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OnlineStoreGetRedirectRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `path` query parameter.
+    pub fn path(mut self, path: &'a str) -> Self {
+        self.inner = self.inner.path(path);
+        self
+    }
+
+    /// Sets the `target` query parameter.
+    pub fn target(mut self, target: &'a str) -> Self {
+        self.inner = self.inner.target(target);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`OnlineStore::get_redirects_count`], set with a builder.
+pub struct OnlineStoreGetRedirectsCountRequest<'a> {
+    inner: crate::online_store::OnlineStoreGetRedirectsCountRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OnlineStoreGetRedirectsCountRequest<'a> {
+    /// Sets the `path` query parameter.
+    pub fn path(mut self, path: &'a str) -> Self {
+        self.inner = self.inner.path(path);
+        self
+    }
+
+    /// Sets the `target` query parameter.
+    pub fn target(mut self, target: &'a str) -> Self {
+        self.inner = self.inner.target(target);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`OnlineStore::get_redirects_param_redirect`], set with a builder.
+pub struct OnlineStoreGetRedirectsParamRedirectRequest<'a> {
+    inner: crate::online_store::OnlineStoreGetRedirectsParamRedirectRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OnlineStoreGetRedirectsParamRedirectRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202101_get_redirect`], set with a builder.
+pub struct OnlineStoreDeprecated202101GetRedirectRequest<'a> {
+    inner: crate::online_store::OnlineStoreDeprecated202101GetRedirectRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OnlineStoreDeprecated202101GetRedirectRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `path` query parameter.
+    pub fn path(mut self, path: &'a str) -> Self {
+        self.inner = self.inner.path(path);
+        self
+    }
+
+    /// Sets the `target` query parameter.
+    pub fn target(mut self, target: &'a str) -> Self {
+        self.inner = self.inner.target(target);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202101_get_redirects_count`], set with a builder.
+pub struct OnlineStoreDeprecated202101GetRedirectsCountRequest<'a> {
+    inner: crate::online_store::OnlineStoreDeprecated202101GetRedirectsCountRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OnlineStoreDeprecated202101GetRedirectsCountRequest<'a> {
+    /// Sets the `path` query parameter.
+    pub fn path(mut self, path: &'a str) -> Self {
+        self.inner = self.inner.path(path);
+        self
+    }
+
+    /// Sets the `target` query parameter.
+    pub fn target(mut self, target: &'a str) -> Self {
+        self.inner = self.inner.target(target);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202101_get_redirects_param_redirect`], set with a builder.
+pub struct OnlineStoreDeprecated202101GetRedirectsParamRedirectRequest<'a> {
+    inner: crate::online_store::OnlineStoreDeprecated202101GetRedirectsParamRedirectRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OnlineStoreDeprecated202101GetRedirectsParamRedirectRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_unstable_get_redirect`], set with a builder.
+pub struct OnlineStoreDeprecatedUnstableGetRedirectRequest<'a> {
+    inner: crate::online_store::OnlineStoreDeprecatedUnstableGetRedirectRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OnlineStoreDeprecatedUnstableGetRedirectRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `path` query parameter.
+    pub fn path(mut self, path: &'a str) -> Self {
+        self.inner = self.inner.path(path);
+        self
+    }
+
+    /// Sets the `target` query parameter.
+    pub fn target(mut self, target: &'a str) -> Self {
+        self.inner = self.inner.target(target);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_unstable_get_redirects_count`], set with a builder.
+pub struct OnlineStoreDeprecatedUnstableGetRedirectsCountRequest<'a> {
+    inner: crate::online_store::OnlineStoreDeprecatedUnstableGetRedirectsCountRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OnlineStoreDeprecatedUnstableGetRedirectsCountRequest<'a> {
+    /// Sets the `path` query parameter.
+    pub fn path(mut self, path: &'a str) -> Self {
+        self.inner = self.inner.path(path);
+        self
+    }
+
+    /// Sets the `target` query parameter.
+    pub fn target(mut self, target: &'a str) -> Self {
+        self.inner = self.inner.target(target);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_unstable_get_redirects_param_redirect`], set with a builder.
+pub struct OnlineStoreDeprecatedUnstableGetRedirectsParamRedirectRequest<'a> {
+    inner: crate::online_store::OnlineStoreDeprecatedUnstableGetRedirectsParamRedirectRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OnlineStoreDeprecatedUnstableGetRedirectsParamRedirectRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202001_get_script_tag`], set with a builder.
+pub struct OnlineStoreDeprecated202001GetScriptTagRequest<'a> {
+    inner: crate::online_store::OnlineStoreDeprecated202001GetScriptTagRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OnlineStoreDeprecated202001GetScriptTagRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.inner = self.inner.created_at_min(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.inner = self.inner.created_at_max(created_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.inner = self.inner.updated_at_min(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.inner = self.inner.updated_at_max(updated_at_max);
+        self
+    }
+
+    /// Sets the `src` query parameter.
+    pub fn src(mut self, src: &'a str) -> Self {
+        self.inner = self.inner.src(src);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202001_get_script_tags_count`], set with a builder.
+pub struct OnlineStoreDeprecated202001GetScriptTagsCountRequest<'a> {
+    inner: crate::online_store::OnlineStoreDeprecated202001GetScriptTagsCountRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OnlineStoreDeprecated202001GetScriptTagsCountRequest<'a> {
+    /// Sets the `src` query parameter.
+    pub fn src(mut self, src: &'a str) -> Self {
+        self.inner = self.inner.src(src);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202001_get_script_tags_param_tag`], set with a builder.
+pub struct OnlineStoreDeprecated202001GetScriptTagsParamTagRequest<'a> {
+    inner: crate::online_store::OnlineStoreDeprecated202001GetScriptTagsParamTagRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OnlineStoreDeprecated202001GetScriptTagsParamTagRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202004_get_script_tag`], set with a builder.
+pub struct OnlineStoreDeprecated202004GetScriptTagRequest<'a> {
+    inner: crate::online_store::OnlineStoreDeprecated202004GetScriptTagRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OnlineStoreDeprecated202004GetScriptTagRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.inner = self.inner.created_at_min(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.inner = self.inner.created_at_max(created_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.inner = self.inner.updated_at_min(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.inner = self.inner.updated_at_max(updated_at_max);
+        self
+    }
+
+    /// Sets the `src` query parameter.
+    pub fn src(mut self, src: &'a str) -> Self {
+        self.inner = self.inner.src(src);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202004_get_script_tags_count`], set with a builder.
+pub struct OnlineStoreDeprecated202004GetScriptTagsCountRequest<'a> {
+    inner: crate::online_store::OnlineStoreDeprecated202004GetScriptTagsCountRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OnlineStoreDeprecated202004GetScriptTagsCountRequest<'a> {
+    /// Sets the `src` query parameter.
+    pub fn src(mut self, src: &'a str) -> Self {
+        self.inner = self.inner.src(src);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202004_get_script_tags_param_tag`], set with a builder.
+pub struct OnlineStoreDeprecated202004GetScriptTagsParamTagRequest<'a> {
+    inner: crate::online_store::OnlineStoreDeprecated202004GetScriptTagsParamTagRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OnlineStoreDeprecated202004GetScriptTagsParamTagRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202007_get_script_tag`], set with a builder.
+pub struct OnlineStoreDeprecated202007GetScriptTagRequest<'a> {
+    inner: crate::online_store::OnlineStoreDeprecated202007GetScriptTagRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OnlineStoreDeprecated202007GetScriptTagRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.inner = self.inner.created_at_min(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.inner = self.inner.created_at_max(created_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.inner = self.inner.updated_at_min(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.inner = self.inner.updated_at_max(updated_at_max);
+        self
+    }
+
+    /// Sets the `src` query parameter.
+    pub fn src(mut self, src: &'a str) -> Self {
+        self.inner = self.inner.src(src);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202007_get_script_tags_count`], set with a builder.
+pub struct OnlineStoreDeprecated202007GetScriptTagsCountRequest<'a> {
+    inner: crate::online_store::OnlineStoreDeprecated202007GetScriptTagsCountRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OnlineStoreDeprecated202007GetScriptTagsCountRequest<'a> {
+    /// Sets the `src` query parameter.
+    pub fn src(mut self, src: &'a str) -> Self {
+        self.inner = self.inner.src(src);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202007_get_script_tags_param_tag`], set with a builder.
+pub struct OnlineStoreDeprecated202007GetScriptTagsParamTagRequest<'a> {
+    inner: crate::online_store::OnlineStoreDeprecated202007GetScriptTagsParamTagRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OnlineStoreDeprecated202007GetScriptTagsParamTagRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`OnlineStore::get_script_tag`], set with a builder.
+pub struct OnlineStoreGetScriptTagRequest<'a> {
+    inner: crate::online_store::OnlineStoreGetScriptTagRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OnlineStoreGetScriptTagRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.inner = self.inner.created_at_min(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.inner = self.inner.created_at_max(created_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.inner = self.inner.updated_at_min(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.inner = self.inner.updated_at_max(updated_at_max);
+        self
+    }
+
+    /// Sets the `src` query parameter.
+    pub fn src(mut self, src: &'a str) -> Self {
+        self.inner = self.inner.src(src);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`OnlineStore::get_script_tags_count`], set with a builder.
+pub struct OnlineStoreGetScriptTagsCountRequest<'a> {
+    inner: crate::online_store::OnlineStoreGetScriptTagsCountRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OnlineStoreGetScriptTagsCountRequest<'a> {
+    /// Sets the `src` query parameter.
+    pub fn src(mut self, src: &'a str) -> Self {
+        self.inner = self.inner.src(src);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`OnlineStore::get_script_tags_param_tag`], set with a builder.
+pub struct OnlineStoreGetScriptTagsParamTagRequest<'a> {
+    inner: crate::online_store::OnlineStoreGetScriptTagsParamTagRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OnlineStoreGetScriptTagsParamTagRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202101_get_script_tag`], set with a builder.
+pub struct OnlineStoreDeprecated202101GetScriptTagRequest<'a> {
+    inner: crate::online_store::OnlineStoreDeprecated202101GetScriptTagRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OnlineStoreDeprecated202101GetScriptTagRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.inner = self.inner.created_at_min(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.inner = self.inner.created_at_max(created_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.inner = self.inner.updated_at_min(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.inner = self.inner.updated_at_max(updated_at_max);
+        self
+    }
+
+    /// Sets the `src` query parameter.
+    pub fn src(mut self, src: &'a str) -> Self {
+        self.inner = self.inner.src(src);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202101_get_script_tags_count`], set with a builder.
+pub struct OnlineStoreDeprecated202101GetScriptTagsCountRequest<'a> {
+    inner: crate::online_store::OnlineStoreDeprecated202101GetScriptTagsCountRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OnlineStoreDeprecated202101GetScriptTagsCountRequest<'a> {
+    /// Sets the `src` query parameter.
+    pub fn src(mut self, src: &'a str) -> Self {
+        self.inner = self.inner.src(src);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202101_get_script_tags_param_tag`], set with a builder.
+pub struct OnlineStoreDeprecated202101GetScriptTagsParamTagRequest<'a> {
+    inner: crate::online_store::OnlineStoreDeprecated202101GetScriptTagsParamTagRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OnlineStoreDeprecated202101GetScriptTagsParamTagRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_unstable_get_script_tag`], set with a builder.
+pub struct OnlineStoreDeprecatedUnstableGetScriptTagRequest<'a> {
+    inner: crate::online_store::OnlineStoreDeprecatedUnstableGetScriptTagRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OnlineStoreDeprecatedUnstableGetScriptTagRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.inner = self.inner.created_at_min(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.inner = self.inner.created_at_max(created_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.inner = self.inner.updated_at_min(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.inner = self.inner.updated_at_max(updated_at_max);
+        self
+    }
+
+    /// Sets the `src` query parameter.
+    pub fn src(mut self, src: &'a str) -> Self {
+        self.inner = self.inner.src(src);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_unstable_get_script_tags_count`], set with a builder.
+pub struct OnlineStoreDeprecatedUnstableGetScriptTagsCountRequest<'a> {
+    inner: crate::online_store::OnlineStoreDeprecatedUnstableGetScriptTagsCountRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OnlineStoreDeprecatedUnstableGetScriptTagsCountRequest<'a> {
+    /// Sets the `src` query parameter.
+    pub fn src(mut self, src: &'a str) -> Self {
+        self.inner = self.inner.src(src);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_unstable_get_script_tags_param_tag`], set with a builder.
+pub struct OnlineStoreDeprecatedUnstableGetScriptTagsParamTagRequest<'a> {
+    inner: crate::online_store::OnlineStoreDeprecatedUnstableGetScriptTagsParamTagRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OnlineStoreDeprecatedUnstableGetScriptTagsParamTagRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202001_get_theme`], set with a builder.
+pub struct OnlineStoreDeprecated202001GetThemeRequest<'a> {
+    inner: crate::online_store::OnlineStoreDeprecated202001GetThemeRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OnlineStoreDeprecated202001GetThemeRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202001_get_themes_param_theme`], set with a builder.
+pub struct OnlineStoreDeprecated202001GetThemesParamThemeRequest<'a> {
+    inner: crate::online_store::OnlineStoreDeprecated202001GetThemesParamThemeRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OnlineStoreDeprecated202001GetThemesParamThemeRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202004_get_theme`], set with a builder.
+pub struct OnlineStoreDeprecated202004GetThemeRequest<'a> {
+    inner: crate::online_store::OnlineStoreDeprecated202004GetThemeRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OnlineStoreDeprecated202004GetThemeRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202004_get_themes_param_theme`], set with a builder.
+pub struct OnlineStoreDeprecated202004GetThemesParamThemeRequest<'a> {
+    inner: crate::online_store::OnlineStoreDeprecated202004GetThemesParamThemeRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OnlineStoreDeprecated202004GetThemesParamThemeRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202007_get_theme`], set with a builder.
+pub struct OnlineStoreDeprecated202007GetThemeRequest<'a> {
+    inner: crate::online_store::OnlineStoreDeprecated202007GetThemeRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OnlineStoreDeprecated202007GetThemeRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202007_get_themes_param_theme`], set with a builder.
+pub struct OnlineStoreDeprecated202007GetThemesParamThemeRequest<'a> {
+    inner: crate::online_store::OnlineStoreDeprecated202007GetThemesParamThemeRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OnlineStoreDeprecated202007GetThemesParamThemeRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`OnlineStore::get_theme`], set with a builder.
+pub struct OnlineStoreGetThemeRequest<'a> {
+    inner: crate::online_store::OnlineStoreGetThemeRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OnlineStoreGetThemeRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`OnlineStore::get_themes_param_theme`], set with a builder.
+pub struct OnlineStoreGetThemesParamThemeRequest<'a> {
+    inner: crate::online_store::OnlineStoreGetThemesParamThemeRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OnlineStoreGetThemesParamThemeRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202101_get_theme`], set with a builder.
+pub struct OnlineStoreDeprecated202101GetThemeRequest<'a> {
+    inner: crate::online_store::OnlineStoreDeprecated202101GetThemeRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OnlineStoreDeprecated202101GetThemeRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202101_get_themes_param_theme`], set with a builder.
+pub struct OnlineStoreDeprecated202101GetThemesParamThemeRequest<'a> {
+    inner: crate::online_store::OnlineStoreDeprecated202101GetThemesParamThemeRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OnlineStoreDeprecated202101GetThemesParamThemeRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_unstable_get_theme`], set with a builder.
+pub struct OnlineStoreDeprecatedUnstableGetThemeRequest<'a> {
+    inner: crate::online_store::OnlineStoreDeprecatedUnstableGetThemeRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OnlineStoreDeprecatedUnstableGetThemeRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_unstable_get_themes_param_theme`], set with a builder.
+pub struct OnlineStoreDeprecatedUnstableGetThemesParamThemeRequest<'a> {
+    inner: crate::online_store::OnlineStoreDeprecatedUnstableGetThemesParamThemeRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OnlineStoreDeprecatedUnstableGetThemesParamThemeRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::online_store::OnlineStore`].
+pub struct OnlineStore {
+    inner: crate::online_store::OnlineStore,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl OnlineStore {
+    pub(crate) fn new(
+        inner: crate::online_store::OnlineStore,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        OnlineStore { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202001_get_blogs_param_blog_article`].
+    pub fn deprecated_202001_get_blogs_param_blog_article(
+        &self,
+        blog_id: &str,
+        limit: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+        handle: &str,
+        tag: &str,
+        author: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202001_get_blogs_param_blog_article(
+                blog_id,
+                limit,
+                since_id,
+                created_at_min,
+                created_at_max,
+                updated_at_min,
+                updated_at_max,
+                published_at_min,
+                published_at_max,
+                published_status,
+                handle,
+                tag,
+                author,
+                fields,
+            ))
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202001_get_blogs_param_blog_article_request`].
+    pub fn deprecated_202001_get_blogs_param_blog_article_request<'a>(
+        &'a self,
+        blog_id: &'a str,
+    ) -> OnlineStoreDeprecated202001GetBlogsParamBlogArticleRequest<'a> {
+        OnlineStoreDeprecated202001GetBlogsParamBlogArticleRequest {
+            inner: self
+                .inner
+                .deprecated_202001_get_blogs_param_blog_article_request(blog_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202001_create_blogs_param_blog_articles`].
+    pub fn deprecated_202001_create_blogs_param_blog_articles(
+        &self,
+        blog_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_create_blogs_param_blog_articles(blog_id, body),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202001_get_blogs_param_blog_articles_count`].
+    pub fn deprecated_202001_get_blogs_param_blog_articles_count(
+        &self,
+        blog_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_get_blogs_param_blog_articles_count(
+                    blog_id,
+                    created_at_min,
+                    created_at_max,
+                    updated_at_min,
+                    updated_at_max,
+                    published_at_min,
+                    published_at_max,
+                    published_status,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202001_get_blogs_param_blog_articles_count_request`].
+    pub fn deprecated_202001_get_blogs_param_blog_articles_count_request<'a>(
+        &'a self,
+        blog_id: &'a str,
+    ) -> OnlineStoreDeprecated202001GetBlogsParamBlogArticlesCountRequest<'a> {
+        OnlineStoreDeprecated202001GetBlogsParamBlogArticlesCountRequest {
+            inner: self
+                .inner
+                .deprecated_202001_get_blogs_param_blog_articles_count_request(blog_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202001_get_blogs_param_blog_articles_article`].
+    pub fn deprecated_202001_get_blogs_param_blog_articles_article(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_get_blogs_param_blog_articles_article(
+                    blog_id, article_id, fields,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202001_get_blogs_param_blog_articles_article_request`].
+    pub fn deprecated_202001_get_blogs_param_blog_articles_article_request<'a>(
+        &'a self,
+        blog_id: &'a str,
+        article_id: &'a str,
+    ) -> OnlineStoreDeprecated202001GetBlogsParamBlogArticlesArticleRequest<'a> {
+        OnlineStoreDeprecated202001GetBlogsParamBlogArticlesArticleRequest {
+            inner: self
+                .inner
+                .deprecated_202001_get_blogs_param_blog_articles_article_request(
+                    blog_id, article_id,
+                ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202001_update_blogs_param_blog_articles_article`].
+    pub fn deprecated_202001_update_blogs_param_blog_articles_article(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_update_blogs_param_blog_articles_article(
+                    blog_id, article_id, body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202001_delete_blogs_param_blog_articles_article`].
+    pub fn deprecated_202001_delete_blogs_param_blog_articles_article(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_delete_blogs_param_blog_articles_article(blog_id, article_id),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202001_get_articles_author`].
+    pub fn deprecated_202001_get_articles_author(&self) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202001_get_articles_author())
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202001_get_articles_tag`].
+    pub fn deprecated_202001_get_articles_tag(
+        &self,
+        limit: &str,
+        popular: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_get_articles_tag(limit, popular),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202001_get_articles_tag_request`].
+    pub fn deprecated_202001_get_articles_tag_request(
+        &self,
+    ) -> OnlineStoreDeprecated202001GetArticlesTagRequest<'_> {
+        OnlineStoreDeprecated202001GetArticlesTagRequest {
+            inner: self.inner.deprecated_202001_get_articles_tag_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202004_get_blogs_param_blog_article`].
+    pub fn deprecated_202004_get_blogs_param_blog_article(
+        &self,
+        blog_id: &str,
+        limit: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+        handle: &str,
+        tag: &str,
+        author: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202004_get_blogs_param_blog_article(
+                blog_id,
+                limit,
+                since_id,
+                created_at_min,
+                created_at_max,
+                updated_at_min,
+                updated_at_max,
+                published_at_min,
+                published_at_max,
+                published_status,
+                handle,
+                tag,
+                author,
+                fields,
+            ))
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202004_get_blogs_param_blog_article_request`].
+    pub fn deprecated_202004_get_blogs_param_blog_article_request<'a>(
+        &'a self,
+        blog_id: &'a str,
+    ) -> OnlineStoreDeprecated202004GetBlogsParamBlogArticleRequest<'a> {
+        OnlineStoreDeprecated202004GetBlogsParamBlogArticleRequest {
+            inner: self
+                .inner
+                .deprecated_202004_get_blogs_param_blog_article_request(blog_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202004_create_blogs_param_blog_articles`].
+    pub fn deprecated_202004_create_blogs_param_blog_articles(
+        &self,
+        blog_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_create_blogs_param_blog_articles(blog_id, body),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202004_get_blogs_param_blog_articles_count`].
+    pub fn deprecated_202004_get_blogs_param_blog_articles_count(
+        &self,
+        blog_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_get_blogs_param_blog_articles_count(
+                    blog_id,
+                    created_at_min,
+                    created_at_max,
+                    updated_at_min,
+                    updated_at_max,
+                    published_at_min,
+                    published_at_max,
+                    published_status,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202004_get_blogs_param_blog_articles_count_request`].
+    pub fn deprecated_202004_get_blogs_param_blog_articles_count_request<'a>(
+        &'a self,
+        blog_id: &'a str,
+    ) -> OnlineStoreDeprecated202004GetBlogsParamBlogArticlesCountRequest<'a> {
+        OnlineStoreDeprecated202004GetBlogsParamBlogArticlesCountRequest {
+            inner: self
+                .inner
+                .deprecated_202004_get_blogs_param_blog_articles_count_request(blog_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202004_get_blogs_param_blog_articles_article`].
+    pub fn deprecated_202004_get_blogs_param_blog_articles_article(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_get_blogs_param_blog_articles_article(
+                    blog_id, article_id, fields,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202004_get_blogs_param_blog_articles_article_request`].
+    pub fn deprecated_202004_get_blogs_param_blog_articles_article_request<'a>(
+        &'a self,
+        blog_id: &'a str,
+        article_id: &'a str,
+    ) -> OnlineStoreDeprecated202004GetBlogsParamBlogArticlesArticleRequest<'a> {
+        OnlineStoreDeprecated202004GetBlogsParamBlogArticlesArticleRequest {
+            inner: self
+                .inner
+                .deprecated_202004_get_blogs_param_blog_articles_article_request(
+                    blog_id, article_id,
+                ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202004_update_blogs_param_blog_articles_article`].
+    pub fn deprecated_202004_update_blogs_param_blog_articles_article(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_update_blogs_param_blog_articles_article(
+                    blog_id, article_id, body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202004_delete_blogs_param_blog_articles_article`].
+    pub fn deprecated_202004_delete_blogs_param_blog_articles_article(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_delete_blogs_param_blog_articles_article(blog_id, article_id),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202004_get_articles_author`].
+    pub fn deprecated_202004_get_articles_author(&self) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202004_get_articles_author())
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202004_get_articles_tag`].
+    pub fn deprecated_202004_get_articles_tag(
+        &self,
+        limit: &str,
+        popular: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_get_articles_tag(limit, popular),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202004_get_articles_tag_request`].
+    pub fn deprecated_202004_get_articles_tag_request(
+        &self,
+    ) -> OnlineStoreDeprecated202004GetArticlesTagRequest<'_> {
+        OnlineStoreDeprecated202004GetArticlesTagRequest {
+            inner: self.inner.deprecated_202004_get_articles_tag_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202007_get_blogs_param_blog_article`].
+    pub fn deprecated_202007_get_blogs_param_blog_article(
+        &self,
+        blog_id: &str,
+        limit: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+        handle: &str,
+        tag: &str,
+        author: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202007_get_blogs_param_blog_article(
+                blog_id,
+                limit,
+                since_id,
+                created_at_min,
+                created_at_max,
+                updated_at_min,
+                updated_at_max,
+                published_at_min,
+                published_at_max,
+                published_status,
+                handle,
+                tag,
+                author,
+                fields,
+            ))
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202007_get_blogs_param_blog_article_request`].
+    pub fn deprecated_202007_get_blogs_param_blog_article_request<'a>(
+        &'a self,
+        blog_id: &'a str,
+    ) -> OnlineStoreDeprecated202007GetBlogsParamBlogArticleRequest<'a> {
+        OnlineStoreDeprecated202007GetBlogsParamBlogArticleRequest {
+            inner: self
+                .inner
+                .deprecated_202007_get_blogs_param_blog_article_request(blog_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202007_create_blogs_param_blog_articles`].
+    pub fn deprecated_202007_create_blogs_param_blog_articles(
+        &self,
+        blog_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_create_blogs_param_blog_articles(blog_id, body),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202007_get_blogs_param_blog_articles_count`].
+    pub fn deprecated_202007_get_blogs_param_blog_articles_count(
+        &self,
+        blog_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_get_blogs_param_blog_articles_count(
+                    blog_id,
+                    created_at_min,
+                    created_at_max,
+                    updated_at_min,
+                    updated_at_max,
+                    published_at_min,
+                    published_at_max,
+                    published_status,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202007_get_blogs_param_blog_articles_count_request`].
+    pub fn deprecated_202007_get_blogs_param_blog_articles_count_request<'a>(
+        &'a self,
+        blog_id: &'a str,
+    ) -> OnlineStoreDeprecated202007GetBlogsParamBlogArticlesCountRequest<'a> {
+        OnlineStoreDeprecated202007GetBlogsParamBlogArticlesCountRequest {
+            inner: self
+                .inner
+                .deprecated_202007_get_blogs_param_blog_articles_count_request(blog_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202007_get_blogs_param_blog_articles_article`].
+    pub fn deprecated_202007_get_blogs_param_blog_articles_article(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_get_blogs_param_blog_articles_article(
+                    blog_id, article_id, fields,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202007_get_blogs_param_blog_articles_article_request`].
+    pub fn deprecated_202007_get_blogs_param_blog_articles_article_request<'a>(
+        &'a self,
+        blog_id: &'a str,
+        article_id: &'a str,
+    ) -> OnlineStoreDeprecated202007GetBlogsParamBlogArticlesArticleRequest<'a> {
+        OnlineStoreDeprecated202007GetBlogsParamBlogArticlesArticleRequest {
+            inner: self
+                .inner
+                .deprecated_202007_get_blogs_param_blog_articles_article_request(
+                    blog_id, article_id,
+                ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202007_update_blogs_param_blog_articles_article`].
+    pub fn deprecated_202007_update_blogs_param_blog_articles_article(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_update_blogs_param_blog_articles_article(
+                    blog_id, article_id, body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202007_delete_blogs_param_blog_articles_article`].
+    pub fn deprecated_202007_delete_blogs_param_blog_articles_article(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_delete_blogs_param_blog_articles_article(blog_id, article_id),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202007_get_articles_author`].
+    pub fn deprecated_202007_get_articles_author(&self) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202007_get_articles_author())
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202007_get_articles_tag`].
+    pub fn deprecated_202007_get_articles_tag(
+        &self,
+        limit: &str,
+        popular: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_get_articles_tag(limit, popular),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202007_get_articles_tag_request`].
+    pub fn deprecated_202007_get_articles_tag_request(
+        &self,
+    ) -> OnlineStoreDeprecated202007GetArticlesTagRequest<'_> {
+        OnlineStoreDeprecated202007GetArticlesTagRequest {
+            inner: self.inner.deprecated_202007_get_articles_tag_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::get_blogs_param_blog_article`].
+    pub fn get_blogs_param_blog_article(
+        &self,
+        blog_id: &str,
+        limit: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+        handle: &str,
+        tag: &str,
+        author: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.get_blogs_param_blog_article(
+                blog_id,
+                limit,
+                since_id,
+                created_at_min,
+                created_at_max,
+                updated_at_min,
+                updated_at_max,
+                published_at_min,
+                published_at_max,
+                published_status,
+                handle,
+                tag,
+                author,
+                fields,
+            ))
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::get_blogs_param_blog_article_request`].
+    pub fn get_blogs_param_blog_article_request<'a>(
+        &'a self,
+        blog_id: &'a str,
+    ) -> OnlineStoreGetBlogsParamBlogArticleRequest<'a> {
+        OnlineStoreGetBlogsParamBlogArticleRequest {
+            inner: self.inner.get_blogs_param_blog_article_request(blog_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::create_blogs_param_blog_articles`].
+    pub fn create_blogs_param_blog_articles(
+        &self,
+        blog_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.create_blogs_param_blog_articles(blog_id, body))
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::get_blogs_param_blog_articles_count`].
+    pub fn get_blogs_param_blog_articles_count(
+        &self,
+        blog_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.get_blogs_param_blog_articles_count(
+                blog_id,
+                created_at_min,
+                created_at_max,
+                updated_at_min,
+                updated_at_max,
+                published_at_min,
+                published_at_max,
+                published_status,
+            ))
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::get_blogs_param_blog_articles_count_request`].
+    pub fn get_blogs_param_blog_articles_count_request<'a>(
+        &'a self,
+        blog_id: &'a str,
+    ) -> OnlineStoreGetBlogsParamBlogArticlesCountRequest<'a> {
+        OnlineStoreGetBlogsParamBlogArticlesCountRequest {
+            inner: self
+                .inner
+                .get_blogs_param_blog_articles_count_request(blog_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::get_blogs_param_blog_articles_article`].
+    pub fn get_blogs_param_blog_articles_article(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .get_blogs_param_blog_articles_article(blog_id, article_id, fields),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::get_blogs_param_blog_articles_article_request`].
+    pub fn get_blogs_param_blog_articles_article_request<'a>(
+        &'a self,
+        blog_id: &'a str,
+        article_id: &'a str,
+    ) -> OnlineStoreGetBlogsParamBlogArticlesArticleRequest<'a> {
+        OnlineStoreGetBlogsParamBlogArticlesArticleRequest {
+            inner: self
+                .inner
+                .get_blogs_param_blog_articles_article_request(blog_id, article_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::update_blogs_param_blog_articles_article`].
+    pub fn update_blogs_param_blog_articles_article(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .update_blogs_param_blog_articles_article(blog_id, article_id, body),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::delete_blogs_param_blog_articles_article`].
+    pub fn delete_blogs_param_blog_articles_article(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .delete_blogs_param_blog_articles_article(blog_id, article_id),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::get_articles_author`].
+    pub fn get_articles_author(&self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.get_articles_author())
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::get_articles_tag`].
+    pub fn get_articles_tag(
+        &self,
+        limit: &str,
+        popular: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.get_articles_tag(limit, popular))
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::get_articles_tag_request`].
+    pub fn get_articles_tag_request(&self) -> OnlineStoreGetArticlesTagRequest<'_> {
+        OnlineStoreGetArticlesTagRequest {
+            inner: self.inner.get_articles_tag_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202101_get_blogs_param_blog_article`].
+    pub fn deprecated_202101_get_blogs_param_blog_article(
+        &self,
+        blog_id: &str,
+        limit: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+        handle: &str,
+        tag: &str,
+        author: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202101_get_blogs_param_blog_article(
+                blog_id,
+                limit,
+                since_id,
+                created_at_min,
+                created_at_max,
+                updated_at_min,
+                updated_at_max,
+                published_at_min,
+                published_at_max,
+                published_status,
+                handle,
+                tag,
+                author,
+                fields,
+            ))
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202101_get_blogs_param_blog_article_request`].
+    pub fn deprecated_202101_get_blogs_param_blog_article_request<'a>(
+        &'a self,
+        blog_id: &'a str,
+    ) -> OnlineStoreDeprecated202101GetBlogsParamBlogArticleRequest<'a> {
+        OnlineStoreDeprecated202101GetBlogsParamBlogArticleRequest {
+            inner: self
+                .inner
+                .deprecated_202101_get_blogs_param_blog_article_request(blog_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202101_create_blogs_param_blog_articles`].
+    pub fn deprecated_202101_create_blogs_param_blog_articles(
+        &self,
+        blog_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_create_blogs_param_blog_articles(blog_id, body),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202101_get_blogs_param_blog_articles_count`].
+    pub fn deprecated_202101_get_blogs_param_blog_articles_count(
+        &self,
+        blog_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_get_blogs_param_blog_articles_count(
+                    blog_id,
+                    created_at_min,
+                    created_at_max,
+                    updated_at_min,
+                    updated_at_max,
+                    published_at_min,
+                    published_at_max,
+                    published_status,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202101_get_blogs_param_blog_articles_count_request`].
+    pub fn deprecated_202101_get_blogs_param_blog_articles_count_request<'a>(
+        &'a self,
+        blog_id: &'a str,
+    ) -> OnlineStoreDeprecated202101GetBlogsParamBlogArticlesCountRequest<'a> {
+        OnlineStoreDeprecated202101GetBlogsParamBlogArticlesCountRequest {
+            inner: self
+                .inner
+                .deprecated_202101_get_blogs_param_blog_articles_count_request(blog_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202101_get_blogs_param_blog_articles_article`].
+    pub fn deprecated_202101_get_blogs_param_blog_articles_article(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_get_blogs_param_blog_articles_article(
+                    blog_id, article_id, fields,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202101_get_blogs_param_blog_articles_article_request`].
+    pub fn deprecated_202101_get_blogs_param_blog_articles_article_request<'a>(
+        &'a self,
+        blog_id: &'a str,
+        article_id: &'a str,
+    ) -> OnlineStoreDeprecated202101GetBlogsParamBlogArticlesArticleRequest<'a> {
+        OnlineStoreDeprecated202101GetBlogsParamBlogArticlesArticleRequest {
+            inner: self
+                .inner
+                .deprecated_202101_get_blogs_param_blog_articles_article_request(
+                    blog_id, article_id,
+                ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202101_update_blogs_param_blog_articles_article`].
+    pub fn deprecated_202101_update_blogs_param_blog_articles_article(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_update_blogs_param_blog_articles_article(
+                    blog_id, article_id, body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202101_delete_blogs_param_blog_articles_article`].
+    pub fn deprecated_202101_delete_blogs_param_blog_articles_article(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_delete_blogs_param_blog_articles_article(blog_id, article_id),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202101_get_articles_author`].
+    pub fn deprecated_202101_get_articles_author(&self) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202101_get_articles_author())
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202101_get_articles_tag`].
+    pub fn deprecated_202101_get_articles_tag(
+        &self,
+        limit: &str,
+        popular: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_get_articles_tag(limit, popular),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202101_get_articles_tag_request`].
+    pub fn deprecated_202101_get_articles_tag_request(
+        &self,
+    ) -> OnlineStoreDeprecated202101GetArticlesTagRequest<'_> {
+        OnlineStoreDeprecated202101GetArticlesTagRequest {
+            inner: self.inner.deprecated_202101_get_articles_tag_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_unstable_get_blogs_param_blog_article`].
+    pub fn deprecated_unstable_get_blogs_param_blog_article(
+        &self,
+        blog_id: &str,
+        limit: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+        handle: &str,
+        tag: &str,
+        author: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_unstable_get_blogs_param_blog_article(
+                blog_id,
+                limit,
+                since_id,
+                created_at_min,
+                created_at_max,
+                updated_at_min,
+                updated_at_max,
+                published_at_min,
+                published_at_max,
+                published_status,
+                handle,
+                tag,
+                author,
+                fields,
+            ))
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_unstable_get_blogs_param_blog_article_request`].
+    pub fn deprecated_unstable_get_blogs_param_blog_article_request<'a>(
+        &'a self,
+        blog_id: &'a str,
+    ) -> OnlineStoreDeprecatedUnstableGetBlogsParamBlogArticleRequest<'a> {
+        OnlineStoreDeprecatedUnstableGetBlogsParamBlogArticleRequest {
+            inner: self
+                .inner
+                .deprecated_unstable_get_blogs_param_blog_article_request(blog_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_unstable_create_blogs_param_blog_articles`].
+    pub fn deprecated_unstable_create_blogs_param_blog_articles(
+        &self,
+        blog_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_create_blogs_param_blog_articles(blog_id, body),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_unstable_get_blogs_param_blog_articles_count`].
+    pub fn deprecated_unstable_get_blogs_param_blog_articles_count(
+        &self,
+        blog_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_get_blogs_param_blog_articles_count(
+                    blog_id,
+                    created_at_min,
+                    created_at_max,
+                    updated_at_min,
+                    updated_at_max,
+                    published_at_min,
+                    published_at_max,
+                    published_status,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_unstable_get_blogs_param_blog_articles_count_request`].
+    pub fn deprecated_unstable_get_blogs_param_blog_articles_count_request<'a>(
+        &'a self,
+        blog_id: &'a str,
+    ) -> OnlineStoreDeprecatedUnstableGetBlogsParamBlogArticlesCountRequest<'a> {
+        OnlineStoreDeprecatedUnstableGetBlogsParamBlogArticlesCountRequest {
+            inner: self
+                .inner
+                .deprecated_unstable_get_blogs_param_blog_articles_count_request(blog_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_unstable_get_blogs_param_blog_articles_article`].
+    pub fn deprecated_unstable_get_blogs_param_blog_articles_article(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_get_blogs_param_blog_articles_article(
+                    blog_id, article_id, fields,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_unstable_get_blogs_param_blog_articles_article_request`].
+    pub fn deprecated_unstable_get_blogs_param_blog_articles_article_request<'a>(
+        &'a self,
+        blog_id: &'a str,
+        article_id: &'a str,
+    ) -> OnlineStoreDeprecatedUnstableGetBlogsParamBlogArticlesArticleRequest<'a> {
+        OnlineStoreDeprecatedUnstableGetBlogsParamBlogArticlesArticleRequest {
+            inner: self
+                .inner
+                .deprecated_unstable_get_blogs_param_blog_articles_article_request(
+                    blog_id, article_id,
+                ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_unstable_update_blogs_param_blog_articles_article`].
+    pub fn deprecated_unstable_update_blogs_param_blog_articles_article(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_update_blogs_param_blog_articles_article(
+                    blog_id, article_id, body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_unstable_delete_blogs_param_blog_articles_article`].
+    pub fn deprecated_unstable_delete_blogs_param_blog_articles_article(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_delete_blogs_param_blog_articles_article(blog_id, article_id),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_unstable_get_articles_author`].
+    pub fn deprecated_unstable_get_articles_author(&self) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_unstable_get_articles_author())
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_unstable_get_articles_tag`].
+    pub fn deprecated_unstable_get_articles_tag(
+        &self,
+        limit: &str,
+        popular: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_get_articles_tag(limit, popular),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_unstable_get_articles_tag_request`].
+    pub fn deprecated_unstable_get_articles_tag_request(
+        &self,
+    ) -> OnlineStoreDeprecatedUnstableGetArticlesTagRequest<'_> {
+        OnlineStoreDeprecatedUnstableGetArticlesTagRequest {
+            inner: self.inner.deprecated_unstable_get_articles_tag_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202001_get_themes_param_theme_asset`].
+    pub fn deprecated_202001_get_themes_param_theme_asset(
+        &self,
+        theme_id: &str,
+        fields: &str,
+        asset_key: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_get_themes_param_theme_asset(theme_id, fields, asset_key),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202001_get_themes_param_theme_asset_request`].
+    pub fn deprecated_202001_get_themes_param_theme_asset_request<'a>(
+        &'a self,
+        theme_id: &'a str,
+    ) -> OnlineStoreDeprecated202001GetThemesParamThemeAssetRequest<'a> {
+        OnlineStoreDeprecated202001GetThemesParamThemeAssetRequest {
+            inner: self
+                .inner
+                .deprecated_202001_get_themes_param_theme_asset_request(theme_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202001_update_themes_param_theme_assets`].
+    pub fn deprecated_202001_update_themes_param_theme_assets(
+        &self,
+        theme_id: &str,
+        src: &str,
+        source_key: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_update_themes_param_theme_assets(
+                    theme_id, src, source_key, body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202001_update_themes_param_theme_assets_request`].
+    pub fn deprecated_202001_update_themes_param_theme_assets_request<'a>(
+        &'a self,
+        theme_id: &'a str,
+        body: &'a serde_json::Value,
+    ) -> OnlineStoreDeprecated202001UpdateThemesParamThemeAssetsRequest<'a> {
+        OnlineStoreDeprecated202001UpdateThemesParamThemeAssetsRequest {
+            inner: self
+                .inner
+                .deprecated_202001_update_themes_param_theme_assets_request(theme_id, body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202001_delete_themes_param_theme_assets`].
+    pub fn deprecated_202001_delete_themes_param_theme_assets(
+        &self,
+        theme_id: &str,
+        asset_key: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_delete_themes_param_theme_assets(theme_id, asset_key),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202001_delete_themes_param_theme_assets_request`].
+    pub fn deprecated_202001_delete_themes_param_theme_assets_request<'a>(
+        &'a self,
+        theme_id: &'a str,
+    ) -> OnlineStoreDeprecated202001DeleteThemesParamThemeAssetsRequest<'a> {
+        OnlineStoreDeprecated202001DeleteThemesParamThemeAssetsRequest {
+            inner: self
+                .inner
+                .deprecated_202001_delete_themes_param_theme_assets_request(theme_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202004_get_themes_param_theme_asset`].
+    pub fn deprecated_202004_get_themes_param_theme_asset(
+        &self,
+        theme_id: &str,
+        fields: &str,
+        asset_key: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_get_themes_param_theme_asset(theme_id, fields, asset_key),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202004_get_themes_param_theme_asset_request`].
+    pub fn deprecated_202004_get_themes_param_theme_asset_request<'a>(
+        &'a self,
+        theme_id: &'a str,
+    ) -> OnlineStoreDeprecated202004GetThemesParamThemeAssetRequest<'a> {
+        OnlineStoreDeprecated202004GetThemesParamThemeAssetRequest {
+            inner: self
+                .inner
+                .deprecated_202004_get_themes_param_theme_asset_request(theme_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202004_update_themes_param_theme_assets`].
+    pub fn deprecated_202004_update_themes_param_theme_assets(
+        &self,
+        theme_id: &str,
+        src: &str,
+        source_key: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_update_themes_param_theme_assets(
+                    theme_id, src, source_key, body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202004_update_themes_param_theme_assets_request`].
+    pub fn deprecated_202004_update_themes_param_theme_assets_request<'a>(
+        &'a self,
+        theme_id: &'a str,
+        body: &'a serde_json::Value,
+    ) -> OnlineStoreDeprecated202004UpdateThemesParamThemeAssetsRequest<'a> {
+        OnlineStoreDeprecated202004UpdateThemesParamThemeAssetsRequest {
+            inner: self
+                .inner
+                .deprecated_202004_update_themes_param_theme_assets_request(theme_id, body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202004_delete_themes_param_theme_assets`].
+    pub fn deprecated_202004_delete_themes_param_theme_assets(
+        &self,
+        theme_id: &str,
+        asset_key: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_delete_themes_param_theme_assets(theme_id, asset_key),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202004_delete_themes_param_theme_assets_request`].
+    pub fn deprecated_202004_delete_themes_param_theme_assets_request<'a>(
+        &'a self,
+        theme_id: &'a str,
+    ) -> OnlineStoreDeprecated202004DeleteThemesParamThemeAssetsRequest<'a> {
+        OnlineStoreDeprecated202004DeleteThemesParamThemeAssetsRequest {
+            inner: self
+                .inner
+                .deprecated_202004_delete_themes_param_theme_assets_request(theme_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202007_get_themes_param_theme_asset`].
+    pub fn deprecated_202007_get_themes_param_theme_asset(
+        &self,
+        theme_id: &str,
+        fields: &str,
+        asset_key: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_get_themes_param_theme_asset(theme_id, fields, asset_key),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202007_get_themes_param_theme_asset_request`].
+    pub fn deprecated_202007_get_themes_param_theme_asset_request<'a>(
+        &'a self,
+        theme_id: &'a str,
+    ) -> OnlineStoreDeprecated202007GetThemesParamThemeAssetRequest<'a> {
+        OnlineStoreDeprecated202007GetThemesParamThemeAssetRequest {
+            inner: self
+                .inner
+                .deprecated_202007_get_themes_param_theme_asset_request(theme_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202007_update_themes_param_theme_assets`].
+    pub fn deprecated_202007_update_themes_param_theme_assets(
+        &self,
+        theme_id: &str,
+        src: &str,
+        source_key: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_update_themes_param_theme_assets(
+                    theme_id, src, source_key, body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202007_update_themes_param_theme_assets_request`].
+    pub fn deprecated_202007_update_themes_param_theme_assets_request<'a>(
+        &'a self,
+        theme_id: &'a str,
+        body: &'a serde_json::Value,
+    ) -> OnlineStoreDeprecated202007UpdateThemesParamThemeAssetsRequest<'a> {
+        OnlineStoreDeprecated202007UpdateThemesParamThemeAssetsRequest {
+            inner: self
+                .inner
+                .deprecated_202007_update_themes_param_theme_assets_request(theme_id, body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202007_delete_themes_param_theme_assets`].
+    pub fn deprecated_202007_delete_themes_param_theme_assets(
+        &self,
+        theme_id: &str,
+        asset_key: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_delete_themes_param_theme_assets(theme_id, asset_key),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202007_delete_themes_param_theme_assets_request`].
+    pub fn deprecated_202007_delete_themes_param_theme_assets_request<'a>(
+        &'a self,
+        theme_id: &'a str,
+    ) -> OnlineStoreDeprecated202007DeleteThemesParamThemeAssetsRequest<'a> {
+        OnlineStoreDeprecated202007DeleteThemesParamThemeAssetsRequest {
+            inner: self
+                .inner
+                .deprecated_202007_delete_themes_param_theme_assets_request(theme_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::get_themes_param_theme_asset`].
+    pub fn get_themes_param_theme_asset(
+        &self,
+        theme_id: &str,
+        fields: &str,
+        asset_key: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .get_themes_param_theme_asset(theme_id, fields, asset_key),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::get_themes_param_theme_asset_request`].
+    pub fn get_themes_param_theme_asset_request<'a>(
+        &'a self,
+        theme_id: &'a str,
+    ) -> OnlineStoreGetThemesParamThemeAssetRequest<'a> {
+        OnlineStoreGetThemesParamThemeAssetRequest {
+            inner: self.inner.get_themes_param_theme_asset_request(theme_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::update_themes_param_theme_assets`].
+    pub fn update_themes_param_theme_assets(
+        &self,
+        theme_id: &str,
+        src: &str,
+        source_key: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .update_themes_param_theme_assets(theme_id, src, source_key, body),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::update_themes_param_theme_assets_request`].
+    pub fn update_themes_param_theme_assets_request<'a>(
+        &'a self,
+        theme_id: &'a str,
+        body: &'a serde_json::Value,
+    ) -> OnlineStoreUpdateThemesParamThemeAssetsRequest<'a> {
+        OnlineStoreUpdateThemesParamThemeAssetsRequest {
+            inner: self
+                .inner
+                .update_themes_param_theme_assets_request(theme_id, body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::delete_themes_param_theme_assets`].
+    pub fn delete_themes_param_theme_assets(
+        &self,
+        theme_id: &str,
+        asset_key: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .delete_themes_param_theme_assets(theme_id, asset_key),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::delete_themes_param_theme_assets_request`].
+    pub fn delete_themes_param_theme_assets_request<'a>(
+        &'a self,
+        theme_id: &'a str,
+    ) -> OnlineStoreDeleteThemesParamThemeAssetsRequest<'a> {
+        OnlineStoreDeleteThemesParamThemeAssetsRequest {
+            inner: self
+                .inner
+                .delete_themes_param_theme_assets_request(theme_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202101_get_themes_param_theme_asset`].
+    pub fn deprecated_202101_get_themes_param_theme_asset(
+        &self,
+        theme_id: &str,
+        fields: &str,
+        asset_key: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_get_themes_param_theme_asset(theme_id, fields, asset_key),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202101_get_themes_param_theme_asset_request`].
+    pub fn deprecated_202101_get_themes_param_theme_asset_request<'a>(
+        &'a self,
+        theme_id: &'a str,
+    ) -> OnlineStoreDeprecated202101GetThemesParamThemeAssetRequest<'a> {
+        OnlineStoreDeprecated202101GetThemesParamThemeAssetRequest {
+            inner: self
+                .inner
+                .deprecated_202101_get_themes_param_theme_asset_request(theme_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202101_update_themes_param_theme_assets`].
+    pub fn deprecated_202101_update_themes_param_theme_assets(
+        &self,
+        theme_id: &str,
+        src: &str,
+        source_key: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_update_themes_param_theme_assets(
+                    theme_id, src, source_key, body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202101_update_themes_param_theme_assets_request`].
+    pub fn deprecated_202101_update_themes_param_theme_assets_request<'a>(
+        &'a self,
+        theme_id: &'a str,
+        body: &'a serde_json::Value,
+    ) -> OnlineStoreDeprecated202101UpdateThemesParamThemeAssetsRequest<'a> {
+        OnlineStoreDeprecated202101UpdateThemesParamThemeAssetsRequest {
+            inner: self
+                .inner
+                .deprecated_202101_update_themes_param_theme_assets_request(theme_id, body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202101_delete_themes_param_theme_assets`].
+    pub fn deprecated_202101_delete_themes_param_theme_assets(
+        &self,
+        theme_id: &str,
+        asset_key: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_delete_themes_param_theme_assets(theme_id, asset_key),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202101_delete_themes_param_theme_assets_request`].
+    pub fn deprecated_202101_delete_themes_param_theme_assets_request<'a>(
+        &'a self,
+        theme_id: &'a str,
+    ) -> OnlineStoreDeprecated202101DeleteThemesParamThemeAssetsRequest<'a> {
+        OnlineStoreDeprecated202101DeleteThemesParamThemeAssetsRequest {
+            inner: self
+                .inner
+                .deprecated_202101_delete_themes_param_theme_assets_request(theme_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_unstable_get_themes_param_theme_asset`].
+    pub fn deprecated_unstable_get_themes_param_theme_asset(
+        &self,
+        theme_id: &str,
+        fields: &str,
+        asset_key: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_get_themes_param_theme_asset(theme_id, fields, asset_key),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_unstable_get_themes_param_theme_asset_request`].
+    pub fn deprecated_unstable_get_themes_param_theme_asset_request<'a>(
+        &'a self,
+        theme_id: &'a str,
+    ) -> OnlineStoreDeprecatedUnstableGetThemesParamThemeAssetRequest<'a> {
+        OnlineStoreDeprecatedUnstableGetThemesParamThemeAssetRequest {
+            inner: self
+                .inner
+                .deprecated_unstable_get_themes_param_theme_asset_request(theme_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_unstable_update_themes_param_theme_assets`].
+    pub fn deprecated_unstable_update_themes_param_theme_assets(
+        &self,
+        theme_id: &str,
+        src: &str,
+        source_key: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_update_themes_param_theme_assets(
+                    theme_id, src, source_key, body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_unstable_update_themes_param_theme_assets_request`].
+    pub fn deprecated_unstable_update_themes_param_theme_assets_request<'a>(
+        &'a self,
+        theme_id: &'a str,
+        body: &'a serde_json::Value,
+    ) -> OnlineStoreDeprecatedUnstableUpdateThemesParamThemeAssetsRequest<'a> {
+        OnlineStoreDeprecatedUnstableUpdateThemesParamThemeAssetsRequest {
+            inner: self
+                .inner
+                .deprecated_unstable_update_themes_param_theme_assets_request(theme_id, body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_unstable_delete_themes_param_theme_assets`].
+    pub fn deprecated_unstable_delete_themes_param_theme_assets(
+        &self,
+        theme_id: &str,
+        asset_key: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_delete_themes_param_theme_assets(theme_id, asset_key),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_unstable_delete_themes_param_theme_assets_request`].
+    pub fn deprecated_unstable_delete_themes_param_theme_assets_request<'a>(
+        &'a self,
+        theme_id: &'a str,
+    ) -> OnlineStoreDeprecatedUnstableDeleteThemesParamThemeAssetsRequest<'a> {
+        OnlineStoreDeprecatedUnstableDeleteThemesParamThemeAssetsRequest {
+            inner: self
+                .inner
+                .deprecated_unstable_delete_themes_param_theme_assets_request(theme_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202001_get_blog`].
+    pub fn deprecated_202001_get_blog(
+        &self,
+        limit: &str,
+        since_id: &str,
+        handle: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_get_blog(limit, since_id, handle, fields),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202001_get_blog_request`].
+    pub fn deprecated_202001_get_blog_request(
+        &self,
+    ) -> OnlineStoreDeprecated202001GetBlogRequest<'_> {
+        OnlineStoreDeprecated202001GetBlogRequest {
+            inner: self.inner.deprecated_202001_get_blog_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202001_create_blogs`].
+    pub fn deprecated_202001_create_blogs(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202001_create_blogs(body))
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202001_get_blogs_count`].
+    pub fn deprecated_202001_get_blogs_count(&self) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202001_get_blogs_count())
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202001_get_blogs_param_blog`].
+    pub fn deprecated_202001_get_blogs_param_blog(
+        &self,
+        blog_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_get_blogs_param_blog(blog_id, fields),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202001_get_blogs_param_blog_request`].
+    pub fn deprecated_202001_get_blogs_param_blog_request<'a>(
+        &'a self,
+        blog_id: &'a str,
+    ) -> OnlineStoreDeprecated202001GetBlogsParamBlogRequest<'a> {
+        OnlineStoreDeprecated202001GetBlogsParamBlogRequest {
+            inner: self
+                .inner
+                .deprecated_202001_get_blogs_param_blog_request(blog_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202001_update_blogs_param_blog`].
+    pub fn deprecated_202001_update_blogs_param_blog(
+        &self,
+        blog_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_update_blogs_param_blog(blog_id, body),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202001_delete_blogs_param_blog`].
+    pub fn deprecated_202001_delete_blogs_param_blog(
+        &self,
+        blog_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_delete_blogs_param_blog(blog_id),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202001_get_comment`].
+    pub fn deprecated_202001_get_comment(
+        &self,
+        limit: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        fields: &str,
+        published_status: &str,
+        status: &str,
+        article_id: i64,
+        blog_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202001_get_comment(
+                limit,
+                since_id,
+                created_at_min,
+                created_at_max,
+                updated_at_min,
+                updated_at_max,
+                published_at_min,
+                published_at_max,
+                fields,
+                published_status,
+                status,
+                article_id,
+                blog_id,
+            ))
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202001_get_comment_request`].
+    pub fn deprecated_202001_get_comment_request(
+        &self,
+    ) -> OnlineStoreDeprecated202001GetCommentRequest<'_> {
+        OnlineStoreDeprecated202001GetCommentRequest {
+            inner: self.inner.deprecated_202001_get_comment_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202001_create_comments`].
+    pub fn deprecated_202001_create_comments(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202001_create_comments(body))
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202001_get_comments_count`].
+    pub fn deprecated_202001_get_comments_count(
+        &self,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+        status: &str,
+        article_id: i64,
+        blog_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202001_get_comments_count(
+                created_at_min,
+                created_at_max,
+                updated_at_min,
+                updated_at_max,
+                published_at_min,
+                published_at_max,
+                published_status,
+                status,
+                article_id,
+                blog_id,
+            ))
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202001_get_comments_count_request`].
+    pub fn deprecated_202001_get_comments_count_request(
+        &self,
+    ) -> OnlineStoreDeprecated202001GetCommentsCountRequest<'_> {
+        OnlineStoreDeprecated202001GetCommentsCountRequest {
+            inner: self.inner.deprecated_202001_get_comments_count_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202001_get_comments_param_comment`].
+    pub fn deprecated_202001_get_comments_param_comment(
+        &self,
+        comment_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_get_comments_param_comment(comment_id, fields),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202001_get_comments_param_comment_request`].
+    pub fn deprecated_202001_get_comments_param_comment_request<'a>(
+        &'a self,
+        comment_id: &'a str,
+    ) -> OnlineStoreDeprecated202001GetCommentsParamCommentRequest<'a> {
+        OnlineStoreDeprecated202001GetCommentsParamCommentRequest {
+            inner: self
+                .inner
+                .deprecated_202001_get_comments_param_comment_request(comment_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202001_update_comments_param_comment`].
+    pub fn deprecated_202001_update_comments_param_comment(
+        &self,
+        comment_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_update_comments_param_comment(comment_id, body),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202001_create_comments_param_comment_spam`].
+    pub fn deprecated_202001_create_comments_param_comment_spam(
+        &self,
+        comment_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_create_comments_param_comment_spam(comment_id, body),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202001_create_comments_param_comment_not_spam`].
+    pub fn deprecated_202001_create_comments_param_comment_not_spam(
+        &self,
+        comment_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_create_comments_param_comment_not_spam(comment_id, body),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202001_create_comments_param_comment_approve`].
+    pub fn deprecated_202001_create_comments_param_comment_approve(
+        &self,
+        comment_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_create_comments_param_comment_approve(comment_id, body),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202001_create_comments_param_comment_remove`].
+    pub fn deprecated_202001_create_comments_param_comment_remove(
+        &self,
+        comment_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_create_comments_param_comment_remove(comment_id, body),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202001_create_comments_param_comment_restore`].
+    pub fn deprecated_202001_create_comments_param_comment_restore(
+        &self,
+        comment_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_create_comments_param_comment_restore(comment_id, body),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202001_get_redirect`].
+    pub fn deprecated_202001_get_redirect(
+        &self,
+        limit: &str,
+        since_id: &str,
+        path: &str,
+        target: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_get_redirect(limit, since_id, path, target, fields),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202001_get_redirect_request`].
+    pub fn deprecated_202001_get_redirect_request(
+        &self,
+    ) -> OnlineStoreDeprecated202001GetRedirectRequest<'_> {
+        OnlineStoreDeprecated202001GetRedirectRequest {
+            inner: self.inner.deprecated_202001_get_redirect_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202001_create_redirects`].
+    pub fn deprecated_202001_create_redirects(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202001_create_redirects(body))
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202001_get_redirects_count`].
+    pub fn deprecated_202001_get_redirects_count(
+        &self,
+        path: &str,
+        target: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_get_redirects_count(path, target),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202001_get_redirects_count_request`].
+    pub fn deprecated_202001_get_redirects_count_request(
+        &self,
+    ) -> OnlineStoreDeprecated202001GetRedirectsCountRequest<'_> {
+        OnlineStoreDeprecated202001GetRedirectsCountRequest {
+            inner: self.inner.deprecated_202001_get_redirects_count_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202001_get_redirects_param_redirect`].
+    pub fn deprecated_202001_get_redirects_param_redirect(
+        &self,
+        redirect_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_get_redirects_param_redirect(redirect_id, fields),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202001_get_redirects_param_redirect_request`].
+    pub fn deprecated_202001_get_redirects_param_redirect_request<'a>(
+        &'a self,
+        redirect_id: &'a str,
+    ) -> OnlineStoreDeprecated202001GetRedirectsParamRedirectRequest<'a> {
+        OnlineStoreDeprecated202001GetRedirectsParamRedirectRequest {
+            inner: self
+                .inner
+                .deprecated_202001_get_redirects_param_redirect_request(redirect_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202001_update_redirects_param_redirect`].
+    pub fn deprecated_202001_update_redirects_param_redirect(
+        &self,
+        redirect_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_update_redirects_param_redirect(redirect_id, body),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202001_delete_redirects_param_redirect`].
+    pub fn deprecated_202001_delete_redirects_param_redirect(
+        &self,
+        redirect_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_delete_redirects_param_redirect(redirect_id),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202004_get_redirect`].
+    pub fn deprecated_202004_get_redirect(
+        &self,
+        limit: &str,
+        since_id: &str,
+        path: &str,
+        target: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_get_redirect(limit, since_id, path, target, fields),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202004_get_redirect_request`].
+    pub fn deprecated_202004_get_redirect_request(
+        &self,
+    ) -> OnlineStoreDeprecated202004GetRedirectRequest<'_> {
+        OnlineStoreDeprecated202004GetRedirectRequest {
+            inner: self.inner.deprecated_202004_get_redirect_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202004_create_redirects`].
+    pub fn deprecated_202004_create_redirects(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202004_create_redirects(body))
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202004_get_redirects_count`].
+    pub fn deprecated_202004_get_redirects_count(
+        &self,
+        path: &str,
+        target: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_get_redirects_count(path, target),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202004_get_redirects_count_request`].
+    pub fn deprecated_202004_get_redirects_count_request(
+        &self,
+    ) -> OnlineStoreDeprecated202004GetRedirectsCountRequest<'_> {
+        OnlineStoreDeprecated202004GetRedirectsCountRequest {
+            inner: self.inner.deprecated_202004_get_redirects_count_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202004_get_redirects_param_redirect`].
+    pub fn deprecated_202004_get_redirects_param_redirect(
+        &self,
+        redirect_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_get_redirects_param_redirect(redirect_id, fields),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202004_get_redirects_param_redirect_request`].
+    pub fn deprecated_202004_get_redirects_param_redirect_request<'a>(
+        &'a self,
+        redirect_id: &'a str,
+    ) -> OnlineStoreDeprecated202004GetRedirectsParamRedirectRequest<'a> {
+        OnlineStoreDeprecated202004GetRedirectsParamRedirectRequest {
+            inner: self
+                .inner
+                .deprecated_202004_get_redirects_param_redirect_request(redirect_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202004_update_redirects_param_redirect`].
+    pub fn deprecated_202004_update_redirects_param_redirect(
+        &self,
+        redirect_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_update_redirects_param_redirect(redirect_id, body),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202004_delete_redirects_param_redirect`].
+    pub fn deprecated_202004_delete_redirects_param_redirect(
+        &self,
+        redirect_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_delete_redirects_param_redirect(redirect_id),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202007_get_redirect`].
+    pub fn deprecated_202007_get_redirect(
+        &self,
+        limit: &str,
+        since_id: &str,
+        path: &str,
+        target: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_get_redirect(limit, since_id, path, target, fields),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202007_get_redirect_request`].
+    pub fn deprecated_202007_get_redirect_request(
+        &self,
+    ) -> OnlineStoreDeprecated202007GetRedirectRequest<'_> {
+        OnlineStoreDeprecated202007GetRedirectRequest {
+            inner: self.inner.deprecated_202007_get_redirect_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202007_create_redirects`].
+    pub fn deprecated_202007_create_redirects(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202007_create_redirects(body))
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202007_get_redirects_count`].
+    pub fn deprecated_202007_get_redirects_count(
+        &self,
+        path: &str,
+        target: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_get_redirects_count(path, target),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202007_get_redirects_count_request`].
+    pub fn deprecated_202007_get_redirects_count_request(
+        &self,
+    ) -> OnlineStoreDeprecated202007GetRedirectsCountRequest<'_> {
+        OnlineStoreDeprecated202007GetRedirectsCountRequest {
+            inner: self.inner.deprecated_202007_get_redirects_count_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202007_get_redirects_param_redirect`].
+    pub fn deprecated_202007_get_redirects_param_redirect(
+        &self,
+        redirect_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_get_redirects_param_redirect(redirect_id, fields),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202007_get_redirects_param_redirect_request`].
+    pub fn deprecated_202007_get_redirects_param_redirect_request<'a>(
+        &'a self,
+        redirect_id: &'a str,
+    ) -> OnlineStoreDeprecated202007GetRedirectsParamRedirectRequest<'a> {
+        OnlineStoreDeprecated202007GetRedirectsParamRedirectRequest {
+            inner: self
+                .inner
+                .deprecated_202007_get_redirects_param_redirect_request(redirect_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202007_update_redirects_param_redirect`].
+    pub fn deprecated_202007_update_redirects_param_redirect(
+        &self,
+        redirect_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_update_redirects_param_redirect(redirect_id, body),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202007_delete_redirects_param_redirect`].
+    pub fn deprecated_202007_delete_redirects_param_redirect(
+        &self,
+        redirect_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_delete_redirects_param_redirect(redirect_id),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::get_redirect`].
+    pub fn get_redirect(
+        &self,
+        limit: &str,
+        since_id: &str,
+        path: &str,
+        target: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .get_redirect(limit, since_id, path, target, fields),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::get_redirect_request`].
+    pub fn get_redirect_request(&self) -> OnlineStoreGetRedirectRequest<'_> {
+        OnlineStoreGetRedirectRequest {
+            inner: self.inner.get_redirect_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::create_redirects`].
+    pub fn create_redirects(&self, body: &serde_json::Value) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.create_redirects(body))
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::get_redirects_count`].
+    pub fn get_redirects_count(
+        &self,
+        path: &str,
+        target: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.get_redirects_count(path, target))
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::get_redirects_count_request`].
+    pub fn get_redirects_count_request(&self) -> OnlineStoreGetRedirectsCountRequest<'_> {
+        OnlineStoreGetRedirectsCountRequest {
+            inner: self.inner.get_redirects_count_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::get_redirects_param_redirect`].
+    pub fn get_redirects_param_redirect(
+        &self,
+        redirect_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.get_redirects_param_redirect(redirect_id, fields))
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::get_redirects_param_redirect_request`].
+    pub fn get_redirects_param_redirect_request<'a>(
+        &'a self,
+        redirect_id: &'a str,
+    ) -> OnlineStoreGetRedirectsParamRedirectRequest<'a> {
+        OnlineStoreGetRedirectsParamRedirectRequest {
+            inner: self.inner.get_redirects_param_redirect_request(redirect_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::update_redirects_param_redirect`].
+    pub fn update_redirects_param_redirect(
+        &self,
+        redirect_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .update_redirects_param_redirect(redirect_id, body),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::delete_redirects_param_redirect`].
+    pub fn delete_redirects_param_redirect(
+        &self,
+        redirect_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete_redirects_param_redirect(redirect_id))
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202101_get_redirect`].
+    pub fn deprecated_202101_get_redirect(
+        &self,
+        limit: &str,
+        since_id: &str,
+        path: &str,
+        target: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_get_redirect(limit, since_id, path, target, fields),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202101_get_redirect_request`].
+    pub fn deprecated_202101_get_redirect_request(
+        &self,
+    ) -> OnlineStoreDeprecated202101GetRedirectRequest<'_> {
+        OnlineStoreDeprecated202101GetRedirectRequest {
+            inner: self.inner.deprecated_202101_get_redirect_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202101_create_redirects`].
+    pub fn deprecated_202101_create_redirects(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202101_create_redirects(body))
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202101_get_redirects_count`].
+    pub fn deprecated_202101_get_redirects_count(
+        &self,
+        path: &str,
+        target: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_get_redirects_count(path, target),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202101_get_redirects_count_request`].
+    pub fn deprecated_202101_get_redirects_count_request(
+        &self,
+    ) -> OnlineStoreDeprecated202101GetRedirectsCountRequest<'_> {
+        OnlineStoreDeprecated202101GetRedirectsCountRequest {
+            inner: self.inner.deprecated_202101_get_redirects_count_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202101_get_redirects_param_redirect`].
+    pub fn deprecated_202101_get_redirects_param_redirect(
+        &self,
+        redirect_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_get_redirects_param_redirect(redirect_id, fields),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202101_get_redirects_param_redirect_request`].
+    pub fn deprecated_202101_get_redirects_param_redirect_request<'a>(
+        &'a self,
+        redirect_id: &'a str,
+    ) -> OnlineStoreDeprecated202101GetRedirectsParamRedirectRequest<'a> {
+        OnlineStoreDeprecated202101GetRedirectsParamRedirectRequest {
+            inner: self
+                .inner
+                .deprecated_202101_get_redirects_param_redirect_request(redirect_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202101_update_redirects_param_redirect`].
+    pub fn deprecated_202101_update_redirects_param_redirect(
+        &self,
+        redirect_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_update_redirects_param_redirect(redirect_id, body),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202101_delete_redirects_param_redirect`].
+    pub fn deprecated_202101_delete_redirects_param_redirect(
+        &self,
+        redirect_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_delete_redirects_param_redirect(redirect_id),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_unstable_get_redirect`].
+    pub fn deprecated_unstable_get_redirect(
+        &self,
+        limit: &str,
+        since_id: &str,
+        path: &str,
+        target: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_get_redirect(limit, since_id, path, target, fields),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_unstable_get_redirect_request`].
+    pub fn deprecated_unstable_get_redirect_request(
+        &self,
+    ) -> OnlineStoreDeprecatedUnstableGetRedirectRequest<'_> {
+        OnlineStoreDeprecatedUnstableGetRedirectRequest {
+            inner: self.inner.deprecated_unstable_get_redirect_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_unstable_create_redirects`].
+    pub fn deprecated_unstable_create_redirects(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_unstable_create_redirects(body))
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_unstable_get_redirects_count`].
+    pub fn deprecated_unstable_get_redirects_count(
+        &self,
+        path: &str,
+        target: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_get_redirects_count(path, target),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_unstable_get_redirects_count_request`].
+    pub fn deprecated_unstable_get_redirects_count_request(
+        &self,
+    ) -> OnlineStoreDeprecatedUnstableGetRedirectsCountRequest<'_> {
+        OnlineStoreDeprecatedUnstableGetRedirectsCountRequest {
+            inner: self.inner.deprecated_unstable_get_redirects_count_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_unstable_get_redirects_param_redirect`].
+    pub fn deprecated_unstable_get_redirects_param_redirect(
+        &self,
+        redirect_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_get_redirects_param_redirect(redirect_id, fields),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_unstable_get_redirects_param_redirect_request`].
+    pub fn deprecated_unstable_get_redirects_param_redirect_request<'a>(
+        &'a self,
+        redirect_id: &'a str,
+    ) -> OnlineStoreDeprecatedUnstableGetRedirectsParamRedirectRequest<'a> {
+        OnlineStoreDeprecatedUnstableGetRedirectsParamRedirectRequest {
+            inner: self
+                .inner
+                .deprecated_unstable_get_redirects_param_redirect_request(redirect_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_unstable_update_redirects_param_redirect`].
+    pub fn deprecated_unstable_update_redirects_param_redirect(
+        &self,
+        redirect_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_update_redirects_param_redirect(redirect_id, body),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_unstable_delete_redirects_param_redirect`].
+    pub fn deprecated_unstable_delete_redirects_param_redirect(
+        &self,
+        redirect_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_delete_redirects_param_redirect(redirect_id),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202001_get_script_tag`].
+    pub fn deprecated_202001_get_script_tag(
+        &self,
+        limit: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        src: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202001_get_script_tag(
+                limit,
+                since_id,
+                created_at_min,
+                created_at_max,
+                updated_at_min,
+                updated_at_max,
+                src,
+                fields,
+            ))
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202001_get_script_tag_request`].
+    pub fn deprecated_202001_get_script_tag_request(
+        &self,
+    ) -> OnlineStoreDeprecated202001GetScriptTagRequest<'_> {
+        OnlineStoreDeprecated202001GetScriptTagRequest {
+            inner: self.inner.deprecated_202001_get_script_tag_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202001_create_script_tags`].
+    pub fn deprecated_202001_create_script_tags(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202001_create_script_tags(body))
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202001_get_script_tags_count`].
+    pub fn deprecated_202001_get_script_tags_count(
+        &self,
+        src: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202001_get_script_tags_count(src))
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202001_get_script_tags_count_request`].
+    pub fn deprecated_202001_get_script_tags_count_request(
+        &self,
+    ) -> OnlineStoreDeprecated202001GetScriptTagsCountRequest<'_> {
+        OnlineStoreDeprecated202001GetScriptTagsCountRequest {
+            inner: self.inner.deprecated_202001_get_script_tags_count_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202001_get_script_tags_param_tag`].
+    pub fn deprecated_202001_get_script_tags_param_tag(
+        &self,
+        script_tag_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_get_script_tags_param_tag(script_tag_id, fields),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202001_get_script_tags_param_tag_request`].
+    pub fn deprecated_202001_get_script_tags_param_tag_request<'a>(
+        &'a self,
+        script_tag_id: &'a str,
+    ) -> OnlineStoreDeprecated202001GetScriptTagsParamTagRequest<'a> {
+        OnlineStoreDeprecated202001GetScriptTagsParamTagRequest {
+            inner: self
+                .inner
+                .deprecated_202001_get_script_tags_param_tag_request(script_tag_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202001_update_script_tags_param_tag`].
+    pub fn deprecated_202001_update_script_tags_param_tag(
+        &self,
+        script_tag_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_update_script_tags_param_tag(script_tag_id, body),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202001_delete_script_tags_param_tag`].
+    pub fn deprecated_202001_delete_script_tags_param_tag(
+        &self,
+        script_tag_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_delete_script_tags_param_tag(script_tag_id),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202004_get_script_tag`].
+    pub fn deprecated_202004_get_script_tag(
+        &self,
+        limit: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        src: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202004_get_script_tag(
+                limit,
+                since_id,
+                created_at_min,
+                created_at_max,
+                updated_at_min,
+                updated_at_max,
+                src,
+                fields,
+            ))
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202004_get_script_tag_request`].
+    pub fn deprecated_202004_get_script_tag_request(
+        &self,
+    ) -> OnlineStoreDeprecated202004GetScriptTagRequest<'_> {
+        OnlineStoreDeprecated202004GetScriptTagRequest {
+            inner: self.inner.deprecated_202004_get_script_tag_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202004_create_script_tags`].
+    pub fn deprecated_202004_create_script_tags(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202004_create_script_tags(body))
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202004_get_script_tags_count`].
+    pub fn deprecated_202004_get_script_tags_count(
+        &self,
+        src: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202004_get_script_tags_count(src))
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202004_get_script_tags_count_request`].
+    pub fn deprecated_202004_get_script_tags_count_request(
+        &self,
+    ) -> OnlineStoreDeprecated202004GetScriptTagsCountRequest<'_> {
+        OnlineStoreDeprecated202004GetScriptTagsCountRequest {
+            inner: self.inner.deprecated_202004_get_script_tags_count_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202004_get_script_tags_param_tag`].
+    pub fn deprecated_202004_get_script_tags_param_tag(
+        &self,
+        script_tag_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_get_script_tags_param_tag(script_tag_id, fields),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202004_get_script_tags_param_tag_request`].
+    pub fn deprecated_202004_get_script_tags_param_tag_request<'a>(
+        &'a self,
+        script_tag_id: &'a str,
+    ) -> OnlineStoreDeprecated202004GetScriptTagsParamTagRequest<'a> {
+        OnlineStoreDeprecated202004GetScriptTagsParamTagRequest {
+            inner: self
+                .inner
+                .deprecated_202004_get_script_tags_param_tag_request(script_tag_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202004_update_script_tags_param_tag`].
+    pub fn deprecated_202004_update_script_tags_param_tag(
+        &self,
+        script_tag_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_update_script_tags_param_tag(script_tag_id, body),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202004_delete_script_tags_param_tag`].
+    pub fn deprecated_202004_delete_script_tags_param_tag(
+        &self,
+        script_tag_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_delete_script_tags_param_tag(script_tag_id),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202007_get_script_tag`].
+    pub fn deprecated_202007_get_script_tag(
+        &self,
+        limit: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        src: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202007_get_script_tag(
+                limit,
+                since_id,
+                created_at_min,
+                created_at_max,
+                updated_at_min,
+                updated_at_max,
+                src,
+                fields,
+            ))
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202007_get_script_tag_request`].
+    pub fn deprecated_202007_get_script_tag_request(
+        &self,
+    ) -> OnlineStoreDeprecated202007GetScriptTagRequest<'_> {
+        OnlineStoreDeprecated202007GetScriptTagRequest {
+            inner: self.inner.deprecated_202007_get_script_tag_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202007_create_script_tags`].
+    pub fn deprecated_202007_create_script_tags(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202007_create_script_tags(body))
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202007_get_script_tags_count`].
+    pub fn deprecated_202007_get_script_tags_count(
+        &self,
+        src: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202007_get_script_tags_count(src))
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202007_get_script_tags_count_request`].
+    pub fn deprecated_202007_get_script_tags_count_request(
+        &self,
+    ) -> OnlineStoreDeprecated202007GetScriptTagsCountRequest<'_> {
+        OnlineStoreDeprecated202007GetScriptTagsCountRequest {
+            inner: self.inner.deprecated_202007_get_script_tags_count_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202007_get_script_tags_param_tag`].
+    pub fn deprecated_202007_get_script_tags_param_tag(
+        &self,
+        script_tag_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_get_script_tags_param_tag(script_tag_id, fields),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202007_get_script_tags_param_tag_request`].
+    pub fn deprecated_202007_get_script_tags_param_tag_request<'a>(
+        &'a self,
+        script_tag_id: &'a str,
+    ) -> OnlineStoreDeprecated202007GetScriptTagsParamTagRequest<'a> {
+        OnlineStoreDeprecated202007GetScriptTagsParamTagRequest {
+            inner: self
+                .inner
+                .deprecated_202007_get_script_tags_param_tag_request(script_tag_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202007_update_script_tags_param_tag`].
+    pub fn deprecated_202007_update_script_tags_param_tag(
+        &self,
+        script_tag_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_update_script_tags_param_tag(script_tag_id, body),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202007_delete_script_tags_param_tag`].
+    pub fn deprecated_202007_delete_script_tags_param_tag(
+        &self,
+        script_tag_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_delete_script_tags_param_tag(script_tag_id),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::get_script_tag`].
+    pub fn get_script_tag(
+        &self,
+        limit: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        src: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.get_script_tag(
+            limit,
+            since_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            src,
+            fields,
+        ))
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::get_script_tag_request`].
+    pub fn get_script_tag_request(&self) -> OnlineStoreGetScriptTagRequest<'_> {
+        OnlineStoreGetScriptTagRequest {
+            inner: self.inner.get_script_tag_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::create_script_tags`].
+    pub fn create_script_tags(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.create_script_tags(body))
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::get_script_tags_count`].
+    pub fn get_script_tags_count(&self, src: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.get_script_tags_count(src))
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::get_script_tags_count_request`].
+    pub fn get_script_tags_count_request(&self) -> OnlineStoreGetScriptTagsCountRequest<'_> {
+        OnlineStoreGetScriptTagsCountRequest {
+            inner: self.inner.get_script_tags_count_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::get_script_tags_param_tag`].
+    pub fn get_script_tags_param_tag(
+        &self,
+        script_tag_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.get_script_tags_param_tag(script_tag_id, fields))
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::get_script_tags_param_tag_request`].
+    pub fn get_script_tags_param_tag_request<'a>(
+        &'a self,
+        script_tag_id: &'a str,
+    ) -> OnlineStoreGetScriptTagsParamTagRequest<'a> {
+        OnlineStoreGetScriptTagsParamTagRequest {
+            inner: self.inner.get_script_tags_param_tag_request(script_tag_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::update_script_tags_param_tag`].
+    pub fn update_script_tags_param_tag(
+        &self,
+        script_tag_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.update_script_tags_param_tag(script_tag_id, body))
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::delete_script_tags_param_tag`].
+    pub fn delete_script_tags_param_tag(
+        &self,
+        script_tag_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete_script_tags_param_tag(script_tag_id))
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202101_get_script_tag`].
+    pub fn deprecated_202101_get_script_tag(
+        &self,
+        limit: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        src: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202101_get_script_tag(
+                limit,
+                since_id,
+                created_at_min,
+                created_at_max,
+                updated_at_min,
+                updated_at_max,
+                src,
+                fields,
+            ))
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202101_get_script_tag_request`].
+    pub fn deprecated_202101_get_script_tag_request(
+        &self,
+    ) -> OnlineStoreDeprecated202101GetScriptTagRequest<'_> {
+        OnlineStoreDeprecated202101GetScriptTagRequest {
+            inner: self.inner.deprecated_202101_get_script_tag_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202101_create_script_tags`].
+    pub fn deprecated_202101_create_script_tags(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202101_create_script_tags(body))
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202101_get_script_tags_count`].
+    pub fn deprecated_202101_get_script_tags_count(
+        &self,
+        src: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202101_get_script_tags_count(src))
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202101_get_script_tags_count_request`].
+    pub fn deprecated_202101_get_script_tags_count_request(
+        &self,
+    ) -> OnlineStoreDeprecated202101GetScriptTagsCountRequest<'_> {
+        OnlineStoreDeprecated202101GetScriptTagsCountRequest {
+            inner: self.inner.deprecated_202101_get_script_tags_count_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202101_get_script_tags_param_tag`].
+    pub fn deprecated_202101_get_script_tags_param_tag(
+        &self,
+        script_tag_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_get_script_tags_param_tag(script_tag_id, fields),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202101_get_script_tags_param_tag_request`].
+    pub fn deprecated_202101_get_script_tags_param_tag_request<'a>(
+        &'a self,
+        script_tag_id: &'a str,
+    ) -> OnlineStoreDeprecated202101GetScriptTagsParamTagRequest<'a> {
+        OnlineStoreDeprecated202101GetScriptTagsParamTagRequest {
+            inner: self
+                .inner
+                .deprecated_202101_get_script_tags_param_tag_request(script_tag_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202101_update_script_tags_param_tag`].
+    pub fn deprecated_202101_update_script_tags_param_tag(
+        &self,
+        script_tag_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_update_script_tags_param_tag(script_tag_id, body),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202101_delete_script_tags_param_tag`].
+    pub fn deprecated_202101_delete_script_tags_param_tag(
+        &self,
+        script_tag_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_delete_script_tags_param_tag(script_tag_id),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_unstable_get_script_tag`].
+    pub fn deprecated_unstable_get_script_tag(
+        &self,
+        limit: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        src: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_unstable_get_script_tag(
+                limit,
+                since_id,
+                created_at_min,
+                created_at_max,
+                updated_at_min,
+                updated_at_max,
+                src,
+                fields,
+            ))
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_unstable_get_script_tag_request`].
+    pub fn deprecated_unstable_get_script_tag_request(
+        &self,
+    ) -> OnlineStoreDeprecatedUnstableGetScriptTagRequest<'_> {
+        OnlineStoreDeprecatedUnstableGetScriptTagRequest {
+            inner: self.inner.deprecated_unstable_get_script_tag_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_unstable_create_script_tags`].
+    pub fn deprecated_unstable_create_script_tags(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_unstable_create_script_tags(body))
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_unstable_get_script_tags_count`].
+    pub fn deprecated_unstable_get_script_tags_count(
+        &self,
+        src: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_unstable_get_script_tags_count(src))
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_unstable_get_script_tags_count_request`].
+    pub fn deprecated_unstable_get_script_tags_count_request(
+        &self,
+    ) -> OnlineStoreDeprecatedUnstableGetScriptTagsCountRequest<'_> {
+        OnlineStoreDeprecatedUnstableGetScriptTagsCountRequest {
+            inner: self
+                .inner
+                .deprecated_unstable_get_script_tags_count_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_unstable_get_script_tags_param_tag`].
+    pub fn deprecated_unstable_get_script_tags_param_tag(
+        &self,
+        script_tag_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_get_script_tags_param_tag(script_tag_id, fields),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_unstable_get_script_tags_param_tag_request`].
+    pub fn deprecated_unstable_get_script_tags_param_tag_request<'a>(
+        &'a self,
+        script_tag_id: &'a str,
+    ) -> OnlineStoreDeprecatedUnstableGetScriptTagsParamTagRequest<'a> {
+        OnlineStoreDeprecatedUnstableGetScriptTagsParamTagRequest {
+            inner: self
+                .inner
+                .deprecated_unstable_get_script_tags_param_tag_request(script_tag_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_unstable_update_script_tags_param_tag`].
+    pub fn deprecated_unstable_update_script_tags_param_tag(
+        &self,
+        script_tag_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_update_script_tags_param_tag(script_tag_id, body),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_unstable_delete_script_tags_param_tag`].
+    pub fn deprecated_unstable_delete_script_tags_param_tag(
+        &self,
+        script_tag_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_delete_script_tags_param_tag(script_tag_id),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202001_get_theme`].
+    pub fn deprecated_202001_get_theme(&self, fields: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202001_get_theme(fields))
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202001_get_theme_request`].
+    pub fn deprecated_202001_get_theme_request(
+        &self,
+    ) -> OnlineStoreDeprecated202001GetThemeRequest<'_> {
+        OnlineStoreDeprecated202001GetThemeRequest {
+            inner: self.inner.deprecated_202001_get_theme_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202001_create_themes`].
+    pub fn deprecated_202001_create_themes(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202001_create_themes(body))
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202001_get_themes_param_theme`].
+    pub fn deprecated_202001_get_themes_param_theme(
+        &self,
+        theme_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_get_themes_param_theme(theme_id, fields),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202001_get_themes_param_theme_request`].
+    pub fn deprecated_202001_get_themes_param_theme_request<'a>(
+        &'a self,
+        theme_id: &'a str,
+    ) -> OnlineStoreDeprecated202001GetThemesParamThemeRequest<'a> {
+        OnlineStoreDeprecated202001GetThemesParamThemeRequest {
+            inner: self
+                .inner
+                .deprecated_202001_get_themes_param_theme_request(theme_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202001_update_themes_param_theme`].
+    pub fn deprecated_202001_update_themes_param_theme(
+        &self,
+        theme_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_update_themes_param_theme(theme_id, body),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202001_delete_themes_param_theme`].
+    pub fn deprecated_202001_delete_themes_param_theme(
+        &self,
+        theme_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_delete_themes_param_theme(theme_id),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202004_get_theme`].
+    pub fn deprecated_202004_get_theme(&self, fields: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202004_get_theme(fields))
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202004_get_theme_request`].
+    pub fn deprecated_202004_get_theme_request(
+        &self,
+    ) -> OnlineStoreDeprecated202004GetThemeRequest<'_> {
+        OnlineStoreDeprecated202004GetThemeRequest {
+            inner: self.inner.deprecated_202004_get_theme_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202004_create_themes`].
+    pub fn deprecated_202004_create_themes(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202004_create_themes(body))
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202004_get_themes_param_theme`].
+    pub fn deprecated_202004_get_themes_param_theme(
+        &self,
+        theme_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_get_themes_param_theme(theme_id, fields),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202004_get_themes_param_theme_request`].
+    pub fn deprecated_202004_get_themes_param_theme_request<'a>(
+        &'a self,
+        theme_id: &'a str,
+    ) -> OnlineStoreDeprecated202004GetThemesParamThemeRequest<'a> {
+        OnlineStoreDeprecated202004GetThemesParamThemeRequest {
+            inner: self
+                .inner
+                .deprecated_202004_get_themes_param_theme_request(theme_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202004_update_themes_param_theme`].
+    pub fn deprecated_202004_update_themes_param_theme(
+        &self,
+        theme_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_update_themes_param_theme(theme_id, body),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202004_delete_themes_param_theme`].
+    pub fn deprecated_202004_delete_themes_param_theme(
+        &self,
+        theme_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_delete_themes_param_theme(theme_id),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202007_get_theme`].
+    pub fn deprecated_202007_get_theme(&self, fields: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202007_get_theme(fields))
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202007_get_theme_request`].
+    pub fn deprecated_202007_get_theme_request(
+        &self,
+    ) -> OnlineStoreDeprecated202007GetThemeRequest<'_> {
+        OnlineStoreDeprecated202007GetThemeRequest {
+            inner: self.inner.deprecated_202007_get_theme_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202007_create_themes`].
+    pub fn deprecated_202007_create_themes(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202007_create_themes(body))
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202007_get_themes_param_theme`].
+    pub fn deprecated_202007_get_themes_param_theme(
+        &self,
+        theme_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_get_themes_param_theme(theme_id, fields),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202007_get_themes_param_theme_request`].
+    pub fn deprecated_202007_get_themes_param_theme_request<'a>(
+        &'a self,
+        theme_id: &'a str,
+    ) -> OnlineStoreDeprecated202007GetThemesParamThemeRequest<'a> {
+        OnlineStoreDeprecated202007GetThemesParamThemeRequest {
+            inner: self
+                .inner
+                .deprecated_202007_get_themes_param_theme_request(theme_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202007_update_themes_param_theme`].
+    pub fn deprecated_202007_update_themes_param_theme(
+        &self,
+        theme_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_update_themes_param_theme(theme_id, body),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202007_delete_themes_param_theme`].
+    pub fn deprecated_202007_delete_themes_param_theme(
+        &self,
+        theme_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_delete_themes_param_theme(theme_id),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::get_theme`].
+    pub fn get_theme(&self, fields: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.get_theme(fields))
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::get_theme_request`].
+    pub fn get_theme_request(&self) -> OnlineStoreGetThemeRequest<'_> {
+        OnlineStoreGetThemeRequest {
+            inner: self.inner.get_theme_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::create_themes`].
+    pub fn create_themes(&self, body: &serde_json::Value) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.create_themes(body))
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::get_themes_param_theme`].
+    pub fn get_themes_param_theme(
+        &self,
+        theme_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.get_themes_param_theme(theme_id, fields))
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::get_themes_param_theme_request`].
+    pub fn get_themes_param_theme_request<'a>(
+        &'a self,
+        theme_id: &'a str,
+    ) -> OnlineStoreGetThemesParamThemeRequest<'a> {
+        OnlineStoreGetThemesParamThemeRequest {
+            inner: self.inner.get_themes_param_theme_request(theme_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::update_themes_param_theme`].
+    pub fn update_themes_param_theme(
+        &self,
+        theme_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.update_themes_param_theme(theme_id, body))
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::delete_themes_param_theme`].
+    pub fn delete_themes_param_theme(&self, theme_id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete_themes_param_theme(theme_id))
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202101_get_theme`].
+    pub fn deprecated_202101_get_theme(&self, fields: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202101_get_theme(fields))
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202101_get_theme_request`].
+    pub fn deprecated_202101_get_theme_request(
+        &self,
+    ) -> OnlineStoreDeprecated202101GetThemeRequest<'_> {
+        OnlineStoreDeprecated202101GetThemeRequest {
+            inner: self.inner.deprecated_202101_get_theme_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202101_create_themes`].
+    pub fn deprecated_202101_create_themes(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202101_create_themes(body))
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202101_get_themes_param_theme`].
+    pub fn deprecated_202101_get_themes_param_theme(
+        &self,
+        theme_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_get_themes_param_theme(theme_id, fields),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202101_get_themes_param_theme_request`].
+    pub fn deprecated_202101_get_themes_param_theme_request<'a>(
+        &'a self,
+        theme_id: &'a str,
+    ) -> OnlineStoreDeprecated202101GetThemesParamThemeRequest<'a> {
+        OnlineStoreDeprecated202101GetThemesParamThemeRequest {
+            inner: self
+                .inner
+                .deprecated_202101_get_themes_param_theme_request(theme_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202101_update_themes_param_theme`].
+    pub fn deprecated_202101_update_themes_param_theme(
+        &self,
+        theme_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_update_themes_param_theme(theme_id, body),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_202101_delete_themes_param_theme`].
+    pub fn deprecated_202101_delete_themes_param_theme(
+        &self,
+        theme_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_delete_themes_param_theme(theme_id),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_unstable_get_theme`].
+    pub fn deprecated_unstable_get_theme(&self, fields: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_unstable_get_theme(fields))
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_unstable_get_theme_request`].
+    pub fn deprecated_unstable_get_theme_request(
+        &self,
+    ) -> OnlineStoreDeprecatedUnstableGetThemeRequest<'_> {
+        OnlineStoreDeprecatedUnstableGetThemeRequest {
+            inner: self.inner.deprecated_unstable_get_theme_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_unstable_create_themes`].
+    pub fn deprecated_unstable_create_themes(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_unstable_create_themes(body))
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_unstable_get_themes_param_theme`].
+    pub fn deprecated_unstable_get_themes_param_theme(
+        &self,
+        theme_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_get_themes_param_theme(theme_id, fields),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_unstable_get_themes_param_theme_request`].
+    pub fn deprecated_unstable_get_themes_param_theme_request<'a>(
+        &'a self,
+        theme_id: &'a str,
+    ) -> OnlineStoreDeprecatedUnstableGetThemesParamThemeRequest<'a> {
+        OnlineStoreDeprecatedUnstableGetThemesParamThemeRequest {
+            inner: self
+                .inner
+                .deprecated_unstable_get_themes_param_theme_request(theme_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_unstable_update_themes_param_theme`].
+    pub fn deprecated_unstable_update_themes_param_theme(
+        &self,
+        theme_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_update_themes_param_theme(theme_id, body),
+        )
+    }
+    /// The blocking version of [`crate::online_store::OnlineStore::deprecated_unstable_delete_themes_param_theme`].
+    pub fn deprecated_unstable_delete_themes_param_theme(
+        &self,
+        theme_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_delete_themes_param_theme(theme_id),
+        )
+    }
+}
@@ -0,0 +1,3371 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Orders::deprecated_202001_get_checkouts_count`], set with a builder.
+pub struct OrdersDeprecated202001GetCheckoutsCountRequest<'a> {
+    inner: crate::orders::OrdersDeprecated202001GetCheckoutsCountRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OrdersDeprecated202001GetCheckoutsCountRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.inner = self.inner.created_at_min(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.inner = self.inner.created_at_max(created_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.inner = self.inner.updated_at_min(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.inner = self.inner.updated_at_max(updated_at_max);
+        self
+    }
+
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: &'a str) -> Self {
+        self.inner = self.inner.status(status);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Orders::deprecated_202004_get_checkouts_count`], set with a builder.
+pub struct OrdersDeprecated202004GetCheckoutsCountRequest<'a> {
+    inner: crate::orders::OrdersDeprecated202004GetCheckoutsCountRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OrdersDeprecated202004GetCheckoutsCountRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.inner = self.inner.created_at_min(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.inner = self.inner.created_at_max(created_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.inner = self.inner.updated_at_min(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.inner = self.inner.updated_at_max(updated_at_max);
+        self
+    }
+
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: &'a str) -> Self {
+        self.inner = self.inner.status(status);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Orders::deprecated_202007_get_checkouts_count`], set with a builder.
+pub struct OrdersDeprecated202007GetCheckoutsCountRequest<'a> {
+    inner: crate::orders::OrdersDeprecated202007GetCheckoutsCountRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OrdersDeprecated202007GetCheckoutsCountRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.inner = self.inner.created_at_min(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.inner = self.inner.created_at_max(created_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.inner = self.inner.updated_at_min(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.inner = self.inner.updated_at_max(updated_at_max);
+        self
+    }
+
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: &'a str) -> Self {
+        self.inner = self.inner.status(status);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Orders::get_checkouts_count`], set with a builder.
+pub struct OrdersGetCheckoutsCountRequest<'a> {
+    inner: crate::orders::OrdersGetCheckoutsCountRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OrdersGetCheckoutsCountRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.inner = self.inner.created_at_min(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.inner = self.inner.created_at_max(created_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.inner = self.inner.updated_at_min(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.inner = self.inner.updated_at_max(updated_at_max);
+        self
+    }
+
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: &'a str) -> Self {
+        self.inner = self.inner.status(status);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Orders::deprecated_202101_get_checkouts_count`], set with a builder.
+pub struct OrdersDeprecated202101GetCheckoutsCountRequest<'a> {
+    inner: crate::orders::OrdersDeprecated202101GetCheckoutsCountRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OrdersDeprecated202101GetCheckoutsCountRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.inner = self.inner.created_at_min(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.inner = self.inner.created_at_max(created_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.inner = self.inner.updated_at_min(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.inner = self.inner.updated_at_max(updated_at_max);
+        self
+    }
+
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: &'a str) -> Self {
+        self.inner = self.inner.status(status);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Orders::deprecated_unstable_get_checkouts_count`], set with a builder.
+pub struct OrdersDeprecatedUnstableGetCheckoutsCountRequest<'a> {
+    inner: crate::orders::OrdersDeprecatedUnstableGetCheckoutsCountRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OrdersDeprecatedUnstableGetCheckoutsCountRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.inner = self.inner.created_at_min(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.inner = self.inner.created_at_max(created_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.inner = self.inner.updated_at_min(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.inner = self.inner.updated_at_max(updated_at_max);
+        self
+    }
+
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: &'a str) -> Self {
+        self.inner = self.inner.status(status);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Orders::deprecated_202001_get`], set with a builder.
+pub struct OrdersDeprecated202001GetRequest<'a> {
+    inner: crate::orders::OrdersDeprecated202001GetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OrdersDeprecated202001GetRequest<'a> {
+    /// Sets the `ids` query parameter.
+    pub fn ids(mut self, ids: &'a str) -> Self {
+        self.inner = self.inner.ids(ids);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.inner = self.inner.created_at_min(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.inner = self.inner.created_at_max(created_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.inner = self.inner.updated_at_min(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.inner = self.inner.updated_at_max(updated_at_max);
+        self
+    }
+
+    /// Sets the `processed_at_min` query parameter.
+    pub fn processed_at_min(mut self, processed_at_min: &'a str) -> Self {
+        self.inner = self.inner.processed_at_min(processed_at_min);
+        self
+    }
+
+    /// Sets the `processed_at_max` query parameter.
+    pub fn processed_at_max(mut self, processed_at_max: &'a str) -> Self {
+        self.inner = self.inner.processed_at_max(processed_at_max);
+        self
+    }
+
+    /// Sets the `attribution_app_id` query parameter.
+    pub fn attribution_app_id(mut self, attribution_app_id: &'a str) -> Self {
+        self.inner = self.inner.attribution_app_id(attribution_app_id);
+        self
+    }
+
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: &'a str) -> Self {
+        self.inner = self.inner.status(status);
+        self
+    }
+
+    /// Sets the `financial_status` query parameter.
+    pub fn financial_status(mut self, financial_status: &'a str) -> Self {
+        self.inner = self.inner.financial_status(financial_status);
+        self
+    }
+
+    /// Sets the `fulfillment_status` query parameter.
+    pub fn fulfillment_status(mut self, fulfillment_status: &'a str) -> Self {
+        self.inner = self.inner.fulfillment_status(fulfillment_status);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Orders::deprecated_202001_get_param`], set with a builder.
+pub struct OrdersDeprecated202001GetParamRequest<'a> {
+    inner: crate::orders::OrdersDeprecated202001GetParamRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OrdersDeprecated202001GetParamRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Orders::deprecated_202001_get_count`], set with a builder.
+pub struct OrdersDeprecated202001GetCountRequest<'a> {
+    inner: crate::orders::OrdersDeprecated202001GetCountRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OrdersDeprecated202001GetCountRequest<'a> {
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.inner = self.inner.created_at_min(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.inner = self.inner.created_at_max(created_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.inner = self.inner.updated_at_min(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.inner = self.inner.updated_at_max(updated_at_max);
+        self
+    }
+
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: &'a str) -> Self {
+        self.inner = self.inner.status(status);
+        self
+    }
+
+    /// Sets the `financial_status` query parameter.
+    pub fn financial_status(mut self, financial_status: &'a str) -> Self {
+        self.inner = self.inner.financial_status(financial_status);
+        self
+    }
+
+    /// Sets the `fulfillment_status` query parameter.
+    pub fn fulfillment_status(mut self, fulfillment_status: &'a str) -> Self {
+        self.inner = self.inner.fulfillment_status(fulfillment_status);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Orders::deprecated_202001_create_param_cancel`], set with a builder.
+pub struct OrdersDeprecated202001CreateParamCancelRequest<'a> {
+    inner: crate::orders::OrdersDeprecated202001CreateParamCancelRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OrdersDeprecated202001CreateParamCancelRequest<'a> {
+    /// Sets the `amount` query parameter.
+    pub fn amount(mut self, amount: &'a str) -> Self {
+        self.inner = self.inner.amount(amount);
+        self
+    }
+
+    /// Sets the `currency` query parameter.
+    pub fn currency(mut self, currency: &'a str) -> Self {
+        self.inner = self.inner.currency(currency);
+        self
+    }
+
+    /// Sets the `restock deprecated` query parameter.
+    pub fn restock_deprecated(mut self, restock_deprecated: &'a str) -> Self {
+        self.inner = self.inner.restock_deprecated(restock_deprecated);
+        self
+    }
+
+    /// Sets the `reason` query parameter.
+    pub fn reason(mut self, reason: &'a str) -> Self {
+        self.inner = self.inner.reason(reason);
+        self
+    }
+
+    /// Sets the `email` query parameter.
+    pub fn email(mut self, email: &'a str) -> Self {
+        self.inner = self.inner.email(email);
+        self
+    }
+
+    /// Sets the `refund` query parameter.
+    pub fn refund(mut self, refund: &'a str) -> Self {
+        self.inner = self.inner.refund(refund);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Orders::deprecated_202004_get`], set with a builder.
+pub struct OrdersDeprecated202004GetRequest<'a> {
+    inner: crate::orders::OrdersDeprecated202004GetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OrdersDeprecated202004GetRequest<'a> {
+    /// Sets the `ids` query parameter.
+    pub fn ids(mut self, ids: &'a str) -> Self {
+        self.inner = self.inner.ids(ids);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.inner = self.inner.created_at_min(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.inner = self.inner.created_at_max(created_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.inner = self.inner.updated_at_min(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.inner = self.inner.updated_at_max(updated_at_max);
+        self
+    }
+
+    /// Sets the `processed_at_min` query parameter.
+    pub fn processed_at_min(mut self, processed_at_min: &'a str) -> Self {
+        self.inner = self.inner.processed_at_min(processed_at_min);
+        self
+    }
+
+    /// Sets the `processed_at_max` query parameter.
+    pub fn processed_at_max(mut self, processed_at_max: &'a str) -> Self {
+        self.inner = self.inner.processed_at_max(processed_at_max);
+        self
+    }
+
+    /// Sets the `attribution_app_id` query parameter.
+    pub fn attribution_app_id(mut self, attribution_app_id: &'a str) -> Self {
+        self.inner = self.inner.attribution_app_id(attribution_app_id);
+        self
+    }
+
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: &'a str) -> Self {
+        self.inner = self.inner.status(status);
+        self
+    }
+
+    /// Sets the `financial_status` query parameter.
+    pub fn financial_status(mut self, financial_status: &'a str) -> Self {
+        self.inner = self.inner.financial_status(financial_status);
+        self
+    }
+
+    /// Sets the `fulfillment_status` query parameter.
+    pub fn fulfillment_status(mut self, fulfillment_status: &'a str) -> Self {
+        self.inner = self.inner.fulfillment_status(fulfillment_status);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Orders::deprecated_202004_get_param`], set with a builder.
+pub struct OrdersDeprecated202004GetParamRequest<'a> {
+    inner: crate::orders::OrdersDeprecated202004GetParamRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OrdersDeprecated202004GetParamRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Orders::deprecated_202004_get_count`], set with a builder.
+pub struct OrdersDeprecated202004GetCountRequest<'a> {
+    inner: crate::orders::OrdersDeprecated202004GetCountRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OrdersDeprecated202004GetCountRequest<'a> {
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.inner = self.inner.created_at_min(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.inner = self.inner.created_at_max(created_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.inner = self.inner.updated_at_min(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.inner = self.inner.updated_at_max(updated_at_max);
+        self
+    }
+
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: &'a str) -> Self {
+        self.inner = self.inner.status(status);
+        self
+    }
+
+    /// Sets the `financial_status` query parameter.
+    pub fn financial_status(mut self, financial_status: &'a str) -> Self {
+        self.inner = self.inner.financial_status(financial_status);
+        self
+    }
+
+    /// Sets the `fulfillment_status` query parameter.
+    pub fn fulfillment_status(mut self, fulfillment_status: &'a str) -> Self {
+        self.inner = self.inner.fulfillment_status(fulfillment_status);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Orders::deprecated_202004_create_param_cancel`], set with a builder.
+pub struct OrdersDeprecated202004CreateParamCancelRequest<'a> {
+    inner: crate::orders::OrdersDeprecated202004CreateParamCancelRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OrdersDeprecated202004CreateParamCancelRequest<'a> {
+    /// Sets the `amount` query parameter.
+    pub fn amount(mut self, amount: &'a str) -> Self {
+        self.inner = self.inner.amount(amount);
+        self
+    }
+
+    /// Sets the `currency` query parameter.
+    pub fn currency(mut self, currency: &'a str) -> Self {
+        self.inner = self.inner.currency(currency);
+        self
+    }
+
+    /// Sets the `restock deprecated` query parameter.
+    pub fn restock_deprecated(mut self, restock_deprecated: &'a str) -> Self {
+        self.inner = self.inner.restock_deprecated(restock_deprecated);
+        self
+    }
+
+    /// Sets the `reason` query parameter.
+    pub fn reason(mut self, reason: &'a str) -> Self {
+        self.inner = self.inner.reason(reason);
+        self
+    }
+
+    /// Sets the `email` query parameter.
+    pub fn email(mut self, email: &'a str) -> Self {
+        self.inner = self.inner.email(email);
+        self
+    }
+
+    /// Sets the `refund` query parameter.
+    pub fn refund(mut self, refund: &'a str) -> Self {
+        self.inner = self.inner.refund(refund);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Orders::deprecated_202007_get`], set with a builder.
+pub struct OrdersDeprecated202007GetRequest<'a> {
+    inner: crate::orders::OrdersDeprecated202007GetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OrdersDeprecated202007GetRequest<'a> {
+    /// Sets the `ids` query parameter.
+    pub fn ids(mut self, ids: &'a str) -> Self {
+        self.inner = self.inner.ids(ids);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.inner = self.inner.created_at_min(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.inner = self.inner.created_at_max(created_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.inner = self.inner.updated_at_min(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.inner = self.inner.updated_at_max(updated_at_max);
+        self
+    }
+
+    /// Sets the `processed_at_min` query parameter.
+    pub fn processed_at_min(mut self, processed_at_min: &'a str) -> Self {
+        self.inner = self.inner.processed_at_min(processed_at_min);
+        self
+    }
+
+    /// Sets the `processed_at_max` query parameter.
+    pub fn processed_at_max(mut self, processed_at_max: &'a str) -> Self {
+        self.inner = self.inner.processed_at_max(processed_at_max);
+        self
+    }
+
+    /// Sets the `attribution_app_id` query parameter.
+    pub fn attribution_app_id(mut self, attribution_app_id: &'a str) -> Self {
+        self.inner = self.inner.attribution_app_id(attribution_app_id);
+        self
+    }
+
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: &'a str) -> Self {
+        self.inner = self.inner.status(status);
+        self
+    }
+
+    /// Sets the `financial_status` query parameter.
+    pub fn financial_status(mut self, financial_status: &'a str) -> Self {
+        self.inner = self.inner.financial_status(financial_status);
+        self
+    }
+
+    /// Sets the `fulfillment_status` query parameter.
+    pub fn fulfillment_status(mut self, fulfillment_status: &'a str) -> Self {
+        self.inner = self.inner.fulfillment_status(fulfillment_status);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Orders::deprecated_202001_get_param_refund`], set with a builder.
+pub struct OrdersDeprecated202001GetParamRefundRequest<'a> {
+    inner: crate::orders::OrdersDeprecated202001GetParamRefundRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OrdersDeprecated202001GetParamRefundRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `in_shop_currency` query parameter.
+    pub fn in_shop_currency(mut self, in_shop_currency: &'a str) -> Self {
+        self.inner = self.inner.in_shop_currency(in_shop_currency);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Orders::deprecated_202001_create_param_refunds`], set with a builder.
+pub struct OrdersDeprecated202001CreateParamRefundsRequest<'a> {
+    inner: crate::orders::OrdersDeprecated202001CreateParamRefundsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OrdersDeprecated202001CreateParamRefundsRequest<'a> {
+    /// Sets the `restock deprecated` query parameter.
+    pub fn restock_deprecated(mut self, restock_deprecated: &'a str) -> Self {
+        self.inner = self.inner.restock_deprecated(restock_deprecated);
+        self
+    }
+
+    /// Sets the `notify` query parameter.
+    pub fn notify(mut self, notify: &'a str) -> Self {
+        self.inner = self.inner.notify(notify);
+        self
+    }
+
+    /// Sets the `note` query parameter.
+    pub fn note(mut self, note: &'a str) -> Self {
+        self.inner = self.inner.note(note);
+        self
+    }
+
+    /// Sets the `discrepancy_reason` query parameter.
+    pub fn discrepancy_reason(mut self, discrepancy_reason: &'a str) -> Self {
+        self.inner = self.inner.discrepancy_reason(discrepancy_reason);
+        self
+    }
+
+    /// Sets the `shipping` query parameter.
+    pub fn shipping(mut self, shipping: &'a str) -> Self {
+        self.inner = self.inner.shipping(shipping);
+        self
+    }
+
+    /// Sets the `refund_line_items` query parameter.
+    pub fn refund_line_items(mut self, refund_line_items: &'a str) -> Self {
+        self.inner = self.inner.refund_line_items(refund_line_items);
+        self
+    }
+
+    /// Sets the `transactions` query parameter.
+    pub fn transactions(mut self, transactions: &'a str) -> Self {
+        self.inner = self.inner.transactions(transactions);
+        self
+    }
+
+    /// Sets the `currency` query parameter.
+    pub fn currency(mut self, currency: &'a str) -> Self {
+        self.inner = self.inner.currency(currency);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Orders::deprecated_202001_get_param_refunds_refund`], set with a builder.
+pub struct OrdersDeprecated202001GetParamRefundsRefundRequest<'a> {
+    inner: crate::orders::OrdersDeprecated202001GetParamRefundsRefundRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OrdersDeprecated202001GetParamRefundsRefundRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `in_shop_currency` query parameter.
+    pub fn in_shop_currency(mut self, in_shop_currency: &'a str) -> Self {
+        self.inner = self.inner.in_shop_currency(in_shop_currency);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Orders::deprecated_202001_create_param_refunds_calculate`], set with a builder.
+pub struct OrdersDeprecated202001CreateParamRefundsCalculateRequest<'a> {
+    inner: crate::orders::OrdersDeprecated202001CreateParamRefundsCalculateRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OrdersDeprecated202001CreateParamRefundsCalculateRequest<'a> {
+    /// Sets the `shipping` query parameter.
+    pub fn shipping(mut self, shipping: &'a str) -> Self {
+        self.inner = self.inner.shipping(shipping);
+        self
+    }
+
+    /// Sets the `refund_line_items` query parameter.
+    pub fn refund_line_items(mut self, refund_line_items: &'a str) -> Self {
+        self.inner = self.inner.refund_line_items(refund_line_items);
+        self
+    }
+
+    /// Sets the `currency` query parameter.
+    pub fn currency(mut self, currency: &'a str) -> Self {
+        self.inner = self.inner.currency(currency);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Orders::deprecated_202004_get_param_refund`], set with a builder.
+pub struct OrdersDeprecated202004GetParamRefundRequest<'a> {
+    inner: crate::orders::OrdersDeprecated202004GetParamRefundRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OrdersDeprecated202004GetParamRefundRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `in_shop_currency` query parameter.
+    pub fn in_shop_currency(mut self, in_shop_currency: &'a str) -> Self {
+        self.inner = self.inner.in_shop_currency(in_shop_currency);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Orders::deprecated_202004_create_param_refunds`], set with a builder.
+pub struct OrdersDeprecated202004CreateParamRefundsRequest<'a> {
+    inner: crate::orders::OrdersDeprecated202004CreateParamRefundsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OrdersDeprecated202004CreateParamRefundsRequest<'a> {
+    /// Sets the `restock deprecated` query parameter.
+    pub fn restock_deprecated(mut self, restock_deprecated: &'a str) -> Self {
+        self.inner = self.inner.restock_deprecated(restock_deprecated);
+        self
+    }
+
+    /// Sets the `notify` query parameter.
+    pub fn notify(mut self, notify: &'a str) -> Self {
+        self.inner = self.inner.notify(notify);
+        self
+    }
+
+    /// Sets the `note` query parameter.
+    pub fn note(mut self, note: &'a str) -> Self {
+        self.inner = self.inner.note(note);
+        self
+    }
+
+    /// Sets the `discrepancy_reason` query parameter.
+    pub fn discrepancy_reason(mut self, discrepancy_reason: &'a str) -> Self {
+        self.inner = self.inner.discrepancy_reason(discrepancy_reason);
+        self
+    }
+
+    /// Sets the `shipping` query parameter.
+    pub fn shipping(mut self, shipping: &'a str) -> Self {
+        self.inner = self.inner.shipping(shipping);
+        self
+    }
+
+    /// Sets the `refund_line_items` query parameter.
+    pub fn refund_line_items(mut self, refund_line_items: &'a str) -> Self {
+        self.inner = self.inner.refund_line_items(refund_line_items);
+        self
+    }
+
+    /// Sets the `transactions` query parameter.
+    pub fn transactions(mut self, transactions: &'a str) -> Self {
+        self.inner = self.inner.transactions(transactions);
+        self
+    }
+
+    /// Sets the `currency` query parameter.
+    pub fn currency(mut self, currency: &'a str) -> Self {
+        self.inner = self.inner.currency(currency);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Orders::deprecated_202004_get_param_refunds_refund`], set with a builder.
+pub struct OrdersDeprecated202004GetParamRefundsRefundRequest<'a> {
+    inner: crate::orders::OrdersDeprecated202004GetParamRefundsRefundRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OrdersDeprecated202004GetParamRefundsRefundRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `in_shop_currency` query parameter.
+    pub fn in_shop_currency(mut self, in_shop_currency: &'a str) -> Self {
+        self.inner = self.inner.in_shop_currency(in_shop_currency);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Orders::deprecated_202004_create_param_refunds_calculate`], set with a builder.
+pub struct OrdersDeprecated202004CreateParamRefundsCalculateRequest<'a> {
+    inner: crate::orders::OrdersDeprecated202004CreateParamRefundsCalculateRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OrdersDeprecated202004CreateParamRefundsCalculateRequest<'a> {
+    /// Sets the `shipping` query parameter.
+    pub fn shipping(mut self, shipping: &'a str) -> Self {
+        self.inner = self.inner.shipping(shipping);
+        self
+    }
+
+    /// Sets the `refund_line_items` query parameter.
+    pub fn refund_line_items(mut self, refund_line_items: &'a str) -> Self {
+        self.inner = self.inner.refund_line_items(refund_line_items);
+        self
+    }
+
+    /// Sets the `currency` query parameter.
+    pub fn currency(mut self, currency: &'a str) -> Self {
+        self.inner = self.inner.currency(currency);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Orders::deprecated_202007_get_param_refund`], set with a builder.
+pub struct OrdersDeprecated202007GetParamRefundRequest<'a> {
+    inner: crate::orders::OrdersDeprecated202007GetParamRefundRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OrdersDeprecated202007GetParamRefundRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `in_shop_currency` query parameter.
+    pub fn in_shop_currency(mut self, in_shop_currency: &'a str) -> Self {
+        self.inner = self.inner.in_shop_currency(in_shop_currency);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Orders::deprecated_202007_create_param_refunds`], set with a builder.
+pub struct OrdersDeprecated202007CreateParamRefundsRequest<'a> {
+    inner: crate::orders::OrdersDeprecated202007CreateParamRefundsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OrdersDeprecated202007CreateParamRefundsRequest<'a> {
+    /// Sets the `restock deprecated` query parameter.
+    pub fn restock_deprecated(mut self, restock_deprecated: &'a str) -> Self {
+        self.inner = self.inner.restock_deprecated(restock_deprecated);
+        self
+    }
+
+    /// Sets the `notify` query parameter.
+    pub fn notify(mut self, notify: &'a str) -> Self {
+        self.inner = self.inner.notify(notify);
+        self
+    }
+
+    /// Sets the `note` query parameter.
+    pub fn note(mut self, note: &'a str) -> Self {
+        self.inner = self.inner.note(note);
+        self
+    }
+
+    /// Sets the `discrepancy_reason` query parameter.
+    pub fn discrepancy_reason(mut self, discrepancy_reason: &'a str) -> Self {
+        self.inner = self.inner.discrepancy_reason(discrepancy_reason);
+        self
+    }
+
+    /// Sets the `shipping` query parameter.
+    pub fn shipping(mut self, shipping: &'a str) -> Self {
+        self.inner = self.inner.shipping(shipping);
+        self
+    }
+
+    /// Sets the `refund_line_items` query parameter.
+    pub fn refund_line_items(mut self, refund_line_items: &'a str) -> Self {
+        self.inner = self.inner.refund_line_items(refund_line_items);
+        self
+    }
+
+    /// Sets the `transactions` query parameter.
+    pub fn transactions(mut self, transactions: &'a str) -> Self {
+        self.inner = self.inner.transactions(transactions);
+        self
+    }
+
+    /// Sets the `currency` query parameter.
+    pub fn currency(mut self, currency: &'a str) -> Self {
+        self.inner = self.inner.currency(currency);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Orders::deprecated_202007_get_param_refunds_refund`], set with a builder.
+pub struct OrdersDeprecated202007GetParamRefundsRefundRequest<'a> {
+    inner: crate::orders::OrdersDeprecated202007GetParamRefundsRefundRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OrdersDeprecated202007GetParamRefundsRefundRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `in_shop_currency` query parameter.
+    pub fn in_shop_currency(mut self, in_shop_currency: &'a str) -> Self {
+        self.inner = self.inner.in_shop_currency(in_shop_currency);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Orders::deprecated_202007_create_param_refunds_calculate`], set with a builder.
+pub struct OrdersDeprecated202007CreateParamRefundsCalculateRequest<'a> {
+    inner: crate::orders::OrdersDeprecated202007CreateParamRefundsCalculateRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OrdersDeprecated202007CreateParamRefundsCalculateRequest<'a> {
+    /// Sets the `shipping` query parameter.
+    pub fn shipping(mut self, shipping: &'a str) -> Self {
+        self.inner = self.inner.shipping(shipping);
+        self
+    }
+
+    /// Sets the `refund_line_items` query parameter.
+    pub fn refund_line_items(mut self, refund_line_items: &'a str) -> Self {
+        self.inner = self.inner.refund_line_items(refund_line_items);
+        self
+    }
+
+    /// Sets the `currency` query parameter.
+    pub fn currency(mut self, currency: &'a str) -> Self {
+        self.inner = self.inner.currency(currency);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Orders::get_param_refund`], set with a builder.
+pub struct OrdersGetParamRefundRequest<'a> {
+    inner: crate::orders::OrdersGetParamRefundRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OrdersGetParamRefundRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `in_shop_currency` query parameter.
+    pub fn in_shop_currency(mut self, in_shop_currency: &'a str) -> Self {
+        self.inner = self.inner.in_shop_currency(in_shop_currency);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Orders::create_param_refunds`], set with a builder.
+pub struct OrdersCreateParamRefundsRequest<'a> {
+    inner: crate::orders::OrdersCreateParamRefundsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OrdersCreateParamRefundsRequest<'a> {
+    /// Sets the `restock deprecated` query parameter.
+    pub fn restock_deprecated(mut self, restock_deprecated: &'a str) -> Self {
+        self.inner = self.inner.restock_deprecated(restock_deprecated);
+        self
+    }
+
+    /// Sets the `notify` query parameter.
+    pub fn notify(mut self, notify: &'a str) -> Self {
+        self.inner = self.inner.notify(notify);
+        self
+    }
+
+    /// Sets the `note` query parameter.
+    pub fn note(mut self, note: &'a str) -> Self {
+        self.inner = self.inner.note(note);
+        self
+    }
+
+    /// Sets the `discrepancy_reason` query parameter.
+    pub fn discrepancy_reason(mut self, discrepancy_reason: &'a str) -> Self {
+        self.inner = self.inner.discrepancy_reason(discrepancy_reason);
+        self
+    }
+
+    /// Sets the `shipping` query parameter.
+    pub fn shipping(mut self, shipping: &'a str) -> Self {
+        self.inner = self.inner.shipping(shipping);
+        self
+    }
+
+    /// Sets the `refund_line_items` query parameter.
+    pub fn refund_line_items(mut self, refund_line_items: &'a str) -> Self {
+        self.inner = self.inner.refund_line_items(refund_line_items);
+        self
+    }
+
+    /// Sets the `transactions` query parameter.
+    pub fn transactions(mut self, transactions: &'a str) -> Self {
+        self.inner = self.inner.transactions(transactions);
+        self
+    }
+
+    /// Sets the `currency` query parameter.
+    pub fn currency(mut self, currency: &'a str) -> Self {
+        self.inner = self.inner.currency(currency);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Orders::get_param_refunds_refund`], set with a builder.
+pub struct OrdersGetParamRefundsRefundRequest<'a> {
+    inner: crate::orders::OrdersGetParamRefundsRefundRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OrdersGetParamRefundsRefundRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `in_shop_currency` query parameter.
+    pub fn in_shop_currency(mut self, in_shop_currency: &'a str) -> Self {
+        self.inner = self.inner.in_shop_currency(in_shop_currency);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Orders::create_param_refunds_calculate`], set with a builder.
+pub struct OrdersCreateParamRefundsCalculateRequest<'a> {
+    inner: crate::orders::OrdersCreateParamRefundsCalculateRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OrdersCreateParamRefundsCalculateRequest<'a> {
+    /// Sets the `shipping` query parameter.
+    pub fn shipping(mut self, shipping: &'a str) -> Self {
+        self.inner = self.inner.shipping(shipping);
+        self
+    }
+
+    /// Sets the `refund_line_items` query parameter.
+    pub fn refund_line_items(mut self, refund_line_items: &'a str) -> Self {
+        self.inner = self.inner.refund_line_items(refund_line_items);
+        self
+    }
+
+    /// Sets the `currency` query parameter.
+    pub fn currency(mut self, currency: &'a str) -> Self {
+        self.inner = self.inner.currency(currency);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Orders::deprecated_202101_get_param_refund`], set with a builder.
+pub struct OrdersDeprecated202101GetParamRefundRequest<'a> {
+    inner: crate::orders::OrdersDeprecated202101GetParamRefundRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OrdersDeprecated202101GetParamRefundRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `in_shop_currency` query parameter.
+    pub fn in_shop_currency(mut self, in_shop_currency: &'a str) -> Self {
+        self.inner = self.inner.in_shop_currency(in_shop_currency);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Orders::deprecated_202101_create_param_refunds`], set with a builder.
+pub struct OrdersDeprecated202101CreateParamRefundsRequest<'a> {
+    inner: crate::orders::OrdersDeprecated202101CreateParamRefundsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OrdersDeprecated202101CreateParamRefundsRequest<'a> {
+    /// Sets the `restock deprecated` query parameter.
+    pub fn restock_deprecated(mut self, restock_deprecated: &'a str) -> Self {
+        self.inner = self.inner.restock_deprecated(restock_deprecated);
+        self
+    }
+
+    /// Sets the `notify` query parameter.
+    pub fn notify(mut self, notify: &'a str) -> Self {
+        self.inner = self.inner.notify(notify);
+        self
+    }
+
+    /// Sets the `note` query parameter.
+    pub fn note(mut self, note: &'a str) -> Self {
+        self.inner = self.inner.note(note);
+        self
+    }
+
+    /// Sets the `discrepancy_reason` query parameter.
+    pub fn discrepancy_reason(mut self, discrepancy_reason: &'a str) -> Self {
+        self.inner = self.inner.discrepancy_reason(discrepancy_reason);
+        self
+    }
+
+    /// Sets the `shipping` query parameter.
+    pub fn shipping(mut self, shipping: &'a str) -> Self {
+        self.inner = self.inner.shipping(shipping);
+        self
+    }
+
+    /// Sets the `refund_line_items` query parameter.
+    pub fn refund_line_items(mut self, refund_line_items: &'a str) -> Self {
+        self.inner = self.inner.refund_line_items(refund_line_items);
+        self
+    }
+
+    /// Sets the `transactions` query parameter.
+    pub fn transactions(mut self, transactions: &'a str) -> Self {
+        self.inner = self.inner.transactions(transactions);
+        self
+    }
+
+    /// Sets the `currency` query parameter.
+    pub fn currency(mut self, currency: &'a str) -> Self {
+        self.inner = self.inner.currency(currency);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Orders::deprecated_202101_get_param_refunds_refund`], set with a builder.
+pub struct OrdersDeprecated202101GetParamRefundsRefundRequest<'a> {
+    inner: crate::orders::OrdersDeprecated202101GetParamRefundsRefundRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OrdersDeprecated202101GetParamRefundsRefundRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `in_shop_currency` query parameter.
+    pub fn in_shop_currency(mut self, in_shop_currency: &'a str) -> Self {
+        self.inner = self.inner.in_shop_currency(in_shop_currency);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Orders::deprecated_202101_create_param_refunds_calculate`], set with a builder.
+pub struct OrdersDeprecated202101CreateParamRefundsCalculateRequest<'a> {
+    inner: crate::orders::OrdersDeprecated202101CreateParamRefundsCalculateRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OrdersDeprecated202101CreateParamRefundsCalculateRequest<'a> {
+    /// Sets the `shipping` query parameter.
+    pub fn shipping(mut self, shipping: &'a str) -> Self {
+        self.inner = self.inner.shipping(shipping);
+        self
+    }
+
+    /// Sets the `refund_line_items` query parameter.
+    pub fn refund_line_items(mut self, refund_line_items: &'a str) -> Self {
+        self.inner = self.inner.refund_line_items(refund_line_items);
+        self
+    }
+
+    /// Sets the `currency` query parameter.
+    pub fn currency(mut self, currency: &'a str) -> Self {
+        self.inner = self.inner.currency(currency);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Orders::deprecated_unstable_get_param_refund`], set with a builder.
+pub struct OrdersDeprecatedUnstableGetParamRefundRequest<'a> {
+    inner: crate::orders::OrdersDeprecatedUnstableGetParamRefundRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OrdersDeprecatedUnstableGetParamRefundRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `in_shop_currency` query parameter.
+    pub fn in_shop_currency(mut self, in_shop_currency: &'a str) -> Self {
+        self.inner = self.inner.in_shop_currency(in_shop_currency);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Orders::deprecated_unstable_create_param_refunds`], set with a builder.
+pub struct OrdersDeprecatedUnstableCreateParamRefundsRequest<'a> {
+    inner: crate::orders::OrdersDeprecatedUnstableCreateParamRefundsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OrdersDeprecatedUnstableCreateParamRefundsRequest<'a> {
+    /// Sets the `restock deprecated` query parameter.
+    pub fn restock_deprecated(mut self, restock_deprecated: &'a str) -> Self {
+        self.inner = self.inner.restock_deprecated(restock_deprecated);
+        self
+    }
+
+    /// Sets the `notify` query parameter.
+    pub fn notify(mut self, notify: &'a str) -> Self {
+        self.inner = self.inner.notify(notify);
+        self
+    }
+
+    /// Sets the `note` query parameter.
+    pub fn note(mut self, note: &'a str) -> Self {
+        self.inner = self.inner.note(note);
+        self
+    }
+
+    /// Sets the `discrepancy_reason` query parameter.
+    pub fn discrepancy_reason(mut self, discrepancy_reason: &'a str) -> Self {
+        self.inner = self.inner.discrepancy_reason(discrepancy_reason);
+        self
+    }
+
+    /// Sets the `shipping` query parameter.
+    pub fn shipping(mut self, shipping: &'a str) -> Self {
+        self.inner = self.inner.shipping(shipping);
+        self
+    }
+
+    /// Sets the `refund_line_items` query parameter.
+    pub fn refund_line_items(mut self, refund_line_items: &'a str) -> Self {
+        self.inner = self.inner.refund_line_items(refund_line_items);
+        self
+    }
+
+    /// Sets the `transactions` query parameter.
+    pub fn transactions(mut self, transactions: &'a str) -> Self {
+        self.inner = self.inner.transactions(transactions);
+        self
+    }
+
+    /// Sets the `currency` query parameter.
+    pub fn currency(mut self, currency: &'a str) -> Self {
+        self.inner = self.inner.currency(currency);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Orders::deprecated_unstable_get_param_refunds_refund`], set with a builder.
+pub struct OrdersDeprecatedUnstableGetParamRefundsRefundRequest<'a> {
+    inner: crate::orders::OrdersDeprecatedUnstableGetParamRefundsRefundRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OrdersDeprecatedUnstableGetParamRefundsRefundRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `in_shop_currency` query parameter.
+    pub fn in_shop_currency(mut self, in_shop_currency: &'a str) -> Self {
+        self.inner = self.inner.in_shop_currency(in_shop_currency);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Orders::deprecated_unstable_create_param_refunds_calculate`], set with a builder.
+pub struct OrdersDeprecatedUnstableCreateParamRefundsCalculateRequest<'a> {
+    inner: crate::orders::OrdersDeprecatedUnstableCreateParamRefundsCalculateRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OrdersDeprecatedUnstableCreateParamRefundsCalculateRequest<'a> {
+    /// Sets the `shipping` query parameter.
+    pub fn shipping(mut self, shipping: &'a str) -> Self {
+        self.inner = self.inner.shipping(shipping);
+        self
+    }
+
+    /// Sets the `refund_line_items` query parameter.
+    pub fn refund_line_items(mut self, refund_line_items: &'a str) -> Self {
+        self.inner = self.inner.refund_line_items(refund_line_items);
+        self
+    }
+
+    /// Sets the `currency` query parameter.
+    pub fn currency(mut self, currency: &'a str) -> Self {
+        self.inner = self.inner.currency(currency);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::orders::Orders`].
+pub struct Orders {
+    inner: crate::orders::Orders,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Orders {
+    pub(crate) fn new(inner: crate::orders::Orders, runtime: Arc<tokio::runtime::Runtime>) -> Self {
+        Orders { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::orders::Orders::deprecated_202001_get_checkouts_count`].
+    pub fn deprecated_202001_get_checkouts_count(
+        &self,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        status: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202001_get_checkouts_count(
+                since_id,
+                created_at_min,
+                created_at_max,
+                updated_at_min,
+                updated_at_max,
+                status,
+            ))
+    }
+    /// The blocking version of [`crate::orders::Orders::deprecated_202001_get_checkouts_count_request`].
+    pub fn deprecated_202001_get_checkouts_count_request(
+        &self,
+    ) -> OrdersDeprecated202001GetCheckoutsCountRequest<'_> {
+        OrdersDeprecated202001GetCheckoutsCountRequest {
+            inner: self.inner.deprecated_202001_get_checkouts_count_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::orders::Orders::deprecated_202004_get_checkouts_count`].
+    pub fn deprecated_202004_get_checkouts_count(
+        &self,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        status: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202004_get_checkouts_count(
+                since_id,
+                created_at_min,
+                created_at_max,
+                updated_at_min,
+                updated_at_max,
+                status,
+            ))
+    }
+    /// The blocking version of [`crate::orders::Orders::deprecated_202004_get_checkouts_count_request`].
+    pub fn deprecated_202004_get_checkouts_count_request(
+        &self,
+    ) -> OrdersDeprecated202004GetCheckoutsCountRequest<'_> {
+        OrdersDeprecated202004GetCheckoutsCountRequest {
+            inner: self.inner.deprecated_202004_get_checkouts_count_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::orders::Orders::deprecated_202007_get_checkouts_count`].
+    pub fn deprecated_202007_get_checkouts_count(
+        &self,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        status: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202007_get_checkouts_count(
+                since_id,
+                created_at_min,
+                created_at_max,
+                updated_at_min,
+                updated_at_max,
+                status,
+            ))
+    }
+    /// The blocking version of [`crate::orders::Orders::deprecated_202007_get_checkouts_count_request`].
+    pub fn deprecated_202007_get_checkouts_count_request(
+        &self,
+    ) -> OrdersDeprecated202007GetCheckoutsCountRequest<'_> {
+        OrdersDeprecated202007GetCheckoutsCountRequest {
+            inner: self.inner.deprecated_202007_get_checkouts_count_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::orders::Orders::get_checkouts_count`].
+    pub fn get_checkouts_count(
+        &self,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        status: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.get_checkouts_count(
+            since_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            status,
+        ))
+    }
+    /// The blocking version of [`crate::orders::Orders::get_checkouts_count_request`].
+    pub fn get_checkouts_count_request(&self) -> OrdersGetCheckoutsCountRequest<'_> {
+        OrdersGetCheckoutsCountRequest {
+            inner: self.inner.get_checkouts_count_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::orders::Orders::deprecated_202101_get_checkouts_count`].
+    pub fn deprecated_202101_get_checkouts_count(
+        &self,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        status: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202101_get_checkouts_count(
+                since_id,
+                created_at_min,
+                created_at_max,
+                updated_at_min,
+                updated_at_max,
+                status,
+            ))
+    }
+    /// The blocking version of [`crate::orders::Orders::deprecated_202101_get_checkouts_count_request`].
+    pub fn deprecated_202101_get_checkouts_count_request(
+        &self,
+    ) -> OrdersDeprecated202101GetCheckoutsCountRequest<'_> {
+        OrdersDeprecated202101GetCheckoutsCountRequest {
+            inner: self.inner.deprecated_202101_get_checkouts_count_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::orders::Orders::deprecated_unstable_get_checkouts_count`].
+    pub fn deprecated_unstable_get_checkouts_count(
+        &self,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        status: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_unstable_get_checkouts_count(
+                since_id,
+                created_at_min,
+                created_at_max,
+                updated_at_min,
+                updated_at_max,
+                status,
+            ))
+    }
+    /// The blocking version of [`crate::orders::Orders::deprecated_unstable_get_checkouts_count_request`].
+    pub fn deprecated_unstable_get_checkouts_count_request(
+        &self,
+    ) -> OrdersDeprecatedUnstableGetCheckoutsCountRequest<'_> {
+        OrdersDeprecatedUnstableGetCheckoutsCountRequest {
+            inner: self.inner.deprecated_unstable_get_checkouts_count_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::orders::Orders::deprecated_202001_get`].
+    pub fn deprecated_202001_get(
+        &self,
+        ids: &str,
+        limit: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        processed_at_min: &str,
+        processed_at_max: &str,
+        attribution_app_id: &str,
+        status: &str,
+        financial_status: &str,
+        fulfillment_status: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.deprecated_202001_get(
+            ids,
+            limit,
+            since_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            processed_at_min,
+            processed_at_max,
+            attribution_app_id,
+            status,
+            financial_status,
+            fulfillment_status,
+            fields,
+        ))
+    }
+    /// The blocking version of [`crate::orders::Orders::deprecated_202001_get_request`].
+    pub fn deprecated_202001_get_request(&self) -> OrdersDeprecated202001GetRequest<'_> {
+        OrdersDeprecated202001GetRequest {
+            inner: self.inner.deprecated_202001_get_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::orders::Orders::deprecated_202001_create`].
+    pub fn deprecated_202001_create(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202001_create(body))
+    }
+    /// The blocking version of [`crate::orders::Orders::deprecated_202001_get_param`].
+    pub fn deprecated_202001_get_param(
+        &self,
+        order_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202001_get_param(order_id, fields))
+    }
+    /// The blocking version of [`crate::orders::Orders::deprecated_202001_get_param_request`].
+    pub fn deprecated_202001_get_param_request<'a>(
+        &'a self,
+        order_id: &'a str,
+    ) -> OrdersDeprecated202001GetParamRequest<'a> {
+        OrdersDeprecated202001GetParamRequest {
+            inner: self.inner.deprecated_202001_get_param_request(order_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::orders::Orders::deprecated_202001_update_param`].
+    pub fn deprecated_202001_update_param(
+        &self,
+        order_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202001_update_param(order_id, body))
+    }
+    /// The blocking version of [`crate::orders::Orders::deprecated_202001_delete_param`].
+    pub fn deprecated_202001_delete_param(
+        &self,
+        order_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202001_delete_param(order_id))
+    }
+    /// The blocking version of [`crate::orders::Orders::deprecated_202001_get_count`].
+    pub fn deprecated_202001_get_count(
+        &self,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        status: &str,
+        financial_status: &str,
+        fulfillment_status: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202001_get_count(
+                created_at_min,
+                created_at_max,
+                updated_at_min,
+                updated_at_max,
+                status,
+                financial_status,
+                fulfillment_status,
+            ))
+    }
+    /// The blocking version of [`crate::orders::Orders::deprecated_202001_get_count_request`].
+    pub fn deprecated_202001_get_count_request(&self) -> OrdersDeprecated202001GetCountRequest<'_> {
+        OrdersDeprecated202001GetCountRequest {
+            inner: self.inner.deprecated_202001_get_count_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::orders::Orders::deprecated_202001_create_param_close`].
+    pub fn deprecated_202001_create_param_close(
+        &self,
+        order_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_create_param_close(order_id, body),
+        )
+    }
+    /// The blocking version of [`crate::orders::Orders::deprecated_202001_create_param_open`].
+    pub fn deprecated_202001_create_param_open(
+        &self,
+        order_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_create_param_open(order_id, body),
+        )
+    }
+    /// The blocking version of [`crate::orders::Orders::deprecated_202001_create_param_cancel`].
+    pub fn deprecated_202001_create_param_cancel(
+        &self,
+        order_id: &str,
+        amount: &str,
+        currency: &str,
+        restock_deprecated: &str,
+        reason: &str,
+        email: &str,
+        refund: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202001_create_param_cancel(
+                order_id,
+                amount,
+                currency,
+                restock_deprecated,
+                reason,
+                email,
+                refund,
+                body,
+            ))
+    }
+    /// The blocking version of [`crate::orders::Orders::deprecated_202001_create_param_cancel_request`].
+    pub fn deprecated_202001_create_param_cancel_request<'a>(
+        &'a self,
+        order_id: &'a str,
+        body: &'a serde_json::Value,
+    ) -> OrdersDeprecated202001CreateParamCancelRequest<'a> {
+        OrdersDeprecated202001CreateParamCancelRequest {
+            inner: self
+                .inner
+                .deprecated_202001_create_param_cancel_request(order_id, body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::orders::Orders::deprecated_202004_get`].
+    pub fn deprecated_202004_get(
+        &self,
+        ids: &str,
+        limit: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        processed_at_min: &str,
+        processed_at_max: &str,
+        attribution_app_id: &str,
+        status: &str,
+        financial_status: &str,
+        fulfillment_status: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.deprecated_202004_get(
+            ids,
+            limit,
+            since_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            processed_at_min,
+            processed_at_max,
+            attribution_app_id,
+            status,
+            financial_status,
+            fulfillment_status,
+            fields,
+        ))
+    }
+    /// The blocking version of [`crate::orders::Orders::deprecated_202004_get_request`].
+    pub fn deprecated_202004_get_request(&self) -> OrdersDeprecated202004GetRequest<'_> {
+        OrdersDeprecated202004GetRequest {
+            inner: self.inner.deprecated_202004_get_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::orders::Orders::deprecated_202004_create`].
+    pub fn deprecated_202004_create(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202004_create(body))
+    }
+    /// The blocking version of [`crate::orders::Orders::deprecated_202004_get_param`].
+    pub fn deprecated_202004_get_param(
+        &self,
+        order_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202004_get_param(order_id, fields))
+    }
+    /// The blocking version of [`crate::orders::Orders::deprecated_202004_get_param_request`].
+    pub fn deprecated_202004_get_param_request<'a>(
+        &'a self,
+        order_id: &'a str,
+    ) -> OrdersDeprecated202004GetParamRequest<'a> {
+        OrdersDeprecated202004GetParamRequest {
+            inner: self.inner.deprecated_202004_get_param_request(order_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::orders::Orders::deprecated_202004_update_param`].
+    pub fn deprecated_202004_update_param(
+        &self,
+        order_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202004_update_param(order_id, body))
+    }
+    /// The blocking version of [`crate::orders::Orders::deprecated_202004_delete_param`].
+    pub fn deprecated_202004_delete_param(
+        &self,
+        order_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202004_delete_param(order_id))
+    }
+    /// The blocking version of [`crate::orders::Orders::deprecated_202004_get_count`].
+    pub fn deprecated_202004_get_count(
+        &self,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        status: &str,
+        financial_status: &str,
+        fulfillment_status: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202004_get_count(
+                created_at_min,
+                created_at_max,
+                updated_at_min,
+                updated_at_max,
+                status,
+                financial_status,
+                fulfillment_status,
+            ))
+    }
+    /// The blocking version of [`crate::orders::Orders::deprecated_202004_get_count_request`].
+    pub fn deprecated_202004_get_count_request(&self) -> OrdersDeprecated202004GetCountRequest<'_> {
+        OrdersDeprecated202004GetCountRequest {
+            inner: self.inner.deprecated_202004_get_count_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::orders::Orders::deprecated_202004_create_param_close`].
+    pub fn deprecated_202004_create_param_close(
+        &self,
+        order_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_create_param_close(order_id, body),
+        )
+    }
+    /// The blocking version of [`crate::orders::Orders::deprecated_202004_create_param_open`].
+    pub fn deprecated_202004_create_param_open(
+        &self,
+        order_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_create_param_open(order_id, body),
+        )
+    }
+    /// The blocking version of [`crate::orders::Orders::deprecated_202004_create_param_cancel`].
+    pub fn deprecated_202004_create_param_cancel(
+        &self,
+        order_id: &str,
+        amount: &str,
+        currency: &str,
+        restock_deprecated: &str,
+        reason: &str,
+        email: &str,
+        refund: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202004_create_param_cancel(
+                order_id,
+                amount,
+                currency,
+                restock_deprecated,
+                reason,
+                email,
+                refund,
+                body,
+            ))
+    }
+    /// The blocking version of [`crate::orders::Orders::deprecated_202004_create_param_cancel_request`].
+    pub fn deprecated_202004_create_param_cancel_request<'a>(
+        &'a self,
+        order_id: &'a str,
+        body: &'a serde_json::Value,
+    ) -> OrdersDeprecated202004CreateParamCancelRequest<'a> {
+        OrdersDeprecated202004CreateParamCancelRequest {
+            inner: self
+                .inner
+                .deprecated_202004_create_param_cancel_request(order_id, body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::orders::Orders::deprecated_202007_get`].
+    pub fn deprecated_202007_get(
+        &self,
+        ids: &str,
+        limit: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        processed_at_min: &str,
+        processed_at_max: &str,
+        attribution_app_id: &str,
+        status: &str,
+        financial_status: &str,
+        fulfillment_status: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.deprecated_202007_get(
+            ids,
+            limit,
+            since_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            processed_at_min,
+            processed_at_max,
+            attribution_app_id,
+            status,
+            financial_status,
+            fulfillment_status,
+            fields,
+        ))
+    }
+    /// The blocking version of [`crate::orders::Orders::deprecated_202007_get_request`].
+    pub fn deprecated_202007_get_request(&self) -> OrdersDeprecated202007GetRequest<'_> {
+        OrdersDeprecated202007GetRequest {
+            inner: self.inner.deprecated_202007_get_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::orders::Orders::deprecated_202001_get_param_risk`].
+    pub fn deprecated_202001_get_param_risk(
+        &self,
+        order_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202001_get_param_risk(order_id))
+    }
+    /// The blocking version of [`crate::orders::Orders::deprecated_202001_create_param_risks`].
+    pub fn deprecated_202001_create_param_risks(
+        &self,
+        order_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_create_param_risks(order_id, body),
+        )
+    }
+    /// The blocking version of [`crate::orders::Orders::deprecated_202001_get_param_risks_risk`].
+    pub fn deprecated_202001_get_param_risks_risk(
+        &self,
+        order_id: &str,
+        risk_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_get_param_risks_risk(order_id, risk_id),
+        )
+    }
+    /// The blocking version of [`crate::orders::Orders::deprecated_202001_update_param_risks_risk`].
+    pub fn deprecated_202001_update_param_risks_risk(
+        &self,
+        order_id: &str,
+        risk_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_update_param_risks_risk(order_id, risk_id, body),
+        )
+    }
+    /// The blocking version of [`crate::orders::Orders::deprecated_202001_delete_param_risks_risk`].
+    pub fn deprecated_202001_delete_param_risks_risk(
+        &self,
+        order_id: &str,
+        risk_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_delete_param_risks_risk(order_id, risk_id),
+        )
+    }
+    /// The blocking version of [`crate::orders::Orders::deprecated_202004_get_param_risk`].
+    pub fn deprecated_202004_get_param_risk(
+        &self,
+        order_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202004_get_param_risk(order_id))
+    }
+    /// The blocking version of [`crate::orders::Orders::deprecated_202004_create_param_risks`].
+    pub fn deprecated_202004_create_param_risks(
+        &self,
+        order_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_create_param_risks(order_id, body),
+        )
+    }
+    /// The blocking version of [`crate::orders::Orders::deprecated_202004_get_param_risks_risk`].
+    pub fn deprecated_202004_get_param_risks_risk(
+        &self,
+        order_id: &str,
+        risk_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_get_param_risks_risk(order_id, risk_id),
+        )
+    }
+    /// The blocking version of [`crate::orders::Orders::deprecated_202004_update_param_risks_risk`].
+    pub fn deprecated_202004_update_param_risks_risk(
+        &self,
+        order_id: &str,
+        risk_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_update_param_risks_risk(order_id, risk_id, body),
+        )
+    }
+    /// The blocking version of [`crate::orders::Orders::deprecated_202004_delete_param_risks_risk`].
+    pub fn deprecated_202004_delete_param_risks_risk(
+        &self,
+        order_id: &str,
+        risk_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_delete_param_risks_risk(order_id, risk_id),
+        )
+    }
+    /// The blocking version of [`crate::orders::Orders::deprecated_202007_get_param_risk`].
+    pub fn deprecated_202007_get_param_risk(
+        &self,
+        order_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202007_get_param_risk(order_id))
+    }
+    /// The blocking version of [`crate::orders::Orders::deprecated_202007_create_param_risks`].
+    pub fn deprecated_202007_create_param_risks(
+        &self,
+        order_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_create_param_risks(order_id, body),
+        )
+    }
+    /// The blocking version of [`crate::orders::Orders::deprecated_202007_get_param_risks_risk`].
+    pub fn deprecated_202007_get_param_risks_risk(
+        &self,
+        order_id: &str,
+        risk_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_get_param_risks_risk(order_id, risk_id),
+        )
+    }
+    /// The blocking version of [`crate::orders::Orders::deprecated_202007_update_param_risks_risk`].
+    pub fn deprecated_202007_update_param_risks_risk(
+        &self,
+        order_id: &str,
+        risk_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_update_param_risks_risk(order_id, risk_id, body),
+        )
+    }
+    /// The blocking version of [`crate::orders::Orders::deprecated_202007_delete_param_risks_risk`].
+    pub fn deprecated_202007_delete_param_risks_risk(
+        &self,
+        order_id: &str,
+        risk_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_delete_param_risks_risk(order_id, risk_id),
+        )
+    }
+    /// The blocking version of [`crate::orders::Orders::get_param_risk`].
+    pub fn get_param_risk(&self, order_id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.get_param_risk(order_id))
+    }
+    /// The blocking version of [`crate::orders::Orders::create_param_risks`].
+    pub fn create_param_risks(
+        &self,
+        order_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.create_param_risks(order_id, body))
+    }
+    /// The blocking version of [`crate::orders::Orders::get_param_risks_risk`].
+    pub fn get_param_risks_risk(
+        &self,
+        order_id: &str,
+        risk_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.get_param_risks_risk(order_id, risk_id))
+    }
+    /// The blocking version of [`crate::orders::Orders::update_param_risks_risk`].
+    pub fn update_param_risks_risk(
+        &self,
+        order_id: &str,
+        risk_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.update_param_risks_risk(order_id, risk_id, body))
+    }
+    /// The blocking version of [`crate::orders::Orders::delete_param_risks_risk`].
+    pub fn delete_param_risks_risk(
+        &self,
+        order_id: &str,
+        risk_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete_param_risks_risk(order_id, risk_id))
+    }
+    /// The blocking version of [`crate::orders::Orders::deprecated_202101_get_param_risk`].
+    pub fn deprecated_202101_get_param_risk(
+        &self,
+        order_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202101_get_param_risk(order_id))
+    }
+    /// The blocking version of [`crate::orders::Orders::deprecated_202101_create_param_risks`].
+    pub fn deprecated_202101_create_param_risks(
+        &self,
+        order_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_create_param_risks(order_id, body),
+        )
+    }
+    /// The blocking version of [`crate::orders::Orders::deprecated_202101_get_param_risks_risk`].
+    pub fn deprecated_202101_get_param_risks_risk(
+        &self,
+        order_id: &str,
+        risk_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_get_param_risks_risk(order_id, risk_id),
+        )
+    }
+    /// The blocking version of [`crate::orders::Orders::deprecated_202101_update_param_risks_risk`].
+    pub fn deprecated_202101_update_param_risks_risk(
+        &self,
+        order_id: &str,
+        risk_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_update_param_risks_risk(order_id, risk_id, body),
+        )
+    }
+    /// The blocking version of [`crate::orders::Orders::deprecated_202101_delete_param_risks_risk`].
+    pub fn deprecated_202101_delete_param_risks_risk(
+        &self,
+        order_id: &str,
+        risk_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_delete_param_risks_risk(order_id, risk_id),
+        )
+    }
+    /// The blocking version of [`crate::orders::Orders::deprecated_unstable_get_param_risk`].
+    pub fn deprecated_unstable_get_param_risk(
+        &self,
+        order_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_unstable_get_param_risk(order_id))
+    }
+    /// The blocking version of [`crate::orders::Orders::deprecated_unstable_create_param_risks`].
+    pub fn deprecated_unstable_create_param_risks(
+        &self,
+        order_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_create_param_risks(order_id, body),
+        )
+    }
+    /// The blocking version of [`crate::orders::Orders::deprecated_unstable_get_param_risks_risk`].
+    pub fn deprecated_unstable_get_param_risks_risk(
+        &self,
+        order_id: &str,
+        risk_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_get_param_risks_risk(order_id, risk_id),
+        )
+    }
+    /// The blocking version of [`crate::orders::Orders::deprecated_unstable_update_param_risks_risk`].
+    pub fn deprecated_unstable_update_param_risks_risk(
+        &self,
+        order_id: &str,
+        risk_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_update_param_risks_risk(order_id, risk_id, body),
+        )
+    }
+    /// The blocking version of [`crate::orders::Orders::deprecated_unstable_delete_param_risks_risk`].
+    pub fn deprecated_unstable_delete_param_risks_risk(
+        &self,
+        order_id: &str,
+        risk_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_delete_param_risks_risk(order_id, risk_id),
+        )
+    }
+    /// The blocking version of [`crate::orders::Orders::deprecated_202001_get_param_refund`].
+    pub fn deprecated_202001_get_param_refund(
+        &self,
+        order_id: &str,
+        limit: &str,
+        fields: &str,
+        in_shop_currency: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202001_get_param_refund(
+                order_id,
+                limit,
+                fields,
+                in_shop_currency,
+            ))
+    }
+    /// The blocking version of [`crate::orders::Orders::deprecated_202001_get_param_refund_request`].
+    pub fn deprecated_202001_get_param_refund_request<'a>(
+        &'a self,
+        order_id: &'a str,
+    ) -> OrdersDeprecated202001GetParamRefundRequest<'a> {
+        OrdersDeprecated202001GetParamRefundRequest {
+            inner: self
+                .inner
+                .deprecated_202001_get_param_refund_request(order_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::orders::Orders::deprecated_202001_create_param_refunds`].
+    pub fn deprecated_202001_create_param_refunds(
+        &self,
+        order_id: &str,
+        restock_deprecated: &str,
+        notify: &str,
+        note: &str,
+        discrepancy_reason: &str,
+        shipping: &str,
+        refund_line_items: &str,
+        transactions: &str,
+        currency: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202001_create_param_refunds(
+                order_id,
+                restock_deprecated,
+                notify,
+                note,
+                discrepancy_reason,
+                shipping,
+                refund_line_items,
+                transactions,
+                currency,
+                body,
+            ))
+    }
+    /// The blocking version of [`crate::orders::Orders::deprecated_202001_create_param_refunds_request`].
+    pub fn deprecated_202001_create_param_refunds_request<'a>(
+        &'a self,
+        order_id: &'a str,
+        body: &'a serde_json::Value,
+    ) -> OrdersDeprecated202001CreateParamRefundsRequest<'a> {
+        OrdersDeprecated202001CreateParamRefundsRequest {
+            inner: self
+                .inner
+                .deprecated_202001_create_param_refunds_request(order_id, body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::orders::Orders::deprecated_202001_get_param_refunds_refund`].
+    pub fn deprecated_202001_get_param_refunds_refund(
+        &self,
+        order_id: &str,
+        refund_id: &str,
+        fields: &str,
+        in_shop_currency: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202001_get_param_refunds_refund(
+                order_id,
+                refund_id,
+                fields,
+                in_shop_currency,
+            ))
+    }
+    /// The blocking version of [`crate::orders::Orders::deprecated_202001_get_param_refunds_refund_request`].
+    pub fn deprecated_202001_get_param_refunds_refund_request<'a>(
+        &'a self,
+        order_id: &'a str,
+        refund_id: &'a str,
+    ) -> OrdersDeprecated202001GetParamRefundsRefundRequest<'a> {
+        OrdersDeprecated202001GetParamRefundsRefundRequest {
+            inner: self
+                .inner
+                .deprecated_202001_get_param_refunds_refund_request(order_id, refund_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::orders::Orders::deprecated_202001_create_param_refunds_calculate`].
+    pub fn deprecated_202001_create_param_refunds_calculate(
+        &self,
+        order_id: &str,
+        shipping: &str,
+        refund_line_items: &str,
+        currency: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202001_create_param_refunds_calculate(
+                order_id,
+                shipping,
+                refund_line_items,
+                currency,
+                body,
+            ))
+    }
+    /// The blocking version of [`crate::orders::Orders::deprecated_202001_create_param_refunds_calculate_request`].
+    pub fn deprecated_202001_create_param_refunds_calculate_request<'a>(
+        &'a self,
+        order_id: &'a str,
+        body: &'a serde_json::Value,
+    ) -> OrdersDeprecated202001CreateParamRefundsCalculateRequest<'a> {
+        OrdersDeprecated202001CreateParamRefundsCalculateRequest {
+            inner: self
+                .inner
+                .deprecated_202001_create_param_refunds_calculate_request(order_id, body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::orders::Orders::deprecated_202004_get_param_refund`].
+    pub fn deprecated_202004_get_param_refund(
+        &self,
+        order_id: &str,
+        limit: &str,
+        fields: &str,
+        in_shop_currency: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202004_get_param_refund(
+                order_id,
+                limit,
+                fields,
+                in_shop_currency,
+            ))
+    }
+    /// The blocking version of [`crate::orders::Orders::deprecated_202004_get_param_refund_request`].
+    pub fn deprecated_202004_get_param_refund_request<'a>(
+        &'a self,
+        order_id: &'a str,
+    ) -> OrdersDeprecated202004GetParamRefundRequest<'a> {
+        OrdersDeprecated202004GetParamRefundRequest {
+            inner: self
+                .inner
+                .deprecated_202004_get_param_refund_request(order_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::orders::Orders::deprecated_202004_create_param_refunds`].
+    pub fn deprecated_202004_create_param_refunds(
+        &self,
+        order_id: &str,
+        restock_deprecated: &str,
+        notify: &str,
+        note: &str,
+        discrepancy_reason: &str,
+        shipping: &str,
+        refund_line_items: &str,
+        transactions: &str,
+        currency: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202004_create_param_refunds(
+                order_id,
+                restock_deprecated,
+                notify,
+                note,
+                discrepancy_reason,
+                shipping,
+                refund_line_items,
+                transactions,
+                currency,
+                body,
+            ))
+    }
+    /// The blocking version of [`crate::orders::Orders::deprecated_202004_create_param_refunds_request`].
+    pub fn deprecated_202004_create_param_refunds_request<'a>(
+        &'a self,
+        order_id: &'a str,
+        body: &'a serde_json::Value,
+    ) -> OrdersDeprecated202004CreateParamRefundsRequest<'a> {
+        OrdersDeprecated202004CreateParamRefundsRequest {
+            inner: self
+                .inner
+                .deprecated_202004_create_param_refunds_request(order_id, body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::orders::Orders::deprecated_202004_get_param_refunds_refund`].
+    pub fn deprecated_202004_get_param_refunds_refund(
+        &self,
+        order_id: &str,
+        refund_id: &str,
+        fields: &str,
+        in_shop_currency: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202004_get_param_refunds_refund(
+                order_id,
+                refund_id,
+                fields,
+                in_shop_currency,
+            ))
+    }
+    /// The blocking version of [`crate::orders::Orders::deprecated_202004_get_param_refunds_refund_request`].
+    pub fn deprecated_202004_get_param_refunds_refund_request<'a>(
+        &'a self,
+        order_id: &'a str,
+        refund_id: &'a str,
+    ) -> OrdersDeprecated202004GetParamRefundsRefundRequest<'a> {
+        OrdersDeprecated202004GetParamRefundsRefundRequest {
+            inner: self
+                .inner
+                .deprecated_202004_get_param_refunds_refund_request(order_id, refund_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::orders::Orders::deprecated_202004_create_param_refunds_calculate`].
+    pub fn deprecated_202004_create_param_refunds_calculate(
+        &self,
+        order_id: &str,
+        shipping: &str,
+        refund_line_items: &str,
+        currency: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202004_create_param_refunds_calculate(
+                order_id,
+                shipping,
+                refund_line_items,
+                currency,
+                body,
+            ))
+    }
+    /// The blocking version of [`crate::orders::Orders::deprecated_202004_create_param_refunds_calculate_request`].
+    pub fn deprecated_202004_create_param_refunds_calculate_request<'a>(
+        &'a self,
+        order_id: &'a str,
+        body: &'a serde_json::Value,
+    ) -> OrdersDeprecated202004CreateParamRefundsCalculateRequest<'a> {
+        OrdersDeprecated202004CreateParamRefundsCalculateRequest {
+            inner: self
+                .inner
+                .deprecated_202004_create_param_refunds_calculate_request(order_id, body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::orders::Orders::deprecated_202007_get_param_refund`].
+    pub fn deprecated_202007_get_param_refund(
+        &self,
+        order_id: &str,
+        limit: &str,
+        fields: &str,
+        in_shop_currency: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202007_get_param_refund(
+                order_id,
+                limit,
+                fields,
+                in_shop_currency,
+            ))
+    }
+    /// The blocking version of [`crate::orders::Orders::deprecated_202007_get_param_refund_request`].
+    pub fn deprecated_202007_get_param_refund_request<'a>(
+        &'a self,
+        order_id: &'a str,
+    ) -> OrdersDeprecated202007GetParamRefundRequest<'a> {
+        OrdersDeprecated202007GetParamRefundRequest {
+            inner: self
+                .inner
+                .deprecated_202007_get_param_refund_request(order_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::orders::Orders::deprecated_202007_create_param_refunds`].
+    pub fn deprecated_202007_create_param_refunds(
+        &self,
+        order_id: &str,
+        restock_deprecated: &str,
+        notify: &str,
+        note: &str,
+        discrepancy_reason: &str,
+        shipping: &str,
+        refund_line_items: &str,
+        transactions: &str,
+        currency: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202007_create_param_refunds(
+                order_id,
+                restock_deprecated,
+                notify,
+                note,
+                discrepancy_reason,
+                shipping,
+                refund_line_items,
+                transactions,
+                currency,
+                body,
+            ))
+    }
+    /// The blocking version of [`crate::orders::Orders::deprecated_202007_create_param_refunds_request`].
+    pub fn deprecated_202007_create_param_refunds_request<'a>(
+        &'a self,
+        order_id: &'a str,
+        body: &'a serde_json::Value,
+    ) -> OrdersDeprecated202007CreateParamRefundsRequest<'a> {
+        OrdersDeprecated202007CreateParamRefundsRequest {
+            inner: self
+                .inner
+                .deprecated_202007_create_param_refunds_request(order_id, body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::orders::Orders::deprecated_202007_get_param_refunds_refund`].
+    pub fn deprecated_202007_get_param_refunds_refund(
+        &self,
+        order_id: &str,
+        refund_id: &str,
+        fields: &str,
+        in_shop_currency: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202007_get_param_refunds_refund(
+                order_id,
+                refund_id,
+                fields,
+                in_shop_currency,
+            ))
+    }
+    /// The blocking version of [`crate::orders::Orders::deprecated_202007_get_param_refunds_refund_request`].
+    pub fn deprecated_202007_get_param_refunds_refund_request<'a>(
+        &'a self,
+        order_id: &'a str,
+        refund_id: &'a str,
+    ) -> OrdersDeprecated202007GetParamRefundsRefundRequest<'a> {
+        OrdersDeprecated202007GetParamRefundsRefundRequest {
+            inner: self
+                .inner
+                .deprecated_202007_get_param_refunds_refund_request(order_id, refund_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::orders::Orders::deprecated_202007_create_param_refunds_calculate`].
+    pub fn deprecated_202007_create_param_refunds_calculate(
+        &self,
+        order_id: &str,
+        shipping: &str,
+        refund_line_items: &str,
+        currency: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202007_create_param_refunds_calculate(
+                order_id,
+                shipping,
+                refund_line_items,
+                currency,
+                body,
+            ))
+    }
+    /// The blocking version of [`crate::orders::Orders::deprecated_202007_create_param_refunds_calculate_request`].
+    pub fn deprecated_202007_create_param_refunds_calculate_request<'a>(
+        &'a self,
+        order_id: &'a str,
+        body: &'a serde_json::Value,
+    ) -> OrdersDeprecated202007CreateParamRefundsCalculateRequest<'a> {
+        OrdersDeprecated202007CreateParamRefundsCalculateRequest {
+            inner: self
+                .inner
+                .deprecated_202007_create_param_refunds_calculate_request(order_id, body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::orders::Orders::get_param_refund`].
+    pub fn get_param_refund(
+        &self,
+        order_id: &str,
+        limit: &str,
+        fields: &str,
+        in_shop_currency: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.get_param_refund(
+            order_id,
+            limit,
+            fields,
+            in_shop_currency,
+        ))
+    }
+    /// The blocking version of [`crate::orders::Orders::get_param_refund_request`].
+    pub fn get_param_refund_request<'a>(
+        &'a self,
+        order_id: &'a str,
+    ) -> OrdersGetParamRefundRequest<'a> {
+        OrdersGetParamRefundRequest {
+            inner: self.inner.get_param_refund_request(order_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::orders::Orders::create_param_refunds`].
+    pub fn create_param_refunds(
+        &self,
+        order_id: &str,
+        restock_deprecated: &str,
+        notify: &str,
+        note: &str,
+        discrepancy_reason: &str,
+        shipping: &str,
+        refund_line_items: &str,
+        transactions: &str,
+        currency: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.create_param_refunds(
+            order_id,
+            restock_deprecated,
+            notify,
+            note,
+            discrepancy_reason,
+            shipping,
+            refund_line_items,
+            transactions,
+            currency,
+            body,
+        ))
+    }
+    /// The blocking version of [`crate::orders::Orders::create_param_refunds_request`].
+    pub fn create_param_refunds_request<'a>(
+        &'a self,
+        order_id: &'a str,
+        body: &'a serde_json::Value,
+    ) -> OrdersCreateParamRefundsRequest<'a> {
+        OrdersCreateParamRefundsRequest {
+            inner: self.inner.create_param_refunds_request(order_id, body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::orders::Orders::get_param_refunds_refund`].
+    pub fn get_param_refunds_refund(
+        &self,
+        order_id: &str,
+        refund_id: &str,
+        fields: &str,
+        in_shop_currency: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.get_param_refunds_refund(
+            order_id,
+            refund_id,
+            fields,
+            in_shop_currency,
+        ))
+    }
+    /// The blocking version of [`crate::orders::Orders::get_param_refunds_refund_request`].
+    pub fn get_param_refunds_refund_request<'a>(
+        &'a self,
+        order_id: &'a str,
+        refund_id: &'a str,
+    ) -> OrdersGetParamRefundsRefundRequest<'a> {
+        OrdersGetParamRefundsRefundRequest {
+            inner: self
+                .inner
+                .get_param_refunds_refund_request(order_id, refund_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::orders::Orders::create_param_refunds_calculate`].
+    pub fn create_param_refunds_calculate(
+        &self,
+        order_id: &str,
+        shipping: &str,
+        refund_line_items: &str,
+        currency: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.create_param_refunds_calculate(
+                order_id,
+                shipping,
+                refund_line_items,
+                currency,
+                body,
+            ))
+    }
+    /// The blocking version of [`crate::orders::Orders::create_param_refunds_calculate_request`].
+    pub fn create_param_refunds_calculate_request<'a>(
+        &'a self,
+        order_id: &'a str,
+        body: &'a serde_json::Value,
+    ) -> OrdersCreateParamRefundsCalculateRequest<'a> {
+        OrdersCreateParamRefundsCalculateRequest {
+            inner: self
+                .inner
+                .create_param_refunds_calculate_request(order_id, body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::orders::Orders::deprecated_202101_get_param_refund`].
+    pub fn deprecated_202101_get_param_refund(
+        &self,
+        order_id: &str,
+        limit: &str,
+        fields: &str,
+        in_shop_currency: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202101_get_param_refund(
+                order_id,
+                limit,
+                fields,
+                in_shop_currency,
+            ))
+    }
+    /// The blocking version of [`crate::orders::Orders::deprecated_202101_get_param_refund_request`].
+    pub fn deprecated_202101_get_param_refund_request<'a>(
+        &'a self,
+        order_id: &'a str,
+    ) -> OrdersDeprecated202101GetParamRefundRequest<'a> {
+        OrdersDeprecated202101GetParamRefundRequest {
+            inner: self
+                .inner
+                .deprecated_202101_get_param_refund_request(order_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::orders::Orders::deprecated_202101_create_param_refunds`].
+    pub fn deprecated_202101_create_param_refunds(
+        &self,
+        order_id: &str,
+        restock_deprecated: &str,
+        notify: &str,
+        note: &str,
+        discrepancy_reason: &str,
+        shipping: &str,
+        refund_line_items: &str,
+        transactions: &str,
+        currency: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202101_create_param_refunds(
+                order_id,
+                restock_deprecated,
+                notify,
+                note,
+                discrepancy_reason,
+                shipping,
+                refund_line_items,
+                transactions,
+                currency,
+                body,
+            ))
+    }
+    /// The blocking version of [`crate::orders::Orders::deprecated_202101_create_param_refunds_request`].
+    pub fn deprecated_202101_create_param_refunds_request<'a>(
+        &'a self,
+        order_id: &'a str,
+        body: &'a serde_json::Value,
+    ) -> OrdersDeprecated202101CreateParamRefundsRequest<'a> {
+        OrdersDeprecated202101CreateParamRefundsRequest {
+            inner: self
+                .inner
+                .deprecated_202101_create_param_refunds_request(order_id, body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::orders::Orders::deprecated_202101_get_param_refunds_refund`].
+    pub fn deprecated_202101_get_param_refunds_refund(
+        &self,
+        order_id: &str,
+        refund_id: &str,
+        fields: &str,
+        in_shop_currency: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202101_get_param_refunds_refund(
+                order_id,
+                refund_id,
+                fields,
+                in_shop_currency,
+            ))
+    }
+    /// The blocking version of [`crate::orders::Orders::deprecated_202101_get_param_refunds_refund_request`].
+    pub fn deprecated_202101_get_param_refunds_refund_request<'a>(
+        &'a self,
+        order_id: &'a str,
+        refund_id: &'a str,
+    ) -> OrdersDeprecated202101GetParamRefundsRefundRequest<'a> {
+        OrdersDeprecated202101GetParamRefundsRefundRequest {
+            inner: self
+                .inner
+                .deprecated_202101_get_param_refunds_refund_request(order_id, refund_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::orders::Orders::deprecated_202101_create_param_refunds_calculate`].
+    pub fn deprecated_202101_create_param_refunds_calculate(
+        &self,
+        order_id: &str,
+        shipping: &str,
+        refund_line_items: &str,
+        currency: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202101_create_param_refunds_calculate(
+                order_id,
+                shipping,
+                refund_line_items,
+                currency,
+                body,
+            ))
+    }
+    /// The blocking version of [`crate::orders::Orders::deprecated_202101_create_param_refunds_calculate_request`].
+    pub fn deprecated_202101_create_param_refunds_calculate_request<'a>(
+        &'a self,
+        order_id: &'a str,
+        body: &'a serde_json::Value,
+    ) -> OrdersDeprecated202101CreateParamRefundsCalculateRequest<'a> {
+        OrdersDeprecated202101CreateParamRefundsCalculateRequest {
+            inner: self
+                .inner
+                .deprecated_202101_create_param_refunds_calculate_request(order_id, body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::orders::Orders::deprecated_unstable_get_param_refund`].
+    pub fn deprecated_unstable_get_param_refund(
+        &self,
+        order_id: &str,
+        limit: &str,
+        fields: &str,
+        in_shop_currency: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_unstable_get_param_refund(
+                order_id,
+                limit,
+                fields,
+                in_shop_currency,
+            ))
+    }
+    /// The blocking version of [`crate::orders::Orders::deprecated_unstable_get_param_refund_request`].
+    pub fn deprecated_unstable_get_param_refund_request<'a>(
+        &'a self,
+        order_id: &'a str,
+    ) -> OrdersDeprecatedUnstableGetParamRefundRequest<'a> {
+        OrdersDeprecatedUnstableGetParamRefundRequest {
+            inner: self
+                .inner
+                .deprecated_unstable_get_param_refund_request(order_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::orders::Orders::deprecated_unstable_create_param_refunds`].
+    pub fn deprecated_unstable_create_param_refunds(
+        &self,
+        order_id: &str,
+        restock_deprecated: &str,
+        notify: &str,
+        note: &str,
+        discrepancy_reason: &str,
+        shipping: &str,
+        refund_line_items: &str,
+        transactions: &str,
+        currency: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_unstable_create_param_refunds(
+                order_id,
+                restock_deprecated,
+                notify,
+                note,
+                discrepancy_reason,
+                shipping,
+                refund_line_items,
+                transactions,
+                currency,
+                body,
+            ))
+    }
+    /// The blocking version of [`crate::orders::Orders::deprecated_unstable_create_param_refunds_request`].
+    pub fn deprecated_unstable_create_param_refunds_request<'a>(
+        &'a self,
+        order_id: &'a str,
+        body: &'a serde_json::Value,
+    ) -> OrdersDeprecatedUnstableCreateParamRefundsRequest<'a> {
+        OrdersDeprecatedUnstableCreateParamRefundsRequest {
+            inner: self
+                .inner
+                .deprecated_unstable_create_param_refunds_request(order_id, body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::orders::Orders::deprecated_unstable_get_param_refunds_refund`].
+    pub fn deprecated_unstable_get_param_refunds_refund(
+        &self,
+        order_id: &str,
+        refund_id: &str,
+        fields: &str,
+        in_shop_currency: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_unstable_get_param_refunds_refund(
+                order_id,
+                refund_id,
+                fields,
+                in_shop_currency,
+            ))
+    }
+    /// The blocking version of [`crate::orders::Orders::deprecated_unstable_get_param_refunds_refund_request`].
+    pub fn deprecated_unstable_get_param_refunds_refund_request<'a>(
+        &'a self,
+        order_id: &'a str,
+        refund_id: &'a str,
+    ) -> OrdersDeprecatedUnstableGetParamRefundsRefundRequest<'a> {
+        OrdersDeprecatedUnstableGetParamRefundsRefundRequest {
+            inner: self
+                .inner
+                .deprecated_unstable_get_param_refunds_refund_request(order_id, refund_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::orders::Orders::deprecated_unstable_create_param_refunds_calculate`].
+    pub fn deprecated_unstable_create_param_refunds_calculate(
+        &self,
+        order_id: &str,
+        shipping: &str,
+        refund_line_items: &str,
+        currency: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_create_param_refunds_calculate(
+                    order_id,
+                    shipping,
+                    refund_line_items,
+                    currency,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::orders::Orders::deprecated_unstable_create_param_refunds_calculate_request`].
+    pub fn deprecated_unstable_create_param_refunds_calculate_request<'a>(
+        &'a self,
+        order_id: &'a str,
+        body: &'a serde_json::Value,
+    ) -> OrdersDeprecatedUnstableCreateParamRefundsCalculateRequest<'a> {
+        OrdersDeprecatedUnstableCreateParamRefundsCalculateRequest {
+            inner: self
+                .inner
+                .deprecated_unstable_create_param_refunds_calculate_request(order_id, body),
+            runtime: &self.runtime,
+        }
+    }
+}
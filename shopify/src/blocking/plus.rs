@@ -0,0 +1,1480 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Plus::deprecated_202001_get_gift_card`], set with a builder.
+pub struct PlusDeprecated202001GetGiftCardRequest<'a> {
+    inner: crate::plus::PlusDeprecated202001GetGiftCardRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> PlusDeprecated202001GetGiftCardRequest<'a> {
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: &'a str) -> Self {
+        self.inner = self.inner.status(status);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Plus::deprecated_202001_get_gift_cards_count`], set with a builder.
+pub struct PlusDeprecated202001GetGiftCardsCountRequest<'a> {
+    inner: crate::plus::PlusDeprecated202001GetGiftCardsCountRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> PlusDeprecated202001GetGiftCardsCountRequest<'a> {
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: &'a str) -> Self {
+        self.inner = self.inner.status(status);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Plus::deprecated_202001_get_gift_cards_search`], set with a builder.
+pub struct PlusDeprecated202001GetGiftCardsSearchRequest<'a> {
+    inner: crate::plus::PlusDeprecated202001GetGiftCardsSearchRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> PlusDeprecated202001GetGiftCardsSearchRequest<'a> {
+    /// Sets the `order` query parameter.
+    pub fn order(mut self, order: &'a str) -> Self {
+        self.inner = self.inner.order(order);
+        self
+    }
+
+    /// Sets the `query` query parameter.
+    pub fn query(mut self, query: &'a str) -> Self {
+        self.inner = self.inner.query(query);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Plus::deprecated_202004_get_gift_card`], set with a builder.
+pub struct PlusDeprecated202004GetGiftCardRequest<'a> {
+    inner: crate::plus::PlusDeprecated202004GetGiftCardRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> PlusDeprecated202004GetGiftCardRequest<'a> {
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: &'a str) -> Self {
+        self.inner = self.inner.status(status);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Plus::deprecated_202004_get_gift_cards_count`], set with a builder.
+pub struct PlusDeprecated202004GetGiftCardsCountRequest<'a> {
+    inner: crate::plus::PlusDeprecated202004GetGiftCardsCountRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> PlusDeprecated202004GetGiftCardsCountRequest<'a> {
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: &'a str) -> Self {
+        self.inner = self.inner.status(status);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Plus::deprecated_202004_get_gift_cards_search`], set with a builder.
+pub struct PlusDeprecated202004GetGiftCardsSearchRequest<'a> {
+    inner: crate::plus::PlusDeprecated202004GetGiftCardsSearchRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> PlusDeprecated202004GetGiftCardsSearchRequest<'a> {
+    /// Sets the `order` query parameter.
+    pub fn order(mut self, order: &'a str) -> Self {
+        self.inner = self.inner.order(order);
+        self
+    }
+
+    /// Sets the `query` query parameter.
+    pub fn query(mut self, query: &'a str) -> Self {
+        self.inner = self.inner.query(query);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Plus::deprecated_202007_get_gift_card`], set with a builder.
+pub struct PlusDeprecated202007GetGiftCardRequest<'a> {
+    inner: crate::plus::PlusDeprecated202007GetGiftCardRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> PlusDeprecated202007GetGiftCardRequest<'a> {
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: &'a str) -> Self {
+        self.inner = self.inner.status(status);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Plus::deprecated_202007_get_gift_cards_count`], set with a builder.
+pub struct PlusDeprecated202007GetGiftCardsCountRequest<'a> {
+    inner: crate::plus::PlusDeprecated202007GetGiftCardsCountRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> PlusDeprecated202007GetGiftCardsCountRequest<'a> {
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: &'a str) -> Self {
+        self.inner = self.inner.status(status);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Plus::deprecated_202007_get_gift_cards_search`], set with a builder.
+pub struct PlusDeprecated202007GetGiftCardsSearchRequest<'a> {
+    inner: crate::plus::PlusDeprecated202007GetGiftCardsSearchRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> PlusDeprecated202007GetGiftCardsSearchRequest<'a> {
+    /// Sets the `order` query parameter.
+    pub fn order(mut self, order: &'a str) -> Self {
+        self.inner = self.inner.order(order);
+        self
+    }
+
+    /// Sets the `query` query parameter.
+    pub fn query(mut self, query: &'a str) -> Self {
+        self.inner = self.inner.query(query);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Plus::get_gift_card`], set with a builder.
+pub struct PlusGetGiftCardRequest<'a> {
+    inner: crate::plus::PlusGetGiftCardRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> PlusGetGiftCardRequest<'a> {
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: &'a str) -> Self {
+        self.inner = self.inner.status(status);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Plus::get_gift_cards_count`], set with a builder.
+pub struct PlusGetGiftCardsCountRequest<'a> {
+    inner: crate::plus::PlusGetGiftCardsCountRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> PlusGetGiftCardsCountRequest<'a> {
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: &'a str) -> Self {
+        self.inner = self.inner.status(status);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Plus::get_gift_cards_search`], set with a builder.
+pub struct PlusGetGiftCardsSearchRequest<'a> {
+    inner: crate::plus::PlusGetGiftCardsSearchRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> PlusGetGiftCardsSearchRequest<'a> {
+    /// Sets the `order` query parameter.
+    pub fn order(mut self, order: &'a str) -> Self {
+        self.inner = self.inner.order(order);
+        self
+    }
+
+    /// Sets the `query` query parameter.
+    pub fn query(mut self, query: &'a str) -> Self {
+        self.inner = self.inner.query(query);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Plus::deprecated_202101_get_gift_card`], set with a builder.
+pub struct PlusDeprecated202101GetGiftCardRequest<'a> {
+    inner: crate::plus::PlusDeprecated202101GetGiftCardRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> PlusDeprecated202101GetGiftCardRequest<'a> {
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: &'a str) -> Self {
+        self.inner = self.inner.status(status);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Plus::deprecated_202101_get_gift_cards_count`], set with a builder.
+pub struct PlusDeprecated202101GetGiftCardsCountRequest<'a> {
+    inner: crate::plus::PlusDeprecated202101GetGiftCardsCountRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> PlusDeprecated202101GetGiftCardsCountRequest<'a> {
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: &'a str) -> Self {
+        self.inner = self.inner.status(status);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Plus::deprecated_202101_get_gift_cards_search`], set with a builder.
+pub struct PlusDeprecated202101GetGiftCardsSearchRequest<'a> {
+    inner: crate::plus::PlusDeprecated202101GetGiftCardsSearchRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> PlusDeprecated202101GetGiftCardsSearchRequest<'a> {
+    /// Sets the `order` query parameter.
+    pub fn order(mut self, order: &'a str) -> Self {
+        self.inner = self.inner.order(order);
+        self
+    }
+
+    /// Sets the `query` query parameter.
+    pub fn query(mut self, query: &'a str) -> Self {
+        self.inner = self.inner.query(query);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Plus::deprecated_unstable_get_gift_card`], set with a builder.
+pub struct PlusDeprecatedUnstableGetGiftCardRequest<'a> {
+    inner: crate::plus::PlusDeprecatedUnstableGetGiftCardRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> PlusDeprecatedUnstableGetGiftCardRequest<'a> {
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: &'a str) -> Self {
+        self.inner = self.inner.status(status);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Plus::deprecated_unstable_get_gift_cards_count`], set with a builder.
+pub struct PlusDeprecatedUnstableGetGiftCardsCountRequest<'a> {
+    inner: crate::plus::PlusDeprecatedUnstableGetGiftCardsCountRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> PlusDeprecatedUnstableGetGiftCardsCountRequest<'a> {
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: &'a str) -> Self {
+        self.inner = self.inner.status(status);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Plus::deprecated_unstable_get_gift_cards_search`], set with a builder.
+pub struct PlusDeprecatedUnstableGetGiftCardsSearchRequest<'a> {
+    inner: crate::plus::PlusDeprecatedUnstableGetGiftCardsSearchRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> PlusDeprecatedUnstableGetGiftCardsSearchRequest<'a> {
+    /// Sets the `order` query parameter.
+    pub fn order(mut self, order: &'a str) -> Self {
+        self.inner = self.inner.order(order);
+        self
+    }
+
+    /// Sets the `query` query parameter.
+    pub fn query(mut self, query: &'a str) -> Self {
+        self.inner = self.inner.query(query);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Plus::deprecated_202001_get_user`], set with a builder.
+pub struct PlusDeprecated202001GetUserRequest<'a> {
+    inner: crate::plus::PlusDeprecated202001GetUserRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> PlusDeprecated202001GetUserRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `page_info` query parameter.
+    pub fn page_info(mut self, page_info: &'a str) -> Self {
+        self.inner = self.inner.page_info(page_info);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Plus::deprecated_202004_get_user`], set with a builder.
+pub struct PlusDeprecated202004GetUserRequest<'a> {
+    inner: crate::plus::PlusDeprecated202004GetUserRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> PlusDeprecated202004GetUserRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `page_info` query parameter.
+    pub fn page_info(mut self, page_info: &'a str) -> Self {
+        self.inner = self.inner.page_info(page_info);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Plus::deprecated_202007_get_user`], set with a builder.
+pub struct PlusDeprecated202007GetUserRequest<'a> {
+    inner: crate::plus::PlusDeprecated202007GetUserRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> PlusDeprecated202007GetUserRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `page_info` query parameter.
+    pub fn page_info(mut self, page_info: &'a str) -> Self {
+        self.inner = self.inner.page_info(page_info);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Plus::get_user`], set with a builder.
+pub struct PlusGetUserRequest<'a> {
+    inner: crate::plus::PlusGetUserRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> PlusGetUserRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `page_info` query parameter.
+    pub fn page_info(mut self, page_info: &'a str) -> Self {
+        self.inner = self.inner.page_info(page_info);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Plus::deprecated_202101_get_user`], set with a builder.
+pub struct PlusDeprecated202101GetUserRequest<'a> {
+    inner: crate::plus::PlusDeprecated202101GetUserRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> PlusDeprecated202101GetUserRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `page_info` query parameter.
+    pub fn page_info(mut self, page_info: &'a str) -> Self {
+        self.inner = self.inner.page_info(page_info);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Plus::deprecated_unstable_get_user`], set with a builder.
+pub struct PlusDeprecatedUnstableGetUserRequest<'a> {
+    inner: crate::plus::PlusDeprecatedUnstableGetUserRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> PlusDeprecatedUnstableGetUserRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `page_info` query parameter.
+    pub fn page_info(mut self, page_info: &'a str) -> Self {
+        self.inner = self.inner.page_info(page_info);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::plus::Plus`].
+pub struct Plus {
+    inner: crate::plus::Plus,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Plus {
+    pub(crate) fn new(inner: crate::plus::Plus, runtime: Arc<tokio::runtime::Runtime>) -> Self {
+        Plus { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::plus::Plus::deprecated_202001_get_gift_card`].
+    pub fn deprecated_202001_get_gift_card(
+        &self,
+        status: &str,
+        limit: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_get_gift_card(status, limit, since_id, fields),
+        )
+    }
+    /// The blocking version of [`crate::plus::Plus::deprecated_202001_get_gift_card_request`].
+    pub fn deprecated_202001_get_gift_card_request(
+        &self,
+    ) -> PlusDeprecated202001GetGiftCardRequest<'_> {
+        PlusDeprecated202001GetGiftCardRequest {
+            inner: self.inner.deprecated_202001_get_gift_card_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::plus::Plus::deprecated_202001_create_gift_cards`].
+    pub fn deprecated_202001_create_gift_cards(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202001_create_gift_cards(body))
+    }
+    /// The blocking version of [`crate::plus::Plus::deprecated_202001_get_gift_cards_param_card`].
+    pub fn deprecated_202001_get_gift_cards_param_card(
+        &self,
+        gift_card_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_get_gift_cards_param_card(gift_card_id),
+        )
+    }
+    /// The blocking version of [`crate::plus::Plus::deprecated_202001_update_gift_cards_param_card`].
+    pub fn deprecated_202001_update_gift_cards_param_card(
+        &self,
+        gift_card_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_update_gift_cards_param_card(gift_card_id, body),
+        )
+    }
+    /// The blocking version of [`crate::plus::Plus::deprecated_202001_get_gift_cards_count`].
+    pub fn deprecated_202001_get_gift_cards_count(
+        &self,
+        status: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202001_get_gift_cards_count(status))
+    }
+    /// The blocking version of [`crate::plus::Plus::deprecated_202001_get_gift_cards_count_request`].
+    pub fn deprecated_202001_get_gift_cards_count_request(
+        &self,
+    ) -> PlusDeprecated202001GetGiftCardsCountRequest<'_> {
+        PlusDeprecated202001GetGiftCardsCountRequest {
+            inner: self.inner.deprecated_202001_get_gift_cards_count_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::plus::Plus::deprecated_202001_create_gift_cards_param_card_disable`].
+    pub fn deprecated_202001_create_gift_cards_param_card_disable(
+        &self,
+        gift_card_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_create_gift_cards_param_card_disable(gift_card_id, body),
+        )
+    }
+    /// The blocking version of [`crate::plus::Plus::deprecated_202001_get_gift_cards_search`].
+    pub fn deprecated_202001_get_gift_cards_search(
+        &self,
+        order: &str,
+        query: &str,
+        limit: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_get_gift_cards_search(order, query, limit, fields),
+        )
+    }
+    /// The blocking version of [`crate::plus::Plus::deprecated_202001_get_gift_cards_search_request`].
+    pub fn deprecated_202001_get_gift_cards_search_request(
+        &self,
+    ) -> PlusDeprecated202001GetGiftCardsSearchRequest<'_> {
+        PlusDeprecated202001GetGiftCardsSearchRequest {
+            inner: self.inner.deprecated_202001_get_gift_cards_search_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::plus::Plus::deprecated_202004_get_gift_card`].
+    pub fn deprecated_202004_get_gift_card(
+        &self,
+        status: &str,
+        limit: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_get_gift_card(status, limit, since_id, fields),
+        )
+    }
+    /// The blocking version of [`crate::plus::Plus::deprecated_202004_get_gift_card_request`].
+    pub fn deprecated_202004_get_gift_card_request(
+        &self,
+    ) -> PlusDeprecated202004GetGiftCardRequest<'_> {
+        PlusDeprecated202004GetGiftCardRequest {
+            inner: self.inner.deprecated_202004_get_gift_card_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::plus::Plus::deprecated_202004_create_gift_cards`].
+    pub fn deprecated_202004_create_gift_cards(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202004_create_gift_cards(body))
+    }
+    /// The blocking version of [`crate::plus::Plus::deprecated_202004_get_gift_cards_param_card`].
+    pub fn deprecated_202004_get_gift_cards_param_card(
+        &self,
+        gift_card_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_get_gift_cards_param_card(gift_card_id),
+        )
+    }
+    /// The blocking version of [`crate::plus::Plus::deprecated_202004_update_gift_cards_param_card`].
+    pub fn deprecated_202004_update_gift_cards_param_card(
+        &self,
+        gift_card_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_update_gift_cards_param_card(gift_card_id, body),
+        )
+    }
+    /// The blocking version of [`crate::plus::Plus::deprecated_202004_get_gift_cards_count`].
+    pub fn deprecated_202004_get_gift_cards_count(
+        &self,
+        status: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202004_get_gift_cards_count(status))
+    }
+    /// The blocking version of [`crate::plus::Plus::deprecated_202004_get_gift_cards_count_request`].
+    pub fn deprecated_202004_get_gift_cards_count_request(
+        &self,
+    ) -> PlusDeprecated202004GetGiftCardsCountRequest<'_> {
+        PlusDeprecated202004GetGiftCardsCountRequest {
+            inner: self.inner.deprecated_202004_get_gift_cards_count_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::plus::Plus::deprecated_202004_create_gift_cards_param_card_disable`].
+    pub fn deprecated_202004_create_gift_cards_param_card_disable(
+        &self,
+        gift_card_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_create_gift_cards_param_card_disable(gift_card_id, body),
+        )
+    }
+    /// The blocking version of [`crate::plus::Plus::deprecated_202004_get_gift_cards_search`].
+    pub fn deprecated_202004_get_gift_cards_search(
+        &self,
+        order: &str,
+        query: &str,
+        limit: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_get_gift_cards_search(order, query, limit, fields),
+        )
+    }
+    /// The blocking version of [`crate::plus::Plus::deprecated_202004_get_gift_cards_search_request`].
+    pub fn deprecated_202004_get_gift_cards_search_request(
+        &self,
+    ) -> PlusDeprecated202004GetGiftCardsSearchRequest<'_> {
+        PlusDeprecated202004GetGiftCardsSearchRequest {
+            inner: self.inner.deprecated_202004_get_gift_cards_search_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::plus::Plus::deprecated_202007_get_gift_card`].
+    pub fn deprecated_202007_get_gift_card(
+        &self,
+        status: &str,
+        limit: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_get_gift_card(status, limit, since_id, fields),
+        )
+    }
+    /// The blocking version of [`crate::plus::Plus::deprecated_202007_get_gift_card_request`].
+    pub fn deprecated_202007_get_gift_card_request(
+        &self,
+    ) -> PlusDeprecated202007GetGiftCardRequest<'_> {
+        PlusDeprecated202007GetGiftCardRequest {
+            inner: self.inner.deprecated_202007_get_gift_card_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::plus::Plus::deprecated_202007_create_gift_cards`].
+    pub fn deprecated_202007_create_gift_cards(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202007_create_gift_cards(body))
+    }
+    /// The blocking version of [`crate::plus::Plus::deprecated_202007_get_gift_cards_param_card`].
+    pub fn deprecated_202007_get_gift_cards_param_card(
+        &self,
+        gift_card_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_get_gift_cards_param_card(gift_card_id),
+        )
+    }
+    /// The blocking version of [`crate::plus::Plus::deprecated_202007_update_gift_cards_param_card`].
+    pub fn deprecated_202007_update_gift_cards_param_card(
+        &self,
+        gift_card_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_update_gift_cards_param_card(gift_card_id, body),
+        )
+    }
+    /// The blocking version of [`crate::plus::Plus::deprecated_202007_get_gift_cards_count`].
+    pub fn deprecated_202007_get_gift_cards_count(
+        &self,
+        status: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202007_get_gift_cards_count(status))
+    }
+    /// The blocking version of [`crate::plus::Plus::deprecated_202007_get_gift_cards_count_request`].
+    pub fn deprecated_202007_get_gift_cards_count_request(
+        &self,
+    ) -> PlusDeprecated202007GetGiftCardsCountRequest<'_> {
+        PlusDeprecated202007GetGiftCardsCountRequest {
+            inner: self.inner.deprecated_202007_get_gift_cards_count_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::plus::Plus::deprecated_202007_create_gift_cards_param_card_disable`].
+    pub fn deprecated_202007_create_gift_cards_param_card_disable(
+        &self,
+        gift_card_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_create_gift_cards_param_card_disable(gift_card_id, body),
+        )
+    }
+    /// The blocking version of [`crate::plus::Plus::deprecated_202007_get_gift_cards_search`].
+    pub fn deprecated_202007_get_gift_cards_search(
+        &self,
+        order: &str,
+        query: &str,
+        limit: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_get_gift_cards_search(order, query, limit, fields),
+        )
+    }
+    /// The blocking version of [`crate::plus::Plus::deprecated_202007_get_gift_cards_search_request`].
+    pub fn deprecated_202007_get_gift_cards_search_request(
+        &self,
+    ) -> PlusDeprecated202007GetGiftCardsSearchRequest<'_> {
+        PlusDeprecated202007GetGiftCardsSearchRequest {
+            inner: self.inner.deprecated_202007_get_gift_cards_search_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::plus::Plus::get_gift_card`].
+    pub fn get_gift_card(
+        &self,
+        status: &str,
+        limit: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.get_gift_card(status, limit, since_id, fields))
+    }
+    /// The blocking version of [`crate::plus::Plus::get_gift_card_request`].
+    pub fn get_gift_card_request(&self) -> PlusGetGiftCardRequest<'_> {
+        PlusGetGiftCardRequest {
+            inner: self.inner.get_gift_card_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::plus::Plus::create_gift_cards`].
+    pub fn create_gift_cards(&self, body: &serde_json::Value) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.create_gift_cards(body))
+    }
+    /// The blocking version of [`crate::plus::Plus::get_gift_cards_param_card`].
+    pub fn get_gift_cards_param_card(
+        &self,
+        gift_card_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.get_gift_cards_param_card(gift_card_id))
+    }
+    /// The blocking version of [`crate::plus::Plus::update_gift_cards_param_card`].
+    pub fn update_gift_cards_param_card(
+        &self,
+        gift_card_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.update_gift_cards_param_card(gift_card_id, body))
+    }
+    /// The blocking version of [`crate::plus::Plus::get_gift_cards_count`].
+    pub fn get_gift_cards_count(&self, status: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.get_gift_cards_count(status))
+    }
+    /// The blocking version of [`crate::plus::Plus::get_gift_cards_count_request`].
+    pub fn get_gift_cards_count_request(&self) -> PlusGetGiftCardsCountRequest<'_> {
+        PlusGetGiftCardsCountRequest {
+            inner: self.inner.get_gift_cards_count_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::plus::Plus::create_gift_cards_param_card_disable`].
+    pub fn create_gift_cards_param_card_disable(
+        &self,
+        gift_card_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .create_gift_cards_param_card_disable(gift_card_id, body),
+        )
+    }
+    /// The blocking version of [`crate::plus::Plus::get_gift_cards_search`].
+    pub fn get_gift_cards_search(
+        &self,
+        order: &str,
+        query: &str,
+        limit: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .get_gift_cards_search(order, query, limit, fields),
+        )
+    }
+    /// The blocking version of [`crate::plus::Plus::get_gift_cards_search_request`].
+    pub fn get_gift_cards_search_request(&self) -> PlusGetGiftCardsSearchRequest<'_> {
+        PlusGetGiftCardsSearchRequest {
+            inner: self.inner.get_gift_cards_search_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::plus::Plus::deprecated_202101_get_gift_card`].
+    pub fn deprecated_202101_get_gift_card(
+        &self,
+        status: &str,
+        limit: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_get_gift_card(status, limit, since_id, fields),
+        )
+    }
+    /// The blocking version of [`crate::plus::Plus::deprecated_202101_get_gift_card_request`].
+    pub fn deprecated_202101_get_gift_card_request(
+        &self,
+    ) -> PlusDeprecated202101GetGiftCardRequest<'_> {
+        PlusDeprecated202101GetGiftCardRequest {
+            inner: self.inner.deprecated_202101_get_gift_card_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::plus::Plus::deprecated_202101_create_gift_cards`].
+    pub fn deprecated_202101_create_gift_cards(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202101_create_gift_cards(body))
+    }
+    /// The blocking version of [`crate::plus::Plus::deprecated_202101_get_gift_cards_param_card`].
+    pub fn deprecated_202101_get_gift_cards_param_card(
+        &self,
+        gift_card_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_get_gift_cards_param_card(gift_card_id),
+        )
+    }
+    /// The blocking version of [`crate::plus::Plus::deprecated_202101_update_gift_cards_param_card`].
+    pub fn deprecated_202101_update_gift_cards_param_card(
+        &self,
+        gift_card_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_update_gift_cards_param_card(gift_card_id, body),
+        )
+    }
+    /// The blocking version of [`crate::plus::Plus::deprecated_202101_get_gift_cards_count`].
+    pub fn deprecated_202101_get_gift_cards_count(
+        &self,
+        status: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202101_get_gift_cards_count(status))
+    }
+    /// The blocking version of [`crate::plus::Plus::deprecated_202101_get_gift_cards_count_request`].
+    pub fn deprecated_202101_get_gift_cards_count_request(
+        &self,
+    ) -> PlusDeprecated202101GetGiftCardsCountRequest<'_> {
+        PlusDeprecated202101GetGiftCardsCountRequest {
+            inner: self.inner.deprecated_202101_get_gift_cards_count_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::plus::Plus::deprecated_202101_create_gift_cards_param_card_disable`].
+    pub fn deprecated_202101_create_gift_cards_param_card_disable(
+        &self,
+        gift_card_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_create_gift_cards_param_card_disable(gift_card_id, body),
+        )
+    }
+    /// The blocking version of [`crate::plus::Plus::deprecated_202101_get_gift_cards_search`].
+    pub fn deprecated_202101_get_gift_cards_search(
+        &self,
+        order: &str,
+        query: &str,
+        limit: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_get_gift_cards_search(order, query, limit, fields),
+        )
+    }
+    /// The blocking version of [`crate::plus::Plus::deprecated_202101_get_gift_cards_search_request`].
+    pub fn deprecated_202101_get_gift_cards_search_request(
+        &self,
+    ) -> PlusDeprecated202101GetGiftCardsSearchRequest<'_> {
+        PlusDeprecated202101GetGiftCardsSearchRequest {
+            inner: self.inner.deprecated_202101_get_gift_cards_search_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::plus::Plus::deprecated_unstable_get_gift_card`].
+    pub fn deprecated_unstable_get_gift_card(
+        &self,
+        status: &str,
+        limit: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_get_gift_card(status, limit, since_id, fields),
+        )
+    }
+    /// The blocking version of [`crate::plus::Plus::deprecated_unstable_get_gift_card_request`].
+    pub fn deprecated_unstable_get_gift_card_request(
+        &self,
+    ) -> PlusDeprecatedUnstableGetGiftCardRequest<'_> {
+        PlusDeprecatedUnstableGetGiftCardRequest {
+            inner: self.inner.deprecated_unstable_get_gift_card_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::plus::Plus::deprecated_unstable_create_gift_cards`].
+    pub fn deprecated_unstable_create_gift_cards(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_unstable_create_gift_cards(body))
+    }
+    /// The blocking version of [`crate::plus::Plus::deprecated_unstable_get_gift_cards_param_card`].
+    pub fn deprecated_unstable_get_gift_cards_param_card(
+        &self,
+        gift_card_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_get_gift_cards_param_card(gift_card_id),
+        )
+    }
+    /// The blocking version of [`crate::plus::Plus::deprecated_unstable_update_gift_cards_param_card`].
+    pub fn deprecated_unstable_update_gift_cards_param_card(
+        &self,
+        gift_card_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_update_gift_cards_param_card(gift_card_id, body),
+        )
+    }
+    /// The blocking version of [`crate::plus::Plus::deprecated_unstable_get_gift_cards_count`].
+    pub fn deprecated_unstable_get_gift_cards_count(
+        &self,
+        status: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_unstable_get_gift_cards_count(status))
+    }
+    /// The blocking version of [`crate::plus::Plus::deprecated_unstable_get_gift_cards_count_request`].
+    pub fn deprecated_unstable_get_gift_cards_count_request(
+        &self,
+    ) -> PlusDeprecatedUnstableGetGiftCardsCountRequest<'_> {
+        PlusDeprecatedUnstableGetGiftCardsCountRequest {
+            inner: self
+                .inner
+                .deprecated_unstable_get_gift_cards_count_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::plus::Plus::deprecated_unstable_create_gift_cards_param_card_disable`].
+    pub fn deprecated_unstable_create_gift_cards_param_card_disable(
+        &self,
+        gift_card_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_create_gift_cards_param_card_disable(gift_card_id, body),
+        )
+    }
+    /// The blocking version of [`crate::plus::Plus::deprecated_unstable_get_gift_cards_search`].
+    pub fn deprecated_unstable_get_gift_cards_search(
+        &self,
+        order: &str,
+        query: &str,
+        limit: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_get_gift_cards_search(order, query, limit, fields),
+        )
+    }
+    /// The blocking version of [`crate::plus::Plus::deprecated_unstable_get_gift_cards_search_request`].
+    pub fn deprecated_unstable_get_gift_cards_search_request(
+        &self,
+    ) -> PlusDeprecatedUnstableGetGiftCardsSearchRequest<'_> {
+        PlusDeprecatedUnstableGetGiftCardsSearchRequest {
+            inner: self
+                .inner
+                .deprecated_unstable_get_gift_cards_search_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::plus::Plus::deprecated_202001_get_user`].
+    pub fn deprecated_202001_get_user(
+        &self,
+        limit: &str,
+        page_info: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202001_get_user(limit, page_info))
+    }
+    /// The blocking version of [`crate::plus::Plus::deprecated_202001_get_user_request`].
+    pub fn deprecated_202001_get_user_request(&self) -> PlusDeprecated202001GetUserRequest<'_> {
+        PlusDeprecated202001GetUserRequest {
+            inner: self.inner.deprecated_202001_get_user_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::plus::Plus::deprecated_202001_get_users_param_user`].
+    pub fn deprecated_202001_get_users_param_user(
+        &self,
+        user_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202001_get_users_param_user(user_id))
+    }
+    /// The blocking version of [`crate::plus::Plus::deprecated_202001_get_users_current`].
+    pub fn deprecated_202001_get_users_current(&self) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202001_get_users_current())
+    }
+    /// The blocking version of [`crate::plus::Plus::deprecated_202004_get_user`].
+    pub fn deprecated_202004_get_user(
+        &self,
+        limit: &str,
+        page_info: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202004_get_user(limit, page_info))
+    }
+    /// The blocking version of [`crate::plus::Plus::deprecated_202004_get_user_request`].
+    pub fn deprecated_202004_get_user_request(&self) -> PlusDeprecated202004GetUserRequest<'_> {
+        PlusDeprecated202004GetUserRequest {
+            inner: self.inner.deprecated_202004_get_user_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::plus::Plus::deprecated_202004_get_users_param_user`].
+    pub fn deprecated_202004_get_users_param_user(
+        &self,
+        user_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202004_get_users_param_user(user_id))
+    }
+    /// The blocking version of [`crate::plus::Plus::deprecated_202004_get_users_current`].
+    pub fn deprecated_202004_get_users_current(&self) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202004_get_users_current())
+    }
+    /// The blocking version of [`crate::plus::Plus::deprecated_202007_get_user`].
+    pub fn deprecated_202007_get_user(
+        &self,
+        limit: &str,
+        page_info: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202007_get_user(limit, page_info))
+    }
+    /// The blocking version of [`crate::plus::Plus::deprecated_202007_get_user_request`].
+    pub fn deprecated_202007_get_user_request(&self) -> PlusDeprecated202007GetUserRequest<'_> {
+        PlusDeprecated202007GetUserRequest {
+            inner: self.inner.deprecated_202007_get_user_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::plus::Plus::deprecated_202007_get_users_param_user`].
+    pub fn deprecated_202007_get_users_param_user(
+        &self,
+        user_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202007_get_users_param_user(user_id))
+    }
+    /// The blocking version of [`crate::plus::Plus::deprecated_202007_get_users_current`].
+    pub fn deprecated_202007_get_users_current(&self) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202007_get_users_current())
+    }
+    /// The blocking version of [`crate::plus::Plus::get_user`].
+    pub fn get_user(&self, limit: &str, page_info: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.get_user(limit, page_info))
+    }
+    /// The blocking version of [`crate::plus::Plus::get_user_request`].
+    pub fn get_user_request(&self) -> PlusGetUserRequest<'_> {
+        PlusGetUserRequest {
+            inner: self.inner.get_user_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::plus::Plus::get_users_param_user`].
+    pub fn get_users_param_user(&self, user_id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.get_users_param_user(user_id))
+    }
+    /// The blocking version of [`crate::plus::Plus::get_users_current`].
+    pub fn get_users_current(&self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.get_users_current())
+    }
+    /// The blocking version of [`crate::plus::Plus::deprecated_202101_get_user`].
+    pub fn deprecated_202101_get_user(
+        &self,
+        limit: &str,
+        page_info: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202101_get_user(limit, page_info))
+    }
+    /// The blocking version of [`crate::plus::Plus::deprecated_202101_get_user_request`].
+    pub fn deprecated_202101_get_user_request(&self) -> PlusDeprecated202101GetUserRequest<'_> {
+        PlusDeprecated202101GetUserRequest {
+            inner: self.inner.deprecated_202101_get_user_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::plus::Plus::deprecated_202101_get_users_param_user`].
+    pub fn deprecated_202101_get_users_param_user(
+        &self,
+        user_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202101_get_users_param_user(user_id))
+    }
+    /// The blocking version of [`crate::plus::Plus::deprecated_202101_get_users_current`].
+    pub fn deprecated_202101_get_users_current(&self) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202101_get_users_current())
+    }
+    /// The blocking version of [`crate::plus::Plus::deprecated_unstable_get_user`].
+    pub fn deprecated_unstable_get_user(
+        &self,
+        limit: &str,
+        page_info: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_unstable_get_user(limit, page_info))
+    }
+    /// The blocking version of [`crate::plus::Plus::deprecated_unstable_get_user_request`].
+    pub fn deprecated_unstable_get_user_request(&self) -> PlusDeprecatedUnstableGetUserRequest<'_> {
+        PlusDeprecatedUnstableGetUserRequest {
+            inner: self.inner.deprecated_unstable_get_user_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::plus::Plus::deprecated_unstable_get_users_param_user`].
+    pub fn deprecated_unstable_get_users_param_user(
+        &self,
+        user_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_unstable_get_users_param_user(user_id))
+    }
+    /// The blocking version of [`crate::plus::Plus::deprecated_unstable_get_users_current`].
+    pub fn deprecated_unstable_get_users_current(&self) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_unstable_get_users_current())
+    }
+}
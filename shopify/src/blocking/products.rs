@@ -0,0 +1,8324 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Products::deprecated_202001_get_collect`], set with a builder.
+pub struct ProductsDeprecated202001GetCollectRequest<'a> {
+    inner: crate::products::ProductsDeprecated202001GetCollectRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProductsDeprecated202001GetCollectRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Products::deprecated_202001_get_collects_param_collect`], set with a builder.
+pub struct ProductsDeprecated202001GetCollectsParamCollectRequest<'a> {
+    inner: crate::products::ProductsDeprecated202001GetCollectsParamCollectRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProductsDeprecated202001GetCollectsParamCollectRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Products::deprecated_202001_get_collects_count`], set with a builder.
+pub struct ProductsDeprecated202001GetCollectsCountRequest<'a> {
+    inner: crate::products::ProductsDeprecated202001GetCollectsCountRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProductsDeprecated202001GetCollectsCountRequest<'a> {
+    /// Sets the `collection_id` query parameter.
+    pub fn collection_id(mut self, collection_id: i64) -> Self {
+        self.inner = self.inner.collection_id(collection_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Products::deprecated_202004_get_collect`], set with a builder.
+pub struct ProductsDeprecated202004GetCollectRequest<'a> {
+    inner: crate::products::ProductsDeprecated202004GetCollectRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProductsDeprecated202004GetCollectRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Products::deprecated_202004_get_collects_param_collect`], set with a builder.
+pub struct ProductsDeprecated202004GetCollectsParamCollectRequest<'a> {
+    inner: crate::products::ProductsDeprecated202004GetCollectsParamCollectRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProductsDeprecated202004GetCollectsParamCollectRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Products::deprecated_202004_get_collects_count`], set with a builder.
+pub struct ProductsDeprecated202004GetCollectsCountRequest<'a> {
+    inner: crate::products::ProductsDeprecated202004GetCollectsCountRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProductsDeprecated202004GetCollectsCountRequest<'a> {
+    /// Sets the `collection_id` query parameter.
+    pub fn collection_id(mut self, collection_id: i64) -> Self {
+        self.inner = self.inner.collection_id(collection_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Products::deprecated_202007_get_collect`], set with a builder.
+pub struct ProductsDeprecated202007GetCollectRequest<'a> {
+    inner: crate::products::ProductsDeprecated202007GetCollectRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProductsDeprecated202007GetCollectRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Products::deprecated_202007_get_collects_param_collect`], set with a builder.
+pub struct ProductsDeprecated202007GetCollectsParamCollectRequest<'a> {
+    inner: crate::products::ProductsDeprecated202007GetCollectsParamCollectRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProductsDeprecated202007GetCollectsParamCollectRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Products::deprecated_202007_get_collects_count`], set with a builder.
+pub struct ProductsDeprecated202007GetCollectsCountRequest<'a> {
+    inner: crate::products::ProductsDeprecated202007GetCollectsCountRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProductsDeprecated202007GetCollectsCountRequest<'a> {
+    /// Sets the `collection_id` query parameter.
+    pub fn collection_id(mut self, collection_id: i64) -> Self {
+        self.inner = self.inner.collection_id(collection_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Products::get_collect`], set with a builder.
+pub struct ProductsGetCollectRequest<'a> {
+    inner: crate::products::ProductsGetCollectRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProductsGetCollectRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Products::get_collects_param_collect`], set with a builder.
+pub struct ProductsGetCollectsParamCollectRequest<'a> {
+    inner: crate::products::ProductsGetCollectsParamCollectRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProductsGetCollectsParamCollectRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Products::get_collects_count`], set with a builder.
+pub struct ProductsGetCollectsCountRequest<'a> {
+    inner: crate::products::ProductsGetCollectsCountRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProductsGetCollectsCountRequest<'a> {
+    /// Sets the `collection_id` query parameter.
+    pub fn collection_id(mut self, collection_id: i64) -> Self {
+        self.inner = self.inner.collection_id(collection_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Products::deprecated_202101_get_collect`], set with a builder.
+pub struct ProductsDeprecated202101GetCollectRequest<'a> {
+    inner: crate::products::ProductsDeprecated202101GetCollectRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProductsDeprecated202101GetCollectRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Products::deprecated_202101_get_collects_param_collect`], set with a builder.
+pub struct ProductsDeprecated202101GetCollectsParamCollectRequest<'a> {
+    inner: crate::products::ProductsDeprecated202101GetCollectsParamCollectRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProductsDeprecated202101GetCollectsParamCollectRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Products::deprecated_202101_get_collects_count`], set with a builder.
+pub struct ProductsDeprecated202101GetCollectsCountRequest<'a> {
+    inner: crate::products::ProductsDeprecated202101GetCollectsCountRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProductsDeprecated202101GetCollectsCountRequest<'a> {
+    /// Sets the `collection_id` query parameter.
+    pub fn collection_id(mut self, collection_id: i64) -> Self {
+        self.inner = self.inner.collection_id(collection_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Products::deprecated_unstable_get_collect`], set with a builder.
+pub struct ProductsDeprecatedUnstableGetCollectRequest<'a> {
+    inner: crate::products::ProductsDeprecatedUnstableGetCollectRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProductsDeprecatedUnstableGetCollectRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Products::deprecated_unstable_get_collects_param_collect`], set with a builder.
+pub struct ProductsDeprecatedUnstableGetCollectsParamCollectRequest<'a> {
+    inner: crate::products::ProductsDeprecatedUnstableGetCollectsParamCollectRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProductsDeprecatedUnstableGetCollectsParamCollectRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Products::deprecated_unstable_get_collects_count`], set with a builder.
+pub struct ProductsDeprecatedUnstableGetCollectsCountRequest<'a> {
+    inner: crate::products::ProductsDeprecatedUnstableGetCollectsCountRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProductsDeprecatedUnstableGetCollectsCountRequest<'a> {
+    /// Sets the `collection_id` query parameter.
+    pub fn collection_id(mut self, collection_id: i64) -> Self {
+        self.inner = self.inner.collection_id(collection_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Products::deprecated_202001_get_collections_param_collection`], set with a builder.
+pub struct ProductsDeprecated202001GetCollectionsParamCollectionRequest<'a> {
+    inner: crate::products::ProductsDeprecated202001GetCollectionsParamCollectionRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProductsDeprecated202001GetCollectionsParamCollectionRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Products::deprecated_202001_get_collections_param_collection_products`], set with a builder.
+pub struct ProductsDeprecated202001GetCollectionsParamCollectionProductsRequest<'a> {
+    inner:
+        crate::products::ProductsDeprecated202001GetCollectionsParamCollectionProductsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProductsDeprecated202001GetCollectionsParamCollectionProductsRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Products::deprecated_202004_get_collections_param_collection`], set with a builder.
+pub struct ProductsDeprecated202004GetCollectionsParamCollectionRequest<'a> {
+    inner: crate::products::ProductsDeprecated202004GetCollectionsParamCollectionRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProductsDeprecated202004GetCollectionsParamCollectionRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Products::deprecated_202004_get_collections_param_collection_products`], set with a builder.
+pub struct ProductsDeprecated202004GetCollectionsParamCollectionProductsRequest<'a> {
+    inner:
+        crate::products::ProductsDeprecated202004GetCollectionsParamCollectionProductsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProductsDeprecated202004GetCollectionsParamCollectionProductsRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Products::deprecated_202007_get_collections_param_collection`], set with a builder.
+pub struct ProductsDeprecated202007GetCollectionsParamCollectionRequest<'a> {
+    inner: crate::products::ProductsDeprecated202007GetCollectionsParamCollectionRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProductsDeprecated202007GetCollectionsParamCollectionRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Products::deprecated_202007_get_collections_param_collection_products`], set with a builder.
+pub struct ProductsDeprecated202007GetCollectionsParamCollectionProductsRequest<'a> {
+    inner:
+        crate::products::ProductsDeprecated202007GetCollectionsParamCollectionProductsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProductsDeprecated202007GetCollectionsParamCollectionProductsRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Products::get_collections_param_collection`], set with a builder.
+pub struct ProductsGetCollectionsParamCollectionRequest<'a> {
+    inner: crate::products::ProductsGetCollectionsParamCollectionRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProductsGetCollectionsParamCollectionRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Products::get_collections_param_collection_products`], set with a builder.
+pub struct ProductsGetCollectionsParamCollectionProductsRequest<'a> {
+    inner: crate::products::ProductsGetCollectionsParamCollectionProductsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProductsGetCollectionsParamCollectionProductsRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Products::deprecated_202101_get_collections_param_collection`], set with a builder.
+pub struct ProductsDeprecated202101GetCollectionsParamCollectionRequest<'a> {
+    inner: crate::products::ProductsDeprecated202101GetCollectionsParamCollectionRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProductsDeprecated202101GetCollectionsParamCollectionRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Products::deprecated_202101_get_collections_param_collection_products`], set with a builder.
+pub struct ProductsDeprecated202101GetCollectionsParamCollectionProductsRequest<'a> {
+    inner:
+        crate::products::ProductsDeprecated202101GetCollectionsParamCollectionProductsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProductsDeprecated202101GetCollectionsParamCollectionProductsRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Products::deprecated_unstable_get_collections_param_collection`], set with a builder.
+pub struct ProductsDeprecatedUnstableGetCollectionsParamCollectionRequest<'a> {
+    inner: crate::products::ProductsDeprecatedUnstableGetCollectionsParamCollectionRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProductsDeprecatedUnstableGetCollectionsParamCollectionRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Products::deprecated_unstable_get_collections_param_collection_products`], set with a builder.
+pub struct ProductsDeprecatedUnstableGetCollectionsParamCollectionProductsRequest<'a> {
+    inner:
+        crate::products::ProductsDeprecatedUnstableGetCollectionsParamCollectionProductsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProductsDeprecatedUnstableGetCollectionsParamCollectionProductsRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Products::deprecated_202001_get_custom_collection`], set with a builder.
+pub struct ProductsDeprecated202001GetCustomCollectionRequest<'a> {
+    inner: crate::products::ProductsDeprecated202001GetCustomCollectionRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProductsDeprecated202001GetCustomCollectionRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `ids` query parameter.
+    pub fn ids(mut self, ids: &'a str) -> Self {
+        self.inner = self.inner.ids(ids);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `title` query parameter.
+    pub fn title(mut self, title: &'a str) -> Self {
+        self.inner = self.inner.title(title);
+        self
+    }
+
+    /// Sets the `product_id` query parameter.
+    pub fn product_id(mut self, product_id: &'a str) -> Self {
+        self.inner = self.inner.product_id(product_id);
+        self
+    }
+
+    /// Sets the `handle` query parameter.
+    pub fn handle(mut self, handle: &'a str) -> Self {
+        self.inner = self.inner.handle(handle);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.inner = self.inner.updated_at_min(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.inner = self.inner.updated_at_max(updated_at_max);
+        self
+    }
+
+    /// Sets the `published_at_min` query parameter.
+    pub fn published_at_min(mut self, published_at_min: &'a str) -> Self {
+        self.inner = self.inner.published_at_min(published_at_min);
+        self
+    }
+
+    /// Sets the `published_at_max` query parameter.
+    pub fn published_at_max(mut self, published_at_max: &'a str) -> Self {
+        self.inner = self.inner.published_at_max(published_at_max);
+        self
+    }
+
+    /// Sets the `published_status` query parameter.
+    pub fn published_status(mut self, published_status: &'a str) -> Self {
+        self.inner = self.inner.published_status(published_status);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Products::deprecated_202001_get_custom_collections_count`], set with a builder.
+pub struct ProductsDeprecated202001GetCustomCollectionsCountRequest<'a> {
+    inner: crate::products::ProductsDeprecated202001GetCustomCollectionsCountRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProductsDeprecated202001GetCustomCollectionsCountRequest<'a> {
+    /// Sets the `title` query parameter.
+    pub fn title(mut self, title: &'a str) -> Self {
+        self.inner = self.inner.title(title);
+        self
+    }
+
+    /// Sets the `product_id` query parameter.
+    pub fn product_id(mut self, product_id: &'a str) -> Self {
+        self.inner = self.inner.product_id(product_id);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.inner = self.inner.updated_at_min(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.inner = self.inner.updated_at_max(updated_at_max);
+        self
+    }
+
+    /// Sets the `published_at_min` query parameter.
+    pub fn published_at_min(mut self, published_at_min: &'a str) -> Self {
+        self.inner = self.inner.published_at_min(published_at_min);
+        self
+    }
+
+    /// Sets the `published_at_max` query parameter.
+    pub fn published_at_max(mut self, published_at_max: &'a str) -> Self {
+        self.inner = self.inner.published_at_max(published_at_max);
+        self
+    }
+
+    /// Sets the `published_status` query parameter.
+    pub fn published_status(mut self, published_status: &'a str) -> Self {
+        self.inner = self.inner.published_status(published_status);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Products::deprecated_202001_get_custom_collections_param_collection`], set with a builder.
+pub struct ProductsDeprecated202001GetCustomCollectionsParamCollectionRequest<'a> {
+    inner: crate::products::ProductsDeprecated202001GetCustomCollectionsParamCollectionRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProductsDeprecated202001GetCustomCollectionsParamCollectionRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Products::deprecated_202004_get_custom_collection`], set with a builder.
+pub struct ProductsDeprecated202004GetCustomCollectionRequest<'a> {
+    inner: crate::products::ProductsDeprecated202004GetCustomCollectionRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProductsDeprecated202004GetCustomCollectionRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `ids` query parameter.
+    pub fn ids(mut self, ids: &'a str) -> Self {
+        self.inner = self.inner.ids(ids);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `title` query parameter.
+    pub fn title(mut self, title: &'a str) -> Self {
+        self.inner = self.inner.title(title);
+        self
+    }
+
+    /// Sets the `product_id` query parameter.
+    pub fn product_id(mut self, product_id: &'a str) -> Self {
+        self.inner = self.inner.product_id(product_id);
+        self
+    }
+
+    /// Sets the `handle` query parameter.
+    pub fn handle(mut self, handle: &'a str) -> Self {
+        self.inner = self.inner.handle(handle);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.inner = self.inner.updated_at_min(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.inner = self.inner.updated_at_max(updated_at_max);
+        self
+    }
+
+    /// Sets the `published_at_min` query parameter.
+    pub fn published_at_min(mut self, published_at_min: &'a str) -> Self {
+        self.inner = self.inner.published_at_min(published_at_min);
+        self
+    }
+
+    /// Sets the `published_at_max` query parameter.
+    pub fn published_at_max(mut self, published_at_max: &'a str) -> Self {
+        self.inner = self.inner.published_at_max(published_at_max);
+        self
+    }
+
+    /// Sets the `published_status` query parameter.
+    pub fn published_status(mut self, published_status: &'a str) -> Self {
+        self.inner = self.inner.published_status(published_status);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Products::deprecated_202004_get_custom_collections_count`], set with a builder.
+pub struct ProductsDeprecated202004GetCustomCollectionsCountRequest<'a> {
+    inner: crate::products::ProductsDeprecated202004GetCustomCollectionsCountRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProductsDeprecated202004GetCustomCollectionsCountRequest<'a> {
+    /// Sets the `title` query parameter.
+    pub fn title(mut self, title: &'a str) -> Self {
+        self.inner = self.inner.title(title);
+        self
+    }
+
+    /// Sets the `product_id` query parameter.
+    pub fn product_id(mut self, product_id: &'a str) -> Self {
+        self.inner = self.inner.product_id(product_id);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.inner = self.inner.updated_at_min(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.inner = self.inner.updated_at_max(updated_at_max);
+        self
+    }
+
+    /// Sets the `published_at_min` query parameter.
+    pub fn published_at_min(mut self, published_at_min: &'a str) -> Self {
+        self.inner = self.inner.published_at_min(published_at_min);
+        self
+    }
+
+    /// Sets the `published_at_max` query parameter.
+    pub fn published_at_max(mut self, published_at_max: &'a str) -> Self {
+        self.inner = self.inner.published_at_max(published_at_max);
+        self
+    }
+
+    /// Sets the `published_status` query parameter.
+    pub fn published_status(mut self, published_status: &'a str) -> Self {
+        self.inner = self.inner.published_status(published_status);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Products::deprecated_202004_get_custom_collections_param_collection`], set with a builder.
+pub struct ProductsDeprecated202004GetCustomCollectionsParamCollectionRequest<'a> {
+    inner: crate::products::ProductsDeprecated202004GetCustomCollectionsParamCollectionRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProductsDeprecated202004GetCustomCollectionsParamCollectionRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Products::deprecated_202007_get_custom_collection`], set with a builder.
+pub struct ProductsDeprecated202007GetCustomCollectionRequest<'a> {
+    inner: crate::products::ProductsDeprecated202007GetCustomCollectionRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProductsDeprecated202007GetCustomCollectionRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `ids` query parameter.
+    pub fn ids(mut self, ids: &'a str) -> Self {
+        self.inner = self.inner.ids(ids);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `title` query parameter.
+    pub fn title(mut self, title: &'a str) -> Self {
+        self.inner = self.inner.title(title);
+        self
+    }
+
+    /// Sets the `product_id` query parameter.
+    pub fn product_id(mut self, product_id: &'a str) -> Self {
+        self.inner = self.inner.product_id(product_id);
+        self
+    }
+
+    /// Sets the `handle` query parameter.
+    pub fn handle(mut self, handle: &'a str) -> Self {
+        self.inner = self.inner.handle(handle);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.inner = self.inner.updated_at_min(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.inner = self.inner.updated_at_max(updated_at_max);
+        self
+    }
+
+    /// Sets the `published_at_min` query parameter.
+    pub fn published_at_min(mut self, published_at_min: &'a str) -> Self {
+        self.inner = self.inner.published_at_min(published_at_min);
+        self
+    }
+
+    /// Sets the `published_at_max` query parameter.
+    pub fn published_at_max(mut self, published_at_max: &'a str) -> Self {
+        self.inner = self.inner.published_at_max(published_at_max);
+        self
+    }
+
+    /// Sets the `published_status` query parameter.
+    pub fn published_status(mut self, published_status: &'a str) -> Self {
+        self.inner = self.inner.published_status(published_status);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Products::deprecated_202007_get_custom_collections_count`], set with a builder.
+pub struct ProductsDeprecated202007GetCustomCollectionsCountRequest<'a> {
+    inner: crate::products::ProductsDeprecated202007GetCustomCollectionsCountRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProductsDeprecated202007GetCustomCollectionsCountRequest<'a> {
+    /// Sets the `title` query parameter.
+    pub fn title(mut self, title: &'a str) -> Self {
+        self.inner = self.inner.title(title);
+        self
+    }
+
+    /// Sets the `product_id` query parameter.
+    pub fn product_id(mut self, product_id: &'a str) -> Self {
+        self.inner = self.inner.product_id(product_id);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.inner = self.inner.updated_at_min(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.inner = self.inner.updated_at_max(updated_at_max);
+        self
+    }
+
+    /// Sets the `published_at_min` query parameter.
+    pub fn published_at_min(mut self, published_at_min: &'a str) -> Self {
+        self.inner = self.inner.published_at_min(published_at_min);
+        self
+    }
+
+    /// Sets the `published_at_max` query parameter.
+    pub fn published_at_max(mut self, published_at_max: &'a str) -> Self {
+        self.inner = self.inner.published_at_max(published_at_max);
+        self
+    }
+
+    /// Sets the `published_status` query parameter.
+    pub fn published_status(mut self, published_status: &'a str) -> Self {
+        self.inner = self.inner.published_status(published_status);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Products::deprecated_202007_get_custom_collections_param_collection`], set with a builder.
+pub struct ProductsDeprecated202007GetCustomCollectionsParamCollectionRequest<'a> {
+    inner: crate::products::ProductsDeprecated202007GetCustomCollectionsParamCollectionRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProductsDeprecated202007GetCustomCollectionsParamCollectionRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Products::get_custom_collection`], set with a builder.
+pub struct ProductsGetCustomCollectionRequest<'a> {
+    inner: crate::products::ProductsGetCustomCollectionRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProductsGetCustomCollectionRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `ids` query parameter.
+    pub fn ids(mut self, ids: &'a str) -> Self {
+        self.inner = self.inner.ids(ids);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `title` query parameter.
+    pub fn title(mut self, title: &'a str) -> Self {
+        self.inner = self.inner.title(title);
+        self
+    }
+
+    /// Sets the `product_id` query parameter.
+    pub fn product_id(mut self, product_id: &'a str) -> Self {
+        self.inner = self.inner.product_id(product_id);
+        self
+    }
+
+    /// Sets the `handle` query parameter.
+    pub fn handle(mut self, handle: &'a str) -> Self {
+        self.inner = self.inner.handle(handle);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.inner = self.inner.updated_at_min(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.inner = self.inner.updated_at_max(updated_at_max);
+        self
+    }
+
+    /// Sets the `published_at_min` query parameter.
+    pub fn published_at_min(mut self, published_at_min: &'a str) -> Self {
+        self.inner = self.inner.published_at_min(published_at_min);
+        self
+    }
+
+    /// Sets the `published_at_max` query parameter.
+    pub fn published_at_max(mut self, published_at_max: &'a str) -> Self {
+        self.inner = self.inner.published_at_max(published_at_max);
+        self
+    }
+
+    /// Sets the `published_status` query parameter.
+    pub fn published_status(mut self, published_status: &'a str) -> Self {
+        self.inner = self.inner.published_status(published_status);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Products::get_custom_collections_count`], set with a builder.
+pub struct ProductsGetCustomCollectionsCountRequest<'a> {
+    inner: crate::products::ProductsGetCustomCollectionsCountRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProductsGetCustomCollectionsCountRequest<'a> {
+    /// Sets the `title` query parameter.
+    pub fn title(mut self, title: &'a str) -> Self {
+        self.inner = self.inner.title(title);
+        self
+    }
+
+    /// Sets the `product_id` query parameter.
+    pub fn product_id(mut self, product_id: &'a str) -> Self {
+        self.inner = self.inner.product_id(product_id);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.inner = self.inner.updated_at_min(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.inner = self.inner.updated_at_max(updated_at_max);
+        self
+    }
+
+    /// Sets the `published_at_min` query parameter.
+    pub fn published_at_min(mut self, published_at_min: &'a str) -> Self {
+        self.inner = self.inner.published_at_min(published_at_min);
+        self
+    }
+
+    /// Sets the `published_at_max` query parameter.
+    pub fn published_at_max(mut self, published_at_max: &'a str) -> Self {
+        self.inner = self.inner.published_at_max(published_at_max);
+        self
+    }
+
+    /// Sets the `published_status` query parameter.
+    pub fn published_status(mut self, published_status: &'a str) -> Self {
+        self.inner = self.inner.published_status(published_status);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Products::get_custom_collections_param_collection`], set with a builder.
+pub struct ProductsGetCustomCollectionsParamCollectionRequest<'a> {
+    inner: crate::products::ProductsGetCustomCollectionsParamCollectionRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProductsGetCustomCollectionsParamCollectionRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Products::deprecated_202101_get_custom_collection`], set with a builder.
+pub struct ProductsDeprecated202101GetCustomCollectionRequest<'a> {
+    inner: crate::products::ProductsDeprecated202101GetCustomCollectionRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProductsDeprecated202101GetCustomCollectionRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `ids` query parameter.
+    pub fn ids(mut self, ids: &'a str) -> Self {
+        self.inner = self.inner.ids(ids);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `title` query parameter.
+    pub fn title(mut self, title: &'a str) -> Self {
+        self.inner = self.inner.title(title);
+        self
+    }
+
+    /// Sets the `product_id` query parameter.
+    pub fn product_id(mut self, product_id: &'a str) -> Self {
+        self.inner = self.inner.product_id(product_id);
+        self
+    }
+
+    /// Sets the `handle` query parameter.
+    pub fn handle(mut self, handle: &'a str) -> Self {
+        self.inner = self.inner.handle(handle);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.inner = self.inner.updated_at_min(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.inner = self.inner.updated_at_max(updated_at_max);
+        self
+    }
+
+    /// Sets the `published_at_min` query parameter.
+    pub fn published_at_min(mut self, published_at_min: &'a str) -> Self {
+        self.inner = self.inner.published_at_min(published_at_min);
+        self
+    }
+
+    /// Sets the `published_at_max` query parameter.
+    pub fn published_at_max(mut self, published_at_max: &'a str) -> Self {
+        self.inner = self.inner.published_at_max(published_at_max);
+        self
+    }
+
+    /// Sets the `published_status` query parameter.
+    pub fn published_status(mut self, published_status: &'a str) -> Self {
+        self.inner = self.inner.published_status(published_status);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Products::deprecated_202101_get_custom_collections_count`], set with a builder.
+pub struct ProductsDeprecated202101GetCustomCollectionsCountRequest<'a> {
+    inner: crate::products::ProductsDeprecated202101GetCustomCollectionsCountRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProductsDeprecated202101GetCustomCollectionsCountRequest<'a> {
+    /// Sets the `title` query parameter.
+    pub fn title(mut self, title: &'a str) -> Self {
+        self.inner = self.inner.title(title);
+        self
+    }
+
+    /// Sets the `product_id` query parameter.
+    pub fn product_id(mut self, product_id: &'a str) -> Self {
+        self.inner = self.inner.product_id(product_id);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.inner = self.inner.updated_at_min(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.inner = self.inner.updated_at_max(updated_at_max);
+        self
+    }
+
+    /// Sets the `published_at_min` query parameter.
+    pub fn published_at_min(mut self, published_at_min: &'a str) -> Self {
+        self.inner = self.inner.published_at_min(published_at_min);
+        self
+    }
+
+    /// Sets the `published_at_max` query parameter.
+    pub fn published_at_max(mut self, published_at_max: &'a str) -> Self {
+        self.inner = self.inner.published_at_max(published_at_max);
+        self
+    }
+
+    /// Sets the `published_status` query parameter.
+    pub fn published_status(mut self, published_status: &'a str) -> Self {
+        self.inner = self.inner.published_status(published_status);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Products::deprecated_202101_get_custom_collections_param_collection`], set with a builder.
+pub struct ProductsDeprecated202101GetCustomCollectionsParamCollectionRequest<'a> {
+    inner: crate::products::ProductsDeprecated202101GetCustomCollectionsParamCollectionRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProductsDeprecated202101GetCustomCollectionsParamCollectionRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Products::deprecated_unstable_get_custom_collection`], set with a builder.
+pub struct ProductsDeprecatedUnstableGetCustomCollectionRequest<'a> {
+    inner: crate::products::ProductsDeprecatedUnstableGetCustomCollectionRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProductsDeprecatedUnstableGetCustomCollectionRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `ids` query parameter.
+    pub fn ids(mut self, ids: &'a str) -> Self {
+        self.inner = self.inner.ids(ids);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `title` query parameter.
+    pub fn title(mut self, title: &'a str) -> Self {
+        self.inner = self.inner.title(title);
+        self
+    }
+
+    /// Sets the `product_id` query parameter.
+    pub fn product_id(mut self, product_id: &'a str) -> Self {
+        self.inner = self.inner.product_id(product_id);
+        self
+    }
+
+    /// Sets the `handle` query parameter.
+    pub fn handle(mut self, handle: &'a str) -> Self {
+        self.inner = self.inner.handle(handle);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.inner = self.inner.updated_at_min(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.inner = self.inner.updated_at_max(updated_at_max);
+        self
+    }
+
+    /// Sets the `published_at_min` query parameter.
+    pub fn published_at_min(mut self, published_at_min: &'a str) -> Self {
+        self.inner = self.inner.published_at_min(published_at_min);
+        self
+    }
+
+    /// Sets the `published_at_max` query parameter.
+    pub fn published_at_max(mut self, published_at_max: &'a str) -> Self {
+        self.inner = self.inner.published_at_max(published_at_max);
+        self
+    }
+
+    /// Sets the `published_status` query parameter.
+    pub fn published_status(mut self, published_status: &'a str) -> Self {
+        self.inner = self.inner.published_status(published_status);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Products::deprecated_unstable_get_custom_collections_count`], set with a builder.
+pub struct ProductsDeprecatedUnstableGetCustomCollectionsCountRequest<'a> {
+    inner: crate::products::ProductsDeprecatedUnstableGetCustomCollectionsCountRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProductsDeprecatedUnstableGetCustomCollectionsCountRequest<'a> {
+    /// Sets the `title` query parameter.
+    pub fn title(mut self, title: &'a str) -> Self {
+        self.inner = self.inner.title(title);
+        self
+    }
+
+    /// Sets the `product_id` query parameter.
+    pub fn product_id(mut self, product_id: &'a str) -> Self {
+        self.inner = self.inner.product_id(product_id);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.inner = self.inner.updated_at_min(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.inner = self.inner.updated_at_max(updated_at_max);
+        self
+    }
+
+    /// Sets the `published_at_min` query parameter.
+    pub fn published_at_min(mut self, published_at_min: &'a str) -> Self {
+        self.inner = self.inner.published_at_min(published_at_min);
+        self
+    }
+
+    /// Sets the `published_at_max` query parameter.
+    pub fn published_at_max(mut self, published_at_max: &'a str) -> Self {
+        self.inner = self.inner.published_at_max(published_at_max);
+        self
+    }
+
+    /// Sets the `published_status` query parameter.
+    pub fn published_status(mut self, published_status: &'a str) -> Self {
+        self.inner = self.inner.published_status(published_status);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Products::deprecated_unstable_get_custom_collections_param_collection`], set with a builder.
+pub struct ProductsDeprecatedUnstableGetCustomCollectionsParamCollectionRequest<'a> {
+    inner:
+        crate::products::ProductsDeprecatedUnstableGetCustomCollectionsParamCollectionRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProductsDeprecatedUnstableGetCustomCollectionsParamCollectionRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Products::deprecated_202001_get`], set with a builder.
+pub struct ProductsDeprecated202001GetRequest<'a> {
+    inner: crate::products::ProductsDeprecated202001GetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProductsDeprecated202001GetRequest<'a> {
+    /// Sets the `ids` query parameter.
+    pub fn ids(mut self, ids: &'a str) -> Self {
+        self.inner = self.inner.ids(ids);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `title` query parameter.
+    pub fn title(mut self, title: &'a str) -> Self {
+        self.inner = self.inner.title(title);
+        self
+    }
+
+    /// Sets the `vendor` query parameter.
+    pub fn vendor(mut self, vendor: &'a str) -> Self {
+        self.inner = self.inner.vendor(vendor);
+        self
+    }
+
+    /// Sets the `handle` query parameter.
+    pub fn handle(mut self, handle: &'a str) -> Self {
+        self.inner = self.inner.handle(handle);
+        self
+    }
+
+    /// Sets the `product_type` query parameter.
+    pub fn product_type(mut self, product_type: &'a str) -> Self {
+        self.inner = self.inner.product_type(product_type);
+        self
+    }
+
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: &'a str) -> Self {
+        self.inner = self.inner.status(status);
+        self
+    }
+
+    /// Sets the `collection_id` query parameter.
+    pub fn collection_id(mut self, collection_id: &'a str) -> Self {
+        self.inner = self.inner.collection_id(collection_id);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.inner = self.inner.created_at_min(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.inner = self.inner.created_at_max(created_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.inner = self.inner.updated_at_min(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.inner = self.inner.updated_at_max(updated_at_max);
+        self
+    }
+
+    /// Sets the `published_at_min` query parameter.
+    pub fn published_at_min(mut self, published_at_min: &'a str) -> Self {
+        self.inner = self.inner.published_at_min(published_at_min);
+        self
+    }
+
+    /// Sets the `published_at_max` query parameter.
+    pub fn published_at_max(mut self, published_at_max: &'a str) -> Self {
+        self.inner = self.inner.published_at_max(published_at_max);
+        self
+    }
+
+    /// Sets the `published_status` query parameter.
+    pub fn published_status(mut self, published_status: &'a str) -> Self {
+        self.inner = self.inner.published_status(published_status);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `presentment_currencies` query parameter.
+    pub fn presentment_currencies(mut self, presentment_currencies: &'a str) -> Self {
+        self.inner = self.inner.presentment_currencies(presentment_currencies);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Products::deprecated_202001_get_count`], set with a builder.
+pub struct ProductsDeprecated202001GetCountRequest<'a> {
+    inner: crate::products::ProductsDeprecated202001GetCountRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProductsDeprecated202001GetCountRequest<'a> {
+    /// Sets the `vendor` query parameter.
+    pub fn vendor(mut self, vendor: &'a str) -> Self {
+        self.inner = self.inner.vendor(vendor);
+        self
+    }
+
+    /// Sets the `product_type` query parameter.
+    pub fn product_type(mut self, product_type: &'a str) -> Self {
+        self.inner = self.inner.product_type(product_type);
+        self
+    }
+
+    /// Sets the `collection_id` query parameter.
+    pub fn collection_id(mut self, collection_id: &'a str) -> Self {
+        self.inner = self.inner.collection_id(collection_id);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.inner = self.inner.created_at_min(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.inner = self.inner.created_at_max(created_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.inner = self.inner.updated_at_min(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.inner = self.inner.updated_at_max(updated_at_max);
+        self
+    }
+
+    /// Sets the `published_at_min` query parameter.
+    pub fn published_at_min(mut self, published_at_min: &'a str) -> Self {
+        self.inner = self.inner.published_at_min(published_at_min);
+        self
+    }
+
+    /// Sets the `published_at_max` query parameter.
+    pub fn published_at_max(mut self, published_at_max: &'a str) -> Self {
+        self.inner = self.inner.published_at_max(published_at_max);
+        self
+    }
+
+    /// Sets the `published_status` query parameter.
+    pub fn published_status(mut self, published_status: &'a str) -> Self {
+        self.inner = self.inner.published_status(published_status);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Products::deprecated_202001_get_param`], set with a builder.
+pub struct ProductsDeprecated202001GetParamRequest<'a> {
+    inner: crate::products::ProductsDeprecated202001GetParamRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProductsDeprecated202001GetParamRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Products::deprecated_202004_get`], set with a builder.
+pub struct ProductsDeprecated202004GetRequest<'a> {
+    inner: crate::products::ProductsDeprecated202004GetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProductsDeprecated202004GetRequest<'a> {
+    /// Sets the `ids` query parameter.
+    pub fn ids(mut self, ids: &'a str) -> Self {
+        self.inner = self.inner.ids(ids);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `title` query parameter.
+    pub fn title(mut self, title: &'a str) -> Self {
+        self.inner = self.inner.title(title);
+        self
+    }
+
+    /// Sets the `vendor` query parameter.
+    pub fn vendor(mut self, vendor: &'a str) -> Self {
+        self.inner = self.inner.vendor(vendor);
+        self
+    }
+
+    /// Sets the `handle` query parameter.
+    pub fn handle(mut self, handle: &'a str) -> Self {
+        self.inner = self.inner.handle(handle);
+        self
+    }
+
+    /// Sets the `product_type` query parameter.
+    pub fn product_type(mut self, product_type: &'a str) -> Self {
+        self.inner = self.inner.product_type(product_type);
+        self
+    }
+
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: &'a str) -> Self {
+        self.inner = self.inner.status(status);
+        self
+    }
+
+    /// Sets the `collection_id` query parameter.
+    pub fn collection_id(mut self, collection_id: &'a str) -> Self {
+        self.inner = self.inner.collection_id(collection_id);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.inner = self.inner.created_at_min(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.inner = self.inner.created_at_max(created_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.inner = self.inner.updated_at_min(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.inner = self.inner.updated_at_max(updated_at_max);
+        self
+    }
+
+    /// Sets the `published_at_min` query parameter.
+    pub fn published_at_min(mut self, published_at_min: &'a str) -> Self {
+        self.inner = self.inner.published_at_min(published_at_min);
+        self
+    }
+
+    /// Sets the `published_at_max` query parameter.
+    pub fn published_at_max(mut self, published_at_max: &'a str) -> Self {
+        self.inner = self.inner.published_at_max(published_at_max);
+        self
+    }
+
+    /// Sets the `published_status` query parameter.
+    pub fn published_status(mut self, published_status: &'a str) -> Self {
+        self.inner = self.inner.published_status(published_status);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `presentment_currencies` query parameter.
+    pub fn presentment_currencies(mut self, presentment_currencies: &'a str) -> Self {
+        self.inner = self.inner.presentment_currencies(presentment_currencies);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Products::deprecated_202004_get_count`], set with a builder.
+pub struct ProductsDeprecated202004GetCountRequest<'a> {
+    inner: crate::products::ProductsDeprecated202004GetCountRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProductsDeprecated202004GetCountRequest<'a> {
+    /// Sets the `vendor` query parameter.
+    pub fn vendor(mut self, vendor: &'a str) -> Self {
+        self.inner = self.inner.vendor(vendor);
+        self
+    }
+
+    /// Sets the `product_type` query parameter.
+    pub fn product_type(mut self, product_type: &'a str) -> Self {
+        self.inner = self.inner.product_type(product_type);
+        self
+    }
+
+    /// Sets the `collection_id` query parameter.
+    pub fn collection_id(mut self, collection_id: &'a str) -> Self {
+        self.inner = self.inner.collection_id(collection_id);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.inner = self.inner.created_at_min(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.inner = self.inner.created_at_max(created_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.inner = self.inner.updated_at_min(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.inner = self.inner.updated_at_max(updated_at_max);
+        self
+    }
+
+    /// Sets the `published_at_min` query parameter.
+    pub fn published_at_min(mut self, published_at_min: &'a str) -> Self {
+        self.inner = self.inner.published_at_min(published_at_min);
+        self
+    }
+
+    /// Sets the `published_at_max` query parameter.
+    pub fn published_at_max(mut self, published_at_max: &'a str) -> Self {
+        self.inner = self.inner.published_at_max(published_at_max);
+        self
+    }
+
+    /// Sets the `published_status` query parameter.
+    pub fn published_status(mut self, published_status: &'a str) -> Self {
+        self.inner = self.inner.published_status(published_status);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Products::deprecated_202004_get_param`], set with a builder.
+pub struct ProductsDeprecated202004GetParamRequest<'a> {
+    inner: crate::products::ProductsDeprecated202004GetParamRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProductsDeprecated202004GetParamRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Products::deprecated_202007_get`], set with a builder.
+pub struct ProductsDeprecated202007GetRequest<'a> {
+    inner: crate::products::ProductsDeprecated202007GetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProductsDeprecated202007GetRequest<'a> {
+    /// Sets the `ids` query parameter.
+    pub fn ids(mut self, ids: &'a str) -> Self {
+        self.inner = self.inner.ids(ids);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `title` query parameter.
+    pub fn title(mut self, title: &'a str) -> Self {
+        self.inner = self.inner.title(title);
+        self
+    }
+
+    /// Sets the `vendor` query parameter.
+    pub fn vendor(mut self, vendor: &'a str) -> Self {
+        self.inner = self.inner.vendor(vendor);
+        self
+    }
+
+    /// Sets the `handle` query parameter.
+    pub fn handle(mut self, handle: &'a str) -> Self {
+        self.inner = self.inner.handle(handle);
+        self
+    }
+
+    /// Sets the `product_type` query parameter.
+    pub fn product_type(mut self, product_type: &'a str) -> Self {
+        self.inner = self.inner.product_type(product_type);
+        self
+    }
+
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: &'a str) -> Self {
+        self.inner = self.inner.status(status);
+        self
+    }
+
+    /// Sets the `collection_id` query parameter.
+    pub fn collection_id(mut self, collection_id: &'a str) -> Self {
+        self.inner = self.inner.collection_id(collection_id);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.inner = self.inner.created_at_min(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.inner = self.inner.created_at_max(created_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.inner = self.inner.updated_at_min(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.inner = self.inner.updated_at_max(updated_at_max);
+        self
+    }
+
+    /// Sets the `published_at_min` query parameter.
+    pub fn published_at_min(mut self, published_at_min: &'a str) -> Self {
+        self.inner = self.inner.published_at_min(published_at_min);
+        self
+    }
+
+    /// Sets the `published_at_max` query parameter.
+    pub fn published_at_max(mut self, published_at_max: &'a str) -> Self {
+        self.inner = self.inner.published_at_max(published_at_max);
+        self
+    }
+
+    /// Sets the `published_status` query parameter.
+    pub fn published_status(mut self, published_status: &'a str) -> Self {
+        self.inner = self.inner.published_status(published_status);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `presentment_currencies` query parameter.
+    pub fn presentment_currencies(mut self, presentment_currencies: &'a str) -> Self {
+        self.inner = self.inner.presentment_currencies(presentment_currencies);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Products::deprecated_202007_get_count`], set with a builder.
+pub struct ProductsDeprecated202007GetCountRequest<'a> {
+    inner: crate::products::ProductsDeprecated202007GetCountRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProductsDeprecated202007GetCountRequest<'a> {
+    /// Sets the `vendor` query parameter.
+    pub fn vendor(mut self, vendor: &'a str) -> Self {
+        self.inner = self.inner.vendor(vendor);
+        self
+    }
+
+    /// Sets the `product_type` query parameter.
+    pub fn product_type(mut self, product_type: &'a str) -> Self {
+        self.inner = self.inner.product_type(product_type);
+        self
+    }
+
+    /// Sets the `collection_id` query parameter.
+    pub fn collection_id(mut self, collection_id: &'a str) -> Self {
+        self.inner = self.inner.collection_id(collection_id);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.inner = self.inner.created_at_min(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.inner = self.inner.created_at_max(created_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.inner = self.inner.updated_at_min(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.inner = self.inner.updated_at_max(updated_at_max);
+        self
+    }
+
+    /// Sets the `published_at_min` query parameter.
+    pub fn published_at_min(mut self, published_at_min: &'a str) -> Self {
+        self.inner = self.inner.published_at_min(published_at_min);
+        self
+    }
+
+    /// Sets the `published_at_max` query parameter.
+    pub fn published_at_max(mut self, published_at_max: &'a str) -> Self {
+        self.inner = self.inner.published_at_max(published_at_max);
+        self
+    }
+
+    /// Sets the `published_status` query parameter.
+    pub fn published_status(mut self, published_status: &'a str) -> Self {
+        self.inner = self.inner.published_status(published_status);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Products::deprecated_202007_get_param`], set with a builder.
+pub struct ProductsDeprecated202007GetParamRequest<'a> {
+    inner: crate::products::ProductsDeprecated202007GetParamRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProductsDeprecated202007GetParamRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Products::get`], set with a builder.
+pub struct ProductsGetRequest<'a> {
+    inner: crate::products::ProductsGetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProductsGetRequest<'a> {
+    /// Sets the `ids` query parameter.
+    pub fn ids(mut self, ids: &'a str) -> Self {
+        self.inner = self.inner.ids(ids);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `title` query parameter.
+    pub fn title(mut self, title: &'a str) -> Self {
+        self.inner = self.inner.title(title);
+        self
+    }
+
+    /// Sets the `vendor` query parameter.
+    pub fn vendor(mut self, vendor: &'a str) -> Self {
+        self.inner = self.inner.vendor(vendor);
+        self
+    }
+
+    /// Sets the `handle` query parameter.
+    pub fn handle(mut self, handle: &'a str) -> Self {
+        self.inner = self.inner.handle(handle);
+        self
+    }
+
+    /// Sets the `product_type` query parameter.
+    pub fn product_type(mut self, product_type: &'a str) -> Self {
+        self.inner = self.inner.product_type(product_type);
+        self
+    }
+
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: &'a str) -> Self {
+        self.inner = self.inner.status(status);
+        self
+    }
+
+    /// Sets the `collection_id` query parameter.
+    pub fn collection_id(mut self, collection_id: &'a str) -> Self {
+        self.inner = self.inner.collection_id(collection_id);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.inner = self.inner.created_at_min(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.inner = self.inner.created_at_max(created_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.inner = self.inner.updated_at_min(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.inner = self.inner.updated_at_max(updated_at_max);
+        self
+    }
+
+    /// Sets the `published_at_min` query parameter.
+    pub fn published_at_min(mut self, published_at_min: &'a str) -> Self {
+        self.inner = self.inner.published_at_min(published_at_min);
+        self
+    }
+
+    /// Sets the `published_at_max` query parameter.
+    pub fn published_at_max(mut self, published_at_max: &'a str) -> Self {
+        self.inner = self.inner.published_at_max(published_at_max);
+        self
+    }
+
+    /// Sets the `published_status` query parameter.
+    pub fn published_status(mut self, published_status: &'a str) -> Self {
+        self.inner = self.inner.published_status(published_status);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `presentment_currencies` query parameter.
+    pub fn presentment_currencies(mut self, presentment_currencies: &'a str) -> Self {
+        self.inner = self.inner.presentment_currencies(presentment_currencies);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Products::get_count`], set with a builder.
+pub struct ProductsGetCountRequest<'a> {
+    inner: crate::products::ProductsGetCountRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProductsGetCountRequest<'a> {
+    /// Sets the `vendor` query parameter.
+    pub fn vendor(mut self, vendor: &'a str) -> Self {
+        self.inner = self.inner.vendor(vendor);
+        self
+    }
+
+    /// Sets the `product_type` query parameter.
+    pub fn product_type(mut self, product_type: &'a str) -> Self {
+        self.inner = self.inner.product_type(product_type);
+        self
+    }
+
+    /// Sets the `collection_id` query parameter.
+    pub fn collection_id(mut self, collection_id: &'a str) -> Self {
+        self.inner = self.inner.collection_id(collection_id);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.inner = self.inner.created_at_min(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.inner = self.inner.created_at_max(created_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.inner = self.inner.updated_at_min(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.inner = self.inner.updated_at_max(updated_at_max);
+        self
+    }
+
+    /// Sets the `published_at_min` query parameter.
+    pub fn published_at_min(mut self, published_at_min: &'a str) -> Self {
+        self.inner = self.inner.published_at_min(published_at_min);
+        self
+    }
+
+    /// Sets the `published_at_max` query parameter.
+    pub fn published_at_max(mut self, published_at_max: &'a str) -> Self {
+        self.inner = self.inner.published_at_max(published_at_max);
+        self
+    }
+
+    /// Sets the `published_status` query parameter.
+    pub fn published_status(mut self, published_status: &'a str) -> Self {
+        self.inner = self.inner.published_status(published_status);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Products::get_param`], set with a builder.
+pub struct ProductsGetParamRequest<'a> {
+    inner: crate::products::ProductsGetParamRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProductsGetParamRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Products::deprecated_202101_get`], set with a builder.
+pub struct ProductsDeprecated202101GetRequest<'a> {
+    inner: crate::products::ProductsDeprecated202101GetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProductsDeprecated202101GetRequest<'a> {
+    /// Sets the `ids` query parameter.
+    pub fn ids(mut self, ids: &'a str) -> Self {
+        self.inner = self.inner.ids(ids);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `title` query parameter.
+    pub fn title(mut self, title: &'a str) -> Self {
+        self.inner = self.inner.title(title);
+        self
+    }
+
+    /// Sets the `vendor` query parameter.
+    pub fn vendor(mut self, vendor: &'a str) -> Self {
+        self.inner = self.inner.vendor(vendor);
+        self
+    }
+
+    /// Sets the `handle` query parameter.
+    pub fn handle(mut self, handle: &'a str) -> Self {
+        self.inner = self.inner.handle(handle);
+        self
+    }
+
+    /// Sets the `product_type` query parameter.
+    pub fn product_type(mut self, product_type: &'a str) -> Self {
+        self.inner = self.inner.product_type(product_type);
+        self
+    }
+
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: &'a str) -> Self {
+        self.inner = self.inner.status(status);
+        self
+    }
+
+    /// Sets the `collection_id` query parameter.
+    pub fn collection_id(mut self, collection_id: &'a str) -> Self {
+        self.inner = self.inner.collection_id(collection_id);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.inner = self.inner.created_at_min(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.inner = self.inner.created_at_max(created_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.inner = self.inner.updated_at_min(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.inner = self.inner.updated_at_max(updated_at_max);
+        self
+    }
+
+    /// Sets the `published_at_min` query parameter.
+    pub fn published_at_min(mut self, published_at_min: &'a str) -> Self {
+        self.inner = self.inner.published_at_min(published_at_min);
+        self
+    }
+
+    /// Sets the `published_at_max` query parameter.
+    pub fn published_at_max(mut self, published_at_max: &'a str) -> Self {
+        self.inner = self.inner.published_at_max(published_at_max);
+        self
+    }
+
+    /// Sets the `published_status` query parameter.
+    pub fn published_status(mut self, published_status: &'a str) -> Self {
+        self.inner = self.inner.published_status(published_status);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `presentment_currencies` query parameter.
+    pub fn presentment_currencies(mut self, presentment_currencies: &'a str) -> Self {
+        self.inner = self.inner.presentment_currencies(presentment_currencies);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Products::deprecated_202101_get_count`], set with a builder.
+pub struct ProductsDeprecated202101GetCountRequest<'a> {
+    inner: crate::products::ProductsDeprecated202101GetCountRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProductsDeprecated202101GetCountRequest<'a> {
+    /// Sets the `vendor` query parameter.
+    pub fn vendor(mut self, vendor: &'a str) -> Self {
+        self.inner = self.inner.vendor(vendor);
+        self
+    }
+
+    /// Sets the `product_type` query parameter.
+    pub fn product_type(mut self, product_type: &'a str) -> Self {
+        self.inner = self.inner.product_type(product_type);
+        self
+    }
+
+    /// Sets the `collection_id` query parameter.
+    pub fn collection_id(mut self, collection_id: &'a str) -> Self {
+        self.inner = self.inner.collection_id(collection_id);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.inner = self.inner.created_at_min(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.inner = self.inner.created_at_max(created_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.inner = self.inner.updated_at_min(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.inner = self.inner.updated_at_max(updated_at_max);
+        self
+    }
+
+    /// Sets the `published_at_min` query parameter.
+    pub fn published_at_min(mut self, published_at_min: &'a str) -> Self {
+        self.inner = self.inner.published_at_min(published_at_min);
+        self
+    }
+
+    /// Sets the `published_at_max` query parameter.
+    pub fn published_at_max(mut self, published_at_max: &'a str) -> Self {
+        self.inner = self.inner.published_at_max(published_at_max);
+        self
+    }
+
+    /// Sets the `published_status` query parameter.
+    pub fn published_status(mut self, published_status: &'a str) -> Self {
+        self.inner = self.inner.published_status(published_status);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Products::deprecated_202101_get_param`], set with a builder.
+pub struct ProductsDeprecated202101GetParamRequest<'a> {
+    inner: crate::products::ProductsDeprecated202101GetParamRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProductsDeprecated202101GetParamRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Products::deprecated_unstable_get`], set with a builder.
+pub struct ProductsDeprecatedUnstableGetRequest<'a> {
+    inner: crate::products::ProductsDeprecatedUnstableGetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProductsDeprecatedUnstableGetRequest<'a> {
+    /// Sets the `ids` query parameter.
+    pub fn ids(mut self, ids: &'a str) -> Self {
+        self.inner = self.inner.ids(ids);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `title` query parameter.
+    pub fn title(mut self, title: &'a str) -> Self {
+        self.inner = self.inner.title(title);
+        self
+    }
+
+    /// Sets the `vendor` query parameter.
+    pub fn vendor(mut self, vendor: &'a str) -> Self {
+        self.inner = self.inner.vendor(vendor);
+        self
+    }
+
+    /// Sets the `handle` query parameter.
+    pub fn handle(mut self, handle: &'a str) -> Self {
+        self.inner = self.inner.handle(handle);
+        self
+    }
+
+    /// Sets the `product_type` query parameter.
+    pub fn product_type(mut self, product_type: &'a str) -> Self {
+        self.inner = self.inner.product_type(product_type);
+        self
+    }
+
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: &'a str) -> Self {
+        self.inner = self.inner.status(status);
+        self
+    }
+
+    /// Sets the `collection_id` query parameter.
+    pub fn collection_id(mut self, collection_id: &'a str) -> Self {
+        self.inner = self.inner.collection_id(collection_id);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.inner = self.inner.created_at_min(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.inner = self.inner.created_at_max(created_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.inner = self.inner.updated_at_min(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.inner = self.inner.updated_at_max(updated_at_max);
+        self
+    }
+
+    /// Sets the `published_at_min` query parameter.
+    pub fn published_at_min(mut self, published_at_min: &'a str) -> Self {
+        self.inner = self.inner.published_at_min(published_at_min);
+        self
+    }
+
+    /// Sets the `published_at_max` query parameter.
+    pub fn published_at_max(mut self, published_at_max: &'a str) -> Self {
+        self.inner = self.inner.published_at_max(published_at_max);
+        self
+    }
+
+    /// Sets the `published_status` query parameter.
+    pub fn published_status(mut self, published_status: &'a str) -> Self {
+        self.inner = self.inner.published_status(published_status);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `presentment_currencies` query parameter.
+    pub fn presentment_currencies(mut self, presentment_currencies: &'a str) -> Self {
+        self.inner = self.inner.presentment_currencies(presentment_currencies);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Products::deprecated_unstable_get_count`], set with a builder.
+pub struct ProductsDeprecatedUnstableGetCountRequest<'a> {
+    inner: crate::products::ProductsDeprecatedUnstableGetCountRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProductsDeprecatedUnstableGetCountRequest<'a> {
+    /// Sets the `vendor` query parameter.
+    pub fn vendor(mut self, vendor: &'a str) -> Self {
+        self.inner = self.inner.vendor(vendor);
+        self
+    }
+
+    /// Sets the `product_type` query parameter.
+    pub fn product_type(mut self, product_type: &'a str) -> Self {
+        self.inner = self.inner.product_type(product_type);
+        self
+    }
+
+    /// Sets the `collection_id` query parameter.
+    pub fn collection_id(mut self, collection_id: &'a str) -> Self {
+        self.inner = self.inner.collection_id(collection_id);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.inner = self.inner.created_at_min(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.inner = self.inner.created_at_max(created_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.inner = self.inner.updated_at_min(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.inner = self.inner.updated_at_max(updated_at_max);
+        self
+    }
+
+    /// Sets the `published_at_min` query parameter.
+    pub fn published_at_min(mut self, published_at_min: &'a str) -> Self {
+        self.inner = self.inner.published_at_min(published_at_min);
+        self
+    }
+
+    /// Sets the `published_at_max` query parameter.
+    pub fn published_at_max(mut self, published_at_max: &'a str) -> Self {
+        self.inner = self.inner.published_at_max(published_at_max);
+        self
+    }
+
+    /// Sets the `published_status` query parameter.
+    pub fn published_status(mut self, published_status: &'a str) -> Self {
+        self.inner = self.inner.published_status(published_status);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Products::deprecated_unstable_get_param`], set with a builder.
+pub struct ProductsDeprecatedUnstableGetParamRequest<'a> {
+    inner: crate::products::ProductsDeprecatedUnstableGetParamRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProductsDeprecatedUnstableGetParamRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Products::deprecated_202001_get_param_image`], set with a builder.
+pub struct ProductsDeprecated202001GetParamImageRequest<'a> {
+    inner: crate::products::ProductsDeprecated202001GetParamImageRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProductsDeprecated202001GetParamImageRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Products::deprecated_202001_get_param_images_count`], set with a builder.
+pub struct ProductsDeprecated202001GetParamImagesCountRequest<'a> {
+    inner: crate::products::ProductsDeprecated202001GetParamImagesCountRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProductsDeprecated202001GetParamImagesCountRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Products::deprecated_202001_get_param_images_image`], set with a builder.
+pub struct ProductsDeprecated202001GetParamImagesImageRequest<'a> {
+    inner: crate::products::ProductsDeprecated202001GetParamImagesImageRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProductsDeprecated202001GetParamImagesImageRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Products::deprecated_202004_get_param_image`], set with a builder.
+pub struct ProductsDeprecated202004GetParamImageRequest<'a> {
+    inner: crate::products::ProductsDeprecated202004GetParamImageRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProductsDeprecated202004GetParamImageRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Products::deprecated_202004_get_param_images_count`], set with a builder.
+pub struct ProductsDeprecated202004GetParamImagesCountRequest<'a> {
+    inner: crate::products::ProductsDeprecated202004GetParamImagesCountRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProductsDeprecated202004GetParamImagesCountRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Products::deprecated_202004_get_param_images_image`], set with a builder.
+pub struct ProductsDeprecated202004GetParamImagesImageRequest<'a> {
+    inner: crate::products::ProductsDeprecated202004GetParamImagesImageRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProductsDeprecated202004GetParamImagesImageRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Products::deprecated_202007_get_param_image`], set with a builder.
+pub struct ProductsDeprecated202007GetParamImageRequest<'a> {
+    inner: crate::products::ProductsDeprecated202007GetParamImageRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProductsDeprecated202007GetParamImageRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Products::deprecated_202007_get_param_images_count`], set with a builder.
+pub struct ProductsDeprecated202007GetParamImagesCountRequest<'a> {
+    inner: crate::products::ProductsDeprecated202007GetParamImagesCountRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProductsDeprecated202007GetParamImagesCountRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Products::deprecated_202007_get_param_images_image`], set with a builder.
+pub struct ProductsDeprecated202007GetParamImagesImageRequest<'a> {
+    inner: crate::products::ProductsDeprecated202007GetParamImagesImageRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProductsDeprecated202007GetParamImagesImageRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Products::get_param_image`], set with a builder.
+pub struct ProductsGetParamImageRequest<'a> {
+    inner: crate::products::ProductsGetParamImageRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProductsGetParamImageRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Products::get_param_images_count`], set with a builder.
+pub struct ProductsGetParamImagesCountRequest<'a> {
+    inner: crate::products::ProductsGetParamImagesCountRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProductsGetParamImagesCountRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Products::get_param_images_image`], set with a builder.
+pub struct ProductsGetParamImagesImageRequest<'a> {
+    inner: crate::products::ProductsGetParamImagesImageRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProductsGetParamImagesImageRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Products::deprecated_202101_get_param_image`], set with a builder.
+pub struct ProductsDeprecated202101GetParamImageRequest<'a> {
+    inner: crate::products::ProductsDeprecated202101GetParamImageRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProductsDeprecated202101GetParamImageRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Products::deprecated_202101_get_param_images_count`], set with a builder.
+pub struct ProductsDeprecated202101GetParamImagesCountRequest<'a> {
+    inner: crate::products::ProductsDeprecated202101GetParamImagesCountRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProductsDeprecated202101GetParamImagesCountRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Products::deprecated_202101_get_param_images_image`], set with a builder.
+pub struct ProductsDeprecated202101GetParamImagesImageRequest<'a> {
+    inner: crate::products::ProductsDeprecated202101GetParamImagesImageRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProductsDeprecated202101GetParamImagesImageRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Products::deprecated_unstable_get_param_image`], set with a builder.
+pub struct ProductsDeprecatedUnstableGetParamImageRequest<'a> {
+    inner: crate::products::ProductsDeprecatedUnstableGetParamImageRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProductsDeprecatedUnstableGetParamImageRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Products::deprecated_unstable_get_param_images_count`], set with a builder.
+pub struct ProductsDeprecatedUnstableGetParamImagesCountRequest<'a> {
+    inner: crate::products::ProductsDeprecatedUnstableGetParamImagesCountRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProductsDeprecatedUnstableGetParamImagesCountRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Products::deprecated_unstable_get_param_images_image`], set with a builder.
+pub struct ProductsDeprecatedUnstableGetParamImagesImageRequest<'a> {
+    inner: crate::products::ProductsDeprecatedUnstableGetParamImagesImageRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProductsDeprecatedUnstableGetParamImagesImageRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Products::deprecated_202001_get_smart_collection`], set with a builder.
+pub struct ProductsDeprecated202001GetSmartCollectionRequest<'a> {
+    inner: crate::products::ProductsDeprecated202001GetSmartCollectionRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProductsDeprecated202001GetSmartCollectionRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `ids` query parameter.
+    pub fn ids(mut self, ids: &'a str) -> Self {
+        self.inner = self.inner.ids(ids);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `title` query parameter.
+    pub fn title(mut self, title: &'a str) -> Self {
+        self.inner = self.inner.title(title);
+        self
+    }
+
+    /// Sets the `product_id` query parameter.
+    pub fn product_id(mut self, product_id: &'a str) -> Self {
+        self.inner = self.inner.product_id(product_id);
+        self
+    }
+
+    /// Sets the `handle` query parameter.
+    pub fn handle(mut self, handle: &'a str) -> Self {
+        self.inner = self.inner.handle(handle);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.inner = self.inner.updated_at_min(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.inner = self.inner.updated_at_max(updated_at_max);
+        self
+    }
+
+    /// Sets the `published_at_min` query parameter.
+    pub fn published_at_min(mut self, published_at_min: &'a str) -> Self {
+        self.inner = self.inner.published_at_min(published_at_min);
+        self
+    }
+
+    /// Sets the `published_at_max` query parameter.
+    pub fn published_at_max(mut self, published_at_max: &'a str) -> Self {
+        self.inner = self.inner.published_at_max(published_at_max);
+        self
+    }
+
+    /// Sets the `published_status` query parameter.
+    pub fn published_status(mut self, published_status: &'a str) -> Self {
+        self.inner = self.inner.published_status(published_status);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Products::deprecated_202001_get_smart_collections_count`], set with a builder.
+pub struct ProductsDeprecated202001GetSmartCollectionsCountRequest<'a> {
+    inner: crate::products::ProductsDeprecated202001GetSmartCollectionsCountRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProductsDeprecated202001GetSmartCollectionsCountRequest<'a> {
+    /// Sets the `title` query parameter.
+    pub fn title(mut self, title: &'a str) -> Self {
+        self.inner = self.inner.title(title);
+        self
+    }
+
+    /// Sets the `product_id` query parameter.
+    pub fn product_id(mut self, product_id: &'a str) -> Self {
+        self.inner = self.inner.product_id(product_id);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.inner = self.inner.updated_at_min(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.inner = self.inner.updated_at_max(updated_at_max);
+        self
+    }
+
+    /// Sets the `published_at_min` query parameter.
+    pub fn published_at_min(mut self, published_at_min: &'a str) -> Self {
+        self.inner = self.inner.published_at_min(published_at_min);
+        self
+    }
+
+    /// Sets the `published_at_max` query parameter.
+    pub fn published_at_max(mut self, published_at_max: &'a str) -> Self {
+        self.inner = self.inner.published_at_max(published_at_max);
+        self
+    }
+
+    /// Sets the `published_status` query parameter.
+    pub fn published_status(mut self, published_status: &'a str) -> Self {
+        self.inner = self.inner.published_status(published_status);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Products::deprecated_202001_get_smart_collections_param_collection`], set with a builder.
+pub struct ProductsDeprecated202001GetSmartCollectionsParamCollectionRequest<'a> {
+    inner: crate::products::ProductsDeprecated202001GetSmartCollectionsParamCollectionRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProductsDeprecated202001GetSmartCollectionsParamCollectionRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Products::deprecated_202001_update_smart_collections_param_collection_order`], set with a builder.
+pub struct ProductsDeprecated202001UpdateSmartCollectionsParamCollectionOrderRequest<'a> {
+    inner:
+        crate::products::ProductsDeprecated202001UpdateSmartCollectionsParamCollectionOrderRequest<
+            'a,
+        >,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProductsDeprecated202001UpdateSmartCollectionsParamCollectionOrderRequest<'a> {
+    /// Sets the `products` query parameter.
+    pub fn products(mut self, products: &'a str) -> Self {
+        self.inner = self.inner.products(products);
+        self
+    }
+
+    /// Sets the `sort_order` query parameter.
+    pub fn sort_order(mut self, sort_order: &'a str) -> Self {
+        self.inner = self.inner.sort_order(sort_order);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Products::deprecated_202004_get_smart_collection`], set with a builder.
+pub struct ProductsDeprecated202004GetSmartCollectionRequest<'a> {
+    inner: crate::products::ProductsDeprecated202004GetSmartCollectionRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProductsDeprecated202004GetSmartCollectionRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `ids` query parameter.
+    pub fn ids(mut self, ids: &'a str) -> Self {
+        self.inner = self.inner.ids(ids);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `title` query parameter.
+    pub fn title(mut self, title: &'a str) -> Self {
+        self.inner = self.inner.title(title);
+        self
+    }
+
+    /// Sets the `product_id` query parameter.
+    pub fn product_id(mut self, product_id: &'a str) -> Self {
+        self.inner = self.inner.product_id(product_id);
+        self
+    }
+
+    /// Sets the `handle` query parameter.
+    pub fn handle(mut self, handle: &'a str) -> Self {
+        self.inner = self.inner.handle(handle);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.inner = self.inner.updated_at_min(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.inner = self.inner.updated_at_max(updated_at_max);
+        self
+    }
+
+    /// Sets the `published_at_min` query parameter.
+    pub fn published_at_min(mut self, published_at_min: &'a str) -> Self {
+        self.inner = self.inner.published_at_min(published_at_min);
+        self
+    }
+
+    /// Sets the `published_at_max` query parameter.
+    pub fn published_at_max(mut self, published_at_max: &'a str) -> Self {
+        self.inner = self.inner.published_at_max(published_at_max);
+        self
+    }
+
+    /// Sets the `published_status` query parameter.
+    pub fn published_status(mut self, published_status: &'a str) -> Self {
+        self.inner = self.inner.published_status(published_status);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Products::deprecated_202004_get_smart_collections_count`], set with a builder.
+pub struct ProductsDeprecated202004GetSmartCollectionsCountRequest<'a> {
+    inner: crate::products::ProductsDeprecated202004GetSmartCollectionsCountRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProductsDeprecated202004GetSmartCollectionsCountRequest<'a> {
+    /// Sets the `title` query parameter.
+    pub fn title(mut self, title: &'a str) -> Self {
+        self.inner = self.inner.title(title);
+        self
+    }
+
+    /// Sets the `product_id` query parameter.
+    pub fn product_id(mut self, product_id: &'a str) -> Self {
+        self.inner = self.inner.product_id(product_id);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.inner = self.inner.updated_at_min(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.inner = self.inner.updated_at_max(updated_at_max);
+        self
+    }
+
+    /// Sets the `published_at_min` query parameter.
+    pub fn published_at_min(mut self, published_at_min: &'a str) -> Self {
+        self.inner = self.inner.published_at_min(published_at_min);
+        self
+    }
+
+    /// Sets the `published_at_max` query parameter.
+    pub fn published_at_max(mut self, published_at_max: &'a str) -> Self {
+        self.inner = self.inner.published_at_max(published_at_max);
+        self
+    }
+
+    /// Sets the `published_status` query parameter.
+    pub fn published_status(mut self, published_status: &'a str) -> Self {
+        self.inner = self.inner.published_status(published_status);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Products::deprecated_202004_get_smart_collections_param_collection`], set with a builder.
+pub struct ProductsDeprecated202004GetSmartCollectionsParamCollectionRequest<'a> {
+    inner: crate::products::ProductsDeprecated202004GetSmartCollectionsParamCollectionRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProductsDeprecated202004GetSmartCollectionsParamCollectionRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Products::deprecated_202004_update_smart_collections_param_collection_order`], set with a builder.
+pub struct ProductsDeprecated202004UpdateSmartCollectionsParamCollectionOrderRequest<'a> {
+    inner:
+        crate::products::ProductsDeprecated202004UpdateSmartCollectionsParamCollectionOrderRequest<
+            'a,
+        >,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProductsDeprecated202004UpdateSmartCollectionsParamCollectionOrderRequest<'a> {
+    /// Sets the `products` query parameter.
+    pub fn products(mut self, products: &'a str) -> Self {
+        self.inner = self.inner.products(products);
+        self
+    }
+
+    /// Sets the `sort_order` query parameter.
+    pub fn sort_order(mut self, sort_order: &'a str) -> Self {
+        self.inner = self.inner.sort_order(sort_order);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Products::deprecated_202007_get_smart_collection`], set with a builder.
+pub struct ProductsDeprecated202007GetSmartCollectionRequest<'a> {
+    inner: crate::products::ProductsDeprecated202007GetSmartCollectionRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProductsDeprecated202007GetSmartCollectionRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `ids` query parameter.
+    pub fn ids(mut self, ids: &'a str) -> Self {
+        self.inner = self.inner.ids(ids);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `title` query parameter.
+    pub fn title(mut self, title: &'a str) -> Self {
+        self.inner = self.inner.title(title);
+        self
+    }
+
+    /// Sets the `product_id` query parameter.
+    pub fn product_id(mut self, product_id: &'a str) -> Self {
+        self.inner = self.inner.product_id(product_id);
+        self
+    }
+
+    /// Sets the `handle` query parameter.
+    pub fn handle(mut self, handle: &'a str) -> Self {
+        self.inner = self.inner.handle(handle);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.inner = self.inner.updated_at_min(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.inner = self.inner.updated_at_max(updated_at_max);
+        self
+    }
+
+    /// Sets the `published_at_min` query parameter.
+    pub fn published_at_min(mut self, published_at_min: &'a str) -> Self {
+        self.inner = self.inner.published_at_min(published_at_min);
+        self
+    }
+
+    /// Sets the `published_at_max` query parameter.
+    pub fn published_at_max(mut self, published_at_max: &'a str) -> Self {
+        self.inner = self.inner.published_at_max(published_at_max);
+        self
+    }
+
+    /// Sets the `published_status` query parameter.
+    pub fn published_status(mut self, published_status: &'a str) -> Self {
+        self.inner = self.inner.published_status(published_status);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Products::deprecated_202007_get_smart_collections_count`], set with a builder.
+pub struct ProductsDeprecated202007GetSmartCollectionsCountRequest<'a> {
+    inner: crate::products::ProductsDeprecated202007GetSmartCollectionsCountRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProductsDeprecated202007GetSmartCollectionsCountRequest<'a> {
+    /// Sets the `title` query parameter.
+    pub fn title(mut self, title: &'a str) -> Self {
+        self.inner = self.inner.title(title);
+        self
+    }
+
+    /// Sets the `product_id` query parameter.
+    pub fn product_id(mut self, product_id: &'a str) -> Self {
+        self.inner = self.inner.product_id(product_id);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.inner = self.inner.updated_at_min(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.inner = self.inner.updated_at_max(updated_at_max);
+        self
+    }
+
+    /// Sets the `published_at_min` query parameter.
+    pub fn published_at_min(mut self, published_at_min: &'a str) -> Self {
+        self.inner = self.inner.published_at_min(published_at_min);
+        self
+    }
+
+    /// Sets the `published_at_max` query parameter.
+    pub fn published_at_max(mut self, published_at_max: &'a str) -> Self {
+        self.inner = self.inner.published_at_max(published_at_max);
+        self
+    }
+
+    /// Sets the `published_status` query parameter.
+    pub fn published_status(mut self, published_status: &'a str) -> Self {
+        self.inner = self.inner.published_status(published_status);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Products::deprecated_202007_get_smart_collections_param_collection`], set with a builder.
+pub struct ProductsDeprecated202007GetSmartCollectionsParamCollectionRequest<'a> {
+    inner: crate::products::ProductsDeprecated202007GetSmartCollectionsParamCollectionRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProductsDeprecated202007GetSmartCollectionsParamCollectionRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Products::deprecated_202007_update_smart_collections_param_collection_order`], set with a builder.
+pub struct ProductsDeprecated202007UpdateSmartCollectionsParamCollectionOrderRequest<'a> {
+    inner:
+        crate::products::ProductsDeprecated202007UpdateSmartCollectionsParamCollectionOrderRequest<
+            'a,
+        >,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProductsDeprecated202007UpdateSmartCollectionsParamCollectionOrderRequest<'a> {
+    /// Sets the `products` query parameter.
+    pub fn products(mut self, products: &'a str) -> Self {
+        self.inner = self.inner.products(products);
+        self
+    }
+
+    /// Sets the `sort_order` query parameter.
+    pub fn sort_order(mut self, sort_order: &'a str) -> Self {
+        self.inner = self.inner.sort_order(sort_order);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Products::get_smart_collection`], set with a builder.
+pub struct ProductsGetSmartCollectionRequest<'a> {
+    inner: crate::products::ProductsGetSmartCollectionRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProductsGetSmartCollectionRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `ids` query parameter.
+    pub fn ids(mut self, ids: &'a str) -> Self {
+        self.inner = self.inner.ids(ids);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `title` query parameter.
+    pub fn title(mut self, title: &'a str) -> Self {
+        self.inner = self.inner.title(title);
+        self
+    }
+
+    /// Sets the `product_id` query parameter.
+    pub fn product_id(mut self, product_id: &'a str) -> Self {
+        self.inner = self.inner.product_id(product_id);
+        self
+    }
+
+    /// Sets the `handle` query parameter.
+    pub fn handle(mut self, handle: &'a str) -> Self {
+        self.inner = self.inner.handle(handle);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.inner = self.inner.updated_at_min(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.inner = self.inner.updated_at_max(updated_at_max);
+        self
+    }
+
+    /// Sets the `published_at_min` query parameter.
+    pub fn published_at_min(mut self, published_at_min: &'a str) -> Self {
+        self.inner = self.inner.published_at_min(published_at_min);
+        self
+    }
+
+    /// Sets the `published_at_max` query parameter.
+    pub fn published_at_max(mut self, published_at_max: &'a str) -> Self {
+        self.inner = self.inner.published_at_max(published_at_max);
+        self
+    }
+
+    /// Sets the `published_status` query parameter.
+    pub fn published_status(mut self, published_status: &'a str) -> Self {
+        self.inner = self.inner.published_status(published_status);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Products::get_smart_collections_count`], set with a builder.
+pub struct ProductsGetSmartCollectionsCountRequest<'a> {
+    inner: crate::products::ProductsGetSmartCollectionsCountRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProductsGetSmartCollectionsCountRequest<'a> {
+    /// Sets the `title` query parameter.
+    pub fn title(mut self, title: &'a str) -> Self {
+        self.inner = self.inner.title(title);
+        self
+    }
+
+    /// Sets the `product_id` query parameter.
+    pub fn product_id(mut self, product_id: &'a str) -> Self {
+        self.inner = self.inner.product_id(product_id);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.inner = self.inner.updated_at_min(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.inner = self.inner.updated_at_max(updated_at_max);
+        self
+    }
+
+    /// Sets the `published_at_min` query parameter.
+    pub fn published_at_min(mut self, published_at_min: &'a str) -> Self {
+        self.inner = self.inner.published_at_min(published_at_min);
+        self
+    }
+
+    /// Sets the `published_at_max` query parameter.
+    pub fn published_at_max(mut self, published_at_max: &'a str) -> Self {
+        self.inner = self.inner.published_at_max(published_at_max);
+        self
+    }
+
+    /// Sets the `published_status` query parameter.
+    pub fn published_status(mut self, published_status: &'a str) -> Self {
+        self.inner = self.inner.published_status(published_status);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Products::get_smart_collections_param_collection`], set with a builder.
+pub struct ProductsGetSmartCollectionsParamCollectionRequest<'a> {
+    inner: crate::products::ProductsGetSmartCollectionsParamCollectionRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProductsGetSmartCollectionsParamCollectionRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Products::update_smart_collections_param_collection_order`], set with a builder.
+pub struct ProductsUpdateSmartCollectionsParamCollectionOrderRequest<'a> {
+    inner: crate::products::ProductsUpdateSmartCollectionsParamCollectionOrderRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProductsUpdateSmartCollectionsParamCollectionOrderRequest<'a> {
+    /// Sets the `products` query parameter.
+    pub fn products(mut self, products: &'a str) -> Self {
+        self.inner = self.inner.products(products);
+        self
+    }
+
+    /// Sets the `sort_order` query parameter.
+    pub fn sort_order(mut self, sort_order: &'a str) -> Self {
+        self.inner = self.inner.sort_order(sort_order);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Products::deprecated_202101_get_smart_collection`], set with a builder.
+pub struct ProductsDeprecated202101GetSmartCollectionRequest<'a> {
+    inner: crate::products::ProductsDeprecated202101GetSmartCollectionRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProductsDeprecated202101GetSmartCollectionRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `ids` query parameter.
+    pub fn ids(mut self, ids: &'a str) -> Self {
+        self.inner = self.inner.ids(ids);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `title` query parameter.
+    pub fn title(mut self, title: &'a str) -> Self {
+        self.inner = self.inner.title(title);
+        self
+    }
+
+    /// Sets the `product_id` query parameter.
+    pub fn product_id(mut self, product_id: &'a str) -> Self {
+        self.inner = self.inner.product_id(product_id);
+        self
+    }
+
+    /// Sets the `handle` query parameter.
+    pub fn handle(mut self, handle: &'a str) -> Self {
+        self.inner = self.inner.handle(handle);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.inner = self.inner.updated_at_min(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.inner = self.inner.updated_at_max(updated_at_max);
+        self
+    }
+
+    /// Sets the `published_at_min` query parameter.
+    pub fn published_at_min(mut self, published_at_min: &'a str) -> Self {
+        self.inner = self.inner.published_at_min(published_at_min);
+        self
+    }
+
+    /// Sets the `published_at_max` query parameter.
+    pub fn published_at_max(mut self, published_at_max: &'a str) -> Self {
+        self.inner = self.inner.published_at_max(published_at_max);
+        self
+    }
+
+    /// Sets the `published_status` query parameter.
+    pub fn published_status(mut self, published_status: &'a str) -> Self {
+        self.inner = self.inner.published_status(published_status);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Products::deprecated_202101_get_smart_collections_count`], set with a builder.
+pub struct ProductsDeprecated202101GetSmartCollectionsCountRequest<'a> {
+    inner: crate::products::ProductsDeprecated202101GetSmartCollectionsCountRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProductsDeprecated202101GetSmartCollectionsCountRequest<'a> {
+    /// Sets the `title` query parameter.
+    pub fn title(mut self, title: &'a str) -> Self {
+        self.inner = self.inner.title(title);
+        self
+    }
+
+    /// Sets the `product_id` query parameter.
+    pub fn product_id(mut self, product_id: &'a str) -> Self {
+        self.inner = self.inner.product_id(product_id);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.inner = self.inner.updated_at_min(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.inner = self.inner.updated_at_max(updated_at_max);
+        self
+    }
+
+    /// Sets the `published_at_min` query parameter.
+    pub fn published_at_min(mut self, published_at_min: &'a str) -> Self {
+        self.inner = self.inner.published_at_min(published_at_min);
+        self
+    }
+
+    /// Sets the `published_at_max` query parameter.
+    pub fn published_at_max(mut self, published_at_max: &'a str) -> Self {
+        self.inner = self.inner.published_at_max(published_at_max);
+        self
+    }
+
+    /// Sets the `published_status` query parameter.
+    pub fn published_status(mut self, published_status: &'a str) -> Self {
+        self.inner = self.inner.published_status(published_status);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Products::deprecated_202101_get_smart_collections_param_collection`], set with a builder.
+pub struct ProductsDeprecated202101GetSmartCollectionsParamCollectionRequest<'a> {
+    inner: crate::products::ProductsDeprecated202101GetSmartCollectionsParamCollectionRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProductsDeprecated202101GetSmartCollectionsParamCollectionRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Products::deprecated_202101_update_smart_collections_param_collection_order`], set with a builder.
+pub struct ProductsDeprecated202101UpdateSmartCollectionsParamCollectionOrderRequest<'a> {
+    inner:
+        crate::products::ProductsDeprecated202101UpdateSmartCollectionsParamCollectionOrderRequest<
+            'a,
+        >,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProductsDeprecated202101UpdateSmartCollectionsParamCollectionOrderRequest<'a> {
+    /// Sets the `products` query parameter.
+    pub fn products(mut self, products: &'a str) -> Self {
+        self.inner = self.inner.products(products);
+        self
+    }
+
+    /// Sets the `sort_order` query parameter.
+    pub fn sort_order(mut self, sort_order: &'a str) -> Self {
+        self.inner = self.inner.sort_order(sort_order);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Products::deprecated_unstable_get_smart_collection`], set with a builder.
+pub struct ProductsDeprecatedUnstableGetSmartCollectionRequest<'a> {
+    inner: crate::products::ProductsDeprecatedUnstableGetSmartCollectionRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProductsDeprecatedUnstableGetSmartCollectionRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `ids` query parameter.
+    pub fn ids(mut self, ids: &'a str) -> Self {
+        self.inner = self.inner.ids(ids);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `title` query parameter.
+    pub fn title(mut self, title: &'a str) -> Self {
+        self.inner = self.inner.title(title);
+        self
+    }
+
+    /// Sets the `product_id` query parameter.
+    pub fn product_id(mut self, product_id: &'a str) -> Self {
+        self.inner = self.inner.product_id(product_id);
+        self
+    }
+
+    /// Sets the `handle` query parameter.
+    pub fn handle(mut self, handle: &'a str) -> Self {
+        self.inner = self.inner.handle(handle);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.inner = self.inner.updated_at_min(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.inner = self.inner.updated_at_max(updated_at_max);
+        self
+    }
+
+    /// Sets the `published_at_min` query parameter.
+    pub fn published_at_min(mut self, published_at_min: &'a str) -> Self {
+        self.inner = self.inner.published_at_min(published_at_min);
+        self
+    }
+
+    /// Sets the `published_at_max` query parameter.
+    pub fn published_at_max(mut self, published_at_max: &'a str) -> Self {
+        self.inner = self.inner.published_at_max(published_at_max);
+        self
+    }
+
+    /// Sets the `published_status` query parameter.
+    pub fn published_status(mut self, published_status: &'a str) -> Self {
+        self.inner = self.inner.published_status(published_status);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Products::deprecated_unstable_get_smart_collections_count`], set with a builder.
+pub struct ProductsDeprecatedUnstableGetSmartCollectionsCountRequest<'a> {
+    inner: crate::products::ProductsDeprecatedUnstableGetSmartCollectionsCountRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProductsDeprecatedUnstableGetSmartCollectionsCountRequest<'a> {
+    /// Sets the `title` query parameter.
+    pub fn title(mut self, title: &'a str) -> Self {
+        self.inner = self.inner.title(title);
+        self
+    }
+
+    /// Sets the `product_id` query parameter.
+    pub fn product_id(mut self, product_id: &'a str) -> Self {
+        self.inner = self.inner.product_id(product_id);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.inner = self.inner.updated_at_min(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.inner = self.inner.updated_at_max(updated_at_max);
+        self
+    }
+
+    /// Sets the `published_at_min` query parameter.
+    pub fn published_at_min(mut self, published_at_min: &'a str) -> Self {
+        self.inner = self.inner.published_at_min(published_at_min);
+        self
+    }
+
+    /// Sets the `published_at_max` query parameter.
+    pub fn published_at_max(mut self, published_at_max: &'a str) -> Self {
+        self.inner = self.inner.published_at_max(published_at_max);
+        self
+    }
+
+    /// Sets the `published_status` query parameter.
+    pub fn published_status(mut self, published_status: &'a str) -> Self {
+        self.inner = self.inner.published_status(published_status);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Products::deprecated_unstable_get_smart_collections_param_collection`], set with a builder.
+pub struct ProductsDeprecatedUnstableGetSmartCollectionsParamCollectionRequest<'a> {
+    inner: crate::products::ProductsDeprecatedUnstableGetSmartCollectionsParamCollectionRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProductsDeprecatedUnstableGetSmartCollectionsParamCollectionRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Products::deprecated_unstable_update_smart_collections_param_collection_order`], set with a builder.
+pub struct ProductsDeprecatedUnstableUpdateSmartCollectionsParamCollectionOrderRequest<'a> {
+            inner: crate::products::ProductsDeprecatedUnstableUpdateSmartCollectionsParamCollectionOrderRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a> ProductsDeprecatedUnstableUpdateSmartCollectionsParamCollectionOrderRequest<'a> {
+    /// Sets the `products` query parameter.
+    pub fn products(mut self, products: &'a str) -> Self {
+        self.inner = self.inner.products(products);
+        self
+    }
+
+    /// Sets the `sort_order` query parameter.
+    pub fn sort_order(mut self, sort_order: &'a str) -> Self {
+        self.inner = self.inner.sort_order(sort_order);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::products::Products`].
+pub struct Products {
+    inner: crate::products::Products,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Products {
+    pub(crate) fn new(
+        inner: crate::products::Products,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Products { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::products::Products::deprecated_202001_get_collect`].
+    pub fn deprecated_202001_get_collect(
+        &self,
+        limit: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_get_collect(limit, since_id, fields),
+        )
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202001_get_collect_request`].
+    pub fn deprecated_202001_get_collect_request(
+        &self,
+    ) -> ProductsDeprecated202001GetCollectRequest<'_> {
+        ProductsDeprecated202001GetCollectRequest {
+            inner: self.inner.deprecated_202001_get_collect_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202001_create_collects`].
+    pub fn deprecated_202001_create_collects(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202001_create_collects(body))
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202001_get_collects_param_collect`].
+    pub fn deprecated_202001_get_collects_param_collect(
+        &self,
+        collect_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_get_collects_param_collect(collect_id, fields),
+        )
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202001_get_collects_param_collect_request`].
+    pub fn deprecated_202001_get_collects_param_collect_request<'a>(
+        &'a self,
+        collect_id: &'a str,
+    ) -> ProductsDeprecated202001GetCollectsParamCollectRequest<'a> {
+        ProductsDeprecated202001GetCollectsParamCollectRequest {
+            inner: self
+                .inner
+                .deprecated_202001_get_collects_param_collect_request(collect_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202001_delete_collects_param_collect`].
+    pub fn deprecated_202001_delete_collects_param_collect(
+        &self,
+        collect_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_delete_collects_param_collect(collect_id),
+        )
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202001_get_collects_count`].
+    pub fn deprecated_202001_get_collects_count(
+        &self,
+        collection_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_get_collects_count(collection_id),
+        )
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202001_get_collects_count_request`].
+    pub fn deprecated_202001_get_collects_count_request(
+        &self,
+    ) -> ProductsDeprecated202001GetCollectsCountRequest<'_> {
+        ProductsDeprecated202001GetCollectsCountRequest {
+            inner: self.inner.deprecated_202001_get_collects_count_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202004_get_collect`].
+    pub fn deprecated_202004_get_collect(
+        &self,
+        limit: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_get_collect(limit, since_id, fields),
+        )
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202004_get_collect_request`].
+    pub fn deprecated_202004_get_collect_request(
+        &self,
+    ) -> ProductsDeprecated202004GetCollectRequest<'_> {
+        ProductsDeprecated202004GetCollectRequest {
+            inner: self.inner.deprecated_202004_get_collect_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202004_create_collects`].
+    pub fn deprecated_202004_create_collects(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202004_create_collects(body))
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202004_get_collects_param_collect`].
+    pub fn deprecated_202004_get_collects_param_collect(
+        &self,
+        collect_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_get_collects_param_collect(collect_id, fields),
+        )
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202004_get_collects_param_collect_request`].
+    pub fn deprecated_202004_get_collects_param_collect_request<'a>(
+        &'a self,
+        collect_id: &'a str,
+    ) -> ProductsDeprecated202004GetCollectsParamCollectRequest<'a> {
+        ProductsDeprecated202004GetCollectsParamCollectRequest {
+            inner: self
+                .inner
+                .deprecated_202004_get_collects_param_collect_request(collect_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202004_delete_collects_param_collect`].
+    pub fn deprecated_202004_delete_collects_param_collect(
+        &self,
+        collect_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_delete_collects_param_collect(collect_id),
+        )
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202004_get_collects_count`].
+    pub fn deprecated_202004_get_collects_count(
+        &self,
+        collection_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_get_collects_count(collection_id),
+        )
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202004_get_collects_count_request`].
+    pub fn deprecated_202004_get_collects_count_request(
+        &self,
+    ) -> ProductsDeprecated202004GetCollectsCountRequest<'_> {
+        ProductsDeprecated202004GetCollectsCountRequest {
+            inner: self.inner.deprecated_202004_get_collects_count_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202007_get_collect`].
+    pub fn deprecated_202007_get_collect(
+        &self,
+        limit: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_get_collect(limit, since_id, fields),
+        )
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202007_get_collect_request`].
+    pub fn deprecated_202007_get_collect_request(
+        &self,
+    ) -> ProductsDeprecated202007GetCollectRequest<'_> {
+        ProductsDeprecated202007GetCollectRequest {
+            inner: self.inner.deprecated_202007_get_collect_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202007_create_collects`].
+    pub fn deprecated_202007_create_collects(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202007_create_collects(body))
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202007_get_collects_param_collect`].
+    pub fn deprecated_202007_get_collects_param_collect(
+        &self,
+        collect_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_get_collects_param_collect(collect_id, fields),
+        )
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202007_get_collects_param_collect_request`].
+    pub fn deprecated_202007_get_collects_param_collect_request<'a>(
+        &'a self,
+        collect_id: &'a str,
+    ) -> ProductsDeprecated202007GetCollectsParamCollectRequest<'a> {
+        ProductsDeprecated202007GetCollectsParamCollectRequest {
+            inner: self
+                .inner
+                .deprecated_202007_get_collects_param_collect_request(collect_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202007_delete_collects_param_collect`].
+    pub fn deprecated_202007_delete_collects_param_collect(
+        &self,
+        collect_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_delete_collects_param_collect(collect_id),
+        )
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202007_get_collects_count`].
+    pub fn deprecated_202007_get_collects_count(
+        &self,
+        collection_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_get_collects_count(collection_id),
+        )
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202007_get_collects_count_request`].
+    pub fn deprecated_202007_get_collects_count_request(
+        &self,
+    ) -> ProductsDeprecated202007GetCollectsCountRequest<'_> {
+        ProductsDeprecated202007GetCollectsCountRequest {
+            inner: self.inner.deprecated_202007_get_collects_count_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::products::Products::get_collect`].
+    pub fn get_collect(
+        &self,
+        limit: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.get_collect(limit, since_id, fields))
+    }
+    /// The blocking version of [`crate::products::Products::get_collect_request`].
+    pub fn get_collect_request(&self) -> ProductsGetCollectRequest<'_> {
+        ProductsGetCollectRequest {
+            inner: self.inner.get_collect_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::products::Products::create_collects`].
+    pub fn create_collects(&self, body: &serde_json::Value) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.create_collects(body))
+    }
+    /// The blocking version of [`crate::products::Products::get_collects_param_collect`].
+    pub fn get_collects_param_collect(
+        &self,
+        collect_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.get_collects_param_collect(collect_id, fields))
+    }
+    /// The blocking version of [`crate::products::Products::get_collects_param_collect_request`].
+    pub fn get_collects_param_collect_request<'a>(
+        &'a self,
+        collect_id: &'a str,
+    ) -> ProductsGetCollectsParamCollectRequest<'a> {
+        ProductsGetCollectsParamCollectRequest {
+            inner: self.inner.get_collects_param_collect_request(collect_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::products::Products::delete_collects_param_collect`].
+    pub fn delete_collects_param_collect(
+        &self,
+        collect_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete_collects_param_collect(collect_id))
+    }
+    /// The blocking version of [`crate::products::Products::get_collects_count`].
+    pub fn get_collects_count(&self, collection_id: i64) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.get_collects_count(collection_id))
+    }
+    /// The blocking version of [`crate::products::Products::get_collects_count_request`].
+    pub fn get_collects_count_request(&self) -> ProductsGetCollectsCountRequest<'_> {
+        ProductsGetCollectsCountRequest {
+            inner: self.inner.get_collects_count_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202101_get_collect`].
+    pub fn deprecated_202101_get_collect(
+        &self,
+        limit: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_get_collect(limit, since_id, fields),
+        )
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202101_get_collect_request`].
+    pub fn deprecated_202101_get_collect_request(
+        &self,
+    ) -> ProductsDeprecated202101GetCollectRequest<'_> {
+        ProductsDeprecated202101GetCollectRequest {
+            inner: self.inner.deprecated_202101_get_collect_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202101_create_collects`].
+    pub fn deprecated_202101_create_collects(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202101_create_collects(body))
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202101_get_collects_param_collect`].
+    pub fn deprecated_202101_get_collects_param_collect(
+        &self,
+        collect_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_get_collects_param_collect(collect_id, fields),
+        )
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202101_get_collects_param_collect_request`].
+    pub fn deprecated_202101_get_collects_param_collect_request<'a>(
+        &'a self,
+        collect_id: &'a str,
+    ) -> ProductsDeprecated202101GetCollectsParamCollectRequest<'a> {
+        ProductsDeprecated202101GetCollectsParamCollectRequest {
+            inner: self
+                .inner
+                .deprecated_202101_get_collects_param_collect_request(collect_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202101_delete_collects_param_collect`].
+    pub fn deprecated_202101_delete_collects_param_collect(
+        &self,
+        collect_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_delete_collects_param_collect(collect_id),
+        )
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202101_get_collects_count`].
+    pub fn deprecated_202101_get_collects_count(
+        &self,
+        collection_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_get_collects_count(collection_id),
+        )
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202101_get_collects_count_request`].
+    pub fn deprecated_202101_get_collects_count_request(
+        &self,
+    ) -> ProductsDeprecated202101GetCollectsCountRequest<'_> {
+        ProductsDeprecated202101GetCollectsCountRequest {
+            inner: self.inner.deprecated_202101_get_collects_count_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_unstable_get_collect`].
+    pub fn deprecated_unstable_get_collect(
+        &self,
+        limit: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_get_collect(limit, since_id, fields),
+        )
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_unstable_get_collect_request`].
+    pub fn deprecated_unstable_get_collect_request(
+        &self,
+    ) -> ProductsDeprecatedUnstableGetCollectRequest<'_> {
+        ProductsDeprecatedUnstableGetCollectRequest {
+            inner: self.inner.deprecated_unstable_get_collect_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_unstable_create_collects`].
+    pub fn deprecated_unstable_create_collects(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_unstable_create_collects(body))
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_unstable_get_collects_param_collect`].
+    pub fn deprecated_unstable_get_collects_param_collect(
+        &self,
+        collect_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_get_collects_param_collect(collect_id, fields),
+        )
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_unstable_get_collects_param_collect_request`].
+    pub fn deprecated_unstable_get_collects_param_collect_request<'a>(
+        &'a self,
+        collect_id: &'a str,
+    ) -> ProductsDeprecatedUnstableGetCollectsParamCollectRequest<'a> {
+        ProductsDeprecatedUnstableGetCollectsParamCollectRequest {
+            inner: self
+                .inner
+                .deprecated_unstable_get_collects_param_collect_request(collect_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_unstable_delete_collects_param_collect`].
+    pub fn deprecated_unstable_delete_collects_param_collect(
+        &self,
+        collect_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_delete_collects_param_collect(collect_id),
+        )
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_unstable_get_collects_count`].
+    pub fn deprecated_unstable_get_collects_count(
+        &self,
+        collection_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_get_collects_count(collection_id),
+        )
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_unstable_get_collects_count_request`].
+    pub fn deprecated_unstable_get_collects_count_request(
+        &self,
+    ) -> ProductsDeprecatedUnstableGetCollectsCountRequest<'_> {
+        ProductsDeprecatedUnstableGetCollectsCountRequest {
+            inner: self.inner.deprecated_unstable_get_collects_count_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202001_get_collections_param_collection`].
+    pub fn deprecated_202001_get_collections_param_collection(
+        &self,
+        collection_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_get_collections_param_collection(collection_id, fields),
+        )
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202001_get_collections_param_collection_request`].
+    pub fn deprecated_202001_get_collections_param_collection_request<'a>(
+        &'a self,
+        collection_id: &'a str,
+    ) -> ProductsDeprecated202001GetCollectionsParamCollectionRequest<'a> {
+        ProductsDeprecated202001GetCollectionsParamCollectionRequest {
+            inner: self
+                .inner
+                .deprecated_202001_get_collections_param_collection_request(collection_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202001_get_collections_param_collection_products`].
+    pub fn deprecated_202001_get_collections_param_collection_products(
+        &self,
+        collection_id: &str,
+        limit: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_get_collections_param_collection_products(collection_id, limit),
+        )
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202001_get_collections_param_collection_products_request`].
+    pub fn deprecated_202001_get_collections_param_collection_products_request<'a>(
+        &'a self,
+        collection_id: &'a str,
+    ) -> ProductsDeprecated202001GetCollectionsParamCollectionProductsRequest<'a> {
+        ProductsDeprecated202001GetCollectionsParamCollectionProductsRequest {
+            inner: self
+                .inner
+                .deprecated_202001_get_collections_param_collection_products_request(collection_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202004_get_collections_param_collection`].
+    pub fn deprecated_202004_get_collections_param_collection(
+        &self,
+        collection_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_get_collections_param_collection(collection_id, fields),
+        )
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202004_get_collections_param_collection_request`].
+    pub fn deprecated_202004_get_collections_param_collection_request<'a>(
+        &'a self,
+        collection_id: &'a str,
+    ) -> ProductsDeprecated202004GetCollectionsParamCollectionRequest<'a> {
+        ProductsDeprecated202004GetCollectionsParamCollectionRequest {
+            inner: self
+                .inner
+                .deprecated_202004_get_collections_param_collection_request(collection_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202004_get_collections_param_collection_products`].
+    pub fn deprecated_202004_get_collections_param_collection_products(
+        &self,
+        collection_id: &str,
+        limit: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_get_collections_param_collection_products(collection_id, limit),
+        )
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202004_get_collections_param_collection_products_request`].
+    pub fn deprecated_202004_get_collections_param_collection_products_request<'a>(
+        &'a self,
+        collection_id: &'a str,
+    ) -> ProductsDeprecated202004GetCollectionsParamCollectionProductsRequest<'a> {
+        ProductsDeprecated202004GetCollectionsParamCollectionProductsRequest {
+            inner: self
+                .inner
+                .deprecated_202004_get_collections_param_collection_products_request(collection_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202007_get_collections_param_collection`].
+    pub fn deprecated_202007_get_collections_param_collection(
+        &self,
+        collection_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_get_collections_param_collection(collection_id, fields),
+        )
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202007_get_collections_param_collection_request`].
+    pub fn deprecated_202007_get_collections_param_collection_request<'a>(
+        &'a self,
+        collection_id: &'a str,
+    ) -> ProductsDeprecated202007GetCollectionsParamCollectionRequest<'a> {
+        ProductsDeprecated202007GetCollectionsParamCollectionRequest {
+            inner: self
+                .inner
+                .deprecated_202007_get_collections_param_collection_request(collection_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202007_get_collections_param_collection_products`].
+    pub fn deprecated_202007_get_collections_param_collection_products(
+        &self,
+        collection_id: &str,
+        limit: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_get_collections_param_collection_products(collection_id, limit),
+        )
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202007_get_collections_param_collection_products_request`].
+    pub fn deprecated_202007_get_collections_param_collection_products_request<'a>(
+        &'a self,
+        collection_id: &'a str,
+    ) -> ProductsDeprecated202007GetCollectionsParamCollectionProductsRequest<'a> {
+        ProductsDeprecated202007GetCollectionsParamCollectionProductsRequest {
+            inner: self
+                .inner
+                .deprecated_202007_get_collections_param_collection_products_request(collection_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::products::Products::get_collections_param_collection`].
+    pub fn get_collections_param_collection(
+        &self,
+        collection_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .get_collections_param_collection(collection_id, fields),
+        )
+    }
+    /// The blocking version of [`crate::products::Products::get_collections_param_collection_request`].
+    pub fn get_collections_param_collection_request<'a>(
+        &'a self,
+        collection_id: &'a str,
+    ) -> ProductsGetCollectionsParamCollectionRequest<'a> {
+        ProductsGetCollectionsParamCollectionRequest {
+            inner: self
+                .inner
+                .get_collections_param_collection_request(collection_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::products::Products::get_collections_param_collection_products`].
+    pub fn get_collections_param_collection_products(
+        &self,
+        collection_id: &str,
+        limit: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .get_collections_param_collection_products(collection_id, limit),
+        )
+    }
+    /// The blocking version of [`crate::products::Products::get_collections_param_collection_products_request`].
+    pub fn get_collections_param_collection_products_request<'a>(
+        &'a self,
+        collection_id: &'a str,
+    ) -> ProductsGetCollectionsParamCollectionProductsRequest<'a> {
+        ProductsGetCollectionsParamCollectionProductsRequest {
+            inner: self
+                .inner
+                .get_collections_param_collection_products_request(collection_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202101_get_collections_param_collection`].
+    pub fn deprecated_202101_get_collections_param_collection(
+        &self,
+        collection_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_get_collections_param_collection(collection_id, fields),
+        )
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202101_get_collections_param_collection_request`].
+    pub fn deprecated_202101_get_collections_param_collection_request<'a>(
+        &'a self,
+        collection_id: &'a str,
+    ) -> ProductsDeprecated202101GetCollectionsParamCollectionRequest<'a> {
+        ProductsDeprecated202101GetCollectionsParamCollectionRequest {
+            inner: self
+                .inner
+                .deprecated_202101_get_collections_param_collection_request(collection_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202101_get_collections_param_collection_products`].
+    pub fn deprecated_202101_get_collections_param_collection_products(
+        &self,
+        collection_id: &str,
+        limit: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_get_collections_param_collection_products(collection_id, limit),
+        )
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202101_get_collections_param_collection_products_request`].
+    pub fn deprecated_202101_get_collections_param_collection_products_request<'a>(
+        &'a self,
+        collection_id: &'a str,
+    ) -> ProductsDeprecated202101GetCollectionsParamCollectionProductsRequest<'a> {
+        ProductsDeprecated202101GetCollectionsParamCollectionProductsRequest {
+            inner: self
+                .inner
+                .deprecated_202101_get_collections_param_collection_products_request(collection_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_unstable_get_collections_param_collection`].
+    pub fn deprecated_unstable_get_collections_param_collection(
+        &self,
+        collection_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_get_collections_param_collection(collection_id, fields),
+        )
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_unstable_get_collections_param_collection_request`].
+    pub fn deprecated_unstable_get_collections_param_collection_request<'a>(
+        &'a self,
+        collection_id: &'a str,
+    ) -> ProductsDeprecatedUnstableGetCollectionsParamCollectionRequest<'a> {
+        ProductsDeprecatedUnstableGetCollectionsParamCollectionRequest {
+            inner: self
+                .inner
+                .deprecated_unstable_get_collections_param_collection_request(collection_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_unstable_get_collections_param_collection_products`].
+    pub fn deprecated_unstable_get_collections_param_collection_products(
+        &self,
+        collection_id: &str,
+        limit: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_get_collections_param_collection_products(
+                    collection_id,
+                    limit,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_unstable_get_collections_param_collection_products_request`].
+    pub fn deprecated_unstable_get_collections_param_collection_products_request<'a>(
+        &'a self,
+        collection_id: &'a str,
+    ) -> ProductsDeprecatedUnstableGetCollectionsParamCollectionProductsRequest<'a> {
+        ProductsDeprecatedUnstableGetCollectionsParamCollectionProductsRequest {
+            inner: self
+                .inner
+                .deprecated_unstable_get_collections_param_collection_products_request(
+                    collection_id,
+                ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202001_get_custom_collection`].
+    pub fn deprecated_202001_get_custom_collection(
+        &self,
+        limit: &str,
+        ids: &str,
+        since_id: &str,
+        title: &str,
+        product_id: &str,
+        handle: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202001_get_custom_collection(
+                limit,
+                ids,
+                since_id,
+                title,
+                product_id,
+                handle,
+                updated_at_min,
+                updated_at_max,
+                published_at_min,
+                published_at_max,
+                published_status,
+                fields,
+            ))
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202001_get_custom_collection_request`].
+    pub fn deprecated_202001_get_custom_collection_request(
+        &self,
+    ) -> ProductsDeprecated202001GetCustomCollectionRequest<'_> {
+        ProductsDeprecated202001GetCustomCollectionRequest {
+            inner: self.inner.deprecated_202001_get_custom_collection_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202001_create_custom_collections`].
+    pub fn deprecated_202001_create_custom_collections(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202001_create_custom_collections(body))
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202001_get_custom_collections_count`].
+    pub fn deprecated_202001_get_custom_collections_count(
+        &self,
+        title: &str,
+        product_id: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202001_get_custom_collections_count(
+                title,
+                product_id,
+                updated_at_min,
+                updated_at_max,
+                published_at_min,
+                published_at_max,
+                published_status,
+            ))
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202001_get_custom_collections_count_request`].
+    pub fn deprecated_202001_get_custom_collections_count_request(
+        &self,
+    ) -> ProductsDeprecated202001GetCustomCollectionsCountRequest<'_> {
+        ProductsDeprecated202001GetCustomCollectionsCountRequest {
+            inner: self
+                .inner
+                .deprecated_202001_get_custom_collections_count_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202001_get_custom_collections_param_collection`].
+    pub fn deprecated_202001_get_custom_collections_param_collection(
+        &self,
+        custom_collection_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_get_custom_collections_param_collection(
+                    custom_collection_id,
+                    fields,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202001_get_custom_collections_param_collection_request`].
+    pub fn deprecated_202001_get_custom_collections_param_collection_request<'a>(
+        &'a self,
+        custom_collection_id: &'a str,
+    ) -> ProductsDeprecated202001GetCustomCollectionsParamCollectionRequest<'a> {
+        ProductsDeprecated202001GetCustomCollectionsParamCollectionRequest {
+            inner: self
+                .inner
+                .deprecated_202001_get_custom_collections_param_collection_request(
+                    custom_collection_id,
+                ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202001_update_custom_collections_param_collection`].
+    pub fn deprecated_202001_update_custom_collections_param_collection(
+        &self,
+        custom_collection_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_update_custom_collections_param_collection(
+                    custom_collection_id,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202001_delete_custom_collections_param_collection`].
+    pub fn deprecated_202001_delete_custom_collections_param_collection(
+        &self,
+        custom_collection_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_delete_custom_collections_param_collection(custom_collection_id),
+        )
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202004_get_custom_collection`].
+    pub fn deprecated_202004_get_custom_collection(
+        &self,
+        limit: &str,
+        ids: &str,
+        since_id: &str,
+        title: &str,
+        product_id: &str,
+        handle: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202004_get_custom_collection(
+                limit,
+                ids,
+                since_id,
+                title,
+                product_id,
+                handle,
+                updated_at_min,
+                updated_at_max,
+                published_at_min,
+                published_at_max,
+                published_status,
+                fields,
+            ))
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202004_get_custom_collection_request`].
+    pub fn deprecated_202004_get_custom_collection_request(
+        &self,
+    ) -> ProductsDeprecated202004GetCustomCollectionRequest<'_> {
+        ProductsDeprecated202004GetCustomCollectionRequest {
+            inner: self.inner.deprecated_202004_get_custom_collection_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202004_create_custom_collections`].
+    pub fn deprecated_202004_create_custom_collections(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202004_create_custom_collections(body))
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202004_get_custom_collections_count`].
+    pub fn deprecated_202004_get_custom_collections_count(
+        &self,
+        title: &str,
+        product_id: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202004_get_custom_collections_count(
+                title,
+                product_id,
+                updated_at_min,
+                updated_at_max,
+                published_at_min,
+                published_at_max,
+                published_status,
+            ))
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202004_get_custom_collections_count_request`].
+    pub fn deprecated_202004_get_custom_collections_count_request(
+        &self,
+    ) -> ProductsDeprecated202004GetCustomCollectionsCountRequest<'_> {
+        ProductsDeprecated202004GetCustomCollectionsCountRequest {
+            inner: self
+                .inner
+                .deprecated_202004_get_custom_collections_count_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202004_get_custom_collections_param_collection`].
+    pub fn deprecated_202004_get_custom_collections_param_collection(
+        &self,
+        custom_collection_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_get_custom_collections_param_collection(
+                    custom_collection_id,
+                    fields,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202004_get_custom_collections_param_collection_request`].
+    pub fn deprecated_202004_get_custom_collections_param_collection_request<'a>(
+        &'a self,
+        custom_collection_id: &'a str,
+    ) -> ProductsDeprecated202004GetCustomCollectionsParamCollectionRequest<'a> {
+        ProductsDeprecated202004GetCustomCollectionsParamCollectionRequest {
+            inner: self
+                .inner
+                .deprecated_202004_get_custom_collections_param_collection_request(
+                    custom_collection_id,
+                ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202004_update_custom_collections_param_collection`].
+    pub fn deprecated_202004_update_custom_collections_param_collection(
+        &self,
+        custom_collection_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_update_custom_collections_param_collection(
+                    custom_collection_id,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202004_delete_custom_collections_param_collection`].
+    pub fn deprecated_202004_delete_custom_collections_param_collection(
+        &self,
+        custom_collection_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_delete_custom_collections_param_collection(custom_collection_id),
+        )
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202007_get_custom_collection`].
+    pub fn deprecated_202007_get_custom_collection(
+        &self,
+        limit: &str,
+        ids: &str,
+        since_id: &str,
+        title: &str,
+        product_id: &str,
+        handle: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202007_get_custom_collection(
+                limit,
+                ids,
+                since_id,
+                title,
+                product_id,
+                handle,
+                updated_at_min,
+                updated_at_max,
+                published_at_min,
+                published_at_max,
+                published_status,
+                fields,
+            ))
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202007_get_custom_collection_request`].
+    pub fn deprecated_202007_get_custom_collection_request(
+        &self,
+    ) -> ProductsDeprecated202007GetCustomCollectionRequest<'_> {
+        ProductsDeprecated202007GetCustomCollectionRequest {
+            inner: self.inner.deprecated_202007_get_custom_collection_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202007_create_custom_collections`].
+    pub fn deprecated_202007_create_custom_collections(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202007_create_custom_collections(body))
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202007_get_custom_collections_count`].
+    pub fn deprecated_202007_get_custom_collections_count(
+        &self,
+        title: &str,
+        product_id: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202007_get_custom_collections_count(
+                title,
+                product_id,
+                updated_at_min,
+                updated_at_max,
+                published_at_min,
+                published_at_max,
+                published_status,
+            ))
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202007_get_custom_collections_count_request`].
+    pub fn deprecated_202007_get_custom_collections_count_request(
+        &self,
+    ) -> ProductsDeprecated202007GetCustomCollectionsCountRequest<'_> {
+        ProductsDeprecated202007GetCustomCollectionsCountRequest {
+            inner: self
+                .inner
+                .deprecated_202007_get_custom_collections_count_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202007_get_custom_collections_param_collection`].
+    pub fn deprecated_202007_get_custom_collections_param_collection(
+        &self,
+        custom_collection_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_get_custom_collections_param_collection(
+                    custom_collection_id,
+                    fields,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202007_get_custom_collections_param_collection_request`].
+    pub fn deprecated_202007_get_custom_collections_param_collection_request<'a>(
+        &'a self,
+        custom_collection_id: &'a str,
+    ) -> ProductsDeprecated202007GetCustomCollectionsParamCollectionRequest<'a> {
+        ProductsDeprecated202007GetCustomCollectionsParamCollectionRequest {
+            inner: self
+                .inner
+                .deprecated_202007_get_custom_collections_param_collection_request(
+                    custom_collection_id,
+                ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202007_update_custom_collections_param_collection`].
+    pub fn deprecated_202007_update_custom_collections_param_collection(
+        &self,
+        custom_collection_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_update_custom_collections_param_collection(
+                    custom_collection_id,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202007_delete_custom_collections_param_collection`].
+    pub fn deprecated_202007_delete_custom_collections_param_collection(
+        &self,
+        custom_collection_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_delete_custom_collections_param_collection(custom_collection_id),
+        )
+    }
+    /// The blocking version of [`crate::products::Products::get_custom_collection`].
+    pub fn get_custom_collection(
+        &self,
+        limit: &str,
+        ids: &str,
+        since_id: &str,
+        title: &str,
+        product_id: &str,
+        handle: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.get_custom_collection(
+            limit,
+            ids,
+            since_id,
+            title,
+            product_id,
+            handle,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            published_status,
+            fields,
+        ))
+    }
+    /// The blocking version of [`crate::products::Products::get_custom_collection_request`].
+    pub fn get_custom_collection_request(&self) -> ProductsGetCustomCollectionRequest<'_> {
+        ProductsGetCustomCollectionRequest {
+            inner: self.inner.get_custom_collection_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::products::Products::create_custom_collections`].
+    pub fn create_custom_collections(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.create_custom_collections(body))
+    }
+    /// The blocking version of [`crate::products::Products::get_custom_collections_count`].
+    pub fn get_custom_collections_count(
+        &self,
+        title: &str,
+        product_id: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.get_custom_collections_count(
+                title,
+                product_id,
+                updated_at_min,
+                updated_at_max,
+                published_at_min,
+                published_at_max,
+                published_status,
+            ))
+    }
+    /// The blocking version of [`crate::products::Products::get_custom_collections_count_request`].
+    pub fn get_custom_collections_count_request(
+        &self,
+    ) -> ProductsGetCustomCollectionsCountRequest<'_> {
+        ProductsGetCustomCollectionsCountRequest {
+            inner: self.inner.get_custom_collections_count_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::products::Products::get_custom_collections_param_collection`].
+    pub fn get_custom_collections_param_collection(
+        &self,
+        custom_collection_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .get_custom_collections_param_collection(custom_collection_id, fields),
+        )
+    }
+    /// The blocking version of [`crate::products::Products::get_custom_collections_param_collection_request`].
+    pub fn get_custom_collections_param_collection_request<'a>(
+        &'a self,
+        custom_collection_id: &'a str,
+    ) -> ProductsGetCustomCollectionsParamCollectionRequest<'a> {
+        ProductsGetCustomCollectionsParamCollectionRequest {
+            inner: self
+                .inner
+                .get_custom_collections_param_collection_request(custom_collection_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::products::Products::update_custom_collections_param_collection`].
+    pub fn update_custom_collections_param_collection(
+        &self,
+        custom_collection_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .update_custom_collections_param_collection(custom_collection_id, body),
+        )
+    }
+    /// The blocking version of [`crate::products::Products::delete_custom_collections_param_collection`].
+    pub fn delete_custom_collections_param_collection(
+        &self,
+        custom_collection_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .delete_custom_collections_param_collection(custom_collection_id),
+        )
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202101_get_custom_collection`].
+    pub fn deprecated_202101_get_custom_collection(
+        &self,
+        limit: &str,
+        ids: &str,
+        since_id: &str,
+        title: &str,
+        product_id: &str,
+        handle: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202101_get_custom_collection(
+                limit,
+                ids,
+                since_id,
+                title,
+                product_id,
+                handle,
+                updated_at_min,
+                updated_at_max,
+                published_at_min,
+                published_at_max,
+                published_status,
+                fields,
+            ))
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202101_get_custom_collection_request`].
+    pub fn deprecated_202101_get_custom_collection_request(
+        &self,
+    ) -> ProductsDeprecated202101GetCustomCollectionRequest<'_> {
+        ProductsDeprecated202101GetCustomCollectionRequest {
+            inner: self.inner.deprecated_202101_get_custom_collection_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202101_create_custom_collections`].
+    pub fn deprecated_202101_create_custom_collections(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202101_create_custom_collections(body))
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202101_get_custom_collections_count`].
+    pub fn deprecated_202101_get_custom_collections_count(
+        &self,
+        title: &str,
+        product_id: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202101_get_custom_collections_count(
+                title,
+                product_id,
+                updated_at_min,
+                updated_at_max,
+                published_at_min,
+                published_at_max,
+                published_status,
+            ))
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202101_get_custom_collections_count_request`].
+    pub fn deprecated_202101_get_custom_collections_count_request(
+        &self,
+    ) -> ProductsDeprecated202101GetCustomCollectionsCountRequest<'_> {
+        ProductsDeprecated202101GetCustomCollectionsCountRequest {
+            inner: self
+                .inner
+                .deprecated_202101_get_custom_collections_count_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202101_get_custom_collections_param_collection`].
+    pub fn deprecated_202101_get_custom_collections_param_collection(
+        &self,
+        custom_collection_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_get_custom_collections_param_collection(
+                    custom_collection_id,
+                    fields,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202101_get_custom_collections_param_collection_request`].
+    pub fn deprecated_202101_get_custom_collections_param_collection_request<'a>(
+        &'a self,
+        custom_collection_id: &'a str,
+    ) -> ProductsDeprecated202101GetCustomCollectionsParamCollectionRequest<'a> {
+        ProductsDeprecated202101GetCustomCollectionsParamCollectionRequest {
+            inner: self
+                .inner
+                .deprecated_202101_get_custom_collections_param_collection_request(
+                    custom_collection_id,
+                ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202101_update_custom_collections_param_collection`].
+    pub fn deprecated_202101_update_custom_collections_param_collection(
+        &self,
+        custom_collection_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_update_custom_collections_param_collection(
+                    custom_collection_id,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202101_delete_custom_collections_param_collection`].
+    pub fn deprecated_202101_delete_custom_collections_param_collection(
+        &self,
+        custom_collection_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_delete_custom_collections_param_collection(custom_collection_id),
+        )
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_unstable_get_custom_collection`].
+    pub fn deprecated_unstable_get_custom_collection(
+        &self,
+        limit: &str,
+        ids: &str,
+        since_id: &str,
+        title: &str,
+        product_id: &str,
+        handle: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_unstable_get_custom_collection(
+                limit,
+                ids,
+                since_id,
+                title,
+                product_id,
+                handle,
+                updated_at_min,
+                updated_at_max,
+                published_at_min,
+                published_at_max,
+                published_status,
+                fields,
+            ))
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_unstable_get_custom_collection_request`].
+    pub fn deprecated_unstable_get_custom_collection_request(
+        &self,
+    ) -> ProductsDeprecatedUnstableGetCustomCollectionRequest<'_> {
+        ProductsDeprecatedUnstableGetCustomCollectionRequest {
+            inner: self
+                .inner
+                .deprecated_unstable_get_custom_collection_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_unstable_create_custom_collections`].
+    pub fn deprecated_unstable_create_custom_collections(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_create_custom_collections(body),
+        )
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_unstable_get_custom_collections_count`].
+    pub fn deprecated_unstable_get_custom_collections_count(
+        &self,
+        title: &str,
+        product_id: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_unstable_get_custom_collections_count(
+                title,
+                product_id,
+                updated_at_min,
+                updated_at_max,
+                published_at_min,
+                published_at_max,
+                published_status,
+            ))
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_unstable_get_custom_collections_count_request`].
+    pub fn deprecated_unstable_get_custom_collections_count_request(
+        &self,
+    ) -> ProductsDeprecatedUnstableGetCustomCollectionsCountRequest<'_> {
+        ProductsDeprecatedUnstableGetCustomCollectionsCountRequest {
+            inner: self
+                .inner
+                .deprecated_unstable_get_custom_collections_count_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_unstable_get_custom_collections_param_collection`].
+    pub fn deprecated_unstable_get_custom_collections_param_collection(
+        &self,
+        custom_collection_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_get_custom_collections_param_collection(
+                    custom_collection_id,
+                    fields,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_unstable_get_custom_collections_param_collection_request`].
+    pub fn deprecated_unstable_get_custom_collections_param_collection_request<'a>(
+        &'a self,
+        custom_collection_id: &'a str,
+    ) -> ProductsDeprecatedUnstableGetCustomCollectionsParamCollectionRequest<'a> {
+        ProductsDeprecatedUnstableGetCustomCollectionsParamCollectionRequest {
+            inner: self
+                .inner
+                .deprecated_unstable_get_custom_collections_param_collection_request(
+                    custom_collection_id,
+                ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_unstable_update_custom_collections_param_collection`].
+    pub fn deprecated_unstable_update_custom_collections_param_collection(
+        &self,
+        custom_collection_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_update_custom_collections_param_collection(
+                    custom_collection_id,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_unstable_delete_custom_collections_param_collection`].
+    pub fn deprecated_unstable_delete_custom_collections_param_collection(
+        &self,
+        custom_collection_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_delete_custom_collections_param_collection(
+                    custom_collection_id,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202001_get`].
+    pub fn deprecated_202001_get(
+        &self,
+        ids: &str,
+        limit: &str,
+        since_id: &str,
+        title: &str,
+        vendor: &str,
+        handle: &str,
+        product_type: &str,
+        status: &str,
+        collection_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+        fields: &str,
+        presentment_currencies: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.deprecated_202001_get(
+            ids,
+            limit,
+            since_id,
+            title,
+            vendor,
+            handle,
+            product_type,
+            status,
+            collection_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            published_status,
+            fields,
+            presentment_currencies,
+        ))
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202001_get_request`].
+    pub fn deprecated_202001_get_request(&self) -> ProductsDeprecated202001GetRequest<'_> {
+        ProductsDeprecated202001GetRequest {
+            inner: self.inner.deprecated_202001_get_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202001_create`].
+    pub fn deprecated_202001_create(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202001_create(body))
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202001_get_count`].
+    pub fn deprecated_202001_get_count(
+        &self,
+        vendor: &str,
+        product_type: &str,
+        collection_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202001_get_count(
+                vendor,
+                product_type,
+                collection_id,
+                created_at_min,
+                created_at_max,
+                updated_at_min,
+                updated_at_max,
+                published_at_min,
+                published_at_max,
+                published_status,
+            ))
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202001_get_count_request`].
+    pub fn deprecated_202001_get_count_request(
+        &self,
+    ) -> ProductsDeprecated202001GetCountRequest<'_> {
+        ProductsDeprecated202001GetCountRequest {
+            inner: self.inner.deprecated_202001_get_count_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202001_get_param`].
+    pub fn deprecated_202001_get_param(
+        &self,
+        product_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202001_get_param(product_id, fields))
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202001_get_param_request`].
+    pub fn deprecated_202001_get_param_request<'a>(
+        &'a self,
+        product_id: &'a str,
+    ) -> ProductsDeprecated202001GetParamRequest<'a> {
+        ProductsDeprecated202001GetParamRequest {
+            inner: self.inner.deprecated_202001_get_param_request(product_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202001_update_param`].
+    pub fn deprecated_202001_update_param(
+        &self,
+        product_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202001_update_param(product_id, body))
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202001_delete_param`].
+    pub fn deprecated_202001_delete_param(
+        &self,
+        product_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202001_delete_param(product_id))
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202004_get`].
+    pub fn deprecated_202004_get(
+        &self,
+        ids: &str,
+        limit: &str,
+        since_id: &str,
+        title: &str,
+        vendor: &str,
+        handle: &str,
+        product_type: &str,
+        status: &str,
+        collection_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+        fields: &str,
+        presentment_currencies: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.deprecated_202004_get(
+            ids,
+            limit,
+            since_id,
+            title,
+            vendor,
+            handle,
+            product_type,
+            status,
+            collection_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            published_status,
+            fields,
+            presentment_currencies,
+        ))
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202004_get_request`].
+    pub fn deprecated_202004_get_request(&self) -> ProductsDeprecated202004GetRequest<'_> {
+        ProductsDeprecated202004GetRequest {
+            inner: self.inner.deprecated_202004_get_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202004_create`].
+    pub fn deprecated_202004_create(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202004_create(body))
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202004_get_count`].
+    pub fn deprecated_202004_get_count(
+        &self,
+        vendor: &str,
+        product_type: &str,
+        collection_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202004_get_count(
+                vendor,
+                product_type,
+                collection_id,
+                created_at_min,
+                created_at_max,
+                updated_at_min,
+                updated_at_max,
+                published_at_min,
+                published_at_max,
+                published_status,
+            ))
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202004_get_count_request`].
+    pub fn deprecated_202004_get_count_request(
+        &self,
+    ) -> ProductsDeprecated202004GetCountRequest<'_> {
+        ProductsDeprecated202004GetCountRequest {
+            inner: self.inner.deprecated_202004_get_count_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202004_get_param`].
+    pub fn deprecated_202004_get_param(
+        &self,
+        product_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202004_get_param(product_id, fields))
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202004_get_param_request`].
+    pub fn deprecated_202004_get_param_request<'a>(
+        &'a self,
+        product_id: &'a str,
+    ) -> ProductsDeprecated202004GetParamRequest<'a> {
+        ProductsDeprecated202004GetParamRequest {
+            inner: self.inner.deprecated_202004_get_param_request(product_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202004_update_param`].
+    pub fn deprecated_202004_update_param(
+        &self,
+        product_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202004_update_param(product_id, body))
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202004_delete_param`].
+    pub fn deprecated_202004_delete_param(
+        &self,
+        product_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202004_delete_param(product_id))
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202007_get`].
+    pub fn deprecated_202007_get(
+        &self,
+        ids: &str,
+        limit: &str,
+        since_id: &str,
+        title: &str,
+        vendor: &str,
+        handle: &str,
+        product_type: &str,
+        status: &str,
+        collection_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+        fields: &str,
+        presentment_currencies: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.deprecated_202007_get(
+            ids,
+            limit,
+            since_id,
+            title,
+            vendor,
+            handle,
+            product_type,
+            status,
+            collection_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            published_status,
+            fields,
+            presentment_currencies,
+        ))
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202007_get_request`].
+    pub fn deprecated_202007_get_request(&self) -> ProductsDeprecated202007GetRequest<'_> {
+        ProductsDeprecated202007GetRequest {
+            inner: self.inner.deprecated_202007_get_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202007_create`].
+    pub fn deprecated_202007_create(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202007_create(body))
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202007_get_count`].
+    pub fn deprecated_202007_get_count(
+        &self,
+        vendor: &str,
+        product_type: &str,
+        collection_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202007_get_count(
+                vendor,
+                product_type,
+                collection_id,
+                created_at_min,
+                created_at_max,
+                updated_at_min,
+                updated_at_max,
+                published_at_min,
+                published_at_max,
+                published_status,
+            ))
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202007_get_count_request`].
+    pub fn deprecated_202007_get_count_request(
+        &self,
+    ) -> ProductsDeprecated202007GetCountRequest<'_> {
+        ProductsDeprecated202007GetCountRequest {
+            inner: self.inner.deprecated_202007_get_count_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202007_get_param`].
+    pub fn deprecated_202007_get_param(
+        &self,
+        product_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202007_get_param(product_id, fields))
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202007_get_param_request`].
+    pub fn deprecated_202007_get_param_request<'a>(
+        &'a self,
+        product_id: &'a str,
+    ) -> ProductsDeprecated202007GetParamRequest<'a> {
+        ProductsDeprecated202007GetParamRequest {
+            inner: self.inner.deprecated_202007_get_param_request(product_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202007_update_param`].
+    pub fn deprecated_202007_update_param(
+        &self,
+        product_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202007_update_param(product_id, body))
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202007_delete_param`].
+    pub fn deprecated_202007_delete_param(
+        &self,
+        product_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202007_delete_param(product_id))
+    }
+    /// The blocking version of [`crate::products::Products::get`].
+    pub fn get(
+        &self,
+        ids: &str,
+        limit: &str,
+        since_id: &str,
+        title: &str,
+        vendor: &str,
+        handle: &str,
+        product_type: &str,
+        status: &str,
+        collection_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+        fields: &str,
+        presentment_currencies: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.get(
+            ids,
+            limit,
+            since_id,
+            title,
+            vendor,
+            handle,
+            product_type,
+            status,
+            collection_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            published_status,
+            fields,
+            presentment_currencies,
+        ))
+    }
+    /// The blocking version of [`crate::products::Products::get_request`].
+    pub fn get_request(&self) -> ProductsGetRequest<'_> {
+        ProductsGetRequest {
+            inner: self.inner.get_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::products::Products::create`].
+    pub fn create(&self, body: &serde_json::Value) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.create(body))
+    }
+    /// The blocking version of [`crate::products::Products::get_count`].
+    pub fn get_count(
+        &self,
+        vendor: &str,
+        product_type: &str,
+        collection_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.get_count(
+            vendor,
+            product_type,
+            collection_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            published_status,
+        ))
+    }
+    /// The blocking version of [`crate::products::Products::get_count_request`].
+    pub fn get_count_request(&self) -> ProductsGetCountRequest<'_> {
+        ProductsGetCountRequest {
+            inner: self.inner.get_count_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::products::Products::get_param`].
+    pub fn get_param(&self, product_id: &str, fields: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.get_param(product_id, fields))
+    }
+    /// The blocking version of [`crate::products::Products::get_param_request`].
+    pub fn get_param_request<'a>(&'a self, product_id: &'a str) -> ProductsGetParamRequest<'a> {
+        ProductsGetParamRequest {
+            inner: self.inner.get_param_request(product_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::products::Products::update_param`].
+    pub fn update_param(
+        &self,
+        product_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.update_param(product_id, body))
+    }
+    /// The blocking version of [`crate::products::Products::delete_param`].
+    pub fn delete_param(&self, product_id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.delete_param(product_id))
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202101_get`].
+    pub fn deprecated_202101_get(
+        &self,
+        ids: &str,
+        limit: &str,
+        since_id: &str,
+        title: &str,
+        vendor: &str,
+        handle: &str,
+        product_type: &str,
+        status: &str,
+        collection_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+        fields: &str,
+        presentment_currencies: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.deprecated_202101_get(
+            ids,
+            limit,
+            since_id,
+            title,
+            vendor,
+            handle,
+            product_type,
+            status,
+            collection_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            published_status,
+            fields,
+            presentment_currencies,
+        ))
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202101_get_request`].
+    pub fn deprecated_202101_get_request(&self) -> ProductsDeprecated202101GetRequest<'_> {
+        ProductsDeprecated202101GetRequest {
+            inner: self.inner.deprecated_202101_get_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202101_create`].
+    pub fn deprecated_202101_create(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202101_create(body))
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202101_get_count`].
+    pub fn deprecated_202101_get_count(
+        &self,
+        vendor: &str,
+        product_type: &str,
+        collection_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202101_get_count(
+                vendor,
+                product_type,
+                collection_id,
+                created_at_min,
+                created_at_max,
+                updated_at_min,
+                updated_at_max,
+                published_at_min,
+                published_at_max,
+                published_status,
+            ))
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202101_get_count_request`].
+    pub fn deprecated_202101_get_count_request(
+        &self,
+    ) -> ProductsDeprecated202101GetCountRequest<'_> {
+        ProductsDeprecated202101GetCountRequest {
+            inner: self.inner.deprecated_202101_get_count_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202101_get_param`].
+    pub fn deprecated_202101_get_param(
+        &self,
+        product_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202101_get_param(product_id, fields))
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202101_get_param_request`].
+    pub fn deprecated_202101_get_param_request<'a>(
+        &'a self,
+        product_id: &'a str,
+    ) -> ProductsDeprecated202101GetParamRequest<'a> {
+        ProductsDeprecated202101GetParamRequest {
+            inner: self.inner.deprecated_202101_get_param_request(product_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202101_update_param`].
+    pub fn deprecated_202101_update_param(
+        &self,
+        product_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202101_update_param(product_id, body))
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202101_delete_param`].
+    pub fn deprecated_202101_delete_param(
+        &self,
+        product_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202101_delete_param(product_id))
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_unstable_get`].
+    pub fn deprecated_unstable_get(
+        &self,
+        ids: &str,
+        limit: &str,
+        since_id: &str,
+        title: &str,
+        vendor: &str,
+        handle: &str,
+        product_type: &str,
+        status: &str,
+        collection_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+        fields: &str,
+        presentment_currencies: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.deprecated_unstable_get(
+            ids,
+            limit,
+            since_id,
+            title,
+            vendor,
+            handle,
+            product_type,
+            status,
+            collection_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            published_status,
+            fields,
+            presentment_currencies,
+        ))
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_unstable_get_request`].
+    pub fn deprecated_unstable_get_request(&self) -> ProductsDeprecatedUnstableGetRequest<'_> {
+        ProductsDeprecatedUnstableGetRequest {
+            inner: self.inner.deprecated_unstable_get_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_unstable_create`].
+    pub fn deprecated_unstable_create(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_unstable_create(body))
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_unstable_get_count`].
+    pub fn deprecated_unstable_get_count(
+        &self,
+        vendor: &str,
+        product_type: &str,
+        collection_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_unstable_get_count(
+                vendor,
+                product_type,
+                collection_id,
+                created_at_min,
+                created_at_max,
+                updated_at_min,
+                updated_at_max,
+                published_at_min,
+                published_at_max,
+                published_status,
+            ))
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_unstable_get_count_request`].
+    pub fn deprecated_unstable_get_count_request(
+        &self,
+    ) -> ProductsDeprecatedUnstableGetCountRequest<'_> {
+        ProductsDeprecatedUnstableGetCountRequest {
+            inner: self.inner.deprecated_unstable_get_count_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_unstable_get_param`].
+    pub fn deprecated_unstable_get_param(
+        &self,
+        product_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_unstable_get_param(product_id, fields))
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_unstable_get_param_request`].
+    pub fn deprecated_unstable_get_param_request<'a>(
+        &'a self,
+        product_id: &'a str,
+    ) -> ProductsDeprecatedUnstableGetParamRequest<'a> {
+        ProductsDeprecatedUnstableGetParamRequest {
+            inner: self.inner.deprecated_unstable_get_param_request(product_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_unstable_update_param`].
+    pub fn deprecated_unstable_update_param(
+        &self,
+        product_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_update_param(product_id, body),
+        )
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_unstable_delete_param`].
+    pub fn deprecated_unstable_delete_param(
+        &self,
+        product_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_unstable_delete_param(product_id))
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202001_get_param_image`].
+    pub fn deprecated_202001_get_param_image(
+        &self,
+        product_id: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_get_param_image(product_id, since_id, fields),
+        )
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202001_get_param_image_request`].
+    pub fn deprecated_202001_get_param_image_request<'a>(
+        &'a self,
+        product_id: &'a str,
+    ) -> ProductsDeprecated202001GetParamImageRequest<'a> {
+        ProductsDeprecated202001GetParamImageRequest {
+            inner: self
+                .inner
+                .deprecated_202001_get_param_image_request(product_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202001_create_param_images`].
+    pub fn deprecated_202001_create_param_images(
+        &self,
+        product_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_create_param_images(product_id, body),
+        )
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202001_get_param_images_count`].
+    pub fn deprecated_202001_get_param_images_count(
+        &self,
+        product_id: &str,
+        since_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_get_param_images_count(product_id, since_id),
+        )
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202001_get_param_images_count_request`].
+    pub fn deprecated_202001_get_param_images_count_request<'a>(
+        &'a self,
+        product_id: &'a str,
+    ) -> ProductsDeprecated202001GetParamImagesCountRequest<'a> {
+        ProductsDeprecated202001GetParamImagesCountRequest {
+            inner: self
+                .inner
+                .deprecated_202001_get_param_images_count_request(product_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202001_get_param_images_image`].
+    pub fn deprecated_202001_get_param_images_image(
+        &self,
+        product_id: &str,
+        image_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_get_param_images_image(product_id, image_id, fields),
+        )
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202001_get_param_images_image_request`].
+    pub fn deprecated_202001_get_param_images_image_request<'a>(
+        &'a self,
+        product_id: &'a str,
+        image_id: &'a str,
+    ) -> ProductsDeprecated202001GetParamImagesImageRequest<'a> {
+        ProductsDeprecated202001GetParamImagesImageRequest {
+            inner: self
+                .inner
+                .deprecated_202001_get_param_images_image_request(product_id, image_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202001_update_param_images_image`].
+    pub fn deprecated_202001_update_param_images_image(
+        &self,
+        product_id: &str,
+        image_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_update_param_images_image(product_id, image_id, body),
+        )
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202001_delete_param_images_image`].
+    pub fn deprecated_202001_delete_param_images_image(
+        &self,
+        product_id: &str,
+        image_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_delete_param_images_image(product_id, image_id),
+        )
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202004_get_param_image`].
+    pub fn deprecated_202004_get_param_image(
+        &self,
+        product_id: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_get_param_image(product_id, since_id, fields),
+        )
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202004_get_param_image_request`].
+    pub fn deprecated_202004_get_param_image_request<'a>(
+        &'a self,
+        product_id: &'a str,
+    ) -> ProductsDeprecated202004GetParamImageRequest<'a> {
+        ProductsDeprecated202004GetParamImageRequest {
+            inner: self
+                .inner
+                .deprecated_202004_get_param_image_request(product_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202004_create_param_images`].
+    pub fn deprecated_202004_create_param_images(
+        &self,
+        product_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_create_param_images(product_id, body),
+        )
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202004_get_param_images_count`].
+    pub fn deprecated_202004_get_param_images_count(
+        &self,
+        product_id: &str,
+        since_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_get_param_images_count(product_id, since_id),
+        )
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202004_get_param_images_count_request`].
+    pub fn deprecated_202004_get_param_images_count_request<'a>(
+        &'a self,
+        product_id: &'a str,
+    ) -> ProductsDeprecated202004GetParamImagesCountRequest<'a> {
+        ProductsDeprecated202004GetParamImagesCountRequest {
+            inner: self
+                .inner
+                .deprecated_202004_get_param_images_count_request(product_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202004_get_param_images_image`].
+    pub fn deprecated_202004_get_param_images_image(
+        &self,
+        product_id: &str,
+        image_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_get_param_images_image(product_id, image_id, fields),
+        )
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202004_get_param_images_image_request`].
+    pub fn deprecated_202004_get_param_images_image_request<'a>(
+        &'a self,
+        product_id: &'a str,
+        image_id: &'a str,
+    ) -> ProductsDeprecated202004GetParamImagesImageRequest<'a> {
+        ProductsDeprecated202004GetParamImagesImageRequest {
+            inner: self
+                .inner
+                .deprecated_202004_get_param_images_image_request(product_id, image_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202004_update_param_images_image`].
+    pub fn deprecated_202004_update_param_images_image(
+        &self,
+        product_id: &str,
+        image_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_update_param_images_image(product_id, image_id, body),
+        )
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202004_delete_param_images_image`].
+    pub fn deprecated_202004_delete_param_images_image(
+        &self,
+        product_id: &str,
+        image_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_delete_param_images_image(product_id, image_id),
+        )
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202007_get_param_image`].
+    pub fn deprecated_202007_get_param_image(
+        &self,
+        product_id: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_get_param_image(product_id, since_id, fields),
+        )
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202007_get_param_image_request`].
+    pub fn deprecated_202007_get_param_image_request<'a>(
+        &'a self,
+        product_id: &'a str,
+    ) -> ProductsDeprecated202007GetParamImageRequest<'a> {
+        ProductsDeprecated202007GetParamImageRequest {
+            inner: self
+                .inner
+                .deprecated_202007_get_param_image_request(product_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202007_create_param_images`].
+    pub fn deprecated_202007_create_param_images(
+        &self,
+        product_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_create_param_images(product_id, body),
+        )
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202007_get_param_images_count`].
+    pub fn deprecated_202007_get_param_images_count(
+        &self,
+        product_id: &str,
+        since_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_get_param_images_count(product_id, since_id),
+        )
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202007_get_param_images_count_request`].
+    pub fn deprecated_202007_get_param_images_count_request<'a>(
+        &'a self,
+        product_id: &'a str,
+    ) -> ProductsDeprecated202007GetParamImagesCountRequest<'a> {
+        ProductsDeprecated202007GetParamImagesCountRequest {
+            inner: self
+                .inner
+                .deprecated_202007_get_param_images_count_request(product_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202007_get_param_images_image`].
+    pub fn deprecated_202007_get_param_images_image(
+        &self,
+        product_id: &str,
+        image_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_get_param_images_image(product_id, image_id, fields),
+        )
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202007_get_param_images_image_request`].
+    pub fn deprecated_202007_get_param_images_image_request<'a>(
+        &'a self,
+        product_id: &'a str,
+        image_id: &'a str,
+    ) -> ProductsDeprecated202007GetParamImagesImageRequest<'a> {
+        ProductsDeprecated202007GetParamImagesImageRequest {
+            inner: self
+                .inner
+                .deprecated_202007_get_param_images_image_request(product_id, image_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202007_update_param_images_image`].
+    pub fn deprecated_202007_update_param_images_image(
+        &self,
+        product_id: &str,
+        image_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_update_param_images_image(product_id, image_id, body),
+        )
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202007_delete_param_images_image`].
+    pub fn deprecated_202007_delete_param_images_image(
+        &self,
+        product_id: &str,
+        image_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_delete_param_images_image(product_id, image_id),
+        )
+    }
+    /// The blocking version of [`crate::products::Products::get_param_image`].
+    pub fn get_param_image(
+        &self,
+        product_id: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.get_param_image(product_id, since_id, fields))
+    }
+    /// The blocking version of [`crate::products::Products::get_param_image_request`].
+    pub fn get_param_image_request<'a>(
+        &'a self,
+        product_id: &'a str,
+    ) -> ProductsGetParamImageRequest<'a> {
+        ProductsGetParamImageRequest {
+            inner: self.inner.get_param_image_request(product_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::products::Products::create_param_images`].
+    pub fn create_param_images(
+        &self,
+        product_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.create_param_images(product_id, body))
+    }
+    /// The blocking version of [`crate::products::Products::get_param_images_count`].
+    pub fn get_param_images_count(
+        &self,
+        product_id: &str,
+        since_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.get_param_images_count(product_id, since_id))
+    }
+    /// The blocking version of [`crate::products::Products::get_param_images_count_request`].
+    pub fn get_param_images_count_request<'a>(
+        &'a self,
+        product_id: &'a str,
+    ) -> ProductsGetParamImagesCountRequest<'a> {
+        ProductsGetParamImagesCountRequest {
+            inner: self.inner.get_param_images_count_request(product_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::products::Products::get_param_images_image`].
+    pub fn get_param_images_image(
+        &self,
+        product_id: &str,
+        image_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .get_param_images_image(product_id, image_id, fields),
+        )
+    }
+    /// The blocking version of [`crate::products::Products::get_param_images_image_request`].
+    pub fn get_param_images_image_request<'a>(
+        &'a self,
+        product_id: &'a str,
+        image_id: &'a str,
+    ) -> ProductsGetParamImagesImageRequest<'a> {
+        ProductsGetParamImagesImageRequest {
+            inner: self
+                .inner
+                .get_param_images_image_request(product_id, image_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::products::Products::update_param_images_image`].
+    pub fn update_param_images_image(
+        &self,
+        product_id: &str,
+        image_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .update_param_images_image(product_id, image_id, body),
+        )
+    }
+    /// The blocking version of [`crate::products::Products::delete_param_images_image`].
+    pub fn delete_param_images_image(
+        &self,
+        product_id: &str,
+        image_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete_param_images_image(product_id, image_id))
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202101_get_param_image`].
+    pub fn deprecated_202101_get_param_image(
+        &self,
+        product_id: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_get_param_image(product_id, since_id, fields),
+        )
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202101_get_param_image_request`].
+    pub fn deprecated_202101_get_param_image_request<'a>(
+        &'a self,
+        product_id: &'a str,
+    ) -> ProductsDeprecated202101GetParamImageRequest<'a> {
+        ProductsDeprecated202101GetParamImageRequest {
+            inner: self
+                .inner
+                .deprecated_202101_get_param_image_request(product_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202101_create_param_images`].
+    pub fn deprecated_202101_create_param_images(
+        &self,
+        product_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_create_param_images(product_id, body),
+        )
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202101_get_param_images_count`].
+    pub fn deprecated_202101_get_param_images_count(
+        &self,
+        product_id: &str,
+        since_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_get_param_images_count(product_id, since_id),
+        )
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202101_get_param_images_count_request`].
+    pub fn deprecated_202101_get_param_images_count_request<'a>(
+        &'a self,
+        product_id: &'a str,
+    ) -> ProductsDeprecated202101GetParamImagesCountRequest<'a> {
+        ProductsDeprecated202101GetParamImagesCountRequest {
+            inner: self
+                .inner
+                .deprecated_202101_get_param_images_count_request(product_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202101_get_param_images_image`].
+    pub fn deprecated_202101_get_param_images_image(
+        &self,
+        product_id: &str,
+        image_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_get_param_images_image(product_id, image_id, fields),
+        )
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202101_get_param_images_image_request`].
+    pub fn deprecated_202101_get_param_images_image_request<'a>(
+        &'a self,
+        product_id: &'a str,
+        image_id: &'a str,
+    ) -> ProductsDeprecated202101GetParamImagesImageRequest<'a> {
+        ProductsDeprecated202101GetParamImagesImageRequest {
+            inner: self
+                .inner
+                .deprecated_202101_get_param_images_image_request(product_id, image_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202101_update_param_images_image`].
+    pub fn deprecated_202101_update_param_images_image(
+        &self,
+        product_id: &str,
+        image_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_update_param_images_image(product_id, image_id, body),
+        )
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202101_delete_param_images_image`].
+    pub fn deprecated_202101_delete_param_images_image(
+        &self,
+        product_id: &str,
+        image_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_delete_param_images_image(product_id, image_id),
+        )
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_unstable_get_param_image`].
+    pub fn deprecated_unstable_get_param_image(
+        &self,
+        product_id: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_get_param_image(product_id, since_id, fields),
+        )
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_unstable_get_param_image_request`].
+    pub fn deprecated_unstable_get_param_image_request<'a>(
+        &'a self,
+        product_id: &'a str,
+    ) -> ProductsDeprecatedUnstableGetParamImageRequest<'a> {
+        ProductsDeprecatedUnstableGetParamImageRequest {
+            inner: self
+                .inner
+                .deprecated_unstable_get_param_image_request(product_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_unstable_create_param_images`].
+    pub fn deprecated_unstable_create_param_images(
+        &self,
+        product_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_create_param_images(product_id, body),
+        )
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_unstable_get_param_images_count`].
+    pub fn deprecated_unstable_get_param_images_count(
+        &self,
+        product_id: &str,
+        since_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_get_param_images_count(product_id, since_id),
+        )
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_unstable_get_param_images_count_request`].
+    pub fn deprecated_unstable_get_param_images_count_request<'a>(
+        &'a self,
+        product_id: &'a str,
+    ) -> ProductsDeprecatedUnstableGetParamImagesCountRequest<'a> {
+        ProductsDeprecatedUnstableGetParamImagesCountRequest {
+            inner: self
+                .inner
+                .deprecated_unstable_get_param_images_count_request(product_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_unstable_get_param_images_image`].
+    pub fn deprecated_unstable_get_param_images_image(
+        &self,
+        product_id: &str,
+        image_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_get_param_images_image(product_id, image_id, fields),
+        )
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_unstable_get_param_images_image_request`].
+    pub fn deprecated_unstable_get_param_images_image_request<'a>(
+        &'a self,
+        product_id: &'a str,
+        image_id: &'a str,
+    ) -> ProductsDeprecatedUnstableGetParamImagesImageRequest<'a> {
+        ProductsDeprecatedUnstableGetParamImagesImageRequest {
+            inner: self
+                .inner
+                .deprecated_unstable_get_param_images_image_request(product_id, image_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_unstable_update_param_images_image`].
+    pub fn deprecated_unstable_update_param_images_image(
+        &self,
+        product_id: &str,
+        image_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_update_param_images_image(product_id, image_id, body),
+        )
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_unstable_delete_param_images_image`].
+    pub fn deprecated_unstable_delete_param_images_image(
+        &self,
+        product_id: &str,
+        image_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_delete_param_images_image(product_id, image_id),
+        )
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202001_get_smart_collection`].
+    pub fn deprecated_202001_get_smart_collection(
+        &self,
+        limit: &str,
+        ids: &str,
+        since_id: &str,
+        title: &str,
+        product_id: &str,
+        handle: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202001_get_smart_collection(
+                limit,
+                ids,
+                since_id,
+                title,
+                product_id,
+                handle,
+                updated_at_min,
+                updated_at_max,
+                published_at_min,
+                published_at_max,
+                published_status,
+                fields,
+            ))
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202001_get_smart_collection_request`].
+    pub fn deprecated_202001_get_smart_collection_request(
+        &self,
+    ) -> ProductsDeprecated202001GetSmartCollectionRequest<'_> {
+        ProductsDeprecated202001GetSmartCollectionRequest {
+            inner: self.inner.deprecated_202001_get_smart_collection_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202001_create_smart_collections`].
+    pub fn deprecated_202001_create_smart_collections(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202001_create_smart_collections(body))
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202001_get_smart_collections_count`].
+    pub fn deprecated_202001_get_smart_collections_count(
+        &self,
+        title: &str,
+        product_id: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202001_get_smart_collections_count(
+                title,
+                product_id,
+                updated_at_min,
+                updated_at_max,
+                published_at_min,
+                published_at_max,
+                published_status,
+            ))
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202001_get_smart_collections_count_request`].
+    pub fn deprecated_202001_get_smart_collections_count_request(
+        &self,
+    ) -> ProductsDeprecated202001GetSmartCollectionsCountRequest<'_> {
+        ProductsDeprecated202001GetSmartCollectionsCountRequest {
+            inner: self
+                .inner
+                .deprecated_202001_get_smart_collections_count_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202001_get_smart_collections_param_collection`].
+    pub fn deprecated_202001_get_smart_collections_param_collection(
+        &self,
+        smart_collection_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_get_smart_collections_param_collection(
+                    smart_collection_id,
+                    fields,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202001_get_smart_collections_param_collection_request`].
+    pub fn deprecated_202001_get_smart_collections_param_collection_request<'a>(
+        &'a self,
+        smart_collection_id: &'a str,
+    ) -> ProductsDeprecated202001GetSmartCollectionsParamCollectionRequest<'a> {
+        ProductsDeprecated202001GetSmartCollectionsParamCollectionRequest {
+            inner: self
+                .inner
+                .deprecated_202001_get_smart_collections_param_collection_request(
+                    smart_collection_id,
+                ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202001_update_smart_collections_param_collection`].
+    pub fn deprecated_202001_update_smart_collections_param_collection(
+        &self,
+        smart_collection_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_update_smart_collections_param_collection(
+                    smart_collection_id,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202001_delete_smart_collections_param_collection`].
+    pub fn deprecated_202001_delete_smart_collections_param_collection(
+        &self,
+        smart_collection_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_delete_smart_collections_param_collection(smart_collection_id),
+        )
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202001_update_smart_collections_param_collection_order`].
+    pub fn deprecated_202001_update_smart_collections_param_collection_order(
+        &self,
+        smart_collection_id: &str,
+        products: &str,
+        sort_order: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_update_smart_collections_param_collection_order(
+                    smart_collection_id,
+                    products,
+                    sort_order,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202001_update_smart_collections_param_collection_order_request`].
+    pub fn deprecated_202001_update_smart_collections_param_collection_order_request<'a>(
+        &'a self,
+        smart_collection_id: &'a str,
+        body: &'a serde_json::Value,
+    ) -> ProductsDeprecated202001UpdateSmartCollectionsParamCollectionOrderRequest<'a> {
+        ProductsDeprecated202001UpdateSmartCollectionsParamCollectionOrderRequest {
+            inner: self
+                .inner
+                .deprecated_202001_update_smart_collections_param_collection_order_request(
+                    smart_collection_id,
+                    body,
+                ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202004_get_smart_collection`].
+    pub fn deprecated_202004_get_smart_collection(
+        &self,
+        limit: &str,
+        ids: &str,
+        since_id: &str,
+        title: &str,
+        product_id: &str,
+        handle: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202004_get_smart_collection(
+                limit,
+                ids,
+                since_id,
+                title,
+                product_id,
+                handle,
+                updated_at_min,
+                updated_at_max,
+                published_at_min,
+                published_at_max,
+                published_status,
+                fields,
+            ))
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202004_get_smart_collection_request`].
+    pub fn deprecated_202004_get_smart_collection_request(
+        &self,
+    ) -> ProductsDeprecated202004GetSmartCollectionRequest<'_> {
+        ProductsDeprecated202004GetSmartCollectionRequest {
+            inner: self.inner.deprecated_202004_get_smart_collection_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202004_create_smart_collections`].
+    pub fn deprecated_202004_create_smart_collections(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202004_create_smart_collections(body))
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202004_get_smart_collections_count`].
+    pub fn deprecated_202004_get_smart_collections_count(
+        &self,
+        title: &str,
+        product_id: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202004_get_smart_collections_count(
+                title,
+                product_id,
+                updated_at_min,
+                updated_at_max,
+                published_at_min,
+                published_at_max,
+                published_status,
+            ))
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202004_get_smart_collections_count_request`].
+    pub fn deprecated_202004_get_smart_collections_count_request(
+        &self,
+    ) -> ProductsDeprecated202004GetSmartCollectionsCountRequest<'_> {
+        ProductsDeprecated202004GetSmartCollectionsCountRequest {
+            inner: self
+                .inner
+                .deprecated_202004_get_smart_collections_count_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202004_get_smart_collections_param_collection`].
+    pub fn deprecated_202004_get_smart_collections_param_collection(
+        &self,
+        smart_collection_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_get_smart_collections_param_collection(
+                    smart_collection_id,
+                    fields,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202004_get_smart_collections_param_collection_request`].
+    pub fn deprecated_202004_get_smart_collections_param_collection_request<'a>(
+        &'a self,
+        smart_collection_id: &'a str,
+    ) -> ProductsDeprecated202004GetSmartCollectionsParamCollectionRequest<'a> {
+        ProductsDeprecated202004GetSmartCollectionsParamCollectionRequest {
+            inner: self
+                .inner
+                .deprecated_202004_get_smart_collections_param_collection_request(
+                    smart_collection_id,
+                ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202004_update_smart_collections_param_collection`].
+    pub fn deprecated_202004_update_smart_collections_param_collection(
+        &self,
+        smart_collection_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_update_smart_collections_param_collection(
+                    smart_collection_id,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202004_delete_smart_collections_param_collection`].
+    pub fn deprecated_202004_delete_smart_collections_param_collection(
+        &self,
+        smart_collection_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_delete_smart_collections_param_collection(smart_collection_id),
+        )
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202004_update_smart_collections_param_collection_order`].
+    pub fn deprecated_202004_update_smart_collections_param_collection_order(
+        &self,
+        smart_collection_id: &str,
+        products: &str,
+        sort_order: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_update_smart_collections_param_collection_order(
+                    smart_collection_id,
+                    products,
+                    sort_order,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202004_update_smart_collections_param_collection_order_request`].
+    pub fn deprecated_202004_update_smart_collections_param_collection_order_request<'a>(
+        &'a self,
+        smart_collection_id: &'a str,
+        body: &'a serde_json::Value,
+    ) -> ProductsDeprecated202004UpdateSmartCollectionsParamCollectionOrderRequest<'a> {
+        ProductsDeprecated202004UpdateSmartCollectionsParamCollectionOrderRequest {
+            inner: self
+                .inner
+                .deprecated_202004_update_smart_collections_param_collection_order_request(
+                    smart_collection_id,
+                    body,
+                ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202007_get_smart_collection`].
+    pub fn deprecated_202007_get_smart_collection(
+        &self,
+        limit: &str,
+        ids: &str,
+        since_id: &str,
+        title: &str,
+        product_id: &str,
+        handle: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202007_get_smart_collection(
+                limit,
+                ids,
+                since_id,
+                title,
+                product_id,
+                handle,
+                updated_at_min,
+                updated_at_max,
+                published_at_min,
+                published_at_max,
+                published_status,
+                fields,
+            ))
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202007_get_smart_collection_request`].
+    pub fn deprecated_202007_get_smart_collection_request(
+        &self,
+    ) -> ProductsDeprecated202007GetSmartCollectionRequest<'_> {
+        ProductsDeprecated202007GetSmartCollectionRequest {
+            inner: self.inner.deprecated_202007_get_smart_collection_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202007_create_smart_collections`].
+    pub fn deprecated_202007_create_smart_collections(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202007_create_smart_collections(body))
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202007_get_smart_collections_count`].
+    pub fn deprecated_202007_get_smart_collections_count(
+        &self,
+        title: &str,
+        product_id: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202007_get_smart_collections_count(
+                title,
+                product_id,
+                updated_at_min,
+                updated_at_max,
+                published_at_min,
+                published_at_max,
+                published_status,
+            ))
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202007_get_smart_collections_count_request`].
+    pub fn deprecated_202007_get_smart_collections_count_request(
+        &self,
+    ) -> ProductsDeprecated202007GetSmartCollectionsCountRequest<'_> {
+        ProductsDeprecated202007GetSmartCollectionsCountRequest {
+            inner: self
+                .inner
+                .deprecated_202007_get_smart_collections_count_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202007_get_smart_collections_param_collection`].
+    pub fn deprecated_202007_get_smart_collections_param_collection(
+        &self,
+        smart_collection_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_get_smart_collections_param_collection(
+                    smart_collection_id,
+                    fields,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202007_get_smart_collections_param_collection_request`].
+    pub fn deprecated_202007_get_smart_collections_param_collection_request<'a>(
+        &'a self,
+        smart_collection_id: &'a str,
+    ) -> ProductsDeprecated202007GetSmartCollectionsParamCollectionRequest<'a> {
+        ProductsDeprecated202007GetSmartCollectionsParamCollectionRequest {
+            inner: self
+                .inner
+                .deprecated_202007_get_smart_collections_param_collection_request(
+                    smart_collection_id,
+                ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202007_update_smart_collections_param_collection`].
+    pub fn deprecated_202007_update_smart_collections_param_collection(
+        &self,
+        smart_collection_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_update_smart_collections_param_collection(
+                    smart_collection_id,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202007_delete_smart_collections_param_collection`].
+    pub fn deprecated_202007_delete_smart_collections_param_collection(
+        &self,
+        smart_collection_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_delete_smart_collections_param_collection(smart_collection_id),
+        )
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202007_update_smart_collections_param_collection_order`].
+    pub fn deprecated_202007_update_smart_collections_param_collection_order(
+        &self,
+        smart_collection_id: &str,
+        products: &str,
+        sort_order: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_update_smart_collections_param_collection_order(
+                    smart_collection_id,
+                    products,
+                    sort_order,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202007_update_smart_collections_param_collection_order_request`].
+    pub fn deprecated_202007_update_smart_collections_param_collection_order_request<'a>(
+        &'a self,
+        smart_collection_id: &'a str,
+        body: &'a serde_json::Value,
+    ) -> ProductsDeprecated202007UpdateSmartCollectionsParamCollectionOrderRequest<'a> {
+        ProductsDeprecated202007UpdateSmartCollectionsParamCollectionOrderRequest {
+            inner: self
+                .inner
+                .deprecated_202007_update_smart_collections_param_collection_order_request(
+                    smart_collection_id,
+                    body,
+                ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::products::Products::get_smart_collection`].
+    pub fn get_smart_collection(
+        &self,
+        limit: &str,
+        ids: &str,
+        since_id: &str,
+        title: &str,
+        product_id: &str,
+        handle: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.get_smart_collection(
+            limit,
+            ids,
+            since_id,
+            title,
+            product_id,
+            handle,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            published_status,
+            fields,
+        ))
+    }
+    /// The blocking version of [`crate::products::Products::get_smart_collection_request`].
+    pub fn get_smart_collection_request(&self) -> ProductsGetSmartCollectionRequest<'_> {
+        ProductsGetSmartCollectionRequest {
+            inner: self.inner.get_smart_collection_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::products::Products::create_smart_collections`].
+    pub fn create_smart_collections(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.create_smart_collections(body))
+    }
+    /// The blocking version of [`crate::products::Products::get_smart_collections_count`].
+    pub fn get_smart_collections_count(
+        &self,
+        title: &str,
+        product_id: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.get_smart_collections_count(
+                title,
+                product_id,
+                updated_at_min,
+                updated_at_max,
+                published_at_min,
+                published_at_max,
+                published_status,
+            ))
+    }
+    /// The blocking version of [`crate::products::Products::get_smart_collections_count_request`].
+    pub fn get_smart_collections_count_request(
+        &self,
+    ) -> ProductsGetSmartCollectionsCountRequest<'_> {
+        ProductsGetSmartCollectionsCountRequest {
+            inner: self.inner.get_smart_collections_count_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::products::Products::get_smart_collections_param_collection`].
+    pub fn get_smart_collections_param_collection(
+        &self,
+        smart_collection_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .get_smart_collections_param_collection(smart_collection_id, fields),
+        )
+    }
+    /// The blocking version of [`crate::products::Products::get_smart_collections_param_collection_request`].
+    pub fn get_smart_collections_param_collection_request<'a>(
+        &'a self,
+        smart_collection_id: &'a str,
+    ) -> ProductsGetSmartCollectionsParamCollectionRequest<'a> {
+        ProductsGetSmartCollectionsParamCollectionRequest {
+            inner: self
+                .inner
+                .get_smart_collections_param_collection_request(smart_collection_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::products::Products::update_smart_collections_param_collection`].
+    pub fn update_smart_collections_param_collection(
+        &self,
+        smart_collection_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .update_smart_collections_param_collection(smart_collection_id, body),
+        )
+    }
+    /// The blocking version of [`crate::products::Products::delete_smart_collections_param_collection`].
+    pub fn delete_smart_collections_param_collection(
+        &self,
+        smart_collection_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .delete_smart_collections_param_collection(smart_collection_id),
+        )
+    }
+    /// The blocking version of [`crate::products::Products::update_smart_collections_param_collection_order`].
+    pub fn update_smart_collections_param_collection_order(
+        &self,
+        smart_collection_id: &str,
+        products: &str,
+        sort_order: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.update_smart_collections_param_collection_order(
+                smart_collection_id,
+                products,
+                sort_order,
+                body,
+            ))
+    }
+    /// The blocking version of [`crate::products::Products::update_smart_collections_param_collection_order_request`].
+    pub fn update_smart_collections_param_collection_order_request<'a>(
+        &'a self,
+        smart_collection_id: &'a str,
+        body: &'a serde_json::Value,
+    ) -> ProductsUpdateSmartCollectionsParamCollectionOrderRequest<'a> {
+        ProductsUpdateSmartCollectionsParamCollectionOrderRequest {
+            inner: self
+                .inner
+                .update_smart_collections_param_collection_order_request(smart_collection_id, body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202101_get_smart_collection`].
+    pub fn deprecated_202101_get_smart_collection(
+        &self,
+        limit: &str,
+        ids: &str,
+        since_id: &str,
+        title: &str,
+        product_id: &str,
+        handle: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202101_get_smart_collection(
+                limit,
+                ids,
+                since_id,
+                title,
+                product_id,
+                handle,
+                updated_at_min,
+                updated_at_max,
+                published_at_min,
+                published_at_max,
+                published_status,
+                fields,
+            ))
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202101_get_smart_collection_request`].
+    pub fn deprecated_202101_get_smart_collection_request(
+        &self,
+    ) -> ProductsDeprecated202101GetSmartCollectionRequest<'_> {
+        ProductsDeprecated202101GetSmartCollectionRequest {
+            inner: self.inner.deprecated_202101_get_smart_collection_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202101_create_smart_collections`].
+    pub fn deprecated_202101_create_smart_collections(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202101_create_smart_collections(body))
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202101_get_smart_collections_count`].
+    pub fn deprecated_202101_get_smart_collections_count(
+        &self,
+        title: &str,
+        product_id: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202101_get_smart_collections_count(
+                title,
+                product_id,
+                updated_at_min,
+                updated_at_max,
+                published_at_min,
+                published_at_max,
+                published_status,
+            ))
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202101_get_smart_collections_count_request`].
+    pub fn deprecated_202101_get_smart_collections_count_request(
+        &self,
+    ) -> ProductsDeprecated202101GetSmartCollectionsCountRequest<'_> {
+        ProductsDeprecated202101GetSmartCollectionsCountRequest {
+            inner: self
+                .inner
+                .deprecated_202101_get_smart_collections_count_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202101_get_smart_collections_param_collection`].
+    pub fn deprecated_202101_get_smart_collections_param_collection(
+        &self,
+        smart_collection_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_get_smart_collections_param_collection(
+                    smart_collection_id,
+                    fields,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202101_get_smart_collections_param_collection_request`].
+    pub fn deprecated_202101_get_smart_collections_param_collection_request<'a>(
+        &'a self,
+        smart_collection_id: &'a str,
+    ) -> ProductsDeprecated202101GetSmartCollectionsParamCollectionRequest<'a> {
+        ProductsDeprecated202101GetSmartCollectionsParamCollectionRequest {
+            inner: self
+                .inner
+                .deprecated_202101_get_smart_collections_param_collection_request(
+                    smart_collection_id,
+                ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202101_update_smart_collections_param_collection`].
+    pub fn deprecated_202101_update_smart_collections_param_collection(
+        &self,
+        smart_collection_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_update_smart_collections_param_collection(
+                    smart_collection_id,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202101_delete_smart_collections_param_collection`].
+    pub fn deprecated_202101_delete_smart_collections_param_collection(
+        &self,
+        smart_collection_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_delete_smart_collections_param_collection(smart_collection_id),
+        )
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202101_update_smart_collections_param_collection_order`].
+    pub fn deprecated_202101_update_smart_collections_param_collection_order(
+        &self,
+        smart_collection_id: &str,
+        products: &str,
+        sort_order: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_update_smart_collections_param_collection_order(
+                    smart_collection_id,
+                    products,
+                    sort_order,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_202101_update_smart_collections_param_collection_order_request`].
+    pub fn deprecated_202101_update_smart_collections_param_collection_order_request<'a>(
+        &'a self,
+        smart_collection_id: &'a str,
+        body: &'a serde_json::Value,
+    ) -> ProductsDeprecated202101UpdateSmartCollectionsParamCollectionOrderRequest<'a> {
+        ProductsDeprecated202101UpdateSmartCollectionsParamCollectionOrderRequest {
+            inner: self
+                .inner
+                .deprecated_202101_update_smart_collections_param_collection_order_request(
+                    smart_collection_id,
+                    body,
+                ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_unstable_get_smart_collection`].
+    pub fn deprecated_unstable_get_smart_collection(
+        &self,
+        limit: &str,
+        ids: &str,
+        since_id: &str,
+        title: &str,
+        product_id: &str,
+        handle: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_unstable_get_smart_collection(
+                limit,
+                ids,
+                since_id,
+                title,
+                product_id,
+                handle,
+                updated_at_min,
+                updated_at_max,
+                published_at_min,
+                published_at_max,
+                published_status,
+                fields,
+            ))
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_unstable_get_smart_collection_request`].
+    pub fn deprecated_unstable_get_smart_collection_request(
+        &self,
+    ) -> ProductsDeprecatedUnstableGetSmartCollectionRequest<'_> {
+        ProductsDeprecatedUnstableGetSmartCollectionRequest {
+            inner: self
+                .inner
+                .deprecated_unstable_get_smart_collection_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_unstable_create_smart_collections`].
+    pub fn deprecated_unstable_create_smart_collections(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_create_smart_collections(body),
+        )
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_unstable_get_smart_collections_count`].
+    pub fn deprecated_unstable_get_smart_collections_count(
+        &self,
+        title: &str,
+        product_id: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_unstable_get_smart_collections_count(
+                title,
+                product_id,
+                updated_at_min,
+                updated_at_max,
+                published_at_min,
+                published_at_max,
+                published_status,
+            ))
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_unstable_get_smart_collections_count_request`].
+    pub fn deprecated_unstable_get_smart_collections_count_request(
+        &self,
+    ) -> ProductsDeprecatedUnstableGetSmartCollectionsCountRequest<'_> {
+        ProductsDeprecatedUnstableGetSmartCollectionsCountRequest {
+            inner: self
+                .inner
+                .deprecated_unstable_get_smart_collections_count_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_unstable_get_smart_collections_param_collection`].
+    pub fn deprecated_unstable_get_smart_collections_param_collection(
+        &self,
+        smart_collection_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_get_smart_collections_param_collection(
+                    smart_collection_id,
+                    fields,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_unstable_get_smart_collections_param_collection_request`].
+    pub fn deprecated_unstable_get_smart_collections_param_collection_request<'a>(
+        &'a self,
+        smart_collection_id: &'a str,
+    ) -> ProductsDeprecatedUnstableGetSmartCollectionsParamCollectionRequest<'a> {
+        ProductsDeprecatedUnstableGetSmartCollectionsParamCollectionRequest {
+            inner: self
+                .inner
+                .deprecated_unstable_get_smart_collections_param_collection_request(
+                    smart_collection_id,
+                ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_unstable_update_smart_collections_param_collection`].
+    pub fn deprecated_unstable_update_smart_collections_param_collection(
+        &self,
+        smart_collection_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_update_smart_collections_param_collection(
+                    smart_collection_id,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_unstable_delete_smart_collections_param_collection`].
+    pub fn deprecated_unstable_delete_smart_collections_param_collection(
+        &self,
+        smart_collection_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_delete_smart_collections_param_collection(smart_collection_id),
+        )
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_unstable_update_smart_collections_param_collection_order`].
+    pub fn deprecated_unstable_update_smart_collections_param_collection_order(
+        &self,
+        smart_collection_id: &str,
+        products: &str,
+        sort_order: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_update_smart_collections_param_collection_order(
+                    smart_collection_id,
+                    products,
+                    sort_order,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::products::Products::deprecated_unstable_update_smart_collections_param_collection_order_request`].
+    pub fn deprecated_unstable_update_smart_collections_param_collection_order_request<'a>(
+        &'a self,
+        smart_collection_id: &'a str,
+        body: &'a serde_json::Value,
+    ) -> ProductsDeprecatedUnstableUpdateSmartCollectionsParamCollectionOrderRequest<'a> {
+        ProductsDeprecatedUnstableUpdateSmartCollectionsParamCollectionOrderRequest {
+            inner: self
+                .inner
+                .deprecated_unstable_update_smart_collections_param_collection_order_request(
+                    smart_collection_id,
+                    body,
+                ),
+            runtime: &self.runtime,
+        }
+    }
+}
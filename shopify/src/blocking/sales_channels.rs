@@ -0,0 +1,2070 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`SalesChannels::deprecated_202001_get_collection_listing`], set with a builder.
+pub struct SalesChannelsDeprecated202001GetCollectionListingRequest<'a> {
+    inner: crate::sales_channels::SalesChannelsDeprecated202001GetCollectionListingRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> SalesChannelsDeprecated202001GetCollectionListingRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`SalesChannels::deprecated_202001_get_collection_listings_param_listing_product_id`], set with a builder.
+pub struct SalesChannelsDeprecated202001GetCollectionListingsParamListingProductIdRequest<'a> {
+            inner: crate::sales_channels::SalesChannelsDeprecated202001GetCollectionListingsParamListingProductIdRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a> SalesChannelsDeprecated202001GetCollectionListingsParamListingProductIdRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`SalesChannels::deprecated_202004_get_collection_listing`], set with a builder.
+pub struct SalesChannelsDeprecated202004GetCollectionListingRequest<'a> {
+    inner: crate::sales_channels::SalesChannelsDeprecated202004GetCollectionListingRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> SalesChannelsDeprecated202004GetCollectionListingRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`SalesChannels::deprecated_202004_get_collection_listings_param_listing_product_id`], set with a builder.
+pub struct SalesChannelsDeprecated202004GetCollectionListingsParamListingProductIdRequest<'a> {
+            inner: crate::sales_channels::SalesChannelsDeprecated202004GetCollectionListingsParamListingProductIdRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a> SalesChannelsDeprecated202004GetCollectionListingsParamListingProductIdRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`SalesChannels::deprecated_202007_get_collection_listing`], set with a builder.
+pub struct SalesChannelsDeprecated202007GetCollectionListingRequest<'a> {
+    inner: crate::sales_channels::SalesChannelsDeprecated202007GetCollectionListingRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> SalesChannelsDeprecated202007GetCollectionListingRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`SalesChannels::deprecated_202007_get_collection_listings_param_listing_product_id`], set with a builder.
+pub struct SalesChannelsDeprecated202007GetCollectionListingsParamListingProductIdRequest<'a> {
+            inner: crate::sales_channels::SalesChannelsDeprecated202007GetCollectionListingsParamListingProductIdRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a> SalesChannelsDeprecated202007GetCollectionListingsParamListingProductIdRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`SalesChannels::get_collection_listing`], set with a builder.
+pub struct SalesChannelsGetCollectionListingRequest<'a> {
+    inner: crate::sales_channels::SalesChannelsGetCollectionListingRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> SalesChannelsGetCollectionListingRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`SalesChannels::get_collection_listings_param_listing_product_id`], set with a builder.
+pub struct SalesChannelsGetCollectionListingsParamListingProductIdRequest<'a> {
+    inner:
+        crate::sales_channels::SalesChannelsGetCollectionListingsParamListingProductIdRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> SalesChannelsGetCollectionListingsParamListingProductIdRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`SalesChannels::deprecated_202101_get_collection_listing`], set with a builder.
+pub struct SalesChannelsDeprecated202101GetCollectionListingRequest<'a> {
+    inner: crate::sales_channels::SalesChannelsDeprecated202101GetCollectionListingRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> SalesChannelsDeprecated202101GetCollectionListingRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`SalesChannels::deprecated_202101_get_collection_listings_param_listing_product_id`], set with a builder.
+pub struct SalesChannelsDeprecated202101GetCollectionListingsParamListingProductIdRequest<'a> {
+            inner: crate::sales_channels::SalesChannelsDeprecated202101GetCollectionListingsParamListingProductIdRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a> SalesChannelsDeprecated202101GetCollectionListingsParamListingProductIdRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`SalesChannels::deprecated_unstable_get_collection_listing`], set with a builder.
+pub struct SalesChannelsDeprecatedUnstableGetCollectionListingRequest<'a> {
+    inner: crate::sales_channels::SalesChannelsDeprecatedUnstableGetCollectionListingRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> SalesChannelsDeprecatedUnstableGetCollectionListingRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`SalesChannels::deprecated_unstable_get_collection_listings_param_listing_product_id`], set with a builder.
+pub struct SalesChannelsDeprecatedUnstableGetCollectionListingsParamListingProductIdRequest<'a> {
+            inner: crate::sales_channels::SalesChannelsDeprecatedUnstableGetCollectionListingsParamListingProductIdRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a> SalesChannelsDeprecatedUnstableGetCollectionListingsParamListingProductIdRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`SalesChannels::deprecated_202001_create_checkouts_param_token_payments`], set with a builder.
+pub struct SalesChannelsDeprecated202001CreateCheckoutsParamTokenPaymentsRequest<'a> {
+            inner: crate::sales_channels::SalesChannelsDeprecated202001CreateCheckoutsParamTokenPaymentsRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a> SalesChannelsDeprecated202001CreateCheckoutsParamTokenPaymentsRequest<'a> {
+    /// Sets the `amount required` query parameter.
+    pub fn amount_required(mut self, amount_required: &'a str) -> Self {
+        self.inner = self.inner.amount_required(amount_required);
+        self
+    }
+
+    /// Sets the `request_details required` query parameter.
+    pub fn request_details_required(mut self, request_details_required: &'a str) -> Self {
+        self.inner = self
+            .inner
+            .request_details_required(request_details_required);
+        self
+    }
+
+    /// Sets the `session_id required` query parameter.
+    pub fn session_id_required(mut self, session_id_required: &'a str) -> Self {
+        self.inner = self.inner.session_id_required(session_id_required);
+        self
+    }
+
+    /// Sets the `unique_token required` query parameter.
+    pub fn unique_token_required(mut self, unique_token_required: &'a str) -> Self {
+        self.inner = self.inner.unique_token_required(unique_token_required);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`SalesChannels::deprecated_202004_create_checkouts_param_token_payments`], set with a builder.
+pub struct SalesChannelsDeprecated202004CreateCheckoutsParamTokenPaymentsRequest<'a> {
+            inner: crate::sales_channels::SalesChannelsDeprecated202004CreateCheckoutsParamTokenPaymentsRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a> SalesChannelsDeprecated202004CreateCheckoutsParamTokenPaymentsRequest<'a> {
+    /// Sets the `amount required` query parameter.
+    pub fn amount_required(mut self, amount_required: &'a str) -> Self {
+        self.inner = self.inner.amount_required(amount_required);
+        self
+    }
+
+    /// Sets the `request_details required` query parameter.
+    pub fn request_details_required(mut self, request_details_required: &'a str) -> Self {
+        self.inner = self
+            .inner
+            .request_details_required(request_details_required);
+        self
+    }
+
+    /// Sets the `session_id required` query parameter.
+    pub fn session_id_required(mut self, session_id_required: &'a str) -> Self {
+        self.inner = self.inner.session_id_required(session_id_required);
+        self
+    }
+
+    /// Sets the `unique_token required` query parameter.
+    pub fn unique_token_required(mut self, unique_token_required: &'a str) -> Self {
+        self.inner = self.inner.unique_token_required(unique_token_required);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`SalesChannels::deprecated_202007_create_checkouts_param_token_payments`], set with a builder.
+pub struct SalesChannelsDeprecated202007CreateCheckoutsParamTokenPaymentsRequest<'a> {
+            inner: crate::sales_channels::SalesChannelsDeprecated202007CreateCheckoutsParamTokenPaymentsRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a> SalesChannelsDeprecated202007CreateCheckoutsParamTokenPaymentsRequest<'a> {
+    /// Sets the `amount required` query parameter.
+    pub fn amount_required(mut self, amount_required: &'a str) -> Self {
+        self.inner = self.inner.amount_required(amount_required);
+        self
+    }
+
+    /// Sets the `request_details required` query parameter.
+    pub fn request_details_required(mut self, request_details_required: &'a str) -> Self {
+        self.inner = self
+            .inner
+            .request_details_required(request_details_required);
+        self
+    }
+
+    /// Sets the `session_id required` query parameter.
+    pub fn session_id_required(mut self, session_id_required: &'a str) -> Self {
+        self.inner = self.inner.session_id_required(session_id_required);
+        self
+    }
+
+    /// Sets the `unique_token required` query parameter.
+    pub fn unique_token_required(mut self, unique_token_required: &'a str) -> Self {
+        self.inner = self.inner.unique_token_required(unique_token_required);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`SalesChannels::create_checkouts_param_token_payments`], set with a builder.
+pub struct SalesChannelsCreateCheckoutsParamTokenPaymentsRequest<'a> {
+    inner: crate::sales_channels::SalesChannelsCreateCheckoutsParamTokenPaymentsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> SalesChannelsCreateCheckoutsParamTokenPaymentsRequest<'a> {
+    /// Sets the `amount required` query parameter.
+    pub fn amount_required(mut self, amount_required: &'a str) -> Self {
+        self.inner = self.inner.amount_required(amount_required);
+        self
+    }
+
+    /// Sets the `request_details required` query parameter.
+    pub fn request_details_required(mut self, request_details_required: &'a str) -> Self {
+        self.inner = self
+            .inner
+            .request_details_required(request_details_required);
+        self
+    }
+
+    /// Sets the `session_id required` query parameter.
+    pub fn session_id_required(mut self, session_id_required: &'a str) -> Self {
+        self.inner = self.inner.session_id_required(session_id_required);
+        self
+    }
+
+    /// Sets the `unique_token required` query parameter.
+    pub fn unique_token_required(mut self, unique_token_required: &'a str) -> Self {
+        self.inner = self.inner.unique_token_required(unique_token_required);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`SalesChannels::deprecated_202101_create_checkouts_param_token_payments`], set with a builder.
+pub struct SalesChannelsDeprecated202101CreateCheckoutsParamTokenPaymentsRequest<'a> {
+            inner: crate::sales_channels::SalesChannelsDeprecated202101CreateCheckoutsParamTokenPaymentsRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a> SalesChannelsDeprecated202101CreateCheckoutsParamTokenPaymentsRequest<'a> {
+    /// Sets the `amount required` query parameter.
+    pub fn amount_required(mut self, amount_required: &'a str) -> Self {
+        self.inner = self.inner.amount_required(amount_required);
+        self
+    }
+
+    /// Sets the `request_details required` query parameter.
+    pub fn request_details_required(mut self, request_details_required: &'a str) -> Self {
+        self.inner = self
+            .inner
+            .request_details_required(request_details_required);
+        self
+    }
+
+    /// Sets the `session_id required` query parameter.
+    pub fn session_id_required(mut self, session_id_required: &'a str) -> Self {
+        self.inner = self.inner.session_id_required(session_id_required);
+        self
+    }
+
+    /// Sets the `unique_token required` query parameter.
+    pub fn unique_token_required(mut self, unique_token_required: &'a str) -> Self {
+        self.inner = self.inner.unique_token_required(unique_token_required);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`SalesChannels::deprecated_unstable_create_checkouts_param_token_payments`], set with a builder.
+pub struct SalesChannelsDeprecatedUnstableCreateCheckoutsParamTokenPaymentsRequest<'a> {
+            inner: crate::sales_channels::SalesChannelsDeprecatedUnstableCreateCheckoutsParamTokenPaymentsRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a> SalesChannelsDeprecatedUnstableCreateCheckoutsParamTokenPaymentsRequest<'a> {
+    /// Sets the `amount required` query parameter.
+    pub fn amount_required(mut self, amount_required: &'a str) -> Self {
+        self.inner = self.inner.amount_required(amount_required);
+        self
+    }
+
+    /// Sets the `request_details required` query parameter.
+    pub fn request_details_required(mut self, request_details_required: &'a str) -> Self {
+        self.inner = self
+            .inner
+            .request_details_required(request_details_required);
+        self
+    }
+
+    /// Sets the `session_id required` query parameter.
+    pub fn session_id_required(mut self, session_id_required: &'a str) -> Self {
+        self.inner = self.inner.session_id_required(session_id_required);
+        self
+    }
+
+    /// Sets the `unique_token required` query parameter.
+    pub fn unique_token_required(mut self, unique_token_required: &'a str) -> Self {
+        self.inner = self.inner.unique_token_required(unique_token_required);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`SalesChannels::deprecated_202001_get_product_listing`], set with a builder.
+pub struct SalesChannelsDeprecated202001GetProductListingRequest<'a> {
+    inner: crate::sales_channels::SalesChannelsDeprecated202001GetProductListingRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> SalesChannelsDeprecated202001GetProductListingRequest<'a> {
+    /// Sets the `product_ids` query parameter.
+    pub fn product_ids(mut self, product_ids: &'a str) -> Self {
+        self.inner = self.inner.product_ids(product_ids);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `collection_id` query parameter.
+    pub fn collection_id(mut self, collection_id: &'a str) -> Self {
+        self.inner = self.inner.collection_id(collection_id);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.inner = self.inner.updated_at_min(updated_at_min);
+        self
+    }
+
+    /// Sets the `handle` query parameter.
+    pub fn handle(mut self, handle: &'a str) -> Self {
+        self.inner = self.inner.handle(handle);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`SalesChannels::deprecated_202001_get_product_listings_id`], set with a builder.
+pub struct SalesChannelsDeprecated202001GetProductListingsIdRequest<'a> {
+    inner: crate::sales_channels::SalesChannelsDeprecated202001GetProductListingsIdRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> SalesChannelsDeprecated202001GetProductListingsIdRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`SalesChannels::deprecated_202004_get_product_listing`], set with a builder.
+pub struct SalesChannelsDeprecated202004GetProductListingRequest<'a> {
+    inner: crate::sales_channels::SalesChannelsDeprecated202004GetProductListingRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> SalesChannelsDeprecated202004GetProductListingRequest<'a> {
+    /// Sets the `product_ids` query parameter.
+    pub fn product_ids(mut self, product_ids: &'a str) -> Self {
+        self.inner = self.inner.product_ids(product_ids);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `collection_id` query parameter.
+    pub fn collection_id(mut self, collection_id: &'a str) -> Self {
+        self.inner = self.inner.collection_id(collection_id);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.inner = self.inner.updated_at_min(updated_at_min);
+        self
+    }
+
+    /// Sets the `handle` query parameter.
+    pub fn handle(mut self, handle: &'a str) -> Self {
+        self.inner = self.inner.handle(handle);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`SalesChannels::deprecated_202004_get_product_listings_id`], set with a builder.
+pub struct SalesChannelsDeprecated202004GetProductListingsIdRequest<'a> {
+    inner: crate::sales_channels::SalesChannelsDeprecated202004GetProductListingsIdRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> SalesChannelsDeprecated202004GetProductListingsIdRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`SalesChannels::deprecated_202007_get_product_listing`], set with a builder.
+pub struct SalesChannelsDeprecated202007GetProductListingRequest<'a> {
+    inner: crate::sales_channels::SalesChannelsDeprecated202007GetProductListingRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> SalesChannelsDeprecated202007GetProductListingRequest<'a> {
+    /// Sets the `product_ids` query parameter.
+    pub fn product_ids(mut self, product_ids: &'a str) -> Self {
+        self.inner = self.inner.product_ids(product_ids);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `collection_id` query parameter.
+    pub fn collection_id(mut self, collection_id: &'a str) -> Self {
+        self.inner = self.inner.collection_id(collection_id);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.inner = self.inner.updated_at_min(updated_at_min);
+        self
+    }
+
+    /// Sets the `handle` query parameter.
+    pub fn handle(mut self, handle: &'a str) -> Self {
+        self.inner = self.inner.handle(handle);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`SalesChannels::deprecated_202007_get_product_listings_id`], set with a builder.
+pub struct SalesChannelsDeprecated202007GetProductListingsIdRequest<'a> {
+    inner: crate::sales_channels::SalesChannelsDeprecated202007GetProductListingsIdRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> SalesChannelsDeprecated202007GetProductListingsIdRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::sales_channels::SalesChannels`].
+pub struct SalesChannels {
+    inner: crate::sales_channels::SalesChannels,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl SalesChannels {
+    pub(crate) fn new(
+        inner: crate::sales_channels::SalesChannels,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        SalesChannels { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::sales_channels::SalesChannels::deprecated_202001_create_checkouts`].
+    pub fn deprecated_202001_create_checkouts(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202001_create_checkouts(body))
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::deprecated_202004_create_checkouts`].
+    pub fn deprecated_202004_create_checkouts(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202004_create_checkouts(body))
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::deprecated_202007_create_checkouts`].
+    pub fn deprecated_202007_create_checkouts(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202007_create_checkouts(body))
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::create_checkouts`].
+    pub fn create_checkouts(&self, body: &serde_json::Value) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.create_checkouts(body))
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::deprecated_202101_create_checkouts`].
+    pub fn deprecated_202101_create_checkouts(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202101_create_checkouts(body))
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::deprecated_unstable_create_checkouts`].
+    pub fn deprecated_unstable_create_checkouts(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_unstable_create_checkouts(body))
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::deprecated_202001_create_checkouts_param_token_complete`].
+    pub fn deprecated_202001_create_checkouts_param_token_complete(
+        &self,
+        token: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_create_checkouts_param_token_complete(token, body),
+        )
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::deprecated_202001_get_checkouts_param_token`].
+    pub fn deprecated_202001_get_checkouts_param_token(
+        &self,
+        token: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_get_checkouts_param_token(token),
+        )
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::deprecated_202001_update_checkouts_param_token`].
+    pub fn deprecated_202001_update_checkouts_param_token(
+        &self,
+        token: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_update_checkouts_param_token(token, body),
+        )
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::deprecated_202001_get_checkouts_param_token_shipping_rate`].
+    pub fn deprecated_202001_get_checkouts_param_token_shipping_rate(
+        &self,
+        token: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_get_checkouts_param_token_shipping_rate(token),
+        )
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::deprecated_202004_create_checkouts_param_token_complete`].
+    pub fn deprecated_202004_create_checkouts_param_token_complete(
+        &self,
+        token: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_create_checkouts_param_token_complete(token, body),
+        )
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::deprecated_202004_get_checkouts_param_token`].
+    pub fn deprecated_202004_get_checkouts_param_token(
+        &self,
+        token: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_get_checkouts_param_token(token),
+        )
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::deprecated_202004_update_checkouts_param_token`].
+    pub fn deprecated_202004_update_checkouts_param_token(
+        &self,
+        token: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_update_checkouts_param_token(token, body),
+        )
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::deprecated_202004_get_checkouts_param_token_shipping_rate`].
+    pub fn deprecated_202004_get_checkouts_param_token_shipping_rate(
+        &self,
+        token: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_get_checkouts_param_token_shipping_rate(token),
+        )
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::deprecated_202007_create_checkouts_param_token_complete`].
+    pub fn deprecated_202007_create_checkouts_param_token_complete(
+        &self,
+        token: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_create_checkouts_param_token_complete(token, body),
+        )
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::deprecated_202007_get_checkouts_param_token`].
+    pub fn deprecated_202007_get_checkouts_param_token(
+        &self,
+        token: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_get_checkouts_param_token(token),
+        )
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::deprecated_202007_update_checkouts_param_token`].
+    pub fn deprecated_202007_update_checkouts_param_token(
+        &self,
+        token: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_update_checkouts_param_token(token, body),
+        )
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::deprecated_202007_get_checkouts_param_token_shipping_rate`].
+    pub fn deprecated_202007_get_checkouts_param_token_shipping_rate(
+        &self,
+        token: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_get_checkouts_param_token_shipping_rate(token),
+        )
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::create_checkouts_param_token_complete`].
+    pub fn create_checkouts_param_token_complete(
+        &self,
+        token: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .create_checkouts_param_token_complete(token, body),
+        )
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::get_checkouts_param_token`].
+    pub fn get_checkouts_param_token(&self, token: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.get_checkouts_param_token(token))
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::update_checkouts_param_token`].
+    pub fn update_checkouts_param_token(
+        &self,
+        token: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.update_checkouts_param_token(token, body))
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::get_checkouts_param_token_shipping_rate`].
+    pub fn get_checkouts_param_token_shipping_rate(
+        &self,
+        token: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.get_checkouts_param_token_shipping_rate(token))
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::deprecated_202101_create_checkouts_param_token_complete`].
+    pub fn deprecated_202101_create_checkouts_param_token_complete(
+        &self,
+        token: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_create_checkouts_param_token_complete(token, body),
+        )
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::deprecated_202101_get_checkouts_param_token`].
+    pub fn deprecated_202101_get_checkouts_param_token(
+        &self,
+        token: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_get_checkouts_param_token(token),
+        )
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::deprecated_202101_update_checkouts_param_token`].
+    pub fn deprecated_202101_update_checkouts_param_token(
+        &self,
+        token: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_update_checkouts_param_token(token, body),
+        )
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::deprecated_202101_get_checkouts_param_token_shipping_rate`].
+    pub fn deprecated_202101_get_checkouts_param_token_shipping_rate(
+        &self,
+        token: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_get_checkouts_param_token_shipping_rate(token),
+        )
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::deprecated_unstable_create_checkouts_param_token_complete`].
+    pub fn deprecated_unstable_create_checkouts_param_token_complete(
+        &self,
+        token: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_create_checkouts_param_token_complete(token, body),
+        )
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::deprecated_unstable_get_checkouts_param_token`].
+    pub fn deprecated_unstable_get_checkouts_param_token(
+        &self,
+        token: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_get_checkouts_param_token(token),
+        )
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::deprecated_unstable_update_checkouts_param_token`].
+    pub fn deprecated_unstable_update_checkouts_param_token(
+        &self,
+        token: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_update_checkouts_param_token(token, body),
+        )
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::deprecated_unstable_get_checkouts_param_token_shipping_rate`].
+    pub fn deprecated_unstable_get_checkouts_param_token_shipping_rate(
+        &self,
+        token: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_get_checkouts_param_token_shipping_rate(token),
+        )
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::deprecated_202001_get_collection_listing`].
+    pub fn deprecated_202001_get_collection_listing(
+        &self,
+        limit: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202001_get_collection_listing(limit))
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::deprecated_202001_get_collection_listing_request`].
+    pub fn deprecated_202001_get_collection_listing_request(
+        &self,
+    ) -> SalesChannelsDeprecated202001GetCollectionListingRequest<'_> {
+        SalesChannelsDeprecated202001GetCollectionListingRequest {
+            inner: self
+                .inner
+                .deprecated_202001_get_collection_listing_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::deprecated_202001_get_collection_listings_param_listing_product_id`].
+    pub fn deprecated_202001_get_collection_listings_param_listing_product_id(
+        &self,
+        collection_listing_id: &str,
+        limit: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_get_collection_listings_param_listing_product_id(
+                    collection_listing_id,
+                    limit,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::deprecated_202001_get_collection_listings_param_listing_product_id_request`].
+    pub fn deprecated_202001_get_collection_listings_param_listing_product_id_request<'a>(
+        &'a self,
+        collection_listing_id: &'a str,
+    ) -> SalesChannelsDeprecated202001GetCollectionListingsParamListingProductIdRequest<'a> {
+        SalesChannelsDeprecated202001GetCollectionListingsParamListingProductIdRequest {
+            inner: self
+                .inner
+                .deprecated_202001_get_collection_listings_param_listing_product_id_request(
+                    collection_listing_id,
+                ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::deprecated_202001_get_collection_listings_param_listing`].
+    pub fn deprecated_202001_get_collection_listings_param_listing(
+        &self,
+        collection_listing_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_get_collection_listings_param_listing(collection_listing_id),
+        )
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::deprecated_202001_update_collection_listings_param_listing`].
+    pub fn deprecated_202001_update_collection_listings_param_listing(
+        &self,
+        collection_listing_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_update_collection_listings_param_listing(
+                    collection_listing_id,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::deprecated_202001_delete_collection_listings_param_listing`].
+    pub fn deprecated_202001_delete_collection_listings_param_listing(
+        &self,
+        collection_listing_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_delete_collection_listings_param_listing(collection_listing_id),
+        )
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::deprecated_202004_get_collection_listing`].
+    pub fn deprecated_202004_get_collection_listing(
+        &self,
+        limit: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202004_get_collection_listing(limit))
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::deprecated_202004_get_collection_listing_request`].
+    pub fn deprecated_202004_get_collection_listing_request(
+        &self,
+    ) -> SalesChannelsDeprecated202004GetCollectionListingRequest<'_> {
+        SalesChannelsDeprecated202004GetCollectionListingRequest {
+            inner: self
+                .inner
+                .deprecated_202004_get_collection_listing_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::deprecated_202004_get_collection_listings_param_listing_product_id`].
+    pub fn deprecated_202004_get_collection_listings_param_listing_product_id(
+        &self,
+        collection_listing_id: &str,
+        limit: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_get_collection_listings_param_listing_product_id(
+                    collection_listing_id,
+                    limit,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::deprecated_202004_get_collection_listings_param_listing_product_id_request`].
+    pub fn deprecated_202004_get_collection_listings_param_listing_product_id_request<'a>(
+        &'a self,
+        collection_listing_id: &'a str,
+    ) -> SalesChannelsDeprecated202004GetCollectionListingsParamListingProductIdRequest<'a> {
+        SalesChannelsDeprecated202004GetCollectionListingsParamListingProductIdRequest {
+            inner: self
+                .inner
+                .deprecated_202004_get_collection_listings_param_listing_product_id_request(
+                    collection_listing_id,
+                ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::deprecated_202004_get_collection_listings_param_listing`].
+    pub fn deprecated_202004_get_collection_listings_param_listing(
+        &self,
+        collection_listing_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_get_collection_listings_param_listing(collection_listing_id),
+        )
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::deprecated_202004_update_collection_listings_param_listing`].
+    pub fn deprecated_202004_update_collection_listings_param_listing(
+        &self,
+        collection_listing_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_update_collection_listings_param_listing(
+                    collection_listing_id,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::deprecated_202004_delete_collection_listings_param_listing`].
+    pub fn deprecated_202004_delete_collection_listings_param_listing(
+        &self,
+        collection_listing_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_delete_collection_listings_param_listing(collection_listing_id),
+        )
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::deprecated_202007_get_collection_listing`].
+    pub fn deprecated_202007_get_collection_listing(
+        &self,
+        limit: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202007_get_collection_listing(limit))
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::deprecated_202007_get_collection_listing_request`].
+    pub fn deprecated_202007_get_collection_listing_request(
+        &self,
+    ) -> SalesChannelsDeprecated202007GetCollectionListingRequest<'_> {
+        SalesChannelsDeprecated202007GetCollectionListingRequest {
+            inner: self
+                .inner
+                .deprecated_202007_get_collection_listing_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::deprecated_202007_get_collection_listings_param_listing_product_id`].
+    pub fn deprecated_202007_get_collection_listings_param_listing_product_id(
+        &self,
+        collection_listing_id: &str,
+        limit: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_get_collection_listings_param_listing_product_id(
+                    collection_listing_id,
+                    limit,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::deprecated_202007_get_collection_listings_param_listing_product_id_request`].
+    pub fn deprecated_202007_get_collection_listings_param_listing_product_id_request<'a>(
+        &'a self,
+        collection_listing_id: &'a str,
+    ) -> SalesChannelsDeprecated202007GetCollectionListingsParamListingProductIdRequest<'a> {
+        SalesChannelsDeprecated202007GetCollectionListingsParamListingProductIdRequest {
+            inner: self
+                .inner
+                .deprecated_202007_get_collection_listings_param_listing_product_id_request(
+                    collection_listing_id,
+                ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::deprecated_202007_get_collection_listings_param_listing`].
+    pub fn deprecated_202007_get_collection_listings_param_listing(
+        &self,
+        collection_listing_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_get_collection_listings_param_listing(collection_listing_id),
+        )
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::deprecated_202007_update_collection_listings_param_listing`].
+    pub fn deprecated_202007_update_collection_listings_param_listing(
+        &self,
+        collection_listing_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_update_collection_listings_param_listing(
+                    collection_listing_id,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::deprecated_202007_delete_collection_listings_param_listing`].
+    pub fn deprecated_202007_delete_collection_listings_param_listing(
+        &self,
+        collection_listing_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_delete_collection_listings_param_listing(collection_listing_id),
+        )
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::get_collection_listing`].
+    pub fn get_collection_listing(&self, limit: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.get_collection_listing(limit))
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::get_collection_listing_request`].
+    pub fn get_collection_listing_request(&self) -> SalesChannelsGetCollectionListingRequest<'_> {
+        SalesChannelsGetCollectionListingRequest {
+            inner: self.inner.get_collection_listing_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::get_collection_listings_param_listing_product_id`].
+    pub fn get_collection_listings_param_listing_product_id(
+        &self,
+        collection_listing_id: &str,
+        limit: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .get_collection_listings_param_listing_product_id(collection_listing_id, limit),
+        )
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::get_collection_listings_param_listing_product_id_request`].
+    pub fn get_collection_listings_param_listing_product_id_request<'a>(
+        &'a self,
+        collection_listing_id: &'a str,
+    ) -> SalesChannelsGetCollectionListingsParamListingProductIdRequest<'a> {
+        SalesChannelsGetCollectionListingsParamListingProductIdRequest {
+            inner: self
+                .inner
+                .get_collection_listings_param_listing_product_id_request(collection_listing_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::get_collection_listings_param_listing`].
+    pub fn get_collection_listings_param_listing(
+        &self,
+        collection_listing_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .get_collection_listings_param_listing(collection_listing_id),
+        )
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::update_collection_listings_param_listing`].
+    pub fn update_collection_listings_param_listing(
+        &self,
+        collection_listing_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .update_collection_listings_param_listing(collection_listing_id, body),
+        )
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::delete_collection_listings_param_listing`].
+    pub fn delete_collection_listings_param_listing(
+        &self,
+        collection_listing_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .delete_collection_listings_param_listing(collection_listing_id),
+        )
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::deprecated_202101_get_collection_listing`].
+    pub fn deprecated_202101_get_collection_listing(
+        &self,
+        limit: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202101_get_collection_listing(limit))
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::deprecated_202101_get_collection_listing_request`].
+    pub fn deprecated_202101_get_collection_listing_request(
+        &self,
+    ) -> SalesChannelsDeprecated202101GetCollectionListingRequest<'_> {
+        SalesChannelsDeprecated202101GetCollectionListingRequest {
+            inner: self
+                .inner
+                .deprecated_202101_get_collection_listing_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::deprecated_202101_get_collection_listings_param_listing_product_id`].
+    pub fn deprecated_202101_get_collection_listings_param_listing_product_id(
+        &self,
+        collection_listing_id: &str,
+        limit: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_get_collection_listings_param_listing_product_id(
+                    collection_listing_id,
+                    limit,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::deprecated_202101_get_collection_listings_param_listing_product_id_request`].
+    pub fn deprecated_202101_get_collection_listings_param_listing_product_id_request<'a>(
+        &'a self,
+        collection_listing_id: &'a str,
+    ) -> SalesChannelsDeprecated202101GetCollectionListingsParamListingProductIdRequest<'a> {
+        SalesChannelsDeprecated202101GetCollectionListingsParamListingProductIdRequest {
+            inner: self
+                .inner
+                .deprecated_202101_get_collection_listings_param_listing_product_id_request(
+                    collection_listing_id,
+                ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::deprecated_202101_get_collection_listings_param_listing`].
+    pub fn deprecated_202101_get_collection_listings_param_listing(
+        &self,
+        collection_listing_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_get_collection_listings_param_listing(collection_listing_id),
+        )
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::deprecated_202101_update_collection_listings_param_listing`].
+    pub fn deprecated_202101_update_collection_listings_param_listing(
+        &self,
+        collection_listing_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_update_collection_listings_param_listing(
+                    collection_listing_id,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::deprecated_202101_delete_collection_listings_param_listing`].
+    pub fn deprecated_202101_delete_collection_listings_param_listing(
+        &self,
+        collection_listing_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_delete_collection_listings_param_listing(collection_listing_id),
+        )
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::deprecated_unstable_get_collection_listing`].
+    pub fn deprecated_unstable_get_collection_listing(
+        &self,
+        limit: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_unstable_get_collection_listing(limit))
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::deprecated_unstable_get_collection_listing_request`].
+    pub fn deprecated_unstable_get_collection_listing_request(
+        &self,
+    ) -> SalesChannelsDeprecatedUnstableGetCollectionListingRequest<'_> {
+        SalesChannelsDeprecatedUnstableGetCollectionListingRequest {
+            inner: self
+                .inner
+                .deprecated_unstable_get_collection_listing_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::deprecated_unstable_get_collection_listings_param_listing_product_id`].
+    pub fn deprecated_unstable_get_collection_listings_param_listing_product_id(
+        &self,
+        collection_listing_id: &str,
+        limit: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_get_collection_listings_param_listing_product_id(
+                    collection_listing_id,
+                    limit,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::deprecated_unstable_get_collection_listings_param_listing_product_id_request`].
+    pub fn deprecated_unstable_get_collection_listings_param_listing_product_id_request<'a>(
+        &'a self,
+        collection_listing_id: &'a str,
+    ) -> SalesChannelsDeprecatedUnstableGetCollectionListingsParamListingProductIdRequest<'a> {
+        SalesChannelsDeprecatedUnstableGetCollectionListingsParamListingProductIdRequest {
+            inner: self
+                .inner
+                .deprecated_unstable_get_collection_listings_param_listing_product_id_request(
+                    collection_listing_id,
+                ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::deprecated_unstable_get_collection_listings_param_listing`].
+    pub fn deprecated_unstable_get_collection_listings_param_listing(
+        &self,
+        collection_listing_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_get_collection_listings_param_listing(collection_listing_id),
+        )
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::deprecated_unstable_update_collection_listings_param_listing`].
+    pub fn deprecated_unstable_update_collection_listings_param_listing(
+        &self,
+        collection_listing_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_update_collection_listings_param_listing(
+                    collection_listing_id,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::deprecated_unstable_delete_collection_listings_param_listing`].
+    pub fn deprecated_unstable_delete_collection_listings_param_listing(
+        &self,
+        collection_listing_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_delete_collection_listings_param_listing(
+                    collection_listing_id,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::deprecated_202001_get_checkouts_param_token_payment`].
+    pub fn deprecated_202001_get_checkouts_param_token_payment(
+        &self,
+        token: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_get_checkouts_param_token_payment(token),
+        )
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::deprecated_202001_create_checkouts_param_token_payments`].
+    pub fn deprecated_202001_create_checkouts_param_token_payments(
+        &self,
+        token: &str,
+        amount_required: &str,
+        request_details_required: &str,
+        session_id_required: &str,
+        unique_token_required: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_create_checkouts_param_token_payments(
+                    token,
+                    amount_required,
+                    request_details_required,
+                    session_id_required,
+                    unique_token_required,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::deprecated_202001_create_checkouts_param_token_payments_request`].
+    pub fn deprecated_202001_create_checkouts_param_token_payments_request<'a>(
+        &'a self,
+        token: &'a str,
+        body: &'a serde_json::Value,
+    ) -> SalesChannelsDeprecated202001CreateCheckoutsParamTokenPaymentsRequest<'a> {
+        SalesChannelsDeprecated202001CreateCheckoutsParamTokenPaymentsRequest {
+            inner: self
+                .inner
+                .deprecated_202001_create_checkouts_param_token_payments_request(token, body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::deprecated_202001_get_checkouts_param_token_payments_payment`].
+    pub fn deprecated_202001_get_checkouts_param_token_payments_payment(
+        &self,
+        token: &str,
+        payment_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_get_checkouts_param_token_payments_payment(token, payment_id),
+        )
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::deprecated_202001_get_checkouts_param_token_payments_count`].
+    pub fn deprecated_202001_get_checkouts_param_token_payments_count(
+        &self,
+        token: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_get_checkouts_param_token_payments_count(token),
+        )
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::deprecated_202004_get_checkouts_param_token_payment`].
+    pub fn deprecated_202004_get_checkouts_param_token_payment(
+        &self,
+        token: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_get_checkouts_param_token_payment(token),
+        )
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::deprecated_202004_create_checkouts_param_token_payments`].
+    pub fn deprecated_202004_create_checkouts_param_token_payments(
+        &self,
+        token: &str,
+        amount_required: &str,
+        request_details_required: &str,
+        session_id_required: &str,
+        unique_token_required: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_create_checkouts_param_token_payments(
+                    token,
+                    amount_required,
+                    request_details_required,
+                    session_id_required,
+                    unique_token_required,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::deprecated_202004_create_checkouts_param_token_payments_request`].
+    pub fn deprecated_202004_create_checkouts_param_token_payments_request<'a>(
+        &'a self,
+        token: &'a str,
+        body: &'a serde_json::Value,
+    ) -> SalesChannelsDeprecated202004CreateCheckoutsParamTokenPaymentsRequest<'a> {
+        SalesChannelsDeprecated202004CreateCheckoutsParamTokenPaymentsRequest {
+            inner: self
+                .inner
+                .deprecated_202004_create_checkouts_param_token_payments_request(token, body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::deprecated_202004_get_checkouts_param_token_payments_payment`].
+    pub fn deprecated_202004_get_checkouts_param_token_payments_payment(
+        &self,
+        token: &str,
+        payment_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_get_checkouts_param_token_payments_payment(token, payment_id),
+        )
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::deprecated_202004_get_checkouts_param_token_payments_count`].
+    pub fn deprecated_202004_get_checkouts_param_token_payments_count(
+        &self,
+        token: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_get_checkouts_param_token_payments_count(token),
+        )
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::deprecated_202007_get_checkouts_param_token_payment`].
+    pub fn deprecated_202007_get_checkouts_param_token_payment(
+        &self,
+        token: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_get_checkouts_param_token_payment(token),
+        )
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::deprecated_202007_create_checkouts_param_token_payments`].
+    pub fn deprecated_202007_create_checkouts_param_token_payments(
+        &self,
+        token: &str,
+        amount_required: &str,
+        request_details_required: &str,
+        session_id_required: &str,
+        unique_token_required: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_create_checkouts_param_token_payments(
+                    token,
+                    amount_required,
+                    request_details_required,
+                    session_id_required,
+                    unique_token_required,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::deprecated_202007_create_checkouts_param_token_payments_request`].
+    pub fn deprecated_202007_create_checkouts_param_token_payments_request<'a>(
+        &'a self,
+        token: &'a str,
+        body: &'a serde_json::Value,
+    ) -> SalesChannelsDeprecated202007CreateCheckoutsParamTokenPaymentsRequest<'a> {
+        SalesChannelsDeprecated202007CreateCheckoutsParamTokenPaymentsRequest {
+            inner: self
+                .inner
+                .deprecated_202007_create_checkouts_param_token_payments_request(token, body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::deprecated_202007_get_checkouts_param_token_payments_payment`].
+    pub fn deprecated_202007_get_checkouts_param_token_payments_payment(
+        &self,
+        token: &str,
+        payment_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_get_checkouts_param_token_payments_payment(token, payment_id),
+        )
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::deprecated_202007_get_checkouts_param_token_payments_count`].
+    pub fn deprecated_202007_get_checkouts_param_token_payments_count(
+        &self,
+        token: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_get_checkouts_param_token_payments_count(token),
+        )
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::get_checkouts_param_token_payment`].
+    pub fn get_checkouts_param_token_payment(
+        &self,
+        token: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.get_checkouts_param_token_payment(token))
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::create_checkouts_param_token_payments`].
+    pub fn create_checkouts_param_token_payments(
+        &self,
+        token: &str,
+        amount_required: &str,
+        request_details_required: &str,
+        session_id_required: &str,
+        unique_token_required: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.create_checkouts_param_token_payments(
+                token,
+                amount_required,
+                request_details_required,
+                session_id_required,
+                unique_token_required,
+                body,
+            ))
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::create_checkouts_param_token_payments_request`].
+    pub fn create_checkouts_param_token_payments_request<'a>(
+        &'a self,
+        token: &'a str,
+        body: &'a serde_json::Value,
+    ) -> SalesChannelsCreateCheckoutsParamTokenPaymentsRequest<'a> {
+        SalesChannelsCreateCheckoutsParamTokenPaymentsRequest {
+            inner: self
+                .inner
+                .create_checkouts_param_token_payments_request(token, body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::get_checkouts_param_token_payments_payment`].
+    pub fn get_checkouts_param_token_payments_payment(
+        &self,
+        token: &str,
+        payment_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .get_checkouts_param_token_payments_payment(token, payment_id),
+        )
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::get_checkouts_param_token_payments_count`].
+    pub fn get_checkouts_param_token_payments_count(
+        &self,
+        token: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.get_checkouts_param_token_payments_count(token))
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::deprecated_202101_get_checkouts_param_token_payment`].
+    pub fn deprecated_202101_get_checkouts_param_token_payment(
+        &self,
+        token: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_get_checkouts_param_token_payment(token),
+        )
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::deprecated_202101_create_checkouts_param_token_payments`].
+    pub fn deprecated_202101_create_checkouts_param_token_payments(
+        &self,
+        token: &str,
+        amount_required: &str,
+        request_details_required: &str,
+        session_id_required: &str,
+        unique_token_required: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_create_checkouts_param_token_payments(
+                    token,
+                    amount_required,
+                    request_details_required,
+                    session_id_required,
+                    unique_token_required,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::deprecated_202101_create_checkouts_param_token_payments_request`].
+    pub fn deprecated_202101_create_checkouts_param_token_payments_request<'a>(
+        &'a self,
+        token: &'a str,
+        body: &'a serde_json::Value,
+    ) -> SalesChannelsDeprecated202101CreateCheckoutsParamTokenPaymentsRequest<'a> {
+        SalesChannelsDeprecated202101CreateCheckoutsParamTokenPaymentsRequest {
+            inner: self
+                .inner
+                .deprecated_202101_create_checkouts_param_token_payments_request(token, body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::deprecated_202101_get_checkouts_param_token_payments_payment`].
+    pub fn deprecated_202101_get_checkouts_param_token_payments_payment(
+        &self,
+        token: &str,
+        payment_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_get_checkouts_param_token_payments_payment(token, payment_id),
+        )
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::deprecated_202101_get_checkouts_param_token_payments_count`].
+    pub fn deprecated_202101_get_checkouts_param_token_payments_count(
+        &self,
+        token: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_get_checkouts_param_token_payments_count(token),
+        )
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::deprecated_unstable_get_checkouts_param_token_payment`].
+    pub fn deprecated_unstable_get_checkouts_param_token_payment(
+        &self,
+        token: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_get_checkouts_param_token_payment(token),
+        )
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::deprecated_unstable_create_checkouts_param_token_payments`].
+    pub fn deprecated_unstable_create_checkouts_param_token_payments(
+        &self,
+        token: &str,
+        amount_required: &str,
+        request_details_required: &str,
+        session_id_required: &str,
+        unique_token_required: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_create_checkouts_param_token_payments(
+                    token,
+                    amount_required,
+                    request_details_required,
+                    session_id_required,
+                    unique_token_required,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::deprecated_unstable_create_checkouts_param_token_payments_request`].
+    pub fn deprecated_unstable_create_checkouts_param_token_payments_request<'a>(
+        &'a self,
+        token: &'a str,
+        body: &'a serde_json::Value,
+    ) -> SalesChannelsDeprecatedUnstableCreateCheckoutsParamTokenPaymentsRequest<'a> {
+        SalesChannelsDeprecatedUnstableCreateCheckoutsParamTokenPaymentsRequest {
+            inner: self
+                .inner
+                .deprecated_unstable_create_checkouts_param_token_payments_request(token, body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::deprecated_unstable_get_checkouts_param_token_payments_payment`].
+    pub fn deprecated_unstable_get_checkouts_param_token_payments_payment(
+        &self,
+        token: &str,
+        payment_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_get_checkouts_param_token_payments_payment(token, payment_id),
+        )
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::deprecated_unstable_get_checkouts_param_token_payments_count`].
+    pub fn deprecated_unstable_get_checkouts_param_token_payments_count(
+        &self,
+        token: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_get_checkouts_param_token_payments_count(token),
+        )
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::deprecated_202001_get_product_listing`].
+    pub fn deprecated_202001_get_product_listing(
+        &self,
+        product_ids: &str,
+        limit: &str,
+        collection_id: &str,
+        updated_at_min: &str,
+        handle: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202001_get_product_listing(
+                product_ids,
+                limit,
+                collection_id,
+                updated_at_min,
+                handle,
+            ))
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::deprecated_202001_get_product_listing_request`].
+    pub fn deprecated_202001_get_product_listing_request(
+        &self,
+    ) -> SalesChannelsDeprecated202001GetProductListingRequest<'_> {
+        SalesChannelsDeprecated202001GetProductListingRequest {
+            inner: self.inner.deprecated_202001_get_product_listing_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::deprecated_202001_get_product_listings_id`].
+    pub fn deprecated_202001_get_product_listings_id(
+        &self,
+        limit: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202001_get_product_listings_id(limit))
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::deprecated_202001_get_product_listings_id_request`].
+    pub fn deprecated_202001_get_product_listings_id_request(
+        &self,
+    ) -> SalesChannelsDeprecated202001GetProductListingsIdRequest<'_> {
+        SalesChannelsDeprecated202001GetProductListingsIdRequest {
+            inner: self
+                .inner
+                .deprecated_202001_get_product_listings_id_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::deprecated_202001_get_product_listings_count`].
+    pub fn deprecated_202001_get_product_listings_count(
+        &self,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202001_get_product_listings_count())
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::deprecated_202001_get_product_listings_param_listing`].
+    pub fn deprecated_202001_get_product_listings_param_listing(
+        &self,
+        product_listing_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_get_product_listings_param_listing(product_listing_id),
+        )
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::deprecated_202001_update_product_listings_param_listing`].
+    pub fn deprecated_202001_update_product_listings_param_listing(
+        &self,
+        product_listing_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_update_product_listings_param_listing(product_listing_id, body),
+        )
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::deprecated_202001_delete_product_listings_param_listing`].
+    pub fn deprecated_202001_delete_product_listings_param_listing(
+        &self,
+        product_listing_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_delete_product_listings_param_listing(product_listing_id),
+        )
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::deprecated_202004_get_product_listing`].
+    pub fn deprecated_202004_get_product_listing(
+        &self,
+        product_ids: &str,
+        limit: &str,
+        collection_id: &str,
+        updated_at_min: &str,
+        handle: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202004_get_product_listing(
+                product_ids,
+                limit,
+                collection_id,
+                updated_at_min,
+                handle,
+            ))
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::deprecated_202004_get_product_listing_request`].
+    pub fn deprecated_202004_get_product_listing_request(
+        &self,
+    ) -> SalesChannelsDeprecated202004GetProductListingRequest<'_> {
+        SalesChannelsDeprecated202004GetProductListingRequest {
+            inner: self.inner.deprecated_202004_get_product_listing_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::deprecated_202004_get_product_listings_id`].
+    pub fn deprecated_202004_get_product_listings_id(
+        &self,
+        limit: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202004_get_product_listings_id(limit))
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::deprecated_202004_get_product_listings_id_request`].
+    pub fn deprecated_202004_get_product_listings_id_request(
+        &self,
+    ) -> SalesChannelsDeprecated202004GetProductListingsIdRequest<'_> {
+        SalesChannelsDeprecated202004GetProductListingsIdRequest {
+            inner: self
+                .inner
+                .deprecated_202004_get_product_listings_id_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::deprecated_202004_get_product_listings_count`].
+    pub fn deprecated_202004_get_product_listings_count(
+        &self,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202004_get_product_listings_count())
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::deprecated_202004_get_product_listings_param_listing`].
+    pub fn deprecated_202004_get_product_listings_param_listing(
+        &self,
+        product_listing_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_get_product_listings_param_listing(product_listing_id),
+        )
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::deprecated_202004_update_product_listings_param_listing`].
+    pub fn deprecated_202004_update_product_listings_param_listing(
+        &self,
+        product_listing_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_update_product_listings_param_listing(product_listing_id, body),
+        )
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::deprecated_202004_delete_product_listings_param_listing`].
+    pub fn deprecated_202004_delete_product_listings_param_listing(
+        &self,
+        product_listing_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_delete_product_listings_param_listing(product_listing_id),
+        )
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::deprecated_202007_get_product_listing`].
+    pub fn deprecated_202007_get_product_listing(
+        &self,
+        product_ids: &str,
+        limit: &str,
+        collection_id: &str,
+        updated_at_min: &str,
+        handle: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202007_get_product_listing(
+                product_ids,
+                limit,
+                collection_id,
+                updated_at_min,
+                handle,
+            ))
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::deprecated_202007_get_product_listing_request`].
+    pub fn deprecated_202007_get_product_listing_request(
+        &self,
+    ) -> SalesChannelsDeprecated202007GetProductListingRequest<'_> {
+        SalesChannelsDeprecated202007GetProductListingRequest {
+            inner: self.inner.deprecated_202007_get_product_listing_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::deprecated_202007_get_product_listings_id`].
+    pub fn deprecated_202007_get_product_listings_id(
+        &self,
+        limit: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202007_get_product_listings_id(limit))
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::deprecated_202007_get_product_listings_id_request`].
+    pub fn deprecated_202007_get_product_listings_id_request(
+        &self,
+    ) -> SalesChannelsDeprecated202007GetProductListingsIdRequest<'_> {
+        SalesChannelsDeprecated202007GetProductListingsIdRequest {
+            inner: self
+                .inner
+                .deprecated_202007_get_product_listings_id_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::deprecated_202007_get_product_listings_count`].
+    pub fn deprecated_202007_get_product_listings_count(
+        &self,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202007_get_product_listings_count())
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::deprecated_202007_get_product_listings_param_listing`].
+    pub fn deprecated_202007_get_product_listings_param_listing(
+        &self,
+        product_listing_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_get_product_listings_param_listing(product_listing_id),
+        )
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::deprecated_202007_update_product_listings_param_listing`].
+    pub fn deprecated_202007_update_product_listings_param_listing(
+        &self,
+        product_listing_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_update_product_listings_param_listing(product_listing_id, body),
+        )
+    }
+    /// The blocking version of [`crate::sales_channels::SalesChannels::deprecated_202007_delete_product_listings_param_listing`].
+    pub fn deprecated_202007_delete_product_listings_param_listing(
+        &self,
+        product_listing_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_delete_product_listings_param_listing(product_listing_id),
+        )
+    }
+}
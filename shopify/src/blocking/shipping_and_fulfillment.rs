@@ -0,0 +1,6097 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_202001_get_assigned_fulfillment_order`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecated202001GetAssignedFulfillmentOrderRequest<'a> {
+            inner: crate::shipping_and_fulfillment::ShippingAndFulfillmentDeprecated202001GetAssignedFulfillmentOrderRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a> ShippingAndFulfillmentDeprecated202001GetAssignedFulfillmentOrderRequest<'a> {
+    /// Sets the `assignment_status` query parameter.
+    pub fn assignment_status(mut self, assignment_status: &'a str) -> Self {
+        self.inner = self.inner.assignment_status(assignment_status);
+        self
+    }
+
+    /// Sets the `location_ids` query parameter.
+    pub fn location_ids(mut self, location_ids: &'a str) -> Self {
+        self.inner = self.inner.location_ids(location_ids);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_202004_get_assigned_fulfillment_order`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecated202004GetAssignedFulfillmentOrderRequest<'a> {
+            inner: crate::shipping_and_fulfillment::ShippingAndFulfillmentDeprecated202004GetAssignedFulfillmentOrderRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a> ShippingAndFulfillmentDeprecated202004GetAssignedFulfillmentOrderRequest<'a> {
+    /// Sets the `assignment_status` query parameter.
+    pub fn assignment_status(mut self, assignment_status: &'a str) -> Self {
+        self.inner = self.inner.assignment_status(assignment_status);
+        self
+    }
+
+    /// Sets the `location_ids` query parameter.
+    pub fn location_ids(mut self, location_ids: &'a str) -> Self {
+        self.inner = self.inner.location_ids(location_ids);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_202007_get_assigned_fulfillment_order`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecated202007GetAssignedFulfillmentOrderRequest<'a> {
+            inner: crate::shipping_and_fulfillment::ShippingAndFulfillmentDeprecated202007GetAssignedFulfillmentOrderRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a> ShippingAndFulfillmentDeprecated202007GetAssignedFulfillmentOrderRequest<'a> {
+    /// Sets the `assignment_status` query parameter.
+    pub fn assignment_status(mut self, assignment_status: &'a str) -> Self {
+        self.inner = self.inner.assignment_status(assignment_status);
+        self
+    }
+
+    /// Sets the `location_ids` query parameter.
+    pub fn location_ids(mut self, location_ids: &'a str) -> Self {
+        self.inner = self.inner.location_ids(location_ids);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::get_assigned_fulfillment_order`], set with a builder.
+pub struct ShippingAndFulfillmentGetAssignedFulfillmentOrderRequest<'a> {
+    inner:
+        crate::shipping_and_fulfillment::ShippingAndFulfillmentGetAssignedFulfillmentOrderRequest<
+            'a,
+        >,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ShippingAndFulfillmentGetAssignedFulfillmentOrderRequest<'a> {
+    /// Sets the `assignment_status` query parameter.
+    pub fn assignment_status(mut self, assignment_status: &'a str) -> Self {
+        self.inner = self.inner.assignment_status(assignment_status);
+        self
+    }
+
+    /// Sets the `location_ids` query parameter.
+    pub fn location_ids(mut self, location_ids: &'a str) -> Self {
+        self.inner = self.inner.location_ids(location_ids);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_202101_get_assigned_fulfillment_order`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecated202101GetAssignedFulfillmentOrderRequest<'a> {
+            inner: crate::shipping_and_fulfillment::ShippingAndFulfillmentDeprecated202101GetAssignedFulfillmentOrderRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a> ShippingAndFulfillmentDeprecated202101GetAssignedFulfillmentOrderRequest<'a> {
+    /// Sets the `assignment_status` query parameter.
+    pub fn assignment_status(mut self, assignment_status: &'a str) -> Self {
+        self.inner = self.inner.assignment_status(assignment_status);
+        self
+    }
+
+    /// Sets the `location_ids` query parameter.
+    pub fn location_ids(mut self, location_ids: &'a str) -> Self {
+        self.inner = self.inner.location_ids(location_ids);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_unstable_get_assigned_fulfillment_order`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecatedUnstableGetAssignedFulfillmentOrderRequest<'a> {
+            inner: crate::shipping_and_fulfillment::ShippingAndFulfillmentDeprecatedUnstableGetAssignedFulfillmentOrderRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a> ShippingAndFulfillmentDeprecatedUnstableGetAssignedFulfillmentOrderRequest<'a> {
+    /// Sets the `assignment_status` query parameter.
+    pub fn assignment_status(mut self, assignment_status: &'a str) -> Self {
+        self.inner = self.inner.assignment_status(assignment_status);
+        self
+    }
+
+    /// Sets the `location_ids` query parameter.
+    pub fn location_ids(mut self, location_ids: &'a str) -> Self {
+        self.inner = self.inner.location_ids(location_ids);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_202001_create_fulfillment_orders_param_order_cancellation_request`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderCancellationRequestRequest<'a> {
+            inner: crate::shipping_and_fulfillment::ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderCancellationRequestRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a> ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderCancellationRequestRequest<'a> {
+/// Sets the `message` query parameter.
+            pub fn message(mut self, message: &'a str) -> Self {
+                self.inner = self.inner.message(message);
+                self
+            }
+
+            /// Sends the request, with the parameters that were set.
+        pub fn send(self) -> ClientResult<crate::Response<()>> {
+            self.runtime.block_on(self.inner.send())
+        }
+        }
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_202001_create_fulfillment_orders_param_order_cancellation_request_accept`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderCancellationRequestAcceptRequest<'a> {
+            inner: crate::shipping_and_fulfillment::ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderCancellationRequestAcceptRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a> ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderCancellationRequestAcceptRequest<'a> {
+/// Sets the `message` query parameter.
+            pub fn message(mut self, message: &'a str) -> Self {
+                self.inner = self.inner.message(message);
+                self
+            }
+
+            /// Sends the request, with the parameters that were set.
+        pub fn send(self) -> ClientResult<crate::Response<()>> {
+            self.runtime.block_on(self.inner.send())
+        }
+        }
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_202001_create_fulfillment_orders_param_order_cancellation_request_reject`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderCancellationRequestRejectRequest<'a> {
+            inner: crate::shipping_and_fulfillment::ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderCancellationRequestRejectRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a> ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderCancellationRequestRejectRequest<'a> {
+/// Sets the `message` query parameter.
+            pub fn message(mut self, message: &'a str) -> Self {
+                self.inner = self.inner.message(message);
+                self
+            }
+
+            /// Sends the request, with the parameters that were set.
+        pub fn send(self) -> ClientResult<crate::Response<()>> {
+            self.runtime.block_on(self.inner.send())
+        }
+        }
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_202004_create_fulfillment_orders_param_order_cancellation_request`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderCancellationRequestRequest<'a> {
+            inner: crate::shipping_and_fulfillment::ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderCancellationRequestRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a> ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderCancellationRequestRequest<'a> {
+/// Sets the `message` query parameter.
+            pub fn message(mut self, message: &'a str) -> Self {
+                self.inner = self.inner.message(message);
+                self
+            }
+
+            /// Sends the request, with the parameters that were set.
+        pub fn send(self) -> ClientResult<crate::Response<()>> {
+            self.runtime.block_on(self.inner.send())
+        }
+        }
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_202004_create_fulfillment_orders_param_order_cancellation_request_accept`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderCancellationRequestAcceptRequest<'a> {
+            inner: crate::shipping_and_fulfillment::ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderCancellationRequestAcceptRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a> ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderCancellationRequestAcceptRequest<'a> {
+/// Sets the `message` query parameter.
+            pub fn message(mut self, message: &'a str) -> Self {
+                self.inner = self.inner.message(message);
+                self
+            }
+
+            /// Sends the request, with the parameters that were set.
+        pub fn send(self) -> ClientResult<crate::Response<()>> {
+            self.runtime.block_on(self.inner.send())
+        }
+        }
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_202004_create_fulfillment_orders_param_order_cancellation_request_reject`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderCancellationRequestRejectRequest<'a> {
+            inner: crate::shipping_and_fulfillment::ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderCancellationRequestRejectRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a> ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderCancellationRequestRejectRequest<'a> {
+/// Sets the `message` query parameter.
+            pub fn message(mut self, message: &'a str) -> Self {
+                self.inner = self.inner.message(message);
+                self
+            }
+
+            /// Sends the request, with the parameters that were set.
+        pub fn send(self) -> ClientResult<crate::Response<()>> {
+            self.runtime.block_on(self.inner.send())
+        }
+        }
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_202007_create_fulfillment_orders_param_order_cancellation_request`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderCancellationRequestRequest<'a> {
+            inner: crate::shipping_and_fulfillment::ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderCancellationRequestRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a> ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderCancellationRequestRequest<'a> {
+/// Sets the `message` query parameter.
+            pub fn message(mut self, message: &'a str) -> Self {
+                self.inner = self.inner.message(message);
+                self
+            }
+
+            /// Sends the request, with the parameters that were set.
+        pub fn send(self) -> ClientResult<crate::Response<()>> {
+            self.runtime.block_on(self.inner.send())
+        }
+        }
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_202007_create_fulfillment_orders_param_order_cancellation_request_accept`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderCancellationRequestAcceptRequest<'a> {
+            inner: crate::shipping_and_fulfillment::ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderCancellationRequestAcceptRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a> ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderCancellationRequestAcceptRequest<'a> {
+/// Sets the `message` query parameter.
+            pub fn message(mut self, message: &'a str) -> Self {
+                self.inner = self.inner.message(message);
+                self
+            }
+
+            /// Sends the request, with the parameters that were set.
+        pub fn send(self) -> ClientResult<crate::Response<()>> {
+            self.runtime.block_on(self.inner.send())
+        }
+        }
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_202007_create_fulfillment_orders_param_order_cancellation_request_reject`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderCancellationRequestRejectRequest<'a> {
+            inner: crate::shipping_and_fulfillment::ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderCancellationRequestRejectRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a> ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderCancellationRequestRejectRequest<'a> {
+/// Sets the `message` query parameter.
+            pub fn message(mut self, message: &'a str) -> Self {
+                self.inner = self.inner.message(message);
+                self
+            }
+
+            /// Sends the request, with the parameters that were set.
+        pub fn send(self) -> ClientResult<crate::Response<()>> {
+            self.runtime.block_on(self.inner.send())
+        }
+        }
+
+/// The parameters of [`ShippingAndFulfillment::create_fulfillment_orders_param_order_cancellation_request`], set with a builder.
+pub struct ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderCancellationRequestRequest<'a> {
+            inner: crate::shipping_and_fulfillment::ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderCancellationRequestRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a> ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderCancellationRequestRequest<'a> {
+    /// Sets the `message` query parameter.
+    pub fn message(mut self, message: &'a str) -> Self {
+        self.inner = self.inner.message(message);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::create_fulfillment_orders_param_order_cancellation_request_accept`], set with a builder.
+pub struct ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderCancellationRequestAcceptRequest<'a> {
+            inner: crate::shipping_and_fulfillment::ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderCancellationRequestAcceptRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a>
+    ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderCancellationRequestAcceptRequest<'a>
+{
+    /// Sets the `message` query parameter.
+    pub fn message(mut self, message: &'a str) -> Self {
+        self.inner = self.inner.message(message);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::create_fulfillment_orders_param_order_cancellation_request_reject`], set with a builder.
+pub struct ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderCancellationRequestRejectRequest<'a> {
+            inner: crate::shipping_and_fulfillment::ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderCancellationRequestRejectRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a>
+    ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderCancellationRequestRejectRequest<'a>
+{
+    /// Sets the `message` query parameter.
+    pub fn message(mut self, message: &'a str) -> Self {
+        self.inner = self.inner.message(message);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_202101_create_fulfillment_orders_param_order_cancellation_request`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderCancellationRequestRequest<'a> {
+            inner: crate::shipping_and_fulfillment::ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderCancellationRequestRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a> ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderCancellationRequestRequest<'a> {
+/// Sets the `message` query parameter.
+            pub fn message(mut self, message: &'a str) -> Self {
+                self.inner = self.inner.message(message);
+                self
+            }
+
+            /// Sends the request, with the parameters that were set.
+        pub fn send(self) -> ClientResult<crate::Response<()>> {
+            self.runtime.block_on(self.inner.send())
+        }
+        }
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_202101_create_fulfillment_orders_param_order_cancellation_request_accept`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderCancellationRequestAcceptRequest<'a> {
+            inner: crate::shipping_and_fulfillment::ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderCancellationRequestAcceptRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a> ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderCancellationRequestAcceptRequest<'a> {
+/// Sets the `message` query parameter.
+            pub fn message(mut self, message: &'a str) -> Self {
+                self.inner = self.inner.message(message);
+                self
+            }
+
+            /// Sends the request, with the parameters that were set.
+        pub fn send(self) -> ClientResult<crate::Response<()>> {
+            self.runtime.block_on(self.inner.send())
+        }
+        }
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_202101_create_fulfillment_orders_param_order_cancellation_request_reject`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderCancellationRequestRejectRequest<'a> {
+            inner: crate::shipping_and_fulfillment::ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderCancellationRequestRejectRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a> ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderCancellationRequestRejectRequest<'a> {
+/// Sets the `message` query parameter.
+            pub fn message(mut self, message: &'a str) -> Self {
+                self.inner = self.inner.message(message);
+                self
+            }
+
+            /// Sends the request, with the parameters that were set.
+        pub fn send(self) -> ClientResult<crate::Response<()>> {
+            self.runtime.block_on(self.inner.send())
+        }
+        }
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_unstable_create_fulfillment_orders_param_order_cancellation_request`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderCancellationRequestRequest<'a> {
+            inner: crate::shipping_and_fulfillment::ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderCancellationRequestRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a> ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderCancellationRequestRequest<'a> {
+/// Sets the `message` query parameter.
+            pub fn message(mut self, message: &'a str) -> Self {
+                self.inner = self.inner.message(message);
+                self
+            }
+
+            /// Sends the request, with the parameters that were set.
+        pub fn send(self) -> ClientResult<crate::Response<()>> {
+            self.runtime.block_on(self.inner.send())
+        }
+        }
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_unstable_create_fulfillment_orders_param_order_cancellation_request_accept`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderCancellationRequestAcceptRequest<'a> {
+            inner: crate::shipping_and_fulfillment::ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderCancellationRequestAcceptRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a> ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderCancellationRequestAcceptRequest<'a> {
+/// Sets the `message` query parameter.
+            pub fn message(mut self, message: &'a str) -> Self {
+                self.inner = self.inner.message(message);
+                self
+            }
+
+            /// Sends the request, with the parameters that were set.
+        pub fn send(self) -> ClientResult<crate::Response<()>> {
+            self.runtime.block_on(self.inner.send())
+        }
+        }
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_unstable_create_fulfillment_orders_param_order_cancellation_request_reject`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderCancellationRequestRejectRequest<'a> {
+            inner: crate::shipping_and_fulfillment::ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderCancellationRequestRejectRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a> ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderCancellationRequestRejectRequest<'a> {
+/// Sets the `message` query parameter.
+            pub fn message(mut self, message: &'a str) -> Self {
+                self.inner = self.inner.message(message);
+                self
+            }
+
+            /// Sends the request, with the parameters that were set.
+        pub fn send(self) -> ClientResult<crate::Response<()>> {
+            self.runtime.block_on(self.inner.send())
+        }
+        }
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_202001_get_orders_param_order_fulfillment`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecated202001GetOrdersParamOrderFulfillmentRequest<'a> {
+            inner: crate::shipping_and_fulfillment::ShippingAndFulfillmentDeprecated202001GetOrdersParamOrderFulfillmentRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a> ShippingAndFulfillmentDeprecated202001GetOrdersParamOrderFulfillmentRequest<'a> {
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.inner = self.inner.created_at_max(created_at_max);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.inner = self.inner.created_at_min(created_at_min);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.inner = self.inner.updated_at_max(updated_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.inner = self.inner.updated_at_min(updated_at_min);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_202001_get_orders_param_order_fulfillments_count`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecated202001GetOrdersParamOrderFulfillmentsCountRequest<'a> {
+            inner: crate::shipping_and_fulfillment::ShippingAndFulfillmentDeprecated202001GetOrdersParamOrderFulfillmentsCountRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a> ShippingAndFulfillmentDeprecated202001GetOrdersParamOrderFulfillmentsCountRequest<'a> {
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.inner = self.inner.created_at_min(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.inner = self.inner.created_at_max(created_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.inner = self.inner.updated_at_min(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.inner = self.inner.updated_at_max(updated_at_max);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_202001_get_orders_param_order_fulfillments_fulfillment`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecated202001GetOrdersParamOrderFulfillmentsFulfillmentRequest<'a> {
+            inner: crate::shipping_and_fulfillment::ShippingAndFulfillmentDeprecated202001GetOrdersParamOrderFulfillmentsFulfillmentRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a>
+    ShippingAndFulfillmentDeprecated202001GetOrdersParamOrderFulfillmentsFulfillmentRequest<'a>
+{
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_202004_get_orders_param_order_fulfillment`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecated202004GetOrdersParamOrderFulfillmentRequest<'a> {
+            inner: crate::shipping_and_fulfillment::ShippingAndFulfillmentDeprecated202004GetOrdersParamOrderFulfillmentRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a> ShippingAndFulfillmentDeprecated202004GetOrdersParamOrderFulfillmentRequest<'a> {
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.inner = self.inner.created_at_max(created_at_max);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.inner = self.inner.created_at_min(created_at_min);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.inner = self.inner.updated_at_max(updated_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.inner = self.inner.updated_at_min(updated_at_min);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_202004_get_orders_param_order_fulfillments_count`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecated202004GetOrdersParamOrderFulfillmentsCountRequest<'a> {
+            inner: crate::shipping_and_fulfillment::ShippingAndFulfillmentDeprecated202004GetOrdersParamOrderFulfillmentsCountRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a> ShippingAndFulfillmentDeprecated202004GetOrdersParamOrderFulfillmentsCountRequest<'a> {
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.inner = self.inner.created_at_min(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.inner = self.inner.created_at_max(created_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.inner = self.inner.updated_at_min(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.inner = self.inner.updated_at_max(updated_at_max);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_202004_get_orders_param_order_fulfillments_fulfillment`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecated202004GetOrdersParamOrderFulfillmentsFulfillmentRequest<'a> {
+            inner: crate::shipping_and_fulfillment::ShippingAndFulfillmentDeprecated202004GetOrdersParamOrderFulfillmentsFulfillmentRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a>
+    ShippingAndFulfillmentDeprecated202004GetOrdersParamOrderFulfillmentsFulfillmentRequest<'a>
+{
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_202007_get_orders_param_order_fulfillment`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecated202007GetOrdersParamOrderFulfillmentRequest<'a> {
+            inner: crate::shipping_and_fulfillment::ShippingAndFulfillmentDeprecated202007GetOrdersParamOrderFulfillmentRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a> ShippingAndFulfillmentDeprecated202007GetOrdersParamOrderFulfillmentRequest<'a> {
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.inner = self.inner.created_at_max(created_at_max);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.inner = self.inner.created_at_min(created_at_min);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.inner = self.inner.updated_at_max(updated_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.inner = self.inner.updated_at_min(updated_at_min);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_202007_get_orders_param_order_fulfillments_count`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecated202007GetOrdersParamOrderFulfillmentsCountRequest<'a> {
+            inner: crate::shipping_and_fulfillment::ShippingAndFulfillmentDeprecated202007GetOrdersParamOrderFulfillmentsCountRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a> ShippingAndFulfillmentDeprecated202007GetOrdersParamOrderFulfillmentsCountRequest<'a> {
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.inner = self.inner.created_at_min(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.inner = self.inner.created_at_max(created_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.inner = self.inner.updated_at_min(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.inner = self.inner.updated_at_max(updated_at_max);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_202007_get_orders_param_order_fulfillments_fulfillment`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecated202007GetOrdersParamOrderFulfillmentsFulfillmentRequest<'a> {
+            inner: crate::shipping_and_fulfillment::ShippingAndFulfillmentDeprecated202007GetOrdersParamOrderFulfillmentsFulfillmentRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a>
+    ShippingAndFulfillmentDeprecated202007GetOrdersParamOrderFulfillmentsFulfillmentRequest<'a>
+{
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::get_orders_param_order_fulfillment`], set with a builder.
+pub struct ShippingAndFulfillmentGetOrdersParamOrderFulfillmentRequest<'a> {
+            inner: crate::shipping_and_fulfillment::ShippingAndFulfillmentGetOrdersParamOrderFulfillmentRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a> ShippingAndFulfillmentGetOrdersParamOrderFulfillmentRequest<'a> {
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.inner = self.inner.created_at_max(created_at_max);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.inner = self.inner.created_at_min(created_at_min);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.inner = self.inner.updated_at_max(updated_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.inner = self.inner.updated_at_min(updated_at_min);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::get_orders_param_order_fulfillments_count`], set with a builder.
+pub struct ShippingAndFulfillmentGetOrdersParamOrderFulfillmentsCountRequest<'a> {
+            inner: crate::shipping_and_fulfillment::ShippingAndFulfillmentGetOrdersParamOrderFulfillmentsCountRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a> ShippingAndFulfillmentGetOrdersParamOrderFulfillmentsCountRequest<'a> {
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.inner = self.inner.created_at_min(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.inner = self.inner.created_at_max(created_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.inner = self.inner.updated_at_min(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.inner = self.inner.updated_at_max(updated_at_max);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::get_orders_param_order_fulfillments_fulfillment`], set with a builder.
+pub struct ShippingAndFulfillmentGetOrdersParamOrderFulfillmentsFulfillmentRequest<'a> {
+            inner: crate::shipping_and_fulfillment::ShippingAndFulfillmentGetOrdersParamOrderFulfillmentsFulfillmentRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a> ShippingAndFulfillmentGetOrdersParamOrderFulfillmentsFulfillmentRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_202101_get_orders_param_order_fulfillment`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecated202101GetOrdersParamOrderFulfillmentRequest<'a> {
+            inner: crate::shipping_and_fulfillment::ShippingAndFulfillmentDeprecated202101GetOrdersParamOrderFulfillmentRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a> ShippingAndFulfillmentDeprecated202101GetOrdersParamOrderFulfillmentRequest<'a> {
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.inner = self.inner.created_at_max(created_at_max);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.inner = self.inner.created_at_min(created_at_min);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.inner = self.inner.updated_at_max(updated_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.inner = self.inner.updated_at_min(updated_at_min);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_202101_get_orders_param_order_fulfillments_count`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecated202101GetOrdersParamOrderFulfillmentsCountRequest<'a> {
+            inner: crate::shipping_and_fulfillment::ShippingAndFulfillmentDeprecated202101GetOrdersParamOrderFulfillmentsCountRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a> ShippingAndFulfillmentDeprecated202101GetOrdersParamOrderFulfillmentsCountRequest<'a> {
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.inner = self.inner.created_at_min(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.inner = self.inner.created_at_max(created_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.inner = self.inner.updated_at_min(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.inner = self.inner.updated_at_max(updated_at_max);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_202101_get_orders_param_order_fulfillments_fulfillment`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecated202101GetOrdersParamOrderFulfillmentsFulfillmentRequest<'a> {
+            inner: crate::shipping_and_fulfillment::ShippingAndFulfillmentDeprecated202101GetOrdersParamOrderFulfillmentsFulfillmentRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a>
+    ShippingAndFulfillmentDeprecated202101GetOrdersParamOrderFulfillmentsFulfillmentRequest<'a>
+{
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_unstable_get_orders_param_order_fulfillment`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecatedUnstableGetOrdersParamOrderFulfillmentRequest<'a> {
+            inner: crate::shipping_and_fulfillment::ShippingAndFulfillmentDeprecatedUnstableGetOrdersParamOrderFulfillmentRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a> ShippingAndFulfillmentDeprecatedUnstableGetOrdersParamOrderFulfillmentRequest<'a> {
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.inner = self.inner.created_at_max(created_at_max);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.inner = self.inner.created_at_min(created_at_min);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.inner = self.inner.updated_at_max(updated_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.inner = self.inner.updated_at_min(updated_at_min);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_unstable_get_orders_param_order_fulfillments_count`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecatedUnstableGetOrdersParamOrderFulfillmentsCountRequest<'a> {
+            inner: crate::shipping_and_fulfillment::ShippingAndFulfillmentDeprecatedUnstableGetOrdersParamOrderFulfillmentsCountRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a> ShippingAndFulfillmentDeprecatedUnstableGetOrdersParamOrderFulfillmentsCountRequest<'a> {
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.inner = self.inner.created_at_min(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.inner = self.inner.created_at_max(created_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.inner = self.inner.updated_at_min(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.inner = self.inner.updated_at_max(updated_at_max);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_unstable_get_orders_param_order_fulfillments_fulfillment`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecatedUnstableGetOrdersParamOrderFulfillmentsFulfillmentRequest<'a> {
+            inner: crate::shipping_and_fulfillment::ShippingAndFulfillmentDeprecatedUnstableGetOrdersParamOrderFulfillmentsFulfillmentRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a>
+    ShippingAndFulfillmentDeprecatedUnstableGetOrdersParamOrderFulfillmentsFulfillmentRequest<'a>
+{
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_202001_create_fulfillment_orders_param_order_close`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderCloseRequest<'a> {
+            inner: crate::shipping_and_fulfillment::ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderCloseRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a> ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderCloseRequest<'a> {
+    /// Sets the `message` query parameter.
+    pub fn message(mut self, message: &'a str) -> Self {
+        self.inner = self.inner.message(message);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_202001_create_fulfillment_orders_param_order_move`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderMoveRequest<'a> {
+            inner: crate::shipping_and_fulfillment::ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderMoveRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a> ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderMoveRequest<'a> {
+    /// Sets the `new_location_id` query parameter.
+    pub fn new_location_id(mut self, new_location_id: &'a str) -> Self {
+        self.inner = self.inner.new_location_id(new_location_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_202004_create_fulfillment_orders_param_order_close`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderCloseRequest<'a> {
+            inner: crate::shipping_and_fulfillment::ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderCloseRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a> ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderCloseRequest<'a> {
+    /// Sets the `message` query parameter.
+    pub fn message(mut self, message: &'a str) -> Self {
+        self.inner = self.inner.message(message);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_202004_create_fulfillment_orders_param_order_move`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderMoveRequest<'a> {
+            inner: crate::shipping_and_fulfillment::ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderMoveRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a> ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderMoveRequest<'a> {
+    /// Sets the `new_location_id` query parameter.
+    pub fn new_location_id(mut self, new_location_id: &'a str) -> Self {
+        self.inner = self.inner.new_location_id(new_location_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_202007_create_fulfillment_orders_param_order_close`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderCloseRequest<'a> {
+            inner: crate::shipping_and_fulfillment::ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderCloseRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a> ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderCloseRequest<'a> {
+    /// Sets the `message` query parameter.
+    pub fn message(mut self, message: &'a str) -> Self {
+        self.inner = self.inner.message(message);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_202007_create_fulfillment_orders_param_order_move`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderMoveRequest<'a> {
+            inner: crate::shipping_and_fulfillment::ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderMoveRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a> ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderMoveRequest<'a> {
+    /// Sets the `new_location_id` query parameter.
+    pub fn new_location_id(mut self, new_location_id: &'a str) -> Self {
+        self.inner = self.inner.new_location_id(new_location_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::create_fulfillment_orders_param_order_close`], set with a builder.
+pub struct ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderCloseRequest<'a> {
+            inner: crate::shipping_and_fulfillment::ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderCloseRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a> ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderCloseRequest<'a> {
+    /// Sets the `message` query parameter.
+    pub fn message(mut self, message: &'a str) -> Self {
+        self.inner = self.inner.message(message);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::create_fulfillment_orders_param_order_move`], set with a builder.
+pub struct ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderMoveRequest<'a> {
+            inner: crate::shipping_and_fulfillment::ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderMoveRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a> ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderMoveRequest<'a> {
+    /// Sets the `new_location_id` query parameter.
+    pub fn new_location_id(mut self, new_location_id: &'a str) -> Self {
+        self.inner = self.inner.new_location_id(new_location_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_202101_create_fulfillment_orders_param_order_close`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderCloseRequest<'a> {
+            inner: crate::shipping_and_fulfillment::ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderCloseRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a> ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderCloseRequest<'a> {
+    /// Sets the `message` query parameter.
+    pub fn message(mut self, message: &'a str) -> Self {
+        self.inner = self.inner.message(message);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_202101_create_fulfillment_orders_param_order_move`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderMoveRequest<'a> {
+            inner: crate::shipping_and_fulfillment::ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderMoveRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a> ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderMoveRequest<'a> {
+    /// Sets the `new_location_id` query parameter.
+    pub fn new_location_id(mut self, new_location_id: &'a str) -> Self {
+        self.inner = self.inner.new_location_id(new_location_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_unstable_create_fulfillment_orders_release_hold`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersReleaseHoldRequest<'a> {
+            inner: crate::shipping_and_fulfillment::ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersReleaseHoldRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a> ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersReleaseHoldRequest<'a> {
+    /// Sets the `order_id` query parameter.
+    pub fn order_id(mut self, order_id: &'a str) -> Self {
+        self.inner = self.inner.order_id(order_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_unstable_create_fulfillment_orders_param_order_close`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderCloseRequest<'a> {
+            inner: crate::shipping_and_fulfillment::ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderCloseRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a> ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderCloseRequest<'a> {
+    /// Sets the `message` query parameter.
+    pub fn message(mut self, message: &'a str) -> Self {
+        self.inner = self.inner.message(message);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_unstable_create_fulfillment_orders_param_order_move`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderMoveRequest<'a> {
+            inner: crate::shipping_and_fulfillment::ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderMoveRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a> ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderMoveRequest<'a> {
+    /// Sets the `new_location_id` query parameter.
+    pub fn new_location_id(mut self, new_location_id: &'a str) -> Self {
+        self.inner = self.inner.new_location_id(new_location_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_202001_create_fulfillment_orders_param_order_request`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderRequestRequest<'a> {
+            inner: crate::shipping_and_fulfillment::ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderRequestRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a> ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderRequestRequest<'a> {
+    /// Sets the `message` query parameter.
+    pub fn message(mut self, message: &'a str) -> Self {
+        self.inner = self.inner.message(message);
+        self
+    }
+
+    /// Sets the `fulfillment_order_line_items` query parameter.
+    pub fn fulfillment_order_line_items(mut self, fulfillment_order_line_items: &'a str) -> Self {
+        self.inner = self
+            .inner
+            .fulfillment_order_line_items(fulfillment_order_line_items);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_202001_create_fulfillment_orders_param_order_request_accept`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderRequestAcceptRequest<'a> {
+            inner: crate::shipping_and_fulfillment::ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderRequestAcceptRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a>
+    ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderRequestAcceptRequest<'a>
+{
+    /// Sets the `message` query parameter.
+    pub fn message(mut self, message: &'a str) -> Self {
+        self.inner = self.inner.message(message);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_202001_create_fulfillment_orders_param_order_request_reject`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderRequestRejectRequest<'a> {
+            inner: crate::shipping_and_fulfillment::ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderRequestRejectRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a>
+    ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderRequestRejectRequest<'a>
+{
+    /// Sets the `message` query parameter.
+    pub fn message(mut self, message: &'a str) -> Self {
+        self.inner = self.inner.message(message);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_202004_create_fulfillment_orders_param_order_request`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderRequestRequest<'a> {
+            inner: crate::shipping_and_fulfillment::ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderRequestRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a> ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderRequestRequest<'a> {
+    /// Sets the `message` query parameter.
+    pub fn message(mut self, message: &'a str) -> Self {
+        self.inner = self.inner.message(message);
+        self
+    }
+
+    /// Sets the `fulfillment_order_line_items` query parameter.
+    pub fn fulfillment_order_line_items(mut self, fulfillment_order_line_items: &'a str) -> Self {
+        self.inner = self
+            .inner
+            .fulfillment_order_line_items(fulfillment_order_line_items);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_202004_create_fulfillment_orders_param_order_request_accept`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderRequestAcceptRequest<'a> {
+            inner: crate::shipping_and_fulfillment::ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderRequestAcceptRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a>
+    ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderRequestAcceptRequest<'a>
+{
+    /// Sets the `message` query parameter.
+    pub fn message(mut self, message: &'a str) -> Self {
+        self.inner = self.inner.message(message);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_202004_create_fulfillment_orders_param_order_request_reject`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderRequestRejectRequest<'a> {
+            inner: crate::shipping_and_fulfillment::ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderRequestRejectRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a>
+    ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderRequestRejectRequest<'a>
+{
+    /// Sets the `message` query parameter.
+    pub fn message(mut self, message: &'a str) -> Self {
+        self.inner = self.inner.message(message);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_202007_create_fulfillment_orders_param_order_request`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderRequestRequest<'a> {
+            inner: crate::shipping_and_fulfillment::ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderRequestRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a> ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderRequestRequest<'a> {
+    /// Sets the `message` query parameter.
+    pub fn message(mut self, message: &'a str) -> Self {
+        self.inner = self.inner.message(message);
+        self
+    }
+
+    /// Sets the `fulfillment_order_line_items` query parameter.
+    pub fn fulfillment_order_line_items(mut self, fulfillment_order_line_items: &'a str) -> Self {
+        self.inner = self
+            .inner
+            .fulfillment_order_line_items(fulfillment_order_line_items);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_202007_create_fulfillment_orders_param_order_request_accept`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderRequestAcceptRequest<'a> {
+            inner: crate::shipping_and_fulfillment::ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderRequestAcceptRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a>
+    ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderRequestAcceptRequest<'a>
+{
+    /// Sets the `message` query parameter.
+    pub fn message(mut self, message: &'a str) -> Self {
+        self.inner = self.inner.message(message);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_202007_create_fulfillment_orders_param_order_request_reject`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderRequestRejectRequest<'a> {
+            inner: crate::shipping_and_fulfillment::ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderRequestRejectRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a>
+    ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderRequestRejectRequest<'a>
+{
+    /// Sets the `message` query parameter.
+    pub fn message(mut self, message: &'a str) -> Self {
+        self.inner = self.inner.message(message);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::create_fulfillment_orders_param_order_request`], set with a builder.
+pub struct ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderRequestRequest<'a> {
+            inner: crate::shipping_and_fulfillment::ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderRequestRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a> ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderRequestRequest<'a> {
+    /// Sets the `message` query parameter.
+    pub fn message(mut self, message: &'a str) -> Self {
+        self.inner = self.inner.message(message);
+        self
+    }
+
+    /// Sets the `fulfillment_order_line_items` query parameter.
+    pub fn fulfillment_order_line_items(mut self, fulfillment_order_line_items: &'a str) -> Self {
+        self.inner = self
+            .inner
+            .fulfillment_order_line_items(fulfillment_order_line_items);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::create_fulfillment_orders_param_order_request_accept`], set with a builder.
+pub struct ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderRequestAcceptRequest<'a> {
+            inner: crate::shipping_and_fulfillment::ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderRequestAcceptRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a> ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderRequestAcceptRequest<'a> {
+    /// Sets the `message` query parameter.
+    pub fn message(mut self, message: &'a str) -> Self {
+        self.inner = self.inner.message(message);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::create_fulfillment_orders_param_order_request_reject`], set with a builder.
+pub struct ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderRequestRejectRequest<'a> {
+            inner: crate::shipping_and_fulfillment::ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderRequestRejectRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a> ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderRequestRejectRequest<'a> {
+    /// Sets the `message` query parameter.
+    pub fn message(mut self, message: &'a str) -> Self {
+        self.inner = self.inner.message(message);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_202101_create_fulfillment_orders_param_order_request`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderRequestRequest<'a> {
+            inner: crate::shipping_and_fulfillment::ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderRequestRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a> ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderRequestRequest<'a> {
+    /// Sets the `message` query parameter.
+    pub fn message(mut self, message: &'a str) -> Self {
+        self.inner = self.inner.message(message);
+        self
+    }
+
+    /// Sets the `fulfillment_order_line_items` query parameter.
+    pub fn fulfillment_order_line_items(mut self, fulfillment_order_line_items: &'a str) -> Self {
+        self.inner = self
+            .inner
+            .fulfillment_order_line_items(fulfillment_order_line_items);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_202101_create_fulfillment_orders_param_order_request_accept`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderRequestAcceptRequest<'a> {
+            inner: crate::shipping_and_fulfillment::ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderRequestAcceptRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a>
+    ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderRequestAcceptRequest<'a>
+{
+    /// Sets the `message` query parameter.
+    pub fn message(mut self, message: &'a str) -> Self {
+        self.inner = self.inner.message(message);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_202101_create_fulfillment_orders_param_order_request_reject`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderRequestRejectRequest<'a> {
+            inner: crate::shipping_and_fulfillment::ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderRequestRejectRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a>
+    ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderRequestRejectRequest<'a>
+{
+    /// Sets the `message` query parameter.
+    pub fn message(mut self, message: &'a str) -> Self {
+        self.inner = self.inner.message(message);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_unstable_create_fulfillment_orders_param_order_request`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderRequestRequest<'a> {
+            inner: crate::shipping_and_fulfillment::ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderRequestRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a>
+    ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderRequestRequest<'a>
+{
+    /// Sets the `message` query parameter.
+    pub fn message(mut self, message: &'a str) -> Self {
+        self.inner = self.inner.message(message);
+        self
+    }
+
+    /// Sets the `fulfillment_order_line_items` query parameter.
+    pub fn fulfillment_order_line_items(mut self, fulfillment_order_line_items: &'a str) -> Self {
+        self.inner = self
+            .inner
+            .fulfillment_order_line_items(fulfillment_order_line_items);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_unstable_create_fulfillment_orders_param_order_request_accept`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderRequestAcceptRequest<'a> {
+            inner: crate::shipping_and_fulfillment::ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderRequestAcceptRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a>
+    ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderRequestAcceptRequest<
+        'a,
+    >
+{
+    /// Sets the `message` query parameter.
+    pub fn message(mut self, message: &'a str) -> Self {
+        self.inner = self.inner.message(message);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_unstable_create_fulfillment_orders_param_order_request_reject`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderRequestRejectRequest<'a> {
+            inner: crate::shipping_and_fulfillment::ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderRequestRejectRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a>
+    ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderRequestRejectRequest<
+        'a,
+    >
+{
+    /// Sets the `message` query parameter.
+    pub fn message(mut self, message: &'a str) -> Self {
+        self.inner = self.inner.message(message);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_unknown_version_get_fetch_tracking_number`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecatedUnknownVersionGetFetchTrackingNumberRequest<'a> {
+            inner: crate::shipping_and_fulfillment::ShippingAndFulfillmentDeprecatedUnknownVersionGetFetchTrackingNumberRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a> ShippingAndFulfillmentDeprecatedUnknownVersionGetFetchTrackingNumberRequest<'a> {
+    /// Sets the `order_names` query parameter.
+    pub fn order_names(mut self, order_names: &'a str) -> Self {
+        self.inner = self.inner.order_names(order_names);
+        self
+    }
+
+    /// Sets the `shop` query parameter.
+    pub fn shop(mut self, shop: &'a str) -> Self {
+        self.inner = self.inner.shop(shop);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_unknown_version_get_fetch_stock`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecatedUnknownVersionGetFetchStockRequest<'a> {
+            inner: crate::shipping_and_fulfillment::ShippingAndFulfillmentDeprecatedUnknownVersionGetFetchStockRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a> ShippingAndFulfillmentDeprecatedUnknownVersionGetFetchStockRequest<'a> {
+    /// Sets the `max_retries` query parameter.
+    pub fn max_retries(mut self, max_retries: &'a str) -> Self {
+        self.inner = self.inner.max_retries(max_retries);
+        self
+    }
+
+    /// Sets the `shop` query parameter.
+    pub fn shop(mut self, shop: &'a str) -> Self {
+        self.inner = self.inner.shop(shop);
+        self
+    }
+
+    /// Sets the `sku` query parameter.
+    pub fn sku(mut self, sku: &'a str) -> Self {
+        self.inner = self.inner.sku(sku);
+        self
+    }
+
+    /// Sets the `timestamp` query parameter.
+    pub fn timestamp(mut self, timestamp: &'a str) -> Self {
+        self.inner = self.inner.timestamp(timestamp);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_202001_get_fulfillment_service`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecated202001GetFulfillmentServiceRequest<'a> {
+            inner: crate::shipping_and_fulfillment::ShippingAndFulfillmentDeprecated202001GetFulfillmentServiceRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a> ShippingAndFulfillmentDeprecated202001GetFulfillmentServiceRequest<'a> {
+    /// Sets the `scope` query parameter.
+    pub fn scope(mut self, scope: &'a str) -> Self {
+        self.inner = self.inner.scope(scope);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_202004_get_fulfillment_service`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecated202004GetFulfillmentServiceRequest<'a> {
+            inner: crate::shipping_and_fulfillment::ShippingAndFulfillmentDeprecated202004GetFulfillmentServiceRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a> ShippingAndFulfillmentDeprecated202004GetFulfillmentServiceRequest<'a> {
+    /// Sets the `scope` query parameter.
+    pub fn scope(mut self, scope: &'a str) -> Self {
+        self.inner = self.inner.scope(scope);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_202007_get_fulfillment_service`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecated202007GetFulfillmentServiceRequest<'a> {
+            inner: crate::shipping_and_fulfillment::ShippingAndFulfillmentDeprecated202007GetFulfillmentServiceRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a> ShippingAndFulfillmentDeprecated202007GetFulfillmentServiceRequest<'a> {
+    /// Sets the `scope` query parameter.
+    pub fn scope(mut self, scope: &'a str) -> Self {
+        self.inner = self.inner.scope(scope);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::get_fulfillment_service`], set with a builder.
+pub struct ShippingAndFulfillmentGetFulfillmentServiceRequest<'a> {
+    inner: crate::shipping_and_fulfillment::ShippingAndFulfillmentGetFulfillmentServiceRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ShippingAndFulfillmentGetFulfillmentServiceRequest<'a> {
+    /// Sets the `scope` query parameter.
+    pub fn scope(mut self, scope: &'a str) -> Self {
+        self.inner = self.inner.scope(scope);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_202101_get_fulfillment_service`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecated202101GetFulfillmentServiceRequest<'a> {
+            inner: crate::shipping_and_fulfillment::ShippingAndFulfillmentDeprecated202101GetFulfillmentServiceRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a> ShippingAndFulfillmentDeprecated202101GetFulfillmentServiceRequest<'a> {
+    /// Sets the `scope` query parameter.
+    pub fn scope(mut self, scope: &'a str) -> Self {
+        self.inner = self.inner.scope(scope);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_unstable_get_fulfillment_service`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecatedUnstableGetFulfillmentServiceRequest<'a> {
+            inner: crate::shipping_and_fulfillment::ShippingAndFulfillmentDeprecatedUnstableGetFulfillmentServiceRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a> ShippingAndFulfillmentDeprecatedUnstableGetFulfillmentServiceRequest<'a> {
+    /// Sets the `scope` query parameter.
+    pub fn scope(mut self, scope: &'a str) -> Self {
+        self.inner = self.inner.scope(scope);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment`].
+pub struct ShippingAndFulfillment {
+    inner: crate::shipping_and_fulfillment::ShippingAndFulfillment,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl ShippingAndFulfillment {
+    pub(crate) fn new(
+        inner: crate::shipping_and_fulfillment::ShippingAndFulfillment,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        ShippingAndFulfillment { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202001_get_assigned_fulfillment_order`].
+    pub fn deprecated_202001_get_assigned_fulfillment_order(
+        &self,
+        assignment_status: &str,
+        location_ids: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_get_assigned_fulfillment_order(assignment_status, location_ids),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202001_get_assigned_fulfillment_order_request`].
+    pub fn deprecated_202001_get_assigned_fulfillment_order_request(
+        &self,
+    ) -> ShippingAndFulfillmentDeprecated202001GetAssignedFulfillmentOrderRequest<'_> {
+        ShippingAndFulfillmentDeprecated202001GetAssignedFulfillmentOrderRequest {
+            inner: self
+                .inner
+                .deprecated_202001_get_assigned_fulfillment_order_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202004_get_assigned_fulfillment_order`].
+    pub fn deprecated_202004_get_assigned_fulfillment_order(
+        &self,
+        assignment_status: &str,
+        location_ids: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_get_assigned_fulfillment_order(assignment_status, location_ids),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202004_get_assigned_fulfillment_order_request`].
+    pub fn deprecated_202004_get_assigned_fulfillment_order_request(
+        &self,
+    ) -> ShippingAndFulfillmentDeprecated202004GetAssignedFulfillmentOrderRequest<'_> {
+        ShippingAndFulfillmentDeprecated202004GetAssignedFulfillmentOrderRequest {
+            inner: self
+                .inner
+                .deprecated_202004_get_assigned_fulfillment_order_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202007_get_assigned_fulfillment_order`].
+    pub fn deprecated_202007_get_assigned_fulfillment_order(
+        &self,
+        assignment_status: &str,
+        location_ids: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_get_assigned_fulfillment_order(assignment_status, location_ids),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202007_get_assigned_fulfillment_order_request`].
+    pub fn deprecated_202007_get_assigned_fulfillment_order_request(
+        &self,
+    ) -> ShippingAndFulfillmentDeprecated202007GetAssignedFulfillmentOrderRequest<'_> {
+        ShippingAndFulfillmentDeprecated202007GetAssignedFulfillmentOrderRequest {
+            inner: self
+                .inner
+                .deprecated_202007_get_assigned_fulfillment_order_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::get_assigned_fulfillment_order`].
+    pub fn get_assigned_fulfillment_order(
+        &self,
+        assignment_status: &str,
+        location_ids: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .get_assigned_fulfillment_order(assignment_status, location_ids),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::get_assigned_fulfillment_order_request`].
+    pub fn get_assigned_fulfillment_order_request(
+        &self,
+    ) -> ShippingAndFulfillmentGetAssignedFulfillmentOrderRequest<'_> {
+        ShippingAndFulfillmentGetAssignedFulfillmentOrderRequest {
+            inner: self.inner.get_assigned_fulfillment_order_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202101_get_assigned_fulfillment_order`].
+    pub fn deprecated_202101_get_assigned_fulfillment_order(
+        &self,
+        assignment_status: &str,
+        location_ids: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_get_assigned_fulfillment_order(assignment_status, location_ids),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202101_get_assigned_fulfillment_order_request`].
+    pub fn deprecated_202101_get_assigned_fulfillment_order_request(
+        &self,
+    ) -> ShippingAndFulfillmentDeprecated202101GetAssignedFulfillmentOrderRequest<'_> {
+        ShippingAndFulfillmentDeprecated202101GetAssignedFulfillmentOrderRequest {
+            inner: self
+                .inner
+                .deprecated_202101_get_assigned_fulfillment_order_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_unstable_get_assigned_fulfillment_order`].
+    pub fn deprecated_unstable_get_assigned_fulfillment_order(
+        &self,
+        assignment_status: &str,
+        location_ids: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_get_assigned_fulfillment_order(
+                    assignment_status,
+                    location_ids,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_unstable_get_assigned_fulfillment_order_request`].
+    pub fn deprecated_unstable_get_assigned_fulfillment_order_request(
+        &self,
+    ) -> ShippingAndFulfillmentDeprecatedUnstableGetAssignedFulfillmentOrderRequest<'_> {
+        ShippingAndFulfillmentDeprecatedUnstableGetAssignedFulfillmentOrderRequest {
+            inner: self
+                .inner
+                .deprecated_unstable_get_assigned_fulfillment_order_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202001_create_fulfillment_orders_param_order_cancellation_request`].
+    pub fn deprecated_202001_create_fulfillment_orders_param_order_cancellation_request(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_create_fulfillment_orders_param_order_cancellation_request(
+                    fulfillment_order_id,
+                    message,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202001_create_fulfillment_orders_param_order_cancellation_request_request`].
+    pub fn deprecated_202001_create_fulfillment_orders_param_order_cancellation_request_request<'a>(&'a self, fulfillment_order_id: &'a str, body: &'a serde_json::Value,) -> ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderCancellationRequestRequest<'a>{
+        ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderCancellationRequestRequest { inner: self.inner.deprecated_202001_create_fulfillment_orders_param_order_cancellation_request_request(fulfillment_order_id, body), runtime: &self.runtime }
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202001_create_fulfillment_orders_param_order_cancellation_request_accept`].
+    pub fn deprecated_202001_create_fulfillment_orders_param_order_cancellation_request_accept(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.deprecated_202001_create_fulfillment_orders_param_order_cancellation_request_accept(fulfillment_order_id, message, body))
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202001_create_fulfillment_orders_param_order_cancellation_request_accept_request`].
+    pub fn deprecated_202001_create_fulfillment_orders_param_order_cancellation_request_accept_request<'a>(&'a self, fulfillment_order_id: &'a str, body: &'a serde_json::Value,) -> ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderCancellationRequestAcceptRequest<'a>{
+        ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderCancellationRequestAcceptRequest { inner: self.inner.deprecated_202001_create_fulfillment_orders_param_order_cancellation_request_accept_request(fulfillment_order_id, body), runtime: &self.runtime }
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202001_create_fulfillment_orders_param_order_cancellation_request_reject`].
+    pub fn deprecated_202001_create_fulfillment_orders_param_order_cancellation_request_reject(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.deprecated_202001_create_fulfillment_orders_param_order_cancellation_request_reject(fulfillment_order_id, message, body))
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202001_create_fulfillment_orders_param_order_cancellation_request_reject_request`].
+    pub fn deprecated_202001_create_fulfillment_orders_param_order_cancellation_request_reject_request<'a>(&'a self, fulfillment_order_id: &'a str, body: &'a serde_json::Value,) -> ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderCancellationRequestRejectRequest<'a>{
+        ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderCancellationRequestRejectRequest { inner: self.inner.deprecated_202001_create_fulfillment_orders_param_order_cancellation_request_reject_request(fulfillment_order_id, body), runtime: &self.runtime }
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202004_create_fulfillment_orders_param_order_cancellation_request`].
+    pub fn deprecated_202004_create_fulfillment_orders_param_order_cancellation_request(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_create_fulfillment_orders_param_order_cancellation_request(
+                    fulfillment_order_id,
+                    message,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202004_create_fulfillment_orders_param_order_cancellation_request_request`].
+    pub fn deprecated_202004_create_fulfillment_orders_param_order_cancellation_request_request<'a>(&'a self, fulfillment_order_id: &'a str, body: &'a serde_json::Value,) -> ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderCancellationRequestRequest<'a>{
+        ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderCancellationRequestRequest { inner: self.inner.deprecated_202004_create_fulfillment_orders_param_order_cancellation_request_request(fulfillment_order_id, body), runtime: &self.runtime }
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202004_create_fulfillment_orders_param_order_cancellation_request_accept`].
+    pub fn deprecated_202004_create_fulfillment_orders_param_order_cancellation_request_accept(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.deprecated_202004_create_fulfillment_orders_param_order_cancellation_request_accept(fulfillment_order_id, message, body))
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202004_create_fulfillment_orders_param_order_cancellation_request_accept_request`].
+    pub fn deprecated_202004_create_fulfillment_orders_param_order_cancellation_request_accept_request<'a>(&'a self, fulfillment_order_id: &'a str, body: &'a serde_json::Value,) -> ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderCancellationRequestAcceptRequest<'a>{
+        ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderCancellationRequestAcceptRequest { inner: self.inner.deprecated_202004_create_fulfillment_orders_param_order_cancellation_request_accept_request(fulfillment_order_id, body), runtime: &self.runtime }
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202004_create_fulfillment_orders_param_order_cancellation_request_reject`].
+    pub fn deprecated_202004_create_fulfillment_orders_param_order_cancellation_request_reject(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.deprecated_202004_create_fulfillment_orders_param_order_cancellation_request_reject(fulfillment_order_id, message, body))
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202004_create_fulfillment_orders_param_order_cancellation_request_reject_request`].
+    pub fn deprecated_202004_create_fulfillment_orders_param_order_cancellation_request_reject_request<'a>(&'a self, fulfillment_order_id: &'a str, body: &'a serde_json::Value,) -> ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderCancellationRequestRejectRequest<'a>{
+        ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderCancellationRequestRejectRequest { inner: self.inner.deprecated_202004_create_fulfillment_orders_param_order_cancellation_request_reject_request(fulfillment_order_id, body), runtime: &self.runtime }
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202007_create_fulfillment_orders_param_order_cancellation_request`].
+    pub fn deprecated_202007_create_fulfillment_orders_param_order_cancellation_request(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_create_fulfillment_orders_param_order_cancellation_request(
+                    fulfillment_order_id,
+                    message,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202007_create_fulfillment_orders_param_order_cancellation_request_request`].
+    pub fn deprecated_202007_create_fulfillment_orders_param_order_cancellation_request_request<'a>(&'a self, fulfillment_order_id: &'a str, body: &'a serde_json::Value,) -> ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderCancellationRequestRequest<'a>{
+        ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderCancellationRequestRequest { inner: self.inner.deprecated_202007_create_fulfillment_orders_param_order_cancellation_request_request(fulfillment_order_id, body), runtime: &self.runtime }
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202007_create_fulfillment_orders_param_order_cancellation_request_accept`].
+    pub fn deprecated_202007_create_fulfillment_orders_param_order_cancellation_request_accept(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.deprecated_202007_create_fulfillment_orders_param_order_cancellation_request_accept(fulfillment_order_id, message, body))
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202007_create_fulfillment_orders_param_order_cancellation_request_accept_request`].
+    pub fn deprecated_202007_create_fulfillment_orders_param_order_cancellation_request_accept_request<'a>(&'a self, fulfillment_order_id: &'a str, body: &'a serde_json::Value,) -> ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderCancellationRequestAcceptRequest<'a>{
+        ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderCancellationRequestAcceptRequest { inner: self.inner.deprecated_202007_create_fulfillment_orders_param_order_cancellation_request_accept_request(fulfillment_order_id, body), runtime: &self.runtime }
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202007_create_fulfillment_orders_param_order_cancellation_request_reject`].
+    pub fn deprecated_202007_create_fulfillment_orders_param_order_cancellation_request_reject(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.deprecated_202007_create_fulfillment_orders_param_order_cancellation_request_reject(fulfillment_order_id, message, body))
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202007_create_fulfillment_orders_param_order_cancellation_request_reject_request`].
+    pub fn deprecated_202007_create_fulfillment_orders_param_order_cancellation_request_reject_request<'a>(&'a self, fulfillment_order_id: &'a str, body: &'a serde_json::Value,) -> ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderCancellationRequestRejectRequest<'a>{
+        ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderCancellationRequestRejectRequest { inner: self.inner.deprecated_202007_create_fulfillment_orders_param_order_cancellation_request_reject_request(fulfillment_order_id, body), runtime: &self.runtime }
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::create_fulfillment_orders_param_order_cancellation_request`].
+    pub fn create_fulfillment_orders_param_order_cancellation_request(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .create_fulfillment_orders_param_order_cancellation_request(
+                    fulfillment_order_id,
+                    message,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::create_fulfillment_orders_param_order_cancellation_request_request`].
+    pub fn create_fulfillment_orders_param_order_cancellation_request_request<'a>(
+        &'a self,
+        fulfillment_order_id: &'a str,
+        body: &'a serde_json::Value,
+    ) -> ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderCancellationRequestRequest<'a> {
+        ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderCancellationRequestRequest {
+            inner: self
+                .inner
+                .create_fulfillment_orders_param_order_cancellation_request_request(
+                    fulfillment_order_id,
+                    body,
+                ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::create_fulfillment_orders_param_order_cancellation_request_accept`].
+    pub fn create_fulfillment_orders_param_order_cancellation_request_accept(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .create_fulfillment_orders_param_order_cancellation_request_accept(
+                    fulfillment_order_id,
+                    message,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::create_fulfillment_orders_param_order_cancellation_request_accept_request`].
+    pub fn create_fulfillment_orders_param_order_cancellation_request_accept_request<'a>(
+        &'a self,
+        fulfillment_order_id: &'a str,
+        body: &'a serde_json::Value,
+    ) -> ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderCancellationRequestAcceptRequest<'a>
+    {
+        ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderCancellationRequestAcceptRequest {
+            inner: self
+                .inner
+                .create_fulfillment_orders_param_order_cancellation_request_accept_request(
+                    fulfillment_order_id,
+                    body,
+                ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::create_fulfillment_orders_param_order_cancellation_request_reject`].
+    pub fn create_fulfillment_orders_param_order_cancellation_request_reject(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .create_fulfillment_orders_param_order_cancellation_request_reject(
+                    fulfillment_order_id,
+                    message,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::create_fulfillment_orders_param_order_cancellation_request_reject_request`].
+    pub fn create_fulfillment_orders_param_order_cancellation_request_reject_request<'a>(
+        &'a self,
+        fulfillment_order_id: &'a str,
+        body: &'a serde_json::Value,
+    ) -> ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderCancellationRequestRejectRequest<'a>
+    {
+        ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderCancellationRequestRejectRequest {
+            inner: self
+                .inner
+                .create_fulfillment_orders_param_order_cancellation_request_reject_request(
+                    fulfillment_order_id,
+                    body,
+                ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202101_create_fulfillment_orders_param_order_cancellation_request`].
+    pub fn deprecated_202101_create_fulfillment_orders_param_order_cancellation_request(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_create_fulfillment_orders_param_order_cancellation_request(
+                    fulfillment_order_id,
+                    message,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202101_create_fulfillment_orders_param_order_cancellation_request_request`].
+    pub fn deprecated_202101_create_fulfillment_orders_param_order_cancellation_request_request<'a>(&'a self, fulfillment_order_id: &'a str, body: &'a serde_json::Value,) -> ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderCancellationRequestRequest<'a>{
+        ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderCancellationRequestRequest { inner: self.inner.deprecated_202101_create_fulfillment_orders_param_order_cancellation_request_request(fulfillment_order_id, body), runtime: &self.runtime }
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202101_create_fulfillment_orders_param_order_cancellation_request_accept`].
+    pub fn deprecated_202101_create_fulfillment_orders_param_order_cancellation_request_accept(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.deprecated_202101_create_fulfillment_orders_param_order_cancellation_request_accept(fulfillment_order_id, message, body))
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202101_create_fulfillment_orders_param_order_cancellation_request_accept_request`].
+    pub fn deprecated_202101_create_fulfillment_orders_param_order_cancellation_request_accept_request<'a>(&'a self, fulfillment_order_id: &'a str, body: &'a serde_json::Value,) -> ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderCancellationRequestAcceptRequest<'a>{
+        ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderCancellationRequestAcceptRequest { inner: self.inner.deprecated_202101_create_fulfillment_orders_param_order_cancellation_request_accept_request(fulfillment_order_id, body), runtime: &self.runtime }
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202101_create_fulfillment_orders_param_order_cancellation_request_reject`].
+    pub fn deprecated_202101_create_fulfillment_orders_param_order_cancellation_request_reject(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.deprecated_202101_create_fulfillment_orders_param_order_cancellation_request_reject(fulfillment_order_id, message, body))
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202101_create_fulfillment_orders_param_order_cancellation_request_reject_request`].
+    pub fn deprecated_202101_create_fulfillment_orders_param_order_cancellation_request_reject_request<'a>(&'a self, fulfillment_order_id: &'a str, body: &'a serde_json::Value,) -> ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderCancellationRequestRejectRequest<'a>{
+        ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderCancellationRequestRejectRequest { inner: self.inner.deprecated_202101_create_fulfillment_orders_param_order_cancellation_request_reject_request(fulfillment_order_id, body), runtime: &self.runtime }
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_unstable_create_fulfillment_orders_param_order_cancellation_request`].
+    pub fn deprecated_unstable_create_fulfillment_orders_param_order_cancellation_request(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_create_fulfillment_orders_param_order_cancellation_request(
+                    fulfillment_order_id,
+                    message,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_unstable_create_fulfillment_orders_param_order_cancellation_request_request`].
+    pub fn deprecated_unstable_create_fulfillment_orders_param_order_cancellation_request_request<'a>(&'a self, fulfillment_order_id: &'a str, body: &'a serde_json::Value,) -> ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderCancellationRequestRequest<'a>{
+        ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderCancellationRequestRequest { inner: self.inner.deprecated_unstable_create_fulfillment_orders_param_order_cancellation_request_request(fulfillment_order_id, body), runtime: &self.runtime }
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_unstable_create_fulfillment_orders_param_order_cancellation_request_accept`].
+    pub fn deprecated_unstable_create_fulfillment_orders_param_order_cancellation_request_accept(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.deprecated_unstable_create_fulfillment_orders_param_order_cancellation_request_accept(fulfillment_order_id, message, body))
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_unstable_create_fulfillment_orders_param_order_cancellation_request_accept_request`].
+    pub fn deprecated_unstable_create_fulfillment_orders_param_order_cancellation_request_accept_request<'a>(&'a self, fulfillment_order_id: &'a str, body: &'a serde_json::Value,) -> ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderCancellationRequestAcceptRequest<'a>{
+        ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderCancellationRequestAcceptRequest { inner: self.inner.deprecated_unstable_create_fulfillment_orders_param_order_cancellation_request_accept_request(fulfillment_order_id, body), runtime: &self.runtime }
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_unstable_create_fulfillment_orders_param_order_cancellation_request_reject`].
+    pub fn deprecated_unstable_create_fulfillment_orders_param_order_cancellation_request_reject(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.deprecated_unstable_create_fulfillment_orders_param_order_cancellation_request_reject(fulfillment_order_id, message, body))
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_unstable_create_fulfillment_orders_param_order_cancellation_request_reject_request`].
+    pub fn deprecated_unstable_create_fulfillment_orders_param_order_cancellation_request_reject_request<'a>(&'a self, fulfillment_order_id: &'a str, body: &'a serde_json::Value,) -> ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderCancellationRequestRejectRequest<'a>{
+        ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderCancellationRequestRejectRequest { inner: self.inner.deprecated_unstable_create_fulfillment_orders_param_order_cancellation_request_reject_request(fulfillment_order_id, body), runtime: &self.runtime }
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202001_get_carrier_service`].
+    pub fn deprecated_202001_get_carrier_service(&self) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202001_get_carrier_service())
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202001_create_carrier_services`].
+    pub fn deprecated_202001_create_carrier_services(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202001_create_carrier_services(body))
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202001_get_carrier_services_param_service`].
+    pub fn deprecated_202001_get_carrier_services_param_service(
+        &self,
+        carrier_service_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_get_carrier_services_param_service(carrier_service_id),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202001_update_carrier_services_param_service`].
+    pub fn deprecated_202001_update_carrier_services_param_service(
+        &self,
+        carrier_service_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_update_carrier_services_param_service(carrier_service_id, body),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202001_delete_carrier_services_param_service`].
+    pub fn deprecated_202001_delete_carrier_services_param_service(
+        &self,
+        carrier_service_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_delete_carrier_services_param_service(carrier_service_id),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202004_get_carrier_service`].
+    pub fn deprecated_202004_get_carrier_service(&self) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202004_get_carrier_service())
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202004_create_carrier_services`].
+    pub fn deprecated_202004_create_carrier_services(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202004_create_carrier_services(body))
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202004_get_carrier_services_param_service`].
+    pub fn deprecated_202004_get_carrier_services_param_service(
+        &self,
+        carrier_service_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_get_carrier_services_param_service(carrier_service_id),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202004_update_carrier_services_param_service`].
+    pub fn deprecated_202004_update_carrier_services_param_service(
+        &self,
+        carrier_service_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_update_carrier_services_param_service(carrier_service_id, body),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202004_delete_carrier_services_param_service`].
+    pub fn deprecated_202004_delete_carrier_services_param_service(
+        &self,
+        carrier_service_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_delete_carrier_services_param_service(carrier_service_id),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202007_get_carrier_service`].
+    pub fn deprecated_202007_get_carrier_service(&self) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202007_get_carrier_service())
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202007_create_carrier_services`].
+    pub fn deprecated_202007_create_carrier_services(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202007_create_carrier_services(body))
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202007_get_carrier_services_param_service`].
+    pub fn deprecated_202007_get_carrier_services_param_service(
+        &self,
+        carrier_service_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_get_carrier_services_param_service(carrier_service_id),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202007_update_carrier_services_param_service`].
+    pub fn deprecated_202007_update_carrier_services_param_service(
+        &self,
+        carrier_service_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_update_carrier_services_param_service(carrier_service_id, body),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202007_delete_carrier_services_param_service`].
+    pub fn deprecated_202007_delete_carrier_services_param_service(
+        &self,
+        carrier_service_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_delete_carrier_services_param_service(carrier_service_id),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::get_carrier_service`].
+    pub fn get_carrier_service(&self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.get_carrier_service())
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::create_carrier_services`].
+    pub fn create_carrier_services(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.create_carrier_services(body))
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::get_carrier_services_param_service`].
+    pub fn get_carrier_services_param_service(
+        &self,
+        carrier_service_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .get_carrier_services_param_service(carrier_service_id),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::update_carrier_services_param_service`].
+    pub fn update_carrier_services_param_service(
+        &self,
+        carrier_service_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .update_carrier_services_param_service(carrier_service_id, body),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::delete_carrier_services_param_service`].
+    pub fn delete_carrier_services_param_service(
+        &self,
+        carrier_service_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .delete_carrier_services_param_service(carrier_service_id),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202101_get_carrier_service`].
+    pub fn deprecated_202101_get_carrier_service(&self) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202101_get_carrier_service())
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202101_create_carrier_services`].
+    pub fn deprecated_202101_create_carrier_services(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202101_create_carrier_services(body))
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202101_get_carrier_services_param_service`].
+    pub fn deprecated_202101_get_carrier_services_param_service(
+        &self,
+        carrier_service_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_get_carrier_services_param_service(carrier_service_id),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202101_update_carrier_services_param_service`].
+    pub fn deprecated_202101_update_carrier_services_param_service(
+        &self,
+        carrier_service_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_update_carrier_services_param_service(carrier_service_id, body),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202101_delete_carrier_services_param_service`].
+    pub fn deprecated_202101_delete_carrier_services_param_service(
+        &self,
+        carrier_service_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_delete_carrier_services_param_service(carrier_service_id),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_unstable_get_carrier_service`].
+    pub fn deprecated_unstable_get_carrier_service(&self) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_unstable_get_carrier_service())
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_unstable_create_carrier_services`].
+    pub fn deprecated_unstable_create_carrier_services(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_unstable_create_carrier_services(body))
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_unstable_get_carrier_services_param_service`].
+    pub fn deprecated_unstable_get_carrier_services_param_service(
+        &self,
+        carrier_service_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_get_carrier_services_param_service(carrier_service_id),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_unstable_update_carrier_services_param_service`].
+    pub fn deprecated_unstable_update_carrier_services_param_service(
+        &self,
+        carrier_service_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_update_carrier_services_param_service(
+                    carrier_service_id,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_unstable_delete_carrier_services_param_service`].
+    pub fn deprecated_unstable_delete_carrier_services_param_service(
+        &self,
+        carrier_service_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_delete_carrier_services_param_service(carrier_service_id),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202001_get_orders_param_order_fulfillment`].
+    pub fn deprecated_202001_get_orders_param_order_fulfillment(
+        &self,
+        order_id: &str,
+        created_at_max: &str,
+        created_at_min: &str,
+        fields: &str,
+        limit: &str,
+        since_id: &str,
+        updated_at_max: &str,
+        updated_at_min: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_get_orders_param_order_fulfillment(
+                    order_id,
+                    created_at_max,
+                    created_at_min,
+                    fields,
+                    limit,
+                    since_id,
+                    updated_at_max,
+                    updated_at_min,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202001_get_orders_param_order_fulfillment_request`].
+    pub fn deprecated_202001_get_orders_param_order_fulfillment_request<'a>(
+        &'a self,
+        order_id: &'a str,
+    ) -> ShippingAndFulfillmentDeprecated202001GetOrdersParamOrderFulfillmentRequest<'a> {
+        ShippingAndFulfillmentDeprecated202001GetOrdersParamOrderFulfillmentRequest {
+            inner: self
+                .inner
+                .deprecated_202001_get_orders_param_order_fulfillment_request(order_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202001_create_orders_param_order_fulfillments`].
+    pub fn deprecated_202001_create_orders_param_order_fulfillments(
+        &self,
+        order_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_create_orders_param_order_fulfillments(order_id, body),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202001_get_fulfillment_orders_param_order_fulfillment`].
+    pub fn deprecated_202001_get_fulfillment_orders_param_order_fulfillment(
+        &self,
+        fulfillment_order_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_get_fulfillment_orders_param_order_fulfillment(
+                    fulfillment_order_id,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202001_get_orders_param_order_fulfillments_count`].
+    pub fn deprecated_202001_get_orders_param_order_fulfillments_count(
+        &self,
+        order_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_get_orders_param_order_fulfillments_count(
+                    order_id,
+                    created_at_min,
+                    created_at_max,
+                    updated_at_min,
+                    updated_at_max,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202001_get_orders_param_order_fulfillments_count_request`].
+    pub fn deprecated_202001_get_orders_param_order_fulfillments_count_request<'a>(
+        &'a self,
+        order_id: &'a str,
+    ) -> ShippingAndFulfillmentDeprecated202001GetOrdersParamOrderFulfillmentsCountRequest<'a> {
+        ShippingAndFulfillmentDeprecated202001GetOrdersParamOrderFulfillmentsCountRequest {
+            inner: self
+                .inner
+                .deprecated_202001_get_orders_param_order_fulfillments_count_request(order_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202001_get_orders_param_order_fulfillments_fulfillment`].
+    pub fn deprecated_202001_get_orders_param_order_fulfillments_fulfillment(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_get_orders_param_order_fulfillments_fulfillment(
+                    order_id,
+                    fulfillment_id,
+                    fields,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202001_get_orders_param_order_fulfillments_fulfillment_request`].
+    pub fn deprecated_202001_get_orders_param_order_fulfillments_fulfillment_request<'a>(
+        &'a self,
+        order_id: &'a str,
+        fulfillment_id: &'a str,
+    ) -> ShippingAndFulfillmentDeprecated202001GetOrdersParamOrderFulfillmentsFulfillmentRequest<'a>
+    {
+        ShippingAndFulfillmentDeprecated202001GetOrdersParamOrderFulfillmentsFulfillmentRequest {
+            inner: self
+                .inner
+                .deprecated_202001_get_orders_param_order_fulfillments_fulfillment_request(
+                    order_id,
+                    fulfillment_id,
+                ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202001_update_orders_param_order_fulfillments_fulfillment`].
+    pub fn deprecated_202001_update_orders_param_order_fulfillments_fulfillment(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_update_orders_param_order_fulfillments_fulfillment(
+                    order_id,
+                    fulfillment_id,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202001_create_fulfillments`].
+    pub fn deprecated_202001_create_fulfillments(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202001_create_fulfillments(body))
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202001_create_fulfillments_param_fulfillment_update_tracking`].
+    pub fn deprecated_202001_create_fulfillments_param_fulfillment_update_tracking(
+        &self,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_create_fulfillments_param_fulfillment_update_tracking(
+                    fulfillment_id,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202001_create_orders_param_order_fulfillments_fulfillment_complete`].
+    pub fn deprecated_202001_create_orders_param_order_fulfillments_fulfillment_complete(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_create_orders_param_order_fulfillments_fulfillment_complete(
+                    order_id,
+                    fulfillment_id,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202001_create_orders_param_order_fulfillments_fulfillment_open`].
+    pub fn deprecated_202001_create_orders_param_order_fulfillments_fulfillment_open(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_create_orders_param_order_fulfillments_fulfillment_open(
+                    order_id,
+                    fulfillment_id,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202001_create_orders_param_order_fulfillments_fulfillment_cancel`].
+    pub fn deprecated_202001_create_orders_param_order_fulfillments_fulfillment_cancel(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_create_orders_param_order_fulfillments_fulfillment_cancel(
+                    order_id,
+                    fulfillment_id,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202001_create_fulfillments_param_fulfillment_cancel`].
+    pub fn deprecated_202001_create_fulfillments_param_fulfillment_cancel(
+        &self,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_create_fulfillments_param_fulfillment_cancel(
+                    fulfillment_id,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202004_get_orders_param_order_fulfillment`].
+    pub fn deprecated_202004_get_orders_param_order_fulfillment(
+        &self,
+        order_id: &str,
+        created_at_max: &str,
+        created_at_min: &str,
+        fields: &str,
+        limit: &str,
+        since_id: &str,
+        updated_at_max: &str,
+        updated_at_min: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_get_orders_param_order_fulfillment(
+                    order_id,
+                    created_at_max,
+                    created_at_min,
+                    fields,
+                    limit,
+                    since_id,
+                    updated_at_max,
+                    updated_at_min,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202004_get_orders_param_order_fulfillment_request`].
+    pub fn deprecated_202004_get_orders_param_order_fulfillment_request<'a>(
+        &'a self,
+        order_id: &'a str,
+    ) -> ShippingAndFulfillmentDeprecated202004GetOrdersParamOrderFulfillmentRequest<'a> {
+        ShippingAndFulfillmentDeprecated202004GetOrdersParamOrderFulfillmentRequest {
+            inner: self
+                .inner
+                .deprecated_202004_get_orders_param_order_fulfillment_request(order_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202004_create_orders_param_order_fulfillments`].
+    pub fn deprecated_202004_create_orders_param_order_fulfillments(
+        &self,
+        order_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_create_orders_param_order_fulfillments(order_id, body),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202004_get_fulfillment_orders_param_order_fulfillment`].
+    pub fn deprecated_202004_get_fulfillment_orders_param_order_fulfillment(
+        &self,
+        fulfillment_order_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_get_fulfillment_orders_param_order_fulfillment(
+                    fulfillment_order_id,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202004_get_orders_param_order_fulfillments_count`].
+    pub fn deprecated_202004_get_orders_param_order_fulfillments_count(
+        &self,
+        order_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_get_orders_param_order_fulfillments_count(
+                    order_id,
+                    created_at_min,
+                    created_at_max,
+                    updated_at_min,
+                    updated_at_max,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202004_get_orders_param_order_fulfillments_count_request`].
+    pub fn deprecated_202004_get_orders_param_order_fulfillments_count_request<'a>(
+        &'a self,
+        order_id: &'a str,
+    ) -> ShippingAndFulfillmentDeprecated202004GetOrdersParamOrderFulfillmentsCountRequest<'a> {
+        ShippingAndFulfillmentDeprecated202004GetOrdersParamOrderFulfillmentsCountRequest {
+            inner: self
+                .inner
+                .deprecated_202004_get_orders_param_order_fulfillments_count_request(order_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202004_get_orders_param_order_fulfillments_fulfillment`].
+    pub fn deprecated_202004_get_orders_param_order_fulfillments_fulfillment(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_get_orders_param_order_fulfillments_fulfillment(
+                    order_id,
+                    fulfillment_id,
+                    fields,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202004_get_orders_param_order_fulfillments_fulfillment_request`].
+    pub fn deprecated_202004_get_orders_param_order_fulfillments_fulfillment_request<'a>(
+        &'a self,
+        order_id: &'a str,
+        fulfillment_id: &'a str,
+    ) -> ShippingAndFulfillmentDeprecated202004GetOrdersParamOrderFulfillmentsFulfillmentRequest<'a>
+    {
+        ShippingAndFulfillmentDeprecated202004GetOrdersParamOrderFulfillmentsFulfillmentRequest {
+            inner: self
+                .inner
+                .deprecated_202004_get_orders_param_order_fulfillments_fulfillment_request(
+                    order_id,
+                    fulfillment_id,
+                ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202004_update_orders_param_order_fulfillments_fulfillment`].
+    pub fn deprecated_202004_update_orders_param_order_fulfillments_fulfillment(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_update_orders_param_order_fulfillments_fulfillment(
+                    order_id,
+                    fulfillment_id,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202004_create_fulfillments`].
+    pub fn deprecated_202004_create_fulfillments(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202004_create_fulfillments(body))
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202004_create_fulfillments_param_fulfillment_update_tracking`].
+    pub fn deprecated_202004_create_fulfillments_param_fulfillment_update_tracking(
+        &self,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_create_fulfillments_param_fulfillment_update_tracking(
+                    fulfillment_id,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202004_create_orders_param_order_fulfillments_fulfillment_complete`].
+    pub fn deprecated_202004_create_orders_param_order_fulfillments_fulfillment_complete(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_create_orders_param_order_fulfillments_fulfillment_complete(
+                    order_id,
+                    fulfillment_id,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202004_create_orders_param_order_fulfillments_fulfillment_open`].
+    pub fn deprecated_202004_create_orders_param_order_fulfillments_fulfillment_open(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_create_orders_param_order_fulfillments_fulfillment_open(
+                    order_id,
+                    fulfillment_id,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202004_create_orders_param_order_fulfillments_fulfillment_cancel`].
+    pub fn deprecated_202004_create_orders_param_order_fulfillments_fulfillment_cancel(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_create_orders_param_order_fulfillments_fulfillment_cancel(
+                    order_id,
+                    fulfillment_id,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202004_create_fulfillments_param_fulfillment_cancel`].
+    pub fn deprecated_202004_create_fulfillments_param_fulfillment_cancel(
+        &self,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_create_fulfillments_param_fulfillment_cancel(
+                    fulfillment_id,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202007_get_orders_param_order_fulfillment`].
+    pub fn deprecated_202007_get_orders_param_order_fulfillment(
+        &self,
+        order_id: &str,
+        created_at_max: &str,
+        created_at_min: &str,
+        fields: &str,
+        limit: &str,
+        since_id: &str,
+        updated_at_max: &str,
+        updated_at_min: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_get_orders_param_order_fulfillment(
+                    order_id,
+                    created_at_max,
+                    created_at_min,
+                    fields,
+                    limit,
+                    since_id,
+                    updated_at_max,
+                    updated_at_min,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202007_get_orders_param_order_fulfillment_request`].
+    pub fn deprecated_202007_get_orders_param_order_fulfillment_request<'a>(
+        &'a self,
+        order_id: &'a str,
+    ) -> ShippingAndFulfillmentDeprecated202007GetOrdersParamOrderFulfillmentRequest<'a> {
+        ShippingAndFulfillmentDeprecated202007GetOrdersParamOrderFulfillmentRequest {
+            inner: self
+                .inner
+                .deprecated_202007_get_orders_param_order_fulfillment_request(order_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202007_create_orders_param_order_fulfillments`].
+    pub fn deprecated_202007_create_orders_param_order_fulfillments(
+        &self,
+        order_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_create_orders_param_order_fulfillments(order_id, body),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202007_get_fulfillment_orders_param_order_fulfillment`].
+    pub fn deprecated_202007_get_fulfillment_orders_param_order_fulfillment(
+        &self,
+        fulfillment_order_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_get_fulfillment_orders_param_order_fulfillment(
+                    fulfillment_order_id,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202007_get_orders_param_order_fulfillments_count`].
+    pub fn deprecated_202007_get_orders_param_order_fulfillments_count(
+        &self,
+        order_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_get_orders_param_order_fulfillments_count(
+                    order_id,
+                    created_at_min,
+                    created_at_max,
+                    updated_at_min,
+                    updated_at_max,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202007_get_orders_param_order_fulfillments_count_request`].
+    pub fn deprecated_202007_get_orders_param_order_fulfillments_count_request<'a>(
+        &'a self,
+        order_id: &'a str,
+    ) -> ShippingAndFulfillmentDeprecated202007GetOrdersParamOrderFulfillmentsCountRequest<'a> {
+        ShippingAndFulfillmentDeprecated202007GetOrdersParamOrderFulfillmentsCountRequest {
+            inner: self
+                .inner
+                .deprecated_202007_get_orders_param_order_fulfillments_count_request(order_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202007_get_orders_param_order_fulfillments_fulfillment`].
+    pub fn deprecated_202007_get_orders_param_order_fulfillments_fulfillment(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_get_orders_param_order_fulfillments_fulfillment(
+                    order_id,
+                    fulfillment_id,
+                    fields,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202007_get_orders_param_order_fulfillments_fulfillment_request`].
+    pub fn deprecated_202007_get_orders_param_order_fulfillments_fulfillment_request<'a>(
+        &'a self,
+        order_id: &'a str,
+        fulfillment_id: &'a str,
+    ) -> ShippingAndFulfillmentDeprecated202007GetOrdersParamOrderFulfillmentsFulfillmentRequest<'a>
+    {
+        ShippingAndFulfillmentDeprecated202007GetOrdersParamOrderFulfillmentsFulfillmentRequest {
+            inner: self
+                .inner
+                .deprecated_202007_get_orders_param_order_fulfillments_fulfillment_request(
+                    order_id,
+                    fulfillment_id,
+                ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202007_update_orders_param_order_fulfillments_fulfillment`].
+    pub fn deprecated_202007_update_orders_param_order_fulfillments_fulfillment(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_update_orders_param_order_fulfillments_fulfillment(
+                    order_id,
+                    fulfillment_id,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202007_create_fulfillments`].
+    pub fn deprecated_202007_create_fulfillments(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202007_create_fulfillments(body))
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202007_create_fulfillments_param_fulfillment_update_tracking`].
+    pub fn deprecated_202007_create_fulfillments_param_fulfillment_update_tracking(
+        &self,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_create_fulfillments_param_fulfillment_update_tracking(
+                    fulfillment_id,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202007_create_orders_param_order_fulfillments_fulfillment_complete`].
+    pub fn deprecated_202007_create_orders_param_order_fulfillments_fulfillment_complete(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_create_orders_param_order_fulfillments_fulfillment_complete(
+                    order_id,
+                    fulfillment_id,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202007_create_orders_param_order_fulfillments_fulfillment_open`].
+    pub fn deprecated_202007_create_orders_param_order_fulfillments_fulfillment_open(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_create_orders_param_order_fulfillments_fulfillment_open(
+                    order_id,
+                    fulfillment_id,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202007_create_orders_param_order_fulfillments_fulfillment_cancel`].
+    pub fn deprecated_202007_create_orders_param_order_fulfillments_fulfillment_cancel(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_create_orders_param_order_fulfillments_fulfillment_cancel(
+                    order_id,
+                    fulfillment_id,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202007_create_fulfillments_param_fulfillment_cancel`].
+    pub fn deprecated_202007_create_fulfillments_param_fulfillment_cancel(
+        &self,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_create_fulfillments_param_fulfillment_cancel(
+                    fulfillment_id,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::get_orders_param_order_fulfillment`].
+    pub fn get_orders_param_order_fulfillment(
+        &self,
+        order_id: &str,
+        created_at_max: &str,
+        created_at_min: &str,
+        fields: &str,
+        limit: &str,
+        since_id: &str,
+        updated_at_max: &str,
+        updated_at_min: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.get_orders_param_order_fulfillment(
+                order_id,
+                created_at_max,
+                created_at_min,
+                fields,
+                limit,
+                since_id,
+                updated_at_max,
+                updated_at_min,
+            ))
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::get_orders_param_order_fulfillment_request`].
+    pub fn get_orders_param_order_fulfillment_request<'a>(
+        &'a self,
+        order_id: &'a str,
+    ) -> ShippingAndFulfillmentGetOrdersParamOrderFulfillmentRequest<'a> {
+        ShippingAndFulfillmentGetOrdersParamOrderFulfillmentRequest {
+            inner: self
+                .inner
+                .get_orders_param_order_fulfillment_request(order_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::create_orders_param_order_fulfillments`].
+    pub fn create_orders_param_order_fulfillments(
+        &self,
+        order_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .create_orders_param_order_fulfillments(order_id, body),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::get_fulfillment_orders_param_order_fulfillment`].
+    pub fn get_fulfillment_orders_param_order_fulfillment(
+        &self,
+        fulfillment_order_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .get_fulfillment_orders_param_order_fulfillment(fulfillment_order_id),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::get_orders_param_order_fulfillments_count`].
+    pub fn get_orders_param_order_fulfillments_count(
+        &self,
+        order_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.get_orders_param_order_fulfillments_count(
+                order_id,
+                created_at_min,
+                created_at_max,
+                updated_at_min,
+                updated_at_max,
+            ))
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::get_orders_param_order_fulfillments_count_request`].
+    pub fn get_orders_param_order_fulfillments_count_request<'a>(
+        &'a self,
+        order_id: &'a str,
+    ) -> ShippingAndFulfillmentGetOrdersParamOrderFulfillmentsCountRequest<'a> {
+        ShippingAndFulfillmentGetOrdersParamOrderFulfillmentsCountRequest {
+            inner: self
+                .inner
+                .get_orders_param_order_fulfillments_count_request(order_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::get_orders_param_order_fulfillments_fulfillment`].
+    pub fn get_orders_param_order_fulfillments_fulfillment(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.get_orders_param_order_fulfillments_fulfillment(
+                order_id,
+                fulfillment_id,
+                fields,
+            ))
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::get_orders_param_order_fulfillments_fulfillment_request`].
+    pub fn get_orders_param_order_fulfillments_fulfillment_request<'a>(
+        &'a self,
+        order_id: &'a str,
+        fulfillment_id: &'a str,
+    ) -> ShippingAndFulfillmentGetOrdersParamOrderFulfillmentsFulfillmentRequest<'a> {
+        ShippingAndFulfillmentGetOrdersParamOrderFulfillmentsFulfillmentRequest {
+            inner: self
+                .inner
+                .get_orders_param_order_fulfillments_fulfillment_request(order_id, fulfillment_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::update_orders_param_order_fulfillments_fulfillment`].
+    pub fn update_orders_param_order_fulfillments_fulfillment(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .update_orders_param_order_fulfillments_fulfillment(order_id, fulfillment_id, body),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::create_fulfillments`].
+    pub fn create_fulfillments(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.create_fulfillments(body))
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::create_fulfillments_param_fulfillment_update_tracking`].
+    pub fn create_fulfillments_param_fulfillment_update_tracking(
+        &self,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .create_fulfillments_param_fulfillment_update_tracking(fulfillment_id, body),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::create_orders_param_order_fulfillments_fulfillment_complete`].
+    pub fn create_orders_param_order_fulfillments_fulfillment_complete(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .create_orders_param_order_fulfillments_fulfillment_complete(
+                    order_id,
+                    fulfillment_id,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::create_orders_param_order_fulfillments_fulfillment_open`].
+    pub fn create_orders_param_order_fulfillments_fulfillment_open(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .create_orders_param_order_fulfillments_fulfillment_open(
+                    order_id,
+                    fulfillment_id,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::create_orders_param_order_fulfillments_fulfillment_cancel`].
+    pub fn create_orders_param_order_fulfillments_fulfillment_cancel(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .create_orders_param_order_fulfillments_fulfillment_cancel(
+                    order_id,
+                    fulfillment_id,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::create_fulfillments_param_fulfillment_cancel`].
+    pub fn create_fulfillments_param_fulfillment_cancel(
+        &self,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .create_fulfillments_param_fulfillment_cancel(fulfillment_id, body),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202101_get_orders_param_order_fulfillment`].
+    pub fn deprecated_202101_get_orders_param_order_fulfillment(
+        &self,
+        order_id: &str,
+        created_at_max: &str,
+        created_at_min: &str,
+        fields: &str,
+        limit: &str,
+        since_id: &str,
+        updated_at_max: &str,
+        updated_at_min: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_get_orders_param_order_fulfillment(
+                    order_id,
+                    created_at_max,
+                    created_at_min,
+                    fields,
+                    limit,
+                    since_id,
+                    updated_at_max,
+                    updated_at_min,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202101_get_orders_param_order_fulfillment_request`].
+    pub fn deprecated_202101_get_orders_param_order_fulfillment_request<'a>(
+        &'a self,
+        order_id: &'a str,
+    ) -> ShippingAndFulfillmentDeprecated202101GetOrdersParamOrderFulfillmentRequest<'a> {
+        ShippingAndFulfillmentDeprecated202101GetOrdersParamOrderFulfillmentRequest {
+            inner: self
+                .inner
+                .deprecated_202101_get_orders_param_order_fulfillment_request(order_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202101_create_orders_param_order_fulfillments`].
+    pub fn deprecated_202101_create_orders_param_order_fulfillments(
+        &self,
+        order_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_create_orders_param_order_fulfillments(order_id, body),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202101_get_fulfillment_orders_param_order_fulfillment`].
+    pub fn deprecated_202101_get_fulfillment_orders_param_order_fulfillment(
+        &self,
+        fulfillment_order_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_get_fulfillment_orders_param_order_fulfillment(
+                    fulfillment_order_id,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202101_get_orders_param_order_fulfillments_count`].
+    pub fn deprecated_202101_get_orders_param_order_fulfillments_count(
+        &self,
+        order_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_get_orders_param_order_fulfillments_count(
+                    order_id,
+                    created_at_min,
+                    created_at_max,
+                    updated_at_min,
+                    updated_at_max,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202101_get_orders_param_order_fulfillments_count_request`].
+    pub fn deprecated_202101_get_orders_param_order_fulfillments_count_request<'a>(
+        &'a self,
+        order_id: &'a str,
+    ) -> ShippingAndFulfillmentDeprecated202101GetOrdersParamOrderFulfillmentsCountRequest<'a> {
+        ShippingAndFulfillmentDeprecated202101GetOrdersParamOrderFulfillmentsCountRequest {
+            inner: self
+                .inner
+                .deprecated_202101_get_orders_param_order_fulfillments_count_request(order_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202101_get_orders_param_order_fulfillments_fulfillment`].
+    pub fn deprecated_202101_get_orders_param_order_fulfillments_fulfillment(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_get_orders_param_order_fulfillments_fulfillment(
+                    order_id,
+                    fulfillment_id,
+                    fields,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202101_get_orders_param_order_fulfillments_fulfillment_request`].
+    pub fn deprecated_202101_get_orders_param_order_fulfillments_fulfillment_request<'a>(
+        &'a self,
+        order_id: &'a str,
+        fulfillment_id: &'a str,
+    ) -> ShippingAndFulfillmentDeprecated202101GetOrdersParamOrderFulfillmentsFulfillmentRequest<'a>
+    {
+        ShippingAndFulfillmentDeprecated202101GetOrdersParamOrderFulfillmentsFulfillmentRequest {
+            inner: self
+                .inner
+                .deprecated_202101_get_orders_param_order_fulfillments_fulfillment_request(
+                    order_id,
+                    fulfillment_id,
+                ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202101_update_orders_param_order_fulfillments_fulfillment`].
+    pub fn deprecated_202101_update_orders_param_order_fulfillments_fulfillment(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_update_orders_param_order_fulfillments_fulfillment(
+                    order_id,
+                    fulfillment_id,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202101_create_fulfillments`].
+    pub fn deprecated_202101_create_fulfillments(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202101_create_fulfillments(body))
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202101_create_fulfillments_param_fulfillment_update_tracking`].
+    pub fn deprecated_202101_create_fulfillments_param_fulfillment_update_tracking(
+        &self,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_create_fulfillments_param_fulfillment_update_tracking(
+                    fulfillment_id,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202101_create_orders_param_order_fulfillments_fulfillment_complete`].
+    pub fn deprecated_202101_create_orders_param_order_fulfillments_fulfillment_complete(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_create_orders_param_order_fulfillments_fulfillment_complete(
+                    order_id,
+                    fulfillment_id,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202101_create_orders_param_order_fulfillments_fulfillment_open`].
+    pub fn deprecated_202101_create_orders_param_order_fulfillments_fulfillment_open(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_create_orders_param_order_fulfillments_fulfillment_open(
+                    order_id,
+                    fulfillment_id,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202101_create_orders_param_order_fulfillments_fulfillment_cancel`].
+    pub fn deprecated_202101_create_orders_param_order_fulfillments_fulfillment_cancel(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_create_orders_param_order_fulfillments_fulfillment_cancel(
+                    order_id,
+                    fulfillment_id,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202101_create_fulfillments_param_fulfillment_cancel`].
+    pub fn deprecated_202101_create_fulfillments_param_fulfillment_cancel(
+        &self,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_create_fulfillments_param_fulfillment_cancel(
+                    fulfillment_id,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_unstable_get_orders_param_order_fulfillment`].
+    pub fn deprecated_unstable_get_orders_param_order_fulfillment(
+        &self,
+        order_id: &str,
+        created_at_max: &str,
+        created_at_min: &str,
+        fields: &str,
+        limit: &str,
+        since_id: &str,
+        updated_at_max: &str,
+        updated_at_min: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_get_orders_param_order_fulfillment(
+                    order_id,
+                    created_at_max,
+                    created_at_min,
+                    fields,
+                    limit,
+                    since_id,
+                    updated_at_max,
+                    updated_at_min,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_unstable_get_orders_param_order_fulfillment_request`].
+    pub fn deprecated_unstable_get_orders_param_order_fulfillment_request<'a>(
+        &'a self,
+        order_id: &'a str,
+    ) -> ShippingAndFulfillmentDeprecatedUnstableGetOrdersParamOrderFulfillmentRequest<'a> {
+        ShippingAndFulfillmentDeprecatedUnstableGetOrdersParamOrderFulfillmentRequest {
+            inner: self
+                .inner
+                .deprecated_unstable_get_orders_param_order_fulfillment_request(order_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_unstable_create_orders_param_order_fulfillments`].
+    pub fn deprecated_unstable_create_orders_param_order_fulfillments(
+        &self,
+        order_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_create_orders_param_order_fulfillments(order_id, body),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_unstable_get_fulfillment_orders_param_order_fulfillment`].
+    pub fn deprecated_unstable_get_fulfillment_orders_param_order_fulfillment(
+        &self,
+        fulfillment_order_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_get_fulfillment_orders_param_order_fulfillment(
+                    fulfillment_order_id,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_unstable_get_orders_param_order_fulfillments_count`].
+    pub fn deprecated_unstable_get_orders_param_order_fulfillments_count(
+        &self,
+        order_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_get_orders_param_order_fulfillments_count(
+                    order_id,
+                    created_at_min,
+                    created_at_max,
+                    updated_at_min,
+                    updated_at_max,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_unstable_get_orders_param_order_fulfillments_count_request`].
+    pub fn deprecated_unstable_get_orders_param_order_fulfillments_count_request<'a>(
+        &'a self,
+        order_id: &'a str,
+    ) -> ShippingAndFulfillmentDeprecatedUnstableGetOrdersParamOrderFulfillmentsCountRequest<'a>
+    {
+        ShippingAndFulfillmentDeprecatedUnstableGetOrdersParamOrderFulfillmentsCountRequest {
+            inner: self
+                .inner
+                .deprecated_unstable_get_orders_param_order_fulfillments_count_request(order_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_unstable_get_orders_param_order_fulfillments_fulfillment`].
+    pub fn deprecated_unstable_get_orders_param_order_fulfillments_fulfillment(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_get_orders_param_order_fulfillments_fulfillment(
+                    order_id,
+                    fulfillment_id,
+                    fields,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_unstable_get_orders_param_order_fulfillments_fulfillment_request`].
+    pub fn deprecated_unstable_get_orders_param_order_fulfillments_fulfillment_request<'a>(
+        &'a self,
+        order_id: &'a str,
+        fulfillment_id: &'a str,
+    ) -> ShippingAndFulfillmentDeprecatedUnstableGetOrdersParamOrderFulfillmentsFulfillmentRequest<'a>
+    {
+        ShippingAndFulfillmentDeprecatedUnstableGetOrdersParamOrderFulfillmentsFulfillmentRequest {
+            inner: self
+                .inner
+                .deprecated_unstable_get_orders_param_order_fulfillments_fulfillment_request(
+                    order_id,
+                    fulfillment_id,
+                ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_unstable_update_orders_param_order_fulfillments_fulfillment`].
+    pub fn deprecated_unstable_update_orders_param_order_fulfillments_fulfillment(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_update_orders_param_order_fulfillments_fulfillment(
+                    order_id,
+                    fulfillment_id,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_unstable_create_fulfillments`].
+    pub fn deprecated_unstable_create_fulfillments(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_unstable_create_fulfillments(body))
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_unstable_create_fulfillments_param_fulfillment_update_tracking`].
+    pub fn deprecated_unstable_create_fulfillments_param_fulfillment_update_tracking(
+        &self,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_create_fulfillments_param_fulfillment_update_tracking(
+                    fulfillment_id,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_unstable_create_orders_param_order_fulfillments_fulfillment_complete`].
+    pub fn deprecated_unstable_create_orders_param_order_fulfillments_fulfillment_complete(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_create_orders_param_order_fulfillments_fulfillment_complete(
+                    order_id,
+                    fulfillment_id,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_unstable_create_orders_param_order_fulfillments_fulfillment_open`].
+    pub fn deprecated_unstable_create_orders_param_order_fulfillments_fulfillment_open(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_create_orders_param_order_fulfillments_fulfillment_open(
+                    order_id,
+                    fulfillment_id,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_unstable_create_orders_param_order_fulfillments_fulfillment_cancel`].
+    pub fn deprecated_unstable_create_orders_param_order_fulfillments_fulfillment_cancel(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_create_orders_param_order_fulfillments_fulfillment_cancel(
+                    order_id,
+                    fulfillment_id,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_unstable_create_fulfillments_param_fulfillment_cancel`].
+    pub fn deprecated_unstable_create_fulfillments_param_fulfillment_cancel(
+        &self,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_create_fulfillments_param_fulfillment_cancel(
+                    fulfillment_id,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202001_get_orders_param_order_fulfillments_fulfillment_event`].
+    pub fn deprecated_202001_get_orders_param_order_fulfillments_fulfillment_event(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_get_orders_param_order_fulfillments_fulfillment_event(
+                    order_id,
+                    fulfillment_id,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202001_create_orders_param_order_fulfillments_fulfillment_events`].
+    pub fn deprecated_202001_create_orders_param_order_fulfillments_fulfillment_events(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_create_orders_param_order_fulfillments_fulfillment_events(
+                    order_id,
+                    fulfillment_id,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202001_get_orders_param_order_fulfillments_fulfillment_events_event`].
+    pub fn deprecated_202001_get_orders_param_order_fulfillments_fulfillment_events_event(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        event_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_get_orders_param_order_fulfillments_fulfillment_events_event(
+                    order_id,
+                    fulfillment_id,
+                    event_id,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202001_delete_orders_param_order_fulfillments_fulfillment_events_event`].
+    pub fn deprecated_202001_delete_orders_param_order_fulfillments_fulfillment_events_event(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        event_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_delete_orders_param_order_fulfillments_fulfillment_events_event(
+                    order_id,
+                    fulfillment_id,
+                    event_id,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202004_get_orders_param_order_fulfillments_fulfillment_event`].
+    pub fn deprecated_202004_get_orders_param_order_fulfillments_fulfillment_event(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_get_orders_param_order_fulfillments_fulfillment_event(
+                    order_id,
+                    fulfillment_id,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202004_create_orders_param_order_fulfillments_fulfillment_events`].
+    pub fn deprecated_202004_create_orders_param_order_fulfillments_fulfillment_events(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_create_orders_param_order_fulfillments_fulfillment_events(
+                    order_id,
+                    fulfillment_id,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202004_get_orders_param_order_fulfillments_fulfillment_events_event`].
+    pub fn deprecated_202004_get_orders_param_order_fulfillments_fulfillment_events_event(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        event_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_get_orders_param_order_fulfillments_fulfillment_events_event(
+                    order_id,
+                    fulfillment_id,
+                    event_id,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202004_delete_orders_param_order_fulfillments_fulfillment_events_event`].
+    pub fn deprecated_202004_delete_orders_param_order_fulfillments_fulfillment_events_event(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        event_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_delete_orders_param_order_fulfillments_fulfillment_events_event(
+                    order_id,
+                    fulfillment_id,
+                    event_id,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202007_get_orders_param_order_fulfillments_fulfillment_event`].
+    pub fn deprecated_202007_get_orders_param_order_fulfillments_fulfillment_event(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_get_orders_param_order_fulfillments_fulfillment_event(
+                    order_id,
+                    fulfillment_id,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202007_create_orders_param_order_fulfillments_fulfillment_events`].
+    pub fn deprecated_202007_create_orders_param_order_fulfillments_fulfillment_events(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_create_orders_param_order_fulfillments_fulfillment_events(
+                    order_id,
+                    fulfillment_id,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202007_get_orders_param_order_fulfillments_fulfillment_events_event`].
+    pub fn deprecated_202007_get_orders_param_order_fulfillments_fulfillment_events_event(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        event_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_get_orders_param_order_fulfillments_fulfillment_events_event(
+                    order_id,
+                    fulfillment_id,
+                    event_id,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202007_delete_orders_param_order_fulfillments_fulfillment_events_event`].
+    pub fn deprecated_202007_delete_orders_param_order_fulfillments_fulfillment_events_event(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        event_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_delete_orders_param_order_fulfillments_fulfillment_events_event(
+                    order_id,
+                    fulfillment_id,
+                    event_id,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::get_orders_param_order_fulfillments_fulfillment_event`].
+    pub fn get_orders_param_order_fulfillments_fulfillment_event(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .get_orders_param_order_fulfillments_fulfillment_event(order_id, fulfillment_id),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::create_orders_param_order_fulfillments_fulfillment_events`].
+    pub fn create_orders_param_order_fulfillments_fulfillment_events(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .create_orders_param_order_fulfillments_fulfillment_events(
+                    order_id,
+                    fulfillment_id,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::get_orders_param_order_fulfillments_fulfillment_events_event`].
+    pub fn get_orders_param_order_fulfillments_fulfillment_events_event(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        event_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .get_orders_param_order_fulfillments_fulfillment_events_event(
+                    order_id,
+                    fulfillment_id,
+                    event_id,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::delete_orders_param_order_fulfillments_fulfillment_events_event`].
+    pub fn delete_orders_param_order_fulfillments_fulfillment_events_event(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        event_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .delete_orders_param_order_fulfillments_fulfillment_events_event(
+                    order_id,
+                    fulfillment_id,
+                    event_id,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202101_get_orders_param_order_fulfillments_fulfillment_event`].
+    pub fn deprecated_202101_get_orders_param_order_fulfillments_fulfillment_event(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_get_orders_param_order_fulfillments_fulfillment_event(
+                    order_id,
+                    fulfillment_id,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202101_create_orders_param_order_fulfillments_fulfillment_events`].
+    pub fn deprecated_202101_create_orders_param_order_fulfillments_fulfillment_events(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_create_orders_param_order_fulfillments_fulfillment_events(
+                    order_id,
+                    fulfillment_id,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202101_get_orders_param_order_fulfillments_fulfillment_events_event`].
+    pub fn deprecated_202101_get_orders_param_order_fulfillments_fulfillment_events_event(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        event_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_get_orders_param_order_fulfillments_fulfillment_events_event(
+                    order_id,
+                    fulfillment_id,
+                    event_id,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202101_delete_orders_param_order_fulfillments_fulfillment_events_event`].
+    pub fn deprecated_202101_delete_orders_param_order_fulfillments_fulfillment_events_event(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        event_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_delete_orders_param_order_fulfillments_fulfillment_events_event(
+                    order_id,
+                    fulfillment_id,
+                    event_id,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_unstable_get_orders_param_order_fulfillments_fulfillment_event`].
+    pub fn deprecated_unstable_get_orders_param_order_fulfillments_fulfillment_event(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_get_orders_param_order_fulfillments_fulfillment_event(
+                    order_id,
+                    fulfillment_id,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_unstable_create_orders_param_order_fulfillments_fulfillment_events`].
+    pub fn deprecated_unstable_create_orders_param_order_fulfillments_fulfillment_events(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_create_orders_param_order_fulfillments_fulfillment_events(
+                    order_id,
+                    fulfillment_id,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_unstable_get_orders_param_order_fulfillments_fulfillment_events_event`].
+    pub fn deprecated_unstable_get_orders_param_order_fulfillments_fulfillment_events_event(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        event_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_get_orders_param_order_fulfillments_fulfillment_events_event(
+                    order_id,
+                    fulfillment_id,
+                    event_id,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_unstable_delete_orders_param_order_fulfillments_fulfillment_events_event`].
+    pub fn deprecated_unstable_delete_orders_param_order_fulfillments_fulfillment_events_event(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        event_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.deprecated_unstable_delete_orders_param_order_fulfillments_fulfillment_events_event(order_id, fulfillment_id, event_id))
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202001_get_orders_param_order_fulfillment_shipping_and_fulfillment`].
+    pub fn deprecated_202001_get_orders_param_order_fulfillment_shipping_and_fulfillment(
+        &self,
+        order_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_get_orders_param_order_fulfillment_shipping_and_fulfillment(
+                    order_id,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202001_get_fulfillment_orders_param_order`].
+    pub fn deprecated_202001_get_fulfillment_orders_param_order(
+        &self,
+        fulfillment_order_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_get_fulfillment_orders_param_order(fulfillment_order_id),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202001_create_fulfillment_orders_param_order_cancel`].
+    pub fn deprecated_202001_create_fulfillment_orders_param_order_cancel(
+        &self,
+        fulfillment_order_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_create_fulfillment_orders_param_order_cancel(
+                    fulfillment_order_id,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202001_create_fulfillment_orders_param_order_close`].
+    pub fn deprecated_202001_create_fulfillment_orders_param_order_close(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_create_fulfillment_orders_param_order_close(
+                    fulfillment_order_id,
+                    message,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202001_create_fulfillment_orders_param_order_close_request`].
+    pub fn deprecated_202001_create_fulfillment_orders_param_order_close_request<'a>(
+        &'a self,
+        fulfillment_order_id: &'a str,
+        body: &'a serde_json::Value,
+    ) -> ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderCloseRequest<'a>
+    {
+        ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderCloseRequest {
+            inner: self
+                .inner
+                .deprecated_202001_create_fulfillment_orders_param_order_close_request(
+                    fulfillment_order_id,
+                    body,
+                ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202001_create_fulfillment_orders_param_order_move`].
+    pub fn deprecated_202001_create_fulfillment_orders_param_order_move(
+        &self,
+        fulfillment_order_id: &str,
+        new_location_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_create_fulfillment_orders_param_order_move(
+                    fulfillment_order_id,
+                    new_location_id,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202001_create_fulfillment_orders_param_order_move_request`].
+    pub fn deprecated_202001_create_fulfillment_orders_param_order_move_request<'a>(
+        &'a self,
+        fulfillment_order_id: &'a str,
+        body: &'a serde_json::Value,
+    ) -> ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderMoveRequest<'a>
+    {
+        ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderMoveRequest {
+            inner: self
+                .inner
+                .deprecated_202001_create_fulfillment_orders_param_order_move_request(
+                    fulfillment_order_id,
+                    body,
+                ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202004_get_orders_param_order_fulfillment_shipping_and_fulfillment`].
+    pub fn deprecated_202004_get_orders_param_order_fulfillment_shipping_and_fulfillment(
+        &self,
+        order_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_get_orders_param_order_fulfillment_shipping_and_fulfillment(
+                    order_id,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202004_get_fulfillment_orders_param_order`].
+    pub fn deprecated_202004_get_fulfillment_orders_param_order(
+        &self,
+        fulfillment_order_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_get_fulfillment_orders_param_order(fulfillment_order_id),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202004_create_fulfillment_orders_param_order_cancel`].
+    pub fn deprecated_202004_create_fulfillment_orders_param_order_cancel(
+        &self,
+        fulfillment_order_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_create_fulfillment_orders_param_order_cancel(
+                    fulfillment_order_id,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202004_create_fulfillment_orders_param_order_close`].
+    pub fn deprecated_202004_create_fulfillment_orders_param_order_close(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_create_fulfillment_orders_param_order_close(
+                    fulfillment_order_id,
+                    message,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202004_create_fulfillment_orders_param_order_close_request`].
+    pub fn deprecated_202004_create_fulfillment_orders_param_order_close_request<'a>(
+        &'a self,
+        fulfillment_order_id: &'a str,
+        body: &'a serde_json::Value,
+    ) -> ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderCloseRequest<'a>
+    {
+        ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderCloseRequest {
+            inner: self
+                .inner
+                .deprecated_202004_create_fulfillment_orders_param_order_close_request(
+                    fulfillment_order_id,
+                    body,
+                ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202004_create_fulfillment_orders_param_order_move`].
+    pub fn deprecated_202004_create_fulfillment_orders_param_order_move(
+        &self,
+        fulfillment_order_id: &str,
+        new_location_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_create_fulfillment_orders_param_order_move(
+                    fulfillment_order_id,
+                    new_location_id,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202004_create_fulfillment_orders_param_order_move_request`].
+    pub fn deprecated_202004_create_fulfillment_orders_param_order_move_request<'a>(
+        &'a self,
+        fulfillment_order_id: &'a str,
+        body: &'a serde_json::Value,
+    ) -> ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderMoveRequest<'a>
+    {
+        ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderMoveRequest {
+            inner: self
+                .inner
+                .deprecated_202004_create_fulfillment_orders_param_order_move_request(
+                    fulfillment_order_id,
+                    body,
+                ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202007_get_orders_param_order_fulfillment_shipping_and_fulfillment`].
+    pub fn deprecated_202007_get_orders_param_order_fulfillment_shipping_and_fulfillment(
+        &self,
+        order_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_get_orders_param_order_fulfillment_shipping_and_fulfillment(
+                    order_id,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202007_get_fulfillment_orders_param_order`].
+    pub fn deprecated_202007_get_fulfillment_orders_param_order(
+        &self,
+        fulfillment_order_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_get_fulfillment_orders_param_order(fulfillment_order_id),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202007_create_fulfillment_orders_param_order_cancel`].
+    pub fn deprecated_202007_create_fulfillment_orders_param_order_cancel(
+        &self,
+        fulfillment_order_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_create_fulfillment_orders_param_order_cancel(
+                    fulfillment_order_id,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202007_create_fulfillment_orders_param_order_close`].
+    pub fn deprecated_202007_create_fulfillment_orders_param_order_close(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_create_fulfillment_orders_param_order_close(
+                    fulfillment_order_id,
+                    message,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202007_create_fulfillment_orders_param_order_close_request`].
+    pub fn deprecated_202007_create_fulfillment_orders_param_order_close_request<'a>(
+        &'a self,
+        fulfillment_order_id: &'a str,
+        body: &'a serde_json::Value,
+    ) -> ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderCloseRequest<'a>
+    {
+        ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderCloseRequest {
+            inner: self
+                .inner
+                .deprecated_202007_create_fulfillment_orders_param_order_close_request(
+                    fulfillment_order_id,
+                    body,
+                ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202007_create_fulfillment_orders_param_order_move`].
+    pub fn deprecated_202007_create_fulfillment_orders_param_order_move(
+        &self,
+        fulfillment_order_id: &str,
+        new_location_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_create_fulfillment_orders_param_order_move(
+                    fulfillment_order_id,
+                    new_location_id,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202007_create_fulfillment_orders_param_order_move_request`].
+    pub fn deprecated_202007_create_fulfillment_orders_param_order_move_request<'a>(
+        &'a self,
+        fulfillment_order_id: &'a str,
+        body: &'a serde_json::Value,
+    ) -> ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderMoveRequest<'a>
+    {
+        ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderMoveRequest {
+            inner: self
+                .inner
+                .deprecated_202007_create_fulfillment_orders_param_order_move_request(
+                    fulfillment_order_id,
+                    body,
+                ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::get_orders_param_order_fulfillment_shipping_and_fulfillment`].
+    pub fn get_orders_param_order_fulfillment_shipping_and_fulfillment(
+        &self,
+        order_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .get_orders_param_order_fulfillment_shipping_and_fulfillment(order_id),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::get_fulfillment_orders_param_order`].
+    pub fn get_fulfillment_orders_param_order(
+        &self,
+        fulfillment_order_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .get_fulfillment_orders_param_order(fulfillment_order_id),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::create_fulfillment_orders_param_order_cancel`].
+    pub fn create_fulfillment_orders_param_order_cancel(
+        &self,
+        fulfillment_order_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .create_fulfillment_orders_param_order_cancel(fulfillment_order_id, body),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::create_fulfillment_orders_param_order_close`].
+    pub fn create_fulfillment_orders_param_order_close(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.create_fulfillment_orders_param_order_close(
+                fulfillment_order_id,
+                message,
+                body,
+            ))
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::create_fulfillment_orders_param_order_close_request`].
+    pub fn create_fulfillment_orders_param_order_close_request<'a>(
+        &'a self,
+        fulfillment_order_id: &'a str,
+        body: &'a serde_json::Value,
+    ) -> ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderCloseRequest<'a> {
+        ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderCloseRequest {
+            inner: self
+                .inner
+                .create_fulfillment_orders_param_order_close_request(fulfillment_order_id, body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::create_fulfillment_orders_param_order_move`].
+    pub fn create_fulfillment_orders_param_order_move(
+        &self,
+        fulfillment_order_id: &str,
+        new_location_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.create_fulfillment_orders_param_order_move(
+                fulfillment_order_id,
+                new_location_id,
+                body,
+            ))
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::create_fulfillment_orders_param_order_move_request`].
+    pub fn create_fulfillment_orders_param_order_move_request<'a>(
+        &'a self,
+        fulfillment_order_id: &'a str,
+        body: &'a serde_json::Value,
+    ) -> ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderMoveRequest<'a> {
+        ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderMoveRequest {
+            inner: self
+                .inner
+                .create_fulfillment_orders_param_order_move_request(fulfillment_order_id, body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202101_get_orders_param_order_fulfillment_shipping_and_fulfillment`].
+    pub fn deprecated_202101_get_orders_param_order_fulfillment_shipping_and_fulfillment(
+        &self,
+        order_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_get_orders_param_order_fulfillment_shipping_and_fulfillment(
+                    order_id,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202101_get_fulfillment_orders_param_order`].
+    pub fn deprecated_202101_get_fulfillment_orders_param_order(
+        &self,
+        fulfillment_order_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_get_fulfillment_orders_param_order(fulfillment_order_id),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202101_create_fulfillment_orders_param_order_cancel`].
+    pub fn deprecated_202101_create_fulfillment_orders_param_order_cancel(
+        &self,
+        fulfillment_order_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_create_fulfillment_orders_param_order_cancel(
+                    fulfillment_order_id,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202101_create_fulfillment_orders_param_order_close`].
+    pub fn deprecated_202101_create_fulfillment_orders_param_order_close(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_create_fulfillment_orders_param_order_close(
+                    fulfillment_order_id,
+                    message,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202101_create_fulfillment_orders_param_order_close_request`].
+    pub fn deprecated_202101_create_fulfillment_orders_param_order_close_request<'a>(
+        &'a self,
+        fulfillment_order_id: &'a str,
+        body: &'a serde_json::Value,
+    ) -> ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderCloseRequest<'a>
+    {
+        ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderCloseRequest {
+            inner: self
+                .inner
+                .deprecated_202101_create_fulfillment_orders_param_order_close_request(
+                    fulfillment_order_id,
+                    body,
+                ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202101_create_fulfillment_orders_param_order_move`].
+    pub fn deprecated_202101_create_fulfillment_orders_param_order_move(
+        &self,
+        fulfillment_order_id: &str,
+        new_location_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_create_fulfillment_orders_param_order_move(
+                    fulfillment_order_id,
+                    new_location_id,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202101_create_fulfillment_orders_param_order_move_request`].
+    pub fn deprecated_202101_create_fulfillment_orders_param_order_move_request<'a>(
+        &'a self,
+        fulfillment_order_id: &'a str,
+        body: &'a serde_json::Value,
+    ) -> ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderMoveRequest<'a>
+    {
+        ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderMoveRequest {
+            inner: self
+                .inner
+                .deprecated_202101_create_fulfillment_orders_param_order_move_request(
+                    fulfillment_order_id,
+                    body,
+                ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202101_create_fulfillment_orders_param_order_open`].
+    pub fn deprecated_202101_create_fulfillment_orders_param_order_open(
+        &self,
+        fulfillment_order_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_create_fulfillment_orders_param_order_open(
+                    fulfillment_order_id,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202101_create_fulfillment_orders_param_order_reschedule`].
+    pub fn deprecated_202101_create_fulfillment_orders_param_order_reschedule(
+        &self,
+        fulfillment_order_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_create_fulfillment_orders_param_order_reschedule(
+                    fulfillment_order_id,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_unstable_get_orders_param_order_fulfillment_shipping_and_fulfillment`].
+    pub fn deprecated_unstable_get_orders_param_order_fulfillment_shipping_and_fulfillment(
+        &self,
+        order_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_get_orders_param_order_fulfillment_shipping_and_fulfillment(
+                    order_id,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_unstable_get_fulfillment_orders_param_order`].
+    pub fn deprecated_unstable_get_fulfillment_orders_param_order(
+        &self,
+        fulfillment_order_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_get_fulfillment_orders_param_order(fulfillment_order_id),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_unstable_create_fulfillment_orders_param_order_cancel`].
+    pub fn deprecated_unstable_create_fulfillment_orders_param_order_cancel(
+        &self,
+        fulfillment_order_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_create_fulfillment_orders_param_order_cancel(
+                    fulfillment_order_id,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_unstable_create_fulfillment_orders_release_hold`].
+    pub fn deprecated_unstable_create_fulfillment_orders_release_hold(
+        &self,
+        order_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_create_fulfillment_orders_release_hold(order_id, body),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_unstable_create_fulfillment_orders_release_hold_request`].
+    pub fn deprecated_unstable_create_fulfillment_orders_release_hold_request<'a>(
+        &'a self,
+        body: &'a serde_json::Value,
+    ) -> ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersReleaseHoldRequest<'a> {
+        ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersReleaseHoldRequest {
+            inner: self
+                .inner
+                .deprecated_unstable_create_fulfillment_orders_release_hold_request(body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_unstable_create_fulfillment_orders_param_order_close`].
+    pub fn deprecated_unstable_create_fulfillment_orders_param_order_close(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_create_fulfillment_orders_param_order_close(
+                    fulfillment_order_id,
+                    message,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_unstable_create_fulfillment_orders_param_order_close_request`].
+    pub fn deprecated_unstable_create_fulfillment_orders_param_order_close_request<'a>(
+        &'a self,
+        fulfillment_order_id: &'a str,
+        body: &'a serde_json::Value,
+    ) -> ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderCloseRequest<'a>
+    {
+        ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderCloseRequest {
+            inner: self
+                .inner
+                .deprecated_unstable_create_fulfillment_orders_param_order_close_request(
+                    fulfillment_order_id,
+                    body,
+                ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_unstable_create_fulfillment_orders_param_order_move`].
+    pub fn deprecated_unstable_create_fulfillment_orders_param_order_move(
+        &self,
+        fulfillment_order_id: &str,
+        new_location_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_create_fulfillment_orders_param_order_move(
+                    fulfillment_order_id,
+                    new_location_id,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_unstable_create_fulfillment_orders_param_order_move_request`].
+    pub fn deprecated_unstable_create_fulfillment_orders_param_order_move_request<'a>(
+        &'a self,
+        fulfillment_order_id: &'a str,
+        body: &'a serde_json::Value,
+    ) -> ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderMoveRequest<'a>
+    {
+        ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderMoveRequest {
+            inner: self
+                .inner
+                .deprecated_unstable_create_fulfillment_orders_param_order_move_request(
+                    fulfillment_order_id,
+                    body,
+                ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_unstable_create_fulfillment_orders_param_order_open`].
+    pub fn deprecated_unstable_create_fulfillment_orders_param_order_open(
+        &self,
+        fulfillment_order_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_create_fulfillment_orders_param_order_open(
+                    fulfillment_order_id,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_unstable_create_fulfillment_orders_param_order_reschedule`].
+    pub fn deprecated_unstable_create_fulfillment_orders_param_order_reschedule(
+        &self,
+        fulfillment_order_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_create_fulfillment_orders_param_order_reschedule(
+                    fulfillment_order_id,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202001_create_fulfillment_orders_param_order_request`].
+    pub fn deprecated_202001_create_fulfillment_orders_param_order_request(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        fulfillment_order_line_items: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_create_fulfillment_orders_param_order_request(
+                    fulfillment_order_id,
+                    message,
+                    fulfillment_order_line_items,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202001_create_fulfillment_orders_param_order_request_request`].
+    pub fn deprecated_202001_create_fulfillment_orders_param_order_request_request<'a>(
+        &'a self,
+        fulfillment_order_id: &'a str,
+        body: &'a serde_json::Value,
+    ) -> ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderRequestRequest<'a>
+    {
+        ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderRequestRequest {
+            inner: self
+                .inner
+                .deprecated_202001_create_fulfillment_orders_param_order_request_request(
+                    fulfillment_order_id,
+                    body,
+                ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202001_create_fulfillment_orders_param_order_request_accept`].
+    pub fn deprecated_202001_create_fulfillment_orders_param_order_request_accept(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_create_fulfillment_orders_param_order_request_accept(
+                    fulfillment_order_id,
+                    message,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202001_create_fulfillment_orders_param_order_request_accept_request`].
+    pub fn deprecated_202001_create_fulfillment_orders_param_order_request_accept_request<'a>(&'a self, fulfillment_order_id: &'a str, body: &'a serde_json::Value,) -> ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderRequestAcceptRequest<'a>{
+        ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderRequestAcceptRequest { inner: self.inner.deprecated_202001_create_fulfillment_orders_param_order_request_accept_request(fulfillment_order_id, body), runtime: &self.runtime }
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202001_create_fulfillment_orders_param_order_request_reject`].
+    pub fn deprecated_202001_create_fulfillment_orders_param_order_request_reject(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_create_fulfillment_orders_param_order_request_reject(
+                    fulfillment_order_id,
+                    message,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202001_create_fulfillment_orders_param_order_request_reject_request`].
+    pub fn deprecated_202001_create_fulfillment_orders_param_order_request_reject_request<'a>(&'a self, fulfillment_order_id: &'a str, body: &'a serde_json::Value,) -> ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderRequestRejectRequest<'a>{
+        ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderRequestRejectRequest { inner: self.inner.deprecated_202001_create_fulfillment_orders_param_order_request_reject_request(fulfillment_order_id, body), runtime: &self.runtime }
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202004_create_fulfillment_orders_param_order_request`].
+    pub fn deprecated_202004_create_fulfillment_orders_param_order_request(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        fulfillment_order_line_items: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_create_fulfillment_orders_param_order_request(
+                    fulfillment_order_id,
+                    message,
+                    fulfillment_order_line_items,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202004_create_fulfillment_orders_param_order_request_request`].
+    pub fn deprecated_202004_create_fulfillment_orders_param_order_request_request<'a>(
+        &'a self,
+        fulfillment_order_id: &'a str,
+        body: &'a serde_json::Value,
+    ) -> ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderRequestRequest<'a>
+    {
+        ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderRequestRequest {
+            inner: self
+                .inner
+                .deprecated_202004_create_fulfillment_orders_param_order_request_request(
+                    fulfillment_order_id,
+                    body,
+                ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202004_create_fulfillment_orders_param_order_request_accept`].
+    pub fn deprecated_202004_create_fulfillment_orders_param_order_request_accept(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_create_fulfillment_orders_param_order_request_accept(
+                    fulfillment_order_id,
+                    message,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202004_create_fulfillment_orders_param_order_request_accept_request`].
+    pub fn deprecated_202004_create_fulfillment_orders_param_order_request_accept_request<'a>(&'a self, fulfillment_order_id: &'a str, body: &'a serde_json::Value,) -> ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderRequestAcceptRequest<'a>{
+        ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderRequestAcceptRequest { inner: self.inner.deprecated_202004_create_fulfillment_orders_param_order_request_accept_request(fulfillment_order_id, body), runtime: &self.runtime }
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202004_create_fulfillment_orders_param_order_request_reject`].
+    pub fn deprecated_202004_create_fulfillment_orders_param_order_request_reject(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_create_fulfillment_orders_param_order_request_reject(
+                    fulfillment_order_id,
+                    message,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202004_create_fulfillment_orders_param_order_request_reject_request`].
+    pub fn deprecated_202004_create_fulfillment_orders_param_order_request_reject_request<'a>(&'a self, fulfillment_order_id: &'a str, body: &'a serde_json::Value,) -> ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderRequestRejectRequest<'a>{
+        ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderRequestRejectRequest { inner: self.inner.deprecated_202004_create_fulfillment_orders_param_order_request_reject_request(fulfillment_order_id, body), runtime: &self.runtime }
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202007_create_fulfillment_orders_param_order_request`].
+    pub fn deprecated_202007_create_fulfillment_orders_param_order_request(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        fulfillment_order_line_items: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_create_fulfillment_orders_param_order_request(
+                    fulfillment_order_id,
+                    message,
+                    fulfillment_order_line_items,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202007_create_fulfillment_orders_param_order_request_request`].
+    pub fn deprecated_202007_create_fulfillment_orders_param_order_request_request<'a>(
+        &'a self,
+        fulfillment_order_id: &'a str,
+        body: &'a serde_json::Value,
+    ) -> ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderRequestRequest<'a>
+    {
+        ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderRequestRequest {
+            inner: self
+                .inner
+                .deprecated_202007_create_fulfillment_orders_param_order_request_request(
+                    fulfillment_order_id,
+                    body,
+                ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202007_create_fulfillment_orders_param_order_request_accept`].
+    pub fn deprecated_202007_create_fulfillment_orders_param_order_request_accept(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_create_fulfillment_orders_param_order_request_accept(
+                    fulfillment_order_id,
+                    message,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202007_create_fulfillment_orders_param_order_request_accept_request`].
+    pub fn deprecated_202007_create_fulfillment_orders_param_order_request_accept_request<'a>(&'a self, fulfillment_order_id: &'a str, body: &'a serde_json::Value,) -> ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderRequestAcceptRequest<'a>{
+        ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderRequestAcceptRequest { inner: self.inner.deprecated_202007_create_fulfillment_orders_param_order_request_accept_request(fulfillment_order_id, body), runtime: &self.runtime }
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202007_create_fulfillment_orders_param_order_request_reject`].
+    pub fn deprecated_202007_create_fulfillment_orders_param_order_request_reject(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_create_fulfillment_orders_param_order_request_reject(
+                    fulfillment_order_id,
+                    message,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202007_create_fulfillment_orders_param_order_request_reject_request`].
+    pub fn deprecated_202007_create_fulfillment_orders_param_order_request_reject_request<'a>(&'a self, fulfillment_order_id: &'a str, body: &'a serde_json::Value,) -> ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderRequestRejectRequest<'a>{
+        ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderRequestRejectRequest { inner: self.inner.deprecated_202007_create_fulfillment_orders_param_order_request_reject_request(fulfillment_order_id, body), runtime: &self.runtime }
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::create_fulfillment_orders_param_order_request`].
+    pub fn create_fulfillment_orders_param_order_request(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        fulfillment_order_line_items: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.create_fulfillment_orders_param_order_request(
+                fulfillment_order_id,
+                message,
+                fulfillment_order_line_items,
+                body,
+            ))
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::create_fulfillment_orders_param_order_request_request`].
+    pub fn create_fulfillment_orders_param_order_request_request<'a>(
+        &'a self,
+        fulfillment_order_id: &'a str,
+        body: &'a serde_json::Value,
+    ) -> ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderRequestRequest<'a> {
+        ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderRequestRequest {
+            inner: self
+                .inner
+                .create_fulfillment_orders_param_order_request_request(fulfillment_order_id, body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::create_fulfillment_orders_param_order_request_accept`].
+    pub fn create_fulfillment_orders_param_order_request_accept(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .create_fulfillment_orders_param_order_request_accept(
+                    fulfillment_order_id,
+                    message,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::create_fulfillment_orders_param_order_request_accept_request`].
+    pub fn create_fulfillment_orders_param_order_request_accept_request<'a>(
+        &'a self,
+        fulfillment_order_id: &'a str,
+        body: &'a serde_json::Value,
+    ) -> ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderRequestAcceptRequest<'a> {
+        ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderRequestAcceptRequest {
+            inner: self
+                .inner
+                .create_fulfillment_orders_param_order_request_accept_request(
+                    fulfillment_order_id,
+                    body,
+                ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::create_fulfillment_orders_param_order_request_reject`].
+    pub fn create_fulfillment_orders_param_order_request_reject(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .create_fulfillment_orders_param_order_request_reject(
+                    fulfillment_order_id,
+                    message,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::create_fulfillment_orders_param_order_request_reject_request`].
+    pub fn create_fulfillment_orders_param_order_request_reject_request<'a>(
+        &'a self,
+        fulfillment_order_id: &'a str,
+        body: &'a serde_json::Value,
+    ) -> ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderRequestRejectRequest<'a> {
+        ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderRequestRejectRequest {
+            inner: self
+                .inner
+                .create_fulfillment_orders_param_order_request_reject_request(
+                    fulfillment_order_id,
+                    body,
+                ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202101_create_fulfillment_orders_param_order_request`].
+    pub fn deprecated_202101_create_fulfillment_orders_param_order_request(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        fulfillment_order_line_items: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_create_fulfillment_orders_param_order_request(
+                    fulfillment_order_id,
+                    message,
+                    fulfillment_order_line_items,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202101_create_fulfillment_orders_param_order_request_request`].
+    pub fn deprecated_202101_create_fulfillment_orders_param_order_request_request<'a>(
+        &'a self,
+        fulfillment_order_id: &'a str,
+        body: &'a serde_json::Value,
+    ) -> ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderRequestRequest<'a>
+    {
+        ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderRequestRequest {
+            inner: self
+                .inner
+                .deprecated_202101_create_fulfillment_orders_param_order_request_request(
+                    fulfillment_order_id,
+                    body,
+                ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202101_create_fulfillment_orders_param_order_request_accept`].
+    pub fn deprecated_202101_create_fulfillment_orders_param_order_request_accept(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_create_fulfillment_orders_param_order_request_accept(
+                    fulfillment_order_id,
+                    message,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202101_create_fulfillment_orders_param_order_request_accept_request`].
+    pub fn deprecated_202101_create_fulfillment_orders_param_order_request_accept_request<'a>(&'a self, fulfillment_order_id: &'a str, body: &'a serde_json::Value,) -> ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderRequestAcceptRequest<'a>{
+        ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderRequestAcceptRequest { inner: self.inner.deprecated_202101_create_fulfillment_orders_param_order_request_accept_request(fulfillment_order_id, body), runtime: &self.runtime }
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202101_create_fulfillment_orders_param_order_request_reject`].
+    pub fn deprecated_202101_create_fulfillment_orders_param_order_request_reject(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_create_fulfillment_orders_param_order_request_reject(
+                    fulfillment_order_id,
+                    message,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202101_create_fulfillment_orders_param_order_request_reject_request`].
+    pub fn deprecated_202101_create_fulfillment_orders_param_order_request_reject_request<'a>(&'a self, fulfillment_order_id: &'a str, body: &'a serde_json::Value,) -> ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderRequestRejectRequest<'a>{
+        ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderRequestRejectRequest { inner: self.inner.deprecated_202101_create_fulfillment_orders_param_order_request_reject_request(fulfillment_order_id, body), runtime: &self.runtime }
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_unstable_create_fulfillment_orders_param_order_request`].
+    pub fn deprecated_unstable_create_fulfillment_orders_param_order_request(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        fulfillment_order_line_items: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_create_fulfillment_orders_param_order_request(
+                    fulfillment_order_id,
+                    message,
+                    fulfillment_order_line_items,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_unstable_create_fulfillment_orders_param_order_request_request`].
+    pub fn deprecated_unstable_create_fulfillment_orders_param_order_request_request<'a>(
+        &'a self,
+        fulfillment_order_id: &'a str,
+        body: &'a serde_json::Value,
+    ) -> ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderRequestRequest<'a>
+    {
+        ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderRequestRequest {
+            inner: self
+                .inner
+                .deprecated_unstable_create_fulfillment_orders_param_order_request_request(
+                    fulfillment_order_id,
+                    body,
+                ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_unstable_create_fulfillment_orders_param_order_request_accept`].
+    pub fn deprecated_unstable_create_fulfillment_orders_param_order_request_accept(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_create_fulfillment_orders_param_order_request_accept(
+                    fulfillment_order_id,
+                    message,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_unstable_create_fulfillment_orders_param_order_request_accept_request`].
+    pub fn deprecated_unstable_create_fulfillment_orders_param_order_request_accept_request<'a>(&'a self, fulfillment_order_id: &'a str, body: &'a serde_json::Value,) -> ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderRequestAcceptRequest<'a>{
+        ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderRequestAcceptRequest { inner: self.inner.deprecated_unstable_create_fulfillment_orders_param_order_request_accept_request(fulfillment_order_id, body), runtime: &self.runtime }
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_unstable_create_fulfillment_orders_param_order_request_reject`].
+    pub fn deprecated_unstable_create_fulfillment_orders_param_order_request_reject(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_create_fulfillment_orders_param_order_request_reject(
+                    fulfillment_order_id,
+                    message,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_unstable_create_fulfillment_orders_param_order_request_reject_request`].
+    pub fn deprecated_unstable_create_fulfillment_orders_param_order_request_reject_request<'a>(&'a self, fulfillment_order_id: &'a str, body: &'a serde_json::Value,) -> ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderRequestRejectRequest<'a>{
+        ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderRequestRejectRequest { inner: self.inner.deprecated_unstable_create_fulfillment_orders_param_order_request_reject_request(fulfillment_order_id, body), runtime: &self.runtime }
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_unknown_version_get_fetch_tracking_number`].
+    pub fn deprecated_unknown_version_get_fetch_tracking_number(
+        &self,
+        order_names: &str,
+        shop: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unknown_version_get_fetch_tracking_number(order_names, shop),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_unknown_version_get_fetch_tracking_number_request`].
+    pub fn deprecated_unknown_version_get_fetch_tracking_number_request(
+        &self,
+    ) -> ShippingAndFulfillmentDeprecatedUnknownVersionGetFetchTrackingNumberRequest<'_> {
+        ShippingAndFulfillmentDeprecatedUnknownVersionGetFetchTrackingNumberRequest {
+            inner: self
+                .inner
+                .deprecated_unknown_version_get_fetch_tracking_number_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_unknown_version_get_fetch_stock`].
+    pub fn deprecated_unknown_version_get_fetch_stock(
+        &self,
+        max_retries: &str,
+        shop: &str,
+        sku: &str,
+        timestamp: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_unknown_version_get_fetch_stock(
+                max_retries,
+                shop,
+                sku,
+                timestamp,
+            ))
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_unknown_version_get_fetch_stock_request`].
+    pub fn deprecated_unknown_version_get_fetch_stock_request(
+        &self,
+    ) -> ShippingAndFulfillmentDeprecatedUnknownVersionGetFetchStockRequest<'_> {
+        ShippingAndFulfillmentDeprecatedUnknownVersionGetFetchStockRequest {
+            inner: self
+                .inner
+                .deprecated_unknown_version_get_fetch_stock_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202001_get_fulfillment_service`].
+    pub fn deprecated_202001_get_fulfillment_service(
+        &self,
+        scope: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202001_get_fulfillment_service(scope))
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202001_get_fulfillment_service_request`].
+    pub fn deprecated_202001_get_fulfillment_service_request(
+        &self,
+    ) -> ShippingAndFulfillmentDeprecated202001GetFulfillmentServiceRequest<'_> {
+        ShippingAndFulfillmentDeprecated202001GetFulfillmentServiceRequest {
+            inner: self
+                .inner
+                .deprecated_202001_get_fulfillment_service_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202001_create_fulfillment_services`].
+    pub fn deprecated_202001_create_fulfillment_services(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_create_fulfillment_services(body),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202001_get_fulfillment_services_param_service`].
+    pub fn deprecated_202001_get_fulfillment_services_param_service(
+        &self,
+        fulfillment_service_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_get_fulfillment_services_param_service(fulfillment_service_id),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202001_update_fulfillment_services_param_service`].
+    pub fn deprecated_202001_update_fulfillment_services_param_service(
+        &self,
+        fulfillment_service_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_update_fulfillment_services_param_service(
+                    fulfillment_service_id,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202001_delete_fulfillment_services_param_service`].
+    pub fn deprecated_202001_delete_fulfillment_services_param_service(
+        &self,
+        fulfillment_service_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_delete_fulfillment_services_param_service(
+                    fulfillment_service_id,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202004_get_fulfillment_service`].
+    pub fn deprecated_202004_get_fulfillment_service(
+        &self,
+        scope: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202004_get_fulfillment_service(scope))
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202004_get_fulfillment_service_request`].
+    pub fn deprecated_202004_get_fulfillment_service_request(
+        &self,
+    ) -> ShippingAndFulfillmentDeprecated202004GetFulfillmentServiceRequest<'_> {
+        ShippingAndFulfillmentDeprecated202004GetFulfillmentServiceRequest {
+            inner: self
+                .inner
+                .deprecated_202004_get_fulfillment_service_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202004_create_fulfillment_services`].
+    pub fn deprecated_202004_create_fulfillment_services(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_create_fulfillment_services(body),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202004_get_fulfillment_services_param_service`].
+    pub fn deprecated_202004_get_fulfillment_services_param_service(
+        &self,
+        fulfillment_service_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_get_fulfillment_services_param_service(fulfillment_service_id),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202004_update_fulfillment_services_param_service`].
+    pub fn deprecated_202004_update_fulfillment_services_param_service(
+        &self,
+        fulfillment_service_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_update_fulfillment_services_param_service(
+                    fulfillment_service_id,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202004_delete_fulfillment_services_param_service`].
+    pub fn deprecated_202004_delete_fulfillment_services_param_service(
+        &self,
+        fulfillment_service_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_delete_fulfillment_services_param_service(
+                    fulfillment_service_id,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202007_get_fulfillment_service`].
+    pub fn deprecated_202007_get_fulfillment_service(
+        &self,
+        scope: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202007_get_fulfillment_service(scope))
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202007_get_fulfillment_service_request`].
+    pub fn deprecated_202007_get_fulfillment_service_request(
+        &self,
+    ) -> ShippingAndFulfillmentDeprecated202007GetFulfillmentServiceRequest<'_> {
+        ShippingAndFulfillmentDeprecated202007GetFulfillmentServiceRequest {
+            inner: self
+                .inner
+                .deprecated_202007_get_fulfillment_service_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202007_create_fulfillment_services`].
+    pub fn deprecated_202007_create_fulfillment_services(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_create_fulfillment_services(body),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202007_get_fulfillment_services_param_service`].
+    pub fn deprecated_202007_get_fulfillment_services_param_service(
+        &self,
+        fulfillment_service_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_get_fulfillment_services_param_service(fulfillment_service_id),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202007_update_fulfillment_services_param_service`].
+    pub fn deprecated_202007_update_fulfillment_services_param_service(
+        &self,
+        fulfillment_service_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_update_fulfillment_services_param_service(
+                    fulfillment_service_id,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202007_delete_fulfillment_services_param_service`].
+    pub fn deprecated_202007_delete_fulfillment_services_param_service(
+        &self,
+        fulfillment_service_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_delete_fulfillment_services_param_service(
+                    fulfillment_service_id,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::get_fulfillment_service`].
+    pub fn get_fulfillment_service(&self, scope: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.get_fulfillment_service(scope))
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::get_fulfillment_service_request`].
+    pub fn get_fulfillment_service_request(
+        &self,
+    ) -> ShippingAndFulfillmentGetFulfillmentServiceRequest<'_> {
+        ShippingAndFulfillmentGetFulfillmentServiceRequest {
+            inner: self.inner.get_fulfillment_service_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::create_fulfillment_services`].
+    pub fn create_fulfillment_services(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.create_fulfillment_services(body))
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::get_fulfillment_services_param_service`].
+    pub fn get_fulfillment_services_param_service(
+        &self,
+        fulfillment_service_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .get_fulfillment_services_param_service(fulfillment_service_id),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::update_fulfillment_services_param_service`].
+    pub fn update_fulfillment_services_param_service(
+        &self,
+        fulfillment_service_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .update_fulfillment_services_param_service(fulfillment_service_id, body),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::delete_fulfillment_services_param_service`].
+    pub fn delete_fulfillment_services_param_service(
+        &self,
+        fulfillment_service_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .delete_fulfillment_services_param_service(fulfillment_service_id),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202101_get_fulfillment_service`].
+    pub fn deprecated_202101_get_fulfillment_service(
+        &self,
+        scope: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202101_get_fulfillment_service(scope))
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202101_get_fulfillment_service_request`].
+    pub fn deprecated_202101_get_fulfillment_service_request(
+        &self,
+    ) -> ShippingAndFulfillmentDeprecated202101GetFulfillmentServiceRequest<'_> {
+        ShippingAndFulfillmentDeprecated202101GetFulfillmentServiceRequest {
+            inner: self
+                .inner
+                .deprecated_202101_get_fulfillment_service_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202101_create_fulfillment_services`].
+    pub fn deprecated_202101_create_fulfillment_services(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_create_fulfillment_services(body),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202101_get_fulfillment_services_param_service`].
+    pub fn deprecated_202101_get_fulfillment_services_param_service(
+        &self,
+        fulfillment_service_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_get_fulfillment_services_param_service(fulfillment_service_id),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202101_update_fulfillment_services_param_service`].
+    pub fn deprecated_202101_update_fulfillment_services_param_service(
+        &self,
+        fulfillment_service_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_update_fulfillment_services_param_service(
+                    fulfillment_service_id,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202101_delete_fulfillment_services_param_service`].
+    pub fn deprecated_202101_delete_fulfillment_services_param_service(
+        &self,
+        fulfillment_service_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_delete_fulfillment_services_param_service(
+                    fulfillment_service_id,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_unstable_get_fulfillment_service`].
+    pub fn deprecated_unstable_get_fulfillment_service(
+        &self,
+        scope: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_get_fulfillment_service(scope),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_unstable_get_fulfillment_service_request`].
+    pub fn deprecated_unstable_get_fulfillment_service_request(
+        &self,
+    ) -> ShippingAndFulfillmentDeprecatedUnstableGetFulfillmentServiceRequest<'_> {
+        ShippingAndFulfillmentDeprecatedUnstableGetFulfillmentServiceRequest {
+            inner: self
+                .inner
+                .deprecated_unstable_get_fulfillment_service_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_unstable_create_fulfillment_services`].
+    pub fn deprecated_unstable_create_fulfillment_services(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_create_fulfillment_services(body),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_unstable_get_fulfillment_services_param_service`].
+    pub fn deprecated_unstable_get_fulfillment_services_param_service(
+        &self,
+        fulfillment_service_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_get_fulfillment_services_param_service(fulfillment_service_id),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_unstable_update_fulfillment_services_param_service`].
+    pub fn deprecated_unstable_update_fulfillment_services_param_service(
+        &self,
+        fulfillment_service_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_update_fulfillment_services_param_service(
+                    fulfillment_service_id,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_unstable_delete_fulfillment_services_param_service`].
+    pub fn deprecated_unstable_delete_fulfillment_services_param_service(
+        &self,
+        fulfillment_service_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_delete_fulfillment_services_param_service(
+                    fulfillment_service_id,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202001_get_fulfillment_orders_param_order_locations_for_move`].
+    pub fn deprecated_202001_get_fulfillment_orders_param_order_locations_for_move(
+        &self,
+        fulfillment_order_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_get_fulfillment_orders_param_order_locations_for_move(
+                    fulfillment_order_id,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202004_get_fulfillment_orders_param_order_locations_for_move`].
+    pub fn deprecated_202004_get_fulfillment_orders_param_order_locations_for_move(
+        &self,
+        fulfillment_order_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_get_fulfillment_orders_param_order_locations_for_move(
+                    fulfillment_order_id,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202007_get_fulfillment_orders_param_order_locations_for_move`].
+    pub fn deprecated_202007_get_fulfillment_orders_param_order_locations_for_move(
+        &self,
+        fulfillment_order_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_get_fulfillment_orders_param_order_locations_for_move(
+                    fulfillment_order_id,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::get_fulfillment_orders_param_order_locations_for_move`].
+    pub fn get_fulfillment_orders_param_order_locations_for_move(
+        &self,
+        fulfillment_order_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .get_fulfillment_orders_param_order_locations_for_move(fulfillment_order_id),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_202101_get_fulfillment_orders_param_order_locations_for_move`].
+    pub fn deprecated_202101_get_fulfillment_orders_param_order_locations_for_move(
+        &self,
+        fulfillment_order_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_get_fulfillment_orders_param_order_locations_for_move(
+                    fulfillment_order_id,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::shipping_and_fulfillment::ShippingAndFulfillment::deprecated_unstable_get_fulfillment_orders_param_order_locations_for_move`].
+    pub fn deprecated_unstable_get_fulfillment_orders_param_order_locations_for_move(
+        &self,
+        fulfillment_order_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_get_fulfillment_orders_param_order_locations_for_move(
+                    fulfillment_order_id,
+                ),
+        )
+    }
+}
@@ -0,0 +1,746 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`ShopifyPayments::deprecated_202001_get_dispute`], set with a builder.
+pub struct ShopifyPaymentsDeprecated202001GetDisputeRequest<'a> {
+    inner: crate::shopify_payments::ShopifyPaymentsDeprecated202001GetDisputeRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ShopifyPaymentsDeprecated202001GetDisputeRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `last_id` query parameter.
+    pub fn last_id(mut self, last_id: &'a str) -> Self {
+        self.inner = self.inner.last_id(last_id);
+        self
+    }
+
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: &'a str) -> Self {
+        self.inner = self.inner.status(status);
+        self
+    }
+
+    /// Sets the `initiated_at` query parameter.
+    pub fn initiated_at(mut self, initiated_at: &'a str) -> Self {
+        self.inner = self.inner.initiated_at(initiated_at);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`ShopifyPayments::deprecated_202004_get_dispute`], set with a builder.
+pub struct ShopifyPaymentsDeprecated202004GetDisputeRequest<'a> {
+    inner: crate::shopify_payments::ShopifyPaymentsDeprecated202004GetDisputeRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ShopifyPaymentsDeprecated202004GetDisputeRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `last_id` query parameter.
+    pub fn last_id(mut self, last_id: &'a str) -> Self {
+        self.inner = self.inner.last_id(last_id);
+        self
+    }
+
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: &'a str) -> Self {
+        self.inner = self.inner.status(status);
+        self
+    }
+
+    /// Sets the `initiated_at` query parameter.
+    pub fn initiated_at(mut self, initiated_at: &'a str) -> Self {
+        self.inner = self.inner.initiated_at(initiated_at);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`ShopifyPayments::deprecated_202001_get_payout`], set with a builder.
+pub struct ShopifyPaymentsDeprecated202001GetPayoutRequest<'a> {
+    inner: crate::shopify_payments::ShopifyPaymentsDeprecated202001GetPayoutRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ShopifyPaymentsDeprecated202001GetPayoutRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `last_id` query parameter.
+    pub fn last_id(mut self, last_id: &'a str) -> Self {
+        self.inner = self.inner.last_id(last_id);
+        self
+    }
+
+    /// Sets the `date_min` query parameter.
+    pub fn date_min(mut self, date_min: &'a str) -> Self {
+        self.inner = self.inner.date_min(date_min);
+        self
+    }
+
+    /// Sets the `date_max` query parameter.
+    pub fn date_max(mut self, date_max: &'a str) -> Self {
+        self.inner = self.inner.date_max(date_max);
+        self
+    }
+
+    /// Sets the `date` query parameter.
+    pub fn date(mut self, date: &'a str) -> Self {
+        self.inner = self.inner.date(date);
+        self
+    }
+
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: &'a str) -> Self {
+        self.inner = self.inner.status(status);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`ShopifyPayments::deprecated_202004_get_payout`], set with a builder.
+pub struct ShopifyPaymentsDeprecated202004GetPayoutRequest<'a> {
+    inner: crate::shopify_payments::ShopifyPaymentsDeprecated202004GetPayoutRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ShopifyPaymentsDeprecated202004GetPayoutRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `last_id` query parameter.
+    pub fn last_id(mut self, last_id: &'a str) -> Self {
+        self.inner = self.inner.last_id(last_id);
+        self
+    }
+
+    /// Sets the `date_min` query parameter.
+    pub fn date_min(mut self, date_min: &'a str) -> Self {
+        self.inner = self.inner.date_min(date_min);
+        self
+    }
+
+    /// Sets the `date_max` query parameter.
+    pub fn date_max(mut self, date_max: &'a str) -> Self {
+        self.inner = self.inner.date_max(date_max);
+        self
+    }
+
+    /// Sets the `date` query parameter.
+    pub fn date(mut self, date: &'a str) -> Self {
+        self.inner = self.inner.date(date);
+        self
+    }
+
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: &'a str) -> Self {
+        self.inner = self.inner.status(status);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`ShopifyPayments::deprecated_202007_get_payout`], set with a builder.
+pub struct ShopifyPaymentsDeprecated202007GetPayoutRequest<'a> {
+    inner: crate::shopify_payments::ShopifyPaymentsDeprecated202007GetPayoutRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ShopifyPaymentsDeprecated202007GetPayoutRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `last_id` query parameter.
+    pub fn last_id(mut self, last_id: &'a str) -> Self {
+        self.inner = self.inner.last_id(last_id);
+        self
+    }
+
+    /// Sets the `date_min` query parameter.
+    pub fn date_min(mut self, date_min: &'a str) -> Self {
+        self.inner = self.inner.date_min(date_min);
+        self
+    }
+
+    /// Sets the `date_max` query parameter.
+    pub fn date_max(mut self, date_max: &'a str) -> Self {
+        self.inner = self.inner.date_max(date_max);
+        self
+    }
+
+    /// Sets the `date` query parameter.
+    pub fn date(mut self, date: &'a str) -> Self {
+        self.inner = self.inner.date(date);
+        self
+    }
+
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: &'a str) -> Self {
+        self.inner = self.inner.status(status);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`ShopifyPayments::get_payout`], set with a builder.
+pub struct ShopifyPaymentsGetPayoutRequest<'a> {
+    inner: crate::shopify_payments::ShopifyPaymentsGetPayoutRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ShopifyPaymentsGetPayoutRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `last_id` query parameter.
+    pub fn last_id(mut self, last_id: &'a str) -> Self {
+        self.inner = self.inner.last_id(last_id);
+        self
+    }
+
+    /// Sets the `date_min` query parameter.
+    pub fn date_min(mut self, date_min: &'a str) -> Self {
+        self.inner = self.inner.date_min(date_min);
+        self
+    }
+
+    /// Sets the `date_max` query parameter.
+    pub fn date_max(mut self, date_max: &'a str) -> Self {
+        self.inner = self.inner.date_max(date_max);
+        self
+    }
+
+    /// Sets the `date` query parameter.
+    pub fn date(mut self, date: &'a str) -> Self {
+        self.inner = self.inner.date(date);
+        self
+    }
+
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: &'a str) -> Self {
+        self.inner = self.inner.status(status);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`ShopifyPayments::deprecated_202101_get_payout`], set with a builder.
+pub struct ShopifyPaymentsDeprecated202101GetPayoutRequest<'a> {
+    inner: crate::shopify_payments::ShopifyPaymentsDeprecated202101GetPayoutRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ShopifyPaymentsDeprecated202101GetPayoutRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `last_id` query parameter.
+    pub fn last_id(mut self, last_id: &'a str) -> Self {
+        self.inner = self.inner.last_id(last_id);
+        self
+    }
+
+    /// Sets the `date_min` query parameter.
+    pub fn date_min(mut self, date_min: &'a str) -> Self {
+        self.inner = self.inner.date_min(date_min);
+        self
+    }
+
+    /// Sets the `date_max` query parameter.
+    pub fn date_max(mut self, date_max: &'a str) -> Self {
+        self.inner = self.inner.date_max(date_max);
+        self
+    }
+
+    /// Sets the `date` query parameter.
+    pub fn date(mut self, date: &'a str) -> Self {
+        self.inner = self.inner.date(date);
+        self
+    }
+
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: &'a str) -> Self {
+        self.inner = self.inner.status(status);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`ShopifyPayments::deprecated_unstable_get_payout`], set with a builder.
+pub struct ShopifyPaymentsDeprecatedUnstableGetPayoutRequest<'a> {
+    inner: crate::shopify_payments::ShopifyPaymentsDeprecatedUnstableGetPayoutRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ShopifyPaymentsDeprecatedUnstableGetPayoutRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `last_id` query parameter.
+    pub fn last_id(mut self, last_id: &'a str) -> Self {
+        self.inner = self.inner.last_id(last_id);
+        self
+    }
+
+    /// Sets the `date_min` query parameter.
+    pub fn date_min(mut self, date_min: &'a str) -> Self {
+        self.inner = self.inner.date_min(date_min);
+        self
+    }
+
+    /// Sets the `date_max` query parameter.
+    pub fn date_max(mut self, date_max: &'a str) -> Self {
+        self.inner = self.inner.date_max(date_max);
+        self
+    }
+
+    /// Sets the `date` query parameter.
+    pub fn date(mut self, date: &'a str) -> Self {
+        self.inner = self.inner.date(date);
+        self
+    }
+
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: &'a str) -> Self {
+        self.inner = self.inner.status(status);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`ShopifyPayments::deprecated_202001_get_balance_transaction`], set with a builder.
+pub struct ShopifyPaymentsDeprecated202001GetBalanceTransactionRequest<'a> {
+    inner: crate::shopify_payments::ShopifyPaymentsDeprecated202001GetBalanceTransactionRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ShopifyPaymentsDeprecated202001GetBalanceTransactionRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `last_id` query parameter.
+    pub fn last_id(mut self, last_id: &'a str) -> Self {
+        self.inner = self.inner.last_id(last_id);
+        self
+    }
+
+    /// Sets the `test` query parameter.
+    pub fn test(mut self, test: &'a str) -> Self {
+        self.inner = self.inner.test(test);
+        self
+    }
+
+    /// Sets the `payout_id` query parameter.
+    pub fn payout_id(mut self, payout_id: &'a str) -> Self {
+        self.inner = self.inner.payout_id(payout_id);
+        self
+    }
+
+    /// Sets the `payout_status` query parameter.
+    pub fn payout_status(mut self, payout_status: &'a str) -> Self {
+        self.inner = self.inner.payout_status(payout_status);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::shopify_payments::ShopifyPayments`].
+pub struct ShopifyPayments {
+    inner: crate::shopify_payments::ShopifyPayments,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl ShopifyPayments {
+    pub(crate) fn new(
+        inner: crate::shopify_payments::ShopifyPayments,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        ShopifyPayments { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::shopify_payments::ShopifyPayments::deprecated_202001_get_balance`].
+    pub fn deprecated_202001_get_balance(&self) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202001_get_balance())
+    }
+    /// The blocking version of [`crate::shopify_payments::ShopifyPayments::deprecated_202004_get_balance`].
+    pub fn deprecated_202004_get_balance(&self) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202004_get_balance())
+    }
+    /// The blocking version of [`crate::shopify_payments::ShopifyPayments::deprecated_202007_get_balance`].
+    pub fn deprecated_202007_get_balance(&self) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202007_get_balance())
+    }
+    /// The blocking version of [`crate::shopify_payments::ShopifyPayments::get_balance`].
+    pub fn get_balance(&self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.get_balance())
+    }
+    /// The blocking version of [`crate::shopify_payments::ShopifyPayments::deprecated_202101_get_balance`].
+    pub fn deprecated_202101_get_balance(&self) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202101_get_balance())
+    }
+    /// The blocking version of [`crate::shopify_payments::ShopifyPayments::deprecated_unstable_get_balance`].
+    pub fn deprecated_unstable_get_balance(&self) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_unstable_get_balance())
+    }
+    /// The blocking version of [`crate::shopify_payments::ShopifyPayments::deprecated_202001_get_dispute`].
+    pub fn deprecated_202001_get_dispute(
+        &self,
+        since_id: &str,
+        last_id: &str,
+        status: &str,
+        initiated_at: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202001_get_dispute(
+                since_id,
+                last_id,
+                status,
+                initiated_at,
+            ))
+    }
+    /// The blocking version of [`crate::shopify_payments::ShopifyPayments::deprecated_202001_get_dispute_request`].
+    pub fn deprecated_202001_get_dispute_request(
+        &self,
+    ) -> ShopifyPaymentsDeprecated202001GetDisputeRequest<'_> {
+        ShopifyPaymentsDeprecated202001GetDisputeRequest {
+            inner: self.inner.deprecated_202001_get_dispute_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::shopify_payments::ShopifyPayments::deprecated_202001_get_disputes_param_dispute`].
+    pub fn deprecated_202001_get_disputes_param_dispute(
+        &self,
+        dispute_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_get_disputes_param_dispute(dispute_id),
+        )
+    }
+    /// The blocking version of [`crate::shopify_payments::ShopifyPayments::deprecated_202004_get_dispute`].
+    pub fn deprecated_202004_get_dispute(
+        &self,
+        since_id: &str,
+        last_id: &str,
+        status: &str,
+        initiated_at: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202004_get_dispute(
+                since_id,
+                last_id,
+                status,
+                initiated_at,
+            ))
+    }
+    /// The blocking version of [`crate::shopify_payments::ShopifyPayments::deprecated_202004_get_dispute_request`].
+    pub fn deprecated_202004_get_dispute_request(
+        &self,
+    ) -> ShopifyPaymentsDeprecated202004GetDisputeRequest<'_> {
+        ShopifyPaymentsDeprecated202004GetDisputeRequest {
+            inner: self.inner.deprecated_202004_get_dispute_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::shopify_payments::ShopifyPayments::deprecated_202001_get_payout`].
+    pub fn deprecated_202001_get_payout(
+        &self,
+        since_id: &str,
+        last_id: &str,
+        date_min: &str,
+        date_max: &str,
+        date: &str,
+        status: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_get_payout(since_id, last_id, date_min, date_max, date, status),
+        )
+    }
+    /// The blocking version of [`crate::shopify_payments::ShopifyPayments::deprecated_202001_get_payout_request`].
+    pub fn deprecated_202001_get_payout_request(
+        &self,
+    ) -> ShopifyPaymentsDeprecated202001GetPayoutRequest<'_> {
+        ShopifyPaymentsDeprecated202001GetPayoutRequest {
+            inner: self.inner.deprecated_202001_get_payout_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::shopify_payments::ShopifyPayments::deprecated_202001_get_payouts_param_payout`].
+    pub fn deprecated_202001_get_payouts_param_payout(
+        &self,
+        payout_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_get_payouts_param_payout(payout_id),
+        )
+    }
+    /// The blocking version of [`crate::shopify_payments::ShopifyPayments::deprecated_202004_get_payout`].
+    pub fn deprecated_202004_get_payout(
+        &self,
+        since_id: &str,
+        last_id: &str,
+        date_min: &str,
+        date_max: &str,
+        date: &str,
+        status: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_get_payout(since_id, last_id, date_min, date_max, date, status),
+        )
+    }
+    /// The blocking version of [`crate::shopify_payments::ShopifyPayments::deprecated_202004_get_payout_request`].
+    pub fn deprecated_202004_get_payout_request(
+        &self,
+    ) -> ShopifyPaymentsDeprecated202004GetPayoutRequest<'_> {
+        ShopifyPaymentsDeprecated202004GetPayoutRequest {
+            inner: self.inner.deprecated_202004_get_payout_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::shopify_payments::ShopifyPayments::deprecated_202004_get_payouts_param_payout`].
+    pub fn deprecated_202004_get_payouts_param_payout(
+        &self,
+        payout_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_get_payouts_param_payout(payout_id),
+        )
+    }
+    /// The blocking version of [`crate::shopify_payments::ShopifyPayments::deprecated_202007_get_payout`].
+    pub fn deprecated_202007_get_payout(
+        &self,
+        since_id: &str,
+        last_id: &str,
+        date_min: &str,
+        date_max: &str,
+        date: &str,
+        status: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_get_payout(since_id, last_id, date_min, date_max, date, status),
+        )
+    }
+    /// The blocking version of [`crate::shopify_payments::ShopifyPayments::deprecated_202007_get_payout_request`].
+    pub fn deprecated_202007_get_payout_request(
+        &self,
+    ) -> ShopifyPaymentsDeprecated202007GetPayoutRequest<'_> {
+        ShopifyPaymentsDeprecated202007GetPayoutRequest {
+            inner: self.inner.deprecated_202007_get_payout_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::shopify_payments::ShopifyPayments::deprecated_202007_get_payouts_param_payout`].
+    pub fn deprecated_202007_get_payouts_param_payout(
+        &self,
+        payout_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_get_payouts_param_payout(payout_id),
+        )
+    }
+    /// The blocking version of [`crate::shopify_payments::ShopifyPayments::get_payout`].
+    pub fn get_payout(
+        &self,
+        since_id: &str,
+        last_id: &str,
+        date_min: &str,
+        date_max: &str,
+        date: &str,
+        status: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .get_payout(since_id, last_id, date_min, date_max, date, status),
+        )
+    }
+    /// The blocking version of [`crate::shopify_payments::ShopifyPayments::get_payout_request`].
+    pub fn get_payout_request(&self) -> ShopifyPaymentsGetPayoutRequest<'_> {
+        ShopifyPaymentsGetPayoutRequest {
+            inner: self.inner.get_payout_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::shopify_payments::ShopifyPayments::get_payouts_param_payout`].
+    pub fn get_payouts_param_payout(&self, payout_id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.get_payouts_param_payout(payout_id))
+    }
+    /// The blocking version of [`crate::shopify_payments::ShopifyPayments::deprecated_202101_get_payout`].
+    pub fn deprecated_202101_get_payout(
+        &self,
+        since_id: &str,
+        last_id: &str,
+        date_min: &str,
+        date_max: &str,
+        date: &str,
+        status: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_get_payout(since_id, last_id, date_min, date_max, date, status),
+        )
+    }
+    /// The blocking version of [`crate::shopify_payments::ShopifyPayments::deprecated_202101_get_payout_request`].
+    pub fn deprecated_202101_get_payout_request(
+        &self,
+    ) -> ShopifyPaymentsDeprecated202101GetPayoutRequest<'_> {
+        ShopifyPaymentsDeprecated202101GetPayoutRequest {
+            inner: self.inner.deprecated_202101_get_payout_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::shopify_payments::ShopifyPayments::deprecated_202101_get_payouts_param_payout`].
+    pub fn deprecated_202101_get_payouts_param_payout(
+        &self,
+        payout_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_get_payouts_param_payout(payout_id),
+        )
+    }
+    /// The blocking version of [`crate::shopify_payments::ShopifyPayments::deprecated_unstable_get_payout`].
+    pub fn deprecated_unstable_get_payout(
+        &self,
+        since_id: &str,
+        last_id: &str,
+        date_min: &str,
+        date_max: &str,
+        date: &str,
+        status: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner.deprecated_unstable_get_payout(
+                since_id, last_id, date_min, date_max, date, status,
+            ),
+        )
+    }
+    /// The blocking version of [`crate::shopify_payments::ShopifyPayments::deprecated_unstable_get_payout_request`].
+    pub fn deprecated_unstable_get_payout_request(
+        &self,
+    ) -> ShopifyPaymentsDeprecatedUnstableGetPayoutRequest<'_> {
+        ShopifyPaymentsDeprecatedUnstableGetPayoutRequest {
+            inner: self.inner.deprecated_unstable_get_payout_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::shopify_payments::ShopifyPayments::deprecated_unstable_get_payouts_param_payout`].
+    pub fn deprecated_unstable_get_payouts_param_payout(
+        &self,
+        payout_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_get_payouts_param_payout(payout_id),
+        )
+    }
+    /// The blocking version of [`crate::shopify_payments::ShopifyPayments::deprecated_202001_get_balance_transaction`].
+    pub fn deprecated_202001_get_balance_transaction(
+        &self,
+        since_id: &str,
+        last_id: &str,
+        test: &str,
+        payout_id: &str,
+        payout_status: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202001_get_balance_transaction(
+                since_id,
+                last_id,
+                test,
+                payout_id,
+                payout_status,
+            ))
+    }
+    /// The blocking version of [`crate::shopify_payments::ShopifyPayments::deprecated_202001_get_balance_transaction_request`].
+    pub fn deprecated_202001_get_balance_transaction_request(
+        &self,
+    ) -> ShopifyPaymentsDeprecated202001GetBalanceTransactionRequest<'_> {
+        ShopifyPaymentsDeprecated202001GetBalanceTransactionRequest {
+            inner: self
+                .inner
+                .deprecated_202001_get_balance_transaction_request(),
+            runtime: &self.runtime,
+        }
+    }
+}
@@ -0,0 +1,1945 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`StoreProperties::deprecated_202001_get_countrie`], set with a builder.
+pub struct StorePropertiesDeprecated202001GetCountrieRequest<'a> {
+    inner: crate::store_properties::StorePropertiesDeprecated202001GetCountrieRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> StorePropertiesDeprecated202001GetCountrieRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`StoreProperties::deprecated_202001_get_countries_param_country`], set with a builder.
+pub struct StorePropertiesDeprecated202001GetCountriesParamCountryRequest<'a> {
+    inner:
+        crate::store_properties::StorePropertiesDeprecated202001GetCountriesParamCountryRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> StorePropertiesDeprecated202001GetCountriesParamCountryRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`StoreProperties::deprecated_202004_get_countrie`], set with a builder.
+pub struct StorePropertiesDeprecated202004GetCountrieRequest<'a> {
+    inner: crate::store_properties::StorePropertiesDeprecated202004GetCountrieRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> StorePropertiesDeprecated202004GetCountrieRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`StoreProperties::deprecated_202004_get_countries_param_country`], set with a builder.
+pub struct StorePropertiesDeprecated202004GetCountriesParamCountryRequest<'a> {
+    inner:
+        crate::store_properties::StorePropertiesDeprecated202004GetCountriesParamCountryRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> StorePropertiesDeprecated202004GetCountriesParamCountryRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`StoreProperties::deprecated_202007_get_countrie`], set with a builder.
+pub struct StorePropertiesDeprecated202007GetCountrieRequest<'a> {
+    inner: crate::store_properties::StorePropertiesDeprecated202007GetCountrieRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> StorePropertiesDeprecated202007GetCountrieRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`StoreProperties::deprecated_202007_get_countries_param_country`], set with a builder.
+pub struct StorePropertiesDeprecated202007GetCountriesParamCountryRequest<'a> {
+    inner:
+        crate::store_properties::StorePropertiesDeprecated202007GetCountriesParamCountryRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> StorePropertiesDeprecated202007GetCountriesParamCountryRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`StoreProperties::get_countrie`], set with a builder.
+pub struct StorePropertiesGetCountrieRequest<'a> {
+    inner: crate::store_properties::StorePropertiesGetCountrieRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> StorePropertiesGetCountrieRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`StoreProperties::get_countries_param_country`], set with a builder.
+pub struct StorePropertiesGetCountriesParamCountryRequest<'a> {
+    inner: crate::store_properties::StorePropertiesGetCountriesParamCountryRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> StorePropertiesGetCountriesParamCountryRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`StoreProperties::deprecated_202101_get_countrie`], set with a builder.
+pub struct StorePropertiesDeprecated202101GetCountrieRequest<'a> {
+    inner: crate::store_properties::StorePropertiesDeprecated202101GetCountrieRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> StorePropertiesDeprecated202101GetCountrieRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`StoreProperties::deprecated_202101_get_countries_param_country`], set with a builder.
+pub struct StorePropertiesDeprecated202101GetCountriesParamCountryRequest<'a> {
+    inner:
+        crate::store_properties::StorePropertiesDeprecated202101GetCountriesParamCountryRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> StorePropertiesDeprecated202101GetCountriesParamCountryRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`StoreProperties::deprecated_unstable_get_countrie`], set with a builder.
+pub struct StorePropertiesDeprecatedUnstableGetCountrieRequest<'a> {
+    inner: crate::store_properties::StorePropertiesDeprecatedUnstableGetCountrieRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> StorePropertiesDeprecatedUnstableGetCountrieRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`StoreProperties::deprecated_unstable_get_countries_param_country`], set with a builder.
+pub struct StorePropertiesDeprecatedUnstableGetCountriesParamCountryRequest<'a> {
+    inner:
+        crate::store_properties::StorePropertiesDeprecatedUnstableGetCountriesParamCountryRequest<
+            'a,
+        >,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> StorePropertiesDeprecatedUnstableGetCountriesParamCountryRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`StoreProperties::deprecated_202001_get_countries_param_country_province`], set with a builder.
+pub struct StorePropertiesDeprecated202001GetCountriesParamCountryProvinceRequest<'a> {
+            inner: crate::store_properties::StorePropertiesDeprecated202001GetCountriesParamCountryProvinceRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a> StorePropertiesDeprecated202001GetCountriesParamCountryProvinceRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`StoreProperties::deprecated_202001_get_countries_param_country_provinces_province`], set with a builder.
+pub struct StorePropertiesDeprecated202001GetCountriesParamCountryProvincesProvinceRequest<'a> {
+            inner: crate::store_properties::StorePropertiesDeprecated202001GetCountriesParamCountryProvincesProvinceRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a> StorePropertiesDeprecated202001GetCountriesParamCountryProvincesProvinceRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`StoreProperties::deprecated_202004_get_countries_param_country_province`], set with a builder.
+pub struct StorePropertiesDeprecated202004GetCountriesParamCountryProvinceRequest<'a> {
+            inner: crate::store_properties::StorePropertiesDeprecated202004GetCountriesParamCountryProvinceRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a> StorePropertiesDeprecated202004GetCountriesParamCountryProvinceRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`StoreProperties::deprecated_202004_get_countries_param_country_provinces_province`], set with a builder.
+pub struct StorePropertiesDeprecated202004GetCountriesParamCountryProvincesProvinceRequest<'a> {
+            inner: crate::store_properties::StorePropertiesDeprecated202004GetCountriesParamCountryProvincesProvinceRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a> StorePropertiesDeprecated202004GetCountriesParamCountryProvincesProvinceRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`StoreProperties::deprecated_202007_get_countries_param_country_province`], set with a builder.
+pub struct StorePropertiesDeprecated202007GetCountriesParamCountryProvinceRequest<'a> {
+            inner: crate::store_properties::StorePropertiesDeprecated202007GetCountriesParamCountryProvinceRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a> StorePropertiesDeprecated202007GetCountriesParamCountryProvinceRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`StoreProperties::deprecated_202007_get_countries_param_country_provinces_province`], set with a builder.
+pub struct StorePropertiesDeprecated202007GetCountriesParamCountryProvincesProvinceRequest<'a> {
+            inner: crate::store_properties::StorePropertiesDeprecated202007GetCountriesParamCountryProvincesProvinceRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a> StorePropertiesDeprecated202007GetCountriesParamCountryProvincesProvinceRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`StoreProperties::get_countries_param_country_province`], set with a builder.
+pub struct StorePropertiesGetCountriesParamCountryProvinceRequest<'a> {
+    inner: crate::store_properties::StorePropertiesGetCountriesParamCountryProvinceRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> StorePropertiesGetCountriesParamCountryProvinceRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`StoreProperties::get_countries_param_country_provinces_province`], set with a builder.
+pub struct StorePropertiesGetCountriesParamCountryProvincesProvinceRequest<'a> {
+    inner: crate::store_properties::StorePropertiesGetCountriesParamCountryProvincesProvinceRequest<
+        'a,
+    >,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> StorePropertiesGetCountriesParamCountryProvincesProvinceRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`StoreProperties::deprecated_202101_get_countries_param_country_province`], set with a builder.
+pub struct StorePropertiesDeprecated202101GetCountriesParamCountryProvinceRequest<'a> {
+            inner: crate::store_properties::StorePropertiesDeprecated202101GetCountriesParamCountryProvinceRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a> StorePropertiesDeprecated202101GetCountriesParamCountryProvinceRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`StoreProperties::deprecated_202101_get_countries_param_country_provinces_province`], set with a builder.
+pub struct StorePropertiesDeprecated202101GetCountriesParamCountryProvincesProvinceRequest<'a> {
+            inner: crate::store_properties::StorePropertiesDeprecated202101GetCountriesParamCountryProvincesProvinceRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a> StorePropertiesDeprecated202101GetCountriesParamCountryProvincesProvinceRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`StoreProperties::deprecated_unstable_get_countries_param_country_province`], set with a builder.
+pub struct StorePropertiesDeprecatedUnstableGetCountriesParamCountryProvinceRequest<'a> {
+            inner: crate::store_properties::StorePropertiesDeprecatedUnstableGetCountriesParamCountryProvinceRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a> StorePropertiesDeprecatedUnstableGetCountriesParamCountryProvinceRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`StoreProperties::deprecated_unstable_get_countries_param_country_provinces_province`], set with a builder.
+pub struct StorePropertiesDeprecatedUnstableGetCountriesParamCountryProvincesProvinceRequest<'a> {
+            inner: crate::store_properties::StorePropertiesDeprecatedUnstableGetCountriesParamCountryProvincesProvinceRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a> StorePropertiesDeprecatedUnstableGetCountriesParamCountryProvincesProvinceRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`StoreProperties::deprecated_202001_get_shipping_zone`], set with a builder.
+pub struct StorePropertiesDeprecated202001GetShippingZoneRequest<'a> {
+    inner: crate::store_properties::StorePropertiesDeprecated202001GetShippingZoneRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> StorePropertiesDeprecated202001GetShippingZoneRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`StoreProperties::deprecated_202004_get_shipping_zone`], set with a builder.
+pub struct StorePropertiesDeprecated202004GetShippingZoneRequest<'a> {
+    inner: crate::store_properties::StorePropertiesDeprecated202004GetShippingZoneRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> StorePropertiesDeprecated202004GetShippingZoneRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`StoreProperties::deprecated_202007_get_shipping_zone`], set with a builder.
+pub struct StorePropertiesDeprecated202007GetShippingZoneRequest<'a> {
+    inner: crate::store_properties::StorePropertiesDeprecated202007GetShippingZoneRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> StorePropertiesDeprecated202007GetShippingZoneRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`StoreProperties::get_shipping_zone`], set with a builder.
+pub struct StorePropertiesGetShippingZoneRequest<'a> {
+    inner: crate::store_properties::StorePropertiesGetShippingZoneRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> StorePropertiesGetShippingZoneRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`StoreProperties::deprecated_202101_get_shipping_zone`], set with a builder.
+pub struct StorePropertiesDeprecated202101GetShippingZoneRequest<'a> {
+    inner: crate::store_properties::StorePropertiesDeprecated202101GetShippingZoneRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> StorePropertiesDeprecated202101GetShippingZoneRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`StoreProperties::deprecated_unstable_get_shipping_zone`], set with a builder.
+pub struct StorePropertiesDeprecatedUnstableGetShippingZoneRequest<'a> {
+    inner: crate::store_properties::StorePropertiesDeprecatedUnstableGetShippingZoneRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> StorePropertiesDeprecatedUnstableGetShippingZoneRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`StoreProperties::deprecated_202001_get_shop`], set with a builder.
+pub struct StorePropertiesDeprecated202001GetShopRequest<'a> {
+    inner: crate::store_properties::StorePropertiesDeprecated202001GetShopRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> StorePropertiesDeprecated202001GetShopRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`StoreProperties::deprecated_202004_get_shop`], set with a builder.
+pub struct StorePropertiesDeprecated202004GetShopRequest<'a> {
+    inner: crate::store_properties::StorePropertiesDeprecated202004GetShopRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> StorePropertiesDeprecated202004GetShopRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`StoreProperties::deprecated_202007_get_shop`], set with a builder.
+pub struct StorePropertiesDeprecated202007GetShopRequest<'a> {
+    inner: crate::store_properties::StorePropertiesDeprecated202007GetShopRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> StorePropertiesDeprecated202007GetShopRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`StoreProperties::get_shop`], set with a builder.
+pub struct StorePropertiesGetShopRequest<'a> {
+    inner: crate::store_properties::StorePropertiesGetShopRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> StorePropertiesGetShopRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`StoreProperties::deprecated_202101_get_shop`], set with a builder.
+pub struct StorePropertiesDeprecated202101GetShopRequest<'a> {
+    inner: crate::store_properties::StorePropertiesDeprecated202101GetShopRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> StorePropertiesDeprecated202101GetShopRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`StoreProperties::deprecated_unstable_get_shop`], set with a builder.
+pub struct StorePropertiesDeprecatedUnstableGetShopRequest<'a> {
+    inner: crate::store_properties::StorePropertiesDeprecatedUnstableGetShopRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> StorePropertiesDeprecatedUnstableGetShopRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.inner = self.inner.fields(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::store_properties::StoreProperties`].
+pub struct StoreProperties {
+    inner: crate::store_properties::StoreProperties,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl StoreProperties {
+    pub(crate) fn new(
+        inner: crate::store_properties::StoreProperties,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        StoreProperties { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::store_properties::StoreProperties::deprecated_202001_get_countrie`].
+    pub fn deprecated_202001_get_countrie(
+        &self,
+        since_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202001_get_countrie(since_id, fields))
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::deprecated_202001_get_countrie_request`].
+    pub fn deprecated_202001_get_countrie_request(
+        &self,
+    ) -> StorePropertiesDeprecated202001GetCountrieRequest<'_> {
+        StorePropertiesDeprecated202001GetCountrieRequest {
+            inner: self.inner.deprecated_202001_get_countrie_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::deprecated_202001_create_countries`].
+    pub fn deprecated_202001_create_countries(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202001_create_countries(body))
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::deprecated_202001_get_countries_count`].
+    pub fn deprecated_202001_get_countries_count(&self) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202001_get_countries_count())
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::deprecated_202001_get_countries_param_country`].
+    pub fn deprecated_202001_get_countries_param_country(
+        &self,
+        country_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_get_countries_param_country(country_id, fields),
+        )
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::deprecated_202001_get_countries_param_country_request`].
+    pub fn deprecated_202001_get_countries_param_country_request<'a>(
+        &'a self,
+        country_id: &'a str,
+    ) -> StorePropertiesDeprecated202001GetCountriesParamCountryRequest<'a> {
+        StorePropertiesDeprecated202001GetCountriesParamCountryRequest {
+            inner: self
+                .inner
+                .deprecated_202001_get_countries_param_country_request(country_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::deprecated_202001_update_countries_param_country`].
+    pub fn deprecated_202001_update_countries_param_country(
+        &self,
+        country_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_update_countries_param_country(country_id, body),
+        )
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::deprecated_202001_delete_countries_param_country`].
+    pub fn deprecated_202001_delete_countries_param_country(
+        &self,
+        country_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_delete_countries_param_country(country_id),
+        )
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::deprecated_202004_get_countrie`].
+    pub fn deprecated_202004_get_countrie(
+        &self,
+        since_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202004_get_countrie(since_id, fields))
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::deprecated_202004_get_countrie_request`].
+    pub fn deprecated_202004_get_countrie_request(
+        &self,
+    ) -> StorePropertiesDeprecated202004GetCountrieRequest<'_> {
+        StorePropertiesDeprecated202004GetCountrieRequest {
+            inner: self.inner.deprecated_202004_get_countrie_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::deprecated_202004_create_countries`].
+    pub fn deprecated_202004_create_countries(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202004_create_countries(body))
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::deprecated_202004_get_countries_count`].
+    pub fn deprecated_202004_get_countries_count(&self) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202004_get_countries_count())
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::deprecated_202004_get_countries_param_country`].
+    pub fn deprecated_202004_get_countries_param_country(
+        &self,
+        country_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_get_countries_param_country(country_id, fields),
+        )
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::deprecated_202004_get_countries_param_country_request`].
+    pub fn deprecated_202004_get_countries_param_country_request<'a>(
+        &'a self,
+        country_id: &'a str,
+    ) -> StorePropertiesDeprecated202004GetCountriesParamCountryRequest<'a> {
+        StorePropertiesDeprecated202004GetCountriesParamCountryRequest {
+            inner: self
+                .inner
+                .deprecated_202004_get_countries_param_country_request(country_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::deprecated_202004_update_countries_param_country`].
+    pub fn deprecated_202004_update_countries_param_country(
+        &self,
+        country_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_update_countries_param_country(country_id, body),
+        )
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::deprecated_202004_delete_countries_param_country`].
+    pub fn deprecated_202004_delete_countries_param_country(
+        &self,
+        country_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_delete_countries_param_country(country_id),
+        )
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::deprecated_202007_get_countrie`].
+    pub fn deprecated_202007_get_countrie(
+        &self,
+        since_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202007_get_countrie(since_id, fields))
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::deprecated_202007_get_countrie_request`].
+    pub fn deprecated_202007_get_countrie_request(
+        &self,
+    ) -> StorePropertiesDeprecated202007GetCountrieRequest<'_> {
+        StorePropertiesDeprecated202007GetCountrieRequest {
+            inner: self.inner.deprecated_202007_get_countrie_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::deprecated_202007_create_countries`].
+    pub fn deprecated_202007_create_countries(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202007_create_countries(body))
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::deprecated_202007_get_countries_count`].
+    pub fn deprecated_202007_get_countries_count(&self) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202007_get_countries_count())
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::deprecated_202007_get_countries_param_country`].
+    pub fn deprecated_202007_get_countries_param_country(
+        &self,
+        country_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_get_countries_param_country(country_id, fields),
+        )
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::deprecated_202007_get_countries_param_country_request`].
+    pub fn deprecated_202007_get_countries_param_country_request<'a>(
+        &'a self,
+        country_id: &'a str,
+    ) -> StorePropertiesDeprecated202007GetCountriesParamCountryRequest<'a> {
+        StorePropertiesDeprecated202007GetCountriesParamCountryRequest {
+            inner: self
+                .inner
+                .deprecated_202007_get_countries_param_country_request(country_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::deprecated_202007_update_countries_param_country`].
+    pub fn deprecated_202007_update_countries_param_country(
+        &self,
+        country_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_update_countries_param_country(country_id, body),
+        )
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::deprecated_202007_delete_countries_param_country`].
+    pub fn deprecated_202007_delete_countries_param_country(
+        &self,
+        country_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_delete_countries_param_country(country_id),
+        )
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::get_countrie`].
+    pub fn get_countrie(&self, since_id: &str, fields: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.get_countrie(since_id, fields))
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::get_countrie_request`].
+    pub fn get_countrie_request(&self) -> StorePropertiesGetCountrieRequest<'_> {
+        StorePropertiesGetCountrieRequest {
+            inner: self.inner.get_countrie_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::create_countries`].
+    pub fn create_countries(&self, body: &serde_json::Value) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.create_countries(body))
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::get_countries_count`].
+    pub fn get_countries_count(&self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.get_countries_count())
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::get_countries_param_country`].
+    pub fn get_countries_param_country(
+        &self,
+        country_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.get_countries_param_country(country_id, fields))
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::get_countries_param_country_request`].
+    pub fn get_countries_param_country_request<'a>(
+        &'a self,
+        country_id: &'a str,
+    ) -> StorePropertiesGetCountriesParamCountryRequest<'a> {
+        StorePropertiesGetCountriesParamCountryRequest {
+            inner: self.inner.get_countries_param_country_request(country_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::update_countries_param_country`].
+    pub fn update_countries_param_country(
+        &self,
+        country_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.update_countries_param_country(country_id, body))
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::delete_countries_param_country`].
+    pub fn delete_countries_param_country(
+        &self,
+        country_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete_countries_param_country(country_id))
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::deprecated_202101_get_countrie`].
+    pub fn deprecated_202101_get_countrie(
+        &self,
+        since_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202101_get_countrie(since_id, fields))
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::deprecated_202101_get_countrie_request`].
+    pub fn deprecated_202101_get_countrie_request(
+        &self,
+    ) -> StorePropertiesDeprecated202101GetCountrieRequest<'_> {
+        StorePropertiesDeprecated202101GetCountrieRequest {
+            inner: self.inner.deprecated_202101_get_countrie_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::deprecated_202101_create_countries`].
+    pub fn deprecated_202101_create_countries(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202101_create_countries(body))
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::deprecated_202101_get_countries_count`].
+    pub fn deprecated_202101_get_countries_count(&self) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202101_get_countries_count())
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::deprecated_202101_get_countries_param_country`].
+    pub fn deprecated_202101_get_countries_param_country(
+        &self,
+        country_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_get_countries_param_country(country_id, fields),
+        )
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::deprecated_202101_get_countries_param_country_request`].
+    pub fn deprecated_202101_get_countries_param_country_request<'a>(
+        &'a self,
+        country_id: &'a str,
+    ) -> StorePropertiesDeprecated202101GetCountriesParamCountryRequest<'a> {
+        StorePropertiesDeprecated202101GetCountriesParamCountryRequest {
+            inner: self
+                .inner
+                .deprecated_202101_get_countries_param_country_request(country_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::deprecated_202101_update_countries_param_country`].
+    pub fn deprecated_202101_update_countries_param_country(
+        &self,
+        country_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_update_countries_param_country(country_id, body),
+        )
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::deprecated_202101_delete_countries_param_country`].
+    pub fn deprecated_202101_delete_countries_param_country(
+        &self,
+        country_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_delete_countries_param_country(country_id),
+        )
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::deprecated_unstable_get_countrie`].
+    pub fn deprecated_unstable_get_countrie(
+        &self,
+        since_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_get_countrie(since_id, fields),
+        )
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::deprecated_unstable_get_countrie_request`].
+    pub fn deprecated_unstable_get_countrie_request(
+        &self,
+    ) -> StorePropertiesDeprecatedUnstableGetCountrieRequest<'_> {
+        StorePropertiesDeprecatedUnstableGetCountrieRequest {
+            inner: self.inner.deprecated_unstable_get_countrie_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::deprecated_unstable_create_countries`].
+    pub fn deprecated_unstable_create_countries(
+        &self,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_unstable_create_countries(body))
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::deprecated_unstable_get_countries_count`].
+    pub fn deprecated_unstable_get_countries_count(&self) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_unstable_get_countries_count())
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::deprecated_unstable_get_countries_param_country`].
+    pub fn deprecated_unstable_get_countries_param_country(
+        &self,
+        country_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_get_countries_param_country(country_id, fields),
+        )
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::deprecated_unstable_get_countries_param_country_request`].
+    pub fn deprecated_unstable_get_countries_param_country_request<'a>(
+        &'a self,
+        country_id: &'a str,
+    ) -> StorePropertiesDeprecatedUnstableGetCountriesParamCountryRequest<'a> {
+        StorePropertiesDeprecatedUnstableGetCountriesParamCountryRequest {
+            inner: self
+                .inner
+                .deprecated_unstable_get_countries_param_country_request(country_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::deprecated_unstable_update_countries_param_country`].
+    pub fn deprecated_unstable_update_countries_param_country(
+        &self,
+        country_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_update_countries_param_country(country_id, body),
+        )
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::deprecated_unstable_delete_countries_param_country`].
+    pub fn deprecated_unstable_delete_countries_param_country(
+        &self,
+        country_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_delete_countries_param_country(country_id),
+        )
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::deprecated_202001_get_currencie`].
+    pub fn deprecated_202001_get_currencie(&self) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202001_get_currencie())
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::deprecated_202004_get_currencie`].
+    pub fn deprecated_202004_get_currencie(&self) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202004_get_currencie())
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::deprecated_202007_get_currencie`].
+    pub fn deprecated_202007_get_currencie(&self) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202007_get_currencie())
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::get_currencie`].
+    pub fn get_currencie(&self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.get_currencie())
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::deprecated_202101_get_currencie`].
+    pub fn deprecated_202101_get_currencie(&self) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202101_get_currencie())
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::deprecated_unstable_get_currencie`].
+    pub fn deprecated_unstable_get_currencie(&self) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_unstable_get_currencie())
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::deprecated_202001_get_policie`].
+    pub fn deprecated_202001_get_policie(&self) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202001_get_policie())
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::deprecated_202004_get_policie`].
+    pub fn deprecated_202004_get_policie(&self) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202004_get_policie())
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::deprecated_202007_get_policie`].
+    pub fn deprecated_202007_get_policie(&self) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202007_get_policie())
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::get_policie`].
+    pub fn get_policie(&self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.get_policie())
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::deprecated_202101_get_policie`].
+    pub fn deprecated_202101_get_policie(&self) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202101_get_policie())
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::deprecated_unstable_get_policie`].
+    pub fn deprecated_unstable_get_policie(&self) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_unstable_get_policie())
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::deprecated_202001_get_countries_param_country_province`].
+    pub fn deprecated_202001_get_countries_param_country_province(
+        &self,
+        country_id: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_get_countries_param_country_province(
+                    country_id, since_id, fields,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::deprecated_202001_get_countries_param_country_province_request`].
+    pub fn deprecated_202001_get_countries_param_country_province_request<'a>(
+        &'a self,
+        country_id: &'a str,
+    ) -> StorePropertiesDeprecated202001GetCountriesParamCountryProvinceRequest<'a> {
+        StorePropertiesDeprecated202001GetCountriesParamCountryProvinceRequest {
+            inner: self
+                .inner
+                .deprecated_202001_get_countries_param_country_province_request(country_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::deprecated_202001_get_countries_param_country_provinces_count`].
+    pub fn deprecated_202001_get_countries_param_country_provinces_count(
+        &self,
+        country_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_get_countries_param_country_provinces_count(country_id),
+        )
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::deprecated_202001_get_countries_param_country_provinces_province`].
+    pub fn deprecated_202001_get_countries_param_country_provinces_province(
+        &self,
+        country_id: &str,
+        province_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_get_countries_param_country_provinces_province(
+                    country_id,
+                    province_id,
+                    fields,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::deprecated_202001_get_countries_param_country_provinces_province_request`].
+    pub fn deprecated_202001_get_countries_param_country_provinces_province_request<'a>(
+        &'a self,
+        country_id: &'a str,
+        province_id: &'a str,
+    ) -> StorePropertiesDeprecated202001GetCountriesParamCountryProvincesProvinceRequest<'a> {
+        StorePropertiesDeprecated202001GetCountriesParamCountryProvincesProvinceRequest {
+            inner: self
+                .inner
+                .deprecated_202001_get_countries_param_country_provinces_province_request(
+                    country_id,
+                    province_id,
+                ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::deprecated_202001_update_countries_param_country_provinces_province`].
+    pub fn deprecated_202001_update_countries_param_country_provinces_province(
+        &self,
+        country_id: &str,
+        province_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202001_update_countries_param_country_provinces_province(
+                    country_id,
+                    province_id,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::deprecated_202004_get_countries_param_country_province`].
+    pub fn deprecated_202004_get_countries_param_country_province(
+        &self,
+        country_id: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_get_countries_param_country_province(
+                    country_id, since_id, fields,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::deprecated_202004_get_countries_param_country_province_request`].
+    pub fn deprecated_202004_get_countries_param_country_province_request<'a>(
+        &'a self,
+        country_id: &'a str,
+    ) -> StorePropertiesDeprecated202004GetCountriesParamCountryProvinceRequest<'a> {
+        StorePropertiesDeprecated202004GetCountriesParamCountryProvinceRequest {
+            inner: self
+                .inner
+                .deprecated_202004_get_countries_param_country_province_request(country_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::deprecated_202004_get_countries_param_country_provinces_count`].
+    pub fn deprecated_202004_get_countries_param_country_provinces_count(
+        &self,
+        country_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_get_countries_param_country_provinces_count(country_id),
+        )
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::deprecated_202004_get_countries_param_country_provinces_province`].
+    pub fn deprecated_202004_get_countries_param_country_provinces_province(
+        &self,
+        country_id: &str,
+        province_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_get_countries_param_country_provinces_province(
+                    country_id,
+                    province_id,
+                    fields,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::deprecated_202004_get_countries_param_country_provinces_province_request`].
+    pub fn deprecated_202004_get_countries_param_country_provinces_province_request<'a>(
+        &'a self,
+        country_id: &'a str,
+        province_id: &'a str,
+    ) -> StorePropertiesDeprecated202004GetCountriesParamCountryProvincesProvinceRequest<'a> {
+        StorePropertiesDeprecated202004GetCountriesParamCountryProvincesProvinceRequest {
+            inner: self
+                .inner
+                .deprecated_202004_get_countries_param_country_provinces_province_request(
+                    country_id,
+                    province_id,
+                ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::deprecated_202004_update_countries_param_country_provinces_province`].
+    pub fn deprecated_202004_update_countries_param_country_provinces_province(
+        &self,
+        country_id: &str,
+        province_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202004_update_countries_param_country_provinces_province(
+                    country_id,
+                    province_id,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::deprecated_202007_get_countries_param_country_province`].
+    pub fn deprecated_202007_get_countries_param_country_province(
+        &self,
+        country_id: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_get_countries_param_country_province(
+                    country_id, since_id, fields,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::deprecated_202007_get_countries_param_country_province_request`].
+    pub fn deprecated_202007_get_countries_param_country_province_request<'a>(
+        &'a self,
+        country_id: &'a str,
+    ) -> StorePropertiesDeprecated202007GetCountriesParamCountryProvinceRequest<'a> {
+        StorePropertiesDeprecated202007GetCountriesParamCountryProvinceRequest {
+            inner: self
+                .inner
+                .deprecated_202007_get_countries_param_country_province_request(country_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::deprecated_202007_get_countries_param_country_provinces_count`].
+    pub fn deprecated_202007_get_countries_param_country_provinces_count(
+        &self,
+        country_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_get_countries_param_country_provinces_count(country_id),
+        )
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::deprecated_202007_get_countries_param_country_provinces_province`].
+    pub fn deprecated_202007_get_countries_param_country_provinces_province(
+        &self,
+        country_id: &str,
+        province_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_get_countries_param_country_provinces_province(
+                    country_id,
+                    province_id,
+                    fields,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::deprecated_202007_get_countries_param_country_provinces_province_request`].
+    pub fn deprecated_202007_get_countries_param_country_provinces_province_request<'a>(
+        &'a self,
+        country_id: &'a str,
+        province_id: &'a str,
+    ) -> StorePropertiesDeprecated202007GetCountriesParamCountryProvincesProvinceRequest<'a> {
+        StorePropertiesDeprecated202007GetCountriesParamCountryProvincesProvinceRequest {
+            inner: self
+                .inner
+                .deprecated_202007_get_countries_param_country_provinces_province_request(
+                    country_id,
+                    province_id,
+                ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::deprecated_202007_update_countries_param_country_provinces_province`].
+    pub fn deprecated_202007_update_countries_param_country_provinces_province(
+        &self,
+        country_id: &str,
+        province_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202007_update_countries_param_country_provinces_province(
+                    country_id,
+                    province_id,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::get_countries_param_country_province`].
+    pub fn get_countries_param_country_province(
+        &self,
+        country_id: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .get_countries_param_country_province(country_id, since_id, fields),
+        )
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::get_countries_param_country_province_request`].
+    pub fn get_countries_param_country_province_request<'a>(
+        &'a self,
+        country_id: &'a str,
+    ) -> StorePropertiesGetCountriesParamCountryProvinceRequest<'a> {
+        StorePropertiesGetCountriesParamCountryProvinceRequest {
+            inner: self
+                .inner
+                .get_countries_param_country_province_request(country_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::get_countries_param_country_provinces_count`].
+    pub fn get_countries_param_country_provinces_count(
+        &self,
+        country_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .get_countries_param_country_provinces_count(country_id),
+        )
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::get_countries_param_country_provinces_province`].
+    pub fn get_countries_param_country_provinces_province(
+        &self,
+        country_id: &str,
+        province_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.get_countries_param_country_provinces_province(
+                country_id,
+                province_id,
+                fields,
+            ))
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::get_countries_param_country_provinces_province_request`].
+    pub fn get_countries_param_country_provinces_province_request<'a>(
+        &'a self,
+        country_id: &'a str,
+        province_id: &'a str,
+    ) -> StorePropertiesGetCountriesParamCountryProvincesProvinceRequest<'a> {
+        StorePropertiesGetCountriesParamCountryProvincesProvinceRequest {
+            inner: self
+                .inner
+                .get_countries_param_country_provinces_province_request(country_id, province_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::update_countries_param_country_provinces_province`].
+    pub fn update_countries_param_country_provinces_province(
+        &self,
+        country_id: &str,
+        province_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .update_countries_param_country_provinces_province(country_id, province_id, body),
+        )
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::deprecated_202101_get_countries_param_country_province`].
+    pub fn deprecated_202101_get_countries_param_country_province(
+        &self,
+        country_id: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_get_countries_param_country_province(
+                    country_id, since_id, fields,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::deprecated_202101_get_countries_param_country_province_request`].
+    pub fn deprecated_202101_get_countries_param_country_province_request<'a>(
+        &'a self,
+        country_id: &'a str,
+    ) -> StorePropertiesDeprecated202101GetCountriesParamCountryProvinceRequest<'a> {
+        StorePropertiesDeprecated202101GetCountriesParamCountryProvinceRequest {
+            inner: self
+                .inner
+                .deprecated_202101_get_countries_param_country_province_request(country_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::deprecated_202101_get_countries_param_country_provinces_count`].
+    pub fn deprecated_202101_get_countries_param_country_provinces_count(
+        &self,
+        country_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_get_countries_param_country_provinces_count(country_id),
+        )
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::deprecated_202101_get_countries_param_country_provinces_province`].
+    pub fn deprecated_202101_get_countries_param_country_provinces_province(
+        &self,
+        country_id: &str,
+        province_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_get_countries_param_country_provinces_province(
+                    country_id,
+                    province_id,
+                    fields,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::deprecated_202101_get_countries_param_country_provinces_province_request`].
+    pub fn deprecated_202101_get_countries_param_country_provinces_province_request<'a>(
+        &'a self,
+        country_id: &'a str,
+        province_id: &'a str,
+    ) -> StorePropertiesDeprecated202101GetCountriesParamCountryProvincesProvinceRequest<'a> {
+        StorePropertiesDeprecated202101GetCountriesParamCountryProvincesProvinceRequest {
+            inner: self
+                .inner
+                .deprecated_202101_get_countries_param_country_provinces_province_request(
+                    country_id,
+                    province_id,
+                ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::deprecated_202101_update_countries_param_country_provinces_province`].
+    pub fn deprecated_202101_update_countries_param_country_provinces_province(
+        &self,
+        country_id: &str,
+        province_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_202101_update_countries_param_country_provinces_province(
+                    country_id,
+                    province_id,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::deprecated_unstable_get_countries_param_country_province`].
+    pub fn deprecated_unstable_get_countries_param_country_province(
+        &self,
+        country_id: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_get_countries_param_country_province(
+                    country_id, since_id, fields,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::deprecated_unstable_get_countries_param_country_province_request`].
+    pub fn deprecated_unstable_get_countries_param_country_province_request<'a>(
+        &'a self,
+        country_id: &'a str,
+    ) -> StorePropertiesDeprecatedUnstableGetCountriesParamCountryProvinceRequest<'a> {
+        StorePropertiesDeprecatedUnstableGetCountriesParamCountryProvinceRequest {
+            inner: self
+                .inner
+                .deprecated_unstable_get_countries_param_country_province_request(country_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::deprecated_unstable_get_countries_param_country_provinces_count`].
+    pub fn deprecated_unstable_get_countries_param_country_provinces_count(
+        &self,
+        country_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_get_countries_param_country_provinces_count(country_id),
+        )
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::deprecated_unstable_get_countries_param_country_provinces_province`].
+    pub fn deprecated_unstable_get_countries_param_country_provinces_province(
+        &self,
+        country_id: &str,
+        province_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_get_countries_param_country_provinces_province(
+                    country_id,
+                    province_id,
+                    fields,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::deprecated_unstable_get_countries_param_country_provinces_province_request`].
+    pub fn deprecated_unstable_get_countries_param_country_provinces_province_request<'a>(
+        &'a self,
+        country_id: &'a str,
+        province_id: &'a str,
+    ) -> StorePropertiesDeprecatedUnstableGetCountriesParamCountryProvincesProvinceRequest<'a> {
+        StorePropertiesDeprecatedUnstableGetCountriesParamCountryProvincesProvinceRequest {
+            inner: self
+                .inner
+                .deprecated_unstable_get_countries_param_country_provinces_province_request(
+                    country_id,
+                    province_id,
+                ),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::deprecated_unstable_update_countries_param_country_provinces_province`].
+    pub fn deprecated_unstable_update_countries_param_country_provinces_province(
+        &self,
+        country_id: &str,
+        province_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .deprecated_unstable_update_countries_param_country_provinces_province(
+                    country_id,
+                    province_id,
+                    body,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::deprecated_202001_get_shipping_zone`].
+    pub fn deprecated_202001_get_shipping_zone(
+        &self,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202001_get_shipping_zone(fields))
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::deprecated_202001_get_shipping_zone_request`].
+    pub fn deprecated_202001_get_shipping_zone_request(
+        &self,
+    ) -> StorePropertiesDeprecated202001GetShippingZoneRequest<'_> {
+        StorePropertiesDeprecated202001GetShippingZoneRequest {
+            inner: self.inner.deprecated_202001_get_shipping_zone_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::deprecated_202004_get_shipping_zone`].
+    pub fn deprecated_202004_get_shipping_zone(
+        &self,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202004_get_shipping_zone(fields))
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::deprecated_202004_get_shipping_zone_request`].
+    pub fn deprecated_202004_get_shipping_zone_request(
+        &self,
+    ) -> StorePropertiesDeprecated202004GetShippingZoneRequest<'_> {
+        StorePropertiesDeprecated202004GetShippingZoneRequest {
+            inner: self.inner.deprecated_202004_get_shipping_zone_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::deprecated_202007_get_shipping_zone`].
+    pub fn deprecated_202007_get_shipping_zone(
+        &self,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202007_get_shipping_zone(fields))
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::deprecated_202007_get_shipping_zone_request`].
+    pub fn deprecated_202007_get_shipping_zone_request(
+        &self,
+    ) -> StorePropertiesDeprecated202007GetShippingZoneRequest<'_> {
+        StorePropertiesDeprecated202007GetShippingZoneRequest {
+            inner: self.inner.deprecated_202007_get_shipping_zone_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::get_shipping_zone`].
+    pub fn get_shipping_zone(&self, fields: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.get_shipping_zone(fields))
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::get_shipping_zone_request`].
+    pub fn get_shipping_zone_request(&self) -> StorePropertiesGetShippingZoneRequest<'_> {
+        StorePropertiesGetShippingZoneRequest {
+            inner: self.inner.get_shipping_zone_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::deprecated_202101_get_shipping_zone`].
+    pub fn deprecated_202101_get_shipping_zone(
+        &self,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202101_get_shipping_zone(fields))
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::deprecated_202101_get_shipping_zone_request`].
+    pub fn deprecated_202101_get_shipping_zone_request(
+        &self,
+    ) -> StorePropertiesDeprecated202101GetShippingZoneRequest<'_> {
+        StorePropertiesDeprecated202101GetShippingZoneRequest {
+            inner: self.inner.deprecated_202101_get_shipping_zone_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::deprecated_unstable_get_shipping_zone`].
+    pub fn deprecated_unstable_get_shipping_zone(
+        &self,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_unstable_get_shipping_zone(fields))
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::deprecated_unstable_get_shipping_zone_request`].
+    pub fn deprecated_unstable_get_shipping_zone_request(
+        &self,
+    ) -> StorePropertiesDeprecatedUnstableGetShippingZoneRequest<'_> {
+        StorePropertiesDeprecatedUnstableGetShippingZoneRequest {
+            inner: self.inner.deprecated_unstable_get_shipping_zone_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::deprecated_202001_get_shop`].
+    pub fn deprecated_202001_get_shop(&self, fields: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202001_get_shop(fields))
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::deprecated_202001_get_shop_request`].
+    pub fn deprecated_202001_get_shop_request(
+        &self,
+    ) -> StorePropertiesDeprecated202001GetShopRequest<'_> {
+        StorePropertiesDeprecated202001GetShopRequest {
+            inner: self.inner.deprecated_202001_get_shop_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::deprecated_202004_get_shop`].
+    pub fn deprecated_202004_get_shop(&self, fields: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202004_get_shop(fields))
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::deprecated_202004_get_shop_request`].
+    pub fn deprecated_202004_get_shop_request(
+        &self,
+    ) -> StorePropertiesDeprecated202004GetShopRequest<'_> {
+        StorePropertiesDeprecated202004GetShopRequest {
+            inner: self.inner.deprecated_202004_get_shop_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::deprecated_202007_get_shop`].
+    pub fn deprecated_202007_get_shop(&self, fields: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202007_get_shop(fields))
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::deprecated_202007_get_shop_request`].
+    pub fn deprecated_202007_get_shop_request(
+        &self,
+    ) -> StorePropertiesDeprecated202007GetShopRequest<'_> {
+        StorePropertiesDeprecated202007GetShopRequest {
+            inner: self.inner.deprecated_202007_get_shop_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::get_shop`].
+    pub fn get_shop(&self, fields: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.get_shop(fields))
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::get_shop_request`].
+    pub fn get_shop_request(&self) -> StorePropertiesGetShopRequest<'_> {
+        StorePropertiesGetShopRequest {
+            inner: self.inner.get_shop_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::deprecated_202101_get_shop`].
+    pub fn deprecated_202101_get_shop(&self, fields: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202101_get_shop(fields))
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::deprecated_202101_get_shop_request`].
+    pub fn deprecated_202101_get_shop_request(
+        &self,
+    ) -> StorePropertiesDeprecated202101GetShopRequest<'_> {
+        StorePropertiesDeprecated202101GetShopRequest {
+            inner: self.inner.deprecated_202101_get_shop_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::deprecated_unstable_get_shop`].
+    pub fn deprecated_unstable_get_shop(&self, fields: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_unstable_get_shop(fields))
+    }
+    /// The blocking version of [`crate::store_properties::StoreProperties::deprecated_unstable_get_shop_request`].
+    pub fn deprecated_unstable_get_shop_request(
+        &self,
+    ) -> StorePropertiesDeprecatedUnstableGetShopRequest<'_> {
+        StorePropertiesDeprecatedUnstableGetShopRequest {
+            inner: self.inner.deprecated_unstable_get_shop_request(),
+            runtime: &self.runtime,
+        }
+    }
+}
@@ -0,0 +1,502 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Tendertransaction::deprecated_202001_get_tender_transaction`], set with a builder.
+pub struct TendertransactionDeprecated202001GetTenderTransactionRequest<'a> {
+    inner:
+        crate::tendertransaction::TendertransactionDeprecated202001GetTenderTransactionRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> TendertransactionDeprecated202001GetTenderTransactionRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `processed_at_min` query parameter.
+    pub fn processed_at_min(mut self, processed_at_min: &'a str) -> Self {
+        self.inner = self.inner.processed_at_min(processed_at_min);
+        self
+    }
+
+    /// Sets the `processed_at_max` query parameter.
+    pub fn processed_at_max(mut self, processed_at_max: &'a str) -> Self {
+        self.inner = self.inner.processed_at_max(processed_at_max);
+        self
+    }
+
+    /// Sets the `processed_at` query parameter.
+    pub fn processed_at(mut self, processed_at: &'a str) -> Self {
+        self.inner = self.inner.processed_at(processed_at);
+        self
+    }
+
+    /// Sets the `order` query parameter.
+    pub fn order(mut self, order: &'a str) -> Self {
+        self.inner = self.inner.order(order);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Tendertransaction::deprecated_202004_get_tender_transaction`], set with a builder.
+pub struct TendertransactionDeprecated202004GetTenderTransactionRequest<'a> {
+    inner:
+        crate::tendertransaction::TendertransactionDeprecated202004GetTenderTransactionRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> TendertransactionDeprecated202004GetTenderTransactionRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `processed_at_min` query parameter.
+    pub fn processed_at_min(mut self, processed_at_min: &'a str) -> Self {
+        self.inner = self.inner.processed_at_min(processed_at_min);
+        self
+    }
+
+    /// Sets the `processed_at_max` query parameter.
+    pub fn processed_at_max(mut self, processed_at_max: &'a str) -> Self {
+        self.inner = self.inner.processed_at_max(processed_at_max);
+        self
+    }
+
+    /// Sets the `processed_at` query parameter.
+    pub fn processed_at(mut self, processed_at: &'a str) -> Self {
+        self.inner = self.inner.processed_at(processed_at);
+        self
+    }
+
+    /// Sets the `order` query parameter.
+    pub fn order(mut self, order: &'a str) -> Self {
+        self.inner = self.inner.order(order);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Tendertransaction::deprecated_202007_get_tender_transaction`], set with a builder.
+pub struct TendertransactionDeprecated202007GetTenderTransactionRequest<'a> {
+    inner:
+        crate::tendertransaction::TendertransactionDeprecated202007GetTenderTransactionRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> TendertransactionDeprecated202007GetTenderTransactionRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `processed_at_min` query parameter.
+    pub fn processed_at_min(mut self, processed_at_min: &'a str) -> Self {
+        self.inner = self.inner.processed_at_min(processed_at_min);
+        self
+    }
+
+    /// Sets the `processed_at_max` query parameter.
+    pub fn processed_at_max(mut self, processed_at_max: &'a str) -> Self {
+        self.inner = self.inner.processed_at_max(processed_at_max);
+        self
+    }
+
+    /// Sets the `processed_at` query parameter.
+    pub fn processed_at(mut self, processed_at: &'a str) -> Self {
+        self.inner = self.inner.processed_at(processed_at);
+        self
+    }
+
+    /// Sets the `order` query parameter.
+    pub fn order(mut self, order: &'a str) -> Self {
+        self.inner = self.inner.order(order);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Tendertransaction::get_tender_transaction`], set with a builder.
+pub struct TendertransactionGetTenderTransactionRequest<'a> {
+    inner: crate::tendertransaction::TendertransactionGetTenderTransactionRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> TendertransactionGetTenderTransactionRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `processed_at_min` query parameter.
+    pub fn processed_at_min(mut self, processed_at_min: &'a str) -> Self {
+        self.inner = self.inner.processed_at_min(processed_at_min);
+        self
+    }
+
+    /// Sets the `processed_at_max` query parameter.
+    pub fn processed_at_max(mut self, processed_at_max: &'a str) -> Self {
+        self.inner = self.inner.processed_at_max(processed_at_max);
+        self
+    }
+
+    /// Sets the `processed_at` query parameter.
+    pub fn processed_at(mut self, processed_at: &'a str) -> Self {
+        self.inner = self.inner.processed_at(processed_at);
+        self
+    }
+
+    /// Sets the `order` query parameter.
+    pub fn order(mut self, order: &'a str) -> Self {
+        self.inner = self.inner.order(order);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Tendertransaction::deprecated_202101_get_tender_transaction`], set with a builder.
+pub struct TendertransactionDeprecated202101GetTenderTransactionRequest<'a> {
+    inner:
+        crate::tendertransaction::TendertransactionDeprecated202101GetTenderTransactionRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> TendertransactionDeprecated202101GetTenderTransactionRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `processed_at_min` query parameter.
+    pub fn processed_at_min(mut self, processed_at_min: &'a str) -> Self {
+        self.inner = self.inner.processed_at_min(processed_at_min);
+        self
+    }
+
+    /// Sets the `processed_at_max` query parameter.
+    pub fn processed_at_max(mut self, processed_at_max: &'a str) -> Self {
+        self.inner = self.inner.processed_at_max(processed_at_max);
+        self
+    }
+
+    /// Sets the `processed_at` query parameter.
+    pub fn processed_at(mut self, processed_at: &'a str) -> Self {
+        self.inner = self.inner.processed_at(processed_at);
+        self
+    }
+
+    /// Sets the `order` query parameter.
+    pub fn order(mut self, order: &'a str) -> Self {
+        self.inner = self.inner.order(order);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Tendertransaction::deprecated_unstable_get_tender_transaction`], set with a builder.
+pub struct TendertransactionDeprecatedUnstableGetTenderTransactionRequest<'a> {
+    inner: crate::tendertransaction::TendertransactionDeprecatedUnstableGetTenderTransactionRequest<
+        'a,
+    >,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> TendertransactionDeprecatedUnstableGetTenderTransactionRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.inner = self.inner.since_id(since_id);
+        self
+    }
+
+    /// Sets the `processed_at_min` query parameter.
+    pub fn processed_at_min(mut self, processed_at_min: &'a str) -> Self {
+        self.inner = self.inner.processed_at_min(processed_at_min);
+        self
+    }
+
+    /// Sets the `processed_at_max` query parameter.
+    pub fn processed_at_max(mut self, processed_at_max: &'a str) -> Self {
+        self.inner = self.inner.processed_at_max(processed_at_max);
+        self
+    }
+
+    /// Sets the `processed_at` query parameter.
+    pub fn processed_at(mut self, processed_at: &'a str) -> Self {
+        self.inner = self.inner.processed_at(processed_at);
+        self
+    }
+
+    /// Sets the `order` query parameter.
+    pub fn order(mut self, order: &'a str) -> Self {
+        self.inner = self.inner.order(order);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::tendertransaction::Tendertransaction`].
+pub struct Tendertransaction {
+    inner: crate::tendertransaction::Tendertransaction,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Tendertransaction {
+    pub(crate) fn new(
+        inner: crate::tendertransaction::Tendertransaction,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Tendertransaction { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::tendertransaction::Tendertransaction::deprecated_202001_get_tender_transaction`].
+    pub fn deprecated_202001_get_tender_transaction(
+        &self,
+        limit: &str,
+        since_id: &str,
+        processed_at_min: &str,
+        processed_at_max: &str,
+        processed_at: &str,
+        order: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202001_get_tender_transaction(
+                limit,
+                since_id,
+                processed_at_min,
+                processed_at_max,
+                processed_at,
+                order,
+            ))
+    }
+    /// The blocking version of [`crate::tendertransaction::Tendertransaction::deprecated_202001_get_tender_transaction_request`].
+    pub fn deprecated_202001_get_tender_transaction_request(
+        &self,
+    ) -> TendertransactionDeprecated202001GetTenderTransactionRequest<'_> {
+        TendertransactionDeprecated202001GetTenderTransactionRequest {
+            inner: self
+                .inner
+                .deprecated_202001_get_tender_transaction_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::tendertransaction::Tendertransaction::deprecated_202004_get_tender_transaction`].
+    pub fn deprecated_202004_get_tender_transaction(
+        &self,
+        limit: &str,
+        since_id: &str,
+        processed_at_min: &str,
+        processed_at_max: &str,
+        processed_at: &str,
+        order: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202004_get_tender_transaction(
+                limit,
+                since_id,
+                processed_at_min,
+                processed_at_max,
+                processed_at,
+                order,
+            ))
+    }
+    /// The blocking version of [`crate::tendertransaction::Tendertransaction::deprecated_202004_get_tender_transaction_request`].
+    pub fn deprecated_202004_get_tender_transaction_request(
+        &self,
+    ) -> TendertransactionDeprecated202004GetTenderTransactionRequest<'_> {
+        TendertransactionDeprecated202004GetTenderTransactionRequest {
+            inner: self
+                .inner
+                .deprecated_202004_get_tender_transaction_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::tendertransaction::Tendertransaction::deprecated_202007_get_tender_transaction`].
+    pub fn deprecated_202007_get_tender_transaction(
+        &self,
+        limit: &str,
+        since_id: &str,
+        processed_at_min: &str,
+        processed_at_max: &str,
+        processed_at: &str,
+        order: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202007_get_tender_transaction(
+                limit,
+                since_id,
+                processed_at_min,
+                processed_at_max,
+                processed_at,
+                order,
+            ))
+    }
+    /// The blocking version of [`crate::tendertransaction::Tendertransaction::deprecated_202007_get_tender_transaction_request`].
+    pub fn deprecated_202007_get_tender_transaction_request(
+        &self,
+    ) -> TendertransactionDeprecated202007GetTenderTransactionRequest<'_> {
+        TendertransactionDeprecated202007GetTenderTransactionRequest {
+            inner: self
+                .inner
+                .deprecated_202007_get_tender_transaction_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::tendertransaction::Tendertransaction::get_tender_transaction`].
+    pub fn get_tender_transaction(
+        &self,
+        limit: &str,
+        since_id: &str,
+        processed_at_min: &str,
+        processed_at_max: &str,
+        processed_at: &str,
+        order: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.get_tender_transaction(
+            limit,
+            since_id,
+            processed_at_min,
+            processed_at_max,
+            processed_at,
+            order,
+        ))
+    }
+    /// The blocking version of [`crate::tendertransaction::Tendertransaction::get_tender_transaction_request`].
+    pub fn get_tender_transaction_request(
+        &self,
+    ) -> TendertransactionGetTenderTransactionRequest<'_> {
+        TendertransactionGetTenderTransactionRequest {
+            inner: self.inner.get_tender_transaction_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::tendertransaction::Tendertransaction::deprecated_202101_get_tender_transaction`].
+    pub fn deprecated_202101_get_tender_transaction(
+        &self,
+        limit: &str,
+        since_id: &str,
+        processed_at_min: &str,
+        processed_at_max: &str,
+        processed_at: &str,
+        order: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_202101_get_tender_transaction(
+                limit,
+                since_id,
+                processed_at_min,
+                processed_at_max,
+                processed_at,
+                order,
+            ))
+    }
+    /// The blocking version of [`crate::tendertransaction::Tendertransaction::deprecated_202101_get_tender_transaction_request`].
+    pub fn deprecated_202101_get_tender_transaction_request(
+        &self,
+    ) -> TendertransactionDeprecated202101GetTenderTransactionRequest<'_> {
+        TendertransactionDeprecated202101GetTenderTransactionRequest {
+            inner: self
+                .inner
+                .deprecated_202101_get_tender_transaction_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::tendertransaction::Tendertransaction::deprecated_unstable_get_tender_transaction`].
+    pub fn deprecated_unstable_get_tender_transaction(
+        &self,
+        limit: &str,
+        since_id: &str,
+        processed_at_min: &str,
+        processed_at_max: &str,
+        processed_at: &str,
+        order: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.deprecated_unstable_get_tender_transaction(
+                limit,
+                since_id,
+                processed_at_min,
+                processed_at_max,
+                processed_at,
+                order,
+            ))
+    }
+    /// The blocking version of [`crate::tendertransaction::Tendertransaction::deprecated_unstable_get_tender_transaction_request`].
+    pub fn deprecated_unstable_get_tender_transaction_request(
+        &self,
+    ) -> TendertransactionDeprecatedUnstableGetTenderTransactionRequest<'_> {
+        TendertransactionDeprecatedUnstableGetTenderTransactionRequest {
+            inner: self
+                .inner
+                .deprecated_unstable_get_tender_transaction_request(),
+            runtime: &self.runtime,
+        }
+    }
+}
@@ -1,6 +1,3159 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Customers::deprecated_202001_get`], set with a builder.
+pub struct CustomersDeprecated202001GetRequest<'a> {
+    client: &'a Customers,
+    ids: Option<&'a str>,
+    since_id: Option<&'a str>,
+    created_at_min: Option<&'a str>,
+    created_at_max: Option<&'a str>,
+    updated_at_min: Option<&'a str>,
+    updated_at_max: Option<&'a str>,
+    limit: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> CustomersDeprecated202001GetRequest<'a> {
+    /// Sets the `ids` query parameter.
+    pub fn ids(mut self, ids: &'a str) -> Self {
+        self.ids = Some(ids);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.created_at_min = Some(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.created_at_max = Some(created_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.updated_at_min = Some(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.updated_at_max = Some(updated_at_max);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            ids,
+            since_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            limit,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = created_at_max {
+            query_args.push(("created_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = created_at_min {
+            query_args.push(("created_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = ids {
+            query_args.push(("ids".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_max {
+            query_args.push(("updated_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_min {
+            query_args.push(("updated_at_min".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-01/customers.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Customers::deprecated_202001_get_search`], set with a builder.
+pub struct CustomersDeprecated202001GetSearchRequest<'a> {
+    client: &'a Customers,
+    order: Option<&'a str>,
+    query: Option<&'a str>,
+    limit: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> CustomersDeprecated202001GetSearchRequest<'a> {
+    /// Sets the `order` query parameter.
+    pub fn order(mut self, order: &'a str) -> Self {
+        self.order = Some(order);
+        self
+    }
+
+    /// Sets the `query` query parameter.
+    pub fn query(mut self, query: &'a str) -> Self {
+        self.query = Some(query);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            order,
+            query,
+            limit,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = order {
+            query_args.push(("order".to_string(), v.to_string()));
+        }
+        if let Some(v) = query {
+            query_args.push(("query".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-01/customers/search.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Customers::deprecated_202001_get_param`], set with a builder.
+pub struct CustomersDeprecated202001GetParamRequest<'a> {
+    client: &'a Customers,
+    customer_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> CustomersDeprecated202001GetParamRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            customer_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-01/customers/{}/json?{}",
+                crate::progenitor_support::encode_path(customer_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Customers::deprecated_202004_get`], set with a builder.
+pub struct CustomersDeprecated202004GetRequest<'a> {
+    client: &'a Customers,
+    ids: Option<&'a str>,
+    since_id: Option<&'a str>,
+    created_at_min: Option<&'a str>,
+    created_at_max: Option<&'a str>,
+    updated_at_min: Option<&'a str>,
+    updated_at_max: Option<&'a str>,
+    limit: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> CustomersDeprecated202004GetRequest<'a> {
+    /// Sets the `ids` query parameter.
+    pub fn ids(mut self, ids: &'a str) -> Self {
+        self.ids = Some(ids);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.created_at_min = Some(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.created_at_max = Some(created_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.updated_at_min = Some(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.updated_at_max = Some(updated_at_max);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            ids,
+            since_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            limit,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = created_at_max {
+            query_args.push(("created_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = created_at_min {
+            query_args.push(("created_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = ids {
+            query_args.push(("ids".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_max {
+            query_args.push(("updated_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_min {
+            query_args.push(("updated_at_min".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-04/customers.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Customers::deprecated_202004_get_search`], set with a builder.
+pub struct CustomersDeprecated202004GetSearchRequest<'a> {
+    client: &'a Customers,
+    order: Option<&'a str>,
+    query: Option<&'a str>,
+    limit: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> CustomersDeprecated202004GetSearchRequest<'a> {
+    /// Sets the `order` query parameter.
+    pub fn order(mut self, order: &'a str) -> Self {
+        self.order = Some(order);
+        self
+    }
+
+    /// Sets the `query` query parameter.
+    pub fn query(mut self, query: &'a str) -> Self {
+        self.query = Some(query);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            order,
+            query,
+            limit,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = order {
+            query_args.push(("order".to_string(), v.to_string()));
+        }
+        if let Some(v) = query {
+            query_args.push(("query".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-04/customers/search.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Customers::deprecated_202004_get_param`], set with a builder.
+pub struct CustomersDeprecated202004GetParamRequest<'a> {
+    client: &'a Customers,
+    customer_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> CustomersDeprecated202004GetParamRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            customer_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-04/customers/{}/json?{}",
+                crate::progenitor_support::encode_path(customer_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Customers::deprecated_202007_get`], set with a builder.
+pub struct CustomersDeprecated202007GetRequest<'a> {
+    client: &'a Customers,
+    ids: Option<&'a str>,
+    since_id: Option<&'a str>,
+    created_at_min: Option<&'a str>,
+    created_at_max: Option<&'a str>,
+    updated_at_min: Option<&'a str>,
+    updated_at_max: Option<&'a str>,
+    limit: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> CustomersDeprecated202007GetRequest<'a> {
+    /// Sets the `ids` query parameter.
+    pub fn ids(mut self, ids: &'a str) -> Self {
+        self.ids = Some(ids);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.created_at_min = Some(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.created_at_max = Some(created_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.updated_at_min = Some(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.updated_at_max = Some(updated_at_max);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            ids,
+            since_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            limit,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = created_at_max {
+            query_args.push(("created_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = created_at_min {
+            query_args.push(("created_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = ids {
+            query_args.push(("ids".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_max {
+            query_args.push(("updated_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_min {
+            query_args.push(("updated_at_min".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-07/customers.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Customers::deprecated_202007_get_search`], set with a builder.
+pub struct CustomersDeprecated202007GetSearchRequest<'a> {
+    client: &'a Customers,
+    order: Option<&'a str>,
+    query: Option<&'a str>,
+    limit: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> CustomersDeprecated202007GetSearchRequest<'a> {
+    /// Sets the `order` query parameter.
+    pub fn order(mut self, order: &'a str) -> Self {
+        self.order = Some(order);
+        self
+    }
+
+    /// Sets the `query` query parameter.
+    pub fn query(mut self, query: &'a str) -> Self {
+        self.query = Some(query);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            order,
+            query,
+            limit,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = order {
+            query_args.push(("order".to_string(), v.to_string()));
+        }
+        if let Some(v) = query {
+            query_args.push(("query".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-07/customers/search.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Customers::deprecated_202007_get_param`], set with a builder.
+pub struct CustomersDeprecated202007GetParamRequest<'a> {
+    client: &'a Customers,
+    customer_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> CustomersDeprecated202007GetParamRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            customer_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-07/customers/{}/json?{}",
+                crate::progenitor_support::encode_path(customer_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Customers::get`], set with a builder.
+pub struct CustomersGetRequest<'a> {
+    client: &'a Customers,
+    ids: Option<&'a str>,
+    since_id: Option<&'a str>,
+    created_at_min: Option<&'a str>,
+    created_at_max: Option<&'a str>,
+    updated_at_min: Option<&'a str>,
+    updated_at_max: Option<&'a str>,
+    limit: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> CustomersGetRequest<'a> {
+    /// Sets the `ids` query parameter.
+    pub fn ids(mut self, ids: &'a str) -> Self {
+        self.ids = Some(ids);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.created_at_min = Some(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.created_at_max = Some(created_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.updated_at_min = Some(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.updated_at_max = Some(updated_at_max);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            ids,
+            since_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            limit,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = created_at_max {
+            query_args.push(("created_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = created_at_min {
+            query_args.push(("created_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = ids {
+            query_args.push(("ids".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_max {
+            query_args.push(("updated_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_min {
+            query_args.push(("updated_at_min".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-10/customers.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Customers::get_search`], set with a builder.
+pub struct CustomersGetSearchRequest<'a> {
+    client: &'a Customers,
+    order: Option<&'a str>,
+    query: Option<&'a str>,
+    limit: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> CustomersGetSearchRequest<'a> {
+    /// Sets the `order` query parameter.
+    pub fn order(mut self, order: &'a str) -> Self {
+        self.order = Some(order);
+        self
+    }
+
+    /// Sets the `query` query parameter.
+    pub fn query(mut self, query: &'a str) -> Self {
+        self.query = Some(query);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            order,
+            query,
+            limit,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = order {
+            query_args.push(("order".to_string(), v.to_string()));
+        }
+        if let Some(v) = query {
+            query_args.push(("query".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-10/customers/search.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Customers::get_param`], set with a builder.
+pub struct CustomersGetParamRequest<'a> {
+    client: &'a Customers,
+    customer_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> CustomersGetParamRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            customer_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-10/customers/{}/json?{}",
+                crate::progenitor_support::encode_path(customer_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Customers::deprecated_202101_get`], set with a builder.
+pub struct CustomersDeprecated202101GetRequest<'a> {
+    client: &'a Customers,
+    ids: Option<&'a str>,
+    since_id: Option<&'a str>,
+    created_at_min: Option<&'a str>,
+    created_at_max: Option<&'a str>,
+    updated_at_min: Option<&'a str>,
+    updated_at_max: Option<&'a str>,
+    limit: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> CustomersDeprecated202101GetRequest<'a> {
+    /// Sets the `ids` query parameter.
+    pub fn ids(mut self, ids: &'a str) -> Self {
+        self.ids = Some(ids);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.created_at_min = Some(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.created_at_max = Some(created_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.updated_at_min = Some(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.updated_at_max = Some(updated_at_max);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            ids,
+            since_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            limit,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = created_at_max {
+            query_args.push(("created_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = created_at_min {
+            query_args.push(("created_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = ids {
+            query_args.push(("ids".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_max {
+            query_args.push(("updated_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_min {
+            query_args.push(("updated_at_min".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2021-01/customers.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Customers::deprecated_202101_get_search`], set with a builder.
+pub struct CustomersDeprecated202101GetSearchRequest<'a> {
+    client: &'a Customers,
+    order: Option<&'a str>,
+    query: Option<&'a str>,
+    limit: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> CustomersDeprecated202101GetSearchRequest<'a> {
+    /// Sets the `order` query parameter.
+    pub fn order(mut self, order: &'a str) -> Self {
+        self.order = Some(order);
+        self
+    }
+
+    /// Sets the `query` query parameter.
+    pub fn query(mut self, query: &'a str) -> Self {
+        self.query = Some(query);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            order,
+            query,
+            limit,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = order {
+            query_args.push(("order".to_string(), v.to_string()));
+        }
+        if let Some(v) = query {
+            query_args.push(("query".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2021-01/customers/search.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Customers::deprecated_202101_get_param`], set with a builder.
+pub struct CustomersDeprecated202101GetParamRequest<'a> {
+    client: &'a Customers,
+    customer_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> CustomersDeprecated202101GetParamRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            customer_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2021-01/customers/{}/json?{}",
+                crate::progenitor_support::encode_path(customer_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Customers::deprecated_unstable_get`], set with a builder.
+pub struct CustomersDeprecatedUnstableGetRequest<'a> {
+    client: &'a Customers,
+    ids: Option<&'a str>,
+    since_id: Option<&'a str>,
+    created_at_min: Option<&'a str>,
+    created_at_max: Option<&'a str>,
+    updated_at_min: Option<&'a str>,
+    updated_at_max: Option<&'a str>,
+    limit: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> CustomersDeprecatedUnstableGetRequest<'a> {
+    /// Sets the `ids` query parameter.
+    pub fn ids(mut self, ids: &'a str) -> Self {
+        self.ids = Some(ids);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.created_at_min = Some(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.created_at_max = Some(created_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.updated_at_min = Some(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.updated_at_max = Some(updated_at_max);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            ids,
+            since_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            limit,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = created_at_max {
+            query_args.push(("created_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = created_at_min {
+            query_args.push(("created_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = ids {
+            query_args.push(("ids".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_max {
+            query_args.push(("updated_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_min {
+            query_args.push(("updated_at_min".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/unstable/customers.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Customers::deprecated_unstable_get_search`], set with a builder.
+pub struct CustomersDeprecatedUnstableGetSearchRequest<'a> {
+    client: &'a Customers,
+    order: Option<&'a str>,
+    query: Option<&'a str>,
+    limit: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> CustomersDeprecatedUnstableGetSearchRequest<'a> {
+    /// Sets the `order` query parameter.
+    pub fn order(mut self, order: &'a str) -> Self {
+        self.order = Some(order);
+        self
+    }
+
+    /// Sets the `query` query parameter.
+    pub fn query(mut self, query: &'a str) -> Self {
+        self.query = Some(query);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            order,
+            query,
+            limit,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = order {
+            query_args.push(("order".to_string(), v.to_string()));
+        }
+        if let Some(v) = query {
+            query_args.push(("query".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/unstable/customers/search.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Customers::deprecated_unstable_get_param`], set with a builder.
+pub struct CustomersDeprecatedUnstableGetParamRequest<'a> {
+    client: &'a Customers,
+    customer_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> CustomersDeprecatedUnstableGetParamRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            customer_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/unstable/customers/{}/json?{}",
+                crate::progenitor_support::encode_path(customer_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Customers::deprecated_202001_update_param_addresses_set`], set with a builder.
+pub struct CustomersDeprecated202001UpdateParamAddressesSetRequest<'a> {
+    client: &'a Customers,
+    customer_id: &'a str,
+    address_ids: Option<i64>,
+    operation: Option<&'a str>,
+}
+
+impl<'a> CustomersDeprecated202001UpdateParamAddressesSetRequest<'a> {
+    /// Sets the `address_ids[]` query parameter.
+    pub fn address_ids(mut self, address_ids: i64) -> Self {
+        self.address_ids = Some(address_ids);
+        self
+    }
+
+    /// Sets the `operation` query parameter.
+    pub fn operation(mut self, operation: &'a str) -> Self {
+        self.operation = Some(operation);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            customer_id,
+            address_ids,
+            operation,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = address_ids {
+            query_args.push(("address_ids[]".to_string(), v.to_string()));
+        }
+        if let Some(v) = operation {
+            query_args.push(("operation".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-01/customers/{}/addresses/set.json?{}",
+                crate::progenitor_support::encode_path(customer_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .put(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Customers::deprecated_202004_update_param_addresses_set`], set with a builder.
+pub struct CustomersDeprecated202004UpdateParamAddressesSetRequest<'a> {
+    client: &'a Customers,
+    customer_id: &'a str,
+    address_ids: Option<i64>,
+    operation: Option<&'a str>,
+}
+
+impl<'a> CustomersDeprecated202004UpdateParamAddressesSetRequest<'a> {
+    /// Sets the `address_ids[]` query parameter.
+    pub fn address_ids(mut self, address_ids: i64) -> Self {
+        self.address_ids = Some(address_ids);
+        self
+    }
+
+    /// Sets the `operation` query parameter.
+    pub fn operation(mut self, operation: &'a str) -> Self {
+        self.operation = Some(operation);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            customer_id,
+            address_ids,
+            operation,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = address_ids {
+            query_args.push(("address_ids[]".to_string(), v.to_string()));
+        }
+        if let Some(v) = operation {
+            query_args.push(("operation".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-04/customers/{}/addresses/set.json?{}",
+                crate::progenitor_support::encode_path(customer_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .put(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Customers::deprecated_202007_update_param_addresses_set`], set with a builder.
+pub struct CustomersDeprecated202007UpdateParamAddressesSetRequest<'a> {
+    client: &'a Customers,
+    customer_id: &'a str,
+    address_ids: Option<i64>,
+    operation: Option<&'a str>,
+}
+
+impl<'a> CustomersDeprecated202007UpdateParamAddressesSetRequest<'a> {
+    /// Sets the `address_ids[]` query parameter.
+    pub fn address_ids(mut self, address_ids: i64) -> Self {
+        self.address_ids = Some(address_ids);
+        self
+    }
+
+    /// Sets the `operation` query parameter.
+    pub fn operation(mut self, operation: &'a str) -> Self {
+        self.operation = Some(operation);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            customer_id,
+            address_ids,
+            operation,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = address_ids {
+            query_args.push(("address_ids[]".to_string(), v.to_string()));
+        }
+        if let Some(v) = operation {
+            query_args.push(("operation".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-07/customers/{}/addresses/set.json?{}",
+                crate::progenitor_support::encode_path(customer_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .put(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Customers::update_param_addresses_set`], set with a builder.
+pub struct CustomersUpdateParamAddressesSetRequest<'a> {
+    client: &'a Customers,
+    customer_id: &'a str,
+    address_ids: Option<i64>,
+    operation: Option<&'a str>,
+}
+
+impl<'a> CustomersUpdateParamAddressesSetRequest<'a> {
+    /// Sets the `address_ids[]` query parameter.
+    pub fn address_ids(mut self, address_ids: i64) -> Self {
+        self.address_ids = Some(address_ids);
+        self
+    }
+
+    /// Sets the `operation` query parameter.
+    pub fn operation(mut self, operation: &'a str) -> Self {
+        self.operation = Some(operation);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            customer_id,
+            address_ids,
+            operation,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = address_ids {
+            query_args.push(("address_ids[]".to_string(), v.to_string()));
+        }
+        if let Some(v) = operation {
+            query_args.push(("operation".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-10/customers/{}/addresses/set.json?{}",
+                crate::progenitor_support::encode_path(customer_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .put(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Customers::deprecated_202101_update_param_addresses_set`], set with a builder.
+pub struct CustomersDeprecated202101UpdateParamAddressesSetRequest<'a> {
+    client: &'a Customers,
+    customer_id: &'a str,
+    address_ids: Option<i64>,
+    operation: Option<&'a str>,
+}
+
+impl<'a> CustomersDeprecated202101UpdateParamAddressesSetRequest<'a> {
+    /// Sets the `address_ids[]` query parameter.
+    pub fn address_ids(mut self, address_ids: i64) -> Self {
+        self.address_ids = Some(address_ids);
+        self
+    }
+
+    /// Sets the `operation` query parameter.
+    pub fn operation(mut self, operation: &'a str) -> Self {
+        self.operation = Some(operation);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            customer_id,
+            address_ids,
+            operation,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = address_ids {
+            query_args.push(("address_ids[]".to_string(), v.to_string()));
+        }
+        if let Some(v) = operation {
+            query_args.push(("operation".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2021-01/customers/{}/addresses/set.json?{}",
+                crate::progenitor_support::encode_path(customer_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .put(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Customers::deprecated_unstable_update_param_addresses_set`], set with a builder.
+pub struct CustomersDeprecatedUnstableUpdateParamAddressesSetRequest<'a> {
+    client: &'a Customers,
+    customer_id: &'a str,
+    address_ids: Option<i64>,
+    operation: Option<&'a str>,
+}
+
+impl<'a> CustomersDeprecatedUnstableUpdateParamAddressesSetRequest<'a> {
+    /// Sets the `address_ids[]` query parameter.
+    pub fn address_ids(mut self, address_ids: i64) -> Self {
+        self.address_ids = Some(address_ids);
+        self
+    }
+
+    /// Sets the `operation` query parameter.
+    pub fn operation(mut self, operation: &'a str) -> Self {
+        self.operation = Some(operation);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            customer_id,
+            address_ids,
+            operation,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = address_ids {
+            query_args.push(("address_ids[]".to_string(), v.to_string()));
+        }
+        if let Some(v) = operation {
+            query_args.push(("operation".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/unstable/customers/{}/addresses/set.json?{}",
+                crate::progenitor_support::encode_path(customer_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .put(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Customers::deprecated_202001_get_saved_searche`], set with a builder.
+pub struct CustomersDeprecated202001GetSavedSearcheRequest<'a> {
+    client: &'a Customers,
+    limit: Option<&'a str>,
+    since_id: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> CustomersDeprecated202001GetSavedSearcheRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            limit,
+            since_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-01/customer_saved_searches.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Customers::deprecated_202001_get_saved_searches_count`], set with a builder.
+pub struct CustomersDeprecated202001GetSavedSearchesCountRequest<'a> {
+    client: &'a Customers,
+    since_id: Option<&'a str>,
+}
+
+impl<'a> CustomersDeprecated202001GetSavedSearchesCountRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self { client, since_id } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-01/customer_saved_searches/count.json?{}",
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Customers::deprecated_202001_get_saved_searches_param_search`], set with a builder.
+pub struct CustomersDeprecated202001GetSavedSearchesParamSearchRequest<'a> {
+    client: &'a Customers,
+    customer_saved_search_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> CustomersDeprecated202001GetSavedSearchesParamSearchRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            customer_saved_search_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-01/customer_saved_searches/{}/json?{}",
+                crate::progenitor_support::encode_path(customer_saved_search_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Customers::deprecated_202001_get_saved_searches_param_search_customers`], set with a builder.
+pub struct CustomersDeprecated202001GetSavedSearchesParamSearchCustomersRequest<'a> {
+    client: &'a Customers,
+    customer_saved_search_id: &'a str,
+    order: Option<&'a str>,
+    limit: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> CustomersDeprecated202001GetSavedSearchesParamSearchCustomersRequest<'a> {
+    /// Sets the `order` query parameter.
+    pub fn order(mut self, order: &'a str) -> Self {
+        self.order = Some(order);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            customer_saved_search_id,
+            order,
+            limit,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = order {
+            query_args.push(("order".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-01/customer_saved_searches/{}/customers.json?{}",
+                crate::progenitor_support::encode_path(customer_saved_search_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Customers::deprecated_202004_get_saved_searche`], set with a builder.
+pub struct CustomersDeprecated202004GetSavedSearcheRequest<'a> {
+    client: &'a Customers,
+    limit: Option<&'a str>,
+    since_id: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> CustomersDeprecated202004GetSavedSearcheRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            limit,
+            since_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-04/customer_saved_searches.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Customers::deprecated_202004_get_saved_searches_count`], set with a builder.
+pub struct CustomersDeprecated202004GetSavedSearchesCountRequest<'a> {
+    client: &'a Customers,
+    since_id: Option<&'a str>,
+}
+
+impl<'a> CustomersDeprecated202004GetSavedSearchesCountRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self { client, since_id } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-04/customer_saved_searches/count.json?{}",
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Customers::deprecated_202004_get_saved_searches_param_search`], set with a builder.
+pub struct CustomersDeprecated202004GetSavedSearchesParamSearchRequest<'a> {
+    client: &'a Customers,
+    customer_saved_search_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> CustomersDeprecated202004GetSavedSearchesParamSearchRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            customer_saved_search_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-04/customer_saved_searches/{}/json?{}",
+                crate::progenitor_support::encode_path(customer_saved_search_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Customers::deprecated_202004_get_saved_searches_param_search_customers`], set with a builder.
+pub struct CustomersDeprecated202004GetSavedSearchesParamSearchCustomersRequest<'a> {
+    client: &'a Customers,
+    customer_saved_search_id: &'a str,
+    order: Option<&'a str>,
+    limit: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> CustomersDeprecated202004GetSavedSearchesParamSearchCustomersRequest<'a> {
+    /// Sets the `order` query parameter.
+    pub fn order(mut self, order: &'a str) -> Self {
+        self.order = Some(order);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            customer_saved_search_id,
+            order,
+            limit,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = order {
+            query_args.push(("order".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-04/customer_saved_searches/{}/customers.json?{}",
+                crate::progenitor_support::encode_path(customer_saved_search_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Customers::deprecated_202007_get_saved_searche`], set with a builder.
+pub struct CustomersDeprecated202007GetSavedSearcheRequest<'a> {
+    client: &'a Customers,
+    limit: Option<&'a str>,
+    since_id: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> CustomersDeprecated202007GetSavedSearcheRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            limit,
+            since_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-07/customer_saved_searches.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Customers::deprecated_202007_get_saved_searches_count`], set with a builder.
+pub struct CustomersDeprecated202007GetSavedSearchesCountRequest<'a> {
+    client: &'a Customers,
+    since_id: Option<&'a str>,
+}
+
+impl<'a> CustomersDeprecated202007GetSavedSearchesCountRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self { client, since_id } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-07/customer_saved_searches/count.json?{}",
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Customers::deprecated_202007_get_saved_searches_param_search`], set with a builder.
+pub struct CustomersDeprecated202007GetSavedSearchesParamSearchRequest<'a> {
+    client: &'a Customers,
+    customer_saved_search_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> CustomersDeprecated202007GetSavedSearchesParamSearchRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            customer_saved_search_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-07/customer_saved_searches/{}/json?{}",
+                crate::progenitor_support::encode_path(customer_saved_search_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Customers::deprecated_202007_get_saved_searches_param_search_customers`], set with a builder.
+pub struct CustomersDeprecated202007GetSavedSearchesParamSearchCustomersRequest<'a> {
+    client: &'a Customers,
+    customer_saved_search_id: &'a str,
+    order: Option<&'a str>,
+    limit: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> CustomersDeprecated202007GetSavedSearchesParamSearchCustomersRequest<'a> {
+    /// Sets the `order` query parameter.
+    pub fn order(mut self, order: &'a str) -> Self {
+        self.order = Some(order);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            customer_saved_search_id,
+            order,
+            limit,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = order {
+            query_args.push(("order".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-07/customer_saved_searches/{}/customers.json?{}",
+                crate::progenitor_support::encode_path(customer_saved_search_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Customers::get_saved_searche`], set with a builder.
+pub struct CustomersGetSavedSearcheRequest<'a> {
+    client: &'a Customers,
+    limit: Option<&'a str>,
+    since_id: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> CustomersGetSavedSearcheRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            limit,
+            since_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-10/customer_saved_searches.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Customers::get_saved_searches_count`], set with a builder.
+pub struct CustomersGetSavedSearchesCountRequest<'a> {
+    client: &'a Customers,
+    since_id: Option<&'a str>,
+}
+
+impl<'a> CustomersGetSavedSearchesCountRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self { client, since_id } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-10/customer_saved_searches/count.json?{}",
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Customers::get_saved_searches_param_search`], set with a builder.
+pub struct CustomersGetSavedSearchesParamSearchRequest<'a> {
+    client: &'a Customers,
+    customer_saved_search_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> CustomersGetSavedSearchesParamSearchRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            customer_saved_search_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-10/customer_saved_searches/{}/json?{}",
+                crate::progenitor_support::encode_path(customer_saved_search_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Customers::get_saved_searches_param_search_customers`], set with a builder.
+pub struct CustomersGetSavedSearchesParamSearchCustomersRequest<'a> {
+    client: &'a Customers,
+    customer_saved_search_id: &'a str,
+    order: Option<&'a str>,
+    limit: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> CustomersGetSavedSearchesParamSearchCustomersRequest<'a> {
+    /// Sets the `order` query parameter.
+    pub fn order(mut self, order: &'a str) -> Self {
+        self.order = Some(order);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            customer_saved_search_id,
+            order,
+            limit,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = order {
+            query_args.push(("order".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-10/customer_saved_searches/{}/customers.json?{}",
+                crate::progenitor_support::encode_path(customer_saved_search_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Customers::deprecated_202101_get_saved_searche`], set with a builder.
+pub struct CustomersDeprecated202101GetSavedSearcheRequest<'a> {
+    client: &'a Customers,
+    limit: Option<&'a str>,
+    since_id: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> CustomersDeprecated202101GetSavedSearcheRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            limit,
+            since_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2021-01/customer_saved_searches.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Customers::deprecated_202101_get_saved_searches_count`], set with a builder.
+pub struct CustomersDeprecated202101GetSavedSearchesCountRequest<'a> {
+    client: &'a Customers,
+    since_id: Option<&'a str>,
+}
+
+impl<'a> CustomersDeprecated202101GetSavedSearchesCountRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self { client, since_id } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2021-01/customer_saved_searches/count.json?{}",
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Customers::deprecated_202101_get_saved_searches_param_search`], set with a builder.
+pub struct CustomersDeprecated202101GetSavedSearchesParamSearchRequest<'a> {
+    client: &'a Customers,
+    customer_saved_search_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> CustomersDeprecated202101GetSavedSearchesParamSearchRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            customer_saved_search_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2021-01/customer_saved_searches/{}/json?{}",
+                crate::progenitor_support::encode_path(customer_saved_search_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Customers::deprecated_202101_get_saved_searches_param_search_customers`], set with a builder.
+pub struct CustomersDeprecated202101GetSavedSearchesParamSearchCustomersRequest<'a> {
+    client: &'a Customers,
+    customer_saved_search_id: &'a str,
+    order: Option<&'a str>,
+    limit: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> CustomersDeprecated202101GetSavedSearchesParamSearchCustomersRequest<'a> {
+    /// Sets the `order` query parameter.
+    pub fn order(mut self, order: &'a str) -> Self {
+        self.order = Some(order);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            customer_saved_search_id,
+            order,
+            limit,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = order {
+            query_args.push(("order".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2021-01/customer_saved_searches/{}/customers.json?{}",
+                crate::progenitor_support::encode_path(customer_saved_search_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Customers::deprecated_unstable_get_saved_searche`], set with a builder.
+pub struct CustomersDeprecatedUnstableGetSavedSearcheRequest<'a> {
+    client: &'a Customers,
+    limit: Option<&'a str>,
+    since_id: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> CustomersDeprecatedUnstableGetSavedSearcheRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            limit,
+            since_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/unstable/customer_saved_searches.json?{}",
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Customers::deprecated_unstable_get_saved_searches_count`], set with a builder.
+pub struct CustomersDeprecatedUnstableGetSavedSearchesCountRequest<'a> {
+    client: &'a Customers,
+    since_id: Option<&'a str>,
+}
+
+impl<'a> CustomersDeprecatedUnstableGetSavedSearchesCountRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self { client, since_id } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/unstable/customer_saved_searches/count.json?{}",
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Customers::deprecated_unstable_get_saved_searches_param_search`], set with a builder.
+pub struct CustomersDeprecatedUnstableGetSavedSearchesParamSearchRequest<'a> {
+    client: &'a Customers,
+    customer_saved_search_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> CustomersDeprecatedUnstableGetSavedSearchesParamSearchRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            customer_saved_search_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/unstable/customer_saved_searches/{}/json?{}",
+                crate::progenitor_support::encode_path(customer_saved_search_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Customers::deprecated_unstable_get_saved_searches_param_search_customers`], set with a builder.
+pub struct CustomersDeprecatedUnstableGetSavedSearchesParamSearchCustomersRequest<'a> {
+    client: &'a Customers,
+    customer_saved_search_id: &'a str,
+    order: Option<&'a str>,
+    limit: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> CustomersDeprecatedUnstableGetSavedSearchesParamSearchCustomersRequest<'a> {
+    /// Sets the `order` query parameter.
+    pub fn order(mut self, order: &'a str) -> Self {
+        self.order = Some(order);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            customer_saved_search_id,
+            order,
+            limit,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = order {
+            query_args.push(("order".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/unstable/customer_saved_searches/{}/customers.json?{}",
+                crate::progenitor_support::encode_path(customer_saved_search_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct Customers {
     pub client: Client,
 }
@@ -81,6 +3234,28 @@ impl Customers {
             )
             .await
     }
+    /**
+     * Retrieves a list of customers. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-01/customers.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_get`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer#index-2020-01
+     */
+    pub fn deprecated_202001_get_request(&self) -> CustomersDeprecated202001GetRequest<'_> {
+        CustomersDeprecated202001GetRequest {
+            client: self,
+            ids: None,
+            since_id: None,
+            created_at_min: None,
+            created_at_max: None,
+            updated_at_min: None,
+            updated_at_max: None,
+            limit: None,
+            fields: None,
+        }
+    }
     /**
      * Creates a customer.
      *
@@ -154,6 +3329,26 @@ impl Customers {
             )
             .await
     }
+    /**
+     * Searches for customers that match a supplied query. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-01/customers/search.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_get_search`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer#search-2020-01
+     */
+    pub fn deprecated_202001_get_search_request(
+        &self,
+    ) -> CustomersDeprecated202001GetSearchRequest<'_> {
+        CustomersDeprecated202001GetSearchRequest {
+            client: self,
+            order: None,
+            query: None,
+            limit: None,
+            fields: None,
+        }
+    }
     /**
      * Retrieves a single customer.
      *
@@ -194,6 +3389,25 @@ impl Customers {
             )
             .await
     }
+    /**
+     * Retrieves a single customer.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-01/customers/{customer_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_get_param`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer#show-2020-01
+     */
+    pub fn deprecated_202001_get_param_request<'a>(
+        &'a self,
+        customer_id: &'a str,
+    ) -> CustomersDeprecated202001GetParamRequest<'a> {
+        CustomersDeprecated202001GetParamRequest {
+            client: self,
+            customer_id,
+            fields: None,
+        }
+    }
     /**
      * Updates a customer.
      *
@@ -449,6 +3663,28 @@ impl Customers {
             )
             .await
     }
+    /**
+     * Retrieves a list of customers. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-04/customers.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_get`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer#index-2020-04
+     */
+    pub fn deprecated_202004_get_request(&self) -> CustomersDeprecated202004GetRequest<'_> {
+        CustomersDeprecated202004GetRequest {
+            client: self,
+            ids: None,
+            since_id: None,
+            created_at_min: None,
+            created_at_max: None,
+            updated_at_min: None,
+            updated_at_max: None,
+            limit: None,
+            fields: None,
+        }
+    }
     /**
      * Creates a customer.
      *
@@ -522,6 +3758,26 @@ impl Customers {
             )
             .await
     }
+    /**
+     * Searches for customers that match a supplied query. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-04/customers/search.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_get_search`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer#search-2020-04
+     */
+    pub fn deprecated_202004_get_search_request(
+        &self,
+    ) -> CustomersDeprecated202004GetSearchRequest<'_> {
+        CustomersDeprecated202004GetSearchRequest {
+            client: self,
+            order: None,
+            query: None,
+            limit: None,
+            fields: None,
+        }
+    }
     /**
      * Retrieves a single customer.
      *
@@ -562,6 +3818,25 @@ impl Customers {
             )
             .await
     }
+    /**
+     * Retrieves a single customer.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-04/customers/{customer_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_get_param`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer#show-2020-04
+     */
+    pub fn deprecated_202004_get_param_request<'a>(
+        &'a self,
+        customer_id: &'a str,
+    ) -> CustomersDeprecated202004GetParamRequest<'a> {
+        CustomersDeprecated202004GetParamRequest {
+            client: self,
+            customer_id,
+            fields: None,
+        }
+    }
     /**
      * Updates a customer.
      *
@@ -817,6 +4092,28 @@ impl Customers {
             )
             .await
     }
+    /**
+     * Retrieves a list of customers. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-07/customers.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_get`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer#index-2020-07
+     */
+    pub fn deprecated_202007_get_request(&self) -> CustomersDeprecated202007GetRequest<'_> {
+        CustomersDeprecated202007GetRequest {
+            client: self,
+            ids: None,
+            since_id: None,
+            created_at_min: None,
+            created_at_max: None,
+            updated_at_min: None,
+            updated_at_max: None,
+            limit: None,
+            fields: None,
+        }
+    }
     /**
      * Creates a customer.
      *
@@ -890,6 +4187,26 @@ impl Customers {
             )
             .await
     }
+    /**
+     * Searches for customers that match a supplied query. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-07/customers/search.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_get_search`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer#search-2020-07
+     */
+    pub fn deprecated_202007_get_search_request(
+        &self,
+    ) -> CustomersDeprecated202007GetSearchRequest<'_> {
+        CustomersDeprecated202007GetSearchRequest {
+            client: self,
+            order: None,
+            query: None,
+            limit: None,
+            fields: None,
+        }
+    }
     /**
      * Retrieves a single customer.
      *
@@ -930,6 +4247,25 @@ impl Customers {
             )
             .await
     }
+    /**
+     * Retrieves a single customer.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-07/customers/{customer_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_get_param`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer#show-2020-07
+     */
+    pub fn deprecated_202007_get_param_request<'a>(
+        &'a self,
+        customer_id: &'a str,
+    ) -> CustomersDeprecated202007GetParamRequest<'a> {
+        CustomersDeprecated202007GetParamRequest {
+            client: self,
+            customer_id,
+            fields: None,
+        }
+    }
     /**
      * Updates a customer.
      *
@@ -1185,6 +4521,28 @@ impl Customers {
             )
             .await
     }
+    /**
+     * Retrieves a list of customers. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-10/customers.json` endpoint.
+     *
+     * As opposed to `get`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer#index-2020-10
+     */
+    pub fn get_request(&self) -> CustomersGetRequest<'_> {
+        CustomersGetRequest {
+            client: self,
+            ids: None,
+            since_id: None,
+            created_at_min: None,
+            created_at_max: None,
+            updated_at_min: None,
+            updated_at_max: None,
+            limit: None,
+            fields: None,
+        }
+    }
     /**
      * Creates a customer.
      *
@@ -1255,6 +4613,24 @@ impl Customers {
             )
             .await
     }
+    /**
+     * Searches for customers that match a supplied query. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-10/customers/search.json` endpoint.
+     *
+     * As opposed to `get_search`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer#search-2020-10
+     */
+    pub fn get_search_request(&self) -> CustomersGetSearchRequest<'_> {
+        CustomersGetSearchRequest {
+            client: self,
+            order: None,
+            query: None,
+            limit: None,
+            fields: None,
+        }
+    }
     /**
      * Retrieves a single customer.
      *
@@ -1295,6 +4671,22 @@ impl Customers {
             )
             .await
     }
+    /**
+     * Retrieves a single customer.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-10/customers/{customer_id}.json` endpoint.
+     *
+     * As opposed to `get_param`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer#show-2020-10
+     */
+    pub fn get_param_request<'a>(&'a self, customer_id: &'a str) -> CustomersGetParamRequest<'a> {
+        CustomersGetParamRequest {
+            client: self,
+            customer_id,
+            fields: None,
+        }
+    }
     /**
      * Updates a customer.
      *
@@ -1544,6 +4936,28 @@ impl Customers {
             )
             .await
     }
+    /**
+     * Retrieves a list of customers. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2021-01/customers.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_get`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer#index-2021-01
+     */
+    pub fn deprecated_202101_get_request(&self) -> CustomersDeprecated202101GetRequest<'_> {
+        CustomersDeprecated202101GetRequest {
+            client: self,
+            ids: None,
+            since_id: None,
+            created_at_min: None,
+            created_at_max: None,
+            updated_at_min: None,
+            updated_at_max: None,
+            limit: None,
+            fields: None,
+        }
+    }
     /**
      * Creates a customer.
      *
@@ -1617,6 +5031,26 @@ impl Customers {
             )
             .await
     }
+    /**
+     * Searches for customers that match a supplied query. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2021-01/customers/search.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_get_search`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer#search-2021-01
+     */
+    pub fn deprecated_202101_get_search_request(
+        &self,
+    ) -> CustomersDeprecated202101GetSearchRequest<'_> {
+        CustomersDeprecated202101GetSearchRequest {
+            client: self,
+            order: None,
+            query: None,
+            limit: None,
+            fields: None,
+        }
+    }
     /**
      * Retrieves a single customer.
      *
@@ -1657,6 +5091,25 @@ impl Customers {
             )
             .await
     }
+    /**
+     * Retrieves a single customer.
+     *
+     * This function performs a `GET` to the `/admin/api/2021-01/customers/{customer_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_get_param`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer#show-2021-01
+     */
+    pub fn deprecated_202101_get_param_request<'a>(
+        &'a self,
+        customer_id: &'a str,
+    ) -> CustomersDeprecated202101GetParamRequest<'a> {
+        CustomersDeprecated202101GetParamRequest {
+            client: self,
+            customer_id,
+            fields: None,
+        }
+    }
     /**
      * Updates a customer.
      *
@@ -1912,6 +5365,28 @@ impl Customers {
             )
             .await
     }
+    /**
+     * Retrieves a list of customers. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/unstable/customers.json` endpoint.
+     *
+     * As opposed to `deprecated_unstable_get`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer#index-unstable
+     */
+    pub fn deprecated_unstable_get_request(&self) -> CustomersDeprecatedUnstableGetRequest<'_> {
+        CustomersDeprecatedUnstableGetRequest {
+            client: self,
+            ids: None,
+            since_id: None,
+            created_at_min: None,
+            created_at_max: None,
+            updated_at_min: None,
+            updated_at_max: None,
+            limit: None,
+            fields: None,
+        }
+    }
     /**
      * Creates a customer.
      *
@@ -1985,6 +5460,26 @@ impl Customers {
             )
             .await
     }
+    /**
+     * Searches for customers that match a supplied query. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/unstable/customers/search.json` endpoint.
+     *
+     * As opposed to `deprecated_unstable_get_search`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer#search-unstable
+     */
+    pub fn deprecated_unstable_get_search_request(
+        &self,
+    ) -> CustomersDeprecatedUnstableGetSearchRequest<'_> {
+        CustomersDeprecatedUnstableGetSearchRequest {
+            client: self,
+            order: None,
+            query: None,
+            limit: None,
+            fields: None,
+        }
+    }
     /**
      * Retrieves a single customer.
      *
@@ -2025,6 +5520,25 @@ impl Customers {
             )
             .await
     }
+    /**
+     * Retrieves a single customer.
+     *
+     * This function performs a `GET` to the `/admin/api/unstable/customers/{customer_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_unstable_get_param`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer#show-unstable
+     */
+    pub fn deprecated_unstable_get_param_request<'a>(
+        &'a self,
+        customer_id: &'a str,
+    ) -> CustomersDeprecatedUnstableGetParamRequest<'a> {
+        CustomersDeprecatedUnstableGetParamRequest {
+            client: self,
+            customer_id,
+            fields: None,
+        }
+    }
     /**
      * Updates a customer.
      *
@@ -2426,6 +5940,26 @@ impl Customers {
             )
             .await
     }
+    /**
+     * Performs bulk operations for multiple customer addresses.
+     *
+     * This function performs a `PUT` to the `/admin/api/2020-01/customers/{customer_id}/addresses/set.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_update_param_addresses_set`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer-address#set-2020-01
+     */
+    pub fn deprecated_202001_update_param_addresses_set_request<'a>(
+        &'a self,
+        customer_id: &'a str,
+    ) -> CustomersDeprecated202001UpdateParamAddressesSetRequest<'a> {
+        CustomersDeprecated202001UpdateParamAddressesSetRequest {
+            client: self,
+            customer_id,
+            address_ids: None,
+            operation: None,
+        }
+    }
     /**
      * Sets the default address for a customer.
      *
@@ -2677,6 +6211,26 @@ impl Customers {
             )
             .await
     }
+    /**
+     * Performs bulk operations for multiple customer addresses.
+     *
+     * This function performs a `PUT` to the `/admin/api/2020-04/customers/{customer_id}/addresses/set.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_update_param_addresses_set`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer-address#set-2020-04
+     */
+    pub fn deprecated_202004_update_param_addresses_set_request<'a>(
+        &'a self,
+        customer_id: &'a str,
+    ) -> CustomersDeprecated202004UpdateParamAddressesSetRequest<'a> {
+        CustomersDeprecated202004UpdateParamAddressesSetRequest {
+            client: self,
+            customer_id,
+            address_ids: None,
+            operation: None,
+        }
+    }
     /**
      * Sets the default address for a customer.
      *
@@ -2928,6 +6482,26 @@ impl Customers {
             )
             .await
     }
+    /**
+     * Performs bulk operations for multiple customer addresses.
+     *
+     * This function performs a `PUT` to the `/admin/api/2020-07/customers/{customer_id}/addresses/set.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_update_param_addresses_set`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer-address#set-2020-07
+     */
+    pub fn deprecated_202007_update_param_addresses_set_request<'a>(
+        &'a self,
+        customer_id: &'a str,
+    ) -> CustomersDeprecated202007UpdateParamAddressesSetRequest<'a> {
+        CustomersDeprecated202007UpdateParamAddressesSetRequest {
+            client: self,
+            customer_id,
+            address_ids: None,
+            operation: None,
+        }
+    }
     /**
      * Sets the default address for a customer.
      *
@@ -3176,6 +6750,26 @@ impl Customers {
             )
             .await
     }
+    /**
+     * Performs bulk operations for multiple customer addresses.
+     *
+     * This function performs a `PUT` to the `/admin/api/2020-10/customers/{customer_id}/addresses/set.json` endpoint.
+     *
+     * As opposed to `update_param_addresses_set`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer-address#set-2020-10
+     */
+    pub fn update_param_addresses_set_request<'a>(
+        &'a self,
+        customer_id: &'a str,
+    ) -> CustomersUpdateParamAddressesSetRequest<'a> {
+        CustomersUpdateParamAddressesSetRequest {
+            client: self,
+            customer_id,
+            address_ids: None,
+            operation: None,
+        }
+    }
     /**
      * Sets the default address for a customer.
      *
@@ -3427,6 +7021,26 @@ impl Customers {
             )
             .await
     }
+    /**
+     * Performs bulk operations for multiple customer addresses.
+     *
+     * This function performs a `PUT` to the `/admin/api/2021-01/customers/{customer_id}/addresses/set.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_update_param_addresses_set`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer-address#set-2021-01
+     */
+    pub fn deprecated_202101_update_param_addresses_set_request<'a>(
+        &'a self,
+        customer_id: &'a str,
+    ) -> CustomersDeprecated202101UpdateParamAddressesSetRequest<'a> {
+        CustomersDeprecated202101UpdateParamAddressesSetRequest {
+            client: self,
+            customer_id,
+            address_ids: None,
+            operation: None,
+        }
+    }
     /**
      * Sets the default address for a customer.
      *
@@ -3678,6 +7292,26 @@ impl Customers {
             )
             .await
     }
+    /**
+     * Performs bulk operations for multiple customer addresses.
+     *
+     * This function performs a `PUT` to the `/admin/api/unstable/customers/{customer_id}/addresses/set.json` endpoint.
+     *
+     * As opposed to `deprecated_unstable_update_param_addresses_set`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer-address#set-unstable
+     */
+    pub fn deprecated_unstable_update_param_addresses_set_request<'a>(
+        &'a self,
+        customer_id: &'a str,
+    ) -> CustomersDeprecatedUnstableUpdateParamAddressesSetRequest<'a> {
+        CustomersDeprecatedUnstableUpdateParamAddressesSetRequest {
+            client: self,
+            customer_id,
+            address_ids: None,
+            operation: None,
+        }
+    }
     /**
      * Sets the default address for a customer.
      *
@@ -3758,6 +7392,25 @@ impl Customers {
             )
             .await
     }
+    /**
+     * Retrieves a list of customer saved searches. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-01/customer_saved_searches.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_get_saved_searche`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/customers/customersavedsearch#index-2020-01
+     */
+    pub fn deprecated_202001_get_saved_searche_request(
+        &self,
+    ) -> CustomersDeprecated202001GetSavedSearcheRequest<'_> {
+        CustomersDeprecated202001GetSavedSearcheRequest {
+            client: self,
+            limit: None,
+            since_id: None,
+            fields: None,
+        }
+    }
     /**
      * Creates a customer saved search.
      *
@@ -3819,6 +7472,23 @@ impl Customers {
             )
             .await
     }
+    /**
+     * Retrieves a count of all customer saved searches.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-01/customer_saved_searches/count.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_get_saved_searches_count`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/customers/customersavedsearch#count-2020-01
+     */
+    pub fn deprecated_202001_get_saved_searches_count_request(
+        &self,
+    ) -> CustomersDeprecated202001GetSavedSearchesCountRequest<'_> {
+        CustomersDeprecated202001GetSavedSearchesCountRequest {
+            client: self,
+            since_id: None,
+        }
+    }
     /**
      * Retrieves a single customer saved search.
      *
@@ -3859,6 +7529,25 @@ impl Customers {
             )
             .await
     }
+    /**
+     * Retrieves a single customer saved search.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-01/customer_saved_searches/{customer_saved_search_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_get_saved_searches_param_search`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/customers/customersavedsearch#show-2020-01
+     */
+    pub fn deprecated_202001_get_saved_searches_param_search_request<'a>(
+        &'a self,
+        customer_saved_search_id: &'a str,
+    ) -> CustomersDeprecated202001GetSavedSearchesParamSearchRequest<'a> {
+        CustomersDeprecated202001GetSavedSearchesParamSearchRequest {
+            client: self,
+            customer_saved_search_id,
+            fields: None,
+        }
+    }
     /**
      * Updates a customer saved search.
      *
@@ -3976,6 +7665,27 @@ impl Customers {
             )
             .await
     }
+    /**
+     * Retrieves all customers returned by a customer saved search.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-01/customer_saved_searches/{customer_saved_search_id}/customers.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_get_saved_searches_param_search_customers`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/customers/customersavedsearch#other-2020-01
+     */
+    pub fn deprecated_202001_get_saved_searches_param_search_customers_request<'a>(
+        &'a self,
+        customer_saved_search_id: &'a str,
+    ) -> CustomersDeprecated202001GetSavedSearchesParamSearchCustomersRequest<'a> {
+        CustomersDeprecated202001GetSavedSearchesParamSearchCustomersRequest {
+            client: self,
+            customer_saved_search_id,
+            order: None,
+            limit: None,
+            fields: None,
+        }
+    }
     /**
      * Retrieves a list of customer saved searches. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -4021,6 +7731,25 @@ impl Customers {
             )
             .await
     }
+    /**
+     * Retrieves a list of customer saved searches. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-04/customer_saved_searches.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_get_saved_searche`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/customers/customersavedsearch#index-2020-04
+     */
+    pub fn deprecated_202004_get_saved_searche_request(
+        &self,
+    ) -> CustomersDeprecated202004GetSavedSearcheRequest<'_> {
+        CustomersDeprecated202004GetSavedSearcheRequest {
+            client: self,
+            limit: None,
+            since_id: None,
+            fields: None,
+        }
+    }
     /**
      * Creates a customer saved search.
      *
@@ -4082,6 +7811,23 @@ impl Customers {
             )
             .await
     }
+    /**
+     * Retrieves a count of all customer saved searches.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-04/customer_saved_searches/count.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_get_saved_searches_count`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/customers/customersavedsearch#count-2020-04
+     */
+    pub fn deprecated_202004_get_saved_searches_count_request(
+        &self,
+    ) -> CustomersDeprecated202004GetSavedSearchesCountRequest<'_> {
+        CustomersDeprecated202004GetSavedSearchesCountRequest {
+            client: self,
+            since_id: None,
+        }
+    }
     /**
      * Retrieves a single customer saved search.
      *
@@ -4122,6 +7868,25 @@ impl Customers {
             )
             .await
     }
+    /**
+     * Retrieves a single customer saved search.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-04/customer_saved_searches/{customer_saved_search_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_get_saved_searches_param_search`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/customers/customersavedsearch#show-2020-04
+     */
+    pub fn deprecated_202004_get_saved_searches_param_search_request<'a>(
+        &'a self,
+        customer_saved_search_id: &'a str,
+    ) -> CustomersDeprecated202004GetSavedSearchesParamSearchRequest<'a> {
+        CustomersDeprecated202004GetSavedSearchesParamSearchRequest {
+            client: self,
+            customer_saved_search_id,
+            fields: None,
+        }
+    }
     /**
      * Updates a customer saved search.
      *
@@ -4239,6 +8004,27 @@ impl Customers {
             )
             .await
     }
+    /**
+     * Retrieves all customers returned by a customer saved search.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-04/customer_saved_searches/{customer_saved_search_id}/customers.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_get_saved_searches_param_search_customers`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/customers/customersavedsearch#other-2020-04
+     */
+    pub fn deprecated_202004_get_saved_searches_param_search_customers_request<'a>(
+        &'a self,
+        customer_saved_search_id: &'a str,
+    ) -> CustomersDeprecated202004GetSavedSearchesParamSearchCustomersRequest<'a> {
+        CustomersDeprecated202004GetSavedSearchesParamSearchCustomersRequest {
+            client: self,
+            customer_saved_search_id,
+            order: None,
+            limit: None,
+            fields: None,
+        }
+    }
     /**
      * Retrieves a list of customer saved searches. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -4284,6 +8070,25 @@ impl Customers {
             )
             .await
     }
+    /**
+     * Retrieves a list of customer saved searches. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-07/customer_saved_searches.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_get_saved_searche`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/customers/customersavedsearch#index-2020-07
+     */
+    pub fn deprecated_202007_get_saved_searche_request(
+        &self,
+    ) -> CustomersDeprecated202007GetSavedSearcheRequest<'_> {
+        CustomersDeprecated202007GetSavedSearcheRequest {
+            client: self,
+            limit: None,
+            since_id: None,
+            fields: None,
+        }
+    }
     /**
      * Creates a customer saved search.
      *
@@ -4345,6 +8150,23 @@ impl Customers {
             )
             .await
     }
+    /**
+     * Retrieves a count of all customer saved searches.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-07/customer_saved_searches/count.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_get_saved_searches_count`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/customers/customersavedsearch#count-2020-07
+     */
+    pub fn deprecated_202007_get_saved_searches_count_request(
+        &self,
+    ) -> CustomersDeprecated202007GetSavedSearchesCountRequest<'_> {
+        CustomersDeprecated202007GetSavedSearchesCountRequest {
+            client: self,
+            since_id: None,
+        }
+    }
     /**
      * Retrieves a single customer saved search.
      *
@@ -4385,6 +8207,25 @@ impl Customers {
             )
             .await
     }
+    /**
+     * Retrieves a single customer saved search.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-07/customer_saved_searches/{customer_saved_search_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_get_saved_searches_param_search`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/customers/customersavedsearch#show-2020-07
+     */
+    pub fn deprecated_202007_get_saved_searches_param_search_request<'a>(
+        &'a self,
+        customer_saved_search_id: &'a str,
+    ) -> CustomersDeprecated202007GetSavedSearchesParamSearchRequest<'a> {
+        CustomersDeprecated202007GetSavedSearchesParamSearchRequest {
+            client: self,
+            customer_saved_search_id,
+            fields: None,
+        }
+    }
     /**
      * Updates a customer saved search.
      *
@@ -4502,6 +8343,27 @@ impl Customers {
             )
             .await
     }
+    /**
+     * Retrieves all customers returned by a customer saved search.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-07/customer_saved_searches/{customer_saved_search_id}/customers.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_get_saved_searches_param_search_customers`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/customers/customersavedsearch#other-2020-07
+     */
+    pub fn deprecated_202007_get_saved_searches_param_search_customers_request<'a>(
+        &'a self,
+        customer_saved_search_id: &'a str,
+    ) -> CustomersDeprecated202007GetSavedSearchesParamSearchCustomersRequest<'a> {
+        CustomersDeprecated202007GetSavedSearchesParamSearchCustomersRequest {
+            client: self,
+            customer_saved_search_id,
+            order: None,
+            limit: None,
+            fields: None,
+        }
+    }
     /**
      * Retrieves a list of customer saved searches. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -4547,6 +8409,23 @@ impl Customers {
             )
             .await
     }
+    /**
+     * Retrieves a list of customer saved searches. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-10/customer_saved_searches.json` endpoint.
+     *
+     * As opposed to `get_saved_searche`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/customers/customersavedsearch#index-2020-10
+     */
+    pub fn get_saved_searche_request(&self) -> CustomersGetSavedSearcheRequest<'_> {
+        CustomersGetSavedSearcheRequest {
+            client: self,
+            limit: None,
+            since_id: None,
+            fields: None,
+        }
+    }
     /**
      * Creates a customer saved search.
      *
@@ -4608,6 +8487,21 @@ impl Customers {
             )
             .await
     }
+    /**
+     * Retrieves a count of all customer saved searches.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-10/customer_saved_searches/count.json` endpoint.
+     *
+     * As opposed to `get_saved_searches_count`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/customers/customersavedsearch#count-2020-10
+     */
+    pub fn get_saved_searches_count_request(&self) -> CustomersGetSavedSearchesCountRequest<'_> {
+        CustomersGetSavedSearchesCountRequest {
+            client: self,
+            since_id: None,
+        }
+    }
     /**
      * Retrieves a single customer saved search.
      *
@@ -4648,6 +8542,25 @@ impl Customers {
             )
             .await
     }
+    /**
+     * Retrieves a single customer saved search.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-10/customer_saved_searches/{customer_saved_search_id}.json` endpoint.
+     *
+     * As opposed to `get_saved_searches_param_search`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/customers/customersavedsearch#show-2020-10
+     */
+    pub fn get_saved_searches_param_search_request<'a>(
+        &'a self,
+        customer_saved_search_id: &'a str,
+    ) -> CustomersGetSavedSearchesParamSearchRequest<'a> {
+        CustomersGetSavedSearchesParamSearchRequest {
+            client: self,
+            customer_saved_search_id,
+            fields: None,
+        }
+    }
     /**
      * Updates a customer saved search.
      *
@@ -4765,6 +8678,27 @@ impl Customers {
             )
             .await
     }
+    /**
+     * Retrieves all customers returned by a customer saved search.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-10/customer_saved_searches/{customer_saved_search_id}/customers.json` endpoint.
+     *
+     * As opposed to `get_saved_searches_param_search_customers`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/customers/customersavedsearch#other-2020-10
+     */
+    pub fn get_saved_searches_param_search_customers_request<'a>(
+        &'a self,
+        customer_saved_search_id: &'a str,
+    ) -> CustomersGetSavedSearchesParamSearchCustomersRequest<'a> {
+        CustomersGetSavedSearchesParamSearchCustomersRequest {
+            client: self,
+            customer_saved_search_id,
+            order: None,
+            limit: None,
+            fields: None,
+        }
+    }
     /**
      * Retrieves a list of customer saved searches. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -4810,6 +8744,25 @@ impl Customers {
             )
             .await
     }
+    /**
+     * Retrieves a list of customer saved searches. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2021-01/customer_saved_searches.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_get_saved_searche`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/customers/customersavedsearch#index-2021-01
+     */
+    pub fn deprecated_202101_get_saved_searche_request(
+        &self,
+    ) -> CustomersDeprecated202101GetSavedSearcheRequest<'_> {
+        CustomersDeprecated202101GetSavedSearcheRequest {
+            client: self,
+            limit: None,
+            since_id: None,
+            fields: None,
+        }
+    }
     /**
      * Creates a customer saved search.
      *
@@ -4871,6 +8824,23 @@ impl Customers {
             )
             .await
     }
+    /**
+     * Retrieves a count of all customer saved searches.
+     *
+     * This function performs a `GET` to the `/admin/api/2021-01/customer_saved_searches/count.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_get_saved_searches_count`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/customers/customersavedsearch#count-2021-01
+     */
+    pub fn deprecated_202101_get_saved_searches_count_request(
+        &self,
+    ) -> CustomersDeprecated202101GetSavedSearchesCountRequest<'_> {
+        CustomersDeprecated202101GetSavedSearchesCountRequest {
+            client: self,
+            since_id: None,
+        }
+    }
     /**
      * Retrieves a single customer saved search.
      *
@@ -4911,6 +8881,25 @@ impl Customers {
             )
             .await
     }
+    /**
+     * Retrieves a single customer saved search.
+     *
+     * This function performs a `GET` to the `/admin/api/2021-01/customer_saved_searches/{customer_saved_search_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_get_saved_searches_param_search`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/customers/customersavedsearch#show-2021-01
+     */
+    pub fn deprecated_202101_get_saved_searches_param_search_request<'a>(
+        &'a self,
+        customer_saved_search_id: &'a str,
+    ) -> CustomersDeprecated202101GetSavedSearchesParamSearchRequest<'a> {
+        CustomersDeprecated202101GetSavedSearchesParamSearchRequest {
+            client: self,
+            customer_saved_search_id,
+            fields: None,
+        }
+    }
     /**
      * Updates a customer saved search.
      *
@@ -5028,6 +9017,27 @@ impl Customers {
             )
             .await
     }
+    /**
+     * Retrieves all customers returned by a customer saved search.
+     *
+     * This function performs a `GET` to the `/admin/api/2021-01/customer_saved_searches/{customer_saved_search_id}/customers.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_get_saved_searches_param_search_customers`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/customers/customersavedsearch#other-2021-01
+     */
+    pub fn deprecated_202101_get_saved_searches_param_search_customers_request<'a>(
+        &'a self,
+        customer_saved_search_id: &'a str,
+    ) -> CustomersDeprecated202101GetSavedSearchesParamSearchCustomersRequest<'a> {
+        CustomersDeprecated202101GetSavedSearchesParamSearchCustomersRequest {
+            client: self,
+            customer_saved_search_id,
+            order: None,
+            limit: None,
+            fields: None,
+        }
+    }
     /**
      * Retrieves a list of customer saved searches. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -5076,6 +9086,25 @@ impl Customers {
             )
             .await
     }
+    /**
+     * Retrieves a list of customer saved searches. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/unstable/customer_saved_searches.json` endpoint.
+     *
+     * As opposed to `deprecated_unstable_get_saved_searche`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/customers/customersavedsearch#index-unstable
+     */
+    pub fn deprecated_unstable_get_saved_searche_request(
+        &self,
+    ) -> CustomersDeprecatedUnstableGetSavedSearcheRequest<'_> {
+        CustomersDeprecatedUnstableGetSavedSearcheRequest {
+            client: self,
+            limit: None,
+            since_id: None,
+            fields: None,
+        }
+    }
     /**
      * Creates a customer saved search.
      *
@@ -5137,6 +9166,23 @@ impl Customers {
             )
             .await
     }
+    /**
+     * Retrieves a count of all customer saved searches.
+     *
+     * This function performs a `GET` to the `/admin/api/unstable/customer_saved_searches/count.json` endpoint.
+     *
+     * As opposed to `deprecated_unstable_get_saved_searches_count`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/customers/customersavedsearch#count-unstable
+     */
+    pub fn deprecated_unstable_get_saved_searches_count_request(
+        &self,
+    ) -> CustomersDeprecatedUnstableGetSavedSearchesCountRequest<'_> {
+        CustomersDeprecatedUnstableGetSavedSearchesCountRequest {
+            client: self,
+            since_id: None,
+        }
+    }
     /**
      * Retrieves a single customer saved search.
      *
@@ -5177,6 +9223,25 @@ impl Customers {
             )
             .await
     }
+    /**
+     * Retrieves a single customer saved search.
+     *
+     * This function performs a `GET` to the `/admin/api/unstable/customer_saved_searches/{customer_saved_search_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_unstable_get_saved_searches_param_search`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/customers/customersavedsearch#show-unstable
+     */
+    pub fn deprecated_unstable_get_saved_searches_param_search_request<'a>(
+        &'a self,
+        customer_saved_search_id: &'a str,
+    ) -> CustomersDeprecatedUnstableGetSavedSearchesParamSearchRequest<'a> {
+        CustomersDeprecatedUnstableGetSavedSearchesParamSearchRequest {
+            client: self,
+            customer_saved_search_id,
+            fields: None,
+        }
+    }
     /**
      * Updates a customer saved search.
      *
@@ -5294,4 +9359,25 @@ impl Customers {
             )
             .await
     }
+    /**
+     * Retrieves all customers returned by a customer saved search.
+     *
+     * This function performs a `GET` to the `/admin/api/unstable/customer_saved_searches/{customer_saved_search_id}/customers.json` endpoint.
+     *
+     * As opposed to `deprecated_unstable_get_saved_searches_param_search_customers`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/customers/customersavedsearch#other-unstable
+     */
+    pub fn deprecated_unstable_get_saved_searches_param_search_customers_request<'a>(
+        &'a self,
+        customer_saved_search_id: &'a str,
+    ) -> CustomersDeprecatedUnstableGetSavedSearchesParamSearchCustomersRequest<'a> {
+        CustomersDeprecatedUnstableGetSavedSearchesParamSearchCustomersRequest {
+            client: self,
+            customer_saved_search_id,
+            order: None,
+            limit: None,
+            fields: None,
+        }
+    }
 }
@@ -1,6 +1,240 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Discounts::deprecated_202001_get_codes_lookup`], set with a builder.
+pub struct DiscountsDeprecated202001GetCodesLookupRequest<'a> {
+    client: &'a Discounts,
+    code: Option<i64>,
+}
+
+impl<'a> DiscountsDeprecated202001GetCodesLookupRequest<'a> {
+    /// Sets the `code` query parameter.
+    pub fn code(mut self, code: i64) -> Self {
+        self.code = Some(code);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self { client, code } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = code {
+            query_args.push(("code".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-01/discount_codes/lookup.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Discounts::deprecated_202004_get_codes_lookup`], set with a builder.
+pub struct DiscountsDeprecated202004GetCodesLookupRequest<'a> {
+    client: &'a Discounts,
+    code: Option<i64>,
+}
+
+impl<'a> DiscountsDeprecated202004GetCodesLookupRequest<'a> {
+    /// Sets the `code` query parameter.
+    pub fn code(mut self, code: i64) -> Self {
+        self.code = Some(code);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self { client, code } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = code {
+            query_args.push(("code".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-04/discount_codes/lookup.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Discounts::deprecated_202007_get_codes_lookup`], set with a builder.
+pub struct DiscountsDeprecated202007GetCodesLookupRequest<'a> {
+    client: &'a Discounts,
+    code: Option<i64>,
+}
+
+impl<'a> DiscountsDeprecated202007GetCodesLookupRequest<'a> {
+    /// Sets the `code` query parameter.
+    pub fn code(mut self, code: i64) -> Self {
+        self.code = Some(code);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self { client, code } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = code {
+            query_args.push(("code".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-07/discount_codes/lookup.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Discounts::get_codes_lookup`], set with a builder.
+pub struct DiscountsGetCodesLookupRequest<'a> {
+    client: &'a Discounts,
+    code: Option<i64>,
+}
+
+impl<'a> DiscountsGetCodesLookupRequest<'a> {
+    /// Sets the `code` query parameter.
+    pub fn code(mut self, code: i64) -> Self {
+        self.code = Some(code);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self { client, code } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = code {
+            query_args.push(("code".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-10/discount_codes/lookup.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Discounts::deprecated_202101_get_codes_lookup`], set with a builder.
+pub struct DiscountsDeprecated202101GetCodesLookupRequest<'a> {
+    client: &'a Discounts,
+    code: Option<i64>,
+}
+
+impl<'a> DiscountsDeprecated202101GetCodesLookupRequest<'a> {
+    /// Sets the `code` query parameter.
+    pub fn code(mut self, code: i64) -> Self {
+        self.code = Some(code);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self { client, code } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = code {
+            query_args.push(("code".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2021-01/discount_codes/lookup.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Discounts::deprecated_unstable_get_codes_lookup`], set with a builder.
+pub struct DiscountsDeprecatedUnstableGetCodesLookupRequest<'a> {
+    client: &'a Discounts,
+    code: Option<i64>,
+}
+
+impl<'a> DiscountsDeprecatedUnstableGetCodesLookupRequest<'a> {
+    /// Sets the `code` query parameter.
+    pub fn code(mut self, code: i64) -> Self {
+        self.code = Some(code);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self { client, code } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = code {
+            query_args.push(("code".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/unstable/discount_codes/lookup.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct Discounts {
     pub client: Client,
 }
@@ -219,6 +453,25 @@ impl Discounts {
             .await
     }
     /**
+    * Retrieves the location of a discount code.
+             The discount code's location is returned in the location header, not in the DiscountCode object itself.
+               Depending on your HTTP client, the location of the discount code might follow the location header automatically.
+    *
+    * This function performs a `GET` to the `/admin/api/2020-01/discount_codes/lookup.json` endpoint.
+    *
+    * As opposed to `deprecated_202001_get_codes_lookup`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+    *
+    * https://shopify.dev/docs/admin-api/rest/reference/discounts/discountcode#lookup-2020-01
+    */
+    pub fn deprecated_202001_get_codes_lookup_request(
+        &self,
+    ) -> DiscountsDeprecated202001GetCodesLookupRequest<'_> {
+        DiscountsDeprecated202001GetCodesLookupRequest {
+            client: self,
+            code: None,
+        }
+    }
+    /**
     * Creates a discount code creation job.
              The batch endpoint can be used to asynchronously create up to 100 discount codes in a single request. It
              enqueues and returns a discount_code_creation object that can be monitored for completion.
@@ -548,6 +801,25 @@ impl Discounts {
             .await
     }
     /**
+    * Retrieves the location of a discount code.
+             The discount code's location is returned in the location header, not in the DiscountCode object itself.
+               Depending on your HTTP client, the location of the discount code might follow the location header automatically.
+    *
+    * This function performs a `GET` to the `/admin/api/2020-04/discount_codes/lookup.json` endpoint.
+    *
+    * As opposed to `deprecated_202004_get_codes_lookup`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+    *
+    * https://shopify.dev/docs/admin-api/rest/reference/discounts/discountcode#lookup-2020-04
+    */
+    pub fn deprecated_202004_get_codes_lookup_request(
+        &self,
+    ) -> DiscountsDeprecated202004GetCodesLookupRequest<'_> {
+        DiscountsDeprecated202004GetCodesLookupRequest {
+            client: self,
+            code: None,
+        }
+    }
+    /**
     * Creates a discount code creation job.
              The batch endpoint can be used to asynchronously create up to 100 discount codes in a single request. It
              enqueues and returns a discount_code_creation object that can be monitored for completion.
@@ -877,6 +1149,25 @@ impl Discounts {
             .await
     }
     /**
+    * Retrieves the location of a discount code.
+             The discount code's location is returned in the location header, not in the DiscountCode object itself.
+               Depending on your HTTP client, the location of the discount code might follow the location header automatically.
+    *
+    * This function performs a `GET` to the `/admin/api/2020-07/discount_codes/lookup.json` endpoint.
+    *
+    * As opposed to `deprecated_202007_get_codes_lookup`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+    *
+    * https://shopify.dev/docs/admin-api/rest/reference/discounts/discountcode#lookup-2020-07
+    */
+    pub fn deprecated_202007_get_codes_lookup_request(
+        &self,
+    ) -> DiscountsDeprecated202007GetCodesLookupRequest<'_> {
+        DiscountsDeprecated202007GetCodesLookupRequest {
+            client: self,
+            code: None,
+        }
+    }
+    /**
     * Creates a discount code creation job.
              The batch endpoint can be used to asynchronously create up to 100 discount codes in a single request. It
              enqueues and returns a discount_code_creation object that can be monitored for completion.
@@ -1203,6 +1494,23 @@ impl Discounts {
             .await
     }
     /**
+    * Retrieves the location of a discount code.
+             The discount code's location is returned in the location header, not in the DiscountCode object itself.
+               Depending on your HTTP client, the location of the discount code might follow the location header automatically.
+    *
+    * This function performs a `GET` to the `/admin/api/2020-10/discount_codes/lookup.json` endpoint.
+    *
+    * As opposed to `get_codes_lookup`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+    *
+    * https://shopify.dev/docs/admin-api/rest/reference/discounts/discountcode#lookup-2020-10
+    */
+    pub fn get_codes_lookup_request(&self) -> DiscountsGetCodesLookupRequest<'_> {
+        DiscountsGetCodesLookupRequest {
+            client: self,
+            code: None,
+        }
+    }
+    /**
     * Creates a discount code creation job.
              The batch endpoint can be used to asynchronously create up to 100 discount codes in a single request. It
              enqueues and returns a discount_code_creation object that can be monitored for completion.
@@ -1532,6 +1840,25 @@ impl Discounts {
             .await
     }
     /**
+    * Retrieves the location of a discount code.
+             The discount code's location is returned in the location header, not in the DiscountCode object itself.
+               Depending on your HTTP client, the location of the discount code might follow the location header automatically.
+    *
+    * This function performs a `GET` to the `/admin/api/2021-01/discount_codes/lookup.json` endpoint.
+    *
+    * As opposed to `deprecated_202101_get_codes_lookup`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+    *
+    * https://shopify.dev/docs/admin-api/rest/reference/discounts/discountcode#lookup-2021-01
+    */
+    pub fn deprecated_202101_get_codes_lookup_request(
+        &self,
+    ) -> DiscountsDeprecated202101GetCodesLookupRequest<'_> {
+        DiscountsDeprecated202101GetCodesLookupRequest {
+            client: self,
+            code: None,
+        }
+    }
+    /**
     * Creates a discount code creation job.
              The batch endpoint can be used to asynchronously create up to 100 discount codes in a single request. It
              enqueues and returns a discount_code_creation object that can be monitored for completion.
@@ -1861,6 +2188,25 @@ impl Discounts {
             .await
     }
     /**
+    * Retrieves the location of a discount code.
+             The discount code's location is returned in the location header, not in the DiscountCode object itself.
+               Depending on your HTTP client, the location of the discount code might follow the location header automatically.
+    *
+    * This function performs a `GET` to the `/admin/api/unstable/discount_codes/lookup.json` endpoint.
+    *
+    * As opposed to `deprecated_unstable_get_codes_lookup`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+    *
+    * https://shopify.dev/docs/admin-api/rest/reference/discounts/discountcode#lookup-unstable
+    */
+    pub fn deprecated_unstable_get_codes_lookup_request(
+        &self,
+    ) -> DiscountsDeprecatedUnstableGetCodesLookupRequest<'_> {
+        DiscountsDeprecatedUnstableGetCodesLookupRequest {
+            client: self,
+            code: None,
+        }
+    }
+    /**
     * Creates a discount code creation job.
              The batch endpoint can be used to asynchronously create up to 100 discount codes in a single request. It
              enqueues and returns a discount_code_creation object that can be monitored for completion.
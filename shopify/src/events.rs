@@ -1,6 +1,2904 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Events::deprecated_202001_get`], set with a builder.
+pub struct EventsDeprecated202001GetRequest<'a> {
+    client: &'a Events,
+    limit: Option<&'a str>,
+    since_id: Option<&'a str>,
+    created_at_min: Option<&'a str>,
+    created_at_max: Option<&'a str>,
+    filter: Option<&'a str>,
+    verb: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> EventsDeprecated202001GetRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.created_at_min = Some(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.created_at_max = Some(created_at_max);
+        self
+    }
+
+    /// Sets the `filter` query parameter.
+    pub fn filter(mut self, filter: &'a str) -> Self {
+        self.filter = Some(filter);
+        self
+    }
+
+    /// Sets the `verb` query parameter.
+    pub fn verb(mut self, verb: &'a str) -> Self {
+        self.verb = Some(verb);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            limit,
+            since_id,
+            created_at_min,
+            created_at_max,
+            filter,
+            verb,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = created_at_max {
+            query_args.push(("created_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = created_at_min {
+            query_args.push(("created_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = filter {
+            query_args.push(("filter".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = verb {
+            query_args.push(("verb".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/admin/api/2020-01/events.json?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Events::deprecated_202001_get_param`], set with a builder.
+pub struct EventsDeprecated202001GetParamRequest<'a> {
+    client: &'a Events,
+    event_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> EventsDeprecated202001GetParamRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            event_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-01/events/{}/json?{}",
+                crate::progenitor_support::encode_path(event_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Events::deprecated_202001_get_count`], set with a builder.
+pub struct EventsDeprecated202001GetCountRequest<'a> {
+    client: &'a Events,
+    created_at_min: Option<&'a str>,
+    created_at_max: Option<&'a str>,
+}
+
+impl<'a> EventsDeprecated202001GetCountRequest<'a> {
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.created_at_min = Some(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.created_at_max = Some(created_at_max);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            created_at_min,
+            created_at_max,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = created_at_max {
+            query_args.push(("created_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = created_at_min {
+            query_args.push(("created_at_min".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-01/events/count.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Events::deprecated_202004_get`], set with a builder.
+pub struct EventsDeprecated202004GetRequest<'a> {
+    client: &'a Events,
+    limit: Option<&'a str>,
+    since_id: Option<&'a str>,
+    created_at_min: Option<&'a str>,
+    created_at_max: Option<&'a str>,
+    filter: Option<&'a str>,
+    verb: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> EventsDeprecated202004GetRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.created_at_min = Some(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.created_at_max = Some(created_at_max);
+        self
+    }
+
+    /// Sets the `filter` query parameter.
+    pub fn filter(mut self, filter: &'a str) -> Self {
+        self.filter = Some(filter);
+        self
+    }
+
+    /// Sets the `verb` query parameter.
+    pub fn verb(mut self, verb: &'a str) -> Self {
+        self.verb = Some(verb);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            limit,
+            since_id,
+            created_at_min,
+            created_at_max,
+            filter,
+            verb,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = created_at_max {
+            query_args.push(("created_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = created_at_min {
+            query_args.push(("created_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = filter {
+            query_args.push(("filter".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = verb {
+            query_args.push(("verb".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/admin/api/2020-04/events.json?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Events::deprecated_202004_get_param`], set with a builder.
+pub struct EventsDeprecated202004GetParamRequest<'a> {
+    client: &'a Events,
+    event_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> EventsDeprecated202004GetParamRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            event_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-04/events/{}/json?{}",
+                crate::progenitor_support::encode_path(event_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Events::deprecated_202004_get_count`], set with a builder.
+pub struct EventsDeprecated202004GetCountRequest<'a> {
+    client: &'a Events,
+    created_at_min: Option<&'a str>,
+    created_at_max: Option<&'a str>,
+}
+
+impl<'a> EventsDeprecated202004GetCountRequest<'a> {
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.created_at_min = Some(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.created_at_max = Some(created_at_max);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            created_at_min,
+            created_at_max,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = created_at_max {
+            query_args.push(("created_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = created_at_min {
+            query_args.push(("created_at_min".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-04/events/count.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Events::deprecated_202007_get`], set with a builder.
+pub struct EventsDeprecated202007GetRequest<'a> {
+    client: &'a Events,
+    limit: Option<&'a str>,
+    since_id: Option<&'a str>,
+    created_at_min: Option<&'a str>,
+    created_at_max: Option<&'a str>,
+    filter: Option<&'a str>,
+    verb: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> EventsDeprecated202007GetRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.created_at_min = Some(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.created_at_max = Some(created_at_max);
+        self
+    }
+
+    /// Sets the `filter` query parameter.
+    pub fn filter(mut self, filter: &'a str) -> Self {
+        self.filter = Some(filter);
+        self
+    }
+
+    /// Sets the `verb` query parameter.
+    pub fn verb(mut self, verb: &'a str) -> Self {
+        self.verb = Some(verb);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            limit,
+            since_id,
+            created_at_min,
+            created_at_max,
+            filter,
+            verb,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = created_at_max {
+            query_args.push(("created_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = created_at_min {
+            query_args.push(("created_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = filter {
+            query_args.push(("filter".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = verb {
+            query_args.push(("verb".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/admin/api/2020-07/events.json?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Events::deprecated_202007_get_param`], set with a builder.
+pub struct EventsDeprecated202007GetParamRequest<'a> {
+    client: &'a Events,
+    event_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> EventsDeprecated202007GetParamRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            event_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-07/events/{}/json?{}",
+                crate::progenitor_support::encode_path(event_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Events::deprecated_202007_get_count`], set with a builder.
+pub struct EventsDeprecated202007GetCountRequest<'a> {
+    client: &'a Events,
+    created_at_min: Option<&'a str>,
+    created_at_max: Option<&'a str>,
+}
+
+impl<'a> EventsDeprecated202007GetCountRequest<'a> {
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.created_at_min = Some(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.created_at_max = Some(created_at_max);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            created_at_min,
+            created_at_max,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = created_at_max {
+            query_args.push(("created_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = created_at_min {
+            query_args.push(("created_at_min".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-07/events/count.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Events::get`], set with a builder.
+pub struct EventsGetRequest<'a> {
+    client: &'a Events,
+    limit: Option<&'a str>,
+    since_id: Option<&'a str>,
+    created_at_min: Option<&'a str>,
+    created_at_max: Option<&'a str>,
+    filter: Option<&'a str>,
+    verb: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> EventsGetRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.created_at_min = Some(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.created_at_max = Some(created_at_max);
+        self
+    }
+
+    /// Sets the `filter` query parameter.
+    pub fn filter(mut self, filter: &'a str) -> Self {
+        self.filter = Some(filter);
+        self
+    }
+
+    /// Sets the `verb` query parameter.
+    pub fn verb(mut self, verb: &'a str) -> Self {
+        self.verb = Some(verb);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            limit,
+            since_id,
+            created_at_min,
+            created_at_max,
+            filter,
+            verb,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = created_at_max {
+            query_args.push(("created_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = created_at_min {
+            query_args.push(("created_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = filter {
+            query_args.push(("filter".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = verb {
+            query_args.push(("verb".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/admin/api/2020-10/events.json?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Events::get_param`], set with a builder.
+pub struct EventsGetParamRequest<'a> {
+    client: &'a Events,
+    event_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> EventsGetParamRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            event_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-10/events/{}/json?{}",
+                crate::progenitor_support::encode_path(event_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Events::get_count`], set with a builder.
+pub struct EventsGetCountRequest<'a> {
+    client: &'a Events,
+    created_at_min: Option<&'a str>,
+    created_at_max: Option<&'a str>,
+}
+
+impl<'a> EventsGetCountRequest<'a> {
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.created_at_min = Some(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.created_at_max = Some(created_at_max);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            created_at_min,
+            created_at_max,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = created_at_max {
+            query_args.push(("created_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = created_at_min {
+            query_args.push(("created_at_min".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-10/events/count.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Events::deprecated_202101_get`], set with a builder.
+pub struct EventsDeprecated202101GetRequest<'a> {
+    client: &'a Events,
+    limit: Option<&'a str>,
+    since_id: Option<&'a str>,
+    created_at_min: Option<&'a str>,
+    created_at_max: Option<&'a str>,
+    filter: Option<&'a str>,
+    verb: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> EventsDeprecated202101GetRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.created_at_min = Some(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.created_at_max = Some(created_at_max);
+        self
+    }
+
+    /// Sets the `filter` query parameter.
+    pub fn filter(mut self, filter: &'a str) -> Self {
+        self.filter = Some(filter);
+        self
+    }
+
+    /// Sets the `verb` query parameter.
+    pub fn verb(mut self, verb: &'a str) -> Self {
+        self.verb = Some(verb);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            limit,
+            since_id,
+            created_at_min,
+            created_at_max,
+            filter,
+            verb,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = created_at_max {
+            query_args.push(("created_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = created_at_min {
+            query_args.push(("created_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = filter {
+            query_args.push(("filter".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = verb {
+            query_args.push(("verb".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/admin/api/2021-01/events.json?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Events::deprecated_202101_get_param`], set with a builder.
+pub struct EventsDeprecated202101GetParamRequest<'a> {
+    client: &'a Events,
+    event_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> EventsDeprecated202101GetParamRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            event_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2021-01/events/{}/json?{}",
+                crate::progenitor_support::encode_path(event_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Events::deprecated_202101_get_count`], set with a builder.
+pub struct EventsDeprecated202101GetCountRequest<'a> {
+    client: &'a Events,
+    created_at_min: Option<&'a str>,
+    created_at_max: Option<&'a str>,
+}
+
+impl<'a> EventsDeprecated202101GetCountRequest<'a> {
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.created_at_min = Some(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.created_at_max = Some(created_at_max);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            created_at_min,
+            created_at_max,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = created_at_max {
+            query_args.push(("created_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = created_at_min {
+            query_args.push(("created_at_min".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2021-01/events/count.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Events::deprecated_unstable_get`], set with a builder.
+pub struct EventsDeprecatedUnstableGetRequest<'a> {
+    client: &'a Events,
+    limit: Option<&'a str>,
+    since_id: Option<&'a str>,
+    created_at_min: Option<&'a str>,
+    created_at_max: Option<&'a str>,
+    filter: Option<&'a str>,
+    verb: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> EventsDeprecatedUnstableGetRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.created_at_min = Some(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.created_at_max = Some(created_at_max);
+        self
+    }
+
+    /// Sets the `filter` query parameter.
+    pub fn filter(mut self, filter: &'a str) -> Self {
+        self.filter = Some(filter);
+        self
+    }
+
+    /// Sets the `verb` query parameter.
+    pub fn verb(mut self, verb: &'a str) -> Self {
+        self.verb = Some(verb);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            limit,
+            since_id,
+            created_at_min,
+            created_at_max,
+            filter,
+            verb,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = created_at_max {
+            query_args.push(("created_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = created_at_min {
+            query_args.push(("created_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = filter {
+            query_args.push(("filter".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = verb {
+            query_args.push(("verb".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/admin/api/unstable/events.json?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Events::deprecated_unstable_get_param`], set with a builder.
+pub struct EventsDeprecatedUnstableGetParamRequest<'a> {
+    client: &'a Events,
+    event_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> EventsDeprecatedUnstableGetParamRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            event_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/unstable/events/{}/json?{}",
+                crate::progenitor_support::encode_path(event_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Events::deprecated_unstable_get_count`], set with a builder.
+pub struct EventsDeprecatedUnstableGetCountRequest<'a> {
+    client: &'a Events,
+    created_at_min: Option<&'a str>,
+    created_at_max: Option<&'a str>,
+}
+
+impl<'a> EventsDeprecatedUnstableGetCountRequest<'a> {
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.created_at_min = Some(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.created_at_max = Some(created_at_max);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            created_at_min,
+            created_at_max,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = created_at_max {
+            query_args.push(("created_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = created_at_min {
+            query_args.push(("created_at_min".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/unstable/events/count.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Events::deprecated_202001_get_webhook`], set with a builder.
+pub struct EventsDeprecated202001GetWebhookRequest<'a> {
+    client: &'a Events,
+    address: Option<&'a str>,
+    created_at_max: Option<&'a str>,
+    created_at_min: Option<&'a str>,
+    fields: Option<&'a str>,
+    limit: Option<&'a str>,
+    since_id: Option<&'a str>,
+    topic: Option<&'a str>,
+    updated_at_min: Option<&'a str>,
+    updated_at_max: Option<&'a str>,
+}
+
+impl<'a> EventsDeprecated202001GetWebhookRequest<'a> {
+    /// Sets the `address` query parameter.
+    pub fn address(mut self, address: &'a str) -> Self {
+        self.address = Some(address);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.created_at_max = Some(created_at_max);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.created_at_min = Some(created_at_min);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `topic` query parameter.
+    pub fn topic(mut self, topic: &'a str) -> Self {
+        self.topic = Some(topic);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.updated_at_min = Some(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.updated_at_max = Some(updated_at_max);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            address,
+            created_at_max,
+            created_at_min,
+            fields,
+            limit,
+            since_id,
+            topic,
+            updated_at_min,
+            updated_at_max,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = address {
+            query_args.push(("address".to_string(), v.to_string()));
+        }
+        if let Some(v) = created_at_max {
+            query_args.push(("created_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = created_at_min {
+            query_args.push(("created_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = topic {
+            query_args.push(("topic".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_max {
+            query_args.push(("updated_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_min {
+            query_args.push(("updated_at_min".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-01/webhooks.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Events::deprecated_202001_create_webhooks`], set with a builder.
+pub struct EventsDeprecated202001CreateWebhooksRequest<'a> {
+    client: &'a Events,
+    format: Option<&'a str>,
+    body: &'a serde_json::Value,
+}
+
+impl<'a> EventsDeprecated202001CreateWebhooksRequest<'a> {
+    /// Sets the `format` query parameter.
+    pub fn format(mut self, format: &'a str) -> Self {
+        self.format = Some(format);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            format,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = format {
+            query_args.push(("format".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-01/webhooks.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Events::deprecated_202001_get_webhooks_count`], set with a builder.
+pub struct EventsDeprecated202001GetWebhooksCountRequest<'a> {
+    client: &'a Events,
+    address: Option<&'a str>,
+    topic: Option<&'a str>,
+}
+
+impl<'a> EventsDeprecated202001GetWebhooksCountRequest<'a> {
+    /// Sets the `address` query parameter.
+    pub fn address(mut self, address: &'a str) -> Self {
+        self.address = Some(address);
+        self
+    }
+
+    /// Sets the `topic` query parameter.
+    pub fn topic(mut self, topic: &'a str) -> Self {
+        self.topic = Some(topic);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            address,
+            topic,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = address {
+            query_args.push(("address".to_string(), v.to_string()));
+        }
+        if let Some(v) = topic {
+            query_args.push(("topic".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-01/webhooks/count.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Events::deprecated_202001_get_webhooks_param_webhook`], set with a builder.
+pub struct EventsDeprecated202001GetWebhooksParamWebhookRequest<'a> {
+    client: &'a Events,
+    webhook_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> EventsDeprecated202001GetWebhooksParamWebhookRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            webhook_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-01/webhooks/{}/json?{}",
+                crate::progenitor_support::encode_path(webhook_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Events::deprecated_202004_get_webhook`], set with a builder.
+pub struct EventsDeprecated202004GetWebhookRequest<'a> {
+    client: &'a Events,
+    address: Option<&'a str>,
+    created_at_max: Option<&'a str>,
+    created_at_min: Option<&'a str>,
+    fields: Option<&'a str>,
+    limit: Option<&'a str>,
+    since_id: Option<&'a str>,
+    topic: Option<&'a str>,
+    updated_at_min: Option<&'a str>,
+    updated_at_max: Option<&'a str>,
+}
+
+impl<'a> EventsDeprecated202004GetWebhookRequest<'a> {
+    /// Sets the `address` query parameter.
+    pub fn address(mut self, address: &'a str) -> Self {
+        self.address = Some(address);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.created_at_max = Some(created_at_max);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.created_at_min = Some(created_at_min);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `topic` query parameter.
+    pub fn topic(mut self, topic: &'a str) -> Self {
+        self.topic = Some(topic);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.updated_at_min = Some(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.updated_at_max = Some(updated_at_max);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            address,
+            created_at_max,
+            created_at_min,
+            fields,
+            limit,
+            since_id,
+            topic,
+            updated_at_min,
+            updated_at_max,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = address {
+            query_args.push(("address".to_string(), v.to_string()));
+        }
+        if let Some(v) = created_at_max {
+            query_args.push(("created_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = created_at_min {
+            query_args.push(("created_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = topic {
+            query_args.push(("topic".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_max {
+            query_args.push(("updated_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_min {
+            query_args.push(("updated_at_min".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-04/webhooks.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Events::deprecated_202004_create_webhooks`], set with a builder.
+pub struct EventsDeprecated202004CreateWebhooksRequest<'a> {
+    client: &'a Events,
+    format: Option<&'a str>,
+    body: &'a serde_json::Value,
+}
+
+impl<'a> EventsDeprecated202004CreateWebhooksRequest<'a> {
+    /// Sets the `format` query parameter.
+    pub fn format(mut self, format: &'a str) -> Self {
+        self.format = Some(format);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            format,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = format {
+            query_args.push(("format".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-04/webhooks.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Events::deprecated_202004_get_webhooks_count`], set with a builder.
+pub struct EventsDeprecated202004GetWebhooksCountRequest<'a> {
+    client: &'a Events,
+    address: Option<&'a str>,
+    topic: Option<&'a str>,
+}
+
+impl<'a> EventsDeprecated202004GetWebhooksCountRequest<'a> {
+    /// Sets the `address` query parameter.
+    pub fn address(mut self, address: &'a str) -> Self {
+        self.address = Some(address);
+        self
+    }
+
+    /// Sets the `topic` query parameter.
+    pub fn topic(mut self, topic: &'a str) -> Self {
+        self.topic = Some(topic);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            address,
+            topic,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = address {
+            query_args.push(("address".to_string(), v.to_string()));
+        }
+        if let Some(v) = topic {
+            query_args.push(("topic".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-04/webhooks/count.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Events::deprecated_202004_get_webhooks_param_webhook`], set with a builder.
+pub struct EventsDeprecated202004GetWebhooksParamWebhookRequest<'a> {
+    client: &'a Events,
+    webhook_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> EventsDeprecated202004GetWebhooksParamWebhookRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            webhook_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-04/webhooks/{}/json?{}",
+                crate::progenitor_support::encode_path(webhook_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Events::deprecated_202007_get_webhook`], set with a builder.
+pub struct EventsDeprecated202007GetWebhookRequest<'a> {
+    client: &'a Events,
+    address: Option<&'a str>,
+    created_at_max: Option<&'a str>,
+    created_at_min: Option<&'a str>,
+    fields: Option<&'a str>,
+    limit: Option<&'a str>,
+    since_id: Option<&'a str>,
+    topic: Option<&'a str>,
+    updated_at_min: Option<&'a str>,
+    updated_at_max: Option<&'a str>,
+}
+
+impl<'a> EventsDeprecated202007GetWebhookRequest<'a> {
+    /// Sets the `address` query parameter.
+    pub fn address(mut self, address: &'a str) -> Self {
+        self.address = Some(address);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.created_at_max = Some(created_at_max);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.created_at_min = Some(created_at_min);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `topic` query parameter.
+    pub fn topic(mut self, topic: &'a str) -> Self {
+        self.topic = Some(topic);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.updated_at_min = Some(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.updated_at_max = Some(updated_at_max);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            address,
+            created_at_max,
+            created_at_min,
+            fields,
+            limit,
+            since_id,
+            topic,
+            updated_at_min,
+            updated_at_max,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = address {
+            query_args.push(("address".to_string(), v.to_string()));
+        }
+        if let Some(v) = created_at_max {
+            query_args.push(("created_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = created_at_min {
+            query_args.push(("created_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = topic {
+            query_args.push(("topic".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_max {
+            query_args.push(("updated_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_min {
+            query_args.push(("updated_at_min".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-07/webhooks.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Events::deprecated_202007_create_webhooks`], set with a builder.
+pub struct EventsDeprecated202007CreateWebhooksRequest<'a> {
+    client: &'a Events,
+    format: Option<&'a str>,
+    body: &'a serde_json::Value,
+}
+
+impl<'a> EventsDeprecated202007CreateWebhooksRequest<'a> {
+    /// Sets the `format` query parameter.
+    pub fn format(mut self, format: &'a str) -> Self {
+        self.format = Some(format);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            format,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = format {
+            query_args.push(("format".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-07/webhooks.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Events::deprecated_202007_get_webhooks_count`], set with a builder.
+pub struct EventsDeprecated202007GetWebhooksCountRequest<'a> {
+    client: &'a Events,
+    address: Option<&'a str>,
+    topic: Option<&'a str>,
+}
+
+impl<'a> EventsDeprecated202007GetWebhooksCountRequest<'a> {
+    /// Sets the `address` query parameter.
+    pub fn address(mut self, address: &'a str) -> Self {
+        self.address = Some(address);
+        self
+    }
+
+    /// Sets the `topic` query parameter.
+    pub fn topic(mut self, topic: &'a str) -> Self {
+        self.topic = Some(topic);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            address,
+            topic,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = address {
+            query_args.push(("address".to_string(), v.to_string()));
+        }
+        if let Some(v) = topic {
+            query_args.push(("topic".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-07/webhooks/count.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Events::deprecated_202007_get_webhooks_param_webhook`], set with a builder.
+pub struct EventsDeprecated202007GetWebhooksParamWebhookRequest<'a> {
+    client: &'a Events,
+    webhook_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> EventsDeprecated202007GetWebhooksParamWebhookRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            webhook_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-07/webhooks/{}/json?{}",
+                crate::progenitor_support::encode_path(webhook_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Events::get_webhook`], set with a builder.
+pub struct EventsGetWebhookRequest<'a> {
+    client: &'a Events,
+    address: Option<&'a str>,
+    created_at_max: Option<&'a str>,
+    created_at_min: Option<&'a str>,
+    fields: Option<&'a str>,
+    limit: Option<&'a str>,
+    since_id: Option<&'a str>,
+    topic: Option<&'a str>,
+    updated_at_min: Option<&'a str>,
+    updated_at_max: Option<&'a str>,
+}
+
+impl<'a> EventsGetWebhookRequest<'a> {
+    /// Sets the `address` query parameter.
+    pub fn address(mut self, address: &'a str) -> Self {
+        self.address = Some(address);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.created_at_max = Some(created_at_max);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.created_at_min = Some(created_at_min);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `topic` query parameter.
+    pub fn topic(mut self, topic: &'a str) -> Self {
+        self.topic = Some(topic);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.updated_at_min = Some(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.updated_at_max = Some(updated_at_max);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            address,
+            created_at_max,
+            created_at_min,
+            fields,
+            limit,
+            since_id,
+            topic,
+            updated_at_min,
+            updated_at_max,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = address {
+            query_args.push(("address".to_string(), v.to_string()));
+        }
+        if let Some(v) = created_at_max {
+            query_args.push(("created_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = created_at_min {
+            query_args.push(("created_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = topic {
+            query_args.push(("topic".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_max {
+            query_args.push(("updated_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_min {
+            query_args.push(("updated_at_min".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-10/webhooks.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Events::create_webhooks`], set with a builder.
+pub struct EventsCreateWebhooksRequest<'a> {
+    client: &'a Events,
+    format: Option<&'a str>,
+    body: &'a serde_json::Value,
+}
+
+impl<'a> EventsCreateWebhooksRequest<'a> {
+    /// Sets the `format` query parameter.
+    pub fn format(mut self, format: &'a str) -> Self {
+        self.format = Some(format);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            format,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = format {
+            query_args.push(("format".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-10/webhooks.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Events::get_webhooks_count`], set with a builder.
+pub struct EventsGetWebhooksCountRequest<'a> {
+    client: &'a Events,
+    address: Option<&'a str>,
+    topic: Option<&'a str>,
+}
+
+impl<'a> EventsGetWebhooksCountRequest<'a> {
+    /// Sets the `address` query parameter.
+    pub fn address(mut self, address: &'a str) -> Self {
+        self.address = Some(address);
+        self
+    }
+
+    /// Sets the `topic` query parameter.
+    pub fn topic(mut self, topic: &'a str) -> Self {
+        self.topic = Some(topic);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            address,
+            topic,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = address {
+            query_args.push(("address".to_string(), v.to_string()));
+        }
+        if let Some(v) = topic {
+            query_args.push(("topic".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-10/webhooks/count.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Events::get_webhooks_param_webhook`], set with a builder.
+pub struct EventsGetWebhooksParamWebhookRequest<'a> {
+    client: &'a Events,
+    webhook_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> EventsGetWebhooksParamWebhookRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            webhook_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-10/webhooks/{}/json?{}",
+                crate::progenitor_support::encode_path(webhook_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Events::deprecated_202101_get_webhook`], set with a builder.
+pub struct EventsDeprecated202101GetWebhookRequest<'a> {
+    client: &'a Events,
+    address: Option<&'a str>,
+    created_at_max: Option<&'a str>,
+    created_at_min: Option<&'a str>,
+    fields: Option<&'a str>,
+    limit: Option<&'a str>,
+    since_id: Option<&'a str>,
+    topic: Option<&'a str>,
+    updated_at_min: Option<&'a str>,
+    updated_at_max: Option<&'a str>,
+}
+
+impl<'a> EventsDeprecated202101GetWebhookRequest<'a> {
+    /// Sets the `address` query parameter.
+    pub fn address(mut self, address: &'a str) -> Self {
+        self.address = Some(address);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.created_at_max = Some(created_at_max);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.created_at_min = Some(created_at_min);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `topic` query parameter.
+    pub fn topic(mut self, topic: &'a str) -> Self {
+        self.topic = Some(topic);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.updated_at_min = Some(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.updated_at_max = Some(updated_at_max);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            address,
+            created_at_max,
+            created_at_min,
+            fields,
+            limit,
+            since_id,
+            topic,
+            updated_at_min,
+            updated_at_max,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = address {
+            query_args.push(("address".to_string(), v.to_string()));
+        }
+        if let Some(v) = created_at_max {
+            query_args.push(("created_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = created_at_min {
+            query_args.push(("created_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = topic {
+            query_args.push(("topic".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_max {
+            query_args.push(("updated_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_min {
+            query_args.push(("updated_at_min".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2021-01/webhooks.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Events::deprecated_202101_create_webhooks`], set with a builder.
+pub struct EventsDeprecated202101CreateWebhooksRequest<'a> {
+    client: &'a Events,
+    format: Option<&'a str>,
+    body: &'a serde_json::Value,
+}
+
+impl<'a> EventsDeprecated202101CreateWebhooksRequest<'a> {
+    /// Sets the `format` query parameter.
+    pub fn format(mut self, format: &'a str) -> Self {
+        self.format = Some(format);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            format,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = format {
+            query_args.push(("format".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2021-01/webhooks.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Events::deprecated_202101_get_webhooks_count`], set with a builder.
+pub struct EventsDeprecated202101GetWebhooksCountRequest<'a> {
+    client: &'a Events,
+    address: Option<&'a str>,
+    topic: Option<&'a str>,
+}
+
+impl<'a> EventsDeprecated202101GetWebhooksCountRequest<'a> {
+    /// Sets the `address` query parameter.
+    pub fn address(mut self, address: &'a str) -> Self {
+        self.address = Some(address);
+        self
+    }
+
+    /// Sets the `topic` query parameter.
+    pub fn topic(mut self, topic: &'a str) -> Self {
+        self.topic = Some(topic);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            address,
+            topic,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = address {
+            query_args.push(("address".to_string(), v.to_string()));
+        }
+        if let Some(v) = topic {
+            query_args.push(("topic".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2021-01/webhooks/count.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Events::deprecated_202101_get_webhooks_param_webhook`], set with a builder.
+pub struct EventsDeprecated202101GetWebhooksParamWebhookRequest<'a> {
+    client: &'a Events,
+    webhook_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> EventsDeprecated202101GetWebhooksParamWebhookRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            webhook_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2021-01/webhooks/{}/json?{}",
+                crate::progenitor_support::encode_path(webhook_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Events::deprecated_unstable_get_webhook`], set with a builder.
+pub struct EventsDeprecatedUnstableGetWebhookRequest<'a> {
+    client: &'a Events,
+    address: Option<&'a str>,
+    created_at_max: Option<&'a str>,
+    created_at_min: Option<&'a str>,
+    fields: Option<&'a str>,
+    limit: Option<&'a str>,
+    since_id: Option<&'a str>,
+    topic: Option<&'a str>,
+    updated_at_min: Option<&'a str>,
+    updated_at_max: Option<&'a str>,
+}
+
+impl<'a> EventsDeprecatedUnstableGetWebhookRequest<'a> {
+    /// Sets the `address` query parameter.
+    pub fn address(mut self, address: &'a str) -> Self {
+        self.address = Some(address);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.created_at_max = Some(created_at_max);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.created_at_min = Some(created_at_min);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `topic` query parameter.
+    pub fn topic(mut self, topic: &'a str) -> Self {
+        self.topic = Some(topic);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.updated_at_min = Some(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.updated_at_max = Some(updated_at_max);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            address,
+            created_at_max,
+            created_at_min,
+            fields,
+            limit,
+            since_id,
+            topic,
+            updated_at_min,
+            updated_at_max,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = address {
+            query_args.push(("address".to_string(), v.to_string()));
+        }
+        if let Some(v) = created_at_max {
+            query_args.push(("created_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = created_at_min {
+            query_args.push(("created_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = topic {
+            query_args.push(("topic".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_max {
+            query_args.push(("updated_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_min {
+            query_args.push(("updated_at_min".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/unstable/webhooks.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Events::deprecated_unstable_create_webhooks`], set with a builder.
+pub struct EventsDeprecatedUnstableCreateWebhooksRequest<'a> {
+    client: &'a Events,
+    format: Option<&'a str>,
+    body: &'a serde_json::Value,
+}
+
+impl<'a> EventsDeprecatedUnstableCreateWebhooksRequest<'a> {
+    /// Sets the `format` query parameter.
+    pub fn format(mut self, format: &'a str) -> Self {
+        self.format = Some(format);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            format,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = format {
+            query_args.push(("format".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/unstable/webhooks.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Events::deprecated_unstable_get_webhooks_count`], set with a builder.
+pub struct EventsDeprecatedUnstableGetWebhooksCountRequest<'a> {
+    client: &'a Events,
+    address: Option<&'a str>,
+    topic: Option<&'a str>,
+}
+
+impl<'a> EventsDeprecatedUnstableGetWebhooksCountRequest<'a> {
+    /// Sets the `address` query parameter.
+    pub fn address(mut self, address: &'a str) -> Self {
+        self.address = Some(address);
+        self
+    }
+
+    /// Sets the `topic` query parameter.
+    pub fn topic(mut self, topic: &'a str) -> Self {
+        self.topic = Some(topic);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            address,
+            topic,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = address {
+            query_args.push(("address".to_string(), v.to_string()));
+        }
+        if let Some(v) = topic {
+            query_args.push(("topic".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/unstable/webhooks/count.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Events::deprecated_unstable_get_webhooks_param_webhook`], set with a builder.
+pub struct EventsDeprecatedUnstableGetWebhooksParamWebhookRequest<'a> {
+    client: &'a Events,
+    webhook_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> EventsDeprecatedUnstableGetWebhooksParamWebhookRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            webhook_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/unstable/webhooks/{}/json?{}",
+                crate::progenitor_support::encode_path(webhook_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct Events {
     pub client: Client,
 }
@@ -75,6 +2973,27 @@ impl Events {
             )
             .await
     }
+    /**
+     * Retrieves a list of events. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-01/events.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_get`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/events/event#index-2020-01
+     */
+    pub fn deprecated_202001_get_request(&self) -> EventsDeprecated202001GetRequest<'_> {
+        EventsDeprecated202001GetRequest {
+            client: self,
+            limit: None,
+            since_id: None,
+            created_at_min: None,
+            created_at_max: None,
+            filter: None,
+            verb: None,
+            fields: None,
+        }
+    }
     /**
      * Retrieves a single event by its ID.
      *
@@ -115,6 +3034,25 @@ impl Events {
             )
             .await
     }
+    /**
+     * Retrieves a single event by its ID.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-01/events/{event_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_get_param`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/events/event#show-2020-01
+     */
+    pub fn deprecated_202001_get_param_request<'a>(
+        &'a self,
+        event_id: &'a str,
+    ) -> EventsDeprecated202001GetParamRequest<'a> {
+        EventsDeprecated202001GetParamRequest {
+            client: self,
+            event_id,
+            fields: None,
+        }
+    }
     /**
      * Retrieves a count of events.
      *
@@ -154,6 +3092,22 @@ impl Events {
             )
             .await
     }
+    /**
+     * Retrieves a count of events.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-01/events/count.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_get_count`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/events/event#count-2020-01
+     */
+    pub fn deprecated_202001_get_count_request(&self) -> EventsDeprecated202001GetCountRequest<'_> {
+        EventsDeprecated202001GetCountRequest {
+            client: self,
+            created_at_min: None,
+            created_at_max: None,
+        }
+    }
     /**
      * Retrieves a list of events. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -218,6 +3172,27 @@ impl Events {
             )
             .await
     }
+    /**
+     * Retrieves a list of events. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-04/events.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_get`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/events/event#index-2020-04
+     */
+    pub fn deprecated_202004_get_request(&self) -> EventsDeprecated202004GetRequest<'_> {
+        EventsDeprecated202004GetRequest {
+            client: self,
+            limit: None,
+            since_id: None,
+            created_at_min: None,
+            created_at_max: None,
+            filter: None,
+            verb: None,
+            fields: None,
+        }
+    }
     /**
      * Retrieves a single event by its ID.
      *
@@ -258,6 +3233,25 @@ impl Events {
             )
             .await
     }
+    /**
+     * Retrieves a single event by its ID.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-04/events/{event_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_get_param`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/events/event#show-2020-04
+     */
+    pub fn deprecated_202004_get_param_request<'a>(
+        &'a self,
+        event_id: &'a str,
+    ) -> EventsDeprecated202004GetParamRequest<'a> {
+        EventsDeprecated202004GetParamRequest {
+            client: self,
+            event_id,
+            fields: None,
+        }
+    }
     /**
      * Retrieves a count of events.
      *
@@ -297,6 +3291,22 @@ impl Events {
             )
             .await
     }
+    /**
+     * Retrieves a count of events.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-04/events/count.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_get_count`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/events/event#count-2020-04
+     */
+    pub fn deprecated_202004_get_count_request(&self) -> EventsDeprecated202004GetCountRequest<'_> {
+        EventsDeprecated202004GetCountRequest {
+            client: self,
+            created_at_min: None,
+            created_at_max: None,
+        }
+    }
     /**
      * Retrieves a list of events. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -361,6 +3371,27 @@ impl Events {
             )
             .await
     }
+    /**
+     * Retrieves a list of events. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-07/events.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_get`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/events/event#index-2020-07
+     */
+    pub fn deprecated_202007_get_request(&self) -> EventsDeprecated202007GetRequest<'_> {
+        EventsDeprecated202007GetRequest {
+            client: self,
+            limit: None,
+            since_id: None,
+            created_at_min: None,
+            created_at_max: None,
+            filter: None,
+            verb: None,
+            fields: None,
+        }
+    }
     /**
      * Retrieves a single event by its ID.
      *
@@ -401,6 +3432,25 @@ impl Events {
             )
             .await
     }
+    /**
+     * Retrieves a single event by its ID.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-07/events/{event_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_get_param`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/events/event#show-2020-07
+     */
+    pub fn deprecated_202007_get_param_request<'a>(
+        &'a self,
+        event_id: &'a str,
+    ) -> EventsDeprecated202007GetParamRequest<'a> {
+        EventsDeprecated202007GetParamRequest {
+            client: self,
+            event_id,
+            fields: None,
+        }
+    }
     /**
      * Retrieves a count of events.
      *
@@ -440,6 +3490,22 @@ impl Events {
             )
             .await
     }
+    /**
+     * Retrieves a count of events.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-07/events/count.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_get_count`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/events/event#count-2020-07
+     */
+    pub fn deprecated_202007_get_count_request(&self) -> EventsDeprecated202007GetCountRequest<'_> {
+        EventsDeprecated202007GetCountRequest {
+            client: self,
+            created_at_min: None,
+            created_at_max: None,
+        }
+    }
     /**
      * Retrieves a list of events. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -504,6 +3570,27 @@ impl Events {
             )
             .await
     }
+    /**
+     * Retrieves a list of events. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-10/events.json` endpoint.
+     *
+     * As opposed to `get`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/events/event#index-2020-10
+     */
+    pub fn get_request(&self) -> EventsGetRequest<'_> {
+        EventsGetRequest {
+            client: self,
+            limit: None,
+            since_id: None,
+            created_at_min: None,
+            created_at_max: None,
+            filter: None,
+            verb: None,
+            fields: None,
+        }
+    }
     /**
      * Retrieves a single event by its ID.
      *
@@ -544,6 +3631,22 @@ impl Events {
             )
             .await
     }
+    /**
+     * Retrieves a single event by its ID.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-10/events/{event_id}.json` endpoint.
+     *
+     * As opposed to `get_param`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/events/event#show-2020-10
+     */
+    pub fn get_param_request<'a>(&'a self, event_id: &'a str) -> EventsGetParamRequest<'a> {
+        EventsGetParamRequest {
+            client: self,
+            event_id,
+            fields: None,
+        }
+    }
     /**
      * Retrieves a count of events.
      *
@@ -583,6 +3686,22 @@ impl Events {
             )
             .await
     }
+    /**
+     * Retrieves a count of events.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-10/events/count.json` endpoint.
+     *
+     * As opposed to `get_count`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/events/event#count-2020-10
+     */
+    pub fn get_count_request(&self) -> EventsGetCountRequest<'_> {
+        EventsGetCountRequest {
+            client: self,
+            created_at_min: None,
+            created_at_max: None,
+        }
+    }
     /**
      * Retrieves a list of events. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -647,6 +3766,27 @@ impl Events {
             )
             .await
     }
+    /**
+     * Retrieves a list of events. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2021-01/events.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_get`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/events/event#index-2021-01
+     */
+    pub fn deprecated_202101_get_request(&self) -> EventsDeprecated202101GetRequest<'_> {
+        EventsDeprecated202101GetRequest {
+            client: self,
+            limit: None,
+            since_id: None,
+            created_at_min: None,
+            created_at_max: None,
+            filter: None,
+            verb: None,
+            fields: None,
+        }
+    }
     /**
      * Retrieves a single event by its ID.
      *
@@ -687,6 +3827,25 @@ impl Events {
             )
             .await
     }
+    /**
+     * Retrieves a single event by its ID.
+     *
+     * This function performs a `GET` to the `/admin/api/2021-01/events/{event_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_get_param`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/events/event#show-2021-01
+     */
+    pub fn deprecated_202101_get_param_request<'a>(
+        &'a self,
+        event_id: &'a str,
+    ) -> EventsDeprecated202101GetParamRequest<'a> {
+        EventsDeprecated202101GetParamRequest {
+            client: self,
+            event_id,
+            fields: None,
+        }
+    }
     /**
      * Retrieves a count of events.
      *
@@ -726,6 +3885,22 @@ impl Events {
             )
             .await
     }
+    /**
+     * Retrieves a count of events.
+     *
+     * This function performs a `GET` to the `/admin/api/2021-01/events/count.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_get_count`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/events/event#count-2021-01
+     */
+    pub fn deprecated_202101_get_count_request(&self) -> EventsDeprecated202101GetCountRequest<'_> {
+        EventsDeprecated202101GetCountRequest {
+            client: self,
+            created_at_min: None,
+            created_at_max: None,
+        }
+    }
     /**
      * Retrieves a list of events. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -790,6 +3965,27 @@ impl Events {
             )
             .await
     }
+    /**
+     * Retrieves a list of events. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/unstable/events.json` endpoint.
+     *
+     * As opposed to `deprecated_unstable_get`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/events/event#index-unstable
+     */
+    pub fn deprecated_unstable_get_request(&self) -> EventsDeprecatedUnstableGetRequest<'_> {
+        EventsDeprecatedUnstableGetRequest {
+            client: self,
+            limit: None,
+            since_id: None,
+            created_at_min: None,
+            created_at_max: None,
+            filter: None,
+            verb: None,
+            fields: None,
+        }
+    }
     /**
      * Retrieves a single event by its ID.
      *
@@ -830,6 +4026,25 @@ impl Events {
             )
             .await
     }
+    /**
+     * Retrieves a single event by its ID.
+     *
+     * This function performs a `GET` to the `/admin/api/unstable/events/{event_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_unstable_get_param`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/events/event#show-unstable
+     */
+    pub fn deprecated_unstable_get_param_request<'a>(
+        &'a self,
+        event_id: &'a str,
+    ) -> EventsDeprecatedUnstableGetParamRequest<'a> {
+        EventsDeprecatedUnstableGetParamRequest {
+            client: self,
+            event_id,
+            fields: None,
+        }
+    }
     /**
      * Retrieves a count of events.
      *
@@ -869,6 +4084,24 @@ impl Events {
             )
             .await
     }
+    /**
+     * Retrieves a count of events.
+     *
+     * This function performs a `GET` to the `/admin/api/unstable/events/count.json` endpoint.
+     *
+     * As opposed to `deprecated_unstable_get_count`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/events/event#count-unstable
+     */
+    pub fn deprecated_unstable_get_count_request(
+        &self,
+    ) -> EventsDeprecatedUnstableGetCountRequest<'_> {
+        EventsDeprecatedUnstableGetCountRequest {
+            client: self,
+            created_at_min: None,
+            created_at_max: None,
+        }
+    }
     /**
      * Retrieves a list of webhooks. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -945,6 +4178,31 @@ impl Events {
             )
             .await
     }
+    /**
+     * Retrieves a list of webhooks. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-01/webhooks.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_get_webhook`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/events/webhook#index-2020-01
+     */
+    pub fn deprecated_202001_get_webhook_request(
+        &self,
+    ) -> EventsDeprecated202001GetWebhookRequest<'_> {
+        EventsDeprecated202001GetWebhookRequest {
+            client: self,
+            address: None,
+            created_at_max: None,
+            created_at_min: None,
+            fields: None,
+            limit: None,
+            since_id: None,
+            topic: None,
+            updated_at_min: None,
+            updated_at_max: None,
+        }
+    }
     /**
      * Create a new webhook subscription by specifying both an address and a topic.
      *
@@ -981,6 +4239,25 @@ impl Events {
             )
             .await
     }
+    /**
+     * Create a new webhook subscription by specifying both an address and a topic.
+     *
+     * This function performs a `POST` to the `/admin/api/2020-01/webhooks.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_create_webhooks`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/events/webhook#create-2020-01
+     */
+    pub fn deprecated_202001_create_webhooks_request<'a>(
+        &'a self,
+        body: &'a serde_json::Value,
+    ) -> EventsDeprecated202001CreateWebhooksRequest<'a> {
+        EventsDeprecated202001CreateWebhooksRequest {
+            client: self,
+            format: None,
+            body,
+        }
+    }
     /**
      * Retrieves a count of existing webhook subscriptions.
      *
@@ -1021,6 +4298,24 @@ impl Events {
             )
             .await
     }
+    /**
+     * Retrieves a count of existing webhook subscriptions.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-01/webhooks/count.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_get_webhooks_count`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/events/webhook#count-2020-01
+     */
+    pub fn deprecated_202001_get_webhooks_count_request(
+        &self,
+    ) -> EventsDeprecated202001GetWebhooksCountRequest<'_> {
+        EventsDeprecated202001GetWebhooksCountRequest {
+            client: self,
+            address: None,
+            topic: None,
+        }
+    }
     /**
      * Retrieves a single webhook subscription.
      *
@@ -1061,6 +4356,25 @@ impl Events {
             )
             .await
     }
+    /**
+     * Retrieves a single webhook subscription.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-01/webhooks/{webhook_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_get_webhooks_param_webhook`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/events/webhook#show-2020-01
+     */
+    pub fn deprecated_202001_get_webhooks_param_webhook_request<'a>(
+        &'a self,
+        webhook_id: &'a str,
+    ) -> EventsDeprecated202001GetWebhooksParamWebhookRequest<'a> {
+        EventsDeprecated202001GetWebhooksParamWebhookRequest {
+            client: self,
+            webhook_id,
+            fields: None,
+        }
+    }
     /**
      * Update a webhook subscription's topic or address URIs.
      *
@@ -1202,6 +4516,31 @@ impl Events {
             )
             .await
     }
+    /**
+     * Retrieves a list of webhooks. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-04/webhooks.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_get_webhook`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/events/webhook#index-2020-04
+     */
+    pub fn deprecated_202004_get_webhook_request(
+        &self,
+    ) -> EventsDeprecated202004GetWebhookRequest<'_> {
+        EventsDeprecated202004GetWebhookRequest {
+            client: self,
+            address: None,
+            created_at_max: None,
+            created_at_min: None,
+            fields: None,
+            limit: None,
+            since_id: None,
+            topic: None,
+            updated_at_min: None,
+            updated_at_max: None,
+        }
+    }
     /**
      * Create a new webhook subscription by specifying both an address and a topic.
      *
@@ -1238,6 +4577,25 @@ impl Events {
             )
             .await
     }
+    /**
+     * Create a new webhook subscription by specifying both an address and a topic.
+     *
+     * This function performs a `POST` to the `/admin/api/2020-04/webhooks.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_create_webhooks`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/events/webhook#create-2020-04
+     */
+    pub fn deprecated_202004_create_webhooks_request<'a>(
+        &'a self,
+        body: &'a serde_json::Value,
+    ) -> EventsDeprecated202004CreateWebhooksRequest<'a> {
+        EventsDeprecated202004CreateWebhooksRequest {
+            client: self,
+            format: None,
+            body,
+        }
+    }
     /**
      * Retrieves a count of existing webhook subscriptions.
      *
@@ -1278,6 +4636,24 @@ impl Events {
             )
             .await
     }
+    /**
+     * Retrieves a count of existing webhook subscriptions.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-04/webhooks/count.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_get_webhooks_count`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/events/webhook#count-2020-04
+     */
+    pub fn deprecated_202004_get_webhooks_count_request(
+        &self,
+    ) -> EventsDeprecated202004GetWebhooksCountRequest<'_> {
+        EventsDeprecated202004GetWebhooksCountRequest {
+            client: self,
+            address: None,
+            topic: None,
+        }
+    }
     /**
      * Retrieves a single webhook subscription.
      *
@@ -1318,6 +4694,25 @@ impl Events {
             )
             .await
     }
+    /**
+     * Retrieves a single webhook subscription.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-04/webhooks/{webhook_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_get_webhooks_param_webhook`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/events/webhook#show-2020-04
+     */
+    pub fn deprecated_202004_get_webhooks_param_webhook_request<'a>(
+        &'a self,
+        webhook_id: &'a str,
+    ) -> EventsDeprecated202004GetWebhooksParamWebhookRequest<'a> {
+        EventsDeprecated202004GetWebhooksParamWebhookRequest {
+            client: self,
+            webhook_id,
+            fields: None,
+        }
+    }
     /**
      * Update a webhook subscription's topic or address URIs.
      *
@@ -1459,6 +4854,31 @@ impl Events {
             )
             .await
     }
+    /**
+     * Retrieves a list of webhooks. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-07/webhooks.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_get_webhook`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/events/webhook#index-2020-07
+     */
+    pub fn deprecated_202007_get_webhook_request(
+        &self,
+    ) -> EventsDeprecated202007GetWebhookRequest<'_> {
+        EventsDeprecated202007GetWebhookRequest {
+            client: self,
+            address: None,
+            created_at_max: None,
+            created_at_min: None,
+            fields: None,
+            limit: None,
+            since_id: None,
+            topic: None,
+            updated_at_min: None,
+            updated_at_max: None,
+        }
+    }
     /**
      * Create a new webhook subscription by specifying both an address and a topic.
      *
@@ -1495,6 +4915,25 @@ impl Events {
             )
             .await
     }
+    /**
+     * Create a new webhook subscription by specifying both an address and a topic.
+     *
+     * This function performs a `POST` to the `/admin/api/2020-07/webhooks.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_create_webhooks`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/events/webhook#create-2020-07
+     */
+    pub fn deprecated_202007_create_webhooks_request<'a>(
+        &'a self,
+        body: &'a serde_json::Value,
+    ) -> EventsDeprecated202007CreateWebhooksRequest<'a> {
+        EventsDeprecated202007CreateWebhooksRequest {
+            client: self,
+            format: None,
+            body,
+        }
+    }
     /**
      * Retrieves a count of existing webhook subscriptions.
      *
@@ -1535,6 +4974,24 @@ impl Events {
             )
             .await
     }
+    /**
+     * Retrieves a count of existing webhook subscriptions.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-07/webhooks/count.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_get_webhooks_count`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/events/webhook#count-2020-07
+     */
+    pub fn deprecated_202007_get_webhooks_count_request(
+        &self,
+    ) -> EventsDeprecated202007GetWebhooksCountRequest<'_> {
+        EventsDeprecated202007GetWebhooksCountRequest {
+            client: self,
+            address: None,
+            topic: None,
+        }
+    }
     /**
      * Retrieves a single webhook subscription.
      *
@@ -1575,6 +5032,25 @@ impl Events {
             )
             .await
     }
+    /**
+     * Retrieves a single webhook subscription.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-07/webhooks/{webhook_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_get_webhooks_param_webhook`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/events/webhook#show-2020-07
+     */
+    pub fn deprecated_202007_get_webhooks_param_webhook_request<'a>(
+        &'a self,
+        webhook_id: &'a str,
+    ) -> EventsDeprecated202007GetWebhooksParamWebhookRequest<'a> {
+        EventsDeprecated202007GetWebhooksParamWebhookRequest {
+            client: self,
+            webhook_id,
+            fields: None,
+        }
+    }
     /**
      * Update a webhook subscription's topic or address URIs.
      *
@@ -1716,6 +5192,29 @@ impl Events {
             )
             .await
     }
+    /**
+     * Retrieves a list of webhooks. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-10/webhooks.json` endpoint.
+     *
+     * As opposed to `get_webhook`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/events/webhook#index-2020-10
+     */
+    pub fn get_webhook_request(&self) -> EventsGetWebhookRequest<'_> {
+        EventsGetWebhookRequest {
+            client: self,
+            address: None,
+            created_at_max: None,
+            created_at_min: None,
+            fields: None,
+            limit: None,
+            since_id: None,
+            topic: None,
+            updated_at_min: None,
+            updated_at_max: None,
+        }
+    }
     /**
      * Create a new webhook subscription by specifying both an address and a topic.
      *
@@ -1752,6 +5251,25 @@ impl Events {
             )
             .await
     }
+    /**
+     * Create a new webhook subscription by specifying both an address and a topic.
+     *
+     * This function performs a `POST` to the `/admin/api/2020-10/webhooks.json` endpoint.
+     *
+     * As opposed to `create_webhooks`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/events/webhook#create-2020-10
+     */
+    pub fn create_webhooks_request<'a>(
+        &'a self,
+        body: &'a serde_json::Value,
+    ) -> EventsCreateWebhooksRequest<'a> {
+        EventsCreateWebhooksRequest {
+            client: self,
+            format: None,
+            body,
+        }
+    }
     /**
      * Retrieves a count of existing webhook subscriptions.
      *
@@ -1792,6 +5310,22 @@ impl Events {
             )
             .await
     }
+    /**
+     * Retrieves a count of existing webhook subscriptions.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-10/webhooks/count.json` endpoint.
+     *
+     * As opposed to `get_webhooks_count`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/events/webhook#count-2020-10
+     */
+    pub fn get_webhooks_count_request(&self) -> EventsGetWebhooksCountRequest<'_> {
+        EventsGetWebhooksCountRequest {
+            client: self,
+            address: None,
+            topic: None,
+        }
+    }
     /**
      * Retrieves a single webhook subscription.
      *
@@ -1832,6 +5366,25 @@ impl Events {
             )
             .await
     }
+    /**
+     * Retrieves a single webhook subscription.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-10/webhooks/{webhook_id}.json` endpoint.
+     *
+     * As opposed to `get_webhooks_param_webhook`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/events/webhook#show-2020-10
+     */
+    pub fn get_webhooks_param_webhook_request<'a>(
+        &'a self,
+        webhook_id: &'a str,
+    ) -> EventsGetWebhooksParamWebhookRequest<'a> {
+        EventsGetWebhooksParamWebhookRequest {
+            client: self,
+            webhook_id,
+            fields: None,
+        }
+    }
     /**
      * Update a webhook subscription's topic or address URIs.
      *
@@ -1973,6 +5526,31 @@ impl Events {
             )
             .await
     }
+    /**
+     * Retrieves a list of webhooks. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2021-01/webhooks.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_get_webhook`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/events/webhook#index-2021-01
+     */
+    pub fn deprecated_202101_get_webhook_request(
+        &self,
+    ) -> EventsDeprecated202101GetWebhookRequest<'_> {
+        EventsDeprecated202101GetWebhookRequest {
+            client: self,
+            address: None,
+            created_at_max: None,
+            created_at_min: None,
+            fields: None,
+            limit: None,
+            since_id: None,
+            topic: None,
+            updated_at_min: None,
+            updated_at_max: None,
+        }
+    }
     /**
      * Create a new webhook subscription by specifying both an address and a topic.
      *
@@ -2009,6 +5587,25 @@ impl Events {
             )
             .await
     }
+    /**
+     * Create a new webhook subscription by specifying both an address and a topic.
+     *
+     * This function performs a `POST` to the `/admin/api/2021-01/webhooks.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_create_webhooks`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/events/webhook#create-2021-01
+     */
+    pub fn deprecated_202101_create_webhooks_request<'a>(
+        &'a self,
+        body: &'a serde_json::Value,
+    ) -> EventsDeprecated202101CreateWebhooksRequest<'a> {
+        EventsDeprecated202101CreateWebhooksRequest {
+            client: self,
+            format: None,
+            body,
+        }
+    }
     /**
      * Retrieves a count of existing webhook subscriptions.
      *
@@ -2049,6 +5646,24 @@ impl Events {
             )
             .await
     }
+    /**
+     * Retrieves a count of existing webhook subscriptions.
+     *
+     * This function performs a `GET` to the `/admin/api/2021-01/webhooks/count.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_get_webhooks_count`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/events/webhook#count-2021-01
+     */
+    pub fn deprecated_202101_get_webhooks_count_request(
+        &self,
+    ) -> EventsDeprecated202101GetWebhooksCountRequest<'_> {
+        EventsDeprecated202101GetWebhooksCountRequest {
+            client: self,
+            address: None,
+            topic: None,
+        }
+    }
     /**
      * Retrieves a single webhook subscription.
      *
@@ -2089,6 +5704,25 @@ impl Events {
             )
             .await
     }
+    /**
+     * Retrieves a single webhook subscription.
+     *
+     * This function performs a `GET` to the `/admin/api/2021-01/webhooks/{webhook_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_get_webhooks_param_webhook`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/events/webhook#show-2021-01
+     */
+    pub fn deprecated_202101_get_webhooks_param_webhook_request<'a>(
+        &'a self,
+        webhook_id: &'a str,
+    ) -> EventsDeprecated202101GetWebhooksParamWebhookRequest<'a> {
+        EventsDeprecated202101GetWebhooksParamWebhookRequest {
+            client: self,
+            webhook_id,
+            fields: None,
+        }
+    }
     /**
      * Update a webhook subscription's topic or address URIs.
      *
@@ -2230,6 +5864,31 @@ impl Events {
             )
             .await
     }
+    /**
+     * Retrieves a list of webhooks. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/unstable/webhooks.json` endpoint.
+     *
+     * As opposed to `deprecated_unstable_get_webhook`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/events/webhook#index-unstable
+     */
+    pub fn deprecated_unstable_get_webhook_request(
+        &self,
+    ) -> EventsDeprecatedUnstableGetWebhookRequest<'_> {
+        EventsDeprecatedUnstableGetWebhookRequest {
+            client: self,
+            address: None,
+            created_at_max: None,
+            created_at_min: None,
+            fields: None,
+            limit: None,
+            since_id: None,
+            topic: None,
+            updated_at_min: None,
+            updated_at_max: None,
+        }
+    }
     /**
      * Create a new webhook subscription by specifying both an address and a topic.
      *
@@ -2266,6 +5925,25 @@ impl Events {
             )
             .await
     }
+    /**
+     * Create a new webhook subscription by specifying both an address and a topic.
+     *
+     * This function performs a `POST` to the `/admin/api/unstable/webhooks.json` endpoint.
+     *
+     * As opposed to `deprecated_unstable_create_webhooks`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/events/webhook#create-unstable
+     */
+    pub fn deprecated_unstable_create_webhooks_request<'a>(
+        &'a self,
+        body: &'a serde_json::Value,
+    ) -> EventsDeprecatedUnstableCreateWebhooksRequest<'a> {
+        EventsDeprecatedUnstableCreateWebhooksRequest {
+            client: self,
+            format: None,
+            body,
+        }
+    }
     /**
      * Retrieves a count of existing webhook subscriptions.
      *
@@ -2306,6 +5984,24 @@ impl Events {
             )
             .await
     }
+    /**
+     * Retrieves a count of existing webhook subscriptions.
+     *
+     * This function performs a `GET` to the `/admin/api/unstable/webhooks/count.json` endpoint.
+     *
+     * As opposed to `deprecated_unstable_get_webhooks_count`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/events/webhook#count-unstable
+     */
+    pub fn deprecated_unstable_get_webhooks_count_request(
+        &self,
+    ) -> EventsDeprecatedUnstableGetWebhooksCountRequest<'_> {
+        EventsDeprecatedUnstableGetWebhooksCountRequest {
+            client: self,
+            address: None,
+            topic: None,
+        }
+    }
     /**
      * Retrieves a single webhook subscription.
      *
@@ -2346,6 +6042,25 @@ impl Events {
             )
             .await
     }
+    /**
+     * Retrieves a single webhook subscription.
+     *
+     * This function performs a `GET` to the `/admin/api/unstable/webhooks/{webhook_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_unstable_get_webhooks_param_webhook`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/events/webhook#show-unstable
+     */
+    pub fn deprecated_unstable_get_webhooks_param_webhook_request<'a>(
+        &'a self,
+        webhook_id: &'a str,
+    ) -> EventsDeprecatedUnstableGetWebhooksParamWebhookRequest<'a> {
+        EventsDeprecatedUnstableGetWebhooksParamWebhookRequest {
+            client: self,
+            webhook_id,
+            fields: None,
+        }
+    }
     /**
      * Update a webhook subscription's topic or address URIs.
      *
@@ -1,6 +1,2895 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Inventory::deprecated_202001_get_item`], set with a builder.
+pub struct InventoryDeprecated202001GetItemRequest<'a> {
+    client: &'a Inventory,
+    ids_required: Option<&'a str>,
+    limit: Option<&'a str>,
+    ids: Option<i64>,
+}
+
+impl<'a> InventoryDeprecated202001GetItemRequest<'a> {
+    /// Sets the `ids required` query parameter.
+    pub fn ids_required(mut self, ids_required: &'a str) -> Self {
+        self.ids_required = Some(ids_required);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `ids` query parameter.
+    pub fn ids(mut self, ids: i64) -> Self {
+        self.ids = Some(ids);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            ids_required,
+            limit,
+            ids,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = ids {
+            query_args.push(("ids".to_string(), v.to_string()));
+        }
+        if let Some(v) = ids_required {
+            query_args.push((
+                "ids
+                  required"
+                    .to_string(),
+                v.to_string(),
+            ));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-01/inventory_items.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Inventory::deprecated_202004_get_item`], set with a builder.
+pub struct InventoryDeprecated202004GetItemRequest<'a> {
+    client: &'a Inventory,
+    ids_required: Option<&'a str>,
+    limit: Option<&'a str>,
+    ids: Option<i64>,
+}
+
+impl<'a> InventoryDeprecated202004GetItemRequest<'a> {
+    /// Sets the `ids required` query parameter.
+    pub fn ids_required(mut self, ids_required: &'a str) -> Self {
+        self.ids_required = Some(ids_required);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `ids` query parameter.
+    pub fn ids(mut self, ids: i64) -> Self {
+        self.ids = Some(ids);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            ids_required,
+            limit,
+            ids,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = ids {
+            query_args.push(("ids".to_string(), v.to_string()));
+        }
+        if let Some(v) = ids_required {
+            query_args.push((
+                "ids
+                  required"
+                    .to_string(),
+                v.to_string(),
+            ));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-04/inventory_items.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Inventory::deprecated_202007_get_item`], set with a builder.
+pub struct InventoryDeprecated202007GetItemRequest<'a> {
+    client: &'a Inventory,
+    ids_required: Option<&'a str>,
+    limit: Option<&'a str>,
+    ids: Option<i64>,
+}
+
+impl<'a> InventoryDeprecated202007GetItemRequest<'a> {
+    /// Sets the `ids required` query parameter.
+    pub fn ids_required(mut self, ids_required: &'a str) -> Self {
+        self.ids_required = Some(ids_required);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `ids` query parameter.
+    pub fn ids(mut self, ids: i64) -> Self {
+        self.ids = Some(ids);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            ids_required,
+            limit,
+            ids,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = ids {
+            query_args.push(("ids".to_string(), v.to_string()));
+        }
+        if let Some(v) = ids_required {
+            query_args.push((
+                "ids
+                  required"
+                    .to_string(),
+                v.to_string(),
+            ));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-07/inventory_items.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Inventory::get_item`], set with a builder.
+pub struct InventoryGetItemRequest<'a> {
+    client: &'a Inventory,
+    ids_required: Option<&'a str>,
+    limit: Option<&'a str>,
+    ids: Option<i64>,
+}
+
+impl<'a> InventoryGetItemRequest<'a> {
+    /// Sets the `ids required` query parameter.
+    pub fn ids_required(mut self, ids_required: &'a str) -> Self {
+        self.ids_required = Some(ids_required);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `ids` query parameter.
+    pub fn ids(mut self, ids: i64) -> Self {
+        self.ids = Some(ids);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            ids_required,
+            limit,
+            ids,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = ids {
+            query_args.push(("ids".to_string(), v.to_string()));
+        }
+        if let Some(v) = ids_required {
+            query_args.push((
+                "ids
+                  required"
+                    .to_string(),
+                v.to_string(),
+            ));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-10/inventory_items.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Inventory::deprecated_202101_get_item`], set with a builder.
+pub struct InventoryDeprecated202101GetItemRequest<'a> {
+    client: &'a Inventory,
+    ids_required: Option<&'a str>,
+    limit: Option<&'a str>,
+    ids: Option<i64>,
+}
+
+impl<'a> InventoryDeprecated202101GetItemRequest<'a> {
+    /// Sets the `ids required` query parameter.
+    pub fn ids_required(mut self, ids_required: &'a str) -> Self {
+        self.ids_required = Some(ids_required);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `ids` query parameter.
+    pub fn ids(mut self, ids: i64) -> Self {
+        self.ids = Some(ids);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            ids_required,
+            limit,
+            ids,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = ids {
+            query_args.push(("ids".to_string(), v.to_string()));
+        }
+        if let Some(v) = ids_required {
+            query_args.push((
+                "ids
+                  required"
+                    .to_string(),
+                v.to_string(),
+            ));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2021-01/inventory_items.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Inventory::deprecated_unstable_get_item`], set with a builder.
+pub struct InventoryDeprecatedUnstableGetItemRequest<'a> {
+    client: &'a Inventory,
+    ids_required: Option<&'a str>,
+    limit: Option<&'a str>,
+    ids: Option<i64>,
+}
+
+impl<'a> InventoryDeprecatedUnstableGetItemRequest<'a> {
+    /// Sets the `ids required` query parameter.
+    pub fn ids_required(mut self, ids_required: &'a str) -> Self {
+        self.ids_required = Some(ids_required);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `ids` query parameter.
+    pub fn ids(mut self, ids: i64) -> Self {
+        self.ids = Some(ids);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            ids_required,
+            limit,
+            ids,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = ids {
+            query_args.push(("ids".to_string(), v.to_string()));
+        }
+        if let Some(v) = ids_required {
+            query_args.push((
+                "ids
+                  required"
+                    .to_string(),
+                v.to_string(),
+            ));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/unstable/inventory_items.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Inventory::deprecated_202001_get_level`], set with a builder.
+pub struct InventoryDeprecated202001GetLevelRequest<'a> {
+    client: &'a Inventory,
+    inventory_item_ids: Option<&'a str>,
+    location_ids: Option<&'a str>,
+    limit: Option<&'a str>,
+    updated_at_min: Option<&'a str>,
+}
+
+impl<'a> InventoryDeprecated202001GetLevelRequest<'a> {
+    /// Sets the `inventory_item_ids` query parameter.
+    pub fn inventory_item_ids(mut self, inventory_item_ids: &'a str) -> Self {
+        self.inventory_item_ids = Some(inventory_item_ids);
+        self
+    }
+
+    /// Sets the `location_ids` query parameter.
+    pub fn location_ids(mut self, location_ids: &'a str) -> Self {
+        self.location_ids = Some(location_ids);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.updated_at_min = Some(updated_at_min);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            inventory_item_ids,
+            location_ids,
+            limit,
+            updated_at_min,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = inventory_item_ids {
+            query_args.push(("inventory_item_ids".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = location_ids {
+            query_args.push(("location_ids".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_min {
+            query_args.push(("updated_at_min".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-01/inventory_levels.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Inventory::deprecated_202001_delete_levels`], set with a builder.
+pub struct InventoryDeprecated202001DeleteLevelsRequest<'a> {
+    client: &'a Inventory,
+    inventory_item_id_required: Option<&'a str>,
+    location_id_required: Option<&'a str>,
+    inventory_item_id: Option<i64>,
+    location_id: Option<i64>,
+}
+
+impl<'a> InventoryDeprecated202001DeleteLevelsRequest<'a> {
+    /// Sets the `inventory_item_id required` query parameter.
+    pub fn inventory_item_id_required(mut self, inventory_item_id_required: &'a str) -> Self {
+        self.inventory_item_id_required = Some(inventory_item_id_required);
+        self
+    }
+
+    /// Sets the `location_id required` query parameter.
+    pub fn location_id_required(mut self, location_id_required: &'a str) -> Self {
+        self.location_id_required = Some(location_id_required);
+        self
+    }
+
+    /// Sets the `inventory_item_id` query parameter.
+    pub fn inventory_item_id(mut self, inventory_item_id: i64) -> Self {
+        self.inventory_item_id = Some(inventory_item_id);
+        self
+    }
+
+    /// Sets the `location_id` query parameter.
+    pub fn location_id(mut self, location_id: i64) -> Self {
+        self.location_id = Some(location_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            inventory_item_id_required,
+            location_id_required,
+            inventory_item_id,
+            location_id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = inventory_item_id {
+            query_args.push(("inventory_item_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = inventory_item_id_required {
+            query_args.push((
+                "inventory_item_id
+                  required"
+                    .to_string(),
+                v.to_string(),
+            ));
+        }
+        if let Some(v) = location_id {
+            query_args.push(("location_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = location_id_required {
+            query_args.push((
+                "location_id
+                  required"
+                    .to_string(),
+                v.to_string(),
+            ));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-01/inventory_levels.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .delete(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Inventory::deprecated_202001_create_levels_adjust`], set with a builder.
+pub struct InventoryDeprecated202001CreateLevelsAdjustRequest<'a> {
+    client: &'a Inventory,
+    inventory_item_id_required: Option<&'a str>,
+    location_id_required: Option<&'a str>,
+    available_adjustment_required: Option<&'a str>,
+    body: &'a serde_json::Value,
+}
+
+impl<'a> InventoryDeprecated202001CreateLevelsAdjustRequest<'a> {
+    /// Sets the `inventory_item_id required` query parameter.
+    pub fn inventory_item_id_required(mut self, inventory_item_id_required: &'a str) -> Self {
+        self.inventory_item_id_required = Some(inventory_item_id_required);
+        self
+    }
+
+    /// Sets the `location_id required` query parameter.
+    pub fn location_id_required(mut self, location_id_required: &'a str) -> Self {
+        self.location_id_required = Some(location_id_required);
+        self
+    }
+
+    /// Sets the `available_adjustment required` query parameter.
+    pub fn available_adjustment_required(mut self, available_adjustment_required: &'a str) -> Self {
+        self.available_adjustment_required = Some(available_adjustment_required);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            inventory_item_id_required,
+            location_id_required,
+            available_adjustment_required,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = available_adjustment_required {
+            query_args.push((
+                "available_adjustment
+                  required"
+                    .to_string(),
+                v.to_string(),
+            ));
+        }
+        if let Some(v) = inventory_item_id_required {
+            query_args.push((
+                "inventory_item_id
+                  required"
+                    .to_string(),
+                v.to_string(),
+            ));
+        }
+        if let Some(v) = location_id_required {
+            query_args.push((
+                "location_id
+                  required"
+                    .to_string(),
+                v.to_string(),
+            ));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-01/inventory_levels/adjust.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Inventory::deprecated_202001_create_levels_connect`], set with a builder.
+pub struct InventoryDeprecated202001CreateLevelsConnectRequest<'a> {
+    client: &'a Inventory,
+    inventory_item_id_required: Option<&'a str>,
+    location_id_required: Option<&'a str>,
+    relocate_if_necessary: Option<&'a str>,
+    body: &'a serde_json::Value,
+}
+
+impl<'a> InventoryDeprecated202001CreateLevelsConnectRequest<'a> {
+    /// Sets the `inventory_item_id required` query parameter.
+    pub fn inventory_item_id_required(mut self, inventory_item_id_required: &'a str) -> Self {
+        self.inventory_item_id_required = Some(inventory_item_id_required);
+        self
+    }
+
+    /// Sets the `location_id required` query parameter.
+    pub fn location_id_required(mut self, location_id_required: &'a str) -> Self {
+        self.location_id_required = Some(location_id_required);
+        self
+    }
+
+    /// Sets the `relocate_if_necessary` query parameter.
+    pub fn relocate_if_necessary(mut self, relocate_if_necessary: &'a str) -> Self {
+        self.relocate_if_necessary = Some(relocate_if_necessary);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            inventory_item_id_required,
+            location_id_required,
+            relocate_if_necessary,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = inventory_item_id_required {
+            query_args.push((
+                "inventory_item_id
+                  required"
+                    .to_string(),
+                v.to_string(),
+            ));
+        }
+        if let Some(v) = location_id_required {
+            query_args.push((
+                "location_id
+                  required"
+                    .to_string(),
+                v.to_string(),
+            ));
+        }
+        if let Some(v) = relocate_if_necessary {
+            query_args.push(("relocate_if_necessary".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-01/inventory_levels/connect.json?{}",
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Inventory::deprecated_202001_create_levels_set`], set with a builder.
+pub struct InventoryDeprecated202001CreateLevelsSetRequest<'a> {
+    client: &'a Inventory,
+    inventory_item_id_required: Option<&'a str>,
+    location_id_required: Option<&'a str>,
+    available_required: Option<&'a str>,
+    disconnect_if_necessary: Option<&'a str>,
+    body: &'a serde_json::Value,
+}
+
+impl<'a> InventoryDeprecated202001CreateLevelsSetRequest<'a> {
+    /// Sets the `inventory_item_id required` query parameter.
+    pub fn inventory_item_id_required(mut self, inventory_item_id_required: &'a str) -> Self {
+        self.inventory_item_id_required = Some(inventory_item_id_required);
+        self
+    }
+
+    /// Sets the `location_id required` query parameter.
+    pub fn location_id_required(mut self, location_id_required: &'a str) -> Self {
+        self.location_id_required = Some(location_id_required);
+        self
+    }
+
+    /// Sets the `available required` query parameter.
+    pub fn available_required(mut self, available_required: &'a str) -> Self {
+        self.available_required = Some(available_required);
+        self
+    }
+
+    /// Sets the `disconnect_if_necessary` query parameter.
+    pub fn disconnect_if_necessary(mut self, disconnect_if_necessary: &'a str) -> Self {
+        self.disconnect_if_necessary = Some(disconnect_if_necessary);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            inventory_item_id_required,
+            location_id_required,
+            available_required,
+            disconnect_if_necessary,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = available_required {
+            query_args.push((
+                "available
+                  required"
+                    .to_string(),
+                v.to_string(),
+            ));
+        }
+        if let Some(v) = disconnect_if_necessary {
+            query_args.push(("disconnect_if_necessary".to_string(), v.to_string()));
+        }
+        if let Some(v) = inventory_item_id_required {
+            query_args.push((
+                "inventory_item_id
+                  required"
+                    .to_string(),
+                v.to_string(),
+            ));
+        }
+        if let Some(v) = location_id_required {
+            query_args.push((
+                "location_id
+                  required"
+                    .to_string(),
+                v.to_string(),
+            ));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-01/inventory_levels/set.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Inventory::deprecated_202004_get_level`], set with a builder.
+pub struct InventoryDeprecated202004GetLevelRequest<'a> {
+    client: &'a Inventory,
+    inventory_item_ids: Option<&'a str>,
+    location_ids: Option<&'a str>,
+    limit: Option<&'a str>,
+    updated_at_min: Option<&'a str>,
+}
+
+impl<'a> InventoryDeprecated202004GetLevelRequest<'a> {
+    /// Sets the `inventory_item_ids` query parameter.
+    pub fn inventory_item_ids(mut self, inventory_item_ids: &'a str) -> Self {
+        self.inventory_item_ids = Some(inventory_item_ids);
+        self
+    }
+
+    /// Sets the `location_ids` query parameter.
+    pub fn location_ids(mut self, location_ids: &'a str) -> Self {
+        self.location_ids = Some(location_ids);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.updated_at_min = Some(updated_at_min);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            inventory_item_ids,
+            location_ids,
+            limit,
+            updated_at_min,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = inventory_item_ids {
+            query_args.push(("inventory_item_ids".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = location_ids {
+            query_args.push(("location_ids".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_min {
+            query_args.push(("updated_at_min".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-04/inventory_levels.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Inventory::deprecated_202004_delete_levels`], set with a builder.
+pub struct InventoryDeprecated202004DeleteLevelsRequest<'a> {
+    client: &'a Inventory,
+    inventory_item_id_required: Option<&'a str>,
+    location_id_required: Option<&'a str>,
+    inventory_item_id: Option<i64>,
+    location_id: Option<i64>,
+}
+
+impl<'a> InventoryDeprecated202004DeleteLevelsRequest<'a> {
+    /// Sets the `inventory_item_id required` query parameter.
+    pub fn inventory_item_id_required(mut self, inventory_item_id_required: &'a str) -> Self {
+        self.inventory_item_id_required = Some(inventory_item_id_required);
+        self
+    }
+
+    /// Sets the `location_id required` query parameter.
+    pub fn location_id_required(mut self, location_id_required: &'a str) -> Self {
+        self.location_id_required = Some(location_id_required);
+        self
+    }
+
+    /// Sets the `inventory_item_id` query parameter.
+    pub fn inventory_item_id(mut self, inventory_item_id: i64) -> Self {
+        self.inventory_item_id = Some(inventory_item_id);
+        self
+    }
+
+    /// Sets the `location_id` query parameter.
+    pub fn location_id(mut self, location_id: i64) -> Self {
+        self.location_id = Some(location_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            inventory_item_id_required,
+            location_id_required,
+            inventory_item_id,
+            location_id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = inventory_item_id {
+            query_args.push(("inventory_item_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = inventory_item_id_required {
+            query_args.push((
+                "inventory_item_id
+                  required"
+                    .to_string(),
+                v.to_string(),
+            ));
+        }
+        if let Some(v) = location_id {
+            query_args.push(("location_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = location_id_required {
+            query_args.push((
+                "location_id
+                  required"
+                    .to_string(),
+                v.to_string(),
+            ));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-04/inventory_levels.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .delete(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Inventory::deprecated_202004_create_levels_adjust`], set with a builder.
+pub struct InventoryDeprecated202004CreateLevelsAdjustRequest<'a> {
+    client: &'a Inventory,
+    inventory_item_id_required: Option<&'a str>,
+    location_id_required: Option<&'a str>,
+    available_adjustment_required: Option<&'a str>,
+    body: &'a serde_json::Value,
+}
+
+impl<'a> InventoryDeprecated202004CreateLevelsAdjustRequest<'a> {
+    /// Sets the `inventory_item_id required` query parameter.
+    pub fn inventory_item_id_required(mut self, inventory_item_id_required: &'a str) -> Self {
+        self.inventory_item_id_required = Some(inventory_item_id_required);
+        self
+    }
+
+    /// Sets the `location_id required` query parameter.
+    pub fn location_id_required(mut self, location_id_required: &'a str) -> Self {
+        self.location_id_required = Some(location_id_required);
+        self
+    }
+
+    /// Sets the `available_adjustment required` query parameter.
+    pub fn available_adjustment_required(mut self, available_adjustment_required: &'a str) -> Self {
+        self.available_adjustment_required = Some(available_adjustment_required);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            inventory_item_id_required,
+            location_id_required,
+            available_adjustment_required,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = available_adjustment_required {
+            query_args.push((
+                "available_adjustment
+                  required"
+                    .to_string(),
+                v.to_string(),
+            ));
+        }
+        if let Some(v) = inventory_item_id_required {
+            query_args.push((
+                "inventory_item_id
+                  required"
+                    .to_string(),
+                v.to_string(),
+            ));
+        }
+        if let Some(v) = location_id_required {
+            query_args.push((
+                "location_id
+                  required"
+                    .to_string(),
+                v.to_string(),
+            ));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-04/inventory_levels/adjust.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Inventory::deprecated_202004_create_levels_connect`], set with a builder.
+pub struct InventoryDeprecated202004CreateLevelsConnectRequest<'a> {
+    client: &'a Inventory,
+    inventory_item_id_required: Option<&'a str>,
+    location_id_required: Option<&'a str>,
+    relocate_if_necessary: Option<&'a str>,
+    body: &'a serde_json::Value,
+}
+
+impl<'a> InventoryDeprecated202004CreateLevelsConnectRequest<'a> {
+    /// Sets the `inventory_item_id required` query parameter.
+    pub fn inventory_item_id_required(mut self, inventory_item_id_required: &'a str) -> Self {
+        self.inventory_item_id_required = Some(inventory_item_id_required);
+        self
+    }
+
+    /// Sets the `location_id required` query parameter.
+    pub fn location_id_required(mut self, location_id_required: &'a str) -> Self {
+        self.location_id_required = Some(location_id_required);
+        self
+    }
+
+    /// Sets the `relocate_if_necessary` query parameter.
+    pub fn relocate_if_necessary(mut self, relocate_if_necessary: &'a str) -> Self {
+        self.relocate_if_necessary = Some(relocate_if_necessary);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            inventory_item_id_required,
+            location_id_required,
+            relocate_if_necessary,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = inventory_item_id_required {
+            query_args.push((
+                "inventory_item_id
+                  required"
+                    .to_string(),
+                v.to_string(),
+            ));
+        }
+        if let Some(v) = location_id_required {
+            query_args.push((
+                "location_id
+                  required"
+                    .to_string(),
+                v.to_string(),
+            ));
+        }
+        if let Some(v) = relocate_if_necessary {
+            query_args.push(("relocate_if_necessary".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-04/inventory_levels/connect.json?{}",
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Inventory::deprecated_202004_create_levels_set`], set with a builder.
+pub struct InventoryDeprecated202004CreateLevelsSetRequest<'a> {
+    client: &'a Inventory,
+    inventory_item_id_required: Option<&'a str>,
+    location_id_required: Option<&'a str>,
+    available_required: Option<&'a str>,
+    disconnect_if_necessary: Option<&'a str>,
+    body: &'a serde_json::Value,
+}
+
+impl<'a> InventoryDeprecated202004CreateLevelsSetRequest<'a> {
+    /// Sets the `inventory_item_id required` query parameter.
+    pub fn inventory_item_id_required(mut self, inventory_item_id_required: &'a str) -> Self {
+        self.inventory_item_id_required = Some(inventory_item_id_required);
+        self
+    }
+
+    /// Sets the `location_id required` query parameter.
+    pub fn location_id_required(mut self, location_id_required: &'a str) -> Self {
+        self.location_id_required = Some(location_id_required);
+        self
+    }
+
+    /// Sets the `available required` query parameter.
+    pub fn available_required(mut self, available_required: &'a str) -> Self {
+        self.available_required = Some(available_required);
+        self
+    }
+
+    /// Sets the `disconnect_if_necessary` query parameter.
+    pub fn disconnect_if_necessary(mut self, disconnect_if_necessary: &'a str) -> Self {
+        self.disconnect_if_necessary = Some(disconnect_if_necessary);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            inventory_item_id_required,
+            location_id_required,
+            available_required,
+            disconnect_if_necessary,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = available_required {
+            query_args.push((
+                "available
+                  required"
+                    .to_string(),
+                v.to_string(),
+            ));
+        }
+        if let Some(v) = disconnect_if_necessary {
+            query_args.push(("disconnect_if_necessary".to_string(), v.to_string()));
+        }
+        if let Some(v) = inventory_item_id_required {
+            query_args.push((
+                "inventory_item_id
+                  required"
+                    .to_string(),
+                v.to_string(),
+            ));
+        }
+        if let Some(v) = location_id_required {
+            query_args.push((
+                "location_id
+                  required"
+                    .to_string(),
+                v.to_string(),
+            ));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-04/inventory_levels/set.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Inventory::deprecated_202007_get_level`], set with a builder.
+pub struct InventoryDeprecated202007GetLevelRequest<'a> {
+    client: &'a Inventory,
+    inventory_item_ids: Option<&'a str>,
+    location_ids: Option<&'a str>,
+    limit: Option<&'a str>,
+    updated_at_min: Option<&'a str>,
+}
+
+impl<'a> InventoryDeprecated202007GetLevelRequest<'a> {
+    /// Sets the `inventory_item_ids` query parameter.
+    pub fn inventory_item_ids(mut self, inventory_item_ids: &'a str) -> Self {
+        self.inventory_item_ids = Some(inventory_item_ids);
+        self
+    }
+
+    /// Sets the `location_ids` query parameter.
+    pub fn location_ids(mut self, location_ids: &'a str) -> Self {
+        self.location_ids = Some(location_ids);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.updated_at_min = Some(updated_at_min);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            inventory_item_ids,
+            location_ids,
+            limit,
+            updated_at_min,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = inventory_item_ids {
+            query_args.push(("inventory_item_ids".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = location_ids {
+            query_args.push(("location_ids".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_min {
+            query_args.push(("updated_at_min".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-07/inventory_levels.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Inventory::deprecated_202007_delete_levels`], set with a builder.
+pub struct InventoryDeprecated202007DeleteLevelsRequest<'a> {
+    client: &'a Inventory,
+    inventory_item_id_required: Option<&'a str>,
+    location_id_required: Option<&'a str>,
+    inventory_item_id: Option<i64>,
+    location_id: Option<i64>,
+}
+
+impl<'a> InventoryDeprecated202007DeleteLevelsRequest<'a> {
+    /// Sets the `inventory_item_id required` query parameter.
+    pub fn inventory_item_id_required(mut self, inventory_item_id_required: &'a str) -> Self {
+        self.inventory_item_id_required = Some(inventory_item_id_required);
+        self
+    }
+
+    /// Sets the `location_id required` query parameter.
+    pub fn location_id_required(mut self, location_id_required: &'a str) -> Self {
+        self.location_id_required = Some(location_id_required);
+        self
+    }
+
+    /// Sets the `inventory_item_id` query parameter.
+    pub fn inventory_item_id(mut self, inventory_item_id: i64) -> Self {
+        self.inventory_item_id = Some(inventory_item_id);
+        self
+    }
+
+    /// Sets the `location_id` query parameter.
+    pub fn location_id(mut self, location_id: i64) -> Self {
+        self.location_id = Some(location_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            inventory_item_id_required,
+            location_id_required,
+            inventory_item_id,
+            location_id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = inventory_item_id {
+            query_args.push(("inventory_item_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = inventory_item_id_required {
+            query_args.push((
+                "inventory_item_id
+                  required"
+                    .to_string(),
+                v.to_string(),
+            ));
+        }
+        if let Some(v) = location_id {
+            query_args.push(("location_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = location_id_required {
+            query_args.push((
+                "location_id
+                  required"
+                    .to_string(),
+                v.to_string(),
+            ));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-07/inventory_levels.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .delete(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Inventory::deprecated_202007_create_levels_adjust`], set with a builder.
+pub struct InventoryDeprecated202007CreateLevelsAdjustRequest<'a> {
+    client: &'a Inventory,
+    inventory_item_id_required: Option<&'a str>,
+    location_id_required: Option<&'a str>,
+    available_adjustment_required: Option<&'a str>,
+    body: &'a serde_json::Value,
+}
+
+impl<'a> InventoryDeprecated202007CreateLevelsAdjustRequest<'a> {
+    /// Sets the `inventory_item_id required` query parameter.
+    pub fn inventory_item_id_required(mut self, inventory_item_id_required: &'a str) -> Self {
+        self.inventory_item_id_required = Some(inventory_item_id_required);
+        self
+    }
+
+    /// Sets the `location_id required` query parameter.
+    pub fn location_id_required(mut self, location_id_required: &'a str) -> Self {
+        self.location_id_required = Some(location_id_required);
+        self
+    }
+
+    /// Sets the `available_adjustment required` query parameter.
+    pub fn available_adjustment_required(mut self, available_adjustment_required: &'a str) -> Self {
+        self.available_adjustment_required = Some(available_adjustment_required);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            inventory_item_id_required,
+            location_id_required,
+            available_adjustment_required,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = available_adjustment_required {
+            query_args.push((
+                "available_adjustment
+                  required"
+                    .to_string(),
+                v.to_string(),
+            ));
+        }
+        if let Some(v) = inventory_item_id_required {
+            query_args.push((
+                "inventory_item_id
+                  required"
+                    .to_string(),
+                v.to_string(),
+            ));
+        }
+        if let Some(v) = location_id_required {
+            query_args.push((
+                "location_id
+                  required"
+                    .to_string(),
+                v.to_string(),
+            ));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-07/inventory_levels/adjust.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Inventory::deprecated_202007_create_levels_connect`], set with a builder.
+pub struct InventoryDeprecated202007CreateLevelsConnectRequest<'a> {
+    client: &'a Inventory,
+    inventory_item_id_required: Option<&'a str>,
+    location_id_required: Option<&'a str>,
+    relocate_if_necessary: Option<&'a str>,
+    body: &'a serde_json::Value,
+}
+
+impl<'a> InventoryDeprecated202007CreateLevelsConnectRequest<'a> {
+    /// Sets the `inventory_item_id required` query parameter.
+    pub fn inventory_item_id_required(mut self, inventory_item_id_required: &'a str) -> Self {
+        self.inventory_item_id_required = Some(inventory_item_id_required);
+        self
+    }
+
+    /// Sets the `location_id required` query parameter.
+    pub fn location_id_required(mut self, location_id_required: &'a str) -> Self {
+        self.location_id_required = Some(location_id_required);
+        self
+    }
+
+    /// Sets the `relocate_if_necessary` query parameter.
+    pub fn relocate_if_necessary(mut self, relocate_if_necessary: &'a str) -> Self {
+        self.relocate_if_necessary = Some(relocate_if_necessary);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            inventory_item_id_required,
+            location_id_required,
+            relocate_if_necessary,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = inventory_item_id_required {
+            query_args.push((
+                "inventory_item_id
+                  required"
+                    .to_string(),
+                v.to_string(),
+            ));
+        }
+        if let Some(v) = location_id_required {
+            query_args.push((
+                "location_id
+                  required"
+                    .to_string(),
+                v.to_string(),
+            ));
+        }
+        if let Some(v) = relocate_if_necessary {
+            query_args.push(("relocate_if_necessary".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-07/inventory_levels/connect.json?{}",
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Inventory::deprecated_202007_create_levels_set`], set with a builder.
+pub struct InventoryDeprecated202007CreateLevelsSetRequest<'a> {
+    client: &'a Inventory,
+    inventory_item_id_required: Option<&'a str>,
+    location_id_required: Option<&'a str>,
+    available_required: Option<&'a str>,
+    disconnect_if_necessary: Option<&'a str>,
+    body: &'a serde_json::Value,
+}
+
+impl<'a> InventoryDeprecated202007CreateLevelsSetRequest<'a> {
+    /// Sets the `inventory_item_id required` query parameter.
+    pub fn inventory_item_id_required(mut self, inventory_item_id_required: &'a str) -> Self {
+        self.inventory_item_id_required = Some(inventory_item_id_required);
+        self
+    }
+
+    /// Sets the `location_id required` query parameter.
+    pub fn location_id_required(mut self, location_id_required: &'a str) -> Self {
+        self.location_id_required = Some(location_id_required);
+        self
+    }
+
+    /// Sets the `available required` query parameter.
+    pub fn available_required(mut self, available_required: &'a str) -> Self {
+        self.available_required = Some(available_required);
+        self
+    }
+
+    /// Sets the `disconnect_if_necessary` query parameter.
+    pub fn disconnect_if_necessary(mut self, disconnect_if_necessary: &'a str) -> Self {
+        self.disconnect_if_necessary = Some(disconnect_if_necessary);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            inventory_item_id_required,
+            location_id_required,
+            available_required,
+            disconnect_if_necessary,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = available_required {
+            query_args.push((
+                "available
+                  required"
+                    .to_string(),
+                v.to_string(),
+            ));
+        }
+        if let Some(v) = disconnect_if_necessary {
+            query_args.push(("disconnect_if_necessary".to_string(), v.to_string()));
+        }
+        if let Some(v) = inventory_item_id_required {
+            query_args.push((
+                "inventory_item_id
+                  required"
+                    .to_string(),
+                v.to_string(),
+            ));
+        }
+        if let Some(v) = location_id_required {
+            query_args.push((
+                "location_id
+                  required"
+                    .to_string(),
+                v.to_string(),
+            ));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-07/inventory_levels/set.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Inventory::get_level`], set with a builder.
+pub struct InventoryGetLevelRequest<'a> {
+    client: &'a Inventory,
+    inventory_item_ids: Option<&'a str>,
+    location_ids: Option<&'a str>,
+    limit: Option<&'a str>,
+    updated_at_min: Option<&'a str>,
+}
+
+impl<'a> InventoryGetLevelRequest<'a> {
+    /// Sets the `inventory_item_ids` query parameter.
+    pub fn inventory_item_ids(mut self, inventory_item_ids: &'a str) -> Self {
+        self.inventory_item_ids = Some(inventory_item_ids);
+        self
+    }
+
+    /// Sets the `location_ids` query parameter.
+    pub fn location_ids(mut self, location_ids: &'a str) -> Self {
+        self.location_ids = Some(location_ids);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.updated_at_min = Some(updated_at_min);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            inventory_item_ids,
+            location_ids,
+            limit,
+            updated_at_min,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = inventory_item_ids {
+            query_args.push(("inventory_item_ids".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = location_ids {
+            query_args.push(("location_ids".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_min {
+            query_args.push(("updated_at_min".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-10/inventory_levels.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Inventory::delete_levels`], set with a builder.
+pub struct InventoryDeleteLevelsRequest<'a> {
+    client: &'a Inventory,
+    inventory_item_id_required: Option<&'a str>,
+    location_id_required: Option<&'a str>,
+    inventory_item_id: Option<i64>,
+    location_id: Option<i64>,
+}
+
+impl<'a> InventoryDeleteLevelsRequest<'a> {
+    /// Sets the `inventory_item_id required` query parameter.
+    pub fn inventory_item_id_required(mut self, inventory_item_id_required: &'a str) -> Self {
+        self.inventory_item_id_required = Some(inventory_item_id_required);
+        self
+    }
+
+    /// Sets the `location_id required` query parameter.
+    pub fn location_id_required(mut self, location_id_required: &'a str) -> Self {
+        self.location_id_required = Some(location_id_required);
+        self
+    }
+
+    /// Sets the `inventory_item_id` query parameter.
+    pub fn inventory_item_id(mut self, inventory_item_id: i64) -> Self {
+        self.inventory_item_id = Some(inventory_item_id);
+        self
+    }
+
+    /// Sets the `location_id` query parameter.
+    pub fn location_id(mut self, location_id: i64) -> Self {
+        self.location_id = Some(location_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            inventory_item_id_required,
+            location_id_required,
+            inventory_item_id,
+            location_id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = inventory_item_id {
+            query_args.push(("inventory_item_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = inventory_item_id_required {
+            query_args.push((
+                "inventory_item_id
+                  required"
+                    .to_string(),
+                v.to_string(),
+            ));
+        }
+        if let Some(v) = location_id {
+            query_args.push(("location_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = location_id_required {
+            query_args.push((
+                "location_id
+                  required"
+                    .to_string(),
+                v.to_string(),
+            ));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-10/inventory_levels.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .delete(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Inventory::create_levels_adjust`], set with a builder.
+pub struct InventoryCreateLevelsAdjustRequest<'a> {
+    client: &'a Inventory,
+    inventory_item_id_required: Option<&'a str>,
+    location_id_required: Option<&'a str>,
+    available_adjustment_required: Option<&'a str>,
+    body: &'a serde_json::Value,
+}
+
+impl<'a> InventoryCreateLevelsAdjustRequest<'a> {
+    /// Sets the `inventory_item_id required` query parameter.
+    pub fn inventory_item_id_required(mut self, inventory_item_id_required: &'a str) -> Self {
+        self.inventory_item_id_required = Some(inventory_item_id_required);
+        self
+    }
+
+    /// Sets the `location_id required` query parameter.
+    pub fn location_id_required(mut self, location_id_required: &'a str) -> Self {
+        self.location_id_required = Some(location_id_required);
+        self
+    }
+
+    /// Sets the `available_adjustment required` query parameter.
+    pub fn available_adjustment_required(mut self, available_adjustment_required: &'a str) -> Self {
+        self.available_adjustment_required = Some(available_adjustment_required);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            inventory_item_id_required,
+            location_id_required,
+            available_adjustment_required,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = available_adjustment_required {
+            query_args.push((
+                "available_adjustment
+                  required"
+                    .to_string(),
+                v.to_string(),
+            ));
+        }
+        if let Some(v) = inventory_item_id_required {
+            query_args.push((
+                "inventory_item_id
+                  required"
+                    .to_string(),
+                v.to_string(),
+            ));
+        }
+        if let Some(v) = location_id_required {
+            query_args.push((
+                "location_id
+                  required"
+                    .to_string(),
+                v.to_string(),
+            ));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-10/inventory_levels/adjust.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Inventory::create_levels_connect`], set with a builder.
+pub struct InventoryCreateLevelsConnectRequest<'a> {
+    client: &'a Inventory,
+    inventory_item_id_required: Option<&'a str>,
+    location_id_required: Option<&'a str>,
+    relocate_if_necessary: Option<&'a str>,
+    body: &'a serde_json::Value,
+}
+
+impl<'a> InventoryCreateLevelsConnectRequest<'a> {
+    /// Sets the `inventory_item_id required` query parameter.
+    pub fn inventory_item_id_required(mut self, inventory_item_id_required: &'a str) -> Self {
+        self.inventory_item_id_required = Some(inventory_item_id_required);
+        self
+    }
+
+    /// Sets the `location_id required` query parameter.
+    pub fn location_id_required(mut self, location_id_required: &'a str) -> Self {
+        self.location_id_required = Some(location_id_required);
+        self
+    }
+
+    /// Sets the `relocate_if_necessary` query parameter.
+    pub fn relocate_if_necessary(mut self, relocate_if_necessary: &'a str) -> Self {
+        self.relocate_if_necessary = Some(relocate_if_necessary);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            inventory_item_id_required,
+            location_id_required,
+            relocate_if_necessary,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = inventory_item_id_required {
+            query_args.push((
+                "inventory_item_id
+                  required"
+                    .to_string(),
+                v.to_string(),
+            ));
+        }
+        if let Some(v) = location_id_required {
+            query_args.push((
+                "location_id
+                  required"
+                    .to_string(),
+                v.to_string(),
+            ));
+        }
+        if let Some(v) = relocate_if_necessary {
+            query_args.push(("relocate_if_necessary".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-10/inventory_levels/connect.json?{}",
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Inventory::create_levels_set`], set with a builder.
+pub struct InventoryCreateLevelsSetRequest<'a> {
+    client: &'a Inventory,
+    inventory_item_id_required: Option<&'a str>,
+    location_id_required: Option<&'a str>,
+    available_required: Option<&'a str>,
+    disconnect_if_necessary: Option<&'a str>,
+    body: &'a serde_json::Value,
+}
+
+impl<'a> InventoryCreateLevelsSetRequest<'a> {
+    /// Sets the `inventory_item_id required` query parameter.
+    pub fn inventory_item_id_required(mut self, inventory_item_id_required: &'a str) -> Self {
+        self.inventory_item_id_required = Some(inventory_item_id_required);
+        self
+    }
+
+    /// Sets the `location_id required` query parameter.
+    pub fn location_id_required(mut self, location_id_required: &'a str) -> Self {
+        self.location_id_required = Some(location_id_required);
+        self
+    }
+
+    /// Sets the `available required` query parameter.
+    pub fn available_required(mut self, available_required: &'a str) -> Self {
+        self.available_required = Some(available_required);
+        self
+    }
+
+    /// Sets the `disconnect_if_necessary` query parameter.
+    pub fn disconnect_if_necessary(mut self, disconnect_if_necessary: &'a str) -> Self {
+        self.disconnect_if_necessary = Some(disconnect_if_necessary);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            inventory_item_id_required,
+            location_id_required,
+            available_required,
+            disconnect_if_necessary,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = available_required {
+            query_args.push((
+                "available
+                  required"
+                    .to_string(),
+                v.to_string(),
+            ));
+        }
+        if let Some(v) = disconnect_if_necessary {
+            query_args.push(("disconnect_if_necessary".to_string(), v.to_string()));
+        }
+        if let Some(v) = inventory_item_id_required {
+            query_args.push((
+                "inventory_item_id
+                  required"
+                    .to_string(),
+                v.to_string(),
+            ));
+        }
+        if let Some(v) = location_id_required {
+            query_args.push((
+                "location_id
+                  required"
+                    .to_string(),
+                v.to_string(),
+            ));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-10/inventory_levels/set.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Inventory::deprecated_202101_get_level`], set with a builder.
+pub struct InventoryDeprecated202101GetLevelRequest<'a> {
+    client: &'a Inventory,
+    inventory_item_ids: Option<&'a str>,
+    location_ids: Option<&'a str>,
+    limit: Option<&'a str>,
+    updated_at_min: Option<&'a str>,
+}
+
+impl<'a> InventoryDeprecated202101GetLevelRequest<'a> {
+    /// Sets the `inventory_item_ids` query parameter.
+    pub fn inventory_item_ids(mut self, inventory_item_ids: &'a str) -> Self {
+        self.inventory_item_ids = Some(inventory_item_ids);
+        self
+    }
+
+    /// Sets the `location_ids` query parameter.
+    pub fn location_ids(mut self, location_ids: &'a str) -> Self {
+        self.location_ids = Some(location_ids);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.updated_at_min = Some(updated_at_min);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            inventory_item_ids,
+            location_ids,
+            limit,
+            updated_at_min,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = inventory_item_ids {
+            query_args.push(("inventory_item_ids".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = location_ids {
+            query_args.push(("location_ids".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_min {
+            query_args.push(("updated_at_min".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2021-01/inventory_levels.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Inventory::deprecated_202101_delete_levels`], set with a builder.
+pub struct InventoryDeprecated202101DeleteLevelsRequest<'a> {
+    client: &'a Inventory,
+    inventory_item_id_required: Option<&'a str>,
+    location_id_required: Option<&'a str>,
+    inventory_item_id: Option<i64>,
+    location_id: Option<i64>,
+}
+
+impl<'a> InventoryDeprecated202101DeleteLevelsRequest<'a> {
+    /// Sets the `inventory_item_id required` query parameter.
+    pub fn inventory_item_id_required(mut self, inventory_item_id_required: &'a str) -> Self {
+        self.inventory_item_id_required = Some(inventory_item_id_required);
+        self
+    }
+
+    /// Sets the `location_id required` query parameter.
+    pub fn location_id_required(mut self, location_id_required: &'a str) -> Self {
+        self.location_id_required = Some(location_id_required);
+        self
+    }
+
+    /// Sets the `inventory_item_id` query parameter.
+    pub fn inventory_item_id(mut self, inventory_item_id: i64) -> Self {
+        self.inventory_item_id = Some(inventory_item_id);
+        self
+    }
+
+    /// Sets the `location_id` query parameter.
+    pub fn location_id(mut self, location_id: i64) -> Self {
+        self.location_id = Some(location_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            inventory_item_id_required,
+            location_id_required,
+            inventory_item_id,
+            location_id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = inventory_item_id {
+            query_args.push(("inventory_item_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = inventory_item_id_required {
+            query_args.push((
+                "inventory_item_id
+                  required"
+                    .to_string(),
+                v.to_string(),
+            ));
+        }
+        if let Some(v) = location_id {
+            query_args.push(("location_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = location_id_required {
+            query_args.push((
+                "location_id
+                  required"
+                    .to_string(),
+                v.to_string(),
+            ));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2021-01/inventory_levels.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .delete(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Inventory::deprecated_202101_create_levels_adjust`], set with a builder.
+pub struct InventoryDeprecated202101CreateLevelsAdjustRequest<'a> {
+    client: &'a Inventory,
+    inventory_item_id_required: Option<&'a str>,
+    location_id_required: Option<&'a str>,
+    available_adjustment_required: Option<&'a str>,
+    body: &'a serde_json::Value,
+}
+
+impl<'a> InventoryDeprecated202101CreateLevelsAdjustRequest<'a> {
+    /// Sets the `inventory_item_id required` query parameter.
+    pub fn inventory_item_id_required(mut self, inventory_item_id_required: &'a str) -> Self {
+        self.inventory_item_id_required = Some(inventory_item_id_required);
+        self
+    }
+
+    /// Sets the `location_id required` query parameter.
+    pub fn location_id_required(mut self, location_id_required: &'a str) -> Self {
+        self.location_id_required = Some(location_id_required);
+        self
+    }
+
+    /// Sets the `available_adjustment required` query parameter.
+    pub fn available_adjustment_required(mut self, available_adjustment_required: &'a str) -> Self {
+        self.available_adjustment_required = Some(available_adjustment_required);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            inventory_item_id_required,
+            location_id_required,
+            available_adjustment_required,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = available_adjustment_required {
+            query_args.push((
+                "available_adjustment
+                  required"
+                    .to_string(),
+                v.to_string(),
+            ));
+        }
+        if let Some(v) = inventory_item_id_required {
+            query_args.push((
+                "inventory_item_id
+                  required"
+                    .to_string(),
+                v.to_string(),
+            ));
+        }
+        if let Some(v) = location_id_required {
+            query_args.push((
+                "location_id
+                  required"
+                    .to_string(),
+                v.to_string(),
+            ));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2021-01/inventory_levels/adjust.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Inventory::deprecated_202101_create_levels_connect`], set with a builder.
+pub struct InventoryDeprecated202101CreateLevelsConnectRequest<'a> {
+    client: &'a Inventory,
+    inventory_item_id_required: Option<&'a str>,
+    location_id_required: Option<&'a str>,
+    relocate_if_necessary: Option<&'a str>,
+    body: &'a serde_json::Value,
+}
+
+impl<'a> InventoryDeprecated202101CreateLevelsConnectRequest<'a> {
+    /// Sets the `inventory_item_id required` query parameter.
+    pub fn inventory_item_id_required(mut self, inventory_item_id_required: &'a str) -> Self {
+        self.inventory_item_id_required = Some(inventory_item_id_required);
+        self
+    }
+
+    /// Sets the `location_id required` query parameter.
+    pub fn location_id_required(mut self, location_id_required: &'a str) -> Self {
+        self.location_id_required = Some(location_id_required);
+        self
+    }
+
+    /// Sets the `relocate_if_necessary` query parameter.
+    pub fn relocate_if_necessary(mut self, relocate_if_necessary: &'a str) -> Self {
+        self.relocate_if_necessary = Some(relocate_if_necessary);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            inventory_item_id_required,
+            location_id_required,
+            relocate_if_necessary,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = inventory_item_id_required {
+            query_args.push((
+                "inventory_item_id
+                  required"
+                    .to_string(),
+                v.to_string(),
+            ));
+        }
+        if let Some(v) = location_id_required {
+            query_args.push((
+                "location_id
+                  required"
+                    .to_string(),
+                v.to_string(),
+            ));
+        }
+        if let Some(v) = relocate_if_necessary {
+            query_args.push(("relocate_if_necessary".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2021-01/inventory_levels/connect.json?{}",
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Inventory::deprecated_202101_create_levels_set`], set with a builder.
+pub struct InventoryDeprecated202101CreateLevelsSetRequest<'a> {
+    client: &'a Inventory,
+    inventory_item_id_required: Option<&'a str>,
+    location_id_required: Option<&'a str>,
+    available_required: Option<&'a str>,
+    disconnect_if_necessary: Option<&'a str>,
+    body: &'a serde_json::Value,
+}
+
+impl<'a> InventoryDeprecated202101CreateLevelsSetRequest<'a> {
+    /// Sets the `inventory_item_id required` query parameter.
+    pub fn inventory_item_id_required(mut self, inventory_item_id_required: &'a str) -> Self {
+        self.inventory_item_id_required = Some(inventory_item_id_required);
+        self
+    }
+
+    /// Sets the `location_id required` query parameter.
+    pub fn location_id_required(mut self, location_id_required: &'a str) -> Self {
+        self.location_id_required = Some(location_id_required);
+        self
+    }
+
+    /// Sets the `available required` query parameter.
+    pub fn available_required(mut self, available_required: &'a str) -> Self {
+        self.available_required = Some(available_required);
+        self
+    }
+
+    /// Sets the `disconnect_if_necessary` query parameter.
+    pub fn disconnect_if_necessary(mut self, disconnect_if_necessary: &'a str) -> Self {
+        self.disconnect_if_necessary = Some(disconnect_if_necessary);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            inventory_item_id_required,
+            location_id_required,
+            available_required,
+            disconnect_if_necessary,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = available_required {
+            query_args.push((
+                "available
+                  required"
+                    .to_string(),
+                v.to_string(),
+            ));
+        }
+        if let Some(v) = disconnect_if_necessary {
+            query_args.push(("disconnect_if_necessary".to_string(), v.to_string()));
+        }
+        if let Some(v) = inventory_item_id_required {
+            query_args.push((
+                "inventory_item_id
+                  required"
+                    .to_string(),
+                v.to_string(),
+            ));
+        }
+        if let Some(v) = location_id_required {
+            query_args.push((
+                "location_id
+                  required"
+                    .to_string(),
+                v.to_string(),
+            ));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2021-01/inventory_levels/set.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Inventory::deprecated_unstable_get_level`], set with a builder.
+pub struct InventoryDeprecatedUnstableGetLevelRequest<'a> {
+    client: &'a Inventory,
+    inventory_item_ids: Option<&'a str>,
+    location_ids: Option<&'a str>,
+    limit: Option<&'a str>,
+    updated_at_min: Option<&'a str>,
+}
+
+impl<'a> InventoryDeprecatedUnstableGetLevelRequest<'a> {
+    /// Sets the `inventory_item_ids` query parameter.
+    pub fn inventory_item_ids(mut self, inventory_item_ids: &'a str) -> Self {
+        self.inventory_item_ids = Some(inventory_item_ids);
+        self
+    }
+
+    /// Sets the `location_ids` query parameter.
+    pub fn location_ids(mut self, location_ids: &'a str) -> Self {
+        self.location_ids = Some(location_ids);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.updated_at_min = Some(updated_at_min);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            inventory_item_ids,
+            location_ids,
+            limit,
+            updated_at_min,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = inventory_item_ids {
+            query_args.push(("inventory_item_ids".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = location_ids {
+            query_args.push(("location_ids".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_min {
+            query_args.push(("updated_at_min".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/unstable/inventory_levels.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Inventory::deprecated_unstable_delete_levels`], set with a builder.
+pub struct InventoryDeprecatedUnstableDeleteLevelsRequest<'a> {
+    client: &'a Inventory,
+    inventory_item_id_required: Option<&'a str>,
+    location_id_required: Option<&'a str>,
+    inventory_item_id: Option<i64>,
+    location_id: Option<i64>,
+}
+
+impl<'a> InventoryDeprecatedUnstableDeleteLevelsRequest<'a> {
+    /// Sets the `inventory_item_id required` query parameter.
+    pub fn inventory_item_id_required(mut self, inventory_item_id_required: &'a str) -> Self {
+        self.inventory_item_id_required = Some(inventory_item_id_required);
+        self
+    }
+
+    /// Sets the `location_id required` query parameter.
+    pub fn location_id_required(mut self, location_id_required: &'a str) -> Self {
+        self.location_id_required = Some(location_id_required);
+        self
+    }
+
+    /// Sets the `inventory_item_id` query parameter.
+    pub fn inventory_item_id(mut self, inventory_item_id: i64) -> Self {
+        self.inventory_item_id = Some(inventory_item_id);
+        self
+    }
+
+    /// Sets the `location_id` query parameter.
+    pub fn location_id(mut self, location_id: i64) -> Self {
+        self.location_id = Some(location_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            inventory_item_id_required,
+            location_id_required,
+            inventory_item_id,
+            location_id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = inventory_item_id {
+            query_args.push(("inventory_item_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = inventory_item_id_required {
+            query_args.push((
+                "inventory_item_id
+                  required"
+                    .to_string(),
+                v.to_string(),
+            ));
+        }
+        if let Some(v) = location_id {
+            query_args.push(("location_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = location_id_required {
+            query_args.push((
+                "location_id
+                  required"
+                    .to_string(),
+                v.to_string(),
+            ));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/unstable/inventory_levels.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .delete(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Inventory::deprecated_unstable_create_levels_adjust`], set with a builder.
+pub struct InventoryDeprecatedUnstableCreateLevelsAdjustRequest<'a> {
+    client: &'a Inventory,
+    inventory_item_id_required: Option<&'a str>,
+    location_id_required: Option<&'a str>,
+    available_adjustment_required: Option<&'a str>,
+    body: &'a serde_json::Value,
+}
+
+impl<'a> InventoryDeprecatedUnstableCreateLevelsAdjustRequest<'a> {
+    /// Sets the `inventory_item_id required` query parameter.
+    pub fn inventory_item_id_required(mut self, inventory_item_id_required: &'a str) -> Self {
+        self.inventory_item_id_required = Some(inventory_item_id_required);
+        self
+    }
+
+    /// Sets the `location_id required` query parameter.
+    pub fn location_id_required(mut self, location_id_required: &'a str) -> Self {
+        self.location_id_required = Some(location_id_required);
+        self
+    }
+
+    /// Sets the `available_adjustment required` query parameter.
+    pub fn available_adjustment_required(mut self, available_adjustment_required: &'a str) -> Self {
+        self.available_adjustment_required = Some(available_adjustment_required);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            inventory_item_id_required,
+            location_id_required,
+            available_adjustment_required,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = available_adjustment_required {
+            query_args.push((
+                "available_adjustment
+                  required"
+                    .to_string(),
+                v.to_string(),
+            ));
+        }
+        if let Some(v) = inventory_item_id_required {
+            query_args.push((
+                "inventory_item_id
+                  required"
+                    .to_string(),
+                v.to_string(),
+            ));
+        }
+        if let Some(v) = location_id_required {
+            query_args.push((
+                "location_id
+                  required"
+                    .to_string(),
+                v.to_string(),
+            ));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/unstable/inventory_levels/adjust.json?{}",
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Inventory::deprecated_unstable_create_levels_connect`], set with a builder.
+pub struct InventoryDeprecatedUnstableCreateLevelsConnectRequest<'a> {
+    client: &'a Inventory,
+    inventory_item_id_required: Option<&'a str>,
+    location_id_required: Option<&'a str>,
+    relocate_if_necessary: Option<&'a str>,
+    body: &'a serde_json::Value,
+}
+
+impl<'a> InventoryDeprecatedUnstableCreateLevelsConnectRequest<'a> {
+    /// Sets the `inventory_item_id required` query parameter.
+    pub fn inventory_item_id_required(mut self, inventory_item_id_required: &'a str) -> Self {
+        self.inventory_item_id_required = Some(inventory_item_id_required);
+        self
+    }
+
+    /// Sets the `location_id required` query parameter.
+    pub fn location_id_required(mut self, location_id_required: &'a str) -> Self {
+        self.location_id_required = Some(location_id_required);
+        self
+    }
+
+    /// Sets the `relocate_if_necessary` query parameter.
+    pub fn relocate_if_necessary(mut self, relocate_if_necessary: &'a str) -> Self {
+        self.relocate_if_necessary = Some(relocate_if_necessary);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            inventory_item_id_required,
+            location_id_required,
+            relocate_if_necessary,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = inventory_item_id_required {
+            query_args.push((
+                "inventory_item_id
+                  required"
+                    .to_string(),
+                v.to_string(),
+            ));
+        }
+        if let Some(v) = location_id_required {
+            query_args.push((
+                "location_id
+                  required"
+                    .to_string(),
+                v.to_string(),
+            ));
+        }
+        if let Some(v) = relocate_if_necessary {
+            query_args.push(("relocate_if_necessary".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/unstable/inventory_levels/connect.json?{}",
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Inventory::deprecated_unstable_create_levels_set`], set with a builder.
+pub struct InventoryDeprecatedUnstableCreateLevelsSetRequest<'a> {
+    client: &'a Inventory,
+    inventory_item_id_required: Option<&'a str>,
+    location_id_required: Option<&'a str>,
+    available_required: Option<&'a str>,
+    disconnect_if_necessary: Option<&'a str>,
+    body: &'a serde_json::Value,
+}
+
+impl<'a> InventoryDeprecatedUnstableCreateLevelsSetRequest<'a> {
+    /// Sets the `inventory_item_id required` query parameter.
+    pub fn inventory_item_id_required(mut self, inventory_item_id_required: &'a str) -> Self {
+        self.inventory_item_id_required = Some(inventory_item_id_required);
+        self
+    }
+
+    /// Sets the `location_id required` query parameter.
+    pub fn location_id_required(mut self, location_id_required: &'a str) -> Self {
+        self.location_id_required = Some(location_id_required);
+        self
+    }
+
+    /// Sets the `available required` query parameter.
+    pub fn available_required(mut self, available_required: &'a str) -> Self {
+        self.available_required = Some(available_required);
+        self
+    }
+
+    /// Sets the `disconnect_if_necessary` query parameter.
+    pub fn disconnect_if_necessary(mut self, disconnect_if_necessary: &'a str) -> Self {
+        self.disconnect_if_necessary = Some(disconnect_if_necessary);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            inventory_item_id_required,
+            location_id_required,
+            available_required,
+            disconnect_if_necessary,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = available_required {
+            query_args.push((
+                "available
+                  required"
+                    .to_string(),
+                v.to_string(),
+            ));
+        }
+        if let Some(v) = disconnect_if_necessary {
+            query_args.push(("disconnect_if_necessary".to_string(), v.to_string()));
+        }
+        if let Some(v) = inventory_item_id_required {
+            query_args.push((
+                "inventory_item_id
+                  required"
+                    .to_string(),
+                v.to_string(),
+            ));
+        }
+        if let Some(v) = location_id_required {
+            query_args.push((
+                "location_id
+                  required"
+                    .to_string(),
+                v.to_string(),
+            ));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/unstable/inventory_levels/set.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
 pub struct Inventory {
     pub client: Client,
 }
@@ -62,6 +2951,25 @@ impl Inventory {
             )
             .await
     }
+    /**
+     * Retrieves a list of inventory items. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-01/inventory_items.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_get_item`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/inventory/inventoryitem#index-2020-01
+     */
+    pub fn deprecated_202001_get_item_request(
+        &self,
+    ) -> InventoryDeprecated202001GetItemRequest<'_> {
+        InventoryDeprecated202001GetItemRequest {
+            client: self,
+            ids_required: None,
+            limit: None,
+            ids: None,
+        }
+    }
     /**
      * Retrieves a single inventory item by ID.
      *
@@ -178,6 +3086,25 @@ impl Inventory {
             )
             .await
     }
+    /**
+     * Retrieves a list of inventory items. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-04/inventory_items.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_get_item`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/inventory/inventoryitem#index-2020-04
+     */
+    pub fn deprecated_202004_get_item_request(
+        &self,
+    ) -> InventoryDeprecated202004GetItemRequest<'_> {
+        InventoryDeprecated202004GetItemRequest {
+            client: self,
+            ids_required: None,
+            limit: None,
+            ids: None,
+        }
+    }
     /**
      * Retrieves a single inventory item by ID.
      *
@@ -294,6 +3221,25 @@ impl Inventory {
             )
             .await
     }
+    /**
+     * Retrieves a list of inventory items. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-07/inventory_items.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_get_item`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/inventory/inventoryitem#index-2020-07
+     */
+    pub fn deprecated_202007_get_item_request(
+        &self,
+    ) -> InventoryDeprecated202007GetItemRequest<'_> {
+        InventoryDeprecated202007GetItemRequest {
+            client: self,
+            ids_required: None,
+            limit: None,
+            ids: None,
+        }
+    }
     /**
      * Retrieves a single inventory item by ID.
      *
@@ -410,6 +3356,23 @@ impl Inventory {
             )
             .await
     }
+    /**
+     * Retrieves a list of inventory items. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-10/inventory_items.json` endpoint.
+     *
+     * As opposed to `get_item`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/inventory/inventoryitem#index-2020-10
+     */
+    pub fn get_item_request(&self) -> InventoryGetItemRequest<'_> {
+        InventoryGetItemRequest {
+            client: self,
+            ids_required: None,
+            limit: None,
+            ids: None,
+        }
+    }
     /**
      * Retrieves a single inventory item by ID.
      *
@@ -526,6 +3489,25 @@ impl Inventory {
             )
             .await
     }
+    /**
+     * Retrieves a list of inventory items. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2021-01/inventory_items.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_get_item`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/inventory/inventoryitem#index-2021-01
+     */
+    pub fn deprecated_202101_get_item_request(
+        &self,
+    ) -> InventoryDeprecated202101GetItemRequest<'_> {
+        InventoryDeprecated202101GetItemRequest {
+            client: self,
+            ids_required: None,
+            limit: None,
+            ids: None,
+        }
+    }
     /**
      * Retrieves a single inventory item by ID.
      *
@@ -642,6 +3624,25 @@ impl Inventory {
             )
             .await
     }
+    /**
+     * Retrieves a list of inventory items. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/unstable/inventory_items.json` endpoint.
+     *
+     * As opposed to `deprecated_unstable_get_item`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/inventory/inventoryitem#index-unstable
+     */
+    pub fn deprecated_unstable_get_item_request(
+        &self,
+    ) -> InventoryDeprecatedUnstableGetItemRequest<'_> {
+        InventoryDeprecatedUnstableGetItemRequest {
+            client: self,
+            ids_required: None,
+            limit: None,
+            ids: None,
+        }
+    }
     /**
      * Retrieves a single inventory item by ID.
      *
@@ -765,6 +3766,28 @@ impl Inventory {
             .await
     }
     /**
+    * Retrieves a list of inventory levels.
+             You must include inventory_item_ids, location_ids, or both as filter parameters.
+             Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+    *
+    * This function performs a `GET` to the `/admin/api/2020-01/inventory_levels.json` endpoint.
+    *
+    * As opposed to `deprecated_202001_get_level`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+    *
+    * https://shopify.dev/docs/admin-api/rest/reference/inventory/inventorylevel#index-2020-01
+    */
+    pub fn deprecated_202001_get_level_request(
+        &self,
+    ) -> InventoryDeprecated202001GetLevelRequest<'_> {
+        InventoryDeprecated202001GetLevelRequest {
+            client: self,
+            inventory_item_ids: None,
+            location_ids: None,
+            limit: None,
+            updated_at_min: None,
+        }
+    }
+    /**
     * Deletes an inventory level of an inventory item at a location.
              Deleting an inventory level for an inventory item removes that item from the specified location.
              Every inventory item must have at least one inventory level. To move inventory to another location,
@@ -829,6 +3852,29 @@ impl Inventory {
             )
             .await
     }
+    /**
+    * Deletes an inventory level of an inventory item at a location.
+             Deleting an inventory level for an inventory item removes that item from the specified location.
+             Every inventory item must have at least one inventory level. To move inventory to another location,
+             first connect the inventory item to another location, and then delete the previous inventory level.
+    *
+    * This function performs a `DELETE` to the `/admin/api/2020-01/inventory_levels.json` endpoint.
+    *
+    * As opposed to `deprecated_202001_delete_levels`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+    *
+    * https://shopify.dev/docs/admin-api/rest/reference/inventory/inventorylevel#destroy-2020-01
+    */
+    pub fn deprecated_202001_delete_levels_request(
+        &self,
+    ) -> InventoryDeprecated202001DeleteLevelsRequest<'_> {
+        InventoryDeprecated202001DeleteLevelsRequest {
+            client: self,
+            inventory_item_id_required: None,
+            location_id_required: None,
+            inventory_item_id: None,
+            location_id: None,
+        }
+    }
     /**
      * Adjusts the inventory level of an inventory item at a single location.
      *
@@ -889,6 +3935,27 @@ impl Inventory {
             )
             .await
     }
+    /**
+     * Adjusts the inventory level of an inventory item at a single location.
+     *
+     * This function performs a `POST` to the `/admin/api/2020-01/inventory_levels/adjust.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_create_levels_adjust`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/inventory/inventorylevel#adjust-2020-01
+     */
+    pub fn deprecated_202001_create_levels_adjust_request<'a>(
+        &'a self,
+        body: &'a serde_json::Value,
+    ) -> InventoryDeprecated202001CreateLevelsAdjustRequest<'a> {
+        InventoryDeprecated202001CreateLevelsAdjustRequest {
+            client: self,
+            inventory_item_id_required: None,
+            location_id_required: None,
+            available_adjustment_required: None,
+            body,
+        }
+    }
     /**
     * Connects an inventory item to a location by creating an inventory level at that location.
            When connecting inventory items to locations, it's important to understand the rules around
@@ -954,6 +4021,29 @@ impl Inventory {
             .await
     }
     /**
+    * Connects an inventory item to a location by creating an inventory level at that location.
+           When connecting inventory items to locations, it's important to understand the rules around
+           fulfillment service locations.
+    *
+    * This function performs a `POST` to the `/admin/api/2020-01/inventory_levels/connect.json` endpoint.
+    *
+    * As opposed to `deprecated_202001_create_levels_connect`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+    *
+    * https://shopify.dev/docs/admin-api/rest/reference/inventory/inventorylevel#connect-2020-01
+    */
+    pub fn deprecated_202001_create_levels_connect_request<'a>(
+        &'a self,
+        body: &'a serde_json::Value,
+    ) -> InventoryDeprecated202001CreateLevelsConnectRequest<'a> {
+        InventoryDeprecated202001CreateLevelsConnectRequest {
+            client: self,
+            inventory_item_id_required: None,
+            location_id_required: None,
+            relocate_if_necessary: None,
+            body,
+        }
+    }
+    /**
     * Sets the inventory level for an inventory item at a location.
              If the specified location is not connected, it will be automatically connected first.
              When connecting inventory items to locations, it's important to understand the rules around
@@ -1026,6 +4116,31 @@ impl Inventory {
             .await
     }
     /**
+    * Sets the inventory level for an inventory item at a location.
+             If the specified location is not connected, it will be automatically connected first.
+             When connecting inventory items to locations, it's important to understand the rules around
+             fulfillment service locations.
+    *
+    * This function performs a `POST` to the `/admin/api/2020-01/inventory_levels/set.json` endpoint.
+    *
+    * As opposed to `deprecated_202001_create_levels_set`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+    *
+    * https://shopify.dev/docs/admin-api/rest/reference/inventory/inventorylevel#set-2020-01
+    */
+    pub fn deprecated_202001_create_levels_set_request<'a>(
+        &'a self,
+        body: &'a serde_json::Value,
+    ) -> InventoryDeprecated202001CreateLevelsSetRequest<'a> {
+        InventoryDeprecated202001CreateLevelsSetRequest {
+            client: self,
+            inventory_item_id_required: None,
+            location_id_required: None,
+            available_required: None,
+            disconnect_if_necessary: None,
+            body,
+        }
+    }
+    /**
     * Retrieves a list of inventory levels.
              You must include inventory_item_ids, location_ids, or both as filter parameters.
              Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
@@ -1083,6 +4198,28 @@ impl Inventory {
             .await
     }
     /**
+    * Retrieves a list of inventory levels.
+             You must include inventory_item_ids, location_ids, or both as filter parameters.
+             Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+    *
+    * This function performs a `GET` to the `/admin/api/2020-04/inventory_levels.json` endpoint.
+    *
+    * As opposed to `deprecated_202004_get_level`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+    *
+    * https://shopify.dev/docs/admin-api/rest/reference/inventory/inventorylevel#index-2020-04
+    */
+    pub fn deprecated_202004_get_level_request(
+        &self,
+    ) -> InventoryDeprecated202004GetLevelRequest<'_> {
+        InventoryDeprecated202004GetLevelRequest {
+            client: self,
+            inventory_item_ids: None,
+            location_ids: None,
+            limit: None,
+            updated_at_min: None,
+        }
+    }
+    /**
     * Deletes an inventory level of an inventory item at a location.
              Deleting an inventory level for an inventory item removes that item from the specified location.
              Every inventory item must have at least one inventory level. To move inventory to another location,
@@ -1147,6 +4284,29 @@ impl Inventory {
             )
             .await
     }
+    /**
+    * Deletes an inventory level of an inventory item at a location.
+             Deleting an inventory level for an inventory item removes that item from the specified location.
+             Every inventory item must have at least one inventory level. To move inventory to another location,
+             first connect the inventory item to another location, and then delete the previous inventory level.
+    *
+    * This function performs a `DELETE` to the `/admin/api/2020-04/inventory_levels.json` endpoint.
+    *
+    * As opposed to `deprecated_202004_delete_levels`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+    *
+    * https://shopify.dev/docs/admin-api/rest/reference/inventory/inventorylevel#destroy-2020-04
+    */
+    pub fn deprecated_202004_delete_levels_request(
+        &self,
+    ) -> InventoryDeprecated202004DeleteLevelsRequest<'_> {
+        InventoryDeprecated202004DeleteLevelsRequest {
+            client: self,
+            inventory_item_id_required: None,
+            location_id_required: None,
+            inventory_item_id: None,
+            location_id: None,
+        }
+    }
     /**
      * Adjusts the inventory level of an inventory item at a single location.
      *
@@ -1207,6 +4367,27 @@ impl Inventory {
             )
             .await
     }
+    /**
+     * Adjusts the inventory level of an inventory item at a single location.
+     *
+     * This function performs a `POST` to the `/admin/api/2020-04/inventory_levels/adjust.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_create_levels_adjust`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/inventory/inventorylevel#adjust-2020-04
+     */
+    pub fn deprecated_202004_create_levels_adjust_request<'a>(
+        &'a self,
+        body: &'a serde_json::Value,
+    ) -> InventoryDeprecated202004CreateLevelsAdjustRequest<'a> {
+        InventoryDeprecated202004CreateLevelsAdjustRequest {
+            client: self,
+            inventory_item_id_required: None,
+            location_id_required: None,
+            available_adjustment_required: None,
+            body,
+        }
+    }
     /**
     * Connects an inventory item to a location by creating an inventory level at that location.
            When connecting inventory items to locations, it's important to understand the rules around
@@ -1272,6 +4453,29 @@ impl Inventory {
             .await
     }
     /**
+    * Connects an inventory item to a location by creating an inventory level at that location.
+           When connecting inventory items to locations, it's important to understand the rules around
+           fulfillment service locations.
+    *
+    * This function performs a `POST` to the `/admin/api/2020-04/inventory_levels/connect.json` endpoint.
+    *
+    * As opposed to `deprecated_202004_create_levels_connect`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+    *
+    * https://shopify.dev/docs/admin-api/rest/reference/inventory/inventorylevel#connect-2020-04
+    */
+    pub fn deprecated_202004_create_levels_connect_request<'a>(
+        &'a self,
+        body: &'a serde_json::Value,
+    ) -> InventoryDeprecated202004CreateLevelsConnectRequest<'a> {
+        InventoryDeprecated202004CreateLevelsConnectRequest {
+            client: self,
+            inventory_item_id_required: None,
+            location_id_required: None,
+            relocate_if_necessary: None,
+            body,
+        }
+    }
+    /**
     * Sets the inventory level for an inventory item at a location.
              If the specified location is not connected, it will be automatically connected first.
              When connecting inventory items to locations, it's important to understand the rules around
@@ -1344,6 +4548,31 @@ impl Inventory {
             .await
     }
     /**
+    * Sets the inventory level for an inventory item at a location.
+             If the specified location is not connected, it will be automatically connected first.
+             When connecting inventory items to locations, it's important to understand the rules around
+             fulfillment service locations.
+    *
+    * This function performs a `POST` to the `/admin/api/2020-04/inventory_levels/set.json` endpoint.
+    *
+    * As opposed to `deprecated_202004_create_levels_set`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+    *
+    * https://shopify.dev/docs/admin-api/rest/reference/inventory/inventorylevel#set-2020-04
+    */
+    pub fn deprecated_202004_create_levels_set_request<'a>(
+        &'a self,
+        body: &'a serde_json::Value,
+    ) -> InventoryDeprecated202004CreateLevelsSetRequest<'a> {
+        InventoryDeprecated202004CreateLevelsSetRequest {
+            client: self,
+            inventory_item_id_required: None,
+            location_id_required: None,
+            available_required: None,
+            disconnect_if_necessary: None,
+            body,
+        }
+    }
+    /**
     * Retrieves a list of inventory levels.
              You must include inventory_item_ids, location_ids, or both as filter parameters.
              Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
@@ -1401,6 +4630,28 @@ impl Inventory {
             .await
     }
     /**
+    * Retrieves a list of inventory levels.
+             You must include inventory_item_ids, location_ids, or both as filter parameters.
+             Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+    *
+    * This function performs a `GET` to the `/admin/api/2020-07/inventory_levels.json` endpoint.
+    *
+    * As opposed to `deprecated_202007_get_level`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+    *
+    * https://shopify.dev/docs/admin-api/rest/reference/inventory/inventorylevel#index-2020-07
+    */
+    pub fn deprecated_202007_get_level_request(
+        &self,
+    ) -> InventoryDeprecated202007GetLevelRequest<'_> {
+        InventoryDeprecated202007GetLevelRequest {
+            client: self,
+            inventory_item_ids: None,
+            location_ids: None,
+            limit: None,
+            updated_at_min: None,
+        }
+    }
+    /**
     * Deletes an inventory level of an inventory item at a location.
              Deleting an inventory level for an inventory item removes that item from the specified location.
              Every inventory item must have at least one inventory level. To move inventory to another location,
@@ -1465,6 +4716,29 @@ impl Inventory {
             )
             .await
     }
+    /**
+    * Deletes an inventory level of an inventory item at a location.
+             Deleting an inventory level for an inventory item removes that item from the specified location.
+             Every inventory item must have at least one inventory level. To move inventory to another location,
+             first connect the inventory item to another location, and then delete the previous inventory level.
+    *
+    * This function performs a `DELETE` to the `/admin/api/2020-07/inventory_levels.json` endpoint.
+    *
+    * As opposed to `deprecated_202007_delete_levels`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+    *
+    * https://shopify.dev/docs/admin-api/rest/reference/inventory/inventorylevel#destroy-2020-07
+    */
+    pub fn deprecated_202007_delete_levels_request(
+        &self,
+    ) -> InventoryDeprecated202007DeleteLevelsRequest<'_> {
+        InventoryDeprecated202007DeleteLevelsRequest {
+            client: self,
+            inventory_item_id_required: None,
+            location_id_required: None,
+            inventory_item_id: None,
+            location_id: None,
+        }
+    }
     /**
      * Adjusts the inventory level of an inventory item at a single location.
      *
@@ -1525,6 +4799,27 @@ impl Inventory {
             )
             .await
     }
+    /**
+     * Adjusts the inventory level of an inventory item at a single location.
+     *
+     * This function performs a `POST` to the `/admin/api/2020-07/inventory_levels/adjust.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_create_levels_adjust`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/inventory/inventorylevel#adjust-2020-07
+     */
+    pub fn deprecated_202007_create_levels_adjust_request<'a>(
+        &'a self,
+        body: &'a serde_json::Value,
+    ) -> InventoryDeprecated202007CreateLevelsAdjustRequest<'a> {
+        InventoryDeprecated202007CreateLevelsAdjustRequest {
+            client: self,
+            inventory_item_id_required: None,
+            location_id_required: None,
+            available_adjustment_required: None,
+            body,
+        }
+    }
     /**
     * Connects an inventory item to a location by creating an inventory level at that location.
            When connecting inventory items to locations, it's important to understand the rules around
@@ -1590,6 +4885,29 @@ impl Inventory {
             .await
     }
     /**
+    * Connects an inventory item to a location by creating an inventory level at that location.
+           When connecting inventory items to locations, it's important to understand the rules around
+           fulfillment service locations.
+    *
+    * This function performs a `POST` to the `/admin/api/2020-07/inventory_levels/connect.json` endpoint.
+    *
+    * As opposed to `deprecated_202007_create_levels_connect`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+    *
+    * https://shopify.dev/docs/admin-api/rest/reference/inventory/inventorylevel#connect-2020-07
+    */
+    pub fn deprecated_202007_create_levels_connect_request<'a>(
+        &'a self,
+        body: &'a serde_json::Value,
+    ) -> InventoryDeprecated202007CreateLevelsConnectRequest<'a> {
+        InventoryDeprecated202007CreateLevelsConnectRequest {
+            client: self,
+            inventory_item_id_required: None,
+            location_id_required: None,
+            relocate_if_necessary: None,
+            body,
+        }
+    }
+    /**
     * Sets the inventory level for an inventory item at a location.
              If the specified location is not connected, it will be automatically connected first.
              When connecting inventory items to locations, it's important to understand the rules around
@@ -1662,6 +4980,31 @@ impl Inventory {
             .await
     }
     /**
+    * Sets the inventory level for an inventory item at a location.
+             If the specified location is not connected, it will be automatically connected first.
+             When connecting inventory items to locations, it's important to understand the rules around
+             fulfillment service locations.
+    *
+    * This function performs a `POST` to the `/admin/api/2020-07/inventory_levels/set.json` endpoint.
+    *
+    * As opposed to `deprecated_202007_create_levels_set`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+    *
+    * https://shopify.dev/docs/admin-api/rest/reference/inventory/inventorylevel#set-2020-07
+    */
+    pub fn deprecated_202007_create_levels_set_request<'a>(
+        &'a self,
+        body: &'a serde_json::Value,
+    ) -> InventoryDeprecated202007CreateLevelsSetRequest<'a> {
+        InventoryDeprecated202007CreateLevelsSetRequest {
+            client: self,
+            inventory_item_id_required: None,
+            location_id_required: None,
+            available_required: None,
+            disconnect_if_necessary: None,
+            body,
+        }
+    }
+    /**
     * Retrieves a list of inventory levels.
              You must include inventory_item_ids, location_ids, or both as filter parameters.
              Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
@@ -1719,6 +5062,26 @@ impl Inventory {
             .await
     }
     /**
+    * Retrieves a list of inventory levels.
+             You must include inventory_item_ids, location_ids, or both as filter parameters.
+             Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+    *
+    * This function performs a `GET` to the `/admin/api/2020-10/inventory_levels.json` endpoint.
+    *
+    * As opposed to `get_level`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+    *
+    * https://shopify.dev/docs/admin-api/rest/reference/inventory/inventorylevel#index-2020-10
+    */
+    pub fn get_level_request(&self) -> InventoryGetLevelRequest<'_> {
+        InventoryGetLevelRequest {
+            client: self,
+            inventory_item_ids: None,
+            location_ids: None,
+            limit: None,
+            updated_at_min: None,
+        }
+    }
+    /**
     * Deletes an inventory level of an inventory item at a location.
              Deleting an inventory level for an inventory item removes that item from the specified location.
              Every inventory item must have at least one inventory level. To move inventory to another location,
@@ -1783,6 +5146,27 @@ impl Inventory {
             )
             .await
     }
+    /**
+    * Deletes an inventory level of an inventory item at a location.
+             Deleting an inventory level for an inventory item removes that item from the specified location.
+             Every inventory item must have at least one inventory level. To move inventory to another location,
+             first connect the inventory item to another location, and then delete the previous inventory level.
+    *
+    * This function performs a `DELETE` to the `/admin/api/2020-10/inventory_levels.json` endpoint.
+    *
+    * As opposed to `delete_levels`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+    *
+    * https://shopify.dev/docs/admin-api/rest/reference/inventory/inventorylevel#destroy-2020-10
+    */
+    pub fn delete_levels_request(&self) -> InventoryDeleteLevelsRequest<'_> {
+        InventoryDeleteLevelsRequest {
+            client: self,
+            inventory_item_id_required: None,
+            location_id_required: None,
+            inventory_item_id: None,
+            location_id: None,
+        }
+    }
     /**
      * Adjusts the inventory level of an inventory item at a single location.
      *
@@ -1843,6 +5227,27 @@ impl Inventory {
             )
             .await
     }
+    /**
+     * Adjusts the inventory level of an inventory item at a single location.
+     *
+     * This function performs a `POST` to the `/admin/api/2020-10/inventory_levels/adjust.json` endpoint.
+     *
+     * As opposed to `create_levels_adjust`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/inventory/inventorylevel#adjust-2020-10
+     */
+    pub fn create_levels_adjust_request<'a>(
+        &'a self,
+        body: &'a serde_json::Value,
+    ) -> InventoryCreateLevelsAdjustRequest<'a> {
+        InventoryCreateLevelsAdjustRequest {
+            client: self,
+            inventory_item_id_required: None,
+            location_id_required: None,
+            available_adjustment_required: None,
+            body,
+        }
+    }
     /**
     * Connects an inventory item to a location by creating an inventory level at that location.
            When connecting inventory items to locations, it's important to understand the rules around
@@ -1908,6 +5313,29 @@ impl Inventory {
             .await
     }
     /**
+    * Connects an inventory item to a location by creating an inventory level at that location.
+           When connecting inventory items to locations, it's important to understand the rules around
+           fulfillment service locations.
+    *
+    * This function performs a `POST` to the `/admin/api/2020-10/inventory_levels/connect.json` endpoint.
+    *
+    * As opposed to `create_levels_connect`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+    *
+    * https://shopify.dev/docs/admin-api/rest/reference/inventory/inventorylevel#connect-2020-10
+    */
+    pub fn create_levels_connect_request<'a>(
+        &'a self,
+        body: &'a serde_json::Value,
+    ) -> InventoryCreateLevelsConnectRequest<'a> {
+        InventoryCreateLevelsConnectRequest {
+            client: self,
+            inventory_item_id_required: None,
+            location_id_required: None,
+            relocate_if_necessary: None,
+            body,
+        }
+    }
+    /**
     * Sets the inventory level for an inventory item at a location.
              If the specified location is not connected, it will be automatically connected first.
              When connecting inventory items to locations, it's important to understand the rules around
@@ -1980,6 +5408,31 @@ impl Inventory {
             .await
     }
     /**
+    * Sets the inventory level for an inventory item at a location.
+             If the specified location is not connected, it will be automatically connected first.
+             When connecting inventory items to locations, it's important to understand the rules around
+             fulfillment service locations.
+    *
+    * This function performs a `POST` to the `/admin/api/2020-10/inventory_levels/set.json` endpoint.
+    *
+    * As opposed to `create_levels_set`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+    *
+    * https://shopify.dev/docs/admin-api/rest/reference/inventory/inventorylevel#set-2020-10
+    */
+    pub fn create_levels_set_request<'a>(
+        &'a self,
+        body: &'a serde_json::Value,
+    ) -> InventoryCreateLevelsSetRequest<'a> {
+        InventoryCreateLevelsSetRequest {
+            client: self,
+            inventory_item_id_required: None,
+            location_id_required: None,
+            available_required: None,
+            disconnect_if_necessary: None,
+            body,
+        }
+    }
+    /**
     * Retrieves a list of inventory levels.
              You must include inventory_item_ids, location_ids, or both as filter parameters.
              Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
@@ -2037,6 +5490,28 @@ impl Inventory {
             .await
     }
     /**
+    * Retrieves a list of inventory levels.
+             You must include inventory_item_ids, location_ids, or both as filter parameters.
+             Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+    *
+    * This function performs a `GET` to the `/admin/api/2021-01/inventory_levels.json` endpoint.
+    *
+    * As opposed to `deprecated_202101_get_level`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+    *
+    * https://shopify.dev/docs/admin-api/rest/reference/inventory/inventorylevel#index-2021-01
+    */
+    pub fn deprecated_202101_get_level_request(
+        &self,
+    ) -> InventoryDeprecated202101GetLevelRequest<'_> {
+        InventoryDeprecated202101GetLevelRequest {
+            client: self,
+            inventory_item_ids: None,
+            location_ids: None,
+            limit: None,
+            updated_at_min: None,
+        }
+    }
+    /**
     * Deletes an inventory level of an inventory item at a location.
              Deleting an inventory level for an inventory item removes that item from the specified location.
              Every inventory item must have at least one inventory level. To move inventory to another location,
@@ -2101,6 +5576,29 @@ impl Inventory {
             )
             .await
     }
+    /**
+    * Deletes an inventory level of an inventory item at a location.
+             Deleting an inventory level for an inventory item removes that item from the specified location.
+             Every inventory item must have at least one inventory level. To move inventory to another location,
+             first connect the inventory item to another location, and then delete the previous inventory level.
+    *
+    * This function performs a `DELETE` to the `/admin/api/2021-01/inventory_levels.json` endpoint.
+    *
+    * As opposed to `deprecated_202101_delete_levels`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+    *
+    * https://shopify.dev/docs/admin-api/rest/reference/inventory/inventorylevel#destroy-2021-01
+    */
+    pub fn deprecated_202101_delete_levels_request(
+        &self,
+    ) -> InventoryDeprecated202101DeleteLevelsRequest<'_> {
+        InventoryDeprecated202101DeleteLevelsRequest {
+            client: self,
+            inventory_item_id_required: None,
+            location_id_required: None,
+            inventory_item_id: None,
+            location_id: None,
+        }
+    }
     /**
      * Adjusts the inventory level of an inventory item at a single location.
      *
@@ -2161,6 +5659,27 @@ impl Inventory {
             )
             .await
     }
+    /**
+     * Adjusts the inventory level of an inventory item at a single location.
+     *
+     * This function performs a `POST` to the `/admin/api/2021-01/inventory_levels/adjust.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_create_levels_adjust`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/inventory/inventorylevel#adjust-2021-01
+     */
+    pub fn deprecated_202101_create_levels_adjust_request<'a>(
+        &'a self,
+        body: &'a serde_json::Value,
+    ) -> InventoryDeprecated202101CreateLevelsAdjustRequest<'a> {
+        InventoryDeprecated202101CreateLevelsAdjustRequest {
+            client: self,
+            inventory_item_id_required: None,
+            location_id_required: None,
+            available_adjustment_required: None,
+            body,
+        }
+    }
     /**
     * Connects an inventory item to a location by creating an inventory level at that location.
            When connecting inventory items to locations, it's important to understand the rules around
@@ -2226,6 +5745,29 @@ impl Inventory {
             .await
     }
     /**
+    * Connects an inventory item to a location by creating an inventory level at that location.
+           When connecting inventory items to locations, it's important to understand the rules around
+           fulfillment service locations.
+    *
+    * This function performs a `POST` to the `/admin/api/2021-01/inventory_levels/connect.json` endpoint.
+    *
+    * As opposed to `deprecated_202101_create_levels_connect`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+    *
+    * https://shopify.dev/docs/admin-api/rest/reference/inventory/inventorylevel#connect-2021-01
+    */
+    pub fn deprecated_202101_create_levels_connect_request<'a>(
+        &'a self,
+        body: &'a serde_json::Value,
+    ) -> InventoryDeprecated202101CreateLevelsConnectRequest<'a> {
+        InventoryDeprecated202101CreateLevelsConnectRequest {
+            client: self,
+            inventory_item_id_required: None,
+            location_id_required: None,
+            relocate_if_necessary: None,
+            body,
+        }
+    }
+    /**
     * Sets the inventory level for an inventory item at a location.
              If the specified location is not connected, it will be automatically connected first.
              When connecting inventory items to locations, it's important to understand the rules around
@@ -2298,6 +5840,31 @@ impl Inventory {
             .await
     }
     /**
+    * Sets the inventory level for an inventory item at a location.
+             If the specified location is not connected, it will be automatically connected first.
+             When connecting inventory items to locations, it's important to understand the rules around
+             fulfillment service locations.
+    *
+    * This function performs a `POST` to the `/admin/api/2021-01/inventory_levels/set.json` endpoint.
+    *
+    * As opposed to `deprecated_202101_create_levels_set`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+    *
+    * https://shopify.dev/docs/admin-api/rest/reference/inventory/inventorylevel#set-2021-01
+    */
+    pub fn deprecated_202101_create_levels_set_request<'a>(
+        &'a self,
+        body: &'a serde_json::Value,
+    ) -> InventoryDeprecated202101CreateLevelsSetRequest<'a> {
+        InventoryDeprecated202101CreateLevelsSetRequest {
+            client: self,
+            inventory_item_id_required: None,
+            location_id_required: None,
+            available_required: None,
+            disconnect_if_necessary: None,
+            body,
+        }
+    }
+    /**
     * Retrieves a list of inventory levels.
              You must include inventory_item_ids, location_ids, or both as filter parameters.
              Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
@@ -2355,6 +5922,28 @@ impl Inventory {
             .await
     }
     /**
+    * Retrieves a list of inventory levels.
+             You must include inventory_item_ids, location_ids, or both as filter parameters.
+             Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+    *
+    * This function performs a `GET` to the `/admin/api/unstable/inventory_levels.json` endpoint.
+    *
+    * As opposed to `deprecated_unstable_get_level`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+    *
+    * https://shopify.dev/docs/admin-api/rest/reference/inventory/inventorylevel#index-unstable
+    */
+    pub fn deprecated_unstable_get_level_request(
+        &self,
+    ) -> InventoryDeprecatedUnstableGetLevelRequest<'_> {
+        InventoryDeprecatedUnstableGetLevelRequest {
+            client: self,
+            inventory_item_ids: None,
+            location_ids: None,
+            limit: None,
+            updated_at_min: None,
+        }
+    }
+    /**
     * Deletes an inventory level of an inventory item at a location.
              Deleting an inventory level for an inventory item removes that item from the specified location.
              Every inventory item must have at least one inventory level. To move inventory to another location,
@@ -2419,6 +6008,29 @@ impl Inventory {
             )
             .await
     }
+    /**
+    * Deletes an inventory level of an inventory item at a location.
+             Deleting an inventory level for an inventory item removes that item from the specified location.
+             Every inventory item must have at least one inventory level. To move inventory to another location,
+             first connect the inventory item to another location, and then delete the previous inventory level.
+    *
+    * This function performs a `DELETE` to the `/admin/api/unstable/inventory_levels.json` endpoint.
+    *
+    * As opposed to `deprecated_unstable_delete_levels`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+    *
+    * https://shopify.dev/docs/admin-api/rest/reference/inventory/inventorylevel#destroy-unstable
+    */
+    pub fn deprecated_unstable_delete_levels_request(
+        &self,
+    ) -> InventoryDeprecatedUnstableDeleteLevelsRequest<'_> {
+        InventoryDeprecatedUnstableDeleteLevelsRequest {
+            client: self,
+            inventory_item_id_required: None,
+            location_id_required: None,
+            inventory_item_id: None,
+            location_id: None,
+        }
+    }
     /**
      * Adjusts the inventory level of an inventory item at a single location.
      *
@@ -2482,6 +6094,27 @@ impl Inventory {
             )
             .await
     }
+    /**
+     * Adjusts the inventory level of an inventory item at a single location.
+     *
+     * This function performs a `POST` to the `/admin/api/unstable/inventory_levels/adjust.json` endpoint.
+     *
+     * As opposed to `deprecated_unstable_create_levels_adjust`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/inventory/inventorylevel#adjust-unstable
+     */
+    pub fn deprecated_unstable_create_levels_adjust_request<'a>(
+        &'a self,
+        body: &'a serde_json::Value,
+    ) -> InventoryDeprecatedUnstableCreateLevelsAdjustRequest<'a> {
+        InventoryDeprecatedUnstableCreateLevelsAdjustRequest {
+            client: self,
+            inventory_item_id_required: None,
+            location_id_required: None,
+            available_adjustment_required: None,
+            body,
+        }
+    }
     /**
     * Connects an inventory item to a location by creating an inventory level at that location.
            When connecting inventory items to locations, it's important to understand the rules around
@@ -2547,6 +6180,29 @@ impl Inventory {
             .await
     }
     /**
+    * Connects an inventory item to a location by creating an inventory level at that location.
+           When connecting inventory items to locations, it's important to understand the rules around
+           fulfillment service locations.
+    *
+    * This function performs a `POST` to the `/admin/api/unstable/inventory_levels/connect.json` endpoint.
+    *
+    * As opposed to `deprecated_unstable_create_levels_connect`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+    *
+    * https://shopify.dev/docs/admin-api/rest/reference/inventory/inventorylevel#connect-unstable
+    */
+    pub fn deprecated_unstable_create_levels_connect_request<'a>(
+        &'a self,
+        body: &'a serde_json::Value,
+    ) -> InventoryDeprecatedUnstableCreateLevelsConnectRequest<'a> {
+        InventoryDeprecatedUnstableCreateLevelsConnectRequest {
+            client: self,
+            inventory_item_id_required: None,
+            location_id_required: None,
+            relocate_if_necessary: None,
+            body,
+        }
+    }
+    /**
     * Sets the inventory level for an inventory item at a location.
              If the specified location is not connected, it will be automatically connected first.
              When connecting inventory items to locations, it's important to understand the rules around
@@ -2618,6 +6274,31 @@ impl Inventory {
             )
             .await
     }
+    /**
+    * Sets the inventory level for an inventory item at a location.
+             If the specified location is not connected, it will be automatically connected first.
+             When connecting inventory items to locations, it's important to understand the rules around
+             fulfillment service locations.
+    *
+    * This function performs a `POST` to the `/admin/api/unstable/inventory_levels/set.json` endpoint.
+    *
+    * As opposed to `deprecated_unstable_create_levels_set`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+    *
+    * https://shopify.dev/docs/admin-api/rest/reference/inventory/inventorylevel#set-unstable
+    */
+    pub fn deprecated_unstable_create_levels_set_request<'a>(
+        &'a self,
+        body: &'a serde_json::Value,
+    ) -> InventoryDeprecatedUnstableCreateLevelsSetRequest<'a> {
+        InventoryDeprecatedUnstableCreateLevelsSetRequest {
+            client: self,
+            inventory_item_id_required: None,
+            location_id_required: None,
+            available_required: None,
+            disconnect_if_necessary: None,
+            body,
+        }
+    }
     /**
      * Retrieves a list of locations.
      *
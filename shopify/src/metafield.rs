@@ -1,6 +1,612 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Metafield::deprecated_202001_get`], set with a builder.
+pub struct MetafieldDeprecated202001GetRequest<'a> {
+    client: &'a Metafield,
+    metafield_owner_id: Option<i64>,
+    metafield_owner_resource: Option<&'a str>,
+}
+
+impl<'a> MetafieldDeprecated202001GetRequest<'a> {
+    /// Sets the `metafield[owner_id]` query parameter.
+    pub fn metafield_owner_id(mut self, metafield_owner_id: i64) -> Self {
+        self.metafield_owner_id = Some(metafield_owner_id);
+        self
+    }
+
+    /// Sets the `metafield[owner_resource]` query parameter.
+    pub fn metafield_owner_resource(mut self, metafield_owner_resource: &'a str) -> Self {
+        self.metafield_owner_resource = Some(metafield_owner_resource);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            metafield_owner_id,
+            metafield_owner_resource,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = metafield_owner_id {
+            query_args.push(("metafield[owner_id]".to_string(), v.to_string()));
+        }
+        if let Some(v) = metafield_owner_resource {
+            query_args.push(("metafield[owner_resource]".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-01/metafields.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Metafield::deprecated_202001_get_param`], set with a builder.
+pub struct MetafieldDeprecated202001GetParamRequest<'a> {
+    client: &'a Metafield,
+    metafield_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> MetafieldDeprecated202001GetParamRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            metafield_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-01/metafields/{}/json?{}",
+                crate::progenitor_support::encode_path(metafield_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Metafield::deprecated_202004_get`], set with a builder.
+pub struct MetafieldDeprecated202004GetRequest<'a> {
+    client: &'a Metafield,
+    metafield_owner_id: Option<i64>,
+    metafield_owner_resource: Option<&'a str>,
+}
+
+impl<'a> MetafieldDeprecated202004GetRequest<'a> {
+    /// Sets the `metafield[owner_id]` query parameter.
+    pub fn metafield_owner_id(mut self, metafield_owner_id: i64) -> Self {
+        self.metafield_owner_id = Some(metafield_owner_id);
+        self
+    }
+
+    /// Sets the `metafield[owner_resource]` query parameter.
+    pub fn metafield_owner_resource(mut self, metafield_owner_resource: &'a str) -> Self {
+        self.metafield_owner_resource = Some(metafield_owner_resource);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            metafield_owner_id,
+            metafield_owner_resource,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = metafield_owner_id {
+            query_args.push(("metafield[owner_id]".to_string(), v.to_string()));
+        }
+        if let Some(v) = metafield_owner_resource {
+            query_args.push(("metafield[owner_resource]".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-04/metafields.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Metafield::deprecated_202004_get_param`], set with a builder.
+pub struct MetafieldDeprecated202004GetParamRequest<'a> {
+    client: &'a Metafield,
+    metafield_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> MetafieldDeprecated202004GetParamRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            metafield_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-04/metafields/{}/json?{}",
+                crate::progenitor_support::encode_path(metafield_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Metafield::deprecated_202007_get`], set with a builder.
+pub struct MetafieldDeprecated202007GetRequest<'a> {
+    client: &'a Metafield,
+    metafield_owner_id: Option<i64>,
+    metafield_owner_resource: Option<&'a str>,
+}
+
+impl<'a> MetafieldDeprecated202007GetRequest<'a> {
+    /// Sets the `metafield[owner_id]` query parameter.
+    pub fn metafield_owner_id(mut self, metafield_owner_id: i64) -> Self {
+        self.metafield_owner_id = Some(metafield_owner_id);
+        self
+    }
+
+    /// Sets the `metafield[owner_resource]` query parameter.
+    pub fn metafield_owner_resource(mut self, metafield_owner_resource: &'a str) -> Self {
+        self.metafield_owner_resource = Some(metafield_owner_resource);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            metafield_owner_id,
+            metafield_owner_resource,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = metafield_owner_id {
+            query_args.push(("metafield[owner_id]".to_string(), v.to_string()));
+        }
+        if let Some(v) = metafield_owner_resource {
+            query_args.push(("metafield[owner_resource]".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-07/metafields.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Metafield::deprecated_202007_get_param`], set with a builder.
+pub struct MetafieldDeprecated202007GetParamRequest<'a> {
+    client: &'a Metafield,
+    metafield_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> MetafieldDeprecated202007GetParamRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            metafield_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-07/metafields/{}/json?{}",
+                crate::progenitor_support::encode_path(metafield_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Metafield::get`], set with a builder.
+pub struct MetafieldGetRequest<'a> {
+    client: &'a Metafield,
+    metafield_owner_id: Option<i64>,
+    metafield_owner_resource: Option<&'a str>,
+}
+
+impl<'a> MetafieldGetRequest<'a> {
+    /// Sets the `metafield[owner_id]` query parameter.
+    pub fn metafield_owner_id(mut self, metafield_owner_id: i64) -> Self {
+        self.metafield_owner_id = Some(metafield_owner_id);
+        self
+    }
+
+    /// Sets the `metafield[owner_resource]` query parameter.
+    pub fn metafield_owner_resource(mut self, metafield_owner_resource: &'a str) -> Self {
+        self.metafield_owner_resource = Some(metafield_owner_resource);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            metafield_owner_id,
+            metafield_owner_resource,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = metafield_owner_id {
+            query_args.push(("metafield[owner_id]".to_string(), v.to_string()));
+        }
+        if let Some(v) = metafield_owner_resource {
+            query_args.push(("metafield[owner_resource]".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-10/metafields.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Metafield::get_param`], set with a builder.
+pub struct MetafieldGetParamRequest<'a> {
+    client: &'a Metafield,
+    metafield_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> MetafieldGetParamRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            metafield_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-10/metafields/{}/json?{}",
+                crate::progenitor_support::encode_path(metafield_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Metafield::deprecated_202101_get`], set with a builder.
+pub struct MetafieldDeprecated202101GetRequest<'a> {
+    client: &'a Metafield,
+    metafield_owner_id: Option<i64>,
+    metafield_owner_resource: Option<&'a str>,
+}
+
+impl<'a> MetafieldDeprecated202101GetRequest<'a> {
+    /// Sets the `metafield[owner_id]` query parameter.
+    pub fn metafield_owner_id(mut self, metafield_owner_id: i64) -> Self {
+        self.metafield_owner_id = Some(metafield_owner_id);
+        self
+    }
+
+    /// Sets the `metafield[owner_resource]` query parameter.
+    pub fn metafield_owner_resource(mut self, metafield_owner_resource: &'a str) -> Self {
+        self.metafield_owner_resource = Some(metafield_owner_resource);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            metafield_owner_id,
+            metafield_owner_resource,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = metafield_owner_id {
+            query_args.push(("metafield[owner_id]".to_string(), v.to_string()));
+        }
+        if let Some(v) = metafield_owner_resource {
+            query_args.push(("metafield[owner_resource]".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2021-01/metafields.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Metafield::deprecated_202101_get_param`], set with a builder.
+pub struct MetafieldDeprecated202101GetParamRequest<'a> {
+    client: &'a Metafield,
+    metafield_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> MetafieldDeprecated202101GetParamRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            metafield_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2021-01/metafields/{}/json?{}",
+                crate::progenitor_support::encode_path(metafield_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Metafield::deprecated_unstable_get`], set with a builder.
+pub struct MetafieldDeprecatedUnstableGetRequest<'a> {
+    client: &'a Metafield,
+    metafield_owner_id: Option<i64>,
+    metafield_owner_resource: Option<&'a str>,
+}
+
+impl<'a> MetafieldDeprecatedUnstableGetRequest<'a> {
+    /// Sets the `metafield[owner_id]` query parameter.
+    pub fn metafield_owner_id(mut self, metafield_owner_id: i64) -> Self {
+        self.metafield_owner_id = Some(metafield_owner_id);
+        self
+    }
+
+    /// Sets the `metafield[owner_resource]` query parameter.
+    pub fn metafield_owner_resource(mut self, metafield_owner_resource: &'a str) -> Self {
+        self.metafield_owner_resource = Some(metafield_owner_resource);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            metafield_owner_id,
+            metafield_owner_resource,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = metafield_owner_id {
+            query_args.push(("metafield[owner_id]".to_string(), v.to_string()));
+        }
+        if let Some(v) = metafield_owner_resource {
+            query_args.push(("metafield[owner_resource]".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/unstable/metafields.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Metafield::deprecated_unstable_get_param`], set with a builder.
+pub struct MetafieldDeprecatedUnstableGetParamRequest<'a> {
+    client: &'a Metafield,
+    metafield_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> MetafieldDeprecatedUnstableGetParamRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            metafield_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/unstable/metafields/{}/json?{}",
+                crate::progenitor_support::encode_path(metafield_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct Metafield {
     pub client: Client,
 }
@@ -56,6 +662,22 @@ impl Metafield {
             )
             .await
     }
+    /**
+     * Retrieves a list of metafields that belong to a Product Image resource.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-01/metafields.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_get`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/metafield#index-2020-01
+     */
+    pub fn deprecated_202001_get_request(&self) -> MetafieldDeprecated202001GetRequest<'_> {
+        MetafieldDeprecated202001GetRequest {
+            client: self,
+            metafield_owner_id: None,
+            metafield_owner_resource: None,
+        }
+    }
     /**
      * Creates a new metafield for a resource.
      *
@@ -139,6 +761,25 @@ impl Metafield {
             )
             .await
     }
+    /**
+     * Retrieves a single metafield from a resource by its ID.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-01/metafields/{metafield_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_get_param`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/metafield#show-2020-01
+     */
+    pub fn deprecated_202001_get_param_request<'a>(
+        &'a self,
+        metafield_id: &'a str,
+    ) -> MetafieldDeprecated202001GetParamRequest<'a> {
+        MetafieldDeprecated202001GetParamRequest {
+            client: self,
+            metafield_id,
+            fields: None,
+        }
+    }
     /**
      * Updates a metafield.
      *
@@ -249,6 +890,22 @@ impl Metafield {
             )
             .await
     }
+    /**
+     * Retrieves a list of metafields that belong to a Product Image resource.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-04/metafields.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_get`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/metafield#index-2020-04
+     */
+    pub fn deprecated_202004_get_request(&self) -> MetafieldDeprecated202004GetRequest<'_> {
+        MetafieldDeprecated202004GetRequest {
+            client: self,
+            metafield_owner_id: None,
+            metafield_owner_resource: None,
+        }
+    }
     /**
      * Creates a new metafield for a resource.
      *
@@ -332,6 +989,25 @@ impl Metafield {
             )
             .await
     }
+    /**
+     * Retrieves a single metafield from a resource by its ID.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-04/metafields/{metafield_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_get_param`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/metafield#show-2020-04
+     */
+    pub fn deprecated_202004_get_param_request<'a>(
+        &'a self,
+        metafield_id: &'a str,
+    ) -> MetafieldDeprecated202004GetParamRequest<'a> {
+        MetafieldDeprecated202004GetParamRequest {
+            client: self,
+            metafield_id,
+            fields: None,
+        }
+    }
     /**
      * Updates a metafield.
      *
@@ -442,6 +1118,22 @@ impl Metafield {
             )
             .await
     }
+    /**
+     * Retrieves a list of metafields that belong to a Product Image resource.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-07/metafields.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_get`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/metafield#index-2020-07
+     */
+    pub fn deprecated_202007_get_request(&self) -> MetafieldDeprecated202007GetRequest<'_> {
+        MetafieldDeprecated202007GetRequest {
+            client: self,
+            metafield_owner_id: None,
+            metafield_owner_resource: None,
+        }
+    }
     /**
      * Creates a new metafield for a resource.
      *
@@ -525,6 +1217,25 @@ impl Metafield {
             )
             .await
     }
+    /**
+     * Retrieves a single metafield from a resource by its ID.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-07/metafields/{metafield_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_get_param`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/metafield#show-2020-07
+     */
+    pub fn deprecated_202007_get_param_request<'a>(
+        &'a self,
+        metafield_id: &'a str,
+    ) -> MetafieldDeprecated202007GetParamRequest<'a> {
+        MetafieldDeprecated202007GetParamRequest {
+            client: self,
+            metafield_id,
+            fields: None,
+        }
+    }
     /**
      * Updates a metafield.
      *
@@ -635,6 +1346,22 @@ impl Metafield {
             )
             .await
     }
+    /**
+     * Retrieves a list of metafields that belong to a Product Image resource.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-10/metafields.json` endpoint.
+     *
+     * As opposed to `get`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/metafield#index-2020-10
+     */
+    pub fn get_request(&self) -> MetafieldGetRequest<'_> {
+        MetafieldGetRequest {
+            client: self,
+            metafield_owner_id: None,
+            metafield_owner_resource: None,
+        }
+    }
     /**
      * Creates a new metafield for a resource.
      *
@@ -715,6 +1442,22 @@ impl Metafield {
             )
             .await
     }
+    /**
+     * Retrieves a single metafield from a resource by its ID.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-10/metafields/{metafield_id}.json` endpoint.
+     *
+     * As opposed to `get_param`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/metafield#show-2020-10
+     */
+    pub fn get_param_request<'a>(&'a self, metafield_id: &'a str) -> MetafieldGetParamRequest<'a> {
+        MetafieldGetParamRequest {
+            client: self,
+            metafield_id,
+            fields: None,
+        }
+    }
     /**
      * Updates a metafield.
      *
@@ -822,6 +1565,22 @@ impl Metafield {
             )
             .await
     }
+    /**
+     * Retrieves a list of metafields that belong to a Product Image resource.
+     *
+     * This function performs a `GET` to the `/admin/api/2021-01/metafields.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_get`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/metafield#index-2021-01
+     */
+    pub fn deprecated_202101_get_request(&self) -> MetafieldDeprecated202101GetRequest<'_> {
+        MetafieldDeprecated202101GetRequest {
+            client: self,
+            metafield_owner_id: None,
+            metafield_owner_resource: None,
+        }
+    }
     /**
      * Creates a new metafield for a resource.
      *
@@ -905,6 +1664,25 @@ impl Metafield {
             )
             .await
     }
+    /**
+     * Retrieves a single metafield from a resource by its ID.
+     *
+     * This function performs a `GET` to the `/admin/api/2021-01/metafields/{metafield_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_get_param`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/metafield#show-2021-01
+     */
+    pub fn deprecated_202101_get_param_request<'a>(
+        &'a self,
+        metafield_id: &'a str,
+    ) -> MetafieldDeprecated202101GetParamRequest<'a> {
+        MetafieldDeprecated202101GetParamRequest {
+            client: self,
+            metafield_id,
+            fields: None,
+        }
+    }
     /**
      * Updates a metafield.
      *
@@ -1015,6 +1793,22 @@ impl Metafield {
             )
             .await
     }
+    /**
+     * Retrieves a list of metafields that belong to a Product Image resource.
+     *
+     * This function performs a `GET` to the `/admin/api/unstable/metafields.json` endpoint.
+     *
+     * As opposed to `deprecated_unstable_get`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/metafield#index-unstable
+     */
+    pub fn deprecated_unstable_get_request(&self) -> MetafieldDeprecatedUnstableGetRequest<'_> {
+        MetafieldDeprecatedUnstableGetRequest {
+            client: self,
+            metafield_owner_id: None,
+            metafield_owner_resource: None,
+        }
+    }
     /**
      * Creates a new metafield for a resource.
      *
@@ -1098,6 +1892,25 @@ impl Metafield {
             )
             .await
     }
+    /**
+     * Retrieves a single metafield from a resource by its ID.
+     *
+     * This function performs a `GET` to the `/admin/api/unstable/metafields/{metafield_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_unstable_get_param`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/metafield#show-unstable
+     */
+    pub fn deprecated_unstable_get_param_request<'a>(
+        &'a self,
+        metafield_id: &'a str,
+    ) -> MetafieldDeprecatedUnstableGetParamRequest<'a> {
+        MetafieldDeprecatedUnstableGetParamRequest {
+            client: self,
+            metafield_id,
+            fields: None,
+        }
+    }
     /**
      * Updates a metafield.
      *
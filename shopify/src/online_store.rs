@@ -1,6 +1,6761 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`OnlineStore::deprecated_202001_get_blogs_param_blog_article`], set with a builder.
+pub struct OnlineStoreDeprecated202001GetBlogsParamBlogArticleRequest<'a> {
+    client: &'a OnlineStore,
+    blog_id: &'a str,
+    limit: Option<&'a str>,
+    since_id: Option<&'a str>,
+    created_at_min: Option<&'a str>,
+    created_at_max: Option<&'a str>,
+    updated_at_min: Option<&'a str>,
+    updated_at_max: Option<&'a str>,
+    published_at_min: Option<&'a str>,
+    published_at_max: Option<&'a str>,
+    published_status: Option<&'a str>,
+    handle: Option<&'a str>,
+    tag: Option<&'a str>,
+    author: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> OnlineStoreDeprecated202001GetBlogsParamBlogArticleRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.created_at_min = Some(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.created_at_max = Some(created_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.updated_at_min = Some(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.updated_at_max = Some(updated_at_max);
+        self
+    }
+
+    /// Sets the `published_at_min` query parameter.
+    pub fn published_at_min(mut self, published_at_min: &'a str) -> Self {
+        self.published_at_min = Some(published_at_min);
+        self
+    }
+
+    /// Sets the `published_at_max` query parameter.
+    pub fn published_at_max(mut self, published_at_max: &'a str) -> Self {
+        self.published_at_max = Some(published_at_max);
+        self
+    }
+
+    /// Sets the `published_status` query parameter.
+    pub fn published_status(mut self, published_status: &'a str) -> Self {
+        self.published_status = Some(published_status);
+        self
+    }
+
+    /// Sets the `handle` query parameter.
+    pub fn handle(mut self, handle: &'a str) -> Self {
+        self.handle = Some(handle);
+        self
+    }
+
+    /// Sets the `tag` query parameter.
+    pub fn tag(mut self, tag: &'a str) -> Self {
+        self.tag = Some(tag);
+        self
+    }
+
+    /// Sets the `author` query parameter.
+    pub fn author(mut self, author: &'a str) -> Self {
+        self.author = Some(author);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            blog_id,
+            limit,
+            since_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            published_status,
+            handle,
+            tag,
+            author,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = author {
+            query_args.push(("author".to_string(), v.to_string()));
+        }
+        if let Some(v) = created_at_max {
+            query_args.push(("created_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = created_at_min {
+            query_args.push(("created_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = handle {
+            query_args.push(("handle".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_at_max {
+            query_args.push(("published_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_at_min {
+            query_args.push(("published_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_status {
+            query_args.push(("published_status".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = tag {
+            query_args.push(("tag".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_max {
+            query_args.push(("updated_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_min {
+            query_args.push(("updated_at_min".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-01/blogs/{}/articles.json?{}",
+                crate::progenitor_support::encode_path(blog_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202001_get_blogs_param_blog_articles_count`], set with a builder.
+pub struct OnlineStoreDeprecated202001GetBlogsParamBlogArticlesCountRequest<'a> {
+    client: &'a OnlineStore,
+    blog_id: &'a str,
+    created_at_min: Option<&'a str>,
+    created_at_max: Option<&'a str>,
+    updated_at_min: Option<&'a str>,
+    updated_at_max: Option<&'a str>,
+    published_at_min: Option<&'a str>,
+    published_at_max: Option<&'a str>,
+    published_status: Option<&'a str>,
+}
+
+impl<'a> OnlineStoreDeprecated202001GetBlogsParamBlogArticlesCountRequest<'a> {
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.created_at_min = Some(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.created_at_max = Some(created_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.updated_at_min = Some(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.updated_at_max = Some(updated_at_max);
+        self
+    }
+
+    /// Sets the `published_at_min` query parameter.
+    pub fn published_at_min(mut self, published_at_min: &'a str) -> Self {
+        self.published_at_min = Some(published_at_min);
+        self
+    }
+
+    /// Sets the `published_at_max` query parameter.
+    pub fn published_at_max(mut self, published_at_max: &'a str) -> Self {
+        self.published_at_max = Some(published_at_max);
+        self
+    }
+
+    /// Sets the `published_status` query parameter.
+    pub fn published_status(mut self, published_status: &'a str) -> Self {
+        self.published_status = Some(published_status);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            blog_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            published_status,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = created_at_max {
+            query_args.push(("created_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = created_at_min {
+            query_args.push(("created_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_at_max {
+            query_args.push(("published_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_at_min {
+            query_args.push(("published_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_status {
+            query_args.push(("published_status".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_max {
+            query_args.push(("updated_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_min {
+            query_args.push(("updated_at_min".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-01/blogs/{}/articles/count.json?{}",
+                crate::progenitor_support::encode_path(blog_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202001_get_blogs_param_blog_articles_article`], set with a builder.
+pub struct OnlineStoreDeprecated202001GetBlogsParamBlogArticlesArticleRequest<'a> {
+    client: &'a OnlineStore,
+    blog_id: &'a str,
+    article_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> OnlineStoreDeprecated202001GetBlogsParamBlogArticlesArticleRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            blog_id,
+            article_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-01/blogs/{}/articles/{}/json?{}",
+                crate::progenitor_support::encode_path(blog_id),
+                crate::progenitor_support::encode_path(article_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202001_get_articles_tag`], set with a builder.
+pub struct OnlineStoreDeprecated202001GetArticlesTagRequest<'a> {
+    client: &'a OnlineStore,
+    limit: Option<&'a str>,
+    popular: Option<&'a str>,
+}
+
+impl<'a> OnlineStoreDeprecated202001GetArticlesTagRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `popular` query parameter.
+    pub fn popular(mut self, popular: &'a str) -> Self {
+        self.popular = Some(popular);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            limit,
+            popular,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = popular {
+            query_args.push(("popular".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-01/articles/tags.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202004_get_blogs_param_blog_article`], set with a builder.
+pub struct OnlineStoreDeprecated202004GetBlogsParamBlogArticleRequest<'a> {
+    client: &'a OnlineStore,
+    blog_id: &'a str,
+    limit: Option<&'a str>,
+    since_id: Option<&'a str>,
+    created_at_min: Option<&'a str>,
+    created_at_max: Option<&'a str>,
+    updated_at_min: Option<&'a str>,
+    updated_at_max: Option<&'a str>,
+    published_at_min: Option<&'a str>,
+    published_at_max: Option<&'a str>,
+    published_status: Option<&'a str>,
+    handle: Option<&'a str>,
+    tag: Option<&'a str>,
+    author: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> OnlineStoreDeprecated202004GetBlogsParamBlogArticleRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.created_at_min = Some(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.created_at_max = Some(created_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.updated_at_min = Some(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.updated_at_max = Some(updated_at_max);
+        self
+    }
+
+    /// Sets the `published_at_min` query parameter.
+    pub fn published_at_min(mut self, published_at_min: &'a str) -> Self {
+        self.published_at_min = Some(published_at_min);
+        self
+    }
+
+    /// Sets the `published_at_max` query parameter.
+    pub fn published_at_max(mut self, published_at_max: &'a str) -> Self {
+        self.published_at_max = Some(published_at_max);
+        self
+    }
+
+    /// Sets the `published_status` query parameter.
+    pub fn published_status(mut self, published_status: &'a str) -> Self {
+        self.published_status = Some(published_status);
+        self
+    }
+
+    /// Sets the `handle` query parameter.
+    pub fn handle(mut self, handle: &'a str) -> Self {
+        self.handle = Some(handle);
+        self
+    }
+
+    /// Sets the `tag` query parameter.
+    pub fn tag(mut self, tag: &'a str) -> Self {
+        self.tag = Some(tag);
+        self
+    }
+
+    /// Sets the `author` query parameter.
+    pub fn author(mut self, author: &'a str) -> Self {
+        self.author = Some(author);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            blog_id,
+            limit,
+            since_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            published_status,
+            handle,
+            tag,
+            author,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = author {
+            query_args.push(("author".to_string(), v.to_string()));
+        }
+        if let Some(v) = created_at_max {
+            query_args.push(("created_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = created_at_min {
+            query_args.push(("created_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = handle {
+            query_args.push(("handle".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_at_max {
+            query_args.push(("published_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_at_min {
+            query_args.push(("published_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_status {
+            query_args.push(("published_status".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = tag {
+            query_args.push(("tag".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_max {
+            query_args.push(("updated_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_min {
+            query_args.push(("updated_at_min".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-04/blogs/{}/articles.json?{}",
+                crate::progenitor_support::encode_path(blog_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202004_get_blogs_param_blog_articles_count`], set with a builder.
+pub struct OnlineStoreDeprecated202004GetBlogsParamBlogArticlesCountRequest<'a> {
+    client: &'a OnlineStore,
+    blog_id: &'a str,
+    created_at_min: Option<&'a str>,
+    created_at_max: Option<&'a str>,
+    updated_at_min: Option<&'a str>,
+    updated_at_max: Option<&'a str>,
+    published_at_min: Option<&'a str>,
+    published_at_max: Option<&'a str>,
+    published_status: Option<&'a str>,
+}
+
+impl<'a> OnlineStoreDeprecated202004GetBlogsParamBlogArticlesCountRequest<'a> {
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.created_at_min = Some(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.created_at_max = Some(created_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.updated_at_min = Some(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.updated_at_max = Some(updated_at_max);
+        self
+    }
+
+    /// Sets the `published_at_min` query parameter.
+    pub fn published_at_min(mut self, published_at_min: &'a str) -> Self {
+        self.published_at_min = Some(published_at_min);
+        self
+    }
+
+    /// Sets the `published_at_max` query parameter.
+    pub fn published_at_max(mut self, published_at_max: &'a str) -> Self {
+        self.published_at_max = Some(published_at_max);
+        self
+    }
+
+    /// Sets the `published_status` query parameter.
+    pub fn published_status(mut self, published_status: &'a str) -> Self {
+        self.published_status = Some(published_status);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            blog_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            published_status,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = created_at_max {
+            query_args.push(("created_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = created_at_min {
+            query_args.push(("created_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_at_max {
+            query_args.push(("published_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_at_min {
+            query_args.push(("published_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_status {
+            query_args.push(("published_status".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_max {
+            query_args.push(("updated_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_min {
+            query_args.push(("updated_at_min".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-04/blogs/{}/articles/count.json?{}",
+                crate::progenitor_support::encode_path(blog_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202004_get_blogs_param_blog_articles_article`], set with a builder.
+pub struct OnlineStoreDeprecated202004GetBlogsParamBlogArticlesArticleRequest<'a> {
+    client: &'a OnlineStore,
+    blog_id: &'a str,
+    article_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> OnlineStoreDeprecated202004GetBlogsParamBlogArticlesArticleRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            blog_id,
+            article_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-04/blogs/{}/articles/{}/json?{}",
+                crate::progenitor_support::encode_path(blog_id),
+                crate::progenitor_support::encode_path(article_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202004_get_articles_tag`], set with a builder.
+pub struct OnlineStoreDeprecated202004GetArticlesTagRequest<'a> {
+    client: &'a OnlineStore,
+    limit: Option<&'a str>,
+    popular: Option<&'a str>,
+}
+
+impl<'a> OnlineStoreDeprecated202004GetArticlesTagRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `popular` query parameter.
+    pub fn popular(mut self, popular: &'a str) -> Self {
+        self.popular = Some(popular);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            limit,
+            popular,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = popular {
+            query_args.push(("popular".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-04/articles/tags.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202007_get_blogs_param_blog_article`], set with a builder.
+pub struct OnlineStoreDeprecated202007GetBlogsParamBlogArticleRequest<'a> {
+    client: &'a OnlineStore,
+    blog_id: &'a str,
+    limit: Option<&'a str>,
+    since_id: Option<&'a str>,
+    created_at_min: Option<&'a str>,
+    created_at_max: Option<&'a str>,
+    updated_at_min: Option<&'a str>,
+    updated_at_max: Option<&'a str>,
+    published_at_min: Option<&'a str>,
+    published_at_max: Option<&'a str>,
+    published_status: Option<&'a str>,
+    handle: Option<&'a str>,
+    tag: Option<&'a str>,
+    author: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> OnlineStoreDeprecated202007GetBlogsParamBlogArticleRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.created_at_min = Some(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.created_at_max = Some(created_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.updated_at_min = Some(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.updated_at_max = Some(updated_at_max);
+        self
+    }
+
+    /// Sets the `published_at_min` query parameter.
+    pub fn published_at_min(mut self, published_at_min: &'a str) -> Self {
+        self.published_at_min = Some(published_at_min);
+        self
+    }
+
+    /// Sets the `published_at_max` query parameter.
+    pub fn published_at_max(mut self, published_at_max: &'a str) -> Self {
+        self.published_at_max = Some(published_at_max);
+        self
+    }
+
+    /// Sets the `published_status` query parameter.
+    pub fn published_status(mut self, published_status: &'a str) -> Self {
+        self.published_status = Some(published_status);
+        self
+    }
+
+    /// Sets the `handle` query parameter.
+    pub fn handle(mut self, handle: &'a str) -> Self {
+        self.handle = Some(handle);
+        self
+    }
+
+    /// Sets the `tag` query parameter.
+    pub fn tag(mut self, tag: &'a str) -> Self {
+        self.tag = Some(tag);
+        self
+    }
+
+    /// Sets the `author` query parameter.
+    pub fn author(mut self, author: &'a str) -> Self {
+        self.author = Some(author);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            blog_id,
+            limit,
+            since_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            published_status,
+            handle,
+            tag,
+            author,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = author {
+            query_args.push(("author".to_string(), v.to_string()));
+        }
+        if let Some(v) = created_at_max {
+            query_args.push(("created_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = created_at_min {
+            query_args.push(("created_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = handle {
+            query_args.push(("handle".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_at_max {
+            query_args.push(("published_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_at_min {
+            query_args.push(("published_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_status {
+            query_args.push(("published_status".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = tag {
+            query_args.push(("tag".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_max {
+            query_args.push(("updated_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_min {
+            query_args.push(("updated_at_min".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-07/blogs/{}/articles.json?{}",
+                crate::progenitor_support::encode_path(blog_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202007_get_blogs_param_blog_articles_count`], set with a builder.
+pub struct OnlineStoreDeprecated202007GetBlogsParamBlogArticlesCountRequest<'a> {
+    client: &'a OnlineStore,
+    blog_id: &'a str,
+    created_at_min: Option<&'a str>,
+    created_at_max: Option<&'a str>,
+    updated_at_min: Option<&'a str>,
+    updated_at_max: Option<&'a str>,
+    published_at_min: Option<&'a str>,
+    published_at_max: Option<&'a str>,
+    published_status: Option<&'a str>,
+}
+
+impl<'a> OnlineStoreDeprecated202007GetBlogsParamBlogArticlesCountRequest<'a> {
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.created_at_min = Some(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.created_at_max = Some(created_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.updated_at_min = Some(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.updated_at_max = Some(updated_at_max);
+        self
+    }
+
+    /// Sets the `published_at_min` query parameter.
+    pub fn published_at_min(mut self, published_at_min: &'a str) -> Self {
+        self.published_at_min = Some(published_at_min);
+        self
+    }
+
+    /// Sets the `published_at_max` query parameter.
+    pub fn published_at_max(mut self, published_at_max: &'a str) -> Self {
+        self.published_at_max = Some(published_at_max);
+        self
+    }
+
+    /// Sets the `published_status` query parameter.
+    pub fn published_status(mut self, published_status: &'a str) -> Self {
+        self.published_status = Some(published_status);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            blog_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            published_status,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = created_at_max {
+            query_args.push(("created_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = created_at_min {
+            query_args.push(("created_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_at_max {
+            query_args.push(("published_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_at_min {
+            query_args.push(("published_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_status {
+            query_args.push(("published_status".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_max {
+            query_args.push(("updated_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_min {
+            query_args.push(("updated_at_min".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-07/blogs/{}/articles/count.json?{}",
+                crate::progenitor_support::encode_path(blog_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202007_get_blogs_param_blog_articles_article`], set with a builder.
+pub struct OnlineStoreDeprecated202007GetBlogsParamBlogArticlesArticleRequest<'a> {
+    client: &'a OnlineStore,
+    blog_id: &'a str,
+    article_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> OnlineStoreDeprecated202007GetBlogsParamBlogArticlesArticleRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            blog_id,
+            article_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-07/blogs/{}/articles/{}/json?{}",
+                crate::progenitor_support::encode_path(blog_id),
+                crate::progenitor_support::encode_path(article_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202007_get_articles_tag`], set with a builder.
+pub struct OnlineStoreDeprecated202007GetArticlesTagRequest<'a> {
+    client: &'a OnlineStore,
+    limit: Option<&'a str>,
+    popular: Option<&'a str>,
+}
+
+impl<'a> OnlineStoreDeprecated202007GetArticlesTagRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `popular` query parameter.
+    pub fn popular(mut self, popular: &'a str) -> Self {
+        self.popular = Some(popular);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            limit,
+            popular,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = popular {
+            query_args.push(("popular".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-07/articles/tags.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`OnlineStore::get_blogs_param_blog_article`], set with a builder.
+pub struct OnlineStoreGetBlogsParamBlogArticleRequest<'a> {
+    client: &'a OnlineStore,
+    blog_id: &'a str,
+    limit: Option<&'a str>,
+    since_id: Option<&'a str>,
+    created_at_min: Option<&'a str>,
+    created_at_max: Option<&'a str>,
+    updated_at_min: Option<&'a str>,
+    updated_at_max: Option<&'a str>,
+    published_at_min: Option<&'a str>,
+    published_at_max: Option<&'a str>,
+    published_status: Option<&'a str>,
+    handle: Option<&'a str>,
+    tag: Option<&'a str>,
+    author: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> OnlineStoreGetBlogsParamBlogArticleRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.created_at_min = Some(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.created_at_max = Some(created_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.updated_at_min = Some(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.updated_at_max = Some(updated_at_max);
+        self
+    }
+
+    /// Sets the `published_at_min` query parameter.
+    pub fn published_at_min(mut self, published_at_min: &'a str) -> Self {
+        self.published_at_min = Some(published_at_min);
+        self
+    }
+
+    /// Sets the `published_at_max` query parameter.
+    pub fn published_at_max(mut self, published_at_max: &'a str) -> Self {
+        self.published_at_max = Some(published_at_max);
+        self
+    }
+
+    /// Sets the `published_status` query parameter.
+    pub fn published_status(mut self, published_status: &'a str) -> Self {
+        self.published_status = Some(published_status);
+        self
+    }
+
+    /// Sets the `handle` query parameter.
+    pub fn handle(mut self, handle: &'a str) -> Self {
+        self.handle = Some(handle);
+        self
+    }
+
+    /// Sets the `tag` query parameter.
+    pub fn tag(mut self, tag: &'a str) -> Self {
+        self.tag = Some(tag);
+        self
+    }
+
+    /// Sets the `author` query parameter.
+    pub fn author(mut self, author: &'a str) -> Self {
+        self.author = Some(author);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            blog_id,
+            limit,
+            since_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            published_status,
+            handle,
+            tag,
+            author,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = author {
+            query_args.push(("author".to_string(), v.to_string()));
+        }
+        if let Some(v) = created_at_max {
+            query_args.push(("created_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = created_at_min {
+            query_args.push(("created_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = handle {
+            query_args.push(("handle".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_at_max {
+            query_args.push(("published_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_at_min {
+            query_args.push(("published_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_status {
+            query_args.push(("published_status".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = tag {
+            query_args.push(("tag".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_max {
+            query_args.push(("updated_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_min {
+            query_args.push(("updated_at_min".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-10/blogs/{}/articles.json?{}",
+                crate::progenitor_support::encode_path(blog_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`OnlineStore::get_blogs_param_blog_articles_count`], set with a builder.
+pub struct OnlineStoreGetBlogsParamBlogArticlesCountRequest<'a> {
+    client: &'a OnlineStore,
+    blog_id: &'a str,
+    created_at_min: Option<&'a str>,
+    created_at_max: Option<&'a str>,
+    updated_at_min: Option<&'a str>,
+    updated_at_max: Option<&'a str>,
+    published_at_min: Option<&'a str>,
+    published_at_max: Option<&'a str>,
+    published_status: Option<&'a str>,
+}
+
+impl<'a> OnlineStoreGetBlogsParamBlogArticlesCountRequest<'a> {
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.created_at_min = Some(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.created_at_max = Some(created_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.updated_at_min = Some(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.updated_at_max = Some(updated_at_max);
+        self
+    }
+
+    /// Sets the `published_at_min` query parameter.
+    pub fn published_at_min(mut self, published_at_min: &'a str) -> Self {
+        self.published_at_min = Some(published_at_min);
+        self
+    }
+
+    /// Sets the `published_at_max` query parameter.
+    pub fn published_at_max(mut self, published_at_max: &'a str) -> Self {
+        self.published_at_max = Some(published_at_max);
+        self
+    }
+
+    /// Sets the `published_status` query parameter.
+    pub fn published_status(mut self, published_status: &'a str) -> Self {
+        self.published_status = Some(published_status);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            blog_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            published_status,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = created_at_max {
+            query_args.push(("created_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = created_at_min {
+            query_args.push(("created_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_at_max {
+            query_args.push(("published_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_at_min {
+            query_args.push(("published_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_status {
+            query_args.push(("published_status".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_max {
+            query_args.push(("updated_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_min {
+            query_args.push(("updated_at_min".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-10/blogs/{}/articles/count.json?{}",
+                crate::progenitor_support::encode_path(blog_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`OnlineStore::get_blogs_param_blog_articles_article`], set with a builder.
+pub struct OnlineStoreGetBlogsParamBlogArticlesArticleRequest<'a> {
+    client: &'a OnlineStore,
+    blog_id: &'a str,
+    article_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> OnlineStoreGetBlogsParamBlogArticlesArticleRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            blog_id,
+            article_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-10/blogs/{}/articles/{}/json?{}",
+                crate::progenitor_support::encode_path(blog_id),
+                crate::progenitor_support::encode_path(article_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`OnlineStore::get_articles_tag`], set with a builder.
+pub struct OnlineStoreGetArticlesTagRequest<'a> {
+    client: &'a OnlineStore,
+    limit: Option<&'a str>,
+    popular: Option<&'a str>,
+}
+
+impl<'a> OnlineStoreGetArticlesTagRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `popular` query parameter.
+    pub fn popular(mut self, popular: &'a str) -> Self {
+        self.popular = Some(popular);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            limit,
+            popular,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = popular {
+            query_args.push(("popular".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-10/articles/tags.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202101_get_blogs_param_blog_article`], set with a builder.
+pub struct OnlineStoreDeprecated202101GetBlogsParamBlogArticleRequest<'a> {
+    client: &'a OnlineStore,
+    blog_id: &'a str,
+    limit: Option<&'a str>,
+    since_id: Option<&'a str>,
+    created_at_min: Option<&'a str>,
+    created_at_max: Option<&'a str>,
+    updated_at_min: Option<&'a str>,
+    updated_at_max: Option<&'a str>,
+    published_at_min: Option<&'a str>,
+    published_at_max: Option<&'a str>,
+    published_status: Option<&'a str>,
+    handle: Option<&'a str>,
+    tag: Option<&'a str>,
+    author: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> OnlineStoreDeprecated202101GetBlogsParamBlogArticleRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.created_at_min = Some(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.created_at_max = Some(created_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.updated_at_min = Some(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.updated_at_max = Some(updated_at_max);
+        self
+    }
+
+    /// Sets the `published_at_min` query parameter.
+    pub fn published_at_min(mut self, published_at_min: &'a str) -> Self {
+        self.published_at_min = Some(published_at_min);
+        self
+    }
+
+    /// Sets the `published_at_max` query parameter.
+    pub fn published_at_max(mut self, published_at_max: &'a str) -> Self {
+        self.published_at_max = Some(published_at_max);
+        self
+    }
+
+    /// Sets the `published_status` query parameter.
+    pub fn published_status(mut self, published_status: &'a str) -> Self {
+        self.published_status = Some(published_status);
+        self
+    }
+
+    /// Sets the `handle` query parameter.
+    pub fn handle(mut self, handle: &'a str) -> Self {
+        self.handle = Some(handle);
+        self
+    }
+
+    /// Sets the `tag` query parameter.
+    pub fn tag(mut self, tag: &'a str) -> Self {
+        self.tag = Some(tag);
+        self
+    }
+
+    /// Sets the `author` query parameter.
+    pub fn author(mut self, author: &'a str) -> Self {
+        self.author = Some(author);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            blog_id,
+            limit,
+            since_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            published_status,
+            handle,
+            tag,
+            author,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = author {
+            query_args.push(("author".to_string(), v.to_string()));
+        }
+        if let Some(v) = created_at_max {
+            query_args.push(("created_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = created_at_min {
+            query_args.push(("created_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = handle {
+            query_args.push(("handle".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_at_max {
+            query_args.push(("published_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_at_min {
+            query_args.push(("published_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_status {
+            query_args.push(("published_status".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = tag {
+            query_args.push(("tag".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_max {
+            query_args.push(("updated_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_min {
+            query_args.push(("updated_at_min".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2021-01/blogs/{}/articles.json?{}",
+                crate::progenitor_support::encode_path(blog_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202101_get_blogs_param_blog_articles_count`], set with a builder.
+pub struct OnlineStoreDeprecated202101GetBlogsParamBlogArticlesCountRequest<'a> {
+    client: &'a OnlineStore,
+    blog_id: &'a str,
+    created_at_min: Option<&'a str>,
+    created_at_max: Option<&'a str>,
+    updated_at_min: Option<&'a str>,
+    updated_at_max: Option<&'a str>,
+    published_at_min: Option<&'a str>,
+    published_at_max: Option<&'a str>,
+    published_status: Option<&'a str>,
+}
+
+impl<'a> OnlineStoreDeprecated202101GetBlogsParamBlogArticlesCountRequest<'a> {
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.created_at_min = Some(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.created_at_max = Some(created_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.updated_at_min = Some(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.updated_at_max = Some(updated_at_max);
+        self
+    }
+
+    /// Sets the `published_at_min` query parameter.
+    pub fn published_at_min(mut self, published_at_min: &'a str) -> Self {
+        self.published_at_min = Some(published_at_min);
+        self
+    }
+
+    /// Sets the `published_at_max` query parameter.
+    pub fn published_at_max(mut self, published_at_max: &'a str) -> Self {
+        self.published_at_max = Some(published_at_max);
+        self
+    }
+
+    /// Sets the `published_status` query parameter.
+    pub fn published_status(mut self, published_status: &'a str) -> Self {
+        self.published_status = Some(published_status);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            blog_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            published_status,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = created_at_max {
+            query_args.push(("created_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = created_at_min {
+            query_args.push(("created_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_at_max {
+            query_args.push(("published_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_at_min {
+            query_args.push(("published_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_status {
+            query_args.push(("published_status".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_max {
+            query_args.push(("updated_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_min {
+            query_args.push(("updated_at_min".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2021-01/blogs/{}/articles/count.json?{}",
+                crate::progenitor_support::encode_path(blog_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202101_get_blogs_param_blog_articles_article`], set with a builder.
+pub struct OnlineStoreDeprecated202101GetBlogsParamBlogArticlesArticleRequest<'a> {
+    client: &'a OnlineStore,
+    blog_id: &'a str,
+    article_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> OnlineStoreDeprecated202101GetBlogsParamBlogArticlesArticleRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            blog_id,
+            article_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2021-01/blogs/{}/articles/{}/json?{}",
+                crate::progenitor_support::encode_path(blog_id),
+                crate::progenitor_support::encode_path(article_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202101_get_articles_tag`], set with a builder.
+pub struct OnlineStoreDeprecated202101GetArticlesTagRequest<'a> {
+    client: &'a OnlineStore,
+    limit: Option<&'a str>,
+    popular: Option<&'a str>,
+}
+
+impl<'a> OnlineStoreDeprecated202101GetArticlesTagRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `popular` query parameter.
+    pub fn popular(mut self, popular: &'a str) -> Self {
+        self.popular = Some(popular);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            limit,
+            popular,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = popular {
+            query_args.push(("popular".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2021-01/articles/tags.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_unstable_get_blogs_param_blog_article`], set with a builder.
+pub struct OnlineStoreDeprecatedUnstableGetBlogsParamBlogArticleRequest<'a> {
+    client: &'a OnlineStore,
+    blog_id: &'a str,
+    limit: Option<&'a str>,
+    since_id: Option<&'a str>,
+    created_at_min: Option<&'a str>,
+    created_at_max: Option<&'a str>,
+    updated_at_min: Option<&'a str>,
+    updated_at_max: Option<&'a str>,
+    published_at_min: Option<&'a str>,
+    published_at_max: Option<&'a str>,
+    published_status: Option<&'a str>,
+    handle: Option<&'a str>,
+    tag: Option<&'a str>,
+    author: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> OnlineStoreDeprecatedUnstableGetBlogsParamBlogArticleRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.created_at_min = Some(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.created_at_max = Some(created_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.updated_at_min = Some(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.updated_at_max = Some(updated_at_max);
+        self
+    }
+
+    /// Sets the `published_at_min` query parameter.
+    pub fn published_at_min(mut self, published_at_min: &'a str) -> Self {
+        self.published_at_min = Some(published_at_min);
+        self
+    }
+
+    /// Sets the `published_at_max` query parameter.
+    pub fn published_at_max(mut self, published_at_max: &'a str) -> Self {
+        self.published_at_max = Some(published_at_max);
+        self
+    }
+
+    /// Sets the `published_status` query parameter.
+    pub fn published_status(mut self, published_status: &'a str) -> Self {
+        self.published_status = Some(published_status);
+        self
+    }
+
+    /// Sets the `handle` query parameter.
+    pub fn handle(mut self, handle: &'a str) -> Self {
+        self.handle = Some(handle);
+        self
+    }
+
+    /// Sets the `tag` query parameter.
+    pub fn tag(mut self, tag: &'a str) -> Self {
+        self.tag = Some(tag);
+        self
+    }
+
+    /// Sets the `author` query parameter.
+    pub fn author(mut self, author: &'a str) -> Self {
+        self.author = Some(author);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            blog_id,
+            limit,
+            since_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            published_status,
+            handle,
+            tag,
+            author,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = author {
+            query_args.push(("author".to_string(), v.to_string()));
+        }
+        if let Some(v) = created_at_max {
+            query_args.push(("created_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = created_at_min {
+            query_args.push(("created_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = handle {
+            query_args.push(("handle".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_at_max {
+            query_args.push(("published_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_at_min {
+            query_args.push(("published_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_status {
+            query_args.push(("published_status".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = tag {
+            query_args.push(("tag".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_max {
+            query_args.push(("updated_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_min {
+            query_args.push(("updated_at_min".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/unstable/blogs/{}/articles.json?{}",
+                crate::progenitor_support::encode_path(blog_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_unstable_get_blogs_param_blog_articles_count`], set with a builder.
+pub struct OnlineStoreDeprecatedUnstableGetBlogsParamBlogArticlesCountRequest<'a> {
+    client: &'a OnlineStore,
+    blog_id: &'a str,
+    created_at_min: Option<&'a str>,
+    created_at_max: Option<&'a str>,
+    updated_at_min: Option<&'a str>,
+    updated_at_max: Option<&'a str>,
+    published_at_min: Option<&'a str>,
+    published_at_max: Option<&'a str>,
+    published_status: Option<&'a str>,
+}
+
+impl<'a> OnlineStoreDeprecatedUnstableGetBlogsParamBlogArticlesCountRequest<'a> {
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.created_at_min = Some(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.created_at_max = Some(created_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.updated_at_min = Some(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.updated_at_max = Some(updated_at_max);
+        self
+    }
+
+    /// Sets the `published_at_min` query parameter.
+    pub fn published_at_min(mut self, published_at_min: &'a str) -> Self {
+        self.published_at_min = Some(published_at_min);
+        self
+    }
+
+    /// Sets the `published_at_max` query parameter.
+    pub fn published_at_max(mut self, published_at_max: &'a str) -> Self {
+        self.published_at_max = Some(published_at_max);
+        self
+    }
+
+    /// Sets the `published_status` query parameter.
+    pub fn published_status(mut self, published_status: &'a str) -> Self {
+        self.published_status = Some(published_status);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            blog_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            published_status,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = created_at_max {
+            query_args.push(("created_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = created_at_min {
+            query_args.push(("created_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_at_max {
+            query_args.push(("published_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_at_min {
+            query_args.push(("published_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_status {
+            query_args.push(("published_status".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_max {
+            query_args.push(("updated_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_min {
+            query_args.push(("updated_at_min".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/unstable/blogs/{}/articles/count.json?{}",
+                crate::progenitor_support::encode_path(blog_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_unstable_get_blogs_param_blog_articles_article`], set with a builder.
+pub struct OnlineStoreDeprecatedUnstableGetBlogsParamBlogArticlesArticleRequest<'a> {
+    client: &'a OnlineStore,
+    blog_id: &'a str,
+    article_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> OnlineStoreDeprecatedUnstableGetBlogsParamBlogArticlesArticleRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            blog_id,
+            article_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/unstable/blogs/{}/articles/{}/json?{}",
+                crate::progenitor_support::encode_path(blog_id),
+                crate::progenitor_support::encode_path(article_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_unstable_get_articles_tag`], set with a builder.
+pub struct OnlineStoreDeprecatedUnstableGetArticlesTagRequest<'a> {
+    client: &'a OnlineStore,
+    limit: Option<&'a str>,
+    popular: Option<&'a str>,
+}
+
+impl<'a> OnlineStoreDeprecatedUnstableGetArticlesTagRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `popular` query parameter.
+    pub fn popular(mut self, popular: &'a str) -> Self {
+        self.popular = Some(popular);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            limit,
+            popular,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = popular {
+            query_args.push(("popular".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/unstable/articles/tags.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202001_get_themes_param_theme_asset`], set with a builder.
+pub struct OnlineStoreDeprecated202001GetThemesParamThemeAssetRequest<'a> {
+    client: &'a OnlineStore,
+    theme_id: &'a str,
+    fields: Option<&'a str>,
+    asset_key: Option<&'a str>,
+}
+
+impl<'a> OnlineStoreDeprecated202001GetThemesParamThemeAssetRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `asset[key]` query parameter.
+    pub fn asset_key(mut self, asset_key: &'a str) -> Self {
+        self.asset_key = Some(asset_key);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            theme_id,
+            fields,
+            asset_key,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = asset_key {
+            query_args.push(("asset[key]".to_string(), v.to_string()));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-01/themes/{}/assets.json?{}",
+                crate::progenitor_support::encode_path(theme_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202001_update_themes_param_theme_assets`], set with a builder.
+pub struct OnlineStoreDeprecated202001UpdateThemesParamThemeAssetsRequest<'a> {
+    client: &'a OnlineStore,
+    theme_id: &'a str,
+    src: Option<&'a str>,
+    source_key: Option<&'a str>,
+    body: &'a serde_json::Value,
+}
+
+impl<'a> OnlineStoreDeprecated202001UpdateThemesParamThemeAssetsRequest<'a> {
+    /// Sets the `src` query parameter.
+    pub fn src(mut self, src: &'a str) -> Self {
+        self.src = Some(src);
+        self
+    }
+
+    /// Sets the `source_key` query parameter.
+    pub fn source_key(mut self, source_key: &'a str) -> Self {
+        self.source_key = Some(source_key);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            theme_id,
+            src,
+            source_key,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = source_key {
+            query_args.push(("source_key".to_string(), v.to_string()));
+        }
+        if let Some(v) = src {
+            query_args.push(("src".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-01/themes/{}/assets.json?{}",
+                crate::progenitor_support::encode_path(theme_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .put(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202001_delete_themes_param_theme_assets`], set with a builder.
+pub struct OnlineStoreDeprecated202001DeleteThemesParamThemeAssetsRequest<'a> {
+    client: &'a OnlineStore,
+    theme_id: &'a str,
+    asset_key: Option<&'a str>,
+}
+
+impl<'a> OnlineStoreDeprecated202001DeleteThemesParamThemeAssetsRequest<'a> {
+    /// Sets the `asset[key]` query parameter.
+    pub fn asset_key(mut self, asset_key: &'a str) -> Self {
+        self.asset_key = Some(asset_key);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            theme_id,
+            asset_key,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = asset_key {
+            query_args.push(("asset[key]".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-01/themes/{}/assets.json?{}",
+                crate::progenitor_support::encode_path(theme_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .delete(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202004_get_themes_param_theme_asset`], set with a builder.
+pub struct OnlineStoreDeprecated202004GetThemesParamThemeAssetRequest<'a> {
+    client: &'a OnlineStore,
+    theme_id: &'a str,
+    fields: Option<&'a str>,
+    asset_key: Option<&'a str>,
+}
+
+impl<'a> OnlineStoreDeprecated202004GetThemesParamThemeAssetRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `asset[key]` query parameter.
+    pub fn asset_key(mut self, asset_key: &'a str) -> Self {
+        self.asset_key = Some(asset_key);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            theme_id,
+            fields,
+            asset_key,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = asset_key {
+            query_args.push(("asset[key]".to_string(), v.to_string()));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-04/themes/{}/assets.json?{}",
+                crate::progenitor_support::encode_path(theme_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202004_update_themes_param_theme_assets`], set with a builder.
+pub struct OnlineStoreDeprecated202004UpdateThemesParamThemeAssetsRequest<'a> {
+    client: &'a OnlineStore,
+    theme_id: &'a str,
+    src: Option<&'a str>,
+    source_key: Option<&'a str>,
+    body: &'a serde_json::Value,
+}
+
+impl<'a> OnlineStoreDeprecated202004UpdateThemesParamThemeAssetsRequest<'a> {
+    /// Sets the `src` query parameter.
+    pub fn src(mut self, src: &'a str) -> Self {
+        self.src = Some(src);
+        self
+    }
+
+    /// Sets the `source_key` query parameter.
+    pub fn source_key(mut self, source_key: &'a str) -> Self {
+        self.source_key = Some(source_key);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            theme_id,
+            src,
+            source_key,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = source_key {
+            query_args.push(("source_key".to_string(), v.to_string()));
+        }
+        if let Some(v) = src {
+            query_args.push(("src".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-04/themes/{}/assets.json?{}",
+                crate::progenitor_support::encode_path(theme_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .put(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202004_delete_themes_param_theme_assets`], set with a builder.
+pub struct OnlineStoreDeprecated202004DeleteThemesParamThemeAssetsRequest<'a> {
+    client: &'a OnlineStore,
+    theme_id: &'a str,
+    asset_key: Option<&'a str>,
+}
+
+impl<'a> OnlineStoreDeprecated202004DeleteThemesParamThemeAssetsRequest<'a> {
+    /// Sets the `asset[key]` query parameter.
+    pub fn asset_key(mut self, asset_key: &'a str) -> Self {
+        self.asset_key = Some(asset_key);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            theme_id,
+            asset_key,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = asset_key {
+            query_args.push(("asset[key]".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-04/themes/{}/assets.json?{}",
+                crate::progenitor_support::encode_path(theme_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .delete(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202007_get_themes_param_theme_asset`], set with a builder.
+pub struct OnlineStoreDeprecated202007GetThemesParamThemeAssetRequest<'a> {
+    client: &'a OnlineStore,
+    theme_id: &'a str,
+    fields: Option<&'a str>,
+    asset_key: Option<&'a str>,
+}
+
+impl<'a> OnlineStoreDeprecated202007GetThemesParamThemeAssetRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `asset[key]` query parameter.
+    pub fn asset_key(mut self, asset_key: &'a str) -> Self {
+        self.asset_key = Some(asset_key);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            theme_id,
+            fields,
+            asset_key,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = asset_key {
+            query_args.push(("asset[key]".to_string(), v.to_string()));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-07/themes/{}/assets.json?{}",
+                crate::progenitor_support::encode_path(theme_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202007_update_themes_param_theme_assets`], set with a builder.
+pub struct OnlineStoreDeprecated202007UpdateThemesParamThemeAssetsRequest<'a> {
+    client: &'a OnlineStore,
+    theme_id: &'a str,
+    src: Option<&'a str>,
+    source_key: Option<&'a str>,
+    body: &'a serde_json::Value,
+}
+
+impl<'a> OnlineStoreDeprecated202007UpdateThemesParamThemeAssetsRequest<'a> {
+    /// Sets the `src` query parameter.
+    pub fn src(mut self, src: &'a str) -> Self {
+        self.src = Some(src);
+        self
+    }
+
+    /// Sets the `source_key` query parameter.
+    pub fn source_key(mut self, source_key: &'a str) -> Self {
+        self.source_key = Some(source_key);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            theme_id,
+            src,
+            source_key,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = source_key {
+            query_args.push(("source_key".to_string(), v.to_string()));
+        }
+        if let Some(v) = src {
+            query_args.push(("src".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-07/themes/{}/assets.json?{}",
+                crate::progenitor_support::encode_path(theme_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .put(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202007_delete_themes_param_theme_assets`], set with a builder.
+pub struct OnlineStoreDeprecated202007DeleteThemesParamThemeAssetsRequest<'a> {
+    client: &'a OnlineStore,
+    theme_id: &'a str,
+    asset_key: Option<&'a str>,
+}
+
+impl<'a> OnlineStoreDeprecated202007DeleteThemesParamThemeAssetsRequest<'a> {
+    /// Sets the `asset[key]` query parameter.
+    pub fn asset_key(mut self, asset_key: &'a str) -> Self {
+        self.asset_key = Some(asset_key);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            theme_id,
+            asset_key,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = asset_key {
+            query_args.push(("asset[key]".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-07/themes/{}/assets.json?{}",
+                crate::progenitor_support::encode_path(theme_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .delete(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`OnlineStore::get_themes_param_theme_asset`], set with a builder.
+pub struct OnlineStoreGetThemesParamThemeAssetRequest<'a> {
+    client: &'a OnlineStore,
+    theme_id: &'a str,
+    fields: Option<&'a str>,
+    asset_key: Option<&'a str>,
+}
+
+impl<'a> OnlineStoreGetThemesParamThemeAssetRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `asset[key]` query parameter.
+    pub fn asset_key(mut self, asset_key: &'a str) -> Self {
+        self.asset_key = Some(asset_key);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            theme_id,
+            fields,
+            asset_key,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = asset_key {
+            query_args.push(("asset[key]".to_string(), v.to_string()));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-10/themes/{}/assets.json?{}",
+                crate::progenitor_support::encode_path(theme_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`OnlineStore::update_themes_param_theme_assets`], set with a builder.
+pub struct OnlineStoreUpdateThemesParamThemeAssetsRequest<'a> {
+    client: &'a OnlineStore,
+    theme_id: &'a str,
+    src: Option<&'a str>,
+    source_key: Option<&'a str>,
+    body: &'a serde_json::Value,
+}
+
+impl<'a> OnlineStoreUpdateThemesParamThemeAssetsRequest<'a> {
+    /// Sets the `src` query parameter.
+    pub fn src(mut self, src: &'a str) -> Self {
+        self.src = Some(src);
+        self
+    }
+
+    /// Sets the `source_key` query parameter.
+    pub fn source_key(mut self, source_key: &'a str) -> Self {
+        self.source_key = Some(source_key);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            theme_id,
+            src,
+            source_key,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = source_key {
+            query_args.push(("source_key".to_string(), v.to_string()));
+        }
+        if let Some(v) = src {
+            query_args.push(("src".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-10/themes/{}/assets.json?{}",
+                crate::progenitor_support::encode_path(theme_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .put(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`OnlineStore::delete_themes_param_theme_assets`], set with a builder.
+pub struct OnlineStoreDeleteThemesParamThemeAssetsRequest<'a> {
+    client: &'a OnlineStore,
+    theme_id: &'a str,
+    asset_key: Option<&'a str>,
+}
+
+impl<'a> OnlineStoreDeleteThemesParamThemeAssetsRequest<'a> {
+    /// Sets the `asset[key]` query parameter.
+    pub fn asset_key(mut self, asset_key: &'a str) -> Self {
+        self.asset_key = Some(asset_key);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            theme_id,
+            asset_key,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = asset_key {
+            query_args.push(("asset[key]".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-10/themes/{}/assets.json?{}",
+                crate::progenitor_support::encode_path(theme_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .delete(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202101_get_themes_param_theme_asset`], set with a builder.
+pub struct OnlineStoreDeprecated202101GetThemesParamThemeAssetRequest<'a> {
+    client: &'a OnlineStore,
+    theme_id: &'a str,
+    fields: Option<&'a str>,
+    asset_key: Option<&'a str>,
+}
+
+impl<'a> OnlineStoreDeprecated202101GetThemesParamThemeAssetRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `asset[key]` query parameter.
+    pub fn asset_key(mut self, asset_key: &'a str) -> Self {
+        self.asset_key = Some(asset_key);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            theme_id,
+            fields,
+            asset_key,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = asset_key {
+            query_args.push(("asset[key]".to_string(), v.to_string()));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2021-01/themes/{}/assets.json?{}",
+                crate::progenitor_support::encode_path(theme_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202101_update_themes_param_theme_assets`], set with a builder.
+pub struct OnlineStoreDeprecated202101UpdateThemesParamThemeAssetsRequest<'a> {
+    client: &'a OnlineStore,
+    theme_id: &'a str,
+    src: Option<&'a str>,
+    source_key: Option<&'a str>,
+    body: &'a serde_json::Value,
+}
+
+impl<'a> OnlineStoreDeprecated202101UpdateThemesParamThemeAssetsRequest<'a> {
+    /// Sets the `src` query parameter.
+    pub fn src(mut self, src: &'a str) -> Self {
+        self.src = Some(src);
+        self
+    }
+
+    /// Sets the `source_key` query parameter.
+    pub fn source_key(mut self, source_key: &'a str) -> Self {
+        self.source_key = Some(source_key);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            theme_id,
+            src,
+            source_key,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = source_key {
+            query_args.push(("source_key".to_string(), v.to_string()));
+        }
+        if let Some(v) = src {
+            query_args.push(("src".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2021-01/themes/{}/assets.json?{}",
+                crate::progenitor_support::encode_path(theme_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .put(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202101_delete_themes_param_theme_assets`], set with a builder.
+pub struct OnlineStoreDeprecated202101DeleteThemesParamThemeAssetsRequest<'a> {
+    client: &'a OnlineStore,
+    theme_id: &'a str,
+    asset_key: Option<&'a str>,
+}
+
+impl<'a> OnlineStoreDeprecated202101DeleteThemesParamThemeAssetsRequest<'a> {
+    /// Sets the `asset[key]` query parameter.
+    pub fn asset_key(mut self, asset_key: &'a str) -> Self {
+        self.asset_key = Some(asset_key);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            theme_id,
+            asset_key,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = asset_key {
+            query_args.push(("asset[key]".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2021-01/themes/{}/assets.json?{}",
+                crate::progenitor_support::encode_path(theme_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .delete(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_unstable_get_themes_param_theme_asset`], set with a builder.
+pub struct OnlineStoreDeprecatedUnstableGetThemesParamThemeAssetRequest<'a> {
+    client: &'a OnlineStore,
+    theme_id: &'a str,
+    fields: Option<&'a str>,
+    asset_key: Option<&'a str>,
+}
+
+impl<'a> OnlineStoreDeprecatedUnstableGetThemesParamThemeAssetRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `asset[key]` query parameter.
+    pub fn asset_key(mut self, asset_key: &'a str) -> Self {
+        self.asset_key = Some(asset_key);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            theme_id,
+            fields,
+            asset_key,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = asset_key {
+            query_args.push(("asset[key]".to_string(), v.to_string()));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/unstable/themes/{}/assets.json?{}",
+                crate::progenitor_support::encode_path(theme_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_unstable_update_themes_param_theme_assets`], set with a builder.
+pub struct OnlineStoreDeprecatedUnstableUpdateThemesParamThemeAssetsRequest<'a> {
+    client: &'a OnlineStore,
+    theme_id: &'a str,
+    src: Option<&'a str>,
+    source_key: Option<&'a str>,
+    body: &'a serde_json::Value,
+}
+
+impl<'a> OnlineStoreDeprecatedUnstableUpdateThemesParamThemeAssetsRequest<'a> {
+    /// Sets the `src` query parameter.
+    pub fn src(mut self, src: &'a str) -> Self {
+        self.src = Some(src);
+        self
+    }
+
+    /// Sets the `source_key` query parameter.
+    pub fn source_key(mut self, source_key: &'a str) -> Self {
+        self.source_key = Some(source_key);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            theme_id,
+            src,
+            source_key,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = source_key {
+            query_args.push(("source_key".to_string(), v.to_string()));
+        }
+        if let Some(v) = src {
+            query_args.push(("src".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/unstable/themes/{}/assets.json?{}",
+                crate::progenitor_support::encode_path(theme_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .put(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_unstable_delete_themes_param_theme_assets`], set with a builder.
+pub struct OnlineStoreDeprecatedUnstableDeleteThemesParamThemeAssetsRequest<'a> {
+    client: &'a OnlineStore,
+    theme_id: &'a str,
+    asset_key: Option<&'a str>,
+}
+
+impl<'a> OnlineStoreDeprecatedUnstableDeleteThemesParamThemeAssetsRequest<'a> {
+    /// Sets the `asset[key]` query parameter.
+    pub fn asset_key(mut self, asset_key: &'a str) -> Self {
+        self.asset_key = Some(asset_key);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            theme_id,
+            asset_key,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = asset_key {
+            query_args.push(("asset[key]".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/unstable/themes/{}/assets.json?{}",
+                crate::progenitor_support::encode_path(theme_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .delete(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202001_get_blog`], set with a builder.
+pub struct OnlineStoreDeprecated202001GetBlogRequest<'a> {
+    client: &'a OnlineStore,
+    limit: Option<&'a str>,
+    since_id: Option<&'a str>,
+    handle: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> OnlineStoreDeprecated202001GetBlogRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `handle` query parameter.
+    pub fn handle(mut self, handle: &'a str) -> Self {
+        self.handle = Some(handle);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            limit,
+            since_id,
+            handle,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = handle {
+            query_args.push(("handle".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/admin/api/2020-01/blogs.json?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202001_get_blogs_param_blog`], set with a builder.
+pub struct OnlineStoreDeprecated202001GetBlogsParamBlogRequest<'a> {
+    client: &'a OnlineStore,
+    blog_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> OnlineStoreDeprecated202001GetBlogsParamBlogRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            blog_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-01/blogs/{}/json?{}",
+                crate::progenitor_support::encode_path(blog_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202001_get_comment`], set with a builder.
+pub struct OnlineStoreDeprecated202001GetCommentRequest<'a> {
+    client: &'a OnlineStore,
+    limit: Option<&'a str>,
+    since_id: Option<&'a str>,
+    created_at_min: Option<&'a str>,
+    created_at_max: Option<&'a str>,
+    updated_at_min: Option<&'a str>,
+    updated_at_max: Option<&'a str>,
+    published_at_min: Option<&'a str>,
+    published_at_max: Option<&'a str>,
+    fields: Option<&'a str>,
+    published_status: Option<&'a str>,
+    status: Option<&'a str>,
+    article_id: Option<i64>,
+    blog_id: Option<i64>,
+}
+
+impl<'a> OnlineStoreDeprecated202001GetCommentRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.created_at_min = Some(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.created_at_max = Some(created_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.updated_at_min = Some(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.updated_at_max = Some(updated_at_max);
+        self
+    }
+
+    /// Sets the `published_at_min` query parameter.
+    pub fn published_at_min(mut self, published_at_min: &'a str) -> Self {
+        self.published_at_min = Some(published_at_min);
+        self
+    }
+
+    /// Sets the `published_at_max` query parameter.
+    pub fn published_at_max(mut self, published_at_max: &'a str) -> Self {
+        self.published_at_max = Some(published_at_max);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `published_status` query parameter.
+    pub fn published_status(mut self, published_status: &'a str) -> Self {
+        self.published_status = Some(published_status);
+        self
+    }
+
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: &'a str) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Sets the `article_id` query parameter.
+    pub fn article_id(mut self, article_id: i64) -> Self {
+        self.article_id = Some(article_id);
+        self
+    }
+
+    /// Sets the `blog_id` query parameter.
+    pub fn blog_id(mut self, blog_id: i64) -> Self {
+        self.blog_id = Some(blog_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            limit,
+            since_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            fields,
+            published_status,
+            status,
+            article_id,
+            blog_id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = article_id {
+            query_args.push(("article_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = blog_id {
+            query_args.push(("blog_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = created_at_max {
+            query_args.push(("created_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = created_at_min {
+            query_args.push(("created_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_at_max {
+            query_args.push(("published_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_at_min {
+            query_args.push(("published_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_status {
+            query_args.push(("published_status".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = status {
+            query_args.push(("status".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_max {
+            query_args.push(("updated_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_min {
+            query_args.push(("updated_at_min".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-01/comments.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202001_get_comments_count`], set with a builder.
+pub struct OnlineStoreDeprecated202001GetCommentsCountRequest<'a> {
+    client: &'a OnlineStore,
+    created_at_min: Option<&'a str>,
+    created_at_max: Option<&'a str>,
+    updated_at_min: Option<&'a str>,
+    updated_at_max: Option<&'a str>,
+    published_at_min: Option<&'a str>,
+    published_at_max: Option<&'a str>,
+    published_status: Option<&'a str>,
+    status: Option<&'a str>,
+    article_id: Option<i64>,
+    blog_id: Option<i64>,
+}
+
+impl<'a> OnlineStoreDeprecated202001GetCommentsCountRequest<'a> {
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.created_at_min = Some(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.created_at_max = Some(created_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.updated_at_min = Some(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.updated_at_max = Some(updated_at_max);
+        self
+    }
+
+    /// Sets the `published_at_min` query parameter.
+    pub fn published_at_min(mut self, published_at_min: &'a str) -> Self {
+        self.published_at_min = Some(published_at_min);
+        self
+    }
+
+    /// Sets the `published_at_max` query parameter.
+    pub fn published_at_max(mut self, published_at_max: &'a str) -> Self {
+        self.published_at_max = Some(published_at_max);
+        self
+    }
+
+    /// Sets the `published_status` query parameter.
+    pub fn published_status(mut self, published_status: &'a str) -> Self {
+        self.published_status = Some(published_status);
+        self
+    }
+
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: &'a str) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Sets the `article_id` query parameter.
+    pub fn article_id(mut self, article_id: i64) -> Self {
+        self.article_id = Some(article_id);
+        self
+    }
+
+    /// Sets the `blog_id` query parameter.
+    pub fn blog_id(mut self, blog_id: i64) -> Self {
+        self.blog_id = Some(blog_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            published_status,
+            status,
+            article_id,
+            blog_id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = article_id {
+            query_args.push(("article_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = blog_id {
+            query_args.push(("blog_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = created_at_max {
+            query_args.push(("created_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = created_at_min {
+            query_args.push(("created_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_at_max {
+            query_args.push(("published_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_at_min {
+            query_args.push(("published_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_status {
+            query_args.push(("published_status".to_string(), v.to_string()));
+        }
+        if let Some(v) = status {
+            query_args.push(("status".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_max {
+            query_args.push(("updated_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_min {
+            query_args.push(("updated_at_min".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-01/comments/count.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202001_get_comments_param_comment`], set with a builder.
+pub struct OnlineStoreDeprecated202001GetCommentsParamCommentRequest<'a> {
+    client: &'a OnlineStore,
+    comment_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> OnlineStoreDeprecated202001GetCommentsParamCommentRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            comment_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-01/comments/{}/json?{}",
+                crate::progenitor_support::encode_path(comment_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202001_get_redirect`], set with a builder.
+pub struct OnlineStoreDeprecated202001GetRedirectRequest<'a> {
+    client: &'a OnlineStore,
+    limit: Option<&'a str>,
+    since_id: Option<&'a str>,
+    path: Option<&'a str>,
+    target: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> OnlineStoreDeprecated202001GetRedirectRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `path` query parameter.
+    pub fn path(mut self, path: &'a str) -> Self {
+        self.path = Some(path);
+        self
+    }
+
+    /// Sets the `target` query parameter.
+    pub fn target(mut self, target: &'a str) -> Self {
+        self.target = Some(target);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            limit,
+            since_id,
+            path,
+            target,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = path {
+            query_args.push(("path".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = target {
+            query_args.push(("target".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-01/redirects.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202001_get_redirects_count`], set with a builder.
+pub struct OnlineStoreDeprecated202001GetRedirectsCountRequest<'a> {
+    client: &'a OnlineStore,
+    path: Option<&'a str>,
+    target: Option<&'a str>,
+}
+
+impl<'a> OnlineStoreDeprecated202001GetRedirectsCountRequest<'a> {
+    /// Sets the `path` query parameter.
+    pub fn path(mut self, path: &'a str) -> Self {
+        self.path = Some(path);
+        self
+    }
+
+    /// Sets the `target` query parameter.
+    pub fn target(mut self, target: &'a str) -> Self {
+        self.target = Some(target);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            path,
+            target,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = path {
+            query_args.push(("path".to_string(), v.to_string()));
+        }
+        if let Some(v) = target {
+            query_args.push(("target".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-01/redirects/count.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202001_get_redirects_param_redirect`], set with a builder.
+pub struct OnlineStoreDeprecated202001GetRedirectsParamRedirectRequest<'a> {
+    client: &'a OnlineStore,
+    redirect_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> OnlineStoreDeprecated202001GetRedirectsParamRedirectRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            redirect_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-01/redirects/{}/json?{}",
+                crate::progenitor_support::encode_path(redirect_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202004_get_redirect`], set with a builder.
+pub struct OnlineStoreDeprecated202004GetRedirectRequest<'a> {
+    client: &'a OnlineStore,
+    limit: Option<&'a str>,
+    since_id: Option<&'a str>,
+    path: Option<&'a str>,
+    target: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> OnlineStoreDeprecated202004GetRedirectRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `path` query parameter.
+    pub fn path(mut self, path: &'a str) -> Self {
+        self.path = Some(path);
+        self
+    }
+
+    /// Sets the `target` query parameter.
+    pub fn target(mut self, target: &'a str) -> Self {
+        self.target = Some(target);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            limit,
+            since_id,
+            path,
+            target,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = path {
+            query_args.push(("path".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = target {
+            query_args.push(("target".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-04/redirects.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202004_get_redirects_count`], set with a builder.
+pub struct OnlineStoreDeprecated202004GetRedirectsCountRequest<'a> {
+    client: &'a OnlineStore,
+    path: Option<&'a str>,
+    target: Option<&'a str>,
+}
+
+impl<'a> OnlineStoreDeprecated202004GetRedirectsCountRequest<'a> {
+    /// Sets the `path` query parameter.
+    pub fn path(mut self, path: &'a str) -> Self {
+        self.path = Some(path);
+        self
+    }
+
+    /// Sets the `target` query parameter.
+    pub fn target(mut self, target: &'a str) -> Self {
+        self.target = Some(target);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            path,
+            target,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = path {
+            query_args.push(("path".to_string(), v.to_string()));
+        }
+        if let Some(v) = target {
+            query_args.push(("target".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-04/redirects/count.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202004_get_redirects_param_redirect`], set with a builder.
+pub struct OnlineStoreDeprecated202004GetRedirectsParamRedirectRequest<'a> {
+    client: &'a OnlineStore,
+    redirect_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> OnlineStoreDeprecated202004GetRedirectsParamRedirectRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            redirect_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-04/redirects/{}/json?{}",
+                crate::progenitor_support::encode_path(redirect_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202007_get_redirect`], set with a builder.
+pub struct OnlineStoreDeprecated202007GetRedirectRequest<'a> {
+    client: &'a OnlineStore,
+    limit: Option<&'a str>,
+    since_id: Option<&'a str>,
+    path: Option<&'a str>,
+    target: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> OnlineStoreDeprecated202007GetRedirectRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `path` query parameter.
+    pub fn path(mut self, path: &'a str) -> Self {
+        self.path = Some(path);
+        self
+    }
+
+    /// Sets the `target` query parameter.
+    pub fn target(mut self, target: &'a str) -> Self {
+        self.target = Some(target);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            limit,
+            since_id,
+            path,
+            target,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = path {
+            query_args.push(("path".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = target {
+            query_args.push(("target".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-07/redirects.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202007_get_redirects_count`], set with a builder.
+pub struct OnlineStoreDeprecated202007GetRedirectsCountRequest<'a> {
+    client: &'a OnlineStore,
+    path: Option<&'a str>,
+    target: Option<&'a str>,
+}
+
+impl<'a> OnlineStoreDeprecated202007GetRedirectsCountRequest<'a> {
+    /// Sets the `path` query parameter.
+    pub fn path(mut self, path: &'a str) -> Self {
+        self.path = Some(path);
+        self
+    }
+
+    /// Sets the `target` query parameter.
+    pub fn target(mut self, target: &'a str) -> Self {
+        self.target = Some(target);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            path,
+            target,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = path {
+            query_args.push(("path".to_string(), v.to_string()));
+        }
+        if let Some(v) = target {
+            query_args.push(("target".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-07/redirects/count.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202007_get_redirects_param_redirect`], set with a builder.
+pub struct OnlineStoreDeprecated202007GetRedirectsParamRedirectRequest<'a> {
+    client: &'a OnlineStore,
+    redirect_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> OnlineStoreDeprecated202007GetRedirectsParamRedirectRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            redirect_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-07/redirects/{}/json?{}",
+                crate::progenitor_support::encode_path(redirect_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`OnlineStore::get_redirect`], set with a builder.
+pub struct OnlineStoreGetRedirectRequest<'a> {
+    client: &'a OnlineStore,
+    limit: Option<&'a str>,
+    since_id: Option<&'a str>,
+    path: Option<&'a str>,
+    target: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> OnlineStoreGetRedirectRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `path` query parameter.
+    pub fn path(mut self, path: &'a str) -> Self {
+        self.path = Some(path);
+        self
+    }
+
+    /// Sets the `target` query parameter.
+    pub fn target(mut self, target: &'a str) -> Self {
+        self.target = Some(target);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            limit,
+            since_id,
+            path,
+            target,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = path {
+            query_args.push(("path".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = target {
+            query_args.push(("target".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-10/redirects.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`OnlineStore::get_redirects_count`], set with a builder.
+pub struct OnlineStoreGetRedirectsCountRequest<'a> {
+    client: &'a OnlineStore,
+    path: Option<&'a str>,
+    target: Option<&'a str>,
+}
+
+impl<'a> OnlineStoreGetRedirectsCountRequest<'a> {
+    /// Sets the `path` query parameter.
+    pub fn path(mut self, path: &'a str) -> Self {
+        self.path = Some(path);
+        self
+    }
+
+    /// Sets the `target` query parameter.
+    pub fn target(mut self, target: &'a str) -> Self {
+        self.target = Some(target);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            path,
+            target,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = path {
+            query_args.push(("path".to_string(), v.to_string()));
+        }
+        if let Some(v) = target {
+            query_args.push(("target".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-10/redirects/count.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`OnlineStore::get_redirects_param_redirect`], set with a builder.
+pub struct OnlineStoreGetRedirectsParamRedirectRequest<'a> {
+    client: &'a OnlineStore,
+    redirect_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> OnlineStoreGetRedirectsParamRedirectRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            redirect_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-10/redirects/{}/json?{}",
+                crate::progenitor_support::encode_path(redirect_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202101_get_redirect`], set with a builder.
+pub struct OnlineStoreDeprecated202101GetRedirectRequest<'a> {
+    client: &'a OnlineStore,
+    limit: Option<&'a str>,
+    since_id: Option<&'a str>,
+    path: Option<&'a str>,
+    target: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> OnlineStoreDeprecated202101GetRedirectRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `path` query parameter.
+    pub fn path(mut self, path: &'a str) -> Self {
+        self.path = Some(path);
+        self
+    }
+
+    /// Sets the `target` query parameter.
+    pub fn target(mut self, target: &'a str) -> Self {
+        self.target = Some(target);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            limit,
+            since_id,
+            path,
+            target,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = path {
+            query_args.push(("path".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = target {
+            query_args.push(("target".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2021-01/redirects.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202101_get_redirects_count`], set with a builder.
+pub struct OnlineStoreDeprecated202101GetRedirectsCountRequest<'a> {
+    client: &'a OnlineStore,
+    path: Option<&'a str>,
+    target: Option<&'a str>,
+}
+
+impl<'a> OnlineStoreDeprecated202101GetRedirectsCountRequest<'a> {
+    /// Sets the `path` query parameter.
+    pub fn path(mut self, path: &'a str) -> Self {
+        self.path = Some(path);
+        self
+    }
+
+    /// Sets the `target` query parameter.
+    pub fn target(mut self, target: &'a str) -> Self {
+        self.target = Some(target);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            path,
+            target,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = path {
+            query_args.push(("path".to_string(), v.to_string()));
+        }
+        if let Some(v) = target {
+            query_args.push(("target".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2021-01/redirects/count.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202101_get_redirects_param_redirect`], set with a builder.
+pub struct OnlineStoreDeprecated202101GetRedirectsParamRedirectRequest<'a> {
+    client: &'a OnlineStore,
+    redirect_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> OnlineStoreDeprecated202101GetRedirectsParamRedirectRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            redirect_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2021-01/redirects/{}/json?{}",
+                crate::progenitor_support::encode_path(redirect_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_unstable_get_redirect`], set with a builder.
+pub struct OnlineStoreDeprecatedUnstableGetRedirectRequest<'a> {
+    client: &'a OnlineStore,
+    limit: Option<&'a str>,
+    since_id: Option<&'a str>,
+    path: Option<&'a str>,
+    target: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> OnlineStoreDeprecatedUnstableGetRedirectRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `path` query parameter.
+    pub fn path(mut self, path: &'a str) -> Self {
+        self.path = Some(path);
+        self
+    }
+
+    /// Sets the `target` query parameter.
+    pub fn target(mut self, target: &'a str) -> Self {
+        self.target = Some(target);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            limit,
+            since_id,
+            path,
+            target,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = path {
+            query_args.push(("path".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = target {
+            query_args.push(("target".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/unstable/redirects.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_unstable_get_redirects_count`], set with a builder.
+pub struct OnlineStoreDeprecatedUnstableGetRedirectsCountRequest<'a> {
+    client: &'a OnlineStore,
+    path: Option<&'a str>,
+    target: Option<&'a str>,
+}
+
+impl<'a> OnlineStoreDeprecatedUnstableGetRedirectsCountRequest<'a> {
+    /// Sets the `path` query parameter.
+    pub fn path(mut self, path: &'a str) -> Self {
+        self.path = Some(path);
+        self
+    }
+
+    /// Sets the `target` query parameter.
+    pub fn target(mut self, target: &'a str) -> Self {
+        self.target = Some(target);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            path,
+            target,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = path {
+            query_args.push(("path".to_string(), v.to_string()));
+        }
+        if let Some(v) = target {
+            query_args.push(("target".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/unstable/redirects/count.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_unstable_get_redirects_param_redirect`], set with a builder.
+pub struct OnlineStoreDeprecatedUnstableGetRedirectsParamRedirectRequest<'a> {
+    client: &'a OnlineStore,
+    redirect_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> OnlineStoreDeprecatedUnstableGetRedirectsParamRedirectRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            redirect_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/unstable/redirects/{}/json?{}",
+                crate::progenitor_support::encode_path(redirect_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202001_get_script_tag`], set with a builder.
+pub struct OnlineStoreDeprecated202001GetScriptTagRequest<'a> {
+    client: &'a OnlineStore,
+    limit: Option<&'a str>,
+    since_id: Option<&'a str>,
+    created_at_min: Option<&'a str>,
+    created_at_max: Option<&'a str>,
+    updated_at_min: Option<&'a str>,
+    updated_at_max: Option<&'a str>,
+    src: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> OnlineStoreDeprecated202001GetScriptTagRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.created_at_min = Some(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.created_at_max = Some(created_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.updated_at_min = Some(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.updated_at_max = Some(updated_at_max);
+        self
+    }
+
+    /// Sets the `src` query parameter.
+    pub fn src(mut self, src: &'a str) -> Self {
+        self.src = Some(src);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            limit,
+            since_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            src,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = created_at_max {
+            query_args.push(("created_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = created_at_min {
+            query_args.push(("created_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = src {
+            query_args.push(("src".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_max {
+            query_args.push(("updated_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_min {
+            query_args.push(("updated_at_min".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-01/script_tags.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202001_get_script_tags_count`], set with a builder.
+pub struct OnlineStoreDeprecated202001GetScriptTagsCountRequest<'a> {
+    client: &'a OnlineStore,
+    src: Option<&'a str>,
+}
+
+impl<'a> OnlineStoreDeprecated202001GetScriptTagsCountRequest<'a> {
+    /// Sets the `src` query parameter.
+    pub fn src(mut self, src: &'a str) -> Self {
+        self.src = Some(src);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self { client, src } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = src {
+            query_args.push(("src".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-01/script_tags/count.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202001_get_script_tags_param_tag`], set with a builder.
+pub struct OnlineStoreDeprecated202001GetScriptTagsParamTagRequest<'a> {
+    client: &'a OnlineStore,
+    script_tag_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> OnlineStoreDeprecated202001GetScriptTagsParamTagRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            script_tag_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-01/script_tags/{}/json?{}",
+                crate::progenitor_support::encode_path(script_tag_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202004_get_script_tag`], set with a builder.
+pub struct OnlineStoreDeprecated202004GetScriptTagRequest<'a> {
+    client: &'a OnlineStore,
+    limit: Option<&'a str>,
+    since_id: Option<&'a str>,
+    created_at_min: Option<&'a str>,
+    created_at_max: Option<&'a str>,
+    updated_at_min: Option<&'a str>,
+    updated_at_max: Option<&'a str>,
+    src: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> OnlineStoreDeprecated202004GetScriptTagRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.created_at_min = Some(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.created_at_max = Some(created_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.updated_at_min = Some(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.updated_at_max = Some(updated_at_max);
+        self
+    }
+
+    /// Sets the `src` query parameter.
+    pub fn src(mut self, src: &'a str) -> Self {
+        self.src = Some(src);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            limit,
+            since_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            src,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = created_at_max {
+            query_args.push(("created_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = created_at_min {
+            query_args.push(("created_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = src {
+            query_args.push(("src".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_max {
+            query_args.push(("updated_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_min {
+            query_args.push(("updated_at_min".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-04/script_tags.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202004_get_script_tags_count`], set with a builder.
+pub struct OnlineStoreDeprecated202004GetScriptTagsCountRequest<'a> {
+    client: &'a OnlineStore,
+    src: Option<&'a str>,
+}
+
+impl<'a> OnlineStoreDeprecated202004GetScriptTagsCountRequest<'a> {
+    /// Sets the `src` query parameter.
+    pub fn src(mut self, src: &'a str) -> Self {
+        self.src = Some(src);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self { client, src } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = src {
+            query_args.push(("src".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-04/script_tags/count.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202004_get_script_tags_param_tag`], set with a builder.
+pub struct OnlineStoreDeprecated202004GetScriptTagsParamTagRequest<'a> {
+    client: &'a OnlineStore,
+    script_tag_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> OnlineStoreDeprecated202004GetScriptTagsParamTagRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            script_tag_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-04/script_tags/{}/json?{}",
+                crate::progenitor_support::encode_path(script_tag_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202007_get_script_tag`], set with a builder.
+pub struct OnlineStoreDeprecated202007GetScriptTagRequest<'a> {
+    client: &'a OnlineStore,
+    limit: Option<&'a str>,
+    since_id: Option<&'a str>,
+    created_at_min: Option<&'a str>,
+    created_at_max: Option<&'a str>,
+    updated_at_min: Option<&'a str>,
+    updated_at_max: Option<&'a str>,
+    src: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> OnlineStoreDeprecated202007GetScriptTagRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.created_at_min = Some(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.created_at_max = Some(created_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.updated_at_min = Some(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.updated_at_max = Some(updated_at_max);
+        self
+    }
+
+    /// Sets the `src` query parameter.
+    pub fn src(mut self, src: &'a str) -> Self {
+        self.src = Some(src);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            limit,
+            since_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            src,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = created_at_max {
+            query_args.push(("created_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = created_at_min {
+            query_args.push(("created_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = src {
+            query_args.push(("src".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_max {
+            query_args.push(("updated_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_min {
+            query_args.push(("updated_at_min".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-07/script_tags.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202007_get_script_tags_count`], set with a builder.
+pub struct OnlineStoreDeprecated202007GetScriptTagsCountRequest<'a> {
+    client: &'a OnlineStore,
+    src: Option<&'a str>,
+}
+
+impl<'a> OnlineStoreDeprecated202007GetScriptTagsCountRequest<'a> {
+    /// Sets the `src` query parameter.
+    pub fn src(mut self, src: &'a str) -> Self {
+        self.src = Some(src);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self { client, src } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = src {
+            query_args.push(("src".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-07/script_tags/count.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202007_get_script_tags_param_tag`], set with a builder.
+pub struct OnlineStoreDeprecated202007GetScriptTagsParamTagRequest<'a> {
+    client: &'a OnlineStore,
+    script_tag_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> OnlineStoreDeprecated202007GetScriptTagsParamTagRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            script_tag_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-07/script_tags/{}/json?{}",
+                crate::progenitor_support::encode_path(script_tag_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`OnlineStore::get_script_tag`], set with a builder.
+pub struct OnlineStoreGetScriptTagRequest<'a> {
+    client: &'a OnlineStore,
+    limit: Option<&'a str>,
+    since_id: Option<&'a str>,
+    created_at_min: Option<&'a str>,
+    created_at_max: Option<&'a str>,
+    updated_at_min: Option<&'a str>,
+    updated_at_max: Option<&'a str>,
+    src: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> OnlineStoreGetScriptTagRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.created_at_min = Some(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.created_at_max = Some(created_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.updated_at_min = Some(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.updated_at_max = Some(updated_at_max);
+        self
+    }
+
+    /// Sets the `src` query parameter.
+    pub fn src(mut self, src: &'a str) -> Self {
+        self.src = Some(src);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            limit,
+            since_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            src,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = created_at_max {
+            query_args.push(("created_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = created_at_min {
+            query_args.push(("created_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = src {
+            query_args.push(("src".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_max {
+            query_args.push(("updated_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_min {
+            query_args.push(("updated_at_min".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-10/script_tags.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`OnlineStore::get_script_tags_count`], set with a builder.
+pub struct OnlineStoreGetScriptTagsCountRequest<'a> {
+    client: &'a OnlineStore,
+    src: Option<&'a str>,
+}
+
+impl<'a> OnlineStoreGetScriptTagsCountRequest<'a> {
+    /// Sets the `src` query parameter.
+    pub fn src(mut self, src: &'a str) -> Self {
+        self.src = Some(src);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self { client, src } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = src {
+            query_args.push(("src".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-10/script_tags/count.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`OnlineStore::get_script_tags_param_tag`], set with a builder.
+pub struct OnlineStoreGetScriptTagsParamTagRequest<'a> {
+    client: &'a OnlineStore,
+    script_tag_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> OnlineStoreGetScriptTagsParamTagRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            script_tag_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-10/script_tags/{}/json?{}",
+                crate::progenitor_support::encode_path(script_tag_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202101_get_script_tag`], set with a builder.
+pub struct OnlineStoreDeprecated202101GetScriptTagRequest<'a> {
+    client: &'a OnlineStore,
+    limit: Option<&'a str>,
+    since_id: Option<&'a str>,
+    created_at_min: Option<&'a str>,
+    created_at_max: Option<&'a str>,
+    updated_at_min: Option<&'a str>,
+    updated_at_max: Option<&'a str>,
+    src: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> OnlineStoreDeprecated202101GetScriptTagRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.created_at_min = Some(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.created_at_max = Some(created_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.updated_at_min = Some(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.updated_at_max = Some(updated_at_max);
+        self
+    }
+
+    /// Sets the `src` query parameter.
+    pub fn src(mut self, src: &'a str) -> Self {
+        self.src = Some(src);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            limit,
+            since_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            src,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = created_at_max {
+            query_args.push(("created_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = created_at_min {
+            query_args.push(("created_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = src {
+            query_args.push(("src".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_max {
+            query_args.push(("updated_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_min {
+            query_args.push(("updated_at_min".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2021-01/script_tags.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202101_get_script_tags_count`], set with a builder.
+pub struct OnlineStoreDeprecated202101GetScriptTagsCountRequest<'a> {
+    client: &'a OnlineStore,
+    src: Option<&'a str>,
+}
+
+impl<'a> OnlineStoreDeprecated202101GetScriptTagsCountRequest<'a> {
+    /// Sets the `src` query parameter.
+    pub fn src(mut self, src: &'a str) -> Self {
+        self.src = Some(src);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self { client, src } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = src {
+            query_args.push(("src".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2021-01/script_tags/count.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202101_get_script_tags_param_tag`], set with a builder.
+pub struct OnlineStoreDeprecated202101GetScriptTagsParamTagRequest<'a> {
+    client: &'a OnlineStore,
+    script_tag_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> OnlineStoreDeprecated202101GetScriptTagsParamTagRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            script_tag_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2021-01/script_tags/{}/json?{}",
+                crate::progenitor_support::encode_path(script_tag_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_unstable_get_script_tag`], set with a builder.
+pub struct OnlineStoreDeprecatedUnstableGetScriptTagRequest<'a> {
+    client: &'a OnlineStore,
+    limit: Option<&'a str>,
+    since_id: Option<&'a str>,
+    created_at_min: Option<&'a str>,
+    created_at_max: Option<&'a str>,
+    updated_at_min: Option<&'a str>,
+    updated_at_max: Option<&'a str>,
+    src: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> OnlineStoreDeprecatedUnstableGetScriptTagRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.created_at_min = Some(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.created_at_max = Some(created_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.updated_at_min = Some(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.updated_at_max = Some(updated_at_max);
+        self
+    }
+
+    /// Sets the `src` query parameter.
+    pub fn src(mut self, src: &'a str) -> Self {
+        self.src = Some(src);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            limit,
+            since_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            src,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = created_at_max {
+            query_args.push(("created_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = created_at_min {
+            query_args.push(("created_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = src {
+            query_args.push(("src".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_max {
+            query_args.push(("updated_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_min {
+            query_args.push(("updated_at_min".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/unstable/script_tags.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_unstable_get_script_tags_count`], set with a builder.
+pub struct OnlineStoreDeprecatedUnstableGetScriptTagsCountRequest<'a> {
+    client: &'a OnlineStore,
+    src: Option<&'a str>,
+}
+
+impl<'a> OnlineStoreDeprecatedUnstableGetScriptTagsCountRequest<'a> {
+    /// Sets the `src` query parameter.
+    pub fn src(mut self, src: &'a str) -> Self {
+        self.src = Some(src);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self { client, src } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = src {
+            query_args.push(("src".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/unstable/script_tags/count.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_unstable_get_script_tags_param_tag`], set with a builder.
+pub struct OnlineStoreDeprecatedUnstableGetScriptTagsParamTagRequest<'a> {
+    client: &'a OnlineStore,
+    script_tag_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> OnlineStoreDeprecatedUnstableGetScriptTagsParamTagRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            script_tag_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/unstable/script_tags/{}/json?{}",
+                crate::progenitor_support::encode_path(script_tag_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202001_get_theme`], set with a builder.
+pub struct OnlineStoreDeprecated202001GetThemeRequest<'a> {
+    client: &'a OnlineStore,
+    fields: Option<&'a str>,
+}
+
+impl<'a> OnlineStoreDeprecated202001GetThemeRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self { client, fields } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/admin/api/2020-01/themes.json?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202001_get_themes_param_theme`], set with a builder.
+pub struct OnlineStoreDeprecated202001GetThemesParamThemeRequest<'a> {
+    client: &'a OnlineStore,
+    theme_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> OnlineStoreDeprecated202001GetThemesParamThemeRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            theme_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-01/themes/{}/json?{}",
+                crate::progenitor_support::encode_path(theme_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202004_get_theme`], set with a builder.
+pub struct OnlineStoreDeprecated202004GetThemeRequest<'a> {
+    client: &'a OnlineStore,
+    fields: Option<&'a str>,
+}
+
+impl<'a> OnlineStoreDeprecated202004GetThemeRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self { client, fields } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/admin/api/2020-04/themes.json?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202004_get_themes_param_theme`], set with a builder.
+pub struct OnlineStoreDeprecated202004GetThemesParamThemeRequest<'a> {
+    client: &'a OnlineStore,
+    theme_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> OnlineStoreDeprecated202004GetThemesParamThemeRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            theme_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-04/themes/{}/json?{}",
+                crate::progenitor_support::encode_path(theme_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202007_get_theme`], set with a builder.
+pub struct OnlineStoreDeprecated202007GetThemeRequest<'a> {
+    client: &'a OnlineStore,
+    fields: Option<&'a str>,
+}
+
+impl<'a> OnlineStoreDeprecated202007GetThemeRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self { client, fields } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/admin/api/2020-07/themes.json?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202007_get_themes_param_theme`], set with a builder.
+pub struct OnlineStoreDeprecated202007GetThemesParamThemeRequest<'a> {
+    client: &'a OnlineStore,
+    theme_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> OnlineStoreDeprecated202007GetThemesParamThemeRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            theme_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-07/themes/{}/json?{}",
+                crate::progenitor_support::encode_path(theme_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`OnlineStore::get_theme`], set with a builder.
+pub struct OnlineStoreGetThemeRequest<'a> {
+    client: &'a OnlineStore,
+    fields: Option<&'a str>,
+}
+
+impl<'a> OnlineStoreGetThemeRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self { client, fields } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/admin/api/2020-10/themes.json?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`OnlineStore::get_themes_param_theme`], set with a builder.
+pub struct OnlineStoreGetThemesParamThemeRequest<'a> {
+    client: &'a OnlineStore,
+    theme_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> OnlineStoreGetThemesParamThemeRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            theme_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-10/themes/{}/json?{}",
+                crate::progenitor_support::encode_path(theme_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202101_get_theme`], set with a builder.
+pub struct OnlineStoreDeprecated202101GetThemeRequest<'a> {
+    client: &'a OnlineStore,
+    fields: Option<&'a str>,
+}
+
+impl<'a> OnlineStoreDeprecated202101GetThemeRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self { client, fields } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/admin/api/2021-01/themes.json?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_202101_get_themes_param_theme`], set with a builder.
+pub struct OnlineStoreDeprecated202101GetThemesParamThemeRequest<'a> {
+    client: &'a OnlineStore,
+    theme_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> OnlineStoreDeprecated202101GetThemesParamThemeRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            theme_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2021-01/themes/{}/json?{}",
+                crate::progenitor_support::encode_path(theme_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_unstable_get_theme`], set with a builder.
+pub struct OnlineStoreDeprecatedUnstableGetThemeRequest<'a> {
+    client: &'a OnlineStore,
+    fields: Option<&'a str>,
+}
+
+impl<'a> OnlineStoreDeprecatedUnstableGetThemeRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self { client, fields } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/admin/api/unstable/themes.json?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`OnlineStore::deprecated_unstable_get_themes_param_theme`], set with a builder.
+pub struct OnlineStoreDeprecatedUnstableGetThemesParamThemeRequest<'a> {
+    client: &'a OnlineStore,
+    theme_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> OnlineStoreDeprecatedUnstableGetThemesParamThemeRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            theme_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/unstable/themes/{}/json?{}",
+                crate::progenitor_support::encode_path(theme_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct OnlineStore {
     pub client: Client,
 }
@@ -117,6 +6872,37 @@ impl OnlineStore {
             )
             .await
     }
+    /**
+     * Retrieves a list of all articles from a blog. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-01/blogs/{blog_id}/articles.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_get_blogs_param_blog_article`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/article#index-2020-01
+     */
+    pub fn deprecated_202001_get_blogs_param_blog_article_request<'a>(
+        &'a self,
+        blog_id: &'a str,
+    ) -> OnlineStoreDeprecated202001GetBlogsParamBlogArticleRequest<'a> {
+        OnlineStoreDeprecated202001GetBlogsParamBlogArticleRequest {
+            client: self,
+            blog_id,
+            limit: None,
+            since_id: None,
+            created_at_min: None,
+            created_at_max: None,
+            updated_at_min: None,
+            updated_at_max: None,
+            published_at_min: None,
+            published_at_max: None,
+            published_status: None,
+            handle: None,
+            tag: None,
+            author: None,
+            fields: None,
+        }
+    }
     /**
      * Creates an article for a blog.
      *
@@ -225,6 +7011,31 @@ impl OnlineStore {
             )
             .await
     }
+    /**
+     * Retrieves a count of all articles from a blog.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-01/blogs/{blog_id}/articles/count.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_get_blogs_param_blog_articles_count`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/article#count-2020-01
+     */
+    pub fn deprecated_202001_get_blogs_param_blog_articles_count_request<'a>(
+        &'a self,
+        blog_id: &'a str,
+    ) -> OnlineStoreDeprecated202001GetBlogsParamBlogArticlesCountRequest<'a> {
+        OnlineStoreDeprecated202001GetBlogsParamBlogArticlesCountRequest {
+            client: self,
+            blog_id,
+            created_at_min: None,
+            created_at_max: None,
+            updated_at_min: None,
+            updated_at_max: None,
+            published_at_min: None,
+            published_at_max: None,
+            published_status: None,
+        }
+    }
     /**
      * Retrieves a single article.
      *
@@ -268,6 +7079,27 @@ impl OnlineStore {
             )
             .await
     }
+    /**
+     * Retrieves a single article.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-01/blogs/{blog_id}/articles/{article_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_get_blogs_param_blog_articles_article`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/article#show-2020-01
+     */
+    pub fn deprecated_202001_get_blogs_param_blog_articles_article_request<'a>(
+        &'a self,
+        blog_id: &'a str,
+        article_id: &'a str,
+    ) -> OnlineStoreDeprecated202001GetBlogsParamBlogArticlesArticleRequest<'a> {
+        OnlineStoreDeprecated202001GetBlogsParamBlogArticlesArticleRequest {
+            client: self,
+            blog_id,
+            article_id,
+            fields: None,
+        }
+    }
     /**
      * Updates an article.
      *
@@ -399,6 +7231,24 @@ impl OnlineStore {
             )
             .await
     }
+    /**
+     * Retrieves a list of all the tags.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-01/articles/tags.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_get_articles_tag`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/article#tags-2020-01
+     */
+    pub fn deprecated_202001_get_articles_tag_request(
+        &self,
+    ) -> OnlineStoreDeprecated202001GetArticlesTagRequest<'_> {
+        OnlineStoreDeprecated202001GetArticlesTagRequest {
+            client: self,
+            limit: None,
+            popular: None,
+        }
+    }
     /**
      * Retrieves a list of all articles from a blog. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -505,6 +7355,37 @@ impl OnlineStore {
             )
             .await
     }
+    /**
+     * Retrieves a list of all articles from a blog. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-04/blogs/{blog_id}/articles.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_get_blogs_param_blog_article`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/article#index-2020-04
+     */
+    pub fn deprecated_202004_get_blogs_param_blog_article_request<'a>(
+        &'a self,
+        blog_id: &'a str,
+    ) -> OnlineStoreDeprecated202004GetBlogsParamBlogArticleRequest<'a> {
+        OnlineStoreDeprecated202004GetBlogsParamBlogArticleRequest {
+            client: self,
+            blog_id,
+            limit: None,
+            since_id: None,
+            created_at_min: None,
+            created_at_max: None,
+            updated_at_min: None,
+            updated_at_max: None,
+            published_at_min: None,
+            published_at_max: None,
+            published_status: None,
+            handle: None,
+            tag: None,
+            author: None,
+            fields: None,
+        }
+    }
     /**
      * Creates an article for a blog.
      *
@@ -613,6 +7494,31 @@ impl OnlineStore {
             )
             .await
     }
+    /**
+     * Retrieves a count of all articles from a blog.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-04/blogs/{blog_id}/articles/count.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_get_blogs_param_blog_articles_count`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/article#count-2020-04
+     */
+    pub fn deprecated_202004_get_blogs_param_blog_articles_count_request<'a>(
+        &'a self,
+        blog_id: &'a str,
+    ) -> OnlineStoreDeprecated202004GetBlogsParamBlogArticlesCountRequest<'a> {
+        OnlineStoreDeprecated202004GetBlogsParamBlogArticlesCountRequest {
+            client: self,
+            blog_id,
+            created_at_min: None,
+            created_at_max: None,
+            updated_at_min: None,
+            updated_at_max: None,
+            published_at_min: None,
+            published_at_max: None,
+            published_status: None,
+        }
+    }
     /**
      * Retrieves a single article.
      *
@@ -656,6 +7562,27 @@ impl OnlineStore {
             )
             .await
     }
+    /**
+     * Retrieves a single article.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-04/blogs/{blog_id}/articles/{article_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_get_blogs_param_blog_articles_article`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/article#show-2020-04
+     */
+    pub fn deprecated_202004_get_blogs_param_blog_articles_article_request<'a>(
+        &'a self,
+        blog_id: &'a str,
+        article_id: &'a str,
+    ) -> OnlineStoreDeprecated202004GetBlogsParamBlogArticlesArticleRequest<'a> {
+        OnlineStoreDeprecated202004GetBlogsParamBlogArticlesArticleRequest {
+            client: self,
+            blog_id,
+            article_id,
+            fields: None,
+        }
+    }
     /**
      * Updates an article.
      *
@@ -787,6 +7714,24 @@ impl OnlineStore {
             )
             .await
     }
+    /**
+     * Retrieves a list of all the tags.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-04/articles/tags.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_get_articles_tag`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/article#tags-2020-04
+     */
+    pub fn deprecated_202004_get_articles_tag_request(
+        &self,
+    ) -> OnlineStoreDeprecated202004GetArticlesTagRequest<'_> {
+        OnlineStoreDeprecated202004GetArticlesTagRequest {
+            client: self,
+            limit: None,
+            popular: None,
+        }
+    }
     /**
      * Retrieves a list of all articles from a blog. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -893,6 +7838,37 @@ impl OnlineStore {
             )
             .await
     }
+    /**
+     * Retrieves a list of all articles from a blog. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-07/blogs/{blog_id}/articles.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_get_blogs_param_blog_article`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/article#index-2020-07
+     */
+    pub fn deprecated_202007_get_blogs_param_blog_article_request<'a>(
+        &'a self,
+        blog_id: &'a str,
+    ) -> OnlineStoreDeprecated202007GetBlogsParamBlogArticleRequest<'a> {
+        OnlineStoreDeprecated202007GetBlogsParamBlogArticleRequest {
+            client: self,
+            blog_id,
+            limit: None,
+            since_id: None,
+            created_at_min: None,
+            created_at_max: None,
+            updated_at_min: None,
+            updated_at_max: None,
+            published_at_min: None,
+            published_at_max: None,
+            published_status: None,
+            handle: None,
+            tag: None,
+            author: None,
+            fields: None,
+        }
+    }
     /**
      * Creates an article for a blog.
      *
@@ -1001,6 +7977,31 @@ impl OnlineStore {
             )
             .await
     }
+    /**
+     * Retrieves a count of all articles from a blog.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-07/blogs/{blog_id}/articles/count.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_get_blogs_param_blog_articles_count`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/article#count-2020-07
+     */
+    pub fn deprecated_202007_get_blogs_param_blog_articles_count_request<'a>(
+        &'a self,
+        blog_id: &'a str,
+    ) -> OnlineStoreDeprecated202007GetBlogsParamBlogArticlesCountRequest<'a> {
+        OnlineStoreDeprecated202007GetBlogsParamBlogArticlesCountRequest {
+            client: self,
+            blog_id,
+            created_at_min: None,
+            created_at_max: None,
+            updated_at_min: None,
+            updated_at_max: None,
+            published_at_min: None,
+            published_at_max: None,
+            published_status: None,
+        }
+    }
     /**
      * Retrieves a single article.
      *
@@ -1044,6 +8045,27 @@ impl OnlineStore {
             )
             .await
     }
+    /**
+     * Retrieves a single article.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-07/blogs/{blog_id}/articles/{article_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_get_blogs_param_blog_articles_article`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/article#show-2020-07
+     */
+    pub fn deprecated_202007_get_blogs_param_blog_articles_article_request<'a>(
+        &'a self,
+        blog_id: &'a str,
+        article_id: &'a str,
+    ) -> OnlineStoreDeprecated202007GetBlogsParamBlogArticlesArticleRequest<'a> {
+        OnlineStoreDeprecated202007GetBlogsParamBlogArticlesArticleRequest {
+            client: self,
+            blog_id,
+            article_id,
+            fields: None,
+        }
+    }
     /**
      * Updates an article.
      *
@@ -1175,6 +8197,24 @@ impl OnlineStore {
             )
             .await
     }
+    /**
+     * Retrieves a list of all the tags.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-07/articles/tags.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_get_articles_tag`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/article#tags-2020-07
+     */
+    pub fn deprecated_202007_get_articles_tag_request(
+        &self,
+    ) -> OnlineStoreDeprecated202007GetArticlesTagRequest<'_> {
+        OnlineStoreDeprecated202007GetArticlesTagRequest {
+            client: self,
+            limit: None,
+            popular: None,
+        }
+    }
     /**
      * Retrieves a list of all articles from a blog. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -1281,6 +8321,37 @@ impl OnlineStore {
             )
             .await
     }
+    /**
+     * Retrieves a list of all articles from a blog. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-10/blogs/{blog_id}/articles.json` endpoint.
+     *
+     * As opposed to `get_blogs_param_blog_article`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/article#index-2020-10
+     */
+    pub fn get_blogs_param_blog_article_request<'a>(
+        &'a self,
+        blog_id: &'a str,
+    ) -> OnlineStoreGetBlogsParamBlogArticleRequest<'a> {
+        OnlineStoreGetBlogsParamBlogArticleRequest {
+            client: self,
+            blog_id,
+            limit: None,
+            since_id: None,
+            created_at_min: None,
+            created_at_max: None,
+            updated_at_min: None,
+            updated_at_max: None,
+            published_at_min: None,
+            published_at_max: None,
+            published_status: None,
+            handle: None,
+            tag: None,
+            author: None,
+            fields: None,
+        }
+    }
     /**
      * Creates an article for a blog.
      *
@@ -1389,6 +8460,31 @@ impl OnlineStore {
             )
             .await
     }
+    /**
+     * Retrieves a count of all articles from a blog.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-10/blogs/{blog_id}/articles/count.json` endpoint.
+     *
+     * As opposed to `get_blogs_param_blog_articles_count`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/article#count-2020-10
+     */
+    pub fn get_blogs_param_blog_articles_count_request<'a>(
+        &'a self,
+        blog_id: &'a str,
+    ) -> OnlineStoreGetBlogsParamBlogArticlesCountRequest<'a> {
+        OnlineStoreGetBlogsParamBlogArticlesCountRequest {
+            client: self,
+            blog_id,
+            created_at_min: None,
+            created_at_max: None,
+            updated_at_min: None,
+            updated_at_max: None,
+            published_at_min: None,
+            published_at_max: None,
+            published_status: None,
+        }
+    }
     /**
      * Retrieves a single article.
      *
@@ -1432,6 +8528,27 @@ impl OnlineStore {
             )
             .await
     }
+    /**
+     * Retrieves a single article.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-10/blogs/{blog_id}/articles/{article_id}.json` endpoint.
+     *
+     * As opposed to `get_blogs_param_blog_articles_article`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/article#show-2020-10
+     */
+    pub fn get_blogs_param_blog_articles_article_request<'a>(
+        &'a self,
+        blog_id: &'a str,
+        article_id: &'a str,
+    ) -> OnlineStoreGetBlogsParamBlogArticlesArticleRequest<'a> {
+        OnlineStoreGetBlogsParamBlogArticlesArticleRequest {
+            client: self,
+            blog_id,
+            article_id,
+            fields: None,
+        }
+    }
     /**
      * Updates an article.
      *
@@ -1563,6 +8680,22 @@ impl OnlineStore {
             )
             .await
     }
+    /**
+     * Retrieves a list of all the tags.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-10/articles/tags.json` endpoint.
+     *
+     * As opposed to `get_articles_tag`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/article#tags-2020-10
+     */
+    pub fn get_articles_tag_request(&self) -> OnlineStoreGetArticlesTagRequest<'_> {
+        OnlineStoreGetArticlesTagRequest {
+            client: self,
+            limit: None,
+            popular: None,
+        }
+    }
     /**
      * Retrieves a list of all articles from a blog. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -1669,6 +8802,37 @@ impl OnlineStore {
             )
             .await
     }
+    /**
+     * Retrieves a list of all articles from a blog. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2021-01/blogs/{blog_id}/articles.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_get_blogs_param_blog_article`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/article#index-2021-01
+     */
+    pub fn deprecated_202101_get_blogs_param_blog_article_request<'a>(
+        &'a self,
+        blog_id: &'a str,
+    ) -> OnlineStoreDeprecated202101GetBlogsParamBlogArticleRequest<'a> {
+        OnlineStoreDeprecated202101GetBlogsParamBlogArticleRequest {
+            client: self,
+            blog_id,
+            limit: None,
+            since_id: None,
+            created_at_min: None,
+            created_at_max: None,
+            updated_at_min: None,
+            updated_at_max: None,
+            published_at_min: None,
+            published_at_max: None,
+            published_status: None,
+            handle: None,
+            tag: None,
+            author: None,
+            fields: None,
+        }
+    }
     /**
      * Creates an article for a blog.
      *
@@ -1777,6 +8941,31 @@ impl OnlineStore {
             )
             .await
     }
+    /**
+     * Retrieves a count of all articles from a blog.
+     *
+     * This function performs a `GET` to the `/admin/api/2021-01/blogs/{blog_id}/articles/count.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_get_blogs_param_blog_articles_count`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/article#count-2021-01
+     */
+    pub fn deprecated_202101_get_blogs_param_blog_articles_count_request<'a>(
+        &'a self,
+        blog_id: &'a str,
+    ) -> OnlineStoreDeprecated202101GetBlogsParamBlogArticlesCountRequest<'a> {
+        OnlineStoreDeprecated202101GetBlogsParamBlogArticlesCountRequest {
+            client: self,
+            blog_id,
+            created_at_min: None,
+            created_at_max: None,
+            updated_at_min: None,
+            updated_at_max: None,
+            published_at_min: None,
+            published_at_max: None,
+            published_status: None,
+        }
+    }
     /**
      * Retrieves a single article.
      *
@@ -1820,6 +9009,27 @@ impl OnlineStore {
             )
             .await
     }
+    /**
+     * Retrieves a single article.
+     *
+     * This function performs a `GET` to the `/admin/api/2021-01/blogs/{blog_id}/articles/{article_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_get_blogs_param_blog_articles_article`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/article#show-2021-01
+     */
+    pub fn deprecated_202101_get_blogs_param_blog_articles_article_request<'a>(
+        &'a self,
+        blog_id: &'a str,
+        article_id: &'a str,
+    ) -> OnlineStoreDeprecated202101GetBlogsParamBlogArticlesArticleRequest<'a> {
+        OnlineStoreDeprecated202101GetBlogsParamBlogArticlesArticleRequest {
+            client: self,
+            blog_id,
+            article_id,
+            fields: None,
+        }
+    }
     /**
      * Updates an article.
      *
@@ -1951,6 +9161,24 @@ impl OnlineStore {
             )
             .await
     }
+    /**
+     * Retrieves a list of all the tags.
+     *
+     * This function performs a `GET` to the `/admin/api/2021-01/articles/tags.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_get_articles_tag`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/article#tags-2021-01
+     */
+    pub fn deprecated_202101_get_articles_tag_request(
+        &self,
+    ) -> OnlineStoreDeprecated202101GetArticlesTagRequest<'_> {
+        OnlineStoreDeprecated202101GetArticlesTagRequest {
+            client: self,
+            limit: None,
+            popular: None,
+        }
+    }
     /**
      * Retrieves a list of all articles from a blog. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -2057,6 +9285,37 @@ impl OnlineStore {
             )
             .await
     }
+    /**
+     * Retrieves a list of all articles from a blog. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/unstable/blogs/{blog_id}/articles.json` endpoint.
+     *
+     * As opposed to `deprecated_unstable_get_blogs_param_blog_article`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/article#index-unstable
+     */
+    pub fn deprecated_unstable_get_blogs_param_blog_article_request<'a>(
+        &'a self,
+        blog_id: &'a str,
+    ) -> OnlineStoreDeprecatedUnstableGetBlogsParamBlogArticleRequest<'a> {
+        OnlineStoreDeprecatedUnstableGetBlogsParamBlogArticleRequest {
+            client: self,
+            blog_id,
+            limit: None,
+            since_id: None,
+            created_at_min: None,
+            created_at_max: None,
+            updated_at_min: None,
+            updated_at_max: None,
+            published_at_min: None,
+            published_at_max: None,
+            published_status: None,
+            handle: None,
+            tag: None,
+            author: None,
+            fields: None,
+        }
+    }
     /**
      * Creates an article for a blog.
      *
@@ -2165,6 +9424,31 @@ impl OnlineStore {
             )
             .await
     }
+    /**
+     * Retrieves a count of all articles from a blog.
+     *
+     * This function performs a `GET` to the `/admin/api/unstable/blogs/{blog_id}/articles/count.json` endpoint.
+     *
+     * As opposed to `deprecated_unstable_get_blogs_param_blog_articles_count`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/article#count-unstable
+     */
+    pub fn deprecated_unstable_get_blogs_param_blog_articles_count_request<'a>(
+        &'a self,
+        blog_id: &'a str,
+    ) -> OnlineStoreDeprecatedUnstableGetBlogsParamBlogArticlesCountRequest<'a> {
+        OnlineStoreDeprecatedUnstableGetBlogsParamBlogArticlesCountRequest {
+            client: self,
+            blog_id,
+            created_at_min: None,
+            created_at_max: None,
+            updated_at_min: None,
+            updated_at_max: None,
+            published_at_min: None,
+            published_at_max: None,
+            published_status: None,
+        }
+    }
     /**
      * Retrieves a single article.
      *
@@ -2208,6 +9492,27 @@ impl OnlineStore {
             )
             .await
     }
+    /**
+     * Retrieves a single article.
+     *
+     * This function performs a `GET` to the `/admin/api/unstable/blogs/{blog_id}/articles/{article_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_unstable_get_blogs_param_blog_articles_article`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/article#show-unstable
+     */
+    pub fn deprecated_unstable_get_blogs_param_blog_articles_article_request<'a>(
+        &'a self,
+        blog_id: &'a str,
+        article_id: &'a str,
+    ) -> OnlineStoreDeprecatedUnstableGetBlogsParamBlogArticlesArticleRequest<'a> {
+        OnlineStoreDeprecatedUnstableGetBlogsParamBlogArticlesArticleRequest {
+            client: self,
+            blog_id,
+            article_id,
+            fields: None,
+        }
+    }
     /**
      * Updates an article.
      *
@@ -2341,6 +9646,24 @@ impl OnlineStore {
             )
             .await
     }
+    /**
+     * Retrieves a list of all the tags.
+     *
+     * This function performs a `GET` to the `/admin/api/unstable/articles/tags.json` endpoint.
+     *
+     * As opposed to `deprecated_unstable_get_articles_tag`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/article#tags-unstable
+     */
+    pub fn deprecated_unstable_get_articles_tag_request(
+        &self,
+    ) -> OnlineStoreDeprecatedUnstableGetArticlesTagRequest<'_> {
+        OnlineStoreDeprecatedUnstableGetArticlesTagRequest {
+            client: self,
+            limit: None,
+            popular: None,
+        }
+    }
     /**
     * Retrieves a single asset for a theme by its key.
              To retrieve a single asset, include asset[key]=#{asset_key} as a request parameter. For example, to retrieve the asset with a key of templates/index.liquid, the request might be /admin/themes/828155753/assets.json?asset[key]=templates/index.liquid.
@@ -2389,6 +9712,28 @@ impl OnlineStore {
             .await
     }
     /**
+    * Retrieves a single asset for a theme by its key.
+             To retrieve a single asset, include asset[key]=#{asset_key} as a request parameter. For example, to retrieve the asset with a key of templates/index.liquid, the request might be /admin/themes/828155753/assets.json?asset[key]=templates/index.liquid.
+             For more information on the key property, see Asset properties.
+    *
+    * This function performs a `GET` to the `/admin/api/2020-01/themes/{theme_id}/assets.json` endpoint.
+    *
+    * As opposed to `deprecated_202001_get_themes_param_theme_asset`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+    *
+    * https://shopify.dev/docs/admin-api/rest/reference/online-store/asset#show-2020-01
+    */
+    pub fn deprecated_202001_get_themes_param_theme_asset_request<'a>(
+        &'a self,
+        theme_id: &'a str,
+    ) -> OnlineStoreDeprecated202001GetThemesParamThemeAssetRequest<'a> {
+        OnlineStoreDeprecated202001GetThemesParamThemeAssetRequest {
+            client: self,
+            theme_id,
+            fields: None,
+            asset_key: None,
+        }
+    }
+    /**
     * Creates or updates an asset for a theme.
              In the PUT request, you can include the src or source_key property to create the asset from an existing file.
     *
@@ -2435,6 +9780,29 @@ impl OnlineStore {
             )
             .await
     }
+    /**
+    * Creates or updates an asset for a theme.
+             In the PUT request, you can include the src or source_key property to create the asset from an existing file.
+    *
+    * This function performs a `PUT` to the `/admin/api/2020-01/themes/{theme_id}/assets.json` endpoint.
+    *
+    * As opposed to `deprecated_202001_update_themes_param_theme_assets`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+    *
+    * https://shopify.dev/docs/admin-api/rest/reference/online-store/asset#update-2020-01
+    */
+    pub fn deprecated_202001_update_themes_param_theme_assets_request<'a>(
+        &'a self,
+        theme_id: &'a str,
+        body: &'a serde_json::Value,
+    ) -> OnlineStoreDeprecated202001UpdateThemesParamThemeAssetsRequest<'a> {
+        OnlineStoreDeprecated202001UpdateThemesParamThemeAssetsRequest {
+            client: self,
+            theme_id,
+            src: None,
+            source_key: None,
+            body,
+        }
+    }
     /**
      * Deletes an asset from a theme.
      *
@@ -2475,6 +9843,25 @@ impl OnlineStore {
             )
             .await
     }
+    /**
+     * Deletes an asset from a theme.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2020-01/themes/{theme_id}/assets.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_delete_themes_param_theme_assets`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/asset#destroy-2020-01
+     */
+    pub fn deprecated_202001_delete_themes_param_theme_assets_request<'a>(
+        &'a self,
+        theme_id: &'a str,
+    ) -> OnlineStoreDeprecated202001DeleteThemesParamThemeAssetsRequest<'a> {
+        OnlineStoreDeprecated202001DeleteThemesParamThemeAssetsRequest {
+            client: self,
+            theme_id,
+            asset_key: None,
+        }
+    }
     /**
     * Retrieves a single asset for a theme by its key.
              To retrieve a single asset, include asset[key]=#{asset_key} as a request parameter. For example, to retrieve the asset with a key of templates/index.liquid, the request might be /admin/themes/828155753/assets.json?asset[key]=templates/index.liquid.
@@ -2523,6 +9910,28 @@ impl OnlineStore {
             .await
     }
     /**
+    * Retrieves a single asset for a theme by its key.
+             To retrieve a single asset, include asset[key]=#{asset_key} as a request parameter. For example, to retrieve the asset with a key of templates/index.liquid, the request might be /admin/themes/828155753/assets.json?asset[key]=templates/index.liquid.
+             For more information on the key property, see Asset properties.
+    *
+    * This function performs a `GET` to the `/admin/api/2020-04/themes/{theme_id}/assets.json` endpoint.
+    *
+    * As opposed to `deprecated_202004_get_themes_param_theme_asset`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+    *
+    * https://shopify.dev/docs/admin-api/rest/reference/online-store/asset#show-2020-04
+    */
+    pub fn deprecated_202004_get_themes_param_theme_asset_request<'a>(
+        &'a self,
+        theme_id: &'a str,
+    ) -> OnlineStoreDeprecated202004GetThemesParamThemeAssetRequest<'a> {
+        OnlineStoreDeprecated202004GetThemesParamThemeAssetRequest {
+            client: self,
+            theme_id,
+            fields: None,
+            asset_key: None,
+        }
+    }
+    /**
     * Creates or updates an asset for a theme.
              In the PUT request, you can include the src or source_key property to create the asset from an existing file.
     *
@@ -2569,6 +9978,29 @@ impl OnlineStore {
             )
             .await
     }
+    /**
+    * Creates or updates an asset for a theme.
+             In the PUT request, you can include the src or source_key property to create the asset from an existing file.
+    *
+    * This function performs a `PUT` to the `/admin/api/2020-04/themes/{theme_id}/assets.json` endpoint.
+    *
+    * As opposed to `deprecated_202004_update_themes_param_theme_assets`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+    *
+    * https://shopify.dev/docs/admin-api/rest/reference/online-store/asset#update-2020-04
+    */
+    pub fn deprecated_202004_update_themes_param_theme_assets_request<'a>(
+        &'a self,
+        theme_id: &'a str,
+        body: &'a serde_json::Value,
+    ) -> OnlineStoreDeprecated202004UpdateThemesParamThemeAssetsRequest<'a> {
+        OnlineStoreDeprecated202004UpdateThemesParamThemeAssetsRequest {
+            client: self,
+            theme_id,
+            src: None,
+            source_key: None,
+            body,
+        }
+    }
     /**
      * Deletes an asset from a theme.
      *
@@ -2609,6 +10041,25 @@ impl OnlineStore {
             )
             .await
     }
+    /**
+     * Deletes an asset from a theme.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2020-04/themes/{theme_id}/assets.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_delete_themes_param_theme_assets`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/asset#destroy-2020-04
+     */
+    pub fn deprecated_202004_delete_themes_param_theme_assets_request<'a>(
+        &'a self,
+        theme_id: &'a str,
+    ) -> OnlineStoreDeprecated202004DeleteThemesParamThemeAssetsRequest<'a> {
+        OnlineStoreDeprecated202004DeleteThemesParamThemeAssetsRequest {
+            client: self,
+            theme_id,
+            asset_key: None,
+        }
+    }
     /**
     * Retrieves a single asset for a theme by its key.
              To retrieve a single asset, include asset[key]=#{asset_key} as a request parameter. For example, to retrieve the asset with a key of templates/index.liquid, the request might be /admin/themes/828155753/assets.json?asset[key]=templates/index.liquid.
@@ -2657,6 +10108,28 @@ impl OnlineStore {
             .await
     }
     /**
+    * Retrieves a single asset for a theme by its key.
+             To retrieve a single asset, include asset[key]=#{asset_key} as a request parameter. For example, to retrieve the asset with a key of templates/index.liquid, the request might be /admin/themes/828155753/assets.json?asset[key]=templates/index.liquid.
+             For more information on the key property, see Asset properties.
+    *
+    * This function performs a `GET` to the `/admin/api/2020-07/themes/{theme_id}/assets.json` endpoint.
+    *
+    * As opposed to `deprecated_202007_get_themes_param_theme_asset`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+    *
+    * https://shopify.dev/docs/admin-api/rest/reference/online-store/asset#show-2020-07
+    */
+    pub fn deprecated_202007_get_themes_param_theme_asset_request<'a>(
+        &'a self,
+        theme_id: &'a str,
+    ) -> OnlineStoreDeprecated202007GetThemesParamThemeAssetRequest<'a> {
+        OnlineStoreDeprecated202007GetThemesParamThemeAssetRequest {
+            client: self,
+            theme_id,
+            fields: None,
+            asset_key: None,
+        }
+    }
+    /**
     * Creates or updates an asset for a theme.
              In the PUT request, you can include the src or source_key property to create the asset from an existing file.
     *
@@ -2703,6 +10176,29 @@ impl OnlineStore {
             )
             .await
     }
+    /**
+    * Creates or updates an asset for a theme.
+             In the PUT request, you can include the src or source_key property to create the asset from an existing file.
+    *
+    * This function performs a `PUT` to the `/admin/api/2020-07/themes/{theme_id}/assets.json` endpoint.
+    *
+    * As opposed to `deprecated_202007_update_themes_param_theme_assets`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+    *
+    * https://shopify.dev/docs/admin-api/rest/reference/online-store/asset#update-2020-07
+    */
+    pub fn deprecated_202007_update_themes_param_theme_assets_request<'a>(
+        &'a self,
+        theme_id: &'a str,
+        body: &'a serde_json::Value,
+    ) -> OnlineStoreDeprecated202007UpdateThemesParamThemeAssetsRequest<'a> {
+        OnlineStoreDeprecated202007UpdateThemesParamThemeAssetsRequest {
+            client: self,
+            theme_id,
+            src: None,
+            source_key: None,
+            body,
+        }
+    }
     /**
      * Deletes an asset from a theme.
      *
@@ -2743,6 +10239,25 @@ impl OnlineStore {
             )
             .await
     }
+    /**
+     * Deletes an asset from a theme.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2020-07/themes/{theme_id}/assets.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_delete_themes_param_theme_assets`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/asset#destroy-2020-07
+     */
+    pub fn deprecated_202007_delete_themes_param_theme_assets_request<'a>(
+        &'a self,
+        theme_id: &'a str,
+    ) -> OnlineStoreDeprecated202007DeleteThemesParamThemeAssetsRequest<'a> {
+        OnlineStoreDeprecated202007DeleteThemesParamThemeAssetsRequest {
+            client: self,
+            theme_id,
+            asset_key: None,
+        }
+    }
     /**
     * Retrieves a single asset for a theme by its key.
              To retrieve a single asset, include asset[key]=#{asset_key} as a request parameter. For example, to retrieve the asset with a key of templates/index.liquid, the request might be /admin/themes/828155753/assets.json?asset[key]=templates/index.liquid.
@@ -2791,6 +10306,28 @@ impl OnlineStore {
             .await
     }
     /**
+    * Retrieves a single asset for a theme by its key.
+             To retrieve a single asset, include asset[key]=#{asset_key} as a request parameter. For example, to retrieve the asset with a key of templates/index.liquid, the request might be /admin/themes/828155753/assets.json?asset[key]=templates/index.liquid.
+             For more information on the key property, see Asset properties.
+    *
+    * This function performs a `GET` to the `/admin/api/2020-10/themes/{theme_id}/assets.json` endpoint.
+    *
+    * As opposed to `get_themes_param_theme_asset`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+    *
+    * https://shopify.dev/docs/admin-api/rest/reference/online-store/asset#show-2020-10
+    */
+    pub fn get_themes_param_theme_asset_request<'a>(
+        &'a self,
+        theme_id: &'a str,
+    ) -> OnlineStoreGetThemesParamThemeAssetRequest<'a> {
+        OnlineStoreGetThemesParamThemeAssetRequest {
+            client: self,
+            theme_id,
+            fields: None,
+            asset_key: None,
+        }
+    }
+    /**
     * Creates or updates an asset for a theme.
              In the PUT request, you can include the src or source_key property to create the asset from an existing file.
     *
@@ -2837,6 +10374,29 @@ impl OnlineStore {
             )
             .await
     }
+    /**
+    * Creates or updates an asset for a theme.
+             In the PUT request, you can include the src or source_key property to create the asset from an existing file.
+    *
+    * This function performs a `PUT` to the `/admin/api/2020-10/themes/{theme_id}/assets.json` endpoint.
+    *
+    * As opposed to `update_themes_param_theme_assets`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+    *
+    * https://shopify.dev/docs/admin-api/rest/reference/online-store/asset#update-2020-10
+    */
+    pub fn update_themes_param_theme_assets_request<'a>(
+        &'a self,
+        theme_id: &'a str,
+        body: &'a serde_json::Value,
+    ) -> OnlineStoreUpdateThemesParamThemeAssetsRequest<'a> {
+        OnlineStoreUpdateThemesParamThemeAssetsRequest {
+            client: self,
+            theme_id,
+            src: None,
+            source_key: None,
+            body,
+        }
+    }
     /**
      * Deletes an asset from a theme.
      *
@@ -2877,6 +10437,25 @@ impl OnlineStore {
             )
             .await
     }
+    /**
+     * Deletes an asset from a theme.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2020-10/themes/{theme_id}/assets.json` endpoint.
+     *
+     * As opposed to `delete_themes_param_theme_assets`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/asset#destroy-2020-10
+     */
+    pub fn delete_themes_param_theme_assets_request<'a>(
+        &'a self,
+        theme_id: &'a str,
+    ) -> OnlineStoreDeleteThemesParamThemeAssetsRequest<'a> {
+        OnlineStoreDeleteThemesParamThemeAssetsRequest {
+            client: self,
+            theme_id,
+            asset_key: None,
+        }
+    }
     /**
     * Retrieves a single asset for a theme by its key.
              To retrieve a single asset, include asset[key]=#{asset_key} as a request parameter. For example, to retrieve the asset with a key of templates/index.liquid, the request might be /admin/themes/828155753/assets.json?asset[key]=templates/index.liquid.
@@ -2925,6 +10504,28 @@ impl OnlineStore {
             .await
     }
     /**
+    * Retrieves a single asset for a theme by its key.
+             To retrieve a single asset, include asset[key]=#{asset_key} as a request parameter. For example, to retrieve the asset with a key of templates/index.liquid, the request might be /admin/themes/828155753/assets.json?asset[key]=templates/index.liquid.
+             For more information on the key property, see Asset properties.
+    *
+    * This function performs a `GET` to the `/admin/api/2021-01/themes/{theme_id}/assets.json` endpoint.
+    *
+    * As opposed to `deprecated_202101_get_themes_param_theme_asset`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+    *
+    * https://shopify.dev/docs/admin-api/rest/reference/online-store/asset#show-2021-01
+    */
+    pub fn deprecated_202101_get_themes_param_theme_asset_request<'a>(
+        &'a self,
+        theme_id: &'a str,
+    ) -> OnlineStoreDeprecated202101GetThemesParamThemeAssetRequest<'a> {
+        OnlineStoreDeprecated202101GetThemesParamThemeAssetRequest {
+            client: self,
+            theme_id,
+            fields: None,
+            asset_key: None,
+        }
+    }
+    /**
     * Creates or updates an asset for a theme.
              In the PUT request, you can include the src or source_key property to create the asset from an existing file.
     *
@@ -2971,6 +10572,29 @@ impl OnlineStore {
             )
             .await
     }
+    /**
+    * Creates or updates an asset for a theme.
+             In the PUT request, you can include the src or source_key property to create the asset from an existing file.
+    *
+    * This function performs a `PUT` to the `/admin/api/2021-01/themes/{theme_id}/assets.json` endpoint.
+    *
+    * As opposed to `deprecated_202101_update_themes_param_theme_assets`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+    *
+    * https://shopify.dev/docs/admin-api/rest/reference/online-store/asset#update-2021-01
+    */
+    pub fn deprecated_202101_update_themes_param_theme_assets_request<'a>(
+        &'a self,
+        theme_id: &'a str,
+        body: &'a serde_json::Value,
+    ) -> OnlineStoreDeprecated202101UpdateThemesParamThemeAssetsRequest<'a> {
+        OnlineStoreDeprecated202101UpdateThemesParamThemeAssetsRequest {
+            client: self,
+            theme_id,
+            src: None,
+            source_key: None,
+            body,
+        }
+    }
     /**
      * Deletes an asset from a theme.
      *
@@ -3011,6 +10635,25 @@ impl OnlineStore {
             )
             .await
     }
+    /**
+     * Deletes an asset from a theme.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2021-01/themes/{theme_id}/assets.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_delete_themes_param_theme_assets`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/asset#destroy-2021-01
+     */
+    pub fn deprecated_202101_delete_themes_param_theme_assets_request<'a>(
+        &'a self,
+        theme_id: &'a str,
+    ) -> OnlineStoreDeprecated202101DeleteThemesParamThemeAssetsRequest<'a> {
+        OnlineStoreDeprecated202101DeleteThemesParamThemeAssetsRequest {
+            client: self,
+            theme_id,
+            asset_key: None,
+        }
+    }
     /**
     * Retrieves a single asset for a theme by its key.
              To retrieve a single asset, include asset[key]=#{asset_key} as a request parameter. For example, to retrieve the asset with a key of templates/index.liquid, the request might be /admin/themes/828155753/assets.json?asset[key]=templates/index.liquid.
@@ -3059,6 +10702,28 @@ impl OnlineStore {
             .await
     }
     /**
+    * Retrieves a single asset for a theme by its key.
+             To retrieve a single asset, include asset[key]=#{asset_key} as a request parameter. For example, to retrieve the asset with a key of templates/index.liquid, the request might be /admin/themes/828155753/assets.json?asset[key]=templates/index.liquid.
+             For more information on the key property, see Asset properties.
+    *
+    * This function performs a `GET` to the `/admin/api/unstable/themes/{theme_id}/assets.json` endpoint.
+    *
+    * As opposed to `deprecated_unstable_get_themes_param_theme_asset`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+    *
+    * https://shopify.dev/docs/admin-api/rest/reference/online-store/asset#show-unstable
+    */
+    pub fn deprecated_unstable_get_themes_param_theme_asset_request<'a>(
+        &'a self,
+        theme_id: &'a str,
+    ) -> OnlineStoreDeprecatedUnstableGetThemesParamThemeAssetRequest<'a> {
+        OnlineStoreDeprecatedUnstableGetThemesParamThemeAssetRequest {
+            client: self,
+            theme_id,
+            fields: None,
+            asset_key: None,
+        }
+    }
+    /**
     * Creates or updates an asset for a theme.
              In the PUT request, you can include the src or source_key property to create the asset from an existing file.
     *
@@ -3105,6 +10770,29 @@ impl OnlineStore {
             )
             .await
     }
+    /**
+    * Creates or updates an asset for a theme.
+             In the PUT request, you can include the src or source_key property to create the asset from an existing file.
+    *
+    * This function performs a `PUT` to the `/admin/api/unstable/themes/{theme_id}/assets.json` endpoint.
+    *
+    * As opposed to `deprecated_unstable_update_themes_param_theme_assets`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+    *
+    * https://shopify.dev/docs/admin-api/rest/reference/online-store/asset#update-unstable
+    */
+    pub fn deprecated_unstable_update_themes_param_theme_assets_request<'a>(
+        &'a self,
+        theme_id: &'a str,
+        body: &'a serde_json::Value,
+    ) -> OnlineStoreDeprecatedUnstableUpdateThemesParamThemeAssetsRequest<'a> {
+        OnlineStoreDeprecatedUnstableUpdateThemesParamThemeAssetsRequest {
+            client: self,
+            theme_id,
+            src: None,
+            source_key: None,
+            body,
+        }
+    }
     /**
      * Deletes an asset from a theme.
      *
@@ -3145,6 +10833,25 @@ impl OnlineStore {
             )
             .await
     }
+    /**
+     * Deletes an asset from a theme.
+     *
+     * This function performs a `DELETE` to the `/admin/api/unstable/themes/{theme_id}/assets.json` endpoint.
+     *
+     * As opposed to `deprecated_unstable_delete_themes_param_theme_assets`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/asset#destroy-unstable
+     */
+    pub fn deprecated_unstable_delete_themes_param_theme_assets_request<'a>(
+        &'a self,
+        theme_id: &'a str,
+    ) -> OnlineStoreDeprecatedUnstableDeleteThemesParamThemeAssetsRequest<'a> {
+        OnlineStoreDeprecatedUnstableDeleteThemesParamThemeAssetsRequest {
+            client: self,
+            theme_id,
+            asset_key: None,
+        }
+    }
     /**
      * Retrieve a list of all blogs. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -3194,6 +10901,26 @@ impl OnlineStore {
             )
             .await
     }
+    /**
+     * Retrieve a list of all blogs. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-01/blogs.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_get_blog`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/blog#index-2020-01
+     */
+    pub fn deprecated_202001_get_blog_request(
+        &self,
+    ) -> OnlineStoreDeprecated202001GetBlogRequest<'_> {
+        OnlineStoreDeprecated202001GetBlogRequest {
+            client: self,
+            limit: None,
+            since_id: None,
+            handle: None,
+            fields: None,
+        }
+    }
     /**
      * Create a new blog.
      *
@@ -3275,6 +11002,25 @@ impl OnlineStore {
             )
             .await
     }
+    /**
+     * Get a single blog by its ID.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-01/blogs/{blog_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_get_blogs_param_blog`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/blog#show-2020-01
+     */
+    pub fn deprecated_202001_get_blogs_param_blog_request<'a>(
+        &'a self,
+        blog_id: &'a str,
+    ) -> OnlineStoreDeprecated202001GetBlogsParamBlogRequest<'a> {
+        OnlineStoreDeprecated202001GetBlogsParamBlogRequest {
+            client: self,
+            blog_id,
+            fields: None,
+        }
+    }
     /**
      * Update a blog.
      *
@@ -3444,6 +11190,35 @@ impl OnlineStore {
             )
             .await
     }
+    /**
+     * Retrieves a list of comments. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-01/comments.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_get_comment`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/comment#index-2020-01
+     */
+    pub fn deprecated_202001_get_comment_request(
+        &self,
+    ) -> OnlineStoreDeprecated202001GetCommentRequest<'_> {
+        OnlineStoreDeprecated202001GetCommentRequest {
+            client: self,
+            limit: None,
+            since_id: None,
+            created_at_min: None,
+            created_at_max: None,
+            updated_at_min: None,
+            updated_at_max: None,
+            published_at_min: None,
+            published_at_max: None,
+            fields: None,
+            published_status: None,
+            status: None,
+            article_id: None,
+            blog_id: None,
+        }
+    }
     /**
      * Creates a comment for an article.
      *
@@ -3554,6 +11329,32 @@ impl OnlineStore {
             )
             .await
     }
+    /**
+     * Retrieves a count of comments.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-01/comments/count.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_get_comments_count`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/comment#count-2020-01
+     */
+    pub fn deprecated_202001_get_comments_count_request(
+        &self,
+    ) -> OnlineStoreDeprecated202001GetCommentsCountRequest<'_> {
+        OnlineStoreDeprecated202001GetCommentsCountRequest {
+            client: self,
+            created_at_min: None,
+            created_at_max: None,
+            updated_at_min: None,
+            updated_at_max: None,
+            published_at_min: None,
+            published_at_max: None,
+            published_status: None,
+            status: None,
+            article_id: None,
+            blog_id: None,
+        }
+    }
     /**
      * Retrieves a single comment by its ID.
      *
@@ -3594,6 +11395,25 @@ impl OnlineStore {
             )
             .await
     }
+    /**
+     * Retrieves a single comment by its ID.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-01/comments/{comment_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_get_comments_param_comment`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/comment#show-2020-01
+     */
+    pub fn deprecated_202001_get_comments_param_comment_request<'a>(
+        &'a self,
+        comment_id: &'a str,
+    ) -> OnlineStoreDeprecated202001GetCommentsParamCommentRequest<'a> {
+        OnlineStoreDeprecated202001GetCommentsParamCommentRequest {
+            client: self,
+            comment_id,
+            fields: None,
+        }
+    }
     /**
      * Updates a comment of an article.
      *
@@ -3847,6 +11667,27 @@ impl OnlineStore {
             )
             .await
     }
+    /**
+     * Retrieves a list of URL redirects. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-01/redirects.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_get_redirect`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/redirect#index-2020-01
+     */
+    pub fn deprecated_202001_get_redirect_request(
+        &self,
+    ) -> OnlineStoreDeprecated202001GetRedirectRequest<'_> {
+        OnlineStoreDeprecated202001GetRedirectRequest {
+            client: self,
+            limit: None,
+            since_id: None,
+            path: None,
+            target: None,
+            fields: None,
+        }
+    }
     /**
     * Creates a redirect. When you provide a full URL as the value of the path property, it will be saved as an absolute path without the domain.
              For example, "path": "http://www.johns-apparel.com/springwear" will be saved as "path": "springwear".
@@ -3909,6 +11750,24 @@ impl OnlineStore {
             )
             .await
     }
+    /**
+     * Retrieves a count of URL redirects.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-01/redirects/count.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_get_redirects_count`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/redirect#count-2020-01
+     */
+    pub fn deprecated_202001_get_redirects_count_request(
+        &self,
+    ) -> OnlineStoreDeprecated202001GetRedirectsCountRequest<'_> {
+        OnlineStoreDeprecated202001GetRedirectsCountRequest {
+            client: self,
+            path: None,
+            target: None,
+        }
+    }
     /**
      * Retrieves a single redirect.
      *
@@ -3949,6 +11808,25 @@ impl OnlineStore {
             )
             .await
     }
+    /**
+     * Retrieves a single redirect.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-01/redirects/{redirect_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_get_redirects_param_redirect`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/redirect#show-2020-01
+     */
+    pub fn deprecated_202001_get_redirects_param_redirect_request<'a>(
+        &'a self,
+        redirect_id: &'a str,
+    ) -> OnlineStoreDeprecated202001GetRedirectsParamRedirectRequest<'a> {
+        OnlineStoreDeprecated202001GetRedirectsParamRedirectRequest {
+            client: self,
+            redirect_id,
+            fields: None,
+        }
+    }
     /**
      * Updates an existing redirect.
      *
@@ -4069,6 +11947,27 @@ impl OnlineStore {
             )
             .await
     }
+    /**
+     * Retrieves a list of URL redirects. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-04/redirects.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_get_redirect`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/redirect#index-2020-04
+     */
+    pub fn deprecated_202004_get_redirect_request(
+        &self,
+    ) -> OnlineStoreDeprecated202004GetRedirectRequest<'_> {
+        OnlineStoreDeprecated202004GetRedirectRequest {
+            client: self,
+            limit: None,
+            since_id: None,
+            path: None,
+            target: None,
+            fields: None,
+        }
+    }
     /**
     * Creates a redirect. When you provide a full URL as the value of the path property, it will be saved as an absolute path without the domain.
              For example, "path": "http://www.johns-apparel.com/springwear" will be saved as "path": "springwear".
@@ -4131,6 +12030,24 @@ impl OnlineStore {
             )
             .await
     }
+    /**
+     * Retrieves a count of URL redirects.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-04/redirects/count.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_get_redirects_count`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/redirect#count-2020-04
+     */
+    pub fn deprecated_202004_get_redirects_count_request(
+        &self,
+    ) -> OnlineStoreDeprecated202004GetRedirectsCountRequest<'_> {
+        OnlineStoreDeprecated202004GetRedirectsCountRequest {
+            client: self,
+            path: None,
+            target: None,
+        }
+    }
     /**
      * Retrieves a single redirect.
      *
@@ -4171,6 +12088,25 @@ impl OnlineStore {
             )
             .await
     }
+    /**
+     * Retrieves a single redirect.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-04/redirects/{redirect_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_get_redirects_param_redirect`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/redirect#show-2020-04
+     */
+    pub fn deprecated_202004_get_redirects_param_redirect_request<'a>(
+        &'a self,
+        redirect_id: &'a str,
+    ) -> OnlineStoreDeprecated202004GetRedirectsParamRedirectRequest<'a> {
+        OnlineStoreDeprecated202004GetRedirectsParamRedirectRequest {
+            client: self,
+            redirect_id,
+            fields: None,
+        }
+    }
     /**
      * Updates an existing redirect.
      *
@@ -4291,6 +12227,27 @@ impl OnlineStore {
             )
             .await
     }
+    /**
+     * Retrieves a list of URL redirects. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-07/redirects.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_get_redirect`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/redirect#index-2020-07
+     */
+    pub fn deprecated_202007_get_redirect_request(
+        &self,
+    ) -> OnlineStoreDeprecated202007GetRedirectRequest<'_> {
+        OnlineStoreDeprecated202007GetRedirectRequest {
+            client: self,
+            limit: None,
+            since_id: None,
+            path: None,
+            target: None,
+            fields: None,
+        }
+    }
     /**
     * Creates a redirect. When you provide a full URL as the value of the path property, it will be saved as an absolute path without the domain.
              For example, "path": "http://www.johns-apparel.com/springwear" will be saved as "path": "springwear".
@@ -4353,6 +12310,24 @@ impl OnlineStore {
             )
             .await
     }
+    /**
+     * Retrieves a count of URL redirects.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-07/redirects/count.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_get_redirects_count`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/redirect#count-2020-07
+     */
+    pub fn deprecated_202007_get_redirects_count_request(
+        &self,
+    ) -> OnlineStoreDeprecated202007GetRedirectsCountRequest<'_> {
+        OnlineStoreDeprecated202007GetRedirectsCountRequest {
+            client: self,
+            path: None,
+            target: None,
+        }
+    }
     /**
      * Retrieves a single redirect.
      *
@@ -4393,6 +12368,25 @@ impl OnlineStore {
             )
             .await
     }
+    /**
+     * Retrieves a single redirect.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-07/redirects/{redirect_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_get_redirects_param_redirect`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/redirect#show-2020-07
+     */
+    pub fn deprecated_202007_get_redirects_param_redirect_request<'a>(
+        &'a self,
+        redirect_id: &'a str,
+    ) -> OnlineStoreDeprecated202007GetRedirectsParamRedirectRequest<'a> {
+        OnlineStoreDeprecated202007GetRedirectsParamRedirectRequest {
+            client: self,
+            redirect_id,
+            fields: None,
+        }
+    }
     /**
      * Updates an existing redirect.
      *
@@ -4513,6 +12507,25 @@ impl OnlineStore {
             )
             .await
     }
+    /**
+     * Retrieves a list of URL redirects. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-10/redirects.json` endpoint.
+     *
+     * As opposed to `get_redirect`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/redirect#index-2020-10
+     */
+    pub fn get_redirect_request(&self) -> OnlineStoreGetRedirectRequest<'_> {
+        OnlineStoreGetRedirectRequest {
+            client: self,
+            limit: None,
+            since_id: None,
+            path: None,
+            target: None,
+            fields: None,
+        }
+    }
     /**
     * Creates a redirect. When you provide a full URL as the value of the path property, it will be saved as an absolute path without the domain.
              For example, "path": "http://www.johns-apparel.com/springwear" will be saved as "path": "springwear".
@@ -4575,6 +12588,22 @@ impl OnlineStore {
             )
             .await
     }
+    /**
+     * Retrieves a count of URL redirects.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-10/redirects/count.json` endpoint.
+     *
+     * As opposed to `get_redirects_count`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/redirect#count-2020-10
+     */
+    pub fn get_redirects_count_request(&self) -> OnlineStoreGetRedirectsCountRequest<'_> {
+        OnlineStoreGetRedirectsCountRequest {
+            client: self,
+            path: None,
+            target: None,
+        }
+    }
     /**
      * Retrieves a single redirect.
      *
@@ -4615,6 +12644,25 @@ impl OnlineStore {
             )
             .await
     }
+    /**
+     * Retrieves a single redirect.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-10/redirects/{redirect_id}.json` endpoint.
+     *
+     * As opposed to `get_redirects_param_redirect`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/redirect#show-2020-10
+     */
+    pub fn get_redirects_param_redirect_request<'a>(
+        &'a self,
+        redirect_id: &'a str,
+    ) -> OnlineStoreGetRedirectsParamRedirectRequest<'a> {
+        OnlineStoreGetRedirectsParamRedirectRequest {
+            client: self,
+            redirect_id,
+            fields: None,
+        }
+    }
     /**
      * Updates an existing redirect.
      *
@@ -4735,6 +12783,27 @@ impl OnlineStore {
             )
             .await
     }
+    /**
+     * Retrieves a list of URL redirects. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2021-01/redirects.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_get_redirect`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/redirect#index-2021-01
+     */
+    pub fn deprecated_202101_get_redirect_request(
+        &self,
+    ) -> OnlineStoreDeprecated202101GetRedirectRequest<'_> {
+        OnlineStoreDeprecated202101GetRedirectRequest {
+            client: self,
+            limit: None,
+            since_id: None,
+            path: None,
+            target: None,
+            fields: None,
+        }
+    }
     /**
     * Creates a redirect. When you provide a full URL as the value of the path property, it will be saved as an absolute path without the domain.
              For example, "path": "http://www.johns-apparel.com/springwear" will be saved as "path": "springwear".
@@ -4797,6 +12866,24 @@ impl OnlineStore {
             )
             .await
     }
+    /**
+     * Retrieves a count of URL redirects.
+     *
+     * This function performs a `GET` to the `/admin/api/2021-01/redirects/count.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_get_redirects_count`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/redirect#count-2021-01
+     */
+    pub fn deprecated_202101_get_redirects_count_request(
+        &self,
+    ) -> OnlineStoreDeprecated202101GetRedirectsCountRequest<'_> {
+        OnlineStoreDeprecated202101GetRedirectsCountRequest {
+            client: self,
+            path: None,
+            target: None,
+        }
+    }
     /**
      * Retrieves a single redirect.
      *
@@ -4837,6 +12924,25 @@ impl OnlineStore {
             )
             .await
     }
+    /**
+     * Retrieves a single redirect.
+     *
+     * This function performs a `GET` to the `/admin/api/2021-01/redirects/{redirect_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_get_redirects_param_redirect`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/redirect#show-2021-01
+     */
+    pub fn deprecated_202101_get_redirects_param_redirect_request<'a>(
+        &'a self,
+        redirect_id: &'a str,
+    ) -> OnlineStoreDeprecated202101GetRedirectsParamRedirectRequest<'a> {
+        OnlineStoreDeprecated202101GetRedirectsParamRedirectRequest {
+            client: self,
+            redirect_id,
+            fields: None,
+        }
+    }
     /**
      * Updates an existing redirect.
      *
@@ -4957,6 +13063,27 @@ impl OnlineStore {
             )
             .await
     }
+    /**
+     * Retrieves a list of URL redirects. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/unstable/redirects.json` endpoint.
+     *
+     * As opposed to `deprecated_unstable_get_redirect`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/redirect#index-unstable
+     */
+    pub fn deprecated_unstable_get_redirect_request(
+        &self,
+    ) -> OnlineStoreDeprecatedUnstableGetRedirectRequest<'_> {
+        OnlineStoreDeprecatedUnstableGetRedirectRequest {
+            client: self,
+            limit: None,
+            since_id: None,
+            path: None,
+            target: None,
+            fields: None,
+        }
+    }
     /**
     * Creates a redirect. When you provide a full URL as the value of the path property, it will be saved as an absolute path without the domain.
              For example, "path": "http://www.johns-apparel.com/springwear" will be saved as "path": "springwear".
@@ -5019,6 +13146,24 @@ impl OnlineStore {
             )
             .await
     }
+    /**
+     * Retrieves a count of URL redirects.
+     *
+     * This function performs a `GET` to the `/admin/api/unstable/redirects/count.json` endpoint.
+     *
+     * As opposed to `deprecated_unstable_get_redirects_count`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/redirect#count-unstable
+     */
+    pub fn deprecated_unstable_get_redirects_count_request(
+        &self,
+    ) -> OnlineStoreDeprecatedUnstableGetRedirectsCountRequest<'_> {
+        OnlineStoreDeprecatedUnstableGetRedirectsCountRequest {
+            client: self,
+            path: None,
+            target: None,
+        }
+    }
     /**
      * Retrieves a single redirect.
      *
@@ -5059,6 +13204,25 @@ impl OnlineStore {
             )
             .await
     }
+    /**
+     * Retrieves a single redirect.
+     *
+     * This function performs a `GET` to the `/admin/api/unstable/redirects/{redirect_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_unstable_get_redirects_param_redirect`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/redirect#show-unstable
+     */
+    pub fn deprecated_unstable_get_redirects_param_redirect_request<'a>(
+        &'a self,
+        redirect_id: &'a str,
+    ) -> OnlineStoreDeprecatedUnstableGetRedirectsParamRedirectRequest<'a> {
+        OnlineStoreDeprecatedUnstableGetRedirectsParamRedirectRequest {
+            client: self,
+            redirect_id,
+            fields: None,
+        }
+    }
     /**
      * Updates an existing redirect.
      *
@@ -5194,6 +13358,30 @@ impl OnlineStore {
             )
             .await
     }
+    /**
+     * Retrieves a list of all script tags. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-01/script_tags.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_get_script_tag`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/scripttag#index-2020-01
+     */
+    pub fn deprecated_202001_get_script_tag_request(
+        &self,
+    ) -> OnlineStoreDeprecated202001GetScriptTagRequest<'_> {
+        OnlineStoreDeprecated202001GetScriptTagRequest {
+            client: self,
+            limit: None,
+            since_id: None,
+            created_at_min: None,
+            created_at_max: None,
+            updated_at_min: None,
+            updated_at_max: None,
+            src: None,
+            fields: None,
+        }
+    }
     /**
      * Creates a new script tag.
      *
@@ -5250,6 +13438,23 @@ impl OnlineStore {
             )
             .await
     }
+    /**
+     * Retrieves a count of all script tags.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-01/script_tags/count.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_get_script_tags_count`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/scripttag#count-2020-01
+     */
+    pub fn deprecated_202001_get_script_tags_count_request(
+        &self,
+    ) -> OnlineStoreDeprecated202001GetScriptTagsCountRequest<'_> {
+        OnlineStoreDeprecated202001GetScriptTagsCountRequest {
+            client: self,
+            src: None,
+        }
+    }
     /**
      * Retrieves a single script tag.
      *
@@ -5290,6 +13495,25 @@ impl OnlineStore {
             )
             .await
     }
+    /**
+     * Retrieves a single script tag.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-01/script_tags/{script_tag_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_get_script_tags_param_tag`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/scripttag#show-2020-01
+     */
+    pub fn deprecated_202001_get_script_tags_param_tag_request<'a>(
+        &'a self,
+        script_tag_id: &'a str,
+    ) -> OnlineStoreDeprecated202001GetScriptTagsParamTagRequest<'a> {
+        OnlineStoreDeprecated202001GetScriptTagsParamTagRequest {
+            client: self,
+            script_tag_id,
+            fields: None,
+        }
+    }
     /**
      * Updates a script tag.
      *
@@ -5425,6 +13649,30 @@ impl OnlineStore {
             )
             .await
     }
+    /**
+     * Retrieves a list of all script tags. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-04/script_tags.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_get_script_tag`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/scripttag#index-2020-04
+     */
+    pub fn deprecated_202004_get_script_tag_request(
+        &self,
+    ) -> OnlineStoreDeprecated202004GetScriptTagRequest<'_> {
+        OnlineStoreDeprecated202004GetScriptTagRequest {
+            client: self,
+            limit: None,
+            since_id: None,
+            created_at_min: None,
+            created_at_max: None,
+            updated_at_min: None,
+            updated_at_max: None,
+            src: None,
+            fields: None,
+        }
+    }
     /**
      * Creates a new script tag.
      *
@@ -5481,6 +13729,23 @@ impl OnlineStore {
             )
             .await
     }
+    /**
+     * Retrieves a count of all script tags.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-04/script_tags/count.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_get_script_tags_count`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/scripttag#count-2020-04
+     */
+    pub fn deprecated_202004_get_script_tags_count_request(
+        &self,
+    ) -> OnlineStoreDeprecated202004GetScriptTagsCountRequest<'_> {
+        OnlineStoreDeprecated202004GetScriptTagsCountRequest {
+            client: self,
+            src: None,
+        }
+    }
     /**
      * Retrieves a single script tag.
      *
@@ -5521,6 +13786,25 @@ impl OnlineStore {
             )
             .await
     }
+    /**
+     * Retrieves a single script tag.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-04/script_tags/{script_tag_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_get_script_tags_param_tag`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/scripttag#show-2020-04
+     */
+    pub fn deprecated_202004_get_script_tags_param_tag_request<'a>(
+        &'a self,
+        script_tag_id: &'a str,
+    ) -> OnlineStoreDeprecated202004GetScriptTagsParamTagRequest<'a> {
+        OnlineStoreDeprecated202004GetScriptTagsParamTagRequest {
+            client: self,
+            script_tag_id,
+            fields: None,
+        }
+    }
     /**
      * Updates a script tag.
      *
@@ -5656,6 +13940,30 @@ impl OnlineStore {
             )
             .await
     }
+    /**
+     * Retrieves a list of all script tags. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-07/script_tags.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_get_script_tag`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/scripttag#index-2020-07
+     */
+    pub fn deprecated_202007_get_script_tag_request(
+        &self,
+    ) -> OnlineStoreDeprecated202007GetScriptTagRequest<'_> {
+        OnlineStoreDeprecated202007GetScriptTagRequest {
+            client: self,
+            limit: None,
+            since_id: None,
+            created_at_min: None,
+            created_at_max: None,
+            updated_at_min: None,
+            updated_at_max: None,
+            src: None,
+            fields: None,
+        }
+    }
     /**
      * Creates a new script tag.
      *
@@ -5712,6 +14020,23 @@ impl OnlineStore {
             )
             .await
     }
+    /**
+     * Retrieves a count of all script tags.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-07/script_tags/count.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_get_script_tags_count`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/scripttag#count-2020-07
+     */
+    pub fn deprecated_202007_get_script_tags_count_request(
+        &self,
+    ) -> OnlineStoreDeprecated202007GetScriptTagsCountRequest<'_> {
+        OnlineStoreDeprecated202007GetScriptTagsCountRequest {
+            client: self,
+            src: None,
+        }
+    }
     /**
      * Retrieves a single script tag.
      *
@@ -5752,6 +14077,25 @@ impl OnlineStore {
             )
             .await
     }
+    /**
+     * Retrieves a single script tag.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-07/script_tags/{script_tag_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_get_script_tags_param_tag`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/scripttag#show-2020-07
+     */
+    pub fn deprecated_202007_get_script_tags_param_tag_request<'a>(
+        &'a self,
+        script_tag_id: &'a str,
+    ) -> OnlineStoreDeprecated202007GetScriptTagsParamTagRequest<'a> {
+        OnlineStoreDeprecated202007GetScriptTagsParamTagRequest {
+            client: self,
+            script_tag_id,
+            fields: None,
+        }
+    }
     /**
      * Updates a script tag.
      *
@@ -5887,6 +14231,28 @@ impl OnlineStore {
             )
             .await
     }
+    /**
+     * Retrieves a list of all script tags. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-10/script_tags.json` endpoint.
+     *
+     * As opposed to `get_script_tag`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/scripttag#index-2020-10
+     */
+    pub fn get_script_tag_request(&self) -> OnlineStoreGetScriptTagRequest<'_> {
+        OnlineStoreGetScriptTagRequest {
+            client: self,
+            limit: None,
+            since_id: None,
+            created_at_min: None,
+            created_at_max: None,
+            updated_at_min: None,
+            updated_at_max: None,
+            src: None,
+            fields: None,
+        }
+    }
     /**
      * Creates a new script tag.
      *
@@ -5940,6 +14306,21 @@ impl OnlineStore {
             )
             .await
     }
+    /**
+     * Retrieves a count of all script tags.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-10/script_tags/count.json` endpoint.
+     *
+     * As opposed to `get_script_tags_count`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/scripttag#count-2020-10
+     */
+    pub fn get_script_tags_count_request(&self) -> OnlineStoreGetScriptTagsCountRequest<'_> {
+        OnlineStoreGetScriptTagsCountRequest {
+            client: self,
+            src: None,
+        }
+    }
     /**
      * Retrieves a single script tag.
      *
@@ -5980,6 +14361,25 @@ impl OnlineStore {
             )
             .await
     }
+    /**
+     * Retrieves a single script tag.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-10/script_tags/{script_tag_id}.json` endpoint.
+     *
+     * As opposed to `get_script_tags_param_tag`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/scripttag#show-2020-10
+     */
+    pub fn get_script_tags_param_tag_request<'a>(
+        &'a self,
+        script_tag_id: &'a str,
+    ) -> OnlineStoreGetScriptTagsParamTagRequest<'a> {
+        OnlineStoreGetScriptTagsParamTagRequest {
+            client: self,
+            script_tag_id,
+            fields: None,
+        }
+    }
     /**
      * Updates a script tag.
      *
@@ -6115,6 +14515,30 @@ impl OnlineStore {
             )
             .await
     }
+    /**
+     * Retrieves a list of all script tags. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2021-01/script_tags.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_get_script_tag`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/scripttag#index-2021-01
+     */
+    pub fn deprecated_202101_get_script_tag_request(
+        &self,
+    ) -> OnlineStoreDeprecated202101GetScriptTagRequest<'_> {
+        OnlineStoreDeprecated202101GetScriptTagRequest {
+            client: self,
+            limit: None,
+            since_id: None,
+            created_at_min: None,
+            created_at_max: None,
+            updated_at_min: None,
+            updated_at_max: None,
+            src: None,
+            fields: None,
+        }
+    }
     /**
      * Creates a new script tag.
      *
@@ -6171,6 +14595,23 @@ impl OnlineStore {
             )
             .await
     }
+    /**
+     * Retrieves a count of all script tags.
+     *
+     * This function performs a `GET` to the `/admin/api/2021-01/script_tags/count.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_get_script_tags_count`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/scripttag#count-2021-01
+     */
+    pub fn deprecated_202101_get_script_tags_count_request(
+        &self,
+    ) -> OnlineStoreDeprecated202101GetScriptTagsCountRequest<'_> {
+        OnlineStoreDeprecated202101GetScriptTagsCountRequest {
+            client: self,
+            src: None,
+        }
+    }
     /**
      * Retrieves a single script tag.
      *
@@ -6211,6 +14652,25 @@ impl OnlineStore {
             )
             .await
     }
+    /**
+     * Retrieves a single script tag.
+     *
+     * This function performs a `GET` to the `/admin/api/2021-01/script_tags/{script_tag_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_get_script_tags_param_tag`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/scripttag#show-2021-01
+     */
+    pub fn deprecated_202101_get_script_tags_param_tag_request<'a>(
+        &'a self,
+        script_tag_id: &'a str,
+    ) -> OnlineStoreDeprecated202101GetScriptTagsParamTagRequest<'a> {
+        OnlineStoreDeprecated202101GetScriptTagsParamTagRequest {
+            client: self,
+            script_tag_id,
+            fields: None,
+        }
+    }
     /**
      * Updates a script tag.
      *
@@ -6346,6 +14806,30 @@ impl OnlineStore {
             )
             .await
     }
+    /**
+     * Retrieves a list of all script tags. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/unstable/script_tags.json` endpoint.
+     *
+     * As opposed to `deprecated_unstable_get_script_tag`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/scripttag#index-unstable
+     */
+    pub fn deprecated_unstable_get_script_tag_request(
+        &self,
+    ) -> OnlineStoreDeprecatedUnstableGetScriptTagRequest<'_> {
+        OnlineStoreDeprecatedUnstableGetScriptTagRequest {
+            client: self,
+            limit: None,
+            since_id: None,
+            created_at_min: None,
+            created_at_max: None,
+            updated_at_min: None,
+            updated_at_max: None,
+            src: None,
+            fields: None,
+        }
+    }
     /**
      * Creates a new script tag.
      *
@@ -6404,6 +14888,23 @@ impl OnlineStore {
             )
             .await
     }
+    /**
+     * Retrieves a count of all script tags.
+     *
+     * This function performs a `GET` to the `/admin/api/unstable/script_tags/count.json` endpoint.
+     *
+     * As opposed to `deprecated_unstable_get_script_tags_count`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/scripttag#count-unstable
+     */
+    pub fn deprecated_unstable_get_script_tags_count_request(
+        &self,
+    ) -> OnlineStoreDeprecatedUnstableGetScriptTagsCountRequest<'_> {
+        OnlineStoreDeprecatedUnstableGetScriptTagsCountRequest {
+            client: self,
+            src: None,
+        }
+    }
     /**
      * Retrieves a single script tag.
      *
@@ -6444,6 +14945,25 @@ impl OnlineStore {
             )
             .await
     }
+    /**
+     * Retrieves a single script tag.
+     *
+     * This function performs a `GET` to the `/admin/api/unstable/script_tags/{script_tag_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_unstable_get_script_tags_param_tag`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/scripttag#show-unstable
+     */
+    pub fn deprecated_unstable_get_script_tags_param_tag_request<'a>(
+        &'a self,
+        script_tag_id: &'a str,
+    ) -> OnlineStoreDeprecatedUnstableGetScriptTagsParamTagRequest<'a> {
+        OnlineStoreDeprecatedUnstableGetScriptTagsParamTagRequest {
+            client: self,
+            script_tag_id,
+            fields: None,
+        }
+    }
     /**
      * Updates a script tag.
      *
@@ -6542,6 +15062,23 @@ impl OnlineStore {
             )
             .await
     }
+    /**
+     * Retrieves a list of themes.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-01/themes.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_get_theme`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/theme#index-2020-01
+     */
+    pub fn deprecated_202001_get_theme_request(
+        &self,
+    ) -> OnlineStoreDeprecated202001GetThemeRequest<'_> {
+        OnlineStoreDeprecated202001GetThemeRequest {
+            client: self,
+            fields: None,
+        }
+    }
     /**
     * Creates a theme by providing the public URL of a ZIP file that contains the theme.
              A new theme is always unpublished by default. To publish a theme when you create it, include
@@ -6607,6 +15144,25 @@ impl OnlineStore {
             )
             .await
     }
+    /**
+     * Retrieves a single theme.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-01/themes/{theme_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_get_themes_param_theme`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/theme#show-2020-01
+     */
+    pub fn deprecated_202001_get_themes_param_theme_request<'a>(
+        &'a self,
+        theme_id: &'a str,
+    ) -> OnlineStoreDeprecated202001GetThemesParamThemeRequest<'a> {
+        OnlineStoreDeprecated202001GetThemesParamThemeRequest {
+            client: self,
+            theme_id,
+            fields: None,
+        }
+    }
     /**
      * Updates an existing theme.
      *
@@ -6705,6 +15261,23 @@ impl OnlineStore {
             )
             .await
     }
+    /**
+     * Retrieves a list of themes.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-04/themes.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_get_theme`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/theme#index-2020-04
+     */
+    pub fn deprecated_202004_get_theme_request(
+        &self,
+    ) -> OnlineStoreDeprecated202004GetThemeRequest<'_> {
+        OnlineStoreDeprecated202004GetThemeRequest {
+            client: self,
+            fields: None,
+        }
+    }
     /**
     * Creates a theme by providing the public URL of a ZIP file that contains the theme.
              A new theme is always unpublished by default. To publish a theme when you create it, include
@@ -6770,6 +15343,25 @@ impl OnlineStore {
             )
             .await
     }
+    /**
+     * Retrieves a single theme.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-04/themes/{theme_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_get_themes_param_theme`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/theme#show-2020-04
+     */
+    pub fn deprecated_202004_get_themes_param_theme_request<'a>(
+        &'a self,
+        theme_id: &'a str,
+    ) -> OnlineStoreDeprecated202004GetThemesParamThemeRequest<'a> {
+        OnlineStoreDeprecated202004GetThemesParamThemeRequest {
+            client: self,
+            theme_id,
+            fields: None,
+        }
+    }
     /**
      * Updates an existing theme.
      *
@@ -6868,6 +15460,23 @@ impl OnlineStore {
             )
             .await
     }
+    /**
+     * Retrieves a list of themes.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-07/themes.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_get_theme`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/theme#index-2020-07
+     */
+    pub fn deprecated_202007_get_theme_request(
+        &self,
+    ) -> OnlineStoreDeprecated202007GetThemeRequest<'_> {
+        OnlineStoreDeprecated202007GetThemeRequest {
+            client: self,
+            fields: None,
+        }
+    }
     /**
     * Creates a theme by providing the public URL of a ZIP file that contains the theme.
              A new theme is always unpublished by default. To publish a theme when you create it, include
@@ -6933,6 +15542,25 @@ impl OnlineStore {
             )
             .await
     }
+    /**
+     * Retrieves a single theme.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-07/themes/{theme_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_get_themes_param_theme`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/theme#show-2020-07
+     */
+    pub fn deprecated_202007_get_themes_param_theme_request<'a>(
+        &'a self,
+        theme_id: &'a str,
+    ) -> OnlineStoreDeprecated202007GetThemesParamThemeRequest<'a> {
+        OnlineStoreDeprecated202007GetThemesParamThemeRequest {
+            client: self,
+            theme_id,
+            fields: None,
+        }
+    }
     /**
      * Updates an existing theme.
      *
@@ -7028,6 +15656,21 @@ impl OnlineStore {
             )
             .await
     }
+    /**
+     * Retrieves a list of themes.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-10/themes.json` endpoint.
+     *
+     * As opposed to `get_theme`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/theme#index-2020-10
+     */
+    pub fn get_theme_request(&self) -> OnlineStoreGetThemeRequest<'_> {
+        OnlineStoreGetThemeRequest {
+            client: self,
+            fields: None,
+        }
+    }
     /**
     * Creates a theme by providing the public URL of a ZIP file that contains the theme.
              A new theme is always unpublished by default. To publish a theme when you create it, include
@@ -7093,6 +15736,25 @@ impl OnlineStore {
             )
             .await
     }
+    /**
+     * Retrieves a single theme.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-10/themes/{theme_id}.json` endpoint.
+     *
+     * As opposed to `get_themes_param_theme`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/theme#show-2020-10
+     */
+    pub fn get_themes_param_theme_request<'a>(
+        &'a self,
+        theme_id: &'a str,
+    ) -> OnlineStoreGetThemesParamThemeRequest<'a> {
+        OnlineStoreGetThemesParamThemeRequest {
+            client: self,
+            theme_id,
+            fields: None,
+        }
+    }
     /**
      * Updates an existing theme.
      *
@@ -7191,6 +15853,23 @@ impl OnlineStore {
             )
             .await
     }
+    /**
+     * Retrieves a list of themes.
+     *
+     * This function performs a `GET` to the `/admin/api/2021-01/themes.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_get_theme`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/theme#index-2021-01
+     */
+    pub fn deprecated_202101_get_theme_request(
+        &self,
+    ) -> OnlineStoreDeprecated202101GetThemeRequest<'_> {
+        OnlineStoreDeprecated202101GetThemeRequest {
+            client: self,
+            fields: None,
+        }
+    }
     /**
     * Creates a theme by providing the public URL of a ZIP file that contains the theme.
              A new theme is always unpublished by default. To publish a theme when you create it, include
@@ -7256,6 +15935,25 @@ impl OnlineStore {
             )
             .await
     }
+    /**
+     * Retrieves a single theme.
+     *
+     * This function performs a `GET` to the `/admin/api/2021-01/themes/{theme_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_get_themes_param_theme`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/theme#show-2021-01
+     */
+    pub fn deprecated_202101_get_themes_param_theme_request<'a>(
+        &'a self,
+        theme_id: &'a str,
+    ) -> OnlineStoreDeprecated202101GetThemesParamThemeRequest<'a> {
+        OnlineStoreDeprecated202101GetThemesParamThemeRequest {
+            client: self,
+            theme_id,
+            fields: None,
+        }
+    }
     /**
      * Updates an existing theme.
      *
@@ -7354,6 +16052,23 @@ impl OnlineStore {
             )
             .await
     }
+    /**
+     * Retrieves a list of themes.
+     *
+     * This function performs a `GET` to the `/admin/api/unstable/themes.json` endpoint.
+     *
+     * As opposed to `deprecated_unstable_get_theme`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/theme#index-unstable
+     */
+    pub fn deprecated_unstable_get_theme_request(
+        &self,
+    ) -> OnlineStoreDeprecatedUnstableGetThemeRequest<'_> {
+        OnlineStoreDeprecatedUnstableGetThemeRequest {
+            client: self,
+            fields: None,
+        }
+    }
     /**
     * Creates a theme by providing the public URL of a ZIP file that contains the theme.
              A new theme is always unpublished by default. To publish a theme when you create it, include
@@ -7419,6 +16134,25 @@ impl OnlineStore {
             )
             .await
     }
+    /**
+     * Retrieves a single theme.
+     *
+     * This function performs a `GET` to the `/admin/api/unstable/themes/{theme_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_unstable_get_themes_param_theme`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/theme#show-unstable
+     */
+    pub fn deprecated_unstable_get_themes_param_theme_request<'a>(
+        &'a self,
+        theme_id: &'a str,
+    ) -> OnlineStoreDeprecatedUnstableGetThemesParamThemeRequest<'a> {
+        OnlineStoreDeprecatedUnstableGetThemesParamThemeRequest {
+            client: self,
+            theme_id,
+            fields: None,
+        }
+    }
     /**
      * Updates an existing theme.
      *
@@ -1,6 +1,3688 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Orders::deprecated_202001_get_checkouts_count`], set with a builder.
+pub struct OrdersDeprecated202001GetCheckoutsCountRequest<'a> {
+    client: &'a Orders,
+    since_id: Option<&'a str>,
+    created_at_min: Option<&'a str>,
+    created_at_max: Option<&'a str>,
+    updated_at_min: Option<&'a str>,
+    updated_at_max: Option<&'a str>,
+    status: Option<&'a str>,
+}
+
+impl<'a> OrdersDeprecated202001GetCheckoutsCountRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.created_at_min = Some(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.created_at_max = Some(created_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.updated_at_min = Some(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.updated_at_max = Some(updated_at_max);
+        self
+    }
+
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: &'a str) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            since_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            status,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = created_at_max {
+            query_args.push(("created_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = created_at_min {
+            query_args.push(("created_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = status {
+            query_args.push(("status".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_max {
+            query_args.push(("updated_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_min {
+            query_args.push(("updated_at_min".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-01/checkouts/count.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Orders::deprecated_202004_get_checkouts_count`], set with a builder.
+pub struct OrdersDeprecated202004GetCheckoutsCountRequest<'a> {
+    client: &'a Orders,
+    since_id: Option<&'a str>,
+    created_at_min: Option<&'a str>,
+    created_at_max: Option<&'a str>,
+    updated_at_min: Option<&'a str>,
+    updated_at_max: Option<&'a str>,
+    status: Option<&'a str>,
+}
+
+impl<'a> OrdersDeprecated202004GetCheckoutsCountRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.created_at_min = Some(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.created_at_max = Some(created_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.updated_at_min = Some(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.updated_at_max = Some(updated_at_max);
+        self
+    }
+
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: &'a str) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            since_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            status,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = created_at_max {
+            query_args.push(("created_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = created_at_min {
+            query_args.push(("created_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = status {
+            query_args.push(("status".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_max {
+            query_args.push(("updated_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_min {
+            query_args.push(("updated_at_min".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-04/checkouts/count.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Orders::deprecated_202007_get_checkouts_count`], set with a builder.
+pub struct OrdersDeprecated202007GetCheckoutsCountRequest<'a> {
+    client: &'a Orders,
+    since_id: Option<&'a str>,
+    created_at_min: Option<&'a str>,
+    created_at_max: Option<&'a str>,
+    updated_at_min: Option<&'a str>,
+    updated_at_max: Option<&'a str>,
+    status: Option<&'a str>,
+}
+
+impl<'a> OrdersDeprecated202007GetCheckoutsCountRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.created_at_min = Some(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.created_at_max = Some(created_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.updated_at_min = Some(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.updated_at_max = Some(updated_at_max);
+        self
+    }
+
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: &'a str) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            since_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            status,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = created_at_max {
+            query_args.push(("created_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = created_at_min {
+            query_args.push(("created_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = status {
+            query_args.push(("status".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_max {
+            query_args.push(("updated_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_min {
+            query_args.push(("updated_at_min".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-07/checkouts/count.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Orders::get_checkouts_count`], set with a builder.
+pub struct OrdersGetCheckoutsCountRequest<'a> {
+    client: &'a Orders,
+    since_id: Option<&'a str>,
+    created_at_min: Option<&'a str>,
+    created_at_max: Option<&'a str>,
+    updated_at_min: Option<&'a str>,
+    updated_at_max: Option<&'a str>,
+    status: Option<&'a str>,
+}
+
+impl<'a> OrdersGetCheckoutsCountRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.created_at_min = Some(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.created_at_max = Some(created_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.updated_at_min = Some(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.updated_at_max = Some(updated_at_max);
+        self
+    }
+
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: &'a str) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            since_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            status,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = created_at_max {
+            query_args.push(("created_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = created_at_min {
+            query_args.push(("created_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = status {
+            query_args.push(("status".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_max {
+            query_args.push(("updated_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_min {
+            query_args.push(("updated_at_min".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-10/checkouts/count.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Orders::deprecated_202101_get_checkouts_count`], set with a builder.
+pub struct OrdersDeprecated202101GetCheckoutsCountRequest<'a> {
+    client: &'a Orders,
+    since_id: Option<&'a str>,
+    created_at_min: Option<&'a str>,
+    created_at_max: Option<&'a str>,
+    updated_at_min: Option<&'a str>,
+    updated_at_max: Option<&'a str>,
+    status: Option<&'a str>,
+}
+
+impl<'a> OrdersDeprecated202101GetCheckoutsCountRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.created_at_min = Some(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.created_at_max = Some(created_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.updated_at_min = Some(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.updated_at_max = Some(updated_at_max);
+        self
+    }
+
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: &'a str) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            since_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            status,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = created_at_max {
+            query_args.push(("created_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = created_at_min {
+            query_args.push(("created_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = status {
+            query_args.push(("status".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_max {
+            query_args.push(("updated_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_min {
+            query_args.push(("updated_at_min".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2021-01/checkouts/count.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Orders::deprecated_unstable_get_checkouts_count`], set with a builder.
+pub struct OrdersDeprecatedUnstableGetCheckoutsCountRequest<'a> {
+    client: &'a Orders,
+    since_id: Option<&'a str>,
+    created_at_min: Option<&'a str>,
+    created_at_max: Option<&'a str>,
+    updated_at_min: Option<&'a str>,
+    updated_at_max: Option<&'a str>,
+    status: Option<&'a str>,
+}
+
+impl<'a> OrdersDeprecatedUnstableGetCheckoutsCountRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.created_at_min = Some(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.created_at_max = Some(created_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.updated_at_min = Some(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.updated_at_max = Some(updated_at_max);
+        self
+    }
+
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: &'a str) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            since_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            status,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = created_at_max {
+            query_args.push(("created_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = created_at_min {
+            query_args.push(("created_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = status {
+            query_args.push(("status".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_max {
+            query_args.push(("updated_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_min {
+            query_args.push(("updated_at_min".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/unstable/checkouts/count.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Orders::deprecated_202001_get`], set with a builder.
+pub struct OrdersDeprecated202001GetRequest<'a> {
+    client: &'a Orders,
+    ids: Option<&'a str>,
+    limit: Option<&'a str>,
+    since_id: Option<&'a str>,
+    created_at_min: Option<&'a str>,
+    created_at_max: Option<&'a str>,
+    updated_at_min: Option<&'a str>,
+    updated_at_max: Option<&'a str>,
+    processed_at_min: Option<&'a str>,
+    processed_at_max: Option<&'a str>,
+    attribution_app_id: Option<&'a str>,
+    status: Option<&'a str>,
+    financial_status: Option<&'a str>,
+    fulfillment_status: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> OrdersDeprecated202001GetRequest<'a> {
+    /// Sets the `ids` query parameter.
+    pub fn ids(mut self, ids: &'a str) -> Self {
+        self.ids = Some(ids);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.created_at_min = Some(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.created_at_max = Some(created_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.updated_at_min = Some(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.updated_at_max = Some(updated_at_max);
+        self
+    }
+
+    /// Sets the `processed_at_min` query parameter.
+    pub fn processed_at_min(mut self, processed_at_min: &'a str) -> Self {
+        self.processed_at_min = Some(processed_at_min);
+        self
+    }
+
+    /// Sets the `processed_at_max` query parameter.
+    pub fn processed_at_max(mut self, processed_at_max: &'a str) -> Self {
+        self.processed_at_max = Some(processed_at_max);
+        self
+    }
+
+    /// Sets the `attribution_app_id` query parameter.
+    pub fn attribution_app_id(mut self, attribution_app_id: &'a str) -> Self {
+        self.attribution_app_id = Some(attribution_app_id);
+        self
+    }
+
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: &'a str) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Sets the `financial_status` query parameter.
+    pub fn financial_status(mut self, financial_status: &'a str) -> Self {
+        self.financial_status = Some(financial_status);
+        self
+    }
+
+    /// Sets the `fulfillment_status` query parameter.
+    pub fn fulfillment_status(mut self, fulfillment_status: &'a str) -> Self {
+        self.fulfillment_status = Some(fulfillment_status);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            ids,
+            limit,
+            since_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            processed_at_min,
+            processed_at_max,
+            attribution_app_id,
+            status,
+            financial_status,
+            fulfillment_status,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = attribution_app_id {
+            query_args.push(("attribution_app_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = created_at_max {
+            query_args.push(("created_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = created_at_min {
+            query_args.push(("created_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = financial_status {
+            query_args.push(("financial_status".to_string(), v.to_string()));
+        }
+        if let Some(v) = fulfillment_status {
+            query_args.push(("fulfillment_status".to_string(), v.to_string()));
+        }
+        if let Some(v) = ids {
+            query_args.push(("ids".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = processed_at_max {
+            query_args.push(("processed_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = processed_at_min {
+            query_args.push(("processed_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = status {
+            query_args.push(("status".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_max {
+            query_args.push(("updated_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_min {
+            query_args.push(("updated_at_min".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/admin/api/2020-01/orders.json?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Orders::deprecated_202001_get_param`], set with a builder.
+pub struct OrdersDeprecated202001GetParamRequest<'a> {
+    client: &'a Orders,
+    order_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> OrdersDeprecated202001GetParamRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            order_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-01/orders/{}/json?{}",
+                crate::progenitor_support::encode_path(order_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Orders::deprecated_202001_get_count`], set with a builder.
+pub struct OrdersDeprecated202001GetCountRequest<'a> {
+    client: &'a Orders,
+    created_at_min: Option<&'a str>,
+    created_at_max: Option<&'a str>,
+    updated_at_min: Option<&'a str>,
+    updated_at_max: Option<&'a str>,
+    status: Option<&'a str>,
+    financial_status: Option<&'a str>,
+    fulfillment_status: Option<&'a str>,
+}
+
+impl<'a> OrdersDeprecated202001GetCountRequest<'a> {
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.created_at_min = Some(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.created_at_max = Some(created_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.updated_at_min = Some(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.updated_at_max = Some(updated_at_max);
+        self
+    }
+
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: &'a str) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Sets the `financial_status` query parameter.
+    pub fn financial_status(mut self, financial_status: &'a str) -> Self {
+        self.financial_status = Some(financial_status);
+        self
+    }
+
+    /// Sets the `fulfillment_status` query parameter.
+    pub fn fulfillment_status(mut self, fulfillment_status: &'a str) -> Self {
+        self.fulfillment_status = Some(fulfillment_status);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            status,
+            financial_status,
+            fulfillment_status,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = created_at_max {
+            query_args.push(("created_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = created_at_min {
+            query_args.push(("created_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = financial_status {
+            query_args.push(("financial_status".to_string(), v.to_string()));
+        }
+        if let Some(v) = fulfillment_status {
+            query_args.push(("fulfillment_status".to_string(), v.to_string()));
+        }
+        if let Some(v) = status {
+            query_args.push(("status".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_max {
+            query_args.push(("updated_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_min {
+            query_args.push(("updated_at_min".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-01/orders/count.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Orders::deprecated_202001_create_param_cancel`], set with a builder.
+pub struct OrdersDeprecated202001CreateParamCancelRequest<'a> {
+    client: &'a Orders,
+    order_id: &'a str,
+    amount: Option<&'a str>,
+    currency: Option<&'a str>,
+    restock_deprecated: Option<&'a str>,
+    reason: Option<&'a str>,
+    email: Option<&'a str>,
+    refund: Option<&'a str>,
+    body: &'a serde_json::Value,
+}
+
+impl<'a> OrdersDeprecated202001CreateParamCancelRequest<'a> {
+    /// Sets the `amount` query parameter.
+    pub fn amount(mut self, amount: &'a str) -> Self {
+        self.amount = Some(amount);
+        self
+    }
+
+    /// Sets the `currency` query parameter.
+    pub fn currency(mut self, currency: &'a str) -> Self {
+        self.currency = Some(currency);
+        self
+    }
+
+    /// Sets the `restock deprecated` query parameter.
+    pub fn restock_deprecated(mut self, restock_deprecated: &'a str) -> Self {
+        self.restock_deprecated = Some(restock_deprecated);
+        self
+    }
+
+    /// Sets the `reason` query parameter.
+    pub fn reason(mut self, reason: &'a str) -> Self {
+        self.reason = Some(reason);
+        self
+    }
+
+    /// Sets the `email` query parameter.
+    pub fn email(mut self, email: &'a str) -> Self {
+        self.email = Some(email);
+        self
+    }
+
+    /// Sets the `refund` query parameter.
+    pub fn refund(mut self, refund: &'a str) -> Self {
+        self.refund = Some(refund);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            order_id,
+            amount,
+            currency,
+            restock_deprecated,
+            reason,
+            email,
+            refund,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = amount {
+            query_args.push(("amount".to_string(), v.to_string()));
+        }
+        if let Some(v) = currency {
+            query_args.push(("currency".to_string(), v.to_string()));
+        }
+        if let Some(v) = email {
+            query_args.push(("email".to_string(), v.to_string()));
+        }
+        if let Some(v) = reason {
+            query_args.push(("reason".to_string(), v.to_string()));
+        }
+        if let Some(v) = refund {
+            query_args.push(("refund".to_string(), v.to_string()));
+        }
+        if let Some(v) = restock_deprecated {
+            query_args.push((
+                "restock
+                  deprecated"
+                    .to_string(),
+                v.to_string(),
+            ));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-01/orders/{}/cancel.json?{}",
+                crate::progenitor_support::encode_path(order_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Orders::deprecated_202004_get`], set with a builder.
+pub struct OrdersDeprecated202004GetRequest<'a> {
+    client: &'a Orders,
+    ids: Option<&'a str>,
+    limit: Option<&'a str>,
+    since_id: Option<&'a str>,
+    created_at_min: Option<&'a str>,
+    created_at_max: Option<&'a str>,
+    updated_at_min: Option<&'a str>,
+    updated_at_max: Option<&'a str>,
+    processed_at_min: Option<&'a str>,
+    processed_at_max: Option<&'a str>,
+    attribution_app_id: Option<&'a str>,
+    status: Option<&'a str>,
+    financial_status: Option<&'a str>,
+    fulfillment_status: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> OrdersDeprecated202004GetRequest<'a> {
+    /// Sets the `ids` query parameter.
+    pub fn ids(mut self, ids: &'a str) -> Self {
+        self.ids = Some(ids);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.created_at_min = Some(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.created_at_max = Some(created_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.updated_at_min = Some(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.updated_at_max = Some(updated_at_max);
+        self
+    }
+
+    /// Sets the `processed_at_min` query parameter.
+    pub fn processed_at_min(mut self, processed_at_min: &'a str) -> Self {
+        self.processed_at_min = Some(processed_at_min);
+        self
+    }
+
+    /// Sets the `processed_at_max` query parameter.
+    pub fn processed_at_max(mut self, processed_at_max: &'a str) -> Self {
+        self.processed_at_max = Some(processed_at_max);
+        self
+    }
+
+    /// Sets the `attribution_app_id` query parameter.
+    pub fn attribution_app_id(mut self, attribution_app_id: &'a str) -> Self {
+        self.attribution_app_id = Some(attribution_app_id);
+        self
+    }
+
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: &'a str) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Sets the `financial_status` query parameter.
+    pub fn financial_status(mut self, financial_status: &'a str) -> Self {
+        self.financial_status = Some(financial_status);
+        self
+    }
+
+    /// Sets the `fulfillment_status` query parameter.
+    pub fn fulfillment_status(mut self, fulfillment_status: &'a str) -> Self {
+        self.fulfillment_status = Some(fulfillment_status);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            ids,
+            limit,
+            since_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            processed_at_min,
+            processed_at_max,
+            attribution_app_id,
+            status,
+            financial_status,
+            fulfillment_status,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = attribution_app_id {
+            query_args.push(("attribution_app_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = created_at_max {
+            query_args.push(("created_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = created_at_min {
+            query_args.push(("created_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = financial_status {
+            query_args.push(("financial_status".to_string(), v.to_string()));
+        }
+        if let Some(v) = fulfillment_status {
+            query_args.push(("fulfillment_status".to_string(), v.to_string()));
+        }
+        if let Some(v) = ids {
+            query_args.push(("ids".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = processed_at_max {
+            query_args.push(("processed_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = processed_at_min {
+            query_args.push(("processed_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = status {
+            query_args.push(("status".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_max {
+            query_args.push(("updated_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_min {
+            query_args.push(("updated_at_min".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/admin/api/2020-04/orders.json?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Orders::deprecated_202004_get_param`], set with a builder.
+pub struct OrdersDeprecated202004GetParamRequest<'a> {
+    client: &'a Orders,
+    order_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> OrdersDeprecated202004GetParamRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            order_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-04/orders/{}/json?{}",
+                crate::progenitor_support::encode_path(order_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Orders::deprecated_202004_get_count`], set with a builder.
+pub struct OrdersDeprecated202004GetCountRequest<'a> {
+    client: &'a Orders,
+    created_at_min: Option<&'a str>,
+    created_at_max: Option<&'a str>,
+    updated_at_min: Option<&'a str>,
+    updated_at_max: Option<&'a str>,
+    status: Option<&'a str>,
+    financial_status: Option<&'a str>,
+    fulfillment_status: Option<&'a str>,
+}
+
+impl<'a> OrdersDeprecated202004GetCountRequest<'a> {
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.created_at_min = Some(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.created_at_max = Some(created_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.updated_at_min = Some(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.updated_at_max = Some(updated_at_max);
+        self
+    }
+
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: &'a str) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Sets the `financial_status` query parameter.
+    pub fn financial_status(mut self, financial_status: &'a str) -> Self {
+        self.financial_status = Some(financial_status);
+        self
+    }
+
+    /// Sets the `fulfillment_status` query parameter.
+    pub fn fulfillment_status(mut self, fulfillment_status: &'a str) -> Self {
+        self.fulfillment_status = Some(fulfillment_status);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            status,
+            financial_status,
+            fulfillment_status,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = created_at_max {
+            query_args.push(("created_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = created_at_min {
+            query_args.push(("created_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = financial_status {
+            query_args.push(("financial_status".to_string(), v.to_string()));
+        }
+        if let Some(v) = fulfillment_status {
+            query_args.push(("fulfillment_status".to_string(), v.to_string()));
+        }
+        if let Some(v) = status {
+            query_args.push(("status".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_max {
+            query_args.push(("updated_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_min {
+            query_args.push(("updated_at_min".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-04/orders/count.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Orders::deprecated_202004_create_param_cancel`], set with a builder.
+pub struct OrdersDeprecated202004CreateParamCancelRequest<'a> {
+    client: &'a Orders,
+    order_id: &'a str,
+    amount: Option<&'a str>,
+    currency: Option<&'a str>,
+    restock_deprecated: Option<&'a str>,
+    reason: Option<&'a str>,
+    email: Option<&'a str>,
+    refund: Option<&'a str>,
+    body: &'a serde_json::Value,
+}
+
+impl<'a> OrdersDeprecated202004CreateParamCancelRequest<'a> {
+    /// Sets the `amount` query parameter.
+    pub fn amount(mut self, amount: &'a str) -> Self {
+        self.amount = Some(amount);
+        self
+    }
+
+    /// Sets the `currency` query parameter.
+    pub fn currency(mut self, currency: &'a str) -> Self {
+        self.currency = Some(currency);
+        self
+    }
+
+    /// Sets the `restock deprecated` query parameter.
+    pub fn restock_deprecated(mut self, restock_deprecated: &'a str) -> Self {
+        self.restock_deprecated = Some(restock_deprecated);
+        self
+    }
+
+    /// Sets the `reason` query parameter.
+    pub fn reason(mut self, reason: &'a str) -> Self {
+        self.reason = Some(reason);
+        self
+    }
+
+    /// Sets the `email` query parameter.
+    pub fn email(mut self, email: &'a str) -> Self {
+        self.email = Some(email);
+        self
+    }
+
+    /// Sets the `refund` query parameter.
+    pub fn refund(mut self, refund: &'a str) -> Self {
+        self.refund = Some(refund);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            order_id,
+            amount,
+            currency,
+            restock_deprecated,
+            reason,
+            email,
+            refund,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = amount {
+            query_args.push(("amount".to_string(), v.to_string()));
+        }
+        if let Some(v) = currency {
+            query_args.push(("currency".to_string(), v.to_string()));
+        }
+        if let Some(v) = email {
+            query_args.push(("email".to_string(), v.to_string()));
+        }
+        if let Some(v) = reason {
+            query_args.push(("reason".to_string(), v.to_string()));
+        }
+        if let Some(v) = refund {
+            query_args.push(("refund".to_string(), v.to_string()));
+        }
+        if let Some(v) = restock_deprecated {
+            query_args.push((
+                "restock
+                  deprecated"
+                    .to_string(),
+                v.to_string(),
+            ));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-04/orders/{}/cancel.json?{}",
+                crate::progenitor_support::encode_path(order_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Orders::deprecated_202007_get`], set with a builder.
+pub struct OrdersDeprecated202007GetRequest<'a> {
+    client: &'a Orders,
+    ids: Option<&'a str>,
+    limit: Option<&'a str>,
+    since_id: Option<&'a str>,
+    created_at_min: Option<&'a str>,
+    created_at_max: Option<&'a str>,
+    updated_at_min: Option<&'a str>,
+    updated_at_max: Option<&'a str>,
+    processed_at_min: Option<&'a str>,
+    processed_at_max: Option<&'a str>,
+    attribution_app_id: Option<&'a str>,
+    status: Option<&'a str>,
+    financial_status: Option<&'a str>,
+    fulfillment_status: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> OrdersDeprecated202007GetRequest<'a> {
+    /// Sets the `ids` query parameter.
+    pub fn ids(mut self, ids: &'a str) -> Self {
+        self.ids = Some(ids);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.created_at_min = Some(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.created_at_max = Some(created_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.updated_at_min = Some(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.updated_at_max = Some(updated_at_max);
+        self
+    }
+
+    /// Sets the `processed_at_min` query parameter.
+    pub fn processed_at_min(mut self, processed_at_min: &'a str) -> Self {
+        self.processed_at_min = Some(processed_at_min);
+        self
+    }
+
+    /// Sets the `processed_at_max` query parameter.
+    pub fn processed_at_max(mut self, processed_at_max: &'a str) -> Self {
+        self.processed_at_max = Some(processed_at_max);
+        self
+    }
+
+    /// Sets the `attribution_app_id` query parameter.
+    pub fn attribution_app_id(mut self, attribution_app_id: &'a str) -> Self {
+        self.attribution_app_id = Some(attribution_app_id);
+        self
+    }
+
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: &'a str) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Sets the `financial_status` query parameter.
+    pub fn financial_status(mut self, financial_status: &'a str) -> Self {
+        self.financial_status = Some(financial_status);
+        self
+    }
+
+    /// Sets the `fulfillment_status` query parameter.
+    pub fn fulfillment_status(mut self, fulfillment_status: &'a str) -> Self {
+        self.fulfillment_status = Some(fulfillment_status);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            ids,
+            limit,
+            since_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            processed_at_min,
+            processed_at_max,
+            attribution_app_id,
+            status,
+            financial_status,
+            fulfillment_status,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = attribution_app_id {
+            query_args.push(("attribution_app_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = created_at_max {
+            query_args.push(("created_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = created_at_min {
+            query_args.push(("created_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = financial_status {
+            query_args.push(("financial_status".to_string(), v.to_string()));
+        }
+        if let Some(v) = fulfillment_status {
+            query_args.push(("fulfillment_status".to_string(), v.to_string()));
+        }
+        if let Some(v) = ids {
+            query_args.push(("ids".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = processed_at_max {
+            query_args.push(("processed_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = processed_at_min {
+            query_args.push(("processed_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = status {
+            query_args.push(("status".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_max {
+            query_args.push(("updated_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_min {
+            query_args.push(("updated_at_min".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/admin/api/2020-07/orders.json?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Orders::deprecated_202001_get_param_refund`], set with a builder.
+pub struct OrdersDeprecated202001GetParamRefundRequest<'a> {
+    client: &'a Orders,
+    order_id: &'a str,
+    limit: Option<&'a str>,
+    fields: Option<&'a str>,
+    in_shop_currency: Option<&'a str>,
+}
+
+impl<'a> OrdersDeprecated202001GetParamRefundRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `in_shop_currency` query parameter.
+    pub fn in_shop_currency(mut self, in_shop_currency: &'a str) -> Self {
+        self.in_shop_currency = Some(in_shop_currency);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            order_id,
+            limit,
+            fields,
+            in_shop_currency,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = in_shop_currency {
+            query_args.push(("in_shop_currency".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-01/orders/{}/refunds.json?{}",
+                crate::progenitor_support::encode_path(order_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Orders::deprecated_202001_create_param_refunds`], set with a builder.
+pub struct OrdersDeprecated202001CreateParamRefundsRequest<'a> {
+    client: &'a Orders,
+    order_id: &'a str,
+    restock_deprecated: Option<&'a str>,
+    notify: Option<&'a str>,
+    note: Option<&'a str>,
+    discrepancy_reason: Option<&'a str>,
+    shipping: Option<&'a str>,
+    refund_line_items: Option<&'a str>,
+    transactions: Option<&'a str>,
+    currency: Option<&'a str>,
+    body: &'a serde_json::Value,
+}
+
+impl<'a> OrdersDeprecated202001CreateParamRefundsRequest<'a> {
+    /// Sets the `restock deprecated` query parameter.
+    pub fn restock_deprecated(mut self, restock_deprecated: &'a str) -> Self {
+        self.restock_deprecated = Some(restock_deprecated);
+        self
+    }
+
+    /// Sets the `notify` query parameter.
+    pub fn notify(mut self, notify: &'a str) -> Self {
+        self.notify = Some(notify);
+        self
+    }
+
+    /// Sets the `note` query parameter.
+    pub fn note(mut self, note: &'a str) -> Self {
+        self.note = Some(note);
+        self
+    }
+
+    /// Sets the `discrepancy_reason` query parameter.
+    pub fn discrepancy_reason(mut self, discrepancy_reason: &'a str) -> Self {
+        self.discrepancy_reason = Some(discrepancy_reason);
+        self
+    }
+
+    /// Sets the `shipping` query parameter.
+    pub fn shipping(mut self, shipping: &'a str) -> Self {
+        self.shipping = Some(shipping);
+        self
+    }
+
+    /// Sets the `refund_line_items` query parameter.
+    pub fn refund_line_items(mut self, refund_line_items: &'a str) -> Self {
+        self.refund_line_items = Some(refund_line_items);
+        self
+    }
+
+    /// Sets the `transactions` query parameter.
+    pub fn transactions(mut self, transactions: &'a str) -> Self {
+        self.transactions = Some(transactions);
+        self
+    }
+
+    /// Sets the `currency` query parameter.
+    pub fn currency(mut self, currency: &'a str) -> Self {
+        self.currency = Some(currency);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            order_id,
+            restock_deprecated,
+            notify,
+            note,
+            discrepancy_reason,
+            shipping,
+            refund_line_items,
+            transactions,
+            currency,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = currency {
+            query_args.push(("currency".to_string(), v.to_string()));
+        }
+        if let Some(v) = discrepancy_reason {
+            query_args.push(("discrepancy_reason".to_string(), v.to_string()));
+        }
+        if let Some(v) = note {
+            query_args.push(("note".to_string(), v.to_string()));
+        }
+        if let Some(v) = notify {
+            query_args.push(("notify".to_string(), v.to_string()));
+        }
+        if let Some(v) = refund_line_items {
+            query_args.push(("refund_line_items".to_string(), v.to_string()));
+        }
+        if let Some(v) = restock_deprecated {
+            query_args.push((
+                "restock
+                  deprecated"
+                    .to_string(),
+                v.to_string(),
+            ));
+        }
+        if let Some(v) = shipping {
+            query_args.push(("shipping".to_string(), v.to_string()));
+        }
+        if let Some(v) = transactions {
+            query_args.push(("transactions".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-01/orders/{}/refunds.json?{}",
+                crate::progenitor_support::encode_path(order_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Orders::deprecated_202001_get_param_refunds_refund`], set with a builder.
+pub struct OrdersDeprecated202001GetParamRefundsRefundRequest<'a> {
+    client: &'a Orders,
+    order_id: &'a str,
+    refund_id: &'a str,
+    fields: Option<&'a str>,
+    in_shop_currency: Option<&'a str>,
+}
+
+impl<'a> OrdersDeprecated202001GetParamRefundsRefundRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `in_shop_currency` query parameter.
+    pub fn in_shop_currency(mut self, in_shop_currency: &'a str) -> Self {
+        self.in_shop_currency = Some(in_shop_currency);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            order_id,
+            refund_id,
+            fields,
+            in_shop_currency,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = in_shop_currency {
+            query_args.push(("in_shop_currency".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-01/orders/{}/refunds/{}/json?{}",
+                crate::progenitor_support::encode_path(order_id),
+                crate::progenitor_support::encode_path(refund_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Orders::deprecated_202001_create_param_refunds_calculate`], set with a builder.
+pub struct OrdersDeprecated202001CreateParamRefundsCalculateRequest<'a> {
+    client: &'a Orders,
+    order_id: &'a str,
+    shipping: Option<&'a str>,
+    refund_line_items: Option<&'a str>,
+    currency: Option<&'a str>,
+    body: &'a serde_json::Value,
+}
+
+impl<'a> OrdersDeprecated202001CreateParamRefundsCalculateRequest<'a> {
+    /// Sets the `shipping` query parameter.
+    pub fn shipping(mut self, shipping: &'a str) -> Self {
+        self.shipping = Some(shipping);
+        self
+    }
+
+    /// Sets the `refund_line_items` query parameter.
+    pub fn refund_line_items(mut self, refund_line_items: &'a str) -> Self {
+        self.refund_line_items = Some(refund_line_items);
+        self
+    }
+
+    /// Sets the `currency` query parameter.
+    pub fn currency(mut self, currency: &'a str) -> Self {
+        self.currency = Some(currency);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            order_id,
+            shipping,
+            refund_line_items,
+            currency,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = currency {
+            query_args.push(("currency".to_string(), v.to_string()));
+        }
+        if let Some(v) = refund_line_items {
+            query_args.push(("refund_line_items".to_string(), v.to_string()));
+        }
+        if let Some(v) = shipping {
+            query_args.push(("shipping".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-01/orders/{}/refunds/calculate.json?{}",
+                crate::progenitor_support::encode_path(order_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Orders::deprecated_202004_get_param_refund`], set with a builder.
+pub struct OrdersDeprecated202004GetParamRefundRequest<'a> {
+    client: &'a Orders,
+    order_id: &'a str,
+    limit: Option<&'a str>,
+    fields: Option<&'a str>,
+    in_shop_currency: Option<&'a str>,
+}
+
+impl<'a> OrdersDeprecated202004GetParamRefundRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `in_shop_currency` query parameter.
+    pub fn in_shop_currency(mut self, in_shop_currency: &'a str) -> Self {
+        self.in_shop_currency = Some(in_shop_currency);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            order_id,
+            limit,
+            fields,
+            in_shop_currency,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = in_shop_currency {
+            query_args.push(("in_shop_currency".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-04/orders/{}/refunds.json?{}",
+                crate::progenitor_support::encode_path(order_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Orders::deprecated_202004_create_param_refunds`], set with a builder.
+pub struct OrdersDeprecated202004CreateParamRefundsRequest<'a> {
+    client: &'a Orders,
+    order_id: &'a str,
+    restock_deprecated: Option<&'a str>,
+    notify: Option<&'a str>,
+    note: Option<&'a str>,
+    discrepancy_reason: Option<&'a str>,
+    shipping: Option<&'a str>,
+    refund_line_items: Option<&'a str>,
+    transactions: Option<&'a str>,
+    currency: Option<&'a str>,
+    body: &'a serde_json::Value,
+}
+
+impl<'a> OrdersDeprecated202004CreateParamRefundsRequest<'a> {
+    /// Sets the `restock deprecated` query parameter.
+    pub fn restock_deprecated(mut self, restock_deprecated: &'a str) -> Self {
+        self.restock_deprecated = Some(restock_deprecated);
+        self
+    }
+
+    /// Sets the `notify` query parameter.
+    pub fn notify(mut self, notify: &'a str) -> Self {
+        self.notify = Some(notify);
+        self
+    }
+
+    /// Sets the `note` query parameter.
+    pub fn note(mut self, note: &'a str) -> Self {
+        self.note = Some(note);
+        self
+    }
+
+    /// Sets the `discrepancy_reason` query parameter.
+    pub fn discrepancy_reason(mut self, discrepancy_reason: &'a str) -> Self {
+        self.discrepancy_reason = Some(discrepancy_reason);
+        self
+    }
+
+    /// Sets the `shipping` query parameter.
+    pub fn shipping(mut self, shipping: &'a str) -> Self {
+        self.shipping = Some(shipping);
+        self
+    }
+
+    /// Sets the `refund_line_items` query parameter.
+    pub fn refund_line_items(mut self, refund_line_items: &'a str) -> Self {
+        self.refund_line_items = Some(refund_line_items);
+        self
+    }
+
+    /// Sets the `transactions` query parameter.
+    pub fn transactions(mut self, transactions: &'a str) -> Self {
+        self.transactions = Some(transactions);
+        self
+    }
+
+    /// Sets the `currency` query parameter.
+    pub fn currency(mut self, currency: &'a str) -> Self {
+        self.currency = Some(currency);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            order_id,
+            restock_deprecated,
+            notify,
+            note,
+            discrepancy_reason,
+            shipping,
+            refund_line_items,
+            transactions,
+            currency,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = currency {
+            query_args.push(("currency".to_string(), v.to_string()));
+        }
+        if let Some(v) = discrepancy_reason {
+            query_args.push(("discrepancy_reason".to_string(), v.to_string()));
+        }
+        if let Some(v) = note {
+            query_args.push(("note".to_string(), v.to_string()));
+        }
+        if let Some(v) = notify {
+            query_args.push(("notify".to_string(), v.to_string()));
+        }
+        if let Some(v) = refund_line_items {
+            query_args.push(("refund_line_items".to_string(), v.to_string()));
+        }
+        if let Some(v) = restock_deprecated {
+            query_args.push((
+                "restock
+                  deprecated"
+                    .to_string(),
+                v.to_string(),
+            ));
+        }
+        if let Some(v) = shipping {
+            query_args.push(("shipping".to_string(), v.to_string()));
+        }
+        if let Some(v) = transactions {
+            query_args.push(("transactions".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-04/orders/{}/refunds.json?{}",
+                crate::progenitor_support::encode_path(order_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Orders::deprecated_202004_get_param_refunds_refund`], set with a builder.
+pub struct OrdersDeprecated202004GetParamRefundsRefundRequest<'a> {
+    client: &'a Orders,
+    order_id: &'a str,
+    refund_id: &'a str,
+    fields: Option<&'a str>,
+    in_shop_currency: Option<&'a str>,
+}
+
+impl<'a> OrdersDeprecated202004GetParamRefundsRefundRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `in_shop_currency` query parameter.
+    pub fn in_shop_currency(mut self, in_shop_currency: &'a str) -> Self {
+        self.in_shop_currency = Some(in_shop_currency);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            order_id,
+            refund_id,
+            fields,
+            in_shop_currency,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = in_shop_currency {
+            query_args.push(("in_shop_currency".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-04/orders/{}/refunds/{}/json?{}",
+                crate::progenitor_support::encode_path(order_id),
+                crate::progenitor_support::encode_path(refund_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Orders::deprecated_202004_create_param_refunds_calculate`], set with a builder.
+pub struct OrdersDeprecated202004CreateParamRefundsCalculateRequest<'a> {
+    client: &'a Orders,
+    order_id: &'a str,
+    shipping: Option<&'a str>,
+    refund_line_items: Option<&'a str>,
+    currency: Option<&'a str>,
+    body: &'a serde_json::Value,
+}
+
+impl<'a> OrdersDeprecated202004CreateParamRefundsCalculateRequest<'a> {
+    /// Sets the `shipping` query parameter.
+    pub fn shipping(mut self, shipping: &'a str) -> Self {
+        self.shipping = Some(shipping);
+        self
+    }
+
+    /// Sets the `refund_line_items` query parameter.
+    pub fn refund_line_items(mut self, refund_line_items: &'a str) -> Self {
+        self.refund_line_items = Some(refund_line_items);
+        self
+    }
+
+    /// Sets the `currency` query parameter.
+    pub fn currency(mut self, currency: &'a str) -> Self {
+        self.currency = Some(currency);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            order_id,
+            shipping,
+            refund_line_items,
+            currency,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = currency {
+            query_args.push(("currency".to_string(), v.to_string()));
+        }
+        if let Some(v) = refund_line_items {
+            query_args.push(("refund_line_items".to_string(), v.to_string()));
+        }
+        if let Some(v) = shipping {
+            query_args.push(("shipping".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-04/orders/{}/refunds/calculate.json?{}",
+                crate::progenitor_support::encode_path(order_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Orders::deprecated_202007_get_param_refund`], set with a builder.
+pub struct OrdersDeprecated202007GetParamRefundRequest<'a> {
+    client: &'a Orders,
+    order_id: &'a str,
+    limit: Option<&'a str>,
+    fields: Option<&'a str>,
+    in_shop_currency: Option<&'a str>,
+}
+
+impl<'a> OrdersDeprecated202007GetParamRefundRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `in_shop_currency` query parameter.
+    pub fn in_shop_currency(mut self, in_shop_currency: &'a str) -> Self {
+        self.in_shop_currency = Some(in_shop_currency);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            order_id,
+            limit,
+            fields,
+            in_shop_currency,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = in_shop_currency {
+            query_args.push(("in_shop_currency".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-07/orders/{}/refunds.json?{}",
+                crate::progenitor_support::encode_path(order_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Orders::deprecated_202007_create_param_refunds`], set with a builder.
+pub struct OrdersDeprecated202007CreateParamRefundsRequest<'a> {
+    client: &'a Orders,
+    order_id: &'a str,
+    restock_deprecated: Option<&'a str>,
+    notify: Option<&'a str>,
+    note: Option<&'a str>,
+    discrepancy_reason: Option<&'a str>,
+    shipping: Option<&'a str>,
+    refund_line_items: Option<&'a str>,
+    transactions: Option<&'a str>,
+    currency: Option<&'a str>,
+    body: &'a serde_json::Value,
+}
+
+impl<'a> OrdersDeprecated202007CreateParamRefundsRequest<'a> {
+    /// Sets the `restock deprecated` query parameter.
+    pub fn restock_deprecated(mut self, restock_deprecated: &'a str) -> Self {
+        self.restock_deprecated = Some(restock_deprecated);
+        self
+    }
+
+    /// Sets the `notify` query parameter.
+    pub fn notify(mut self, notify: &'a str) -> Self {
+        self.notify = Some(notify);
+        self
+    }
+
+    /// Sets the `note` query parameter.
+    pub fn note(mut self, note: &'a str) -> Self {
+        self.note = Some(note);
+        self
+    }
+
+    /// Sets the `discrepancy_reason` query parameter.
+    pub fn discrepancy_reason(mut self, discrepancy_reason: &'a str) -> Self {
+        self.discrepancy_reason = Some(discrepancy_reason);
+        self
+    }
+
+    /// Sets the `shipping` query parameter.
+    pub fn shipping(mut self, shipping: &'a str) -> Self {
+        self.shipping = Some(shipping);
+        self
+    }
+
+    /// Sets the `refund_line_items` query parameter.
+    pub fn refund_line_items(mut self, refund_line_items: &'a str) -> Self {
+        self.refund_line_items = Some(refund_line_items);
+        self
+    }
+
+    /// Sets the `transactions` query parameter.
+    pub fn transactions(mut self, transactions: &'a str) -> Self {
+        self.transactions = Some(transactions);
+        self
+    }
+
+    /// Sets the `currency` query parameter.
+    pub fn currency(mut self, currency: &'a str) -> Self {
+        self.currency = Some(currency);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            order_id,
+            restock_deprecated,
+            notify,
+            note,
+            discrepancy_reason,
+            shipping,
+            refund_line_items,
+            transactions,
+            currency,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = currency {
+            query_args.push(("currency".to_string(), v.to_string()));
+        }
+        if let Some(v) = discrepancy_reason {
+            query_args.push(("discrepancy_reason".to_string(), v.to_string()));
+        }
+        if let Some(v) = note {
+            query_args.push(("note".to_string(), v.to_string()));
+        }
+        if let Some(v) = notify {
+            query_args.push(("notify".to_string(), v.to_string()));
+        }
+        if let Some(v) = refund_line_items {
+            query_args.push(("refund_line_items".to_string(), v.to_string()));
+        }
+        if let Some(v) = restock_deprecated {
+            query_args.push((
+                "restock
+                  deprecated"
+                    .to_string(),
+                v.to_string(),
+            ));
+        }
+        if let Some(v) = shipping {
+            query_args.push(("shipping".to_string(), v.to_string()));
+        }
+        if let Some(v) = transactions {
+            query_args.push(("transactions".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-07/orders/{}/refunds.json?{}",
+                crate::progenitor_support::encode_path(order_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Orders::deprecated_202007_get_param_refunds_refund`], set with a builder.
+pub struct OrdersDeprecated202007GetParamRefundsRefundRequest<'a> {
+    client: &'a Orders,
+    order_id: &'a str,
+    refund_id: &'a str,
+    fields: Option<&'a str>,
+    in_shop_currency: Option<&'a str>,
+}
+
+impl<'a> OrdersDeprecated202007GetParamRefundsRefundRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `in_shop_currency` query parameter.
+    pub fn in_shop_currency(mut self, in_shop_currency: &'a str) -> Self {
+        self.in_shop_currency = Some(in_shop_currency);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            order_id,
+            refund_id,
+            fields,
+            in_shop_currency,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = in_shop_currency {
+            query_args.push(("in_shop_currency".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-07/orders/{}/refunds/{}/json?{}",
+                crate::progenitor_support::encode_path(order_id),
+                crate::progenitor_support::encode_path(refund_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Orders::deprecated_202007_create_param_refunds_calculate`], set with a builder.
+pub struct OrdersDeprecated202007CreateParamRefundsCalculateRequest<'a> {
+    client: &'a Orders,
+    order_id: &'a str,
+    shipping: Option<&'a str>,
+    refund_line_items: Option<&'a str>,
+    currency: Option<&'a str>,
+    body: &'a serde_json::Value,
+}
+
+impl<'a> OrdersDeprecated202007CreateParamRefundsCalculateRequest<'a> {
+    /// Sets the `shipping` query parameter.
+    pub fn shipping(mut self, shipping: &'a str) -> Self {
+        self.shipping = Some(shipping);
+        self
+    }
+
+    /// Sets the `refund_line_items` query parameter.
+    pub fn refund_line_items(mut self, refund_line_items: &'a str) -> Self {
+        self.refund_line_items = Some(refund_line_items);
+        self
+    }
+
+    /// Sets the `currency` query parameter.
+    pub fn currency(mut self, currency: &'a str) -> Self {
+        self.currency = Some(currency);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            order_id,
+            shipping,
+            refund_line_items,
+            currency,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = currency {
+            query_args.push(("currency".to_string(), v.to_string()));
+        }
+        if let Some(v) = refund_line_items {
+            query_args.push(("refund_line_items".to_string(), v.to_string()));
+        }
+        if let Some(v) = shipping {
+            query_args.push(("shipping".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-07/orders/{}/refunds/calculate.json?{}",
+                crate::progenitor_support::encode_path(order_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Orders::get_param_refund`], set with a builder.
+pub struct OrdersGetParamRefundRequest<'a> {
+    client: &'a Orders,
+    order_id: &'a str,
+    limit: Option<&'a str>,
+    fields: Option<&'a str>,
+    in_shop_currency: Option<&'a str>,
+}
+
+impl<'a> OrdersGetParamRefundRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `in_shop_currency` query parameter.
+    pub fn in_shop_currency(mut self, in_shop_currency: &'a str) -> Self {
+        self.in_shop_currency = Some(in_shop_currency);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            order_id,
+            limit,
+            fields,
+            in_shop_currency,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = in_shop_currency {
+            query_args.push(("in_shop_currency".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-10/orders/{}/refunds.json?{}",
+                crate::progenitor_support::encode_path(order_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Orders::create_param_refunds`], set with a builder.
+pub struct OrdersCreateParamRefundsRequest<'a> {
+    client: &'a Orders,
+    order_id: &'a str,
+    restock_deprecated: Option<&'a str>,
+    notify: Option<&'a str>,
+    note: Option<&'a str>,
+    discrepancy_reason: Option<&'a str>,
+    shipping: Option<&'a str>,
+    refund_line_items: Option<&'a str>,
+    transactions: Option<&'a str>,
+    currency: Option<&'a str>,
+    body: &'a serde_json::Value,
+}
+
+impl<'a> OrdersCreateParamRefundsRequest<'a> {
+    /// Sets the `restock deprecated` query parameter.
+    pub fn restock_deprecated(mut self, restock_deprecated: &'a str) -> Self {
+        self.restock_deprecated = Some(restock_deprecated);
+        self
+    }
+
+    /// Sets the `notify` query parameter.
+    pub fn notify(mut self, notify: &'a str) -> Self {
+        self.notify = Some(notify);
+        self
+    }
+
+    /// Sets the `note` query parameter.
+    pub fn note(mut self, note: &'a str) -> Self {
+        self.note = Some(note);
+        self
+    }
+
+    /// Sets the `discrepancy_reason` query parameter.
+    pub fn discrepancy_reason(mut self, discrepancy_reason: &'a str) -> Self {
+        self.discrepancy_reason = Some(discrepancy_reason);
+        self
+    }
+
+    /// Sets the `shipping` query parameter.
+    pub fn shipping(mut self, shipping: &'a str) -> Self {
+        self.shipping = Some(shipping);
+        self
+    }
+
+    /// Sets the `refund_line_items` query parameter.
+    pub fn refund_line_items(mut self, refund_line_items: &'a str) -> Self {
+        self.refund_line_items = Some(refund_line_items);
+        self
+    }
+
+    /// Sets the `transactions` query parameter.
+    pub fn transactions(mut self, transactions: &'a str) -> Self {
+        self.transactions = Some(transactions);
+        self
+    }
+
+    /// Sets the `currency` query parameter.
+    pub fn currency(mut self, currency: &'a str) -> Self {
+        self.currency = Some(currency);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            order_id,
+            restock_deprecated,
+            notify,
+            note,
+            discrepancy_reason,
+            shipping,
+            refund_line_items,
+            transactions,
+            currency,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = currency {
+            query_args.push(("currency".to_string(), v.to_string()));
+        }
+        if let Some(v) = discrepancy_reason {
+            query_args.push(("discrepancy_reason".to_string(), v.to_string()));
+        }
+        if let Some(v) = note {
+            query_args.push(("note".to_string(), v.to_string()));
+        }
+        if let Some(v) = notify {
+            query_args.push(("notify".to_string(), v.to_string()));
+        }
+        if let Some(v) = refund_line_items {
+            query_args.push(("refund_line_items".to_string(), v.to_string()));
+        }
+        if let Some(v) = restock_deprecated {
+            query_args.push((
+                "restock
+                  deprecated"
+                    .to_string(),
+                v.to_string(),
+            ));
+        }
+        if let Some(v) = shipping {
+            query_args.push(("shipping".to_string(), v.to_string()));
+        }
+        if let Some(v) = transactions {
+            query_args.push(("transactions".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-10/orders/{}/refunds.json?{}",
+                crate::progenitor_support::encode_path(order_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Orders::get_param_refunds_refund`], set with a builder.
+pub struct OrdersGetParamRefundsRefundRequest<'a> {
+    client: &'a Orders,
+    order_id: &'a str,
+    refund_id: &'a str,
+    fields: Option<&'a str>,
+    in_shop_currency: Option<&'a str>,
+}
+
+impl<'a> OrdersGetParamRefundsRefundRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `in_shop_currency` query parameter.
+    pub fn in_shop_currency(mut self, in_shop_currency: &'a str) -> Self {
+        self.in_shop_currency = Some(in_shop_currency);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            order_id,
+            refund_id,
+            fields,
+            in_shop_currency,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = in_shop_currency {
+            query_args.push(("in_shop_currency".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-10/orders/{}/refunds/{}/json?{}",
+                crate::progenitor_support::encode_path(order_id),
+                crate::progenitor_support::encode_path(refund_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Orders::create_param_refunds_calculate`], set with a builder.
+pub struct OrdersCreateParamRefundsCalculateRequest<'a> {
+    client: &'a Orders,
+    order_id: &'a str,
+    shipping: Option<&'a str>,
+    refund_line_items: Option<&'a str>,
+    currency: Option<&'a str>,
+    body: &'a serde_json::Value,
+}
+
+impl<'a> OrdersCreateParamRefundsCalculateRequest<'a> {
+    /// Sets the `shipping` query parameter.
+    pub fn shipping(mut self, shipping: &'a str) -> Self {
+        self.shipping = Some(shipping);
+        self
+    }
+
+    /// Sets the `refund_line_items` query parameter.
+    pub fn refund_line_items(mut self, refund_line_items: &'a str) -> Self {
+        self.refund_line_items = Some(refund_line_items);
+        self
+    }
+
+    /// Sets the `currency` query parameter.
+    pub fn currency(mut self, currency: &'a str) -> Self {
+        self.currency = Some(currency);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            order_id,
+            shipping,
+            refund_line_items,
+            currency,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = currency {
+            query_args.push(("currency".to_string(), v.to_string()));
+        }
+        if let Some(v) = refund_line_items {
+            query_args.push(("refund_line_items".to_string(), v.to_string()));
+        }
+        if let Some(v) = shipping {
+            query_args.push(("shipping".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-10/orders/{}/refunds/calculate.json?{}",
+                crate::progenitor_support::encode_path(order_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Orders::deprecated_202101_get_param_refund`], set with a builder.
+pub struct OrdersDeprecated202101GetParamRefundRequest<'a> {
+    client: &'a Orders,
+    order_id: &'a str,
+    limit: Option<&'a str>,
+    fields: Option<&'a str>,
+    in_shop_currency: Option<&'a str>,
+}
+
+impl<'a> OrdersDeprecated202101GetParamRefundRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `in_shop_currency` query parameter.
+    pub fn in_shop_currency(mut self, in_shop_currency: &'a str) -> Self {
+        self.in_shop_currency = Some(in_shop_currency);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            order_id,
+            limit,
+            fields,
+            in_shop_currency,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = in_shop_currency {
+            query_args.push(("in_shop_currency".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2021-01/orders/{}/refunds.json?{}",
+                crate::progenitor_support::encode_path(order_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Orders::deprecated_202101_create_param_refunds`], set with a builder.
+pub struct OrdersDeprecated202101CreateParamRefundsRequest<'a> {
+    client: &'a Orders,
+    order_id: &'a str,
+    restock_deprecated: Option<&'a str>,
+    notify: Option<&'a str>,
+    note: Option<&'a str>,
+    discrepancy_reason: Option<&'a str>,
+    shipping: Option<&'a str>,
+    refund_line_items: Option<&'a str>,
+    transactions: Option<&'a str>,
+    currency: Option<&'a str>,
+    body: &'a serde_json::Value,
+}
+
+impl<'a> OrdersDeprecated202101CreateParamRefundsRequest<'a> {
+    /// Sets the `restock deprecated` query parameter.
+    pub fn restock_deprecated(mut self, restock_deprecated: &'a str) -> Self {
+        self.restock_deprecated = Some(restock_deprecated);
+        self
+    }
+
+    /// Sets the `notify` query parameter.
+    pub fn notify(mut self, notify: &'a str) -> Self {
+        self.notify = Some(notify);
+        self
+    }
+
+    /// Sets the `note` query parameter.
+    pub fn note(mut self, note: &'a str) -> Self {
+        self.note = Some(note);
+        self
+    }
+
+    /// Sets the `discrepancy_reason` query parameter.
+    pub fn discrepancy_reason(mut self, discrepancy_reason: &'a str) -> Self {
+        self.discrepancy_reason = Some(discrepancy_reason);
+        self
+    }
+
+    /// Sets the `shipping` query parameter.
+    pub fn shipping(mut self, shipping: &'a str) -> Self {
+        self.shipping = Some(shipping);
+        self
+    }
+
+    /// Sets the `refund_line_items` query parameter.
+    pub fn refund_line_items(mut self, refund_line_items: &'a str) -> Self {
+        self.refund_line_items = Some(refund_line_items);
+        self
+    }
+
+    /// Sets the `transactions` query parameter.
+    pub fn transactions(mut self, transactions: &'a str) -> Self {
+        self.transactions = Some(transactions);
+        self
+    }
+
+    /// Sets the `currency` query parameter.
+    pub fn currency(mut self, currency: &'a str) -> Self {
+        self.currency = Some(currency);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            order_id,
+            restock_deprecated,
+            notify,
+            note,
+            discrepancy_reason,
+            shipping,
+            refund_line_items,
+            transactions,
+            currency,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = currency {
+            query_args.push(("currency".to_string(), v.to_string()));
+        }
+        if let Some(v) = discrepancy_reason {
+            query_args.push(("discrepancy_reason".to_string(), v.to_string()));
+        }
+        if let Some(v) = note {
+            query_args.push(("note".to_string(), v.to_string()));
+        }
+        if let Some(v) = notify {
+            query_args.push(("notify".to_string(), v.to_string()));
+        }
+        if let Some(v) = refund_line_items {
+            query_args.push(("refund_line_items".to_string(), v.to_string()));
+        }
+        if let Some(v) = restock_deprecated {
+            query_args.push((
+                "restock
+                  deprecated"
+                    .to_string(),
+                v.to_string(),
+            ));
+        }
+        if let Some(v) = shipping {
+            query_args.push(("shipping".to_string(), v.to_string()));
+        }
+        if let Some(v) = transactions {
+            query_args.push(("transactions".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2021-01/orders/{}/refunds.json?{}",
+                crate::progenitor_support::encode_path(order_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Orders::deprecated_202101_get_param_refunds_refund`], set with a builder.
+pub struct OrdersDeprecated202101GetParamRefundsRefundRequest<'a> {
+    client: &'a Orders,
+    order_id: &'a str,
+    refund_id: &'a str,
+    fields: Option<&'a str>,
+    in_shop_currency: Option<&'a str>,
+}
+
+impl<'a> OrdersDeprecated202101GetParamRefundsRefundRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `in_shop_currency` query parameter.
+    pub fn in_shop_currency(mut self, in_shop_currency: &'a str) -> Self {
+        self.in_shop_currency = Some(in_shop_currency);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            order_id,
+            refund_id,
+            fields,
+            in_shop_currency,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = in_shop_currency {
+            query_args.push(("in_shop_currency".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2021-01/orders/{}/refunds/{}/json?{}",
+                crate::progenitor_support::encode_path(order_id),
+                crate::progenitor_support::encode_path(refund_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Orders::deprecated_202101_create_param_refunds_calculate`], set with a builder.
+pub struct OrdersDeprecated202101CreateParamRefundsCalculateRequest<'a> {
+    client: &'a Orders,
+    order_id: &'a str,
+    shipping: Option<&'a str>,
+    refund_line_items: Option<&'a str>,
+    currency: Option<&'a str>,
+    body: &'a serde_json::Value,
+}
+
+impl<'a> OrdersDeprecated202101CreateParamRefundsCalculateRequest<'a> {
+    /// Sets the `shipping` query parameter.
+    pub fn shipping(mut self, shipping: &'a str) -> Self {
+        self.shipping = Some(shipping);
+        self
+    }
+
+    /// Sets the `refund_line_items` query parameter.
+    pub fn refund_line_items(mut self, refund_line_items: &'a str) -> Self {
+        self.refund_line_items = Some(refund_line_items);
+        self
+    }
+
+    /// Sets the `currency` query parameter.
+    pub fn currency(mut self, currency: &'a str) -> Self {
+        self.currency = Some(currency);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            order_id,
+            shipping,
+            refund_line_items,
+            currency,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = currency {
+            query_args.push(("currency".to_string(), v.to_string()));
+        }
+        if let Some(v) = refund_line_items {
+            query_args.push(("refund_line_items".to_string(), v.to_string()));
+        }
+        if let Some(v) = shipping {
+            query_args.push(("shipping".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2021-01/orders/{}/refunds/calculate.json?{}",
+                crate::progenitor_support::encode_path(order_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Orders::deprecated_unstable_get_param_refund`], set with a builder.
+pub struct OrdersDeprecatedUnstableGetParamRefundRequest<'a> {
+    client: &'a Orders,
+    order_id: &'a str,
+    limit: Option<&'a str>,
+    fields: Option<&'a str>,
+    in_shop_currency: Option<&'a str>,
+}
+
+impl<'a> OrdersDeprecatedUnstableGetParamRefundRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `in_shop_currency` query parameter.
+    pub fn in_shop_currency(mut self, in_shop_currency: &'a str) -> Self {
+        self.in_shop_currency = Some(in_shop_currency);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            order_id,
+            limit,
+            fields,
+            in_shop_currency,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = in_shop_currency {
+            query_args.push(("in_shop_currency".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/unstable/orders/{}/refunds.json?{}",
+                crate::progenitor_support::encode_path(order_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Orders::deprecated_unstable_create_param_refunds`], set with a builder.
+pub struct OrdersDeprecatedUnstableCreateParamRefundsRequest<'a> {
+    client: &'a Orders,
+    order_id: &'a str,
+    restock_deprecated: Option<&'a str>,
+    notify: Option<&'a str>,
+    note: Option<&'a str>,
+    discrepancy_reason: Option<&'a str>,
+    shipping: Option<&'a str>,
+    refund_line_items: Option<&'a str>,
+    transactions: Option<&'a str>,
+    currency: Option<&'a str>,
+    body: &'a serde_json::Value,
+}
+
+impl<'a> OrdersDeprecatedUnstableCreateParamRefundsRequest<'a> {
+    /// Sets the `restock deprecated` query parameter.
+    pub fn restock_deprecated(mut self, restock_deprecated: &'a str) -> Self {
+        self.restock_deprecated = Some(restock_deprecated);
+        self
+    }
+
+    /// Sets the `notify` query parameter.
+    pub fn notify(mut self, notify: &'a str) -> Self {
+        self.notify = Some(notify);
+        self
+    }
+
+    /// Sets the `note` query parameter.
+    pub fn note(mut self, note: &'a str) -> Self {
+        self.note = Some(note);
+        self
+    }
+
+    /// Sets the `discrepancy_reason` query parameter.
+    pub fn discrepancy_reason(mut self, discrepancy_reason: &'a str) -> Self {
+        self.discrepancy_reason = Some(discrepancy_reason);
+        self
+    }
+
+    /// Sets the `shipping` query parameter.
+    pub fn shipping(mut self, shipping: &'a str) -> Self {
+        self.shipping = Some(shipping);
+        self
+    }
+
+    /// Sets the `refund_line_items` query parameter.
+    pub fn refund_line_items(mut self, refund_line_items: &'a str) -> Self {
+        self.refund_line_items = Some(refund_line_items);
+        self
+    }
+
+    /// Sets the `transactions` query parameter.
+    pub fn transactions(mut self, transactions: &'a str) -> Self {
+        self.transactions = Some(transactions);
+        self
+    }
+
+    /// Sets the `currency` query parameter.
+    pub fn currency(mut self, currency: &'a str) -> Self {
+        self.currency = Some(currency);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            order_id,
+            restock_deprecated,
+            notify,
+            note,
+            discrepancy_reason,
+            shipping,
+            refund_line_items,
+            transactions,
+            currency,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = currency {
+            query_args.push(("currency".to_string(), v.to_string()));
+        }
+        if let Some(v) = discrepancy_reason {
+            query_args.push(("discrepancy_reason".to_string(), v.to_string()));
+        }
+        if let Some(v) = note {
+            query_args.push(("note".to_string(), v.to_string()));
+        }
+        if let Some(v) = notify {
+            query_args.push(("notify".to_string(), v.to_string()));
+        }
+        if let Some(v) = refund_line_items {
+            query_args.push(("refund_line_items".to_string(), v.to_string()));
+        }
+        if let Some(v) = restock_deprecated {
+            query_args.push((
+                "restock
+                  deprecated"
+                    .to_string(),
+                v.to_string(),
+            ));
+        }
+        if let Some(v) = shipping {
+            query_args.push(("shipping".to_string(), v.to_string()));
+        }
+        if let Some(v) = transactions {
+            query_args.push(("transactions".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/unstable/orders/{}/refunds.json?{}",
+                crate::progenitor_support::encode_path(order_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Orders::deprecated_unstable_get_param_refunds_refund`], set with a builder.
+pub struct OrdersDeprecatedUnstableGetParamRefundsRefundRequest<'a> {
+    client: &'a Orders,
+    order_id: &'a str,
+    refund_id: &'a str,
+    fields: Option<&'a str>,
+    in_shop_currency: Option<&'a str>,
+}
+
+impl<'a> OrdersDeprecatedUnstableGetParamRefundsRefundRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `in_shop_currency` query parameter.
+    pub fn in_shop_currency(mut self, in_shop_currency: &'a str) -> Self {
+        self.in_shop_currency = Some(in_shop_currency);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            order_id,
+            refund_id,
+            fields,
+            in_shop_currency,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = in_shop_currency {
+            query_args.push(("in_shop_currency".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/unstable/orders/{}/refunds/{}/json?{}",
+                crate::progenitor_support::encode_path(order_id),
+                crate::progenitor_support::encode_path(refund_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Orders::deprecated_unstable_create_param_refunds_calculate`], set with a builder.
+pub struct OrdersDeprecatedUnstableCreateParamRefundsCalculateRequest<'a> {
+    client: &'a Orders,
+    order_id: &'a str,
+    shipping: Option<&'a str>,
+    refund_line_items: Option<&'a str>,
+    currency: Option<&'a str>,
+    body: &'a serde_json::Value,
+}
+
+impl<'a> OrdersDeprecatedUnstableCreateParamRefundsCalculateRequest<'a> {
+    /// Sets the `shipping` query parameter.
+    pub fn shipping(mut self, shipping: &'a str) -> Self {
+        self.shipping = Some(shipping);
+        self
+    }
+
+    /// Sets the `refund_line_items` query parameter.
+    pub fn refund_line_items(mut self, refund_line_items: &'a str) -> Self {
+        self.refund_line_items = Some(refund_line_items);
+        self
+    }
+
+    /// Sets the `currency` query parameter.
+    pub fn currency(mut self, currency: &'a str) -> Self {
+        self.currency = Some(currency);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            order_id,
+            shipping,
+            refund_line_items,
+            currency,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = currency {
+            query_args.push(("currency".to_string(), v.to_string()));
+        }
+        if let Some(v) = refund_line_items {
+            query_args.push(("refund_line_items".to_string(), v.to_string()));
+        }
+        if let Some(v) = shipping {
+            query_args.push(("shipping".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/unstable/orders/{}/refunds/calculate.json?{}",
+                crate::progenitor_support::encode_path(order_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
 pub struct Orders {
     pub client: Client,
 }
@@ -74,6 +3756,28 @@ impl Orders {
             )
             .await
     }
+    /**
+     * Retrieves a count of checkouts from the past 90 days.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-01/checkouts/count.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_get_checkouts_count`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/orders/abandoned-checkouts#count-2020-01
+     */
+    pub fn deprecated_202001_get_checkouts_count_request(
+        &self,
+    ) -> OrdersDeprecated202001GetCheckoutsCountRequest<'_> {
+        OrdersDeprecated202001GetCheckoutsCountRequest {
+            client: self,
+            since_id: None,
+            created_at_min: None,
+            created_at_max: None,
+            updated_at_min: None,
+            updated_at_max: None,
+            status: None,
+        }
+    }
     /**
      * Retrieves a count of checkouts from the past 90 days.
      *
@@ -137,6 +3841,28 @@ impl Orders {
             )
             .await
     }
+    /**
+     * Retrieves a count of checkouts from the past 90 days.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-04/checkouts/count.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_get_checkouts_count`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/orders/abandoned-checkouts#count-2020-04
+     */
+    pub fn deprecated_202004_get_checkouts_count_request(
+        &self,
+    ) -> OrdersDeprecated202004GetCheckoutsCountRequest<'_> {
+        OrdersDeprecated202004GetCheckoutsCountRequest {
+            client: self,
+            since_id: None,
+            created_at_min: None,
+            created_at_max: None,
+            updated_at_min: None,
+            updated_at_max: None,
+            status: None,
+        }
+    }
     /**
      * Retrieves a count of checkouts from the past 90 days.
      *
@@ -200,6 +3926,28 @@ impl Orders {
             )
             .await
     }
+    /**
+     * Retrieves a count of checkouts from the past 90 days.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-07/checkouts/count.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_get_checkouts_count`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/orders/abandoned-checkouts#count-2020-07
+     */
+    pub fn deprecated_202007_get_checkouts_count_request(
+        &self,
+    ) -> OrdersDeprecated202007GetCheckoutsCountRequest<'_> {
+        OrdersDeprecated202007GetCheckoutsCountRequest {
+            client: self,
+            since_id: None,
+            created_at_min: None,
+            created_at_max: None,
+            updated_at_min: None,
+            updated_at_max: None,
+            status: None,
+        }
+    }
     /**
      * Retrieves a count of checkouts from the past 90 days.
      *
@@ -263,6 +4011,26 @@ impl Orders {
             )
             .await
     }
+    /**
+     * Retrieves a count of checkouts from the past 90 days.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-10/checkouts/count.json` endpoint.
+     *
+     * As opposed to `get_checkouts_count`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/orders/abandoned-checkouts#count-2020-10
+     */
+    pub fn get_checkouts_count_request(&self) -> OrdersGetCheckoutsCountRequest<'_> {
+        OrdersGetCheckoutsCountRequest {
+            client: self,
+            since_id: None,
+            created_at_min: None,
+            created_at_max: None,
+            updated_at_min: None,
+            updated_at_max: None,
+            status: None,
+        }
+    }
     /**
      * Retrieves a count of checkouts from the past 90 days.
      *
@@ -326,6 +4094,28 @@ impl Orders {
             )
             .await
     }
+    /**
+     * Retrieves a count of checkouts from the past 90 days.
+     *
+     * This function performs a `GET` to the `/admin/api/2021-01/checkouts/count.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_get_checkouts_count`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/orders/abandoned-checkouts#count-2021-01
+     */
+    pub fn deprecated_202101_get_checkouts_count_request(
+        &self,
+    ) -> OrdersDeprecated202101GetCheckoutsCountRequest<'_> {
+        OrdersDeprecated202101GetCheckoutsCountRequest {
+            client: self,
+            since_id: None,
+            created_at_min: None,
+            created_at_max: None,
+            updated_at_min: None,
+            updated_at_max: None,
+            status: None,
+        }
+    }
     /**
      * Retrieves a count of checkouts from the past 90 days.
      *
@@ -389,6 +4179,28 @@ impl Orders {
             )
             .await
     }
+    /**
+     * Retrieves a count of checkouts from the past 90 days.
+     *
+     * This function performs a `GET` to the `/admin/api/unstable/checkouts/count.json` endpoint.
+     *
+     * As opposed to `deprecated_unstable_get_checkouts_count`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/orders/abandoned-checkouts#count-unstable
+     */
+    pub fn deprecated_unstable_get_checkouts_count_request(
+        &self,
+    ) -> OrdersDeprecatedUnstableGetCheckoutsCountRequest<'_> {
+        OrdersDeprecatedUnstableGetCheckoutsCountRequest {
+            client: self,
+            since_id: None,
+            created_at_min: None,
+            created_at_max: None,
+            updated_at_min: None,
+            updated_at_max: None,
+            status: None,
+        }
+    }
     /**
      * Retrieves a list of orders. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -518,6 +4330,34 @@ impl Orders {
             )
             .await
     }
+    /**
+     * Retrieves a list of orders. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-01/orders.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_get`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/orders/order#index-2020-01
+     */
+    pub fn deprecated_202001_get_request(&self) -> OrdersDeprecated202001GetRequest<'_> {
+        OrdersDeprecated202001GetRequest {
+            client: self,
+            ids: None,
+            limit: None,
+            since_id: None,
+            created_at_min: None,
+            created_at_max: None,
+            updated_at_min: None,
+            updated_at_max: None,
+            processed_at_min: None,
+            processed_at_max: None,
+            attribution_app_id: None,
+            status: None,
+            financial_status: None,
+            fulfillment_status: None,
+            fields: None,
+        }
+    }
     /**
     * Creates an order. By default, product inventory is not claimed.
              When you create an order, you can include the following option parameters in the body of the request:
@@ -603,6 +4443,25 @@ impl Orders {
             )
             .await
     }
+    /**
+     * Retrieves a specific order.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-01/orders/{order_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_get_param`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/orders/order#show-2020-01
+     */
+    pub fn deprecated_202001_get_param_request<'a>(
+        &'a self,
+        order_id: &'a str,
+    ) -> OrdersDeprecated202001GetParamRequest<'a> {
+        OrdersDeprecated202001GetParamRequest {
+            client: self,
+            order_id,
+            fields: None,
+        }
+    }
     /**
      * Updates an order.
      *
@@ -755,6 +4614,27 @@ impl Orders {
             )
             .await
     }
+    /**
+     * Retrieves an order count.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-01/orders/count.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_get_count`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/orders/order#count-2020-01
+     */
+    pub fn deprecated_202001_get_count_request(&self) -> OrdersDeprecated202001GetCountRequest<'_> {
+        OrdersDeprecated202001GetCountRequest {
+            client: self,
+            created_at_min: None,
+            created_at_max: None,
+            updated_at_min: None,
+            updated_at_max: None,
+            status: None,
+            financial_status: None,
+            fulfillment_status: None,
+        }
+    }
     /**
      * Closes an order.
      *
@@ -898,6 +4778,35 @@ impl Orders {
             )
             .await
     }
+    /**
+     * Caution
+      For multi-currency orders, the currency property is required whenever the amount property is provided. For more information, see Migrating to support multiple currencies.
+
+    Cancels an order. Orders that have a fulfillment object can't be canceled.
+     *
+     * This function performs a `POST` to the `/admin/api/2020-01/orders/{order_id}/cancel.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_create_param_cancel`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/orders/order#cancel-2020-01
+     */
+    pub fn deprecated_202001_create_param_cancel_request<'a>(
+        &'a self,
+        order_id: &'a str,
+        body: &'a serde_json::Value,
+    ) -> OrdersDeprecated202001CreateParamCancelRequest<'a> {
+        OrdersDeprecated202001CreateParamCancelRequest {
+            client: self,
+            order_id,
+            amount: None,
+            currency: None,
+            restock_deprecated: None,
+            reason: None,
+            email: None,
+            refund: None,
+            body,
+        }
+    }
     /**
      * Retrieves a list of orders. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -1027,6 +4936,34 @@ impl Orders {
             )
             .await
     }
+    /**
+     * Retrieves a list of orders. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-04/orders.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_get`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/orders/order#index-2020-04
+     */
+    pub fn deprecated_202004_get_request(&self) -> OrdersDeprecated202004GetRequest<'_> {
+        OrdersDeprecated202004GetRequest {
+            client: self,
+            ids: None,
+            limit: None,
+            since_id: None,
+            created_at_min: None,
+            created_at_max: None,
+            updated_at_min: None,
+            updated_at_max: None,
+            processed_at_min: None,
+            processed_at_max: None,
+            attribution_app_id: None,
+            status: None,
+            financial_status: None,
+            fulfillment_status: None,
+            fields: None,
+        }
+    }
     /**
     * Creates an order. By default, product inventory is not claimed.
              When you create an order, you can include the following option parameters in the body of the request:
@@ -1112,6 +5049,25 @@ impl Orders {
             )
             .await
     }
+    /**
+     * Retrieves a specific order.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-04/orders/{order_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_get_param`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/orders/order#show-2020-04
+     */
+    pub fn deprecated_202004_get_param_request<'a>(
+        &'a self,
+        order_id: &'a str,
+    ) -> OrdersDeprecated202004GetParamRequest<'a> {
+        OrdersDeprecated202004GetParamRequest {
+            client: self,
+            order_id,
+            fields: None,
+        }
+    }
     /**
      * Updates an order.
      *
@@ -1264,6 +5220,27 @@ impl Orders {
             )
             .await
     }
+    /**
+     * Retrieves an order count.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-04/orders/count.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_get_count`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/orders/order#count-2020-04
+     */
+    pub fn deprecated_202004_get_count_request(&self) -> OrdersDeprecated202004GetCountRequest<'_> {
+        OrdersDeprecated202004GetCountRequest {
+            client: self,
+            created_at_min: None,
+            created_at_max: None,
+            updated_at_min: None,
+            updated_at_max: None,
+            status: None,
+            financial_status: None,
+            fulfillment_status: None,
+        }
+    }
     /**
      * Closes an order.
      *
@@ -1407,6 +5384,35 @@ impl Orders {
             )
             .await
     }
+    /**
+     * Caution
+      For multi-currency orders, the currency property is required whenever the amount property is provided. For more information, see Migrating to support multiple currencies.
+
+    Cancels an order. Orders that have a fulfillment object can't be canceled.
+     *
+     * This function performs a `POST` to the `/admin/api/2020-04/orders/{order_id}/cancel.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_create_param_cancel`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/orders/order#cancel-2020-04
+     */
+    pub fn deprecated_202004_create_param_cancel_request<'a>(
+        &'a self,
+        order_id: &'a str,
+        body: &'a serde_json::Value,
+    ) -> OrdersDeprecated202004CreateParamCancelRequest<'a> {
+        OrdersDeprecated202004CreateParamCancelRequest {
+            client: self,
+            order_id,
+            amount: None,
+            currency: None,
+            restock_deprecated: None,
+            reason: None,
+            email: None,
+            refund: None,
+            body,
+        }
+    }
     /**
      * Retrieves a list of orders. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -1536,6 +5542,34 @@ impl Orders {
             )
             .await
     }
+    /**
+     * Retrieves a list of orders. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-07/orders.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_get`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/orders/order#index-2020-07
+     */
+    pub fn deprecated_202007_get_request(&self) -> OrdersDeprecated202007GetRequest<'_> {
+        OrdersDeprecated202007GetRequest {
+            client: self,
+            ids: None,
+            limit: None,
+            since_id: None,
+            created_at_min: None,
+            created_at_max: None,
+            updated_at_min: None,
+            updated_at_max: None,
+            processed_at_min: None,
+            processed_at_max: None,
+            attribution_app_id: None,
+            status: None,
+            financial_status: None,
+            fulfillment_status: None,
+            fields: None,
+        }
+    }
     /**
      * Retrieves a list of all order risks for an order. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -2659,6 +6693,27 @@ impl Orders {
             )
             .await
     }
+    /**
+     * Retrieves a list of refunds for an order. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-01/orders/{order_id}/refunds.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_get_param_refund`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/orders/refund#index-2020-01
+     */
+    pub fn deprecated_202001_get_param_refund_request<'a>(
+        &'a self,
+        order_id: &'a str,
+    ) -> OrdersDeprecated202001GetParamRefundRequest<'a> {
+        OrdersDeprecated202001GetParamRefundRequest {
+            client: self,
+            order_id,
+            limit: None,
+            fields: None,
+            in_shop_currency: None,
+        }
+    }
     /**
     * Caution
                For multi-currency orders, the currency property is required whenever the amount property is provided. For more information, see Migrating to support multiple currencies.
@@ -2777,6 +6832,41 @@ impl Orders {
             )
             .await
     }
+    /**
+    * Caution
+               For multi-currency orders, the currency property is required whenever the amount property is provided. For more information, see Migrating to support multiple currencies.
+
+             Creates a refund. Use the calculate endpoint to produce the transactions to submit.
+
+
+               Note
+               When you use this endpoint with a Partner development store or a trial store, you can create only five refunds per minute.
+    *
+    * This function performs a `POST` to the `/admin/api/2020-01/orders/{order_id}/refunds.json` endpoint.
+    *
+    * As opposed to `deprecated_202001_create_param_refunds`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+    *
+    * https://shopify.dev/docs/admin-api/rest/reference/orders/refund#create-2020-01
+    */
+    pub fn deprecated_202001_create_param_refunds_request<'a>(
+        &'a self,
+        order_id: &'a str,
+        body: &'a serde_json::Value,
+    ) -> OrdersDeprecated202001CreateParamRefundsRequest<'a> {
+        OrdersDeprecated202001CreateParamRefundsRequest {
+            client: self,
+            order_id,
+            restock_deprecated: None,
+            notify: None,
+            note: None,
+            discrepancy_reason: None,
+            shipping: None,
+            refund_line_items: None,
+            transactions: None,
+            currency: None,
+            body,
+        }
+    }
     /**
      * Retrieves a specific refund.
      *
@@ -2826,6 +6916,28 @@ impl Orders {
             )
             .await
     }
+    /**
+     * Retrieves a specific refund.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-01/orders/{order_id}/refunds/{refund_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_get_param_refunds_refund`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/orders/refund#show-2020-01
+     */
+    pub fn deprecated_202001_get_param_refunds_refund_request<'a>(
+        &'a self,
+        order_id: &'a str,
+        refund_id: &'a str,
+    ) -> OrdersDeprecated202001GetParamRefundsRefundRequest<'a> {
+        OrdersDeprecated202001GetParamRefundsRefundRequest {
+            client: self,
+            order_id,
+            refund_id,
+            fields: None,
+            in_shop_currency: None,
+        }
+    }
     /**
     * Caution
              For multi-currency orders, the currency property is required whenever the amount property is provided. For more information, see Migrating to support multiple currencies.
@@ -2914,6 +7026,38 @@ impl Orders {
             )
             .await
     }
+    /**
+    * Caution
+             For multi-currency orders, the currency property is required whenever the amount property is provided. For more information, see Migrating to support multiple currencies.
+
+             Calculates refund transactions based on line items and shipping. When you want to create a refund,
+             you should first use the calculate endpoint to generate accurate refund transactions. Specify the line items
+             that are being refunded, their quantity and restock instructions, and whether you intend to refund
+             shipping costs. If the restock instructions can't be met—for example, because you try to return more items than have been
+             fulfilled—then the endpoint returns modified restock instructions. You can then use the response in the body of the request to create the actual refund.
+             The response includes a transactions object with "kind": "suggested_refund",
+             which must to be changed to "kind" : "refund" for the refund to be accepted.
+    *
+    * This function performs a `POST` to the `/admin/api/2020-01/orders/{order_id}/refunds/calculate.json` endpoint.
+    *
+    * As opposed to `deprecated_202001_create_param_refunds_calculate`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+    *
+    * https://shopify.dev/docs/admin-api/rest/reference/orders/refund#calculate-2020-01
+    */
+    pub fn deprecated_202001_create_param_refunds_calculate_request<'a>(
+        &'a self,
+        order_id: &'a str,
+        body: &'a serde_json::Value,
+    ) -> OrdersDeprecated202001CreateParamRefundsCalculateRequest<'a> {
+        OrdersDeprecated202001CreateParamRefundsCalculateRequest {
+            client: self,
+            order_id,
+            shipping: None,
+            refund_line_items: None,
+            currency: None,
+            body,
+        }
+    }
     /**
      * Retrieves a list of refunds for an order. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -2966,6 +7110,27 @@ impl Orders {
             )
             .await
     }
+    /**
+     * Retrieves a list of refunds for an order. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-04/orders/{order_id}/refunds.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_get_param_refund`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/orders/refund#index-2020-04
+     */
+    pub fn deprecated_202004_get_param_refund_request<'a>(
+        &'a self,
+        order_id: &'a str,
+    ) -> OrdersDeprecated202004GetParamRefundRequest<'a> {
+        OrdersDeprecated202004GetParamRefundRequest {
+            client: self,
+            order_id,
+            limit: None,
+            fields: None,
+            in_shop_currency: None,
+        }
+    }
     /**
     * Caution
                For multi-currency orders, the currency property is required whenever the amount property is provided. For more information, see Migrating to support multiple currencies.
@@ -3084,6 +7249,41 @@ impl Orders {
             )
             .await
     }
+    /**
+    * Caution
+               For multi-currency orders, the currency property is required whenever the amount property is provided. For more information, see Migrating to support multiple currencies.
+
+             Creates a refund. Use the calculate endpoint to produce the transactions to submit.
+
+
+               Note
+               When you use this endpoint with a Partner development store or a trial store, you can create only five refunds per minute.
+    *
+    * This function performs a `POST` to the `/admin/api/2020-04/orders/{order_id}/refunds.json` endpoint.
+    *
+    * As opposed to `deprecated_202004_create_param_refunds`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+    *
+    * https://shopify.dev/docs/admin-api/rest/reference/orders/refund#create-2020-04
+    */
+    pub fn deprecated_202004_create_param_refunds_request<'a>(
+        &'a self,
+        order_id: &'a str,
+        body: &'a serde_json::Value,
+    ) -> OrdersDeprecated202004CreateParamRefundsRequest<'a> {
+        OrdersDeprecated202004CreateParamRefundsRequest {
+            client: self,
+            order_id,
+            restock_deprecated: None,
+            notify: None,
+            note: None,
+            discrepancy_reason: None,
+            shipping: None,
+            refund_line_items: None,
+            transactions: None,
+            currency: None,
+            body,
+        }
+    }
     /**
      * Retrieves a specific refund.
      *
@@ -3133,6 +7333,28 @@ impl Orders {
             )
             .await
     }
+    /**
+     * Retrieves a specific refund.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-04/orders/{order_id}/refunds/{refund_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_get_param_refunds_refund`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/orders/refund#show-2020-04
+     */
+    pub fn deprecated_202004_get_param_refunds_refund_request<'a>(
+        &'a self,
+        order_id: &'a str,
+        refund_id: &'a str,
+    ) -> OrdersDeprecated202004GetParamRefundsRefundRequest<'a> {
+        OrdersDeprecated202004GetParamRefundsRefundRequest {
+            client: self,
+            order_id,
+            refund_id,
+            fields: None,
+            in_shop_currency: None,
+        }
+    }
     /**
     * Caution
              For multi-currency orders, the currency property is required whenever the amount property is provided. For more information, see Migrating to support multiple currencies.
@@ -3221,6 +7443,38 @@ impl Orders {
             )
             .await
     }
+    /**
+    * Caution
+             For multi-currency orders, the currency property is required whenever the amount property is provided. For more information, see Migrating to support multiple currencies.
+
+             Calculates refund transactions based on line items and shipping. When you want to create a refund,
+             you should first use the calculate endpoint to generate accurate refund transactions. Specify the line items
+             that are being refunded, their quantity and restock instructions, and whether you intend to refund
+             shipping costs. If the restock instructions can't be met—for example, because you try to return more items than have been
+             fulfilled—then the endpoint returns modified restock instructions. You can then use the response in the body of the request to create the actual refund.
+             The response includes a transactions object with "kind": "suggested_refund",
+             which must to be changed to "kind" : "refund" for the refund to be accepted.
+    *
+    * This function performs a `POST` to the `/admin/api/2020-04/orders/{order_id}/refunds/calculate.json` endpoint.
+    *
+    * As opposed to `deprecated_202004_create_param_refunds_calculate`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+    *
+    * https://shopify.dev/docs/admin-api/rest/reference/orders/refund#calculate-2020-04
+    */
+    pub fn deprecated_202004_create_param_refunds_calculate_request<'a>(
+        &'a self,
+        order_id: &'a str,
+        body: &'a serde_json::Value,
+    ) -> OrdersDeprecated202004CreateParamRefundsCalculateRequest<'a> {
+        OrdersDeprecated202004CreateParamRefundsCalculateRequest {
+            client: self,
+            order_id,
+            shipping: None,
+            refund_line_items: None,
+            currency: None,
+            body,
+        }
+    }
     /**
      * Retrieves a list of refunds for an order. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -3273,6 +7527,27 @@ impl Orders {
             )
             .await
     }
+    /**
+     * Retrieves a list of refunds for an order. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-07/orders/{order_id}/refunds.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_get_param_refund`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/orders/refund#index-2020-07
+     */
+    pub fn deprecated_202007_get_param_refund_request<'a>(
+        &'a self,
+        order_id: &'a str,
+    ) -> OrdersDeprecated202007GetParamRefundRequest<'a> {
+        OrdersDeprecated202007GetParamRefundRequest {
+            client: self,
+            order_id,
+            limit: None,
+            fields: None,
+            in_shop_currency: None,
+        }
+    }
     /**
     * Caution
                For multi-currency orders, the currency property is required whenever the amount property is provided. For more information, see Migrating to support multiple currencies.
@@ -3391,6 +7666,41 @@ impl Orders {
             )
             .await
     }
+    /**
+    * Caution
+               For multi-currency orders, the currency property is required whenever the amount property is provided. For more information, see Migrating to support multiple currencies.
+
+             Creates a refund. Use the calculate endpoint to produce the transactions to submit.
+
+
+               Note
+               When you use this endpoint with a Partner development store or a trial store, you can create only five refunds per minute.
+    *
+    * This function performs a `POST` to the `/admin/api/2020-07/orders/{order_id}/refunds.json` endpoint.
+    *
+    * As opposed to `deprecated_202007_create_param_refunds`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+    *
+    * https://shopify.dev/docs/admin-api/rest/reference/orders/refund#create-2020-07
+    */
+    pub fn deprecated_202007_create_param_refunds_request<'a>(
+        &'a self,
+        order_id: &'a str,
+        body: &'a serde_json::Value,
+    ) -> OrdersDeprecated202007CreateParamRefundsRequest<'a> {
+        OrdersDeprecated202007CreateParamRefundsRequest {
+            client: self,
+            order_id,
+            restock_deprecated: None,
+            notify: None,
+            note: None,
+            discrepancy_reason: None,
+            shipping: None,
+            refund_line_items: None,
+            transactions: None,
+            currency: None,
+            body,
+        }
+    }
     /**
      * Retrieves a specific refund.
      *
@@ -3440,6 +7750,28 @@ impl Orders {
             )
             .await
     }
+    /**
+     * Retrieves a specific refund.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-07/orders/{order_id}/refunds/{refund_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_get_param_refunds_refund`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/orders/refund#show-2020-07
+     */
+    pub fn deprecated_202007_get_param_refunds_refund_request<'a>(
+        &'a self,
+        order_id: &'a str,
+        refund_id: &'a str,
+    ) -> OrdersDeprecated202007GetParamRefundsRefundRequest<'a> {
+        OrdersDeprecated202007GetParamRefundsRefundRequest {
+            client: self,
+            order_id,
+            refund_id,
+            fields: None,
+            in_shop_currency: None,
+        }
+    }
     /**
     * Caution
              For multi-currency orders, the currency property is required whenever the amount property is provided. For more information, see Migrating to support multiple currencies.
@@ -3528,6 +7860,38 @@ impl Orders {
             )
             .await
     }
+    /**
+    * Caution
+             For multi-currency orders, the currency property is required whenever the amount property is provided. For more information, see Migrating to support multiple currencies.
+
+             Calculates refund transactions based on line items and shipping. When you want to create a refund,
+             you should first use the calculate endpoint to generate accurate refund transactions. Specify the line items
+             that are being refunded, their quantity and restock instructions, and whether you intend to refund
+             shipping costs. If the restock instructions can't be met—for example, because you try to return more items than have been
+             fulfilled—then the endpoint returns modified restock instructions. You can then use the response in the body of the request to create the actual refund.
+             The response includes a transactions object with "kind": "suggested_refund",
+             which must to be changed to "kind" : "refund" for the refund to be accepted.
+    *
+    * This function performs a `POST` to the `/admin/api/2020-07/orders/{order_id}/refunds/calculate.json` endpoint.
+    *
+    * As opposed to `deprecated_202007_create_param_refunds_calculate`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+    *
+    * https://shopify.dev/docs/admin-api/rest/reference/orders/refund#calculate-2020-07
+    */
+    pub fn deprecated_202007_create_param_refunds_calculate_request<'a>(
+        &'a self,
+        order_id: &'a str,
+        body: &'a serde_json::Value,
+    ) -> OrdersDeprecated202007CreateParamRefundsCalculateRequest<'a> {
+        OrdersDeprecated202007CreateParamRefundsCalculateRequest {
+            client: self,
+            order_id,
+            shipping: None,
+            refund_line_items: None,
+            currency: None,
+            body,
+        }
+    }
     /**
      * Retrieves a list of refunds for an order. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -3580,6 +7944,27 @@ impl Orders {
             )
             .await
     }
+    /**
+     * Retrieves a list of refunds for an order. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-10/orders/{order_id}/refunds.json` endpoint.
+     *
+     * As opposed to `get_param_refund`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/orders/refund#index-2020-10
+     */
+    pub fn get_param_refund_request<'a>(
+        &'a self,
+        order_id: &'a str,
+    ) -> OrdersGetParamRefundRequest<'a> {
+        OrdersGetParamRefundRequest {
+            client: self,
+            order_id,
+            limit: None,
+            fields: None,
+            in_shop_currency: None,
+        }
+    }
     /**
     * Caution
                For multi-currency orders, the currency property is required whenever the amount property is provided. For more information, see Migrating to support multiple currencies.
@@ -3698,6 +8083,41 @@ impl Orders {
             )
             .await
     }
+    /**
+    * Caution
+               For multi-currency orders, the currency property is required whenever the amount property is provided. For more information, see Migrating to support multiple currencies.
+
+             Creates a refund. Use the calculate endpoint to produce the transactions to submit.
+
+
+               Note
+               When you use this endpoint with a Partner development store or a trial store, you can create only five refunds per minute.
+    *
+    * This function performs a `POST` to the `/admin/api/2020-10/orders/{order_id}/refunds.json` endpoint.
+    *
+    * As opposed to `create_param_refunds`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+    *
+    * https://shopify.dev/docs/admin-api/rest/reference/orders/refund#create-2020-10
+    */
+    pub fn create_param_refunds_request<'a>(
+        &'a self,
+        order_id: &'a str,
+        body: &'a serde_json::Value,
+    ) -> OrdersCreateParamRefundsRequest<'a> {
+        OrdersCreateParamRefundsRequest {
+            client: self,
+            order_id,
+            restock_deprecated: None,
+            notify: None,
+            note: None,
+            discrepancy_reason: None,
+            shipping: None,
+            refund_line_items: None,
+            transactions: None,
+            currency: None,
+            body,
+        }
+    }
     /**
      * Retrieves a specific refund.
      *
@@ -3747,6 +8167,28 @@ impl Orders {
             )
             .await
     }
+    /**
+     * Retrieves a specific refund.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-10/orders/{order_id}/refunds/{refund_id}.json` endpoint.
+     *
+     * As opposed to `get_param_refunds_refund`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/orders/refund#show-2020-10
+     */
+    pub fn get_param_refunds_refund_request<'a>(
+        &'a self,
+        order_id: &'a str,
+        refund_id: &'a str,
+    ) -> OrdersGetParamRefundsRefundRequest<'a> {
+        OrdersGetParamRefundsRefundRequest {
+            client: self,
+            order_id,
+            refund_id,
+            fields: None,
+            in_shop_currency: None,
+        }
+    }
     /**
     * Caution
              For multi-currency orders, the currency property is required whenever the amount property is provided. For more information, see Migrating to support multiple currencies.
@@ -3835,6 +8277,38 @@ impl Orders {
             )
             .await
     }
+    /**
+    * Caution
+             For multi-currency orders, the currency property is required whenever the amount property is provided. For more information, see Migrating to support multiple currencies.
+
+             Calculates refund transactions based on line items and shipping. When you want to create a refund,
+             you should first use the calculate endpoint to generate accurate refund transactions. Specify the line items
+             that are being refunded, their quantity and restock instructions, and whether you intend to refund
+             shipping costs. If the restock instructions can't be met—for example, because you try to return more items than have been
+             fulfilled—then the endpoint returns modified restock instructions. You can then use the response in the body of the request to create the actual refund.
+             The response includes a transactions object with "kind": "suggested_refund",
+             which must to be changed to "kind" : "refund" for the refund to be accepted.
+    *
+    * This function performs a `POST` to the `/admin/api/2020-10/orders/{order_id}/refunds/calculate.json` endpoint.
+    *
+    * As opposed to `create_param_refunds_calculate`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+    *
+    * https://shopify.dev/docs/admin-api/rest/reference/orders/refund#calculate-2020-10
+    */
+    pub fn create_param_refunds_calculate_request<'a>(
+        &'a self,
+        order_id: &'a str,
+        body: &'a serde_json::Value,
+    ) -> OrdersCreateParamRefundsCalculateRequest<'a> {
+        OrdersCreateParamRefundsCalculateRequest {
+            client: self,
+            order_id,
+            shipping: None,
+            refund_line_items: None,
+            currency: None,
+            body,
+        }
+    }
     /**
      * Retrieves a list of refunds for an order. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -3887,6 +8361,27 @@ impl Orders {
             )
             .await
     }
+    /**
+     * Retrieves a list of refunds for an order. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2021-01/orders/{order_id}/refunds.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_get_param_refund`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/orders/refund#index-2021-01
+     */
+    pub fn deprecated_202101_get_param_refund_request<'a>(
+        &'a self,
+        order_id: &'a str,
+    ) -> OrdersDeprecated202101GetParamRefundRequest<'a> {
+        OrdersDeprecated202101GetParamRefundRequest {
+            client: self,
+            order_id,
+            limit: None,
+            fields: None,
+            in_shop_currency: None,
+        }
+    }
     /**
     * Caution
                For multi-currency orders, the currency property is required whenever the amount property is provided. For more information, see Migrating to support multiple currencies.
@@ -4005,6 +8500,41 @@ impl Orders {
             )
             .await
     }
+    /**
+    * Caution
+               For multi-currency orders, the currency property is required whenever the amount property is provided. For more information, see Migrating to support multiple currencies.
+
+             Creates a refund. Use the calculate endpoint to produce the transactions to submit.
+
+
+               Note
+               When you use this endpoint with a Partner development store or a trial store, you can create only five refunds per minute.
+    *
+    * This function performs a `POST` to the `/admin/api/2021-01/orders/{order_id}/refunds.json` endpoint.
+    *
+    * As opposed to `deprecated_202101_create_param_refunds`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+    *
+    * https://shopify.dev/docs/admin-api/rest/reference/orders/refund#create-2021-01
+    */
+    pub fn deprecated_202101_create_param_refunds_request<'a>(
+        &'a self,
+        order_id: &'a str,
+        body: &'a serde_json::Value,
+    ) -> OrdersDeprecated202101CreateParamRefundsRequest<'a> {
+        OrdersDeprecated202101CreateParamRefundsRequest {
+            client: self,
+            order_id,
+            restock_deprecated: None,
+            notify: None,
+            note: None,
+            discrepancy_reason: None,
+            shipping: None,
+            refund_line_items: None,
+            transactions: None,
+            currency: None,
+            body,
+        }
+    }
     /**
      * Retrieves a specific refund.
      *
@@ -4054,6 +8584,28 @@ impl Orders {
             )
             .await
     }
+    /**
+     * Retrieves a specific refund.
+     *
+     * This function performs a `GET` to the `/admin/api/2021-01/orders/{order_id}/refunds/{refund_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_get_param_refunds_refund`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/orders/refund#show-2021-01
+     */
+    pub fn deprecated_202101_get_param_refunds_refund_request<'a>(
+        &'a self,
+        order_id: &'a str,
+        refund_id: &'a str,
+    ) -> OrdersDeprecated202101GetParamRefundsRefundRequest<'a> {
+        OrdersDeprecated202101GetParamRefundsRefundRequest {
+            client: self,
+            order_id,
+            refund_id,
+            fields: None,
+            in_shop_currency: None,
+        }
+    }
     /**
     * Caution
              For multi-currency orders, the currency property is required whenever the amount property is provided. For more information, see Migrating to support multiple currencies.
@@ -4142,6 +8694,38 @@ impl Orders {
             )
             .await
     }
+    /**
+    * Caution
+             For multi-currency orders, the currency property is required whenever the amount property is provided. For more information, see Migrating to support multiple currencies.
+
+             Calculates refund transactions based on line items and shipping. When you want to create a refund,
+             you should first use the calculate endpoint to generate accurate refund transactions. Specify the line items
+             that are being refunded, their quantity and restock instructions, and whether you intend to refund
+             shipping costs. If the restock instructions can't be met—for example, because you try to return more items than have been
+             fulfilled—then the endpoint returns modified restock instructions. You can then use the response in the body of the request to create the actual refund.
+             The response includes a transactions object with "kind": "suggested_refund",
+             which must to be changed to "kind" : "refund" for the refund to be accepted.
+    *
+    * This function performs a `POST` to the `/admin/api/2021-01/orders/{order_id}/refunds/calculate.json` endpoint.
+    *
+    * As opposed to `deprecated_202101_create_param_refunds_calculate`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+    *
+    * https://shopify.dev/docs/admin-api/rest/reference/orders/refund#calculate-2021-01
+    */
+    pub fn deprecated_202101_create_param_refunds_calculate_request<'a>(
+        &'a self,
+        order_id: &'a str,
+        body: &'a serde_json::Value,
+    ) -> OrdersDeprecated202101CreateParamRefundsCalculateRequest<'a> {
+        OrdersDeprecated202101CreateParamRefundsCalculateRequest {
+            client: self,
+            order_id,
+            shipping: None,
+            refund_line_items: None,
+            currency: None,
+            body,
+        }
+    }
     /**
      * Retrieves a list of refunds for an order. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -4194,6 +8778,27 @@ impl Orders {
             )
             .await
     }
+    /**
+     * Retrieves a list of refunds for an order. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/unstable/orders/{order_id}/refunds.json` endpoint.
+     *
+     * As opposed to `deprecated_unstable_get_param_refund`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/orders/refund#index-unstable
+     */
+    pub fn deprecated_unstable_get_param_refund_request<'a>(
+        &'a self,
+        order_id: &'a str,
+    ) -> OrdersDeprecatedUnstableGetParamRefundRequest<'a> {
+        OrdersDeprecatedUnstableGetParamRefundRequest {
+            client: self,
+            order_id,
+            limit: None,
+            fields: None,
+            in_shop_currency: None,
+        }
+    }
     /**
     * Caution
                For multi-currency orders, the currency property is required whenever the amount property is provided. For more information, see Migrating to support multiple currencies.
@@ -4312,6 +8917,41 @@ impl Orders {
             )
             .await
     }
+    /**
+    * Caution
+               For multi-currency orders, the currency property is required whenever the amount property is provided. For more information, see Migrating to support multiple currencies.
+
+             Creates a refund. Use the calculate endpoint to produce the transactions to submit.
+
+
+               Note
+               When you use this endpoint with a Partner development store or a trial store, you can create only five refunds per minute.
+    *
+    * This function performs a `POST` to the `/admin/api/unstable/orders/{order_id}/refunds.json` endpoint.
+    *
+    * As opposed to `deprecated_unstable_create_param_refunds`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+    *
+    * https://shopify.dev/docs/admin-api/rest/reference/orders/refund#create-unstable
+    */
+    pub fn deprecated_unstable_create_param_refunds_request<'a>(
+        &'a self,
+        order_id: &'a str,
+        body: &'a serde_json::Value,
+    ) -> OrdersDeprecatedUnstableCreateParamRefundsRequest<'a> {
+        OrdersDeprecatedUnstableCreateParamRefundsRequest {
+            client: self,
+            order_id,
+            restock_deprecated: None,
+            notify: None,
+            note: None,
+            discrepancy_reason: None,
+            shipping: None,
+            refund_line_items: None,
+            transactions: None,
+            currency: None,
+            body,
+        }
+    }
     /**
      * Retrieves a specific refund.
      *
@@ -4361,6 +9001,28 @@ impl Orders {
             )
             .await
     }
+    /**
+     * Retrieves a specific refund.
+     *
+     * This function performs a `GET` to the `/admin/api/unstable/orders/{order_id}/refunds/{refund_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_unstable_get_param_refunds_refund`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/orders/refund#show-unstable
+     */
+    pub fn deprecated_unstable_get_param_refunds_refund_request<'a>(
+        &'a self,
+        order_id: &'a str,
+        refund_id: &'a str,
+    ) -> OrdersDeprecatedUnstableGetParamRefundsRefundRequest<'a> {
+        OrdersDeprecatedUnstableGetParamRefundsRefundRequest {
+            client: self,
+            order_id,
+            refund_id,
+            fields: None,
+            in_shop_currency: None,
+        }
+    }
     /**
     * Caution
              For multi-currency orders, the currency property is required whenever the amount property is provided. For more information, see Migrating to support multiple currencies.
@@ -4449,4 +9111,36 @@ impl Orders {
             )
             .await
     }
+    /**
+    * Caution
+             For multi-currency orders, the currency property is required whenever the amount property is provided. For more information, see Migrating to support multiple currencies.
+
+             Calculates refund transactions based on line items and shipping. When you want to create a refund,
+             you should first use the calculate endpoint to generate accurate refund transactions. Specify the line items
+             that are being refunded, their quantity and restock instructions, and whether you intend to refund
+             shipping costs. If the restock instructions can't be met—for example, because you try to return more items than have been
+             fulfilled—then the endpoint returns modified restock instructions. You can then use the response in the body of the request to create the actual refund.
+             The response includes a transactions object with "kind": "suggested_refund",
+             which must to be changed to "kind" : "refund" for the refund to be accepted.
+    *
+    * This function performs a `POST` to the `/admin/api/unstable/orders/{order_id}/refunds/calculate.json` endpoint.
+    *
+    * As opposed to `deprecated_unstable_create_param_refunds_calculate`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+    *
+    * https://shopify.dev/docs/admin-api/rest/reference/orders/refund#calculate-unstable
+    */
+    pub fn deprecated_unstable_create_param_refunds_calculate_request<'a>(
+        &'a self,
+        order_id: &'a str,
+        body: &'a serde_json::Value,
+    ) -> OrdersDeprecatedUnstableCreateParamRefundsCalculateRequest<'a> {
+        OrdersDeprecatedUnstableCreateParamRefundsCalculateRequest {
+            client: self,
+            order_id,
+            shipping: None,
+            refund_line_items: None,
+            currency: None,
+            body,
+        }
+    }
 }
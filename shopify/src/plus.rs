@@ -1,6 +1,1452 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Plus::deprecated_202001_get_gift_card`], set with a builder.
+pub struct PlusDeprecated202001GetGiftCardRequest<'a> {
+    client: &'a Plus,
+    status: Option<&'a str>,
+    limit: Option<&'a str>,
+    since_id: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> PlusDeprecated202001GetGiftCardRequest<'a> {
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: &'a str) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            status,
+            limit,
+            since_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = status {
+            query_args.push(("status".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-01/gift_cards.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Plus::deprecated_202001_get_gift_cards_count`], set with a builder.
+pub struct PlusDeprecated202001GetGiftCardsCountRequest<'a> {
+    client: &'a Plus,
+    status: Option<&'a str>,
+}
+
+impl<'a> PlusDeprecated202001GetGiftCardsCountRequest<'a> {
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: &'a str) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self { client, status } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = status {
+            query_args.push(("status".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-01/gift_cards/count.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Plus::deprecated_202001_get_gift_cards_search`], set with a builder.
+pub struct PlusDeprecated202001GetGiftCardsSearchRequest<'a> {
+    client: &'a Plus,
+    order: Option<&'a str>,
+    query: Option<&'a str>,
+    limit: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> PlusDeprecated202001GetGiftCardsSearchRequest<'a> {
+    /// Sets the `order` query parameter.
+    pub fn order(mut self, order: &'a str) -> Self {
+        self.order = Some(order);
+        self
+    }
+
+    /// Sets the `query` query parameter.
+    pub fn query(mut self, query: &'a str) -> Self {
+        self.query = Some(query);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            order,
+            query,
+            limit,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = order {
+            query_args.push(("order".to_string(), v.to_string()));
+        }
+        if let Some(v) = query {
+            query_args.push(("query".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-01/gift_cards/search.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Plus::deprecated_202004_get_gift_card`], set with a builder.
+pub struct PlusDeprecated202004GetGiftCardRequest<'a> {
+    client: &'a Plus,
+    status: Option<&'a str>,
+    limit: Option<&'a str>,
+    since_id: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> PlusDeprecated202004GetGiftCardRequest<'a> {
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: &'a str) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            status,
+            limit,
+            since_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = status {
+            query_args.push(("status".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-04/gift_cards.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Plus::deprecated_202004_get_gift_cards_count`], set with a builder.
+pub struct PlusDeprecated202004GetGiftCardsCountRequest<'a> {
+    client: &'a Plus,
+    status: Option<&'a str>,
+}
+
+impl<'a> PlusDeprecated202004GetGiftCardsCountRequest<'a> {
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: &'a str) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self { client, status } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = status {
+            query_args.push(("status".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-04/gift_cards/count.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Plus::deprecated_202004_get_gift_cards_search`], set with a builder.
+pub struct PlusDeprecated202004GetGiftCardsSearchRequest<'a> {
+    client: &'a Plus,
+    order: Option<&'a str>,
+    query: Option<&'a str>,
+    limit: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> PlusDeprecated202004GetGiftCardsSearchRequest<'a> {
+    /// Sets the `order` query parameter.
+    pub fn order(mut self, order: &'a str) -> Self {
+        self.order = Some(order);
+        self
+    }
+
+    /// Sets the `query` query parameter.
+    pub fn query(mut self, query: &'a str) -> Self {
+        self.query = Some(query);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            order,
+            query,
+            limit,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = order {
+            query_args.push(("order".to_string(), v.to_string()));
+        }
+        if let Some(v) = query {
+            query_args.push(("query".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-04/gift_cards/search.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Plus::deprecated_202007_get_gift_card`], set with a builder.
+pub struct PlusDeprecated202007GetGiftCardRequest<'a> {
+    client: &'a Plus,
+    status: Option<&'a str>,
+    limit: Option<&'a str>,
+    since_id: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> PlusDeprecated202007GetGiftCardRequest<'a> {
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: &'a str) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            status,
+            limit,
+            since_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = status {
+            query_args.push(("status".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-07/gift_cards.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Plus::deprecated_202007_get_gift_cards_count`], set with a builder.
+pub struct PlusDeprecated202007GetGiftCardsCountRequest<'a> {
+    client: &'a Plus,
+    status: Option<&'a str>,
+}
+
+impl<'a> PlusDeprecated202007GetGiftCardsCountRequest<'a> {
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: &'a str) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self { client, status } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = status {
+            query_args.push(("status".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-07/gift_cards/count.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Plus::deprecated_202007_get_gift_cards_search`], set with a builder.
+pub struct PlusDeprecated202007GetGiftCardsSearchRequest<'a> {
+    client: &'a Plus,
+    order: Option<&'a str>,
+    query: Option<&'a str>,
+    limit: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> PlusDeprecated202007GetGiftCardsSearchRequest<'a> {
+    /// Sets the `order` query parameter.
+    pub fn order(mut self, order: &'a str) -> Self {
+        self.order = Some(order);
+        self
+    }
+
+    /// Sets the `query` query parameter.
+    pub fn query(mut self, query: &'a str) -> Self {
+        self.query = Some(query);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            order,
+            query,
+            limit,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = order {
+            query_args.push(("order".to_string(), v.to_string()));
+        }
+        if let Some(v) = query {
+            query_args.push(("query".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-07/gift_cards/search.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Plus::get_gift_card`], set with a builder.
+pub struct PlusGetGiftCardRequest<'a> {
+    client: &'a Plus,
+    status: Option<&'a str>,
+    limit: Option<&'a str>,
+    since_id: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> PlusGetGiftCardRequest<'a> {
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: &'a str) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            status,
+            limit,
+            since_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = status {
+            query_args.push(("status".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-10/gift_cards.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Plus::get_gift_cards_count`], set with a builder.
+pub struct PlusGetGiftCardsCountRequest<'a> {
+    client: &'a Plus,
+    status: Option<&'a str>,
+}
+
+impl<'a> PlusGetGiftCardsCountRequest<'a> {
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: &'a str) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self { client, status } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = status {
+            query_args.push(("status".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-10/gift_cards/count.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Plus::get_gift_cards_search`], set with a builder.
+pub struct PlusGetGiftCardsSearchRequest<'a> {
+    client: &'a Plus,
+    order: Option<&'a str>,
+    query: Option<&'a str>,
+    limit: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> PlusGetGiftCardsSearchRequest<'a> {
+    /// Sets the `order` query parameter.
+    pub fn order(mut self, order: &'a str) -> Self {
+        self.order = Some(order);
+        self
+    }
+
+    /// Sets the `query` query parameter.
+    pub fn query(mut self, query: &'a str) -> Self {
+        self.query = Some(query);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            order,
+            query,
+            limit,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = order {
+            query_args.push(("order".to_string(), v.to_string()));
+        }
+        if let Some(v) = query {
+            query_args.push(("query".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-10/gift_cards/search.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Plus::deprecated_202101_get_gift_card`], set with a builder.
+pub struct PlusDeprecated202101GetGiftCardRequest<'a> {
+    client: &'a Plus,
+    status: Option<&'a str>,
+    limit: Option<&'a str>,
+    since_id: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> PlusDeprecated202101GetGiftCardRequest<'a> {
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: &'a str) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            status,
+            limit,
+            since_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = status {
+            query_args.push(("status".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2021-01/gift_cards.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Plus::deprecated_202101_get_gift_cards_count`], set with a builder.
+pub struct PlusDeprecated202101GetGiftCardsCountRequest<'a> {
+    client: &'a Plus,
+    status: Option<&'a str>,
+}
+
+impl<'a> PlusDeprecated202101GetGiftCardsCountRequest<'a> {
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: &'a str) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self { client, status } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = status {
+            query_args.push(("status".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2021-01/gift_cards/count.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Plus::deprecated_202101_get_gift_cards_search`], set with a builder.
+pub struct PlusDeprecated202101GetGiftCardsSearchRequest<'a> {
+    client: &'a Plus,
+    order: Option<&'a str>,
+    query: Option<&'a str>,
+    limit: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> PlusDeprecated202101GetGiftCardsSearchRequest<'a> {
+    /// Sets the `order` query parameter.
+    pub fn order(mut self, order: &'a str) -> Self {
+        self.order = Some(order);
+        self
+    }
+
+    /// Sets the `query` query parameter.
+    pub fn query(mut self, query: &'a str) -> Self {
+        self.query = Some(query);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            order,
+            query,
+            limit,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = order {
+            query_args.push(("order".to_string(), v.to_string()));
+        }
+        if let Some(v) = query {
+            query_args.push(("query".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2021-01/gift_cards/search.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Plus::deprecated_unstable_get_gift_card`], set with a builder.
+pub struct PlusDeprecatedUnstableGetGiftCardRequest<'a> {
+    client: &'a Plus,
+    status: Option<&'a str>,
+    limit: Option<&'a str>,
+    since_id: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> PlusDeprecatedUnstableGetGiftCardRequest<'a> {
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: &'a str) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            status,
+            limit,
+            since_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = status {
+            query_args.push(("status".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/unstable/gift_cards.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Plus::deprecated_unstable_get_gift_cards_count`], set with a builder.
+pub struct PlusDeprecatedUnstableGetGiftCardsCountRequest<'a> {
+    client: &'a Plus,
+    status: Option<&'a str>,
+}
+
+impl<'a> PlusDeprecatedUnstableGetGiftCardsCountRequest<'a> {
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: &'a str) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self { client, status } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = status {
+            query_args.push(("status".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/unstable/gift_cards/count.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Plus::deprecated_unstable_get_gift_cards_search`], set with a builder.
+pub struct PlusDeprecatedUnstableGetGiftCardsSearchRequest<'a> {
+    client: &'a Plus,
+    order: Option<&'a str>,
+    query: Option<&'a str>,
+    limit: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> PlusDeprecatedUnstableGetGiftCardsSearchRequest<'a> {
+    /// Sets the `order` query parameter.
+    pub fn order(mut self, order: &'a str) -> Self {
+        self.order = Some(order);
+        self
+    }
+
+    /// Sets the `query` query parameter.
+    pub fn query(mut self, query: &'a str) -> Self {
+        self.query = Some(query);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            order,
+            query,
+            limit,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = order {
+            query_args.push(("order".to_string(), v.to_string()));
+        }
+        if let Some(v) = query {
+            query_args.push(("query".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/unstable/gift_cards/search.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Plus::deprecated_202001_get_user`], set with a builder.
+pub struct PlusDeprecated202001GetUserRequest<'a> {
+    client: &'a Plus,
+    limit: Option<&'a str>,
+    page_info: Option<&'a str>,
+}
+
+impl<'a> PlusDeprecated202001GetUserRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `page_info` query parameter.
+    pub fn page_info(mut self, page_info: &'a str) -> Self {
+        self.page_info = Some(page_info);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            limit,
+            page_info,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_info {
+            query_args.push(("page_info".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/admin/api/2020-01/users.json?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Plus::deprecated_202004_get_user`], set with a builder.
+pub struct PlusDeprecated202004GetUserRequest<'a> {
+    client: &'a Plus,
+    limit: Option<&'a str>,
+    page_info: Option<&'a str>,
+}
+
+impl<'a> PlusDeprecated202004GetUserRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `page_info` query parameter.
+    pub fn page_info(mut self, page_info: &'a str) -> Self {
+        self.page_info = Some(page_info);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            limit,
+            page_info,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_info {
+            query_args.push(("page_info".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/admin/api/2020-04/users.json?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Plus::deprecated_202007_get_user`], set with a builder.
+pub struct PlusDeprecated202007GetUserRequest<'a> {
+    client: &'a Plus,
+    limit: Option<&'a str>,
+    page_info: Option<&'a str>,
+}
+
+impl<'a> PlusDeprecated202007GetUserRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `page_info` query parameter.
+    pub fn page_info(mut self, page_info: &'a str) -> Self {
+        self.page_info = Some(page_info);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            limit,
+            page_info,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_info {
+            query_args.push(("page_info".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/admin/api/2020-07/users.json?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Plus::get_user`], set with a builder.
+pub struct PlusGetUserRequest<'a> {
+    client: &'a Plus,
+    limit: Option<&'a str>,
+    page_info: Option<&'a str>,
+}
+
+impl<'a> PlusGetUserRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `page_info` query parameter.
+    pub fn page_info(mut self, page_info: &'a str) -> Self {
+        self.page_info = Some(page_info);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            limit,
+            page_info,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_info {
+            query_args.push(("page_info".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/admin/api/2020-10/users.json?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Plus::deprecated_202101_get_user`], set with a builder.
+pub struct PlusDeprecated202101GetUserRequest<'a> {
+    client: &'a Plus,
+    limit: Option<&'a str>,
+    page_info: Option<&'a str>,
+}
+
+impl<'a> PlusDeprecated202101GetUserRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `page_info` query parameter.
+    pub fn page_info(mut self, page_info: &'a str) -> Self {
+        self.page_info = Some(page_info);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            limit,
+            page_info,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_info {
+            query_args.push(("page_info".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/admin/api/2021-01/users.json?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Plus::deprecated_unstable_get_user`], set with a builder.
+pub struct PlusDeprecatedUnstableGetUserRequest<'a> {
+    client: &'a Plus,
+    limit: Option<&'a str>,
+    page_info: Option<&'a str>,
+}
+
+impl<'a> PlusDeprecatedUnstableGetUserRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `page_info` query parameter.
+    pub fn page_info(mut self, page_info: &'a str) -> Self {
+        self.page_info = Some(page_info);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            limit,
+            page_info,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_info {
+            query_args.push(("page_info".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/admin/api/unstable/users.json?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct Plus {
     pub client: Client,
 }
@@ -64,6 +1510,26 @@ impl Plus {
             )
             .await
     }
+    /**
+     * Retrieves a list of gift cards. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-01/gift_cards.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_get_gift_card`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/plus/giftcard#index-2020-01
+     */
+    pub fn deprecated_202001_get_gift_card_request(
+        &self,
+    ) -> PlusDeprecated202001GetGiftCardRequest<'_> {
+        PlusDeprecated202001GetGiftCardRequest {
+            client: self,
+            status: None,
+            limit: None,
+            since_id: None,
+            fields: None,
+        }
+    }
     /**
      * Creates a gift card.
      *
@@ -189,6 +1655,23 @@ impl Plus {
             )
             .await
     }
+    /**
+     * Retrieves a count of gift cards.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-01/gift_cards/count.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_get_gift_cards_count`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/plus/giftcard#count-2020-01
+     */
+    pub fn deprecated_202001_get_gift_cards_count_request(
+        &self,
+    ) -> PlusDeprecated202001GetGiftCardsCountRequest<'_> {
+        PlusDeprecated202001GetGiftCardsCountRequest {
+            client: self,
+            status: None,
+        }
+    }
     /**
      * Disables a gift card. Disabling a gift card can't be undone.
      *
@@ -284,6 +1767,37 @@ impl Plus {
             )
             .await
     }
+    /**
+    * Searches for gift cards that match a supplied query. The following fields are indexed by search:
+
+               created_at
+               updated_at
+               disabled_at
+               balance
+               initial_value
+               amount_spent
+               email
+               last_characters
+
+             Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+    *
+    * This function performs a `GET` to the `/admin/api/2020-01/gift_cards/search.json` endpoint.
+    *
+    * As opposed to `deprecated_202001_get_gift_cards_search`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+    *
+    * https://shopify.dev/docs/admin-api/rest/reference/plus/giftcard#search-2020-01
+    */
+    pub fn deprecated_202001_get_gift_cards_search_request(
+        &self,
+    ) -> PlusDeprecated202001GetGiftCardsSearchRequest<'_> {
+        PlusDeprecated202001GetGiftCardsSearchRequest {
+            client: self,
+            order: None,
+            query: None,
+            limit: None,
+            fields: None,
+        }
+    }
     /**
      * Retrieves a list of gift cards. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -337,6 +1851,26 @@ impl Plus {
             )
             .await
     }
+    /**
+     * Retrieves a list of gift cards. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-04/gift_cards.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_get_gift_card`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/plus/giftcard#index-2020-04
+     */
+    pub fn deprecated_202004_get_gift_card_request(
+        &self,
+    ) -> PlusDeprecated202004GetGiftCardRequest<'_> {
+        PlusDeprecated202004GetGiftCardRequest {
+            client: self,
+            status: None,
+            limit: None,
+            since_id: None,
+            fields: None,
+        }
+    }
     /**
      * Creates a gift card.
      *
@@ -462,6 +1996,23 @@ impl Plus {
             )
             .await
     }
+    /**
+     * Retrieves a count of gift cards.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-04/gift_cards/count.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_get_gift_cards_count`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/plus/giftcard#count-2020-04
+     */
+    pub fn deprecated_202004_get_gift_cards_count_request(
+        &self,
+    ) -> PlusDeprecated202004GetGiftCardsCountRequest<'_> {
+        PlusDeprecated202004GetGiftCardsCountRequest {
+            client: self,
+            status: None,
+        }
+    }
     /**
      * Disables a gift card. Disabling a gift card can't be undone.
      *
@@ -557,6 +2108,37 @@ impl Plus {
             )
             .await
     }
+    /**
+    * Searches for gift cards that match a supplied query. The following fields are indexed by search:
+
+               created_at
+               updated_at
+               disabled_at
+               balance
+               initial_value
+               amount_spent
+               email
+               last_characters
+
+             Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+    *
+    * This function performs a `GET` to the `/admin/api/2020-04/gift_cards/search.json` endpoint.
+    *
+    * As opposed to `deprecated_202004_get_gift_cards_search`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+    *
+    * https://shopify.dev/docs/admin-api/rest/reference/plus/giftcard#search-2020-04
+    */
+    pub fn deprecated_202004_get_gift_cards_search_request(
+        &self,
+    ) -> PlusDeprecated202004GetGiftCardsSearchRequest<'_> {
+        PlusDeprecated202004GetGiftCardsSearchRequest {
+            client: self,
+            order: None,
+            query: None,
+            limit: None,
+            fields: None,
+        }
+    }
     /**
      * Retrieves a list of gift cards. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -610,6 +2192,26 @@ impl Plus {
             )
             .await
     }
+    /**
+     * Retrieves a list of gift cards. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-07/gift_cards.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_get_gift_card`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/plus/giftcard#index-2020-07
+     */
+    pub fn deprecated_202007_get_gift_card_request(
+        &self,
+    ) -> PlusDeprecated202007GetGiftCardRequest<'_> {
+        PlusDeprecated202007GetGiftCardRequest {
+            client: self,
+            status: None,
+            limit: None,
+            since_id: None,
+            fields: None,
+        }
+    }
     /**
      * Creates a gift card.
      *
@@ -735,6 +2337,23 @@ impl Plus {
             )
             .await
     }
+    /**
+     * Retrieves a count of gift cards.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-07/gift_cards/count.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_get_gift_cards_count`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/plus/giftcard#count-2020-07
+     */
+    pub fn deprecated_202007_get_gift_cards_count_request(
+        &self,
+    ) -> PlusDeprecated202007GetGiftCardsCountRequest<'_> {
+        PlusDeprecated202007GetGiftCardsCountRequest {
+            client: self,
+            status: None,
+        }
+    }
     /**
      * Disables a gift card. Disabling a gift card can't be undone.
      *
@@ -830,6 +2449,37 @@ impl Plus {
             )
             .await
     }
+    /**
+    * Searches for gift cards that match a supplied query. The following fields are indexed by search:
+
+               created_at
+               updated_at
+               disabled_at
+               balance
+               initial_value
+               amount_spent
+               email
+               last_characters
+
+             Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+    *
+    * This function performs a `GET` to the `/admin/api/2020-07/gift_cards/search.json` endpoint.
+    *
+    * As opposed to `deprecated_202007_get_gift_cards_search`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+    *
+    * https://shopify.dev/docs/admin-api/rest/reference/plus/giftcard#search-2020-07
+    */
+    pub fn deprecated_202007_get_gift_cards_search_request(
+        &self,
+    ) -> PlusDeprecated202007GetGiftCardsSearchRequest<'_> {
+        PlusDeprecated202007GetGiftCardsSearchRequest {
+            client: self,
+            order: None,
+            query: None,
+            limit: None,
+            fields: None,
+        }
+    }
     /**
      * Retrieves a list of gift cards. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -883,6 +2533,24 @@ impl Plus {
             )
             .await
     }
+    /**
+     * Retrieves a list of gift cards. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-10/gift_cards.json` endpoint.
+     *
+     * As opposed to `get_gift_card`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/plus/giftcard#index-2020-10
+     */
+    pub fn get_gift_card_request(&self) -> PlusGetGiftCardRequest<'_> {
+        PlusGetGiftCardRequest {
+            client: self,
+            status: None,
+            limit: None,
+            since_id: None,
+            fields: None,
+        }
+    }
     /**
      * Creates a gift card.
      *
@@ -1005,6 +2673,21 @@ impl Plus {
             )
             .await
     }
+    /**
+     * Retrieves a count of gift cards.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-10/gift_cards/count.json` endpoint.
+     *
+     * As opposed to `get_gift_cards_count`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/plus/giftcard#count-2020-10
+     */
+    pub fn get_gift_cards_count_request(&self) -> PlusGetGiftCardsCountRequest<'_> {
+        PlusGetGiftCardsCountRequest {
+            client: self,
+            status: None,
+        }
+    }
     /**
      * Disables a gift card. Disabling a gift card can't be undone.
      *
@@ -1100,6 +2783,35 @@ impl Plus {
             )
             .await
     }
+    /**
+    * Searches for gift cards that match a supplied query. The following fields are indexed by search:
+
+               created_at
+               updated_at
+               disabled_at
+               balance
+               initial_value
+               amount_spent
+               email
+               last_characters
+
+             Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+    *
+    * This function performs a `GET` to the `/admin/api/2020-10/gift_cards/search.json` endpoint.
+    *
+    * As opposed to `get_gift_cards_search`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+    *
+    * https://shopify.dev/docs/admin-api/rest/reference/plus/giftcard#search-2020-10
+    */
+    pub fn get_gift_cards_search_request(&self) -> PlusGetGiftCardsSearchRequest<'_> {
+        PlusGetGiftCardsSearchRequest {
+            client: self,
+            order: None,
+            query: None,
+            limit: None,
+            fields: None,
+        }
+    }
     /**
      * Retrieves a list of gift cards. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -1153,6 +2865,26 @@ impl Plus {
             )
             .await
     }
+    /**
+     * Retrieves a list of gift cards. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2021-01/gift_cards.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_get_gift_card`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/plus/giftcard#index-2021-01
+     */
+    pub fn deprecated_202101_get_gift_card_request(
+        &self,
+    ) -> PlusDeprecated202101GetGiftCardRequest<'_> {
+        PlusDeprecated202101GetGiftCardRequest {
+            client: self,
+            status: None,
+            limit: None,
+            since_id: None,
+            fields: None,
+        }
+    }
     /**
      * Creates a gift card.
      *
@@ -1278,6 +3010,23 @@ impl Plus {
             )
             .await
     }
+    /**
+     * Retrieves a count of gift cards.
+     *
+     * This function performs a `GET` to the `/admin/api/2021-01/gift_cards/count.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_get_gift_cards_count`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/plus/giftcard#count-2021-01
+     */
+    pub fn deprecated_202101_get_gift_cards_count_request(
+        &self,
+    ) -> PlusDeprecated202101GetGiftCardsCountRequest<'_> {
+        PlusDeprecated202101GetGiftCardsCountRequest {
+            client: self,
+            status: None,
+        }
+    }
     /**
      * Disables a gift card. Disabling a gift card can't be undone.
      *
@@ -1373,6 +3122,37 @@ impl Plus {
             )
             .await
     }
+    /**
+    * Searches for gift cards that match a supplied query. The following fields are indexed by search:
+
+               created_at
+               updated_at
+               disabled_at
+               balance
+               initial_value
+               amount_spent
+               email
+               last_characters
+
+             Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+    *
+    * This function performs a `GET` to the `/admin/api/2021-01/gift_cards/search.json` endpoint.
+    *
+    * As opposed to `deprecated_202101_get_gift_cards_search`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+    *
+    * https://shopify.dev/docs/admin-api/rest/reference/plus/giftcard#search-2021-01
+    */
+    pub fn deprecated_202101_get_gift_cards_search_request(
+        &self,
+    ) -> PlusDeprecated202101GetGiftCardsSearchRequest<'_> {
+        PlusDeprecated202101GetGiftCardsSearchRequest {
+            client: self,
+            order: None,
+            query: None,
+            limit: None,
+            fields: None,
+        }
+    }
     /**
      * Retrieves a list of gift cards. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -1426,6 +3206,26 @@ impl Plus {
             )
             .await
     }
+    /**
+     * Retrieves a list of gift cards. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/unstable/gift_cards.json` endpoint.
+     *
+     * As opposed to `deprecated_unstable_get_gift_card`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/plus/giftcard#index-unstable
+     */
+    pub fn deprecated_unstable_get_gift_card_request(
+        &self,
+    ) -> PlusDeprecatedUnstableGetGiftCardRequest<'_> {
+        PlusDeprecatedUnstableGetGiftCardRequest {
+            client: self,
+            status: None,
+            limit: None,
+            since_id: None,
+            fields: None,
+        }
+    }
     /**
      * Creates a gift card.
      *
@@ -1551,6 +3351,23 @@ impl Plus {
             )
             .await
     }
+    /**
+     * Retrieves a count of gift cards.
+     *
+     * This function performs a `GET` to the `/admin/api/unstable/gift_cards/count.json` endpoint.
+     *
+     * As opposed to `deprecated_unstable_get_gift_cards_count`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/plus/giftcard#count-unstable
+     */
+    pub fn deprecated_unstable_get_gift_cards_count_request(
+        &self,
+    ) -> PlusDeprecatedUnstableGetGiftCardsCountRequest<'_> {
+        PlusDeprecatedUnstableGetGiftCardsCountRequest {
+            client: self,
+            status: None,
+        }
+    }
     /**
      * Disables a gift card. Disabling a gift card can't be undone.
      *
@@ -1646,6 +3463,37 @@ impl Plus {
             )
             .await
     }
+    /**
+    * Searches for gift cards that match a supplied query. The following fields are indexed by search:
+
+               created_at
+               updated_at
+               disabled_at
+               balance
+               initial_value
+               amount_spent
+               email
+               last_characters
+
+             Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+    *
+    * This function performs a `GET` to the `/admin/api/unstable/gift_cards/search.json` endpoint.
+    *
+    * As opposed to `deprecated_unstable_get_gift_cards_search`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+    *
+    * https://shopify.dev/docs/admin-api/rest/reference/plus/giftcard#search-unstable
+    */
+    pub fn deprecated_unstable_get_gift_cards_search_request(
+        &self,
+    ) -> PlusDeprecatedUnstableGetGiftCardsSearchRequest<'_> {
+        PlusDeprecatedUnstableGetGiftCardsSearchRequest {
+            client: self,
+            order: None,
+            query: None,
+            limit: None,
+            fields: None,
+        }
+    }
     /**
      * Retrieves a list of all users. Note: As of version 2021-01, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -1685,6 +3533,22 @@ impl Plus {
             )
             .await
     }
+    /**
+     * Retrieves a list of all users. Note: As of version 2021-01, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-01/users.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_get_user`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/plus/user#index-2020-01
+     */
+    pub fn deprecated_202001_get_user_request(&self) -> PlusDeprecated202001GetUserRequest<'_> {
+        PlusDeprecated202001GetUserRequest {
+            client: self,
+            limit: None,
+            page_info: None,
+        }
+    }
     /**
      * Retrieves a single user.
      *
@@ -1777,6 +3641,22 @@ impl Plus {
             )
             .await
     }
+    /**
+     * Retrieves a list of all users. Note: As of version 2021-01, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-04/users.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_get_user`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/plus/user#index-2020-04
+     */
+    pub fn deprecated_202004_get_user_request(&self) -> PlusDeprecated202004GetUserRequest<'_> {
+        PlusDeprecated202004GetUserRequest {
+            client: self,
+            limit: None,
+            page_info: None,
+        }
+    }
     /**
      * Retrieves a single user.
      *
@@ -1869,6 +3749,22 @@ impl Plus {
             )
             .await
     }
+    /**
+     * Retrieves a list of all users. Note: As of version 2021-01, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-07/users.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_get_user`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/plus/user#index-2020-07
+     */
+    pub fn deprecated_202007_get_user_request(&self) -> PlusDeprecated202007GetUserRequest<'_> {
+        PlusDeprecated202007GetUserRequest {
+            client: self,
+            limit: None,
+            page_info: None,
+        }
+    }
     /**
      * Retrieves a single user.
      *
@@ -1961,6 +3857,22 @@ impl Plus {
             )
             .await
     }
+    /**
+     * Retrieves a list of all users. Note: As of version 2021-01, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-10/users.json` endpoint.
+     *
+     * As opposed to `get_user`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/plus/user#index-2020-10
+     */
+    pub fn get_user_request(&self) -> PlusGetUserRequest<'_> {
+        PlusGetUserRequest {
+            client: self,
+            limit: None,
+            page_info: None,
+        }
+    }
     /**
      * Retrieves a single user.
      *
@@ -2050,6 +3962,22 @@ impl Plus {
             )
             .await
     }
+    /**
+     * Retrieves a list of all users. Note: As of version 2021-01, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2021-01/users.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_get_user`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/plus/user#index-2021-01
+     */
+    pub fn deprecated_202101_get_user_request(&self) -> PlusDeprecated202101GetUserRequest<'_> {
+        PlusDeprecated202101GetUserRequest {
+            client: self,
+            limit: None,
+            page_info: None,
+        }
+    }
     /**
      * Retrieves a single user.
      *
@@ -2142,6 +4070,22 @@ impl Plus {
             )
             .await
     }
+    /**
+     * Retrieves a list of all users. Note: As of version 2021-01, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/unstable/users.json` endpoint.
+     *
+     * As opposed to `deprecated_unstable_get_user`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/plus/user#index-unstable
+     */
+    pub fn deprecated_unstable_get_user_request(&self) -> PlusDeprecatedUnstableGetUserRequest<'_> {
+        PlusDeprecatedUnstableGetUserRequest {
+            client: self,
+            limit: None,
+            page_info: None,
+        }
+    }
     /**
      * Retrieves a single user.
      *
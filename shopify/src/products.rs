@@ -1,6 +1,9177 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Products::deprecated_202001_get_collect`], set with a builder.
+pub struct ProductsDeprecated202001GetCollectRequest<'a> {
+    client: &'a Products,
+    limit: Option<&'a str>,
+    since_id: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> ProductsDeprecated202001GetCollectRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            limit,
+            since_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-01/collects.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Products::deprecated_202001_get_collects_param_collect`], set with a builder.
+pub struct ProductsDeprecated202001GetCollectsParamCollectRequest<'a> {
+    client: &'a Products,
+    collect_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> ProductsDeprecated202001GetCollectsParamCollectRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            collect_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-01/collects/{}/json?{}",
+                crate::progenitor_support::encode_path(collect_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Products::deprecated_202001_get_collects_count`], set with a builder.
+pub struct ProductsDeprecated202001GetCollectsCountRequest<'a> {
+    client: &'a Products,
+    collection_id: Option<i64>,
+}
+
+impl<'a> ProductsDeprecated202001GetCollectsCountRequest<'a> {
+    /// Sets the `collection_id` query parameter.
+    pub fn collection_id(mut self, collection_id: i64) -> Self {
+        self.collection_id = Some(collection_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            collection_id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = collection_id {
+            query_args.push(("collection_id".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-01/collects/count.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Products::deprecated_202004_get_collect`], set with a builder.
+pub struct ProductsDeprecated202004GetCollectRequest<'a> {
+    client: &'a Products,
+    limit: Option<&'a str>,
+    since_id: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> ProductsDeprecated202004GetCollectRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            limit,
+            since_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-04/collects.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Products::deprecated_202004_get_collects_param_collect`], set with a builder.
+pub struct ProductsDeprecated202004GetCollectsParamCollectRequest<'a> {
+    client: &'a Products,
+    collect_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> ProductsDeprecated202004GetCollectsParamCollectRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            collect_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-04/collects/{}/json?{}",
+                crate::progenitor_support::encode_path(collect_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Products::deprecated_202004_get_collects_count`], set with a builder.
+pub struct ProductsDeprecated202004GetCollectsCountRequest<'a> {
+    client: &'a Products,
+    collection_id: Option<i64>,
+}
+
+impl<'a> ProductsDeprecated202004GetCollectsCountRequest<'a> {
+    /// Sets the `collection_id` query parameter.
+    pub fn collection_id(mut self, collection_id: i64) -> Self {
+        self.collection_id = Some(collection_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            collection_id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = collection_id {
+            query_args.push(("collection_id".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-04/collects/count.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Products::deprecated_202007_get_collect`], set with a builder.
+pub struct ProductsDeprecated202007GetCollectRequest<'a> {
+    client: &'a Products,
+    limit: Option<&'a str>,
+    since_id: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> ProductsDeprecated202007GetCollectRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            limit,
+            since_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-07/collects.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Products::deprecated_202007_get_collects_param_collect`], set with a builder.
+pub struct ProductsDeprecated202007GetCollectsParamCollectRequest<'a> {
+    client: &'a Products,
+    collect_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> ProductsDeprecated202007GetCollectsParamCollectRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            collect_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-07/collects/{}/json?{}",
+                crate::progenitor_support::encode_path(collect_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Products::deprecated_202007_get_collects_count`], set with a builder.
+pub struct ProductsDeprecated202007GetCollectsCountRequest<'a> {
+    client: &'a Products,
+    collection_id: Option<i64>,
+}
+
+impl<'a> ProductsDeprecated202007GetCollectsCountRequest<'a> {
+    /// Sets the `collection_id` query parameter.
+    pub fn collection_id(mut self, collection_id: i64) -> Self {
+        self.collection_id = Some(collection_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            collection_id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = collection_id {
+            query_args.push(("collection_id".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-07/collects/count.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Products::get_collect`], set with a builder.
+pub struct ProductsGetCollectRequest<'a> {
+    client: &'a Products,
+    limit: Option<&'a str>,
+    since_id: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> ProductsGetCollectRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            limit,
+            since_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-10/collects.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Products::get_collects_param_collect`], set with a builder.
+pub struct ProductsGetCollectsParamCollectRequest<'a> {
+    client: &'a Products,
+    collect_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> ProductsGetCollectsParamCollectRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            collect_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-10/collects/{}/json?{}",
+                crate::progenitor_support::encode_path(collect_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Products::get_collects_count`], set with a builder.
+pub struct ProductsGetCollectsCountRequest<'a> {
+    client: &'a Products,
+    collection_id: Option<i64>,
+}
+
+impl<'a> ProductsGetCollectsCountRequest<'a> {
+    /// Sets the `collection_id` query parameter.
+    pub fn collection_id(mut self, collection_id: i64) -> Self {
+        self.collection_id = Some(collection_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            collection_id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = collection_id {
+            query_args.push(("collection_id".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-10/collects/count.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Products::deprecated_202101_get_collect`], set with a builder.
+pub struct ProductsDeprecated202101GetCollectRequest<'a> {
+    client: &'a Products,
+    limit: Option<&'a str>,
+    since_id: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> ProductsDeprecated202101GetCollectRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            limit,
+            since_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2021-01/collects.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Products::deprecated_202101_get_collects_param_collect`], set with a builder.
+pub struct ProductsDeprecated202101GetCollectsParamCollectRequest<'a> {
+    client: &'a Products,
+    collect_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> ProductsDeprecated202101GetCollectsParamCollectRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            collect_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2021-01/collects/{}/json?{}",
+                crate::progenitor_support::encode_path(collect_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Products::deprecated_202101_get_collects_count`], set with a builder.
+pub struct ProductsDeprecated202101GetCollectsCountRequest<'a> {
+    client: &'a Products,
+    collection_id: Option<i64>,
+}
+
+impl<'a> ProductsDeprecated202101GetCollectsCountRequest<'a> {
+    /// Sets the `collection_id` query parameter.
+    pub fn collection_id(mut self, collection_id: i64) -> Self {
+        self.collection_id = Some(collection_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            collection_id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = collection_id {
+            query_args.push(("collection_id".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2021-01/collects/count.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Products::deprecated_unstable_get_collect`], set with a builder.
+pub struct ProductsDeprecatedUnstableGetCollectRequest<'a> {
+    client: &'a Products,
+    limit: Option<&'a str>,
+    since_id: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> ProductsDeprecatedUnstableGetCollectRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            limit,
+            since_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/unstable/collects.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Products::deprecated_unstable_get_collects_param_collect`], set with a builder.
+pub struct ProductsDeprecatedUnstableGetCollectsParamCollectRequest<'a> {
+    client: &'a Products,
+    collect_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> ProductsDeprecatedUnstableGetCollectsParamCollectRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            collect_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/unstable/collects/{}/json?{}",
+                crate::progenitor_support::encode_path(collect_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Products::deprecated_unstable_get_collects_count`], set with a builder.
+pub struct ProductsDeprecatedUnstableGetCollectsCountRequest<'a> {
+    client: &'a Products,
+    collection_id: Option<i64>,
+}
+
+impl<'a> ProductsDeprecatedUnstableGetCollectsCountRequest<'a> {
+    /// Sets the `collection_id` query parameter.
+    pub fn collection_id(mut self, collection_id: i64) -> Self {
+        self.collection_id = Some(collection_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            collection_id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = collection_id {
+            query_args.push(("collection_id".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/unstable/collects/count.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Products::deprecated_202001_get_collections_param_collection`], set with a builder.
+pub struct ProductsDeprecated202001GetCollectionsParamCollectionRequest<'a> {
+    client: &'a Products,
+    collection_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> ProductsDeprecated202001GetCollectionsParamCollectionRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            collection_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-01/collections/{}/json?{}",
+                crate::progenitor_support::encode_path(collection_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Products::deprecated_202001_get_collections_param_collection_products`], set with a builder.
+pub struct ProductsDeprecated202001GetCollectionsParamCollectionProductsRequest<'a> {
+    client: &'a Products,
+    collection_id: &'a str,
+    limit: Option<&'a str>,
+}
+
+impl<'a> ProductsDeprecated202001GetCollectionsParamCollectionProductsRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            collection_id,
+            limit,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-01/collections/{}/products.json?{}",
+                crate::progenitor_support::encode_path(collection_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Products::deprecated_202004_get_collections_param_collection`], set with a builder.
+pub struct ProductsDeprecated202004GetCollectionsParamCollectionRequest<'a> {
+    client: &'a Products,
+    collection_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> ProductsDeprecated202004GetCollectionsParamCollectionRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            collection_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-04/collections/{}/json?{}",
+                crate::progenitor_support::encode_path(collection_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Products::deprecated_202004_get_collections_param_collection_products`], set with a builder.
+pub struct ProductsDeprecated202004GetCollectionsParamCollectionProductsRequest<'a> {
+    client: &'a Products,
+    collection_id: &'a str,
+    limit: Option<&'a str>,
+}
+
+impl<'a> ProductsDeprecated202004GetCollectionsParamCollectionProductsRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            collection_id,
+            limit,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-04/collections/{}/products.json?{}",
+                crate::progenitor_support::encode_path(collection_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Products::deprecated_202007_get_collections_param_collection`], set with a builder.
+pub struct ProductsDeprecated202007GetCollectionsParamCollectionRequest<'a> {
+    client: &'a Products,
+    collection_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> ProductsDeprecated202007GetCollectionsParamCollectionRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            collection_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-07/collections/{}/json?{}",
+                crate::progenitor_support::encode_path(collection_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Products::deprecated_202007_get_collections_param_collection_products`], set with a builder.
+pub struct ProductsDeprecated202007GetCollectionsParamCollectionProductsRequest<'a> {
+    client: &'a Products,
+    collection_id: &'a str,
+    limit: Option<&'a str>,
+}
+
+impl<'a> ProductsDeprecated202007GetCollectionsParamCollectionProductsRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            collection_id,
+            limit,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-07/collections/{}/products.json?{}",
+                crate::progenitor_support::encode_path(collection_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Products::get_collections_param_collection`], set with a builder.
+pub struct ProductsGetCollectionsParamCollectionRequest<'a> {
+    client: &'a Products,
+    collection_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> ProductsGetCollectionsParamCollectionRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            collection_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-10/collections/{}/json?{}",
+                crate::progenitor_support::encode_path(collection_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Products::get_collections_param_collection_products`], set with a builder.
+pub struct ProductsGetCollectionsParamCollectionProductsRequest<'a> {
+    client: &'a Products,
+    collection_id: &'a str,
+    limit: Option<&'a str>,
+}
+
+impl<'a> ProductsGetCollectionsParamCollectionProductsRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            collection_id,
+            limit,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-10/collections/{}/products.json?{}",
+                crate::progenitor_support::encode_path(collection_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Products::deprecated_202101_get_collections_param_collection`], set with a builder.
+pub struct ProductsDeprecated202101GetCollectionsParamCollectionRequest<'a> {
+    client: &'a Products,
+    collection_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> ProductsDeprecated202101GetCollectionsParamCollectionRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            collection_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2021-01/collections/{}/json?{}",
+                crate::progenitor_support::encode_path(collection_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Products::deprecated_202101_get_collections_param_collection_products`], set with a builder.
+pub struct ProductsDeprecated202101GetCollectionsParamCollectionProductsRequest<'a> {
+    client: &'a Products,
+    collection_id: &'a str,
+    limit: Option<&'a str>,
+}
+
+impl<'a> ProductsDeprecated202101GetCollectionsParamCollectionProductsRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            collection_id,
+            limit,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2021-01/collections/{}/products.json?{}",
+                crate::progenitor_support::encode_path(collection_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Products::deprecated_unstable_get_collections_param_collection`], set with a builder.
+pub struct ProductsDeprecatedUnstableGetCollectionsParamCollectionRequest<'a> {
+    client: &'a Products,
+    collection_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> ProductsDeprecatedUnstableGetCollectionsParamCollectionRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            collection_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/unstable/collections/{}/json?{}",
+                crate::progenitor_support::encode_path(collection_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Products::deprecated_unstable_get_collections_param_collection_products`], set with a builder.
+pub struct ProductsDeprecatedUnstableGetCollectionsParamCollectionProductsRequest<'a> {
+    client: &'a Products,
+    collection_id: &'a str,
+    limit: Option<&'a str>,
+}
+
+impl<'a> ProductsDeprecatedUnstableGetCollectionsParamCollectionProductsRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            collection_id,
+            limit,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/unstable/collections/{}/products.json?{}",
+                crate::progenitor_support::encode_path(collection_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Products::deprecated_202001_get_custom_collection`], set with a builder.
+pub struct ProductsDeprecated202001GetCustomCollectionRequest<'a> {
+    client: &'a Products,
+    limit: Option<&'a str>,
+    ids: Option<&'a str>,
+    since_id: Option<&'a str>,
+    title: Option<&'a str>,
+    product_id: Option<&'a str>,
+    handle: Option<&'a str>,
+    updated_at_min: Option<&'a str>,
+    updated_at_max: Option<&'a str>,
+    published_at_min: Option<&'a str>,
+    published_at_max: Option<&'a str>,
+    published_status: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> ProductsDeprecated202001GetCustomCollectionRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `ids` query parameter.
+    pub fn ids(mut self, ids: &'a str) -> Self {
+        self.ids = Some(ids);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `title` query parameter.
+    pub fn title(mut self, title: &'a str) -> Self {
+        self.title = Some(title);
+        self
+    }
+
+    /// Sets the `product_id` query parameter.
+    pub fn product_id(mut self, product_id: &'a str) -> Self {
+        self.product_id = Some(product_id);
+        self
+    }
+
+    /// Sets the `handle` query parameter.
+    pub fn handle(mut self, handle: &'a str) -> Self {
+        self.handle = Some(handle);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.updated_at_min = Some(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.updated_at_max = Some(updated_at_max);
+        self
+    }
+
+    /// Sets the `published_at_min` query parameter.
+    pub fn published_at_min(mut self, published_at_min: &'a str) -> Self {
+        self.published_at_min = Some(published_at_min);
+        self
+    }
+
+    /// Sets the `published_at_max` query parameter.
+    pub fn published_at_max(mut self, published_at_max: &'a str) -> Self {
+        self.published_at_max = Some(published_at_max);
+        self
+    }
+
+    /// Sets the `published_status` query parameter.
+    pub fn published_status(mut self, published_status: &'a str) -> Self {
+        self.published_status = Some(published_status);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            limit,
+            ids,
+            since_id,
+            title,
+            product_id,
+            handle,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            published_status,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = handle {
+            query_args.push(("handle".to_string(), v.to_string()));
+        }
+        if let Some(v) = ids {
+            query_args.push(("ids".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = product_id {
+            query_args.push(("product_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_at_max {
+            query_args.push(("published_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_at_min {
+            query_args.push(("published_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_status {
+            query_args.push(("published_status".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = title {
+            query_args.push(("title".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_max {
+            query_args.push(("updated_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_min {
+            query_args.push(("updated_at_min".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-01/custom_collections.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Products::deprecated_202001_get_custom_collections_count`], set with a builder.
+pub struct ProductsDeprecated202001GetCustomCollectionsCountRequest<'a> {
+    client: &'a Products,
+    title: Option<&'a str>,
+    product_id: Option<&'a str>,
+    updated_at_min: Option<&'a str>,
+    updated_at_max: Option<&'a str>,
+    published_at_min: Option<&'a str>,
+    published_at_max: Option<&'a str>,
+    published_status: Option<&'a str>,
+}
+
+impl<'a> ProductsDeprecated202001GetCustomCollectionsCountRequest<'a> {
+    /// Sets the `title` query parameter.
+    pub fn title(mut self, title: &'a str) -> Self {
+        self.title = Some(title);
+        self
+    }
+
+    /// Sets the `product_id` query parameter.
+    pub fn product_id(mut self, product_id: &'a str) -> Self {
+        self.product_id = Some(product_id);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.updated_at_min = Some(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.updated_at_max = Some(updated_at_max);
+        self
+    }
+
+    /// Sets the `published_at_min` query parameter.
+    pub fn published_at_min(mut self, published_at_min: &'a str) -> Self {
+        self.published_at_min = Some(published_at_min);
+        self
+    }
+
+    /// Sets the `published_at_max` query parameter.
+    pub fn published_at_max(mut self, published_at_max: &'a str) -> Self {
+        self.published_at_max = Some(published_at_max);
+        self
+    }
+
+    /// Sets the `published_status` query parameter.
+    pub fn published_status(mut self, published_status: &'a str) -> Self {
+        self.published_status = Some(published_status);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            title,
+            product_id,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            published_status,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = product_id {
+            query_args.push(("product_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_at_max {
+            query_args.push(("published_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_at_min {
+            query_args.push(("published_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_status {
+            query_args.push(("published_status".to_string(), v.to_string()));
+        }
+        if let Some(v) = title {
+            query_args.push(("title".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_max {
+            query_args.push(("updated_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_min {
+            query_args.push(("updated_at_min".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-01/custom_collections/count.json?{}",
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Products::deprecated_202001_get_custom_collections_param_collection`], set with a builder.
+pub struct ProductsDeprecated202001GetCustomCollectionsParamCollectionRequest<'a> {
+    client: &'a Products,
+    custom_collection_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> ProductsDeprecated202001GetCustomCollectionsParamCollectionRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            custom_collection_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-01/custom_collections/{}/json?{}",
+                crate::progenitor_support::encode_path(custom_collection_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Products::deprecated_202004_get_custom_collection`], set with a builder.
+pub struct ProductsDeprecated202004GetCustomCollectionRequest<'a> {
+    client: &'a Products,
+    limit: Option<&'a str>,
+    ids: Option<&'a str>,
+    since_id: Option<&'a str>,
+    title: Option<&'a str>,
+    product_id: Option<&'a str>,
+    handle: Option<&'a str>,
+    updated_at_min: Option<&'a str>,
+    updated_at_max: Option<&'a str>,
+    published_at_min: Option<&'a str>,
+    published_at_max: Option<&'a str>,
+    published_status: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> ProductsDeprecated202004GetCustomCollectionRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `ids` query parameter.
+    pub fn ids(mut self, ids: &'a str) -> Self {
+        self.ids = Some(ids);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `title` query parameter.
+    pub fn title(mut self, title: &'a str) -> Self {
+        self.title = Some(title);
+        self
+    }
+
+    /// Sets the `product_id` query parameter.
+    pub fn product_id(mut self, product_id: &'a str) -> Self {
+        self.product_id = Some(product_id);
+        self
+    }
+
+    /// Sets the `handle` query parameter.
+    pub fn handle(mut self, handle: &'a str) -> Self {
+        self.handle = Some(handle);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.updated_at_min = Some(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.updated_at_max = Some(updated_at_max);
+        self
+    }
+
+    /// Sets the `published_at_min` query parameter.
+    pub fn published_at_min(mut self, published_at_min: &'a str) -> Self {
+        self.published_at_min = Some(published_at_min);
+        self
+    }
+
+    /// Sets the `published_at_max` query parameter.
+    pub fn published_at_max(mut self, published_at_max: &'a str) -> Self {
+        self.published_at_max = Some(published_at_max);
+        self
+    }
+
+    /// Sets the `published_status` query parameter.
+    pub fn published_status(mut self, published_status: &'a str) -> Self {
+        self.published_status = Some(published_status);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            limit,
+            ids,
+            since_id,
+            title,
+            product_id,
+            handle,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            published_status,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = handle {
+            query_args.push(("handle".to_string(), v.to_string()));
+        }
+        if let Some(v) = ids {
+            query_args.push(("ids".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = product_id {
+            query_args.push(("product_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_at_max {
+            query_args.push(("published_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_at_min {
+            query_args.push(("published_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_status {
+            query_args.push(("published_status".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = title {
+            query_args.push(("title".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_max {
+            query_args.push(("updated_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_min {
+            query_args.push(("updated_at_min".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-04/custom_collections.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Products::deprecated_202004_get_custom_collections_count`], set with a builder.
+pub struct ProductsDeprecated202004GetCustomCollectionsCountRequest<'a> {
+    client: &'a Products,
+    title: Option<&'a str>,
+    product_id: Option<&'a str>,
+    updated_at_min: Option<&'a str>,
+    updated_at_max: Option<&'a str>,
+    published_at_min: Option<&'a str>,
+    published_at_max: Option<&'a str>,
+    published_status: Option<&'a str>,
+}
+
+impl<'a> ProductsDeprecated202004GetCustomCollectionsCountRequest<'a> {
+    /// Sets the `title` query parameter.
+    pub fn title(mut self, title: &'a str) -> Self {
+        self.title = Some(title);
+        self
+    }
+
+    /// Sets the `product_id` query parameter.
+    pub fn product_id(mut self, product_id: &'a str) -> Self {
+        self.product_id = Some(product_id);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.updated_at_min = Some(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.updated_at_max = Some(updated_at_max);
+        self
+    }
+
+    /// Sets the `published_at_min` query parameter.
+    pub fn published_at_min(mut self, published_at_min: &'a str) -> Self {
+        self.published_at_min = Some(published_at_min);
+        self
+    }
+
+    /// Sets the `published_at_max` query parameter.
+    pub fn published_at_max(mut self, published_at_max: &'a str) -> Self {
+        self.published_at_max = Some(published_at_max);
+        self
+    }
+
+    /// Sets the `published_status` query parameter.
+    pub fn published_status(mut self, published_status: &'a str) -> Self {
+        self.published_status = Some(published_status);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            title,
+            product_id,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            published_status,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = product_id {
+            query_args.push(("product_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_at_max {
+            query_args.push(("published_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_at_min {
+            query_args.push(("published_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_status {
+            query_args.push(("published_status".to_string(), v.to_string()));
+        }
+        if let Some(v) = title {
+            query_args.push(("title".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_max {
+            query_args.push(("updated_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_min {
+            query_args.push(("updated_at_min".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-04/custom_collections/count.json?{}",
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Products::deprecated_202004_get_custom_collections_param_collection`], set with a builder.
+pub struct ProductsDeprecated202004GetCustomCollectionsParamCollectionRequest<'a> {
+    client: &'a Products,
+    custom_collection_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> ProductsDeprecated202004GetCustomCollectionsParamCollectionRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            custom_collection_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-04/custom_collections/{}/json?{}",
+                crate::progenitor_support::encode_path(custom_collection_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Products::deprecated_202007_get_custom_collection`], set with a builder.
+pub struct ProductsDeprecated202007GetCustomCollectionRequest<'a> {
+    client: &'a Products,
+    limit: Option<&'a str>,
+    ids: Option<&'a str>,
+    since_id: Option<&'a str>,
+    title: Option<&'a str>,
+    product_id: Option<&'a str>,
+    handle: Option<&'a str>,
+    updated_at_min: Option<&'a str>,
+    updated_at_max: Option<&'a str>,
+    published_at_min: Option<&'a str>,
+    published_at_max: Option<&'a str>,
+    published_status: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> ProductsDeprecated202007GetCustomCollectionRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `ids` query parameter.
+    pub fn ids(mut self, ids: &'a str) -> Self {
+        self.ids = Some(ids);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `title` query parameter.
+    pub fn title(mut self, title: &'a str) -> Self {
+        self.title = Some(title);
+        self
+    }
+
+    /// Sets the `product_id` query parameter.
+    pub fn product_id(mut self, product_id: &'a str) -> Self {
+        self.product_id = Some(product_id);
+        self
+    }
+
+    /// Sets the `handle` query parameter.
+    pub fn handle(mut self, handle: &'a str) -> Self {
+        self.handle = Some(handle);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.updated_at_min = Some(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.updated_at_max = Some(updated_at_max);
+        self
+    }
+
+    /// Sets the `published_at_min` query parameter.
+    pub fn published_at_min(mut self, published_at_min: &'a str) -> Self {
+        self.published_at_min = Some(published_at_min);
+        self
+    }
+
+    /// Sets the `published_at_max` query parameter.
+    pub fn published_at_max(mut self, published_at_max: &'a str) -> Self {
+        self.published_at_max = Some(published_at_max);
+        self
+    }
+
+    /// Sets the `published_status` query parameter.
+    pub fn published_status(mut self, published_status: &'a str) -> Self {
+        self.published_status = Some(published_status);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            limit,
+            ids,
+            since_id,
+            title,
+            product_id,
+            handle,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            published_status,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = handle {
+            query_args.push(("handle".to_string(), v.to_string()));
+        }
+        if let Some(v) = ids {
+            query_args.push(("ids".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = product_id {
+            query_args.push(("product_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_at_max {
+            query_args.push(("published_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_at_min {
+            query_args.push(("published_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_status {
+            query_args.push(("published_status".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = title {
+            query_args.push(("title".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_max {
+            query_args.push(("updated_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_min {
+            query_args.push(("updated_at_min".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-07/custom_collections.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Products::deprecated_202007_get_custom_collections_count`], set with a builder.
+pub struct ProductsDeprecated202007GetCustomCollectionsCountRequest<'a> {
+    client: &'a Products,
+    title: Option<&'a str>,
+    product_id: Option<&'a str>,
+    updated_at_min: Option<&'a str>,
+    updated_at_max: Option<&'a str>,
+    published_at_min: Option<&'a str>,
+    published_at_max: Option<&'a str>,
+    published_status: Option<&'a str>,
+}
+
+impl<'a> ProductsDeprecated202007GetCustomCollectionsCountRequest<'a> {
+    /// Sets the `title` query parameter.
+    pub fn title(mut self, title: &'a str) -> Self {
+        self.title = Some(title);
+        self
+    }
+
+    /// Sets the `product_id` query parameter.
+    pub fn product_id(mut self, product_id: &'a str) -> Self {
+        self.product_id = Some(product_id);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.updated_at_min = Some(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.updated_at_max = Some(updated_at_max);
+        self
+    }
+
+    /// Sets the `published_at_min` query parameter.
+    pub fn published_at_min(mut self, published_at_min: &'a str) -> Self {
+        self.published_at_min = Some(published_at_min);
+        self
+    }
+
+    /// Sets the `published_at_max` query parameter.
+    pub fn published_at_max(mut self, published_at_max: &'a str) -> Self {
+        self.published_at_max = Some(published_at_max);
+        self
+    }
+
+    /// Sets the `published_status` query parameter.
+    pub fn published_status(mut self, published_status: &'a str) -> Self {
+        self.published_status = Some(published_status);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            title,
+            product_id,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            published_status,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = product_id {
+            query_args.push(("product_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_at_max {
+            query_args.push(("published_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_at_min {
+            query_args.push(("published_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_status {
+            query_args.push(("published_status".to_string(), v.to_string()));
+        }
+        if let Some(v) = title {
+            query_args.push(("title".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_max {
+            query_args.push(("updated_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_min {
+            query_args.push(("updated_at_min".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-07/custom_collections/count.json?{}",
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Products::deprecated_202007_get_custom_collections_param_collection`], set with a builder.
+pub struct ProductsDeprecated202007GetCustomCollectionsParamCollectionRequest<'a> {
+    client: &'a Products,
+    custom_collection_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> ProductsDeprecated202007GetCustomCollectionsParamCollectionRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            custom_collection_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-07/custom_collections/{}/json?{}",
+                crate::progenitor_support::encode_path(custom_collection_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Products::get_custom_collection`], set with a builder.
+pub struct ProductsGetCustomCollectionRequest<'a> {
+    client: &'a Products,
+    limit: Option<&'a str>,
+    ids: Option<&'a str>,
+    since_id: Option<&'a str>,
+    title: Option<&'a str>,
+    product_id: Option<&'a str>,
+    handle: Option<&'a str>,
+    updated_at_min: Option<&'a str>,
+    updated_at_max: Option<&'a str>,
+    published_at_min: Option<&'a str>,
+    published_at_max: Option<&'a str>,
+    published_status: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> ProductsGetCustomCollectionRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `ids` query parameter.
+    pub fn ids(mut self, ids: &'a str) -> Self {
+        self.ids = Some(ids);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `title` query parameter.
+    pub fn title(mut self, title: &'a str) -> Self {
+        self.title = Some(title);
+        self
+    }
+
+    /// Sets the `product_id` query parameter.
+    pub fn product_id(mut self, product_id: &'a str) -> Self {
+        self.product_id = Some(product_id);
+        self
+    }
+
+    /// Sets the `handle` query parameter.
+    pub fn handle(mut self, handle: &'a str) -> Self {
+        self.handle = Some(handle);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.updated_at_min = Some(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.updated_at_max = Some(updated_at_max);
+        self
+    }
+
+    /// Sets the `published_at_min` query parameter.
+    pub fn published_at_min(mut self, published_at_min: &'a str) -> Self {
+        self.published_at_min = Some(published_at_min);
+        self
+    }
+
+    /// Sets the `published_at_max` query parameter.
+    pub fn published_at_max(mut self, published_at_max: &'a str) -> Self {
+        self.published_at_max = Some(published_at_max);
+        self
+    }
+
+    /// Sets the `published_status` query parameter.
+    pub fn published_status(mut self, published_status: &'a str) -> Self {
+        self.published_status = Some(published_status);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            limit,
+            ids,
+            since_id,
+            title,
+            product_id,
+            handle,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            published_status,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = handle {
+            query_args.push(("handle".to_string(), v.to_string()));
+        }
+        if let Some(v) = ids {
+            query_args.push(("ids".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = product_id {
+            query_args.push(("product_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_at_max {
+            query_args.push(("published_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_at_min {
+            query_args.push(("published_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_status {
+            query_args.push(("published_status".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = title {
+            query_args.push(("title".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_max {
+            query_args.push(("updated_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_min {
+            query_args.push(("updated_at_min".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-10/custom_collections.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Products::get_custom_collections_count`], set with a builder.
+pub struct ProductsGetCustomCollectionsCountRequest<'a> {
+    client: &'a Products,
+    title: Option<&'a str>,
+    product_id: Option<&'a str>,
+    updated_at_min: Option<&'a str>,
+    updated_at_max: Option<&'a str>,
+    published_at_min: Option<&'a str>,
+    published_at_max: Option<&'a str>,
+    published_status: Option<&'a str>,
+}
+
+impl<'a> ProductsGetCustomCollectionsCountRequest<'a> {
+    /// Sets the `title` query parameter.
+    pub fn title(mut self, title: &'a str) -> Self {
+        self.title = Some(title);
+        self
+    }
+
+    /// Sets the `product_id` query parameter.
+    pub fn product_id(mut self, product_id: &'a str) -> Self {
+        self.product_id = Some(product_id);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.updated_at_min = Some(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.updated_at_max = Some(updated_at_max);
+        self
+    }
+
+    /// Sets the `published_at_min` query parameter.
+    pub fn published_at_min(mut self, published_at_min: &'a str) -> Self {
+        self.published_at_min = Some(published_at_min);
+        self
+    }
+
+    /// Sets the `published_at_max` query parameter.
+    pub fn published_at_max(mut self, published_at_max: &'a str) -> Self {
+        self.published_at_max = Some(published_at_max);
+        self
+    }
+
+    /// Sets the `published_status` query parameter.
+    pub fn published_status(mut self, published_status: &'a str) -> Self {
+        self.published_status = Some(published_status);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            title,
+            product_id,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            published_status,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = product_id {
+            query_args.push(("product_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_at_max {
+            query_args.push(("published_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_at_min {
+            query_args.push(("published_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_status {
+            query_args.push(("published_status".to_string(), v.to_string()));
+        }
+        if let Some(v) = title {
+            query_args.push(("title".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_max {
+            query_args.push(("updated_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_min {
+            query_args.push(("updated_at_min".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-10/custom_collections/count.json?{}",
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Products::get_custom_collections_param_collection`], set with a builder.
+pub struct ProductsGetCustomCollectionsParamCollectionRequest<'a> {
+    client: &'a Products,
+    custom_collection_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> ProductsGetCustomCollectionsParamCollectionRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            custom_collection_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-10/custom_collections/{}/json?{}",
+                crate::progenitor_support::encode_path(custom_collection_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Products::deprecated_202101_get_custom_collection`], set with a builder.
+pub struct ProductsDeprecated202101GetCustomCollectionRequest<'a> {
+    client: &'a Products,
+    limit: Option<&'a str>,
+    ids: Option<&'a str>,
+    since_id: Option<&'a str>,
+    title: Option<&'a str>,
+    product_id: Option<&'a str>,
+    handle: Option<&'a str>,
+    updated_at_min: Option<&'a str>,
+    updated_at_max: Option<&'a str>,
+    published_at_min: Option<&'a str>,
+    published_at_max: Option<&'a str>,
+    published_status: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> ProductsDeprecated202101GetCustomCollectionRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `ids` query parameter.
+    pub fn ids(mut self, ids: &'a str) -> Self {
+        self.ids = Some(ids);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `title` query parameter.
+    pub fn title(mut self, title: &'a str) -> Self {
+        self.title = Some(title);
+        self
+    }
+
+    /// Sets the `product_id` query parameter.
+    pub fn product_id(mut self, product_id: &'a str) -> Self {
+        self.product_id = Some(product_id);
+        self
+    }
+
+    /// Sets the `handle` query parameter.
+    pub fn handle(mut self, handle: &'a str) -> Self {
+        self.handle = Some(handle);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.updated_at_min = Some(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.updated_at_max = Some(updated_at_max);
+        self
+    }
+
+    /// Sets the `published_at_min` query parameter.
+    pub fn published_at_min(mut self, published_at_min: &'a str) -> Self {
+        self.published_at_min = Some(published_at_min);
+        self
+    }
+
+    /// Sets the `published_at_max` query parameter.
+    pub fn published_at_max(mut self, published_at_max: &'a str) -> Self {
+        self.published_at_max = Some(published_at_max);
+        self
+    }
+
+    /// Sets the `published_status` query parameter.
+    pub fn published_status(mut self, published_status: &'a str) -> Self {
+        self.published_status = Some(published_status);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            limit,
+            ids,
+            since_id,
+            title,
+            product_id,
+            handle,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            published_status,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = handle {
+            query_args.push(("handle".to_string(), v.to_string()));
+        }
+        if let Some(v) = ids {
+            query_args.push(("ids".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = product_id {
+            query_args.push(("product_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_at_max {
+            query_args.push(("published_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_at_min {
+            query_args.push(("published_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_status {
+            query_args.push(("published_status".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = title {
+            query_args.push(("title".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_max {
+            query_args.push(("updated_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_min {
+            query_args.push(("updated_at_min".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2021-01/custom_collections.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Products::deprecated_202101_get_custom_collections_count`], set with a builder.
+pub struct ProductsDeprecated202101GetCustomCollectionsCountRequest<'a> {
+    client: &'a Products,
+    title: Option<&'a str>,
+    product_id: Option<&'a str>,
+    updated_at_min: Option<&'a str>,
+    updated_at_max: Option<&'a str>,
+    published_at_min: Option<&'a str>,
+    published_at_max: Option<&'a str>,
+    published_status: Option<&'a str>,
+}
+
+impl<'a> ProductsDeprecated202101GetCustomCollectionsCountRequest<'a> {
+    /// Sets the `title` query parameter.
+    pub fn title(mut self, title: &'a str) -> Self {
+        self.title = Some(title);
+        self
+    }
+
+    /// Sets the `product_id` query parameter.
+    pub fn product_id(mut self, product_id: &'a str) -> Self {
+        self.product_id = Some(product_id);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.updated_at_min = Some(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.updated_at_max = Some(updated_at_max);
+        self
+    }
+
+    /// Sets the `published_at_min` query parameter.
+    pub fn published_at_min(mut self, published_at_min: &'a str) -> Self {
+        self.published_at_min = Some(published_at_min);
+        self
+    }
+
+    /// Sets the `published_at_max` query parameter.
+    pub fn published_at_max(mut self, published_at_max: &'a str) -> Self {
+        self.published_at_max = Some(published_at_max);
+        self
+    }
+
+    /// Sets the `published_status` query parameter.
+    pub fn published_status(mut self, published_status: &'a str) -> Self {
+        self.published_status = Some(published_status);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            title,
+            product_id,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            published_status,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = product_id {
+            query_args.push(("product_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_at_max {
+            query_args.push(("published_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_at_min {
+            query_args.push(("published_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_status {
+            query_args.push(("published_status".to_string(), v.to_string()));
+        }
+        if let Some(v) = title {
+            query_args.push(("title".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_max {
+            query_args.push(("updated_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_min {
+            query_args.push(("updated_at_min".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2021-01/custom_collections/count.json?{}",
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Products::deprecated_202101_get_custom_collections_param_collection`], set with a builder.
+pub struct ProductsDeprecated202101GetCustomCollectionsParamCollectionRequest<'a> {
+    client: &'a Products,
+    custom_collection_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> ProductsDeprecated202101GetCustomCollectionsParamCollectionRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            custom_collection_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2021-01/custom_collections/{}/json?{}",
+                crate::progenitor_support::encode_path(custom_collection_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Products::deprecated_unstable_get_custom_collection`], set with a builder.
+pub struct ProductsDeprecatedUnstableGetCustomCollectionRequest<'a> {
+    client: &'a Products,
+    limit: Option<&'a str>,
+    ids: Option<&'a str>,
+    since_id: Option<&'a str>,
+    title: Option<&'a str>,
+    product_id: Option<&'a str>,
+    handle: Option<&'a str>,
+    updated_at_min: Option<&'a str>,
+    updated_at_max: Option<&'a str>,
+    published_at_min: Option<&'a str>,
+    published_at_max: Option<&'a str>,
+    published_status: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> ProductsDeprecatedUnstableGetCustomCollectionRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `ids` query parameter.
+    pub fn ids(mut self, ids: &'a str) -> Self {
+        self.ids = Some(ids);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `title` query parameter.
+    pub fn title(mut self, title: &'a str) -> Self {
+        self.title = Some(title);
+        self
+    }
+
+    /// Sets the `product_id` query parameter.
+    pub fn product_id(mut self, product_id: &'a str) -> Self {
+        self.product_id = Some(product_id);
+        self
+    }
+
+    /// Sets the `handle` query parameter.
+    pub fn handle(mut self, handle: &'a str) -> Self {
+        self.handle = Some(handle);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.updated_at_min = Some(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.updated_at_max = Some(updated_at_max);
+        self
+    }
+
+    /// Sets the `published_at_min` query parameter.
+    pub fn published_at_min(mut self, published_at_min: &'a str) -> Self {
+        self.published_at_min = Some(published_at_min);
+        self
+    }
+
+    /// Sets the `published_at_max` query parameter.
+    pub fn published_at_max(mut self, published_at_max: &'a str) -> Self {
+        self.published_at_max = Some(published_at_max);
+        self
+    }
+
+    /// Sets the `published_status` query parameter.
+    pub fn published_status(mut self, published_status: &'a str) -> Self {
+        self.published_status = Some(published_status);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            limit,
+            ids,
+            since_id,
+            title,
+            product_id,
+            handle,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            published_status,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = handle {
+            query_args.push(("handle".to_string(), v.to_string()));
+        }
+        if let Some(v) = ids {
+            query_args.push(("ids".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = product_id {
+            query_args.push(("product_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_at_max {
+            query_args.push(("published_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_at_min {
+            query_args.push(("published_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_status {
+            query_args.push(("published_status".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = title {
+            query_args.push(("title".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_max {
+            query_args.push(("updated_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_min {
+            query_args.push(("updated_at_min".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/unstable/custom_collections.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Products::deprecated_unstable_get_custom_collections_count`], set with a builder.
+pub struct ProductsDeprecatedUnstableGetCustomCollectionsCountRequest<'a> {
+    client: &'a Products,
+    title: Option<&'a str>,
+    product_id: Option<&'a str>,
+    updated_at_min: Option<&'a str>,
+    updated_at_max: Option<&'a str>,
+    published_at_min: Option<&'a str>,
+    published_at_max: Option<&'a str>,
+    published_status: Option<&'a str>,
+}
+
+impl<'a> ProductsDeprecatedUnstableGetCustomCollectionsCountRequest<'a> {
+    /// Sets the `title` query parameter.
+    pub fn title(mut self, title: &'a str) -> Self {
+        self.title = Some(title);
+        self
+    }
+
+    /// Sets the `product_id` query parameter.
+    pub fn product_id(mut self, product_id: &'a str) -> Self {
+        self.product_id = Some(product_id);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.updated_at_min = Some(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.updated_at_max = Some(updated_at_max);
+        self
+    }
+
+    /// Sets the `published_at_min` query parameter.
+    pub fn published_at_min(mut self, published_at_min: &'a str) -> Self {
+        self.published_at_min = Some(published_at_min);
+        self
+    }
+
+    /// Sets the `published_at_max` query parameter.
+    pub fn published_at_max(mut self, published_at_max: &'a str) -> Self {
+        self.published_at_max = Some(published_at_max);
+        self
+    }
+
+    /// Sets the `published_status` query parameter.
+    pub fn published_status(mut self, published_status: &'a str) -> Self {
+        self.published_status = Some(published_status);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            title,
+            product_id,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            published_status,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = product_id {
+            query_args.push(("product_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_at_max {
+            query_args.push(("published_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_at_min {
+            query_args.push(("published_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_status {
+            query_args.push(("published_status".to_string(), v.to_string()));
+        }
+        if let Some(v) = title {
+            query_args.push(("title".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_max {
+            query_args.push(("updated_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_min {
+            query_args.push(("updated_at_min".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/unstable/custom_collections/count.json?{}",
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Products::deprecated_unstable_get_custom_collections_param_collection`], set with a builder.
+pub struct ProductsDeprecatedUnstableGetCustomCollectionsParamCollectionRequest<'a> {
+    client: &'a Products,
+    custom_collection_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> ProductsDeprecatedUnstableGetCustomCollectionsParamCollectionRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            custom_collection_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/unstable/custom_collections/{}/json?{}",
+                crate::progenitor_support::encode_path(custom_collection_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Products::deprecated_202001_get`], set with a builder.
+pub struct ProductsDeprecated202001GetRequest<'a> {
+    client: &'a Products,
+    ids: Option<&'a str>,
+    limit: Option<&'a str>,
+    since_id: Option<&'a str>,
+    title: Option<&'a str>,
+    vendor: Option<&'a str>,
+    handle: Option<&'a str>,
+    product_type: Option<&'a str>,
+    status: Option<&'a str>,
+    collection_id: Option<&'a str>,
+    created_at_min: Option<&'a str>,
+    created_at_max: Option<&'a str>,
+    updated_at_min: Option<&'a str>,
+    updated_at_max: Option<&'a str>,
+    published_at_min: Option<&'a str>,
+    published_at_max: Option<&'a str>,
+    published_status: Option<&'a str>,
+    fields: Option<&'a str>,
+    presentment_currencies: Option<&'a str>,
+}
+
+impl<'a> ProductsDeprecated202001GetRequest<'a> {
+    /// Sets the `ids` query parameter.
+    pub fn ids(mut self, ids: &'a str) -> Self {
+        self.ids = Some(ids);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `title` query parameter.
+    pub fn title(mut self, title: &'a str) -> Self {
+        self.title = Some(title);
+        self
+    }
+
+    /// Sets the `vendor` query parameter.
+    pub fn vendor(mut self, vendor: &'a str) -> Self {
+        self.vendor = Some(vendor);
+        self
+    }
+
+    /// Sets the `handle` query parameter.
+    pub fn handle(mut self, handle: &'a str) -> Self {
+        self.handle = Some(handle);
+        self
+    }
+
+    /// Sets the `product_type` query parameter.
+    pub fn product_type(mut self, product_type: &'a str) -> Self {
+        self.product_type = Some(product_type);
+        self
+    }
+
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: &'a str) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Sets the `collection_id` query parameter.
+    pub fn collection_id(mut self, collection_id: &'a str) -> Self {
+        self.collection_id = Some(collection_id);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.created_at_min = Some(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.created_at_max = Some(created_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.updated_at_min = Some(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.updated_at_max = Some(updated_at_max);
+        self
+    }
+
+    /// Sets the `published_at_min` query parameter.
+    pub fn published_at_min(mut self, published_at_min: &'a str) -> Self {
+        self.published_at_min = Some(published_at_min);
+        self
+    }
+
+    /// Sets the `published_at_max` query parameter.
+    pub fn published_at_max(mut self, published_at_max: &'a str) -> Self {
+        self.published_at_max = Some(published_at_max);
+        self
+    }
+
+    /// Sets the `published_status` query parameter.
+    pub fn published_status(mut self, published_status: &'a str) -> Self {
+        self.published_status = Some(published_status);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `presentment_currencies` query parameter.
+    pub fn presentment_currencies(mut self, presentment_currencies: &'a str) -> Self {
+        self.presentment_currencies = Some(presentment_currencies);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            ids,
+            limit,
+            since_id,
+            title,
+            vendor,
+            handle,
+            product_type,
+            status,
+            collection_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            published_status,
+            fields,
+            presentment_currencies,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = collection_id {
+            query_args.push(("collection_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = created_at_max {
+            query_args.push(("created_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = created_at_min {
+            query_args.push(("created_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = handle {
+            query_args.push(("handle".to_string(), v.to_string()));
+        }
+        if let Some(v) = ids {
+            query_args.push(("ids".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = presentment_currencies {
+            query_args.push(("presentment_currencies".to_string(), v.to_string()));
+        }
+        if let Some(v) = product_type {
+            query_args.push(("product_type".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_at_max {
+            query_args.push(("published_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_at_min {
+            query_args.push(("published_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_status {
+            query_args.push(("published_status".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = status {
+            query_args.push(("status".to_string(), v.to_string()));
+        }
+        if let Some(v) = title {
+            query_args.push(("title".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_max {
+            query_args.push(("updated_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_min {
+            query_args.push(("updated_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = vendor {
+            query_args.push(("vendor".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-01/products.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Products::deprecated_202001_get_count`], set with a builder.
+pub struct ProductsDeprecated202001GetCountRequest<'a> {
+    client: &'a Products,
+    vendor: Option<&'a str>,
+    product_type: Option<&'a str>,
+    collection_id: Option<&'a str>,
+    created_at_min: Option<&'a str>,
+    created_at_max: Option<&'a str>,
+    updated_at_min: Option<&'a str>,
+    updated_at_max: Option<&'a str>,
+    published_at_min: Option<&'a str>,
+    published_at_max: Option<&'a str>,
+    published_status: Option<&'a str>,
+}
+
+impl<'a> ProductsDeprecated202001GetCountRequest<'a> {
+    /// Sets the `vendor` query parameter.
+    pub fn vendor(mut self, vendor: &'a str) -> Self {
+        self.vendor = Some(vendor);
+        self
+    }
+
+    /// Sets the `product_type` query parameter.
+    pub fn product_type(mut self, product_type: &'a str) -> Self {
+        self.product_type = Some(product_type);
+        self
+    }
+
+    /// Sets the `collection_id` query parameter.
+    pub fn collection_id(mut self, collection_id: &'a str) -> Self {
+        self.collection_id = Some(collection_id);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.created_at_min = Some(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.created_at_max = Some(created_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.updated_at_min = Some(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.updated_at_max = Some(updated_at_max);
+        self
+    }
+
+    /// Sets the `published_at_min` query parameter.
+    pub fn published_at_min(mut self, published_at_min: &'a str) -> Self {
+        self.published_at_min = Some(published_at_min);
+        self
+    }
+
+    /// Sets the `published_at_max` query parameter.
+    pub fn published_at_max(mut self, published_at_max: &'a str) -> Self {
+        self.published_at_max = Some(published_at_max);
+        self
+    }
+
+    /// Sets the `published_status` query parameter.
+    pub fn published_status(mut self, published_status: &'a str) -> Self {
+        self.published_status = Some(published_status);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            vendor,
+            product_type,
+            collection_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            published_status,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = collection_id {
+            query_args.push(("collection_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = created_at_max {
+            query_args.push(("created_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = created_at_min {
+            query_args.push(("created_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = product_type {
+            query_args.push(("product_type".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_at_max {
+            query_args.push(("published_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_at_min {
+            query_args.push(("published_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_status {
+            query_args.push(("published_status".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_max {
+            query_args.push(("updated_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_min {
+            query_args.push(("updated_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = vendor {
+            query_args.push(("vendor".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-01/products/count.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Products::deprecated_202001_get_param`], set with a builder.
+pub struct ProductsDeprecated202001GetParamRequest<'a> {
+    client: &'a Products,
+    product_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> ProductsDeprecated202001GetParamRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            product_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-01/products/{}/json?{}",
+                crate::progenitor_support::encode_path(product_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Products::deprecated_202004_get`], set with a builder.
+pub struct ProductsDeprecated202004GetRequest<'a> {
+    client: &'a Products,
+    ids: Option<&'a str>,
+    limit: Option<&'a str>,
+    since_id: Option<&'a str>,
+    title: Option<&'a str>,
+    vendor: Option<&'a str>,
+    handle: Option<&'a str>,
+    product_type: Option<&'a str>,
+    status: Option<&'a str>,
+    collection_id: Option<&'a str>,
+    created_at_min: Option<&'a str>,
+    created_at_max: Option<&'a str>,
+    updated_at_min: Option<&'a str>,
+    updated_at_max: Option<&'a str>,
+    published_at_min: Option<&'a str>,
+    published_at_max: Option<&'a str>,
+    published_status: Option<&'a str>,
+    fields: Option<&'a str>,
+    presentment_currencies: Option<&'a str>,
+}
+
+impl<'a> ProductsDeprecated202004GetRequest<'a> {
+    /// Sets the `ids` query parameter.
+    pub fn ids(mut self, ids: &'a str) -> Self {
+        self.ids = Some(ids);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `title` query parameter.
+    pub fn title(mut self, title: &'a str) -> Self {
+        self.title = Some(title);
+        self
+    }
+
+    /// Sets the `vendor` query parameter.
+    pub fn vendor(mut self, vendor: &'a str) -> Self {
+        self.vendor = Some(vendor);
+        self
+    }
+
+    /// Sets the `handle` query parameter.
+    pub fn handle(mut self, handle: &'a str) -> Self {
+        self.handle = Some(handle);
+        self
+    }
+
+    /// Sets the `product_type` query parameter.
+    pub fn product_type(mut self, product_type: &'a str) -> Self {
+        self.product_type = Some(product_type);
+        self
+    }
+
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: &'a str) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Sets the `collection_id` query parameter.
+    pub fn collection_id(mut self, collection_id: &'a str) -> Self {
+        self.collection_id = Some(collection_id);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.created_at_min = Some(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.created_at_max = Some(created_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.updated_at_min = Some(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.updated_at_max = Some(updated_at_max);
+        self
+    }
+
+    /// Sets the `published_at_min` query parameter.
+    pub fn published_at_min(mut self, published_at_min: &'a str) -> Self {
+        self.published_at_min = Some(published_at_min);
+        self
+    }
+
+    /// Sets the `published_at_max` query parameter.
+    pub fn published_at_max(mut self, published_at_max: &'a str) -> Self {
+        self.published_at_max = Some(published_at_max);
+        self
+    }
+
+    /// Sets the `published_status` query parameter.
+    pub fn published_status(mut self, published_status: &'a str) -> Self {
+        self.published_status = Some(published_status);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `presentment_currencies` query parameter.
+    pub fn presentment_currencies(mut self, presentment_currencies: &'a str) -> Self {
+        self.presentment_currencies = Some(presentment_currencies);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            ids,
+            limit,
+            since_id,
+            title,
+            vendor,
+            handle,
+            product_type,
+            status,
+            collection_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            published_status,
+            fields,
+            presentment_currencies,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = collection_id {
+            query_args.push(("collection_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = created_at_max {
+            query_args.push(("created_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = created_at_min {
+            query_args.push(("created_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = handle {
+            query_args.push(("handle".to_string(), v.to_string()));
+        }
+        if let Some(v) = ids {
+            query_args.push(("ids".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = presentment_currencies {
+            query_args.push(("presentment_currencies".to_string(), v.to_string()));
+        }
+        if let Some(v) = product_type {
+            query_args.push(("product_type".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_at_max {
+            query_args.push(("published_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_at_min {
+            query_args.push(("published_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_status {
+            query_args.push(("published_status".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = status {
+            query_args.push(("status".to_string(), v.to_string()));
+        }
+        if let Some(v) = title {
+            query_args.push(("title".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_max {
+            query_args.push(("updated_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_min {
+            query_args.push(("updated_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = vendor {
+            query_args.push(("vendor".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-04/products.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Products::deprecated_202004_get_count`], set with a builder.
+pub struct ProductsDeprecated202004GetCountRequest<'a> {
+    client: &'a Products,
+    vendor: Option<&'a str>,
+    product_type: Option<&'a str>,
+    collection_id: Option<&'a str>,
+    created_at_min: Option<&'a str>,
+    created_at_max: Option<&'a str>,
+    updated_at_min: Option<&'a str>,
+    updated_at_max: Option<&'a str>,
+    published_at_min: Option<&'a str>,
+    published_at_max: Option<&'a str>,
+    published_status: Option<&'a str>,
+}
+
+impl<'a> ProductsDeprecated202004GetCountRequest<'a> {
+    /// Sets the `vendor` query parameter.
+    pub fn vendor(mut self, vendor: &'a str) -> Self {
+        self.vendor = Some(vendor);
+        self
+    }
+
+    /// Sets the `product_type` query parameter.
+    pub fn product_type(mut self, product_type: &'a str) -> Self {
+        self.product_type = Some(product_type);
+        self
+    }
+
+    /// Sets the `collection_id` query parameter.
+    pub fn collection_id(mut self, collection_id: &'a str) -> Self {
+        self.collection_id = Some(collection_id);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.created_at_min = Some(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.created_at_max = Some(created_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.updated_at_min = Some(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.updated_at_max = Some(updated_at_max);
+        self
+    }
+
+    /// Sets the `published_at_min` query parameter.
+    pub fn published_at_min(mut self, published_at_min: &'a str) -> Self {
+        self.published_at_min = Some(published_at_min);
+        self
+    }
+
+    /// Sets the `published_at_max` query parameter.
+    pub fn published_at_max(mut self, published_at_max: &'a str) -> Self {
+        self.published_at_max = Some(published_at_max);
+        self
+    }
+
+    /// Sets the `published_status` query parameter.
+    pub fn published_status(mut self, published_status: &'a str) -> Self {
+        self.published_status = Some(published_status);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            vendor,
+            product_type,
+            collection_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            published_status,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = collection_id {
+            query_args.push(("collection_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = created_at_max {
+            query_args.push(("created_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = created_at_min {
+            query_args.push(("created_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = product_type {
+            query_args.push(("product_type".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_at_max {
+            query_args.push(("published_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_at_min {
+            query_args.push(("published_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_status {
+            query_args.push(("published_status".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_max {
+            query_args.push(("updated_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_min {
+            query_args.push(("updated_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = vendor {
+            query_args.push(("vendor".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-04/products/count.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Products::deprecated_202004_get_param`], set with a builder.
+pub struct ProductsDeprecated202004GetParamRequest<'a> {
+    client: &'a Products,
+    product_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> ProductsDeprecated202004GetParamRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            product_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-04/products/{}/json?{}",
+                crate::progenitor_support::encode_path(product_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Products::deprecated_202007_get`], set with a builder.
+pub struct ProductsDeprecated202007GetRequest<'a> {
+    client: &'a Products,
+    ids: Option<&'a str>,
+    limit: Option<&'a str>,
+    since_id: Option<&'a str>,
+    title: Option<&'a str>,
+    vendor: Option<&'a str>,
+    handle: Option<&'a str>,
+    product_type: Option<&'a str>,
+    status: Option<&'a str>,
+    collection_id: Option<&'a str>,
+    created_at_min: Option<&'a str>,
+    created_at_max: Option<&'a str>,
+    updated_at_min: Option<&'a str>,
+    updated_at_max: Option<&'a str>,
+    published_at_min: Option<&'a str>,
+    published_at_max: Option<&'a str>,
+    published_status: Option<&'a str>,
+    fields: Option<&'a str>,
+    presentment_currencies: Option<&'a str>,
+}
+
+impl<'a> ProductsDeprecated202007GetRequest<'a> {
+    /// Sets the `ids` query parameter.
+    pub fn ids(mut self, ids: &'a str) -> Self {
+        self.ids = Some(ids);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `title` query parameter.
+    pub fn title(mut self, title: &'a str) -> Self {
+        self.title = Some(title);
+        self
+    }
+
+    /// Sets the `vendor` query parameter.
+    pub fn vendor(mut self, vendor: &'a str) -> Self {
+        self.vendor = Some(vendor);
+        self
+    }
+
+    /// Sets the `handle` query parameter.
+    pub fn handle(mut self, handle: &'a str) -> Self {
+        self.handle = Some(handle);
+        self
+    }
+
+    /// Sets the `product_type` query parameter.
+    pub fn product_type(mut self, product_type: &'a str) -> Self {
+        self.product_type = Some(product_type);
+        self
+    }
+
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: &'a str) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Sets the `collection_id` query parameter.
+    pub fn collection_id(mut self, collection_id: &'a str) -> Self {
+        self.collection_id = Some(collection_id);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.created_at_min = Some(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.created_at_max = Some(created_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.updated_at_min = Some(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.updated_at_max = Some(updated_at_max);
+        self
+    }
+
+    /// Sets the `published_at_min` query parameter.
+    pub fn published_at_min(mut self, published_at_min: &'a str) -> Self {
+        self.published_at_min = Some(published_at_min);
+        self
+    }
+
+    /// Sets the `published_at_max` query parameter.
+    pub fn published_at_max(mut self, published_at_max: &'a str) -> Self {
+        self.published_at_max = Some(published_at_max);
+        self
+    }
+
+    /// Sets the `published_status` query parameter.
+    pub fn published_status(mut self, published_status: &'a str) -> Self {
+        self.published_status = Some(published_status);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `presentment_currencies` query parameter.
+    pub fn presentment_currencies(mut self, presentment_currencies: &'a str) -> Self {
+        self.presentment_currencies = Some(presentment_currencies);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            ids,
+            limit,
+            since_id,
+            title,
+            vendor,
+            handle,
+            product_type,
+            status,
+            collection_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            published_status,
+            fields,
+            presentment_currencies,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = collection_id {
+            query_args.push(("collection_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = created_at_max {
+            query_args.push(("created_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = created_at_min {
+            query_args.push(("created_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = handle {
+            query_args.push(("handle".to_string(), v.to_string()));
+        }
+        if let Some(v) = ids {
+            query_args.push(("ids".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = presentment_currencies {
+            query_args.push(("presentment_currencies".to_string(), v.to_string()));
+        }
+        if let Some(v) = product_type {
+            query_args.push(("product_type".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_at_max {
+            query_args.push(("published_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_at_min {
+            query_args.push(("published_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_status {
+            query_args.push(("published_status".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = status {
+            query_args.push(("status".to_string(), v.to_string()));
+        }
+        if let Some(v) = title {
+            query_args.push(("title".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_max {
+            query_args.push(("updated_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_min {
+            query_args.push(("updated_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = vendor {
+            query_args.push(("vendor".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-07/products.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Products::deprecated_202007_get_count`], set with a builder.
+pub struct ProductsDeprecated202007GetCountRequest<'a> {
+    client: &'a Products,
+    vendor: Option<&'a str>,
+    product_type: Option<&'a str>,
+    collection_id: Option<&'a str>,
+    created_at_min: Option<&'a str>,
+    created_at_max: Option<&'a str>,
+    updated_at_min: Option<&'a str>,
+    updated_at_max: Option<&'a str>,
+    published_at_min: Option<&'a str>,
+    published_at_max: Option<&'a str>,
+    published_status: Option<&'a str>,
+}
+
+impl<'a> ProductsDeprecated202007GetCountRequest<'a> {
+    /// Sets the `vendor` query parameter.
+    pub fn vendor(mut self, vendor: &'a str) -> Self {
+        self.vendor = Some(vendor);
+        self
+    }
+
+    /// Sets the `product_type` query parameter.
+    pub fn product_type(mut self, product_type: &'a str) -> Self {
+        self.product_type = Some(product_type);
+        self
+    }
+
+    /// Sets the `collection_id` query parameter.
+    pub fn collection_id(mut self, collection_id: &'a str) -> Self {
+        self.collection_id = Some(collection_id);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.created_at_min = Some(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.created_at_max = Some(created_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.updated_at_min = Some(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.updated_at_max = Some(updated_at_max);
+        self
+    }
+
+    /// Sets the `published_at_min` query parameter.
+    pub fn published_at_min(mut self, published_at_min: &'a str) -> Self {
+        self.published_at_min = Some(published_at_min);
+        self
+    }
+
+    /// Sets the `published_at_max` query parameter.
+    pub fn published_at_max(mut self, published_at_max: &'a str) -> Self {
+        self.published_at_max = Some(published_at_max);
+        self
+    }
+
+    /// Sets the `published_status` query parameter.
+    pub fn published_status(mut self, published_status: &'a str) -> Self {
+        self.published_status = Some(published_status);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            vendor,
+            product_type,
+            collection_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            published_status,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = collection_id {
+            query_args.push(("collection_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = created_at_max {
+            query_args.push(("created_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = created_at_min {
+            query_args.push(("created_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = product_type {
+            query_args.push(("product_type".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_at_max {
+            query_args.push(("published_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_at_min {
+            query_args.push(("published_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_status {
+            query_args.push(("published_status".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_max {
+            query_args.push(("updated_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_min {
+            query_args.push(("updated_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = vendor {
+            query_args.push(("vendor".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-07/products/count.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Products::deprecated_202007_get_param`], set with a builder.
+pub struct ProductsDeprecated202007GetParamRequest<'a> {
+    client: &'a Products,
+    product_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> ProductsDeprecated202007GetParamRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            product_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-07/products/{}/json?{}",
+                crate::progenitor_support::encode_path(product_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Products::get`], set with a builder.
+pub struct ProductsGetRequest<'a> {
+    client: &'a Products,
+    ids: Option<&'a str>,
+    limit: Option<&'a str>,
+    since_id: Option<&'a str>,
+    title: Option<&'a str>,
+    vendor: Option<&'a str>,
+    handle: Option<&'a str>,
+    product_type: Option<&'a str>,
+    status: Option<&'a str>,
+    collection_id: Option<&'a str>,
+    created_at_min: Option<&'a str>,
+    created_at_max: Option<&'a str>,
+    updated_at_min: Option<&'a str>,
+    updated_at_max: Option<&'a str>,
+    published_at_min: Option<&'a str>,
+    published_at_max: Option<&'a str>,
+    published_status: Option<&'a str>,
+    fields: Option<&'a str>,
+    presentment_currencies: Option<&'a str>,
+}
+
+impl<'a> ProductsGetRequest<'a> {
+    /// Sets the `ids` query parameter.
+    pub fn ids(mut self, ids: &'a str) -> Self {
+        self.ids = Some(ids);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `title` query parameter.
+    pub fn title(mut self, title: &'a str) -> Self {
+        self.title = Some(title);
+        self
+    }
+
+    /// Sets the `vendor` query parameter.
+    pub fn vendor(mut self, vendor: &'a str) -> Self {
+        self.vendor = Some(vendor);
+        self
+    }
+
+    /// Sets the `handle` query parameter.
+    pub fn handle(mut self, handle: &'a str) -> Self {
+        self.handle = Some(handle);
+        self
+    }
+
+    /// Sets the `product_type` query parameter.
+    pub fn product_type(mut self, product_type: &'a str) -> Self {
+        self.product_type = Some(product_type);
+        self
+    }
+
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: &'a str) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Sets the `collection_id` query parameter.
+    pub fn collection_id(mut self, collection_id: &'a str) -> Self {
+        self.collection_id = Some(collection_id);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.created_at_min = Some(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.created_at_max = Some(created_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.updated_at_min = Some(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.updated_at_max = Some(updated_at_max);
+        self
+    }
+
+    /// Sets the `published_at_min` query parameter.
+    pub fn published_at_min(mut self, published_at_min: &'a str) -> Self {
+        self.published_at_min = Some(published_at_min);
+        self
+    }
+
+    /// Sets the `published_at_max` query parameter.
+    pub fn published_at_max(mut self, published_at_max: &'a str) -> Self {
+        self.published_at_max = Some(published_at_max);
+        self
+    }
+
+    /// Sets the `published_status` query parameter.
+    pub fn published_status(mut self, published_status: &'a str) -> Self {
+        self.published_status = Some(published_status);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `presentment_currencies` query parameter.
+    pub fn presentment_currencies(mut self, presentment_currencies: &'a str) -> Self {
+        self.presentment_currencies = Some(presentment_currencies);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            ids,
+            limit,
+            since_id,
+            title,
+            vendor,
+            handle,
+            product_type,
+            status,
+            collection_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            published_status,
+            fields,
+            presentment_currencies,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = collection_id {
+            query_args.push(("collection_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = created_at_max {
+            query_args.push(("created_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = created_at_min {
+            query_args.push(("created_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = handle {
+            query_args.push(("handle".to_string(), v.to_string()));
+        }
+        if let Some(v) = ids {
+            query_args.push(("ids".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = presentment_currencies {
+            query_args.push(("presentment_currencies".to_string(), v.to_string()));
+        }
+        if let Some(v) = product_type {
+            query_args.push(("product_type".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_at_max {
+            query_args.push(("published_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_at_min {
+            query_args.push(("published_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_status {
+            query_args.push(("published_status".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = status {
+            query_args.push(("status".to_string(), v.to_string()));
+        }
+        if let Some(v) = title {
+            query_args.push(("title".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_max {
+            query_args.push(("updated_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_min {
+            query_args.push(("updated_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = vendor {
+            query_args.push(("vendor".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-10/products.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Products::get_count`], set with a builder.
+pub struct ProductsGetCountRequest<'a> {
+    client: &'a Products,
+    vendor: Option<&'a str>,
+    product_type: Option<&'a str>,
+    collection_id: Option<&'a str>,
+    created_at_min: Option<&'a str>,
+    created_at_max: Option<&'a str>,
+    updated_at_min: Option<&'a str>,
+    updated_at_max: Option<&'a str>,
+    published_at_min: Option<&'a str>,
+    published_at_max: Option<&'a str>,
+    published_status: Option<&'a str>,
+}
+
+impl<'a> ProductsGetCountRequest<'a> {
+    /// Sets the `vendor` query parameter.
+    pub fn vendor(mut self, vendor: &'a str) -> Self {
+        self.vendor = Some(vendor);
+        self
+    }
+
+    /// Sets the `product_type` query parameter.
+    pub fn product_type(mut self, product_type: &'a str) -> Self {
+        self.product_type = Some(product_type);
+        self
+    }
+
+    /// Sets the `collection_id` query parameter.
+    pub fn collection_id(mut self, collection_id: &'a str) -> Self {
+        self.collection_id = Some(collection_id);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.created_at_min = Some(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.created_at_max = Some(created_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.updated_at_min = Some(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.updated_at_max = Some(updated_at_max);
+        self
+    }
+
+    /// Sets the `published_at_min` query parameter.
+    pub fn published_at_min(mut self, published_at_min: &'a str) -> Self {
+        self.published_at_min = Some(published_at_min);
+        self
+    }
+
+    /// Sets the `published_at_max` query parameter.
+    pub fn published_at_max(mut self, published_at_max: &'a str) -> Self {
+        self.published_at_max = Some(published_at_max);
+        self
+    }
+
+    /// Sets the `published_status` query parameter.
+    pub fn published_status(mut self, published_status: &'a str) -> Self {
+        self.published_status = Some(published_status);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            vendor,
+            product_type,
+            collection_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            published_status,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = collection_id {
+            query_args.push(("collection_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = created_at_max {
+            query_args.push(("created_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = created_at_min {
+            query_args.push(("created_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = product_type {
+            query_args.push(("product_type".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_at_max {
+            query_args.push(("published_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_at_min {
+            query_args.push(("published_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_status {
+            query_args.push(("published_status".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_max {
+            query_args.push(("updated_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_min {
+            query_args.push(("updated_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = vendor {
+            query_args.push(("vendor".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-10/products/count.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Products::get_param`], set with a builder.
+pub struct ProductsGetParamRequest<'a> {
+    client: &'a Products,
+    product_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> ProductsGetParamRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            product_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-10/products/{}/json?{}",
+                crate::progenitor_support::encode_path(product_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Products::deprecated_202101_get`], set with a builder.
+pub struct ProductsDeprecated202101GetRequest<'a> {
+    client: &'a Products,
+    ids: Option<&'a str>,
+    limit: Option<&'a str>,
+    since_id: Option<&'a str>,
+    title: Option<&'a str>,
+    vendor: Option<&'a str>,
+    handle: Option<&'a str>,
+    product_type: Option<&'a str>,
+    status: Option<&'a str>,
+    collection_id: Option<&'a str>,
+    created_at_min: Option<&'a str>,
+    created_at_max: Option<&'a str>,
+    updated_at_min: Option<&'a str>,
+    updated_at_max: Option<&'a str>,
+    published_at_min: Option<&'a str>,
+    published_at_max: Option<&'a str>,
+    published_status: Option<&'a str>,
+    fields: Option<&'a str>,
+    presentment_currencies: Option<&'a str>,
+}
+
+impl<'a> ProductsDeprecated202101GetRequest<'a> {
+    /// Sets the `ids` query parameter.
+    pub fn ids(mut self, ids: &'a str) -> Self {
+        self.ids = Some(ids);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `title` query parameter.
+    pub fn title(mut self, title: &'a str) -> Self {
+        self.title = Some(title);
+        self
+    }
+
+    /// Sets the `vendor` query parameter.
+    pub fn vendor(mut self, vendor: &'a str) -> Self {
+        self.vendor = Some(vendor);
+        self
+    }
+
+    /// Sets the `handle` query parameter.
+    pub fn handle(mut self, handle: &'a str) -> Self {
+        self.handle = Some(handle);
+        self
+    }
+
+    /// Sets the `product_type` query parameter.
+    pub fn product_type(mut self, product_type: &'a str) -> Self {
+        self.product_type = Some(product_type);
+        self
+    }
+
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: &'a str) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Sets the `collection_id` query parameter.
+    pub fn collection_id(mut self, collection_id: &'a str) -> Self {
+        self.collection_id = Some(collection_id);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.created_at_min = Some(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.created_at_max = Some(created_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.updated_at_min = Some(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.updated_at_max = Some(updated_at_max);
+        self
+    }
+
+    /// Sets the `published_at_min` query parameter.
+    pub fn published_at_min(mut self, published_at_min: &'a str) -> Self {
+        self.published_at_min = Some(published_at_min);
+        self
+    }
+
+    /// Sets the `published_at_max` query parameter.
+    pub fn published_at_max(mut self, published_at_max: &'a str) -> Self {
+        self.published_at_max = Some(published_at_max);
+        self
+    }
+
+    /// Sets the `published_status` query parameter.
+    pub fn published_status(mut self, published_status: &'a str) -> Self {
+        self.published_status = Some(published_status);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `presentment_currencies` query parameter.
+    pub fn presentment_currencies(mut self, presentment_currencies: &'a str) -> Self {
+        self.presentment_currencies = Some(presentment_currencies);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            ids,
+            limit,
+            since_id,
+            title,
+            vendor,
+            handle,
+            product_type,
+            status,
+            collection_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            published_status,
+            fields,
+            presentment_currencies,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = collection_id {
+            query_args.push(("collection_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = created_at_max {
+            query_args.push(("created_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = created_at_min {
+            query_args.push(("created_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = handle {
+            query_args.push(("handle".to_string(), v.to_string()));
+        }
+        if let Some(v) = ids {
+            query_args.push(("ids".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = presentment_currencies {
+            query_args.push(("presentment_currencies".to_string(), v.to_string()));
+        }
+        if let Some(v) = product_type {
+            query_args.push(("product_type".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_at_max {
+            query_args.push(("published_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_at_min {
+            query_args.push(("published_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_status {
+            query_args.push(("published_status".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = status {
+            query_args.push(("status".to_string(), v.to_string()));
+        }
+        if let Some(v) = title {
+            query_args.push(("title".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_max {
+            query_args.push(("updated_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_min {
+            query_args.push(("updated_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = vendor {
+            query_args.push(("vendor".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2021-01/products.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Products::deprecated_202101_get_count`], set with a builder.
+pub struct ProductsDeprecated202101GetCountRequest<'a> {
+    client: &'a Products,
+    vendor: Option<&'a str>,
+    product_type: Option<&'a str>,
+    collection_id: Option<&'a str>,
+    created_at_min: Option<&'a str>,
+    created_at_max: Option<&'a str>,
+    updated_at_min: Option<&'a str>,
+    updated_at_max: Option<&'a str>,
+    published_at_min: Option<&'a str>,
+    published_at_max: Option<&'a str>,
+    published_status: Option<&'a str>,
+}
+
+impl<'a> ProductsDeprecated202101GetCountRequest<'a> {
+    /// Sets the `vendor` query parameter.
+    pub fn vendor(mut self, vendor: &'a str) -> Self {
+        self.vendor = Some(vendor);
+        self
+    }
+
+    /// Sets the `product_type` query parameter.
+    pub fn product_type(mut self, product_type: &'a str) -> Self {
+        self.product_type = Some(product_type);
+        self
+    }
+
+    /// Sets the `collection_id` query parameter.
+    pub fn collection_id(mut self, collection_id: &'a str) -> Self {
+        self.collection_id = Some(collection_id);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.created_at_min = Some(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.created_at_max = Some(created_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.updated_at_min = Some(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.updated_at_max = Some(updated_at_max);
+        self
+    }
+
+    /// Sets the `published_at_min` query parameter.
+    pub fn published_at_min(mut self, published_at_min: &'a str) -> Self {
+        self.published_at_min = Some(published_at_min);
+        self
+    }
+
+    /// Sets the `published_at_max` query parameter.
+    pub fn published_at_max(mut self, published_at_max: &'a str) -> Self {
+        self.published_at_max = Some(published_at_max);
+        self
+    }
+
+    /// Sets the `published_status` query parameter.
+    pub fn published_status(mut self, published_status: &'a str) -> Self {
+        self.published_status = Some(published_status);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            vendor,
+            product_type,
+            collection_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            published_status,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = collection_id {
+            query_args.push(("collection_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = created_at_max {
+            query_args.push(("created_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = created_at_min {
+            query_args.push(("created_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = product_type {
+            query_args.push(("product_type".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_at_max {
+            query_args.push(("published_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_at_min {
+            query_args.push(("published_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_status {
+            query_args.push(("published_status".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_max {
+            query_args.push(("updated_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_min {
+            query_args.push(("updated_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = vendor {
+            query_args.push(("vendor".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2021-01/products/count.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Products::deprecated_202101_get_param`], set with a builder.
+pub struct ProductsDeprecated202101GetParamRequest<'a> {
+    client: &'a Products,
+    product_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> ProductsDeprecated202101GetParamRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            product_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2021-01/products/{}/json?{}",
+                crate::progenitor_support::encode_path(product_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Products::deprecated_unstable_get`], set with a builder.
+pub struct ProductsDeprecatedUnstableGetRequest<'a> {
+    client: &'a Products,
+    ids: Option<&'a str>,
+    limit: Option<&'a str>,
+    since_id: Option<&'a str>,
+    title: Option<&'a str>,
+    vendor: Option<&'a str>,
+    handle: Option<&'a str>,
+    product_type: Option<&'a str>,
+    status: Option<&'a str>,
+    collection_id: Option<&'a str>,
+    created_at_min: Option<&'a str>,
+    created_at_max: Option<&'a str>,
+    updated_at_min: Option<&'a str>,
+    updated_at_max: Option<&'a str>,
+    published_at_min: Option<&'a str>,
+    published_at_max: Option<&'a str>,
+    published_status: Option<&'a str>,
+    fields: Option<&'a str>,
+    presentment_currencies: Option<&'a str>,
+}
+
+impl<'a> ProductsDeprecatedUnstableGetRequest<'a> {
+    /// Sets the `ids` query parameter.
+    pub fn ids(mut self, ids: &'a str) -> Self {
+        self.ids = Some(ids);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `title` query parameter.
+    pub fn title(mut self, title: &'a str) -> Self {
+        self.title = Some(title);
+        self
+    }
+
+    /// Sets the `vendor` query parameter.
+    pub fn vendor(mut self, vendor: &'a str) -> Self {
+        self.vendor = Some(vendor);
+        self
+    }
+
+    /// Sets the `handle` query parameter.
+    pub fn handle(mut self, handle: &'a str) -> Self {
+        self.handle = Some(handle);
+        self
+    }
+
+    /// Sets the `product_type` query parameter.
+    pub fn product_type(mut self, product_type: &'a str) -> Self {
+        self.product_type = Some(product_type);
+        self
+    }
+
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: &'a str) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Sets the `collection_id` query parameter.
+    pub fn collection_id(mut self, collection_id: &'a str) -> Self {
+        self.collection_id = Some(collection_id);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.created_at_min = Some(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.created_at_max = Some(created_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.updated_at_min = Some(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.updated_at_max = Some(updated_at_max);
+        self
+    }
+
+    /// Sets the `published_at_min` query parameter.
+    pub fn published_at_min(mut self, published_at_min: &'a str) -> Self {
+        self.published_at_min = Some(published_at_min);
+        self
+    }
+
+    /// Sets the `published_at_max` query parameter.
+    pub fn published_at_max(mut self, published_at_max: &'a str) -> Self {
+        self.published_at_max = Some(published_at_max);
+        self
+    }
+
+    /// Sets the `published_status` query parameter.
+    pub fn published_status(mut self, published_status: &'a str) -> Self {
+        self.published_status = Some(published_status);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `presentment_currencies` query parameter.
+    pub fn presentment_currencies(mut self, presentment_currencies: &'a str) -> Self {
+        self.presentment_currencies = Some(presentment_currencies);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            ids,
+            limit,
+            since_id,
+            title,
+            vendor,
+            handle,
+            product_type,
+            status,
+            collection_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            published_status,
+            fields,
+            presentment_currencies,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = collection_id {
+            query_args.push(("collection_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = created_at_max {
+            query_args.push(("created_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = created_at_min {
+            query_args.push(("created_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = handle {
+            query_args.push(("handle".to_string(), v.to_string()));
+        }
+        if let Some(v) = ids {
+            query_args.push(("ids".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = presentment_currencies {
+            query_args.push(("presentment_currencies".to_string(), v.to_string()));
+        }
+        if let Some(v) = product_type {
+            query_args.push(("product_type".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_at_max {
+            query_args.push(("published_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_at_min {
+            query_args.push(("published_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_status {
+            query_args.push(("published_status".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = status {
+            query_args.push(("status".to_string(), v.to_string()));
+        }
+        if let Some(v) = title {
+            query_args.push(("title".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_max {
+            query_args.push(("updated_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_min {
+            query_args.push(("updated_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = vendor {
+            query_args.push(("vendor".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/unstable/products.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Products::deprecated_unstable_get_count`], set with a builder.
+pub struct ProductsDeprecatedUnstableGetCountRequest<'a> {
+    client: &'a Products,
+    vendor: Option<&'a str>,
+    product_type: Option<&'a str>,
+    collection_id: Option<&'a str>,
+    created_at_min: Option<&'a str>,
+    created_at_max: Option<&'a str>,
+    updated_at_min: Option<&'a str>,
+    updated_at_max: Option<&'a str>,
+    published_at_min: Option<&'a str>,
+    published_at_max: Option<&'a str>,
+    published_status: Option<&'a str>,
+}
+
+impl<'a> ProductsDeprecatedUnstableGetCountRequest<'a> {
+    /// Sets the `vendor` query parameter.
+    pub fn vendor(mut self, vendor: &'a str) -> Self {
+        self.vendor = Some(vendor);
+        self
+    }
+
+    /// Sets the `product_type` query parameter.
+    pub fn product_type(mut self, product_type: &'a str) -> Self {
+        self.product_type = Some(product_type);
+        self
+    }
+
+    /// Sets the `collection_id` query parameter.
+    pub fn collection_id(mut self, collection_id: &'a str) -> Self {
+        self.collection_id = Some(collection_id);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.created_at_min = Some(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.created_at_max = Some(created_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.updated_at_min = Some(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.updated_at_max = Some(updated_at_max);
+        self
+    }
+
+    /// Sets the `published_at_min` query parameter.
+    pub fn published_at_min(mut self, published_at_min: &'a str) -> Self {
+        self.published_at_min = Some(published_at_min);
+        self
+    }
+
+    /// Sets the `published_at_max` query parameter.
+    pub fn published_at_max(mut self, published_at_max: &'a str) -> Self {
+        self.published_at_max = Some(published_at_max);
+        self
+    }
+
+    /// Sets the `published_status` query parameter.
+    pub fn published_status(mut self, published_status: &'a str) -> Self {
+        self.published_status = Some(published_status);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            vendor,
+            product_type,
+            collection_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            published_status,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = collection_id {
+            query_args.push(("collection_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = created_at_max {
+            query_args.push(("created_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = created_at_min {
+            query_args.push(("created_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = product_type {
+            query_args.push(("product_type".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_at_max {
+            query_args.push(("published_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_at_min {
+            query_args.push(("published_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_status {
+            query_args.push(("published_status".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_max {
+            query_args.push(("updated_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_min {
+            query_args.push(("updated_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = vendor {
+            query_args.push(("vendor".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/unstable/products/count.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Products::deprecated_unstable_get_param`], set with a builder.
+pub struct ProductsDeprecatedUnstableGetParamRequest<'a> {
+    client: &'a Products,
+    product_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> ProductsDeprecatedUnstableGetParamRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            product_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/unstable/products/{}/json?{}",
+                crate::progenitor_support::encode_path(product_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Products::deprecated_202001_get_param_image`], set with a builder.
+pub struct ProductsDeprecated202001GetParamImageRequest<'a> {
+    client: &'a Products,
+    product_id: &'a str,
+    since_id: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> ProductsDeprecated202001GetParamImageRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            product_id,
+            since_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-01/products/{}/images.json?{}",
+                crate::progenitor_support::encode_path(product_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Products::deprecated_202001_get_param_images_count`], set with a builder.
+pub struct ProductsDeprecated202001GetParamImagesCountRequest<'a> {
+    client: &'a Products,
+    product_id: &'a str,
+    since_id: Option<&'a str>,
+}
+
+impl<'a> ProductsDeprecated202001GetParamImagesCountRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            product_id,
+            since_id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-01/products/{}/images/count.json?{}",
+                crate::progenitor_support::encode_path(product_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Products::deprecated_202001_get_param_images_image`], set with a builder.
+pub struct ProductsDeprecated202001GetParamImagesImageRequest<'a> {
+    client: &'a Products,
+    product_id: &'a str,
+    image_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> ProductsDeprecated202001GetParamImagesImageRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            product_id,
+            image_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-01/products/{}/images/{}/json?{}",
+                crate::progenitor_support::encode_path(product_id),
+                crate::progenitor_support::encode_path(image_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Products::deprecated_202004_get_param_image`], set with a builder.
+pub struct ProductsDeprecated202004GetParamImageRequest<'a> {
+    client: &'a Products,
+    product_id: &'a str,
+    since_id: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> ProductsDeprecated202004GetParamImageRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            product_id,
+            since_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-04/products/{}/images.json?{}",
+                crate::progenitor_support::encode_path(product_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Products::deprecated_202004_get_param_images_count`], set with a builder.
+pub struct ProductsDeprecated202004GetParamImagesCountRequest<'a> {
+    client: &'a Products,
+    product_id: &'a str,
+    since_id: Option<&'a str>,
+}
+
+impl<'a> ProductsDeprecated202004GetParamImagesCountRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            product_id,
+            since_id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-04/products/{}/images/count.json?{}",
+                crate::progenitor_support::encode_path(product_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Products::deprecated_202004_get_param_images_image`], set with a builder.
+pub struct ProductsDeprecated202004GetParamImagesImageRequest<'a> {
+    client: &'a Products,
+    product_id: &'a str,
+    image_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> ProductsDeprecated202004GetParamImagesImageRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            product_id,
+            image_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-04/products/{}/images/{}/json?{}",
+                crate::progenitor_support::encode_path(product_id),
+                crate::progenitor_support::encode_path(image_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Products::deprecated_202007_get_param_image`], set with a builder.
+pub struct ProductsDeprecated202007GetParamImageRequest<'a> {
+    client: &'a Products,
+    product_id: &'a str,
+    since_id: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> ProductsDeprecated202007GetParamImageRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            product_id,
+            since_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-07/products/{}/images.json?{}",
+                crate::progenitor_support::encode_path(product_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Products::deprecated_202007_get_param_images_count`], set with a builder.
+pub struct ProductsDeprecated202007GetParamImagesCountRequest<'a> {
+    client: &'a Products,
+    product_id: &'a str,
+    since_id: Option<&'a str>,
+}
+
+impl<'a> ProductsDeprecated202007GetParamImagesCountRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            product_id,
+            since_id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-07/products/{}/images/count.json?{}",
+                crate::progenitor_support::encode_path(product_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Products::deprecated_202007_get_param_images_image`], set with a builder.
+pub struct ProductsDeprecated202007GetParamImagesImageRequest<'a> {
+    client: &'a Products,
+    product_id: &'a str,
+    image_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> ProductsDeprecated202007GetParamImagesImageRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            product_id,
+            image_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-07/products/{}/images/{}/json?{}",
+                crate::progenitor_support::encode_path(product_id),
+                crate::progenitor_support::encode_path(image_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Products::get_param_image`], set with a builder.
+pub struct ProductsGetParamImageRequest<'a> {
+    client: &'a Products,
+    product_id: &'a str,
+    since_id: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> ProductsGetParamImageRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            product_id,
+            since_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-10/products/{}/images.json?{}",
+                crate::progenitor_support::encode_path(product_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Products::get_param_images_count`], set with a builder.
+pub struct ProductsGetParamImagesCountRequest<'a> {
+    client: &'a Products,
+    product_id: &'a str,
+    since_id: Option<&'a str>,
+}
+
+impl<'a> ProductsGetParamImagesCountRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            product_id,
+            since_id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-10/products/{}/images/count.json?{}",
+                crate::progenitor_support::encode_path(product_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Products::get_param_images_image`], set with a builder.
+pub struct ProductsGetParamImagesImageRequest<'a> {
+    client: &'a Products,
+    product_id: &'a str,
+    image_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> ProductsGetParamImagesImageRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            product_id,
+            image_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-10/products/{}/images/{}/json?{}",
+                crate::progenitor_support::encode_path(product_id),
+                crate::progenitor_support::encode_path(image_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Products::deprecated_202101_get_param_image`], set with a builder.
+pub struct ProductsDeprecated202101GetParamImageRequest<'a> {
+    client: &'a Products,
+    product_id: &'a str,
+    since_id: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> ProductsDeprecated202101GetParamImageRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            product_id,
+            since_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2021-01/products/{}/images.json?{}",
+                crate::progenitor_support::encode_path(product_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Products::deprecated_202101_get_param_images_count`], set with a builder.
+pub struct ProductsDeprecated202101GetParamImagesCountRequest<'a> {
+    client: &'a Products,
+    product_id: &'a str,
+    since_id: Option<&'a str>,
+}
+
+impl<'a> ProductsDeprecated202101GetParamImagesCountRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            product_id,
+            since_id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2021-01/products/{}/images/count.json?{}",
+                crate::progenitor_support::encode_path(product_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Products::deprecated_202101_get_param_images_image`], set with a builder.
+pub struct ProductsDeprecated202101GetParamImagesImageRequest<'a> {
+    client: &'a Products,
+    product_id: &'a str,
+    image_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> ProductsDeprecated202101GetParamImagesImageRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            product_id,
+            image_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2021-01/products/{}/images/{}/json?{}",
+                crate::progenitor_support::encode_path(product_id),
+                crate::progenitor_support::encode_path(image_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Products::deprecated_unstable_get_param_image`], set with a builder.
+pub struct ProductsDeprecatedUnstableGetParamImageRequest<'a> {
+    client: &'a Products,
+    product_id: &'a str,
+    since_id: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> ProductsDeprecatedUnstableGetParamImageRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            product_id,
+            since_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/unstable/products/{}/images.json?{}",
+                crate::progenitor_support::encode_path(product_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Products::deprecated_unstable_get_param_images_count`], set with a builder.
+pub struct ProductsDeprecatedUnstableGetParamImagesCountRequest<'a> {
+    client: &'a Products,
+    product_id: &'a str,
+    since_id: Option<&'a str>,
+}
+
+impl<'a> ProductsDeprecatedUnstableGetParamImagesCountRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            product_id,
+            since_id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/unstable/products/{}/images/count.json?{}",
+                crate::progenitor_support::encode_path(product_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Products::deprecated_unstable_get_param_images_image`], set with a builder.
+pub struct ProductsDeprecatedUnstableGetParamImagesImageRequest<'a> {
+    client: &'a Products,
+    product_id: &'a str,
+    image_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> ProductsDeprecatedUnstableGetParamImagesImageRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            product_id,
+            image_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/unstable/products/{}/images/{}/json?{}",
+                crate::progenitor_support::encode_path(product_id),
+                crate::progenitor_support::encode_path(image_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Products::deprecated_202001_get_smart_collection`], set with a builder.
+pub struct ProductsDeprecated202001GetSmartCollectionRequest<'a> {
+    client: &'a Products,
+    limit: Option<&'a str>,
+    ids: Option<&'a str>,
+    since_id: Option<&'a str>,
+    title: Option<&'a str>,
+    product_id: Option<&'a str>,
+    handle: Option<&'a str>,
+    updated_at_min: Option<&'a str>,
+    updated_at_max: Option<&'a str>,
+    published_at_min: Option<&'a str>,
+    published_at_max: Option<&'a str>,
+    published_status: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> ProductsDeprecated202001GetSmartCollectionRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `ids` query parameter.
+    pub fn ids(mut self, ids: &'a str) -> Self {
+        self.ids = Some(ids);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `title` query parameter.
+    pub fn title(mut self, title: &'a str) -> Self {
+        self.title = Some(title);
+        self
+    }
+
+    /// Sets the `product_id` query parameter.
+    pub fn product_id(mut self, product_id: &'a str) -> Self {
+        self.product_id = Some(product_id);
+        self
+    }
+
+    /// Sets the `handle` query parameter.
+    pub fn handle(mut self, handle: &'a str) -> Self {
+        self.handle = Some(handle);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.updated_at_min = Some(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.updated_at_max = Some(updated_at_max);
+        self
+    }
+
+    /// Sets the `published_at_min` query parameter.
+    pub fn published_at_min(mut self, published_at_min: &'a str) -> Self {
+        self.published_at_min = Some(published_at_min);
+        self
+    }
+
+    /// Sets the `published_at_max` query parameter.
+    pub fn published_at_max(mut self, published_at_max: &'a str) -> Self {
+        self.published_at_max = Some(published_at_max);
+        self
+    }
+
+    /// Sets the `published_status` query parameter.
+    pub fn published_status(mut self, published_status: &'a str) -> Self {
+        self.published_status = Some(published_status);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            limit,
+            ids,
+            since_id,
+            title,
+            product_id,
+            handle,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            published_status,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = handle {
+            query_args.push(("handle".to_string(), v.to_string()));
+        }
+        if let Some(v) = ids {
+            query_args.push(("ids".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = product_id {
+            query_args.push(("product_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_at_max {
+            query_args.push(("published_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_at_min {
+            query_args.push(("published_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_status {
+            query_args.push(("published_status".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = title {
+            query_args.push(("title".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_max {
+            query_args.push(("updated_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_min {
+            query_args.push(("updated_at_min".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-01/smart_collections.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Products::deprecated_202001_get_smart_collections_count`], set with a builder.
+pub struct ProductsDeprecated202001GetSmartCollectionsCountRequest<'a> {
+    client: &'a Products,
+    title: Option<&'a str>,
+    product_id: Option<&'a str>,
+    updated_at_min: Option<&'a str>,
+    updated_at_max: Option<&'a str>,
+    published_at_min: Option<&'a str>,
+    published_at_max: Option<&'a str>,
+    published_status: Option<&'a str>,
+}
+
+impl<'a> ProductsDeprecated202001GetSmartCollectionsCountRequest<'a> {
+    /// Sets the `title` query parameter.
+    pub fn title(mut self, title: &'a str) -> Self {
+        self.title = Some(title);
+        self
+    }
+
+    /// Sets the `product_id` query parameter.
+    pub fn product_id(mut self, product_id: &'a str) -> Self {
+        self.product_id = Some(product_id);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.updated_at_min = Some(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.updated_at_max = Some(updated_at_max);
+        self
+    }
+
+    /// Sets the `published_at_min` query parameter.
+    pub fn published_at_min(mut self, published_at_min: &'a str) -> Self {
+        self.published_at_min = Some(published_at_min);
+        self
+    }
+
+    /// Sets the `published_at_max` query parameter.
+    pub fn published_at_max(mut self, published_at_max: &'a str) -> Self {
+        self.published_at_max = Some(published_at_max);
+        self
+    }
+
+    /// Sets the `published_status` query parameter.
+    pub fn published_status(mut self, published_status: &'a str) -> Self {
+        self.published_status = Some(published_status);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            title,
+            product_id,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            published_status,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = product_id {
+            query_args.push(("product_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_at_max {
+            query_args.push(("published_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_at_min {
+            query_args.push(("published_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_status {
+            query_args.push(("published_status".to_string(), v.to_string()));
+        }
+        if let Some(v) = title {
+            query_args.push(("title".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_max {
+            query_args.push(("updated_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_min {
+            query_args.push(("updated_at_min".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-01/smart_collections/count.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Products::deprecated_202001_get_smart_collections_param_collection`], set with a builder.
+pub struct ProductsDeprecated202001GetSmartCollectionsParamCollectionRequest<'a> {
+    client: &'a Products,
+    smart_collection_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> ProductsDeprecated202001GetSmartCollectionsParamCollectionRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            smart_collection_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-01/smart_collections/{}/json?{}",
+                crate::progenitor_support::encode_path(smart_collection_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Products::deprecated_202001_update_smart_collections_param_collection_order`], set with a builder.
+pub struct ProductsDeprecated202001UpdateSmartCollectionsParamCollectionOrderRequest<'a> {
+    client: &'a Products,
+    smart_collection_id: &'a str,
+    products: Option<&'a str>,
+    sort_order: Option<&'a str>,
+    body: &'a serde_json::Value,
+}
+
+impl<'a> ProductsDeprecated202001UpdateSmartCollectionsParamCollectionOrderRequest<'a> {
+    /// Sets the `products` query parameter.
+    pub fn products(mut self, products: &'a str) -> Self {
+        self.products = Some(products);
+        self
+    }
+
+    /// Sets the `sort_order` query parameter.
+    pub fn sort_order(mut self, sort_order: &'a str) -> Self {
+        self.sort_order = Some(sort_order);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            smart_collection_id,
+            products,
+            sort_order,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = products {
+            query_args.push(("products".to_string(), v.to_string()));
+        }
+        if let Some(v) = sort_order {
+            query_args.push(("sort_order".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-01/smart_collections/{}/order.json?{}",
+                crate::progenitor_support::encode_path(smart_collection_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .put(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Products::deprecated_202004_get_smart_collection`], set with a builder.
+pub struct ProductsDeprecated202004GetSmartCollectionRequest<'a> {
+    client: &'a Products,
+    limit: Option<&'a str>,
+    ids: Option<&'a str>,
+    since_id: Option<&'a str>,
+    title: Option<&'a str>,
+    product_id: Option<&'a str>,
+    handle: Option<&'a str>,
+    updated_at_min: Option<&'a str>,
+    updated_at_max: Option<&'a str>,
+    published_at_min: Option<&'a str>,
+    published_at_max: Option<&'a str>,
+    published_status: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> ProductsDeprecated202004GetSmartCollectionRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `ids` query parameter.
+    pub fn ids(mut self, ids: &'a str) -> Self {
+        self.ids = Some(ids);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `title` query parameter.
+    pub fn title(mut self, title: &'a str) -> Self {
+        self.title = Some(title);
+        self
+    }
+
+    /// Sets the `product_id` query parameter.
+    pub fn product_id(mut self, product_id: &'a str) -> Self {
+        self.product_id = Some(product_id);
+        self
+    }
+
+    /// Sets the `handle` query parameter.
+    pub fn handle(mut self, handle: &'a str) -> Self {
+        self.handle = Some(handle);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.updated_at_min = Some(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.updated_at_max = Some(updated_at_max);
+        self
+    }
+
+    /// Sets the `published_at_min` query parameter.
+    pub fn published_at_min(mut self, published_at_min: &'a str) -> Self {
+        self.published_at_min = Some(published_at_min);
+        self
+    }
+
+    /// Sets the `published_at_max` query parameter.
+    pub fn published_at_max(mut self, published_at_max: &'a str) -> Self {
+        self.published_at_max = Some(published_at_max);
+        self
+    }
+
+    /// Sets the `published_status` query parameter.
+    pub fn published_status(mut self, published_status: &'a str) -> Self {
+        self.published_status = Some(published_status);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            limit,
+            ids,
+            since_id,
+            title,
+            product_id,
+            handle,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            published_status,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = handle {
+            query_args.push(("handle".to_string(), v.to_string()));
+        }
+        if let Some(v) = ids {
+            query_args.push(("ids".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = product_id {
+            query_args.push(("product_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_at_max {
+            query_args.push(("published_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_at_min {
+            query_args.push(("published_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_status {
+            query_args.push(("published_status".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = title {
+            query_args.push(("title".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_max {
+            query_args.push(("updated_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_min {
+            query_args.push(("updated_at_min".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-04/smart_collections.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Products::deprecated_202004_get_smart_collections_count`], set with a builder.
+pub struct ProductsDeprecated202004GetSmartCollectionsCountRequest<'a> {
+    client: &'a Products,
+    title: Option<&'a str>,
+    product_id: Option<&'a str>,
+    updated_at_min: Option<&'a str>,
+    updated_at_max: Option<&'a str>,
+    published_at_min: Option<&'a str>,
+    published_at_max: Option<&'a str>,
+    published_status: Option<&'a str>,
+}
+
+impl<'a> ProductsDeprecated202004GetSmartCollectionsCountRequest<'a> {
+    /// Sets the `title` query parameter.
+    pub fn title(mut self, title: &'a str) -> Self {
+        self.title = Some(title);
+        self
+    }
+
+    /// Sets the `product_id` query parameter.
+    pub fn product_id(mut self, product_id: &'a str) -> Self {
+        self.product_id = Some(product_id);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.updated_at_min = Some(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.updated_at_max = Some(updated_at_max);
+        self
+    }
+
+    /// Sets the `published_at_min` query parameter.
+    pub fn published_at_min(mut self, published_at_min: &'a str) -> Self {
+        self.published_at_min = Some(published_at_min);
+        self
+    }
+
+    /// Sets the `published_at_max` query parameter.
+    pub fn published_at_max(mut self, published_at_max: &'a str) -> Self {
+        self.published_at_max = Some(published_at_max);
+        self
+    }
+
+    /// Sets the `published_status` query parameter.
+    pub fn published_status(mut self, published_status: &'a str) -> Self {
+        self.published_status = Some(published_status);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            title,
+            product_id,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            published_status,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = product_id {
+            query_args.push(("product_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_at_max {
+            query_args.push(("published_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_at_min {
+            query_args.push(("published_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_status {
+            query_args.push(("published_status".to_string(), v.to_string()));
+        }
+        if let Some(v) = title {
+            query_args.push(("title".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_max {
+            query_args.push(("updated_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_min {
+            query_args.push(("updated_at_min".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-04/smart_collections/count.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Products::deprecated_202004_get_smart_collections_param_collection`], set with a builder.
+pub struct ProductsDeprecated202004GetSmartCollectionsParamCollectionRequest<'a> {
+    client: &'a Products,
+    smart_collection_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> ProductsDeprecated202004GetSmartCollectionsParamCollectionRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            smart_collection_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-04/smart_collections/{}/json?{}",
+                crate::progenitor_support::encode_path(smart_collection_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Products::deprecated_202004_update_smart_collections_param_collection_order`], set with a builder.
+pub struct ProductsDeprecated202004UpdateSmartCollectionsParamCollectionOrderRequest<'a> {
+    client: &'a Products,
+    smart_collection_id: &'a str,
+    products: Option<&'a str>,
+    sort_order: Option<&'a str>,
+    body: &'a serde_json::Value,
+}
+
+impl<'a> ProductsDeprecated202004UpdateSmartCollectionsParamCollectionOrderRequest<'a> {
+    /// Sets the `products` query parameter.
+    pub fn products(mut self, products: &'a str) -> Self {
+        self.products = Some(products);
+        self
+    }
+
+    /// Sets the `sort_order` query parameter.
+    pub fn sort_order(mut self, sort_order: &'a str) -> Self {
+        self.sort_order = Some(sort_order);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            smart_collection_id,
+            products,
+            sort_order,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = products {
+            query_args.push(("products".to_string(), v.to_string()));
+        }
+        if let Some(v) = sort_order {
+            query_args.push(("sort_order".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-04/smart_collections/{}/order.json?{}",
+                crate::progenitor_support::encode_path(smart_collection_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .put(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Products::deprecated_202007_get_smart_collection`], set with a builder.
+pub struct ProductsDeprecated202007GetSmartCollectionRequest<'a> {
+    client: &'a Products,
+    limit: Option<&'a str>,
+    ids: Option<&'a str>,
+    since_id: Option<&'a str>,
+    title: Option<&'a str>,
+    product_id: Option<&'a str>,
+    handle: Option<&'a str>,
+    updated_at_min: Option<&'a str>,
+    updated_at_max: Option<&'a str>,
+    published_at_min: Option<&'a str>,
+    published_at_max: Option<&'a str>,
+    published_status: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> ProductsDeprecated202007GetSmartCollectionRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `ids` query parameter.
+    pub fn ids(mut self, ids: &'a str) -> Self {
+        self.ids = Some(ids);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `title` query parameter.
+    pub fn title(mut self, title: &'a str) -> Self {
+        self.title = Some(title);
+        self
+    }
+
+    /// Sets the `product_id` query parameter.
+    pub fn product_id(mut self, product_id: &'a str) -> Self {
+        self.product_id = Some(product_id);
+        self
+    }
+
+    /// Sets the `handle` query parameter.
+    pub fn handle(mut self, handle: &'a str) -> Self {
+        self.handle = Some(handle);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.updated_at_min = Some(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.updated_at_max = Some(updated_at_max);
+        self
+    }
+
+    /// Sets the `published_at_min` query parameter.
+    pub fn published_at_min(mut self, published_at_min: &'a str) -> Self {
+        self.published_at_min = Some(published_at_min);
+        self
+    }
+
+    /// Sets the `published_at_max` query parameter.
+    pub fn published_at_max(mut self, published_at_max: &'a str) -> Self {
+        self.published_at_max = Some(published_at_max);
+        self
+    }
+
+    /// Sets the `published_status` query parameter.
+    pub fn published_status(mut self, published_status: &'a str) -> Self {
+        self.published_status = Some(published_status);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            limit,
+            ids,
+            since_id,
+            title,
+            product_id,
+            handle,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            published_status,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = handle {
+            query_args.push(("handle".to_string(), v.to_string()));
+        }
+        if let Some(v) = ids {
+            query_args.push(("ids".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = product_id {
+            query_args.push(("product_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_at_max {
+            query_args.push(("published_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_at_min {
+            query_args.push(("published_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_status {
+            query_args.push(("published_status".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = title {
+            query_args.push(("title".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_max {
+            query_args.push(("updated_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_min {
+            query_args.push(("updated_at_min".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-07/smart_collections.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Products::deprecated_202007_get_smart_collections_count`], set with a builder.
+pub struct ProductsDeprecated202007GetSmartCollectionsCountRequest<'a> {
+    client: &'a Products,
+    title: Option<&'a str>,
+    product_id: Option<&'a str>,
+    updated_at_min: Option<&'a str>,
+    updated_at_max: Option<&'a str>,
+    published_at_min: Option<&'a str>,
+    published_at_max: Option<&'a str>,
+    published_status: Option<&'a str>,
+}
+
+impl<'a> ProductsDeprecated202007GetSmartCollectionsCountRequest<'a> {
+    /// Sets the `title` query parameter.
+    pub fn title(mut self, title: &'a str) -> Self {
+        self.title = Some(title);
+        self
+    }
+
+    /// Sets the `product_id` query parameter.
+    pub fn product_id(mut self, product_id: &'a str) -> Self {
+        self.product_id = Some(product_id);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.updated_at_min = Some(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.updated_at_max = Some(updated_at_max);
+        self
+    }
+
+    /// Sets the `published_at_min` query parameter.
+    pub fn published_at_min(mut self, published_at_min: &'a str) -> Self {
+        self.published_at_min = Some(published_at_min);
+        self
+    }
+
+    /// Sets the `published_at_max` query parameter.
+    pub fn published_at_max(mut self, published_at_max: &'a str) -> Self {
+        self.published_at_max = Some(published_at_max);
+        self
+    }
+
+    /// Sets the `published_status` query parameter.
+    pub fn published_status(mut self, published_status: &'a str) -> Self {
+        self.published_status = Some(published_status);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            title,
+            product_id,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            published_status,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = product_id {
+            query_args.push(("product_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_at_max {
+            query_args.push(("published_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_at_min {
+            query_args.push(("published_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_status {
+            query_args.push(("published_status".to_string(), v.to_string()));
+        }
+        if let Some(v) = title {
+            query_args.push(("title".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_max {
+            query_args.push(("updated_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_min {
+            query_args.push(("updated_at_min".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-07/smart_collections/count.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Products::deprecated_202007_get_smart_collections_param_collection`], set with a builder.
+pub struct ProductsDeprecated202007GetSmartCollectionsParamCollectionRequest<'a> {
+    client: &'a Products,
+    smart_collection_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> ProductsDeprecated202007GetSmartCollectionsParamCollectionRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            smart_collection_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-07/smart_collections/{}/json?{}",
+                crate::progenitor_support::encode_path(smart_collection_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Products::deprecated_202007_update_smart_collections_param_collection_order`], set with a builder.
+pub struct ProductsDeprecated202007UpdateSmartCollectionsParamCollectionOrderRequest<'a> {
+    client: &'a Products,
+    smart_collection_id: &'a str,
+    products: Option<&'a str>,
+    sort_order: Option<&'a str>,
+    body: &'a serde_json::Value,
+}
+
+impl<'a> ProductsDeprecated202007UpdateSmartCollectionsParamCollectionOrderRequest<'a> {
+    /// Sets the `products` query parameter.
+    pub fn products(mut self, products: &'a str) -> Self {
+        self.products = Some(products);
+        self
+    }
+
+    /// Sets the `sort_order` query parameter.
+    pub fn sort_order(mut self, sort_order: &'a str) -> Self {
+        self.sort_order = Some(sort_order);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            smart_collection_id,
+            products,
+            sort_order,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = products {
+            query_args.push(("products".to_string(), v.to_string()));
+        }
+        if let Some(v) = sort_order {
+            query_args.push(("sort_order".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-07/smart_collections/{}/order.json?{}",
+                crate::progenitor_support::encode_path(smart_collection_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .put(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Products::get_smart_collection`], set with a builder.
+pub struct ProductsGetSmartCollectionRequest<'a> {
+    client: &'a Products,
+    limit: Option<&'a str>,
+    ids: Option<&'a str>,
+    since_id: Option<&'a str>,
+    title: Option<&'a str>,
+    product_id: Option<&'a str>,
+    handle: Option<&'a str>,
+    updated_at_min: Option<&'a str>,
+    updated_at_max: Option<&'a str>,
+    published_at_min: Option<&'a str>,
+    published_at_max: Option<&'a str>,
+    published_status: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> ProductsGetSmartCollectionRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `ids` query parameter.
+    pub fn ids(mut self, ids: &'a str) -> Self {
+        self.ids = Some(ids);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `title` query parameter.
+    pub fn title(mut self, title: &'a str) -> Self {
+        self.title = Some(title);
+        self
+    }
+
+    /// Sets the `product_id` query parameter.
+    pub fn product_id(mut self, product_id: &'a str) -> Self {
+        self.product_id = Some(product_id);
+        self
+    }
+
+    /// Sets the `handle` query parameter.
+    pub fn handle(mut self, handle: &'a str) -> Self {
+        self.handle = Some(handle);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.updated_at_min = Some(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.updated_at_max = Some(updated_at_max);
+        self
+    }
+
+    /// Sets the `published_at_min` query parameter.
+    pub fn published_at_min(mut self, published_at_min: &'a str) -> Self {
+        self.published_at_min = Some(published_at_min);
+        self
+    }
+
+    /// Sets the `published_at_max` query parameter.
+    pub fn published_at_max(mut self, published_at_max: &'a str) -> Self {
+        self.published_at_max = Some(published_at_max);
+        self
+    }
+
+    /// Sets the `published_status` query parameter.
+    pub fn published_status(mut self, published_status: &'a str) -> Self {
+        self.published_status = Some(published_status);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            limit,
+            ids,
+            since_id,
+            title,
+            product_id,
+            handle,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            published_status,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = handle {
+            query_args.push(("handle".to_string(), v.to_string()));
+        }
+        if let Some(v) = ids {
+            query_args.push(("ids".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = product_id {
+            query_args.push(("product_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_at_max {
+            query_args.push(("published_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_at_min {
+            query_args.push(("published_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_status {
+            query_args.push(("published_status".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = title {
+            query_args.push(("title".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_max {
+            query_args.push(("updated_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_min {
+            query_args.push(("updated_at_min".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-10/smart_collections.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Products::get_smart_collections_count`], set with a builder.
+pub struct ProductsGetSmartCollectionsCountRequest<'a> {
+    client: &'a Products,
+    title: Option<&'a str>,
+    product_id: Option<&'a str>,
+    updated_at_min: Option<&'a str>,
+    updated_at_max: Option<&'a str>,
+    published_at_min: Option<&'a str>,
+    published_at_max: Option<&'a str>,
+    published_status: Option<&'a str>,
+}
+
+impl<'a> ProductsGetSmartCollectionsCountRequest<'a> {
+    /// Sets the `title` query parameter.
+    pub fn title(mut self, title: &'a str) -> Self {
+        self.title = Some(title);
+        self
+    }
+
+    /// Sets the `product_id` query parameter.
+    pub fn product_id(mut self, product_id: &'a str) -> Self {
+        self.product_id = Some(product_id);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.updated_at_min = Some(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.updated_at_max = Some(updated_at_max);
+        self
+    }
+
+    /// Sets the `published_at_min` query parameter.
+    pub fn published_at_min(mut self, published_at_min: &'a str) -> Self {
+        self.published_at_min = Some(published_at_min);
+        self
+    }
+
+    /// Sets the `published_at_max` query parameter.
+    pub fn published_at_max(mut self, published_at_max: &'a str) -> Self {
+        self.published_at_max = Some(published_at_max);
+        self
+    }
+
+    /// Sets the `published_status` query parameter.
+    pub fn published_status(mut self, published_status: &'a str) -> Self {
+        self.published_status = Some(published_status);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            title,
+            product_id,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            published_status,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = product_id {
+            query_args.push(("product_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_at_max {
+            query_args.push(("published_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_at_min {
+            query_args.push(("published_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_status {
+            query_args.push(("published_status".to_string(), v.to_string()));
+        }
+        if let Some(v) = title {
+            query_args.push(("title".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_max {
+            query_args.push(("updated_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_min {
+            query_args.push(("updated_at_min".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-10/smart_collections/count.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Products::get_smart_collections_param_collection`], set with a builder.
+pub struct ProductsGetSmartCollectionsParamCollectionRequest<'a> {
+    client: &'a Products,
+    smart_collection_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> ProductsGetSmartCollectionsParamCollectionRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            smart_collection_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-10/smart_collections/{}/json?{}",
+                crate::progenitor_support::encode_path(smart_collection_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Products::update_smart_collections_param_collection_order`], set with a builder.
+pub struct ProductsUpdateSmartCollectionsParamCollectionOrderRequest<'a> {
+    client: &'a Products,
+    smart_collection_id: &'a str,
+    products: Option<&'a str>,
+    sort_order: Option<&'a str>,
+    body: &'a serde_json::Value,
+}
+
+impl<'a> ProductsUpdateSmartCollectionsParamCollectionOrderRequest<'a> {
+    /// Sets the `products` query parameter.
+    pub fn products(mut self, products: &'a str) -> Self {
+        self.products = Some(products);
+        self
+    }
+
+    /// Sets the `sort_order` query parameter.
+    pub fn sort_order(mut self, sort_order: &'a str) -> Self {
+        self.sort_order = Some(sort_order);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            smart_collection_id,
+            products,
+            sort_order,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = products {
+            query_args.push(("products".to_string(), v.to_string()));
+        }
+        if let Some(v) = sort_order {
+            query_args.push(("sort_order".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-10/smart_collections/{}/order.json?{}",
+                crate::progenitor_support::encode_path(smart_collection_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .put(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Products::deprecated_202101_get_smart_collection`], set with a builder.
+pub struct ProductsDeprecated202101GetSmartCollectionRequest<'a> {
+    client: &'a Products,
+    limit: Option<&'a str>,
+    ids: Option<&'a str>,
+    since_id: Option<&'a str>,
+    title: Option<&'a str>,
+    product_id: Option<&'a str>,
+    handle: Option<&'a str>,
+    updated_at_min: Option<&'a str>,
+    updated_at_max: Option<&'a str>,
+    published_at_min: Option<&'a str>,
+    published_at_max: Option<&'a str>,
+    published_status: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> ProductsDeprecated202101GetSmartCollectionRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `ids` query parameter.
+    pub fn ids(mut self, ids: &'a str) -> Self {
+        self.ids = Some(ids);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `title` query parameter.
+    pub fn title(mut self, title: &'a str) -> Self {
+        self.title = Some(title);
+        self
+    }
+
+    /// Sets the `product_id` query parameter.
+    pub fn product_id(mut self, product_id: &'a str) -> Self {
+        self.product_id = Some(product_id);
+        self
+    }
+
+    /// Sets the `handle` query parameter.
+    pub fn handle(mut self, handle: &'a str) -> Self {
+        self.handle = Some(handle);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.updated_at_min = Some(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.updated_at_max = Some(updated_at_max);
+        self
+    }
+
+    /// Sets the `published_at_min` query parameter.
+    pub fn published_at_min(mut self, published_at_min: &'a str) -> Self {
+        self.published_at_min = Some(published_at_min);
+        self
+    }
+
+    /// Sets the `published_at_max` query parameter.
+    pub fn published_at_max(mut self, published_at_max: &'a str) -> Self {
+        self.published_at_max = Some(published_at_max);
+        self
+    }
+
+    /// Sets the `published_status` query parameter.
+    pub fn published_status(mut self, published_status: &'a str) -> Self {
+        self.published_status = Some(published_status);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            limit,
+            ids,
+            since_id,
+            title,
+            product_id,
+            handle,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            published_status,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = handle {
+            query_args.push(("handle".to_string(), v.to_string()));
+        }
+        if let Some(v) = ids {
+            query_args.push(("ids".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = product_id {
+            query_args.push(("product_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_at_max {
+            query_args.push(("published_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_at_min {
+            query_args.push(("published_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_status {
+            query_args.push(("published_status".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = title {
+            query_args.push(("title".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_max {
+            query_args.push(("updated_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_min {
+            query_args.push(("updated_at_min".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2021-01/smart_collections.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Products::deprecated_202101_get_smart_collections_count`], set with a builder.
+pub struct ProductsDeprecated202101GetSmartCollectionsCountRequest<'a> {
+    client: &'a Products,
+    title: Option<&'a str>,
+    product_id: Option<&'a str>,
+    updated_at_min: Option<&'a str>,
+    updated_at_max: Option<&'a str>,
+    published_at_min: Option<&'a str>,
+    published_at_max: Option<&'a str>,
+    published_status: Option<&'a str>,
+}
+
+impl<'a> ProductsDeprecated202101GetSmartCollectionsCountRequest<'a> {
+    /// Sets the `title` query parameter.
+    pub fn title(mut self, title: &'a str) -> Self {
+        self.title = Some(title);
+        self
+    }
+
+    /// Sets the `product_id` query parameter.
+    pub fn product_id(mut self, product_id: &'a str) -> Self {
+        self.product_id = Some(product_id);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.updated_at_min = Some(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.updated_at_max = Some(updated_at_max);
+        self
+    }
+
+    /// Sets the `published_at_min` query parameter.
+    pub fn published_at_min(mut self, published_at_min: &'a str) -> Self {
+        self.published_at_min = Some(published_at_min);
+        self
+    }
+
+    /// Sets the `published_at_max` query parameter.
+    pub fn published_at_max(mut self, published_at_max: &'a str) -> Self {
+        self.published_at_max = Some(published_at_max);
+        self
+    }
+
+    /// Sets the `published_status` query parameter.
+    pub fn published_status(mut self, published_status: &'a str) -> Self {
+        self.published_status = Some(published_status);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            title,
+            product_id,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            published_status,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = product_id {
+            query_args.push(("product_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_at_max {
+            query_args.push(("published_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_at_min {
+            query_args.push(("published_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_status {
+            query_args.push(("published_status".to_string(), v.to_string()));
+        }
+        if let Some(v) = title {
+            query_args.push(("title".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_max {
+            query_args.push(("updated_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_min {
+            query_args.push(("updated_at_min".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2021-01/smart_collections/count.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Products::deprecated_202101_get_smart_collections_param_collection`], set with a builder.
+pub struct ProductsDeprecated202101GetSmartCollectionsParamCollectionRequest<'a> {
+    client: &'a Products,
+    smart_collection_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> ProductsDeprecated202101GetSmartCollectionsParamCollectionRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            smart_collection_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2021-01/smart_collections/{}/json?{}",
+                crate::progenitor_support::encode_path(smart_collection_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Products::deprecated_202101_update_smart_collections_param_collection_order`], set with a builder.
+pub struct ProductsDeprecated202101UpdateSmartCollectionsParamCollectionOrderRequest<'a> {
+    client: &'a Products,
+    smart_collection_id: &'a str,
+    products: Option<&'a str>,
+    sort_order: Option<&'a str>,
+    body: &'a serde_json::Value,
+}
+
+impl<'a> ProductsDeprecated202101UpdateSmartCollectionsParamCollectionOrderRequest<'a> {
+    /// Sets the `products` query parameter.
+    pub fn products(mut self, products: &'a str) -> Self {
+        self.products = Some(products);
+        self
+    }
+
+    /// Sets the `sort_order` query parameter.
+    pub fn sort_order(mut self, sort_order: &'a str) -> Self {
+        self.sort_order = Some(sort_order);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            smart_collection_id,
+            products,
+            sort_order,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = products {
+            query_args.push(("products".to_string(), v.to_string()));
+        }
+        if let Some(v) = sort_order {
+            query_args.push(("sort_order".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2021-01/smart_collections/{}/order.json?{}",
+                crate::progenitor_support::encode_path(smart_collection_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .put(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Products::deprecated_unstable_get_smart_collection`], set with a builder.
+pub struct ProductsDeprecatedUnstableGetSmartCollectionRequest<'a> {
+    client: &'a Products,
+    limit: Option<&'a str>,
+    ids: Option<&'a str>,
+    since_id: Option<&'a str>,
+    title: Option<&'a str>,
+    product_id: Option<&'a str>,
+    handle: Option<&'a str>,
+    updated_at_min: Option<&'a str>,
+    updated_at_max: Option<&'a str>,
+    published_at_min: Option<&'a str>,
+    published_at_max: Option<&'a str>,
+    published_status: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> ProductsDeprecatedUnstableGetSmartCollectionRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `ids` query parameter.
+    pub fn ids(mut self, ids: &'a str) -> Self {
+        self.ids = Some(ids);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `title` query parameter.
+    pub fn title(mut self, title: &'a str) -> Self {
+        self.title = Some(title);
+        self
+    }
+
+    /// Sets the `product_id` query parameter.
+    pub fn product_id(mut self, product_id: &'a str) -> Self {
+        self.product_id = Some(product_id);
+        self
+    }
+
+    /// Sets the `handle` query parameter.
+    pub fn handle(mut self, handle: &'a str) -> Self {
+        self.handle = Some(handle);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.updated_at_min = Some(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.updated_at_max = Some(updated_at_max);
+        self
+    }
+
+    /// Sets the `published_at_min` query parameter.
+    pub fn published_at_min(mut self, published_at_min: &'a str) -> Self {
+        self.published_at_min = Some(published_at_min);
+        self
+    }
+
+    /// Sets the `published_at_max` query parameter.
+    pub fn published_at_max(mut self, published_at_max: &'a str) -> Self {
+        self.published_at_max = Some(published_at_max);
+        self
+    }
+
+    /// Sets the `published_status` query parameter.
+    pub fn published_status(mut self, published_status: &'a str) -> Self {
+        self.published_status = Some(published_status);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            limit,
+            ids,
+            since_id,
+            title,
+            product_id,
+            handle,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            published_status,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = handle {
+            query_args.push(("handle".to_string(), v.to_string()));
+        }
+        if let Some(v) = ids {
+            query_args.push(("ids".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = product_id {
+            query_args.push(("product_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_at_max {
+            query_args.push(("published_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_at_min {
+            query_args.push(("published_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_status {
+            query_args.push(("published_status".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = title {
+            query_args.push(("title".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_max {
+            query_args.push(("updated_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_min {
+            query_args.push(("updated_at_min".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/unstable/smart_collections.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Products::deprecated_unstable_get_smart_collections_count`], set with a builder.
+pub struct ProductsDeprecatedUnstableGetSmartCollectionsCountRequest<'a> {
+    client: &'a Products,
+    title: Option<&'a str>,
+    product_id: Option<&'a str>,
+    updated_at_min: Option<&'a str>,
+    updated_at_max: Option<&'a str>,
+    published_at_min: Option<&'a str>,
+    published_at_max: Option<&'a str>,
+    published_status: Option<&'a str>,
+}
+
+impl<'a> ProductsDeprecatedUnstableGetSmartCollectionsCountRequest<'a> {
+    /// Sets the `title` query parameter.
+    pub fn title(mut self, title: &'a str) -> Self {
+        self.title = Some(title);
+        self
+    }
+
+    /// Sets the `product_id` query parameter.
+    pub fn product_id(mut self, product_id: &'a str) -> Self {
+        self.product_id = Some(product_id);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.updated_at_min = Some(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.updated_at_max = Some(updated_at_max);
+        self
+    }
+
+    /// Sets the `published_at_min` query parameter.
+    pub fn published_at_min(mut self, published_at_min: &'a str) -> Self {
+        self.published_at_min = Some(published_at_min);
+        self
+    }
+
+    /// Sets the `published_at_max` query parameter.
+    pub fn published_at_max(mut self, published_at_max: &'a str) -> Self {
+        self.published_at_max = Some(published_at_max);
+        self
+    }
+
+    /// Sets the `published_status` query parameter.
+    pub fn published_status(mut self, published_status: &'a str) -> Self {
+        self.published_status = Some(published_status);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            title,
+            product_id,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            published_status,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = product_id {
+            query_args.push(("product_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_at_max {
+            query_args.push(("published_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_at_min {
+            query_args.push(("published_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = published_status {
+            query_args.push(("published_status".to_string(), v.to_string()));
+        }
+        if let Some(v) = title {
+            query_args.push(("title".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_max {
+            query_args.push(("updated_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_min {
+            query_args.push(("updated_at_min".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/unstable/smart_collections/count.json?{}",
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Products::deprecated_unstable_get_smart_collections_param_collection`], set with a builder.
+pub struct ProductsDeprecatedUnstableGetSmartCollectionsParamCollectionRequest<'a> {
+    client: &'a Products,
+    smart_collection_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> ProductsDeprecatedUnstableGetSmartCollectionsParamCollectionRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            smart_collection_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/unstable/smart_collections/{}/json?{}",
+                crate::progenitor_support::encode_path(smart_collection_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Products::deprecated_unstable_update_smart_collections_param_collection_order`], set with a builder.
+pub struct ProductsDeprecatedUnstableUpdateSmartCollectionsParamCollectionOrderRequest<'a> {
+    client: &'a Products,
+    smart_collection_id: &'a str,
+    products: Option<&'a str>,
+    sort_order: Option<&'a str>,
+    body: &'a serde_json::Value,
+}
+
+impl<'a> ProductsDeprecatedUnstableUpdateSmartCollectionsParamCollectionOrderRequest<'a> {
+    /// Sets the `products` query parameter.
+    pub fn products(mut self, products: &'a str) -> Self {
+        self.products = Some(products);
+        self
+    }
+
+    /// Sets the `sort_order` query parameter.
+    pub fn sort_order(mut self, sort_order: &'a str) -> Self {
+        self.sort_order = Some(sort_order);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            smart_collection_id,
+            products,
+            sort_order,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = products {
+            query_args.push(("products".to_string(), v.to_string()));
+        }
+        if let Some(v) = sort_order {
+            query_args.push(("sort_order".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/unstable/smart_collections/{}/order.json?{}",
+                crate::progenitor_support::encode_path(smart_collection_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .put(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
 pub struct Products {
     pub client: Client,
 }
@@ -56,6 +9227,25 @@ impl Products {
             )
             .await
     }
+    /**
+     * Retrieves a list of collects. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-01/collects.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_get_collect`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/collect#index-2020-01
+     */
+    pub fn deprecated_202001_get_collect_request(
+        &self,
+    ) -> ProductsDeprecated202001GetCollectRequest<'_> {
+        ProductsDeprecated202001GetCollectRequest {
+            client: self,
+            limit: None,
+            since_id: None,
+            fields: None,
+        }
+    }
     /**
      * Adds a product to a custom collection.
      *
@@ -118,6 +9308,25 @@ impl Products {
             )
             .await
     }
+    /**
+     * Retrieves a specific collect by its ID.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-01/collects/{collect_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_get_collects_param_collect`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/collect#show-2020-01
+     */
+    pub fn deprecated_202001_get_collects_param_collect_request<'a>(
+        &'a self,
+        collect_id: &'a str,
+    ) -> ProductsDeprecated202001GetCollectsParamCollectRequest<'a> {
+        ProductsDeprecated202001GetCollectsParamCollectRequest {
+            client: self,
+            collect_id,
+            fields: None,
+        }
+    }
     /**
      * Removes a product from a collection.
      *
@@ -184,6 +9393,23 @@ impl Products {
             )
             .await
     }
+    /**
+     * Retrieves a count of collects.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-01/collects/count.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_get_collects_count`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/collect#count-2020-01
+     */
+    pub fn deprecated_202001_get_collects_count_request(
+        &self,
+    ) -> ProductsDeprecated202001GetCollectsCountRequest<'_> {
+        ProductsDeprecated202001GetCollectsCountRequest {
+            client: self,
+            collection_id: None,
+        }
+    }
     /**
      * Retrieves a list of collects. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -229,6 +9455,25 @@ impl Products {
             )
             .await
     }
+    /**
+     * Retrieves a list of collects. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-04/collects.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_get_collect`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/collect#index-2020-04
+     */
+    pub fn deprecated_202004_get_collect_request(
+        &self,
+    ) -> ProductsDeprecated202004GetCollectRequest<'_> {
+        ProductsDeprecated202004GetCollectRequest {
+            client: self,
+            limit: None,
+            since_id: None,
+            fields: None,
+        }
+    }
     /**
      * Adds a product to a custom collection.
      *
@@ -291,6 +9536,25 @@ impl Products {
             )
             .await
     }
+    /**
+     * Retrieves a specific collect by its ID.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-04/collects/{collect_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_get_collects_param_collect`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/collect#show-2020-04
+     */
+    pub fn deprecated_202004_get_collects_param_collect_request<'a>(
+        &'a self,
+        collect_id: &'a str,
+    ) -> ProductsDeprecated202004GetCollectsParamCollectRequest<'a> {
+        ProductsDeprecated202004GetCollectsParamCollectRequest {
+            client: self,
+            collect_id,
+            fields: None,
+        }
+    }
     /**
      * Removes a product from a collection.
      *
@@ -357,6 +9621,23 @@ impl Products {
             )
             .await
     }
+    /**
+     * Retrieves a count of collects.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-04/collects/count.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_get_collects_count`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/collect#count-2020-04
+     */
+    pub fn deprecated_202004_get_collects_count_request(
+        &self,
+    ) -> ProductsDeprecated202004GetCollectsCountRequest<'_> {
+        ProductsDeprecated202004GetCollectsCountRequest {
+            client: self,
+            collection_id: None,
+        }
+    }
     /**
      * Retrieves a list of collects. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -402,6 +9683,25 @@ impl Products {
             )
             .await
     }
+    /**
+     * Retrieves a list of collects. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-07/collects.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_get_collect`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/collect#index-2020-07
+     */
+    pub fn deprecated_202007_get_collect_request(
+        &self,
+    ) -> ProductsDeprecated202007GetCollectRequest<'_> {
+        ProductsDeprecated202007GetCollectRequest {
+            client: self,
+            limit: None,
+            since_id: None,
+            fields: None,
+        }
+    }
     /**
      * Adds a product to a custom collection.
      *
@@ -464,6 +9764,25 @@ impl Products {
             )
             .await
     }
+    /**
+     * Retrieves a specific collect by its ID.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-07/collects/{collect_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_get_collects_param_collect`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/collect#show-2020-07
+     */
+    pub fn deprecated_202007_get_collects_param_collect_request<'a>(
+        &'a self,
+        collect_id: &'a str,
+    ) -> ProductsDeprecated202007GetCollectsParamCollectRequest<'a> {
+        ProductsDeprecated202007GetCollectsParamCollectRequest {
+            client: self,
+            collect_id,
+            fields: None,
+        }
+    }
     /**
      * Removes a product from a collection.
      *
@@ -530,6 +9849,23 @@ impl Products {
             )
             .await
     }
+    /**
+     * Retrieves a count of collects.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-07/collects/count.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_get_collects_count`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/collect#count-2020-07
+     */
+    pub fn deprecated_202007_get_collects_count_request(
+        &self,
+    ) -> ProductsDeprecated202007GetCollectsCountRequest<'_> {
+        ProductsDeprecated202007GetCollectsCountRequest {
+            client: self,
+            collection_id: None,
+        }
+    }
     /**
      * Retrieves a list of collects. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -575,6 +9911,23 @@ impl Products {
             )
             .await
     }
+    /**
+     * Retrieves a list of collects. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-10/collects.json` endpoint.
+     *
+     * As opposed to `get_collect`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/collect#index-2020-10
+     */
+    pub fn get_collect_request(&self) -> ProductsGetCollectRequest<'_> {
+        ProductsGetCollectRequest {
+            client: self,
+            limit: None,
+            since_id: None,
+            fields: None,
+        }
+    }
     /**
      * Adds a product to a custom collection.
      *
@@ -637,6 +9990,25 @@ impl Products {
             )
             .await
     }
+    /**
+     * Retrieves a specific collect by its ID.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-10/collects/{collect_id}.json` endpoint.
+     *
+     * As opposed to `get_collects_param_collect`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/collect#show-2020-10
+     */
+    pub fn get_collects_param_collect_request<'a>(
+        &'a self,
+        collect_id: &'a str,
+    ) -> ProductsGetCollectsParamCollectRequest<'a> {
+        ProductsGetCollectsParamCollectRequest {
+            client: self,
+            collect_id,
+            fields: None,
+        }
+    }
     /**
      * Removes a product from a collection.
      *
@@ -703,6 +10075,21 @@ impl Products {
             )
             .await
     }
+    /**
+     * Retrieves a count of collects.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-10/collects/count.json` endpoint.
+     *
+     * As opposed to `get_collects_count`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/collect#count-2020-10
+     */
+    pub fn get_collects_count_request(&self) -> ProductsGetCollectsCountRequest<'_> {
+        ProductsGetCollectsCountRequest {
+            client: self,
+            collection_id: None,
+        }
+    }
     /**
      * Retrieves a list of collects. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -748,6 +10135,25 @@ impl Products {
             )
             .await
     }
+    /**
+     * Retrieves a list of collects. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2021-01/collects.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_get_collect`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/collect#index-2021-01
+     */
+    pub fn deprecated_202101_get_collect_request(
+        &self,
+    ) -> ProductsDeprecated202101GetCollectRequest<'_> {
+        ProductsDeprecated202101GetCollectRequest {
+            client: self,
+            limit: None,
+            since_id: None,
+            fields: None,
+        }
+    }
     /**
      * Adds a product to a custom collection.
      *
@@ -810,6 +10216,25 @@ impl Products {
             )
             .await
     }
+    /**
+     * Retrieves a specific collect by its ID.
+     *
+     * This function performs a `GET` to the `/admin/api/2021-01/collects/{collect_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_get_collects_param_collect`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/collect#show-2021-01
+     */
+    pub fn deprecated_202101_get_collects_param_collect_request<'a>(
+        &'a self,
+        collect_id: &'a str,
+    ) -> ProductsDeprecated202101GetCollectsParamCollectRequest<'a> {
+        ProductsDeprecated202101GetCollectsParamCollectRequest {
+            client: self,
+            collect_id,
+            fields: None,
+        }
+    }
     /**
      * Removes a product from a collection.
      *
@@ -876,6 +10301,23 @@ impl Products {
             )
             .await
     }
+    /**
+     * Retrieves a count of collects.
+     *
+     * This function performs a `GET` to the `/admin/api/2021-01/collects/count.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_get_collects_count`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/collect#count-2021-01
+     */
+    pub fn deprecated_202101_get_collects_count_request(
+        &self,
+    ) -> ProductsDeprecated202101GetCollectsCountRequest<'_> {
+        ProductsDeprecated202101GetCollectsCountRequest {
+            client: self,
+            collection_id: None,
+        }
+    }
     /**
      * Retrieves a list of collects. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -921,6 +10363,25 @@ impl Products {
             )
             .await
     }
+    /**
+     * Retrieves a list of collects. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/unstable/collects.json` endpoint.
+     *
+     * As opposed to `deprecated_unstable_get_collect`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/collect#index-unstable
+     */
+    pub fn deprecated_unstable_get_collect_request(
+        &self,
+    ) -> ProductsDeprecatedUnstableGetCollectRequest<'_> {
+        ProductsDeprecatedUnstableGetCollectRequest {
+            client: self,
+            limit: None,
+            since_id: None,
+            fields: None,
+        }
+    }
     /**
      * Adds a product to a custom collection.
      *
@@ -983,6 +10444,25 @@ impl Products {
             )
             .await
     }
+    /**
+     * Retrieves a specific collect by its ID.
+     *
+     * This function performs a `GET` to the `/admin/api/unstable/collects/{collect_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_unstable_get_collects_param_collect`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/collect#show-unstable
+     */
+    pub fn deprecated_unstable_get_collects_param_collect_request<'a>(
+        &'a self,
+        collect_id: &'a str,
+    ) -> ProductsDeprecatedUnstableGetCollectsParamCollectRequest<'a> {
+        ProductsDeprecatedUnstableGetCollectsParamCollectRequest {
+            client: self,
+            collect_id,
+            fields: None,
+        }
+    }
     /**
      * Removes a product from a collection.
      *
@@ -1049,6 +10529,23 @@ impl Products {
             )
             .await
     }
+    /**
+     * Retrieves a count of collects.
+     *
+     * This function performs a `GET` to the `/admin/api/unstable/collects/count.json` endpoint.
+     *
+     * As opposed to `deprecated_unstable_get_collects_count`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/collect#count-unstable
+     */
+    pub fn deprecated_unstable_get_collects_count_request(
+        &self,
+    ) -> ProductsDeprecatedUnstableGetCollectsCountRequest<'_> {
+        ProductsDeprecatedUnstableGetCollectsCountRequest {
+            client: self,
+            collection_id: None,
+        }
+    }
     /**
      * Retrieves a single collection.
      *
@@ -1089,6 +10586,25 @@ impl Products {
             )
             .await
     }
+    /**
+     * Retrieves a single collection.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-01/collections/{collection_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_get_collections_param_collection`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/collection#show-2020-01
+     */
+    pub fn deprecated_202001_get_collections_param_collection_request<'a>(
+        &'a self,
+        collection_id: &'a str,
+    ) -> ProductsDeprecated202001GetCollectionsParamCollectionRequest<'a> {
+        ProductsDeprecated202001GetCollectionsParamCollectionRequest {
+            client: self,
+            collection_id,
+            fields: None,
+        }
+    }
     /**
      * Retrieve a list of products belonging to a collection. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.. The products returned are sorted by the collection's sort order.
      *
@@ -1130,6 +10646,25 @@ impl Products {
             )
             .await
     }
+    /**
+     * Retrieve a list of products belonging to a collection. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.. The products returned are sorted by the collection's sort order.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-01/collections/{collection_id}/products.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_get_collections_param_collection_products`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/collection#products-2020-01
+     */
+    pub fn deprecated_202001_get_collections_param_collection_products_request<'a>(
+        &'a self,
+        collection_id: &'a str,
+    ) -> ProductsDeprecated202001GetCollectionsParamCollectionProductsRequest<'a> {
+        ProductsDeprecated202001GetCollectionsParamCollectionProductsRequest {
+            client: self,
+            collection_id,
+            limit: None,
+        }
+    }
     /**
      * Retrieves a single collection.
      *
@@ -1170,6 +10705,25 @@ impl Products {
             )
             .await
     }
+    /**
+     * Retrieves a single collection.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-04/collections/{collection_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_get_collections_param_collection`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/collection#show-2020-04
+     */
+    pub fn deprecated_202004_get_collections_param_collection_request<'a>(
+        &'a self,
+        collection_id: &'a str,
+    ) -> ProductsDeprecated202004GetCollectionsParamCollectionRequest<'a> {
+        ProductsDeprecated202004GetCollectionsParamCollectionRequest {
+            client: self,
+            collection_id,
+            fields: None,
+        }
+    }
     /**
      * Retrieve a list of products belonging to a collection. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.. The products returned are sorted by the collection's sort order.
      *
@@ -1211,6 +10765,25 @@ impl Products {
             )
             .await
     }
+    /**
+     * Retrieve a list of products belonging to a collection. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.. The products returned are sorted by the collection's sort order.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-04/collections/{collection_id}/products.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_get_collections_param_collection_products`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/collection#products-2020-04
+     */
+    pub fn deprecated_202004_get_collections_param_collection_products_request<'a>(
+        &'a self,
+        collection_id: &'a str,
+    ) -> ProductsDeprecated202004GetCollectionsParamCollectionProductsRequest<'a> {
+        ProductsDeprecated202004GetCollectionsParamCollectionProductsRequest {
+            client: self,
+            collection_id,
+            limit: None,
+        }
+    }
     /**
      * Retrieves a single collection.
      *
@@ -1251,6 +10824,25 @@ impl Products {
             )
             .await
     }
+    /**
+     * Retrieves a single collection.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-07/collections/{collection_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_get_collections_param_collection`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/collection#show-2020-07
+     */
+    pub fn deprecated_202007_get_collections_param_collection_request<'a>(
+        &'a self,
+        collection_id: &'a str,
+    ) -> ProductsDeprecated202007GetCollectionsParamCollectionRequest<'a> {
+        ProductsDeprecated202007GetCollectionsParamCollectionRequest {
+            client: self,
+            collection_id,
+            fields: None,
+        }
+    }
     /**
      * Retrieve a list of products belonging to a collection. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.. The products returned are sorted by the collection's sort order.
      *
@@ -1292,6 +10884,25 @@ impl Products {
             )
             .await
     }
+    /**
+     * Retrieve a list of products belonging to a collection. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.. The products returned are sorted by the collection's sort order.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-07/collections/{collection_id}/products.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_get_collections_param_collection_products`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/collection#products-2020-07
+     */
+    pub fn deprecated_202007_get_collections_param_collection_products_request<'a>(
+        &'a self,
+        collection_id: &'a str,
+    ) -> ProductsDeprecated202007GetCollectionsParamCollectionProductsRequest<'a> {
+        ProductsDeprecated202007GetCollectionsParamCollectionProductsRequest {
+            client: self,
+            collection_id,
+            limit: None,
+        }
+    }
     /**
      * Retrieves a single collection.
      *
@@ -1332,6 +10943,25 @@ impl Products {
             )
             .await
     }
+    /**
+     * Retrieves a single collection.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-10/collections/{collection_id}.json` endpoint.
+     *
+     * As opposed to `get_collections_param_collection`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/collection#show-2020-10
+     */
+    pub fn get_collections_param_collection_request<'a>(
+        &'a self,
+        collection_id: &'a str,
+    ) -> ProductsGetCollectionsParamCollectionRequest<'a> {
+        ProductsGetCollectionsParamCollectionRequest {
+            client: self,
+            collection_id,
+            fields: None,
+        }
+    }
     /**
      * Retrieve a list of products belonging to a collection. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.. The products returned are sorted by the collection's sort order.
      *
@@ -1373,6 +11003,25 @@ impl Products {
             )
             .await
     }
+    /**
+     * Retrieve a list of products belonging to a collection. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.. The products returned are sorted by the collection's sort order.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-10/collections/{collection_id}/products.json` endpoint.
+     *
+     * As opposed to `get_collections_param_collection_products`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/collection#products-2020-10
+     */
+    pub fn get_collections_param_collection_products_request<'a>(
+        &'a self,
+        collection_id: &'a str,
+    ) -> ProductsGetCollectionsParamCollectionProductsRequest<'a> {
+        ProductsGetCollectionsParamCollectionProductsRequest {
+            client: self,
+            collection_id,
+            limit: None,
+        }
+    }
     /**
      * Retrieves a single collection.
      *
@@ -1413,6 +11062,25 @@ impl Products {
             )
             .await
     }
+    /**
+     * Retrieves a single collection.
+     *
+     * This function performs a `GET` to the `/admin/api/2021-01/collections/{collection_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_get_collections_param_collection`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/collection#show-2021-01
+     */
+    pub fn deprecated_202101_get_collections_param_collection_request<'a>(
+        &'a self,
+        collection_id: &'a str,
+    ) -> ProductsDeprecated202101GetCollectionsParamCollectionRequest<'a> {
+        ProductsDeprecated202101GetCollectionsParamCollectionRequest {
+            client: self,
+            collection_id,
+            fields: None,
+        }
+    }
     /**
      * Retrieve a list of products belonging to a collection. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.. The products returned are sorted by the collection's sort order.
      *
@@ -1454,6 +11122,25 @@ impl Products {
             )
             .await
     }
+    /**
+     * Retrieve a list of products belonging to a collection. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.. The products returned are sorted by the collection's sort order.
+     *
+     * This function performs a `GET` to the `/admin/api/2021-01/collections/{collection_id}/products.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_get_collections_param_collection_products`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/collection#products-2021-01
+     */
+    pub fn deprecated_202101_get_collections_param_collection_products_request<'a>(
+        &'a self,
+        collection_id: &'a str,
+    ) -> ProductsDeprecated202101GetCollectionsParamCollectionProductsRequest<'a> {
+        ProductsDeprecated202101GetCollectionsParamCollectionProductsRequest {
+            client: self,
+            collection_id,
+            limit: None,
+        }
+    }
     /**
      * Retrieves a single collection.
      *
@@ -1494,6 +11181,25 @@ impl Products {
             )
             .await
     }
+    /**
+     * Retrieves a single collection.
+     *
+     * This function performs a `GET` to the `/admin/api/unstable/collections/{collection_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_unstable_get_collections_param_collection`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/collection#show-unstable
+     */
+    pub fn deprecated_unstable_get_collections_param_collection_request<'a>(
+        &'a self,
+        collection_id: &'a str,
+    ) -> ProductsDeprecatedUnstableGetCollectionsParamCollectionRequest<'a> {
+        ProductsDeprecatedUnstableGetCollectionsParamCollectionRequest {
+            client: self,
+            collection_id,
+            fields: None,
+        }
+    }
     /**
      * Retrieve a list of products belonging to a collection. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.. The products returned are sorted by the collection's sort order.
      *
@@ -1535,6 +11241,25 @@ impl Products {
             )
             .await
     }
+    /**
+     * Retrieve a list of products belonging to a collection. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.. The products returned are sorted by the collection's sort order.
+     *
+     * This function performs a `GET` to the `/admin/api/unstable/collections/{collection_id}/products.json` endpoint.
+     *
+     * As opposed to `deprecated_unstable_get_collections_param_collection_products`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/collection#products-unstable
+     */
+    pub fn deprecated_unstable_get_collections_param_collection_products_request<'a>(
+        &'a self,
+        collection_id: &'a str,
+    ) -> ProductsDeprecatedUnstableGetCollectionsParamCollectionProductsRequest<'a> {
+        ProductsDeprecatedUnstableGetCollectionsParamCollectionProductsRequest {
+            client: self,
+            collection_id,
+            limit: None,
+        }
+    }
     /**
      * Retrieves a list of custom collections. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -1630,6 +11355,34 @@ impl Products {
             )
             .await
     }
+    /**
+     * Retrieves a list of custom collections. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-01/custom_collections.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_get_custom_collection`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/customcollection#index-2020-01
+     */
+    pub fn deprecated_202001_get_custom_collection_request(
+        &self,
+    ) -> ProductsDeprecated202001GetCustomCollectionRequest<'_> {
+        ProductsDeprecated202001GetCustomCollectionRequest {
+            client: self,
+            limit: None,
+            ids: None,
+            since_id: None,
+            title: None,
+            product_id: None,
+            handle: None,
+            updated_at_min: None,
+            updated_at_max: None,
+            published_at_min: None,
+            published_at_max: None,
+            published_status: None,
+            fields: None,
+        }
+    }
     /**
      * Creates a custom collection.
      *
@@ -1726,6 +11479,29 @@ impl Products {
             )
             .await
     }
+    /**
+     * Retrieves a count of custom collections.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-01/custom_collections/count.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_get_custom_collections_count`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/customcollection#count-2020-01
+     */
+    pub fn deprecated_202001_get_custom_collections_count_request(
+        &self,
+    ) -> ProductsDeprecated202001GetCustomCollectionsCountRequest<'_> {
+        ProductsDeprecated202001GetCustomCollectionsCountRequest {
+            client: self,
+            title: None,
+            product_id: None,
+            updated_at_min: None,
+            updated_at_max: None,
+            published_at_min: None,
+            published_at_max: None,
+            published_status: None,
+        }
+    }
     /**
      * Retrieves a single custom collection.
      *
@@ -1766,6 +11542,25 @@ impl Products {
             )
             .await
     }
+    /**
+     * Retrieves a single custom collection.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-01/custom_collections/{custom_collection_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_get_custom_collections_param_collection`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/customcollection#show-2020-01
+     */
+    pub fn deprecated_202001_get_custom_collections_param_collection_request<'a>(
+        &'a self,
+        custom_collection_id: &'a str,
+    ) -> ProductsDeprecated202001GetCustomCollectionsParamCollectionRequest<'a> {
+        ProductsDeprecated202001GetCustomCollectionsParamCollectionRequest {
+            client: self,
+            custom_collection_id,
+            fields: None,
+        }
+    }
     /**
      * Updates an existing custom collection.
      *
@@ -1926,6 +11721,34 @@ impl Products {
             )
             .await
     }
+    /**
+     * Retrieves a list of custom collections. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-04/custom_collections.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_get_custom_collection`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/customcollection#index-2020-04
+     */
+    pub fn deprecated_202004_get_custom_collection_request(
+        &self,
+    ) -> ProductsDeprecated202004GetCustomCollectionRequest<'_> {
+        ProductsDeprecated202004GetCustomCollectionRequest {
+            client: self,
+            limit: None,
+            ids: None,
+            since_id: None,
+            title: None,
+            product_id: None,
+            handle: None,
+            updated_at_min: None,
+            updated_at_max: None,
+            published_at_min: None,
+            published_at_max: None,
+            published_status: None,
+            fields: None,
+        }
+    }
     /**
      * Creates a custom collection.
      *
@@ -2022,6 +11845,29 @@ impl Products {
             )
             .await
     }
+    /**
+     * Retrieves a count of custom collections.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-04/custom_collections/count.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_get_custom_collections_count`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/customcollection#count-2020-04
+     */
+    pub fn deprecated_202004_get_custom_collections_count_request(
+        &self,
+    ) -> ProductsDeprecated202004GetCustomCollectionsCountRequest<'_> {
+        ProductsDeprecated202004GetCustomCollectionsCountRequest {
+            client: self,
+            title: None,
+            product_id: None,
+            updated_at_min: None,
+            updated_at_max: None,
+            published_at_min: None,
+            published_at_max: None,
+            published_status: None,
+        }
+    }
     /**
      * Retrieves a single custom collection.
      *
@@ -2062,6 +11908,25 @@ impl Products {
             )
             .await
     }
+    /**
+     * Retrieves a single custom collection.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-04/custom_collections/{custom_collection_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_get_custom_collections_param_collection`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/customcollection#show-2020-04
+     */
+    pub fn deprecated_202004_get_custom_collections_param_collection_request<'a>(
+        &'a self,
+        custom_collection_id: &'a str,
+    ) -> ProductsDeprecated202004GetCustomCollectionsParamCollectionRequest<'a> {
+        ProductsDeprecated202004GetCustomCollectionsParamCollectionRequest {
+            client: self,
+            custom_collection_id,
+            fields: None,
+        }
+    }
     /**
      * Updates an existing custom collection.
      *
@@ -2222,6 +12087,34 @@ impl Products {
             )
             .await
     }
+    /**
+     * Retrieves a list of custom collections. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-07/custom_collections.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_get_custom_collection`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/customcollection#index-2020-07
+     */
+    pub fn deprecated_202007_get_custom_collection_request(
+        &self,
+    ) -> ProductsDeprecated202007GetCustomCollectionRequest<'_> {
+        ProductsDeprecated202007GetCustomCollectionRequest {
+            client: self,
+            limit: None,
+            ids: None,
+            since_id: None,
+            title: None,
+            product_id: None,
+            handle: None,
+            updated_at_min: None,
+            updated_at_max: None,
+            published_at_min: None,
+            published_at_max: None,
+            published_status: None,
+            fields: None,
+        }
+    }
     /**
      * Creates a custom collection.
      *
@@ -2318,6 +12211,29 @@ impl Products {
             )
             .await
     }
+    /**
+     * Retrieves a count of custom collections.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-07/custom_collections/count.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_get_custom_collections_count`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/customcollection#count-2020-07
+     */
+    pub fn deprecated_202007_get_custom_collections_count_request(
+        &self,
+    ) -> ProductsDeprecated202007GetCustomCollectionsCountRequest<'_> {
+        ProductsDeprecated202007GetCustomCollectionsCountRequest {
+            client: self,
+            title: None,
+            product_id: None,
+            updated_at_min: None,
+            updated_at_max: None,
+            published_at_min: None,
+            published_at_max: None,
+            published_status: None,
+        }
+    }
     /**
      * Retrieves a single custom collection.
      *
@@ -2358,6 +12274,25 @@ impl Products {
             )
             .await
     }
+    /**
+     * Retrieves a single custom collection.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-07/custom_collections/{custom_collection_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_get_custom_collections_param_collection`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/customcollection#show-2020-07
+     */
+    pub fn deprecated_202007_get_custom_collections_param_collection_request<'a>(
+        &'a self,
+        custom_collection_id: &'a str,
+    ) -> ProductsDeprecated202007GetCustomCollectionsParamCollectionRequest<'a> {
+        ProductsDeprecated202007GetCustomCollectionsParamCollectionRequest {
+            client: self,
+            custom_collection_id,
+            fields: None,
+        }
+    }
     /**
      * Updates an existing custom collection.
      *
@@ -2518,6 +12453,32 @@ impl Products {
             )
             .await
     }
+    /**
+     * Retrieves a list of custom collections. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-10/custom_collections.json` endpoint.
+     *
+     * As opposed to `get_custom_collection`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/customcollection#index-2020-10
+     */
+    pub fn get_custom_collection_request(&self) -> ProductsGetCustomCollectionRequest<'_> {
+        ProductsGetCustomCollectionRequest {
+            client: self,
+            limit: None,
+            ids: None,
+            since_id: None,
+            title: None,
+            product_id: None,
+            handle: None,
+            updated_at_min: None,
+            updated_at_max: None,
+            published_at_min: None,
+            published_at_max: None,
+            published_status: None,
+            fields: None,
+        }
+    }
     /**
      * Creates a custom collection.
      *
@@ -2614,6 +12575,29 @@ impl Products {
             )
             .await
     }
+    /**
+     * Retrieves a count of custom collections.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-10/custom_collections/count.json` endpoint.
+     *
+     * As opposed to `get_custom_collections_count`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/customcollection#count-2020-10
+     */
+    pub fn get_custom_collections_count_request(
+        &self,
+    ) -> ProductsGetCustomCollectionsCountRequest<'_> {
+        ProductsGetCustomCollectionsCountRequest {
+            client: self,
+            title: None,
+            product_id: None,
+            updated_at_min: None,
+            updated_at_max: None,
+            published_at_min: None,
+            published_at_max: None,
+            published_status: None,
+        }
+    }
     /**
      * Retrieves a single custom collection.
      *
@@ -2654,6 +12638,25 @@ impl Products {
             )
             .await
     }
+    /**
+     * Retrieves a single custom collection.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-10/custom_collections/{custom_collection_id}.json` endpoint.
+     *
+     * As opposed to `get_custom_collections_param_collection`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/customcollection#show-2020-10
+     */
+    pub fn get_custom_collections_param_collection_request<'a>(
+        &'a self,
+        custom_collection_id: &'a str,
+    ) -> ProductsGetCustomCollectionsParamCollectionRequest<'a> {
+        ProductsGetCustomCollectionsParamCollectionRequest {
+            client: self,
+            custom_collection_id,
+            fields: None,
+        }
+    }
     /**
      * Updates an existing custom collection.
      *
@@ -2814,6 +12817,34 @@ impl Products {
             )
             .await
     }
+    /**
+     * Retrieves a list of custom collections. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2021-01/custom_collections.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_get_custom_collection`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/customcollection#index-2021-01
+     */
+    pub fn deprecated_202101_get_custom_collection_request(
+        &self,
+    ) -> ProductsDeprecated202101GetCustomCollectionRequest<'_> {
+        ProductsDeprecated202101GetCustomCollectionRequest {
+            client: self,
+            limit: None,
+            ids: None,
+            since_id: None,
+            title: None,
+            product_id: None,
+            handle: None,
+            updated_at_min: None,
+            updated_at_max: None,
+            published_at_min: None,
+            published_at_max: None,
+            published_status: None,
+            fields: None,
+        }
+    }
     /**
      * Creates a custom collection.
      *
@@ -2910,6 +12941,29 @@ impl Products {
             )
             .await
     }
+    /**
+     * Retrieves a count of custom collections.
+     *
+     * This function performs a `GET` to the `/admin/api/2021-01/custom_collections/count.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_get_custom_collections_count`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/customcollection#count-2021-01
+     */
+    pub fn deprecated_202101_get_custom_collections_count_request(
+        &self,
+    ) -> ProductsDeprecated202101GetCustomCollectionsCountRequest<'_> {
+        ProductsDeprecated202101GetCustomCollectionsCountRequest {
+            client: self,
+            title: None,
+            product_id: None,
+            updated_at_min: None,
+            updated_at_max: None,
+            published_at_min: None,
+            published_at_max: None,
+            published_status: None,
+        }
+    }
     /**
      * Retrieves a single custom collection.
      *
@@ -2950,6 +13004,25 @@ impl Products {
             )
             .await
     }
+    /**
+     * Retrieves a single custom collection.
+     *
+     * This function performs a `GET` to the `/admin/api/2021-01/custom_collections/{custom_collection_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_get_custom_collections_param_collection`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/customcollection#show-2021-01
+     */
+    pub fn deprecated_202101_get_custom_collections_param_collection_request<'a>(
+        &'a self,
+        custom_collection_id: &'a str,
+    ) -> ProductsDeprecated202101GetCustomCollectionsParamCollectionRequest<'a> {
+        ProductsDeprecated202101GetCustomCollectionsParamCollectionRequest {
+            client: self,
+            custom_collection_id,
+            fields: None,
+        }
+    }
     /**
      * Updates an existing custom collection.
      *
@@ -3110,6 +13183,34 @@ impl Products {
             )
             .await
     }
+    /**
+     * Retrieves a list of custom collections. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/unstable/custom_collections.json` endpoint.
+     *
+     * As opposed to `deprecated_unstable_get_custom_collection`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/customcollection#index-unstable
+     */
+    pub fn deprecated_unstable_get_custom_collection_request(
+        &self,
+    ) -> ProductsDeprecatedUnstableGetCustomCollectionRequest<'_> {
+        ProductsDeprecatedUnstableGetCustomCollectionRequest {
+            client: self,
+            limit: None,
+            ids: None,
+            since_id: None,
+            title: None,
+            product_id: None,
+            handle: None,
+            updated_at_min: None,
+            updated_at_max: None,
+            published_at_min: None,
+            published_at_max: None,
+            published_status: None,
+            fields: None,
+        }
+    }
     /**
      * Creates a custom collection.
      *
@@ -3206,6 +13307,29 @@ impl Products {
             )
             .await
     }
+    /**
+     * Retrieves a count of custom collections.
+     *
+     * This function performs a `GET` to the `/admin/api/unstable/custom_collections/count.json` endpoint.
+     *
+     * As opposed to `deprecated_unstable_get_custom_collections_count`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/customcollection#count-unstable
+     */
+    pub fn deprecated_unstable_get_custom_collections_count_request(
+        &self,
+    ) -> ProductsDeprecatedUnstableGetCustomCollectionsCountRequest<'_> {
+        ProductsDeprecatedUnstableGetCustomCollectionsCountRequest {
+            client: self,
+            title: None,
+            product_id: None,
+            updated_at_min: None,
+            updated_at_max: None,
+            published_at_min: None,
+            published_at_max: None,
+            published_status: None,
+        }
+    }
     /**
      * Retrieves a single custom collection.
      *
@@ -3246,6 +13370,25 @@ impl Products {
             )
             .await
     }
+    /**
+     * Retrieves a single custom collection.
+     *
+     * This function performs a `GET` to the `/admin/api/unstable/custom_collections/{custom_collection_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_unstable_get_custom_collections_param_collection`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/customcollection#show-unstable
+     */
+    pub fn deprecated_unstable_get_custom_collections_param_collection_request<'a>(
+        &'a self,
+        custom_collection_id: &'a str,
+    ) -> ProductsDeprecatedUnstableGetCustomCollectionsParamCollectionRequest<'a> {
+        ProductsDeprecatedUnstableGetCustomCollectionsParamCollectionRequest {
+            client: self,
+            custom_collection_id,
+            fields: None,
+        }
+    }
     /**
      * Updates an existing custom collection.
      *
@@ -3444,6 +13587,38 @@ impl Products {
             )
             .await
     }
+    /**
+     * Retrieves a list of products. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-01/products.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_get`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/product#index-2020-01
+     */
+    pub fn deprecated_202001_get_request(&self) -> ProductsDeprecated202001GetRequest<'_> {
+        ProductsDeprecated202001GetRequest {
+            client: self,
+            ids: None,
+            limit: None,
+            since_id: None,
+            title: None,
+            vendor: None,
+            handle: None,
+            product_type: None,
+            status: None,
+            collection_id: None,
+            created_at_min: None,
+            created_at_max: None,
+            updated_at_min: None,
+            updated_at_max: None,
+            published_at_min: None,
+            published_at_max: None,
+            published_status: None,
+            fields: None,
+            presentment_currencies: None,
+        }
+    }
     /**
     * Creates a new product.
              If you want to set the product's SEO information, then you can use the following properties:
@@ -3554,6 +13729,32 @@ impl Products {
             )
             .await
     }
+    /**
+     * Retrieves a count of products.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-01/products/count.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_get_count`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/product#count-2020-01
+     */
+    pub fn deprecated_202001_get_count_request(
+        &self,
+    ) -> ProductsDeprecated202001GetCountRequest<'_> {
+        ProductsDeprecated202001GetCountRequest {
+            client: self,
+            vendor: None,
+            product_type: None,
+            collection_id: None,
+            created_at_min: None,
+            created_at_max: None,
+            updated_at_min: None,
+            updated_at_max: None,
+            published_at_min: None,
+            published_at_max: None,
+            published_status: None,
+        }
+    }
     /**
      * Retrieves a single product.
      *
@@ -3594,6 +13795,25 @@ impl Products {
             )
             .await
     }
+    /**
+     * Retrieves a single product.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-01/products/{product_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_get_param`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/product#show-2020-01
+     */
+    pub fn deprecated_202001_get_param_request<'a>(
+        &'a self,
+        product_id: &'a str,
+    ) -> ProductsDeprecated202001GetParamRequest<'a> {
+        ProductsDeprecated202001GetParamRequest {
+            client: self,
+            product_id,
+            fields: None,
+        }
+    }
     /**
     * Updates a product and its variants and images.
              If you want to update the product's SEO information, then you can use the following properties:
@@ -3796,6 +14016,38 @@ impl Products {
             )
             .await
     }
+    /**
+     * Retrieves a list of products. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-04/products.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_get`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/product#index-2020-04
+     */
+    pub fn deprecated_202004_get_request(&self) -> ProductsDeprecated202004GetRequest<'_> {
+        ProductsDeprecated202004GetRequest {
+            client: self,
+            ids: None,
+            limit: None,
+            since_id: None,
+            title: None,
+            vendor: None,
+            handle: None,
+            product_type: None,
+            status: None,
+            collection_id: None,
+            created_at_min: None,
+            created_at_max: None,
+            updated_at_min: None,
+            updated_at_max: None,
+            published_at_min: None,
+            published_at_max: None,
+            published_status: None,
+            fields: None,
+            presentment_currencies: None,
+        }
+    }
     /**
     * Creates a new product.
              If you want to set the product's SEO information, then you can use the following properties:
@@ -3906,6 +14158,32 @@ impl Products {
             )
             .await
     }
+    /**
+     * Retrieves a count of products.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-04/products/count.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_get_count`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/product#count-2020-04
+     */
+    pub fn deprecated_202004_get_count_request(
+        &self,
+    ) -> ProductsDeprecated202004GetCountRequest<'_> {
+        ProductsDeprecated202004GetCountRequest {
+            client: self,
+            vendor: None,
+            product_type: None,
+            collection_id: None,
+            created_at_min: None,
+            created_at_max: None,
+            updated_at_min: None,
+            updated_at_max: None,
+            published_at_min: None,
+            published_at_max: None,
+            published_status: None,
+        }
+    }
     /**
      * Retrieves a single product.
      *
@@ -3946,6 +14224,25 @@ impl Products {
             )
             .await
     }
+    /**
+     * Retrieves a single product.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-04/products/{product_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_get_param`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/product#show-2020-04
+     */
+    pub fn deprecated_202004_get_param_request<'a>(
+        &'a self,
+        product_id: &'a str,
+    ) -> ProductsDeprecated202004GetParamRequest<'a> {
+        ProductsDeprecated202004GetParamRequest {
+            client: self,
+            product_id,
+            fields: None,
+        }
+    }
     /**
     * Updates a product and its variants and images.
              If you want to update the product's SEO information, then you can use the following properties:
@@ -4148,6 +14445,38 @@ impl Products {
             )
             .await
     }
+    /**
+     * Retrieves a list of products. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-07/products.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_get`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/product#index-2020-07
+     */
+    pub fn deprecated_202007_get_request(&self) -> ProductsDeprecated202007GetRequest<'_> {
+        ProductsDeprecated202007GetRequest {
+            client: self,
+            ids: None,
+            limit: None,
+            since_id: None,
+            title: None,
+            vendor: None,
+            handle: None,
+            product_type: None,
+            status: None,
+            collection_id: None,
+            created_at_min: None,
+            created_at_max: None,
+            updated_at_min: None,
+            updated_at_max: None,
+            published_at_min: None,
+            published_at_max: None,
+            published_status: None,
+            fields: None,
+            presentment_currencies: None,
+        }
+    }
     /**
     * Creates a new product.
              If you want to set the product's SEO information, then you can use the following properties:
@@ -4258,6 +14587,32 @@ impl Products {
             )
             .await
     }
+    /**
+     * Retrieves a count of products.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-07/products/count.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_get_count`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/product#count-2020-07
+     */
+    pub fn deprecated_202007_get_count_request(
+        &self,
+    ) -> ProductsDeprecated202007GetCountRequest<'_> {
+        ProductsDeprecated202007GetCountRequest {
+            client: self,
+            vendor: None,
+            product_type: None,
+            collection_id: None,
+            created_at_min: None,
+            created_at_max: None,
+            updated_at_min: None,
+            updated_at_max: None,
+            published_at_min: None,
+            published_at_max: None,
+            published_status: None,
+        }
+    }
     /**
      * Retrieves a single product.
      *
@@ -4298,6 +14653,25 @@ impl Products {
             )
             .await
     }
+    /**
+     * Retrieves a single product.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-07/products/{product_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_get_param`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/product#show-2020-07
+     */
+    pub fn deprecated_202007_get_param_request<'a>(
+        &'a self,
+        product_id: &'a str,
+    ) -> ProductsDeprecated202007GetParamRequest<'a> {
+        ProductsDeprecated202007GetParamRequest {
+            client: self,
+            product_id,
+            fields: None,
+        }
+    }
     /**
     * Updates a product and its variants and images.
              If you want to update the product's SEO information, then you can use the following properties:
@@ -4500,6 +14874,38 @@ impl Products {
             )
             .await
     }
+    /**
+     * Retrieves a list of products. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-10/products.json` endpoint.
+     *
+     * As opposed to `get`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/product#index-2020-10
+     */
+    pub fn get_request(&self) -> ProductsGetRequest<'_> {
+        ProductsGetRequest {
+            client: self,
+            ids: None,
+            limit: None,
+            since_id: None,
+            title: None,
+            vendor: None,
+            handle: None,
+            product_type: None,
+            status: None,
+            collection_id: None,
+            created_at_min: None,
+            created_at_max: None,
+            updated_at_min: None,
+            updated_at_max: None,
+            published_at_min: None,
+            published_at_max: None,
+            published_status: None,
+            fields: None,
+            presentment_currencies: None,
+        }
+    }
     /**
     * Creates a new product.
              If you want to set the product's SEO information, then you can use the following properties:
@@ -4607,6 +15013,30 @@ impl Products {
             )
             .await
     }
+    /**
+     * Retrieves a count of products.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-10/products/count.json` endpoint.
+     *
+     * As opposed to `get_count`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/product#count-2020-10
+     */
+    pub fn get_count_request(&self) -> ProductsGetCountRequest<'_> {
+        ProductsGetCountRequest {
+            client: self,
+            vendor: None,
+            product_type: None,
+            collection_id: None,
+            created_at_min: None,
+            created_at_max: None,
+            updated_at_min: None,
+            updated_at_max: None,
+            published_at_min: None,
+            published_at_max: None,
+            published_status: None,
+        }
+    }
     /**
      * Retrieves a single product.
      *
@@ -4647,6 +15077,22 @@ impl Products {
             )
             .await
     }
+    /**
+     * Retrieves a single product.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-10/products/{product_id}.json` endpoint.
+     *
+     * As opposed to `get_param`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/product#show-2020-10
+     */
+    pub fn get_param_request<'a>(&'a self, product_id: &'a str) -> ProductsGetParamRequest<'a> {
+        ProductsGetParamRequest {
+            client: self,
+            product_id,
+            fields: None,
+        }
+    }
     /**
     * Updates a product and its variants and images.
              If you want to update the product's SEO information, then you can use the following properties:
@@ -4846,6 +15292,38 @@ impl Products {
             )
             .await
     }
+    /**
+     * Retrieves a list of products. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2021-01/products.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_get`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/product#index-2021-01
+     */
+    pub fn deprecated_202101_get_request(&self) -> ProductsDeprecated202101GetRequest<'_> {
+        ProductsDeprecated202101GetRequest {
+            client: self,
+            ids: None,
+            limit: None,
+            since_id: None,
+            title: None,
+            vendor: None,
+            handle: None,
+            product_type: None,
+            status: None,
+            collection_id: None,
+            created_at_min: None,
+            created_at_max: None,
+            updated_at_min: None,
+            updated_at_max: None,
+            published_at_min: None,
+            published_at_max: None,
+            published_status: None,
+            fields: None,
+            presentment_currencies: None,
+        }
+    }
     /**
     * Creates a new product.
              If you want to set the product's SEO information, then you can use the following properties:
@@ -4956,6 +15434,32 @@ impl Products {
             )
             .await
     }
+    /**
+     * Retrieves a count of products.
+     *
+     * This function performs a `GET` to the `/admin/api/2021-01/products/count.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_get_count`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/product#count-2021-01
+     */
+    pub fn deprecated_202101_get_count_request(
+        &self,
+    ) -> ProductsDeprecated202101GetCountRequest<'_> {
+        ProductsDeprecated202101GetCountRequest {
+            client: self,
+            vendor: None,
+            product_type: None,
+            collection_id: None,
+            created_at_min: None,
+            created_at_max: None,
+            updated_at_min: None,
+            updated_at_max: None,
+            published_at_min: None,
+            published_at_max: None,
+            published_status: None,
+        }
+    }
     /**
      * Retrieves a single product.
      *
@@ -4996,6 +15500,25 @@ impl Products {
             )
             .await
     }
+    /**
+     * Retrieves a single product.
+     *
+     * This function performs a `GET` to the `/admin/api/2021-01/products/{product_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_get_param`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/product#show-2021-01
+     */
+    pub fn deprecated_202101_get_param_request<'a>(
+        &'a self,
+        product_id: &'a str,
+    ) -> ProductsDeprecated202101GetParamRequest<'a> {
+        ProductsDeprecated202101GetParamRequest {
+            client: self,
+            product_id,
+            fields: None,
+        }
+    }
     /**
     * Updates a product and its variants and images.
              If you want to update the product's SEO information, then you can use the following properties:
@@ -5198,6 +15721,38 @@ impl Products {
             )
             .await
     }
+    /**
+     * Retrieves a list of products. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/unstable/products.json` endpoint.
+     *
+     * As opposed to `deprecated_unstable_get`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/product#index-unstable
+     */
+    pub fn deprecated_unstable_get_request(&self) -> ProductsDeprecatedUnstableGetRequest<'_> {
+        ProductsDeprecatedUnstableGetRequest {
+            client: self,
+            ids: None,
+            limit: None,
+            since_id: None,
+            title: None,
+            vendor: None,
+            handle: None,
+            product_type: None,
+            status: None,
+            collection_id: None,
+            created_at_min: None,
+            created_at_max: None,
+            updated_at_min: None,
+            updated_at_max: None,
+            published_at_min: None,
+            published_at_max: None,
+            published_status: None,
+            fields: None,
+            presentment_currencies: None,
+        }
+    }
     /**
     * Creates a new product.
              If you want to set the product's SEO information, then you can use the following properties:
@@ -5308,6 +15863,32 @@ impl Products {
             )
             .await
     }
+    /**
+     * Retrieves a count of products.
+     *
+     * This function performs a `GET` to the `/admin/api/unstable/products/count.json` endpoint.
+     *
+     * As opposed to `deprecated_unstable_get_count`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/product#count-unstable
+     */
+    pub fn deprecated_unstable_get_count_request(
+        &self,
+    ) -> ProductsDeprecatedUnstableGetCountRequest<'_> {
+        ProductsDeprecatedUnstableGetCountRequest {
+            client: self,
+            vendor: None,
+            product_type: None,
+            collection_id: None,
+            created_at_min: None,
+            created_at_max: None,
+            updated_at_min: None,
+            updated_at_max: None,
+            published_at_min: None,
+            published_at_max: None,
+            published_status: None,
+        }
+    }
     /**
      * Retrieves a single product.
      *
@@ -5348,6 +15929,25 @@ impl Products {
             )
             .await
     }
+    /**
+     * Retrieves a single product.
+     *
+     * This function performs a `GET` to the `/admin/api/unstable/products/{product_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_unstable_get_param`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/product#show-unstable
+     */
+    pub fn deprecated_unstable_get_param_request<'a>(
+        &'a self,
+        product_id: &'a str,
+    ) -> ProductsDeprecatedUnstableGetParamRequest<'a> {
+        ProductsDeprecatedUnstableGetParamRequest {
+            client: self,
+            product_id,
+            fields: None,
+        }
+    }
     /**
     * Updates a product and its variants and images.
              If you want to update the product's SEO information, then you can use the following properties:
@@ -5462,6 +16062,26 @@ impl Products {
             )
             .await
     }
+    /**
+     * Get all product images.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-01/products/{product_id}/images.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_get_param_image`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/product-image#index-2020-01
+     */
+    pub fn deprecated_202001_get_param_image_request<'a>(
+        &'a self,
+        product_id: &'a str,
+    ) -> ProductsDeprecated202001GetParamImageRequest<'a> {
+        ProductsDeprecated202001GetParamImageRequest {
+            client: self,
+            product_id,
+            since_id: None,
+            fields: None,
+        }
+    }
     /**
      * Create a new product image.
      *
@@ -5535,6 +16155,25 @@ impl Products {
             )
             .await
     }
+    /**
+     * Get a count of all product images.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-01/products/{product_id}/images/count.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_get_param_images_count`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/product-image#count-2020-01
+     */
+    pub fn deprecated_202001_get_param_images_count_request<'a>(
+        &'a self,
+        product_id: &'a str,
+    ) -> ProductsDeprecated202001GetParamImagesCountRequest<'a> {
+        ProductsDeprecated202001GetParamImagesCountRequest {
+            client: self,
+            product_id,
+            since_id: None,
+        }
+    }
     /**
      * Get a single product image by id.
      *
@@ -5578,6 +16217,27 @@ impl Products {
             )
             .await
     }
+    /**
+     * Get a single product image by id.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-01/products/{product_id}/images/{image_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_get_param_images_image`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/product-image#show-2020-01
+     */
+    pub fn deprecated_202001_get_param_images_image_request<'a>(
+        &'a self,
+        product_id: &'a str,
+        image_id: &'a str,
+    ) -> ProductsDeprecated202001GetParamImagesImageRequest<'a> {
+        ProductsDeprecated202001GetParamImagesImageRequest {
+            client: self,
+            product_id,
+            image_id,
+            fields: None,
+        }
+    }
     /**
      * Modify an existing product image.
      *
@@ -5694,6 +16354,26 @@ impl Products {
             )
             .await
     }
+    /**
+     * Get all product images.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-04/products/{product_id}/images.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_get_param_image`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/product-image#index-2020-04
+     */
+    pub fn deprecated_202004_get_param_image_request<'a>(
+        &'a self,
+        product_id: &'a str,
+    ) -> ProductsDeprecated202004GetParamImageRequest<'a> {
+        ProductsDeprecated202004GetParamImageRequest {
+            client: self,
+            product_id,
+            since_id: None,
+            fields: None,
+        }
+    }
     /**
      * Create a new product image.
      *
@@ -5767,6 +16447,25 @@ impl Products {
             )
             .await
     }
+    /**
+     * Get a count of all product images.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-04/products/{product_id}/images/count.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_get_param_images_count`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/product-image#count-2020-04
+     */
+    pub fn deprecated_202004_get_param_images_count_request<'a>(
+        &'a self,
+        product_id: &'a str,
+    ) -> ProductsDeprecated202004GetParamImagesCountRequest<'a> {
+        ProductsDeprecated202004GetParamImagesCountRequest {
+            client: self,
+            product_id,
+            since_id: None,
+        }
+    }
     /**
      * Get a single product image by id.
      *
@@ -5810,6 +16509,27 @@ impl Products {
             )
             .await
     }
+    /**
+     * Get a single product image by id.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-04/products/{product_id}/images/{image_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_get_param_images_image`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/product-image#show-2020-04
+     */
+    pub fn deprecated_202004_get_param_images_image_request<'a>(
+        &'a self,
+        product_id: &'a str,
+        image_id: &'a str,
+    ) -> ProductsDeprecated202004GetParamImagesImageRequest<'a> {
+        ProductsDeprecated202004GetParamImagesImageRequest {
+            client: self,
+            product_id,
+            image_id,
+            fields: None,
+        }
+    }
     /**
      * Modify an existing product image.
      *
@@ -5926,6 +16646,26 @@ impl Products {
             )
             .await
     }
+    /**
+     * Get all product images.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-07/products/{product_id}/images.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_get_param_image`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/product-image#index-2020-07
+     */
+    pub fn deprecated_202007_get_param_image_request<'a>(
+        &'a self,
+        product_id: &'a str,
+    ) -> ProductsDeprecated202007GetParamImageRequest<'a> {
+        ProductsDeprecated202007GetParamImageRequest {
+            client: self,
+            product_id,
+            since_id: None,
+            fields: None,
+        }
+    }
     /**
      * Create a new product image.
      *
@@ -5999,6 +16739,25 @@ impl Products {
             )
             .await
     }
+    /**
+     * Get a count of all product images.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-07/products/{product_id}/images/count.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_get_param_images_count`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/product-image#count-2020-07
+     */
+    pub fn deprecated_202007_get_param_images_count_request<'a>(
+        &'a self,
+        product_id: &'a str,
+    ) -> ProductsDeprecated202007GetParamImagesCountRequest<'a> {
+        ProductsDeprecated202007GetParamImagesCountRequest {
+            client: self,
+            product_id,
+            since_id: None,
+        }
+    }
     /**
      * Get a single product image by id.
      *
@@ -6042,6 +16801,27 @@ impl Products {
             )
             .await
     }
+    /**
+     * Get a single product image by id.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-07/products/{product_id}/images/{image_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_get_param_images_image`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/product-image#show-2020-07
+     */
+    pub fn deprecated_202007_get_param_images_image_request<'a>(
+        &'a self,
+        product_id: &'a str,
+        image_id: &'a str,
+    ) -> ProductsDeprecated202007GetParamImagesImageRequest<'a> {
+        ProductsDeprecated202007GetParamImagesImageRequest {
+            client: self,
+            product_id,
+            image_id,
+            fields: None,
+        }
+    }
     /**
      * Modify an existing product image.
      *
@@ -6158,6 +16938,26 @@ impl Products {
             )
             .await
     }
+    /**
+     * Get all product images.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-10/products/{product_id}/images.json` endpoint.
+     *
+     * As opposed to `get_param_image`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/product-image#index-2020-10
+     */
+    pub fn get_param_image_request<'a>(
+        &'a self,
+        product_id: &'a str,
+    ) -> ProductsGetParamImageRequest<'a> {
+        ProductsGetParamImageRequest {
+            client: self,
+            product_id,
+            since_id: None,
+            fields: None,
+        }
+    }
     /**
      * Create a new product image.
      *
@@ -6231,6 +17031,25 @@ impl Products {
             )
             .await
     }
+    /**
+     * Get a count of all product images.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-10/products/{product_id}/images/count.json` endpoint.
+     *
+     * As opposed to `get_param_images_count`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/product-image#count-2020-10
+     */
+    pub fn get_param_images_count_request<'a>(
+        &'a self,
+        product_id: &'a str,
+    ) -> ProductsGetParamImagesCountRequest<'a> {
+        ProductsGetParamImagesCountRequest {
+            client: self,
+            product_id,
+            since_id: None,
+        }
+    }
     /**
      * Get a single product image by id.
      *
@@ -6274,6 +17093,27 @@ impl Products {
             )
             .await
     }
+    /**
+     * Get a single product image by id.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-10/products/{product_id}/images/{image_id}.json` endpoint.
+     *
+     * As opposed to `get_param_images_image`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/product-image#show-2020-10
+     */
+    pub fn get_param_images_image_request<'a>(
+        &'a self,
+        product_id: &'a str,
+        image_id: &'a str,
+    ) -> ProductsGetParamImagesImageRequest<'a> {
+        ProductsGetParamImagesImageRequest {
+            client: self,
+            product_id,
+            image_id,
+            fields: None,
+        }
+    }
     /**
      * Modify an existing product image.
      *
@@ -6390,6 +17230,26 @@ impl Products {
             )
             .await
     }
+    /**
+     * Get all product images.
+     *
+     * This function performs a `GET` to the `/admin/api/2021-01/products/{product_id}/images.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_get_param_image`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/product-image#index-2021-01
+     */
+    pub fn deprecated_202101_get_param_image_request<'a>(
+        &'a self,
+        product_id: &'a str,
+    ) -> ProductsDeprecated202101GetParamImageRequest<'a> {
+        ProductsDeprecated202101GetParamImageRequest {
+            client: self,
+            product_id,
+            since_id: None,
+            fields: None,
+        }
+    }
     /**
      * Create a new product image.
      *
@@ -6463,6 +17323,25 @@ impl Products {
             )
             .await
     }
+    /**
+     * Get a count of all product images.
+     *
+     * This function performs a `GET` to the `/admin/api/2021-01/products/{product_id}/images/count.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_get_param_images_count`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/product-image#count-2021-01
+     */
+    pub fn deprecated_202101_get_param_images_count_request<'a>(
+        &'a self,
+        product_id: &'a str,
+    ) -> ProductsDeprecated202101GetParamImagesCountRequest<'a> {
+        ProductsDeprecated202101GetParamImagesCountRequest {
+            client: self,
+            product_id,
+            since_id: None,
+        }
+    }
     /**
      * Get a single product image by id.
      *
@@ -6506,6 +17385,27 @@ impl Products {
             )
             .await
     }
+    /**
+     * Get a single product image by id.
+     *
+     * This function performs a `GET` to the `/admin/api/2021-01/products/{product_id}/images/{image_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_get_param_images_image`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/product-image#show-2021-01
+     */
+    pub fn deprecated_202101_get_param_images_image_request<'a>(
+        &'a self,
+        product_id: &'a str,
+        image_id: &'a str,
+    ) -> ProductsDeprecated202101GetParamImagesImageRequest<'a> {
+        ProductsDeprecated202101GetParamImagesImageRequest {
+            client: self,
+            product_id,
+            image_id,
+            fields: None,
+        }
+    }
     /**
      * Modify an existing product image.
      *
@@ -6622,6 +17522,26 @@ impl Products {
             )
             .await
     }
+    /**
+     * Get all product images.
+     *
+     * This function performs a `GET` to the `/admin/api/unstable/products/{product_id}/images.json` endpoint.
+     *
+     * As opposed to `deprecated_unstable_get_param_image`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/product-image#index-unstable
+     */
+    pub fn deprecated_unstable_get_param_image_request<'a>(
+        &'a self,
+        product_id: &'a str,
+    ) -> ProductsDeprecatedUnstableGetParamImageRequest<'a> {
+        ProductsDeprecatedUnstableGetParamImageRequest {
+            client: self,
+            product_id,
+            since_id: None,
+            fields: None,
+        }
+    }
     /**
      * Create a new product image.
      *
@@ -6695,6 +17615,25 @@ impl Products {
             )
             .await
     }
+    /**
+     * Get a count of all product images.
+     *
+     * This function performs a `GET` to the `/admin/api/unstable/products/{product_id}/images/count.json` endpoint.
+     *
+     * As opposed to `deprecated_unstable_get_param_images_count`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/product-image#count-unstable
+     */
+    pub fn deprecated_unstable_get_param_images_count_request<'a>(
+        &'a self,
+        product_id: &'a str,
+    ) -> ProductsDeprecatedUnstableGetParamImagesCountRequest<'a> {
+        ProductsDeprecatedUnstableGetParamImagesCountRequest {
+            client: self,
+            product_id,
+            since_id: None,
+        }
+    }
     /**
      * Get a single product image by id.
      *
@@ -6738,6 +17677,27 @@ impl Products {
             )
             .await
     }
+    /**
+     * Get a single product image by id.
+     *
+     * This function performs a `GET` to the `/admin/api/unstable/products/{product_id}/images/{image_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_unstable_get_param_images_image`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/product-image#show-unstable
+     */
+    pub fn deprecated_unstable_get_param_images_image_request<'a>(
+        &'a self,
+        product_id: &'a str,
+        image_id: &'a str,
+    ) -> ProductsDeprecatedUnstableGetParamImagesImageRequest<'a> {
+        ProductsDeprecatedUnstableGetParamImagesImageRequest {
+            client: self,
+            product_id,
+            image_id,
+            fields: None,
+        }
+    }
     /**
      * Modify an existing product image.
      *
@@ -6904,6 +17864,34 @@ impl Products {
             )
             .await
     }
+    /**
+     * Retrieves a list of smart collections. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-01/smart_collections.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_get_smart_collection`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/smartcollection#index-2020-01
+     */
+    pub fn deprecated_202001_get_smart_collection_request(
+        &self,
+    ) -> ProductsDeprecated202001GetSmartCollectionRequest<'_> {
+        ProductsDeprecated202001GetSmartCollectionRequest {
+            client: self,
+            limit: None,
+            ids: None,
+            since_id: None,
+            title: None,
+            product_id: None,
+            handle: None,
+            updated_at_min: None,
+            updated_at_max: None,
+            published_at_min: None,
+            published_at_max: None,
+            published_status: None,
+            fields: None,
+        }
+    }
     /**
      * Creates a new smart collection using the specified rules.
      *
@@ -6997,6 +17985,29 @@ impl Products {
             )
             .await
     }
+    /**
+     * Retrieves a count of smart collections.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-01/smart_collections/count.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_get_smart_collections_count`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/smartcollection#count-2020-01
+     */
+    pub fn deprecated_202001_get_smart_collections_count_request(
+        &self,
+    ) -> ProductsDeprecated202001GetSmartCollectionsCountRequest<'_> {
+        ProductsDeprecated202001GetSmartCollectionsCountRequest {
+            client: self,
+            title: None,
+            product_id: None,
+            updated_at_min: None,
+            updated_at_max: None,
+            published_at_min: None,
+            published_at_max: None,
+            published_status: None,
+        }
+    }
     /**
      * Retrieves a single smart collection.
      *
@@ -7037,6 +18048,25 @@ impl Products {
             )
             .await
     }
+    /**
+     * Retrieves a single smart collection.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-01/smart_collections/{smart_collection_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_get_smart_collections_param_collection`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/smartcollection#show-2020-01
+     */
+    pub fn deprecated_202001_get_smart_collections_param_collection_request<'a>(
+        &'a self,
+        smart_collection_id: &'a str,
+    ) -> ProductsDeprecated202001GetSmartCollectionsParamCollectionRequest<'a> {
+        ProductsDeprecated202001GetSmartCollectionsParamCollectionRequest {
+            client: self,
+            smart_collection_id,
+            fields: None,
+        }
+    }
     /**
      * Updates an existing smart collection.
      *
@@ -7150,6 +18180,28 @@ impl Products {
             )
             .await
     }
+    /**
+     * Updates the ordering type of products in a smart collection.
+     *
+     * This function performs a `PUT` to the `/admin/api/2020-01/smart_collections/{smart_collection_id}/order.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_update_smart_collections_param_collection_order`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/smartcollection#order-2020-01
+     */
+    pub fn deprecated_202001_update_smart_collections_param_collection_order_request<'a>(
+        &'a self,
+        smart_collection_id: &'a str,
+        body: &'a serde_json::Value,
+    ) -> ProductsDeprecated202001UpdateSmartCollectionsParamCollectionOrderRequest<'a> {
+        ProductsDeprecated202001UpdateSmartCollectionsParamCollectionOrderRequest {
+            client: self,
+            smart_collection_id,
+            products: None,
+            sort_order: None,
+            body,
+        }
+    }
     /**
      * Retrieves a list of smart collections. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -7245,6 +18297,34 @@ impl Products {
             )
             .await
     }
+    /**
+     * Retrieves a list of smart collections. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-04/smart_collections.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_get_smart_collection`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/smartcollection#index-2020-04
+     */
+    pub fn deprecated_202004_get_smart_collection_request(
+        &self,
+    ) -> ProductsDeprecated202004GetSmartCollectionRequest<'_> {
+        ProductsDeprecated202004GetSmartCollectionRequest {
+            client: self,
+            limit: None,
+            ids: None,
+            since_id: None,
+            title: None,
+            product_id: None,
+            handle: None,
+            updated_at_min: None,
+            updated_at_max: None,
+            published_at_min: None,
+            published_at_max: None,
+            published_status: None,
+            fields: None,
+        }
+    }
     /**
      * Creates a new smart collection using the specified rules.
      *
@@ -7338,6 +18418,29 @@ impl Products {
             )
             .await
     }
+    /**
+     * Retrieves a count of smart collections.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-04/smart_collections/count.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_get_smart_collections_count`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/smartcollection#count-2020-04
+     */
+    pub fn deprecated_202004_get_smart_collections_count_request(
+        &self,
+    ) -> ProductsDeprecated202004GetSmartCollectionsCountRequest<'_> {
+        ProductsDeprecated202004GetSmartCollectionsCountRequest {
+            client: self,
+            title: None,
+            product_id: None,
+            updated_at_min: None,
+            updated_at_max: None,
+            published_at_min: None,
+            published_at_max: None,
+            published_status: None,
+        }
+    }
     /**
      * Retrieves a single smart collection.
      *
@@ -7378,6 +18481,25 @@ impl Products {
             )
             .await
     }
+    /**
+     * Retrieves a single smart collection.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-04/smart_collections/{smart_collection_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_get_smart_collections_param_collection`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/smartcollection#show-2020-04
+     */
+    pub fn deprecated_202004_get_smart_collections_param_collection_request<'a>(
+        &'a self,
+        smart_collection_id: &'a str,
+    ) -> ProductsDeprecated202004GetSmartCollectionsParamCollectionRequest<'a> {
+        ProductsDeprecated202004GetSmartCollectionsParamCollectionRequest {
+            client: self,
+            smart_collection_id,
+            fields: None,
+        }
+    }
     /**
      * Updates an existing smart collection.
      *
@@ -7491,6 +18613,28 @@ impl Products {
             )
             .await
     }
+    /**
+     * Updates the ordering type of products in a smart collection.
+     *
+     * This function performs a `PUT` to the `/admin/api/2020-04/smart_collections/{smart_collection_id}/order.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_update_smart_collections_param_collection_order`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/smartcollection#order-2020-04
+     */
+    pub fn deprecated_202004_update_smart_collections_param_collection_order_request<'a>(
+        &'a self,
+        smart_collection_id: &'a str,
+        body: &'a serde_json::Value,
+    ) -> ProductsDeprecated202004UpdateSmartCollectionsParamCollectionOrderRequest<'a> {
+        ProductsDeprecated202004UpdateSmartCollectionsParamCollectionOrderRequest {
+            client: self,
+            smart_collection_id,
+            products: None,
+            sort_order: None,
+            body,
+        }
+    }
     /**
      * Retrieves a list of smart collections. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -7586,6 +18730,34 @@ impl Products {
             )
             .await
     }
+    /**
+     * Retrieves a list of smart collections. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-07/smart_collections.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_get_smart_collection`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/smartcollection#index-2020-07
+     */
+    pub fn deprecated_202007_get_smart_collection_request(
+        &self,
+    ) -> ProductsDeprecated202007GetSmartCollectionRequest<'_> {
+        ProductsDeprecated202007GetSmartCollectionRequest {
+            client: self,
+            limit: None,
+            ids: None,
+            since_id: None,
+            title: None,
+            product_id: None,
+            handle: None,
+            updated_at_min: None,
+            updated_at_max: None,
+            published_at_min: None,
+            published_at_max: None,
+            published_status: None,
+            fields: None,
+        }
+    }
     /**
      * Creates a new smart collection using the specified rules.
      *
@@ -7679,6 +18851,29 @@ impl Products {
             )
             .await
     }
+    /**
+     * Retrieves a count of smart collections.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-07/smart_collections/count.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_get_smart_collections_count`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/smartcollection#count-2020-07
+     */
+    pub fn deprecated_202007_get_smart_collections_count_request(
+        &self,
+    ) -> ProductsDeprecated202007GetSmartCollectionsCountRequest<'_> {
+        ProductsDeprecated202007GetSmartCollectionsCountRequest {
+            client: self,
+            title: None,
+            product_id: None,
+            updated_at_min: None,
+            updated_at_max: None,
+            published_at_min: None,
+            published_at_max: None,
+            published_status: None,
+        }
+    }
     /**
      * Retrieves a single smart collection.
      *
@@ -7719,6 +18914,25 @@ impl Products {
             )
             .await
     }
+    /**
+     * Retrieves a single smart collection.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-07/smart_collections/{smart_collection_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_get_smart_collections_param_collection`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/smartcollection#show-2020-07
+     */
+    pub fn deprecated_202007_get_smart_collections_param_collection_request<'a>(
+        &'a self,
+        smart_collection_id: &'a str,
+    ) -> ProductsDeprecated202007GetSmartCollectionsParamCollectionRequest<'a> {
+        ProductsDeprecated202007GetSmartCollectionsParamCollectionRequest {
+            client: self,
+            smart_collection_id,
+            fields: None,
+        }
+    }
     /**
      * Updates an existing smart collection.
      *
@@ -7832,6 +19046,28 @@ impl Products {
             )
             .await
     }
+    /**
+     * Updates the ordering type of products in a smart collection.
+     *
+     * This function performs a `PUT` to the `/admin/api/2020-07/smart_collections/{smart_collection_id}/order.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_update_smart_collections_param_collection_order`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/smartcollection#order-2020-07
+     */
+    pub fn deprecated_202007_update_smart_collections_param_collection_order_request<'a>(
+        &'a self,
+        smart_collection_id: &'a str,
+        body: &'a serde_json::Value,
+    ) -> ProductsDeprecated202007UpdateSmartCollectionsParamCollectionOrderRequest<'a> {
+        ProductsDeprecated202007UpdateSmartCollectionsParamCollectionOrderRequest {
+            client: self,
+            smart_collection_id,
+            products: None,
+            sort_order: None,
+            body,
+        }
+    }
     /**
      * Retrieves a list of smart collections. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -7927,6 +19163,32 @@ impl Products {
             )
             .await
     }
+    /**
+     * Retrieves a list of smart collections. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-10/smart_collections.json` endpoint.
+     *
+     * As opposed to `get_smart_collection`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/smartcollection#index-2020-10
+     */
+    pub fn get_smart_collection_request(&self) -> ProductsGetSmartCollectionRequest<'_> {
+        ProductsGetSmartCollectionRequest {
+            client: self,
+            limit: None,
+            ids: None,
+            since_id: None,
+            title: None,
+            product_id: None,
+            handle: None,
+            updated_at_min: None,
+            updated_at_max: None,
+            published_at_min: None,
+            published_at_max: None,
+            published_status: None,
+            fields: None,
+        }
+    }
     /**
      * Creates a new smart collection using the specified rules.
      *
@@ -8020,6 +19282,29 @@ impl Products {
             )
             .await
     }
+    /**
+     * Retrieves a count of smart collections.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-10/smart_collections/count.json` endpoint.
+     *
+     * As opposed to `get_smart_collections_count`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/smartcollection#count-2020-10
+     */
+    pub fn get_smart_collections_count_request(
+        &self,
+    ) -> ProductsGetSmartCollectionsCountRequest<'_> {
+        ProductsGetSmartCollectionsCountRequest {
+            client: self,
+            title: None,
+            product_id: None,
+            updated_at_min: None,
+            updated_at_max: None,
+            published_at_min: None,
+            published_at_max: None,
+            published_status: None,
+        }
+    }
     /**
      * Retrieves a single smart collection.
      *
@@ -8060,6 +19345,25 @@ impl Products {
             )
             .await
     }
+    /**
+     * Retrieves a single smart collection.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-10/smart_collections/{smart_collection_id}.json` endpoint.
+     *
+     * As opposed to `get_smart_collections_param_collection`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/smartcollection#show-2020-10
+     */
+    pub fn get_smart_collections_param_collection_request<'a>(
+        &'a self,
+        smart_collection_id: &'a str,
+    ) -> ProductsGetSmartCollectionsParamCollectionRequest<'a> {
+        ProductsGetSmartCollectionsParamCollectionRequest {
+            client: self,
+            smart_collection_id,
+            fields: None,
+        }
+    }
     /**
      * Updates an existing smart collection.
      *
@@ -8173,6 +19477,28 @@ impl Products {
             )
             .await
     }
+    /**
+     * Updates the ordering type of products in a smart collection.
+     *
+     * This function performs a `PUT` to the `/admin/api/2020-10/smart_collections/{smart_collection_id}/order.json` endpoint.
+     *
+     * As opposed to `update_smart_collections_param_collection_order`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/smartcollection#order-2020-10
+     */
+    pub fn update_smart_collections_param_collection_order_request<'a>(
+        &'a self,
+        smart_collection_id: &'a str,
+        body: &'a serde_json::Value,
+    ) -> ProductsUpdateSmartCollectionsParamCollectionOrderRequest<'a> {
+        ProductsUpdateSmartCollectionsParamCollectionOrderRequest {
+            client: self,
+            smart_collection_id,
+            products: None,
+            sort_order: None,
+            body,
+        }
+    }
     /**
      * Retrieves a list of smart collections. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -8268,6 +19594,34 @@ impl Products {
             )
             .await
     }
+    /**
+     * Retrieves a list of smart collections. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2021-01/smart_collections.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_get_smart_collection`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/smartcollection#index-2021-01
+     */
+    pub fn deprecated_202101_get_smart_collection_request(
+        &self,
+    ) -> ProductsDeprecated202101GetSmartCollectionRequest<'_> {
+        ProductsDeprecated202101GetSmartCollectionRequest {
+            client: self,
+            limit: None,
+            ids: None,
+            since_id: None,
+            title: None,
+            product_id: None,
+            handle: None,
+            updated_at_min: None,
+            updated_at_max: None,
+            published_at_min: None,
+            published_at_max: None,
+            published_status: None,
+            fields: None,
+        }
+    }
     /**
      * Creates a new smart collection using the specified rules.
      *
@@ -8361,6 +19715,29 @@ impl Products {
             )
             .await
     }
+    /**
+     * Retrieves a count of smart collections.
+     *
+     * This function performs a `GET` to the `/admin/api/2021-01/smart_collections/count.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_get_smart_collections_count`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/smartcollection#count-2021-01
+     */
+    pub fn deprecated_202101_get_smart_collections_count_request(
+        &self,
+    ) -> ProductsDeprecated202101GetSmartCollectionsCountRequest<'_> {
+        ProductsDeprecated202101GetSmartCollectionsCountRequest {
+            client: self,
+            title: None,
+            product_id: None,
+            updated_at_min: None,
+            updated_at_max: None,
+            published_at_min: None,
+            published_at_max: None,
+            published_status: None,
+        }
+    }
     /**
      * Retrieves a single smart collection.
      *
@@ -8401,6 +19778,25 @@ impl Products {
             )
             .await
     }
+    /**
+     * Retrieves a single smart collection.
+     *
+     * This function performs a `GET` to the `/admin/api/2021-01/smart_collections/{smart_collection_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_get_smart_collections_param_collection`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/smartcollection#show-2021-01
+     */
+    pub fn deprecated_202101_get_smart_collections_param_collection_request<'a>(
+        &'a self,
+        smart_collection_id: &'a str,
+    ) -> ProductsDeprecated202101GetSmartCollectionsParamCollectionRequest<'a> {
+        ProductsDeprecated202101GetSmartCollectionsParamCollectionRequest {
+            client: self,
+            smart_collection_id,
+            fields: None,
+        }
+    }
     /**
      * Updates an existing smart collection.
      *
@@ -8514,6 +19910,28 @@ impl Products {
             )
             .await
     }
+    /**
+     * Updates the ordering type of products in a smart collection.
+     *
+     * This function performs a `PUT` to the `/admin/api/2021-01/smart_collections/{smart_collection_id}/order.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_update_smart_collections_param_collection_order`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/smartcollection#order-2021-01
+     */
+    pub fn deprecated_202101_update_smart_collections_param_collection_order_request<'a>(
+        &'a self,
+        smart_collection_id: &'a str,
+        body: &'a serde_json::Value,
+    ) -> ProductsDeprecated202101UpdateSmartCollectionsParamCollectionOrderRequest<'a> {
+        ProductsDeprecated202101UpdateSmartCollectionsParamCollectionOrderRequest {
+            client: self,
+            smart_collection_id,
+            products: None,
+            sort_order: None,
+            body,
+        }
+    }
     /**
      * Retrieves a list of smart collections. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -8609,6 +20027,34 @@ impl Products {
             )
             .await
     }
+    /**
+     * Retrieves a list of smart collections. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/unstable/smart_collections.json` endpoint.
+     *
+     * As opposed to `deprecated_unstable_get_smart_collection`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/smartcollection#index-unstable
+     */
+    pub fn deprecated_unstable_get_smart_collection_request(
+        &self,
+    ) -> ProductsDeprecatedUnstableGetSmartCollectionRequest<'_> {
+        ProductsDeprecatedUnstableGetSmartCollectionRequest {
+            client: self,
+            limit: None,
+            ids: None,
+            since_id: None,
+            title: None,
+            product_id: None,
+            handle: None,
+            updated_at_min: None,
+            updated_at_max: None,
+            published_at_min: None,
+            published_at_max: None,
+            published_status: None,
+            fields: None,
+        }
+    }
     /**
      * Creates a new smart collection using the specified rules.
      *
@@ -8705,6 +20151,29 @@ impl Products {
             )
             .await
     }
+    /**
+     * Retrieves a count of smart collections.
+     *
+     * This function performs a `GET` to the `/admin/api/unstable/smart_collections/count.json` endpoint.
+     *
+     * As opposed to `deprecated_unstable_get_smart_collections_count`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/smartcollection#count-unstable
+     */
+    pub fn deprecated_unstable_get_smart_collections_count_request(
+        &self,
+    ) -> ProductsDeprecatedUnstableGetSmartCollectionsCountRequest<'_> {
+        ProductsDeprecatedUnstableGetSmartCollectionsCountRequest {
+            client: self,
+            title: None,
+            product_id: None,
+            updated_at_min: None,
+            updated_at_max: None,
+            published_at_min: None,
+            published_at_max: None,
+            published_status: None,
+        }
+    }
     /**
      * Retrieves a single smart collection.
      *
@@ -8745,6 +20214,25 @@ impl Products {
             )
             .await
     }
+    /**
+     * Retrieves a single smart collection.
+     *
+     * This function performs a `GET` to the `/admin/api/unstable/smart_collections/{smart_collection_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_unstable_get_smart_collections_param_collection`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/smartcollection#show-unstable
+     */
+    pub fn deprecated_unstable_get_smart_collections_param_collection_request<'a>(
+        &'a self,
+        smart_collection_id: &'a str,
+    ) -> ProductsDeprecatedUnstableGetSmartCollectionsParamCollectionRequest<'a> {
+        ProductsDeprecatedUnstableGetSmartCollectionsParamCollectionRequest {
+            client: self,
+            smart_collection_id,
+            fields: None,
+        }
+    }
     /**
      * Updates an existing smart collection.
      *
@@ -8858,4 +20346,26 @@ impl Products {
             )
             .await
     }
+    /**
+     * Updates the ordering type of products in a smart collection.
+     *
+     * This function performs a `PUT` to the `/admin/api/unstable/smart_collections/{smart_collection_id}/order.json` endpoint.
+     *
+     * As opposed to `deprecated_unstable_update_smart_collections_param_collection_order`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/smartcollection#order-unstable
+     */
+    pub fn deprecated_unstable_update_smart_collections_param_collection_order_request<'a>(
+        &'a self,
+        smart_collection_id: &'a str,
+        body: &'a serde_json::Value,
+    ) -> ProductsDeprecatedUnstableUpdateSmartCollectionsParamCollectionOrderRequest<'a> {
+        ProductsDeprecatedUnstableUpdateSmartCollectionsParamCollectionOrderRequest {
+            client: self,
+            smart_collection_id,
+            products: None,
+            sort_order: None,
+            body,
+        }
+    }
 }
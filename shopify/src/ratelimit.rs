@@ -0,0 +1,107 @@
+//! The rate limits the API reports in the headers of its responses.
+
+use std::{
+    sync::Mutex,
+    time::{Duration, SystemTime},
+};
+
+/// The state of the rate limit of the API, as reported by a response.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct RateLimitInfo {
+    /// How many requests can be made in the current window.
+    pub limit: Option<u64>,
+    /// How many requests are left in the current window.
+    pub remaining: Option<u64>,
+    /// When the current window ends.
+    pub reset: Option<SystemTime>,
+    /// How long the API asked to wait before the next request, through the
+    /// `Retry-After` header.
+    pub retry_after: Option<Duration>,
+}
+
+impl RateLimitInfo {
+    /// Parses the rate limit headers of a response, returning `None` if it has none.
+    pub fn from_headers(headers: &reqwest::header::HeaderMap) -> Option<Self> {
+        let info = RateLimitInfo {
+            retry_after: crate::middleware::retry_after(headers),
+            ..parse(headers)
+        };
+
+        if info == RateLimitInfo::default() {
+            None
+        } else {
+            Some(info)
+        }
+    }
+
+    /// Returns how long to wait before the next request, if the limit is exhausted.
+    pub fn wait(&self) -> Option<Duration> {
+        if let Some(retry_after) = self.retry_after {
+            return Some(retry_after);
+        }
+
+        match (self.remaining, self.reset) {
+            (Some(0), Some(reset)) => {
+                Some(reset.duration_since(SystemTime::now()).unwrap_or_default())
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Shopify reports how full its leaky bucket is, as `used/limit`. The bucket leaks at
+/// least one request a second, so a full one has room again a second later.
+fn parse(headers: &reqwest::header::HeaderMap) -> RateLimitInfo {
+    let call_limit = headers
+        .get("x-shopify-shop-api-call-limit")
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.split_once('/'))
+        .and_then(|(used, limit)| {
+            Some((
+                used.trim().parse::<u64>().ok()?,
+                limit.trim().parse::<u64>().ok()?,
+            ))
+        });
+    let remaining = call_limit.map(|(used, limit)| limit.saturating_sub(used));
+
+    RateLimitInfo {
+        limit: call_limit.map(|(_, limit)| limit),
+        remaining,
+        reset: remaining
+            .filter(|remaining| *remaining == 0)
+            .map(|_| SystemTime::now() + Duration::from_secs(1)),
+        retry_after: None,
+    }
+}
+
+/// Waits out exhausted rate limits before sending requests, rather than sending them
+/// only to have them rejected.
+pub(crate) struct RateLimiter {
+    pub(crate) max_wait: Duration,
+    pub(crate) until: Mutex<Option<SystemTime>>,
+}
+
+#[async_trait::async_trait]
+impl reqwest_middleware::Middleware for RateLimiter {
+    async fn handle(
+        &self,
+        req: reqwest::Request,
+        extensions: &mut task_local_extensions::Extensions,
+        next: reqwest_middleware::Next<'_>,
+    ) -> reqwest_middleware::Result<reqwest::Response> {
+        let until = *self.until.lock().unwrap();
+        if let Some(wait) = until.and_then(|until| until.duration_since(SystemTime::now()).ok()) {
+            // Requests that would have to wait for too long are sent anyway, for the API
+            // to reject them.
+            if wait <= self.max_wait {
+                tokio::time::sleep(wait).await;
+            }
+        }
+
+        let response = next.run(req, extensions).await?;
+        let wait = RateLimitInfo::from_headers(response.headers()).and_then(|info| info.wait());
+        *self.until.lock().unwrap() = wait.map(|wait| SystemTime::now() + wait);
+
+        Ok(response)
+    }
+}
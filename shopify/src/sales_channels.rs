@@ -1,6 +1,1530 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`SalesChannels::deprecated_202001_get_collection_listing`], set with a builder.
+pub struct SalesChannelsDeprecated202001GetCollectionListingRequest<'a> {
+    client: &'a SalesChannels,
+    limit: Option<&'a str>,
+}
+
+impl<'a> SalesChannelsDeprecated202001GetCollectionListingRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self { client, limit } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-01/collection_listings.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`SalesChannels::deprecated_202001_get_collection_listings_param_listing_product_id`], set with a builder.
+pub struct SalesChannelsDeprecated202001GetCollectionListingsParamListingProductIdRequest<'a> {
+    client: &'a SalesChannels,
+    collection_listing_id: &'a str,
+    limit: Option<&'a str>,
+}
+
+impl<'a> SalesChannelsDeprecated202001GetCollectionListingsParamListingProductIdRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            collection_listing_id,
+            limit,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-01/collection_listings/{}/product_ids.json?{}",
+                crate::progenitor_support::encode_path(collection_listing_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`SalesChannels::deprecated_202004_get_collection_listing`], set with a builder.
+pub struct SalesChannelsDeprecated202004GetCollectionListingRequest<'a> {
+    client: &'a SalesChannels,
+    limit: Option<&'a str>,
+}
+
+impl<'a> SalesChannelsDeprecated202004GetCollectionListingRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self { client, limit } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-04/collection_listings.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`SalesChannels::deprecated_202004_get_collection_listings_param_listing_product_id`], set with a builder.
+pub struct SalesChannelsDeprecated202004GetCollectionListingsParamListingProductIdRequest<'a> {
+    client: &'a SalesChannels,
+    collection_listing_id: &'a str,
+    limit: Option<&'a str>,
+}
+
+impl<'a> SalesChannelsDeprecated202004GetCollectionListingsParamListingProductIdRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            collection_listing_id,
+            limit,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-04/collection_listings/{}/product_ids.json?{}",
+                crate::progenitor_support::encode_path(collection_listing_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`SalesChannels::deprecated_202007_get_collection_listing`], set with a builder.
+pub struct SalesChannelsDeprecated202007GetCollectionListingRequest<'a> {
+    client: &'a SalesChannels,
+    limit: Option<&'a str>,
+}
+
+impl<'a> SalesChannelsDeprecated202007GetCollectionListingRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self { client, limit } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-07/collection_listings.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`SalesChannels::deprecated_202007_get_collection_listings_param_listing_product_id`], set with a builder.
+pub struct SalesChannelsDeprecated202007GetCollectionListingsParamListingProductIdRequest<'a> {
+    client: &'a SalesChannels,
+    collection_listing_id: &'a str,
+    limit: Option<&'a str>,
+}
+
+impl<'a> SalesChannelsDeprecated202007GetCollectionListingsParamListingProductIdRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            collection_listing_id,
+            limit,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-07/collection_listings/{}/product_ids.json?{}",
+                crate::progenitor_support::encode_path(collection_listing_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`SalesChannels::get_collection_listing`], set with a builder.
+pub struct SalesChannelsGetCollectionListingRequest<'a> {
+    client: &'a SalesChannels,
+    limit: Option<&'a str>,
+}
+
+impl<'a> SalesChannelsGetCollectionListingRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self { client, limit } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-10/collection_listings.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`SalesChannels::get_collection_listings_param_listing_product_id`], set with a builder.
+pub struct SalesChannelsGetCollectionListingsParamListingProductIdRequest<'a> {
+    client: &'a SalesChannels,
+    collection_listing_id: &'a str,
+    limit: Option<&'a str>,
+}
+
+impl<'a> SalesChannelsGetCollectionListingsParamListingProductIdRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            collection_listing_id,
+            limit,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-10/collection_listings/{}/product_ids.json?{}",
+                crate::progenitor_support::encode_path(collection_listing_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`SalesChannels::deprecated_202101_get_collection_listing`], set with a builder.
+pub struct SalesChannelsDeprecated202101GetCollectionListingRequest<'a> {
+    client: &'a SalesChannels,
+    limit: Option<&'a str>,
+}
+
+impl<'a> SalesChannelsDeprecated202101GetCollectionListingRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self { client, limit } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2021-01/collection_listings.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`SalesChannels::deprecated_202101_get_collection_listings_param_listing_product_id`], set with a builder.
+pub struct SalesChannelsDeprecated202101GetCollectionListingsParamListingProductIdRequest<'a> {
+    client: &'a SalesChannels,
+    collection_listing_id: &'a str,
+    limit: Option<&'a str>,
+}
+
+impl<'a> SalesChannelsDeprecated202101GetCollectionListingsParamListingProductIdRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            collection_listing_id,
+            limit,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2021-01/collection_listings/{}/product_ids.json?{}",
+                crate::progenitor_support::encode_path(collection_listing_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`SalesChannels::deprecated_unstable_get_collection_listing`], set with a builder.
+pub struct SalesChannelsDeprecatedUnstableGetCollectionListingRequest<'a> {
+    client: &'a SalesChannels,
+    limit: Option<&'a str>,
+}
+
+impl<'a> SalesChannelsDeprecatedUnstableGetCollectionListingRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self { client, limit } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/unstable/collection_listings.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`SalesChannels::deprecated_unstable_get_collection_listings_param_listing_product_id`], set with a builder.
+pub struct SalesChannelsDeprecatedUnstableGetCollectionListingsParamListingProductIdRequest<'a> {
+    client: &'a SalesChannels,
+    collection_listing_id: &'a str,
+    limit: Option<&'a str>,
+}
+
+impl<'a> SalesChannelsDeprecatedUnstableGetCollectionListingsParamListingProductIdRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            collection_listing_id,
+            limit,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/unstable/collection_listings/{}/product_ids.json?{}",
+                crate::progenitor_support::encode_path(collection_listing_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`SalesChannels::deprecated_202001_create_checkouts_param_token_payments`], set with a builder.
+pub struct SalesChannelsDeprecated202001CreateCheckoutsParamTokenPaymentsRequest<'a> {
+    client: &'a SalesChannels,
+    token: &'a str,
+    amount_required: Option<&'a str>,
+    request_details_required: Option<&'a str>,
+    session_id_required: Option<&'a str>,
+    unique_token_required: Option<&'a str>,
+    body: &'a serde_json::Value,
+}
+
+impl<'a> SalesChannelsDeprecated202001CreateCheckoutsParamTokenPaymentsRequest<'a> {
+    /// Sets the `amount required` query parameter.
+    pub fn amount_required(mut self, amount_required: &'a str) -> Self {
+        self.amount_required = Some(amount_required);
+        self
+    }
+
+    /// Sets the `request_details required` query parameter.
+    pub fn request_details_required(mut self, request_details_required: &'a str) -> Self {
+        self.request_details_required = Some(request_details_required);
+        self
+    }
+
+    /// Sets the `session_id required` query parameter.
+    pub fn session_id_required(mut self, session_id_required: &'a str) -> Self {
+        self.session_id_required = Some(session_id_required);
+        self
+    }
+
+    /// Sets the `unique_token required` query parameter.
+    pub fn unique_token_required(mut self, unique_token_required: &'a str) -> Self {
+        self.unique_token_required = Some(unique_token_required);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            token,
+            amount_required,
+            request_details_required,
+            session_id_required,
+            unique_token_required,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = amount_required {
+            query_args.push((
+                "amount
+                  required"
+                    .to_string(),
+                v.to_string(),
+            ));
+        }
+        if let Some(v) = request_details_required {
+            query_args.push((
+                "request_details
+                  required"
+                    .to_string(),
+                v.to_string(),
+            ));
+        }
+        if let Some(v) = session_id_required {
+            query_args.push((
+                "session_id
+                  required"
+                    .to_string(),
+                v.to_string(),
+            ));
+        }
+        if let Some(v) = unique_token_required {
+            query_args.push((
+                "unique_token
+                  required"
+                    .to_string(),
+                v.to_string(),
+            ));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-01/checkouts/{}/payments.json?{}",
+                crate::progenitor_support::encode_path(token),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`SalesChannels::deprecated_202004_create_checkouts_param_token_payments`], set with a builder.
+pub struct SalesChannelsDeprecated202004CreateCheckoutsParamTokenPaymentsRequest<'a> {
+    client: &'a SalesChannels,
+    token: &'a str,
+    amount_required: Option<&'a str>,
+    request_details_required: Option<&'a str>,
+    session_id_required: Option<&'a str>,
+    unique_token_required: Option<&'a str>,
+    body: &'a serde_json::Value,
+}
+
+impl<'a> SalesChannelsDeprecated202004CreateCheckoutsParamTokenPaymentsRequest<'a> {
+    /// Sets the `amount required` query parameter.
+    pub fn amount_required(mut self, amount_required: &'a str) -> Self {
+        self.amount_required = Some(amount_required);
+        self
+    }
+
+    /// Sets the `request_details required` query parameter.
+    pub fn request_details_required(mut self, request_details_required: &'a str) -> Self {
+        self.request_details_required = Some(request_details_required);
+        self
+    }
+
+    /// Sets the `session_id required` query parameter.
+    pub fn session_id_required(mut self, session_id_required: &'a str) -> Self {
+        self.session_id_required = Some(session_id_required);
+        self
+    }
+
+    /// Sets the `unique_token required` query parameter.
+    pub fn unique_token_required(mut self, unique_token_required: &'a str) -> Self {
+        self.unique_token_required = Some(unique_token_required);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            token,
+            amount_required,
+            request_details_required,
+            session_id_required,
+            unique_token_required,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = amount_required {
+            query_args.push((
+                "amount
+                  required"
+                    .to_string(),
+                v.to_string(),
+            ));
+        }
+        if let Some(v) = request_details_required {
+            query_args.push((
+                "request_details
+                  required"
+                    .to_string(),
+                v.to_string(),
+            ));
+        }
+        if let Some(v) = session_id_required {
+            query_args.push((
+                "session_id
+                  required"
+                    .to_string(),
+                v.to_string(),
+            ));
+        }
+        if let Some(v) = unique_token_required {
+            query_args.push((
+                "unique_token
+                  required"
+                    .to_string(),
+                v.to_string(),
+            ));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-04/checkouts/{}/payments.json?{}",
+                crate::progenitor_support::encode_path(token),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`SalesChannels::deprecated_202007_create_checkouts_param_token_payments`], set with a builder.
+pub struct SalesChannelsDeprecated202007CreateCheckoutsParamTokenPaymentsRequest<'a> {
+    client: &'a SalesChannels,
+    token: &'a str,
+    amount_required: Option<&'a str>,
+    request_details_required: Option<&'a str>,
+    session_id_required: Option<&'a str>,
+    unique_token_required: Option<&'a str>,
+    body: &'a serde_json::Value,
+}
+
+impl<'a> SalesChannelsDeprecated202007CreateCheckoutsParamTokenPaymentsRequest<'a> {
+    /// Sets the `amount required` query parameter.
+    pub fn amount_required(mut self, amount_required: &'a str) -> Self {
+        self.amount_required = Some(amount_required);
+        self
+    }
+
+    /// Sets the `request_details required` query parameter.
+    pub fn request_details_required(mut self, request_details_required: &'a str) -> Self {
+        self.request_details_required = Some(request_details_required);
+        self
+    }
+
+    /// Sets the `session_id required` query parameter.
+    pub fn session_id_required(mut self, session_id_required: &'a str) -> Self {
+        self.session_id_required = Some(session_id_required);
+        self
+    }
+
+    /// Sets the `unique_token required` query parameter.
+    pub fn unique_token_required(mut self, unique_token_required: &'a str) -> Self {
+        self.unique_token_required = Some(unique_token_required);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            token,
+            amount_required,
+            request_details_required,
+            session_id_required,
+            unique_token_required,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = amount_required {
+            query_args.push((
+                "amount
+                  required"
+                    .to_string(),
+                v.to_string(),
+            ));
+        }
+        if let Some(v) = request_details_required {
+            query_args.push((
+                "request_details
+                  required"
+                    .to_string(),
+                v.to_string(),
+            ));
+        }
+        if let Some(v) = session_id_required {
+            query_args.push((
+                "session_id
+                  required"
+                    .to_string(),
+                v.to_string(),
+            ));
+        }
+        if let Some(v) = unique_token_required {
+            query_args.push((
+                "unique_token
+                  required"
+                    .to_string(),
+                v.to_string(),
+            ));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-07/checkouts/{}/payments.json?{}",
+                crate::progenitor_support::encode_path(token),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`SalesChannels::create_checkouts_param_token_payments`], set with a builder.
+pub struct SalesChannelsCreateCheckoutsParamTokenPaymentsRequest<'a> {
+    client: &'a SalesChannels,
+    token: &'a str,
+    amount_required: Option<&'a str>,
+    request_details_required: Option<&'a str>,
+    session_id_required: Option<&'a str>,
+    unique_token_required: Option<&'a str>,
+    body: &'a serde_json::Value,
+}
+
+impl<'a> SalesChannelsCreateCheckoutsParamTokenPaymentsRequest<'a> {
+    /// Sets the `amount required` query parameter.
+    pub fn amount_required(mut self, amount_required: &'a str) -> Self {
+        self.amount_required = Some(amount_required);
+        self
+    }
+
+    /// Sets the `request_details required` query parameter.
+    pub fn request_details_required(mut self, request_details_required: &'a str) -> Self {
+        self.request_details_required = Some(request_details_required);
+        self
+    }
+
+    /// Sets the `session_id required` query parameter.
+    pub fn session_id_required(mut self, session_id_required: &'a str) -> Self {
+        self.session_id_required = Some(session_id_required);
+        self
+    }
+
+    /// Sets the `unique_token required` query parameter.
+    pub fn unique_token_required(mut self, unique_token_required: &'a str) -> Self {
+        self.unique_token_required = Some(unique_token_required);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            token,
+            amount_required,
+            request_details_required,
+            session_id_required,
+            unique_token_required,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = amount_required {
+            query_args.push((
+                "amount
+                  required"
+                    .to_string(),
+                v.to_string(),
+            ));
+        }
+        if let Some(v) = request_details_required {
+            query_args.push((
+                "request_details
+                  required"
+                    .to_string(),
+                v.to_string(),
+            ));
+        }
+        if let Some(v) = session_id_required {
+            query_args.push((
+                "session_id
+                  required"
+                    .to_string(),
+                v.to_string(),
+            ));
+        }
+        if let Some(v) = unique_token_required {
+            query_args.push((
+                "unique_token
+                  required"
+                    .to_string(),
+                v.to_string(),
+            ));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-10/checkouts/{}/payments.json?{}",
+                crate::progenitor_support::encode_path(token),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`SalesChannels::deprecated_202101_create_checkouts_param_token_payments`], set with a builder.
+pub struct SalesChannelsDeprecated202101CreateCheckoutsParamTokenPaymentsRequest<'a> {
+    client: &'a SalesChannels,
+    token: &'a str,
+    amount_required: Option<&'a str>,
+    request_details_required: Option<&'a str>,
+    session_id_required: Option<&'a str>,
+    unique_token_required: Option<&'a str>,
+    body: &'a serde_json::Value,
+}
+
+impl<'a> SalesChannelsDeprecated202101CreateCheckoutsParamTokenPaymentsRequest<'a> {
+    /// Sets the `amount required` query parameter.
+    pub fn amount_required(mut self, amount_required: &'a str) -> Self {
+        self.amount_required = Some(amount_required);
+        self
+    }
+
+    /// Sets the `request_details required` query parameter.
+    pub fn request_details_required(mut self, request_details_required: &'a str) -> Self {
+        self.request_details_required = Some(request_details_required);
+        self
+    }
+
+    /// Sets the `session_id required` query parameter.
+    pub fn session_id_required(mut self, session_id_required: &'a str) -> Self {
+        self.session_id_required = Some(session_id_required);
+        self
+    }
+
+    /// Sets the `unique_token required` query parameter.
+    pub fn unique_token_required(mut self, unique_token_required: &'a str) -> Self {
+        self.unique_token_required = Some(unique_token_required);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            token,
+            amount_required,
+            request_details_required,
+            session_id_required,
+            unique_token_required,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = amount_required {
+            query_args.push((
+                "amount
+                  required"
+                    .to_string(),
+                v.to_string(),
+            ));
+        }
+        if let Some(v) = request_details_required {
+            query_args.push((
+                "request_details
+                  required"
+                    .to_string(),
+                v.to_string(),
+            ));
+        }
+        if let Some(v) = session_id_required {
+            query_args.push((
+                "session_id
+                  required"
+                    .to_string(),
+                v.to_string(),
+            ));
+        }
+        if let Some(v) = unique_token_required {
+            query_args.push((
+                "unique_token
+                  required"
+                    .to_string(),
+                v.to_string(),
+            ));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2021-01/checkouts/{}/payments.json?{}",
+                crate::progenitor_support::encode_path(token),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`SalesChannels::deprecated_unstable_create_checkouts_param_token_payments`], set with a builder.
+pub struct SalesChannelsDeprecatedUnstableCreateCheckoutsParamTokenPaymentsRequest<'a> {
+    client: &'a SalesChannels,
+    token: &'a str,
+    amount_required: Option<&'a str>,
+    request_details_required: Option<&'a str>,
+    session_id_required: Option<&'a str>,
+    unique_token_required: Option<&'a str>,
+    body: &'a serde_json::Value,
+}
+
+impl<'a> SalesChannelsDeprecatedUnstableCreateCheckoutsParamTokenPaymentsRequest<'a> {
+    /// Sets the `amount required` query parameter.
+    pub fn amount_required(mut self, amount_required: &'a str) -> Self {
+        self.amount_required = Some(amount_required);
+        self
+    }
+
+    /// Sets the `request_details required` query parameter.
+    pub fn request_details_required(mut self, request_details_required: &'a str) -> Self {
+        self.request_details_required = Some(request_details_required);
+        self
+    }
+
+    /// Sets the `session_id required` query parameter.
+    pub fn session_id_required(mut self, session_id_required: &'a str) -> Self {
+        self.session_id_required = Some(session_id_required);
+        self
+    }
+
+    /// Sets the `unique_token required` query parameter.
+    pub fn unique_token_required(mut self, unique_token_required: &'a str) -> Self {
+        self.unique_token_required = Some(unique_token_required);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            token,
+            amount_required,
+            request_details_required,
+            session_id_required,
+            unique_token_required,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = amount_required {
+            query_args.push((
+                "amount
+                  required"
+                    .to_string(),
+                v.to_string(),
+            ));
+        }
+        if let Some(v) = request_details_required {
+            query_args.push((
+                "request_details
+                  required"
+                    .to_string(),
+                v.to_string(),
+            ));
+        }
+        if let Some(v) = session_id_required {
+            query_args.push((
+                "session_id
+                  required"
+                    .to_string(),
+                v.to_string(),
+            ));
+        }
+        if let Some(v) = unique_token_required {
+            query_args.push((
+                "unique_token
+                  required"
+                    .to_string(),
+                v.to_string(),
+            ));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/unstable/checkouts/{}/payments.json?{}",
+                crate::progenitor_support::encode_path(token),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`SalesChannels::deprecated_202001_get_product_listing`], set with a builder.
+pub struct SalesChannelsDeprecated202001GetProductListingRequest<'a> {
+    client: &'a SalesChannels,
+    product_ids: Option<&'a str>,
+    limit: Option<&'a str>,
+    collection_id: Option<&'a str>,
+    updated_at_min: Option<&'a str>,
+    handle: Option<&'a str>,
+}
+
+impl<'a> SalesChannelsDeprecated202001GetProductListingRequest<'a> {
+    /// Sets the `product_ids` query parameter.
+    pub fn product_ids(mut self, product_ids: &'a str) -> Self {
+        self.product_ids = Some(product_ids);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `collection_id` query parameter.
+    pub fn collection_id(mut self, collection_id: &'a str) -> Self {
+        self.collection_id = Some(collection_id);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.updated_at_min = Some(updated_at_min);
+        self
+    }
+
+    /// Sets the `handle` query parameter.
+    pub fn handle(mut self, handle: &'a str) -> Self {
+        self.handle = Some(handle);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            product_ids,
+            limit,
+            collection_id,
+            updated_at_min,
+            handle,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = collection_id {
+            query_args.push(("collection_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = handle {
+            query_args.push(("handle".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = product_ids {
+            query_args.push(("product_ids".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_min {
+            query_args.push(("updated_at_min".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-01/product_listings.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`SalesChannels::deprecated_202001_get_product_listings_id`], set with a builder.
+pub struct SalesChannelsDeprecated202001GetProductListingsIdRequest<'a> {
+    client: &'a SalesChannels,
+    limit: Option<&'a str>,
+}
+
+impl<'a> SalesChannelsDeprecated202001GetProductListingsIdRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self { client, limit } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-01/product_listings/product_ids.json?{}",
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`SalesChannels::deprecated_202004_get_product_listing`], set with a builder.
+pub struct SalesChannelsDeprecated202004GetProductListingRequest<'a> {
+    client: &'a SalesChannels,
+    product_ids: Option<&'a str>,
+    limit: Option<&'a str>,
+    collection_id: Option<&'a str>,
+    updated_at_min: Option<&'a str>,
+    handle: Option<&'a str>,
+}
+
+impl<'a> SalesChannelsDeprecated202004GetProductListingRequest<'a> {
+    /// Sets the `product_ids` query parameter.
+    pub fn product_ids(mut self, product_ids: &'a str) -> Self {
+        self.product_ids = Some(product_ids);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `collection_id` query parameter.
+    pub fn collection_id(mut self, collection_id: &'a str) -> Self {
+        self.collection_id = Some(collection_id);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.updated_at_min = Some(updated_at_min);
+        self
+    }
+
+    /// Sets the `handle` query parameter.
+    pub fn handle(mut self, handle: &'a str) -> Self {
+        self.handle = Some(handle);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            product_ids,
+            limit,
+            collection_id,
+            updated_at_min,
+            handle,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = collection_id {
+            query_args.push(("collection_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = handle {
+            query_args.push(("handle".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = product_ids {
+            query_args.push(("product_ids".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_min {
+            query_args.push(("updated_at_min".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-04/product_listings.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`SalesChannels::deprecated_202004_get_product_listings_id`], set with a builder.
+pub struct SalesChannelsDeprecated202004GetProductListingsIdRequest<'a> {
+    client: &'a SalesChannels,
+    limit: Option<&'a str>,
+}
+
+impl<'a> SalesChannelsDeprecated202004GetProductListingsIdRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self { client, limit } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-04/product_listings/product_ids.json?{}",
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`SalesChannels::deprecated_202007_get_product_listing`], set with a builder.
+pub struct SalesChannelsDeprecated202007GetProductListingRequest<'a> {
+    client: &'a SalesChannels,
+    product_ids: Option<&'a str>,
+    limit: Option<&'a str>,
+    collection_id: Option<&'a str>,
+    updated_at_min: Option<&'a str>,
+    handle: Option<&'a str>,
+}
+
+impl<'a> SalesChannelsDeprecated202007GetProductListingRequest<'a> {
+    /// Sets the `product_ids` query parameter.
+    pub fn product_ids(mut self, product_ids: &'a str) -> Self {
+        self.product_ids = Some(product_ids);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `collection_id` query parameter.
+    pub fn collection_id(mut self, collection_id: &'a str) -> Self {
+        self.collection_id = Some(collection_id);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.updated_at_min = Some(updated_at_min);
+        self
+    }
+
+    /// Sets the `handle` query parameter.
+    pub fn handle(mut self, handle: &'a str) -> Self {
+        self.handle = Some(handle);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            product_ids,
+            limit,
+            collection_id,
+            updated_at_min,
+            handle,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = collection_id {
+            query_args.push(("collection_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = handle {
+            query_args.push(("handle".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = product_ids {
+            query_args.push(("product_ids".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_min {
+            query_args.push(("updated_at_min".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-07/product_listings.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`SalesChannels::deprecated_202007_get_product_listings_id`], set with a builder.
+pub struct SalesChannelsDeprecated202007GetProductListingsIdRequest<'a> {
+    client: &'a SalesChannels,
+    limit: Option<&'a str>,
+}
+
+impl<'a> SalesChannelsDeprecated202007GetProductListingsIdRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self { client, limit } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-07/product_listings/product_ids.json?{}",
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct SalesChannels {
     pub client: Client,
 }
@@ -970,6 +2494,23 @@ impl SalesChannels {
             )
             .await
     }
+    /**
+     * Retrieve collection listings that are published to your app. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-01/collection_listings.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_get_collection_listing`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/collectionlisting#index-2020-01
+     */
+    pub fn deprecated_202001_get_collection_listing_request(
+        &self,
+    ) -> SalesChannelsDeprecated202001GetCollectionListingRequest<'_> {
+        SalesChannelsDeprecated202001GetCollectionListingRequest {
+            client: self,
+            limit: None,
+        }
+    }
     /**
      * Retrieve product_ids that are published to a collection_id.       Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -1011,6 +2552,25 @@ impl SalesChannels {
             )
             .await
     }
+    /**
+     * Retrieve product_ids that are published to a collection_id.       Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-01/collection_listings/{collection_listing_id}/product_ids.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_get_collection_listings_param_listing_product_id`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/collectionlisting#product_ids-2020-01
+     */
+    pub fn deprecated_202001_get_collection_listings_param_listing_product_id_request<'a>(
+        &'a self,
+        collection_listing_id: &'a str,
+    ) -> SalesChannelsDeprecated202001GetCollectionListingsParamListingProductIdRequest<'a> {
+        SalesChannelsDeprecated202001GetCollectionListingsParamListingProductIdRequest {
+            client: self,
+            collection_listing_id,
+            limit: None,
+        }
+    }
     /**
      * Retrieve a specific collection listing that is published to your app.
      *
@@ -1143,6 +2703,23 @@ impl SalesChannels {
             )
             .await
     }
+    /**
+     * Retrieve collection listings that are published to your app. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-04/collection_listings.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_get_collection_listing`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/collectionlisting#index-2020-04
+     */
+    pub fn deprecated_202004_get_collection_listing_request(
+        &self,
+    ) -> SalesChannelsDeprecated202004GetCollectionListingRequest<'_> {
+        SalesChannelsDeprecated202004GetCollectionListingRequest {
+            client: self,
+            limit: None,
+        }
+    }
     /**
      * Retrieve product_ids that are published to a collection_id.       Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -1184,6 +2761,25 @@ impl SalesChannels {
             )
             .await
     }
+    /**
+     * Retrieve product_ids that are published to a collection_id.       Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-04/collection_listings/{collection_listing_id}/product_ids.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_get_collection_listings_param_listing_product_id`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/collectionlisting#product_ids-2020-04
+     */
+    pub fn deprecated_202004_get_collection_listings_param_listing_product_id_request<'a>(
+        &'a self,
+        collection_listing_id: &'a str,
+    ) -> SalesChannelsDeprecated202004GetCollectionListingsParamListingProductIdRequest<'a> {
+        SalesChannelsDeprecated202004GetCollectionListingsParamListingProductIdRequest {
+            client: self,
+            collection_listing_id,
+            limit: None,
+        }
+    }
     /**
      * Retrieve a specific collection listing that is published to your app.
      *
@@ -1316,6 +2912,23 @@ impl SalesChannels {
             )
             .await
     }
+    /**
+     * Retrieve collection listings that are published to your app. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-07/collection_listings.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_get_collection_listing`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/collectionlisting#index-2020-07
+     */
+    pub fn deprecated_202007_get_collection_listing_request(
+        &self,
+    ) -> SalesChannelsDeprecated202007GetCollectionListingRequest<'_> {
+        SalesChannelsDeprecated202007GetCollectionListingRequest {
+            client: self,
+            limit: None,
+        }
+    }
     /**
      * Retrieve product_ids that are published to a collection_id.       Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -1357,6 +2970,25 @@ impl SalesChannels {
             )
             .await
     }
+    /**
+     * Retrieve product_ids that are published to a collection_id.       Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-07/collection_listings/{collection_listing_id}/product_ids.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_get_collection_listings_param_listing_product_id`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/collectionlisting#product_ids-2020-07
+     */
+    pub fn deprecated_202007_get_collection_listings_param_listing_product_id_request<'a>(
+        &'a self,
+        collection_listing_id: &'a str,
+    ) -> SalesChannelsDeprecated202007GetCollectionListingsParamListingProductIdRequest<'a> {
+        SalesChannelsDeprecated202007GetCollectionListingsParamListingProductIdRequest {
+            client: self,
+            collection_listing_id,
+            limit: None,
+        }
+    }
     /**
      * Retrieve a specific collection listing that is published to your app.
      *
@@ -1486,6 +3118,21 @@ impl SalesChannels {
             )
             .await
     }
+    /**
+     * Retrieve collection listings that are published to your app. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-10/collection_listings.json` endpoint.
+     *
+     * As opposed to `get_collection_listing`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/collectionlisting#index-2020-10
+     */
+    pub fn get_collection_listing_request(&self) -> SalesChannelsGetCollectionListingRequest<'_> {
+        SalesChannelsGetCollectionListingRequest {
+            client: self,
+            limit: None,
+        }
+    }
     /**
      * Retrieve product_ids that are published to a collection_id.       Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -1527,6 +3174,25 @@ impl SalesChannels {
             )
             .await
     }
+    /**
+     * Retrieve product_ids that are published to a collection_id.       Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-10/collection_listings/{collection_listing_id}/product_ids.json` endpoint.
+     *
+     * As opposed to `get_collection_listings_param_listing_product_id`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/collectionlisting#product_ids-2020-10
+     */
+    pub fn get_collection_listings_param_listing_product_id_request<'a>(
+        &'a self,
+        collection_listing_id: &'a str,
+    ) -> SalesChannelsGetCollectionListingsParamListingProductIdRequest<'a> {
+        SalesChannelsGetCollectionListingsParamListingProductIdRequest {
+            client: self,
+            collection_listing_id,
+            limit: None,
+        }
+    }
     /**
      * Retrieve a specific collection listing that is published to your app.
      *
@@ -1659,6 +3325,23 @@ impl SalesChannels {
             )
             .await
     }
+    /**
+     * Retrieve collection listings that are published to your app. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2021-01/collection_listings.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_get_collection_listing`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/collectionlisting#index-2021-01
+     */
+    pub fn deprecated_202101_get_collection_listing_request(
+        &self,
+    ) -> SalesChannelsDeprecated202101GetCollectionListingRequest<'_> {
+        SalesChannelsDeprecated202101GetCollectionListingRequest {
+            client: self,
+            limit: None,
+        }
+    }
     /**
      * Retrieve product_ids that are published to a collection_id.       Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -1700,6 +3383,25 @@ impl SalesChannels {
             )
             .await
     }
+    /**
+     * Retrieve product_ids that are published to a collection_id.       Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2021-01/collection_listings/{collection_listing_id}/product_ids.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_get_collection_listings_param_listing_product_id`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/collectionlisting#product_ids-2021-01
+     */
+    pub fn deprecated_202101_get_collection_listings_param_listing_product_id_request<'a>(
+        &'a self,
+        collection_listing_id: &'a str,
+    ) -> SalesChannelsDeprecated202101GetCollectionListingsParamListingProductIdRequest<'a> {
+        SalesChannelsDeprecated202101GetCollectionListingsParamListingProductIdRequest {
+            client: self,
+            collection_listing_id,
+            limit: None,
+        }
+    }
     /**
      * Retrieve a specific collection listing that is published to your app.
      *
@@ -1832,6 +3534,23 @@ impl SalesChannels {
             )
             .await
     }
+    /**
+     * Retrieve collection listings that are published to your app. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/unstable/collection_listings.json` endpoint.
+     *
+     * As opposed to `deprecated_unstable_get_collection_listing`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/collectionlisting#index-unstable
+     */
+    pub fn deprecated_unstable_get_collection_listing_request(
+        &self,
+    ) -> SalesChannelsDeprecatedUnstableGetCollectionListingRequest<'_> {
+        SalesChannelsDeprecatedUnstableGetCollectionListingRequest {
+            client: self,
+            limit: None,
+        }
+    }
     /**
      * Retrieve product_ids that are published to a collection_id.       Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -1873,6 +3592,25 @@ impl SalesChannels {
             )
             .await
     }
+    /**
+     * Retrieve product_ids that are published to a collection_id.       Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/unstable/collection_listings/{collection_listing_id}/product_ids.json` endpoint.
+     *
+     * As opposed to `deprecated_unstable_get_collection_listings_param_listing_product_id`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/collectionlisting#product_ids-unstable
+     */
+    pub fn deprecated_unstable_get_collection_listings_param_listing_product_id_request<'a>(
+        &'a self,
+        collection_listing_id: &'a str,
+    ) -> SalesChannelsDeprecatedUnstableGetCollectionListingsParamListingProductIdRequest<'a> {
+        SalesChannelsDeprecatedUnstableGetCollectionListingsParamListingProductIdRequest {
+            client: self,
+            collection_listing_id,
+            limit: None,
+        }
+    }
     /**
      * Retrieve a specific collection listing that is published to your app.
      *
@@ -2083,6 +3821,30 @@ impl SalesChannels {
             )
             .await
     }
+    /**
+     * Creates a payment on a checkout using the session ID returned by the card vault.
+     *
+     * This function performs a `POST` to the `/admin/api/2020-01/checkouts/{token}/payments.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_create_checkouts_param_token_payments`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/payment#create_payment-2020-01
+     */
+    pub fn deprecated_202001_create_checkouts_param_token_payments_request<'a>(
+        &'a self,
+        token: &'a str,
+        body: &'a serde_json::Value,
+    ) -> SalesChannelsDeprecated202001CreateCheckoutsParamTokenPaymentsRequest<'a> {
+        SalesChannelsDeprecated202001CreateCheckoutsParamTokenPaymentsRequest {
+            client: self,
+            token,
+            amount_required: None,
+            request_details_required: None,
+            session_id_required: None,
+            unique_token_required: None,
+            body,
+        }
+    }
     /**
      * Retrieves the payment information for an existing payment.
      *
@@ -2263,6 +4025,30 @@ impl SalesChannels {
             )
             .await
     }
+    /**
+     * Creates a payment on a checkout using the session ID returned by the card vault.
+     *
+     * This function performs a `POST` to the `/admin/api/2020-04/checkouts/{token}/payments.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_create_checkouts_param_token_payments`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/payment#create_payment-2020-04
+     */
+    pub fn deprecated_202004_create_checkouts_param_token_payments_request<'a>(
+        &'a self,
+        token: &'a str,
+        body: &'a serde_json::Value,
+    ) -> SalesChannelsDeprecated202004CreateCheckoutsParamTokenPaymentsRequest<'a> {
+        SalesChannelsDeprecated202004CreateCheckoutsParamTokenPaymentsRequest {
+            client: self,
+            token,
+            amount_required: None,
+            request_details_required: None,
+            session_id_required: None,
+            unique_token_required: None,
+            body,
+        }
+    }
     /**
      * Retrieves the payment information for an existing payment.
      *
@@ -2443,6 +4229,30 @@ impl SalesChannels {
             )
             .await
     }
+    /**
+     * Creates a payment on a checkout using the session ID returned by the card vault.
+     *
+     * This function performs a `POST` to the `/admin/api/2020-07/checkouts/{token}/payments.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_create_checkouts_param_token_payments`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/payment#create_payment-2020-07
+     */
+    pub fn deprecated_202007_create_checkouts_param_token_payments_request<'a>(
+        &'a self,
+        token: &'a str,
+        body: &'a serde_json::Value,
+    ) -> SalesChannelsDeprecated202007CreateCheckoutsParamTokenPaymentsRequest<'a> {
+        SalesChannelsDeprecated202007CreateCheckoutsParamTokenPaymentsRequest {
+            client: self,
+            token,
+            amount_required: None,
+            request_details_required: None,
+            session_id_required: None,
+            unique_token_required: None,
+            body,
+        }
+    }
     /**
      * Retrieves the payment information for an existing payment.
      *
@@ -2623,6 +4433,30 @@ impl SalesChannels {
             )
             .await
     }
+    /**
+     * Creates a payment on a checkout using the session ID returned by the card vault.
+     *
+     * This function performs a `POST` to the `/admin/api/2020-10/checkouts/{token}/payments.json` endpoint.
+     *
+     * As opposed to `create_checkouts_param_token_payments`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/payment#create_payment-2020-10
+     */
+    pub fn create_checkouts_param_token_payments_request<'a>(
+        &'a self,
+        token: &'a str,
+        body: &'a serde_json::Value,
+    ) -> SalesChannelsCreateCheckoutsParamTokenPaymentsRequest<'a> {
+        SalesChannelsCreateCheckoutsParamTokenPaymentsRequest {
+            client: self,
+            token,
+            amount_required: None,
+            request_details_required: None,
+            session_id_required: None,
+            unique_token_required: None,
+            body,
+        }
+    }
     /**
      * Retrieves the payment information for an existing payment.
      *
@@ -2803,6 +4637,30 @@ impl SalesChannels {
             )
             .await
     }
+    /**
+     * Creates a payment on a checkout using the session ID returned by the card vault.
+     *
+     * This function performs a `POST` to the `/admin/api/2021-01/checkouts/{token}/payments.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_create_checkouts_param_token_payments`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/payment#create_payment-2021-01
+     */
+    pub fn deprecated_202101_create_checkouts_param_token_payments_request<'a>(
+        &'a self,
+        token: &'a str,
+        body: &'a serde_json::Value,
+    ) -> SalesChannelsDeprecated202101CreateCheckoutsParamTokenPaymentsRequest<'a> {
+        SalesChannelsDeprecated202101CreateCheckoutsParamTokenPaymentsRequest {
+            client: self,
+            token,
+            amount_required: None,
+            request_details_required: None,
+            session_id_required: None,
+            unique_token_required: None,
+            body,
+        }
+    }
     /**
      * Retrieves the payment information for an existing payment.
      *
@@ -2983,6 +4841,30 @@ impl SalesChannels {
             )
             .await
     }
+    /**
+     * Creates a payment on a checkout using the session ID returned by the card vault.
+     *
+     * This function performs a `POST` to the `/admin/api/unstable/checkouts/{token}/payments.json` endpoint.
+     *
+     * As opposed to `deprecated_unstable_create_checkouts_param_token_payments`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/payment#create_payment-unstable
+     */
+    pub fn deprecated_unstable_create_checkouts_param_token_payments_request<'a>(
+        &'a self,
+        token: &'a str,
+        body: &'a serde_json::Value,
+    ) -> SalesChannelsDeprecatedUnstableCreateCheckoutsParamTokenPaymentsRequest<'a> {
+        SalesChannelsDeprecatedUnstableCreateCheckoutsParamTokenPaymentsRequest {
+            client: self,
+            token,
+            amount_required: None,
+            request_details_required: None,
+            session_id_required: None,
+            unique_token_required: None,
+            body,
+        }
+    }
     /**
      * Retrieves the payment information for an existing payment.
      *
@@ -3105,6 +4987,27 @@ impl SalesChannels {
             )
             .await
     }
+    /**
+     * Retrieve product listings that are published to your app. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-01/product_listings.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_get_product_listing`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/productlisting#index-2020-01
+     */
+    pub fn deprecated_202001_get_product_listing_request(
+        &self,
+    ) -> SalesChannelsDeprecated202001GetProductListingRequest<'_> {
+        SalesChannelsDeprecated202001GetProductListingRequest {
+            client: self,
+            product_ids: None,
+            limit: None,
+            collection_id: None,
+            updated_at_min: None,
+            handle: None,
+        }
+    }
     /**
      * Retrieve product_ids that are published to your app. Maximum 1,000 results per page.
      *
@@ -3143,6 +5046,23 @@ impl SalesChannels {
             )
             .await
     }
+    /**
+     * Retrieve product_ids that are published to your app. Maximum 1,000 results per page.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-01/product_listings/product_ids.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_get_product_listings_id`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/productlisting#product_ids-2020-01
+     */
+    pub fn deprecated_202001_get_product_listings_id_request(
+        &self,
+    ) -> SalesChannelsDeprecated202001GetProductListingsIdRequest<'_> {
+        SalesChannelsDeprecated202001GetProductListingsIdRequest {
+            client: self,
+            limit: None,
+        }
+    }
     /**
      * Retrieve a count of products that are published to your app.
      *
@@ -3318,6 +5238,27 @@ impl SalesChannels {
             )
             .await
     }
+    /**
+     * Retrieve product listings that are published to your app. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-04/product_listings.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_get_product_listing`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/productlisting#index-2020-04
+     */
+    pub fn deprecated_202004_get_product_listing_request(
+        &self,
+    ) -> SalesChannelsDeprecated202004GetProductListingRequest<'_> {
+        SalesChannelsDeprecated202004GetProductListingRequest {
+            client: self,
+            product_ids: None,
+            limit: None,
+            collection_id: None,
+            updated_at_min: None,
+            handle: None,
+        }
+    }
     /**
      * Retrieve product_ids that are published to your app. Maximum 1,000 results per page.
      *
@@ -3356,6 +5297,23 @@ impl SalesChannels {
             )
             .await
     }
+    /**
+     * Retrieve product_ids that are published to your app. Maximum 1,000 results per page.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-04/product_listings/product_ids.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_get_product_listings_id`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/productlisting#product_ids-2020-04
+     */
+    pub fn deprecated_202004_get_product_listings_id_request(
+        &self,
+    ) -> SalesChannelsDeprecated202004GetProductListingsIdRequest<'_> {
+        SalesChannelsDeprecated202004GetProductListingsIdRequest {
+            client: self,
+            limit: None,
+        }
+    }
     /**
      * Retrieve a count of products that are published to your app.
      *
@@ -3531,6 +5489,27 @@ impl SalesChannels {
             )
             .await
     }
+    /**
+     * Retrieve product listings that are published to your app. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-07/product_listings.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_get_product_listing`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/productlisting#index-2020-07
+     */
+    pub fn deprecated_202007_get_product_listing_request(
+        &self,
+    ) -> SalesChannelsDeprecated202007GetProductListingRequest<'_> {
+        SalesChannelsDeprecated202007GetProductListingRequest {
+            client: self,
+            product_ids: None,
+            limit: None,
+            collection_id: None,
+            updated_at_min: None,
+            handle: None,
+        }
+    }
     /**
      * Retrieve product_ids that are published to your app. Maximum 1,000 results per page.
      *
@@ -3569,6 +5548,23 @@ impl SalesChannels {
             )
             .await
     }
+    /**
+     * Retrieve product_ids that are published to your app. Maximum 1,000 results per page.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-07/product_listings/product_ids.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_get_product_listings_id`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/productlisting#product_ids-2020-07
+     */
+    pub fn deprecated_202007_get_product_listings_id_request(
+        &self,
+    ) -> SalesChannelsDeprecated202007GetProductListingsIdRequest<'_> {
+        SalesChannelsDeprecated202007GetProductListingsIdRequest {
+            client: self,
+            limit: None,
+        }
+    }
     /**
      * Retrieve a count of products that are published to your app.
      *
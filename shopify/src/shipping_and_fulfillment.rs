@@ -1,6 +1,4486 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`ShippingAndFulfillment::deprecated_202001_get_assigned_fulfillment_order`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecated202001GetAssignedFulfillmentOrderRequest<'a> {
+    client: &'a ShippingAndFulfillment,
+    assignment_status: Option<&'a str>,
+    location_ids: Option<&'a str>,
+}
+
+impl<'a> ShippingAndFulfillmentDeprecated202001GetAssignedFulfillmentOrderRequest<'a> {
+    /// Sets the `assignment_status` query parameter.
+    pub fn assignment_status(mut self, assignment_status: &'a str) -> Self {
+        self.assignment_status = Some(assignment_status);
+        self
+    }
+
+    /// Sets the `location_ids` query parameter.
+    pub fn location_ids(mut self, location_ids: &'a str) -> Self {
+        self.location_ids = Some(location_ids);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            assignment_status,
+            location_ids,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = assignment_status {
+            query_args.push(("assignment_status".to_string(), v.to_string()));
+        }
+        if let Some(v) = location_ids {
+            query_args.push(("location_ids".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-01/assigned_fulfillment_orders.json?{}",
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_202004_get_assigned_fulfillment_order`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecated202004GetAssignedFulfillmentOrderRequest<'a> {
+    client: &'a ShippingAndFulfillment,
+    assignment_status: Option<&'a str>,
+    location_ids: Option<&'a str>,
+}
+
+impl<'a> ShippingAndFulfillmentDeprecated202004GetAssignedFulfillmentOrderRequest<'a> {
+    /// Sets the `assignment_status` query parameter.
+    pub fn assignment_status(mut self, assignment_status: &'a str) -> Self {
+        self.assignment_status = Some(assignment_status);
+        self
+    }
+
+    /// Sets the `location_ids` query parameter.
+    pub fn location_ids(mut self, location_ids: &'a str) -> Self {
+        self.location_ids = Some(location_ids);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            assignment_status,
+            location_ids,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = assignment_status {
+            query_args.push(("assignment_status".to_string(), v.to_string()));
+        }
+        if let Some(v) = location_ids {
+            query_args.push(("location_ids".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-04/assigned_fulfillment_orders.json?{}",
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_202007_get_assigned_fulfillment_order`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecated202007GetAssignedFulfillmentOrderRequest<'a> {
+    client: &'a ShippingAndFulfillment,
+    assignment_status: Option<&'a str>,
+    location_ids: Option<&'a str>,
+}
+
+impl<'a> ShippingAndFulfillmentDeprecated202007GetAssignedFulfillmentOrderRequest<'a> {
+    /// Sets the `assignment_status` query parameter.
+    pub fn assignment_status(mut self, assignment_status: &'a str) -> Self {
+        self.assignment_status = Some(assignment_status);
+        self
+    }
+
+    /// Sets the `location_ids` query parameter.
+    pub fn location_ids(mut self, location_ids: &'a str) -> Self {
+        self.location_ids = Some(location_ids);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            assignment_status,
+            location_ids,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = assignment_status {
+            query_args.push(("assignment_status".to_string(), v.to_string()));
+        }
+        if let Some(v) = location_ids {
+            query_args.push(("location_ids".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-07/assigned_fulfillment_orders.json?{}",
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::get_assigned_fulfillment_order`], set with a builder.
+pub struct ShippingAndFulfillmentGetAssignedFulfillmentOrderRequest<'a> {
+    client: &'a ShippingAndFulfillment,
+    assignment_status: Option<&'a str>,
+    location_ids: Option<&'a str>,
+}
+
+impl<'a> ShippingAndFulfillmentGetAssignedFulfillmentOrderRequest<'a> {
+    /// Sets the `assignment_status` query parameter.
+    pub fn assignment_status(mut self, assignment_status: &'a str) -> Self {
+        self.assignment_status = Some(assignment_status);
+        self
+    }
+
+    /// Sets the `location_ids` query parameter.
+    pub fn location_ids(mut self, location_ids: &'a str) -> Self {
+        self.location_ids = Some(location_ids);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            assignment_status,
+            location_ids,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = assignment_status {
+            query_args.push(("assignment_status".to_string(), v.to_string()));
+        }
+        if let Some(v) = location_ids {
+            query_args.push(("location_ids".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-10/assigned_fulfillment_orders.json?{}",
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_202101_get_assigned_fulfillment_order`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecated202101GetAssignedFulfillmentOrderRequest<'a> {
+    client: &'a ShippingAndFulfillment,
+    assignment_status: Option<&'a str>,
+    location_ids: Option<&'a str>,
+}
+
+impl<'a> ShippingAndFulfillmentDeprecated202101GetAssignedFulfillmentOrderRequest<'a> {
+    /// Sets the `assignment_status` query parameter.
+    pub fn assignment_status(mut self, assignment_status: &'a str) -> Self {
+        self.assignment_status = Some(assignment_status);
+        self
+    }
+
+    /// Sets the `location_ids` query parameter.
+    pub fn location_ids(mut self, location_ids: &'a str) -> Self {
+        self.location_ids = Some(location_ids);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            assignment_status,
+            location_ids,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = assignment_status {
+            query_args.push(("assignment_status".to_string(), v.to_string()));
+        }
+        if let Some(v) = location_ids {
+            query_args.push(("location_ids".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2021-01/assigned_fulfillment_orders.json?{}",
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_unstable_get_assigned_fulfillment_order`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecatedUnstableGetAssignedFulfillmentOrderRequest<'a> {
+    client: &'a ShippingAndFulfillment,
+    assignment_status: Option<&'a str>,
+    location_ids: Option<&'a str>,
+}
+
+impl<'a> ShippingAndFulfillmentDeprecatedUnstableGetAssignedFulfillmentOrderRequest<'a> {
+    /// Sets the `assignment_status` query parameter.
+    pub fn assignment_status(mut self, assignment_status: &'a str) -> Self {
+        self.assignment_status = Some(assignment_status);
+        self
+    }
+
+    /// Sets the `location_ids` query parameter.
+    pub fn location_ids(mut self, location_ids: &'a str) -> Self {
+        self.location_ids = Some(location_ids);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            assignment_status,
+            location_ids,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = assignment_status {
+            query_args.push(("assignment_status".to_string(), v.to_string()));
+        }
+        if let Some(v) = location_ids {
+            query_args.push(("location_ids".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/unstable/assigned_fulfillment_orders.json?{}",
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_202001_create_fulfillment_orders_param_order_cancellation_request`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderCancellationRequestRequest<
+    'a,
+> {
+    client: &'a ShippingAndFulfillment,
+    fulfillment_order_id: &'a str,
+    message: Option<&'a str>,
+    body: &'a serde_json::Value,
+}
+
+impl<'a> ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderCancellationRequestRequest<'a> {
+/// Sets the `message` query parameter.
+            pub fn message(mut self, message: &'a str) -> Self {
+                self.message = Some(message);
+                self
+            }
+
+            /// Sends the request, with the parameters that were set.
+        pub async fn send(self) -> ClientResult<crate::Response<()>> {
+            let Self { client, fulfillment_order_id, message, body } = self;
+            let mut query_args: Vec<(String, String)> = Default::default();
+if let Some(v) = message { query_args.push(("message".to_string(), v.to_string())); }
+let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+let url = client.client.url(
+&format!("/admin/api/2020-01/fulfillment_orders/{}/cancellation_request.json?{}",
+crate::progenitor_support::encode_path(fulfillment_order_id),query_), None);
+
+            client.client.post(&url, crate::Message { body: Some(reqwest::Body::from(serde_json::to_vec(body)?)), content_type: Some("application/json".to_string()) } ).await
+        }
+        }
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_202001_create_fulfillment_orders_param_order_cancellation_request_accept`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderCancellationRequestAcceptRequest<
+    'a,
+> {
+    client: &'a ShippingAndFulfillment,
+    fulfillment_order_id: &'a str,
+    message: Option<&'a str>,
+    body: &'a serde_json::Value,
+}
+
+impl<'a> ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderCancellationRequestAcceptRequest<'a> {
+/// Sets the `message` query parameter.
+            pub fn message(mut self, message: &'a str) -> Self {
+                self.message = Some(message);
+                self
+            }
+
+            /// Sends the request, with the parameters that were set.
+        pub async fn send(self) -> ClientResult<crate::Response<()>> {
+            let Self { client, fulfillment_order_id, message, body } = self;
+            let mut query_args: Vec<(String, String)> = Default::default();
+if let Some(v) = message { query_args.push(("message".to_string(), v.to_string())); }
+let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+let url = client.client.url(
+&format!("/admin/api/2020-01/fulfillment_orders/{}/cancellation_request/accept.json?{}",
+crate::progenitor_support::encode_path(fulfillment_order_id),query_), None);
+
+            client.client.post(&url, crate::Message { body: Some(reqwest::Body::from(serde_json::to_vec(body)?)), content_type: Some("application/json".to_string()) } ).await
+        }
+        }
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_202001_create_fulfillment_orders_param_order_cancellation_request_reject`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderCancellationRequestRejectRequest<
+    'a,
+> {
+    client: &'a ShippingAndFulfillment,
+    fulfillment_order_id: &'a str,
+    message: Option<&'a str>,
+    body: &'a serde_json::Value,
+}
+
+impl<'a> ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderCancellationRequestRejectRequest<'a> {
+/// Sets the `message` query parameter.
+            pub fn message(mut self, message: &'a str) -> Self {
+                self.message = Some(message);
+                self
+            }
+
+            /// Sends the request, with the parameters that were set.
+        pub async fn send(self) -> ClientResult<crate::Response<()>> {
+            let Self { client, fulfillment_order_id, message, body } = self;
+            let mut query_args: Vec<(String, String)> = Default::default();
+if let Some(v) = message { query_args.push(("message".to_string(), v.to_string())); }
+let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+let url = client.client.url(
+&format!("/admin/api/2020-01/fulfillment_orders/{}/cancellation_request/reject.json?{}",
+crate::progenitor_support::encode_path(fulfillment_order_id),query_), None);
+
+            client.client.post(&url, crate::Message { body: Some(reqwest::Body::from(serde_json::to_vec(body)?)), content_type: Some("application/json".to_string()) } ).await
+        }
+        }
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_202004_create_fulfillment_orders_param_order_cancellation_request`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderCancellationRequestRequest<
+    'a,
+> {
+    client: &'a ShippingAndFulfillment,
+    fulfillment_order_id: &'a str,
+    message: Option<&'a str>,
+    body: &'a serde_json::Value,
+}
+
+impl<'a> ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderCancellationRequestRequest<'a> {
+/// Sets the `message` query parameter.
+            pub fn message(mut self, message: &'a str) -> Self {
+                self.message = Some(message);
+                self
+            }
+
+            /// Sends the request, with the parameters that were set.
+        pub async fn send(self) -> ClientResult<crate::Response<()>> {
+            let Self { client, fulfillment_order_id, message, body } = self;
+            let mut query_args: Vec<(String, String)> = Default::default();
+if let Some(v) = message { query_args.push(("message".to_string(), v.to_string())); }
+let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+let url = client.client.url(
+&format!("/admin/api/2020-04/fulfillment_orders/{}/cancellation_request.json?{}",
+crate::progenitor_support::encode_path(fulfillment_order_id),query_), None);
+
+            client.client.post(&url, crate::Message { body: Some(reqwest::Body::from(serde_json::to_vec(body)?)), content_type: Some("application/json".to_string()) } ).await
+        }
+        }
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_202004_create_fulfillment_orders_param_order_cancellation_request_accept`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderCancellationRequestAcceptRequest<
+    'a,
+> {
+    client: &'a ShippingAndFulfillment,
+    fulfillment_order_id: &'a str,
+    message: Option<&'a str>,
+    body: &'a serde_json::Value,
+}
+
+impl<'a> ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderCancellationRequestAcceptRequest<'a> {
+/// Sets the `message` query parameter.
+            pub fn message(mut self, message: &'a str) -> Self {
+                self.message = Some(message);
+                self
+            }
+
+            /// Sends the request, with the parameters that were set.
+        pub async fn send(self) -> ClientResult<crate::Response<()>> {
+            let Self { client, fulfillment_order_id, message, body } = self;
+            let mut query_args: Vec<(String, String)> = Default::default();
+if let Some(v) = message { query_args.push(("message".to_string(), v.to_string())); }
+let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+let url = client.client.url(
+&format!("/admin/api/2020-04/fulfillment_orders/{}/cancellation_request/accept.json?{}",
+crate::progenitor_support::encode_path(fulfillment_order_id),query_), None);
+
+            client.client.post(&url, crate::Message { body: Some(reqwest::Body::from(serde_json::to_vec(body)?)), content_type: Some("application/json".to_string()) } ).await
+        }
+        }
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_202004_create_fulfillment_orders_param_order_cancellation_request_reject`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderCancellationRequestRejectRequest<
+    'a,
+> {
+    client: &'a ShippingAndFulfillment,
+    fulfillment_order_id: &'a str,
+    message: Option<&'a str>,
+    body: &'a serde_json::Value,
+}
+
+impl<'a> ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderCancellationRequestRejectRequest<'a> {
+/// Sets the `message` query parameter.
+            pub fn message(mut self, message: &'a str) -> Self {
+                self.message = Some(message);
+                self
+            }
+
+            /// Sends the request, with the parameters that were set.
+        pub async fn send(self) -> ClientResult<crate::Response<()>> {
+            let Self { client, fulfillment_order_id, message, body } = self;
+            let mut query_args: Vec<(String, String)> = Default::default();
+if let Some(v) = message { query_args.push(("message".to_string(), v.to_string())); }
+let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+let url = client.client.url(
+&format!("/admin/api/2020-04/fulfillment_orders/{}/cancellation_request/reject.json?{}",
+crate::progenitor_support::encode_path(fulfillment_order_id),query_), None);
+
+            client.client.post(&url, crate::Message { body: Some(reqwest::Body::from(serde_json::to_vec(body)?)), content_type: Some("application/json".to_string()) } ).await
+        }
+        }
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_202007_create_fulfillment_orders_param_order_cancellation_request`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderCancellationRequestRequest<
+    'a,
+> {
+    client: &'a ShippingAndFulfillment,
+    fulfillment_order_id: &'a str,
+    message: Option<&'a str>,
+    body: &'a serde_json::Value,
+}
+
+impl<'a> ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderCancellationRequestRequest<'a> {
+/// Sets the `message` query parameter.
+            pub fn message(mut self, message: &'a str) -> Self {
+                self.message = Some(message);
+                self
+            }
+
+            /// Sends the request, with the parameters that were set.
+        pub async fn send(self) -> ClientResult<crate::Response<()>> {
+            let Self { client, fulfillment_order_id, message, body } = self;
+            let mut query_args: Vec<(String, String)> = Default::default();
+if let Some(v) = message { query_args.push(("message".to_string(), v.to_string())); }
+let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+let url = client.client.url(
+&format!("/admin/api/2020-07/fulfillment_orders/{}/cancellation_request.json?{}",
+crate::progenitor_support::encode_path(fulfillment_order_id),query_), None);
+
+            client.client.post(&url, crate::Message { body: Some(reqwest::Body::from(serde_json::to_vec(body)?)), content_type: Some("application/json".to_string()) } ).await
+        }
+        }
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_202007_create_fulfillment_orders_param_order_cancellation_request_accept`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderCancellationRequestAcceptRequest<
+    'a,
+> {
+    client: &'a ShippingAndFulfillment,
+    fulfillment_order_id: &'a str,
+    message: Option<&'a str>,
+    body: &'a serde_json::Value,
+}
+
+impl<'a> ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderCancellationRequestAcceptRequest<'a> {
+/// Sets the `message` query parameter.
+            pub fn message(mut self, message: &'a str) -> Self {
+                self.message = Some(message);
+                self
+            }
+
+            /// Sends the request, with the parameters that were set.
+        pub async fn send(self) -> ClientResult<crate::Response<()>> {
+            let Self { client, fulfillment_order_id, message, body } = self;
+            let mut query_args: Vec<(String, String)> = Default::default();
+if let Some(v) = message { query_args.push(("message".to_string(), v.to_string())); }
+let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+let url = client.client.url(
+&format!("/admin/api/2020-07/fulfillment_orders/{}/cancellation_request/accept.json?{}",
+crate::progenitor_support::encode_path(fulfillment_order_id),query_), None);
+
+            client.client.post(&url, crate::Message { body: Some(reqwest::Body::from(serde_json::to_vec(body)?)), content_type: Some("application/json".to_string()) } ).await
+        }
+        }
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_202007_create_fulfillment_orders_param_order_cancellation_request_reject`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderCancellationRequestRejectRequest<
+    'a,
+> {
+    client: &'a ShippingAndFulfillment,
+    fulfillment_order_id: &'a str,
+    message: Option<&'a str>,
+    body: &'a serde_json::Value,
+}
+
+impl<'a> ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderCancellationRequestRejectRequest<'a> {
+/// Sets the `message` query parameter.
+            pub fn message(mut self, message: &'a str) -> Self {
+                self.message = Some(message);
+                self
+            }
+
+            /// Sends the request, with the parameters that were set.
+        pub async fn send(self) -> ClientResult<crate::Response<()>> {
+            let Self { client, fulfillment_order_id, message, body } = self;
+            let mut query_args: Vec<(String, String)> = Default::default();
+if let Some(v) = message { query_args.push(("message".to_string(), v.to_string())); }
+let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+let url = client.client.url(
+&format!("/admin/api/2020-07/fulfillment_orders/{}/cancellation_request/reject.json?{}",
+crate::progenitor_support::encode_path(fulfillment_order_id),query_), None);
+
+            client.client.post(&url, crate::Message { body: Some(reqwest::Body::from(serde_json::to_vec(body)?)), content_type: Some("application/json".to_string()) } ).await
+        }
+        }
+
+/// The parameters of [`ShippingAndFulfillment::create_fulfillment_orders_param_order_cancellation_request`], set with a builder.
+pub struct ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderCancellationRequestRequest<'a> {
+    client: &'a ShippingAndFulfillment,
+    fulfillment_order_id: &'a str,
+    message: Option<&'a str>,
+    body: &'a serde_json::Value,
+}
+
+impl<'a> ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderCancellationRequestRequest<'a> {
+    /// Sets the `message` query parameter.
+    pub fn message(mut self, message: &'a str) -> Self {
+        self.message = Some(message);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            fulfillment_order_id,
+            message,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = message {
+            query_args.push(("message".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-10/fulfillment_orders/{}/cancellation_request.json?{}",
+                crate::progenitor_support::encode_path(fulfillment_order_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::create_fulfillment_orders_param_order_cancellation_request_accept`], set with a builder.
+pub struct ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderCancellationRequestAcceptRequest<
+    'a,
+> {
+    client: &'a ShippingAndFulfillment,
+    fulfillment_order_id: &'a str,
+    message: Option<&'a str>,
+    body: &'a serde_json::Value,
+}
+
+impl<'a>
+    ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderCancellationRequestAcceptRequest<'a>
+{
+    /// Sets the `message` query parameter.
+    pub fn message(mut self, message: &'a str) -> Self {
+        self.message = Some(message);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            fulfillment_order_id,
+            message,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = message {
+            query_args.push(("message".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-10/fulfillment_orders/{}/cancellation_request/accept.json?{}",
+                crate::progenitor_support::encode_path(fulfillment_order_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::create_fulfillment_orders_param_order_cancellation_request_reject`], set with a builder.
+pub struct ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderCancellationRequestRejectRequest<
+    'a,
+> {
+    client: &'a ShippingAndFulfillment,
+    fulfillment_order_id: &'a str,
+    message: Option<&'a str>,
+    body: &'a serde_json::Value,
+}
+
+impl<'a>
+    ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderCancellationRequestRejectRequest<'a>
+{
+    /// Sets the `message` query parameter.
+    pub fn message(mut self, message: &'a str) -> Self {
+        self.message = Some(message);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            fulfillment_order_id,
+            message,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = message {
+            query_args.push(("message".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-10/fulfillment_orders/{}/cancellation_request/reject.json?{}",
+                crate::progenitor_support::encode_path(fulfillment_order_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_202101_create_fulfillment_orders_param_order_cancellation_request`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderCancellationRequestRequest<
+    'a,
+> {
+    client: &'a ShippingAndFulfillment,
+    fulfillment_order_id: &'a str,
+    message: Option<&'a str>,
+    body: &'a serde_json::Value,
+}
+
+impl<'a> ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderCancellationRequestRequest<'a> {
+/// Sets the `message` query parameter.
+            pub fn message(mut self, message: &'a str) -> Self {
+                self.message = Some(message);
+                self
+            }
+
+            /// Sends the request, with the parameters that were set.
+        pub async fn send(self) -> ClientResult<crate::Response<()>> {
+            let Self { client, fulfillment_order_id, message, body } = self;
+            let mut query_args: Vec<(String, String)> = Default::default();
+if let Some(v) = message { query_args.push(("message".to_string(), v.to_string())); }
+let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+let url = client.client.url(
+&format!("/admin/api/2021-01/fulfillment_orders/{}/cancellation_request.json?{}",
+crate::progenitor_support::encode_path(fulfillment_order_id),query_), None);
+
+            client.client.post(&url, crate::Message { body: Some(reqwest::Body::from(serde_json::to_vec(body)?)), content_type: Some("application/json".to_string()) } ).await
+        }
+        }
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_202101_create_fulfillment_orders_param_order_cancellation_request_accept`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderCancellationRequestAcceptRequest<
+    'a,
+> {
+    client: &'a ShippingAndFulfillment,
+    fulfillment_order_id: &'a str,
+    message: Option<&'a str>,
+    body: &'a serde_json::Value,
+}
+
+impl<'a> ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderCancellationRequestAcceptRequest<'a> {
+/// Sets the `message` query parameter.
+            pub fn message(mut self, message: &'a str) -> Self {
+                self.message = Some(message);
+                self
+            }
+
+            /// Sends the request, with the parameters that were set.
+        pub async fn send(self) -> ClientResult<crate::Response<()>> {
+            let Self { client, fulfillment_order_id, message, body } = self;
+            let mut query_args: Vec<(String, String)> = Default::default();
+if let Some(v) = message { query_args.push(("message".to_string(), v.to_string())); }
+let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+let url = client.client.url(
+&format!("/admin/api/2021-01/fulfillment_orders/{}/cancellation_request/accept.json?{}",
+crate::progenitor_support::encode_path(fulfillment_order_id),query_), None);
+
+            client.client.post(&url, crate::Message { body: Some(reqwest::Body::from(serde_json::to_vec(body)?)), content_type: Some("application/json".to_string()) } ).await
+        }
+        }
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_202101_create_fulfillment_orders_param_order_cancellation_request_reject`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderCancellationRequestRejectRequest<
+    'a,
+> {
+    client: &'a ShippingAndFulfillment,
+    fulfillment_order_id: &'a str,
+    message: Option<&'a str>,
+    body: &'a serde_json::Value,
+}
+
+impl<'a> ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderCancellationRequestRejectRequest<'a> {
+/// Sets the `message` query parameter.
+            pub fn message(mut self, message: &'a str) -> Self {
+                self.message = Some(message);
+                self
+            }
+
+            /// Sends the request, with the parameters that were set.
+        pub async fn send(self) -> ClientResult<crate::Response<()>> {
+            let Self { client, fulfillment_order_id, message, body } = self;
+            let mut query_args: Vec<(String, String)> = Default::default();
+if let Some(v) = message { query_args.push(("message".to_string(), v.to_string())); }
+let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+let url = client.client.url(
+&format!("/admin/api/2021-01/fulfillment_orders/{}/cancellation_request/reject.json?{}",
+crate::progenitor_support::encode_path(fulfillment_order_id),query_), None);
+
+            client.client.post(&url, crate::Message { body: Some(reqwest::Body::from(serde_json::to_vec(body)?)), content_type: Some("application/json".to_string()) } ).await
+        }
+        }
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_unstable_create_fulfillment_orders_param_order_cancellation_request`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderCancellationRequestRequest<
+    'a,
+> {
+    client: &'a ShippingAndFulfillment,
+    fulfillment_order_id: &'a str,
+    message: Option<&'a str>,
+    body: &'a serde_json::Value,
+}
+
+impl<'a> ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderCancellationRequestRequest<'a> {
+/// Sets the `message` query parameter.
+            pub fn message(mut self, message: &'a str) -> Self {
+                self.message = Some(message);
+                self
+            }
+
+            /// Sends the request, with the parameters that were set.
+        pub async fn send(self) -> ClientResult<crate::Response<()>> {
+            let Self { client, fulfillment_order_id, message, body } = self;
+            let mut query_args: Vec<(String, String)> = Default::default();
+if let Some(v) = message { query_args.push(("message".to_string(), v.to_string())); }
+let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+let url = client.client.url(
+&format!("/admin/api/unstable/fulfillment_orders/{}/cancellation_request.json?{}",
+crate::progenitor_support::encode_path(fulfillment_order_id),query_), None);
+
+            client.client.post(&url, crate::Message { body: Some(reqwest::Body::from(serde_json::to_vec(body)?)), content_type: Some("application/json".to_string()) } ).await
+        }
+        }
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_unstable_create_fulfillment_orders_param_order_cancellation_request_accept`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderCancellationRequestAcceptRequest<
+    'a,
+> {
+    client: &'a ShippingAndFulfillment,
+    fulfillment_order_id: &'a str,
+    message: Option<&'a str>,
+    body: &'a serde_json::Value,
+}
+
+impl<'a> ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderCancellationRequestAcceptRequest<'a> {
+/// Sets the `message` query parameter.
+            pub fn message(mut self, message: &'a str) -> Self {
+                self.message = Some(message);
+                self
+            }
+
+            /// Sends the request, with the parameters that were set.
+        pub async fn send(self) -> ClientResult<crate::Response<()>> {
+            let Self { client, fulfillment_order_id, message, body } = self;
+            let mut query_args: Vec<(String, String)> = Default::default();
+if let Some(v) = message { query_args.push(("message".to_string(), v.to_string())); }
+let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+let url = client.client.url(
+&format!("/admin/api/unstable/fulfillment_orders/{}/cancellation_request/accept.json?{}",
+crate::progenitor_support::encode_path(fulfillment_order_id),query_), None);
+
+            client.client.post(&url, crate::Message { body: Some(reqwest::Body::from(serde_json::to_vec(body)?)), content_type: Some("application/json".to_string()) } ).await
+        }
+        }
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_unstable_create_fulfillment_orders_param_order_cancellation_request_reject`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderCancellationRequestRejectRequest<
+    'a,
+> {
+    client: &'a ShippingAndFulfillment,
+    fulfillment_order_id: &'a str,
+    message: Option<&'a str>,
+    body: &'a serde_json::Value,
+}
+
+impl<'a> ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderCancellationRequestRejectRequest<'a> {
+/// Sets the `message` query parameter.
+            pub fn message(mut self, message: &'a str) -> Self {
+                self.message = Some(message);
+                self
+            }
+
+            /// Sends the request, with the parameters that were set.
+        pub async fn send(self) -> ClientResult<crate::Response<()>> {
+            let Self { client, fulfillment_order_id, message, body } = self;
+            let mut query_args: Vec<(String, String)> = Default::default();
+if let Some(v) = message { query_args.push(("message".to_string(), v.to_string())); }
+let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+let url = client.client.url(
+&format!("/admin/api/unstable/fulfillment_orders/{}/cancellation_request/reject.json?{}",
+crate::progenitor_support::encode_path(fulfillment_order_id),query_), None);
+
+            client.client.post(&url, crate::Message { body: Some(reqwest::Body::from(serde_json::to_vec(body)?)), content_type: Some("application/json".to_string()) } ).await
+        }
+        }
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_202001_get_orders_param_order_fulfillment`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecated202001GetOrdersParamOrderFulfillmentRequest<'a> {
+    client: &'a ShippingAndFulfillment,
+    order_id: &'a str,
+    created_at_max: Option<&'a str>,
+    created_at_min: Option<&'a str>,
+    fields: Option<&'a str>,
+    limit: Option<&'a str>,
+    since_id: Option<&'a str>,
+    updated_at_max: Option<&'a str>,
+    updated_at_min: Option<&'a str>,
+}
+
+impl<'a> ShippingAndFulfillmentDeprecated202001GetOrdersParamOrderFulfillmentRequest<'a> {
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.created_at_max = Some(created_at_max);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.created_at_min = Some(created_at_min);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.updated_at_max = Some(updated_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.updated_at_min = Some(updated_at_min);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            order_id,
+            created_at_max,
+            created_at_min,
+            fields,
+            limit,
+            since_id,
+            updated_at_max,
+            updated_at_min,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = created_at_max {
+            query_args.push(("created_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = created_at_min {
+            query_args.push(("created_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_max {
+            query_args.push(("updated_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_min {
+            query_args.push(("updated_at_min".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-01/orders/{}/fulfillments.json?{}",
+                crate::progenitor_support::encode_path(order_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_202001_get_orders_param_order_fulfillments_count`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecated202001GetOrdersParamOrderFulfillmentsCountRequest<'a> {
+    client: &'a ShippingAndFulfillment,
+    order_id: &'a str,
+    created_at_min: Option<&'a str>,
+    created_at_max: Option<&'a str>,
+    updated_at_min: Option<&'a str>,
+    updated_at_max: Option<&'a str>,
+}
+
+impl<'a> ShippingAndFulfillmentDeprecated202001GetOrdersParamOrderFulfillmentsCountRequest<'a> {
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.created_at_min = Some(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.created_at_max = Some(created_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.updated_at_min = Some(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.updated_at_max = Some(updated_at_max);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            order_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = created_at_max {
+            query_args.push(("created_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = created_at_min {
+            query_args.push(("created_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_max {
+            query_args.push(("updated_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_min {
+            query_args.push(("updated_at_min".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-01/orders/{}/fulfillments/count.json?{}",
+                crate::progenitor_support::encode_path(order_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_202001_get_orders_param_order_fulfillments_fulfillment`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecated202001GetOrdersParamOrderFulfillmentsFulfillmentRequest<
+    'a,
+> {
+    client: &'a ShippingAndFulfillment,
+    order_id: &'a str,
+    fulfillment_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a>
+    ShippingAndFulfillmentDeprecated202001GetOrdersParamOrderFulfillmentsFulfillmentRequest<'a>
+{
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            order_id,
+            fulfillment_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-01/orders/{}/fulfillments/{}/json?{}",
+                crate::progenitor_support::encode_path(order_id),
+                crate::progenitor_support::encode_path(fulfillment_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_202004_get_orders_param_order_fulfillment`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecated202004GetOrdersParamOrderFulfillmentRequest<'a> {
+    client: &'a ShippingAndFulfillment,
+    order_id: &'a str,
+    created_at_max: Option<&'a str>,
+    created_at_min: Option<&'a str>,
+    fields: Option<&'a str>,
+    limit: Option<&'a str>,
+    since_id: Option<&'a str>,
+    updated_at_max: Option<&'a str>,
+    updated_at_min: Option<&'a str>,
+}
+
+impl<'a> ShippingAndFulfillmentDeprecated202004GetOrdersParamOrderFulfillmentRequest<'a> {
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.created_at_max = Some(created_at_max);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.created_at_min = Some(created_at_min);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.updated_at_max = Some(updated_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.updated_at_min = Some(updated_at_min);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            order_id,
+            created_at_max,
+            created_at_min,
+            fields,
+            limit,
+            since_id,
+            updated_at_max,
+            updated_at_min,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = created_at_max {
+            query_args.push(("created_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = created_at_min {
+            query_args.push(("created_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_max {
+            query_args.push(("updated_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_min {
+            query_args.push(("updated_at_min".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-04/orders/{}/fulfillments.json?{}",
+                crate::progenitor_support::encode_path(order_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_202004_get_orders_param_order_fulfillments_count`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecated202004GetOrdersParamOrderFulfillmentsCountRequest<'a> {
+    client: &'a ShippingAndFulfillment,
+    order_id: &'a str,
+    created_at_min: Option<&'a str>,
+    created_at_max: Option<&'a str>,
+    updated_at_min: Option<&'a str>,
+    updated_at_max: Option<&'a str>,
+}
+
+impl<'a> ShippingAndFulfillmentDeprecated202004GetOrdersParamOrderFulfillmentsCountRequest<'a> {
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.created_at_min = Some(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.created_at_max = Some(created_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.updated_at_min = Some(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.updated_at_max = Some(updated_at_max);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            order_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = created_at_max {
+            query_args.push(("created_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = created_at_min {
+            query_args.push(("created_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_max {
+            query_args.push(("updated_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_min {
+            query_args.push(("updated_at_min".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-04/orders/{}/fulfillments/count.json?{}",
+                crate::progenitor_support::encode_path(order_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_202004_get_orders_param_order_fulfillments_fulfillment`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecated202004GetOrdersParamOrderFulfillmentsFulfillmentRequest<
+    'a,
+> {
+    client: &'a ShippingAndFulfillment,
+    order_id: &'a str,
+    fulfillment_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a>
+    ShippingAndFulfillmentDeprecated202004GetOrdersParamOrderFulfillmentsFulfillmentRequest<'a>
+{
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            order_id,
+            fulfillment_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-04/orders/{}/fulfillments/{}/json?{}",
+                crate::progenitor_support::encode_path(order_id),
+                crate::progenitor_support::encode_path(fulfillment_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_202007_get_orders_param_order_fulfillment`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecated202007GetOrdersParamOrderFulfillmentRequest<'a> {
+    client: &'a ShippingAndFulfillment,
+    order_id: &'a str,
+    created_at_max: Option<&'a str>,
+    created_at_min: Option<&'a str>,
+    fields: Option<&'a str>,
+    limit: Option<&'a str>,
+    since_id: Option<&'a str>,
+    updated_at_max: Option<&'a str>,
+    updated_at_min: Option<&'a str>,
+}
+
+impl<'a> ShippingAndFulfillmentDeprecated202007GetOrdersParamOrderFulfillmentRequest<'a> {
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.created_at_max = Some(created_at_max);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.created_at_min = Some(created_at_min);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.updated_at_max = Some(updated_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.updated_at_min = Some(updated_at_min);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            order_id,
+            created_at_max,
+            created_at_min,
+            fields,
+            limit,
+            since_id,
+            updated_at_max,
+            updated_at_min,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = created_at_max {
+            query_args.push(("created_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = created_at_min {
+            query_args.push(("created_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_max {
+            query_args.push(("updated_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_min {
+            query_args.push(("updated_at_min".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-07/orders/{}/fulfillments.json?{}",
+                crate::progenitor_support::encode_path(order_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_202007_get_orders_param_order_fulfillments_count`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecated202007GetOrdersParamOrderFulfillmentsCountRequest<'a> {
+    client: &'a ShippingAndFulfillment,
+    order_id: &'a str,
+    created_at_min: Option<&'a str>,
+    created_at_max: Option<&'a str>,
+    updated_at_min: Option<&'a str>,
+    updated_at_max: Option<&'a str>,
+}
+
+impl<'a> ShippingAndFulfillmentDeprecated202007GetOrdersParamOrderFulfillmentsCountRequest<'a> {
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.created_at_min = Some(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.created_at_max = Some(created_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.updated_at_min = Some(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.updated_at_max = Some(updated_at_max);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            order_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = created_at_max {
+            query_args.push(("created_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = created_at_min {
+            query_args.push(("created_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_max {
+            query_args.push(("updated_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_min {
+            query_args.push(("updated_at_min".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-07/orders/{}/fulfillments/count.json?{}",
+                crate::progenitor_support::encode_path(order_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_202007_get_orders_param_order_fulfillments_fulfillment`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecated202007GetOrdersParamOrderFulfillmentsFulfillmentRequest<
+    'a,
+> {
+    client: &'a ShippingAndFulfillment,
+    order_id: &'a str,
+    fulfillment_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a>
+    ShippingAndFulfillmentDeprecated202007GetOrdersParamOrderFulfillmentsFulfillmentRequest<'a>
+{
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            order_id,
+            fulfillment_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-07/orders/{}/fulfillments/{}/json?{}",
+                crate::progenitor_support::encode_path(order_id),
+                crate::progenitor_support::encode_path(fulfillment_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::get_orders_param_order_fulfillment`], set with a builder.
+pub struct ShippingAndFulfillmentGetOrdersParamOrderFulfillmentRequest<'a> {
+    client: &'a ShippingAndFulfillment,
+    order_id: &'a str,
+    created_at_max: Option<&'a str>,
+    created_at_min: Option<&'a str>,
+    fields: Option<&'a str>,
+    limit: Option<&'a str>,
+    since_id: Option<&'a str>,
+    updated_at_max: Option<&'a str>,
+    updated_at_min: Option<&'a str>,
+}
+
+impl<'a> ShippingAndFulfillmentGetOrdersParamOrderFulfillmentRequest<'a> {
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.created_at_max = Some(created_at_max);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.created_at_min = Some(created_at_min);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.updated_at_max = Some(updated_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.updated_at_min = Some(updated_at_min);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            order_id,
+            created_at_max,
+            created_at_min,
+            fields,
+            limit,
+            since_id,
+            updated_at_max,
+            updated_at_min,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = created_at_max {
+            query_args.push(("created_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = created_at_min {
+            query_args.push(("created_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_max {
+            query_args.push(("updated_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_min {
+            query_args.push(("updated_at_min".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-10/orders/{}/fulfillments.json?{}",
+                crate::progenitor_support::encode_path(order_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::get_orders_param_order_fulfillments_count`], set with a builder.
+pub struct ShippingAndFulfillmentGetOrdersParamOrderFulfillmentsCountRequest<'a> {
+    client: &'a ShippingAndFulfillment,
+    order_id: &'a str,
+    created_at_min: Option<&'a str>,
+    created_at_max: Option<&'a str>,
+    updated_at_min: Option<&'a str>,
+    updated_at_max: Option<&'a str>,
+}
+
+impl<'a> ShippingAndFulfillmentGetOrdersParamOrderFulfillmentsCountRequest<'a> {
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.created_at_min = Some(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.created_at_max = Some(created_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.updated_at_min = Some(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.updated_at_max = Some(updated_at_max);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            order_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = created_at_max {
+            query_args.push(("created_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = created_at_min {
+            query_args.push(("created_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_max {
+            query_args.push(("updated_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_min {
+            query_args.push(("updated_at_min".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-10/orders/{}/fulfillments/count.json?{}",
+                crate::progenitor_support::encode_path(order_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::get_orders_param_order_fulfillments_fulfillment`], set with a builder.
+pub struct ShippingAndFulfillmentGetOrdersParamOrderFulfillmentsFulfillmentRequest<'a> {
+    client: &'a ShippingAndFulfillment,
+    order_id: &'a str,
+    fulfillment_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> ShippingAndFulfillmentGetOrdersParamOrderFulfillmentsFulfillmentRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            order_id,
+            fulfillment_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-10/orders/{}/fulfillments/{}/json?{}",
+                crate::progenitor_support::encode_path(order_id),
+                crate::progenitor_support::encode_path(fulfillment_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_202101_get_orders_param_order_fulfillment`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecated202101GetOrdersParamOrderFulfillmentRequest<'a> {
+    client: &'a ShippingAndFulfillment,
+    order_id: &'a str,
+    created_at_max: Option<&'a str>,
+    created_at_min: Option<&'a str>,
+    fields: Option<&'a str>,
+    limit: Option<&'a str>,
+    since_id: Option<&'a str>,
+    updated_at_max: Option<&'a str>,
+    updated_at_min: Option<&'a str>,
+}
+
+impl<'a> ShippingAndFulfillmentDeprecated202101GetOrdersParamOrderFulfillmentRequest<'a> {
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.created_at_max = Some(created_at_max);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.created_at_min = Some(created_at_min);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.updated_at_max = Some(updated_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.updated_at_min = Some(updated_at_min);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            order_id,
+            created_at_max,
+            created_at_min,
+            fields,
+            limit,
+            since_id,
+            updated_at_max,
+            updated_at_min,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = created_at_max {
+            query_args.push(("created_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = created_at_min {
+            query_args.push(("created_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_max {
+            query_args.push(("updated_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_min {
+            query_args.push(("updated_at_min".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2021-01/orders/{}/fulfillments.json?{}",
+                crate::progenitor_support::encode_path(order_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_202101_get_orders_param_order_fulfillments_count`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecated202101GetOrdersParamOrderFulfillmentsCountRequest<'a> {
+    client: &'a ShippingAndFulfillment,
+    order_id: &'a str,
+    created_at_min: Option<&'a str>,
+    created_at_max: Option<&'a str>,
+    updated_at_min: Option<&'a str>,
+    updated_at_max: Option<&'a str>,
+}
+
+impl<'a> ShippingAndFulfillmentDeprecated202101GetOrdersParamOrderFulfillmentsCountRequest<'a> {
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.created_at_min = Some(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.created_at_max = Some(created_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.updated_at_min = Some(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.updated_at_max = Some(updated_at_max);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            order_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = created_at_max {
+            query_args.push(("created_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = created_at_min {
+            query_args.push(("created_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_max {
+            query_args.push(("updated_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_min {
+            query_args.push(("updated_at_min".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2021-01/orders/{}/fulfillments/count.json?{}",
+                crate::progenitor_support::encode_path(order_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_202101_get_orders_param_order_fulfillments_fulfillment`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecated202101GetOrdersParamOrderFulfillmentsFulfillmentRequest<
+    'a,
+> {
+    client: &'a ShippingAndFulfillment,
+    order_id: &'a str,
+    fulfillment_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a>
+    ShippingAndFulfillmentDeprecated202101GetOrdersParamOrderFulfillmentsFulfillmentRequest<'a>
+{
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            order_id,
+            fulfillment_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2021-01/orders/{}/fulfillments/{}/json?{}",
+                crate::progenitor_support::encode_path(order_id),
+                crate::progenitor_support::encode_path(fulfillment_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_unstable_get_orders_param_order_fulfillment`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecatedUnstableGetOrdersParamOrderFulfillmentRequest<'a> {
+    client: &'a ShippingAndFulfillment,
+    order_id: &'a str,
+    created_at_max: Option<&'a str>,
+    created_at_min: Option<&'a str>,
+    fields: Option<&'a str>,
+    limit: Option<&'a str>,
+    since_id: Option<&'a str>,
+    updated_at_max: Option<&'a str>,
+    updated_at_min: Option<&'a str>,
+}
+
+impl<'a> ShippingAndFulfillmentDeprecatedUnstableGetOrdersParamOrderFulfillmentRequest<'a> {
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.created_at_max = Some(created_at_max);
+        self
+    }
+
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.created_at_min = Some(created_at_min);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.updated_at_max = Some(updated_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.updated_at_min = Some(updated_at_min);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            order_id,
+            created_at_max,
+            created_at_min,
+            fields,
+            limit,
+            since_id,
+            updated_at_max,
+            updated_at_min,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = created_at_max {
+            query_args.push(("created_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = created_at_min {
+            query_args.push(("created_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_max {
+            query_args.push(("updated_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_min {
+            query_args.push(("updated_at_min".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/unstable/orders/{}/fulfillments.json?{}",
+                crate::progenitor_support::encode_path(order_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_unstable_get_orders_param_order_fulfillments_count`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecatedUnstableGetOrdersParamOrderFulfillmentsCountRequest<'a> {
+    client: &'a ShippingAndFulfillment,
+    order_id: &'a str,
+    created_at_min: Option<&'a str>,
+    created_at_max: Option<&'a str>,
+    updated_at_min: Option<&'a str>,
+    updated_at_max: Option<&'a str>,
+}
+
+impl<'a> ShippingAndFulfillmentDeprecatedUnstableGetOrdersParamOrderFulfillmentsCountRequest<'a> {
+    /// Sets the `created_at_min` query parameter.
+    pub fn created_at_min(mut self, created_at_min: &'a str) -> Self {
+        self.created_at_min = Some(created_at_min);
+        self
+    }
+
+    /// Sets the `created_at_max` query parameter.
+    pub fn created_at_max(mut self, created_at_max: &'a str) -> Self {
+        self.created_at_max = Some(created_at_max);
+        self
+    }
+
+    /// Sets the `updated_at_min` query parameter.
+    pub fn updated_at_min(mut self, updated_at_min: &'a str) -> Self {
+        self.updated_at_min = Some(updated_at_min);
+        self
+    }
+
+    /// Sets the `updated_at_max` query parameter.
+    pub fn updated_at_max(mut self, updated_at_max: &'a str) -> Self {
+        self.updated_at_max = Some(updated_at_max);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            order_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = created_at_max {
+            query_args.push(("created_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = created_at_min {
+            query_args.push(("created_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_max {
+            query_args.push(("updated_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = updated_at_min {
+            query_args.push(("updated_at_min".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/unstable/orders/{}/fulfillments/count.json?{}",
+                crate::progenitor_support::encode_path(order_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_unstable_get_orders_param_order_fulfillments_fulfillment`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecatedUnstableGetOrdersParamOrderFulfillmentsFulfillmentRequest<
+    'a,
+> {
+    client: &'a ShippingAndFulfillment,
+    order_id: &'a str,
+    fulfillment_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a>
+    ShippingAndFulfillmentDeprecatedUnstableGetOrdersParamOrderFulfillmentsFulfillmentRequest<'a>
+{
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            order_id,
+            fulfillment_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/unstable/orders/{}/fulfillments/{}/json?{}",
+                crate::progenitor_support::encode_path(order_id),
+                crate::progenitor_support::encode_path(fulfillment_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_202001_create_fulfillment_orders_param_order_close`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderCloseRequest<'a> {
+    client: &'a ShippingAndFulfillment,
+    fulfillment_order_id: &'a str,
+    message: Option<&'a str>,
+    body: &'a serde_json::Value,
+}
+
+impl<'a> ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderCloseRequest<'a> {
+    /// Sets the `message` query parameter.
+    pub fn message(mut self, message: &'a str) -> Self {
+        self.message = Some(message);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            fulfillment_order_id,
+            message,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = message {
+            query_args.push(("message".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-01/fulfillment_orders/{}/close.json?{}",
+                crate::progenitor_support::encode_path(fulfillment_order_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_202001_create_fulfillment_orders_param_order_move`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderMoveRequest<'a> {
+    client: &'a ShippingAndFulfillment,
+    fulfillment_order_id: &'a str,
+    new_location_id: Option<&'a str>,
+    body: &'a serde_json::Value,
+}
+
+impl<'a> ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderMoveRequest<'a> {
+    /// Sets the `new_location_id` query parameter.
+    pub fn new_location_id(mut self, new_location_id: &'a str) -> Self {
+        self.new_location_id = Some(new_location_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            fulfillment_order_id,
+            new_location_id,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = new_location_id {
+            query_args.push(("new_location_id".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-01/fulfillment_orders/{}/move.json?{}",
+                crate::progenitor_support::encode_path(fulfillment_order_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_202004_create_fulfillment_orders_param_order_close`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderCloseRequest<'a> {
+    client: &'a ShippingAndFulfillment,
+    fulfillment_order_id: &'a str,
+    message: Option<&'a str>,
+    body: &'a serde_json::Value,
+}
+
+impl<'a> ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderCloseRequest<'a> {
+    /// Sets the `message` query parameter.
+    pub fn message(mut self, message: &'a str) -> Self {
+        self.message = Some(message);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            fulfillment_order_id,
+            message,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = message {
+            query_args.push(("message".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-04/fulfillment_orders/{}/close.json?{}",
+                crate::progenitor_support::encode_path(fulfillment_order_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_202004_create_fulfillment_orders_param_order_move`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderMoveRequest<'a> {
+    client: &'a ShippingAndFulfillment,
+    fulfillment_order_id: &'a str,
+    new_location_id: Option<&'a str>,
+    body: &'a serde_json::Value,
+}
+
+impl<'a> ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderMoveRequest<'a> {
+    /// Sets the `new_location_id` query parameter.
+    pub fn new_location_id(mut self, new_location_id: &'a str) -> Self {
+        self.new_location_id = Some(new_location_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            fulfillment_order_id,
+            new_location_id,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = new_location_id {
+            query_args.push(("new_location_id".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-04/fulfillment_orders/{}/move.json?{}",
+                crate::progenitor_support::encode_path(fulfillment_order_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_202007_create_fulfillment_orders_param_order_close`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderCloseRequest<'a> {
+    client: &'a ShippingAndFulfillment,
+    fulfillment_order_id: &'a str,
+    message: Option<&'a str>,
+    body: &'a serde_json::Value,
+}
+
+impl<'a> ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderCloseRequest<'a> {
+    /// Sets the `message` query parameter.
+    pub fn message(mut self, message: &'a str) -> Self {
+        self.message = Some(message);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            fulfillment_order_id,
+            message,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = message {
+            query_args.push(("message".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-07/fulfillment_orders/{}/close.json?{}",
+                crate::progenitor_support::encode_path(fulfillment_order_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_202007_create_fulfillment_orders_param_order_move`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderMoveRequest<'a> {
+    client: &'a ShippingAndFulfillment,
+    fulfillment_order_id: &'a str,
+    new_location_id: Option<&'a str>,
+    body: &'a serde_json::Value,
+}
+
+impl<'a> ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderMoveRequest<'a> {
+    /// Sets the `new_location_id` query parameter.
+    pub fn new_location_id(mut self, new_location_id: &'a str) -> Self {
+        self.new_location_id = Some(new_location_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            fulfillment_order_id,
+            new_location_id,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = new_location_id {
+            query_args.push(("new_location_id".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-07/fulfillment_orders/{}/move.json?{}",
+                crate::progenitor_support::encode_path(fulfillment_order_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::create_fulfillment_orders_param_order_close`], set with a builder.
+pub struct ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderCloseRequest<'a> {
+    client: &'a ShippingAndFulfillment,
+    fulfillment_order_id: &'a str,
+    message: Option<&'a str>,
+    body: &'a serde_json::Value,
+}
+
+impl<'a> ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderCloseRequest<'a> {
+    /// Sets the `message` query parameter.
+    pub fn message(mut self, message: &'a str) -> Self {
+        self.message = Some(message);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            fulfillment_order_id,
+            message,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = message {
+            query_args.push(("message".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-10/fulfillment_orders/{}/close.json?{}",
+                crate::progenitor_support::encode_path(fulfillment_order_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::create_fulfillment_orders_param_order_move`], set with a builder.
+pub struct ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderMoveRequest<'a> {
+    client: &'a ShippingAndFulfillment,
+    fulfillment_order_id: &'a str,
+    new_location_id: Option<&'a str>,
+    body: &'a serde_json::Value,
+}
+
+impl<'a> ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderMoveRequest<'a> {
+    /// Sets the `new_location_id` query parameter.
+    pub fn new_location_id(mut self, new_location_id: &'a str) -> Self {
+        self.new_location_id = Some(new_location_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            fulfillment_order_id,
+            new_location_id,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = new_location_id {
+            query_args.push(("new_location_id".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-10/fulfillment_orders/{}/move.json?{}",
+                crate::progenitor_support::encode_path(fulfillment_order_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_202101_create_fulfillment_orders_param_order_close`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderCloseRequest<'a> {
+    client: &'a ShippingAndFulfillment,
+    fulfillment_order_id: &'a str,
+    message: Option<&'a str>,
+    body: &'a serde_json::Value,
+}
+
+impl<'a> ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderCloseRequest<'a> {
+    /// Sets the `message` query parameter.
+    pub fn message(mut self, message: &'a str) -> Self {
+        self.message = Some(message);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            fulfillment_order_id,
+            message,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = message {
+            query_args.push(("message".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2021-01/fulfillment_orders/{}/close.json?{}",
+                crate::progenitor_support::encode_path(fulfillment_order_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_202101_create_fulfillment_orders_param_order_move`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderMoveRequest<'a> {
+    client: &'a ShippingAndFulfillment,
+    fulfillment_order_id: &'a str,
+    new_location_id: Option<&'a str>,
+    body: &'a serde_json::Value,
+}
+
+impl<'a> ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderMoveRequest<'a> {
+    /// Sets the `new_location_id` query parameter.
+    pub fn new_location_id(mut self, new_location_id: &'a str) -> Self {
+        self.new_location_id = Some(new_location_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            fulfillment_order_id,
+            new_location_id,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = new_location_id {
+            query_args.push(("new_location_id".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2021-01/fulfillment_orders/{}/move.json?{}",
+                crate::progenitor_support::encode_path(fulfillment_order_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_unstable_create_fulfillment_orders_release_hold`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersReleaseHoldRequest<'a> {
+    client: &'a ShippingAndFulfillment,
+    order_id: Option<&'a str>,
+    body: &'a serde_json::Value,
+}
+
+impl<'a> ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersReleaseHoldRequest<'a> {
+    /// Sets the `order_id` query parameter.
+    pub fn order_id(mut self, order_id: &'a str) -> Self {
+        self.order_id = Some(order_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            order_id,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = order_id {
+            query_args.push(("order_id".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/unstable/fulfillment_orders/release_hold.json?{}",
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_unstable_create_fulfillment_orders_param_order_close`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderCloseRequest<'a>
+{
+    client: &'a ShippingAndFulfillment,
+    fulfillment_order_id: &'a str,
+    message: Option<&'a str>,
+    body: &'a serde_json::Value,
+}
+
+impl<'a> ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderCloseRequest<'a> {
+    /// Sets the `message` query parameter.
+    pub fn message(mut self, message: &'a str) -> Self {
+        self.message = Some(message);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            fulfillment_order_id,
+            message,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = message {
+            query_args.push(("message".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/unstable/fulfillment_orders/{}/close.json?{}",
+                crate::progenitor_support::encode_path(fulfillment_order_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_unstable_create_fulfillment_orders_param_order_move`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderMoveRequest<'a>
+{
+    client: &'a ShippingAndFulfillment,
+    fulfillment_order_id: &'a str,
+    new_location_id: Option<&'a str>,
+    body: &'a serde_json::Value,
+}
+
+impl<'a> ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderMoveRequest<'a> {
+    /// Sets the `new_location_id` query parameter.
+    pub fn new_location_id(mut self, new_location_id: &'a str) -> Self {
+        self.new_location_id = Some(new_location_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            fulfillment_order_id,
+            new_location_id,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = new_location_id {
+            query_args.push(("new_location_id".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/unstable/fulfillment_orders/{}/move.json?{}",
+                crate::progenitor_support::encode_path(fulfillment_order_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_202001_create_fulfillment_orders_param_order_request`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderRequestRequest<'a>
+{
+    client: &'a ShippingAndFulfillment,
+    fulfillment_order_id: &'a str,
+    message: Option<&'a str>,
+    fulfillment_order_line_items: Option<&'a str>,
+    body: &'a serde_json::Value,
+}
+
+impl<'a> ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderRequestRequest<'a> {
+    /// Sets the `message` query parameter.
+    pub fn message(mut self, message: &'a str) -> Self {
+        self.message = Some(message);
+        self
+    }
+
+    /// Sets the `fulfillment_order_line_items` query parameter.
+    pub fn fulfillment_order_line_items(mut self, fulfillment_order_line_items: &'a str) -> Self {
+        self.fulfillment_order_line_items = Some(fulfillment_order_line_items);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            fulfillment_order_id,
+            message,
+            fulfillment_order_line_items,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fulfillment_order_line_items {
+            query_args.push(("fulfillment_order_line_items".to_string(), v.to_string()));
+        }
+        if let Some(v) = message {
+            query_args.push(("message".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-01/fulfillment_orders/{}/fulfillment_request.json?{}",
+                crate::progenitor_support::encode_path(fulfillment_order_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_202001_create_fulfillment_orders_param_order_request_accept`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderRequestAcceptRequest<
+    'a,
+> {
+    client: &'a ShippingAndFulfillment,
+    fulfillment_order_id: &'a str,
+    message: Option<&'a str>,
+    body: &'a serde_json::Value,
+}
+
+impl<'a>
+    ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderRequestAcceptRequest<'a>
+{
+    /// Sets the `message` query parameter.
+    pub fn message(mut self, message: &'a str) -> Self {
+        self.message = Some(message);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            fulfillment_order_id,
+            message,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = message {
+            query_args.push(("message".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-01/fulfillment_orders/{}/fulfillment_request/accept.json?{}",
+                crate::progenitor_support::encode_path(fulfillment_order_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_202001_create_fulfillment_orders_param_order_request_reject`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderRequestRejectRequest<
+    'a,
+> {
+    client: &'a ShippingAndFulfillment,
+    fulfillment_order_id: &'a str,
+    message: Option<&'a str>,
+    body: &'a serde_json::Value,
+}
+
+impl<'a>
+    ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderRequestRejectRequest<'a>
+{
+    /// Sets the `message` query parameter.
+    pub fn message(mut self, message: &'a str) -> Self {
+        self.message = Some(message);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            fulfillment_order_id,
+            message,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = message {
+            query_args.push(("message".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-01/fulfillment_orders/{}/fulfillment_request/reject.json?{}",
+                crate::progenitor_support::encode_path(fulfillment_order_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_202004_create_fulfillment_orders_param_order_request`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderRequestRequest<'a>
+{
+    client: &'a ShippingAndFulfillment,
+    fulfillment_order_id: &'a str,
+    message: Option<&'a str>,
+    fulfillment_order_line_items: Option<&'a str>,
+    body: &'a serde_json::Value,
+}
+
+impl<'a> ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderRequestRequest<'a> {
+    /// Sets the `message` query parameter.
+    pub fn message(mut self, message: &'a str) -> Self {
+        self.message = Some(message);
+        self
+    }
+
+    /// Sets the `fulfillment_order_line_items` query parameter.
+    pub fn fulfillment_order_line_items(mut self, fulfillment_order_line_items: &'a str) -> Self {
+        self.fulfillment_order_line_items = Some(fulfillment_order_line_items);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            fulfillment_order_id,
+            message,
+            fulfillment_order_line_items,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fulfillment_order_line_items {
+            query_args.push(("fulfillment_order_line_items".to_string(), v.to_string()));
+        }
+        if let Some(v) = message {
+            query_args.push(("message".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-04/fulfillment_orders/{}/fulfillment_request.json?{}",
+                crate::progenitor_support::encode_path(fulfillment_order_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_202004_create_fulfillment_orders_param_order_request_accept`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderRequestAcceptRequest<
+    'a,
+> {
+    client: &'a ShippingAndFulfillment,
+    fulfillment_order_id: &'a str,
+    message: Option<&'a str>,
+    body: &'a serde_json::Value,
+}
+
+impl<'a>
+    ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderRequestAcceptRequest<'a>
+{
+    /// Sets the `message` query parameter.
+    pub fn message(mut self, message: &'a str) -> Self {
+        self.message = Some(message);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            fulfillment_order_id,
+            message,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = message {
+            query_args.push(("message".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-04/fulfillment_orders/{}/fulfillment_request/accept.json?{}",
+                crate::progenitor_support::encode_path(fulfillment_order_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_202004_create_fulfillment_orders_param_order_request_reject`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderRequestRejectRequest<
+    'a,
+> {
+    client: &'a ShippingAndFulfillment,
+    fulfillment_order_id: &'a str,
+    message: Option<&'a str>,
+    body: &'a serde_json::Value,
+}
+
+impl<'a>
+    ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderRequestRejectRequest<'a>
+{
+    /// Sets the `message` query parameter.
+    pub fn message(mut self, message: &'a str) -> Self {
+        self.message = Some(message);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            fulfillment_order_id,
+            message,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = message {
+            query_args.push(("message".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-04/fulfillment_orders/{}/fulfillment_request/reject.json?{}",
+                crate::progenitor_support::encode_path(fulfillment_order_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_202007_create_fulfillment_orders_param_order_request`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderRequestRequest<'a>
+{
+    client: &'a ShippingAndFulfillment,
+    fulfillment_order_id: &'a str,
+    message: Option<&'a str>,
+    fulfillment_order_line_items: Option<&'a str>,
+    body: &'a serde_json::Value,
+}
+
+impl<'a> ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderRequestRequest<'a> {
+    /// Sets the `message` query parameter.
+    pub fn message(mut self, message: &'a str) -> Self {
+        self.message = Some(message);
+        self
+    }
+
+    /// Sets the `fulfillment_order_line_items` query parameter.
+    pub fn fulfillment_order_line_items(mut self, fulfillment_order_line_items: &'a str) -> Self {
+        self.fulfillment_order_line_items = Some(fulfillment_order_line_items);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            fulfillment_order_id,
+            message,
+            fulfillment_order_line_items,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fulfillment_order_line_items {
+            query_args.push(("fulfillment_order_line_items".to_string(), v.to_string()));
+        }
+        if let Some(v) = message {
+            query_args.push(("message".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-07/fulfillment_orders/{}/fulfillment_request.json?{}",
+                crate::progenitor_support::encode_path(fulfillment_order_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_202007_create_fulfillment_orders_param_order_request_accept`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderRequestAcceptRequest<
+    'a,
+> {
+    client: &'a ShippingAndFulfillment,
+    fulfillment_order_id: &'a str,
+    message: Option<&'a str>,
+    body: &'a serde_json::Value,
+}
+
+impl<'a>
+    ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderRequestAcceptRequest<'a>
+{
+    /// Sets the `message` query parameter.
+    pub fn message(mut self, message: &'a str) -> Self {
+        self.message = Some(message);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            fulfillment_order_id,
+            message,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = message {
+            query_args.push(("message".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-07/fulfillment_orders/{}/fulfillment_request/accept.json?{}",
+                crate::progenitor_support::encode_path(fulfillment_order_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_202007_create_fulfillment_orders_param_order_request_reject`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderRequestRejectRequest<
+    'a,
+> {
+    client: &'a ShippingAndFulfillment,
+    fulfillment_order_id: &'a str,
+    message: Option<&'a str>,
+    body: &'a serde_json::Value,
+}
+
+impl<'a>
+    ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderRequestRejectRequest<'a>
+{
+    /// Sets the `message` query parameter.
+    pub fn message(mut self, message: &'a str) -> Self {
+        self.message = Some(message);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            fulfillment_order_id,
+            message,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = message {
+            query_args.push(("message".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-07/fulfillment_orders/{}/fulfillment_request/reject.json?{}",
+                crate::progenitor_support::encode_path(fulfillment_order_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::create_fulfillment_orders_param_order_request`], set with a builder.
+pub struct ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderRequestRequest<'a> {
+    client: &'a ShippingAndFulfillment,
+    fulfillment_order_id: &'a str,
+    message: Option<&'a str>,
+    fulfillment_order_line_items: Option<&'a str>,
+    body: &'a serde_json::Value,
+}
+
+impl<'a> ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderRequestRequest<'a> {
+    /// Sets the `message` query parameter.
+    pub fn message(mut self, message: &'a str) -> Self {
+        self.message = Some(message);
+        self
+    }
+
+    /// Sets the `fulfillment_order_line_items` query parameter.
+    pub fn fulfillment_order_line_items(mut self, fulfillment_order_line_items: &'a str) -> Self {
+        self.fulfillment_order_line_items = Some(fulfillment_order_line_items);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            fulfillment_order_id,
+            message,
+            fulfillment_order_line_items,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fulfillment_order_line_items {
+            query_args.push(("fulfillment_order_line_items".to_string(), v.to_string()));
+        }
+        if let Some(v) = message {
+            query_args.push(("message".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-10/fulfillment_orders/{}/fulfillment_request.json?{}",
+                crate::progenitor_support::encode_path(fulfillment_order_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::create_fulfillment_orders_param_order_request_accept`], set with a builder.
+pub struct ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderRequestAcceptRequest<'a> {
+    client: &'a ShippingAndFulfillment,
+    fulfillment_order_id: &'a str,
+    message: Option<&'a str>,
+    body: &'a serde_json::Value,
+}
+
+impl<'a> ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderRequestAcceptRequest<'a> {
+    /// Sets the `message` query parameter.
+    pub fn message(mut self, message: &'a str) -> Self {
+        self.message = Some(message);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            fulfillment_order_id,
+            message,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = message {
+            query_args.push(("message".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-10/fulfillment_orders/{}/fulfillment_request/accept.json?{}",
+                crate::progenitor_support::encode_path(fulfillment_order_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::create_fulfillment_orders_param_order_request_reject`], set with a builder.
+pub struct ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderRequestRejectRequest<'a> {
+    client: &'a ShippingAndFulfillment,
+    fulfillment_order_id: &'a str,
+    message: Option<&'a str>,
+    body: &'a serde_json::Value,
+}
+
+impl<'a> ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderRequestRejectRequest<'a> {
+    /// Sets the `message` query parameter.
+    pub fn message(mut self, message: &'a str) -> Self {
+        self.message = Some(message);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            fulfillment_order_id,
+            message,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = message {
+            query_args.push(("message".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-10/fulfillment_orders/{}/fulfillment_request/reject.json?{}",
+                crate::progenitor_support::encode_path(fulfillment_order_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_202101_create_fulfillment_orders_param_order_request`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderRequestRequest<'a>
+{
+    client: &'a ShippingAndFulfillment,
+    fulfillment_order_id: &'a str,
+    message: Option<&'a str>,
+    fulfillment_order_line_items: Option<&'a str>,
+    body: &'a serde_json::Value,
+}
+
+impl<'a> ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderRequestRequest<'a> {
+    /// Sets the `message` query parameter.
+    pub fn message(mut self, message: &'a str) -> Self {
+        self.message = Some(message);
+        self
+    }
+
+    /// Sets the `fulfillment_order_line_items` query parameter.
+    pub fn fulfillment_order_line_items(mut self, fulfillment_order_line_items: &'a str) -> Self {
+        self.fulfillment_order_line_items = Some(fulfillment_order_line_items);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            fulfillment_order_id,
+            message,
+            fulfillment_order_line_items,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fulfillment_order_line_items {
+            query_args.push(("fulfillment_order_line_items".to_string(), v.to_string()));
+        }
+        if let Some(v) = message {
+            query_args.push(("message".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2021-01/fulfillment_orders/{}/fulfillment_request.json?{}",
+                crate::progenitor_support::encode_path(fulfillment_order_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_202101_create_fulfillment_orders_param_order_request_accept`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderRequestAcceptRequest<
+    'a,
+> {
+    client: &'a ShippingAndFulfillment,
+    fulfillment_order_id: &'a str,
+    message: Option<&'a str>,
+    body: &'a serde_json::Value,
+}
+
+impl<'a>
+    ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderRequestAcceptRequest<'a>
+{
+    /// Sets the `message` query parameter.
+    pub fn message(mut self, message: &'a str) -> Self {
+        self.message = Some(message);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            fulfillment_order_id,
+            message,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = message {
+            query_args.push(("message".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2021-01/fulfillment_orders/{}/fulfillment_request/accept.json?{}",
+                crate::progenitor_support::encode_path(fulfillment_order_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_202101_create_fulfillment_orders_param_order_request_reject`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderRequestRejectRequest<
+    'a,
+> {
+    client: &'a ShippingAndFulfillment,
+    fulfillment_order_id: &'a str,
+    message: Option<&'a str>,
+    body: &'a serde_json::Value,
+}
+
+impl<'a>
+    ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderRequestRejectRequest<'a>
+{
+    /// Sets the `message` query parameter.
+    pub fn message(mut self, message: &'a str) -> Self {
+        self.message = Some(message);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            fulfillment_order_id,
+            message,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = message {
+            query_args.push(("message".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2021-01/fulfillment_orders/{}/fulfillment_request/reject.json?{}",
+                crate::progenitor_support::encode_path(fulfillment_order_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_unstable_create_fulfillment_orders_param_order_request`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderRequestRequest<
+    'a,
+> {
+    client: &'a ShippingAndFulfillment,
+    fulfillment_order_id: &'a str,
+    message: Option<&'a str>,
+    fulfillment_order_line_items: Option<&'a str>,
+    body: &'a serde_json::Value,
+}
+
+impl<'a>
+    ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderRequestRequest<'a>
+{
+    /// Sets the `message` query parameter.
+    pub fn message(mut self, message: &'a str) -> Self {
+        self.message = Some(message);
+        self
+    }
+
+    /// Sets the `fulfillment_order_line_items` query parameter.
+    pub fn fulfillment_order_line_items(mut self, fulfillment_order_line_items: &'a str) -> Self {
+        self.fulfillment_order_line_items = Some(fulfillment_order_line_items);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            fulfillment_order_id,
+            message,
+            fulfillment_order_line_items,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fulfillment_order_line_items {
+            query_args.push(("fulfillment_order_line_items".to_string(), v.to_string()));
+        }
+        if let Some(v) = message {
+            query_args.push(("message".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/unstable/fulfillment_orders/{}/fulfillment_request.json?{}",
+                crate::progenitor_support::encode_path(fulfillment_order_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_unstable_create_fulfillment_orders_param_order_request_accept`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderRequestAcceptRequest<
+    'a,
+> {
+    client: &'a ShippingAndFulfillment,
+    fulfillment_order_id: &'a str,
+    message: Option<&'a str>,
+    body: &'a serde_json::Value,
+}
+
+impl<'a>
+    ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderRequestAcceptRequest<
+        'a,
+    >
+{
+    /// Sets the `message` query parameter.
+    pub fn message(mut self, message: &'a str) -> Self {
+        self.message = Some(message);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            fulfillment_order_id,
+            message,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = message {
+            query_args.push(("message".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/unstable/fulfillment_orders/{}/fulfillment_request/accept.json?{}",
+                crate::progenitor_support::encode_path(fulfillment_order_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_unstable_create_fulfillment_orders_param_order_request_reject`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderRequestRejectRequest<
+    'a,
+> {
+    client: &'a ShippingAndFulfillment,
+    fulfillment_order_id: &'a str,
+    message: Option<&'a str>,
+    body: &'a serde_json::Value,
+}
+
+impl<'a>
+    ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderRequestRejectRequest<
+        'a,
+    >
+{
+    /// Sets the `message` query parameter.
+    pub fn message(mut self, message: &'a str) -> Self {
+        self.message = Some(message);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            fulfillment_order_id,
+            message,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = message {
+            query_args.push(("message".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/unstable/fulfillment_orders/{}/fulfillment_request/reject.json?{}",
+                crate::progenitor_support::encode_path(fulfillment_order_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_unknown_version_get_fetch_tracking_number`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecatedUnknownVersionGetFetchTrackingNumberRequest<'a> {
+    client: &'a ShippingAndFulfillment,
+    order_names: Option<&'a str>,
+    shop: Option<&'a str>,
+}
+
+impl<'a> ShippingAndFulfillmentDeprecatedUnknownVersionGetFetchTrackingNumberRequest<'a> {
+    /// Sets the `order_names` query parameter.
+    pub fn order_names(mut self, order_names: &'a str) -> Self {
+        self.order_names = Some(order_names);
+        self
+    }
+
+    /// Sets the `shop` query parameter.
+    pub fn shop(mut self, shop: &'a str) -> Self {
+        self.shop = Some(shop);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            order_names,
+            shop,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = order_names {
+            query_args.push(("order_names".to_string(), v.to_string()));
+        }
+        if let Some(v) = shop {
+            query_args.push(("shop".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/fetch_tracking_numbers?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_unknown_version_get_fetch_stock`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecatedUnknownVersionGetFetchStockRequest<'a> {
+    client: &'a ShippingAndFulfillment,
+    max_retries: Option<&'a str>,
+    shop: Option<&'a str>,
+    sku: Option<&'a str>,
+    timestamp: Option<&'a str>,
+}
+
+impl<'a> ShippingAndFulfillmentDeprecatedUnknownVersionGetFetchStockRequest<'a> {
+    /// Sets the `max_retries` query parameter.
+    pub fn max_retries(mut self, max_retries: &'a str) -> Self {
+        self.max_retries = Some(max_retries);
+        self
+    }
+
+    /// Sets the `shop` query parameter.
+    pub fn shop(mut self, shop: &'a str) -> Self {
+        self.shop = Some(shop);
+        self
+    }
+
+    /// Sets the `sku` query parameter.
+    pub fn sku(mut self, sku: &'a str) -> Self {
+        self.sku = Some(sku);
+        self
+    }
+
+    /// Sets the `timestamp` query parameter.
+    pub fn timestamp(mut self, timestamp: &'a str) -> Self {
+        self.timestamp = Some(timestamp);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            max_retries,
+            shop,
+            sku,
+            timestamp,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = max_retries {
+            query_args.push(("max_retries".to_string(), v.to_string()));
+        }
+        if let Some(v) = shop {
+            query_args.push(("shop".to_string(), v.to_string()));
+        }
+        if let Some(v) = sku {
+            query_args.push(("sku".to_string(), v.to_string()));
+        }
+        if let Some(v) = timestamp {
+            query_args.push(("timestamp".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(&format!("/fetch_stock?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_202001_get_fulfillment_service`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecated202001GetFulfillmentServiceRequest<'a> {
+    client: &'a ShippingAndFulfillment,
+    scope: Option<&'a str>,
+}
+
+impl<'a> ShippingAndFulfillmentDeprecated202001GetFulfillmentServiceRequest<'a> {
+    /// Sets the `scope` query parameter.
+    pub fn scope(mut self, scope: &'a str) -> Self {
+        self.scope = Some(scope);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self { client, scope } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = scope {
+            query_args.push(("scope".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-01/fulfillment_services.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_202004_get_fulfillment_service`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecated202004GetFulfillmentServiceRequest<'a> {
+    client: &'a ShippingAndFulfillment,
+    scope: Option<&'a str>,
+}
+
+impl<'a> ShippingAndFulfillmentDeprecated202004GetFulfillmentServiceRequest<'a> {
+    /// Sets the `scope` query parameter.
+    pub fn scope(mut self, scope: &'a str) -> Self {
+        self.scope = Some(scope);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self { client, scope } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = scope {
+            query_args.push(("scope".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-04/fulfillment_services.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_202007_get_fulfillment_service`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecated202007GetFulfillmentServiceRequest<'a> {
+    client: &'a ShippingAndFulfillment,
+    scope: Option<&'a str>,
+}
+
+impl<'a> ShippingAndFulfillmentDeprecated202007GetFulfillmentServiceRequest<'a> {
+    /// Sets the `scope` query parameter.
+    pub fn scope(mut self, scope: &'a str) -> Self {
+        self.scope = Some(scope);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self { client, scope } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = scope {
+            query_args.push(("scope".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-07/fulfillment_services.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::get_fulfillment_service`], set with a builder.
+pub struct ShippingAndFulfillmentGetFulfillmentServiceRequest<'a> {
+    client: &'a ShippingAndFulfillment,
+    scope: Option<&'a str>,
+}
+
+impl<'a> ShippingAndFulfillmentGetFulfillmentServiceRequest<'a> {
+    /// Sets the `scope` query parameter.
+    pub fn scope(mut self, scope: &'a str) -> Self {
+        self.scope = Some(scope);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self { client, scope } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = scope {
+            query_args.push(("scope".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-10/fulfillment_services.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_202101_get_fulfillment_service`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecated202101GetFulfillmentServiceRequest<'a> {
+    client: &'a ShippingAndFulfillment,
+    scope: Option<&'a str>,
+}
+
+impl<'a> ShippingAndFulfillmentDeprecated202101GetFulfillmentServiceRequest<'a> {
+    /// Sets the `scope` query parameter.
+    pub fn scope(mut self, scope: &'a str) -> Self {
+        self.scope = Some(scope);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self { client, scope } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = scope {
+            query_args.push(("scope".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2021-01/fulfillment_services.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`ShippingAndFulfillment::deprecated_unstable_get_fulfillment_service`], set with a builder.
+pub struct ShippingAndFulfillmentDeprecatedUnstableGetFulfillmentServiceRequest<'a> {
+    client: &'a ShippingAndFulfillment,
+    scope: Option<&'a str>,
+}
+
+impl<'a> ShippingAndFulfillmentDeprecatedUnstableGetFulfillmentServiceRequest<'a> {
+    /// Sets the `scope` query parameter.
+    pub fn scope(mut self, scope: &'a str) -> Self {
+        self.scope = Some(scope);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self { client, scope } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = scope {
+            query_args.push(("scope".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/unstable/fulfillment_services.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct ShippingAndFulfillment {
     pub client: Client,
 }
@@ -61,6 +4541,24 @@ impl ShippingAndFulfillment {
             )
             .await
     }
+    /**
+     * Retrieves a list of fulfillment orders on a shop for a specific app.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-01/assigned_fulfillment_orders.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_get_assigned_fulfillment_order`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/assignedfulfillmentorder#index-2020-01
+     */
+    pub fn deprecated_202001_get_assigned_fulfillment_order_request(
+        &self,
+    ) -> ShippingAndFulfillmentDeprecated202001GetAssignedFulfillmentOrderRequest<'_> {
+        ShippingAndFulfillmentDeprecated202001GetAssignedFulfillmentOrderRequest {
+            client: self,
+            assignment_status: None,
+            location_ids: None,
+        }
+    }
     /**
      * Retrieves a list of fulfillment orders on a shop for a specific app.
      *
@@ -111,6 +4609,24 @@ impl ShippingAndFulfillment {
             )
             .await
     }
+    /**
+     * Retrieves a list of fulfillment orders on a shop for a specific app.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-04/assigned_fulfillment_orders.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_get_assigned_fulfillment_order`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/assignedfulfillmentorder#index-2020-04
+     */
+    pub fn deprecated_202004_get_assigned_fulfillment_order_request(
+        &self,
+    ) -> ShippingAndFulfillmentDeprecated202004GetAssignedFulfillmentOrderRequest<'_> {
+        ShippingAndFulfillmentDeprecated202004GetAssignedFulfillmentOrderRequest {
+            client: self,
+            assignment_status: None,
+            location_ids: None,
+        }
+    }
     /**
      * Retrieves a list of fulfillment orders on a shop for a specific app.
      *
@@ -161,6 +4677,24 @@ impl ShippingAndFulfillment {
             )
             .await
     }
+    /**
+     * Retrieves a list of fulfillment orders on a shop for a specific app.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-07/assigned_fulfillment_orders.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_get_assigned_fulfillment_order`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/assignedfulfillmentorder#index-2020-07
+     */
+    pub fn deprecated_202007_get_assigned_fulfillment_order_request(
+        &self,
+    ) -> ShippingAndFulfillmentDeprecated202007GetAssignedFulfillmentOrderRequest<'_> {
+        ShippingAndFulfillmentDeprecated202007GetAssignedFulfillmentOrderRequest {
+            client: self,
+            assignment_status: None,
+            location_ids: None,
+        }
+    }
     /**
      * Retrieves a list of fulfillment orders on a shop for a specific app.
      *
@@ -211,6 +4745,24 @@ impl ShippingAndFulfillment {
             )
             .await
     }
+    /**
+     * Retrieves a list of fulfillment orders on a shop for a specific app.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-10/assigned_fulfillment_orders.json` endpoint.
+     *
+     * As opposed to `get_assigned_fulfillment_order`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/assignedfulfillmentorder#index-2020-10
+     */
+    pub fn get_assigned_fulfillment_order_request(
+        &self,
+    ) -> ShippingAndFulfillmentGetAssignedFulfillmentOrderRequest<'_> {
+        ShippingAndFulfillmentGetAssignedFulfillmentOrderRequest {
+            client: self,
+            assignment_status: None,
+            location_ids: None,
+        }
+    }
     /**
      * Retrieves a list of fulfillment orders on a shop for a specific app.
      *
@@ -261,6 +4813,24 @@ impl ShippingAndFulfillment {
             )
             .await
     }
+    /**
+     * Retrieves a list of fulfillment orders on a shop for a specific app.
+     *
+     * This function performs a `GET` to the `/admin/api/2021-01/assigned_fulfillment_orders.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_get_assigned_fulfillment_order`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/assignedfulfillmentorder#index-2021-01
+     */
+    pub fn deprecated_202101_get_assigned_fulfillment_order_request(
+        &self,
+    ) -> ShippingAndFulfillmentDeprecated202101GetAssignedFulfillmentOrderRequest<'_> {
+        ShippingAndFulfillmentDeprecated202101GetAssignedFulfillmentOrderRequest {
+            client: self,
+            assignment_status: None,
+            location_ids: None,
+        }
+    }
     /**
      * Retrieves a list of fulfillment orders on a shop for a specific app.
      *
@@ -311,6 +4881,24 @@ impl ShippingAndFulfillment {
             )
             .await
     }
+    /**
+     * Retrieves a list of fulfillment orders on a shop for a specific app.
+     *
+     * This function performs a `GET` to the `/admin/api/unstable/assigned_fulfillment_orders.json` endpoint.
+     *
+     * As opposed to `deprecated_unstable_get_assigned_fulfillment_order`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/assignedfulfillmentorder#index-unstable
+     */
+    pub fn deprecated_unstable_get_assigned_fulfillment_order_request(
+        &self,
+    ) -> ShippingAndFulfillmentDeprecatedUnstableGetAssignedFulfillmentOrderRequest<'_> {
+        ShippingAndFulfillmentDeprecatedUnstableGetAssignedFulfillmentOrderRequest {
+            client: self,
+            assignment_status: None,
+            location_ids: None,
+        }
+    }
     /**
      * Sends a cancellation request to the fulfillment service of a fulfillment order.
      *
@@ -352,6 +4940,17 @@ impl ShippingAndFulfillment {
             )
             .await
     }
+    /**
+     * Sends a cancellation request to the fulfillment service of a fulfillment order.
+     *
+     * This function performs a `POST` to the `/admin/api/2020-01/fulfillment_orders/{fulfillment_order_id}/cancellation_request.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_create_fulfillment_orders_param_order_cancellation_request`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/cancellationrequest#create-2020-01
+     */    pub fn deprecated_202001_create_fulfillment_orders_param_order_cancellation_request_request<'a>(&'a self, fulfillment_order_id: &'a str, body: &'a serde_json::Value,) -> ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderCancellationRequestRequest<'a>{
+        ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderCancellationRequestRequest { client: self,fulfillment_order_id,message: None,body,}
+    }
     /**
      * Accepts a cancellation request sent to a fulfillment service for a fulfillment order.
      *
@@ -393,6 +4992,17 @@ impl ShippingAndFulfillment {
             )
             .await
     }
+    /**
+     * Accepts a cancellation request sent to a fulfillment service for a fulfillment order.
+     *
+     * This function performs a `POST` to the `/admin/api/2020-01/fulfillment_orders/{fulfillment_order_id}/cancellation_request/accept.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_create_fulfillment_orders_param_order_cancellation_request_accept`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/cancellationrequest#accept-2020-01
+     */    pub fn deprecated_202001_create_fulfillment_orders_param_order_cancellation_request_accept_request<'a>(&'a self, fulfillment_order_id: &'a str, body: &'a serde_json::Value,) -> ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderCancellationRequestAcceptRequest<'a>{
+        ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderCancellationRequestAcceptRequest { client: self,fulfillment_order_id,message: None,body,}
+    }
     /**
      * Rejects a cancellation request sent to a fulfillment service for a fulfillment order.
      *
@@ -434,6 +5044,17 @@ impl ShippingAndFulfillment {
             )
             .await
     }
+    /**
+     * Rejects a cancellation request sent to a fulfillment service for a fulfillment order.
+     *
+     * This function performs a `POST` to the `/admin/api/2020-01/fulfillment_orders/{fulfillment_order_id}/cancellation_request/reject.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_create_fulfillment_orders_param_order_cancellation_request_reject`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/cancellationrequest#reject-2020-01
+     */    pub fn deprecated_202001_create_fulfillment_orders_param_order_cancellation_request_reject_request<'a>(&'a self, fulfillment_order_id: &'a str, body: &'a serde_json::Value,) -> ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderCancellationRequestRejectRequest<'a>{
+        ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderCancellationRequestRejectRequest { client: self,fulfillment_order_id,message: None,body,}
+    }
     /**
      * Sends a cancellation request to the fulfillment service of a fulfillment order.
      *
@@ -475,6 +5096,17 @@ impl ShippingAndFulfillment {
             )
             .await
     }
+    /**
+     * Sends a cancellation request to the fulfillment service of a fulfillment order.
+     *
+     * This function performs a `POST` to the `/admin/api/2020-04/fulfillment_orders/{fulfillment_order_id}/cancellation_request.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_create_fulfillment_orders_param_order_cancellation_request`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/cancellationrequest#create-2020-04
+     */    pub fn deprecated_202004_create_fulfillment_orders_param_order_cancellation_request_request<'a>(&'a self, fulfillment_order_id: &'a str, body: &'a serde_json::Value,) -> ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderCancellationRequestRequest<'a>{
+        ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderCancellationRequestRequest { client: self,fulfillment_order_id,message: None,body,}
+    }
     /**
      * Accepts a cancellation request sent to a fulfillment service for a fulfillment order.
      *
@@ -516,6 +5148,17 @@ impl ShippingAndFulfillment {
             )
             .await
     }
+    /**
+     * Accepts a cancellation request sent to a fulfillment service for a fulfillment order.
+     *
+     * This function performs a `POST` to the `/admin/api/2020-04/fulfillment_orders/{fulfillment_order_id}/cancellation_request/accept.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_create_fulfillment_orders_param_order_cancellation_request_accept`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/cancellationrequest#accept-2020-04
+     */    pub fn deprecated_202004_create_fulfillment_orders_param_order_cancellation_request_accept_request<'a>(&'a self, fulfillment_order_id: &'a str, body: &'a serde_json::Value,) -> ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderCancellationRequestAcceptRequest<'a>{
+        ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderCancellationRequestAcceptRequest { client: self,fulfillment_order_id,message: None,body,}
+    }
     /**
      * Rejects a cancellation request sent to a fulfillment service for a fulfillment order.
      *
@@ -557,6 +5200,17 @@ impl ShippingAndFulfillment {
             )
             .await
     }
+    /**
+     * Rejects a cancellation request sent to a fulfillment service for a fulfillment order.
+     *
+     * This function performs a `POST` to the `/admin/api/2020-04/fulfillment_orders/{fulfillment_order_id}/cancellation_request/reject.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_create_fulfillment_orders_param_order_cancellation_request_reject`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/cancellationrequest#reject-2020-04
+     */    pub fn deprecated_202004_create_fulfillment_orders_param_order_cancellation_request_reject_request<'a>(&'a self, fulfillment_order_id: &'a str, body: &'a serde_json::Value,) -> ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderCancellationRequestRejectRequest<'a>{
+        ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderCancellationRequestRejectRequest { client: self,fulfillment_order_id,message: None,body,}
+    }
     /**
      * Sends a cancellation request to the fulfillment service of a fulfillment order.
      *
@@ -598,6 +5252,17 @@ impl ShippingAndFulfillment {
             )
             .await
     }
+    /**
+     * Sends a cancellation request to the fulfillment service of a fulfillment order.
+     *
+     * This function performs a `POST` to the `/admin/api/2020-07/fulfillment_orders/{fulfillment_order_id}/cancellation_request.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_create_fulfillment_orders_param_order_cancellation_request`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/cancellationrequest#create-2020-07
+     */    pub fn deprecated_202007_create_fulfillment_orders_param_order_cancellation_request_request<'a>(&'a self, fulfillment_order_id: &'a str, body: &'a serde_json::Value,) -> ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderCancellationRequestRequest<'a>{
+        ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderCancellationRequestRequest { client: self,fulfillment_order_id,message: None,body,}
+    }
     /**
      * Accepts a cancellation request sent to a fulfillment service for a fulfillment order.
      *
@@ -639,6 +5304,17 @@ impl ShippingAndFulfillment {
             )
             .await
     }
+    /**
+     * Accepts a cancellation request sent to a fulfillment service for a fulfillment order.
+     *
+     * This function performs a `POST` to the `/admin/api/2020-07/fulfillment_orders/{fulfillment_order_id}/cancellation_request/accept.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_create_fulfillment_orders_param_order_cancellation_request_accept`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/cancellationrequest#accept-2020-07
+     */    pub fn deprecated_202007_create_fulfillment_orders_param_order_cancellation_request_accept_request<'a>(&'a self, fulfillment_order_id: &'a str, body: &'a serde_json::Value,) -> ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderCancellationRequestAcceptRequest<'a>{
+        ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderCancellationRequestAcceptRequest { client: self,fulfillment_order_id,message: None,body,}
+    }
     /**
      * Rejects a cancellation request sent to a fulfillment service for a fulfillment order.
      *
@@ -680,6 +5356,17 @@ impl ShippingAndFulfillment {
             )
             .await
     }
+    /**
+     * Rejects a cancellation request sent to a fulfillment service for a fulfillment order.
+     *
+     * This function performs a `POST` to the `/admin/api/2020-07/fulfillment_orders/{fulfillment_order_id}/cancellation_request/reject.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_create_fulfillment_orders_param_order_cancellation_request_reject`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/cancellationrequest#reject-2020-07
+     */    pub fn deprecated_202007_create_fulfillment_orders_param_order_cancellation_request_reject_request<'a>(&'a self, fulfillment_order_id: &'a str, body: &'a serde_json::Value,) -> ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderCancellationRequestRejectRequest<'a>{
+        ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderCancellationRequestRejectRequest { client: self,fulfillment_order_id,message: None,body,}
+    }
     /**
      * Sends a cancellation request to the fulfillment service of a fulfillment order.
      *
@@ -721,6 +5408,27 @@ impl ShippingAndFulfillment {
             )
             .await
     }
+    /**
+     * Sends a cancellation request to the fulfillment service of a fulfillment order.
+     *
+     * This function performs a `POST` to the `/admin/api/2020-10/fulfillment_orders/{fulfillment_order_id}/cancellation_request.json` endpoint.
+     *
+     * As opposed to `create_fulfillment_orders_param_order_cancellation_request`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/cancellationrequest#create-2020-10
+     */
+    pub fn create_fulfillment_orders_param_order_cancellation_request_request<'a>(
+        &'a self,
+        fulfillment_order_id: &'a str,
+        body: &'a serde_json::Value,
+    ) -> ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderCancellationRequestRequest<'a> {
+        ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderCancellationRequestRequest {
+            client: self,
+            fulfillment_order_id,
+            message: None,
+            body,
+        }
+    }
     /**
      * Accepts a cancellation request sent to a fulfillment service for a fulfillment order.
      *
@@ -762,6 +5470,28 @@ impl ShippingAndFulfillment {
             )
             .await
     }
+    /**
+     * Accepts a cancellation request sent to a fulfillment service for a fulfillment order.
+     *
+     * This function performs a `POST` to the `/admin/api/2020-10/fulfillment_orders/{fulfillment_order_id}/cancellation_request/accept.json` endpoint.
+     *
+     * As opposed to `create_fulfillment_orders_param_order_cancellation_request_accept`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/cancellationrequest#accept-2020-10
+     */
+    pub fn create_fulfillment_orders_param_order_cancellation_request_accept_request<'a>(
+        &'a self,
+        fulfillment_order_id: &'a str,
+        body: &'a serde_json::Value,
+    ) -> ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderCancellationRequestAcceptRequest<'a>
+    {
+        ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderCancellationRequestAcceptRequest {
+            client: self,
+            fulfillment_order_id,
+            message: None,
+            body,
+        }
+    }
     /**
      * Rejects a cancellation request sent to a fulfillment service for a fulfillment order.
      *
@@ -803,6 +5533,28 @@ impl ShippingAndFulfillment {
             )
             .await
     }
+    /**
+     * Rejects a cancellation request sent to a fulfillment service for a fulfillment order.
+     *
+     * This function performs a `POST` to the `/admin/api/2020-10/fulfillment_orders/{fulfillment_order_id}/cancellation_request/reject.json` endpoint.
+     *
+     * As opposed to `create_fulfillment_orders_param_order_cancellation_request_reject`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/cancellationrequest#reject-2020-10
+     */
+    pub fn create_fulfillment_orders_param_order_cancellation_request_reject_request<'a>(
+        &'a self,
+        fulfillment_order_id: &'a str,
+        body: &'a serde_json::Value,
+    ) -> ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderCancellationRequestRejectRequest<'a>
+    {
+        ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderCancellationRequestRejectRequest {
+            client: self,
+            fulfillment_order_id,
+            message: None,
+            body,
+        }
+    }
     /**
      * Sends a cancellation request to the fulfillment service of a fulfillment order.
      *
@@ -844,6 +5596,17 @@ impl ShippingAndFulfillment {
             )
             .await
     }
+    /**
+     * Sends a cancellation request to the fulfillment service of a fulfillment order.
+     *
+     * This function performs a `POST` to the `/admin/api/2021-01/fulfillment_orders/{fulfillment_order_id}/cancellation_request.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_create_fulfillment_orders_param_order_cancellation_request`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/cancellationrequest#create-2021-01
+     */    pub fn deprecated_202101_create_fulfillment_orders_param_order_cancellation_request_request<'a>(&'a self, fulfillment_order_id: &'a str, body: &'a serde_json::Value,) -> ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderCancellationRequestRequest<'a>{
+        ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderCancellationRequestRequest { client: self,fulfillment_order_id,message: None,body,}
+    }
     /**
      * Accepts a cancellation request sent to a fulfillment service for a fulfillment order.
      *
@@ -885,6 +5648,17 @@ impl ShippingAndFulfillment {
             )
             .await
     }
+    /**
+     * Accepts a cancellation request sent to a fulfillment service for a fulfillment order.
+     *
+     * This function performs a `POST` to the `/admin/api/2021-01/fulfillment_orders/{fulfillment_order_id}/cancellation_request/accept.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_create_fulfillment_orders_param_order_cancellation_request_accept`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/cancellationrequest#accept-2021-01
+     */    pub fn deprecated_202101_create_fulfillment_orders_param_order_cancellation_request_accept_request<'a>(&'a self, fulfillment_order_id: &'a str, body: &'a serde_json::Value,) -> ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderCancellationRequestAcceptRequest<'a>{
+        ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderCancellationRequestAcceptRequest { client: self,fulfillment_order_id,message: None,body,}
+    }
     /**
      * Rejects a cancellation request sent to a fulfillment service for a fulfillment order.
      *
@@ -926,6 +5700,17 @@ impl ShippingAndFulfillment {
             )
             .await
     }
+    /**
+     * Rejects a cancellation request sent to a fulfillment service for a fulfillment order.
+     *
+     * This function performs a `POST` to the `/admin/api/2021-01/fulfillment_orders/{fulfillment_order_id}/cancellation_request/reject.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_create_fulfillment_orders_param_order_cancellation_request_reject`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/cancellationrequest#reject-2021-01
+     */    pub fn deprecated_202101_create_fulfillment_orders_param_order_cancellation_request_reject_request<'a>(&'a self, fulfillment_order_id: &'a str, body: &'a serde_json::Value,) -> ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderCancellationRequestRejectRequest<'a>{
+        ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderCancellationRequestRejectRequest { client: self,fulfillment_order_id,message: None,body,}
+    }
     /**
      * Sends a cancellation request to the fulfillment service of a fulfillment order.
      *
@@ -967,6 +5752,17 @@ impl ShippingAndFulfillment {
             )
             .await
     }
+    /**
+     * Sends a cancellation request to the fulfillment service of a fulfillment order.
+     *
+     * This function performs a `POST` to the `/admin/api/unstable/fulfillment_orders/{fulfillment_order_id}/cancellation_request.json` endpoint.
+     *
+     * As opposed to `deprecated_unstable_create_fulfillment_orders_param_order_cancellation_request`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/cancellationrequest#create-unstable
+     */    pub fn deprecated_unstable_create_fulfillment_orders_param_order_cancellation_request_request<'a>(&'a self, fulfillment_order_id: &'a str, body: &'a serde_json::Value,) -> ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderCancellationRequestRequest<'a>{
+        ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderCancellationRequestRequest { client: self,fulfillment_order_id,message: None,body,}
+    }
     /**
      * Accepts a cancellation request sent to a fulfillment service for a fulfillment order.
      *
@@ -1008,6 +5804,17 @@ impl ShippingAndFulfillment {
             )
             .await
     }
+    /**
+     * Accepts a cancellation request sent to a fulfillment service for a fulfillment order.
+     *
+     * This function performs a `POST` to the `/admin/api/unstable/fulfillment_orders/{fulfillment_order_id}/cancellation_request/accept.json` endpoint.
+     *
+     * As opposed to `deprecated_unstable_create_fulfillment_orders_param_order_cancellation_request_accept`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/cancellationrequest#accept-unstable
+     */    pub fn deprecated_unstable_create_fulfillment_orders_param_order_cancellation_request_accept_request<'a>(&'a self, fulfillment_order_id: &'a str, body: &'a serde_json::Value,) -> ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderCancellationRequestAcceptRequest<'a>{
+        ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderCancellationRequestAcceptRequest { client: self,fulfillment_order_id,message: None,body,}
+    }
     /**
      * Rejects a cancellation request sent to a fulfillment service for a fulfillment order.
      *
@@ -1049,6 +5856,17 @@ impl ShippingAndFulfillment {
             )
             .await
     }
+    /**
+     * Rejects a cancellation request sent to a fulfillment service for a fulfillment order.
+     *
+     * This function performs a `POST` to the `/admin/api/unstable/fulfillment_orders/{fulfillment_order_id}/cancellation_request/reject.json` endpoint.
+     *
+     * As opposed to `deprecated_unstable_create_fulfillment_orders_param_order_cancellation_request_reject`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/cancellationrequest#reject-unstable
+     */    pub fn deprecated_unstable_create_fulfillment_orders_param_order_cancellation_request_reject_request<'a>(&'a self, fulfillment_order_id: &'a str, body: &'a serde_json::Value,) -> ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderCancellationRequestRejectRequest<'a>{
+        ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderCancellationRequestRejectRequest { client: self,fulfillment_order_id,message: None,body,}
+    }
     /**
      * Retrieves a list of carrier services.
      *
@@ -1974,6 +6792,31 @@ impl ShippingAndFulfillment {
             )
             .await
     }
+    /**
+     * Retrieves fulfillments associated with an order. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-01/orders/{order_id}/fulfillments.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_get_orders_param_order_fulfillment`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillment#index-2020-01
+     */
+    pub fn deprecated_202001_get_orders_param_order_fulfillment_request<'a>(
+        &'a self,
+        order_id: &'a str,
+    ) -> ShippingAndFulfillmentDeprecated202001GetOrdersParamOrderFulfillmentRequest<'a> {
+        ShippingAndFulfillmentDeprecated202001GetOrdersParamOrderFulfillmentRequest {
+            client: self,
+            order_id,
+            created_at_max: None,
+            created_at_min: None,
+            fields: None,
+            limit: None,
+            since_id: None,
+            updated_at_max: None,
+            updated_at_min: None,
+        }
+    }
     /**
     * Create a fulfillment for the specified order and line items.
              The fulfillment's status depends on the line items in the order:
@@ -2117,6 +6960,28 @@ impl ShippingAndFulfillment {
             )
             .await
     }
+    /**
+     * Retrieves a count of fulfillments associated with a specific order.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-01/orders/{order_id}/fulfillments/count.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_get_orders_param_order_fulfillments_count`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillment#count-2020-01
+     */
+    pub fn deprecated_202001_get_orders_param_order_fulfillments_count_request<'a>(
+        &'a self,
+        order_id: &'a str,
+    ) -> ShippingAndFulfillmentDeprecated202001GetOrdersParamOrderFulfillmentsCountRequest<'a> {
+        ShippingAndFulfillmentDeprecated202001GetOrdersParamOrderFulfillmentsCountRequest {
+            client: self,
+            order_id,
+            created_at_min: None,
+            created_at_max: None,
+            updated_at_min: None,
+            updated_at_max: None,
+        }
+    }
     /**
      * Retrieve a specific fulfillment.
      *
@@ -2160,6 +7025,28 @@ impl ShippingAndFulfillment {
             )
             .await
     }
+    /**
+     * Retrieve a specific fulfillment.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-01/orders/{order_id}/fulfillments/{fulfillment_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_get_orders_param_order_fulfillments_fulfillment`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillment#show-2020-01
+     */
+    pub fn deprecated_202001_get_orders_param_order_fulfillments_fulfillment_request<'a>(
+        &'a self,
+        order_id: &'a str,
+        fulfillment_id: &'a str,
+    ) -> ShippingAndFulfillmentDeprecated202001GetOrdersParamOrderFulfillmentsFulfillmentRequest<'a>
+    {
+        ShippingAndFulfillmentDeprecated202001GetOrdersParamOrderFulfillmentsFulfillmentRequest {
+            client: self,
+            order_id,
+            fulfillment_id,
+            fields: None,
+        }
+    }
     /**
      * Update information associated with a fulfillment.
      *
@@ -2465,6 +7352,31 @@ impl ShippingAndFulfillment {
             )
             .await
     }
+    /**
+     * Retrieves fulfillments associated with an order. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-04/orders/{order_id}/fulfillments.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_get_orders_param_order_fulfillment`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillment#index-2020-04
+     */
+    pub fn deprecated_202004_get_orders_param_order_fulfillment_request<'a>(
+        &'a self,
+        order_id: &'a str,
+    ) -> ShippingAndFulfillmentDeprecated202004GetOrdersParamOrderFulfillmentRequest<'a> {
+        ShippingAndFulfillmentDeprecated202004GetOrdersParamOrderFulfillmentRequest {
+            client: self,
+            order_id,
+            created_at_max: None,
+            created_at_min: None,
+            fields: None,
+            limit: None,
+            since_id: None,
+            updated_at_max: None,
+            updated_at_min: None,
+        }
+    }
     /**
     * Create a fulfillment for the specified order and line items.
              The fulfillment's status depends on the line items in the order:
@@ -2608,6 +7520,28 @@ impl ShippingAndFulfillment {
             )
             .await
     }
+    /**
+     * Retrieves a count of fulfillments associated with a specific order.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-04/orders/{order_id}/fulfillments/count.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_get_orders_param_order_fulfillments_count`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillment#count-2020-04
+     */
+    pub fn deprecated_202004_get_orders_param_order_fulfillments_count_request<'a>(
+        &'a self,
+        order_id: &'a str,
+    ) -> ShippingAndFulfillmentDeprecated202004GetOrdersParamOrderFulfillmentsCountRequest<'a> {
+        ShippingAndFulfillmentDeprecated202004GetOrdersParamOrderFulfillmentsCountRequest {
+            client: self,
+            order_id,
+            created_at_min: None,
+            created_at_max: None,
+            updated_at_min: None,
+            updated_at_max: None,
+        }
+    }
     /**
      * Retrieve a specific fulfillment.
      *
@@ -2651,6 +7585,28 @@ impl ShippingAndFulfillment {
             )
             .await
     }
+    /**
+     * Retrieve a specific fulfillment.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-04/orders/{order_id}/fulfillments/{fulfillment_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_get_orders_param_order_fulfillments_fulfillment`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillment#show-2020-04
+     */
+    pub fn deprecated_202004_get_orders_param_order_fulfillments_fulfillment_request<'a>(
+        &'a self,
+        order_id: &'a str,
+        fulfillment_id: &'a str,
+    ) -> ShippingAndFulfillmentDeprecated202004GetOrdersParamOrderFulfillmentsFulfillmentRequest<'a>
+    {
+        ShippingAndFulfillmentDeprecated202004GetOrdersParamOrderFulfillmentsFulfillmentRequest {
+            client: self,
+            order_id,
+            fulfillment_id,
+            fields: None,
+        }
+    }
     /**
      * Update information associated with a fulfillment.
      *
@@ -2956,6 +7912,31 @@ impl ShippingAndFulfillment {
             )
             .await
     }
+    /**
+     * Retrieves fulfillments associated with an order. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-07/orders/{order_id}/fulfillments.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_get_orders_param_order_fulfillment`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillment#index-2020-07
+     */
+    pub fn deprecated_202007_get_orders_param_order_fulfillment_request<'a>(
+        &'a self,
+        order_id: &'a str,
+    ) -> ShippingAndFulfillmentDeprecated202007GetOrdersParamOrderFulfillmentRequest<'a> {
+        ShippingAndFulfillmentDeprecated202007GetOrdersParamOrderFulfillmentRequest {
+            client: self,
+            order_id,
+            created_at_max: None,
+            created_at_min: None,
+            fields: None,
+            limit: None,
+            since_id: None,
+            updated_at_max: None,
+            updated_at_min: None,
+        }
+    }
     /**
     * Create a fulfillment for the specified order and line items.
              The fulfillment's status depends on the line items in the order:
@@ -3099,6 +8080,28 @@ impl ShippingAndFulfillment {
             )
             .await
     }
+    /**
+     * Retrieves a count of fulfillments associated with a specific order.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-07/orders/{order_id}/fulfillments/count.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_get_orders_param_order_fulfillments_count`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillment#count-2020-07
+     */
+    pub fn deprecated_202007_get_orders_param_order_fulfillments_count_request<'a>(
+        &'a self,
+        order_id: &'a str,
+    ) -> ShippingAndFulfillmentDeprecated202007GetOrdersParamOrderFulfillmentsCountRequest<'a> {
+        ShippingAndFulfillmentDeprecated202007GetOrdersParamOrderFulfillmentsCountRequest {
+            client: self,
+            order_id,
+            created_at_min: None,
+            created_at_max: None,
+            updated_at_min: None,
+            updated_at_max: None,
+        }
+    }
     /**
      * Retrieve a specific fulfillment.
      *
@@ -3142,6 +8145,28 @@ impl ShippingAndFulfillment {
             )
             .await
     }
+    /**
+     * Retrieve a specific fulfillment.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-07/orders/{order_id}/fulfillments/{fulfillment_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_get_orders_param_order_fulfillments_fulfillment`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillment#show-2020-07
+     */
+    pub fn deprecated_202007_get_orders_param_order_fulfillments_fulfillment_request<'a>(
+        &'a self,
+        order_id: &'a str,
+        fulfillment_id: &'a str,
+    ) -> ShippingAndFulfillmentDeprecated202007GetOrdersParamOrderFulfillmentsFulfillmentRequest<'a>
+    {
+        ShippingAndFulfillmentDeprecated202007GetOrdersParamOrderFulfillmentsFulfillmentRequest {
+            client: self,
+            order_id,
+            fulfillment_id,
+            fields: None,
+        }
+    }
     /**
      * Update information associated with a fulfillment.
      *
@@ -3447,6 +8472,31 @@ impl ShippingAndFulfillment {
             )
             .await
     }
+    /**
+     * Retrieves fulfillments associated with an order. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-10/orders/{order_id}/fulfillments.json` endpoint.
+     *
+     * As opposed to `get_orders_param_order_fulfillment`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillment#index-2020-10
+     */
+    pub fn get_orders_param_order_fulfillment_request<'a>(
+        &'a self,
+        order_id: &'a str,
+    ) -> ShippingAndFulfillmentGetOrdersParamOrderFulfillmentRequest<'a> {
+        ShippingAndFulfillmentGetOrdersParamOrderFulfillmentRequest {
+            client: self,
+            order_id,
+            created_at_max: None,
+            created_at_min: None,
+            fields: None,
+            limit: None,
+            since_id: None,
+            updated_at_max: None,
+            updated_at_min: None,
+        }
+    }
     /**
     * Create a fulfillment for the specified order and line items.
              The fulfillment's status depends on the line items in the order:
@@ -3590,6 +8640,28 @@ impl ShippingAndFulfillment {
             )
             .await
     }
+    /**
+     * Retrieves a count of fulfillments associated with a specific order.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-10/orders/{order_id}/fulfillments/count.json` endpoint.
+     *
+     * As opposed to `get_orders_param_order_fulfillments_count`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillment#count-2020-10
+     */
+    pub fn get_orders_param_order_fulfillments_count_request<'a>(
+        &'a self,
+        order_id: &'a str,
+    ) -> ShippingAndFulfillmentGetOrdersParamOrderFulfillmentsCountRequest<'a> {
+        ShippingAndFulfillmentGetOrdersParamOrderFulfillmentsCountRequest {
+            client: self,
+            order_id,
+            created_at_min: None,
+            created_at_max: None,
+            updated_at_min: None,
+            updated_at_max: None,
+        }
+    }
     /**
      * Retrieve a specific fulfillment.
      *
@@ -3633,6 +8705,27 @@ impl ShippingAndFulfillment {
             )
             .await
     }
+    /**
+     * Retrieve a specific fulfillment.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-10/orders/{order_id}/fulfillments/{fulfillment_id}.json` endpoint.
+     *
+     * As opposed to `get_orders_param_order_fulfillments_fulfillment`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillment#show-2020-10
+     */
+    pub fn get_orders_param_order_fulfillments_fulfillment_request<'a>(
+        &'a self,
+        order_id: &'a str,
+        fulfillment_id: &'a str,
+    ) -> ShippingAndFulfillmentGetOrdersParamOrderFulfillmentsFulfillmentRequest<'a> {
+        ShippingAndFulfillmentGetOrdersParamOrderFulfillmentsFulfillmentRequest {
+            client: self,
+            order_id,
+            fulfillment_id,
+            fields: None,
+        }
+    }
     /**
      * Update information associated with a fulfillment.
      *
@@ -3938,6 +9031,31 @@ impl ShippingAndFulfillment {
             )
             .await
     }
+    /**
+     * Retrieves fulfillments associated with an order. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2021-01/orders/{order_id}/fulfillments.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_get_orders_param_order_fulfillment`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillment#index-2021-01
+     */
+    pub fn deprecated_202101_get_orders_param_order_fulfillment_request<'a>(
+        &'a self,
+        order_id: &'a str,
+    ) -> ShippingAndFulfillmentDeprecated202101GetOrdersParamOrderFulfillmentRequest<'a> {
+        ShippingAndFulfillmentDeprecated202101GetOrdersParamOrderFulfillmentRequest {
+            client: self,
+            order_id,
+            created_at_max: None,
+            created_at_min: None,
+            fields: None,
+            limit: None,
+            since_id: None,
+            updated_at_max: None,
+            updated_at_min: None,
+        }
+    }
     /**
     * Create a fulfillment for the specified order and line items.
              The fulfillment's status depends on the line items in the order:
@@ -4081,6 +9199,28 @@ impl ShippingAndFulfillment {
             )
             .await
     }
+    /**
+     * Retrieves a count of fulfillments associated with a specific order.
+     *
+     * This function performs a `GET` to the `/admin/api/2021-01/orders/{order_id}/fulfillments/count.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_get_orders_param_order_fulfillments_count`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillment#count-2021-01
+     */
+    pub fn deprecated_202101_get_orders_param_order_fulfillments_count_request<'a>(
+        &'a self,
+        order_id: &'a str,
+    ) -> ShippingAndFulfillmentDeprecated202101GetOrdersParamOrderFulfillmentsCountRequest<'a> {
+        ShippingAndFulfillmentDeprecated202101GetOrdersParamOrderFulfillmentsCountRequest {
+            client: self,
+            order_id,
+            created_at_min: None,
+            created_at_max: None,
+            updated_at_min: None,
+            updated_at_max: None,
+        }
+    }
     /**
      * Retrieve a specific fulfillment.
      *
@@ -4124,6 +9264,28 @@ impl ShippingAndFulfillment {
             )
             .await
     }
+    /**
+     * Retrieve a specific fulfillment.
+     *
+     * This function performs a `GET` to the `/admin/api/2021-01/orders/{order_id}/fulfillments/{fulfillment_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_get_orders_param_order_fulfillments_fulfillment`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillment#show-2021-01
+     */
+    pub fn deprecated_202101_get_orders_param_order_fulfillments_fulfillment_request<'a>(
+        &'a self,
+        order_id: &'a str,
+        fulfillment_id: &'a str,
+    ) -> ShippingAndFulfillmentDeprecated202101GetOrdersParamOrderFulfillmentsFulfillmentRequest<'a>
+    {
+        ShippingAndFulfillmentDeprecated202101GetOrdersParamOrderFulfillmentsFulfillmentRequest {
+            client: self,
+            order_id,
+            fulfillment_id,
+            fields: None,
+        }
+    }
     /**
      * Update information associated with a fulfillment.
      *
@@ -4429,6 +9591,31 @@ impl ShippingAndFulfillment {
             )
             .await
     }
+    /**
+     * Retrieves fulfillments associated with an order. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/unstable/orders/{order_id}/fulfillments.json` endpoint.
+     *
+     * As opposed to `deprecated_unstable_get_orders_param_order_fulfillment`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillment#index-unstable
+     */
+    pub fn deprecated_unstable_get_orders_param_order_fulfillment_request<'a>(
+        &'a self,
+        order_id: &'a str,
+    ) -> ShippingAndFulfillmentDeprecatedUnstableGetOrdersParamOrderFulfillmentRequest<'a> {
+        ShippingAndFulfillmentDeprecatedUnstableGetOrdersParamOrderFulfillmentRequest {
+            client: self,
+            order_id,
+            created_at_max: None,
+            created_at_min: None,
+            fields: None,
+            limit: None,
+            since_id: None,
+            updated_at_max: None,
+            updated_at_min: None,
+        }
+    }
     /**
     * Create a fulfillment for the specified order and line items.
              The fulfillment's status depends on the line items in the order:
@@ -4572,6 +9759,29 @@ impl ShippingAndFulfillment {
             )
             .await
     }
+    /**
+     * Retrieves a count of fulfillments associated with a specific order.
+     *
+     * This function performs a `GET` to the `/admin/api/unstable/orders/{order_id}/fulfillments/count.json` endpoint.
+     *
+     * As opposed to `deprecated_unstable_get_orders_param_order_fulfillments_count`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillment#count-unstable
+     */
+    pub fn deprecated_unstable_get_orders_param_order_fulfillments_count_request<'a>(
+        &'a self,
+        order_id: &'a str,
+    ) -> ShippingAndFulfillmentDeprecatedUnstableGetOrdersParamOrderFulfillmentsCountRequest<'a>
+    {
+        ShippingAndFulfillmentDeprecatedUnstableGetOrdersParamOrderFulfillmentsCountRequest {
+            client: self,
+            order_id,
+            created_at_min: None,
+            created_at_max: None,
+            updated_at_min: None,
+            updated_at_max: None,
+        }
+    }
     /**
      * Retrieve a specific fulfillment.
      *
@@ -4615,6 +9825,28 @@ impl ShippingAndFulfillment {
             )
             .await
     }
+    /**
+     * Retrieve a specific fulfillment.
+     *
+     * This function performs a `GET` to the `/admin/api/unstable/orders/{order_id}/fulfillments/{fulfillment_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_unstable_get_orders_param_order_fulfillments_fulfillment`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillment#show-unstable
+     */
+    pub fn deprecated_unstable_get_orders_param_order_fulfillments_fulfillment_request<'a>(
+        &'a self,
+        order_id: &'a str,
+        fulfillment_id: &'a str,
+    ) -> ShippingAndFulfillmentDeprecatedUnstableGetOrdersParamOrderFulfillmentsFulfillmentRequest<'a>
+    {
+        ShippingAndFulfillmentDeprecatedUnstableGetOrdersParamOrderFulfillmentsFulfillmentRequest {
+            client: self,
+            order_id,
+            fulfillment_id,
+            fields: None,
+        }
+    }
     /**
      * Update information associated with a fulfillment.
      *
@@ -5889,6 +11121,29 @@ impl ShippingAndFulfillment {
             )
             .await
     }
+    /**
+    * Marks an in progress fulfillment order as incomplete, indicating the fulfillment service
+           is unable to ship any remaining items and intends to close the fulfillment order.
+    *
+    * This function performs a `POST` to the `/admin/api/2020-01/fulfillment_orders/{fulfillment_order_id}/close.json` endpoint.
+    *
+    * As opposed to `deprecated_202001_create_fulfillment_orders_param_order_close`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+    *
+    * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentorder#close-2020-01
+    */
+    pub fn deprecated_202001_create_fulfillment_orders_param_order_close_request<'a>(
+        &'a self,
+        fulfillment_order_id: &'a str,
+        body: &'a serde_json::Value,
+    ) -> ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderCloseRequest<'a>
+    {
+        ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderCloseRequest {
+            client: self,
+            fulfillment_order_id,
+            message: None,
+            body,
+        }
+    }
     /**
      * Moves a fulfillment order from one merchant managed location to another merchant managed location.
      *
@@ -5930,6 +11185,28 @@ impl ShippingAndFulfillment {
             )
             .await
     }
+    /**
+     * Moves a fulfillment order from one merchant managed location to another merchant managed location.
+     *
+     * This function performs a `POST` to the `/admin/api/2020-01/fulfillment_orders/{fulfillment_order_id}/move.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_create_fulfillment_orders_param_order_move`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentorder#move-2020-01
+     */
+    pub fn deprecated_202001_create_fulfillment_orders_param_order_move_request<'a>(
+        &'a self,
+        fulfillment_order_id: &'a str,
+        body: &'a serde_json::Value,
+    ) -> ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderMoveRequest<'a>
+    {
+        ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderMoveRequest {
+            client: self,
+            fulfillment_order_id,
+            new_location_id: None,
+            body,
+        }
+    }
     /**
      * Retrieves a list of fulfillment orders for a specific order.
      *
@@ -6070,6 +11347,29 @@ impl ShippingAndFulfillment {
             )
             .await
     }
+    /**
+    * Marks an in progress fulfillment order as incomplete, indicating the fulfillment service
+           is unable to ship any remaining items and intends to close the fulfillment order.
+    *
+    * This function performs a `POST` to the `/admin/api/2020-04/fulfillment_orders/{fulfillment_order_id}/close.json` endpoint.
+    *
+    * As opposed to `deprecated_202004_create_fulfillment_orders_param_order_close`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+    *
+    * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentorder#close-2020-04
+    */
+    pub fn deprecated_202004_create_fulfillment_orders_param_order_close_request<'a>(
+        &'a self,
+        fulfillment_order_id: &'a str,
+        body: &'a serde_json::Value,
+    ) -> ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderCloseRequest<'a>
+    {
+        ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderCloseRequest {
+            client: self,
+            fulfillment_order_id,
+            message: None,
+            body,
+        }
+    }
     /**
      * Moves a fulfillment order from one merchant managed location to another merchant managed location.
      *
@@ -6111,6 +11411,28 @@ impl ShippingAndFulfillment {
             )
             .await
     }
+    /**
+     * Moves a fulfillment order from one merchant managed location to another merchant managed location.
+     *
+     * This function performs a `POST` to the `/admin/api/2020-04/fulfillment_orders/{fulfillment_order_id}/move.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_create_fulfillment_orders_param_order_move`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentorder#move-2020-04
+     */
+    pub fn deprecated_202004_create_fulfillment_orders_param_order_move_request<'a>(
+        &'a self,
+        fulfillment_order_id: &'a str,
+        body: &'a serde_json::Value,
+    ) -> ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderMoveRequest<'a>
+    {
+        ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderMoveRequest {
+            client: self,
+            fulfillment_order_id,
+            new_location_id: None,
+            body,
+        }
+    }
     /**
      * Retrieves a list of fulfillment orders for a specific order.
      *
@@ -6251,6 +11573,29 @@ impl ShippingAndFulfillment {
             )
             .await
     }
+    /**
+    * Marks an in progress fulfillment order as incomplete, indicating the fulfillment service
+           is unable to ship any remaining items and intends to close the fulfillment order.
+    *
+    * This function performs a `POST` to the `/admin/api/2020-07/fulfillment_orders/{fulfillment_order_id}/close.json` endpoint.
+    *
+    * As opposed to `deprecated_202007_create_fulfillment_orders_param_order_close`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+    *
+    * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentorder#close-2020-07
+    */
+    pub fn deprecated_202007_create_fulfillment_orders_param_order_close_request<'a>(
+        &'a self,
+        fulfillment_order_id: &'a str,
+        body: &'a serde_json::Value,
+    ) -> ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderCloseRequest<'a>
+    {
+        ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderCloseRequest {
+            client: self,
+            fulfillment_order_id,
+            message: None,
+            body,
+        }
+    }
     /**
      * Moves a fulfillment order from one merchant managed location to another merchant managed location.
      *
@@ -6292,6 +11637,28 @@ impl ShippingAndFulfillment {
             )
             .await
     }
+    /**
+     * Moves a fulfillment order from one merchant managed location to another merchant managed location.
+     *
+     * This function performs a `POST` to the `/admin/api/2020-07/fulfillment_orders/{fulfillment_order_id}/move.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_create_fulfillment_orders_param_order_move`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentorder#move-2020-07
+     */
+    pub fn deprecated_202007_create_fulfillment_orders_param_order_move_request<'a>(
+        &'a self,
+        fulfillment_order_id: &'a str,
+        body: &'a serde_json::Value,
+    ) -> ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderMoveRequest<'a>
+    {
+        ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderMoveRequest {
+            client: self,
+            fulfillment_order_id,
+            new_location_id: None,
+            body,
+        }
+    }
     /**
      * Retrieves a list of fulfillment orders for a specific order.
      *
@@ -6432,6 +11799,28 @@ impl ShippingAndFulfillment {
             )
             .await
     }
+    /**
+    * Marks an in progress fulfillment order as incomplete, indicating the fulfillment service
+           is unable to ship any remaining items and intends to close the fulfillment order.
+    *
+    * This function performs a `POST` to the `/admin/api/2020-10/fulfillment_orders/{fulfillment_order_id}/close.json` endpoint.
+    *
+    * As opposed to `create_fulfillment_orders_param_order_close`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+    *
+    * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentorder#close-2020-10
+    */
+    pub fn create_fulfillment_orders_param_order_close_request<'a>(
+        &'a self,
+        fulfillment_order_id: &'a str,
+        body: &'a serde_json::Value,
+    ) -> ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderCloseRequest<'a> {
+        ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderCloseRequest {
+            client: self,
+            fulfillment_order_id,
+            message: None,
+            body,
+        }
+    }
     /**
      * Moves a fulfillment order from one merchant managed location to another merchant managed location.
      *
@@ -6473,6 +11862,27 @@ impl ShippingAndFulfillment {
             )
             .await
     }
+    /**
+     * Moves a fulfillment order from one merchant managed location to another merchant managed location.
+     *
+     * This function performs a `POST` to the `/admin/api/2020-10/fulfillment_orders/{fulfillment_order_id}/move.json` endpoint.
+     *
+     * As opposed to `create_fulfillment_orders_param_order_move`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentorder#move-2020-10
+     */
+    pub fn create_fulfillment_orders_param_order_move_request<'a>(
+        &'a self,
+        fulfillment_order_id: &'a str,
+        body: &'a serde_json::Value,
+    ) -> ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderMoveRequest<'a> {
+        ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderMoveRequest {
+            client: self,
+            fulfillment_order_id,
+            new_location_id: None,
+            body,
+        }
+    }
     /**
      * Retrieves a list of fulfillment orders for a specific order.
      *
@@ -6613,6 +12023,29 @@ impl ShippingAndFulfillment {
             )
             .await
     }
+    /**
+    * Marks an in progress fulfillment order as incomplete, indicating the fulfillment service
+           is unable to ship any remaining items and intends to close the fulfillment order.
+    *
+    * This function performs a `POST` to the `/admin/api/2021-01/fulfillment_orders/{fulfillment_order_id}/close.json` endpoint.
+    *
+    * As opposed to `deprecated_202101_create_fulfillment_orders_param_order_close`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+    *
+    * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentorder#close-2021-01
+    */
+    pub fn deprecated_202101_create_fulfillment_orders_param_order_close_request<'a>(
+        &'a self,
+        fulfillment_order_id: &'a str,
+        body: &'a serde_json::Value,
+    ) -> ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderCloseRequest<'a>
+    {
+        ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderCloseRequest {
+            client: self,
+            fulfillment_order_id,
+            message: None,
+            body,
+        }
+    }
     /**
      * Moves a fulfillment order from one merchant managed location to another merchant managed location.
      *
@@ -6654,6 +12087,28 @@ impl ShippingAndFulfillment {
             )
             .await
     }
+    /**
+     * Moves a fulfillment order from one merchant managed location to another merchant managed location.
+     *
+     * This function performs a `POST` to the `/admin/api/2021-01/fulfillment_orders/{fulfillment_order_id}/move.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_create_fulfillment_orders_param_order_move`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentorder#move-2021-01
+     */
+    pub fn deprecated_202101_create_fulfillment_orders_param_order_move_request<'a>(
+        &'a self,
+        fulfillment_order_id: &'a str,
+        body: &'a serde_json::Value,
+    ) -> ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderMoveRequest<'a>
+    {
+        ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderMoveRequest {
+            client: self,
+            fulfillment_order_id,
+            new_location_id: None,
+            body,
+        }
+    }
     /**
     * Marks a scheduled fulfillment order as ready for fulfillment.
              This endpoint allows merchants to work on a scheduled fulfillment order before its expected fulfill_at datetime.
@@ -6860,6 +12315,26 @@ impl ShippingAndFulfillment {
             .await
     }
     /**
+    * Releases the fulfillment order holds for a specific order. Fulfillment orders are created
+             with an ON_HOLD status if the channel that created the order has a fulfillment hold policy.
+    *
+    * This function performs a `POST` to the `/admin/api/unstable/fulfillment_orders/release_hold.json` endpoint.
+    *
+    * As opposed to `deprecated_unstable_create_fulfillment_orders_release_hold`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+    *
+    * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentorder#release_hold-unstable
+    */
+    pub fn deprecated_unstable_create_fulfillment_orders_release_hold_request<'a>(
+        &'a self,
+        body: &'a serde_json::Value,
+    ) -> ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersReleaseHoldRequest<'a> {
+        ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersReleaseHoldRequest {
+            client: self,
+            order_id: None,
+            body,
+        }
+    }
+    /**
     * Marks an in progress fulfillment order as incomplete, indicating the fulfillment service
            is unable to ship any remaining items and intends to close the fulfillment order.
     *
@@ -6901,6 +12376,29 @@ impl ShippingAndFulfillment {
             )
             .await
     }
+    /**
+    * Marks an in progress fulfillment order as incomplete, indicating the fulfillment service
+           is unable to ship any remaining items and intends to close the fulfillment order.
+    *
+    * This function performs a `POST` to the `/admin/api/unstable/fulfillment_orders/{fulfillment_order_id}/close.json` endpoint.
+    *
+    * As opposed to `deprecated_unstable_create_fulfillment_orders_param_order_close`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+    *
+    * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentorder#close-unstable
+    */
+    pub fn deprecated_unstable_create_fulfillment_orders_param_order_close_request<'a>(
+        &'a self,
+        fulfillment_order_id: &'a str,
+        body: &'a serde_json::Value,
+    ) -> ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderCloseRequest<'a>
+    {
+        ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderCloseRequest {
+            client: self,
+            fulfillment_order_id,
+            message: None,
+            body,
+        }
+    }
     /**
      * Moves a fulfillment order from one merchant managed location to another merchant managed location.
      *
@@ -6942,6 +12440,28 @@ impl ShippingAndFulfillment {
             )
             .await
     }
+    /**
+     * Moves a fulfillment order from one merchant managed location to another merchant managed location.
+     *
+     * This function performs a `POST` to the `/admin/api/unstable/fulfillment_orders/{fulfillment_order_id}/move.json` endpoint.
+     *
+     * As opposed to `deprecated_unstable_create_fulfillment_orders_param_order_move`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentorder#move-unstable
+     */
+    pub fn deprecated_unstable_create_fulfillment_orders_param_order_move_request<'a>(
+        &'a self,
+        fulfillment_order_id: &'a str,
+        body: &'a serde_json::Value,
+    ) -> ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderMoveRequest<'a>
+    {
+        ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderMoveRequest {
+            client: self,
+            fulfillment_order_id,
+            new_location_id: None,
+            body,
+        }
+    }
     /**
     * Marks a scheduled fulfillment order as ready for fulfillment.
              This endpoint allows merchants to work on a scheduled fulfillment order before its expected fulfill_at datetime.
@@ -7059,6 +12579,29 @@ impl ShippingAndFulfillment {
             )
             .await
     }
+    /**
+     * Sends a fulfillment request to the fulfillment service of a fulfillment order.
+     *
+     * This function performs a `POST` to the `/admin/api/2020-01/fulfillment_orders/{fulfillment_order_id}/fulfillment_request.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_create_fulfillment_orders_param_order_request`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentrequest#create-2020-01
+     */
+    pub fn deprecated_202001_create_fulfillment_orders_param_order_request_request<'a>(
+        &'a self,
+        fulfillment_order_id: &'a str,
+        body: &'a serde_json::Value,
+    ) -> ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderRequestRequest<'a>
+    {
+        ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderRequestRequest {
+            client: self,
+            fulfillment_order_id,
+            message: None,
+            fulfillment_order_line_items: None,
+            body,
+        }
+    }
     /**
      * Accepts a fulfillment request sent to a fulfillment service for a fulfillment order.
      *
@@ -7100,6 +12643,17 @@ impl ShippingAndFulfillment {
             )
             .await
     }
+    /**
+     * Accepts a fulfillment request sent to a fulfillment service for a fulfillment order.
+     *
+     * This function performs a `POST` to the `/admin/api/2020-01/fulfillment_orders/{fulfillment_order_id}/fulfillment_request/accept.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_create_fulfillment_orders_param_order_request_accept`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentrequest#accept-2020-01
+     */    pub fn deprecated_202001_create_fulfillment_orders_param_order_request_accept_request<'a>(&'a self, fulfillment_order_id: &'a str, body: &'a serde_json::Value,) -> ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderRequestAcceptRequest<'a>{
+        ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderRequestAcceptRequest { client: self,fulfillment_order_id,message: None,body,}
+    }
     /**
      * Rejects a fulfillment request sent to a fulfillment service for a fulfillment order.
      *
@@ -7141,6 +12695,17 @@ impl ShippingAndFulfillment {
             )
             .await
     }
+    /**
+     * Rejects a fulfillment request sent to a fulfillment service for a fulfillment order.
+     *
+     * This function performs a `POST` to the `/admin/api/2020-01/fulfillment_orders/{fulfillment_order_id}/fulfillment_request/reject.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_create_fulfillment_orders_param_order_request_reject`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentrequest#reject-2020-01
+     */    pub fn deprecated_202001_create_fulfillment_orders_param_order_request_reject_request<'a>(&'a self, fulfillment_order_id: &'a str, body: &'a serde_json::Value,) -> ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderRequestRejectRequest<'a>{
+        ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderRequestRejectRequest { client: self,fulfillment_order_id,message: None,body,}
+    }
     /**
      * Sends a fulfillment request to the fulfillment service of a fulfillment order.
      *
@@ -7190,6 +12755,29 @@ impl ShippingAndFulfillment {
             )
             .await
     }
+    /**
+     * Sends a fulfillment request to the fulfillment service of a fulfillment order.
+     *
+     * This function performs a `POST` to the `/admin/api/2020-04/fulfillment_orders/{fulfillment_order_id}/fulfillment_request.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_create_fulfillment_orders_param_order_request`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentrequest#create-2020-04
+     */
+    pub fn deprecated_202004_create_fulfillment_orders_param_order_request_request<'a>(
+        &'a self,
+        fulfillment_order_id: &'a str,
+        body: &'a serde_json::Value,
+    ) -> ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderRequestRequest<'a>
+    {
+        ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderRequestRequest {
+            client: self,
+            fulfillment_order_id,
+            message: None,
+            fulfillment_order_line_items: None,
+            body,
+        }
+    }
     /**
      * Accepts a fulfillment request sent to a fulfillment service for a fulfillment order.
      *
@@ -7231,6 +12819,17 @@ impl ShippingAndFulfillment {
             )
             .await
     }
+    /**
+     * Accepts a fulfillment request sent to a fulfillment service for a fulfillment order.
+     *
+     * This function performs a `POST` to the `/admin/api/2020-04/fulfillment_orders/{fulfillment_order_id}/fulfillment_request/accept.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_create_fulfillment_orders_param_order_request_accept`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentrequest#accept-2020-04
+     */    pub fn deprecated_202004_create_fulfillment_orders_param_order_request_accept_request<'a>(&'a self, fulfillment_order_id: &'a str, body: &'a serde_json::Value,) -> ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderRequestAcceptRequest<'a>{
+        ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderRequestAcceptRequest { client: self,fulfillment_order_id,message: None,body,}
+    }
     /**
      * Rejects a fulfillment request sent to a fulfillment service for a fulfillment order.
      *
@@ -7272,6 +12871,17 @@ impl ShippingAndFulfillment {
             )
             .await
     }
+    /**
+     * Rejects a fulfillment request sent to a fulfillment service for a fulfillment order.
+     *
+     * This function performs a `POST` to the `/admin/api/2020-04/fulfillment_orders/{fulfillment_order_id}/fulfillment_request/reject.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_create_fulfillment_orders_param_order_request_reject`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentrequest#reject-2020-04
+     */    pub fn deprecated_202004_create_fulfillment_orders_param_order_request_reject_request<'a>(&'a self, fulfillment_order_id: &'a str, body: &'a serde_json::Value,) -> ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderRequestRejectRequest<'a>{
+        ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderRequestRejectRequest { client: self,fulfillment_order_id,message: None,body,}
+    }
     /**
      * Sends a fulfillment request to the fulfillment service of a fulfillment order.
      *
@@ -7321,6 +12931,29 @@ impl ShippingAndFulfillment {
             )
             .await
     }
+    /**
+     * Sends a fulfillment request to the fulfillment service of a fulfillment order.
+     *
+     * This function performs a `POST` to the `/admin/api/2020-07/fulfillment_orders/{fulfillment_order_id}/fulfillment_request.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_create_fulfillment_orders_param_order_request`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentrequest#create-2020-07
+     */
+    pub fn deprecated_202007_create_fulfillment_orders_param_order_request_request<'a>(
+        &'a self,
+        fulfillment_order_id: &'a str,
+        body: &'a serde_json::Value,
+    ) -> ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderRequestRequest<'a>
+    {
+        ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderRequestRequest {
+            client: self,
+            fulfillment_order_id,
+            message: None,
+            fulfillment_order_line_items: None,
+            body,
+        }
+    }
     /**
      * Accepts a fulfillment request sent to a fulfillment service for a fulfillment order.
      *
@@ -7362,6 +12995,17 @@ impl ShippingAndFulfillment {
             )
             .await
     }
+    /**
+     * Accepts a fulfillment request sent to a fulfillment service for a fulfillment order.
+     *
+     * This function performs a `POST` to the `/admin/api/2020-07/fulfillment_orders/{fulfillment_order_id}/fulfillment_request/accept.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_create_fulfillment_orders_param_order_request_accept`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentrequest#accept-2020-07
+     */    pub fn deprecated_202007_create_fulfillment_orders_param_order_request_accept_request<'a>(&'a self, fulfillment_order_id: &'a str, body: &'a serde_json::Value,) -> ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderRequestAcceptRequest<'a>{
+        ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderRequestAcceptRequest { client: self,fulfillment_order_id,message: None,body,}
+    }
     /**
      * Rejects a fulfillment request sent to a fulfillment service for a fulfillment order.
      *
@@ -7403,6 +13047,17 @@ impl ShippingAndFulfillment {
             )
             .await
     }
+    /**
+     * Rejects a fulfillment request sent to a fulfillment service for a fulfillment order.
+     *
+     * This function performs a `POST` to the `/admin/api/2020-07/fulfillment_orders/{fulfillment_order_id}/fulfillment_request/reject.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_create_fulfillment_orders_param_order_request_reject`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentrequest#reject-2020-07
+     */    pub fn deprecated_202007_create_fulfillment_orders_param_order_request_reject_request<'a>(&'a self, fulfillment_order_id: &'a str, body: &'a serde_json::Value,) -> ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderRequestRejectRequest<'a>{
+        ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderRequestRejectRequest { client: self,fulfillment_order_id,message: None,body,}
+    }
     /**
      * Sends a fulfillment request to the fulfillment service of a fulfillment order.
      *
@@ -7452,6 +13107,28 @@ impl ShippingAndFulfillment {
             )
             .await
     }
+    /**
+     * Sends a fulfillment request to the fulfillment service of a fulfillment order.
+     *
+     * This function performs a `POST` to the `/admin/api/2020-10/fulfillment_orders/{fulfillment_order_id}/fulfillment_request.json` endpoint.
+     *
+     * As opposed to `create_fulfillment_orders_param_order_request`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentrequest#create-2020-10
+     */
+    pub fn create_fulfillment_orders_param_order_request_request<'a>(
+        &'a self,
+        fulfillment_order_id: &'a str,
+        body: &'a serde_json::Value,
+    ) -> ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderRequestRequest<'a> {
+        ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderRequestRequest {
+            client: self,
+            fulfillment_order_id,
+            message: None,
+            fulfillment_order_line_items: None,
+            body,
+        }
+    }
     /**
      * Accepts a fulfillment request sent to a fulfillment service for a fulfillment order.
      *
@@ -7493,6 +13170,27 @@ impl ShippingAndFulfillment {
             )
             .await
     }
+    /**
+     * Accepts a fulfillment request sent to a fulfillment service for a fulfillment order.
+     *
+     * This function performs a `POST` to the `/admin/api/2020-10/fulfillment_orders/{fulfillment_order_id}/fulfillment_request/accept.json` endpoint.
+     *
+     * As opposed to `create_fulfillment_orders_param_order_request_accept`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentrequest#accept-2020-10
+     */
+    pub fn create_fulfillment_orders_param_order_request_accept_request<'a>(
+        &'a self,
+        fulfillment_order_id: &'a str,
+        body: &'a serde_json::Value,
+    ) -> ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderRequestAcceptRequest<'a> {
+        ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderRequestAcceptRequest {
+            client: self,
+            fulfillment_order_id,
+            message: None,
+            body,
+        }
+    }
     /**
      * Rejects a fulfillment request sent to a fulfillment service for a fulfillment order.
      *
@@ -7534,6 +13232,27 @@ impl ShippingAndFulfillment {
             )
             .await
     }
+    /**
+     * Rejects a fulfillment request sent to a fulfillment service for a fulfillment order.
+     *
+     * This function performs a `POST` to the `/admin/api/2020-10/fulfillment_orders/{fulfillment_order_id}/fulfillment_request/reject.json` endpoint.
+     *
+     * As opposed to `create_fulfillment_orders_param_order_request_reject`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentrequest#reject-2020-10
+     */
+    pub fn create_fulfillment_orders_param_order_request_reject_request<'a>(
+        &'a self,
+        fulfillment_order_id: &'a str,
+        body: &'a serde_json::Value,
+    ) -> ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderRequestRejectRequest<'a> {
+        ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderRequestRejectRequest {
+            client: self,
+            fulfillment_order_id,
+            message: None,
+            body,
+        }
+    }
     /**
      * Sends a fulfillment request to the fulfillment service of a fulfillment order.
      *
@@ -7583,6 +13302,29 @@ impl ShippingAndFulfillment {
             )
             .await
     }
+    /**
+     * Sends a fulfillment request to the fulfillment service of a fulfillment order.
+     *
+     * This function performs a `POST` to the `/admin/api/2021-01/fulfillment_orders/{fulfillment_order_id}/fulfillment_request.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_create_fulfillment_orders_param_order_request`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentrequest#create-2021-01
+     */
+    pub fn deprecated_202101_create_fulfillment_orders_param_order_request_request<'a>(
+        &'a self,
+        fulfillment_order_id: &'a str,
+        body: &'a serde_json::Value,
+    ) -> ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderRequestRequest<'a>
+    {
+        ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderRequestRequest {
+            client: self,
+            fulfillment_order_id,
+            message: None,
+            fulfillment_order_line_items: None,
+            body,
+        }
+    }
     /**
      * Accepts a fulfillment request sent to a fulfillment service for a fulfillment order.
      *
@@ -7624,6 +13366,17 @@ impl ShippingAndFulfillment {
             )
             .await
     }
+    /**
+     * Accepts a fulfillment request sent to a fulfillment service for a fulfillment order.
+     *
+     * This function performs a `POST` to the `/admin/api/2021-01/fulfillment_orders/{fulfillment_order_id}/fulfillment_request/accept.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_create_fulfillment_orders_param_order_request_accept`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentrequest#accept-2021-01
+     */    pub fn deprecated_202101_create_fulfillment_orders_param_order_request_accept_request<'a>(&'a self, fulfillment_order_id: &'a str, body: &'a serde_json::Value,) -> ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderRequestAcceptRequest<'a>{
+        ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderRequestAcceptRequest { client: self,fulfillment_order_id,message: None,body,}
+    }
     /**
      * Rejects a fulfillment request sent to a fulfillment service for a fulfillment order.
      *
@@ -7665,6 +13418,17 @@ impl ShippingAndFulfillment {
             )
             .await
     }
+    /**
+     * Rejects a fulfillment request sent to a fulfillment service for a fulfillment order.
+     *
+     * This function performs a `POST` to the `/admin/api/2021-01/fulfillment_orders/{fulfillment_order_id}/fulfillment_request/reject.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_create_fulfillment_orders_param_order_request_reject`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentrequest#reject-2021-01
+     */    pub fn deprecated_202101_create_fulfillment_orders_param_order_request_reject_request<'a>(&'a self, fulfillment_order_id: &'a str, body: &'a serde_json::Value,) -> ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderRequestRejectRequest<'a>{
+        ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderRequestRejectRequest { client: self,fulfillment_order_id,message: None,body,}
+    }
     /**
      * Sends a fulfillment request to the fulfillment service of a fulfillment order.
      *
@@ -7714,6 +13478,29 @@ impl ShippingAndFulfillment {
             )
             .await
     }
+    /**
+     * Sends a fulfillment request to the fulfillment service of a fulfillment order.
+     *
+     * This function performs a `POST` to the `/admin/api/unstable/fulfillment_orders/{fulfillment_order_id}/fulfillment_request.json` endpoint.
+     *
+     * As opposed to `deprecated_unstable_create_fulfillment_orders_param_order_request`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentrequest#create-unstable
+     */
+    pub fn deprecated_unstable_create_fulfillment_orders_param_order_request_request<'a>(
+        &'a self,
+        fulfillment_order_id: &'a str,
+        body: &'a serde_json::Value,
+    ) -> ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderRequestRequest<'a>
+    {
+        ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderRequestRequest {
+            client: self,
+            fulfillment_order_id,
+            message: None,
+            fulfillment_order_line_items: None,
+            body,
+        }
+    }
     /**
      * Accepts a fulfillment request sent to a fulfillment service for a fulfillment order.
      *
@@ -7755,6 +13542,17 @@ impl ShippingAndFulfillment {
             )
             .await
     }
+    /**
+     * Accepts a fulfillment request sent to a fulfillment service for a fulfillment order.
+     *
+     * This function performs a `POST` to the `/admin/api/unstable/fulfillment_orders/{fulfillment_order_id}/fulfillment_request/accept.json` endpoint.
+     *
+     * As opposed to `deprecated_unstable_create_fulfillment_orders_param_order_request_accept`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentrequest#accept-unstable
+     */    pub fn deprecated_unstable_create_fulfillment_orders_param_order_request_accept_request<'a>(&'a self, fulfillment_order_id: &'a str, body: &'a serde_json::Value,) -> ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderRequestAcceptRequest<'a>{
+        ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderRequestAcceptRequest { client: self,fulfillment_order_id,message: None,body,}
+    }
     /**
      * Rejects a fulfillment request sent to a fulfillment service for a fulfillment order.
      *
@@ -7796,6 +13594,17 @@ impl ShippingAndFulfillment {
             )
             .await
     }
+    /**
+     * Rejects a fulfillment request sent to a fulfillment service for a fulfillment order.
+     *
+     * This function performs a `POST` to the `/admin/api/unstable/fulfillment_orders/{fulfillment_order_id}/fulfillment_request/reject.json` endpoint.
+     *
+     * As opposed to `deprecated_unstable_create_fulfillment_orders_param_order_request_reject`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentrequest#reject-unstable
+     */    pub fn deprecated_unstable_create_fulfillment_orders_param_order_request_reject_request<'a>(&'a self, fulfillment_order_id: &'a str, body: &'a serde_json::Value,) -> ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderRequestRejectRequest<'a>{
+        ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderRequestRejectRequest { client: self,fulfillment_order_id,message: None,body,}
+    }
     /**
      * Get tracking numbers for orders.
      *
@@ -7834,6 +13643,24 @@ impl ShippingAndFulfillment {
             )
             .await
     }
+    /**
+     * Get tracking numbers for orders.
+     *
+     * This function performs a `GET` to the `/fetch_tracking_numbers` endpoint.
+     *
+     * As opposed to `deprecated_unknown_version_get_fetch_tracking_number`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentservice#fetch-tracking-numbers
+     */
+    pub fn deprecated_unknown_version_get_fetch_tracking_number_request(
+        &self,
+    ) -> ShippingAndFulfillmentDeprecatedUnknownVersionGetFetchTrackingNumberRequest<'_> {
+        ShippingAndFulfillmentDeprecatedUnknownVersionGetFetchTrackingNumberRequest {
+            client: self,
+            order_names: None,
+            shop: None,
+        }
+    }
     /**
      * Get inventory levels.
      *
@@ -7880,6 +13707,26 @@ impl ShippingAndFulfillment {
             )
             .await
     }
+    /**
+     * Get inventory levels.
+     *
+     * This function performs a `GET` to the `/fetch_stock` endpoint.
+     *
+     * As opposed to `deprecated_unknown_version_get_fetch_stock`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentservice#fetch-stock
+     */
+    pub fn deprecated_unknown_version_get_fetch_stock_request(
+        &self,
+    ) -> ShippingAndFulfillmentDeprecatedUnknownVersionGetFetchStockRequest<'_> {
+        ShippingAndFulfillmentDeprecatedUnknownVersionGetFetchStockRequest {
+            client: self,
+            max_retries: None,
+            shop: None,
+            sku: None,
+            timestamp: None,
+        }
+    }
     /**
      * .
      *
@@ -7915,6 +13762,23 @@ impl ShippingAndFulfillment {
             )
             .await
     }
+    /**
+     * .
+     *
+     * This function performs a `GET` to the `/admin/api/2020-01/fulfillment_services.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_get_fulfillment_service`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentservice#index-2020-01
+     */
+    pub fn deprecated_202001_get_fulfillment_service_request(
+        &self,
+    ) -> ShippingAndFulfillmentDeprecated202001GetFulfillmentServiceRequest<'_> {
+        ShippingAndFulfillmentDeprecated202001GetFulfillmentServiceRequest {
+            client: self,
+            scope: None,
+        }
+    }
     /**
     * To create a fulfillment service, you can also use a cURL request that uses that fulfillment_service.json payload:
              Copy  curl -X POST -d @fulfillment_service.json -H"Accept:application/json" -H"Content-Type:application/json" -H"X-Shopify-Access-Token:THE_TOKEN_GOES_HERE" https://AUTHORIZED_SHOP.myshopify.com/admin/fulfillment_services
@@ -8074,6 +13938,23 @@ impl ShippingAndFulfillment {
             )
             .await
     }
+    /**
+     * .
+     *
+     * This function performs a `GET` to the `/admin/api/2020-04/fulfillment_services.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_get_fulfillment_service`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentservice#index-2020-04
+     */
+    pub fn deprecated_202004_get_fulfillment_service_request(
+        &self,
+    ) -> ShippingAndFulfillmentDeprecated202004GetFulfillmentServiceRequest<'_> {
+        ShippingAndFulfillmentDeprecated202004GetFulfillmentServiceRequest {
+            client: self,
+            scope: None,
+        }
+    }
     /**
     * To create a fulfillment service, you can also use a cURL request that uses that fulfillment_service.json payload:
              Copy  curl -X POST -d @fulfillment_service.json -H"Accept:application/json" -H"Content-Type:application/json" -H"X-Shopify-Access-Token:THE_TOKEN_GOES_HERE" https://AUTHORIZED_SHOP.myshopify.com/admin/fulfillment_services
@@ -8233,6 +14114,23 @@ impl ShippingAndFulfillment {
             )
             .await
     }
+    /**
+     * .
+     *
+     * This function performs a `GET` to the `/admin/api/2020-07/fulfillment_services.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_get_fulfillment_service`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentservice#index-2020-07
+     */
+    pub fn deprecated_202007_get_fulfillment_service_request(
+        &self,
+    ) -> ShippingAndFulfillmentDeprecated202007GetFulfillmentServiceRequest<'_> {
+        ShippingAndFulfillmentDeprecated202007GetFulfillmentServiceRequest {
+            client: self,
+            scope: None,
+        }
+    }
     /**
     * To create a fulfillment service, you can also use a cURL request that uses that fulfillment_service.json payload:
              Copy  curl -X POST -d @fulfillment_service.json -H"Accept:application/json" -H"Content-Type:application/json" -H"X-Shopify-Access-Token:THE_TOKEN_GOES_HERE" https://AUTHORIZED_SHOP.myshopify.com/admin/fulfillment_services
@@ -8389,6 +14287,23 @@ impl ShippingAndFulfillment {
             )
             .await
     }
+    /**
+     * .
+     *
+     * This function performs a `GET` to the `/admin/api/2020-10/fulfillment_services.json` endpoint.
+     *
+     * As opposed to `get_fulfillment_service`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentservice#index-2020-10
+     */
+    pub fn get_fulfillment_service_request(
+        &self,
+    ) -> ShippingAndFulfillmentGetFulfillmentServiceRequest<'_> {
+        ShippingAndFulfillmentGetFulfillmentServiceRequest {
+            client: self,
+            scope: None,
+        }
+    }
     /**
     * To create a fulfillment service, you can also use a cURL request that uses that fulfillment_service.json payload:
              Copy  curl -X POST -d @fulfillment_service.json -H"Accept:application/json" -H"Content-Type:application/json" -H"X-Shopify-Access-Token:THE_TOKEN_GOES_HERE" https://AUTHORIZED_SHOP.myshopify.com/admin/fulfillment_services
@@ -8548,6 +14463,23 @@ impl ShippingAndFulfillment {
             )
             .await
     }
+    /**
+     * .
+     *
+     * This function performs a `GET` to the `/admin/api/2021-01/fulfillment_services.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_get_fulfillment_service`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentservice#index-2021-01
+     */
+    pub fn deprecated_202101_get_fulfillment_service_request(
+        &self,
+    ) -> ShippingAndFulfillmentDeprecated202101GetFulfillmentServiceRequest<'_> {
+        ShippingAndFulfillmentDeprecated202101GetFulfillmentServiceRequest {
+            client: self,
+            scope: None,
+        }
+    }
     /**
     * To create a fulfillment service, you can also use a cURL request that uses that fulfillment_service.json payload:
              Copy  curl -X POST -d @fulfillment_service.json -H"Accept:application/json" -H"Content-Type:application/json" -H"X-Shopify-Access-Token:THE_TOKEN_GOES_HERE" https://AUTHORIZED_SHOP.myshopify.com/admin/fulfillment_services
@@ -8707,6 +14639,23 @@ impl ShippingAndFulfillment {
             )
             .await
     }
+    /**
+     * .
+     *
+     * This function performs a `GET` to the `/admin/api/unstable/fulfillment_services.json` endpoint.
+     *
+     * As opposed to `deprecated_unstable_get_fulfillment_service`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentservice#index-unstable
+     */
+    pub fn deprecated_unstable_get_fulfillment_service_request(
+        &self,
+    ) -> ShippingAndFulfillmentDeprecatedUnstableGetFulfillmentServiceRequest<'_> {
+        ShippingAndFulfillmentDeprecatedUnstableGetFulfillmentServiceRequest {
+            client: self,
+            scope: None,
+        }
+    }
     /**
     * To create a fulfillment service, you can also use a cURL request that uses that fulfillment_service.json payload:
              Copy  curl -X POST -d @fulfillment_service.json -H"Accept:application/json" -H"Content-Type:application/json" -H"X-Shopify-Access-Token:THE_TOKEN_GOES_HERE" https://AUTHORIZED_SHOP.myshopify.com/admin/fulfillment_services
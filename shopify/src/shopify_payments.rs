@@ -1,6 +1,851 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`ShopifyPayments::deprecated_202001_get_dispute`], set with a builder.
+pub struct ShopifyPaymentsDeprecated202001GetDisputeRequest<'a> {
+    client: &'a ShopifyPayments,
+    since_id: Option<&'a str>,
+    last_id: Option<&'a str>,
+    status: Option<&'a str>,
+    initiated_at: Option<&'a str>,
+}
+
+impl<'a> ShopifyPaymentsDeprecated202001GetDisputeRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `last_id` query parameter.
+    pub fn last_id(mut self, last_id: &'a str) -> Self {
+        self.last_id = Some(last_id);
+        self
+    }
+
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: &'a str) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Sets the `initiated_at` query parameter.
+    pub fn initiated_at(mut self, initiated_at: &'a str) -> Self {
+        self.initiated_at = Some(initiated_at);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            since_id,
+            last_id,
+            status,
+            initiated_at,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = initiated_at {
+            query_args.push(("initiated_at".to_string(), v.to_string()));
+        }
+        if let Some(v) = last_id {
+            query_args.push(("last_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = status {
+            query_args.push(("status".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-01/shopify_payments/disputes.json?{}",
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`ShopifyPayments::deprecated_202004_get_dispute`], set with a builder.
+pub struct ShopifyPaymentsDeprecated202004GetDisputeRequest<'a> {
+    client: &'a ShopifyPayments,
+    since_id: Option<&'a str>,
+    last_id: Option<&'a str>,
+    status: Option<&'a str>,
+    initiated_at: Option<&'a str>,
+}
+
+impl<'a> ShopifyPaymentsDeprecated202004GetDisputeRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `last_id` query parameter.
+    pub fn last_id(mut self, last_id: &'a str) -> Self {
+        self.last_id = Some(last_id);
+        self
+    }
+
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: &'a str) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Sets the `initiated_at` query parameter.
+    pub fn initiated_at(mut self, initiated_at: &'a str) -> Self {
+        self.initiated_at = Some(initiated_at);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            since_id,
+            last_id,
+            status,
+            initiated_at,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = initiated_at {
+            query_args.push(("initiated_at".to_string(), v.to_string()));
+        }
+        if let Some(v) = last_id {
+            query_args.push(("last_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = status {
+            query_args.push(("status".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-04/shopify_payments/disputes.json?{}",
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`ShopifyPayments::deprecated_202001_get_payout`], set with a builder.
+pub struct ShopifyPaymentsDeprecated202001GetPayoutRequest<'a> {
+    client: &'a ShopifyPayments,
+    since_id: Option<&'a str>,
+    last_id: Option<&'a str>,
+    date_min: Option<&'a str>,
+    date_max: Option<&'a str>,
+    date: Option<&'a str>,
+    status: Option<&'a str>,
+}
+
+impl<'a> ShopifyPaymentsDeprecated202001GetPayoutRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `last_id` query parameter.
+    pub fn last_id(mut self, last_id: &'a str) -> Self {
+        self.last_id = Some(last_id);
+        self
+    }
+
+    /// Sets the `date_min` query parameter.
+    pub fn date_min(mut self, date_min: &'a str) -> Self {
+        self.date_min = Some(date_min);
+        self
+    }
+
+    /// Sets the `date_max` query parameter.
+    pub fn date_max(mut self, date_max: &'a str) -> Self {
+        self.date_max = Some(date_max);
+        self
+    }
+
+    /// Sets the `date` query parameter.
+    pub fn date(mut self, date: &'a str) -> Self {
+        self.date = Some(date);
+        self
+    }
+
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: &'a str) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            since_id,
+            last_id,
+            date_min,
+            date_max,
+            date,
+            status,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = date {
+            query_args.push(("date".to_string(), v.to_string()));
+        }
+        if let Some(v) = date_max {
+            query_args.push(("date_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = date_min {
+            query_args.push(("date_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = last_id {
+            query_args.push(("last_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = status {
+            query_args.push(("status".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-01/shopify_payments/payouts.json?{}",
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`ShopifyPayments::deprecated_202004_get_payout`], set with a builder.
+pub struct ShopifyPaymentsDeprecated202004GetPayoutRequest<'a> {
+    client: &'a ShopifyPayments,
+    since_id: Option<&'a str>,
+    last_id: Option<&'a str>,
+    date_min: Option<&'a str>,
+    date_max: Option<&'a str>,
+    date: Option<&'a str>,
+    status: Option<&'a str>,
+}
+
+impl<'a> ShopifyPaymentsDeprecated202004GetPayoutRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `last_id` query parameter.
+    pub fn last_id(mut self, last_id: &'a str) -> Self {
+        self.last_id = Some(last_id);
+        self
+    }
+
+    /// Sets the `date_min` query parameter.
+    pub fn date_min(mut self, date_min: &'a str) -> Self {
+        self.date_min = Some(date_min);
+        self
+    }
+
+    /// Sets the `date_max` query parameter.
+    pub fn date_max(mut self, date_max: &'a str) -> Self {
+        self.date_max = Some(date_max);
+        self
+    }
+
+    /// Sets the `date` query parameter.
+    pub fn date(mut self, date: &'a str) -> Self {
+        self.date = Some(date);
+        self
+    }
+
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: &'a str) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            since_id,
+            last_id,
+            date_min,
+            date_max,
+            date,
+            status,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = date {
+            query_args.push(("date".to_string(), v.to_string()));
+        }
+        if let Some(v) = date_max {
+            query_args.push(("date_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = date_min {
+            query_args.push(("date_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = last_id {
+            query_args.push(("last_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = status {
+            query_args.push(("status".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-04/shopify_payments/payouts.json?{}",
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`ShopifyPayments::deprecated_202007_get_payout`], set with a builder.
+pub struct ShopifyPaymentsDeprecated202007GetPayoutRequest<'a> {
+    client: &'a ShopifyPayments,
+    since_id: Option<&'a str>,
+    last_id: Option<&'a str>,
+    date_min: Option<&'a str>,
+    date_max: Option<&'a str>,
+    date: Option<&'a str>,
+    status: Option<&'a str>,
+}
+
+impl<'a> ShopifyPaymentsDeprecated202007GetPayoutRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `last_id` query parameter.
+    pub fn last_id(mut self, last_id: &'a str) -> Self {
+        self.last_id = Some(last_id);
+        self
+    }
+
+    /// Sets the `date_min` query parameter.
+    pub fn date_min(mut self, date_min: &'a str) -> Self {
+        self.date_min = Some(date_min);
+        self
+    }
+
+    /// Sets the `date_max` query parameter.
+    pub fn date_max(mut self, date_max: &'a str) -> Self {
+        self.date_max = Some(date_max);
+        self
+    }
+
+    /// Sets the `date` query parameter.
+    pub fn date(mut self, date: &'a str) -> Self {
+        self.date = Some(date);
+        self
+    }
+
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: &'a str) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            since_id,
+            last_id,
+            date_min,
+            date_max,
+            date,
+            status,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = date {
+            query_args.push(("date".to_string(), v.to_string()));
+        }
+        if let Some(v) = date_max {
+            query_args.push(("date_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = date_min {
+            query_args.push(("date_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = last_id {
+            query_args.push(("last_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = status {
+            query_args.push(("status".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-07/shopify_payments/payouts.json?{}",
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`ShopifyPayments::get_payout`], set with a builder.
+pub struct ShopifyPaymentsGetPayoutRequest<'a> {
+    client: &'a ShopifyPayments,
+    since_id: Option<&'a str>,
+    last_id: Option<&'a str>,
+    date_min: Option<&'a str>,
+    date_max: Option<&'a str>,
+    date: Option<&'a str>,
+    status: Option<&'a str>,
+}
+
+impl<'a> ShopifyPaymentsGetPayoutRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `last_id` query parameter.
+    pub fn last_id(mut self, last_id: &'a str) -> Self {
+        self.last_id = Some(last_id);
+        self
+    }
+
+    /// Sets the `date_min` query parameter.
+    pub fn date_min(mut self, date_min: &'a str) -> Self {
+        self.date_min = Some(date_min);
+        self
+    }
+
+    /// Sets the `date_max` query parameter.
+    pub fn date_max(mut self, date_max: &'a str) -> Self {
+        self.date_max = Some(date_max);
+        self
+    }
+
+    /// Sets the `date` query parameter.
+    pub fn date(mut self, date: &'a str) -> Self {
+        self.date = Some(date);
+        self
+    }
+
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: &'a str) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            since_id,
+            last_id,
+            date_min,
+            date_max,
+            date,
+            status,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = date {
+            query_args.push(("date".to_string(), v.to_string()));
+        }
+        if let Some(v) = date_max {
+            query_args.push(("date_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = date_min {
+            query_args.push(("date_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = last_id {
+            query_args.push(("last_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = status {
+            query_args.push(("status".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-10/shopify_payments/payouts.json?{}",
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`ShopifyPayments::deprecated_202101_get_payout`], set with a builder.
+pub struct ShopifyPaymentsDeprecated202101GetPayoutRequest<'a> {
+    client: &'a ShopifyPayments,
+    since_id: Option<&'a str>,
+    last_id: Option<&'a str>,
+    date_min: Option<&'a str>,
+    date_max: Option<&'a str>,
+    date: Option<&'a str>,
+    status: Option<&'a str>,
+}
+
+impl<'a> ShopifyPaymentsDeprecated202101GetPayoutRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `last_id` query parameter.
+    pub fn last_id(mut self, last_id: &'a str) -> Self {
+        self.last_id = Some(last_id);
+        self
+    }
+
+    /// Sets the `date_min` query parameter.
+    pub fn date_min(mut self, date_min: &'a str) -> Self {
+        self.date_min = Some(date_min);
+        self
+    }
+
+    /// Sets the `date_max` query parameter.
+    pub fn date_max(mut self, date_max: &'a str) -> Self {
+        self.date_max = Some(date_max);
+        self
+    }
+
+    /// Sets the `date` query parameter.
+    pub fn date(mut self, date: &'a str) -> Self {
+        self.date = Some(date);
+        self
+    }
+
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: &'a str) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            since_id,
+            last_id,
+            date_min,
+            date_max,
+            date,
+            status,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = date {
+            query_args.push(("date".to_string(), v.to_string()));
+        }
+        if let Some(v) = date_max {
+            query_args.push(("date_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = date_min {
+            query_args.push(("date_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = last_id {
+            query_args.push(("last_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = status {
+            query_args.push(("status".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2021-01/shopify_payments/payouts.json?{}",
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`ShopifyPayments::deprecated_unstable_get_payout`], set with a builder.
+pub struct ShopifyPaymentsDeprecatedUnstableGetPayoutRequest<'a> {
+    client: &'a ShopifyPayments,
+    since_id: Option<&'a str>,
+    last_id: Option<&'a str>,
+    date_min: Option<&'a str>,
+    date_max: Option<&'a str>,
+    date: Option<&'a str>,
+    status: Option<&'a str>,
+}
+
+impl<'a> ShopifyPaymentsDeprecatedUnstableGetPayoutRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `last_id` query parameter.
+    pub fn last_id(mut self, last_id: &'a str) -> Self {
+        self.last_id = Some(last_id);
+        self
+    }
+
+    /// Sets the `date_min` query parameter.
+    pub fn date_min(mut self, date_min: &'a str) -> Self {
+        self.date_min = Some(date_min);
+        self
+    }
+
+    /// Sets the `date_max` query parameter.
+    pub fn date_max(mut self, date_max: &'a str) -> Self {
+        self.date_max = Some(date_max);
+        self
+    }
+
+    /// Sets the `date` query parameter.
+    pub fn date(mut self, date: &'a str) -> Self {
+        self.date = Some(date);
+        self
+    }
+
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: &'a str) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            since_id,
+            last_id,
+            date_min,
+            date_max,
+            date,
+            status,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = date {
+            query_args.push(("date".to_string(), v.to_string()));
+        }
+        if let Some(v) = date_max {
+            query_args.push(("date_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = date_min {
+            query_args.push(("date_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = last_id {
+            query_args.push(("last_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = status {
+            query_args.push(("status".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/unstable/shopify_payments/payouts.json?{}",
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`ShopifyPayments::deprecated_202001_get_balance_transaction`], set with a builder.
+pub struct ShopifyPaymentsDeprecated202001GetBalanceTransactionRequest<'a> {
+    client: &'a ShopifyPayments,
+    since_id: Option<&'a str>,
+    last_id: Option<&'a str>,
+    test: Option<&'a str>,
+    payout_id: Option<&'a str>,
+    payout_status: Option<&'a str>,
+}
+
+impl<'a> ShopifyPaymentsDeprecated202001GetBalanceTransactionRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `last_id` query parameter.
+    pub fn last_id(mut self, last_id: &'a str) -> Self {
+        self.last_id = Some(last_id);
+        self
+    }
+
+    /// Sets the `test` query parameter.
+    pub fn test(mut self, test: &'a str) -> Self {
+        self.test = Some(test);
+        self
+    }
+
+    /// Sets the `payout_id` query parameter.
+    pub fn payout_id(mut self, payout_id: &'a str) -> Self {
+        self.payout_id = Some(payout_id);
+        self
+    }
+
+    /// Sets the `payout_status` query parameter.
+    pub fn payout_status(mut self, payout_status: &'a str) -> Self {
+        self.payout_status = Some(payout_status);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            since_id,
+            last_id,
+            test,
+            payout_id,
+            payout_status,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = last_id {
+            query_args.push(("last_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = payout_id {
+            query_args.push(("payout_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = payout_status {
+            query_args.push(("payout_status".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = test {
+            query_args.push(("test".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-01/shopify_payments/balance/transactions.json?{}",
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct ShopifyPayments {
     pub client: Client,
 }
@@ -190,6 +1035,27 @@ impl ShopifyPayments {
             )
             .await
     }
+    /**
+    * Retrieve all disputes ordered by initiated_at date and time (ISO 8601 format), with the most recent being first.
+             Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+    *
+    * This function performs a `GET` to the `/admin/api/2020-01/shopify_payments/disputes.json` endpoint.
+    *
+    * As opposed to `deprecated_202001_get_dispute`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+    *
+    * https://shopify.dev/docs/admin-api/rest/reference/shopify_payments/dispute#index-2020-01
+    */
+    pub fn deprecated_202001_get_dispute_request(
+        &self,
+    ) -> ShopifyPaymentsDeprecated202001GetDisputeRequest<'_> {
+        ShopifyPaymentsDeprecated202001GetDisputeRequest {
+            client: self,
+            since_id: None,
+            last_id: None,
+            status: None,
+            initiated_at: None,
+        }
+    }
     /**
      * Retrieves a single dispute by ID.
      *
@@ -276,6 +1142,27 @@ impl ShopifyPayments {
             .await
     }
     /**
+    * Retrieve all disputes ordered by initiated_at date and time (ISO 8601 format), with the most recent being first.
+             Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+    *
+    * This function performs a `GET` to the `/admin/api/2020-04/shopify_payments/disputes.json` endpoint.
+    *
+    * As opposed to `deprecated_202004_get_dispute`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+    *
+    * https://shopify.dev/docs/admin-api/rest/reference/shopify_payments/dispute#index-2020-04
+    */
+    pub fn deprecated_202004_get_dispute_request(
+        &self,
+    ) -> ShopifyPaymentsDeprecated202004GetDisputeRequest<'_> {
+        ShopifyPaymentsDeprecated202004GetDisputeRequest {
+            client: self,
+            since_id: None,
+            last_id: None,
+            status: None,
+            initiated_at: None,
+        }
+    }
+    /**
     * Retrieves a list of all payouts ordered by payout date, with the most recent being first.
              Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
     *
@@ -338,6 +1225,29 @@ impl ShopifyPayments {
             )
             .await
     }
+    /**
+    * Retrieves a list of all payouts ordered by payout date, with the most recent being first.
+             Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+    *
+    * This function performs a `GET` to the `/admin/api/2020-01/shopify_payments/payouts.json` endpoint.
+    *
+    * As opposed to `deprecated_202001_get_payout`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+    *
+    * https://shopify.dev/docs/admin-api/rest/reference/shopify_payments/payout#index-2020-01
+    */
+    pub fn deprecated_202001_get_payout_request(
+        &self,
+    ) -> ShopifyPaymentsDeprecated202001GetPayoutRequest<'_> {
+        ShopifyPaymentsDeprecated202001GetPayoutRequest {
+            client: self,
+            since_id: None,
+            last_id: None,
+            date_min: None,
+            date_max: None,
+            date: None,
+            status: None,
+        }
+    }
     /**
      * Retrieves a single payout by id.
      *
@@ -433,6 +1343,29 @@ impl ShopifyPayments {
             )
             .await
     }
+    /**
+    * Retrieves a list of all payouts ordered by payout date, with the most recent being first.
+             Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+    *
+    * This function performs a `GET` to the `/admin/api/2020-04/shopify_payments/payouts.json` endpoint.
+    *
+    * As opposed to `deprecated_202004_get_payout`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+    *
+    * https://shopify.dev/docs/admin-api/rest/reference/shopify_payments/payout#index-2020-04
+    */
+    pub fn deprecated_202004_get_payout_request(
+        &self,
+    ) -> ShopifyPaymentsDeprecated202004GetPayoutRequest<'_> {
+        ShopifyPaymentsDeprecated202004GetPayoutRequest {
+            client: self,
+            since_id: None,
+            last_id: None,
+            date_min: None,
+            date_max: None,
+            date: None,
+            status: None,
+        }
+    }
     /**
      * Retrieves a single payout by id.
      *
@@ -528,6 +1461,29 @@ impl ShopifyPayments {
             )
             .await
     }
+    /**
+    * Retrieves a list of all payouts ordered by payout date, with the most recent being first.
+             Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+    *
+    * This function performs a `GET` to the `/admin/api/2020-07/shopify_payments/payouts.json` endpoint.
+    *
+    * As opposed to `deprecated_202007_get_payout`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+    *
+    * https://shopify.dev/docs/admin-api/rest/reference/shopify_payments/payout#index-2020-07
+    */
+    pub fn deprecated_202007_get_payout_request(
+        &self,
+    ) -> ShopifyPaymentsDeprecated202007GetPayoutRequest<'_> {
+        ShopifyPaymentsDeprecated202007GetPayoutRequest {
+            client: self,
+            since_id: None,
+            last_id: None,
+            date_min: None,
+            date_max: None,
+            date: None,
+            status: None,
+        }
+    }
     /**
      * Retrieves a single payout by id.
      *
@@ -623,6 +1579,27 @@ impl ShopifyPayments {
             )
             .await
     }
+    /**
+    * Retrieves a list of all payouts ordered by payout date, with the most recent being first.
+             Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+    *
+    * This function performs a `GET` to the `/admin/api/2020-10/shopify_payments/payouts.json` endpoint.
+    *
+    * As opposed to `get_payout`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+    *
+    * https://shopify.dev/docs/admin-api/rest/reference/shopify_payments/payout#index-2020-10
+    */
+    pub fn get_payout_request(&self) -> ShopifyPaymentsGetPayoutRequest<'_> {
+        ShopifyPaymentsGetPayoutRequest {
+            client: self,
+            since_id: None,
+            last_id: None,
+            date_min: None,
+            date_max: None,
+            date: None,
+            status: None,
+        }
+    }
     /**
      * Retrieves a single payout by id.
      *
@@ -718,6 +1695,29 @@ impl ShopifyPayments {
             )
             .await
     }
+    /**
+    * Retrieves a list of all payouts ordered by payout date, with the most recent being first.
+             Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+    *
+    * This function performs a `GET` to the `/admin/api/2021-01/shopify_payments/payouts.json` endpoint.
+    *
+    * As opposed to `deprecated_202101_get_payout`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+    *
+    * https://shopify.dev/docs/admin-api/rest/reference/shopify_payments/payout#index-2021-01
+    */
+    pub fn deprecated_202101_get_payout_request(
+        &self,
+    ) -> ShopifyPaymentsDeprecated202101GetPayoutRequest<'_> {
+        ShopifyPaymentsDeprecated202101GetPayoutRequest {
+            client: self,
+            since_id: None,
+            last_id: None,
+            date_min: None,
+            date_max: None,
+            date: None,
+            status: None,
+        }
+    }
     /**
      * Retrieves a single payout by id.
      *
@@ -813,6 +1813,29 @@ impl ShopifyPayments {
             )
             .await
     }
+    /**
+    * Retrieves a list of all payouts ordered by payout date, with the most recent being first.
+             Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+    *
+    * This function performs a `GET` to the `/admin/api/unstable/shopify_payments/payouts.json` endpoint.
+    *
+    * As opposed to `deprecated_unstable_get_payout`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+    *
+    * https://shopify.dev/docs/admin-api/rest/reference/shopify_payments/payout#index-unstable
+    */
+    pub fn deprecated_unstable_get_payout_request(
+        &self,
+    ) -> ShopifyPaymentsDeprecatedUnstableGetPayoutRequest<'_> {
+        ShopifyPaymentsDeprecatedUnstableGetPayoutRequest {
+            client: self,
+            since_id: None,
+            last_id: None,
+            date_min: None,
+            date_max: None,
+            date: None,
+            status: None,
+        }
+    }
     /**
      * Retrieves a single payout by id.
      *
@@ -904,4 +1927,27 @@ impl ShopifyPayments {
             )
             .await
     }
+    /**
+     * Retrieves a list of all balance transactions ordered by processing
+    time, with the most recent being first.
+    Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-01/shopify_payments/balance/transactions.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_get_balance_transaction`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/shopify_payments/transaction#index-2020-01
+     */
+    pub fn deprecated_202001_get_balance_transaction_request(
+        &self,
+    ) -> ShopifyPaymentsDeprecated202001GetBalanceTransactionRequest<'_> {
+        ShopifyPaymentsDeprecated202001GetBalanceTransactionRequest {
+            client: self,
+            since_id: None,
+            last_id: None,
+            test: None,
+            payout_id: None,
+            payout_status: None,
+        }
+    }
 }
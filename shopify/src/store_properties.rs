@@ -1,6 +1,1734 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`StoreProperties::deprecated_202001_get_countrie`], set with a builder.
+pub struct StorePropertiesDeprecated202001GetCountrieRequest<'a> {
+    client: &'a StoreProperties,
+    since_id: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> StorePropertiesDeprecated202001GetCountrieRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            since_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-01/countries.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`StoreProperties::deprecated_202001_get_countries_param_country`], set with a builder.
+pub struct StorePropertiesDeprecated202001GetCountriesParamCountryRequest<'a> {
+    client: &'a StoreProperties,
+    country_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> StorePropertiesDeprecated202001GetCountriesParamCountryRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            country_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-01/countries/{}/json?{}",
+                crate::progenitor_support::encode_path(country_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`StoreProperties::deprecated_202004_get_countrie`], set with a builder.
+pub struct StorePropertiesDeprecated202004GetCountrieRequest<'a> {
+    client: &'a StoreProperties,
+    since_id: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> StorePropertiesDeprecated202004GetCountrieRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            since_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-04/countries.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`StoreProperties::deprecated_202004_get_countries_param_country`], set with a builder.
+pub struct StorePropertiesDeprecated202004GetCountriesParamCountryRequest<'a> {
+    client: &'a StoreProperties,
+    country_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> StorePropertiesDeprecated202004GetCountriesParamCountryRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            country_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-04/countries/{}/json?{}",
+                crate::progenitor_support::encode_path(country_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`StoreProperties::deprecated_202007_get_countrie`], set with a builder.
+pub struct StorePropertiesDeprecated202007GetCountrieRequest<'a> {
+    client: &'a StoreProperties,
+    since_id: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> StorePropertiesDeprecated202007GetCountrieRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            since_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-07/countries.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`StoreProperties::deprecated_202007_get_countries_param_country`], set with a builder.
+pub struct StorePropertiesDeprecated202007GetCountriesParamCountryRequest<'a> {
+    client: &'a StoreProperties,
+    country_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> StorePropertiesDeprecated202007GetCountriesParamCountryRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            country_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-07/countries/{}/json?{}",
+                crate::progenitor_support::encode_path(country_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`StoreProperties::get_countrie`], set with a builder.
+pub struct StorePropertiesGetCountrieRequest<'a> {
+    client: &'a StoreProperties,
+    since_id: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> StorePropertiesGetCountrieRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            since_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-10/countries.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`StoreProperties::get_countries_param_country`], set with a builder.
+pub struct StorePropertiesGetCountriesParamCountryRequest<'a> {
+    client: &'a StoreProperties,
+    country_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> StorePropertiesGetCountriesParamCountryRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            country_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-10/countries/{}/json?{}",
+                crate::progenitor_support::encode_path(country_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`StoreProperties::deprecated_202101_get_countrie`], set with a builder.
+pub struct StorePropertiesDeprecated202101GetCountrieRequest<'a> {
+    client: &'a StoreProperties,
+    since_id: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> StorePropertiesDeprecated202101GetCountrieRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            since_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2021-01/countries.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`StoreProperties::deprecated_202101_get_countries_param_country`], set with a builder.
+pub struct StorePropertiesDeprecated202101GetCountriesParamCountryRequest<'a> {
+    client: &'a StoreProperties,
+    country_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> StorePropertiesDeprecated202101GetCountriesParamCountryRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            country_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2021-01/countries/{}/json?{}",
+                crate::progenitor_support::encode_path(country_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`StoreProperties::deprecated_unstable_get_countrie`], set with a builder.
+pub struct StorePropertiesDeprecatedUnstableGetCountrieRequest<'a> {
+    client: &'a StoreProperties,
+    since_id: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> StorePropertiesDeprecatedUnstableGetCountrieRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            since_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/unstable/countries.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`StoreProperties::deprecated_unstable_get_countries_param_country`], set with a builder.
+pub struct StorePropertiesDeprecatedUnstableGetCountriesParamCountryRequest<'a> {
+    client: &'a StoreProperties,
+    country_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> StorePropertiesDeprecatedUnstableGetCountriesParamCountryRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            country_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/unstable/countries/{}/json?{}",
+                crate::progenitor_support::encode_path(country_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`StoreProperties::deprecated_202001_get_countries_param_country_province`], set with a builder.
+pub struct StorePropertiesDeprecated202001GetCountriesParamCountryProvinceRequest<'a> {
+    client: &'a StoreProperties,
+    country_id: &'a str,
+    since_id: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> StorePropertiesDeprecated202001GetCountriesParamCountryProvinceRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            country_id,
+            since_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-01/countries/{}/provinces.json?{}",
+                crate::progenitor_support::encode_path(country_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`StoreProperties::deprecated_202001_get_countries_param_country_provinces_province`], set with a builder.
+pub struct StorePropertiesDeprecated202001GetCountriesParamCountryProvincesProvinceRequest<'a> {
+    client: &'a StoreProperties,
+    country_id: &'a str,
+    province_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> StorePropertiesDeprecated202001GetCountriesParamCountryProvincesProvinceRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            country_id,
+            province_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-01/countries/{}/provinces/{}/json?{}",
+                crate::progenitor_support::encode_path(country_id),
+                crate::progenitor_support::encode_path(province_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`StoreProperties::deprecated_202004_get_countries_param_country_province`], set with a builder.
+pub struct StorePropertiesDeprecated202004GetCountriesParamCountryProvinceRequest<'a> {
+    client: &'a StoreProperties,
+    country_id: &'a str,
+    since_id: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> StorePropertiesDeprecated202004GetCountriesParamCountryProvinceRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            country_id,
+            since_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-04/countries/{}/provinces.json?{}",
+                crate::progenitor_support::encode_path(country_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`StoreProperties::deprecated_202004_get_countries_param_country_provinces_province`], set with a builder.
+pub struct StorePropertiesDeprecated202004GetCountriesParamCountryProvincesProvinceRequest<'a> {
+    client: &'a StoreProperties,
+    country_id: &'a str,
+    province_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> StorePropertiesDeprecated202004GetCountriesParamCountryProvincesProvinceRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            country_id,
+            province_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-04/countries/{}/provinces/{}/json?{}",
+                crate::progenitor_support::encode_path(country_id),
+                crate::progenitor_support::encode_path(province_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`StoreProperties::deprecated_202007_get_countries_param_country_province`], set with a builder.
+pub struct StorePropertiesDeprecated202007GetCountriesParamCountryProvinceRequest<'a> {
+    client: &'a StoreProperties,
+    country_id: &'a str,
+    since_id: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> StorePropertiesDeprecated202007GetCountriesParamCountryProvinceRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            country_id,
+            since_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-07/countries/{}/provinces.json?{}",
+                crate::progenitor_support::encode_path(country_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`StoreProperties::deprecated_202007_get_countries_param_country_provinces_province`], set with a builder.
+pub struct StorePropertiesDeprecated202007GetCountriesParamCountryProvincesProvinceRequest<'a> {
+    client: &'a StoreProperties,
+    country_id: &'a str,
+    province_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> StorePropertiesDeprecated202007GetCountriesParamCountryProvincesProvinceRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            country_id,
+            province_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-07/countries/{}/provinces/{}/json?{}",
+                crate::progenitor_support::encode_path(country_id),
+                crate::progenitor_support::encode_path(province_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`StoreProperties::get_countries_param_country_province`], set with a builder.
+pub struct StorePropertiesGetCountriesParamCountryProvinceRequest<'a> {
+    client: &'a StoreProperties,
+    country_id: &'a str,
+    since_id: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> StorePropertiesGetCountriesParamCountryProvinceRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            country_id,
+            since_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-10/countries/{}/provinces.json?{}",
+                crate::progenitor_support::encode_path(country_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`StoreProperties::get_countries_param_country_provinces_province`], set with a builder.
+pub struct StorePropertiesGetCountriesParamCountryProvincesProvinceRequest<'a> {
+    client: &'a StoreProperties,
+    country_id: &'a str,
+    province_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> StorePropertiesGetCountriesParamCountryProvincesProvinceRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            country_id,
+            province_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2020-10/countries/{}/provinces/{}/json?{}",
+                crate::progenitor_support::encode_path(country_id),
+                crate::progenitor_support::encode_path(province_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`StoreProperties::deprecated_202101_get_countries_param_country_province`], set with a builder.
+pub struct StorePropertiesDeprecated202101GetCountriesParamCountryProvinceRequest<'a> {
+    client: &'a StoreProperties,
+    country_id: &'a str,
+    since_id: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> StorePropertiesDeprecated202101GetCountriesParamCountryProvinceRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            country_id,
+            since_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2021-01/countries/{}/provinces.json?{}",
+                crate::progenitor_support::encode_path(country_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`StoreProperties::deprecated_202101_get_countries_param_country_provinces_province`], set with a builder.
+pub struct StorePropertiesDeprecated202101GetCountriesParamCountryProvincesProvinceRequest<'a> {
+    client: &'a StoreProperties,
+    country_id: &'a str,
+    province_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> StorePropertiesDeprecated202101GetCountriesParamCountryProvincesProvinceRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            country_id,
+            province_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/2021-01/countries/{}/provinces/{}/json?{}",
+                crate::progenitor_support::encode_path(country_id),
+                crate::progenitor_support::encode_path(province_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`StoreProperties::deprecated_unstable_get_countries_param_country_province`], set with a builder.
+pub struct StorePropertiesDeprecatedUnstableGetCountriesParamCountryProvinceRequest<'a> {
+    client: &'a StoreProperties,
+    country_id: &'a str,
+    since_id: Option<&'a str>,
+    fields: Option<&'a str>,
+}
+
+impl<'a> StorePropertiesDeprecatedUnstableGetCountriesParamCountryProvinceRequest<'a> {
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            country_id,
+            since_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/unstable/countries/{}/provinces.json?{}",
+                crate::progenitor_support::encode_path(country_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`StoreProperties::deprecated_unstable_get_countries_param_country_provinces_province`], set with a builder.
+pub struct StorePropertiesDeprecatedUnstableGetCountriesParamCountryProvincesProvinceRequest<'a> {
+    client: &'a StoreProperties,
+    country_id: &'a str,
+    province_id: &'a str,
+    fields: Option<&'a str>,
+}
+
+impl<'a> StorePropertiesDeprecatedUnstableGetCountriesParamCountryProvincesProvinceRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            country_id,
+            province_id,
+            fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin/api/unstable/countries/{}/provinces/{}/json?{}",
+                crate::progenitor_support::encode_path(country_id),
+                crate::progenitor_support::encode_path(province_id),
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`StoreProperties::deprecated_202001_get_shipping_zone`], set with a builder.
+pub struct StorePropertiesDeprecated202001GetShippingZoneRequest<'a> {
+    client: &'a StoreProperties,
+    fields: Option<&'a str>,
+}
+
+impl<'a> StorePropertiesDeprecated202001GetShippingZoneRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self { client, fields } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-01/shipping_zones.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`StoreProperties::deprecated_202004_get_shipping_zone`], set with a builder.
+pub struct StorePropertiesDeprecated202004GetShippingZoneRequest<'a> {
+    client: &'a StoreProperties,
+    fields: Option<&'a str>,
+}
+
+impl<'a> StorePropertiesDeprecated202004GetShippingZoneRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self { client, fields } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-04/shipping_zones.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`StoreProperties::deprecated_202007_get_shipping_zone`], set with a builder.
+pub struct StorePropertiesDeprecated202007GetShippingZoneRequest<'a> {
+    client: &'a StoreProperties,
+    fields: Option<&'a str>,
+}
+
+impl<'a> StorePropertiesDeprecated202007GetShippingZoneRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self { client, fields } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-07/shipping_zones.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`StoreProperties::get_shipping_zone`], set with a builder.
+pub struct StorePropertiesGetShippingZoneRequest<'a> {
+    client: &'a StoreProperties,
+    fields: Option<&'a str>,
+}
+
+impl<'a> StorePropertiesGetShippingZoneRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self { client, fields } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-10/shipping_zones.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`StoreProperties::deprecated_202101_get_shipping_zone`], set with a builder.
+pub struct StorePropertiesDeprecated202101GetShippingZoneRequest<'a> {
+    client: &'a StoreProperties,
+    fields: Option<&'a str>,
+}
+
+impl<'a> StorePropertiesDeprecated202101GetShippingZoneRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self { client, fields } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2021-01/shipping_zones.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`StoreProperties::deprecated_unstable_get_shipping_zone`], set with a builder.
+pub struct StorePropertiesDeprecatedUnstableGetShippingZoneRequest<'a> {
+    client: &'a StoreProperties,
+    fields: Option<&'a str>,
+}
+
+impl<'a> StorePropertiesDeprecatedUnstableGetShippingZoneRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self { client, fields } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/unstable/shipping_zones.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`StoreProperties::deprecated_202001_get_shop`], set with a builder.
+pub struct StorePropertiesDeprecated202001GetShopRequest<'a> {
+    client: &'a StoreProperties,
+    fields: Option<&'a str>,
+}
+
+impl<'a> StorePropertiesDeprecated202001GetShopRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self { client, fields } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/admin/api/2020-01/shop.json?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`StoreProperties::deprecated_202004_get_shop`], set with a builder.
+pub struct StorePropertiesDeprecated202004GetShopRequest<'a> {
+    client: &'a StoreProperties,
+    fields: Option<&'a str>,
+}
+
+impl<'a> StorePropertiesDeprecated202004GetShopRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self { client, fields } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/admin/api/2020-04/shop.json?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`StoreProperties::deprecated_202007_get_shop`], set with a builder.
+pub struct StorePropertiesDeprecated202007GetShopRequest<'a> {
+    client: &'a StoreProperties,
+    fields: Option<&'a str>,
+}
+
+impl<'a> StorePropertiesDeprecated202007GetShopRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self { client, fields } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/admin/api/2020-07/shop.json?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`StoreProperties::get_shop`], set with a builder.
+pub struct StorePropertiesGetShopRequest<'a> {
+    client: &'a StoreProperties,
+    fields: Option<&'a str>,
+}
+
+impl<'a> StorePropertiesGetShopRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self { client, fields } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/admin/api/2020-10/shop.json?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`StoreProperties::deprecated_202101_get_shop`], set with a builder.
+pub struct StorePropertiesDeprecated202101GetShopRequest<'a> {
+    client: &'a StoreProperties,
+    fields: Option<&'a str>,
+}
+
+impl<'a> StorePropertiesDeprecated202101GetShopRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self { client, fields } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/admin/api/2021-01/shop.json?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`StoreProperties::deprecated_unstable_get_shop`], set with a builder.
+pub struct StorePropertiesDeprecatedUnstableGetShopRequest<'a> {
+    client: &'a StoreProperties,
+    fields: Option<&'a str>,
+}
+
+impl<'a> StorePropertiesDeprecatedUnstableGetShopRequest<'a> {
+    /// Sets the `fields` query parameter.
+    pub fn fields(mut self, fields: &'a str) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self { client, fields } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = fields {
+            query_args.push(("fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/admin/api/unstable/shop.json?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct StoreProperties {
     pub client: Client,
 }
@@ -50,6 +1778,24 @@ impl StoreProperties {
             )
             .await
     }
+    /**
+     * Retrieves a list of countries.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-01/countries.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_get_countrie`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/country#index-2020-01
+     */
+    pub fn deprecated_202001_get_countrie_request(
+        &self,
+    ) -> StorePropertiesDeprecated202001GetCountrieRequest<'_> {
+        StorePropertiesDeprecated202001GetCountrieRequest {
+            client: self,
+            since_id: None,
+            fields: None,
+        }
+    }
     /**
      * Caution
       As of version 2020-10, the tax field is deprecated.
@@ -136,6 +1882,25 @@ impl StoreProperties {
             )
             .await
     }
+    /**
+     * Retrieves a specific county.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-01/countries/{country_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_get_countries_param_country`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/country#show-2020-01
+     */
+    pub fn deprecated_202001_get_countries_param_country_request<'a>(
+        &'a self,
+        country_id: &'a str,
+    ) -> StorePropertiesDeprecated202001GetCountriesParamCountryRequest<'a> {
+        StorePropertiesDeprecated202001GetCountriesParamCountryRequest {
+            client: self,
+            country_id,
+            fields: None,
+        }
+    }
     /**
      * Caution
       As of version 2020-10, the tax field is deprecated.
@@ -243,6 +2008,24 @@ impl StoreProperties {
             )
             .await
     }
+    /**
+     * Retrieves a list of countries.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-04/countries.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_get_countrie`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/country#index-2020-04
+     */
+    pub fn deprecated_202004_get_countrie_request(
+        &self,
+    ) -> StorePropertiesDeprecated202004GetCountrieRequest<'_> {
+        StorePropertiesDeprecated202004GetCountrieRequest {
+            client: self,
+            since_id: None,
+            fields: None,
+        }
+    }
     /**
      * Caution
       As of version 2020-10, the tax field is deprecated.
@@ -329,6 +2112,25 @@ impl StoreProperties {
             )
             .await
     }
+    /**
+     * Retrieves a specific county.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-04/countries/{country_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_get_countries_param_country`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/country#show-2020-04
+     */
+    pub fn deprecated_202004_get_countries_param_country_request<'a>(
+        &'a self,
+        country_id: &'a str,
+    ) -> StorePropertiesDeprecated202004GetCountriesParamCountryRequest<'a> {
+        StorePropertiesDeprecated202004GetCountriesParamCountryRequest {
+            client: self,
+            country_id,
+            fields: None,
+        }
+    }
     /**
      * Caution
       As of version 2020-10, the tax field is deprecated.
@@ -436,6 +2238,24 @@ impl StoreProperties {
             )
             .await
     }
+    /**
+     * Retrieves a list of countries.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-07/countries.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_get_countrie`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/country#index-2020-07
+     */
+    pub fn deprecated_202007_get_countrie_request(
+        &self,
+    ) -> StorePropertiesDeprecated202007GetCountrieRequest<'_> {
+        StorePropertiesDeprecated202007GetCountrieRequest {
+            client: self,
+            since_id: None,
+            fields: None,
+        }
+    }
     /**
      * Caution
       As of version 2020-10, the tax field is deprecated.
@@ -522,6 +2342,25 @@ impl StoreProperties {
             )
             .await
     }
+    /**
+     * Retrieves a specific county.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-07/countries/{country_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_get_countries_param_country`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/country#show-2020-07
+     */
+    pub fn deprecated_202007_get_countries_param_country_request<'a>(
+        &'a self,
+        country_id: &'a str,
+    ) -> StorePropertiesDeprecated202007GetCountriesParamCountryRequest<'a> {
+        StorePropertiesDeprecated202007GetCountriesParamCountryRequest {
+            client: self,
+            country_id,
+            fields: None,
+        }
+    }
     /**
      * Caution
       As of version 2020-10, the tax field is deprecated.
@@ -629,6 +2468,22 @@ impl StoreProperties {
             )
             .await
     }
+    /**
+     * Retrieves a list of countries.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-10/countries.json` endpoint.
+     *
+     * As opposed to `get_countrie`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/country#index-2020-10
+     */
+    pub fn get_countrie_request(&self) -> StorePropertiesGetCountrieRequest<'_> {
+        StorePropertiesGetCountrieRequest {
+            client: self,
+            since_id: None,
+            fields: None,
+        }
+    }
     /**
      * Caution
       As of version 2020-10, the tax field is deprecated.
@@ -715,6 +2570,25 @@ impl StoreProperties {
             )
             .await
     }
+    /**
+     * Retrieves a specific county.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-10/countries/{country_id}.json` endpoint.
+     *
+     * As opposed to `get_countries_param_country`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/country#show-2020-10
+     */
+    pub fn get_countries_param_country_request<'a>(
+        &'a self,
+        country_id: &'a str,
+    ) -> StorePropertiesGetCountriesParamCountryRequest<'a> {
+        StorePropertiesGetCountriesParamCountryRequest {
+            client: self,
+            country_id,
+            fields: None,
+        }
+    }
     /**
      * Caution
       As of version 2020-10, the tax field is deprecated.
@@ -822,6 +2696,24 @@ impl StoreProperties {
             )
             .await
     }
+    /**
+     * Retrieves a list of countries.
+     *
+     * This function performs a `GET` to the `/admin/api/2021-01/countries.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_get_countrie`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/country#index-2021-01
+     */
+    pub fn deprecated_202101_get_countrie_request(
+        &self,
+    ) -> StorePropertiesDeprecated202101GetCountrieRequest<'_> {
+        StorePropertiesDeprecated202101GetCountrieRequest {
+            client: self,
+            since_id: None,
+            fields: None,
+        }
+    }
     /**
      * Caution
       As of version 2020-10, the tax field is deprecated.
@@ -908,6 +2800,25 @@ impl StoreProperties {
             )
             .await
     }
+    /**
+     * Retrieves a specific county.
+     *
+     * This function performs a `GET` to the `/admin/api/2021-01/countries/{country_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_get_countries_param_country`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/country#show-2021-01
+     */
+    pub fn deprecated_202101_get_countries_param_country_request<'a>(
+        &'a self,
+        country_id: &'a str,
+    ) -> StorePropertiesDeprecated202101GetCountriesParamCountryRequest<'a> {
+        StorePropertiesDeprecated202101GetCountriesParamCountryRequest {
+            client: self,
+            country_id,
+            fields: None,
+        }
+    }
     /**
      * Caution
       As of version 2020-10, the tax field is deprecated.
@@ -1015,6 +2926,24 @@ impl StoreProperties {
             )
             .await
     }
+    /**
+     * Retrieves a list of countries.
+     *
+     * This function performs a `GET` to the `/admin/api/unstable/countries.json` endpoint.
+     *
+     * As opposed to `deprecated_unstable_get_countrie`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/country#index-unstable
+     */
+    pub fn deprecated_unstable_get_countrie_request(
+        &self,
+    ) -> StorePropertiesDeprecatedUnstableGetCountrieRequest<'_> {
+        StorePropertiesDeprecatedUnstableGetCountrieRequest {
+            client: self,
+            since_id: None,
+            fields: None,
+        }
+    }
     /**
      * Caution
       As of version 2020-10, the tax field is deprecated.
@@ -1103,6 +3032,25 @@ impl StoreProperties {
             )
             .await
     }
+    /**
+     * Retrieves a specific county.
+     *
+     * This function performs a `GET` to the `/admin/api/unstable/countries/{country_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_unstable_get_countries_param_country`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/country#show-unstable
+     */
+    pub fn deprecated_unstable_get_countries_param_country_request<'a>(
+        &'a self,
+        country_id: &'a str,
+    ) -> StorePropertiesDeprecatedUnstableGetCountriesParamCountryRequest<'a> {
+        StorePropertiesDeprecatedUnstableGetCountriesParamCountryRequest {
+            client: self,
+            country_id,
+            fields: None,
+        }
+    }
     /**
      * Caution
       As of version 2020-10, the tax field is deprecated.
@@ -1444,6 +3392,26 @@ impl StoreProperties {
             )
             .await
     }
+    /**
+     * Retrieves a list of provinces.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-01/countries/{country_id}/provinces.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_get_countries_param_country_province`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/province#index-2020-01
+     */
+    pub fn deprecated_202001_get_countries_param_country_province_request<'a>(
+        &'a self,
+        country_id: &'a str,
+    ) -> StorePropertiesDeprecated202001GetCountriesParamCountryProvinceRequest<'a> {
+        StorePropertiesDeprecated202001GetCountriesParamCountryProvinceRequest {
+            client: self,
+            country_id,
+            since_id: None,
+            fields: None,
+        }
+    }
     /**
      * Retrieves a count of provinces for a country.
      *
@@ -1519,6 +3487,27 @@ impl StoreProperties {
             )
             .await
     }
+    /**
+     * Retrieves a single province for a country.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-01/countries/{country_id}/provinces/{province_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_get_countries_param_country_provinces_province`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/province#show-2020-01
+     */
+    pub fn deprecated_202001_get_countries_param_country_provinces_province_request<'a>(
+        &'a self,
+        country_id: &'a str,
+        province_id: &'a str,
+    ) -> StorePropertiesDeprecated202001GetCountriesParamCountryProvincesProvinceRequest<'a> {
+        StorePropertiesDeprecated202001GetCountriesParamCountryProvincesProvinceRequest {
+            client: self,
+            country_id,
+            province_id,
+            fields: None,
+        }
+    }
     /**
      * Caution
       As of version 2020-10, the tax field is deprecated.
@@ -1603,6 +3592,26 @@ impl StoreProperties {
             )
             .await
     }
+    /**
+     * Retrieves a list of provinces.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-04/countries/{country_id}/provinces.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_get_countries_param_country_province`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/province#index-2020-04
+     */
+    pub fn deprecated_202004_get_countries_param_country_province_request<'a>(
+        &'a self,
+        country_id: &'a str,
+    ) -> StorePropertiesDeprecated202004GetCountriesParamCountryProvinceRequest<'a> {
+        StorePropertiesDeprecated202004GetCountriesParamCountryProvinceRequest {
+            client: self,
+            country_id,
+            since_id: None,
+            fields: None,
+        }
+    }
     /**
      * Retrieves a count of provinces for a country.
      *
@@ -1678,6 +3687,27 @@ impl StoreProperties {
             )
             .await
     }
+    /**
+     * Retrieves a single province for a country.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-04/countries/{country_id}/provinces/{province_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_get_countries_param_country_provinces_province`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/province#show-2020-04
+     */
+    pub fn deprecated_202004_get_countries_param_country_provinces_province_request<'a>(
+        &'a self,
+        country_id: &'a str,
+        province_id: &'a str,
+    ) -> StorePropertiesDeprecated202004GetCountriesParamCountryProvincesProvinceRequest<'a> {
+        StorePropertiesDeprecated202004GetCountriesParamCountryProvincesProvinceRequest {
+            client: self,
+            country_id,
+            province_id,
+            fields: None,
+        }
+    }
     /**
      * Caution
       As of version 2020-10, the tax field is deprecated.
@@ -1762,6 +3792,26 @@ impl StoreProperties {
             )
             .await
     }
+    /**
+     * Retrieves a list of provinces.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-07/countries/{country_id}/provinces.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_get_countries_param_country_province`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/province#index-2020-07
+     */
+    pub fn deprecated_202007_get_countries_param_country_province_request<'a>(
+        &'a self,
+        country_id: &'a str,
+    ) -> StorePropertiesDeprecated202007GetCountriesParamCountryProvinceRequest<'a> {
+        StorePropertiesDeprecated202007GetCountriesParamCountryProvinceRequest {
+            client: self,
+            country_id,
+            since_id: None,
+            fields: None,
+        }
+    }
     /**
      * Retrieves a count of provinces for a country.
      *
@@ -1837,6 +3887,27 @@ impl StoreProperties {
             )
             .await
     }
+    /**
+     * Retrieves a single province for a country.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-07/countries/{country_id}/provinces/{province_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_get_countries_param_country_provinces_province`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/province#show-2020-07
+     */
+    pub fn deprecated_202007_get_countries_param_country_provinces_province_request<'a>(
+        &'a self,
+        country_id: &'a str,
+        province_id: &'a str,
+    ) -> StorePropertiesDeprecated202007GetCountriesParamCountryProvincesProvinceRequest<'a> {
+        StorePropertiesDeprecated202007GetCountriesParamCountryProvincesProvinceRequest {
+            client: self,
+            country_id,
+            province_id,
+            fields: None,
+        }
+    }
     /**
      * Caution
       As of version 2020-10, the tax field is deprecated.
@@ -1921,6 +3992,26 @@ impl StoreProperties {
             )
             .await
     }
+    /**
+     * Retrieves a list of provinces.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-10/countries/{country_id}/provinces.json` endpoint.
+     *
+     * As opposed to `get_countries_param_country_province`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/province#index-2020-10
+     */
+    pub fn get_countries_param_country_province_request<'a>(
+        &'a self,
+        country_id: &'a str,
+    ) -> StorePropertiesGetCountriesParamCountryProvinceRequest<'a> {
+        StorePropertiesGetCountriesParamCountryProvinceRequest {
+            client: self,
+            country_id,
+            since_id: None,
+            fields: None,
+        }
+    }
     /**
      * Retrieves a count of provinces for a country.
      *
@@ -1996,6 +4087,27 @@ impl StoreProperties {
             )
             .await
     }
+    /**
+     * Retrieves a single province for a country.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-10/countries/{country_id}/provinces/{province_id}.json` endpoint.
+     *
+     * As opposed to `get_countries_param_country_provinces_province`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/province#show-2020-10
+     */
+    pub fn get_countries_param_country_provinces_province_request<'a>(
+        &'a self,
+        country_id: &'a str,
+        province_id: &'a str,
+    ) -> StorePropertiesGetCountriesParamCountryProvincesProvinceRequest<'a> {
+        StorePropertiesGetCountriesParamCountryProvincesProvinceRequest {
+            client: self,
+            country_id,
+            province_id,
+            fields: None,
+        }
+    }
     /**
      * Caution
       As of version 2020-10, the tax field is deprecated.
@@ -2080,6 +4192,26 @@ impl StoreProperties {
             )
             .await
     }
+    /**
+     * Retrieves a list of provinces.
+     *
+     * This function performs a `GET` to the `/admin/api/2021-01/countries/{country_id}/provinces.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_get_countries_param_country_province`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/province#index-2021-01
+     */
+    pub fn deprecated_202101_get_countries_param_country_province_request<'a>(
+        &'a self,
+        country_id: &'a str,
+    ) -> StorePropertiesDeprecated202101GetCountriesParamCountryProvinceRequest<'a> {
+        StorePropertiesDeprecated202101GetCountriesParamCountryProvinceRequest {
+            client: self,
+            country_id,
+            since_id: None,
+            fields: None,
+        }
+    }
     /**
      * Retrieves a count of provinces for a country.
      *
@@ -2155,6 +4287,27 @@ impl StoreProperties {
             )
             .await
     }
+    /**
+     * Retrieves a single province for a country.
+     *
+     * This function performs a `GET` to the `/admin/api/2021-01/countries/{country_id}/provinces/{province_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_get_countries_param_country_provinces_province`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/province#show-2021-01
+     */
+    pub fn deprecated_202101_get_countries_param_country_provinces_province_request<'a>(
+        &'a self,
+        country_id: &'a str,
+        province_id: &'a str,
+    ) -> StorePropertiesDeprecated202101GetCountriesParamCountryProvincesProvinceRequest<'a> {
+        StorePropertiesDeprecated202101GetCountriesParamCountryProvincesProvinceRequest {
+            client: self,
+            country_id,
+            province_id,
+            fields: None,
+        }
+    }
     /**
      * Caution
       As of version 2020-10, the tax field is deprecated.
@@ -2239,6 +4392,26 @@ impl StoreProperties {
             )
             .await
     }
+    /**
+     * Retrieves a list of provinces.
+     *
+     * This function performs a `GET` to the `/admin/api/unstable/countries/{country_id}/provinces.json` endpoint.
+     *
+     * As opposed to `deprecated_unstable_get_countries_param_country_province`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/province#index-unstable
+     */
+    pub fn deprecated_unstable_get_countries_param_country_province_request<'a>(
+        &'a self,
+        country_id: &'a str,
+    ) -> StorePropertiesDeprecatedUnstableGetCountriesParamCountryProvinceRequest<'a> {
+        StorePropertiesDeprecatedUnstableGetCountriesParamCountryProvinceRequest {
+            client: self,
+            country_id,
+            since_id: None,
+            fields: None,
+        }
+    }
     /**
      * Retrieves a count of provinces for a country.
      *
@@ -2314,6 +4487,27 @@ impl StoreProperties {
             )
             .await
     }
+    /**
+     * Retrieves a single province for a country.
+     *
+     * This function performs a `GET` to the `/admin/api/unstable/countries/{country_id}/provinces/{province_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_unstable_get_countries_param_country_provinces_province`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/province#show-unstable
+     */
+    pub fn deprecated_unstable_get_countries_param_country_provinces_province_request<'a>(
+        &'a self,
+        country_id: &'a str,
+        province_id: &'a str,
+    ) -> StorePropertiesDeprecatedUnstableGetCountriesParamCountryProvincesProvinceRequest<'a> {
+        StorePropertiesDeprecatedUnstableGetCountriesParamCountryProvincesProvinceRequest {
+            client: self,
+            country_id,
+            province_id,
+            fields: None,
+        }
+    }
     /**
      * Caution
       As of version 2020-10, the tax field is deprecated.
@@ -2387,6 +4581,23 @@ impl StoreProperties {
             )
             .await
     }
+    /**
+     * Get a list of all shipping zones.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-01/shipping_zones.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_get_shipping_zone`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/shippingzone#index-2020-01
+     */
+    pub fn deprecated_202001_get_shipping_zone_request(
+        &self,
+    ) -> StorePropertiesDeprecated202001GetShippingZoneRequest<'_> {
+        StorePropertiesDeprecated202001GetShippingZoneRequest {
+            client: self,
+            fields: None,
+        }
+    }
     /**
      * Get a list of all shipping zones.
      *
@@ -2421,6 +4632,23 @@ impl StoreProperties {
             )
             .await
     }
+    /**
+     * Get a list of all shipping zones.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-04/shipping_zones.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_get_shipping_zone`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/shippingzone#index-2020-04
+     */
+    pub fn deprecated_202004_get_shipping_zone_request(
+        &self,
+    ) -> StorePropertiesDeprecated202004GetShippingZoneRequest<'_> {
+        StorePropertiesDeprecated202004GetShippingZoneRequest {
+            client: self,
+            fields: None,
+        }
+    }
     /**
      * Get a list of all shipping zones.
      *
@@ -2455,6 +4683,23 @@ impl StoreProperties {
             )
             .await
     }
+    /**
+     * Get a list of all shipping zones.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-07/shipping_zones.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_get_shipping_zone`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/shippingzone#index-2020-07
+     */
+    pub fn deprecated_202007_get_shipping_zone_request(
+        &self,
+    ) -> StorePropertiesDeprecated202007GetShippingZoneRequest<'_> {
+        StorePropertiesDeprecated202007GetShippingZoneRequest {
+            client: self,
+            fields: None,
+        }
+    }
     /**
      * Get a list of all shipping zones.
      *
@@ -2486,6 +4731,21 @@ impl StoreProperties {
             )
             .await
     }
+    /**
+     * Get a list of all shipping zones.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-10/shipping_zones.json` endpoint.
+     *
+     * As opposed to `get_shipping_zone`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/shippingzone#index-2020-10
+     */
+    pub fn get_shipping_zone_request(&self) -> StorePropertiesGetShippingZoneRequest<'_> {
+        StorePropertiesGetShippingZoneRequest {
+            client: self,
+            fields: None,
+        }
+    }
     /**
      * Get a list of all shipping zones.
      *
@@ -2520,6 +4780,23 @@ impl StoreProperties {
             )
             .await
     }
+    /**
+     * Get a list of all shipping zones.
+     *
+     * This function performs a `GET` to the `/admin/api/2021-01/shipping_zones.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_get_shipping_zone`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/shippingzone#index-2021-01
+     */
+    pub fn deprecated_202101_get_shipping_zone_request(
+        &self,
+    ) -> StorePropertiesDeprecated202101GetShippingZoneRequest<'_> {
+        StorePropertiesDeprecated202101GetShippingZoneRequest {
+            client: self,
+            fields: None,
+        }
+    }
     /**
      * Get a list of all shipping zones.
      *
@@ -2554,6 +4831,23 @@ impl StoreProperties {
             )
             .await
     }
+    /**
+     * Get a list of all shipping zones.
+     *
+     * This function performs a `GET` to the `/admin/api/unstable/shipping_zones.json` endpoint.
+     *
+     * As opposed to `deprecated_unstable_get_shipping_zone`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/shippingzone#index-unstable
+     */
+    pub fn deprecated_unstable_get_shipping_zone_request(
+        &self,
+    ) -> StorePropertiesDeprecatedUnstableGetShippingZoneRequest<'_> {
+        StorePropertiesDeprecatedUnstableGetShippingZoneRequest {
+            client: self,
+            fields: None,
+        }
+    }
     /**
      * Retrieves the shop's configuration.
      *
@@ -2587,6 +4881,23 @@ impl StoreProperties {
             )
             .await
     }
+    /**
+     * Retrieves the shop's configuration.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-01/shop.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_get_shop`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/shop#show-2020-01
+     */
+    pub fn deprecated_202001_get_shop_request(
+        &self,
+    ) -> StorePropertiesDeprecated202001GetShopRequest<'_> {
+        StorePropertiesDeprecated202001GetShopRequest {
+            client: self,
+            fields: None,
+        }
+    }
     /**
      * Retrieves the shop's configuration.
      *
@@ -2620,6 +4931,23 @@ impl StoreProperties {
             )
             .await
     }
+    /**
+     * Retrieves the shop's configuration.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-04/shop.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_get_shop`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/shop#show-2020-04
+     */
+    pub fn deprecated_202004_get_shop_request(
+        &self,
+    ) -> StorePropertiesDeprecated202004GetShopRequest<'_> {
+        StorePropertiesDeprecated202004GetShopRequest {
+            client: self,
+            fields: None,
+        }
+    }
     /**
      * Retrieves the shop's configuration.
      *
@@ -2653,6 +4981,23 @@ impl StoreProperties {
             )
             .await
     }
+    /**
+     * Retrieves the shop's configuration.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-07/shop.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_get_shop`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/shop#show-2020-07
+     */
+    pub fn deprecated_202007_get_shop_request(
+        &self,
+    ) -> StorePropertiesDeprecated202007GetShopRequest<'_> {
+        StorePropertiesDeprecated202007GetShopRequest {
+            client: self,
+            fields: None,
+        }
+    }
     /**
      * Retrieves the shop's configuration.
      *
@@ -2683,6 +5028,21 @@ impl StoreProperties {
             )
             .await
     }
+    /**
+     * Retrieves the shop's configuration.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-10/shop.json` endpoint.
+     *
+     * As opposed to `get_shop`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/shop#show-2020-10
+     */
+    pub fn get_shop_request(&self) -> StorePropertiesGetShopRequest<'_> {
+        StorePropertiesGetShopRequest {
+            client: self,
+            fields: None,
+        }
+    }
     /**
      * Retrieves the shop's configuration.
      *
@@ -2716,6 +5076,23 @@ impl StoreProperties {
             )
             .await
     }
+    /**
+     * Retrieves the shop's configuration.
+     *
+     * This function performs a `GET` to the `/admin/api/2021-01/shop.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_get_shop`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/shop#show-2021-01
+     */
+    pub fn deprecated_202101_get_shop_request(
+        &self,
+    ) -> StorePropertiesDeprecated202101GetShopRequest<'_> {
+        StorePropertiesDeprecated202101GetShopRequest {
+            client: self,
+            fields: None,
+        }
+    }
     /**
      * Retrieves the shop's configuration.
      *
@@ -2749,4 +5126,21 @@ impl StoreProperties {
             )
             .await
     }
+    /**
+     * Retrieves the shop's configuration.
+     *
+     * This function performs a `GET` to the `/admin/api/unstable/shop.json` endpoint.
+     *
+     * As opposed to `deprecated_unstable_get_shop`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/store-properties/shop#show-unstable
+     */
+    pub fn deprecated_unstable_get_shop_request(
+        &self,
+    ) -> StorePropertiesDeprecatedUnstableGetShopRequest<'_> {
+        StorePropertiesDeprecatedUnstableGetShopRequest {
+            client: self,
+            fields: None,
+        }
+    }
 }
@@ -1,6 +1,588 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Tendertransaction::deprecated_202001_get_tender_transaction`], set with a builder.
+pub struct TendertransactionDeprecated202001GetTenderTransactionRequest<'a> {
+    client: &'a Tendertransaction,
+    limit: Option<&'a str>,
+    since_id: Option<&'a str>,
+    processed_at_min: Option<&'a str>,
+    processed_at_max: Option<&'a str>,
+    processed_at: Option<&'a str>,
+    order: Option<&'a str>,
+}
+
+impl<'a> TendertransactionDeprecated202001GetTenderTransactionRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `processed_at_min` query parameter.
+    pub fn processed_at_min(mut self, processed_at_min: &'a str) -> Self {
+        self.processed_at_min = Some(processed_at_min);
+        self
+    }
+
+    /// Sets the `processed_at_max` query parameter.
+    pub fn processed_at_max(mut self, processed_at_max: &'a str) -> Self {
+        self.processed_at_max = Some(processed_at_max);
+        self
+    }
+
+    /// Sets the `processed_at` query parameter.
+    pub fn processed_at(mut self, processed_at: &'a str) -> Self {
+        self.processed_at = Some(processed_at);
+        self
+    }
+
+    /// Sets the `order` query parameter.
+    pub fn order(mut self, order: &'a str) -> Self {
+        self.order = Some(order);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            limit,
+            since_id,
+            processed_at_min,
+            processed_at_max,
+            processed_at,
+            order,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = order {
+            query_args.push(("order".to_string(), v.to_string()));
+        }
+        if let Some(v) = processed_at {
+            query_args.push(("processed_at".to_string(), v.to_string()));
+        }
+        if let Some(v) = processed_at_max {
+            query_args.push(("processed_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = processed_at_min {
+            query_args.push(("processed_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-01/tender_transactions.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Tendertransaction::deprecated_202004_get_tender_transaction`], set with a builder.
+pub struct TendertransactionDeprecated202004GetTenderTransactionRequest<'a> {
+    client: &'a Tendertransaction,
+    limit: Option<&'a str>,
+    since_id: Option<&'a str>,
+    processed_at_min: Option<&'a str>,
+    processed_at_max: Option<&'a str>,
+    processed_at: Option<&'a str>,
+    order: Option<&'a str>,
+}
+
+impl<'a> TendertransactionDeprecated202004GetTenderTransactionRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `processed_at_min` query parameter.
+    pub fn processed_at_min(mut self, processed_at_min: &'a str) -> Self {
+        self.processed_at_min = Some(processed_at_min);
+        self
+    }
+
+    /// Sets the `processed_at_max` query parameter.
+    pub fn processed_at_max(mut self, processed_at_max: &'a str) -> Self {
+        self.processed_at_max = Some(processed_at_max);
+        self
+    }
+
+    /// Sets the `processed_at` query parameter.
+    pub fn processed_at(mut self, processed_at: &'a str) -> Self {
+        self.processed_at = Some(processed_at);
+        self
+    }
+
+    /// Sets the `order` query parameter.
+    pub fn order(mut self, order: &'a str) -> Self {
+        self.order = Some(order);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            limit,
+            since_id,
+            processed_at_min,
+            processed_at_max,
+            processed_at,
+            order,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = order {
+            query_args.push(("order".to_string(), v.to_string()));
+        }
+        if let Some(v) = processed_at {
+            query_args.push(("processed_at".to_string(), v.to_string()));
+        }
+        if let Some(v) = processed_at_max {
+            query_args.push(("processed_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = processed_at_min {
+            query_args.push(("processed_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-04/tender_transactions.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Tendertransaction::deprecated_202007_get_tender_transaction`], set with a builder.
+pub struct TendertransactionDeprecated202007GetTenderTransactionRequest<'a> {
+    client: &'a Tendertransaction,
+    limit: Option<&'a str>,
+    since_id: Option<&'a str>,
+    processed_at_min: Option<&'a str>,
+    processed_at_max: Option<&'a str>,
+    processed_at: Option<&'a str>,
+    order: Option<&'a str>,
+}
+
+impl<'a> TendertransactionDeprecated202007GetTenderTransactionRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `processed_at_min` query parameter.
+    pub fn processed_at_min(mut self, processed_at_min: &'a str) -> Self {
+        self.processed_at_min = Some(processed_at_min);
+        self
+    }
+
+    /// Sets the `processed_at_max` query parameter.
+    pub fn processed_at_max(mut self, processed_at_max: &'a str) -> Self {
+        self.processed_at_max = Some(processed_at_max);
+        self
+    }
+
+    /// Sets the `processed_at` query parameter.
+    pub fn processed_at(mut self, processed_at: &'a str) -> Self {
+        self.processed_at = Some(processed_at);
+        self
+    }
+
+    /// Sets the `order` query parameter.
+    pub fn order(mut self, order: &'a str) -> Self {
+        self.order = Some(order);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            limit,
+            since_id,
+            processed_at_min,
+            processed_at_max,
+            processed_at,
+            order,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = order {
+            query_args.push(("order".to_string(), v.to_string()));
+        }
+        if let Some(v) = processed_at {
+            query_args.push(("processed_at".to_string(), v.to_string()));
+        }
+        if let Some(v) = processed_at_max {
+            query_args.push(("processed_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = processed_at_min {
+            query_args.push(("processed_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-07/tender_transactions.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Tendertransaction::get_tender_transaction`], set with a builder.
+pub struct TendertransactionGetTenderTransactionRequest<'a> {
+    client: &'a Tendertransaction,
+    limit: Option<&'a str>,
+    since_id: Option<&'a str>,
+    processed_at_min: Option<&'a str>,
+    processed_at_max: Option<&'a str>,
+    processed_at: Option<&'a str>,
+    order: Option<&'a str>,
+}
+
+impl<'a> TendertransactionGetTenderTransactionRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `processed_at_min` query parameter.
+    pub fn processed_at_min(mut self, processed_at_min: &'a str) -> Self {
+        self.processed_at_min = Some(processed_at_min);
+        self
+    }
+
+    /// Sets the `processed_at_max` query parameter.
+    pub fn processed_at_max(mut self, processed_at_max: &'a str) -> Self {
+        self.processed_at_max = Some(processed_at_max);
+        self
+    }
+
+    /// Sets the `processed_at` query parameter.
+    pub fn processed_at(mut self, processed_at: &'a str) -> Self {
+        self.processed_at = Some(processed_at);
+        self
+    }
+
+    /// Sets the `order` query parameter.
+    pub fn order(mut self, order: &'a str) -> Self {
+        self.order = Some(order);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            limit,
+            since_id,
+            processed_at_min,
+            processed_at_max,
+            processed_at,
+            order,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = order {
+            query_args.push(("order".to_string(), v.to_string()));
+        }
+        if let Some(v) = processed_at {
+            query_args.push(("processed_at".to_string(), v.to_string()));
+        }
+        if let Some(v) = processed_at_max {
+            query_args.push(("processed_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = processed_at_min {
+            query_args.push(("processed_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2020-10/tender_transactions.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Tendertransaction::deprecated_202101_get_tender_transaction`], set with a builder.
+pub struct TendertransactionDeprecated202101GetTenderTransactionRequest<'a> {
+    client: &'a Tendertransaction,
+    limit: Option<&'a str>,
+    since_id: Option<&'a str>,
+    processed_at_min: Option<&'a str>,
+    processed_at_max: Option<&'a str>,
+    processed_at: Option<&'a str>,
+    order: Option<&'a str>,
+}
+
+impl<'a> TendertransactionDeprecated202101GetTenderTransactionRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `processed_at_min` query parameter.
+    pub fn processed_at_min(mut self, processed_at_min: &'a str) -> Self {
+        self.processed_at_min = Some(processed_at_min);
+        self
+    }
+
+    /// Sets the `processed_at_max` query parameter.
+    pub fn processed_at_max(mut self, processed_at_max: &'a str) -> Self {
+        self.processed_at_max = Some(processed_at_max);
+        self
+    }
+
+    /// Sets the `processed_at` query parameter.
+    pub fn processed_at(mut self, processed_at: &'a str) -> Self {
+        self.processed_at = Some(processed_at);
+        self
+    }
+
+    /// Sets the `order` query parameter.
+    pub fn order(mut self, order: &'a str) -> Self {
+        self.order = Some(order);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            limit,
+            since_id,
+            processed_at_min,
+            processed_at_max,
+            processed_at,
+            order,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = order {
+            query_args.push(("order".to_string(), v.to_string()));
+        }
+        if let Some(v) = processed_at {
+            query_args.push(("processed_at".to_string(), v.to_string()));
+        }
+        if let Some(v) = processed_at_max {
+            query_args.push(("processed_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = processed_at_min {
+            query_args.push(("processed_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/2021-01/tender_transactions.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Tendertransaction::deprecated_unstable_get_tender_transaction`], set with a builder.
+pub struct TendertransactionDeprecatedUnstableGetTenderTransactionRequest<'a> {
+    client: &'a Tendertransaction,
+    limit: Option<&'a str>,
+    since_id: Option<&'a str>,
+    processed_at_min: Option<&'a str>,
+    processed_at_max: Option<&'a str>,
+    processed_at: Option<&'a str>,
+    order: Option<&'a str>,
+}
+
+impl<'a> TendertransactionDeprecatedUnstableGetTenderTransactionRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: &'a str) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `since_id` query parameter.
+    pub fn since_id(mut self, since_id: &'a str) -> Self {
+        self.since_id = Some(since_id);
+        self
+    }
+
+    /// Sets the `processed_at_min` query parameter.
+    pub fn processed_at_min(mut self, processed_at_min: &'a str) -> Self {
+        self.processed_at_min = Some(processed_at_min);
+        self
+    }
+
+    /// Sets the `processed_at_max` query parameter.
+    pub fn processed_at_max(mut self, processed_at_max: &'a str) -> Self {
+        self.processed_at_max = Some(processed_at_max);
+        self
+    }
+
+    /// Sets the `processed_at` query parameter.
+    pub fn processed_at(mut self, processed_at: &'a str) -> Self {
+        self.processed_at = Some(processed_at);
+        self
+    }
+
+    /// Sets the `order` query parameter.
+    pub fn order(mut self, order: &'a str) -> Self {
+        self.order = Some(order);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            limit,
+            since_id,
+            processed_at_min,
+            processed_at_max,
+            processed_at,
+            order,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = order {
+            query_args.push(("order".to_string(), v.to_string()));
+        }
+        if let Some(v) = processed_at {
+            query_args.push(("processed_at".to_string(), v.to_string()));
+        }
+        if let Some(v) = processed_at_max {
+            query_args.push(("processed_at_max".to_string(), v.to_string()));
+        }
+        if let Some(v) = processed_at_min {
+            query_args.push(("processed_at_min".to_string(), v.to_string()));
+        }
+        if let Some(v) = since_id {
+            query_args.push(("since_id".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin/api/unstable/tender_transactions.json?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct Tendertransaction {
     pub client: Client,
 }
@@ -71,6 +653,28 @@ impl Tendertransaction {
             )
             .await
     }
+    /**
+     * Retrieves a list of tender transactions. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-01/tender_transactions.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_get_tender_transaction`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/tendertransaction#index-2020-01
+     */
+    pub fn deprecated_202001_get_tender_transaction_request(
+        &self,
+    ) -> TendertransactionDeprecated202001GetTenderTransactionRequest<'_> {
+        TendertransactionDeprecated202001GetTenderTransactionRequest {
+            client: self,
+            limit: None,
+            since_id: None,
+            processed_at_min: None,
+            processed_at_max: None,
+            processed_at: None,
+            order: None,
+        }
+    }
     /**
      * Retrieves a list of tender transactions. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -131,6 +735,28 @@ impl Tendertransaction {
             )
             .await
     }
+    /**
+     * Retrieves a list of tender transactions. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-04/tender_transactions.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_get_tender_transaction`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/tendertransaction#index-2020-04
+     */
+    pub fn deprecated_202004_get_tender_transaction_request(
+        &self,
+    ) -> TendertransactionDeprecated202004GetTenderTransactionRequest<'_> {
+        TendertransactionDeprecated202004GetTenderTransactionRequest {
+            client: self,
+            limit: None,
+            since_id: None,
+            processed_at_min: None,
+            processed_at_max: None,
+            processed_at: None,
+            order: None,
+        }
+    }
     /**
      * Retrieves a list of tender transactions. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -191,6 +817,28 @@ impl Tendertransaction {
             )
             .await
     }
+    /**
+     * Retrieves a list of tender transactions. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-07/tender_transactions.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_get_tender_transaction`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/tendertransaction#index-2020-07
+     */
+    pub fn deprecated_202007_get_tender_transaction_request(
+        &self,
+    ) -> TendertransactionDeprecated202007GetTenderTransactionRequest<'_> {
+        TendertransactionDeprecated202007GetTenderTransactionRequest {
+            client: self,
+            limit: None,
+            since_id: None,
+            processed_at_min: None,
+            processed_at_max: None,
+            processed_at: None,
+            order: None,
+        }
+    }
     /**
      * Retrieves a list of tender transactions. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -251,6 +899,28 @@ impl Tendertransaction {
             )
             .await
     }
+    /**
+     * Retrieves a list of tender transactions. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-10/tender_transactions.json` endpoint.
+     *
+     * As opposed to `get_tender_transaction`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/tendertransaction#index-2020-10
+     */
+    pub fn get_tender_transaction_request(
+        &self,
+    ) -> TendertransactionGetTenderTransactionRequest<'_> {
+        TendertransactionGetTenderTransactionRequest {
+            client: self,
+            limit: None,
+            since_id: None,
+            processed_at_min: None,
+            processed_at_max: None,
+            processed_at: None,
+            order: None,
+        }
+    }
     /**
      * Retrieves a list of tender transactions. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -311,6 +981,28 @@ impl Tendertransaction {
             )
             .await
     }
+    /**
+     * Retrieves a list of tender transactions. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/2021-01/tender_transactions.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_get_tender_transaction`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/tendertransaction#index-2021-01
+     */
+    pub fn deprecated_202101_get_tender_transaction_request(
+        &self,
+    ) -> TendertransactionDeprecated202101GetTenderTransactionRequest<'_> {
+        TendertransactionDeprecated202101GetTenderTransactionRequest {
+            client: self,
+            limit: None,
+            since_id: None,
+            processed_at_min: None,
+            processed_at_max: None,
+            processed_at: None,
+            order: None,
+        }
+    }
     /**
      * Retrieves a list of tender transactions. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -371,4 +1063,26 @@ impl Tendertransaction {
             )
             .await
     }
+    /**
+     * Retrieves a list of tender transactions. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/unstable/tender_transactions.json` endpoint.
+     *
+     * As opposed to `deprecated_unstable_get_tender_transaction`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/tendertransaction#index-unstable
+     */
+    pub fn deprecated_unstable_get_tender_transaction_request(
+        &self,
+    ) -> TendertransactionDeprecatedUnstableGetTenderTransactionRequest<'_> {
+        TendertransactionDeprecatedUnstableGetTenderTransactionRequest {
+            client: self,
+            limit: None,
+            since_id: None,
+            processed_at_min: None,
+            processed_at_max: None,
+            processed_at: None,
+            order: None,
+        }
+    }
 }
@@ -1 +1,228 @@
+use std::mem;
 
+use wiremock::{
+    matchers::{method, path, query_param, query_param_is_missing},
+    Mock, MockServer, ResponseTemplate,
+};
+
+use shopify::{
+    utils,
+    webhooks::{self, Webhook, WebhookError, WebhookEvent},
+    Client, ClientError, HeaderMap, RateLimitInfo,
+};
+
+fn next_page_info(headers: &HeaderMap) -> Option<String> {
+    let link = headers.get("link")?.to_str().ok()?;
+    let next = utils::next_link(&parse_link_header::parse(link).ok()?)?;
+    let url = reqwest::Url::parse(&next.0).ok()?;
+    url.query_pairs()
+        .find(|(key, _)| key == "page_info")
+        .map(|(_, value)| value.to_string())
+}
+
+#[tokio::test]
+async fn test_page_info_cursor_comes_from_link_header() {
+    let server = MockServer::start().await;
+    let users = format!("{}/admin/api/2020-04/users.json", server.uri());
+
+    Mock::given(method("GET"))
+        .and(path("/admin/api/2020-04/users.json"))
+        .and(query_param("page_info", "def"))
+        .respond_with(ResponseTemplate::new(200).insert_header(
+            "link",
+            format!("<{users}?limit=1&page_info=abc>; rel=\"previous\"").as_str(),
+        ))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/admin/api/2020-04/users.json"))
+        .and(query_param_is_missing("page_info"))
+        .respond_with(ResponseTemplate::new(200).insert_header(
+            "link",
+            format!(
+                "<{users}?limit=1&page_info=abc>; rel=\"previous\", <{users}?limit=1&page_info=def>; rel=\"next\""
+            )
+            .as_str(),
+        ))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut client = Client::new(
+        "client-id",
+        "hush",
+        "https://example.com/callback",
+        "token",
+        "",
+    );
+    client.with_host_override(server.uri());
+    let plus = client.plus();
+
+    let first = plus.deprecated_202004_get_user("1", "").await.unwrap();
+    let page_info = next_page_info(&first.headers).unwrap();
+    assert_eq!(page_info, "def");
+
+    let last = plus
+        .deprecated_202004_get_user("1", &page_info)
+        .await
+        .unwrap();
+    assert_eq!(next_page_info(&last.headers), None);
+
+    mem::drop(server)
+}
+
+#[test]
+fn test_rate_limit_from_call_limit_header() {
+    let mut headers = HeaderMap::new();
+    assert_eq!(RateLimitInfo::from_headers(&headers), None);
+
+    headers.insert("x-shopify-shop-api-call-limit", "32/40".parse().unwrap());
+    let rate_limit = RateLimitInfo::from_headers(&headers).unwrap();
+    assert_eq!(rate_limit.limit, Some(40));
+    assert_eq!(rate_limit.remaining, Some(8));
+    assert_eq!(rate_limit.wait(), None);
+
+    headers.insert("x-shopify-shop-api-call-limit", "40/40".parse().unwrap());
+    let rate_limit = RateLimitInfo::from_headers(&headers).unwrap();
+    assert_eq!(rate_limit.remaining, Some(0));
+    assert!(rate_limit.wait().unwrap() <= std::time::Duration::from_secs(1));
+}
+
+#[tokio::test]
+async fn test_failed_call_at_full_bucket_keeps_api_error() {
+    let server = MockServer::start().await;
+
+    // The call used up the bucket, but failed for another reason than the limit.
+    Mock::given(method("GET"))
+        .and(path("/admin/api/2020-04/users.json"))
+        .respond_with(
+            ResponseTemplate::new(404)
+                .insert_header("x-shopify-shop-api-call-limit", "40/40")
+                .set_body_json(serde_json::json!({ "errors": "Not Found" })),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut client = Client::new(
+        "client-id",
+        "hush",
+        "https://example.com/callback",
+        "token",
+        "",
+    );
+    client.with_host_override(server.uri());
+
+    match client.plus().deprecated_202004_get_user("1", "").await {
+        Err(ClientError::ApiError { status, error, .. }) => {
+            assert_eq!(status, http::StatusCode::NOT_FOUND);
+            assert_eq!(error.message, "Not Found");
+        }
+        result => unreachable!("Expected an API error, got {:?}", result),
+    }
+
+    mem::drop(server)
+}
+
+fn webhook_headers(webhook: &Webhook, topic: &str, payload: &[u8]) -> Vec<(String, String)> {
+    vec![
+        ("X-Shopify-Topic".to_string(), topic.to_string()),
+        ("x-shopify-hmac-sha256".to_string(), webhook.sign(payload)),
+        (
+            "X-Shopify-Shop-Domain".to_string(),
+            "example.myshopify.com".to_string(),
+        ),
+    ]
+}
+
+#[test]
+fn test_oauth_callback_hmac() {
+    // The example of the Shopify documentation.
+    let webhook = Webhook::new("hush");
+    let query = "code=0907a61c0c8d55e99db179b68161bc00&hmac=700e2dadb827fcc8609e9d5ce208b2e9cdaab9df07390d2cbca10d7c328fc4bf&shop=some-shop.myshopify.com&state=0.6784241404160823&timestamp=1337178173";
+    assert!(webhook.verify_oauth_callback(query).is_ok());
+
+    let query = query.replace("some-shop", "another-shop");
+    assert!(matches!(
+        webhook.verify_oauth_callback(&query),
+        Err(WebhookError::SignatureMismatch)
+    ));
+
+    assert!(matches!(
+        webhook.verify_oauth_callback(
+            "code=0907a61c0c8d55e99db179b68161bc00&shop=some-shop.myshopify.com"
+        ),
+        Err(WebhookError::MissingHmac)
+    ));
+}
+
+#[test]
+fn test_client_verifies_with_its_client_secret() {
+    let client = Client::new("client-id", "hush", "https://example.com/callback", "", "");
+    let payload = include_bytes!("fixtures/app_uninstalled.json");
+
+    let signature = Webhook::new("hush").sign(payload);
+    assert!(client.webhook().verify(payload, &signature).is_ok());
+
+    let signature = Webhook::new("another secret").sign(payload);
+    assert!(matches!(
+        client.webhook().verify(payload, &signature),
+        Err(WebhookError::SignatureMismatch)
+    ));
+    assert!(matches!(
+        client.webhook().verify(payload, "not base64!"),
+        Err(WebhookError::InvalidSignature)
+    ));
+}
+
+#[test]
+fn test_webhook_topics_are_typed() {
+    let webhook = Webhook::new("hush");
+
+    let payload = include_bytes!("fixtures/orders_create.json");
+    let event = webhook
+        .construct_event(webhook_headers(&webhook, "orders/create", payload), payload)
+        .unwrap();
+    let WebhookEvent::OrdersCreate(order) = event else {
+        unreachable!("Expected an order, got {:?}", event);
+    };
+    assert_eq!(order.name, "#9999");
+    assert_eq!(order.total_price, "403.00");
+    assert_eq!(order.line_items.len(), 2);
+    assert_eq!(order.line_items[0].product_id, 632910392);
+    assert_eq!(order.customer.unwrap().first_name, "John");
+    assert_eq!(order.shipping_address.unwrap().province_code, "KY");
+
+    let payload = include_bytes!("fixtures/products_update.json");
+    let event = webhooks::parse_event("products/update", payload).unwrap();
+    let WebhookEvent::ProductsUpdate(product) = event else {
+        unreachable!("Expected a product, got {:?}", event);
+    };
+    assert_eq!(product.handle, "example-t-shirt");
+    assert_eq!(product.variants[1].sku, "example-shirt-m");
+    assert_eq!(product.variants[1].inventory_quantity, 50);
+
+    let payload = include_bytes!("fixtures/app_uninstalled.json");
+    let event = webhooks::parse_event("app/uninstalled", payload).unwrap();
+    let WebhookEvent::AppUninstalled(shop) = event else {
+        unreachable!("Expected a shop, got {:?}", event);
+    };
+    assert_eq!(shop.myshopify_domain, "example.myshopify.com");
+
+    let event = webhooks::parse_event("customers/create", br#"{"id": 1}"#).unwrap();
+    assert!(matches!(event, WebhookEvent::Other { topic, .. } if topic == "customers/create"));
+}
+
+#[test]
+fn test_webhook_needs_its_headers() {
+    let webhook = Webhook::new("hush");
+    let payload = include_bytes!("fixtures/app_uninstalled.json");
+
+    let headers = vec![("X-Shopify-Topic", "app/uninstalled")];
+    assert!(matches!(
+        webhook.construct_event(headers, payload),
+        Err(WebhookError::MissingHeader("X-Shopify-Hmac-Sha256"))
+    ));
+}
@@ -1,6 +1,80 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`AdminAppsApproved::list`], set with a builder.
+pub struct AdminAppsApprovedListRequest<'a> {
+    client: &'a AdminAppsApproved,
+    limit: Option<i64>,
+    cursor: Option<&'a str>,
+    team_id: Option<&'a str>,
+    enterprise_id: Option<&'a str>,
+}
+
+impl<'a> AdminAppsApprovedListRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `cursor` query parameter.
+    pub fn cursor(mut self, cursor: &'a str) -> Self {
+        self.cursor = Some(cursor);
+        self
+    }
+
+    /// Sets the `team_id` query parameter.
+    pub fn team_id(mut self, team_id: &'a str) -> Self {
+        self.team_id = Some(team_id);
+        self
+    }
+
+    /// Sets the `enterprise_id` query parameter.
+    pub fn enterprise_id(mut self, enterprise_id: &'a str) -> Self {
+        self.enterprise_id = Some(enterprise_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        let Self {
+            client,
+            limit,
+            cursor,
+            team_id,
+            enterprise_id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = cursor {
+            query_args.push(("cursor".to_string(), v.to_string()));
+        }
+        if let Some(v) = enterprise_id {
+            query_args.push(("enterprise_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = team_id {
+            query_args.push(("team_id".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/admin.apps.approved.list?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct AdminAppsApproved {
     pub client: Client,
 }
@@ -60,4 +134,22 @@ impl AdminAppsApproved {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/admin.apps.approved.list` endpoint.
+     *
+     * As opposed to `list`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * List approved apps for an org or workspace.
+     *
+     * FROM: <https://api.slack.com/methods/admin.apps.approved.list>
+     */
+    pub fn list_request(&self) -> AdminAppsApprovedListRequest<'_> {
+        AdminAppsApprovedListRequest {
+            client: self,
+            limit: None,
+            cursor: None,
+            team_id: None,
+            enterprise_id: None,
+        }
+    }
 }
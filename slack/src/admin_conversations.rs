@@ -1,6 +1,174 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`AdminConversations::get_team`], set with a builder.
+pub struct AdminConversationsGetTeamRequest<'a> {
+    client: &'a AdminConversations,
+    channel_id: &'a str,
+    cursor: Option<&'a str>,
+    limit: Option<i64>,
+}
+
+impl<'a> AdminConversationsGetTeamRequest<'a> {
+    /// Sets the `cursor` query parameter.
+    pub fn cursor(mut self, cursor: &'a str) -> Self {
+        self.cursor = Some(cursor);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::AdminConversationsGetTeamsSchema>> {
+        let Self {
+            client,
+            channel_id,
+            cursor,
+            limit,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !channel_id.is_empty() {
+            query_args.push(("channel_id".to_string(), channel_id.to_string()));
+        }
+        if let Some(v) = cursor {
+            query_args.push(("cursor".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/admin.conversations.getTeams?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`AdminConversations::search`], set with a builder.
+pub struct AdminConversationsSearchRequest<'a> {
+    client: &'a AdminConversations,
+    team_ids: Option<&'a str>,
+    query: Option<&'a str>,
+    limit: Option<i64>,
+    cursor: Option<&'a str>,
+    search_channel_types: Option<&'a str>,
+    sort: Option<&'a str>,
+    sort_dir: Option<&'a str>,
+}
+
+impl<'a> AdminConversationsSearchRequest<'a> {
+    /// Sets the `team_ids` query parameter.
+    pub fn team_ids(mut self, team_ids: &'a str) -> Self {
+        self.team_ids = Some(team_ids);
+        self
+    }
+
+    /// Sets the `query` query parameter.
+    pub fn query(mut self, query: &'a str) -> Self {
+        self.query = Some(query);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `cursor` query parameter.
+    pub fn cursor(mut self, cursor: &'a str) -> Self {
+        self.cursor = Some(cursor);
+        self
+    }
+
+    /// Sets the `search_channel_types` query parameter.
+    pub fn search_channel_types(mut self, search_channel_types: &'a str) -> Self {
+        self.search_channel_types = Some(search_channel_types);
+        self
+    }
+
+    /// Sets the `sort` query parameter.
+    pub fn sort(mut self, sort: &'a str) -> Self {
+        self.sort = Some(sort);
+        self
+    }
+
+    /// Sets the `sort_dir` query parameter.
+    pub fn sort_dir(mut self, sort_dir: &'a str) -> Self {
+        self.sort_dir = Some(sort_dir);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::AdminConversationsSearchSchema>> {
+        let Self {
+            client,
+            team_ids,
+            query,
+            limit,
+            cursor,
+            search_channel_types,
+            sort,
+            sort_dir,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = cursor {
+            query_args.push(("cursor".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = query {
+            query_args.push(("query".to_string(), v.to_string()));
+        }
+        if let Some(v) = search_channel_types {
+            query_args.push(("search_channel_types".to_string(), v.to_string()));
+        }
+        if let Some(v) = sort {
+            query_args.push(("sort".to_string(), v.to_string()));
+        }
+        if let Some(v) = sort_dir {
+            query_args.push(("sort_dir".to_string(), v.to_string()));
+        }
+        if let Some(v) = team_ids {
+            query_args.push(("team_ids".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/admin.conversations.search?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct AdminConversations {
     pub client: Client,
 }
@@ -22,13 +190,16 @@ impl AdminConversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.conversations:write`.
      */
-    pub async fn archive(&self) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+    pub async fn archive(
+        &self,
+        body: &crate::types::AdminConversationsDeleteRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let url = self.client.url("/admin.conversations.archive", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -47,6 +218,7 @@ impl AdminConversations {
      */
     pub async fn convert_private(
         &self,
+        body: &crate::types::AdminConversationsDeleteRequest,
     ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let url = self
             .client
@@ -55,7 +227,7 @@ impl AdminConversations {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -74,13 +246,14 @@ impl AdminConversations {
      */
     pub async fn create(
         &self,
+        body: &crate::types::AdminConversationsCreateRequest,
     ) -> ClientResult<crate::Response<crate::types::AdminConversationsCreateSchema>> {
         let url = self.client.url("/admin.conversations.create", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -97,13 +270,16 @@ impl AdminConversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.conversations:write`.
      */
-    pub async fn delete(&self) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+    pub async fn delete(
+        &self,
+        body: &crate::types::AdminConversationsDeleteRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let url = self.client.url("/admin.conversations.delete", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -122,6 +298,7 @@ impl AdminConversations {
      */
     pub async fn disconnect_shared(
         &self,
+        body: &crate::types::AdminConversationsDisconnectSharedRequest,
     ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let url = self
             .client
@@ -130,7 +307,7 @@ impl AdminConversations {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -216,6 +393,26 @@ impl AdminConversations {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/admin.conversations.getTeams` endpoint.
+     *
+     * As opposed to `get_team`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get all the workspaces a given public or private channel is connected to within this Enterprise org.
+     *
+     * FROM: <https://api.slack.com/methods/admin.conversations.getTeams>
+     */
+    pub fn get_team_request<'a>(
+        &'a self,
+        channel_id: &'a str,
+    ) -> AdminConversationsGetTeamRequest<'a> {
+        AdminConversationsGetTeamRequest {
+            client: self,
+            channel_id,
+            cursor: None,
+            limit: None,
+        }
+    }
     /**
      * This function performs a `POST` to the `/admin.conversations.invite` endpoint.
      *
@@ -227,13 +424,16 @@ impl AdminConversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.conversations:write`.
      */
-    pub async fn invite(&self) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+    pub async fn invite(
+        &self,
+        body: &crate::types::AdminConversationsInviteRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let url = self.client.url("/admin.conversations.invite", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -250,13 +450,16 @@ impl AdminConversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.conversations:write`.
      */
-    pub async fn rename(&self) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+    pub async fn rename(
+        &self,
+        body: &crate::types::AdminConversationsRenameRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let url = self.client.url("/admin.conversations.rename", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -329,6 +532,27 @@ impl AdminConversations {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/admin.conversations.search` endpoint.
+     *
+     * As opposed to `search`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Search for public or private channels in an Enterprise organization.
+     *
+     * FROM: <https://api.slack.com/methods/admin.conversations.search>
+     */
+    pub fn search_request(&self) -> AdminConversationsSearchRequest<'_> {
+        AdminConversationsSearchRequest {
+            client: self,
+            team_ids: None,
+            query: None,
+            limit: None,
+            cursor: None,
+            search_channel_types: None,
+            sort: None,
+            sort_dir: None,
+        }
+    }
     /**
      * This function performs a `POST` to the `/admin.conversations.setConversationPrefs` endpoint.
      *
@@ -342,6 +566,7 @@ impl AdminConversations {
      */
     pub async fn set_conversation_prefs(
         &self,
+        body: &crate::types::AdminConversationsSetConversationPrefsRequest,
     ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let url = self
             .client
@@ -350,7 +575,7 @@ impl AdminConversations {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -367,13 +592,16 @@ impl AdminConversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.conversations:write`.
      */
-    pub async fn set_teams(&self) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+    pub async fn set_teams(
+        &self,
+        body: &crate::types::AdminConversationsSetTeamsRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let url = self.client.url("/admin.conversations.setTeams", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -390,13 +618,16 @@ impl AdminConversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.conversations:write`.
      */
-    pub async fn unarchive(&self) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+    pub async fn unarchive(
+        &self,
+        body: &crate::types::AdminConversationsDeleteRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let url = self.client.url("/admin.conversations.unarchive", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
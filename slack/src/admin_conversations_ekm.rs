@@ -1,6 +1,84 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`AdminConversationsEkm::list_original_connected_channel_info`], set with a builder.
+pub struct AdminConversationsEkmListOriginalConnectedChannelInfoRequest<'a> {
+    client: &'a AdminConversationsEkm,
+    channel_ids: Option<&'a str>,
+    team_ids: Option<&'a str>,
+    limit: Option<i64>,
+    cursor: Option<&'a str>,
+}
+
+impl<'a> AdminConversationsEkmListOriginalConnectedChannelInfoRequest<'a> {
+    /// Sets the `channel_ids` query parameter.
+    pub fn channel_ids(mut self, channel_ids: &'a str) -> Self {
+        self.channel_ids = Some(channel_ids);
+        self
+    }
+
+    /// Sets the `team_ids` query parameter.
+    pub fn team_ids(mut self, team_ids: &'a str) -> Self {
+        self.team_ids = Some(team_ids);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `cursor` query parameter.
+    pub fn cursor(mut self, cursor: &'a str) -> Self {
+        self.cursor = Some(cursor);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        let Self {
+            client,
+            channel_ids,
+            team_ids,
+            limit,
+            cursor,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = channel_ids {
+            query_args.push(("channel_ids".to_string(), v.to_string()));
+        }
+        if let Some(v) = cursor {
+            query_args.push(("cursor".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = team_ids {
+            query_args.push(("team_ids".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/admin.conversations.ekm.listOriginalConnectedChannelInfo?{}",
+                query_
+            ),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct AdminConversationsEkm {
     pub client: Client,
 }
@@ -64,4 +142,24 @@ impl AdminConversationsEkm {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/admin.conversations.ekm.listOriginalConnectedChannelInfo` endpoint.
+     *
+     * As opposed to `list_original_connected_channel_info`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * List all disconnected channels—i.e., channels that were once connected to other workspaces and then disconnected—and the corresponding original channel IDs for key revocation with EKM.
+     *
+     * FROM: <https://api.slack.com/methods/admin.conversations.ekm.listOriginalConnectedChannelInfo>
+     */
+    pub fn list_original_connected_channel_info_request(
+        &self,
+    ) -> AdminConversationsEkmListOriginalConnectedChannelInfoRequest<'_> {
+        AdminConversationsEkmListOriginalConnectedChannelInfoRequest {
+            client: self,
+            channel_ids: None,
+            team_ids: None,
+            limit: None,
+            cursor: None,
+        }
+    }
 }
@@ -1,6 +1,53 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`AdminConversationsRestrictAccess::list_group`], set with a builder.
+pub struct AdminConversationsRestrictAccessListGroupRequest<'a> {
+    client: &'a AdminConversationsRestrictAccess,
+    channel_id: &'a str,
+    team_id: Option<&'a str>,
+}
+
+impl<'a> AdminConversationsRestrictAccessListGroupRequest<'a> {
+    /// Sets the `team_id` query parameter.
+    pub fn team_id(mut self, team_id: &'a str) -> Self {
+        self.team_id = Some(team_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        let Self {
+            client,
+            channel_id,
+            team_id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !channel_id.is_empty() {
+            query_args.push(("channel_id".to_string(), channel_id.to_string()));
+        }
+        if let Some(v) = team_id {
+            query_args.push(("team_id".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin.conversations.restrictAccess.listGroups?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct AdminConversationsRestrictAccess {
     pub client: Client,
 }
@@ -18,7 +65,10 @@ impl AdminConversationsRestrictAccess {
      *
      * FROM: <https://api.slack.com/methods/admin.conversations.restrictAccess.addGroup>
      */
-    pub async fn add_group(&self) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+    pub async fn add_group(
+        &self,
+        body: &crate::types::AdminConversationsRestrictAccessAddGroupRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let url = self
             .client
             .url("/admin.conversations.restrictAccess.addGroup", None);
@@ -26,7 +76,7 @@ impl AdminConversationsRestrictAccess {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -72,6 +122,25 @@ impl AdminConversationsRestrictAccess {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/admin.conversations.restrictAccess.listGroups` endpoint.
+     *
+     * As opposed to `list_group`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * List all IDP Groups linked to a channel
+     *
+     * FROM: <https://api.slack.com/methods/admin.conversations.restrictAccess.listGroups>
+     */
+    pub fn list_group_request<'a>(
+        &'a self,
+        channel_id: &'a str,
+    ) -> AdminConversationsRestrictAccessListGroupRequest<'a> {
+        AdminConversationsRestrictAccessListGroupRequest {
+            client: self,
+            channel_id,
+            team_id: None,
+        }
+    }
     /**
      * This function performs a `POST` to the `/admin.conversations.restrictAccess.removeGroup` endpoint.
      *
@@ -79,7 +148,10 @@ impl AdminConversationsRestrictAccess {
      *
      * FROM: <https://api.slack.com/methods/admin.conversations.restrictAccess.removeGroup>
      */
-    pub async fn remove_group(&self) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+    pub async fn remove_group(
+        &self,
+        body: &crate::types::AdminConversationsRestrictAccessRemoveGroupRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let url = self
             .client
             .url("/admin.conversations.restrictAccess.removeGroup", None);
@@ -87,7 +159,7 @@ impl AdminConversationsRestrictAccess {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -1,6 +1,58 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`AdminEmoji::list`], set with a builder.
+pub struct AdminEmojiListRequest<'a> {
+    client: &'a AdminEmoji,
+    cursor: Option<&'a str>,
+    limit: Option<i64>,
+}
+
+impl<'a> AdminEmojiListRequest<'a> {
+    /// Sets the `cursor` query parameter.
+    pub fn cursor(mut self, cursor: &'a str) -> Self {
+        self.cursor = Some(cursor);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        let Self {
+            client,
+            cursor,
+            limit,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = cursor {
+            query_args.push(("cursor".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/admin.emoji.list?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct AdminEmoji {
     pub client: Client,
 }
@@ -18,13 +70,16 @@ impl AdminEmoji {
      *
      * FROM: <https://api.slack.com/methods/admin.emoji.add>
      */
-    pub async fn add(&self) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+    pub async fn add(
+        &self,
+        body: &crate::types::AdminEmojiAddRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let url = self.client.url("/admin.emoji.add", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -37,13 +92,16 @@ impl AdminEmoji {
      *
      * FROM: <https://api.slack.com/methods/admin.emoji.addAlias>
      */
-    pub async fn add_alias(&self) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+    pub async fn add_alias(
+        &self,
+        body: &crate::types::AdminEmojiAddAliasRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let url = self.client.url("/admin.emoji.addAlias", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -88,6 +146,22 @@ impl AdminEmoji {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/admin.emoji.list` endpoint.
+     *
+     * As opposed to `list`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * List emoji for an Enterprise Grid organization.
+     *
+     * FROM: <https://api.slack.com/methods/admin.emoji.list>
+     */
+    pub fn list_request(&self) -> AdminEmojiListRequest<'_> {
+        AdminEmojiListRequest {
+            client: self,
+            cursor: None,
+            limit: None,
+        }
+    }
     /**
      * This function performs a `POST` to the `/admin.emoji.remove` endpoint.
      *
@@ -95,13 +169,16 @@ impl AdminEmoji {
      *
      * FROM: <https://api.slack.com/methods/admin.emoji.remove>
      */
-    pub async fn remove(&self) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+    pub async fn remove(
+        &self,
+        body: &crate::types::AdminEmojiRemoveRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let url = self.client.url("/admin.emoji.remove", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -114,13 +191,16 @@ impl AdminEmoji {
      *
      * FROM: <https://api.slack.com/methods/admin.emoji.rename>
      */
-    pub async fn rename(&self) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+    pub async fn rename(
+        &self,
+        body: &crate::types::AdminEmojiRenameRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let url = self.client.url("/admin.emoji.rename", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
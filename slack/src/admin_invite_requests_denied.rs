@@ -1,6 +1,70 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`AdminInviteRequestsDenied::list`], set with a builder.
+pub struct AdminInviteRequestsDeniedListRequest<'a> {
+    client: &'a AdminInviteRequestsDenied,
+    team_id: Option<&'a str>,
+    cursor: Option<&'a str>,
+    limit: Option<i64>,
+}
+
+impl<'a> AdminInviteRequestsDeniedListRequest<'a> {
+    /// Sets the `team_id` query parameter.
+    pub fn team_id(mut self, team_id: &'a str) -> Self {
+        self.team_id = Some(team_id);
+        self
+    }
+
+    /// Sets the `cursor` query parameter.
+    pub fn cursor(mut self, cursor: &'a str) -> Self {
+        self.cursor = Some(cursor);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        let Self {
+            client,
+            team_id,
+            cursor,
+            limit,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = cursor {
+            query_args.push(("cursor".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = team_id {
+            query_args.push(("team_id".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/admin.inviteRequests.denied.list?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct AdminInviteRequestsDenied {
     pub client: Client,
 }
@@ -56,4 +120,21 @@ impl AdminInviteRequestsDenied {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/admin.inviteRequests.denied.list` endpoint.
+     *
+     * As opposed to `list`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * List all denied workspace invite requests.
+     *
+     * FROM: <https://api.slack.com/methods/admin.inviteRequests.denied.list>
+     */
+    pub fn list_request(&self) -> AdminInviteRequestsDeniedListRequest<'_> {
+        AdminInviteRequestsDeniedListRequest {
+            client: self,
+            team_id: None,
+            cursor: None,
+            limit: None,
+        }
+    }
 }
@@ -1,6 +1,58 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`AdminTeams::list`], set with a builder.
+pub struct AdminTeamsListRequest<'a> {
+    client: &'a AdminTeams,
+    limit: Option<i64>,
+    cursor: Option<&'a str>,
+}
+
+impl<'a> AdminTeamsListRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `cursor` query parameter.
+    pub fn cursor(mut self, cursor: &'a str) -> Self {
+        self.cursor = Some(cursor);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        let Self {
+            client,
+            limit,
+            cursor,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = cursor {
+            query_args.push(("cursor".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/admin.teams.list?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct AdminTeams {
     pub client: Client,
 }
@@ -22,13 +74,16 @@ impl AdminTeams {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.teams:write`.
      */
-    pub async fn create(&self) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+    pub async fn create(
+        &self,
+        body: &crate::types::AdminTeamsCreateRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let url = self.client.url("/admin.teams.create", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -73,4 +128,20 @@ impl AdminTeams {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/admin.teams.list` endpoint.
+     *
+     * As opposed to `list`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * List all teams on an Enterprise organization
+     *
+     * FROM: <https://api.slack.com/methods/admin.teams.list>
+     */
+    pub fn list_request(&self) -> AdminTeamsListRequest<'_> {
+        AdminTeamsListRequest {
+            client: self,
+            limit: None,
+            cursor: None,
+        }
+    }
 }
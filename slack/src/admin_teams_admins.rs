@@ -1,6 +1,63 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`AdminTeamsAdmins::list`], set with a builder.
+pub struct AdminTeamsAdminsListRequest<'a> {
+    client: &'a AdminTeamsAdmins,
+    limit: Option<i64>,
+    cursor: Option<&'a str>,
+    team_id: &'a str,
+}
+
+impl<'a> AdminTeamsAdminsListRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `cursor` query parameter.
+    pub fn cursor(mut self, cursor: &'a str) -> Self {
+        self.cursor = Some(cursor);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        let Self {
+            client,
+            limit,
+            cursor,
+            team_id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = cursor {
+            query_args.push(("cursor".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if !team_id.is_empty() {
+            query_args.push(("team_id".to_string(), team_id.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/admin.teams.admins.list?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct AdminTeamsAdmins {
     pub client: Client,
 }
@@ -55,4 +112,21 @@ impl AdminTeamsAdmins {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/admin.teams.admins.list` endpoint.
+     *
+     * As opposed to `list`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * List all of the admins on a given workspace.
+     *
+     * FROM: <https://api.slack.com/methods/admin.teams.admins.list>
+     */
+    pub fn list_request<'a>(&'a self, team_id: &'a str) -> AdminTeamsAdminsListRequest<'a> {
+        AdminTeamsAdminsListRequest {
+            client: self,
+            limit: None,
+            cursor: None,
+            team_id,
+        }
+    }
 }
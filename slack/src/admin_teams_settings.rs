@@ -54,6 +54,7 @@ impl AdminTeamsSettings {
      */
     pub async fn set_default_channels(
         &self,
+        body: &crate::types::AdminTeamsSettingsSetDefaultChannelsRequest,
     ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let url = self
             .client
@@ -62,7 +63,7 @@ impl AdminTeamsSettings {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -81,6 +82,7 @@ impl AdminTeamsSettings {
      */
     pub async fn set_description(
         &self,
+        body: &crate::types::AdminTeamsSettingsSetDescriptionRequest,
     ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let url = self
             .client
@@ -89,7 +91,7 @@ impl AdminTeamsSettings {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -108,6 +110,7 @@ impl AdminTeamsSettings {
      */
     pub async fn set_discoverability(
         &self,
+        body: &crate::types::AdminTeamsSettingsSetDiscoverabilityRequest,
     ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let url = self
             .client
@@ -116,7 +119,7 @@ impl AdminTeamsSettings {
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -129,13 +132,16 @@ impl AdminTeamsSettings {
      *
      * FROM: <https://api.slack.com/methods/admin.teams.settings.setIcon>
      */
-    pub async fn set_icon(&self) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+    pub async fn set_icon(
+        &self,
+        body: &crate::types::AdminTeamsSettingsSetIconRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let url = self.client.url("/admin.teams.settings.setIcon", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -152,13 +158,16 @@ impl AdminTeamsSettings {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.teams:write`.
      */
-    pub async fn set_name(&self) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+    pub async fn set_name(
+        &self,
+        body: &crate::types::AdminTeamsSettingsSetNameRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let url = self.client.url("/admin.teams.settings.setName", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -1,6 +1,63 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`AdminUsergroups::list_channel`], set with a builder.
+pub struct AdminUsergroupsListChannelRequest<'a> {
+    client: &'a AdminUsergroups,
+    usergroup_id: &'a str,
+    team_id: Option<&'a str>,
+    include_num_members: Option<bool>,
+}
+
+impl<'a> AdminUsergroupsListChannelRequest<'a> {
+    /// Sets the `team_id` query parameter.
+    pub fn team_id(mut self, team_id: &'a str) -> Self {
+        self.team_id = Some(team_id);
+        self
+    }
+
+    /// Sets the `include_num_members` query parameter.
+    pub fn include_num_members(mut self, include_num_members: bool) -> Self {
+        self.include_num_members = Some(include_num_members);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        let Self {
+            client,
+            usergroup_id,
+            team_id,
+            include_num_members,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = include_num_members {
+            query_args.push(("include_num_members".to_string(), v.to_string()));
+        }
+        if let Some(v) = team_id {
+            query_args.push(("team_id".to_string(), v.to_string()));
+        }
+        if !usergroup_id.is_empty() {
+            query_args.push(("usergroup_id".to_string(), usergroup_id.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/admin.usergroups.listChannels?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct AdminUsergroups {
     pub client: Client,
 }
@@ -22,13 +79,16 @@ impl AdminUsergroups {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.usergroups:write`.
      */
-    pub async fn add_channels(&self) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+    pub async fn add_channels(
+        &self,
+        body: &crate::types::AdminUsergroupsAddChannelsRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let url = self.client.url("/admin.usergroups.addChannels", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -45,13 +105,16 @@ impl AdminUsergroups {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.teams:write`.
      */
-    pub async fn add_teams(&self) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+    pub async fn add_teams(
+        &self,
+        body: &crate::types::AdminUsergroupsAddTeamsRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let url = self.client.url("/admin.usergroups.addTeams", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -104,6 +167,26 @@ impl AdminUsergroups {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/admin.usergroups.listChannels` endpoint.
+     *
+     * As opposed to `list_channel`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * List the channels linked to an org-level IDP group (user group).
+     *
+     * FROM: <https://api.slack.com/methods/admin.usergroups.listChannels>
+     */
+    pub fn list_channel_request<'a>(
+        &'a self,
+        usergroup_id: &'a str,
+    ) -> AdminUsergroupsListChannelRequest<'a> {
+        AdminUsergroupsListChannelRequest {
+            client: self,
+            usergroup_id,
+            team_id: None,
+            include_num_members: None,
+        }
+    }
     /**
      * This function performs a `POST` to the `/admin.usergroups.removeChannels` endpoint.
      *
@@ -117,13 +200,14 @@ impl AdminUsergroups {
      */
     pub async fn remove_channels(
         &self,
+        body: &crate::types::AdminUsergroupsRemoveChannelsRequest,
     ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let url = self.client.url("/admin.usergroups.removeChannels", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -1,6 +1,56 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Api::test`], set with a builder.
+pub struct ApiTestRequest<'a> {
+    client: &'a Api,
+    error: Option<&'a str>,
+    foo_: Option<&'a str>,
+}
+
+impl<'a> ApiTestRequest<'a> {
+    /// Sets the `error` query parameter.
+    pub fn error(mut self, error: &'a str) -> Self {
+        self.error = Some(error);
+        self
+    }
+
+    /// Sets the `foo` query parameter.
+    pub fn foo_(mut self, foo_: &'a str) -> Self {
+        self.foo_ = Some(foo_);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        let Self {
+            client,
+            error,
+            foo_,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = error {
+            query_args.push(("error".to_string(), v.to_string()));
+        }
+        if let Some(v) = foo_ {
+            query_args.push(("foo".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(&format!("/api.test?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct Api {
     pub client: Client,
 }
@@ -47,4 +97,20 @@ impl Api {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api.test` endpoint.
+     *
+     * As opposed to `test`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Checks API calling code.
+     *
+     * FROM: <https://api.slack.com/methods/api.test>
+     */
+    pub fn test_request(&self) -> ApiTestRequest<'_> {
+        ApiTestRequest {
+            client: self,
+            error: None,
+            foo_: None,
+        }
+    }
 }
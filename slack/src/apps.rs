@@ -1,6 +1,58 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Apps::uninstall`], set with a builder.
+pub struct AppsUninstallRequest<'a> {
+    client: &'a Apps,
+    client_id: Option<&'a str>,
+    client_secret: Option<&'a str>,
+}
+
+impl<'a> AppsUninstallRequest<'a> {
+    /// Sets the `client_id` query parameter.
+    pub fn client_id(mut self, client_id: &'a str) -> Self {
+        self.client_id = Some(client_id);
+        self
+    }
+
+    /// Sets the `client_secret` query parameter.
+    pub fn client_secret(mut self, client_secret: &'a str) -> Self {
+        self.client_secret = Some(client_secret);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        let Self {
+            client,
+            client_id,
+            client_secret,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = client_id {
+            query_args.push(("client_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = client_secret {
+            query_args.push(("client_secret".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/apps.uninstall?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct Apps {
     pub client: Client,
 }
@@ -50,4 +102,20 @@ impl Apps {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/apps.uninstall` endpoint.
+     *
+     * As opposed to `uninstall`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Uninstalls your app from a workspace.
+     *
+     * FROM: <https://api.slack.com/methods/apps.uninstall>
+     */
+    pub fn uninstall_request(&self) -> AppsUninstallRequest<'_> {
+        AppsUninstallRequest {
+            client: self,
+            client_id: None,
+            client_secret: None,
+        }
+    }
 }
@@ -1,6 +1,63 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`AppsEventAuthorizations::list`], set with a builder.
+pub struct AppsEventAuthorizationsListRequest<'a> {
+    client: &'a AppsEventAuthorizations,
+    event_context: &'a str,
+    cursor: Option<&'a str>,
+    limit: Option<i64>,
+}
+
+impl<'a> AppsEventAuthorizationsListRequest<'a> {
+    /// Sets the `cursor` query parameter.
+    pub fn cursor(mut self, cursor: &'a str) -> Self {
+        self.cursor = Some(cursor);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        let Self {
+            client,
+            event_context,
+            cursor,
+            limit,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = cursor {
+            query_args.push(("cursor".to_string(), v.to_string()));
+        }
+        if !event_context.is_empty() {
+            query_args.push(("event_context".to_string(), event_context.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/apps.event.authorizations.list?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct AppsEventAuthorizations {
     pub client: Client,
 }
@@ -55,4 +112,24 @@ impl AppsEventAuthorizations {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/apps.event.authorizations.list` endpoint.
+     *
+     * As opposed to `list`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get a list of authorizations for the given event context. Each authorization represents an app installation that the event is visible to.
+     *
+     * FROM: <https://api.slack.com/methods/apps.event.authorizations.list>
+     */
+    pub fn list_request<'a>(
+        &'a self,
+        event_context: &'a str,
+    ) -> AppsEventAuthorizationsListRequest<'a> {
+        AppsEventAuthorizationsListRequest {
+            client: self,
+            event_context,
+            cursor: None,
+            limit: None,
+        }
+    }
 }
@@ -1,6 +1,62 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`AppsPermissionsResources::list`], set with a builder.
+pub struct AppsPermissionsResourcesListRequest<'a> {
+    client: &'a AppsPermissionsResources,
+    cursor: Option<&'a str>,
+    limit: Option<i64>,
+}
+
+impl<'a> AppsPermissionsResourcesListRequest<'a> {
+    /// Sets the `cursor` query parameter.
+    pub fn cursor(mut self, cursor: &'a str) -> Self {
+        self.cursor = Some(cursor);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::AppsPermissionsResourcesListSuccessSchema>>
+    {
+        let Self {
+            client,
+            cursor,
+            limit,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = cursor {
+            query_args.push(("cursor".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/apps.permissions.resources.list?{}", query_),
+            None,
+        );
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct AppsPermissionsResources {
     pub client: Client,
 }
@@ -52,4 +108,20 @@ impl AppsPermissionsResources {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/apps.permissions.resources.list` endpoint.
+     *
+     * As opposed to `list`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Returns list of resource grants this app has on a team.
+     *
+     * FROM: <https://api.slack.com/methods/apps.permissions.resources.list>
+     */
+    pub fn list_request(&self) -> AppsPermissionsResourcesListRequest<'_> {
+        AppsPermissionsResourcesListRequest {
+            client: self,
+            cursor: None,
+            limit: None,
+        }
+    }
 }
@@ -1,6 +1,42 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Auth::revoke`], set with a builder.
+pub struct AuthRevokeRequest<'a> {
+    client: &'a Auth,
+    test: Option<bool>,
+}
+
+impl<'a> AuthRevokeRequest<'a> {
+    /// Sets the `test` query parameter.
+    pub fn test(mut self, test: bool) -> Self {
+        self.test = Some(test);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::AuthRevokeSchema>> {
+        let Self { client, test } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = test {
+            query_args.push(("test".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(&format!("/auth.revoke?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct Auth {
     pub client: Client,
 }
@@ -43,6 +79,21 @@ impl Auth {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/auth.revoke` endpoint.
+     *
+     * As opposed to `revoke`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Revokes a token.
+     *
+     * FROM: <https://api.slack.com/methods/auth.revoke>
+     */
+    pub fn revoke_request(&self) -> AuthRevokeRequest<'_> {
+        AuthRevokeRequest {
+            client: self,
+            test: None,
+        }
+    }
     /**
      * This function performs a `GET` to the `/auth.test` endpoint.
      *
@@ -0,0 +1,33 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::admin_apps::AdminApps`].
+pub struct AdminApps {
+    inner: crate::admin_apps::AdminApps,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl AdminApps {
+    pub(crate) fn new(
+        inner: crate::admin_apps::AdminApps,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        AdminApps { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::admin_apps::AdminApps::approve`].
+    pub fn approve(
+        &self,
+        body: &crate::types::AdminAppsApproveRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime.block_on(self.inner.approve(body))
+    }
+    /// The blocking version of [`crate::admin_apps::AdminApps::restrict`].
+    pub fn restrict(
+        &self,
+        body: &crate::types::AdminAppsApproveRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime.block_on(self.inner.restrict(body))
+    }
+}
@@ -0,0 +1,74 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`AdminAppsApproved::list`], set with a builder.
+pub struct AdminAppsApprovedListRequest<'a> {
+    inner: crate::admin_apps_approved::AdminAppsApprovedListRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> AdminAppsApprovedListRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `cursor` query parameter.
+    pub fn cursor(mut self, cursor: &'a str) -> Self {
+        self.inner = self.inner.cursor(cursor);
+        self
+    }
+
+    /// Sets the `team_id` query parameter.
+    pub fn team_id(mut self, team_id: &'a str) -> Self {
+        self.inner = self.inner.team_id(team_id);
+        self
+    }
+
+    /// Sets the `enterprise_id` query parameter.
+    pub fn enterprise_id(mut self, enterprise_id: &'a str) -> Self {
+        self.inner = self.inner.enterprise_id(enterprise_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::admin_apps_approved::AdminAppsApproved`].
+pub struct AdminAppsApproved {
+    inner: crate::admin_apps_approved::AdminAppsApproved,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl AdminAppsApproved {
+    pub(crate) fn new(
+        inner: crate::admin_apps_approved::AdminAppsApproved,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        AdminAppsApproved { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::admin_apps_approved::AdminAppsApproved::list`].
+    pub fn list(
+        &self,
+        limit: i64,
+        cursor: &str,
+        team_id: &str,
+        enterprise_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime
+            .block_on(self.inner.list(limit, cursor, team_id, enterprise_id))
+    }
+    /// The blocking version of [`crate::admin_apps_approved::AdminAppsApproved::list_request`].
+    pub fn list_request(&self) -> AdminAppsApprovedListRequest<'_> {
+        AdminAppsApprovedListRequest {
+            inner: self.inner.list_request(),
+            runtime: &self.runtime,
+        }
+    }
+}
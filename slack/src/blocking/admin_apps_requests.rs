@@ -0,0 +1,67 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`AdminAppsRequests::list`], set with a builder.
+pub struct AdminAppsRequestsListRequest<'a> {
+    inner: crate::admin_apps_requests::AdminAppsRequestsListRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> AdminAppsRequestsListRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `cursor` query parameter.
+    pub fn cursor(mut self, cursor: &'a str) -> Self {
+        self.inner = self.inner.cursor(cursor);
+        self
+    }
+
+    /// Sets the `team_id` query parameter.
+    pub fn team_id(mut self, team_id: &'a str) -> Self {
+        self.inner = self.inner.team_id(team_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::admin_apps_requests::AdminAppsRequests`].
+pub struct AdminAppsRequests {
+    inner: crate::admin_apps_requests::AdminAppsRequests,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl AdminAppsRequests {
+    pub(crate) fn new(
+        inner: crate::admin_apps_requests::AdminAppsRequests,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        AdminAppsRequests { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::admin_apps_requests::AdminAppsRequests::list`].
+    pub fn list(
+        &self,
+        limit: i64,
+        cursor: &str,
+        team_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime
+            .block_on(self.inner.list(limit, cursor, team_id))
+    }
+    /// The blocking version of [`crate::admin_apps_requests::AdminAppsRequests::list_request`].
+    pub fn list_request(&self) -> AdminAppsRequestsListRequest<'_> {
+        AdminAppsRequestsListRequest {
+            inner: self.inner.list_request(),
+            runtime: &self.runtime,
+        }
+    }
+}
@@ -0,0 +1,231 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`AdminConversations::get_team`], set with a builder.
+pub struct AdminConversationsGetTeamRequest<'a> {
+    inner: crate::admin_conversations::AdminConversationsGetTeamRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> AdminConversationsGetTeamRequest<'a> {
+    /// Sets the `cursor` query parameter.
+    pub fn cursor(mut self, cursor: &'a str) -> Self {
+        self.inner = self.inner.cursor(cursor);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::AdminConversationsGetTeamsSchema>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`AdminConversations::search`], set with a builder.
+pub struct AdminConversationsSearchRequest<'a> {
+    inner: crate::admin_conversations::AdminConversationsSearchRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> AdminConversationsSearchRequest<'a> {
+    /// Sets the `team_ids` query parameter.
+    pub fn team_ids(mut self, team_ids: &'a str) -> Self {
+        self.inner = self.inner.team_ids(team_ids);
+        self
+    }
+
+    /// Sets the `query` query parameter.
+    pub fn query(mut self, query: &'a str) -> Self {
+        self.inner = self.inner.query(query);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `cursor` query parameter.
+    pub fn cursor(mut self, cursor: &'a str) -> Self {
+        self.inner = self.inner.cursor(cursor);
+        self
+    }
+
+    /// Sets the `search_channel_types` query parameter.
+    pub fn search_channel_types(mut self, search_channel_types: &'a str) -> Self {
+        self.inner = self.inner.search_channel_types(search_channel_types);
+        self
+    }
+
+    /// Sets the `sort` query parameter.
+    pub fn sort(mut self, sort: &'a str) -> Self {
+        self.inner = self.inner.sort(sort);
+        self
+    }
+
+    /// Sets the `sort_dir` query parameter.
+    pub fn sort_dir(mut self, sort_dir: &'a str) -> Self {
+        self.inner = self.inner.sort_dir(sort_dir);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::AdminConversationsSearchSchema>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::admin_conversations::AdminConversations`].
+pub struct AdminConversations {
+    inner: crate::admin_conversations::AdminConversations,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl AdminConversations {
+    pub(crate) fn new(
+        inner: crate::admin_conversations::AdminConversations,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        AdminConversations { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::admin_conversations::AdminConversations::archive`].
+    pub fn archive(
+        &self,
+        body: &crate::types::AdminConversationsDeleteRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime.block_on(self.inner.archive(body))
+    }
+    /// The blocking version of [`crate::admin_conversations::AdminConversations::convert_private`].
+    pub fn convert_private(
+        &self,
+        body: &crate::types::AdminConversationsDeleteRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime.block_on(self.inner.convert_private(body))
+    }
+    /// The blocking version of [`crate::admin_conversations::AdminConversations::create`].
+    pub fn create(
+        &self,
+        body: &crate::types::AdminConversationsCreateRequest,
+    ) -> ClientResult<crate::Response<crate::types::AdminConversationsCreateSchema>> {
+        self.runtime.block_on(self.inner.create(body))
+    }
+    /// The blocking version of [`crate::admin_conversations::AdminConversations::delete`].
+    pub fn delete(
+        &self,
+        body: &crate::types::AdminConversationsDeleteRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime.block_on(self.inner.delete(body))
+    }
+    /// The blocking version of [`crate::admin_conversations::AdminConversations::disconnect_shared`].
+    pub fn disconnect_shared(
+        &self,
+        body: &crate::types::AdminConversationsDisconnectSharedRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime.block_on(self.inner.disconnect_shared(body))
+    }
+    /// The blocking version of [`crate::admin_conversations::AdminConversations::get_conversation_pref`].
+    pub fn get_conversation_pref(
+        &self,
+        channel_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::AdminConversationsGetConversationPrefsSchemaData>>
+    {
+        self.runtime
+            .block_on(self.inner.get_conversation_pref(channel_id))
+    }
+    /// The blocking version of [`crate::admin_conversations::AdminConversations::get_team`].
+    pub fn get_team(
+        &self,
+        channel_id: &str,
+        cursor: &str,
+        limit: i64,
+    ) -> ClientResult<crate::Response<crate::types::AdminConversationsGetTeamsSchema>> {
+        self.runtime
+            .block_on(self.inner.get_team(channel_id, cursor, limit))
+    }
+    /// The blocking version of [`crate::admin_conversations::AdminConversations::get_team_request`].
+    pub fn get_team_request<'a>(
+        &'a self,
+        channel_id: &'a str,
+    ) -> AdminConversationsGetTeamRequest<'a> {
+        AdminConversationsGetTeamRequest {
+            inner: self.inner.get_team_request(channel_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::admin_conversations::AdminConversations::invite`].
+    pub fn invite(
+        &self,
+        body: &crate::types::AdminConversationsInviteRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime.block_on(self.inner.invite(body))
+    }
+    /// The blocking version of [`crate::admin_conversations::AdminConversations::rename`].
+    pub fn rename(
+        &self,
+        body: &crate::types::AdminConversationsRenameRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime.block_on(self.inner.rename(body))
+    }
+    /// The blocking version of [`crate::admin_conversations::AdminConversations::search`].
+    pub fn search(
+        &self,
+        team_ids: &str,
+        query: &str,
+        limit: i64,
+        cursor: &str,
+        search_channel_types: &str,
+        sort: &str,
+        sort_dir: &str,
+    ) -> ClientResult<crate::Response<crate::types::AdminConversationsSearchSchema>> {
+        self.runtime.block_on(self.inner.search(
+            team_ids,
+            query,
+            limit,
+            cursor,
+            search_channel_types,
+            sort,
+            sort_dir,
+        ))
+    }
+    /// The blocking version of [`crate::admin_conversations::AdminConversations::search_request`].
+    pub fn search_request(&self) -> AdminConversationsSearchRequest<'_> {
+        AdminConversationsSearchRequest {
+            inner: self.inner.search_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::admin_conversations::AdminConversations::set_conversation_prefs`].
+    pub fn set_conversation_prefs(
+        &self,
+        body: &crate::types::AdminConversationsSetConversationPrefsRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime
+            .block_on(self.inner.set_conversation_prefs(body))
+    }
+    /// The blocking version of [`crate::admin_conversations::AdminConversations::set_teams`].
+    pub fn set_teams(
+        &self,
+        body: &crate::types::AdminConversationsSetTeamsRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime.block_on(self.inner.set_teams(body))
+    }
+    /// The blocking version of [`crate::admin_conversations::AdminConversations::unarchive`].
+    pub fn unarchive(
+        &self,
+        body: &crate::types::AdminConversationsDeleteRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime.block_on(self.inner.unarchive(body))
+    }
+}
@@ -0,0 +1,81 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`AdminConversationsEkm::list_original_connected_channel_info`], set with a builder.
+pub struct AdminConversationsEkmListOriginalConnectedChannelInfoRequest<'a> {
+            inner: crate::admin_conversations_ekm::AdminConversationsEkmListOriginalConnectedChannelInfoRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a> AdminConversationsEkmListOriginalConnectedChannelInfoRequest<'a> {
+    /// Sets the `channel_ids` query parameter.
+    pub fn channel_ids(mut self, channel_ids: &'a str) -> Self {
+        self.inner = self.inner.channel_ids(channel_ids);
+        self
+    }
+
+    /// Sets the `team_ids` query parameter.
+    pub fn team_ids(mut self, team_ids: &'a str) -> Self {
+        self.inner = self.inner.team_ids(team_ids);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `cursor` query parameter.
+    pub fn cursor(mut self, cursor: &'a str) -> Self {
+        self.inner = self.inner.cursor(cursor);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::admin_conversations_ekm::AdminConversationsEkm`].
+pub struct AdminConversationsEkm {
+    inner: crate::admin_conversations_ekm::AdminConversationsEkm,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl AdminConversationsEkm {
+    pub(crate) fn new(
+        inner: crate::admin_conversations_ekm::AdminConversationsEkm,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        AdminConversationsEkm { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::admin_conversations_ekm::AdminConversationsEkm::list_original_connected_channel_info`].
+    pub fn list_original_connected_channel_info(
+        &self,
+        channel_ids: &str,
+        team_ids: &str,
+        limit: i64,
+        cursor: &str,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime
+            .block_on(self.inner.list_original_connected_channel_info(
+                channel_ids,
+                team_ids,
+                limit,
+                cursor,
+            ))
+    }
+    /// The blocking version of [`crate::admin_conversations_ekm::AdminConversationsEkm::list_original_connected_channel_info_request`].
+    pub fn list_original_connected_channel_info_request(
+        &self,
+    ) -> AdminConversationsEkmListOriginalConnectedChannelInfoRequest<'_> {
+        AdminConversationsEkmListOriginalConnectedChannelInfoRequest {
+            inner: self.inner.list_original_connected_channel_info_request(),
+            runtime: &self.runtime,
+        }
+    }
+}
@@ -0,0 +1,71 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`AdminConversationsRestrictAccess::list_group`], set with a builder.
+pub struct AdminConversationsRestrictAccessListGroupRequest<'a> {
+            inner: crate::admin_conversations_restrict_access::AdminConversationsRestrictAccessListGroupRequest<'a>,
+            runtime: &'a tokio::runtime::Runtime,
+        }
+
+impl<'a> AdminConversationsRestrictAccessListGroupRequest<'a> {
+    /// Sets the `team_id` query parameter.
+    pub fn team_id(mut self, team_id: &'a str) -> Self {
+        self.inner = self.inner.team_id(team_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::admin_conversations_restrict_access::AdminConversationsRestrictAccess`].
+pub struct AdminConversationsRestrictAccess {
+    inner: crate::admin_conversations_restrict_access::AdminConversationsRestrictAccess,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl AdminConversationsRestrictAccess {
+    pub(crate) fn new(
+        inner: crate::admin_conversations_restrict_access::AdminConversationsRestrictAccess,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        AdminConversationsRestrictAccess { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::admin_conversations_restrict_access::AdminConversationsRestrictAccess::add_group`].
+    pub fn add_group(
+        &self,
+        body: &crate::types::AdminConversationsRestrictAccessAddGroupRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime.block_on(self.inner.add_group(body))
+    }
+    /// The blocking version of [`crate::admin_conversations_restrict_access::AdminConversationsRestrictAccess::list_group`].
+    pub fn list_group(
+        &self,
+        channel_id: &str,
+        team_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime
+            .block_on(self.inner.list_group(channel_id, team_id))
+    }
+    /// The blocking version of [`crate::admin_conversations_restrict_access::AdminConversationsRestrictAccess::list_group_request`].
+    pub fn list_group_request<'a>(
+        &'a self,
+        channel_id: &'a str,
+    ) -> AdminConversationsRestrictAccessListGroupRequest<'a> {
+        AdminConversationsRestrictAccessListGroupRequest {
+            inner: self.inner.list_group_request(channel_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::admin_conversations_restrict_access::AdminConversationsRestrictAccess::remove_group`].
+    pub fn remove_group(
+        &self,
+        body: &crate::types::AdminConversationsRestrictAccessRemoveGroupRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime.block_on(self.inner.remove_group(body))
+    }
+}
@@ -0,0 +1,87 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`AdminEmoji::list`], set with a builder.
+pub struct AdminEmojiListRequest<'a> {
+    inner: crate::admin_emoji::AdminEmojiListRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> AdminEmojiListRequest<'a> {
+    /// Sets the `cursor` query parameter.
+    pub fn cursor(mut self, cursor: &'a str) -> Self {
+        self.inner = self.inner.cursor(cursor);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::admin_emoji::AdminEmoji`].
+pub struct AdminEmoji {
+    inner: crate::admin_emoji::AdminEmoji,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl AdminEmoji {
+    pub(crate) fn new(
+        inner: crate::admin_emoji::AdminEmoji,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        AdminEmoji { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::admin_emoji::AdminEmoji::add`].
+    pub fn add(
+        &self,
+        body: &crate::types::AdminEmojiAddRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime.block_on(self.inner.add(body))
+    }
+    /// The blocking version of [`crate::admin_emoji::AdminEmoji::add_alias`].
+    pub fn add_alias(
+        &self,
+        body: &crate::types::AdminEmojiAddAliasRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime.block_on(self.inner.add_alias(body))
+    }
+    /// The blocking version of [`crate::admin_emoji::AdminEmoji::list`].
+    pub fn list(
+        &self,
+        cursor: &str,
+        limit: i64,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime.block_on(self.inner.list(cursor, limit))
+    }
+    /// The blocking version of [`crate::admin_emoji::AdminEmoji::list_request`].
+    pub fn list_request(&self) -> AdminEmojiListRequest<'_> {
+        AdminEmojiListRequest {
+            inner: self.inner.list_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::admin_emoji::AdminEmoji::remove`].
+    pub fn remove(
+        &self,
+        body: &crate::types::AdminEmojiRemoveRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime.block_on(self.inner.remove(body))
+    }
+    /// The blocking version of [`crate::admin_emoji::AdminEmoji::rename`].
+    pub fn rename(
+        &self,
+        body: &crate::types::AdminEmojiRenameRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime.block_on(self.inner.rename(body))
+    }
+}
@@ -0,0 +1,81 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`AdminInviteRequests::list`], set with a builder.
+pub struct AdminInviteRequestsListRequest<'a> {
+    inner: crate::admin_invite_requests::AdminInviteRequestsListRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> AdminInviteRequestsListRequest<'a> {
+    /// Sets the `team_id` query parameter.
+    pub fn team_id(mut self, team_id: &'a str) -> Self {
+        self.inner = self.inner.team_id(team_id);
+        self
+    }
+
+    /// Sets the `cursor` query parameter.
+    pub fn cursor(mut self, cursor: &'a str) -> Self {
+        self.inner = self.inner.cursor(cursor);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::admin_invite_requests::AdminInviteRequests`].
+pub struct AdminInviteRequests {
+    inner: crate::admin_invite_requests::AdminInviteRequests,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl AdminInviteRequests {
+    pub(crate) fn new(
+        inner: crate::admin_invite_requests::AdminInviteRequests,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        AdminInviteRequests { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::admin_invite_requests::AdminInviteRequests::approve`].
+    pub fn approve(
+        &self,
+        body: &crate::types::AdminInviteRequestsApproveRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime.block_on(self.inner.approve(body))
+    }
+    /// The blocking version of [`crate::admin_invite_requests::AdminInviteRequests::deny`].
+    pub fn deny(
+        &self,
+        body: &crate::types::AdminInviteRequestsApproveRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime.block_on(self.inner.deny(body))
+    }
+    /// The blocking version of [`crate::admin_invite_requests::AdminInviteRequests::list`].
+    pub fn list(
+        &self,
+        team_id: &str,
+        cursor: &str,
+        limit: i64,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime
+            .block_on(self.inner.list(team_id, cursor, limit))
+    }
+    /// The blocking version of [`crate::admin_invite_requests::AdminInviteRequests::list_request`].
+    pub fn list_request(&self) -> AdminInviteRequestsListRequest<'_> {
+        AdminInviteRequestsListRequest {
+            inner: self.inner.list_request(),
+            runtime: &self.runtime,
+        }
+    }
+}
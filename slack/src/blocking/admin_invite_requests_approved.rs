@@ -0,0 +1,67 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`AdminInviteRequestsApproved::list`], set with a builder.
+pub struct AdminInviteRequestsApprovedListRequest<'a> {
+    inner: crate::admin_invite_requests_approved::AdminInviteRequestsApprovedListRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> AdminInviteRequestsApprovedListRequest<'a> {
+    /// Sets the `team_id` query parameter.
+    pub fn team_id(mut self, team_id: &'a str) -> Self {
+        self.inner = self.inner.team_id(team_id);
+        self
+    }
+
+    /// Sets the `cursor` query parameter.
+    pub fn cursor(mut self, cursor: &'a str) -> Self {
+        self.inner = self.inner.cursor(cursor);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::admin_invite_requests_approved::AdminInviteRequestsApproved`].
+pub struct AdminInviteRequestsApproved {
+    inner: crate::admin_invite_requests_approved::AdminInviteRequestsApproved,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl AdminInviteRequestsApproved {
+    pub(crate) fn new(
+        inner: crate::admin_invite_requests_approved::AdminInviteRequestsApproved,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        AdminInviteRequestsApproved { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::admin_invite_requests_approved::AdminInviteRequestsApproved::list`].
+    pub fn list(
+        &self,
+        team_id: &str,
+        cursor: &str,
+        limit: i64,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime
+            .block_on(self.inner.list(team_id, cursor, limit))
+    }
+    /// The blocking version of [`crate::admin_invite_requests_approved::AdminInviteRequestsApproved::list_request`].
+    pub fn list_request(&self) -> AdminInviteRequestsApprovedListRequest<'_> {
+        AdminInviteRequestsApprovedListRequest {
+            inner: self.inner.list_request(),
+            runtime: &self.runtime,
+        }
+    }
+}
@@ -0,0 +1,67 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`AdminInviteRequestsDenied::list`], set with a builder.
+pub struct AdminInviteRequestsDeniedListRequest<'a> {
+    inner: crate::admin_invite_requests_denied::AdminInviteRequestsDeniedListRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> AdminInviteRequestsDeniedListRequest<'a> {
+    /// Sets the `team_id` query parameter.
+    pub fn team_id(mut self, team_id: &'a str) -> Self {
+        self.inner = self.inner.team_id(team_id);
+        self
+    }
+
+    /// Sets the `cursor` query parameter.
+    pub fn cursor(mut self, cursor: &'a str) -> Self {
+        self.inner = self.inner.cursor(cursor);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::admin_invite_requests_denied::AdminInviteRequestsDenied`].
+pub struct AdminInviteRequestsDenied {
+    inner: crate::admin_invite_requests_denied::AdminInviteRequestsDenied,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl AdminInviteRequestsDenied {
+    pub(crate) fn new(
+        inner: crate::admin_invite_requests_denied::AdminInviteRequestsDenied,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        AdminInviteRequestsDenied { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::admin_invite_requests_denied::AdminInviteRequestsDenied::list`].
+    pub fn list(
+        &self,
+        team_id: &str,
+        cursor: &str,
+        limit: i64,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime
+            .block_on(self.inner.list(team_id, cursor, limit))
+    }
+    /// The blocking version of [`crate::admin_invite_requests_denied::AdminInviteRequestsDenied::list_request`].
+    pub fn list_request(&self) -> AdminInviteRequestsDeniedListRequest<'_> {
+        AdminInviteRequestsDeniedListRequest {
+            inner: self.inner.list_request(),
+            runtime: &self.runtime,
+        }
+    }
+}
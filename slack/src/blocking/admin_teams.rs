@@ -0,0 +1,66 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`AdminTeams::list`], set with a builder.
+pub struct AdminTeamsListRequest<'a> {
+    inner: crate::admin_teams::AdminTeamsListRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> AdminTeamsListRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `cursor` query parameter.
+    pub fn cursor(mut self, cursor: &'a str) -> Self {
+        self.inner = self.inner.cursor(cursor);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::admin_teams::AdminTeams`].
+pub struct AdminTeams {
+    inner: crate::admin_teams::AdminTeams,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl AdminTeams {
+    pub(crate) fn new(
+        inner: crate::admin_teams::AdminTeams,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        AdminTeams { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::admin_teams::AdminTeams::create`].
+    pub fn create(
+        &self,
+        body: &crate::types::AdminTeamsCreateRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime.block_on(self.inner.create(body))
+    }
+    /// The blocking version of [`crate::admin_teams::AdminTeams::list`].
+    pub fn list(
+        &self,
+        limit: i64,
+        cursor: &str,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime.block_on(self.inner.list(limit, cursor))
+    }
+    /// The blocking version of [`crate::admin_teams::AdminTeams::list_request`].
+    pub fn list_request(&self) -> AdminTeamsListRequest<'_> {
+        AdminTeamsListRequest {
+            inner: self.inner.list_request(),
+            runtime: &self.runtime,
+        }
+    }
+}
@@ -0,0 +1,61 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`AdminTeamsAdmins::list`], set with a builder.
+pub struct AdminTeamsAdminsListRequest<'a> {
+    inner: crate::admin_teams_admins::AdminTeamsAdminsListRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> AdminTeamsAdminsListRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `cursor` query parameter.
+    pub fn cursor(mut self, cursor: &'a str) -> Self {
+        self.inner = self.inner.cursor(cursor);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::admin_teams_admins::AdminTeamsAdmins`].
+pub struct AdminTeamsAdmins {
+    inner: crate::admin_teams_admins::AdminTeamsAdmins,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl AdminTeamsAdmins {
+    pub(crate) fn new(
+        inner: crate::admin_teams_admins::AdminTeamsAdmins,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        AdminTeamsAdmins { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::admin_teams_admins::AdminTeamsAdmins::list`].
+    pub fn list(
+        &self,
+        limit: i64,
+        cursor: &str,
+        team_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime
+            .block_on(self.inner.list(limit, cursor, team_id))
+    }
+    /// The blocking version of [`crate::admin_teams_admins::AdminTeamsAdmins::list_request`].
+    pub fn list_request<'a>(&'a self, team_id: &'a str) -> AdminTeamsAdminsListRequest<'a> {
+        AdminTeamsAdminsListRequest {
+            inner: self.inner.list_request(team_id),
+            runtime: &self.runtime,
+        }
+    }
+}
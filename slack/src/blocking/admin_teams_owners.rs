@@ -0,0 +1,61 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`AdminTeamsOwners::list`], set with a builder.
+pub struct AdminTeamsOwnersListRequest<'a> {
+    inner: crate::admin_teams_owners::AdminTeamsOwnersListRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> AdminTeamsOwnersListRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `cursor` query parameter.
+    pub fn cursor(mut self, cursor: &'a str) -> Self {
+        self.inner = self.inner.cursor(cursor);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::admin_teams_owners::AdminTeamsOwners`].
+pub struct AdminTeamsOwners {
+    inner: crate::admin_teams_owners::AdminTeamsOwners,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl AdminTeamsOwners {
+    pub(crate) fn new(
+        inner: crate::admin_teams_owners::AdminTeamsOwners,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        AdminTeamsOwners { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::admin_teams_owners::AdminTeamsOwners::list`].
+    pub fn list(
+        &self,
+        team_id: &str,
+        limit: i64,
+        cursor: &str,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime
+            .block_on(self.inner.list(team_id, limit, cursor))
+    }
+    /// The blocking version of [`crate::admin_teams_owners::AdminTeamsOwners::list_request`].
+    pub fn list_request<'a>(&'a self, team_id: &'a str) -> AdminTeamsOwnersListRequest<'a> {
+        AdminTeamsOwnersListRequest {
+            inner: self.inner.list_request(team_id),
+            runtime: &self.runtime,
+        }
+    }
+}
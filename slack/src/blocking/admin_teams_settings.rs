@@ -0,0 +1,58 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::admin_teams_settings::AdminTeamsSettings`].
+pub struct AdminTeamsSettings {
+    inner: crate::admin_teams_settings::AdminTeamsSettings,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl AdminTeamsSettings {
+    pub(crate) fn new(
+        inner: crate::admin_teams_settings::AdminTeamsSettings,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        AdminTeamsSettings { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::admin_teams_settings::AdminTeamsSettings::info`].
+    pub fn info(&self, team_id: &str) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime.block_on(self.inner.info(team_id))
+    }
+    /// The blocking version of [`crate::admin_teams_settings::AdminTeamsSettings::set_default_channels`].
+    pub fn set_default_channels(
+        &self,
+        body: &crate::types::AdminTeamsSettingsSetDefaultChannelsRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime.block_on(self.inner.set_default_channels(body))
+    }
+    /// The blocking version of [`crate::admin_teams_settings::AdminTeamsSettings::set_description`].
+    pub fn set_description(
+        &self,
+        body: &crate::types::AdminTeamsSettingsSetDescriptionRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime.block_on(self.inner.set_description(body))
+    }
+    /// The blocking version of [`crate::admin_teams_settings::AdminTeamsSettings::set_discoverability`].
+    pub fn set_discoverability(
+        &self,
+        body: &crate::types::AdminTeamsSettingsSetDiscoverabilityRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime.block_on(self.inner.set_discoverability(body))
+    }
+    /// The blocking version of [`crate::admin_teams_settings::AdminTeamsSettings::set_icon`].
+    pub fn set_icon(
+        &self,
+        body: &crate::types::AdminTeamsSettingsSetIconRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime.block_on(self.inner.set_icon(body))
+    }
+    /// The blocking version of [`crate::admin_teams_settings::AdminTeamsSettings::set_name`].
+    pub fn set_name(
+        &self,
+        body: &crate::types::AdminTeamsSettingsSetNameRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime.block_on(self.inner.set_name(body))
+    }
+}
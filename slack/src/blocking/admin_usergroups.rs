@@ -0,0 +1,87 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`AdminUsergroups::list_channel`], set with a builder.
+pub struct AdminUsergroupsListChannelRequest<'a> {
+    inner: crate::admin_usergroups::AdminUsergroupsListChannelRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> AdminUsergroupsListChannelRequest<'a> {
+    /// Sets the `team_id` query parameter.
+    pub fn team_id(mut self, team_id: &'a str) -> Self {
+        self.inner = self.inner.team_id(team_id);
+        self
+    }
+
+    /// Sets the `include_num_members` query parameter.
+    pub fn include_num_members(mut self, include_num_members: bool) -> Self {
+        self.inner = self.inner.include_num_members(include_num_members);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::admin_usergroups::AdminUsergroups`].
+pub struct AdminUsergroups {
+    inner: crate::admin_usergroups::AdminUsergroups,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl AdminUsergroups {
+    pub(crate) fn new(
+        inner: crate::admin_usergroups::AdminUsergroups,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        AdminUsergroups { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::admin_usergroups::AdminUsergroups::add_channels`].
+    pub fn add_channels(
+        &self,
+        body: &crate::types::AdminUsergroupsAddChannelsRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime.block_on(self.inner.add_channels(body))
+    }
+    /// The blocking version of [`crate::admin_usergroups::AdminUsergroups::add_teams`].
+    pub fn add_teams(
+        &self,
+        body: &crate::types::AdminUsergroupsAddTeamsRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime.block_on(self.inner.add_teams(body))
+    }
+    /// The blocking version of [`crate::admin_usergroups::AdminUsergroups::list_channel`].
+    pub fn list_channel(
+        &self,
+        usergroup_id: &str,
+        team_id: &str,
+        include_num_members: bool,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime.block_on(
+            self.inner
+                .list_channel(usergroup_id, team_id, include_num_members),
+        )
+    }
+    /// The blocking version of [`crate::admin_usergroups::AdminUsergroups::list_channel_request`].
+    pub fn list_channel_request<'a>(
+        &'a self,
+        usergroup_id: &'a str,
+    ) -> AdminUsergroupsListChannelRequest<'a> {
+        AdminUsergroupsListChannelRequest {
+            inner: self.inner.list_channel_request(usergroup_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::admin_usergroups::AdminUsergroups::remove_channels`].
+    pub fn remove_channels(
+        &self,
+        body: &crate::types::AdminUsergroupsRemoveChannelsRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime.block_on(self.inner.remove_channels(body))
+    }
+}
@@ -0,0 +1,110 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`AdminUsers::list`], set with a builder.
+pub struct AdminUsersListRequest<'a> {
+    inner: crate::admin_users::AdminUsersListRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> AdminUsersListRequest<'a> {
+    /// Sets the `cursor` query parameter.
+    pub fn cursor(mut self, cursor: &'a str) -> Self {
+        self.inner = self.inner.cursor(cursor);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::admin_users::AdminUsers`].
+pub struct AdminUsers {
+    inner: crate::admin_users::AdminUsers,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl AdminUsers {
+    pub(crate) fn new(
+        inner: crate::admin_users::AdminUsers,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        AdminUsers { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::admin_users::AdminUsers::assign`].
+    pub fn assign(
+        &self,
+        body: &crate::types::AdminUsersAssignRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime.block_on(self.inner.assign(body))
+    }
+    /// The blocking version of [`crate::admin_users::AdminUsers::invite`].
+    pub fn invite(
+        &self,
+        body: &crate::types::AdminUsersInviteRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime.block_on(self.inner.invite(body))
+    }
+    /// The blocking version of [`crate::admin_users::AdminUsers::list`].
+    pub fn list(
+        &self,
+        team_id: &str,
+        cursor: &str,
+        limit: i64,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime
+            .block_on(self.inner.list(team_id, cursor, limit))
+    }
+    /// The blocking version of [`crate::admin_users::AdminUsers::list_request`].
+    pub fn list_request<'a>(&'a self, team_id: &'a str) -> AdminUsersListRequest<'a> {
+        AdminUsersListRequest {
+            inner: self.inner.list_request(team_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::admin_users::AdminUsers::remove`].
+    pub fn remove(
+        &self,
+        body: &crate::types::AdminUsersSetRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime.block_on(self.inner.remove(body))
+    }
+    /// The blocking version of [`crate::admin_users::AdminUsers::set`].
+    pub fn set(
+        &self,
+        body: &crate::types::AdminUsersSetRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime.block_on(self.inner.set(body))
+    }
+    /// The blocking version of [`crate::admin_users::AdminUsers::set_expiration`].
+    pub fn set_expiration(
+        &self,
+        body: &crate::types::AdminUsersSetExpirationRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime.block_on(self.inner.set_expiration(body))
+    }
+    /// The blocking version of [`crate::admin_users::AdminUsers::set_owner`].
+    pub fn set_owner(
+        &self,
+        body: &crate::types::AdminUsersSetRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime.block_on(self.inner.set_owner(body))
+    }
+    /// The blocking version of [`crate::admin_users::AdminUsers::set_regular`].
+    pub fn set_regular(
+        &self,
+        body: &crate::types::AdminUsersSetRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime.block_on(self.inner.set_regular(body))
+    }
+}
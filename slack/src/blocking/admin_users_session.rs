@@ -0,0 +1,33 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::admin_users_session::AdminUsersSession`].
+pub struct AdminUsersSession {
+    inner: crate::admin_users_session::AdminUsersSession,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl AdminUsersSession {
+    pub(crate) fn new(
+        inner: crate::admin_users_session::AdminUsersSession,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        AdminUsersSession { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::admin_users_session::AdminUsersSession::invalidate`].
+    pub fn invalidate(
+        &self,
+        body: &crate::types::AdminUsersSessionInvalidateRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime.block_on(self.inner.invalidate(body))
+    }
+    /// The blocking version of [`crate::admin_users_session::AdminUsersSession::reset`].
+    pub fn reset(
+        &self,
+        body: &crate::types::AdminUsersSessionResetRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime.block_on(self.inner.reset(body))
+    }
+}
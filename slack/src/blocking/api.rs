@@ -0,0 +1,56 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Api::test`], set with a builder.
+pub struct ApiTestRequest<'a> {
+    inner: crate::api::ApiTestRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ApiTestRequest<'a> {
+    /// Sets the `error` query parameter.
+    pub fn error(mut self, error: &'a str) -> Self {
+        self.inner = self.inner.error(error);
+        self
+    }
+
+    /// Sets the `foo` query parameter.
+    pub fn foo_(mut self, foo_: &'a str) -> Self {
+        self.inner = self.inner.foo_(foo_);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::api::Api`].
+pub struct Api {
+    inner: crate::api::Api,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Api {
+    pub(crate) fn new(inner: crate::api::Api, runtime: Arc<tokio::runtime::Runtime>) -> Self {
+        Api { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::api::Api::test`].
+    pub fn test(
+        &self,
+        error: &str,
+        foo_: &str,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime.block_on(self.inner.test(error, foo_))
+    }
+    /// The blocking version of [`crate::api::Api::test_request`].
+    pub fn test_request(&self) -> ApiTestRequest<'_> {
+        ApiTestRequest {
+            inner: self.inner.test_request(),
+            runtime: &self.runtime,
+        }
+    }
+}
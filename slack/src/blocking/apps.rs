@@ -0,0 +1,57 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Apps::uninstall`], set with a builder.
+pub struct AppsUninstallRequest<'a> {
+    inner: crate::apps::AppsUninstallRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> AppsUninstallRequest<'a> {
+    /// Sets the `client_id` query parameter.
+    pub fn client_id(mut self, client_id: &'a str) -> Self {
+        self.inner = self.inner.client_id(client_id);
+        self
+    }
+
+    /// Sets the `client_secret` query parameter.
+    pub fn client_secret(mut self, client_secret: &'a str) -> Self {
+        self.inner = self.inner.client_secret(client_secret);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::apps::Apps`].
+pub struct Apps {
+    inner: crate::apps::Apps,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Apps {
+    pub(crate) fn new(inner: crate::apps::Apps, runtime: Arc<tokio::runtime::Runtime>) -> Self {
+        Apps { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::apps::Apps::uninstall`].
+    pub fn uninstall(
+        &self,
+        client_id: &str,
+        client_secret: &str,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime
+            .block_on(self.inner.uninstall(client_id, client_secret))
+    }
+    /// The blocking version of [`crate::apps::Apps::uninstall_request`].
+    pub fn uninstall_request(&self) -> AppsUninstallRequest<'_> {
+        AppsUninstallRequest {
+            inner: self.inner.uninstall_request(),
+            runtime: &self.runtime,
+        }
+    }
+}
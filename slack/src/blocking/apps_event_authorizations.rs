@@ -0,0 +1,64 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`AppsEventAuthorizations::list`], set with a builder.
+pub struct AppsEventAuthorizationsListRequest<'a> {
+    inner: crate::apps_event_authorizations::AppsEventAuthorizationsListRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> AppsEventAuthorizationsListRequest<'a> {
+    /// Sets the `cursor` query parameter.
+    pub fn cursor(mut self, cursor: &'a str) -> Self {
+        self.inner = self.inner.cursor(cursor);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::apps_event_authorizations::AppsEventAuthorizations`].
+pub struct AppsEventAuthorizations {
+    inner: crate::apps_event_authorizations::AppsEventAuthorizations,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl AppsEventAuthorizations {
+    pub(crate) fn new(
+        inner: crate::apps_event_authorizations::AppsEventAuthorizations,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        AppsEventAuthorizations { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::apps_event_authorizations::AppsEventAuthorizations::list`].
+    pub fn list(
+        &self,
+        event_context: &str,
+        cursor: &str,
+        limit: i64,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime
+            .block_on(self.inner.list(event_context, cursor, limit))
+    }
+    /// The blocking version of [`crate::apps_event_authorizations::AppsEventAuthorizations::list_request`].
+    pub fn list_request<'a>(
+        &'a self,
+        event_context: &'a str,
+    ) -> AppsEventAuthorizationsListRequest<'a> {
+        AppsEventAuthorizationsListRequest {
+            inner: self.inner.list_request(event_context),
+            runtime: &self.runtime,
+        }
+    }
+}
@@ -0,0 +1,32 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::apps_permissions::AppsPermissions`].
+pub struct AppsPermissions {
+    inner: crate::apps_permissions::AppsPermissions,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl AppsPermissions {
+    pub(crate) fn new(
+        inner: crate::apps_permissions::AppsPermissions,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        AppsPermissions { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::apps_permissions::AppsPermissions::info`].
+    pub fn info(&self) -> ClientResult<crate::Response<crate::types::AppsPermissionsInfoSchema>> {
+        self.runtime.block_on(self.inner.info())
+    }
+    /// The blocking version of [`crate::apps_permissions::AppsPermissions::request`].
+    pub fn request(
+        &self,
+        scopes: &str,
+        trigger_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime
+            .block_on(self.inner.request(scopes, trigger_id))
+    }
+}
@@ -0,0 +1,63 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`AppsPermissionsResources::list`], set with a builder.
+pub struct AppsPermissionsResourcesListRequest<'a> {
+    inner: crate::apps_permissions_resources::AppsPermissionsResourcesListRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> AppsPermissionsResourcesListRequest<'a> {
+    /// Sets the `cursor` query parameter.
+    pub fn cursor(mut self, cursor: &'a str) -> Self {
+        self.inner = self.inner.cursor(cursor);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::AppsPermissionsResourcesListSuccessSchema>>
+    {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::apps_permissions_resources::AppsPermissionsResources`].
+pub struct AppsPermissionsResources {
+    inner: crate::apps_permissions_resources::AppsPermissionsResources,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl AppsPermissionsResources {
+    pub(crate) fn new(
+        inner: crate::apps_permissions_resources::AppsPermissionsResources,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        AppsPermissionsResources { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::apps_permissions_resources::AppsPermissionsResources::list`].
+    pub fn list(
+        &self,
+        cursor: &str,
+        limit: i64,
+    ) -> ClientResult<crate::Response<crate::types::AppsPermissionsResourcesListSuccessSchema>>
+    {
+        self.runtime.block_on(self.inner.list(cursor, limit))
+    }
+    /// The blocking version of [`crate::apps_permissions_resources::AppsPermissionsResources::list_request`].
+    pub fn list_request(&self) -> AppsPermissionsResourcesListRequest<'_> {
+        AppsPermissionsResourcesListRequest {
+            inner: self.inner.list_request(),
+            runtime: &self.runtime,
+        }
+    }
+}
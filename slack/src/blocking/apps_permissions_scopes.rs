@@ -0,0 +1,25 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::apps_permissions_scopes::AppsPermissionsScopes`].
+pub struct AppsPermissionsScopes {
+    inner: crate::apps_permissions_scopes::AppsPermissionsScopes,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl AppsPermissionsScopes {
+    pub(crate) fn new(
+        inner: crate::apps_permissions_scopes::AppsPermissionsScopes,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        AppsPermissionsScopes { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::apps_permissions_scopes::AppsPermissionsScopes::list`].
+    pub fn list(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::ApiPermissionsScopesListSuccessSchema>> {
+        self.runtime.block_on(self.inner.list())
+    }
+}
@@ -0,0 +1,69 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`AppsPermissionsUsers::list`], set with a builder.
+pub struct AppsPermissionsUsersListRequest<'a> {
+    inner: crate::apps_permissions_users::AppsPermissionsUsersListRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> AppsPermissionsUsersListRequest<'a> {
+    /// Sets the `cursor` query parameter.
+    pub fn cursor(mut self, cursor: &'a str) -> Self {
+        self.inner = self.inner.cursor(cursor);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::apps_permissions_users::AppsPermissionsUsers`].
+pub struct AppsPermissionsUsers {
+    inner: crate::apps_permissions_users::AppsPermissionsUsers,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl AppsPermissionsUsers {
+    pub(crate) fn new(
+        inner: crate::apps_permissions_users::AppsPermissionsUsers,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        AppsPermissionsUsers { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::apps_permissions_users::AppsPermissionsUsers::list`].
+    pub fn list(
+        &self,
+        cursor: &str,
+        limit: i64,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime.block_on(self.inner.list(cursor, limit))
+    }
+    /// The blocking version of [`crate::apps_permissions_users::AppsPermissionsUsers::list_request`].
+    pub fn list_request(&self) -> AppsPermissionsUsersListRequest<'_> {
+        AppsPermissionsUsersListRequest {
+            inner: self.inner.list_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::apps_permissions_users::AppsPermissionsUsers::request`].
+    pub fn request(
+        &self,
+        scopes: &str,
+        trigger_id: &str,
+        user: &str,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime
+            .block_on(self.inner.request(scopes, trigger_id, user))
+    }
+}
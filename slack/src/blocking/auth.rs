@@ -0,0 +1,53 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Auth::revoke`], set with a builder.
+pub struct AuthRevokeRequest<'a> {
+    inner: crate::auth::AuthRevokeRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> AuthRevokeRequest<'a> {
+    /// Sets the `test` query parameter.
+    pub fn test(mut self, test: bool) -> Self {
+        self.inner = self.inner.test(test);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::AuthRevokeSchema>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::auth::Auth`].
+pub struct Auth {
+    inner: crate::auth::Auth,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Auth {
+    pub(crate) fn new(inner: crate::auth::Auth, runtime: Arc<tokio::runtime::Runtime>) -> Self {
+        Auth { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::auth::Auth::revoke`].
+    pub fn revoke(
+        &self,
+        test: bool,
+    ) -> ClientResult<crate::Response<crate::types::AuthRevokeSchema>> {
+        self.runtime.block_on(self.inner.revoke(test))
+    }
+    /// The blocking version of [`crate::auth::Auth::revoke_request`].
+    pub fn revoke_request(&self) -> AuthRevokeRequest<'_> {
+        AuthRevokeRequest {
+            inner: self.inner.revoke_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::auth::Auth::test`].
+    pub fn test(&self) -> ClientResult<crate::Response<crate::types::AuthTestSuccessSchema>> {
+        self.runtime.block_on(self.inner.test())
+    }
+}
@@ -0,0 +1,46 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Bots::info`], set with a builder.
+pub struct BotsInfoRequest<'a> {
+    inner: crate::bots::BotsInfoRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> BotsInfoRequest<'a> {
+    /// Sets the `bot` query parameter.
+    pub fn bot(mut self, bot: &'a str) -> Self {
+        self.inner = self.inner.bot(bot);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::BotsInfoSchema>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::bots::Bots`].
+pub struct Bots {
+    inner: crate::bots::Bots,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Bots {
+    pub(crate) fn new(inner: crate::bots::Bots, runtime: Arc<tokio::runtime::Runtime>) -> Self {
+        Bots { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::bots::Bots::info`].
+    pub fn info(&self, bot: &str) -> ClientResult<crate::Response<crate::types::BotsInfoSchema>> {
+        self.runtime.block_on(self.inner.info(bot))
+    }
+    /// The blocking version of [`crate::bots::Bots::info_request`].
+    pub fn info_request(&self) -> BotsInfoRequest<'_> {
+        BotsInfoRequest {
+            inner: self.inner.info_request(),
+            runtime: &self.runtime,
+        }
+    }
+}
@@ -0,0 +1,41 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::calls::Calls`].
+pub struct Calls {
+    inner: crate::calls::Calls,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Calls {
+    pub(crate) fn new(inner: crate::calls::Calls, runtime: Arc<tokio::runtime::Runtime>) -> Self {
+        Calls { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::calls::Calls::add`].
+    pub fn add(
+        &self,
+        body: &crate::types::CallsAddRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime.block_on(self.inner.add(body))
+    }
+    /// The blocking version of [`crate::calls::Calls::end`].
+    pub fn end(
+        &self,
+        body: &crate::types::CallsEndRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime.block_on(self.inner.end(body))
+    }
+    /// The blocking version of [`crate::calls::Calls::info`].
+    pub fn info(&self, id: &str) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime.block_on(self.inner.info(id))
+    }
+    /// The blocking version of [`crate::calls::Calls::update`].
+    pub fn update(
+        &self,
+        body: &crate::types::CallsUpdateRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime.block_on(self.inner.update(body))
+    }
+}
@@ -0,0 +1,33 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::calls_participants::CallsParticipants`].
+pub struct CallsParticipants {
+    inner: crate::calls_participants::CallsParticipants,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl CallsParticipants {
+    pub(crate) fn new(
+        inner: crate::calls_participants::CallsParticipants,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        CallsParticipants { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::calls_participants::CallsParticipants::add`].
+    pub fn add(
+        &self,
+        body: &crate::types::CallsParticipantsAddRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime.block_on(self.inner.add(body))
+    }
+    /// The blocking version of [`crate::calls_participants::CallsParticipants::remove`].
+    pub fn remove(
+        &self,
+        body: &crate::types::CallsParticipantsAddRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime.block_on(self.inner.remove(body))
+    }
+}
@@ -0,0 +1,82 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::chat::Chat`].
+pub struct Chat {
+    inner: crate::chat::Chat,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Chat {
+    pub(crate) fn new(inner: crate::chat::Chat, runtime: Arc<tokio::runtime::Runtime>) -> Self {
+        Chat { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::chat::Chat::delete`].
+    pub fn delete(
+        &self,
+        body: &crate::types::ChatDeleteRequest,
+    ) -> ClientResult<crate::Response<crate::types::ChatDeleteSuccessSchema>> {
+        self.runtime.block_on(self.inner.delete(body))
+    }
+    /// The blocking version of [`crate::chat::Chat::delete_scheduled_message`].
+    pub fn delete_scheduled_message(
+        &self,
+        body: &crate::types::ChatDeleteScheduledMessageRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime
+            .block_on(self.inner.delete_scheduled_message(body))
+    }
+    /// The blocking version of [`crate::chat::Chat::get_permalink`].
+    pub fn get_permalink(
+        &self,
+        channel: &str,
+        message_ts: &str,
+    ) -> ClientResult<crate::Response<crate::types::ChatGetPermalinkSuccessSchema>> {
+        self.runtime
+            .block_on(self.inner.get_permalink(channel, message_ts))
+    }
+    /// The blocking version of [`crate::chat::Chat::me_message`].
+    pub fn me_message(
+        &self,
+        body: &crate::types::ChatMeMessageRequest,
+    ) -> ClientResult<crate::Response<crate::types::ChatMeMessageSchema>> {
+        self.runtime.block_on(self.inner.me_message(body))
+    }
+    /// The blocking version of [`crate::chat::Chat::post_ephemeral`].
+    pub fn post_ephemeral(
+        &self,
+        body: &crate::types::ChatPostEphemeralRequest,
+    ) -> ClientResult<crate::Response<crate::types::ChatPostEphemeralSuccessSchema>> {
+        self.runtime.block_on(self.inner.post_ephemeral(body))
+    }
+    /// The blocking version of [`crate::chat::Chat::post_message`].
+    pub fn post_message(
+        &self,
+        body: &crate::types::ChatPostMessageRequest,
+    ) -> ClientResult<crate::Response<crate::types::ChatPostMessageSuccessSchema>> {
+        self.runtime.block_on(self.inner.post_message(body))
+    }
+    /// The blocking version of [`crate::chat::Chat::schedule_message`].
+    pub fn schedule_message(
+        &self,
+        body: &crate::types::ChatScheduleMessageRequest,
+    ) -> ClientResult<crate::Response<crate::types::ChatScheduleMessageSuccessSchema>> {
+        self.runtime.block_on(self.inner.schedule_message(body))
+    }
+    /// The blocking version of [`crate::chat::Chat::unfurl`].
+    pub fn unfurl(
+        &self,
+        body: &crate::types::ChatUnfurlRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime.block_on(self.inner.unfurl(body))
+    }
+    /// The blocking version of [`crate::chat::Chat::update`].
+    pub fn update(
+        &self,
+        body: &crate::types::ChatUpdateRequest,
+    ) -> ClientResult<crate::Response<crate::types::ChatUpdateSuccessSchema>> {
+        self.runtime.block_on(self.inner.update(body))
+    }
+}
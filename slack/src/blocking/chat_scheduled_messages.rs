@@ -0,0 +1,83 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`ChatScheduledMessages::list`], set with a builder.
+pub struct ChatScheduledMessagesListRequest<'a> {
+    inner: crate::chat_scheduled_messages::ChatScheduledMessagesListRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ChatScheduledMessagesListRequest<'a> {
+    /// Sets the `channel` query parameter.
+    pub fn channel(mut self, channel: &'a str) -> Self {
+        self.inner = self.inner.channel(channel);
+        self
+    }
+
+    /// Sets the `latest` query parameter.
+    pub fn latest(mut self, latest: f64) -> Self {
+        self.inner = self.inner.latest(latest);
+        self
+    }
+
+    /// Sets the `oldest` query parameter.
+    pub fn oldest(mut self, oldest: f64) -> Self {
+        self.inner = self.inner.oldest(oldest);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `cursor` query parameter.
+    pub fn cursor(mut self, cursor: &'a str) -> Self {
+        self.inner = self.inner.cursor(cursor);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::ChatScheduledMessagesListSchema>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::chat_scheduled_messages::ChatScheduledMessages`].
+pub struct ChatScheduledMessages {
+    inner: crate::chat_scheduled_messages::ChatScheduledMessages,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl ChatScheduledMessages {
+    pub(crate) fn new(
+        inner: crate::chat_scheduled_messages::ChatScheduledMessages,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        ChatScheduledMessages { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::chat_scheduled_messages::ChatScheduledMessages::list`].
+    pub fn list(
+        &self,
+        channel: &str,
+        latest: f64,
+        oldest: f64,
+        limit: i64,
+        cursor: &str,
+    ) -> ClientResult<crate::Response<crate::types::ChatScheduledMessagesListSchema>> {
+        self.runtime
+            .block_on(self.inner.list(channel, latest, oldest, limit, cursor))
+    }
+    /// The blocking version of [`crate::chat_scheduled_messages::ChatScheduledMessages::list_request`].
+    pub fn list_request(&self) -> ChatScheduledMessagesListRequest<'_> {
+        ChatScheduledMessagesListRequest {
+            inner: self.inner.list_request(),
+            runtime: &self.runtime,
+        }
+    }
+}
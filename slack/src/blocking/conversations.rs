@@ -0,0 +1,422 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Conversations::history`], set with a builder.
+pub struct ConversationsHistoryRequest<'a> {
+    inner: crate::conversations::ConversationsHistoryRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ConversationsHistoryRequest<'a> {
+    /// Sets the `channel` query parameter.
+    pub fn channel(mut self, channel: &'a str) -> Self {
+        self.inner = self.inner.channel(channel);
+        self
+    }
+
+    /// Sets the `latest` query parameter.
+    pub fn latest(mut self, latest: f64) -> Self {
+        self.inner = self.inner.latest(latest);
+        self
+    }
+
+    /// Sets the `oldest` query parameter.
+    pub fn oldest(mut self, oldest: f64) -> Self {
+        self.inner = self.inner.oldest(oldest);
+        self
+    }
+
+    /// Sets the `inclusive` query parameter.
+    pub fn inclusive(mut self, inclusive: bool) -> Self {
+        self.inner = self.inner.inclusive(inclusive);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `cursor` query parameter.
+    pub fn cursor(mut self, cursor: &'a str) -> Self {
+        self.inner = self.inner.cursor(cursor);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::ConversationsHistorySuccessSchema>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Conversations::info`], set with a builder.
+pub struct ConversationsInfoRequest<'a> {
+    inner: crate::conversations::ConversationsInfoRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ConversationsInfoRequest<'a> {
+    /// Sets the `channel` query parameter.
+    pub fn channel(mut self, channel: &'a str) -> Self {
+        self.inner = self.inner.channel(channel);
+        self
+    }
+
+    /// Sets the `include_locale` query parameter.
+    pub fn include_locale(mut self, include_locale: bool) -> Self {
+        self.inner = self.inner.include_locale(include_locale);
+        self
+    }
+
+    /// Sets the `include_num_members` query parameter.
+    pub fn include_num_members(mut self, include_num_members: bool) -> Self {
+        self.inner = self.inner.include_num_members(include_num_members);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::ConversationsInfoSuccessSchema>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Conversations::list`], set with a builder.
+pub struct ConversationsListRequest<'a> {
+    inner: crate::conversations::ConversationsListRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ConversationsListRequest<'a> {
+    /// Sets the `exclude_archived` query parameter.
+    pub fn exclude_archived(mut self, exclude_archived: bool) -> Self {
+        self.inner = self.inner.exclude_archived(exclude_archived);
+        self
+    }
+
+    /// Sets the `types` query parameter.
+    pub fn types(mut self, types: &'a str) -> Self {
+        self.inner = self.inner.types(types);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `cursor` query parameter.
+    pub fn cursor(mut self, cursor: &'a str) -> Self {
+        self.inner = self.inner.cursor(cursor);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::ConversationsListSuccessSchema>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Conversations::member`], set with a builder.
+pub struct ConversationsMemberRequest<'a> {
+    inner: crate::conversations::ConversationsMemberRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ConversationsMemberRequest<'a> {
+    /// Sets the `channel` query parameter.
+    pub fn channel(mut self, channel: &'a str) -> Self {
+        self.inner = self.inner.channel(channel);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `cursor` query parameter.
+    pub fn cursor(mut self, cursor: &'a str) -> Self {
+        self.inner = self.inner.cursor(cursor);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::ConversationsMembersSuccessSchema>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Conversations::replie`], set with a builder.
+pub struct ConversationsReplieRequest<'a> {
+    inner: crate::conversations::ConversationsReplieRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ConversationsReplieRequest<'a> {
+    /// Sets the `channel` query parameter.
+    pub fn channel(mut self, channel: &'a str) -> Self {
+        self.inner = self.inner.channel(channel);
+        self
+    }
+
+    /// Sets the `ts` query parameter.
+    pub fn ts(mut self, ts: f64) -> Self {
+        self.inner = self.inner.ts(ts);
+        self
+    }
+
+    /// Sets the `latest` query parameter.
+    pub fn latest(mut self, latest: f64) -> Self {
+        self.inner = self.inner.latest(latest);
+        self
+    }
+
+    /// Sets the `oldest` query parameter.
+    pub fn oldest(mut self, oldest: f64) -> Self {
+        self.inner = self.inner.oldest(oldest);
+        self
+    }
+
+    /// Sets the `inclusive` query parameter.
+    pub fn inclusive(mut self, inclusive: bool) -> Self {
+        self.inner = self.inner.inclusive(inclusive);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `cursor` query parameter.
+    pub fn cursor(mut self, cursor: &'a str) -> Self {
+        self.inner = self.inner.cursor(cursor);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::ConversationsRepliesSuccessSchema>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::conversations::Conversations`].
+pub struct Conversations {
+    inner: crate::conversations::Conversations,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Conversations {
+    pub(crate) fn new(
+        inner: crate::conversations::Conversations,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Conversations { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::conversations::Conversations::archive`].
+    pub fn archive(
+        &self,
+        body: &crate::types::ConversationsJoinRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime.block_on(self.inner.archive(body))
+    }
+    /// The blocking version of [`crate::conversations::Conversations::close`].
+    pub fn close(
+        &self,
+        body: &crate::types::ConversationsJoinRequest,
+    ) -> ClientResult<crate::Response<crate::types::ConversationsCloseSuccessSchema>> {
+        self.runtime.block_on(self.inner.close(body))
+    }
+    /// The blocking version of [`crate::conversations::Conversations::create`].
+    pub fn create(
+        &self,
+        body: &crate::types::ConversationsCreateRequest,
+    ) -> ClientResult<crate::Response<crate::types::ConversationsInfoSuccessSchema>> {
+        self.runtime.block_on(self.inner.create(body))
+    }
+    /// The blocking version of [`crate::conversations::Conversations::history`].
+    pub fn history(
+        &self,
+        channel: &str,
+        latest: f64,
+        oldest: f64,
+        inclusive: bool,
+        limit: i64,
+        cursor: &str,
+    ) -> ClientResult<crate::Response<crate::types::ConversationsHistorySuccessSchema>> {
+        self.runtime.block_on(
+            self.inner
+                .history(channel, latest, oldest, inclusive, limit, cursor),
+        )
+    }
+    /// The blocking version of [`crate::conversations::Conversations::history_request`].
+    pub fn history_request(&self) -> ConversationsHistoryRequest<'_> {
+        ConversationsHistoryRequest {
+            inner: self.inner.history_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::conversations::Conversations::info`].
+    pub fn info(
+        &self,
+        channel: &str,
+        include_locale: bool,
+        include_num_members: bool,
+    ) -> ClientResult<crate::Response<crate::types::ConversationsInfoSuccessSchema>> {
+        self.runtime.block_on(
+            self.inner
+                .info(channel, include_locale, include_num_members),
+        )
+    }
+    /// The blocking version of [`crate::conversations::Conversations::info_request`].
+    pub fn info_request(&self) -> ConversationsInfoRequest<'_> {
+        ConversationsInfoRequest {
+            inner: self.inner.info_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::conversations::Conversations::invite`].
+    pub fn invite(
+        &self,
+        body: &crate::types::ConversationsInviteRequest,
+    ) -> ClientResult<crate::Response<crate::types::ConversationsInfoSuccessSchema>> {
+        self.runtime.block_on(self.inner.invite(body))
+    }
+    /// The blocking version of [`crate::conversations::Conversations::join`].
+    pub fn join(
+        &self,
+        body: &crate::types::ConversationsJoinRequest,
+    ) -> ClientResult<crate::Response<crate::types::ConversationsJoinSuccessSchema>> {
+        self.runtime.block_on(self.inner.join(body))
+    }
+    /// The blocking version of [`crate::conversations::Conversations::kick`].
+    pub fn kick(
+        &self,
+        body: &crate::types::ConversationsKickRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime.block_on(self.inner.kick(body))
+    }
+    /// The blocking version of [`crate::conversations::Conversations::leave`].
+    pub fn leave(
+        &self,
+        body: &crate::types::ConversationsJoinRequest,
+    ) -> ClientResult<crate::Response<crate::types::ConversationsLeaveSuccessSchema>> {
+        self.runtime.block_on(self.inner.leave(body))
+    }
+    /// The blocking version of [`crate::conversations::Conversations::list`].
+    pub fn list(
+        &self,
+        exclude_archived: bool,
+        types: &str,
+        limit: i64,
+        cursor: &str,
+    ) -> ClientResult<crate::Response<crate::types::ConversationsListSuccessSchema>> {
+        self.runtime
+            .block_on(self.inner.list(exclude_archived, types, limit, cursor))
+    }
+    /// The blocking version of [`crate::conversations::Conversations::list_request`].
+    pub fn list_request(&self) -> ConversationsListRequest<'_> {
+        ConversationsListRequest {
+            inner: self.inner.list_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::conversations::Conversations::mark`].
+    pub fn mark(
+        &self,
+        body: &crate::types::ConversationsMarkRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime.block_on(self.inner.mark(body))
+    }
+    /// The blocking version of [`crate::conversations::Conversations::member`].
+    pub fn member(
+        &self,
+        channel: &str,
+        limit: i64,
+        cursor: &str,
+    ) -> ClientResult<crate::Response<crate::types::ConversationsMembersSuccessSchema>> {
+        self.runtime
+            .block_on(self.inner.member(channel, limit, cursor))
+    }
+    /// The blocking version of [`crate::conversations::Conversations::member_request`].
+    pub fn member_request(&self) -> ConversationsMemberRequest<'_> {
+        ConversationsMemberRequest {
+            inner: self.inner.member_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::conversations::Conversations::open`].
+    pub fn open(
+        &self,
+        body: &crate::types::ConversationsOpenRequest,
+    ) -> ClientResult<crate::Response<crate::types::ConversationsOpenSuccessSchema>> {
+        self.runtime.block_on(self.inner.open(body))
+    }
+    /// The blocking version of [`crate::conversations::Conversations::rename`].
+    pub fn rename(
+        &self,
+        body: &crate::types::ConversationsRenameRequest,
+    ) -> ClientResult<crate::Response<crate::types::ConversationsInfoSuccessSchema>> {
+        self.runtime.block_on(self.inner.rename(body))
+    }
+    /// The blocking version of [`crate::conversations::Conversations::replie`].
+    pub fn replie(
+        &self,
+        channel: &str,
+        ts: f64,
+        latest: f64,
+        oldest: f64,
+        inclusive: bool,
+        limit: i64,
+        cursor: &str,
+    ) -> ClientResult<crate::Response<crate::types::ConversationsRepliesSuccessSchema>> {
+        self.runtime.block_on(
+            self.inner
+                .replie(channel, ts, latest, oldest, inclusive, limit, cursor),
+        )
+    }
+    /// The blocking version of [`crate::conversations::Conversations::replie_request`].
+    pub fn replie_request(&self) -> ConversationsReplieRequest<'_> {
+        ConversationsReplieRequest {
+            inner: self.inner.replie_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::conversations::Conversations::set_purpose`].
+    pub fn set_purpose(
+        &self,
+        body: &crate::types::ConversationsSetPurposeRequest,
+    ) -> ClientResult<crate::Response<crate::types::ConversationsInfoSuccessSchema>> {
+        self.runtime.block_on(self.inner.set_purpose(body))
+    }
+    /// The blocking version of [`crate::conversations::Conversations::set_topic`].
+    pub fn set_topic(
+        &self,
+        body: &crate::types::ConversationsSetTopicRequest,
+    ) -> ClientResult<crate::Response<crate::types::ConversationsInfoSuccessSchema>> {
+        self.runtime.block_on(self.inner.set_topic(body))
+    }
+    /// The blocking version of [`crate::conversations::Conversations::unarchive`].
+    pub fn unarchive(
+        &self,
+        body: &crate::types::ConversationsJoinRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime.block_on(self.inner.unarchive(body))
+    }
+}
@@ -0,0 +1,24 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::dialog::Dialog`].
+pub struct Dialog {
+    inner: crate::dialog::Dialog,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Dialog {
+    pub(crate) fn new(inner: crate::dialog::Dialog, runtime: Arc<tokio::runtime::Runtime>) -> Self {
+        Dialog { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::dialog::Dialog::open`].
+    pub fn open(
+        &self,
+        dialog: &str,
+        trigger_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime.block_on(self.inner.open(dialog, trigger_id))
+    }
+}
@@ -0,0 +1,94 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Dnd::info`], set with a builder.
+pub struct DndInfoRequest<'a> {
+    inner: crate::dnd::DndInfoRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> DndInfoRequest<'a> {
+    /// Sets the `user` query parameter.
+    pub fn user(mut self, user: &'a str) -> Self {
+        self.inner = self.inner.user(user);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::DndInfoSchema>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Dnd::team_info`], set with a builder.
+pub struct DndTeamInfoRequest<'a> {
+    inner: crate::dnd::DndTeamInfoRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> DndTeamInfoRequest<'a> {
+    /// Sets the `users` query parameter.
+    pub fn users(mut self, users: &'a str) -> Self {
+        self.inner = self.inner.users(users);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::dnd::Dnd`].
+pub struct Dnd {
+    inner: crate::dnd::Dnd,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Dnd {
+    pub(crate) fn new(inner: crate::dnd::Dnd, runtime: Arc<tokio::runtime::Runtime>) -> Self {
+        Dnd { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::dnd::Dnd::end`].
+    pub fn end(&self) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime.block_on(self.inner.end())
+    }
+    /// The blocking version of [`crate::dnd::Dnd::end_snooze`].
+    pub fn end_snooze(&self) -> ClientResult<crate::Response<crate::types::DndEndSnoozeSchema>> {
+        self.runtime.block_on(self.inner.end_snooze())
+    }
+    /// The blocking version of [`crate::dnd::Dnd::info`].
+    pub fn info(&self, user: &str) -> ClientResult<crate::Response<crate::types::DndInfoSchema>> {
+        self.runtime.block_on(self.inner.info(user))
+    }
+    /// The blocking version of [`crate::dnd::Dnd::info_request`].
+    pub fn info_request(&self) -> DndInfoRequest<'_> {
+        DndInfoRequest {
+            inner: self.inner.info_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::dnd::Dnd::set_snooze`].
+    pub fn set_snooze(
+        &self,
+        body: &crate::types::DndSetSnoozeRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndSetSnoozeSchema>> {
+        self.runtime.block_on(self.inner.set_snooze(body))
+    }
+    /// The blocking version of [`crate::dnd::Dnd::team_info`].
+    pub fn team_info(
+        &self,
+        users: &str,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime.block_on(self.inner.team_info(users))
+    }
+    /// The blocking version of [`crate::dnd::Dnd::team_info_request`].
+    pub fn team_info_request(&self) -> DndTeamInfoRequest<'_> {
+        DndTeamInfoRequest {
+            inner: self.inner.team_info_request(),
+            runtime: &self.runtime,
+        }
+    }
+}
@@ -0,0 +1,20 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::emoji::Emoji`].
+pub struct Emoji {
+    inner: crate::emoji::Emoji,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Emoji {
+    pub(crate) fn new(inner: crate::emoji::Emoji, runtime: Arc<tokio::runtime::Runtime>) -> Self {
+        Emoji { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::emoji::Emoji::list`].
+    pub fn list(&self) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime.block_on(self.inner.list())
+    }
+}
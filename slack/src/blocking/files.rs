@@ -0,0 +1,199 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Files::info`], set with a builder.
+pub struct FilesInfoRequest<'a> {
+    inner: crate::files::FilesInfoRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> FilesInfoRequest<'a> {
+    /// Sets the `file` query parameter.
+    pub fn file(mut self, file: &'a str) -> Self {
+        self.inner = self.inner.file(file);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: &'a str) -> Self {
+        self.inner = self.inner.count(count);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: &'a str) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `cursor` query parameter.
+    pub fn cursor(mut self, cursor: &'a str) -> Self {
+        self.inner = self.inner.cursor(cursor);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::FilesInfoSchema>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Files::list`], set with a builder.
+pub struct FilesListRequest<'a> {
+    inner: crate::files::FilesListRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> FilesListRequest<'a> {
+    /// Sets the `user` query parameter.
+    pub fn user(mut self, user: &'a str) -> Self {
+        self.inner = self.inner.user(user);
+        self
+    }
+
+    /// Sets the `channel` query parameter.
+    pub fn channel(mut self, channel: &'a str) -> Self {
+        self.inner = self.inner.channel(channel);
+        self
+    }
+
+    /// Sets the `ts_from` query parameter.
+    pub fn ts_from(mut self, ts_from: f64) -> Self {
+        self.inner = self.inner.ts_from(ts_from);
+        self
+    }
+
+    /// Sets the `ts_to` query parameter.
+    pub fn ts_to(mut self, ts_to: f64) -> Self {
+        self.inner = self.inner.ts_to(ts_to);
+        self
+    }
+
+    /// Sets the `types` query parameter.
+    pub fn types(mut self, types: &'a str) -> Self {
+        self.inner = self.inner.types(types);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: &'a str) -> Self {
+        self.inner = self.inner.count(count);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: &'a str) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sets the `show_files_hidden_by_limit` query parameter.
+    pub fn show_files_hidden_by_limit(mut self, show_files_hidden_by_limit: bool) -> Self {
+        self.inner = self
+            .inner
+            .show_files_hidden_by_limit(show_files_hidden_by_limit);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::FilesListSchema>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::files::Files`].
+pub struct Files {
+    inner: crate::files::Files,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Files {
+    pub(crate) fn new(inner: crate::files::Files, runtime: Arc<tokio::runtime::Runtime>) -> Self {
+        Files { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::files::Files::delete`].
+    pub fn delete(
+        &self,
+        body: &crate::types::FilesDeleteRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime.block_on(self.inner.delete(body))
+    }
+    /// The blocking version of [`crate::files::Files::info`].
+    pub fn info(
+        &self,
+        file: &str,
+        count: &str,
+        page: &str,
+        limit: i64,
+        cursor: &str,
+    ) -> ClientResult<crate::Response<crate::types::FilesInfoSchema>> {
+        self.runtime
+            .block_on(self.inner.info(file, count, page, limit, cursor))
+    }
+    /// The blocking version of [`crate::files::Files::info_request`].
+    pub fn info_request(&self) -> FilesInfoRequest<'_> {
+        FilesInfoRequest {
+            inner: self.inner.info_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::files::Files::list`].
+    pub fn list(
+        &self,
+        user: &str,
+        channel: &str,
+        ts_from: f64,
+        ts_to: f64,
+        types: &str,
+        count: &str,
+        page: &str,
+        show_files_hidden_by_limit: bool,
+    ) -> ClientResult<crate::Response<crate::types::FilesListSchema>> {
+        self.runtime.block_on(self.inner.list(
+            user,
+            channel,
+            ts_from,
+            ts_to,
+            types,
+            count,
+            page,
+            show_files_hidden_by_limit,
+        ))
+    }
+    /// The blocking version of [`crate::files::Files::list_request`].
+    pub fn list_request(&self) -> FilesListRequest<'_> {
+        FilesListRequest {
+            inner: self.inner.list_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::files::Files::revoke_public_url`].
+    pub fn revoke_public_url(
+        &self,
+        body: &crate::types::FilesDeleteRequest,
+    ) -> ClientResult<crate::Response<crate::types::FilesUploadSchema>> {
+        self.runtime.block_on(self.inner.revoke_public_url(body))
+    }
+    /// The blocking version of [`crate::files::Files::shared_public_url`].
+    pub fn shared_public_url(
+        &self,
+        body: &crate::types::FilesDeleteRequest,
+    ) -> ClientResult<crate::Response<crate::types::FilesUploadSchema>> {
+        self.runtime.block_on(self.inner.shared_public_url(body))
+    }
+    /// The blocking version of [`crate::files::Files::upload`].
+    pub fn upload(
+        &self,
+        body: &crate::types::FilesUploadRequest,
+    ) -> ClientResult<crate::Response<crate::types::FilesUploadSchema>> {
+        self.runtime.block_on(self.inner.upload(body))
+    }
+}
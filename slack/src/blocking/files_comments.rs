@@ -0,0 +1,26 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::files_comments::FilesComments`].
+pub struct FilesComments {
+    inner: crate::files_comments::FilesComments,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl FilesComments {
+    pub(crate) fn new(
+        inner: crate::files_comments::FilesComments,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        FilesComments { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::files_comments::FilesComments::delete`].
+    pub fn delete(
+        &self,
+        body: &crate::types::FilesCommentsDeleteRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime.block_on(self.inner.delete(body))
+    }
+}
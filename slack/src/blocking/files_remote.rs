@@ -0,0 +1,190 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`FilesRemote::info`], set with a builder.
+pub struct FilesRemoteInfoRequest<'a> {
+    inner: crate::files_remote::FilesRemoteInfoRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> FilesRemoteInfoRequest<'a> {
+    /// Sets the `file` query parameter.
+    pub fn file(mut self, file: &'a str) -> Self {
+        self.inner = self.inner.file(file);
+        self
+    }
+
+    /// Sets the `external_id` query parameter.
+    pub fn external_id(mut self, external_id: &'a str) -> Self {
+        self.inner = self.inner.external_id(external_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`FilesRemote::list`], set with a builder.
+pub struct FilesRemoteListRequest<'a> {
+    inner: crate::files_remote::FilesRemoteListRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> FilesRemoteListRequest<'a> {
+    /// Sets the `channel` query parameter.
+    pub fn channel(mut self, channel: &'a str) -> Self {
+        self.inner = self.inner.channel(channel);
+        self
+    }
+
+    /// Sets the `ts_from` query parameter.
+    pub fn ts_from(mut self, ts_from: f64) -> Self {
+        self.inner = self.inner.ts_from(ts_from);
+        self
+    }
+
+    /// Sets the `ts_to` query parameter.
+    pub fn ts_to(mut self, ts_to: f64) -> Self {
+        self.inner = self.inner.ts_to(ts_to);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `cursor` query parameter.
+    pub fn cursor(mut self, cursor: &'a str) -> Self {
+        self.inner = self.inner.cursor(cursor);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`FilesRemote::share`], set with a builder.
+pub struct FilesRemoteShareRequest<'a> {
+    inner: crate::files_remote::FilesRemoteShareRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> FilesRemoteShareRequest<'a> {
+    /// Sets the `file` query parameter.
+    pub fn file(mut self, file: &'a str) -> Self {
+        self.inner = self.inner.file(file);
+        self
+    }
+
+    /// Sets the `external_id` query parameter.
+    pub fn external_id(mut self, external_id: &'a str) -> Self {
+        self.inner = self.inner.external_id(external_id);
+        self
+    }
+
+    /// Sets the `channels` query parameter.
+    pub fn channels(mut self, channels: &'a str) -> Self {
+        self.inner = self.inner.channels(channels);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::files_remote::FilesRemote`].
+pub struct FilesRemote {
+    inner: crate::files_remote::FilesRemote,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl FilesRemote {
+    pub(crate) fn new(
+        inner: crate::files_remote::FilesRemote,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        FilesRemote { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::files_remote::FilesRemote::add`].
+    pub fn add(
+        &self,
+        body: &crate::types::FilesRemoteAddRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime.block_on(self.inner.add(body))
+    }
+    /// The blocking version of [`crate::files_remote::FilesRemote::info`].
+    pub fn info(
+        &self,
+        file: &str,
+        external_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime.block_on(self.inner.info(file, external_id))
+    }
+    /// The blocking version of [`crate::files_remote::FilesRemote::info_request`].
+    pub fn info_request(&self) -> FilesRemoteInfoRequest<'_> {
+        FilesRemoteInfoRequest {
+            inner: self.inner.info_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::files_remote::FilesRemote::list`].
+    pub fn list(
+        &self,
+        channel: &str,
+        ts_from: f64,
+        ts_to: f64,
+        limit: i64,
+        cursor: &str,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime
+            .block_on(self.inner.list(channel, ts_from, ts_to, limit, cursor))
+    }
+    /// The blocking version of [`crate::files_remote::FilesRemote::list_request`].
+    pub fn list_request(&self) -> FilesRemoteListRequest<'_> {
+        FilesRemoteListRequest {
+            inner: self.inner.list_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::files_remote::FilesRemote::remove`].
+    pub fn remove(
+        &self,
+        body: &crate::types::FilesRemoteRemoveRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime.block_on(self.inner.remove(body))
+    }
+    /// The blocking version of [`crate::files_remote::FilesRemote::share`].
+    pub fn share(
+        &self,
+        file: &str,
+        external_id: &str,
+        channels: &str,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime
+            .block_on(self.inner.share(file, external_id, channels))
+    }
+    /// The blocking version of [`crate::files_remote::FilesRemote::share_request`].
+    pub fn share_request(&self) -> FilesRemoteShareRequest<'_> {
+        FilesRemoteShareRequest {
+            inner: self.inner.share_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::files_remote::FilesRemote::update`].
+    pub fn update(
+        &self,
+        body: &crate::types::FilesRemoteUpdateRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime.block_on(self.inner.update(body))
+    }
+}
@@ -0,0 +1,63 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Migration::exchange`], set with a builder.
+pub struct MigrationExchangeRequest<'a> {
+    inner: crate::migration::MigrationExchangeRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> MigrationExchangeRequest<'a> {
+    /// Sets the `team_id` query parameter.
+    pub fn team_id(mut self, team_id: &'a str) -> Self {
+        self.inner = self.inner.team_id(team_id);
+        self
+    }
+
+    /// Sets the `to_old` query parameter.
+    pub fn to_old(mut self, to_old: bool) -> Self {
+        self.inner = self.inner.to_old(to_old);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::MigrationExchangeSuccessSchema>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::migration::Migration`].
+pub struct Migration {
+    inner: crate::migration::Migration,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Migration {
+    pub(crate) fn new(
+        inner: crate::migration::Migration,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Migration { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::migration::Migration::exchange`].
+    pub fn exchange(
+        &self,
+        users: &str,
+        team_id: &str,
+        to_old: bool,
+    ) -> ClientResult<crate::Response<crate::types::MigrationExchangeSuccessSchema>> {
+        self.runtime
+            .block_on(self.inner.exchange(users, team_id, to_old))
+    }
+    /// The blocking version of [`crate::migration::Migration::exchange_request`].
+    pub fn exchange_request<'a>(&'a self, users: &'a str) -> MigrationExchangeRequest<'a> {
+        MigrationExchangeRequest {
+            inner: self.inner.exchange_request(users),
+            runtime: &self.runtime,
+        }
+    }
+}
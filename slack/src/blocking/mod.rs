@@ -0,0 +1,435 @@
+//! A blocking client, for the programs that are not async.
+//!
+//! It has the functions of the async [`Client`](crate::Client), which it runs on a
+//! runtime of its own, so they must not be called from within an async runtime. The
+//! `*_stream` functions are left out, the `*_all` ones returning all the pages at once.
+
+use std::{future::Future, sync::Arc};
+
+pub mod admin_apps;
+pub mod admin_apps_approved;
+pub mod admin_apps_requests;
+pub mod admin_apps_restricted;
+pub mod admin_conversations;
+pub mod admin_conversations_ekm;
+pub mod admin_conversations_restrict_access;
+pub mod admin_emoji;
+pub mod admin_invite_requests;
+pub mod admin_invite_requests_approved;
+pub mod admin_invite_requests_denied;
+pub mod admin_teams;
+pub mod admin_teams_admins;
+pub mod admin_teams_owners;
+pub mod admin_teams_settings;
+pub mod admin_usergroups;
+pub mod admin_users;
+pub mod admin_users_session;
+pub mod api;
+pub mod apps;
+pub mod apps_event_authorizations;
+pub mod apps_permissions;
+pub mod apps_permissions_resources;
+pub mod apps_permissions_scopes;
+pub mod apps_permissions_users;
+pub mod auth;
+pub mod bots;
+pub mod calls;
+pub mod calls_participants;
+pub mod chat;
+pub mod chat_scheduled_messages;
+pub mod conversations;
+pub mod dialog;
+pub mod dnd;
+pub mod emoji;
+pub mod files;
+pub mod files_comments;
+pub mod files_remote;
+pub mod migration;
+pub mod oauth;
+pub mod oauth_v_2;
+pub mod pins;
+pub mod reactions;
+pub mod reminders;
+pub mod rtm;
+pub mod search;
+pub mod stars;
+pub mod team;
+pub mod team_profile;
+pub mod usergroups;
+pub mod usergroups_users;
+pub mod users;
+pub mod users_profile;
+pub mod views;
+pub mod workflows;
+
+/// A blocking client, sending its requests with an async [`Client`](crate::Client).
+#[derive(Clone)]
+pub struct Client {
+    inner: crate::Client,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Client {
+    /// Wraps an async client, creating the runtime its requests run on.
+    pub fn new(client: crate::Client) -> std::io::Result<Self> {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()?;
+
+        Ok(Client {
+            inner: client,
+            runtime: Arc::new(runtime),
+        })
+    }
+
+    /// Returns the async client the requests are sent with.
+    pub fn inner(&self) -> &crate::Client {
+        &self.inner
+    }
+
+    /// Runs a future to completion on the runtime of the client, for the async
+    /// functions that have no blocking version.
+    pub fn block_on<F: Future>(&self, future: F) -> F::Output {
+        self.runtime.block_on(future)
+    }
+
+    /// The blocking version of [`crate::Client::admin_apps`].
+    pub fn admin_apps(&self) -> admin_apps::AdminApps {
+        admin_apps::AdminApps::new(self.inner.admin_apps(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::admin_apps_approved`].
+    pub fn admin_apps_approved(&self) -> admin_apps_approved::AdminAppsApproved {
+        admin_apps_approved::AdminAppsApproved::new(
+            self.inner.admin_apps_approved(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::admin_apps_requests`].
+    pub fn admin_apps_requests(&self) -> admin_apps_requests::AdminAppsRequests {
+        admin_apps_requests::AdminAppsRequests::new(
+            self.inner.admin_apps_requests(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::admin_apps_restricted`].
+    pub fn admin_apps_restricted(&self) -> admin_apps_restricted::AdminAppsRestricted {
+        admin_apps_restricted::AdminAppsRestricted::new(
+            self.inner.admin_apps_restricted(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::admin_conversations`].
+    pub fn admin_conversations(&self) -> admin_conversations::AdminConversations {
+        admin_conversations::AdminConversations::new(
+            self.inner.admin_conversations(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::admin_conversations_ekm`].
+    pub fn admin_conversations_ekm(&self) -> admin_conversations_ekm::AdminConversationsEkm {
+        admin_conversations_ekm::AdminConversationsEkm::new(
+            self.inner.admin_conversations_ekm(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::admin_conversations_restrict_access`].
+    pub fn admin_conversations_restrict_access(
+        &self,
+    ) -> admin_conversations_restrict_access::AdminConversationsRestrictAccess {
+        admin_conversations_restrict_access::AdminConversationsRestrictAccess::new(
+            self.inner.admin_conversations_restrict_access(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::admin_emoji`].
+    pub fn admin_emoji(&self) -> admin_emoji::AdminEmoji {
+        admin_emoji::AdminEmoji::new(self.inner.admin_emoji(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::admin_invite_requests`].
+    pub fn admin_invite_requests(&self) -> admin_invite_requests::AdminInviteRequests {
+        admin_invite_requests::AdminInviteRequests::new(
+            self.inner.admin_invite_requests(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::admin_invite_requests_approved`].
+    pub fn admin_invite_requests_approved(
+        &self,
+    ) -> admin_invite_requests_approved::AdminInviteRequestsApproved {
+        admin_invite_requests_approved::AdminInviteRequestsApproved::new(
+            self.inner.admin_invite_requests_approved(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::admin_invite_requests_denied`].
+    pub fn admin_invite_requests_denied(
+        &self,
+    ) -> admin_invite_requests_denied::AdminInviteRequestsDenied {
+        admin_invite_requests_denied::AdminInviteRequestsDenied::new(
+            self.inner.admin_invite_requests_denied(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::admin_teams`].
+    pub fn admin_teams(&self) -> admin_teams::AdminTeams {
+        admin_teams::AdminTeams::new(self.inner.admin_teams(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::admin_teams_admins`].
+    pub fn admin_teams_admins(&self) -> admin_teams_admins::AdminTeamsAdmins {
+        admin_teams_admins::AdminTeamsAdmins::new(
+            self.inner.admin_teams_admins(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::admin_teams_owners`].
+    pub fn admin_teams_owners(&self) -> admin_teams_owners::AdminTeamsOwners {
+        admin_teams_owners::AdminTeamsOwners::new(
+            self.inner.admin_teams_owners(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::admin_teams_settings`].
+    pub fn admin_teams_settings(&self) -> admin_teams_settings::AdminTeamsSettings {
+        admin_teams_settings::AdminTeamsSettings::new(
+            self.inner.admin_teams_settings(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::admin_usergroups`].
+    pub fn admin_usergroups(&self) -> admin_usergroups::AdminUsergroups {
+        admin_usergroups::AdminUsergroups::new(self.inner.admin_usergroups(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::admin_users`].
+    pub fn admin_users(&self) -> admin_users::AdminUsers {
+        admin_users::AdminUsers::new(self.inner.admin_users(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::admin_users_session`].
+    pub fn admin_users_session(&self) -> admin_users_session::AdminUsersSession {
+        admin_users_session::AdminUsersSession::new(
+            self.inner.admin_users_session(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::api`].
+    pub fn api(&self) -> api::Api {
+        api::Api::new(self.inner.api(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::apps`].
+    pub fn apps(&self) -> apps::Apps {
+        apps::Apps::new(self.inner.apps(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::apps_event_authorizations`].
+    pub fn apps_event_authorizations(&self) -> apps_event_authorizations::AppsEventAuthorizations {
+        apps_event_authorizations::AppsEventAuthorizations::new(
+            self.inner.apps_event_authorizations(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::apps_permissions`].
+    pub fn apps_permissions(&self) -> apps_permissions::AppsPermissions {
+        apps_permissions::AppsPermissions::new(self.inner.apps_permissions(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::apps_permissions_resources`].
+    pub fn apps_permissions_resources(
+        &self,
+    ) -> apps_permissions_resources::AppsPermissionsResources {
+        apps_permissions_resources::AppsPermissionsResources::new(
+            self.inner.apps_permissions_resources(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::apps_permissions_scopes`].
+    pub fn apps_permissions_scopes(&self) -> apps_permissions_scopes::AppsPermissionsScopes {
+        apps_permissions_scopes::AppsPermissionsScopes::new(
+            self.inner.apps_permissions_scopes(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::apps_permissions_users`].
+    pub fn apps_permissions_users(&self) -> apps_permissions_users::AppsPermissionsUsers {
+        apps_permissions_users::AppsPermissionsUsers::new(
+            self.inner.apps_permissions_users(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::auth`].
+    pub fn auth(&self) -> auth::Auth {
+        auth::Auth::new(self.inner.auth(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::bots`].
+    pub fn bots(&self) -> bots::Bots {
+        bots::Bots::new(self.inner.bots(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::calls`].
+    pub fn calls(&self) -> calls::Calls {
+        calls::Calls::new(self.inner.calls(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::calls_participants`].
+    pub fn calls_participants(&self) -> calls_participants::CallsParticipants {
+        calls_participants::CallsParticipants::new(
+            self.inner.calls_participants(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::chat`].
+    pub fn chat(&self) -> chat::Chat {
+        chat::Chat::new(self.inner.chat(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::chat_scheduled_messages`].
+    pub fn chat_scheduled_messages(&self) -> chat_scheduled_messages::ChatScheduledMessages {
+        chat_scheduled_messages::ChatScheduledMessages::new(
+            self.inner.chat_scheduled_messages(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::conversations`].
+    pub fn conversations(&self) -> conversations::Conversations {
+        conversations::Conversations::new(self.inner.conversations(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::dialog`].
+    pub fn dialog(&self) -> dialog::Dialog {
+        dialog::Dialog::new(self.inner.dialog(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::dnd`].
+    pub fn dnd(&self) -> dnd::Dnd {
+        dnd::Dnd::new(self.inner.dnd(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::emoji`].
+    pub fn emoji(&self) -> emoji::Emoji {
+        emoji::Emoji::new(self.inner.emoji(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::files`].
+    pub fn files(&self) -> files::Files {
+        files::Files::new(self.inner.files(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::files_comments`].
+    pub fn files_comments(&self) -> files_comments::FilesComments {
+        files_comments::FilesComments::new(self.inner.files_comments(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::files_remote`].
+    pub fn files_remote(&self) -> files_remote::FilesRemote {
+        files_remote::FilesRemote::new(self.inner.files_remote(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::migration`].
+    pub fn migration(&self) -> migration::Migration {
+        migration::Migration::new(self.inner.migration(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::oauth`].
+    pub fn oauth(&self) -> oauth::Oauth {
+        oauth::Oauth::new(self.inner.oauth(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::oauth_v_2`].
+    pub fn oauth_v_2(&self) -> oauth_v_2::OauthV2 {
+        oauth_v_2::OauthV2::new(self.inner.oauth_v_2(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::pins`].
+    pub fn pins(&self) -> pins::Pins {
+        pins::Pins::new(self.inner.pins(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::reactions`].
+    pub fn reactions(&self) -> reactions::Reactions {
+        reactions::Reactions::new(self.inner.reactions(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::reminders`].
+    pub fn reminders(&self) -> reminders::Reminders {
+        reminders::Reminders::new(self.inner.reminders(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::rtm`].
+    pub fn rtm(&self) -> rtm::Rtm {
+        rtm::Rtm::new(self.inner.rtm(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::search`].
+    pub fn search(&self) -> search::Search {
+        search::Search::new(self.inner.search(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::stars`].
+    pub fn stars(&self) -> stars::Stars {
+        stars::Stars::new(self.inner.stars(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::team`].
+    pub fn team(&self) -> team::Team {
+        team::Team::new(self.inner.team(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::team_profile`].
+    pub fn team_profile(&self) -> team_profile::TeamProfile {
+        team_profile::TeamProfile::new(self.inner.team_profile(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::usergroups`].
+    pub fn usergroups(&self) -> usergroups::Usergroups {
+        usergroups::Usergroups::new(self.inner.usergroups(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::usergroups_users`].
+    pub fn usergroups_users(&self) -> usergroups_users::UsergroupsUsers {
+        usergroups_users::UsergroupsUsers::new(self.inner.usergroups_users(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::users`].
+    pub fn users(&self) -> users::Users {
+        users::Users::new(self.inner.users(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::users_profile`].
+    pub fn users_profile(&self) -> users_profile::UsersProfile {
+        users_profile::UsersProfile::new(self.inner.users_profile(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::views`].
+    pub fn views(&self) -> views::Views {
+        views::Views::new(self.inner.views(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::workflows`].
+    pub fn workflows(&self) -> workflows::Workflows {
+        workflows::Workflows::new(self.inner.workflows(), self.runtime.clone())
+    }
+}
@@ -0,0 +1,150 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Oauth::access`], set with a builder.
+pub struct OauthAccessRequest<'a> {
+    inner: crate::oauth::OauthAccessRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OauthAccessRequest<'a> {
+    /// Sets the `client_id` query parameter.
+    pub fn client_id(mut self, client_id: &'a str) -> Self {
+        self.inner = self.inner.client_id(client_id);
+        self
+    }
+
+    /// Sets the `client_secret` query parameter.
+    pub fn client_secret(mut self, client_secret: &'a str) -> Self {
+        self.inner = self.inner.client_secret(client_secret);
+        self
+    }
+
+    /// Sets the `code` query parameter.
+    pub fn code(mut self, code: &'a str) -> Self {
+        self.inner = self.inner.code(code);
+        self
+    }
+
+    /// Sets the `redirect_uri` query parameter.
+    pub fn redirect_uri(mut self, redirect_uri: &'a str) -> Self {
+        self.inner = self.inner.redirect_uri(redirect_uri);
+        self
+    }
+
+    /// Sets the `single_channel` query parameter.
+    pub fn single_channel(mut self, single_channel: bool) -> Self {
+        self.inner = self.inner.single_channel(single_channel);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Oauth::token`], set with a builder.
+pub struct OauthTokenRequest<'a> {
+    inner: crate::oauth::OauthTokenRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OauthTokenRequest<'a> {
+    /// Sets the `client_id` query parameter.
+    pub fn client_id(mut self, client_id: &'a str) -> Self {
+        self.inner = self.inner.client_id(client_id);
+        self
+    }
+
+    /// Sets the `client_secret` query parameter.
+    pub fn client_secret(mut self, client_secret: &'a str) -> Self {
+        self.inner = self.inner.client_secret(client_secret);
+        self
+    }
+
+    /// Sets the `code` query parameter.
+    pub fn code(mut self, code: &'a str) -> Self {
+        self.inner = self.inner.code(code);
+        self
+    }
+
+    /// Sets the `redirect_uri` query parameter.
+    pub fn redirect_uri(mut self, redirect_uri: &'a str) -> Self {
+        self.inner = self.inner.redirect_uri(redirect_uri);
+        self
+    }
+
+    /// Sets the `single_channel` query parameter.
+    pub fn single_channel(mut self, single_channel: bool) -> Self {
+        self.inner = self.inner.single_channel(single_channel);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::oauth::Oauth`].
+pub struct Oauth {
+    inner: crate::oauth::Oauth,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Oauth {
+    pub(crate) fn new(inner: crate::oauth::Oauth, runtime: Arc<tokio::runtime::Runtime>) -> Self {
+        Oauth { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::oauth::Oauth::access`].
+    pub fn access(
+        &self,
+        client_id: &str,
+        client_secret: &str,
+        code: &str,
+        redirect_uri: &str,
+        single_channel: bool,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime.block_on(self.inner.access(
+            client_id,
+            client_secret,
+            code,
+            redirect_uri,
+            single_channel,
+        ))
+    }
+    /// The blocking version of [`crate::oauth::Oauth::access_request`].
+    pub fn access_request(&self) -> OauthAccessRequest<'_> {
+        OauthAccessRequest {
+            inner: self.inner.access_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::oauth::Oauth::token`].
+    pub fn token(
+        &self,
+        client_id: &str,
+        client_secret: &str,
+        code: &str,
+        redirect_uri: &str,
+        single_channel: bool,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime.block_on(self.inner.token(
+            client_id,
+            client_secret,
+            code,
+            redirect_uri,
+            single_channel,
+        ))
+    }
+    /// The blocking version of [`crate::oauth::Oauth::token_request`].
+    pub fn token_request(&self) -> OauthTokenRequest<'_> {
+        OauthTokenRequest {
+            inner: self.inner.token_request(),
+            runtime: &self.runtime,
+        }
+    }
+}
@@ -0,0 +1,70 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`OauthV2::oauth_access`], set with a builder.
+pub struct OauthV2OauthAccessRequest<'a> {
+    inner: crate::oauth_v_2::OauthV2OauthAccessRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OauthV2OauthAccessRequest<'a> {
+    /// Sets the `client_id` query parameter.
+    pub fn client_id(mut self, client_id: &'a str) -> Self {
+        self.inner = self.inner.client_id(client_id);
+        self
+    }
+
+    /// Sets the `client_secret` query parameter.
+    pub fn client_secret(mut self, client_secret: &'a str) -> Self {
+        self.inner = self.inner.client_secret(client_secret);
+        self
+    }
+
+    /// Sets the `redirect_uri` query parameter.
+    pub fn redirect_uri(mut self, redirect_uri: &'a str) -> Self {
+        self.inner = self.inner.redirect_uri(redirect_uri);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::oauth_v_2::OauthV2`].
+pub struct OauthV2 {
+    inner: crate::oauth_v_2::OauthV2,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl OauthV2 {
+    pub(crate) fn new(
+        inner: crate::oauth_v_2::OauthV2,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        OauthV2 { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::oauth_v_2::OauthV2::oauth_access`].
+    pub fn oauth_access(
+        &self,
+        client_id: &str,
+        client_secret: &str,
+        code: &str,
+        redirect_uri: &str,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime.block_on(
+            self.inner
+                .oauth_access(client_id, client_secret, code, redirect_uri),
+        )
+    }
+    /// The blocking version of [`crate::oauth_v_2::OauthV2::oauth_access_request`].
+    pub fn oauth_access_request<'a>(&'a self, code: &'a str) -> OauthV2OauthAccessRequest<'a> {
+        OauthV2OauthAccessRequest {
+            inner: self.inner.oauth_access_request(code),
+            runtime: &self.runtime,
+        }
+    }
+}
@@ -0,0 +1,44 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::pins::Pins`].
+pub struct Pins {
+    inner: crate::pins::Pins,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Pins {
+    pub(crate) fn new(inner: crate::pins::Pins, runtime: Arc<tokio::runtime::Runtime>) -> Self {
+        Pins { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::pins::Pins::add`].
+    pub fn add(
+        &self,
+        body: &crate::types::PinsAddRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime.block_on(self.inner.add(body))
+    }
+    /// The blocking version of [`crate::pins::Pins::list`].
+    pub fn list(
+        &self,
+        channel: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::PinsListResponseAnyOf>>> {
+        self.runtime.block_on(self.inner.list(channel))
+    }
+    /// The blocking version of [`crate::pins::Pins::list_all`].
+    pub fn list_all(
+        &self,
+        channel: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::PinsListResponseAnyOf>>> {
+        self.runtime.block_on(self.inner.list_all(channel))
+    }
+    /// The blocking version of [`crate::pins::Pins::remove`].
+    pub fn remove(
+        &self,
+        body: &crate::types::PinsAddRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime.block_on(self.inner.remove(body))
+    }
+}
@@ -0,0 +1,164 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Reactions::get`], set with a builder.
+pub struct ReactionsGetRequest<'a> {
+    inner: crate::reactions::ReactionsGetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ReactionsGetRequest<'a> {
+    /// Sets the `channel` query parameter.
+    pub fn channel(mut self, channel: &'a str) -> Self {
+        self.inner = self.inner.channel(channel);
+        self
+    }
+
+    /// Sets the `file` query parameter.
+    pub fn file(mut self, file: &'a str) -> Self {
+        self.inner = self.inner.file(file);
+        self
+    }
+
+    /// Sets the `file_comment` query parameter.
+    pub fn file_comment(mut self, file_comment: &'a str) -> Self {
+        self.inner = self.inner.file_comment(file_comment);
+        self
+    }
+
+    /// Sets the `full` query parameter.
+    pub fn full(mut self, full: bool) -> Self {
+        self.inner = self.inner.full(full);
+        self
+    }
+
+    /// Sets the `timestamp` query parameter.
+    pub fn timestamp(mut self, timestamp: &'a str) -> Self {
+        self.inner = self.inner.timestamp(timestamp);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::Fields>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Reactions::list`], set with a builder.
+pub struct ReactionsListRequest<'a> {
+    inner: crate::reactions::ReactionsListRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ReactionsListRequest<'a> {
+    /// Sets the `user` query parameter.
+    pub fn user(mut self, user: &'a str) -> Self {
+        self.inner = self.inner.user(user);
+        self
+    }
+
+    /// Sets the `full` query parameter.
+    pub fn full(mut self, full: bool) -> Self {
+        self.inner = self.inner.full(full);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.inner = self.inner.count(count);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sets the `cursor` query parameter.
+    pub fn cursor(mut self, cursor: &'a str) -> Self {
+        self.inner = self.inner.cursor(cursor);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::ReactionsListSchema>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::reactions::Reactions`].
+pub struct Reactions {
+    inner: crate::reactions::Reactions,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Reactions {
+    pub(crate) fn new(
+        inner: crate::reactions::Reactions,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Reactions { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::reactions::Reactions::add`].
+    pub fn add(
+        &self,
+        body: &crate::types::ReactionsAddRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime.block_on(self.inner.add(body))
+    }
+    /// The blocking version of [`crate::reactions::Reactions::get`].
+    pub fn get(
+        &self,
+        channel: &str,
+        file: &str,
+        file_comment: &str,
+        full: bool,
+        timestamp: &str,
+    ) -> ClientResult<crate::Response<crate::types::Fields>> {
+        self.runtime
+            .block_on(self.inner.get(channel, file, file_comment, full, timestamp))
+    }
+    /// The blocking version of [`crate::reactions::Reactions::get_request`].
+    pub fn get_request(&self) -> ReactionsGetRequest<'_> {
+        ReactionsGetRequest {
+            inner: self.inner.get_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::reactions::Reactions::list`].
+    pub fn list(
+        &self,
+        user: &str,
+        full: bool,
+        count: i64,
+        page: i64,
+        cursor: &str,
+        limit: i64,
+    ) -> ClientResult<crate::Response<crate::types::ReactionsListSchema>> {
+        self.runtime
+            .block_on(self.inner.list(user, full, count, page, cursor, limit))
+    }
+    /// The blocking version of [`crate::reactions::Reactions::list_request`].
+    pub fn list_request(&self) -> ReactionsListRequest<'_> {
+        ReactionsListRequest {
+            inner: self.inner.list_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::reactions::Reactions::remove`].
+    pub fn remove(
+        &self,
+        body: &crate::types::ReactionsRemoveRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime.block_on(self.inner.remove(body))
+    }
+}
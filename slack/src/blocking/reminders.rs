@@ -0,0 +1,77 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Reminders::info`], set with a builder.
+pub struct RemindersInfoRequest<'a> {
+    inner: crate::reminders::RemindersInfoRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> RemindersInfoRequest<'a> {
+    /// Sets the `reminder` query parameter.
+    pub fn reminder(mut self, reminder: &'a str) -> Self {
+        self.inner = self.inner.reminder(reminder);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::RemindersAddSchema>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::reminders::Reminders`].
+pub struct Reminders {
+    inner: crate::reminders::Reminders,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Reminders {
+    pub(crate) fn new(
+        inner: crate::reminders::Reminders,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Reminders { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::reminders::Reminders::add`].
+    pub fn add(
+        &self,
+        body: &crate::types::RemindersAddRequest,
+    ) -> ClientResult<crate::Response<crate::types::RemindersAddSchema>> {
+        self.runtime.block_on(self.inner.add(body))
+    }
+    /// The blocking version of [`crate::reminders::Reminders::complete`].
+    pub fn complete(
+        &self,
+        body: &crate::types::RemindersDeleteRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime.block_on(self.inner.complete(body))
+    }
+    /// The blocking version of [`crate::reminders::Reminders::delete`].
+    pub fn delete(
+        &self,
+        body: &crate::types::RemindersDeleteRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime.block_on(self.inner.delete(body))
+    }
+    /// The blocking version of [`crate::reminders::Reminders::info`].
+    pub fn info(
+        &self,
+        reminder: &str,
+    ) -> ClientResult<crate::Response<crate::types::RemindersAddSchema>> {
+        self.runtime.block_on(self.inner.info(reminder))
+    }
+    /// The blocking version of [`crate::reminders::Reminders::info_request`].
+    pub fn info_request(&self) -> RemindersInfoRequest<'_> {
+        RemindersInfoRequest {
+            inner: self.inner.info_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::reminders::Reminders::list`].
+    pub fn list(&self) -> ClientResult<crate::Response<crate::types::RemindersListSchema>> {
+        self.runtime.block_on(self.inner.list())
+    }
+}
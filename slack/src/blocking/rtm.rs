@@ -0,0 +1,57 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Rtm::connect`], set with a builder.
+pub struct RtmConnectRequest<'a> {
+    inner: crate::rtm::RtmConnectRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> RtmConnectRequest<'a> {
+    /// Sets the `batch_presence_aware` query parameter.
+    pub fn batch_presence_aware(mut self, batch_presence_aware: bool) -> Self {
+        self.inner = self.inner.batch_presence_aware(batch_presence_aware);
+        self
+    }
+
+    /// Sets the `presence_sub` query parameter.
+    pub fn presence_sub(mut self, presence_sub: bool) -> Self {
+        self.inner = self.inner.presence_sub(presence_sub);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::RtmConnectSchema>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::rtm::Rtm`].
+pub struct Rtm {
+    inner: crate::rtm::Rtm,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Rtm {
+    pub(crate) fn new(inner: crate::rtm::Rtm, runtime: Arc<tokio::runtime::Runtime>) -> Self {
+        Rtm { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::rtm::Rtm::connect`].
+    pub fn connect(
+        &self,
+        batch_presence_aware: bool,
+        presence_sub: bool,
+    ) -> ClientResult<crate::Response<crate::types::RtmConnectSchema>> {
+        self.runtime
+            .block_on(self.inner.connect(batch_presence_aware, presence_sub))
+    }
+    /// The blocking version of [`crate::rtm::Rtm::connect_request`].
+    pub fn connect_request(&self) -> RtmConnectRequest<'_> {
+        RtmConnectRequest {
+            inner: self.inner.connect_request(),
+            runtime: &self.runtime,
+        }
+    }
+}
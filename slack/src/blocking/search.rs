@@ -0,0 +1,81 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Search::message`], set with a builder.
+pub struct SearchMessageRequest<'a> {
+    inner: crate::search::SearchMessageRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> SearchMessageRequest<'a> {
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.inner = self.inner.count(count);
+        self
+    }
+
+    /// Sets the `highlight` query parameter.
+    pub fn highlight(mut self, highlight: bool) -> Self {
+        self.inner = self.inner.highlight(highlight);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sets the `sort` query parameter.
+    pub fn sort(mut self, sort: &'a str) -> Self {
+        self.inner = self.inner.sort(sort);
+        self
+    }
+
+    /// Sets the `sort_dir` query parameter.
+    pub fn sort_dir(mut self, sort_dir: &'a str) -> Self {
+        self.inner = self.inner.sort_dir(sort_dir);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::search::Search`].
+pub struct Search {
+    inner: crate::search::Search,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Search {
+    pub(crate) fn new(inner: crate::search::Search, runtime: Arc<tokio::runtime::Runtime>) -> Self {
+        Search { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::search::Search::message`].
+    pub fn message(
+        &self,
+        count: i64,
+        highlight: bool,
+        page: i64,
+        query: &str,
+        sort: &str,
+        sort_dir: &str,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime.block_on(
+            self.inner
+                .message(count, highlight, page, query, sort, sort_dir),
+        )
+    }
+    /// The blocking version of [`crate::search::Search::message_request`].
+    pub fn message_request<'a>(&'a self, query: &'a str) -> SearchMessageRequest<'a> {
+        SearchMessageRequest {
+            inner: self.inner.message_request(query),
+            runtime: &self.runtime,
+        }
+    }
+}
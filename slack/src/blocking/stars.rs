@@ -0,0 +1,85 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Stars::list`], set with a builder.
+pub struct StarsListRequest<'a> {
+    inner: crate::stars::StarsListRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> StarsListRequest<'a> {
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: &'a str) -> Self {
+        self.inner = self.inner.count(count);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: &'a str) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sets the `cursor` query parameter.
+    pub fn cursor(mut self, cursor: &'a str) -> Self {
+        self.inner = self.inner.cursor(cursor);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::StarsListSchema>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::stars::Stars`].
+pub struct Stars {
+    inner: crate::stars::Stars,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Stars {
+    pub(crate) fn new(inner: crate::stars::Stars, runtime: Arc<tokio::runtime::Runtime>) -> Self {
+        Stars { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::stars::Stars::add`].
+    pub fn add(
+        &self,
+        body: &crate::types::StarsAddRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime.block_on(self.inner.add(body))
+    }
+    /// The blocking version of [`crate::stars::Stars::list`].
+    pub fn list(
+        &self,
+        count: &str,
+        page: &str,
+        cursor: &str,
+        limit: i64,
+    ) -> ClientResult<crate::Response<crate::types::StarsListSchema>> {
+        self.runtime
+            .block_on(self.inner.list(count, page, cursor, limit))
+    }
+    /// The blocking version of [`crate::stars::Stars::list_request`].
+    pub fn list_request(&self) -> StarsListRequest<'_> {
+        StarsListRequest {
+            inner: self.inner.list_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::stars::Stars::remove`].
+    pub fn remove(
+        &self,
+        body: &crate::types::StarsAddRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime.block_on(self.inner.remove(body))
+    }
+}
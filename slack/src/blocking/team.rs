@@ -0,0 +1,202 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Team::access_log`], set with a builder.
+pub struct TeamAccessLogRequest<'a> {
+    inner: crate::team::TeamAccessLogRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> TeamAccessLogRequest<'a> {
+    /// Sets the `before` query parameter.
+    pub fn before(mut self, before: &'a str) -> Self {
+        self.inner = self.inner.before(before);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: &'a str) -> Self {
+        self.inner = self.inner.count(count);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: &'a str) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::TeamAccessLogsSchema>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Team::billable_info`], set with a builder.
+pub struct TeamBillableInfoRequest<'a> {
+    inner: crate::team::TeamBillableInfoRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> TeamBillableInfoRequest<'a> {
+    /// Sets the `user` query parameter.
+    pub fn user(mut self, user: &'a str) -> Self {
+        self.inner = self.inner.user(user);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Team::info`], set with a builder.
+pub struct TeamInfoRequest<'a> {
+    inner: crate::team::TeamInfoRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> TeamInfoRequest<'a> {
+    /// Sets the `team` query parameter.
+    pub fn team(mut self, team: &'a str) -> Self {
+        self.inner = self.inner.team(team);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::TeamInfoSchema>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Team::integration_log`], set with a builder.
+pub struct TeamIntegrationLogRequest<'a> {
+    inner: crate::team::TeamIntegrationLogRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> TeamIntegrationLogRequest<'a> {
+    /// Sets the `app_id` query parameter.
+    pub fn app_id(mut self, app_id: &'a str) -> Self {
+        self.inner = self.inner.app_id(app_id);
+        self
+    }
+
+    /// Sets the `change_type` query parameter.
+    pub fn change_type(mut self, change_type: &'a str) -> Self {
+        self.inner = self.inner.change_type(change_type);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: &'a str) -> Self {
+        self.inner = self.inner.count(count);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: &'a str) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sets the `service_id` query parameter.
+    pub fn service_id(mut self, service_id: &'a str) -> Self {
+        self.inner = self.inner.service_id(service_id);
+        self
+    }
+
+    /// Sets the `user` query parameter.
+    pub fn user(mut self, user: &'a str) -> Self {
+        self.inner = self.inner.user(user);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::TeamIntegrationLogsSchema>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::team::Team`].
+pub struct Team {
+    inner: crate::team::Team,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Team {
+    pub(crate) fn new(inner: crate::team::Team, runtime: Arc<tokio::runtime::Runtime>) -> Self {
+        Team { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::team::Team::access_log`].
+    pub fn access_log(
+        &self,
+        before: &str,
+        count: &str,
+        page: &str,
+    ) -> ClientResult<crate::Response<crate::types::TeamAccessLogsSchema>> {
+        self.runtime
+            .block_on(self.inner.access_log(before, count, page))
+    }
+    /// The blocking version of [`crate::team::Team::access_log_request`].
+    pub fn access_log_request(&self) -> TeamAccessLogRequest<'_> {
+        TeamAccessLogRequest {
+            inner: self.inner.access_log_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::team::Team::billable_info`].
+    pub fn billable_info(
+        &self,
+        user: &str,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime.block_on(self.inner.billable_info(user))
+    }
+    /// The blocking version of [`crate::team::Team::billable_info_request`].
+    pub fn billable_info_request(&self) -> TeamBillableInfoRequest<'_> {
+        TeamBillableInfoRequest {
+            inner: self.inner.billable_info_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::team::Team::info`].
+    pub fn info(&self, team: &str) -> ClientResult<crate::Response<crate::types::TeamInfoSchema>> {
+        self.runtime.block_on(self.inner.info(team))
+    }
+    /// The blocking version of [`crate::team::Team::info_request`].
+    pub fn info_request(&self) -> TeamInfoRequest<'_> {
+        TeamInfoRequest {
+            inner: self.inner.info_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::team::Team::integration_log`].
+    pub fn integration_log(
+        &self,
+        app_id: &str,
+        change_type: &str,
+        count: &str,
+        page: &str,
+        service_id: &str,
+        user: &str,
+    ) -> ClientResult<crate::Response<crate::types::TeamIntegrationLogsSchema>> {
+        self.runtime.block_on(self.inner.integration_log(
+            app_id,
+            change_type,
+            count,
+            page,
+            service_id,
+            user,
+        ))
+    }
+    /// The blocking version of [`crate::team::Team::integration_log_request`].
+    pub fn integration_log_request(&self) -> TeamIntegrationLogRequest<'_> {
+        TeamIntegrationLogRequest {
+            inner: self.inner.integration_log_request(),
+            runtime: &self.runtime,
+        }
+    }
+}
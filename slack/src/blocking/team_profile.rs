@@ -0,0 +1,52 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`TeamProfile::get`], set with a builder.
+pub struct TeamProfileGetRequest<'a> {
+    inner: crate::team_profile::TeamProfileGetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> TeamProfileGetRequest<'a> {
+    /// Sets the `visibility` query parameter.
+    pub fn visibility(mut self, visibility: &'a str) -> Self {
+        self.inner = self.inner.visibility(visibility);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::TeamProfileGetSuccessSchema>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::team_profile::TeamProfile`].
+pub struct TeamProfile {
+    inner: crate::team_profile::TeamProfile,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl TeamProfile {
+    pub(crate) fn new(
+        inner: crate::team_profile::TeamProfile,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        TeamProfile { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::team_profile::TeamProfile::get`].
+    pub fn get(
+        &self,
+        visibility: &str,
+    ) -> ClientResult<crate::Response<crate::types::TeamProfileGetSuccessSchema>> {
+        self.runtime.block_on(self.inner.get(visibility))
+    }
+    /// The blocking version of [`crate::team_profile::TeamProfile::get_request`].
+    pub fn get_request(&self) -> TeamProfileGetRequest<'_> {
+        TeamProfileGetRequest {
+            inner: self.inner.get_request(),
+            runtime: &self.runtime,
+        }
+    }
+}
@@ -0,0 +1,97 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Usergroups::list`], set with a builder.
+pub struct UsergroupsListRequest<'a> {
+    inner: crate::usergroups::UsergroupsListRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> UsergroupsListRequest<'a> {
+    /// Sets the `include_users` query parameter.
+    pub fn include_users(mut self, include_users: bool) -> Self {
+        self.inner = self.inner.include_users(include_users);
+        self
+    }
+
+    /// Sets the `include_count` query parameter.
+    pub fn include_count(mut self, include_count: bool) -> Self {
+        self.inner = self.inner.include_count(include_count);
+        self
+    }
+
+    /// Sets the `include_disabled` query parameter.
+    pub fn include_disabled(mut self, include_disabled: bool) -> Self {
+        self.inner = self.inner.include_disabled(include_disabled);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::UsergroupsListSchema>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::usergroups::Usergroups`].
+pub struct Usergroups {
+    inner: crate::usergroups::Usergroups,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Usergroups {
+    pub(crate) fn new(
+        inner: crate::usergroups::Usergroups,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Usergroups { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::usergroups::Usergroups::create`].
+    pub fn create(
+        &self,
+        body: &crate::types::UsergroupsCreateRequest,
+    ) -> ClientResult<crate::Response<crate::types::UsergroupsCreateSchema>> {
+        self.runtime.block_on(self.inner.create(body))
+    }
+    /// The blocking version of [`crate::usergroups::Usergroups::disable`].
+    pub fn disable(
+        &self,
+        body: &crate::types::UsergroupsEnableRequest,
+    ) -> ClientResult<crate::Response<crate::types::UsergroupsCreateSchema>> {
+        self.runtime.block_on(self.inner.disable(body))
+    }
+    /// The blocking version of [`crate::usergroups::Usergroups::enable`].
+    pub fn enable(
+        &self,
+        body: &crate::types::UsergroupsEnableRequest,
+    ) -> ClientResult<crate::Response<crate::types::UsergroupsCreateSchema>> {
+        self.runtime.block_on(self.inner.enable(body))
+    }
+    /// The blocking version of [`crate::usergroups::Usergroups::list`].
+    pub fn list(
+        &self,
+        include_users: bool,
+        include_count: bool,
+        include_disabled: bool,
+    ) -> ClientResult<crate::Response<crate::types::UsergroupsListSchema>> {
+        self.runtime.block_on(
+            self.inner
+                .list(include_users, include_count, include_disabled),
+        )
+    }
+    /// The blocking version of [`crate::usergroups::Usergroups::list_request`].
+    pub fn list_request(&self) -> UsergroupsListRequest<'_> {
+        UsergroupsListRequest {
+            inner: self.inner.list_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::usergroups::Usergroups::update`].
+    pub fn update(
+        &self,
+        body: &crate::types::UsergroupsUpdateRequest,
+    ) -> ClientResult<crate::Response<crate::types::UsergroupsCreateSchema>> {
+        self.runtime.block_on(self.inner.update(body))
+    }
+}
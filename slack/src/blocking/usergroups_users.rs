@@ -0,0 +1,61 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`UsergroupsUsers::list`], set with a builder.
+pub struct UsergroupsUsersListRequest<'a> {
+    inner: crate::usergroups_users::UsergroupsUsersListRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> UsergroupsUsersListRequest<'a> {
+    /// Sets the `include_disabled` query parameter.
+    pub fn include_disabled(mut self, include_disabled: bool) -> Self {
+        self.inner = self.inner.include_disabled(include_disabled);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::UsergroupsUsersListSchema>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::usergroups_users::UsergroupsUsers`].
+pub struct UsergroupsUsers {
+    inner: crate::usergroups_users::UsergroupsUsers,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl UsergroupsUsers {
+    pub(crate) fn new(
+        inner: crate::usergroups_users::UsergroupsUsers,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        UsergroupsUsers { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::usergroups_users::UsergroupsUsers::list`].
+    pub fn list(
+        &self,
+        include_disabled: bool,
+        usergroup: &str,
+    ) -> ClientResult<crate::Response<crate::types::UsergroupsUsersListSchema>> {
+        self.runtime
+            .block_on(self.inner.list(include_disabled, usergroup))
+    }
+    /// The blocking version of [`crate::usergroups_users::UsergroupsUsers::list_request`].
+    pub fn list_request<'a>(&'a self, usergroup: &'a str) -> UsergroupsUsersListRequest<'a> {
+        UsergroupsUsersListRequest {
+            inner: self.inner.list_request(usergroup),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::usergroups_users::UsergroupsUsers::update`].
+    pub fn update(
+        &self,
+        body: &crate::types::UsergroupsUsersUpdateRequest,
+    ) -> ClientResult<crate::Response<crate::types::UsergroupsCreateSchema>> {
+        self.runtime.block_on(self.inner.update(body))
+    }
+}
@@ -0,0 +1,247 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Users::conversation`], set with a builder.
+pub struct UsersConversationRequest<'a> {
+    inner: crate::users::UsersConversationRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> UsersConversationRequest<'a> {
+    /// Sets the `user` query parameter.
+    pub fn user(mut self, user: &'a str) -> Self {
+        self.inner = self.inner.user(user);
+        self
+    }
+
+    /// Sets the `types` query parameter.
+    pub fn types(mut self, types: &'a str) -> Self {
+        self.inner = self.inner.types(types);
+        self
+    }
+
+    /// Sets the `exclude_archived` query parameter.
+    pub fn exclude_archived(mut self, exclude_archived: bool) -> Self {
+        self.inner = self.inner.exclude_archived(exclude_archived);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `cursor` query parameter.
+    pub fn cursor(mut self, cursor: &'a str) -> Self {
+        self.inner = self.inner.cursor(cursor);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::UsersConversationsSuccessSchema>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Users::get_presence`], set with a builder.
+pub struct UsersGetPresenceRequest<'a> {
+    inner: crate::users::UsersGetPresenceRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> UsersGetPresenceRequest<'a> {
+    /// Sets the `user` query parameter.
+    pub fn user(mut self, user: &'a str) -> Self {
+        self.inner = self.inner.user(user);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::ApiMethodUsersGetPresence>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Users::info`], set with a builder.
+pub struct UsersInfoRequest<'a> {
+    inner: crate::users::UsersInfoRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> UsersInfoRequest<'a> {
+    /// Sets the `include_locale` query parameter.
+    pub fn include_locale(mut self, include_locale: bool) -> Self {
+        self.inner = self.inner.include_locale(include_locale);
+        self
+    }
+
+    /// Sets the `user` query parameter.
+    pub fn user(mut self, user: &'a str) -> Self {
+        self.inner = self.inner.user(user);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::UsersInfoSuccessSchema>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Users::list`], set with a builder.
+pub struct UsersListRequest<'a> {
+    inner: crate::users::UsersListRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> UsersListRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `cursor` query parameter.
+    pub fn cursor(mut self, cursor: &'a str) -> Self {
+        self.inner = self.inner.cursor(cursor);
+        self
+    }
+
+    /// Sets the `include_locale` query parameter.
+    pub fn include_locale(mut self, include_locale: bool) -> Self {
+        self.inner = self.inner.include_locale(include_locale);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::UsersListSchema>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::users::Users`].
+pub struct Users {
+    inner: crate::users::Users,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Users {
+    pub(crate) fn new(inner: crate::users::Users, runtime: Arc<tokio::runtime::Runtime>) -> Self {
+        Users { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::users::Users::conversation`].
+    pub fn conversation(
+        &self,
+        user: &str,
+        types: &str,
+        exclude_archived: bool,
+        limit: i64,
+        cursor: &str,
+    ) -> ClientResult<crate::Response<crate::types::UsersConversationsSuccessSchema>> {
+        self.runtime.block_on(
+            self.inner
+                .conversation(user, types, exclude_archived, limit, cursor),
+        )
+    }
+    /// The blocking version of [`crate::users::Users::conversation_request`].
+    pub fn conversation_request(&self) -> UsersConversationRequest<'_> {
+        UsersConversationRequest {
+            inner: self.inner.conversation_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::users::Users::delete_photo`].
+    pub fn delete_photo(
+        &self,
+        body: &crate::types::UsersDeletePhotoRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime.block_on(self.inner.delete_photo(body))
+    }
+    /// The blocking version of [`crate::users::Users::get_presence`].
+    pub fn get_presence(
+        &self,
+        user: &str,
+    ) -> ClientResult<crate::Response<crate::types::ApiMethodUsersGetPresence>> {
+        self.runtime.block_on(self.inner.get_presence(user))
+    }
+    /// The blocking version of [`crate::users::Users::get_presence_request`].
+    pub fn get_presence_request(&self) -> UsersGetPresenceRequest<'_> {
+        UsersGetPresenceRequest {
+            inner: self.inner.get_presence_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::users::Users::identity`].
+    pub fn identity(
+        &self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::UsersIdentityResponseAnyOf>>> {
+        self.runtime.block_on(self.inner.identity())
+    }
+    /// The blocking version of [`crate::users::Users::get_all_identity`].
+    pub fn get_all_identity(
+        &self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::UsersIdentityResponseAnyOf>>> {
+        self.runtime.block_on(self.inner.get_all_identity())
+    }
+    /// The blocking version of [`crate::users::Users::info`].
+    pub fn info(
+        &self,
+        include_locale: bool,
+        user: &str,
+    ) -> ClientResult<crate::Response<crate::types::UsersInfoSuccessSchema>> {
+        self.runtime.block_on(self.inner.info(include_locale, user))
+    }
+    /// The blocking version of [`crate::users::Users::info_request`].
+    pub fn info_request(&self) -> UsersInfoRequest<'_> {
+        UsersInfoRequest {
+            inner: self.inner.info_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::users::Users::list`].
+    pub fn list(
+        &self,
+        limit: i64,
+        cursor: &str,
+        include_locale: bool,
+    ) -> ClientResult<crate::Response<crate::types::UsersListSchema>> {
+        self.runtime
+            .block_on(self.inner.list(limit, cursor, include_locale))
+    }
+    /// The blocking version of [`crate::users::Users::list_request`].
+    pub fn list_request(&self) -> UsersListRequest<'_> {
+        UsersListRequest {
+            inner: self.inner.list_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::users::Users::lookup_email`].
+    pub fn lookup_email(
+        &self,
+        email: &str,
+    ) -> ClientResult<crate::Response<crate::types::UsersInfoSuccessSchema>> {
+        self.runtime.block_on(self.inner.lookup_email(email))
+    }
+    /// The blocking version of [`crate::users::Users::set_active`].
+    pub fn set_active(&self) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime.block_on(self.inner.set_active())
+    }
+    /// The blocking version of [`crate::users::Users::set_photo`].
+    pub fn set_photo(
+        &self,
+        body: &crate::types::UsersSetPhotoRequest,
+    ) -> ClientResult<crate::Response<crate::types::UsersSetPhotoSchema>> {
+        self.runtime.block_on(self.inner.set_photo(body))
+    }
+    /// The blocking version of [`crate::users::Users::set_presence`].
+    pub fn set_presence(
+        &self,
+        body: &crate::types::UsersSetPresenceRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime.block_on(self.inner.set_presence(body))
+    }
+}
@@ -0,0 +1,66 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`UsersProfile::get`], set with a builder.
+pub struct UsersProfileGetRequest<'a> {
+    inner: crate::users_profile::UsersProfileGetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> UsersProfileGetRequest<'a> {
+    /// Sets the `include_labels` query parameter.
+    pub fn include_labels(mut self, include_labels: bool) -> Self {
+        self.inner = self.inner.include_labels(include_labels);
+        self
+    }
+
+    /// Sets the `user` query parameter.
+    pub fn user(mut self, user: &'a str) -> Self {
+        self.inner = self.inner.user(user);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::UsersProfileGetSchema>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::users_profile::UsersProfile`].
+pub struct UsersProfile {
+    inner: crate::users_profile::UsersProfile,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl UsersProfile {
+    pub(crate) fn new(
+        inner: crate::users_profile::UsersProfile,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        UsersProfile { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::users_profile::UsersProfile::get`].
+    pub fn get(
+        &self,
+        include_labels: bool,
+        user: &str,
+    ) -> ClientResult<crate::Response<crate::types::UsersProfileGetSchema>> {
+        self.runtime.block_on(self.inner.get(include_labels, user))
+    }
+    /// The blocking version of [`crate::users_profile::UsersProfile::get_request`].
+    pub fn get_request(&self) -> UsersProfileGetRequest<'_> {
+        UsersProfileGetRequest {
+            inner: self.inner.get_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::users_profile::UsersProfile::set`].
+    pub fn set(
+        &self,
+        body: &crate::types::UsersProfileSetRequest,
+    ) -> ClientResult<crate::Response<crate::types::UsersProfileSetSchema>> {
+        self.runtime.block_on(self.inner.set(body))
+    }
+}
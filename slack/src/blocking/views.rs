@@ -0,0 +1,127 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Views::publish`], set with a builder.
+pub struct ViewsPublishRequest<'a> {
+    inner: crate::views::ViewsPublishRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ViewsPublishRequest<'a> {
+    /// Sets the `hash` query parameter.
+    pub fn hash(mut self, hash: &'a str) -> Self {
+        self.inner = self.inner.hash(hash);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Views::update`], set with a builder.
+pub struct ViewsUpdateRequest<'a> {
+    inner: crate::views::ViewsUpdateRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ViewsUpdateRequest<'a> {
+    /// Sets the `view_id` query parameter.
+    pub fn view_id(mut self, view_id: &'a str) -> Self {
+        self.inner = self.inner.view_id(view_id);
+        self
+    }
+
+    /// Sets the `external_id` query parameter.
+    pub fn external_id(mut self, external_id: &'a str) -> Self {
+        self.inner = self.inner.external_id(external_id);
+        self
+    }
+
+    /// Sets the `view` query parameter.
+    pub fn view(mut self, view: &'a str) -> Self {
+        self.inner = self.inner.view(view);
+        self
+    }
+
+    /// Sets the `hash` query parameter.
+    pub fn hash(mut self, hash: &'a str) -> Self {
+        self.inner = self.inner.hash(hash);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::views::Views`].
+pub struct Views {
+    inner: crate::views::Views,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Views {
+    pub(crate) fn new(inner: crate::views::Views, runtime: Arc<tokio::runtime::Runtime>) -> Self {
+        Views { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::views::Views::open`].
+    pub fn open(
+        &self,
+        trigger_id: &str,
+        view: &str,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime.block_on(self.inner.open(trigger_id, view))
+    }
+    /// The blocking version of [`crate::views::Views::publish`].
+    pub fn publish(
+        &self,
+        user_id: &str,
+        view: &str,
+        hash: &str,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime
+            .block_on(self.inner.publish(user_id, view, hash))
+    }
+    /// The blocking version of [`crate::views::Views::publish_request`].
+    pub fn publish_request<'a>(
+        &'a self,
+        user_id: &'a str,
+        view: &'a str,
+    ) -> ViewsPublishRequest<'a> {
+        ViewsPublishRequest {
+            inner: self.inner.publish_request(user_id, view),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::views::Views::push`].
+    pub fn push(
+        &self,
+        trigger_id: &str,
+        view: &str,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime.block_on(self.inner.push(trigger_id, view))
+    }
+    /// The blocking version of [`crate::views::Views::update`].
+    pub fn update(
+        &self,
+        view_id: &str,
+        external_id: &str,
+        view: &str,
+        hash: &str,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime
+            .block_on(self.inner.update(view_id, external_id, view, hash))
+    }
+    /// The blocking version of [`crate::views::Views::update_request`].
+    pub fn update_request(&self) -> ViewsUpdateRequest<'_> {
+        ViewsUpdateRequest {
+            inner: self.inner.update_request(),
+            runtime: &self.runtime,
+        }
+    }
+}
@@ -0,0 +1,130 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Workflows::step_completed`], set with a builder.
+pub struct WorkflowsStepCompletedRequest<'a> {
+    inner: crate::workflows::WorkflowsStepCompletedRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> WorkflowsStepCompletedRequest<'a> {
+    /// Sets the `outputs` query parameter.
+    pub fn outputs(mut self, outputs: &'a str) -> Self {
+        self.inner = self.inner.outputs(outputs);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Workflows::update_step`], set with a builder.
+pub struct WorkflowsUpdateStepRequest<'a> {
+    inner: crate::workflows::WorkflowsUpdateStepRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> WorkflowsUpdateStepRequest<'a> {
+    /// Sets the `inputs` query parameter.
+    pub fn inputs(mut self, inputs: &'a str) -> Self {
+        self.inner = self.inner.inputs(inputs);
+        self
+    }
+
+    /// Sets the `outputs` query parameter.
+    pub fn outputs(mut self, outputs: &'a str) -> Self {
+        self.inner = self.inner.outputs(outputs);
+        self
+    }
+
+    /// Sets the `step_name` query parameter.
+    pub fn step_name(mut self, step_name: &'a str) -> Self {
+        self.inner = self.inner.step_name(step_name);
+        self
+    }
+
+    /// Sets the `step_image_url` query parameter.
+    pub fn step_image_url(mut self, step_image_url: &'a str) -> Self {
+        self.inner = self.inner.step_image_url(step_image_url);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::workflows::Workflows`].
+pub struct Workflows {
+    inner: crate::workflows::Workflows,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Workflows {
+    pub(crate) fn new(
+        inner: crate::workflows::Workflows,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Workflows { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::workflows::Workflows::step_completed`].
+    pub fn step_completed(
+        &self,
+        workflow_step_execute_id: &str,
+        outputs: &str,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime
+            .block_on(self.inner.step_completed(workflow_step_execute_id, outputs))
+    }
+    /// The blocking version of [`crate::workflows::Workflows::step_completed_request`].
+    pub fn step_completed_request<'a>(
+        &'a self,
+        workflow_step_execute_id: &'a str,
+    ) -> WorkflowsStepCompletedRequest<'a> {
+        WorkflowsStepCompletedRequest {
+            inner: self.inner.step_completed_request(workflow_step_execute_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::workflows::Workflows::step_failed`].
+    pub fn step_failed(
+        &self,
+        workflow_step_execute_id: &str,
+        error: &str,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime
+            .block_on(self.inner.step_failed(workflow_step_execute_id, error))
+    }
+    /// The blocking version of [`crate::workflows::Workflows::update_step`].
+    pub fn update_step(
+        &self,
+        workflow_step_edit_id: &str,
+        inputs: &str,
+        outputs: &str,
+        step_name: &str,
+        step_image_url: &str,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        self.runtime.block_on(self.inner.update_step(
+            workflow_step_edit_id,
+            inputs,
+            outputs,
+            step_name,
+            step_image_url,
+        ))
+    }
+    /// The blocking version of [`crate::workflows::Workflows::update_step_request`].
+    pub fn update_step_request<'a>(
+        &'a self,
+        workflow_step_edit_id: &'a str,
+    ) -> WorkflowsUpdateStepRequest<'a> {
+        WorkflowsUpdateStepRequest {
+            inner: self.inner.update_step_request(workflow_step_edit_id),
+            runtime: &self.runtime,
+        }
+    }
+}
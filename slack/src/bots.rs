@@ -1,6 +1,42 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Bots::info`], set with a builder.
+pub struct BotsInfoRequest<'a> {
+    client: &'a Bots,
+    bot: Option<&'a str>,
+}
+
+impl<'a> BotsInfoRequest<'a> {
+    /// Sets the `bot` query parameter.
+    pub fn bot(mut self, bot: &'a str) -> Self {
+        self.bot = Some(bot);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::BotsInfoSchema>> {
+        let Self { client, bot } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = bot {
+            query_args.push(("bot".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(&format!("/bots.info?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct Bots {
     pub client: Client,
 }
@@ -43,4 +79,19 @@ impl Bots {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/bots.info` endpoint.
+     *
+     * As opposed to `info`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Gets information about a bot user.
+     *
+     * FROM: <https://api.slack.com/methods/bots.info>
+     */
+    pub fn info_request(&self) -> BotsInfoRequest<'_> {
+        BotsInfoRequest {
+            client: self,
+            bot: None,
+        }
+    }
 }
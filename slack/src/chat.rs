@@ -24,13 +24,14 @@ impl Chat {
      */
     pub async fn delete(
         &self,
+        body: &crate::types::ChatDeleteRequest,
     ) -> ClientResult<crate::Response<crate::types::ChatDeleteSuccessSchema>> {
         let url = self.client.url("/chat.delete", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -49,13 +50,14 @@ impl Chat {
      */
     pub async fn delete_scheduled_message(
         &self,
+        body: &crate::types::ChatDeleteScheduledMessageRequest,
     ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let url = self.client.url("/chat.deleteScheduledMessage", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -113,13 +115,14 @@ impl Chat {
      */
     pub async fn me_message(
         &self,
+        body: &crate::types::ChatMeMessageRequest,
     ) -> ClientResult<crate::Response<crate::types::ChatMeMessageSchema>> {
         let url = self.client.url("/chat.meMessage", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -138,13 +141,14 @@ impl Chat {
      */
     pub async fn post_ephemeral(
         &self,
+        body: &crate::types::ChatPostEphemeralRequest,
     ) -> ClientResult<crate::Response<crate::types::ChatPostEphemeralSuccessSchema>> {
         let url = self.client.url("/chat.postEphemeral", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -163,13 +167,14 @@ impl Chat {
      */
     pub async fn post_message(
         &self,
+        body: &crate::types::ChatPostMessageRequest,
     ) -> ClientResult<crate::Response<crate::types::ChatPostMessageSuccessSchema>> {
         let url = self.client.url("/chat.postMessage", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -188,13 +193,14 @@ impl Chat {
      */
     pub async fn schedule_message(
         &self,
+        body: &crate::types::ChatScheduleMessageRequest,
     ) -> ClientResult<crate::Response<crate::types::ChatScheduleMessageSuccessSchema>> {
         let url = self.client.url("/chat.scheduleMessage", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -211,13 +217,16 @@ impl Chat {
      *
      * * `token: &str` -- Authentication token. Requires scope: `links:write`.
      */
-    pub async fn unfurl(&self) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+    pub async fn unfurl(
+        &self,
+        body: &crate::types::ChatUnfurlRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let url = self.client.url("/chat.unfurl", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -236,13 +245,14 @@ impl Chat {
      */
     pub async fn update(
         &self,
+        body: &crate::types::ChatUpdateRequest,
     ) -> ClientResult<crate::Response<crate::types::ChatUpdateSuccessSchema>> {
         let url = self.client.url("/chat.update", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -1,6 +1,93 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`ChatScheduledMessages::list`], set with a builder.
+pub struct ChatScheduledMessagesListRequest<'a> {
+    client: &'a ChatScheduledMessages,
+    channel: Option<&'a str>,
+    latest: Option<f64>,
+    oldest: Option<f64>,
+    limit: Option<i64>,
+    cursor: Option<&'a str>,
+}
+
+impl<'a> ChatScheduledMessagesListRequest<'a> {
+    /// Sets the `channel` query parameter.
+    pub fn channel(mut self, channel: &'a str) -> Self {
+        self.channel = Some(channel);
+        self
+    }
+
+    /// Sets the `latest` query parameter.
+    pub fn latest(mut self, latest: f64) -> Self {
+        self.latest = Some(latest);
+        self
+    }
+
+    /// Sets the `oldest` query parameter.
+    pub fn oldest(mut self, oldest: f64) -> Self {
+        self.oldest = Some(oldest);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `cursor` query parameter.
+    pub fn cursor(mut self, cursor: &'a str) -> Self {
+        self.cursor = Some(cursor);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::ChatScheduledMessagesListSchema>> {
+        let Self {
+            client,
+            channel,
+            latest,
+            oldest,
+            limit,
+            cursor,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = channel {
+            query_args.push(("channel".to_string(), v.to_string()));
+        }
+        if let Some(v) = cursor {
+            query_args.push(("cursor".to_string(), v.to_string()));
+        }
+        if let Some(v) = latest {
+            query_args.push(("latest".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = oldest {
+            query_args.push(("oldest".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/chat.scheduledMessages.list?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct ChatScheduledMessages {
     pub client: Client,
 }
@@ -65,4 +152,23 @@ impl ChatScheduledMessages {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/chat.scheduledMessages.list` endpoint.
+     *
+     * As opposed to `list`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Returns a list of scheduled messages.
+     *
+     * FROM: <https://api.slack.com/methods/chat.scheduledMessages.list>
+     */
+    pub fn list_request(&self) -> ChatScheduledMessagesListRequest<'_> {
+        ChatScheduledMessagesListRequest {
+            client: self,
+            channel: None,
+            latest: None,
+            oldest: None,
+            limit: None,
+            cursor: None,
+        }
+    }
 }
@@ -1,6 +1,419 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Conversations::history`], set with a builder.
+pub struct ConversationsHistoryRequest<'a> {
+    client: &'a Conversations,
+    channel: Option<&'a str>,
+    latest: Option<f64>,
+    oldest: Option<f64>,
+    inclusive: Option<bool>,
+    limit: Option<i64>,
+    cursor: Option<&'a str>,
+}
+
+impl<'a> ConversationsHistoryRequest<'a> {
+    /// Sets the `channel` query parameter.
+    pub fn channel(mut self, channel: &'a str) -> Self {
+        self.channel = Some(channel);
+        self
+    }
+
+    /// Sets the `latest` query parameter.
+    pub fn latest(mut self, latest: f64) -> Self {
+        self.latest = Some(latest);
+        self
+    }
+
+    /// Sets the `oldest` query parameter.
+    pub fn oldest(mut self, oldest: f64) -> Self {
+        self.oldest = Some(oldest);
+        self
+    }
+
+    /// Sets the `inclusive` query parameter.
+    pub fn inclusive(mut self, inclusive: bool) -> Self {
+        self.inclusive = Some(inclusive);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `cursor` query parameter.
+    pub fn cursor(mut self, cursor: &'a str) -> Self {
+        self.cursor = Some(cursor);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::ConversationsHistorySuccessSchema>> {
+        let Self {
+            client,
+            channel,
+            latest,
+            oldest,
+            inclusive,
+            limit,
+            cursor,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = channel {
+            query_args.push(("channel".to_string(), v.to_string()));
+        }
+        if let Some(v) = cursor {
+            query_args.push(("cursor".to_string(), v.to_string()));
+        }
+        if let Some(v) = inclusive {
+            query_args.push(("inclusive".to_string(), v.to_string()));
+        }
+        if let Some(v) = latest {
+            query_args.push(("latest".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = oldest {
+            query_args.push(("oldest".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/conversations.history?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Conversations::info`], set with a builder.
+pub struct ConversationsInfoRequest<'a> {
+    client: &'a Conversations,
+    channel: Option<&'a str>,
+    include_locale: Option<bool>,
+    include_num_members: Option<bool>,
+}
+
+impl<'a> ConversationsInfoRequest<'a> {
+    /// Sets the `channel` query parameter.
+    pub fn channel(mut self, channel: &'a str) -> Self {
+        self.channel = Some(channel);
+        self
+    }
+
+    /// Sets the `include_locale` query parameter.
+    pub fn include_locale(mut self, include_locale: bool) -> Self {
+        self.include_locale = Some(include_locale);
+        self
+    }
+
+    /// Sets the `include_num_members` query parameter.
+    pub fn include_num_members(mut self, include_num_members: bool) -> Self {
+        self.include_num_members = Some(include_num_members);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::ConversationsInfoSuccessSchema>> {
+        let Self {
+            client,
+            channel,
+            include_locale,
+            include_num_members,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = channel {
+            query_args.push(("channel".to_string(), v.to_string()));
+        }
+        if let Some(v) = include_locale {
+            query_args.push(("include_locale".to_string(), v.to_string()));
+        }
+        if let Some(v) = include_num_members {
+            query_args.push(("include_num_members".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/conversations.info?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Conversations::list`], set with a builder.
+pub struct ConversationsListRequest<'a> {
+    client: &'a Conversations,
+    exclude_archived: Option<bool>,
+    types: Option<&'a str>,
+    limit: Option<i64>,
+    cursor: Option<&'a str>,
+}
+
+impl<'a> ConversationsListRequest<'a> {
+    /// Sets the `exclude_archived` query parameter.
+    pub fn exclude_archived(mut self, exclude_archived: bool) -> Self {
+        self.exclude_archived = Some(exclude_archived);
+        self
+    }
+
+    /// Sets the `types` query parameter.
+    pub fn types(mut self, types: &'a str) -> Self {
+        self.types = Some(types);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `cursor` query parameter.
+    pub fn cursor(mut self, cursor: &'a str) -> Self {
+        self.cursor = Some(cursor);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::ConversationsListSuccessSchema>> {
+        let Self {
+            client,
+            exclude_archived,
+            types,
+            limit,
+            cursor,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = cursor {
+            query_args.push(("cursor".to_string(), v.to_string()));
+        }
+        if let Some(v) = exclude_archived {
+            query_args.push(("exclude_archived".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = types {
+            query_args.push(("types".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/conversations.list?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Conversations::member`], set with a builder.
+pub struct ConversationsMemberRequest<'a> {
+    client: &'a Conversations,
+    channel: Option<&'a str>,
+    limit: Option<i64>,
+    cursor: Option<&'a str>,
+}
+
+impl<'a> ConversationsMemberRequest<'a> {
+    /// Sets the `channel` query parameter.
+    pub fn channel(mut self, channel: &'a str) -> Self {
+        self.channel = Some(channel);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `cursor` query parameter.
+    pub fn cursor(mut self, cursor: &'a str) -> Self {
+        self.cursor = Some(cursor);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::ConversationsMembersSuccessSchema>> {
+        let Self {
+            client,
+            channel,
+            limit,
+            cursor,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = channel {
+            query_args.push(("channel".to_string(), v.to_string()));
+        }
+        if let Some(v) = cursor {
+            query_args.push(("cursor".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/conversations.members?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Conversations::replie`], set with a builder.
+pub struct ConversationsReplieRequest<'a> {
+    client: &'a Conversations,
+    channel: Option<&'a str>,
+    ts: Option<f64>,
+    latest: Option<f64>,
+    oldest: Option<f64>,
+    inclusive: Option<bool>,
+    limit: Option<i64>,
+    cursor: Option<&'a str>,
+}
+
+impl<'a> ConversationsReplieRequest<'a> {
+    /// Sets the `channel` query parameter.
+    pub fn channel(mut self, channel: &'a str) -> Self {
+        self.channel = Some(channel);
+        self
+    }
+
+    /// Sets the `ts` query parameter.
+    pub fn ts(mut self, ts: f64) -> Self {
+        self.ts = Some(ts);
+        self
+    }
+
+    /// Sets the `latest` query parameter.
+    pub fn latest(mut self, latest: f64) -> Self {
+        self.latest = Some(latest);
+        self
+    }
+
+    /// Sets the `oldest` query parameter.
+    pub fn oldest(mut self, oldest: f64) -> Self {
+        self.oldest = Some(oldest);
+        self
+    }
+
+    /// Sets the `inclusive` query parameter.
+    pub fn inclusive(mut self, inclusive: bool) -> Self {
+        self.inclusive = Some(inclusive);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `cursor` query parameter.
+    pub fn cursor(mut self, cursor: &'a str) -> Self {
+        self.cursor = Some(cursor);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::ConversationsRepliesSuccessSchema>> {
+        let Self {
+            client,
+            channel,
+            ts,
+            latest,
+            oldest,
+            inclusive,
+            limit,
+            cursor,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = channel {
+            query_args.push(("channel".to_string(), v.to_string()));
+        }
+        if let Some(v) = cursor {
+            query_args.push(("cursor".to_string(), v.to_string()));
+        }
+        if let Some(v) = inclusive {
+            query_args.push(("inclusive".to_string(), v.to_string()));
+        }
+        if let Some(v) = latest {
+            query_args.push(("latest".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = oldest {
+            query_args.push(("oldest".to_string(), v.to_string()));
+        }
+        if let Some(v) = ts {
+            query_args.push(("ts".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/conversations.replies?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct Conversations {
     pub client: Client,
 }
@@ -22,13 +435,16 @@ impl Conversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `conversations:write`.
      */
-    pub async fn archive(&self) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+    pub async fn archive(
+        &self,
+        body: &crate::types::ConversationsJoinRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let url = self.client.url("/conversations.archive", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -47,13 +463,14 @@ impl Conversations {
      */
     pub async fn close(
         &self,
+        body: &crate::types::ConversationsJoinRequest,
     ) -> ClientResult<crate::Response<crate::types::ConversationsCloseSuccessSchema>> {
         let url = self.client.url("/conversations.close", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -72,13 +489,14 @@ impl Conversations {
      */
     pub async fn create(
         &self,
+        body: &crate::types::ConversationsCreateRequest,
     ) -> ClientResult<crate::Response<crate::types::ConversationsInfoSuccessSchema>> {
         let url = self.client.url("/conversations.create", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -143,6 +561,26 @@ impl Conversations {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/conversations.history` endpoint.
+     *
+     * As opposed to `history`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Fetches a conversation's history of messages and events.
+     *
+     * FROM: <https://api.slack.com/methods/conversations.history>
+     */
+    pub fn history_request(&self) -> ConversationsHistoryRequest<'_> {
+        ConversationsHistoryRequest {
+            client: self,
+            channel: None,
+            latest: None,
+            oldest: None,
+            inclusive: None,
+            limit: None,
+            cursor: None,
+        }
+    }
     /**
      * This function performs a `GET` to the `/conversations.info` endpoint.
      *
@@ -190,6 +628,23 @@ impl Conversations {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/conversations.info` endpoint.
+     *
+     * As opposed to `info`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Retrieve information about a conversation.
+     *
+     * FROM: <https://api.slack.com/methods/conversations.info>
+     */
+    pub fn info_request(&self) -> ConversationsInfoRequest<'_> {
+        ConversationsInfoRequest {
+            client: self,
+            channel: None,
+            include_locale: None,
+            include_num_members: None,
+        }
+    }
     /**
      * This function performs a `POST` to the `/conversations.invite` endpoint.
      *
@@ -203,13 +658,14 @@ impl Conversations {
      */
     pub async fn invite(
         &self,
+        body: &crate::types::ConversationsInviteRequest,
     ) -> ClientResult<crate::Response<crate::types::ConversationsInfoSuccessSchema>> {
         let url = self.client.url("/conversations.invite", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -228,13 +684,14 @@ impl Conversations {
      */
     pub async fn join(
         &self,
+        body: &crate::types::ConversationsJoinRequest,
     ) -> ClientResult<crate::Response<crate::types::ConversationsJoinSuccessSchema>> {
         let url = self.client.url("/conversations.join", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -251,13 +708,16 @@ impl Conversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `conversations:write`.
      */
-    pub async fn kick(&self) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+    pub async fn kick(
+        &self,
+        body: &crate::types::ConversationsKickRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let url = self.client.url("/conversations.kick", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -276,13 +736,14 @@ impl Conversations {
      */
     pub async fn leave(
         &self,
+        body: &crate::types::ConversationsJoinRequest,
     ) -> ClientResult<crate::Response<crate::types::ConversationsLeaveSuccessSchema>> {
         let url = self.client.url("/conversations.leave", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -337,6 +798,24 @@ impl Conversations {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/conversations.list` endpoint.
+     *
+     * As opposed to `list`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Lists all channels in a Slack team.
+     *
+     * FROM: <https://api.slack.com/methods/conversations.list>
+     */
+    pub fn list_request(&self) -> ConversationsListRequest<'_> {
+        ConversationsListRequest {
+            client: self,
+            exclude_archived: None,
+            types: None,
+            limit: None,
+            cursor: None,
+        }
+    }
     /**
      * This function performs a `POST` to the `/conversations.mark` endpoint.
      *
@@ -348,13 +827,16 @@ impl Conversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `conversations:write`.
      */
-    pub async fn mark(&self) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+    pub async fn mark(
+        &self,
+        body: &crate::types::ConversationsMarkRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let url = self.client.url("/conversations.mark", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -404,6 +886,23 @@ impl Conversations {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/conversations.members` endpoint.
+     *
+     * As opposed to `member`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Retrieve members of a conversation.
+     *
+     * FROM: <https://api.slack.com/methods/conversations.members>
+     */
+    pub fn member_request(&self) -> ConversationsMemberRequest<'_> {
+        ConversationsMemberRequest {
+            client: self,
+            channel: None,
+            limit: None,
+            cursor: None,
+        }
+    }
     /**
      * This function performs a `POST` to the `/conversations.open` endpoint.
      *
@@ -417,13 +916,14 @@ impl Conversations {
      */
     pub async fn open(
         &self,
+        body: &crate::types::ConversationsOpenRequest,
     ) -> ClientResult<crate::Response<crate::types::ConversationsOpenSuccessSchema>> {
         let url = self.client.url("/conversations.open", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -442,13 +942,14 @@ impl Conversations {
      */
     pub async fn rename(
         &self,
+        body: &crate::types::ConversationsRenameRequest,
     ) -> ClientResult<crate::Response<crate::types::ConversationsInfoSuccessSchema>> {
         let url = self.client.url("/conversations.rename", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -518,6 +1019,27 @@ impl Conversations {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/conversations.replies` endpoint.
+     *
+     * As opposed to `replie`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Retrieve a thread of messages posted to a conversation
+     *
+     * FROM: <https://api.slack.com/methods/conversations.replies>
+     */
+    pub fn replie_request(&self) -> ConversationsReplieRequest<'_> {
+        ConversationsReplieRequest {
+            client: self,
+            channel: None,
+            ts: None,
+            latest: None,
+            oldest: None,
+            inclusive: None,
+            limit: None,
+            cursor: None,
+        }
+    }
     /**
      * This function performs a `POST` to the `/conversations.setPurpose` endpoint.
      *
@@ -531,13 +1053,14 @@ impl Conversations {
      */
     pub async fn set_purpose(
         &self,
+        body: &crate::types::ConversationsSetPurposeRequest,
     ) -> ClientResult<crate::Response<crate::types::ConversationsInfoSuccessSchema>> {
         let url = self.client.url("/conversations.setPurpose", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -556,13 +1079,14 @@ impl Conversations {
      */
     pub async fn set_topic(
         &self,
+        body: &crate::types::ConversationsSetTopicRequest,
     ) -> ClientResult<crate::Response<crate::types::ConversationsInfoSuccessSchema>> {
         let url = self.client.url("/conversations.setTopic", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -579,13 +1103,16 @@ impl Conversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `conversations:write`.
      */
-    pub async fn unarchive(&self) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+    pub async fn unarchive(
+        &self,
+        body: &crate::types::ConversationsJoinRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let url = self.client.url("/conversations.unarchive", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
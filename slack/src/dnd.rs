@@ -1,6 +1,80 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Dnd::info`], set with a builder.
+pub struct DndInfoRequest<'a> {
+    client: &'a Dnd,
+    user: Option<&'a str>,
+}
+
+impl<'a> DndInfoRequest<'a> {
+    /// Sets the `user` query parameter.
+    pub fn user(mut self, user: &'a str) -> Self {
+        self.user = Some(user);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::DndInfoSchema>> {
+        let Self { client, user } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = user {
+            query_args.push(("user".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(&format!("/dnd.info?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Dnd::team_info`], set with a builder.
+pub struct DndTeamInfoRequest<'a> {
+    client: &'a Dnd,
+    users: Option<&'a str>,
+}
+
+impl<'a> DndTeamInfoRequest<'a> {
+    /// Sets the `users` query parameter.
+    pub fn users(mut self, users: &'a str) -> Self {
+        self.users = Some(users);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        let Self { client, users } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = users {
+            query_args.push(("users".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/dnd.teamInfo?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct Dnd {
     pub client: Client,
 }
@@ -91,6 +165,21 @@ impl Dnd {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/dnd.info` endpoint.
+     *
+     * As opposed to `info`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Retrieves a user's current Do Not Disturb status.
+     *
+     * FROM: <https://api.slack.com/methods/dnd.info>
+     */
+    pub fn info_request(&self) -> DndInfoRequest<'_> {
+        DndInfoRequest {
+            client: self,
+            user: None,
+        }
+    }
     /**
      * This function performs a `POST` to the `/dnd.setSnooze` endpoint.
      *
@@ -100,13 +189,14 @@ impl Dnd {
      */
     pub async fn set_snooze(
         &self,
+        body: &crate::types::DndSetSnoozeRequest,
     ) -> ClientResult<crate::Response<crate::types::DndSetSnoozeSchema>> {
         let url = self.client.url("/dnd.setSnooze", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -144,4 +234,19 @@ impl Dnd {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/dnd.teamInfo` endpoint.
+     *
+     * As opposed to `team_info`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Retrieves the Do Not Disturb status for up to 50 users on a team.
+     *
+     * FROM: <https://api.slack.com/methods/dnd.teamInfo>
+     */
+    pub fn team_info_request(&self) -> DndTeamInfoRequest<'_> {
+        DndTeamInfoRequest {
+            client: self,
+            users: None,
+        }
+    }
 }
@@ -0,0 +1,164 @@
+//! The errors returned by the API.
+
+use std::fmt;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// An error returned by the Slack API, in a response with `"ok": false`.
+///
+/// FROM: <https://api.slack.com/web#evaluating_responses>
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default, JsonSchema)]
+pub struct SlackError {
+    /// The error code, such as `channel_not_found`.
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub error: String,
+    /// The scopes the method needs, for `missing_scope` errors.
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub needed: String,
+    /// The scopes the token has, for `missing_scope` errors.
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub provided: String,
+    /// The warnings about the call, separated by commas, such as `missing_charset`.
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub warning: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub response_metadata: Option<ApiErrorMetadata>,
+}
+
+/// The details Slack attaches to an error.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default, JsonSchema)]
+pub struct ApiErrorMetadata {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub messages: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<String>,
+}
+
+/// The errors of the Slack API are `SlackError`s.
+pub type ApiError = SlackError;
+
+impl SlackError {
+    fn from_body(body: &[u8]) -> Option<Self> {
+        serde_json::from_slice::<Self>(body)
+            .ok()
+            .filter(|error| !error.error.is_empty())
+    }
+}
+
+impl fmt::Display for SlackError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.error)?;
+        if !self.needed.is_empty() {
+            write!(f, " (needed: {}, provided: {})", self.needed, self.provided)?;
+        }
+        Ok(())
+    }
+}
+
+impl crate::ClientError {
+    /// Turns a successful response that reports a failed call into an error.
+    ///
+    /// Calls that were rate limited become `ClientError::RateLimited`, which waits for as
+    /// long as the `Retry-After` header of the response asks, if it has one.
+    pub(crate) fn from_slack_response(
+        status: http::StatusCode,
+        headers: &reqwest::header::HeaderMap,
+        body: &[u8],
+    ) -> Option<Self> {
+        #[derive(Deserialize)]
+        struct Envelope {
+            ok: Option<bool>,
+            #[serde(flatten)]
+            error: SlackError,
+        }
+
+        if !status.is_success() {
+            return None;
+        }
+
+        match serde_json::from_slice::<Envelope>(body).ok()? {
+            Envelope {
+                ok: Some(false),
+                error,
+            } if error.error == "ratelimited" => {
+                let rate_limit = crate::RateLimitInfo::from_headers(headers).unwrap_or_default();
+                Some(crate::ClientError::RateLimited {
+                    status,
+                    headers: headers.clone(),
+                    duration: rate_limit.wait().map(|wait| wait.as_secs()),
+                    info: rate_limit,
+                    error: Some(Box::new(error)),
+                })
+            }
+            Envelope {
+                ok: Some(false),
+                error,
+            } => Some(crate::ClientError::ApiError {
+                status,
+                headers: headers.clone(),
+                error: Box::new(error),
+            }),
+            _ => None,
+        }
+    }
+}
+
+impl crate::ClientError {
+    /// Turns an unsuccessful response into an error, parsing its body into an `ApiError`
+    /// when it holds one. Responses to requests that were rate limited become
+    /// `ClientError::RateLimited`. Those keep their `ApiError` as well.
+    pub(crate) fn from_response(
+        status: http::StatusCode,
+        headers: reqwest::header::HeaderMap,
+        body: &[u8],
+    ) -> Self {
+        let rate_limit = crate::RateLimitInfo::from_headers(&headers).unwrap_or_default();
+        if status == http::StatusCode::TOO_MANY_REQUESTS {
+            return crate::ClientError::RateLimited {
+                status,
+                duration: rate_limit.wait().map(|wait| wait.as_secs()),
+                info: rate_limit,
+                error: ApiError::from_body(body).map(Box::new),
+                headers,
+            };
+        }
+
+        if body.is_empty() {
+            return crate::ClientError::HttpError {
+                status,
+                headers,
+                error: "empty response".into(),
+            };
+        }
+
+        match ApiError::from_body(body) {
+            Some(error) => crate::ClientError::ApiError {
+                status,
+                headers,
+                error: Box::new(error),
+            },
+            None => crate::ClientError::HttpError {
+                status,
+                headers,
+                error: String::from_utf8_lossy(body).into(),
+            },
+        }
+    }
+}
@@ -1,6 +1,205 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Files::info`], set with a builder.
+pub struct FilesInfoRequest<'a> {
+    client: &'a Files,
+    file: Option<&'a str>,
+    count: Option<&'a str>,
+    page: Option<&'a str>,
+    limit: Option<i64>,
+    cursor: Option<&'a str>,
+}
+
+impl<'a> FilesInfoRequest<'a> {
+    /// Sets the `file` query parameter.
+    pub fn file(mut self, file: &'a str) -> Self {
+        self.file = Some(file);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: &'a str) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: &'a str) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `cursor` query parameter.
+    pub fn cursor(mut self, cursor: &'a str) -> Self {
+        self.cursor = Some(cursor);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::FilesInfoSchema>> {
+        let Self {
+            client,
+            file,
+            count,
+            page,
+            limit,
+            cursor,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = count {
+            query_args.push(("count".to_string(), v.to_string()));
+        }
+        if let Some(v) = cursor {
+            query_args.push(("cursor".to_string(), v.to_string()));
+        }
+        if let Some(v) = file {
+            query_args.push(("file".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(&format!("/files.info?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Files::list`], set with a builder.
+pub struct FilesListRequest<'a> {
+    client: &'a Files,
+    user: Option<&'a str>,
+    channel: Option<&'a str>,
+    ts_from: Option<f64>,
+    ts_to: Option<f64>,
+    types: Option<&'a str>,
+    count: Option<&'a str>,
+    page: Option<&'a str>,
+    show_files_hidden_by_limit: Option<bool>,
+}
+
+impl<'a> FilesListRequest<'a> {
+    /// Sets the `user` query parameter.
+    pub fn user(mut self, user: &'a str) -> Self {
+        self.user = Some(user);
+        self
+    }
+
+    /// Sets the `channel` query parameter.
+    pub fn channel(mut self, channel: &'a str) -> Self {
+        self.channel = Some(channel);
+        self
+    }
+
+    /// Sets the `ts_from` query parameter.
+    pub fn ts_from(mut self, ts_from: f64) -> Self {
+        self.ts_from = Some(ts_from);
+        self
+    }
+
+    /// Sets the `ts_to` query parameter.
+    pub fn ts_to(mut self, ts_to: f64) -> Self {
+        self.ts_to = Some(ts_to);
+        self
+    }
+
+    /// Sets the `types` query parameter.
+    pub fn types(mut self, types: &'a str) -> Self {
+        self.types = Some(types);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: &'a str) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: &'a str) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sets the `show_files_hidden_by_limit` query parameter.
+    pub fn show_files_hidden_by_limit(mut self, show_files_hidden_by_limit: bool) -> Self {
+        self.show_files_hidden_by_limit = Some(show_files_hidden_by_limit);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::FilesListSchema>> {
+        let Self {
+            client,
+            user,
+            channel,
+            ts_from,
+            ts_to,
+            types,
+            count,
+            page,
+            show_files_hidden_by_limit,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = channel {
+            query_args.push(("channel".to_string(), v.to_string()));
+        }
+        if let Some(v) = count {
+            query_args.push(("count".to_string(), v.to_string()));
+        }
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = show_files_hidden_by_limit {
+            query_args.push(("show_files_hidden_by_limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = ts_from {
+            query_args.push(("ts_from".to_string(), v.to_string()));
+        }
+        if let Some(v) = ts_to {
+            query_args.push(("ts_to".to_string(), v.to_string()));
+        }
+        if let Some(v) = types {
+            query_args.push(("types".to_string(), v.to_string()));
+        }
+        if let Some(v) = user {
+            query_args.push(("user".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(&format!("/files.list?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct Files {
     pub client: Client,
 }
@@ -22,13 +221,16 @@ impl Files {
      *
      * * `token: &str` -- Authentication token. Requires scope: `files:write:user`.
      */
-    pub async fn delete(&self) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+    pub async fn delete(
+        &self,
+        body: &crate::types::FilesDeleteRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let url = self.client.url("/files.delete", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -86,6 +288,25 @@ impl Files {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/files.info` endpoint.
+     *
+     * As opposed to `info`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Gets information about a file.
+     *
+     * FROM: <https://api.slack.com/methods/files.info>
+     */
+    pub fn info_request(&self) -> FilesInfoRequest<'_> {
+        FilesInfoRequest {
+            client: self,
+            file: None,
+            count: None,
+            page: None,
+            limit: None,
+            cursor: None,
+        }
+    }
     /**
      * This function performs a `GET` to the `/files.list` endpoint.
      *
@@ -156,6 +377,28 @@ impl Files {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/files.list` endpoint.
+     *
+     * As opposed to `list`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * List for a team, in a channel, or from a user with applied filters.
+     *
+     * FROM: <https://api.slack.com/methods/files.list>
+     */
+    pub fn list_request(&self) -> FilesListRequest<'_> {
+        FilesListRequest {
+            client: self,
+            user: None,
+            channel: None,
+            ts_from: None,
+            ts_to: None,
+            types: None,
+            count: None,
+            page: None,
+            show_files_hidden_by_limit: None,
+        }
+    }
     /**
      * This function performs a `POST` to the `/files.revokePublicURL` endpoint.
      *
@@ -169,13 +412,14 @@ impl Files {
      */
     pub async fn revoke_public_url(
         &self,
+        body: &crate::types::FilesDeleteRequest,
     ) -> ClientResult<crate::Response<crate::types::FilesUploadSchema>> {
         let url = self.client.url("/files.revokePublicURL", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -194,13 +438,14 @@ impl Files {
      */
     pub async fn shared_public_url(
         &self,
+        body: &crate::types::FilesDeleteRequest,
     ) -> ClientResult<crate::Response<crate::types::FilesUploadSchema>> {
         let url = self.client.url("/files.sharedPublicURL", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -213,13 +458,16 @@ impl Files {
      *
      * FROM: <https://api.slack.com/methods/files.upload>
      */
-    pub async fn upload(&self) -> ClientResult<crate::Response<crate::types::FilesUploadSchema>> {
+    pub async fn upload(
+        &self,
+        body: &crate::types::FilesUploadRequest,
+    ) -> ClientResult<crate::Response<crate::types::FilesUploadSchema>> {
         let url = self.client.url("/files.upload", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -1,6 +1,206 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`FilesRemote::info`], set with a builder.
+pub struct FilesRemoteInfoRequest<'a> {
+    client: &'a FilesRemote,
+    file: Option<&'a str>,
+    external_id: Option<&'a str>,
+}
+
+impl<'a> FilesRemoteInfoRequest<'a> {
+    /// Sets the `file` query parameter.
+    pub fn file(mut self, file: &'a str) -> Self {
+        self.file = Some(file);
+        self
+    }
+
+    /// Sets the `external_id` query parameter.
+    pub fn external_id(mut self, external_id: &'a str) -> Self {
+        self.external_id = Some(external_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        let Self {
+            client,
+            file,
+            external_id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = external_id {
+            query_args.push(("external_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = file {
+            query_args.push(("file".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/files.remote.info?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`FilesRemote::list`], set with a builder.
+pub struct FilesRemoteListRequest<'a> {
+    client: &'a FilesRemote,
+    channel: Option<&'a str>,
+    ts_from: Option<f64>,
+    ts_to: Option<f64>,
+    limit: Option<i64>,
+    cursor: Option<&'a str>,
+}
+
+impl<'a> FilesRemoteListRequest<'a> {
+    /// Sets the `channel` query parameter.
+    pub fn channel(mut self, channel: &'a str) -> Self {
+        self.channel = Some(channel);
+        self
+    }
+
+    /// Sets the `ts_from` query parameter.
+    pub fn ts_from(mut self, ts_from: f64) -> Self {
+        self.ts_from = Some(ts_from);
+        self
+    }
+
+    /// Sets the `ts_to` query parameter.
+    pub fn ts_to(mut self, ts_to: f64) -> Self {
+        self.ts_to = Some(ts_to);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `cursor` query parameter.
+    pub fn cursor(mut self, cursor: &'a str) -> Self {
+        self.cursor = Some(cursor);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        let Self {
+            client,
+            channel,
+            ts_from,
+            ts_to,
+            limit,
+            cursor,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = channel {
+            query_args.push(("channel".to_string(), v.to_string()));
+        }
+        if let Some(v) = cursor {
+            query_args.push(("cursor".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = ts_from {
+            query_args.push(("ts_from".to_string(), v.to_string()));
+        }
+        if let Some(v) = ts_to {
+            query_args.push(("ts_to".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/files.remote.list?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`FilesRemote::share`], set with a builder.
+pub struct FilesRemoteShareRequest<'a> {
+    client: &'a FilesRemote,
+    file: Option<&'a str>,
+    external_id: Option<&'a str>,
+    channels: Option<&'a str>,
+}
+
+impl<'a> FilesRemoteShareRequest<'a> {
+    /// Sets the `file` query parameter.
+    pub fn file(mut self, file: &'a str) -> Self {
+        self.file = Some(file);
+        self
+    }
+
+    /// Sets the `external_id` query parameter.
+    pub fn external_id(mut self, external_id: &'a str) -> Self {
+        self.external_id = Some(external_id);
+        self
+    }
+
+    /// Sets the `channels` query parameter.
+    pub fn channels(mut self, channels: &'a str) -> Self {
+        self.channels = Some(channels);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        let Self {
+            client,
+            file,
+            external_id,
+            channels,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = channels {
+            query_args.push(("channels".to_string(), v.to_string()));
+        }
+        if let Some(v) = external_id {
+            query_args.push(("external_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = file {
+            query_args.push(("file".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/files.remote.share?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct FilesRemote {
     pub client: Client,
 }
@@ -18,13 +218,16 @@ impl FilesRemote {
      *
      * FROM: <https://api.slack.com/methods/files.remote.add>
      */
-    pub async fn add(&self) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+    pub async fn add(
+        &self,
+        body: &crate::types::FilesRemoteAddRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let url = self.client.url("/files.remote.add", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -69,6 +272,22 @@ impl FilesRemote {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/files.remote.info` endpoint.
+     *
+     * As opposed to `info`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Retrieve information about a remote file added to Slack
+     *
+     * FROM: <https://api.slack.com/methods/files.remote.info>
+     */
+    pub fn info_request(&self) -> FilesRemoteInfoRequest<'_> {
+        FilesRemoteInfoRequest {
+            client: self,
+            file: None,
+            external_id: None,
+        }
+    }
     /**
      * This function performs a `GET` to the `/files.remote.list` endpoint.
      *
@@ -123,6 +342,25 @@ impl FilesRemote {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/files.remote.list` endpoint.
+     *
+     * As opposed to `list`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Retrieve information about a remote file added to Slack
+     *
+     * FROM: <https://api.slack.com/methods/files.remote.list>
+     */
+    pub fn list_request(&self) -> FilesRemoteListRequest<'_> {
+        FilesRemoteListRequest {
+            client: self,
+            channel: None,
+            ts_from: None,
+            ts_to: None,
+            limit: None,
+            cursor: None,
+        }
+    }
     /**
      * This function performs a `POST` to the `/files.remote.remove` endpoint.
      *
@@ -130,13 +368,16 @@ impl FilesRemote {
      *
      * FROM: <https://api.slack.com/methods/files.remote.remove>
      */
-    pub async fn remove(&self) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+    pub async fn remove(
+        &self,
+        body: &crate::types::FilesRemoteRemoveRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let url = self.client.url("/files.remote.remove", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -186,6 +427,23 @@ impl FilesRemote {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/files.remote.share` endpoint.
+     *
+     * As opposed to `share`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Share a remote file into a channel.
+     *
+     * FROM: <https://api.slack.com/methods/files.remote.share>
+     */
+    pub fn share_request(&self) -> FilesRemoteShareRequest<'_> {
+        FilesRemoteShareRequest {
+            client: self,
+            file: None,
+            external_id: None,
+            channels: None,
+        }
+    }
     /**
      * This function performs a `POST` to the `/files.remote.update` endpoint.
      *
@@ -193,13 +451,16 @@ impl FilesRemote {
      *
      * FROM: <https://api.slack.com/methods/files.remote.update>
      */
-    pub async fn update(&self) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+    pub async fn update(
+        &self,
+        body: &crate::types::FilesRemoteUpdateRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let url = self.client.url("/files.remote.update", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -1,6 +1,65 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Migration::exchange`], set with a builder.
+pub struct MigrationExchangeRequest<'a> {
+    client: &'a Migration,
+    users: &'a str,
+    team_id: Option<&'a str>,
+    to_old: Option<bool>,
+}
+
+impl<'a> MigrationExchangeRequest<'a> {
+    /// Sets the `team_id` query parameter.
+    pub fn team_id(mut self, team_id: &'a str) -> Self {
+        self.team_id = Some(team_id);
+        self
+    }
+
+    /// Sets the `to_old` query parameter.
+    pub fn to_old(mut self, to_old: bool) -> Self {
+        self.to_old = Some(to_old);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::MigrationExchangeSuccessSchema>> {
+        let Self {
+            client,
+            users,
+            team_id,
+            to_old,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = team_id {
+            query_args.push(("team_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = to_old {
+            query_args.push(("to_old".to_string(), v.to_string()));
+        }
+        if !users.is_empty() {
+            query_args.push(("users".to_string(), users.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/migration.exchange?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct Migration {
     pub client: Client,
 }
@@ -55,4 +114,21 @@ impl Migration {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/migration.exchange` endpoint.
+     *
+     * As opposed to `exchange`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * For Enterprise Grid workspaces, map local user IDs to global user IDs
+     *
+     * FROM: <https://api.slack.com/methods/migration.exchange>
+     */
+    pub fn exchange_request<'a>(&'a self, users: &'a str) -> MigrationExchangeRequest<'a> {
+        MigrationExchangeRequest {
+            client: self,
+            users,
+            team_id: None,
+            to_old: None,
+        }
+    }
 }
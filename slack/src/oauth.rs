@@ -1,6 +1,174 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Oauth::access`], set with a builder.
+pub struct OauthAccessRequest<'a> {
+    client: &'a Oauth,
+    client_id: Option<&'a str>,
+    client_secret: Option<&'a str>,
+    code: Option<&'a str>,
+    redirect_uri: Option<&'a str>,
+    single_channel: Option<bool>,
+}
+
+impl<'a> OauthAccessRequest<'a> {
+    /// Sets the `client_id` query parameter.
+    pub fn client_id(mut self, client_id: &'a str) -> Self {
+        self.client_id = Some(client_id);
+        self
+    }
+
+    /// Sets the `client_secret` query parameter.
+    pub fn client_secret(mut self, client_secret: &'a str) -> Self {
+        self.client_secret = Some(client_secret);
+        self
+    }
+
+    /// Sets the `code` query parameter.
+    pub fn code(mut self, code: &'a str) -> Self {
+        self.code = Some(code);
+        self
+    }
+
+    /// Sets the `redirect_uri` query parameter.
+    pub fn redirect_uri(mut self, redirect_uri: &'a str) -> Self {
+        self.redirect_uri = Some(redirect_uri);
+        self
+    }
+
+    /// Sets the `single_channel` query parameter.
+    pub fn single_channel(mut self, single_channel: bool) -> Self {
+        self.single_channel = Some(single_channel);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        let Self {
+            client,
+            client_id,
+            client_secret,
+            code,
+            redirect_uri,
+            single_channel,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = client_id {
+            query_args.push(("client_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = client_secret {
+            query_args.push(("client_secret".to_string(), v.to_string()));
+        }
+        if let Some(v) = code {
+            query_args.push(("code".to_string(), v.to_string()));
+        }
+        if let Some(v) = redirect_uri {
+            query_args.push(("redirect_uri".to_string(), v.to_string()));
+        }
+        if let Some(v) = single_channel {
+            query_args.push(("single_channel".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/oauth.access?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Oauth::token`], set with a builder.
+pub struct OauthTokenRequest<'a> {
+    client: &'a Oauth,
+    client_id: Option<&'a str>,
+    client_secret: Option<&'a str>,
+    code: Option<&'a str>,
+    redirect_uri: Option<&'a str>,
+    single_channel: Option<bool>,
+}
+
+impl<'a> OauthTokenRequest<'a> {
+    /// Sets the `client_id` query parameter.
+    pub fn client_id(mut self, client_id: &'a str) -> Self {
+        self.client_id = Some(client_id);
+        self
+    }
+
+    /// Sets the `client_secret` query parameter.
+    pub fn client_secret(mut self, client_secret: &'a str) -> Self {
+        self.client_secret = Some(client_secret);
+        self
+    }
+
+    /// Sets the `code` query parameter.
+    pub fn code(mut self, code: &'a str) -> Self {
+        self.code = Some(code);
+        self
+    }
+
+    /// Sets the `redirect_uri` query parameter.
+    pub fn redirect_uri(mut self, redirect_uri: &'a str) -> Self {
+        self.redirect_uri = Some(redirect_uri);
+        self
+    }
+
+    /// Sets the `single_channel` query parameter.
+    pub fn single_channel(mut self, single_channel: bool) -> Self {
+        self.single_channel = Some(single_channel);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        let Self {
+            client,
+            client_id,
+            client_secret,
+            code,
+            redirect_uri,
+            single_channel,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = client_id {
+            query_args.push(("client_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = client_secret {
+            query_args.push(("client_secret".to_string(), v.to_string()));
+        }
+        if let Some(v) = code {
+            query_args.push(("code".to_string(), v.to_string()));
+        }
+        if let Some(v) = redirect_uri {
+            query_args.push(("redirect_uri".to_string(), v.to_string()));
+        }
+        if let Some(v) = single_channel {
+            query_args.push(("single_channel".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(&format!("/oauth.token?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct Oauth {
     pub client: Client,
 }
@@ -62,6 +230,25 @@ impl Oauth {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/oauth.access` endpoint.
+     *
+     * As opposed to `access`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Exchanges a temporary OAuth verifier code for an access token.
+     *
+     * FROM: <https://api.slack.com/methods/oauth.access>
+     */
+    pub fn access_request(&self) -> OauthAccessRequest<'_> {
+        OauthAccessRequest {
+            client: self,
+            client_id: None,
+            client_secret: None,
+            code: None,
+            redirect_uri: None,
+            single_channel: None,
+        }
+    }
     /**
      * This function performs a `GET` to the `/oauth.token` endpoint.
      *
@@ -113,4 +300,23 @@ impl Oauth {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/oauth.token` endpoint.
+     *
+     * As opposed to `token`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Exchanges a temporary OAuth verifier code for a workspace token.
+     *
+     * FROM: <https://api.slack.com/methods/oauth.token>
+     */
+    pub fn token_request(&self) -> OauthTokenRequest<'_> {
+        OauthTokenRequest {
+            client: self,
+            client_id: None,
+            client_secret: None,
+            code: None,
+            redirect_uri: None,
+            single_channel: None,
+        }
+    }
 }
@@ -1,6 +1,74 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`OauthV2::oauth_access`], set with a builder.
+pub struct OauthV2OauthAccessRequest<'a> {
+    client: &'a OauthV2,
+    client_id: Option<&'a str>,
+    client_secret: Option<&'a str>,
+    code: &'a str,
+    redirect_uri: Option<&'a str>,
+}
+
+impl<'a> OauthV2OauthAccessRequest<'a> {
+    /// Sets the `client_id` query parameter.
+    pub fn client_id(mut self, client_id: &'a str) -> Self {
+        self.client_id = Some(client_id);
+        self
+    }
+
+    /// Sets the `client_secret` query parameter.
+    pub fn client_secret(mut self, client_secret: &'a str) -> Self {
+        self.client_secret = Some(client_secret);
+        self
+    }
+
+    /// Sets the `redirect_uri` query parameter.
+    pub fn redirect_uri(mut self, redirect_uri: &'a str) -> Self {
+        self.redirect_uri = Some(redirect_uri);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        let Self {
+            client,
+            client_id,
+            client_secret,
+            code,
+            redirect_uri,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = client_id {
+            query_args.push(("client_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = client_secret {
+            query_args.push(("client_secret".to_string(), v.to_string()));
+        }
+        if !code.is_empty() {
+            query_args.push(("code".to_string(), code.to_string()));
+        }
+        if let Some(v) = redirect_uri {
+            query_args.push(("redirect_uri".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/oauth.v2.access?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct OauthV2 {
     pub client: Client,
 }
@@ -59,4 +127,22 @@ impl OauthV2 {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/oauth.v2.access` endpoint.
+     *
+     * As opposed to `oauth_access`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Exchanges a temporary OAuth verifier code for an access token.
+     *
+     * FROM: <https://api.slack.com/methods/oauth.v2.access>
+     */
+    pub fn oauth_access_request<'a>(&'a self, code: &'a str) -> OauthV2OauthAccessRequest<'a> {
+        OauthV2OauthAccessRequest {
+            client: self,
+            client_id: None,
+            client_secret: None,
+            code,
+            redirect_uri: None,
+        }
+    }
 }
@@ -22,13 +22,16 @@ impl Pins {
      *
      * * `token: &str` -- Authentication token. Requires scope: `pins:write`.
      */
-    pub async fn add(&self) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+    pub async fn add(
+        &self,
+        body: &crate::types::PinsAddRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let url = self.client.url("/pins.add", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -95,6 +98,28 @@ impl Pins {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/pins.list` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Lists items pinned to a channel.
+     *
+     * FROM: <https://api.slack.com/methods/pins.list>
+     */
+    pub fn list_all_stream(
+        &self,
+        channel: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::PinsListResponseAnyOf>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !channel.is_empty() {
+            query_args.push(("channel".to_string(), channel.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/pins.list?{}", query_), None);
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * This function performs a `POST` to the `/pins.remove` endpoint.
      *
@@ -106,13 +131,16 @@ impl Pins {
      *
      * * `token: &str` -- Authentication token. Requires scope: `pins:write`.
      */
-    pub async fn remove(&self) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+    pub async fn remove(
+        &self,
+        body: &crate::types::PinsAddRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let url = self.client.url("/pins.remove", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -1,6 +1,187 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Reactions::get`], set with a builder.
+pub struct ReactionsGetRequest<'a> {
+    client: &'a Reactions,
+    channel: Option<&'a str>,
+    file: Option<&'a str>,
+    file_comment: Option<&'a str>,
+    full: Option<bool>,
+    timestamp: Option<&'a str>,
+}
+
+impl<'a> ReactionsGetRequest<'a> {
+    /// Sets the `channel` query parameter.
+    pub fn channel(mut self, channel: &'a str) -> Self {
+        self.channel = Some(channel);
+        self
+    }
+
+    /// Sets the `file` query parameter.
+    pub fn file(mut self, file: &'a str) -> Self {
+        self.file = Some(file);
+        self
+    }
+
+    /// Sets the `file_comment` query parameter.
+    pub fn file_comment(mut self, file_comment: &'a str) -> Self {
+        self.file_comment = Some(file_comment);
+        self
+    }
+
+    /// Sets the `full` query parameter.
+    pub fn full(mut self, full: bool) -> Self {
+        self.full = Some(full);
+        self
+    }
+
+    /// Sets the `timestamp` query parameter.
+    pub fn timestamp(mut self, timestamp: &'a str) -> Self {
+        self.timestamp = Some(timestamp);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::Fields>> {
+        let Self {
+            client,
+            channel,
+            file,
+            file_comment,
+            full,
+            timestamp,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = channel {
+            query_args.push(("channel".to_string(), v.to_string()));
+        }
+        if let Some(v) = file {
+            query_args.push(("file".to_string(), v.to_string()));
+        }
+        if let Some(v) = file_comment {
+            query_args.push(("file_comment".to_string(), v.to_string()));
+        }
+        if let Some(v) = full {
+            query_args.push(("full".to_string(), v.to_string()));
+        }
+        if let Some(v) = timestamp {
+            query_args.push(("timestamp".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/reactions.get?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Reactions::list`], set with a builder.
+pub struct ReactionsListRequest<'a> {
+    client: &'a Reactions,
+    user: Option<&'a str>,
+    full: Option<bool>,
+    count: Option<i64>,
+    page: Option<i64>,
+    cursor: Option<&'a str>,
+    limit: Option<i64>,
+}
+
+impl<'a> ReactionsListRequest<'a> {
+    /// Sets the `user` query parameter.
+    pub fn user(mut self, user: &'a str) -> Self {
+        self.user = Some(user);
+        self
+    }
+
+    /// Sets the `full` query parameter.
+    pub fn full(mut self, full: bool) -> Self {
+        self.full = Some(full);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sets the `cursor` query parameter.
+    pub fn cursor(mut self, cursor: &'a str) -> Self {
+        self.cursor = Some(cursor);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::ReactionsListSchema>> {
+        let Self {
+            client,
+            user,
+            full,
+            count,
+            page,
+            cursor,
+            limit,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = count {
+            query_args.push(("count".to_string(), v.to_string()));
+        }
+        if let Some(v) = cursor {
+            query_args.push(("cursor".to_string(), v.to_string()));
+        }
+        if let Some(v) = full {
+            query_args.push(("full".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = user {
+            query_args.push(("user".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/reactions.list?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct Reactions {
     pub client: Client,
 }
@@ -22,13 +203,16 @@ impl Reactions {
      *
      * * `token: &str` -- Authentication token. Requires scope: `reactions:write`.
      */
-    pub async fn add(&self) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+    pub async fn add(
+        &self,
+        body: &crate::types::ReactionsAddRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let url = self.client.url("/reactions.add", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -86,6 +270,25 @@ impl Reactions {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/reactions.get` endpoint.
+     *
+     * As opposed to `get`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Gets reactions for an item.
+     *
+     * FROM: <https://api.slack.com/methods/reactions.get>
+     */
+    pub fn get_request(&self) -> ReactionsGetRequest<'_> {
+        ReactionsGetRequest {
+            client: self,
+            channel: None,
+            file: None,
+            file_comment: None,
+            full: None,
+            timestamp: None,
+        }
+    }
     /**
      * This function performs a `GET` to the `/reactions.list` endpoint.
      *
@@ -145,6 +348,26 @@ impl Reactions {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/reactions.list` endpoint.
+     *
+     * As opposed to `list`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Lists reactions made by a user.
+     *
+     * FROM: <https://api.slack.com/methods/reactions.list>
+     */
+    pub fn list_request(&self) -> ReactionsListRequest<'_> {
+        ReactionsListRequest {
+            client: self,
+            user: None,
+            full: None,
+            count: None,
+            page: None,
+            cursor: None,
+            limit: None,
+        }
+    }
     /**
      * This function performs a `POST` to the `/reactions.remove` endpoint.
      *
@@ -156,13 +379,16 @@ impl Reactions {
      *
      * * `token: &str` -- Authentication token. Requires scope: `reactions:write`.
      */
-    pub async fn remove(&self) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+    pub async fn remove(
+        &self,
+        body: &crate::types::ReactionsRemoveRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let url = self.client.url("/reactions.remove", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
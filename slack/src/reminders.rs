@@ -1,6 +1,44 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Reminders::info`], set with a builder.
+pub struct RemindersInfoRequest<'a> {
+    client: &'a Reminders,
+    reminder: Option<&'a str>,
+}
+
+impl<'a> RemindersInfoRequest<'a> {
+    /// Sets the `reminder` query parameter.
+    pub fn reminder(mut self, reminder: &'a str) -> Self {
+        self.reminder = Some(reminder);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::RemindersAddSchema>> {
+        let Self { client, reminder } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = reminder {
+            query_args.push(("reminder".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/reminders.info?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct Reminders {
     pub client: Client,
 }
@@ -22,13 +60,16 @@ impl Reminders {
      *
      * * `token: &str` -- Authentication token. Requires scope: `reminders:write`.
      */
-    pub async fn add(&self) -> ClientResult<crate::Response<crate::types::RemindersAddSchema>> {
+    pub async fn add(
+        &self,
+        body: &crate::types::RemindersAddRequest,
+    ) -> ClientResult<crate::Response<crate::types::RemindersAddSchema>> {
         let url = self.client.url("/reminders.add", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -45,13 +86,16 @@ impl Reminders {
      *
      * * `token: &str` -- Authentication token. Requires scope: `reminders:write`.
      */
-    pub async fn complete(&self) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+    pub async fn complete(
+        &self,
+        body: &crate::types::RemindersDeleteRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let url = self.client.url("/reminders.complete", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -68,13 +112,16 @@ impl Reminders {
      *
      * * `token: &str` -- Authentication token. Requires scope: `reminders:write`.
      */
-    pub async fn delete(&self) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+    pub async fn delete(
+        &self,
+        body: &crate::types::RemindersDeleteRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let url = self.client.url("/reminders.delete", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -114,6 +161,21 @@ impl Reminders {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/reminders.info` endpoint.
+     *
+     * As opposed to `info`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Gets information about a reminder.
+     *
+     * FROM: <https://api.slack.com/methods/reminders.info>
+     */
+    pub fn info_request(&self) -> RemindersInfoRequest<'_> {
+        RemindersInfoRequest {
+            client: self,
+            reminder: None,
+        }
+    }
     /**
      * This function performs a `GET` to the `/reminders.list` endpoint.
      *
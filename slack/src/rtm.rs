@@ -1,6 +1,56 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Rtm::connect`], set with a builder.
+pub struct RtmConnectRequest<'a> {
+    client: &'a Rtm,
+    batch_presence_aware: Option<bool>,
+    presence_sub: Option<bool>,
+}
+
+impl<'a> RtmConnectRequest<'a> {
+    /// Sets the `batch_presence_aware` query parameter.
+    pub fn batch_presence_aware(mut self, batch_presence_aware: bool) -> Self {
+        self.batch_presence_aware = Some(batch_presence_aware);
+        self
+    }
+
+    /// Sets the `presence_sub` query parameter.
+    pub fn presence_sub(mut self, presence_sub: bool) -> Self {
+        self.presence_sub = Some(presence_sub);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::RtmConnectSchema>> {
+        let Self {
+            client,
+            batch_presence_aware,
+            presence_sub,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = batch_presence_aware {
+            query_args.push(("batch_presence_aware".to_string(), v.to_string()));
+        }
+        if let Some(v) = presence_sub {
+            query_args.push(("presence_sub".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(&format!("/rtm.connect?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct Rtm {
     pub client: Client,
 }
@@ -51,4 +101,20 @@ impl Rtm {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/rtm.connect` endpoint.
+     *
+     * As opposed to `connect`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Starts a Real Time Messaging session.
+     *
+     * FROM: <https://api.slack.com/methods/rtm.connect>
+     */
+    pub fn connect_request(&self) -> RtmConnectRequest<'_> {
+        RtmConnectRequest {
+            client: self,
+            batch_presence_aware: None,
+            presence_sub: None,
+        }
+    }
 }
@@ -1,6 +1,96 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Search::message`], set with a builder.
+pub struct SearchMessageRequest<'a> {
+    client: &'a Search,
+    count: Option<i64>,
+    highlight: Option<bool>,
+    page: Option<i64>,
+    query: &'a str,
+    sort: Option<&'a str>,
+    sort_dir: Option<&'a str>,
+}
+
+impl<'a> SearchMessageRequest<'a> {
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Sets the `highlight` query parameter.
+    pub fn highlight(mut self, highlight: bool) -> Self {
+        self.highlight = Some(highlight);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sets the `sort` query parameter.
+    pub fn sort(mut self, sort: &'a str) -> Self {
+        self.sort = Some(sort);
+        self
+    }
+
+    /// Sets the `sort_dir` query parameter.
+    pub fn sort_dir(mut self, sort_dir: &'a str) -> Self {
+        self.sort_dir = Some(sort_dir);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        let Self {
+            client,
+            count,
+            highlight,
+            page,
+            query,
+            sort,
+            sort_dir,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = count {
+            query_args.push(("count".to_string(), v.to_string()));
+        }
+        if let Some(v) = highlight {
+            query_args.push(("highlight".to_string(), v.to_string()));
+        }
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if !query.is_empty() {
+            query_args.push(("query".to_string(), query.to_string()));
+        }
+        if let Some(v) = sort {
+            query_args.push(("sort".to_string(), v.to_string()));
+        }
+        if let Some(v) = sort_dir {
+            query_args.push(("sort_dir".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/search.messages?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct Search {
     pub client: Client,
 }
@@ -70,4 +160,24 @@ impl Search {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/search.messages` endpoint.
+     *
+     * As opposed to `message`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Searches for messages matching a query.
+     *
+     * FROM: <https://api.slack.com/methods/search.messages>
+     */
+    pub fn message_request<'a>(&'a self, query: &'a str) -> SearchMessageRequest<'a> {
+        SearchMessageRequest {
+            client: self,
+            count: None,
+            highlight: None,
+            page: None,
+            query,
+            sort: None,
+            sort_dir: None,
+        }
+    }
 }
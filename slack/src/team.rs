@@ -1,6 +1,241 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Team::access_log`], set with a builder.
+pub struct TeamAccessLogRequest<'a> {
+    client: &'a Team,
+    before: Option<&'a str>,
+    count: Option<&'a str>,
+    page: Option<&'a str>,
+}
+
+impl<'a> TeamAccessLogRequest<'a> {
+    /// Sets the `before` query parameter.
+    pub fn before(mut self, before: &'a str) -> Self {
+        self.before = Some(before);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: &'a str) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: &'a str) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::TeamAccessLogsSchema>> {
+        let Self {
+            client,
+            before,
+            count,
+            page,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = before {
+            query_args.push(("before".to_string(), v.to_string()));
+        }
+        if let Some(v) = count {
+            query_args.push(("count".to_string(), v.to_string()));
+        }
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/team.accessLogs?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Team::billable_info`], set with a builder.
+pub struct TeamBillableInfoRequest<'a> {
+    client: &'a Team,
+    user: Option<&'a str>,
+}
+
+impl<'a> TeamBillableInfoRequest<'a> {
+    /// Sets the `user` query parameter.
+    pub fn user(mut self, user: &'a str) -> Self {
+        self.user = Some(user);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        let Self { client, user } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = user {
+            query_args.push(("user".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/team.billableInfo?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Team::info`], set with a builder.
+pub struct TeamInfoRequest<'a> {
+    client: &'a Team,
+    team: Option<&'a str>,
+}
+
+impl<'a> TeamInfoRequest<'a> {
+    /// Sets the `team` query parameter.
+    pub fn team(mut self, team: &'a str) -> Self {
+        self.team = Some(team);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::TeamInfoSchema>> {
+        let Self { client, team } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = team {
+            query_args.push(("team".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(&format!("/team.info?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Team::integration_log`], set with a builder.
+pub struct TeamIntegrationLogRequest<'a> {
+    client: &'a Team,
+    app_id: Option<&'a str>,
+    change_type: Option<&'a str>,
+    count: Option<&'a str>,
+    page: Option<&'a str>,
+    service_id: Option<&'a str>,
+    user: Option<&'a str>,
+}
+
+impl<'a> TeamIntegrationLogRequest<'a> {
+    /// Sets the `app_id` query parameter.
+    pub fn app_id(mut self, app_id: &'a str) -> Self {
+        self.app_id = Some(app_id);
+        self
+    }
+
+    /// Sets the `change_type` query parameter.
+    pub fn change_type(mut self, change_type: &'a str) -> Self {
+        self.change_type = Some(change_type);
+        self
+    }
+
+    /// Sets the `count` query parameter.
+    pub fn count(mut self, count: &'a str) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: &'a str) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sets the `service_id` query parameter.
+    pub fn service_id(mut self, service_id: &'a str) -> Self {
+        self.service_id = Some(service_id);
+        self
+    }
+
+    /// Sets the `user` query parameter.
+    pub fn user(mut self, user: &'a str) -> Self {
+        self.user = Some(user);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::TeamIntegrationLogsSchema>> {
+        let Self {
+            client,
+            app_id,
+            change_type,
+            count,
+            page,
+            service_id,
+            user,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = app_id {
+            query_args.push(("app_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = change_type {
+            query_args.push(("change_type".to_string(), v.to_string()));
+        }
+        if let Some(v) = count {
+            query_args.push(("count".to_string(), v.to_string()));
+        }
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = service_id {
+            query_args.push(("service_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = user {
+            query_args.push(("user".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/team.integrationLogs?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct Team {
     pub client: Client,
 }
@@ -55,6 +290,23 @@ impl Team {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/team.accessLogs` endpoint.
+     *
+     * As opposed to `access_log`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Gets the access logs for the current team.
+     *
+     * FROM: <https://api.slack.com/methods/team.accessLogs>
+     */
+    pub fn access_log_request(&self) -> TeamAccessLogRequest<'_> {
+        TeamAccessLogRequest {
+            client: self,
+            before: None,
+            count: None,
+            page: None,
+        }
+    }
     /**
      * This function performs a `GET` to the `/team.billableInfo` endpoint.
      *
@@ -89,6 +341,21 @@ impl Team {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/team.billableInfo` endpoint.
+     *
+     * As opposed to `billable_info`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Gets billable users information for the current team.
+     *
+     * FROM: <https://api.slack.com/methods/team.billableInfo>
+     */
+    pub fn billable_info_request(&self) -> TeamBillableInfoRequest<'_> {
+        TeamBillableInfoRequest {
+            client: self,
+            user: None,
+        }
+    }
     /**
      * This function performs a `GET` to the `/team.info` endpoint.
      *
@@ -121,6 +388,21 @@ impl Team {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/team.info` endpoint.
+     *
+     * As opposed to `info`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Gets information about the current team.
+     *
+     * FROM: <https://api.slack.com/methods/team.info>
+     */
+    pub fn info_request(&self) -> TeamInfoRequest<'_> {
+        TeamInfoRequest {
+            client: self,
+            team: None,
+        }
+    }
     /**
      * This function performs a `GET` to the `/team.integrationLogs` endpoint.
      *
@@ -180,4 +462,24 @@ impl Team {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/team.integrationLogs` endpoint.
+     *
+     * As opposed to `integration_log`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Gets the integration logs for the current team.
+     *
+     * FROM: <https://api.slack.com/methods/team.integrationLogs>
+     */
+    pub fn integration_log_request(&self) -> TeamIntegrationLogRequest<'_> {
+        TeamIntegrationLogRequest {
+            client: self,
+            app_id: None,
+            change_type: None,
+            count: None,
+            page: None,
+            service_id: None,
+            user: None,
+        }
+    }
 }
@@ -1,6 +1,46 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`TeamProfile::get`], set with a builder.
+pub struct TeamProfileGetRequest<'a> {
+    client: &'a TeamProfile,
+    visibility: Option<&'a str>,
+}
+
+impl<'a> TeamProfileGetRequest<'a> {
+    /// Sets the `visibility` query parameter.
+    pub fn visibility(mut self, visibility: &'a str) -> Self {
+        self.visibility = Some(visibility);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::TeamProfileGetSuccessSchema>> {
+        let Self { client, visibility } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = visibility {
+            query_args.push(("visibility".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/team.profile.get?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct TeamProfile {
     pub client: Client,
 }
@@ -45,4 +85,19 @@ impl TeamProfile {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/team.profile.get` endpoint.
+     *
+     * As opposed to `get`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Retrieve a team's profile.
+     *
+     * FROM: <https://api.slack.com/methods/team.profile.get>
+     */
+    pub fn get_request(&self) -> TeamProfileGetRequest<'_> {
+        TeamProfileGetRequest {
+            client: self,
+            visibility: None,
+        }
+    }
 }
@@ -1,6 +1,69 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Usergroups::list`], set with a builder.
+pub struct UsergroupsListRequest<'a> {
+    client: &'a Usergroups,
+    include_users: Option<bool>,
+    include_count: Option<bool>,
+    include_disabled: Option<bool>,
+}
+
+impl<'a> UsergroupsListRequest<'a> {
+    /// Sets the `include_users` query parameter.
+    pub fn include_users(mut self, include_users: bool) -> Self {
+        self.include_users = Some(include_users);
+        self
+    }
+
+    /// Sets the `include_count` query parameter.
+    pub fn include_count(mut self, include_count: bool) -> Self {
+        self.include_count = Some(include_count);
+        self
+    }
+
+    /// Sets the `include_disabled` query parameter.
+    pub fn include_disabled(mut self, include_disabled: bool) -> Self {
+        self.include_disabled = Some(include_disabled);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::UsergroupsListSchema>> {
+        let Self {
+            client,
+            include_users,
+            include_count,
+            include_disabled,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = include_count {
+            query_args.push(("include_count".to_string(), v.to_string()));
+        }
+        if let Some(v) = include_disabled {
+            query_args.push(("include_disabled".to_string(), v.to_string()));
+        }
+        if let Some(v) = include_users {
+            query_args.push(("include_users".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/usergroups.list?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct Usergroups {
     pub client: Client,
 }
@@ -24,13 +87,14 @@ impl Usergroups {
      */
     pub async fn create(
         &self,
+        body: &crate::types::UsergroupsCreateRequest,
     ) -> ClientResult<crate::Response<crate::types::UsergroupsCreateSchema>> {
         let url = self.client.url("/usergroups.create", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -49,13 +113,14 @@ impl Usergroups {
      */
     pub async fn disable(
         &self,
+        body: &crate::types::UsergroupsEnableRequest,
     ) -> ClientResult<crate::Response<crate::types::UsergroupsCreateSchema>> {
         let url = self.client.url("/usergroups.disable", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -74,13 +139,14 @@ impl Usergroups {
      */
     pub async fn enable(
         &self,
+        body: &crate::types::UsergroupsEnableRequest,
     ) -> ClientResult<crate::Response<crate::types::UsergroupsCreateSchema>> {
         let url = self.client.url("/usergroups.enable", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -130,6 +196,23 @@ impl Usergroups {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/usergroups.list` endpoint.
+     *
+     * As opposed to `list`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * List all User Groups for a team
+     *
+     * FROM: <https://api.slack.com/methods/usergroups.list>
+     */
+    pub fn list_request(&self) -> UsergroupsListRequest<'_> {
+        UsergroupsListRequest {
+            client: self,
+            include_users: None,
+            include_count: None,
+            include_disabled: None,
+        }
+    }
     /**
      * This function performs a `POST` to the `/usergroups.update` endpoint.
      *
@@ -143,13 +226,14 @@ impl Usergroups {
      */
     pub async fn update(
         &self,
+        body: &crate::types::UsergroupsUpdateRequest,
     ) -> ClientResult<crate::Response<crate::types::UsergroupsCreateSchema>> {
         let url = self.client.url("/usergroups.update", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
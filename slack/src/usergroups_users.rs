@@ -1,6 +1,54 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`UsergroupsUsers::list`], set with a builder.
+pub struct UsergroupsUsersListRequest<'a> {
+    client: &'a UsergroupsUsers,
+    include_disabled: Option<bool>,
+    usergroup: &'a str,
+}
+
+impl<'a> UsergroupsUsersListRequest<'a> {
+    /// Sets the `include_disabled` query parameter.
+    pub fn include_disabled(mut self, include_disabled: bool) -> Self {
+        self.include_disabled = Some(include_disabled);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::UsergroupsUsersListSchema>> {
+        let Self {
+            client,
+            include_disabled,
+            usergroup,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = include_disabled {
+            query_args.push(("include_disabled".to_string(), v.to_string()));
+        }
+        if !usergroup.is_empty() {
+            query_args.push(("usergroup".to_string(), usergroup.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/usergroups.users.list?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct UsergroupsUsers {
     pub client: Client,
 }
@@ -50,6 +98,22 @@ impl UsergroupsUsers {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/usergroups.users.list` endpoint.
+     *
+     * As opposed to `list`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * List all users in a User Group
+     *
+     * FROM: <https://api.slack.com/methods/usergroups.users.list>
+     */
+    pub fn list_request<'a>(&'a self, usergroup: &'a str) -> UsergroupsUsersListRequest<'a> {
+        UsergroupsUsersListRequest {
+            client: self,
+            include_disabled: None,
+            usergroup,
+        }
+    }
     /**
      * This function performs a `POST` to the `/usergroups.users.update` endpoint.
      *
@@ -63,13 +127,14 @@ impl UsergroupsUsers {
      */
     pub async fn update(
         &self,
+        body: &crate::types::UsergroupsUsersUpdateRequest,
     ) -> ClientResult<crate::Response<crate::types::UsergroupsCreateSchema>> {
         let url = self.client.url("/usergroups.users.update", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -1,6 +1,244 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Users::conversation`], set with a builder.
+pub struct UsersConversationRequest<'a> {
+    client: &'a Users,
+    user: Option<&'a str>,
+    types: Option<&'a str>,
+    exclude_archived: Option<bool>,
+    limit: Option<i64>,
+    cursor: Option<&'a str>,
+}
+
+impl<'a> UsersConversationRequest<'a> {
+    /// Sets the `user` query parameter.
+    pub fn user(mut self, user: &'a str) -> Self {
+        self.user = Some(user);
+        self
+    }
+
+    /// Sets the `types` query parameter.
+    pub fn types(mut self, types: &'a str) -> Self {
+        self.types = Some(types);
+        self
+    }
+
+    /// Sets the `exclude_archived` query parameter.
+    pub fn exclude_archived(mut self, exclude_archived: bool) -> Self {
+        self.exclude_archived = Some(exclude_archived);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `cursor` query parameter.
+    pub fn cursor(mut self, cursor: &'a str) -> Self {
+        self.cursor = Some(cursor);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::UsersConversationsSuccessSchema>> {
+        let Self {
+            client,
+            user,
+            types,
+            exclude_archived,
+            limit,
+            cursor,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = cursor {
+            query_args.push(("cursor".to_string(), v.to_string()));
+        }
+        if let Some(v) = exclude_archived {
+            query_args.push(("exclude_archived".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = types {
+            query_args.push(("types".to_string(), v.to_string()));
+        }
+        if let Some(v) = user {
+            query_args.push(("user".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/users.conversations?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Users::get_presence`], set with a builder.
+pub struct UsersGetPresenceRequest<'a> {
+    client: &'a Users,
+    user: Option<&'a str>,
+}
+
+impl<'a> UsersGetPresenceRequest<'a> {
+    /// Sets the `user` query parameter.
+    pub fn user(mut self, user: &'a str) -> Self {
+        self.user = Some(user);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::ApiMethodUsersGetPresence>> {
+        let Self { client, user } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = user {
+            query_args.push(("user".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/users.getPresence?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Users::info`], set with a builder.
+pub struct UsersInfoRequest<'a> {
+    client: &'a Users,
+    include_locale: Option<bool>,
+    user: Option<&'a str>,
+}
+
+impl<'a> UsersInfoRequest<'a> {
+    /// Sets the `include_locale` query parameter.
+    pub fn include_locale(mut self, include_locale: bool) -> Self {
+        self.include_locale = Some(include_locale);
+        self
+    }
+
+    /// Sets the `user` query parameter.
+    pub fn user(mut self, user: &'a str) -> Self {
+        self.user = Some(user);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::UsersInfoSuccessSchema>> {
+        let Self {
+            client,
+            include_locale,
+            user,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = include_locale {
+            query_args.push(("include_locale".to_string(), v.to_string()));
+        }
+        if let Some(v) = user {
+            query_args.push(("user".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(&format!("/users.info?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Users::list`], set with a builder.
+pub struct UsersListRequest<'a> {
+    client: &'a Users,
+    limit: Option<i64>,
+    cursor: Option<&'a str>,
+    include_locale: Option<bool>,
+}
+
+impl<'a> UsersListRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `cursor` query parameter.
+    pub fn cursor(mut self, cursor: &'a str) -> Self {
+        self.cursor = Some(cursor);
+        self
+    }
+
+    /// Sets the `include_locale` query parameter.
+    pub fn include_locale(mut self, include_locale: bool) -> Self {
+        self.include_locale = Some(include_locale);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::UsersListSchema>> {
+        let Self {
+            client,
+            limit,
+            cursor,
+            include_locale,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = cursor {
+            query_args.push(("cursor".to_string(), v.to_string()));
+        }
+        if let Some(v) = include_locale {
+            query_args.push(("include_locale".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(&format!("/users.list?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct Users {
     pub client: Client,
 }
@@ -65,6 +303,25 @@ impl Users {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/users.conversations` endpoint.
+     *
+     * As opposed to `conversation`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * List conversations the calling user may access.
+     *
+     * FROM: <https://api.slack.com/methods/users.conversations>
+     */
+    pub fn conversation_request(&self) -> UsersConversationRequest<'_> {
+        UsersConversationRequest {
+            client: self,
+            user: None,
+            types: None,
+            exclude_archived: None,
+            limit: None,
+            cursor: None,
+        }
+    }
     /**
      * This function performs a `POST` to the `/users.deletePhoto` endpoint.
      *
@@ -72,13 +329,16 @@ impl Users {
      *
      * FROM: <https://api.slack.com/methods/users.deletePhoto>
      */
-    pub async fn delete_photo(&self) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+    pub async fn delete_photo(
+        &self,
+        body: &crate::types::UsersDeletePhotoRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let url = self.client.url("/users.deletePhoto", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -118,6 +378,21 @@ impl Users {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/users.getPresence` endpoint.
+     *
+     * As opposed to `get_presence`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Gets user presence information.
+     *
+     * FROM: <https://api.slack.com/methods/users.getPresence>
+     */
+    pub fn get_presence_request(&self) -> UsersGetPresenceRequest<'_> {
+        UsersGetPresenceRequest {
+            client: self,
+            user: None,
+        }
+    }
     /**
      * This function performs a `GET` to the `/users.identity` endpoint.
      *
@@ -166,6 +441,22 @@ impl Users {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/users.identity` endpoint.
+     *
+     * As opposed to `get_all_identity`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Get a user's identity.
+     *
+     * FROM: <https://api.slack.com/methods/users.identity>
+     */
+    pub fn get_all_identity_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::UsersIdentityResponseAnyOf>> + Unpin + '_
+    {
+        let url = self.client.url("/users.identity", None);
+        self.client.get_all_pages_stream(url)
+    }
     /**
      * This function performs a `GET` to the `/users.info` endpoint.
      *
@@ -203,6 +494,22 @@ impl Users {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/users.info` endpoint.
+     *
+     * As opposed to `info`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Gets information about a user.
+     *
+     * FROM: <https://api.slack.com/methods/users.info>
+     */
+    pub fn info_request(&self) -> UsersInfoRequest<'_> {
+        UsersInfoRequest {
+            client: self,
+            include_locale: None,
+            user: None,
+        }
+    }
     /**
      * This function performs a `GET` to the `/users.list` endpoint.
      *
@@ -245,6 +552,23 @@ impl Users {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/users.list` endpoint.
+     *
+     * As opposed to `list`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Lists all users in a Slack team.
+     *
+     * FROM: <https://api.slack.com/methods/users.list>
+     */
+    pub fn list_request(&self) -> UsersListRequest<'_> {
+        UsersListRequest {
+            client: self,
+            limit: None,
+            cursor: None,
+            include_locale: None,
+        }
+    }
     /**
      * This function performs a `GET` to the `/users.lookupByEmail` endpoint.
      *
@@ -311,13 +635,14 @@ impl Users {
      */
     pub async fn set_photo(
         &self,
+        body: &crate::types::UsersSetPhotoRequest,
     ) -> ClientResult<crate::Response<crate::types::UsersSetPhotoSchema>> {
         let url = self.client.url("/users.setPhoto", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -334,13 +659,16 @@ impl Users {
      *
      * * `token: &str` -- Authentication token. Requires scope: `users:write`.
      */
-    pub async fn set_presence(&self) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+    pub async fn set_presence(
+        &self,
+        body: &crate::types::UsersSetPresenceRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let url = self.client.url("/users.setPresence", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -1,6 +1,58 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`UsersProfile::get`], set with a builder.
+pub struct UsersProfileGetRequest<'a> {
+    client: &'a UsersProfile,
+    include_labels: Option<bool>,
+    user: Option<&'a str>,
+}
+
+impl<'a> UsersProfileGetRequest<'a> {
+    /// Sets the `include_labels` query parameter.
+    pub fn include_labels(mut self, include_labels: bool) -> Self {
+        self.include_labels = Some(include_labels);
+        self
+    }
+
+    /// Sets the `user` query parameter.
+    pub fn user(mut self, user: &'a str) -> Self {
+        self.user = Some(user);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::UsersProfileGetSchema>> {
+        let Self {
+            client,
+            include_labels,
+            user,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = include_labels {
+            query_args.push(("include_labels".to_string(), v.to_string()));
+        }
+        if let Some(v) = user {
+            query_args.push(("user".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/users.profile.get?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct UsersProfile {
     pub client: Client,
 }
@@ -50,6 +102,22 @@ impl UsersProfile {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/users.profile.get` endpoint.
+     *
+     * As opposed to `get`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Retrieves a user's profile information.
+     *
+     * FROM: <https://api.slack.com/methods/users.profile.get>
+     */
+    pub fn get_request(&self) -> UsersProfileGetRequest<'_> {
+        UsersProfileGetRequest {
+            client: self,
+            include_labels: None,
+            user: None,
+        }
+    }
     /**
      * This function performs a `POST` to the `/users.profile.set` endpoint.
      *
@@ -61,13 +129,16 @@ impl UsersProfile {
      *
      * * `token: &str` -- Authentication token. Requires scope: `users.profile:write`.
      */
-    pub async fn set(&self) -> ClientResult<crate::Response<crate::types::UsersProfileSetSchema>> {
+    pub async fn set(
+        &self,
+        body: &crate::types::UsersProfileSetRequest,
+    ) -> ClientResult<crate::Response<crate::types::UsersProfileSetSchema>> {
         let url = self.client.url("/users.profile.set", None);
         self.client
             .post(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(serde_urlencoded::to_string(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
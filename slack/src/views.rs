@@ -1,6 +1,131 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Views::publish`], set with a builder.
+pub struct ViewsPublishRequest<'a> {
+    client: &'a Views,
+    user_id: &'a str,
+    view: &'a str,
+    hash: Option<&'a str>,
+}
+
+impl<'a> ViewsPublishRequest<'a> {
+    /// Sets the `hash` query parameter.
+    pub fn hash(mut self, hash: &'a str) -> Self {
+        self.hash = Some(hash);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        let Self {
+            client,
+            user_id,
+            view,
+            hash,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = hash {
+            query_args.push(("hash".to_string(), v.to_string()));
+        }
+        if !user_id.is_empty() {
+            query_args.push(("user_id".to_string(), user_id.to_string()));
+        }
+        if !view.is_empty() {
+            query_args.push(("view".to_string(), view.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/views.publish?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Views::update`], set with a builder.
+pub struct ViewsUpdateRequest<'a> {
+    client: &'a Views,
+    view_id: Option<&'a str>,
+    external_id: Option<&'a str>,
+    view: Option<&'a str>,
+    hash: Option<&'a str>,
+}
+
+impl<'a> ViewsUpdateRequest<'a> {
+    /// Sets the `view_id` query parameter.
+    pub fn view_id(mut self, view_id: &'a str) -> Self {
+        self.view_id = Some(view_id);
+        self
+    }
+
+    /// Sets the `external_id` query parameter.
+    pub fn external_id(mut self, external_id: &'a str) -> Self {
+        self.external_id = Some(external_id);
+        self
+    }
+
+    /// Sets the `view` query parameter.
+    pub fn view(mut self, view: &'a str) -> Self {
+        self.view = Some(view);
+        self
+    }
+
+    /// Sets the `hash` query parameter.
+    pub fn hash(mut self, hash: &'a str) -> Self {
+        self.hash = Some(hash);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        let Self {
+            client,
+            view_id,
+            external_id,
+            view,
+            hash,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = external_id {
+            query_args.push(("external_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = hash {
+            query_args.push(("hash".to_string(), v.to_string()));
+        }
+        if let Some(v) = view {
+            query_args.push(("view".to_string(), v.to_string()));
+        }
+        if let Some(v) = view_id {
+            query_args.push(("view_id".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/views.update?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct Views {
     pub client: Client,
 }
@@ -90,6 +215,27 @@ impl Views {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/views.publish` endpoint.
+     *
+     * As opposed to `publish`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Publish a static view for a User.
+     *
+     * FROM: <https://api.slack.com/methods/views.publish>
+     */
+    pub fn publish_request<'a>(
+        &'a self,
+        user_id: &'a str,
+        view: &'a str,
+    ) -> ViewsPublishRequest<'a> {
+        ViewsPublishRequest {
+            client: self,
+            user_id,
+            view,
+            hash: None,
+        }
+    }
     /**
      * This function performs a `GET` to the `/views.push` endpoint.
      *
@@ -174,4 +320,22 @@ impl Views {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/views.update` endpoint.
+     *
+     * As opposed to `update`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Update an existing view.
+     *
+     * FROM: <https://api.slack.com/methods/views.update>
+     */
+    pub fn update_request(&self) -> ViewsUpdateRequest<'_> {
+        ViewsUpdateRequest {
+            client: self,
+            view_id: None,
+            external_id: None,
+            view: None,
+            hash: None,
+        }
+    }
 }
@@ -0,0 +1,833 @@
+//! For verifying and parsing the requests Slack sends to an app: Events API events,
+//! slash commands and interactivity payloads.
+//!
+//! FROM: <https://api.slack.com/authentication/verifying-requests-from-slack>
+use std::time::{Duration, SystemTime};
+
+use hmac::{Hmac, Mac};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+
+use crate::types;
+
+/// The header Slack signs requests with.
+pub const SIGNATURE_HEADER: &str = "X-Slack-Signature";
+
+/// The header holding the time Slack signed a request at, in seconds since the epoch.
+pub const TIMESTAMP_HEADER: &str = "X-Slack-Request-Timestamp";
+
+/// How far the timestamp of a request may be from the current time by default.
+pub const DEFAULT_TOLERANCE: Duration = Duration::from_secs(300);
+
+/// Errors returned while verifying or parsing a request.
+#[derive(Debug, thiserror::Error)]
+pub enum WebhookError {
+    /// A header the request needs is missing
+    #[error("missing {0} header")]
+    MissingHeader(&'static str),
+    /// The signature or timestamp header is malformed
+    #[error("invalid X-Slack-Signature or X-Slack-Request-Timestamp header")]
+    InvalidHeader,
+    /// The signature does not match the payload
+    #[error("the signature does not match the payload")]
+    SignatureMismatch,
+    /// The request is too old, or from the future
+    #[error("the request timestamp is outside of the tolerance")]
+    TimestampOutsideTolerance,
+    /// The payload is not JSON of the expected shape
+    #[error(transparent)]
+    SerdeJsonError(#[from] serde_json::Error),
+    /// The payload is not a form of the expected shape
+    #[error(transparent)]
+    SerdeUrlencodedError(#[from] serde_urlencoded::de::Error),
+}
+
+/// The body of a request to the Request URL of the Events API.
+#[derive(Debug, Clone, PartialEq)]
+pub enum EventPayload {
+    /// Sent once, when the Request URL is set, to check the app controls it.
+    UrlVerification(UrlVerification),
+    /// An event the app is subscribed to.
+    EventCallback(Box<EventCallback>),
+    /// Sent when the app is sent more events than it is allowed to receive.
+    AppRateLimited(AppRateLimited),
+    Other(serde_json::Value),
+}
+
+impl EventPayload {
+    /// Returns the challenge to respond with, as the plain text body of the response,
+    /// for the URL verification request.
+    pub fn challenge(&self) -> Option<&str> {
+        match self {
+            EventPayload::UrlVerification(verification) => Some(&verification.challenge),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default, JsonSchema)]
+pub struct UrlVerification {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub challenge: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub token: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default, JsonSchema)]
+pub struct AppRateLimited {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub api_app_id: String,
+    /// The start of the minute the app was rate limited for, in seconds since the epoch.
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub minute_rate_limited: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub team_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub token: String,
+}
+
+/// The envelope of an event the app is subscribed to.
+#[derive(Deserialize, PartialEq, Debug, Clone)]
+pub struct EventCallback {
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub api_app_id: String,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub authorizations: Vec<serde_json::Value>,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub enterprise_id: String,
+    pub event: Event,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub event_id: String,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub event_time: i64,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub is_ext_shared_channel: bool,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub team_id: String,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub token: String,
+}
+
+/// An event of the Events API, by its type.
+///
+/// Events without a variant of their own are kept as JSON.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Event {
+    AppHomeOpened(AppHomeOpenedEvent),
+    AppMention(Box<MessageEvent>),
+    ChannelCreated(Box<ChannelCreatedEvent>),
+    MemberJoinedChannel(MemberChannelEvent),
+    MemberLeftChannel(MemberChannelEvent),
+    Message(Box<MessageEvent>),
+    ReactionAdded(ReactionEvent),
+    ReactionRemoved(ReactionEvent),
+    TeamJoin(Box<TeamJoinEvent>),
+    Other(serde_json::Value),
+}
+
+impl<'de> Deserialize<'de> for Event {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let event = serde_json::Value::deserialize(deserializer)?;
+        let kind = event.get("type").and_then(|kind| kind.as_str());
+        let event = match kind {
+            Some("app_home_opened") => serde_json::from_value(event).map(Event::AppHomeOpened),
+            Some("app_mention") => serde_json::from_value(event).map(Event::AppMention),
+            Some("channel_created") => serde_json::from_value(event).map(Event::ChannelCreated),
+            Some("member_joined_channel") => {
+                serde_json::from_value(event).map(Event::MemberJoinedChannel)
+            }
+            Some("member_left_channel") => {
+                serde_json::from_value(event).map(Event::MemberLeftChannel)
+            }
+            Some("message") => serde_json::from_value(event).map(Event::Message),
+            Some("reaction_added") => serde_json::from_value(event).map(Event::ReactionAdded),
+            Some("reaction_removed") => serde_json::from_value(event).map(Event::ReactionRemoved),
+            Some("team_join") => serde_json::from_value(event).map(Event::TeamJoin),
+            _ => Ok(Event::Other(event)),
+        };
+
+        event.map_err(serde::de::Error::custom)
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default, JsonSchema)]
+pub struct AppHomeOpenedEvent {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub channel: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub event_ts: String,
+    /// The tab of the App Home that was opened, `home` or `messages`.
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub tab: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub user: String,
+}
+
+/// A message posted in a channel the app is in, or mentioning the app.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct MessageEvent {
+    #[serde(flatten)]
+    pub message: types::ObjsMessage,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub channel: String,
+    /// The type of the channel, like `channel`, `group`, `im` or `mpim`.
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub channel_type: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub event_ts: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ChannelCreatedEvent {
+    pub channel: types::ObjsChannel,
+}
+
+/// A user joining or leaving a channel.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default, JsonSchema)]
+pub struct MemberChannelEvent {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub channel: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub channel_type: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub event_ts: String,
+    /// The user who invited the user to the channel, if any.
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub inviter: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub team: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub user: String,
+}
+
+/// A reaction added to, or removed from, an item.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default, JsonSchema)]
+pub struct ReactionEvent {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub event_ts: String,
+    /// The item the reaction is on, like a message, a file or a file comment.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub item: Option<serde_json::Value>,
+    /// The user who created the item.
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub item_user: String,
+    /// The name of the emoji of the reaction.
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub reaction: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub user: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct TeamJoinEvent {
+    pub user: types::ObjsUser,
+}
+
+/// The body of a slash command request.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default, JsonSchema)]
+pub struct SlashCommand {
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub api_app_id: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub channel_id: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub channel_name: String,
+    /// The command that was typed, like `/weather`.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub command: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub enterprise_id: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub enterprise_name: String,
+    /// The URL to send delayed responses to the command to.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub response_url: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub team_domain: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub team_id: String,
+    /// The text typed after the command.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub text: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub token: String,
+    /// The ID to open a modal with, within 3 seconds.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub trigger_id: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub user_id: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub user_name: String,
+}
+
+/// The payload of an interactivity request, by its type.
+///
+/// Payloads without a variant of their own are kept as JSON.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Interaction {
+    BlockActions(Box<BlockActions>),
+    MessageAction(Box<MessageAction>),
+    Shortcut(Box<Shortcut>),
+    ViewClosed(Box<ViewInteraction>),
+    ViewSubmission(Box<ViewInteraction>),
+    Other(serde_json::Value),
+}
+
+impl Interaction {
+    fn from_value(interaction: serde_json::Value) -> serde_json::Result<Self> {
+        let kind = interaction.get("type").and_then(|kind| kind.as_str());
+        Ok(match kind {
+            Some("block_actions") => {
+                Interaction::BlockActions(serde_json::from_value(interaction)?)
+            }
+            Some("message_action") => {
+                Interaction::MessageAction(serde_json::from_value(interaction)?)
+            }
+            Some("shortcut") => Interaction::Shortcut(serde_json::from_value(interaction)?),
+            Some("view_closed") => Interaction::ViewClosed(serde_json::from_value(interaction)?),
+            Some("view_submission") => {
+                Interaction::ViewSubmission(serde_json::from_value(interaction)?)
+            }
+            _ => Interaction::Other(interaction),
+        })
+    }
+}
+
+/// The team of the user who interacted with the app.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default, JsonSchema)]
+pub struct InteractionTeam {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub domain: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub id: String,
+}
+
+/// The user who interacted with the app.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default, JsonSchema)]
+pub struct InteractionUser {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub team_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub username: String,
+}
+
+/// The channel an interaction happened in.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default, JsonSchema)]
+pub struct InteractionChannel {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+}
+
+/// An interactive component of a block that was used.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default, JsonSchema)]
+pub struct BlockAction {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub action_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub action_ts: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub block_id: String,
+    /// The option chosen, for select menus, radio buttons and overflow menus.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub selected_option: Option<serde_json::Value>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "type"
+    )]
+    pub type_: String,
+    /// The value of the component, for buttons.
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub value: String,
+}
+
+/// Interactive components of blocks, in a message or a view, that were used.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default, JsonSchema)]
+pub struct BlockActions {
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub actions: Vec<BlockAction>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub api_app_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub channel: Option<InteractionChannel>,
+    /// Where the blocks are, a message or a view.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub container: Option<serde_json::Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<types::ObjsMessage>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub response_url: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team: Option<InteractionTeam>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub token: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub trigger_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user: Option<InteractionUser>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub view: Option<serde_json::Value>,
+}
+
+/// A message shortcut that was used on a message.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default, JsonSchema)]
+pub struct MessageAction {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub callback_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub channel: Option<InteractionChannel>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<types::ObjsMessage>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub response_url: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team: Option<InteractionTeam>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub token: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub trigger_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user: Option<InteractionUser>,
+}
+
+/// A global shortcut that was used.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default, JsonSchema)]
+pub struct Shortcut {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub callback_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team: Option<InteractionTeam>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub token: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub trigger_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user: Option<InteractionUser>,
+}
+
+/// A modal that was submitted or closed.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default, JsonSchema)]
+pub struct ViewInteraction {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub api_app_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team: Option<InteractionTeam>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub token: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub trigger_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user: Option<InteractionUser>,
+    /// The view, with the values of its inputs in `state.values`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub view: Option<serde_json::Value>,
+}
+
+/// Verifies the requests Slack sends to an app, with its signing secret.
+#[derive(Clone)]
+pub struct Webhook {
+    secret: String,
+    tolerance: Duration,
+}
+
+impl Webhook {
+    /// Create a new request verifier from the signing secret of the app.
+    pub fn new<S>(secret: S) -> Self
+    where
+        S: ToString,
+    {
+        Webhook {
+            secret: secret.to_string(),
+            tolerance: DEFAULT_TOLERANCE,
+        }
+    }
+
+    /// Sets how far the timestamp of a request may be from the current time, to
+    /// protect against replayed requests.
+    pub fn with_tolerance(&mut self, tolerance: Duration) -> &mut Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    fn mac(&self, timestamp: &str, payload: &[u8]) -> Hmac<Sha256> {
+        let mut mac = Hmac::<Sha256>::new_from_slice(self.secret.as_bytes())
+            .expect("HMAC can take a key of any size");
+        mac.update(b"v0:");
+        mac.update(timestamp.as_bytes());
+        mac.update(b":");
+        mac.update(payload);
+        mac
+    }
+
+    /// Returns the `X-Slack-Signature` header Slack would send along with a payload at
+    /// `timestamp`, in seconds since the epoch, to test request handlers.
+    pub fn sign(&self, payload: &[u8], timestamp: u64) -> String {
+        let signature = self
+            .mac(&timestamp.to_string(), payload)
+            .finalize()
+            .into_bytes();
+        format!("v0={}", hex::encode(signature))
+    }
+
+    /// Verifies the `X-Slack-Signature` and `X-Slack-Request-Timestamp` headers of a
+    /// request against its raw body.
+    pub fn verify(
+        &self,
+        payload: &[u8],
+        timestamp: &str,
+        signature: &str,
+    ) -> Result<(), WebhookError> {
+        let timestamp = timestamp.trim();
+        let seconds = timestamp
+            .parse::<u64>()
+            .map_err(|_| WebhookError::InvalidHeader)?;
+        let signature = signature
+            .trim()
+            .strip_prefix("v0=")
+            .and_then(|signature| hex::decode(signature).ok())
+            .ok_or(WebhookError::InvalidHeader)?;
+
+        self.mac(timestamp, payload)
+            .verify_slice(&signature)
+            .map_err(|_| WebhookError::SignatureMismatch)?;
+
+        let signed_at = SystemTime::UNIX_EPOCH + Duration::from_secs(seconds);
+        let age = match SystemTime::now().duration_since(signed_at) {
+            Ok(age) => age,
+            Err(e) => e.duration(),
+        };
+        if age > self.tolerance {
+            return Err(WebhookError::TimestampOutsideTolerance);
+        }
+
+        Ok(())
+    }
+
+    /// Verifies a request from its headers.
+    ///
+    /// Slack signs the request timestamp along with the body, and sends them in the
+    /// `X-Slack-Signature` and `X-Slack-Request-Timestamp` headers.
+    pub fn verify_request<I, K, V>(&self, headers: I, payload: &[u8]) -> Result<(), WebhookError>
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: AsRef<str>,
+        V: AsRef<[u8]>,
+    {
+        let [signature, timestamp] =
+            crate::utils::find_headers(headers, [SIGNATURE_HEADER, TIMESTAMP_HEADER]);
+        let signature = signature.ok_or(WebhookError::MissingHeader(SIGNATURE_HEADER))?;
+        let timestamp = timestamp.ok_or(WebhookError::MissingHeader(TIMESTAMP_HEADER))?;
+
+        self.verify(payload, &timestamp, &signature)
+    }
+
+    /// Verifies a request to the Request URL of the Events API and parses its body.
+    pub fn construct_event<I, K, V>(
+        &self,
+        headers: I,
+        payload: &[u8],
+    ) -> Result<EventPayload, WebhookError>
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: AsRef<str>,
+        V: AsRef<[u8]>,
+    {
+        self.verify_request(headers, payload)?;
+        parse_event(payload)
+    }
+
+    /// Verifies a slash command request and parses its body.
+    pub fn construct_command<I, K, V>(
+        &self,
+        headers: I,
+        payload: &[u8],
+    ) -> Result<SlashCommand, WebhookError>
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: AsRef<str>,
+        V: AsRef<[u8]>,
+    {
+        self.verify_request(headers, payload)?;
+        parse_command(payload)
+    }
+
+    /// Verifies an interactivity request and parses its body.
+    pub fn construct_interaction<I, K, V>(
+        &self,
+        headers: I,
+        payload: &[u8],
+    ) -> Result<Interaction, WebhookError>
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: AsRef<str>,
+        V: AsRef<[u8]>,
+    {
+        self.verify_request(headers, payload)?;
+        parse_interaction(payload)
+    }
+}
+
+/// Parses the JSON body of a request to the Request URL of the Events API, without
+/// verifying it.
+pub fn parse_event(payload: &[u8]) -> Result<EventPayload, WebhookError> {
+    let event = serde_json::from_slice::<serde_json::Value>(payload)?;
+    let kind = event.get("type").and_then(|kind| kind.as_str());
+    Ok(match kind {
+        Some("url_verification") => EventPayload::UrlVerification(serde_json::from_value(event)?),
+        Some("event_callback") => EventPayload::EventCallback(serde_json::from_value(event)?),
+        Some("app_rate_limited") => EventPayload::AppRateLimited(serde_json::from_value(event)?),
+        _ => EventPayload::Other(event),
+    })
+}
+
+/// Parses the form body of a slash command request, without verifying it.
+pub fn parse_command(payload: &[u8]) -> Result<SlashCommand, WebhookError> {
+    Ok(serde_urlencoded::from_bytes(payload)?)
+}
+
+/// Parses the form body of an interactivity request, whose `payload` field holds the
+/// JSON of the interaction, without verifying it.
+pub fn parse_interaction(payload: &[u8]) -> Result<Interaction, WebhookError> {
+    #[derive(Deserialize)]
+    struct Form {
+        payload: String,
+    }
+
+    let Form { payload } = serde_urlencoded::from_bytes(payload)?;
+    Ok(Interaction::from_value(serde_json::from_str(&payload)?)?)
+}
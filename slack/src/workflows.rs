@@ -1,6 +1,137 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Workflows::step_completed`], set with a builder.
+pub struct WorkflowsStepCompletedRequest<'a> {
+    client: &'a Workflows,
+    workflow_step_execute_id: &'a str,
+    outputs: Option<&'a str>,
+}
+
+impl<'a> WorkflowsStepCompletedRequest<'a> {
+    /// Sets the `outputs` query parameter.
+    pub fn outputs(mut self, outputs: &'a str) -> Self {
+        self.outputs = Some(outputs);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        let Self {
+            client,
+            workflow_step_execute_id,
+            outputs,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = outputs {
+            query_args.push(("outputs".to_string(), v.to_string()));
+        }
+        if !workflow_step_execute_id.is_empty() {
+            query_args.push((
+                "workflow_step_execute_id".to_string(),
+                workflow_step_execute_id.to_string(),
+            ));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/workflows.stepCompleted?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Workflows::update_step`], set with a builder.
+pub struct WorkflowsUpdateStepRequest<'a> {
+    client: &'a Workflows,
+    workflow_step_edit_id: &'a str,
+    inputs: Option<&'a str>,
+    outputs: Option<&'a str>,
+    step_name: Option<&'a str>,
+    step_image_url: Option<&'a str>,
+}
+
+impl<'a> WorkflowsUpdateStepRequest<'a> {
+    /// Sets the `inputs` query parameter.
+    pub fn inputs(mut self, inputs: &'a str) -> Self {
+        self.inputs = Some(inputs);
+        self
+    }
+
+    /// Sets the `outputs` query parameter.
+    pub fn outputs(mut self, outputs: &'a str) -> Self {
+        self.outputs = Some(outputs);
+        self
+    }
+
+    /// Sets the `step_name` query parameter.
+    pub fn step_name(mut self, step_name: &'a str) -> Self {
+        self.step_name = Some(step_name);
+        self
+    }
+
+    /// Sets the `step_image_url` query parameter.
+    pub fn step_image_url(mut self, step_image_url: &'a str) -> Self {
+        self.step_image_url = Some(step_image_url);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
+        let Self {
+            client,
+            workflow_step_edit_id,
+            inputs,
+            outputs,
+            step_name,
+            step_image_url,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = inputs {
+            query_args.push(("inputs".to_string(), v.to_string()));
+        }
+        if let Some(v) = outputs {
+            query_args.push(("outputs".to_string(), v.to_string()));
+        }
+        if let Some(v) = step_image_url {
+            query_args.push(("step_image_url".to_string(), v.to_string()));
+        }
+        if let Some(v) = step_name {
+            query_args.push(("step_name".to_string(), v.to_string()));
+        }
+        if !workflow_step_edit_id.is_empty() {
+            query_args.push((
+                "workflow_step_edit_id".to_string(),
+                workflow_step_edit_id.to_string(),
+            ));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/workflows.updateStep?{}", query_), None);
+
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct Workflows {
     pub client: Client,
 }
@@ -53,6 +184,25 @@ impl Workflows {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/workflows.stepCompleted` endpoint.
+     *
+     * As opposed to `step_completed`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Indicate that an app's step in a workflow completed execution.
+     *
+     * FROM: <https://api.slack.com/methods/workflows.stepCompleted>
+     */
+    pub fn step_completed_request<'a>(
+        &'a self,
+        workflow_step_execute_id: &'a str,
+    ) -> WorkflowsStepCompletedRequest<'a> {
+        WorkflowsStepCompletedRequest {
+            client: self,
+            workflow_step_execute_id,
+            outputs: None,
+        }
+    }
     /**
      * This function performs a `GET` to the `/workflows.stepFailed` endpoint.
      *
@@ -152,4 +302,26 @@ impl Workflows {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/workflows.updateStep` endpoint.
+     *
+     * As opposed to `update_step`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Update the configuration for a workflow extension step.
+     *
+     * FROM: <https://api.slack.com/methods/workflows.updateStep>
+     */
+    pub fn update_step_request<'a>(
+        &'a self,
+        workflow_step_edit_id: &'a str,
+    ) -> WorkflowsUpdateStepRequest<'a> {
+        WorkflowsUpdateStepRequest {
+            client: self,
+            workflow_step_edit_id,
+            inputs: None,
+            outputs: None,
+            step_name: None,
+            step_image_url: None,
+        }
+    }
 }
@@ -1 +1,358 @@
+use std::{
+    mem,
+    time::{Duration, SystemTime},
+};
 
+use wiremock::{
+    matchers::{body_string, header, method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+use slack_chat_api::{
+    types::ChatPostMessageRequest,
+    webhooks::{self, Event, EventPayload, Interaction, Webhook, WebhookError},
+    Client, ClientBuilder, ClientError,
+};
+
+#[tokio::test]
+async fn test_post_message_sends_form_body() {
+    let server = MockServer::start().await;
+    let response = ResponseTemplate::new(200).set_body_json(serde_json::json!({
+        "ok": true,
+        "channel": "C1234567890",
+        "ts": "1503435956.000247",
+        "message": {
+            "text": "Hello world",
+            "type": "message",
+            "ts": "1503435956.000247"
+        }
+    }));
+
+    Mock::given(method("POST"))
+        .and(path("/chat.postMessage"))
+        .and(header("content-type", "application/x-www-form-urlencoded"))
+        .and(body_string(
+            "channel=C1234567890&text=Hello+world&unfurl_links=false",
+        ))
+        .respond_with(response)
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut client = Client::new("client_id", "client_secret", "redirect_uri", "token", "");
+    client.with_host_override(server.uri());
+
+    let resp = client
+        .chat()
+        .post_message(&ChatPostMessageRequest {
+            channel: "C1234567890".to_string(),
+            text: "Hello world".to_string(),
+            unfurl_links: Some(false),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+
+    assert!(resp.body.ok);
+    assert_eq!(resp.body.channel, "C1234567890");
+
+    mem::drop(server)
+}
+
+async fn post_message(server: &MockServer, response: ResponseTemplate) -> ClientError {
+    Mock::given(method("POST"))
+        .and(path("/chat.postMessage"))
+        .respond_with(response)
+        .expect(1)
+        .mount(server)
+        .await;
+
+    let mut client = Client::new("client_id", "client_secret", "redirect_uri", "token", "");
+    client
+        .with_host_override(server.uri())
+        .with_client_builder(ClientBuilder::new().max_retries(0))
+        .unwrap();
+
+    client
+        .chat()
+        .post_message(&ChatPostMessageRequest {
+            channel: "C1234567890".to_string(),
+            text: "Hello world".to_string(),
+            ..Default::default()
+        })
+        .await
+        .expect_err("post message should fail")
+}
+
+#[tokio::test]
+async fn test_ok_false_is_an_error() {
+    let server = MockServer::start().await;
+    let response = ResponseTemplate::new(200).set_body_json(serde_json::json!({
+        "ok": false,
+        "error": "channel_not_found",
+        "warning": "missing_charset",
+        "response_metadata": { "warnings": ["missing_charset"] }
+    }));
+
+    match post_message(&server, response).await {
+        ClientError::ApiError { status, error, .. } => {
+            assert_eq!(status, 200);
+            assert_eq!(error.error, "channel_not_found");
+            assert_eq!(error.warning, "missing_charset");
+            assert_eq!(
+                error.response_metadata.unwrap().warnings,
+                vec!["missing_charset".to_string()]
+            );
+        }
+        err => unreachable!("Expected Slack error, got {:?}", err),
+    }
+
+    mem::drop(server)
+}
+
+#[tokio::test]
+async fn test_ratelimited_is_an_error() {
+    let server = MockServer::start().await;
+    let response = ResponseTemplate::new(429)
+        .insert_header("retry-after", "30")
+        .set_body_json(serde_json::json!({ "ok": false, "error": "ratelimited" }));
+
+    match post_message(&server, response).await {
+        ClientError::RateLimited {
+            duration, error, ..
+        } => {
+            assert_eq!(duration, Some(30));
+            assert_eq!(error.unwrap().error, "ratelimited");
+        }
+        err => unreachable!("Expected rate limit error, got {:?}", err),
+    }
+
+    mem::drop(server)
+}
+
+async fn mount_pins_page(server: &MockServer, page: &str, pins: usize, next: Option<&str>) {
+    let items = (0..pins)
+        .map(|_| serde_json::json!({ "ok": true, "count": 1 }))
+        .collect::<Vec<_>>();
+    let mut response = ResponseTemplate::new(200).set_body_json(items);
+    if let Some(next) = next {
+        response = response.insert_header(
+            "link",
+            format!("<{}/pins.list?page={next}>; rel=\"next\"", server.uri()).as_str(),
+        );
+    }
+
+    let mock = Mock::given(method("GET")).and(path("/pins.list"));
+    let mock = if page == "1" {
+        mock.and(query_param("channel", "C1234567890"))
+    } else {
+        mock.and(query_param("page", page))
+    };
+
+    mock.respond_with(response).mount(server).await;
+}
+
+fn pages_client(server: &MockServer) -> Client {
+    let mut client = Client::new("client_id", "client_secret", "redirect_uri", "token", "");
+    client.with_host_override(server.uri());
+    client
+}
+
+async fn list_all_pins(client: &Client) -> Result<usize, ClientError> {
+    client
+        .pins()
+        .list_all("C1234567890")
+        .await
+        .map(|response| response.body.len())
+}
+
+#[tokio::test]
+async fn test_get_all_pages_fails_on_repeated_next_link() {
+    let server = MockServer::start().await;
+
+    // The second page keeps pointing back at itself.
+    mount_pins_page(&server, "1", 2, Some("2")).await;
+    mount_pins_page(&server, "2", 1, Some("2")).await;
+
+    let client = pages_client(&server);
+    assert!(matches!(
+        list_all_pins(&client).await,
+        Err(ClientError::IncompletePages { page_token }) if page_token.ends_with("/pins.list?page=2")
+    ));
+    assert_eq!(server.received_requests().await.unwrap().len(), 2);
+
+    mem::drop(server)
+}
+
+#[tokio::test]
+async fn test_get_all_pages_stops_on_empty_page() {
+    let server = MockServer::start().await;
+
+    mount_pins_page(&server, "1", 2, Some("2")).await;
+    mount_pins_page(&server, "2", 0, Some("3")).await;
+    mount_pins_page(&server, "3", 1, None).await;
+
+    let client = pages_client(&server);
+    assert_eq!(list_all_pins(&client).await.unwrap(), 2);
+    assert_eq!(server.received_requests().await.unwrap().len(), 2);
+
+    mem::drop(server)
+}
+
+#[tokio::test]
+async fn test_get_all_pages_respects_page_limits() {
+    let server = MockServer::start().await;
+
+    mount_pins_page(&server, "1", 2, Some("2")).await;
+    mount_pins_page(&server, "2", 2, Some("3")).await;
+    mount_pins_page(&server, "3", 2, None).await;
+
+    let mut client = pages_client(&server);
+    client.with_max_pages(2);
+    assert_eq!(list_all_pins(&client).await.unwrap(), 4);
+    assert_eq!(server.received_requests().await.unwrap().len(), 2);
+
+    server.reset().await;
+    mount_pins_page(&server, "1", 2, Some("2")).await;
+    mount_pins_page(&server, "2", 2, Some("3")).await;
+    mount_pins_page(&server, "3", 2, None).await;
+
+    let mut client = pages_client(&server);
+    client.with_max_items(3);
+    assert_eq!(list_all_pins(&client).await.unwrap(), 3);
+    assert_eq!(server.received_requests().await.unwrap().len(), 2);
+
+    mem::drop(server)
+}
+
+const SIGNING_SECRET: &str = "8f742231b10e8888abcd99yyyzzz85a5";
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .unwrap()
+        .as_secs()
+}
+
+fn signed_headers(webhook: &Webhook, payload: &[u8], timestamp: u64) -> Vec<(String, String)> {
+    vec![
+        (
+            "X-Slack-Request-Timestamp".to_string(),
+            timestamp.to_string(),
+        ),
+        (
+            "x-slack-signature".to_string(),
+            webhook.sign(payload, timestamp),
+        ),
+    ]
+}
+
+#[test]
+fn test_request_signature_matches_slack() {
+    // The example of the Slack documentation.
+    let payload = b"token=xyzz0WbapA4vBCDEFasx0q6G&team_id=T1DC2JH3J&team_domain=testteamnow&channel_id=G8PSS9T3V&channel_name=foobar&user_id=U2CERLKJA&user_name=roadrunner&command=%2Fwebhook-collect&text=&response_url=https%3A%2F%2Fhooks.slack.com%2Fcommands%2FT1DC2JH3J%2F397700885554%2F96rGlfmibIGlgcZRskXaIFfN&trigger_id=398738663015.47445629121.803a0bc887a14d10d2c447fce8b6703c";
+    let webhook = Webhook::new(SIGNING_SECRET);
+    assert_eq!(
+        webhook.sign(payload, 1531420618),
+        "v0=a2114d57b48eac39b9ad189dd8316235a7b4a8d21a10bd27519666489c69b503"
+    );
+
+    let command = webhooks::parse_command(payload).unwrap();
+    assert_eq!(command.command, "/webhook-collect");
+    assert_eq!(command.user_name, "roadrunner");
+    assert_eq!(command.text, "");
+}
+
+#[test]
+fn test_request_verification_rejects_bad_requests() {
+    let payload = include_bytes!("fixtures/message.json");
+    let mut webhook = Webhook::new(SIGNING_SECRET);
+
+    let headers = signed_headers(&Webhook::new("another secret"), payload, now());
+    assert!(matches!(
+        webhook.verify_request(headers, payload),
+        Err(WebhookError::SignatureMismatch)
+    ));
+
+    let headers = signed_headers(&webhook, payload, now() - 600);
+    assert!(matches!(
+        webhook.verify_request(headers.clone(), payload),
+        Err(WebhookError::TimestampOutsideTolerance)
+    ));
+    webhook.with_tolerance(Duration::from_secs(3600));
+    assert!(webhook.verify_request(headers, payload).is_ok());
+
+    let headers = vec![("X-Slack-Signature", webhook.sign(payload, now()))];
+    assert!(matches!(
+        webhook.verify_request(headers, payload),
+        Err(WebhookError::MissingHeader("X-Slack-Request-Timestamp"))
+    ));
+}
+
+#[test]
+fn test_url_verification_challenge() {
+    let payload = include_bytes!("fixtures/url_verification.json");
+    let webhook = Webhook::new(SIGNING_SECRET);
+
+    let event = webhook
+        .construct_event(signed_headers(&webhook, payload, now()), payload)
+        .unwrap();
+    assert_eq!(
+        event.challenge(),
+        Some("3eZbrw1aBm2rZgRNFdxV2595E9CY3gmdALWMmHkvFXO7tYXAYM8P")
+    );
+}
+
+#[test]
+fn test_event_callback_is_typed() {
+    let payload = include_bytes!("fixtures/message.json");
+    let webhook = Webhook::new(SIGNING_SECRET);
+
+    let event = webhook
+        .construct_event(signed_headers(&webhook, payload, now()), payload)
+        .unwrap();
+    let EventPayload::EventCallback(callback) = event else {
+        unreachable!("Expected an event callback, got {:?}", event);
+    };
+    assert_eq!(callback.event_id, "Ev123ABC456");
+    assert_eq!(callback.team_id, "T123ABC456");
+    let Event::Message(message) = callback.event else {
+        unreachable!("Expected a message, got {:?}", callback.event);
+    };
+    assert_eq!(message.channel, "C123ABC456");
+    assert_eq!(message.channel_type, "channel");
+    assert_eq!(message.message.text, "Live long and prospect.");
+    assert_eq!(message.message.user, "U123ABC456");
+    assert_eq!(message.message.blocks[0].type_, "rich_text");
+
+    let payload = br#"{"type": "event_callback", "event": {"type": "emoji_changed", "subtype": "add", "name": "picard_facepalm"}}"#;
+    let EventPayload::EventCallback(callback) = webhooks::parse_event(payload).unwrap() else {
+        unreachable!("Expected an event callback");
+    };
+    assert!(matches!(callback.event, Event::Other(_)));
+}
+
+#[test]
+fn test_block_actions_are_typed() {
+    let json = include_str!("fixtures/block_actions.json");
+    let payload = serde_urlencoded::to_string([("payload", json)]).unwrap();
+    let webhook = Webhook::new(SIGNING_SECRET);
+
+    let interaction = webhook
+        .construct_interaction(
+            signed_headers(&webhook, payload.as_bytes(), now()),
+            payload.as_bytes(),
+        )
+        .unwrap();
+    let Interaction::BlockActions(actions) = interaction else {
+        unreachable!("Expected block actions, got {:?}", interaction);
+    };
+    assert_eq!(actions.user.unwrap().username, "spengler");
+    assert_eq!(actions.actions[0].action_id, "approve");
+    assert_eq!(actions.actions[0].type_, "button");
+    assert_eq!(actions.actions[0].value, "request-4242");
+    assert_eq!(
+        actions.message.unwrap().text,
+        "Would you like to approve the request?"
+    );
+}
@@ -1,6 +1,213 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Account::get_external`], set with a builder.
+pub struct AccountGetExternalRequest<'a> {
+    client: &'a Account,
+    ending_before: Option<&'a str>,
+    limit: Option<i64>,
+    starting_after: Option<&'a str>,
+}
+
+impl<'a> AccountGetExternalRequest<'a> {
+    /// Sets the `ending_before` query parameter.
+    pub fn ending_before(mut self, ending_before: &'a str) -> Self {
+        self.ending_before = Some(ending_before);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.starting_after = Some(starting_after);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::DataAnyOf>>> {
+        let Self {
+            client,
+            ending_before,
+            limit,
+            starting_after,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = ending_before {
+            query_args.push(("ending_before".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = starting_after {
+            query_args.push(("starting_after".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/v1/account/external_accounts?{}", query_), None);
+        let resp: crate::Response<crate::types::ExternalAccounts> = client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: Some("application/x-www-form-urlencoded".to_string()),
+                },
+            )
+            .await?;
+
+        // Return our response data.
+        Ok(crate::Response::new(
+            resp.status,
+            resp.headers,
+            resp.body.data.to_vec(),
+        ))
+    }
+}
+
+/// The parameters of [`Account::get_people`], set with a builder.
+pub struct AccountGetPeopleRequest<'a> {
+    client: &'a Account,
+    ending_before: Option<&'a str>,
+    limit: Option<i64>,
+    starting_after: Option<&'a str>,
+}
+
+impl<'a> AccountGetPeopleRequest<'a> {
+    /// Sets the `ending_before` query parameter.
+    pub fn ending_before(mut self, ending_before: &'a str) -> Self {
+        self.ending_before = Some(ending_before);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.starting_after = Some(starting_after);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Person>>> {
+        let Self {
+            client,
+            ending_before,
+            limit,
+            starting_after,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = ending_before {
+            query_args.push(("ending_before".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = starting_after {
+            query_args.push(("starting_after".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/v1/account/people?{}", query_), None);
+        let resp: crate::Response<crate::types::GetAccountPeopleResponse> = client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: Some("application/x-www-form-urlencoded".to_string()),
+                },
+            )
+            .await?;
+
+        // Return our response data.
+        Ok(crate::Response::new(
+            resp.status,
+            resp.headers,
+            resp.body.data.to_vec(),
+        ))
+    }
+}
+
+/// The parameters of [`Account::get_persons`], set with a builder.
+pub struct AccountGetPersonsRequest<'a> {
+    client: &'a Account,
+    ending_before: Option<&'a str>,
+    limit: Option<i64>,
+    starting_after: Option<&'a str>,
+}
+
+impl<'a> AccountGetPersonsRequest<'a> {
+    /// Sets the `ending_before` query parameter.
+    pub fn ending_before(mut self, ending_before: &'a str) -> Self {
+        self.ending_before = Some(ending_before);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.starting_after = Some(starting_after);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Person>>> {
+        let Self {
+            client,
+            ending_before,
+            limit,
+            starting_after,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = ending_before {
+            query_args.push(("ending_before".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = starting_after {
+            query_args.push(("starting_after".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/v1/account/persons?{}", query_), None);
+        let resp: crate::Response<crate::types::GetAccountPeopleResponse> = client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: Some("application/x-www-form-urlencoded".to_string()),
+                },
+            )
+            .await?;
+
+        // Return our response data.
+        Ok(crate::Response::new(
+            resp.status,
+            resp.headers,
+            resp.body.data.to_vec(),
+        ))
+    }
+}
+
 pub struct Account {
     pub client: Client,
 }
@@ -224,11 +431,11 @@ impl Account {
         &self,
     ) -> ClientResult<crate::Response<Vec<crate::types::Capability>>> {
         let url = self.client.url("/v1/account/capabilities", None);
-        let crate::Response::<crate::types::ListAccountCapability> {
-            mut status,
-            mut headers,
-            mut body,
-        } = self
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let mut pages = crate::utils::Pages::new(self.client.page_limits);
+        pages.visit(&url)?;
+
+        let mut response: crate::Response<crate::types::ListAccountCapability> = self
             .client
             .get(
                 &url,
@@ -238,62 +445,80 @@ impl Account {
                 },
             )
             .await?;
-
-        let mut data = body.data;
-        let mut has_more = body.has_more;
-        let mut page = "".to_string();
+        let mut data = Vec::new();
 
         // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                crate::Response::<crate::types::ListAccountCapability> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}?startng_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
+        loop {
+            let page = if response.body.has_more {
+                response
+                    .body
+                    .data
+                    .last()
+                    .and_then(|last| serde_json::to_value(last).ok())
+                    .and_then(|last| {
+                        last.get("id")
+                            .and_then(|id| id.as_str())
+                            .map(|id| format!("{}{}starting_after={}", url, sep, id))
+                    })
             } else {
-                crate::Response::<crate::types::ListAccountCapability> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}&starting_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
+                None
+            };
+            let more = pages.keep(&mut response.body.data);
+            data.append(&mut response.body.data);
 
-            data.append(&mut body.data);
-
-            has_more = body.has_more;
+            let next = match page {
+                Some(next) if more && pages.visit(&next)? => next,
+                _ => break,
+            };
+            response = self
+                .client
+                .get(
+                    &next,
+                    crate::Message {
+                        body: None,
+                        content_type: None,
+                    },
+                )
+                .await?;
         }
 
         // Return our response data.
-        Ok(crate::Response::new(status, headers, data.to_vec()))
+        Ok(crate::Response::new(
+            response.status,
+            response.headers,
+            data,
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/account/capabilities` endpoint.
+     *
+     * As opposed to `get_all_capabilities`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * <p>Returns a list of capabilities associated with the account. The capabilities are returned sorted by creation date, with the most recent capability appearing first.</p>
+     */
+    pub fn get_all_capabilities_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Capability>> + Unpin + '_ {
+        let url = self.client.url("/v1/account/capabilities", None);
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let next = format!("{}{}starting_after=", url, sep);
+        self.client
+            .get_pages_stream(url, move |body: crate::types::ListAccountCapability| {
+                let page = if body.has_more {
+                    body.data
+                        .last()
+                        .and_then(|last| serde_json::to_value(last).ok())
+                        .and_then(|last| {
+                            last.get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| format!("{}{}", next, id))
+                        })
+                } else {
+                    None
+                };
+
+                (body.data.to_vec(), page)
+            })
     }
     /**
      * This function performs a `GET` to the `/v1/account/capabilities/{capability}` endpoint.
@@ -406,6 +631,21 @@ impl Account {
             resp.body.data.to_vec(),
         ))
     }
+    /**
+     * This function performs a `GET` to the `/v1/account/external_accounts` endpoint.
+     *
+     * As opposed to `get_external`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * <p>List external accounts for an account.</p>
+     */
+    pub fn get_external_request(&self) -> AccountGetExternalRequest<'_> {
+        AccountGetExternalRequest {
+            client: self,
+            ending_before: None,
+            limit: None,
+            starting_after: None,
+        }
+    }
     /**
      * This function performs a `GET` to the `/v1/account/external_accounts` endpoint.
      *
@@ -417,11 +657,11 @@ impl Account {
         &self,
     ) -> ClientResult<crate::Response<Vec<crate::types::DataAnyOf>>> {
         let url = self.client.url("/v1/account/external_accounts", None);
-        let crate::Response::<crate::types::ExternalAccounts> {
-            mut status,
-            mut headers,
-            mut body,
-        } = self
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let mut pages = crate::utils::Pages::new(self.client.page_limits);
+        pages.visit(&url)?;
+
+        let mut response: crate::Response<crate::types::ExternalAccounts> = self
             .client
             .get(
                 &url,
@@ -431,62 +671,80 @@ impl Account {
                 },
             )
             .await?;
-
-        let mut data = body.data;
-        let mut has_more = body.has_more;
-        let mut page = "".to_string();
+        let mut data = Vec::new();
 
         // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                crate::Response::<crate::types::ExternalAccounts> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}?startng_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
+        loop {
+            let page = if response.body.has_more {
+                response
+                    .body
+                    .data
+                    .last()
+                    .and_then(|last| serde_json::to_value(last).ok())
+                    .and_then(|last| {
+                        last.get("id")
+                            .and_then(|id| id.as_str())
+                            .map(|id| format!("{}{}starting_after={}", url, sep, id))
+                    })
             } else {
-                crate::Response::<crate::types::ExternalAccounts> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}&starting_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
-
-            data.append(&mut body.data);
+                None
+            };
+            let more = pages.keep(&mut response.body.data);
+            data.append(&mut response.body.data);
 
-            has_more = body.has_more;
+            let next = match page {
+                Some(next) if more && pages.visit(&next)? => next,
+                _ => break,
+            };
+            response = self
+                .client
+                .get(
+                    &next,
+                    crate::Message {
+                        body: None,
+                        content_type: None,
+                    },
+                )
+                .await?;
         }
 
         // Return our response data.
-        Ok(crate::Response::new(status, headers, data.to_vec()))
+        Ok(crate::Response::new(
+            response.status,
+            response.headers,
+            data,
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/account/external_accounts` endpoint.
+     *
+     * As opposed to `get_all_external`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * <p>List external accounts for an account.</p>
+     */
+    pub fn get_all_external_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::DataAnyOf>> + Unpin + '_ {
+        let url = self.client.url("/v1/account/external_accounts", None);
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let next = format!("{}{}starting_after=", url, sep);
+        self.client
+            .get_pages_stream(url, move |body: crate::types::ExternalAccounts| {
+                let page = if body.has_more {
+                    body.data
+                        .last()
+                        .and_then(|last| serde_json::to_value(last).ok())
+                        .and_then(|last| {
+                            last.get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| format!("{}{}", next, id))
+                        })
+                } else {
+                    None
+                };
+
+                (body.data.to_vec(), page)
+            })
     }
     /**
      * This function performs a `POST` to the `/v1/account/external_accounts` endpoint.
@@ -669,6 +927,21 @@ impl Account {
             resp.body.data.to_vec(),
         ))
     }
+    /**
+     * This function performs a `GET` to the `/v1/account/people` endpoint.
+     *
+     * As opposed to `get_people`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * <p>Returns a list of people associated with the account’s legal entity. The people are returned sorted by creation date, with the most recent people appearing first.</p>
+     */
+    pub fn get_people_request(&self) -> AccountGetPeopleRequest<'_> {
+        AccountGetPeopleRequest {
+            client: self,
+            ending_before: None,
+            limit: None,
+            starting_after: None,
+        }
+    }
     /**
      * This function performs a `GET` to the `/v1/account/people` endpoint.
      *
@@ -681,11 +954,11 @@ impl Account {
         _relationship: &str,
     ) -> ClientResult<crate::Response<Vec<crate::types::Person>>> {
         let url = self.client.url("/v1/account/people", None);
-        let crate::Response::<crate::types::GetAccountPeopleResponse> {
-            mut status,
-            mut headers,
-            mut body,
-        } = self
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let mut pages = crate::utils::Pages::new(self.client.page_limits);
+        pages.visit(&url)?;
+
+        let mut response: crate::Response<crate::types::GetAccountPeopleResponse> = self
             .client
             .get(
                 &url,
@@ -695,62 +968,81 @@ impl Account {
                 },
             )
             .await?;
-
-        let mut data = body.data;
-        let mut has_more = body.has_more;
-        let mut page = "".to_string();
+        let mut data = Vec::new();
 
         // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                crate::Response::<crate::types::GetAccountPeopleResponse> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}?startng_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
+        loop {
+            let page = if response.body.has_more {
+                response
+                    .body
+                    .data
+                    .last()
+                    .and_then(|last| serde_json::to_value(last).ok())
+                    .and_then(|last| {
+                        last.get("id")
+                            .and_then(|id| id.as_str())
+                            .map(|id| format!("{}{}starting_after={}", url, sep, id))
+                    })
             } else {
-                crate::Response::<crate::types::GetAccountPeopleResponse> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}&starting_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
-
-            data.append(&mut body.data);
+                None
+            };
+            let more = pages.keep(&mut response.body.data);
+            data.append(&mut response.body.data);
 
-            has_more = body.has_more;
+            let next = match page {
+                Some(next) if more && pages.visit(&next)? => next,
+                _ => break,
+            };
+            response = self
+                .client
+                .get(
+                    &next,
+                    crate::Message {
+                        body: None,
+                        content_type: None,
+                    },
+                )
+                .await?;
         }
 
         // Return our response data.
-        Ok(crate::Response::new(status, headers, data.to_vec()))
+        Ok(crate::Response::new(
+            response.status,
+            response.headers,
+            data,
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/account/people` endpoint.
+     *
+     * As opposed to `get_all_people`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * <p>Returns a list of people associated with the account’s legal entity. The people are returned sorted by creation date, with the most recent people appearing first.</p>
+     */
+    pub fn get_all_people_stream(
+        &self,
+        _relationship: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Person>> + Unpin + '_ {
+        let url = self.client.url("/v1/account/people", None);
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let next = format!("{}{}starting_after=", url, sep);
+        self.client
+            .get_pages_stream(url, move |body: crate::types::GetAccountPeopleResponse| {
+                let page = if body.has_more {
+                    body.data
+                        .last()
+                        .and_then(|last| serde_json::to_value(last).ok())
+                        .and_then(|last| {
+                            last.get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| format!("{}{}", next, id))
+                        })
+                } else {
+                    None
+                };
+
+                (body.data.to_vec(), page)
+            })
     }
     /**
      * This function performs a `POST` to the `/v1/account/people` endpoint.
@@ -912,6 +1204,21 @@ impl Account {
             resp.body.data.to_vec(),
         ))
     }
+    /**
+     * This function performs a `GET` to the `/v1/account/persons` endpoint.
+     *
+     * As opposed to `get_persons`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * <p>Returns a list of people associated with the account’s legal entity. The people are returned sorted by creation date, with the most recent people appearing first.</p>
+     */
+    pub fn get_persons_request(&self) -> AccountGetPersonsRequest<'_> {
+        AccountGetPersonsRequest {
+            client: self,
+            ending_before: None,
+            limit: None,
+            starting_after: None,
+        }
+    }
     /**
      * This function performs a `GET` to the `/v1/account/persons` endpoint.
      *
@@ -924,11 +1231,11 @@ impl Account {
         _relationship: &str,
     ) -> ClientResult<crate::Response<Vec<crate::types::Person>>> {
         let url = self.client.url("/v1/account/persons", None);
-        let crate::Response::<crate::types::GetAccountPeopleResponse> {
-            mut status,
-            mut headers,
-            mut body,
-        } = self
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let mut pages = crate::utils::Pages::new(self.client.page_limits);
+        pages.visit(&url)?;
+
+        let mut response: crate::Response<crate::types::GetAccountPeopleResponse> = self
             .client
             .get(
                 &url,
@@ -938,62 +1245,81 @@ impl Account {
                 },
             )
             .await?;
-
-        let mut data = body.data;
-        let mut has_more = body.has_more;
-        let mut page = "".to_string();
+        let mut data = Vec::new();
 
         // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                crate::Response::<crate::types::GetAccountPeopleResponse> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}?startng_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
+        loop {
+            let page = if response.body.has_more {
+                response
+                    .body
+                    .data
+                    .last()
+                    .and_then(|last| serde_json::to_value(last).ok())
+                    .and_then(|last| {
+                        last.get("id")
+                            .and_then(|id| id.as_str())
+                            .map(|id| format!("{}{}starting_after={}", url, sep, id))
+                    })
             } else {
-                crate::Response::<crate::types::GetAccountPeopleResponse> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}&starting_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
+                None
+            };
+            let more = pages.keep(&mut response.body.data);
+            data.append(&mut response.body.data);
 
-            data.append(&mut body.data);
-
-            has_more = body.has_more;
+            let next = match page {
+                Some(next) if more && pages.visit(&next)? => next,
+                _ => break,
+            };
+            response = self
+                .client
+                .get(
+                    &next,
+                    crate::Message {
+                        body: None,
+                        content_type: None,
+                    },
+                )
+                .await?;
         }
 
         // Return our response data.
-        Ok(crate::Response::new(status, headers, data.to_vec()))
+        Ok(crate::Response::new(
+            response.status,
+            response.headers,
+            data,
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/account/persons` endpoint.
+     *
+     * As opposed to `get_all_persons`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * <p>Returns a list of people associated with the account’s legal entity. The people are returned sorted by creation date, with the most recent people appearing first.</p>
+     */
+    pub fn get_all_persons_stream(
+        &self,
+        _relationship: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Person>> + Unpin + '_ {
+        let url = self.client.url("/v1/account/persons", None);
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let next = format!("{}{}starting_after=", url, sep);
+        self.client
+            .get_pages_stream(url, move |body: crate::types::GetAccountPeopleResponse| {
+                let page = if body.has_more {
+                    body.data
+                        .last()
+                        .and_then(|last| serde_json::to_value(last).ok())
+                        .and_then(|last| {
+                            last.get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| format!("{}{}", next, id))
+                        })
+                } else {
+                    None
+                };
+
+                (body.data.to_vec(), page)
+            })
     }
     /**
      * This function performs a `POST` to the `/v1/account/persons` endpoint.
@@ -1,6 +1,301 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Accounts::get_page`], set with a builder.
+pub struct AccountsGetPageRequest<'a> {
+    client: &'a Accounts,
+    ending_before: Option<&'a str>,
+    limit: Option<i64>,
+    starting_after: Option<&'a str>,
+}
+
+impl<'a> AccountsGetPageRequest<'a> {
+    /// Sets the `ending_before` query parameter.
+    pub fn ending_before(mut self, ending_before: &'a str) -> Self {
+        self.ending_before = Some(ending_before);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.starting_after = Some(starting_after);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Account>>> {
+        let Self {
+            client,
+            ending_before,
+            limit,
+            starting_after,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = ending_before {
+            query_args.push(("ending_before".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = starting_after {
+            query_args.push(("starting_after".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(&format!("/v1/accounts?{}", query_), None);
+        let resp: crate::Response<crate::types::GetAccountsResponse> = client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: Some("application/x-www-form-urlencoded".to_string()),
+                },
+            )
+            .await?;
+
+        // Return our response data.
+        Ok(crate::Response::new(
+            resp.status,
+            resp.headers,
+            resp.body.data.to_vec(),
+        ))
+    }
+}
+
+/// The parameters of [`Accounts::get_external`], set with a builder.
+pub struct AccountsGetExternalRequest<'a> {
+    client: &'a Accounts,
+    account: &'a str,
+    ending_before: Option<&'a str>,
+    limit: Option<i64>,
+    starting_after: Option<&'a str>,
+}
+
+impl<'a> AccountsGetExternalRequest<'a> {
+    /// Sets the `ending_before` query parameter.
+    pub fn ending_before(mut self, ending_before: &'a str) -> Self {
+        self.ending_before = Some(ending_before);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.starting_after = Some(starting_after);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::DataAnyOf>>> {
+        let Self {
+            client,
+            account,
+            ending_before,
+            limit,
+            starting_after,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = ending_before {
+            query_args.push(("ending_before".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = starting_after {
+            query_args.push(("starting_after".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v1/accounts/{}/external_accounts?{}",
+                crate::progenitor_support::encode_path(account),
+                query_
+            ),
+            None,
+        );
+        let resp: crate::Response<crate::types::ExternalAccounts> = client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: Some("application/x-www-form-urlencoded".to_string()),
+                },
+            )
+            .await?;
+
+        // Return our response data.
+        Ok(crate::Response::new(
+            resp.status,
+            resp.headers,
+            resp.body.data.to_vec(),
+        ))
+    }
+}
+
+/// The parameters of [`Accounts::get_people`], set with a builder.
+pub struct AccountsGetPeopleRequest<'a> {
+    client: &'a Accounts,
+    account: &'a str,
+    ending_before: Option<&'a str>,
+    limit: Option<i64>,
+    starting_after: Option<&'a str>,
+}
+
+impl<'a> AccountsGetPeopleRequest<'a> {
+    /// Sets the `ending_before` query parameter.
+    pub fn ending_before(mut self, ending_before: &'a str) -> Self {
+        self.ending_before = Some(ending_before);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.starting_after = Some(starting_after);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Person>>> {
+        let Self {
+            client,
+            account,
+            ending_before,
+            limit,
+            starting_after,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = ending_before {
+            query_args.push(("ending_before".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = starting_after {
+            query_args.push(("starting_after".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v1/accounts/{}/people?{}",
+                crate::progenitor_support::encode_path(account),
+                query_
+            ),
+            None,
+        );
+        let resp: crate::Response<crate::types::GetAccountPeopleResponse> = client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: Some("application/x-www-form-urlencoded".to_string()),
+                },
+            )
+            .await?;
+
+        // Return our response data.
+        Ok(crate::Response::new(
+            resp.status,
+            resp.headers,
+            resp.body.data.to_vec(),
+        ))
+    }
+}
+
+/// The parameters of [`Accounts::get_persons`], set with a builder.
+pub struct AccountsGetPersonsRequest<'a> {
+    client: &'a Accounts,
+    account: &'a str,
+    ending_before: Option<&'a str>,
+    limit: Option<i64>,
+    starting_after: Option<&'a str>,
+}
+
+impl<'a> AccountsGetPersonsRequest<'a> {
+    /// Sets the `ending_before` query parameter.
+    pub fn ending_before(mut self, ending_before: &'a str) -> Self {
+        self.ending_before = Some(ending_before);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.starting_after = Some(starting_after);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Person>>> {
+        let Self {
+            client,
+            account,
+            ending_before,
+            limit,
+            starting_after,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = ending_before {
+            query_args.push(("ending_before".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = starting_after {
+            query_args.push(("starting_after".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v1/accounts/{}/persons?{}",
+                crate::progenitor_support::encode_path(account),
+                query_
+            ),
+            None,
+        );
+        let resp: crate::Response<crate::types::GetAccountPeopleResponse> = client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: Some("application/x-www-form-urlencoded".to_string()),
+                },
+            )
+            .await?;
+
+        // Return our response data.
+        Ok(crate::Response::new(
+            resp.status,
+            resp.headers,
+            resp.body.data.to_vec(),
+        ))
+    }
+}
+
 pub struct Accounts {
     pub client: Client,
 }
@@ -61,6 +356,21 @@ impl Accounts {
             resp.body.data.to_vec(),
         ))
     }
+    /**
+     * This function performs a `GET` to the `/v1/accounts` endpoint.
+     *
+     * As opposed to `get_page`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * <p>Returns a list of accounts connected to your platform via <a href="/docs/connect">Connect</a>. If you’re not a platform, the list is empty.</p>
+     */
+    pub fn get_page_request(&self) -> AccountsGetPageRequest<'_> {
+        AccountsGetPageRequest {
+            client: self,
+            ending_before: None,
+            limit: None,
+            starting_after: None,
+        }
+    }
     /**
      * This function performs a `GET` to the `/v1/accounts` endpoint.
      *
@@ -73,11 +383,11 @@ impl Accounts {
         _created: &str,
     ) -> ClientResult<crate::Response<Vec<crate::types::Account>>> {
         let url = self.client.url("/v1/accounts", None);
-        let crate::Response::<crate::types::GetAccountsResponse> {
-            mut status,
-            mut headers,
-            mut body,
-        } = self
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let mut pages = crate::utils::Pages::new(self.client.page_limits);
+        pages.visit(&url)?;
+
+        let mut response: crate::Response<crate::types::GetAccountsResponse> = self
             .client
             .get(
                 &url,
@@ -87,62 +397,81 @@ impl Accounts {
                 },
             )
             .await?;
-
-        let mut data = body.data;
-        let mut has_more = body.has_more;
-        let mut page = "".to_string();
+        let mut data = Vec::new();
 
         // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                crate::Response::<crate::types::GetAccountsResponse> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}?startng_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
+        loop {
+            let page = if response.body.has_more {
+                response
+                    .body
+                    .data
+                    .last()
+                    .and_then(|last| serde_json::to_value(last).ok())
+                    .and_then(|last| {
+                        last.get("id")
+                            .and_then(|id| id.as_str())
+                            .map(|id| format!("{}{}starting_after={}", url, sep, id))
+                    })
             } else {
-                crate::Response::<crate::types::GetAccountsResponse> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}&starting_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
-
-            data.append(&mut body.data);
+                None
+            };
+            let more = pages.keep(&mut response.body.data);
+            data.append(&mut response.body.data);
 
-            has_more = body.has_more;
+            let next = match page {
+                Some(next) if more && pages.visit(&next)? => next,
+                _ => break,
+            };
+            response = self
+                .client
+                .get(
+                    &next,
+                    crate::Message {
+                        body: None,
+                        content_type: None,
+                    },
+                )
+                .await?;
         }
 
         // Return our response data.
-        Ok(crate::Response::new(status, headers, data.to_vec()))
+        Ok(crate::Response::new(
+            response.status,
+            response.headers,
+            data,
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/accounts` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * <p>Returns a list of accounts connected to your platform via <a href="/docs/connect">Connect</a>. If you’re not a platform, the list is empty.</p>
+     */
+    pub fn get_all_stream(
+        &self,
+        _created: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Account>> + Unpin + '_ {
+        let url = self.client.url("/v1/accounts", None);
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let next = format!("{}{}starting_after=", url, sep);
+        self.client
+            .get_pages_stream(url, move |body: crate::types::GetAccountsResponse| {
+                let page = if body.has_more {
+                    body.data
+                        .last()
+                        .and_then(|last| serde_json::to_value(last).ok())
+                        .and_then(|last| {
+                            last.get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| format!("{}{}", next, id))
+                        })
+                } else {
+                    None
+                };
+
+                (body.data.to_vec(), page)
+            })
     }
     /**
      * This function performs a `POST` to the `/v1/accounts` endpoint.
@@ -445,11 +774,11 @@ impl Accounts {
             ),
             None,
         );
-        let crate::Response::<crate::types::ListAccountCapability> {
-            mut status,
-            mut headers,
-            mut body,
-        } = self
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let mut pages = crate::utils::Pages::new(self.client.page_limits);
+        pages.visit(&url)?;
+
+        let mut response: crate::Response<crate::types::ListAccountCapability> = self
             .client
             .get(
                 &url,
@@ -459,62 +788,87 @@ impl Accounts {
                 },
             )
             .await?;
-
-        let mut data = body.data;
-        let mut has_more = body.has_more;
-        let mut page = "".to_string();
+        let mut data = Vec::new();
 
         // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                crate::Response::<crate::types::ListAccountCapability> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}?startng_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
+        loop {
+            let page = if response.body.has_more {
+                response
+                    .body
+                    .data
+                    .last()
+                    .and_then(|last| serde_json::to_value(last).ok())
+                    .and_then(|last| {
+                        last.get("id")
+                            .and_then(|id| id.as_str())
+                            .map(|id| format!("{}{}starting_after={}", url, sep, id))
+                    })
             } else {
-                crate::Response::<crate::types::ListAccountCapability> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}&starting_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
-
-            data.append(&mut body.data);
+                None
+            };
+            let more = pages.keep(&mut response.body.data);
+            data.append(&mut response.body.data);
 
-            has_more = body.has_more;
+            let next = match page {
+                Some(next) if more && pages.visit(&next)? => next,
+                _ => break,
+            };
+            response = self
+                .client
+                .get(
+                    &next,
+                    crate::Message {
+                        body: None,
+                        content_type: None,
+                    },
+                )
+                .await?;
         }
 
         // Return our response data.
-        Ok(crate::Response::new(status, headers, data.to_vec()))
+        Ok(crate::Response::new(
+            response.status,
+            response.headers,
+            data,
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/accounts/{account}/capabilities` endpoint.
+     *
+     * As opposed to `get_all_capabilities`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * <p>Returns a list of capabilities associated with the account. The capabilities are returned sorted by creation date, with the most recent capability appearing first.</p>
+     */
+    pub fn get_all_capabilities_stream(
+        &self,
+        account: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Capability>> + Unpin + '_ {
+        let url = self.client.url(
+            &format!(
+                "/v1/accounts/{}/capabilities",
+                crate::progenitor_support::encode_path(account),
+            ),
+            None,
+        );
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let next = format!("{}{}starting_after=", url, sep);
+        self.client
+            .get_pages_stream(url, move |body: crate::types::ListAccountCapability| {
+                let page = if body.has_more {
+                    body.data
+                        .last()
+                        .and_then(|last| serde_json::to_value(last).ok())
+                        .and_then(|last| {
+                            last.get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| format!("{}{}", next, id))
+                        })
+                } else {
+                    None
+                };
+
+                (body.data.to_vec(), page)
+            })
     }
     /**
      * This function performs a `GET` to the `/v1/accounts/{account}/capabilities/{capability}` endpoint.
@@ -640,6 +994,22 @@ impl Accounts {
             resp.body.data.to_vec(),
         ))
     }
+    /**
+     * This function performs a `GET` to the `/v1/accounts/{account}/external_accounts` endpoint.
+     *
+     * As opposed to `get_external`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * <p>List external accounts for an account.</p>
+     */
+    pub fn get_external_request<'a>(&'a self, account: &'a str) -> AccountsGetExternalRequest<'a> {
+        AccountsGetExternalRequest {
+            client: self,
+            account,
+            ending_before: None,
+            limit: None,
+            starting_after: None,
+        }
+    }
     /**
      * This function performs a `GET` to the `/v1/accounts/{account}/external_accounts` endpoint.
      *
@@ -658,11 +1028,11 @@ impl Accounts {
             ),
             None,
         );
-        let crate::Response::<crate::types::ExternalAccounts> {
-            mut status,
-            mut headers,
-            mut body,
-        } = self
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let mut pages = crate::utils::Pages::new(self.client.page_limits);
+        pages.visit(&url)?;
+
+        let mut response: crate::Response<crate::types::ExternalAccounts> = self
             .client
             .get(
                 &url,
@@ -672,62 +1042,87 @@ impl Accounts {
                 },
             )
             .await?;
-
-        let mut data = body.data;
-        let mut has_more = body.has_more;
-        let mut page = "".to_string();
+        let mut data = Vec::new();
 
         // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                crate::Response::<crate::types::ExternalAccounts> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}?startng_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
+        loop {
+            let page = if response.body.has_more {
+                response
+                    .body
+                    .data
+                    .last()
+                    .and_then(|last| serde_json::to_value(last).ok())
+                    .and_then(|last| {
+                        last.get("id")
+                            .and_then(|id| id.as_str())
+                            .map(|id| format!("{}{}starting_after={}", url, sep, id))
+                    })
             } else {
-                crate::Response::<crate::types::ExternalAccounts> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}&starting_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
+                None
+            };
+            let more = pages.keep(&mut response.body.data);
+            data.append(&mut response.body.data);
 
-            data.append(&mut body.data);
-
-            has_more = body.has_more;
+            let next = match page {
+                Some(next) if more && pages.visit(&next)? => next,
+                _ => break,
+            };
+            response = self
+                .client
+                .get(
+                    &next,
+                    crate::Message {
+                        body: None,
+                        content_type: None,
+                    },
+                )
+                .await?;
         }
 
         // Return our response data.
-        Ok(crate::Response::new(status, headers, data.to_vec()))
+        Ok(crate::Response::new(
+            response.status,
+            response.headers,
+            data,
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/accounts/{account}/external_accounts` endpoint.
+     *
+     * As opposed to `get_all_external`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * <p>List external accounts for an account.</p>
+     */
+    pub fn get_all_external_stream(
+        &self,
+        account: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::DataAnyOf>> + Unpin + '_ {
+        let url = self.client.url(
+            &format!(
+                "/v1/accounts/{}/external_accounts",
+                crate::progenitor_support::encode_path(account),
+            ),
+            None,
+        );
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let next = format!("{}{}starting_after=", url, sep);
+        self.client
+            .get_pages_stream(url, move |body: crate::types::ExternalAccounts| {
+                let page = if body.has_more {
+                    body.data
+                        .last()
+                        .and_then(|last| serde_json::to_value(last).ok())
+                        .and_then(|last| {
+                            last.get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| format!("{}{}", next, id))
+                        })
+                } else {
+                    None
+                };
+
+                (body.data.to_vec(), page)
+            })
     }
     /**
      * This function performs a `POST` to the `/v1/accounts/{account}/external_accounts` endpoint.
@@ -952,6 +1347,22 @@ impl Accounts {
             resp.body.data.to_vec(),
         ))
     }
+    /**
+     * This function performs a `GET` to the `/v1/accounts/{account}/people` endpoint.
+     *
+     * As opposed to `get_people`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * <p>Returns a list of people associated with the account’s legal entity. The people are returned sorted by creation date, with the most recent people appearing first.</p>
+     */
+    pub fn get_people_request<'a>(&'a self, account: &'a str) -> AccountsGetPeopleRequest<'a> {
+        AccountsGetPeopleRequest {
+            client: self,
+            account,
+            ending_before: None,
+            limit: None,
+            starting_after: None,
+        }
+    }
     /**
      * This function performs a `GET` to the `/v1/accounts/{account}/people` endpoint.
      *
@@ -971,11 +1382,11 @@ impl Accounts {
             ),
             None,
         );
-        let crate::Response::<crate::types::GetAccountPeopleResponse> {
-            mut status,
-            mut headers,
-            mut body,
-        } = self
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let mut pages = crate::utils::Pages::new(self.client.page_limits);
+        pages.visit(&url)?;
+
+        let mut response: crate::Response<crate::types::GetAccountPeopleResponse> = self
             .client
             .get(
                 &url,
@@ -985,62 +1396,88 @@ impl Accounts {
                 },
             )
             .await?;
-
-        let mut data = body.data;
-        let mut has_more = body.has_more;
-        let mut page = "".to_string();
+        let mut data = Vec::new();
 
         // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                crate::Response::<crate::types::GetAccountPeopleResponse> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}?startng_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
+        loop {
+            let page = if response.body.has_more {
+                response
+                    .body
+                    .data
+                    .last()
+                    .and_then(|last| serde_json::to_value(last).ok())
+                    .and_then(|last| {
+                        last.get("id")
+                            .and_then(|id| id.as_str())
+                            .map(|id| format!("{}{}starting_after={}", url, sep, id))
+                    })
             } else {
-                crate::Response::<crate::types::GetAccountPeopleResponse> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}&starting_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
+                None
+            };
+            let more = pages.keep(&mut response.body.data);
+            data.append(&mut response.body.data);
 
-            data.append(&mut body.data);
-
-            has_more = body.has_more;
+            let next = match page {
+                Some(next) if more && pages.visit(&next)? => next,
+                _ => break,
+            };
+            response = self
+                .client
+                .get(
+                    &next,
+                    crate::Message {
+                        body: None,
+                        content_type: None,
+                    },
+                )
+                .await?;
         }
 
         // Return our response data.
-        Ok(crate::Response::new(status, headers, data.to_vec()))
+        Ok(crate::Response::new(
+            response.status,
+            response.headers,
+            data,
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/accounts/{account}/people` endpoint.
+     *
+     * As opposed to `get_all_people`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * <p>Returns a list of people associated with the account’s legal entity. The people are returned sorted by creation date, with the most recent people appearing first.</p>
+     */
+    pub fn get_all_people_stream(
+        &self,
+        account: &str,
+        _relationship: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Person>> + Unpin + '_ {
+        let url = self.client.url(
+            &format!(
+                "/v1/accounts/{}/people",
+                crate::progenitor_support::encode_path(account),
+            ),
+            None,
+        );
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let next = format!("{}{}starting_after=", url, sep);
+        self.client
+            .get_pages_stream(url, move |body: crate::types::GetAccountPeopleResponse| {
+                let page = if body.has_more {
+                    body.data
+                        .last()
+                        .and_then(|last| serde_json::to_value(last).ok())
+                        .and_then(|last| {
+                            last.get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| format!("{}{}", next, id))
+                        })
+                } else {
+                    None
+                };
+
+                (body.data.to_vec(), page)
+            })
     }
     /**
      * This function performs a `POST` to the `/v1/accounts/{account}/people` endpoint.
@@ -1231,6 +1668,22 @@ impl Accounts {
             resp.body.data.to_vec(),
         ))
     }
+    /**
+     * This function performs a `GET` to the `/v1/accounts/{account}/persons` endpoint.
+     *
+     * As opposed to `get_persons`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * <p>Returns a list of people associated with the account’s legal entity. The people are returned sorted by creation date, with the most recent people appearing first.</p>
+     */
+    pub fn get_persons_request<'a>(&'a self, account: &'a str) -> AccountsGetPersonsRequest<'a> {
+        AccountsGetPersonsRequest {
+            client: self,
+            account,
+            ending_before: None,
+            limit: None,
+            starting_after: None,
+        }
+    }
     /**
      * This function performs a `GET` to the `/v1/accounts/{account}/persons` endpoint.
      *
@@ -1250,11 +1703,11 @@ impl Accounts {
             ),
             None,
         );
-        let crate::Response::<crate::types::GetAccountPeopleResponse> {
-            mut status,
-            mut headers,
-            mut body,
-        } = self
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let mut pages = crate::utils::Pages::new(self.client.page_limits);
+        pages.visit(&url)?;
+
+        let mut response: crate::Response<crate::types::GetAccountPeopleResponse> = self
             .client
             .get(
                 &url,
@@ -1264,62 +1717,88 @@ impl Accounts {
                 },
             )
             .await?;
-
-        let mut data = body.data;
-        let mut has_more = body.has_more;
-        let mut page = "".to_string();
+        let mut data = Vec::new();
 
         // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                crate::Response::<crate::types::GetAccountPeopleResponse> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}?startng_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
+        loop {
+            let page = if response.body.has_more {
+                response
+                    .body
+                    .data
+                    .last()
+                    .and_then(|last| serde_json::to_value(last).ok())
+                    .and_then(|last| {
+                        last.get("id")
+                            .and_then(|id| id.as_str())
+                            .map(|id| format!("{}{}starting_after={}", url, sep, id))
+                    })
             } else {
-                crate::Response::<crate::types::GetAccountPeopleResponse> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}&starting_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
+                None
+            };
+            let more = pages.keep(&mut response.body.data);
+            data.append(&mut response.body.data);
 
-            data.append(&mut body.data);
-
-            has_more = body.has_more;
+            let next = match page {
+                Some(next) if more && pages.visit(&next)? => next,
+                _ => break,
+            };
+            response = self
+                .client
+                .get(
+                    &next,
+                    crate::Message {
+                        body: None,
+                        content_type: None,
+                    },
+                )
+                .await?;
         }
 
         // Return our response data.
-        Ok(crate::Response::new(status, headers, data.to_vec()))
+        Ok(crate::Response::new(
+            response.status,
+            response.headers,
+            data,
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/accounts/{account}/persons` endpoint.
+     *
+     * As opposed to `get_all_persons`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * <p>Returns a list of people associated with the account’s legal entity. The people are returned sorted by creation date, with the most recent people appearing first.</p>
+     */
+    pub fn get_all_persons_stream(
+        &self,
+        account: &str,
+        _relationship: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Person>> + Unpin + '_ {
+        let url = self.client.url(
+            &format!(
+                "/v1/accounts/{}/persons",
+                crate::progenitor_support::encode_path(account),
+            ),
+            None,
+        );
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let next = format!("{}{}starting_after=", url, sep);
+        self.client
+            .get_pages_stream(url, move |body: crate::types::GetAccountPeopleResponse| {
+                let page = if body.has_more {
+                    body.data
+                        .last()
+                        .and_then(|last| serde_json::to_value(last).ok())
+                        .and_then(|last| {
+                            last.get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| format!("{}{}", next, id))
+                        })
+                } else {
+                    None
+                };
+
+                (body.data.to_vec(), page)
+            })
     }
     /**
      * This function performs a `POST` to the `/v1/accounts/{account}/persons` endpoint.
@@ -1,6 +1,86 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`ApplePay::get_domains`], set with a builder.
+pub struct ApplePayGetDomainsRequest<'a> {
+    client: &'a ApplePay,
+    domain_name: Option<&'a str>,
+    ending_before: Option<&'a str>,
+    limit: Option<i64>,
+    starting_after: Option<&'a str>,
+}
+
+impl<'a> ApplePayGetDomainsRequest<'a> {
+    /// Sets the `domain_name` query parameter.
+    pub fn domain_name(mut self, domain_name: &'a str) -> Self {
+        self.domain_name = Some(domain_name);
+        self
+    }
+
+    /// Sets the `ending_before` query parameter.
+    pub fn ending_before(mut self, ending_before: &'a str) -> Self {
+        self.ending_before = Some(ending_before);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.starting_after = Some(starting_after);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::ApplePayDomain>>> {
+        let Self {
+            client,
+            domain_name,
+            ending_before,
+            limit,
+            starting_after,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = domain_name {
+            query_args.push(("domain_name".to_string(), v.to_string()));
+        }
+        if let Some(v) = ending_before {
+            query_args.push(("ending_before".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = starting_after {
+            query_args.push(("starting_after".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/v1/apple_pay/domains?{}", query_), None);
+        let resp: crate::Response<crate::types::ApplePayDomainList> = client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: Some("application/x-www-form-urlencoded".to_string()),
+                },
+            )
+            .await?;
+
+        // Return our response data.
+        Ok(crate::Response::new(
+            resp.status,
+            resp.headers,
+            resp.body.data.to_vec(),
+        ))
+    }
+}
+
 pub struct ApplePay {
     pub client: Client,
 }
@@ -66,6 +146,22 @@ impl ApplePay {
             resp.body.data.to_vec(),
         ))
     }
+    /**
+     * This function performs a `GET` to the `/v1/apple_pay/domains` endpoint.
+     *
+     * As opposed to `get_domains`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * <p>List apple pay domains.</p>
+     */
+    pub fn get_domains_request(&self) -> ApplePayGetDomainsRequest<'_> {
+        ApplePayGetDomainsRequest {
+            client: self,
+            domain_name: None,
+            ending_before: None,
+            limit: None,
+            starting_after: None,
+        }
+    }
     /**
      * This function performs a `GET` to the `/v1/apple_pay/domains` endpoint.
      *
@@ -85,11 +181,11 @@ impl ApplePay {
         let url = self
             .client
             .url(&format!("/v1/apple_pay/domains?{}", query_), None);
-        let crate::Response::<crate::types::ApplePayDomainList> {
-            mut status,
-            mut headers,
-            mut body,
-        } = self
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let mut pages = crate::utils::Pages::new(self.client.page_limits);
+        pages.visit(&url)?;
+
+        let mut response: crate::Response<crate::types::ApplePayDomainList> = self
             .client
             .get(
                 &url,
@@ -99,62 +195,88 @@ impl ApplePay {
                 },
             )
             .await?;
-
-        let mut data = body.data;
-        let mut has_more = body.has_more;
-        let mut page = "".to_string();
+        let mut data = Vec::new();
 
         // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                crate::Response::<crate::types::ApplePayDomainList> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}?startng_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
+        loop {
+            let page = if response.body.has_more {
+                response
+                    .body
+                    .data
+                    .last()
+                    .and_then(|last| serde_json::to_value(last).ok())
+                    .and_then(|last| {
+                        last.get("id")
+                            .and_then(|id| id.as_str())
+                            .map(|id| format!("{}{}starting_after={}", url, sep, id))
+                    })
             } else {
-                crate::Response::<crate::types::ApplePayDomainList> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}&starting_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
-
-            data.append(&mut body.data);
-
-            has_more = body.has_more;
+                None
+            };
+            let more = pages.keep(&mut response.body.data);
+            data.append(&mut response.body.data);
+
+            let next = match page {
+                Some(next) if more && pages.visit(&next)? => next,
+                _ => break,
+            };
+            response = self
+                .client
+                .get(
+                    &next,
+                    crate::Message {
+                        body: None,
+                        content_type: None,
+                    },
+                )
+                .await?;
         }
 
         // Return our response data.
-        Ok(crate::Response::new(status, headers, data.to_vec()))
+        Ok(crate::Response::new(
+            response.status,
+            response.headers,
+            data,
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/apple_pay/domains` endpoint.
+     *
+     * As opposed to `get_all_domains`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * <p>List apple pay domains.</p>
+     */
+    pub fn get_all_domains_stream(
+        &self,
+        domain_name: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::ApplePayDomain>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !domain_name.is_empty() {
+            query_args.push(("domain_name".to_string(), domain_name.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/v1/apple_pay/domains?{}", query_), None);
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let next = format!("{}{}starting_after=", url, sep);
+        self.client
+            .get_pages_stream(url, move |body: crate::types::ApplePayDomainList| {
+                let page = if body.has_more {
+                    body.data
+                        .last()
+                        .and_then(|last| serde_json::to_value(last).ok())
+                        .and_then(|last| {
+                            last.get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| format!("{}{}", next, id))
+                        })
+                } else {
+                    None
+                };
+
+                (body.data.to_vec(), page)
+            })
     }
     /**
      * This function performs a `POST` to the `/v1/apple_pay/domains` endpoint.
@@ -1,6 +1,162 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`ApplicationFees::get_page`], set with a builder.
+pub struct ApplicationFeesGetPageRequest<'a> {
+    client: &'a ApplicationFees,
+    charge: Option<&'a str>,
+    ending_before: Option<&'a str>,
+    limit: Option<i64>,
+    starting_after: Option<&'a str>,
+}
+
+impl<'a> ApplicationFeesGetPageRequest<'a> {
+    /// Sets the `charge` query parameter.
+    pub fn charge(mut self, charge: &'a str) -> Self {
+        self.charge = Some(charge);
+        self
+    }
+
+    /// Sets the `ending_before` query parameter.
+    pub fn ending_before(mut self, ending_before: &'a str) -> Self {
+        self.ending_before = Some(ending_before);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.starting_after = Some(starting_after);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::PlatformFee>>> {
+        let Self {
+            client,
+            charge,
+            ending_before,
+            limit,
+            starting_after,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = charge {
+            query_args.push(("charge".to_string(), v.to_string()));
+        }
+        if let Some(v) = ending_before {
+            query_args.push(("ending_before".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = starting_after {
+            query_args.push(("starting_after".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/v1/application_fees?{}", query_), None);
+        let resp: crate::Response<crate::types::GetApplicationFeesResponse> = client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: Some("application/x-www-form-urlencoded".to_string()),
+                },
+            )
+            .await?;
+
+        // Return our response data.
+        Ok(crate::Response::new(
+            resp.status,
+            resp.headers,
+            resp.body.data.to_vec(),
+        ))
+    }
+}
+
+/// The parameters of [`ApplicationFees::get_refunds`], set with a builder.
+pub struct ApplicationFeesGetRefundsRequest<'a> {
+    client: &'a ApplicationFees,
+    ending_before: Option<&'a str>,
+    id: &'a str,
+    limit: Option<i64>,
+    starting_after: Option<&'a str>,
+}
+
+impl<'a> ApplicationFeesGetRefundsRequest<'a> {
+    /// Sets the `ending_before` query parameter.
+    pub fn ending_before(mut self, ending_before: &'a str) -> Self {
+        self.ending_before = Some(ending_before);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.starting_after = Some(starting_after);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::FeeRefund>>> {
+        let Self {
+            client,
+            ending_before,
+            id,
+            limit,
+            starting_after,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = ending_before {
+            query_args.push(("ending_before".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = starting_after {
+            query_args.push(("starting_after".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v1/application_fees/{}/refunds?{}",
+                crate::progenitor_support::encode_path(id),
+                query_
+            ),
+            None,
+        );
+        let resp: crate::Response<crate::types::Refunds> = client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: Some("application/x-www-form-urlencoded".to_string()),
+                },
+            )
+            .await?;
+
+        // Return our response data.
+        Ok(crate::Response::new(
+            resp.status,
+            resp.headers,
+            resp.body.data.to_vec(),
+        ))
+    }
+}
+
 pub struct ApplicationFees {
     pub client: Client,
 }
@@ -68,6 +224,22 @@ impl ApplicationFees {
             resp.body.data.to_vec(),
         ))
     }
+    /**
+     * This function performs a `GET` to the `/v1/application_fees` endpoint.
+     *
+     * As opposed to `get_page`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * <p>Returns a list of application fees you’ve previously collected. The application fees are returned in sorted order, with the most recent fees appearing first.</p>
+     */
+    pub fn get_page_request(&self) -> ApplicationFeesGetPageRequest<'_> {
+        ApplicationFeesGetPageRequest {
+            client: self,
+            charge: None,
+            ending_before: None,
+            limit: None,
+            starting_after: None,
+        }
+    }
     /**
      * This function performs a `GET` to the `/v1/application_fees` endpoint.
      *
@@ -88,11 +260,11 @@ impl ApplicationFees {
         let url = self
             .client
             .url(&format!("/v1/application_fees?{}", query_), None);
-        let crate::Response::<crate::types::GetApplicationFeesResponse> {
-            mut status,
-            mut headers,
-            mut body,
-        } = self
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let mut pages = crate::utils::Pages::new(self.client.page_limits);
+        pages.visit(&url)?;
+
+        let mut response: crate::Response<crate::types::GetApplicationFeesResponse> = self
             .client
             .get(
                 &url,
@@ -102,62 +274,91 @@ impl ApplicationFees {
                 },
             )
             .await?;
-
-        let mut data = body.data;
-        let mut has_more = body.has_more;
-        let mut page = "".to_string();
+        let mut data = Vec::new();
 
         // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                crate::Response::<crate::types::GetApplicationFeesResponse> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}?startng_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
+        loop {
+            let page = if response.body.has_more {
+                response
+                    .body
+                    .data
+                    .last()
+                    .and_then(|last| serde_json::to_value(last).ok())
+                    .and_then(|last| {
+                        last.get("id")
+                            .and_then(|id| id.as_str())
+                            .map(|id| format!("{}{}starting_after={}", url, sep, id))
+                    })
             } else {
-                crate::Response::<crate::types::GetApplicationFeesResponse> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}&starting_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
+                None
+            };
+            let more = pages.keep(&mut response.body.data);
+            data.append(&mut response.body.data);
 
-            data.append(&mut body.data);
-
-            has_more = body.has_more;
+            let next = match page {
+                Some(next) if more && pages.visit(&next)? => next,
+                _ => break,
+            };
+            response = self
+                .client
+                .get(
+                    &next,
+                    crate::Message {
+                        body: None,
+                        content_type: None,
+                    },
+                )
+                .await?;
         }
 
         // Return our response data.
-        Ok(crate::Response::new(status, headers, data.to_vec()))
+        Ok(crate::Response::new(
+            response.status,
+            response.headers,
+            data,
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/application_fees` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * <p>Returns a list of application fees you’ve previously collected. The application fees are returned in sorted order, with the most recent fees appearing first.</p>
+     */
+    pub fn get_all_stream(
+        &self,
+        charge: &str,
+        _created: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::PlatformFee>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !charge.is_empty() {
+            query_args.push(("charge".to_string(), charge.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/v1/application_fees?{}", query_), None);
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let next = format!("{}{}starting_after=", url, sep);
+        self.client.get_pages_stream(
+            url,
+            move |body: crate::types::GetApplicationFeesResponse| {
+                let page = if body.has_more {
+                    body.data
+                        .last()
+                        .and_then(|last| serde_json::to_value(last).ok())
+                        .and_then(|last| {
+                            last.get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| format!("{}{}", next, id))
+                        })
+                } else {
+                    None
+                };
+
+                (body.data.to_vec(), page)
+            },
+        )
     }
     /**
      * This function performs a `GET` to the `/v1/application_fees/{fee}/refunds/{id}` endpoint.
@@ -343,6 +544,22 @@ impl ApplicationFees {
             resp.body.data.to_vec(),
         ))
     }
+    /**
+     * This function performs a `GET` to the `/v1/application_fees/{id}/refunds` endpoint.
+     *
+     * As opposed to `get_refunds`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * <p>You can see a list of the refunds belonging to a specific application fee. Note that the 10 most recent refunds are always available by default on the application fee object. If you need more than those 10, you can use this API method and the <code>limit</code> and <code>starting_after</code> parameters to page through additional refunds.</p>
+     */
+    pub fn get_refunds_request<'a>(&'a self, id: &'a str) -> ApplicationFeesGetRefundsRequest<'a> {
+        ApplicationFeesGetRefundsRequest {
+            client: self,
+            ending_before: None,
+            id,
+            limit: None,
+            starting_after: None,
+        }
+    }
     /**
      * This function performs a `GET` to the `/v1/application_fees/{id}/refunds` endpoint.
      *
@@ -361,11 +578,11 @@ impl ApplicationFees {
             ),
             None,
         );
-        let crate::Response::<crate::types::Refunds> {
-            mut status,
-            mut headers,
-            mut body,
-        } = self
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let mut pages = crate::utils::Pages::new(self.client.page_limits);
+        pages.visit(&url)?;
+
+        let mut response: crate::Response<crate::types::Refunds> = self
             .client
             .get(
                 &url,
@@ -375,62 +592,87 @@ impl ApplicationFees {
                 },
             )
             .await?;
-
-        let mut data = body.data;
-        let mut has_more = body.has_more;
-        let mut page = "".to_string();
+        let mut data = Vec::new();
 
         // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                crate::Response::<crate::types::Refunds> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}?startng_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
+        loop {
+            let page = if response.body.has_more {
+                response
+                    .body
+                    .data
+                    .last()
+                    .and_then(|last| serde_json::to_value(last).ok())
+                    .and_then(|last| {
+                        last.get("id")
+                            .and_then(|id| id.as_str())
+                            .map(|id| format!("{}{}starting_after={}", url, sep, id))
+                    })
             } else {
-                crate::Response::<crate::types::Refunds> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}&starting_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
+                None
+            };
+            let more = pages.keep(&mut response.body.data);
+            data.append(&mut response.body.data);
 
-            data.append(&mut body.data);
-
-            has_more = body.has_more;
+            let next = match page {
+                Some(next) if more && pages.visit(&next)? => next,
+                _ => break,
+            };
+            response = self
+                .client
+                .get(
+                    &next,
+                    crate::Message {
+                        body: None,
+                        content_type: None,
+                    },
+                )
+                .await?;
         }
 
         // Return our response data.
-        Ok(crate::Response::new(status, headers, data.to_vec()))
+        Ok(crate::Response::new(
+            response.status,
+            response.headers,
+            data,
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/application_fees/{id}/refunds` endpoint.
+     *
+     * As opposed to `get_all_refunds`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * <p>You can see a list of the refunds belonging to a specific application fee. Note that the 10 most recent refunds are always available by default on the application fee object. If you need more than those 10, you can use this API method and the <code>limit</code> and <code>starting_after</code> parameters to page through additional refunds.</p>
+     */
+    pub fn get_all_refunds_stream(
+        &self,
+        id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::FeeRefund>> + Unpin + '_ {
+        let url = self.client.url(
+            &format!(
+                "/v1/application_fees/{}/refunds",
+                crate::progenitor_support::encode_path(id),
+            ),
+            None,
+        );
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let next = format!("{}{}starting_after=", url, sep);
+        self.client
+            .get_pages_stream(url, move |body: crate::types::Refunds| {
+                let page = if body.has_more {
+                    body.data
+                        .last()
+                        .and_then(|last| serde_json::to_value(last).ok())
+                        .and_then(|last| {
+                            last.get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| format!("{}{}", next, id))
+                        })
+                } else {
+                    None
+                };
+
+                (body.data.to_vec(), page)
+            })
     }
     /**
      * This function performs a `POST` to the `/v1/application_fees/{id}/refunds` endpoint.
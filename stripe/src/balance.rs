@@ -1,6 +1,121 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Balance::get_history`], set with a builder.
+pub struct BalanceGetHistoryRequest<'a> {
+    client: &'a Balance,
+    currency: Option<&'a str>,
+    ending_before: Option<&'a str>,
+    limit: Option<i64>,
+    payout: Option<&'a str>,
+    source: Option<&'a str>,
+    starting_after: Option<&'a str>,
+    type_: Option<&'a str>,
+}
+
+impl<'a> BalanceGetHistoryRequest<'a> {
+    /// Sets the `currency` query parameter.
+    pub fn currency(mut self, currency: &'a str) -> Self {
+        self.currency = Some(currency);
+        self
+    }
+
+    /// Sets the `ending_before` query parameter.
+    pub fn ending_before(mut self, ending_before: &'a str) -> Self {
+        self.ending_before = Some(ending_before);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `payout` query parameter.
+    pub fn payout(mut self, payout: &'a str) -> Self {
+        self.payout = Some(payout);
+        self
+    }
+
+    /// Sets the `source` query parameter.
+    pub fn source(mut self, source: &'a str) -> Self {
+        self.source = Some(source);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.starting_after = Some(starting_after);
+        self
+    }
+
+    /// Sets the `type` query parameter.
+    pub fn type_(mut self, type_: &'a str) -> Self {
+        self.type_ = Some(type_);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::BalanceTransaction>>> {
+        let Self {
+            client,
+            currency,
+            ending_before,
+            limit,
+            payout,
+            source,
+            starting_after,
+            type_,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = currency {
+            query_args.push(("currency".to_string(), v.to_string()));
+        }
+        if let Some(v) = ending_before {
+            query_args.push(("ending_before".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = payout {
+            query_args.push(("payout".to_string(), v.to_string()));
+        }
+        if let Some(v) = source {
+            query_args.push(("source".to_string(), v.to_string()));
+        }
+        if let Some(v) = starting_after {
+            query_args.push(("starting_after".to_string(), v.to_string()));
+        }
+        if let Some(v) = type_ {
+            query_args.push(("type".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/v1/balance/history?{}", query_), None);
+        let resp: crate::Response<crate::types::BalanceTransactionsList> = client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: Some("application/x-www-form-urlencoded".to_string()),
+                },
+            )
+            .await?;
+
+        // Return our response data.
+        Ok(crate::Response::new(
+            resp.status,
+            resp.headers,
+            resp.body.data.to_vec(),
+        ))
+    }
+}
+
 pub struct Balance {
     pub client: Client,
 }
@@ -107,6 +222,27 @@ impl Balance {
             resp.body.data.to_vec(),
         ))
     }
+    /**
+     * This function performs a `GET` to the `/v1/balance/history` endpoint.
+     *
+     * As opposed to `get_history`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * <p>Returns a list of transactions that have contributed to the Stripe account balance (e.g., charges, transfers, and so forth). The transactions are returned in sorted order, with the most recent transactions appearing first.</p>
+     *
+     * <p>Note that this endpoint was previously called “Balance history” and used the path <code>/v1/balance/history</code>.</p>
+     */
+    pub fn get_history_request(&self) -> BalanceGetHistoryRequest<'_> {
+        BalanceGetHistoryRequest {
+            client: self,
+            currency: None,
+            ending_before: None,
+            limit: None,
+            payout: None,
+            source: None,
+            starting_after: None,
+            type_: None,
+        }
+    }
     /**
      * This function performs a `GET` to the `/v1/balance/history` endpoint.
      *
@@ -141,11 +277,11 @@ impl Balance {
         let url = self
             .client
             .url(&format!("/v1/balance/history?{}", query_), None);
-        let crate::Response::<crate::types::BalanceTransactionsList> {
-            mut status,
-            mut headers,
-            mut body,
-        } = self
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let mut pages = crate::utils::Pages::new(self.client.page_limits);
+        pages.visit(&url)?;
+
+        let mut response: crate::Response<crate::types::BalanceTransactionsList> = self
             .client
             .get(
                 &url,
@@ -155,62 +291,104 @@ impl Balance {
                 },
             )
             .await?;
-
-        let mut data = body.data;
-        let mut has_more = body.has_more;
-        let mut page = "".to_string();
+        let mut data = Vec::new();
 
         // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                crate::Response::<crate::types::BalanceTransactionsList> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}?startng_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
+        loop {
+            let page = if response.body.has_more {
+                response
+                    .body
+                    .data
+                    .last()
+                    .and_then(|last| serde_json::to_value(last).ok())
+                    .and_then(|last| {
+                        last.get("id")
+                            .and_then(|id| id.as_str())
+                            .map(|id| format!("{}{}starting_after={}", url, sep, id))
+                    })
             } else {
-                crate::Response::<crate::types::BalanceTransactionsList> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}&starting_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
-
-            data.append(&mut body.data);
-
-            has_more = body.has_more;
+                None
+            };
+            let more = pages.keep(&mut response.body.data);
+            data.append(&mut response.body.data);
+
+            let next = match page {
+                Some(next) if more && pages.visit(&next)? => next,
+                _ => break,
+            };
+            response = self
+                .client
+                .get(
+                    &next,
+                    crate::Message {
+                        body: None,
+                        content_type: None,
+                    },
+                )
+                .await?;
         }
 
         // Return our response data.
-        Ok(crate::Response::new(status, headers, data.to_vec()))
+        Ok(crate::Response::new(
+            response.status,
+            response.headers,
+            data,
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/balance/history` endpoint.
+     *
+     * As opposed to `get_all_history`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * <p>Returns a list of transactions that have contributed to the Stripe account balance (e.g., charges, transfers, and so forth). The transactions are returned in sorted order, with the most recent transactions appearing first.</p>
+     *
+     * <p>Note that this endpoint was previously called “Balance history” and used the path <code>/v1/balance/history</code>.</p>
+     */
+    pub fn get_all_history_stream(
+        &self,
+        _created: &str,
+        currency: &str,
+        payout: &str,
+        source: &str,
+        type_: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::BalanceTransaction>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !currency.is_empty() {
+            query_args.push(("currency".to_string(), currency.to_string()));
+        }
+        if !payout.is_empty() {
+            query_args.push(("payout".to_string(), payout.to_string()));
+        }
+        if !source.is_empty() {
+            query_args.push(("source".to_string(), source.to_string()));
+        }
+        if !type_.is_empty() {
+            query_args.push(("type".to_string(), type_.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/v1/balance/history?{}", query_), None);
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let next = format!("{}{}starting_after=", url, sep);
+        self.client
+            .get_pages_stream(url, move |body: crate::types::BalanceTransactionsList| {
+                let page = if body.has_more {
+                    body.data
+                        .last()
+                        .and_then(|last| serde_json::to_value(last).ok())
+                        .and_then(|last| {
+                            last.get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| format!("{}{}", next, id))
+                        })
+                } else {
+                    None
+                };
+
+                (body.data.to_vec(), page)
+            })
     }
     /**
      * This function performs a `GET` to the `/v1/balance/history/{id}` endpoint.
@@ -1,6 +1,100 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`BillingPortal::get_configurations`], set with a builder.
+pub struct BillingPortalGetConfigurationsRequest<'a> {
+    client: &'a BillingPortal,
+    active: Option<bool>,
+    ending_before: Option<&'a str>,
+    is_default: Option<bool>,
+    limit: Option<i64>,
+    starting_after: Option<&'a str>,
+}
+
+impl<'a> BillingPortalGetConfigurationsRequest<'a> {
+    /// Sets the `active` query parameter.
+    pub fn active(mut self, active: bool) -> Self {
+        self.active = Some(active);
+        self
+    }
+
+    /// Sets the `ending_before` query parameter.
+    pub fn ending_before(mut self, ending_before: &'a str) -> Self {
+        self.ending_before = Some(ending_before);
+        self
+    }
+
+    /// Sets the `is_default` query parameter.
+    pub fn is_default(mut self, is_default: bool) -> Self {
+        self.is_default = Some(is_default);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.starting_after = Some(starting_after);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::PortalConfiguration>>> {
+        let Self {
+            client,
+            active,
+            ending_before,
+            is_default,
+            limit,
+            starting_after,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = active {
+            query_args.push(("active".to_string(), v.to_string()));
+        }
+        if let Some(v) = ending_before {
+            query_args.push(("ending_before".to_string(), v.to_string()));
+        }
+        if let Some(v) = is_default {
+            query_args.push(("is_default".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = starting_after {
+            query_args.push(("starting_after".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!("/v1/billing_portal/configurations?{}", query_),
+            None,
+        );
+        let resp: crate::Response<crate::types::GetBillingPortalConfigurationsResponse> = client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: Some("application/x-www-form-urlencoded".to_string()),
+                },
+            )
+            .await?;
+
+        // Return our response data.
+        Ok(crate::Response::new(
+            resp.status,
+            resp.headers,
+            resp.body.data.to_vec(),
+        ))
+    }
+}
+
 pub struct BillingPortal {
     pub client: Client,
 }
@@ -72,6 +166,23 @@ impl BillingPortal {
             resp.body.data.to_vec(),
         ))
     }
+    /**
+     * This function performs a `GET` to the `/v1/billing_portal/configurations` endpoint.
+     *
+     * As opposed to `get_configurations`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * <p>Returns a list of configurations that describe the functionality of the customer portal.</p>
+     */
+    pub fn get_configurations_request(&self) -> BillingPortalGetConfigurationsRequest<'_> {
+        BillingPortalGetConfigurationsRequest {
+            client: self,
+            active: None,
+            ending_before: None,
+            is_default: None,
+            limit: None,
+            starting_after: None,
+        }
+    }
     /**
      * This function performs a `GET` to the `/v1/billing_portal/configurations` endpoint.
      *
@@ -96,76 +207,110 @@ impl BillingPortal {
             &format!("/v1/billing_portal/configurations?{}", query_),
             None,
         );
-        let crate::Response::<crate::types::GetBillingPortalConfigurationsResponse> {
-            mut status,
-            mut headers,
-            mut body,
-        } = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let mut pages = crate::utils::Pages::new(self.client.page_limits);
+        pages.visit(&url)?;
 
-        let mut data = body.data;
-        let mut has_more = body.has_more;
-        let mut page = "".to_string();
+        let mut response: crate::Response<crate::types::GetBillingPortalConfigurationsResponse> =
+            self.client
+                .get(
+                    &url,
+                    crate::Message {
+                        body: None,
+                        content_type: None,
+                    },
+                )
+                .await?;
+        let mut data = Vec::new();
 
         // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                crate::Response::<crate::types::GetBillingPortalConfigurationsResponse> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}?startng_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
+        loop {
+            let page = if response.body.has_more {
+                response
+                    .body
+                    .data
+                    .last()
+                    .and_then(|last| serde_json::to_value(last).ok())
+                    .and_then(|last| {
+                        last.get("id")
+                            .and_then(|id| id.as_str())
+                            .map(|id| format!("{}{}starting_after={}", url, sep, id))
+                    })
             } else {
-                crate::Response::<crate::types::GetBillingPortalConfigurationsResponse> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}&starting_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
+                None
+            };
+            let more = pages.keep(&mut response.body.data);
+            data.append(&mut response.body.data);
 
-            data.append(&mut body.data);
-
-            has_more = body.has_more;
+            let next = match page {
+                Some(next) if more && pages.visit(&next)? => next,
+                _ => break,
+            };
+            response = self
+                .client
+                .get(
+                    &next,
+                    crate::Message {
+                        body: None,
+                        content_type: None,
+                    },
+                )
+                .await?;
         }
 
         // Return our response data.
-        Ok(crate::Response::new(status, headers, data.to_vec()))
+        Ok(crate::Response::new(
+            response.status,
+            response.headers,
+            data,
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/billing_portal/configurations` endpoint.
+     *
+     * As opposed to `get_all_configurations`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * <p>Returns a list of configurations that describe the functionality of the customer portal.</p>
+     */
+    pub fn get_all_configurations_stream(
+        &self,
+        active: bool,
+        is_default: bool,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::PortalConfiguration>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if active {
+            query_args.push(("active".to_string(), active.to_string()));
+        }
+        if is_default {
+            query_args.push(("is_default".to_string(), is_default.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!("/v1/billing_portal/configurations?{}", query_),
+            None,
+        );
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let next = format!("{}{}starting_after=", url, sep);
+        self.client.get_pages_stream(
+            url,
+            move |body: crate::types::GetBillingPortalConfigurationsResponse| {
+                let page = if body.has_more {
+                    body.data
+                        .last()
+                        .and_then(|last| serde_json::to_value(last).ok())
+                        .and_then(|last| {
+                            last.get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| format!("{}{}", next, id))
+                        })
+                } else {
+                    None
+                };
+
+                (body.data.to_vec(), page)
+            },
+        )
     }
     /**
      * This function performs a `POST` to the `/v1/billing_portal/configurations` endpoint.
@@ -1,6 +1,290 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Bitcoin::get_receivers`], set with a builder.
+pub struct BitcoinGetReceiversRequest<'a> {
+    client: &'a Bitcoin,
+    active: Option<bool>,
+    ending_before: Option<&'a str>,
+    filled: Option<bool>,
+    limit: Option<i64>,
+    starting_after: Option<&'a str>,
+    uncaptured_funds: Option<bool>,
+}
+
+impl<'a> BitcoinGetReceiversRequest<'a> {
+    /// Sets the `active` query parameter.
+    pub fn active(mut self, active: bool) -> Self {
+        self.active = Some(active);
+        self
+    }
+
+    /// Sets the `ending_before` query parameter.
+    pub fn ending_before(mut self, ending_before: &'a str) -> Self {
+        self.ending_before = Some(ending_before);
+        self
+    }
+
+    /// Sets the `filled` query parameter.
+    pub fn filled(mut self, filled: bool) -> Self {
+        self.filled = Some(filled);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.starting_after = Some(starting_after);
+        self
+    }
+
+    /// Sets the `uncaptured_funds` query parameter.
+    pub fn uncaptured_funds(mut self, uncaptured_funds: bool) -> Self {
+        self.uncaptured_funds = Some(uncaptured_funds);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::BitcoinReceiver>>> {
+        let Self {
+            client,
+            active,
+            ending_before,
+            filled,
+            limit,
+            starting_after,
+            uncaptured_funds,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = active {
+            query_args.push(("active".to_string(), v.to_string()));
+        }
+        if let Some(v) = ending_before {
+            query_args.push(("ending_before".to_string(), v.to_string()));
+        }
+        if let Some(v) = filled {
+            query_args.push(("filled".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = starting_after {
+            query_args.push(("starting_after".to_string(), v.to_string()));
+        }
+        if let Some(v) = uncaptured_funds {
+            query_args.push(("uncaptured_funds".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/v1/bitcoin/receivers?{}", query_), None);
+        let resp: crate::Response<crate::types::GetBitcoinReceiversResponse> = client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: Some("application/x-www-form-urlencoded".to_string()),
+                },
+            )
+            .await?;
+
+        // Return our response data.
+        Ok(crate::Response::new(
+            resp.status,
+            resp.headers,
+            resp.body.data.to_vec(),
+        ))
+    }
+}
+
+/// The parameters of [`Bitcoin::get_receivers_receiver_transactions`], set with a builder.
+pub struct BitcoinGetReceiversReceiverTransactionsRequest<'a> {
+    client: &'a Bitcoin,
+    customer: Option<&'a str>,
+    ending_before: Option<&'a str>,
+    limit: Option<i64>,
+    receiver: &'a str,
+    starting_after: Option<&'a str>,
+}
+
+impl<'a> BitcoinGetReceiversReceiverTransactionsRequest<'a> {
+    /// Sets the `customer` query parameter.
+    pub fn customer(mut self, customer: &'a str) -> Self {
+        self.customer = Some(customer);
+        self
+    }
+
+    /// Sets the `ending_before` query parameter.
+    pub fn ending_before(mut self, ending_before: &'a str) -> Self {
+        self.ending_before = Some(ending_before);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.starting_after = Some(starting_after);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::BitcoinTransaction>>> {
+        let Self {
+            client,
+            customer,
+            ending_before,
+            limit,
+            receiver,
+            starting_after,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = customer {
+            query_args.push(("customer".to_string(), v.to_string()));
+        }
+        if let Some(v) = ending_before {
+            query_args.push(("ending_before".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = starting_after {
+            query_args.push(("starting_after".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v1/bitcoin/receivers/{}/transactions?{}",
+                crate::progenitor_support::encode_path(receiver),
+                query_
+            ),
+            None,
+        );
+        let resp: crate::Response<crate::types::Transactions> = client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: Some("application/x-www-form-urlencoded".to_string()),
+                },
+            )
+            .await?;
+
+        // Return our response data.
+        Ok(crate::Response::new(
+            resp.status,
+            resp.headers,
+            resp.body.data.to_vec(),
+        ))
+    }
+}
+
+/// The parameters of [`Bitcoin::get_transactions`], set with a builder.
+pub struct BitcoinGetTransactionsRequest<'a> {
+    client: &'a Bitcoin,
+    customer: Option<&'a str>,
+    ending_before: Option<&'a str>,
+    limit: Option<i64>,
+    receiver: Option<&'a str>,
+    starting_after: Option<&'a str>,
+}
+
+impl<'a> BitcoinGetTransactionsRequest<'a> {
+    /// Sets the `customer` query parameter.
+    pub fn customer(mut self, customer: &'a str) -> Self {
+        self.customer = Some(customer);
+        self
+    }
+
+    /// Sets the `ending_before` query parameter.
+    pub fn ending_before(mut self, ending_before: &'a str) -> Self {
+        self.ending_before = Some(ending_before);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `receiver` query parameter.
+    pub fn receiver(mut self, receiver: &'a str) -> Self {
+        self.receiver = Some(receiver);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.starting_after = Some(starting_after);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::BitcoinTransaction>>> {
+        let Self {
+            client,
+            customer,
+            ending_before,
+            limit,
+            receiver,
+            starting_after,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = customer {
+            query_args.push(("customer".to_string(), v.to_string()));
+        }
+        if let Some(v) = ending_before {
+            query_args.push(("ending_before".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = receiver {
+            query_args.push(("receiver".to_string(), v.to_string()));
+        }
+        if let Some(v) = starting_after {
+            query_args.push(("starting_after".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/v1/bitcoin/transactions?{}", query_), None);
+        let resp: crate::Response<crate::types::Transactions> = client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: Some("application/x-www-form-urlencoded".to_string()),
+                },
+            )
+            .await?;
+
+        // Return our response data.
+        Ok(crate::Response::new(
+            resp.status,
+            resp.headers,
+            resp.body.data.to_vec(),
+        ))
+    }
+}
+
 pub struct Bitcoin {
     pub client: Client,
 }
@@ -76,6 +360,24 @@ impl Bitcoin {
             resp.body.data.to_vec(),
         ))
     }
+    /**
+     * This function performs a `GET` to the `/v1/bitcoin/receivers` endpoint.
+     *
+     * As opposed to `get_receivers`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * <p>Returns a list of your receivers. Receivers are returned sorted by creation date, with the most recently created receivers appearing first.</p>
+     */
+    pub fn get_receivers_request(&self) -> BitcoinGetReceiversRequest<'_> {
+        BitcoinGetReceiversRequest {
+            client: self,
+            active: None,
+            ending_before: None,
+            filled: None,
+            limit: None,
+            starting_after: None,
+            uncaptured_funds: None,
+        }
+    }
     /**
      * This function performs a `GET` to the `/v1/bitcoin/receivers` endpoint.
      *
@@ -103,11 +405,11 @@ impl Bitcoin {
         let url = self
             .client
             .url(&format!("/v1/bitcoin/receivers?{}", query_), None);
-        let crate::Response::<crate::types::GetBitcoinReceiversResponse> {
-            mut status,
-            mut headers,
-            mut body,
-        } = self
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let mut pages = crate::utils::Pages::new(self.client.page_limits);
+        pages.visit(&url)?;
+
+        let mut response: crate::Response<crate::types::GetBitcoinReceiversResponse> = self
             .client
             .get(
                 &url,
@@ -117,62 +419,98 @@ impl Bitcoin {
                 },
             )
             .await?;
-
-        let mut data = body.data;
-        let mut has_more = body.has_more;
-        let mut page = "".to_string();
+        let mut data = Vec::new();
 
         // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                crate::Response::<crate::types::GetBitcoinReceiversResponse> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}?startng_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
+        loop {
+            let page = if response.body.has_more {
+                response
+                    .body
+                    .data
+                    .last()
+                    .and_then(|last| serde_json::to_value(last).ok())
+                    .and_then(|last| {
+                        last.get("id")
+                            .and_then(|id| id.as_str())
+                            .map(|id| format!("{}{}starting_after={}", url, sep, id))
+                    })
             } else {
-                crate::Response::<crate::types::GetBitcoinReceiversResponse> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}&starting_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
-
-            data.append(&mut body.data);
-
-            has_more = body.has_more;
+                None
+            };
+            let more = pages.keep(&mut response.body.data);
+            data.append(&mut response.body.data);
+
+            let next = match page {
+                Some(next) if more && pages.visit(&next)? => next,
+                _ => break,
+            };
+            response = self
+                .client
+                .get(
+                    &next,
+                    crate::Message {
+                        body: None,
+                        content_type: None,
+                    },
+                )
+                .await?;
         }
 
         // Return our response data.
-        Ok(crate::Response::new(status, headers, data.to_vec()))
+        Ok(crate::Response::new(
+            response.status,
+            response.headers,
+            data,
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/bitcoin/receivers` endpoint.
+     *
+     * As opposed to `get_all_receivers`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * <p>Returns a list of your receivers. Receivers are returned sorted by creation date, with the most recently created receivers appearing first.</p>
+     */
+    pub fn get_all_receivers_stream(
+        &self,
+        active: bool,
+        filled: bool,
+        uncaptured_funds: bool,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::BitcoinReceiver>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if active {
+            query_args.push(("active".to_string(), active.to_string()));
+        }
+        if filled {
+            query_args.push(("filled".to_string(), filled.to_string()));
+        }
+        if uncaptured_funds {
+            query_args.push(("uncaptured_funds".to_string(), uncaptured_funds.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/v1/bitcoin/receivers?{}", query_), None);
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let next = format!("{}{}starting_after=", url, sep);
+        self.client.get_pages_stream(
+            url,
+            move |body: crate::types::GetBitcoinReceiversResponse| {
+                let page = if body.has_more {
+                    body.data
+                        .last()
+                        .and_then(|last| serde_json::to_value(last).ok())
+                        .and_then(|last| {
+                            last.get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| format!("{}{}", next, id))
+                        })
+                } else {
+                    None
+                };
+
+                (body.data.to_vec(), page)
+            },
+        )
     }
     /**
      * This function performs a `GET` to the `/v1/bitcoin/receivers/{id}` endpoint.
@@ -267,6 +605,26 @@ impl Bitcoin {
             resp.body.data.to_vec(),
         ))
     }
+    /**
+     * This function performs a `GET` to the `/v1/bitcoin/receivers/{receiver}/transactions` endpoint.
+     *
+     * As opposed to `get_receivers_receiver_transactions`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * <p>List bitcoin transacitons for a given receiver.</p>
+     */
+    pub fn get_receivers_receiver_transactions_request<'a>(
+        &'a self,
+        receiver: &'a str,
+    ) -> BitcoinGetReceiversReceiverTransactionsRequest<'a> {
+        BitcoinGetReceiversReceiverTransactionsRequest {
+            client: self,
+            customer: None,
+            ending_before: None,
+            limit: None,
+            receiver,
+            starting_after: None,
+        }
+    }
     /**
      * This function performs a `GET` to the `/v1/bitcoin/receivers/{receiver}/transactions` endpoint.
      *
@@ -292,11 +650,11 @@ impl Bitcoin {
             ),
             None,
         );
-        let crate::Response::<crate::types::Transactions> {
-            mut status,
-            mut headers,
-            mut body,
-        } = self
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let mut pages = crate::utils::Pages::new(self.client.page_limits);
+        pages.visit(&url)?;
+
+        let mut response: crate::Response<crate::types::Transactions> = self
             .client
             .get(
                 &url,
@@ -306,62 +664,95 @@ impl Bitcoin {
                 },
             )
             .await?;
-
-        let mut data = body.data;
-        let mut has_more = body.has_more;
-        let mut page = "".to_string();
+        let mut data = Vec::new();
 
         // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                crate::Response::<crate::types::Transactions> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}?startng_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
+        loop {
+            let page = if response.body.has_more {
+                response
+                    .body
+                    .data
+                    .last()
+                    .and_then(|last| serde_json::to_value(last).ok())
+                    .and_then(|last| {
+                        last.get("id")
+                            .and_then(|id| id.as_str())
+                            .map(|id| format!("{}{}starting_after={}", url, sep, id))
+                    })
             } else {
-                crate::Response::<crate::types::Transactions> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}&starting_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
-
-            data.append(&mut body.data);
-
-            has_more = body.has_more;
+                None
+            };
+            let more = pages.keep(&mut response.body.data);
+            data.append(&mut response.body.data);
+
+            let next = match page {
+                Some(next) if more && pages.visit(&next)? => next,
+                _ => break,
+            };
+            response = self
+                .client
+                .get(
+                    &next,
+                    crate::Message {
+                        body: None,
+                        content_type: None,
+                    },
+                )
+                .await?;
         }
 
         // Return our response data.
-        Ok(crate::Response::new(status, headers, data.to_vec()))
+        Ok(crate::Response::new(
+            response.status,
+            response.headers,
+            data,
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/bitcoin/receivers/{receiver}/transactions` endpoint.
+     *
+     * As opposed to `get_all_receivers_receiver_transactions`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * <p>List bitcoin transacitons for a given receiver.</p>
+     */
+    pub fn get_all_receivers_receiver_transactions_stream(
+        &self,
+        customer: &str,
+        receiver: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::BitcoinTransaction>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !customer.is_empty() {
+            query_args.push(("customer".to_string(), customer.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/v1/bitcoin/receivers/{}/transactions?{}",
+                crate::progenitor_support::encode_path(receiver),
+                query_
+            ),
+            None,
+        );
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let next = format!("{}{}starting_after=", url, sep);
+        self.client
+            .get_pages_stream(url, move |body: crate::types::Transactions| {
+                let page = if body.has_more {
+                    body.data
+                        .last()
+                        .and_then(|last| serde_json::to_value(last).ok())
+                        .and_then(|last| {
+                            last.get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| format!("{}{}", next, id))
+                        })
+                } else {
+                    None
+                };
+
+                (body.data.to_vec(), page)
+            })
     }
     /**
      * This function performs a `GET` to the `/v1/bitcoin/transactions` endpoint.
@@ -423,6 +814,23 @@ impl Bitcoin {
             resp.body.data.to_vec(),
         ))
     }
+    /**
+     * This function performs a `GET` to the `/v1/bitcoin/transactions` endpoint.
+     *
+     * As opposed to `get_transactions`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * <p>List bitcoin transacitons for a given receiver.</p>
+     */
+    pub fn get_transactions_request(&self) -> BitcoinGetTransactionsRequest<'_> {
+        BitcoinGetTransactionsRequest {
+            client: self,
+            customer: None,
+            ending_before: None,
+            limit: None,
+            receiver: None,
+            starting_after: None,
+        }
+    }
     /**
      * This function performs a `GET` to the `/v1/bitcoin/transactions` endpoint.
      *
@@ -446,11 +854,11 @@ impl Bitcoin {
         let url = self
             .client
             .url(&format!("/v1/bitcoin/transactions?{}", query_), None);
-        let crate::Response::<crate::types::Transactions> {
-            mut status,
-            mut headers,
-            mut body,
-        } = self
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let mut pages = crate::utils::Pages::new(self.client.page_limits);
+        pages.visit(&url)?;
+
+        let mut response: crate::Response<crate::types::Transactions> = self
             .client
             .get(
                 &url,
@@ -460,61 +868,92 @@ impl Bitcoin {
                 },
             )
             .await?;
-
-        let mut data = body.data;
-        let mut has_more = body.has_more;
-        let mut page = "".to_string();
+        let mut data = Vec::new();
 
         // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                crate::Response::<crate::types::Transactions> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}?startng_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
+        loop {
+            let page = if response.body.has_more {
+                response
+                    .body
+                    .data
+                    .last()
+                    .and_then(|last| serde_json::to_value(last).ok())
+                    .and_then(|last| {
+                        last.get("id")
+                            .and_then(|id| id.as_str())
+                            .map(|id| format!("{}{}starting_after={}", url, sep, id))
+                    })
             } else {
-                crate::Response::<crate::types::Transactions> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}&starting_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
-
-            data.append(&mut body.data);
-
-            has_more = body.has_more;
+                None
+            };
+            let more = pages.keep(&mut response.body.data);
+            data.append(&mut response.body.data);
+
+            let next = match page {
+                Some(next) if more && pages.visit(&next)? => next,
+                _ => break,
+            };
+            response = self
+                .client
+                .get(
+                    &next,
+                    crate::Message {
+                        body: None,
+                        content_type: None,
+                    },
+                )
+                .await?;
         }
 
         // Return our response data.
-        Ok(crate::Response::new(status, headers, data.to_vec()))
+        Ok(crate::Response::new(
+            response.status,
+            response.headers,
+            data,
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/bitcoin/transactions` endpoint.
+     *
+     * As opposed to `get_all_transactions`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * <p>List bitcoin transacitons for a given receiver.</p>
+     */
+    pub fn get_all_transactions_stream(
+        &self,
+        customer: &str,
+        receiver: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::BitcoinTransaction>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !customer.is_empty() {
+            query_args.push(("customer".to_string(), customer.to_string()));
+        }
+        if !receiver.is_empty() {
+            query_args.push(("receiver".to_string(), receiver.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/v1/bitcoin/transactions?{}", query_), None);
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let next = format!("{}{}starting_after=", url, sep);
+        self.client
+            .get_pages_stream(url, move |body: crate::types::Transactions| {
+                let page = if body.has_more {
+                    body.data
+                        .last()
+                        .and_then(|last| serde_json::to_value(last).ok())
+                        .and_then(|last| {
+                            last.get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| format!("{}{}", next, id))
+                        })
+                } else {
+                    None
+                };
+
+                (body.data.to_vec(), page)
+            })
     }
 }
@@ -0,0 +1,337 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Account::get_external`], set with a builder.
+pub struct AccountGetExternalRequest<'a> {
+    inner: crate::account::AccountGetExternalRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> AccountGetExternalRequest<'a> {
+    /// Sets the `ending_before` query parameter.
+    pub fn ending_before(mut self, ending_before: &'a str) -> Self {
+        self.inner = self.inner.ending_before(ending_before);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.inner = self.inner.starting_after(starting_after);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::DataAnyOf>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Account::get_people`], set with a builder.
+pub struct AccountGetPeopleRequest<'a> {
+    inner: crate::account::AccountGetPeopleRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> AccountGetPeopleRequest<'a> {
+    /// Sets the `ending_before` query parameter.
+    pub fn ending_before(mut self, ending_before: &'a str) -> Self {
+        self.inner = self.inner.ending_before(ending_before);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.inner = self.inner.starting_after(starting_after);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Person>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Account::get_persons`], set with a builder.
+pub struct AccountGetPersonsRequest<'a> {
+    inner: crate::account::AccountGetPersonsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> AccountGetPersonsRequest<'a> {
+    /// Sets the `ending_before` query parameter.
+    pub fn ending_before(mut self, ending_before: &'a str) -> Self {
+        self.inner = self.inner.ending_before(ending_before);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.inner = self.inner.starting_after(starting_after);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Person>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::account::Account`].
+pub struct Account {
+    inner: crate::account::Account,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Account {
+    pub(crate) fn new(
+        inner: crate::account::Account,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Account { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::account::Account::get`].
+    pub fn get(&self) -> ClientResult<crate::Response<crate::types::Account>> {
+        self.runtime.block_on(self.inner.get())
+    }
+    /// The blocking version of [`crate::account::Account::post`].
+    pub fn post(&self) -> ClientResult<crate::Response<crate::types::Account>> {
+        self.runtime.block_on(self.inner.post())
+    }
+    /// The blocking version of [`crate::account::Account::delete`].
+    pub fn delete(&self) -> ClientResult<crate::Response<crate::types::DeletedAccount>> {
+        self.runtime.block_on(self.inner.delete())
+    }
+    /// The blocking version of [`crate::account::Account::post_bank`].
+    pub fn post_bank(&self) -> ClientResult<crate::Response<crate::types::DataAnyOf>> {
+        self.runtime.block_on(self.inner.post_bank())
+    }
+    /// The blocking version of [`crate::account::Account::get_bank`].
+    pub fn get_bank(&self, id: &str) -> ClientResult<crate::Response<crate::types::DataAnyOf>> {
+        self.runtime.block_on(self.inner.get_bank(id))
+    }
+    /// The blocking version of [`crate::account::Account::post_bank_account`].
+    pub fn post_bank_account(
+        &self,
+        id: &str,
+    ) -> ClientResult<crate::Response<crate::types::DataAnyOf>> {
+        self.runtime.block_on(self.inner.post_bank_account(id))
+    }
+    /// The blocking version of [`crate::account::Account::delete_bank`].
+    pub fn delete_bank(
+        &self,
+        id: &str,
+    ) -> ClientResult<crate::Response<crate::types::DeletedExternalAccountAnyOf>> {
+        self.runtime.block_on(self.inner.delete_bank(id))
+    }
+    /// The blocking version of [`crate::account::Account::get_capabilities`].
+    pub fn get_capabilities(&self) -> ClientResult<crate::Response<Vec<crate::types::Capability>>> {
+        self.runtime.block_on(self.inner.get_capabilities())
+    }
+    /// The blocking version of [`crate::account::Account::get_all_capabilities`].
+    pub fn get_all_capabilities(
+        &self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Capability>>> {
+        self.runtime.block_on(self.inner.get_all_capabilities())
+    }
+    /// The blocking version of [`crate::account::Account::get_capabilities_capability`].
+    pub fn get_capabilities_capability(
+        &self,
+        capability: &str,
+    ) -> ClientResult<crate::Response<crate::types::Capability>> {
+        self.runtime
+            .block_on(self.inner.get_capabilities_capability(capability))
+    }
+    /// The blocking version of [`crate::account::Account::post_capabilities_capability`].
+    pub fn post_capabilities_capability(
+        &self,
+        capability: &str,
+    ) -> ClientResult<crate::Response<crate::types::Capability>> {
+        self.runtime
+            .block_on(self.inner.post_capabilities_capability(capability))
+    }
+    /// The blocking version of [`crate::account::Account::get_external`].
+    pub fn get_external(
+        &self,
+        ending_before: &str,
+        limit: i64,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::DataAnyOf>>> {
+        self.runtime.block_on(
+            self.inner
+                .get_external(ending_before, limit, starting_after),
+        )
+    }
+    /// The blocking version of [`crate::account::Account::get_external_request`].
+    pub fn get_external_request(&self) -> AccountGetExternalRequest<'_> {
+        AccountGetExternalRequest {
+            inner: self.inner.get_external_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::account::Account::get_all_external`].
+    pub fn get_all_external(&self) -> ClientResult<crate::Response<Vec<crate::types::DataAnyOf>>> {
+        self.runtime.block_on(self.inner.get_all_external())
+    }
+    /// The blocking version of [`crate::account::Account::post_external`].
+    pub fn post_external(&self) -> ClientResult<crate::Response<crate::types::DataAnyOf>> {
+        self.runtime.block_on(self.inner.post_external())
+    }
+    /// The blocking version of [`crate::account::Account::get_external_account`].
+    pub fn get_external_account(
+        &self,
+        id: &str,
+    ) -> ClientResult<crate::Response<crate::types::DataAnyOf>> {
+        self.runtime.block_on(self.inner.get_external_account(id))
+    }
+    /// The blocking version of [`crate::account::Account::post_external_account`].
+    pub fn post_external_account(
+        &self,
+        id: &str,
+    ) -> ClientResult<crate::Response<crate::types::DataAnyOf>> {
+        self.runtime.block_on(self.inner.post_external_account(id))
+    }
+    /// The blocking version of [`crate::account::Account::delete_external`].
+    pub fn delete_external(
+        &self,
+        id: &str,
+    ) -> ClientResult<crate::Response<crate::types::DeletedExternalAccountAnyOf>> {
+        self.runtime.block_on(self.inner.delete_external(id))
+    }
+    /// The blocking version of [`crate::account::Account::post_login_link`].
+    pub fn post_login_link(&self) -> ClientResult<crate::Response<crate::types::LoginLink>> {
+        self.runtime.block_on(self.inner.post_login_link())
+    }
+    /// The blocking version of [`crate::account::Account::get_people`].
+    pub fn get_people(
+        &self,
+        ending_before: &str,
+        limit: i64,
+        _relationship: &str,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Person>>> {
+        self.runtime.block_on(self.inner.get_people(
+            ending_before,
+            limit,
+            _relationship,
+            starting_after,
+        ))
+    }
+    /// The blocking version of [`crate::account::Account::get_people_request`].
+    pub fn get_people_request(&self) -> AccountGetPeopleRequest<'_> {
+        AccountGetPeopleRequest {
+            inner: self.inner.get_people_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::account::Account::get_all_people`].
+    pub fn get_all_people(
+        &self,
+        _relationship: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Person>>> {
+        self.runtime
+            .block_on(self.inner.get_all_people(_relationship))
+    }
+    /// The blocking version of [`crate::account::Account::post_people`].
+    pub fn post_people(&self) -> ClientResult<crate::Response<crate::types::Person>> {
+        self.runtime.block_on(self.inner.post_people())
+    }
+    /// The blocking version of [`crate::account::Account::get_people_person`].
+    pub fn get_people_person(
+        &self,
+        person: &str,
+    ) -> ClientResult<crate::Response<crate::types::Person>> {
+        self.runtime.block_on(self.inner.get_people_person(person))
+    }
+    /// The blocking version of [`crate::account::Account::post_people_person`].
+    pub fn post_people_person(
+        &self,
+        person: &str,
+    ) -> ClientResult<crate::Response<crate::types::Person>> {
+        self.runtime.block_on(self.inner.post_people_person(person))
+    }
+    /// The blocking version of [`crate::account::Account::delete_people_person`].
+    pub fn delete_people_person(
+        &self,
+        person: &str,
+    ) -> ClientResult<crate::Response<crate::types::DeletedPerson>> {
+        self.runtime
+            .block_on(self.inner.delete_people_person(person))
+    }
+    /// The blocking version of [`crate::account::Account::get_persons`].
+    pub fn get_persons(
+        &self,
+        ending_before: &str,
+        limit: i64,
+        _relationship: &str,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Person>>> {
+        self.runtime.block_on(self.inner.get_persons(
+            ending_before,
+            limit,
+            _relationship,
+            starting_after,
+        ))
+    }
+    /// The blocking version of [`crate::account::Account::get_persons_request`].
+    pub fn get_persons_request(&self) -> AccountGetPersonsRequest<'_> {
+        AccountGetPersonsRequest {
+            inner: self.inner.get_persons_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::account::Account::get_all_persons`].
+    pub fn get_all_persons(
+        &self,
+        _relationship: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Person>>> {
+        self.runtime
+            .block_on(self.inner.get_all_persons(_relationship))
+    }
+    /// The blocking version of [`crate::account::Account::post_person`].
+    pub fn post_person(&self) -> ClientResult<crate::Response<crate::types::Person>> {
+        self.runtime.block_on(self.inner.post_person())
+    }
+    /// The blocking version of [`crate::account::Account::get_persons_person`].
+    pub fn get_persons_person(
+        &self,
+        person: &str,
+    ) -> ClientResult<crate::Response<crate::types::Person>> {
+        self.runtime.block_on(self.inner.get_persons_person(person))
+    }
+    /// The blocking version of [`crate::account::Account::post_persons_person`].
+    pub fn post_persons_person(
+        &self,
+        person: &str,
+    ) -> ClientResult<crate::Response<crate::types::Person>> {
+        self.runtime
+            .block_on(self.inner.post_persons_person(person))
+    }
+    /// The blocking version of [`crate::account::Account::delete_persons_person`].
+    pub fn delete_persons_person(
+        &self,
+        person: &str,
+    ) -> ClientResult<crate::Response<crate::types::DeletedPerson>> {
+        self.runtime
+            .block_on(self.inner.delete_persons_person(person))
+    }
+}
@@ -0,0 +1,23 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::account_links::AccountLinks`].
+pub struct AccountLinks {
+    inner: crate::account_links::AccountLinks,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl AccountLinks {
+    pub(crate) fn new(
+        inner: crate::account_links::AccountLinks,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        AccountLinks { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::account_links::AccountLinks::post`].
+    pub fn post(&self) -> ClientResult<crate::Response<crate::types::AccountLink>> {
+        self.runtime.block_on(self.inner.post())
+    }
+}
@@ -0,0 +1,468 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Accounts::get_page`], set with a builder.
+pub struct AccountsGetPageRequest<'a> {
+    inner: crate::accounts::AccountsGetPageRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> AccountsGetPageRequest<'a> {
+    /// Sets the `ending_before` query parameter.
+    pub fn ending_before(mut self, ending_before: &'a str) -> Self {
+        self.inner = self.inner.ending_before(ending_before);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.inner = self.inner.starting_after(starting_after);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Account>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Accounts::get_external`], set with a builder.
+pub struct AccountsGetExternalRequest<'a> {
+    inner: crate::accounts::AccountsGetExternalRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> AccountsGetExternalRequest<'a> {
+    /// Sets the `ending_before` query parameter.
+    pub fn ending_before(mut self, ending_before: &'a str) -> Self {
+        self.inner = self.inner.ending_before(ending_before);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.inner = self.inner.starting_after(starting_after);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::DataAnyOf>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Accounts::get_people`], set with a builder.
+pub struct AccountsGetPeopleRequest<'a> {
+    inner: crate::accounts::AccountsGetPeopleRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> AccountsGetPeopleRequest<'a> {
+    /// Sets the `ending_before` query parameter.
+    pub fn ending_before(mut self, ending_before: &'a str) -> Self {
+        self.inner = self.inner.ending_before(ending_before);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.inner = self.inner.starting_after(starting_after);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Person>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Accounts::get_persons`], set with a builder.
+pub struct AccountsGetPersonsRequest<'a> {
+    inner: crate::accounts::AccountsGetPersonsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> AccountsGetPersonsRequest<'a> {
+    /// Sets the `ending_before` query parameter.
+    pub fn ending_before(mut self, ending_before: &'a str) -> Self {
+        self.inner = self.inner.ending_before(ending_before);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.inner = self.inner.starting_after(starting_after);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Person>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::accounts::Accounts`].
+pub struct Accounts {
+    inner: crate::accounts::Accounts,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Accounts {
+    pub(crate) fn new(
+        inner: crate::accounts::Accounts,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Accounts { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::accounts::Accounts::get_page`].
+    pub fn get_page(
+        &self,
+        _created: &str,
+        ending_before: &str,
+        limit: i64,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Account>>> {
+        self.runtime.block_on(
+            self.inner
+                .get_page(_created, ending_before, limit, starting_after),
+        )
+    }
+    /// The blocking version of [`crate::accounts::Accounts::get_page_request`].
+    pub fn get_page_request(&self) -> AccountsGetPageRequest<'_> {
+        AccountsGetPageRequest {
+            inner: self.inner.get_page_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::accounts::Accounts::get_all`].
+    pub fn get_all(
+        &self,
+        _created: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Account>>> {
+        self.runtime.block_on(self.inner.get_all(_created))
+    }
+    /// The blocking version of [`crate::accounts::Accounts::post`].
+    pub fn post(&self) -> ClientResult<crate::Response<crate::types::Account>> {
+        self.runtime.block_on(self.inner.post())
+    }
+    /// The blocking version of [`crate::accounts::Accounts::get`].
+    pub fn get(&self, account: &str) -> ClientResult<crate::Response<crate::types::Account>> {
+        self.runtime.block_on(self.inner.get(account))
+    }
+    /// The blocking version of [`crate::accounts::Accounts::post_accounts`].
+    pub fn post_accounts(
+        &self,
+        account: &str,
+    ) -> ClientResult<crate::Response<crate::types::Account>> {
+        self.runtime.block_on(self.inner.post_accounts(account))
+    }
+    /// The blocking version of [`crate::accounts::Accounts::delete`].
+    pub fn delete(
+        &self,
+        account: &str,
+    ) -> ClientResult<crate::Response<crate::types::DeletedAccount>> {
+        self.runtime.block_on(self.inner.delete(account))
+    }
+    /// The blocking version of [`crate::accounts::Accounts::post_bank`].
+    pub fn post_bank(
+        &self,
+        account: &str,
+    ) -> ClientResult<crate::Response<crate::types::DataAnyOf>> {
+        self.runtime.block_on(self.inner.post_bank(account))
+    }
+    /// The blocking version of [`crate::accounts::Accounts::get_bank`].
+    pub fn get_bank(
+        &self,
+        account: &str,
+        id: &str,
+    ) -> ClientResult<crate::Response<crate::types::DataAnyOf>> {
+        self.runtime.block_on(self.inner.get_bank(account, id))
+    }
+    /// The blocking version of [`crate::accounts::Accounts::post_bank_accounts`].
+    pub fn post_bank_accounts(
+        &self,
+        account: &str,
+        id: &str,
+    ) -> ClientResult<crate::Response<crate::types::DataAnyOf>> {
+        self.runtime
+            .block_on(self.inner.post_bank_accounts(account, id))
+    }
+    /// The blocking version of [`crate::accounts::Accounts::delete_bank`].
+    pub fn delete_bank(
+        &self,
+        account: &str,
+        id: &str,
+    ) -> ClientResult<crate::Response<crate::types::DeletedExternalAccountAnyOf>> {
+        self.runtime.block_on(self.inner.delete_bank(account, id))
+    }
+    /// The blocking version of [`crate::accounts::Accounts::get_capabilities`].
+    pub fn get_capabilities(
+        &self,
+        account: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Capability>>> {
+        self.runtime.block_on(self.inner.get_capabilities(account))
+    }
+    /// The blocking version of [`crate::accounts::Accounts::get_all_capabilities`].
+    pub fn get_all_capabilities(
+        &self,
+        account: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Capability>>> {
+        self.runtime
+            .block_on(self.inner.get_all_capabilities(account))
+    }
+    /// The blocking version of [`crate::accounts::Accounts::get_capabilities_capability`].
+    pub fn get_capabilities_capability(
+        &self,
+        account: &str,
+        capability: &str,
+    ) -> ClientResult<crate::Response<crate::types::Capability>> {
+        self.runtime
+            .block_on(self.inner.get_capabilities_capability(account, capability))
+    }
+    /// The blocking version of [`crate::accounts::Accounts::post_capabilities_capability`].
+    pub fn post_capabilities_capability(
+        &self,
+        account: &str,
+        capability: &str,
+    ) -> ClientResult<crate::Response<crate::types::Capability>> {
+        self.runtime
+            .block_on(self.inner.post_capabilities_capability(account, capability))
+    }
+    /// The blocking version of [`crate::accounts::Accounts::get_external`].
+    pub fn get_external(
+        &self,
+        account: &str,
+        ending_before: &str,
+        limit: i64,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::DataAnyOf>>> {
+        self.runtime.block_on(self.inner.get_external(
+            account,
+            ending_before,
+            limit,
+            starting_after,
+        ))
+    }
+    /// The blocking version of [`crate::accounts::Accounts::get_external_request`].
+    pub fn get_external_request<'a>(&'a self, account: &'a str) -> AccountsGetExternalRequest<'a> {
+        AccountsGetExternalRequest {
+            inner: self.inner.get_external_request(account),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::accounts::Accounts::get_all_external`].
+    pub fn get_all_external(
+        &self,
+        account: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::DataAnyOf>>> {
+        self.runtime.block_on(self.inner.get_all_external(account))
+    }
+    /// The blocking version of [`crate::accounts::Accounts::post_external`].
+    pub fn post_external(
+        &self,
+        account: &str,
+    ) -> ClientResult<crate::Response<crate::types::DataAnyOf>> {
+        self.runtime.block_on(self.inner.post_external(account))
+    }
+    /// The blocking version of [`crate::accounts::Accounts::get_external_accounts`].
+    pub fn get_external_accounts(
+        &self,
+        account: &str,
+        id: &str,
+    ) -> ClientResult<crate::Response<crate::types::DataAnyOf>> {
+        self.runtime
+            .block_on(self.inner.get_external_accounts(account, id))
+    }
+    /// The blocking version of [`crate::accounts::Accounts::post_external_accounts`].
+    pub fn post_external_accounts(
+        &self,
+        account: &str,
+        id: &str,
+    ) -> ClientResult<crate::Response<crate::types::DataAnyOf>> {
+        self.runtime
+            .block_on(self.inner.post_external_accounts(account, id))
+    }
+    /// The blocking version of [`crate::accounts::Accounts::delete_external`].
+    pub fn delete_external(
+        &self,
+        account: &str,
+        id: &str,
+    ) -> ClientResult<crate::Response<crate::types::DeletedExternalAccountAnyOf>> {
+        self.runtime
+            .block_on(self.inner.delete_external(account, id))
+    }
+    /// The blocking version of [`crate::accounts::Accounts::post_login_link`].
+    pub fn post_login_link(
+        &self,
+        account: &str,
+    ) -> ClientResult<crate::Response<crate::types::LoginLink>> {
+        self.runtime.block_on(self.inner.post_login_link(account))
+    }
+    /// The blocking version of [`crate::accounts::Accounts::get_people`].
+    pub fn get_people(
+        &self,
+        account: &str,
+        ending_before: &str,
+        limit: i64,
+        _relationship: &str,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Person>>> {
+        self.runtime.block_on(self.inner.get_people(
+            account,
+            ending_before,
+            limit,
+            _relationship,
+            starting_after,
+        ))
+    }
+    /// The blocking version of [`crate::accounts::Accounts::get_people_request`].
+    pub fn get_people_request<'a>(&'a self, account: &'a str) -> AccountsGetPeopleRequest<'a> {
+        AccountsGetPeopleRequest {
+            inner: self.inner.get_people_request(account),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::accounts::Accounts::get_all_people`].
+    pub fn get_all_people(
+        &self,
+        account: &str,
+        _relationship: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Person>>> {
+        self.runtime
+            .block_on(self.inner.get_all_people(account, _relationship))
+    }
+    /// The blocking version of [`crate::accounts::Accounts::post_people`].
+    pub fn post_people(
+        &self,
+        account: &str,
+    ) -> ClientResult<crate::Response<crate::types::Person>> {
+        self.runtime.block_on(self.inner.post_people(account))
+    }
+    /// The blocking version of [`crate::accounts::Accounts::get_people_person`].
+    pub fn get_people_person(
+        &self,
+        account: &str,
+        person: &str,
+    ) -> ClientResult<crate::Response<crate::types::Person>> {
+        self.runtime
+            .block_on(self.inner.get_people_person(account, person))
+    }
+    /// The blocking version of [`crate::accounts::Accounts::post_people_person`].
+    pub fn post_people_person(
+        &self,
+        account: &str,
+        person: &str,
+    ) -> ClientResult<crate::Response<crate::types::Person>> {
+        self.runtime
+            .block_on(self.inner.post_people_person(account, person))
+    }
+    /// The blocking version of [`crate::accounts::Accounts::delete_people_person`].
+    pub fn delete_people_person(
+        &self,
+        account: &str,
+        person: &str,
+    ) -> ClientResult<crate::Response<crate::types::DeletedPerson>> {
+        self.runtime
+            .block_on(self.inner.delete_people_person(account, person))
+    }
+    /// The blocking version of [`crate::accounts::Accounts::get_persons`].
+    pub fn get_persons(
+        &self,
+        account: &str,
+        ending_before: &str,
+        limit: i64,
+        _relationship: &str,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Person>>> {
+        self.runtime.block_on(self.inner.get_persons(
+            account,
+            ending_before,
+            limit,
+            _relationship,
+            starting_after,
+        ))
+    }
+    /// The blocking version of [`crate::accounts::Accounts::get_persons_request`].
+    pub fn get_persons_request<'a>(&'a self, account: &'a str) -> AccountsGetPersonsRequest<'a> {
+        AccountsGetPersonsRequest {
+            inner: self.inner.get_persons_request(account),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::accounts::Accounts::get_all_persons`].
+    pub fn get_all_persons(
+        &self,
+        account: &str,
+        _relationship: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Person>>> {
+        self.runtime
+            .block_on(self.inner.get_all_persons(account, _relationship))
+    }
+    /// The blocking version of [`crate::accounts::Accounts::post_person`].
+    pub fn post_person(
+        &self,
+        account: &str,
+    ) -> ClientResult<crate::Response<crate::types::Person>> {
+        self.runtime.block_on(self.inner.post_person(account))
+    }
+    /// The blocking version of [`crate::accounts::Accounts::get_persons_person`].
+    pub fn get_persons_person(
+        &self,
+        account: &str,
+        person: &str,
+    ) -> ClientResult<crate::Response<crate::types::Person>> {
+        self.runtime
+            .block_on(self.inner.get_persons_person(account, person))
+    }
+    /// The blocking version of [`crate::accounts::Accounts::post_persons_person`].
+    pub fn post_persons_person(
+        &self,
+        account: &str,
+        person: &str,
+    ) -> ClientResult<crate::Response<crate::types::Person>> {
+        self.runtime
+            .block_on(self.inner.post_persons_person(account, person))
+    }
+    /// The blocking version of [`crate::accounts::Accounts::delete_persons_person`].
+    pub fn delete_persons_person(
+        &self,
+        account: &str,
+        person: &str,
+    ) -> ClientResult<crate::Response<crate::types::DeletedPerson>> {
+        self.runtime
+            .block_on(self.inner.delete_persons_person(account, person))
+    }
+    /// The blocking version of [`crate::accounts::Accounts::post_reject`].
+    pub fn post_reject(
+        &self,
+        account: &str,
+    ) -> ClientResult<crate::Response<crate::types::Account>> {
+        self.runtime.block_on(self.inner.post_reject(account))
+    }
+}
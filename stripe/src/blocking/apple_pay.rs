@@ -0,0 +1,105 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`ApplePay::get_domains`], set with a builder.
+pub struct ApplePayGetDomainsRequest<'a> {
+    inner: crate::apple_pay::ApplePayGetDomainsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ApplePayGetDomainsRequest<'a> {
+    /// Sets the `domain_name` query parameter.
+    pub fn domain_name(mut self, domain_name: &'a str) -> Self {
+        self.inner = self.inner.domain_name(domain_name);
+        self
+    }
+
+    /// Sets the `ending_before` query parameter.
+    pub fn ending_before(mut self, ending_before: &'a str) -> Self {
+        self.inner = self.inner.ending_before(ending_before);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.inner = self.inner.starting_after(starting_after);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::ApplePayDomain>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::apple_pay::ApplePay`].
+pub struct ApplePay {
+    inner: crate::apple_pay::ApplePay,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl ApplePay {
+    pub(crate) fn new(
+        inner: crate::apple_pay::ApplePay,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        ApplePay { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::apple_pay::ApplePay::get_domains`].
+    pub fn get_domains(
+        &self,
+        domain_name: &str,
+        ending_before: &str,
+        limit: i64,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ApplePayDomain>>> {
+        self.runtime.block_on(self.inner.get_domains(
+            domain_name,
+            ending_before,
+            limit,
+            starting_after,
+        ))
+    }
+    /// The blocking version of [`crate::apple_pay::ApplePay::get_domains_request`].
+    pub fn get_domains_request(&self) -> ApplePayGetDomainsRequest<'_> {
+        ApplePayGetDomainsRequest {
+            inner: self.inner.get_domains_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::apple_pay::ApplePay::get_all_domains`].
+    pub fn get_all_domains(
+        &self,
+        domain_name: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ApplePayDomain>>> {
+        self.runtime
+            .block_on(self.inner.get_all_domains(domain_name))
+    }
+    /// The blocking version of [`crate::apple_pay::ApplePay::post_domain`].
+    pub fn post_domain(&self) -> ClientResult<crate::Response<crate::types::ApplePayDomain>> {
+        self.runtime.block_on(self.inner.post_domain())
+    }
+    /// The blocking version of [`crate::apple_pay::ApplePay::get_domains_domain`].
+    pub fn get_domains_domain(
+        &self,
+        domain: &str,
+    ) -> ClientResult<crate::Response<crate::types::ApplePayDomain>> {
+        self.runtime.block_on(self.inner.get_domains_domain(domain))
+    }
+    /// The blocking version of [`crate::apple_pay::ApplePay::delete_domains_domain`].
+    pub fn delete_domains_domain(
+        &self,
+        domain: &str,
+    ) -> ClientResult<crate::Response<crate::types::DeletedApplePayDomain>> {
+        self.runtime
+            .block_on(self.inner.delete_domains_domain(domain))
+    }
+}
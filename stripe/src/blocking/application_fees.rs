@@ -0,0 +1,181 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`ApplicationFees::get_page`], set with a builder.
+pub struct ApplicationFeesGetPageRequest<'a> {
+    inner: crate::application_fees::ApplicationFeesGetPageRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ApplicationFeesGetPageRequest<'a> {
+    /// Sets the `charge` query parameter.
+    pub fn charge(mut self, charge: &'a str) -> Self {
+        self.inner = self.inner.charge(charge);
+        self
+    }
+
+    /// Sets the `ending_before` query parameter.
+    pub fn ending_before(mut self, ending_before: &'a str) -> Self {
+        self.inner = self.inner.ending_before(ending_before);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.inner = self.inner.starting_after(starting_after);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::PlatformFee>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`ApplicationFees::get_refunds`], set with a builder.
+pub struct ApplicationFeesGetRefundsRequest<'a> {
+    inner: crate::application_fees::ApplicationFeesGetRefundsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ApplicationFeesGetRefundsRequest<'a> {
+    /// Sets the `ending_before` query parameter.
+    pub fn ending_before(mut self, ending_before: &'a str) -> Self {
+        self.inner = self.inner.ending_before(ending_before);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.inner = self.inner.starting_after(starting_after);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::FeeRefund>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::application_fees::ApplicationFees`].
+pub struct ApplicationFees {
+    inner: crate::application_fees::ApplicationFees,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl ApplicationFees {
+    pub(crate) fn new(
+        inner: crate::application_fees::ApplicationFees,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        ApplicationFees { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::application_fees::ApplicationFees::get_page`].
+    pub fn get_page(
+        &self,
+        charge: &str,
+        _created: &str,
+        ending_before: &str,
+        limit: i64,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::PlatformFee>>> {
+        self.runtime.block_on(self.inner.get_page(
+            charge,
+            _created,
+            ending_before,
+            limit,
+            starting_after,
+        ))
+    }
+    /// The blocking version of [`crate::application_fees::ApplicationFees::get_page_request`].
+    pub fn get_page_request(&self) -> ApplicationFeesGetPageRequest<'_> {
+        ApplicationFeesGetPageRequest {
+            inner: self.inner.get_page_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::application_fees::ApplicationFees::get_all`].
+    pub fn get_all(
+        &self,
+        charge: &str,
+        _created: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::PlatformFee>>> {
+        self.runtime.block_on(self.inner.get_all(charge, _created))
+    }
+    /// The blocking version of [`crate::application_fees::ApplicationFees::get_fee_refund`].
+    pub fn get_fee_refund(
+        &self,
+        fee: &str,
+        id: &str,
+    ) -> ClientResult<crate::Response<crate::types::FeeRefund>> {
+        self.runtime.block_on(self.inner.get_fee_refund(fee, id))
+    }
+    /// The blocking version of [`crate::application_fees::ApplicationFees::post_fee_refund`].
+    pub fn post_fee_refund(
+        &self,
+        fee: &str,
+        id: &str,
+    ) -> ClientResult<crate::Response<crate::types::FeeRefund>> {
+        self.runtime.block_on(self.inner.post_fee_refund(fee, id))
+    }
+    /// The blocking version of [`crate::application_fees::ApplicationFees::get`].
+    pub fn get(&self, id: &str) -> ClientResult<crate::Response<crate::types::PlatformFee>> {
+        self.runtime.block_on(self.inner.get(id))
+    }
+    /// The blocking version of [`crate::application_fees::ApplicationFees::post_refund`].
+    pub fn post_refund(
+        &self,
+        id: &str,
+    ) -> ClientResult<crate::Response<crate::types::PlatformFee>> {
+        self.runtime.block_on(self.inner.post_refund(id))
+    }
+    /// The blocking version of [`crate::application_fees::ApplicationFees::get_refunds`].
+    pub fn get_refunds(
+        &self,
+        ending_before: &str,
+        id: &str,
+        limit: i64,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::FeeRefund>>> {
+        self.runtime.block_on(
+            self.inner
+                .get_refunds(ending_before, id, limit, starting_after),
+        )
+    }
+    /// The blocking version of [`crate::application_fees::ApplicationFees::get_refunds_request`].
+    pub fn get_refunds_request<'a>(&'a self, id: &'a str) -> ApplicationFeesGetRefundsRequest<'a> {
+        ApplicationFeesGetRefundsRequest {
+            inner: self.inner.get_refunds_request(id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::application_fees::ApplicationFees::get_all_refunds`].
+    pub fn get_all_refunds(
+        &self,
+        id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::FeeRefund>>> {
+        self.runtime.block_on(self.inner.get_all_refunds(id))
+    }
+    /// The blocking version of [`crate::application_fees::ApplicationFees::post_refund_application_fees`].
+    pub fn post_refund_application_fees(
+        &self,
+        id: &str,
+    ) -> ClientResult<crate::Response<crate::types::FeeRefund>> {
+        self.runtime
+            .block_on(self.inner.post_refund_application_fees(id))
+    }
+}
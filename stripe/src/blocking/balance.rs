@@ -0,0 +1,129 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Balance::get_history`], set with a builder.
+pub struct BalanceGetHistoryRequest<'a> {
+    inner: crate::balance::BalanceGetHistoryRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> BalanceGetHistoryRequest<'a> {
+    /// Sets the `currency` query parameter.
+    pub fn currency(mut self, currency: &'a str) -> Self {
+        self.inner = self.inner.currency(currency);
+        self
+    }
+
+    /// Sets the `ending_before` query parameter.
+    pub fn ending_before(mut self, ending_before: &'a str) -> Self {
+        self.inner = self.inner.ending_before(ending_before);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `payout` query parameter.
+    pub fn payout(mut self, payout: &'a str) -> Self {
+        self.inner = self.inner.payout(payout);
+        self
+    }
+
+    /// Sets the `source` query parameter.
+    pub fn source(mut self, source: &'a str) -> Self {
+        self.inner = self.inner.source(source);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.inner = self.inner.starting_after(starting_after);
+        self
+    }
+
+    /// Sets the `type` query parameter.
+    pub fn type_(mut self, type_: &'a str) -> Self {
+        self.inner = self.inner.type_(type_);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::BalanceTransaction>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::balance::Balance`].
+pub struct Balance {
+    inner: crate::balance::Balance,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Balance {
+    pub(crate) fn new(
+        inner: crate::balance::Balance,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Balance { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::balance::Balance::get`].
+    pub fn get(&self) -> ClientResult<crate::Response<crate::types::Balance>> {
+        self.runtime.block_on(self.inner.get())
+    }
+    /// The blocking version of [`crate::balance::Balance::get_history`].
+    pub fn get_history(
+        &self,
+        _created: &str,
+        currency: &str,
+        ending_before: &str,
+        limit: i64,
+        payout: &str,
+        source: &str,
+        starting_after: &str,
+        type_: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::BalanceTransaction>>> {
+        self.runtime.block_on(self.inner.get_history(
+            _created,
+            currency,
+            ending_before,
+            limit,
+            payout,
+            source,
+            starting_after,
+            type_,
+        ))
+    }
+    /// The blocking version of [`crate::balance::Balance::get_history_request`].
+    pub fn get_history_request(&self) -> BalanceGetHistoryRequest<'_> {
+        BalanceGetHistoryRequest {
+            inner: self.inner.get_history_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::balance::Balance::get_all_history`].
+    pub fn get_all_history(
+        &self,
+        _created: &str,
+        currency: &str,
+        payout: &str,
+        source: &str,
+        type_: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::BalanceTransaction>>> {
+        self.runtime.block_on(
+            self.inner
+                .get_all_history(_created, currency, payout, source, type_),
+        )
+    }
+    /// The blocking version of [`crate::balance::Balance::get_history_balance`].
+    pub fn get_history_balance(
+        &self,
+        id: &str,
+    ) -> ClientResult<crate::Response<crate::types::BalanceTransaction>> {
+        self.runtime.block_on(self.inner.get_history_balance(id))
+    }
+}
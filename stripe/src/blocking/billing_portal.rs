@@ -0,0 +1,121 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`BillingPortal::get_configurations`], set with a builder.
+pub struct BillingPortalGetConfigurationsRequest<'a> {
+    inner: crate::billing_portal::BillingPortalGetConfigurationsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> BillingPortalGetConfigurationsRequest<'a> {
+    /// Sets the `active` query parameter.
+    pub fn active(mut self, active: bool) -> Self {
+        self.inner = self.inner.active(active);
+        self
+    }
+
+    /// Sets the `ending_before` query parameter.
+    pub fn ending_before(mut self, ending_before: &'a str) -> Self {
+        self.inner = self.inner.ending_before(ending_before);
+        self
+    }
+
+    /// Sets the `is_default` query parameter.
+    pub fn is_default(mut self, is_default: bool) -> Self {
+        self.inner = self.inner.is_default(is_default);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.inner = self.inner.starting_after(starting_after);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::PortalConfiguration>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::billing_portal::BillingPortal`].
+pub struct BillingPortal {
+    inner: crate::billing_portal::BillingPortal,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl BillingPortal {
+    pub(crate) fn new(
+        inner: crate::billing_portal::BillingPortal,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        BillingPortal { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::billing_portal::BillingPortal::get_configurations`].
+    pub fn get_configurations(
+        &self,
+        active: bool,
+        ending_before: &str,
+        is_default: bool,
+        limit: i64,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::PortalConfiguration>>> {
+        self.runtime.block_on(self.inner.get_configurations(
+            active,
+            ending_before,
+            is_default,
+            limit,
+            starting_after,
+        ))
+    }
+    /// The blocking version of [`crate::billing_portal::BillingPortal::get_configurations_request`].
+    pub fn get_configurations_request(&self) -> BillingPortalGetConfigurationsRequest<'_> {
+        BillingPortalGetConfigurationsRequest {
+            inner: self.inner.get_configurations_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::billing_portal::BillingPortal::get_all_configurations`].
+    pub fn get_all_configurations(
+        &self,
+        active: bool,
+        is_default: bool,
+    ) -> ClientResult<crate::Response<Vec<crate::types::PortalConfiguration>>> {
+        self.runtime
+            .block_on(self.inner.get_all_configurations(active, is_default))
+    }
+    /// The blocking version of [`crate::billing_portal::BillingPortal::post_configuration`].
+    pub fn post_configuration(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::PortalConfiguration>> {
+        self.runtime.block_on(self.inner.post_configuration())
+    }
+    /// The blocking version of [`crate::billing_portal::BillingPortal::get_configurations_configuration`].
+    pub fn get_configurations_configuration(
+        &self,
+        configuration: &str,
+    ) -> ClientResult<crate::Response<crate::types::PortalConfiguration>> {
+        self.runtime
+            .block_on(self.inner.get_configurations_configuration(configuration))
+    }
+    /// The blocking version of [`crate::billing_portal::BillingPortal::post_configurations_configuration`].
+    pub fn post_configurations_configuration(
+        &self,
+        configuration: &str,
+    ) -> ClientResult<crate::Response<crate::types::PortalConfiguration>> {
+        self.runtime
+            .block_on(self.inner.post_configurations_configuration(configuration))
+    }
+    /// The blocking version of [`crate::billing_portal::BillingPortal::post_session`].
+    pub fn post_session(&self) -> ClientResult<crate::Response<crate::types::PortalSession>> {
+        self.runtime.block_on(self.inner.post_session())
+    }
+}
@@ -0,0 +1,267 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Bitcoin::get_receivers`], set with a builder.
+pub struct BitcoinGetReceiversRequest<'a> {
+    inner: crate::bitcoin::BitcoinGetReceiversRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> BitcoinGetReceiversRequest<'a> {
+    /// Sets the `active` query parameter.
+    pub fn active(mut self, active: bool) -> Self {
+        self.inner = self.inner.active(active);
+        self
+    }
+
+    /// Sets the `ending_before` query parameter.
+    pub fn ending_before(mut self, ending_before: &'a str) -> Self {
+        self.inner = self.inner.ending_before(ending_before);
+        self
+    }
+
+    /// Sets the `filled` query parameter.
+    pub fn filled(mut self, filled: bool) -> Self {
+        self.inner = self.inner.filled(filled);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.inner = self.inner.starting_after(starting_after);
+        self
+    }
+
+    /// Sets the `uncaptured_funds` query parameter.
+    pub fn uncaptured_funds(mut self, uncaptured_funds: bool) -> Self {
+        self.inner = self.inner.uncaptured_funds(uncaptured_funds);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::BitcoinReceiver>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Bitcoin::get_receivers_receiver_transactions`], set with a builder.
+pub struct BitcoinGetReceiversReceiverTransactionsRequest<'a> {
+    inner: crate::bitcoin::BitcoinGetReceiversReceiverTransactionsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> BitcoinGetReceiversReceiverTransactionsRequest<'a> {
+    /// Sets the `customer` query parameter.
+    pub fn customer(mut self, customer: &'a str) -> Self {
+        self.inner = self.inner.customer(customer);
+        self
+    }
+
+    /// Sets the `ending_before` query parameter.
+    pub fn ending_before(mut self, ending_before: &'a str) -> Self {
+        self.inner = self.inner.ending_before(ending_before);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.inner = self.inner.starting_after(starting_after);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::BitcoinTransaction>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Bitcoin::get_transactions`], set with a builder.
+pub struct BitcoinGetTransactionsRequest<'a> {
+    inner: crate::bitcoin::BitcoinGetTransactionsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> BitcoinGetTransactionsRequest<'a> {
+    /// Sets the `customer` query parameter.
+    pub fn customer(mut self, customer: &'a str) -> Self {
+        self.inner = self.inner.customer(customer);
+        self
+    }
+
+    /// Sets the `ending_before` query parameter.
+    pub fn ending_before(mut self, ending_before: &'a str) -> Self {
+        self.inner = self.inner.ending_before(ending_before);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `receiver` query parameter.
+    pub fn receiver(mut self, receiver: &'a str) -> Self {
+        self.inner = self.inner.receiver(receiver);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.inner = self.inner.starting_after(starting_after);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::BitcoinTransaction>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::bitcoin::Bitcoin`].
+pub struct Bitcoin {
+    inner: crate::bitcoin::Bitcoin,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Bitcoin {
+    pub(crate) fn new(
+        inner: crate::bitcoin::Bitcoin,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Bitcoin { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::bitcoin::Bitcoin::get_receivers`].
+    pub fn get_receivers(
+        &self,
+        active: bool,
+        ending_before: &str,
+        filled: bool,
+        limit: i64,
+        starting_after: &str,
+        uncaptured_funds: bool,
+    ) -> ClientResult<crate::Response<Vec<crate::types::BitcoinReceiver>>> {
+        self.runtime.block_on(self.inner.get_receivers(
+            active,
+            ending_before,
+            filled,
+            limit,
+            starting_after,
+            uncaptured_funds,
+        ))
+    }
+    /// The blocking version of [`crate::bitcoin::Bitcoin::get_receivers_request`].
+    pub fn get_receivers_request(&self) -> BitcoinGetReceiversRequest<'_> {
+        BitcoinGetReceiversRequest {
+            inner: self.inner.get_receivers_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::bitcoin::Bitcoin::get_all_receivers`].
+    pub fn get_all_receivers(
+        &self,
+        active: bool,
+        filled: bool,
+        uncaptured_funds: bool,
+    ) -> ClientResult<crate::Response<Vec<crate::types::BitcoinReceiver>>> {
+        self.runtime.block_on(
+            self.inner
+                .get_all_receivers(active, filled, uncaptured_funds),
+        )
+    }
+    /// The blocking version of [`crate::bitcoin::Bitcoin::get_receiver`].
+    pub fn get_receiver(
+        &self,
+        id: &str,
+    ) -> ClientResult<crate::Response<crate::types::BitcoinReceiver>> {
+        self.runtime.block_on(self.inner.get_receiver(id))
+    }
+    /// The blocking version of [`crate::bitcoin::Bitcoin::get_receivers_receiver_transactions`].
+    pub fn get_receivers_receiver_transactions(
+        &self,
+        customer: &str,
+        ending_before: &str,
+        limit: i64,
+        receiver: &str,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::BitcoinTransaction>>> {
+        self.runtime
+            .block_on(self.inner.get_receivers_receiver_transactions(
+                customer,
+                ending_before,
+                limit,
+                receiver,
+                starting_after,
+            ))
+    }
+    /// The blocking version of [`crate::bitcoin::Bitcoin::get_receivers_receiver_transactions_request`].
+    pub fn get_receivers_receiver_transactions_request<'a>(
+        &'a self,
+        receiver: &'a str,
+    ) -> BitcoinGetReceiversReceiverTransactionsRequest<'a> {
+        BitcoinGetReceiversReceiverTransactionsRequest {
+            inner: self
+                .inner
+                .get_receivers_receiver_transactions_request(receiver),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::bitcoin::Bitcoin::get_all_receivers_receiver_transactions`].
+    pub fn get_all_receivers_receiver_transactions(
+        &self,
+        customer: &str,
+        receiver: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::BitcoinTransaction>>> {
+        self.runtime.block_on(
+            self.inner
+                .get_all_receivers_receiver_transactions(customer, receiver),
+        )
+    }
+    /// The blocking version of [`crate::bitcoin::Bitcoin::get_transactions`].
+    pub fn get_transactions(
+        &self,
+        customer: &str,
+        ending_before: &str,
+        limit: i64,
+        receiver: &str,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::BitcoinTransaction>>> {
+        self.runtime.block_on(self.inner.get_transactions(
+            customer,
+            ending_before,
+            limit,
+            receiver,
+            starting_after,
+        ))
+    }
+    /// The blocking version of [`crate::bitcoin::Bitcoin::get_transactions_request`].
+    pub fn get_transactions_request(&self) -> BitcoinGetTransactionsRequest<'_> {
+        BitcoinGetTransactionsRequest {
+            inner: self.inner.get_transactions_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::bitcoin::Bitcoin::get_all_transactions`].
+    pub fn get_all_transactions(
+        &self,
+        customer: &str,
+        receiver: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::BitcoinTransaction>>> {
+        self.runtime
+            .block_on(self.inner.get_all_transactions(customer, receiver))
+    }
+}
@@ -0,0 +1,291 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Charges::get_page`], set with a builder.
+pub struct ChargesGetPageRequest<'a> {
+    inner: crate::charges::ChargesGetPageRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ChargesGetPageRequest<'a> {
+    /// Sets the `customer` query parameter.
+    pub fn customer(mut self, customer: &'a str) -> Self {
+        self.inner = self.inner.customer(customer);
+        self
+    }
+
+    /// Sets the `ending_before` query parameter.
+    pub fn ending_before(mut self, ending_before: &'a str) -> Self {
+        self.inner = self.inner.ending_before(ending_before);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `payment_intent` query parameter.
+    pub fn payment_intent(mut self, payment_intent: &'a str) -> Self {
+        self.inner = self.inner.payment_intent(payment_intent);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.inner = self.inner.starting_after(starting_after);
+        self
+    }
+
+    /// Sets the `transfer_group` query parameter.
+    pub fn transfer_group(mut self, transfer_group: &'a str) -> Self {
+        self.inner = self.inner.transfer_group(transfer_group);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Charge>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Charges::get_search`], set with a builder.
+pub struct ChargesGetSearchRequest<'a> {
+    inner: crate::charges::ChargesGetSearchRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ChargesGetSearchRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: &'a str) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Charge>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Charges::get_refunds`], set with a builder.
+pub struct ChargesGetRefundsRequest<'a> {
+    inner: crate::charges::ChargesGetRefundsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ChargesGetRefundsRequest<'a> {
+    /// Sets the `ending_before` query parameter.
+    pub fn ending_before(mut self, ending_before: &'a str) -> Self {
+        self.inner = self.inner.ending_before(ending_before);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.inner = self.inner.starting_after(starting_after);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Refund>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::charges::Charges`].
+pub struct Charges {
+    inner: crate::charges::Charges,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Charges {
+    pub(crate) fn new(
+        inner: crate::charges::Charges,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Charges { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::charges::Charges::get_page`].
+    pub fn get_page(
+        &self,
+        _created: &str,
+        customer: &str,
+        ending_before: &str,
+        limit: i64,
+        payment_intent: &str,
+        starting_after: &str,
+        transfer_group: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Charge>>> {
+        self.runtime.block_on(self.inner.get_page(
+            _created,
+            customer,
+            ending_before,
+            limit,
+            payment_intent,
+            starting_after,
+            transfer_group,
+        ))
+    }
+    /// The blocking version of [`crate::charges::Charges::get_page_request`].
+    pub fn get_page_request(&self) -> ChargesGetPageRequest<'_> {
+        ChargesGetPageRequest {
+            inner: self.inner.get_page_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::charges::Charges::get_all`].
+    pub fn get_all(
+        &self,
+        _created: &str,
+        customer: &str,
+        payment_intent: &str,
+        transfer_group: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Charge>>> {
+        self.runtime.block_on(self.inner.get_all(
+            _created,
+            customer,
+            payment_intent,
+            transfer_group,
+        ))
+    }
+    /// The blocking version of [`crate::charges::Charges::post`].
+    pub fn post(&self) -> ClientResult<crate::Response<crate::types::Charge>> {
+        self.runtime.block_on(self.inner.post())
+    }
+    /// The blocking version of [`crate::charges::Charges::get_search`].
+    pub fn get_search(
+        &self,
+        limit: i64,
+        page: &str,
+        query: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Charge>>> {
+        self.runtime
+            .block_on(self.inner.get_search(limit, page, query))
+    }
+    /// The blocking version of [`crate::charges::Charges::get_search_request`].
+    pub fn get_search_request<'a>(&'a self, query: &'a str) -> ChargesGetSearchRequest<'a> {
+        ChargesGetSearchRequest {
+            inner: self.inner.get_search_request(query),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::charges::Charges::get_all_search`].
+    pub fn get_all_search(
+        &self,
+        query: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Charge>>> {
+        self.runtime.block_on(self.inner.get_all_search(query))
+    }
+    /// The blocking version of [`crate::charges::Charges::get`].
+    pub fn get(&self, charge: &str) -> ClientResult<crate::Response<crate::types::Charge>> {
+        self.runtime.block_on(self.inner.get(charge))
+    }
+    /// The blocking version of [`crate::charges::Charges::post_charges`].
+    pub fn post_charges(
+        &self,
+        charge: &str,
+    ) -> ClientResult<crate::Response<crate::types::Charge>> {
+        self.runtime.block_on(self.inner.post_charges(charge))
+    }
+    /// The blocking version of [`crate::charges::Charges::post_capture`].
+    pub fn post_capture(
+        &self,
+        charge: &str,
+    ) -> ClientResult<crate::Response<crate::types::Charge>> {
+        self.runtime.block_on(self.inner.post_capture(charge))
+    }
+    /// The blocking version of [`crate::charges::Charges::get_dispute`].
+    pub fn get_dispute(
+        &self,
+        charge: &str,
+    ) -> ClientResult<crate::Response<crate::types::Dispute>> {
+        self.runtime.block_on(self.inner.get_dispute(charge))
+    }
+    /// The blocking version of [`crate::charges::Charges::post_dispute`].
+    pub fn post_dispute(
+        &self,
+        charge: &str,
+    ) -> ClientResult<crate::Response<crate::types::Dispute>> {
+        self.runtime.block_on(self.inner.post_dispute(charge))
+    }
+    /// The blocking version of [`crate::charges::Charges::post_dispute_close`].
+    pub fn post_dispute_close(
+        &self,
+        charge: &str,
+    ) -> ClientResult<crate::Response<crate::types::Dispute>> {
+        self.runtime.block_on(self.inner.post_dispute_close(charge))
+    }
+    /// The blocking version of [`crate::charges::Charges::post_refund`].
+    pub fn post_refund(&self, charge: &str) -> ClientResult<crate::Response<crate::types::Charge>> {
+        self.runtime.block_on(self.inner.post_refund(charge))
+    }
+    /// The blocking version of [`crate::charges::Charges::get_refunds`].
+    pub fn get_refunds(
+        &self,
+        charge: &str,
+        ending_before: &str,
+        limit: i64,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Refund>>> {
+        self.runtime.block_on(
+            self.inner
+                .get_refunds(charge, ending_before, limit, starting_after),
+        )
+    }
+    /// The blocking version of [`crate::charges::Charges::get_refunds_request`].
+    pub fn get_refunds_request<'a>(&'a self, charge: &'a str) -> ChargesGetRefundsRequest<'a> {
+        ChargesGetRefundsRequest {
+            inner: self.inner.get_refunds_request(charge),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::charges::Charges::get_all_refunds`].
+    pub fn get_all_refunds(
+        &self,
+        charge: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Refund>>> {
+        self.runtime.block_on(self.inner.get_all_refunds(charge))
+    }
+    /// The blocking version of [`crate::charges::Charges::post_refund_charges`].
+    pub fn post_refund_charges(
+        &self,
+        charge: &str,
+    ) -> ClientResult<crate::Response<crate::types::Refund>> {
+        self.runtime
+            .block_on(self.inner.post_refund_charges(charge))
+    }
+    /// The blocking version of [`crate::charges::Charges::get_refunds_refund`].
+    pub fn get_refunds_refund(
+        &self,
+        charge: &str,
+        refund: &str,
+    ) -> ClientResult<crate::Response<crate::types::Refund>> {
+        self.runtime
+            .block_on(self.inner.get_refunds_refund(charge, refund))
+    }
+    /// The blocking version of [`crate::charges::Charges::post_refunds_refund`].
+    pub fn post_refunds_refund(
+        &self,
+        charge: &str,
+        refund: &str,
+    ) -> ClientResult<crate::Response<crate::types::Refund>> {
+        self.runtime
+            .block_on(self.inner.post_refunds_refund(charge, refund))
+    }
+}
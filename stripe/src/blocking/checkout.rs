@@ -0,0 +1,180 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Checkout::get_sessions`], set with a builder.
+pub struct CheckoutGetSessionsRequest<'a> {
+    inner: crate::checkout::CheckoutGetSessionsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> CheckoutGetSessionsRequest<'a> {
+    /// Sets the `ending_before` query parameter.
+    pub fn ending_before(mut self, ending_before: &'a str) -> Self {
+        self.inner = self.inner.ending_before(ending_before);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `payment_intent` query parameter.
+    pub fn payment_intent(mut self, payment_intent: &'a str) -> Self {
+        self.inner = self.inner.payment_intent(payment_intent);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.inner = self.inner.starting_after(starting_after);
+        self
+    }
+
+    /// Sets the `subscription` query parameter.
+    pub fn subscription(mut self, subscription: &'a str) -> Self {
+        self.inner = self.inner.subscription(subscription);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Session>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Checkout::get_sessions_session_line_items`], set with a builder.
+pub struct CheckoutGetSessionsSessionLineItemsRequest<'a> {
+    inner: crate::checkout::CheckoutGetSessionsSessionLineItemsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> CheckoutGetSessionsSessionLineItemsRequest<'a> {
+    /// Sets the `ending_before` query parameter.
+    pub fn ending_before(mut self, ending_before: &'a str) -> Self {
+        self.inner = self.inner.ending_before(ending_before);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.inner = self.inner.starting_after(starting_after);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Item>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::checkout::Checkout`].
+pub struct Checkout {
+    inner: crate::checkout::Checkout,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Checkout {
+    pub(crate) fn new(
+        inner: crate::checkout::Checkout,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Checkout { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::checkout::Checkout::get_sessions`].
+    pub fn get_sessions(
+        &self,
+        ending_before: &str,
+        limit: i64,
+        payment_intent: &str,
+        starting_after: &str,
+        subscription: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Session>>> {
+        self.runtime.block_on(self.inner.get_sessions(
+            ending_before,
+            limit,
+            payment_intent,
+            starting_after,
+            subscription,
+        ))
+    }
+    /// The blocking version of [`crate::checkout::Checkout::get_sessions_request`].
+    pub fn get_sessions_request(&self) -> CheckoutGetSessionsRequest<'_> {
+        CheckoutGetSessionsRequest {
+            inner: self.inner.get_sessions_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::checkout::Checkout::get_all_sessions`].
+    pub fn get_all_sessions(
+        &self,
+        payment_intent: &str,
+        subscription: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Session>>> {
+        self.runtime
+            .block_on(self.inner.get_all_sessions(payment_intent, subscription))
+    }
+    /// The blocking version of [`crate::checkout::Checkout::post_session`].
+    pub fn post_session(&self) -> ClientResult<crate::Response<crate::types::Session>> {
+        self.runtime.block_on(self.inner.post_session())
+    }
+    /// The blocking version of [`crate::checkout::Checkout::get_sessions_session`].
+    pub fn get_sessions_session(
+        &self,
+        session: &str,
+    ) -> ClientResult<crate::Response<crate::types::Session>> {
+        self.runtime
+            .block_on(self.inner.get_sessions_session(session))
+    }
+    /// The blocking version of [`crate::checkout::Checkout::post_sessions_session_expire`].
+    pub fn post_sessions_session_expire(
+        &self,
+        session: &str,
+    ) -> ClientResult<crate::Response<crate::types::Session>> {
+        self.runtime
+            .block_on(self.inner.post_sessions_session_expire(session))
+    }
+    /// The blocking version of [`crate::checkout::Checkout::get_sessions_session_line_items`].
+    pub fn get_sessions_session_line_items(
+        &self,
+        ending_before: &str,
+        limit: i64,
+        session: &str,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Item>>> {
+        self.runtime
+            .block_on(self.inner.get_sessions_session_line_items(
+                ending_before,
+                limit,
+                session,
+                starting_after,
+            ))
+    }
+    /// The blocking version of [`crate::checkout::Checkout::get_sessions_session_line_items_request`].
+    pub fn get_sessions_session_line_items_request<'a>(
+        &'a self,
+        session: &'a str,
+    ) -> CheckoutGetSessionsSessionLineItemsRequest<'a> {
+        CheckoutGetSessionsSessionLineItemsRequest {
+            inner: self.inner.get_sessions_session_line_items_request(session),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::checkout::Checkout::get_all_sessions_session_line_items`].
+    pub fn get_all_sessions_session_line_items(
+        &self,
+        session: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Item>>> {
+        self.runtime
+            .block_on(self.inner.get_all_sessions_session_line_items(session))
+    }
+}
@@ -0,0 +1,75 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`CountrySpecs::get_page`], set with a builder.
+pub struct CountrySpecsGetPageRequest<'a> {
+    inner: crate::country_specs::CountrySpecsGetPageRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> CountrySpecsGetPageRequest<'a> {
+    /// Sets the `ending_before` query parameter.
+    pub fn ending_before(mut self, ending_before: &'a str) -> Self {
+        self.inner = self.inner.ending_before(ending_before);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.inner = self.inner.starting_after(starting_after);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::CountrySpec>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::country_specs::CountrySpecs`].
+pub struct CountrySpecs {
+    inner: crate::country_specs::CountrySpecs,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl CountrySpecs {
+    pub(crate) fn new(
+        inner: crate::country_specs::CountrySpecs,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        CountrySpecs { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::country_specs::CountrySpecs::get_page`].
+    pub fn get_page(
+        &self,
+        ending_before: &str,
+        limit: i64,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::CountrySpec>>> {
+        self.runtime
+            .block_on(self.inner.get_page(ending_before, limit, starting_after))
+    }
+    /// The blocking version of [`crate::country_specs::CountrySpecs::get_page_request`].
+    pub fn get_page_request(&self) -> CountrySpecsGetPageRequest<'_> {
+        CountrySpecsGetPageRequest {
+            inner: self.inner.get_page_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::country_specs::CountrySpecs::get_all`].
+    pub fn get_all(&self) -> ClientResult<crate::Response<Vec<crate::types::CountrySpec>>> {
+        self.runtime.block_on(self.inner.get_all())
+    }
+    /// The blocking version of [`crate::country_specs::CountrySpecs::get`].
+    pub fn get(&self, country: &str) -> ClientResult<crate::Response<crate::types::CountrySpec>> {
+        self.runtime.block_on(self.inner.get(country))
+    }
+}
@@ -0,0 +1,99 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Coupons::get_page`], set with a builder.
+pub struct CouponsGetPageRequest<'a> {
+    inner: crate::coupons::CouponsGetPageRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> CouponsGetPageRequest<'a> {
+    /// Sets the `ending_before` query parameter.
+    pub fn ending_before(mut self, ending_before: &'a str) -> Self {
+        self.inner = self.inner.ending_before(ending_before);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.inner = self.inner.starting_after(starting_after);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Coupon>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::coupons::Coupons`].
+pub struct Coupons {
+    inner: crate::coupons::Coupons,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Coupons {
+    pub(crate) fn new(
+        inner: crate::coupons::Coupons,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Coupons { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::coupons::Coupons::get_page`].
+    pub fn get_page(
+        &self,
+        _created: &str,
+        ending_before: &str,
+        limit: i64,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Coupon>>> {
+        self.runtime.block_on(
+            self.inner
+                .get_page(_created, ending_before, limit, starting_after),
+        )
+    }
+    /// The blocking version of [`crate::coupons::Coupons::get_page_request`].
+    pub fn get_page_request(&self) -> CouponsGetPageRequest<'_> {
+        CouponsGetPageRequest {
+            inner: self.inner.get_page_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::coupons::Coupons::get_all`].
+    pub fn get_all(
+        &self,
+        _created: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Coupon>>> {
+        self.runtime.block_on(self.inner.get_all(_created))
+    }
+    /// The blocking version of [`crate::coupons::Coupons::post`].
+    pub fn post(&self) -> ClientResult<crate::Response<crate::types::Coupon>> {
+        self.runtime.block_on(self.inner.post())
+    }
+    /// The blocking version of [`crate::coupons::Coupons::get`].
+    pub fn get(&self, coupon: &str) -> ClientResult<crate::Response<crate::types::Coupon>> {
+        self.runtime.block_on(self.inner.get(coupon))
+    }
+    /// The blocking version of [`crate::coupons::Coupons::post_coupons`].
+    pub fn post_coupons(
+        &self,
+        coupon: &str,
+    ) -> ClientResult<crate::Response<crate::types::Coupon>> {
+        self.runtime.block_on(self.inner.post_coupons(coupon))
+    }
+    /// The blocking version of [`crate::coupons::Coupons::delete`].
+    pub fn delete(
+        &self,
+        coupon: &str,
+    ) -> ClientResult<crate::Response<crate::types::DeletedCoupon>> {
+        self.runtime.block_on(self.inner.delete(coupon))
+    }
+}
@@ -0,0 +1,409 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`CreditNotes::get_page`], set with a builder.
+pub struct CreditNotesGetPageRequest<'a> {
+    inner: crate::credit_notes::CreditNotesGetPageRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> CreditNotesGetPageRequest<'a> {
+    /// Sets the `customer` query parameter.
+    pub fn customer(mut self, customer: &'a str) -> Self {
+        self.inner = self.inner.customer(customer);
+        self
+    }
+
+    /// Sets the `ending_before` query parameter.
+    pub fn ending_before(mut self, ending_before: &'a str) -> Self {
+        self.inner = self.inner.ending_before(ending_before);
+        self
+    }
+
+    /// Sets the `invoice` query parameter.
+    pub fn invoice(mut self, invoice: &'a str) -> Self {
+        self.inner = self.inner.invoice(invoice);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.inner = self.inner.starting_after(starting_after);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::CreditNote>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`CreditNotes::get_preview`], set with a builder.
+pub struct CreditNotesGetPreviewRequest<'a> {
+    inner: crate::credit_notes::CreditNotesGetPreviewRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> CreditNotesGetPreviewRequest<'a> {
+    /// Sets the `amount` query parameter.
+    pub fn amount(mut self, amount: i64) -> Self {
+        self.inner = self.inner.amount(amount);
+        self
+    }
+
+    /// Sets the `credit_amount` query parameter.
+    pub fn credit_amount(mut self, credit_amount: i64) -> Self {
+        self.inner = self.inner.credit_amount(credit_amount);
+        self
+    }
+
+    /// Sets the `memo` query parameter.
+    pub fn memo(mut self, memo: &'a str) -> Self {
+        self.inner = self.inner.memo(memo);
+        self
+    }
+
+    /// Sets the `out_of_band_amount` query parameter.
+    pub fn out_of_band_amount(mut self, out_of_band_amount: i64) -> Self {
+        self.inner = self.inner.out_of_band_amount(out_of_band_amount);
+        self
+    }
+
+    /// Sets the `reason` query parameter.
+    pub fn reason(mut self, reason: crate::types::Reason) -> Self {
+        self.inner = self.inner.reason(reason);
+        self
+    }
+
+    /// Sets the `refund` query parameter.
+    pub fn refund(mut self, refund: &'a str) -> Self {
+        self.inner = self.inner.refund(refund);
+        self
+    }
+
+    /// Sets the `refund_amount` query parameter.
+    pub fn refund_amount(mut self, refund_amount: i64) -> Self {
+        self.inner = self.inner.refund_amount(refund_amount);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::CreditNote>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`CreditNotes::get_preview_lines`], set with a builder.
+pub struct CreditNotesGetPreviewLinesRequest<'a> {
+    inner: crate::credit_notes::CreditNotesGetPreviewLinesRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> CreditNotesGetPreviewLinesRequest<'a> {
+    /// Sets the `amount` query parameter.
+    pub fn amount(mut self, amount: i64) -> Self {
+        self.inner = self.inner.amount(amount);
+        self
+    }
+
+    /// Sets the `credit_amount` query parameter.
+    pub fn credit_amount(mut self, credit_amount: i64) -> Self {
+        self.inner = self.inner.credit_amount(credit_amount);
+        self
+    }
+
+    /// Sets the `ending_before` query parameter.
+    pub fn ending_before(mut self, ending_before: &'a str) -> Self {
+        self.inner = self.inner.ending_before(ending_before);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `memo` query parameter.
+    pub fn memo(mut self, memo: &'a str) -> Self {
+        self.inner = self.inner.memo(memo);
+        self
+    }
+
+    /// Sets the `out_of_band_amount` query parameter.
+    pub fn out_of_band_amount(mut self, out_of_band_amount: i64) -> Self {
+        self.inner = self.inner.out_of_band_amount(out_of_band_amount);
+        self
+    }
+
+    /// Sets the `reason` query parameter.
+    pub fn reason(mut self, reason: crate::types::Reason) -> Self {
+        self.inner = self.inner.reason(reason);
+        self
+    }
+
+    /// Sets the `refund` query parameter.
+    pub fn refund(mut self, refund: &'a str) -> Self {
+        self.inner = self.inner.refund(refund);
+        self
+    }
+
+    /// Sets the `refund_amount` query parameter.
+    pub fn refund_amount(mut self, refund_amount: i64) -> Self {
+        self.inner = self.inner.refund_amount(refund_amount);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.inner = self.inner.starting_after(starting_after);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::CreditNoteLineItem>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`CreditNotes::get_note_lines`], set with a builder.
+pub struct CreditNotesGetNoteLinesRequest<'a> {
+    inner: crate::credit_notes::CreditNotesGetNoteLinesRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> CreditNotesGetNoteLinesRequest<'a> {
+    /// Sets the `ending_before` query parameter.
+    pub fn ending_before(mut self, ending_before: &'a str) -> Self {
+        self.inner = self.inner.ending_before(ending_before);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.inner = self.inner.starting_after(starting_after);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::CreditNoteLineItem>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::credit_notes::CreditNotes`].
+pub struct CreditNotes {
+    inner: crate::credit_notes::CreditNotes,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl CreditNotes {
+    pub(crate) fn new(
+        inner: crate::credit_notes::CreditNotes,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        CreditNotes { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::credit_notes::CreditNotes::get_page`].
+    pub fn get_page(
+        &self,
+        customer: &str,
+        ending_before: &str,
+        invoice: &str,
+        limit: i64,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::CreditNote>>> {
+        self.runtime.block_on(self.inner.get_page(
+            customer,
+            ending_before,
+            invoice,
+            limit,
+            starting_after,
+        ))
+    }
+    /// The blocking version of [`crate::credit_notes::CreditNotes::get_page_request`].
+    pub fn get_page_request(&self) -> CreditNotesGetPageRequest<'_> {
+        CreditNotesGetPageRequest {
+            inner: self.inner.get_page_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::credit_notes::CreditNotes::get_all`].
+    pub fn get_all(
+        &self,
+        customer: &str,
+        invoice: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::CreditNote>>> {
+        self.runtime.block_on(self.inner.get_all(customer, invoice))
+    }
+    /// The blocking version of [`crate::credit_notes::CreditNotes::post`].
+    pub fn post(&self) -> ClientResult<crate::Response<crate::types::CreditNote>> {
+        self.runtime.block_on(self.inner.post())
+    }
+    /// The blocking version of [`crate::credit_notes::CreditNotes::get_preview`].
+    pub fn get_preview(
+        &self,
+        amount: i64,
+        credit_amount: i64,
+        invoice: &str,
+        _lines: &[String],
+        memo: &str,
+        _metadata: &str,
+        out_of_band_amount: i64,
+        reason: crate::types::Reason,
+        refund: &str,
+        refund_amount: i64,
+    ) -> ClientResult<crate::Response<crate::types::CreditNote>> {
+        self.runtime.block_on(self.inner.get_preview(
+            amount,
+            credit_amount,
+            invoice,
+            _lines,
+            memo,
+            _metadata,
+            out_of_band_amount,
+            reason,
+            refund,
+            refund_amount,
+        ))
+    }
+    /// The blocking version of [`crate::credit_notes::CreditNotes::get_preview_request`].
+    pub fn get_preview_request<'a>(&'a self, invoice: &'a str) -> CreditNotesGetPreviewRequest<'a> {
+        CreditNotesGetPreviewRequest {
+            inner: self.inner.get_preview_request(invoice),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::credit_notes::CreditNotes::get_preview_lines`].
+    pub fn get_preview_lines(
+        &self,
+        amount: i64,
+        credit_amount: i64,
+        ending_before: &str,
+        invoice: &str,
+        limit: i64,
+        _lines: &[String],
+        memo: &str,
+        _metadata: &str,
+        out_of_band_amount: i64,
+        reason: crate::types::Reason,
+        refund: &str,
+        refund_amount: i64,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::CreditNoteLineItem>>> {
+        self.runtime.block_on(self.inner.get_preview_lines(
+            amount,
+            credit_amount,
+            ending_before,
+            invoice,
+            limit,
+            _lines,
+            memo,
+            _metadata,
+            out_of_band_amount,
+            reason,
+            refund,
+            refund_amount,
+            starting_after,
+        ))
+    }
+    /// The blocking version of [`crate::credit_notes::CreditNotes::get_preview_lines_request`].
+    pub fn get_preview_lines_request<'a>(
+        &'a self,
+        invoice: &'a str,
+    ) -> CreditNotesGetPreviewLinesRequest<'a> {
+        CreditNotesGetPreviewLinesRequest {
+            inner: self.inner.get_preview_lines_request(invoice),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::credit_notes::CreditNotes::get_all_preview_lines`].
+    pub fn get_all_preview_lines(
+        &self,
+        amount: i64,
+        credit_amount: i64,
+        invoice: &str,
+        _lines: &[String],
+        memo: &str,
+        _metadata: &str,
+        out_of_band_amount: i64,
+        reason: crate::types::Reason,
+        refund: &str,
+        refund_amount: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::CreditNoteLineItem>>> {
+        self.runtime.block_on(self.inner.get_all_preview_lines(
+            amount,
+            credit_amount,
+            invoice,
+            _lines,
+            memo,
+            _metadata,
+            out_of_band_amount,
+            reason,
+            refund,
+            refund_amount,
+        ))
+    }
+    /// The blocking version of [`crate::credit_notes::CreditNotes::get_note_lines`].
+    pub fn get_note_lines(
+        &self,
+        credit_note: &str,
+        ending_before: &str,
+        limit: i64,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::CreditNoteLineItem>>> {
+        self.runtime.block_on(self.inner.get_note_lines(
+            credit_note,
+            ending_before,
+            limit,
+            starting_after,
+        ))
+    }
+    /// The blocking version of [`crate::credit_notes::CreditNotes::get_note_lines_request`].
+    pub fn get_note_lines_request<'a>(
+        &'a self,
+        credit_note: &'a str,
+    ) -> CreditNotesGetNoteLinesRequest<'a> {
+        CreditNotesGetNoteLinesRequest {
+            inner: self.inner.get_note_lines_request(credit_note),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::credit_notes::CreditNotes::get_all_note_lines`].
+    pub fn get_all_note_lines(
+        &self,
+        credit_note: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::CreditNoteLineItem>>> {
+        self.runtime
+            .block_on(self.inner.get_all_note_lines(credit_note))
+    }
+    /// The blocking version of [`crate::credit_notes::CreditNotes::get`].
+    pub fn get(&self, id: &str) -> ClientResult<crate::Response<crate::types::CreditNote>> {
+        self.runtime.block_on(self.inner.get(id))
+    }
+    /// The blocking version of [`crate::credit_notes::CreditNotes::post_credit_notes`].
+    pub fn post_credit_notes(
+        &self,
+        id: &str,
+    ) -> ClientResult<crate::Response<crate::types::CreditNote>> {
+        self.runtime.block_on(self.inner.post_credit_notes(id))
+    }
+    /// The blocking version of [`crate::credit_notes::CreditNotes::post_void`].
+    pub fn post_void(&self, id: &str) -> ClientResult<crate::Response<crate::types::CreditNote>> {
+        self.runtime.block_on(self.inner.post_void(id))
+    }
+}
@@ -0,0 +1,884 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Customers::get_page`], set with a builder.
+pub struct CustomersGetPageRequest<'a> {
+    inner: crate::customers::CustomersGetPageRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> CustomersGetPageRequest<'a> {
+    /// Sets the `email` query parameter.
+    pub fn email(mut self, email: &'a str) -> Self {
+        self.inner = self.inner.email(email);
+        self
+    }
+
+    /// Sets the `ending_before` query parameter.
+    pub fn ending_before(mut self, ending_before: &'a str) -> Self {
+        self.inner = self.inner.ending_before(ending_before);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.inner = self.inner.starting_after(starting_after);
+        self
+    }
+
+    /// Sets the `test_clock` query parameter.
+    pub fn test_clock(mut self, test_clock: &'a str) -> Self {
+        self.inner = self.inner.test_clock(test_clock);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Customer>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Customers::get_search`], set with a builder.
+pub struct CustomersGetSearchRequest<'a> {
+    inner: crate::customers::CustomersGetSearchRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> CustomersGetSearchRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: &'a str) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Charge>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Customers::get_balance_transactions`], set with a builder.
+pub struct CustomersGetBalanceTransactionsRequest<'a> {
+    inner: crate::customers::CustomersGetBalanceTransactionsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> CustomersGetBalanceTransactionsRequest<'a> {
+    /// Sets the `ending_before` query parameter.
+    pub fn ending_before(mut self, ending_before: &'a str) -> Self {
+        self.inner = self.inner.ending_before(ending_before);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.inner = self.inner.starting_after(starting_after);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(
+        self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::CustomerBalanceTransaction>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Customers::get_bank_accounts`], set with a builder.
+pub struct CustomersGetBankAccountsRequest<'a> {
+    inner: crate::customers::CustomersGetBankAccountsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> CustomersGetBankAccountsRequest<'a> {
+    /// Sets the `ending_before` query parameter.
+    pub fn ending_before(mut self, ending_before: &'a str) -> Self {
+        self.inner = self.inner.ending_before(ending_before);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.inner = self.inner.starting_after(starting_after);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::BankAccount>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Customers::get_cards`], set with a builder.
+pub struct CustomersGetCardsRequest<'a> {
+    inner: crate::customers::CustomersGetCardsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> CustomersGetCardsRequest<'a> {
+    /// Sets the `ending_before` query parameter.
+    pub fn ending_before(mut self, ending_before: &'a str) -> Self {
+        self.inner = self.inner.ending_before(ending_before);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.inner = self.inner.starting_after(starting_after);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Card>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Customers::get_payment_methods`], set with a builder.
+pub struct CustomersGetPaymentMethodsRequest<'a> {
+    inner: crate::customers::CustomersGetPaymentMethodsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> CustomersGetPaymentMethodsRequest<'a> {
+    /// Sets the `ending_before` query parameter.
+    pub fn ending_before(mut self, ending_before: &'a str) -> Self {
+        self.inner = self.inner.ending_before(ending_before);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.inner = self.inner.starting_after(starting_after);
+        self
+    }
+
+    /// Sets the `type` query parameter.
+    pub fn type_(mut self, type_: crate::types::GetCustomersCustomerPaymentMethodsType) -> Self {
+        self.inner = self.inner.type_(type_);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::PaymentMethod>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Customers::get_sources`], set with a builder.
+pub struct CustomersGetSourcesRequest<'a> {
+    inner: crate::customers::CustomersGetSourcesRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> CustomersGetSourcesRequest<'a> {
+    /// Sets the `ending_before` query parameter.
+    pub fn ending_before(mut self, ending_before: &'a str) -> Self {
+        self.inner = self.inner.ending_before(ending_before);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `object` query parameter.
+    pub fn object(mut self, object: &'a str) -> Self {
+        self.inner = self.inner.object(object);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.inner = self.inner.starting_after(starting_after);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(
+        self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::CustomerSourcesDataAnyOf>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Customers::get_subscriptions`], set with a builder.
+pub struct CustomersGetSubscriptionsRequest<'a> {
+    inner: crate::customers::CustomersGetSubscriptionsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> CustomersGetSubscriptionsRequest<'a> {
+    /// Sets the `ending_before` query parameter.
+    pub fn ending_before(mut self, ending_before: &'a str) -> Self {
+        self.inner = self.inner.ending_before(ending_before);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.inner = self.inner.starting_after(starting_after);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Subscription>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Customers::get_tax_ids`], set with a builder.
+pub struct CustomersGetTaxIdsRequest<'a> {
+    inner: crate::customers::CustomersGetTaxIdsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> CustomersGetTaxIdsRequest<'a> {
+    /// Sets the `ending_before` query parameter.
+    pub fn ending_before(mut self, ending_before: &'a str) -> Self {
+        self.inner = self.inner.ending_before(ending_before);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.inner = self.inner.starting_after(starting_after);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::TaxId>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::customers::Customers`].
+pub struct Customers {
+    inner: crate::customers::Customers,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Customers {
+    pub(crate) fn new(
+        inner: crate::customers::Customers,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Customers { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::customers::Customers::get_page`].
+    pub fn get_page(
+        &self,
+        _created: &str,
+        email: &str,
+        ending_before: &str,
+        limit: i64,
+        starting_after: &str,
+        test_clock: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Customer>>> {
+        self.runtime.block_on(self.inner.get_page(
+            _created,
+            email,
+            ending_before,
+            limit,
+            starting_after,
+            test_clock,
+        ))
+    }
+    /// The blocking version of [`crate::customers::Customers::get_page_request`].
+    pub fn get_page_request(&self) -> CustomersGetPageRequest<'_> {
+        CustomersGetPageRequest {
+            inner: self.inner.get_page_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::customers::Customers::get_all`].
+    pub fn get_all(
+        &self,
+        _created: &str,
+        email: &str,
+        test_clock: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Customer>>> {
+        self.runtime
+            .block_on(self.inner.get_all(_created, email, test_clock))
+    }
+    /// The blocking version of [`crate::customers::Customers::post`].
+    pub fn post(&self) -> ClientResult<crate::Response<crate::types::Customer>> {
+        self.runtime.block_on(self.inner.post())
+    }
+    /// The blocking version of [`crate::customers::Customers::get_search`].
+    pub fn get_search(
+        &self,
+        limit: i64,
+        page: &str,
+        query: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Charge>>> {
+        self.runtime
+            .block_on(self.inner.get_search(limit, page, query))
+    }
+    /// The blocking version of [`crate::customers::Customers::get_search_request`].
+    pub fn get_search_request<'a>(&'a self, query: &'a str) -> CustomersGetSearchRequest<'a> {
+        CustomersGetSearchRequest {
+            inner: self.inner.get_search_request(query),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::customers::Customers::get_all_search`].
+    pub fn get_all_search(
+        &self,
+        query: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Charge>>> {
+        self.runtime.block_on(self.inner.get_all_search(query))
+    }
+    /// The blocking version of [`crate::customers::Customers::get`].
+    pub fn get(
+        &self,
+        customer: &str,
+    ) -> ClientResult<crate::Response<crate::types::GetCustomersCustomerResponseAnyOf>> {
+        self.runtime.block_on(self.inner.get(customer))
+    }
+    /// The blocking version of [`crate::customers::Customers::post_customers`].
+    pub fn post_customers(
+        &self,
+        customer: &str,
+    ) -> ClientResult<crate::Response<crate::types::Customer>> {
+        self.runtime.block_on(self.inner.post_customers(customer))
+    }
+    /// The blocking version of [`crate::customers::Customers::delete`].
+    pub fn delete(
+        &self,
+        customer: &str,
+    ) -> ClientResult<crate::Response<crate::types::DeletedCustomer>> {
+        self.runtime.block_on(self.inner.delete(customer))
+    }
+    /// The blocking version of [`crate::customers::Customers::get_balance_transactions`].
+    pub fn get_balance_transactions(
+        &self,
+        customer: &str,
+        ending_before: &str,
+        limit: i64,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::CustomerBalanceTransaction>>> {
+        self.runtime.block_on(self.inner.get_balance_transactions(
+            customer,
+            ending_before,
+            limit,
+            starting_after,
+        ))
+    }
+    /// The blocking version of [`crate::customers::Customers::get_balance_transactions_request`].
+    pub fn get_balance_transactions_request<'a>(
+        &'a self,
+        customer: &'a str,
+    ) -> CustomersGetBalanceTransactionsRequest<'a> {
+        CustomersGetBalanceTransactionsRequest {
+            inner: self.inner.get_balance_transactions_request(customer),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::customers::Customers::get_all_balance_transactions`].
+    pub fn get_all_balance_transactions(
+        &self,
+        customer: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::CustomerBalanceTransaction>>> {
+        self.runtime
+            .block_on(self.inner.get_all_balance_transactions(customer))
+    }
+    /// The blocking version of [`crate::customers::Customers::post_balance_transaction`].
+    pub fn post_balance_transaction(
+        &self,
+        customer: &str,
+    ) -> ClientResult<crate::Response<crate::types::CustomerBalanceTransaction>> {
+        self.runtime
+            .block_on(self.inner.post_balance_transaction(customer))
+    }
+    /// The blocking version of [`crate::customers::Customers::get_balance_transactions_transaction`].
+    pub fn get_balance_transactions_transaction(
+        &self,
+        customer: &str,
+        transaction: &str,
+    ) -> ClientResult<crate::Response<crate::types::CustomerBalanceTransaction>> {
+        self.runtime.block_on(
+            self.inner
+                .get_balance_transactions_transaction(customer, transaction),
+        )
+    }
+    /// The blocking version of [`crate::customers::Customers::post_balance_transactions_transaction`].
+    pub fn post_balance_transactions_transaction(
+        &self,
+        customer: &str,
+        transaction: &str,
+    ) -> ClientResult<crate::Response<crate::types::CustomerBalanceTransaction>> {
+        self.runtime.block_on(
+            self.inner
+                .post_balance_transactions_transaction(customer, transaction),
+        )
+    }
+    /// The blocking version of [`crate::customers::Customers::get_bank_accounts`].
+    pub fn get_bank_accounts(
+        &self,
+        customer: &str,
+        ending_before: &str,
+        limit: i64,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::BankAccount>>> {
+        self.runtime.block_on(self.inner.get_bank_accounts(
+            customer,
+            ending_before,
+            limit,
+            starting_after,
+        ))
+    }
+    /// The blocking version of [`crate::customers::Customers::get_bank_accounts_request`].
+    pub fn get_bank_accounts_request<'a>(
+        &'a self,
+        customer: &'a str,
+    ) -> CustomersGetBankAccountsRequest<'a> {
+        CustomersGetBankAccountsRequest {
+            inner: self.inner.get_bank_accounts_request(customer),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::customers::Customers::get_all_bank_accounts`].
+    pub fn get_all_bank_accounts(
+        &self,
+        customer: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::BankAccount>>> {
+        self.runtime
+            .block_on(self.inner.get_all_bank_accounts(customer))
+    }
+    /// The blocking version of [`crate::customers::Customers::post_bank_account`].
+    pub fn post_bank_account(
+        &self,
+        customer: &str,
+    ) -> ClientResult<crate::Response<crate::types::PaymentSourceAnyOf>> {
+        self.runtime
+            .block_on(self.inner.post_bank_account(customer))
+    }
+    /// The blocking version of [`crate::customers::Customers::get_bank_account`].
+    pub fn get_bank_account(
+        &self,
+        customer: &str,
+        id: &str,
+    ) -> ClientResult<crate::Response<crate::types::BankAccount>> {
+        self.runtime
+            .block_on(self.inner.get_bank_account(customer, id))
+    }
+    /// The blocking version of [`crate::customers::Customers::post_bank_account_customers`].
+    pub fn post_bank_account_customers(
+        &self,
+        customer: &str,
+        id: &str,
+    ) -> ClientResult<crate::Response<crate::types::SourceAnyOf>> {
+        self.runtime
+            .block_on(self.inner.post_bank_account_customers(customer, id))
+    }
+    /// The blocking version of [`crate::customers::Customers::delete_bank_accounts`].
+    pub fn delete_bank_accounts(
+        &self,
+        customer: &str,
+        id: &str,
+    ) -> ClientResult<crate::Response<crate::types::DeleteCustomersCustomerCardsResponseAnyOf>>
+    {
+        self.runtime
+            .block_on(self.inner.delete_bank_accounts(customer, id))
+    }
+    /// The blocking version of [`crate::customers::Customers::post_bank_accounts_verify`].
+    pub fn post_bank_accounts_verify(
+        &self,
+        customer: &str,
+        id: &str,
+    ) -> ClientResult<crate::Response<crate::types::BankAccount>> {
+        self.runtime
+            .block_on(self.inner.post_bank_accounts_verify(customer, id))
+    }
+    /// The blocking version of [`crate::customers::Customers::get_cards`].
+    pub fn get_cards(
+        &self,
+        customer: &str,
+        ending_before: &str,
+        limit: i64,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Card>>> {
+        self.runtime.block_on(
+            self.inner
+                .get_cards(customer, ending_before, limit, starting_after),
+        )
+    }
+    /// The blocking version of [`crate::customers::Customers::get_cards_request`].
+    pub fn get_cards_request<'a>(&'a self, customer: &'a str) -> CustomersGetCardsRequest<'a> {
+        CustomersGetCardsRequest {
+            inner: self.inner.get_cards_request(customer),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::customers::Customers::get_all_cards`].
+    pub fn get_all_cards(
+        &self,
+        customer: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Card>>> {
+        self.runtime.block_on(self.inner.get_all_cards(customer))
+    }
+    /// The blocking version of [`crate::customers::Customers::post_card`].
+    pub fn post_card(
+        &self,
+        customer: &str,
+    ) -> ClientResult<crate::Response<crate::types::PaymentSourceAnyOf>> {
+        self.runtime.block_on(self.inner.post_card(customer))
+    }
+    /// The blocking version of [`crate::customers::Customers::get_card`].
+    pub fn get_card(
+        &self,
+        customer: &str,
+        id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Card>> {
+        self.runtime.block_on(self.inner.get_card(customer, id))
+    }
+    /// The blocking version of [`crate::customers::Customers::post_card_customers`].
+    pub fn post_card_customers(
+        &self,
+        customer: &str,
+        id: &str,
+    ) -> ClientResult<crate::Response<crate::types::SourceAnyOf>> {
+        self.runtime
+            .block_on(self.inner.post_card_customers(customer, id))
+    }
+    /// The blocking version of [`crate::customers::Customers::delete_cards`].
+    pub fn delete_cards(
+        &self,
+        customer: &str,
+        id: &str,
+    ) -> ClientResult<crate::Response<crate::types::DeleteCustomersCustomerCardsResponseAnyOf>>
+    {
+        self.runtime.block_on(self.inner.delete_cards(customer, id))
+    }
+    /// The blocking version of [`crate::customers::Customers::get_discount`].
+    pub fn get_discount(
+        &self,
+        customer: &str,
+    ) -> ClientResult<crate::Response<crate::types::DiscountData>> {
+        self.runtime.block_on(self.inner.get_discount(customer))
+    }
+    /// The blocking version of [`crate::customers::Customers::delete_discount`].
+    pub fn delete_discount(
+        &self,
+        customer: &str,
+    ) -> ClientResult<crate::Response<crate::types::DeletedDiscount>> {
+        self.runtime.block_on(self.inner.delete_discount(customer))
+    }
+    /// The blocking version of [`crate::customers::Customers::get_payment_methods`].
+    pub fn get_payment_methods(
+        &self,
+        customer: &str,
+        ending_before: &str,
+        limit: i64,
+        starting_after: &str,
+        type_: crate::types::GetCustomersCustomerPaymentMethodsType,
+    ) -> ClientResult<crate::Response<Vec<crate::types::PaymentMethod>>> {
+        self.runtime.block_on(self.inner.get_payment_methods(
+            customer,
+            ending_before,
+            limit,
+            starting_after,
+            type_,
+        ))
+    }
+    /// The blocking version of [`crate::customers::Customers::get_payment_methods_request`].
+    pub fn get_payment_methods_request<'a>(
+        &'a self,
+        customer: &'a str,
+    ) -> CustomersGetPaymentMethodsRequest<'a> {
+        CustomersGetPaymentMethodsRequest {
+            inner: self.inner.get_payment_methods_request(customer),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::customers::Customers::get_all_payment_methods`].
+    pub fn get_all_payment_methods(
+        &self,
+        customer: &str,
+        type_: crate::types::GetCustomersCustomerPaymentMethodsType,
+    ) -> ClientResult<crate::Response<Vec<crate::types::PaymentMethod>>> {
+        self.runtime
+            .block_on(self.inner.get_all_payment_methods(customer, type_))
+    }
+    /// The blocking version of [`crate::customers::Customers::get_sources`].
+    pub fn get_sources(
+        &self,
+        customer: &str,
+        ending_before: &str,
+        limit: i64,
+        object: &str,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::CustomerSourcesDataAnyOf>>> {
+        self.runtime.block_on(self.inner.get_sources(
+            customer,
+            ending_before,
+            limit,
+            object,
+            starting_after,
+        ))
+    }
+    /// The blocking version of [`crate::customers::Customers::get_sources_request`].
+    pub fn get_sources_request<'a>(&'a self, customer: &'a str) -> CustomersGetSourcesRequest<'a> {
+        CustomersGetSourcesRequest {
+            inner: self.inner.get_sources_request(customer),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::customers::Customers::get_all_sources`].
+    pub fn get_all_sources(
+        &self,
+        customer: &str,
+        object: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::CustomerSourcesDataAnyOf>>> {
+        self.runtime
+            .block_on(self.inner.get_all_sources(customer, object))
+    }
+    /// The blocking version of [`crate::customers::Customers::post_source`].
+    pub fn post_source(
+        &self,
+        customer: &str,
+    ) -> ClientResult<crate::Response<crate::types::PaymentSourceAnyOf>> {
+        self.runtime.block_on(self.inner.post_source(customer))
+    }
+    /// The blocking version of [`crate::customers::Customers::get_source`].
+    pub fn get_source(
+        &self,
+        customer: &str,
+        id: &str,
+    ) -> ClientResult<crate::Response<crate::types::PaymentSourceAnyOf>> {
+        self.runtime.block_on(self.inner.get_source(customer, id))
+    }
+    /// The blocking version of [`crate::customers::Customers::post_source_customers`].
+    pub fn post_source_customers(
+        &self,
+        customer: &str,
+        id: &str,
+    ) -> ClientResult<crate::Response<crate::types::SourceAnyOf>> {
+        self.runtime
+            .block_on(self.inner.post_source_customers(customer, id))
+    }
+    /// The blocking version of [`crate::customers::Customers::delete_sources`].
+    pub fn delete_sources(
+        &self,
+        customer: &str,
+        id: &str,
+    ) -> ClientResult<crate::Response<crate::types::DeleteCustomersCustomerCardsResponseAnyOf>>
+    {
+        self.runtime
+            .block_on(self.inner.delete_sources(customer, id))
+    }
+    /// The blocking version of [`crate::customers::Customers::post_sources_verify`].
+    pub fn post_sources_verify(
+        &self,
+        customer: &str,
+        id: &str,
+    ) -> ClientResult<crate::Response<crate::types::BankAccount>> {
+        self.runtime
+            .block_on(self.inner.post_sources_verify(customer, id))
+    }
+    /// The blocking version of [`crate::customers::Customers::get_subscriptions`].
+    pub fn get_subscriptions(
+        &self,
+        customer: &str,
+        ending_before: &str,
+        limit: i64,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Subscription>>> {
+        self.runtime.block_on(self.inner.get_subscriptions(
+            customer,
+            ending_before,
+            limit,
+            starting_after,
+        ))
+    }
+    /// The blocking version of [`crate::customers::Customers::get_subscriptions_request`].
+    pub fn get_subscriptions_request<'a>(
+        &'a self,
+        customer: &'a str,
+    ) -> CustomersGetSubscriptionsRequest<'a> {
+        CustomersGetSubscriptionsRequest {
+            inner: self.inner.get_subscriptions_request(customer),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::customers::Customers::get_all_subscriptions`].
+    pub fn get_all_subscriptions(
+        &self,
+        customer: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Subscription>>> {
+        self.runtime
+            .block_on(self.inner.get_all_subscriptions(customer))
+    }
+    /// The blocking version of [`crate::customers::Customers::post_subscription`].
+    pub fn post_subscription(
+        &self,
+        customer: &str,
+    ) -> ClientResult<crate::Response<crate::types::Subscription>> {
+        self.runtime
+            .block_on(self.inner.post_subscription(customer))
+    }
+    /// The blocking version of [`crate::customers::Customers::get_subscriptions_subscription_exposed`].
+    pub fn get_subscriptions_subscription_exposed(
+        &self,
+        customer: &str,
+        subscription_exposed_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Subscription>> {
+        self.runtime.block_on(
+            self.inner
+                .get_subscriptions_subscription_exposed(customer, subscription_exposed_id),
+        )
+    }
+    /// The blocking version of [`crate::customers::Customers::post_subscriptions_subscription_exposed`].
+    pub fn post_subscriptions_subscription_exposed(
+        &self,
+        customer: &str,
+        subscription_exposed_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Subscription>> {
+        self.runtime.block_on(
+            self.inner
+                .post_subscriptions_subscription_exposed(customer, subscription_exposed_id),
+        )
+    }
+    /// The blocking version of [`crate::customers::Customers::delete_subscriptions_subscription_exposed`].
+    pub fn delete_subscriptions_subscription_exposed(
+        &self,
+        customer: &str,
+        subscription_exposed_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Subscription>> {
+        self.runtime.block_on(
+            self.inner
+                .delete_subscriptions_subscription_exposed(customer, subscription_exposed_id),
+        )
+    }
+    /// The blocking version of [`crate::customers::Customers::get_subscriptions_subscription_exposed_discount`].
+    pub fn get_subscriptions_subscription_exposed_discount(
+        &self,
+        customer: &str,
+        subscription_exposed_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::DiscountData>> {
+        self.runtime.block_on(
+            self.inner
+                .get_subscriptions_subscription_exposed_discount(customer, subscription_exposed_id),
+        )
+    }
+    /// The blocking version of [`crate::customers::Customers::delete_subscriptions_subscription_exposed_discount`].
+    pub fn delete_subscriptions_subscription_exposed_discount(
+        &self,
+        customer: &str,
+        subscription_exposed_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::DeletedDiscount>> {
+        self.runtime.block_on(
+            self.inner
+                .delete_subscriptions_subscription_exposed_discount(
+                    customer,
+                    subscription_exposed_id,
+                ),
+        )
+    }
+    /// The blocking version of [`crate::customers::Customers::get_tax_ids`].
+    pub fn get_tax_ids(
+        &self,
+        customer: &str,
+        ending_before: &str,
+        limit: i64,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::TaxId>>> {
+        self.runtime.block_on(self.inner.get_tax_ids(
+            customer,
+            ending_before,
+            limit,
+            starting_after,
+        ))
+    }
+    /// The blocking version of [`crate::customers::Customers::get_tax_ids_request`].
+    pub fn get_tax_ids_request<'a>(&'a self, customer: &'a str) -> CustomersGetTaxIdsRequest<'a> {
+        CustomersGetTaxIdsRequest {
+            inner: self.inner.get_tax_ids_request(customer),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::customers::Customers::get_all_tax_ids`].
+    pub fn get_all_tax_ids(
+        &self,
+        customer: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::TaxId>>> {
+        self.runtime.block_on(self.inner.get_all_tax_ids(customer))
+    }
+    /// The blocking version of [`crate::customers::Customers::post_tax_id`].
+    pub fn post_tax_id(
+        &self,
+        customer: &str,
+    ) -> ClientResult<crate::Response<crate::types::TaxId>> {
+        self.runtime.block_on(self.inner.post_tax_id(customer))
+    }
+    /// The blocking version of [`crate::customers::Customers::get_tax_id`].
+    pub fn get_tax_id(
+        &self,
+        customer: &str,
+        id: &str,
+    ) -> ClientResult<crate::Response<crate::types::TaxId>> {
+        self.runtime.block_on(self.inner.get_tax_id(customer, id))
+    }
+    /// The blocking version of [`crate::customers::Customers::delete_tax_ids`].
+    pub fn delete_tax_ids(
+        &self,
+        customer: &str,
+        id: &str,
+    ) -> ClientResult<crate::Response<crate::types::DeletedTaxId>> {
+        self.runtime
+            .block_on(self.inner.delete_tax_ids(customer, id))
+    }
+}
@@ -0,0 +1,113 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Disputes::get_page`], set with a builder.
+pub struct DisputesGetPageRequest<'a> {
+    inner: crate::disputes::DisputesGetPageRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> DisputesGetPageRequest<'a> {
+    /// Sets the `charge` query parameter.
+    pub fn charge(mut self, charge: &'a str) -> Self {
+        self.inner = self.inner.charge(charge);
+        self
+    }
+
+    /// Sets the `ending_before` query parameter.
+    pub fn ending_before(mut self, ending_before: &'a str) -> Self {
+        self.inner = self.inner.ending_before(ending_before);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `payment_intent` query parameter.
+    pub fn payment_intent(mut self, payment_intent: &'a str) -> Self {
+        self.inner = self.inner.payment_intent(payment_intent);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.inner = self.inner.starting_after(starting_after);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Dispute>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::disputes::Disputes`].
+pub struct Disputes {
+    inner: crate::disputes::Disputes,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Disputes {
+    pub(crate) fn new(
+        inner: crate::disputes::Disputes,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Disputes { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::disputes::Disputes::get_page`].
+    pub fn get_page(
+        &self,
+        charge: &str,
+        _created: &str,
+        ending_before: &str,
+        limit: i64,
+        payment_intent: &str,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Dispute>>> {
+        self.runtime.block_on(self.inner.get_page(
+            charge,
+            _created,
+            ending_before,
+            limit,
+            payment_intent,
+            starting_after,
+        ))
+    }
+    /// The blocking version of [`crate::disputes::Disputes::get_page_request`].
+    pub fn get_page_request(&self) -> DisputesGetPageRequest<'_> {
+        DisputesGetPageRequest {
+            inner: self.inner.get_page_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::disputes::Disputes::get_all`].
+    pub fn get_all(
+        &self,
+        charge: &str,
+        _created: &str,
+        payment_intent: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Dispute>>> {
+        self.runtime
+            .block_on(self.inner.get_all(charge, _created, payment_intent))
+    }
+    /// The blocking version of [`crate::disputes::Disputes::get`].
+    pub fn get(&self, dispute: &str) -> ClientResult<crate::Response<crate::types::Dispute>> {
+        self.runtime.block_on(self.inner.get(dispute))
+    }
+    /// The blocking version of [`crate::disputes::Disputes::post`].
+    pub fn post(&self, dispute: &str) -> ClientResult<crate::Response<crate::types::Dispute>> {
+        self.runtime.block_on(self.inner.post(dispute))
+    }
+    /// The blocking version of [`crate::disputes::Disputes::post_close`].
+    pub fn post_close(
+        &self,
+        dispute: &str,
+    ) -> ClientResult<crate::Response<crate::types::Dispute>> {
+        self.runtime.block_on(self.inner.post_close(dispute))
+    }
+}
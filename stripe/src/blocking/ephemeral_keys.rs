@@ -0,0 +1,30 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::ephemeral_keys::EphemeralKeys`].
+pub struct EphemeralKeys {
+    inner: crate::ephemeral_keys::EphemeralKeys,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl EphemeralKeys {
+    pub(crate) fn new(
+        inner: crate::ephemeral_keys::EphemeralKeys,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        EphemeralKeys { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::ephemeral_keys::EphemeralKeys::post`].
+    pub fn post(&self) -> ClientResult<crate::Response<crate::types::EphemeralKey>> {
+        self.runtime.block_on(self.inner.post())
+    }
+    /// The blocking version of [`crate::ephemeral_keys::EphemeralKeys::delete_key`].
+    pub fn delete_key(
+        &self,
+        key: &str,
+    ) -> ClientResult<crate::Response<crate::types::EphemeralKey>> {
+        self.runtime.block_on(self.inner.delete_key(key))
+    }
+}
@@ -0,0 +1,104 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Events::get_page`], set with a builder.
+pub struct EventsGetPageRequest<'a> {
+    inner: crate::events::EventsGetPageRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> EventsGetPageRequest<'a> {
+    /// Sets the `delivery_success` query parameter.
+    pub fn delivery_success(mut self, delivery_success: bool) -> Self {
+        self.inner = self.inner.delivery_success(delivery_success);
+        self
+    }
+
+    /// Sets the `ending_before` query parameter.
+    pub fn ending_before(mut self, ending_before: &'a str) -> Self {
+        self.inner = self.inner.ending_before(ending_before);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.inner = self.inner.starting_after(starting_after);
+        self
+    }
+
+    /// Sets the `type` query parameter.
+    pub fn type_(mut self, type_: &'a str) -> Self {
+        self.inner = self.inner.type_(type_);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Event>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::events::Events`].
+pub struct Events {
+    inner: crate::events::Events,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Events {
+    pub(crate) fn new(inner: crate::events::Events, runtime: Arc<tokio::runtime::Runtime>) -> Self {
+        Events { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::events::Events::get_page`].
+    pub fn get_page(
+        &self,
+        _created: &str,
+        delivery_success: bool,
+        ending_before: &str,
+        limit: i64,
+        starting_after: &str,
+        type_: &str,
+        _types: &[String],
+    ) -> ClientResult<crate::Response<Vec<crate::types::Event>>> {
+        self.runtime.block_on(self.inner.get_page(
+            _created,
+            delivery_success,
+            ending_before,
+            limit,
+            starting_after,
+            type_,
+            _types,
+        ))
+    }
+    /// The blocking version of [`crate::events::Events::get_page_request`].
+    pub fn get_page_request(&self) -> EventsGetPageRequest<'_> {
+        EventsGetPageRequest {
+            inner: self.inner.get_page_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::events::Events::get_all`].
+    pub fn get_all(
+        &self,
+        _created: &str,
+        delivery_success: bool,
+        type_: &str,
+        _types: &[String],
+    ) -> ClientResult<crate::Response<Vec<crate::types::Event>>> {
+        self.runtime.block_on(
+            self.inner
+                .get_all(_created, delivery_success, type_, _types),
+        )
+    }
+    /// The blocking version of [`crate::events::Events::get`].
+    pub fn get(&self, id: &str) -> ClientResult<crate::Response<crate::types::Event>> {
+        self.runtime.block_on(self.inner.get(id))
+    }
+}
@@ -0,0 +1,110 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`FileLinks::get_page`], set with a builder.
+pub struct FileLinksGetPageRequest<'a> {
+    inner: crate::file_links::FileLinksGetPageRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> FileLinksGetPageRequest<'a> {
+    /// Sets the `ending_before` query parameter.
+    pub fn ending_before(mut self, ending_before: &'a str) -> Self {
+        self.inner = self.inner.ending_before(ending_before);
+        self
+    }
+
+    /// Sets the `expired` query parameter.
+    pub fn expired(mut self, expired: bool) -> Self {
+        self.inner = self.inner.expired(expired);
+        self
+    }
+
+    /// Sets the `file` query parameter.
+    pub fn file(mut self, file: &'a str) -> Self {
+        self.inner = self.inner.file(file);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.inner = self.inner.starting_after(starting_after);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::FileLink>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::file_links::FileLinks`].
+pub struct FileLinks {
+    inner: crate::file_links::FileLinks,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl FileLinks {
+    pub(crate) fn new(
+        inner: crate::file_links::FileLinks,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        FileLinks { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::file_links::FileLinks::get_page`].
+    pub fn get_page(
+        &self,
+        _created: &str,
+        ending_before: &str,
+        expired: bool,
+        file: &str,
+        limit: i64,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::FileLink>>> {
+        self.runtime.block_on(self.inner.get_page(
+            _created,
+            ending_before,
+            expired,
+            file,
+            limit,
+            starting_after,
+        ))
+    }
+    /// The blocking version of [`crate::file_links::FileLinks::get_page_request`].
+    pub fn get_page_request(&self) -> FileLinksGetPageRequest<'_> {
+        FileLinksGetPageRequest {
+            inner: self.inner.get_page_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::file_links::FileLinks::get_all`].
+    pub fn get_all(
+        &self,
+        _created: &str,
+        expired: bool,
+        file: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::FileLink>>> {
+        self.runtime
+            .block_on(self.inner.get_all(_created, expired, file))
+    }
+    /// The blocking version of [`crate::file_links::FileLinks::post`].
+    pub fn post(&self) -> ClientResult<crate::Response<crate::types::FileLink>> {
+        self.runtime.block_on(self.inner.post())
+    }
+    /// The blocking version of [`crate::file_links::FileLinks::get_link`].
+    pub fn get_link(&self, link: &str) -> ClientResult<crate::Response<crate::types::FileLink>> {
+        self.runtime.block_on(self.inner.get_link(link))
+    }
+    /// The blocking version of [`crate::file_links::FileLinks::post_link`].
+    pub fn post_link(&self, link: &str) -> ClientResult<crate::Response<crate::types::FileLink>> {
+        self.runtime.block_on(self.inner.post_link(link))
+    }
+}
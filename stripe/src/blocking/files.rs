@@ -0,0 +1,93 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Files::get_page`], set with a builder.
+pub struct FilesGetPageRequest<'a> {
+    inner: crate::files::FilesGetPageRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> FilesGetPageRequest<'a> {
+    /// Sets the `ending_before` query parameter.
+    pub fn ending_before(mut self, ending_before: &'a str) -> Self {
+        self.inner = self.inner.ending_before(ending_before);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `purpose` query parameter.
+    pub fn purpose(mut self, purpose: crate::types::Purpose) -> Self {
+        self.inner = self.inner.purpose(purpose);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.inner = self.inner.starting_after(starting_after);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::File>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::files::Files`].
+pub struct Files {
+    inner: crate::files::Files,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Files {
+    pub(crate) fn new(inner: crate::files::Files, runtime: Arc<tokio::runtime::Runtime>) -> Self {
+        Files { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::files::Files::get_page`].
+    pub fn get_page(
+        &self,
+        _created: &str,
+        ending_before: &str,
+        limit: i64,
+        purpose: crate::types::Purpose,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::File>>> {
+        self.runtime.block_on(self.inner.get_page(
+            _created,
+            ending_before,
+            limit,
+            purpose,
+            starting_after,
+        ))
+    }
+    /// The blocking version of [`crate::files::Files::get_page_request`].
+    pub fn get_page_request(&self) -> FilesGetPageRequest<'_> {
+        FilesGetPageRequest {
+            inner: self.inner.get_page_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::files::Files::get_all`].
+    pub fn get_all(
+        &self,
+        _created: &str,
+        purpose: crate::types::Purpose,
+    ) -> ClientResult<crate::Response<Vec<crate::types::File>>> {
+        self.runtime.block_on(self.inner.get_all(_created, purpose))
+    }
+    /// The blocking version of [`crate::files::Files::post`].
+    pub fn post(&self) -> ClientResult<crate::Response<crate::types::File>> {
+        self.runtime.block_on(self.inner.post())
+    }
+    /// The blocking version of [`crate::files::Files::get`].
+    pub fn get(&self, file: &str) -> ClientResult<crate::Response<crate::types::File>> {
+        self.runtime.block_on(self.inner.get(file))
+    }
+}
@@ -0,0 +1,227 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Identity::get_verification_reports`], set with a builder.
+pub struct IdentityGetVerificationReportsRequest<'a> {
+    inner: crate::identity::IdentityGetVerificationReportsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> IdentityGetVerificationReportsRequest<'a> {
+    /// Sets the `ending_before` query parameter.
+    pub fn ending_before(mut self, ending_before: &'a str) -> Self {
+        self.inner = self.inner.ending_before(ending_before);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.inner = self.inner.starting_after(starting_after);
+        self
+    }
+
+    /// Sets the `type` query parameter.
+    pub fn type_(mut self, type_: crate::types::GelatoVerificationReportType) -> Self {
+        self.inner = self.inner.type_(type_);
+        self
+    }
+
+    /// Sets the `verification_session` query parameter.
+    pub fn verification_session(mut self, verification_session: &'a str) -> Self {
+        self.inner = self.inner.verification_session(verification_session);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(
+        self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GelatoVerificationReport>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Identity::get_verification_sessions`], set with a builder.
+pub struct IdentityGetVerificationSessionsRequest<'a> {
+    inner: crate::identity::IdentityGetVerificationSessionsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> IdentityGetVerificationSessionsRequest<'a> {
+    /// Sets the `ending_before` query parameter.
+    pub fn ending_before(mut self, ending_before: &'a str) -> Self {
+        self.inner = self.inner.ending_before(ending_before);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.inner = self.inner.starting_after(starting_after);
+        self
+    }
+
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: crate::types::GelatoVerificationSessionStatus) -> Self {
+        self.inner = self.inner.status(status);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(
+        self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GelatoVerificationSession>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::identity::Identity`].
+pub struct Identity {
+    inner: crate::identity::Identity,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Identity {
+    pub(crate) fn new(
+        inner: crate::identity::Identity,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Identity { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::identity::Identity::get_verification_reports`].
+    pub fn get_verification_reports(
+        &self,
+        _created: &str,
+        ending_before: &str,
+        limit: i64,
+        starting_after: &str,
+        type_: crate::types::GelatoVerificationReportType,
+        verification_session: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GelatoVerificationReport>>> {
+        self.runtime.block_on(self.inner.get_verification_reports(
+            _created,
+            ending_before,
+            limit,
+            starting_after,
+            type_,
+            verification_session,
+        ))
+    }
+    /// The blocking version of [`crate::identity::Identity::get_verification_reports_request`].
+    pub fn get_verification_reports_request(&self) -> IdentityGetVerificationReportsRequest<'_> {
+        IdentityGetVerificationReportsRequest {
+            inner: self.inner.get_verification_reports_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::identity::Identity::get_all_verification_reports`].
+    pub fn get_all_verification_reports(
+        &self,
+        _created: &str,
+        type_: crate::types::GelatoVerificationReportType,
+        verification_session: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GelatoVerificationReport>>> {
+        self.runtime
+            .block_on(self.inner.get_all_verification_reports(
+                _created,
+                type_,
+                verification_session,
+            ))
+    }
+    /// The blocking version of [`crate::identity::Identity::get_verification_reports_report`].
+    pub fn get_verification_reports_report(
+        &self,
+        report: &str,
+    ) -> ClientResult<crate::Response<crate::types::GelatoVerificationReport>> {
+        self.runtime
+            .block_on(self.inner.get_verification_reports_report(report))
+    }
+    /// The blocking version of [`crate::identity::Identity::get_verification_sessions`].
+    pub fn get_verification_sessions(
+        &self,
+        _created: &str,
+        ending_before: &str,
+        limit: i64,
+        starting_after: &str,
+        status: crate::types::GelatoVerificationSessionStatus,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GelatoVerificationSession>>> {
+        self.runtime.block_on(self.inner.get_verification_sessions(
+            _created,
+            ending_before,
+            limit,
+            starting_after,
+            status,
+        ))
+    }
+    /// The blocking version of [`crate::identity::Identity::get_verification_sessions_request`].
+    pub fn get_verification_sessions_request(&self) -> IdentityGetVerificationSessionsRequest<'_> {
+        IdentityGetVerificationSessionsRequest {
+            inner: self.inner.get_verification_sessions_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::identity::Identity::get_all_verification_sessions`].
+    pub fn get_all_verification_sessions(
+        &self,
+        _created: &str,
+        status: crate::types::GelatoVerificationSessionStatus,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GelatoVerificationSession>>> {
+        self.runtime
+            .block_on(self.inner.get_all_verification_sessions(_created, status))
+    }
+    /// The blocking version of [`crate::identity::Identity::post_verification_session`].
+    pub fn post_verification_session(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::GelatoVerificationSession>> {
+        self.runtime
+            .block_on(self.inner.post_verification_session())
+    }
+    /// The blocking version of [`crate::identity::Identity::get_verification_sessions_session`].
+    pub fn get_verification_sessions_session(
+        &self,
+        session: &str,
+    ) -> ClientResult<crate::Response<crate::types::GelatoVerificationSession>> {
+        self.runtime
+            .block_on(self.inner.get_verification_sessions_session(session))
+    }
+    /// The blocking version of [`crate::identity::Identity::post_verification_sessions_session`].
+    pub fn post_verification_sessions_session(
+        &self,
+        session: &str,
+    ) -> ClientResult<crate::Response<crate::types::GelatoVerificationSession>> {
+        self.runtime
+            .block_on(self.inner.post_verification_sessions_session(session))
+    }
+    /// The blocking version of [`crate::identity::Identity::post_verification_sessions_session_cancel`].
+    pub fn post_verification_sessions_session_cancel(
+        &self,
+        session: &str,
+    ) -> ClientResult<crate::Response<crate::types::GelatoVerificationSession>> {
+        self.runtime.block_on(
+            self.inner
+                .post_verification_sessions_session_cancel(session),
+        )
+    }
+    /// The blocking version of [`crate::identity::Identity::post_verification_sessions_session_redact`].
+    pub fn post_verification_sessions_session_redact(
+        &self,
+        session: &str,
+    ) -> ClientResult<crate::Response<crate::types::GelatoVerificationSession>> {
+        self.runtime.block_on(
+            self.inner
+                .post_verification_sessions_session_redact(session),
+        )
+    }
+}
@@ -0,0 +1,133 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Invoiceitems::get_page`], set with a builder.
+pub struct InvoiceitemsGetPageRequest<'a> {
+    inner: crate::invoiceitems::InvoiceitemsGetPageRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> InvoiceitemsGetPageRequest<'a> {
+    /// Sets the `customer` query parameter.
+    pub fn customer(mut self, customer: &'a str) -> Self {
+        self.inner = self.inner.customer(customer);
+        self
+    }
+
+    /// Sets the `ending_before` query parameter.
+    pub fn ending_before(mut self, ending_before: &'a str) -> Self {
+        self.inner = self.inner.ending_before(ending_before);
+        self
+    }
+
+    /// Sets the `invoice` query parameter.
+    pub fn invoice(mut self, invoice: &'a str) -> Self {
+        self.inner = self.inner.invoice(invoice);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `pending` query parameter.
+    pub fn pending(mut self, pending: bool) -> Self {
+        self.inner = self.inner.pending(pending);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.inner = self.inner.starting_after(starting_after);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::InvoiceItem>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::invoiceitems::Invoiceitems`].
+pub struct Invoiceitems {
+    inner: crate::invoiceitems::Invoiceitems,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Invoiceitems {
+    pub(crate) fn new(
+        inner: crate::invoiceitems::Invoiceitems,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Invoiceitems { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::invoiceitems::Invoiceitems::get_page`].
+    pub fn get_page(
+        &self,
+        _created: &str,
+        customer: &str,
+        ending_before: &str,
+        invoice: &str,
+        limit: i64,
+        pending: bool,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::InvoiceItem>>> {
+        self.runtime.block_on(self.inner.get_page(
+            _created,
+            customer,
+            ending_before,
+            invoice,
+            limit,
+            pending,
+            starting_after,
+        ))
+    }
+    /// The blocking version of [`crate::invoiceitems::Invoiceitems::get_page_request`].
+    pub fn get_page_request(&self) -> InvoiceitemsGetPageRequest<'_> {
+        InvoiceitemsGetPageRequest {
+            inner: self.inner.get_page_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::invoiceitems::Invoiceitems::get_all`].
+    pub fn get_all(
+        &self,
+        _created: &str,
+        customer: &str,
+        invoice: &str,
+        pending: bool,
+    ) -> ClientResult<crate::Response<Vec<crate::types::InvoiceItem>>> {
+        self.runtime
+            .block_on(self.inner.get_all(_created, customer, invoice, pending))
+    }
+    /// The blocking version of [`crate::invoiceitems::Invoiceitems::post`].
+    pub fn post(&self) -> ClientResult<crate::Response<crate::types::InvoiceItem>> {
+        self.runtime.block_on(self.inner.post())
+    }
+    /// The blocking version of [`crate::invoiceitems::Invoiceitems::get`].
+    pub fn get(
+        &self,
+        invoiceitem: &str,
+    ) -> ClientResult<crate::Response<crate::types::InvoiceItem>> {
+        self.runtime.block_on(self.inner.get(invoiceitem))
+    }
+    /// The blocking version of [`crate::invoiceitems::Invoiceitems::post_invoiceitems`].
+    pub fn post_invoiceitems(
+        &self,
+        invoiceitem: &str,
+    ) -> ClientResult<crate::Response<crate::types::InvoiceItem>> {
+        self.runtime
+            .block_on(self.inner.post_invoiceitems(invoiceitem))
+    }
+    /// The blocking version of [`crate::invoiceitems::Invoiceitems::delete`].
+    pub fn delete(
+        &self,
+        invoiceitem: &str,
+    ) -> ClientResult<crate::Response<crate::types::DeletedInvoiceItem>> {
+        self.runtime.block_on(self.inner.delete(invoiceitem))
+    }
+}
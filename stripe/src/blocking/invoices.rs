@@ -0,0 +1,628 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Invoices::get_page`], set with a builder.
+pub struct InvoicesGetPageRequest<'a> {
+    inner: crate::invoices::InvoicesGetPageRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> InvoicesGetPageRequest<'a> {
+    /// Sets the `collection_method` query parameter.
+    pub fn collection_method(mut self, collection_method: crate::types::CollectionMethod) -> Self {
+        self.inner = self.inner.collection_method(collection_method);
+        self
+    }
+
+    /// Sets the `customer` query parameter.
+    pub fn customer(mut self, customer: &'a str) -> Self {
+        self.inner = self.inner.customer(customer);
+        self
+    }
+
+    /// Sets the `ending_before` query parameter.
+    pub fn ending_before(mut self, ending_before: &'a str) -> Self {
+        self.inner = self.inner.ending_before(ending_before);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.inner = self.inner.starting_after(starting_after);
+        self
+    }
+
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: crate::types::GetInvoicesStatus) -> Self {
+        self.inner = self.inner.status(status);
+        self
+    }
+
+    /// Sets the `subscription` query parameter.
+    pub fn subscription(mut self, subscription: &'a str) -> Self {
+        self.inner = self.inner.subscription(subscription);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Invoice>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Invoices::get_search`], set with a builder.
+pub struct InvoicesGetSearchRequest<'a> {
+    inner: crate::invoices::InvoicesGetSearchRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> InvoicesGetSearchRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: &'a str) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Charge>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Invoices::get_upcoming`], set with a builder.
+pub struct InvoicesGetUpcomingRequest<'a> {
+    inner: crate::invoices::InvoicesGetUpcomingRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> InvoicesGetUpcomingRequest<'a> {
+    /// Sets the `coupon` query parameter.
+    pub fn coupon(mut self, coupon: &'a str) -> Self {
+        self.inner = self.inner.coupon(coupon);
+        self
+    }
+
+    /// Sets the `customer` query parameter.
+    pub fn customer(mut self, customer: &'a str) -> Self {
+        self.inner = self.inner.customer(customer);
+        self
+    }
+
+    /// Sets the `schedule` query parameter.
+    pub fn schedule(mut self, schedule: &'a str) -> Self {
+        self.inner = self.inner.schedule(schedule);
+        self
+    }
+
+    /// Sets the `subscription` query parameter.
+    pub fn subscription(mut self, subscription: &'a str) -> Self {
+        self.inner = self.inner.subscription(subscription);
+        self
+    }
+
+    /// Sets the `subscription_cancel_at_period_end` query parameter.
+    pub fn subscription_cancel_at_period_end(
+        mut self,
+        subscription_cancel_at_period_end: bool,
+    ) -> Self {
+        self.inner = self
+            .inner
+            .subscription_cancel_at_period_end(subscription_cancel_at_period_end);
+        self
+    }
+
+    /// Sets the `subscription_cancel_now` query parameter.
+    pub fn subscription_cancel_now(mut self, subscription_cancel_now: bool) -> Self {
+        self.inner = self.inner.subscription_cancel_now(subscription_cancel_now);
+        self
+    }
+
+    /// Sets the `subscription_proration_behavior` query parameter.
+    pub fn subscription_proration_behavior(
+        mut self,
+        subscription_proration_behavior: crate::types::ProrationBehavior,
+    ) -> Self {
+        self.inner = self
+            .inner
+            .subscription_proration_behavior(subscription_proration_behavior);
+        self
+    }
+
+    /// Sets the `subscription_proration_date` query parameter.
+    pub fn subscription_proration_date(mut self, subscription_proration_date: i64) -> Self {
+        self.inner = self
+            .inner
+            .subscription_proration_date(subscription_proration_date);
+        self
+    }
+
+    /// Sets the `subscription_start_date` query parameter.
+    pub fn subscription_start_date(mut self, subscription_start_date: i64) -> Self {
+        self.inner = self.inner.subscription_start_date(subscription_start_date);
+        self
+    }
+
+    /// Sets the `subscription_trial_from_plan` query parameter.
+    pub fn subscription_trial_from_plan(mut self, subscription_trial_from_plan: bool) -> Self {
+        self.inner = self
+            .inner
+            .subscription_trial_from_plan(subscription_trial_from_plan);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::Invoice>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Invoices::get_upcoming_lines`], set with a builder.
+pub struct InvoicesGetUpcomingLinesRequest<'a> {
+    inner: crate::invoices::InvoicesGetUpcomingLinesRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> InvoicesGetUpcomingLinesRequest<'a> {
+    /// Sets the `coupon` query parameter.
+    pub fn coupon(mut self, coupon: &'a str) -> Self {
+        self.inner = self.inner.coupon(coupon);
+        self
+    }
+
+    /// Sets the `customer` query parameter.
+    pub fn customer(mut self, customer: &'a str) -> Self {
+        self.inner = self.inner.customer(customer);
+        self
+    }
+
+    /// Sets the `ending_before` query parameter.
+    pub fn ending_before(mut self, ending_before: &'a str) -> Self {
+        self.inner = self.inner.ending_before(ending_before);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `schedule` query parameter.
+    pub fn schedule(mut self, schedule: &'a str) -> Self {
+        self.inner = self.inner.schedule(schedule);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.inner = self.inner.starting_after(starting_after);
+        self
+    }
+
+    /// Sets the `subscription` query parameter.
+    pub fn subscription(mut self, subscription: &'a str) -> Self {
+        self.inner = self.inner.subscription(subscription);
+        self
+    }
+
+    /// Sets the `subscription_cancel_at_period_end` query parameter.
+    pub fn subscription_cancel_at_period_end(
+        mut self,
+        subscription_cancel_at_period_end: bool,
+    ) -> Self {
+        self.inner = self
+            .inner
+            .subscription_cancel_at_period_end(subscription_cancel_at_period_end);
+        self
+    }
+
+    /// Sets the `subscription_cancel_now` query parameter.
+    pub fn subscription_cancel_now(mut self, subscription_cancel_now: bool) -> Self {
+        self.inner = self.inner.subscription_cancel_now(subscription_cancel_now);
+        self
+    }
+
+    /// Sets the `subscription_proration_behavior` query parameter.
+    pub fn subscription_proration_behavior(
+        mut self,
+        subscription_proration_behavior: crate::types::ProrationBehavior,
+    ) -> Self {
+        self.inner = self
+            .inner
+            .subscription_proration_behavior(subscription_proration_behavior);
+        self
+    }
+
+    /// Sets the `subscription_proration_date` query parameter.
+    pub fn subscription_proration_date(mut self, subscription_proration_date: i64) -> Self {
+        self.inner = self
+            .inner
+            .subscription_proration_date(subscription_proration_date);
+        self
+    }
+
+    /// Sets the `subscription_start_date` query parameter.
+    pub fn subscription_start_date(mut self, subscription_start_date: i64) -> Self {
+        self.inner = self.inner.subscription_start_date(subscription_start_date);
+        self
+    }
+
+    /// Sets the `subscription_trial_from_plan` query parameter.
+    pub fn subscription_trial_from_plan(mut self, subscription_trial_from_plan: bool) -> Self {
+        self.inner = self
+            .inner
+            .subscription_trial_from_plan(subscription_trial_from_plan);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::LineItem>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Invoices::get_lines`], set with a builder.
+pub struct InvoicesGetLinesRequest<'a> {
+    inner: crate::invoices::InvoicesGetLinesRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> InvoicesGetLinesRequest<'a> {
+    /// Sets the `ending_before` query parameter.
+    pub fn ending_before(mut self, ending_before: &'a str) -> Self {
+        self.inner = self.inner.ending_before(ending_before);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.inner = self.inner.starting_after(starting_after);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::LineItem>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::invoices::Invoices`].
+pub struct Invoices {
+    inner: crate::invoices::Invoices,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Invoices {
+    pub(crate) fn new(
+        inner: crate::invoices::Invoices,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Invoices { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::invoices::Invoices::get_page`].
+    pub fn get_page(
+        &self,
+        collection_method: crate::types::CollectionMethod,
+        _created: &str,
+        customer: &str,
+        _due_date: &str,
+        ending_before: &str,
+        limit: i64,
+        starting_after: &str,
+        status: crate::types::GetInvoicesStatus,
+        subscription: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Invoice>>> {
+        self.runtime.block_on(self.inner.get_page(
+            collection_method,
+            _created,
+            customer,
+            _due_date,
+            ending_before,
+            limit,
+            starting_after,
+            status,
+            subscription,
+        ))
+    }
+    /// The blocking version of [`crate::invoices::Invoices::get_page_request`].
+    pub fn get_page_request(&self) -> InvoicesGetPageRequest<'_> {
+        InvoicesGetPageRequest {
+            inner: self.inner.get_page_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::invoices::Invoices::get_all`].
+    pub fn get_all(
+        &self,
+        collection_method: crate::types::CollectionMethod,
+        _created: &str,
+        customer: &str,
+        _due_date: &str,
+        status: crate::types::GetInvoicesStatus,
+        subscription: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Invoice>>> {
+        self.runtime.block_on(self.inner.get_all(
+            collection_method,
+            _created,
+            customer,
+            _due_date,
+            status,
+            subscription,
+        ))
+    }
+    /// The blocking version of [`crate::invoices::Invoices::post`].
+    pub fn post(&self) -> ClientResult<crate::Response<crate::types::Invoice>> {
+        self.runtime.block_on(self.inner.post())
+    }
+    /// The blocking version of [`crate::invoices::Invoices::get_search`].
+    pub fn get_search(
+        &self,
+        limit: i64,
+        page: &str,
+        query: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Charge>>> {
+        self.runtime
+            .block_on(self.inner.get_search(limit, page, query))
+    }
+    /// The blocking version of [`crate::invoices::Invoices::get_search_request`].
+    pub fn get_search_request<'a>(&'a self, query: &'a str) -> InvoicesGetSearchRequest<'a> {
+        InvoicesGetSearchRequest {
+            inner: self.inner.get_search_request(query),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::invoices::Invoices::get_all_search`].
+    pub fn get_all_search(
+        &self,
+        query: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Charge>>> {
+        self.runtime.block_on(self.inner.get_all_search(query))
+    }
+    /// The blocking version of [`crate::invoices::Invoices::get_upcoming`].
+    pub fn get_upcoming(
+        &self,
+        _automatic_tax: &str,
+        coupon: &str,
+        customer: &str,
+        _customer_details: &str,
+        _discounts: &str,
+        _invoice_items: &[String],
+        schedule: &str,
+        subscription: &str,
+        _subscription_billing_cycle_anchor: &str,
+        _subscription_cancel_at: &str,
+        subscription_cancel_at_period_end: bool,
+        subscription_cancel_now: bool,
+        _subscription_default_tax_rates: &str,
+        _subscription_items: &[String],
+        subscription_proration_behavior: crate::types::ProrationBehavior,
+        subscription_proration_date: i64,
+        subscription_start_date: i64,
+        _subscription_trial_end: &str,
+        subscription_trial_from_plan: bool,
+    ) -> ClientResult<crate::Response<crate::types::Invoice>> {
+        self.runtime.block_on(self.inner.get_upcoming(
+            _automatic_tax,
+            coupon,
+            customer,
+            _customer_details,
+            _discounts,
+            _invoice_items,
+            schedule,
+            subscription,
+            _subscription_billing_cycle_anchor,
+            _subscription_cancel_at,
+            subscription_cancel_at_period_end,
+            subscription_cancel_now,
+            _subscription_default_tax_rates,
+            _subscription_items,
+            subscription_proration_behavior,
+            subscription_proration_date,
+            subscription_start_date,
+            _subscription_trial_end,
+            subscription_trial_from_plan,
+        ))
+    }
+    /// The blocking version of [`crate::invoices::Invoices::get_upcoming_request`].
+    pub fn get_upcoming_request(&self) -> InvoicesGetUpcomingRequest<'_> {
+        InvoicesGetUpcomingRequest {
+            inner: self.inner.get_upcoming_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::invoices::Invoices::get_upcoming_lines`].
+    pub fn get_upcoming_lines(
+        &self,
+        _automatic_tax: &str,
+        coupon: &str,
+        customer: &str,
+        _customer_details: &str,
+        _discounts: &str,
+        ending_before: &str,
+        _invoice_items: &[String],
+        limit: i64,
+        schedule: &str,
+        starting_after: &str,
+        subscription: &str,
+        _subscription_billing_cycle_anchor: &str,
+        _subscription_cancel_at: &str,
+        subscription_cancel_at_period_end: bool,
+        subscription_cancel_now: bool,
+        _subscription_default_tax_rates: &str,
+        _subscription_items: &[String],
+        subscription_proration_behavior: crate::types::ProrationBehavior,
+        subscription_proration_date: i64,
+        subscription_start_date: i64,
+        _subscription_trial_end: &str,
+        subscription_trial_from_plan: bool,
+    ) -> ClientResult<crate::Response<Vec<crate::types::LineItem>>> {
+        self.runtime.block_on(self.inner.get_upcoming_lines(
+            _automatic_tax,
+            coupon,
+            customer,
+            _customer_details,
+            _discounts,
+            ending_before,
+            _invoice_items,
+            limit,
+            schedule,
+            starting_after,
+            subscription,
+            _subscription_billing_cycle_anchor,
+            _subscription_cancel_at,
+            subscription_cancel_at_period_end,
+            subscription_cancel_now,
+            _subscription_default_tax_rates,
+            _subscription_items,
+            subscription_proration_behavior,
+            subscription_proration_date,
+            subscription_start_date,
+            _subscription_trial_end,
+            subscription_trial_from_plan,
+        ))
+    }
+    /// The blocking version of [`crate::invoices::Invoices::get_upcoming_lines_request`].
+    pub fn get_upcoming_lines_request(&self) -> InvoicesGetUpcomingLinesRequest<'_> {
+        InvoicesGetUpcomingLinesRequest {
+            inner: self.inner.get_upcoming_lines_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::invoices::Invoices::get_all_upcoming_lines`].
+    pub fn get_all_upcoming_lines(
+        &self,
+        _automatic_tax: &str,
+        coupon: &str,
+        customer: &str,
+        _customer_details: &str,
+        _discounts: &str,
+        _invoice_items: &[String],
+        schedule: &str,
+        subscription: &str,
+        _subscription_billing_cycle_anchor: &str,
+        _subscription_cancel_at: &str,
+        subscription_cancel_at_period_end: bool,
+        subscription_cancel_now: bool,
+        _subscription_default_tax_rates: &str,
+        _subscription_items: &[String],
+        subscription_proration_behavior: crate::types::ProrationBehavior,
+        subscription_proration_date: i64,
+        subscription_start_date: i64,
+        _subscription_trial_end: &str,
+        subscription_trial_from_plan: bool,
+    ) -> ClientResult<crate::Response<Vec<crate::types::LineItem>>> {
+        self.runtime.block_on(self.inner.get_all_upcoming_lines(
+            _automatic_tax,
+            coupon,
+            customer,
+            _customer_details,
+            _discounts,
+            _invoice_items,
+            schedule,
+            subscription,
+            _subscription_billing_cycle_anchor,
+            _subscription_cancel_at,
+            subscription_cancel_at_period_end,
+            subscription_cancel_now,
+            _subscription_default_tax_rates,
+            _subscription_items,
+            subscription_proration_behavior,
+            subscription_proration_date,
+            subscription_start_date,
+            _subscription_trial_end,
+            subscription_trial_from_plan,
+        ))
+    }
+    /// The blocking version of [`crate::invoices::Invoices::get`].
+    pub fn get(&self, invoice: &str) -> ClientResult<crate::Response<crate::types::Invoice>> {
+        self.runtime.block_on(self.inner.get(invoice))
+    }
+    /// The blocking version of [`crate::invoices::Invoices::post_invoices`].
+    pub fn post_invoices(
+        &self,
+        invoice: &str,
+    ) -> ClientResult<crate::Response<crate::types::Invoice>> {
+        self.runtime.block_on(self.inner.post_invoices(invoice))
+    }
+    /// The blocking version of [`crate::invoices::Invoices::delete`].
+    pub fn delete(
+        &self,
+        invoice: &str,
+    ) -> ClientResult<crate::Response<crate::types::DeletedInvoice>> {
+        self.runtime.block_on(self.inner.delete(invoice))
+    }
+    /// The blocking version of [`crate::invoices::Invoices::post_finalize`].
+    pub fn post_finalize(
+        &self,
+        invoice: &str,
+    ) -> ClientResult<crate::Response<crate::types::Invoice>> {
+        self.runtime.block_on(self.inner.post_finalize(invoice))
+    }
+    /// The blocking version of [`crate::invoices::Invoices::get_lines`].
+    pub fn get_lines(
+        &self,
+        ending_before: &str,
+        invoice: &str,
+        limit: i64,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::LineItem>>> {
+        self.runtime.block_on(
+            self.inner
+                .get_lines(ending_before, invoice, limit, starting_after),
+        )
+    }
+    /// The blocking version of [`crate::invoices::Invoices::get_lines_request`].
+    pub fn get_lines_request<'a>(&'a self, invoice: &'a str) -> InvoicesGetLinesRequest<'a> {
+        InvoicesGetLinesRequest {
+            inner: self.inner.get_lines_request(invoice),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::invoices::Invoices::get_all_lines`].
+    pub fn get_all_lines(
+        &self,
+        invoice: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::LineItem>>> {
+        self.runtime.block_on(self.inner.get_all_lines(invoice))
+    }
+    /// The blocking version of [`crate::invoices::Invoices::post_mark_uncollectible`].
+    pub fn post_mark_uncollectible(
+        &self,
+        invoice: &str,
+    ) -> ClientResult<crate::Response<crate::types::Invoice>> {
+        self.runtime
+            .block_on(self.inner.post_mark_uncollectible(invoice))
+    }
+    /// The blocking version of [`crate::invoices::Invoices::post_pay`].
+    pub fn post_pay(&self, invoice: &str) -> ClientResult<crate::Response<crate::types::Invoice>> {
+        self.runtime.block_on(self.inner.post_pay(invoice))
+    }
+    /// The blocking version of [`crate::invoices::Invoices::post_send`].
+    pub fn post_send(&self, invoice: &str) -> ClientResult<crate::Response<crate::types::Invoice>> {
+        self.runtime.block_on(self.inner.post_send(invoice))
+    }
+    /// The blocking version of [`crate::invoices::Invoices::post_void`].
+    pub fn post_void(&self, invoice: &str) -> ClientResult<crate::Response<crate::types::Invoice>> {
+        self.runtime.block_on(self.inner.post_void(invoice))
+    }
+}
@@ -0,0 +1,91 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`IssuerFraudRecords::get_page`], set with a builder.
+pub struct IssuerFraudRecordsGetPageRequest<'a> {
+    inner: crate::issuer_fraud_records::IssuerFraudRecordsGetPageRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> IssuerFraudRecordsGetPageRequest<'a> {
+    /// Sets the `charge` query parameter.
+    pub fn charge(mut self, charge: &'a str) -> Self {
+        self.inner = self.inner.charge(charge);
+        self
+    }
+
+    /// Sets the `ending_before` query parameter.
+    pub fn ending_before(mut self, ending_before: &'a str) -> Self {
+        self.inner = self.inner.ending_before(ending_before);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.inner = self.inner.starting_after(starting_after);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::IssuerFraudRecord>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::issuer_fraud_records::IssuerFraudRecords`].
+pub struct IssuerFraudRecords {
+    inner: crate::issuer_fraud_records::IssuerFraudRecords,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl IssuerFraudRecords {
+    pub(crate) fn new(
+        inner: crate::issuer_fraud_records::IssuerFraudRecords,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        IssuerFraudRecords { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::issuer_fraud_records::IssuerFraudRecords::get_page`].
+    pub fn get_page(
+        &self,
+        charge: &str,
+        ending_before: &str,
+        limit: i64,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::IssuerFraudRecord>>> {
+        self.runtime.block_on(
+            self.inner
+                .get_page(charge, ending_before, limit, starting_after),
+        )
+    }
+    /// The blocking version of [`crate::issuer_fraud_records::IssuerFraudRecords::get_page_request`].
+    pub fn get_page_request(&self) -> IssuerFraudRecordsGetPageRequest<'_> {
+        IssuerFraudRecordsGetPageRequest {
+            inner: self.inner.get_page_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::issuer_fraud_records::IssuerFraudRecords::get_all`].
+    pub fn get_all(
+        &self,
+        charge: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::IssuerFraudRecord>>> {
+        self.runtime.block_on(self.inner.get_all(charge))
+    }
+    /// The blocking version of [`crate::issuer_fraud_records::IssuerFraudRecords::get_record`].
+    pub fn get_record(
+        &self,
+        issuer_fraud_record: &str,
+    ) -> ClientResult<crate::Response<crate::types::IssuerFraudRecord>> {
+        self.runtime
+            .block_on(self.inner.get_record(issuer_fraud_record))
+    }
+}
@@ -0,0 +1,693 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Issuing::get_authorizations`], set with a builder.
+pub struct IssuingGetAuthorizationsRequest<'a> {
+    inner: crate::issuing::IssuingGetAuthorizationsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> IssuingGetAuthorizationsRequest<'a> {
+    /// Sets the `card` query parameter.
+    pub fn card(mut self, card: &'a str) -> Self {
+        self.inner = self.inner.card(card);
+        self
+    }
+
+    /// Sets the `cardholder` query parameter.
+    pub fn cardholder(mut self, cardholder: &'a str) -> Self {
+        self.inner = self.inner.cardholder(cardholder);
+        self
+    }
+
+    /// Sets the `ending_before` query parameter.
+    pub fn ending_before(mut self, ending_before: &'a str) -> Self {
+        self.inner = self.inner.ending_before(ending_before);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.inner = self.inner.starting_after(starting_after);
+        self
+    }
+
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: crate::types::IssuingAuthorizationStatus) -> Self {
+        self.inner = self.inner.status(status);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::IssuingAuthorization>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Issuing::get_cardholders`], set with a builder.
+pub struct IssuingGetCardholdersRequest<'a> {
+    inner: crate::issuing::IssuingGetCardholdersRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> IssuingGetCardholdersRequest<'a> {
+    /// Sets the `email` query parameter.
+    pub fn email(mut self, email: &'a str) -> Self {
+        self.inner = self.inner.email(email);
+        self
+    }
+
+    /// Sets the `ending_before` query parameter.
+    pub fn ending_before(mut self, ending_before: &'a str) -> Self {
+        self.inner = self.inner.ending_before(ending_before);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `phone_number` query parameter.
+    pub fn phone_number(mut self, phone_number: &'a str) -> Self {
+        self.inner = self.inner.phone_number(phone_number);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.inner = self.inner.starting_after(starting_after);
+        self
+    }
+
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: crate::types::IssuingCardholderStatus) -> Self {
+        self.inner = self.inner.status(status);
+        self
+    }
+
+    /// Sets the `type` query parameter.
+    pub fn type_(mut self, type_: crate::types::AccountHolderType) -> Self {
+        self.inner = self.inner.type_(type_);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::IssuingCardholder>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Issuing::get_cards`], set with a builder.
+pub struct IssuingGetCardsRequest<'a> {
+    inner: crate::issuing::IssuingGetCardsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> IssuingGetCardsRequest<'a> {
+    /// Sets the `cardholder` query parameter.
+    pub fn cardholder(mut self, cardholder: &'a str) -> Self {
+        self.inner = self.inner.cardholder(cardholder);
+        self
+    }
+
+    /// Sets the `ending_before` query parameter.
+    pub fn ending_before(mut self, ending_before: &'a str) -> Self {
+        self.inner = self.inner.ending_before(ending_before);
+        self
+    }
+
+    /// Sets the `exp_month` query parameter.
+    pub fn exp_month(mut self, exp_month: i64) -> Self {
+        self.inner = self.inner.exp_month(exp_month);
+        self
+    }
+
+    /// Sets the `exp_year` query parameter.
+    pub fn exp_year(mut self, exp_year: i64) -> Self {
+        self.inner = self.inner.exp_year(exp_year);
+        self
+    }
+
+    /// Sets the `last4` query parameter.
+    pub fn last_4(mut self, last_4: &'a str) -> Self {
+        self.inner = self.inner.last_4(last_4);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.inner = self.inner.starting_after(starting_after);
+        self
+    }
+
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: crate::types::IssuingCardStatus) -> Self {
+        self.inner = self.inner.status(status);
+        self
+    }
+
+    /// Sets the `type` query parameter.
+    pub fn type_(mut self, type_: crate::types::IssuingCardType) -> Self {
+        self.inner = self.inner.type_(type_);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::IssuingCard>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Issuing::get_disputes`], set with a builder.
+pub struct IssuingGetDisputesRequest<'a> {
+    inner: crate::issuing::IssuingGetDisputesRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> IssuingGetDisputesRequest<'a> {
+    /// Sets the `ending_before` query parameter.
+    pub fn ending_before(mut self, ending_before: &'a str) -> Self {
+        self.inner = self.inner.ending_before(ending_before);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.inner = self.inner.starting_after(starting_after);
+        self
+    }
+
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: crate::types::IssuingDisputeStatus) -> Self {
+        self.inner = self.inner.status(status);
+        self
+    }
+
+    /// Sets the `transaction` query parameter.
+    pub fn transaction(mut self, transaction: &'a str) -> Self {
+        self.inner = self.inner.transaction(transaction);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::IssuingDispute>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Issuing::get_settlements`], set with a builder.
+pub struct IssuingGetSettlementsRequest<'a> {
+    inner: crate::issuing::IssuingGetSettlementsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> IssuingGetSettlementsRequest<'a> {
+    /// Sets the `ending_before` query parameter.
+    pub fn ending_before(mut self, ending_before: &'a str) -> Self {
+        self.inner = self.inner.ending_before(ending_before);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.inner = self.inner.starting_after(starting_after);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::IssuingSettlement>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Issuing::get_transactions`], set with a builder.
+pub struct IssuingGetTransactionsRequest<'a> {
+    inner: crate::issuing::IssuingGetTransactionsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> IssuingGetTransactionsRequest<'a> {
+    /// Sets the `card` query parameter.
+    pub fn card(mut self, card: &'a str) -> Self {
+        self.inner = self.inner.card(card);
+        self
+    }
+
+    /// Sets the `cardholder` query parameter.
+    pub fn cardholder(mut self, cardholder: &'a str) -> Self {
+        self.inner = self.inner.cardholder(cardholder);
+        self
+    }
+
+    /// Sets the `ending_before` query parameter.
+    pub fn ending_before(mut self, ending_before: &'a str) -> Self {
+        self.inner = self.inner.ending_before(ending_before);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.inner = self.inner.starting_after(starting_after);
+        self
+    }
+
+    /// Sets the `type` query parameter.
+    pub fn type_(mut self, type_: crate::types::IssuingTransactionType) -> Self {
+        self.inner = self.inner.type_(type_);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::IssuingTransaction>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::issuing::Issuing`].
+pub struct Issuing {
+    inner: crate::issuing::Issuing,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Issuing {
+    pub(crate) fn new(
+        inner: crate::issuing::Issuing,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Issuing { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::issuing::Issuing::get_authorizations`].
+    pub fn get_authorizations(
+        &self,
+        card: &str,
+        cardholder: &str,
+        _created: &str,
+        ending_before: &str,
+        limit: i64,
+        starting_after: &str,
+        status: crate::types::IssuingAuthorizationStatus,
+    ) -> ClientResult<crate::Response<Vec<crate::types::IssuingAuthorization>>> {
+        self.runtime.block_on(self.inner.get_authorizations(
+            card,
+            cardholder,
+            _created,
+            ending_before,
+            limit,
+            starting_after,
+            status,
+        ))
+    }
+    /// The blocking version of [`crate::issuing::Issuing::get_authorizations_request`].
+    pub fn get_authorizations_request(&self) -> IssuingGetAuthorizationsRequest<'_> {
+        IssuingGetAuthorizationsRequest {
+            inner: self.inner.get_authorizations_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::issuing::Issuing::get_all_authorizations`].
+    pub fn get_all_authorizations(
+        &self,
+        card: &str,
+        cardholder: &str,
+        _created: &str,
+        status: crate::types::IssuingAuthorizationStatus,
+    ) -> ClientResult<crate::Response<Vec<crate::types::IssuingAuthorization>>> {
+        self.runtime.block_on(
+            self.inner
+                .get_all_authorizations(card, cardholder, _created, status),
+        )
+    }
+    /// The blocking version of [`crate::issuing::Issuing::get_authorizations_authorization`].
+    pub fn get_authorizations_authorization(
+        &self,
+        authorization: &str,
+    ) -> ClientResult<crate::Response<crate::types::IssuingAuthorization>> {
+        self.runtime
+            .block_on(self.inner.get_authorizations_authorization(authorization))
+    }
+    /// The blocking version of [`crate::issuing::Issuing::post_authorizations_authorization`].
+    pub fn post_authorizations_authorization(
+        &self,
+        authorization: &str,
+    ) -> ClientResult<crate::Response<crate::types::IssuingAuthorization>> {
+        self.runtime
+            .block_on(self.inner.post_authorizations_authorization(authorization))
+    }
+    /// The blocking version of [`crate::issuing::Issuing::post_authorizations_authorization_approve`].
+    pub fn post_authorizations_authorization_approve(
+        &self,
+        authorization: &str,
+    ) -> ClientResult<crate::Response<crate::types::IssuingAuthorization>> {
+        self.runtime.block_on(
+            self.inner
+                .post_authorizations_authorization_approve(authorization),
+        )
+    }
+    /// The blocking version of [`crate::issuing::Issuing::post_authorizations_authorization_decline`].
+    pub fn post_authorizations_authorization_decline(
+        &self,
+        authorization: &str,
+    ) -> ClientResult<crate::Response<crate::types::IssuingAuthorization>> {
+        self.runtime.block_on(
+            self.inner
+                .post_authorizations_authorization_decline(authorization),
+        )
+    }
+    /// The blocking version of [`crate::issuing::Issuing::get_cardholders`].
+    pub fn get_cardholders(
+        &self,
+        _created: &str,
+        email: &str,
+        ending_before: &str,
+        limit: i64,
+        phone_number: &str,
+        starting_after: &str,
+        status: crate::types::IssuingCardholderStatus,
+        type_: crate::types::AccountHolderType,
+    ) -> ClientResult<crate::Response<Vec<crate::types::IssuingCardholder>>> {
+        self.runtime.block_on(self.inner.get_cardholders(
+            _created,
+            email,
+            ending_before,
+            limit,
+            phone_number,
+            starting_after,
+            status,
+            type_,
+        ))
+    }
+    /// The blocking version of [`crate::issuing::Issuing::get_cardholders_request`].
+    pub fn get_cardholders_request(&self) -> IssuingGetCardholdersRequest<'_> {
+        IssuingGetCardholdersRequest {
+            inner: self.inner.get_cardholders_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::issuing::Issuing::get_all_cardholders`].
+    pub fn get_all_cardholders(
+        &self,
+        _created: &str,
+        email: &str,
+        phone_number: &str,
+        status: crate::types::IssuingCardholderStatus,
+        type_: crate::types::AccountHolderType,
+    ) -> ClientResult<crate::Response<Vec<crate::types::IssuingCardholder>>> {
+        self.runtime.block_on(self.inner.get_all_cardholders(
+            _created,
+            email,
+            phone_number,
+            status,
+            type_,
+        ))
+    }
+    /// The blocking version of [`crate::issuing::Issuing::post_cardholder`].
+    pub fn post_cardholder(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::IssuingCardholder>> {
+        self.runtime.block_on(self.inner.post_cardholder())
+    }
+    /// The blocking version of [`crate::issuing::Issuing::get_cardholders_cardholder`].
+    pub fn get_cardholders_cardholder(
+        &self,
+        cardholder: &str,
+    ) -> ClientResult<crate::Response<crate::types::IssuingCardholder>> {
+        self.runtime
+            .block_on(self.inner.get_cardholders_cardholder(cardholder))
+    }
+    /// The blocking version of [`crate::issuing::Issuing::post_cardholders_cardholder`].
+    pub fn post_cardholders_cardholder(
+        &self,
+        cardholder: &str,
+    ) -> ClientResult<crate::Response<crate::types::IssuingCardholder>> {
+        self.runtime
+            .block_on(self.inner.post_cardholders_cardholder(cardholder))
+    }
+    /// The blocking version of [`crate::issuing::Issuing::get_cards`].
+    pub fn get_cards(
+        &self,
+        cardholder: &str,
+        _created: &str,
+        ending_before: &str,
+        exp_month: i64,
+        exp_year: i64,
+        last_4: &str,
+        limit: i64,
+        starting_after: &str,
+        status: crate::types::IssuingCardStatus,
+        type_: crate::types::IssuingCardType,
+    ) -> ClientResult<crate::Response<Vec<crate::types::IssuingCard>>> {
+        self.runtime.block_on(self.inner.get_cards(
+            cardholder,
+            _created,
+            ending_before,
+            exp_month,
+            exp_year,
+            last_4,
+            limit,
+            starting_after,
+            status,
+            type_,
+        ))
+    }
+    /// The blocking version of [`crate::issuing::Issuing::get_cards_request`].
+    pub fn get_cards_request(&self) -> IssuingGetCardsRequest<'_> {
+        IssuingGetCardsRequest {
+            inner: self.inner.get_cards_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::issuing::Issuing::get_all_cards`].
+    pub fn get_all_cards(
+        &self,
+        cardholder: &str,
+        _created: &str,
+        exp_month: i64,
+        exp_year: i64,
+        last_4: &str,
+        status: crate::types::IssuingCardStatus,
+        type_: crate::types::IssuingCardType,
+    ) -> ClientResult<crate::Response<Vec<crate::types::IssuingCard>>> {
+        self.runtime.block_on(self.inner.get_all_cards(
+            cardholder, _created, exp_month, exp_year, last_4, status, type_,
+        ))
+    }
+    /// The blocking version of [`crate::issuing::Issuing::post_card`].
+    pub fn post_card(&self) -> ClientResult<crate::Response<crate::types::IssuingCard>> {
+        self.runtime.block_on(self.inner.post_card())
+    }
+    /// The blocking version of [`crate::issuing::Issuing::get_cards_card`].
+    pub fn get_cards_card(
+        &self,
+        card: &str,
+    ) -> ClientResult<crate::Response<crate::types::IssuingCard>> {
+        self.runtime.block_on(self.inner.get_cards_card(card))
+    }
+    /// The blocking version of [`crate::issuing::Issuing::post_cards_card`].
+    pub fn post_cards_card(
+        &self,
+        card: &str,
+    ) -> ClientResult<crate::Response<crate::types::IssuingCard>> {
+        self.runtime.block_on(self.inner.post_cards_card(card))
+    }
+    /// The blocking version of [`crate::issuing::Issuing::get_disputes`].
+    pub fn get_disputes(
+        &self,
+        _created: &str,
+        ending_before: &str,
+        limit: i64,
+        starting_after: &str,
+        status: crate::types::IssuingDisputeStatus,
+        transaction: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::IssuingDispute>>> {
+        self.runtime.block_on(self.inner.get_disputes(
+            _created,
+            ending_before,
+            limit,
+            starting_after,
+            status,
+            transaction,
+        ))
+    }
+    /// The blocking version of [`crate::issuing::Issuing::get_disputes_request`].
+    pub fn get_disputes_request(&self) -> IssuingGetDisputesRequest<'_> {
+        IssuingGetDisputesRequest {
+            inner: self.inner.get_disputes_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::issuing::Issuing::get_all_disputes`].
+    pub fn get_all_disputes(
+        &self,
+        _created: &str,
+        status: crate::types::IssuingDisputeStatus,
+        transaction: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::IssuingDispute>>> {
+        self.runtime
+            .block_on(self.inner.get_all_disputes(_created, status, transaction))
+    }
+    /// The blocking version of [`crate::issuing::Issuing::post_dispute`].
+    pub fn post_dispute(&self) -> ClientResult<crate::Response<crate::types::IssuingDispute>> {
+        self.runtime.block_on(self.inner.post_dispute())
+    }
+    /// The blocking version of [`crate::issuing::Issuing::get_disputes_dispute`].
+    pub fn get_disputes_dispute(
+        &self,
+        dispute: &str,
+    ) -> ClientResult<crate::Response<crate::types::IssuingDispute>> {
+        self.runtime
+            .block_on(self.inner.get_disputes_dispute(dispute))
+    }
+    /// The blocking version of [`crate::issuing::Issuing::post_disputes_dispute`].
+    pub fn post_disputes_dispute(
+        &self,
+        dispute: &str,
+    ) -> ClientResult<crate::Response<crate::types::IssuingDispute>> {
+        self.runtime
+            .block_on(self.inner.post_disputes_dispute(dispute))
+    }
+    /// The blocking version of [`crate::issuing::Issuing::post_disputes_dispute_submit`].
+    pub fn post_disputes_dispute_submit(
+        &self,
+        dispute: &str,
+    ) -> ClientResult<crate::Response<crate::types::IssuingDispute>> {
+        self.runtime
+            .block_on(self.inner.post_disputes_dispute_submit(dispute))
+    }
+    /// The blocking version of [`crate::issuing::Issuing::get_settlements`].
+    pub fn get_settlements(
+        &self,
+        _created: &str,
+        ending_before: &str,
+        limit: i64,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::IssuingSettlement>>> {
+        self.runtime.block_on(self.inner.get_settlements(
+            _created,
+            ending_before,
+            limit,
+            starting_after,
+        ))
+    }
+    /// The blocking version of [`crate::issuing::Issuing::get_settlements_request`].
+    pub fn get_settlements_request(&self) -> IssuingGetSettlementsRequest<'_> {
+        IssuingGetSettlementsRequest {
+            inner: self.inner.get_settlements_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::issuing::Issuing::get_all_settlements`].
+    pub fn get_all_settlements(
+        &self,
+        _created: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::IssuingSettlement>>> {
+        self.runtime
+            .block_on(self.inner.get_all_settlements(_created))
+    }
+    /// The blocking version of [`crate::issuing::Issuing::get_settlements_settlement`].
+    pub fn get_settlements_settlement(
+        &self,
+        settlement: &str,
+    ) -> ClientResult<crate::Response<crate::types::IssuingSettlement>> {
+        self.runtime
+            .block_on(self.inner.get_settlements_settlement(settlement))
+    }
+    /// The blocking version of [`crate::issuing::Issuing::post_settlements_settlement`].
+    pub fn post_settlements_settlement(
+        &self,
+        settlement: &str,
+    ) -> ClientResult<crate::Response<crate::types::IssuingSettlement>> {
+        self.runtime
+            .block_on(self.inner.post_settlements_settlement(settlement))
+    }
+    /// The blocking version of [`crate::issuing::Issuing::get_transactions`].
+    pub fn get_transactions(
+        &self,
+        card: &str,
+        cardholder: &str,
+        _created: &str,
+        ending_before: &str,
+        limit: i64,
+        starting_after: &str,
+        type_: crate::types::IssuingTransactionType,
+    ) -> ClientResult<crate::Response<Vec<crate::types::IssuingTransaction>>> {
+        self.runtime.block_on(self.inner.get_transactions(
+            card,
+            cardholder,
+            _created,
+            ending_before,
+            limit,
+            starting_after,
+            type_,
+        ))
+    }
+    /// The blocking version of [`crate::issuing::Issuing::get_transactions_request`].
+    pub fn get_transactions_request(&self) -> IssuingGetTransactionsRequest<'_> {
+        IssuingGetTransactionsRequest {
+            inner: self.inner.get_transactions_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::issuing::Issuing::get_all_transactions`].
+    pub fn get_all_transactions(
+        &self,
+        card: &str,
+        cardholder: &str,
+        _created: &str,
+        type_: crate::types::IssuingTransactionType,
+    ) -> ClientResult<crate::Response<Vec<crate::types::IssuingTransaction>>> {
+        self.runtime.block_on(
+            self.inner
+                .get_all_transactions(card, cardholder, _created, type_),
+        )
+    }
+    /// The blocking version of [`crate::issuing::Issuing::get_transactions_transaction`].
+    pub fn get_transactions_transaction(
+        &self,
+        transaction: &str,
+    ) -> ClientResult<crate::Response<crate::types::IssuingTransaction>> {
+        self.runtime
+            .block_on(self.inner.get_transactions_transaction(transaction))
+    }
+    /// The blocking version of [`crate::issuing::Issuing::post_transactions_transaction`].
+    pub fn post_transactions_transaction(
+        &self,
+        transaction: &str,
+    ) -> ClientResult<crate::Response<crate::types::IssuingTransaction>> {
+        self.runtime
+            .block_on(self.inner.post_transactions_transaction(transaction))
+    }
+}
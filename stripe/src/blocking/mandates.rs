@@ -0,0 +1,23 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::mandates::Mandates`].
+pub struct Mandates {
+    inner: crate::mandates::Mandates,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Mandates {
+    pub(crate) fn new(
+        inner: crate::mandates::Mandates,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Mandates { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::mandates::Mandates::get`].
+    pub fn get(&self, mandate: &str) -> ClientResult<crate::Response<crate::types::Mandate>> {
+        self.runtime.block_on(self.inner.get(mandate))
+    }
+}
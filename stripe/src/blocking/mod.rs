@@ -0,0 +1,421 @@
+//! A blocking client, for the programs that are not async.
+//!
+//! It has the functions of the async [`Client`](crate::Client), which it runs on a
+//! runtime of its own, so they must not be called from within an async runtime. The
+//! `*_stream` functions are left out, the `*_all` ones returning all the pages at once.
+
+use std::{future::Future, sync::Arc};
+
+pub mod account;
+pub mod account_links;
+pub mod accounts;
+pub mod apple_pay;
+pub mod application_fees;
+pub mod balance;
+pub mod balance_transactions;
+pub mod billing_portal;
+pub mod bitcoin;
+pub mod charges;
+pub mod checkout;
+pub mod country_specs;
+pub mod coupons;
+pub mod credit_notes;
+pub mod customers;
+pub mod disputes;
+pub mod ephemeral_keys;
+pub mod events;
+pub mod exchange_rates;
+pub mod file_links;
+pub mod files;
+pub mod identity;
+pub mod invoiceitems;
+pub mod invoices;
+pub mod issuer_fraud_records;
+pub mod issuing;
+pub mod mandates;
+pub mod order_returns;
+pub mod orders;
+pub mod payment_intents;
+pub mod payment_links;
+pub mod payment_methods;
+pub mod payouts;
+pub mod plans;
+pub mod prices;
+pub mod products;
+pub mod promotion_codes;
+pub mod quotes;
+pub mod radar;
+pub mod recipients;
+pub mod refunds;
+pub mod reporting;
+pub mod reviews;
+pub mod setup_attempts;
+pub mod setup_intents;
+pub mod shipping_rates;
+pub mod sigma;
+pub mod skus;
+pub mod sources;
+pub mod subscription_items;
+pub mod subscription_schedules;
+pub mod subscriptions;
+pub mod tax_codes;
+pub mod tax_rates;
+pub mod terminal;
+pub mod test_helpers;
+pub mod three_d_secure;
+pub mod tokens;
+pub mod topups;
+pub mod transfers;
+pub mod webhook_endpoints;
+
+/// A blocking client, sending its requests with an async [`Client`](crate::Client).
+#[derive(Clone)]
+pub struct Client {
+    inner: crate::Client,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Client {
+    /// Wraps an async client, creating the runtime its requests run on.
+    pub fn new(client: crate::Client) -> std::io::Result<Self> {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()?;
+
+        Ok(Client {
+            inner: client,
+            runtime: Arc::new(runtime),
+        })
+    }
+
+    /// Returns the async client the requests are sent with.
+    pub fn inner(&self) -> &crate::Client {
+        &self.inner
+    }
+
+    /// Runs a future to completion on the runtime of the client, for the async
+    /// functions that have no blocking version.
+    pub fn block_on<F: Future>(&self, future: F) -> F::Output {
+        self.runtime.block_on(future)
+    }
+
+    /// The blocking version of [`crate::Client::account`].
+    pub fn account(&self) -> account::Account {
+        account::Account::new(self.inner.account(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::account_links`].
+    pub fn account_links(&self) -> account_links::AccountLinks {
+        account_links::AccountLinks::new(self.inner.account_links(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::accounts`].
+    pub fn accounts(&self) -> accounts::Accounts {
+        accounts::Accounts::new(self.inner.accounts(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::apple_pay`].
+    pub fn apple_pay(&self) -> apple_pay::ApplePay {
+        apple_pay::ApplePay::new(self.inner.apple_pay(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::application_fees`].
+    pub fn application_fees(&self) -> application_fees::ApplicationFees {
+        application_fees::ApplicationFees::new(self.inner.application_fees(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::balance`].
+    pub fn balance(&self) -> balance::Balance {
+        balance::Balance::new(self.inner.balance(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::balance_transactions`].
+    pub fn balance_transactions(&self) -> balance_transactions::BalanceTransactions {
+        balance_transactions::BalanceTransactions::new(
+            self.inner.balance_transactions(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::billing_portal`].
+    pub fn billing_portal(&self) -> billing_portal::BillingPortal {
+        billing_portal::BillingPortal::new(self.inner.billing_portal(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::bitcoin`].
+    pub fn bitcoin(&self) -> bitcoin::Bitcoin {
+        bitcoin::Bitcoin::new(self.inner.bitcoin(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::charges`].
+    pub fn charges(&self) -> charges::Charges {
+        charges::Charges::new(self.inner.charges(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::checkout`].
+    pub fn checkout(&self) -> checkout::Checkout {
+        checkout::Checkout::new(self.inner.checkout(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::country_specs`].
+    pub fn country_specs(&self) -> country_specs::CountrySpecs {
+        country_specs::CountrySpecs::new(self.inner.country_specs(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::coupons`].
+    pub fn coupons(&self) -> coupons::Coupons {
+        coupons::Coupons::new(self.inner.coupons(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::credit_notes`].
+    pub fn credit_notes(&self) -> credit_notes::CreditNotes {
+        credit_notes::CreditNotes::new(self.inner.credit_notes(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::customers`].
+    pub fn customers(&self) -> customers::Customers {
+        customers::Customers::new(self.inner.customers(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::disputes`].
+    pub fn disputes(&self) -> disputes::Disputes {
+        disputes::Disputes::new(self.inner.disputes(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::ephemeral_keys`].
+    pub fn ephemeral_keys(&self) -> ephemeral_keys::EphemeralKeys {
+        ephemeral_keys::EphemeralKeys::new(self.inner.ephemeral_keys(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::events`].
+    pub fn events(&self) -> events::Events {
+        events::Events::new(self.inner.events(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::exchange_rates`].
+    pub fn exchange_rates(&self) -> exchange_rates::ExchangeRates {
+        exchange_rates::ExchangeRates::new(self.inner.exchange_rates(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::file_links`].
+    pub fn file_links(&self) -> file_links::FileLinks {
+        file_links::FileLinks::new(self.inner.file_links(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::files`].
+    pub fn files(&self) -> files::Files {
+        files::Files::new(self.inner.files(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::identity`].
+    pub fn identity(&self) -> identity::Identity {
+        identity::Identity::new(self.inner.identity(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::invoiceitems`].
+    pub fn invoiceitems(&self) -> invoiceitems::Invoiceitems {
+        invoiceitems::Invoiceitems::new(self.inner.invoiceitems(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::invoices`].
+    pub fn invoices(&self) -> invoices::Invoices {
+        invoices::Invoices::new(self.inner.invoices(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::issuer_fraud_records`].
+    pub fn issuer_fraud_records(&self) -> issuer_fraud_records::IssuerFraudRecords {
+        issuer_fraud_records::IssuerFraudRecords::new(
+            self.inner.issuer_fraud_records(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::issuing`].
+    pub fn issuing(&self) -> issuing::Issuing {
+        issuing::Issuing::new(self.inner.issuing(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::mandates`].
+    pub fn mandates(&self) -> mandates::Mandates {
+        mandates::Mandates::new(self.inner.mandates(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::order_returns`].
+    pub fn order_returns(&self) -> order_returns::OrderReturns {
+        order_returns::OrderReturns::new(self.inner.order_returns(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::orders`].
+    pub fn orders(&self) -> orders::Orders {
+        orders::Orders::new(self.inner.orders(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::payment_intents`].
+    pub fn payment_intents(&self) -> payment_intents::PaymentIntents {
+        payment_intents::PaymentIntents::new(self.inner.payment_intents(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::payment_links`].
+    pub fn payment_links(&self) -> payment_links::PaymentLinks {
+        payment_links::PaymentLinks::new(self.inner.payment_links(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::payment_methods`].
+    pub fn payment_methods(&self) -> payment_methods::PaymentMethods {
+        payment_methods::PaymentMethods::new(self.inner.payment_methods(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::payouts`].
+    pub fn payouts(&self) -> payouts::Payouts {
+        payouts::Payouts::new(self.inner.payouts(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::plans`].
+    pub fn plans(&self) -> plans::Plans {
+        plans::Plans::new(self.inner.plans(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::prices`].
+    pub fn prices(&self) -> prices::Prices {
+        prices::Prices::new(self.inner.prices(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::products`].
+    pub fn products(&self) -> products::Products {
+        products::Products::new(self.inner.products(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::promotion_codes`].
+    pub fn promotion_codes(&self) -> promotion_codes::PromotionCodes {
+        promotion_codes::PromotionCodes::new(self.inner.promotion_codes(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::quotes`].
+    pub fn quotes(&self) -> quotes::Quotes {
+        quotes::Quotes::new(self.inner.quotes(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::radar`].
+    pub fn radar(&self) -> radar::Radar {
+        radar::Radar::new(self.inner.radar(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::recipients`].
+    pub fn recipients(&self) -> recipients::Recipients {
+        recipients::Recipients::new(self.inner.recipients(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::refunds`].
+    pub fn refunds(&self) -> refunds::Refunds {
+        refunds::Refunds::new(self.inner.refunds(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::reporting`].
+    pub fn reporting(&self) -> reporting::Reporting {
+        reporting::Reporting::new(self.inner.reporting(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::reviews`].
+    pub fn reviews(&self) -> reviews::Reviews {
+        reviews::Reviews::new(self.inner.reviews(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::setup_attempts`].
+    pub fn setup_attempts(&self) -> setup_attempts::SetupAttempts {
+        setup_attempts::SetupAttempts::new(self.inner.setup_attempts(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::setup_intents`].
+    pub fn setup_intents(&self) -> setup_intents::SetupIntents {
+        setup_intents::SetupIntents::new(self.inner.setup_intents(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::shipping_rates`].
+    pub fn shipping_rates(&self) -> shipping_rates::ShippingRates {
+        shipping_rates::ShippingRates::new(self.inner.shipping_rates(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::sigma`].
+    pub fn sigma(&self) -> sigma::Sigma {
+        sigma::Sigma::new(self.inner.sigma(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::skus`].
+    pub fn skus(&self) -> skus::Skus {
+        skus::Skus::new(self.inner.skus(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::sources`].
+    pub fn sources(&self) -> sources::Sources {
+        sources::Sources::new(self.inner.sources(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::subscription_items`].
+    pub fn subscription_items(&self) -> subscription_items::SubscriptionItems {
+        subscription_items::SubscriptionItems::new(
+            self.inner.subscription_items(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::subscription_schedules`].
+    pub fn subscription_schedules(&self) -> subscription_schedules::SubscriptionSchedules {
+        subscription_schedules::SubscriptionSchedules::new(
+            self.inner.subscription_schedules(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::subscriptions`].
+    pub fn subscriptions(&self) -> subscriptions::Subscriptions {
+        subscriptions::Subscriptions::new(self.inner.subscriptions(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::tax_codes`].
+    pub fn tax_codes(&self) -> tax_codes::TaxCodes {
+        tax_codes::TaxCodes::new(self.inner.tax_codes(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::tax_rates`].
+    pub fn tax_rates(&self) -> tax_rates::TaxRates {
+        tax_rates::TaxRates::new(self.inner.tax_rates(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::terminal`].
+    pub fn terminal(&self) -> terminal::Terminal {
+        terminal::Terminal::new(self.inner.terminal(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::test_helpers`].
+    pub fn test_helpers(&self) -> test_helpers::TestHelpers {
+        test_helpers::TestHelpers::new(self.inner.test_helpers(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::three_d_secure`].
+    pub fn three_d_secure(&self) -> three_d_secure::ThreeDSecure {
+        three_d_secure::ThreeDSecure::new(self.inner.three_d_secure(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::tokens`].
+    pub fn tokens(&self) -> tokens::Tokens {
+        tokens::Tokens::new(self.inner.tokens(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::topups`].
+    pub fn topups(&self) -> topups::Topups {
+        topups::Topups::new(self.inner.topups(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::transfers`].
+    pub fn transfers(&self) -> transfers::Transfers {
+        transfers::Transfers::new(self.inner.transfers(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::webhook_endpoints`].
+    pub fn webhook_endpoints(&self) -> webhook_endpoints::WebhookEndpoints {
+        webhook_endpoints::WebhookEndpoints::new(
+            self.inner.webhook_endpoints(),
+            self.runtime.clone(),
+        )
+    }
+}
@@ -0,0 +1,92 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`OrderReturns::get_page`], set with a builder.
+pub struct OrderReturnsGetPageRequest<'a> {
+    inner: crate::order_returns::OrderReturnsGetPageRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OrderReturnsGetPageRequest<'a> {
+    /// Sets the `ending_before` query parameter.
+    pub fn ending_before(mut self, ending_before: &'a str) -> Self {
+        self.inner = self.inner.ending_before(ending_before);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `order` query parameter.
+    pub fn order(mut self, order: &'a str) -> Self {
+        self.inner = self.inner.order(order);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.inner = self.inner.starting_after(starting_after);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::OrderReturn>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::order_returns::OrderReturns`].
+pub struct OrderReturns {
+    inner: crate::order_returns::OrderReturns,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl OrderReturns {
+    pub(crate) fn new(
+        inner: crate::order_returns::OrderReturns,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        OrderReturns { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::order_returns::OrderReturns::get_page`].
+    pub fn get_page(
+        &self,
+        _created: &str,
+        ending_before: &str,
+        limit: i64,
+        order: &str,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::OrderReturn>>> {
+        self.runtime.block_on(self.inner.get_page(
+            _created,
+            ending_before,
+            limit,
+            order,
+            starting_after,
+        ))
+    }
+    /// The blocking version of [`crate::order_returns::OrderReturns::get_page_request`].
+    pub fn get_page_request(&self) -> OrderReturnsGetPageRequest<'_> {
+        OrderReturnsGetPageRequest {
+            inner: self.inner.get_page_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::order_returns::OrderReturns::get_all`].
+    pub fn get_all(
+        &self,
+        _created: &str,
+        order: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::OrderReturn>>> {
+        self.runtime.block_on(self.inner.get_all(_created, order))
+    }
+    /// The blocking version of [`crate::order_returns::OrderReturns::get`].
+    pub fn get(&self, id: &str) -> ClientResult<crate::Response<crate::types::OrderReturn>> {
+        self.runtime.block_on(self.inner.get(id))
+    }
+}
@@ -0,0 +1,133 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Orders::get_page`], set with a builder.
+pub struct OrdersGetPageRequest<'a> {
+    inner: crate::orders::OrdersGetPageRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> OrdersGetPageRequest<'a> {
+    /// Sets the `customer` query parameter.
+    pub fn customer(mut self, customer: &'a str) -> Self {
+        self.inner = self.inner.customer(customer);
+        self
+    }
+
+    /// Sets the `ending_before` query parameter.
+    pub fn ending_before(mut self, ending_before: &'a str) -> Self {
+        self.inner = self.inner.ending_before(ending_before);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.inner = self.inner.starting_after(starting_after);
+        self
+    }
+
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: &'a str) -> Self {
+        self.inner = self.inner.status(status);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Order>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::orders::Orders`].
+pub struct Orders {
+    inner: crate::orders::Orders,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Orders {
+    pub(crate) fn new(inner: crate::orders::Orders, runtime: Arc<tokio::runtime::Runtime>) -> Self {
+        Orders { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::orders::Orders::get_page`].
+    pub fn get_page(
+        &self,
+        _created: &str,
+        customer: &str,
+        ending_before: &str,
+        _ids: &[String],
+        limit: i64,
+        starting_after: &str,
+        status: &str,
+        _status_transitions: &str,
+        _upstream_ids: &[String],
+    ) -> ClientResult<crate::Response<Vec<crate::types::Order>>> {
+        self.runtime.block_on(self.inner.get_page(
+            _created,
+            customer,
+            ending_before,
+            _ids,
+            limit,
+            starting_after,
+            status,
+            _status_transitions,
+            _upstream_ids,
+        ))
+    }
+    /// The blocking version of [`crate::orders::Orders::get_page_request`].
+    pub fn get_page_request(&self) -> OrdersGetPageRequest<'_> {
+        OrdersGetPageRequest {
+            inner: self.inner.get_page_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::orders::Orders::get_all`].
+    pub fn get_all(
+        &self,
+        _created: &str,
+        customer: &str,
+        _ids: &[String],
+        status: &str,
+        _status_transitions: &str,
+        _upstream_ids: &[String],
+    ) -> ClientResult<crate::Response<Vec<crate::types::Order>>> {
+        self.runtime.block_on(self.inner.get_all(
+            _created,
+            customer,
+            _ids,
+            status,
+            _status_transitions,
+            _upstream_ids,
+        ))
+    }
+    /// The blocking version of [`crate::orders::Orders::post`].
+    pub fn post(&self) -> ClientResult<crate::Response<crate::types::Order>> {
+        self.runtime.block_on(self.inner.post())
+    }
+    /// The blocking version of [`crate::orders::Orders::get`].
+    pub fn get(&self, id: &str) -> ClientResult<crate::Response<crate::types::Order>> {
+        self.runtime.block_on(self.inner.get(id))
+    }
+    /// The blocking version of [`crate::orders::Orders::post_orders`].
+    pub fn post_orders(&self, id: &str) -> ClientResult<crate::Response<crate::types::Order>> {
+        self.runtime.block_on(self.inner.post_orders(id))
+    }
+    /// The blocking version of [`crate::orders::Orders::post_pay`].
+    pub fn post_pay(&self, id: &str) -> ClientResult<crate::Response<crate::types::Order>> {
+        self.runtime.block_on(self.inner.post_pay(id))
+    }
+    /// The blocking version of [`crate::orders::Orders::post_return`].
+    pub fn post_return(
+        &self,
+        id: &str,
+    ) -> ClientResult<crate::Response<crate::types::OrderReturn>> {
+        self.runtime.block_on(self.inner.post_return(id))
+    }
+}
@@ -0,0 +1,215 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`PaymentIntents::get_page`], set with a builder.
+pub struct PaymentIntentsGetPageRequest<'a> {
+    inner: crate::payment_intents::PaymentIntentsGetPageRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> PaymentIntentsGetPageRequest<'a> {
+    /// Sets the `customer` query parameter.
+    pub fn customer(mut self, customer: &'a str) -> Self {
+        self.inner = self.inner.customer(customer);
+        self
+    }
+
+    /// Sets the `ending_before` query parameter.
+    pub fn ending_before(mut self, ending_before: &'a str) -> Self {
+        self.inner = self.inner.ending_before(ending_before);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.inner = self.inner.starting_after(starting_after);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::PaymentIntent>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`PaymentIntents::get_search`], set with a builder.
+pub struct PaymentIntentsGetSearchRequest<'a> {
+    inner: crate::payment_intents::PaymentIntentsGetSearchRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> PaymentIntentsGetSearchRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: &'a str) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Charge>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`PaymentIntents::get_intent`], set with a builder.
+pub struct PaymentIntentsGetIntentRequest<'a> {
+    inner: crate::payment_intents::PaymentIntentsGetIntentRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> PaymentIntentsGetIntentRequest<'a> {
+    /// Sets the `client_secret` query parameter.
+    pub fn client_secret(mut self, client_secret: &'a str) -> Self {
+        self.inner = self.inner.client_secret(client_secret);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::PaymentIntent>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::payment_intents::PaymentIntents`].
+pub struct PaymentIntents {
+    inner: crate::payment_intents::PaymentIntents,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl PaymentIntents {
+    pub(crate) fn new(
+        inner: crate::payment_intents::PaymentIntents,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        PaymentIntents { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::payment_intents::PaymentIntents::get_page`].
+    pub fn get_page(
+        &self,
+        _created: &str,
+        customer: &str,
+        ending_before: &str,
+        limit: i64,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::PaymentIntent>>> {
+        self.runtime.block_on(self.inner.get_page(
+            _created,
+            customer,
+            ending_before,
+            limit,
+            starting_after,
+        ))
+    }
+    /// The blocking version of [`crate::payment_intents::PaymentIntents::get_page_request`].
+    pub fn get_page_request(&self) -> PaymentIntentsGetPageRequest<'_> {
+        PaymentIntentsGetPageRequest {
+            inner: self.inner.get_page_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::payment_intents::PaymentIntents::get_all`].
+    pub fn get_all(
+        &self,
+        _created: &str,
+        customer: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::PaymentIntent>>> {
+        self.runtime
+            .block_on(self.inner.get_all(_created, customer))
+    }
+    /// The blocking version of [`crate::payment_intents::PaymentIntents::post`].
+    pub fn post(&self) -> ClientResult<crate::Response<crate::types::PaymentIntent>> {
+        self.runtime.block_on(self.inner.post())
+    }
+    /// The blocking version of [`crate::payment_intents::PaymentIntents::get_search`].
+    pub fn get_search(
+        &self,
+        limit: i64,
+        page: &str,
+        query: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Charge>>> {
+        self.runtime
+            .block_on(self.inner.get_search(limit, page, query))
+    }
+    /// The blocking version of [`crate::payment_intents::PaymentIntents::get_search_request`].
+    pub fn get_search_request<'a>(&'a self, query: &'a str) -> PaymentIntentsGetSearchRequest<'a> {
+        PaymentIntentsGetSearchRequest {
+            inner: self.inner.get_search_request(query),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::payment_intents::PaymentIntents::get_all_search`].
+    pub fn get_all_search(
+        &self,
+        query: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Charge>>> {
+        self.runtime.block_on(self.inner.get_all_search(query))
+    }
+    /// The blocking version of [`crate::payment_intents::PaymentIntents::get_intent`].
+    pub fn get_intent(
+        &self,
+        client_secret: &str,
+        intent: &str,
+    ) -> ClientResult<crate::Response<crate::types::PaymentIntent>> {
+        self.runtime
+            .block_on(self.inner.get_intent(client_secret, intent))
+    }
+    /// The blocking version of [`crate::payment_intents::PaymentIntents::get_intent_request`].
+    pub fn get_intent_request<'a>(&'a self, intent: &'a str) -> PaymentIntentsGetIntentRequest<'a> {
+        PaymentIntentsGetIntentRequest {
+            inner: self.inner.get_intent_request(intent),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::payment_intents::PaymentIntents::post_intent`].
+    pub fn post_intent(
+        &self,
+        intent: &str,
+    ) -> ClientResult<crate::Response<crate::types::PaymentIntent>> {
+        self.runtime.block_on(self.inner.post_intent(intent))
+    }
+    /// The blocking version of [`crate::payment_intents::PaymentIntents::post_intent_cancel`].
+    pub fn post_intent_cancel(
+        &self,
+        intent: &str,
+    ) -> ClientResult<crate::Response<crate::types::PaymentIntent>> {
+        self.runtime.block_on(self.inner.post_intent_cancel(intent))
+    }
+    /// The blocking version of [`crate::payment_intents::PaymentIntents::post_intent_capture`].
+    pub fn post_intent_capture(
+        &self,
+        intent: &str,
+    ) -> ClientResult<crate::Response<crate::types::PaymentIntent>> {
+        self.runtime
+            .block_on(self.inner.post_intent_capture(intent))
+    }
+    /// The blocking version of [`crate::payment_intents::PaymentIntents::post_intent_confirm`].
+    pub fn post_intent_confirm(
+        &self,
+        intent: &str,
+    ) -> ClientResult<crate::Response<crate::types::PaymentIntent>> {
+        self.runtime
+            .block_on(self.inner.post_intent_confirm(intent))
+    }
+    /// The blocking version of [`crate::payment_intents::PaymentIntents::post_intent_verify_microdeposit`].
+    pub fn post_intent_verify_microdeposit(
+        &self,
+        intent: &str,
+    ) -> ClientResult<crate::Response<crate::types::PaymentIntent>> {
+        self.runtime
+            .block_on(self.inner.post_intent_verify_microdeposit(intent))
+    }
+}
@@ -0,0 +1,165 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`PaymentLinks::get_page`], set with a builder.
+pub struct PaymentLinksGetPageRequest<'a> {
+    inner: crate::payment_links::PaymentLinksGetPageRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> PaymentLinksGetPageRequest<'a> {
+    /// Sets the `active` query parameter.
+    pub fn active(mut self, active: bool) -> Self {
+        self.inner = self.inner.active(active);
+        self
+    }
+
+    /// Sets the `ending_before` query parameter.
+    pub fn ending_before(mut self, ending_before: &'a str) -> Self {
+        self.inner = self.inner.ending_before(ending_before);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.inner = self.inner.starting_after(starting_after);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::PaymentLink>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`PaymentLinks::get_link_line_items`], set with a builder.
+pub struct PaymentLinksGetLinkLineItemsRequest<'a> {
+    inner: crate::payment_links::PaymentLinksGetLinkLineItemsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> PaymentLinksGetLinkLineItemsRequest<'a> {
+    /// Sets the `ending_before` query parameter.
+    pub fn ending_before(mut self, ending_before: &'a str) -> Self {
+        self.inner = self.inner.ending_before(ending_before);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.inner = self.inner.starting_after(starting_after);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Item>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::payment_links::PaymentLinks`].
+pub struct PaymentLinks {
+    inner: crate::payment_links::PaymentLinks,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl PaymentLinks {
+    pub(crate) fn new(
+        inner: crate::payment_links::PaymentLinks,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        PaymentLinks { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::payment_links::PaymentLinks::get_page`].
+    pub fn get_page(
+        &self,
+        active: bool,
+        ending_before: &str,
+        limit: i64,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::PaymentLink>>> {
+        self.runtime.block_on(
+            self.inner
+                .get_page(active, ending_before, limit, starting_after),
+        )
+    }
+    /// The blocking version of [`crate::payment_links::PaymentLinks::get_page_request`].
+    pub fn get_page_request(&self) -> PaymentLinksGetPageRequest<'_> {
+        PaymentLinksGetPageRequest {
+            inner: self.inner.get_page_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::payment_links::PaymentLinks::get_all`].
+    pub fn get_all(
+        &self,
+        active: bool,
+    ) -> ClientResult<crate::Response<Vec<crate::types::PaymentLink>>> {
+        self.runtime.block_on(self.inner.get_all(active))
+    }
+    /// The blocking version of [`crate::payment_links::PaymentLinks::post`].
+    pub fn post(&self) -> ClientResult<crate::Response<crate::types::PaymentLink>> {
+        self.runtime.block_on(self.inner.post())
+    }
+    /// The blocking version of [`crate::payment_links::PaymentLinks::get_link`].
+    pub fn get_link(
+        &self,
+        payment_link: &str,
+    ) -> ClientResult<crate::Response<crate::types::PaymentLink>> {
+        self.runtime.block_on(self.inner.get_link(payment_link))
+    }
+    /// The blocking version of [`crate::payment_links::PaymentLinks::post_link`].
+    pub fn post_link(
+        &self,
+        payment_link: &str,
+    ) -> ClientResult<crate::Response<crate::types::PaymentLink>> {
+        self.runtime.block_on(self.inner.post_link(payment_link))
+    }
+    /// The blocking version of [`crate::payment_links::PaymentLinks::get_link_line_items`].
+    pub fn get_link_line_items(
+        &self,
+        ending_before: &str,
+        limit: i64,
+        payment_link: &str,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Item>>> {
+        self.runtime.block_on(self.inner.get_link_line_items(
+            ending_before,
+            limit,
+            payment_link,
+            starting_after,
+        ))
+    }
+    /// The blocking version of [`crate::payment_links::PaymentLinks::get_link_line_items_request`].
+    pub fn get_link_line_items_request<'a>(
+        &'a self,
+        payment_link: &'a str,
+    ) -> PaymentLinksGetLinkLineItemsRequest<'a> {
+        PaymentLinksGetLinkLineItemsRequest {
+            inner: self.inner.get_link_line_items_request(payment_link),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::payment_links::PaymentLinks::get_all_link_line_items`].
+    pub fn get_all_link_line_items(
+        &self,
+        payment_link: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Item>>> {
+        self.runtime
+            .block_on(self.inner.get_all_link_line_items(payment_link))
+    }
+}
@@ -0,0 +1,129 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`PaymentMethods::get_page`], set with a builder.
+pub struct PaymentMethodsGetPageRequest<'a> {
+    inner: crate::payment_methods::PaymentMethodsGetPageRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> PaymentMethodsGetPageRequest<'a> {
+    /// Sets the `customer` query parameter.
+    pub fn customer(mut self, customer: &'a str) -> Self {
+        self.inner = self.inner.customer(customer);
+        self
+    }
+
+    /// Sets the `ending_before` query parameter.
+    pub fn ending_before(mut self, ending_before: &'a str) -> Self {
+        self.inner = self.inner.ending_before(ending_before);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.inner = self.inner.starting_after(starting_after);
+        self
+    }
+
+    /// Sets the `type` query parameter.
+    pub fn type_(mut self, type_: crate::types::GetCustomersCustomerPaymentMethodsType) -> Self {
+        self.inner = self.inner.type_(type_);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::PaymentMethod>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::payment_methods::PaymentMethods`].
+pub struct PaymentMethods {
+    inner: crate::payment_methods::PaymentMethods,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl PaymentMethods {
+    pub(crate) fn new(
+        inner: crate::payment_methods::PaymentMethods,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        PaymentMethods { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::payment_methods::PaymentMethods::get_page`].
+    pub fn get_page(
+        &self,
+        customer: &str,
+        ending_before: &str,
+        limit: i64,
+        starting_after: &str,
+        type_: crate::types::GetCustomersCustomerPaymentMethodsType,
+    ) -> ClientResult<crate::Response<Vec<crate::types::PaymentMethod>>> {
+        self.runtime.block_on(self.inner.get_page(
+            customer,
+            ending_before,
+            limit,
+            starting_after,
+            type_,
+        ))
+    }
+    /// The blocking version of [`crate::payment_methods::PaymentMethods::get_page_request`].
+    pub fn get_page_request(&self) -> PaymentMethodsGetPageRequest<'_> {
+        PaymentMethodsGetPageRequest {
+            inner: self.inner.get_page_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::payment_methods::PaymentMethods::get_all`].
+    pub fn get_all(
+        &self,
+        customer: &str,
+        type_: crate::types::GetCustomersCustomerPaymentMethodsType,
+    ) -> ClientResult<crate::Response<Vec<crate::types::PaymentMethod>>> {
+        self.runtime.block_on(self.inner.get_all(customer, type_))
+    }
+    /// The blocking version of [`crate::payment_methods::PaymentMethods::post`].
+    pub fn post(&self) -> ClientResult<crate::Response<crate::types::PaymentMethod>> {
+        self.runtime.block_on(self.inner.post())
+    }
+    /// The blocking version of [`crate::payment_methods::PaymentMethods::get_method`].
+    pub fn get_method(
+        &self,
+        payment_method: &str,
+    ) -> ClientResult<crate::Response<crate::types::PaymentMethod>> {
+        self.runtime.block_on(self.inner.get_method(payment_method))
+    }
+    /// The blocking version of [`crate::payment_methods::PaymentMethods::post_method`].
+    pub fn post_method(
+        &self,
+        payment_method: &str,
+    ) -> ClientResult<crate::Response<crate::types::PaymentMethod>> {
+        self.runtime
+            .block_on(self.inner.post_method(payment_method))
+    }
+    /// The blocking version of [`crate::payment_methods::PaymentMethods::post_method_attach`].
+    pub fn post_method_attach(
+        &self,
+        payment_method: &str,
+    ) -> ClientResult<crate::Response<crate::types::PaymentMethod>> {
+        self.runtime
+            .block_on(self.inner.post_method_attach(payment_method))
+    }
+    /// The blocking version of [`crate::payment_methods::PaymentMethods::post_method_detach`].
+    pub fn post_method_detach(
+        &self,
+        payment_method: &str,
+    ) -> ClientResult<crate::Response<crate::types::PaymentMethod>> {
+        self.runtime
+            .block_on(self.inner.post_method_detach(payment_method))
+    }
+}
@@ -0,0 +1,129 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Payouts::get_page`], set with a builder.
+pub struct PayoutsGetPageRequest<'a> {
+    inner: crate::payouts::PayoutsGetPageRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> PayoutsGetPageRequest<'a> {
+    /// Sets the `destination` query parameter.
+    pub fn destination(mut self, destination: &'a str) -> Self {
+        self.inner = self.inner.destination(destination);
+        self
+    }
+
+    /// Sets the `ending_before` query parameter.
+    pub fn ending_before(mut self, ending_before: &'a str) -> Self {
+        self.inner = self.inner.ending_before(ending_before);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.inner = self.inner.starting_after(starting_after);
+        self
+    }
+
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: &'a str) -> Self {
+        self.inner = self.inner.status(status);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Payout>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::payouts::Payouts`].
+pub struct Payouts {
+    inner: crate::payouts::Payouts,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Payouts {
+    pub(crate) fn new(
+        inner: crate::payouts::Payouts,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Payouts { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::payouts::Payouts::get_page`].
+    pub fn get_page(
+        &self,
+        _arrival_date: &str,
+        _created: &str,
+        destination: &str,
+        ending_before: &str,
+        limit: i64,
+        starting_after: &str,
+        status: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Payout>>> {
+        self.runtime.block_on(self.inner.get_page(
+            _arrival_date,
+            _created,
+            destination,
+            ending_before,
+            limit,
+            starting_after,
+            status,
+        ))
+    }
+    /// The blocking version of [`crate::payouts::Payouts::get_page_request`].
+    pub fn get_page_request(&self) -> PayoutsGetPageRequest<'_> {
+        PayoutsGetPageRequest {
+            inner: self.inner.get_page_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::payouts::Payouts::get_all`].
+    pub fn get_all(
+        &self,
+        _arrival_date: &str,
+        _created: &str,
+        destination: &str,
+        status: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Payout>>> {
+        self.runtime.block_on(
+            self.inner
+                .get_all(_arrival_date, _created, destination, status),
+        )
+    }
+    /// The blocking version of [`crate::payouts::Payouts::post`].
+    pub fn post(&self) -> ClientResult<crate::Response<crate::types::Payout>> {
+        self.runtime.block_on(self.inner.post())
+    }
+    /// The blocking version of [`crate::payouts::Payouts::get`].
+    pub fn get(&self, payout: &str) -> ClientResult<crate::Response<crate::types::Payout>> {
+        self.runtime.block_on(self.inner.get(payout))
+    }
+    /// The blocking version of [`crate::payouts::Payouts::post_payouts`].
+    pub fn post_payouts(
+        &self,
+        payout: &str,
+    ) -> ClientResult<crate::Response<crate::types::Payout>> {
+        self.runtime.block_on(self.inner.post_payouts(payout))
+    }
+    /// The blocking version of [`crate::payouts::Payouts::post_cancel`].
+    pub fn post_cancel(&self, payout: &str) -> ClientResult<crate::Response<crate::types::Payout>> {
+        self.runtime.block_on(self.inner.post_cancel(payout))
+    }
+    /// The blocking version of [`crate::payouts::Payouts::post_reverse`].
+    pub fn post_reverse(
+        &self,
+        payout: &str,
+    ) -> ClientResult<crate::Response<crate::types::Payout>> {
+        self.runtime.block_on(self.inner.post_reverse(payout))
+    }
+}
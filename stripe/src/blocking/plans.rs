@@ -0,0 +1,111 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Plans::get_page`], set with a builder.
+pub struct PlansGetPageRequest<'a> {
+    inner: crate::plans::PlansGetPageRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> PlansGetPageRequest<'a> {
+    /// Sets the `active` query parameter.
+    pub fn active(mut self, active: bool) -> Self {
+        self.inner = self.inner.active(active);
+        self
+    }
+
+    /// Sets the `ending_before` query parameter.
+    pub fn ending_before(mut self, ending_before: &'a str) -> Self {
+        self.inner = self.inner.ending_before(ending_before);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `product` query parameter.
+    pub fn product(mut self, product: &'a str) -> Self {
+        self.inner = self.inner.product(product);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.inner = self.inner.starting_after(starting_after);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::PlanData>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::plans::Plans`].
+pub struct Plans {
+    inner: crate::plans::Plans,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Plans {
+    pub(crate) fn new(inner: crate::plans::Plans, runtime: Arc<tokio::runtime::Runtime>) -> Self {
+        Plans { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::plans::Plans::get_page`].
+    pub fn get_page(
+        &self,
+        active: bool,
+        _created: &str,
+        ending_before: &str,
+        limit: i64,
+        product: &str,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::PlanData>>> {
+        self.runtime.block_on(self.inner.get_page(
+            active,
+            _created,
+            ending_before,
+            limit,
+            product,
+            starting_after,
+        ))
+    }
+    /// The blocking version of [`crate::plans::Plans::get_page_request`].
+    pub fn get_page_request(&self) -> PlansGetPageRequest<'_> {
+        PlansGetPageRequest {
+            inner: self.inner.get_page_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::plans::Plans::get_all`].
+    pub fn get_all(
+        &self,
+        active: bool,
+        _created: &str,
+        product: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::PlanData>>> {
+        self.runtime
+            .block_on(self.inner.get_all(active, _created, product))
+    }
+    /// The blocking version of [`crate::plans::Plans::post`].
+    pub fn post(&self) -> ClientResult<crate::Response<crate::types::PlanData>> {
+        self.runtime.block_on(self.inner.post())
+    }
+    /// The blocking version of [`crate::plans::Plans::get`].
+    pub fn get(&self, plan: &str) -> ClientResult<crate::Response<crate::types::PlanData>> {
+        self.runtime.block_on(self.inner.get(plan))
+    }
+    /// The blocking version of [`crate::plans::Plans::post_plans`].
+    pub fn post_plans(&self, plan: &str) -> ClientResult<crate::Response<crate::types::PlanData>> {
+        self.runtime.block_on(self.inner.post_plans(plan))
+    }
+    /// The blocking version of [`crate::plans::Plans::delete`].
+    pub fn delete(&self, plan: &str) -> ClientResult<crate::Response<crate::types::DeletedPlan>> {
+        self.runtime.block_on(self.inner.delete(plan))
+    }
+}
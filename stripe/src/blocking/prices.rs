@@ -0,0 +1,190 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Prices::get_page`], set with a builder.
+pub struct PricesGetPageRequest<'a> {
+    inner: crate::prices::PricesGetPageRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> PricesGetPageRequest<'a> {
+    /// Sets the `active` query parameter.
+    pub fn active(mut self, active: bool) -> Self {
+        self.inner = self.inner.active(active);
+        self
+    }
+
+    /// Sets the `currency` query parameter.
+    pub fn currency(mut self, currency: &'a str) -> Self {
+        self.inner = self.inner.currency(currency);
+        self
+    }
+
+    /// Sets the `ending_before` query parameter.
+    pub fn ending_before(mut self, ending_before: &'a str) -> Self {
+        self.inner = self.inner.ending_before(ending_before);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `product` query parameter.
+    pub fn product(mut self, product: &'a str) -> Self {
+        self.inner = self.inner.product(product);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.inner = self.inner.starting_after(starting_after);
+        self
+    }
+
+    /// Sets the `type` query parameter.
+    pub fn type_(mut self, type_: crate::types::PriceType) -> Self {
+        self.inner = self.inner.type_(type_);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::PriceData>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Prices::get_search`], set with a builder.
+pub struct PricesGetSearchRequest<'a> {
+    inner: crate::prices::PricesGetSearchRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> PricesGetSearchRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: &'a str) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Charge>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::prices::Prices`].
+pub struct Prices {
+    inner: crate::prices::Prices,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Prices {
+    pub(crate) fn new(inner: crate::prices::Prices, runtime: Arc<tokio::runtime::Runtime>) -> Self {
+        Prices { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::prices::Prices::get_page`].
+    pub fn get_page(
+        &self,
+        active: bool,
+        _created: &str,
+        currency: &str,
+        ending_before: &str,
+        limit: i64,
+        _lookup_keys: &[String],
+        product: &str,
+        _recurring: &str,
+        starting_after: &str,
+        type_: crate::types::PriceType,
+    ) -> ClientResult<crate::Response<Vec<crate::types::PriceData>>> {
+        self.runtime.block_on(self.inner.get_page(
+            active,
+            _created,
+            currency,
+            ending_before,
+            limit,
+            _lookup_keys,
+            product,
+            _recurring,
+            starting_after,
+            type_,
+        ))
+    }
+    /// The blocking version of [`crate::prices::Prices::get_page_request`].
+    pub fn get_page_request(&self) -> PricesGetPageRequest<'_> {
+        PricesGetPageRequest {
+            inner: self.inner.get_page_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::prices::Prices::get_all`].
+    pub fn get_all(
+        &self,
+        active: bool,
+        _created: &str,
+        currency: &str,
+        _lookup_keys: &[String],
+        product: &str,
+        _recurring: &str,
+        type_: crate::types::PriceType,
+    ) -> ClientResult<crate::Response<Vec<crate::types::PriceData>>> {
+        self.runtime.block_on(self.inner.get_all(
+            active,
+            _created,
+            currency,
+            _lookup_keys,
+            product,
+            _recurring,
+            type_,
+        ))
+    }
+    /// The blocking version of [`crate::prices::Prices::post`].
+    pub fn post(&self) -> ClientResult<crate::Response<crate::types::PriceData>> {
+        self.runtime.block_on(self.inner.post())
+    }
+    /// The blocking version of [`crate::prices::Prices::get_search`].
+    pub fn get_search(
+        &self,
+        limit: i64,
+        page: &str,
+        query: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Charge>>> {
+        self.runtime
+            .block_on(self.inner.get_search(limit, page, query))
+    }
+    /// The blocking version of [`crate::prices::Prices::get_search_request`].
+    pub fn get_search_request<'a>(&'a self, query: &'a str) -> PricesGetSearchRequest<'a> {
+        PricesGetSearchRequest {
+            inner: self.inner.get_search_request(query),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::prices::Prices::get_all_search`].
+    pub fn get_all_search(
+        &self,
+        query: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Charge>>> {
+        self.runtime.block_on(self.inner.get_all_search(query))
+    }
+    /// The blocking version of [`crate::prices::Prices::get`].
+    pub fn get(&self, price: &str) -> ClientResult<crate::Response<crate::types::PriceData>> {
+        self.runtime.block_on(self.inner.get(price))
+    }
+    /// The blocking version of [`crate::prices::Prices::post_prices`].
+    pub fn post_prices(
+        &self,
+        price: &str,
+    ) -> ClientResult<crate::Response<crate::types::PriceData>> {
+        self.runtime.block_on(self.inner.post_prices(price))
+    }
+}
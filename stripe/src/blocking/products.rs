@@ -0,0 +1,175 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Products::get_page`], set with a builder.
+pub struct ProductsGetPageRequest<'a> {
+    inner: crate::products::ProductsGetPageRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProductsGetPageRequest<'a> {
+    /// Sets the `active` query parameter.
+    pub fn active(mut self, active: bool) -> Self {
+        self.inner = self.inner.active(active);
+        self
+    }
+
+    /// Sets the `ending_before` query parameter.
+    pub fn ending_before(mut self, ending_before: &'a str) -> Self {
+        self.inner = self.inner.ending_before(ending_before);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `shippable` query parameter.
+    pub fn shippable(mut self, shippable: bool) -> Self {
+        self.inner = self.inner.shippable(shippable);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.inner = self.inner.starting_after(starting_after);
+        self
+    }
+
+    /// Sets the `url` query parameter.
+    pub fn url(mut self, url: &'a str) -> Self {
+        self.inner = self.inner.url(url);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Product>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Products::get_search`], set with a builder.
+pub struct ProductsGetSearchRequest<'a> {
+    inner: crate::products::ProductsGetSearchRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ProductsGetSearchRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: &'a str) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Charge>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::products::Products`].
+pub struct Products {
+    inner: crate::products::Products,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Products {
+    pub(crate) fn new(
+        inner: crate::products::Products,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Products { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::products::Products::get_page`].
+    pub fn get_page(
+        &self,
+        active: bool,
+        _created: &str,
+        ending_before: &str,
+        _ids: &[String],
+        limit: i64,
+        shippable: bool,
+        starting_after: &str,
+        url: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Product>>> {
+        self.runtime.block_on(self.inner.get_page(
+            active,
+            _created,
+            ending_before,
+            _ids,
+            limit,
+            shippable,
+            starting_after,
+            url,
+        ))
+    }
+    /// The blocking version of [`crate::products::Products::get_page_request`].
+    pub fn get_page_request(&self) -> ProductsGetPageRequest<'_> {
+        ProductsGetPageRequest {
+            inner: self.inner.get_page_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::products::Products::get_all`].
+    pub fn get_all(
+        &self,
+        active: bool,
+        _created: &str,
+        _ids: &[String],
+        shippable: bool,
+        url: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Product>>> {
+        self.runtime
+            .block_on(self.inner.get_all(active, _created, _ids, shippable, url))
+    }
+    /// The blocking version of [`crate::products::Products::post`].
+    pub fn post(&self) -> ClientResult<crate::Response<crate::types::Product>> {
+        self.runtime.block_on(self.inner.post())
+    }
+    /// The blocking version of [`crate::products::Products::get_search`].
+    pub fn get_search(
+        &self,
+        limit: i64,
+        page: &str,
+        query: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Charge>>> {
+        self.runtime
+            .block_on(self.inner.get_search(limit, page, query))
+    }
+    /// The blocking version of [`crate::products::Products::get_search_request`].
+    pub fn get_search_request<'a>(&'a self, query: &'a str) -> ProductsGetSearchRequest<'a> {
+        ProductsGetSearchRequest {
+            inner: self.inner.get_search_request(query),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::products::Products::get_all_search`].
+    pub fn get_all_search(
+        &self,
+        query: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Charge>>> {
+        self.runtime.block_on(self.inner.get_all_search(query))
+    }
+    /// The blocking version of [`crate::products::Products::get`].
+    pub fn get(&self, id: &str) -> ClientResult<crate::Response<crate::types::Product>> {
+        self.runtime.block_on(self.inner.get(id))
+    }
+    /// The blocking version of [`crate::products::Products::post_products`].
+    pub fn post_products(&self, id: &str) -> ClientResult<crate::Response<crate::types::Product>> {
+        self.runtime.block_on(self.inner.post_products(id))
+    }
+    /// The blocking version of [`crate::products::Products::delete`].
+    pub fn delete(&self, id: &str) -> ClientResult<crate::Response<crate::types::DeletedProduct>> {
+        self.runtime.block_on(self.inner.delete(id))
+    }
+}
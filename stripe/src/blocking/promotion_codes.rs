@@ -0,0 +1,134 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`PromotionCodes::get_page`], set with a builder.
+pub struct PromotionCodesGetPageRequest<'a> {
+    inner: crate::promotion_codes::PromotionCodesGetPageRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> PromotionCodesGetPageRequest<'a> {
+    /// Sets the `active` query parameter.
+    pub fn active(mut self, active: bool) -> Self {
+        self.inner = self.inner.active(active);
+        self
+    }
+
+    /// Sets the `code` query parameter.
+    pub fn code(mut self, code: &'a str) -> Self {
+        self.inner = self.inner.code(code);
+        self
+    }
+
+    /// Sets the `coupon` query parameter.
+    pub fn coupon(mut self, coupon: &'a str) -> Self {
+        self.inner = self.inner.coupon(coupon);
+        self
+    }
+
+    /// Sets the `customer` query parameter.
+    pub fn customer(mut self, customer: &'a str) -> Self {
+        self.inner = self.inner.customer(customer);
+        self
+    }
+
+    /// Sets the `ending_before` query parameter.
+    pub fn ending_before(mut self, ending_before: &'a str) -> Self {
+        self.inner = self.inner.ending_before(ending_before);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.inner = self.inner.starting_after(starting_after);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::PromotionCode>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::promotion_codes::PromotionCodes`].
+pub struct PromotionCodes {
+    inner: crate::promotion_codes::PromotionCodes,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl PromotionCodes {
+    pub(crate) fn new(
+        inner: crate::promotion_codes::PromotionCodes,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        PromotionCodes { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::promotion_codes::PromotionCodes::get_page`].
+    pub fn get_page(
+        &self,
+        active: bool,
+        code: &str,
+        coupon: &str,
+        _created: &str,
+        customer: &str,
+        ending_before: &str,
+        limit: i64,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::PromotionCode>>> {
+        self.runtime.block_on(self.inner.get_page(
+            active,
+            code,
+            coupon,
+            _created,
+            customer,
+            ending_before,
+            limit,
+            starting_after,
+        ))
+    }
+    /// The blocking version of [`crate::promotion_codes::PromotionCodes::get_page_request`].
+    pub fn get_page_request(&self) -> PromotionCodesGetPageRequest<'_> {
+        PromotionCodesGetPageRequest {
+            inner: self.inner.get_page_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::promotion_codes::PromotionCodes::get_all`].
+    pub fn get_all(
+        &self,
+        active: bool,
+        code: &str,
+        coupon: &str,
+        _created: &str,
+        customer: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::PromotionCode>>> {
+        self.runtime
+            .block_on(self.inner.get_all(active, code, coupon, _created, customer))
+    }
+    /// The blocking version of [`crate::promotion_codes::PromotionCodes::post`].
+    pub fn post(&self) -> ClientResult<crate::Response<crate::types::PromotionCode>> {
+        self.runtime.block_on(self.inner.post())
+    }
+    /// The blocking version of [`crate::promotion_codes::PromotionCodes::get_code`].
+    pub fn get_code(
+        &self,
+        promotion_code: &str,
+    ) -> ClientResult<crate::Response<crate::types::PromotionCode>> {
+        self.runtime.block_on(self.inner.get_code(promotion_code))
+    }
+    /// The blocking version of [`crate::promotion_codes::PromotionCodes::post_code`].
+    pub fn post_code(
+        &self,
+        promotion_code: &str,
+    ) -> ClientResult<crate::Response<crate::types::PromotionCode>> {
+        self.runtime.block_on(self.inner.post_code(promotion_code))
+    }
+}
@@ -0,0 +1,254 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Quotes::get_page`], set with a builder.
+pub struct QuotesGetPageRequest<'a> {
+    inner: crate::quotes::QuotesGetPageRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> QuotesGetPageRequest<'a> {
+    /// Sets the `customer` query parameter.
+    pub fn customer(mut self, customer: &'a str) -> Self {
+        self.inner = self.inner.customer(customer);
+        self
+    }
+
+    /// Sets the `ending_before` query parameter.
+    pub fn ending_before(mut self, ending_before: &'a str) -> Self {
+        self.inner = self.inner.ending_before(ending_before);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.inner = self.inner.starting_after(starting_after);
+        self
+    }
+
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: crate::types::QuoteStatus) -> Self {
+        self.inner = self.inner.status(status);
+        self
+    }
+
+    /// Sets the `test_clock` query parameter.
+    pub fn test_clock(mut self, test_clock: &'a str) -> Self {
+        self.inner = self.inner.test_clock(test_clock);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Quote>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Quotes::get_computed_upfront_line_items`], set with a builder.
+pub struct QuotesGetComputedUpfrontLineItemsRequest<'a> {
+    inner: crate::quotes::QuotesGetComputedUpfrontLineItemsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> QuotesGetComputedUpfrontLineItemsRequest<'a> {
+    /// Sets the `ending_before` query parameter.
+    pub fn ending_before(mut self, ending_before: &'a str) -> Self {
+        self.inner = self.inner.ending_before(ending_before);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.inner = self.inner.starting_after(starting_after);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Item>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Quotes::get_line_items`], set with a builder.
+pub struct QuotesGetLineItemsRequest<'a> {
+    inner: crate::quotes::QuotesGetLineItemsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> QuotesGetLineItemsRequest<'a> {
+    /// Sets the `ending_before` query parameter.
+    pub fn ending_before(mut self, ending_before: &'a str) -> Self {
+        self.inner = self.inner.ending_before(ending_before);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.inner = self.inner.starting_after(starting_after);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Item>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::quotes::Quotes`].
+pub struct Quotes {
+    inner: crate::quotes::Quotes,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Quotes {
+    pub(crate) fn new(inner: crate::quotes::Quotes, runtime: Arc<tokio::runtime::Runtime>) -> Self {
+        Quotes { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::quotes::Quotes::get_page`].
+    pub fn get_page(
+        &self,
+        customer: &str,
+        ending_before: &str,
+        limit: i64,
+        starting_after: &str,
+        status: crate::types::QuoteStatus,
+        test_clock: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Quote>>> {
+        self.runtime.block_on(self.inner.get_page(
+            customer,
+            ending_before,
+            limit,
+            starting_after,
+            status,
+            test_clock,
+        ))
+    }
+    /// The blocking version of [`crate::quotes::Quotes::get_page_request`].
+    pub fn get_page_request(&self) -> QuotesGetPageRequest<'_> {
+        QuotesGetPageRequest {
+            inner: self.inner.get_page_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::quotes::Quotes::get_all`].
+    pub fn get_all(
+        &self,
+        customer: &str,
+        status: crate::types::QuoteStatus,
+        test_clock: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Quote>>> {
+        self.runtime
+            .block_on(self.inner.get_all(customer, status, test_clock))
+    }
+    /// The blocking version of [`crate::quotes::Quotes::post`].
+    pub fn post(&self) -> ClientResult<crate::Response<crate::types::Quote>> {
+        self.runtime.block_on(self.inner.post())
+    }
+    /// The blocking version of [`crate::quotes::Quotes::get`].
+    pub fn get(&self, quote: &str) -> ClientResult<crate::Response<crate::types::Quote>> {
+        self.runtime.block_on(self.inner.get(quote))
+    }
+    /// The blocking version of [`crate::quotes::Quotes::post_quotes`].
+    pub fn post_quotes(&self, quote: &str) -> ClientResult<crate::Response<crate::types::Quote>> {
+        self.runtime.block_on(self.inner.post_quotes(quote))
+    }
+    /// The blocking version of [`crate::quotes::Quotes::post_accept`].
+    pub fn post_accept(&self, quote: &str) -> ClientResult<crate::Response<crate::types::Quote>> {
+        self.runtime.block_on(self.inner.post_accept(quote))
+    }
+    /// The blocking version of [`crate::quotes::Quotes::post_cancel`].
+    pub fn post_cancel(&self, quote: &str) -> ClientResult<crate::Response<crate::types::Quote>> {
+        self.runtime.block_on(self.inner.post_cancel(quote))
+    }
+    /// The blocking version of [`crate::quotes::Quotes::get_computed_upfront_line_items`].
+    pub fn get_computed_upfront_line_items(
+        &self,
+        ending_before: &str,
+        limit: i64,
+        quote: &str,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Item>>> {
+        self.runtime
+            .block_on(self.inner.get_computed_upfront_line_items(
+                ending_before,
+                limit,
+                quote,
+                starting_after,
+            ))
+    }
+    /// The blocking version of [`crate::quotes::Quotes::get_computed_upfront_line_items_request`].
+    pub fn get_computed_upfront_line_items_request<'a>(
+        &'a self,
+        quote: &'a str,
+    ) -> QuotesGetComputedUpfrontLineItemsRequest<'a> {
+        QuotesGetComputedUpfrontLineItemsRequest {
+            inner: self.inner.get_computed_upfront_line_items_request(quote),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::quotes::Quotes::get_all_computed_upfront_line_items`].
+    pub fn get_all_computed_upfront_line_items(
+        &self,
+        quote: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Item>>> {
+        self.runtime
+            .block_on(self.inner.get_all_computed_upfront_line_items(quote))
+    }
+    /// The blocking version of [`crate::quotes::Quotes::post_finalize`].
+    pub fn post_finalize(&self, quote: &str) -> ClientResult<crate::Response<crate::types::Quote>> {
+        self.runtime.block_on(self.inner.post_finalize(quote))
+    }
+    /// The blocking version of [`crate::quotes::Quotes::get_line_items`].
+    pub fn get_line_items(
+        &self,
+        ending_before: &str,
+        limit: i64,
+        quote: &str,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Item>>> {
+        self.runtime.block_on(self.inner.get_line_items(
+            ending_before,
+            limit,
+            quote,
+            starting_after,
+        ))
+    }
+    /// The blocking version of [`crate::quotes::Quotes::get_line_items_request`].
+    pub fn get_line_items_request<'a>(&'a self, quote: &'a str) -> QuotesGetLineItemsRequest<'a> {
+        QuotesGetLineItemsRequest {
+            inner: self.inner.get_line_items_request(quote),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::quotes::Quotes::get_all_line_items`].
+    pub fn get_all_line_items(
+        &self,
+        quote: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Item>>> {
+        self.runtime.block_on(self.inner.get_all_line_items(quote))
+    }
+    /// The blocking version of [`crate::quotes::Quotes::get_pdf`].
+    pub fn get_pdf(&self, quote: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.get_pdf(quote))
+    }
+}
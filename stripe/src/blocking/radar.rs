@@ -0,0 +1,314 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Radar::get_early_fraud_warnings`], set with a builder.
+pub struct RadarGetEarlyFraudWarningsRequest<'a> {
+    inner: crate::radar::RadarGetEarlyFraudWarningsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> RadarGetEarlyFraudWarningsRequest<'a> {
+    /// Sets the `charge` query parameter.
+    pub fn charge(mut self, charge: &'a str) -> Self {
+        self.inner = self.inner.charge(charge);
+        self
+    }
+
+    /// Sets the `ending_before` query parameter.
+    pub fn ending_before(mut self, ending_before: &'a str) -> Self {
+        self.inner = self.inner.ending_before(ending_before);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `payment_intent` query parameter.
+    pub fn payment_intent(mut self, payment_intent: &'a str) -> Self {
+        self.inner = self.inner.payment_intent(payment_intent);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.inner = self.inner.starting_after(starting_after);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::RadarEarlyFraudWarning>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Radar::get_value_list_items`], set with a builder.
+pub struct RadarGetValueListItemsRequest<'a> {
+    inner: crate::radar::RadarGetValueListItemsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> RadarGetValueListItemsRequest<'a> {
+    /// Sets the `ending_before` query parameter.
+    pub fn ending_before(mut self, ending_before: &'a str) -> Self {
+        self.inner = self.inner.ending_before(ending_before);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.inner = self.inner.starting_after(starting_after);
+        self
+    }
+
+    /// Sets the `value` query parameter.
+    pub fn value(mut self, value: &'a str) -> Self {
+        self.inner = self.inner.value(value);
+        self
+    }
+
+    /// Sets the `value_list` query parameter.
+    pub fn value_list(mut self, value_list: &'a str) -> Self {
+        self.inner = self.inner.value_list(value_list);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::RadarListItem>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Radar::get_value_lists`], set with a builder.
+pub struct RadarGetValueListsRequest<'a> {
+    inner: crate::radar::RadarGetValueListsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> RadarGetValueListsRequest<'a> {
+    /// Sets the `alias` query parameter.
+    pub fn alias(mut self, alias: &'a str) -> Self {
+        self.inner = self.inner.alias(alias);
+        self
+    }
+
+    /// Sets the `contains` query parameter.
+    pub fn contains(mut self, contains: &'a str) -> Self {
+        self.inner = self.inner.contains(contains);
+        self
+    }
+
+    /// Sets the `ending_before` query parameter.
+    pub fn ending_before(mut self, ending_before: &'a str) -> Self {
+        self.inner = self.inner.ending_before(ending_before);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.inner = self.inner.starting_after(starting_after);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::RadarList>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::radar::Radar`].
+pub struct Radar {
+    inner: crate::radar::Radar,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Radar {
+    pub(crate) fn new(inner: crate::radar::Radar, runtime: Arc<tokio::runtime::Runtime>) -> Self {
+        Radar { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::radar::Radar::get_early_fraud_warnings`].
+    pub fn get_early_fraud_warnings(
+        &self,
+        charge: &str,
+        ending_before: &str,
+        limit: i64,
+        payment_intent: &str,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::RadarEarlyFraudWarning>>> {
+        self.runtime.block_on(self.inner.get_early_fraud_warnings(
+            charge,
+            ending_before,
+            limit,
+            payment_intent,
+            starting_after,
+        ))
+    }
+    /// The blocking version of [`crate::radar::Radar::get_early_fraud_warnings_request`].
+    pub fn get_early_fraud_warnings_request(&self) -> RadarGetEarlyFraudWarningsRequest<'_> {
+        RadarGetEarlyFraudWarningsRequest {
+            inner: self.inner.get_early_fraud_warnings_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::radar::Radar::get_all_early_fraud_warnings`].
+    pub fn get_all_early_fraud_warnings(
+        &self,
+        charge: &str,
+        payment_intent: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::RadarEarlyFraudWarning>>> {
+        self.runtime.block_on(
+            self.inner
+                .get_all_early_fraud_warnings(charge, payment_intent),
+        )
+    }
+    /// The blocking version of [`crate::radar::Radar::get_early_fraud_warnings_warning`].
+    pub fn get_early_fraud_warnings_warning(
+        &self,
+        early_fraud_warning: &str,
+    ) -> ClientResult<crate::Response<crate::types::RadarEarlyFraudWarning>> {
+        self.runtime.block_on(
+            self.inner
+                .get_early_fraud_warnings_warning(early_fraud_warning),
+        )
+    }
+    /// The blocking version of [`crate::radar::Radar::get_value_list_items`].
+    pub fn get_value_list_items(
+        &self,
+        _created: &str,
+        ending_before: &str,
+        limit: i64,
+        starting_after: &str,
+        value: &str,
+        value_list: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::RadarListItem>>> {
+        self.runtime.block_on(self.inner.get_value_list_items(
+            _created,
+            ending_before,
+            limit,
+            starting_after,
+            value,
+            value_list,
+        ))
+    }
+    /// The blocking version of [`crate::radar::Radar::get_value_list_items_request`].
+    pub fn get_value_list_items_request(&self) -> RadarGetValueListItemsRequest<'_> {
+        RadarGetValueListItemsRequest {
+            inner: self.inner.get_value_list_items_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::radar::Radar::get_all_value_list_all_items`].
+    pub fn get_all_value_list_all_items(
+        &self,
+        _created: &str,
+        value: &str,
+        value_list: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::RadarListItem>>> {
+        self.runtime.block_on(
+            self.inner
+                .get_all_value_list_all_items(_created, value, value_list),
+        )
+    }
+    /// The blocking version of [`crate::radar::Radar::post_value_list_item`].
+    pub fn post_value_list_item(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::RadarListItem>> {
+        self.runtime.block_on(self.inner.post_value_list_item())
+    }
+    /// The blocking version of [`crate::radar::Radar::get_value_list_items_item`].
+    pub fn get_value_list_items_item(
+        &self,
+        item: &str,
+    ) -> ClientResult<crate::Response<crate::types::RadarListItem>> {
+        self.runtime
+            .block_on(self.inner.get_value_list_items_item(item))
+    }
+    /// The blocking version of [`crate::radar::Radar::delete_value_list_items_item`].
+    pub fn delete_value_list_items_item(
+        &self,
+        item: &str,
+    ) -> ClientResult<crate::Response<crate::types::RadarListDeletedItem>> {
+        self.runtime
+            .block_on(self.inner.delete_value_list_items_item(item))
+    }
+    /// The blocking version of [`crate::radar::Radar::get_value_lists`].
+    pub fn get_value_lists(
+        &self,
+        alias: &str,
+        contains: &str,
+        _created: &str,
+        ending_before: &str,
+        limit: i64,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::RadarList>>> {
+        self.runtime.block_on(self.inner.get_value_lists(
+            alias,
+            contains,
+            _created,
+            ending_before,
+            limit,
+            starting_after,
+        ))
+    }
+    /// The blocking version of [`crate::radar::Radar::get_value_lists_request`].
+    pub fn get_value_lists_request(&self) -> RadarGetValueListsRequest<'_> {
+        RadarGetValueListsRequest {
+            inner: self.inner.get_value_lists_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::radar::Radar::get_all_value_lists`].
+    pub fn get_all_value_lists(
+        &self,
+        alias: &str,
+        contains: &str,
+        _created: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::RadarList>>> {
+        self.runtime
+            .block_on(self.inner.get_all_value_lists(alias, contains, _created))
+    }
+    /// The blocking version of [`crate::radar::Radar::post_value_list`].
+    pub fn post_value_list(&self) -> ClientResult<crate::Response<crate::types::RadarList>> {
+        self.runtime.block_on(self.inner.post_value_list())
+    }
+    /// The blocking version of [`crate::radar::Radar::get_value_lists_list`].
+    pub fn get_value_lists_list(
+        &self,
+        value_list: &str,
+    ) -> ClientResult<crate::Response<crate::types::RadarList>> {
+        self.runtime
+            .block_on(self.inner.get_value_lists_list(value_list))
+    }
+    /// The blocking version of [`crate::radar::Radar::post_value_lists_list`].
+    pub fn post_value_lists_list(
+        &self,
+        value_list: &str,
+    ) -> ClientResult<crate::Response<crate::types::RadarList>> {
+        self.runtime
+            .block_on(self.inner.post_value_lists_list(value_list))
+    }
+    /// The blocking version of [`crate::radar::Radar::delete_value_lists_list`].
+    pub fn delete_value_lists_list(
+        &self,
+        value_list: &str,
+    ) -> ClientResult<crate::Response<crate::types::RadarListDeleted>> {
+        self.runtime
+            .block_on(self.inner.delete_value_lists_list(value_list))
+    }
+}
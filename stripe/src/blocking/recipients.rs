@@ -0,0 +1,123 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Recipients::get_page`], set with a builder.
+pub struct RecipientsGetPageRequest<'a> {
+    inner: crate::recipients::RecipientsGetPageRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> RecipientsGetPageRequest<'a> {
+    /// Sets the `ending_before` query parameter.
+    pub fn ending_before(mut self, ending_before: &'a str) -> Self {
+        self.inner = self.inner.ending_before(ending_before);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.inner = self.inner.starting_after(starting_after);
+        self
+    }
+
+    /// Sets the `type` query parameter.
+    pub fn type_(mut self, type_: crate::types::GetRecipientsType) -> Self {
+        self.inner = self.inner.type_(type_);
+        self
+    }
+
+    /// Sets the `verified` query parameter.
+    pub fn verified(mut self, verified: bool) -> Self {
+        self.inner = self.inner.verified(verified);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Recipient>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::recipients::Recipients`].
+pub struct Recipients {
+    inner: crate::recipients::Recipients,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Recipients {
+    pub(crate) fn new(
+        inner: crate::recipients::Recipients,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Recipients { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::recipients::Recipients::get_page`].
+    pub fn get_page(
+        &self,
+        _created: &str,
+        ending_before: &str,
+        limit: i64,
+        starting_after: &str,
+        type_: crate::types::GetRecipientsType,
+        verified: bool,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Recipient>>> {
+        self.runtime.block_on(self.inner.get_page(
+            _created,
+            ending_before,
+            limit,
+            starting_after,
+            type_,
+            verified,
+        ))
+    }
+    /// The blocking version of [`crate::recipients::Recipients::get_page_request`].
+    pub fn get_page_request(&self) -> RecipientsGetPageRequest<'_> {
+        RecipientsGetPageRequest {
+            inner: self.inner.get_page_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::recipients::Recipients::get_all`].
+    pub fn get_all(
+        &self,
+        _created: &str,
+        type_: crate::types::GetRecipientsType,
+        verified: bool,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Recipient>>> {
+        self.runtime
+            .block_on(self.inner.get_all(_created, type_, verified))
+    }
+    /// The blocking version of [`crate::recipients::Recipients::post`].
+    pub fn post(&self) -> ClientResult<crate::Response<crate::types::Recipient>> {
+        self.runtime.block_on(self.inner.post())
+    }
+    /// The blocking version of [`crate::recipients::Recipients::get`].
+    pub fn get(
+        &self,
+        id: &str,
+    ) -> ClientResult<crate::Response<crate::types::GetRecipientsResponseAnyOf>> {
+        self.runtime.block_on(self.inner.get(id))
+    }
+    /// The blocking version of [`crate::recipients::Recipients::post_recipients`].
+    pub fn post_recipients(
+        &self,
+        id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Recipient>> {
+        self.runtime.block_on(self.inner.post_recipients(id))
+    }
+    /// The blocking version of [`crate::recipients::Recipients::delete`].
+    pub fn delete(
+        &self,
+        id: &str,
+    ) -> ClientResult<crate::Response<crate::types::DeletedRecipient>> {
+        self.runtime.block_on(self.inner.delete(id))
+    }
+}
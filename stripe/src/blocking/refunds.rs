@@ -0,0 +1,117 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Refunds::get_page`], set with a builder.
+pub struct RefundsGetPageRequest<'a> {
+    inner: crate::refunds::RefundsGetPageRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> RefundsGetPageRequest<'a> {
+    /// Sets the `charge` query parameter.
+    pub fn charge(mut self, charge: &'a str) -> Self {
+        self.inner = self.inner.charge(charge);
+        self
+    }
+
+    /// Sets the `ending_before` query parameter.
+    pub fn ending_before(mut self, ending_before: &'a str) -> Self {
+        self.inner = self.inner.ending_before(ending_before);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `payment_intent` query parameter.
+    pub fn payment_intent(mut self, payment_intent: &'a str) -> Self {
+        self.inner = self.inner.payment_intent(payment_intent);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.inner = self.inner.starting_after(starting_after);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Refund>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::refunds::Refunds`].
+pub struct Refunds {
+    inner: crate::refunds::Refunds,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Refunds {
+    pub(crate) fn new(
+        inner: crate::refunds::Refunds,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Refunds { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::refunds::Refunds::get_page`].
+    pub fn get_page(
+        &self,
+        charge: &str,
+        _created: &str,
+        ending_before: &str,
+        limit: i64,
+        payment_intent: &str,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Refund>>> {
+        self.runtime.block_on(self.inner.get_page(
+            charge,
+            _created,
+            ending_before,
+            limit,
+            payment_intent,
+            starting_after,
+        ))
+    }
+    /// The blocking version of [`crate::refunds::Refunds::get_page_request`].
+    pub fn get_page_request(&self) -> RefundsGetPageRequest<'_> {
+        RefundsGetPageRequest {
+            inner: self.inner.get_page_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::refunds::Refunds::get_all`].
+    pub fn get_all(
+        &self,
+        charge: &str,
+        _created: &str,
+        payment_intent: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Refund>>> {
+        self.runtime
+            .block_on(self.inner.get_all(charge, _created, payment_intent))
+    }
+    /// The blocking version of [`crate::refunds::Refunds::post`].
+    pub fn post(&self) -> ClientResult<crate::Response<crate::types::Refund>> {
+        self.runtime.block_on(self.inner.post())
+    }
+    /// The blocking version of [`crate::refunds::Refunds::get`].
+    pub fn get(&self, refund: &str) -> ClientResult<crate::Response<crate::types::Refund>> {
+        self.runtime.block_on(self.inner.get(refund))
+    }
+    /// The blocking version of [`crate::refunds::Refunds::post_refunds`].
+    pub fn post_refunds(
+        &self,
+        refund: &str,
+    ) -> ClientResult<crate::Response<crate::types::Refund>> {
+        self.runtime.block_on(self.inner.post_refunds(refund))
+    }
+    /// The blocking version of [`crate::refunds::Refunds::post_cancel`].
+    pub fn post_cancel(&self, refund: &str) -> ClientResult<crate::Response<crate::types::Refund>> {
+        self.runtime.block_on(self.inner.post_cancel(refund))
+    }
+}
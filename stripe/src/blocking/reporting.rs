@@ -0,0 +1,114 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Reporting::get_report_runs`], set with a builder.
+pub struct ReportingGetReportRunsRequest<'a> {
+    inner: crate::reporting::ReportingGetReportRunsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ReportingGetReportRunsRequest<'a> {
+    /// Sets the `ending_before` query parameter.
+    pub fn ending_before(mut self, ending_before: &'a str) -> Self {
+        self.inner = self.inner.ending_before(ending_before);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.inner = self.inner.starting_after(starting_after);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::ReportingReportRun>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::reporting::Reporting`].
+pub struct Reporting {
+    inner: crate::reporting::Reporting,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Reporting {
+    pub(crate) fn new(
+        inner: crate::reporting::Reporting,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Reporting { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::reporting::Reporting::get_report_runs`].
+    pub fn get_report_runs(
+        &self,
+        _created: &str,
+        ending_before: &str,
+        limit: i64,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ReportingReportRun>>> {
+        self.runtime.block_on(self.inner.get_report_runs(
+            _created,
+            ending_before,
+            limit,
+            starting_after,
+        ))
+    }
+    /// The blocking version of [`crate::reporting::Reporting::get_report_runs_request`].
+    pub fn get_report_runs_request(&self) -> ReportingGetReportRunsRequest<'_> {
+        ReportingGetReportRunsRequest {
+            inner: self.inner.get_report_runs_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::reporting::Reporting::get_all_report_runs`].
+    pub fn get_all_report_runs(
+        &self,
+        _created: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ReportingReportRun>>> {
+        self.runtime
+            .block_on(self.inner.get_all_report_runs(_created))
+    }
+    /// The blocking version of [`crate::reporting::Reporting::post_report_run`].
+    pub fn post_report_run(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::ReportingReportRun>> {
+        self.runtime.block_on(self.inner.post_report_run())
+    }
+    /// The blocking version of [`crate::reporting::Reporting::get_report_runs_run`].
+    pub fn get_report_runs_run(
+        &self,
+        report_run: &str,
+    ) -> ClientResult<crate::Response<crate::types::ReportingReportRun>> {
+        self.runtime
+            .block_on(self.inner.get_report_runs_run(report_run))
+    }
+    /// The blocking version of [`crate::reporting::Reporting::get_report_types`].
+    pub fn get_report_types(
+        &self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ReportingReportType>>> {
+        self.runtime.block_on(self.inner.get_report_types())
+    }
+    /// The blocking version of [`crate::reporting::Reporting::get_all_report_types`].
+    pub fn get_all_report_types(
+        &self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ReportingReportType>>> {
+        self.runtime.block_on(self.inner.get_all_report_types())
+    }
+    /// The blocking version of [`crate::reporting::Reporting::get_report_types_type`].
+    pub fn get_report_types_type(
+        &self,
+        report_type: &str,
+    ) -> ClientResult<crate::Response<crate::types::ReportingReportType>> {
+        self.runtime
+            .block_on(self.inner.get_report_types_type(report_type))
+    }
+}
@@ -0,0 +1,88 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Reviews::get_page`], set with a builder.
+pub struct ReviewsGetPageRequest<'a> {
+    inner: crate::reviews::ReviewsGetPageRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ReviewsGetPageRequest<'a> {
+    /// Sets the `ending_before` query parameter.
+    pub fn ending_before(mut self, ending_before: &'a str) -> Self {
+        self.inner = self.inner.ending_before(ending_before);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.inner = self.inner.starting_after(starting_after);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Review>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::reviews::Reviews`].
+pub struct Reviews {
+    inner: crate::reviews::Reviews,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Reviews {
+    pub(crate) fn new(
+        inner: crate::reviews::Reviews,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Reviews { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::reviews::Reviews::get_page`].
+    pub fn get_page(
+        &self,
+        _created: &str,
+        ending_before: &str,
+        limit: i64,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Review>>> {
+        self.runtime.block_on(
+            self.inner
+                .get_page(_created, ending_before, limit, starting_after),
+        )
+    }
+    /// The blocking version of [`crate::reviews::Reviews::get_page_request`].
+    pub fn get_page_request(&self) -> ReviewsGetPageRequest<'_> {
+        ReviewsGetPageRequest {
+            inner: self.inner.get_page_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::reviews::Reviews::get_all`].
+    pub fn get_all(
+        &self,
+        _created: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Review>>> {
+        self.runtime.block_on(self.inner.get_all(_created))
+    }
+    /// The blocking version of [`crate::reviews::Reviews::get`].
+    pub fn get(&self, review: &str) -> ClientResult<crate::Response<crate::types::Review>> {
+        self.runtime.block_on(self.inner.get(review))
+    }
+    /// The blocking version of [`crate::reviews::Reviews::post_approve`].
+    pub fn post_approve(
+        &self,
+        review: &str,
+    ) -> ClientResult<crate::Response<crate::types::Review>> {
+        self.runtime.block_on(self.inner.post_approve(review))
+    }
+}
@@ -0,0 +1,89 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`SetupAttempts::get_page`], set with a builder.
+pub struct SetupAttemptsGetPageRequest<'a> {
+    inner: crate::setup_attempts::SetupAttemptsGetPageRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> SetupAttemptsGetPageRequest<'a> {
+    /// Sets the `ending_before` query parameter.
+    pub fn ending_before(mut self, ending_before: &'a str) -> Self {
+        self.inner = self.inner.ending_before(ending_before);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `setup_intent` query parameter.
+    pub fn setup_intent(mut self, setup_intent: &'a str) -> Self {
+        self.inner = self.inner.setup_intent(setup_intent);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.inner = self.inner.starting_after(starting_after);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::SetupAttempt>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::setup_attempts::SetupAttempts`].
+pub struct SetupAttempts {
+    inner: crate::setup_attempts::SetupAttempts,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl SetupAttempts {
+    pub(crate) fn new(
+        inner: crate::setup_attempts::SetupAttempts,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        SetupAttempts { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::setup_attempts::SetupAttempts::get_page`].
+    pub fn get_page(
+        &self,
+        _created: &str,
+        ending_before: &str,
+        limit: i64,
+        setup_intent: &str,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::SetupAttempt>>> {
+        self.runtime.block_on(self.inner.get_page(
+            _created,
+            ending_before,
+            limit,
+            setup_intent,
+            starting_after,
+        ))
+    }
+    /// The blocking version of [`crate::setup_attempts::SetupAttempts::get_page_request`].
+    pub fn get_page_request(&self) -> SetupAttemptsGetPageRequest<'_> {
+        SetupAttemptsGetPageRequest {
+            inner: self.inner.get_page_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::setup_attempts::SetupAttempts::get_all`].
+    pub fn get_all(
+        &self,
+        _created: &str,
+        setup_intent: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::SetupAttempt>>> {
+        self.runtime
+            .block_on(self.inner.get_all(_created, setup_intent))
+    }
+}
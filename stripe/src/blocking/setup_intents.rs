@@ -0,0 +1,167 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`SetupIntents::get_page`], set with a builder.
+pub struct SetupIntentsGetPageRequest<'a> {
+    inner: crate::setup_intents::SetupIntentsGetPageRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> SetupIntentsGetPageRequest<'a> {
+    /// Sets the `customer` query parameter.
+    pub fn customer(mut self, customer: &'a str) -> Self {
+        self.inner = self.inner.customer(customer);
+        self
+    }
+
+    /// Sets the `ending_before` query parameter.
+    pub fn ending_before(mut self, ending_before: &'a str) -> Self {
+        self.inner = self.inner.ending_before(ending_before);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `payment_method` query parameter.
+    pub fn payment_method(mut self, payment_method: &'a str) -> Self {
+        self.inner = self.inner.payment_method(payment_method);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.inner = self.inner.starting_after(starting_after);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::SetupIntent>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`SetupIntents::get_intent`], set with a builder.
+pub struct SetupIntentsGetIntentRequest<'a> {
+    inner: crate::setup_intents::SetupIntentsGetIntentRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> SetupIntentsGetIntentRequest<'a> {
+    /// Sets the `client_secret` query parameter.
+    pub fn client_secret(mut self, client_secret: &'a str) -> Self {
+        self.inner = self.inner.client_secret(client_secret);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::SetupIntent>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::setup_intents::SetupIntents`].
+pub struct SetupIntents {
+    inner: crate::setup_intents::SetupIntents,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl SetupIntents {
+    pub(crate) fn new(
+        inner: crate::setup_intents::SetupIntents,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        SetupIntents { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::setup_intents::SetupIntents::get_page`].
+    pub fn get_page(
+        &self,
+        _created: &str,
+        customer: &str,
+        ending_before: &str,
+        limit: i64,
+        payment_method: &str,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::SetupIntent>>> {
+        self.runtime.block_on(self.inner.get_page(
+            _created,
+            customer,
+            ending_before,
+            limit,
+            payment_method,
+            starting_after,
+        ))
+    }
+    /// The blocking version of [`crate::setup_intents::SetupIntents::get_page_request`].
+    pub fn get_page_request(&self) -> SetupIntentsGetPageRequest<'_> {
+        SetupIntentsGetPageRequest {
+            inner: self.inner.get_page_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::setup_intents::SetupIntents::get_all`].
+    pub fn get_all(
+        &self,
+        _created: &str,
+        customer: &str,
+        payment_method: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::SetupIntent>>> {
+        self.runtime
+            .block_on(self.inner.get_all(_created, customer, payment_method))
+    }
+    /// The blocking version of [`crate::setup_intents::SetupIntents::post`].
+    pub fn post(&self) -> ClientResult<crate::Response<crate::types::SetupIntent>> {
+        self.runtime.block_on(self.inner.post())
+    }
+    /// The blocking version of [`crate::setup_intents::SetupIntents::get_intent`].
+    pub fn get_intent(
+        &self,
+        client_secret: &str,
+        intent: &str,
+    ) -> ClientResult<crate::Response<crate::types::SetupIntent>> {
+        self.runtime
+            .block_on(self.inner.get_intent(client_secret, intent))
+    }
+    /// The blocking version of [`crate::setup_intents::SetupIntents::get_intent_request`].
+    pub fn get_intent_request<'a>(&'a self, intent: &'a str) -> SetupIntentsGetIntentRequest<'a> {
+        SetupIntentsGetIntentRequest {
+            inner: self.inner.get_intent_request(intent),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::setup_intents::SetupIntents::post_intent`].
+    pub fn post_intent(
+        &self,
+        intent: &str,
+    ) -> ClientResult<crate::Response<crate::types::SetupIntent>> {
+        self.runtime.block_on(self.inner.post_intent(intent))
+    }
+    /// The blocking version of [`crate::setup_intents::SetupIntents::post_intent_cancel`].
+    pub fn post_intent_cancel(
+        &self,
+        intent: &str,
+    ) -> ClientResult<crate::Response<crate::types::SetupIntent>> {
+        self.runtime.block_on(self.inner.post_intent_cancel(intent))
+    }
+    /// The blocking version of [`crate::setup_intents::SetupIntents::post_intent_confirm`].
+    pub fn post_intent_confirm(
+        &self,
+        intent: &str,
+    ) -> ClientResult<crate::Response<crate::types::SetupIntent>> {
+        self.runtime
+            .block_on(self.inner.post_intent_confirm(intent))
+    }
+    /// The blocking version of [`crate::setup_intents::SetupIntents::post_intent_verify_microdeposit`].
+    pub fn post_intent_verify_microdeposit(
+        &self,
+        intent: &str,
+    ) -> ClientResult<crate::Response<crate::types::SetupIntent>> {
+        self.runtime
+            .block_on(self.inner.post_intent_verify_microdeposit(intent))
+    }
+}
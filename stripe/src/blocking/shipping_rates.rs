@@ -0,0 +1,118 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`ShippingRates::get_page`], set with a builder.
+pub struct ShippingRatesGetPageRequest<'a> {
+    inner: crate::shipping_rates::ShippingRatesGetPageRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ShippingRatesGetPageRequest<'a> {
+    /// Sets the `active` query parameter.
+    pub fn active(mut self, active: bool) -> Self {
+        self.inner = self.inner.active(active);
+        self
+    }
+
+    /// Sets the `currency` query parameter.
+    pub fn currency(mut self, currency: &'a str) -> Self {
+        self.inner = self.inner.currency(currency);
+        self
+    }
+
+    /// Sets the `ending_before` query parameter.
+    pub fn ending_before(mut self, ending_before: &'a str) -> Self {
+        self.inner = self.inner.ending_before(ending_before);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.inner = self.inner.starting_after(starting_after);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::ShippingRate>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::shipping_rates::ShippingRates`].
+pub struct ShippingRates {
+    inner: crate::shipping_rates::ShippingRates,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl ShippingRates {
+    pub(crate) fn new(
+        inner: crate::shipping_rates::ShippingRates,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        ShippingRates { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::shipping_rates::ShippingRates::get_page`].
+    pub fn get_page(
+        &self,
+        active: bool,
+        _created: &str,
+        currency: &str,
+        ending_before: &str,
+        limit: i64,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ShippingRate>>> {
+        self.runtime.block_on(self.inner.get_page(
+            active,
+            _created,
+            currency,
+            ending_before,
+            limit,
+            starting_after,
+        ))
+    }
+    /// The blocking version of [`crate::shipping_rates::ShippingRates::get_page_request`].
+    pub fn get_page_request(&self) -> ShippingRatesGetPageRequest<'_> {
+        ShippingRatesGetPageRequest {
+            inner: self.inner.get_page_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::shipping_rates::ShippingRates::get_all`].
+    pub fn get_all(
+        &self,
+        active: bool,
+        _created: &str,
+        currency: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ShippingRate>>> {
+        self.runtime
+            .block_on(self.inner.get_all(active, _created, currency))
+    }
+    /// The blocking version of [`crate::shipping_rates::ShippingRates::post`].
+    pub fn post(&self) -> ClientResult<crate::Response<crate::types::ShippingRate>> {
+        self.runtime.block_on(self.inner.post())
+    }
+    /// The blocking version of [`crate::shipping_rates::ShippingRates::get_rate_token`].
+    pub fn get_rate_token(
+        &self,
+        shipping_rate_token: &str,
+    ) -> ClientResult<crate::Response<crate::types::ShippingRate>> {
+        self.runtime
+            .block_on(self.inner.get_rate_token(shipping_rate_token))
+    }
+    /// The blocking version of [`crate::shipping_rates::ShippingRates::post_rate_token`].
+    pub fn post_rate_token(
+        &self,
+        shipping_rate_token: &str,
+    ) -> ClientResult<crate::Response<crate::types::ShippingRate>> {
+        self.runtime
+            .block_on(self.inner.post_rate_token(shipping_rate_token))
+    }
+}
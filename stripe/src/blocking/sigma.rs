@@ -0,0 +1,82 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Sigma::get_scheduled_query_runs`], set with a builder.
+pub struct SigmaGetScheduledQueryRunsRequest<'a> {
+    inner: crate::sigma::SigmaGetScheduledQueryRunsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> SigmaGetScheduledQueryRunsRequest<'a> {
+    /// Sets the `ending_before` query parameter.
+    pub fn ending_before(mut self, ending_before: &'a str) -> Self {
+        self.inner = self.inner.ending_before(ending_before);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.inner = self.inner.starting_after(starting_after);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::ScheduledQueryRun>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::sigma::Sigma`].
+pub struct Sigma {
+    inner: crate::sigma::Sigma,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Sigma {
+    pub(crate) fn new(inner: crate::sigma::Sigma, runtime: Arc<tokio::runtime::Runtime>) -> Self {
+        Sigma { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::sigma::Sigma::get_scheduled_query_runs`].
+    pub fn get_scheduled_query_runs(
+        &self,
+        ending_before: &str,
+        limit: i64,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ScheduledQueryRun>>> {
+        self.runtime.block_on(self.inner.get_scheduled_query_runs(
+            ending_before,
+            limit,
+            starting_after,
+        ))
+    }
+    /// The blocking version of [`crate::sigma::Sigma::get_scheduled_query_runs_request`].
+    pub fn get_scheduled_query_runs_request(&self) -> SigmaGetScheduledQueryRunsRequest<'_> {
+        SigmaGetScheduledQueryRunsRequest {
+            inner: self.inner.get_scheduled_query_runs_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::sigma::Sigma::get_all_scheduled_query_runs`].
+    pub fn get_all_scheduled_query_runs(
+        &self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ScheduledQueryRun>>> {
+        self.runtime
+            .block_on(self.inner.get_all_scheduled_query_runs())
+    }
+    /// The blocking version of [`crate::sigma::Sigma::get_scheduled_query_runs_run`].
+    pub fn get_scheduled_query_runs_run(
+        &self,
+        scheduled_query_run: &str,
+    ) -> ClientResult<crate::Response<crate::types::ScheduledQueryRun>> {
+        self.runtime
+            .block_on(self.inner.get_scheduled_query_runs_run(scheduled_query_run))
+    }
+}
@@ -0,0 +1,128 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Skus::get_page`], set with a builder.
+pub struct SkusGetPageRequest<'a> {
+    inner: crate::skus::SkusGetPageRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> SkusGetPageRequest<'a> {
+    /// Sets the `active` query parameter.
+    pub fn active(mut self, active: bool) -> Self {
+        self.inner = self.inner.active(active);
+        self
+    }
+
+    /// Sets the `ending_before` query parameter.
+    pub fn ending_before(mut self, ending_before: &'a str) -> Self {
+        self.inner = self.inner.ending_before(ending_before);
+        self
+    }
+
+    /// Sets the `in_stock` query parameter.
+    pub fn in_stock(mut self, in_stock: bool) -> Self {
+        self.inner = self.inner.in_stock(in_stock);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `product` query parameter.
+    pub fn product(mut self, product: &'a str) -> Self {
+        self.inner = self.inner.product(product);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.inner = self.inner.starting_after(starting_after);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Sku>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::skus::Skus`].
+pub struct Skus {
+    inner: crate::skus::Skus,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Skus {
+    pub(crate) fn new(inner: crate::skus::Skus, runtime: Arc<tokio::runtime::Runtime>) -> Self {
+        Skus { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::skus::Skus::get_page`].
+    pub fn get_page(
+        &self,
+        active: bool,
+        _attributes: &str,
+        ending_before: &str,
+        _ids: &[String],
+        in_stock: bool,
+        limit: i64,
+        product: &str,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Sku>>> {
+        self.runtime.block_on(self.inner.get_page(
+            active,
+            _attributes,
+            ending_before,
+            _ids,
+            in_stock,
+            limit,
+            product,
+            starting_after,
+        ))
+    }
+    /// The blocking version of [`crate::skus::Skus::get_page_request`].
+    pub fn get_page_request(&self) -> SkusGetPageRequest<'_> {
+        SkusGetPageRequest {
+            inner: self.inner.get_page_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::skus::Skus::get_all`].
+    pub fn get_all(
+        &self,
+        active: bool,
+        _attributes: &str,
+        _ids: &[String],
+        in_stock: bool,
+        product: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Sku>>> {
+        self.runtime.block_on(
+            self.inner
+                .get_all(active, _attributes, _ids, in_stock, product),
+        )
+    }
+    /// The blocking version of [`crate::skus::Skus::post`].
+    pub fn post(&self) -> ClientResult<crate::Response<crate::types::Sku>> {
+        self.runtime.block_on(self.inner.post())
+    }
+    /// The blocking version of [`crate::skus::Skus::get`].
+    pub fn get(
+        &self,
+        id: &str,
+    ) -> ClientResult<crate::Response<crate::types::GetSkusResponseAnyOf>> {
+        self.runtime.block_on(self.inner.get(id))
+    }
+    /// The blocking version of [`crate::skus::Skus::post_skus`].
+    pub fn post_skus(&self, id: &str) -> ClientResult<crate::Response<crate::types::Sku>> {
+        self.runtime.block_on(self.inner.post_skus(id))
+    }
+    /// The blocking version of [`crate::skus::Skus::delete`].
+    pub fn delete(&self, id: &str) -> ClientResult<crate::Response<crate::types::DeletedSku>> {
+        self.runtime.block_on(self.inner.delete(id))
+    }
+}
@@ -0,0 +1,157 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Sources::get`], set with a builder.
+pub struct SourcesGetRequest<'a> {
+    inner: crate::sources::SourcesGetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> SourcesGetRequest<'a> {
+    /// Sets the `client_secret` query parameter.
+    pub fn client_secret(mut self, client_secret: &'a str) -> Self {
+        self.inner = self.inner.client_secret(client_secret);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::SourceData>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Sources::get_transactions`], set with a builder.
+pub struct SourcesGetTransactionsRequest<'a> {
+    inner: crate::sources::SourcesGetTransactionsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> SourcesGetTransactionsRequest<'a> {
+    /// Sets the `ending_before` query parameter.
+    pub fn ending_before(mut self, ending_before: &'a str) -> Self {
+        self.inner = self.inner.ending_before(ending_before);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.inner = self.inner.starting_after(starting_after);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::SourceTransaction>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::sources::Sources`].
+pub struct Sources {
+    inner: crate::sources::Sources,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Sources {
+    pub(crate) fn new(
+        inner: crate::sources::Sources,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Sources { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::sources::Sources::post`].
+    pub fn post(&self) -> ClientResult<crate::Response<crate::types::SourceData>> {
+        self.runtime.block_on(self.inner.post())
+    }
+    /// The blocking version of [`crate::sources::Sources::get`].
+    pub fn get(
+        &self,
+        client_secret: &str,
+        source: &str,
+    ) -> ClientResult<crate::Response<crate::types::SourceData>> {
+        self.runtime.block_on(self.inner.get(client_secret, source))
+    }
+    /// The blocking version of [`crate::sources::Sources::get_request`].
+    pub fn get_request<'a>(&'a self, source: &'a str) -> SourcesGetRequest<'a> {
+        SourcesGetRequest {
+            inner: self.inner.get_request(source),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::sources::Sources::post_sources`].
+    pub fn post_sources(
+        &self,
+        source: &str,
+    ) -> ClientResult<crate::Response<crate::types::SourceData>> {
+        self.runtime.block_on(self.inner.post_sources(source))
+    }
+    /// The blocking version of [`crate::sources::Sources::get_mandate_notifications_notification`].
+    pub fn get_mandate_notifications_notification(
+        &self,
+        mandate_notification: &str,
+        source: &str,
+    ) -> ClientResult<crate::Response<crate::types::SourceMandateNotification>> {
+        self.runtime.block_on(
+            self.inner
+                .get_mandate_notifications_notification(mandate_notification, source),
+        )
+    }
+    /// The blocking version of [`crate::sources::Sources::get_transactions`].
+    pub fn get_transactions(
+        &self,
+        ending_before: &str,
+        limit: i64,
+        source: &str,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::SourceTransaction>>> {
+        self.runtime.block_on(self.inner.get_transactions(
+            ending_before,
+            limit,
+            source,
+            starting_after,
+        ))
+    }
+    /// The blocking version of [`crate::sources::Sources::get_transactions_request`].
+    pub fn get_transactions_request<'a>(
+        &'a self,
+        source: &'a str,
+    ) -> SourcesGetTransactionsRequest<'a> {
+        SourcesGetTransactionsRequest {
+            inner: self.inner.get_transactions_request(source),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::sources::Sources::get_all_transactions`].
+    pub fn get_all_transactions(
+        &self,
+        source: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::SourceTransaction>>> {
+        self.runtime
+            .block_on(self.inner.get_all_transactions(source))
+    }
+    /// The blocking version of [`crate::sources::Sources::get_transactions_transaction`].
+    pub fn get_transactions_transaction(
+        &self,
+        source: &str,
+        source_transaction: &str,
+    ) -> ClientResult<crate::Response<crate::types::SourceTransaction>> {
+        self.runtime.block_on(
+            self.inner
+                .get_transactions_transaction(source, source_transaction),
+        )
+    }
+    /// The blocking version of [`crate::sources::Sources::post_verify`].
+    pub fn post_verify(
+        &self,
+        source: &str,
+    ) -> ClientResult<crate::Response<crate::types::SourceData>> {
+        self.runtime.block_on(self.inner.post_verify(source))
+    }
+}
@@ -0,0 +1,184 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`SubscriptionItems::get_page`], set with a builder.
+pub struct SubscriptionItemsGetPageRequest<'a> {
+    inner: crate::subscription_items::SubscriptionItemsGetPageRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> SubscriptionItemsGetPageRequest<'a> {
+    /// Sets the `ending_before` query parameter.
+    pub fn ending_before(mut self, ending_before: &'a str) -> Self {
+        self.inner = self.inner.ending_before(ending_before);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.inner = self.inner.starting_after(starting_after);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::SubscriptionItem>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`SubscriptionItems::get_item_usage_record_summaries`], set with a builder.
+pub struct SubscriptionItemsGetItemUsageRecordSummariesRequest<'a> {
+    inner: crate::subscription_items::SubscriptionItemsGetItemUsageRecordSummariesRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> SubscriptionItemsGetItemUsageRecordSummariesRequest<'a> {
+    /// Sets the `ending_before` query parameter.
+    pub fn ending_before(mut self, ending_before: &'a str) -> Self {
+        self.inner = self.inner.ending_before(ending_before);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.inner = self.inner.starting_after(starting_after);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::UsageRecordSummary>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::subscription_items::SubscriptionItems`].
+pub struct SubscriptionItems {
+    inner: crate::subscription_items::SubscriptionItems,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl SubscriptionItems {
+    pub(crate) fn new(
+        inner: crate::subscription_items::SubscriptionItems,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        SubscriptionItems { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::subscription_items::SubscriptionItems::get_page`].
+    pub fn get_page(
+        &self,
+        ending_before: &str,
+        limit: i64,
+        starting_after: &str,
+        subscription: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::SubscriptionItem>>> {
+        self.runtime.block_on(self.inner.get_page(
+            ending_before,
+            limit,
+            starting_after,
+            subscription,
+        ))
+    }
+    /// The blocking version of [`crate::subscription_items::SubscriptionItems::get_page_request`].
+    pub fn get_page_request<'a>(
+        &'a self,
+        subscription: &'a str,
+    ) -> SubscriptionItemsGetPageRequest<'a> {
+        SubscriptionItemsGetPageRequest {
+            inner: self.inner.get_page_request(subscription),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::subscription_items::SubscriptionItems::get_all`].
+    pub fn get_all(
+        &self,
+        subscription: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::SubscriptionItem>>> {
+        self.runtime.block_on(self.inner.get_all(subscription))
+    }
+    /// The blocking version of [`crate::subscription_items::SubscriptionItems::post`].
+    pub fn post(&self) -> ClientResult<crate::Response<crate::types::SubscriptionItem>> {
+        self.runtime.block_on(self.inner.post())
+    }
+    /// The blocking version of [`crate::subscription_items::SubscriptionItems::get_item`].
+    pub fn get_item(
+        &self,
+        item: &str,
+    ) -> ClientResult<crate::Response<crate::types::SubscriptionItem>> {
+        self.runtime.block_on(self.inner.get_item(item))
+    }
+    /// The blocking version of [`crate::subscription_items::SubscriptionItems::post_item`].
+    pub fn post_item(
+        &self,
+        item: &str,
+    ) -> ClientResult<crate::Response<crate::types::SubscriptionItem>> {
+        self.runtime.block_on(self.inner.post_item(item))
+    }
+    /// The blocking version of [`crate::subscription_items::SubscriptionItems::delete_item`].
+    pub fn delete_item(
+        &self,
+        item: &str,
+    ) -> ClientResult<crate::Response<crate::types::DeletedSubscriptionItem>> {
+        self.runtime.block_on(self.inner.delete_item(item))
+    }
+    /// The blocking version of [`crate::subscription_items::SubscriptionItems::get_item_usage_record_summaries`].
+    pub fn get_item_usage_record_summaries(
+        &self,
+        ending_before: &str,
+        limit: i64,
+        starting_after: &str,
+        subscription_item: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::UsageRecordSummary>>> {
+        self.runtime
+            .block_on(self.inner.get_item_usage_record_summaries(
+                ending_before,
+                limit,
+                starting_after,
+                subscription_item,
+            ))
+    }
+    /// The blocking version of [`crate::subscription_items::SubscriptionItems::get_item_usage_record_summaries_request`].
+    pub fn get_item_usage_record_summaries_request<'a>(
+        &'a self,
+        subscription_item: &'a str,
+    ) -> SubscriptionItemsGetItemUsageRecordSummariesRequest<'a> {
+        SubscriptionItemsGetItemUsageRecordSummariesRequest {
+            inner: self
+                .inner
+                .get_item_usage_record_summaries_request(subscription_item),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::subscription_items::SubscriptionItems::get_all_item_usage_record_summaries`].
+    pub fn get_all_item_usage_record_summaries(
+        &self,
+        subscription_item: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::UsageRecordSummary>>> {
+        self.runtime.block_on(
+            self.inner
+                .get_all_item_usage_record_summaries(subscription_item),
+        )
+    }
+    /// The blocking version of [`crate::subscription_items::SubscriptionItems::post_item_usage_record`].
+    pub fn post_item_usage_record(
+        &self,
+        subscription_item: &str,
+    ) -> ClientResult<crate::Response<crate::types::UsageRecord>> {
+        self.runtime
+            .block_on(self.inner.post_item_usage_record(subscription_item))
+    }
+}
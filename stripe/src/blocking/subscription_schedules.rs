@@ -0,0 +1,147 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`SubscriptionSchedules::get_page`], set with a builder.
+pub struct SubscriptionSchedulesGetPageRequest<'a> {
+    inner: crate::subscription_schedules::SubscriptionSchedulesGetPageRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> SubscriptionSchedulesGetPageRequest<'a> {
+    /// Sets the `customer` query parameter.
+    pub fn customer(mut self, customer: &'a str) -> Self {
+        self.inner = self.inner.customer(customer);
+        self
+    }
+
+    /// Sets the `ending_before` query parameter.
+    pub fn ending_before(mut self, ending_before: &'a str) -> Self {
+        self.inner = self.inner.ending_before(ending_before);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `scheduled` query parameter.
+    pub fn scheduled(mut self, scheduled: bool) -> Self {
+        self.inner = self.inner.scheduled(scheduled);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.inner = self.inner.starting_after(starting_after);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::SubscriptionSchedule>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::subscription_schedules::SubscriptionSchedules`].
+pub struct SubscriptionSchedules {
+    inner: crate::subscription_schedules::SubscriptionSchedules,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl SubscriptionSchedules {
+    pub(crate) fn new(
+        inner: crate::subscription_schedules::SubscriptionSchedules,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        SubscriptionSchedules { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::subscription_schedules::SubscriptionSchedules::get_page`].
+    pub fn get_page(
+        &self,
+        _canceled_at: &str,
+        _completed_at: &str,
+        _created: &str,
+        customer: &str,
+        ending_before: &str,
+        limit: i64,
+        _released_at: &str,
+        scheduled: bool,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::SubscriptionSchedule>>> {
+        self.runtime.block_on(self.inner.get_page(
+            _canceled_at,
+            _completed_at,
+            _created,
+            customer,
+            ending_before,
+            limit,
+            _released_at,
+            scheduled,
+            starting_after,
+        ))
+    }
+    /// The blocking version of [`crate::subscription_schedules::SubscriptionSchedules::get_page_request`].
+    pub fn get_page_request(&self) -> SubscriptionSchedulesGetPageRequest<'_> {
+        SubscriptionSchedulesGetPageRequest {
+            inner: self.inner.get_page_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::subscription_schedules::SubscriptionSchedules::get_all`].
+    pub fn get_all(
+        &self,
+        _canceled_at: &str,
+        _completed_at: &str,
+        _created: &str,
+        customer: &str,
+        _released_at: &str,
+        scheduled: bool,
+    ) -> ClientResult<crate::Response<Vec<crate::types::SubscriptionSchedule>>> {
+        self.runtime.block_on(self.inner.get_all(
+            _canceled_at,
+            _completed_at,
+            _created,
+            customer,
+            _released_at,
+            scheduled,
+        ))
+    }
+    /// The blocking version of [`crate::subscription_schedules::SubscriptionSchedules::post`].
+    pub fn post(&self) -> ClientResult<crate::Response<crate::types::SubscriptionSchedule>> {
+        self.runtime.block_on(self.inner.post())
+    }
+    /// The blocking version of [`crate::subscription_schedules::SubscriptionSchedules::get_schedule`].
+    pub fn get_schedule(
+        &self,
+        schedule: &str,
+    ) -> ClientResult<crate::Response<crate::types::SubscriptionSchedule>> {
+        self.runtime.block_on(self.inner.get_schedule(schedule))
+    }
+    /// The blocking version of [`crate::subscription_schedules::SubscriptionSchedules::post_schedule`].
+    pub fn post_schedule(
+        &self,
+        schedule: &str,
+    ) -> ClientResult<crate::Response<crate::types::SubscriptionSchedule>> {
+        self.runtime.block_on(self.inner.post_schedule(schedule))
+    }
+    /// The blocking version of [`crate::subscription_schedules::SubscriptionSchedules::post_schedule_cancel`].
+    pub fn post_schedule_cancel(
+        &self,
+        schedule: &str,
+    ) -> ClientResult<crate::Response<crate::types::SubscriptionSchedule>> {
+        self.runtime
+            .block_on(self.inner.post_schedule_cancel(schedule))
+    }
+    /// The blocking version of [`crate::subscription_schedules::SubscriptionSchedules::post_schedule_release`].
+    pub fn post_schedule_release(
+        &self,
+        schedule: &str,
+    ) -> ClientResult<crate::Response<crate::types::SubscriptionSchedule>> {
+        self.runtime
+            .block_on(self.inner.post_schedule_release(schedule))
+    }
+}
@@ -0,0 +1,224 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Subscriptions::get_page`], set with a builder.
+pub struct SubscriptionsGetPageRequest<'a> {
+    inner: crate::subscriptions::SubscriptionsGetPageRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> SubscriptionsGetPageRequest<'a> {
+    /// Sets the `collection_method` query parameter.
+    pub fn collection_method(mut self, collection_method: crate::types::CollectionMethod) -> Self {
+        self.inner = self.inner.collection_method(collection_method);
+        self
+    }
+
+    /// Sets the `customer` query parameter.
+    pub fn customer(mut self, customer: &'a str) -> Self {
+        self.inner = self.inner.customer(customer);
+        self
+    }
+
+    /// Sets the `ending_before` query parameter.
+    pub fn ending_before(mut self, ending_before: &'a str) -> Self {
+        self.inner = self.inner.ending_before(ending_before);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `price` query parameter.
+    pub fn price(mut self, price: &'a str) -> Self {
+        self.inner = self.inner.price(price);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.inner = self.inner.starting_after(starting_after);
+        self
+    }
+
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: crate::types::GetSubscriptionsStatus) -> Self {
+        self.inner = self.inner.status(status);
+        self
+    }
+
+    /// Sets the `test_clock` query parameter.
+    pub fn test_clock(mut self, test_clock: &'a str) -> Self {
+        self.inner = self.inner.test_clock(test_clock);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Subscription>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Subscriptions::get_search`], set with a builder.
+pub struct SubscriptionsGetSearchRequest<'a> {
+    inner: crate::subscriptions::SubscriptionsGetSearchRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> SubscriptionsGetSearchRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: &'a str) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Charge>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::subscriptions::Subscriptions`].
+pub struct Subscriptions {
+    inner: crate::subscriptions::Subscriptions,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Subscriptions {
+    pub(crate) fn new(
+        inner: crate::subscriptions::Subscriptions,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Subscriptions { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::subscriptions::Subscriptions::get_page`].
+    pub fn get_page(
+        &self,
+        collection_method: crate::types::CollectionMethod,
+        _created: &str,
+        _current_period_end: &str,
+        _current_period_start: &str,
+        customer: &str,
+        ending_before: &str,
+        limit: i64,
+        price: &str,
+        starting_after: &str,
+        status: crate::types::GetSubscriptionsStatus,
+        test_clock: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Subscription>>> {
+        self.runtime.block_on(self.inner.get_page(
+            collection_method,
+            _created,
+            _current_period_end,
+            _current_period_start,
+            customer,
+            ending_before,
+            limit,
+            price,
+            starting_after,
+            status,
+            test_clock,
+        ))
+    }
+    /// The blocking version of [`crate::subscriptions::Subscriptions::get_page_request`].
+    pub fn get_page_request(&self) -> SubscriptionsGetPageRequest<'_> {
+        SubscriptionsGetPageRequest {
+            inner: self.inner.get_page_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::subscriptions::Subscriptions::get_all`].
+    pub fn get_all(
+        &self,
+        collection_method: crate::types::CollectionMethod,
+        _created: &str,
+        _current_period_end: &str,
+        _current_period_start: &str,
+        customer: &str,
+        price: &str,
+        status: crate::types::GetSubscriptionsStatus,
+        test_clock: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Subscription>>> {
+        self.runtime.block_on(self.inner.get_all(
+            collection_method,
+            _created,
+            _current_period_end,
+            _current_period_start,
+            customer,
+            price,
+            status,
+            test_clock,
+        ))
+    }
+    /// The blocking version of [`crate::subscriptions::Subscriptions::post`].
+    pub fn post(&self) -> ClientResult<crate::Response<crate::types::Subscription>> {
+        self.runtime.block_on(self.inner.post())
+    }
+    /// The blocking version of [`crate::subscriptions::Subscriptions::get_search`].
+    pub fn get_search(
+        &self,
+        limit: i64,
+        page: &str,
+        query: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Charge>>> {
+        self.runtime
+            .block_on(self.inner.get_search(limit, page, query))
+    }
+    /// The blocking version of [`crate::subscriptions::Subscriptions::get_search_request`].
+    pub fn get_search_request<'a>(&'a self, query: &'a str) -> SubscriptionsGetSearchRequest<'a> {
+        SubscriptionsGetSearchRequest {
+            inner: self.inner.get_search_request(query),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::subscriptions::Subscriptions::get_all_search`].
+    pub fn get_all_search(
+        &self,
+        query: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Charge>>> {
+        self.runtime.block_on(self.inner.get_all_search(query))
+    }
+    /// The blocking version of [`crate::subscriptions::Subscriptions::get_exposed`].
+    pub fn get_exposed(
+        &self,
+        subscription_exposed_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Subscription>> {
+        self.runtime
+            .block_on(self.inner.get_exposed(subscription_exposed_id))
+    }
+    /// The blocking version of [`crate::subscriptions::Subscriptions::post_exposed`].
+    pub fn post_exposed(
+        &self,
+        subscription_exposed_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Subscription>> {
+        self.runtime
+            .block_on(self.inner.post_exposed(subscription_exposed_id))
+    }
+    /// The blocking version of [`crate::subscriptions::Subscriptions::delete_exposed`].
+    pub fn delete_exposed(
+        &self,
+        subscription_exposed_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Subscription>> {
+        self.runtime
+            .block_on(self.inner.delete_exposed(subscription_exposed_id))
+    }
+    /// The blocking version of [`crate::subscriptions::Subscriptions::delete_exposed_discount`].
+    pub fn delete_exposed_discount(
+        &self,
+        subscription_exposed_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::DeletedDiscount>> {
+        self.runtime
+            .block_on(self.inner.delete_exposed_discount(subscription_exposed_id))
+    }
+}
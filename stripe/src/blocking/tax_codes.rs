@@ -0,0 +1,75 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`TaxCodes::get_page`], set with a builder.
+pub struct TaxCodesGetPageRequest<'a> {
+    inner: crate::tax_codes::TaxCodesGetPageRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> TaxCodesGetPageRequest<'a> {
+    /// Sets the `ending_before` query parameter.
+    pub fn ending_before(mut self, ending_before: &'a str) -> Self {
+        self.inner = self.inner.ending_before(ending_before);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.inner = self.inner.starting_after(starting_after);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::TaxCode>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::tax_codes::TaxCodes`].
+pub struct TaxCodes {
+    inner: crate::tax_codes::TaxCodes,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl TaxCodes {
+    pub(crate) fn new(
+        inner: crate::tax_codes::TaxCodes,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        TaxCodes { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::tax_codes::TaxCodes::get_page`].
+    pub fn get_page(
+        &self,
+        ending_before: &str,
+        limit: i64,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::TaxCode>>> {
+        self.runtime
+            .block_on(self.inner.get_page(ending_before, limit, starting_after))
+    }
+    /// The blocking version of [`crate::tax_codes::TaxCodes::get_page_request`].
+    pub fn get_page_request(&self) -> TaxCodesGetPageRequest<'_> {
+        TaxCodesGetPageRequest {
+            inner: self.inner.get_page_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::tax_codes::TaxCodes::get_all`].
+    pub fn get_all(&self) -> ClientResult<crate::Response<Vec<crate::types::TaxCode>>> {
+        self.runtime.block_on(self.inner.get_all())
+    }
+    /// The blocking version of [`crate::tax_codes::TaxCodes::get`].
+    pub fn get(&self, id: &str) -> ClientResult<crate::Response<crate::types::TaxCode>> {
+        self.runtime.block_on(self.inner.get(id))
+    }
+}
@@ -0,0 +1,113 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`TaxRates::get_page`], set with a builder.
+pub struct TaxRatesGetPageRequest<'a> {
+    inner: crate::tax_rates::TaxRatesGetPageRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> TaxRatesGetPageRequest<'a> {
+    /// Sets the `active` query parameter.
+    pub fn active(mut self, active: bool) -> Self {
+        self.inner = self.inner.active(active);
+        self
+    }
+
+    /// Sets the `ending_before` query parameter.
+    pub fn ending_before(mut self, ending_before: &'a str) -> Self {
+        self.inner = self.inner.ending_before(ending_before);
+        self
+    }
+
+    /// Sets the `inclusive` query parameter.
+    pub fn inclusive(mut self, inclusive: bool) -> Self {
+        self.inner = self.inner.inclusive(inclusive);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.inner = self.inner.starting_after(starting_after);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::TaxRate>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::tax_rates::TaxRates`].
+pub struct TaxRates {
+    inner: crate::tax_rates::TaxRates,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl TaxRates {
+    pub(crate) fn new(
+        inner: crate::tax_rates::TaxRates,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        TaxRates { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::tax_rates::TaxRates::get_page`].
+    pub fn get_page(
+        &self,
+        active: bool,
+        _created: &str,
+        ending_before: &str,
+        inclusive: bool,
+        limit: i64,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::TaxRate>>> {
+        self.runtime.block_on(self.inner.get_page(
+            active,
+            _created,
+            ending_before,
+            inclusive,
+            limit,
+            starting_after,
+        ))
+    }
+    /// The blocking version of [`crate::tax_rates::TaxRates::get_page_request`].
+    pub fn get_page_request(&self) -> TaxRatesGetPageRequest<'_> {
+        TaxRatesGetPageRequest {
+            inner: self.inner.get_page_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::tax_rates::TaxRates::get_all`].
+    pub fn get_all(
+        &self,
+        active: bool,
+        _created: &str,
+        inclusive: bool,
+    ) -> ClientResult<crate::Response<Vec<crate::types::TaxRate>>> {
+        self.runtime
+            .block_on(self.inner.get_all(active, _created, inclusive))
+    }
+    /// The blocking version of [`crate::tax_rates::TaxRates::post`].
+    pub fn post(&self) -> ClientResult<crate::Response<crate::types::TaxRate>> {
+        self.runtime.block_on(self.inner.post())
+    }
+    /// The blocking version of [`crate::tax_rates::TaxRates::get_rate`].
+    pub fn get_rate(&self, tax_rate: &str) -> ClientResult<crate::Response<crate::types::TaxRate>> {
+        self.runtime.block_on(self.inner.get_rate(tax_rate))
+    }
+    /// The blocking version of [`crate::tax_rates::TaxRates::post_rate`].
+    pub fn post_rate(
+        &self,
+        tax_rate: &str,
+    ) -> ClientResult<crate::Response<crate::types::TaxRate>> {
+        self.runtime.block_on(self.inner.post_rate(tax_rate))
+    }
+}
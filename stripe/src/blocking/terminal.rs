@@ -0,0 +1,255 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Terminal::get_locations`], set with a builder.
+pub struct TerminalGetLocationsRequest<'a> {
+    inner: crate::terminal::TerminalGetLocationsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> TerminalGetLocationsRequest<'a> {
+    /// Sets the `ending_before` query parameter.
+    pub fn ending_before(mut self, ending_before: &'a str) -> Self {
+        self.inner = self.inner.ending_before(ending_before);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.inner = self.inner.starting_after(starting_after);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::TerminalLocation>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Terminal::get_readers`], set with a builder.
+pub struct TerminalGetReadersRequest<'a> {
+    inner: crate::terminal::TerminalGetReadersRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> TerminalGetReadersRequest<'a> {
+    /// Sets the `device_type` query parameter.
+    pub fn device_type(mut self, device_type: crate::types::DeviceType) -> Self {
+        self.inner = self.inner.device_type(device_type);
+        self
+    }
+
+    /// Sets the `ending_before` query parameter.
+    pub fn ending_before(mut self, ending_before: &'a str) -> Self {
+        self.inner = self.inner.ending_before(ending_before);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `location` query parameter.
+    pub fn location(mut self, location: &'a str) -> Self {
+        self.inner = self.inner.location(location);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.inner = self.inner.starting_after(starting_after);
+        self
+    }
+
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: crate::types::CustomerAcceptanceType) -> Self {
+        self.inner = self.inner.status(status);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::TerminalReader>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::terminal::Terminal`].
+pub struct Terminal {
+    inner: crate::terminal::Terminal,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Terminal {
+    pub(crate) fn new(
+        inner: crate::terminal::Terminal,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Terminal { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::terminal::Terminal::post_connection_token`].
+    pub fn post_connection_token(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::TerminalConnectionToken>> {
+        self.runtime.block_on(self.inner.post_connection_token())
+    }
+    /// The blocking version of [`crate::terminal::Terminal::get_locations`].
+    pub fn get_locations(
+        &self,
+        ending_before: &str,
+        limit: i64,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::TerminalLocation>>> {
+        self.runtime.block_on(
+            self.inner
+                .get_locations(ending_before, limit, starting_after),
+        )
+    }
+    /// The blocking version of [`crate::terminal::Terminal::get_locations_request`].
+    pub fn get_locations_request(&self) -> TerminalGetLocationsRequest<'_> {
+        TerminalGetLocationsRequest {
+            inner: self.inner.get_locations_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::terminal::Terminal::get_all_locations`].
+    pub fn get_all_locations(
+        &self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::TerminalLocation>>> {
+        self.runtime.block_on(self.inner.get_all_locations())
+    }
+    /// The blocking version of [`crate::terminal::Terminal::post_location`].
+    pub fn post_location(&self) -> ClientResult<crate::Response<crate::types::TerminalLocation>> {
+        self.runtime.block_on(self.inner.post_location())
+    }
+    /// The blocking version of [`crate::terminal::Terminal::get_locations_location`].
+    pub fn get_locations_location(
+        &self,
+        location: &str,
+    ) -> ClientResult<crate::Response<crate::types::GetTerminalLocationResponseAnyOf>> {
+        self.runtime
+            .block_on(self.inner.get_locations_location(location))
+    }
+    /// The blocking version of [`crate::terminal::Terminal::post_locations_location`].
+    pub fn post_locations_location(
+        &self,
+        location: &str,
+    ) -> ClientResult<crate::Response<crate::types::GetTerminalLocationResponseAnyOf>> {
+        self.runtime
+            .block_on(self.inner.post_locations_location(location))
+    }
+    /// The blocking version of [`crate::terminal::Terminal::delete_locations_location`].
+    pub fn delete_locations_location(
+        &self,
+        location: &str,
+    ) -> ClientResult<crate::Response<crate::types::DeletedTerminalLocation>> {
+        self.runtime
+            .block_on(self.inner.delete_locations_location(location))
+    }
+    /// The blocking version of [`crate::terminal::Terminal::get_readers`].
+    pub fn get_readers(
+        &self,
+        device_type: crate::types::DeviceType,
+        ending_before: &str,
+        limit: i64,
+        location: &str,
+        starting_after: &str,
+        status: crate::types::CustomerAcceptanceType,
+    ) -> ClientResult<crate::Response<Vec<crate::types::TerminalReader>>> {
+        self.runtime.block_on(self.inner.get_readers(
+            device_type,
+            ending_before,
+            limit,
+            location,
+            starting_after,
+            status,
+        ))
+    }
+    /// The blocking version of [`crate::terminal::Terminal::get_readers_request`].
+    pub fn get_readers_request(&self) -> TerminalGetReadersRequest<'_> {
+        TerminalGetReadersRequest {
+            inner: self.inner.get_readers_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::terminal::Terminal::get_all_readers`].
+    pub fn get_all_readers(
+        &self,
+        device_type: crate::types::DeviceType,
+        location: &str,
+        status: crate::types::CustomerAcceptanceType,
+    ) -> ClientResult<crate::Response<Vec<crate::types::TerminalReader>>> {
+        self.runtime
+            .block_on(self.inner.get_all_readers(device_type, location, status))
+    }
+    /// The blocking version of [`crate::terminal::Terminal::post_reader`].
+    pub fn post_reader(&self) -> ClientResult<crate::Response<crate::types::TerminalReader>> {
+        self.runtime.block_on(self.inner.post_reader())
+    }
+    /// The blocking version of [`crate::terminal::Terminal::get_readers_reader`].
+    pub fn get_readers_reader(
+        &self,
+        reader: &str,
+    ) -> ClientResult<crate::Response<crate::types::GetTerminalReadersReaderResponseAnyOf>> {
+        self.runtime.block_on(self.inner.get_readers_reader(reader))
+    }
+    /// The blocking version of [`crate::terminal::Terminal::post_readers_reader`].
+    pub fn post_readers_reader(
+        &self,
+        reader: &str,
+    ) -> ClientResult<crate::Response<crate::types::GetTerminalReadersReaderResponseAnyOf>> {
+        self.runtime
+            .block_on(self.inner.post_readers_reader(reader))
+    }
+    /// The blocking version of [`crate::terminal::Terminal::delete_readers_reader`].
+    pub fn delete_readers_reader(
+        &self,
+        reader: &str,
+    ) -> ClientResult<crate::Response<crate::types::DeletedTerminalReader>> {
+        self.runtime
+            .block_on(self.inner.delete_readers_reader(reader))
+    }
+    /// The blocking version of [`crate::terminal::Terminal::post_readers_reader_cancel_action`].
+    pub fn post_readers_reader_cancel_action(
+        &self,
+        reader: &str,
+    ) -> ClientResult<crate::Response<crate::types::TerminalReader>> {
+        self.runtime
+            .block_on(self.inner.post_readers_reader_cancel_action(reader))
+    }
+    /// The blocking version of [`crate::terminal::Terminal::post_readers_reader_process_payment_intent`].
+    pub fn post_readers_reader_process_payment_intent(
+        &self,
+        reader: &str,
+    ) -> ClientResult<crate::Response<crate::types::TerminalReader>> {
+        self.runtime.block_on(
+            self.inner
+                .post_readers_reader_process_payment_intent(reader),
+        )
+    }
+    /// The blocking version of [`crate::terminal::Terminal::post_readers_reader_process_setup_intent`].
+    pub fn post_readers_reader_process_setup_intent(
+        &self,
+        reader: &str,
+    ) -> ClientResult<crate::Response<crate::types::TerminalReader>> {
+        self.runtime
+            .block_on(self.inner.post_readers_reader_process_setup_intent(reader))
+    }
+    /// The blocking version of [`crate::terminal::Terminal::post_readers_reader_set_display`].
+    pub fn post_readers_reader_set_display(
+        &self,
+        reader: &str,
+    ) -> ClientResult<crate::Response<crate::types::TerminalReader>> {
+        self.runtime
+            .block_on(self.inner.post_readers_reader_set_display(reader))
+    }
+}
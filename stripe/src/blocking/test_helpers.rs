@@ -0,0 +1,109 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`TestHelpers::get_clocks`], set with a builder.
+pub struct TestHelpersGetClocksRequest<'a> {
+    inner: crate::test_helpers::TestHelpersGetClocksRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> TestHelpersGetClocksRequest<'a> {
+    /// Sets the `ending_before` query parameter.
+    pub fn ending_before(mut self, ending_before: &'a str) -> Self {
+        self.inner = self.inner.ending_before(ending_before);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.inner = self.inner.starting_after(starting_after);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::TestClock>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::test_helpers::TestHelpers`].
+pub struct TestHelpers {
+    inner: crate::test_helpers::TestHelpers,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl TestHelpers {
+    pub(crate) fn new(
+        inner: crate::test_helpers::TestHelpers,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        TestHelpers { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::test_helpers::TestHelpers::post_terminal_readers_reader_present_payment_method`].
+    pub fn post_terminal_readers_reader_present_payment_method(
+        &self,
+        reader: &str,
+    ) -> ClientResult<crate::Response<crate::types::TerminalReader>> {
+        self.runtime.block_on(
+            self.inner
+                .post_terminal_readers_reader_present_payment_method(reader),
+        )
+    }
+    /// The blocking version of [`crate::test_helpers::TestHelpers::get_clocks`].
+    pub fn get_clocks(
+        &self,
+        ending_before: &str,
+        limit: i64,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::TestClock>>> {
+        self.runtime
+            .block_on(self.inner.get_clocks(ending_before, limit, starting_after))
+    }
+    /// The blocking version of [`crate::test_helpers::TestHelpers::get_clocks_request`].
+    pub fn get_clocks_request(&self) -> TestHelpersGetClocksRequest<'_> {
+        TestHelpersGetClocksRequest {
+            inner: self.inner.get_clocks_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::test_helpers::TestHelpers::get_all_clocks`].
+    pub fn get_all_clocks(&self) -> ClientResult<crate::Response<Vec<crate::types::TestClock>>> {
+        self.runtime.block_on(self.inner.get_all_clocks())
+    }
+    /// The blocking version of [`crate::test_helpers::TestHelpers::post_clock`].
+    pub fn post_clock(&self) -> ClientResult<crate::Response<crate::types::TestClock>> {
+        self.runtime.block_on(self.inner.post_clock())
+    }
+    /// The blocking version of [`crate::test_helpers::TestHelpers::get_clocks_clock`].
+    pub fn get_clocks_clock(
+        &self,
+        test_clock: &str,
+    ) -> ClientResult<crate::Response<crate::types::TestClock>> {
+        self.runtime
+            .block_on(self.inner.get_clocks_clock(test_clock))
+    }
+    /// The blocking version of [`crate::test_helpers::TestHelpers::delete_clocks_clock`].
+    pub fn delete_clocks_clock(
+        &self,
+        test_clock: &str,
+    ) -> ClientResult<crate::Response<crate::types::DeletedTestClock>> {
+        self.runtime
+            .block_on(self.inner.delete_clocks_clock(test_clock))
+    }
+    /// The blocking version of [`crate::test_helpers::TestHelpers::post_clocks_clock_advance`].
+    pub fn post_clocks_clock_advance(
+        &self,
+        test_clock: &str,
+    ) -> ClientResult<crate::Response<crate::types::TestClock>> {
+        self.runtime
+            .block_on(self.inner.post_clocks_clock_advance(test_clock))
+    }
+}
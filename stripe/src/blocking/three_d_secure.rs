@@ -0,0 +1,31 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::three_d_secure::ThreeDSecure`].
+pub struct ThreeDSecure {
+    inner: crate::three_d_secure::ThreeDSecure,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl ThreeDSecure {
+    pub(crate) fn new(
+        inner: crate::three_d_secure::ThreeDSecure,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        ThreeDSecure { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::three_d_secure::ThreeDSecure::post_3d_secure`].
+    pub fn post_3d_secure(&self) -> ClientResult<crate::Response<crate::types::ThreeDSecure>> {
+        self.runtime.block_on(self.inner.post_3d_secure())
+    }
+    /// The blocking version of [`crate::three_d_secure::ThreeDSecure::get_3d_secure_three_d`].
+    pub fn get_3d_secure_three_d(
+        &self,
+        three_d_secure: &str,
+    ) -> ClientResult<crate::Response<crate::types::ThreeDSecure>> {
+        self.runtime
+            .block_on(self.inner.get_3d_secure_three_d(three_d_secure))
+    }
+}
@@ -0,0 +1,24 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::tokens::Tokens`].
+pub struct Tokens {
+    inner: crate::tokens::Tokens,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Tokens {
+    pub(crate) fn new(inner: crate::tokens::Tokens, runtime: Arc<tokio::runtime::Runtime>) -> Self {
+        Tokens { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::tokens::Tokens::post`].
+    pub fn post(&self) -> ClientResult<crate::Response<crate::types::Token>> {
+        self.runtime.block_on(self.inner.post())
+    }
+    /// The blocking version of [`crate::tokens::Tokens::get`].
+    pub fn get(&self, token: &str) -> ClientResult<crate::Response<crate::types::Token>> {
+        self.runtime.block_on(self.inner.get(token))
+    }
+}
@@ -0,0 +1,105 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Topups::get_page`], set with a builder.
+pub struct TopupsGetPageRequest<'a> {
+    inner: crate::topups::TopupsGetPageRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> TopupsGetPageRequest<'a> {
+    /// Sets the `ending_before` query parameter.
+    pub fn ending_before(mut self, ending_before: &'a str) -> Self {
+        self.inner = self.inner.ending_before(ending_before);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.inner = self.inner.starting_after(starting_after);
+        self
+    }
+
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: crate::types::GetTopupsStatus) -> Self {
+        self.inner = self.inner.status(status);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Topup>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::topups::Topups`].
+pub struct Topups {
+    inner: crate::topups::Topups,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Topups {
+    pub(crate) fn new(inner: crate::topups::Topups, runtime: Arc<tokio::runtime::Runtime>) -> Self {
+        Topups { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::topups::Topups::get_page`].
+    pub fn get_page(
+        &self,
+        _amount: &str,
+        _created: &str,
+        ending_before: &str,
+        limit: i64,
+        starting_after: &str,
+        status: crate::types::GetTopupsStatus,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Topup>>> {
+        self.runtime.block_on(self.inner.get_page(
+            _amount,
+            _created,
+            ending_before,
+            limit,
+            starting_after,
+            status,
+        ))
+    }
+    /// The blocking version of [`crate::topups::Topups::get_page_request`].
+    pub fn get_page_request(&self) -> TopupsGetPageRequest<'_> {
+        TopupsGetPageRequest {
+            inner: self.inner.get_page_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::topups::Topups::get_all`].
+    pub fn get_all(
+        &self,
+        _amount: &str,
+        _created: &str,
+        status: crate::types::GetTopupsStatus,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Topup>>> {
+        self.runtime
+            .block_on(self.inner.get_all(_amount, _created, status))
+    }
+    /// The blocking version of [`crate::topups::Topups::post`].
+    pub fn post(&self) -> ClientResult<crate::Response<crate::types::Topup>> {
+        self.runtime.block_on(self.inner.post())
+    }
+    /// The blocking version of [`crate::topups::Topups::get`].
+    pub fn get(&self, topup: &str) -> ClientResult<crate::Response<crate::types::Topup>> {
+        self.runtime.block_on(self.inner.get(topup))
+    }
+    /// The blocking version of [`crate::topups::Topups::post_topups`].
+    pub fn post_topups(&self, topup: &str) -> ClientResult<crate::Response<crate::types::Topup>> {
+        self.runtime.block_on(self.inner.post_topups(topup))
+    }
+    /// The blocking version of [`crate::topups::Topups::post_cancel`].
+    pub fn post_cancel(&self, topup: &str) -> ClientResult<crate::Response<crate::types::Topup>> {
+        self.runtime.block_on(self.inner.post_cancel(topup))
+    }
+}
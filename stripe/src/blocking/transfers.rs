@@ -0,0 +1,195 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Transfers::get_page`], set with a builder.
+pub struct TransfersGetPageRequest<'a> {
+    inner: crate::transfers::TransfersGetPageRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> TransfersGetPageRequest<'a> {
+    /// Sets the `destination` query parameter.
+    pub fn destination(mut self, destination: &'a str) -> Self {
+        self.inner = self.inner.destination(destination);
+        self
+    }
+
+    /// Sets the `ending_before` query parameter.
+    pub fn ending_before(mut self, ending_before: &'a str) -> Self {
+        self.inner = self.inner.ending_before(ending_before);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.inner = self.inner.starting_after(starting_after);
+        self
+    }
+
+    /// Sets the `transfer_group` query parameter.
+    pub fn transfer_group(mut self, transfer_group: &'a str) -> Self {
+        self.inner = self.inner.transfer_group(transfer_group);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Transfer>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Transfers::get_reversals`], set with a builder.
+pub struct TransfersGetReversalsRequest<'a> {
+    inner: crate::transfers::TransfersGetReversalsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> TransfersGetReversalsRequest<'a> {
+    /// Sets the `ending_before` query parameter.
+    pub fn ending_before(mut self, ending_before: &'a str) -> Self {
+        self.inner = self.inner.ending_before(ending_before);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.inner = self.inner.starting_after(starting_after);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::TransferReversal>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::transfers::Transfers`].
+pub struct Transfers {
+    inner: crate::transfers::Transfers,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Transfers {
+    pub(crate) fn new(
+        inner: crate::transfers::Transfers,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Transfers { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::transfers::Transfers::get_page`].
+    pub fn get_page(
+        &self,
+        _created: &str,
+        destination: &str,
+        ending_before: &str,
+        limit: i64,
+        starting_after: &str,
+        transfer_group: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Transfer>>> {
+        self.runtime.block_on(self.inner.get_page(
+            _created,
+            destination,
+            ending_before,
+            limit,
+            starting_after,
+            transfer_group,
+        ))
+    }
+    /// The blocking version of [`crate::transfers::Transfers::get_page_request`].
+    pub fn get_page_request(&self) -> TransfersGetPageRequest<'_> {
+        TransfersGetPageRequest {
+            inner: self.inner.get_page_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::transfers::Transfers::get_all`].
+    pub fn get_all(
+        &self,
+        _created: &str,
+        destination: &str,
+        transfer_group: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Transfer>>> {
+        self.runtime
+            .block_on(self.inner.get_all(_created, destination, transfer_group))
+    }
+    /// The blocking version of [`crate::transfers::Transfers::post`].
+    pub fn post(&self) -> ClientResult<crate::Response<crate::types::Transfer>> {
+        self.runtime.block_on(self.inner.post())
+    }
+    /// The blocking version of [`crate::transfers::Transfers::get_reversals`].
+    pub fn get_reversals(
+        &self,
+        ending_before: &str,
+        id: &str,
+        limit: i64,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::TransferReversal>>> {
+        self.runtime.block_on(
+            self.inner
+                .get_reversals(ending_before, id, limit, starting_after),
+        )
+    }
+    /// The blocking version of [`crate::transfers::Transfers::get_reversals_request`].
+    pub fn get_reversals_request<'a>(&'a self, id: &'a str) -> TransfersGetReversalsRequest<'a> {
+        TransfersGetReversalsRequest {
+            inner: self.inner.get_reversals_request(id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::transfers::Transfers::get_all_reversals`].
+    pub fn get_all_reversals(
+        &self,
+        id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::TransferReversal>>> {
+        self.runtime.block_on(self.inner.get_all_reversals(id))
+    }
+    /// The blocking version of [`crate::transfers::Transfers::post_reversal`].
+    pub fn post_reversal(
+        &self,
+        id: &str,
+    ) -> ClientResult<crate::Response<crate::types::TransferReversal>> {
+        self.runtime.block_on(self.inner.post_reversal(id))
+    }
+    /// The blocking version of [`crate::transfers::Transfers::get`].
+    pub fn get(&self, transfer: &str) -> ClientResult<crate::Response<crate::types::Transfer>> {
+        self.runtime.block_on(self.inner.get(transfer))
+    }
+    /// The blocking version of [`crate::transfers::Transfers::post_transfers`].
+    pub fn post_transfers(
+        &self,
+        transfer: &str,
+    ) -> ClientResult<crate::Response<crate::types::Transfer>> {
+        self.runtime.block_on(self.inner.post_transfers(transfer))
+    }
+    /// The blocking version of [`crate::transfers::Transfers::get_reversal`].
+    pub fn get_reversal(
+        &self,
+        id: &str,
+        transfer: &str,
+    ) -> ClientResult<crate::Response<crate::types::TransferReversal>> {
+        self.runtime.block_on(self.inner.get_reversal(id, transfer))
+    }
+    /// The blocking version of [`crate::transfers::Transfers::post_reversal_transfers`].
+    pub fn post_reversal_transfers(
+        &self,
+        id: &str,
+        transfer: &str,
+    ) -> ClientResult<crate::Response<crate::types::TransferReversal>> {
+        self.runtime
+            .block_on(self.inner.post_reversal_transfers(id, transfer))
+    }
+}
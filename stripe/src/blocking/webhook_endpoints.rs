@@ -0,0 +1,99 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`WebhookEndpoints::get_page`], set with a builder.
+pub struct WebhookEndpointsGetPageRequest<'a> {
+    inner: crate::webhook_endpoints::WebhookEndpointsGetPageRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> WebhookEndpointsGetPageRequest<'a> {
+    /// Sets the `ending_before` query parameter.
+    pub fn ending_before(mut self, ending_before: &'a str) -> Self {
+        self.inner = self.inner.ending_before(ending_before);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.inner = self.inner.limit(limit);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.inner = self.inner.starting_after(starting_after);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::WebhookEndpoint>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::webhook_endpoints::WebhookEndpoints`].
+pub struct WebhookEndpoints {
+    inner: crate::webhook_endpoints::WebhookEndpoints,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl WebhookEndpoints {
+    pub(crate) fn new(
+        inner: crate::webhook_endpoints::WebhookEndpoints,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        WebhookEndpoints { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::webhook_endpoints::WebhookEndpoints::get_page`].
+    pub fn get_page(
+        &self,
+        ending_before: &str,
+        limit: i64,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::WebhookEndpoint>>> {
+        self.runtime
+            .block_on(self.inner.get_page(ending_before, limit, starting_after))
+    }
+    /// The blocking version of [`crate::webhook_endpoints::WebhookEndpoints::get_page_request`].
+    pub fn get_page_request(&self) -> WebhookEndpointsGetPageRequest<'_> {
+        WebhookEndpointsGetPageRequest {
+            inner: self.inner.get_page_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::webhook_endpoints::WebhookEndpoints::get_all`].
+    pub fn get_all(&self) -> ClientResult<crate::Response<Vec<crate::types::WebhookEndpoint>>> {
+        self.runtime.block_on(self.inner.get_all())
+    }
+    /// The blocking version of [`crate::webhook_endpoints::WebhookEndpoints::post`].
+    pub fn post(&self) -> ClientResult<crate::Response<crate::types::WebhookEndpoint>> {
+        self.runtime.block_on(self.inner.post())
+    }
+    /// The blocking version of [`crate::webhook_endpoints::WebhookEndpoints::get_endpoint`].
+    pub fn get_endpoint(
+        &self,
+        webhook_endpoint: &str,
+    ) -> ClientResult<crate::Response<crate::types::WebhookEndpoint>> {
+        self.runtime
+            .block_on(self.inner.get_endpoint(webhook_endpoint))
+    }
+    /// The blocking version of [`crate::webhook_endpoints::WebhookEndpoints::post_endpoint`].
+    pub fn post_endpoint(
+        &self,
+        webhook_endpoint: &str,
+    ) -> ClientResult<crate::Response<crate::types::WebhookEndpoint>> {
+        self.runtime
+            .block_on(self.inner.post_endpoint(webhook_endpoint))
+    }
+    /// The blocking version of [`crate::webhook_endpoints::WebhookEndpoints::delete_endpoint`].
+    pub fn delete_endpoint(
+        &self,
+        webhook_endpoint: &str,
+    ) -> ClientResult<crate::Response<crate::types::DeletedWebhookEndpoint>> {
+        self.runtime
+            .block_on(self.inner.delete_endpoint(webhook_endpoint))
+    }
+}
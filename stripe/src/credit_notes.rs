@@ -1,6 +1,439 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`CreditNotes::get_page`], set with a builder.
+pub struct CreditNotesGetPageRequest<'a> {
+    client: &'a CreditNotes,
+    customer: Option<&'a str>,
+    ending_before: Option<&'a str>,
+    invoice: Option<&'a str>,
+    limit: Option<i64>,
+    starting_after: Option<&'a str>,
+}
+
+impl<'a> CreditNotesGetPageRequest<'a> {
+    /// Sets the `customer` query parameter.
+    pub fn customer(mut self, customer: &'a str) -> Self {
+        self.customer = Some(customer);
+        self
+    }
+
+    /// Sets the `ending_before` query parameter.
+    pub fn ending_before(mut self, ending_before: &'a str) -> Self {
+        self.ending_before = Some(ending_before);
+        self
+    }
+
+    /// Sets the `invoice` query parameter.
+    pub fn invoice(mut self, invoice: &'a str) -> Self {
+        self.invoice = Some(invoice);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.starting_after = Some(starting_after);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::CreditNote>>> {
+        let Self {
+            client,
+            customer,
+            ending_before,
+            invoice,
+            limit,
+            starting_after,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = customer {
+            query_args.push(("customer".to_string(), v.to_string()));
+        }
+        if let Some(v) = ending_before {
+            query_args.push(("ending_before".to_string(), v.to_string()));
+        }
+        if let Some(v) = invoice {
+            query_args.push(("invoice".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = starting_after {
+            query_args.push(("starting_after".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/v1/credit_notes?{}", query_), None);
+        let resp: crate::Response<crate::types::CreditNotesList> = client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: Some("application/x-www-form-urlencoded".to_string()),
+                },
+            )
+            .await?;
+
+        // Return our response data.
+        Ok(crate::Response::new(
+            resp.status,
+            resp.headers,
+            resp.body.data.to_vec(),
+        ))
+    }
+}
+
+/// The parameters of [`CreditNotes::get_preview`], set with a builder.
+pub struct CreditNotesGetPreviewRequest<'a> {
+    client: &'a CreditNotes,
+    amount: Option<i64>,
+    credit_amount: Option<i64>,
+    invoice: &'a str,
+    memo: Option<&'a str>,
+    out_of_band_amount: Option<i64>,
+    reason: Option<crate::types::Reason>,
+    refund: Option<&'a str>,
+    refund_amount: Option<i64>,
+}
+
+impl<'a> CreditNotesGetPreviewRequest<'a> {
+    /// Sets the `amount` query parameter.
+    pub fn amount(mut self, amount: i64) -> Self {
+        self.amount = Some(amount);
+        self
+    }
+
+    /// Sets the `credit_amount` query parameter.
+    pub fn credit_amount(mut self, credit_amount: i64) -> Self {
+        self.credit_amount = Some(credit_amount);
+        self
+    }
+
+    /// Sets the `memo` query parameter.
+    pub fn memo(mut self, memo: &'a str) -> Self {
+        self.memo = Some(memo);
+        self
+    }
+
+    /// Sets the `out_of_band_amount` query parameter.
+    pub fn out_of_band_amount(mut self, out_of_band_amount: i64) -> Self {
+        self.out_of_band_amount = Some(out_of_band_amount);
+        self
+    }
+
+    /// Sets the `reason` query parameter.
+    pub fn reason(mut self, reason: crate::types::Reason) -> Self {
+        self.reason = Some(reason);
+        self
+    }
+
+    /// Sets the `refund` query parameter.
+    pub fn refund(mut self, refund: &'a str) -> Self {
+        self.refund = Some(refund);
+        self
+    }
+
+    /// Sets the `refund_amount` query parameter.
+    pub fn refund_amount(mut self, refund_amount: i64) -> Self {
+        self.refund_amount = Some(refund_amount);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::CreditNote>> {
+        let Self {
+            client,
+            amount,
+            credit_amount,
+            invoice,
+            memo,
+            out_of_band_amount,
+            reason,
+            refund,
+            refund_amount,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = amount {
+            query_args.push(("amount".to_string(), v.to_string()));
+        }
+        if let Some(v) = credit_amount {
+            query_args.push(("credit_amount".to_string(), v.to_string()));
+        }
+        if !invoice.is_empty() {
+            query_args.push(("invoice".to_string(), invoice.to_string()));
+        }
+        if let Some(v) = memo {
+            query_args.push(("memo".to_string(), v.to_string()));
+        }
+        if let Some(v) = out_of_band_amount {
+            query_args.push(("out_of_band_amount".to_string(), v.to_string()));
+        }
+        if let Some(v) = reason {
+            query_args.push(("reason".to_string(), v.to_string()));
+        }
+        if let Some(v) = refund {
+            query_args.push(("refund".to_string(), v.to_string()));
+        }
+        if let Some(v) = refund_amount {
+            query_args.push(("refund_amount".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/v1/credit_notes/preview?{}", query_), None);
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: Some("application/x-www-form-urlencoded".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`CreditNotes::get_preview_lines`], set with a builder.
+pub struct CreditNotesGetPreviewLinesRequest<'a> {
+    client: &'a CreditNotes,
+    amount: Option<i64>,
+    credit_amount: Option<i64>,
+    ending_before: Option<&'a str>,
+    invoice: &'a str,
+    limit: Option<i64>,
+    memo: Option<&'a str>,
+    out_of_band_amount: Option<i64>,
+    reason: Option<crate::types::Reason>,
+    refund: Option<&'a str>,
+    refund_amount: Option<i64>,
+    starting_after: Option<&'a str>,
+}
+
+impl<'a> CreditNotesGetPreviewLinesRequest<'a> {
+    /// Sets the `amount` query parameter.
+    pub fn amount(mut self, amount: i64) -> Self {
+        self.amount = Some(amount);
+        self
+    }
+
+    /// Sets the `credit_amount` query parameter.
+    pub fn credit_amount(mut self, credit_amount: i64) -> Self {
+        self.credit_amount = Some(credit_amount);
+        self
+    }
+
+    /// Sets the `ending_before` query parameter.
+    pub fn ending_before(mut self, ending_before: &'a str) -> Self {
+        self.ending_before = Some(ending_before);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `memo` query parameter.
+    pub fn memo(mut self, memo: &'a str) -> Self {
+        self.memo = Some(memo);
+        self
+    }
+
+    /// Sets the `out_of_band_amount` query parameter.
+    pub fn out_of_band_amount(mut self, out_of_band_amount: i64) -> Self {
+        self.out_of_band_amount = Some(out_of_band_amount);
+        self
+    }
+
+    /// Sets the `reason` query parameter.
+    pub fn reason(mut self, reason: crate::types::Reason) -> Self {
+        self.reason = Some(reason);
+        self
+    }
+
+    /// Sets the `refund` query parameter.
+    pub fn refund(mut self, refund: &'a str) -> Self {
+        self.refund = Some(refund);
+        self
+    }
+
+    /// Sets the `refund_amount` query parameter.
+    pub fn refund_amount(mut self, refund_amount: i64) -> Self {
+        self.refund_amount = Some(refund_amount);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.starting_after = Some(starting_after);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::CreditNoteLineItem>>> {
+        let Self {
+            client,
+            amount,
+            credit_amount,
+            ending_before,
+            invoice,
+            limit,
+            memo,
+            out_of_band_amount,
+            reason,
+            refund,
+            refund_amount,
+            starting_after,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = amount {
+            query_args.push(("amount".to_string(), v.to_string()));
+        }
+        if let Some(v) = credit_amount {
+            query_args.push(("credit_amount".to_string(), v.to_string()));
+        }
+        if let Some(v) = ending_before {
+            query_args.push(("ending_before".to_string(), v.to_string()));
+        }
+        if !invoice.is_empty() {
+            query_args.push(("invoice".to_string(), invoice.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = memo {
+            query_args.push(("memo".to_string(), v.to_string()));
+        }
+        if let Some(v) = out_of_band_amount {
+            query_args.push(("out_of_band_amount".to_string(), v.to_string()));
+        }
+        if let Some(v) = reason {
+            query_args.push(("reason".to_string(), v.to_string()));
+        }
+        if let Some(v) = refund {
+            query_args.push(("refund".to_string(), v.to_string()));
+        }
+        if let Some(v) = refund_amount {
+            query_args.push(("refund_amount".to_string(), v.to_string()));
+        }
+        if let Some(v) = starting_after {
+            query_args.push(("starting_after".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/v1/credit_notes/preview/lines?{}", query_), None);
+        let resp: crate::Response<crate::types::Lines> = client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: Some("application/x-www-form-urlencoded".to_string()),
+                },
+            )
+            .await?;
+
+        // Return our response data.
+        Ok(crate::Response::new(
+            resp.status,
+            resp.headers,
+            resp.body.data.to_vec(),
+        ))
+    }
+}
+
+/// The parameters of [`CreditNotes::get_note_lines`], set with a builder.
+pub struct CreditNotesGetNoteLinesRequest<'a> {
+    client: &'a CreditNotes,
+    credit_note: &'a str,
+    ending_before: Option<&'a str>,
+    limit: Option<i64>,
+    starting_after: Option<&'a str>,
+}
+
+impl<'a> CreditNotesGetNoteLinesRequest<'a> {
+    /// Sets the `ending_before` query parameter.
+    pub fn ending_before(mut self, ending_before: &'a str) -> Self {
+        self.ending_before = Some(ending_before);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.starting_after = Some(starting_after);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::CreditNoteLineItem>>> {
+        let Self {
+            client,
+            credit_note,
+            ending_before,
+            limit,
+            starting_after,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = ending_before {
+            query_args.push(("ending_before".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = starting_after {
+            query_args.push(("starting_after".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v1/credit_notes/{}/lines?{}",
+                crate::progenitor_support::encode_path(credit_note),
+                query_
+            ),
+            None,
+        );
+        let resp: crate::Response<crate::types::Lines> = client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: Some("application/x-www-form-urlencoded".to_string()),
+                },
+            )
+            .await?;
+
+        // Return our response data.
+        Ok(crate::Response::new(
+            resp.status,
+            resp.headers,
+            resp.body.data.to_vec(),
+        ))
+    }
+}
+
 pub struct CreditNotes {
     pub client: Client,
 }
@@ -71,6 +504,23 @@ impl CreditNotes {
             resp.body.data.to_vec(),
         ))
     }
+    /**
+     * This function performs a `GET` to the `/v1/credit_notes` endpoint.
+     *
+     * As opposed to `get_page`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * <p>Returns a list of credit notes.</p>
+     */
+    pub fn get_page_request(&self) -> CreditNotesGetPageRequest<'_> {
+        CreditNotesGetPageRequest {
+            client: self,
+            customer: None,
+            ending_before: None,
+            invoice: None,
+            limit: None,
+            starting_after: None,
+        }
+    }
     /**
      * This function performs a `GET` to the `/v1/credit_notes` endpoint.
      *
@@ -94,11 +544,11 @@ impl CreditNotes {
         let url = self
             .client
             .url(&format!("/v1/credit_notes?{}", query_), None);
-        let crate::Response::<crate::types::CreditNotesList> {
-            mut status,
-            mut headers,
-            mut body,
-        } = self
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let mut pages = crate::utils::Pages::new(self.client.page_limits);
+        pages.visit(&url)?;
+
+        let mut response: crate::Response<crate::types::CreditNotesList> = self
             .client
             .get(
                 &url,
@@ -108,62 +558,92 @@ impl CreditNotes {
                 },
             )
             .await?;
-
-        let mut data = body.data;
-        let mut has_more = body.has_more;
-        let mut page = "".to_string();
+        let mut data = Vec::new();
 
         // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                crate::Response::<crate::types::CreditNotesList> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}?startng_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
+        loop {
+            let page = if response.body.has_more {
+                response
+                    .body
+                    .data
+                    .last()
+                    .and_then(|last| serde_json::to_value(last).ok())
+                    .and_then(|last| {
+                        last.get("id")
+                            .and_then(|id| id.as_str())
+                            .map(|id| format!("{}{}starting_after={}", url, sep, id))
+                    })
             } else {
-                crate::Response::<crate::types::CreditNotesList> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}&starting_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
-
-            data.append(&mut body.data);
-
-            has_more = body.has_more;
+                None
+            };
+            let more = pages.keep(&mut response.body.data);
+            data.append(&mut response.body.data);
+
+            let next = match page {
+                Some(next) if more && pages.visit(&next)? => next,
+                _ => break,
+            };
+            response = self
+                .client
+                .get(
+                    &next,
+                    crate::Message {
+                        body: None,
+                        content_type: None,
+                    },
+                )
+                .await?;
         }
 
         // Return our response data.
-        Ok(crate::Response::new(status, headers, data.to_vec()))
+        Ok(crate::Response::new(
+            response.status,
+            response.headers,
+            data,
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/credit_notes` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * <p>Returns a list of credit notes.</p>
+     */
+    pub fn get_all_stream(
+        &self,
+        customer: &str,
+        invoice: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::CreditNote>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !customer.is_empty() {
+            query_args.push(("customer".to_string(), customer.to_string()));
+        }
+        if !invoice.is_empty() {
+            query_args.push(("invoice".to_string(), invoice.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/v1/credit_notes?{}", query_), None);
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let next = format!("{}{}starting_after=", url, sep);
+        self.client
+            .get_pages_stream(url, move |body: crate::types::CreditNotesList| {
+                let page = if body.has_more {
+                    body.data
+                        .last()
+                        .and_then(|last| serde_json::to_value(last).ok())
+                        .and_then(|last| {
+                            last.get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| format!("{}{}", next, id))
+                        })
+                } else {
+                    None
+                };
+
+                (body.data.to_vec(), page)
+            })
     }
     /**
      * This function performs a `POST` to the `/v1/credit_notes` endpoint.
@@ -269,6 +749,26 @@ impl CreditNotes {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/v1/credit_notes/preview` endpoint.
+     *
+     * As opposed to `get_preview`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * <p>Get a preview of a credit note without creating it.</p>
+     */
+    pub fn get_preview_request<'a>(&'a self, invoice: &'a str) -> CreditNotesGetPreviewRequest<'a> {
+        CreditNotesGetPreviewRequest {
+            client: self,
+            amount: None,
+            credit_amount: None,
+            invoice,
+            memo: None,
+            out_of_band_amount: None,
+            reason: None,
+            refund: None,
+            refund_amount: None,
+        }
+    }
     /**
      * This function performs a `GET` to the `/v1/credit_notes/preview/lines` endpoint.
      *
@@ -366,6 +866,32 @@ impl CreditNotes {
             resp.body.data.to_vec(),
         ))
     }
+    /**
+     * This function performs a `GET` to the `/v1/credit_notes/preview/lines` endpoint.
+     *
+     * As opposed to `get_preview_lines`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * <p>When retrieving a credit note preview, you’ll get a <strong>lines</strong> property containing the first handful of those items. This URL you can retrieve the full (paginated) list of line items.</p>
+     */
+    pub fn get_preview_lines_request<'a>(
+        &'a self,
+        invoice: &'a str,
+    ) -> CreditNotesGetPreviewLinesRequest<'a> {
+        CreditNotesGetPreviewLinesRequest {
+            client: self,
+            amount: None,
+            credit_amount: None,
+            ending_before: None,
+            invoice,
+            limit: None,
+            memo: None,
+            out_of_band_amount: None,
+            reason: None,
+            refund: None,
+            refund_amount: None,
+            starting_after: None,
+        }
+    }
     /**
      * This function performs a `GET` to the `/v1/credit_notes/preview/lines` endpoint.
      *
@@ -418,11 +944,11 @@ impl CreditNotes {
         let url = self
             .client
             .url(&format!("/v1/credit_notes/preview/lines?{}", query_), None);
-        let crate::Response::<crate::types::Lines> {
-            mut status,
-            mut headers,
-            mut body,
-        } = self
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let mut pages = crate::utils::Pages::new(self.client.page_limits);
+        pages.visit(&url)?;
+
+        let mut response: crate::Response<crate::types::Lines> = self
             .client
             .get(
                 &url,
@@ -432,62 +958,122 @@ impl CreditNotes {
                 },
             )
             .await?;
-
-        let mut data = body.data;
-        let mut has_more = body.has_more;
-        let mut page = "".to_string();
+        let mut data = Vec::new();
 
         // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                crate::Response::<crate::types::Lines> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}?startng_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
+        loop {
+            let page = if response.body.has_more {
+                response
+                    .body
+                    .data
+                    .last()
+                    .and_then(|last| serde_json::to_value(last).ok())
+                    .and_then(|last| {
+                        last.get("id")
+                            .and_then(|id| id.as_str())
+                            .map(|id| format!("{}{}starting_after={}", url, sep, id))
+                    })
             } else {
-                crate::Response::<crate::types::Lines> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}&starting_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
-
-            data.append(&mut body.data);
-
-            has_more = body.has_more;
+                None
+            };
+            let more = pages.keep(&mut response.body.data);
+            data.append(&mut response.body.data);
+
+            let next = match page {
+                Some(next) if more && pages.visit(&next)? => next,
+                _ => break,
+            };
+            response = self
+                .client
+                .get(
+                    &next,
+                    crate::Message {
+                        body: None,
+                        content_type: None,
+                    },
+                )
+                .await?;
         }
 
         // Return our response data.
-        Ok(crate::Response::new(status, headers, data.to_vec()))
+        Ok(crate::Response::new(
+            response.status,
+            response.headers,
+            data,
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/credit_notes/preview/lines` endpoint.
+     *
+     * As opposed to `get_all_preview_lines`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * <p>When retrieving a credit note preview, you’ll get a <strong>lines</strong> property containing the first handful of those items. This URL you can retrieve the full (paginated) list of line items.</p>
+     */
+    pub fn get_all_preview_lines_stream(
+        &self,
+        amount: i64,
+        credit_amount: i64,
+        invoice: &str,
+        _lines: &[String],
+        memo: &str,
+        _metadata: &str,
+        out_of_band_amount: i64,
+        reason: crate::types::Reason,
+        refund: &str,
+        refund_amount: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::CreditNoteLineItem>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if amount > 0 {
+            query_args.push(("amount".to_string(), amount.to_string()));
+        }
+        if credit_amount > 0 {
+            query_args.push(("credit_amount".to_string(), credit_amount.to_string()));
+        }
+        if !invoice.is_empty() {
+            query_args.push(("invoice".to_string(), invoice.to_string()));
+        }
+        if !memo.is_empty() {
+            query_args.push(("memo".to_string(), memo.to_string()));
+        }
+        if out_of_band_amount > 0 {
+            query_args.push((
+                "out_of_band_amount".to_string(),
+                out_of_band_amount.to_string(),
+            ));
+        }
+        if !reason.to_string().is_empty() {
+            query_args.push(("reason".to_string(), reason.to_string()));
+        }
+        if !refund.is_empty() {
+            query_args.push(("refund".to_string(), refund.to_string()));
+        }
+        if refund_amount > 0 {
+            query_args.push(("refund_amount".to_string(), refund_amount.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/v1/credit_notes/preview/lines?{}", query_), None);
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let next = format!("{}{}starting_after=", url, sep);
+        self.client
+            .get_pages_stream(url, move |body: crate::types::Lines| {
+                let page = if body.has_more {
+                    body.data
+                        .last()
+                        .and_then(|last| serde_json::to_value(last).ok())
+                        .and_then(|last| {
+                            last.get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| format!("{}{}", next, id))
+                        })
+                } else {
+                    None
+                };
+
+                (body.data.to_vec(), page)
+            })
     }
     /**
      * This function performs a `GET` to the `/v1/credit_notes/{credit_note}/lines` endpoint.
@@ -546,6 +1132,25 @@ impl CreditNotes {
             resp.body.data.to_vec(),
         ))
     }
+    /**
+     * This function performs a `GET` to the `/v1/credit_notes/{credit_note}/lines` endpoint.
+     *
+     * As opposed to `get_note_lines`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * <p>When retrieving a credit note, you’ll get a <strong>lines</strong> property containing the the first handful of those items. There is also a URL where you can retrieve the full (paginated) list of line items.</p>
+     */
+    pub fn get_note_lines_request<'a>(
+        &'a self,
+        credit_note: &'a str,
+    ) -> CreditNotesGetNoteLinesRequest<'a> {
+        CreditNotesGetNoteLinesRequest {
+            client: self,
+            credit_note,
+            ending_before: None,
+            limit: None,
+            starting_after: None,
+        }
+    }
     /**
      * This function performs a `GET` to the `/v1/credit_notes/{credit_note}/lines` endpoint.
      *
@@ -564,11 +1169,11 @@ impl CreditNotes {
             ),
             None,
         );
-        let crate::Response::<crate::types::Lines> {
-            mut status,
-            mut headers,
-            mut body,
-        } = self
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let mut pages = crate::utils::Pages::new(self.client.page_limits);
+        pages.visit(&url)?;
+
+        let mut response: crate::Response<crate::types::Lines> = self
             .client
             .get(
                 &url,
@@ -578,62 +1183,88 @@ impl CreditNotes {
                 },
             )
             .await?;
-
-        let mut data = body.data;
-        let mut has_more = body.has_more;
-        let mut page = "".to_string();
+        let mut data = Vec::new();
 
         // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                crate::Response::<crate::types::Lines> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}?startng_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
+        loop {
+            let page = if response.body.has_more {
+                response
+                    .body
+                    .data
+                    .last()
+                    .and_then(|last| serde_json::to_value(last).ok())
+                    .and_then(|last| {
+                        last.get("id")
+                            .and_then(|id| id.as_str())
+                            .map(|id| format!("{}{}starting_after={}", url, sep, id))
+                    })
             } else {
-                crate::Response::<crate::types::Lines> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}&starting_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
-
-            data.append(&mut body.data);
-
-            has_more = body.has_more;
+                None
+            };
+            let more = pages.keep(&mut response.body.data);
+            data.append(&mut response.body.data);
+
+            let next = match page {
+                Some(next) if more && pages.visit(&next)? => next,
+                _ => break,
+            };
+            response = self
+                .client
+                .get(
+                    &next,
+                    crate::Message {
+                        body: None,
+                        content_type: None,
+                    },
+                )
+                .await?;
         }
 
         // Return our response data.
-        Ok(crate::Response::new(status, headers, data.to_vec()))
+        Ok(crate::Response::new(
+            response.status,
+            response.headers,
+            data,
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/credit_notes/{credit_note}/lines` endpoint.
+     *
+     * As opposed to `get_all_note_lines`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * <p>When retrieving a credit note, you’ll get a <strong>lines</strong> property containing the the first handful of those items. There is also a URL where you can retrieve the full (paginated) list of line items.</p>
+     */
+    pub fn get_all_note_lines_stream(
+        &self,
+        credit_note: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::CreditNoteLineItem>> + Unpin + '_
+    {
+        let url = self.client.url(
+            &format!(
+                "/v1/credit_notes/{}/lines",
+                crate::progenitor_support::encode_path(credit_note),
+            ),
+            None,
+        );
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let next = format!("{}{}starting_after=", url, sep);
+        self.client
+            .get_pages_stream(url, move |body: crate::types::Lines| {
+                let page = if body.has_more {
+                    body.data
+                        .last()
+                        .and_then(|last| serde_json::to_value(last).ok())
+                        .and_then(|last| {
+                            last.get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| format!("{}{}", next, id))
+                        })
+                } else {
+                    None
+                };
+
+                (body.data.to_vec(), page)
+            })
     }
     /**
      * This function performs a `GET` to the `/v1/credit_notes/{id}` endpoint.
@@ -1,6 +1,592 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Invoices::get_page`], set with a builder.
+pub struct InvoicesGetPageRequest<'a> {
+    client: &'a Invoices,
+    collection_method: Option<crate::types::CollectionMethod>,
+    customer: Option<&'a str>,
+    ending_before: Option<&'a str>,
+    limit: Option<i64>,
+    starting_after: Option<&'a str>,
+    status: Option<crate::types::GetInvoicesStatus>,
+    subscription: Option<&'a str>,
+}
+
+impl<'a> InvoicesGetPageRequest<'a> {
+    /// Sets the `collection_method` query parameter.
+    pub fn collection_method(mut self, collection_method: crate::types::CollectionMethod) -> Self {
+        self.collection_method = Some(collection_method);
+        self
+    }
+
+    /// Sets the `customer` query parameter.
+    pub fn customer(mut self, customer: &'a str) -> Self {
+        self.customer = Some(customer);
+        self
+    }
+
+    /// Sets the `ending_before` query parameter.
+    pub fn ending_before(mut self, ending_before: &'a str) -> Self {
+        self.ending_before = Some(ending_before);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.starting_after = Some(starting_after);
+        self
+    }
+
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: crate::types::GetInvoicesStatus) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Sets the `subscription` query parameter.
+    pub fn subscription(mut self, subscription: &'a str) -> Self {
+        self.subscription = Some(subscription);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Invoice>>> {
+        let Self {
+            client,
+            collection_method,
+            customer,
+            ending_before,
+            limit,
+            starting_after,
+            status,
+            subscription,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = collection_method {
+            query_args.push(("collection_method".to_string(), v.to_string()));
+        }
+        if let Some(v) = customer {
+            query_args.push(("customer".to_string(), v.to_string()));
+        }
+        if let Some(v) = ending_before {
+            query_args.push(("ending_before".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = starting_after {
+            query_args.push(("starting_after".to_string(), v.to_string()));
+        }
+        if let Some(v) = status {
+            query_args.push(("status".to_string(), v.to_string()));
+        }
+        if let Some(v) = subscription {
+            query_args.push(("subscription".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(&format!("/v1/invoices?{}", query_), None);
+        let resp: crate::Response<crate::types::InvoicesList> = client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: Some("application/x-www-form-urlencoded".to_string()),
+                },
+            )
+            .await?;
+
+        // Return our response data.
+        Ok(crate::Response::new(
+            resp.status,
+            resp.headers,
+            resp.body.data.to_vec(),
+        ))
+    }
+}
+
+/// The parameters of [`Invoices::get_search`], set with a builder.
+pub struct InvoicesGetSearchRequest<'a> {
+    client: &'a Invoices,
+    limit: Option<i64>,
+    page: Option<&'a str>,
+    query: &'a str,
+}
+
+impl<'a> InvoicesGetSearchRequest<'a> {
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: &'a str) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Charge>>> {
+        let Self {
+            client,
+            limit,
+            page,
+            query,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if !query.is_empty() {
+            query_args.push(("query".to_string(), query.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/v1/invoices/search?{}", query_), None);
+        let resp: crate::Response<crate::types::SearchResult> = client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: Some("application/x-www-form-urlencoded".to_string()),
+                },
+            )
+            .await?;
+
+        // Return our response data.
+        Ok(crate::Response::new(
+            resp.status,
+            resp.headers,
+            resp.body.data.to_vec(),
+        ))
+    }
+}
+
+/// The parameters of [`Invoices::get_upcoming`], set with a builder.
+pub struct InvoicesGetUpcomingRequest<'a> {
+    client: &'a Invoices,
+    coupon: Option<&'a str>,
+    customer: Option<&'a str>,
+    schedule: Option<&'a str>,
+    subscription: Option<&'a str>,
+    subscription_cancel_at_period_end: Option<bool>,
+    subscription_cancel_now: Option<bool>,
+    subscription_proration_behavior: Option<crate::types::ProrationBehavior>,
+    subscription_proration_date: Option<i64>,
+    subscription_start_date: Option<i64>,
+    subscription_trial_from_plan: Option<bool>,
+}
+
+impl<'a> InvoicesGetUpcomingRequest<'a> {
+    /// Sets the `coupon` query parameter.
+    pub fn coupon(mut self, coupon: &'a str) -> Self {
+        self.coupon = Some(coupon);
+        self
+    }
+
+    /// Sets the `customer` query parameter.
+    pub fn customer(mut self, customer: &'a str) -> Self {
+        self.customer = Some(customer);
+        self
+    }
+
+    /// Sets the `schedule` query parameter.
+    pub fn schedule(mut self, schedule: &'a str) -> Self {
+        self.schedule = Some(schedule);
+        self
+    }
+
+    /// Sets the `subscription` query parameter.
+    pub fn subscription(mut self, subscription: &'a str) -> Self {
+        self.subscription = Some(subscription);
+        self
+    }
+
+    /// Sets the `subscription_cancel_at_period_end` query parameter.
+    pub fn subscription_cancel_at_period_end(
+        mut self,
+        subscription_cancel_at_period_end: bool,
+    ) -> Self {
+        self.subscription_cancel_at_period_end = Some(subscription_cancel_at_period_end);
+        self
+    }
+
+    /// Sets the `subscription_cancel_now` query parameter.
+    pub fn subscription_cancel_now(mut self, subscription_cancel_now: bool) -> Self {
+        self.subscription_cancel_now = Some(subscription_cancel_now);
+        self
+    }
+
+    /// Sets the `subscription_proration_behavior` query parameter.
+    pub fn subscription_proration_behavior(
+        mut self,
+        subscription_proration_behavior: crate::types::ProrationBehavior,
+    ) -> Self {
+        self.subscription_proration_behavior = Some(subscription_proration_behavior);
+        self
+    }
+
+    /// Sets the `subscription_proration_date` query parameter.
+    pub fn subscription_proration_date(mut self, subscription_proration_date: i64) -> Self {
+        self.subscription_proration_date = Some(subscription_proration_date);
+        self
+    }
+
+    /// Sets the `subscription_start_date` query parameter.
+    pub fn subscription_start_date(mut self, subscription_start_date: i64) -> Self {
+        self.subscription_start_date = Some(subscription_start_date);
+        self
+    }
+
+    /// Sets the `subscription_trial_from_plan` query parameter.
+    pub fn subscription_trial_from_plan(mut self, subscription_trial_from_plan: bool) -> Self {
+        self.subscription_trial_from_plan = Some(subscription_trial_from_plan);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::Invoice>> {
+        let Self {
+            client,
+            coupon,
+            customer,
+            schedule,
+            subscription,
+            subscription_cancel_at_period_end,
+            subscription_cancel_now,
+            subscription_proration_behavior,
+            subscription_proration_date,
+            subscription_start_date,
+            subscription_trial_from_plan,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = coupon {
+            query_args.push(("coupon".to_string(), v.to_string()));
+        }
+        if let Some(v) = customer {
+            query_args.push(("customer".to_string(), v.to_string()));
+        }
+        if let Some(v) = schedule {
+            query_args.push(("schedule".to_string(), v.to_string()));
+        }
+        if let Some(v) = subscription {
+            query_args.push(("subscription".to_string(), v.to_string()));
+        }
+        if let Some(v) = subscription_cancel_at_period_end {
+            query_args.push((
+                "subscription_cancel_at_period_end".to_string(),
+                v.to_string(),
+            ));
+        }
+        if let Some(v) = subscription_cancel_now {
+            query_args.push(("subscription_cancel_now".to_string(), v.to_string()));
+        }
+        if let Some(v) = subscription_proration_behavior {
+            query_args.push(("subscription_proration_behavior".to_string(), v.to_string()));
+        }
+        if let Some(v) = subscription_proration_date {
+            query_args.push(("subscription_proration_date".to_string(), v.to_string()));
+        }
+        if let Some(v) = subscription_start_date {
+            query_args.push(("subscription_start_date".to_string(), v.to_string()));
+        }
+        if let Some(v) = subscription_trial_from_plan {
+            query_args.push(("subscription_trial_from_plan".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/v1/invoices/upcoming?{}", query_), None);
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: Some("application/x-www-form-urlencoded".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Invoices::get_upcoming_lines`], set with a builder.
+pub struct InvoicesGetUpcomingLinesRequest<'a> {
+    client: &'a Invoices,
+    coupon: Option<&'a str>,
+    customer: Option<&'a str>,
+    ending_before: Option<&'a str>,
+    limit: Option<i64>,
+    schedule: Option<&'a str>,
+    starting_after: Option<&'a str>,
+    subscription: Option<&'a str>,
+    subscription_cancel_at_period_end: Option<bool>,
+    subscription_cancel_now: Option<bool>,
+    subscription_proration_behavior: Option<crate::types::ProrationBehavior>,
+    subscription_proration_date: Option<i64>,
+    subscription_start_date: Option<i64>,
+    subscription_trial_from_plan: Option<bool>,
+}
+
+impl<'a> InvoicesGetUpcomingLinesRequest<'a> {
+    /// Sets the `coupon` query parameter.
+    pub fn coupon(mut self, coupon: &'a str) -> Self {
+        self.coupon = Some(coupon);
+        self
+    }
+
+    /// Sets the `customer` query parameter.
+    pub fn customer(mut self, customer: &'a str) -> Self {
+        self.customer = Some(customer);
+        self
+    }
+
+    /// Sets the `ending_before` query parameter.
+    pub fn ending_before(mut self, ending_before: &'a str) -> Self {
+        self.ending_before = Some(ending_before);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `schedule` query parameter.
+    pub fn schedule(mut self, schedule: &'a str) -> Self {
+        self.schedule = Some(schedule);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.starting_after = Some(starting_after);
+        self
+    }
+
+    /// Sets the `subscription` query parameter.
+    pub fn subscription(mut self, subscription: &'a str) -> Self {
+        self.subscription = Some(subscription);
+        self
+    }
+
+    /// Sets the `subscription_cancel_at_period_end` query parameter.
+    pub fn subscription_cancel_at_period_end(
+        mut self,
+        subscription_cancel_at_period_end: bool,
+    ) -> Self {
+        self.subscription_cancel_at_period_end = Some(subscription_cancel_at_period_end);
+        self
+    }
+
+    /// Sets the `subscription_cancel_now` query parameter.
+    pub fn subscription_cancel_now(mut self, subscription_cancel_now: bool) -> Self {
+        self.subscription_cancel_now = Some(subscription_cancel_now);
+        self
+    }
+
+    /// Sets the `subscription_proration_behavior` query parameter.
+    pub fn subscription_proration_behavior(
+        mut self,
+        subscription_proration_behavior: crate::types::ProrationBehavior,
+    ) -> Self {
+        self.subscription_proration_behavior = Some(subscription_proration_behavior);
+        self
+    }
+
+    /// Sets the `subscription_proration_date` query parameter.
+    pub fn subscription_proration_date(mut self, subscription_proration_date: i64) -> Self {
+        self.subscription_proration_date = Some(subscription_proration_date);
+        self
+    }
+
+    /// Sets the `subscription_start_date` query parameter.
+    pub fn subscription_start_date(mut self, subscription_start_date: i64) -> Self {
+        self.subscription_start_date = Some(subscription_start_date);
+        self
+    }
+
+    /// Sets the `subscription_trial_from_plan` query parameter.
+    pub fn subscription_trial_from_plan(mut self, subscription_trial_from_plan: bool) -> Self {
+        self.subscription_trial_from_plan = Some(subscription_trial_from_plan);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::LineItem>>> {
+        let Self {
+            client,
+            coupon,
+            customer,
+            ending_before,
+            limit,
+            schedule,
+            starting_after,
+            subscription,
+            subscription_cancel_at_period_end,
+            subscription_cancel_now,
+            subscription_proration_behavior,
+            subscription_proration_date,
+            subscription_start_date,
+            subscription_trial_from_plan,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = coupon {
+            query_args.push(("coupon".to_string(), v.to_string()));
+        }
+        if let Some(v) = customer {
+            query_args.push(("customer".to_string(), v.to_string()));
+        }
+        if let Some(v) = ending_before {
+            query_args.push(("ending_before".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = schedule {
+            query_args.push(("schedule".to_string(), v.to_string()));
+        }
+        if let Some(v) = starting_after {
+            query_args.push(("starting_after".to_string(), v.to_string()));
+        }
+        if let Some(v) = subscription {
+            query_args.push(("subscription".to_string(), v.to_string()));
+        }
+        if let Some(v) = subscription_cancel_at_period_end {
+            query_args.push((
+                "subscription_cancel_at_period_end".to_string(),
+                v.to_string(),
+            ));
+        }
+        if let Some(v) = subscription_cancel_now {
+            query_args.push(("subscription_cancel_now".to_string(), v.to_string()));
+        }
+        if let Some(v) = subscription_proration_behavior {
+            query_args.push(("subscription_proration_behavior".to_string(), v.to_string()));
+        }
+        if let Some(v) = subscription_proration_date {
+            query_args.push(("subscription_proration_date".to_string(), v.to_string()));
+        }
+        if let Some(v) = subscription_start_date {
+            query_args.push(("subscription_start_date".to_string(), v.to_string()));
+        }
+        if let Some(v) = subscription_trial_from_plan {
+            query_args.push(("subscription_trial_from_plan".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/v1/invoices/upcoming/lines?{}", query_), None);
+        let resp: crate::Response<crate::types::InvoiceLinesList> = client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: Some("application/x-www-form-urlencoded".to_string()),
+                },
+            )
+            .await?;
+
+        // Return our response data.
+        Ok(crate::Response::new(
+            resp.status,
+            resp.headers,
+            resp.body.data.to_vec(),
+        ))
+    }
+}
+
+/// The parameters of [`Invoices::get_lines`], set with a builder.
+pub struct InvoicesGetLinesRequest<'a> {
+    client: &'a Invoices,
+    ending_before: Option<&'a str>,
+    invoice: &'a str,
+    limit: Option<i64>,
+    starting_after: Option<&'a str>,
+}
+
+impl<'a> InvoicesGetLinesRequest<'a> {
+    /// Sets the `ending_before` query parameter.
+    pub fn ending_before(mut self, ending_before: &'a str) -> Self {
+        self.ending_before = Some(ending_before);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.starting_after = Some(starting_after);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::LineItem>>> {
+        let Self {
+            client,
+            ending_before,
+            invoice,
+            limit,
+            starting_after,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = ending_before {
+            query_args.push(("ending_before".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = starting_after {
+            query_args.push(("starting_after".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v1/invoices/{}/lines?{}",
+                crate::progenitor_support::encode_path(invoice),
+                query_
+            ),
+            None,
+        );
+        let resp: crate::Response<crate::types::InvoiceLinesList> = client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: Some("application/x-www-form-urlencoded".to_string()),
+                },
+            )
+            .await?;
+
+        // Return our response data.
+        Ok(crate::Response::new(
+            resp.status,
+            resp.headers,
+            resp.body.data.to_vec(),
+        ))
+    }
+}
+
 pub struct Invoices {
     pub client: Client,
 }
@@ -86,6 +672,25 @@ impl Invoices {
             resp.body.data.to_vec(),
         ))
     }
+    /**
+     * This function performs a `GET` to the `/v1/invoices` endpoint.
+     *
+     * As opposed to `get_page`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * <p>You can list all invoices, or list the invoices for a specific customer. The invoices are returned sorted by creation date, with the most recently created invoices appearing first.</p>
+     */
+    pub fn get_page_request(&self) -> InvoicesGetPageRequest<'_> {
+        InvoicesGetPageRequest {
+            client: self,
+            collection_method: None,
+            customer: None,
+            ending_before: None,
+            limit: None,
+            starting_after: None,
+            status: None,
+            subscription: None,
+        }
+    }
     /**
      * This function performs a `GET` to the `/v1/invoices` endpoint.
      *
@@ -120,11 +725,11 @@ impl Invoices {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/v1/invoices?{}", query_), None);
-        let crate::Response::<crate::types::InvoicesList> {
-            mut status,
-            mut headers,
-            mut body,
-        } = self
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let mut pages = crate::utils::Pages::new(self.client.page_limits);
+        pages.visit(&url)?;
+
+        let mut response: crate::Response<crate::types::InvoicesList> = self
             .client
             .get(
                 &url,
@@ -134,62 +739,103 @@ impl Invoices {
                 },
             )
             .await?;
-
-        let mut data = body.data;
-        let mut has_more = body.has_more;
-        let mut page = "".to_string();
+        let mut data = Vec::new();
 
         // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                crate::Response::<crate::types::InvoicesList> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}?startng_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
+        loop {
+            let page = if response.body.has_more {
+                response
+                    .body
+                    .data
+                    .last()
+                    .and_then(|last| serde_json::to_value(last).ok())
+                    .and_then(|last| {
+                        last.get("id")
+                            .and_then(|id| id.as_str())
+                            .map(|id| format!("{}{}starting_after={}", url, sep, id))
+                    })
             } else {
-                crate::Response::<crate::types::InvoicesList> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}&starting_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
-
-            data.append(&mut body.data);
-
-            has_more = body.has_more;
+                None
+            };
+            let more = pages.keep(&mut response.body.data);
+            data.append(&mut response.body.data);
+
+            let next = match page {
+                Some(next) if more && pages.visit(&next)? => next,
+                _ => break,
+            };
+            response = self
+                .client
+                .get(
+                    &next,
+                    crate::Message {
+                        body: None,
+                        content_type: None,
+                    },
+                )
+                .await?;
         }
 
         // Return our response data.
-        Ok(crate::Response::new(status, headers, data.to_vec()))
+        Ok(crate::Response::new(
+            response.status,
+            response.headers,
+            data,
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/invoices` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * <p>You can list all invoices, or list the invoices for a specific customer. The invoices are returned sorted by creation date, with the most recently created invoices appearing first.</p>
+     */
+    pub fn get_all_stream(
+        &self,
+        collection_method: crate::types::CollectionMethod,
+        _created: &str,
+        customer: &str,
+        _due_date: &str,
+        status: crate::types::GetInvoicesStatus,
+        subscription: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Invoice>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !collection_method.to_string().is_empty() {
+            query_args.push((
+                "collection_method".to_string(),
+                collection_method.to_string(),
+            ));
+        }
+        if !customer.is_empty() {
+            query_args.push(("customer".to_string(), customer.to_string()));
+        }
+        if !status.to_string().is_empty() {
+            query_args.push(("status".to_string(), status.to_string()));
+        }
+        if !subscription.is_empty() {
+            query_args.push(("subscription".to_string(), subscription.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/v1/invoices?{}", query_), None);
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let next = format!("{}{}starting_after=", url, sep);
+        self.client
+            .get_pages_stream(url, move |body: crate::types::InvoicesList| {
+                let page = if body.has_more {
+                    body.data
+                        .last()
+                        .and_then(|last| serde_json::to_value(last).ok())
+                        .and_then(|last| {
+                            last.get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| format!("{}{}", next, id))
+                        })
+                } else {
+                    None
+                };
+
+                (body.data.to_vec(), page)
+            })
     }
     /**
      * This function performs a `POST` to the `/v1/invoices` endpoint.
@@ -261,6 +907,24 @@ impl Invoices {
             resp.body.data.to_vec(),
         ))
     }
+    /**
+     * This function performs a `GET` to the `/v1/invoices/search` endpoint.
+     *
+     * As opposed to `get_search`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * <p>Search for invoices you’ve previously created using Stripe’s <a href="/docs/search#search-query-language">Search Query Language</a>.
+     * Don’t use search in read-after-write flows where strict consistency is necessary. Under normal operating
+     * conditions, data is searchable in less than a minute. Occasionally, propagation of new or updated data can be up
+     * to an hour behind during outages. Search functionality is not available to merchants in India.</p>
+     */
+    pub fn get_search_request<'a>(&'a self, query: &'a str) -> InvoicesGetSearchRequest<'a> {
+        InvoicesGetSearchRequest {
+            client: self,
+            limit: None,
+            page: None,
+            query,
+        }
+    }
     /**
      * This function performs a `GET` to the `/v1/invoices/search` endpoint.
      *
@@ -283,11 +947,11 @@ impl Invoices {
         let url = self
             .client
             .url(&format!("/v1/invoices/search?{}", query_), None);
-        let crate::Response::<crate::types::SearchResult> {
-            mut status,
-            mut headers,
-            mut body,
-        } = self
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let mut pages = crate::utils::Pages::new(self.client.page_limits);
+        pages.visit(&url)?;
+
+        let mut response: crate::Response<crate::types::SearchResult> = self
             .client
             .get(
                 &url,
@@ -297,62 +961,91 @@ impl Invoices {
                 },
             )
             .await?;
-
-        let mut data = body.data;
-        let mut has_more = body.has_more;
-        let mut page = "".to_string();
+        let mut data = Vec::new();
 
         // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                crate::Response::<crate::types::SearchResult> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}?startng_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
+        loop {
+            let page = if response.body.has_more {
+                response
+                    .body
+                    .data
+                    .last()
+                    .and_then(|last| serde_json::to_value(last).ok())
+                    .and_then(|last| {
+                        last.get("id")
+                            .and_then(|id| id.as_str())
+                            .map(|id| format!("{}{}starting_after={}", url, sep, id))
+                    })
             } else {
-                crate::Response::<crate::types::SearchResult> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}&starting_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
-
-            data.append(&mut body.data);
-
-            has_more = body.has_more;
+                None
+            };
+            let more = pages.keep(&mut response.body.data);
+            data.append(&mut response.body.data);
+
+            let next = match page {
+                Some(next) if more && pages.visit(&next)? => next,
+                _ => break,
+            };
+            response = self
+                .client
+                .get(
+                    &next,
+                    crate::Message {
+                        body: None,
+                        content_type: None,
+                    },
+                )
+                .await?;
         }
 
         // Return our response data.
-        Ok(crate::Response::new(status, headers, data.to_vec()))
+        Ok(crate::Response::new(
+            response.status,
+            response.headers,
+            data,
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/invoices/search` endpoint.
+     *
+     * As opposed to `get_all_search`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * <p>Search for invoices you’ve previously created using Stripe’s <a href="/docs/search#search-query-language">Search Query Language</a>.
+     * Don’t use search in read-after-write flows where strict consistency is necessary. Under normal operating
+     * conditions, data is searchable in less than a minute. Occasionally, propagation of new or updated data can be up
+     * to an hour behind during outages. Search functionality is not available to merchants in India.</p>
+     */
+    pub fn get_all_search_stream(
+        &self,
+        query: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Charge>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !query.is_empty() {
+            query_args.push(("query".to_string(), query.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/v1/invoices/search?{}", query_), None);
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let next = format!("{}{}starting_after=", url, sep);
+        self.client
+            .get_pages_stream(url, move |body: crate::types::SearchResult| {
+                let page = if body.has_more {
+                    body.data
+                        .last()
+                        .and_then(|last| serde_json::to_value(last).ok())
+                        .and_then(|last| {
+                            last.get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| format!("{}{}", next, id))
+                        })
+                } else {
+                    None
+                };
+
+                (body.data.to_vec(), page)
+            })
     }
     /**
      * This function performs a `GET` to the `/v1/invoices/upcoming` endpoint.
@@ -475,6 +1168,32 @@ impl Invoices {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/v1/invoices/upcoming` endpoint.
+     *
+     * As opposed to `get_upcoming`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * <p>At any time, you can preview the upcoming invoice for a customer. This will show you all the charges that are pending, including subscription renewal charges, invoice item charges, etc. It will also show you any discounts that are applicable to the invoice.</p>
+     *
+     * <p>Note that when you are viewing an upcoming invoice, you are simply viewing a preview – the invoice has not yet been created. As such, the upcoming invoice will not show up in invoice listing calls, and you cannot use the API to pay or edit the invoice. If you want to change the amount that your customer will be billed, you can add, remove, or update pending invoice items, or update the customer’s discount.</p>
+     *
+     * <p>You can preview the effects of updating a subscription, including a preview of what proration will take place. To ensure that the actual proration is calculated exactly the same as the previewed proration, you should pass a <code>proration_date</code> parameter when doing the actual subscription update. The value passed in should be the same as the <code>subscription_proration_date</code> returned on the upcoming invoice resource. The recommended way to get only the prorations being previewed is to consider only proration line items where <code>period[start]</code> is equal to the <code>subscription_proration_date</code> on the upcoming invoice resource.</p>
+     */
+    pub fn get_upcoming_request(&self) -> InvoicesGetUpcomingRequest<'_> {
+        InvoicesGetUpcomingRequest {
+            client: self,
+            coupon: None,
+            customer: None,
+            schedule: None,
+            subscription: None,
+            subscription_cancel_at_period_end: None,
+            subscription_cancel_now: None,
+            subscription_proration_behavior: None,
+            subscription_proration_date: None,
+            subscription_start_date: None,
+            subscription_trial_from_plan: None,
+        }
+    }
     /**
      * This function performs a `GET` to the `/v1/invoices/upcoming/lines` endpoint.
      *
@@ -615,6 +1334,31 @@ impl Invoices {
             resp.body.data.to_vec(),
         ))
     }
+    /**
+     * This function performs a `GET` to the `/v1/invoices/upcoming/lines` endpoint.
+     *
+     * As opposed to `get_upcoming_lines`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * <p>When retrieving an upcoming invoice, you’ll get a <strong>lines</strong> property containing the total count of line items and the first handful of those items. There is also a URL where you can retrieve the full (paginated) list of line items.</p>
+     */
+    pub fn get_upcoming_lines_request(&self) -> InvoicesGetUpcomingLinesRequest<'_> {
+        InvoicesGetUpcomingLinesRequest {
+            client: self,
+            coupon: None,
+            customer: None,
+            ending_before: None,
+            limit: None,
+            schedule: None,
+            starting_after: None,
+            subscription: None,
+            subscription_cancel_at_period_end: None,
+            subscription_cancel_now: None,
+            subscription_proration_behavior: None,
+            subscription_proration_date: None,
+            subscription_start_date: None,
+            subscription_trial_from_plan: None,
+        }
+    }
     /**
      * This function performs a `GET` to the `/v1/invoices/upcoming/lines` endpoint.
      *
@@ -697,11 +1441,11 @@ impl Invoices {
         let url = self
             .client
             .url(&format!("/v1/invoices/upcoming/lines?{}", query_), None);
-        let crate::Response::<crate::types::InvoiceLinesList> {
-            mut status,
-            mut headers,
-            mut body,
-        } = self
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let mut pages = crate::utils::Pages::new(self.client.page_limits);
+        pages.visit(&url)?;
+
+        let mut response: crate::Response<crate::types::InvoiceLinesList> = self
             .client
             .get(
                 &url,
@@ -711,62 +1455,151 @@ impl Invoices {
                 },
             )
             .await?;
-
-        let mut data = body.data;
-        let mut has_more = body.has_more;
-        let mut page = "".to_string();
+        let mut data = Vec::new();
 
         // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                crate::Response::<crate::types::InvoiceLinesList> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}?startng_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
+        loop {
+            let page = if response.body.has_more {
+                response
+                    .body
+                    .data
+                    .last()
+                    .and_then(|last| serde_json::to_value(last).ok())
+                    .and_then(|last| {
+                        last.get("id")
+                            .and_then(|id| id.as_str())
+                            .map(|id| format!("{}{}starting_after={}", url, sep, id))
+                    })
             } else {
-                crate::Response::<crate::types::InvoiceLinesList> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}&starting_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
-
-            data.append(&mut body.data);
-
-            has_more = body.has_more;
+                None
+            };
+            let more = pages.keep(&mut response.body.data);
+            data.append(&mut response.body.data);
+
+            let next = match page {
+                Some(next) if more && pages.visit(&next)? => next,
+                _ => break,
+            };
+            response = self
+                .client
+                .get(
+                    &next,
+                    crate::Message {
+                        body: None,
+                        content_type: None,
+                    },
+                )
+                .await?;
         }
 
         // Return our response data.
-        Ok(crate::Response::new(status, headers, data.to_vec()))
+        Ok(crate::Response::new(
+            response.status,
+            response.headers,
+            data,
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/invoices/upcoming/lines` endpoint.
+     *
+     * As opposed to `get_all_upcoming_lines`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * <p>When retrieving an upcoming invoice, you’ll get a <strong>lines</strong> property containing the total count of line items and the first handful of those items. There is also a URL where you can retrieve the full (paginated) list of line items.</p>
+     */
+    pub fn get_all_upcoming_lines_stream(
+        &self,
+        _automatic_tax: &str,
+        coupon: &str,
+        customer: &str,
+        _customer_details: &str,
+        _discounts: &str,
+        _invoice_items: &[String],
+        schedule: &str,
+        subscription: &str,
+        _subscription_billing_cycle_anchor: &str,
+        _subscription_cancel_at: &str,
+        subscription_cancel_at_period_end: bool,
+        subscription_cancel_now: bool,
+        _subscription_default_tax_rates: &str,
+        _subscription_items: &[String],
+        subscription_proration_behavior: crate::types::ProrationBehavior,
+        subscription_proration_date: i64,
+        subscription_start_date: i64,
+        _subscription_trial_end: &str,
+        subscription_trial_from_plan: bool,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::LineItem>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !coupon.is_empty() {
+            query_args.push(("coupon".to_string(), coupon.to_string()));
+        }
+        if !customer.is_empty() {
+            query_args.push(("customer".to_string(), customer.to_string()));
+        }
+        if !schedule.is_empty() {
+            query_args.push(("schedule".to_string(), schedule.to_string()));
+        }
+        if !subscription.is_empty() {
+            query_args.push(("subscription".to_string(), subscription.to_string()));
+        }
+        if subscription_cancel_at_period_end {
+            query_args.push((
+                "subscription_cancel_at_period_end".to_string(),
+                subscription_cancel_at_period_end.to_string(),
+            ));
+        }
+        if subscription_cancel_now {
+            query_args.push((
+                "subscription_cancel_now".to_string(),
+                subscription_cancel_now.to_string(),
+            ));
+        }
+        if !subscription_proration_behavior.to_string().is_empty() {
+            query_args.push((
+                "subscription_proration_behavior".to_string(),
+                subscription_proration_behavior.to_string(),
+            ));
+        }
+        if subscription_proration_date > 0 {
+            query_args.push((
+                "subscription_proration_date".to_string(),
+                subscription_proration_date.to_string(),
+            ));
+        }
+        if subscription_start_date > 0 {
+            query_args.push((
+                "subscription_start_date".to_string(),
+                subscription_start_date.to_string(),
+            ));
+        }
+        if subscription_trial_from_plan {
+            query_args.push((
+                "subscription_trial_from_plan".to_string(),
+                subscription_trial_from_plan.to_string(),
+            ));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/v1/invoices/upcoming/lines?{}", query_), None);
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let next = format!("{}{}starting_after=", url, sep);
+        self.client
+            .get_pages_stream(url, move |body: crate::types::InvoiceLinesList| {
+                let page = if body.has_more {
+                    body.data
+                        .last()
+                        .and_then(|last| serde_json::to_value(last).ok())
+                        .and_then(|last| {
+                            last.get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| format!("{}{}", next, id))
+                        })
+                } else {
+                    None
+                };
+
+                (body.data.to_vec(), page)
+            })
     }
     /**
      * This function performs a `GET` to the `/v1/invoices/{invoice}` endpoint.
@@ -948,6 +1781,22 @@ impl Invoices {
             resp.body.data.to_vec(),
         ))
     }
+    /**
+     * This function performs a `GET` to the `/v1/invoices/{invoice}/lines` endpoint.
+     *
+     * As opposed to `get_lines`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * <p>When retrieving an invoice, you’ll get a <strong>lines</strong> property containing the total count of line items and the first handful of those items. There is also a URL where you can retrieve the full (paginated) list of line items.</p>
+     */
+    pub fn get_lines_request<'a>(&'a self, invoice: &'a str) -> InvoicesGetLinesRequest<'a> {
+        InvoicesGetLinesRequest {
+            client: self,
+            ending_before: None,
+            invoice,
+            limit: None,
+            starting_after: None,
+        }
+    }
     /**
      * This function performs a `GET` to the `/v1/invoices/{invoice}/lines` endpoint.
      *
@@ -966,11 +1815,11 @@ impl Invoices {
             ),
             None,
         );
-        let crate::Response::<crate::types::InvoiceLinesList> {
-            mut status,
-            mut headers,
-            mut body,
-        } = self
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let mut pages = crate::utils::Pages::new(self.client.page_limits);
+        pages.visit(&url)?;
+
+        let mut response: crate::Response<crate::types::InvoiceLinesList> = self
             .client
             .get(
                 &url,
@@ -980,62 +1829,87 @@ impl Invoices {
                 },
             )
             .await?;
-
-        let mut data = body.data;
-        let mut has_more = body.has_more;
-        let mut page = "".to_string();
+        let mut data = Vec::new();
 
         // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                crate::Response::<crate::types::InvoiceLinesList> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}?startng_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
+        loop {
+            let page = if response.body.has_more {
+                response
+                    .body
+                    .data
+                    .last()
+                    .and_then(|last| serde_json::to_value(last).ok())
+                    .and_then(|last| {
+                        last.get("id")
+                            .and_then(|id| id.as_str())
+                            .map(|id| format!("{}{}starting_after={}", url, sep, id))
+                    })
             } else {
-                crate::Response::<crate::types::InvoiceLinesList> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}&starting_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
-
-            data.append(&mut body.data);
-
-            has_more = body.has_more;
+                None
+            };
+            let more = pages.keep(&mut response.body.data);
+            data.append(&mut response.body.data);
+
+            let next = match page {
+                Some(next) if more && pages.visit(&next)? => next,
+                _ => break,
+            };
+            response = self
+                .client
+                .get(
+                    &next,
+                    crate::Message {
+                        body: None,
+                        content_type: None,
+                    },
+                )
+                .await?;
         }
 
         // Return our response data.
-        Ok(crate::Response::new(status, headers, data.to_vec()))
+        Ok(crate::Response::new(
+            response.status,
+            response.headers,
+            data,
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/invoices/{invoice}/lines` endpoint.
+     *
+     * As opposed to `get_all_lines`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * <p>When retrieving an invoice, you’ll get a <strong>lines</strong> property containing the total count of line items and the first handful of those items. There is also a URL where you can retrieve the full (paginated) list of line items.</p>
+     */
+    pub fn get_all_lines_stream(
+        &self,
+        invoice: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::LineItem>> + Unpin + '_ {
+        let url = self.client.url(
+            &format!(
+                "/v1/invoices/{}/lines",
+                crate::progenitor_support::encode_path(invoice),
+            ),
+            None,
+        );
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let next = format!("{}{}starting_after=", url, sep);
+        self.client
+            .get_pages_stream(url, move |body: crate::types::InvoiceLinesList| {
+                let page = if body.has_more {
+                    body.data
+                        .last()
+                        .and_then(|last| serde_json::to_value(last).ok())
+                        .and_then(|last| {
+                            last.get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| format!("{}{}", next, id))
+                        })
+                } else {
+                    None
+                };
+
+                (body.data.to_vec(), page)
+            })
     }
     /**
      * This function performs a `POST` to the `/v1/invoices/{invoice}/mark_uncollectible` endpoint.
@@ -1,6 +1,86 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`SetupAttempts::get_page`], set with a builder.
+pub struct SetupAttemptsGetPageRequest<'a> {
+    client: &'a SetupAttempts,
+    ending_before: Option<&'a str>,
+    limit: Option<i64>,
+    setup_intent: Option<&'a str>,
+    starting_after: Option<&'a str>,
+}
+
+impl<'a> SetupAttemptsGetPageRequest<'a> {
+    /// Sets the `ending_before` query parameter.
+    pub fn ending_before(mut self, ending_before: &'a str) -> Self {
+        self.ending_before = Some(ending_before);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `setup_intent` query parameter.
+    pub fn setup_intent(mut self, setup_intent: &'a str) -> Self {
+        self.setup_intent = Some(setup_intent);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.starting_after = Some(starting_after);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::SetupAttempt>>> {
+        let Self {
+            client,
+            ending_before,
+            limit,
+            setup_intent,
+            starting_after,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = ending_before {
+            query_args.push(("ending_before".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = setup_intent {
+            query_args.push(("setup_intent".to_string(), v.to_string()));
+        }
+        if let Some(v) = starting_after {
+            query_args.push(("starting_after".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/v1/setup_attempts?{}", query_), None);
+        let resp: crate::Response<crate::types::PaymentFlowsSetupIntentAttemptList> = client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: Some("application/x-www-form-urlencoded".to_string()),
+                },
+            )
+            .await?;
+
+        // Return our response data.
+        Ok(crate::Response::new(
+            resp.status,
+            resp.headers,
+            resp.body.data.to_vec(),
+        ))
+    }
+}
+
 pub struct SetupAttempts {
     pub client: Client,
 }
@@ -71,6 +151,22 @@ impl SetupAttempts {
             resp.body.data.to_vec(),
         ))
     }
+    /**
+     * This function performs a `GET` to the `/v1/setup_attempts` endpoint.
+     *
+     * As opposed to `get_page`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * <p>Returns a list of SetupAttempts associated with a provided SetupIntent.</p>
+     */
+    pub fn get_page_request(&self) -> SetupAttemptsGetPageRequest<'_> {
+        SetupAttemptsGetPageRequest {
+            client: self,
+            ending_before: None,
+            limit: None,
+            setup_intent: None,
+            starting_after: None,
+        }
+    }
     /**
      * This function performs a `GET` to the `/v1/setup_attempts` endpoint.
      *
@@ -91,11 +187,11 @@ impl SetupAttempts {
         let url = self
             .client
             .url(&format!("/v1/setup_attempts?{}", query_), None);
-        let crate::Response::<crate::types::PaymentFlowsSetupIntentAttemptList> {
-            mut status,
-            mut headers,
-            mut body,
-        } = self
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let mut pages = crate::utils::Pages::new(self.client.page_limits);
+        pages.visit(&url)?;
+
+        let mut response: crate::Response<crate::types::PaymentFlowsSetupIntentAttemptList> = self
             .client
             .get(
                 &url,
@@ -105,61 +201,90 @@ impl SetupAttempts {
                 },
             )
             .await?;
-
-        let mut data = body.data;
-        let mut has_more = body.has_more;
-        let mut page = "".to_string();
+        let mut data = Vec::new();
 
         // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                crate::Response::<crate::types::PaymentFlowsSetupIntentAttemptList> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}?startng_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
+        loop {
+            let page = if response.body.has_more {
+                response
+                    .body
+                    .data
+                    .last()
+                    .and_then(|last| serde_json::to_value(last).ok())
+                    .and_then(|last| {
+                        last.get("id")
+                            .and_then(|id| id.as_str())
+                            .map(|id| format!("{}{}starting_after={}", url, sep, id))
+                    })
             } else {
-                crate::Response::<crate::types::PaymentFlowsSetupIntentAttemptList> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}&starting_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
-
-            data.append(&mut body.data);
-
-            has_more = body.has_more;
+                None
+            };
+            let more = pages.keep(&mut response.body.data);
+            data.append(&mut response.body.data);
+
+            let next = match page {
+                Some(next) if more && pages.visit(&next)? => next,
+                _ => break,
+            };
+            response = self
+                .client
+                .get(
+                    &next,
+                    crate::Message {
+                        body: None,
+                        content_type: None,
+                    },
+                )
+                .await?;
         }
 
         // Return our response data.
-        Ok(crate::Response::new(status, headers, data.to_vec()))
+        Ok(crate::Response::new(
+            response.status,
+            response.headers,
+            data,
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/setup_attempts` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * <p>Returns a list of SetupAttempts associated with a provided SetupIntent.</p>
+     */
+    pub fn get_all_stream(
+        &self,
+        _created: &str,
+        setup_intent: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::SetupAttempt>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !setup_intent.is_empty() {
+            query_args.push(("setup_intent".to_string(), setup_intent.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/v1/setup_attempts?{}", query_), None);
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let next = format!("{}{}starting_after=", url, sep);
+        self.client.get_pages_stream(
+            url,
+            move |body: crate::types::PaymentFlowsSetupIntentAttemptList| {
+                let page = if body.has_more {
+                    body.data
+                        .last()
+                        .and_then(|last| serde_json::to_value(last).ok())
+                        .and_then(|last| {
+                            last.get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| format!("{}{}", next, id))
+                        })
+                } else {
+                    None
+                };
+
+                (body.data.to_vec(), page)
+            },
+        )
     }
 }
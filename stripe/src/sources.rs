@@ -1,6 +1,129 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Sources::get`], set with a builder.
+pub struct SourcesGetRequest<'a> {
+    client: &'a Sources,
+    client_secret: Option<&'a str>,
+    source: &'a str,
+}
+
+impl<'a> SourcesGetRequest<'a> {
+    /// Sets the `client_secret` query parameter.
+    pub fn client_secret(mut self, client_secret: &'a str) -> Self {
+        self.client_secret = Some(client_secret);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::SourceData>> {
+        let Self {
+            client,
+            client_secret,
+            source,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = client_secret {
+            query_args.push(("client_secret".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v1/sources/{}?{}",
+                crate::progenitor_support::encode_path(source),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: Some("application/x-www-form-urlencoded".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Sources::get_transactions`], set with a builder.
+pub struct SourcesGetTransactionsRequest<'a> {
+    client: &'a Sources,
+    ending_before: Option<&'a str>,
+    limit: Option<i64>,
+    source: &'a str,
+    starting_after: Option<&'a str>,
+}
+
+impl<'a> SourcesGetTransactionsRequest<'a> {
+    /// Sets the `ending_before` query parameter.
+    pub fn ending_before(mut self, ending_before: &'a str) -> Self {
+        self.ending_before = Some(ending_before);
+        self
+    }
+
+    /// Sets the `limit` query parameter.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `starting_after` query parameter.
+    pub fn starting_after(mut self, starting_after: &'a str) -> Self {
+        self.starting_after = Some(starting_after);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::SourceTransaction>>> {
+        let Self {
+            client,
+            ending_before,
+            limit,
+            source,
+            starting_after,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = ending_before {
+            query_args.push(("ending_before".to_string(), v.to_string()));
+        }
+        if let Some(v) = limit {
+            query_args.push(("limit".to_string(), v.to_string()));
+        }
+        if let Some(v) = starting_after {
+            query_args.push(("starting_after".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/v1/sources/{}/source_transactions?{}",
+                crate::progenitor_support::encode_path(source),
+                query_
+            ),
+            None,
+        );
+        let resp: crate::Response<crate::types::ApmsSourcesSourceTransactionList> = client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: Some("application/x-www-form-urlencoded".to_string()),
+                },
+            )
+            .await?;
+
+        // Return our response data.
+        Ok(crate::Response::new(
+            resp.status,
+            resp.headers,
+            resp.body.data.to_vec(),
+        ))
+    }
+}
+
 pub struct Sources {
     pub client: Client,
 }
@@ -67,6 +190,20 @@ impl Sources {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/v1/sources/{source}` endpoint.
+     *
+     * As opposed to `get`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * <p>Retrieves an existing source object. Supply the unique source ID from a source creation request and Stripe will return the corresponding up-to-date source object information.</p>
+     */
+    pub fn get_request<'a>(&'a self, source: &'a str) -> SourcesGetRequest<'a> {
+        SourcesGetRequest {
+            client: self,
+            client_secret: None,
+            source,
+        }
+    }
     /**
      * This function performs a `POST` to the `/v1/sources/{source}` endpoint.
      *
@@ -190,6 +327,25 @@ impl Sources {
             resp.body.data.to_vec(),
         ))
     }
+    /**
+     * This function performs a `GET` to the `/v1/sources/{source}/source_transactions` endpoint.
+     *
+     * As opposed to `get_transactions`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * <p>List source transactions for a given source.</p>
+     */
+    pub fn get_transactions_request<'a>(
+        &'a self,
+        source: &'a str,
+    ) -> SourcesGetTransactionsRequest<'a> {
+        SourcesGetTransactionsRequest {
+            client: self,
+            ending_before: None,
+            limit: None,
+            source,
+            starting_after: None,
+        }
+    }
     /**
      * This function performs a `GET` to the `/v1/sources/{source}/source_transactions` endpoint.
      *
@@ -208,11 +364,11 @@ impl Sources {
             ),
             None,
         );
-        let crate::Response::<crate::types::ApmsSourcesSourceTransactionList> {
-            mut status,
-            mut headers,
-            mut body,
-        } = self
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let mut pages = crate::utils::Pages::new(self.client.page_limits);
+        pages.visit(&url)?;
+
+        let mut response: crate::Response<crate::types::ApmsSourcesSourceTransactionList> = self
             .client
             .get(
                 &url,
@@ -222,62 +378,90 @@ impl Sources {
                 },
             )
             .await?;
-
-        let mut data = body.data;
-        let mut has_more = body.has_more;
-        let mut page = "".to_string();
+        let mut data = Vec::new();
 
         // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                crate::Response::<crate::types::ApmsSourcesSourceTransactionList> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}?startng_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
+        loop {
+            let page = if response.body.has_more {
+                response
+                    .body
+                    .data
+                    .last()
+                    .and_then(|last| serde_json::to_value(last).ok())
+                    .and_then(|last| {
+                        last.get("id")
+                            .and_then(|id| id.as_str())
+                            .map(|id| format!("{}{}starting_after={}", url, sep, id))
+                    })
             } else {
-                crate::Response::<crate::types::ApmsSourcesSourceTransactionList> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}&starting_after={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
-
-            data.append(&mut body.data);
+                None
+            };
+            let more = pages.keep(&mut response.body.data);
+            data.append(&mut response.body.data);
 
-            has_more = body.has_more;
+            let next = match page {
+                Some(next) if more && pages.visit(&next)? => next,
+                _ => break,
+            };
+            response = self
+                .client
+                .get(
+                    &next,
+                    crate::Message {
+                        body: None,
+                        content_type: None,
+                    },
+                )
+                .await?;
         }
 
         // Return our response data.
-        Ok(crate::Response::new(status, headers, data.to_vec()))
+        Ok(crate::Response::new(
+            response.status,
+            response.headers,
+            data,
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/sources/{source}/source_transactions` endpoint.
+     *
+     * As opposed to `get_all_transactions`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * <p>List source transactions for a given source.</p>
+     */
+    pub fn get_all_transactions_stream(
+        &self,
+        source: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::SourceTransaction>> + Unpin + '_
+    {
+        let url = self.client.url(
+            &format!(
+                "/v1/sources/{}/source_transactions",
+                crate::progenitor_support::encode_path(source),
+            ),
+            None,
+        );
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let next = format!("{}{}starting_after=", url, sep);
+        self.client.get_pages_stream(
+            url,
+            move |body: crate::types::ApmsSourcesSourceTransactionList| {
+                let page = if body.has_more {
+                    body.data
+                        .last()
+                        .and_then(|last| serde_json::to_value(last).ok())
+                        .and_then(|last| {
+                            last.get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| format!("{}{}", next, id))
+                        })
+                } else {
+                    None
+                };
+
+                (body.data.to_vec(), page)
+            },
+        )
     }
     /**
      * This function performs a `GET` to the `/v1/sources/{source}/source_transactions/{source_transaction}` endpoint.
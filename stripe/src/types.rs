@@ -18,8 +18,9 @@ pub enum BusinessType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for BusinessType {
@@ -30,7 +31,7 @@ impl std::fmt::Display for BusinessType {
             BusinessType::Individual => "individual",
             BusinessType::NonProfit => "non_profit",
             BusinessType::Noop => "",
-            BusinessType::FallthroughString => "*",
+            BusinessType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -98,8 +99,9 @@ pub enum Object {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Object {
@@ -107,7 +109,7 @@ impl std::fmt::Display for Object {
         match self {
             Object::List => "list",
             Object::Noop => "",
-            Object::FallthroughString => "*",
+            Object::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -148,6 +150,10 @@ pub struct ExternalAccounts {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -160,8 +166,9 @@ pub enum AccountObject {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AccountObject {
@@ -169,7 +176,7 @@ impl std::fmt::Display for AccountObject {
         match self {
             AccountObject::Account => "account",
             AccountObject::Noop => "",
-            AccountObject::FallthroughString => "*",
+            AccountObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -195,8 +202,9 @@ pub enum Type {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Type {
@@ -206,7 +214,7 @@ impl std::fmt::Display for Type {
             Type::Express => "express",
             Type::Standard => "standard",
             Type::Noop => "",
-            Type::FallthroughString => "*",
+            Type::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -454,6 +462,10 @@ pub struct Account {
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
     pub type_: Option<Type>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -468,6 +480,10 @@ pub struct AccountBacsDebitPaymentsSettings {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub display_name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types:
@@ -553,6 +569,10 @@ pub struct AccountBrandingSettings {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub secondary_color: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -626,6 +646,10 @@ pub struct AccountBusinessProfile {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -642,8 +666,9 @@ pub enum Transfers {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Transfers {
@@ -653,7 +678,7 @@ impl std::fmt::Display for Transfers {
             Transfers::Inactive => "inactive",
             Transfers::Pending => "pending",
             Transfers::Noop => "",
-            Transfers::FallthroughString => "*",
+            Transfers::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -812,6 +837,10 @@ pub struct AccountCapabilities {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub us_bank_account_ach_payments: Option<Transfers>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -889,6 +918,10 @@ pub struct AccountCapabilityFutureRequirements {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub pending_verification: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -966,6 +999,10 @@ pub struct AccountCapabilityRequirements {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub pending_verification: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -976,6 +1013,10 @@ pub struct AccountCardIssuingSettings {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub tos_acceptance: Option<CardIssuingAccountTermsOfService>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -995,6 +1036,10 @@ pub struct AccountCardPaymentsSettings {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub statement_descriptor_prefix: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -1018,6 +1063,10 @@ pub struct AccountDashboardSettings {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub timezone: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -1039,6 +1088,10 @@ pub struct AccountDeclineChargeOn {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub cvc_failure: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -1116,6 +1169,10 @@ pub struct AccountFutureRequirements {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub pending_verification: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -1128,8 +1185,9 @@ pub enum AccountLinkObject {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AccountLinkObject {
@@ -1137,7 +1195,7 @@ impl std::fmt::Display for AccountLinkObject {
         match self {
             AccountLinkObject::AccountLink => "account_link",
             AccountLinkObject::Noop => "",
-            AccountLinkObject::FallthroughString => "*",
+            AccountLinkObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1187,6 +1245,10 @@ pub struct AccountLink {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -1219,6 +1281,10 @@ pub struct AccountPaymentsSettings {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub statement_descriptor_kanji: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -1245,6 +1311,10 @@ pub struct AccountPayoutSettings {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub statement_descriptor: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -1322,6 +1392,10 @@ pub struct AccountRequirements {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub pending_verification: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -1345,6 +1419,10 @@ pub struct AccountRequirementsAlternative {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub original_fields_due: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -1445,8 +1523,9 @@ pub enum Code {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Code {
@@ -1508,7 +1587,7 @@ impl std::fmt::Display for Code {
                 "verification_requires_additional_memorandum_of_associations"
             }
             Code::Noop => "",
-            Code::FallthroughString => "*",
+            Code::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1546,6 +1625,10 @@ pub struct AccountRequirementsError {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub requirement: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -1560,6 +1643,10 @@ pub struct AccountSepaDebitPaymentsSettings {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub creditor_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -1601,6 +1688,10 @@ pub struct AccountSettings {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub sepa_debit_payments: Option<AccountSepaDebitPaymentsSettings>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -1642,6 +1733,10 @@ pub struct AccountTosAcceptance {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub user_agent: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -1656,8 +1751,9 @@ pub enum AccountUnificationControllerType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AccountUnificationControllerType {
@@ -1666,7 +1762,7 @@ impl std::fmt::Display for AccountUnificationControllerType {
             AccountUnificationControllerType::Account => "account",
             AccountUnificationControllerType::Application => "application",
             AccountUnificationControllerType::Noop => "",
-            AccountUnificationControllerType::FallthroughString => "*",
+            AccountUnificationControllerType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1698,6 +1794,10 @@ pub struct AccountUnificationController {
         rename = "type"
     )]
     pub type_: AccountUnificationControllerType,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -1759,6 +1859,10 @@ pub struct Address {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub state: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types:
@@ -1833,8 +1937,9 @@ pub enum AlipayAccountObject {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AlipayAccountObject {
@@ -1842,7 +1947,7 @@ impl std::fmt::Display for AlipayAccountObject {
         match self {
             AlipayAccountObject::AlipayAccount => "alipay_account",
             AlipayAccountObject::Noop => "",
-            AlipayAccountObject::FallthroughString => "*",
+            AlipayAccountObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1953,6 +2058,10 @@ pub struct AlipayAccount {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub username: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types:
@@ -2034,8 +2143,9 @@ pub enum ApiErrorsType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ApiErrorsType {
@@ -2046,7 +2156,7 @@ impl std::fmt::Display for ApiErrorsType {
             ApiErrorsType::IdempotencyError => "idempotency_error",
             ApiErrorsType::InvalidRequestError => "invalid_request_error",
             ApiErrorsType::Noop => "",
-            ApiErrorsType::FallthroughString => "*",
+            ApiErrorsType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2151,6 +2261,10 @@ pub struct ApiErrors {
         rename = "type"
     )]
     pub type_: ApiErrorsType,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -2163,8 +2277,9 @@ pub enum ApplePayDomainObject {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ApplePayDomainObject {
@@ -2172,7 +2287,7 @@ impl std::fmt::Display for ApplePayDomainObject {
         match self {
             ApplePayDomainObject::ApplePayDomain => "apple_pay_domain",
             ApplePayDomainObject::Noop => "",
-            ApplePayDomainObject::FallthroughString => "*",
+            ApplePayDomainObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2227,6 +2342,10 @@ pub struct ApplePayDomain {
      */
     #[serde(default, skip_serializing_if = "ApplePayDomainObject::is_noop")]
     pub object: ApplePayDomainObject,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -2239,8 +2358,9 @@ pub enum ApplicationObject {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ApplicationObject {
@@ -2248,7 +2368,7 @@ impl std::fmt::Display for ApplicationObject {
         match self {
             ApplicationObject::Application => "application",
             ApplicationObject::Noop => "",
-            ApplicationObject::FallthroughString => "*",
+            ApplicationObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2286,6 +2406,10 @@ pub struct Application {
      */
     #[serde(default, skip_serializing_if = "ApplicationObject::is_noop")]
     pub object: ApplicationObject,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types:
@@ -2502,8 +2626,9 @@ pub enum PlatformFeeObject {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PlatformFeeObject {
@@ -2511,7 +2636,7 @@ impl std::fmt::Display for PlatformFeeObject {
         match self {
             PlatformFeeObject::ApplicationFee => "application_fee",
             PlatformFeeObject::Noop => "",
-            PlatformFeeObject::FallthroughString => "*",
+            PlatformFeeObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2557,6 +2682,10 @@ pub struct Refunds {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -2643,6 +2772,10 @@ pub struct PlatformFee {
      * A list of refunds that have been applied to the fee.
      */
     pub refunds: Refunds,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -2659,8 +2792,9 @@ pub enum Status {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Status {
@@ -2670,7 +2804,7 @@ impl std::fmt::Display for Status {
             Status::Failed => "failed",
             Status::RequiresLocationInputs => "requires_location_inputs",
             Status::Noop => "",
-            Status::FallthroughString => "*",
+            Status::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2698,6 +2832,10 @@ pub struct AutomaticTax {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub status: Option<Status>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -2710,8 +2848,9 @@ pub enum BalanceObject {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for BalanceObject {
@@ -2719,7 +2858,7 @@ impl std::fmt::Display for BalanceObject {
         match self {
             BalanceObject::Balance => "balance",
             BalanceObject::Noop => "",
-            BalanceObject::FallthroughString => "*",
+            BalanceObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2828,6 +2967,10 @@ pub struct Balance {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub pending: Vec<BalanceAmount>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -2856,6 +2999,10 @@ pub struct BalanceAmount {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub source_types: Option<BalanceAmountBySourceType>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -2888,6 +3035,10 @@ pub struct BalanceAmountBySourceType {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub fpx: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -2902,6 +3053,10 @@ pub struct BalanceDetail {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub available: Vec<BalanceAmount>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -2914,8 +3069,9 @@ pub enum BalanceTransactionObject {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for BalanceTransactionObject {
@@ -2923,7 +3079,7 @@ impl std::fmt::Display for BalanceTransactionObject {
         match self {
             BalanceTransactionObject::BalanceTransaction => "balance_transaction",
             BalanceTransactionObject::Noop => "",
-            BalanceTransactionObject::FallthroughString => "*",
+            BalanceTransactionObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3295,8 +3451,9 @@ pub enum BalanceTransactionType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for BalanceTransactionType {
@@ -3335,7 +3492,7 @@ impl std::fmt::Display for BalanceTransactionType {
             BalanceTransactionType::TransferFailure => "transfer_failure",
             BalanceTransactionType::TransferRefund => "transfer_refund",
             BalanceTransactionType::Noop => "",
-            BalanceTransactionType::FallthroughString => "*",
+            BalanceTransactionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3485,6 +3642,10 @@ pub struct BalanceTransaction {
         rename = "type"
     )]
     pub type_: BalanceTransactionType,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -3496,8 +3657,9 @@ pub enum AvailablePayoutMethods {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AvailablePayoutMethods {
@@ -3506,7 +3668,7 @@ impl std::fmt::Display for AvailablePayoutMethods {
             AvailablePayoutMethods::Instant => "instant",
             AvailablePayoutMethods::Standard => "standard",
             AvailablePayoutMethods::Noop => "",
-            AvailablePayoutMethods::FallthroughString => "*",
+            AvailablePayoutMethods::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3528,8 +3690,9 @@ pub enum BankAccountObject {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for BankAccountObject {
@@ -3537,7 +3700,7 @@ impl std::fmt::Display for BankAccountObject {
         match self {
             BankAccountObject::BankAccount => "bank_account",
             BankAccountObject::Noop => "",
-            BankAccountObject::FallthroughString => "*",
+            BankAccountObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3757,6 +3920,10 @@ pub struct BankAccount {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub status: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -3794,6 +3961,10 @@ pub struct BillingDetails {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub phone: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -3806,8 +3977,9 @@ pub enum PortalConfigurationObject {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PortalConfigurationObject {
@@ -3815,7 +3987,7 @@ impl std::fmt::Display for PortalConfigurationObject {
         match self {
             PortalConfigurationObject::BillingPortalConfiguration => "billing_portal.configuration",
             PortalConfigurationObject::Noop => "",
-            PortalConfigurationObject::FallthroughString => "*",
+            PortalConfigurationObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3921,6 +4093,10 @@ pub struct PortalConfiguration {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub updated: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types:
@@ -4073,8 +4249,9 @@ pub enum Locale {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Locale {
@@ -4128,7 +4305,7 @@ impl std::fmt::Display for Locale {
             Locale::ZhHk => "zh-HK",
             Locale::ZhTw => "zh-TW",
             Locale::Noop => "",
-            Locale::FallthroughString => "*",
+            Locale::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -4150,8 +4327,9 @@ pub enum PortalSessionObject {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PortalSessionObject {
@@ -4159,7 +4337,7 @@ impl std::fmt::Display for PortalSessionObject {
         match self {
             PortalSessionObject::BillingPortalSession => "billing_portal.session",
             PortalSessionObject::Noop => "",
-            PortalSessionObject::FallthroughString => "*",
+            PortalSessionObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -4273,6 +4451,10 @@ pub struct PortalSession {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -4285,8 +4467,9 @@ pub enum BitcoinReceiverObject {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for BitcoinReceiverObject {
@@ -4294,7 +4477,7 @@ impl std::fmt::Display for BitcoinReceiverObject {
         match self {
             BitcoinReceiverObject::BitcoinReceiver => "bitcoin_receiver",
             BitcoinReceiverObject::Noop => "",
-            BitcoinReceiverObject::FallthroughString => "*",
+            BitcoinReceiverObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -4340,6 +4523,10 @@ pub struct Transactions {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -4530,6 +4717,10 @@ pub struct BitcoinReceiver {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub used_for_payment: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -4542,8 +4733,9 @@ pub enum BitcoinTransactionObject {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for BitcoinTransactionObject {
@@ -4551,7 +4743,7 @@ impl std::fmt::Display for BitcoinTransactionObject {
         match self {
             BitcoinTransactionObject::BitcoinTransaction => "bitcoin_transaction",
             BitcoinTransactionObject::Noop => "",
-            BitcoinTransactionObject::FallthroughString => "*",
+            BitcoinTransactionObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -4625,6 +4817,10 @@ pub struct BitcoinTransaction {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub receiver: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -4637,8 +4833,9 @@ pub enum CapabilityObject {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for CapabilityObject {
@@ -4646,7 +4843,7 @@ impl std::fmt::Display for CapabilityObject {
         match self {
             CapabilityObject::Capability => "capability",
             CapabilityObject::Noop => "",
-            CapabilityObject::FallthroughString => "*",
+            CapabilityObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -4676,8 +4873,9 @@ pub enum CapabilityStatus {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for CapabilityStatus {
@@ -4689,7 +4887,7 @@ impl std::fmt::Display for CapabilityStatus {
             CapabilityStatus::Pending => "pending",
             CapabilityStatus::Unrequested => "unrequested",
             CapabilityStatus::Noop => "",
-            CapabilityStatus::FallthroughString => "*",
+            CapabilityStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -4759,6 +4957,10 @@ pub struct Capability {
      */
     #[serde(default, skip_serializing_if = "CapabilityStatus::is_noop")]
     pub status: CapabilityStatus,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -4771,8 +4973,9 @@ pub enum CardObject {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for CardObject {
@@ -4780,7 +4983,7 @@ impl std::fmt::Display for CardObject {
         match self {
             CardObject::Card => "card",
             CardObject::Noop => "",
-            CardObject::FallthroughString => "*",
+            CardObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -5186,6 +5389,10 @@ pub struct Card {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub tokenization_method: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -5206,6 +5413,10 @@ pub struct CardGeneratedFromPaymentMethodDetails {
         rename = "type"
     )]
     pub type_: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -5238,11 +5449,20 @@ pub struct CardIssuingAccountTermsOfService {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub user_agent: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// When confirming a PaymentIntent with Stripe.js, Stripe.js depends on the contents of this dictionary to invoke authentication flows. The shape of the contents is subject to change and is only intended to be used by Stripe.js.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct UseStripeSdk {}
+pub struct UseStripeSdk {
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
 
 /// All of the following types:
 ///
@@ -5381,8 +5601,9 @@ pub enum ChargeObject {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ChargeObject {
@@ -5390,7 +5611,7 @@ impl std::fmt::Display for ChargeObject {
         match self {
             ChargeObject::Charge => "charge",
             ChargeObject::Noop => "",
-            ChargeObject::FallthroughString => "*",
+            ChargeObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -5546,6 +5767,10 @@ pub struct RefundList {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types:
@@ -5670,8 +5895,9 @@ pub enum ChargeStatus {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ChargeStatus {
@@ -5681,7 +5907,7 @@ impl std::fmt::Display for ChargeStatus {
             ChargeStatus::Pending => "pending",
             ChargeStatus::Succeeded => "succeeded",
             ChargeStatus::Noop => "",
-            ChargeStatus::FallthroughString => "*",
+            ChargeStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -6098,6 +6324,10 @@ pub struct Charge {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub transfer_group: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -6121,6 +6351,10 @@ pub struct ChargeFraudDetails {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub user_report: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types:
@@ -6233,6 +6467,10 @@ pub struct ChargeOutcome {
         rename = "type"
     )]
     pub type_: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -6248,6 +6486,10 @@ pub struct ChargeTransferData {
     )]
     pub amount: i64,
     pub destination: Box<AccountAnyOf>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -6262,8 +6504,9 @@ pub enum BillingAddressCollection {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for BillingAddressCollection {
@@ -6272,7 +6515,7 @@ impl std::fmt::Display for BillingAddressCollection {
             BillingAddressCollection::Auto => "auto",
             BillingAddressCollection::Required => "required",
             BillingAddressCollection::Noop => "",
-            BillingAddressCollection::FallthroughString => "*",
+            BillingAddressCollection::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -6296,8 +6539,9 @@ pub enum CustomerCreation {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for CustomerCreation {
@@ -6306,7 +6550,7 @@ impl std::fmt::Display for CustomerCreation {
             CustomerCreation::Always => "always",
             CustomerCreation::IfRequired => "if_required",
             CustomerCreation::Noop => "",
-            CustomerCreation::FallthroughString => "*",
+            CustomerCreation::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -6352,6 +6596,10 @@ pub struct LineItems {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -6444,8 +6692,9 @@ pub enum SessionLocale {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SessionLocale {
@@ -6493,7 +6742,7 @@ impl std::fmt::Display for SessionLocale {
             SessionLocale::ZhHk => "zh-HK",
             SessionLocale::ZhTw => "zh-TW",
             SessionLocale::Noop => "",
-            SessionLocale::FallthroughString => "*",
+            SessionLocale::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -6519,8 +6768,9 @@ pub enum Mode {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Mode {
@@ -6530,7 +6780,7 @@ impl std::fmt::Display for Mode {
             Mode::Setup => "setup",
             Mode::Subscription => "subscription",
             Mode::Noop => "",
-            Mode::FallthroughString => "*",
+            Mode::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -6552,8 +6802,9 @@ pub enum SessionObject {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SessionObject {
@@ -6561,7 +6812,7 @@ impl std::fmt::Display for SessionObject {
         match self {
             SessionObject::CheckoutSession => "checkout.session",
             SessionObject::Noop => "",
-            SessionObject::FallthroughString => "*",
+            SessionObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -6640,8 +6891,9 @@ pub enum PaymentStatus {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PaymentStatus {
@@ -6651,7 +6903,7 @@ impl std::fmt::Display for PaymentStatus {
             PaymentStatus::Paid => "paid",
             PaymentStatus::Unpaid => "unpaid",
             PaymentStatus::Noop => "",
-            PaymentStatus::FallthroughString => "*",
+            PaymentStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -6795,8 +7047,9 @@ pub enum SessionStatus {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SessionStatus {
@@ -6806,7 +7059,7 @@ impl std::fmt::Display for SessionStatus {
             SessionStatus::Expired => "expired",
             SessionStatus::Open => "open",
             SessionStatus::Noop => "",
-            SessionStatus::FallthroughString => "*",
+            SessionStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -6837,8 +7090,9 @@ pub enum SubmitType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SubmitType {
@@ -6849,7 +7103,7 @@ impl std::fmt::Display for SubmitType {
             SubmitType::Donate => "donate",
             SubmitType::Pay => "pay",
             SubmitType::Noop => "",
-            SubmitType::FallthroughString => "*",
+            SubmitType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -7430,6 +7684,10 @@ pub struct Session {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -7441,8 +7699,9 @@ pub enum DefaultFor {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DefaultFor {
@@ -7451,7 +7710,7 @@ impl std::fmt::Display for DefaultFor {
             DefaultFor::Invoice => "invoice",
             DefaultFor::Subscription => "subscription",
             DefaultFor::Noop => "",
-            DefaultFor::FallthroughString => "*",
+            DefaultFor::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -7477,8 +7736,9 @@ pub enum PaymentSchedule {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PaymentSchedule {
@@ -7488,7 +7748,7 @@ impl std::fmt::Display for PaymentSchedule {
             PaymentSchedule::Interval => "interval",
             PaymentSchedule::Sporadic => "sporadic",
             PaymentSchedule::Noop => "",
-            PaymentSchedule::FallthroughString => "*",
+            PaymentSchedule::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -7512,8 +7772,9 @@ pub enum TransactionType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for TransactionType {
@@ -7522,7 +7783,7 @@ impl std::fmt::Display for TransactionType {
             TransactionType::Business => "business",
             TransactionType::Personal => "personal",
             TransactionType::Noop => "",
-            TransactionType::FallthroughString => "*",
+            TransactionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -7574,6 +7835,10 @@ pub struct CheckoutAcssDebitMandateOptions {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub transaction_type: Option<TransactionType>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -7588,8 +7853,9 @@ pub enum Currency {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Currency {
@@ -7598,7 +7864,7 @@ impl std::fmt::Display for Currency {
             Currency::Cad => "cad",
             Currency::Usd => "usd",
             Currency::Noop => "",
-            Currency::FallthroughString => "*",
+            Currency::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -7624,8 +7890,9 @@ pub enum VerificationMethod {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for VerificationMethod {
@@ -7635,7 +7902,7 @@ impl std::fmt::Display for VerificationMethod {
             VerificationMethod::Instant => "instant",
             VerificationMethod::Microdeposits => "microdeposits",
             VerificationMethod::Noop => "",
-            VerificationMethod::FallthroughString => "*",
+            VerificationMethod::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -7665,6 +7932,10 @@ pub struct CheckoutAcssDebitPaymentMethodOptions {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub verification_method: Option<VerificationMethod>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -7679,6 +7950,10 @@ pub struct CheckoutOxxoPaymentMethodOptions {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub expires_after_days: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -7693,6 +7968,10 @@ pub struct CheckoutKonbiniPaymentMethodOptions {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub expires_after_days: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -7723,6 +8002,10 @@ pub struct CheckoutSessionPaymentMethodOptions {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub us_bank_account: Option<CheckoutUsBankAccountPaymentMethodOptions>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -7737,8 +8020,9 @@ pub enum CheckoutUsBankAccountPaymentMethodOptionsVerification {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for CheckoutUsBankAccountPaymentMethodOptionsVerification {
@@ -7747,7 +8031,7 @@ impl std::fmt::Display for CheckoutUsBankAccountPaymentMethodOptionsVerification
             CheckoutUsBankAccountPaymentMethodOptionsVerification::Automatic => "automatic",
             CheckoutUsBankAccountPaymentMethodOptionsVerification::Instant => "instant",
             CheckoutUsBankAccountPaymentMethodOptionsVerification::Noop => "",
-            CheckoutUsBankAccountPaymentMethodOptionsVerification::FallthroughString => "*",
+            CheckoutUsBankAccountPaymentMethodOptionsVerification::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -7770,6 +8054,10 @@ pub struct CheckoutUsBankAccountPaymentMethodOptions {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub verification_method: Option<CheckoutUsBankAccountPaymentMethodOptionsVerification>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -7782,8 +8070,9 @@ pub enum ConnectCollectionTransferObject {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ConnectCollectionTransferObject {
@@ -7793,7 +8082,7 @@ impl std::fmt::Display for ConnectCollectionTransferObject {
                 "connect_collection_transfer"
             }
             ConnectCollectionTransferObject::Noop => "",
-            ConnectCollectionTransferObject::FallthroughString => "*",
+            ConnectCollectionTransferObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -7852,6 +8141,10 @@ pub struct ConnectCollectionTransfer {
         skip_serializing_if = "ConnectCollectionTransferObject::is_noop"
     )]
     pub object: ConnectCollectionTransferObject,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -7864,8 +8157,9 @@ pub enum CountrySpecObject {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for CountrySpecObject {
@@ -7873,7 +8167,7 @@ impl std::fmt::Display for CountrySpecObject {
         match self {
             CountrySpecObject::CountrySpec => "country_spec",
             CountrySpecObject::Noop => "",
-            CountrySpecObject::FallthroughString => "*",
+            CountrySpecObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -7956,6 +8250,10 @@ pub struct CountrySpec {
      *
      */
     pub verification_fields: CountrySpecVerificationFields,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -7979,6 +8277,10 @@ pub struct CountrySpecVerificationFieldDetails {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub minimum: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -7992,6 +8294,10 @@ pub struct CountrySpecVerificationFields {
      *
      */
     pub individual: CountrySpecVerificationFieldDetails,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -8008,8 +8314,9 @@ pub enum Duration {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Duration {
@@ -8019,7 +8326,7 @@ impl std::fmt::Display for Duration {
             Duration::Once => "once",
             Duration::Repeating => "repeating",
             Duration::Noop => "",
-            Duration::FallthroughString => "*",
+            Duration::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -8041,8 +8348,9 @@ pub enum CouponObject {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for CouponObject {
@@ -8050,7 +8358,7 @@ impl std::fmt::Display for CouponObject {
         match self {
             CouponObject::Coupon => "coupon",
             CouponObject::Noop => "",
-            CouponObject::FallthroughString => "*",
+            CouponObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -8213,6 +8521,10 @@ pub struct Coupon {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub valid: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -8227,6 +8539,10 @@ pub struct CouponAppliesTo {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub products: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types:
@@ -8316,6 +8632,10 @@ pub struct Lines {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -8328,8 +8648,9 @@ pub enum CreditNoteObject {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for CreditNoteObject {
@@ -8337,7 +8658,7 @@ impl std::fmt::Display for CreditNoteObject {
         match self {
             CreditNoteObject::CreditNote => "credit_note",
             CreditNoteObject::Noop => "",
-            CreditNoteObject::FallthroughString => "*",
+            CreditNoteObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -8365,8 +8686,9 @@ pub enum Reason {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Reason {
@@ -8377,7 +8699,7 @@ impl std::fmt::Display for Reason {
             Reason::OrderChange => "order_change",
             Reason::ProductUnsatisfactory => "product_unsatisfactory",
             Reason::Noop => "",
-            Reason::FallthroughString => "*",
+            Reason::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -8453,8 +8775,9 @@ pub enum CreditNoteStatus {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for CreditNoteStatus {
@@ -8463,7 +8786,7 @@ impl std::fmt::Display for CreditNoteStatus {
             CreditNoteStatus::Issued => "issued",
             CreditNoteStatus::Void => "void",
             CreditNoteStatus::Noop => "",
-            CreditNoteStatus::FallthroughString => "*",
+            CreditNoteStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -8487,8 +8810,9 @@ pub enum CreditNoteType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for CreditNoteType {
@@ -8497,7 +8821,7 @@ impl std::fmt::Display for CreditNoteType {
             CreditNoteType::PostPayment => "post_payment",
             CreditNoteType::PrePayment => "pre_payment",
             CreditNoteType::Noop => "",
-            CreditNoteType::FallthroughString => "*",
+            CreditNoteType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -8707,6 +9031,10 @@ pub struct CreditNote {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub voided_at: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -8719,8 +9047,9 @@ pub enum CreditNoteLineItemObject {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for CreditNoteLineItemObject {
@@ -8728,7 +9057,7 @@ impl std::fmt::Display for CreditNoteLineItemObject {
         match self {
             CreditNoteLineItemObject::CreditNoteLineItem => "credit_note_line_item",
             CreditNoteLineItemObject::Noop => "",
-            CreditNoteLineItemObject::FallthroughString => "*",
+            CreditNoteLineItemObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -8752,8 +9081,9 @@ pub enum CreditNoteLineItemType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for CreditNoteLineItemType {
@@ -8762,7 +9092,7 @@ impl std::fmt::Display for CreditNoteLineItemType {
             CreditNoteLineItemType::CustomLineItem => "custom_line_item",
             CreditNoteLineItemType::InvoiceLineItem => "invoice_line_item",
             CreditNoteLineItemType::Noop => "",
-            CreditNoteLineItemType::FallthroughString => "*",
+            CreditNoteLineItemType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -8898,6 +9228,10 @@ pub struct CreditNoteLineItem {
         deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub unit_amount_decimal: f64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types:
@@ -8971,6 +9305,10 @@ pub struct InvoiceTaxAmount {
     )]
     pub inclusive: bool,
     pub tax_rate: Box<TaxRateAnyOf>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types:
@@ -9094,8 +9432,9 @@ pub enum CustomerObject {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for CustomerObject {
@@ -9103,7 +9442,7 @@ impl std::fmt::Display for CustomerObject {
         match self {
             CustomerObject::Customer => "customer",
             CustomerObject::Noop => "",
-            CustomerObject::FallthroughString => "*",
+            CustomerObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -9231,6 +9570,10 @@ pub struct Sources {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The customer's current subscriptions, if any.
@@ -9267,6 +9610,10 @@ pub struct Subscriptions {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -9283,8 +9630,9 @@ pub enum TaxExempt {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for TaxExempt {
@@ -9294,7 +9642,7 @@ impl std::fmt::Display for TaxExempt {
             TaxExempt::None => "none",
             TaxExempt::Reverse => "reverse",
             TaxExempt::Noop => "",
-            TaxExempt::FallthroughString => "*",
+            TaxExempt::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -9340,6 +9688,10 @@ pub struct TaxIds {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types:
@@ -9615,6 +9967,10 @@ pub struct Customer {
      *  Related guide: [Save a card during payment](https://stripe.com/docs/payments/save-during-payment).
      */
     pub test_clock: Box<Option<TestClockAnyOf>>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -9629,8 +9985,9 @@ pub enum CustomerAcceptanceType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for CustomerAcceptanceType {
@@ -9639,7 +9996,7 @@ impl std::fmt::Display for CustomerAcceptanceType {
             CustomerAcceptanceType::Offline => "offline",
             CustomerAcceptanceType::Online => "online",
             CustomerAcceptanceType::Noop => "",
-            CustomerAcceptanceType::FallthroughString => "*",
+            CustomerAcceptanceType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -9682,6 +10039,10 @@ pub struct CustomerAcceptance {
         rename = "type"
     )]
     pub type_: CustomerAcceptanceType,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types:
@@ -9794,8 +10155,9 @@ pub enum CustomerBalanceTransactionObject {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for CustomerBalanceTransactionObject {
@@ -9805,7 +10167,7 @@ impl std::fmt::Display for CustomerBalanceTransactionObject {
                 "customer_balance_transaction"
             }
             CustomerBalanceTransactionObject::Noop => "",
-            CustomerBalanceTransactionObject::FallthroughString => "*",
+            CustomerBalanceTransactionObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -9843,8 +10205,9 @@ pub enum CustomerBalanceTransactionType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for CustomerBalanceTransactionType {
@@ -9860,7 +10223,7 @@ impl std::fmt::Display for CustomerBalanceTransactionType {
             CustomerBalanceTransactionType::UnappliedFromInvoice => "unapplied_from_invoice",
             CustomerBalanceTransactionType::UnspentReceiverCredit => "unspent_receiver_credit",
             CustomerBalanceTransactionType::Noop => "",
-            CustomerBalanceTransactionType::FallthroughString => "*",
+            CustomerBalanceTransactionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -9997,6 +10360,10 @@ pub struct CustomerBalanceTransaction {
         rename = "type"
     )]
     pub type_: CustomerBalanceTransactionType,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -10015,8 +10382,9 @@ pub enum CustomerTaxAutomatic {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for CustomerTaxAutomatic {
@@ -10027,7 +10395,7 @@ impl std::fmt::Display for CustomerTaxAutomatic {
             CustomerTaxAutomatic::Supported => "supported",
             CustomerTaxAutomatic::UnrecognizedLocation => "unrecognized_location",
             CustomerTaxAutomatic::Noop => "",
-            CustomerTaxAutomatic::FallthroughString => "*",
+            CustomerTaxAutomatic::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -10061,6 +10429,10 @@ pub struct CustomerTax {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub location: Option<CustomerTaxLocation>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -10079,8 +10451,9 @@ pub enum Source {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Source {
@@ -10091,7 +10464,7 @@ impl std::fmt::Display for Source {
             Source::PaymentMethod => "payment_method",
             Source::ShippingDestination => "shipping_destination",
             Source::Noop => "",
-            Source::FallthroughString => "*",
+            Source::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -10129,6 +10502,10 @@ pub struct CustomerTaxLocation {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub state: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -10156,6 +10533,10 @@ pub struct DeletedAccount {
      */
     #[serde(default, skip_serializing_if = "AccountObject::is_noop")]
     pub object: AccountObject,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -10183,6 +10564,10 @@ pub struct AlipayDeletedAccount {
      */
     #[serde(default, skip_serializing_if = "AlipayAccountObject::is_noop")]
     pub object: AlipayAccountObject,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -10210,6 +10595,10 @@ pub struct DeletedApplePayDomain {
      */
     #[serde(default, skip_serializing_if = "ApplePayDomainObject::is_noop")]
     pub object: ApplePayDomainObject,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -10246,6 +10635,10 @@ pub struct DeletedBankAccount {
      */
     #[serde(default, skip_serializing_if = "BankAccountObject::is_noop")]
     pub object: BankAccountObject,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -10273,6 +10666,10 @@ pub struct BitcoinDeletedReceiver {
      */
     #[serde(default, skip_serializing_if = "BitcoinReceiverObject::is_noop")]
     pub object: BitcoinReceiverObject,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -10309,6 +10706,10 @@ pub struct DeletedCard {
      */
     #[serde(default, skip_serializing_if = "CardObject::is_noop")]
     pub object: CardObject,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -10336,6 +10737,10 @@ pub struct DeletedCoupon {
      */
     #[serde(default, skip_serializing_if = "CouponObject::is_noop")]
     pub object: CouponObject,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -10363,6 +10768,10 @@ pub struct DeletedCustomer {
      */
     #[serde(default, skip_serializing_if = "CustomerObject::is_noop")]
     pub object: CustomerObject,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -10375,8 +10784,9 @@ pub enum DeletedDiscountObject {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DeletedDiscountObject {
@@ -10384,7 +10794,7 @@ impl std::fmt::Display for DeletedDiscountObject {
         match self {
             DeletedDiscountObject::Discount => "discount",
             DeletedDiscountObject::Noop => "",
-            DeletedDiscountObject::FallthroughString => "*",
+            DeletedDiscountObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -10529,6 +10939,10 @@ pub struct DeletedDiscount {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub subscription: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types:
@@ -10577,8 +10991,9 @@ pub enum DeletedInvoiceObject {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DeletedInvoiceObject {
@@ -10586,7 +11001,7 @@ impl std::fmt::Display for DeletedInvoiceObject {
         match self {
             DeletedInvoiceObject::Invoice => "invoice",
             DeletedInvoiceObject::Noop => "",
-            DeletedInvoiceObject::FallthroughString => "*",
+            DeletedInvoiceObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -10623,6 +11038,10 @@ pub struct DeletedInvoice {
      */
     #[serde(default, skip_serializing_if = "DeletedInvoiceObject::is_noop")]
     pub object: DeletedInvoiceObject,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -10635,8 +11054,9 @@ pub enum DeletedInvoiceItemObject {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DeletedInvoiceItemObject {
@@ -10644,7 +11064,7 @@ impl std::fmt::Display for DeletedInvoiceItemObject {
         match self {
             DeletedInvoiceItemObject::Invoiceitem => "invoiceitem",
             DeletedInvoiceItemObject::Noop => "",
-            DeletedInvoiceItemObject::FallthroughString => "*",
+            DeletedInvoiceItemObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -10681,6 +11101,10 @@ pub struct DeletedInvoiceItem {
      */
     #[serde(default, skip_serializing_if = "DeletedInvoiceItemObject::is_noop")]
     pub object: DeletedInvoiceItemObject,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types:
@@ -10753,8 +11177,9 @@ pub enum DeletedPersonObject {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DeletedPersonObject {
@@ -10762,7 +11187,7 @@ impl std::fmt::Display for DeletedPersonObject {
         match self {
             DeletedPersonObject::Person => "person",
             DeletedPersonObject::Noop => "",
-            DeletedPersonObject::FallthroughString => "*",
+            DeletedPersonObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -10799,6 +11224,10 @@ pub struct DeletedPerson {
      */
     #[serde(default, skip_serializing_if = "DeletedPersonObject::is_noop")]
     pub object: DeletedPersonObject,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -10811,8 +11240,9 @@ pub enum DeletedPlanObject {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DeletedPlanObject {
@@ -10820,7 +11250,7 @@ impl std::fmt::Display for DeletedPlanObject {
         match self {
             DeletedPlanObject::Plan => "plan",
             DeletedPlanObject::Noop => "",
-            DeletedPlanObject::FallthroughString => "*",
+            DeletedPlanObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -10857,6 +11287,10 @@ pub struct DeletedPlan {
      */
     #[serde(default, skip_serializing_if = "DeletedPlanObject::is_noop")]
     pub object: DeletedPlanObject,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -10869,8 +11303,9 @@ pub enum DeletedPriceObject {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DeletedPriceObject {
@@ -10878,7 +11313,7 @@ impl std::fmt::Display for DeletedPriceObject {
         match self {
             DeletedPriceObject::Price => "price",
             DeletedPriceObject::Noop => "",
-            DeletedPriceObject::FallthroughString => "*",
+            DeletedPriceObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -10915,6 +11350,10 @@ pub struct DeletedPrice {
      */
     #[serde(default, skip_serializing_if = "DeletedPriceObject::is_noop")]
     pub object: DeletedPriceObject,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -10927,8 +11366,9 @@ pub enum DeletedProductObject {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DeletedProductObject {
@@ -10936,7 +11376,7 @@ impl std::fmt::Display for DeletedProductObject {
         match self {
             DeletedProductObject::Product => "product",
             DeletedProductObject::Noop => "",
-            DeletedProductObject::FallthroughString => "*",
+            DeletedProductObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -10973,6 +11413,10 @@ pub struct DeletedProduct {
      */
     #[serde(default, skip_serializing_if = "DeletedProductObject::is_noop")]
     pub object: DeletedProductObject,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -10985,8 +11429,9 @@ pub enum RadarListDeletedObject {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for RadarListDeletedObject {
@@ -10994,7 +11439,7 @@ impl std::fmt::Display for RadarListDeletedObject {
         match self {
             RadarListDeletedObject::RadarValueList => "radar.value_list",
             RadarListDeletedObject::Noop => "",
-            RadarListDeletedObject::FallthroughString => "*",
+            RadarListDeletedObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -11031,6 +11476,10 @@ pub struct RadarListDeleted {
      */
     #[serde(default, skip_serializing_if = "RadarListDeletedObject::is_noop")]
     pub object: RadarListDeletedObject,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -11043,8 +11492,9 @@ pub enum RadarListDeletedItemObject {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for RadarListDeletedItemObject {
@@ -11052,7 +11502,7 @@ impl std::fmt::Display for RadarListDeletedItemObject {
         match self {
             RadarListDeletedItemObject::RadarValueListItem => "radar.value_list_item",
             RadarListDeletedItemObject::Noop => "",
-            RadarListDeletedItemObject::FallthroughString => "*",
+            RadarListDeletedItemObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -11089,6 +11539,10 @@ pub struct RadarListDeletedItem {
      */
     #[serde(default, skip_serializing_if = "RadarListDeletedItemObject::is_noop")]
     pub object: RadarListDeletedItemObject,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -11101,8 +11555,9 @@ pub enum DeletedRecipientObject {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DeletedRecipientObject {
@@ -11110,7 +11565,7 @@ impl std::fmt::Display for DeletedRecipientObject {
         match self {
             DeletedRecipientObject::Recipient => "recipient",
             DeletedRecipientObject::Noop => "",
-            DeletedRecipientObject::FallthroughString => "*",
+            DeletedRecipientObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -11147,6 +11602,10 @@ pub struct DeletedRecipient {
      */
     #[serde(default, skip_serializing_if = "DeletedRecipientObject::is_noop")]
     pub object: DeletedRecipientObject,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -11159,8 +11618,9 @@ pub enum DeletedSkuObject {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DeletedSkuObject {
@@ -11168,7 +11628,7 @@ impl std::fmt::Display for DeletedSkuObject {
         match self {
             DeletedSkuObject::Sku => "sku",
             DeletedSkuObject::Noop => "",
-            DeletedSkuObject::FallthroughString => "*",
+            DeletedSkuObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -11205,6 +11665,10 @@ pub struct DeletedSku {
      */
     #[serde(default, skip_serializing_if = "DeletedSkuObject::is_noop")]
     pub object: DeletedSkuObject,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -11217,8 +11681,9 @@ pub enum DeletedSubscriptionItemObject {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DeletedSubscriptionItemObject {
@@ -11226,7 +11691,7 @@ impl std::fmt::Display for DeletedSubscriptionItemObject {
         match self {
             DeletedSubscriptionItemObject::SubscriptionItem => "subscription_item",
             DeletedSubscriptionItemObject::Noop => "",
-            DeletedSubscriptionItemObject::FallthroughString => "*",
+            DeletedSubscriptionItemObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -11266,6 +11731,10 @@ pub struct DeletedSubscriptionItem {
         skip_serializing_if = "DeletedSubscriptionItemObject::is_noop"
     )]
     pub object: DeletedSubscriptionItemObject,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -11278,8 +11747,9 @@ pub enum DeletedTaxObject {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DeletedTaxObject {
@@ -11287,7 +11757,7 @@ impl std::fmt::Display for DeletedTaxObject {
         match self {
             DeletedTaxObject::TaxId => "tax_id",
             DeletedTaxObject::Noop => "",
-            DeletedTaxObject::FallthroughString => "*",
+            DeletedTaxObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -11324,6 +11794,10 @@ pub struct DeletedTaxId {
      */
     #[serde(default, skip_serializing_if = "DeletedTaxObject::is_noop")]
     pub object: DeletedTaxObject,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -11336,8 +11810,9 @@ pub enum DeletedTerminalLocationObject {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DeletedTerminalLocationObject {
@@ -11345,7 +11820,7 @@ impl std::fmt::Display for DeletedTerminalLocationObject {
         match self {
             DeletedTerminalLocationObject::TerminalLocation => "terminal.location",
             DeletedTerminalLocationObject::Noop => "",
-            DeletedTerminalLocationObject::FallthroughString => "*",
+            DeletedTerminalLocationObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -11385,6 +11860,10 @@ pub struct DeletedTerminalLocation {
         skip_serializing_if = "DeletedTerminalLocationObject::is_noop"
     )]
     pub object: DeletedTerminalLocationObject,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -11397,8 +11876,9 @@ pub enum DeletedTerminalReaderObject {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DeletedTerminalReaderObject {
@@ -11406,7 +11886,7 @@ impl std::fmt::Display for DeletedTerminalReaderObject {
         match self {
             DeletedTerminalReaderObject::TerminalReader => "terminal.reader",
             DeletedTerminalReaderObject::Noop => "",
-            DeletedTerminalReaderObject::FallthroughString => "*",
+            DeletedTerminalReaderObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -11443,6 +11923,10 @@ pub struct DeletedTerminalReader {
      */
     #[serde(default, skip_serializing_if = "DeletedTerminalReaderObject::is_noop")]
     pub object: DeletedTerminalReaderObject,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -11455,8 +11939,9 @@ pub enum DeletedTestClockObject {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DeletedTestClockObject {
@@ -11464,7 +11949,7 @@ impl std::fmt::Display for DeletedTestClockObject {
         match self {
             DeletedTestClockObject::TestHelpersClock => "test_helpers.test_clock",
             DeletedTestClockObject::Noop => "",
-            DeletedTestClockObject::FallthroughString => "*",
+            DeletedTestClockObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -11501,6 +11986,10 @@ pub struct DeletedTestClock {
      */
     #[serde(default, skip_serializing_if = "DeletedTestClockObject::is_noop")]
     pub object: DeletedTestClockObject,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -11513,8 +12002,9 @@ pub enum DeletedWebhookEndpointObject {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DeletedWebhookEndpointObject {
@@ -11522,7 +12012,7 @@ impl std::fmt::Display for DeletedWebhookEndpointObject {
         match self {
             DeletedWebhookEndpointObject::WebhookEndpoint => "webhook_endpoint",
             DeletedWebhookEndpointObject::Noop => "",
-            DeletedWebhookEndpointObject::FallthroughString => "*",
+            DeletedWebhookEndpointObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -11559,6 +12049,10 @@ pub struct DeletedWebhookEndpoint {
      */
     #[serde(default, skip_serializing_if = "DeletedWebhookEndpointObject::is_noop")]
     pub object: DeletedWebhookEndpointObject,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -11601,6 +12095,10 @@ pub struct DeliveryEstimate {
         rename = "type"
     )]
     pub type_: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A discount represents the actual application of a coupon to a particular
@@ -11720,6 +12218,10 @@ pub struct DiscountData {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub subscription: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types:
@@ -11799,6 +12301,10 @@ pub struct DiscountsResourceDiscountAmount {
     )]
     pub amount: i64,
     pub discount: Box<DiscountAnyOf>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -11811,8 +12317,9 @@ pub enum DisputeObject {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DisputeObject {
@@ -11820,7 +12327,7 @@ impl std::fmt::Display for DisputeObject {
         match self {
             DisputeObject::Dispute => "dispute",
             DisputeObject::Noop => "",
-            DisputeObject::FallthroughString => "*",
+            DisputeObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -11856,8 +12363,9 @@ pub enum DisputeStatus {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DisputeStatus {
@@ -11872,7 +12380,7 @@ impl std::fmt::Display for DisputeStatus {
             DisputeStatus::WarningUnderReview => "warning_under_review",
             DisputeStatus::Won => "won",
             DisputeStatus::Noop => "",
-            DisputeStatus::FallthroughString => "*",
+            DisputeStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -12001,6 +12509,10 @@ pub struct Dispute {
      */
     #[serde(default, skip_serializing_if = "DisputeStatus::is_noop")]
     pub status: DisputeStatus,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -12204,6 +12716,10 @@ pub struct DisputeEvidence {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub uncategorized_text: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -12243,6 +12759,10 @@ pub struct DisputeEvidenceDetails {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub submission_count: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -12266,6 +12786,10 @@ pub struct EmailSent {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub email_sent_to: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -12278,8 +12802,9 @@ pub enum EphemeralKeyObject {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EphemeralKeyObject {
@@ -12287,7 +12812,7 @@ impl std::fmt::Display for EphemeralKeyObject {
         match self {
             EphemeralKeyObject::EphemeralKey => "ephemeral_key",
             EphemeralKeyObject::Noop => "",
-            EphemeralKeyObject::FallthroughString => "*",
+            EphemeralKeyObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -12351,6 +12876,10 @@ pub struct EphemeralKey {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub secret: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// An error response from the Stripe API
@@ -12360,6 +12889,10 @@ pub struct Error {
      *
      */
     pub error: ApiErrors,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -12372,8 +12905,9 @@ pub enum EventObject {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EventObject {
@@ -12381,7 +12915,7 @@ impl std::fmt::Display for EventObject {
         match self {
             EventObject::Event => "event",
             EventObject::Noop => "",
-            EventObject::FallthroughString => "*",
+            EventObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -12557,6 +13091,10 @@ pub struct Event {
         rename = "type"
     )]
     pub type_: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -12569,8 +13107,9 @@ pub enum ExchangeRateObject {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ExchangeRateObject {
@@ -12578,7 +13117,7 @@ impl std::fmt::Display for ExchangeRateObject {
         match self {
             ExchangeRateObject::ExchangeRate => "exchange_rate",
             ExchangeRateObject::Noop => "",
-            ExchangeRateObject::FallthroughString => "*",
+            ExchangeRateObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -12626,6 +13165,10 @@ pub struct ExchangeRate {
         deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub rates: f64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -12677,6 +13220,10 @@ pub struct Fee {
         rename = "type"
     )]
     pub type_: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -12689,8 +13236,9 @@ pub enum FeeRefundObject {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for FeeRefundObject {
@@ -12698,7 +13246,7 @@ impl std::fmt::Display for FeeRefundObject {
         match self {
             FeeRefundObject::FeeRefund => "fee_refund",
             FeeRefundObject::Noop => "",
-            FeeRefundObject::FallthroughString => "*",
+            FeeRefundObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -12780,6 +13328,10 @@ pub struct FeeRefund {
      */
     #[serde(default, skip_serializing_if = "FeeRefundObject::is_noop")]
     pub object: FeeRefundObject,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A list of [file links](https://stripe.com/docs/api#file_links) that point at this file.
@@ -12816,6 +13368,10 @@ pub struct Links {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -12828,8 +13384,9 @@ pub enum FileObject {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for FileObject {
@@ -12837,7 +13394,7 @@ impl std::fmt::Display for FileObject {
         match self {
             FileObject::File => "file",
             FileObject::Noop => "",
-            FileObject::FallthroughString => "*",
+            FileObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -12885,8 +13442,9 @@ pub enum Purpose {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Purpose {
@@ -12907,7 +13465,7 @@ impl std::fmt::Display for Purpose {
             Purpose::SigmaScheduledQuery => "sigma_scheduled_query",
             Purpose::TaxDocumentUserUpload => "tax_document_user_upload",
             Purpose::Noop => "",
-            Purpose::FallthroughString => "*",
+            Purpose::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -13046,6 +13604,10 @@ pub struct File {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -13058,8 +13620,9 @@ pub enum FileLinkObject {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for FileLinkObject {
@@ -13067,7 +13630,7 @@ impl std::fmt::Display for FileLinkObject {
         match self {
             FileLinkObject::FileLink => "file_link",
             FileLinkObject::Noop => "",
-            FileLinkObject::FallthroughString => "*",
+            FileLinkObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -13155,6 +13718,10 @@ pub struct FileLink {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -13232,6 +13799,10 @@ pub struct FinancialReportingFinanceReportRunParameters {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub timezone: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Point in Time
@@ -13264,6 +13835,10 @@ pub struct GelatoDataDocumentReportDateOfBirth {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub year: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Point in Time
@@ -13296,6 +13871,10 @@ pub struct GelatoDataDocumentReportExpirationDate {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub year: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Point in Time
@@ -13328,6 +13907,10 @@ pub struct GelatoDataDocumentReportIssuedDate {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub year: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Point in Time
@@ -13360,6 +13943,10 @@ pub struct GelatoDataNumberReportDate {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub year: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Point in Time
@@ -13392,6 +13979,10 @@ pub struct GelatoDataVerifiedOutputsDate {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub year: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -13406,8 +13997,9 @@ pub enum GelatoDocumentReportStatus {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GelatoDocumentReportStatus {
@@ -13416,7 +14008,7 @@ impl std::fmt::Display for GelatoDocumentReportStatus {
             GelatoDocumentReportStatus::Unverified => "unverified",
             GelatoDocumentReportStatus::Verified => "verified",
             GelatoDocumentReportStatus::Noop => "",
-            GelatoDocumentReportStatus::FallthroughString => "*",
+            GelatoDocumentReportStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -13439,8 +14031,9 @@ pub enum AllowedTypes {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AllowedTypes {
@@ -13450,7 +14043,7 @@ impl std::fmt::Display for AllowedTypes {
             AllowedTypes::IdCard => "id_card",
             AllowedTypes::Passport => "passport",
             AllowedTypes::Noop => "",
-            AllowedTypes::FallthroughString => "*",
+            AllowedTypes::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -13545,6 +14138,10 @@ pub struct GelatoDocumentReport {
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
     pub type_: Option<AllowedTypes>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -13561,8 +14158,9 @@ pub enum GelatoDocumentReportErrorCode {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GelatoDocumentReportErrorCode {
@@ -13574,7 +14172,7 @@ impl std::fmt::Display for GelatoDocumentReportErrorCode {
             }
             GelatoDocumentReportErrorCode::DocumentUnverifiedOther => "document_unverified_other",
             GelatoDocumentReportErrorCode::Noop => "",
-            GelatoDocumentReportErrorCode::FallthroughString => "*",
+            GelatoDocumentReportErrorCode::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -13603,6 +14201,10 @@ pub struct GelatoDocumentReportErrorData {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub reason: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -13619,8 +14221,9 @@ pub enum IdNumberType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for IdNumberType {
@@ -13630,7 +14233,7 @@ impl std::fmt::Display for IdNumberType {
             IdNumberType::SgNric => "sg_nric",
             IdNumberType::UsSsn => "us_ssn",
             IdNumberType::Noop => "",
-            IdNumberType::FallthroughString => "*",
+            IdNumberType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -13692,6 +14295,10 @@ pub struct GelatoNumberReport {
      */
     #[serde(default, skip_serializing_if = "GelatoDocumentReportStatus::is_noop")]
     pub status: GelatoDocumentReportStatus,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -13708,8 +14315,9 @@ pub enum GelatoNumberReportErrorCode {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GelatoNumberReportErrorCode {
@@ -13721,7 +14329,7 @@ impl std::fmt::Display for GelatoNumberReportErrorCode {
             GelatoNumberReportErrorCode::IdNumberMismatch => "id_number_mismatch",
             GelatoNumberReportErrorCode::IdNumberUnverifiedOther => "id_number_unverified_other",
             GelatoNumberReportErrorCode::Noop => "",
-            GelatoNumberReportErrorCode::FallthroughString => "*",
+            GelatoNumberReportErrorCode::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -13750,6 +14358,10 @@ pub struct GelatoNumberReportErrorData {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub reason: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -13788,6 +14400,10 @@ pub struct GelatoReportDocumentOptions {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub require_matching_selfie: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Result from a selfie check
@@ -13821,6 +14437,10 @@ pub struct GelatoSelfieReport {
      */
     #[serde(default, skip_serializing_if = "GelatoDocumentReportStatus::is_noop")]
     pub status: GelatoDocumentReportStatus,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -13839,8 +14459,9 @@ pub enum GelatoSelfieReportErrorCode {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GelatoSelfieReportErrorCode {
@@ -13853,7 +14474,7 @@ impl std::fmt::Display for GelatoSelfieReportErrorCode {
             GelatoSelfieReportErrorCode::SelfieManipulated => "selfie_manipulated",
             GelatoSelfieReportErrorCode::SelfieUnverifiedOther => "selfie_unverified_other",
             GelatoSelfieReportErrorCode::Noop => "",
-            GelatoSelfieReportErrorCode::FallthroughString => "*",
+            GelatoSelfieReportErrorCode::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -13882,6 +14503,10 @@ pub struct GelatoSelfieReportErrorData {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub reason: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -13920,6 +14545,10 @@ pub struct GelatoSessionDocumentOptions {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub require_matching_selfie: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -13960,8 +14589,9 @@ pub enum GelatoSessionLastErrorCode {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GelatoSessionLastErrorCode {
@@ -13987,7 +14617,7 @@ impl std::fmt::Display for GelatoSessionLastErrorCode {
             GelatoSessionLastErrorCode::SelfieUnverifiedOther => "selfie_unverified_other",
             GelatoSessionLastErrorCode::UnderSupportedAge => "under_supported_age",
             GelatoSessionLastErrorCode::Noop => "",
-            GelatoSessionLastErrorCode::FallthroughString => "*",
+            GelatoSessionLastErrorCode::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -14016,6 +14646,10 @@ pub struct GelatoSessionLastError {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub reason: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -14031,6 +14665,10 @@ pub struct GelatoVerificationReportOptions {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub id_number: Option<UseStripeSdk>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -14046,6 +14684,10 @@ pub struct GelatoVerificationSessionOptions {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub id_number: Option<UseStripeSdk>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -14093,6 +14735,10 @@ pub struct GelatoVerifiedOutputs {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub last_name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -14105,8 +14751,9 @@ pub enum GelatoVerificationReportObject {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GelatoVerificationReportObject {
@@ -14116,7 +14763,7 @@ impl std::fmt::Display for GelatoVerificationReportObject {
                 "identity.verification_report"
             }
             GelatoVerificationReportObject::Noop => "",
-            GelatoVerificationReportObject::FallthroughString => "*",
+            GelatoVerificationReportObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -14140,8 +14787,9 @@ pub enum GelatoVerificationReportType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GelatoVerificationReportType {
@@ -14150,7 +14798,7 @@ impl std::fmt::Display for GelatoVerificationReportType {
             GelatoVerificationReportType::Document => "document",
             GelatoVerificationReportType::IdNumber => "id_number",
             GelatoVerificationReportType::Noop => "",
-            GelatoVerificationReportType::FallthroughString => "*",
+            GelatoVerificationReportType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -14286,6 +14934,10 @@ pub struct GelatoVerificationReport {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub verification_session: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types:
@@ -14356,8 +15008,9 @@ pub enum GelatoVerificationSessionObject {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GelatoVerificationSessionObject {
@@ -14367,7 +15020,7 @@ impl std::fmt::Display for GelatoVerificationSessionObject {
                 "identity.verification_session"
             }
             GelatoVerificationSessionObject::Noop => "",
-            GelatoVerificationSessionObject::FallthroughString => "*",
+            GelatoVerificationSessionObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -14395,8 +15048,9 @@ pub enum GelatoVerificationSessionStatus {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GelatoVerificationSessionStatus {
@@ -14407,7 +15061,7 @@ impl std::fmt::Display for GelatoVerificationSessionStatus {
             GelatoVerificationSessionStatus::RequiresInput => "requires_input",
             GelatoVerificationSessionStatus::Verified => "verified",
             GelatoVerificationSessionStatus::Noop => "",
-            GelatoVerificationSessionStatus::FallthroughString => "*",
+            GelatoVerificationSessionStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -14563,6 +15217,10 @@ pub struct GelatoVerificationSession {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub verified_outputs: Option<GelatoVerifiedOutputs>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types:
@@ -14654,8 +15312,9 @@ pub enum BillingReason {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for BillingReason {
@@ -14671,7 +15330,7 @@ impl std::fmt::Display for BillingReason {
             BillingReason::SubscriptionUpdate => "subscription_update",
             BillingReason::Upcoming => "upcoming",
             BillingReason::Noop => "",
-            BillingReason::FallthroughString => "*",
+            BillingReason::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -14695,8 +15354,9 @@ pub enum CollectionMethod {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for CollectionMethod {
@@ -14705,7 +15365,7 @@ impl std::fmt::Display for CollectionMethod {
             CollectionMethod::ChargeAutomatically => "charge_automatically",
             CollectionMethod::SendInvoice => "send_invoice",
             CollectionMethod::Noop => "",
-            CollectionMethod::FallthroughString => "*",
+            CollectionMethod::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -14803,6 +15463,10 @@ pub struct InvoiceLinesList {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types:
@@ -14874,8 +15538,9 @@ pub enum InvoiceStatus {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for InvoiceStatus {
@@ -14888,7 +15553,7 @@ impl std::fmt::Display for InvoiceStatus {
             InvoiceStatus::Uncollectible => "uncollectible",
             InvoiceStatus::Void => "void",
             InvoiceStatus::Noop => "",
-            InvoiceStatus::FallthroughString => "*",
+            InvoiceStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -16462,6 +17127,10 @@ pub struct Invoice {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub webhooks_delivered_at: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -16485,6 +17154,10 @@ pub struct InvoiceItemThresholdReason {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub usage_gte: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -16508,6 +17181,10 @@ pub struct InvoiceLineItemPeriod {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub start: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -16522,8 +17199,9 @@ pub enum AmountType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AmountType {
@@ -16532,7 +17210,7 @@ impl std::fmt::Display for AmountType {
             AmountType::Fixed => "fixed",
             AmountType::Maximum => "maximum",
             AmountType::Noop => "",
-            AmountType::FallthroughString => "*",
+            AmountType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -16570,6 +17248,10 @@ pub struct InvoiceMandateOptionsCard {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub description: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -16585,6 +17267,10 @@ pub struct InvoicePaymentMethodOptionsAcssDebit {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub verification_method: Option<VerificationMethod>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -16595,6 +17281,10 @@ pub struct InvoicePaymentMethodOptionsAcssDebitMandate {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub transaction_type: Option<TransactionType>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -16613,8 +17303,9 @@ pub enum PreferredLanguage {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PreferredLanguage {
@@ -16625,7 +17316,7 @@ impl std::fmt::Display for PreferredLanguage {
             PreferredLanguage::Fr => "fr",
             PreferredLanguage::Nl => "nl",
             PreferredLanguage::Noop => "",
-            PreferredLanguage::FallthroughString => "*",
+            PreferredLanguage::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -16645,6 +17336,10 @@ pub struct InvoicePaymentMethodOptionsBancontact {
      */
     #[serde(default, skip_serializing_if = "PreferredLanguage::is_noop")]
     pub preferred_language: PreferredLanguage,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -16659,8 +17354,9 @@ pub enum RequestThreeDSecure {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for RequestThreeDSecure {
@@ -16669,7 +17365,7 @@ impl std::fmt::Display for RequestThreeDSecure {
             RequestThreeDSecure::Any => "any",
             RequestThreeDSecure::Automatic => "automatic",
             RequestThreeDSecure::Noop => "",
-            RequestThreeDSecure::FallthroughString => "*",
+            RequestThreeDSecure::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -16689,6 +17385,10 @@ pub struct InvoicePaymentMethodOptionsCard {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub request_three_d_secure: Option<RequestThreeDSecure>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -16701,8 +17401,9 @@ pub enum FundingType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for FundingType {
@@ -16710,7 +17411,7 @@ impl std::fmt::Display for FundingType {
         match self {
             FundingType::BankTransfer => "bank_transfer",
             FundingType::Noop => "",
-            FundingType::FallthroughString => "*",
+            FundingType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -16735,6 +17436,10 @@ pub struct InvoicePaymentMethodOptionsCustomerBalance {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub funding_type: Option<FundingType>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -16750,6 +17455,10 @@ pub struct InvoicePaymentMethodOptionsCustomerBalanceBankTransfer {
         rename = "type"
     )]
     pub type_: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -16760,6 +17469,10 @@ pub struct InvoicePaymentMethodOptionsUsBankAccount {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub verification_method: Option<VerificationMethod>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -16783,6 +17496,10 @@ pub struct InvoiceSettingCustomField {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub value: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -16810,6 +17527,10 @@ pub struct InvoiceSettingCustomer {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub footer: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -16824,6 +17545,10 @@ pub struct InvoiceSettingQuote {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub days_until_due: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -16838,6 +17563,10 @@ pub struct InvoiceSettingSubscriptionSchedule {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub days_until_due: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -16861,6 +17590,10 @@ pub struct InvoiceThresholdReason {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub item_reasons: Vec<InvoiceItemThresholdReason>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -16876,6 +17609,10 @@ pub struct InvoiceTransferDataType {
     )]
     pub amount: i64,
     pub destination: Box<AccountAnyOf>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types:
@@ -17141,6 +17878,10 @@ pub struct InvoiceItem {
         deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub unit_amount_decimal: f64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -17164,6 +17905,10 @@ pub struct InvoicesLineItemsCredited {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub invoice_line_items: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -17174,6 +17919,10 @@ pub struct InvoicesLineItemsProrationDetails {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub credited_items: Option<InvoicesLineItemsCredited>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -17209,6 +17958,10 @@ pub struct InvoicesPaymentMethodOptions {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub us_bank_account: Option<InvoicePaymentMethodOptionsUsBankAccount>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -17254,8 +18007,9 @@ pub enum PaymentMethodTypes {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PaymentMethodTypes {
@@ -17281,7 +18035,7 @@ impl std::fmt::Display for PaymentMethodTypes {
             PaymentMethodTypes::UsBankAccount => "us_bank_account",
             PaymentMethodTypes::WechatPay => "wechat_pay",
             PaymentMethodTypes::Noop => "",
-            PaymentMethodTypes::FallthroughString => "*",
+            PaymentMethodTypes::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -17310,6 +18064,10 @@ pub struct InvoicesPaymentSettings {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub payment_method_types: Vec<PaymentMethodTypes>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -17403,8 +18161,6 @@ pub enum InvoicesResourceInvoiceTaxType {
     TwVat,
     #[serde(rename = "ua_vat")]
     UaVat,
-    #[serde(rename = "unknown")]
-    Unknown,
     #[serde(rename = "us_ein")]
     UsEin,
     #[serde(rename = "za_vat")]
@@ -17412,8 +18168,9 @@ pub enum InvoicesResourceInvoiceTaxType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for InvoicesResourceInvoiceTaxType {
@@ -17462,11 +18219,10 @@ impl std::fmt::Display for InvoicesResourceInvoiceTaxType {
             InvoicesResourceInvoiceTaxType::ThVat => "th_vat",
             InvoicesResourceInvoiceTaxType::TwVat => "tw_vat",
             InvoicesResourceInvoiceTaxType::UaVat => "ua_vat",
-            InvoicesResourceInvoiceTaxType::Unknown => "unknown",
             InvoicesResourceInvoiceTaxType::UsEin => "us_ein",
             InvoicesResourceInvoiceTaxType::ZaVat => "za_vat",
             InvoicesResourceInvoiceTaxType::Noop => "",
-            InvoicesResourceInvoiceTaxType::FallthroughString => "*",
+            InvoicesResourceInvoiceTaxType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -17499,6 +18255,10 @@ pub struct InvoicesResourceInvoiceTaxId {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub value: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -17540,6 +18300,10 @@ pub struct InvoicesStatusTransitions {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub voided_at: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -17552,8 +18316,9 @@ pub enum IssuerFraudRecordObject {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for IssuerFraudRecordObject {
@@ -17561,7 +18326,7 @@ impl std::fmt::Display for IssuerFraudRecordObject {
         match self {
             IssuerFraudRecordObject::IssuerFraudRecord => "issuer_fraud_record",
             IssuerFraudRecordObject::Noop => "",
-            IssuerFraudRecordObject::FallthroughString => "*",
+            IssuerFraudRecordObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -17644,6 +18409,10 @@ pub struct IssuerFraudRecord {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub post_date: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -17664,8 +18433,9 @@ pub enum AuthorizationMethod {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AuthorizationMethod {
@@ -17677,7 +18447,7 @@ impl std::fmt::Display for AuthorizationMethod {
             AuthorizationMethod::Online => "online",
             AuthorizationMethod::Swipe => "swipe",
             AuthorizationMethod::Noop => "",
-            AuthorizationMethod::FallthroughString => "*",
+            AuthorizationMethod::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -17749,8 +18519,9 @@ pub enum IssuingAuthorizationObject {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for IssuingAuthorizationObject {
@@ -17758,7 +18529,7 @@ impl std::fmt::Display for IssuingAuthorizationObject {
         match self {
             IssuingAuthorizationObject::IssuingAuthorization => "issuing.authorization",
             IssuingAuthorizationObject::Noop => "",
-            IssuingAuthorizationObject::FallthroughString => "*",
+            IssuingAuthorizationObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -17784,8 +18555,9 @@ pub enum IssuingAuthorizationStatus {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for IssuingAuthorizationStatus {
@@ -17795,7 +18567,7 @@ impl std::fmt::Display for IssuingAuthorizationStatus {
             IssuingAuthorizationStatus::Pending => "pending",
             IssuingAuthorizationStatus::Reversed => "reversed",
             IssuingAuthorizationStatus::Noop => "",
-            IssuingAuthorizationStatus::FallthroughString => "*",
+            IssuingAuthorizationStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -17978,6 +18750,10 @@ pub struct IssuingAuthorization {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub wallet: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -17992,8 +18768,9 @@ pub enum CancellationReason {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for CancellationReason {
@@ -18002,7 +18779,7 @@ impl std::fmt::Display for CancellationReason {
             CancellationReason::Lost => "lost",
             CancellationReason::Stolen => "stolen",
             CancellationReason::Noop => "",
-            CancellationReason::FallthroughString => "*",
+            CancellationReason::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -18024,8 +18801,9 @@ pub enum IssuingCardObject {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for IssuingCardObject {
@@ -18033,7 +18811,7 @@ impl std::fmt::Display for IssuingCardObject {
         match self {
             IssuingCardObject::IssuingCard => "issuing.card",
             IssuingCardObject::Noop => "",
-            IssuingCardObject::FallthroughString => "*",
+            IssuingCardObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -18109,8 +18887,9 @@ pub enum ReplacementReason {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ReplacementReason {
@@ -18121,7 +18900,7 @@ impl std::fmt::Display for ReplacementReason {
             ReplacementReason::Lost => "lost",
             ReplacementReason::Stolen => "stolen",
             ReplacementReason::Noop => "",
-            ReplacementReason::FallthroughString => "*",
+            ReplacementReason::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -18147,8 +18926,9 @@ pub enum IssuingCardStatus {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for IssuingCardStatus {
@@ -18158,7 +18938,7 @@ impl std::fmt::Display for IssuingCardStatus {
             IssuingCardStatus::Canceled => "canceled",
             IssuingCardStatus::Inactive => "inactive",
             IssuingCardStatus::Noop => "",
-            IssuingCardStatus::FallthroughString => "*",
+            IssuingCardStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -18182,8 +18962,9 @@ pub enum IssuingCardType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for IssuingCardType {
@@ -18192,7 +18973,7 @@ impl std::fmt::Display for IssuingCardType {
             IssuingCardType::Physical => "physical",
             IssuingCardType::Virtual => "virtual",
             IssuingCardType::Noop => "",
-            IssuingCardType::FallthroughString => "*",
+            IssuingCardType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -18363,6 +19144,10 @@ pub struct IssuingCard {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub wallets: Option<IssuingCardWallets>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -18375,8 +19160,9 @@ pub enum IssuingCardholderObject {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for IssuingCardholderObject {
@@ -18384,7 +19170,7 @@ impl std::fmt::Display for IssuingCardholderObject {
         match self {
             IssuingCardholderObject::IssuingCardholder => "issuing.cardholder",
             IssuingCardholderObject::Noop => "",
-            IssuingCardholderObject::FallthroughString => "*",
+            IssuingCardholderObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -18410,8 +19196,9 @@ pub enum IssuingCardholderStatus {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for IssuingCardholderStatus {
@@ -18421,7 +19208,7 @@ impl std::fmt::Display for IssuingCardholderStatus {
             IssuingCardholderStatus::Blocked => "blocked",
             IssuingCardholderStatus::Inactive => "inactive",
             IssuingCardholderStatus::Noop => "",
-            IssuingCardholderStatus::FallthroughString => "*",
+            IssuingCardholderStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -18445,8 +19232,9 @@ pub enum AccountHolderType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AccountHolderType {
@@ -18455,7 +19243,7 @@ impl std::fmt::Display for AccountHolderType {
             AccountHolderType::Company => "company",
             AccountHolderType::Individual => "individual",
             AccountHolderType::Noop => "",
-            AccountHolderType::FallthroughString => "*",
+            AccountHolderType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -18580,6 +19368,10 @@ pub struct IssuingCardholder {
         rename = "type"
     )]
     pub type_: AccountHolderType,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -18592,8 +19384,9 @@ pub enum IssuingDisputeObject {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for IssuingDisputeObject {
@@ -18601,7 +19394,7 @@ impl std::fmt::Display for IssuingDisputeObject {
         match self {
             IssuingDisputeObject::IssuingDispute => "issuing.dispute",
             IssuingDisputeObject::Noop => "",
-            IssuingDisputeObject::FallthroughString => "*",
+            IssuingDisputeObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -18631,8 +19424,9 @@ pub enum IssuingDisputeStatus {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for IssuingDisputeStatus {
@@ -18644,7 +19438,7 @@ impl std::fmt::Display for IssuingDisputeStatus {
             IssuingDisputeStatus::Unsubmitted => "unsubmitted",
             IssuingDisputeStatus::Won => "won",
             IssuingDisputeStatus::Noop => "",
-            IssuingDisputeStatus::FallthroughString => "*",
+            IssuingDisputeStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -18792,6 +19586,10 @@ pub struct IssuingDispute {
     #[serde(default, skip_serializing_if = "IssuingDisputeStatus::is_noop")]
     pub status: IssuingDisputeStatus,
     pub transaction: Box<TransactionAnyOf>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -18804,8 +19602,9 @@ pub enum Network {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Network {
@@ -18813,7 +19612,7 @@ impl std::fmt::Display for Network {
         match self {
             Network::Visa => "visa",
             Network::Noop => "",
-            Network::FallthroughString => "*",
+            Network::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -18835,8 +19634,9 @@ pub enum IssuingSettlementObject {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for IssuingSettlementObject {
@@ -18844,7 +19644,7 @@ impl std::fmt::Display for IssuingSettlementObject {
         match self {
             IssuingSettlementObject::IssuingSettlement => "issuing.settlement",
             IssuingSettlementObject::Noop => "",
-            IssuingSettlementObject::FallthroughString => "*",
+            IssuingSettlementObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -18994,6 +19794,10 @@ pub struct IssuingSettlement {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub transaction_volume: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types:
@@ -19108,8 +19912,9 @@ pub enum IssuingTransactionObject {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for IssuingTransactionObject {
@@ -19117,7 +19922,7 @@ impl std::fmt::Display for IssuingTransactionObject {
         match self {
             IssuingTransactionObject::IssuingTransaction => "issuing.transaction",
             IssuingTransactionObject::Noop => "",
-            IssuingTransactionObject::FallthroughString => "*",
+            IssuingTransactionObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -19141,8 +19946,9 @@ pub enum IssuingTransactionType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for IssuingTransactionType {
@@ -19151,7 +19957,7 @@ impl std::fmt::Display for IssuingTransactionType {
             IssuingTransactionType::Capture => "capture",
             IssuingTransactionType::Refund => "refund",
             IssuingTransactionType::Noop => "",
-            IssuingTransactionType::FallthroughString => "*",
+            IssuingTransactionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -19177,8 +19983,9 @@ pub enum Wallet {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Wallet {
@@ -19188,7 +19995,7 @@ impl std::fmt::Display for Wallet {
             Wallet::GooglePay => "google_pay",
             Wallet::SamsungPay => "samsung_pay",
             Wallet::Noop => "",
-            Wallet::FallthroughString => "*",
+            Wallet::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -19344,6 +20151,10 @@ pub struct IssuingTransaction {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub wallet: Option<Wallet>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -19358,6 +20169,10 @@ pub struct IssuingAuthorizationAmountDetails {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub atm_fee: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -19435,6 +20250,10 @@ pub struct IssuingAuthorizationMerchantData {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub state: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -19486,6 +20305,10 @@ pub struct IssuingAuthorizationPendingRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub merchant_currency: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -19522,8 +20345,9 @@ pub enum IssuingAuthorizationRequestReason {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for IssuingAuthorizationRequestReason {
@@ -19545,7 +20369,7 @@ impl std::fmt::Display for IssuingAuthorizationRequestReason {
             IssuingAuthorizationRequestReason::WebhookDeclined => "webhook_declined",
             IssuingAuthorizationRequestReason::WebhookTimeout => "webhook_timeout",
             IssuingAuthorizationRequestReason::Noop => "",
-            IssuingAuthorizationRequestReason::FallthroughString => "*",
+            IssuingAuthorizationRequestReason::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -19623,6 +20447,10 @@ pub struct IssuingAuthorizationRequest {
         skip_serializing_if = "IssuingAuthorizationRequestReason::is_noop"
     )]
     pub reason: IssuingAuthorizationRequestReason,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -19639,8 +20467,9 @@ pub enum CvcCheck {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for CvcCheck {
@@ -19650,7 +20479,7 @@ impl std::fmt::Display for CvcCheck {
             CvcCheck::Mismatch => "mismatch",
             CvcCheck::NotProvided => "not_provided",
             CvcCheck::Noop => "",
-            CvcCheck::FallthroughString => "*",
+            CvcCheck::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -19689,6 +20518,10 @@ pub struct IssuingAuthorizationVerificationData {
      */
     #[serde(default, skip_serializing_if = "CvcCheck::is_noop")]
     pub expiry_check: CvcCheck,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -19705,8 +20538,9 @@ pub enum IneligibleReason {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for IneligibleReason {
@@ -19716,7 +20550,7 @@ impl std::fmt::Display for IneligibleReason {
             IneligibleReason::MissingCardholderContact => "missing_cardholder_contact",
             IneligibleReason::UnsupportedRegion => "unsupported_region",
             IneligibleReason::Noop => "",
-            IneligibleReason::FallthroughString => "*",
+            IneligibleReason::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -19744,6 +20578,10 @@ pub struct IssuingCardApplePay {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub ineligible_reason: Option<IneligibleReason>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -20327,8 +21165,9 @@ pub enum Categories {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Categories {
@@ -20691,7 +21530,7 @@ impl std::fmt::Display for Categories {
             Categories::WomensReadyToWearStores => "womens_ready_to_wear_stores",
             Categories::WreckingAndSalvageYards => "wrecking_and_salvage_yards",
             Categories::Noop => "",
-            Categories::FallthroughString => "*",
+            Categories::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -20742,6 +21581,10 @@ pub struct IssuingCardAuthorizationControls {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub spending_limits_currency: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -20760,8 +21603,9 @@ pub enum Carrier {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Carrier {
@@ -20772,7 +21616,7 @@ impl std::fmt::Display for Carrier {
             Carrier::RoyalMail => "royal_mail",
             Carrier::Usps => "usps",
             Carrier::Noop => "",
-            Carrier::FallthroughString => "*",
+            Carrier::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -20798,8 +21642,9 @@ pub enum Service {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Service {
@@ -20809,7 +21654,7 @@ impl std::fmt::Display for Service {
             Service::Priority => "priority",
             Service::Standard => "standard",
             Service::Noop => "",
-            Service::FallthroughString => "*",
+            Service::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -20841,8 +21686,9 @@ pub enum IssuingCardShippingStatus {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for IssuingCardShippingStatus {
@@ -20855,7 +21701,7 @@ impl std::fmt::Display for IssuingCardShippingStatus {
             IssuingCardShippingStatus::Returned => "returned",
             IssuingCardShippingStatus::Shipped => "shipped",
             IssuingCardShippingStatus::Noop => "",
-            IssuingCardShippingStatus::FallthroughString => "*",
+            IssuingCardShippingStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -20879,8 +21725,9 @@ pub enum IssuingCardShippingType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for IssuingCardShippingType {
@@ -20889,7 +21736,7 @@ impl std::fmt::Display for IssuingCardShippingType {
             IssuingCardShippingType::Bulk => "bulk",
             IssuingCardShippingType::Individual => "individual",
             IssuingCardShippingType::Noop => "",
-            IssuingCardShippingType::FallthroughString => "*",
+            IssuingCardShippingType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -20968,6 +21815,10 @@ pub struct IssuingCardShippingData {
         rename = "type"
     )]
     pub type_: IssuingCardShippingType,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -20990,8 +21841,9 @@ pub enum Interval {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Interval {
@@ -21004,7 +21856,7 @@ impl std::fmt::Display for Interval {
             Interval::Weekly => "weekly",
             Interval::Yearly => "yearly",
             Interval::Noop => "",
-            Interval::FallthroughString => "*",
+            Interval::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -21042,6 +21894,10 @@ pub struct IssuingCardSpendingLimit {
      */
     #[serde(default, skip_serializing_if = "Interval::is_noop")]
     pub interval: Interval,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -21064,6 +21920,10 @@ pub struct IssuingCardWallets {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub primary_account_identifier: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -21073,6 +21933,10 @@ pub struct IssuingCardholderAddress {
      *
      */
     pub address: Address,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -21114,6 +21978,10 @@ pub struct IssuingCardholderAuthorizationControls {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub spending_limits_currency: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -21127,6 +21995,10 @@ pub struct IssuingCardholderCompany {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub tax_id_provided: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -21140,6 +22012,10 @@ pub struct IssuingCardholderDocument {
      *
      */
     pub front: Box<Option<IconAnyOf>>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -21173,6 +22049,10 @@ pub struct IssuingCardholderIndividual {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub verification: Option<IssuingCardholderVerification>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -21205,6 +22085,10 @@ pub struct IssuingCardholderIndividualDobData {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub year: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -21221,8 +22105,9 @@ pub enum DisabledReason {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DisabledReason {
@@ -21232,7 +22117,7 @@ impl std::fmt::Display for DisabledReason {
             DisabledReason::RejectedListed => "rejected.listed",
             DisabledReason::UnderReview => "under_review",
             DisabledReason::Noop => "",
-            DisabledReason::FallthroughString => "*",
+            DisabledReason::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -21263,8 +22148,9 @@ pub enum PastDue {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PastDue {
@@ -21278,7 +22164,7 @@ impl std::fmt::Display for PastDue {
             PastDue::IndividualLastName => "individual.last_name",
             PastDue::IndividualVerificationDocument => "individual.verification.document",
             PastDue::Noop => "",
-            PastDue::FallthroughString => "*",
+            PastDue::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -21307,6 +22193,10 @@ pub struct IssuingCardholderRequirements {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub past_due: Vec<PastDue>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -21317,6 +22207,10 @@ pub struct IssuingCardholderVerification {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub document: Option<IssuingCardholderDocument>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -21331,8 +22225,9 @@ pub enum ProductType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ProductType {
@@ -21341,7 +22236,7 @@ impl std::fmt::Display for ProductType {
             ProductType::Merchandise => "merchandise",
             ProductType::Service => "service",
             ProductType::Noop => "",
-            ProductType::FallthroughString => "*",
+            ProductType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -21365,8 +22260,9 @@ pub enum ReturnStatus {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ReturnStatus {
@@ -21375,7 +22271,7 @@ impl std::fmt::Display for ReturnStatus {
             ReturnStatus::MerchantRejected => "merchant_rejected",
             ReturnStatus::Successful => "successful",
             ReturnStatus::Noop => "",
-            ReturnStatus::FallthroughString => "*",
+            ReturnStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -21466,6 +22362,10 @@ pub struct IssuingDisputeCanceledEvidence {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub returned_at: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -21505,6 +22405,10 @@ pub struct IssuingDisputeDuplicateEvidence {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub original_transaction: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -21529,8 +22433,9 @@ pub enum IssuingDisputeEvidenceReason {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for IssuingDisputeEvidenceReason {
@@ -21546,7 +22451,7 @@ impl std::fmt::Display for IssuingDisputeEvidenceReason {
             IssuingDisputeEvidenceReason::Other => "other",
             IssuingDisputeEvidenceReason::ServiceNotAsDescribed => "service_not_as_described",
             IssuingDisputeEvidenceReason::Noop => "",
-            IssuingDisputeEvidenceReason::FallthroughString => "*",
+            IssuingDisputeEvidenceReason::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -21601,6 +22506,10 @@ pub struct IssuingDisputeEvidence {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub service_not_as_described: Option<IssuingDisputeServiceNotAsDescribedEvidence>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -21619,6 +22528,10 @@ pub struct IssuingDisputeFraudulentEvidence {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub explanation: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -21669,6 +22582,10 @@ pub struct IssuingDisputeMerchandiseNotAsDescribedEvidence {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub returned_at: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -21710,6 +22627,10 @@ pub struct IssuingDisputeNotReceivedEvidence {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub product_type: Option<ProductType>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -21742,6 +22663,10 @@ pub struct IssuingDisputeOtherEvidence {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub product_type: Option<ProductType>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -21787,6 +22712,10 @@ pub struct IssuingDisputeServiceNotAsDescribedEvidence {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub received_at: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -21801,6 +22730,10 @@ pub struct IssuingTransactionAmountDetailsData {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub atm_fee: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -21850,6 +22783,10 @@ pub struct IssuingTransactionFlightData {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub travel_agency: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -21908,6 +22845,10 @@ pub struct IssuingTransactionFlightDataLeg {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub stopover_allowed: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -21950,6 +22891,10 @@ pub struct IssuingTransactionFuelData {
         deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub volume_decimal: f64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -21973,6 +22918,10 @@ pub struct IssuingTransactionLodgingData {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub nights: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -22011,6 +22960,10 @@ pub struct IssuingTransactionPurchaseDetails {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub reference: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -22052,6 +23005,10 @@ pub struct IssuingTransactionReceiptData {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub unit_cost: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -22064,8 +23021,9 @@ pub enum ItemObject {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ItemObject {
@@ -22073,7 +23031,7 @@ impl std::fmt::Display for ItemObject {
         match self {
             ItemObject::Item => "item",
             ItemObject::Noop => "",
-            ItemObject::FallthroughString => "*",
+            ItemObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -22170,6 +23128,10 @@ pub struct Item {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub taxes: Vec<LineItemsTaxAmount>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -22220,8 +23182,9 @@ pub enum Structure {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Structure {
@@ -22250,7 +23213,7 @@ impl std::fmt::Display for Structure {
             Structure::UnincorporatedAssociation => "unincorporated_association",
             Structure::UnincorporatedNonProfit => "unincorporated_non_profit",
             Structure::Noop => "",
-            Structure::FallthroughString => "*",
+            Structure::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -22380,6 +23343,10 @@ pub struct LegalEntityCompany {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub verification: Option<LegalEntityCompanyVerificationData>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -22389,6 +23356,10 @@ pub struct LegalEntityCompanyVerificationData {
      *
      */
     pub document: LegalEntityCompanyVerificationDocument,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -22420,6 +23391,10 @@ pub struct LegalEntityCompanyVerificationDocument {
      *
      */
     pub front: Box<Option<IconAnyOf>>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -22452,6 +23427,10 @@ pub struct LegalEntityDob {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub year: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -22522,6 +23501,10 @@ pub struct LegalEntityJapanAddress {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub town: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -22564,6 +23547,10 @@ pub struct LegalEntityPersonVerification {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub status: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -22595,6 +23582,10 @@ pub struct LegalEntityPersonVerificationDocument {
      *
      */
     pub front: Box<Option<IconAnyOf>>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -22627,6 +23618,10 @@ pub struct LegalEntityUboDeclaration {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub user_agent: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -22639,8 +23634,9 @@ pub enum LineItemObject {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for LineItemObject {
@@ -22648,7 +23644,7 @@ impl std::fmt::Display for LineItemObject {
         match self {
             LineItemObject::LineItem => "line_item",
             LineItemObject::Noop => "",
-            LineItemObject::FallthroughString => "*",
+            LineItemObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -22672,8 +23668,9 @@ pub enum LineItemType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for LineItemType {
@@ -22682,7 +23679,7 @@ impl std::fmt::Display for LineItemType {
             LineItemType::Invoiceitem => "invoiceitem",
             LineItemType::Subscription => "subscription",
             LineItemType::Noop => "",
-            LineItemType::FallthroughString => "*",
+            LineItemType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -22861,6 +23858,10 @@ pub struct LineItem {
         rename = "type"
     )]
     pub type_: LineItemType,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -22883,6 +23884,10 @@ pub struct LineItemsDiscountAmount {
      *  Related guide: [Applying Discounts to Subscriptions](https://stripe.com/docs/billing/subscriptions/discounts).
      */
     pub discount: DiscountData,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -22903,6 +23908,10 @@ pub struct LineItemsTaxAmount {
      *  Related guide: [Tax Rates](https://stripe.com/docs/billing/taxes/tax-rates).
      */
     pub rate: TaxRate,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -22915,8 +23924,9 @@ pub enum LoginLinkObject {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for LoginLinkObject {
@@ -22924,7 +23934,7 @@ impl std::fmt::Display for LoginLinkObject {
         match self {
             LoginLinkObject::LoginLink => "login_link",
             LoginLinkObject::Noop => "",
-            LoginLinkObject::FallthroughString => "*",
+            LoginLinkObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -22962,6 +23972,10 @@ pub struct LoginLink {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -22974,8 +23988,9 @@ pub enum MandateObject {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for MandateObject {
@@ -22983,7 +23998,7 @@ impl std::fmt::Display for MandateObject {
         match self {
             MandateObject::Mandate => "mandate",
             MandateObject::Noop => "",
-            MandateObject::FallthroughString => "*",
+            MandateObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -23007,8 +24022,9 @@ pub enum MandateType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for MandateType {
@@ -23017,7 +24033,7 @@ impl std::fmt::Display for MandateType {
             MandateType::MultiUse => "multi_use",
             MandateType::SingleUse => "single_use",
             MandateType::Noop => "",
-            MandateType::FallthroughString => "*",
+            MandateType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -23083,6 +24099,10 @@ pub struct Mandate {
      */
     #[serde(default, skip_serializing_if = "MandateType::is_noop", rename = "type")]
     pub type_: MandateType,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -23116,6 +24136,10 @@ pub struct MandateAcssDebit {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub transaction_type: Option<TransactionType>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -23130,6 +24154,10 @@ pub struct MandateAuBecsDebit {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -23148,8 +24176,9 @@ pub enum NetworkStatus {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for NetworkStatus {
@@ -23160,7 +24189,7 @@ impl std::fmt::Display for NetworkStatus {
             NetworkStatus::Refused => "refused",
             NetworkStatus::Revoked => "revoked",
             NetworkStatus::Noop => "",
-            NetworkStatus::FallthroughString => "*",
+            NetworkStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -23198,6 +24227,10 @@ pub struct MandateBacsDebit {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -23243,6 +24276,10 @@ pub struct MandatePaymentMethodDetails {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub us_bank_account: Option<UseStripeSdk>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -23266,6 +24303,10 @@ pub struct MandateSepaDebit {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -23289,6 +24330,10 @@ pub struct MandateSingleUse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub currency: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -23312,6 +24357,10 @@ pub struct Networks {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub preferred: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -23326,6 +24375,10 @@ pub struct NotificationEventData {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub previous_attributes: Option<UseStripeSdk>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -23349,6 +24402,10 @@ pub struct NotificationEventRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub idempotency_key: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -23372,6 +24429,10 @@ pub struct OnlineAcceptance {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub user_agent: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -23384,8 +24445,9 @@ pub enum OrderObject {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OrderObject {
@@ -23393,7 +24455,7 @@ impl std::fmt::Display for OrderObject {
         match self {
             OrderObject::Order => "order",
             OrderObject::Noop => "",
-            OrderObject::FallthroughString => "*",
+            OrderObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -23439,6 +24501,10 @@ pub struct Returns {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Order objects are created to handle end customers' purchases of previously
@@ -23672,6 +24738,10 @@ pub struct Order {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub upstream_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -23684,8 +24754,9 @@ pub enum OrderItemObject {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OrderItemObject {
@@ -23693,7 +24764,7 @@ impl std::fmt::Display for OrderItemObject {
         match self {
             OrderItemObject::OrderItem => "order_item",
             OrderItemObject::Noop => "",
-            OrderItemObject::FallthroughString => "*",
+            OrderItemObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -23825,6 +24896,10 @@ pub struct OrderItem {
         rename = "type"
     )]
     pub type_: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -23837,8 +24912,9 @@ pub enum OrderReturnObject {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OrderReturnObject {
@@ -23846,7 +24922,7 @@ impl std::fmt::Display for OrderReturnObject {
         match self {
             OrderReturnObject::OrderReturn => "order_return",
             OrderReturnObject::Noop => "",
-            OrderReturnObject::FallthroughString => "*",
+            OrderReturnObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -23936,6 +25012,10 @@ pub struct OrderReturn {
      *  Related guide: [Handling Returns](https://stripe.com/docs/orders/guide#handling-returns).
      */
     pub refund: Box<Option<RefundAnyOf>>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -23977,6 +25057,10 @@ pub struct PackageDimensions {
         deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub width: f64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -23990,6 +25074,10 @@ pub struct PortalInvoiceList {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub enabled: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -24022,6 +25110,10 @@ pub struct PaymentFlowsPrivateMethodsAlipayDetails {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub transaction_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -24054,6 +25146,10 @@ pub struct PaymentFlowsPrivateMethodsKlarnaDob {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub year: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -24078,8 +25174,9 @@ pub enum PaymentIntentCancellationReason {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PaymentIntentCancellationReason {
@@ -24093,7 +25190,7 @@ impl std::fmt::Display for PaymentIntentCancellationReason {
             PaymentIntentCancellationReason::RequestedByCustomer => "requested_by_customer",
             PaymentIntentCancellationReason::VoidInvoice => "void_invoice",
             PaymentIntentCancellationReason::Noop => "",
-            PaymentIntentCancellationReason::FallthroughString => "*",
+            PaymentIntentCancellationReason::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -24117,8 +25214,9 @@ pub enum CaptureMethod {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for CaptureMethod {
@@ -24127,7 +25225,7 @@ impl std::fmt::Display for CaptureMethod {
             CaptureMethod::Automatic => "automatic",
             CaptureMethod::Manual => "manual",
             CaptureMethod::Noop => "",
-            CaptureMethod::FallthroughString => "*",
+            CaptureMethod::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -24173,6 +25271,10 @@ pub struct Charges {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -24185,8 +25287,9 @@ pub enum PaymentIntentObject {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PaymentIntentObject {
@@ -24194,7 +25297,7 @@ impl std::fmt::Display for PaymentIntentObject {
         match self {
             PaymentIntentObject::PaymentIntent => "payment_intent",
             PaymentIntentObject::Noop => "",
-            PaymentIntentObject::FallthroughString => "*",
+            PaymentIntentObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -24222,8 +25325,9 @@ pub enum SetupFutureUsage {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SetupFutureUsage {
@@ -24232,7 +25336,7 @@ impl std::fmt::Display for SetupFutureUsage {
             SetupFutureUsage::OffSession => "off_session",
             SetupFutureUsage::OnSession => "on_session",
             SetupFutureUsage::Noop => "",
-            SetupFutureUsage::FallthroughString => "*",
+            SetupFutureUsage::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -24266,8 +25370,9 @@ pub enum PaymentIntentStatus {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PaymentIntentStatus {
@@ -24281,7 +25386,7 @@ impl std::fmt::Display for PaymentIntentStatus {
             PaymentIntentStatus::RequiresPaymentMethod => "requires_payment_method",
             PaymentIntentStatus::Succeeded => "succeeded",
             PaymentIntentStatus::Noop => "",
-            PaymentIntentStatus::FallthroughString => "*",
+            PaymentIntentStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -24735,6 +25840,10 @@ pub struct PaymentIntent {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub transfer_group: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -24745,6 +25854,10 @@ pub struct PaymentIntentCardProcessing {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub customer_notification: Option<PaymentIntentProcessingCustomerNotification>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -24821,6 +25934,10 @@ pub struct PaymentIntentNextAction {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub wechat_pay_redirect_to_ios_app: Option<PaymentIntentNextActionWechatPayRedirectIosApp>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -24862,6 +25979,10 @@ pub struct PaymentIntentNextActionAlipayHandleRedirect {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -24903,6 +26024,10 @@ pub struct PaymentIntentNextActionBoleto {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub pdf: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -24925,6 +26050,10 @@ pub struct PaymentIntentNextActionCardAwaitNotification {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub customer_approval_required: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -24957,6 +26086,10 @@ pub struct PaymentIntentNextActionDisplayOxxoDetails {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub number: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -24984,6 +26117,10 @@ pub struct PaymentIntentNextActionKonbini {
      *
      */
     pub stores: PaymentIntentNextActionKonbiniStores,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -25007,6 +26144,10 @@ pub struct PaymentIntentNextActionKonbiniFamilymart {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub payment_code: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -25030,6 +26171,10 @@ pub struct PaymentIntentNextActionKonbiniLawson {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub payment_code: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -25053,6 +26198,10 @@ pub struct PaymentIntentNextActionKonbiniMinistop {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub payment_code: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -25076,6 +26225,10 @@ pub struct PaymentIntentNextActionKonbiniSeicomart {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub payment_code: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -25101,6 +26254,10 @@ pub struct PaymentIntentNextActionKonbiniStores {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub seicomart: Option<PaymentIntentNextActionKonbiniSeicomart>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -25133,6 +26290,10 @@ pub struct PaymentIntentNextActionPaynowDisplayQrCode {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub image_url_svg: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -25156,6 +26317,10 @@ pub struct PaymentIntentNextActionRedirectUrl {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -25170,8 +26335,9 @@ pub enum MicrodepositType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for MicrodepositType {
@@ -25180,7 +26346,7 @@ impl std::fmt::Display for MicrodepositType {
             MicrodepositType::Amounts => "amounts",
             MicrodepositType::DescriptorCode => "descriptor_code",
             MicrodepositType::Noop => "",
-            MicrodepositType::FallthroughString => "*",
+            MicrodepositType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -25218,6 +26384,10 @@ pub struct SetupIntentNextActionVerifyWithMicrodeposits {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub microdeposit_type: Option<MicrodepositType>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -25259,6 +26429,10 @@ pub struct PaymentIntentNextActionWechatPayDisplayQrCode {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub image_url_svg: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -25327,6 +26501,10 @@ pub struct PaymentIntentNextActionWechatPayRedirectAndroidApp {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub timestamp: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -25341,6 +26519,10 @@ pub struct PaymentIntentNextActionWechatPayRedirectIosApp {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub native_url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types:
@@ -26368,6 +27550,10 @@ pub struct PaymentIntentMethodOptionsData {
      *
      */
     pub wechat_pay: Box<Option<WechatPayAnyOf>>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -26388,8 +27574,9 @@ pub enum PaymentIntentMethodOptionsAcssDebitSetupFutureUsage {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PaymentIntentMethodOptionsAcssDebitSetupFutureUsage {
@@ -26399,7 +27586,7 @@ impl std::fmt::Display for PaymentIntentMethodOptionsAcssDebitSetupFutureUsage {
             PaymentIntentMethodOptionsAcssDebitSetupFutureUsage::OffSession => "off_session",
             PaymentIntentMethodOptionsAcssDebitSetupFutureUsage::OnSession => "on_session",
             PaymentIntentMethodOptionsAcssDebitSetupFutureUsage::Noop => "",
-            PaymentIntentMethodOptionsAcssDebitSetupFutureUsage::FallthroughString => "*",
+            PaymentIntentMethodOptionsAcssDebitSetupFutureUsage::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -26432,6 +27619,10 @@ pub struct PaymentIntentMethodOptionsAcssDebit {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub verification_method: Option<VerificationMethod>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -26442,6 +27633,10 @@ pub struct PaymentIntentMethodOptionsAuBecsDebit {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub setup_future_usage: Option<PaymentIntentMethodOptionsAcssDebitSetupFutureUsage>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -26454,8 +27649,9 @@ pub enum PaymentIntentMethodOptionsCardCapture {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PaymentIntentMethodOptionsCardCapture {
@@ -26463,7 +27659,7 @@ impl std::fmt::Display for PaymentIntentMethodOptionsCardCapture {
         match self {
             PaymentIntentMethodOptionsCardCapture::Manual => "manual",
             PaymentIntentMethodOptionsCardCapture::Noop => "",
-            PaymentIntentMethodOptionsCardCapture::FallthroughString => "*",
+            PaymentIntentMethodOptionsCardCapture::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -26496,15 +27692,14 @@ pub enum PaymentIntentMethodOptionsCardNetwork {
     Mastercard,
     #[serde(rename = "unionpay")]
     Unionpay,
-    #[serde(rename = "unknown")]
-    Unknown,
     #[serde(rename = "visa")]
     Visa,
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PaymentIntentMethodOptionsCardNetwork {
@@ -26518,10 +27713,9 @@ impl std::fmt::Display for PaymentIntentMethodOptionsCardNetwork {
             PaymentIntentMethodOptionsCardNetwork::Jcb => "jcb",
             PaymentIntentMethodOptionsCardNetwork::Mastercard => "mastercard",
             PaymentIntentMethodOptionsCardNetwork::Unionpay => "unionpay",
-            PaymentIntentMethodOptionsCardNetwork::Unknown => "unknown",
             PaymentIntentMethodOptionsCardNetwork::Visa => "visa",
             PaymentIntentMethodOptionsCardNetwork::Noop => "",
-            PaymentIntentMethodOptionsCardNetwork::FallthroughString => "*",
+            PaymentIntentMethodOptionsCardNetwork::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -26547,8 +27741,9 @@ pub enum PaymentIntentMethodOptionsCardRequestThreeDSecure {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PaymentIntentMethodOptionsCardRequestThreeDSecure {
@@ -26558,7 +27753,7 @@ impl std::fmt::Display for PaymentIntentMethodOptionsCardRequestThreeDSecure {
             PaymentIntentMethodOptionsCardRequestThreeDSecure::Automatic => "automatic",
             PaymentIntentMethodOptionsCardRequestThreeDSecure::ChallengeOnly => "challenge_only",
             PaymentIntentMethodOptionsCardRequestThreeDSecure::Noop => "",
-            PaymentIntentMethodOptionsCardRequestThreeDSecure::FallthroughString => "*",
+            PaymentIntentMethodOptionsCardRequestThreeDSecure::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -26608,6 +27803,10 @@ pub struct PaymentIntentMethodOptionsCard {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub setup_future_usage: Option<PaymentIntentMethodOptionsAcssDebitSetupFutureUsage>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -26624,8 +27823,9 @@ pub enum PaymentIntentMethodOptionsEpsSetupFutureUsage {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PaymentIntentMethodOptionsEpsSetupFutureUsage {
@@ -26633,7 +27833,7 @@ impl std::fmt::Display for PaymentIntentMethodOptionsEpsSetupFutureUsage {
         match self {
             PaymentIntentMethodOptionsEpsSetupFutureUsage::None => "none",
             PaymentIntentMethodOptionsEpsSetupFutureUsage::Noop => "",
-            PaymentIntentMethodOptionsEpsSetupFutureUsage::FallthroughString => "*",
+            PaymentIntentMethodOptionsEpsSetupFutureUsage::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -26653,6 +27853,10 @@ pub struct PaymentIntentMethodOptionsEps {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub setup_future_usage: Option<PaymentIntentMethodOptionsEpsSetupFutureUsage>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -26686,6 +27890,10 @@ pub struct PaymentIntentMethodOptionsMandateAcssDebit {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub transaction_type: Option<TransactionType>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -26701,6 +27909,10 @@ pub struct PaymentIntentMethodOptionsSepaDebit {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub setup_future_usage: Option<PaymentIntentMethodOptionsAcssDebitSetupFutureUsage>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -26716,6 +27928,10 @@ pub struct PaymentIntentMethodOptionsUsBankAccount {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub verification_method: Option<VerificationMethod>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -26731,6 +27947,10 @@ pub struct PaymentIntentProcessing {
      */
     #[serde(default, skip_serializing_if = "CardObject::is_noop", rename = "type")]
     pub type_: CardObject,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -26753,6 +27973,10 @@ pub struct PaymentIntentProcessingCustomerNotification {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub completes_at: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -26773,6 +27997,10 @@ pub struct PaymentIntentTypeSpecificMethodOptionsClient {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub verification_method: Option<VerificationMethod>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -26785,8 +28013,9 @@ pub enum PaymentLinkObject {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PaymentLinkObject {
@@ -26794,7 +28023,7 @@ impl std::fmt::Display for PaymentLinkObject {
         match self {
             PaymentLinkObject::PaymentLink => "payment_link",
             PaymentLinkObject::Noop => "",
-            PaymentLinkObject::FallthroughString => "*",
+            PaymentLinkObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -26949,6 +28178,10 @@ pub struct PaymentLink {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -26963,8 +28196,9 @@ pub enum PaymentLinksResourceAfterCompletionType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PaymentLinksResourceAfterCompletionType {
@@ -26973,7 +28207,7 @@ impl std::fmt::Display for PaymentLinksResourceAfterCompletionType {
             PaymentLinksResourceAfterCompletionType::HostedConfirmation => "hosted_confirmation",
             PaymentLinksResourceAfterCompletionType::Redirect => "redirect",
             PaymentLinksResourceAfterCompletionType::Noop => "",
-            PaymentLinksResourceAfterCompletionType::FallthroughString => "*",
+            PaymentLinksResourceAfterCompletionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -27007,6 +28241,10 @@ pub struct PaymentLinksResourceAfterCompletion {
         rename = "type"
     )]
     pub type_: PaymentLinksResourceAfterCompletionType,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -27021,6 +28259,10 @@ pub struct PaymentLinksResourceCompletionBehaviorConfirmationPage {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub custom_message: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -27502,8 +28744,9 @@ pub enum AllowedCountries {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AllowedCountries {
@@ -27747,7 +28990,7 @@ impl std::fmt::Display for AllowedCountries {
             AllowedCountries::Zw => "ZW",
             AllowedCountries::Zz => "ZZ",
             AllowedCountries::Noop => "",
-            AllowedCountries::FallthroughString => "*",
+            AllowedCountries::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -27771,6 +29014,10 @@ pub struct PaymentLinksResourceShippingAddressCollection {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub allowed_countries: Vec<AllowedCountries>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -27785,6 +29032,10 @@ pub struct PaymentLinksResourceSubscriptionData {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub trial_period_days: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -27800,6 +29051,10 @@ pub struct PaymentLinksResourceTransferData {
     )]
     pub amount: i64,
     pub destination: Box<AccountAnyOf>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -27812,8 +29067,9 @@ pub enum PaymentMethodObject {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PaymentMethodObject {
@@ -27821,7 +29077,7 @@ impl std::fmt::Display for PaymentMethodObject {
         match self {
             PaymentMethodObject::PaymentMethod => "payment_method",
             PaymentMethodObject::Noop => "",
-            PaymentMethodObject::FallthroughString => "*",
+            PaymentMethodObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -27889,8 +29145,9 @@ pub enum PaymentMethodType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PaymentMethodType {
@@ -27921,7 +29178,7 @@ impl std::fmt::Display for PaymentMethodType {
             PaymentMethodType::UsBankAccount => "us_bank_account",
             PaymentMethodType::WechatPay => "wechat_pay",
             PaymentMethodType::Noop => "",
-            PaymentMethodType::FallthroughString => "*",
+            PaymentMethodType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -28222,6 +29479,10 @@ pub struct PaymentMethod {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub wechat_pay: Option<UseStripeSdk>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -28273,6 +29534,10 @@ pub struct PaymentMethodAcssDebit {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub transit_number: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -28306,6 +29571,10 @@ pub struct PaymentMethodAuBecsDebit {
         rename = "last4"
     )]
     pub last_4: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -28339,6 +29608,10 @@ pub struct PaymentMethodBacsDebit {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub sort_code: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -28353,6 +29626,10 @@ pub struct PaymentMethodBoleto {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub tax_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -28447,6 +29724,10 @@ pub struct PaymentMethodCard {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub wallet: Option<PaymentMethodCardWalletData>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -28480,6 +29761,10 @@ pub struct PaymentMethodCardChecks {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub cvc_check: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types:
@@ -28554,6 +29839,10 @@ pub struct PaymentMethodCardGenerated {
      *
      */
     pub setup_attempt: Box<Option<SetupAttemptAnyOf>>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -28576,8 +29865,9 @@ pub enum PaymentMethodCardWalletType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PaymentMethodCardWalletType {
@@ -28590,7 +29880,7 @@ impl std::fmt::Display for PaymentMethodCardWalletType {
             PaymentMethodCardWalletType::SamsungPay => "samsung_pay",
             PaymentMethodCardWalletType::VisaCheckout => "visa_checkout",
             PaymentMethodCardWalletType::Noop => "",
-            PaymentMethodCardWalletType::FallthroughString => "*",
+            PaymentMethodCardWalletType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -28654,6 +29944,10 @@ pub struct PaymentMethodCardWalletData {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub visa_checkout: Option<PaymentMethodCardWalletVisaCheckout>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -28687,6 +29981,10 @@ pub struct PaymentMethodCardWalletMasterpass {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub shipping_address: Option<Address>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -28720,6 +30018,10 @@ pub struct PaymentMethodCardWalletVisaCheckout {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub shipping_address: Option<Address>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -28880,6 +30182,10 @@ pub struct PaymentMethodDetails {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub wechat_pay: Option<PaymentMethodDetailsWechatPay>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -28921,6 +30227,10 @@ pub struct PaymentMethodDetailsAchCreditTransfer {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub swift_code: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -28977,6 +30287,10 @@ pub struct PaymentMethodDetailsAchDebit {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub routing_number: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -29037,6 +30351,10 @@ pub struct PaymentMethodDetailsAcssDebit {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub transit_number: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -29051,6 +30369,10 @@ pub struct PaymentMethodDetailsAfterpayClearpay {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub reference: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -29093,6 +30415,10 @@ pub struct PaymentMethodDetailsAuBecsDebit {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub mandate: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -29135,6 +30461,10 @@ pub struct PaymentMethodDetailsBacsDebit {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub sort_code: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types:
@@ -29247,6 +30577,10 @@ pub struct PaymentMethodDetailsBancontact {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub verified_name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -29356,6 +30690,10 @@ pub struct PaymentMethodDetailsCard {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub wallet: Option<PaymentMethodDetailsCardWalletData>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -29389,6 +30727,10 @@ pub struct PaymentMethodDetailsCardChecksData {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub cvc_check: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -29399,6 +30741,10 @@ pub struct PaymentMethodDetailsCardInstallmentsData {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub plan: Option<PaymentMethodDetailsCardInstallmentsPlan>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -29412,8 +30758,9 @@ pub enum PaymentMethodDetailsCardInstallmentsPlanInterval {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PaymentMethodDetailsCardInstallmentsPlanInterval {
@@ -29421,7 +30768,7 @@ impl std::fmt::Display for PaymentMethodDetailsCardInstallmentsPlanInterval {
         match self {
             PaymentMethodDetailsCardInstallmentsPlanInterval::Month => "month",
             PaymentMethodDetailsCardInstallmentsPlanInterval::Noop => "",
-            PaymentMethodDetailsCardInstallmentsPlanInterval::FallthroughString => "*",
+            PaymentMethodDetailsCardInstallmentsPlanInterval::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -29443,8 +30790,9 @@ pub enum PaymentMethodDetailsCardInstallmentsPlanType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PaymentMethodDetailsCardInstallmentsPlanType {
@@ -29452,7 +30800,7 @@ impl std::fmt::Display for PaymentMethodDetailsCardInstallmentsPlanType {
         match self {
             PaymentMethodDetailsCardInstallmentsPlanType::FixedCount => "fixed_count",
             PaymentMethodDetailsCardInstallmentsPlanType::Noop => "",
-            PaymentMethodDetailsCardInstallmentsPlanType::FallthroughString => "*",
+            PaymentMethodDetailsCardInstallmentsPlanType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -29491,6 +30839,10 @@ pub struct PaymentMethodDetailsCardInstallmentsPlan {
         rename = "type"
     )]
     pub type_: PaymentMethodDetailsCardInstallmentsPlanType,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -29511,8 +30863,9 @@ pub enum ReadMethod {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ReadMethod {
@@ -29524,7 +30877,7 @@ impl std::fmt::Display for ReadMethod {
             ReadMethod::MagneticStripeFallback => "magnetic_stripe_fallback",
             ReadMethod::MagneticStripeTrack2 => "magnetic_stripe_track2",
             ReadMethod::Noop => "",
-            ReadMethod::FallthroughString => "*",
+            ReadMethod::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -29675,6 +31028,10 @@ pub struct PaymentMethodDetailsCardPresent {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub receipt: Option<PaymentMethodDetailsCardPresentReceipt>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -29688,13 +31045,12 @@ pub enum AccountType {
     Credit,
     #[serde(rename = "prepaid")]
     Prepaid,
-    #[serde(rename = "unknown")]
-    Unknown,
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AccountType {
@@ -29703,9 +31059,8 @@ impl std::fmt::Display for AccountType {
             AccountType::Checking => "checking",
             AccountType::Credit => "credit",
             AccountType::Prepaid => "prepaid",
-            AccountType::Unknown => "unknown",
             AccountType::Noop => "",
-            AccountType::FallthroughString => "*",
+            AccountType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -29797,6 +31152,10 @@ pub struct PaymentMethodDetailsCardPresentReceipt {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub transaction_status_information: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -29851,6 +31210,10 @@ pub struct PaymentMethodDetailsCardWalletData {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub visa_checkout: Option<PaymentMethodDetailsCardWalletVisaCheckout>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -29884,6 +31247,10 @@ pub struct PaymentMethodDetailsCardWalletMasterpass {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub shipping_address: Option<Address>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -29917,6 +31284,10 @@ pub struct PaymentMethodDetailsCardWalletVisaCheckout {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub shipping_address: Option<Address>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -29981,8 +31352,9 @@ pub enum Bank {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Bank {
@@ -30016,7 +31388,7 @@ impl std::fmt::Display for Bank {
             Bank::VolkskreditbankAg => "volkskreditbank_ag",
             Bank::VrBankBraunau => "vr_bank_braunau",
             Bank::Noop => "",
-            Bank::FallthroughString => "*",
+            Bank::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -30045,6 +31417,10 @@ pub struct PaymentMethodDetailsEps {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub verified_name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -30097,8 +31473,9 @@ pub enum PaymentMethodDetailsFpxBank {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PaymentMethodDetailsFpxBank {
@@ -30126,7 +31503,7 @@ impl std::fmt::Display for PaymentMethodDetailsFpxBank {
             PaymentMethodDetailsFpxBank::StandardChartered => "standard_chartered",
             PaymentMethodDetailsFpxBank::Uob => "uob",
             PaymentMethodDetailsFpxBank::Noop => "",
-            PaymentMethodDetailsFpxBank::FallthroughString => "*",
+            PaymentMethodDetailsFpxBank::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -30155,6 +31532,10 @@ pub struct PaymentMethodDetailsFpx {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub transaction_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -30196,6 +31577,10 @@ pub struct PaymentMethodDetailsGiropay {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub verified_name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -30210,6 +31595,10 @@ pub struct PaymentMethodDetailsGrabpay {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub transaction_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -30246,8 +31635,9 @@ pub enum PaymentMethodDetailsIdealBank {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PaymentMethodDetailsIdealBank {
@@ -30267,7 +31657,7 @@ impl std::fmt::Display for PaymentMethodDetailsIdealBank {
             PaymentMethodDetailsIdealBank::TriodosBank => "triodos_bank",
             PaymentMethodDetailsIdealBank::VanLanschot => "van_lanschot",
             PaymentMethodDetailsIdealBank::Noop => "",
-            PaymentMethodDetailsIdealBank::FallthroughString => "*",
+            PaymentMethodDetailsIdealBank::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -30313,8 +31703,9 @@ pub enum Bic {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Bic {
@@ -30334,7 +31725,7 @@ impl std::fmt::Display for Bic {
             Bic::Snsbnl2A => "SNSBNL2A",
             Bic::Trionl2U => "TRIONL2U",
             Bic::Noop => "",
-            Bic::FallthroughString => "*",
+            Bic::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -30386,6 +31777,10 @@ pub struct PaymentMethodDetailsIdeal {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub verified_name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -30510,6 +31905,10 @@ pub struct PaymentMethodDetailsInteracPresent {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub receipt: Option<PaymentMethodDetailsInteracPresentReceiptData>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -30521,13 +31920,12 @@ pub enum PaymentMethodDetailsInteracPresentReceiptAccountType {
     Checking,
     #[serde(rename = "savings")]
     Savings,
-    #[serde(rename = "unknown")]
-    Unknown,
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PaymentMethodDetailsInteracPresentReceiptAccountType {
@@ -30535,9 +31933,8 @@ impl std::fmt::Display for PaymentMethodDetailsInteracPresentReceiptAccountType
         match self {
             PaymentMethodDetailsInteracPresentReceiptAccountType::Checking => "checking",
             PaymentMethodDetailsInteracPresentReceiptAccountType::Savings => "savings",
-            PaymentMethodDetailsInteracPresentReceiptAccountType::Unknown => "unknown",
             PaymentMethodDetailsInteracPresentReceiptAccountType::Noop => "",
-            PaymentMethodDetailsInteracPresentReceiptAccountType::FallthroughString => "*",
+            PaymentMethodDetailsInteracPresentReceiptAccountType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -30632,6 +32029,10 @@ pub struct PaymentMethodDetailsInteracPresentReceiptData {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub transaction_status_information: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -30655,6 +32056,10 @@ pub struct PaymentMethodDetailsKlarna {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub preferred_locale: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -30665,6 +32070,10 @@ pub struct PaymentMethodDetailsKonbini {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub store: Option<PaymentMethodDetailsKonbiniStore>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -30683,8 +32092,9 @@ pub enum Chain {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Chain {
@@ -30695,7 +32105,7 @@ impl std::fmt::Display for Chain {
             Chain::Ministop => "ministop",
             Chain::Seicomart => "seicomart",
             Chain::Noop => "",
-            Chain::FallthroughString => "*",
+            Chain::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -30715,6 +32125,10 @@ pub struct PaymentMethodDetailsKonbiniStore {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub chain: Option<Chain>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -30738,6 +32152,10 @@ pub struct PaymentMethodDetailsMultibanco {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub reference: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -30752,6 +32170,10 @@ pub struct PaymentMethodDetailsOxxo {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub number: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -30812,8 +32234,9 @@ pub enum PaymentMethodDetailsP24Bank {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PaymentMethodDetailsP24Bank {
@@ -30845,7 +32268,7 @@ impl std::fmt::Display for PaymentMethodDetailsP24Bank {
             PaymentMethodDetailsP24Bank::ToyotaBank => "toyota_bank",
             PaymentMethodDetailsP24Bank::VolkswagenBank => "volkswagen_bank",
             PaymentMethodDetailsP24Bank::Noop => "",
-            PaymentMethodDetailsP24Bank::FallthroughString => "*",
+            PaymentMethodDetailsP24Bank::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -30883,6 +32306,10 @@ pub struct PaymentMethodDetailsP24 {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub verified_name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -30897,6 +32324,10 @@ pub struct PaymentMethodDetailsPaynow {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub reference: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -30957,6 +32388,10 @@ pub struct PaymentMethodDetailsSepaDebit {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub mandate: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -30982,8 +32417,9 @@ pub enum PaymentMethodDetailsSofortPreferredLanguage {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PaymentMethodDetailsSofortPreferredLanguage {
@@ -30997,7 +32433,7 @@ impl std::fmt::Display for PaymentMethodDetailsSofortPreferredLanguage {
             PaymentMethodDetailsSofortPreferredLanguage::Nl => "nl",
             PaymentMethodDetailsSofortPreferredLanguage::Pl => "pl",
             PaymentMethodDetailsSofortPreferredLanguage::Noop => "",
-            PaymentMethodDetailsSofortPreferredLanguage::FallthroughString => "*",
+            PaymentMethodDetailsSofortPreferredLanguage::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -31081,6 +32517,10 @@ pub struct PaymentMethodDetailsSofort {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub verified_name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -31095,8 +32535,9 @@ pub enum PaymentMethodDetailsUsBankAccountType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PaymentMethodDetailsUsBankAccountType {
@@ -31105,7 +32546,7 @@ impl std::fmt::Display for PaymentMethodDetailsUsBankAccountType {
             PaymentMethodDetailsUsBankAccountType::Checking => "checking",
             PaymentMethodDetailsUsBankAccountType::Savings => "savings",
             PaymentMethodDetailsUsBankAccountType::Noop => "",
-            PaymentMethodDetailsUsBankAccountType::FallthroughString => "*",
+            PaymentMethodDetailsUsBankAccountType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -31167,6 +32608,10 @@ pub struct PaymentMethodDetailsUsBankAccount {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub routing_number: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -31190,6 +32635,10 @@ pub struct PaymentMethodDetailsWechatPay {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub transaction_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -31200,6 +32649,10 @@ pub struct PaymentMethodEps {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub bank: Option<Bank>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -31210,6 +32663,10 @@ pub struct PaymentMethodFpx {
      */
     #[serde(default, skip_serializing_if = "PaymentMethodDetailsFpxBank::is_noop")]
     pub bank: PaymentMethodDetailsFpxBank,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -31225,6 +32682,10 @@ pub struct PaymentMethodIdeal {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub bic: Option<Bic>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -31235,6 +32696,10 @@ pub struct PaymentMethodKlarna {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub dob: Option<PaymentFlowsPrivateMethodsKlarnaDob>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -31259,6 +32724,10 @@ pub struct PaymentMethodOptionsAfterpayClearpay {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub setup_future_usage: Option<PaymentIntentMethodOptionsEpsSetupFutureUsage>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -31277,8 +32746,9 @@ pub enum PaymentMethodOptionsAlipaySetupFutureUsage {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PaymentMethodOptionsAlipaySetupFutureUsage {
@@ -31287,7 +32757,7 @@ impl std::fmt::Display for PaymentMethodOptionsAlipaySetupFutureUsage {
             PaymentMethodOptionsAlipaySetupFutureUsage::None => "none",
             PaymentMethodOptionsAlipaySetupFutureUsage::OffSession => "off_session",
             PaymentMethodOptionsAlipaySetupFutureUsage::Noop => "",
-            PaymentMethodOptionsAlipaySetupFutureUsage::FallthroughString => "*",
+            PaymentMethodOptionsAlipaySetupFutureUsage::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -31307,6 +32777,10 @@ pub struct PaymentMethodOptionsAlipay {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub setup_future_usage: Option<PaymentMethodOptionsAlipaySetupFutureUsage>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -31317,6 +32791,10 @@ pub struct PaymentMethodOptionsBacsDebit {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub setup_future_usage: Option<PaymentIntentMethodOptionsAcssDebitSetupFutureUsage>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -31332,6 +32810,10 @@ pub struct PaymentMethodOptionsBancontact {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub setup_future_usage: Option<PaymentMethodOptionsAlipaySetupFutureUsage>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -31351,6 +32833,10 @@ pub struct PaymentMethodOptionsBoleto {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub setup_future_usage: Option<PaymentIntentMethodOptionsAcssDebitSetupFutureUsage>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -31378,6 +32864,10 @@ pub struct PaymentMethodOptionsCardInstallments {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub plan: Option<PaymentMethodDetailsCardInstallmentsPlan>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -31398,8 +32888,9 @@ pub enum PaymentMethodOptionsCardMandateInterval {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PaymentMethodOptionsCardMandateInterval {
@@ -31411,7 +32902,7 @@ impl std::fmt::Display for PaymentMethodOptionsCardMandateInterval {
             PaymentMethodOptionsCardMandateInterval::Week => "week",
             PaymentMethodOptionsCardMandateInterval::Year => "year",
             PaymentMethodOptionsCardMandateInterval::Noop => "",
-            PaymentMethodOptionsCardMandateInterval::FallthroughString => "*",
+            PaymentMethodOptionsCardMandateInterval::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -31430,8 +32921,9 @@ pub enum SupportedTypes {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SupportedTypes {
@@ -31439,7 +32931,7 @@ impl std::fmt::Display for SupportedTypes {
         match self {
             SupportedTypes::India => "india",
             SupportedTypes::Noop => "",
-            SupportedTypes::FallthroughString => "*",
+            SupportedTypes::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -31530,6 +33022,10 @@ pub struct PaymentMethodOptionsCardMandate {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub supported_types: Vec<SupportedTypes>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -31543,6 +33039,10 @@ pub struct PaymentMethodOptionsCardPresent {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub request_extended_authorization: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -31553,6 +33053,10 @@ pub struct PaymentMethodOptionsFpx {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub setup_future_usage: Option<PaymentIntentMethodOptionsEpsSetupFutureUsage>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -31563,6 +33067,10 @@ pub struct PaymentMethodOptionsGiropay {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub setup_future_usage: Option<PaymentIntentMethodOptionsEpsSetupFutureUsage>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -31573,6 +33081,10 @@ pub struct PaymentMethodOptionsGrabpay {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub setup_future_usage: Option<PaymentIntentMethodOptionsEpsSetupFutureUsage>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -31583,6 +33095,10 @@ pub struct PaymentMethodOptionsIdeal {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub setup_future_usage: Option<PaymentMethodOptionsAlipaySetupFutureUsage>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -31607,6 +33123,10 @@ pub struct PaymentMethodOptionsKlarna {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub setup_future_usage: Option<PaymentIntentMethodOptionsEpsSetupFutureUsage>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -31653,6 +33173,10 @@ pub struct PaymentMethodOptionsKonbini {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub setup_future_usage: Option<PaymentIntentMethodOptionsEpsSetupFutureUsage>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -31672,6 +33196,10 @@ pub struct PaymentMethodOptionsOxxo {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub setup_future_usage: Option<PaymentIntentMethodOptionsEpsSetupFutureUsage>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -31682,6 +33210,10 @@ pub struct PaymentMethodOptionsP24 {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub setup_future_usage: Option<PaymentIntentMethodOptionsEpsSetupFutureUsage>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -31692,6 +33224,10 @@ pub struct PaymentMethodOptionsPaynow {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub setup_future_usage: Option<PaymentIntentMethodOptionsEpsSetupFutureUsage>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -31708,6 +33244,10 @@ pub struct PaymentMethodOptionsSofort {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub setup_future_usage: Option<PaymentMethodOptionsAlipaySetupFutureUsage>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -31724,8 +33264,9 @@ pub enum Client {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Client {
@@ -31735,7 +33276,7 @@ impl std::fmt::Display for Client {
             Client::Ios => "ios",
             Client::Web => "web",
             Client::Noop => "",
-            Client::FallthroughString => "*",
+            Client::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -31769,6 +33310,10 @@ pub struct PaymentMethodOptionsWechatPay {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub setup_future_usage: Option<PaymentIntentMethodOptionsEpsSetupFutureUsage>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -31779,6 +33324,10 @@ pub struct PaymentMethodP24 {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub bank: Option<PaymentMethodDetailsP24Bank>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -31835,6 +33384,10 @@ pub struct PaymentMethodSepaDebit {
         rename = "last4"
     )]
     pub last_4: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -31849,6 +33402,10 @@ pub struct PaymentMethodSofort {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub country: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -31901,6 +33458,10 @@ pub struct PaymentMethodUsBankAccount {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub routing_number: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -31911,6 +33472,10 @@ pub struct PaymentPagesCheckoutSessionAfterExpiration {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub recovery: Option<PaymentPagesCheckoutSessionAfterExpirationRecovery>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -31950,6 +33515,10 @@ pub struct PaymentPagesCheckoutSessionAfterExpirationRecovery {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -31965,8 +33534,9 @@ pub enum Promotions {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Promotions {
@@ -31975,7 +33545,7 @@ impl std::fmt::Display for Promotions {
             Promotions::OptIn => "opt_in",
             Promotions::OptOut => "opt_out",
             Promotions::Noop => "",
-            Promotions::FallthroughString => "*",
+            Promotions::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -31996,6 +33566,10 @@ pub struct PaymentPagesCheckoutSessionConsent {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub promotions: Option<Promotions>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -32010,8 +33584,9 @@ pub enum PaymentPagesCheckoutSessionConsentCollectionPromotions {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PaymentPagesCheckoutSessionConsentCollectionPromotions {
@@ -32019,7 +33594,7 @@ impl std::fmt::Display for PaymentPagesCheckoutSessionConsentCollectionPromotion
         match self {
             PaymentPagesCheckoutSessionConsentCollectionPromotions::Auto => "auto",
             PaymentPagesCheckoutSessionConsentCollectionPromotions::Noop => "",
-            PaymentPagesCheckoutSessionConsentCollectionPromotions::FallthroughString => "*",
+            PaymentPagesCheckoutSessionConsentCollectionPromotions::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -32044,6 +33619,10 @@ pub struct PaymentPagesCheckoutSessionConsentCollection {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub promotions: Option<PaymentPagesCheckoutSessionConsentCollectionPromotions>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -32095,6 +33674,10 @@ pub struct PaymentPagesCheckoutSessionCustomerDetails {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub tax_ids: Vec<PaymentPagesCheckoutSessionTaxId>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -32110,6 +33693,10 @@ pub struct PaymentPagesCheckoutSessionShippingOption {
     )]
     pub shipping_amount: i64,
     pub shipping_rate: Box<ShippingRateAnyOf>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -32133,6 +33720,10 @@ pub struct PaymentPagesCheckoutSessionTaxId {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub value: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -32170,6 +33761,10 @@ pub struct PaymentPagesCheckoutSessionTotalDetails {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub breakdown: Option<QuotesResourceTotalDetailsBreakdown>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -32193,6 +33788,10 @@ pub struct QuotesResourceTotalDetailsBreakdown {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub taxes: Vec<LineItemsTaxAmount>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types:
@@ -32403,8 +34002,9 @@ pub enum PayoutObject {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PayoutObject {
@@ -32412,7 +34012,7 @@ impl std::fmt::Display for PayoutObject {
         match self {
             PayoutObject::Payout => "payout",
             PayoutObject::Noop => "",
-            PayoutObject::FallthroughString => "*",
+            PayoutObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -32491,8 +34091,9 @@ pub enum PayoutType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PayoutType {
@@ -32501,7 +34102,7 @@ impl std::fmt::Display for PayoutType {
             PayoutType::BankAccount => "bank_account",
             PayoutType::Card => "card",
             PayoutType::Noop => "",
-            PayoutType::FallthroughString => "*",
+            PayoutType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -32756,6 +34357,10 @@ pub struct Payout {
      */
     #[serde(default, skip_serializing_if = "PayoutType::is_noop", rename = "type")]
     pub type_: PayoutType,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -32779,6 +34384,10 @@ pub struct Period {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub start: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -32793,8 +34402,9 @@ pub enum PoliticalExposure {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PoliticalExposure {
@@ -32803,7 +34413,7 @@ impl std::fmt::Display for PoliticalExposure {
             PoliticalExposure::Existing => "existing",
             PoliticalExposure::None => "none",
             PoliticalExposure::Noop => "",
-            PoliticalExposure::FallthroughString => "*",
+            PoliticalExposure::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -33127,6 +34737,10 @@ pub struct Person {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub verification: Option<LegalEntityPersonVerification>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -33186,6 +34800,10 @@ pub struct PersonRequirements {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub pending_verification: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -33241,6 +34859,10 @@ pub struct PersonRelationship {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub title: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -33259,8 +34881,9 @@ pub enum AggregateUsage {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AggregateUsage {
@@ -33271,7 +34894,7 @@ impl std::fmt::Display for AggregateUsage {
             AggregateUsage::Max => "max",
             AggregateUsage::Sum => "sum",
             AggregateUsage::Noop => "",
-            AggregateUsage::FallthroughString => "*",
+            AggregateUsage::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -33295,8 +34918,9 @@ pub enum BillingScheme {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for BillingScheme {
@@ -33305,7 +34929,7 @@ impl std::fmt::Display for BillingScheme {
             BillingScheme::PerUnit => "per_unit",
             BillingScheme::Tiered => "tiered",
             BillingScheme::Noop => "",
-            BillingScheme::FallthroughString => "*",
+            BillingScheme::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -33333,8 +34957,9 @@ pub enum PlanInterval {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PlanInterval {
@@ -33345,7 +34970,7 @@ impl std::fmt::Display for PlanInterval {
             PlanInterval::Week => "week",
             PlanInterval::Year => "year",
             PlanInterval::Noop => "",
-            PlanInterval::FallthroughString => "*",
+            PlanInterval::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -33436,8 +35061,9 @@ pub enum TiersMode {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for TiersMode {
@@ -33446,7 +35072,7 @@ impl std::fmt::Display for TiersMode {
             TiersMode::Graduated => "graduated",
             TiersMode::Volume => "volume",
             TiersMode::Noop => "",
-            TiersMode::FallthroughString => "*",
+            TiersMode::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -33470,8 +35096,9 @@ pub enum UsageType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for UsageType {
@@ -33480,7 +35107,7 @@ impl std::fmt::Display for UsageType {
             UsageType::Licensed => "licensed",
             UsageType::Metered => "metered",
             UsageType::Noop => "",
-            UsageType::FallthroughString => "*",
+            UsageType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -33689,6 +35316,10 @@ pub struct PlanData {
      */
     #[serde(default, skip_serializing_if = "UsageType::is_noop")]
     pub usage_type: UsageType,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -33739,6 +35370,10 @@ pub struct PlanTier {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub up_to: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -33751,8 +35386,9 @@ pub enum PlatformTaxObject {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PlatformTaxObject {
@@ -33760,7 +35396,7 @@ impl std::fmt::Display for PlatformTaxObject {
         match self {
             PlatformTaxObject::PlatformTaxFee => "platform_tax_fee",
             PlatformTaxObject::Noop => "",
-            PlatformTaxObject::FallthroughString => "*",
+            PlatformTaxObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -33817,6 +35453,10 @@ pub struct PlatformTax {
         rename = "type"
     )]
     pub type_: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -33849,6 +35489,10 @@ pub struct PortalBusinessProfile {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub terms_of_service_url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -33866,8 +35510,9 @@ pub enum AllowedUpdates {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AllowedUpdates {
@@ -33879,7 +35524,7 @@ impl std::fmt::Display for AllowedUpdates {
             AllowedUpdates::Shipping => "shipping",
             AllowedUpdates::TaxId => "tax_id",
             AllowedUpdates::Noop => "",
-            AllowedUpdates::FallthroughString => "*",
+            AllowedUpdates::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -33911,6 +35556,10 @@ pub struct PortalCustomerUpdate {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub enabled: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -33940,6 +35589,10 @@ pub struct PortalFeatures {
      *
      */
     pub subscription_update: PortalSubscriptionUpdate,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -33954,8 +35607,9 @@ pub enum PortalSubscriptionCancelMode {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PortalSubscriptionCancelMode {
@@ -33964,7 +35618,7 @@ impl std::fmt::Display for PortalSubscriptionCancelMode {
             PortalSubscriptionCancelMode::AtPeriodEnd => "at_period_end",
             PortalSubscriptionCancelMode::Immediately => "immediately",
             PortalSubscriptionCancelMode::Noop => "",
-            PortalSubscriptionCancelMode::FallthroughString => "*",
+            PortalSubscriptionCancelMode::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -33990,8 +35644,9 @@ pub enum ProrationBehavior {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ProrationBehavior {
@@ -34001,7 +35656,7 @@ impl std::fmt::Display for ProrationBehavior {
             ProrationBehavior::CreateProrations => "create_prorations",
             ProrationBehavior::None => "none",
             ProrationBehavior::Noop => "",
-            ProrationBehavior::FallthroughString => "*",
+            ProrationBehavior::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -34038,6 +35693,10 @@ pub struct PortalSubscriptionCancel {
      */
     #[serde(default, skip_serializing_if = "ProrationBehavior::is_noop")]
     pub proration_behavior: ProrationBehavior,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -34061,8 +35720,9 @@ pub enum Options {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Options {
@@ -34077,7 +35737,7 @@ impl std::fmt::Display for Options {
             Options::TooExpensive => "too_expensive",
             Options::Unused => "unused",
             Options::Noop => "",
-            Options::FallthroughString => "*",
+            Options::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -34109,6 +35769,10 @@ pub struct PortalSubscriptionCancellationReason {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub options: Vec<Options>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -34122,8 +35786,9 @@ pub enum DefaultAllowedUpdates {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DefaultAllowedUpdates {
@@ -34133,7 +35798,7 @@ impl std::fmt::Display for DefaultAllowedUpdates {
             DefaultAllowedUpdates::PromotionCode => "promotion_code",
             DefaultAllowedUpdates::Quantity => "quantity",
             DefaultAllowedUpdates::Noop => "",
-            DefaultAllowedUpdates::FallthroughString => "*",
+            DefaultAllowedUpdates::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -34179,6 +35844,10 @@ pub struct PortalSubscriptionUpdate {
      */
     #[serde(default, skip_serializing_if = "ProrationBehavior::is_noop")]
     pub proration_behavior: ProrationBehavior,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -34202,6 +35871,10 @@ pub struct PortalSubscriptionUpdateProduct {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub product: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -34218,8 +35891,9 @@ pub enum TaxBehavior {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for TaxBehavior {
@@ -34229,7 +35903,7 @@ impl std::fmt::Display for TaxBehavior {
             TaxBehavior::Inclusive => "inclusive",
             TaxBehavior::Unspecified => "unspecified",
             TaxBehavior::Noop => "",
-            TaxBehavior::FallthroughString => "*",
+            TaxBehavior::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -34253,8 +35927,9 @@ pub enum PriceType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PriceType {
@@ -34263,7 +35938,7 @@ impl std::fmt::Display for PriceType {
             PriceType::OneTime => "one_time",
             PriceType::Recurring => "recurring",
             PriceType::Noop => "",
-            PriceType::FallthroughString => "*",
+            PriceType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -34436,6 +36111,10 @@ pub struct PriceData {
         deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub unit_amount_decimal: f64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -34486,6 +36165,10 @@ pub struct PriceTier {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub up_to: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types:
@@ -34716,6 +36399,10 @@ pub struct Product {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -34728,8 +36415,9 @@ pub enum PromotionCodeObject {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PromotionCodeObject {
@@ -34737,7 +36425,7 @@ impl std::fmt::Display for PromotionCodeObject {
         match self {
             PromotionCodeObject::PromotionCode => "promotion_code",
             PromotionCodeObject::Noop => "",
-            PromotionCodeObject::FallthroughString => "*",
+            PromotionCodeObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -34855,6 +36543,10 @@ pub struct PromotionCode {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub times_redeemed: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -34886,6 +36578,10 @@ pub struct PromotionCodesResourceRestrictions {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub minimum_amount_currency: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types:
@@ -34989,8 +36685,9 @@ pub enum QuoteObject {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for QuoteObject {
@@ -34998,7 +36695,7 @@ impl std::fmt::Display for QuoteObject {
         match self {
             QuoteObject::Quote => "quote",
             QuoteObject::Noop => "",
-            QuoteObject::FallthroughString => "*",
+            QuoteObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -35026,8 +36723,9 @@ pub enum QuoteStatus {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for QuoteStatus {
@@ -35038,7 +36736,7 @@ impl std::fmt::Display for QuoteStatus {
             QuoteStatus::Draft => "draft",
             QuoteStatus::Open => "open",
             QuoteStatus::Noop => "",
-            QuoteStatus::FallthroughString => "*",
+            QuoteStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -35330,6 +37028,10 @@ pub struct Quote {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub transfer_data: Option<QuotesResourceTransferData>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -35344,6 +37046,10 @@ pub struct QuotesResourceComputed {
      *
      */
     pub upfront: QuotesResourceUpfront,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -35358,6 +37064,10 @@ pub struct QuotesResourceFromQuote {
     )]
     pub is_revision: bool,
     pub quote: Box<QuoteAnyOf>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -35399,6 +37109,10 @@ pub struct QuotesResourceRecurring {
      *
      */
     pub total_details: QuotesResourceTotalDetails,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -35431,6 +37145,10 @@ pub struct QuotesResourceStatusTransitions {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub finalized_at: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -35454,6 +37172,10 @@ pub struct QuotesResourceSubscriptionData {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub trial_period_days: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -35491,6 +37213,10 @@ pub struct QuotesResourceTotalDetails {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub breakdown: Option<QuotesResourceTotalDetailsBreakdown>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -35515,6 +37241,10 @@ pub struct QuotesResourceTransferData {
     )]
     pub amount_percent: f64,
     pub destination: Box<AccountAnyOf>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -35547,6 +37277,10 @@ pub struct QuotesResourceUpfront {
      *
      */
     pub total_details: QuotesResourceTotalDetails,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -35559,8 +37293,9 @@ pub enum RadarEarlyFraudWarningObject {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for RadarEarlyFraudWarningObject {
@@ -35568,7 +37303,7 @@ impl std::fmt::Display for RadarEarlyFraudWarningObject {
         match self {
             RadarEarlyFraudWarningObject::RadarEarlyFraudWarning => "radar.early_fraud_warning",
             RadarEarlyFraudWarningObject::Noop => "",
-            RadarEarlyFraudWarningObject::FallthroughString => "*",
+            RadarEarlyFraudWarningObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -35642,6 +37377,10 @@ pub struct RadarEarlyFraudWarning {
      *  Related guide: [Early Fraud Warnings](https://stripe.com/docs/disputes/measuring#early-fraud-warnings).
      */
     pub payment_intent: Box<Option<PaymentIntentAnyOf>>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -35668,8 +37407,9 @@ pub enum ItemType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ItemType {
@@ -35684,7 +37424,7 @@ impl std::fmt::Display for ItemType {
             ItemType::IpAddress => "ip_address",
             ItemType::String => "string",
             ItemType::Noop => "",
-            ItemType::FallthroughString => "*",
+            ItemType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -35730,6 +37470,10 @@ pub struct ListItems {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Value lists allow you to group values together which can then be referenced in rules.
@@ -35813,6 +37557,10 @@ pub struct RadarList {
      */
     #[serde(default, skip_serializing_if = "RadarListDeletedObject::is_noop")]
     pub object: RadarListDeletedObject,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Value list items allow you to add specific values to a given Radar value list, which can then be used in rules.
@@ -35878,6 +37626,10 @@ pub struct RadarListItem {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub value_list: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -35928,6 +37680,10 @@ pub struct RadarReviewResourceLocation {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub region: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -35969,6 +37725,10 @@ pub struct RadarReviewResourceSession {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub version: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -36002,6 +37762,10 @@ pub struct Cards {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types:
@@ -36221,6 +37985,10 @@ pub struct Recipient {
         rename = "type"
     )]
     pub type_: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -36250,6 +38018,10 @@ pub struct RecurringData {
      */
     #[serde(default, skip_serializing_if = "UsageType::is_noop")]
     pub usage_type: UsageType,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -36262,8 +38034,9 @@ pub enum RefundObject {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for RefundObject {
@@ -36271,7 +38044,7 @@ impl std::fmt::Display for RefundObject {
         match self {
             RefundObject::Refund => "refund",
             RefundObject::Noop => "",
-            RefundObject::FallthroughString => "*",
+            RefundObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -36299,8 +38072,9 @@ pub enum RefundReason {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for RefundReason {
@@ -36311,7 +38085,7 @@ impl std::fmt::Display for RefundReason {
             RefundReason::Fraudulent => "fraudulent",
             RefundReason::RequestedByCustomer => "requested_by_customer",
             RefundReason::Noop => "",
-            RefundReason::FallthroughString => "*",
+            RefundReason::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -36558,6 +38332,10 @@ pub struct Refund {
      *  Related guide: [Refunds](https://stripe.com/docs/refunds).
      */
     pub transfer_reversal: Box<Option<TransferReversalAnyOf>>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -36578,6 +38356,10 @@ pub struct RefundNextAction {
         rename = "type"
     )]
     pub type_: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -36596,6 +38378,10 @@ pub struct RefundNextActionDisplayDetails {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub expires_at: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -36608,8 +38394,9 @@ pub enum ReportingReportRunObject {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ReportingReportRunObject {
@@ -36617,7 +38404,7 @@ impl std::fmt::Display for ReportingReportRunObject {
         match self {
             ReportingReportRunObject::ReportingReportRun => "reporting.report_run",
             ReportingReportRunObject::Noop => "",
-            ReportingReportRunObject::FallthroughString => "*",
+            ReportingReportRunObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -36730,6 +38517,10 @@ pub struct ReportingReportRun {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub succeeded_at: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -36742,8 +38533,9 @@ pub enum ReportingReportTypeObject {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ReportingReportTypeObject {
@@ -36751,7 +38543,7 @@ impl std::fmt::Display for ReportingReportTypeObject {
         match self {
             ReportingReportTypeObject::ReportingReportType => "reporting.report_type",
             ReportingReportTypeObject::Noop => "",
-            ReportingReportTypeObject::FallthroughString => "*",
+            ReportingReportTypeObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -36856,6 +38648,10 @@ pub struct ReportingReportType {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub version: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -36868,8 +38664,9 @@ pub enum ReserveTransactionObject {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ReserveTransactionObject {
@@ -36877,7 +38674,7 @@ impl std::fmt::Display for ReserveTransactionObject {
         match self {
             ReserveTransactionObject::ReserveTransaction => "reserve_transaction",
             ReserveTransactionObject::Noop => "",
-            ReserveTransactionObject::FallthroughString => "*",
+            ReserveTransactionObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -36933,6 +38730,10 @@ pub struct ReserveTransaction {
      */
     #[serde(default, skip_serializing_if = "ReserveTransactionObject::is_noop")]
     pub object: ReserveTransactionObject,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -36953,8 +38754,9 @@ pub enum ClosedReason {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ClosedReason {
@@ -36966,7 +38768,7 @@ impl std::fmt::Display for ClosedReason {
             ClosedReason::Refunded => "refunded",
             ClosedReason::RefundedAsFraud => "refunded_as_fraud",
             ClosedReason::Noop => "",
-            ClosedReason::FallthroughString => "*",
+            ClosedReason::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -36988,8 +38790,9 @@ pub enum ReviewObject {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ReviewObject {
@@ -36997,7 +38800,7 @@ impl std::fmt::Display for ReviewObject {
         match self {
             ReviewObject::Review => "review",
             ReviewObject::Noop => "",
-            ReviewObject::FallthroughString => "*",
+            ReviewObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -37021,8 +38824,9 @@ pub enum OpenedReason {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OpenedReason {
@@ -37031,7 +38835,7 @@ impl std::fmt::Display for OpenedReason {
             OpenedReason::Manual => "manual",
             OpenedReason::Rule => "rule",
             OpenedReason::Noop => "",
-            OpenedReason::FallthroughString => "*",
+            OpenedReason::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -37155,6 +38959,10 @@ pub struct Review {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub session: Option<RadarReviewResourceSession>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -37187,6 +38995,10 @@ pub struct Rule {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub predicate: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -37199,8 +39011,9 @@ pub enum ScheduledQueryRunObject {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ScheduledQueryRunObject {
@@ -37208,7 +39021,7 @@ impl std::fmt::Display for ScheduledQueryRunObject {
         match self {
             ScheduledQueryRunObject::ScheduledQueryRun => "scheduled_query_run",
             ScheduledQueryRunObject::Noop => "",
-            ScheduledQueryRunObject::FallthroughString => "*",
+            ScheduledQueryRunObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -37316,6 +39129,10 @@ pub struct ScheduledQueryRun {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub title: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -37329,6 +39146,10 @@ pub struct SepaDebitGeneratedFrom {
      *
      */
     pub setup_attempt: Box<Option<SetupAttemptAnyOf>>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -37341,8 +39162,9 @@ pub enum SetupAttemptObject {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SetupAttemptObject {
@@ -37350,7 +39172,7 @@ impl std::fmt::Display for SetupAttemptObject {
         match self {
             SetupAttemptObject::SetupAttempt => "setup_attempt",
             SetupAttemptObject::Noop => "",
-            SetupAttemptObject::FallthroughString => "*",
+            SetupAttemptObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -37448,6 +39270,10 @@ pub struct SetupAttempt {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub usage: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -37518,6 +39344,10 @@ pub struct SetupAttemptPaymentMethodDetails {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub us_bank_account: Option<UseStripeSdk>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -37582,6 +39412,10 @@ pub struct SetupAttemptPaymentMethodDetailsBancontact {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub verified_name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -37592,6 +39426,10 @@ pub struct SetupAttemptPaymentMethodDetailsCard {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub three_d_secure: Option<ThreeDSecureDetails>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -37601,6 +39439,10 @@ pub struct SetupAttemptPaymentMethodDetailsCardPresent {
      *
      */
     pub generated_card: Box<Option<PaymentMethodAnyOf>>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -37643,6 +39485,10 @@ pub struct SetupAttemptPaymentMethodDetailsIdeal {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub verified_name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -37707,6 +39553,10 @@ pub struct SetupAttemptPaymentMethodDetailsSofort {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub verified_name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -37723,8 +39573,9 @@ pub enum SetupIntentCancellationReason {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SetupIntentCancellationReason {
@@ -37734,7 +39585,7 @@ impl std::fmt::Display for SetupIntentCancellationReason {
             SetupIntentCancellationReason::Duplicate => "duplicate",
             SetupIntentCancellationReason::RequestedByCustomer => "requested_by_customer",
             SetupIntentCancellationReason::Noop => "",
-            SetupIntentCancellationReason::FallthroughString => "*",
+            SetupIntentCancellationReason::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -37756,8 +39607,9 @@ pub enum SetupIntentObject {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SetupIntentObject {
@@ -37765,7 +39617,7 @@ impl std::fmt::Display for SetupIntentObject {
         match self {
             SetupIntentObject::SetupIntent => "setup_intent",
             SetupIntentObject::Noop => "",
-            SetupIntentObject::FallthroughString => "*",
+            SetupIntentObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -37797,8 +39649,9 @@ pub enum SetupIntentStatus {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SetupIntentStatus {
@@ -37811,7 +39664,7 @@ impl std::fmt::Display for SetupIntentStatus {
             SetupIntentStatus::RequiresPaymentMethod => "requires_payment_method",
             SetupIntentStatus::Succeeded => "succeeded",
             SetupIntentStatus::Noop => "",
-            SetupIntentStatus::FallthroughString => "*",
+            SetupIntentStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -38185,6 +40038,10 @@ pub struct SetupIntent {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub usage: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -38215,6 +40072,10 @@ pub struct SetupIntentNextActionData {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub verify_with_microdeposits: Option<SetupIntentNextActionVerifyWithMicrodeposits>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -38238,6 +40099,10 @@ pub struct SetupIntentNextActionRedirectUrl {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types:
@@ -38386,6 +40251,10 @@ pub struct SetupIntentPaymentMethodOptionsData {
      *
      */
     pub us_bank_account: Box<Option<SetupIntentPaymentMethodOptionsUsBankAccountAnyOf>>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -38406,6 +40275,10 @@ pub struct SetupIntentPaymentMethodOptionsAcssDebit {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub verification_method: Option<VerificationMethod>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -38421,6 +40294,10 @@ pub struct SetupIntentPaymentMethodOptionsCard {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub request_three_d_secure: Option<PaymentIntentMethodOptionsCardRequestThreeDSecure>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -38511,6 +40388,10 @@ pub struct SetupIntentPaymentMethodOptionsCardMandateData {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub supported_types: Vec<SupportedTypes>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -38553,6 +40434,10 @@ pub struct SetupIntentPaymentMethodOptionsMandateAcssDebit {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub transaction_type: Option<TransactionType>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -38563,6 +40448,10 @@ pub struct SetupIntentPaymentMethodOptionsSepaDebit {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub mandate_options: Option<UseStripeSdk>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -38573,6 +40462,10 @@ pub struct SetupIntentPaymentMethodOptionsUsBankAccount {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub verification_method: Option<VerificationMethod>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -38583,6 +40476,10 @@ pub struct SetupIntentTypeSpecificPaymentMethodOptionsClient {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub verification_method: Option<VerificationMethod>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -38629,6 +40526,10 @@ pub struct ShippingData {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub tracking_number: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -38675,6 +40576,10 @@ pub struct ShippingMethod {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -38687,8 +40592,9 @@ pub enum ShippingRateObject {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ShippingRateObject {
@@ -38696,7 +40602,7 @@ impl std::fmt::Display for ShippingRateObject {
         match self {
             ShippingRateObject::ShippingRate => "shipping_rate",
             ShippingRateObject::Noop => "",
-            ShippingRateObject::FallthroughString => "*",
+            ShippingRateObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -38718,8 +40624,9 @@ pub enum ShippingRateType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ShippingRateType {
@@ -38727,7 +40634,7 @@ impl std::fmt::Display for ShippingRateType {
         match self {
             ShippingRateType::FixedAmount => "fixed_amount",
             ShippingRateType::Noop => "",
-            ShippingRateType::FallthroughString => "*",
+            ShippingRateType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -38831,6 +40738,10 @@ pub struct ShippingRate {
         rename = "type"
     )]
     pub type_: ShippingRateType,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -38846,6 +40757,10 @@ pub struct ShippingRateDeliveryEstimateData {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub minimum: Option<ShippingRateDeliveryEstimateBound>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -38866,8 +40781,9 @@ pub enum Unit {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Unit {
@@ -38879,7 +40795,7 @@ impl std::fmt::Display for Unit {
             Unit::Month => "month",
             Unit::Week => "week",
             Unit::Noop => "",
-            Unit::FallthroughString => "*",
+            Unit::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -38908,6 +40824,10 @@ pub struct ShippingRateDeliveryEstimateBound {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub value: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -38922,6 +40842,10 @@ pub struct SigmaScheduledQueryRunError {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub message: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types:
@@ -39095,6 +41019,10 @@ pub struct Sku {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub updated: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -39128,6 +41056,10 @@ pub struct SkuInventory {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub value: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -39140,8 +41072,9 @@ pub enum SourceObject {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SourceObject {
@@ -39149,7 +41082,7 @@ impl std::fmt::Display for SourceObject {
         match self {
             SourceObject::Source => "source",
             SourceObject::Noop => "",
-            SourceObject::FallthroughString => "*",
+            SourceObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -39205,8 +41138,9 @@ pub enum SourceType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SourceType {
@@ -39231,7 +41165,7 @@ impl std::fmt::Display for SourceType {
             SourceType::ThreeDSecure => "three_d_secure",
             SourceType::Wechat => "wechat",
             SourceType::Noop => "",
-            SourceType::FallthroughString => "*",
+            SourceType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -39623,6 +41557,10 @@ pub struct SourceData {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub wechat: Option<SourceTypeWechat>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -39646,6 +41584,10 @@ pub struct SourceCodeVerificationFlow {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub status: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -39658,8 +41600,9 @@ pub enum SourceMandateNotificationObject {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SourceMandateNotificationObject {
@@ -39669,7 +41612,7 @@ impl std::fmt::Display for SourceMandateNotificationObject {
                 "source_mandate_notification"
             }
             SourceMandateNotificationObject::Noop => "",
-            SourceMandateNotificationObject::FallthroughString => "*",
+            SourceMandateNotificationObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -39789,6 +41732,10 @@ pub struct SourceMandateNotification {
         rename = "type"
     )]
     pub type_: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -39803,6 +41750,10 @@ pub struct SourceMandateNotificationAcssDebitData {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub statement_descriptor: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -39818,6 +41769,10 @@ pub struct SourceMandateNotificationBacsDebitData {
         rename = "last4"
     )]
     pub last_4: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -39851,6 +41806,10 @@ pub struct SourceMandateNotificationSepaDebitData {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub mandate_reference: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -39897,6 +41856,10 @@ pub struct SourceOrder {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub shipping: Option<ShippingData>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -39957,6 +41920,10 @@ pub struct SourceOrderItem {
         rename = "type"
     )]
     pub type_: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -40026,6 +41993,10 @@ pub struct SourceOwner {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub verified_phone: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -40085,6 +42056,10 @@ pub struct SourceReceiverFlow {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub refund_attributes_status: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -40126,6 +42101,10 @@ pub struct SourceRedirectFlow {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -40138,8 +42117,9 @@ pub enum SourceTransactionObject {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SourceTransactionObject {
@@ -40147,7 +42127,7 @@ impl std::fmt::Display for SourceTransactionObject {
         match self {
             SourceTransactionObject::SourceTransaction => "source_transaction",
             SourceTransactionObject::Noop => "",
-            SourceTransactionObject::FallthroughString => "*",
+            SourceTransactionObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -40199,8 +42179,9 @@ pub enum SourceTransactionType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SourceTransactionType {
@@ -40223,7 +42204,7 @@ impl std::fmt::Display for SourceTransactionType {
             SourceTransactionType::ThreeDSecure => "three_d_secure",
             SourceTransactionType::Wechat => "wechat",
             SourceTransactionType::Noop => "",
-            SourceTransactionType::FallthroughString => "*",
+            SourceTransactionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -40357,6 +42338,10 @@ pub struct SourceTransaction {
         rename = "type"
     )]
     pub type_: SourceTransactionType,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -40399,6 +42384,10 @@ pub struct SourceTransactionAchCreditTransferData {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub routing_number: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -40450,6 +42439,10 @@ pub struct SourceTransactionChfCreditTransferData {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub sender_name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -40519,6 +42512,10 @@ pub struct SourceTransactionGbpCreditTransferData {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub sender_sort_code: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -40542,6 +42539,10 @@ pub struct SourceTransactionPaperCheckData {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub invoices: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -40574,6 +42575,10 @@ pub struct SourceTransactionSepaCreditTransferData {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub sender_name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -40650,6 +42655,10 @@ pub struct SourceTypeAchCreditTransfer {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub swift_code: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -40710,6 +42719,10 @@ pub struct SourceTypeAchDebit {
         rename = "type"
     )]
     pub type_: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -40805,6 +42818,10 @@ pub struct SourceTypeAcssDebit {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub routing_number: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -40836,6 +42853,10 @@ pub struct SourceTypeAlipay {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub statement_descriptor: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -40868,6 +42889,10 @@ pub struct SourceTypeAuBecsDebit {
         rename = "last4"
     )]
     pub last_4: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -40927,6 +42952,10 @@ pub struct SourceTypeBancontact {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub statement_descriptor: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -41060,6 +43089,10 @@ pub struct SourceTypeCard {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub tokenization_method: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -41272,6 +43305,10 @@ pub struct SourceTypeCardPresent {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub transaction_status_information: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -41294,6 +43331,10 @@ pub struct SourceTypeEps {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub statement_descriptor: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -41334,6 +43375,10 @@ pub struct SourceTypeGiropay {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub statement_descriptor: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -41375,6 +43420,10 @@ pub struct SourceTypeIdeal {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub statement_descriptor: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -41613,6 +43662,10 @@ pub struct SourceTypeKlarna {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub shipping_last_name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -41709,6 +43762,10 @@ pub struct SourceTypeMultibanco {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub refund_iban: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -41722,6 +43779,10 @@ pub struct SourceTypeP24 {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub reference: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -41790,6 +43851,10 @@ pub struct SourceTypeSepaDebit {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub mandate_url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -41858,6 +43923,10 @@ pub struct SourceTypeSofort {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub statement_descriptor: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -42017,6 +44086,10 @@ pub struct SourceTypeThreeDSecure {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub tokenization_method: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -42048,6 +44121,10 @@ pub struct SourceTypeWechat {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub statement_descriptor: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -42089,6 +44166,10 @@ pub struct StatusTransitions {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub returned: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// List of subscription items, each with an attached price.
@@ -42125,6 +44206,10 @@ pub struct Items {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -42137,8 +44222,9 @@ pub enum SubscriptionObject {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SubscriptionObject {
@@ -42146,7 +44232,7 @@ impl std::fmt::Display for SubscriptionObject {
         match self {
             SubscriptionObject::Subscription => "subscription",
             SubscriptionObject::Noop => "",
-            SubscriptionObject::FallthroughString => "*",
+            SubscriptionObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -42188,8 +44274,9 @@ pub enum SubscriptionStatus {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SubscriptionStatus {
@@ -42203,7 +44290,7 @@ impl std::fmt::Display for SubscriptionStatus {
             SubscriptionStatus::Trialing => "trialing",
             SubscriptionStatus::Unpaid => "unpaid",
             SubscriptionStatus::Noop => "",
-            SubscriptionStatus::FallthroughString => "*",
+            SubscriptionStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -42499,6 +44586,10 @@ pub struct Subscription {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub trial_start: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -42521,6 +44612,10 @@ pub struct SubscriptionBillingThresholds {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub reset_billing_cycle_anchor: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Subscription items allow you to create customer subscriptions with more than
@@ -42605,6 +44700,10 @@ pub struct SubscriptionItem {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub tax_rates: Vec<TaxRate>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -42619,6 +44718,10 @@ pub struct SubscriptionItemBillingThresholdsData {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub usage_gte: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -42634,6 +44737,10 @@ pub struct SubscriptionPaymentMethodOptionsCard {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub request_three_d_secure: Option<RequestThreeDSecure>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -42653,6 +44760,10 @@ pub struct SubscriptionPendingInvoiceItemInterval {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub interval_count: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -42671,8 +44782,9 @@ pub enum EndBehavior {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EndBehavior {
@@ -42683,7 +44795,7 @@ impl std::fmt::Display for EndBehavior {
             EndBehavior::Release => "release",
             EndBehavior::Renew => "renew",
             EndBehavior::Noop => "",
-            EndBehavior::FallthroughString => "*",
+            EndBehavior::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -42705,8 +44817,9 @@ pub enum SubscriptionScheduleObject {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SubscriptionScheduleObject {
@@ -42714,7 +44827,7 @@ impl std::fmt::Display for SubscriptionScheduleObject {
         match self {
             SubscriptionScheduleObject::SubscriptionSchedule => "subscription_schedule",
             SubscriptionScheduleObject::Noop => "",
-            SubscriptionScheduleObject::FallthroughString => "*",
+            SubscriptionScheduleObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -42744,8 +44857,9 @@ pub enum SubscriptionScheduleStatus {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SubscriptionScheduleStatus {
@@ -42757,7 +44871,7 @@ impl std::fmt::Display for SubscriptionScheduleStatus {
             SubscriptionScheduleStatus::NotStarted => "not_started",
             SubscriptionScheduleStatus::Released => "released",
             SubscriptionScheduleStatus::Noop => "",
-            SubscriptionScheduleStatus::FallthroughString => "*",
+            SubscriptionScheduleStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -42901,6 +45015,10 @@ pub struct SubscriptionSchedule {
      *  Related guide: [Subscription Schedules](https://stripe.com/docs/billing/subscriptions/subscription-schedules).
      */
     pub test_clock: Box<Option<TestClockAnyOf>>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types:
@@ -42990,6 +45108,10 @@ pub struct SubscriptionScheduleAddInvoiceItem {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub tax_rates: Vec<TaxRate>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A phase item describes the price and quantity of a phase.
@@ -43019,6 +45141,10 @@ pub struct SubscriptionScheduleConfigurationItem {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub tax_rates: Vec<TaxRate>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -43042,6 +45168,10 @@ pub struct SubscriptionScheduleCurrentPhase {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub start_date: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -43056,8 +45186,9 @@ pub enum BillingCycleAnchor {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for BillingCycleAnchor {
@@ -43066,7 +45197,7 @@ impl std::fmt::Display for BillingCycleAnchor {
             BillingCycleAnchor::Automatic => "automatic",
             BillingCycleAnchor::PhaseStart => "phase_start",
             BillingCycleAnchor::Noop => "",
-            BillingCycleAnchor::FallthroughString => "*",
+            BillingCycleAnchor::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -43249,6 +45380,10 @@ pub struct SubscriptionSchedulePhaseConfiguration {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub trial_end: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -43297,6 +45432,10 @@ pub struct SubscriptionSchedulesResourceDefaultSettings {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub transfer_data: Option<SubscriptionTransferDataType>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -43312,6 +45451,10 @@ pub struct SubscriptionTransferDataType {
     )]
     pub amount_percent: f64,
     pub destination: Box<AccountAnyOf>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -43328,8 +45471,9 @@ pub enum Behavior {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Behavior {
@@ -43339,7 +45483,7 @@ impl std::fmt::Display for Behavior {
             Behavior::MarkUncollectible => "mark_uncollectible",
             Behavior::Void => "void",
             Behavior::Noop => "",
-            Behavior::FallthroughString => "*",
+            Behavior::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -43370,6 +45514,10 @@ pub struct SubscriptionsResourcePauseCollection {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub resumes_at: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -43405,6 +45553,10 @@ pub struct SubscriptionsResourcePaymentMethodOptions {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub us_bank_account: Option<InvoicePaymentMethodOptionsUsBankAccount>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -43424,6 +45576,10 @@ pub struct SubscriptionsResourcePaymentSettings {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub payment_method_types: Vec<PaymentMethodTypes>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Pending Updates store the changes pending from a previous update that will be applied
@@ -43478,6 +45634,10 @@ pub struct SubscriptionsResourcePendingUpdate {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub trial_from_plan: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -43490,8 +45650,9 @@ pub enum TaxCodeObject {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for TaxCodeObject {
@@ -43499,7 +45660,7 @@ impl std::fmt::Display for TaxCodeObject {
         match self {
             TaxCodeObject::TaxCode => "tax_code",
             TaxCodeObject::Noop => "",
-            TaxCodeObject::FallthroughString => "*",
+            TaxCodeObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -43546,6 +45707,10 @@ pub struct TaxCode {
      */
     #[serde(default, skip_serializing_if = "TaxCodeObject::is_noop")]
     pub object: TaxCodeObject,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -43558,8 +45723,9 @@ pub enum TaxDeductedAtSourceObject {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for TaxDeductedAtSourceObject {
@@ -43567,7 +45733,7 @@ impl std::fmt::Display for TaxDeductedAtSourceObject {
         match self {
             TaxDeductedAtSourceObject::TaxDeductedAtSource => "tax_deducted_at_source",
             TaxDeductedAtSourceObject::Noop => "",
-            TaxDeductedAtSourceObject::FallthroughString => "*",
+            TaxDeductedAtSourceObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -43623,6 +45789,10 @@ pub struct TaxDeductedAtSource {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub tax_deduction_account_number: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// You can add one or multiple tax IDs to a [customer](https://stripe.com/docs/api/customers).
@@ -43705,6 +45875,10 @@ pub struct TaxId {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub verification: Option<TaxVerificationData>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -43723,8 +45897,9 @@ pub enum TaxVerificationStatus {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for TaxVerificationStatus {
@@ -43735,7 +45910,7 @@ impl std::fmt::Display for TaxVerificationStatus {
             TaxVerificationStatus::Unverified => "unverified",
             TaxVerificationStatus::Verified => "verified",
             TaxVerificationStatus::Noop => "",
-            TaxVerificationStatus::FallthroughString => "*",
+            TaxVerificationStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -43773,6 +45948,10 @@ pub struct TaxVerificationData {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub verified_name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -43785,8 +45964,9 @@ pub enum TaxRateObject {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for TaxRateObject {
@@ -43794,7 +45974,7 @@ impl std::fmt::Display for TaxRateObject {
         match self {
             TaxRateObject::TaxRate => "tax_rate",
             TaxRateObject::Noop => "",
-            TaxRateObject::FallthroughString => "*",
+            TaxRateObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -43830,8 +46010,9 @@ pub enum TaxType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for TaxType {
@@ -43846,7 +46027,7 @@ impl std::fmt::Display for TaxType {
             TaxType::SalesTax => "sales_tax",
             TaxType::Vat => "vat",
             TaxType::Noop => "",
-            TaxType::FallthroughString => "*",
+            TaxType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -43988,6 +46169,10 @@ pub struct TaxRate {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub tax_type: Option<TaxType>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -44000,8 +46185,9 @@ pub enum TerminalConnectionTokenObject {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for TerminalConnectionTokenObject {
@@ -44009,7 +46195,7 @@ impl std::fmt::Display for TerminalConnectionTokenObject {
         match self {
             TerminalConnectionTokenObject::TerminalConnectionToken => "terminal.connection_token",
             TerminalConnectionTokenObject::Noop => "",
-            TerminalConnectionTokenObject::FallthroughString => "*",
+            TerminalConnectionTokenObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -44054,6 +46240,10 @@ pub struct TerminalConnectionToken {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub secret: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A Location represents a grouping of readers.
@@ -44108,6 +46298,10 @@ pub struct TerminalLocation {
         skip_serializing_if = "DeletedTerminalLocationObject::is_noop"
     )]
     pub object: DeletedTerminalLocationObject,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -44128,8 +46322,9 @@ pub enum DeviceType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DeviceType {
@@ -44141,7 +46336,7 @@ impl std::fmt::Display for DeviceType {
             DeviceType::StripeM2 => "stripe_m2",
             DeviceType::VerifoneP400 => "verifone_P400",
             DeviceType::Noop => "",
-            DeviceType::FallthroughString => "*",
+            DeviceType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -44306,6 +46501,10 @@ pub struct TerminalReader {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub status: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Represents a cart to be displayed on the reader
@@ -44347,6 +46546,10 @@ pub struct TerminalReaderResourceCart {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Represents a line item to be displayed on the reader
@@ -44379,12 +46582,20 @@ pub struct TerminalReaderResourceLineItem {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub quantity: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Represents a reader action to process a payment intent
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct TerminalReaderResourceProcessPaymentIntentAction {
     pub payment_intent: Box<PaymentIntentAnyOf>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Represents a reader action to process a setup intent
@@ -44400,6 +46611,10 @@ pub struct TerminalReaderResourceProcessSetupIntentAction {
     )]
     pub generated_card: String,
     pub setup_intent: Box<SetupIntentAnyOf>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -44416,8 +46631,9 @@ pub enum TerminalReaderResourceActionStatus {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for TerminalReaderResourceActionStatus {
@@ -44427,7 +46643,7 @@ impl std::fmt::Display for TerminalReaderResourceActionStatus {
             TerminalReaderResourceActionStatus::InProgress => "in_progress",
             TerminalReaderResourceActionStatus::Succeeded => "succeeded",
             TerminalReaderResourceActionStatus::Noop => "",
-            TerminalReaderResourceActionStatus::FallthroughString => "*",
+            TerminalReaderResourceActionStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -44453,8 +46669,9 @@ pub enum TerminalReaderResourceActionType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for TerminalReaderResourceActionType {
@@ -44464,7 +46681,7 @@ impl std::fmt::Display for TerminalReaderResourceActionType {
             TerminalReaderResourceActionType::ProcessSetupIntent => "process_setup_intent",
             TerminalReaderResourceActionType::SetReaderDisplay => "set_reader_display",
             TerminalReaderResourceActionType::Noop => "",
-            TerminalReaderResourceActionType::FallthroughString => "*",
+            TerminalReaderResourceActionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -44529,6 +46746,10 @@ pub struct TerminalReaderResourceAction {
         rename = "type"
     )]
     pub type_: TerminalReaderResourceActionType,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -44541,8 +46762,9 @@ pub enum TerminalReaderResourceSetDisplayActionType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for TerminalReaderResourceSetDisplayActionType {
@@ -44550,7 +46772,7 @@ impl std::fmt::Display for TerminalReaderResourceSetDisplayActionType {
         match self {
             TerminalReaderResourceSetDisplayActionType::Cart => "cart",
             TerminalReaderResourceSetDisplayActionType::Noop => "",
-            TerminalReaderResourceSetDisplayActionType::FallthroughString => "*",
+            TerminalReaderResourceSetDisplayActionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -44579,6 +46801,10 @@ pub struct TerminalReaderResourceSetDisplayAction {
         rename = "type"
     )]
     pub type_: TerminalReaderResourceSetDisplayActionType,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -44595,8 +46821,9 @@ pub enum TestClockStatus {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for TestClockStatus {
@@ -44606,7 +46833,7 @@ impl std::fmt::Display for TestClockStatus {
             TestClockStatus::InternalFailure => "internal_failure",
             TestClockStatus::Ready => "ready",
             TestClockStatus::Noop => "",
-            TestClockStatus::FallthroughString => "*",
+            TestClockStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -44688,6 +46915,10 @@ pub struct TestClock {
      */
     #[serde(default, skip_serializing_if = "TestClockStatus::is_noop")]
     pub status: TestClockStatus,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -44700,8 +46931,9 @@ pub enum ThreeDSecureObject {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ThreeDSecureObject {
@@ -44709,7 +46941,7 @@ impl std::fmt::Display for ThreeDSecureObject {
         match self {
             ThreeDSecureObject::ThreeDSecure => "three_d_secure",
             ThreeDSecureObject::Noop => "",
-            ThreeDSecureObject::FallthroughString => "*",
+            ThreeDSecureObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -44811,6 +47043,10 @@ pub struct ThreeDSecure {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub status: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -44826,8 +47062,9 @@ pub enum AuthenticationFlow {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AuthenticationFlow {
@@ -44836,7 +47073,7 @@ impl std::fmt::Display for AuthenticationFlow {
             AuthenticationFlow::Challenge => "challenge",
             AuthenticationFlow::Frictionless => "frictionless",
             AuthenticationFlow::Noop => "",
-            AuthenticationFlow::FallthroughString => "*",
+            AuthenticationFlow::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -44866,8 +47103,9 @@ pub enum ThreeDSecureDetailsResult {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ThreeDSecureDetailsResult {
@@ -44879,7 +47117,7 @@ impl std::fmt::Display for ThreeDSecureDetailsResult {
             ThreeDSecureDetailsResult::NotSupported => "not_supported",
             ThreeDSecureDetailsResult::ProcessingError => "processing_error",
             ThreeDSecureDetailsResult::Noop => "",
-            ThreeDSecureDetailsResult::FallthroughString => "*",
+            ThreeDSecureDetailsResult::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -44914,8 +47152,9 @@ pub enum ResultReason {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ResultReason {
@@ -44929,7 +47168,7 @@ impl std::fmt::Display for ResultReason {
             ResultReason::ProtocolError => "protocol_error",
             ResultReason::Rejected => "rejected",
             ResultReason::Noop => "",
-            ResultReason::FallthroughString => "*",
+            ResultReason::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -44955,8 +47194,9 @@ pub enum Version {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Version {
@@ -44966,7 +47206,7 @@ impl std::fmt::Display for Version {
             Version::TwoHundredAndTen => "2.1.0",
             Version::Twenty => "2.2.0",
             Version::Noop => "",
-            Version::FallthroughString => "*",
+            Version::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -45003,6 +47243,10 @@ pub struct ThreeDSecureDetails {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub version: Option<Version>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -45016,6 +47260,10 @@ pub struct ThreeDSecureUsage {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub supported: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -45028,8 +47276,9 @@ pub enum TokenObject {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for TokenObject {
@@ -45037,7 +47286,7 @@ impl std::fmt::Display for TokenObject {
         match self {
             TokenObject::Token => "token",
             TokenObject::Noop => "",
-            TokenObject::FallthroughString => "*",
+            TokenObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -45204,6 +47453,10 @@ pub struct Token {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub used: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -45216,8 +47469,9 @@ pub enum TopupObject {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for TopupObject {
@@ -45225,7 +47479,7 @@ impl std::fmt::Display for TopupObject {
         match self {
             TopupObject::Topup => "topup",
             TopupObject::Noop => "",
-            TopupObject::FallthroughString => "*",
+            TopupObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -45255,8 +47509,9 @@ pub enum TopupStatus {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for TopupStatus {
@@ -45268,7 +47523,7 @@ impl std::fmt::Display for TopupStatus {
             TopupStatus::Reversed => "reversed",
             TopupStatus::Succeeded => "succeeded",
             TopupStatus::Noop => "",
-            TopupStatus::FallthroughString => "*",
+            TopupStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -45445,6 +47700,10 @@ pub struct Topup {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub transfer_group: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -45457,8 +47716,9 @@ pub enum TransferObject {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for TransferObject {
@@ -45466,7 +47726,7 @@ impl std::fmt::Display for TransferObject {
         match self {
             TransferObject::Transfer => "transfer",
             TransferObject::Noop => "",
-            TransferObject::FallthroughString => "*",
+            TransferObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -45512,6 +47772,10 @@ pub struct Reversals {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A `Transfer` object is created when you move funds between Stripe accounts as
@@ -45711,6 +47975,10 @@ pub struct Transfer {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub transfer_group: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -45726,6 +47994,10 @@ pub struct TransferData {
     )]
     pub amount: i64,
     pub destination: Box<AccountAnyOf>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -45738,8 +48010,9 @@ pub enum TransferReversalObject {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for TransferReversalObject {
@@ -45747,7 +48020,7 @@ impl std::fmt::Display for TransferReversalObject {
         match self {
             TransferReversalObject::TransferReversal => "transfer_reversal",
             TransferReversalObject::Noop => "",
-            TransferReversalObject::FallthroughString => "*",
+            TransferReversalObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -45885,6 +48158,10 @@ pub struct TransferReversal {
      */
     pub source_refund: Box<Option<RefundAnyOf>>,
     pub transfer: Box<TransferAnyOf>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -45926,6 +48203,10 @@ pub struct TransferSchedule {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub weekly_anchor: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -45940,8 +48221,9 @@ pub enum Round {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Round {
@@ -45950,7 +48232,7 @@ impl std::fmt::Display for Round {
             Round::Down => "down",
             Round::Up => "up",
             Round::Noop => "",
-            Round::FallthroughString => "*",
+            Round::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -45979,6 +48261,10 @@ pub struct TransformUsage {
      */
     #[serde(default, skip_serializing_if = "Round::is_noop")]
     pub round: Round,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -45991,8 +48277,9 @@ pub enum UsageRecordObject {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for UsageRecordObject {
@@ -46000,7 +48287,7 @@ impl std::fmt::Display for UsageRecordObject {
         match self {
             UsageRecordObject::UsageRecord => "usage_record",
             UsageRecordObject::Noop => "",
-            UsageRecordObject::FallthroughString => "*",
+            UsageRecordObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -46067,6 +48354,10 @@ pub struct UsageRecord {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub timestamp: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -46079,8 +48370,9 @@ pub enum UsageRecordSummaryObject {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for UsageRecordSummaryObject {
@@ -46088,7 +48380,7 @@ impl std::fmt::Display for UsageRecordSummaryObject {
         match self {
             UsageRecordSummaryObject::UsageRecordSummary => "usage_record_summary",
             UsageRecordSummaryObject::Noop => "",
-            UsageRecordSummaryObject::FallthroughString => "*",
+            UsageRecordSummaryObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -46156,6 +48448,10 @@ pub struct UsageRecordSummary {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_usage: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -46170,8 +48466,9 @@ pub enum VerificationSessionRedactionStatus {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for VerificationSessionRedactionStatus {
@@ -46180,7 +48477,7 @@ impl std::fmt::Display for VerificationSessionRedactionStatus {
             VerificationSessionRedactionStatus::Processing => "processing",
             VerificationSessionRedactionStatus::Redacted => "redacted",
             VerificationSessionRedactionStatus::Noop => "",
-            VerificationSessionRedactionStatus::FallthroughString => "*",
+            VerificationSessionRedactionStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -46203,6 +48500,10 @@ pub struct VerificationSessionRedaction {
         skip_serializing_if = "VerificationSessionRedactionStatus::is_noop"
     )]
     pub status: VerificationSessionRedactionStatus,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// You can configure [webhook endpoints](https://stripe.com/docs/webhooks/) via the API to be
@@ -46341,6 +48642,10 @@ pub struct WebhookEndpoint {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -46374,6 +48679,10 @@ pub struct ListAccountCapability {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Filters on the list of people returned based on the person's relationship to the account's company.
@@ -46411,6 +48720,10 @@ pub struct Relationship {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub representative: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -46444,6 +48757,10 @@ pub struct GetAccountPeopleResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -46472,6 +48789,10 @@ pub struct Paid {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub lte: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types:
@@ -46550,6 +48871,10 @@ pub struct GetAccountsResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -46583,6 +48908,10 @@ pub struct ApplePayDomainList {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -46616,6 +48945,10 @@ pub struct GetApplicationFeesResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -46652,6 +48985,10 @@ pub struct BalanceTransactionsList {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -46685,6 +49022,10 @@ pub struct GetBillingPortalConfigurationsResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -46718,6 +49059,10 @@ pub struct GetBitcoinReceiversResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -46730,8 +49075,9 @@ pub enum SearchResultObject {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SearchResultObject {
@@ -46739,7 +49085,7 @@ impl std::fmt::Display for SearchResultObject {
         match self {
             SearchResultObject::SearchResult => "search_result",
             SearchResultObject::Noop => "",
-            SearchResultObject::FallthroughString => "*",
+            SearchResultObject::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -46803,6 +49149,10 @@ pub struct SearchResult {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -46836,6 +49186,10 @@ pub struct PaymentPagesCheckoutSessionList {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -46869,6 +49223,10 @@ pub struct GetCountrySpecsResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -46902,6 +49260,10 @@ pub struct GetCouponsResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -46935,6 +49297,10 @@ pub struct CreditNotesList {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -46942,15 +49308,16 @@ pub enum Shipping {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Shipping {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Shipping::Noop => "",
-            Shipping::FallthroughString => "*",
+            Shipping::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -47058,6 +49425,10 @@ pub struct CreditNoteLineItemParams {
         deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub unit_amount_decimal: f64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -47091,6 +49462,10 @@ pub struct GetCustomersResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types:
@@ -47165,6 +49540,10 @@ pub struct CustomerBalanceTransactionList {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -47201,6 +49580,10 @@ pub struct BankAccountList {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types:
@@ -47285,8 +49668,9 @@ pub enum GetCustomersCustomerPaymentMethodsType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GetCustomersCustomerPaymentMethodsType {
@@ -47315,7 +49699,7 @@ impl std::fmt::Display for GetCustomersCustomerPaymentMethodsType {
             GetCustomersCustomerPaymentMethodsType::UsBankAccount => "us_bank_account",
             GetCustomersCustomerPaymentMethodsType::WechatPay => "wechat_pay",
             GetCustomersCustomerPaymentMethodsType::Noop => "",
-            GetCustomersCustomerPaymentMethodsType::FallthroughString => "*",
+            GetCustomersCustomerPaymentMethodsType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -47358,6 +49742,10 @@ pub struct PaymentFlowsMethodList {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -47391,6 +49779,10 @@ pub struct GetDisputesResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -47424,6 +49816,10 @@ pub struct NotificationEventList {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -47457,6 +49853,10 @@ pub struct GetExchangeRatesResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -47490,6 +49890,10 @@ pub struct GetFilesResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -47523,6 +49927,10 @@ pub struct GetIdentityVerificationReportsResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -47556,6 +49964,10 @@ pub struct GetIdentityVerificationSessionsResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -47589,6 +50001,10 @@ pub struct GetInvoiceitemsResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -47609,8 +50025,9 @@ pub enum GetInvoicesStatus {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GetInvoicesStatus {
@@ -47622,7 +50039,7 @@ impl std::fmt::Display for GetInvoicesStatus {
             GetInvoicesStatus::Uncollectible => "uncollectible",
             GetInvoicesStatus::Void => "void",
             GetInvoicesStatus::Noop => "",
-            GetInvoicesStatus::FallthroughString => "*",
+            GetInvoicesStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -47665,6 +50082,10 @@ pub struct InvoicesList {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -47707,6 +50128,10 @@ pub struct OptionalFieldsAddress {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub state: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types:
@@ -47757,6 +50182,10 @@ pub struct CustomerShipping {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub phone: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types:
@@ -47837,6 +50266,10 @@ impl std::convert::From<IpAddressAnyOf> for String {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Tax {
     pub ip_address: Box<Option<IpAddressAnyOf>>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -47850,8 +50283,9 @@ pub enum GetInvoicesUpcomingCustomerDetailsTaxExempt {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GetInvoicesUpcomingCustomerDetailsTaxExempt {
@@ -47861,7 +50295,7 @@ impl std::fmt::Display for GetInvoicesUpcomingCustomerDetailsTaxExempt {
             GetInvoicesUpcomingCustomerDetailsTaxExempt::None => "none",
             GetInvoicesUpcomingCustomerDetailsTaxExempt::Reverse => "reverse",
             GetInvoicesUpcomingCustomerDetailsTaxExempt::Noop => "",
-            GetInvoicesUpcomingCustomerDetailsTaxExempt::FallthroughString => "*",
+            GetInvoicesUpcomingCustomerDetailsTaxExempt::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -47968,8 +50402,9 @@ pub enum GetInvoicesUpcomingCustomerDetailsDataParamsType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GetInvoicesUpcomingCustomerDetailsDataParamsType {
@@ -48021,7 +50456,7 @@ impl std::fmt::Display for GetInvoicesUpcomingCustomerDetailsDataParamsType {
             GetInvoicesUpcomingCustomerDetailsDataParamsType::UsEin => "us_ein",
             GetInvoicesUpcomingCustomerDetailsDataParamsType::ZaVat => "za_vat",
             GetInvoicesUpcomingCustomerDetailsDataParamsType::Noop => "",
-            GetInvoicesUpcomingCustomerDetailsDataParamsType::FallthroughString => "*",
+            GetInvoicesUpcomingCustomerDetailsDataParamsType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -48050,6 +50485,10 @@ pub struct DataParams {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub value: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Details about the customer you want to invoice or overrides for an existing customer.
@@ -48082,6 +50521,10 @@ pub struct CustomerDetails {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub tax_ids: Vec<DataParams>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -48098,6 +50541,10 @@ pub struct Discounts {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub discount: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types:
@@ -48230,6 +50677,10 @@ pub struct OneTimePriceData {
         deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub unit_amount_decimal: f64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -48297,6 +50748,10 @@ pub struct InvoiceItems {
         deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub unit_amount_decimal: f64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -48311,8 +50766,9 @@ pub enum SubscriptionBillingCycleAnchor {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SubscriptionBillingCycleAnchor {
@@ -48321,7 +50777,7 @@ impl std::fmt::Display for SubscriptionBillingCycleAnchor {
             SubscriptionBillingCycleAnchor::Now => "now",
             SubscriptionBillingCycleAnchor::Unchanged => "unchanged",
             SubscriptionBillingCycleAnchor::Noop => "",
-            SubscriptionBillingCycleAnchor::FallthroughString => "*",
+            SubscriptionBillingCycleAnchor::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -48437,6 +50893,10 @@ pub struct BillingThresholds {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub usage_gte: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types:
@@ -48482,6 +50942,10 @@ pub struct RecurringAdhoc {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub interval_count: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -48525,6 +50989,10 @@ pub struct RecurringPriceData {
         deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub unit_amount_decimal: f64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -48562,6 +51030,10 @@ pub struct SubscriptionItems {
     )]
     pub quantity: i64,
     pub tax_rates: Box<Option<TaxRatesAnyOf>>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -48574,8 +51046,9 @@ pub enum SubscriptionTrialEnd {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SubscriptionTrialEnd {
@@ -48583,7 +51056,7 @@ impl std::fmt::Display for SubscriptionTrialEnd {
         match self {
             SubscriptionTrialEnd::Now => "now",
             SubscriptionTrialEnd::Noop => "",
-            SubscriptionTrialEnd::FallthroughString => "*",
+            SubscriptionTrialEnd::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -48674,6 +51147,10 @@ pub struct RadarIssuerFraudRecordList {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -48707,6 +51184,10 @@ pub struct GetIssuingAuthorizationsResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -48740,6 +51221,10 @@ pub struct GetIssuingCardholdersResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -48773,6 +51258,10 @@ pub struct GetIssuingCardsResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -48806,6 +51295,10 @@ pub struct IssuingDisputeList {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -48839,6 +51332,10 @@ pub struct GetIssuingSettlementsResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -48875,6 +51372,10 @@ pub struct GetIssuingTransactionsResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Filter orders based on when they were paid, fulfilled, canceled, or returned.
@@ -48896,6 +51397,10 @@ pub struct OrderTimestampSpecs {
      * Filter orders based on when they were paid, fulfilled, canceled, or returned.
      */
     pub returned: Box<Option<PaidAnyOf>>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -48929,6 +51434,10 @@ pub struct OrdersLegacyResourceOrderList {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -48962,6 +51471,10 @@ pub struct PaymentFlowsIntentList {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -48995,6 +51508,10 @@ pub struct GetPaymentLinksResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -49028,6 +51545,10 @@ pub struct PayoutList {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -49064,6 +51585,10 @@ pub struct PlanList {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Only return prices with these recurring fields.
@@ -49079,6 +51604,10 @@ pub struct AllPricesRecurringParams {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub usage_type: Option<UsageType>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -49115,6 +51644,10 @@ pub struct PriceList {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -49151,6 +51684,10 @@ pub struct ProductList {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -49184,6 +51721,10 @@ pub struct GetPromotionCodesResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -49217,6 +51758,10 @@ pub struct GetQuotesResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -49250,6 +51795,10 @@ pub struct RadarEarlyFraudWarningList {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -49283,6 +51832,10 @@ pub struct GetRadarValueListsResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -49294,8 +51847,9 @@ pub enum GetRecipientsType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GetRecipientsType {
@@ -49304,7 +51858,7 @@ impl std::fmt::Display for GetRecipientsType {
             GetRecipientsType::Corporation => "corporation",
             GetRecipientsType::Individual => "individual",
             GetRecipientsType::Noop => "",
-            GetRecipientsType::FallthroughString => "*",
+            GetRecipientsType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -49347,6 +51901,10 @@ pub struct GetRecipientsResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types:
@@ -49425,6 +51983,10 @@ pub struct GetReportingReportRunsResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -49458,6 +52020,10 @@ pub struct FinancialReportingFinanceReportTypeList {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -49491,6 +52057,10 @@ pub struct GetReviewsResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -49524,6 +52094,10 @@ pub struct PaymentFlowsSetupIntentAttemptList {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -49557,6 +52131,10 @@ pub struct PaymentFlowsSetupIntentList {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -49590,6 +52168,10 @@ pub struct ShippingResourcesRateList {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -49623,6 +52205,10 @@ pub struct GetSigmaScheduledQueryRunsResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -49656,6 +52242,10 @@ pub struct GetSkusResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types:
@@ -49730,6 +52320,10 @@ pub struct ApmsSourcesSourceTransactionList {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -49763,6 +52357,10 @@ pub struct GetSubscriptionItemsItemUsageRecordSummariesResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -49796,6 +52394,10 @@ pub struct GetSubscriptionSchedulesResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -49824,8 +52426,9 @@ pub enum GetSubscriptionsStatus {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GetSubscriptionsStatus {
@@ -49841,7 +52444,7 @@ impl std::fmt::Display for GetSubscriptionsStatus {
             GetSubscriptionsStatus::Trialing => "trialing",
             GetSubscriptionsStatus::Unpaid => "unpaid",
             GetSubscriptionsStatus::Noop => "",
-            GetSubscriptionsStatus::FallthroughString => "*",
+            GetSubscriptionsStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -49884,6 +52487,10 @@ pub struct TaxProductResourceCodeList {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -49920,6 +52527,10 @@ pub struct GetTaxRatesResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -49953,6 +52564,10 @@ pub struct TerminalLocationList {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types:
@@ -50027,6 +52642,10 @@ pub struct TerminalReaderRetrieve {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types:
@@ -50098,6 +52717,10 @@ pub struct GetTestHelpersClocksResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -50116,8 +52739,9 @@ pub enum GetTopupsStatus {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GetTopupsStatus {
@@ -50128,7 +52752,7 @@ impl std::fmt::Display for GetTopupsStatus {
             GetTopupsStatus::Pending => "pending",
             GetTopupsStatus::Succeeded => "succeeded",
             GetTopupsStatus::Noop => "",
-            GetTopupsStatus::FallthroughString => "*",
+            GetTopupsStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -50171,6 +52795,10 @@ pub struct TopupList {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -50207,6 +52835,10 @@ pub struct TransferList {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -50240,4 +52872,8 @@ pub struct GetWebhookEndpointsResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
@@ -0,0 +1,206 @@
+//! For verifying and parsing the webhook events Stripe sends to an endpoint.
+//!
+//! FROM: <https://stripe.com/docs/webhooks/signatures>
+use std::time::{Duration, SystemTime};
+
+use hmac::{Hmac, Mac};
+use serde::Deserialize;
+use sha2::Sha256;
+
+use crate::types;
+
+/// The header Stripe signs webhook requests with.
+pub const SIGNATURE_HEADER: &str = "Stripe-Signature";
+
+/// How far the timestamp of a signature may be from the current time by default.
+pub const DEFAULT_TOLERANCE: Duration = Duration::from_secs(300);
+
+/// Errors returned while verifying or parsing a webhook event.
+#[derive(Debug, thiserror::Error)]
+pub enum WebhookError {
+    /// The signature header is malformed
+    #[error("invalid Stripe-Signature header")]
+    InvalidHeader,
+    /// None of the signatures of the header match the payload
+    #[error("no signature matches the payload")]
+    NoMatchingSignature,
+    /// The signature is too old, or from the future
+    #[error("the signature timestamp is outside of the tolerance")]
+    TimestampOutsideTolerance,
+    /// The payload is not an event
+    #[error(transparent)]
+    SerdeJsonError(#[from] serde_json::Error),
+}
+
+/// A webhook event, verified and parsed.
+#[derive(Debug, Clone)]
+pub struct WebhookEvent {
+    pub event: types::Event,
+    /// The object the event is about, in its state after the event.
+    pub object: WebhookObject,
+    /// The attributes of the object that changed, with their values before the event,
+    /// for `*.updated` events.
+    pub previous_attributes: Option<serde_json::Value>,
+}
+
+/// The object a webhook event is about, by its kind.
+///
+/// Objects of the kinds without a variant of their own are kept as JSON.
+#[derive(Debug, Clone)]
+pub enum WebhookObject {
+    Charge(Box<types::Charge>),
+    CheckoutSession(Box<types::Session>),
+    Customer(Box<types::Customer>),
+    Dispute(Box<types::Dispute>),
+    Invoice(Box<types::Invoice>),
+    PaymentIntent(Box<types::PaymentIntent>),
+    PaymentMethod(Box<types::PaymentMethod>),
+    Payout(Box<types::Payout>),
+    Product(Box<types::Product>),
+    Refund(Box<types::Refund>),
+    SetupIntent(Box<types::SetupIntent>),
+    Subscription(Box<types::Subscription>),
+    Other(serde_json::Value),
+}
+
+impl WebhookObject {
+    fn from_value(object: serde_json::Value) -> serde_json::Result<Self> {
+        let kind = object.get("object").and_then(|kind| kind.as_str());
+        Ok(match kind {
+            Some("charge") => WebhookObject::Charge(serde_json::from_value(object)?),
+            Some("checkout.session") => {
+                WebhookObject::CheckoutSession(serde_json::from_value(object)?)
+            }
+            Some("customer") => WebhookObject::Customer(serde_json::from_value(object)?),
+            Some("dispute") => WebhookObject::Dispute(serde_json::from_value(object)?),
+            Some("invoice") => WebhookObject::Invoice(serde_json::from_value(object)?),
+            Some("payment_intent") => WebhookObject::PaymentIntent(serde_json::from_value(object)?),
+            Some("payment_method") => WebhookObject::PaymentMethod(serde_json::from_value(object)?),
+            Some("payout") => WebhookObject::Payout(serde_json::from_value(object)?),
+            Some("product") => WebhookObject::Product(serde_json::from_value(object)?),
+            Some("refund") => WebhookObject::Refund(serde_json::from_value(object)?),
+            Some("setup_intent") => WebhookObject::SetupIntent(serde_json::from_value(object)?),
+            Some("subscription") => WebhookObject::Subscription(serde_json::from_value(object)?),
+            _ => WebhookObject::Other(object),
+        })
+    }
+}
+
+/// Verifies the webhook requests of an endpoint, with its signing secret.
+#[derive(Clone)]
+pub struct Webhook {
+    secret: String,
+    tolerance: Duration,
+}
+
+impl Webhook {
+    /// Create a new webhook verifier from the signing secret of the endpoint, which
+    /// starts with `whsec_`.
+    pub fn new<S>(secret: S) -> Self
+    where
+        S: ToString,
+    {
+        Webhook {
+            secret: secret.to_string(),
+            tolerance: DEFAULT_TOLERANCE,
+        }
+    }
+
+    /// Sets how far the timestamp of a signature may be from the current time, to
+    /// protect against replayed requests.
+    pub fn with_tolerance(&mut self, tolerance: Duration) -> &mut Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    fn mac(&self, timestamp: u64, payload: &[u8]) -> Hmac<Sha256> {
+        let mut mac = Hmac::<Sha256>::new_from_slice(self.secret.as_bytes())
+            .expect("HMAC can take a key of any size");
+        mac.update(timestamp.to_string().as_bytes());
+        mac.update(b".");
+        mac.update(payload);
+        mac
+    }
+
+    /// Returns the `Stripe-Signature` header Stripe would send along with a payload at
+    /// `timestamp`, in seconds since the epoch, to test webhook handlers.
+    pub fn sign(&self, payload: &[u8], timestamp: u64) -> String {
+        let signature = self.mac(timestamp, payload).finalize().into_bytes();
+        format!("t={},v1={}", timestamp, hex::encode(signature))
+    }
+
+    /// Verifies the `Stripe-Signature` header of a request against its raw body.
+    pub fn verify(&self, payload: &[u8], signature: &str) -> Result<(), WebhookError> {
+        let mut timestamp = None;
+        let mut signatures = Vec::new();
+        for (key, value) in signature.split(',').filter_map(|part| part.split_once('=')) {
+            match key.trim() {
+                "t" => timestamp = value.trim().parse::<u64>().ok(),
+                "v1" => signatures.push(value.trim()),
+                // Other schemes, like the `v0` of test mode, are not secure enough.
+                _ => {}
+            }
+        }
+        let timestamp = timestamp.ok_or(WebhookError::InvalidHeader)?;
+        if signatures.is_empty() {
+            return Err(WebhookError::NoMatchingSignature);
+        }
+
+        let matches = signatures
+            .iter()
+            .filter_map(|signature| hex::decode(signature).ok())
+            .any(|signature| {
+                self.mac(timestamp, payload)
+                    .verify_slice(&signature)
+                    .is_ok()
+            });
+        if !matches {
+            return Err(WebhookError::NoMatchingSignature);
+        }
+
+        let signed_at = SystemTime::UNIX_EPOCH + Duration::from_secs(timestamp);
+        let age = match SystemTime::now().duration_since(signed_at) {
+            Ok(age) => age,
+            Err(e) => e.duration(),
+        };
+        if age > self.tolerance {
+            return Err(WebhookError::TimestampOutsideTolerance);
+        }
+
+        Ok(())
+    }
+
+    /// Verifies a request and parses its body into an event.
+    pub fn construct_event(
+        &self,
+        payload: &[u8],
+        signature: &str,
+    ) -> Result<WebhookEvent, WebhookError> {
+        self.verify(payload, signature)?;
+        parse_event(payload)
+    }
+}
+
+/// Parses the body of a webhook request into an event, without verifying it.
+pub fn parse_event(payload: &[u8]) -> Result<WebhookEvent, WebhookError> {
+    #[derive(Deserialize)]
+    struct Data {
+        object: serde_json::Value,
+        #[serde(default)]
+        previous_attributes: Option<serde_json::Value>,
+    }
+
+    #[derive(Deserialize)]
+    struct Payload {
+        data: Data,
+    }
+
+    let event = serde_json::from_slice::<types::Event>(payload)?;
+    let Payload { data } = serde_json::from_slice::<Payload>(payload)?;
+
+    Ok(WebhookEvent {
+        event,
+        object: WebhookObject::from_value(data.object)?,
+        previous_attributes: data.previous_attributes,
+    })
+}
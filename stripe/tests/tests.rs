@@ -1 +1,320 @@
+use std::{
+    mem,
+    time::{Duration, SystemTime},
+};
 
+use wiremock::{
+    matchers::{method, path, query_param, query_param_is_missing},
+    Mock, MockServer, ResponseTemplate,
+};
+
+use dolladollabills::{
+    webhooks::{self, Webhook, WebhookError, WebhookObject},
+    Client, ClientBuilder, ClientError, StatusCode,
+};
+
+#[tokio::test]
+async fn test_api_error_is_parsed() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/charges/ch_1"))
+        .respond_with(ResponseTemplate::new(402).set_body_json(serde_json::json!({
+            "error": {
+                "type": "card_error",
+                "code": "card_declined",
+                "decline_code": "insufficient_funds",
+                "message": "Your card has insufficient funds.",
+                "charge": "ch_1"
+            }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut client = Client::new("token");
+    client.with_host_override(server.uri());
+
+    match client.charges().get("ch_1").await {
+        Err(ClientError::ApiError { status, error, .. }) => {
+            assert_eq!(status, StatusCode::PAYMENT_REQUIRED);
+            assert_eq!(error.type_, "card_error");
+            assert_eq!(error.decline_code, "insufficient_funds");
+            assert_eq!(
+                error.to_string(),
+                "Your card has insufficient funds. (card_declined: insufficient_funds)"
+            );
+        }
+        result => unreachable!("Expected API error, got {:?}", result),
+    }
+
+    mem::drop(server)
+}
+
+#[tokio::test]
+async fn test_rate_limited_keeps_api_error() {
+    let server = MockServer::start().await;
+
+    // Stripe does not say how long to wait.
+    Mock::given(method("GET"))
+        .and(path("/v1/charges/ch_1"))
+        .respond_with(ResponseTemplate::new(429).set_body_json(serde_json::json!({
+            "error": {
+                "type": "invalid_request_error",
+                "code": "rate_limit",
+                "message": "Too many requests hit the API too quickly."
+            }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut client = Client::new("token");
+    client
+        .with_host_override(server.uri())
+        .with_client_builder(ClientBuilder::new().max_retries(0))
+        .unwrap();
+
+    match client.charges().get("ch_1").await {
+        Err(ClientError::RateLimited {
+            status,
+            duration,
+            error,
+            ..
+        }) => {
+            assert_eq!(status, StatusCode::TOO_MANY_REQUESTS);
+            assert_eq!(duration, None);
+            assert_eq!(error.unwrap().code, "rate_limit");
+        }
+        result => unreachable!("Expected rate limit error, got {:?}", result),
+    }
+
+    mem::drop(server)
+}
+
+async fn mount_refunds_page(
+    server: &MockServer,
+    starting_after: Option<&str>,
+    refunds: &[&str],
+    has_more: bool,
+) {
+    let mock = Mock::given(method("GET")).and(path("/v1/charges/ch_1/refunds"));
+    let mock = match starting_after {
+        Some(id) => mock.and(query_param("starting_after", id)),
+        None => mock.and(query_param_is_missing("starting_after")),
+    };
+
+    mock.respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+        "object": "list",
+        "url": "/v1/charges/ch_1/refunds",
+        "has_more": has_more,
+        "data": refunds
+            .iter()
+            .map(|id| serde_json::json!({ "id": id, "object": "refund" }))
+            .collect::<Vec<_>>()
+    })))
+    .mount(server)
+    .await;
+}
+
+async fn list_all_refunds(client: &Client) -> Result<Vec<String>, ClientError> {
+    client
+        .charges()
+        .get_all_refunds("ch_1")
+        .await
+        .map(|response| response.body.into_iter().map(|refund| refund.id).collect())
+}
+
+#[tokio::test]
+async fn test_get_all_follows_starting_after() {
+    let server = MockServer::start().await;
+
+    mount_refunds_page(&server, None, &["re_1", "re_2"], true).await;
+    mount_refunds_page(&server, Some("re_2"), &["re_3"], false).await;
+
+    let mut client = Client::new("token");
+    client.with_host_override(server.uri());
+    assert_eq!(
+        list_all_refunds(&client).await.unwrap(),
+        vec!["re_1", "re_2", "re_3"]
+    );
+
+    // The last page still says there is more, but hands back the object the
+    // request started after.
+    server.reset().await;
+    mount_refunds_page(&server, None, &["re_1", "re_2"], true).await;
+    mount_refunds_page(&server, Some("re_2"), &["re_3", "re_2"], true).await;
+
+    match list_all_refunds(&client).await {
+        Err(ClientError::IncompletePages { page_token }) => {
+            assert!(page_token.ends_with("starting_after=re_2"))
+        }
+        result => unreachable!("Expected incomplete pages, got {:?}", result),
+    }
+    assert_eq!(server.received_requests().await.unwrap().len(), 2);
+
+    mem::drop(server)
+}
+
+#[tokio::test]
+async fn test_get_all_respects_max_items() {
+    let server = MockServer::start().await;
+
+    mount_refunds_page(&server, None, &["re_1", "re_2"], true).await;
+    mount_refunds_page(&server, Some("re_2"), &["re_3", "re_4"], true).await;
+    mount_refunds_page(&server, Some("re_4"), &["re_5"], false).await;
+
+    let mut client = Client::new("token");
+    client.with_host_override(server.uri()).with_max_items(3);
+    assert_eq!(
+        list_all_refunds(&client).await.unwrap(),
+        vec!["re_1", "re_2", "re_3"]
+    );
+    assert_eq!(server.received_requests().await.unwrap().len(), 2);
+
+    mem::drop(server)
+}
+
+#[tokio::test]
+async fn test_get_page_request_sends_only_the_parameters_set() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/customers"))
+        .and(query_param("email", "jenny@example.com"))
+        .and(query_param("limit", "3"))
+        .and(query_param_is_missing("ending_before"))
+        .and(query_param_is_missing("starting_after"))
+        .and(query_param_is_missing("test_clock"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "object": "list",
+            "url": "/v1/customers",
+            "has_more": false,
+            "data": [{ "id": "cus_1", "object": "customer" }]
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut client = Client::new("token");
+    client.with_host_override(server.uri());
+
+    let customers = client
+        .customers()
+        .get_page_request()
+        .email("jenny@example.com")
+        .limit(3)
+        .send()
+        .await
+        .unwrap()
+        .body;
+    assert_eq!(customers.len(), 1);
+    assert_eq!(customers[0].id, "cus_1");
+
+    mem::drop(server)
+}
+
+const WEBHOOK_SECRET: &str = "whsec_test_secret";
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .unwrap()
+        .as_secs()
+}
+
+#[test]
+fn test_webhook_constructs_typed_event() {
+    let payload = include_bytes!("fixtures/charge.succeeded.json");
+    let webhook = Webhook::new(WEBHOOK_SECRET);
+    let signature = webhook.sign(payload, now());
+
+    let event = webhook.construct_event(payload, &signature).unwrap();
+    assert_eq!(event.event.id, "evt_3MtwBwLkdIwHu7ix28a3tqPa");
+    assert_eq!(event.event.type_, "charge.succeeded");
+    match event.object {
+        WebhookObject::Charge(charge) => {
+            assert_eq!(charge.id, "ch_3MtwBwLkdIwHu7ix28a3tqPa");
+            assert_eq!(charge.amount, 1099);
+        }
+        object => unreachable!("Expected a charge, got {:?}", object),
+    }
+    assert_eq!(event.previous_attributes, None);
+}
+
+#[test]
+fn test_webhook_keeps_previous_attributes() {
+    let payload = include_bytes!("fixtures/customer.updated.json");
+    let event = webhooks::parse_event(payload).unwrap();
+
+    assert!(matches!(event.object, WebhookObject::Customer(_)));
+    assert_eq!(
+        event.previous_attributes,
+        Some(serde_json::json!({ "email": "jenny@example.com" }))
+    );
+}
+
+#[test]
+fn test_webhook_accepts_any_v1_signature() {
+    let payload = include_bytes!("fixtures/charge.succeeded.json");
+    let timestamp = now();
+
+    // The endpoint signs with both secrets while its secret is being rolled.
+    let old = Webhook::new("whsec_old_secret").sign(payload, timestamp);
+    let new = Webhook::new(WEBHOOK_SECRET).sign(payload, timestamp);
+    let signature = format!(
+        "{},{},v0=6ffbb59b2300aae63f272406069a9788598b792a944a07aba816edb039989a39",
+        old,
+        new.split_once(',').unwrap().1
+    );
+
+    assert!(Webhook::new(WEBHOOK_SECRET)
+        .verify(payload, &signature)
+        .is_ok());
+}
+
+#[test]
+fn test_webhook_rejects_bad_signatures() {
+    let payload = include_bytes!("fixtures/charge.succeeded.json");
+    let webhook = Webhook::new(WEBHOOK_SECRET);
+
+    let signature = Webhook::new("whsec_other_secret").sign(payload, now());
+    assert!(matches!(
+        webhook.verify(payload, &signature),
+        Err(WebhookError::NoMatchingSignature)
+    ));
+
+    let signature = webhook.sign(b"{}", now());
+    assert!(matches!(
+        webhook.verify(payload, &signature),
+        Err(WebhookError::NoMatchingSignature)
+    ));
+
+    let signature = webhook.sign(payload, now());
+    let signature = signature.split_once(',').unwrap().1;
+    assert!(matches!(
+        webhook.verify(payload, signature),
+        Err(WebhookError::InvalidHeader)
+    ));
+}
+
+#[test]
+fn test_webhook_enforces_tolerance() {
+    let payload = include_bytes!("fixtures/charge.succeeded.json");
+    let mut webhook = Webhook::new(WEBHOOK_SECRET);
+
+    let signature = webhook.sign(payload, now() - 600);
+    assert!(matches!(
+        webhook.verify(payload, &signature),
+        Err(WebhookError::TimestampOutsideTolerance)
+    ));
+
+    let signature = webhook.sign(payload, now() + 600);
+    assert!(matches!(
+        webhook.verify(payload, &signature),
+        Err(WebhookError::TimestampOutsideTolerance)
+    ));
+
+    webhook.with_tolerance(Duration::from_secs(3600));
+    assert!(webhook.verify(payload, &signature).is_ok());
+}
@@ -0,0 +1,123 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`BookingData::get_booking_report`], set with a builder.
+pub struct BookingDataGetBookingReportRequest<'a> {
+    inner: crate::booking_data::BookingDataGetBookingReportRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> BookingDataGetBookingReportRequest<'a> {
+    /// Sets the `startDateFrom` query parameter.
+    pub fn start_date_from(mut self, start_date_from: &'a str) -> Self {
+        self.inner = self.inner.start_date_from(start_date_from);
+        self
+    }
+
+    /// Sets the `startDateTo` query parameter.
+    pub fn start_date_to(mut self, start_date_to: &'a str) -> Self {
+        self.inner = self.inner.start_date_to(start_date_to);
+        self
+    }
+
+    /// Sets the `bookingStatus` query parameter.
+    pub fn booking_status(mut self, booking_status: crate::types::BookingStatus) -> Self {
+        self.inner = self.inner.booking_status(booking_status);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: u64) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// Sets the `size` query parameter.
+    pub fn size(mut self, size: i64) -> Self {
+        self.inner = self.inner.size(size);
+        self
+    }
+
+    /// Sets the `bookingType` query parameter.
+    pub fn booking_type(mut self, booking_type: crate::types::BookingType) -> Self {
+        self.inner = self.inner.booking_type(booking_type);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::BookingReport>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::booking_data::BookingData`].
+pub struct BookingData {
+    inner: crate::booking_data::BookingData,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl BookingData {
+    pub(crate) fn new(
+        inner: crate::booking_data::BookingData,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        BookingData { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::booking_data::BookingData::get_booking_report`].
+    pub fn get_booking_report(
+        &self,
+        created_from: &str,
+        created_to: &str,
+        start_date_from: &str,
+        start_date_to: &str,
+        booking_status: crate::types::BookingStatus,
+        page: u64,
+        size: i64,
+        booking_type: crate::types::BookingType,
+    ) -> ClientResult<crate::Response<Vec<crate::types::BookingReport>>> {
+        self.runtime.block_on(self.inner.get_booking_report(
+            created_from,
+            created_to,
+            start_date_from,
+            start_date_to,
+            booking_status,
+            page,
+            size,
+            booking_type,
+        ))
+    }
+    /// The blocking version of [`crate::booking_data::BookingData::get_booking_report_request`].
+    pub fn get_booking_report_request<'a>(
+        &'a self,
+        created_from: &'a str,
+        created_to: &'a str,
+    ) -> BookingDataGetBookingReportRequest<'a> {
+        BookingDataGetBookingReportRequest {
+            inner: self
+                .inner
+                .get_booking_report_request(created_from, created_to),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::booking_data::BookingData::get_all_booking_report`].
+    pub fn get_all_booking_report(
+        &self,
+        created_from: &str,
+        created_to: &str,
+        start_date_from: &str,
+        start_date_to: &str,
+        booking_status: crate::types::BookingStatus,
+        booking_type: crate::types::BookingType,
+    ) -> ClientResult<crate::Response<Vec<crate::types::BookingReport>>> {
+        self.runtime.block_on(self.inner.get_all_booking_report(
+            created_from,
+            created_to,
+            start_date_from,
+            start_date_to,
+            booking_status,
+            booking_type,
+        ))
+    }
+}
@@ -1,6 +1,117 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`BookingData::get_booking_report`], set with a builder.
+pub struct BookingDataGetBookingReportRequest<'a> {
+    client: &'a BookingData,
+    created_from: &'a str,
+    created_to: &'a str,
+    start_date_from: Option<&'a str>,
+    start_date_to: Option<&'a str>,
+    booking_status: Option<crate::types::BookingStatus>,
+    page: Option<u64>,
+    size: Option<i64>,
+    booking_type: Option<crate::types::BookingType>,
+}
+
+impl<'a> BookingDataGetBookingReportRequest<'a> {
+    /// Sets the `startDateFrom` query parameter.
+    pub fn start_date_from(mut self, start_date_from: &'a str) -> Self {
+        self.start_date_from = Some(start_date_from);
+        self
+    }
+
+    /// Sets the `startDateTo` query parameter.
+    pub fn start_date_to(mut self, start_date_to: &'a str) -> Self {
+        self.start_date_to = Some(start_date_to);
+        self
+    }
+
+    /// Sets the `bookingStatus` query parameter.
+    pub fn booking_status(mut self, booking_status: crate::types::BookingStatus) -> Self {
+        self.booking_status = Some(booking_status);
+        self
+    }
+
+    /// Sets the `page` query parameter.
+    pub fn page(mut self, page: u64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sets the `size` query parameter.
+    pub fn size(mut self, size: i64) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Sets the `bookingType` query parameter.
+    pub fn booking_type(mut self, booking_type: crate::types::BookingType) -> Self {
+        self.booking_type = Some(booking_type);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::BookingReport>>> {
+        let Self {
+            client,
+            created_from,
+            created_to,
+            start_date_from,
+            start_date_to,
+            booking_status,
+            page,
+            size,
+            booking_type,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = booking_status {
+            query_args.push(("bookingStatus".to_string(), v.to_string()));
+        }
+        if let Some(v) = booking_type {
+            query_args.push(("bookingType".to_string(), v.to_string()));
+        }
+        if !created_from.is_empty() {
+            query_args.push(("createdFrom".to_string(), created_from.to_string()));
+        }
+        if !created_to.is_empty() {
+            query_args.push(("createdTo".to_string(), created_to.to_string()));
+        }
+        if let Some(v) = page {
+            query_args.push(("page".to_string(), v.to_string()));
+        }
+        if let Some(v) = size {
+            query_args.push(("size".to_string(), v.to_string()));
+        }
+        if let Some(v) = start_date_from {
+            query_args.push(("startDateFrom".to_string(), v.to_string()));
+        }
+        if let Some(v) = start_date_to {
+            query_args.push(("startDateTo".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(&format!("/v1/bookings?{}", query_), None);
+
+        let resp: crate::Response<crate::types::BookingReportResponse> = client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await?;
+
+        // Return our response data.
+        Ok(crate::Response::new(
+            resp.status,
+            resp.headers,
+            resp.body.data.to_vec(),
+        ))
+    }
+}
+
 pub struct BookingData {
     pub client: Client,
 }
@@ -85,6 +196,32 @@ impl BookingData {
             resp.body.data.to_vec(),
         ))
     }
+    /**
+     * Your company's bookings.
+     *
+     * This function performs a `GET` to the `/v1/bookings` endpoint.
+     *
+     * As opposed to `get_booking_report`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Return booking rows filtered by the parameters you select.
+     */
+    pub fn get_booking_report_request<'a>(
+        &'a self,
+        created_from: &'a str,
+        created_to: &'a str,
+    ) -> BookingDataGetBookingReportRequest<'a> {
+        BookingDataGetBookingReportRequest {
+            client: self,
+            created_from,
+            created_to,
+            start_date_from: None,
+            start_date_to: None,
+            booking_status: None,
+            page: None,
+            size: None,
+            booking_type: None,
+        }
+    }
     /**
      * Your company's bookings.
      *
@@ -124,76 +261,116 @@ impl BookingData {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/v1/bookings?{}", query_), None);
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let first = format!("{}{}page=0&size=100", url, sep);
+        let mut pages = crate::utils::Pages::new(self.client.page_limits);
+        pages.visit(&first)?;
 
-        let crate::Response::<crate::types::BookingReportResponse> {
-            mut status,
-            mut headers,
-            mut body,
-        } = if !url.contains('?') {
-            self.client
-                .get(
-                    &format!("{}?page=0&size=100", url),
-                    crate::Message {
-                        body: None,
-                        content_type: None,
-                    },
-                )
-                .await?
-        } else {
-            self.client
+        let mut response: crate::Response<crate::types::BookingReportResponse> = self
+            .client
+            .get(
+                &first,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await?;
+        let mut data = Vec::new();
+
+        // Paginate if we should.
+        loop {
+            let page = if response.body.page.current_page + 1 < response.body.page.total_pages {
+                Some(format!(
+                    "{}{}page={}&size=100",
+                    url,
+                    sep,
+                    response.body.page.current_page + 1
+                ))
+            } else {
+                None
+            };
+            let more = pages.keep(&mut response.body.data);
+            data.append(&mut response.body.data);
+
+            let next = match page {
+                Some(next) if more && pages.visit(&next)? => next,
+                _ => break,
+            };
+            response = self
+                .client
                 .get(
-                    &format!("{}&page=0&size=100", url),
+                    &next,
                     crate::Message {
                         body: None,
                         content_type: None,
                     },
                 )
-                .await?
-        };
-
-        let mut data = body.data;
-        let mut page = body.page.current_page + 1;
-
-        // Paginate if we should.
-        while page <= (body.page.total_pages - 1) {
-            if !url.contains('?') {
-                crate::Response::<crate::types::BookingReportResponse> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}?page={}&size=100", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            } else {
-                crate::Response::<crate::types::BookingReportResponse> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}&page={}&size=100", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
-
-            data.append(&mut body.data);
-
-            page = body.page.current_page + 1;
+                .await?;
         }
 
         // Return our response data.
-        Ok(crate::Response::new(status, headers, data))
+        Ok(crate::Response::new(
+            response.status,
+            response.headers,
+            data,
+        ))
+    }
+    /**
+     * Your company's bookings.
+     *
+     * This function performs a `GET` to the `/v1/bookings` endpoint.
+     *
+     * As opposed to `get_all_booking_report`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Return booking rows filtered by the parameters you select.
+     */
+    pub fn get_all_booking_report_stream(
+        &self,
+        created_from: &str,
+        created_to: &str,
+        start_date_from: &str,
+        start_date_to: &str,
+        booking_status: crate::types::BookingStatus,
+        booking_type: crate::types::BookingType,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::BookingReport>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !booking_status.to_string().is_empty() {
+            query_args.push(("bookingStatus".to_string(), booking_status.to_string()));
+        }
+        if !booking_type.to_string().is_empty() {
+            query_args.push(("bookingType".to_string(), booking_type.to_string()));
+        }
+        if !created_from.is_empty() {
+            query_args.push(("createdFrom".to_string(), created_from.to_string()));
+        }
+        if !created_to.is_empty() {
+            query_args.push(("createdTo".to_string(), created_to.to_string()));
+        }
+        if !start_date_from.is_empty() {
+            query_args.push(("startDateFrom".to_string(), start_date_from.to_string()));
+        }
+        if !start_date_to.is_empty() {
+            query_args.push(("startDateTo".to_string(), start_date_to.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/v1/bookings?{}", query_), None);
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let first = format!("{}{}page=0&size=100", url, sep);
+        self.client
+            .get_pages_stream(first, move |body: crate::types::BookingReportResponse| {
+                let page = if body.page.current_page + 1 < body.page.total_pages {
+                    Some(format!(
+                        "{}{}page={}&size=100",
+                        url,
+                        sep,
+                        body.page.current_page + 1
+                    ))
+                } else {
+                    None
+                };
+
+                (body.data, page)
+            })
     }
 }
@@ -11,8 +11,9 @@ pub enum TravelerType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for TravelerType {
@@ -21,7 +22,7 @@ impl std::fmt::Display for TravelerType {
             TravelerType::Guest => "GUEST",
             TravelerType::Passenger => "PASSENGER",
             TravelerType::Noop => "",
-            TravelerType::FallthroughString => "*",
+            TravelerType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -48,8 +49,9 @@ pub enum BookingStatus {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for BookingStatus {
@@ -61,7 +63,7 @@ impl std::fmt::Display for BookingStatus {
             BookingStatus::Ticketed => "TICKETED",
             BookingStatus::Voided => "VOIDED",
             BookingStatus::Noop => "",
-            BookingStatus::FallthroughString => "*",
+            BookingStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -90,8 +92,9 @@ pub enum BookingType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for BookingType {
@@ -104,7 +107,7 @@ impl std::fmt::Display for BookingType {
             BookingType::Rail => "RAIL",
             BookingType::Transportation => "TRANSPORTATION",
             BookingType::Noop => "",
-            BookingType::FallthroughString => "*",
+            BookingType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -124,13 +127,12 @@ pub enum PaymentSchedule {
     Now,
     #[serde(rename = "PARTIAL")]
     Partial,
-    #[serde(rename = "UNKNOWN")]
-    Unknown,
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PaymentSchedule {
@@ -139,9 +141,8 @@ impl std::fmt::Display for PaymentSchedule {
             PaymentSchedule::Later => "LATER",
             PaymentSchedule::Now => "NOW",
             PaymentSchedule::Partial => "PARTIAL",
-            PaymentSchedule::Unknown => "UNKNOWN",
             PaymentSchedule::Noop => "",
-            PaymentSchedule::FallthroughString => "*",
+            PaymentSchedule::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -162,8 +163,9 @@ pub enum PassengerStatus {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PassengerStatus {
@@ -172,7 +174,7 @@ impl std::fmt::Display for PassengerStatus {
             PassengerStatus::Disabled => "DISABLED",
             PassengerStatus::Enabled => "ENABLED",
             PassengerStatus::Noop => "",
-            PassengerStatus::FallthroughString => "*",
+            PassengerStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -211,6 +213,10 @@ pub struct Location {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub state: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -266,6 +272,10 @@ pub struct Segment {
         rename = "startTimestamp"
     )]
     pub start_timestamp: Option<serde_json::Value>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -348,6 +358,10 @@ pub struct Person {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub uuid: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -362,6 +376,10 @@ pub struct Passenger {
         rename = "travelerType"
     )]
     pub traveler_type: Option<TravelerType>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -378,6 +396,10 @@ pub struct Property {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub value: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -390,6 +412,10 @@ pub struct BookingReportResponse {
     pub data: Vec<BookingReport>,
     #[serde(default)]
     pub page: Page,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -422,6 +448,10 @@ pub struct Page {
         rename = "totalPages"
     )]
     pub total_pages: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -440,6 +470,10 @@ pub struct Cnr {
         rename = "publishedPrice"
     )]
     pub published_price: f64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -1021,4 +1055,8 @@ pub struct BookingReport {
         rename = "vipFee"
     )]
     pub vip_fee: f64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
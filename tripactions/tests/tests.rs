@@ -1 +1,82 @@
+use std::mem;
 
+use wiremock::{
+    matchers::{method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+use tripactions::{
+    types::{BookingStatus, BookingType},
+    Client, ClientError,
+};
+
+async fn mount_bookings_page(server: &MockServer, page: i64, current_page: i64, total_pages: i64) {
+    Mock::given(method("GET"))
+        .and(path("/v1/bookings"))
+        .and(query_param("bookingStatus", "TICKETED"))
+        .and(query_param("page", page.to_string()))
+        .and(query_param("size", "100"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "data": [{ "uuid": format!("booking-{}", page) }],
+            "page": {
+                "currentPage": current_page,
+                "pageSize": 100,
+                "totalElements": total_pages,
+                "totalPages": total_pages
+            }
+        })))
+        .mount(server)
+        .await;
+}
+
+async fn get_all_bookings(client: &Client) -> Result<Vec<String>, ClientError> {
+    client
+        .booking_data()
+        .get_all_booking_report(
+            "",
+            "",
+            "",
+            "",
+            BookingStatus::Ticketed,
+            BookingType::default(),
+        )
+        .await
+        .map(|response| {
+            response
+                .body
+                .into_iter()
+                .map(|booking| booking.uuid)
+                .collect()
+        })
+}
+
+#[tokio::test]
+async fn test_get_all_walks_page_numbers() {
+    let server = MockServer::start().await;
+
+    mount_bookings_page(&server, 0, 0, 3).await;
+    mount_bookings_page(&server, 1, 1, 3).await;
+    mount_bookings_page(&server, 2, 2, 3).await;
+
+    let mut client = Client::new("client_id", "client_secret", "token");
+    client.with_host_override(server.uri());
+    assert_eq!(
+        get_all_bookings(&client).await.unwrap(),
+        vec!["booking-0", "booking-1", "booking-2"]
+    );
+
+    // The second page claims to be the first one again.
+    server.reset().await;
+    mount_bookings_page(&server, 0, 0, 3).await;
+    mount_bookings_page(&server, 1, 0, 3).await;
+
+    match get_all_bookings(&client).await {
+        Err(ClientError::IncompletePages { page_token }) => {
+            assert!(page_token.ends_with("page=1&size=100"))
+        }
+        result => unreachable!("Expected incomplete pages, got {:?}", result),
+    }
+    assert_eq!(server.received_requests().await.unwrap().len(), 2);
+
+    mem::drop(server)
+}
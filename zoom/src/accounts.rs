@@ -1,6 +1,280 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Accounts::get`], set with a builder.
+pub struct AccountsGetRequest<'a> {
+    client: &'a Accounts,
+    page_size: Option<i64>,
+    page_number: Option<i64>,
+    next_page_token: Option<&'a str>,
+}
+
+impl<'a> AccountsGetRequest<'a> {
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Sets the `page_number` query parameter.
+    pub fn page_number(mut self, page_number: i64) -> Self {
+        self.page_number = Some(page_number);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.next_page_token = Some(next_page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::Domains>> {
+        let Self {
+            client,
+            page_size,
+            page_number,
+            next_page_token,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = next_page_token {
+            query_args.push(("next_page_token".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_number {
+            query_args.push(("page_number".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_size {
+            query_args.push(("page_size".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(&format!("/accounts?{}", query_), None);
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Accounts::setting`], set with a builder.
+pub struct AccountsSettingRequest<'a> {
+    client: &'a Accounts,
+    account_id: &'a str,
+    option: Option<crate::types::OptionData>,
+    custom_query_fields: Option<&'a str>,
+}
+
+impl<'a> AccountsSettingRequest<'a> {
+    /// Sets the `option` query parameter.
+    pub fn option(mut self, option: crate::types::OptionData) -> Self {
+        self.option = Some(option);
+        self
+    }
+
+    /// Sets the `custom_query_fields` query parameter.
+    pub fn custom_query_fields(mut self, custom_query_fields: &'a str) -> Self {
+        self.custom_query_fields = Some(custom_query_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::AccountSettingsResponseOneOf>> {
+        let Self {
+            client,
+            account_id,
+            option,
+            custom_query_fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = custom_query_fields {
+            query_args.push(("custom_query_fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = option {
+            query_args.push(("option".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/accounts/{}/settings?{}",
+                crate::progenitor_support::encode_path(account_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Accounts::settings_update`], set with a builder.
+pub struct AccountsSettingsUpdateRequest<'a> {
+    client: &'a Accounts,
+    account_id: &'a str,
+    option: Option<crate::types::AccountSettingsUpdateOption>,
+    body: &'a crate::types::AccountSettingsUpdateRequestOneOf,
+}
+
+impl<'a> AccountsSettingsUpdateRequest<'a> {
+    /// Sets the `option` query parameter.
+    pub fn option(mut self, option: crate::types::AccountSettingsUpdateOption) -> Self {
+        self.option = Some(option);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            account_id,
+            option,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = option {
+            query_args.push(("option".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/accounts/{}/settings?{}",
+                crate::progenitor_support::encode_path(account_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .patch(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Accounts::get_lock_setting`], set with a builder.
+pub struct AccountsGetLockSettingRequest<'a> {
+    client: &'a Accounts,
+    account_id: &'a str,
+    option: Option<&'a str>,
+    custom_query_fields: Option<&'a str>,
+}
+
+impl<'a> AccountsGetLockSettingRequest<'a> {
+    /// Sets the `option` query parameter.
+    pub fn option(mut self, option: &'a str) -> Self {
+        self.option = Some(option);
+        self
+    }
+
+    /// Sets the `custom_query_fields` query parameter.
+    pub fn custom_query_fields(mut self, custom_query_fields: &'a str) -> Self {
+        self.custom_query_fields = Some(custom_query_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::Domains>> {
+        let Self {
+            client,
+            account_id,
+            option,
+            custom_query_fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = custom_query_fields {
+            query_args.push(("custom_query_fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = option {
+            query_args.push(("option".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/accounts/{}/lock_settings?{}",
+                crate::progenitor_support::encode_path(account_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Accounts::del_vb`], set with a builder.
+pub struct AccountsDelVbRequest<'a> {
+    client: &'a Accounts,
+    account_id: &'a str,
+    file_ids: Option<&'a str>,
+}
+
+impl<'a> AccountsDelVbRequest<'a> {
+    /// Sets the `file_ids` query parameter.
+    pub fn file_ids(mut self, file_ids: &'a str) -> Self {
+        self.file_ids = Some(file_ids);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            account_id,
+            file_ids,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = file_ids {
+            query_args.push(("file_ids".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/accounts/{}/settings/virtual_backgrounds?{}",
+                crate::progenitor_support::encode_path(account_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .delete(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct Accounts {
     pub client: Client,
 }
@@ -63,6 +337,31 @@ impl Accounts {
             )
             .await
     }
+    /**
+     * List sub accounts.
+     *
+     * This function performs a `GET` to the `/accounts` endpoint.
+     *
+     * As opposed to `get`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * List all the sub accounts that have been created by a master account.<br><br>Zoom allows only [approved partners](https://marketplace.zoom.us/docs/api-reference/master-account-apis) to use master APIs and manage sub accounts. Email the partner programs team at **partner-success@zoom.us** for more details.
+     *
+     * <br>**Prerequisites:**<br>
+     * * Pro or a higher paid account with master account option enabled. <br>
+     *
+     * **Scope**: `account:read:admin`
+     * <br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`<br>
+     *
+     *
+     */
+    pub fn get_request(&self) -> AccountsGetRequest<'_> {
+        AccountsGetRequest {
+            client: self,
+            page_size: None,
+            page_number: None,
+            next_page_token: None,
+        }
+    }
     /**
      * Create a sub account.
      *
@@ -535,6 +834,30 @@ impl Accounts {
             )
             .await
     }
+    /**
+     * Get settings.
+     *
+     * This function performs a `GET` to the `/accounts/{accountId}/settings` endpoint.
+     *
+     * As opposed to `setting`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Use this API to get an account's settings information.
+     *
+     * To get the settings of a master account, use `me` as the value for the `accountId` path parameter.
+     *
+     * **Scopes:** `account:read:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     *
+     * **Prerequisites**:
+     * * The account must be a paid account
+     */
+    pub fn setting_request<'a>(&'a self, account_id: &'a str) -> AccountsSettingRequest<'a> {
+        AccountsSettingRequest {
+            client: self,
+            account_id,
+            option: None,
+            custom_query_fields: None,
+        }
+    }
     /**
      * Update settings.
      *
@@ -581,6 +904,32 @@ impl Accounts {
             )
             .await
     }
+    /**
+     * Update settings.
+     *
+     * This function performs a `PATCH` to the `/accounts/{accountId}/settings` endpoint.
+     *
+     * As opposed to `settings_update`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Update the settings of a sub account that is under a master account.<br> To update the settings of the master account, use `me` as the value of the `accountId` path parameter.<br><br>
+     * **Prerequisites**:
+     *  * The sub account must be a paid account.<br>
+     * **Scopes**: `account:write:admin`
+     * <br>
+     * **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     */
+    pub fn settings_update_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        body: &'a crate::types::AccountSettingsUpdateRequestOneOf,
+    ) -> AccountsSettingsUpdateRequest<'a> {
+        AccountsSettingsUpdateRequest {
+            client: self,
+            account_id,
+            option: None,
+            body,
+        }
+    }
     /**
      * Get managed domains.
      *
@@ -730,6 +1079,40 @@ impl Accounts {
             )
             .await
     }
+    /**
+     * Get locked settings.
+     *
+     * This function performs a `GET` to the `/accounts/{accountId}/lock_settings` endpoint.
+     *
+     * As opposed to `get_lock_setting`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * [Account Locked Settings](https://support.zoom.us/hc/en-us/articles/115005269866) allow you turn settings on or off for all users in your account. No user except the account admin or account owner can change these settings. With lock settings, you force the settings on for all users.
+     * Use this API to retrieve an account's locked settings.
+     *
+     * **Note:** This API can be used by Zoom Accounts that are on a Pro or a higher plan as well accounts that have master and sub accounts options enabled. <br><br>
+     * **Prerequisites:**
+     * * Pro or a higher paid account. <br>
+     *
+     * **Scope**: `account:read:admin`.
+     * <br> **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`<br>
+     *
+     *
+     *
+     *
+     *
+     * **Scope:** account:read:admin
+     */
+    pub fn get_lock_setting_request<'a>(
+        &'a self,
+        account_id: &'a str,
+    ) -> AccountsGetLockSettingRequest<'a> {
+        AccountsGetLockSettingRequest {
+            client: self,
+            account_id,
+            option: None,
+            custom_query_fields: None,
+        }
+    }
     /**
      * Update locked settings.
      *
@@ -900,4 +1283,27 @@ impl Accounts {
             )
             .await
     }
+    /**
+     * Delete virtual background files.
+     *
+     * This function performs a `DELETE` to the `/accounts/{accountId}/settings/virtual_backgrounds` endpoint.
+     *
+     * As opposed to `del_vb`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Delete existing virtual background file(s) from an account.
+     *
+     * **Prerequisites:**<br>
+     * * Virtual background feature must be [enabled](https://support.zoom.us/hc/en-us/articles/210707503-Virtual-Background#h_2ef28080-fce9-4ac2-b567-dc958afab1b7) on the account.
+     * <br> **Scope:** `account:write:admin`<br> <br>
+     * **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Light`<br>
+     *
+     *
+     */
+    pub fn del_vb_request<'a>(&'a self, account_id: &'a str) -> AccountsDelVbRequest<'a> {
+        AccountsDelVbRequest {
+            client: self,
+            account_id,
+            file_ids: None,
+        }
+    }
 }
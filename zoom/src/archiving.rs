@@ -1,6 +1,102 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Archiving::list_archived_files`], set with a builder.
+pub struct ArchivingListArchivedFilesRequest<'a> {
+    client: &'a Archiving,
+    page_size: Option<i64>,
+    next_page_token: Option<&'a str>,
+    from: Option<&'a str>,
+    to: Option<&'a str>,
+    query_date_type: Option<crate::types::ListArchivedFilesQueryDateType>,
+}
+
+impl<'a> ArchivingListArchivedFilesRequest<'a> {
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.next_page_token = Some(next_page_token);
+        self
+    }
+
+    /// Sets the `from` query parameter.
+    pub fn from(mut self, from: &'a str) -> Self {
+        self.from = Some(from);
+        self
+    }
+
+    /// Sets the `to` query parameter.
+    pub fn to(mut self, to: &'a str) -> Self {
+        self.to = Some(to);
+        self
+    }
+
+    /// Sets the `query_date_type` query parameter.
+    pub fn query_date_type(
+        mut self,
+        query_date_type: crate::types::ListArchivedFilesQueryDateType,
+    ) -> Self {
+        self.query_date_type = Some(query_date_type);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ListArchivedFilesResponseMeetings>>> {
+        let Self {
+            client,
+            page_size,
+            next_page_token,
+            from,
+            to,
+            query_date_type,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = from {
+            query_args.push(("from".to_string(), v.to_string()));
+        }
+        if let Some(v) = next_page_token {
+            query_args.push(("next_page_token".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_size {
+            query_args.push(("page_size".to_string(), v.to_string()));
+        }
+        if let Some(v) = query_date_type {
+            query_args.push(("query_date_type".to_string(), v.to_string()));
+        }
+        if let Some(v) = to {
+            query_args.push(("to".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/archive_files?{}", query_), None);
+        let resp: crate::Response<crate::types::ListArchivedFilesResponse> = client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await?;
+
+        // Return our response data.
+        Ok(crate::Response::new(
+            resp.status,
+            resp.headers,
+            resp.body.meetings.to_vec(),
+        ))
+    }
+}
+
 pub struct Archiving {
     pub client: Client,
 }
@@ -77,6 +173,32 @@ impl Archiving {
             resp.body.meetings.to_vec(),
         ))
     }
+    /**
+     * List archived files.
+     *
+     * This function performs a `GET` to the `/archive_files` endpoint.
+     *
+     * As opposed to `list_archived_files`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Zoom’s [archiving solution](https://support.zoom.us/hc/en-us/articles/360050431572-Archiving-Meeting-and-Webinar-data) allows account administrators to set up an automated mechanism to record, collect and archive meeting data to a 3rd party platform of their choice and hence, satisfy FINRA and/ or other compliance requirements.<br><br>
+     * Use this API to retrieve archived meeting or webinar files of an account.
+     *
+     * **Scope:** `recording:read:admin`<br>
+     * **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`<br><br>
+     * **Prerequisites:** <br>
+     * * Enable cloud recording.
+     * * Follow the [enablement process](https://support.zoom.us/hc/en-us/articles/360050431572-Archiving-Meeting-and-Webinar-data#h_01ENPBD3WR68D7FAKTBY92SG45) to access the archiving feature.
+     */
+    pub fn list_archived_files_request(&self) -> ArchivingListArchivedFilesRequest<'_> {
+        ArchivingListArchivedFilesRequest {
+            client: self,
+            page_size: None,
+            next_page_token: None,
+            from: None,
+            to: None,
+            query_date_type: None,
+        }
+    }
     /**
      * List archived files.
      *
@@ -111,11 +233,11 @@ impl Archiving {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/archive_files?{}", query_), None);
-        let crate::Response::<crate::types::ListArchivedFilesResponse> {
-            mut status,
-            mut headers,
-            mut body,
-        } = self
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let mut pages = crate::utils::Pages::new(self.client.page_limits);
+        pages.visit(&url)?;
+
+        let mut response: crate::Response<crate::types::ListArchivedFilesResponse> = self
             .client
             .get(
                 &url,
@@ -125,56 +247,92 @@ impl Archiving {
                 },
             )
             .await?;
-
-        let mut meetings = body.meetings;
-        let mut page = body.next_page_token;
+        let mut meetings = Vec::new();
 
         // Paginate if we should.
-        while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                crate::Response::<crate::types::ListArchivedFilesResponse> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}?next_page_token={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            } else {
-                crate::Response::<crate::types::ListArchivedFilesResponse> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}&next_page_token={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
-
-            meetings.append(&mut body.meetings);
-
-            if !body.next_page_token.is_empty() && body.next_page_token != page {
-                page = body.next_page_token.to_string();
+        loop {
+            let page = if response.body.next_page_token.is_empty() {
+                None
             } else {
-                page = "".to_string();
-            }
+                Some(format!(
+                    "{}{}next_page_token={}",
+                    url, sep, response.body.next_page_token
+                ))
+            };
+            let more = pages.keep(&mut response.body.meetings);
+            meetings.append(&mut response.body.meetings);
+
+            let next = match page {
+                Some(next) if more && pages.visit(&next)? => next,
+                _ => break,
+            };
+            response = self
+                .client
+                .get(
+                    &next,
+                    crate::Message {
+                        body: None,
+                        content_type: None,
+                    },
+                )
+                .await?;
         }
 
         // Return our response data.
-        Ok(crate::Response::new(status, headers, meetings))
+        Ok(crate::Response::new(
+            response.status,
+            response.headers,
+            meetings,
+        ))
+    }
+    /**
+     * List archived files.
+     *
+     * This function performs a `GET` to the `/archive_files` endpoint.
+     *
+     * As opposed to `list_all_archived_files`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Zoom’s [archiving solution](https://support.zoom.us/hc/en-us/articles/360050431572-Archiving-Meeting-and-Webinar-data) allows account administrators to set up an automated mechanism to record, collect and archive meeting data to a 3rd party platform of their choice and hence, satisfy FINRA and/ or other compliance requirements.<br><br>
+     * Use this API to retrieve archived meeting or webinar files of an account.
+     *
+     * **Scope:** `recording:read:admin`<br>
+     * **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`<br><br>
+     * **Prerequisites:** <br>
+     * * Enable cloud recording.
+     * * Follow the [enablement process](https://support.zoom.us/hc/en-us/articles/360050431572-Archiving-Meeting-and-Webinar-data#h_01ENPBD3WR68D7FAKTBY92SG45) to access the archiving feature.
+     */
+    pub fn list_all_archived_files_stream(
+        &self,
+        from: &str,
+        to: &str,
+        query_date_type: crate::types::ListArchivedFilesQueryDateType,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::ListArchivedFilesResponseMeetings>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !from.is_empty() {
+            query_args.push(("from".to_string(), from.to_string()));
+        }
+        if !query_date_type.to_string().is_empty() {
+            query_args.push(("query_date_type".to_string(), query_date_type.to_string()));
+        }
+        if !to.is_empty() {
+            query_args.push(("to".to_string(), to.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/archive_files?{}", query_), None);
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let next = format!("{}{}next_page_token=", url, sep);
+        self.client
+            .get_pages_stream(url, move |body: crate::types::ListArchivedFilesResponse| {
+                let page = if body.next_page_token.is_empty() {
+                    None
+                } else {
+                    Some(format!("{}{}", next, body.next_page_token))
+                };
+
+                (body.meetings, page)
+            })
     }
     /**
      * Get meeting archived files.
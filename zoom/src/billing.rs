@@ -1,6 +1,66 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Billing::account_invoice`], set with a builder.
+pub struct BillingAccountInvoiceRequest<'a> {
+    client: &'a Billing,
+    account_id: &'a str,
+    from: Option<chrono::NaiveDate>,
+    to: Option<chrono::NaiveDate>,
+}
+
+impl<'a> BillingAccountInvoiceRequest<'a> {
+    /// Sets the `from` query parameter.
+    pub fn from(mut self, from: chrono::NaiveDate) -> Self {
+        self.from = Some(from);
+        self
+    }
+
+    /// Sets the `to` query parameter.
+    pub fn to(mut self, to: chrono::NaiveDate) -> Self {
+        self.to = Some(to);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::AccountBillingInvoicesResponseData>> {
+        let Self {
+            client,
+            account_id,
+            from,
+            to,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = from {
+            query_args.push(("from".to_string(), v.to_string()));
+        }
+        if let Some(v) = to {
+            query_args.push(("to".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/accounts/{}/billing/invoices?{}",
+                crate::progenitor_support::encode_path(account_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct Billing {
     pub client: Client,
 }
@@ -454,6 +514,34 @@ impl Billing {
             )
             .await
     }
+    /**
+     * List billing invoices.
+     *
+     * This function performs a `GET` to the `/accounts/{accountId}/billing/invoices` endpoint.
+     *
+     * As opposed to `account_invoice`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * List [invoices](https://support.zoom.us/hc/en-us/articles/207276556-Viewing-your-invoice-history#h_6710542f-23cc-4059-9cc7-ff02bec7314e) of a Zoom account.
+     *
+     * To list a regular Zoom account's invoices or a master account's invoices, provide `me` as the value of the `accountId` path parameter. To list a sub account's invoices, provide the account ID of the sub account in the `accountId` path parameter.
+     *
+     * **Prerequisites:**
+     * * Account must be enrolled in Pro or a higher plan.<br>
+     *
+     * **Scope**:`billing:master`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Heavy`<br>
+     * **Additional Rate Limit:** You can make **one** API request per account(`accountId`) every **five** minutes until the daily limit is reached. This API has a daily limit of **6** requests per account(`accountId`).
+     */
+    pub fn account_invoice_request<'a>(
+        &'a self,
+        account_id: &'a str,
+    ) -> BillingAccountInvoiceRequest<'a> {
+        BillingAccountInvoiceRequest {
+            client: self,
+            account_id,
+            from: None,
+            to: None,
+        }
+    }
     /**
      * Get invoice details.
      *
@@ -0,0 +1,340 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Accounts::get`], set with a builder.
+pub struct AccountsGetRequest<'a> {
+    inner: crate::accounts::AccountsGetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> AccountsGetRequest<'a> {
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Sets the `page_number` query parameter.
+    pub fn page_number(mut self, page_number: i64) -> Self {
+        self.inner = self.inner.page_number(page_number);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.inner = self.inner.next_page_token(next_page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::Domains>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Accounts::setting`], set with a builder.
+pub struct AccountsSettingRequest<'a> {
+    inner: crate::accounts::AccountsSettingRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> AccountsSettingRequest<'a> {
+    /// Sets the `option` query parameter.
+    pub fn option(mut self, option: crate::types::OptionData) -> Self {
+        self.inner = self.inner.option(option);
+        self
+    }
+
+    /// Sets the `custom_query_fields` query parameter.
+    pub fn custom_query_fields(mut self, custom_query_fields: &'a str) -> Self {
+        self.inner = self.inner.custom_query_fields(custom_query_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::AccountSettingsResponseOneOf>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Accounts::settings_update`], set with a builder.
+pub struct AccountsSettingsUpdateRequest<'a> {
+    inner: crate::accounts::AccountsSettingsUpdateRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> AccountsSettingsUpdateRequest<'a> {
+    /// Sets the `option` query parameter.
+    pub fn option(mut self, option: crate::types::AccountSettingsUpdateOption) -> Self {
+        self.inner = self.inner.option(option);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Accounts::get_lock_setting`], set with a builder.
+pub struct AccountsGetLockSettingRequest<'a> {
+    inner: crate::accounts::AccountsGetLockSettingRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> AccountsGetLockSettingRequest<'a> {
+    /// Sets the `option` query parameter.
+    pub fn option(mut self, option: &'a str) -> Self {
+        self.inner = self.inner.option(option);
+        self
+    }
+
+    /// Sets the `custom_query_fields` query parameter.
+    pub fn custom_query_fields(mut self, custom_query_fields: &'a str) -> Self {
+        self.inner = self.inner.custom_query_fields(custom_query_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::Domains>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Accounts::del_vb`], set with a builder.
+pub struct AccountsDelVbRequest<'a> {
+    inner: crate::accounts::AccountsDelVbRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> AccountsDelVbRequest<'a> {
+    /// Sets the `file_ids` query parameter.
+    pub fn file_ids(mut self, file_ids: &'a str) -> Self {
+        self.inner = self.inner.file_ids(file_ids);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::accounts::Accounts`].
+pub struct Accounts {
+    inner: crate::accounts::Accounts,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Accounts {
+    pub(crate) fn new(
+        inner: crate::accounts::Accounts,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Accounts { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::accounts::Accounts::get`].
+    pub fn get(
+        &self,
+        page_size: i64,
+        page_number: i64,
+        next_page_token: &str,
+    ) -> ClientResult<crate::Response<crate::types::Domains>> {
+        self.runtime
+            .block_on(self.inner.get(page_size, page_number, next_page_token))
+    }
+    /// The blocking version of [`crate::accounts::Accounts::get_request`].
+    pub fn get_request(&self) -> AccountsGetRequest<'_> {
+        AccountsGetRequest {
+            inner: self.inner.get_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::accounts::Accounts::create`].
+    pub fn create(
+        &self,
+        body: &crate::types::AccountCreateRequest,
+    ) -> ClientResult<crate::Response<crate::types::AccountCreateResponse>> {
+        self.runtime.block_on(self.inner.create(body))
+    }
+    /// The blocking version of [`crate::accounts::Accounts::account`].
+    pub fn account(
+        &self,
+        account_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::AccountResponse>> {
+        self.runtime.block_on(self.inner.account(account_id))
+    }
+    /// The blocking version of [`crate::accounts::Accounts::disassociate`].
+    pub fn disassociate(&self, account_id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.disassociate(account_id))
+    }
+    /// The blocking version of [`crate::accounts::Accounts::options_update`].
+    pub fn options_update(
+        &self,
+        account_id: &str,
+        body: &crate::types::Options,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.options_update(account_id, body))
+    }
+    /// The blocking version of [`crate::accounts::Accounts::settings_domains`].
+    pub fn settings_domains(
+        &self,
+        account_id: &str,
+        option: crate::types::OptionData,
+        custom_query_fields: &str,
+    ) -> ClientResult<crate::Response<crate::types::Domains>> {
+        self.runtime.block_on(
+            self.inner
+                .settings_domains(account_id, option, custom_query_fields),
+        )
+    }
+    /// The blocking version of [`crate::accounts::Accounts::settings_security`].
+    pub fn settings_security(
+        &self,
+        account_id: &str,
+        option: crate::types::OptionData,
+        custom_query_fields: &str,
+    ) -> ClientResult<crate::Response<crate::types::Security>> {
+        self.runtime.block_on(
+            self.inner
+                .settings_security(account_id, option, custom_query_fields),
+        )
+    }
+    /// The blocking version of [`crate::accounts::Accounts::settings_account`].
+    pub fn settings_account(
+        &self,
+        account_id: &str,
+        option: crate::types::OptionData,
+        custom_query_fields: &str,
+    ) -> ClientResult<crate::Response<crate::types::AccountSettings>> {
+        self.runtime.block_on(
+            self.inner
+                .settings_account(account_id, option, custom_query_fields),
+        )
+    }
+    /// The blocking version of [`crate::accounts::Accounts::settings_meeting_security`].
+    pub fn settings_meeting_security(
+        &self,
+        account_id: &str,
+        option: crate::types::OptionData,
+        custom_query_fields: &str,
+    ) -> ClientResult<crate::Response<crate::types::MeetingSecuritySettings>> {
+        self.runtime.block_on(self.inner.settings_meeting_security(
+            account_id,
+            option,
+            custom_query_fields,
+        ))
+    }
+    /// The blocking version of [`crate::accounts::Accounts::setting`].
+    pub fn setting(
+        &self,
+        account_id: &str,
+        option: crate::types::OptionData,
+        custom_query_fields: &str,
+    ) -> ClientResult<crate::Response<crate::types::AccountSettingsResponseOneOf>> {
+        self.runtime
+            .block_on(self.inner.setting(account_id, option, custom_query_fields))
+    }
+    /// The blocking version of [`crate::accounts::Accounts::setting_request`].
+    pub fn setting_request<'a>(&'a self, account_id: &'a str) -> AccountsSettingRequest<'a> {
+        AccountsSettingRequest {
+            inner: self.inner.setting_request(account_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::accounts::Accounts::settings_update`].
+    pub fn settings_update(
+        &self,
+        account_id: &str,
+        option: crate::types::AccountSettingsUpdateOption,
+        body: &crate::types::AccountSettingsUpdateRequestOneOf,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.settings_update(account_id, option, body))
+    }
+    /// The blocking version of [`crate::accounts::Accounts::settings_update_request`].
+    pub fn settings_update_request<'a>(
+        &'a self,
+        account_id: &'a str,
+        body: &'a crate::types::AccountSettingsUpdateRequestOneOf,
+    ) -> AccountsSettingsUpdateRequest<'a> {
+        AccountsSettingsUpdateRequest {
+            inner: self.inner.settings_update_request(account_id, body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::accounts::Accounts::managed_domain`].
+    pub fn managed_domain(
+        &self,
+        account_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::DomainsList>> {
+        self.runtime.block_on(self.inner.managed_domain(account_id))
+    }
+    /// The blocking version of [`crate::accounts::Accounts::trusted_domain`].
+    pub fn trusted_domain(
+        &self,
+        account_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::AccountTrustedDomainResponse>> {
+        self.runtime.block_on(self.inner.trusted_domain(account_id))
+    }
+    /// The blocking version of [`crate::accounts::Accounts::get_lock_setting`].
+    pub fn get_lock_setting(
+        &self,
+        account_id: &str,
+        option: &str,
+        custom_query_fields: &str,
+    ) -> ClientResult<crate::Response<crate::types::Domains>> {
+        self.runtime.block_on(
+            self.inner
+                .get_lock_setting(account_id, option, custom_query_fields),
+        )
+    }
+    /// The blocking version of [`crate::accounts::Accounts::get_lock_setting_request`].
+    pub fn get_lock_setting_request<'a>(
+        &'a self,
+        account_id: &'a str,
+    ) -> AccountsGetLockSettingRequest<'a> {
+        AccountsGetLockSettingRequest {
+            inner: self.inner.get_lock_setting_request(account_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::accounts::Accounts::update_lock_settings`].
+    pub fn update_lock_settings(&self, account_id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.update_lock_settings(account_id))
+    }
+    /// The blocking version of [`crate::accounts::Accounts::update_owner`].
+    pub fn update_owner(
+        &self,
+        account_id: &str,
+        body: &crate::types::Members,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.update_owner(account_id, body))
+    }
+    /// The blocking version of [`crate::accounts::Accounts::upload_vb`].
+    pub fn upload_vb(
+        &self,
+        account_id: &str,
+        body: &crate::types::UploadVbRequest,
+    ) -> ClientResult<crate::Response<crate::types::Files>> {
+        self.runtime
+            .block_on(self.inner.upload_vb(account_id, body))
+    }
+    /// The blocking version of [`crate::accounts::Accounts::del_vb`].
+    pub fn del_vb(&self, account_id: &str, file_ids: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.del_vb(account_id, file_ids))
+    }
+    /// The blocking version of [`crate::accounts::Accounts::del_vb_request`].
+    pub fn del_vb_request<'a>(&'a self, account_id: &'a str) -> AccountsDelVbRequest<'a> {
+        AccountsDelVbRequest {
+            inner: self.inner.del_vb_request(account_id),
+            runtime: &self.runtime,
+        }
+    }
+}
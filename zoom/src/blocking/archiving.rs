@@ -0,0 +1,111 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Archiving::list_archived_files`], set with a builder.
+pub struct ArchivingListArchivedFilesRequest<'a> {
+    inner: crate::archiving::ArchivingListArchivedFilesRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ArchivingListArchivedFilesRequest<'a> {
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.inner = self.inner.next_page_token(next_page_token);
+        self
+    }
+
+    /// Sets the `from` query parameter.
+    pub fn from(mut self, from: &'a str) -> Self {
+        self.inner = self.inner.from(from);
+        self
+    }
+
+    /// Sets the `to` query parameter.
+    pub fn to(mut self, to: &'a str) -> Self {
+        self.inner = self.inner.to(to);
+        self
+    }
+
+    /// Sets the `query_date_type` query parameter.
+    pub fn query_date_type(
+        mut self,
+        query_date_type: crate::types::ListArchivedFilesQueryDateType,
+    ) -> Self {
+        self.inner = self.inner.query_date_type(query_date_type);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(
+        self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ListArchivedFilesResponseMeetings>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::archiving::Archiving`].
+pub struct Archiving {
+    inner: crate::archiving::Archiving,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Archiving {
+    pub(crate) fn new(
+        inner: crate::archiving::Archiving,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Archiving { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::archiving::Archiving::list_archived_files`].
+    pub fn list_archived_files(
+        &self,
+        page_size: i64,
+        next_page_token: &str,
+        from: &str,
+        to: &str,
+        query_date_type: crate::types::ListArchivedFilesQueryDateType,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ListArchivedFilesResponseMeetings>>> {
+        self.runtime.block_on(self.inner.list_archived_files(
+            page_size,
+            next_page_token,
+            from,
+            to,
+            query_date_type,
+        ))
+    }
+    /// The blocking version of [`crate::archiving::Archiving::list_archived_files_request`].
+    pub fn list_archived_files_request(&self) -> ArchivingListArchivedFilesRequest<'_> {
+        ArchivingListArchivedFilesRequest {
+            inner: self.inner.list_archived_files_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::archiving::Archiving::list_all_archived_files`].
+    pub fn list_all_archived_files(
+        &self,
+        from: &str,
+        to: &str,
+        query_date_type: crate::types::ListArchivedFilesQueryDateType,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ListArchivedFilesResponseMeetings>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_all_archived_files(from, to, query_date_type),
+        )
+    }
+    /// The blocking version of [`crate::archiving::Archiving::testget_record_archived_file`].
+    pub fn testget_record_archived_file(
+        &self,
+        meeting_uuid: &str,
+    ) -> ClientResult<crate::Response<crate::types::CloudArchivedFiles>> {
+        self.runtime
+            .block_on(self.inner.testget_record_archived_file(meeting_uuid))
+    }
+}
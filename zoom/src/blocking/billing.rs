@@ -0,0 +1,164 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Billing::account_invoice`], set with a builder.
+pub struct BillingAccountInvoiceRequest<'a> {
+    inner: crate::billing::BillingAccountInvoiceRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> BillingAccountInvoiceRequest<'a> {
+    /// Sets the `from` query parameter.
+    pub fn from(mut self, from: chrono::NaiveDate) -> Self {
+        self.inner = self.inner.from(from);
+        self
+    }
+
+    /// Sets the `to` query parameter.
+    pub fn to(mut self, to: chrono::NaiveDate) -> Self {
+        self.inner = self.inner.to(to);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::AccountBillingInvoicesResponseData>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::billing::Billing`].
+pub struct Billing {
+    inner: crate::billing::Billing,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Billing {
+    pub(crate) fn new(
+        inner: crate::billing::Billing,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Billing { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::billing::Billing::account`].
+    pub fn account(
+        &self,
+        account_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Contact>> {
+        self.runtime.block_on(self.inner.account(account_id))
+    }
+    /// The blocking version of [`crate::billing::Billing::account_update`].
+    pub fn account_update(
+        &self,
+        account_id: &str,
+        body: &crate::types::BillingContact,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.account_update(account_id, body))
+    }
+    /// The blocking version of [`crate::billing::Billing::account_plans`].
+    pub fn account_plans(
+        &self,
+        account_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::AccountPlansResponse>> {
+        self.runtime.block_on(self.inner.account_plans(account_id))
+    }
+    /// The blocking version of [`crate::billing::Billing::account_plan_create`].
+    pub fn account_plan_create(
+        &self,
+        account_id: &str,
+        body: &crate::types::AccountPlanCreateRequestAllOf,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.account_plan_create(account_id, body))
+    }
+    /// The blocking version of [`crate::billing::Billing::account_plan_base_update`].
+    pub fn account_plan_base_update(
+        &self,
+        account_id: &str,
+        body: &crate::types::PlanBase,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.account_plan_base_update(account_id, body))
+    }
+    /// The blocking version of [`crate::billing::Billing::account_plan_addon_update`].
+    pub fn account_plan_addon_update(
+        &self,
+        account_id: &str,
+        body: &crate::types::PlanBase,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.account_plan_addon_update(account_id, body))
+    }
+    /// The blocking version of [`crate::billing::Billing::account_plan_addon_create`].
+    pub fn account_plan_addon_create(
+        &self,
+        account_id: &str,
+        body: &crate::types::AccountPlanAddonCreateRequestOneOf,
+    ) -> ClientResult<crate::Response<crate::types::AccountPlans>> {
+        self.runtime
+            .block_on(self.inner.account_plan_addon_create(account_id, body))
+    }
+    /// The blocking version of [`crate::billing::Billing::account_plan_base_delete`].
+    pub fn account_plan_base_delete(
+        &self,
+        account_id: &str,
+        body: &crate::types::AccountPlanBaseDeleteRequest,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.account_plan_base_delete(account_id, body))
+    }
+    /// The blocking version of [`crate::billing::Billing::account_plan_addon_cancel`].
+    pub fn account_plan_addon_cancel(
+        &self,
+        account_id: &str,
+        body: &crate::types::AccountPlanAddonCancelRequest,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.account_plan_addon_cancel(account_id, body))
+    }
+    /// The blocking version of [`crate::billing::Billing::get_plan_usage`].
+    pub fn get_plan_usage(
+        &self,
+        account_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::GetPlanUsageResponse>> {
+        self.runtime.block_on(self.inner.get_plan_usage(account_id))
+    }
+    /// The blocking version of [`crate::billing::Billing::account_invoice`].
+    pub fn account_invoice(
+        &self,
+        account_id: &str,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> ClientResult<crate::Response<crate::types::AccountBillingInvoicesResponseData>> {
+        self.runtime
+            .block_on(self.inner.account_invoice(account_id, from, to))
+    }
+    /// The blocking version of [`crate::billing::Billing::account_invoice_request`].
+    pub fn account_invoice_request<'a>(
+        &'a self,
+        account_id: &'a str,
+    ) -> BillingAccountInvoiceRequest<'a> {
+        BillingAccountInvoiceRequest {
+            inner: self.inner.account_invoice_request(account_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::billing::Billing::get_account_invoice`].
+    pub fn get_account_invoice(
+        &self,
+        account_id: &str,
+        invoice_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::GetAccountBillingInvoiceResponse>> {
+        self.runtime
+            .block_on(self.inner.get_account_invoice(account_id, invoice_id))
+    }
+    /// The blocking version of [`crate::billing::Billing::download_invoice_pdf`].
+    pub fn download_invoice_pdf(&self, invoice_id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.download_invoice_pdf(invoice_id))
+    }
+}
@@ -0,0 +1,124 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`ChatChannels::get_channels`], set with a builder.
+pub struct ChatChannelsGetChannelsRequest<'a> {
+    inner: crate::chat_channels::ChatChannelsGetChannelsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ChatChannelsGetChannelsRequest<'a> {
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.inner = self.inner.next_page_token(next_page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Channels>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::chat_channels::ChatChannels`].
+pub struct ChatChannels {
+    inner: crate::chat_channels::ChatChannels,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl ChatChannels {
+    pub(crate) fn new(
+        inner: crate::chat_channels::ChatChannels,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        ChatChannels { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::chat_channels::ChatChannels::get_channels`].
+    pub fn get_channels(
+        &self,
+        user_id: &str,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Channels>>> {
+        self.runtime
+            .block_on(self.inner.get_channels(user_id, page_size, next_page_token))
+    }
+    /// The blocking version of [`crate::chat_channels::ChatChannels::get_channels_request`].
+    pub fn get_channels_request<'a>(
+        &'a self,
+        user_id: &'a str,
+    ) -> ChatChannelsGetChannelsRequest<'a> {
+        ChatChannelsGetChannelsRequest {
+            inner: self.inner.get_channels_request(user_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::chat_channels::ChatChannels::get_all_channels`].
+    pub fn get_all_channels(
+        &self,
+        user_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Channels>>> {
+        self.runtime.block_on(self.inner.get_all_channels(user_id))
+    }
+    /// The blocking version of [`crate::chat_channels::ChatChannels::create_channel`].
+    pub fn create_channel(
+        &self,
+        user_id: &str,
+        body: &crate::types::CreateChannelRequest,
+    ) -> ClientResult<crate::Response<crate::types::CreateChannelResponse>> {
+        self.runtime
+            .block_on(self.inner.create_channel(user_id, body))
+    }
+    /// The blocking version of [`crate::chat_channels::ChatChannels::get_user_level_channel`].
+    pub fn get_user_level_channel(
+        &self,
+        channel_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Channel>> {
+        self.runtime
+            .block_on(self.inner.get_user_level_channel(channel_id))
+    }
+    /// The blocking version of [`crate::chat_channels::ChatChannels::delete_user_level_channel`].
+    pub fn delete_user_level_channel(&self, channel_id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete_user_level_channel(channel_id))
+    }
+    /// The blocking version of [`crate::chat_channels::ChatChannels::update_user_level_channel`].
+    pub fn update_user_level_channel(
+        &self,
+        channel_id: &str,
+        body: &crate::types::Attendees,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.update_user_level_channel(channel_id, body))
+    }
+    /// The blocking version of [`crate::chat_channels::ChatChannels::remove_user_level_channel_member`].
+    pub fn remove_user_level_channel_member(
+        &self,
+        channel_id: &str,
+        member_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .remove_user_level_channel_member(channel_id, member_id),
+        )
+    }
+    /// The blocking version of [`crate::chat_channels::ChatChannels::join_channel`].
+    pub fn join_channel(
+        &self,
+        channel_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::JoinChannelResponse>> {
+        self.runtime.block_on(self.inner.join_channel(channel_id))
+    }
+    /// The blocking version of [`crate::chat_channels::ChatChannels::leave_channel`].
+    pub fn leave_channel(&self, channel_id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.leave_channel(channel_id))
+    }
+}
@@ -0,0 +1,132 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`ChatChannelsAccountLevel::list_channel_members`], set with a builder.
+pub struct ChatChannelsAccountLevelListChannelMembersRequest<'a> {
+    inner:
+        crate::chat_channels_account_level::ChatChannelsAccountLevelListChannelMembersRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ChatChannelsAccountLevelListChannelMembersRequest<'a> {
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.inner = self.inner.next_page_token(next_page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(
+        self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ListChannelMembersResponse>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::chat_channels_account_level::ChatChannelsAccountLevel`].
+pub struct ChatChannelsAccountLevel {
+    inner: crate::chat_channels_account_level::ChatChannelsAccountLevel,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl ChatChannelsAccountLevel {
+    pub(crate) fn new(
+        inner: crate::chat_channels_account_level::ChatChannelsAccountLevel,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        ChatChannelsAccountLevel { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::chat_channels_account_level::ChatChannelsAccountLevel::get_channel`].
+    pub fn get_channel(
+        &self,
+        user_id: &str,
+        channel_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Channel>> {
+        self.runtime
+            .block_on(self.inner.get_channel(user_id, channel_id))
+    }
+    /// The blocking version of [`crate::chat_channels_account_level::ChatChannelsAccountLevel::delete_channel`].
+    pub fn delete_channel(
+        &self,
+        user_id: &str,
+        channel_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete_channel(user_id, channel_id))
+    }
+    /// The blocking version of [`crate::chat_channels_account_level::ChatChannelsAccountLevel::update_channel`].
+    pub fn update_channel(
+        &self,
+        user_id: &str,
+        channel_id: &str,
+        body: &crate::types::Attendees,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.update_channel(user_id, channel_id, body))
+    }
+    /// The blocking version of [`crate::chat_channels_account_level::ChatChannelsAccountLevel::list_channel_members`].
+    pub fn list_channel_members(
+        &self,
+        user_id: &str,
+        channel_id: &str,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ListChannelMembersResponse>>> {
+        self.runtime.block_on(self.inner.list_channel_members(
+            user_id,
+            channel_id,
+            page_size,
+            next_page_token,
+        ))
+    }
+    /// The blocking version of [`crate::chat_channels_account_level::ChatChannelsAccountLevel::list_channel_members_request`].
+    pub fn list_channel_members_request<'a>(
+        &'a self,
+        user_id: &'a str,
+        channel_id: &'a str,
+    ) -> ChatChannelsAccountLevelListChannelMembersRequest<'a> {
+        ChatChannelsAccountLevelListChannelMembersRequest {
+            inner: self.inner.list_channel_members_request(user_id, channel_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::chat_channels_account_level::ChatChannelsAccountLevel::list_all_channel_members`].
+    pub fn list_all_channel_members(
+        &self,
+        user_id: &str,
+        channel_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ListChannelMembersResponse>>> {
+        self.runtime
+            .block_on(self.inner.list_all_channel_members(user_id, channel_id))
+    }
+    /// The blocking version of [`crate::chat_channels_account_level::ChatChannelsAccountLevel::invite_channel_members`].
+    pub fn invite_channel_members(
+        &self,
+        user_id: &str,
+        channel_id: &str,
+        body: &crate::types::InviteChannelMembersRequest,
+    ) -> ClientResult<crate::Response<crate::types::InviteChannelMembersResponse>> {
+        self.runtime
+            .block_on(self.inner.invite_channel_members(user_id, channel_id, body))
+    }
+    /// The blocking version of [`crate::chat_channels_account_level::ChatChannelsAccountLevel::remove_channel_member`].
+    pub fn remove_channel_member(
+        &self,
+        user_id: &str,
+        channel_id: &str,
+        member_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .remove_channel_member(user_id, channel_id, member_id),
+        )
+    }
+}
@@ -0,0 +1,252 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`ChatMessages::get_page`], set with a builder.
+pub struct ChatMessagesGetPageRequest<'a> {
+    inner: crate::chat_messages::ChatMessagesGetPageRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ChatMessagesGetPageRequest<'a> {
+    /// Sets the `to_contact` query parameter.
+    pub fn to_contact(mut self, to_contact: &'a str) -> Self {
+        self.inner = self.inner.to_contact(to_contact);
+        self
+    }
+
+    /// Sets the `to_channel` query parameter.
+    pub fn to_channel(mut self, to_channel: &'a str) -> Self {
+        self.inner = self.inner.to_channel(to_channel);
+        self
+    }
+
+    /// Sets the `date` query parameter.
+    pub fn date(mut self, date: chrono::NaiveDate) -> Self {
+        self.inner = self.inner.date(date);
+        self
+    }
+
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.inner = self.inner.next_page_token(next_page_token);
+        self
+    }
+
+    /// Sets the `include_deleted_and_edited_message` query parameter.
+    pub fn include_deleted_and_edited_message(
+        mut self,
+        include_deleted_and_edited_message: &'a str,
+    ) -> Self {
+        self.inner = self
+            .inner
+            .include_deleted_and_edited_message(include_deleted_and_edited_message);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Messages>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`ChatMessages::get`], set with a builder.
+pub struct ChatMessagesGetRequest<'a> {
+    inner: crate::chat_messages::ChatMessagesGetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ChatMessagesGetRequest<'a> {
+    /// Sets the `to_contact` query parameter.
+    pub fn to_contact(mut self, to_contact: &'a str) -> Self {
+        self.inner = self.inner.to_contact(to_contact);
+        self
+    }
+
+    /// Sets the `to_channel` query parameter.
+    pub fn to_channel(mut self, to_channel: &'a str) -> Self {
+        self.inner = self.inner.to_channel(to_channel);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::GetChatMessageResponse>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`ChatMessages::delete`], set with a builder.
+pub struct ChatMessagesDeleteRequest<'a> {
+    inner: crate::chat_messages::ChatMessagesDeleteRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ChatMessagesDeleteRequest<'a> {
+    /// Sets the `to_contact` query parameter.
+    pub fn to_contact(mut self, to_contact: &'a str) -> Self {
+        self.inner = self.inner.to_contact(to_contact);
+        self
+    }
+
+    /// Sets the `to_channel` query parameter.
+    pub fn to_channel(mut self, to_channel: &'a str) -> Self {
+        self.inner = self.inner.to_channel(to_channel);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::chat_messages::ChatMessages`].
+pub struct ChatMessages {
+    inner: crate::chat_messages::ChatMessages,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl ChatMessages {
+    pub(crate) fn new(
+        inner: crate::chat_messages::ChatMessages,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        ChatMessages { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::chat_messages::ChatMessages::get_page`].
+    pub fn get_page(
+        &self,
+        user_id: &str,
+        to_contact: &str,
+        to_channel: &str,
+        date: chrono::NaiveDate,
+        page_size: i64,
+        next_page_token: &str,
+        include_deleted_and_edited_message: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Messages>>> {
+        self.runtime.block_on(self.inner.get_page(
+            user_id,
+            to_contact,
+            to_channel,
+            date,
+            page_size,
+            next_page_token,
+            include_deleted_and_edited_message,
+        ))
+    }
+    /// The blocking version of [`crate::chat_messages::ChatMessages::get_page_request`].
+    pub fn get_page_request<'a>(&'a self, user_id: &'a str) -> ChatMessagesGetPageRequest<'a> {
+        ChatMessagesGetPageRequest {
+            inner: self.inner.get_page_request(user_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::chat_messages::ChatMessages::get_all`].
+    pub fn get_all(
+        &self,
+        user_id: &str,
+        to_contact: &str,
+        to_channel: &str,
+        date: chrono::NaiveDate,
+        include_deleted_and_edited_message: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Messages>>> {
+        self.runtime.block_on(self.inner.get_all(
+            user_id,
+            to_contact,
+            to_channel,
+            date,
+            include_deleted_and_edited_message,
+        ))
+    }
+    /// The blocking version of [`crate::chat_messages::ChatMessages::senda`].
+    pub fn senda(
+        &self,
+        user_id: &str,
+        body: &crate::types::SendaChatMessageRequest,
+    ) -> ClientResult<crate::Response<crate::types::Groups>> {
+        self.runtime.block_on(self.inner.senda(user_id, body))
+    }
+    /// The blocking version of [`crate::chat_messages::ChatMessages::mark_message`].
+    pub fn mark_message(
+        &self,
+        user_id: &str,
+        message_id: &str,
+        body: &crate::types::MarkMessageRequest,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.mark_message(user_id, message_id, body))
+    }
+    /// The blocking version of [`crate::chat_messages::ChatMessages::react_message`].
+    pub fn react_message(
+        &self,
+        user_id: &str,
+        message_id: &str,
+        body: &crate::types::ReactMessageRequest,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.react_message(user_id, message_id, body))
+    }
+    /// The blocking version of [`crate::chat_messages::ChatMessages::get`].
+    pub fn get(
+        &self,
+        user_id: &str,
+        message_id: &str,
+        to_contact: &str,
+        to_channel: &str,
+    ) -> ClientResult<crate::Response<crate::types::GetChatMessageResponse>> {
+        self.runtime
+            .block_on(self.inner.get(user_id, message_id, to_contact, to_channel))
+    }
+    /// The blocking version of [`crate::chat_messages::ChatMessages::get_request`].
+    pub fn get_request<'a>(
+        &'a self,
+        user_id: &'a str,
+        message_id: &'a str,
+    ) -> ChatMessagesGetRequest<'a> {
+        ChatMessagesGetRequest {
+            inner: self.inner.get_request(user_id, message_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::chat_messages::ChatMessages::edit_message`].
+    pub fn edit_message(
+        &self,
+        user_id: &str,
+        message_id: &str,
+        body: &crate::types::EditMessageRequest,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.edit_message(user_id, message_id, body))
+    }
+    /// The blocking version of [`crate::chat_messages::ChatMessages::delete`].
+    pub fn delete(
+        &self,
+        user_id: &str,
+        message_id: &str,
+        to_contact: &str,
+        to_channel: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .delete(user_id, message_id, to_contact, to_channel),
+        )
+    }
+    /// The blocking version of [`crate::chat_messages::ChatMessages::delete_request`].
+    pub fn delete_request<'a>(
+        &'a self,
+        user_id: &'a str,
+        message_id: &'a str,
+    ) -> ChatMessagesDeleteRequest<'a> {
+        ChatMessagesDeleteRequest {
+            inner: self.inner.delete_request(user_id, message_id),
+            runtime: &self.runtime,
+        }
+    }
+}
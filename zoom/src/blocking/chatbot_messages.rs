@@ -0,0 +1,42 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::chatbot_messages::ChatbotMessages`].
+pub struct ChatbotMessages {
+    inner: crate::chatbot_messages::ChatbotMessages,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl ChatbotMessages {
+    pub(crate) fn new(
+        inner: crate::chatbot_messages::ChatbotMessages,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        ChatbotMessages { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::chatbot_messages::ChatbotMessages::sendchatbot`].
+    pub fn sendchatbot(
+        &self,
+        body: &crate::types::SendchatbotRequest,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.sendchatbot(body))
+    }
+    /// The blocking version of [`crate::chatbot_messages::ChatbotMessages::edit`].
+    pub fn edit(
+        &self,
+        message_id: &str,
+        body: &crate::types::EditChatbotMessageRequest,
+    ) -> ClientResult<crate::Response<crate::types::EditChatbotMessageResponse>> {
+        self.runtime.block_on(self.inner.edit(message_id, body))
+    }
+    /// The blocking version of [`crate::chatbot_messages::ChatbotMessages::delete`].
+    pub fn delete(
+        &self,
+        message_id: &str,
+        body: &crate::types::DeleteChatbotMessageRequest,
+    ) -> ClientResult<crate::Response<crate::types::DeleteChatbotMessageResponse>> {
+        self.runtime.block_on(self.inner.delete(message_id, body))
+    }
+}
@@ -0,0 +1,455 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`CloudRecording::recordings_list`], set with a builder.
+pub struct CloudRecordingRecordingsListRequest<'a> {
+    inner: crate::cloud_recording::CloudRecordingRecordingsListRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> CloudRecordingRecordingsListRequest<'a> {
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.inner = self.inner.next_page_token(next_page_token);
+        self
+    }
+
+    /// Sets the `mc` query parameter.
+    pub fn mc(mut self, mc: &'a str) -> Self {
+        self.inner = self.inner.mc(mc);
+        self
+    }
+
+    /// Sets the `trash` query parameter.
+    pub fn trash(mut self, trash: bool) -> Self {
+        self.inner = self.inner.trash(trash);
+        self
+    }
+
+    /// Sets the `from` query parameter.
+    pub fn from(mut self, from: chrono::NaiveDate) -> Self {
+        self.inner = self.inner.from(from);
+        self
+    }
+
+    /// Sets the `to` query parameter.
+    pub fn to(mut self, to: chrono::NaiveDate) -> Self {
+        self.inner = self.inner.to(to);
+        self
+    }
+
+    /// Sets the `trash_type` query parameter.
+    pub fn trash_type(mut self, trash_type: &'a str) -> Self {
+        self.inner = self.inner.trash_type(trash_type);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::Domains>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`CloudRecording::recording_get`], set with a builder.
+pub struct CloudRecordingRecordingGetRequest<'a> {
+    inner: crate::cloud_recording::CloudRecordingRecordingGetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> CloudRecordingRecordingGetRequest<'a> {
+    /// Sets the `include_fields` query parameter.
+    pub fn include_fields(mut self, include_fields: &'a str) -> Self {
+        self.inner = self.inner.include_fields(include_fields);
+        self
+    }
+
+    /// Sets the `ttl` query parameter.
+    pub fn ttl(mut self, ttl: u64) -> Self {
+        self.inner = self.inner.ttl(ttl);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::RecordingGetResponseAllOf>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`CloudRecording::recording_delete`], set with a builder.
+pub struct CloudRecordingRecordingDeleteRequest<'a> {
+    inner: crate::cloud_recording::CloudRecordingRecordingDeleteRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> CloudRecordingRecordingDeleteRequest<'a> {
+    /// Sets the `action` query parameter.
+    pub fn action(mut self, action: crate::types::RecordingDeleteAction) -> Self {
+        self.inner = self.inner.action(action);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`CloudRecording::recording_delete_one`], set with a builder.
+pub struct CloudRecordingRecordingDeleteOneRequest<'a> {
+    inner: crate::cloud_recording::CloudRecordingRecordingDeleteOneRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> CloudRecordingRecordingDeleteOneRequest<'a> {
+    /// Sets the `action` query parameter.
+    pub fn action(mut self, action: crate::types::RecordingDeleteAction) -> Self {
+        self.inner = self.inner.action(action);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`CloudRecording::meeting_recording_registrant`], set with a builder.
+pub struct CloudRecordingMeetingRecordingRegistrantRequest<'a> {
+    inner: crate::cloud_recording::CloudRecordingMeetingRecordingRegistrantRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> CloudRecordingMeetingRecordingRegistrantRequest<'a> {
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: crate::types::MeetingRegistrantsStatus) -> Self {
+        self.inner = self.inner.status(status);
+        self
+    }
+
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Sets the `page_number` query parameter.
+    pub fn page_number(mut self, page_number: i64) -> Self {
+        self.inner = self.inner.page_number(page_number);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.inner = self.inner.next_page_token(next_page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::Domains>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`CloudRecording::get_account`], set with a builder.
+pub struct CloudRecordingGetAccountRequest<'a> {
+    inner: crate::cloud_recording::CloudRecordingGetAccountRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> CloudRecordingGetAccountRequest<'a> {
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.inner = self.inner.next_page_token(next_page_token);
+        self
+    }
+
+    /// Sets the `from` query parameter.
+    pub fn from(mut self, from: chrono::DateTime<chrono::Utc>) -> Self {
+        self.inner = self.inner.from(from);
+        self
+    }
+
+    /// Sets the `to` query parameter.
+    pub fn to(mut self, to: chrono::DateTime<chrono::Utc>) -> Self {
+        self.inner = self.inner.to(to);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(
+        self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GetAccountCloudRecordingResponseMeetings>>>
+    {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::cloud_recording::CloudRecording`].
+pub struct CloudRecording {
+    inner: crate::cloud_recording::CloudRecording,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl CloudRecording {
+    pub(crate) fn new(
+        inner: crate::cloud_recording::CloudRecording,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        CloudRecording { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::cloud_recording::CloudRecording::recordings_list`].
+    pub fn recordings_list(
+        &self,
+        user_id: &str,
+        page_size: i64,
+        next_page_token: &str,
+        mc: &str,
+        trash: bool,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        trash_type: &str,
+    ) -> ClientResult<crate::Response<crate::types::Domains>> {
+        self.runtime.block_on(self.inner.recordings_list(
+            user_id,
+            page_size,
+            next_page_token,
+            mc,
+            trash,
+            from,
+            to,
+            trash_type,
+        ))
+    }
+    /// The blocking version of [`crate::cloud_recording::CloudRecording::recordings_list_request`].
+    pub fn recordings_list_request<'a>(
+        &'a self,
+        user_id: &'a str,
+    ) -> CloudRecordingRecordingsListRequest<'a> {
+        CloudRecordingRecordingsListRequest {
+            inner: self.inner.recordings_list_request(user_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::cloud_recording::CloudRecording::recording_get`].
+    pub fn recording_get(
+        &self,
+        meeting_id: &str,
+        include_fields: &str,
+        ttl: u64,
+    ) -> ClientResult<crate::Response<crate::types::RecordingGetResponseAllOf>> {
+        self.runtime
+            .block_on(self.inner.recording_get(meeting_id, include_fields, ttl))
+    }
+    /// The blocking version of [`crate::cloud_recording::CloudRecording::recording_get_request`].
+    pub fn recording_get_request<'a>(
+        &'a self,
+        meeting_id: &'a str,
+    ) -> CloudRecordingRecordingGetRequest<'a> {
+        CloudRecordingRecordingGetRequest {
+            inner: self.inner.recording_get_request(meeting_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::cloud_recording::CloudRecording::recording_delete`].
+    pub fn recording_delete(
+        &self,
+        meeting_id: &str,
+        action: crate::types::RecordingDeleteAction,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.recording_delete(meeting_id, action))
+    }
+    /// The blocking version of [`crate::cloud_recording::CloudRecording::recording_delete_request`].
+    pub fn recording_delete_request<'a>(
+        &'a self,
+        meeting_id: &'a str,
+    ) -> CloudRecordingRecordingDeleteRequest<'a> {
+        CloudRecordingRecordingDeleteRequest {
+            inner: self.inner.recording_delete_request(meeting_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::cloud_recording::CloudRecording::recording_delete_one`].
+    pub fn recording_delete_one(
+        &self,
+        meeting_id: &str,
+        recording_id: &str,
+        action: crate::types::RecordingDeleteAction,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .recording_delete_one(meeting_id, recording_id, action),
+        )
+    }
+    /// The blocking version of [`crate::cloud_recording::CloudRecording::recording_delete_one_request`].
+    pub fn recording_delete_one_request<'a>(
+        &'a self,
+        meeting_id: &'a str,
+        recording_id: &'a str,
+    ) -> CloudRecordingRecordingDeleteOneRequest<'a> {
+        CloudRecordingRecordingDeleteOneRequest {
+            inner: self
+                .inner
+                .recording_delete_one_request(meeting_id, recording_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::cloud_recording::CloudRecording::recording_status_update`].
+    pub fn recording_status_update(
+        &self,
+        meeting_id: &str,
+        body: &crate::types::RecordingStatusUpdateBodyRequest,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.recording_status_update(meeting_id, body))
+    }
+    /// The blocking version of [`crate::cloud_recording::CloudRecording::recording_status_update_one`].
+    pub fn recording_status_update_one(
+        &self,
+        meeting_id: &str,
+        recording_id: &str,
+        body: &crate::types::RecordingStatusUpdateBodyRequest,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(
+                self.inner
+                    .recording_status_update_one(meeting_id, recording_id, body),
+            )
+    }
+    /// The blocking version of [`crate::cloud_recording::CloudRecording::recording_setting_update`].
+    pub fn recording_setting_update(
+        &self,
+        meeting_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::RecordingSettings>> {
+        self.runtime
+            .block_on(self.inner.recording_setting_update(meeting_id))
+    }
+    /// The blocking version of [`crate::cloud_recording::CloudRecording::recording_settings_update`].
+    pub fn recording_settings_update(
+        &self,
+        meeting_id: &str,
+        body: &crate::types::RecordingSettings,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.recording_settings_update(meeting_id, body))
+    }
+    /// The blocking version of [`crate::cloud_recording::CloudRecording::meeting_recording_registrant`].
+    pub fn meeting_recording_registrant(
+        &self,
+        meeting_id: i64,
+        status: crate::types::MeetingRegistrantsStatus,
+        page_size: i64,
+        page_number: i64,
+        next_page_token: &str,
+    ) -> ClientResult<crate::Response<crate::types::Domains>> {
+        self.runtime
+            .block_on(self.inner.meeting_recording_registrant(
+                meeting_id,
+                status,
+                page_size,
+                page_number,
+                next_page_token,
+            ))
+    }
+    /// The blocking version of [`crate::cloud_recording::CloudRecording::meeting_recording_registrant_request`].
+    pub fn meeting_recording_registrant_request(
+        &self,
+        meeting_id: i64,
+    ) -> CloudRecordingMeetingRecordingRegistrantRequest<'_> {
+        CloudRecordingMeetingRecordingRegistrantRequest {
+            inner: self.inner.meeting_recording_registrant_request(meeting_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::cloud_recording::CloudRecording::meeting_recording_registrant_create`].
+    pub fn meeting_recording_registrant_create(
+        &self,
+        meeting_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::MeetingRecordingRegistrantCreateResponse>> {
+        self.runtime
+            .block_on(self.inner.meeting_recording_registrant_create(meeting_id))
+    }
+    /// The blocking version of [`crate::cloud_recording::CloudRecording::meeting_recording_registrant_status`].
+    pub fn meeting_recording_registrant_status(
+        &self,
+        meeting_id: i64,
+        body: &crate::types::RecordingRegistrantStatus,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .meeting_recording_registrant_status(meeting_id, body),
+        )
+    }
+    /// The blocking version of [`crate::cloud_recording::CloudRecording::recording_registrants_questions_get`].
+    pub fn recording_registrants_questions_get(
+        &self,
+        meeting_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::RecordingRegistrantQuestionsData>> {
+        self.runtime
+            .block_on(self.inner.recording_registrants_questions_get(meeting_id))
+    }
+    /// The blocking version of [`crate::cloud_recording::CloudRecording::recording_registrant_question_update`].
+    pub fn recording_registrant_question_update(
+        &self,
+        meeting_id: &str,
+        body: &crate::types::RecordingRegistrantQuestionsData,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .recording_registrant_question_update(meeting_id, body),
+        )
+    }
+    /// The blocking version of [`crate::cloud_recording::CloudRecording::get_account`].
+    pub fn get_account(
+        &self,
+        account_id: &str,
+        page_size: i64,
+        next_page_token: &str,
+        from: Option<chrono::DateTime<chrono::Utc>>,
+        to: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GetAccountCloudRecordingResponseMeetings>>>
+    {
+        self.runtime.block_on(self.inner.get_account(
+            account_id,
+            page_size,
+            next_page_token,
+            from,
+            to,
+        ))
+    }
+    /// The blocking version of [`crate::cloud_recording::CloudRecording::get_account_request`].
+    pub fn get_account_request<'a>(
+        &'a self,
+        account_id: &'a str,
+    ) -> CloudRecordingGetAccountRequest<'a> {
+        CloudRecordingGetAccountRequest {
+            inner: self.inner.get_account_request(account_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::cloud_recording::CloudRecording::get_all_account`].
+    pub fn get_all_account(
+        &self,
+        account_id: &str,
+        from: Option<chrono::DateTime<chrono::Utc>>,
+        to: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GetAccountCloudRecordingResponseMeetings>>>
+    {
+        self.runtime
+            .block_on(self.inner.get_all_account(account_id, from, to))
+    }
+}
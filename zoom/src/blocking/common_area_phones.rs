@@ -0,0 +1,135 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`CommonAreaPhones::list`], set with a builder.
+pub struct CommonAreaPhonesListRequest<'a> {
+    inner: crate::common_area_phones::CommonAreaPhonesListRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> CommonAreaPhonesListRequest<'a> {
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.inner = self.inner.next_page_token(next_page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::CommonAreaPhones>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::common_area_phones::CommonAreaPhones`].
+pub struct CommonAreaPhones {
+    inner: crate::common_area_phones::CommonAreaPhones,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl CommonAreaPhones {
+    pub(crate) fn new(
+        inner: crate::common_area_phones::CommonAreaPhones,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        CommonAreaPhones { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::common_area_phones::CommonAreaPhones::list`].
+    pub fn list(
+        &self,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::CommonAreaPhones>>> {
+        self.runtime
+            .block_on(self.inner.list(page_size, next_page_token))
+    }
+    /// The blocking version of [`crate::common_area_phones::CommonAreaPhones::list_request`].
+    pub fn list_request(&self) -> CommonAreaPhonesListRequest<'_> {
+        CommonAreaPhonesListRequest {
+            inner: self.inner.list_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::common_area_phones::CommonAreaPhones::list_all`].
+    pub fn list_all(&self) -> ClientResult<crate::Response<Vec<crate::types::CommonAreaPhones>>> {
+        self.runtime.block_on(self.inner.list_all())
+    }
+    /// The blocking version of [`crate::common_area_phones::CommonAreaPhones::add`].
+    pub fn add(
+        &self,
+        body: &crate::types::AddCommonAreaPhoneRequest,
+    ) -> ClientResult<crate::Response<crate::types::AddCommonAreaPhoneResponse>> {
+        self.runtime.block_on(self.inner.add(body))
+    }
+    /// The blocking version of [`crate::common_area_phones::CommonAreaPhones::get`].
+    pub fn get(
+        &self,
+        common_area_phone_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::GetCommonAreaPhoneResponse>> {
+        self.runtime.block_on(self.inner.get(common_area_phone_id))
+    }
+    /// The blocking version of [`crate::common_area_phones::CommonAreaPhones::delete`].
+    pub fn delete(&self, common_area_phone_id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete(common_area_phone_id))
+    }
+    /// The blocking version of [`crate::common_area_phones::CommonAreaPhones::update`].
+    pub fn update(
+        &self,
+        common_area_phone_id: &str,
+        body: &crate::types::UpdateCommonAreaPhoneRequest,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.update(common_area_phone_id, body))
+    }
+    /// The blocking version of [`crate::common_area_phones::CommonAreaPhones::assign_phone_numbers_common_area`].
+    pub fn assign_phone_numbers_common_area(
+        &self,
+        common_area_phone_id: &str,
+        body: &crate::types::AssignPhoneNumbersCommonAreaRequest,
+    ) -> ClientResult<crate::Response<crate::types::AssignPhoneNumbersCommonAreaResponseData>> {
+        self.runtime.block_on(
+            self.inner
+                .assign_phone_numbers_common_area(common_area_phone_id, body),
+        )
+    }
+    /// The blocking version of [`crate::common_area_phones::CommonAreaPhones::unassign_phone_numbers_from_common_area`].
+    pub fn unassign_phone_numbers_from_common_area(
+        &self,
+        common_area_phone_id: &str,
+        phone_number_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .unassign_phone_numbers_from_common_area(common_area_phone_id, phone_number_id),
+        )
+    }
+    /// The blocking version of [`crate::common_area_phones::CommonAreaPhones::assign_calling_plans`].
+    pub fn assign_calling_plans(
+        &self,
+        common_area_phone_id: &str,
+        body: &crate::types::AssignCallingPlansCommonAreaPhoneRequestData,
+    ) -> ClientResult<crate::Response<crate::types::AssignCallingPlansCommonAreaPhoneResponseData>>
+    {
+        self.runtime
+            .block_on(self.inner.assign_calling_plans(common_area_phone_id, body))
+    }
+    /// The blocking version of [`crate::common_area_phones::CommonAreaPhones::unassign_calling_plans_from`].
+    pub fn unassign_calling_plans_from(
+        &self,
+        common_area_phone_id: &str,
+        type_: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .unassign_calling_plans_from(common_area_phone_id, type_),
+        )
+    }
+}
@@ -0,0 +1,184 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Contacts::search_company`], set with a builder.
+pub struct ContactsSearchCompanyRequest<'a> {
+    inner: crate::contacts::ContactsSearchCompanyRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ContactsSearchCompanyRequest<'a> {
+    /// Sets the `search_key` query parameter.
+    pub fn search_key(mut self, search_key: &'a str) -> Self {
+        self.inner = self.inner.search_key(search_key);
+        self
+    }
+
+    /// Sets the `query_presence_status` query parameter.
+    pub fn query_presence_status(mut self, query_presence_status: &'a str) -> Self {
+        self.inner = self.inner.query_presence_status(query_presence_status);
+        self
+    }
+
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.inner = self.inner.next_page_token(next_page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Contacts>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Contacts::get_user`], set with a builder.
+pub struct ContactsGetUserRequest<'a> {
+    inner: crate::contacts::ContactsGetUserRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ContactsGetUserRequest<'a> {
+    /// Sets the `type` query parameter.
+    pub fn type_(mut self, type_: &'a str) -> Self {
+        self.inner = self.inner.type_(type_);
+        self
+    }
+
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.inner = self.inner.next_page_token(next_page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::GetUserContactsResponse>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Contacts::get_user_contacts`], set with a builder.
+pub struct ContactsGetUserContactsRequest<'a> {
+    inner: crate::contacts::ContactsGetUserContactsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ContactsGetUserContactsRequest<'a> {
+    /// Sets the `query_presence_status` query parameter.
+    pub fn query_presence_status(mut self, query_presence_status: bool) -> Self {
+        self.inner = self.inner.query_presence_status(query_presence_status);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::GetUserContactResponse>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::contacts::Contacts`].
+pub struct Contacts {
+    inner: crate::contacts::Contacts,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Contacts {
+    pub(crate) fn new(
+        inner: crate::contacts::Contacts,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Contacts { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::contacts::Contacts::search_company`].
+    pub fn search_company(
+        &self,
+        search_key: &str,
+        query_presence_status: &str,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Contacts>>> {
+        self.runtime.block_on(self.inner.search_company(
+            search_key,
+            query_presence_status,
+            page_size,
+            next_page_token,
+        ))
+    }
+    /// The blocking version of [`crate::contacts::Contacts::search_company_request`].
+    pub fn search_company_request(&self) -> ContactsSearchCompanyRequest<'_> {
+        ContactsSearchCompanyRequest {
+            inner: self.inner.search_company_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::contacts::Contacts::get_all_search_company`].
+    pub fn get_all_search_company(
+        &self,
+        search_key: &str,
+        query_presence_status: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Contacts>>> {
+        self.runtime.block_on(
+            self.inner
+                .get_all_search_company(search_key, query_presence_status),
+        )
+    }
+    /// The blocking version of [`crate::contacts::Contacts::get_user`].
+    pub fn get_user(
+        &self,
+        type_: &str,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GetUserContactsResponse>>> {
+        self.runtime
+            .block_on(self.inner.get_user(type_, page_size, next_page_token))
+    }
+    /// The blocking version of [`crate::contacts::Contacts::get_user_request`].
+    pub fn get_user_request(&self) -> ContactsGetUserRequest<'_> {
+        ContactsGetUserRequest {
+            inner: self.inner.get_user_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::contacts::Contacts::get_all_user`].
+    pub fn get_all_user(
+        &self,
+        type_: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GetUserContactsResponse>>> {
+        self.runtime.block_on(self.inner.get_all_user(type_))
+    }
+    /// The blocking version of [`crate::contacts::Contacts::get_user_contacts`].
+    pub fn get_user_contacts(
+        &self,
+        contact_id: &str,
+        query_presence_status: bool,
+    ) -> ClientResult<crate::Response<crate::types::GetUserContactResponse>> {
+        self.runtime.block_on(
+            self.inner
+                .get_user_contacts(contact_id, query_presence_status),
+        )
+    }
+    /// The blocking version of [`crate::contacts::Contacts::get_user_contacts_request`].
+    pub fn get_user_contacts_request<'a>(
+        &'a self,
+        contact_id: &'a str,
+    ) -> ContactsGetUserContactsRequest<'a> {
+        ContactsGetUserContactsRequest {
+            inner: self.inner.get_user_contacts_request(contact_id),
+            runtime: &self.runtime,
+        }
+    }
+}
@@ -0,0 +1,1381 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Dashboards::meeting`], set with a builder.
+pub struct DashboardsMeetingRequest<'a> {
+    inner: crate::dashboards::DashboardsMeetingRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> DashboardsMeetingRequest<'a> {
+    /// Sets the `type` query parameter.
+    pub fn type_(mut self, type_: crate::types::DashboardMeetingsType) -> Self {
+        self.inner = self.inner.type_(type_);
+        self
+    }
+
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.inner = self.inner.next_page_token(next_page_token);
+        self
+    }
+
+    /// Sets the `include_fields` query parameter.
+    pub fn include_fields(mut self, include_fields: crate::types::IncludeFields) -> Self {
+        self.inner = self.inner.include_fields(include_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::DashboardMeetingsResponseAllOf>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Dashboards::meeting_detail`], set with a builder.
+pub struct DashboardsMeetingDetailRequest<'a> {
+    inner: crate::dashboards::DashboardsMeetingDetailRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> DashboardsMeetingDetailRequest<'a> {
+    /// Sets the `type` query parameter.
+    pub fn type_(mut self, type_: crate::types::DashboardMeetingsType) -> Self {
+        self.inner = self.inner.type_(type_);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::MeetingMetric>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Dashboards::meeting_participant`], set with a builder.
+pub struct DashboardsMeetingParticipantRequest<'a> {
+    inner: crate::dashboards::DashboardsMeetingParticipantRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> DashboardsMeetingParticipantRequest<'a> {
+    /// Sets the `type` query parameter.
+    pub fn type_(mut self, type_: crate::types::DashboardMeetingsType) -> Self {
+        self.inner = self.inner.type_(type_);
+        self
+    }
+
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.inner = self.inner.next_page_token(next_page_token);
+        self
+    }
+
+    /// Sets the `include_fields` query parameter.
+    pub fn include_fields(
+        mut self,
+        include_fields: crate::types::DashboardMeetingParticipantsIncludeFields,
+    ) -> Self {
+        self.inner = self.inner.include_fields(include_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::DashboardMeetingParticipantsResponseAllOf>>
+    {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Dashboards::meeting_participant_qo`], set with a builder.
+pub struct DashboardsMeetingParticipantQoRequest<'a> {
+    inner: crate::dashboards::DashboardsMeetingParticipantQoRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> DashboardsMeetingParticipantQoRequest<'a> {
+    /// Sets the `type` query parameter.
+    pub fn type_(mut self, type_: crate::types::DashboardMeetingsType) -> Self {
+        self.inner = self.inner.type_(type_);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::ParticipantQos>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Dashboards::meeting_participants_qo`], set with a builder.
+pub struct DashboardsMeetingParticipantsQoRequest<'a> {
+    inner: crate::dashboards::DashboardsMeetingParticipantsQoRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> DashboardsMeetingParticipantsQoRequest<'a> {
+    /// Sets the `type` query parameter.
+    pub fn type_(mut self, type_: crate::types::DashboardMeetingsType) -> Self {
+        self.inner = self.inner.type_(type_);
+        self
+    }
+
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.inner = self.inner.next_page_token(next_page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::Domains>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Dashboards::meeting_participant_share`], set with a builder.
+pub struct DashboardsMeetingParticipantShareRequest<'a> {
+    inner: crate::dashboards::DashboardsMeetingParticipantShareRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> DashboardsMeetingParticipantShareRequest<'a> {
+    /// Sets the `type` query parameter.
+    pub fn type_(mut self, type_: crate::types::DashboardMeetingsType) -> Self {
+        self.inner = self.inner.type_(type_);
+        self
+    }
+
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.inner = self.inner.next_page_token(next_page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::DashboardMeetingParticipantShareResponseAllOf>>
+    {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Dashboards::webinar`], set with a builder.
+pub struct DashboardsWebinarRequest<'a> {
+    inner: crate::dashboards::DashboardsWebinarRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> DashboardsWebinarRequest<'a> {
+    /// Sets the `type` query parameter.
+    pub fn type_(mut self, type_: crate::types::DashboardWebinarsType) -> Self {
+        self.inner = self.inner.type_(type_);
+        self
+    }
+
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.inner = self.inner.next_page_token(next_page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::DashboardWebinarsResponseAllOf>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Dashboards::webinar_detail`], set with a builder.
+pub struct DashboardsWebinarDetailRequest<'a> {
+    inner: crate::dashboards::DashboardsWebinarDetailRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> DashboardsWebinarDetailRequest<'a> {
+    /// Sets the `type` query parameter.
+    pub fn type_(mut self, type_: crate::types::DashboardWebinarsType) -> Self {
+        self.inner = self.inner.type_(type_);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::Webinars>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Dashboards::webinar_participant`], set with a builder.
+pub struct DashboardsWebinarParticipantRequest<'a> {
+    inner: crate::dashboards::DashboardsWebinarParticipantRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> DashboardsWebinarParticipantRequest<'a> {
+    /// Sets the `type` query parameter.
+    pub fn type_(mut self, type_: crate::types::DashboardWebinarsType) -> Self {
+        self.inner = self.inner.type_(type_);
+        self
+    }
+
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.inner = self.inner.next_page_token(next_page_token);
+        self
+    }
+
+    /// Sets the `include_fields` query parameter.
+    pub fn include_fields(
+        mut self,
+        include_fields: crate::types::DashboardMeetingParticipantsIncludeFields,
+    ) -> Self {
+        self.inner = self.inner.include_fields(include_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::DashboardWebinarParticipantsResponseAllOf>>
+    {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Dashboards::webinar_participant_qo`], set with a builder.
+pub struct DashboardsWebinarParticipantQoRequest<'a> {
+    inner: crate::dashboards::DashboardsWebinarParticipantQoRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> DashboardsWebinarParticipantQoRequest<'a> {
+    /// Sets the `type` query parameter.
+    pub fn type_(mut self, type_: crate::types::DashboardWebinarsType) -> Self {
+        self.inner = self.inner.type_(type_);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::ParticipantQos>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Dashboards::webinar_participants_qo`], set with a builder.
+pub struct DashboardsWebinarParticipantsQoRequest<'a> {
+    inner: crate::dashboards::DashboardsWebinarParticipantsQoRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> DashboardsWebinarParticipantsQoRequest<'a> {
+    /// Sets the `type` query parameter.
+    pub fn type_(mut self, type_: crate::types::DashboardWebinarsType) -> Self {
+        self.inner = self.inner.type_(type_);
+        self
+    }
+
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.inner = self.inner.next_page_token(next_page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::Domains>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Dashboards::webinar_participant_share`], set with a builder.
+pub struct DashboardsWebinarParticipantShareRequest<'a> {
+    inner: crate::dashboards::DashboardsWebinarParticipantShareRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> DashboardsWebinarParticipantShareRequest<'a> {
+    /// Sets the `type` query parameter.
+    pub fn type_(mut self, type_: crate::types::DashboardWebinarsType) -> Self {
+        self.inner = self.inner.type_(type_);
+        self
+    }
+
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.inner = self.inner.next_page_token(next_page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::DashboardMeetingParticipantShareResponseAllOf>>
+    {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Dashboards::zoom_room`], set with a builder.
+pub struct DashboardsZoomRoomRequest<'a> {
+    inner: crate::dashboards::DashboardsZoomRoomRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> DashboardsZoomRoomRequest<'a> {
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Sets the `page_number` query parameter.
+    pub fn page_number(mut self, page_number: i64) -> Self {
+        self.inner = self.inner.page_number(page_number);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.inner = self.inner.next_page_token(next_page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::Domains>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Dashboards::zoom_room_dashboards`], set with a builder.
+pub struct DashboardsZoomRoomDashboardsRequest<'a> {
+    inner: crate::dashboards::DashboardsZoomRoomDashboardsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> DashboardsZoomRoomDashboardsRequest<'a> {
+    /// Sets the `from` query parameter.
+    pub fn from(mut self, from: chrono::NaiveDate) -> Self {
+        self.inner = self.inner.from(from);
+        self
+    }
+
+    /// Sets the `to` query parameter.
+    pub fn to(mut self, to: chrono::NaiveDate) -> Self {
+        self.inner = self.inner.to(to);
+        self
+    }
+
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.inner = self.inner.next_page_token(next_page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::Domains>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Dashboards::im`], set with a builder.
+pub struct DashboardsImRequest<'a> {
+    inner: crate::dashboards::DashboardsImRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> DashboardsImRequest<'a> {
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.inner = self.inner.next_page_token(next_page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::DashboardImResponseAllOf>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Dashboards::chat`], set with a builder.
+pub struct DashboardsChatRequest<'a> {
+    inner: crate::dashboards::DashboardsChatRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> DashboardsChatRequest<'a> {
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.inner = self.inner.next_page_token(next_page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::DashboardChatResponseAllOf>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Dashboards::client_feedback`], set with a builder.
+pub struct DashboardsClientFeedbackRequest<'a> {
+    inner: crate::dashboards::DashboardsClientFeedbackRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> DashboardsClientFeedbackRequest<'a> {
+    /// Sets the `from` query parameter.
+    pub fn from(mut self, from: chrono::NaiveDate) -> Self {
+        self.inner = self.inner.from(from);
+        self
+    }
+
+    /// Sets the `to` query parameter.
+    pub fn to(mut self, to: chrono::NaiveDate) -> Self {
+        self.inner = self.inner.to(to);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::DashboardClientFeedbackResponse>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Dashboards::issue_detail_zoom_room`], set with a builder.
+pub struct DashboardsIssueDetailZoomRoomRequest<'a> {
+    inner: crate::dashboards::DashboardsIssueDetailZoomRoomRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> DashboardsIssueDetailZoomRoomRequest<'a> {
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.inner = self.inner.next_page_token(next_page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::DashboardIssueDetailZoomRoomResponseAllOf>>
+    {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Dashboards::client_feedback_detail`], set with a builder.
+pub struct DashboardsClientFeedbackDetailRequest<'a> {
+    inner: crate::dashboards::DashboardsClientFeedbackDetailRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> DashboardsClientFeedbackDetailRequest<'a> {
+    /// Sets the `from` query parameter.
+    pub fn from(mut self, from: chrono::NaiveDate) -> Self {
+        self.inner = self.inner.from(from);
+        self
+    }
+
+    /// Sets the `to` query parameter.
+    pub fn to(mut self, to: chrono::NaiveDate) -> Self {
+        self.inner = self.inner.to(to);
+        self
+    }
+
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.inner = self.inner.next_page_token(next_page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::DashboardClientFeedbackDetailResponseAllOf>>
+    {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Dashboards::list_meeting_satisfaction`], set with a builder.
+pub struct DashboardsListMeetingSatisfactionRequest<'a> {
+    inner: crate::dashboards::DashboardsListMeetingSatisfactionRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> DashboardsListMeetingSatisfactionRequest<'a> {
+    /// Sets the `from` query parameter.
+    pub fn from(mut self, from: chrono::NaiveDate) -> Self {
+        self.inner = self.inner.from(from);
+        self
+    }
+
+    /// Sets the `to` query parameter.
+    pub fn to(mut self, to: chrono::NaiveDate) -> Self {
+        self.inner = self.inner.to(to);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::ListMeetingSatisfactionResponse>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Dashboards::list_call_logs_metrics`], set with a builder.
+pub struct DashboardsListCallLogsMetricsRequest<'a> {
+    inner: crate::dashboards::DashboardsListCallLogsMetricsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> DashboardsListCallLogsMetricsRequest<'a> {
+    /// Sets the `from` query parameter.
+    pub fn from(mut self, from: &'a str) -> Self {
+        self.inner = self.inner.from(from);
+        self
+    }
+
+    /// Sets the `to` query parameter.
+    pub fn to(mut self, to: &'a str) -> Self {
+        self.inner = self.inner.to(to);
+        self
+    }
+
+    /// Sets the `site_id` query parameter.
+    pub fn site_id(mut self, site_id: &'a str) -> Self {
+        self.inner = self.inner.site_id(site_id);
+        self
+    }
+
+    /// Sets the `quality_type` query parameter.
+    pub fn quality_type(mut self, quality_type: &'a str) -> Self {
+        self.inner = self.inner.quality_type(quality_type);
+        self
+    }
+
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.inner = self.inner.next_page_token(next_page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(
+        self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ListCallLogsMetricsResponse>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Dashboards::participant_feedback`], set with a builder.
+pub struct DashboardsParticipantFeedbackRequest<'a> {
+    inner: crate::dashboards::DashboardsParticipantFeedbackRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> DashboardsParticipantFeedbackRequest<'a> {
+    /// Sets the `type` query parameter.
+    pub fn type_(mut self, type_: crate::types::DashboardMeetingsType) -> Self {
+        self.inner = self.inner.type_(type_);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.inner = self.inner.next_page_token(next_page_token);
+        self
+    }
+
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(
+        self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ParticipantFeedbackResponseParticipants>>>
+    {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Dashboards::participant_webinar_feedback`], set with a builder.
+pub struct DashboardsParticipantWebinarFeedbackRequest<'a> {
+    inner: crate::dashboards::DashboardsParticipantWebinarFeedbackRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> DashboardsParticipantWebinarFeedbackRequest<'a> {
+    /// Sets the `type` query parameter.
+    pub fn type_(mut self, type_: crate::types::DashboardMeetingsType) -> Self {
+        self.inner = self.inner.type_(type_);
+        self
+    }
+
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.inner = self.inner.next_page_token(next_page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(
+        self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ParticipantFeedbackResponseParticipants>>>
+    {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::dashboards::Dashboards`].
+pub struct Dashboards {
+    inner: crate::dashboards::Dashboards,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Dashboards {
+    pub(crate) fn new(
+        inner: crate::dashboards::Dashboards,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Dashboards { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::dashboards::Dashboards::meeting`].
+    pub fn meeting(
+        &self,
+        type_: crate::types::DashboardMeetingsType,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        page_size: i64,
+        next_page_token: &str,
+        include_fields: crate::types::IncludeFields,
+    ) -> ClientResult<crate::Response<crate::types::DashboardMeetingsResponseAllOf>> {
+        self.runtime.block_on(self.inner.meeting(
+            type_,
+            from,
+            to,
+            page_size,
+            next_page_token,
+            include_fields,
+        ))
+    }
+    /// The blocking version of [`crate::dashboards::Dashboards::meeting_request`].
+    pub fn meeting_request(
+        &self,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> DashboardsMeetingRequest<'_> {
+        DashboardsMeetingRequest {
+            inner: self.inner.meeting_request(from, to),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::dashboards::Dashboards::meeting_detail`].
+    pub fn meeting_detail(
+        &self,
+        meeting_id: &str,
+        type_: crate::types::DashboardMeetingsType,
+    ) -> ClientResult<crate::Response<crate::types::MeetingMetric>> {
+        self.runtime
+            .block_on(self.inner.meeting_detail(meeting_id, type_))
+    }
+    /// The blocking version of [`crate::dashboards::Dashboards::meeting_detail_request`].
+    pub fn meeting_detail_request<'a>(
+        &'a self,
+        meeting_id: &'a str,
+    ) -> DashboardsMeetingDetailRequest<'a> {
+        DashboardsMeetingDetailRequest {
+            inner: self.inner.meeting_detail_request(meeting_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::dashboards::Dashboards::meeting_participant`].
+    pub fn meeting_participant(
+        &self,
+        meeting_id: &str,
+        type_: crate::types::DashboardMeetingsType,
+        page_size: i64,
+        next_page_token: &str,
+        include_fields: crate::types::DashboardMeetingParticipantsIncludeFields,
+    ) -> ClientResult<crate::Response<crate::types::DashboardMeetingParticipantsResponseAllOf>>
+    {
+        self.runtime.block_on(self.inner.meeting_participant(
+            meeting_id,
+            type_,
+            page_size,
+            next_page_token,
+            include_fields,
+        ))
+    }
+    /// The blocking version of [`crate::dashboards::Dashboards::meeting_participant_request`].
+    pub fn meeting_participant_request<'a>(
+        &'a self,
+        meeting_id: &'a str,
+    ) -> DashboardsMeetingParticipantRequest<'a> {
+        DashboardsMeetingParticipantRequest {
+            inner: self.inner.meeting_participant_request(meeting_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::dashboards::Dashboards::meeting_participant_qo`].
+    pub fn meeting_participant_qo(
+        &self,
+        meeting_id: &str,
+        participant_id: &str,
+        type_: crate::types::DashboardMeetingsType,
+    ) -> ClientResult<crate::Response<crate::types::ParticipantQos>> {
+        self.runtime.block_on(
+            self.inner
+                .meeting_participant_qo(meeting_id, participant_id, type_),
+        )
+    }
+    /// The blocking version of [`crate::dashboards::Dashboards::meeting_participant_qo_request`].
+    pub fn meeting_participant_qo_request<'a>(
+        &'a self,
+        meeting_id: &'a str,
+        participant_id: &'a str,
+    ) -> DashboardsMeetingParticipantQoRequest<'a> {
+        DashboardsMeetingParticipantQoRequest {
+            inner: self
+                .inner
+                .meeting_participant_qo_request(meeting_id, participant_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::dashboards::Dashboards::meeting_participants_qo`].
+    pub fn meeting_participants_qo(
+        &self,
+        meeting_id: &str,
+        type_: crate::types::DashboardMeetingsType,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> ClientResult<crate::Response<crate::types::Domains>> {
+        self.runtime.block_on(self.inner.meeting_participants_qo(
+            meeting_id,
+            type_,
+            page_size,
+            next_page_token,
+        ))
+    }
+    /// The blocking version of [`crate::dashboards::Dashboards::meeting_participants_qo_request`].
+    pub fn meeting_participants_qo_request<'a>(
+        &'a self,
+        meeting_id: &'a str,
+    ) -> DashboardsMeetingParticipantsQoRequest<'a> {
+        DashboardsMeetingParticipantsQoRequest {
+            inner: self.inner.meeting_participants_qo_request(meeting_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::dashboards::Dashboards::meeting_participant_share`].
+    pub fn meeting_participant_share(
+        &self,
+        meeting_id: &str,
+        type_: crate::types::DashboardMeetingsType,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> ClientResult<crate::Response<crate::types::DashboardMeetingParticipantShareResponseAllOf>>
+    {
+        self.runtime.block_on(self.inner.meeting_participant_share(
+            meeting_id,
+            type_,
+            page_size,
+            next_page_token,
+        ))
+    }
+    /// The blocking version of [`crate::dashboards::Dashboards::meeting_participant_share_request`].
+    pub fn meeting_participant_share_request<'a>(
+        &'a self,
+        meeting_id: &'a str,
+    ) -> DashboardsMeetingParticipantShareRequest<'a> {
+        DashboardsMeetingParticipantShareRequest {
+            inner: self.inner.meeting_participant_share_request(meeting_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::dashboards::Dashboards::webinar`].
+    pub fn webinar(
+        &self,
+        type_: crate::types::DashboardWebinarsType,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> ClientResult<crate::Response<crate::types::DashboardWebinarsResponseAllOf>> {
+        self.runtime.block_on(
+            self.inner
+                .webinar(type_, from, to, page_size, next_page_token),
+        )
+    }
+    /// The blocking version of [`crate::dashboards::Dashboards::webinar_request`].
+    pub fn webinar_request(
+        &self,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> DashboardsWebinarRequest<'_> {
+        DashboardsWebinarRequest {
+            inner: self.inner.webinar_request(from, to),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::dashboards::Dashboards::webinar_detail`].
+    pub fn webinar_detail(
+        &self,
+        webinar_id: &str,
+        type_: crate::types::DashboardWebinarsType,
+    ) -> ClientResult<crate::Response<crate::types::Webinars>> {
+        self.runtime
+            .block_on(self.inner.webinar_detail(webinar_id, type_))
+    }
+    /// The blocking version of [`crate::dashboards::Dashboards::webinar_detail_request`].
+    pub fn webinar_detail_request<'a>(
+        &'a self,
+        webinar_id: &'a str,
+    ) -> DashboardsWebinarDetailRequest<'a> {
+        DashboardsWebinarDetailRequest {
+            inner: self.inner.webinar_detail_request(webinar_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::dashboards::Dashboards::webinar_participant`].
+    pub fn webinar_participant(
+        &self,
+        webinar_id: &str,
+        type_: crate::types::DashboardWebinarsType,
+        page_size: i64,
+        next_page_token: &str,
+        include_fields: crate::types::DashboardMeetingParticipantsIncludeFields,
+    ) -> ClientResult<crate::Response<crate::types::DashboardWebinarParticipantsResponseAllOf>>
+    {
+        self.runtime.block_on(self.inner.webinar_participant(
+            webinar_id,
+            type_,
+            page_size,
+            next_page_token,
+            include_fields,
+        ))
+    }
+    /// The blocking version of [`crate::dashboards::Dashboards::webinar_participant_request`].
+    pub fn webinar_participant_request<'a>(
+        &'a self,
+        webinar_id: &'a str,
+    ) -> DashboardsWebinarParticipantRequest<'a> {
+        DashboardsWebinarParticipantRequest {
+            inner: self.inner.webinar_participant_request(webinar_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::dashboards::Dashboards::webinar_participant_qo`].
+    pub fn webinar_participant_qo(
+        &self,
+        webinar_id: &str,
+        participant_id: &str,
+        type_: crate::types::DashboardWebinarsType,
+    ) -> ClientResult<crate::Response<crate::types::ParticipantQos>> {
+        self.runtime.block_on(
+            self.inner
+                .webinar_participant_qo(webinar_id, participant_id, type_),
+        )
+    }
+    /// The blocking version of [`crate::dashboards::Dashboards::webinar_participant_qo_request`].
+    pub fn webinar_participant_qo_request<'a>(
+        &'a self,
+        webinar_id: &'a str,
+        participant_id: &'a str,
+    ) -> DashboardsWebinarParticipantQoRequest<'a> {
+        DashboardsWebinarParticipantQoRequest {
+            inner: self
+                .inner
+                .webinar_participant_qo_request(webinar_id, participant_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::dashboards::Dashboards::webinar_participants_qo`].
+    pub fn webinar_participants_qo(
+        &self,
+        webinar_id: &str,
+        type_: crate::types::DashboardWebinarsType,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> ClientResult<crate::Response<crate::types::Domains>> {
+        self.runtime.block_on(self.inner.webinar_participants_qo(
+            webinar_id,
+            type_,
+            page_size,
+            next_page_token,
+        ))
+    }
+    /// The blocking version of [`crate::dashboards::Dashboards::webinar_participants_qo_request`].
+    pub fn webinar_participants_qo_request<'a>(
+        &'a self,
+        webinar_id: &'a str,
+    ) -> DashboardsWebinarParticipantsQoRequest<'a> {
+        DashboardsWebinarParticipantsQoRequest {
+            inner: self.inner.webinar_participants_qo_request(webinar_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::dashboards::Dashboards::webinar_participant_share`].
+    pub fn webinar_participant_share(
+        &self,
+        webinar_id: &str,
+        type_: crate::types::DashboardWebinarsType,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> ClientResult<crate::Response<crate::types::DashboardMeetingParticipantShareResponseAllOf>>
+    {
+        self.runtime.block_on(self.inner.webinar_participant_share(
+            webinar_id,
+            type_,
+            page_size,
+            next_page_token,
+        ))
+    }
+    /// The blocking version of [`crate::dashboards::Dashboards::webinar_participant_share_request`].
+    pub fn webinar_participant_share_request<'a>(
+        &'a self,
+        webinar_id: &'a str,
+    ) -> DashboardsWebinarParticipantShareRequest<'a> {
+        DashboardsWebinarParticipantShareRequest {
+            inner: self.inner.webinar_participant_share_request(webinar_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::dashboards::Dashboards::zoom_room`].
+    pub fn zoom_room(
+        &self,
+        page_size: i64,
+        page_number: i64,
+        next_page_token: &str,
+    ) -> ClientResult<crate::Response<crate::types::Domains>> {
+        self.runtime.block_on(
+            self.inner
+                .zoom_room(page_size, page_number, next_page_token),
+        )
+    }
+    /// The blocking version of [`crate::dashboards::Dashboards::zoom_room_request`].
+    pub fn zoom_room_request(&self) -> DashboardsZoomRoomRequest<'_> {
+        DashboardsZoomRoomRequest {
+            inner: self.inner.zoom_room_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::dashboards::Dashboards::zoom_room_dashboards`].
+    pub fn zoom_room_dashboards(
+        &self,
+        zoomroom_id: &str,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> ClientResult<crate::Response<crate::types::Domains>> {
+        self.runtime.block_on(self.inner.zoom_room_dashboards(
+            zoomroom_id,
+            from,
+            to,
+            page_size,
+            next_page_token,
+        ))
+    }
+    /// The blocking version of [`crate::dashboards::Dashboards::zoom_room_dashboards_request`].
+    pub fn zoom_room_dashboards_request<'a>(
+        &'a self,
+        zoomroom_id: &'a str,
+    ) -> DashboardsZoomRoomDashboardsRequest<'a> {
+        DashboardsZoomRoomDashboardsRequest {
+            inner: self.inner.zoom_room_dashboards_request(zoomroom_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::dashboards::Dashboards::crc`].
+    pub fn crc(
+        &self,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> ClientResult<crate::Response<crate::types::Domains>> {
+        self.runtime.block_on(self.inner.crc(from, to))
+    }
+    /// The blocking version of [`crate::dashboards::Dashboards::im`].
+    pub fn im(
+        &self,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> ClientResult<crate::Response<crate::types::DashboardImResponseAllOf>> {
+        self.runtime
+            .block_on(self.inner.im(from, to, page_size, next_page_token))
+    }
+    /// The blocking version of [`crate::dashboards::Dashboards::im_request`].
+    pub fn im_request(
+        &self,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> DashboardsImRequest<'_> {
+        DashboardsImRequest {
+            inner: self.inner.im_request(from, to),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::dashboards::Dashboards::chat`].
+    pub fn chat(
+        &self,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> ClientResult<crate::Response<crate::types::DashboardChatResponseAllOf>> {
+        self.runtime
+            .block_on(self.inner.chat(from, to, page_size, next_page_token))
+    }
+    /// The blocking version of [`crate::dashboards::Dashboards::chat_request`].
+    pub fn chat_request(
+        &self,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> DashboardsChatRequest<'_> {
+        DashboardsChatRequest {
+            inner: self.inner.chat_request(from, to),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::dashboards::Dashboards::client_feedback`].
+    pub fn client_feedback(
+        &self,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> ClientResult<crate::Response<crate::types::DashboardClientFeedbackResponse>> {
+        self.runtime.block_on(self.inner.client_feedback(from, to))
+    }
+    /// The blocking version of [`crate::dashboards::Dashboards::client_feedback_request`].
+    pub fn client_feedback_request(&self) -> DashboardsClientFeedbackRequest<'_> {
+        DashboardsClientFeedbackRequest {
+            inner: self.inner.client_feedback_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::dashboards::Dashboards::zoom_room_issue`].
+    pub fn zoom_room_issue(
+        &self,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> ClientResult<crate::Response<crate::types::Domains>> {
+        self.runtime.block_on(self.inner.zoom_room_issue(from, to))
+    }
+    /// The blocking version of [`crate::dashboards::Dashboards::issue_zoom_room`].
+    pub fn issue_zoom_room(
+        &self,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> ClientResult<crate::Response<crate::types::DashboardIssueZoomRoomResponseAllOf>> {
+        self.runtime.block_on(self.inner.issue_zoom_room(from, to))
+    }
+    /// The blocking version of [`crate::dashboards::Dashboards::issue_detail_zoom_room`].
+    pub fn issue_detail_zoom_room(
+        &self,
+        zoomroom_id: &str,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> ClientResult<crate::Response<crate::types::DashboardIssueDetailZoomRoomResponseAllOf>>
+    {
+        self.runtime.block_on(self.inner.issue_detail_zoom_room(
+            zoomroom_id,
+            from,
+            to,
+            page_size,
+            next_page_token,
+        ))
+    }
+    /// The blocking version of [`crate::dashboards::Dashboards::issue_detail_zoom_room_request`].
+    pub fn issue_detail_zoom_room_request<'a>(
+        &'a self,
+        zoomroom_id: &'a str,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> DashboardsIssueDetailZoomRoomRequest<'a> {
+        DashboardsIssueDetailZoomRoomRequest {
+            inner: self
+                .inner
+                .issue_detail_zoom_room_request(zoomroom_id, from, to),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::dashboards::Dashboards::client_feedback_detail`].
+    pub fn client_feedback_detail(
+        &self,
+        feedback_id: &str,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> ClientResult<crate::Response<crate::types::DashboardClientFeedbackDetailResponseAllOf>>
+    {
+        self.runtime.block_on(self.inner.client_feedback_detail(
+            feedback_id,
+            from,
+            to,
+            page_size,
+            next_page_token,
+        ))
+    }
+    /// The blocking version of [`crate::dashboards::Dashboards::client_feedback_detail_request`].
+    pub fn client_feedback_detail_request<'a>(
+        &'a self,
+        feedback_id: &'a str,
+    ) -> DashboardsClientFeedbackDetailRequest<'a> {
+        DashboardsClientFeedbackDetailRequest {
+            inner: self.inner.client_feedback_detail_request(feedback_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::dashboards::Dashboards::list_meeting_satisfaction`].
+    pub fn list_meeting_satisfaction(
+        &self,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> ClientResult<crate::Response<crate::types::ListMeetingSatisfactionResponse>> {
+        self.runtime
+            .block_on(self.inner.list_meeting_satisfaction(from, to))
+    }
+    /// The blocking version of [`crate::dashboards::Dashboards::list_meeting_satisfaction_request`].
+    pub fn list_meeting_satisfaction_request(
+        &self,
+    ) -> DashboardsListMeetingSatisfactionRequest<'_> {
+        DashboardsListMeetingSatisfactionRequest {
+            inner: self.inner.list_meeting_satisfaction_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::dashboards::Dashboards::list_call_logs_metrics`].
+    pub fn list_call_logs_metrics(
+        &self,
+        from: &str,
+        to: &str,
+        site_id: &str,
+        quality_type: &str,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ListCallLogsMetricsResponse>>> {
+        self.runtime.block_on(self.inner.list_call_logs_metrics(
+            from,
+            to,
+            site_id,
+            quality_type,
+            page_size,
+            next_page_token,
+        ))
+    }
+    /// The blocking version of [`crate::dashboards::Dashboards::list_call_logs_metrics_request`].
+    pub fn list_call_logs_metrics_request(&self) -> DashboardsListCallLogsMetricsRequest<'_> {
+        DashboardsListCallLogsMetricsRequest {
+            inner: self.inner.list_call_logs_metrics_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::dashboards::Dashboards::list_all_call_logs_metrics`].
+    pub fn list_all_call_logs_metrics(
+        &self,
+        from: &str,
+        to: &str,
+        site_id: &str,
+        quality_type: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ListCallLogsMetricsResponse>>> {
+        self.runtime.block_on(self.inner.list_all_call_logs_metrics(
+            from,
+            to,
+            site_id,
+            quality_type,
+        ))
+    }
+    /// The blocking version of [`crate::dashboards::Dashboards::get_call_log_metrics_details`].
+    pub fn get_call_log_metrics_details(
+        &self,
+        call_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ListCallLogsMetricsResponse>> {
+        self.runtime
+            .block_on(self.inner.get_call_log_metrics_details(call_id))
+    }
+    /// The blocking version of [`crate::dashboards::Dashboards::get_call_qo`].
+    pub fn get_call_qo(
+        &self,
+        call_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::GetCallQoSResponse>> {
+        self.runtime.block_on(self.inner.get_call_qo(call_id))
+    }
+    /// The blocking version of [`crate::dashboards::Dashboards::participant_feedback`].
+    pub fn participant_feedback(
+        &self,
+        meeting_id: &str,
+        type_: crate::types::DashboardMeetingsType,
+        next_page_token: &str,
+        page_size: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ParticipantFeedbackResponseParticipants>>>
+    {
+        self.runtime.block_on(self.inner.participant_feedback(
+            meeting_id,
+            type_,
+            next_page_token,
+            page_size,
+        ))
+    }
+    /// The blocking version of [`crate::dashboards::Dashboards::participant_feedback_request`].
+    pub fn participant_feedback_request<'a>(
+        &'a self,
+        meeting_id: &'a str,
+    ) -> DashboardsParticipantFeedbackRequest<'a> {
+        DashboardsParticipantFeedbackRequest {
+            inner: self.inner.participant_feedback_request(meeting_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::dashboards::Dashboards::get_all_participant_feedback`].
+    pub fn get_all_participant_feedback(
+        &self,
+        meeting_id: &str,
+        type_: crate::types::DashboardMeetingsType,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ParticipantFeedbackResponseParticipants>>>
+    {
+        self.runtime
+            .block_on(self.inner.get_all_participant_feedback(meeting_id, type_))
+    }
+    /// The blocking version of [`crate::dashboards::Dashboards::participant_webinar_feedback`].
+    pub fn participant_webinar_feedback(
+        &self,
+        type_: crate::types::DashboardMeetingsType,
+        page_size: i64,
+        next_page_token: &str,
+        webinar_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ParticipantFeedbackResponseParticipants>>>
+    {
+        self.runtime
+            .block_on(self.inner.participant_webinar_feedback(
+                type_,
+                page_size,
+                next_page_token,
+                webinar_id,
+            ))
+    }
+    /// The blocking version of [`crate::dashboards::Dashboards::participant_webinar_feedback_request`].
+    pub fn participant_webinar_feedback_request<'a>(
+        &'a self,
+        webinar_id: &'a str,
+    ) -> DashboardsParticipantWebinarFeedbackRequest<'a> {
+        DashboardsParticipantWebinarFeedbackRequest {
+            inner: self.inner.participant_webinar_feedback_request(webinar_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::dashboards::Dashboards::get_all_participant_webinar_feedback`].
+    pub fn get_all_participant_webinar_feedback(
+        &self,
+        type_: crate::types::DashboardMeetingsType,
+        webinar_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ParticipantFeedbackResponseParticipants>>>
+    {
+        self.runtime.block_on(
+            self.inner
+                .get_all_participant_webinar_feedback(type_, webinar_id),
+        )
+    }
+}
@@ -0,0 +1,35 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::deprecated_api_endpoints::DeprecatedApiEndpoints`].
+pub struct DeprecatedApiEndpoints {
+    inner: crate::deprecated_api_endpoints::DeprecatedApiEndpoints,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl DeprecatedApiEndpoints {
+    pub(crate) fn new(
+        inner: crate::deprecated_api_endpoints::DeprecatedApiEndpoints,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        DeprecatedApiEndpoints { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::deprecated_api_endpoints::DeprecatedApiEndpoints::list_past_meeting_files`].
+    pub fn list_past_meeting_files(
+        &self,
+        meeting_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ListPastMeetingFilesResponse>> {
+        self.runtime
+            .block_on(self.inner.list_past_meeting_files(meeting_id))
+    }
+    /// The blocking version of [`crate::deprecated_api_endpoints::DeprecatedApiEndpoints::list_past_webinar_files`].
+    pub fn list_past_webinar_files(
+        &self,
+        webinar_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ListPastMeetingFilesResponse>> {
+        self.runtime
+            .block_on(self.inner.list_past_webinar_files(webinar_id))
+    }
+}
@@ -0,0 +1,83 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Devices::list`], set with a builder.
+pub struct DevicesListRequest<'a> {
+    inner: crate::devices::DevicesListRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> DevicesListRequest<'a> {
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Sets the `page_number` query parameter.
+    pub fn page_number(mut self, page_number: i64) -> Self {
+        self.inner = self.inner.page_number(page_number);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.inner = self.inner.next_page_token(next_page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::Domains>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::devices::Devices`].
+pub struct Devices {
+    inner: crate::devices::Devices,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Devices {
+    pub(crate) fn new(
+        inner: crate::devices::Devices,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Devices { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::devices::Devices::list`].
+    pub fn list(
+        &self,
+        page_size: i64,
+        page_number: i64,
+        next_page_token: &str,
+    ) -> ClientResult<crate::Response<crate::types::Domains>> {
+        self.runtime
+            .block_on(self.inner.list(page_size, page_number, next_page_token))
+    }
+    /// The blocking version of [`crate::devices::Devices::list_request`].
+    pub fn list_request(&self) -> DevicesListRequest<'_> {
+        DevicesListRequest {
+            inner: self.inner.list_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::devices::Devices::create`].
+    pub fn create(&self, body: &crate::types::Device) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.create(body))
+    }
+    /// The blocking version of [`crate::devices::Devices::delete`].
+    pub fn delete(&self, device_id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.delete(device_id))
+    }
+    /// The blocking version of [`crate::devices::Devices::update`].
+    pub fn update(
+        &self,
+        device_id: &str,
+        body: &crate::types::Device,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.update(device_id, body))
+    }
+}
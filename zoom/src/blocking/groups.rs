@@ -0,0 +1,467 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Groups::members`], set with a builder.
+pub struct GroupsMembersRequest<'a> {
+    inner: crate::groups::GroupsMembersRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> GroupsMembersRequest<'a> {
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Sets the `page_number` query parameter.
+    pub fn page_number(mut self, page_number: i64) -> Self {
+        self.inner = self.inner.page_number(page_number);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.inner = self.inner.next_page_token(next_page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::UserCreateResponse>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Groups::get_setting`], set with a builder.
+pub struct GroupsGetSettingRequest<'a> {
+    inner: crate::groups::GroupsGetSettingRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> GroupsGetSettingRequest<'a> {
+    /// Sets the `custom_query_fields` query parameter.
+    pub fn custom_query_fields(mut self, custom_query_fields: &'a str) -> Self {
+        self.inner = self.inner.custom_query_fields(custom_query_fields);
+        self
+    }
+
+    /// Sets the `option` query parameter.
+    pub fn option(mut self, option: crate::types::OptionData) -> Self {
+        self.inner = self.inner.option(option);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::GetGroupSettingsResponseOneOf>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Groups::update_settings`], set with a builder.
+pub struct GroupsUpdateSettingsRequest<'a> {
+    inner: crate::groups::GroupsUpdateSettingsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> GroupsUpdateSettingsRequest<'a> {
+    /// Sets the `custom_query_fields` query parameter.
+    pub fn custom_query_fields(mut self, custom_query_fields: &'a str) -> Self {
+        self.inner = self.inner.custom_query_fields(custom_query_fields);
+        self
+    }
+
+    /// Sets the `option` query parameter.
+    pub fn option(mut self, option: crate::types::UpdateGroupSettingsOption) -> Self {
+        self.inner = self.inner.option(option);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Groups::get_lock_setting`], set with a builder.
+pub struct GroupsGetLockSettingRequest<'a> {
+    inner: crate::groups::GroupsGetLockSettingRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> GroupsGetLockSettingRequest<'a> {
+    /// Sets the `custom_query_fields` query parameter.
+    pub fn custom_query_fields(mut self, custom_query_fields: &'a str) -> Self {
+        self.inner = self.inner.custom_query_fields(custom_query_fields);
+        self
+    }
+
+    /// Sets the `option` query parameter.
+    pub fn option(mut self, option: &'a str) -> Self {
+        self.inner = self.inner.option(option);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::GetGroupLockSettingsResponseOneOf>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Groups::locked_settings`], set with a builder.
+pub struct GroupsLockedSettingsRequest<'a> {
+    inner: crate::groups::GroupsLockedSettingsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> GroupsLockedSettingsRequest<'a> {
+    /// Sets the `custom_query_fields` query parameter.
+    pub fn custom_query_fields(mut self, custom_query_fields: &'a str) -> Self {
+        self.inner = self.inner.custom_query_fields(custom_query_fields);
+        self
+    }
+
+    /// Sets the `option` query parameter.
+    pub fn option(mut self, option: &'a str) -> Self {
+        self.inner = self.inner.option(option);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Groups::upload_vb`], set with a builder.
+pub struct GroupsUploadVbRequest<'a> {
+    inner: crate::groups::GroupsUploadVbRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> GroupsUploadVbRequest<'a> {
+    /// Sets the `file_ids` query parameter.
+    pub fn file_ids(mut self, file_ids: &'a str) -> Self {
+        self.inner = self.inner.file_ids(file_ids);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::Files>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Groups::del_vb`], set with a builder.
+pub struct GroupsDelVbRequest<'a> {
+    inner: crate::groups::GroupsDelVbRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> GroupsDelVbRequest<'a> {
+    /// Sets the `file_ids` query parameter.
+    pub fn file_ids(mut self, file_ids: &'a str) -> Self {
+        self.inner = self.inner.file_ids(file_ids);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::groups::Groups`].
+pub struct Groups {
+    inner: crate::groups::Groups,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Groups {
+    pub(crate) fn new(inner: crate::groups::Groups, runtime: Arc<tokio::runtime::Runtime>) -> Self {
+        Groups { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::groups::Groups::get`].
+    pub fn get(&self) -> ClientResult<crate::Response<crate::types::GroupList>> {
+        self.runtime.block_on(self.inner.get())
+    }
+    /// The blocking version of [`crate::groups::Groups::create`].
+    pub fn create(
+        &self,
+        body: &crate::types::GroupCreateRequest,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.create(body))
+    }
+    /// The blocking version of [`crate::groups::Groups::group`].
+    pub fn group(
+        &self,
+        group_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::GroupResponse>> {
+        self.runtime.block_on(self.inner.group(group_id))
+    }
+    /// The blocking version of [`crate::groups::Groups::delete`].
+    pub fn delete(&self, group_id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.delete(group_id))
+    }
+    /// The blocking version of [`crate::groups::Groups::update`].
+    pub fn update(
+        &self,
+        group_id: &str,
+        body: &crate::types::GroupCreateRequest,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.update(group_id, body))
+    }
+    /// The blocking version of [`crate::groups::Groups::members`].
+    pub fn members(
+        &self,
+        group_id: &str,
+        page_size: i64,
+        page_number: i64,
+        next_page_token: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::UserCreateResponse>>> {
+        self.runtime.block_on(
+            self.inner
+                .members(group_id, page_size, page_number, next_page_token),
+        )
+    }
+    /// The blocking version of [`crate::groups::Groups::members_request`].
+    pub fn members_request<'a>(&'a self, group_id: &'a str) -> GroupsMembersRequest<'a> {
+        GroupsMembersRequest {
+            inner: self.inner.members_request(group_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::groups::Groups::get_all_members`].
+    pub fn get_all_members(
+        &self,
+        group_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::UserCreateResponse>>> {
+        self.runtime.block_on(self.inner.get_all_members(group_id))
+    }
+    /// The blocking version of [`crate::groups::Groups::members_create`].
+    pub fn members_create(
+        &self,
+        group_id: &str,
+        body: &crate::types::AddRoleMembersRequest,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.members_create(group_id, body))
+    }
+    /// The blocking version of [`crate::groups::Groups::members_delete`].
+    pub fn members_delete(
+        &self,
+        group_id: &str,
+        member_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.members_delete(group_id, member_id))
+    }
+    /// The blocking version of [`crate::groups::Groups::update_member`].
+    pub fn update_member(
+        &self,
+        group_id: &str,
+        member_id: &str,
+        body: &crate::types::UpdateGroupMemberRequest,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.update_member(group_id, member_id, body))
+    }
+    /// The blocking version of [`crate::groups::Groups::get_settings_domains`].
+    pub fn get_settings_domains(
+        &self,
+        group_id: &str,
+        custom_query_fields: &str,
+        option: crate::types::OptionData,
+    ) -> ClientResult<crate::Response<crate::types::Domains>> {
+        self.runtime.block_on(self.inner.get_settings_domains(
+            group_id,
+            custom_query_fields,
+            option,
+        ))
+    }
+    /// The blocking version of [`crate::groups::Groups::get_settings_meeting_security`].
+    pub fn get_settings_meeting_security(
+        &self,
+        group_id: &str,
+        custom_query_fields: &str,
+        option: crate::types::OptionData,
+    ) -> ClientResult<crate::Response<crate::types::MeetingSecuritySettings>> {
+        self.runtime
+            .block_on(self.inner.get_settings_meeting_security(
+                group_id,
+                custom_query_fields,
+                option,
+            ))
+    }
+    /// The blocking version of [`crate::groups::Groups::get_settings_group_response`].
+    pub fn get_settings_group_response(
+        &self,
+        group_id: &str,
+        custom_query_fields: &str,
+        option: crate::types::OptionData,
+    ) -> ClientResult<crate::Response<crate::types::GetGroupSettingsResponse>> {
+        self.runtime
+            .block_on(
+                self.inner
+                    .get_settings_group_response(group_id, custom_query_fields, option),
+            )
+    }
+    /// The blocking version of [`crate::groups::Groups::get_setting`].
+    pub fn get_setting(
+        &self,
+        group_id: &str,
+        custom_query_fields: &str,
+        option: crate::types::OptionData,
+    ) -> ClientResult<crate::Response<crate::types::GetGroupSettingsResponseOneOf>> {
+        self.runtime.block_on(
+            self.inner
+                .get_setting(group_id, custom_query_fields, option),
+        )
+    }
+    /// The blocking version of [`crate::groups::Groups::get_setting_request`].
+    pub fn get_setting_request<'a>(&'a self, group_id: &'a str) -> GroupsGetSettingRequest<'a> {
+        GroupsGetSettingRequest {
+            inner: self.inner.get_setting_request(group_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::groups::Groups::update_settings`].
+    pub fn update_settings(
+        &self,
+        group_id: &str,
+        custom_query_fields: &str,
+        option: crate::types::UpdateGroupSettingsOption,
+        body: &crate::types::UpdateGroupSettingsRequestOneOf,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.update_settings(
+            group_id,
+            custom_query_fields,
+            option,
+            body,
+        ))
+    }
+    /// The blocking version of [`crate::groups::Groups::update_settings_request`].
+    pub fn update_settings_request<'a>(
+        &'a self,
+        group_id: &'a str,
+        body: &'a crate::types::UpdateGroupSettingsRequestOneOf,
+    ) -> GroupsUpdateSettingsRequest<'a> {
+        GroupsUpdateSettingsRequest {
+            inner: self.inner.update_settings_request(group_id, body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::groups::Groups::get_lock_settings_meeting_security`].
+    pub fn get_lock_settings_meeting_security(
+        &self,
+        group_id: &str,
+        custom_query_fields: &str,
+        option: &str,
+    ) -> ClientResult<crate::Response<crate::types::MeetingSecuritySettings>> {
+        self.runtime
+            .block_on(self.inner.get_lock_settings_meeting_security(
+                group_id,
+                custom_query_fields,
+                option,
+            ))
+    }
+    /// The blocking version of [`crate::groups::Groups::get_lock_settings_group_response`].
+    pub fn get_lock_settings_group_response(
+        &self,
+        group_id: &str,
+        custom_query_fields: &str,
+        option: &str,
+    ) -> ClientResult<crate::Response<crate::types::GetGroupLockSettingsResponse>> {
+        self.runtime
+            .block_on(self.inner.get_lock_settings_group_response(
+                group_id,
+                custom_query_fields,
+                option,
+            ))
+    }
+    /// The blocking version of [`crate::groups::Groups::get_lock_setting`].
+    pub fn get_lock_setting(
+        &self,
+        group_id: &str,
+        custom_query_fields: &str,
+        option: &str,
+    ) -> ClientResult<crate::Response<crate::types::GetGroupLockSettingsResponseOneOf>> {
+        self.runtime.block_on(
+            self.inner
+                .get_lock_setting(group_id, custom_query_fields, option),
+        )
+    }
+    /// The blocking version of [`crate::groups::Groups::get_lock_setting_request`].
+    pub fn get_lock_setting_request<'a>(
+        &'a self,
+        group_id: &'a str,
+    ) -> GroupsGetLockSettingRequest<'a> {
+        GroupsGetLockSettingRequest {
+            inner: self.inner.get_lock_setting_request(group_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::groups::Groups::locked_settings`].
+    pub fn locked_settings(
+        &self,
+        group_id: &str,
+        custom_query_fields: &str,
+        option: &str,
+        body: &crate::types::GroupLockedSettingsRequestOneOf,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.locked_settings(
+            group_id,
+            custom_query_fields,
+            option,
+            body,
+        ))
+    }
+    /// The blocking version of [`crate::groups::Groups::locked_settings_request`].
+    pub fn locked_settings_request<'a>(
+        &'a self,
+        group_id: &'a str,
+        body: &'a crate::types::GroupLockedSettingsRequestOneOf,
+    ) -> GroupsLockedSettingsRequest<'a> {
+        GroupsLockedSettingsRequest {
+            inner: self.inner.locked_settings_request(group_id, body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::groups::Groups::upload_vb`].
+    pub fn upload_vb(
+        &self,
+        file_ids: &str,
+        group_id: &str,
+        body: &crate::types::UploadVbRequest,
+    ) -> ClientResult<crate::Response<crate::types::Files>> {
+        self.runtime
+            .block_on(self.inner.upload_vb(file_ids, group_id, body))
+    }
+    /// The blocking version of [`crate::groups::Groups::upload_vb_request`].
+    pub fn upload_vb_request<'a>(
+        &'a self,
+        group_id: &'a str,
+        body: &'a crate::types::UploadVbRequest,
+    ) -> GroupsUploadVbRequest<'a> {
+        GroupsUploadVbRequest {
+            inner: self.inner.upload_vb_request(group_id, body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::groups::Groups::del_vb`].
+    pub fn del_vb(&self, file_ids: &str, group_id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.del_vb(file_ids, group_id))
+    }
+    /// The blocking version of [`crate::groups::Groups::del_vb_request`].
+    pub fn del_vb_request<'a>(&'a self, group_id: &'a str) -> GroupsDelVbRequest<'a> {
+        GroupsDelVbRequest {
+            inner: self.inner.del_vb_request(group_id),
+            runtime: &self.runtime,
+        }
+    }
+}
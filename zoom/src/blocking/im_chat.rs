@@ -0,0 +1,242 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`ImChat::session`], set with a builder.
+pub struct ImChatSessionRequest<'a> {
+    inner: crate::im_chat::ImChatSessionRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ImChatSessionRequest<'a> {
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.inner = self.inner.next_page_token(next_page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::ImChatSessionsResponseAllOf>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`ImChat::message`], set with a builder.
+pub struct ImChatMessageRequest<'a> {
+    inner: crate::im_chat::ImChatMessageRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ImChatMessageRequest<'a> {
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.inner = self.inner.next_page_token(next_page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::ImChatMessagesResponseAllOf>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`ImChat::list_im_messages`], set with a builder.
+pub struct ImChatListImMessagesRequest<'a> {
+    inner: crate::im_chat::ImChatListImMessagesRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ImChatListImMessagesRequest<'a> {
+    /// Sets the `chat_user` query parameter.
+    pub fn chat_user(mut self, chat_user: &'a str) -> Self {
+        self.inner = self.inner.chat_user(chat_user);
+        self
+    }
+
+    /// Sets the `channel` query parameter.
+    pub fn channel(mut self, channel: &'a str) -> Self {
+        self.inner = self.inner.channel(channel);
+        self
+    }
+
+    /// Sets the `date` query parameter.
+    pub fn date(mut self, date: &'a str) -> Self {
+        self.inner = self.inner.date(date);
+        self
+    }
+
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.inner = self.inner.next_page_token(next_page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(
+        self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ListimmessagesResponseMessages>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`ImChat::send_im_messages`], set with a builder.
+pub struct ImChatSendImMessagesRequest<'a> {
+    inner: crate::im_chat::ImChatSendImMessagesRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ImChatSendImMessagesRequest<'a> {
+    /// Sets the `chat_user` query parameter.
+    pub fn chat_user(mut self, chat_user: &'a str) -> Self {
+        self.inner = self.inner.chat_user(chat_user);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::Groups>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::im_chat::ImChat`].
+pub struct ImChat {
+    inner: crate::im_chat::ImChat,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl ImChat {
+    pub(crate) fn new(
+        inner: crate::im_chat::ImChat,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        ImChat { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::im_chat::ImChat::session`].
+    pub fn session(
+        &self,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> ClientResult<crate::Response<crate::types::ImChatSessionsResponseAllOf>> {
+        self.runtime
+            .block_on(self.inner.session(from, to, page_size, next_page_token))
+    }
+    /// The blocking version of [`crate::im_chat::ImChat::session_request`].
+    pub fn session_request(
+        &self,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> ImChatSessionRequest<'_> {
+        ImChatSessionRequest {
+            inner: self.inner.session_request(from, to),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::im_chat::ImChat::message`].
+    pub fn message(
+        &self,
+        session_id: &str,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> ClientResult<crate::Response<crate::types::ImChatMessagesResponseAllOf>> {
+        self.runtime.block_on(
+            self.inner
+                .message(session_id, from, to, page_size, next_page_token),
+        )
+    }
+    /// The blocking version of [`crate::im_chat::ImChat::message_request`].
+    pub fn message_request<'a>(
+        &'a self,
+        session_id: &'a str,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> ImChatMessageRequest<'a> {
+        ImChatMessageRequest {
+            inner: self.inner.message_request(session_id, from, to),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::im_chat::ImChat::list_im_messages`].
+    pub fn list_im_messages(
+        &self,
+        user_id: &str,
+        chat_user: &str,
+        channel: &str,
+        date: &str,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ListimmessagesResponseMessages>>> {
+        self.runtime.block_on(self.inner.list_im_messages(
+            user_id,
+            chat_user,
+            channel,
+            date,
+            page_size,
+            next_page_token,
+        ))
+    }
+    /// The blocking version of [`crate::im_chat::ImChat::list_im_messages_request`].
+    pub fn list_im_messages_request<'a>(
+        &'a self,
+        user_id: &'a str,
+    ) -> ImChatListImMessagesRequest<'a> {
+        ImChatListImMessagesRequest {
+            inner: self.inner.list_im_messages_request(user_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::im_chat::ImChat::list_all_im_messages`].
+    pub fn list_all_im_messages(
+        &self,
+        user_id: &str,
+        chat_user: &str,
+        channel: &str,
+        date: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ListimmessagesResponseMessages>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_all_im_messages(user_id, chat_user, channel, date),
+        )
+    }
+    /// The blocking version of [`crate::im_chat::ImChat::send_im_messages`].
+    pub fn send_im_messages(
+        &self,
+        chat_user: &str,
+        body: &crate::types::SendimmessagesRequest,
+    ) -> ClientResult<crate::Response<crate::types::Groups>> {
+        self.runtime
+            .block_on(self.inner.send_im_messages(chat_user, body))
+    }
+    /// The blocking version of [`crate::im_chat::ImChat::send_im_messages_request`].
+    pub fn send_im_messages_request<'a>(
+        &'a self,
+        body: &'a crate::types::SendimmessagesRequest,
+    ) -> ImChatSendImMessagesRequest<'a> {
+        ImChatSendImMessagesRequest {
+            inner: self.inner.send_im_messages_request(body),
+            runtime: &self.runtime,
+        }
+    }
+}
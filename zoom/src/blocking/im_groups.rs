@@ -0,0 +1,118 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`ImGroups::member`], set with a builder.
+pub struct ImGroupsMemberRequest<'a> {
+    inner: crate::im_groups::ImGroupsMemberRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ImGroupsMemberRequest<'a> {
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Sets the `page_number` query parameter.
+    pub fn page_number(mut self, page_number: i64) -> Self {
+        self.inner = self.inner.page_number(page_number);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.inner = self.inner.next_page_token(next_page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::Domains>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::im_groups::ImGroups`].
+pub struct ImGroups {
+    inner: crate::im_groups::ImGroups,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl ImGroups {
+    pub(crate) fn new(
+        inner: crate::im_groups::ImGroups,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        ImGroups { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::im_groups::ImGroups::get`].
+    pub fn get(&self) -> ClientResult<crate::Response<crate::types::Domains>> {
+        self.runtime.block_on(self.inner.get())
+    }
+    /// The blocking version of [`crate::im_groups::ImGroups::create`].
+    pub fn create(
+        &self,
+        body: &crate::types::ImGroupCreateRequest,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.create(body))
+    }
+    /// The blocking version of [`crate::im_groups::ImGroups::im_group`].
+    pub fn im_group(
+        &self,
+        group_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ImGroupResponseAllOf>> {
+        self.runtime.block_on(self.inner.im_group(group_id))
+    }
+    /// The blocking version of [`crate::im_groups::ImGroups::delete`].
+    pub fn delete(&self, group_id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.delete(group_id))
+    }
+    /// The blocking version of [`crate::im_groups::ImGroups::update`].
+    pub fn update(
+        &self,
+        group_id: &str,
+        body: &crate::types::ImGroupCreateRequest,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.update(group_id, body))
+    }
+    /// The blocking version of [`crate::im_groups::ImGroups::member`].
+    pub fn member(
+        &self,
+        group_id: &str,
+        page_size: i64,
+        page_number: i64,
+        next_page_token: &str,
+    ) -> ClientResult<crate::Response<crate::types::Domains>> {
+        self.runtime.block_on(
+            self.inner
+                .member(group_id, page_size, page_number, next_page_token),
+        )
+    }
+    /// The blocking version of [`crate::im_groups::ImGroups::member_request`].
+    pub fn member_request<'a>(&'a self, group_id: &'a str) -> ImGroupsMemberRequest<'a> {
+        ImGroupsMemberRequest {
+            inner: self.inner.member_request(group_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::im_groups::ImGroups::members_create`].
+    pub fn members_create(
+        &self,
+        group_id: &str,
+        body: &crate::types::AddRoleMembersRequest,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.members_create(group_id, body))
+    }
+    /// The blocking version of [`crate::im_groups::ImGroups::members_delete`].
+    pub fn members_delete(
+        &self,
+        group_id: &str,
+        member_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.members_delete(group_id, member_id))
+    }
+}
@@ -0,0 +1,634 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Meetings::get`], set with a builder.
+pub struct MeetingsGetRequest<'a> {
+    inner: crate::meetings::MeetingsGetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> MeetingsGetRequest<'a> {
+    /// Sets the `type` query parameter.
+    pub fn type_(mut self, type_: crate::types::MeetingsType) -> Self {
+        self.inner = self.inner.type_(type_);
+        self
+    }
+
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.inner = self.inner.next_page_token(next_page_token);
+        self
+    }
+
+    /// Sets the `page_number` query parameter.
+    pub fn page_number(mut self, page_number: &'a str) -> Self {
+        self.inner = self.inner.page_number(page_number);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::Domains>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Meetings::meeting`], set with a builder.
+pub struct MeetingsMeetingRequest<'a> {
+    inner: crate::meetings::MeetingsMeetingRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> MeetingsMeetingRequest<'a> {
+    /// Sets the `occurrence_id` query parameter.
+    pub fn occurrence_id(mut self, occurrence_id: &'a str) -> Self {
+        self.inner = self.inner.occurrence_id(occurrence_id);
+        self
+    }
+
+    /// Sets the `show_previous_occurrences` query parameter.
+    pub fn show_previous_occurrences(mut self, show_previous_occurrences: bool) -> Self {
+        self.inner = self
+            .inner
+            .show_previous_occurrences(show_previous_occurrences);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::MeetingResponseAllOf>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Meetings::delete`], set with a builder.
+pub struct MeetingsDeleteRequest<'a> {
+    inner: crate::meetings::MeetingsDeleteRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> MeetingsDeleteRequest<'a> {
+    /// Sets the `occurrence_id` query parameter.
+    pub fn occurrence_id(mut self, occurrence_id: &'a str) -> Self {
+        self.inner = self.inner.occurrence_id(occurrence_id);
+        self
+    }
+
+    /// Sets the `schedule_for_reminder` query parameter.
+    pub fn schedule_for_reminder(mut self, schedule_for_reminder: bool) -> Self {
+        self.inner = self.inner.schedule_for_reminder(schedule_for_reminder);
+        self
+    }
+
+    /// Sets the `cancel_meeting_reminder` query parameter.
+    pub fn cancel_meeting_reminder(mut self, cancel_meeting_reminder: &'a str) -> Self {
+        self.inner = self.inner.cancel_meeting_reminder(cancel_meeting_reminder);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Meetings::update`], set with a builder.
+pub struct MeetingsUpdateRequest<'a> {
+    inner: crate::meetings::MeetingsUpdateRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> MeetingsUpdateRequest<'a> {
+    /// Sets the `occurrence_id` query parameter.
+    pub fn occurrence_id(mut self, occurrence_id: &'a str) -> Self {
+        self.inner = self.inner.occurrence_id(occurrence_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Meetings::registrant`], set with a builder.
+pub struct MeetingsRegistrantRequest<'a> {
+    inner: crate::meetings::MeetingsRegistrantRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> MeetingsRegistrantRequest<'a> {
+    /// Sets the `occurrence_id` query parameter.
+    pub fn occurrence_id(mut self, occurrence_id: &'a str) -> Self {
+        self.inner = self.inner.occurrence_id(occurrence_id);
+        self
+    }
+
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: crate::types::MeetingRegistrantsStatus) -> Self {
+        self.inner = self.inner.status(status);
+        self
+    }
+
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Sets the `page_number` query parameter.
+    pub fn page_number(mut self, page_number: i64) -> Self {
+        self.inner = self.inner.page_number(page_number);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.inner = self.inner.next_page_token(next_page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::Domains>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Meetings::registrant_create`], set with a builder.
+pub struct MeetingsRegistrantCreateRequest<'a> {
+    inner: crate::meetings::MeetingsRegistrantCreateRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> MeetingsRegistrantCreateRequest<'a> {
+    /// Sets the `occurrence_ids` query parameter.
+    pub fn occurrence_ids(mut self, occurrence_ids: &'a str) -> Self {
+        self.inner = self.inner.occurrence_ids(occurrence_ids);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::MeetingRegistrantCreateResponse>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Meetings::meetingregistrantdelete`], set with a builder.
+pub struct MeetingsMeetingregistrantdeleteRequest<'a> {
+    inner: crate::meetings::MeetingsMeetingregistrantdeleteRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> MeetingsMeetingregistrantdeleteRequest<'a> {
+    /// Sets the `occurrence_id` query parameter.
+    pub fn occurrence_id(mut self, occurrence_id: &'a str) -> Self {
+        self.inner = self.inner.occurrence_id(occurrence_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Meetings::registrant_status`], set with a builder.
+pub struct MeetingsRegistrantStatusRequest<'a> {
+    inner: crate::meetings::MeetingsRegistrantStatusRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> MeetingsRegistrantStatusRequest<'a> {
+    /// Sets the `occurrence_id` query parameter.
+    pub fn occurrence_id(mut self, occurrence_id: &'a str) -> Self {
+        self.inner = self.inner.occurrence_id(occurrence_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Meetings::past_participant`], set with a builder.
+pub struct MeetingsPastParticipantRequest<'a> {
+    inner: crate::meetings::MeetingsPastParticipantRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> MeetingsPastParticipantRequest<'a> {
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.inner = self.inner.next_page_token(next_page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::PastMeetingParticipantsResponseAllOf>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::meetings::Meetings`].
+pub struct Meetings {
+    inner: crate::meetings::Meetings,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Meetings {
+    pub(crate) fn new(
+        inner: crate::meetings::Meetings,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Meetings { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::meetings::Meetings::get`].
+    pub fn get(
+        &self,
+        user_id: &str,
+        type_: crate::types::MeetingsType,
+        page_size: i64,
+        next_page_token: &str,
+        page_number: &str,
+    ) -> ClientResult<crate::Response<crate::types::Domains>> {
+        self.runtime.block_on(self.inner.get(
+            user_id,
+            type_,
+            page_size,
+            next_page_token,
+            page_number,
+        ))
+    }
+    /// The blocking version of [`crate::meetings::Meetings::get_request`].
+    pub fn get_request<'a>(&'a self, user_id: &'a str) -> MeetingsGetRequest<'a> {
+        MeetingsGetRequest {
+            inner: self.inner.get_request(user_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::meetings::Meetings::create`].
+    pub fn create(
+        &self,
+        user_id: &str,
+        body: &crate::types::MeetingCreate,
+    ) -> ClientResult<crate::Response<crate::types::MeetingCreateResponseAllOf>> {
+        self.runtime.block_on(self.inner.create(user_id, body))
+    }
+    /// The blocking version of [`crate::meetings::Meetings::meeting`].
+    pub fn meeting(
+        &self,
+        meeting_id: i64,
+        occurrence_id: &str,
+        show_previous_occurrences: bool,
+    ) -> ClientResult<crate::Response<crate::types::MeetingResponseAllOf>> {
+        self.runtime.block_on(self.inner.meeting(
+            meeting_id,
+            occurrence_id,
+            show_previous_occurrences,
+        ))
+    }
+    /// The blocking version of [`crate::meetings::Meetings::meeting_request`].
+    pub fn meeting_request(&self, meeting_id: i64) -> MeetingsMeetingRequest<'_> {
+        MeetingsMeetingRequest {
+            inner: self.inner.meeting_request(meeting_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::meetings::Meetings::delete`].
+    pub fn delete(
+        &self,
+        meeting_id: i64,
+        occurrence_id: &str,
+        schedule_for_reminder: bool,
+        cancel_meeting_reminder: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.delete(
+            meeting_id,
+            occurrence_id,
+            schedule_for_reminder,
+            cancel_meeting_reminder,
+        ))
+    }
+    /// The blocking version of [`crate::meetings::Meetings::delete_request`].
+    pub fn delete_request(&self, meeting_id: i64) -> MeetingsDeleteRequest<'_> {
+        MeetingsDeleteRequest {
+            inner: self.inner.delete_request(meeting_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::meetings::Meetings::update`].
+    pub fn update(
+        &self,
+        meeting_id: i64,
+        occurrence_id: &str,
+        body: &crate::types::MeetingUpdateRequestAllOf,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.update(meeting_id, occurrence_id, body))
+    }
+    /// The blocking version of [`crate::meetings::Meetings::update_request`].
+    pub fn update_request<'a>(
+        &'a self,
+        meeting_id: i64,
+        body: &'a crate::types::MeetingUpdateRequestAllOf,
+    ) -> MeetingsUpdateRequest<'a> {
+        MeetingsUpdateRequest {
+            inner: self.inner.update_request(meeting_id, body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::meetings::Meetings::status`].
+    pub fn status(
+        &self,
+        meeting_id: i64,
+        body: &crate::types::MeetingStatusRequest,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.status(meeting_id, body))
+    }
+    /// The blocking version of [`crate::meetings::Meetings::registrant`].
+    pub fn registrant(
+        &self,
+        meeting_id: i64,
+        occurrence_id: &str,
+        status: crate::types::MeetingRegistrantsStatus,
+        page_size: i64,
+        page_number: i64,
+        next_page_token: &str,
+    ) -> ClientResult<crate::Response<crate::types::Domains>> {
+        self.runtime.block_on(self.inner.registrant(
+            meeting_id,
+            occurrence_id,
+            status,
+            page_size,
+            page_number,
+            next_page_token,
+        ))
+    }
+    /// The blocking version of [`crate::meetings::Meetings::registrant_request`].
+    pub fn registrant_request(&self, meeting_id: i64) -> MeetingsRegistrantRequest<'_> {
+        MeetingsRegistrantRequest {
+            inner: self.inner.registrant_request(meeting_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::meetings::Meetings::registrant_create`].
+    pub fn registrant_create(
+        &self,
+        meeting_id: i64,
+        occurrence_ids: &str,
+    ) -> ClientResult<crate::Response<crate::types::MeetingRegistrantCreateResponse>> {
+        self.runtime
+            .block_on(self.inner.registrant_create(meeting_id, occurrence_ids))
+    }
+    /// The blocking version of [`crate::meetings::Meetings::registrant_create_request`].
+    pub fn registrant_create_request(
+        &self,
+        meeting_id: i64,
+    ) -> MeetingsRegistrantCreateRequest<'_> {
+        MeetingsRegistrantCreateRequest {
+            inner: self.inner.registrant_create_request(meeting_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::meetings::Meetings::meetingregistrantdelete`].
+    pub fn meetingregistrantdelete(
+        &self,
+        occurrence_id: &str,
+        meeting_id: i64,
+        registrant_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.meetingregistrantdelete(
+            occurrence_id,
+            meeting_id,
+            registrant_id,
+        ))
+    }
+    /// The blocking version of [`crate::meetings::Meetings::meetingregistrantdelete_request`].
+    pub fn meetingregistrantdelete_request<'a>(
+        &'a self,
+        meeting_id: i64,
+        registrant_id: &'a str,
+    ) -> MeetingsMeetingregistrantdeleteRequest<'a> {
+        MeetingsMeetingregistrantdeleteRequest {
+            inner: self
+                .inner
+                .meetingregistrantdelete_request(meeting_id, registrant_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::meetings::Meetings::registrant_status`].
+    pub fn registrant_status(
+        &self,
+        meeting_id: i64,
+        occurrence_id: &str,
+        body: &crate::types::RegistrantStatus,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .registrant_status(meeting_id, occurrence_id, body),
+        )
+    }
+    /// The blocking version of [`crate::meetings::Meetings::registrant_status_request`].
+    pub fn registrant_status_request<'a>(
+        &'a self,
+        meeting_id: i64,
+        body: &'a crate::types::RegistrantStatus,
+    ) -> MeetingsRegistrantStatusRequest<'a> {
+        MeetingsRegistrantStatusRequest {
+            inner: self.inner.registrant_status_request(meeting_id, body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::meetings::Meetings::past_details`].
+    pub fn past_details(
+        &self,
+        meeting_uuid: &str,
+    ) -> ClientResult<crate::Response<crate::types::PastMeetingDetailsResponse>> {
+        self.runtime.block_on(self.inner.past_details(meeting_uuid))
+    }
+    /// The blocking version of [`crate::meetings::Meetings::past_participant`].
+    pub fn past_participant(
+        &self,
+        meeting_uuid: &str,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> ClientResult<crate::Response<crate::types::PastMeetingParticipantsResponseAllOf>> {
+        self.runtime.block_on(
+            self.inner
+                .past_participant(meeting_uuid, page_size, next_page_token),
+        )
+    }
+    /// The blocking version of [`crate::meetings::Meetings::past_participant_request`].
+    pub fn past_participant_request<'a>(
+        &'a self,
+        meeting_uuid: &'a str,
+    ) -> MeetingsPastParticipantRequest<'a> {
+        MeetingsPastParticipantRequest {
+            inner: self.inner.past_participant_request(meeting_uuid),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::meetings::Meetings::past`].
+    pub fn past(&self, meeting_id: i64) -> ClientResult<crate::Response<crate::types::Domains>> {
+        self.runtime.block_on(self.inner.past(meeting_id))
+    }
+    /// The blocking version of [`crate::meetings::Meetings::poll`].
+    pub fn poll(&self, meeting_id: i64) -> ClientResult<crate::Response<crate::types::Domains>> {
+        self.runtime.block_on(self.inner.poll(meeting_id))
+    }
+    /// The blocking version of [`crate::meetings::Meetings::poll_create`].
+    pub fn poll_create(
+        &self,
+        meeting_id: i64,
+        body: &crate::types::Poll,
+    ) -> ClientResult<crate::Response<crate::types::MeetingPollGetResponseAllOf>> {
+        self.runtime
+            .block_on(self.inner.poll_create(meeting_id, body))
+    }
+    /// The blocking version of [`crate::meetings::Meetings::poll_get`].
+    pub fn poll_get(
+        &self,
+        meeting_id: i64,
+        poll_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::MeetingPollGetResponseAllOf>> {
+        self.runtime
+            .block_on(self.inner.poll_get(meeting_id, poll_id))
+    }
+    /// The blocking version of [`crate::meetings::Meetings::poll_update`].
+    pub fn poll_update(
+        &self,
+        meeting_id: i64,
+        poll_id: &str,
+        body: &crate::types::Poll,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.poll_update(meeting_id, poll_id, body))
+    }
+    /// The blocking version of [`crate::meetings::Meetings::poll_delete`].
+    pub fn poll_delete(&self, meeting_id: i64, poll_id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.poll_delete(meeting_id, poll_id))
+    }
+    /// The blocking version of [`crate::meetings::Meetings::registrants_questions_get`].
+    pub fn registrants_questions_get(
+        &self,
+        meeting_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::MeetingRegistrantQuestionsData>> {
+        self.runtime
+            .block_on(self.inner.registrants_questions_get(meeting_id))
+    }
+    /// The blocking version of [`crate::meetings::Meetings::registrant_question_update`].
+    pub fn registrant_question_update(
+        &self,
+        meeting_id: i64,
+        body: &crate::types::MeetingRegistrantQuestionsData,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.registrant_question_update(meeting_id, body))
+    }
+    /// The blocking version of [`crate::meetings::Meetings::invitation`].
+    pub fn invitation(
+        &self,
+        meeting_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::MeetingInvitation>> {
+        self.runtime.block_on(self.inner.invitation(meeting_id))
+    }
+    /// The blocking version of [`crate::meetings::Meetings::get_live_stream_details`].
+    pub fn get_live_stream_details(
+        &self,
+        meeting_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::GetLiveStreamDetailsResponse>> {
+        self.runtime
+            .block_on(self.inner.get_live_stream_details(meeting_id))
+    }
+    /// The blocking version of [`crate::meetings::Meetings::live_stream_update`].
+    pub fn live_stream_update(
+        &self,
+        meeting_id: &str,
+        body: &crate::types::MeetingLiveStream,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.live_stream_update(meeting_id, body))
+    }
+    /// The blocking version of [`crate::meetings::Meetings::live_stream_status_update`].
+    pub fn live_stream_status_update(
+        &self,
+        meeting_id: i64,
+        body: &crate::types::MeetingLiveStreamStatus,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.live_stream_status_update(meeting_id, body))
+    }
+    /// The blocking version of [`crate::meetings::Meetings::list_past_polls`].
+    pub fn list_past_polls(
+        &self,
+        meeting_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ReportMeetingPollsResponse>> {
+        self.runtime
+            .block_on(self.inner.list_past_polls(meeting_id))
+    }
+    /// The blocking version of [`crate::meetings::Meetings::add_batch_registrants`].
+    pub fn add_batch_registrants(
+        &self,
+        meeting_id: &str,
+        body: &crate::types::AddBatchRegistrantsRequest,
+    ) -> ClientResult<crate::Response<crate::types::AddBatchRegistrantsResponse>> {
+        self.runtime
+            .block_on(self.inner.add_batch_registrants(meeting_id, body))
+    }
+    /// The blocking version of [`crate::meetings::Meetings::recording_control`].
+    pub fn recording_control(
+        &self,
+        meeting_id: &str,
+        body: &crate::types::InMeetingRecordingControlRequest,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.recording_control(meeting_id, body))
+    }
+    /// The blocking version of [`crate::meetings::Meetings::quality_score`].
+    pub fn quality_score(&self) -> ClientResult<crate::Response<crate::types::Domains>> {
+        self.runtime.block_on(self.inner.quality_score())
+    }
+    /// The blocking version of [`crate::meetings::Meetings::create_batch_polls`].
+    pub fn create_batch_polls(
+        &self,
+        meeting_id: &str,
+        body: &crate::types::CreateBatchPollsRequest,
+    ) -> ClientResult<crate::Response<crate::types::CreateBatchPollsResponse>> {
+        self.runtime
+            .block_on(self.inner.create_batch_polls(meeting_id, body))
+    }
+    /// The blocking version of [`crate::meetings::Meetings::list_template`].
+    pub fn list_template(
+        &self,
+        user_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ListMeetingTemplatesResponseData>> {
+        self.runtime.block_on(self.inner.list_template(user_id))
+    }
+    /// The blocking version of [`crate::meetings::Meetings::invite_links_create`].
+    pub fn invite_links_create(
+        &self,
+        meeting_id: i64,
+        body: &crate::types::InviteLink,
+    ) -> ClientResult<crate::Response<crate::types::InviteLinks>> {
+        self.runtime
+            .block_on(self.inner.invite_links_create(meeting_id, body))
+    }
+}
@@ -0,0 +1,294 @@
+//! A blocking client, for the programs that are not async.
+//!
+//! It has the functions of the async [`Client`](crate::Client), which it runs on a
+//! runtime of its own, so they must not be called from within an async runtime. The
+//! `*_stream` functions are left out, the `*_all` ones returning all the pages at once.
+
+use std::{future::Future, sync::Arc};
+
+pub mod accounts;
+pub mod archiving;
+pub mod billing;
+pub mod chat_channels;
+pub mod chat_channels_account_level;
+pub mod chat_messages;
+pub mod chatbot_messages;
+pub mod cloud_recording;
+pub mod common_area_phones;
+pub mod contacts;
+pub mod dashboards;
+pub mod deprecated_api_endpoints;
+pub mod devices;
+pub mod groups;
+pub mod im_chat;
+pub mod im_groups;
+pub mod meetings;
+pub mod pac;
+pub mod phone;
+pub mod phone_auto_receptionists;
+pub mod phone_blocked_list;
+pub mod phone_call_queues;
+pub mod phone_devices;
+pub mod phone_reports;
+pub mod phone_shared_line_groups;
+pub mod phone_site;
+pub mod reports;
+pub mod roles;
+pub mod rooms;
+pub mod rooms_account;
+pub mod rooms_devices;
+pub mod rooms_location;
+pub mod sip_connected_audio;
+pub mod sip_phone;
+pub mod tracking_field;
+pub mod tsp;
+pub mod users;
+pub mod webinars;
+
+/// A blocking client, sending its requests with an async [`Client`](crate::Client).
+#[derive(Clone)]
+pub struct Client {
+    inner: crate::Client,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Client {
+    /// Wraps an async client, creating the runtime its requests run on.
+    pub fn new(client: crate::Client) -> std::io::Result<Self> {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()?;
+
+        Ok(Client {
+            inner: client,
+            runtime: Arc::new(runtime),
+        })
+    }
+
+    /// Returns the async client the requests are sent with.
+    pub fn inner(&self) -> &crate::Client {
+        &self.inner
+    }
+
+    /// Runs a future to completion on the runtime of the client, for the async
+    /// functions that have no blocking version.
+    pub fn block_on<F: Future>(&self, future: F) -> F::Output {
+        self.runtime.block_on(future)
+    }
+
+    /// The blocking version of [`crate::Client::accounts`].
+    pub fn accounts(&self) -> accounts::Accounts {
+        accounts::Accounts::new(self.inner.accounts(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::archiving`].
+    pub fn archiving(&self) -> archiving::Archiving {
+        archiving::Archiving::new(self.inner.archiving(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::billing`].
+    pub fn billing(&self) -> billing::Billing {
+        billing::Billing::new(self.inner.billing(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::chat_channels`].
+    pub fn chat_channels(&self) -> chat_channels::ChatChannels {
+        chat_channels::ChatChannels::new(self.inner.chat_channels(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::chat_channels_account_level`].
+    pub fn chat_channels_account_level(
+        &self,
+    ) -> chat_channels_account_level::ChatChannelsAccountLevel {
+        chat_channels_account_level::ChatChannelsAccountLevel::new(
+            self.inner.chat_channels_account_level(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::chat_messages`].
+    pub fn chat_messages(&self) -> chat_messages::ChatMessages {
+        chat_messages::ChatMessages::new(self.inner.chat_messages(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::chatbot_messages`].
+    pub fn chatbot_messages(&self) -> chatbot_messages::ChatbotMessages {
+        chatbot_messages::ChatbotMessages::new(self.inner.chatbot_messages(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::cloud_recording`].
+    pub fn cloud_recording(&self) -> cloud_recording::CloudRecording {
+        cloud_recording::CloudRecording::new(self.inner.cloud_recording(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::common_area_phones`].
+    pub fn common_area_phones(&self) -> common_area_phones::CommonAreaPhones {
+        common_area_phones::CommonAreaPhones::new(
+            self.inner.common_area_phones(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::contacts`].
+    pub fn contacts(&self) -> contacts::Contacts {
+        contacts::Contacts::new(self.inner.contacts(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::dashboards`].
+    pub fn dashboards(&self) -> dashboards::Dashboards {
+        dashboards::Dashboards::new(self.inner.dashboards(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::deprecated_api_endpoints`].
+    pub fn deprecated_api_endpoints(&self) -> deprecated_api_endpoints::DeprecatedApiEndpoints {
+        deprecated_api_endpoints::DeprecatedApiEndpoints::new(
+            self.inner.deprecated_api_endpoints(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::devices`].
+    pub fn devices(&self) -> devices::Devices {
+        devices::Devices::new(self.inner.devices(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::groups`].
+    pub fn groups(&self) -> groups::Groups {
+        groups::Groups::new(self.inner.groups(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::im_chat`].
+    pub fn im_chat(&self) -> im_chat::ImChat {
+        im_chat::ImChat::new(self.inner.im_chat(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::im_groups`].
+    pub fn im_groups(&self) -> im_groups::ImGroups {
+        im_groups::ImGroups::new(self.inner.im_groups(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::meetings`].
+    pub fn meetings(&self) -> meetings::Meetings {
+        meetings::Meetings::new(self.inner.meetings(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::pac`].
+    pub fn pac(&self) -> pac::Pac {
+        pac::Pac::new(self.inner.pac(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::phone`].
+    pub fn phone(&self) -> phone::Phone {
+        phone::Phone::new(self.inner.phone(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::phone_auto_receptionists`].
+    pub fn phone_auto_receptionists(&self) -> phone_auto_receptionists::PhoneAutoReceptionists {
+        phone_auto_receptionists::PhoneAutoReceptionists::new(
+            self.inner.phone_auto_receptionists(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::phone_blocked_list`].
+    pub fn phone_blocked_list(&self) -> phone_blocked_list::PhoneBlockedList {
+        phone_blocked_list::PhoneBlockedList::new(
+            self.inner.phone_blocked_list(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::phone_call_queues`].
+    pub fn phone_call_queues(&self) -> phone_call_queues::PhoneCallQueues {
+        phone_call_queues::PhoneCallQueues::new(
+            self.inner.phone_call_queues(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::phone_devices`].
+    pub fn phone_devices(&self) -> phone_devices::PhoneDevices {
+        phone_devices::PhoneDevices::new(self.inner.phone_devices(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::phone_reports`].
+    pub fn phone_reports(&self) -> phone_reports::PhoneReports {
+        phone_reports::PhoneReports::new(self.inner.phone_reports(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::phone_shared_line_groups`].
+    pub fn phone_shared_line_groups(&self) -> phone_shared_line_groups::PhoneSharedLineGroups {
+        phone_shared_line_groups::PhoneSharedLineGroups::new(
+            self.inner.phone_shared_line_groups(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::phone_site`].
+    pub fn phone_site(&self) -> phone_site::PhoneSite {
+        phone_site::PhoneSite::new(self.inner.phone_site(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::reports`].
+    pub fn reports(&self) -> reports::Reports {
+        reports::Reports::new(self.inner.reports(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::roles`].
+    pub fn roles(&self) -> roles::Roles {
+        roles::Roles::new(self.inner.roles(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::rooms`].
+    pub fn rooms(&self) -> rooms::Rooms {
+        rooms::Rooms::new(self.inner.rooms(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::rooms_account`].
+    pub fn rooms_account(&self) -> rooms_account::RoomsAccount {
+        rooms_account::RoomsAccount::new(self.inner.rooms_account(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::rooms_devices`].
+    pub fn rooms_devices(&self) -> rooms_devices::RoomsDevices {
+        rooms_devices::RoomsDevices::new(self.inner.rooms_devices(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::rooms_location`].
+    pub fn rooms_location(&self) -> rooms_location::RoomsLocation {
+        rooms_location::RoomsLocation::new(self.inner.rooms_location(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::sip_connected_audio`].
+    pub fn sip_connected_audio(&self) -> sip_connected_audio::SipConnectedAudio {
+        sip_connected_audio::SipConnectedAudio::new(
+            self.inner.sip_connected_audio(),
+            self.runtime.clone(),
+        )
+    }
+
+    /// The blocking version of [`crate::Client::sip_phone`].
+    pub fn sip_phone(&self) -> sip_phone::SipPhone {
+        sip_phone::SipPhone::new(self.inner.sip_phone(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::tracking_field`].
+    pub fn tracking_field(&self) -> tracking_field::TrackingField {
+        tracking_field::TrackingField::new(self.inner.tracking_field(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::tsp`].
+    pub fn tsp(&self) -> tsp::Tsp {
+        tsp::Tsp::new(self.inner.tsp(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::users`].
+    pub fn users(&self) -> users::Users {
+        users::Users::new(self.inner.users(), self.runtime.clone())
+    }
+
+    /// The blocking version of [`crate::Client::webinars`].
+    pub fn webinars(&self) -> webinars::Webinars {
+        webinars::Webinars::new(self.inner.webinars(), self.runtime.clone())
+    }
+}
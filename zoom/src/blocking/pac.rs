@@ -0,0 +1,23 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::pac::Pac`].
+pub struct Pac {
+    inner: crate::pac::Pac,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Pac {
+    pub(crate) fn new(inner: crate::pac::Pac, runtime: Arc<tokio::runtime::Runtime>) -> Self {
+        Pac { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::pac::Pac::user_pa_cs`].
+    pub fn user_pa_cs(
+        &self,
+        user_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::UserPaCsResponse>> {
+        self.runtime.block_on(self.inner.user_pa_cs(user_id))
+    }
+}
@@ -0,0 +1,1256 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Phone::list_account_numbers`], set with a builder.
+pub struct PhoneListAccountNumbersRequest<'a> {
+    inner: crate::phone::PhoneListAccountNumbersRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> PhoneListAccountNumbersRequest<'a> {
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.inner = self.inner.next_page_token(next_page_token);
+        self
+    }
+
+    /// Sets the `type` query parameter.
+    pub fn type_(mut self, type_: crate::types::ListAccountPhoneNumbersType) -> Self {
+        self.inner = self.inner.type_(type_);
+        self
+    }
+
+    /// Sets the `extension_type` query parameter.
+    pub fn extension_type(mut self, extension_type: crate::types::ExtensionType) -> Self {
+        self.inner = self.inner.extension_type(extension_type);
+        self
+    }
+
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Sets the `number_type` query parameter.
+    pub fn number_type(mut self, number_type: crate::types::Type) -> Self {
+        self.inner = self.inner.number_type(number_type);
+        self
+    }
+
+    /// Sets the `pending_numbers` query parameter.
+    pub fn pending_numbers(mut self, pending_numbers: bool) -> Self {
+        self.inner = self.inner.pending_numbers(pending_numbers);
+        self
+    }
+
+    /// Sets the `site_id` query parameter.
+    pub fn site_id(mut self, site_id: &'a str) -> Self {
+        self.inner = self.inner.site_id(site_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(
+        self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ListAccountPhoneNumbersResponse>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Phone::list_setting_templates`], set with a builder.
+pub struct PhoneListSettingTemplatesRequest<'a> {
+    inner: crate::phone::PhoneListSettingTemplatesRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> PhoneListSettingTemplatesRequest<'a> {
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.inner = self.inner.next_page_token(next_page_token);
+        self
+    }
+
+    /// Sets the `site_id` query parameter.
+    pub fn site_id(mut self, site_id: &'a str) -> Self {
+        self.inner = self.inner.site_id(site_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Templates>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Phone::list_locations`], set with a builder.
+pub struct PhoneListLocationsRequest<'a> {
+    inner: crate::phone::PhoneListLocationsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> PhoneListLocationsRequest<'a> {
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.inner = self.inner.next_page_token(next_page_token);
+        self
+    }
+
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::ListLocationsResponse>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Phone::list_sip_groups`], set with a builder.
+pub struct PhoneListSipGroupsRequest<'a> {
+    inner: crate::phone::PhoneListSipGroupsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> PhoneListSipGroupsRequest<'a> {
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.inner = self.inner.next_page_token(next_page_token);
+        self
+    }
+
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::SipGroups>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Phone::get_setting_template`], set with a builder.
+pub struct PhoneGetSettingTemplateRequest<'a> {
+    inner: crate::phone::PhoneGetSettingTemplateRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> PhoneGetSettingTemplateRequest<'a> {
+    /// Sets the `custom_query_fields` query parameter.
+    pub fn custom_query_fields(mut self, custom_query_fields: &'a str) -> Self {
+        self.inner = self.inner.custom_query_fields(custom_query_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::GetSettingTemplateResponse>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Phone::user_call_logs`], set with a builder.
+pub struct PhoneUserCallLogsRequest<'a> {
+    inner: crate::phone::PhoneUserCallLogsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> PhoneUserCallLogsRequest<'a> {
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Sets the `from` query parameter.
+    pub fn from(mut self, from: chrono::NaiveDate) -> Self {
+        self.inner = self.inner.from(from);
+        self
+    }
+
+    /// Sets the `to` query parameter.
+    pub fn to(mut self, to: chrono::NaiveDate) -> Self {
+        self.inner = self.inner.to(to);
+        self
+    }
+
+    /// Sets the `type` query parameter.
+    pub fn type_(mut self, type_: crate::types::PhoneUserCallLogsType) -> Self {
+        self.inner = self.inner.type_(type_);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.inner = self.inner.next_page_token(next_page_token);
+        self
+    }
+
+    /// Sets the `phone_number` query parameter.
+    pub fn phone_number(mut self, phone_number: &'a str) -> Self {
+        self.inner = self.inner.phone_number(phone_number);
+        self
+    }
+
+    /// Sets the `time_type` query parameter.
+    pub fn time_type(mut self, time_type: crate::types::TimeType) -> Self {
+        self.inner = self.inner.time_type(time_type);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::CallLogs>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Phone::user_recordings`], set with a builder.
+pub struct PhoneUserRecordingsRequest<'a> {
+    inner: crate::phone::PhoneUserRecordingsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> PhoneUserRecordingsRequest<'a> {
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.inner = self.inner.next_page_token(next_page_token);
+        self
+    }
+
+    /// Sets the `from` query parameter.
+    pub fn from(mut self, from: chrono::NaiveDate) -> Self {
+        self.inner = self.inner.from(from);
+        self
+    }
+
+    /// Sets the `to` query parameter.
+    pub fn to(mut self, to: chrono::NaiveDate) -> Self {
+        self.inner = self.inner.to(to);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Recordings>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Phone::user_voice_mails`], set with a builder.
+pub struct PhoneUserVoiceMailsRequest<'a> {
+    inner: crate::phone::PhoneUserVoiceMailsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> PhoneUserVoiceMailsRequest<'a> {
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: crate::types::PhoneUserVoiceMailsStatus) -> Self {
+        self.inner = self.inner.status(status);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.inner = self.inner.next_page_token(next_page_token);
+        self
+    }
+
+    /// Sets the `from` query parameter.
+    pub fn from(mut self, from: chrono::NaiveDate) -> Self {
+        self.inner = self.inner.from(from);
+        self
+    }
+
+    /// Sets the `to` query parameter.
+    pub fn to(mut self, to: chrono::NaiveDate) -> Self {
+        self.inner = self.inner.to(to);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::VoiceMails>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Phone::delete_user_setting`], set with a builder.
+pub struct PhoneDeleteUserSettingRequest<'a> {
+    inner: crate::phone::PhoneDeleteUserSettingRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> PhoneDeleteUserSettingRequest<'a> {
+    /// Sets the `shared_id` query parameter.
+    pub fn shared_id(mut self, shared_id: &'a str) -> Self {
+        self.inner = self.inner.shared_id(shared_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Phone::account_call_logs`], set with a builder.
+pub struct PhoneAccountCallLogsRequest<'a> {
+    inner: crate::phone::PhoneAccountCallLogsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> PhoneAccountCallLogsRequest<'a> {
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Sets the `from` query parameter.
+    pub fn from(mut self, from: &'a str) -> Self {
+        self.inner = self.inner.from(from);
+        self
+    }
+
+    /// Sets the `to` query parameter.
+    pub fn to(mut self, to: &'a str) -> Self {
+        self.inner = self.inner.to(to);
+        self
+    }
+
+    /// Sets the `type` query parameter.
+    pub fn type_(mut self, type_: &'a str) -> Self {
+        self.inner = self.inner.type_(type_);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.inner = self.inner.next_page_token(next_page_token);
+        self
+    }
+
+    /// Sets the `path` query parameter.
+    pub fn path(mut self, path: &'a str) -> Self {
+        self.inner = self.inner.path(path);
+        self
+    }
+
+    /// Sets the `time_type` query parameter.
+    pub fn time_type(mut self, time_type: crate::types::TimeType) -> Self {
+        self.inner = self.inner.time_type(time_type);
+        self
+    }
+
+    /// Sets the `site_id` query parameter.
+    pub fn site_id(mut self, site_id: &'a str) -> Self {
+        self.inner = self.inner.site_id(site_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::AccountCallLogsResponse>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Phone::get_recordings`], set with a builder.
+pub struct PhoneGetRecordingsRequest<'a> {
+    inner: crate::phone::PhoneGetRecordingsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> PhoneGetRecordingsRequest<'a> {
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.inner = self.inner.next_page_token(next_page_token);
+        self
+    }
+
+    /// Sets the `from` query parameter.
+    pub fn from(mut self, from: &'a str) -> Self {
+        self.inner = self.inner.from(from);
+        self
+    }
+
+    /// Sets the `to` query parameter.
+    pub fn to(mut self, to: &'a str) -> Self {
+        self.inner = self.inner.to(to);
+        self
+    }
+
+    /// Sets the `owner_type` query parameter.
+    pub fn owner_type(mut self, owner_type: &'a str) -> Self {
+        self.inner = self.inner.owner_type(owner_type);
+        self
+    }
+
+    /// Sets the `recording_type` query parameter.
+    pub fn recording_type(mut self, recording_type: &'a str) -> Self {
+        self.inner = self.inner.recording_type(recording_type);
+        self
+    }
+
+    /// Sets the `site_id` query parameter.
+    pub fn site_id(mut self, site_id: &'a str) -> Self {
+        self.inner = self.inner.site_id(site_id);
+        self
+    }
+
+    /// Sets the `query_date_type` query parameter.
+    pub fn query_date_type(mut self, query_date_type: crate::types::QueryDateType) -> Self {
+        self.inner = self.inner.query_date_type(query_date_type);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(
+        self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GetPhoneRecordingsResponse>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Phone::list_byocsip_trunk`], set with a builder.
+pub struct PhoneListByocsipTrunkRequest<'a> {
+    inner: crate::phone::PhoneListByocsipTrunkRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> PhoneListByocsipTrunkRequest<'a> {
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.inner = self.inner.next_page_token(next_page_token);
+        self
+    }
+
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::ByocSipTrunk>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Phone::list_external_contacts`], set with a builder.
+pub struct PhoneListExternalContactsRequest<'a> {
+    inner: crate::phone::PhoneListExternalContactsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> PhoneListExternalContactsRequest<'a> {
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.inner = self.inner.next_page_token(next_page_token);
+        self
+    }
+
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::ExternalContacts>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Phone::list_users`], set with a builder.
+pub struct PhoneListUsersRequest<'a> {
+    inner: crate::phone::PhoneListUsersRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> PhoneListUsersRequest<'a> {
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.inner = self.inner.next_page_token(next_page_token);
+        self
+    }
+
+    /// Sets the `site_id` query parameter.
+    pub fn site_id(mut self, site_id: &'a str) -> Self {
+        self.inner = self.inner.site_id(site_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::ListPhoneUsersResponse>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::phone::Phone`].
+pub struct Phone {
+    inner: crate::phone::Phone,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Phone {
+    pub(crate) fn new(inner: crate::phone::Phone, runtime: Arc<tokio::runtime::Runtime>) -> Self {
+        Phone { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::phone::Phone::set_up_account`].
+    pub fn set_up_account(
+        &self,
+        account_id: &str,
+        body: &crate::types::SetUpAccountRequest,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.set_up_account(account_id, body))
+    }
+    /// The blocking version of [`crate::phone::Phone::list_account_numbers`].
+    pub fn list_account_numbers(
+        &self,
+        next_page_token: &str,
+        type_: crate::types::ListAccountPhoneNumbersType,
+        extension_type: crate::types::ExtensionType,
+        page_size: i64,
+        number_type: crate::types::Type,
+        pending_numbers: bool,
+        site_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ListAccountPhoneNumbersResponse>>> {
+        self.runtime.block_on(self.inner.list_account_numbers(
+            next_page_token,
+            type_,
+            extension_type,
+            page_size,
+            number_type,
+            pending_numbers,
+            site_id,
+        ))
+    }
+    /// The blocking version of [`crate::phone::Phone::list_account_numbers_request`].
+    pub fn list_account_numbers_request(&self) -> PhoneListAccountNumbersRequest<'_> {
+        PhoneListAccountNumbersRequest {
+            inner: self.inner.list_account_numbers_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::phone::Phone::list_all_account_numbers`].
+    pub fn list_all_account_numbers(
+        &self,
+        type_: crate::types::ListAccountPhoneNumbersType,
+        extension_type: crate::types::ExtensionType,
+        number_type: crate::types::Type,
+        pending_numbers: bool,
+        site_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ListAccountPhoneNumbersResponse>>> {
+        self.runtime.block_on(self.inner.list_all_account_numbers(
+            type_,
+            extension_type,
+            number_type,
+            pending_numbers,
+            site_id,
+        ))
+    }
+    /// The blocking version of [`crate::phone::Phone::user`].
+    pub fn user(
+        &self,
+        user_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::PhoneUserResponse>> {
+        self.runtime.block_on(self.inner.user(user_id))
+    }
+    /// The blocking version of [`crate::phone::Phone::update_user_profile`].
+    pub fn update_user_profile(
+        &self,
+        user_id: &str,
+        body: &crate::types::UpdateUserProfileRequest,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.update_user_profile(user_id, body))
+    }
+    /// The blocking version of [`crate::phone::Phone::setting`].
+    pub fn setting(
+        &self,
+        _account_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::PhoneSettingResponse>> {
+        self.runtime.block_on(self.inner.setting(_account_id))
+    }
+    /// The blocking version of [`crate::phone::Phone::update_settings`].
+    pub fn update_settings(
+        &self,
+        _account_id: &str,
+        body: &crate::types::UpdatePhoneSettingsRequest,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.update_settings(_account_id, body))
+    }
+    /// The blocking version of [`crate::phone::Phone::user_settings`].
+    pub fn user_settings(
+        &self,
+        user_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::PhoneUserSettingsResponse>> {
+        self.runtime.block_on(self.inner.user_settings(user_id))
+    }
+    /// The blocking version of [`crate::phone::Phone::list_setting_templates`].
+    pub fn list_setting_templates(
+        &self,
+        page_size: i64,
+        next_page_token: &str,
+        site_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Templates>>> {
+        self.runtime.block_on(self.inner.list_setting_templates(
+            page_size,
+            next_page_token,
+            site_id,
+        ))
+    }
+    /// The blocking version of [`crate::phone::Phone::list_setting_templates_request`].
+    pub fn list_setting_templates_request(&self) -> PhoneListSettingTemplatesRequest<'_> {
+        PhoneListSettingTemplatesRequest {
+            inner: self.inner.list_setting_templates_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::phone::Phone::list_all_setting_templates`].
+    pub fn list_all_setting_templates(
+        &self,
+        site_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Templates>>> {
+        self.runtime
+            .block_on(self.inner.list_all_setting_templates(site_id))
+    }
+    /// The blocking version of [`crate::phone::Phone::add_setting_template`].
+    pub fn add_setting_template(
+        &self,
+        body: &crate::types::AddSettingTemplateRequest,
+    ) -> ClientResult<crate::Response<crate::types::AddSettingTemplateResponse>> {
+        self.runtime.block_on(self.inner.add_setting_template(body))
+    }
+    /// The blocking version of [`crate::phone::Phone::batch_add_locations`].
+    pub fn batch_add_locations(
+        &self,
+        body: &crate::types::BatchAddLocationsRequest,
+    ) -> ClientResult<crate::Response<Vec<crate::types::BatchAddLocationsResponse>>> {
+        self.runtime.block_on(self.inner.batch_add_locations(body))
+    }
+    /// The blocking version of [`crate::phone::Phone::list_locations`].
+    pub fn list_locations(
+        &self,
+        next_page_token: &str,
+        page_size: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ListLocationsResponse>>> {
+        self.runtime
+            .block_on(self.inner.list_locations(next_page_token, page_size))
+    }
+    /// The blocking version of [`crate::phone::Phone::list_locations_request`].
+    pub fn list_locations_request(&self) -> PhoneListLocationsRequest<'_> {
+        PhoneListLocationsRequest {
+            inner: self.inner.list_locations_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::phone::Phone::list_all_locations`].
+    pub fn list_all_locations(
+        &self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ListLocationsResponse>>> {
+        self.runtime.block_on(self.inner.list_all_locations())
+    }
+    /// The blocking version of [`crate::phone::Phone::add_location`].
+    pub fn add_location(
+        &self,
+        body: &crate::types::AddLocationRequest,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Site>>> {
+        self.runtime.block_on(self.inner.add_location(body))
+    }
+    /// The blocking version of [`crate::phone::Phone::get_location`].
+    pub fn get_location(
+        &self,
+        location_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::GetLocationResponse>> {
+        self.runtime.block_on(self.inner.get_location(location_id))
+    }
+    /// The blocking version of [`crate::phone::Phone::delete_location`].
+    pub fn delete_location(&self, location_id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete_location(location_id))
+    }
+    /// The blocking version of [`crate::phone::Phone::update_location`].
+    pub fn update_location(
+        &self,
+        location_id: &str,
+        body: &crate::types::UpdateLocationRequest,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.update_location(location_id, body))
+    }
+    /// The blocking version of [`crate::phone::Phone::list_sip_groups`].
+    pub fn list_sip_groups(
+        &self,
+        next_page_token: &str,
+        page_size: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::SipGroups>>> {
+        self.runtime
+            .block_on(self.inner.list_sip_groups(next_page_token, page_size))
+    }
+    /// The blocking version of [`crate::phone::Phone::list_sip_groups_request`].
+    pub fn list_sip_groups_request(&self) -> PhoneListSipGroupsRequest<'_> {
+        PhoneListSipGroupsRequest {
+            inner: self.inner.list_sip_groups_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::phone::Phone::list_all_sip_groups`].
+    pub fn list_all_sip_groups(
+        &self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::SipGroups>>> {
+        self.runtime.block_on(self.inner.list_all_sip_groups())
+    }
+    /// The blocking version of [`crate::phone::Phone::get_setting_template`].
+    pub fn get_setting_template(
+        &self,
+        template_id: &str,
+        custom_query_fields: &str,
+    ) -> ClientResult<crate::Response<crate::types::GetSettingTemplateResponse>> {
+        self.runtime.block_on(
+            self.inner
+                .get_setting_template(template_id, custom_query_fields),
+        )
+    }
+    /// The blocking version of [`crate::phone::Phone::get_setting_template_request`].
+    pub fn get_setting_template_request<'a>(
+        &'a self,
+        template_id: &'a str,
+    ) -> PhoneGetSettingTemplateRequest<'a> {
+        PhoneGetSettingTemplateRequest {
+            inner: self.inner.get_setting_template_request(template_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::phone::Phone::update_setting_template`].
+    pub fn update_setting_template(
+        &self,
+        template_id: &str,
+        body: &crate::types::UpdateSettingTemplateRequest,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.update_setting_template(template_id, body))
+    }
+    /// The blocking version of [`crate::phone::Phone::user_call_logs`].
+    pub fn user_call_logs(
+        &self,
+        user_id: &str,
+        page_size: i64,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        type_: crate::types::PhoneUserCallLogsType,
+        next_page_token: &str,
+        phone_number: &str,
+        time_type: crate::types::TimeType,
+    ) -> ClientResult<crate::Response<Vec<crate::types::CallLogs>>> {
+        self.runtime.block_on(self.inner.user_call_logs(
+            user_id,
+            page_size,
+            from,
+            to,
+            type_,
+            next_page_token,
+            phone_number,
+            time_type,
+        ))
+    }
+    /// The blocking version of [`crate::phone::Phone::user_call_logs_request`].
+    pub fn user_call_logs_request<'a>(&'a self, user_id: &'a str) -> PhoneUserCallLogsRequest<'a> {
+        PhoneUserCallLogsRequest {
+            inner: self.inner.user_call_logs_request(user_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::phone::Phone::get_all_user_call_logs`].
+    pub fn get_all_user_call_logs(
+        &self,
+        user_id: &str,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        type_: crate::types::PhoneUserCallLogsType,
+        phone_number: &str,
+        time_type: crate::types::TimeType,
+    ) -> ClientResult<crate::Response<Vec<crate::types::CallLogs>>> {
+        self.runtime.block_on(self.inner.get_all_user_call_logs(
+            user_id,
+            from,
+            to,
+            type_,
+            phone_number,
+            time_type,
+        ))
+    }
+    /// The blocking version of [`crate::phone::Phone::user_recordings`].
+    pub fn user_recordings(
+        &self,
+        user_id: &str,
+        page_size: i64,
+        next_page_token: &str,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Recordings>>> {
+        self.runtime.block_on(self.inner.user_recordings(
+            user_id,
+            page_size,
+            next_page_token,
+            from,
+            to,
+        ))
+    }
+    /// The blocking version of [`crate::phone::Phone::user_recordings_request`].
+    pub fn user_recordings_request<'a>(
+        &'a self,
+        user_id: &'a str,
+    ) -> PhoneUserRecordingsRequest<'a> {
+        PhoneUserRecordingsRequest {
+            inner: self.inner.user_recordings_request(user_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::phone::Phone::get_all_user_recordings`].
+    pub fn get_all_user_recordings(
+        &self,
+        user_id: &str,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Recordings>>> {
+        self.runtime
+            .block_on(self.inner.get_all_user_recordings(user_id, from, to))
+    }
+    /// The blocking version of [`crate::phone::Phone::user_voice_mails`].
+    pub fn user_voice_mails(
+        &self,
+        user_id: &str,
+        page_size: i64,
+        status: crate::types::PhoneUserVoiceMailsStatus,
+        next_page_token: &str,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> ClientResult<crate::Response<Vec<crate::types::VoiceMails>>> {
+        self.runtime.block_on(self.inner.user_voice_mails(
+            user_id,
+            page_size,
+            status,
+            next_page_token,
+            from,
+            to,
+        ))
+    }
+    /// The blocking version of [`crate::phone::Phone::user_voice_mails_request`].
+    pub fn user_voice_mails_request<'a>(
+        &'a self,
+        user_id: &'a str,
+    ) -> PhoneUserVoiceMailsRequest<'a> {
+        PhoneUserVoiceMailsRequest {
+            inner: self.inner.user_voice_mails_request(user_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::phone::Phone::get_all_user_voice_mails`].
+    pub fn get_all_user_voice_mails(
+        &self,
+        user_id: &str,
+        status: crate::types::PhoneUserVoiceMailsStatus,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> ClientResult<crate::Response<Vec<crate::types::VoiceMails>>> {
+        self.runtime.block_on(
+            self.inner
+                .get_all_user_voice_mails(user_id, status, from, to),
+        )
+    }
+    /// The blocking version of [`crate::phone::Phone::add_user_setting`].
+    pub fn add_user_setting(
+        &self,
+        user_id: &str,
+        setting_type: &str,
+        body: &crate::types::AddUserSettingRequest,
+    ) -> ClientResult<crate::Response<crate::types::AddUserSettingResponse>> {
+        self.runtime
+            .block_on(self.inner.add_user_setting(user_id, setting_type, body))
+    }
+    /// The blocking version of [`crate::phone::Phone::delete_user_setting`].
+    pub fn delete_user_setting(
+        &self,
+        user_id: &str,
+        setting_type: &str,
+        shared_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .delete_user_setting(user_id, setting_type, shared_id),
+        )
+    }
+    /// The blocking version of [`crate::phone::Phone::delete_user_setting_request`].
+    pub fn delete_user_setting_request<'a>(
+        &'a self,
+        user_id: &'a str,
+        setting_type: &'a str,
+    ) -> PhoneDeleteUserSettingRequest<'a> {
+        PhoneDeleteUserSettingRequest {
+            inner: self
+                .inner
+                .delete_user_setting_request(user_id, setting_type),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::phone::Phone::update_user_setting`].
+    pub fn update_user_setting(
+        &self,
+        setting_type: &str,
+        user_id: &str,
+        body: &crate::types::UpdateUserSettingRequest,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.update_user_setting(setting_type, user_id, body))
+    }
+    /// The blocking version of [`crate::phone::Phone::account_call_logs`].
+    pub fn account_call_logs(
+        &self,
+        page_size: i64,
+        from: &str,
+        to: &str,
+        type_: &str,
+        next_page_token: &str,
+        path: &str,
+        time_type: crate::types::TimeType,
+        site_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::AccountCallLogsResponse>>> {
+        self.runtime.block_on(self.inner.account_call_logs(
+            page_size,
+            from,
+            to,
+            type_,
+            next_page_token,
+            path,
+            time_type,
+            site_id,
+        ))
+    }
+    /// The blocking version of [`crate::phone::Phone::account_call_logs_request`].
+    pub fn account_call_logs_request(&self) -> PhoneAccountCallLogsRequest<'_> {
+        PhoneAccountCallLogsRequest {
+            inner: self.inner.account_call_logs_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::phone::Phone::get_all_account_call_logs`].
+    pub fn get_all_account_call_logs(
+        &self,
+        from: &str,
+        to: &str,
+        type_: &str,
+        path: &str,
+        time_type: crate::types::TimeType,
+        site_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::AccountCallLogsResponse>>> {
+        self.runtime.block_on(
+            self.inner
+                .get_all_account_call_logs(from, to, type_, path, time_type, site_id),
+        )
+    }
+    /// The blocking version of [`crate::phone::Phone::assign_number`].
+    pub fn assign_number(
+        &self,
+        user_id: &str,
+        body: &crate::types::AddByocNumberResponse,
+    ) -> ClientResult<crate::Response<crate::types::AddByocNumberResponse>> {
+        self.runtime
+            .block_on(self.inner.assign_number(user_id, body))
+    }
+    /// The blocking version of [`crate::phone::Phone::unassign_number`].
+    pub fn unassign_number(
+        &self,
+        user_id: &str,
+        phone_number_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.unassign_number(user_id, phone_number_id))
+    }
+    /// The blocking version of [`crate::phone::Phone::assign_calling_plan`].
+    pub fn assign_calling_plan(
+        &self,
+        user_id: &str,
+        body: &crate::types::AssignCallingPlanRequest,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.assign_calling_plan(user_id, body))
+    }
+    /// The blocking version of [`crate::phone::Phone::unassign_calling_plan`].
+    pub fn unassign_calling_plan(
+        &self,
+        user_id: &str,
+        type_: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.unassign_calling_plan(user_id, type_))
+    }
+    /// The blocking version of [`crate::phone::Phone::get_recordings`].
+    pub fn get_recordings(
+        &self,
+        page_size: i64,
+        next_page_token: &str,
+        from: &str,
+        to: &str,
+        owner_type: &str,
+        recording_type: &str,
+        site_id: &str,
+        query_date_type: crate::types::QueryDateType,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GetPhoneRecordingsResponse>>> {
+        self.runtime.block_on(self.inner.get_recordings(
+            page_size,
+            next_page_token,
+            from,
+            to,
+            owner_type,
+            recording_type,
+            site_id,
+            query_date_type,
+        ))
+    }
+    /// The blocking version of [`crate::phone::Phone::get_recordings_request`].
+    pub fn get_recordings_request(&self) -> PhoneGetRecordingsRequest<'_> {
+        PhoneGetRecordingsRequest {
+            inner: self.inner.get_recordings_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::phone::Phone::get_all_recordings`].
+    pub fn get_all_recordings(
+        &self,
+        from: &str,
+        to: &str,
+        owner_type: &str,
+        recording_type: &str,
+        site_id: &str,
+        query_date_type: crate::types::QueryDateType,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GetPhoneRecordingsResponse>>> {
+        self.runtime.block_on(self.inner.get_all_recordings(
+            from,
+            to,
+            owner_type,
+            recording_type,
+            site_id,
+            query_date_type,
+        ))
+    }
+    /// The blocking version of [`crate::phone::Phone::list_byocsip_trunk`].
+    pub fn list_byocsip_trunk(
+        &self,
+        next_page_token: &str,
+        page_size: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ByocSipTrunk>>> {
+        self.runtime
+            .block_on(self.inner.list_byocsip_trunk(next_page_token, page_size))
+    }
+    /// The blocking version of [`crate::phone::Phone::list_byocsip_trunk_request`].
+    pub fn list_byocsip_trunk_request(&self) -> PhoneListByocsipTrunkRequest<'_> {
+        PhoneListByocsipTrunkRequest {
+            inner: self.inner.list_byocsip_trunk_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::phone::Phone::list_all_byocsip_trunk`].
+    pub fn list_all_byocsip_trunk(
+        &self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ByocSipTrunk>>> {
+        self.runtime.block_on(self.inner.list_all_byocsip_trunk())
+    }
+    /// The blocking version of [`crate::phone::Phone::post_sip_trunk`].
+    pub fn post_sip_trunk(
+        &self,
+        account_id: &str,
+        body: &crate::types::PostPhoneSipTrunkRequest,
+    ) -> ClientResult<crate::Response<crate::types::PostPhoneSipTrunkRequest>> {
+        self.runtime
+            .block_on(self.inner.post_sip_trunk(account_id, body))
+    }
+    /// The blocking version of [`crate::phone::Phone::update_sip_trunk`].
+    pub fn update_sip_trunk(
+        &self,
+        sip_trunk_id: &str,
+        account_id: &str,
+        body: &crate::types::UpdatePhoneSipTrunkRequest,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.update_sip_trunk(sip_trunk_id, account_id, body))
+    }
+    /// The blocking version of [`crate::phone::Phone::list_external_contacts`].
+    pub fn list_external_contacts(
+        &self,
+        next_page_token: &str,
+        page_size: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ExternalContacts>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_external_contacts(next_page_token, page_size),
+        )
+    }
+    /// The blocking version of [`crate::phone::Phone::list_external_contacts_request`].
+    pub fn list_external_contacts_request(&self) -> PhoneListExternalContactsRequest<'_> {
+        PhoneListExternalContactsRequest {
+            inner: self.inner.list_external_contacts_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::phone::Phone::list_all_external_contacts`].
+    pub fn list_all_external_contacts(
+        &self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ExternalContacts>>> {
+        self.runtime
+            .block_on(self.inner.list_all_external_contacts())
+    }
+    /// The blocking version of [`crate::phone::Phone::add_external_contact`].
+    pub fn add_external_contact(
+        &self,
+        body: &crate::types::AddExternalContactRequest,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.add_external_contact(body))
+    }
+    /// The blocking version of [`crate::phone::Phone::get_external_contact`].
+    pub fn get_external_contact(
+        &self,
+        external_contact_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ExternalContacts>> {
+        self.runtime
+            .block_on(self.inner.get_external_contact(external_contact_id))
+    }
+    /// The blocking version of [`crate::phone::Phone::delete_external_contact`].
+    pub fn delete_external_contact(
+        &self,
+        external_contact_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete_external_contact(external_contact_id))
+    }
+    /// The blocking version of [`crate::phone::Phone::update_external_contact`].
+    pub fn update_external_contact(
+        &self,
+        external_contact_id: &str,
+        body: &crate::types::UpdateExternalContactRequest,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .update_external_contact(external_contact_id, body),
+        )
+    }
+    /// The blocking version of [`crate::phone::Phone::get_number_details`].
+    pub fn get_number_details(
+        &self,
+        number_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::GetPhoneNumberDetailsResponse>> {
+        self.runtime
+            .block_on(self.inner.get_number_details(number_id))
+    }
+    /// The blocking version of [`crate::phone::Phone::update_number_details`].
+    pub fn update_number_details(
+        &self,
+        number_id: &str,
+        body: &crate::types::UpdatePhoneNumberDetailsRequest,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.update_number_details(number_id, body))
+    }
+    /// The blocking version of [`crate::phone::Phone::change_main_company_number`].
+    pub fn change_main_company_number(
+        &self,
+        body: &crate::types::ChangeMainCompanyNumberRequest,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.change_main_company_number(body))
+    }
+    /// The blocking version of [`crate::phone::Phone::list_calling_plan`].
+    pub fn list_calling_plan(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::ListCallingPlansResponseData>> {
+        self.runtime.block_on(self.inner.list_calling_plan())
+    }
+    /// The blocking version of [`crate::phone::Phone::list_users`].
+    pub fn list_users(
+        &self,
+        page_size: i64,
+        next_page_token: &str,
+        site_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ListPhoneUsersResponse>>> {
+        self.runtime
+            .block_on(self.inner.list_users(page_size, next_page_token, site_id))
+    }
+    /// The blocking version of [`crate::phone::Phone::list_users_request`].
+    pub fn list_users_request(&self) -> PhoneListUsersRequest<'_> {
+        PhoneListUsersRequest {
+            inner: self.inner.list_users_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::phone::Phone::list_all_users`].
+    pub fn list_all_users(
+        &self,
+        site_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ListPhoneUsersResponse>>> {
+        self.runtime.block_on(self.inner.list_all_users(site_id))
+    }
+    /// The blocking version of [`crate::phone::Phone::get_call_log_details`].
+    pub fn get_call_log_details(
+        &self,
+        call_log_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::GetCallLogDetailsResponse>> {
+        self.runtime
+            .block_on(self.inner.get_call_log_details(call_log_id))
+    }
+    /// The blocking version of [`crate::phone::Phone::delete_call_log`].
+    pub fn delete_call_log(
+        &self,
+        user_id: &str,
+        call_log_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete_call_log(user_id, call_log_id))
+    }
+    /// The blocking version of [`crate::phone::Phone::add_byoc_number`].
+    pub fn add_byoc_number(
+        &self,
+        body: &crate::types::AddByocNumberRequest,
+    ) -> ClientResult<crate::Response<crate::types::AddByocNumberResponse>> {
+        self.runtime.block_on(self.inner.add_byoc_number(body))
+    }
+    /// The blocking version of [`crate::phone::Phone::delete_voicemail`].
+    pub fn delete_voicemail(&self, voicemail_id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete_voicemail(voicemail_id))
+    }
+}
@@ -0,0 +1,70 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::phone_auto_receptionists::PhoneAutoReceptionists`].
+pub struct PhoneAutoReceptionists {
+    inner: crate::phone_auto_receptionists::PhoneAutoReceptionists,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl PhoneAutoReceptionists {
+    pub(crate) fn new(
+        inner: crate::phone_auto_receptionists::PhoneAutoReceptionists,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        PhoneAutoReceptionists { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::phone_auto_receptionists::PhoneAutoReceptionists::update_auto_receptionist`].
+    pub fn update_auto_receptionist(
+        &self,
+        auto_receptionist_id: &str,
+        body: &crate::types::UpdateAutoReceptionistRequest,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .update_auto_receptionist(auto_receptionist_id, body),
+        )
+    }
+    /// The blocking version of [`crate::phone_auto_receptionists::PhoneAutoReceptionists::assign_phone_numbers_auto_receptionist`].
+    pub fn assign_phone_numbers_auto_receptionist(
+        &self,
+        auto_receptionist_id: &str,
+        body: &crate::types::AddByocNumberResponse,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .assign_phone_numbers_auto_receptionist(auto_receptionist_id, body),
+        )
+    }
+    /// The blocking version of [`crate::phone_auto_receptionists::PhoneAutoReceptionists::unassign_all_phone_nums_auto_receptionist`].
+    pub fn unassign_all_phone_nums_auto_receptionist(
+        &self,
+        auto_receptionist_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .unassign_all_phone_nums_auto_receptionist(auto_receptionist_id),
+        )
+    }
+    /// The blocking version of [`crate::phone_auto_receptionists::PhoneAutoReceptionists::unassign_phone_num_auto_receptionist`].
+    pub fn unassign_phone_num_auto_receptionist(
+        &self,
+        auto_receptionist_id: &str,
+        phone_number_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .unassign_phone_num_auto_receptionist(auto_receptionist_id, phone_number_id),
+        )
+    }
+    /// The blocking version of [`crate::phone_auto_receptionists::PhoneAutoReceptionists::add_auto_receptionist`].
+    pub fn add_auto_receptionist(
+        &self,
+        body: &crate::types::AddAutoReceptionistRequest,
+    ) -> ClientResult<crate::Response<crate::types::AddAutoReceptionistResponse>> {
+        self.runtime
+            .block_on(self.inner.add_auto_receptionist(body))
+    }
+}
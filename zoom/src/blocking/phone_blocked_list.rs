@@ -0,0 +1,96 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`PhoneBlockedList::list_blocked`], set with a builder.
+pub struct PhoneBlockedListListBlockedRequest<'a> {
+    inner: crate::phone_blocked_list::PhoneBlockedListListBlockedRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> PhoneBlockedListListBlockedRequest<'a> {
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.inner = self.inner.next_page_token(next_page_token);
+        self
+    }
+
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::BlockedList>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::phone_blocked_list::PhoneBlockedList`].
+pub struct PhoneBlockedList {
+    inner: crate::phone_blocked_list::PhoneBlockedList,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl PhoneBlockedList {
+    pub(crate) fn new(
+        inner: crate::phone_blocked_list::PhoneBlockedList,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        PhoneBlockedList { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::phone_blocked_list::PhoneBlockedList::list_blocked`].
+    pub fn list_blocked(
+        &self,
+        next_page_token: &str,
+        page_size: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::BlockedList>>> {
+        self.runtime
+            .block_on(self.inner.list_blocked(next_page_token, page_size))
+    }
+    /// The blocking version of [`crate::phone_blocked_list::PhoneBlockedList::list_blocked_request`].
+    pub fn list_blocked_request(&self) -> PhoneBlockedListListBlockedRequest<'_> {
+        PhoneBlockedListListBlockedRequest {
+            inner: self.inner.list_blocked_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::phone_blocked_list::PhoneBlockedList::list_all_blocked`].
+    pub fn list_all_blocked(
+        &self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::BlockedList>>> {
+        self.runtime.block_on(self.inner.list_all_blocked())
+    }
+    /// The blocking version of [`crate::phone_blocked_list::PhoneBlockedList::add_anumber_blocked_list`].
+    pub fn add_anumber_blocked_list(
+        &self,
+        body: &crate::types::UpdateBlockedListRequest,
+    ) -> ClientResult<crate::Response<crate::types::Groups>> {
+        self.runtime
+            .block_on(self.inner.add_anumber_blocked_list(body))
+    }
+    /// The blocking version of [`crate::phone_blocked_list::PhoneBlockedList::get_blocked_list`].
+    pub fn get_blocked_list(
+        &self,
+        blocked_list_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::BlockedList>> {
+        self.runtime
+            .block_on(self.inner.get_blocked_list(blocked_list_id))
+    }
+    /// The blocking version of [`crate::phone_blocked_list::PhoneBlockedList::delete_blocked_list`].
+    pub fn delete_blocked_list(&self, blocked_list_id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete_blocked_list(blocked_list_id))
+    }
+    /// The blocking version of [`crate::phone_blocked_list::PhoneBlockedList::update_blocked_list`].
+    pub fn update_blocked_list(
+        &self,
+        blocked_list_id: &str,
+        body: &crate::types::UpdateBlockedListRequest,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.update_blocked_list(blocked_list_id, body))
+    }
+}
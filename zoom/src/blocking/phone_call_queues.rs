@@ -0,0 +1,235 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`PhoneCallQueues::list_call_queues`], set with a builder.
+pub struct PhoneCallQueuesListCallQueuesRequest<'a> {
+    inner: crate::phone_call_queues::PhoneCallQueuesListCallQueuesRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> PhoneCallQueuesListCallQueuesRequest<'a> {
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.inner = self.inner.next_page_token(next_page_token);
+        self
+    }
+
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::CallQueues>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`PhoneCallQueues::get_call_queue_recordings`], set with a builder.
+pub struct PhoneCallQueuesGetCallQueueRecordingsRequest<'a> {
+    inner: crate::phone_call_queues::PhoneCallQueuesGetCallQueueRecordingsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> PhoneCallQueuesGetCallQueueRecordingsRequest<'a> {
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.inner = self.inner.next_page_token(next_page_token);
+        self
+    }
+
+    /// Sets the `from` query parameter.
+    pub fn from(mut self, from: chrono::NaiveDate) -> Self {
+        self.inner = self.inner.from(from);
+        self
+    }
+
+    /// Sets the `to` query parameter.
+    pub fn to(mut self, to: chrono::NaiveDate) -> Self {
+        self.inner = self.inner.to(to);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(
+        self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GetCallQueueRecordingsResponse>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::phone_call_queues::PhoneCallQueues`].
+pub struct PhoneCallQueues {
+    inner: crate::phone_call_queues::PhoneCallQueues,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl PhoneCallQueues {
+    pub(crate) fn new(
+        inner: crate::phone_call_queues::PhoneCallQueues,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        PhoneCallQueues { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::phone_call_queues::PhoneCallQueues::list_call_queues`].
+    pub fn list_call_queues(
+        &self,
+        next_page_token: &str,
+        page_size: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::CallQueues>>> {
+        self.runtime
+            .block_on(self.inner.list_call_queues(next_page_token, page_size))
+    }
+    /// The blocking version of [`crate::phone_call_queues::PhoneCallQueues::list_call_queues_request`].
+    pub fn list_call_queues_request(&self) -> PhoneCallQueuesListCallQueuesRequest<'_> {
+        PhoneCallQueuesListCallQueuesRequest {
+            inner: self.inner.list_call_queues_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::phone_call_queues::PhoneCallQueues::list_all_call_queues`].
+    pub fn list_all_call_queues(
+        &self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::CallQueues>>> {
+        self.runtime.block_on(self.inner.list_all_call_queues())
+    }
+    /// The blocking version of [`crate::phone_call_queues::PhoneCallQueues::create_call_queue`].
+    pub fn create_call_queue(
+        &self,
+        body: &crate::types::CreateCallQueueRequest,
+    ) -> ClientResult<crate::Response<crate::types::CreateCallQueueResponse>> {
+        self.runtime.block_on(self.inner.create_call_queue(body))
+    }
+    /// The blocking version of [`crate::phone_call_queues::PhoneCallQueues::get_call_queue`].
+    pub fn get_call_queue(
+        &self,
+        call_queue_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::GetCallQueueResponse>> {
+        self.runtime
+            .block_on(self.inner.get_call_queue(call_queue_id))
+    }
+    /// The blocking version of [`crate::phone_call_queues::PhoneCallQueues::delete_call_queue`].
+    pub fn delete_call_queue(&self, call_queue_id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete_call_queue(call_queue_id))
+    }
+    /// The blocking version of [`crate::phone_call_queues::PhoneCallQueues::update_call_queue`].
+    pub fn update_call_queue(
+        &self,
+        call_queue_id: &str,
+        body: &crate::types::UpdateCallQueueRequest,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.update_call_queue(call_queue_id, body))
+    }
+    /// The blocking version of [`crate::phone_call_queues::PhoneCallQueues::assign`].
+    pub fn assign(
+        &self,
+        call_queue_id: &str,
+        body: &crate::types::AddByocNumberResponse,
+    ) -> ClientResult<crate::Response<crate::types::Domains>> {
+        self.runtime
+            .block_on(self.inner.assign(call_queue_id, body))
+    }
+    /// The blocking version of [`crate::phone_call_queues::PhoneCallQueues::unassign_phone_num_call_queue`].
+    pub fn unassign_phone_num_call_queue(
+        &self,
+        call_queue_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Domains>> {
+        self.runtime
+            .block_on(self.inner.unassign_phone_num_call_queue(call_queue_id))
+    }
+    /// The blocking version of [`crate::phone_call_queues::PhoneCallQueues::un_assign_phone_num_call_queue`].
+    pub fn un_assign_phone_num_call_queue(
+        &self,
+        call_queue_id: &str,
+        phone_number_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Domains>> {
+        self.runtime.block_on(
+            self.inner
+                .un_assign_phone_num_call_queue(call_queue_id, phone_number_id),
+        )
+    }
+    /// The blocking version of [`crate::phone_call_queues::PhoneCallQueues::add_members_call_queue`].
+    pub fn add_members_call_queue(
+        &self,
+        call_queue_id: &str,
+        body: &crate::types::AddMembersCallQueueRequestData,
+    ) -> ClientResult<crate::Response<crate::types::Domains>> {
+        self.runtime
+            .block_on(self.inner.add_members_call_queue(call_queue_id, body))
+    }
+    /// The blocking version of [`crate::phone_call_queues::PhoneCallQueues::unassign_all_members`].
+    pub fn unassign_all_members(&self, call_queue_id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.unassign_all_members(call_queue_id))
+    }
+    /// The blocking version of [`crate::phone_call_queues::PhoneCallQueues::unassign_member_from_call_queue`].
+    pub fn unassign_member_from_call_queue(
+        &self,
+        call_queue_id: &str,
+        member_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .unassign_member_from_call_queue(call_queue_id, member_id),
+        )
+    }
+    /// The blocking version of [`crate::phone_call_queues::PhoneCallQueues::change_call_queue_manager`].
+    pub fn change_call_queue_manager(
+        &self,
+        call_queue_id: &str,
+        body: &crate::types::ChangeCallQueueManagerRequest,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.change_call_queue_manager(call_queue_id, body))
+    }
+    /// The blocking version of [`crate::phone_call_queues::PhoneCallQueues::get_call_queue_recordings`].
+    pub fn get_call_queue_recordings(
+        &self,
+        call_queue_id: &str,
+        page_size: i64,
+        next_page_token: &str,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GetCallQueueRecordingsResponse>>> {
+        self.runtime.block_on(self.inner.get_call_queue_recordings(
+            call_queue_id,
+            page_size,
+            next_page_token,
+            from,
+            to,
+        ))
+    }
+    /// The blocking version of [`crate::phone_call_queues::PhoneCallQueues::get_call_queue_recordings_request`].
+    pub fn get_call_queue_recordings_request<'a>(
+        &'a self,
+        call_queue_id: &'a str,
+    ) -> PhoneCallQueuesGetCallQueueRecordingsRequest<'a> {
+        PhoneCallQueuesGetCallQueueRecordingsRequest {
+            inner: self.inner.get_call_queue_recordings_request(call_queue_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::phone_call_queues::PhoneCallQueues::get_all_call_queue_recordings`].
+    pub fn get_all_call_queue_recordings(
+        &self,
+        call_queue_id: &str,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GetCallQueueRecordingsResponse>>> {
+        self.runtime.block_on(
+            self.inner
+                .get_all_call_queue_recordings(call_queue_id, from, to),
+        )
+    }
+}
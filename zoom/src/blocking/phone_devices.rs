@@ -0,0 +1,103 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`PhoneDevices::list`], set with a builder.
+pub struct PhoneDevicesListRequest<'a> {
+    inner: crate::phone_devices::PhoneDevicesListRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> PhoneDevicesListRequest<'a> {
+    /// Sets the `type` query parameter.
+    pub fn type_(mut self, type_: crate::types::ListPhoneDevicesType) -> Self {
+        self.inner = self.inner.type_(type_);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.inner = self.inner.next_page_token(next_page_token);
+        self
+    }
+
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(
+        self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ListPhoneDevicesResponse>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::phone_devices::PhoneDevices`].
+pub struct PhoneDevices {
+    inner: crate::phone_devices::PhoneDevices,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl PhoneDevices {
+    pub(crate) fn new(
+        inner: crate::phone_devices::PhoneDevices,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        PhoneDevices { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::phone_devices::PhoneDevices::list`].
+    pub fn list(
+        &self,
+        type_: crate::types::ListPhoneDevicesType,
+        next_page_token: &str,
+        page_size: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ListPhoneDevicesResponse>>> {
+        self.runtime
+            .block_on(self.inner.list(type_, next_page_token, page_size))
+    }
+    /// The blocking version of [`crate::phone_devices::PhoneDevices::list_request`].
+    pub fn list_request(&self) -> PhoneDevicesListRequest<'_> {
+        PhoneDevicesListRequest {
+            inner: self.inner.list_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::phone_devices::PhoneDevices::list_all`].
+    pub fn list_all(
+        &self,
+        type_: crate::types::ListPhoneDevicesType,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ListPhoneDevicesResponse>>> {
+        self.runtime.block_on(self.inner.list_all(type_))
+    }
+    /// The blocking version of [`crate::phone_devices::PhoneDevices::add`].
+    pub fn add(
+        &self,
+        body: &crate::types::AddPhoneDeviceRequest,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.add(body))
+    }
+    /// The blocking version of [`crate::phone_devices::PhoneDevices::get_device`].
+    pub fn get_device(
+        &self,
+        device_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::GetDeviceResponse>> {
+        self.runtime.block_on(self.inner.get_device(device_id))
+    }
+    /// The blocking version of [`crate::phone_devices::PhoneDevices::delete_device`].
+    pub fn delete_device(&self, device_id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.delete_device(device_id))
+    }
+    /// The blocking version of [`crate::phone_devices::PhoneDevices::update_device`].
+    pub fn update_device(
+        &self,
+        device_id: &str,
+        body: &crate::types::UpdateDeviceRequest,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.update_device(device_id, body))
+    }
+}
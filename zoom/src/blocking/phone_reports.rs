@@ -0,0 +1,88 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`PhoneReports::get_ps_operation_log`], set with a builder.
+pub struct PhoneReportsGetPsOperationLogRequest<'a> {
+    inner: crate::phone_reports::PhoneReportsGetPsOperationLogRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> PhoneReportsGetPsOperationLogRequest<'a> {
+    /// Sets the `from` query parameter.
+    pub fn from(mut self, from: &'a str) -> Self {
+        self.inner = self.inner.from(from);
+        self
+    }
+
+    /// Sets the `to` query parameter.
+    pub fn to(mut self, to: &'a str) -> Self {
+        self.inner = self.inner.to(to);
+        self
+    }
+
+    /// Sets the `category_type` query parameter.
+    pub fn category_type(mut self, category_type: &'a str) -> Self {
+        self.inner = self.inner.category_type(category_type);
+        self
+    }
+
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.inner = self.inner.next_page_token(next_page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::ReportOperationLogsResponseAllOf>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::phone_reports::PhoneReports`].
+pub struct PhoneReports {
+    inner: crate::phone_reports::PhoneReports,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl PhoneReports {
+    pub(crate) fn new(
+        inner: crate::phone_reports::PhoneReports,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        PhoneReports { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::phone_reports::PhoneReports::get_ps_operation_log`].
+    pub fn get_ps_operation_log(
+        &self,
+        from: &str,
+        to: &str,
+        category_type: &str,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> ClientResult<crate::Response<crate::types::ReportOperationLogsResponseAllOf>> {
+        self.runtime.block_on(self.inner.get_ps_operation_log(
+            from,
+            to,
+            category_type,
+            page_size,
+            next_page_token,
+        ))
+    }
+    /// The blocking version of [`crate::phone_reports::PhoneReports::get_ps_operation_log_request`].
+    pub fn get_ps_operation_log_request(&self) -> PhoneReportsGetPsOperationLogRequest<'_> {
+        PhoneReportsGetPsOperationLogRequest {
+            inner: self.inner.get_ps_operation_log_request(),
+            runtime: &self.runtime,
+        }
+    }
+}
@@ -0,0 +1,166 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`PhoneSharedLineGroups::list_shared_line_groups`], set with a builder.
+pub struct PhoneSharedLineGroupsListSharedLineGroupsRequest<'a> {
+    inner: crate::phone_shared_line_groups::PhoneSharedLineGroupsListSharedLineGroupsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> PhoneSharedLineGroupsListSharedLineGroupsRequest<'a> {
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.inner = self.inner.next_page_token(next_page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::SharedLineGroups>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::phone_shared_line_groups::PhoneSharedLineGroups`].
+pub struct PhoneSharedLineGroups {
+    inner: crate::phone_shared_line_groups::PhoneSharedLineGroups,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl PhoneSharedLineGroups {
+    pub(crate) fn new(
+        inner: crate::phone_shared_line_groups::PhoneSharedLineGroups,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        PhoneSharedLineGroups { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::phone_shared_line_groups::PhoneSharedLineGroups::list_shared_line_groups`].
+    pub fn list_shared_line_groups(
+        &self,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::SharedLineGroups>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_shared_line_groups(page_size, next_page_token),
+        )
+    }
+    /// The blocking version of [`crate::phone_shared_line_groups::PhoneSharedLineGroups::list_shared_line_groups_request`].
+    pub fn list_shared_line_groups_request(
+        &self,
+    ) -> PhoneSharedLineGroupsListSharedLineGroupsRequest<'_> {
+        PhoneSharedLineGroupsListSharedLineGroupsRequest {
+            inner: self.inner.list_shared_line_groups_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::phone_shared_line_groups::PhoneSharedLineGroups::list_all_shared_line_groups`].
+    pub fn list_all_shared_line_groups(
+        &self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::SharedLineGroups>>> {
+        self.runtime
+            .block_on(self.inner.list_all_shared_line_groups())
+    }
+    /// The blocking version of [`crate::phone_shared_line_groups::PhoneSharedLineGroups::create_shared_line_group`].
+    pub fn create_shared_line_group(
+        &self,
+        body: &crate::types::CreateSharedLineGroupRequest,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.create_shared_line_group(body))
+    }
+    /// The blocking version of [`crate::phone_shared_line_groups::PhoneSharedLineGroups::get_shared_line_group`].
+    pub fn get_shared_line_group(
+        &self,
+        shared_line_group_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::GetSharedLineGroupResponse>> {
+        self.runtime
+            .block_on(self.inner.get_shared_line_group(shared_line_group_id))
+    }
+    /// The blocking version of [`crate::phone_shared_line_groups::PhoneSharedLineGroups::delete_shared_line_group`].
+    pub fn delete_shared_line_group(
+        &self,
+        shared_line_group_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete_shared_line_group(shared_line_group_id))
+    }
+    /// The blocking version of [`crate::phone_shared_line_groups::PhoneSharedLineGroups::update_shared_line_group`].
+    pub fn update_shared_line_group(
+        &self,
+        shared_line_group_id: &str,
+        body: &crate::types::UpdateSharedLineGroupRequest,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .update_shared_line_group(shared_line_group_id, body),
+        )
+    }
+    /// The blocking version of [`crate::phone_shared_line_groups::PhoneSharedLineGroups::add_members_shared_line_group`].
+    pub fn add_members_shared_line_group(
+        &self,
+        shared_line_group_id: &str,
+        body: &crate::types::AddMembersSharedLineGroupRequestData,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .add_members_shared_line_group(shared_line_group_id, body),
+        )
+    }
+    /// The blocking version of [`crate::phone_shared_line_groups::PhoneSharedLineGroups::delete_members_of_slg`].
+    pub fn delete_members_of_slg(
+        &self,
+        shared_line_group_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete_members_of_slg(shared_line_group_id))
+    }
+    /// The blocking version of [`crate::phone_shared_line_groups::PhoneSharedLineGroups::delete_member_slg`].
+    pub fn delete_member_slg(
+        &self,
+        shared_line_group_id: &str,
+        member_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .delete_member_slg(shared_line_group_id, member_id),
+        )
+    }
+    /// The blocking version of [`crate::phone_shared_line_groups::PhoneSharedLineGroups::assign_phone_numbers_slg`].
+    pub fn assign_phone_numbers_slg(
+        &self,
+        shared_line_group_id: &str,
+        body: &crate::types::AddByocNumberResponse,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .assign_phone_numbers_slg(shared_line_group_id, body),
+        )
+    }
+    /// The blocking version of [`crate::phone_shared_line_groups::PhoneSharedLineGroups::delete_phone_numbers_slg`].
+    pub fn delete_phone_numbers_slg(
+        &self,
+        shared_line_group_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete_phone_numbers_slg(shared_line_group_id))
+    }
+    /// The blocking version of [`crate::phone_shared_line_groups::PhoneSharedLineGroups::delete_phone_number_slg`].
+    pub fn delete_phone_number_slg(
+        &self,
+        shared_line_group_id: &str,
+        phone_number_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .delete_phone_number_slg(shared_line_group_id, phone_number_id),
+        )
+    }
+}
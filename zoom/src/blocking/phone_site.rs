@@ -0,0 +1,122 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`PhoneSite::list`], set with a builder.
+pub struct PhoneSiteListRequest<'a> {
+    inner: crate::phone_site::PhoneSiteListRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> PhoneSiteListRequest<'a> {
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.inner = self.inner.next_page_token(next_page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Sites>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`PhoneSite::delete`], set with a builder.
+pub struct PhoneSiteDeleteRequest<'a> {
+    inner: crate::phone_site::PhoneSiteDeleteRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> PhoneSiteDeleteRequest<'a> {
+    /// Sets the `transfer_site_id` query parameter.
+    pub fn transfer_site_id(mut self, transfer_site_id: &'a str) -> Self {
+        self.inner = self.inner.transfer_site_id(transfer_site_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::phone_site::PhoneSite`].
+pub struct PhoneSite {
+    inner: crate::phone_site::PhoneSite,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl PhoneSite {
+    pub(crate) fn new(
+        inner: crate::phone_site::PhoneSite,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        PhoneSite { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::phone_site::PhoneSite::list`].
+    pub fn list(
+        &self,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Sites>>> {
+        self.runtime
+            .block_on(self.inner.list(page_size, next_page_token))
+    }
+    /// The blocking version of [`crate::phone_site::PhoneSite::list_request`].
+    pub fn list_request(&self) -> PhoneSiteListRequest<'_> {
+        PhoneSiteListRequest {
+            inner: self.inner.list_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::phone_site::PhoneSite::list_all`].
+    pub fn list_all(&self) -> ClientResult<crate::Response<Vec<crate::types::Sites>>> {
+        self.runtime.block_on(self.inner.list_all())
+    }
+    /// The blocking version of [`crate::phone_site::PhoneSite::create`].
+    pub fn create(
+        &self,
+        body: &crate::types::CreatePhoneSiteRequest,
+    ) -> ClientResult<crate::Response<crate::types::Site>> {
+        self.runtime.block_on(self.inner.create(body))
+    }
+    /// The blocking version of [`crate::phone_site::PhoneSite::get_site`].
+    pub fn get_site(
+        &self,
+        site_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::GetSiteResponse>> {
+        self.runtime.block_on(self.inner.get_site(site_id))
+    }
+    /// The blocking version of [`crate::phone_site::PhoneSite::delete`].
+    pub fn delete(
+        &self,
+        site_id: &str,
+        transfer_site_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete(site_id, transfer_site_id))
+    }
+    /// The blocking version of [`crate::phone_site::PhoneSite::delete_request`].
+    pub fn delete_request<'a>(&'a self, site_id: &'a str) -> PhoneSiteDeleteRequest<'a> {
+        PhoneSiteDeleteRequest {
+            inner: self.inner.delete_request(site_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::phone_site::PhoneSite::update_site_details`].
+    pub fn update_site_details(
+        &self,
+        site_id: &str,
+        body: &crate::types::UpdateSiteDetailsRequest,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.update_site_details(site_id, body))
+    }
+}
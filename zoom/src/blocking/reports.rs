@@ -0,0 +1,599 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Reports::daily`], set with a builder.
+pub struct ReportsDailyRequest<'a> {
+    inner: crate::reports::ReportsDailyRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ReportsDailyRequest<'a> {
+    /// Sets the `year` query parameter.
+    pub fn year(mut self, year: i64) -> Self {
+        self.inner = self.inner.year(year);
+        self
+    }
+
+    /// Sets the `month` query parameter.
+    pub fn month(mut self, month: i64) -> Self {
+        self.inner = self.inner.month(month);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::ReportDailyResponse>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Reports::user`], set with a builder.
+pub struct ReportsUserRequest<'a> {
+    inner: crate::reports::ReportsUserRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ReportsUserRequest<'a> {
+    /// Sets the `type` query parameter.
+    pub fn type_(mut self, type_: crate::types::ReportUsersType) -> Self {
+        self.inner = self.inner.type_(type_);
+        self
+    }
+
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Sets the `page_number` query parameter.
+    pub fn page_number(mut self, page_number: i64) -> Self {
+        self.inner = self.inner.page_number(page_number);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.inner = self.inner.next_page_token(next_page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::Domains>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Reports::meeting`], set with a builder.
+pub struct ReportsMeetingRequest<'a> {
+    inner: crate::reports::ReportsMeetingRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ReportsMeetingRequest<'a> {
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.inner = self.inner.next_page_token(next_page_token);
+        self
+    }
+
+    /// Sets the `type` query parameter.
+    pub fn type_(mut self, type_: crate::types::ReportMeetingsType) -> Self {
+        self.inner = self.inner.type_(type_);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::ReportMeetingsResponseAllOf>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Reports::meeting_participant`], set with a builder.
+pub struct ReportsMeetingParticipantRequest<'a> {
+    inner: crate::reports::ReportsMeetingParticipantRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ReportsMeetingParticipantRequest<'a> {
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.inner = self.inner.next_page_token(next_page_token);
+        self
+    }
+
+    /// Sets the `include_fields` query parameter.
+    pub fn include_fields(
+        mut self,
+        include_fields: crate::types::DashboardMeetingParticipantsIncludeFields,
+    ) -> Self {
+        self.inner = self.inner.include_fields(include_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::ReportMeetingParticipantsResponseAllOf>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Reports::webinar_participant`], set with a builder.
+pub struct ReportsWebinarParticipantRequest<'a> {
+    inner: crate::reports::ReportsWebinarParticipantRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ReportsWebinarParticipantRequest<'a> {
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.inner = self.inner.next_page_token(next_page_token);
+        self
+    }
+
+    /// Sets the `include_fields` query parameter.
+    pub fn include_fields(
+        mut self,
+        include_fields: crate::types::DashboardMeetingParticipantsIncludeFields,
+    ) -> Self {
+        self.inner = self.inner.include_fields(include_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::ReportWebinarParticipantsResponseAllOf>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Reports::telephone`], set with a builder.
+pub struct ReportsTelephoneRequest<'a> {
+    inner: crate::reports::ReportsTelephoneRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ReportsTelephoneRequest<'a> {
+    /// Sets the `type` query parameter.
+    pub fn type_(mut self, type_: &'a str) -> Self {
+        self.inner = self.inner.type_(type_);
+        self
+    }
+
+    /// Sets the `query_date_type` query parameter.
+    pub fn query_date_type(mut self, query_date_type: crate::types::QueryDateType) -> Self {
+        self.inner = self.inner.query_date_type(query_date_type);
+        self
+    }
+
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Sets the `page_number` query parameter.
+    pub fn page_number(mut self, page_number: i64) -> Self {
+        self.inner = self.inner.page_number(page_number);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.inner = self.inner.next_page_token(next_page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::Domains>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Reports::operation_log`], set with a builder.
+pub struct ReportsOperationLogRequest<'a> {
+    inner: crate::reports::ReportsOperationLogRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ReportsOperationLogRequest<'a> {
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.inner = self.inner.next_page_token(next_page_token);
+        self
+    }
+
+    /// Sets the `category_type` query parameter.
+    pub fn category_type(mut self, category_type: crate::types::CategoryType) -> Self {
+        self.inner = self.inner.category_type(category_type);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::ReportOperationLogsResponseAllOf>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Reports::sign_out_activities`], set with a builder.
+pub struct ReportsSignOutActivitiesRequest<'a> {
+    inner: crate::reports::ReportsSignOutActivitiesRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ReportsSignOutActivitiesRequest<'a> {
+    /// Sets the `from` query parameter.
+    pub fn from(mut self, from: chrono::NaiveDate) -> Self {
+        self.inner = self.inner.from(from);
+        self
+    }
+
+    /// Sets the `to` query parameter.
+    pub fn to(mut self, to: chrono::NaiveDate) -> Self {
+        self.inner = self.inner.to(to);
+        self
+    }
+
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.inner = self.inner.next_page_token(next_page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::ActivityLogs>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Reports::get_billing_invoices`], set with a builder.
+pub struct ReportsGetBillingInvoicesRequest<'a> {
+    inner: crate::reports::ReportsGetBillingInvoicesRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> ReportsGetBillingInvoicesRequest<'a> {
+    /// Sets the `billing_id` query parameter.
+    pub fn billing_id(mut self, billing_id: &'a str) -> Self {
+        self.inner = self.inner.billing_id(billing_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::GetBillingInvoicesReportsResponse>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::reports::Reports`].
+pub struct Reports {
+    inner: crate::reports::Reports,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Reports {
+    pub(crate) fn new(
+        inner: crate::reports::Reports,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Reports { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::reports::Reports::daily`].
+    pub fn daily(
+        &self,
+        year: i64,
+        month: i64,
+    ) -> ClientResult<crate::Response<crate::types::ReportDailyResponse>> {
+        self.runtime.block_on(self.inner.daily(year, month))
+    }
+    /// The blocking version of [`crate::reports::Reports::daily_request`].
+    pub fn daily_request(&self) -> ReportsDailyRequest<'_> {
+        ReportsDailyRequest {
+            inner: self.inner.daily_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::reports::Reports::user`].
+    pub fn user(
+        &self,
+        type_: crate::types::ReportUsersType,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        page_size: i64,
+        page_number: i64,
+        next_page_token: &str,
+    ) -> ClientResult<crate::Response<crate::types::Domains>> {
+        self.runtime.block_on(self.inner.user(
+            type_,
+            from,
+            to,
+            page_size,
+            page_number,
+            next_page_token,
+        ))
+    }
+    /// The blocking version of [`crate::reports::Reports::user_request`].
+    pub fn user_request(
+        &self,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> ReportsUserRequest<'_> {
+        ReportsUserRequest {
+            inner: self.inner.user_request(from, to),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::reports::Reports::meeting`].
+    pub fn meeting(
+        &self,
+        user_id: &str,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        page_size: i64,
+        next_page_token: &str,
+        type_: crate::types::ReportMeetingsType,
+    ) -> ClientResult<crate::Response<crate::types::ReportMeetingsResponseAllOf>> {
+        self.runtime.block_on(self.inner.meeting(
+            user_id,
+            from,
+            to,
+            page_size,
+            next_page_token,
+            type_,
+        ))
+    }
+    /// The blocking version of [`crate::reports::Reports::meeting_request`].
+    pub fn meeting_request<'a>(
+        &'a self,
+        user_id: &'a str,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> ReportsMeetingRequest<'a> {
+        ReportsMeetingRequest {
+            inner: self.inner.meeting_request(user_id, from, to),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::reports::Reports::meeting_details`].
+    pub fn meeting_details(
+        &self,
+        meeting_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ReportMeetingDetailsResponse>> {
+        self.runtime
+            .block_on(self.inner.meeting_details(meeting_id))
+    }
+    /// The blocking version of [`crate::reports::Reports::meeting_participant`].
+    pub fn meeting_participant(
+        &self,
+        meeting_id: &str,
+        page_size: i64,
+        next_page_token: &str,
+        include_fields: crate::types::DashboardMeetingParticipantsIncludeFields,
+    ) -> ClientResult<crate::Response<crate::types::ReportMeetingParticipantsResponseAllOf>> {
+        self.runtime.block_on(self.inner.meeting_participant(
+            meeting_id,
+            page_size,
+            next_page_token,
+            include_fields,
+        ))
+    }
+    /// The blocking version of [`crate::reports::Reports::meeting_participant_request`].
+    pub fn meeting_participant_request<'a>(
+        &'a self,
+        meeting_id: &'a str,
+    ) -> ReportsMeetingParticipantRequest<'a> {
+        ReportsMeetingParticipantRequest {
+            inner: self.inner.meeting_participant_request(meeting_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::reports::Reports::meeting_polls`].
+    pub fn meeting_polls(
+        &self,
+        meeting_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ReportMeetingPollsResponse>> {
+        self.runtime.block_on(self.inner.meeting_polls(meeting_id))
+    }
+    /// The blocking version of [`crate::reports::Reports::webinar_details`].
+    pub fn webinar_details(
+        &self,
+        webinar_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ReportMeetingDetailsResponse>> {
+        self.runtime
+            .block_on(self.inner.webinar_details(webinar_id))
+    }
+    /// The blocking version of [`crate::reports::Reports::webinar_participant`].
+    pub fn webinar_participant(
+        &self,
+        webinar_id: &str,
+        page_size: i64,
+        next_page_token: &str,
+        include_fields: crate::types::DashboardMeetingParticipantsIncludeFields,
+    ) -> ClientResult<crate::Response<crate::types::ReportWebinarParticipantsResponseAllOf>> {
+        self.runtime.block_on(self.inner.webinar_participant(
+            webinar_id,
+            page_size,
+            next_page_token,
+            include_fields,
+        ))
+    }
+    /// The blocking version of [`crate::reports::Reports::webinar_participant_request`].
+    pub fn webinar_participant_request<'a>(
+        &'a self,
+        webinar_id: &'a str,
+    ) -> ReportsWebinarParticipantRequest<'a> {
+        ReportsWebinarParticipantRequest {
+            inner: self.inner.webinar_participant_request(webinar_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::reports::Reports::webinar_polls`].
+    pub fn webinar_polls(
+        &self,
+        webinar_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ReportMeetingPollsResponse>> {
+        self.runtime.block_on(self.inner.webinar_polls(webinar_id))
+    }
+    /// The blocking version of [`crate::reports::Reports::webinar_qa`].
+    pub fn webinar_qa(
+        &self,
+        webinar_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ReportWebinarQaResponse>> {
+        self.runtime.block_on(self.inner.webinar_qa(webinar_id))
+    }
+    /// The blocking version of [`crate::reports::Reports::telephone`].
+    pub fn telephone(
+        &self,
+        type_: &str,
+        query_date_type: crate::types::QueryDateType,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        page_size: i64,
+        page_number: i64,
+        next_page_token: &str,
+    ) -> ClientResult<crate::Response<crate::types::Domains>> {
+        self.runtime.block_on(self.inner.telephone(
+            type_,
+            query_date_type,
+            from,
+            to,
+            page_size,
+            page_number,
+            next_page_token,
+        ))
+    }
+    /// The blocking version of [`crate::reports::Reports::telephone_request`].
+    pub fn telephone_request(
+        &self,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> ReportsTelephoneRequest<'_> {
+        ReportsTelephoneRequest {
+            inner: self.inner.telephone_request(from, to),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::reports::Reports::cloud_recording`].
+    pub fn cloud_recording(
+        &self,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> ClientResult<crate::Response<crate::types::ReportCloudRecordingResponseAllOf>> {
+        self.runtime.block_on(self.inner.cloud_recording(from, to))
+    }
+    /// The blocking version of [`crate::reports::Reports::operation_log`].
+    pub fn operation_log(
+        &self,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        page_size: i64,
+        next_page_token: &str,
+        category_type: crate::types::CategoryType,
+    ) -> ClientResult<crate::Response<crate::types::ReportOperationLogsResponseAllOf>> {
+        self.runtime.block_on(self.inner.operation_log(
+            from,
+            to,
+            page_size,
+            next_page_token,
+            category_type,
+        ))
+    }
+    /// The blocking version of [`crate::reports::Reports::operation_log_request`].
+    pub fn operation_log_request(
+        &self,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> ReportsOperationLogRequest<'_> {
+        ReportsOperationLogRequest {
+            inner: self.inner.operation_log_request(from, to),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::reports::Reports::sign_out_activities`].
+    pub fn sign_out_activities(
+        &self,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ActivityLogs>>> {
+        self.runtime.block_on(
+            self.inner
+                .sign_out_activities(from, to, page_size, next_page_token),
+        )
+    }
+    /// The blocking version of [`crate::reports::Reports::sign_out_activities_request`].
+    pub fn sign_out_activities_request(&self) -> ReportsSignOutActivitiesRequest<'_> {
+        ReportsSignOutActivitiesRequest {
+            inner: self.inner.sign_out_activities_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::reports::Reports::get_all_sign_out_activities`].
+    pub fn get_all_sign_out_activities(
+        &self,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ActivityLogs>>> {
+        self.runtime
+            .block_on(self.inner.get_all_sign_out_activities(from, to))
+    }
+    /// The blocking version of [`crate::reports::Reports::get_billing`].
+    pub fn get_billing(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::GetBillingReportResponse>> {
+        self.runtime.block_on(self.inner.get_billing())
+    }
+    /// The blocking version of [`crate::reports::Reports::get_billing_invoices`].
+    pub fn get_billing_invoices(
+        &self,
+        billing_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::GetBillingInvoicesReportsResponse>> {
+        self.runtime
+            .block_on(self.inner.get_billing_invoices(billing_id))
+    }
+    /// The blocking version of [`crate::reports::Reports::get_billing_invoices_request`].
+    pub fn get_billing_invoices_request(&self) -> ReportsGetBillingInvoicesRequest<'_> {
+        ReportsGetBillingInvoicesRequest {
+            inner: self.inner.get_billing_invoices_request(),
+            runtime: &self.runtime,
+        }
+    }
+}
@@ -0,0 +1,133 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Roles::members`], set with a builder.
+pub struct RolesMembersRequest<'a> {
+    inner: crate::roles::RolesMembersRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> RolesMembersRequest<'a> {
+    /// Sets the `page_count` query parameter.
+    pub fn page_count(mut self, page_count: &'a str) -> Self {
+        self.inner = self.inner.page_count(page_count);
+        self
+    }
+
+    /// Sets the `page_number` query parameter.
+    pub fn page_number(mut self, page_number: i64) -> Self {
+        self.inner = self.inner.page_number(page_number);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.inner = self.inner.next_page_token(next_page_token);
+        self
+    }
+
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Domains>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::roles::Roles`].
+pub struct Roles {
+    inner: crate::roles::Roles,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Roles {
+    pub(crate) fn new(inner: crate::roles::Roles, runtime: Arc<tokio::runtime::Runtime>) -> Self {
+        Roles { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::roles::Roles::get`].
+    pub fn get(&self) -> ClientResult<crate::Response<crate::types::Domains>> {
+        self.runtime.block_on(self.inner.get())
+    }
+    /// The blocking version of [`crate::roles::Roles::create`].
+    pub fn create(
+        &self,
+        body: &crate::types::CreateRoleRequest,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.create(body))
+    }
+    /// The blocking version of [`crate::roles::Roles::members`].
+    pub fn members(
+        &self,
+        role_id: &str,
+        page_count: &str,
+        page_number: i64,
+        next_page_token: &str,
+        page_size: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Domains>>> {
+        self.runtime.block_on(self.inner.members(
+            role_id,
+            page_count,
+            page_number,
+            next_page_token,
+            page_size,
+        ))
+    }
+    /// The blocking version of [`crate::roles::Roles::members_request`].
+    pub fn members_request<'a>(&'a self, role_id: &'a str) -> RolesMembersRequest<'a> {
+        RolesMembersRequest {
+            inner: self.inner.members_request(role_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::roles::Roles::get_all_members`].
+    pub fn get_all_members(
+        &self,
+        role_id: &str,
+        page_count: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Domains>>> {
+        self.runtime
+            .block_on(self.inner.get_all_members(role_id, page_count))
+    }
+    /// The blocking version of [`crate::roles::Roles::add_members`].
+    pub fn add_members(
+        &self,
+        role_id: &str,
+        body: &crate::types::AddRoleMembersRequest,
+    ) -> ClientResult<crate::Response<crate::types::AddRoleMembersResponse>> {
+        self.runtime.block_on(self.inner.add_members(role_id, body))
+    }
+    /// The blocking version of [`crate::roles::Roles::member_delete`].
+    pub fn member_delete(
+        &self,
+        role_id: &str,
+        member_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.member_delete(role_id, member_id))
+    }
+    /// The blocking version of [`crate::roles::Roles::get_information`].
+    pub fn get_information(
+        &self,
+        role_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::GetRoleInformationResponse>> {
+        self.runtime.block_on(self.inner.get_information(role_id))
+    }
+    /// The blocking version of [`crate::roles::Roles::delete`].
+    pub fn delete(&self, role_id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.delete(role_id))
+    }
+    /// The blocking version of [`crate::roles::Roles::update`].
+    pub fn update(
+        &self,
+        role_id: &str,
+        body: &crate::types::UpdateRoleRequest,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.update(role_id, body))
+    }
+}
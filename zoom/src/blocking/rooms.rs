@@ -0,0 +1,312 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Rooms::list_zoom`], set with a builder.
+pub struct RoomsListZoomRequest<'a> {
+    inner: crate::rooms::RoomsListZoomRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> RoomsListZoomRequest<'a> {
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: crate::types::ListZoomRoomsStatus) -> Self {
+        self.inner = self.inner.status(status);
+        self
+    }
+
+    /// Sets the `type` query parameter.
+    pub fn type_(mut self, type_: crate::types::ListZoomRoomsType) -> Self {
+        self.inner = self.inner.type_(type_);
+        self
+    }
+
+    /// Sets the `unassigned_rooms` query parameter.
+    pub fn unassigned_rooms(mut self, unassigned_rooms: bool) -> Self {
+        self.inner = self.inner.unassigned_rooms(unassigned_rooms);
+        self
+    }
+
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.inner = self.inner.next_page_token(next_page_token);
+        self
+    }
+
+    /// Sets the `location_id` query parameter.
+    pub fn location_id(mut self, location_id: &'a str) -> Self {
+        self.inner = self.inner.location_id(location_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::ListZoomRoomsResponse>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Rooms::get_zr_setting`], set with a builder.
+pub struct RoomsGetZrSettingRequest<'a> {
+    inner: crate::rooms::RoomsGetZrSettingRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> RoomsGetZrSettingRequest<'a> {
+    /// Sets the `setting_type` query parameter.
+    pub fn setting_type(mut self, setting_type: &'a str) -> Self {
+        self.inner = self.inner.setting_type(setting_type);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::Domains>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Rooms::update_zr_settings`], set with a builder.
+pub struct RoomsUpdateZrSettingsRequest<'a> {
+    inner: crate::rooms::RoomsUpdateZrSettingsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> RoomsUpdateZrSettingsRequest<'a> {
+    /// Sets the `setting_type` query parameter.
+    pub fn setting_type(mut self, setting_type: &'a str) -> Self {
+        self.inner = self.inner.setting_type(setting_type);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Rooms::list_digital_signage_content`], set with a builder.
+pub struct RoomsListDigitalSignageContentRequest<'a> {
+    inner: crate::rooms::RoomsListDigitalSignageContentRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> RoomsListDigitalSignageContentRequest<'a> {
+    /// Sets the `type` query parameter.
+    pub fn type_(mut self, type_: &'a str) -> Self {
+        self.inner = self.inner.type_(type_);
+        self
+    }
+
+    /// Sets the `folder_id` query parameter.
+    pub fn folder_id(mut self, folder_id: &'a str) -> Self {
+        self.inner = self.inner.folder_id(folder_id);
+        self
+    }
+
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.inner = self.inner.next_page_token(next_page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Site>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::rooms::Rooms`].
+pub struct Rooms {
+    inner: crate::rooms::Rooms,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Rooms {
+    pub(crate) fn new(inner: crate::rooms::Rooms, runtime: Arc<tokio::runtime::Runtime>) -> Self {
+        Rooms { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::rooms::Rooms::list_zoom`].
+    pub fn list_zoom(
+        &self,
+        status: crate::types::ListZoomRoomsStatus,
+        type_: crate::types::ListZoomRoomsType,
+        unassigned_rooms: bool,
+        page_size: i64,
+        next_page_token: &str,
+        location_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ListZoomRoomsResponse>>> {
+        self.runtime.block_on(self.inner.list_zoom(
+            status,
+            type_,
+            unassigned_rooms,
+            page_size,
+            next_page_token,
+            location_id,
+        ))
+    }
+    /// The blocking version of [`crate::rooms::Rooms::list_zoom_request`].
+    pub fn list_zoom_request(&self) -> RoomsListZoomRequest<'_> {
+        RoomsListZoomRequest {
+            inner: self.inner.list_zoom_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::rooms::Rooms::list_all_zoom`].
+    pub fn list_all_zoom(
+        &self,
+        status: crate::types::ListZoomRoomsStatus,
+        type_: crate::types::ListZoomRoomsType,
+        unassigned_rooms: bool,
+        location_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ListZoomRoomsResponse>>> {
+        self.runtime.block_on(self.inner.list_all_zoom(
+            status,
+            type_,
+            unassigned_rooms,
+            location_id,
+        ))
+    }
+    /// The blocking version of [`crate::rooms::Rooms::add`].
+    pub fn add(
+        &self,
+        body: &crate::types::AddRoomRequest,
+    ) -> ClientResult<crate::Response<crate::types::AddRoomResponse>> {
+        self.runtime.block_on(self.inner.add(body))
+    }
+    /// The blocking version of [`crate::rooms::Rooms::get_zr_profile`].
+    pub fn get_zr_profile(
+        &self,
+        room_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::GetZrProfileResponse>> {
+        self.runtime.block_on(self.inner.get_zr_profile(room_id))
+    }
+    /// The blocking version of [`crate::rooms::Rooms::delete_zoom`].
+    pub fn delete_zoom(
+        &self,
+        room_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Domains>> {
+        self.runtime.block_on(self.inner.delete_zoom(room_id))
+    }
+    /// The blocking version of [`crate::rooms::Rooms::update_profile`].
+    pub fn update_profile(
+        &self,
+        room_id: &str,
+        body: &crate::types::UpdateRoomProfileRequest,
+    ) -> ClientResult<crate::Response<crate::types::Domains>> {
+        self.runtime
+            .block_on(self.inner.update_profile(room_id, body))
+    }
+    /// The blocking version of [`crate::rooms::Rooms::get_zr_setting`].
+    pub fn get_zr_setting(
+        &self,
+        room_id: &str,
+        setting_type: &str,
+    ) -> ClientResult<crate::Response<crate::types::Domains>> {
+        self.runtime
+            .block_on(self.inner.get_zr_setting(room_id, setting_type))
+    }
+    /// The blocking version of [`crate::rooms::Rooms::get_zr_setting_request`].
+    pub fn get_zr_setting_request<'a>(&'a self, room_id: &'a str) -> RoomsGetZrSettingRequest<'a> {
+        RoomsGetZrSettingRequest {
+            inner: self.inner.get_zr_setting_request(room_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::rooms::Rooms::update_zr_settings`].
+    pub fn update_zr_settings(
+        &self,
+        room_id: &str,
+        setting_type: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.update_zr_settings(room_id, setting_type))
+    }
+    /// The blocking version of [`crate::rooms::Rooms::update_zr_settings_request`].
+    pub fn update_zr_settings_request<'a>(
+        &'a self,
+        room_id: &'a str,
+    ) -> RoomsUpdateZrSettingsRequest<'a> {
+        RoomsUpdateZrSettingsRequest {
+            inner: self.inner.update_zr_settings_request(room_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::rooms::Rooms::list_zr_devices`].
+    pub fn list_zr_devices(
+        &self,
+        room_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ListZrDevicesResponse>> {
+        self.runtime.block_on(self.inner.list_zr_devices(room_id))
+    }
+    /// The blocking version of [`crate::rooms::Rooms::change_zr_location`].
+    pub fn change_zr_location(
+        &self,
+        room_id: &str,
+        body: &crate::types::ChangeZrLocationRequest,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.change_zr_location(room_id, body))
+    }
+    /// The blocking version of [`crate::rooms::Rooms::check`].
+    pub fn check(
+        &self,
+        id: &str,
+        body: &crate::types::CheckInRoomsRequest,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.check(id, body))
+    }
+    /// The blocking version of [`crate::rooms::Rooms::list_digital_signage_content`].
+    pub fn list_digital_signage_content(
+        &self,
+        type_: &str,
+        folder_id: &str,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Site>>> {
+        self.runtime
+            .block_on(self.inner.list_digital_signage_content(
+                type_,
+                folder_id,
+                page_size,
+                next_page_token,
+            ))
+    }
+    /// The blocking version of [`crate::rooms::Rooms::list_digital_signage_content_request`].
+    pub fn list_digital_signage_content_request(
+        &self,
+    ) -> RoomsListDigitalSignageContentRequest<'_> {
+        RoomsListDigitalSignageContentRequest {
+            inner: self.inner.list_digital_signage_content_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::rooms::Rooms::list_all_digital_signage_content`].
+    pub fn list_all_digital_signage_content(
+        &self,
+        type_: &str,
+        folder_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Site>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_all_digital_signage_content(type_, folder_id),
+        )
+    }
+    /// The blocking version of [`crate::rooms::Rooms::manage_e_91_1signage`].
+    pub fn manage_e_91_1signage(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::ManageE911SignageResponse>> {
+        self.runtime.block_on(self.inner.manage_e_91_1signage())
+    }
+}
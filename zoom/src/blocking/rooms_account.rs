@@ -0,0 +1,103 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`RoomsAccount::get_zr_account_setting`], set with a builder.
+pub struct RoomsAccountGetZrAccountSettingRequest<'a> {
+    inner: crate::rooms_account::RoomsAccountGetZrAccountSettingRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> RoomsAccountGetZrAccountSettingRequest<'a> {
+    /// Sets the `setting_type` query parameter.
+    pub fn setting_type(mut self, setting_type: crate::types::SettingType) -> Self {
+        self.inner = self.inner.setting_type(setting_type);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::Domains>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`RoomsAccount::update_zoom_room_acc_settings`], set with a builder.
+pub struct RoomsAccountUpdateZoomRoomAccSettingsRequest<'a> {
+    inner: crate::rooms_account::RoomsAccountUpdateZoomRoomAccSettingsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> RoomsAccountUpdateZoomRoomAccSettingsRequest<'a> {
+    /// Sets the `setting_type` query parameter.
+    pub fn setting_type(mut self, setting_type: &'a str) -> Self {
+        self.inner = self.inner.setting_type(setting_type);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::Domains>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::rooms_account::RoomsAccount`].
+pub struct RoomsAccount {
+    inner: crate::rooms_account::RoomsAccount,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl RoomsAccount {
+    pub(crate) fn new(
+        inner: crate::rooms_account::RoomsAccount,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        RoomsAccount { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::rooms_account::RoomsAccount::get_zr_account_profile`].
+    pub fn get_zr_account_profile(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::UpdateZrAccProfileRequest>> {
+        self.runtime.block_on(self.inner.get_zr_account_profile())
+    }
+    /// The blocking version of [`crate::rooms_account::RoomsAccount::update_zr_acc_profile`].
+    pub fn update_zr_acc_profile(
+        &self,
+        body: &crate::types::UpdateZrAccProfileRequest,
+    ) -> ClientResult<crate::Response<crate::types::Domains>> {
+        self.runtime
+            .block_on(self.inner.update_zr_acc_profile(body))
+    }
+    /// The blocking version of [`crate::rooms_account::RoomsAccount::get_zr_account_setting`].
+    pub fn get_zr_account_setting(
+        &self,
+        setting_type: crate::types::SettingType,
+    ) -> ClientResult<crate::Response<crate::types::Domains>> {
+        self.runtime
+            .block_on(self.inner.get_zr_account_setting(setting_type))
+    }
+    /// The blocking version of [`crate::rooms_account::RoomsAccount::get_zr_account_setting_request`].
+    pub fn get_zr_account_setting_request(&self) -> RoomsAccountGetZrAccountSettingRequest<'_> {
+        RoomsAccountGetZrAccountSettingRequest {
+            inner: self.inner.get_zr_account_setting_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::rooms_account::RoomsAccount::update_zoom_room_acc_settings`].
+    pub fn update_zoom_room_acc_settings(
+        &self,
+        setting_type: &str,
+    ) -> ClientResult<crate::Response<crate::types::Domains>> {
+        self.runtime
+            .block_on(self.inner.update_zoom_room_acc_settings(setting_type))
+    }
+    /// The blocking version of [`crate::rooms_account::RoomsAccount::update_zoom_room_acc_settings_request`].
+    pub fn update_zoom_room_acc_settings_request(
+        &self,
+    ) -> RoomsAccountUpdateZoomRoomAccSettingsRequest<'_> {
+        RoomsAccountUpdateZoomRoomAccSettingsRequest {
+            inner: self.inner.update_zoom_room_acc_settings_request(),
+            runtime: &self.runtime,
+        }
+    }
+}
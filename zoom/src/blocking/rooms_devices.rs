@@ -0,0 +1,31 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::rooms_devices::RoomsDevices`].
+pub struct RoomsDevices {
+    inner: crate::rooms_devices::RoomsDevices,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl RoomsDevices {
+    pub(crate) fn new(
+        inner: crate::rooms_devices::RoomsDevices,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        RoomsDevices { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::rooms_devices::RoomsDevices::change_zoom_rooms_app_version`].
+    pub fn change_zoom_rooms_app_version(
+        &self,
+        room_id: &str,
+        device_id: &str,
+        body: &crate::types::ChangeZoomRoomsAppVersionRequest,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .change_zoom_rooms_app_version(room_id, device_id, body),
+        )
+    }
+}
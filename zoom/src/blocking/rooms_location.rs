@@ -0,0 +1,215 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`RoomsLocation::list_zr_locations`], set with a builder.
+pub struct RoomsLocationListZrLocationsRequest<'a> {
+    inner: crate::rooms_location::RoomsLocationListZrLocationsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> RoomsLocationListZrLocationsRequest<'a> {
+    /// Sets the `parent_location_id` query parameter.
+    pub fn parent_location_id(mut self, parent_location_id: &'a str) -> Self {
+        self.inner = self.inner.parent_location_id(parent_location_id);
+        self
+    }
+
+    /// Sets the `type` query parameter.
+    pub fn type_(mut self, type_: &'a str) -> Self {
+        self.inner = self.inner.type_(type_);
+        self
+    }
+
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.inner = self.inner.next_page_token(next_page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::AddAzrLocationResponse>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`RoomsLocation::get_zr_location_setting`], set with a builder.
+pub struct RoomsLocationGetZrLocationSettingRequest<'a> {
+    inner: crate::rooms_location::RoomsLocationGetZrLocationSettingRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> RoomsLocationGetZrLocationSettingRequest<'a> {
+    /// Sets the `setting_type` query parameter.
+    pub fn setting_type(mut self, setting_type: &'a str) -> Self {
+        self.inner = self.inner.setting_type(setting_type);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::Domains>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`RoomsLocation::update_zr_location_settings`], set with a builder.
+pub struct RoomsLocationUpdateZrLocationSettingsRequest<'a> {
+    inner: crate::rooms_location::RoomsLocationUpdateZrLocationSettingsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> RoomsLocationUpdateZrLocationSettingsRequest<'a> {
+    /// Sets the `setting_type` query parameter.
+    pub fn setting_type(mut self, setting_type: &'a str) -> Self {
+        self.inner = self.inner.setting_type(setting_type);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::rooms_location::RoomsLocation`].
+pub struct RoomsLocation {
+    inner: crate::rooms_location::RoomsLocation,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl RoomsLocation {
+    pub(crate) fn new(
+        inner: crate::rooms_location::RoomsLocation,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        RoomsLocation { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::rooms_location::RoomsLocation::list_zr_locations`].
+    pub fn list_zr_locations(
+        &self,
+        parent_location_id: &str,
+        type_: &str,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::AddAzrLocationResponse>>> {
+        self.runtime.block_on(self.inner.list_zr_locations(
+            parent_location_id,
+            type_,
+            page_size,
+            next_page_token,
+        ))
+    }
+    /// The blocking version of [`crate::rooms_location::RoomsLocation::list_zr_locations_request`].
+    pub fn list_zr_locations_request(&self) -> RoomsLocationListZrLocationsRequest<'_> {
+        RoomsLocationListZrLocationsRequest {
+            inner: self.inner.list_zr_locations_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::rooms_location::RoomsLocation::list_all_zr_locations`].
+    pub fn list_all_zr_locations(
+        &self,
+        parent_location_id: &str,
+        type_: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::AddAzrLocationResponse>>> {
+        self.runtime
+            .block_on(self.inner.list_all_zr_locations(parent_location_id, type_))
+    }
+    /// The blocking version of [`crate::rooms_location::RoomsLocation::add_azr_location`].
+    pub fn add_azr_location(
+        &self,
+        body: &crate::types::AddAzrLocationRequest,
+    ) -> ClientResult<crate::Response<crate::types::AddAzrLocationResponse>> {
+        self.runtime.block_on(self.inner.add_azr_location(body))
+    }
+    /// The blocking version of [`crate::rooms_location::RoomsLocation::get_zr_location_profile`].
+    pub fn get_zr_location_profile(
+        &self,
+        location_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::GetZrLocationProfileResponse>> {
+        self.runtime
+            .block_on(self.inner.get_zr_location_profile(location_id))
+    }
+    /// The blocking version of [`crate::rooms_location::RoomsLocation::update_zr_location_profile`].
+    pub fn update_zr_location_profile(
+        &self,
+        location_id: &str,
+        body: &crate::types::GetZrLocationProfileResponse,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.update_zr_location_profile(location_id, body))
+    }
+    /// The blocking version of [`crate::rooms_location::RoomsLocation::get_zr_location_setting`].
+    pub fn get_zr_location_setting(
+        &self,
+        location_id: &str,
+        setting_type: &str,
+    ) -> ClientResult<crate::Response<crate::types::Domains>> {
+        self.runtime.block_on(
+            self.inner
+                .get_zr_location_setting(location_id, setting_type),
+        )
+    }
+    /// The blocking version of [`crate::rooms_location::RoomsLocation::get_zr_location_setting_request`].
+    pub fn get_zr_location_setting_request<'a>(
+        &'a self,
+        location_id: &'a str,
+    ) -> RoomsLocationGetZrLocationSettingRequest<'a> {
+        RoomsLocationGetZrLocationSettingRequest {
+            inner: self.inner.get_zr_location_setting_request(location_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::rooms_location::RoomsLocation::update_zr_location_settings`].
+    pub fn update_zr_location_settings(
+        &self,
+        location_id: &str,
+        setting_type: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .update_zr_location_settings(location_id, setting_type),
+        )
+    }
+    /// The blocking version of [`crate::rooms_location::RoomsLocation::update_zr_location_settings_request`].
+    pub fn update_zr_location_settings_request<'a>(
+        &'a self,
+        location_id: &'a str,
+    ) -> RoomsLocationUpdateZrLocationSettingsRequest<'a> {
+        RoomsLocationUpdateZrLocationSettingsRequest {
+            inner: self.inner.update_zr_location_settings_request(location_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::rooms_location::RoomsLocation::get_zr_location_structure`].
+    pub fn get_zr_location_structure(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::GetZrLocationStructureResponse>> {
+        self.runtime
+            .block_on(self.inner.get_zr_location_structure())
+    }
+    /// The blocking version of [`crate::rooms_location::RoomsLocation::update_zoom_structure`].
+    pub fn update_zoom_structure(
+        &self,
+        body: &crate::types::GetZrLocationStructureResponse,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.update_zoom_structure(body))
+    }
+    /// The blocking version of [`crate::rooms_location::RoomsLocation::change_parent_location`].
+    pub fn change_parent_location(
+        &self,
+        location_id: &str,
+        body: &crate::types::ChangeParentLocationRequest,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.change_parent_location(location_id, body))
+    }
+}
@@ -0,0 +1,175 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`SipConnectedAudio::list_internal_numbers`], set with a builder.
+pub struct SipConnectedAudioListInternalNumbersRequest<'a> {
+    inner: crate::sip_connected_audio::SipConnectedAudioListInternalNumbersRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> SipConnectedAudioListInternalNumbersRequest<'a> {
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.inner = self.inner.next_page_token(next_page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::InternalNumbers>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::sip_connected_audio::SipConnectedAudio`].
+pub struct SipConnectedAudio {
+    inner: crate::sip_connected_audio::SipConnectedAudio,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl SipConnectedAudio {
+    pub(crate) fn new(
+        inner: crate::sip_connected_audio::SipConnectedAudio,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        SipConnectedAudio { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::sip_connected_audio::SipConnectedAudio::list_sip_trunk_numbers`].
+    pub fn list_sip_trunk_numbers(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::ListSipTrunkNumbersResponse>> {
+        self.runtime.block_on(self.inner.list_sip_trunk_numbers())
+    }
+    /// The blocking version of [`crate::sip_connected_audio::SipConnectedAudio::assign_sip_config`].
+    pub fn assign_sip_config(
+        &self,
+        account_id: &str,
+        body: &crate::types::AssignSipConfigRequest,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.assign_sip_config(account_id, body))
+    }
+    /// The blocking version of [`crate::sip_connected_audio::SipConnectedAudio::assign_sip_trunk_numbers`].
+    pub fn assign_sip_trunk_numbers(
+        &self,
+        account_id: &str,
+        body: &crate::types::AssignSipTrunkNumbersRequest,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.assign_sip_trunk_numbers(account_id, body))
+    }
+    /// The blocking version of [`crate::sip_connected_audio::SipConnectedAudio::delete_all_sip_numbers`].
+    pub fn delete_all_sip_numbers(&self, account_id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete_all_sip_numbers(account_id))
+    }
+    /// The blocking version of [`crate::sip_connected_audio::SipConnectedAudio::list_sip_trunk`].
+    pub fn list_sip_trunk(
+        &self,
+        account_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ListSipTrunksResponseData>> {
+        self.runtime.block_on(self.inner.list_sip_trunk(account_id))
+    }
+    /// The blocking version of [`crate::sip_connected_audio::SipConnectedAudio::assign_sip_trunks`].
+    pub fn assign_sip_trunks(
+        &self,
+        account_id: &str,
+        body: &crate::types::AssignSipTrunksRequestData,
+    ) -> ClientResult<crate::Response<crate::types::AssignSipTrunksResponseData>> {
+        self.runtime
+            .block_on(self.inner.assign_sip_trunks(account_id, body))
+    }
+    /// The blocking version of [`crate::sip_connected_audio::SipConnectedAudio::delete_sip_trunk`].
+    pub fn delete_sip_trunk(
+        &self,
+        account_id: &str,
+        trunk_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Domains>> {
+        self.runtime
+            .block_on(self.inner.delete_sip_trunk(account_id, trunk_id))
+    }
+    /// The blocking version of [`crate::sip_connected_audio::SipConnectedAudio::list_internal_callout_countries`].
+    pub fn list_internal_callout_countries(
+        &self,
+        account_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ListInternalCalloutCountriesResponse>> {
+        self.runtime
+            .block_on(self.inner.list_internal_callout_countries(account_id))
+    }
+    /// The blocking version of [`crate::sip_connected_audio::SipConnectedAudio::add_callout_countries`].
+    pub fn add_callout_countries(
+        &self,
+        account_id: &str,
+        body: &crate::types::AddCalloutCountriesRequestData,
+    ) -> ClientResult<crate::Response<crate::types::AddCalloutCountriesResponse>> {
+        self.runtime
+            .block_on(self.inner.add_callout_countries(account_id, body))
+    }
+    /// The blocking version of [`crate::sip_connected_audio::SipConnectedAudio::delete_internal_call_out_country`].
+    pub fn delete_internal_call_out_country(
+        &self,
+        account_id: &str,
+        country_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .delete_internal_call_out_country(account_id, country_id),
+        )
+    }
+    /// The blocking version of [`crate::sip_connected_audio::SipConnectedAudio::list_internal_numbers`].
+    pub fn list_internal_numbers(
+        &self,
+        account_id: &str,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::InternalNumbers>>> {
+        self.runtime.block_on(self.inner.list_internal_numbers(
+            account_id,
+            page_size,
+            next_page_token,
+        ))
+    }
+    /// The blocking version of [`crate::sip_connected_audio::SipConnectedAudio::list_internal_numbers_request`].
+    pub fn list_internal_numbers_request<'a>(
+        &'a self,
+        account_id: &'a str,
+    ) -> SipConnectedAudioListInternalNumbersRequest<'a> {
+        SipConnectedAudioListInternalNumbersRequest {
+            inner: self.inner.list_internal_numbers_request(account_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::sip_connected_audio::SipConnectedAudio::list_all_internal_numbers`].
+    pub fn list_all_internal_numbers(
+        &self,
+        account_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::InternalNumbers>>> {
+        self.runtime
+            .block_on(self.inner.list_all_internal_numbers(account_id))
+    }
+    /// The blocking version of [`crate::sip_connected_audio::SipConnectedAudio::add_internal_numbers`].
+    pub fn add_internal_numbers(
+        &self,
+        account_id: &str,
+        body: &crate::types::AddInternalNumbersRequest,
+    ) -> ClientResult<crate::Response<crate::types::AddInternalNumbersResponseData>> {
+        self.runtime
+            .block_on(self.inner.add_internal_numbers(account_id, body))
+    }
+    /// The blocking version of [`crate::sip_connected_audio::SipConnectedAudio::delete_internal_number`].
+    pub fn delete_internal_number(
+        &self,
+        account_id: &str,
+        number_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.delete_internal_number(account_id, number_id))
+    }
+}
@@ -0,0 +1,102 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`SipPhone::list`], set with a builder.
+pub struct SipPhoneListRequest<'a> {
+    inner: crate::sip_phone::SipPhoneListRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> SipPhoneListRequest<'a> {
+    /// Sets the `page_number` query parameter.
+    pub fn page_number(mut self, page_number: i64) -> Self {
+        self.inner = self.inner.page_number(page_number);
+        self
+    }
+
+    /// Sets the `search_key` query parameter.
+    pub fn search_key(mut self, search_key: &'a str) -> Self {
+        self.inner = self.inner.search_key(search_key);
+        self
+    }
+
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.inner = self.inner.next_page_token(next_page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Phones>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::sip_phone::SipPhone`].
+pub struct SipPhone {
+    inner: crate::sip_phone::SipPhone,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl SipPhone {
+    pub(crate) fn new(
+        inner: crate::sip_phone::SipPhone,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        SipPhone { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::sip_phone::SipPhone::list`].
+    pub fn list(
+        &self,
+        page_number: i64,
+        search_key: &str,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Phones>>> {
+        self.runtime.block_on(
+            self.inner
+                .list(page_number, search_key, page_size, next_page_token),
+        )
+    }
+    /// The blocking version of [`crate::sip_phone::SipPhone::list_request`].
+    pub fn list_request(&self) -> SipPhoneListRequest<'_> {
+        SipPhoneListRequest {
+            inner: self.inner.list_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::sip_phone::SipPhone::list_all`].
+    pub fn list_all(
+        &self,
+        search_key: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Phones>>> {
+        self.runtime.block_on(self.inner.list_all(search_key))
+    }
+    /// The blocking version of [`crate::sip_phone::SipPhone::create`].
+    pub fn create(
+        &self,
+        body: &crate::types::CreateSipPhoneRequest,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.create(body))
+    }
+    /// The blocking version of [`crate::sip_phone::SipPhone::delete`].
+    pub fn delete(&self, phone_id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.delete(phone_id))
+    }
+    /// The blocking version of [`crate::sip_phone::SipPhone::update`].
+    pub fn update(
+        &self,
+        phone_id: &str,
+        body: &crate::types::UpdateSipPhoneRequest,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.update(phone_id, body))
+    }
+}
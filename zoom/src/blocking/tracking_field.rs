@@ -0,0 +1,52 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::tracking_field::TrackingField`].
+pub struct TrackingField {
+    inner: crate::tracking_field::TrackingField,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl TrackingField {
+    pub(crate) fn new(
+        inner: crate::tracking_field::TrackingField,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        TrackingField { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::tracking_field::TrackingField::trackingfield_list`].
+    pub fn trackingfield_list(&self) -> ClientResult<crate::Response<crate::types::Domains>> {
+        self.runtime.block_on(self.inner.trackingfield_list())
+    }
+    /// The blocking version of [`crate::tracking_field::TrackingField::trackingfield_create`].
+    pub fn trackingfield_create(
+        &self,
+        body: &crate::types::TrackingField,
+    ) -> ClientResult<crate::Response<crate::types::TrackingfieldGetResponseAllOf>> {
+        self.runtime.block_on(self.inner.trackingfield_create(body))
+    }
+    /// The blocking version of [`crate::tracking_field::TrackingField::trackingfield_get`].
+    pub fn trackingfield_get(
+        &self,
+        field_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::TrackingfieldGetResponseAllOf>> {
+        self.runtime
+            .block_on(self.inner.trackingfield_get(field_id))
+    }
+    /// The blocking version of [`crate::tracking_field::TrackingField::trackingfield_delete`].
+    pub fn trackingfield_delete(&self, field_id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.trackingfield_delete(field_id))
+    }
+    /// The blocking version of [`crate::tracking_field::TrackingField::trackingfield_update`].
+    pub fn trackingfield_update(
+        &self,
+        field_id: &str,
+        body: &crate::types::TrackingField,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.trackingfield_update(field_id, body))
+    }
+}
@@ -0,0 +1,73 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The blocking version of [`crate::tsp::Tsp`].
+pub struct Tsp {
+    inner: crate::tsp::Tsp,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Tsp {
+    pub(crate) fn new(inner: crate::tsp::Tsp, runtime: Arc<tokio::runtime::Runtime>) -> Self {
+        Tsp { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::tsp::Tsp::get`].
+    pub fn get(&self) -> ClientResult<crate::Response<crate::types::TspResponse>> {
+        self.runtime.block_on(self.inner.get())
+    }
+    /// The blocking version of [`crate::tsp::Tsp::update`].
+    pub fn update(
+        &self,
+        body: &crate::types::TspUpdateRequest,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.update(body))
+    }
+    /// The blocking version of [`crate::tsp::Tsp::user_ts_ps`].
+    pub fn user_ts_ps(
+        &self,
+        user_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::UserTsPsResponse>> {
+        self.runtime.block_on(self.inner.user_ts_ps(user_id))
+    }
+    /// The blocking version of [`crate::tsp::Tsp::user_create`].
+    pub fn user_create(
+        &self,
+        user_id: &str,
+        body: &crate::types::TspAccountsList,
+    ) -> ClientResult<crate::Response<crate::types::TspAccountsList>> {
+        self.runtime.block_on(self.inner.user_create(user_id, body))
+    }
+    /// The blocking version of [`crate::tsp::Tsp::user`].
+    pub fn user(
+        &self,
+        user_id: &str,
+        tsp_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::TspAccount>> {
+        self.runtime.block_on(self.inner.user(user_id, tsp_id))
+    }
+    /// The blocking version of [`crate::tsp::Tsp::user_delete`].
+    pub fn user_delete(&self, user_id: &str, tsp_id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.user_delete(user_id, tsp_id))
+    }
+    /// The blocking version of [`crate::tsp::Tsp::user_update`].
+    pub fn user_update(
+        &self,
+        user_id: &str,
+        tsp_id: &str,
+        body: &crate::types::TspAccountData,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.user_update(user_id, tsp_id, body))
+    }
+    /// The blocking version of [`crate::tsp::Tsp::url_update`].
+    pub fn url_update(
+        &self,
+        user_id: &str,
+        body: &crate::types::TspGlobalDialIn,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.url_update(user_id, body))
+    }
+}
@@ -0,0 +1,653 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Users::get_page`], set with a builder.
+pub struct UsersGetPageRequest<'a> {
+    inner: crate::users::UsersGetPageRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> UsersGetPageRequest<'a> {
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: crate::types::UsersStatus) -> Self {
+        self.inner = self.inner.status(status);
+        self
+    }
+
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Sets the `role_id` query parameter.
+    pub fn role_id(mut self, role_id: &'a str) -> Self {
+        self.inner = self.inner.role_id(role_id);
+        self
+    }
+
+    /// Sets the `page_number` query parameter.
+    pub fn page_number(mut self, page_number: &'a str) -> Self {
+        self.inner = self.inner.page_number(page_number);
+        self
+    }
+
+    /// Sets the `include_fields` query parameter.
+    pub fn include_fields(mut self, include_fields: crate::types::UsersIncludeFields) -> Self {
+        self.inner = self.inner.include_fields(include_fields);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.inner = self.inner.next_page_token(next_page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::UsersResponse>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Users::user`], set with a builder.
+pub struct UsersUserRequest<'a> {
+    inner: crate::users::UsersUserRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> UsersUserRequest<'a> {
+    /// Sets the `login_type` query parameter.
+    pub fn login_type(mut self, login_type: crate::types::LoginType) -> Self {
+        self.inner = self.inner.login_type(login_type);
+        self
+    }
+
+    /// Sets the `encrypted_email` query parameter.
+    pub fn encrypted_email(mut self, encrypted_email: bool) -> Self {
+        self.inner = self.inner.encrypted_email(encrypted_email);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::UserResponseAllOf>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Users::delete`], set with a builder.
+pub struct UsersDeleteRequest<'a> {
+    inner: crate::users::UsersDeleteRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> UsersDeleteRequest<'a> {
+    /// Sets the `action` query parameter.
+    pub fn action(mut self, action: crate::types::UserDeleteAction) -> Self {
+        self.inner = self.inner.action(action);
+        self
+    }
+
+    /// Sets the `transfer_email` query parameter.
+    pub fn transfer_email(mut self, transfer_email: &'a str) -> Self {
+        self.inner = self.inner.transfer_email(transfer_email);
+        self
+    }
+
+    /// Sets the `transfer_meeting` query parameter.
+    pub fn transfer_meeting(mut self, transfer_meeting: bool) -> Self {
+        self.inner = self.inner.transfer_meeting(transfer_meeting);
+        self
+    }
+
+    /// Sets the `transfer_webinar` query parameter.
+    pub fn transfer_webinar(mut self, transfer_webinar: bool) -> Self {
+        self.inner = self.inner.transfer_webinar(transfer_webinar);
+        self
+    }
+
+    /// Sets the `transfer_recording` query parameter.
+    pub fn transfer_recording(mut self, transfer_recording: bool) -> Self {
+        self.inner = self.inner.transfer_recording(transfer_recording);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Users::update`], set with a builder.
+pub struct UsersUpdateRequest<'a> {
+    inner: crate::users::UsersUpdateRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> UsersUpdateRequest<'a> {
+    /// Sets the `login_type` query parameter.
+    pub fn login_type(mut self, login_type: crate::types::LoginType) -> Self {
+        self.inner = self.inner.login_type(login_type);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Users::setting`], set with a builder.
+pub struct UsersSettingRequest<'a> {
+    inner: crate::users::UsersSettingRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> UsersSettingRequest<'a> {
+    /// Sets the `login_type` query parameter.
+    pub fn login_type(mut self, login_type: crate::types::LoginType) -> Self {
+        self.inner = self.inner.login_type(login_type);
+        self
+    }
+
+    /// Sets the `option` query parameter.
+    pub fn option(mut self, option: crate::types::OptionData) -> Self {
+        self.inner = self.inner.option(option);
+        self
+    }
+
+    /// Sets the `custom_query_fields` query parameter.
+    pub fn custom_query_fields(mut self, custom_query_fields: &'a str) -> Self {
+        self.inner = self.inner.custom_query_fields(custom_query_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::UserSettingsResponseOneOf>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Users::settings_update`], set with a builder.
+pub struct UsersSettingsUpdateRequest<'a> {
+    inner: crate::users::UsersSettingsUpdateRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> UsersSettingsUpdateRequest<'a> {
+    /// Sets the `option` query parameter.
+    pub fn option(mut self, option: crate::types::UserSettingsUpdateOption) -> Self {
+        self.inner = self.inner.option(option);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Users::token`], set with a builder.
+pub struct UsersTokenRequest<'a> {
+    inner: crate::users::UsersTokenRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> UsersTokenRequest<'a> {
+    /// Sets the `type` query parameter.
+    pub fn type_(mut self, type_: crate::types::UserTokenType) -> Self {
+        self.inner = self.inner.type_(type_);
+        self
+    }
+
+    /// Sets the `ttl` query parameter.
+    pub fn ttl(mut self, ttl: i64) -> Self {
+        self.inner = self.inner.ttl(ttl);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::UserZakResponse>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Users::email`], set with a builder.
+pub struct UsersEmailRequest<'a> {
+    inner: crate::users::UsersEmailRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> UsersEmailRequest<'a> {
+    /// Sets the `email` query parameter.
+    pub fn email(mut self, email: &'a str) -> Self {
+        self.inner = self.inner.email(email);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::UserEmailResponse>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Users::vanity_name`], set with a builder.
+pub struct UsersVanityNameRequest<'a> {
+    inner: crate::users::UsersVanityNameRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> UsersVanityNameRequest<'a> {
+    /// Sets the `vanity_name` query parameter.
+    pub fn vanity_name(mut self, vanity_name: &'a str) -> Self {
+        self.inner = self.inner.vanity_name(vanity_name);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::UserVanityNameResponse>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Users::del_vb`], set with a builder.
+pub struct UsersDelVbRequest<'a> {
+    inner: crate::users::UsersDelVbRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> UsersDelVbRequest<'a> {
+    /// Sets the `file_ids` query parameter.
+    pub fn file_ids(mut self, file_ids: &'a str) -> Self {
+        self.inner = self.inner.file_ids(file_ids);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::users::Users`].
+pub struct Users {
+    inner: crate::users::Users,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Users {
+    pub(crate) fn new(inner: crate::users::Users, runtime: Arc<tokio::runtime::Runtime>) -> Self {
+        Users { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::users::Users::get_page`].
+    pub fn get_page(
+        &self,
+        status: crate::types::UsersStatus,
+        page_size: i64,
+        role_id: &str,
+        page_number: &str,
+        include_fields: crate::types::UsersIncludeFields,
+        next_page_token: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::UsersResponse>>> {
+        self.runtime.block_on(self.inner.get_page(
+            status,
+            page_size,
+            role_id,
+            page_number,
+            include_fields,
+            next_page_token,
+        ))
+    }
+    /// The blocking version of [`crate::users::Users::get_page_request`].
+    pub fn get_page_request(&self) -> UsersGetPageRequest<'_> {
+        UsersGetPageRequest {
+            inner: self.inner.get_page_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::users::Users::get_all`].
+    pub fn get_all(
+        &self,
+        status: crate::types::UsersStatus,
+        role_id: &str,
+        include_fields: crate::types::UsersIncludeFields,
+    ) -> ClientResult<crate::Response<Vec<crate::types::UsersResponse>>> {
+        self.runtime
+            .block_on(self.inner.get_all(status, role_id, include_fields))
+    }
+    /// The blocking version of [`crate::users::Users::create`].
+    pub fn create(
+        &self,
+        body: &crate::types::UserCreateRequest,
+    ) -> ClientResult<crate::Response<crate::types::UserCreateResponse>> {
+        self.runtime.block_on(self.inner.create(body))
+    }
+    /// The blocking version of [`crate::users::Users::user`].
+    pub fn user(
+        &self,
+        user_id: &str,
+        login_type: crate::types::LoginType,
+        encrypted_email: bool,
+    ) -> ClientResult<crate::Response<crate::types::UserResponseAllOf>> {
+        self.runtime
+            .block_on(self.inner.user(user_id, login_type, encrypted_email))
+    }
+    /// The blocking version of [`crate::users::Users::user_request`].
+    pub fn user_request<'a>(&'a self, user_id: &'a str) -> UsersUserRequest<'a> {
+        UsersUserRequest {
+            inner: self.inner.user_request(user_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::users::Users::delete`].
+    pub fn delete(
+        &self,
+        user_id: &str,
+        action: crate::types::UserDeleteAction,
+        transfer_email: &str,
+        transfer_meeting: bool,
+        transfer_webinar: bool,
+        transfer_recording: bool,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.delete(
+            user_id,
+            action,
+            transfer_email,
+            transfer_meeting,
+            transfer_webinar,
+            transfer_recording,
+        ))
+    }
+    /// The blocking version of [`crate::users::Users::delete_request`].
+    pub fn delete_request<'a>(&'a self, user_id: &'a str) -> UsersDeleteRequest<'a> {
+        UsersDeleteRequest {
+            inner: self.inner.delete_request(user_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::users::Users::update`].
+    pub fn update(
+        &self,
+        user_id: &str,
+        login_type: crate::types::LoginType,
+        body: &crate::types::UserUpdate,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.update(user_id, login_type, body))
+    }
+    /// The blocking version of [`crate::users::Users::update_request`].
+    pub fn update_request<'a>(
+        &'a self,
+        user_id: &'a str,
+        body: &'a crate::types::UserUpdate,
+    ) -> UsersUpdateRequest<'a> {
+        UsersUpdateRequest {
+            inner: self.inner.update_request(user_id, body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::users::Users::zak`].
+    pub fn zak(&self) -> ClientResult<crate::Response<crate::types::UserZakResponse>> {
+        self.runtime.block_on(self.inner.zak())
+    }
+    /// The blocking version of [`crate::users::Users::assistant`].
+    pub fn assistant(
+        &self,
+        user_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::UserAssistantsList>> {
+        self.runtime.block_on(self.inner.assistant(user_id))
+    }
+    /// The blocking version of [`crate::users::Users::assistant_create`].
+    pub fn assistant_create(
+        &self,
+        user_id: &str,
+        body: &crate::types::UserAssistantsList,
+    ) -> ClientResult<crate::Response<crate::types::AddRoleMembersResponse>> {
+        self.runtime
+            .block_on(self.inner.assistant_create(user_id, body))
+    }
+    /// The blocking version of [`crate::users::Users::assistants_delete`].
+    pub fn assistants_delete(&self, user_id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.assistants_delete(user_id))
+    }
+    /// The blocking version of [`crate::users::Users::assistant_delete`].
+    pub fn assistant_delete(
+        &self,
+        user_id: &str,
+        assistant_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.assistant_delete(user_id, assistant_id))
+    }
+    /// The blocking version of [`crate::users::Users::scheduler`].
+    pub fn scheduler(
+        &self,
+        user_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::UserSchedulersList>> {
+        self.runtime.block_on(self.inner.scheduler(user_id))
+    }
+    /// The blocking version of [`crate::users::Users::schedulers_delete`].
+    pub fn schedulers_delete(&self, user_id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.schedulers_delete(user_id))
+    }
+    /// The blocking version of [`crate::users::Users::scheduler_delete`].
+    pub fn scheduler_delete(
+        &self,
+        user_id: &str,
+        scheduler_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.scheduler_delete(user_id, scheduler_id))
+    }
+    /// The blocking version of [`crate::users::Users::picture`].
+    pub fn picture(&self, user_id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.picture(user_id))
+    }
+    /// The blocking version of [`crate::users::Users::settings_domains`].
+    pub fn settings_domains(
+        &self,
+        user_id: &str,
+        login_type: crate::types::LoginType,
+        option: crate::types::OptionData,
+        custom_query_fields: &str,
+    ) -> ClientResult<crate::Response<crate::types::Domains>> {
+        self.runtime.block_on(self.inner.settings_domains(
+            user_id,
+            login_type,
+            option,
+            custom_query_fields,
+        ))
+    }
+    /// The blocking version of [`crate::users::Users::settings_user`].
+    pub fn settings_user(
+        &self,
+        user_id: &str,
+        login_type: crate::types::LoginType,
+        option: crate::types::OptionData,
+        custom_query_fields: &str,
+    ) -> ClientResult<crate::Response<crate::types::UserSettings>> {
+        self.runtime.block_on(self.inner.settings_user(
+            user_id,
+            login_type,
+            option,
+            custom_query_fields,
+        ))
+    }
+    /// The blocking version of [`crate::users::Users::settings_meeting_security`].
+    pub fn settings_meeting_security(
+        &self,
+        user_id: &str,
+        login_type: crate::types::LoginType,
+        option: crate::types::OptionData,
+        custom_query_fields: &str,
+    ) -> ClientResult<crate::Response<crate::types::MeetingSecuritySettings>> {
+        self.runtime.block_on(self.inner.settings_meeting_security(
+            user_id,
+            login_type,
+            option,
+            custom_query_fields,
+        ))
+    }
+    /// The blocking version of [`crate::users::Users::setting`].
+    pub fn setting(
+        &self,
+        user_id: &str,
+        login_type: crate::types::LoginType,
+        option: crate::types::OptionData,
+        custom_query_fields: &str,
+    ) -> ClientResult<crate::Response<crate::types::UserSettingsResponseOneOf>> {
+        self.runtime.block_on(
+            self.inner
+                .setting(user_id, login_type, option, custom_query_fields),
+        )
+    }
+    /// The blocking version of [`crate::users::Users::setting_request`].
+    pub fn setting_request<'a>(&'a self, user_id: &'a str) -> UsersSettingRequest<'a> {
+        UsersSettingRequest {
+            inner: self.inner.setting_request(user_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::users::Users::settings_update`].
+    pub fn settings_update(
+        &self,
+        option: crate::types::UserSettingsUpdateOption,
+        user_id: &str,
+        body: &crate::types::UserSettingsUpdateRequestOneOf,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.settings_update(option, user_id, body))
+    }
+    /// The blocking version of [`crate::users::Users::settings_update_request`].
+    pub fn settings_update_request<'a>(
+        &'a self,
+        user_id: &'a str,
+        body: &'a crate::types::UserSettingsUpdateRequestOneOf,
+    ) -> UsersSettingsUpdateRequest<'a> {
+        UsersSettingsUpdateRequest {
+            inner: self.inner.settings_update_request(user_id, body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::users::Users::status`].
+    pub fn status(
+        &self,
+        user_id: &str,
+        body: &crate::types::UserStatusRequest,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.status(user_id, body))
+    }
+    /// The blocking version of [`crate::users::Users::password`].
+    pub fn password(
+        &self,
+        user_id: &str,
+        body: &crate::types::UserPasswordRequest,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.password(user_id, body))
+    }
+    /// The blocking version of [`crate::users::Users::permission`].
+    pub fn permission(
+        &self,
+        user_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::UserPermissions>> {
+        self.runtime.block_on(self.inner.permission(user_id))
+    }
+    /// The blocking version of [`crate::users::Users::token`].
+    pub fn token(
+        &self,
+        user_id: &str,
+        type_: crate::types::UserTokenType,
+        ttl: i64,
+    ) -> ClientResult<crate::Response<crate::types::UserZakResponse>> {
+        self.runtime.block_on(self.inner.token(user_id, type_, ttl))
+    }
+    /// The blocking version of [`crate::users::Users::token_request`].
+    pub fn token_request<'a>(&'a self, user_id: &'a str) -> UsersTokenRequest<'a> {
+        UsersTokenRequest {
+            inner: self.inner.token_request(user_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::users::Users::sso_token_delete`].
+    pub fn sso_token_delete(&self, user_id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.sso_token_delete(user_id))
+    }
+    /// The blocking version of [`crate::users::Users::email`].
+    pub fn email(
+        &self,
+        email: &str,
+    ) -> ClientResult<crate::Response<crate::types::UserEmailResponse>> {
+        self.runtime.block_on(self.inner.email(email))
+    }
+    /// The blocking version of [`crate::users::Users::email_request`].
+    pub fn email_request(&self) -> UsersEmailRequest<'_> {
+        UsersEmailRequest {
+            inner: self.inner.email_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::users::Users::email_update`].
+    pub fn email_update(
+        &self,
+        user_id: &str,
+        body: &crate::types::Members,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.email_update(user_id, body))
+    }
+    /// The blocking version of [`crate::users::Users::vanity_name`].
+    pub fn vanity_name(
+        &self,
+        vanity_name: &str,
+    ) -> ClientResult<crate::Response<crate::types::UserVanityNameResponse>> {
+        self.runtime.block_on(self.inner.vanity_name(vanity_name))
+    }
+    /// The blocking version of [`crate::users::Users::vanity_name_request`].
+    pub fn vanity_name_request(&self) -> UsersVanityNameRequest<'_> {
+        UsersVanityNameRequest {
+            inner: self.inner.vanity_name_request(),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::users::Users::switch_account`].
+    pub fn switch_account(
+        &self,
+        account_id: &str,
+        user_id: &str,
+        body: &crate::types::SwitchUserAccountRequest,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.switch_account(account_id, user_id, body))
+    }
+    /// The blocking version of [`crate::users::Users::update_presence_status`].
+    pub fn update_presence_status(
+        &self,
+        user_id: &str,
+        body: &crate::types::UpdatePresenceStatusRequestData,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.update_presence_status(user_id, body))
+    }
+    /// The blocking version of [`crate::users::Users::upload_v_buser`].
+    pub fn upload_v_buser(
+        &self,
+        user_id: &str,
+        body: &crate::types::UploadVbRequest,
+    ) -> ClientResult<crate::Response<crate::types::Files>> {
+        self.runtime
+            .block_on(self.inner.upload_v_buser(user_id, body))
+    }
+    /// The blocking version of [`crate::users::Users::del_vb`].
+    pub fn del_vb(&self, user_id: &str, file_ids: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.del_vb(user_id, file_ids))
+    }
+    /// The blocking version of [`crate::users::Users::del_vb_request`].
+    pub fn del_vb_request<'a>(&'a self, user_id: &'a str) -> UsersDelVbRequest<'a> {
+        UsersDelVbRequest {
+            inner: self.inner.del_vb_request(user_id),
+            runtime: &self.runtime,
+        }
+    }
+}
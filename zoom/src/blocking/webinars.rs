@@ -0,0 +1,718 @@
+use std::sync::Arc;
+
+use crate::ClientResult;
+
+/// The parameters of [`Webinars::get`], set with a builder.
+pub struct WebinarsGetRequest<'a> {
+    inner: crate::webinars::WebinarsGetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> WebinarsGetRequest<'a> {
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Sets the `page_number` query parameter.
+    pub fn page_number(mut self, page_number: i64) -> Self {
+        self.inner = self.inner.page_number(page_number);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::Domains>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Webinars::webinar`], set with a builder.
+pub struct WebinarsWebinarRequest<'a> {
+    inner: crate::webinars::WebinarsWebinarRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> WebinarsWebinarRequest<'a> {
+    /// Sets the `occurrence_id` query parameter.
+    pub fn occurrence_id(mut self, occurrence_id: &'a str) -> Self {
+        self.inner = self.inner.occurrence_id(occurrence_id);
+        self
+    }
+
+    /// Sets the `show_previous_occurrences` query parameter.
+    pub fn show_previous_occurrences(mut self, show_previous_occurrences: bool) -> Self {
+        self.inner = self
+            .inner
+            .show_previous_occurrences(show_previous_occurrences);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::WebinarResponseAllOf>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Webinars::delete`], set with a builder.
+pub struct WebinarsDeleteRequest<'a> {
+    inner: crate::webinars::WebinarsDeleteRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> WebinarsDeleteRequest<'a> {
+    /// Sets the `occurrence_id` query parameter.
+    pub fn occurrence_id(mut self, occurrence_id: &'a str) -> Self {
+        self.inner = self.inner.occurrence_id(occurrence_id);
+        self
+    }
+
+    /// Sets the `cancel_webinar_reminder` query parameter.
+    pub fn cancel_webinar_reminder(mut self, cancel_webinar_reminder: &'a str) -> Self {
+        self.inner = self.inner.cancel_webinar_reminder(cancel_webinar_reminder);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Webinars::update`], set with a builder.
+pub struct WebinarsUpdateRequest<'a> {
+    inner: crate::webinars::WebinarsUpdateRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> WebinarsUpdateRequest<'a> {
+    /// Sets the `occurrence_id` query parameter.
+    pub fn occurrence_id(mut self, occurrence_id: &'a str) -> Self {
+        self.inner = self.inner.occurrence_id(occurrence_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Webinars::list_participants`], set with a builder.
+pub struct WebinarsListParticipantsRequest<'a> {
+    inner: crate::webinars::WebinarsListParticipantsRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> WebinarsListParticipantsRequest<'a> {
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.inner = self.inner.next_page_token(next_page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Participants>>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Webinars::registrant`], set with a builder.
+pub struct WebinarsRegistrantRequest<'a> {
+    inner: crate::webinars::WebinarsRegistrantRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> WebinarsRegistrantRequest<'a> {
+    /// Sets the `occurrence_id` query parameter.
+    pub fn occurrence_id(mut self, occurrence_id: &'a str) -> Self {
+        self.inner = self.inner.occurrence_id(occurrence_id);
+        self
+    }
+
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: crate::types::MeetingRegistrantsStatus) -> Self {
+        self.inner = self.inner.status(status);
+        self
+    }
+
+    /// Sets the `tracking_source_id` query parameter.
+    pub fn tracking_source_id(mut self, tracking_source_id: &'a str) -> Self {
+        self.inner = self.inner.tracking_source_id(tracking_source_id);
+        self
+    }
+
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Sets the `page_number` query parameter.
+    pub fn page_number(mut self, page_number: i64) -> Self {
+        self.inner = self.inner.page_number(page_number);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.inner = self.inner.next_page_token(next_page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::Domains>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Webinars::registrant_create`], set with a builder.
+pub struct WebinarsRegistrantCreateRequest<'a> {
+    inner: crate::webinars::WebinarsRegistrantCreateRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> WebinarsRegistrantCreateRequest<'a> {
+    /// Sets the `occurrence_ids` query parameter.
+    pub fn occurrence_ids(mut self, occurrence_ids: &'a str) -> Self {
+        self.inner = self.inner.occurrence_ids(occurrence_ids);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::WebinarRegistrantCreateResponse>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Webinars::registrant_status`], set with a builder.
+pub struct WebinarsRegistrantStatusRequest<'a> {
+    inner: crate::webinars::WebinarsRegistrantStatusRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> WebinarsRegistrantStatusRequest<'a> {
+    /// Sets the `occurrence_id` query parameter.
+    pub fn occurrence_id(mut self, occurrence_id: &'a str) -> Self {
+        self.inner = self.inner.occurrence_id(occurrence_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Webinars::registrant_get`], set with a builder.
+pub struct WebinarsRegistrantGetRequest<'a> {
+    inner: crate::webinars::WebinarsRegistrantGetRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> WebinarsRegistrantGetRequest<'a> {
+    /// Sets the `occurrence_id` query parameter.
+    pub fn occurrence_id(mut self, occurrence_id: &'a str) -> Self {
+        self.inner = self.inner.occurrence_id(occurrence_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::Domains>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Webinars::delete_registrant`], set with a builder.
+pub struct WebinarsDeleteRegistrantRequest<'a> {
+    inner: crate::webinars::WebinarsDeleteRegistrantRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> WebinarsDeleteRegistrantRequest<'a> {
+    /// Sets the `occurrence_id` query parameter.
+    pub fn occurrence_id(mut self, occurrence_id: &'a str) -> Self {
+        self.inner = self.inner.occurrence_id(occurrence_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The parameters of [`Webinars::absentee`], set with a builder.
+pub struct WebinarsAbsenteeRequest<'a> {
+    inner: crate::webinars::WebinarsAbsenteeRequest<'a>,
+    runtime: &'a tokio::runtime::Runtime,
+}
+
+impl<'a> WebinarsAbsenteeRequest<'a> {
+    /// Sets the `occurrence_id` query parameter.
+    pub fn occurrence_id(mut self, occurrence_id: &'a str) -> Self {
+        self.inner = self.inner.occurrence_id(occurrence_id);
+        self
+    }
+
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.inner = self.inner.next_page_token(next_page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub fn send(self) -> ClientResult<crate::Response<crate::types::Domains>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+/// The blocking version of [`crate::webinars::Webinars`].
+pub struct Webinars {
+    inner: crate::webinars::Webinars,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Webinars {
+    pub(crate) fn new(
+        inner: crate::webinars::Webinars,
+        runtime: Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Webinars { inner, runtime }
+    }
+
+    /// The blocking version of [`crate::webinars::Webinars::get`].
+    pub fn get(
+        &self,
+        user_id: &str,
+        page_size: i64,
+        page_number: i64,
+    ) -> ClientResult<crate::Response<crate::types::Domains>> {
+        self.runtime
+            .block_on(self.inner.get(user_id, page_size, page_number))
+    }
+    /// The blocking version of [`crate::webinars::Webinars::get_request`].
+    pub fn get_request<'a>(&'a self, user_id: &'a str) -> WebinarsGetRequest<'a> {
+        WebinarsGetRequest {
+            inner: self.inner.get_request(user_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::webinars::Webinars::create`].
+    pub fn create(
+        &self,
+        user_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::WebinarCreateResponseAllOf>> {
+        self.runtime.block_on(self.inner.create(user_id))
+    }
+    /// The blocking version of [`crate::webinars::Webinars::webinar`].
+    pub fn webinar(
+        &self,
+        webinar_id: i64,
+        occurrence_id: &str,
+        show_previous_occurrences: bool,
+    ) -> ClientResult<crate::Response<crate::types::WebinarResponseAllOf>> {
+        self.runtime.block_on(self.inner.webinar(
+            webinar_id,
+            occurrence_id,
+            show_previous_occurrences,
+        ))
+    }
+    /// The blocking version of [`crate::webinars::Webinars::webinar_request`].
+    pub fn webinar_request(&self, webinar_id: i64) -> WebinarsWebinarRequest<'_> {
+        WebinarsWebinarRequest {
+            inner: self.inner.webinar_request(webinar_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::webinars::Webinars::delete`].
+    pub fn delete(
+        &self,
+        webinar_id: i64,
+        occurrence_id: &str,
+        cancel_webinar_reminder: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .delete(webinar_id, occurrence_id, cancel_webinar_reminder),
+        )
+    }
+    /// The blocking version of [`crate::webinars::Webinars::delete_request`].
+    pub fn delete_request(&self, webinar_id: i64) -> WebinarsDeleteRequest<'_> {
+        WebinarsDeleteRequest {
+            inner: self.inner.delete_request(webinar_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::webinars::Webinars::update`].
+    pub fn update(
+        &self,
+        webinar_id: i64,
+        occurrence_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.update(webinar_id, occurrence_id))
+    }
+    /// The blocking version of [`crate::webinars::Webinars::update_request`].
+    pub fn update_request(&self, webinar_id: i64) -> WebinarsUpdateRequest<'_> {
+        WebinarsUpdateRequest {
+            inner: self.inner.update_request(webinar_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::webinars::Webinars::list_participants`].
+    pub fn list_participants(
+        &self,
+        webinar_id: &str,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Participants>>> {
+        self.runtime.block_on(
+            self.inner
+                .list_participants(webinar_id, page_size, next_page_token),
+        )
+    }
+    /// The blocking version of [`crate::webinars::Webinars::list_participants_request`].
+    pub fn list_participants_request<'a>(
+        &'a self,
+        webinar_id: &'a str,
+    ) -> WebinarsListParticipantsRequest<'a> {
+        WebinarsListParticipantsRequest {
+            inner: self.inner.list_participants_request(webinar_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::webinars::Webinars::list_all_participants`].
+    pub fn list_all_participants(
+        &self,
+        webinar_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Participants>>> {
+        self.runtime
+            .block_on(self.inner.list_all_participants(webinar_id))
+    }
+    /// The blocking version of [`crate::webinars::Webinars::status`].
+    pub fn status(
+        &self,
+        webinar_id: i64,
+        body: &crate::types::WebinarStatusRequest,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.status(webinar_id, body))
+    }
+    /// The blocking version of [`crate::webinars::Webinars::panelist`].
+    pub fn panelist(
+        &self,
+        webinar_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::Domains>> {
+        self.runtime.block_on(self.inner.panelist(webinar_id))
+    }
+    /// The blocking version of [`crate::webinars::Webinars::panelist_create`].
+    pub fn panelist_create(
+        &self,
+        webinar_id: i64,
+        body: &crate::types::WebinarPanelist,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.panelist_create(webinar_id, body))
+    }
+    /// The blocking version of [`crate::webinars::Webinars::panelists_delete`].
+    pub fn panelists_delete(&self, webinar_id: i64) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.panelists_delete(webinar_id))
+    }
+    /// The blocking version of [`crate::webinars::Webinars::panelist_delete`].
+    pub fn panelist_delete(
+        &self,
+        webinar_id: i64,
+        panelist_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.panelist_delete(webinar_id, panelist_id))
+    }
+    /// The blocking version of [`crate::webinars::Webinars::registrant`].
+    pub fn registrant(
+        &self,
+        webinar_id: &str,
+        occurrence_id: &str,
+        status: crate::types::MeetingRegistrantsStatus,
+        tracking_source_id: &str,
+        page_size: i64,
+        page_number: i64,
+        next_page_token: &str,
+    ) -> ClientResult<crate::Response<crate::types::Domains>> {
+        self.runtime.block_on(self.inner.registrant(
+            webinar_id,
+            occurrence_id,
+            status,
+            tracking_source_id,
+            page_size,
+            page_number,
+            next_page_token,
+        ))
+    }
+    /// The blocking version of [`crate::webinars::Webinars::registrant_request`].
+    pub fn registrant_request<'a>(&'a self, webinar_id: &'a str) -> WebinarsRegistrantRequest<'a> {
+        WebinarsRegistrantRequest {
+            inner: self.inner.registrant_request(webinar_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::webinars::Webinars::registrant_create`].
+    pub fn registrant_create(
+        &self,
+        webinar_id: &str,
+        occurrence_ids: &str,
+    ) -> ClientResult<crate::Response<crate::types::WebinarRegistrantCreateResponse>> {
+        self.runtime
+            .block_on(self.inner.registrant_create(webinar_id, occurrence_ids))
+    }
+    /// The blocking version of [`crate::webinars::Webinars::registrant_create_request`].
+    pub fn registrant_create_request<'a>(
+        &'a self,
+        webinar_id: &'a str,
+    ) -> WebinarsRegistrantCreateRequest<'a> {
+        WebinarsRegistrantCreateRequest {
+            inner: self.inner.registrant_create_request(webinar_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::webinars::Webinars::add_batch_registrants`].
+    pub fn add_batch_registrants(
+        &self,
+        webinar_id: &str,
+        body: &crate::types::AddBatchRegistrantsRequest,
+    ) -> ClientResult<crate::Response<crate::types::AddBatchRegistrantsResponse>> {
+        self.runtime
+            .block_on(self.inner.add_batch_registrants(webinar_id, body))
+    }
+    /// The blocking version of [`crate::webinars::Webinars::registrant_status`].
+    pub fn registrant_status(
+        &self,
+        webinar_id: i64,
+        occurrence_id: &str,
+        body: &crate::types::RegistrantStatus,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(
+            self.inner
+                .registrant_status(webinar_id, occurrence_id, body),
+        )
+    }
+    /// The blocking version of [`crate::webinars::Webinars::registrant_status_request`].
+    pub fn registrant_status_request<'a>(
+        &'a self,
+        webinar_id: i64,
+        body: &'a crate::types::RegistrantStatus,
+    ) -> WebinarsRegistrantStatusRequest<'a> {
+        WebinarsRegistrantStatusRequest {
+            inner: self.inner.registrant_status_request(webinar_id, body),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::webinars::Webinars::past`].
+    pub fn past(&self, webinar_id: i64) -> ClientResult<crate::Response<crate::types::Domains>> {
+        self.runtime.block_on(self.inner.past(webinar_id))
+    }
+    /// The blocking version of [`crate::webinars::Webinars::poll`].
+    pub fn poll(&self, webinar_id: i64) -> ClientResult<crate::Response<crate::types::Domains>> {
+        self.runtime.block_on(self.inner.poll(webinar_id))
+    }
+    /// The blocking version of [`crate::webinars::Webinars::poll_create`].
+    pub fn poll_create(
+        &self,
+        webinar_id: i64,
+        body: &crate::types::Poll,
+    ) -> ClientResult<crate::Response<crate::types::MeetingPollGetResponseAllOf>> {
+        self.runtime
+            .block_on(self.inner.poll_create(webinar_id, body))
+    }
+    /// The blocking version of [`crate::webinars::Webinars::poll_get`].
+    pub fn poll_get(
+        &self,
+        webinar_id: i64,
+        poll_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::MeetingPollGetResponseAllOf>> {
+        self.runtime
+            .block_on(self.inner.poll_get(webinar_id, poll_id))
+    }
+    /// The blocking version of [`crate::webinars::Webinars::poll_update`].
+    pub fn poll_update(
+        &self,
+        webinar_id: i64,
+        poll_id: &str,
+        body: &crate::types::Poll,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.poll_update(webinar_id, poll_id, body))
+    }
+    /// The blocking version of [`crate::webinars::Webinars::poll_delete`].
+    pub fn poll_delete(&self, webinar_id: i64, poll_id: &str) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.poll_delete(webinar_id, poll_id))
+    }
+    /// The blocking version of [`crate::webinars::Webinars::registrants_questions_get`].
+    pub fn registrants_questions_get(
+        &self,
+        webinar_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::WebinarRegistrantQuestions>> {
+        self.runtime
+            .block_on(self.inner.registrants_questions_get(webinar_id))
+    }
+    /// The blocking version of [`crate::webinars::Webinars::registrant_question_update`].
+    pub fn registrant_question_update(
+        &self,
+        webinar_id: i64,
+        body: &crate::types::WebinarRegistrantQuestions,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.registrant_question_update(webinar_id, body))
+    }
+    /// The blocking version of [`crate::webinars::Webinars::registrant_get`].
+    pub fn registrant_get(
+        &self,
+        webinar_id: &str,
+        registrant_id: &str,
+        occurrence_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Domains>> {
+        self.runtime.block_on(
+            self.inner
+                .registrant_get(webinar_id, registrant_id, occurrence_id),
+        )
+    }
+    /// The blocking version of [`crate::webinars::Webinars::registrant_get_request`].
+    pub fn registrant_get_request<'a>(
+        &'a self,
+        webinar_id: &'a str,
+        registrant_id: &'a str,
+    ) -> WebinarsRegistrantGetRequest<'a> {
+        WebinarsRegistrantGetRequest {
+            inner: self.inner.registrant_get_request(webinar_id, registrant_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::webinars::Webinars::delete_registrant`].
+    pub fn delete_registrant(
+        &self,
+        webinar_id: &str,
+        registrant_id: &str,
+        occurrence_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime.block_on(self.inner.delete_registrant(
+            webinar_id,
+            registrant_id,
+            occurrence_id,
+        ))
+    }
+    /// The blocking version of [`crate::webinars::Webinars::delete_registrant_request`].
+    pub fn delete_registrant_request<'a>(
+        &'a self,
+        webinar_id: &'a str,
+        registrant_id: &'a str,
+    ) -> WebinarsDeleteRegistrantRequest<'a> {
+        WebinarsDeleteRegistrantRequest {
+            inner: self
+                .inner
+                .delete_registrant_request(webinar_id, registrant_id),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::webinars::Webinars::absentee`].
+    pub fn absentee(
+        &self,
+        occurrence_id: &str,
+        page_size: i64,
+        next_page_token: &str,
+        webinar_uuid: &str,
+    ) -> ClientResult<crate::Response<crate::types::Domains>> {
+        self.runtime.block_on(self.inner.absentee(
+            occurrence_id,
+            page_size,
+            next_page_token,
+            webinar_uuid,
+        ))
+    }
+    /// The blocking version of [`crate::webinars::Webinars::absentee_request`].
+    pub fn absentee_request<'a>(&'a self, webinar_uuid: &'a str) -> WebinarsAbsenteeRequest<'a> {
+        WebinarsAbsenteeRequest {
+            inner: self.inner.absentee_request(webinar_uuid),
+            runtime: &self.runtime,
+        }
+    }
+    /// The blocking version of [`crate::webinars::Webinars::get_tracking_sources`].
+    pub fn get_tracking_sources(
+        &self,
+        webinar_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::GetTrackingSourcesResponse>> {
+        self.runtime
+            .block_on(self.inner.get_tracking_sources(webinar_id))
+    }
+    /// The blocking version of [`crate::webinars::Webinars::list_past_poll_results`].
+    pub fn list_past_poll_results(
+        &self,
+        webinar_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ReportMeetingPollsResponse>> {
+        self.runtime
+            .block_on(self.inner.list_past_poll_results(webinar_id))
+    }
+    /// The blocking version of [`crate::webinars::Webinars::list_past_qa`].
+    pub fn list_past_qa(
+        &self,
+        webinar_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ReportWebinarQaResponse>> {
+        self.runtime.block_on(self.inner.list_past_qa(webinar_id))
+    }
+    /// The blocking version of [`crate::webinars::Webinars::list_templates`].
+    pub fn list_templates(
+        &self,
+        user_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ListWebinarTemplatesResponse>> {
+        self.runtime.block_on(self.inner.list_templates(user_id))
+    }
+    /// The blocking version of [`crate::webinars::Webinars::get_live_stream_details`].
+    pub fn get_live_stream_details(
+        &self,
+        webinar_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::GetLiveStreamDetailsResponse>> {
+        self.runtime
+            .block_on(self.inner.get_live_stream_details(webinar_id))
+    }
+    /// The blocking version of [`crate::webinars::Webinars::live_stream_update`].
+    pub fn live_stream_update(
+        &self,
+        webinar_id: &str,
+        body: &crate::types::MeetingLiveStream,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.live_stream_update(webinar_id, body))
+    }
+    /// The blocking version of [`crate::webinars::Webinars::live_stream_status_update`].
+    pub fn live_stream_status_update(
+        &self,
+        webinar_id: i64,
+        body: &crate::types::WebinarLiveStreamStatus,
+    ) -> ClientResult<crate::Response<()>> {
+        self.runtime
+            .block_on(self.inner.live_stream_status_update(webinar_id, body))
+    }
+    /// The blocking version of [`crate::webinars::Webinars::invite_links_create`].
+    pub fn invite_links_create(
+        &self,
+        webinar_id: i64,
+        body: &crate::types::InviteLink,
+    ) -> ClientResult<crate::Response<crate::types::InviteLinks>> {
+        self.runtime
+            .block_on(self.inner.invite_links_create(webinar_id, body))
+    }
+}
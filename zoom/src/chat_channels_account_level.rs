@@ -1,6 +1,76 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`ChatChannelsAccountLevel::list_channel_members`], set with a builder.
+pub struct ChatChannelsAccountLevelListChannelMembersRequest<'a> {
+    client: &'a ChatChannelsAccountLevel,
+    user_id: &'a str,
+    channel_id: &'a str,
+    page_size: Option<i64>,
+    next_page_token: Option<&'a str>,
+}
+
+impl<'a> ChatChannelsAccountLevelListChannelMembersRequest<'a> {
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.next_page_token = Some(next_page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ListChannelMembersResponse>>> {
+        let Self {
+            client,
+            user_id,
+            channel_id,
+            page_size,
+            next_page_token,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = next_page_token {
+            query_args.push(("next_page_token".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_size {
+            query_args.push(("page_size".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/chat/users/{}/channels/{}/members?{}",
+                crate::progenitor_support::encode_path(user_id),
+                crate::progenitor_support::encode_path(channel_id),
+                query_
+            ),
+            None,
+        );
+        let resp: crate::Response<crate::types::ListChannelMembersResponseData> = client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await?;
+
+        // Return our response data.
+        Ok(crate::Response::new(
+            resp.status,
+            resp.headers,
+            resp.body.members.to_vec(),
+        ))
+    }
+}
+
 pub struct ChatChannelsAccountLevel {
     pub client: Client,
 }
@@ -194,6 +264,32 @@ impl ChatChannelsAccountLevel {
             resp.body.members.to_vec(),
         ))
     }
+    /**
+     * List channel members.
+     *
+     * This function performs a `GET` to the `/chat/users/{userId}/channels/{channelId}/members` endpoint.
+     *
+     * As opposed to `list_channel_members`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Use this API to list all members of a channel. For user-level apps, pass [the `me` value](https://marketplace.zoom.us/docs/api-reference/using-zoom-apis#mekeyword) instead of the `userId` parameter.
+     *
+     * **Scopes:** `chat_channel:read:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     *
+     * <p style="background-color:#e1f5fe; color:#01579b; padding:8px"> <b>Note:</b> For an<b> account-level</b> <a href="https://marketplace.zoom.us/docs/guides/getting-started/app-types/create-oauth-app">OAuth app</a>, this API can only be used on behalf of a user who is assigned with a <a href="https://support.zoom.us/hc/en-us/articles/115001078646-Using-role-management#:~:text=Each%20user%20in%20a%20Zoom,owner%2C%20administrator%2C%20or%20member.&text=Role%2Dbased%20access%20control%20enables,needs%20to%20view%20or%20edit."> role</a> that has the <b>View</b> or <b>Edit</b> permission for <b>Chat Channels</b>.</p>
+     */
+    pub fn list_channel_members_request<'a>(
+        &'a self,
+        user_id: &'a str,
+        channel_id: &'a str,
+    ) -> ChatChannelsAccountLevelListChannelMembersRequest<'a> {
+        ChatChannelsAccountLevelListChannelMembersRequest {
+            client: self,
+            user_id,
+            channel_id,
+            page_size: None,
+            next_page_token: None,
+        }
+    }
     /**
      * List channel members.
      *
@@ -220,11 +316,11 @@ impl ChatChannelsAccountLevel {
             ),
             None,
         );
-        let crate::Response::<crate::types::ListChannelMembersResponseData> {
-            mut status,
-            mut headers,
-            mut body,
-        } = self
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let mut pages = crate::utils::Pages::new(self.client.page_limits);
+        pages.visit(&url)?;
+
+        let mut response: crate::Response<crate::types::ListChannelMembersResponseData> = self
             .client
             .get(
                 &url,
@@ -234,56 +330,85 @@ impl ChatChannelsAccountLevel {
                 },
             )
             .await?;
-
-        let mut members = body.members;
-        let mut page = body.next_page_token;
+        let mut members = Vec::new();
 
         // Paginate if we should.
-        while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                crate::Response::<crate::types::ListChannelMembersResponseData> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}?next_page_token={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
+        loop {
+            let page = if response.body.next_page_token.is_empty() {
+                None
             } else {
-                crate::Response::<crate::types::ListChannelMembersResponseData> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}&next_page_token={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
-
-            members.append(&mut body.members);
+                Some(format!(
+                    "{}{}next_page_token={}",
+                    url, sep, response.body.next_page_token
+                ))
+            };
+            let more = pages.keep(&mut response.body.members);
+            members.append(&mut response.body.members);
 
-            if !body.next_page_token.is_empty() && body.next_page_token != page {
-                page = body.next_page_token.to_string();
-            } else {
-                page = "".to_string();
-            }
+            let next = match page {
+                Some(next) if more && pages.visit(&next)? => next,
+                _ => break,
+            };
+            response = self
+                .client
+                .get(
+                    &next,
+                    crate::Message {
+                        body: None,
+                        content_type: None,
+                    },
+                )
+                .await?;
         }
 
         // Return our response data.
-        Ok(crate::Response::new(status, headers, members))
+        Ok(crate::Response::new(
+            response.status,
+            response.headers,
+            members,
+        ))
+    }
+    /**
+     * List channel members.
+     *
+     * This function performs a `GET` to the `/chat/users/{userId}/channels/{channelId}/members` endpoint.
+     *
+     * As opposed to `list_all_channel_members`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Use this API to list all members of a channel. For user-level apps, pass [the `me` value](https://marketplace.zoom.us/docs/api-reference/using-zoom-apis#mekeyword) instead of the `userId` parameter.
+     *
+     * **Scopes:** `chat_channel:read:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     *
+     * <p style="background-color:#e1f5fe; color:#01579b; padding:8px"> <b>Note:</b> For an<b> account-level</b> <a href="https://marketplace.zoom.us/docs/guides/getting-started/app-types/create-oauth-app">OAuth app</a>, this API can only be used on behalf of a user who is assigned with a <a href="https://support.zoom.us/hc/en-us/articles/115001078646-Using-role-management#:~:text=Each%20user%20in%20a%20Zoom,owner%2C%20administrator%2C%20or%20member.&text=Role%2Dbased%20access%20control%20enables,needs%20to%20view%20or%20edit."> role</a> that has the <b>View</b> or <b>Edit</b> permission for <b>Chat Channels</b>.</p>
+     */
+    pub fn list_all_channel_members_stream(
+        &self,
+        user_id: &str,
+        channel_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::ListChannelMembersResponse>> + Unpin + '_
+    {
+        let url = self.client.url(
+            &format!(
+                "/chat/users/{}/channels/{}/members",
+                crate::progenitor_support::encode_path(user_id),
+                crate::progenitor_support::encode_path(channel_id),
+            ),
+            None,
+        );
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let next = format!("{}{}next_page_token=", url, sep);
+        self.client.get_pages_stream(
+            url,
+            move |body: crate::types::ListChannelMembersResponseData| {
+                let page = if body.next_page_token.is_empty() {
+                    None
+                } else {
+                    Some(format!("{}{}", next, body.next_page_token))
+                };
+
+                (body.members, page)
+            },
+        )
     }
     /**
      * Invite channel members.
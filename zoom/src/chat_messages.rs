@@ -1,6 +1,243 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`ChatMessages::get_page`], set with a builder.
+pub struct ChatMessagesGetPageRequest<'a> {
+    client: &'a ChatMessages,
+    user_id: &'a str,
+    to_contact: Option<&'a str>,
+    to_channel: Option<&'a str>,
+    date: Option<chrono::NaiveDate>,
+    page_size: Option<i64>,
+    next_page_token: Option<&'a str>,
+    include_deleted_and_edited_message: Option<&'a str>,
+}
+
+impl<'a> ChatMessagesGetPageRequest<'a> {
+    /// Sets the `to_contact` query parameter.
+    pub fn to_contact(mut self, to_contact: &'a str) -> Self {
+        self.to_contact = Some(to_contact);
+        self
+    }
+
+    /// Sets the `to_channel` query parameter.
+    pub fn to_channel(mut self, to_channel: &'a str) -> Self {
+        self.to_channel = Some(to_channel);
+        self
+    }
+
+    /// Sets the `date` query parameter.
+    pub fn date(mut self, date: chrono::NaiveDate) -> Self {
+        self.date = Some(date);
+        self
+    }
+
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.next_page_token = Some(next_page_token);
+        self
+    }
+
+    /// Sets the `include_deleted_and_edited_message` query parameter.
+    pub fn include_deleted_and_edited_message(
+        mut self,
+        include_deleted_and_edited_message: &'a str,
+    ) -> Self {
+        self.include_deleted_and_edited_message = Some(include_deleted_and_edited_message);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Messages>>> {
+        let Self {
+            client,
+            user_id,
+            to_contact,
+            to_channel,
+            date,
+            page_size,
+            next_page_token,
+            include_deleted_and_edited_message,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = date {
+            query_args.push(("date".to_string(), v.to_string()));
+        }
+        if let Some(v) = include_deleted_and_edited_message {
+            query_args.push((
+                "include_deleted_and_edited_message".to_string(),
+                v.to_string(),
+            ));
+        }
+        if let Some(v) = next_page_token {
+            query_args.push(("next_page_token".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_size {
+            query_args.push(("page_size".to_string(), v.to_string()));
+        }
+        if let Some(v) = to_channel {
+            query_args.push(("to_channel".to_string(), v.to_string()));
+        }
+        if let Some(v) = to_contact {
+            query_args.push(("to_contact".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/chat/users/{}/messages?{}",
+                crate::progenitor_support::encode_path(user_id),
+                query_
+            ),
+            None,
+        );
+        let resp: crate::Response<crate::types::GetChatMessagesResponse> = client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await?;
+
+        // Return our response data.
+        Ok(crate::Response::new(
+            resp.status,
+            resp.headers,
+            resp.body.messages.to_vec(),
+        ))
+    }
+}
+
+/// The parameters of [`ChatMessages::get`], set with a builder.
+pub struct ChatMessagesGetRequest<'a> {
+    client: &'a ChatMessages,
+    user_id: &'a str,
+    message_id: &'a str,
+    to_contact: Option<&'a str>,
+    to_channel: Option<&'a str>,
+}
+
+impl<'a> ChatMessagesGetRequest<'a> {
+    /// Sets the `to_contact` query parameter.
+    pub fn to_contact(mut self, to_contact: &'a str) -> Self {
+        self.to_contact = Some(to_contact);
+        self
+    }
+
+    /// Sets the `to_channel` query parameter.
+    pub fn to_channel(mut self, to_channel: &'a str) -> Self {
+        self.to_channel = Some(to_channel);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::GetChatMessageResponse>> {
+        let Self {
+            client,
+            user_id,
+            message_id,
+            to_contact,
+            to_channel,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = to_channel {
+            query_args.push(("to_channel".to_string(), v.to_string()));
+        }
+        if let Some(v) = to_contact {
+            query_args.push(("to_contact".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/chat/users/{}/messages/{}?{}",
+                crate::progenitor_support::encode_path(user_id),
+                crate::progenitor_support::encode_path(message_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`ChatMessages::delete`], set with a builder.
+pub struct ChatMessagesDeleteRequest<'a> {
+    client: &'a ChatMessages,
+    user_id: &'a str,
+    message_id: &'a str,
+    to_contact: Option<&'a str>,
+    to_channel: Option<&'a str>,
+}
+
+impl<'a> ChatMessagesDeleteRequest<'a> {
+    /// Sets the `to_contact` query parameter.
+    pub fn to_contact(mut self, to_contact: &'a str) -> Self {
+        self.to_contact = Some(to_contact);
+        self
+    }
+
+    /// Sets the `to_channel` query parameter.
+    pub fn to_channel(mut self, to_channel: &'a str) -> Self {
+        self.to_channel = Some(to_channel);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            user_id,
+            message_id,
+            to_contact,
+            to_channel,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = to_channel {
+            query_args.push(("to_channel".to_string(), v.to_string()));
+        }
+        if let Some(v) = to_contact {
+            query_args.push(("to_contact".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/chat/users/{}/messages/{}?{}",
+                crate::progenitor_support::encode_path(user_id),
+                crate::progenitor_support::encode_path(message_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .delete(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct ChatMessages {
     pub client: Client,
 }
@@ -100,6 +337,36 @@ impl ChatMessages {
             resp.body.messages.to_vec(),
         ))
     }
+    /**
+     * List user's chat messages.
+     *
+     * This function performs a `GET` to the `/chat/users/{userId}/messages` endpoint.
+     *
+     * As opposed to `get_page`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Use this API to list the current user's chat messages between the user and an individual contact or a chat channel. For user-level apps, pass [the `me` value](https://marketplace.zoom.us/docs/api-reference/using-zoom-apis#mekeyword) instead of the `userId` parameter.
+     *
+     * In the query parameter, you must provide one of the following:
+     *
+     * * `to_contact`: The email address of the contact with whom the user conversed by sending or receiving messages.
+     * * `to_channel`: The channel ID of the channel to or from which the user has sent and/or received messages.
+     *
+     * <p style="background-color:#e1f5fe; color:#01579b; padding:8px"> <b>Note:</b> For an <b>account-level</b> <a href="https://marketplace.zoom.us/docs/guides/getting-started/app-types/create-oauth-app">OAuth app</a>, this API can only be used on behalf of a user who is assigned with a <a href="https://support.zoom.us/hc/en-us/articles/115001078646-Using-role-management#:~:text=Each%20user%20in%20a%20Zoom,owner%2C%20administrator%2C%20or%20member.&text=Role%2Dbased%20access%20control%20enables,needs%20to%20view%20or%20edit.">role</a> that has the <b>View</b> or <b>Edit</b> permission for <b>Chat Messages</b>.</p>
+     *
+     * **Scopes:** `chat_message:read`, `chat_message:read:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     */
+    pub fn get_page_request<'a>(&'a self, user_id: &'a str) -> ChatMessagesGetPageRequest<'a> {
+        ChatMessagesGetPageRequest {
+            client: self,
+            user_id,
+            to_contact: None,
+            to_channel: None,
+            date: None,
+            page_size: None,
+            next_page_token: None,
+            include_deleted_and_edited_message: None,
+        }
+    }
     /**
      * List user's chat messages.
      *
@@ -151,11 +418,11 @@ impl ChatMessages {
             ),
             None,
         );
-        let crate::Response::<crate::types::GetChatMessagesResponse> {
-            mut status,
-            mut headers,
-            mut body,
-        } = self
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let mut pages = crate::utils::Pages::new(self.client.page_limits);
+        pages.visit(&url)?;
+
+        let mut response: crate::Response<crate::types::GetChatMessagesResponse> = self
             .client
             .get(
                 &url,
@@ -165,56 +432,107 @@ impl ChatMessages {
                 },
             )
             .await?;
-
-        let mut messages = body.messages;
-        let mut page = body.next_page_token;
+        let mut messages = Vec::new();
 
         // Paginate if we should.
-        while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                crate::Response::<crate::types::GetChatMessagesResponse> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}?next_page_token={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
+        loop {
+            let page = if response.body.next_page_token.is_empty() {
+                None
             } else {
-                crate::Response::<crate::types::GetChatMessagesResponse> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}&next_page_token={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
+                Some(format!(
+                    "{}{}next_page_token={}",
+                    url, sep, response.body.next_page_token
+                ))
+            };
+            let more = pages.keep(&mut response.body.messages);
+            messages.append(&mut response.body.messages);
 
-            messages.append(&mut body.messages);
-
-            if !body.next_page_token.is_empty() && body.next_page_token != page {
-                page = body.next_page_token.to_string();
-            } else {
-                page = "".to_string();
-            }
+            let next = match page {
+                Some(next) if more && pages.visit(&next)? => next,
+                _ => break,
+            };
+            response = self
+                .client
+                .get(
+                    &next,
+                    crate::Message {
+                        body: None,
+                        content_type: None,
+                    },
+                )
+                .await?;
         }
 
         // Return our response data.
-        Ok(crate::Response::new(status, headers, messages))
+        Ok(crate::Response::new(
+            response.status,
+            response.headers,
+            messages,
+        ))
+    }
+    /**
+     * List user's chat messages.
+     *
+     * This function performs a `GET` to the `/chat/users/{userId}/messages` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Use this API to list the current user's chat messages between the user and an individual contact or a chat channel. For user-level apps, pass [the `me` value](https://marketplace.zoom.us/docs/api-reference/using-zoom-apis#mekeyword) instead of the `userId` parameter.
+     *
+     * In the query parameter, you must provide one of the following:
+     *
+     * * `to_contact`: The email address of the contact with whom the user conversed by sending or receiving messages.
+     * * `to_channel`: The channel ID of the channel to or from which the user has sent and/or received messages.
+     *
+     * <p style="background-color:#e1f5fe; color:#01579b; padding:8px"> <b>Note:</b> For an <b>account-level</b> <a href="https://marketplace.zoom.us/docs/guides/getting-started/app-types/create-oauth-app">OAuth app</a>, this API can only be used on behalf of a user who is assigned with a <a href="https://support.zoom.us/hc/en-us/articles/115001078646-Using-role-management#:~:text=Each%20user%20in%20a%20Zoom,owner%2C%20administrator%2C%20or%20member.&text=Role%2Dbased%20access%20control%20enables,needs%20to%20view%20or%20edit.">role</a> that has the <b>View</b> or <b>Edit</b> permission for <b>Chat Messages</b>.</p>
+     *
+     * **Scopes:** `chat_message:read`, `chat_message:read:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     */
+    pub fn get_all_stream(
+        &self,
+        user_id: &str,
+        to_contact: &str,
+        to_channel: &str,
+        date: chrono::NaiveDate,
+        include_deleted_and_edited_message: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Messages>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !date.to_string().is_empty() {
+            query_args.push(("date".to_string(), date.to_string()));
+        }
+        if !include_deleted_and_edited_message.is_empty() {
+            query_args.push((
+                "include_deleted_and_edited_message".to_string(),
+                include_deleted_and_edited_message.to_string(),
+            ));
+        }
+        if !to_channel.is_empty() {
+            query_args.push(("to_channel".to_string(), to_channel.to_string()));
+        }
+        if !to_contact.is_empty() {
+            query_args.push(("to_contact".to_string(), to_contact.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/chat/users/{}/messages?{}",
+                crate::progenitor_support::encode_path(user_id),
+                query_
+            ),
+            None,
+        );
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let next = format!("{}{}next_page_token=", url, sep);
+        self.client
+            .get_pages_stream(url, move |body: crate::types::GetChatMessagesResponse| {
+                let page = if body.next_page_token.is_empty() {
+                    None
+                } else {
+                    Some(format!("{}{}", next, body.next_page_token))
+                };
+
+                (body.messages, page)
+            })
     }
     /**
      * Send a chat message.
@@ -386,6 +704,36 @@ impl ChatMessages {
             )
             .await
     }
+    /**
+     * Get a message.
+     *
+     * This function performs a `GET` to the `/chat/users/{userId}/messages/{messageId}` endpoint.
+     *
+     * As opposed to `get`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get a chat message previously sent to a contact or a channel. For user-level apps, pass [the `me` value](https://marketplace.zoom.us/docs/api-reference/using-zoom-apis#mekeyword) instead of the `userId` parameter.
+     *
+     * You must provide one of the following query parameters:<br>
+     * * `to_contact` — The email address of the Zoom contact to whom you sent the message.
+     * * `to_channel` — The ID of the Zoom channel where you sent the message.
+     *
+     * **Scopes:** `chat_message:read`, `chat_message:read:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     *
+     * <p style="background-color:#e1f5fe; color:#01579b; padding:8px"> <b>Note:</b> For an <b>account-level</b> <a href="https://marketplace.zoom.us/docs/guides/getting-started/app-types/create-oauth-app">OAuth app</a>, you can only use this API for a user assigned <a href="https://support.zoom.us/hc/en-us/articles/115001078646-Using-role-management#:~:text=Each%20user%20in%20a%20Zoom,owner%2C%20administrator%2C%20or%20member.&text=Role%2Dbased%20access%20control%20enables,needs%20to%20view%20or%20edit.">the <b>Edit</b> permission for the <b>Chat message</b> role setting</a>.</p>
+     */
+    pub fn get_request<'a>(
+        &'a self,
+        user_id: &'a str,
+        message_id: &'a str,
+    ) -> ChatMessagesGetRequest<'a> {
+        ChatMessagesGetRequest {
+            client: self,
+            user_id,
+            message_id,
+            to_contact: None,
+            to_channel: None,
+        }
+    }
     /**
      * Update a message.
      *
@@ -490,4 +838,37 @@ impl ChatMessages {
             )
             .await
     }
+    /**
+     * Delete a message.
+     *
+     * This function performs a `DELETE` to the `/chat/users/{userId}/messages/{messageId}` endpoint.
+     *
+     * As opposed to `delete`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Delete a chat message that you previously sent to a contact or a channel. For user-level apps, pass [the `me` value](https://marketplace.zoom.us/docs/api-reference/using-zoom-apis#mekeyword) instead of the `userId` parameter.
+     *
+     * In the query parameter, you must provide either of the following:
+     *
+     * * `to_contact`: The email address of the contact to whom you sent the message. Use this parameter to delete a message sent to an individual contact in Zoom.
+     * * `to_channel`: The channel ID of the channel where you sent the message. Use this parameter to delete a message sent to a channel in Zoom.
+     *
+     *
+     *
+     * **Scopes:** `chat_message:write`, `chat_message:write:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     *
+     * <p style="background-color:#e1f5fe; color:#01579b; padding:8px"> <b>Note:</b> For an <b>account-level</b> <a href="https://marketplace.zoom.us/docs/guides/getting-started/app-types/create-oauth-app">OAuth app</a>, this API can only be used on behalf of a user who is assigned with a <a href="https://support.zoom.us/hc/en-us/articles/115001078646-Using-role-management#:~:text=Each%20user%20in%20a%20Zoom,owner%2C%20administrator%2C%20or%20member.&text=Role%2Dbased%20access%20control%20enables,needs%20to%20view%20or%20edit."> role</a> that has the <b>Edit</b> permission for <b>Chat Messages</b>.</p>
+     */
+    pub fn delete_request<'a>(
+        &'a self,
+        user_id: &'a str,
+        message_id: &'a str,
+    ) -> ChatMessagesDeleteRequest<'a> {
+        ChatMessagesDeleteRequest {
+            client: self,
+            user_id,
+            message_id,
+            to_contact: None,
+            to_channel: None,
+        }
+    }
 }
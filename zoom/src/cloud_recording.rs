@@ -1,6 +1,446 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`CloudRecording::recordings_list`], set with a builder.
+pub struct CloudRecordingRecordingsListRequest<'a> {
+    client: &'a CloudRecording,
+    user_id: &'a str,
+    page_size: Option<i64>,
+    next_page_token: Option<&'a str>,
+    mc: Option<&'a str>,
+    trash: Option<bool>,
+    from: Option<chrono::NaiveDate>,
+    to: Option<chrono::NaiveDate>,
+    trash_type: Option<&'a str>,
+}
+
+impl<'a> CloudRecordingRecordingsListRequest<'a> {
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.next_page_token = Some(next_page_token);
+        self
+    }
+
+    /// Sets the `mc` query parameter.
+    pub fn mc(mut self, mc: &'a str) -> Self {
+        self.mc = Some(mc);
+        self
+    }
+
+    /// Sets the `trash` query parameter.
+    pub fn trash(mut self, trash: bool) -> Self {
+        self.trash = Some(trash);
+        self
+    }
+
+    /// Sets the `from` query parameter.
+    pub fn from(mut self, from: chrono::NaiveDate) -> Self {
+        self.from = Some(from);
+        self
+    }
+
+    /// Sets the `to` query parameter.
+    pub fn to(mut self, to: chrono::NaiveDate) -> Self {
+        self.to = Some(to);
+        self
+    }
+
+    /// Sets the `trash_type` query parameter.
+    pub fn trash_type(mut self, trash_type: &'a str) -> Self {
+        self.trash_type = Some(trash_type);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::Domains>> {
+        let Self {
+            client,
+            user_id,
+            page_size,
+            next_page_token,
+            mc,
+            trash,
+            from,
+            to,
+            trash_type,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = from {
+            query_args.push(("from".to_string(), v.to_string()));
+        }
+        if let Some(v) = mc {
+            query_args.push(("mc".to_string(), v.to_string()));
+        }
+        if let Some(v) = next_page_token {
+            query_args.push(("next_page_token".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_size {
+            query_args.push(("page_size".to_string(), v.to_string()));
+        }
+        if let Some(v) = to {
+            query_args.push(("to".to_string(), v.to_string()));
+        }
+        if let Some(v) = trash {
+            query_args.push(("trash".to_string(), v.to_string()));
+        }
+        if let Some(v) = trash_type {
+            query_args.push(("trash_type".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/users/{}/recordings?{}",
+                crate::progenitor_support::encode_path(user_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`CloudRecording::recording_get`], set with a builder.
+pub struct CloudRecordingRecordingGetRequest<'a> {
+    client: &'a CloudRecording,
+    meeting_id: &'a str,
+    include_fields: Option<&'a str>,
+    ttl: Option<u64>,
+}
+
+impl<'a> CloudRecordingRecordingGetRequest<'a> {
+    /// Sets the `include_fields` query parameter.
+    pub fn include_fields(mut self, include_fields: &'a str) -> Self {
+        self.include_fields = Some(include_fields);
+        self
+    }
+
+    /// Sets the `ttl` query parameter.
+    pub fn ttl(mut self, ttl: u64) -> Self {
+        self.ttl = Some(ttl);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::RecordingGetResponseAllOf>> {
+        let Self {
+            client,
+            meeting_id,
+            include_fields,
+            ttl,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = include_fields {
+            query_args.push(("include_fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = ttl {
+            query_args.push(("ttl".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/meetings/{}/recordings?{}",
+                crate::progenitor_support::encode_path(meeting_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`CloudRecording::recording_delete`], set with a builder.
+pub struct CloudRecordingRecordingDeleteRequest<'a> {
+    client: &'a CloudRecording,
+    meeting_id: &'a str,
+    action: Option<crate::types::RecordingDeleteAction>,
+}
+
+impl<'a> CloudRecordingRecordingDeleteRequest<'a> {
+    /// Sets the `action` query parameter.
+    pub fn action(mut self, action: crate::types::RecordingDeleteAction) -> Self {
+        self.action = Some(action);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            meeting_id,
+            action,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = action {
+            query_args.push(("action".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/meetings/{}/recordings?{}",
+                crate::progenitor_support::encode_path(meeting_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .delete(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`CloudRecording::recording_delete_one`], set with a builder.
+pub struct CloudRecordingRecordingDeleteOneRequest<'a> {
+    client: &'a CloudRecording,
+    meeting_id: &'a str,
+    recording_id: &'a str,
+    action: Option<crate::types::RecordingDeleteAction>,
+}
+
+impl<'a> CloudRecordingRecordingDeleteOneRequest<'a> {
+    /// Sets the `action` query parameter.
+    pub fn action(mut self, action: crate::types::RecordingDeleteAction) -> Self {
+        self.action = Some(action);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            meeting_id,
+            recording_id,
+            action,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = action {
+            query_args.push(("action".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/meetings/{}/recordings/{}?{}",
+                crate::progenitor_support::encode_path(meeting_id),
+                crate::progenitor_support::encode_path(recording_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .delete(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`CloudRecording::meeting_recording_registrant`], set with a builder.
+pub struct CloudRecordingMeetingRecordingRegistrantRequest<'a> {
+    client: &'a CloudRecording,
+    meeting_id: i64,
+    status: Option<crate::types::MeetingRegistrantsStatus>,
+    page_size: Option<i64>,
+    page_number: Option<i64>,
+    next_page_token: Option<&'a str>,
+}
+
+impl<'a> CloudRecordingMeetingRecordingRegistrantRequest<'a> {
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: crate::types::MeetingRegistrantsStatus) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Sets the `page_number` query parameter.
+    pub fn page_number(mut self, page_number: i64) -> Self {
+        self.page_number = Some(page_number);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.next_page_token = Some(next_page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::Domains>> {
+        let Self {
+            client,
+            meeting_id,
+            status,
+            page_size,
+            page_number,
+            next_page_token,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = next_page_token {
+            query_args.push(("next_page_token".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_number {
+            query_args.push(("page_number".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_size {
+            query_args.push(("page_size".to_string(), v.to_string()));
+        }
+        if let Some(v) = status {
+            query_args.push(("status".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/meetings/{}/recordings/registrants?{}",
+                crate::progenitor_support::encode_path(&meeting_id.to_string()),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`CloudRecording::get_account`], set with a builder.
+pub struct CloudRecordingGetAccountRequest<'a> {
+    client: &'a CloudRecording,
+    account_id: &'a str,
+    page_size: Option<i64>,
+    next_page_token: Option<&'a str>,
+    from: Option<chrono::DateTime<chrono::Utc>>,
+    to: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+impl<'a> CloudRecordingGetAccountRequest<'a> {
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.next_page_token = Some(next_page_token);
+        self
+    }
+
+    /// Sets the `from` query parameter.
+    pub fn from(mut self, from: chrono::DateTime<chrono::Utc>) -> Self {
+        self.from = Some(from);
+        self
+    }
+
+    /// Sets the `to` query parameter.
+    pub fn to(mut self, to: chrono::DateTime<chrono::Utc>) -> Self {
+        self.to = Some(to);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GetAccountCloudRecordingResponseMeetings>>>
+    {
+        let Self {
+            client,
+            account_id,
+            page_size,
+            next_page_token,
+            from,
+            to,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(date) = from {
+            query_args.push(("from".to_string(), date.to_rfc3339()));
+        }
+        if let Some(v) = next_page_token {
+            query_args.push(("next_page_token".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_size {
+            query_args.push(("page_size".to_string(), v.to_string()));
+        }
+        if let Some(date) = to {
+            query_args.push(("to".to_string(), date.to_rfc3339()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/accounts/{}/recordings?{}",
+                crate::progenitor_support::encode_path(account_id),
+                query_
+            ),
+            None,
+        );
+        let resp: crate::Response<crate::types::GetAccountCloudRecordingResponse> = client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await?;
+
+        // Return our response data.
+        Ok(crate::Response::new(
+            resp.status,
+            resp.headers,
+            resp.body.meetings.to_vec(),
+        ))
+    }
+}
+
 pub struct CloudRecording {
     pub client: Client,
 }
@@ -95,6 +535,41 @@ impl CloudRecording {
             )
             .await
     }
+    /**
+     * List all recordings.
+     *
+     * This function performs a `GET` to the `/users/{userId}/recordings` endpoint.
+     *
+     * As opposed to `recordings_list`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Use this API to list all [cloud recordings](https://support.zoom.us/hc/en-us/articles/203741855-Cloud-Recording) of a user. For user-level apps, pass [the `me` value](https://marketplace.zoom.us/docs/api-reference/using-zoom-apis#mekeyword) instead of the `userId` parameter.
+     *
+     * <p style="background-color:#e1f5fe; color:#01579b; padding:8px"> <b>Note:</b> To access a user's password protected cloud recording, add an <code>access_token</code> parameter to the download URL and provide either the <a href="https://marketplace.zoom.us/docs/guides/getting-started/app-types/create-jwt-app">JWT</a> or the user's OAuth access token as the value of the <code>access_token</code> parameter.</p>
+     *
+     * When a user records a meeting or a webinar by choosing the **Record to the Cloud** option, the video, audio, and chat text are recorded in the Zoom cloud.
+     *
+     * **Scopes:** `recording:read:admin`, `recording:read`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     *
+     * **Prerequisites:**
+     * * Pro or a higher plan.
+     * * Cloud Recording must be enabled on the user's account.
+     */
+    pub fn recordings_list_request<'a>(
+        &'a self,
+        user_id: &'a str,
+    ) -> CloudRecordingRecordingsListRequest<'a> {
+        CloudRecordingRecordingsListRequest {
+            client: self,
+            user_id,
+            page_size: None,
+            next_page_token: None,
+            mc: None,
+            trash: None,
+            from: None,
+            to: None,
+            trash_type: None,
+        }
+    }
     /**
      * Get meeting recordings.
      *
@@ -148,6 +623,30 @@ impl CloudRecording {
             )
             .await
     }
+    /**
+     * Get meeting recordings.
+     *
+     * This function performs a `GET` to the `/meetings/{meetingId}/recordings` endpoint.
+     *
+     * As opposed to `recording_get`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get all the [recordings](https://support.zoom.us/hc/en-us/articles/203741855-Cloud-Recording#h_7420acb5-1897-4061-87b4-5b76e99c03b4) from a meeting or Webinar instance. The recording files can be downloaded via the `download_url` property listed in the response.
+     *
+     * > To access a password-protected cloud recording, add an `access_token` parameter to the download URL and provide OAuth access token or [JWT](https://marketplace.zoom.us/docs/guides/getting-started/app-types/create-jwt-app) as the `access_token` value.
+     *
+     * **Scopes:** `recording:read:admin`, `recording:read`</br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Light`
+     */
+    pub fn recording_get_request<'a>(
+        &'a self,
+        meeting_id: &'a str,
+    ) -> CloudRecordingRecordingGetRequest<'a> {
+        CloudRecordingRecordingGetRequest {
+            client: self,
+            meeting_id,
+            include_fields: None,
+            ttl: None,
+        }
+    }
     /**
      * Delete meeting recordings.
      *
@@ -198,6 +697,31 @@ impl CloudRecording {
             )
             .await
     }
+    /**
+     * Delete meeting recordings.
+     *
+     * This function performs a `DELETE` to the `/meetings/{meetingId}/recordings` endpoint.
+     *
+     * As opposed to `recording_delete`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Delete all recording files of a meeting.<br><br>
+     *
+     * **Scopes:** `recording:write:admin` `recording:write`<br>
+     *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Light`<br>
+     * **Prerequisites**:
+     * * Cloud Recording should be enabled on the user's account.<br>
+     *
+     */
+    pub fn recording_delete_request<'a>(
+        &'a self,
+        meeting_id: &'a str,
+    ) -> CloudRecordingRecordingDeleteRequest<'a> {
+        CloudRecordingRecordingDeleteRequest {
+            client: self,
+            meeting_id,
+            action: None,
+        }
+    }
     /**
      * Delete a meeting recording file.
      *
@@ -247,6 +771,29 @@ impl CloudRecording {
             )
             .await
     }
+    /**
+     * Delete a meeting recording file.
+     *
+     * This function performs a `DELETE` to the `/meetings/{meetingId}/recordings/{recordingId}` endpoint.
+     *
+     * As opposed to `recording_delete_one`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Delete a specific recording file from a meeting.<p style="background-color:#e1f5fe; color:#01579b; padding:8px"> <b>Note:</b> To use this API, you must enable the <b>The host can delete cloud recordings</b> setting. You can find this setting in the <b>Recording</b> tab of the <b>Settings</b> interface in the [Zoom web portal](https://zoom.us/).</p>
+     *
+     * **Scopes**: `recording:write:admin`, `recording:write`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Light`
+     */
+    pub fn recording_delete_one_request<'a>(
+        &'a self,
+        meeting_id: &'a str,
+        recording_id: &'a str,
+    ) -> CloudRecordingRecordingDeleteOneRequest<'a> {
+        CloudRecordingRecordingDeleteOneRequest {
+            client: self,
+            meeting_id,
+            recording_id,
+            action: None,
+        }
+    }
     /**
      * Recover meeting recordings.
      *
@@ -478,6 +1025,34 @@ impl CloudRecording {
             )
             .await
     }
+    /**
+     * List recording registrants.
+     *
+     * This function performs a `GET` to the `/meetings/{meetingId}/recordings/registrants` endpoint.
+     *
+     * As opposed to `meeting_recording_registrant`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Cloud Recordings of past Zoom Meetings can be made [on-demand](https://support.zoom.us/hc/en-us/articles/360000488283-On-demand-Recordings). Users should be [registered](https://marketplace.zoom.us/docs/api-reference/zoom-api/cloud-recording/meetingrecordingregistrantcreate) to view these recordings.
+     *
+     * Use this API to list registrants of **On-demand Cloud Recordings** of a past meeting.<br>
+     * **Scopes:** `recording:read:admin`, `recording:read`.<br>
+     *  
+     *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     *
+     */
+    pub fn meeting_recording_registrant_request(
+        &self,
+        meeting_id: i64,
+    ) -> CloudRecordingMeetingRecordingRegistrantRequest<'_> {
+        CloudRecordingMeetingRecordingRegistrantRequest {
+            client: self,
+            meeting_id,
+            status: None,
+            page_size: None,
+            page_number: None,
+            next_page_token: None,
+        }
+    }
     /**
      * Create a recording registrant.
      *
@@ -719,6 +1294,39 @@ impl CloudRecording {
             resp.body.meetings.to_vec(),
         ))
     }
+    /**
+     * List recordings of an account.
+     *
+     * This function performs a `GET` to the `/accounts/{accountId}/recordings` endpoint.
+     *
+     * As opposed to `get_account`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * List [Cloud Recordings](https://support.zoom.us/hc/en-us/articles/203741855-Cloud-Recording) available on an Account.
+     *
+     * > To access a password protected cloud recording, add an "access_token" parameter to the download URL and provide [JWT](https://marketplace.zoom.us/docs/guides/getting-started/app-types/create-jwt-app) as the value of the "access_token".
+     * <br>
+     * **Prerequisites**:<br>
+     * * A Pro or a higher paid plan with Cloud Recording option enabled.<br>
+     * **Scopes**: `recording:read:admin` or `account:read:admin`
+     *
+     * If the scope `recording:read:admin` is used, the Account ID of the Account must be provided in the `accountId` path parameter to list recordings that belong to the Account. This scope only works for sub accounts.
+     *
+     * To list recordings of a master account, the scope must be `account:read:admin` and the value of `accountId` should be `me`.<br>  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`<br>
+     *
+     */
+    pub fn get_account_request<'a>(
+        &'a self,
+        account_id: &'a str,
+    ) -> CloudRecordingGetAccountRequest<'a> {
+        CloudRecordingGetAccountRequest {
+            client: self,
+            account_id,
+            page_size: None,
+            next_page_token: None,
+            from: None,
+            to: None,
+        }
+    }
     /**
      * List recordings of an account.
      *
@@ -762,11 +1370,11 @@ impl CloudRecording {
             ),
             None,
         );
-        let crate::Response::<crate::types::GetAccountCloudRecordingResponse> {
-            mut status,
-            mut headers,
-            mut body,
-        } = self
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let mut pages = crate::utils::Pages::new(self.client.page_limits);
+        pages.visit(&url)?;
+
+        let mut response: crate::Response<crate::types::GetAccountCloudRecordingResponse> = self
             .client
             .get(
                 &url,
@@ -776,55 +1384,102 @@ impl CloudRecording {
                 },
             )
             .await?;
-
-        let mut meetings = body.meetings;
-        let mut page = body.next_page_token;
+        let mut meetings = Vec::new();
 
         // Paginate if we should.
-        while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                crate::Response::<crate::types::GetAccountCloudRecordingResponse> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}?next_page_token={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            } else {
-                crate::Response::<crate::types::GetAccountCloudRecordingResponse> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}&next_page_token={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
-
-            meetings.append(&mut body.meetings);
-
-            if !body.next_page_token.is_empty() && body.next_page_token != page {
-                page = body.next_page_token.to_string();
+        loop {
+            let page = if response.body.next_page_token.is_empty() {
+                None
             } else {
-                page = "".to_string();
-            }
+                Some(format!(
+                    "{}{}next_page_token={}",
+                    url, sep, response.body.next_page_token
+                ))
+            };
+            let more = pages.keep(&mut response.body.meetings);
+            meetings.append(&mut response.body.meetings);
+
+            let next = match page {
+                Some(next) if more && pages.visit(&next)? => next,
+                _ => break,
+            };
+            response = self
+                .client
+                .get(
+                    &next,
+                    crate::Message {
+                        body: None,
+                        content_type: None,
+                    },
+                )
+                .await?;
         }
 
         // Return our response data.
-        Ok(crate::Response::new(status, headers, meetings))
+        Ok(crate::Response::new(
+            response.status,
+            response.headers,
+            meetings,
+        ))
+    }
+    /**
+     * List recordings of an account.
+     *
+     * This function performs a `GET` to the `/accounts/{accountId}/recordings` endpoint.
+     *
+     * As opposed to `get_all_account`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * List [Cloud Recordings](https://support.zoom.us/hc/en-us/articles/203741855-Cloud-Recording) available on an Account.
+     *
+     * > To access a password protected cloud recording, add an "access_token" parameter to the download URL and provide [JWT](https://marketplace.zoom.us/docs/guides/getting-started/app-types/create-jwt-app) as the value of the "access_token".
+     * <br>
+     * **Prerequisites**:<br>
+     * * A Pro or a higher paid plan with Cloud Recording option enabled.<br>
+     * **Scopes**: `recording:read:admin` or `account:read:admin`
+     *
+     * If the scope `recording:read:admin` is used, the Account ID of the Account must be provided in the `accountId` path parameter to list recordings that belong to the Account. This scope only works for sub accounts.
+     *
+     * To list recordings of a master account, the scope must be `account:read:admin` and the value of `accountId` should be `me`.<br>  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`<br>
+     *
+     */
+    pub fn get_all_account_stream(
+        &self,
+        account_id: &str,
+        from: Option<chrono::DateTime<chrono::Utc>>,
+        to: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> impl futures::Stream<
+        Item = ClientResult<crate::types::GetAccountCloudRecordingResponseMeetings>,
+    > + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(date) = from {
+            query_args.push(("from".to_string(), date.to_rfc3339()));
+        }
+        if let Some(date) = to {
+            query_args.push(("to".to_string(), date.to_rfc3339()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/accounts/{}/recordings?{}",
+                crate::progenitor_support::encode_path(account_id),
+                query_
+            ),
+            None,
+        );
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let next = format!("{}{}next_page_token=", url, sep);
+        self.client.get_pages_stream(
+            url,
+            move |body: crate::types::GetAccountCloudRecordingResponse| {
+                let page = if body.next_page_token.is_empty() {
+                    None
+                } else {
+                    Some(format!("{}{}", next, body.next_page_token))
+                };
+
+                (body.meetings, page)
+            },
+        )
     }
 }
@@ -1,6 +1,64 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`CommonAreaPhones::list`], set with a builder.
+pub struct CommonAreaPhonesListRequest<'a> {
+    client: &'a CommonAreaPhones,
+    page_size: Option<i64>,
+    next_page_token: Option<&'a str>,
+}
+
+impl<'a> CommonAreaPhonesListRequest<'a> {
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.next_page_token = Some(next_page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::CommonAreaPhones>>> {
+        let Self {
+            client,
+            page_size,
+            next_page_token,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = next_page_token {
+            query_args.push(("next_page_token".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_size {
+            query_args.push(("page_size".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/phone/common_area_phones?{}", query_), None);
+        let resp: crate::Response<crate::types::ListCommonAreaPhonesResponse> = client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await?;
+
+        // Return our response data.
+        Ok(crate::Response::new(
+            resp.status,
+            resp.headers,
+            resp.body.common_area_phones.to_vec(),
+        ))
+    }
+}
+
 pub struct CommonAreaPhones {
     pub client: Client,
 }
@@ -67,6 +125,32 @@ impl CommonAreaPhones {
             resp.body.common_area_phones.to_vec(),
         ))
     }
+    /**
+     * List common area phones.
+     *
+     * This function performs a `GET` to the `/phone/common_area_phones` endpoint.
+     *
+     * As opposed to `list`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Use this API to list all of an account's [common area phones](https://support.zoom.us/hc/en-us/articles/360028516231-Managing-Common-Area-Phones).
+     *
+     * A common area phone can be provisioned by a Zoom account owner or a Zoom admin so that anyone in an organization can use it. For example, if your office has shared desks that don't belong to a specific employees, you could add a common area phone so that any person can use it.
+     *
+     * **Scopes:** `phone:read:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     *
+     * **Prerequisites:**
+     *
+     * * Pro or a higher account with Zoom Phone license.
+     * * Account owner or admin permissions.
+     * * A [supported device](https://support.zoom.us/hc/en-us/articles/360001299063-Zoom-Voice-Supported-Devices)
+     */
+    pub fn list_request(&self) -> CommonAreaPhonesListRequest<'_> {
+        CommonAreaPhonesListRequest {
+            client: self,
+            page_size: None,
+            next_page_token: None,
+        }
+    }
     /**
      * List common area phones.
      *
@@ -90,11 +174,11 @@ impl CommonAreaPhones {
         &self,
     ) -> ClientResult<crate::Response<Vec<crate::types::CommonAreaPhones>>> {
         let url = self.client.url("/phone/common_area_phones", None);
-        let crate::Response::<crate::types::ListCommonAreaPhonesResponse> {
-            mut status,
-            mut headers,
-            mut body,
-        } = self
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let mut pages = crate::utils::Pages::new(self.client.page_limits);
+        pages.visit(&url)?;
+
+        let mut response: crate::Response<crate::types::ListCommonAreaPhonesResponse> = self
             .client
             .get(
                 &url,
@@ -104,56 +188,82 @@ impl CommonAreaPhones {
                 },
             )
             .await?;
-
-        let mut common_area_phones = body.common_area_phones;
-        let mut page = body.next_page_token;
+        let mut common_area_phones = Vec::new();
 
         // Paginate if we should.
-        while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                crate::Response::<crate::types::ListCommonAreaPhonesResponse> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}?next_page_token={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
+        loop {
+            let page = if response.body.next_page_token.is_empty() {
+                None
             } else {
-                crate::Response::<crate::types::ListCommonAreaPhonesResponse> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}&next_page_token={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
+                Some(format!(
+                    "{}{}next_page_token={}",
+                    url, sep, response.body.next_page_token
+                ))
+            };
+            let more = pages.keep(&mut response.body.common_area_phones);
+            common_area_phones.append(&mut response.body.common_area_phones);
 
-            common_area_phones.append(&mut body.common_area_phones);
-
-            if !body.next_page_token.is_empty() && body.next_page_token != page {
-                page = body.next_page_token.to_string();
-            } else {
-                page = "".to_string();
-            }
+            let next = match page {
+                Some(next) if more && pages.visit(&next)? => next,
+                _ => break,
+            };
+            response = self
+                .client
+                .get(
+                    &next,
+                    crate::Message {
+                        body: None,
+                        content_type: None,
+                    },
+                )
+                .await?;
         }
 
         // Return our response data.
-        Ok(crate::Response::new(status, headers, common_area_phones))
+        Ok(crate::Response::new(
+            response.status,
+            response.headers,
+            common_area_phones,
+        ))
+    }
+    /**
+     * List common area phones.
+     *
+     * This function performs a `GET` to the `/phone/common_area_phones` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Use this API to list all of an account's [common area phones](https://support.zoom.us/hc/en-us/articles/360028516231-Managing-Common-Area-Phones).
+     *
+     * A common area phone can be provisioned by a Zoom account owner or a Zoom admin so that anyone in an organization can use it. For example, if your office has shared desks that don't belong to a specific employees, you could add a common area phone so that any person can use it.
+     *
+     * **Scopes:** `phone:read:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     *
+     * **Prerequisites:**
+     *
+     * * Pro or a higher account with Zoom Phone license.
+     * * Account owner or admin permissions.
+     * * A [supported device](https://support.zoom.us/hc/en-us/articles/360001299063-Zoom-Voice-Supported-Devices)
+     */
+    pub fn list_all_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::CommonAreaPhones>> + Unpin + '_
+    {
+        let url = self.client.url("/phone/common_area_phones", None);
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let next = format!("{}{}next_page_token=", url, sep);
+        self.client.get_pages_stream(
+            url,
+            move |body: crate::types::ListCommonAreaPhonesResponse| {
+                let page = if body.next_page_token.is_empty() {
+                    None
+                } else {
+                    Some(format!("{}{}", next, body.next_page_token))
+                };
+
+                (body.common_area_phones, page)
+            },
+        )
     }
     /**
      * Add a common area phone.
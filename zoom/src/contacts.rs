@@ -1,6 +1,202 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Contacts::search_company`], set with a builder.
+pub struct ContactsSearchCompanyRequest<'a> {
+    client: &'a Contacts,
+    search_key: Option<&'a str>,
+    query_presence_status: Option<&'a str>,
+    page_size: Option<i64>,
+    next_page_token: Option<&'a str>,
+}
+
+impl<'a> ContactsSearchCompanyRequest<'a> {
+    /// Sets the `search_key` query parameter.
+    pub fn search_key(mut self, search_key: &'a str) -> Self {
+        self.search_key = Some(search_key);
+        self
+    }
+
+    /// Sets the `query_presence_status` query parameter.
+    pub fn query_presence_status(mut self, query_presence_status: &'a str) -> Self {
+        self.query_presence_status = Some(query_presence_status);
+        self
+    }
+
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.next_page_token = Some(next_page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Contacts>>> {
+        let Self {
+            client,
+            search_key,
+            query_presence_status,
+            page_size,
+            next_page_token,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = next_page_token {
+            query_args.push(("next_page_token".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_size {
+            query_args.push(("page_size".to_string(), v.to_string()));
+        }
+        if let Some(v) = query_presence_status {
+            query_args.push(("query_presence_status".to_string(), v.to_string()));
+        }
+        if let Some(v) = search_key {
+            query_args.push(("search_key".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(&format!("/contacts?{}", query_), None);
+        let resp: crate::Response<crate::types::SearchCompanyContactsResponse> = client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await?;
+
+        // Return our response data.
+        Ok(crate::Response::new(
+            resp.status,
+            resp.headers,
+            resp.body.contacts.to_vec(),
+        ))
+    }
+}
+
+/// The parameters of [`Contacts::get_user`], set with a builder.
+pub struct ContactsGetUserRequest<'a> {
+    client: &'a Contacts,
+    type_: Option<&'a str>,
+    page_size: Option<i64>,
+    next_page_token: Option<&'a str>,
+}
+
+impl<'a> ContactsGetUserRequest<'a> {
+    /// Sets the `type` query parameter.
+    pub fn type_(mut self, type_: &'a str) -> Self {
+        self.type_ = Some(type_);
+        self
+    }
+
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.next_page_token = Some(next_page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GetUserContactsResponse>>> {
+        let Self {
+            client,
+            type_,
+            page_size,
+            next_page_token,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = next_page_token {
+            query_args.push(("next_page_token".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_size {
+            query_args.push(("page_size".to_string(), v.to_string()));
+        }
+        if let Some(v) = type_ {
+            query_args.push(("type".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/chat/users/me/contacts?{}", query_), None);
+        let resp: crate::Response<crate::types::GetUserContactsResponseData> = client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await?;
+
+        // Return our response data.
+        Ok(crate::Response::new(
+            resp.status,
+            resp.headers,
+            resp.body.contacts.to_vec(),
+        ))
+    }
+}
+
+/// The parameters of [`Contacts::get_user_contacts`], set with a builder.
+pub struct ContactsGetUserContactsRequest<'a> {
+    client: &'a Contacts,
+    contact_id: &'a str,
+    query_presence_status: Option<bool>,
+}
+
+impl<'a> ContactsGetUserContactsRequest<'a> {
+    /// Sets the `query_presence_status` query parameter.
+    pub fn query_presence_status(mut self, query_presence_status: bool) -> Self {
+        self.query_presence_status = Some(query_presence_status);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::GetUserContactResponse>> {
+        let Self {
+            client,
+            contact_id,
+            query_presence_status,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = query_presence_status {
+            query_args.push(("query_presence_status".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/chat/users/me/contacts/{}?{}",
+                crate::progenitor_support::encode_path(contact_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct Contacts {
     pub client: Client,
 }
@@ -72,6 +268,28 @@ impl Contacts {
             resp.body.contacts.to_vec(),
         ))
     }
+    /**
+     * Search company contacts.
+     *
+     * This function performs a `GET` to the `/contacts` endpoint.
+     *
+     * As opposed to `search_company`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * A user under an organization's Zoom account has internal users listed under Company Contacts in the Zoom Client. Use this API to search users that are in the company contacts of a Zoom account. Using the `search_key` query parameter, provide either first name, last name or the email address of the user that you would like to search for. Optionally, set `query_presence_status` to `true` in order to include the presence status of a contact. <br><br>
+     *
+     * **Scopes:** `contact:read:admin`, `contact:read`<br>
+     *
+     *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     */
+    pub fn search_company_request(&self) -> ContactsSearchCompanyRequest<'_> {
+        ContactsSearchCompanyRequest {
+            client: self,
+            search_key: None,
+            query_presence_status: None,
+            page_size: None,
+            next_page_token: None,
+        }
+    }
     /**
      * Search company contacts.
      *
@@ -102,11 +320,11 @@ impl Contacts {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/contacts?{}", query_), None);
-        let crate::Response::<crate::types::SearchCompanyContactsResponse> {
-            mut status,
-            mut headers,
-            mut body,
-        } = self
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let mut pages = crate::utils::Pages::new(self.client.page_limits);
+        pages.visit(&url)?;
+
+        let mut response: crate::Response<crate::types::SearchCompanyContactsResponse> = self
             .client
             .get(
                 &url,
@@ -116,56 +334,88 @@ impl Contacts {
                 },
             )
             .await?;
-
-        let mut contacts = body.contacts;
-        let mut page = body.next_page_token;
+        let mut contacts = Vec::new();
 
         // Paginate if we should.
-        while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                crate::Response::<crate::types::SearchCompanyContactsResponse> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}?next_page_token={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
+        loop {
+            let page = if response.body.next_page_token.is_empty() {
+                None
             } else {
-                crate::Response::<crate::types::SearchCompanyContactsResponse> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}&next_page_token={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
+                Some(format!(
+                    "{}{}next_page_token={}",
+                    url, sep, response.body.next_page_token
+                ))
+            };
+            let more = pages.keep(&mut response.body.contacts);
+            contacts.append(&mut response.body.contacts);
 
-            contacts.append(&mut body.contacts);
-
-            if !body.next_page_token.is_empty() && body.next_page_token != page {
-                page = body.next_page_token.to_string();
-            } else {
-                page = "".to_string();
-            }
+            let next = match page {
+                Some(next) if more && pages.visit(&next)? => next,
+                _ => break,
+            };
+            response = self
+                .client
+                .get(
+                    &next,
+                    crate::Message {
+                        body: None,
+                        content_type: None,
+                    },
+                )
+                .await?;
         }
 
         // Return our response data.
-        Ok(crate::Response::new(status, headers, contacts))
+        Ok(crate::Response::new(
+            response.status,
+            response.headers,
+            contacts,
+        ))
+    }
+    /**
+     * Search company contacts.
+     *
+     * This function performs a `GET` to the `/contacts` endpoint.
+     *
+     * As opposed to `get_all_search_company`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * A user under an organization's Zoom account has internal users listed under Company Contacts in the Zoom Client. Use this API to search users that are in the company contacts of a Zoom account. Using the `search_key` query parameter, provide either first name, last name or the email address of the user that you would like to search for. Optionally, set `query_presence_status` to `true` in order to include the presence status of a contact. <br><br>
+     *
+     * **Scopes:** `contact:read:admin`, `contact:read`<br>
+     *
+     *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     */
+    pub fn get_all_search_company_stream(
+        &self,
+        search_key: &str,
+        query_presence_status: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Contacts>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !query_presence_status.is_empty() {
+            query_args.push((
+                "query_presence_status".to_string(),
+                query_presence_status.to_string(),
+            ));
+        }
+        if !search_key.is_empty() {
+            query_args.push(("search_key".to_string(), search_key.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/contacts?{}", query_), None);
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let next = format!("{}{}next_page_token=", url, sep);
+        self.client.get_pages_stream(
+            url,
+            move |body: crate::types::SearchCompanyContactsResponse| {
+                let page = if body.next_page_token.is_empty() {
+                    None
+                } else {
+                    Some(format!("{}{}", next, body.next_page_token))
+                };
+
+                (body.contacts, page)
+            },
+        )
     }
     /**
      * List user's contacts.
@@ -226,6 +476,29 @@ impl Contacts {
             resp.body.contacts.to_vec(),
         ))
     }
+    /**
+     * List user's contacts.
+     *
+     * This function performs a `GET` to the `/chat/users/me/contacts` endpoint.
+     *
+     * As opposed to `get_user`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * A user under an organization’s Zoom account has internal users listed under Company Contacts in the Zoom Client. A Zoom user can also add another Zoom user as a [contact](https://support.zoom.us/hc/en-us/articles/115004055706-Managing-Contacts). Call this API to list all the contacts of a Zoom user. Zoom contacts are categorized into "company contacts" and "external contacts". You must specify the contact type in the `type` query parameter. If you do not specify, by default, the type will be set as company contact.
+     *
+     * <p style="background-color:#e1f5fe; color:#01579b; padding:8px"> <b>Note: </b> This API only supports <b>user-managed</b> <a href="https://marketplace.zoom.us/docs/guides/getting-started/app-types/create-oauth-app">OAuth app</a>.</p><br>
+     *
+     * **Scope**: `chat_contact:read`<br>
+     *
+     *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     */
+    pub fn get_user_request(&self) -> ContactsGetUserRequest<'_> {
+        ContactsGetUserRequest {
+            client: self,
+            type_: None,
+            page_size: None,
+            next_page_token: None,
+        }
+    }
     /**
      * List user's contacts.
      *
@@ -253,11 +526,11 @@ impl Contacts {
         let url = self
             .client
             .url(&format!("/chat/users/me/contacts?{}", query_), None);
-        let crate::Response::<crate::types::GetUserContactsResponseData> {
-            mut status,
-            mut headers,
-            mut body,
-        } = self
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let mut pages = crate::utils::Pages::new(self.client.page_limits);
+        pages.visit(&url)?;
+
+        let mut response: crate::Response<crate::types::GetUserContactsResponseData> = self
             .client
             .get(
                 &url,
@@ -267,56 +540,86 @@ impl Contacts {
                 },
             )
             .await?;
-
-        let mut contacts = body.contacts;
-        let mut page = body.next_page_token;
+        let mut contacts = Vec::new();
 
         // Paginate if we should.
-        while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                crate::Response::<crate::types::GetUserContactsResponseData> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}?next_page_token={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
+        loop {
+            let page = if response.body.next_page_token.is_empty() {
+                None
             } else {
-                crate::Response::<crate::types::GetUserContactsResponseData> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}&next_page_token={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
-
-            contacts.append(&mut body.contacts);
+                Some(format!(
+                    "{}{}next_page_token={}",
+                    url, sep, response.body.next_page_token
+                ))
+            };
+            let more = pages.keep(&mut response.body.contacts);
+            contacts.append(&mut response.body.contacts);
 
-            if !body.next_page_token.is_empty() && body.next_page_token != page {
-                page = body.next_page_token.to_string();
-            } else {
-                page = "".to_string();
-            }
+            let next = match page {
+                Some(next) if more && pages.visit(&next)? => next,
+                _ => break,
+            };
+            response = self
+                .client
+                .get(
+                    &next,
+                    crate::Message {
+                        body: None,
+                        content_type: None,
+                    },
+                )
+                .await?;
         }
 
         // Return our response data.
-        Ok(crate::Response::new(status, headers, contacts))
+        Ok(crate::Response::new(
+            response.status,
+            response.headers,
+            contacts,
+        ))
+    }
+    /**
+     * List user's contacts.
+     *
+     * This function performs a `GET` to the `/chat/users/me/contacts` endpoint.
+     *
+     * As opposed to `get_all_user`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * A user under an organization’s Zoom account has internal users listed under Company Contacts in the Zoom Client. A Zoom user can also add another Zoom user as a [contact](https://support.zoom.us/hc/en-us/articles/115004055706-Managing-Contacts). Call this API to list all the contacts of a Zoom user. Zoom contacts are categorized into "company contacts" and "external contacts". You must specify the contact type in the `type` query parameter. If you do not specify, by default, the type will be set as company contact.
+     *
+     * <p style="background-color:#e1f5fe; color:#01579b; padding:8px"> <b>Note: </b> This API only supports <b>user-managed</b> <a href="https://marketplace.zoom.us/docs/guides/getting-started/app-types/create-oauth-app">OAuth app</a>.</p><br>
+     *
+     * **Scope**: `chat_contact:read`<br>
+     *
+     *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     */
+    pub fn get_all_user_stream(
+        &self,
+        type_: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::GetUserContactsResponse>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !type_.is_empty() {
+            query_args.push(("type".to_string(), type_.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/chat/users/me/contacts?{}", query_), None);
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let next = format!("{}{}next_page_token=", url, sep);
+        self.client.get_pages_stream(
+            url,
+            move |body: crate::types::GetUserContactsResponseData| {
+                let page = if body.next_page_token.is_empty() {
+                    None
+                } else {
+                    Some(format!("{}{}", next, body.next_page_token))
+                };
+
+                (body.contacts, page)
+            },
+        )
     }
     /**
      * Get user's contact details.
@@ -367,4 +670,29 @@ impl Contacts {
             )
             .await
     }
+    /**
+     * Get user's contact details.
+     *
+     * This function performs a `GET` to the `/chat/users/me/contacts/{contactId}` endpoint.
+     *
+     * As opposed to `get_user_contacts`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * A user under an organization’s Zoom account has internal users listed under Company Contacts in the Zoom Client. A Zoom user can also add another Zoom user as a [contact](https://support.zoom.us/hc/en-us/articles/115004055706-Managing-Contacts). Call this API to get information on a specific contact of the Zoom user.
+     *
+     * <p style="background-color:#e1f5fe; color:#01579b; padding:8px"> <b>Note: </b>This API only supports <b>user-managed</b> <a href="https://marketplace.zoom.us/docs/guides/getting-started/app-types/create-oauth-app">OAuth app</a>.</p><br>
+     *
+     * **Scope**: `chat_contact:read`<br>
+     *
+     *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     */
+    pub fn get_user_contacts_request<'a>(
+        &'a self,
+        contact_id: &'a str,
+    ) -> ContactsGetUserContactsRequest<'a> {
+        ContactsGetUserContactsRequest {
+            client: self,
+            contact_id,
+            query_presence_status: None,
+        }
+    }
 }
@@ -1,6 +1,1593 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Dashboards::meeting`], set with a builder.
+pub struct DashboardsMeetingRequest<'a> {
+    client: &'a Dashboards,
+    type_: Option<crate::types::DashboardMeetingsType>,
+    from: chrono::NaiveDate,
+    to: chrono::NaiveDate,
+    page_size: Option<i64>,
+    next_page_token: Option<&'a str>,
+    include_fields: Option<crate::types::IncludeFields>,
+}
+
+impl<'a> DashboardsMeetingRequest<'a> {
+    /// Sets the `type` query parameter.
+    pub fn type_(mut self, type_: crate::types::DashboardMeetingsType) -> Self {
+        self.type_ = Some(type_);
+        self
+    }
+
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.next_page_token = Some(next_page_token);
+        self
+    }
+
+    /// Sets the `include_fields` query parameter.
+    pub fn include_fields(mut self, include_fields: crate::types::IncludeFields) -> Self {
+        self.include_fields = Some(include_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::DashboardMeetingsResponseAllOf>> {
+        let Self {
+            client,
+            type_,
+            from,
+            to,
+            page_size,
+            next_page_token,
+            include_fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !from.to_string().is_empty() {
+            query_args.push(("from".to_string(), from.to_string()));
+        }
+        if let Some(v) = include_fields {
+            query_args.push(("include_fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = next_page_token {
+            query_args.push(("next_page_token".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_size {
+            query_args.push(("page_size".to_string(), v.to_string()));
+        }
+        if !to.to_string().is_empty() {
+            query_args.push(("to".to_string(), to.to_string()));
+        }
+        if let Some(v) = type_ {
+            query_args.push(("type".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/metrics/meetings?{}", query_), None);
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Dashboards::meeting_detail`], set with a builder.
+pub struct DashboardsMeetingDetailRequest<'a> {
+    client: &'a Dashboards,
+    meeting_id: &'a str,
+    type_: Option<crate::types::DashboardMeetingsType>,
+}
+
+impl<'a> DashboardsMeetingDetailRequest<'a> {
+    /// Sets the `type` query parameter.
+    pub fn type_(mut self, type_: crate::types::DashboardMeetingsType) -> Self {
+        self.type_ = Some(type_);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::MeetingMetric>> {
+        let Self {
+            client,
+            meeting_id,
+            type_,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = type_ {
+            query_args.push(("type".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/metrics/meetings/{}?{}",
+                crate::progenitor_support::encode_path(meeting_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Dashboards::meeting_participant`], set with a builder.
+pub struct DashboardsMeetingParticipantRequest<'a> {
+    client: &'a Dashboards,
+    meeting_id: &'a str,
+    type_: Option<crate::types::DashboardMeetingsType>,
+    page_size: Option<i64>,
+    next_page_token: Option<&'a str>,
+    include_fields: Option<crate::types::DashboardMeetingParticipantsIncludeFields>,
+}
+
+impl<'a> DashboardsMeetingParticipantRequest<'a> {
+    /// Sets the `type` query parameter.
+    pub fn type_(mut self, type_: crate::types::DashboardMeetingsType) -> Self {
+        self.type_ = Some(type_);
+        self
+    }
+
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.next_page_token = Some(next_page_token);
+        self
+    }
+
+    /// Sets the `include_fields` query parameter.
+    pub fn include_fields(
+        mut self,
+        include_fields: crate::types::DashboardMeetingParticipantsIncludeFields,
+    ) -> Self {
+        self.include_fields = Some(include_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::DashboardMeetingParticipantsResponseAllOf>>
+    {
+        let Self {
+            client,
+            meeting_id,
+            type_,
+            page_size,
+            next_page_token,
+            include_fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = include_fields {
+            query_args.push(("include_fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = next_page_token {
+            query_args.push(("next_page_token".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_size {
+            query_args.push(("page_size".to_string(), v.to_string()));
+        }
+        if let Some(v) = type_ {
+            query_args.push(("type".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/metrics/meetings/{}/participants?{}",
+                crate::progenitor_support::encode_path(meeting_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Dashboards::meeting_participant_qo`], set with a builder.
+pub struct DashboardsMeetingParticipantQoRequest<'a> {
+    client: &'a Dashboards,
+    meeting_id: &'a str,
+    participant_id: &'a str,
+    type_: Option<crate::types::DashboardMeetingsType>,
+}
+
+impl<'a> DashboardsMeetingParticipantQoRequest<'a> {
+    /// Sets the `type` query parameter.
+    pub fn type_(mut self, type_: crate::types::DashboardMeetingsType) -> Self {
+        self.type_ = Some(type_);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::ParticipantQos>> {
+        let Self {
+            client,
+            meeting_id,
+            participant_id,
+            type_,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = type_ {
+            query_args.push(("type".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/metrics/meetings/{}/participants/{}/qos?{}",
+                crate::progenitor_support::encode_path(meeting_id),
+                crate::progenitor_support::encode_path(participant_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Dashboards::meeting_participants_qo`], set with a builder.
+pub struct DashboardsMeetingParticipantsQoRequest<'a> {
+    client: &'a Dashboards,
+    meeting_id: &'a str,
+    type_: Option<crate::types::DashboardMeetingsType>,
+    page_size: Option<i64>,
+    next_page_token: Option<&'a str>,
+}
+
+impl<'a> DashboardsMeetingParticipantsQoRequest<'a> {
+    /// Sets the `type` query parameter.
+    pub fn type_(mut self, type_: crate::types::DashboardMeetingsType) -> Self {
+        self.type_ = Some(type_);
+        self
+    }
+
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.next_page_token = Some(next_page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::Domains>> {
+        let Self {
+            client,
+            meeting_id,
+            type_,
+            page_size,
+            next_page_token,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = next_page_token {
+            query_args.push(("next_page_token".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_size {
+            query_args.push(("page_size".to_string(), v.to_string()));
+        }
+        if let Some(v) = type_ {
+            query_args.push(("type".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/metrics/meetings/{}/participants/qos?{}",
+                crate::progenitor_support::encode_path(meeting_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Dashboards::meeting_participant_share`], set with a builder.
+pub struct DashboardsMeetingParticipantShareRequest<'a> {
+    client: &'a Dashboards,
+    meeting_id: &'a str,
+    type_: Option<crate::types::DashboardMeetingsType>,
+    page_size: Option<i64>,
+    next_page_token: Option<&'a str>,
+}
+
+impl<'a> DashboardsMeetingParticipantShareRequest<'a> {
+    /// Sets the `type` query parameter.
+    pub fn type_(mut self, type_: crate::types::DashboardMeetingsType) -> Self {
+        self.type_ = Some(type_);
+        self
+    }
+
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.next_page_token = Some(next_page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::DashboardMeetingParticipantShareResponseAllOf>>
+    {
+        let Self {
+            client,
+            meeting_id,
+            type_,
+            page_size,
+            next_page_token,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = next_page_token {
+            query_args.push(("next_page_token".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_size {
+            query_args.push(("page_size".to_string(), v.to_string()));
+        }
+        if let Some(v) = type_ {
+            query_args.push(("type".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/metrics/meetings/{}/participants/sharing?{}",
+                crate::progenitor_support::encode_path(meeting_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Dashboards::webinar`], set with a builder.
+pub struct DashboardsWebinarRequest<'a> {
+    client: &'a Dashboards,
+    type_: Option<crate::types::DashboardWebinarsType>,
+    from: chrono::NaiveDate,
+    to: chrono::NaiveDate,
+    page_size: Option<i64>,
+    next_page_token: Option<&'a str>,
+}
+
+impl<'a> DashboardsWebinarRequest<'a> {
+    /// Sets the `type` query parameter.
+    pub fn type_(mut self, type_: crate::types::DashboardWebinarsType) -> Self {
+        self.type_ = Some(type_);
+        self
+    }
+
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.next_page_token = Some(next_page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::DashboardWebinarsResponseAllOf>> {
+        let Self {
+            client,
+            type_,
+            from,
+            to,
+            page_size,
+            next_page_token,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !from.to_string().is_empty() {
+            query_args.push(("from".to_string(), from.to_string()));
+        }
+        if let Some(v) = next_page_token {
+            query_args.push(("next_page_token".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_size {
+            query_args.push(("page_size".to_string(), v.to_string()));
+        }
+        if !to.to_string().is_empty() {
+            query_args.push(("to".to_string(), to.to_string()));
+        }
+        if let Some(v) = type_ {
+            query_args.push(("type".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/metrics/webinars?{}", query_), None);
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Dashboards::webinar_detail`], set with a builder.
+pub struct DashboardsWebinarDetailRequest<'a> {
+    client: &'a Dashboards,
+    webinar_id: &'a str,
+    type_: Option<crate::types::DashboardWebinarsType>,
+}
+
+impl<'a> DashboardsWebinarDetailRequest<'a> {
+    /// Sets the `type` query parameter.
+    pub fn type_(mut self, type_: crate::types::DashboardWebinarsType) -> Self {
+        self.type_ = Some(type_);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::Webinars>> {
+        let Self {
+            client,
+            webinar_id,
+            type_,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = type_ {
+            query_args.push(("type".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/metrics/webinars/{}?{}",
+                crate::progenitor_support::encode_path(webinar_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Dashboards::webinar_participant`], set with a builder.
+pub struct DashboardsWebinarParticipantRequest<'a> {
+    client: &'a Dashboards,
+    webinar_id: &'a str,
+    type_: Option<crate::types::DashboardWebinarsType>,
+    page_size: Option<i64>,
+    next_page_token: Option<&'a str>,
+    include_fields: Option<crate::types::DashboardMeetingParticipantsIncludeFields>,
+}
+
+impl<'a> DashboardsWebinarParticipantRequest<'a> {
+    /// Sets the `type` query parameter.
+    pub fn type_(mut self, type_: crate::types::DashboardWebinarsType) -> Self {
+        self.type_ = Some(type_);
+        self
+    }
+
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.next_page_token = Some(next_page_token);
+        self
+    }
+
+    /// Sets the `include_fields` query parameter.
+    pub fn include_fields(
+        mut self,
+        include_fields: crate::types::DashboardMeetingParticipantsIncludeFields,
+    ) -> Self {
+        self.include_fields = Some(include_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::DashboardWebinarParticipantsResponseAllOf>>
+    {
+        let Self {
+            client,
+            webinar_id,
+            type_,
+            page_size,
+            next_page_token,
+            include_fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = include_fields {
+            query_args.push(("include_fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = next_page_token {
+            query_args.push(("next_page_token".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_size {
+            query_args.push(("page_size".to_string(), v.to_string()));
+        }
+        if let Some(v) = type_ {
+            query_args.push(("type".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/metrics/webinars/{}/participants?{}",
+                crate::progenitor_support::encode_path(webinar_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Dashboards::webinar_participant_qo`], set with a builder.
+pub struct DashboardsWebinarParticipantQoRequest<'a> {
+    client: &'a Dashboards,
+    webinar_id: &'a str,
+    participant_id: &'a str,
+    type_: Option<crate::types::DashboardWebinarsType>,
+}
+
+impl<'a> DashboardsWebinarParticipantQoRequest<'a> {
+    /// Sets the `type` query parameter.
+    pub fn type_(mut self, type_: crate::types::DashboardWebinarsType) -> Self {
+        self.type_ = Some(type_);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::ParticipantQos>> {
+        let Self {
+            client,
+            webinar_id,
+            participant_id,
+            type_,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = type_ {
+            query_args.push(("type".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/metrics/webinars/{}/participants/{}/qos?{}",
+                crate::progenitor_support::encode_path(webinar_id),
+                crate::progenitor_support::encode_path(participant_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Dashboards::webinar_participants_qo`], set with a builder.
+pub struct DashboardsWebinarParticipantsQoRequest<'a> {
+    client: &'a Dashboards,
+    webinar_id: &'a str,
+    type_: Option<crate::types::DashboardWebinarsType>,
+    page_size: Option<i64>,
+    next_page_token: Option<&'a str>,
+}
+
+impl<'a> DashboardsWebinarParticipantsQoRequest<'a> {
+    /// Sets the `type` query parameter.
+    pub fn type_(mut self, type_: crate::types::DashboardWebinarsType) -> Self {
+        self.type_ = Some(type_);
+        self
+    }
+
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.next_page_token = Some(next_page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::Domains>> {
+        let Self {
+            client,
+            webinar_id,
+            type_,
+            page_size,
+            next_page_token,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = next_page_token {
+            query_args.push(("next_page_token".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_size {
+            query_args.push(("page_size".to_string(), v.to_string()));
+        }
+        if let Some(v) = type_ {
+            query_args.push(("type".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/metrics/webinars/{}/participants/qos?{}",
+                crate::progenitor_support::encode_path(webinar_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Dashboards::webinar_participant_share`], set with a builder.
+pub struct DashboardsWebinarParticipantShareRequest<'a> {
+    client: &'a Dashboards,
+    webinar_id: &'a str,
+    type_: Option<crate::types::DashboardWebinarsType>,
+    page_size: Option<i64>,
+    next_page_token: Option<&'a str>,
+}
+
+impl<'a> DashboardsWebinarParticipantShareRequest<'a> {
+    /// Sets the `type` query parameter.
+    pub fn type_(mut self, type_: crate::types::DashboardWebinarsType) -> Self {
+        self.type_ = Some(type_);
+        self
+    }
+
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.next_page_token = Some(next_page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::DashboardMeetingParticipantShareResponseAllOf>>
+    {
+        let Self {
+            client,
+            webinar_id,
+            type_,
+            page_size,
+            next_page_token,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = next_page_token {
+            query_args.push(("next_page_token".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_size {
+            query_args.push(("page_size".to_string(), v.to_string()));
+        }
+        if let Some(v) = type_ {
+            query_args.push(("type".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/metrics/webinars/{}/participants/sharing?{}",
+                crate::progenitor_support::encode_path(webinar_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Dashboards::zoom_room`], set with a builder.
+pub struct DashboardsZoomRoomRequest<'a> {
+    client: &'a Dashboards,
+    page_size: Option<i64>,
+    page_number: Option<i64>,
+    next_page_token: Option<&'a str>,
+}
+
+impl<'a> DashboardsZoomRoomRequest<'a> {
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Sets the `page_number` query parameter.
+    pub fn page_number(mut self, page_number: i64) -> Self {
+        self.page_number = Some(page_number);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.next_page_token = Some(next_page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::Domains>> {
+        let Self {
+            client,
+            page_size,
+            page_number,
+            next_page_token,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = next_page_token {
+            query_args.push(("next_page_token".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_number {
+            query_args.push(("page_number".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_size {
+            query_args.push(("page_size".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/metrics/zoomrooms?{}", query_), None);
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Dashboards::zoom_room_dashboards`], set with a builder.
+pub struct DashboardsZoomRoomDashboardsRequest<'a> {
+    client: &'a Dashboards,
+    zoomroom_id: &'a str,
+    from: Option<chrono::NaiveDate>,
+    to: Option<chrono::NaiveDate>,
+    page_size: Option<i64>,
+    next_page_token: Option<&'a str>,
+}
+
+impl<'a> DashboardsZoomRoomDashboardsRequest<'a> {
+    /// Sets the `from` query parameter.
+    pub fn from(mut self, from: chrono::NaiveDate) -> Self {
+        self.from = Some(from);
+        self
+    }
+
+    /// Sets the `to` query parameter.
+    pub fn to(mut self, to: chrono::NaiveDate) -> Self {
+        self.to = Some(to);
+        self
+    }
+
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.next_page_token = Some(next_page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::Domains>> {
+        let Self {
+            client,
+            zoomroom_id,
+            from,
+            to,
+            page_size,
+            next_page_token,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = from {
+            query_args.push(("from".to_string(), v.to_string()));
+        }
+        if let Some(v) = next_page_token {
+            query_args.push(("next_page_token".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_size {
+            query_args.push(("page_size".to_string(), v.to_string()));
+        }
+        if let Some(v) = to {
+            query_args.push(("to".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/metrics/zoomrooms/{}?{}",
+                crate::progenitor_support::encode_path(zoomroom_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Dashboards::im`], set with a builder.
+pub struct DashboardsImRequest<'a> {
+    client: &'a Dashboards,
+    from: chrono::NaiveDate,
+    to: chrono::NaiveDate,
+    page_size: Option<i64>,
+    next_page_token: Option<&'a str>,
+}
+
+impl<'a> DashboardsImRequest<'a> {
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.next_page_token = Some(next_page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::DashboardImResponseAllOf>> {
+        let Self {
+            client,
+            from,
+            to,
+            page_size,
+            next_page_token,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !from.to_string().is_empty() {
+            query_args.push(("from".to_string(), from.to_string()));
+        }
+        if let Some(v) = next_page_token {
+            query_args.push(("next_page_token".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_size {
+            query_args.push(("page_size".to_string(), v.to_string()));
+        }
+        if !to.to_string().is_empty() {
+            query_args.push(("to".to_string(), to.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(&format!("/metrics/im?{}", query_), None);
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Dashboards::chat`], set with a builder.
+pub struct DashboardsChatRequest<'a> {
+    client: &'a Dashboards,
+    from: chrono::NaiveDate,
+    to: chrono::NaiveDate,
+    page_size: Option<i64>,
+    next_page_token: Option<&'a str>,
+}
+
+impl<'a> DashboardsChatRequest<'a> {
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.next_page_token = Some(next_page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::DashboardChatResponseAllOf>> {
+        let Self {
+            client,
+            from,
+            to,
+            page_size,
+            next_page_token,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !from.to_string().is_empty() {
+            query_args.push(("from".to_string(), from.to_string()));
+        }
+        if let Some(v) = next_page_token {
+            query_args.push(("next_page_token".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_size {
+            query_args.push(("page_size".to_string(), v.to_string()));
+        }
+        if !to.to_string().is_empty() {
+            query_args.push(("to".to_string(), to.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/metrics/chat?{}", query_), None);
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Dashboards::client_feedback`], set with a builder.
+pub struct DashboardsClientFeedbackRequest<'a> {
+    client: &'a Dashboards,
+    from: Option<chrono::NaiveDate>,
+    to: Option<chrono::NaiveDate>,
+}
+
+impl<'a> DashboardsClientFeedbackRequest<'a> {
+    /// Sets the `from` query parameter.
+    pub fn from(mut self, from: chrono::NaiveDate) -> Self {
+        self.from = Some(from);
+        self
+    }
+
+    /// Sets the `to` query parameter.
+    pub fn to(mut self, to: chrono::NaiveDate) -> Self {
+        self.to = Some(to);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::DashboardClientFeedbackResponse>> {
+        let Self { client, from, to } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = from {
+            query_args.push(("from".to_string(), v.to_string()));
+        }
+        if let Some(v) = to {
+            query_args.push(("to".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/metrics/client/feedback?{}", query_), None);
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Dashboards::issue_detail_zoom_room`], set with a builder.
+pub struct DashboardsIssueDetailZoomRoomRequest<'a> {
+    client: &'a Dashboards,
+    zoomroom_id: &'a str,
+    from: chrono::NaiveDate,
+    to: chrono::NaiveDate,
+    page_size: Option<i64>,
+    next_page_token: Option<&'a str>,
+}
+
+impl<'a> DashboardsIssueDetailZoomRoomRequest<'a> {
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.next_page_token = Some(next_page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::DashboardIssueDetailZoomRoomResponseAllOf>>
+    {
+        let Self {
+            client,
+            zoomroom_id,
+            from,
+            to,
+            page_size,
+            next_page_token,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !from.to_string().is_empty() {
+            query_args.push(("from".to_string(), from.to_string()));
+        }
+        if let Some(v) = next_page_token {
+            query_args.push(("next_page_token".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_size {
+            query_args.push(("page_size".to_string(), v.to_string()));
+        }
+        if !to.to_string().is_empty() {
+            query_args.push(("to".to_string(), to.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/metrics/issues/zoomrooms/{}?{}",
+                crate::progenitor_support::encode_path(zoomroom_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Dashboards::client_feedback_detail`], set with a builder.
+pub struct DashboardsClientFeedbackDetailRequest<'a> {
+    client: &'a Dashboards,
+    feedback_id: &'a str,
+    from: Option<chrono::NaiveDate>,
+    to: Option<chrono::NaiveDate>,
+    page_size: Option<i64>,
+    next_page_token: Option<&'a str>,
+}
+
+impl<'a> DashboardsClientFeedbackDetailRequest<'a> {
+    /// Sets the `from` query parameter.
+    pub fn from(mut self, from: chrono::NaiveDate) -> Self {
+        self.from = Some(from);
+        self
+    }
+
+    /// Sets the `to` query parameter.
+    pub fn to(mut self, to: chrono::NaiveDate) -> Self {
+        self.to = Some(to);
+        self
+    }
+
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.next_page_token = Some(next_page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::DashboardClientFeedbackDetailResponseAllOf>>
+    {
+        let Self {
+            client,
+            feedback_id,
+            from,
+            to,
+            page_size,
+            next_page_token,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = from {
+            query_args.push(("from".to_string(), v.to_string()));
+        }
+        if let Some(v) = next_page_token {
+            query_args.push(("next_page_token".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_size {
+            query_args.push(("page_size".to_string(), v.to_string()));
+        }
+        if let Some(v) = to {
+            query_args.push(("to".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/metrics/client/feedback/{}?{}",
+                crate::progenitor_support::encode_path(feedback_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Dashboards::list_meeting_satisfaction`], set with a builder.
+pub struct DashboardsListMeetingSatisfactionRequest<'a> {
+    client: &'a Dashboards,
+    from: Option<chrono::NaiveDate>,
+    to: Option<chrono::NaiveDate>,
+}
+
+impl<'a> DashboardsListMeetingSatisfactionRequest<'a> {
+    /// Sets the `from` query parameter.
+    pub fn from(mut self, from: chrono::NaiveDate) -> Self {
+        self.from = Some(from);
+        self
+    }
+
+    /// Sets the `to` query parameter.
+    pub fn to(mut self, to: chrono::NaiveDate) -> Self {
+        self.to = Some(to);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::ListMeetingSatisfactionResponse>> {
+        let Self { client, from, to } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = from {
+            query_args.push(("from".to_string(), v.to_string()));
+        }
+        if let Some(v) = to {
+            query_args.push(("to".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/metrics/client/satisfaction?{}", query_), None);
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Dashboards::list_call_logs_metrics`], set with a builder.
+pub struct DashboardsListCallLogsMetricsRequest<'a> {
+    client: &'a Dashboards,
+    from: Option<&'a str>,
+    to: Option<&'a str>,
+    site_id: Option<&'a str>,
+    quality_type: Option<&'a str>,
+    page_size: Option<i64>,
+    next_page_token: Option<&'a str>,
+}
+
+impl<'a> DashboardsListCallLogsMetricsRequest<'a> {
+    /// Sets the `from` query parameter.
+    pub fn from(mut self, from: &'a str) -> Self {
+        self.from = Some(from);
+        self
+    }
+
+    /// Sets the `to` query parameter.
+    pub fn to(mut self, to: &'a str) -> Self {
+        self.to = Some(to);
+        self
+    }
+
+    /// Sets the `site_id` query parameter.
+    pub fn site_id(mut self, site_id: &'a str) -> Self {
+        self.site_id = Some(site_id);
+        self
+    }
+
+    /// Sets the `quality_type` query parameter.
+    pub fn quality_type(mut self, quality_type: &'a str) -> Self {
+        self.quality_type = Some(quality_type);
+        self
+    }
+
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.next_page_token = Some(next_page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ListCallLogsMetricsResponse>>> {
+        let Self {
+            client,
+            from,
+            to,
+            site_id,
+            quality_type,
+            page_size,
+            next_page_token,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = from {
+            query_args.push(("from".to_string(), v.to_string()));
+        }
+        if let Some(v) = next_page_token {
+            query_args.push(("next_page_token".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_size {
+            query_args.push(("page_size".to_string(), v.to_string()));
+        }
+        if let Some(v) = quality_type {
+            query_args.push(("quality_type".to_string(), v.to_string()));
+        }
+        if let Some(v) = site_id {
+            query_args.push(("site_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = to {
+            query_args.push(("to".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/phone/metrics/call_logs?{}", query_), None);
+        let resp: crate::Response<crate::types::ListCallLogsMetricsResponseData> = client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await?;
+
+        // Return our response data.
+        Ok(crate::Response::new(
+            resp.status,
+            resp.headers,
+            resp.body.call_logs.to_vec(),
+        ))
+    }
+}
+
+/// The parameters of [`Dashboards::participant_feedback`], set with a builder.
+pub struct DashboardsParticipantFeedbackRequest<'a> {
+    client: &'a Dashboards,
+    meeting_id: &'a str,
+    type_: Option<crate::types::DashboardMeetingsType>,
+    next_page_token: Option<&'a str>,
+    page_size: Option<i64>,
+}
+
+impl<'a> DashboardsParticipantFeedbackRequest<'a> {
+    /// Sets the `type` query parameter.
+    pub fn type_(mut self, type_: crate::types::DashboardMeetingsType) -> Self {
+        self.type_ = Some(type_);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.next_page_token = Some(next_page_token);
+        self
+    }
+
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ParticipantFeedbackResponseParticipants>>>
+    {
+        let Self {
+            client,
+            meeting_id,
+            type_,
+            next_page_token,
+            page_size,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = next_page_token {
+            query_args.push(("next_page_token".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_size {
+            query_args.push(("page_size".to_string(), v.to_string()));
+        }
+        if let Some(v) = type_ {
+            query_args.push(("type".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/metrics/meetings/{}/participants/satisfaction?{}",
+                crate::progenitor_support::encode_path(meeting_id),
+                query_
+            ),
+            None,
+        );
+        let resp: crate::Response<crate::types::ParticipantFeedbackResponse> = client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await?;
+
+        // Return our response data.
+        Ok(crate::Response::new(
+            resp.status,
+            resp.headers,
+            resp.body.participants.to_vec(),
+        ))
+    }
+}
+
+/// The parameters of [`Dashboards::participant_webinar_feedback`], set with a builder.
+pub struct DashboardsParticipantWebinarFeedbackRequest<'a> {
+    client: &'a Dashboards,
+    type_: Option<crate::types::DashboardMeetingsType>,
+    page_size: Option<i64>,
+    next_page_token: Option<&'a str>,
+    webinar_id: &'a str,
+}
+
+impl<'a> DashboardsParticipantWebinarFeedbackRequest<'a> {
+    /// Sets the `type` query parameter.
+    pub fn type_(mut self, type_: crate::types::DashboardMeetingsType) -> Self {
+        self.type_ = Some(type_);
+        self
+    }
+
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.next_page_token = Some(next_page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ParticipantFeedbackResponseParticipants>>>
+    {
+        let Self {
+            client,
+            type_,
+            page_size,
+            next_page_token,
+            webinar_id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = next_page_token {
+            query_args.push(("next_page_token".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_size {
+            query_args.push(("page_size".to_string(), v.to_string()));
+        }
+        if let Some(v) = type_ {
+            query_args.push(("type".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/metrics/webinars/{}/participants/satisfaction?{}",
+                crate::progenitor_support::encode_path(webinar_id),
+                query_
+            ),
+            None,
+        );
+        let resp: crate::Response<crate::types::ParticipantFeedbackResponse> = client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await?;
+
+        // Return our response data.
+        Ok(crate::Response::new(
+            resp.status,
+            resp.headers,
+            resp.body.participants.to_vec(),
+        ))
+    }
+}
+
 pub struct Dashboards {
     pub client: Client,
 }
@@ -75,6 +1662,34 @@ impl Dashboards {
             )
             .await
     }
+    /**
+     * List meetings.
+     *
+     * This function performs a `GET` to the `/metrics/meetings` endpoint.
+     *
+     * As opposed to `meeting`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * List total live or past meetings that occurred during a specified period of time. This overview will show if features such as audio, video, screen sharing, and recording were being used in the meeting. You can also see the license types of each user on your account.<br> You can specify a monthly date range for the dashboard data using the `from` and `to` query parameters. The month should fall within the last six months.<br>
+     * **Scopes:** `dashboard_meetings:read:admin`<br>
+     *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Resource-intensive`<br><br>
+     * **Prerequisites:** <br>
+     * * Business or a higher plan.<br><br>
+     */
+    pub fn meeting_request(
+        &self,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> DashboardsMeetingRequest<'_> {
+        DashboardsMeetingRequest {
+            client: self,
+            type_: None,
+            from,
+            to,
+            page_size: None,
+            next_page_token: None,
+            include_fields: None,
+        }
+    }
     /**
      * Get meeting details.
      *
@@ -123,6 +1738,29 @@ impl Dashboards {
             )
             .await
     }
+    /**
+     * Get meeting details.
+     *
+     * This function performs a `GET` to the `/metrics/meetings/{meetingId}` endpoint.
+     *
+     * As opposed to `meeting_detail`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get details on live or past meetings. This overview will show if features such as audio, video, screen sharing, and recording were being used in the meeting. You can also see the license types of each user on your account.<br> You can specify a monthly date range for the dashboard data using the `from` and `to` query parameters. The month should fall within the last six months.  <br>
+     * **Scopes:** `dashboard_meetings:read:admin`<br>
+     *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Heavy`<br>
+     * **Prerequisites:** <br>
+     * * Business or a higher plan.
+     */
+    pub fn meeting_detail_request<'a>(
+        &'a self,
+        meeting_id: &'a str,
+    ) -> DashboardsMeetingDetailRequest<'a> {
+        DashboardsMeetingDetailRequest {
+            client: self,
+            meeting_id,
+            type_: None,
+        }
+    }
     /**
      * List meeting participants.
      *
@@ -188,6 +1826,33 @@ impl Dashboards {
             )
             .await
     }
+    /**
+     * List meeting participants.
+     *
+     * This function performs a `GET` to the `/metrics/meetings/{meetingId}/participants` endpoint.
+     *
+     * As opposed to `meeting_participant`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get a list of participants from live or past meetings.<br><br>
+     * If you do not provide the `type` query parameter, the default value will be set to `live` and thus, you will only see metrics for participants in a live meeting, if any meeting is currently being conducted. To view metrics on past meeting participants, provide the appropriate value for `type`. <br> You can specify a monthly date range for the dashboard data using the `from` and `to` query parameters. The month should fall within the last six months.
+     *
+     * **Scopes:** `dashboard_meetings:read:admin`<br>
+     *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Heavy`<br>
+     * **Prerequisites:** Business or a higher plan.
+     */
+    pub fn meeting_participant_request<'a>(
+        &'a self,
+        meeting_id: &'a str,
+    ) -> DashboardsMeetingParticipantRequest<'a> {
+        DashboardsMeetingParticipantRequest {
+            client: self,
+            meeting_id,
+            type_: None,
+            page_size: None,
+            next_page_token: None,
+            include_fields: None,
+        }
+    }
     /**
      * Get meeting participant QoS.
      *
@@ -240,6 +1905,32 @@ impl Dashboards {
             )
             .await
     }
+    /**
+     * Get meeting participant QoS.
+     *
+     * This function performs a `GET` to the `/metrics/meetings/{meetingId}/participants/{participantId}/qos` endpoint.
+     *
+     * As opposed to `meeting_participant_qo`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Use this API to retrieve the quality of service (QoS) for participants from live or past meetings. The data returned indicates the connection quality for sending/receiving video, audio, and shared content. The API returns this data for either the API request or when the API request was last received.
+     *
+     * This API will **not** return data if there is no data being sent or received at the time of request.
+     * <p style="background-color:#e1f5fe; color:#01579b; padding:8px"> <b>Note:</b> When the sender sends its data, a timestamp is attached to the sender’s data packet. The receiver then returns this timestamp to the sender. This helps determine the upstream and downstream latency, which includes the application processing time. The latency data returned is the five second average and five second maximum.</p>
+     *
+     * **Scopes:** `dashboard_meetings:read:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Heavy`
+     */
+    pub fn meeting_participant_qo_request<'a>(
+        &'a self,
+        meeting_id: &'a str,
+        participant_id: &'a str,
+    ) -> DashboardsMeetingParticipantQoRequest<'a> {
+        DashboardsMeetingParticipantQoRequest {
+            client: self,
+            meeting_id,
+            participant_id,
+            type_: None,
+        }
+    }
     /**
      * List meeting participants QoS.
      *
@@ -298,6 +1989,31 @@ impl Dashboards {
             )
             .await
     }
+    /**
+     * List meeting participants QoS.
+     *
+     * This function performs a `GET` to the `/metrics/meetings/{meetingId}/participants/qos` endpoint.
+     *
+     * As opposed to `meeting_participants_qo`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get a list of meeting participants from live or past meetings along with the quality of service they recieve during the meeting such as connection quality for sending/receiving video, audio, and shared content.<br>If you do not provide the `type` query parameter, the default value will be set to `live` and thus, you will only see metrics for participants in a live meeting, if any meeting is currently being conducted. To view metrics on past meeting participants, provide the appropriate value for `type`.<br> <br> You can specify a monthly date range for the dashboard data using the `from` and `to` query parameters. The month should fall within the last six months.<br><br>
+     * **Scopes:** `dashboard_meetings:read:admin`<br>
+     *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Heavy`<br>
+     * **Prerequisites:** <br>
+     * * Business or a higher plan.
+     */
+    pub fn meeting_participants_qo_request<'a>(
+        &'a self,
+        meeting_id: &'a str,
+    ) -> DashboardsMeetingParticipantsQoRequest<'a> {
+        DashboardsMeetingParticipantsQoRequest {
+            client: self,
+            meeting_id,
+            type_: None,
+            page_size: None,
+            next_page_token: None,
+        }
+    }
     /**
      * Get sharing/recording details.
      *
@@ -357,6 +2073,31 @@ impl Dashboards {
             )
             .await
     }
+    /**
+     * Get sharing/recording details.
+     *
+     * This function performs a `GET` to the `/metrics/meetings/{meetingId}/participants/sharing` endpoint.
+     *
+     * As opposed to `meeting_participant_share`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Retrieve the sharing and recording details of participants from live or past meetings.<br>
+     * **Scopes:** `dashboard_meetings:read:admin`<br>
+     *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Heavy`<br>
+     * **Prerequisites:** <br>
+     * * Business or a higher plan.
+     */
+    pub fn meeting_participant_share_request<'a>(
+        &'a self,
+        meeting_id: &'a str,
+    ) -> DashboardsMeetingParticipantShareRequest<'a> {
+        DashboardsMeetingParticipantShareRequest {
+            client: self,
+            meeting_id,
+            type_: None,
+            page_size: None,
+            next_page_token: None,
+        }
+    }
     /**
      * List webinars.
      *
@@ -417,6 +2158,36 @@ impl Dashboards {
             )
             .await
     }
+    /**
+     * List webinars.
+     *
+     * This function performs a `GET` to the `/metrics/webinars` endpoint.
+     *
+     * As opposed to `webinar`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * List all the live or past webinars from a specified period of time. <br><br>
+     * **Scopes:** `dashboard_webinars:read:admin`<br>
+     * **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Resource-intensive`<br>
+     * **Prerequisites:**<br>
+     * * Business, Education or API Plan with Webinar add-on.
+     *
+     *
+     *
+     */
+    pub fn webinar_request(
+        &self,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> DashboardsWebinarRequest<'_> {
+        DashboardsWebinarRequest {
+            client: self,
+            type_: None,
+            from,
+            to,
+            page_size: None,
+            next_page_token: None,
+        }
+    }
     /**
      * Get webinar details.
      *
@@ -465,6 +2236,31 @@ impl Dashboards {
             )
             .await
     }
+    /**
+     * Get webinar details.
+     *
+     * This function performs a `GET` to the `/metrics/webinars/{webinarId}` endpoint.
+     *
+     * As opposed to `webinar_detail`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Retrieve details from live or past webinars.<br><br>
+     * **Scopes:** `dashboard_webinars:read:admin`<br>
+     *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Heavy`<br>
+     * **Prerequisites:**<br>
+     * * Business, Education or API Plan with Webinar add-on.
+     *
+     *
+     */
+    pub fn webinar_detail_request<'a>(
+        &'a self,
+        webinar_id: &'a str,
+    ) -> DashboardsWebinarDetailRequest<'a> {
+        DashboardsWebinarDetailRequest {
+            client: self,
+            webinar_id,
+            type_: None,
+        }
+    }
     /**
      * Get webinar participants.
      *
@@ -529,6 +2325,34 @@ impl Dashboards {
             )
             .await
     }
+    /**
+     * Get webinar participants.
+     *
+     * This function performs a `GET` to the `/metrics/webinars/{webinarId}/participants` endpoint.
+     *
+     * As opposed to `webinar_participant`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Retrieve details on participants from live or past webinars.<br><br>
+     * **Scopes:** `dashboard_webinars:read:admin`<br>
+     *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Heavy`<br>
+     * **Prerequisites:**<br>
+     * * Business, Education or API Plan with Webinar add-on.
+     *
+     *
+     */
+    pub fn webinar_participant_request<'a>(
+        &'a self,
+        webinar_id: &'a str,
+    ) -> DashboardsWebinarParticipantRequest<'a> {
+        DashboardsWebinarParticipantRequest {
+            client: self,
+            webinar_id,
+            type_: None,
+            page_size: None,
+            next_page_token: None,
+            include_fields: None,
+        }
+    }
     /**
      * Get webinar participant QoS.
      *
@@ -579,6 +2403,32 @@ impl Dashboards {
             )
             .await
     }
+    /**
+     * Get webinar participant QoS.
+     *
+     * This function performs a `GET` to the `/metrics/webinars/{webinarId}/participants/{participantId}/qos` endpoint.
+     *
+     * As opposed to `webinar_participant_qo`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Retrieve details on the quality of service that participants from live or past webinars recieved.<br>This data indicates the connection quality for sending/receiving video, audio, and shared content. If nothing is being sent or received at that time, no information will be shown in the fields.<br>
+     * **Scopes:** `dashboard_webinars:read:admin`<br>
+     *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Heavy` <br>
+     * **Prerequisites:** <br>
+     * * Business, Education or API Plan with Zoom Rooms set up.
+     *
+     */
+    pub fn webinar_participant_qo_request<'a>(
+        &'a self,
+        webinar_id: &'a str,
+        participant_id: &'a str,
+    ) -> DashboardsWebinarParticipantQoRequest<'a> {
+        DashboardsWebinarParticipantQoRequest {
+            client: self,
+            webinar_id,
+            participant_id,
+            type_: None,
+        }
+    }
     /**
      * List webinar participant QoS.
      *
@@ -637,6 +2487,33 @@ impl Dashboards {
             )
             .await
     }
+    /**
+     * List webinar participant QoS.
+     *
+     * This function performs a `GET` to the `/metrics/webinars/{webinarId}/participants/qos` endpoint.
+     *
+     * As opposed to `webinar_participants_qo`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Retrieve a list of participants from live or past webinars and the quality of service they received.<br>This data indicates the connection quality for sending/receiving video, audio, and shared content. If nothing is being sent or received at that time, no information will be shown in the fields.<br>
+     * **Scopes:** `dashboard_webinars:read:admin`<br>
+     *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Heavy`<br>
+     * **Prerequisites:**
+     * * Business, Education or API Plan with Webinar add-on.
+     *
+     *
+     */
+    pub fn webinar_participants_qo_request<'a>(
+        &'a self,
+        webinar_id: &'a str,
+    ) -> DashboardsWebinarParticipantsQoRequest<'a> {
+        DashboardsWebinarParticipantsQoRequest {
+            client: self,
+            webinar_id,
+            type_: None,
+            page_size: None,
+            next_page_token: None,
+        }
+    }
     /**
      * Get sharing/recording details.
      *
@@ -696,6 +2573,33 @@ impl Dashboards {
             )
             .await
     }
+    /**
+     * Get sharing/recording details.
+     *
+     * This function performs a `GET` to the `/metrics/webinars/{webinarId}/participants/sharing` endpoint.
+     *
+     * As opposed to `webinar_participant_share`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Retrieve the sharing and recording details of participants from live or past webinars. <br><br>
+     * **Scopes:** `dashboard_webinars:read:admin`<br>
+     *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Heavy` <br>
+     * **Prerequisites:**<br>
+     * * Business, Education or API Plan with Webinar add-on.
+     *
+     *
+     */
+    pub fn webinar_participant_share_request<'a>(
+        &'a self,
+        webinar_id: &'a str,
+    ) -> DashboardsWebinarParticipantShareRequest<'a> {
+        DashboardsWebinarParticipantShareRequest {
+            client: self,
+            webinar_id,
+            type_: None,
+            page_size: None,
+            next_page_token: None,
+        }
+    }
     /**
      * List Zoom Rooms.
      *
@@ -747,6 +2651,31 @@ impl Dashboards {
             )
             .await
     }
+    /**
+     * List Zoom Rooms.
+     *
+     * This function performs a `GET` to the `/metrics/zoomrooms` endpoint.
+     *
+     * As opposed to `zoom_room`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * List information on all Zoom Rooms in an account.<br><br>
+     * **Scopes:** `dashboard_zr:read:admin`
+     *
+     *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Resource-intensive`<br>
+     *  **Prerequisites:**<br>
+     * * Business, Education or API Plan with Zoom Rooms set up.
+     *
+     *
+     *
+     */
+    pub fn zoom_room_request(&self) -> DashboardsZoomRoomRequest<'_> {
+        DashboardsZoomRoomRequest {
+            client: self,
+            page_size: None,
+            page_number: None,
+            next_page_token: None,
+        }
+    }
     /**
      * Get Zoom Rooms details.
      *
@@ -809,6 +2738,35 @@ impl Dashboards {
             )
             .await
     }
+    /**
+     * Get Zoom Rooms details.
+     *
+     * This function performs a `GET` to the `/metrics/zoomrooms/{zoomroomId}` endpoint.
+     *
+     * As opposed to `zoom_room_dashboards`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * The Zoom Rooms dashboard metrics lets you know the type of configuration a Zoom room has and details on the meetings held in that room.
+     *
+     * Use this API to retrieve information on a specific room.<br><br>
+     * **Scopes:** `dashboard_zr:read:admin`<br> <br>
+     *
+     *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Heavy`**Prerequisites:**<br>
+     * * Business, Education or API Plan with Zoom Rooms set up.
+     *
+     */
+    pub fn zoom_room_dashboards_request<'a>(
+        &'a self,
+        zoomroom_id: &'a str,
+    ) -> DashboardsZoomRoomDashboardsRequest<'a> {
+        DashboardsZoomRoomDashboardsRequest {
+            client: self,
+            zoomroom_id,
+            from: None,
+            to: None,
+            page_size: None,
+            next_page_token: None,
+        }
+    }
     /**
      * Get CRC port usage.
      *
@@ -906,6 +2864,36 @@ impl Dashboards {
             )
             .await
     }
+    /**
+     * Get IM metrics.
+     *
+     * This function performs a `GET` to the `/metrics/im` endpoint.
+     *
+     * As opposed to `im`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get [metrics](https://support.zoom.us/hc/en-us/articles/204654719-Dashboard#h_cc7e9749-1c70-4afb-a9a2-9680654821e4) on how users are utilizing the Zoom Chat client.
+     *
+     * You can specify a monthly date range for the dashboard data using the `from` and `to` query parameters. The month should fall within the last six months.<p style="background-color:#e1f5fe; color:#000000; padding:8px"><b>Deprecated:</b> We will completely deprecate this endpoint in a future release. You can continue using this endpoint to query data for messages sent <b>before</b> July 1, 2021.</br></br>To get metrics on chat messages sent <b>on and after</b> July 1, 2021, use the <a href="https://marketplace.zoom.us/docs/api-reference/zoom-api/dashboards/dashboardchat">Get chat metrics API</a>.</p>
+     *
+     * **Scopes:** `dashboard_im:read:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Resource-intensive`
+     *
+     * **Prerequisites:**
+     *
+     * * Business or a higher plan.
+     */
+    pub fn im_request(
+        &self,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> DashboardsImRequest<'_> {
+        DashboardsImRequest {
+            client: self,
+            from,
+            to,
+            page_size: None,
+            next_page_token: None,
+        }
+    }
     /**
      * Get chat metrics.
      *
@@ -962,6 +2950,38 @@ impl Dashboards {
             )
             .await
     }
+    /**
+     * Get chat metrics.
+     *
+     * This function performs a `GET` to the `/metrics/chat` endpoint.
+     *
+     * As opposed to `chat`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get [metrics](https://support.zoom.us/hc/en-us/articles/204654719-Dashboard#h_cc7e9749-1c70-4afb-a9a2-9680654821e4) for how users are utilizing Zoom Chat to send messages.
+     *
+     * Use the `from` and `to` query parameters to specify a monthly date range for the dashboard data. The monthly date range must be within the last six months.
+     *
+     * > **Note:** To query chat metrics from July 1, 2021 and later, use this endpoint instead of the [Get IM metrics API](https://marketplace.zoom.us/docs/api-reference/zoom-api/dashboards/dashboardim).
+     *
+     * **Scope:** `dashboard_im:read:admin`</br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Resource-intensive`
+     *
+     * **Prerequisites:**
+     *
+     * * Business or a higher plan
+     */
+    pub fn chat_request(
+        &self,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> DashboardsChatRequest<'_> {
+        DashboardsChatRequest {
+            client: self,
+            from,
+            to,
+            page_size: None,
+            next_page_token: None,
+        }
+    }
     /**
      * List Zoom meetings client feedback.
      *
@@ -1008,6 +3028,30 @@ impl Dashboards {
             )
             .await
     }
+    /**
+     * List Zoom meetings client feedback.
+     *
+     * This function performs a `GET` to the `/metrics/client/feedback` endpoint.
+     *
+     * As opposed to `client_feedback`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Retrieve survey results from [Zoom meetings client feedback](https://support.zoom.us/hc/en-us/articles/115005855266-End-of-Meeting-Feedback-Survey#h_e30d552b-6d8e-4e0a-a588-9ca8180c4dbf). <br> You can specify a monthly date range for the dashboard data using the `from` and `to` query parameters. The month should fall within the last six months.
+     *
+     * **Prerequisites:**
+     * * Business or higher account
+     * * [Feedback to Zoom](https://support.zoom.us/hc/en-us/articles/115005838023) enabled.
+     *
+     * **Scope:** `account:read:admin`<br>
+     *
+     *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Heavy`
+     */
+    pub fn client_feedback_request(&self) -> DashboardsClientFeedbackRequest<'_> {
+        DashboardsClientFeedbackRequest {
+            client: self,
+            from: None,
+            to: None,
+        }
+    }
     /**
      * Get top 25 issues of Zoom Rooms.
      *
@@ -1158,6 +3202,36 @@ impl Dashboards {
             )
             .await
     }
+    /**
+     * Get issues of Zoom Rooms.
+     *
+     * This function performs a `GET` to the `/metrics/issues/zoomrooms/{zoomroomId}` endpoint.
+     *
+     * As opposed to `issue_detail_zoom_room`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get information about the issues that occured on the Top 25 **Zoom Rooms with issues** in an acount. <br> You can specify a monthly date range for the dashboard data using the `from` and `to` query parameters. The month should fall within the last six months.
+     *
+     * **Scope:** `dashboard_home:read:admin`<br>
+     *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Heavy`<br>
+     * **Prerequisites:** <br>
+     * * Business or a higher plan.
+     * * Zoom Room must be enabled in the account.
+     */
+    pub fn issue_detail_zoom_room_request<'a>(
+        &'a self,
+        zoomroom_id: &'a str,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> DashboardsIssueDetailZoomRoomRequest<'a> {
+        DashboardsIssueDetailZoomRoomRequest {
+            client: self,
+            zoomroom_id,
+            from,
+            to,
+            page_size: None,
+            next_page_token: None,
+        }
+    }
     /**
      * Get zoom meetings client feedback.
      *
@@ -1223,6 +3297,37 @@ impl Dashboards {
             )
             .await
     }
+    /**
+     * Get zoom meetings client feedback.
+     *
+     * This function performs a `GET` to the `/metrics/client/feedback/{feedbackId}` endpoint.
+     *
+     * As opposed to `client_feedback_detail`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Retrieve detailed information on a [Zoom meetings client feedback](https://support.zoom.us/hc/en-us/articles/115005855266-End-of-Meeting-Feedback-Survey#h_e30d552b-6d8e-4e0a-a588-9ca8180c4dbf). <br> You can specify a monthly date range for the dashboard data using the `from` and `to` query parameters. The month should fall within the last six months.
+     *
+     * **Prerequisites:**
+     * * Business or higher account
+     * * [Feedback to Zoom](https://support.zoom.us/hc/en-us/articles/115005838023) enabled.
+     *
+     * **Scope:** `dashboard_home:read:admin`<br>
+     *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Heavy`
+     *
+     * `
+     */
+    pub fn client_feedback_detail_request<'a>(
+        &'a self,
+        feedback_id: &'a str,
+    ) -> DashboardsClientFeedbackDetailRequest<'a> {
+        DashboardsClientFeedbackDetailRequest {
+            client: self,
+            feedback_id,
+            from: None,
+            to: None,
+            page_size: None,
+            next_page_token: None,
+        }
+    }
     /**
      * List client meeting satisfaction.
      *
@@ -1265,6 +3370,28 @@ impl Dashboards {
             )
             .await
     }
+    /**
+     * List client meeting satisfaction.
+     *
+     * This function performs a `GET` to the `/metrics/client/satisfaction` endpoint.
+     *
+     * As opposed to `list_meeting_satisfaction`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * If the [End of Meeting Feedback Survey](https://support.zoom.us/hc/en-us/articles/115005855266) option is enabled, attendees will be prompted with a survey window where they can tap either the **Thumbs Up** or **Thumbs Down** button that indicates their Zoom meeting experience. With this API, you can get information on the attendees' meeting satisfaction. Specify a monthly date range for the query using the from and to query parameters. The month should fall within the last six months.
+     *
+     * To get information on the survey results with negative experiences (indicated by **Thumbs Down**), use the [Get Zoom Meetings Client Feedback API](https://marketplace.zoom.us/docs/api-reference/zoom-api/dashboards/dashboardclientfeedbackdetail).<br>
+     * **Scopes:** `dashboard:read:admin`<br>
+     *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Heavy`
+     */
+    pub fn list_meeting_satisfaction_request(
+        &self,
+    ) -> DashboardsListMeetingSatisfactionRequest<'_> {
+        DashboardsListMeetingSatisfactionRequest {
+            client: self,
+            from: None,
+            to: None,
+        }
+    }
     /**
      * List call logs.
      *
@@ -1347,6 +3474,35 @@ impl Dashboards {
             resp.body.call_logs.to_vec(),
         ))
     }
+    /**
+     * List call logs.
+     *
+     * This function performs a `GET` to the `/phone/metrics/call_logs` endpoint.
+     *
+     * As opposed to `list_call_logs_metrics`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Call logs provide a record of all incoming and outgoing calls over Zoom Phone in an account.
+     *
+     * Use this API to list monthly call logs metrics. You can use query parameters to filter the response by date, site and MOS(Mean Opinion Score) of the call.
+     *
+     * **Prerequisites:**
+     * * Business, or Education account
+     * * Zoom Phone license <br><br>
+     *
+     * **Scopes:** `phone:read:admin`, `phone:write:admin`<br>
+     * **Rate Limit Label:** `Heavy`
+     */
+    pub fn list_call_logs_metrics_request(&self) -> DashboardsListCallLogsMetricsRequest<'_> {
+        DashboardsListCallLogsMetricsRequest {
+            client: self,
+            from: None,
+            to: None,
+            site_id: None,
+            quality_type: None,
+            page_size: None,
+            next_page_token: None,
+        }
+    }
     /**
      * List call logs.
      *
@@ -1389,11 +3545,11 @@ impl Dashboards {
         let url = self
             .client
             .url(&format!("/phone/metrics/call_logs?{}", query_), None);
-        let crate::Response::<crate::types::ListCallLogsMetricsResponseData> {
-            mut status,
-            mut headers,
-            mut body,
-        } = self
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let mut pages = crate::utils::Pages::new(self.client.page_limits);
+        pages.visit(&url)?;
+
+        let mut response: crate::Response<crate::types::ListCallLogsMetricsResponseData> = self
             .client
             .get(
                 &url,
@@ -1403,56 +3559,101 @@ impl Dashboards {
                 },
             )
             .await?;
-
-        let mut call_logs = body.call_logs;
-        let mut page = body.next_page_token;
+        let mut call_logs = Vec::new();
 
         // Paginate if we should.
-        while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                crate::Response::<crate::types::ListCallLogsMetricsResponseData> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}?next_page_token={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
+        loop {
+            let page = if response.body.next_page_token.is_empty() {
+                None
             } else {
-                crate::Response::<crate::types::ListCallLogsMetricsResponseData> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}&next_page_token={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
-
-            call_logs.append(&mut body.call_logs);
-
-            if !body.next_page_token.is_empty() && body.next_page_token != page {
-                page = body.next_page_token.to_string();
-            } else {
-                page = "".to_string();
-            }
+                Some(format!(
+                    "{}{}next_page_token={}",
+                    url, sep, response.body.next_page_token
+                ))
+            };
+            let more = pages.keep(&mut response.body.call_logs);
+            call_logs.append(&mut response.body.call_logs);
+
+            let next = match page {
+                Some(next) if more && pages.visit(&next)? => next,
+                _ => break,
+            };
+            response = self
+                .client
+                .get(
+                    &next,
+                    crate::Message {
+                        body: None,
+                        content_type: None,
+                    },
+                )
+                .await?;
         }
 
         // Return our response data.
-        Ok(crate::Response::new(status, headers, call_logs))
+        Ok(crate::Response::new(
+            response.status,
+            response.headers,
+            call_logs,
+        ))
+    }
+    /**
+     * List call logs.
+     *
+     * This function performs a `GET` to the `/phone/metrics/call_logs` endpoint.
+     *
+     * As opposed to `list_all_call_logs_metrics`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Call logs provide a record of all incoming and outgoing calls over Zoom Phone in an account.
+     *
+     * Use this API to list monthly call logs metrics. You can use query parameters to filter the response by date, site and MOS(Mean Opinion Score) of the call.
+     *
+     * **Prerequisites:**
+     * * Business, or Education account
+     * * Zoom Phone license <br><br>
+     *
+     * **Scopes:** `phone:read:admin`, `phone:write:admin`<br>
+     * **Rate Limit Label:** `Heavy`
+     */
+    pub fn list_all_call_logs_metrics_stream(
+        &self,
+        from: &str,
+        to: &str,
+        site_id: &str,
+        quality_type: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::ListCallLogsMetricsResponse>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !from.is_empty() {
+            query_args.push(("from".to_string(), from.to_string()));
+        }
+        if !quality_type.is_empty() {
+            query_args.push(("quality_type".to_string(), quality_type.to_string()));
+        }
+        if !site_id.is_empty() {
+            query_args.push(("site_id".to_string(), site_id.to_string()));
+        }
+        if !to.is_empty() {
+            query_args.push(("to".to_string(), to.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/phone/metrics/call_logs?{}", query_), None);
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let next = format!("{}{}next_page_token=", url, sep);
+        self.client.get_pages_stream(
+            url,
+            move |body: crate::types::ListCallLogsMetricsResponseData| {
+                let page = if body.next_page_token.is_empty() {
+                    None
+                } else {
+                    Some(format!("{}{}", next, body.next_page_token))
+                };
+
+                (body.call_logs, page)
+            },
+        )
     }
     /**
      * Get call details from call log.
@@ -1605,6 +3806,35 @@ impl Dashboards {
             resp.body.participants.to_vec(),
         ))
     }
+    /**
+     * Get post meeting feedback.
+     *
+     * This function performs a `GET` to the `/metrics/meetings/{meetingId}/participants/satisfaction` endpoint.
+     *
+     * As opposed to `participant_feedback`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * When a meeting ends, each attendee will be prompted to share their meeting experience by clicking either thumbs up or thumbs down. Use this API to retrieve the feedback submitted for a specific meeting. Note that this API only works for meetings scheduled after December 20, 2020.
+     *
+     * **Prerequisites:**
+     * * [Feedback to Zoom](https://support.zoom.us/hc/en-us/articles/115005838023) setting must be enabled by the participant prior to the meeting.
+     * * The user making the API request must be enrolled in a Business or a higher plan.
+     *
+     * <br> **Scope:** `dashboard_meetings:read:admiin`
+     *
+     * **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Heavy`<br>
+     */
+    pub fn participant_feedback_request<'a>(
+        &'a self,
+        meeting_id: &'a str,
+    ) -> DashboardsParticipantFeedbackRequest<'a> {
+        DashboardsParticipantFeedbackRequest {
+            client: self,
+            meeting_id,
+            type_: None,
+            next_page_token: None,
+            page_size: None,
+        }
+    }
     /**
      * Get post meeting feedback.
      *
@@ -1641,11 +3871,11 @@ impl Dashboards {
             ),
             None,
         );
-        let crate::Response::<crate::types::ParticipantFeedbackResponse> {
-            mut status,
-            mut headers,
-            mut body,
-        } = self
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let mut pages = crate::utils::Pages::new(self.client.page_limits);
+        pages.visit(&url)?;
+
+        let mut response: crate::Response<crate::types::ParticipantFeedbackResponse> = self
             .client
             .get(
                 &url,
@@ -1655,56 +3885,96 @@ impl Dashboards {
                 },
             )
             .await?;
-
-        let mut participants = body.participants;
-        let mut page = body.next_page_token;
+        let mut participants = Vec::new();
 
         // Paginate if we should.
-        while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                crate::Response::<crate::types::ParticipantFeedbackResponse> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}?next_page_token={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
+        loop {
+            let page = if response.body.next_page_token.is_empty() {
+                None
             } else {
-                crate::Response::<crate::types::ParticipantFeedbackResponse> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}&next_page_token={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
-
-            participants.append(&mut body.participants);
-
-            if !body.next_page_token.is_empty() && body.next_page_token != page {
-                page = body.next_page_token.to_string();
-            } else {
-                page = "".to_string();
-            }
+                Some(format!(
+                    "{}{}next_page_token={}",
+                    url, sep, response.body.next_page_token
+                ))
+            };
+            let more = pages.keep(&mut response.body.participants);
+            participants.append(&mut response.body.participants);
+
+            let next = match page {
+                Some(next) if more && pages.visit(&next)? => next,
+                _ => break,
+            };
+            response = self
+                .client
+                .get(
+                    &next,
+                    crate::Message {
+                        body: None,
+                        content_type: None,
+                    },
+                )
+                .await?;
         }
 
         // Return our response data.
-        Ok(crate::Response::new(status, headers, participants))
+        Ok(crate::Response::new(
+            response.status,
+            response.headers,
+            participants,
+        ))
+    }
+    /**
+     * Get post meeting feedback.
+     *
+     * This function performs a `GET` to the `/metrics/meetings/{meetingId}/participants/satisfaction` endpoint.
+     *
+     * As opposed to `get_all_participant_feedback`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * When a meeting ends, each attendee will be prompted to share their meeting experience by clicking either thumbs up or thumbs down. Use this API to retrieve the feedback submitted for a specific meeting. Note that this API only works for meetings scheduled after December 20, 2020.
+     *
+     * **Prerequisites:**
+     * * [Feedback to Zoom](https://support.zoom.us/hc/en-us/articles/115005838023) setting must be enabled by the participant prior to the meeting.
+     * * The user making the API request must be enrolled in a Business or a higher plan.
+     *
+     * <br> **Scope:** `dashboard_meetings:read:admiin`
+     *
+     * **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Heavy`<br>
+     */
+    pub fn get_all_participant_feedback_stream(
+        &self,
+        meeting_id: &str,
+        type_: crate::types::DashboardMeetingsType,
+    ) -> impl futures::Stream<
+        Item = ClientResult<crate::types::ParticipantFeedbackResponseParticipants>,
+    > + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !type_.to_string().is_empty() {
+            query_args.push(("type".to_string(), type_.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/metrics/meetings/{}/participants/satisfaction?{}",
+                crate::progenitor_support::encode_path(meeting_id),
+                query_
+            ),
+            None,
+        );
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let next = format!("{}{}next_page_token=", url, sep);
+        self.client.get_pages_stream(
+            url,
+            move |body: crate::types::ParticipantFeedbackResponse| {
+                let page = if body.next_page_token.is_empty() {
+                    None
+                } else {
+                    Some(format!("{}{}", next, body.next_page_token))
+                };
+
+                (body.participants, page)
+            },
+        )
     }
     /**
      * Get post webinar feedback.
@@ -1778,6 +4048,36 @@ impl Dashboards {
             resp.body.participants.to_vec(),
         ))
     }
+    /**
+     * Get post webinar feedback.
+     *
+     * This function performs a `GET` to the `/metrics/webinars/{webinarId}/participants/satisfaction` endpoint.
+     *
+     * As opposed to `participant_webinar_feedback`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * When a Webinar ends, each attendee will be prompted to share their Webinar experience by clicking either thumbs up or thumbs down. Use this API to retrieve the feedback submitted for a specific webinar. Note that this API only works for meetings scheduled after December 20, 2020.
+     *
+     * **Prerequisites:**
+     * * [Feedback to Zoom](https://support.zoom.us/hc/en-us/articles/115005838023) setting must be enabled by the participant prior to the meeting.
+     * * The user making the API request must be enrolled in a Business or a higher plan.
+     *
+     *
+     * <br> **Scope:** `dashboard_webinars:read:admin`
+     *
+     * **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Heavy`<br>
+     */
+    pub fn participant_webinar_feedback_request<'a>(
+        &'a self,
+        webinar_id: &'a str,
+    ) -> DashboardsParticipantWebinarFeedbackRequest<'a> {
+        DashboardsParticipantWebinarFeedbackRequest {
+            client: self,
+            type_: None,
+            page_size: None,
+            next_page_token: None,
+            webinar_id,
+        }
+    }
     /**
      * Get post webinar feedback.
      *
@@ -1815,11 +4115,11 @@ impl Dashboards {
             ),
             None,
         );
-        let crate::Response::<crate::types::ParticipantFeedbackResponse> {
-            mut status,
-            mut headers,
-            mut body,
-        } = self
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let mut pages = crate::utils::Pages::new(self.client.page_limits);
+        pages.visit(&url)?;
+
+        let mut response: crate::Response<crate::types::ParticipantFeedbackResponse> = self
             .client
             .get(
                 &url,
@@ -1829,55 +4129,96 @@ impl Dashboards {
                 },
             )
             .await?;
-
-        let mut participants = body.participants;
-        let mut page = body.next_page_token;
+        let mut participants = Vec::new();
 
         // Paginate if we should.
-        while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                crate::Response::<crate::types::ParticipantFeedbackResponse> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}?next_page_token={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            } else {
-                crate::Response::<crate::types::ParticipantFeedbackResponse> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}&next_page_token={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
-
-            participants.append(&mut body.participants);
-
-            if !body.next_page_token.is_empty() && body.next_page_token != page {
-                page = body.next_page_token.to_string();
+        loop {
+            let page = if response.body.next_page_token.is_empty() {
+                None
             } else {
-                page = "".to_string();
-            }
+                Some(format!(
+                    "{}{}next_page_token={}",
+                    url, sep, response.body.next_page_token
+                ))
+            };
+            let more = pages.keep(&mut response.body.participants);
+            participants.append(&mut response.body.participants);
+
+            let next = match page {
+                Some(next) if more && pages.visit(&next)? => next,
+                _ => break,
+            };
+            response = self
+                .client
+                .get(
+                    &next,
+                    crate::Message {
+                        body: None,
+                        content_type: None,
+                    },
+                )
+                .await?;
         }
 
         // Return our response data.
-        Ok(crate::Response::new(status, headers, participants))
+        Ok(crate::Response::new(
+            response.status,
+            response.headers,
+            participants,
+        ))
+    }
+    /**
+     * Get post webinar feedback.
+     *
+     * This function performs a `GET` to the `/metrics/webinars/{webinarId}/participants/satisfaction` endpoint.
+     *
+     * As opposed to `get_all_participant_webinar_feedback`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * When a Webinar ends, each attendee will be prompted to share their Webinar experience by clicking either thumbs up or thumbs down. Use this API to retrieve the feedback submitted for a specific webinar. Note that this API only works for meetings scheduled after December 20, 2020.
+     *
+     * **Prerequisites:**
+     * * [Feedback to Zoom](https://support.zoom.us/hc/en-us/articles/115005838023) setting must be enabled by the participant prior to the meeting.
+     * * The user making the API request must be enrolled in a Business or a higher plan.
+     *
+     *
+     * <br> **Scope:** `dashboard_webinars:read:admin`
+     *
+     * **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Heavy`<br>
+     */
+    pub fn get_all_participant_webinar_feedback_stream(
+        &self,
+        type_: crate::types::DashboardMeetingsType,
+        webinar_id: &str,
+    ) -> impl futures::Stream<
+        Item = ClientResult<crate::types::ParticipantFeedbackResponseParticipants>,
+    > + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !type_.to_string().is_empty() {
+            query_args.push(("type".to_string(), type_.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/metrics/webinars/{}/participants/satisfaction?{}",
+                crate::progenitor_support::encode_path(webinar_id),
+                query_
+            ),
+            None,
+        );
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let next = format!("{}{}next_page_token=", url, sep);
+        self.client.get_pages_stream(
+            url,
+            move |body: crate::types::ParticipantFeedbackResponse| {
+                let page = if body.next_page_token.is_empty() {
+                    None
+                } else {
+                    Some(format!("{}{}", next, body.next_page_token))
+                };
+
+                (body.participants, page)
+            },
+        )
     }
 }
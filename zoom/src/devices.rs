@@ -1,6 +1,68 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Devices::list`], set with a builder.
+pub struct DevicesListRequest<'a> {
+    client: &'a Devices,
+    page_size: Option<i64>,
+    page_number: Option<i64>,
+    next_page_token: Option<&'a str>,
+}
+
+impl<'a> DevicesListRequest<'a> {
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Sets the `page_number` query parameter.
+    pub fn page_number(mut self, page_number: i64) -> Self {
+        self.page_number = Some(page_number);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.next_page_token = Some(next_page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::Domains>> {
+        let Self {
+            client,
+            page_size,
+            page_number,
+            next_page_token,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = next_page_token {
+            query_args.push(("next_page_token".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_number {
+            query_args.push(("page_number".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_size {
+            query_args.push(("page_size".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/h323/devices?{}", query_), None);
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct Devices {
     pub client: Client,
 }
@@ -58,6 +120,26 @@ impl Devices {
             )
             .await
     }
+    /**
+     * List H.323/SIP devices.
+     *
+     * This function performs a `GET` to the `/h323/devices` endpoint.
+     *
+     * As opposed to `list`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * A H.323 or SIP device can make a video call to a [Room Connector](https://support.zoom.us/hc/en-us/articles/201363273-Getting-Started-With-H-323-SIP-Room-Connector) to join a Zoom cloud meeting. A Room Connector can also call out to a H.323 or SIP device to join a Zoom cloud meeting. Use this API to list all H.323/SIP Devices on a Zoom account.<br><br>
+     * **Scopes:** `h323:read:admin`<br>
+     *
+     *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`<br>
+     */
+    pub fn list_request(&self) -> DevicesListRequest<'_> {
+        DevicesListRequest {
+            client: self,
+            page_size: None,
+            page_number: None,
+            next_page_token: None,
+        }
+    }
     /**
      * Create a H.323/SIP device.
      *
@@ -1,6 +1,420 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Groups::members`], set with a builder.
+pub struct GroupsMembersRequest<'a> {
+    client: &'a Groups,
+    group_id: &'a str,
+    page_size: Option<i64>,
+    page_number: Option<i64>,
+    next_page_token: Option<&'a str>,
+}
+
+impl<'a> GroupsMembersRequest<'a> {
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Sets the `page_number` query parameter.
+    pub fn page_number(mut self, page_number: i64) -> Self {
+        self.page_number = Some(page_number);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.next_page_token = Some(next_page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::UserCreateResponse>>> {
+        let Self {
+            client,
+            group_id,
+            page_size,
+            page_number,
+            next_page_token,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = next_page_token {
+            query_args.push(("next_page_token".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_number {
+            query_args.push(("page_number".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_size {
+            query_args.push(("page_size".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/groups/{}/members?{}",
+                crate::progenitor_support::encode_path(group_id),
+                query_
+            ),
+            None,
+        );
+        let resp: crate::Response<crate::types::GroupMembersResponseData> = client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await?;
+
+        // Return our response data.
+        Ok(crate::Response::new(
+            resp.status,
+            resp.headers,
+            resp.body.members.to_vec(),
+        ))
+    }
+}
+
+/// The parameters of [`Groups::get_setting`], set with a builder.
+pub struct GroupsGetSettingRequest<'a> {
+    client: &'a Groups,
+    group_id: &'a str,
+    custom_query_fields: Option<&'a str>,
+    option: Option<crate::types::OptionData>,
+}
+
+impl<'a> GroupsGetSettingRequest<'a> {
+    /// Sets the `custom_query_fields` query parameter.
+    pub fn custom_query_fields(mut self, custom_query_fields: &'a str) -> Self {
+        self.custom_query_fields = Some(custom_query_fields);
+        self
+    }
+
+    /// Sets the `option` query parameter.
+    pub fn option(mut self, option: crate::types::OptionData) -> Self {
+        self.option = Some(option);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::GetGroupSettingsResponseOneOf>> {
+        let Self {
+            client,
+            group_id,
+            custom_query_fields,
+            option,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = custom_query_fields {
+            query_args.push(("custom_query_fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = option {
+            query_args.push(("option".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/groups/{}/settings?{}",
+                crate::progenitor_support::encode_path(group_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Groups::update_settings`], set with a builder.
+pub struct GroupsUpdateSettingsRequest<'a> {
+    client: &'a Groups,
+    group_id: &'a str,
+    custom_query_fields: Option<&'a str>,
+    option: Option<crate::types::UpdateGroupSettingsOption>,
+    body: &'a crate::types::UpdateGroupSettingsRequestOneOf,
+}
+
+impl<'a> GroupsUpdateSettingsRequest<'a> {
+    /// Sets the `custom_query_fields` query parameter.
+    pub fn custom_query_fields(mut self, custom_query_fields: &'a str) -> Self {
+        self.custom_query_fields = Some(custom_query_fields);
+        self
+    }
+
+    /// Sets the `option` query parameter.
+    pub fn option(mut self, option: crate::types::UpdateGroupSettingsOption) -> Self {
+        self.option = Some(option);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            group_id,
+            custom_query_fields,
+            option,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = custom_query_fields {
+            query_args.push(("custom_query_fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = option {
+            query_args.push(("option".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/groups/{}/settings?{}",
+                crate::progenitor_support::encode_path(group_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .patch(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Groups::get_lock_setting`], set with a builder.
+pub struct GroupsGetLockSettingRequest<'a> {
+    client: &'a Groups,
+    group_id: &'a str,
+    custom_query_fields: Option<&'a str>,
+    option: Option<&'a str>,
+}
+
+impl<'a> GroupsGetLockSettingRequest<'a> {
+    /// Sets the `custom_query_fields` query parameter.
+    pub fn custom_query_fields(mut self, custom_query_fields: &'a str) -> Self {
+        self.custom_query_fields = Some(custom_query_fields);
+        self
+    }
+
+    /// Sets the `option` query parameter.
+    pub fn option(mut self, option: &'a str) -> Self {
+        self.option = Some(option);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::GetGroupLockSettingsResponseOneOf>> {
+        let Self {
+            client,
+            group_id,
+            custom_query_fields,
+            option,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = custom_query_fields {
+            query_args.push(("custom_query_fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = option {
+            query_args.push(("option".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/groups/{}/lock_settings?{}",
+                crate::progenitor_support::encode_path(group_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Groups::locked_settings`], set with a builder.
+pub struct GroupsLockedSettingsRequest<'a> {
+    client: &'a Groups,
+    group_id: &'a str,
+    custom_query_fields: Option<&'a str>,
+    option: Option<&'a str>,
+    body: &'a crate::types::GroupLockedSettingsRequestOneOf,
+}
+
+impl<'a> GroupsLockedSettingsRequest<'a> {
+    /// Sets the `custom_query_fields` query parameter.
+    pub fn custom_query_fields(mut self, custom_query_fields: &'a str) -> Self {
+        self.custom_query_fields = Some(custom_query_fields);
+        self
+    }
+
+    /// Sets the `option` query parameter.
+    pub fn option(mut self, option: &'a str) -> Self {
+        self.option = Some(option);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            group_id,
+            custom_query_fields,
+            option,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = custom_query_fields {
+            query_args.push(("custom_query_fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = option {
+            query_args.push(("option".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/groups/{}/lock_settings?{}",
+                crate::progenitor_support::encode_path(group_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .patch(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Groups::upload_vb`], set with a builder.
+pub struct GroupsUploadVbRequest<'a> {
+    client: &'a Groups,
+    file_ids: Option<&'a str>,
+    group_id: &'a str,
+    body: &'a crate::types::UploadVbRequest,
+}
+
+impl<'a> GroupsUploadVbRequest<'a> {
+    /// Sets the `file_ids` query parameter.
+    pub fn file_ids(mut self, file_ids: &'a str) -> Self {
+        self.file_ids = Some(file_ids);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::Files>> {
+        let Self {
+            client,
+            file_ids,
+            group_id,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = file_ids {
+            query_args.push(("file_ids".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/groups/{}/settings/virtual_backgrounds?{}",
+                crate::progenitor_support::encode_path(group_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Groups::del_vb`], set with a builder.
+pub struct GroupsDelVbRequest<'a> {
+    client: &'a Groups,
+    file_ids: Option<&'a str>,
+    group_id: &'a str,
+}
+
+impl<'a> GroupsDelVbRequest<'a> {
+    /// Sets the `file_ids` query parameter.
+    pub fn file_ids(mut self, file_ids: &'a str) -> Self {
+        self.file_ids = Some(file_ids);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            file_ids,
+            group_id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = file_ids {
+            query_args.push(("file_ids".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/groups/{}/settings/virtual_backgrounds?{}",
+                crate::progenitor_support::encode_path(group_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .delete(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct Groups {
     pub client: Client,
 }
@@ -243,6 +657,29 @@ impl Groups {
             resp.body.members.to_vec(),
         ))
     }
+    /**
+     * List group members .
+     *
+     * This function performs a `GET` to the `/groups/{groupId}/members` endpoint.
+     *
+     * As opposed to `members`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * List the members of a [group](https://support.zoom.us/hc/en-us/articles/204519819-Group-Management-) under your account.
+     *
+     * **Prerequisite**: Pro, Business, or Education account<br>
+     * **Scopes**: `group:read:admin`<br>
+     *
+     *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     */
+    pub fn members_request<'a>(&'a self, group_id: &'a str) -> GroupsMembersRequest<'a> {
+        GroupsMembersRequest {
+            client: self,
+            group_id,
+            page_size: None,
+            page_number: None,
+            next_page_token: None,
+        }
+    }
     /**
      * List group members .
      *
@@ -268,11 +705,11 @@ impl Groups {
             ),
             None,
         );
-        let crate::Response::<crate::types::GroupMembersResponseData> {
-            mut status,
-            mut headers,
-            mut body,
-        } = self
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let mut pages = crate::utils::Pages::new(self.client.page_limits);
+        pages.visit(&url)?;
+
+        let mut response: crate::Response<crate::types::GroupMembersResponseData> = self
             .client
             .get(
                 &url,
@@ -282,56 +719,82 @@ impl Groups {
                 },
             )
             .await?;
-
-        let mut members = body.members;
-        let mut page = body.next_page_token;
+        let mut members = Vec::new();
 
         // Paginate if we should.
-        while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                crate::Response::<crate::types::GroupMembersResponseData> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}?next_page_token={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
+        loop {
+            let page = if response.body.next_page_token.is_empty() {
+                None
             } else {
-                crate::Response::<crate::types::GroupMembersResponseData> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}&next_page_token={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
-
-            members.append(&mut body.members);
+                Some(format!(
+                    "{}{}next_page_token={}",
+                    url, sep, response.body.next_page_token
+                ))
+            };
+            let more = pages.keep(&mut response.body.members);
+            members.append(&mut response.body.members);
 
-            if !body.next_page_token.is_empty() && body.next_page_token != page {
-                page = body.next_page_token.to_string();
-            } else {
-                page = "".to_string();
-            }
+            let next = match page {
+                Some(next) if more && pages.visit(&next)? => next,
+                _ => break,
+            };
+            response = self
+                .client
+                .get(
+                    &next,
+                    crate::Message {
+                        body: None,
+                        content_type: None,
+                    },
+                )
+                .await?;
         }
 
         // Return our response data.
-        Ok(crate::Response::new(status, headers, members))
+        Ok(crate::Response::new(
+            response.status,
+            response.headers,
+            members,
+        ))
+    }
+    /**
+     * List group members .
+     *
+     * This function performs a `GET` to the `/groups/{groupId}/members` endpoint.
+     *
+     * As opposed to `get_all_members`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * List the members of a [group](https://support.zoom.us/hc/en-us/articles/204519819-Group-Management-) under your account.
+     *
+     * **Prerequisite**: Pro, Business, or Education account<br>
+     * **Scopes**: `group:read:admin`<br>
+     *
+     *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     */
+    pub fn get_all_members_stream(
+        &self,
+        group_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::UserCreateResponse>> + Unpin + '_
+    {
+        let url = self.client.url(
+            &format!(
+                "/groups/{}/members",
+                crate::progenitor_support::encode_path(group_id),
+            ),
+            None,
+        );
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let next = format!("{}{}next_page_token=", url, sep);
+        self.client
+            .get_pages_stream(url, move |body: crate::types::GroupMembersResponseData| {
+                let page = if body.next_page_token.is_empty() {
+                    None
+                } else {
+                    Some(format!("{}{}", next, body.next_page_token))
+                };
+
+                (body.members, page)
+            })
     }
     /**
      * Add group members.
@@ -676,6 +1139,27 @@ impl Groups {
             )
             .await
     }
+    /**
+     * Get a group's settings.
+     *
+     * This function performs a `GET` to the `/groups/{groupId}/settings` endpoint.
+     *
+     * As opposed to `get_setting`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get settings for a [group](https://support.zoom.us/hc/en-us/articles/204519819-Group-Management-).
+     * **Prerequisite**: Pro, Business, or Education account<br>
+     * **Scopes**: `group:read:admin`<br>
+     *  
+     *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     */
+    pub fn get_setting_request<'a>(&'a self, group_id: &'a str) -> GroupsGetSettingRequest<'a> {
+        GroupsGetSettingRequest {
+            client: self,
+            group_id,
+            custom_query_fields: None,
+            option: None,
+        }
+    }
     /**
      * Update a group's settings.
      *
@@ -728,6 +1212,32 @@ impl Groups {
             )
             .await
     }
+    /**
+     * Update a group's settings.
+     *
+     * This function performs a `PATCH` to the `/groups/{groupId}/settings` endpoint.
+     *
+     * As opposed to `update_settings`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Update settings for a [group](https://support.zoom.us/hc/en-us/articles/204519819-Group-Management-).<p style="background-color:#FEEFB3; color:#9F6000"><br>Note:</b> The `force_pmi_jbh_password` field under meeting settings is planned to be deprecated on September 22, 2019. This field will be replaced by another field that will provide the same functionality.</p>
+     * **Prerequisite**: Pro, Business, or Education account<br>
+     * **Scopes**: `group:write:admin`<br>
+     *
+     *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     */
+    pub fn update_settings_request<'a>(
+        &'a self,
+        group_id: &'a str,
+        body: &'a crate::types::UpdateGroupSettingsRequestOneOf,
+    ) -> GroupsUpdateSettingsRequest<'a> {
+        GroupsUpdateSettingsRequest {
+            client: self,
+            group_id,
+            custom_query_fields: None,
+            option: None,
+            body,
+        }
+    }
     /**
      * Get locked settings.
      *
@@ -884,6 +1394,31 @@ impl Groups {
             )
             .await
     }
+    /**
+     * Get locked settings.
+     *
+     * This function performs a `GET` to the `/groups/{groupId}/lock_settings` endpoint.
+     *
+     * As opposed to `get_lock_setting`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Retrieve a [group's](https://support.zoom.us/hc/en-us/articles/204519819-Group-Management-) locked settings. If you lock a setting, the group members will not be able to modify it individually. <p style="background-color:#FEEFB3; color:#9F6000"><br>Note:</b> The `force_pmi_jbh_password` field under meeting settings is planned to be deprecated on September 22, 2019. This field will be replaced by another field that will provide the same functionality.</p>
+     *
+     * **Prerequisite**: Pro, Business, or Education account<br>
+     * **Scopes**: `group:read:admin`<br>
+     *
+     *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     */
+    pub fn get_lock_setting_request<'a>(
+        &'a self,
+        group_id: &'a str,
+    ) -> GroupsGetLockSettingRequest<'a> {
+        GroupsGetLockSettingRequest {
+            client: self,
+            group_id,
+            custom_query_fields: None,
+            option: None,
+        }
+    }
     /**
      * Update locked settings.
      *
@@ -937,6 +1472,33 @@ impl Groups {
             )
             .await
     }
+    /**
+     * Update locked settings.
+     *
+     * This function performs a `PATCH` to the `/groups/{groupId}/lock_settings` endpoint.
+     *
+     * As opposed to `locked_settings`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Update a [group's](https://support.zoom.us/hc/en-us/articles/204519819-Group-Management-) locked settings. If you lock a setting, the group members will not be able to modify it individually. <p style="background-color:#FEEFB3; color:#9F6000"><br>Note:</b> The `force_pmi_jbh_password` field under meeting settings is planned to be deprecated on September 22, 2019. This field will be replaced by another field that will provide the same functionality.</p>
+     *
+     * **Prerequisite**: Pro, Business, or Education account<br>
+     * **Scopes**: `group:write:admin`<br>
+     *
+     *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     */
+    pub fn locked_settings_request<'a>(
+        &'a self,
+        group_id: &'a str,
+        body: &'a crate::types::GroupLockedSettingsRequestOneOf,
+    ) -> GroupsLockedSettingsRequest<'a> {
+        GroupsLockedSettingsRequest {
+            client: self,
+            group_id,
+            custom_query_fields: None,
+            option: None,
+            body,
+        }
+    }
     /**
      * Upload virtual background files.
      *
@@ -986,6 +1548,36 @@ impl Groups {
             )
             .await
     }
+    /**
+     * Upload virtual background files.
+     *
+     * This function performs a `POST` to the `/groups/{groupId}/settings/virtual_backgrounds` endpoint.
+     *
+     * As opposed to `upload_vb`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Use this API to [upload virtual background files](https://support.zoom.us/hc/en-us/articles/210707503-Virtual-Background#h_01EJF3YFEWGT8YA0ZJ079JEDQE) for all users in a group to use.
+     *
+     *
+     * **Prerequisites:**<br>
+     * * Virtual background feature must be [enabled](https://support.zoom.us/hc/en-us/articles/210707503-Virtual-Background#h_2ef28080-fce9-4ac2-b567-dc958afab1b7) on the account.
+     * <br> **Scope:** `group:write:admin`<br><br>
+     * **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`<br>
+     *
+     *
+     * `
+     */
+    pub fn upload_vb_request<'a>(
+        &'a self,
+        group_id: &'a str,
+        body: &'a crate::types::UploadVbRequest,
+    ) -> GroupsUploadVbRequest<'a> {
+        GroupsUploadVbRequest {
+            client: self,
+            file_ids: None,
+            group_id,
+            body,
+        }
+    }
     /**
      * Delete virtual background files.
      *
@@ -1033,4 +1625,27 @@ impl Groups {
             )
             .await
     }
+    /**
+     * Delete virtual background files.
+     *
+     * This function performs a `DELETE` to the `/groups/{groupId}/settings/virtual_backgrounds` endpoint.
+     *
+     * As opposed to `del_vb`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Delete existing virtual background file(s) from an account.
+     *
+     * **Prerequisites:**<br>
+     * * Virtual background feature must be [enabled](https://support.zoom.us/hc/en-us/articles/210707503-Virtual-Background#h_2ef28080-fce9-4ac2-b567-dc958afab1b7) on the account.
+     * <br> **Scope:** `group:write:admin`<br><br>
+     * **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Light`<br>
+     *
+     *
+     */
+    pub fn del_vb_request<'a>(&'a self, group_id: &'a str) -> GroupsDelVbRequest<'a> {
+        GroupsDelVbRequest {
+            client: self,
+            file_ids: None,
+            group_id,
+        }
+    }
 }
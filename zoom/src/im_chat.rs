@@ -1,6 +1,281 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`ImChat::session`], set with a builder.
+pub struct ImChatSessionRequest<'a> {
+    client: &'a ImChat,
+    from: chrono::NaiveDate,
+    to: chrono::NaiveDate,
+    page_size: Option<i64>,
+    next_page_token: Option<&'a str>,
+}
+
+impl<'a> ImChatSessionRequest<'a> {
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.next_page_token = Some(next_page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::ImChatSessionsResponseAllOf>> {
+        let Self {
+            client,
+            from,
+            to,
+            page_size,
+            next_page_token,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !from.to_string().is_empty() {
+            query_args.push(("from".to_string(), from.to_string()));
+        }
+        if let Some(v) = next_page_token {
+            query_args.push(("next_page_token".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_size {
+            query_args.push(("page_size".to_string(), v.to_string()));
+        }
+        if !to.to_string().is_empty() {
+            query_args.push(("to".to_string(), to.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/im/chat/sessions?{}", query_), None);
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`ImChat::message`], set with a builder.
+pub struct ImChatMessageRequest<'a> {
+    client: &'a ImChat,
+    session_id: &'a str,
+    from: chrono::NaiveDate,
+    to: chrono::NaiveDate,
+    page_size: Option<i64>,
+    next_page_token: Option<&'a str>,
+}
+
+impl<'a> ImChatMessageRequest<'a> {
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.next_page_token = Some(next_page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::ImChatMessagesResponseAllOf>> {
+        let Self {
+            client,
+            session_id,
+            from,
+            to,
+            page_size,
+            next_page_token,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !from.to_string().is_empty() {
+            query_args.push(("from".to_string(), from.to_string()));
+        }
+        if let Some(v) = next_page_token {
+            query_args.push(("next_page_token".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_size {
+            query_args.push(("page_size".to_string(), v.to_string()));
+        }
+        if !to.to_string().is_empty() {
+            query_args.push(("to".to_string(), to.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/im/chat/sessions/{}?{}",
+                crate::progenitor_support::encode_path(session_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`ImChat::list_im_messages`], set with a builder.
+pub struct ImChatListImMessagesRequest<'a> {
+    client: &'a ImChat,
+    user_id: &'a str,
+    chat_user: Option<&'a str>,
+    channel: Option<&'a str>,
+    date: Option<&'a str>,
+    page_size: Option<i64>,
+    next_page_token: Option<&'a str>,
+}
+
+impl<'a> ImChatListImMessagesRequest<'a> {
+    /// Sets the `chat_user` query parameter.
+    pub fn chat_user(mut self, chat_user: &'a str) -> Self {
+        self.chat_user = Some(chat_user);
+        self
+    }
+
+    /// Sets the `channel` query parameter.
+    pub fn channel(mut self, channel: &'a str) -> Self {
+        self.channel = Some(channel);
+        self
+    }
+
+    /// Sets the `date` query parameter.
+    pub fn date(mut self, date: &'a str) -> Self {
+        self.date = Some(date);
+        self
+    }
+
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.next_page_token = Some(next_page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ListimmessagesResponseMessages>>> {
+        let Self {
+            client,
+            user_id,
+            chat_user,
+            channel,
+            date,
+            page_size,
+            next_page_token,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = channel {
+            query_args.push(("channel".to_string(), v.to_string()));
+        }
+        if let Some(v) = chat_user {
+            query_args.push(("chat_user".to_string(), v.to_string()));
+        }
+        if let Some(v) = date {
+            query_args.push(("date".to_string(), v.to_string()));
+        }
+        if let Some(v) = next_page_token {
+            query_args.push(("next_page_token".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_size {
+            query_args.push(("page_size".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/im/users/{}/chat/messages?{}",
+                crate::progenitor_support::encode_path(user_id),
+                query_
+            ),
+            None,
+        );
+        let resp: crate::Response<crate::types::ListimmessagesResponse> = client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await?;
+
+        // Return our response data.
+        Ok(crate::Response::new(
+            resp.status,
+            resp.headers,
+            resp.body.messages.to_vec(),
+        ))
+    }
+}
+
+/// The parameters of [`ImChat::send_im_messages`], set with a builder.
+pub struct ImChatSendImMessagesRequest<'a> {
+    client: &'a ImChat,
+    chat_user: Option<&'a str>,
+    body: &'a crate::types::SendimmessagesRequest,
+}
+
+impl<'a> ImChatSendImMessagesRequest<'a> {
+    /// Sets the `chat_user` query parameter.
+    pub fn chat_user(mut self, chat_user: &'a str) -> Self {
+        self.chat_user = Some(chat_user);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::Groups>> {
+        let Self {
+            client,
+            chat_user,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = chat_user {
+            query_args.push(("chat_user".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/im/users/me/chat/messages?{}", query_), None);
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
 pub struct ImChat {
     pub client: Client,
 }
@@ -66,6 +341,35 @@ impl ImChat {
             )
             .await
     }
+    /**
+     * Get IM chat sessions.
+     *
+     * This function performs a `GET` to the `/im/chat/sessions` endpoint.
+     *
+     * As opposed to `session`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Retrieve IM Chat sessions for a specified period of time. This API only supports Oauth2.<br>
+     *
+     * **Scopes:** `imchat:read, imchat:read:admin`<br>
+     *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Heavy`
+     *  <br><br>
+     *   <p style="background-color:#e1f5fe; color:#000000; padding:8px"><b>Deprecated:</b> By end of 2021, Zoom is deprecating this API in favor of a consolidated set of APIs. The API will still be available for you to use, though Zoom will no longer provide support for it. For further information, see <a href="https://marketplace.zoom.us/docs/guides/stay-up-to-date/announcements#im-api-notice">Announcements: IM APIs Deprecation</a>.</p>
+     *  
+     *
+     */
+    pub fn session_request(
+        &self,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> ImChatSessionRequest<'_> {
+        ImChatSessionRequest {
+            client: self,
+            from,
+            to,
+            page_size: None,
+            next_page_token: None,
+        }
+    }
     /**
      * Get IM chat messages.
      *
@@ -132,6 +436,41 @@ impl ImChat {
             )
             .await
     }
+    /**
+     * Get IM chat messages.
+     *
+     * This function performs a `GET` to the `/im/chat/sessions/{sessionId}` endpoint.
+     *
+     * As opposed to `message`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Retrieve IM chat messages for a specified period of time. This API only supports oauth2.<br>
+     *
+     * **Scopes:** `imchat:read`<br>
+     *
+     *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`<br>
+     *  
+     *  <br>
+     *  
+     *  <p style="background-color:#e1f5fe; color:#000000; padding:8px"><b>Deprecated:</b> By end of 2021, Zoom is deprecating this API in favor of a consolidated set of APIs. The API will still be available for you to use, though Zoom will no longer provide support for it. For further information, see <a href="https://marketplace.zoom.us/docs/guides/stay-up-to-date/announcements#im-api-notice">Announcements: IM APIs Deprecation</a>.</p>
+     *
+     *
+     *
+     */
+    pub fn message_request<'a>(
+        &'a self,
+        session_id: &'a str,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> ImChatMessageRequest<'a> {
+        ImChatMessageRequest {
+            client: self,
+            session_id,
+            from,
+            to,
+            page_size: None,
+            next_page_token: None,
+        }
+    }
     /**
      * Get user’s IM messages.
      *
@@ -205,6 +544,34 @@ impl ImChat {
             resp.body.messages.to_vec(),
         ))
     }
+    /**
+     * Get user’s IM messages.
+     *
+     * This function performs a `GET` to the `/im/users/{userId}/chat/messages` endpoint.
+     *
+     * As opposed to `list_im_messages`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get IM Chat messages for a specified period of time. This API only supports Oauth2.<br>
+     * **Scopes:** `imchat:read`<br>
+     * **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     * <br><br>
+     *   <p style="background-color:#e1f5fe; color:#000000; padding:8px"><b>Deprecated:</b> By end of 2021, Zoom is deprecating this API in favor of a consolidated set of APIs. The API will still be available for you to use, though Zoom will no longer provide support for it. For further information, see <a href="https://marketplace.zoom.us/docs/guides/stay-up-to-date/announcements#im-api-notice">Announcements: IM APIs Deprecation</a>.</p>
+     *
+     */
+    pub fn list_im_messages_request<'a>(
+        &'a self,
+        user_id: &'a str,
+    ) -> ImChatListImMessagesRequest<'a> {
+        ImChatListImMessagesRequest {
+            client: self,
+            user_id,
+            chat_user: None,
+            channel: None,
+            date: None,
+            page_size: None,
+            next_page_token: None,
+        }
+    }
     /**
      * Get user’s IM messages.
      *
@@ -245,11 +612,11 @@ impl ImChat {
             ),
             None,
         );
-        let crate::Response::<crate::types::ListimmessagesResponse> {
-            mut status,
-            mut headers,
-            mut body,
-        } = self
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let mut pages = crate::utils::Pages::new(self.client.page_limits);
+        pages.visit(&url)?;
+
+        let mut response: crate::Response<crate::types::ListimmessagesResponse> = self
             .client
             .get(
                 &url,
@@ -259,56 +626,98 @@ impl ImChat {
                 },
             )
             .await?;
-
-        let mut messages = body.messages;
-        let mut page = body.next_page_token;
+        let mut messages = Vec::new();
 
         // Paginate if we should.
-        while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                crate::Response::<crate::types::ListimmessagesResponse> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}?next_page_token={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
+        loop {
+            let page = if response.body.next_page_token.is_empty() {
+                None
             } else {
-                crate::Response::<crate::types::ListimmessagesResponse> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}&next_page_token={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
+                Some(format!(
+                    "{}{}next_page_token={}",
+                    url, sep, response.body.next_page_token
+                ))
+            };
+            let more = pages.keep(&mut response.body.messages);
+            messages.append(&mut response.body.messages);
 
-            messages.append(&mut body.messages);
-
-            if !body.next_page_token.is_empty() && body.next_page_token != page {
-                page = body.next_page_token.to_string();
-            } else {
-                page = "".to_string();
-            }
+            let next = match page {
+                Some(next) if more && pages.visit(&next)? => next,
+                _ => break,
+            };
+            response = self
+                .client
+                .get(
+                    &next,
+                    crate::Message {
+                        body: None,
+                        content_type: None,
+                    },
+                )
+                .await?;
         }
 
         // Return our response data.
-        Ok(crate::Response::new(status, headers, messages))
+        Ok(crate::Response::new(
+            response.status,
+            response.headers,
+            messages,
+        ))
+    }
+    /**
+     * Get user’s IM messages.
+     *
+     * This function performs a `GET` to the `/im/users/{userId}/chat/messages` endpoint.
+     *
+     * As opposed to `list_all_im_messages`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Get IM Chat messages for a specified period of time. This API only supports Oauth2.<br>
+     * **Scopes:** `imchat:read`<br>
+     * **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     * <br><br>
+     *   <p style="background-color:#e1f5fe; color:#000000; padding:8px"><b>Deprecated:</b> By end of 2021, Zoom is deprecating this API in favor of a consolidated set of APIs. The API will still be available for you to use, though Zoom will no longer provide support for it. For further information, see <a href="https://marketplace.zoom.us/docs/guides/stay-up-to-date/announcements#im-api-notice">Announcements: IM APIs Deprecation</a>.</p>
+     *
+     */
+    pub fn list_all_im_messages_stream(
+        &self,
+        user_id: &str,
+        chat_user: &str,
+        channel: &str,
+        date: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::ListimmessagesResponseMessages>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !channel.is_empty() {
+            query_args.push(("channel".to_string(), channel.to_string()));
+        }
+        if !chat_user.is_empty() {
+            query_args.push(("chat_user".to_string(), chat_user.to_string()));
+        }
+        if !date.is_empty() {
+            query_args.push(("date".to_string(), date.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/im/users/{}/chat/messages?{}",
+                crate::progenitor_support::encode_path(user_id),
+                query_
+            ),
+            None,
+        );
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let next = format!("{}{}next_page_token=", url, sep);
+        self.client
+            .get_pages_stream(url, move |body: crate::types::ListimmessagesResponse| {
+                let page = if body.next_page_token.is_empty() {
+                    None
+                } else {
+                    Some(format!("{}{}", next, body.next_page_token))
+                };
+
+                (body.messages, page)
+            })
     }
     /**
      * Send IM messages.
@@ -346,4 +755,25 @@ impl ImChat {
             )
             .await
     }
+    /**
+     * Send IM messages.
+     *
+     * This function performs a `POST` to the `/im/users/me/chat/messages` endpoint.
+     *
+     * As opposed to `send_im_messages`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Send chat message to a user. <aside>Note: This API only supports OAuth 2.0.</aside><br><br>**Scope:** `imchat:write`
+     *  
+     *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     */
+    pub fn send_im_messages_request<'a>(
+        &'a self,
+        body: &'a crate::types::SendimmessagesRequest,
+    ) -> ImChatSendImMessagesRequest<'a> {
+        ImChatSendImMessagesRequest {
+            client: self,
+            chat_user: None,
+            body,
+        }
+    }
 }
@@ -1,6 +1,561 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Meetings::get`], set with a builder.
+pub struct MeetingsGetRequest<'a> {
+    client: &'a Meetings,
+    user_id: &'a str,
+    type_: Option<crate::types::MeetingsType>,
+    page_size: Option<i64>,
+    next_page_token: Option<&'a str>,
+    page_number: Option<&'a str>,
+}
+
+impl<'a> MeetingsGetRequest<'a> {
+    /// Sets the `type` query parameter.
+    pub fn type_(mut self, type_: crate::types::MeetingsType) -> Self {
+        self.type_ = Some(type_);
+        self
+    }
+
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.next_page_token = Some(next_page_token);
+        self
+    }
+
+    /// Sets the `page_number` query parameter.
+    pub fn page_number(mut self, page_number: &'a str) -> Self {
+        self.page_number = Some(page_number);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::Domains>> {
+        let Self {
+            client,
+            user_id,
+            type_,
+            page_size,
+            next_page_token,
+            page_number,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = next_page_token {
+            query_args.push(("next_page_token".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_number {
+            query_args.push(("page_number".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_size {
+            query_args.push(("page_size".to_string(), v.to_string()));
+        }
+        if let Some(v) = type_ {
+            query_args.push(("type".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/users/{}/meetings?{}",
+                crate::progenitor_support::encode_path(user_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Meetings::meeting`], set with a builder.
+pub struct MeetingsMeetingRequest<'a> {
+    client: &'a Meetings,
+    meeting_id: i64,
+    occurrence_id: Option<&'a str>,
+    show_previous_occurrences: Option<bool>,
+}
+
+impl<'a> MeetingsMeetingRequest<'a> {
+    /// Sets the `occurrence_id` query parameter.
+    pub fn occurrence_id(mut self, occurrence_id: &'a str) -> Self {
+        self.occurrence_id = Some(occurrence_id);
+        self
+    }
+
+    /// Sets the `show_previous_occurrences` query parameter.
+    pub fn show_previous_occurrences(mut self, show_previous_occurrences: bool) -> Self {
+        self.show_previous_occurrences = Some(show_previous_occurrences);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::MeetingResponseAllOf>> {
+        let Self {
+            client,
+            meeting_id,
+            occurrence_id,
+            show_previous_occurrences,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = occurrence_id {
+            query_args.push(("occurrence_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = show_previous_occurrences {
+            query_args.push(("show_previous_occurrences".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/meetings/{}?{}",
+                crate::progenitor_support::encode_path(&meeting_id.to_string()),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Meetings::delete`], set with a builder.
+pub struct MeetingsDeleteRequest<'a> {
+    client: &'a Meetings,
+    meeting_id: i64,
+    occurrence_id: Option<&'a str>,
+    schedule_for_reminder: Option<bool>,
+    cancel_meeting_reminder: Option<&'a str>,
+}
+
+impl<'a> MeetingsDeleteRequest<'a> {
+    /// Sets the `occurrence_id` query parameter.
+    pub fn occurrence_id(mut self, occurrence_id: &'a str) -> Self {
+        self.occurrence_id = Some(occurrence_id);
+        self
+    }
+
+    /// Sets the `schedule_for_reminder` query parameter.
+    pub fn schedule_for_reminder(mut self, schedule_for_reminder: bool) -> Self {
+        self.schedule_for_reminder = Some(schedule_for_reminder);
+        self
+    }
+
+    /// Sets the `cancel_meeting_reminder` query parameter.
+    pub fn cancel_meeting_reminder(mut self, cancel_meeting_reminder: &'a str) -> Self {
+        self.cancel_meeting_reminder = Some(cancel_meeting_reminder);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            meeting_id,
+            occurrence_id,
+            schedule_for_reminder,
+            cancel_meeting_reminder,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = cancel_meeting_reminder {
+            query_args.push(("cancel_meeting_reminder".to_string(), v.to_string()));
+        }
+        if let Some(v) = occurrence_id {
+            query_args.push(("occurrence_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = schedule_for_reminder {
+            query_args.push(("schedule_for_reminder".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/meetings/{}?{}",
+                crate::progenitor_support::encode_path(&meeting_id.to_string()),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .delete(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Meetings::update`], set with a builder.
+pub struct MeetingsUpdateRequest<'a> {
+    client: &'a Meetings,
+    meeting_id: i64,
+    occurrence_id: Option<&'a str>,
+    body: &'a crate::types::MeetingUpdateRequestAllOf,
+}
+
+impl<'a> MeetingsUpdateRequest<'a> {
+    /// Sets the `occurrence_id` query parameter.
+    pub fn occurrence_id(mut self, occurrence_id: &'a str) -> Self {
+        self.occurrence_id = Some(occurrence_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            meeting_id,
+            occurrence_id,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = occurrence_id {
+            query_args.push(("occurrence_id".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/meetings/{}?{}",
+                crate::progenitor_support::encode_path(&meeting_id.to_string()),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .patch(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Meetings::registrant`], set with a builder.
+pub struct MeetingsRegistrantRequest<'a> {
+    client: &'a Meetings,
+    meeting_id: i64,
+    occurrence_id: Option<&'a str>,
+    status: Option<crate::types::MeetingRegistrantsStatus>,
+    page_size: Option<i64>,
+    page_number: Option<i64>,
+    next_page_token: Option<&'a str>,
+}
+
+impl<'a> MeetingsRegistrantRequest<'a> {
+    /// Sets the `occurrence_id` query parameter.
+    pub fn occurrence_id(mut self, occurrence_id: &'a str) -> Self {
+        self.occurrence_id = Some(occurrence_id);
+        self
+    }
+
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: crate::types::MeetingRegistrantsStatus) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Sets the `page_number` query parameter.
+    pub fn page_number(mut self, page_number: i64) -> Self {
+        self.page_number = Some(page_number);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.next_page_token = Some(next_page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::Domains>> {
+        let Self {
+            client,
+            meeting_id,
+            occurrence_id,
+            status,
+            page_size,
+            page_number,
+            next_page_token,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = next_page_token {
+            query_args.push(("next_page_token".to_string(), v.to_string()));
+        }
+        if let Some(v) = occurrence_id {
+            query_args.push(("occurrence_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_number {
+            query_args.push(("page_number".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_size {
+            query_args.push(("page_size".to_string(), v.to_string()));
+        }
+        if let Some(v) = status {
+            query_args.push(("status".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/meetings/{}/registrants?{}",
+                crate::progenitor_support::encode_path(&meeting_id.to_string()),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Meetings::registrant_create`], set with a builder.
+pub struct MeetingsRegistrantCreateRequest<'a> {
+    client: &'a Meetings,
+    meeting_id: i64,
+    occurrence_ids: Option<&'a str>,
+}
+
+impl<'a> MeetingsRegistrantCreateRequest<'a> {
+    /// Sets the `occurrence_ids` query parameter.
+    pub fn occurrence_ids(mut self, occurrence_ids: &'a str) -> Self {
+        self.occurrence_ids = Some(occurrence_ids);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::MeetingRegistrantCreateResponse>> {
+        let Self {
+            client,
+            meeting_id,
+            occurrence_ids,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = occurrence_ids {
+            query_args.push(("occurrence_ids".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/meetings/{}/registrants?{}",
+                crate::progenitor_support::encode_path(&meeting_id.to_string()),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Meetings::meetingregistrantdelete`], set with a builder.
+pub struct MeetingsMeetingregistrantdeleteRequest<'a> {
+    client: &'a Meetings,
+    occurrence_id: Option<&'a str>,
+    meeting_id: i64,
+    registrant_id: &'a str,
+}
+
+impl<'a> MeetingsMeetingregistrantdeleteRequest<'a> {
+    /// Sets the `occurrence_id` query parameter.
+    pub fn occurrence_id(mut self, occurrence_id: &'a str) -> Self {
+        self.occurrence_id = Some(occurrence_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            occurrence_id,
+            meeting_id,
+            registrant_id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = occurrence_id {
+            query_args.push(("occurrence_id".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/meetings/{}/registrants/{}?{}",
+                crate::progenitor_support::encode_path(&meeting_id.to_string()),
+                crate::progenitor_support::encode_path(registrant_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .delete(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Meetings::registrant_status`], set with a builder.
+pub struct MeetingsRegistrantStatusRequest<'a> {
+    client: &'a Meetings,
+    meeting_id: i64,
+    occurrence_id: Option<&'a str>,
+    body: &'a crate::types::RegistrantStatus,
+}
+
+impl<'a> MeetingsRegistrantStatusRequest<'a> {
+    /// Sets the `occurrence_id` query parameter.
+    pub fn occurrence_id(mut self, occurrence_id: &'a str) -> Self {
+        self.occurrence_id = Some(occurrence_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            meeting_id,
+            occurrence_id,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = occurrence_id {
+            query_args.push(("occurrence_id".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/meetings/{}/registrants/status?{}",
+                crate::progenitor_support::encode_path(&meeting_id.to_string()),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .put(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Meetings::past_participant`], set with a builder.
+pub struct MeetingsPastParticipantRequest<'a> {
+    client: &'a Meetings,
+    meeting_uuid: &'a str,
+    page_size: Option<i64>,
+    next_page_token: Option<&'a str>,
+}
+
+impl<'a> MeetingsPastParticipantRequest<'a> {
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.next_page_token = Some(next_page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::PastMeetingParticipantsResponseAllOf>> {
+        let Self {
+            client,
+            meeting_uuid,
+            page_size,
+            next_page_token,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = next_page_token {
+            query_args.push(("next_page_token".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_size {
+            query_args.push(("page_size".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/past_meetings/{}/participants?{}",
+                crate::progenitor_support::encode_path(meeting_uuid),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct Meetings {
     pub client: Client,
 }
@@ -70,6 +625,29 @@ impl Meetings {
             )
             .await
     }
+    /**
+     * List meetings.
+     *
+     * This function performs a `GET` to the `/users/{userId}/meetings` endpoint.
+     *
+     * As opposed to `get`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * List all the meetings that were scheduled for a user (meeting host). For user-level apps, pass [the `me` value](https://marketplace.zoom.us/docs/api-reference/using-zoom-apis#mekeyword) instead of the `userId` parameter.
+     *
+     * This API **only** supports scheduled meetings. This API does not return information about instant meetings.
+     *
+     * **Scopes:** `meeting:read:admin`, `meeting:read`</br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     */
+    pub fn get_request<'a>(&'a self, user_id: &'a str) -> MeetingsGetRequest<'a> {
+        MeetingsGetRequest {
+            client: self,
+            user_id,
+            type_: None,
+            page_size: None,
+            next_page_token: None,
+            page_number: None,
+        }
+    }
     /**
      * Create a meeting.
      *
@@ -169,6 +747,28 @@ impl Meetings {
             )
             .await
     }
+    /**
+     * Get a meeting.
+     *
+     * This function performs a `GET` to the `/meetings/{meetingId}` endpoint.
+     *
+     * As opposed to `meeting`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Retrieve the details of a meeting.<br><br>
+     * **Scopes:** `meeting:read:admin` `meeting:read`<br>
+     *
+     *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Light`
+     *
+     *
+     */
+    pub fn meeting_request(&self, meeting_id: i64) -> MeetingsMeetingRequest<'_> {
+        MeetingsMeetingRequest {
+            client: self,
+            meeting_id,
+            occurrence_id: None,
+            show_previous_occurrences: None,
+        }
+    }
     /**
      * Delete a meeting.
      *
@@ -236,6 +836,29 @@ impl Meetings {
             )
             .await
     }
+    /**
+     * Delete a meeting.
+     *
+     * This function performs a `DELETE` to the `/meetings/{meetingId}` endpoint.
+     *
+     * As opposed to `delete`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Delete a meeting.<br><br>
+     * **Scopes:** `meeting:write:admin` `meeting:write`<br>
+     *
+     *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Light`
+     *
+     *
+     */
+    pub fn delete_request(&self, meeting_id: i64) -> MeetingsDeleteRequest<'_> {
+        MeetingsDeleteRequest {
+            client: self,
+            meeting_id,
+            occurrence_id: None,
+            schedule_for_reminder: None,
+            cancel_meeting_reminder: None,
+        }
+    }
     /**
      * Update a meeting.
      *
@@ -281,6 +904,29 @@ impl Meetings {
             )
             .await
     }
+    /**
+     * Update a meeting.
+     *
+     * This function performs a `PATCH` to the `/meetings/{meetingId}` endpoint.
+     *
+     * As opposed to `update`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Update the details of a meeting. This API has a rate limit of 100 requests per day. Because of this, a meeting can only be updated for a maximum of 100 times within a 24 hour period.
+     *
+     * **Scopes:** `meeting:write:admin`, `meeting:write`</br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Light`
+     */
+    pub fn update_request<'a>(
+        &'a self,
+        meeting_id: i64,
+        body: &'a crate::types::MeetingUpdateRequestAllOf,
+    ) -> MeetingsUpdateRequest<'a> {
+        MeetingsUpdateRequest {
+            client: self,
+            meeting_id,
+            occurrence_id: None,
+            body,
+        }
+    }
     /**
      * Update meeting status.
      *
@@ -386,6 +1032,29 @@ impl Meetings {
             )
             .await
     }
+    /**
+     * List meeting registrants.
+     *
+     * This function performs a `GET` to the `/meetings/{meetingId}/registrants` endpoint.
+     *
+     * As opposed to `registrant`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * A host or a user with admin permission can require [registration for a Zoom meeting](https://support.zoom.us/hc/en-us/articles/211579443-Registration-for-Meetings). Use this API to list users that have registered for a meeting.<br><br>
+     * **Scopes**: `meeting:read:admin` `meeting:read`<br>
+     *
+     *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     */
+    pub fn registrant_request(&self, meeting_id: i64) -> MeetingsRegistrantRequest<'_> {
+        MeetingsRegistrantRequest {
+            client: self,
+            meeting_id,
+            occurrence_id: None,
+            status: None,
+            page_size: None,
+            page_number: None,
+            next_page_token: None,
+        }
+    }
     /**
      * Add meeting registrant.
      *
@@ -434,6 +1103,31 @@ impl Meetings {
             )
             .await
     }
+    /**
+     * Add meeting registrant.
+     *
+     * This function performs a `POST` to the `/meetings/{meetingId}/registrants` endpoint.
+     *
+     * As opposed to `registrant_create`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Register a participant for a meeting.<br><br> Note that there is a maximum limit of 4999 registrants per meeting and users will see an error if the capacity has reached.
+     *
+     * **Prerequisite:**<br>
+     * * Host user type must be "Licensed".
+     *
+     * **Scopes:** `meeting:write:admin` `meeting:write`
+     *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Light`
+     */
+    pub fn registrant_create_request(
+        &self,
+        meeting_id: i64,
+    ) -> MeetingsRegistrantCreateRequest<'_> {
+        MeetingsRegistrantCreateRequest {
+            client: self,
+            meeting_id,
+            occurrence_ids: None,
+        }
+    }
     /**
      * Delete a meeting registrant.
      *
@@ -480,6 +1174,30 @@ impl Meetings {
             )
             .await
     }
+    /**
+     * Delete a meeting registrant.
+     *
+     * This function performs a `DELETE` to the `/meetings/{meetingId}/registrants/{registrantId}` endpoint.
+     *
+     * As opposed to `meetingregistrantdelete`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Delete a meeting registrant.<br><br>
+     * **Scopes**: `meeting:write:admin` `meeting:write`<br>
+     *  <br>
+     *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Light`
+     */
+    pub fn meetingregistrantdelete_request<'a>(
+        &'a self,
+        meeting_id: i64,
+        registrant_id: &'a str,
+    ) -> MeetingsMeetingregistrantdeleteRequest<'a> {
+        MeetingsMeetingregistrantdeleteRequest {
+            client: self,
+            occurrence_id: None,
+            meeting_id,
+            registrant_id,
+        }
+    }
     /**
      * Update registrant's status.
      *
@@ -525,6 +1243,29 @@ impl Meetings {
             )
             .await
     }
+    /**
+     * Update registrant's status.
+     *
+     * This function performs a `PUT` to the `/meetings/{meetingId}/registrants/status` endpoint.
+     *
+     * As opposed to `registrant_status`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Update a meeting registrant's status by either approving, cancelling or denying a registrant from joining the meeting.<br><br>
+     * **Scopes:** `meeting:write:admin` `meeting:write`
+     *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     */
+    pub fn registrant_status_request<'a>(
+        &'a self,
+        meeting_id: i64,
+        body: &'a crate::types::RegistrantStatus,
+    ) -> MeetingsRegistrantStatusRequest<'a> {
+        MeetingsRegistrantStatusRequest {
+            client: self,
+            meeting_id,
+            occurrence_id: None,
+            body,
+        }
+    }
     /**
      * Get past meeting details.
      *
@@ -618,6 +1359,34 @@ impl Meetings {
             )
             .await
     }
+    /**
+     * Get past meeting participants.
+     *
+     * This function performs a `GET` to the `/past_meetings/{meetingUUID}/participants` endpoint.
+     *
+     * As opposed to `past_participant`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Retrieve information on participants from a past meeting. <br><br>
+     * **Scopes:** `meeting:read:admin` `meeting:read`
+     *
+     *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     * **Prerequisites:**<br>
+     * * Paid account on a Pro or higher plan.
+     *
+     * <br> <br>  **Note**: Please double encode your UUID when using this API if the UUID begins with a '/'or contains '//' in it.
+     *
+     */
+    pub fn past_participant_request<'a>(
+        &'a self,
+        meeting_uuid: &'a str,
+    ) -> MeetingsPastParticipantRequest<'a> {
+        MeetingsPastParticipantRequest {
+            client: self,
+            meeting_uuid,
+            page_size: None,
+            next_page_token: None,
+        }
+    }
     /**
      * List ended meeting instances.
      *
@@ -1,6 +1,1149 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Phone::list_account_numbers`], set with a builder.
+pub struct PhoneListAccountNumbersRequest<'a> {
+    client: &'a Phone,
+    next_page_token: Option<&'a str>,
+    type_: Option<crate::types::ListAccountPhoneNumbersType>,
+    extension_type: Option<crate::types::ExtensionType>,
+    page_size: Option<i64>,
+    number_type: Option<crate::types::Type>,
+    pending_numbers: Option<bool>,
+    site_id: Option<&'a str>,
+}
+
+impl<'a> PhoneListAccountNumbersRequest<'a> {
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.next_page_token = Some(next_page_token);
+        self
+    }
+
+    /// Sets the `type` query parameter.
+    pub fn type_(mut self, type_: crate::types::ListAccountPhoneNumbersType) -> Self {
+        self.type_ = Some(type_);
+        self
+    }
+
+    /// Sets the `extension_type` query parameter.
+    pub fn extension_type(mut self, extension_type: crate::types::ExtensionType) -> Self {
+        self.extension_type = Some(extension_type);
+        self
+    }
+
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Sets the `number_type` query parameter.
+    pub fn number_type(mut self, number_type: crate::types::Type) -> Self {
+        self.number_type = Some(number_type);
+        self
+    }
+
+    /// Sets the `pending_numbers` query parameter.
+    pub fn pending_numbers(mut self, pending_numbers: bool) -> Self {
+        self.pending_numbers = Some(pending_numbers);
+        self
+    }
+
+    /// Sets the `site_id` query parameter.
+    pub fn site_id(mut self, site_id: &'a str) -> Self {
+        self.site_id = Some(site_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ListAccountPhoneNumbersResponse>>> {
+        let Self {
+            client,
+            next_page_token,
+            type_,
+            extension_type,
+            page_size,
+            number_type,
+            pending_numbers,
+            site_id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = extension_type {
+            query_args.push(("extension_type".to_string(), v.to_string()));
+        }
+        if let Some(v) = next_page_token {
+            query_args.push(("next_page_token".to_string(), v.to_string()));
+        }
+        if let Some(v) = number_type {
+            query_args.push(("number_type".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_size {
+            query_args.push(("page_size".to_string(), v.to_string()));
+        }
+        if let Some(v) = pending_numbers {
+            query_args.push(("pending_numbers".to_string(), v.to_string()));
+        }
+        if let Some(v) = site_id {
+            query_args.push(("site_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = type_ {
+            query_args.push(("type".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/phone/numbers?{}", query_), None);
+        let resp: crate::Response<crate::types::ListAccountPhoneNumbersResponseData> = client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await?;
+
+        // Return our response data.
+        Ok(crate::Response::new(
+            resp.status,
+            resp.headers,
+            resp.body.phone_numbers.to_vec(),
+        ))
+    }
+}
+
+/// The parameters of [`Phone::list_setting_templates`], set with a builder.
+pub struct PhoneListSettingTemplatesRequest<'a> {
+    client: &'a Phone,
+    page_size: Option<i64>,
+    next_page_token: Option<&'a str>,
+    site_id: Option<&'a str>,
+}
+
+impl<'a> PhoneListSettingTemplatesRequest<'a> {
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.next_page_token = Some(next_page_token);
+        self
+    }
+
+    /// Sets the `site_id` query parameter.
+    pub fn site_id(mut self, site_id: &'a str) -> Self {
+        self.site_id = Some(site_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Templates>>> {
+        let Self {
+            client,
+            page_size,
+            next_page_token,
+            site_id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = next_page_token {
+            query_args.push(("next_page_token".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_size {
+            query_args.push(("page_size".to_string(), v.to_string()));
+        }
+        if let Some(v) = site_id {
+            query_args.push(("site_id".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/phone/setting_templates?{}", query_), None);
+        let resp: crate::Response<crate::types::ListSettingTemplatesResponse> = client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await?;
+
+        // Return our response data.
+        Ok(crate::Response::new(
+            resp.status,
+            resp.headers,
+            resp.body.templates.to_vec(),
+        ))
+    }
+}
+
+/// The parameters of [`Phone::list_locations`], set with a builder.
+pub struct PhoneListLocationsRequest<'a> {
+    client: &'a Phone,
+    next_page_token: Option<&'a str>,
+    page_size: Option<i64>,
+}
+
+impl<'a> PhoneListLocationsRequest<'a> {
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.next_page_token = Some(next_page_token);
+        self
+    }
+
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ListLocationsResponse>>> {
+        let Self {
+            client,
+            next_page_token,
+            page_size,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = next_page_token {
+            query_args.push(("next_page_token".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_size {
+            query_args.push(("page_size".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/phone/locations?{}", query_), None);
+        let resp: crate::Response<crate::types::ListLocationsResponseData> = client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await?;
+
+        // Return our response data.
+        Ok(crate::Response::new(
+            resp.status,
+            resp.headers,
+            resp.body.locations.to_vec(),
+        ))
+    }
+}
+
+/// The parameters of [`Phone::list_sip_groups`], set with a builder.
+pub struct PhoneListSipGroupsRequest<'a> {
+    client: &'a Phone,
+    next_page_token: Option<&'a str>,
+    page_size: Option<i64>,
+}
+
+impl<'a> PhoneListSipGroupsRequest<'a> {
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.next_page_token = Some(next_page_token);
+        self
+    }
+
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::SipGroups>>> {
+        let Self {
+            client,
+            next_page_token,
+            page_size,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = next_page_token {
+            query_args.push(("next_page_token".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_size {
+            query_args.push(("page_size".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/phone/sip_groups?{}", query_), None);
+        let resp: crate::Response<crate::types::ListSipGroupsResponse> = client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await?;
+
+        // Return our response data.
+        Ok(crate::Response::new(
+            resp.status,
+            resp.headers,
+            resp.body.sip_groups.to_vec(),
+        ))
+    }
+}
+
+/// The parameters of [`Phone::get_setting_template`], set with a builder.
+pub struct PhoneGetSettingTemplateRequest<'a> {
+    client: &'a Phone,
+    template_id: &'a str,
+    custom_query_fields: Option<&'a str>,
+}
+
+impl<'a> PhoneGetSettingTemplateRequest<'a> {
+    /// Sets the `custom_query_fields` query parameter.
+    pub fn custom_query_fields(mut self, custom_query_fields: &'a str) -> Self {
+        self.custom_query_fields = Some(custom_query_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::GetSettingTemplateResponse>> {
+        let Self {
+            client,
+            template_id,
+            custom_query_fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = custom_query_fields {
+            query_args.push(("custom_query_fields".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/phone/setting_templates/{}?{}",
+                crate::progenitor_support::encode_path(template_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Phone::user_call_logs`], set with a builder.
+pub struct PhoneUserCallLogsRequest<'a> {
+    client: &'a Phone,
+    user_id: &'a str,
+    page_size: Option<i64>,
+    from: Option<chrono::NaiveDate>,
+    to: Option<chrono::NaiveDate>,
+    type_: Option<crate::types::PhoneUserCallLogsType>,
+    next_page_token: Option<&'a str>,
+    phone_number: Option<&'a str>,
+    time_type: Option<crate::types::TimeType>,
+}
+
+impl<'a> PhoneUserCallLogsRequest<'a> {
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Sets the `from` query parameter.
+    pub fn from(mut self, from: chrono::NaiveDate) -> Self {
+        self.from = Some(from);
+        self
+    }
+
+    /// Sets the `to` query parameter.
+    pub fn to(mut self, to: chrono::NaiveDate) -> Self {
+        self.to = Some(to);
+        self
+    }
+
+    /// Sets the `type` query parameter.
+    pub fn type_(mut self, type_: crate::types::PhoneUserCallLogsType) -> Self {
+        self.type_ = Some(type_);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.next_page_token = Some(next_page_token);
+        self
+    }
+
+    /// Sets the `phone_number` query parameter.
+    pub fn phone_number(mut self, phone_number: &'a str) -> Self {
+        self.phone_number = Some(phone_number);
+        self
+    }
+
+    /// Sets the `time_type` query parameter.
+    pub fn time_type(mut self, time_type: crate::types::TimeType) -> Self {
+        self.time_type = Some(time_type);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::CallLogs>>> {
+        let Self {
+            client,
+            user_id,
+            page_size,
+            from,
+            to,
+            type_,
+            next_page_token,
+            phone_number,
+            time_type,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = from {
+            query_args.push(("from".to_string(), v.to_string()));
+        }
+        if let Some(v) = next_page_token {
+            query_args.push(("next_page_token".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_size {
+            query_args.push(("page_size".to_string(), v.to_string()));
+        }
+        if let Some(v) = phone_number {
+            query_args.push(("phone_number".to_string(), v.to_string()));
+        }
+        if let Some(v) = time_type {
+            query_args.push(("time_type".to_string(), v.to_string()));
+        }
+        if let Some(v) = to {
+            query_args.push(("to".to_string(), v.to_string()));
+        }
+        if let Some(v) = type_ {
+            query_args.push(("type".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/phone/users/{}/call_logs?{}",
+                crate::progenitor_support::encode_path(user_id),
+                query_
+            ),
+            None,
+        );
+        let resp: crate::Response<crate::types::PhoneUserCallLogsResponse> = client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await?;
+
+        // Return our response data.
+        Ok(crate::Response::new(
+            resp.status,
+            resp.headers,
+            resp.body.call_logs.to_vec(),
+        ))
+    }
+}
+
+/// The parameters of [`Phone::user_recordings`], set with a builder.
+pub struct PhoneUserRecordingsRequest<'a> {
+    client: &'a Phone,
+    user_id: &'a str,
+    page_size: Option<i64>,
+    next_page_token: Option<&'a str>,
+    from: Option<chrono::NaiveDate>,
+    to: Option<chrono::NaiveDate>,
+}
+
+impl<'a> PhoneUserRecordingsRequest<'a> {
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.next_page_token = Some(next_page_token);
+        self
+    }
+
+    /// Sets the `from` query parameter.
+    pub fn from(mut self, from: chrono::NaiveDate) -> Self {
+        self.from = Some(from);
+        self
+    }
+
+    /// Sets the `to` query parameter.
+    pub fn to(mut self, to: chrono::NaiveDate) -> Self {
+        self.to = Some(to);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Recordings>>> {
+        let Self {
+            client,
+            user_id,
+            page_size,
+            next_page_token,
+            from,
+            to,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = from {
+            query_args.push(("from".to_string(), v.to_string()));
+        }
+        if let Some(v) = next_page_token {
+            query_args.push(("next_page_token".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_size {
+            query_args.push(("page_size".to_string(), v.to_string()));
+        }
+        if let Some(v) = to {
+            query_args.push(("to".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/phone/users/{}/recordings?{}",
+                crate::progenitor_support::encode_path(user_id),
+                query_
+            ),
+            None,
+        );
+        let resp: crate::Response<crate::types::PhoneUserRecordingsResponse> = client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await?;
+
+        // Return our response data.
+        Ok(crate::Response::new(
+            resp.status,
+            resp.headers,
+            resp.body.recordings.to_vec(),
+        ))
+    }
+}
+
+/// The parameters of [`Phone::user_voice_mails`], set with a builder.
+pub struct PhoneUserVoiceMailsRequest<'a> {
+    client: &'a Phone,
+    user_id: &'a str,
+    page_size: Option<i64>,
+    status: Option<crate::types::PhoneUserVoiceMailsStatus>,
+    next_page_token: Option<&'a str>,
+    from: Option<chrono::NaiveDate>,
+    to: Option<chrono::NaiveDate>,
+}
+
+impl<'a> PhoneUserVoiceMailsRequest<'a> {
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: crate::types::PhoneUserVoiceMailsStatus) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.next_page_token = Some(next_page_token);
+        self
+    }
+
+    /// Sets the `from` query parameter.
+    pub fn from(mut self, from: chrono::NaiveDate) -> Self {
+        self.from = Some(from);
+        self
+    }
+
+    /// Sets the `to` query parameter.
+    pub fn to(mut self, to: chrono::NaiveDate) -> Self {
+        self.to = Some(to);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::VoiceMails>>> {
+        let Self {
+            client,
+            user_id,
+            page_size,
+            status,
+            next_page_token,
+            from,
+            to,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = from {
+            query_args.push(("from".to_string(), v.to_string()));
+        }
+        if let Some(v) = next_page_token {
+            query_args.push(("next_page_token".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_size {
+            query_args.push(("page_size".to_string(), v.to_string()));
+        }
+        if let Some(v) = status {
+            query_args.push(("status".to_string(), v.to_string()));
+        }
+        if let Some(v) = to {
+            query_args.push(("to".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/phone/users/{}/voice_mails?{}",
+                crate::progenitor_support::encode_path(user_id),
+                query_
+            ),
+            None,
+        );
+        let resp: crate::Response<crate::types::PhoneUserVoiceMailsResponse> = client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await?;
+
+        // Return our response data.
+        Ok(crate::Response::new(
+            resp.status,
+            resp.headers,
+            resp.body.voice_mails.to_vec(),
+        ))
+    }
+}
+
+/// The parameters of [`Phone::delete_user_setting`], set with a builder.
+pub struct PhoneDeleteUserSettingRequest<'a> {
+    client: &'a Phone,
+    user_id: &'a str,
+    setting_type: &'a str,
+    shared_id: Option<&'a str>,
+}
+
+impl<'a> PhoneDeleteUserSettingRequest<'a> {
+    /// Sets the `shared_id` query parameter.
+    pub fn shared_id(mut self, shared_id: &'a str) -> Self {
+        self.shared_id = Some(shared_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            user_id,
+            setting_type,
+            shared_id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = shared_id {
+            query_args.push(("shared_id".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/phone/users/{}/settings/{}?{}",
+                crate::progenitor_support::encode_path(user_id),
+                crate::progenitor_support::encode_path(setting_type),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .delete(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Phone::account_call_logs`], set with a builder.
+pub struct PhoneAccountCallLogsRequest<'a> {
+    client: &'a Phone,
+    page_size: Option<i64>,
+    from: Option<&'a str>,
+    to: Option<&'a str>,
+    type_: Option<&'a str>,
+    next_page_token: Option<&'a str>,
+    path: Option<&'a str>,
+    time_type: Option<crate::types::TimeType>,
+    site_id: Option<&'a str>,
+}
+
+impl<'a> PhoneAccountCallLogsRequest<'a> {
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Sets the `from` query parameter.
+    pub fn from(mut self, from: &'a str) -> Self {
+        self.from = Some(from);
+        self
+    }
+
+    /// Sets the `to` query parameter.
+    pub fn to(mut self, to: &'a str) -> Self {
+        self.to = Some(to);
+        self
+    }
+
+    /// Sets the `type` query parameter.
+    pub fn type_(mut self, type_: &'a str) -> Self {
+        self.type_ = Some(type_);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.next_page_token = Some(next_page_token);
+        self
+    }
+
+    /// Sets the `path` query parameter.
+    pub fn path(mut self, path: &'a str) -> Self {
+        self.path = Some(path);
+        self
+    }
+
+    /// Sets the `time_type` query parameter.
+    pub fn time_type(mut self, time_type: crate::types::TimeType) -> Self {
+        self.time_type = Some(time_type);
+        self
+    }
+
+    /// Sets the `site_id` query parameter.
+    pub fn site_id(mut self, site_id: &'a str) -> Self {
+        self.site_id = Some(site_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::AccountCallLogsResponse>>> {
+        let Self {
+            client,
+            page_size,
+            from,
+            to,
+            type_,
+            next_page_token,
+            path,
+            time_type,
+            site_id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = from {
+            query_args.push(("from".to_string(), v.to_string()));
+        }
+        if let Some(v) = next_page_token {
+            query_args.push(("next_page_token".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_size {
+            query_args.push(("page_size".to_string(), v.to_string()));
+        }
+        if let Some(v) = path {
+            query_args.push(("path".to_string(), v.to_string()));
+        }
+        if let Some(v) = site_id {
+            query_args.push(("site_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = time_type {
+            query_args.push(("time_type".to_string(), v.to_string()));
+        }
+        if let Some(v) = to {
+            query_args.push(("to".to_string(), v.to_string()));
+        }
+        if let Some(v) = type_ {
+            query_args.push(("type".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/phone/call_logs?{}", query_), None);
+        let resp: crate::Response<crate::types::AccountCallLogsResponseData> = client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await?;
+
+        // Return our response data.
+        Ok(crate::Response::new(
+            resp.status,
+            resp.headers,
+            resp.body.call_logs.to_vec(),
+        ))
+    }
+}
+
+/// The parameters of [`Phone::get_recordings`], set with a builder.
+pub struct PhoneGetRecordingsRequest<'a> {
+    client: &'a Phone,
+    page_size: Option<i64>,
+    next_page_token: Option<&'a str>,
+    from: Option<&'a str>,
+    to: Option<&'a str>,
+    owner_type: Option<&'a str>,
+    recording_type: Option<&'a str>,
+    site_id: Option<&'a str>,
+    query_date_type: Option<crate::types::QueryDateType>,
+}
+
+impl<'a> PhoneGetRecordingsRequest<'a> {
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.next_page_token = Some(next_page_token);
+        self
+    }
+
+    /// Sets the `from` query parameter.
+    pub fn from(mut self, from: &'a str) -> Self {
+        self.from = Some(from);
+        self
+    }
+
+    /// Sets the `to` query parameter.
+    pub fn to(mut self, to: &'a str) -> Self {
+        self.to = Some(to);
+        self
+    }
+
+    /// Sets the `owner_type` query parameter.
+    pub fn owner_type(mut self, owner_type: &'a str) -> Self {
+        self.owner_type = Some(owner_type);
+        self
+    }
+
+    /// Sets the `recording_type` query parameter.
+    pub fn recording_type(mut self, recording_type: &'a str) -> Self {
+        self.recording_type = Some(recording_type);
+        self
+    }
+
+    /// Sets the `site_id` query parameter.
+    pub fn site_id(mut self, site_id: &'a str) -> Self {
+        self.site_id = Some(site_id);
+        self
+    }
+
+    /// Sets the `query_date_type` query parameter.
+    pub fn query_date_type(mut self, query_date_type: crate::types::QueryDateType) -> Self {
+        self.query_date_type = Some(query_date_type);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GetPhoneRecordingsResponse>>> {
+        let Self {
+            client,
+            page_size,
+            next_page_token,
+            from,
+            to,
+            owner_type,
+            recording_type,
+            site_id,
+            query_date_type,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = from {
+            query_args.push(("from".to_string(), v.to_string()));
+        }
+        if let Some(v) = next_page_token {
+            query_args.push(("next_page_token".to_string(), v.to_string()));
+        }
+        if let Some(v) = owner_type {
+            query_args.push(("owner_type".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_size {
+            query_args.push(("page_size".to_string(), v.to_string()));
+        }
+        if let Some(v) = query_date_type {
+            query_args.push(("query_date_type".to_string(), v.to_string()));
+        }
+        if let Some(v) = recording_type {
+            query_args.push(("recording_type".to_string(), v.to_string()));
+        }
+        if let Some(v) = site_id {
+            query_args.push(("site_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = to {
+            query_args.push(("to".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/phone/recordings?{}", query_), None);
+        let resp: crate::Response<crate::types::GetPhoneRecordingsResponseData> = client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await?;
+
+        // Return our response data.
+        Ok(crate::Response::new(
+            resp.status,
+            resp.headers,
+            resp.body.recordings.to_vec(),
+        ))
+    }
+}
+
+/// The parameters of [`Phone::list_byocsip_trunk`], set with a builder.
+pub struct PhoneListByocsipTrunkRequest<'a> {
+    client: &'a Phone,
+    next_page_token: Option<&'a str>,
+    page_size: Option<i64>,
+}
+
+impl<'a> PhoneListByocsipTrunkRequest<'a> {
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.next_page_token = Some(next_page_token);
+        self
+    }
+
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::ByocSipTrunk>>> {
+        let Self {
+            client,
+            next_page_token,
+            page_size,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = next_page_token {
+            query_args.push(("next_page_token".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_size {
+            query_args.push(("page_size".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/phone/sip_trunk/trunks?{}", query_), None);
+        let resp: crate::Response<crate::types::ListByocsipTrunkResponse> = client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await?;
+
+        // Return our response data.
+        Ok(crate::Response::new(
+            resp.status,
+            resp.headers,
+            resp.body.byoc_sip_trunk.to_vec(),
+        ))
+    }
+}
+
+/// The parameters of [`Phone::list_external_contacts`], set with a builder.
+pub struct PhoneListExternalContactsRequest<'a> {
+    client: &'a Phone,
+    next_page_token: Option<&'a str>,
+    page_size: Option<i64>,
+}
+
+impl<'a> PhoneListExternalContactsRequest<'a> {
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.next_page_token = Some(next_page_token);
+        self
+    }
+
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::ExternalContacts>>> {
+        let Self {
+            client,
+            next_page_token,
+            page_size,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = next_page_token {
+            query_args.push(("next_page_token".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_size {
+            query_args.push(("page_size".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/phone/external_contacts?{}", query_), None);
+        let resp: crate::Response<crate::types::ListExternalContactsResponse> = client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await?;
+
+        // Return our response data.
+        Ok(crate::Response::new(
+            resp.status,
+            resp.headers,
+            resp.body.external_contacts.to_vec(),
+        ))
+    }
+}
+
+/// The parameters of [`Phone::list_users`], set with a builder.
+pub struct PhoneListUsersRequest<'a> {
+    client: &'a Phone,
+    page_size: Option<i64>,
+    next_page_token: Option<&'a str>,
+    site_id: Option<&'a str>,
+}
+
+impl<'a> PhoneListUsersRequest<'a> {
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.next_page_token = Some(next_page_token);
+        self
+    }
+
+    /// Sets the `site_id` query parameter.
+    pub fn site_id(mut self, site_id: &'a str) -> Self {
+        self.site_id = Some(site_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ListPhoneUsersResponse>>> {
+        let Self {
+            client,
+            page_size,
+            next_page_token,
+            site_id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = next_page_token {
+            query_args.push(("next_page_token".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_size {
+            query_args.push(("page_size".to_string(), v.to_string()));
+        }
+        if let Some(v) = site_id {
+            query_args.push(("site_id".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(&format!("/phone/users?{}", query_), None);
+        let resp: crate::Response<crate::types::ListPhoneUsersResponseData> = client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await?;
+
+        // Return our response data.
+        Ok(crate::Response::new(
+            resp.status,
+            resp.headers,
+            resp.body.users.to_vec(),
+        ))
+    }
+}
+
 pub struct Phone {
     pub client: Client,
 }
@@ -127,9 +1270,129 @@ impl Phone {
 
         // Return our response data.
         Ok(crate::Response::new(
-            resp.status,
-            resp.headers,
-            resp.body.phone_numbers.to_vec(),
+            resp.status,
+            resp.headers,
+            resp.body.phone_numbers.to_vec(),
+        ))
+    }
+    /**
+     * List phone numbers.
+     *
+     * This function performs a `GET` to the `/phone/numbers` endpoint.
+     *
+     * As opposed to `list_account_numbers`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Use this API to list all Zoom Phone numbers in a Zoom account.
+     *
+     * **Scopes:** `phone:read:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     *
+     * **Prerequisites:**
+     * * A Pro or higher account plan
+     * * A Zoom Phone license
+     */
+    pub fn list_account_numbers_request(&self) -> PhoneListAccountNumbersRequest<'_> {
+        PhoneListAccountNumbersRequest {
+            client: self,
+            next_page_token: None,
+            type_: None,
+            extension_type: None,
+            page_size: None,
+            number_type: None,
+            pending_numbers: None,
+            site_id: None,
+        }
+    }
+    /**
+     * List phone numbers.
+     *
+     * This function performs a `GET` to the `/phone/numbers` endpoint.
+     *
+     * As opposed to `list_account_numbers`, this function returns all the pages of the request at once.
+     *
+     * Use this API to list all Zoom Phone numbers in a Zoom account.
+     *
+     * **Scopes:** `phone:read:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     *
+     * **Prerequisites:**
+     * * A Pro or higher account plan
+     * * A Zoom Phone license
+     */
+    pub async fn list_all_account_numbers(
+        &self,
+        type_: crate::types::ListAccountPhoneNumbersType,
+        extension_type: crate::types::ExtensionType,
+        number_type: crate::types::Type,
+        pending_numbers: bool,
+        site_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ListAccountPhoneNumbersResponse>>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !extension_type.to_string().is_empty() {
+            query_args.push(("extension_type".to_string(), extension_type.to_string()));
+        }
+        if !number_type.to_string().is_empty() {
+            query_args.push(("number_type".to_string(), number_type.to_string()));
+        }
+        if pending_numbers {
+            query_args.push(("pending_numbers".to_string(), pending_numbers.to_string()));
+        }
+        if !site_id.is_empty() {
+            query_args.push(("site_id".to_string(), site_id.to_string()));
+        }
+        if !type_.to_string().is_empty() {
+            query_args.push(("type".to_string(), type_.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/phone/numbers?{}", query_), None);
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let mut pages = crate::utils::Pages::new(self.client.page_limits);
+        pages.visit(&url)?;
+
+        let mut response: crate::Response<crate::types::ListAccountPhoneNumbersResponseData> = self
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await?;
+        let mut phone_numbers = Vec::new();
+
+        // Paginate if we should.
+        loop {
+            let page = if response.body.next_page_token.is_empty() {
+                None
+            } else {
+                Some(format!(
+                    "{}{}next_page_token={}",
+                    url, sep, response.body.next_page_token
+                ))
+            };
+            let more = pages.keep(&mut response.body.phone_numbers);
+            phone_numbers.append(&mut response.body.phone_numbers);
+
+            let next = match page {
+                Some(next) if more && pages.visit(&next)? => next,
+                _ => break,
+            };
+            response = self
+                .client
+                .get(
+                    &next,
+                    crate::Message {
+                        body: None,
+                        content_type: None,
+                    },
+                )
+                .await?;
+        }
+
+        // Return our response data.
+        Ok(crate::Response::new(
+            response.status,
+            response.headers,
+            phone_numbers,
         ))
     }
     /**
@@ -137,7 +1400,7 @@ impl Phone {
      *
      * This function performs a `GET` to the `/phone/numbers` endpoint.
      *
-     * As opposed to `list_account_numbers`, this function returns all the pages of the request at once.
+     * As opposed to `list_all_account_numbers`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
      *
      * Use this API to list all Zoom Phone numbers in a Zoom account.
      *
@@ -147,14 +1410,16 @@ impl Phone {
      * * A Pro or higher account plan
      * * A Zoom Phone license
      */
-    pub async fn list_all_account_numbers(
+    pub fn list_all_account_numbers_stream(
         &self,
         type_: crate::types::ListAccountPhoneNumbersType,
         extension_type: crate::types::ExtensionType,
         number_type: crate::types::Type,
         pending_numbers: bool,
         site_id: &str,
-    ) -> ClientResult<crate::Response<Vec<crate::types::ListAccountPhoneNumbersResponse>>> {
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::ListAccountPhoneNumbersResponse>>
+           + Unpin
+           + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !extension_type.to_string().is_empty() {
             query_args.push(("extension_type".to_string(), extension_type.to_string()));
@@ -173,70 +1438,20 @@ impl Phone {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/phone/numbers?{}", query_), None);
-        let crate::Response::<crate::types::ListAccountPhoneNumbersResponseData> {
-            mut status,
-            mut headers,
-            mut body,
-        } = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut phone_numbers = body.phone_numbers;
-        let mut page = body.next_page_token;
-
-        // Paginate if we should.
-        while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                crate::Response::<crate::types::ListAccountPhoneNumbersResponseData> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}?next_page_token={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            } else {
-                crate::Response::<crate::types::ListAccountPhoneNumbersResponseData> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}&next_page_token={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
-
-            phone_numbers.append(&mut body.phone_numbers);
-
-            if !body.next_page_token.is_empty() && body.next_page_token != page {
-                page = body.next_page_token.to_string();
-            } else {
-                page = "".to_string();
-            }
-        }
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let next = format!("{}{}next_page_token=", url, sep);
+        self.client.get_pages_stream(
+            url,
+            move |body: crate::types::ListAccountPhoneNumbersResponseData| {
+                let page = if body.next_page_token.is_empty() {
+                    None
+                } else {
+                    Some(format!("{}{}", next, body.next_page_token))
+                };
 
-        // Return our response data.
-        Ok(crate::Response::new(status, headers, phone_numbers))
+                (body.phone_numbers, page)
+            },
+        )
     }
     /**
      * Get user's profile.
@@ -466,6 +1681,29 @@ impl Phone {
             resp.body.templates.to_vec(),
         ))
     }
+    /**
+     * List setting templates.
+     *
+     * This function performs a `GET` to the `/phone/setting_templates` endpoint.
+     *
+     * As opposed to `list_setting_templates`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Use this API to get a list of all the created phone template settings.
+     *
+     * **Scopes:** `phone:read:admin` or `phone:read`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Light`
+     *
+     * **Prerequisites:**
+     * * A Business or Enterprise account
+     * * A Zoom Phone license
+     */
+    pub fn list_setting_templates_request(&self) -> PhoneListSettingTemplatesRequest<'_> {
+        PhoneListSettingTemplatesRequest {
+            client: self,
+            page_size: None,
+            next_page_token: None,
+            site_id: None,
+        }
+    }
     /**
      * List setting templates.
      *
@@ -493,11 +1731,11 @@ impl Phone {
         let url = self
             .client
             .url(&format!("/phone/setting_templates?{}", query_), None);
-        let crate::Response::<crate::types::ListSettingTemplatesResponse> {
-            mut status,
-            mut headers,
-            mut body,
-        } = self
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let mut pages = crate::utils::Pages::new(self.client.page_limits);
+        pages.visit(&url)?;
+
+        let mut response: crate::Response<crate::types::ListSettingTemplatesResponse> = self
             .client
             .get(
                 &url,
@@ -507,56 +1745,85 @@ impl Phone {
                 },
             )
             .await?;
-
-        let mut templates = body.templates;
-        let mut page = body.next_page_token;
+        let mut templates = Vec::new();
 
         // Paginate if we should.
-        while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                crate::Response::<crate::types::ListSettingTemplatesResponse> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}?next_page_token={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            } else {
-                crate::Response::<crate::types::ListSettingTemplatesResponse> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}&next_page_token={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
-
-            templates.append(&mut body.templates);
-
-            if !body.next_page_token.is_empty() && body.next_page_token != page {
-                page = body.next_page_token.to_string();
+        loop {
+            let page = if response.body.next_page_token.is_empty() {
+                None
             } else {
-                page = "".to_string();
-            }
+                Some(format!(
+                    "{}{}next_page_token={}",
+                    url, sep, response.body.next_page_token
+                ))
+            };
+            let more = pages.keep(&mut response.body.templates);
+            templates.append(&mut response.body.templates);
+
+            let next = match page {
+                Some(next) if more && pages.visit(&next)? => next,
+                _ => break,
+            };
+            response = self
+                .client
+                .get(
+                    &next,
+                    crate::Message {
+                        body: None,
+                        content_type: None,
+                    },
+                )
+                .await?;
         }
 
         // Return our response data.
-        Ok(crate::Response::new(status, headers, templates))
+        Ok(crate::Response::new(
+            response.status,
+            response.headers,
+            templates,
+        ))
+    }
+    /**
+     * List setting templates.
+     *
+     * This function performs a `GET` to the `/phone/setting_templates` endpoint.
+     *
+     * As opposed to `list_all_setting_templates`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Use this API to get a list of all the created phone template settings.
+     *
+     * **Scopes:** `phone:read:admin` or `phone:read`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Light`
+     *
+     * **Prerequisites:**
+     * * A Business or Enterprise account
+     * * A Zoom Phone license
+     */
+    pub fn list_all_setting_templates_stream(
+        &self,
+        site_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Templates>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !site_id.is_empty() {
+            query_args.push(("site_id".to_string(), site_id.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/phone/setting_templates?{}", query_), None);
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let next = format!("{}{}next_page_token=", url, sep);
+        self.client.get_pages_stream(
+            url,
+            move |body: crate::types::ListSettingTemplatesResponse| {
+                let page = if body.next_page_token.is_empty() {
+                    None
+                } else {
+                    Some(format!("{}{}", next, body.next_page_token))
+                };
+
+                (body.templates, page)
+            },
+        )
     }
     /**
      * Add a setting template.
@@ -660,6 +1927,28 @@ impl Phone {
             resp.body.locations.to_vec(),
         ))
     }
+    /**
+     * List emergency service locations.
+     *
+     * This function performs a `GET` to the `/phone/locations` endpoint.
+     *
+     * As opposed to `list_locations`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Use this API to list emergency service locations.
+     *
+     * **Scopes:** `phone:read:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Light`
+     *
+     * **Prerequisites:**
+     * * Pro or a higher account with Zoom Phone license
+     * * Account owner or admin permissions
+     */
+    pub fn list_locations_request(&self) -> PhoneListLocationsRequest<'_> {
+        PhoneListLocationsRequest {
+            client: self,
+            next_page_token: None,
+            page_size: None,
+        }
+    }
     /**
      * List emergency service locations.
      *
@@ -679,11 +1968,11 @@ impl Phone {
         &self,
     ) -> ClientResult<crate::Response<Vec<crate::types::ListLocationsResponse>>> {
         let url = self.client.url("/phone/locations", None);
-        let crate::Response::<crate::types::ListLocationsResponseData> {
-            mut status,
-            mut headers,
-            mut body,
-        } = self
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let mut pages = crate::utils::Pages::new(self.client.page_limits);
+        pages.visit(&url)?;
+
+        let mut response: crate::Response<crate::types::ListLocationsResponseData> = self
             .client
             .get(
                 &url,
@@ -693,56 +1982,76 @@ impl Phone {
                 },
             )
             .await?;
-
-        let mut locations = body.locations;
-        let mut page = body.next_page_token;
+        let mut locations = Vec::new();
 
         // Paginate if we should.
-        while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                crate::Response::<crate::types::ListLocationsResponseData> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}?next_page_token={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            } else {
-                crate::Response::<crate::types::ListLocationsResponseData> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}&next_page_token={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
-
-            locations.append(&mut body.locations);
-
-            if !body.next_page_token.is_empty() && body.next_page_token != page {
-                page = body.next_page_token.to_string();
+        loop {
+            let page = if response.body.next_page_token.is_empty() {
+                None
             } else {
-                page = "".to_string();
-            }
+                Some(format!(
+                    "{}{}next_page_token={}",
+                    url, sep, response.body.next_page_token
+                ))
+            };
+            let more = pages.keep(&mut response.body.locations);
+            locations.append(&mut response.body.locations);
+
+            let next = match page {
+                Some(next) if more && pages.visit(&next)? => next,
+                _ => break,
+            };
+            response = self
+                .client
+                .get(
+                    &next,
+                    crate::Message {
+                        body: None,
+                        content_type: None,
+                    },
+                )
+                .await?;
         }
 
         // Return our response data.
-        Ok(crate::Response::new(status, headers, locations))
+        Ok(crate::Response::new(
+            response.status,
+            response.headers,
+            locations,
+        ))
+    }
+    /**
+     * List emergency service locations.
+     *
+     * This function performs a `GET` to the `/phone/locations` endpoint.
+     *
+     * As opposed to `list_all_locations`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Use this API to list emergency service locations.
+     *
+     * **Scopes:** `phone:read:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Light`
+     *
+     * **Prerequisites:**
+     * * Pro or a higher account with Zoom Phone license
+     * * Account owner or admin permissions
+     */
+    pub fn list_all_locations_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::ListLocationsResponse>> + Unpin + '_
+    {
+        let url = self.client.url("/phone/locations", None);
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let next = format!("{}{}next_page_token=", url, sep);
+        self.client
+            .get_pages_stream(url, move |body: crate::types::ListLocationsResponseData| {
+                let page = if body.next_page_token.is_empty() {
+                    None
+                } else {
+                    Some(format!("{}{}", next, body.next_page_token))
+                };
+
+                (body.locations, page)
+            })
     }
     /**
      * Add emergency service location.
@@ -932,6 +2241,28 @@ impl Phone {
             resp.body.sip_groups.to_vec(),
         ))
     }
+    /**
+     * List SIP groups.
+     *
+     * This function performs a `GET` to the `/phone/sip_groups` endpoint.
+     *
+     * As opposed to `list_sip_groups`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Use this API to list SIP (Session Initiation Protocol) groups.
+     *
+     * **Scopes:** `phone:read:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Light`
+     *
+     * **Prerequisites:**
+     * * Pro or a higher account with Zoom Phone license
+     * * Account owner or admin permissions
+     */
+    pub fn list_sip_groups_request(&self) -> PhoneListSipGroupsRequest<'_> {
+        PhoneListSipGroupsRequest {
+            client: self,
+            next_page_token: None,
+            page_size: None,
+        }
+    }
     /**
      * List SIP groups.
      *
@@ -951,11 +2282,11 @@ impl Phone {
         &self,
     ) -> ClientResult<crate::Response<Vec<crate::types::SipGroups>>> {
         let url = self.client.url("/phone/sip_groups", None);
-        let crate::Response::<crate::types::ListSipGroupsResponse> {
-            mut status,
-            mut headers,
-            mut body,
-        } = self
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let mut pages = crate::utils::Pages::new(self.client.page_limits);
+        pages.visit(&url)?;
+
+        let mut response: crate::Response<crate::types::ListSipGroupsResponse> = self
             .client
             .get(
                 &url,
@@ -965,56 +2296,75 @@ impl Phone {
                 },
             )
             .await?;
-
-        let mut sip_groups = body.sip_groups;
-        let mut page = body.next_page_token;
+        let mut sip_groups = Vec::new();
 
         // Paginate if we should.
-        while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                crate::Response::<crate::types::ListSipGroupsResponse> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}?next_page_token={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
+        loop {
+            let page = if response.body.next_page_token.is_empty() {
+                None
             } else {
-                crate::Response::<crate::types::ListSipGroupsResponse> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}&next_page_token={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
-
-            sip_groups.append(&mut body.sip_groups);
-
-            if !body.next_page_token.is_empty() && body.next_page_token != page {
-                page = body.next_page_token.to_string();
-            } else {
-                page = "".to_string();
-            }
+                Some(format!(
+                    "{}{}next_page_token={}",
+                    url, sep, response.body.next_page_token
+                ))
+            };
+            let more = pages.keep(&mut response.body.sip_groups);
+            sip_groups.append(&mut response.body.sip_groups);
+
+            let next = match page {
+                Some(next) if more && pages.visit(&next)? => next,
+                _ => break,
+            };
+            response = self
+                .client
+                .get(
+                    &next,
+                    crate::Message {
+                        body: None,
+                        content_type: None,
+                    },
+                )
+                .await?;
         }
 
         // Return our response data.
-        Ok(crate::Response::new(status, headers, sip_groups))
+        Ok(crate::Response::new(
+            response.status,
+            response.headers,
+            sip_groups,
+        ))
+    }
+    /**
+     * List SIP groups.
+     *
+     * This function performs a `GET` to the `/phone/sip_groups` endpoint.
+     *
+     * As opposed to `list_all_sip_groups`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Use this API to list SIP (Session Initiation Protocol) groups.
+     *
+     * **Scopes:** `phone:read:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Light`
+     *
+     * **Prerequisites:**
+     * * Pro or a higher account with Zoom Phone license
+     * * Account owner or admin permissions
+     */
+    pub fn list_all_sip_groups_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::SipGroups>> + Unpin + '_ {
+        let url = self.client.url("/phone/sip_groups", None);
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let next = format!("{}{}next_page_token=", url, sep);
+        self.client
+            .get_pages_stream(url, move |body: crate::types::ListSipGroupsResponse| {
+                let page = if body.next_page_token.is_empty() {
+                    None
+                } else {
+                    Some(format!("{}{}", next, body.next_page_token))
+                };
+
+                (body.sip_groups, page)
+            })
     }
     /**
      * Get setting template details.
@@ -1065,6 +2415,31 @@ impl Phone {
             )
             .await
     }
+    /**
+     * Get setting template details.
+     *
+     * This function performs a `GET` to the `/phone/setting_templates/{templateId}` endpoint.
+     *
+     * As opposed to `get_setting_template`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Use this API to return information about an account's phone template.
+     *
+     * **Scopes:** `phone:write:admin` or `phone:write`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Light`
+     *
+     * **Prerequisites:**
+     * * A Business or Enterprise account
+     * * A Zoom Phone license
+     */
+    pub fn get_setting_template_request<'a>(
+        &'a self,
+        template_id: &'a str,
+    ) -> PhoneGetSettingTemplateRequest<'a> {
+        PhoneGetSettingTemplateRequest {
+            client: self,
+            template_id,
+            custom_query_fields: None,
+        }
+    }
     /**
      * Update a setting template.
      *
@@ -1193,7 +2568,136 @@ impl Phone {
      *
      * This function performs a `GET` to the `/phone/users/{userId}/call_logs` endpoint.
      *
-     * As opposed to `user_call_logs`, this function returns all the pages of the request at once.
+     * As opposed to `user_call_logs`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Use this API to get a user's [Zoom phone](https://support.zoom.us/hc/en-us/articles/360001297663-Quickstart-Guide-for-Zoom-Phone-Administrators) call logs. For user-level apps, pass [the `me` value](https://marketplace.zoom.us/docs/api-reference/using-zoom-apis#mekeyword) instead of the `userId` parameter.
+     *
+     * **Scopes:** `phone:read`, `phone:read:admin`, `phone_call_log:read`, `phone_call_log:read:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Heavy`
+     *
+     * **Prerequisites:**
+     * * A Business or Enterprise account
+     * * A Zoom Phone license
+     */
+    pub fn user_call_logs_request<'a>(&'a self, user_id: &'a str) -> PhoneUserCallLogsRequest<'a> {
+        PhoneUserCallLogsRequest {
+            client: self,
+            user_id,
+            page_size: None,
+            from: None,
+            to: None,
+            type_: None,
+            next_page_token: None,
+            phone_number: None,
+            time_type: None,
+        }
+    }
+    /**
+     * Get user's call logs.
+     *
+     * This function performs a `GET` to the `/phone/users/{userId}/call_logs` endpoint.
+     *
+     * As opposed to `user_call_logs`, this function returns all the pages of the request at once.
+     *
+     * Use this API to get a user's [Zoom phone](https://support.zoom.us/hc/en-us/articles/360001297663-Quickstart-Guide-for-Zoom-Phone-Administrators) call logs. For user-level apps, pass [the `me` value](https://marketplace.zoom.us/docs/api-reference/using-zoom-apis#mekeyword) instead of the `userId` parameter.
+     *
+     * **Scopes:** `phone:read`, `phone:read:admin`, `phone_call_log:read`, `phone_call_log:read:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Heavy`
+     *
+     * **Prerequisites:**
+     * * A Business or Enterprise account
+     * * A Zoom Phone license
+     */
+    pub async fn get_all_user_call_logs(
+        &self,
+        user_id: &str,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        type_: crate::types::PhoneUserCallLogsType,
+        phone_number: &str,
+        time_type: crate::types::TimeType,
+    ) -> ClientResult<crate::Response<Vec<crate::types::CallLogs>>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !from.to_string().is_empty() {
+            query_args.push(("from".to_string(), from.to_string()));
+        }
+        if !phone_number.is_empty() {
+            query_args.push(("phone_number".to_string(), phone_number.to_string()));
+        }
+        if !time_type.to_string().is_empty() {
+            query_args.push(("time_type".to_string(), time_type.to_string()));
+        }
+        if !to.to_string().is_empty() {
+            query_args.push(("to".to_string(), to.to_string()));
+        }
+        if !type_.to_string().is_empty() {
+            query_args.push(("type".to_string(), type_.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/phone/users/{}/call_logs?{}",
+                crate::progenitor_support::encode_path(user_id),
+                query_
+            ),
+            None,
+        );
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let mut pages = crate::utils::Pages::new(self.client.page_limits);
+        pages.visit(&url)?;
+
+        let mut response: crate::Response<crate::types::PhoneUserCallLogsResponse> = self
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await?;
+        let mut call_logs = Vec::new();
+
+        // Paginate if we should.
+        loop {
+            let page = if response.body.next_page_token.is_empty() {
+                None
+            } else {
+                Some(format!(
+                    "{}{}next_page_token={}",
+                    url, sep, response.body.next_page_token
+                ))
+            };
+            let more = pages.keep(&mut response.body.call_logs);
+            call_logs.append(&mut response.body.call_logs);
+
+            let next = match page {
+                Some(next) if more && pages.visit(&next)? => next,
+                _ => break,
+            };
+            response = self
+                .client
+                .get(
+                    &next,
+                    crate::Message {
+                        body: None,
+                        content_type: None,
+                    },
+                )
+                .await?;
+        }
+
+        // Return our response data.
+        Ok(crate::Response::new(
+            response.status,
+            response.headers,
+            call_logs,
+        ))
+    }
+    /**
+     * Get user's call logs.
+     *
+     * This function performs a `GET` to the `/phone/users/{userId}/call_logs` endpoint.
+     *
+     * As opposed to `get_all_user_call_logs`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
      *
      * Use this API to get a user's [Zoom phone](https://support.zoom.us/hc/en-us/articles/360001297663-Quickstart-Guide-for-Zoom-Phone-Administrators) call logs. For user-level apps, pass [the `me` value](https://marketplace.zoom.us/docs/api-reference/using-zoom-apis#mekeyword) instead of the `userId` parameter.
      *
@@ -1203,7 +2707,7 @@ impl Phone {
      * * A Business or Enterprise account
      * * A Zoom Phone license
      */
-    pub async fn get_all_user_call_logs(
+    pub fn get_all_user_call_logs_stream(
         &self,
         user_id: &str,
         from: chrono::NaiveDate,
@@ -1211,7 +2715,7 @@ impl Phone {
         type_: crate::types::PhoneUserCallLogsType,
         phone_number: &str,
         time_type: crate::types::TimeType,
-    ) -> ClientResult<crate::Response<Vec<crate::types::CallLogs>>> {
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::CallLogs>> + Unpin + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !from.to_string().is_empty() {
             query_args.push(("from".to_string(), from.to_string()));
@@ -1237,70 +2741,18 @@ impl Phone {
             ),
             None,
         );
-        let crate::Response::<crate::types::PhoneUserCallLogsResponse> {
-            mut status,
-            mut headers,
-            mut body,
-        } = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut call_logs = body.call_logs;
-        let mut page = body.next_page_token;
-
-        // Paginate if we should.
-        while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                crate::Response::<crate::types::PhoneUserCallLogsResponse> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}?next_page_token={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            } else {
-                crate::Response::<crate::types::PhoneUserCallLogsResponse> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}&next_page_token={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
-
-            call_logs.append(&mut body.call_logs);
-
-            if !body.next_page_token.is_empty() && body.next_page_token != page {
-                page = body.next_page_token.to_string();
-            } else {
-                page = "".to_string();
-            }
-        }
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let next = format!("{}{}next_page_token=", url, sep);
+        self.client
+            .get_pages_stream(url, move |body: crate::types::PhoneUserCallLogsResponse| {
+                let page = if body.next_page_token.is_empty() {
+                    None
+                } else {
+                    Some(format!("{}{}", next, body.next_page_token))
+                };
 
-        // Return our response data.
-        Ok(crate::Response::new(status, headers, call_logs))
+                (body.call_logs, page)
+            })
     }
     /**
      * Get user's recordings.
@@ -1371,6 +2823,34 @@ impl Phone {
             resp.body.recordings.to_vec(),
         ))
     }
+    /**
+     * Get user's recordings.
+     *
+     * This function performs a `GET` to the `/phone/users/{userId}/recordings` endpoint.
+     *
+     * As opposed to `user_recordings`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Use this API to get a user's [Zoom Phone recordings](https://support.zoom.us/hc/en-us/articles/360021336671-Viewing-Call-History-and-Recordings). For user-level apps, pass [the `me` value](https://marketplace.zoom.us/docs/api-reference/using-zoom-apis#mekeyword) instead of the `userId` parameter.
+     *
+     * **Scopes:** `phone:read`, `phone:read:admin`, `phone_recording:read`, `phone_recording:read:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     *
+     * **Prerequisites:**
+     * * A Business or Enterprise account
+     * * A Zoom Phone license
+     */
+    pub fn user_recordings_request<'a>(
+        &'a self,
+        user_id: &'a str,
+    ) -> PhoneUserRecordingsRequest<'a> {
+        PhoneUserRecordingsRequest {
+            client: self,
+            user_id,
+            page_size: None,
+            next_page_token: None,
+            from: None,
+            to: None,
+        }
+    }
     /**
      * Get user's recordings.
      *
@@ -1408,11 +2888,11 @@ impl Phone {
             ),
             None,
         );
-        let crate::Response::<crate::types::PhoneUserRecordingsResponse> {
-            mut status,
-            mut headers,
-            mut body,
-        } = self
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let mut pages = crate::utils::Pages::new(self.client.page_limits);
+        pages.visit(&url)?;
+
+        let mut response: crate::Response<crate::types::PhoneUserRecordingsResponse> = self
             .client
             .get(
                 &url,
@@ -1422,56 +2902,95 @@ impl Phone {
                 },
             )
             .await?;
-
-        let mut recordings = body.recordings;
-        let mut page = body.next_page_token;
+        let mut recordings = Vec::new();
 
         // Paginate if we should.
-        while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                crate::Response::<crate::types::PhoneUserRecordingsResponse> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}?next_page_token={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
+        loop {
+            let page = if response.body.next_page_token.is_empty() {
+                None
             } else {
-                crate::Response::<crate::types::PhoneUserRecordingsResponse> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}&next_page_token={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
-
-            recordings.append(&mut body.recordings);
-
-            if !body.next_page_token.is_empty() && body.next_page_token != page {
-                page = body.next_page_token.to_string();
-            } else {
-                page = "".to_string();
-            }
+                Some(format!(
+                    "{}{}next_page_token={}",
+                    url, sep, response.body.next_page_token
+                ))
+            };
+            let more = pages.keep(&mut response.body.recordings);
+            recordings.append(&mut response.body.recordings);
+
+            let next = match page {
+                Some(next) if more && pages.visit(&next)? => next,
+                _ => break,
+            };
+            response = self
+                .client
+                .get(
+                    &next,
+                    crate::Message {
+                        body: None,
+                        content_type: None,
+                    },
+                )
+                .await?;
         }
 
         // Return our response data.
-        Ok(crate::Response::new(status, headers, recordings))
+        Ok(crate::Response::new(
+            response.status,
+            response.headers,
+            recordings,
+        ))
+    }
+    /**
+     * Get user's recordings.
+     *
+     * This function performs a `GET` to the `/phone/users/{userId}/recordings` endpoint.
+     *
+     * As opposed to `get_all_user_recordings`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Use this API to get a user's [Zoom Phone recordings](https://support.zoom.us/hc/en-us/articles/360021336671-Viewing-Call-History-and-Recordings). For user-level apps, pass [the `me` value](https://marketplace.zoom.us/docs/api-reference/using-zoom-apis#mekeyword) instead of the `userId` parameter.
+     *
+     * **Scopes:** `phone:read`, `phone:read:admin`, `phone_recording:read`, `phone_recording:read:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     *
+     * **Prerequisites:**
+     * * A Business or Enterprise account
+     * * A Zoom Phone license
+     */
+    pub fn get_all_user_recordings_stream(
+        &self,
+        user_id: &str,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Recordings>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !from.to_string().is_empty() {
+            query_args.push(("from".to_string(), from.to_string()));
+        }
+        if !to.to_string().is_empty() {
+            query_args.push(("to".to_string(), to.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/phone/users/{}/recordings?{}",
+                crate::progenitor_support::encode_path(user_id),
+                query_
+            ),
+            None,
+        );
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let next = format!("{}{}next_page_token=", url, sep);
+        self.client.get_pages_stream(
+            url,
+            move |body: crate::types::PhoneUserRecordingsResponse| {
+                let page = if body.next_page_token.is_empty() {
+                    None
+                } else {
+                    Some(format!("{}{}", next, body.next_page_token))
+                };
+
+                (body.recordings, page)
+            },
+        )
     }
     /**
      * Get user's voicemails.
@@ -1547,6 +3066,35 @@ impl Phone {
             resp.body.voice_mails.to_vec(),
         ))
     }
+    /**
+     * Get user's voicemails.
+     *
+     * This function performs a `GET` to the `/phone/users/{userId}/voice_mails` endpoint.
+     *
+     * As opposed to `user_voice_mails`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Use this API to get a user's Zoom Phone voicemails. For user-level apps, pass [the `me` value](https://marketplace.zoom.us/docs/api-reference/using-zoom-apis#mekeyword) instead of the `userId` parameter.
+     *
+     * **Scopes:** `phone:read`, `phone:read:admin`, `phone_voicemail:read`, `phone_voicemail:read:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     *
+     * **Prerequisites:**
+     * * A Business or Enterprise account
+     * * A Zoom Phone license
+     */
+    pub fn user_voice_mails_request<'a>(
+        &'a self,
+        user_id: &'a str,
+    ) -> PhoneUserVoiceMailsRequest<'a> {
+        PhoneUserVoiceMailsRequest {
+            client: self,
+            user_id,
+            page_size: None,
+            status: None,
+            next_page_token: None,
+            from: None,
+            to: None,
+        }
+    }
     /**
      * Get user's voicemails.
      *
@@ -1588,11 +3136,11 @@ impl Phone {
             ),
             None,
         );
-        let crate::Response::<crate::types::PhoneUserVoiceMailsResponse> {
-            mut status,
-            mut headers,
-            mut body,
-        } = self
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let mut pages = crate::utils::Pages::new(self.client.page_limits);
+        pages.visit(&url)?;
+
+        let mut response: crate::Response<crate::types::PhoneUserVoiceMailsResponse> = self
             .client
             .get(
                 &url,
@@ -1602,56 +3150,99 @@ impl Phone {
                 },
             )
             .await?;
-
-        let mut voice_mails = body.voice_mails;
-        let mut page = body.next_page_token;
+        let mut voice_mails = Vec::new();
 
         // Paginate if we should.
-        while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                crate::Response::<crate::types::PhoneUserVoiceMailsResponse> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}?next_page_token={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
+        loop {
+            let page = if response.body.next_page_token.is_empty() {
+                None
             } else {
-                crate::Response::<crate::types::PhoneUserVoiceMailsResponse> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}&next_page_token={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
-
-            voice_mails.append(&mut body.voice_mails);
-
-            if !body.next_page_token.is_empty() && body.next_page_token != page {
-                page = body.next_page_token.to_string();
-            } else {
-                page = "".to_string();
-            }
+                Some(format!(
+                    "{}{}next_page_token={}",
+                    url, sep, response.body.next_page_token
+                ))
+            };
+            let more = pages.keep(&mut response.body.voice_mails);
+            voice_mails.append(&mut response.body.voice_mails);
+
+            let next = match page {
+                Some(next) if more && pages.visit(&next)? => next,
+                _ => break,
+            };
+            response = self
+                .client
+                .get(
+                    &next,
+                    crate::Message {
+                        body: None,
+                        content_type: None,
+                    },
+                )
+                .await?;
         }
 
         // Return our response data.
-        Ok(crate::Response::new(status, headers, voice_mails))
+        Ok(crate::Response::new(
+            response.status,
+            response.headers,
+            voice_mails,
+        ))
+    }
+    /**
+     * Get user's voicemails.
+     *
+     * This function performs a `GET` to the `/phone/users/{userId}/voice_mails` endpoint.
+     *
+     * As opposed to `get_all_user_voice_mails`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Use this API to get a user's Zoom Phone voicemails. For user-level apps, pass [the `me` value](https://marketplace.zoom.us/docs/api-reference/using-zoom-apis#mekeyword) instead of the `userId` parameter.
+     *
+     * **Scopes:** `phone:read`, `phone:read:admin`, `phone_voicemail:read`, `phone_voicemail:read:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     *
+     * **Prerequisites:**
+     * * A Business or Enterprise account
+     * * A Zoom Phone license
+     */
+    pub fn get_all_user_voice_mails_stream(
+        &self,
+        user_id: &str,
+        status: crate::types::PhoneUserVoiceMailsStatus,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::VoiceMails>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !from.to_string().is_empty() {
+            query_args.push(("from".to_string(), from.to_string()));
+        }
+        if !status.to_string().is_empty() {
+            query_args.push(("status".to_string(), status.to_string()));
+        }
+        if !to.to_string().is_empty() {
+            query_args.push(("to".to_string(), to.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/phone/users/{}/voice_mails?{}",
+                crate::progenitor_support::encode_path(user_id),
+                query_
+            ),
+            None,
+        );
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let next = format!("{}{}next_page_token=", url, sep);
+        self.client.get_pages_stream(
+            url,
+            move |body: crate::types::PhoneUserVoiceMailsResponse| {
+                let page = if body.next_page_token.is_empty() {
+                    None
+                } else {
+                    Some(format!("{}{}", next, body.next_page_token))
+                };
+
+                (body.voice_mails, page)
+            },
+        )
     }
     /**
      * Set up shared access.
@@ -1748,6 +3339,34 @@ impl Phone {
             )
             .await
     }
+    /**
+     * Remove shared access.
+     *
+     * This function performs a `DELETE` to the `/phone/users/{userId}/settings/{settingType}` endpoint.
+     *
+     * As opposed to `delete_user_setting`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Use this API to remove a user's shared voicemail access settings. For user-level apps, pass [the `me` value](https://marketplace.zoom.us/docs/api-reference/using-zoom-apis#mekeyword) instead of the `userId` parameter.
+     *
+     * To view these settings in your Zoom web portal, navigate to the **Admin >> Phone System Management >> Users & Rooms** interface. Click the **Users** tab and select **User Settings**. Scroll down to **Voicemail & Call Recordings**.
+     *
+     * **Scopes:** `phone:write:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Light`
+     *
+     * **Prerequisites:**
+     * * A Business or Enterprise account
+     */
+    pub fn delete_user_setting_request<'a>(
+        &'a self,
+        user_id: &'a str,
+        setting_type: &'a str,
+    ) -> PhoneDeleteUserSettingRequest<'a> {
+        PhoneDeleteUserSettingRequest {
+            client: self,
+            user_id,
+            setting_type,
+            shared_id: None,
+        }
+    }
     /**
      * Update shared access.
      *
@@ -1806,29 +3425,130 @@ impl Phone {
      * * A Business or Enterprise account
      * * A Zoom Phone license
      * * Account owner and a [role](https://support.zoom.us/hc/en-us/articles/115001078646-Role-Based-Access-Control) with Zoom Phone management
-     *
-     * **Parameters:**
-     *
-     * * `page_size: i64` -- The number of records returned within a single API call.
-     * * `from: &str` -- Start date from which you would like to get the call logs. The start date should be within past six months. <br>
-     *   
-     *   The API only returns data pertaining to a month. Thus, the date range(defined using "from" and "to" fields) for which the call logs are to be returned must not exceed a month.
-     * * `to: &str` -- The end date upto which you would like to get the call logs for. The end date should be within past six months.
-     * * `type_: &str` -- The type of the call logs. The value can be either "all" or "missed".
-     * * `next_page_token: &str` -- The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
-     * * `path: &str` -- Filter the API response by [path](https://support.zoom.us/hc/en-us/articles/360021114452-Viewing-and-identifying-logs#h_646b46c6-0623-4ab1-8b8b-ea5b8bcef679) of the call. The value of this field can be one of the following: `voiceMail`, `message`, `forward`, `extension`, `callQueue`, `ivrMenu`, `companyDirectory`, `autoReceptionist`, `contactCenter`, `disconnected`, `commonAreaPhone`,
-     *   `pstn`, `transfer`, `sharedLines`, `sharedLineGroup`, `tollFreeBilling`, `meetingService`, `parkPickup`,
-     *   `parkTimeout`, `monitor`, `takeover`, `sipGroup`.
-     * * `time_type: crate::types::TimeType` -- Enables you to sort call logs by start or end time. Choose the sort time value. Values include `startTime` or `endTime`.
-     * * `site_id: &str` -- Unique identifier of the [site](https://support.zoom.us/hc/en-us/articles/360020809672-Managing-multiple-sites). Use this query parameter if you have enabled multiple sites and would like to filter the response of this API call by call logs of a specific phone site.
+     *
+     * **Parameters:**
+     *
+     * * `page_size: i64` -- The number of records returned within a single API call.
+     * * `from: &str` -- Start date from which you would like to get the call logs. The start date should be within past six months. <br>
+     *   
+     *   The API only returns data pertaining to a month. Thus, the date range(defined using "from" and "to" fields) for which the call logs are to be returned must not exceed a month.
+     * * `to: &str` -- The end date upto which you would like to get the call logs for. The end date should be within past six months.
+     * * `type_: &str` -- The type of the call logs. The value can be either "all" or "missed".
+     * * `next_page_token: &str` -- The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
+     * * `path: &str` -- Filter the API response by [path](https://support.zoom.us/hc/en-us/articles/360021114452-Viewing-and-identifying-logs#h_646b46c6-0623-4ab1-8b8b-ea5b8bcef679) of the call. The value of this field can be one of the following: `voiceMail`, `message`, `forward`, `extension`, `callQueue`, `ivrMenu`, `companyDirectory`, `autoReceptionist`, `contactCenter`, `disconnected`, `commonAreaPhone`,
+     *   `pstn`, `transfer`, `sharedLines`, `sharedLineGroup`, `tollFreeBilling`, `meetingService`, `parkPickup`,
+     *   `parkTimeout`, `monitor`, `takeover`, `sipGroup`.
+     * * `time_type: crate::types::TimeType` -- Enables you to sort call logs by start or end time. Choose the sort time value. Values include `startTime` or `endTime`.
+     * * `site_id: &str` -- Unique identifier of the [site](https://support.zoom.us/hc/en-us/articles/360020809672-Managing-multiple-sites). Use this query parameter if you have enabled multiple sites and would like to filter the response of this API call by call logs of a specific phone site.
+     */
+    pub async fn account_call_logs(
+        &self,
+        page_size: i64,
+        from: &str,
+        to: &str,
+        type_: &str,
+        next_page_token: &str,
+        path: &str,
+        time_type: crate::types::TimeType,
+        site_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::AccountCallLogsResponse>>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !from.is_empty() {
+            query_args.push(("from".to_string(), from.to_string()));
+        }
+        if !next_page_token.is_empty() {
+            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+        }
+        if page_size > 0 {
+            query_args.push(("page_size".to_string(), page_size.to_string()));
+        }
+        if !path.is_empty() {
+            query_args.push(("path".to_string(), path.to_string()));
+        }
+        if !site_id.is_empty() {
+            query_args.push(("site_id".to_string(), site_id.to_string()));
+        }
+        if !time_type.to_string().is_empty() {
+            query_args.push(("time_type".to_string(), time_type.to_string()));
+        }
+        if !to.is_empty() {
+            query_args.push(("to".to_string(), to.to_string()));
+        }
+        if !type_.is_empty() {
+            query_args.push(("type".to_string(), type_.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/phone/call_logs?{}", query_), None);
+        let resp: crate::Response<crate::types::AccountCallLogsResponseData> = self
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await?;
+
+        // Return our response data.
+        Ok(crate::Response::new(
+            resp.status,
+            resp.headers,
+            resp.body.call_logs.to_vec(),
+        ))
+    }
+    /**
+     * Get account's call logs.
+     *
+     * This function performs a `GET` to the `/phone/call_logs` endpoint.
+     *
+     * As opposed to `account_call_logs`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Use this API to return an account's [call logs](https://support.zoom.us/hc/en-us/articles/360021114452-Viewing-Call-Logs).
+     *
+     * **Scopes:** `phone:read:admin`, `phone_call_log:read:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Heavy`
+     *
+     * **Prerequisites:**
+     * * A Business or Enterprise account
+     * * A Zoom Phone license
+     * * Account owner and a [role](https://support.zoom.us/hc/en-us/articles/115001078646-Role-Based-Access-Control) with Zoom Phone management
+     */
+    pub fn account_call_logs_request(&self) -> PhoneAccountCallLogsRequest<'_> {
+        PhoneAccountCallLogsRequest {
+            client: self,
+            page_size: None,
+            from: None,
+            to: None,
+            type_: None,
+            next_page_token: None,
+            path: None,
+            time_type: None,
+            site_id: None,
+        }
+    }
+    /**
+     * Get account's call logs.
+     *
+     * This function performs a `GET` to the `/phone/call_logs` endpoint.
+     *
+     * As opposed to `account_call_logs`, this function returns all the pages of the request at once.
+     *
+     * Use this API to return an account's [call logs](https://support.zoom.us/hc/en-us/articles/360021114452-Viewing-Call-Logs).
+     *
+     * **Scopes:** `phone:read:admin`, `phone_call_log:read:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Heavy`
+     *
+     * **Prerequisites:**
+     * * A Business or Enterprise account
+     * * A Zoom Phone license
+     * * Account owner and a [role](https://support.zoom.us/hc/en-us/articles/115001078646-Role-Based-Access-Control) with Zoom Phone management
      */
-    pub async fn account_call_logs(
+    pub async fn get_all_account_call_logs(
         &self,
-        page_size: i64,
         from: &str,
         to: &str,
         type_: &str,
-        next_page_token: &str,
         path: &str,
         time_type: crate::types::TimeType,
         site_id: &str,
@@ -1837,12 +3557,6 @@ impl Phone {
         if !from.is_empty() {
             query_args.push(("from".to_string(), from.to_string()));
         }
-        if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
-        }
-        if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
-        }
         if !path.is_empty() {
             query_args.push(("path".to_string(), path.to_string()));
         }
@@ -1862,7 +3576,11 @@ impl Phone {
         let url = self
             .client
             .url(&format!("/phone/call_logs?{}", query_), None);
-        let resp: crate::Response<crate::types::AccountCallLogsResponseData> = self
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let mut pages = crate::utils::Pages::new(self.client.page_limits);
+        pages.visit(&url)?;
+
+        let mut response: crate::Response<crate::types::AccountCallLogsResponseData> = self
             .client
             .get(
                 &url,
@@ -1872,12 +3590,42 @@ impl Phone {
                 },
             )
             .await?;
+        let mut call_logs = Vec::new();
+
+        // Paginate if we should.
+        loop {
+            let page = if response.body.next_page_token.is_empty() {
+                None
+            } else {
+                Some(format!(
+                    "{}{}next_page_token={}",
+                    url, sep, response.body.next_page_token
+                ))
+            };
+            let more = pages.keep(&mut response.body.call_logs);
+            call_logs.append(&mut response.body.call_logs);
+
+            let next = match page {
+                Some(next) if more && pages.visit(&next)? => next,
+                _ => break,
+            };
+            response = self
+                .client
+                .get(
+                    &next,
+                    crate::Message {
+                        body: None,
+                        content_type: None,
+                    },
+                )
+                .await?;
+        }
 
         // Return our response data.
         Ok(crate::Response::new(
-            resp.status,
-            resp.headers,
-            resp.body.call_logs.to_vec(),
+            response.status,
+            response.headers,
+            call_logs,
         ))
     }
     /**
@@ -1885,7 +3633,7 @@ impl Phone {
      *
      * This function performs a `GET` to the `/phone/call_logs` endpoint.
      *
-     * As opposed to `account_call_logs`, this function returns all the pages of the request at once.
+     * As opposed to `get_all_account_call_logs`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
      *
      * Use this API to return an account's [call logs](https://support.zoom.us/hc/en-us/articles/360021114452-Viewing-Call-Logs).
      *
@@ -1896,7 +3644,7 @@ impl Phone {
      * * A Zoom Phone license
      * * Account owner and a [role](https://support.zoom.us/hc/en-us/articles/115001078646-Role-Based-Access-Control) with Zoom Phone management
      */
-    pub async fn get_all_account_call_logs(
+    pub fn get_all_account_call_logs_stream(
         &self,
         from: &str,
         to: &str,
@@ -1904,7 +3652,8 @@ impl Phone {
         path: &str,
         time_type: crate::types::TimeType,
         site_id: &str,
-    ) -> ClientResult<crate::Response<Vec<crate::types::AccountCallLogsResponse>>> {
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::AccountCallLogsResponse>> + Unpin + '_
+    {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !from.is_empty() {
             query_args.push(("from".to_string(), from.to_string()));
@@ -1928,70 +3677,20 @@ impl Phone {
         let url = self
             .client
             .url(&format!("/phone/call_logs?{}", query_), None);
-        let crate::Response::<crate::types::AccountCallLogsResponseData> {
-            mut status,
-            mut headers,
-            mut body,
-        } = self
-            .client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await?;
-
-        let mut call_logs = body.call_logs;
-        let mut page = body.next_page_token;
-
-        // Paginate if we should.
-        while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                crate::Response::<crate::types::AccountCallLogsResponseData> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}?next_page_token={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            } else {
-                crate::Response::<crate::types::AccountCallLogsResponseData> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}&next_page_token={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
-
-            call_logs.append(&mut body.call_logs);
-
-            if !body.next_page_token.is_empty() && body.next_page_token != page {
-                page = body.next_page_token.to_string();
-            } else {
-                page = "".to_string();
-            }
-        }
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let next = format!("{}{}next_page_token=", url, sep);
+        self.client.get_pages_stream(
+            url,
+            move |body: crate::types::AccountCallLogsResponseData| {
+                let page = if body.next_page_token.is_empty() {
+                    None
+                } else {
+                    Some(format!("{}{}", next, body.next_page_token))
+                };
 
-        // Return our response data.
-        Ok(crate::Response::new(status, headers, call_logs))
+                (body.call_logs, page)
+            },
+        )
     }
     /**
      * Assign phone number to user.
@@ -2236,6 +3935,35 @@ impl Phone {
             resp.body.recordings.to_vec(),
         ))
     }
+    /**
+     * Get call recordings.
+     *
+     * This function performs a `GET` to the `/phone/recordings` endpoint.
+     *
+     * As opposed to `get_recordings`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Use this API to list an account's [call recordings](https://support.zoom.us/hc/en-us/articles/360038521091-Accessing-and-sharing-call-recordings)
+     *
+     * **Scopes:** `phone:read:admin`, `phone:write:admin`,`phone_recording:read:admin`
+     *
+     * **Prerequisties:**
+     * * A Pro or higher account plan
+     * * A Zoom Phone license
+     * * Account owner or admin privileges
+     */
+    pub fn get_recordings_request(&self) -> PhoneGetRecordingsRequest<'_> {
+        PhoneGetRecordingsRequest {
+            client: self,
+            page_size: None,
+            next_page_token: None,
+            from: None,
+            to: None,
+            owner_type: None,
+            recording_type: None,
+            site_id: None,
+            query_date_type: None,
+        }
+    }
     /**
      * Get call recordings.
      *
@@ -2284,11 +4012,11 @@ impl Phone {
         let url = self
             .client
             .url(&format!("/phone/recordings?{}", query_), None);
-        let crate::Response::<crate::types::GetPhoneRecordingsResponseData> {
-            mut status,
-            mut headers,
-            mut body,
-        } = self
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let mut pages = crate::utils::Pages::new(self.client.page_limits);
+        pages.visit(&url)?;
+
+        let mut response: crate::Response<crate::types::GetPhoneRecordingsResponseData> = self
             .client
             .get(
                 &url,
@@ -2298,56 +4026,107 @@ impl Phone {
                 },
             )
             .await?;
-
-        let mut recordings = body.recordings;
-        let mut page = body.next_page_token;
+        let mut recordings = Vec::new();
 
         // Paginate if we should.
-        while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                crate::Response::<crate::types::GetPhoneRecordingsResponseData> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}?next_page_token={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
+        loop {
+            let page = if response.body.next_page_token.is_empty() {
+                None
             } else {
-                crate::Response::<crate::types::GetPhoneRecordingsResponseData> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}&next_page_token={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
-
-            recordings.append(&mut body.recordings);
-
-            if !body.next_page_token.is_empty() && body.next_page_token != page {
-                page = body.next_page_token.to_string();
-            } else {
-                page = "".to_string();
-            }
+                Some(format!(
+                    "{}{}next_page_token={}",
+                    url, sep, response.body.next_page_token
+                ))
+            };
+            let more = pages.keep(&mut response.body.recordings);
+            recordings.append(&mut response.body.recordings);
+
+            let next = match page {
+                Some(next) if more && pages.visit(&next)? => next,
+                _ => break,
+            };
+            response = self
+                .client
+                .get(
+                    &next,
+                    crate::Message {
+                        body: None,
+                        content_type: None,
+                    },
+                )
+                .await?;
         }
 
         // Return our response data.
-        Ok(crate::Response::new(status, headers, recordings))
+        Ok(crate::Response::new(
+            response.status,
+            response.headers,
+            recordings,
+        ))
+    }
+    /**
+     * Get call recordings.
+     *
+     * This function performs a `GET` to the `/phone/recordings` endpoint.
+     *
+     * As opposed to `get_all_recordings`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Use this API to list an account's [call recordings](https://support.zoom.us/hc/en-us/articles/360038521091-Accessing-and-sharing-call-recordings)
+     *
+     * **Scopes:** `phone:read:admin`, `phone:write:admin`,`phone_recording:read:admin`
+     *
+     * **Prerequisties:**
+     * * A Pro or higher account plan
+     * * A Zoom Phone license
+     * * Account owner or admin privileges
+     */
+    pub fn get_all_recordings_stream(
+        &self,
+        from: &str,
+        to: &str,
+        owner_type: &str,
+        recording_type: &str,
+        site_id: &str,
+        query_date_type: crate::types::QueryDateType,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::GetPhoneRecordingsResponse>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !from.is_empty() {
+            query_args.push(("from".to_string(), from.to_string()));
+        }
+        if !owner_type.is_empty() {
+            query_args.push(("owner_type".to_string(), owner_type.to_string()));
+        }
+        if !query_date_type.to_string().is_empty() {
+            query_args.push(("query_date_type".to_string(), query_date_type.to_string()));
+        }
+        if !recording_type.is_empty() {
+            query_args.push(("recording_type".to_string(), recording_type.to_string()));
+        }
+        if !site_id.is_empty() {
+            query_args.push(("site_id".to_string(), site_id.to_string()));
+        }
+        if !to.is_empty() {
+            query_args.push(("to".to_string(), to.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/phone/recordings?{}", query_), None);
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let next = format!("{}{}next_page_token=", url, sep);
+        self.client.get_pages_stream(
+            url,
+            move |body: crate::types::GetPhoneRecordingsResponseData| {
+                let page = if body.next_page_token.is_empty() {
+                    None
+                } else {
+                    Some(format!("{}{}", next, body.next_page_token))
+                };
+
+                (body.recordings, page)
+            },
+        )
     }
     /**
      * List BYOC SIP trunks.
@@ -2400,6 +4179,27 @@ impl Phone {
             resp.body.byoc_sip_trunk.to_vec(),
         ))
     }
+    /**
+     * List BYOC SIP trunks.
+     *
+     * This function performs a `GET` to the `/phone/sip_trunk/trunks` endpoint.
+     *
+     * As opposed to `list_byocsip_trunk`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Use this API to return a list of an account's assigned [BYOC (Bring Your Own Carrier) SIP (Session Initiation Protocol) trunks](https://zoom.us/docs/doc/Zoom-Bring%20Your%20Own%20Carrier.pdf).
+     *
+     * **Scopes:** `phone:write:admin` or `phone:master`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Light`
+     *
+     * **Prerequisites:**
+     * * A Business or Enterprise account
+     */
+    pub fn list_byocsip_trunk_request(&self) -> PhoneListByocsipTrunkRequest<'_> {
+        PhoneListByocsipTrunkRequest {
+            client: self,
+            next_page_token: None,
+            page_size: None,
+        }
+    }
     /**
      * List BYOC SIP trunks.
      *
@@ -2418,11 +4218,11 @@ impl Phone {
         &self,
     ) -> ClientResult<crate::Response<Vec<crate::types::ByocSipTrunk>>> {
         let url = self.client.url("/phone/sip_trunk/trunks", None);
-        let crate::Response::<crate::types::ListByocsipTrunkResponse> {
-            mut status,
-            mut headers,
-            mut body,
-        } = self
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let mut pages = crate::utils::Pages::new(self.client.page_limits);
+        pages.visit(&url)?;
+
+        let mut response: crate::Response<crate::types::ListByocsipTrunkResponse> = self
             .client
             .get(
                 &url,
@@ -2432,56 +4232,74 @@ impl Phone {
                 },
             )
             .await?;
-
-        let mut byoc_sip_trunk = body.byoc_sip_trunk;
-        let mut page = body.next_page_token;
+        let mut byoc_sip_trunk = Vec::new();
 
         // Paginate if we should.
-        while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                crate::Response::<crate::types::ListByocsipTrunkResponse> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}?next_page_token={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
+        loop {
+            let page = if response.body.next_page_token.is_empty() {
+                None
             } else {
-                crate::Response::<crate::types::ListByocsipTrunkResponse> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}&next_page_token={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
-
-            byoc_sip_trunk.append(&mut body.byoc_sip_trunk);
-
-            if !body.next_page_token.is_empty() && body.next_page_token != page {
-                page = body.next_page_token.to_string();
-            } else {
-                page = "".to_string();
-            }
+                Some(format!(
+                    "{}{}next_page_token={}",
+                    url, sep, response.body.next_page_token
+                ))
+            };
+            let more = pages.keep(&mut response.body.byoc_sip_trunk);
+            byoc_sip_trunk.append(&mut response.body.byoc_sip_trunk);
+
+            let next = match page {
+                Some(next) if more && pages.visit(&next)? => next,
+                _ => break,
+            };
+            response = self
+                .client
+                .get(
+                    &next,
+                    crate::Message {
+                        body: None,
+                        content_type: None,
+                    },
+                )
+                .await?;
         }
 
         // Return our response data.
-        Ok(crate::Response::new(status, headers, byoc_sip_trunk))
+        Ok(crate::Response::new(
+            response.status,
+            response.headers,
+            byoc_sip_trunk,
+        ))
+    }
+    /**
+     * List BYOC SIP trunks.
+     *
+     * This function performs a `GET` to the `/phone/sip_trunk/trunks` endpoint.
+     *
+     * As opposed to `list_all_byocsip_trunk`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Use this API to return a list of an account's assigned [BYOC (Bring Your Own Carrier) SIP (Session Initiation Protocol) trunks](https://zoom.us/docs/doc/Zoom-Bring%20Your%20Own%20Carrier.pdf).
+     *
+     * **Scopes:** `phone:write:admin` or `phone:master`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Light`
+     *
+     * **Prerequisites:**
+     * * A Business or Enterprise account
+     */
+    pub fn list_all_byocsip_trunk_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::ByocSipTrunk>> + Unpin + '_ {
+        let url = self.client.url("/phone/sip_trunk/trunks", None);
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let next = format!("{}{}next_page_token=", url, sep);
+        self.client
+            .get_pages_stream(url, move |body: crate::types::ListByocsipTrunkResponse| {
+                let page = if body.next_page_token.is_empty() {
+                    None
+                } else {
+                    Some(format!("{}{}", next, body.next_page_token))
+                };
+
+                (body.byoc_sip_trunk, page)
+            })
     }
     /**
      * Assign SIP trunks.
@@ -2614,6 +4432,28 @@ impl Phone {
             resp.body.external_contacts.to_vec(),
         ))
     }
+    /**
+     * List external contacts.
+     *
+     * This function performs a `GET` to the `/phone/external_contacts` endpoint.
+     *
+     * As opposed to `list_external_contacts`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Use this API to list external contacts.
+     *
+     * **Scopes:** `phone:read:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Light`
+     *
+     * **Prerequisites:**
+     * * Pro or a higher account with Zoom Phone license
+     * * Account owner or admin permissions
+     */
+    pub fn list_external_contacts_request(&self) -> PhoneListExternalContactsRequest<'_> {
+        PhoneListExternalContactsRequest {
+            client: self,
+            next_page_token: None,
+            page_size: None,
+        }
+    }
     /**
      * List external contacts.
      *
@@ -2633,11 +4473,11 @@ impl Phone {
         &self,
     ) -> ClientResult<crate::Response<Vec<crate::types::ExternalContacts>>> {
         let url = self.client.url("/phone/external_contacts", None);
-        let crate::Response::<crate::types::ListExternalContactsResponse> {
-            mut status,
-            mut headers,
-            mut body,
-        } = self
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let mut pages = crate::utils::Pages::new(self.client.page_limits);
+        pages.visit(&url)?;
+
+        let mut response: crate::Response<crate::types::ListExternalContactsResponse> = self
             .client
             .get(
                 &url,
@@ -2647,56 +4487,78 @@ impl Phone {
                 },
             )
             .await?;
-
-        let mut external_contacts = body.external_contacts;
-        let mut page = body.next_page_token;
+        let mut external_contacts = Vec::new();
 
         // Paginate if we should.
-        while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                crate::Response::<crate::types::ListExternalContactsResponse> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}?next_page_token={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
+        loop {
+            let page = if response.body.next_page_token.is_empty() {
+                None
             } else {
-                crate::Response::<crate::types::ListExternalContactsResponse> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}&next_page_token={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
-
-            external_contacts.append(&mut body.external_contacts);
-
-            if !body.next_page_token.is_empty() && body.next_page_token != page {
-                page = body.next_page_token.to_string();
-            } else {
-                page = "".to_string();
-            }
+                Some(format!(
+                    "{}{}next_page_token={}",
+                    url, sep, response.body.next_page_token
+                ))
+            };
+            let more = pages.keep(&mut response.body.external_contacts);
+            external_contacts.append(&mut response.body.external_contacts);
+
+            let next = match page {
+                Some(next) if more && pages.visit(&next)? => next,
+                _ => break,
+            };
+            response = self
+                .client
+                .get(
+                    &next,
+                    crate::Message {
+                        body: None,
+                        content_type: None,
+                    },
+                )
+                .await?;
         }
 
         // Return our response data.
-        Ok(crate::Response::new(status, headers, external_contacts))
+        Ok(crate::Response::new(
+            response.status,
+            response.headers,
+            external_contacts,
+        ))
+    }
+    /**
+     * List external contacts.
+     *
+     * This function performs a `GET` to the `/phone/external_contacts` endpoint.
+     *
+     * As opposed to `list_all_external_contacts`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Use this API to list external contacts.
+     *
+     * **Scopes:** `phone:read:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Light`
+     *
+     * **Prerequisites:**
+     * * Pro or a higher account with Zoom Phone license
+     * * Account owner or admin permissions
+     */
+    pub fn list_all_external_contacts_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::ExternalContacts>> + Unpin + '_
+    {
+        let url = self.client.url("/phone/external_contacts", None);
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let next = format!("{}{}next_page_token=", url, sep);
+        self.client.get_pages_stream(
+            url,
+            move |body: crate::types::ListExternalContactsResponse| {
+                let page = if body.next_page_token.is_empty() {
+                    None
+                } else {
+                    Some(format!("{}{}", next, body.next_page_token))
+                };
+
+                (body.external_contacts, page)
+            },
+        )
     }
     /**
      * Add an external contact.
@@ -3029,6 +4891,29 @@ impl Phone {
             resp.body.users.to_vec(),
         ))
     }
+    /**
+     * List phone users.
+     *
+     * This function performs a `GET` to the `/phone/users` endpoint.
+     *
+     * As opposed to `list_users`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Use this API to return a list of all of an account's users who are assigned a Zoom Phone license.
+     *
+     * **Scopes:** `phone:read:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Light`
+     *
+     * **Prerequisites:**
+     * * A Pro or higher account plan
+     * * A Zoom Phone license
+     */
+    pub fn list_users_request(&self) -> PhoneListUsersRequest<'_> {
+        PhoneListUsersRequest {
+            client: self,
+            page_size: None,
+            next_page_token: None,
+            site_id: None,
+        }
+    }
     /**
      * List phone users.
      *
@@ -3054,11 +4939,11 @@ impl Phone {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/phone/users?{}", query_), None);
-        let crate::Response::<crate::types::ListPhoneUsersResponseData> {
-            mut status,
-            mut headers,
-            mut body,
-        } = self
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let mut pages = crate::utils::Pages::new(self.client.page_limits);
+        pages.visit(&url)?;
+
+        let mut response: crate::Response<crate::types::ListPhoneUsersResponseData> = self
             .client
             .get(
                 &url,
@@ -3068,56 +4953,84 @@ impl Phone {
                 },
             )
             .await?;
-
-        let mut users = body.users;
-        let mut page = body.next_page_token;
+        let mut users = Vec::new();
 
         // Paginate if we should.
-        while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                crate::Response::<crate::types::ListPhoneUsersResponseData> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}?next_page_token={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
+        loop {
+            let page = if response.body.next_page_token.is_empty() {
+                None
             } else {
-                crate::Response::<crate::types::ListPhoneUsersResponseData> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}&next_page_token={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
-
-            users.append(&mut body.users);
-
-            if !body.next_page_token.is_empty() && body.next_page_token != page {
-                page = body.next_page_token.to_string();
-            } else {
-                page = "".to_string();
-            }
+                Some(format!(
+                    "{}{}next_page_token={}",
+                    url, sep, response.body.next_page_token
+                ))
+            };
+            let more = pages.keep(&mut response.body.users);
+            users.append(&mut response.body.users);
+
+            let next = match page {
+                Some(next) if more && pages.visit(&next)? => next,
+                _ => break,
+            };
+            response = self
+                .client
+                .get(
+                    &next,
+                    crate::Message {
+                        body: None,
+                        content_type: None,
+                    },
+                )
+                .await?;
         }
 
         // Return our response data.
-        Ok(crate::Response::new(status, headers, users))
+        Ok(crate::Response::new(
+            response.status,
+            response.headers,
+            users,
+        ))
+    }
+    /**
+     * List phone users.
+     *
+     * This function performs a `GET` to the `/phone/users` endpoint.
+     *
+     * As opposed to `list_all_users`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Use this API to return a list of all of an account's users who are assigned a Zoom Phone license.
+     *
+     * **Scopes:** `phone:read:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Light`
+     *
+     * **Prerequisites:**
+     * * A Pro or higher account plan
+     * * A Zoom Phone license
+     */
+    pub fn list_all_users_stream(
+        &self,
+        site_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::ListPhoneUsersResponse>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !site_id.is_empty() {
+            query_args.push(("site_id".to_string(), site_id.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/phone/users?{}", query_), None);
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let next = format!("{}{}next_page_token=", url, sep);
+        self.client.get_pages_stream(
+            url,
+            move |body: crate::types::ListPhoneUsersResponseData| {
+                let page = if body.next_page_token.is_empty() {
+                    None
+                } else {
+                    Some(format!("{}{}", next, body.next_page_token))
+                };
+
+                (body.users, page)
+            },
+        )
     }
     /**
      * Get call log details.
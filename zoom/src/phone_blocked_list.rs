@@ -1,6 +1,64 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`PhoneBlockedList::list_blocked`], set with a builder.
+pub struct PhoneBlockedListListBlockedRequest<'a> {
+    client: &'a PhoneBlockedList,
+    next_page_token: Option<&'a str>,
+    page_size: Option<i64>,
+}
+
+impl<'a> PhoneBlockedListListBlockedRequest<'a> {
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.next_page_token = Some(next_page_token);
+        self
+    }
+
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::BlockedList>>> {
+        let Self {
+            client,
+            next_page_token,
+            page_size,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = next_page_token {
+            query_args.push(("next_page_token".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_size {
+            query_args.push(("page_size".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/phone/blocked_list?{}", query_), None);
+        let resp: crate::Response<crate::types::ListBlockedResponse> = client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await?;
+
+        // Return our response data.
+        Ok(crate::Response::new(
+            resp.status,
+            resp.headers,
+            resp.body.blocked_list.to_vec(),
+        ))
+    }
+}
+
 pub struct PhoneBlockedList {
     pub client: Client,
 }
@@ -63,6 +121,28 @@ impl PhoneBlockedList {
             resp.body.blocked_list.to_vec(),
         ))
     }
+    /**
+     * List blocked lists.
+     *
+     * This function performs a `GET` to the `/phone/blocked_list` endpoint.
+     *
+     * As opposed to `list_blocked`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * A Zoom account owner or a user with admin privilege can block phone numbers for phone users in an account. Blocked numbers can be inbound (numbers will be blocked from calling in) and outbound (phone users in your account won't be able to dial those numbers). Blocked callers will hear a generic message stating that the person they are calling is not available.<br>Use this API to list all the blocked lists in an acccount.<br>
+     * **Prerequisites:**
+     * * Pro or higher account plan with Zoom phone license<br>
+     * **Scope:** `phone:read:admin`<br>
+     *
+     *
+     *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     */
+    pub fn list_blocked_request(&self) -> PhoneBlockedListListBlockedRequest<'_> {
+        PhoneBlockedListListBlockedRequest {
+            client: self,
+            next_page_token: None,
+            page_size: None,
+        }
+    }
     /**
      * List blocked lists.
      *
@@ -82,11 +162,11 @@ impl PhoneBlockedList {
         &self,
     ) -> ClientResult<crate::Response<Vec<crate::types::BlockedList>>> {
         let url = self.client.url("/phone/blocked_list", None);
-        let crate::Response::<crate::types::ListBlockedResponse> {
-            mut status,
-            mut headers,
-            mut body,
-        } = self
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let mut pages = crate::utils::Pages::new(self.client.page_limits);
+        pages.visit(&url)?;
+
+        let mut response: crate::Response<crate::types::ListBlockedResponse> = self
             .client
             .get(
                 &url,
@@ -96,56 +176,75 @@ impl PhoneBlockedList {
                 },
             )
             .await?;
-
-        let mut blocked_list = body.blocked_list;
-        let mut page = body.next_page_token;
+        let mut blocked_list = Vec::new();
 
         // Paginate if we should.
-        while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                crate::Response::<crate::types::ListBlockedResponse> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}?next_page_token={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
+        loop {
+            let page = if response.body.next_page_token.is_empty() {
+                None
             } else {
-                crate::Response::<crate::types::ListBlockedResponse> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}&next_page_token={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
-
-            blocked_list.append(&mut body.blocked_list);
+                Some(format!(
+                    "{}{}next_page_token={}",
+                    url, sep, response.body.next_page_token
+                ))
+            };
+            let more = pages.keep(&mut response.body.blocked_list);
+            blocked_list.append(&mut response.body.blocked_list);
 
-            if !body.next_page_token.is_empty() && body.next_page_token != page {
-                page = body.next_page_token.to_string();
-            } else {
-                page = "".to_string();
-            }
+            let next = match page {
+                Some(next) if more && pages.visit(&next)? => next,
+                _ => break,
+            };
+            response = self
+                .client
+                .get(
+                    &next,
+                    crate::Message {
+                        body: None,
+                        content_type: None,
+                    },
+                )
+                .await?;
         }
 
         // Return our response data.
-        Ok(crate::Response::new(status, headers, blocked_list))
+        Ok(crate::Response::new(
+            response.status,
+            response.headers,
+            blocked_list,
+        ))
+    }
+    /**
+     * List blocked lists.
+     *
+     * This function performs a `GET` to the `/phone/blocked_list` endpoint.
+     *
+     * As opposed to `list_all_blocked`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * A Zoom account owner or a user with admin privilege can block phone numbers for phone users in an account. Blocked numbers can be inbound (numbers will be blocked from calling in) and outbound (phone users in your account won't be able to dial those numbers). Blocked callers will hear a generic message stating that the person they are calling is not available.<br>Use this API to list all the blocked lists in an acccount.<br>
+     * **Prerequisites:**
+     * * Pro or higher account plan with Zoom phone license<br>
+     * **Scope:** `phone:read:admin`<br>
+     *
+     *
+     *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     */
+    pub fn list_all_blocked_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::BlockedList>> + Unpin + '_ {
+        let url = self.client.url("/phone/blocked_list", None);
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let next = format!("{}{}next_page_token=", url, sep);
+        self.client
+            .get_pages_stream(url, move |body: crate::types::ListBlockedResponse| {
+                let page = if body.next_page_token.is_empty() {
+                    None
+                } else {
+                    Some(format!("{}{}", next, body.next_page_token))
+                };
+
+                (body.blocked_list, page)
+            })
     }
     /**
      * Create a blocked list.
@@ -1,6 +1,153 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`PhoneCallQueues::list_call_queues`], set with a builder.
+pub struct PhoneCallQueuesListCallQueuesRequest<'a> {
+    client: &'a PhoneCallQueues,
+    next_page_token: Option<&'a str>,
+    page_size: Option<i64>,
+}
+
+impl<'a> PhoneCallQueuesListCallQueuesRequest<'a> {
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.next_page_token = Some(next_page_token);
+        self
+    }
+
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::CallQueues>>> {
+        let Self {
+            client,
+            next_page_token,
+            page_size,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = next_page_token {
+            query_args.push(("next_page_token".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_size {
+            query_args.push(("page_size".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/phone/call_queues?{}", query_), None);
+        let resp: crate::Response<crate::types::ListCallQueuesResponse> = client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await?;
+
+        // Return our response data.
+        Ok(crate::Response::new(
+            resp.status,
+            resp.headers,
+            resp.body.call_queues.to_vec(),
+        ))
+    }
+}
+
+/// The parameters of [`PhoneCallQueues::get_call_queue_recordings`], set with a builder.
+pub struct PhoneCallQueuesGetCallQueueRecordingsRequest<'a> {
+    client: &'a PhoneCallQueues,
+    call_queue_id: &'a str,
+    page_size: Option<i64>,
+    next_page_token: Option<&'a str>,
+    from: Option<chrono::NaiveDate>,
+    to: Option<chrono::NaiveDate>,
+}
+
+impl<'a> PhoneCallQueuesGetCallQueueRecordingsRequest<'a> {
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.next_page_token = Some(next_page_token);
+        self
+    }
+
+    /// Sets the `from` query parameter.
+    pub fn from(mut self, from: chrono::NaiveDate) -> Self {
+        self.from = Some(from);
+        self
+    }
+
+    /// Sets the `to` query parameter.
+    pub fn to(mut self, to: chrono::NaiveDate) -> Self {
+        self.to = Some(to);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GetCallQueueRecordingsResponse>>> {
+        let Self {
+            client,
+            call_queue_id,
+            page_size,
+            next_page_token,
+            from,
+            to,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = from {
+            query_args.push(("from".to_string(), v.to_string()));
+        }
+        if let Some(v) = next_page_token {
+            query_args.push(("next_page_token".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_size {
+            query_args.push(("page_size".to_string(), v.to_string()));
+        }
+        if let Some(v) = to {
+            query_args.push(("to".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/phone/call_queues/{}/recordings?{}",
+                crate::progenitor_support::encode_path(call_queue_id),
+                query_
+            ),
+            None,
+        );
+        let resp: crate::Response<crate::types::GetCallQueueRecordingsResponseData> = client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await?;
+
+        // Return our response data.
+        Ok(crate::Response::new(
+            resp.status,
+            resp.headers,
+            resp.body.recordings.to_vec(),
+        ))
+    }
+}
+
 pub struct PhoneCallQueues {
     pub client: Client,
 }
@@ -66,6 +213,31 @@ impl PhoneCallQueues {
             resp.body.call_queues.to_vec(),
         ))
     }
+    /**
+     * List call queues.
+     *
+     * This function performs a `GET` to the `/phone/call_queues` endpoint.
+     *
+     * As opposed to `list_call_queues`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Call queues allow you to route incoming calls to a group of users. For instance, you can use call queues to route calls to various departments in your organization such as sales, engineering, billing, customer service etc.<br> Use this API to list Call queues.<br><br>
+     * **Prerequisites:**<br>
+     * * Pro, Business, or Education account
+     * * Account owner or admin permissions
+     * * Zoom Phone license<br>
+     * **Scopes:** `phone:read:admin`<br>
+     *
+     *
+     *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     *
+     */
+    pub fn list_call_queues_request(&self) -> PhoneCallQueuesListCallQueuesRequest<'_> {
+        PhoneCallQueuesListCallQueuesRequest {
+            client: self,
+            next_page_token: None,
+            page_size: None,
+        }
+    }
     /**
      * List call queues.
      *
@@ -88,11 +260,11 @@ impl PhoneCallQueues {
         &self,
     ) -> ClientResult<crate::Response<Vec<crate::types::CallQueues>>> {
         let url = self.client.url("/phone/call_queues", None);
-        let crate::Response::<crate::types::ListCallQueuesResponse> {
-            mut status,
-            mut headers,
-            mut body,
-        } = self
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let mut pages = crate::utils::Pages::new(self.client.page_limits);
+        pages.visit(&url)?;
+
+        let mut response: crate::Response<crate::types::ListCallQueuesResponse> = self
             .client
             .get(
                 &url,
@@ -102,56 +274,78 @@ impl PhoneCallQueues {
                 },
             )
             .await?;
-
-        let mut call_queues = body.call_queues;
-        let mut page = body.next_page_token;
+        let mut call_queues = Vec::new();
 
         // Paginate if we should.
-        while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                crate::Response::<crate::types::ListCallQueuesResponse> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}?next_page_token={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
+        loop {
+            let page = if response.body.next_page_token.is_empty() {
+                None
             } else {
-                crate::Response::<crate::types::ListCallQueuesResponse> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}&next_page_token={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
-
-            call_queues.append(&mut body.call_queues);
+                Some(format!(
+                    "{}{}next_page_token={}",
+                    url, sep, response.body.next_page_token
+                ))
+            };
+            let more = pages.keep(&mut response.body.call_queues);
+            call_queues.append(&mut response.body.call_queues);
 
-            if !body.next_page_token.is_empty() && body.next_page_token != page {
-                page = body.next_page_token.to_string();
-            } else {
-                page = "".to_string();
-            }
+            let next = match page {
+                Some(next) if more && pages.visit(&next)? => next,
+                _ => break,
+            };
+            response = self
+                .client
+                .get(
+                    &next,
+                    crate::Message {
+                        body: None,
+                        content_type: None,
+                    },
+                )
+                .await?;
         }
 
         // Return our response data.
-        Ok(crate::Response::new(status, headers, call_queues))
+        Ok(crate::Response::new(
+            response.status,
+            response.headers,
+            call_queues,
+        ))
+    }
+    /**
+     * List call queues.
+     *
+     * This function performs a `GET` to the `/phone/call_queues` endpoint.
+     *
+     * As opposed to `list_all_call_queues`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Call queues allow you to route incoming calls to a group of users. For instance, you can use call queues to route calls to various departments in your organization such as sales, engineering, billing, customer service etc.<br> Use this API to list Call queues.<br><br>
+     * **Prerequisites:**<br>
+     * * Pro, Business, or Education account
+     * * Account owner or admin permissions
+     * * Zoom Phone license<br>
+     * **Scopes:** `phone:read:admin`<br>
+     *
+     *
+     *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     *
+     */
+    pub fn list_all_call_queues_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::CallQueues>> + Unpin + '_ {
+        let url = self.client.url("/phone/call_queues", None);
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let next = format!("{}{}next_page_token=", url, sep);
+        self.client
+            .get_pages_stream(url, move |body: crate::types::ListCallQueuesResponse| {
+                let page = if body.next_page_token.is_empty() {
+                    None
+                } else {
+                    Some(format!("{}{}", next, body.next_page_token))
+                };
+
+                (body.call_queues, page)
+            })
     }
     /**
      * Create a call queue.
@@ -662,6 +856,35 @@ impl PhoneCallQueues {
             resp.body.recordings.to_vec(),
         ))
     }
+    /**
+     * Get call queue recordings.
+     *
+     * This function performs a `GET` to the `/phone/call_queues/{callQueueId}/recordings` endpoint.
+     *
+     * As opposed to `get_call_queue_recordings`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Use this API to view [call recordings](https://support.zoom.us/hc/en-us/articles/360038521091#h_cbc9f2a3-e06c-4daa-83d4-ddbceef9c77b) from the call queue.<br><br>
+     * **Prerequisites:**<br>
+     * * Pro or higher account with Zoom Phone license.
+     * * [Automatic call recordings](https://support.zoom.us/hc/en-us/articles/360033511872#h_fcb297bb-14e8-4094-91ca-dc61e1a18734) must be enabled in the Policy Settings for call queues. <br> **Scope:** `phone:read:admin`<br> **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     *
+     *
+     *
+     *
+     */
+    pub fn get_call_queue_recordings_request<'a>(
+        &'a self,
+        call_queue_id: &'a str,
+    ) -> PhoneCallQueuesGetCallQueueRecordingsRequest<'a> {
+        PhoneCallQueuesGetCallQueueRecordingsRequest {
+            client: self,
+            call_queue_id,
+            page_size: None,
+            next_page_token: None,
+            from: None,
+            to: None,
+        }
+    }
     /**
      * Get call queue recordings.
      *
@@ -700,11 +923,11 @@ impl PhoneCallQueues {
             ),
             None,
         );
-        let crate::Response::<crate::types::GetCallQueueRecordingsResponseData> {
-            mut status,
-            mut headers,
-            mut body,
-        } = self
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let mut pages = crate::utils::Pages::new(self.client.page_limits);
+        pages.visit(&url)?;
+
+        let mut response: crate::Response<crate::types::GetCallQueueRecordingsResponseData> = self
             .client
             .get(
                 &url,
@@ -714,55 +937,97 @@ impl PhoneCallQueues {
                 },
             )
             .await?;
-
-        let mut recordings = body.recordings;
-        let mut page = body.next_page_token;
+        let mut recordings = Vec::new();
 
         // Paginate if we should.
-        while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                crate::Response::<crate::types::GetCallQueueRecordingsResponseData> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}?next_page_token={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
+        loop {
+            let page = if response.body.next_page_token.is_empty() {
+                None
             } else {
-                crate::Response::<crate::types::GetCallQueueRecordingsResponseData> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}&next_page_token={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
-
-            recordings.append(&mut body.recordings);
+                Some(format!(
+                    "{}{}next_page_token={}",
+                    url, sep, response.body.next_page_token
+                ))
+            };
+            let more = pages.keep(&mut response.body.recordings);
+            recordings.append(&mut response.body.recordings);
 
-            if !body.next_page_token.is_empty() && body.next_page_token != page {
-                page = body.next_page_token.to_string();
-            } else {
-                page = "".to_string();
-            }
+            let next = match page {
+                Some(next) if more && pages.visit(&next)? => next,
+                _ => break,
+            };
+            response = self
+                .client
+                .get(
+                    &next,
+                    crate::Message {
+                        body: None,
+                        content_type: None,
+                    },
+                )
+                .await?;
         }
 
         // Return our response data.
-        Ok(crate::Response::new(status, headers, recordings))
+        Ok(crate::Response::new(
+            response.status,
+            response.headers,
+            recordings,
+        ))
+    }
+    /**
+     * Get call queue recordings.
+     *
+     * This function performs a `GET` to the `/phone/call_queues/{callQueueId}/recordings` endpoint.
+     *
+     * As opposed to `get_all_call_queue_recordings`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Use this API to view [call recordings](https://support.zoom.us/hc/en-us/articles/360038521091#h_cbc9f2a3-e06c-4daa-83d4-ddbceef9c77b) from the call queue.<br><br>
+     * **Prerequisites:**<br>
+     * * Pro or higher account with Zoom Phone license.
+     * * [Automatic call recordings](https://support.zoom.us/hc/en-us/articles/360033511872#h_fcb297bb-14e8-4094-91ca-dc61e1a18734) must be enabled in the Policy Settings for call queues. <br> **Scope:** `phone:read:admin`<br> **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     *
+     *
+     *
+     *
+     */
+    pub fn get_all_call_queue_recordings_stream(
+        &self,
+        call_queue_id: &str,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::GetCallQueueRecordingsResponse>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !from.to_string().is_empty() {
+            query_args.push(("from".to_string(), from.to_string()));
+        }
+        if !to.to_string().is_empty() {
+            query_args.push(("to".to_string(), to.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/phone/call_queues/{}/recordings?{}",
+                crate::progenitor_support::encode_path(call_queue_id),
+                query_
+            ),
+            None,
+        );
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let next = format!("{}{}next_page_token=", url, sep);
+        self.client.get_pages_stream(
+            url,
+            move |body: crate::types::GetCallQueueRecordingsResponseData| {
+                let page = if body.next_page_token.is_empty() {
+                    None
+                } else {
+                    Some(format!("{}{}", next, body.next_page_token))
+                };
+
+                (body.recordings, page)
+            },
+        )
     }
 }
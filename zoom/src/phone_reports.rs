@@ -1,6 +1,92 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`PhoneReports::get_ps_operation_log`], set with a builder.
+pub struct PhoneReportsGetPsOperationLogRequest<'a> {
+    client: &'a PhoneReports,
+    from: Option<&'a str>,
+    to: Option<&'a str>,
+    category_type: Option<&'a str>,
+    page_size: Option<i64>,
+    next_page_token: Option<&'a str>,
+}
+
+impl<'a> PhoneReportsGetPsOperationLogRequest<'a> {
+    /// Sets the `from` query parameter.
+    pub fn from(mut self, from: &'a str) -> Self {
+        self.from = Some(from);
+        self
+    }
+
+    /// Sets the `to` query parameter.
+    pub fn to(mut self, to: &'a str) -> Self {
+        self.to = Some(to);
+        self
+    }
+
+    /// Sets the `category_type` query parameter.
+    pub fn category_type(mut self, category_type: &'a str) -> Self {
+        self.category_type = Some(category_type);
+        self
+    }
+
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.next_page_token = Some(next_page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::ReportOperationLogsResponseAllOf>> {
+        let Self {
+            client,
+            from,
+            to,
+            category_type,
+            page_size,
+            next_page_token,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = category_type {
+            query_args.push(("category_type".to_string(), v.to_string()));
+        }
+        if let Some(v) = from {
+            query_args.push(("from".to_string(), v.to_string()));
+        }
+        if let Some(v) = next_page_token {
+            query_args.push(("next_page_token".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_size {
+            query_args.push(("page_size".to_string(), v.to_string()));
+        }
+        if let Some(v) = to {
+            query_args.push(("to".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/phone/reports/operationlogs?{}", query_), None);
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct PhoneReports {
     pub client: Client,
 }
@@ -71,4 +157,30 @@ impl PhoneReports {
             )
             .await
     }
+    /**
+     * Get operation logs report.
+     *
+     * This function performs a `GET` to the `/phone/reports/operationlogs` endpoint.
+     *
+     * As opposed to `get_ps_operation_log`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * The **Phone System operation logs report** allows account owners and admins to view monthly Zoom phone related admin operation details.
+     *
+     * Use this API to retrieve the **Phone System Operation Logs Report**. Account owners and admins can also access this information by logging into their Zoom accounts and navigating to [Phone System Operation Logs](https://zoom.us/pbx/page/report/operations#/report/operation-logs).<br><br> **Scopes:** `phone:read:admin`, `phone:write:admin` <br> **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Heavy`<br>
+     * <br> **Prerequisites:** <br>
+     * * Account must be enrollled in Pro or a higher plan
+     * * Account must be enrolled in a [Zoom Phone](https://zoom.us/pricing/zoom-phone) plan
+     *
+     *
+     */
+    pub fn get_ps_operation_log_request(&self) -> PhoneReportsGetPsOperationLogRequest<'_> {
+        PhoneReportsGetPsOperationLogRequest {
+            client: self,
+            from: None,
+            to: None,
+            category_type: None,
+            page_size: None,
+            next_page_token: None,
+        }
+    }
 }
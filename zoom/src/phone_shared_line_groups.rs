@@ -1,6 +1,64 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`PhoneSharedLineGroups::list_shared_line_groups`], set with a builder.
+pub struct PhoneSharedLineGroupsListSharedLineGroupsRequest<'a> {
+    client: &'a PhoneSharedLineGroups,
+    page_size: Option<i64>,
+    next_page_token: Option<&'a str>,
+}
+
+impl<'a> PhoneSharedLineGroupsListSharedLineGroupsRequest<'a> {
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.next_page_token = Some(next_page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::SharedLineGroups>>> {
+        let Self {
+            client,
+            page_size,
+            next_page_token,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = next_page_token {
+            query_args.push(("next_page_token".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_size {
+            query_args.push(("page_size".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/phone/shared_line_groups?{}", query_), None);
+        let resp: crate::Response<crate::types::ListSharedLineGroupsResponse> = client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await?;
+
+        // Return our response data.
+        Ok(crate::Response::new(
+            resp.status,
+            resp.headers,
+            resp.body.shared_line_groups.to_vec(),
+        ))
+    }
+}
+
 pub struct PhoneSharedLineGroups {
     pub client: Client,
 }
@@ -66,6 +124,33 @@ impl PhoneSharedLineGroups {
             resp.body.shared_line_groups.to_vec(),
         ))
     }
+    /**
+     * List shared line groups.
+     *
+     * This function performs a `GET` to the `/phone/shared_line_groups` endpoint.
+     *
+     * As opposed to `list_shared_line_groups`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * A [shared line group](https://support.zoom.us/hc/en-us/articles/360038850792) allows Zoom Phone admins to share a phone number and extension with a group of phone users or common area phones. This gives members of the shared line group access to the group's direct phone number and voicemail. Use this API to list all the Shared Line Groups.
+     *
+     * **Prerequisties:** <br>
+     * * Pro or higher account with Zoom Phone license.
+     * * Account owner or admin privileges  <br>
+     *
+     * **Scopes:** `phone:read:admin`, `phone:write:admin`
+     *
+     *  
+     *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     */
+    pub fn list_shared_line_groups_request(
+        &self,
+    ) -> PhoneSharedLineGroupsListSharedLineGroupsRequest<'_> {
+        PhoneSharedLineGroupsListSharedLineGroupsRequest {
+            client: self,
+            page_size: None,
+            next_page_token: None,
+        }
+    }
     /**
      * List shared line groups.
      *
@@ -88,11 +173,11 @@ impl PhoneSharedLineGroups {
         &self,
     ) -> ClientResult<crate::Response<Vec<crate::types::SharedLineGroups>>> {
         let url = self.client.url("/phone/shared_line_groups", None);
-        let crate::Response::<crate::types::ListSharedLineGroupsResponse> {
-            mut status,
-            mut headers,
-            mut body,
-        } = self
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let mut pages = crate::utils::Pages::new(self.client.page_limits);
+        pages.visit(&url)?;
+
+        let mut response: crate::Response<crate::types::ListSharedLineGroupsResponse> = self
             .client
             .get(
                 &url,
@@ -102,56 +187,81 @@ impl PhoneSharedLineGroups {
                 },
             )
             .await?;
-
-        let mut shared_line_groups = body.shared_line_groups;
-        let mut page = body.next_page_token;
+        let mut shared_line_groups = Vec::new();
 
         // Paginate if we should.
-        while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                crate::Response::<crate::types::ListSharedLineGroupsResponse> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}?next_page_token={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
+        loop {
+            let page = if response.body.next_page_token.is_empty() {
+                None
             } else {
-                crate::Response::<crate::types::ListSharedLineGroupsResponse> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}&next_page_token={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
-
-            shared_line_groups.append(&mut body.shared_line_groups);
+                Some(format!(
+                    "{}{}next_page_token={}",
+                    url, sep, response.body.next_page_token
+                ))
+            };
+            let more = pages.keep(&mut response.body.shared_line_groups);
+            shared_line_groups.append(&mut response.body.shared_line_groups);
 
-            if !body.next_page_token.is_empty() && body.next_page_token != page {
-                page = body.next_page_token.to_string();
-            } else {
-                page = "".to_string();
-            }
+            let next = match page {
+                Some(next) if more && pages.visit(&next)? => next,
+                _ => break,
+            };
+            response = self
+                .client
+                .get(
+                    &next,
+                    crate::Message {
+                        body: None,
+                        content_type: None,
+                    },
+                )
+                .await?;
         }
 
         // Return our response data.
-        Ok(crate::Response::new(status, headers, shared_line_groups))
+        Ok(crate::Response::new(
+            response.status,
+            response.headers,
+            shared_line_groups,
+        ))
+    }
+    /**
+     * List shared line groups.
+     *
+     * This function performs a `GET` to the `/phone/shared_line_groups` endpoint.
+     *
+     * As opposed to `list_all_shared_line_groups`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * A [shared line group](https://support.zoom.us/hc/en-us/articles/360038850792) allows Zoom Phone admins to share a phone number and extension with a group of phone users or common area phones. This gives members of the shared line group access to the group's direct phone number and voicemail. Use this API to list all the Shared Line Groups.
+     *
+     * **Prerequisties:** <br>
+     * * Pro or higher account with Zoom Phone license.
+     * * Account owner or admin privileges  <br>
+     *
+     * **Scopes:** `phone:read:admin`, `phone:write:admin`
+     *
+     *  
+     *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     */
+    pub fn list_all_shared_line_groups_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::SharedLineGroups>> + Unpin + '_
+    {
+        let url = self.client.url("/phone/shared_line_groups", None);
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let next = format!("{}{}next_page_token=", url, sep);
+        self.client.get_pages_stream(
+            url,
+            move |body: crate::types::ListSharedLineGroupsResponse| {
+                let page = if body.next_page_token.is_empty() {
+                    None
+                } else {
+                    Some(format!("{}{}", next, body.next_page_token))
+                };
+
+                (body.shared_line_groups, page)
+            },
+        )
     }
     /**
      * Create a shared line group.
@@ -1,6 +1,109 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`PhoneSite::list`], set with a builder.
+pub struct PhoneSiteListRequest<'a> {
+    client: &'a PhoneSite,
+    page_size: Option<i64>,
+    next_page_token: Option<&'a str>,
+}
+
+impl<'a> PhoneSiteListRequest<'a> {
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.next_page_token = Some(next_page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Sites>>> {
+        let Self {
+            client,
+            page_size,
+            next_page_token,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = next_page_token {
+            query_args.push(("next_page_token".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_size {
+            query_args.push(("page_size".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(&format!("/phone/sites?{}", query_), None);
+        let resp: crate::Response<crate::types::ListPhoneSitesResponse> = client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await?;
+
+        // Return our response data.
+        Ok(crate::Response::new(
+            resp.status,
+            resp.headers,
+            resp.body.sites.to_vec(),
+        ))
+    }
+}
+
+/// The parameters of [`PhoneSite::delete`], set with a builder.
+pub struct PhoneSiteDeleteRequest<'a> {
+    client: &'a PhoneSite,
+    site_id: &'a str,
+    transfer_site_id: Option<&'a str>,
+}
+
+impl<'a> PhoneSiteDeleteRequest<'a> {
+    /// Sets the `transfer_site_id` query parameter.
+    pub fn transfer_site_id(mut self, transfer_site_id: &'a str) -> Self {
+        self.transfer_site_id = Some(transfer_site_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            site_id,
+            transfer_site_id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = transfer_site_id {
+            query_args.push(("transfer_site_id".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/phone/sites/{}?{}",
+                crate::progenitor_support::encode_path(site_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .delete(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct PhoneSite {
     pub client: Client,
 }
@@ -61,6 +164,28 @@ impl PhoneSite {
             resp.body.sites.to_vec(),
         ))
     }
+    /**
+     * List phone sites.
+     *
+     * This function performs a `GET` to the `/phone/sites` endpoint.
+     *
+     * As opposed to `list`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Sites allow you to organize Zoom Phone users in your organization. Use this API to list all the [sites](https://support.zoom.us/hc/en-us/articles/360020809672) that have been created for an account.<br>
+     * **Prerequisites:**<br>
+     * * Multiple Sites must be [enabled](https://support.zoom.us/hc/en-us/articles/360020809672-Managing-Multiple-Sites#h_05c88e35-1593-491f-b1a8-b7139a75dc15).
+     * * Pro or a higher account with Zoom Phone enabled.
+     *
+     * **Scope:** `phone:read:admin`<br>
+     *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     */
+    pub fn list_request(&self) -> PhoneSiteListRequest<'_> {
+        PhoneSiteListRequest {
+            client: self,
+            page_size: None,
+            next_page_token: None,
+        }
+    }
     /**
      * List phone sites.
      *
@@ -78,11 +203,11 @@ impl PhoneSite {
      */
     pub async fn list_all(&self) -> ClientResult<crate::Response<Vec<crate::types::Sites>>> {
         let url = self.client.url("/phone/sites", None);
-        let crate::Response::<crate::types::ListPhoneSitesResponse> {
-            mut status,
-            mut headers,
-            mut body,
-        } = self
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let mut pages = crate::utils::Pages::new(self.client.page_limits);
+        pages.visit(&url)?;
+
+        let mut response: crate::Response<crate::types::ListPhoneSitesResponse> = self
             .client
             .get(
                 &url,
@@ -92,56 +217,75 @@ impl PhoneSite {
                 },
             )
             .await?;
-
-        let mut sites = body.sites;
-        let mut page = body.next_page_token;
+        let mut sites = Vec::new();
 
         // Paginate if we should.
-        while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                crate::Response::<crate::types::ListPhoneSitesResponse> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}?next_page_token={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
+        loop {
+            let page = if response.body.next_page_token.is_empty() {
+                None
             } else {
-                crate::Response::<crate::types::ListPhoneSitesResponse> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}&next_page_token={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
+                Some(format!(
+                    "{}{}next_page_token={}",
+                    url, sep, response.body.next_page_token
+                ))
+            };
+            let more = pages.keep(&mut response.body.sites);
+            sites.append(&mut response.body.sites);
 
-            sites.append(&mut body.sites);
-
-            if !body.next_page_token.is_empty() && body.next_page_token != page {
-                page = body.next_page_token.to_string();
-            } else {
-                page = "".to_string();
-            }
+            let next = match page {
+                Some(next) if more && pages.visit(&next)? => next,
+                _ => break,
+            };
+            response = self
+                .client
+                .get(
+                    &next,
+                    crate::Message {
+                        body: None,
+                        content_type: None,
+                    },
+                )
+                .await?;
         }
 
         // Return our response data.
-        Ok(crate::Response::new(status, headers, sites))
+        Ok(crate::Response::new(
+            response.status,
+            response.headers,
+            sites,
+        ))
+    }
+    /**
+     * List phone sites.
+     *
+     * This function performs a `GET` to the `/phone/sites` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Sites allow you to organize Zoom Phone users in your organization. Use this API to list all the [sites](https://support.zoom.us/hc/en-us/articles/360020809672) that have been created for an account.<br>
+     * **Prerequisites:**<br>
+     * * Multiple Sites must be [enabled](https://support.zoom.us/hc/en-us/articles/360020809672-Managing-Multiple-Sites#h_05c88e35-1593-491f-b1a8-b7139a75dc15).
+     * * Pro or a higher account with Zoom Phone enabled.
+     *
+     * **Scope:** `phone:read:admin`<br>
+     *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     */
+    pub fn list_all_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Sites>> + Unpin + '_ {
+        let url = self.client.url("/phone/sites", None);
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let next = format!("{}{}next_page_token=", url, sep);
+        self.client
+            .get_pages_stream(url, move |body: crate::types::ListPhoneSitesResponse| {
+                let page = if body.next_page_token.is_empty() {
+                    None
+                } else {
+                    Some(format!("{}{}", next, body.next_page_token))
+                };
+
+                (body.sites, page)
+            })
     }
     /**
      * Create a phone site.
@@ -263,6 +407,31 @@ impl PhoneSite {
             )
             .await
     }
+    /**
+     * Delete a phone site.
+     *
+     * This function performs a `DELETE` to the `/phone/sites/{siteId}` endpoint.
+     *
+     * As opposed to `delete`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Sites allow you to organize Zoom Phone users in your organization. Use this API to delete a specific [site](https://support.zoom.us/hc/en-us/articles/360020809672) in a Zoom account. To delete a site, in the query parameter, you must provide the Site ID of another site where the assets of current site (users, numbers and phones) can be transferred to.  You cannot use this API to delete the main site.
+     *
+     * **Prerequisites:** <br>
+     * * Account must have a Pro or a higher plan with Zoom Phone license.
+     * * [Multiple Sites](https://support.zoom.us/hc/en-us/articles/360020809672-Managing-Multiple-Sites) must be enabled.<br>
+     * **Scope:** `phone:write:admin`
+     * <br>
+     *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Light`
+     *
+     *
+     */
+    pub fn delete_request<'a>(&'a self, site_id: &'a str) -> PhoneSiteDeleteRequest<'a> {
+        PhoneSiteDeleteRequest {
+            client: self,
+            site_id,
+            transfer_site_id: None,
+        }
+    }
     /**
      * Update phone site details.
      *
@@ -1,6 +1,656 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Reports::daily`], set with a builder.
+pub struct ReportsDailyRequest<'a> {
+    client: &'a Reports,
+    year: Option<i64>,
+    month: Option<i64>,
+}
+
+impl<'a> ReportsDailyRequest<'a> {
+    /// Sets the `year` query parameter.
+    pub fn year(mut self, year: i64) -> Self {
+        self.year = Some(year);
+        self
+    }
+
+    /// Sets the `month` query parameter.
+    pub fn month(mut self, month: i64) -> Self {
+        self.month = Some(month);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::ReportDailyResponse>> {
+        let Self {
+            client,
+            year,
+            month,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = month {
+            query_args.push(("month".to_string(), v.to_string()));
+        }
+        if let Some(v) = year {
+            query_args.push(("year".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/report/daily?{}", query_), None);
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Reports::user`], set with a builder.
+pub struct ReportsUserRequest<'a> {
+    client: &'a Reports,
+    type_: Option<crate::types::ReportUsersType>,
+    from: chrono::NaiveDate,
+    to: chrono::NaiveDate,
+    page_size: Option<i64>,
+    page_number: Option<i64>,
+    next_page_token: Option<&'a str>,
+}
+
+impl<'a> ReportsUserRequest<'a> {
+    /// Sets the `type` query parameter.
+    pub fn type_(mut self, type_: crate::types::ReportUsersType) -> Self {
+        self.type_ = Some(type_);
+        self
+    }
+
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Sets the `page_number` query parameter.
+    pub fn page_number(mut self, page_number: i64) -> Self {
+        self.page_number = Some(page_number);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.next_page_token = Some(next_page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::Domains>> {
+        let Self {
+            client,
+            type_,
+            from,
+            to,
+            page_size,
+            page_number,
+            next_page_token,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !from.to_string().is_empty() {
+            query_args.push(("from".to_string(), from.to_string()));
+        }
+        if let Some(v) = next_page_token {
+            query_args.push(("next_page_token".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_number {
+            query_args.push(("page_number".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_size {
+            query_args.push(("page_size".to_string(), v.to_string()));
+        }
+        if !to.to_string().is_empty() {
+            query_args.push(("to".to_string(), to.to_string()));
+        }
+        if let Some(v) = type_ {
+            query_args.push(("type".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/report/users?{}", query_), None);
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Reports::meeting`], set with a builder.
+pub struct ReportsMeetingRequest<'a> {
+    client: &'a Reports,
+    user_id: &'a str,
+    from: chrono::NaiveDate,
+    to: chrono::NaiveDate,
+    page_size: Option<i64>,
+    next_page_token: Option<&'a str>,
+    type_: Option<crate::types::ReportMeetingsType>,
+}
+
+impl<'a> ReportsMeetingRequest<'a> {
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.next_page_token = Some(next_page_token);
+        self
+    }
+
+    /// Sets the `type` query parameter.
+    pub fn type_(mut self, type_: crate::types::ReportMeetingsType) -> Self {
+        self.type_ = Some(type_);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::ReportMeetingsResponseAllOf>> {
+        let Self {
+            client,
+            user_id,
+            from,
+            to,
+            page_size,
+            next_page_token,
+            type_,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !from.to_string().is_empty() {
+            query_args.push(("from".to_string(), from.to_string()));
+        }
+        if let Some(v) = next_page_token {
+            query_args.push(("next_page_token".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_size {
+            query_args.push(("page_size".to_string(), v.to_string()));
+        }
+        if !to.to_string().is_empty() {
+            query_args.push(("to".to_string(), to.to_string()));
+        }
+        if let Some(v) = type_ {
+            query_args.push(("type".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/report/users/{}/meetings?{}",
+                crate::progenitor_support::encode_path(user_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Reports::meeting_participant`], set with a builder.
+pub struct ReportsMeetingParticipantRequest<'a> {
+    client: &'a Reports,
+    meeting_id: &'a str,
+    page_size: Option<i64>,
+    next_page_token: Option<&'a str>,
+    include_fields: Option<crate::types::DashboardMeetingParticipantsIncludeFields>,
+}
+
+impl<'a> ReportsMeetingParticipantRequest<'a> {
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.next_page_token = Some(next_page_token);
+        self
+    }
+
+    /// Sets the `include_fields` query parameter.
+    pub fn include_fields(
+        mut self,
+        include_fields: crate::types::DashboardMeetingParticipantsIncludeFields,
+    ) -> Self {
+        self.include_fields = Some(include_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::ReportMeetingParticipantsResponseAllOf>> {
+        let Self {
+            client,
+            meeting_id,
+            page_size,
+            next_page_token,
+            include_fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = include_fields {
+            query_args.push(("include_fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = next_page_token {
+            query_args.push(("next_page_token".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_size {
+            query_args.push(("page_size".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/report/meetings/{}/participants?{}",
+                crate::progenitor_support::encode_path(meeting_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Reports::webinar_participant`], set with a builder.
+pub struct ReportsWebinarParticipantRequest<'a> {
+    client: &'a Reports,
+    webinar_id: &'a str,
+    page_size: Option<i64>,
+    next_page_token: Option<&'a str>,
+    include_fields: Option<crate::types::DashboardMeetingParticipantsIncludeFields>,
+}
+
+impl<'a> ReportsWebinarParticipantRequest<'a> {
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.next_page_token = Some(next_page_token);
+        self
+    }
+
+    /// Sets the `include_fields` query parameter.
+    pub fn include_fields(
+        mut self,
+        include_fields: crate::types::DashboardMeetingParticipantsIncludeFields,
+    ) -> Self {
+        self.include_fields = Some(include_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::ReportWebinarParticipantsResponseAllOf>> {
+        let Self {
+            client,
+            webinar_id,
+            page_size,
+            next_page_token,
+            include_fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = include_fields {
+            query_args.push(("include_fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = next_page_token {
+            query_args.push(("next_page_token".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_size {
+            query_args.push(("page_size".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/report/webinars/{}/participants?{}",
+                crate::progenitor_support::encode_path(webinar_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Reports::telephone`], set with a builder.
+pub struct ReportsTelephoneRequest<'a> {
+    client: &'a Reports,
+    type_: Option<&'a str>,
+    query_date_type: Option<crate::types::QueryDateType>,
+    from: chrono::NaiveDate,
+    to: chrono::NaiveDate,
+    page_size: Option<i64>,
+    page_number: Option<i64>,
+    next_page_token: Option<&'a str>,
+}
+
+impl<'a> ReportsTelephoneRequest<'a> {
+    /// Sets the `type` query parameter.
+    pub fn type_(mut self, type_: &'a str) -> Self {
+        self.type_ = Some(type_);
+        self
+    }
+
+    /// Sets the `query_date_type` query parameter.
+    pub fn query_date_type(mut self, query_date_type: crate::types::QueryDateType) -> Self {
+        self.query_date_type = Some(query_date_type);
+        self
+    }
+
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Sets the `page_number` query parameter.
+    pub fn page_number(mut self, page_number: i64) -> Self {
+        self.page_number = Some(page_number);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.next_page_token = Some(next_page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::Domains>> {
+        let Self {
+            client,
+            type_,
+            query_date_type,
+            from,
+            to,
+            page_size,
+            page_number,
+            next_page_token,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !from.to_string().is_empty() {
+            query_args.push(("from".to_string(), from.to_string()));
+        }
+        if let Some(v) = next_page_token {
+            query_args.push(("next_page_token".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_number {
+            query_args.push(("page_number".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_size {
+            query_args.push(("page_size".to_string(), v.to_string()));
+        }
+        if let Some(v) = query_date_type {
+            query_args.push(("query_date_type".to_string(), v.to_string()));
+        }
+        if !to.to_string().is_empty() {
+            query_args.push(("to".to_string(), to.to_string()));
+        }
+        if let Some(v) = type_ {
+            query_args.push(("type".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/report/telephone?{}", query_), None);
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Reports::operation_log`], set with a builder.
+pub struct ReportsOperationLogRequest<'a> {
+    client: &'a Reports,
+    from: chrono::NaiveDate,
+    to: chrono::NaiveDate,
+    page_size: Option<i64>,
+    next_page_token: Option<&'a str>,
+    category_type: Option<crate::types::CategoryType>,
+}
+
+impl<'a> ReportsOperationLogRequest<'a> {
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.next_page_token = Some(next_page_token);
+        self
+    }
+
+    /// Sets the `category_type` query parameter.
+    pub fn category_type(mut self, category_type: crate::types::CategoryType) -> Self {
+        self.category_type = Some(category_type);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::ReportOperationLogsResponseAllOf>> {
+        let Self {
+            client,
+            from,
+            to,
+            page_size,
+            next_page_token,
+            category_type,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = category_type {
+            query_args.push(("category_type".to_string(), v.to_string()));
+        }
+        if !from.to_string().is_empty() {
+            query_args.push(("from".to_string(), from.to_string()));
+        }
+        if let Some(v) = next_page_token {
+            query_args.push(("next_page_token".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_size {
+            query_args.push(("page_size".to_string(), v.to_string()));
+        }
+        if !to.to_string().is_empty() {
+            query_args.push(("to".to_string(), to.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/report/operationlogs?{}", query_), None);
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Reports::sign_out_activities`], set with a builder.
+pub struct ReportsSignOutActivitiesRequest<'a> {
+    client: &'a Reports,
+    from: Option<chrono::NaiveDate>,
+    to: Option<chrono::NaiveDate>,
+    page_size: Option<i64>,
+    next_page_token: Option<&'a str>,
+}
+
+impl<'a> ReportsSignOutActivitiesRequest<'a> {
+    /// Sets the `from` query parameter.
+    pub fn from(mut self, from: chrono::NaiveDate) -> Self {
+        self.from = Some(from);
+        self
+    }
+
+    /// Sets the `to` query parameter.
+    pub fn to(mut self, to: chrono::NaiveDate) -> Self {
+        self.to = Some(to);
+        self
+    }
+
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.next_page_token = Some(next_page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::ActivityLogs>>> {
+        let Self {
+            client,
+            from,
+            to,
+            page_size,
+            next_page_token,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = from {
+            query_args.push(("from".to_string(), v.to_string()));
+        }
+        if let Some(v) = next_page_token {
+            query_args.push(("next_page_token".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_size {
+            query_args.push(("page_size".to_string(), v.to_string()));
+        }
+        if let Some(v) = to {
+            query_args.push(("to".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/report/activities?{}", query_), None);
+        let resp: crate::Response<crate::types::ReportSignInOutActivitiesResponse> = client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await?;
+
+        // Return our response data.
+        Ok(crate::Response::new(
+            resp.status,
+            resp.headers,
+            resp.body.activity_logs.to_vec(),
+        ))
+    }
+}
+
+/// The parameters of [`Reports::get_billing_invoices`], set with a builder.
+pub struct ReportsGetBillingInvoicesRequest<'a> {
+    client: &'a Reports,
+    billing_id: Option<&'a str>,
+}
+
+impl<'a> ReportsGetBillingInvoicesRequest<'a> {
+    /// Sets the `billing_id` query parameter.
+    pub fn billing_id(mut self, billing_id: &'a str) -> Self {
+        self.billing_id = Some(billing_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::GetBillingInvoicesReportsResponse>> {
+        let Self { client, billing_id } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = billing_id {
+            query_args.push(("billing_id".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/report/billing/invoices?{}", query_), None);
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct Reports {
     pub client: Client,
 }
@@ -52,6 +702,27 @@ impl Reports {
             )
             .await
     }
+    /**
+     * Get daily usage report.
+     *
+     * This function performs a `GET` to the `/report/daily` endpoint.
+     *
+     * As opposed to `daily`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Retrieve daily report to access the account-wide usage of Zoom services for each day in a given month. It lists the number of new users, meetings, participants, and meeting minutes.<br>
+     * **Prerequisites**<br>
+     * * Pro or higher plan.<br>
+     * **Scopes:** `report:read:admin`<br>
+     *  
+     *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Heavy`
+     */
+    pub fn daily_request(&self) -> ReportsDailyRequest<'_> {
+        ReportsDailyRequest {
+            client: self,
+            year: None,
+            month: None,
+        }
+    }
     /**
      * Get active/inactive host reports.
      *
@@ -114,6 +785,36 @@ impl Reports {
             )
             .await
     }
+    /**
+     * Get active/inactive host reports.
+     *
+     * This function performs a `GET` to the `/report/users` endpoint.
+     *
+     * As opposed to `user`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * A user is considered to be an active host during the month specified in the "from" and "to" range, if the user has hosted at least one meeting during this period. If the user didn't host any meetings during this period, the user is considered to be inactive.<br>The Active Hosts report displays a list of meetings, participants, and meeting minutes for a specific time range, up to one month. The month should fall within the last six months.<br>The Inactive Hosts report pulls a list of users who were not active during a specific period of time.
+     * Use this API to retrieve an active or inactive host report for a specified period of time. The time range for the report is limited to a month and the month should fall under the past six months. <br>You can specify the type of report and date range using the query parameters.<br>
+     * **Scopes:** `report:read:admin`<br>
+     *  
+     *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Heavy`<br>
+     * **Prerequisites:**<br>
+     * * Pro or higher plan.
+     */
+    pub fn user_request(
+        &self,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> ReportsUserRequest<'_> {
+        ReportsUserRequest {
+            client: self,
+            type_: None,
+            from,
+            to,
+            page_size: None,
+            page_number: None,
+            next_page_token: None,
+        }
+    }
     /**
      * Get meeting reports.
      *
@@ -181,6 +882,38 @@ impl Reports {
             )
             .await
     }
+    /**
+     * Get meeting reports.
+     *
+     * This function performs a `GET` to the `/report/users/{userId}/meetings` endpoint.
+     *
+     * As opposed to `meeting`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Retrieve [report](https://support.zoom.us/hc/en-us/articles/216378603-Meeting-Reporting) on past meetings and webinars for a specified time period. The time range for the report is limited to a month and the month must fall within the past six months.
+     *
+     * Meetings and webinars are returned only if they have two or more unique participants.  <br><br>
+     * **Scopes:** `report:read:admin`<br>
+     *  
+     *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Heavy`<br>
+     * **Prerequisites:**<br>
+     * * Pro or higher plan.
+     */
+    pub fn meeting_request<'a>(
+        &'a self,
+        user_id: &'a str,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> ReportsMeetingRequest<'a> {
+        ReportsMeetingRequest {
+            client: self,
+            user_id,
+            from,
+            to,
+            page_size: None,
+            next_page_token: None,
+            type_: None,
+        }
+    }
     /**
      * Get meeting detail reports.
      *
@@ -280,6 +1013,34 @@ impl Reports {
             )
             .await
     }
+    /**
+     * Get meeting participant reports.
+     *
+     * This function performs a `GET` to the `/report/meetings/{meetingId}/participants` endpoint.
+     *
+     * As opposed to `meeting_participant`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Use this API to return a report of a past meeting with two or more participants, including the host.
+     *
+     * To return a report for past meeting with only **one** participant, use the [List meeting participants](https://marketplace.zoom.us/docs/api-reference/zoom-api/dashboards/dashboardmeetingparticipants) API.
+     *
+     * **Scopes:** `report:read:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Heavy`
+     *
+     * **Prerequisites:**
+     * * Pro or a higher plan.
+     */
+    pub fn meeting_participant_request<'a>(
+        &'a self,
+        meeting_id: &'a str,
+    ) -> ReportsMeetingParticipantRequest<'a> {
+        ReportsMeetingParticipantRequest {
+            client: self,
+            meeting_id,
+            page_size: None,
+            next_page_token: None,
+            include_fields: None,
+        }
+    }
     /**
      * Get meeting poll reports.
      *
@@ -416,6 +1177,32 @@ impl Reports {
             )
             .await
     }
+    /**
+     * Get webinar participant reports.
+     *
+     * This function performs a `GET` to the `/report/webinars/{webinarId}/participants` endpoint.
+     *
+     * As opposed to `webinar_participant`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get detailed report on each attendee of a webinar.<br><br>
+     * **Scopes:** `report:read:admin`<br>
+     *  
+     *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Heavy`<br>
+     * **Prerequisites:**<br>
+     * * Pro or a higher plan with Webinar add-on enabled.
+     */
+    pub fn webinar_participant_request<'a>(
+        &'a self,
+        webinar_id: &'a str,
+    ) -> ReportsWebinarParticipantRequest<'a> {
+        ReportsWebinarParticipantRequest {
+            client: self,
+            webinar_id,
+            page_size: None,
+            next_page_token: None,
+            include_fields: None,
+        }
+    }
     /**
      * Get webinar poll reports.
      *
@@ -568,6 +1355,36 @@ impl Reports {
             )
             .await
     }
+    /**
+     * Get telephone reports.
+     *
+     * This function performs a `GET` to the `/report/telephone` endpoint.
+     *
+     * As opposed to `telephone`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * The [telephone report](https://support.zoom.us/hc/en-us/articles/206514816-Telephone-reports) allows you to view who dialed into meetings via phone (Audio Conferencing or SIP Connected Audio) and which number they dialed into and other details. Use this API to get telephone report for a specified period of time.
+     *
+     * **Scopes:** `report:read:admin`<br>
+     *  
+     *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Heavy`<br>**Prerequisites:**<br>
+     * * Pro or higher plan.
+     */
+    pub fn telephone_request(
+        &self,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> ReportsTelephoneRequest<'_> {
+        ReportsTelephoneRequest {
+            client: self,
+            type_: None,
+            query_date_type: None,
+            from,
+            to,
+            page_size: None,
+            page_number: None,
+            next_page_token: None,
+        }
+    }
     /**
      * Get cloud recording usage report.
      *
@@ -672,6 +1489,35 @@ impl Reports {
             )
             .await
     }
+    /**
+     * Get operation logs report.
+     *
+     * This function performs a `GET` to the `/report/operationlogs` endpoint.
+     *
+     * As opposed to `operation_log`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * The [Operations Logs](https://support.zoom.us/hc/en-us/articles/360032748331-Operation-Logs) report allows you to audit admin and user activity, such as adding a new user, changing account settings, and deleting recordings.<br>
+     * Use this API to retrieve operation logs report for a specified period of time.<br>
+     * **Scopes:** `report:read:admin`<br>
+     *  
+     *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Heavy`<br>
+     * **Prerequisites:**<br>
+     * * Pro or higher plan.
+     */
+    pub fn operation_log_request(
+        &self,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> ReportsOperationLogRequest<'_> {
+        ReportsOperationLogRequest {
+            client: self,
+            from,
+            to,
+            page_size: None,
+            next_page_token: None,
+            category_type: None,
+        }
+    }
     /**
      * Get sign In / sign out activity report.
      *
@@ -733,6 +1579,29 @@ impl Reports {
             resp.body.activity_logs.to_vec(),
         ))
     }
+    /**
+     * Get sign In / sign out activity report.
+     *
+     * This function performs a `GET` to the `/report/activities` endpoint.
+     *
+     * As opposed to `sign_out_activities`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Retrieve a list of sign in / sign out activity logs [report](https://support.zoom.us/hc/en-us/articles/201363213-Getting-Started-with-Reports) of users under a Zoom account.<br>
+     * **Prerequisites**<br>
+     * * Pro or higher plan.<br>
+     * **Scopes:** `report:read:admin`<br>
+     *  
+     *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Heavy`
+     */
+    pub fn sign_out_activities_request(&self) -> ReportsSignOutActivitiesRequest<'_> {
+        ReportsSignOutActivitiesRequest {
+            client: self,
+            from: None,
+            to: None,
+            page_size: None,
+            next_page_token: None,
+        }
+    }
     /**
      * Get sign In / sign out activity report.
      *
@@ -763,11 +1632,11 @@ impl Reports {
         let url = self
             .client
             .url(&format!("/report/activities?{}", query_), None);
-        let crate::Response::<crate::types::ReportSignInOutActivitiesResponse> {
-            mut status,
-            mut headers,
-            mut body,
-        } = self
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let mut pages = crate::utils::Pages::new(self.client.page_limits);
+        pages.visit(&url)?;
+
+        let mut response: crate::Response<crate::types::ReportSignInOutActivitiesResponse> = self
             .client
             .get(
                 &url,
@@ -777,56 +1646,88 @@ impl Reports {
                 },
             )
             .await?;
-
-        let mut activity_logs = body.activity_logs;
-        let mut page = body.next_page_token;
+        let mut activity_logs = Vec::new();
 
         // Paginate if we should.
-        while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                crate::Response::<crate::types::ReportSignInOutActivitiesResponse> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}?next_page_token={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            } else {
-                crate::Response::<crate::types::ReportSignInOutActivitiesResponse> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}&next_page_token={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
-
-            activity_logs.append(&mut body.activity_logs);
-
-            if !body.next_page_token.is_empty() && body.next_page_token != page {
-                page = body.next_page_token.to_string();
+        loop {
+            let page = if response.body.next_page_token.is_empty() {
+                None
             } else {
-                page = "".to_string();
-            }
+                Some(format!(
+                    "{}{}next_page_token={}",
+                    url, sep, response.body.next_page_token
+                ))
+            };
+            let more = pages.keep(&mut response.body.activity_logs);
+            activity_logs.append(&mut response.body.activity_logs);
+
+            let next = match page {
+                Some(next) if more && pages.visit(&next)? => next,
+                _ => break,
+            };
+            response = self
+                .client
+                .get(
+                    &next,
+                    crate::Message {
+                        body: None,
+                        content_type: None,
+                    },
+                )
+                .await?;
         }
 
         // Return our response data.
-        Ok(crate::Response::new(status, headers, activity_logs))
+        Ok(crate::Response::new(
+            response.status,
+            response.headers,
+            activity_logs,
+        ))
+    }
+    /**
+     * Get sign In / sign out activity report.
+     *
+     * This function performs a `GET` to the `/report/activities` endpoint.
+     *
+     * As opposed to `get_all_sign_out_activities`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Retrieve a list of sign in / sign out activity logs [report](https://support.zoom.us/hc/en-us/articles/201363213-Getting-Started-with-Reports) of users under a Zoom account.<br>
+     * **Prerequisites**<br>
+     * * Pro or higher plan.<br>
+     * **Scopes:** `report:read:admin`<br>
+     *  
+     *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Heavy`
+     */
+    pub fn get_all_sign_out_activities_stream(
+        &self,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::ActivityLogs>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !from.to_string().is_empty() {
+            query_args.push(("from".to_string(), from.to_string()));
+        }
+        if !to.to_string().is_empty() {
+            query_args.push(("to".to_string(), to.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/report/activities?{}", query_), None);
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let next = format!("{}{}next_page_token=", url, sep);
+        self.client.get_pages_stream(
+            url,
+            move |body: crate::types::ReportSignInOutActivitiesResponse| {
+                let page = if body.next_page_token.is_empty() {
+                    None
+                } else {
+                    Some(format!("{}{}", next, body.next_page_token))
+                };
+
+                (body.activity_logs, page)
+            },
+        )
     }
     /**
      * Get billing reports.
@@ -899,4 +1800,27 @@ impl Reports {
             )
             .await
     }
+    /**
+     * Get billing invoice reports.
+     *
+     * This function performs a `GET` to the `/report/billing/invoices` endpoint.
+     *
+     * As opposed to `get_billing_invoices`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get department billing invoices reports for a specific billing period. Provide the `billing_id` of the billing period for which you would like to retrieve the invoices for. This ID can be retrieved from **Get Billing Reports** API.
+     *
+     * **Prerequisites:**<br>
+     * * Pro or a higher account with Department Billing option enabled. Contact the Zoom Support team to enable this feature.
+     *
+     * **Scopes:** `report:read:admin`, `report:master`
+     *
+     *  
+     *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Heavy`
+     */
+    pub fn get_billing_invoices_request(&self) -> ReportsGetBillingInvoicesRequest<'_> {
+        ReportsGetBillingInvoicesRequest {
+            client: self,
+            billing_id: None,
+        }
+    }
 }
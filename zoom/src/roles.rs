@@ -1,6 +1,93 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Roles::members`], set with a builder.
+pub struct RolesMembersRequest<'a> {
+    client: &'a Roles,
+    role_id: &'a str,
+    page_count: Option<&'a str>,
+    page_number: Option<i64>,
+    next_page_token: Option<&'a str>,
+    page_size: Option<i64>,
+}
+
+impl<'a> RolesMembersRequest<'a> {
+    /// Sets the `page_count` query parameter.
+    pub fn page_count(mut self, page_count: &'a str) -> Self {
+        self.page_count = Some(page_count);
+        self
+    }
+
+    /// Sets the `page_number` query parameter.
+    pub fn page_number(mut self, page_number: i64) -> Self {
+        self.page_number = Some(page_number);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.next_page_token = Some(next_page_token);
+        self
+    }
+
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Domains>>> {
+        let Self {
+            client,
+            role_id,
+            page_count,
+            page_number,
+            next_page_token,
+            page_size,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = next_page_token {
+            query_args.push(("next_page_token".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_count {
+            query_args.push(("page_count".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_number {
+            query_args.push(("page_number".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_size {
+            query_args.push(("page_size".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/roles/{}/members?{}",
+                crate::progenitor_support::encode_path(role_id),
+                query_
+            ),
+            None,
+        );
+        let resp: crate::Response<crate::types::RoleMembersList> = client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await?;
+
+        // Return our response data.
+        Ok(crate::Response::new(
+            resp.status,
+            resp.headers,
+            resp.body.members.to_vec(),
+        ))
+    }
+}
+
 pub struct Roles {
     pub client: Client,
 }
@@ -139,6 +226,30 @@ impl Roles {
             resp.body.members.to_vec(),
         ))
     }
+    /**
+     * List members in a role.
+     *
+     * This function performs a `GET` to the `/roles/{roleId}/members` endpoint.
+     *
+     * As opposed to `members`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * User [roles](https://support.zoom.us/hc/en-us/articles/115001078646-Role-Based-Access-Control) can have a set of permissions that allows access only to the pages a user needs to view or edit. Use this API to list all the members that are assigned a specific role.
+     *
+     * **Scope:** `role:read:admin`<br>
+     *  
+     *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`<br>**Prerequisites:**<br>
+     * * A Pro or a higher plan.
+     */
+    pub fn members_request<'a>(&'a self, role_id: &'a str) -> RolesMembersRequest<'a> {
+        RolesMembersRequest {
+            client: self,
+            role_id,
+            page_count: None,
+            page_number: None,
+            next_page_token: None,
+            page_size: None,
+        }
+    }
     /**
      * List members in a role.
      *
@@ -171,11 +282,11 @@ impl Roles {
             ),
             None,
         );
-        let crate::Response::<crate::types::RoleMembersList> {
-            mut status,
-            mut headers,
-            mut body,
-        } = self
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let mut pages = crate::utils::Pages::new(self.client.page_limits);
+        pages.visit(&url)?;
+
+        let mut response: crate::Response<crate::types::RoleMembersList> = self
             .client
             .get(
                 &url,
@@ -185,56 +296,88 @@ impl Roles {
                 },
             )
             .await?;
-
-        let mut members = body.members;
-        let mut page = body.next_page_token;
+        let mut members = Vec::new();
 
         // Paginate if we should.
-        while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                crate::Response::<crate::types::RoleMembersList> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}?next_page_token={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
+        loop {
+            let page = if response.body.next_page_token.is_empty() {
+                None
             } else {
-                crate::Response::<crate::types::RoleMembersList> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}&next_page_token={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
-
-            members.append(&mut body.members);
+                Some(format!(
+                    "{}{}next_page_token={}",
+                    url, sep, response.body.next_page_token
+                ))
+            };
+            let more = pages.keep(&mut response.body.members);
+            members.append(&mut response.body.members);
 
-            if !body.next_page_token.is_empty() && body.next_page_token != page {
-                page = body.next_page_token.to_string();
-            } else {
-                page = "".to_string();
-            }
+            let next = match page {
+                Some(next) if more && pages.visit(&next)? => next,
+                _ => break,
+            };
+            response = self
+                .client
+                .get(
+                    &next,
+                    crate::Message {
+                        body: None,
+                        content_type: None,
+                    },
+                )
+                .await?;
         }
 
         // Return our response data.
-        Ok(crate::Response::new(status, headers, members))
+        Ok(crate::Response::new(
+            response.status,
+            response.headers,
+            members,
+        ))
+    }
+    /**
+     * List members in a role.
+     *
+     * This function performs a `GET` to the `/roles/{roleId}/members` endpoint.
+     *
+     * As opposed to `get_all_members`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * User [roles](https://support.zoom.us/hc/en-us/articles/115001078646-Role-Based-Access-Control) can have a set of permissions that allows access only to the pages a user needs to view or edit. Use this API to list all the members that are assigned a specific role.
+     *
+     * **Scope:** `role:read:admin`<br>
+     *  
+     *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`<br>**Prerequisites:**<br>
+     * * A Pro or a higher plan.
+     */
+    pub fn get_all_members_stream(
+        &self,
+        role_id: &str,
+        page_count: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Domains>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !page_count.is_empty() {
+            query_args.push(("page_count".to_string(), page_count.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/roles/{}/members?{}",
+                crate::progenitor_support::encode_path(role_id),
+                query_
+            ),
+            None,
+        );
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let next = format!("{}{}next_page_token=", url, sep);
+        self.client
+            .get_pages_stream(url, move |body: crate::types::RoleMembersList| {
+                let page = if body.next_page_token.is_empty() {
+                    None
+                } else {
+                    Some(format!("{}{}", next, body.next_page_token))
+                };
+
+                (body.members, page)
+            })
     }
     /**
      * Assign a role.
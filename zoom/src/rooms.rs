@@ -1,6 +1,282 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Rooms::list_zoom`], set with a builder.
+pub struct RoomsListZoomRequest<'a> {
+    client: &'a Rooms,
+    status: Option<crate::types::ListZoomRoomsStatus>,
+    type_: Option<crate::types::ListZoomRoomsType>,
+    unassigned_rooms: Option<bool>,
+    page_size: Option<i64>,
+    next_page_token: Option<&'a str>,
+    location_id: Option<&'a str>,
+}
+
+impl<'a> RoomsListZoomRequest<'a> {
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: crate::types::ListZoomRoomsStatus) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Sets the `type` query parameter.
+    pub fn type_(mut self, type_: crate::types::ListZoomRoomsType) -> Self {
+        self.type_ = Some(type_);
+        self
+    }
+
+    /// Sets the `unassigned_rooms` query parameter.
+    pub fn unassigned_rooms(mut self, unassigned_rooms: bool) -> Self {
+        self.unassigned_rooms = Some(unassigned_rooms);
+        self
+    }
+
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.next_page_token = Some(next_page_token);
+        self
+    }
+
+    /// Sets the `location_id` query parameter.
+    pub fn location_id(mut self, location_id: &'a str) -> Self {
+        self.location_id = Some(location_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ListZoomRoomsResponse>>> {
+        let Self {
+            client,
+            status,
+            type_,
+            unassigned_rooms,
+            page_size,
+            next_page_token,
+            location_id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = location_id {
+            query_args.push(("location_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = next_page_token {
+            query_args.push(("next_page_token".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_size {
+            query_args.push(("page_size".to_string(), v.to_string()));
+        }
+        if let Some(v) = status {
+            query_args.push(("status".to_string(), v.to_string()));
+        }
+        if let Some(v) = type_ {
+            query_args.push(("type".to_string(), v.to_string()));
+        }
+        if let Some(v) = unassigned_rooms {
+            query_args.push(("unassigned_rooms".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(&format!("/rooms?{}", query_), None);
+        let resp: crate::Response<crate::types::ListZoomRoomsResponseData> = client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await?;
+
+        // Return our response data.
+        Ok(crate::Response::new(
+            resp.status,
+            resp.headers,
+            resp.body.rooms.to_vec(),
+        ))
+    }
+}
+
+/// The parameters of [`Rooms::get_zr_setting`], set with a builder.
+pub struct RoomsGetZrSettingRequest<'a> {
+    client: &'a Rooms,
+    room_id: &'a str,
+    setting_type: Option<&'a str>,
+}
+
+impl<'a> RoomsGetZrSettingRequest<'a> {
+    /// Sets the `setting_type` query parameter.
+    pub fn setting_type(mut self, setting_type: &'a str) -> Self {
+        self.setting_type = Some(setting_type);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::Domains>> {
+        let Self {
+            client,
+            room_id,
+            setting_type,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = setting_type {
+            query_args.push(("setting_type".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/rooms/{}/settings?{}",
+                crate::progenitor_support::encode_path(room_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Rooms::update_zr_settings`], set with a builder.
+pub struct RoomsUpdateZrSettingsRequest<'a> {
+    client: &'a Rooms,
+    room_id: &'a str,
+    setting_type: Option<&'a str>,
+}
+
+impl<'a> RoomsUpdateZrSettingsRequest<'a> {
+    /// Sets the `setting_type` query parameter.
+    pub fn setting_type(mut self, setting_type: &'a str) -> Self {
+        self.setting_type = Some(setting_type);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            room_id,
+            setting_type,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = setting_type {
+            query_args.push(("setting_type".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/rooms/{}/settings?{}",
+                crate::progenitor_support::encode_path(room_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .patch(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Rooms::list_digital_signage_content`], set with a builder.
+pub struct RoomsListDigitalSignageContentRequest<'a> {
+    client: &'a Rooms,
+    type_: Option<&'a str>,
+    folder_id: Option<&'a str>,
+    page_size: Option<i64>,
+    next_page_token: Option<&'a str>,
+}
+
+impl<'a> RoomsListDigitalSignageContentRequest<'a> {
+    /// Sets the `type` query parameter.
+    pub fn type_(mut self, type_: &'a str) -> Self {
+        self.type_ = Some(type_);
+        self
+    }
+
+    /// Sets the `folder_id` query parameter.
+    pub fn folder_id(mut self, folder_id: &'a str) -> Self {
+        self.folder_id = Some(folder_id);
+        self
+    }
+
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.next_page_token = Some(next_page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Site>>> {
+        let Self {
+            client,
+            type_,
+            folder_id,
+            page_size,
+            next_page_token,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = folder_id {
+            query_args.push(("folder_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = next_page_token {
+            query_args.push(("next_page_token".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_size {
+            query_args.push(("page_size".to_string(), v.to_string()));
+        }
+        if let Some(v) = type_ {
+            query_args.push(("type".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/rooms/digital_signage?{}", query_), None);
+        let resp: crate::Response<crate::types::ListDigitalSignageContentResponse> = client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await?;
+
+        // Return our response data.
+        Ok(crate::Response::new(
+            resp.status,
+            resp.headers,
+            resp.body.contents.to_vec(),
+        ))
+    }
+}
+
 pub struct Rooms {
     pub client: Client,
 }
@@ -79,6 +355,30 @@ impl Rooms {
             resp.body.rooms.to_vec(),
         ))
     }
+    /**
+     * List Zoom Rooms.
+     *
+     * This function performs a `GET` to the `/rooms` endpoint.
+     *
+     * As opposed to `list_zoom`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Zoom Rooms is a software-based room system that provides an integrated experience for audio conferencing, wireless screen sharing and video conferencing. Use this API to list all the existing [Zoom Rooms](https://support.zoom.us/hc/en-us/articles/207483343-Getting-Started-with-Zoom-Rooms) in a Zoom account.<br><br>
+     * **Prerequisites:**<br>
+     * * Pro or a higher plan with [Zoom Room](https://zoom.us/zoomrooms) license.<br>
+     * **Scopes**: `room:read:admin`<br>
+     *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     */
+    pub fn list_zoom_request(&self) -> RoomsListZoomRequest<'_> {
+        RoomsListZoomRequest {
+            client: self,
+            status: None,
+            type_: None,
+            unassigned_rooms: None,
+            page_size: None,
+            next_page_token: None,
+            location_id: None,
+        }
+    }
     /**
      * List Zoom Rooms.
      *
@@ -114,11 +414,11 @@ impl Rooms {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/rooms?{}", query_), None);
-        let crate::Response::<crate::types::ListZoomRoomsResponseData> {
-            mut status,
-            mut headers,
-            mut body,
-        } = self
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let mut pages = crate::utils::Pages::new(self.client.page_limits);
+        pages.visit(&url)?;
+
+        let mut response: crate::Response<crate::types::ListZoomRoomsResponseData> = self
             .client
             .get(
                 &url,
@@ -128,56 +428,92 @@ impl Rooms {
                 },
             )
             .await?;
-
-        let mut rooms = body.rooms;
-        let mut page = body.next_page_token;
+        let mut rooms = Vec::new();
 
         // Paginate if we should.
-        while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                crate::Response::<crate::types::ListZoomRoomsResponseData> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}?next_page_token={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
+        loop {
+            let page = if response.body.next_page_token.is_empty() {
+                None
             } else {
-                crate::Response::<crate::types::ListZoomRoomsResponseData> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}&next_page_token={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
-
-            rooms.append(&mut body.rooms);
-
-            if !body.next_page_token.is_empty() && body.next_page_token != page {
-                page = body.next_page_token.to_string();
-            } else {
-                page = "".to_string();
-            }
+                Some(format!(
+                    "{}{}next_page_token={}",
+                    url, sep, response.body.next_page_token
+                ))
+            };
+            let more = pages.keep(&mut response.body.rooms);
+            rooms.append(&mut response.body.rooms);
+
+            let next = match page {
+                Some(next) if more && pages.visit(&next)? => next,
+                _ => break,
+            };
+            response = self
+                .client
+                .get(
+                    &next,
+                    crate::Message {
+                        body: None,
+                        content_type: None,
+                    },
+                )
+                .await?;
         }
 
         // Return our response data.
-        Ok(crate::Response::new(status, headers, rooms))
+        Ok(crate::Response::new(
+            response.status,
+            response.headers,
+            rooms,
+        ))
+    }
+    /**
+     * List Zoom Rooms.
+     *
+     * This function performs a `GET` to the `/rooms` endpoint.
+     *
+     * As opposed to `list_all_zoom`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Zoom Rooms is a software-based room system that provides an integrated experience for audio conferencing, wireless screen sharing and video conferencing. Use this API to list all the existing [Zoom Rooms](https://support.zoom.us/hc/en-us/articles/207483343-Getting-Started-with-Zoom-Rooms) in a Zoom account.<br><br>
+     * **Prerequisites:**<br>
+     * * Pro or a higher plan with [Zoom Room](https://zoom.us/zoomrooms) license.<br>
+     * **Scopes**: `room:read:admin`<br>
+     *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     */
+    pub fn list_all_zoom_stream(
+        &self,
+        status: crate::types::ListZoomRoomsStatus,
+        type_: crate::types::ListZoomRoomsType,
+        unassigned_rooms: bool,
+        location_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::ListZoomRoomsResponse>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !location_id.is_empty() {
+            query_args.push(("location_id".to_string(), location_id.to_string()));
+        }
+        if !status.to_string().is_empty() {
+            query_args.push(("status".to_string(), status.to_string()));
+        }
+        if !type_.to_string().is_empty() {
+            query_args.push(("type".to_string(), type_.to_string()));
+        }
+        if unassigned_rooms {
+            query_args.push(("unassigned_rooms".to_string(), unassigned_rooms.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/rooms?{}", query_), None);
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let next = format!("{}{}next_page_token=", url, sep);
+        self.client
+            .get_pages_stream(url, move |body: crate::types::ListZoomRoomsResponseData| {
+                let page = if body.next_page_token.is_empty() {
+                    None
+                } else {
+                    Some(format!("{}{}", next, body.next_page_token))
+                };
+
+                (body.rooms, page)
+            })
     }
     /**
      * Add a Zoom Room.
@@ -355,6 +691,28 @@ impl Rooms {
             )
             .await
     }
+    /**
+     * Get Zoom Room settings.
+     *
+     * This function performs a `GET` to the `/rooms/{roomId}/settings` endpoint.
+     *
+     * As opposed to `get_zr_setting`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get information on meeting or alert settings applied to a specific Zoom Room. By default, only **Meeting Settings** are returned. To view only **Alert Settings**, specify `alert` as the value of the `setting_type` query parameter.<br>
+     * **Prerequisites:**<br>
+     * * Zoom Room licenses
+     * * Owner or Admin privileges on the Zoom Account.<br>
+     * **Scopes:** `room:read:admin`
+     *  
+     *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`<br>
+     */
+    pub fn get_zr_setting_request<'a>(&'a self, room_id: &'a str) -> RoomsGetZrSettingRequest<'a> {
+        RoomsGetZrSettingRequest {
+            client: self,
+            room_id,
+            setting_type: None,
+        }
+    }
     /**
      * Update Zoom Room settings.
      *
@@ -402,6 +760,30 @@ impl Rooms {
             )
             .await
     }
+    /**
+     * Update Zoom Room settings.
+     *
+     * This function performs a `PATCH` to the `/rooms/{roomId}/settings` endpoint.
+     *
+     * As opposed to `update_zr_settings`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Update either meeting or alert settings applied to a specific Zoom Room. To update **Alert Settings**, specify `alert` as the value of the `setting_type` query parameter. To update **Meeting Settings**, specify `meeting` as the value of the `setting_type` query parameter.<br>
+     * **Prerequisites:**<br>
+     * * Zoom Room licenses
+     * * Owner or Admin privileges on the Zoom Account.<br>
+     * **Scopes:** `room:write:admin`<br>
+     *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     */
+    pub fn update_zr_settings_request<'a>(
+        &'a self,
+        room_id: &'a str,
+    ) -> RoomsUpdateZrSettingsRequest<'a> {
+        RoomsUpdateZrSettingsRequest {
+            client: self,
+            room_id,
+            setting_type: None,
+        }
+    }
     /**
      * List Zoom Room devices.
      *
@@ -582,6 +964,33 @@ impl Rooms {
             resp.body.contents.to_vec(),
         ))
     }
+    /**
+     * List digital signage contents.
+     *
+     * This function performs a `GET` to the `/rooms/digital_signage` endpoint.
+     *
+     * As opposed to `list_digital_signage_content`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * List information about existing [Zoom Rooms digital signage](https://support.zoom.us/hc/en-us/articles/360000030683-Zoom-Rooms-digital-signage) content in a Zoom account.<br> You can also access this information by logging into your Zoom account in the Zoom web portal and visiting the [Digital Signage Content](https://zoom.us/digitalsignage#/) page listed under **Room Management**.
+     *
+     * **Prerequisites:**
+     * * Pro or a higher account with Zoom Rooms.
+     * * Existing content files or folder in [Digital Signage Content](https://zoom.us/digitalsignage#/) page.
+     *
+     *
+     *
+     */
+    pub fn list_digital_signage_content_request(
+        &self,
+    ) -> RoomsListDigitalSignageContentRequest<'_> {
+        RoomsListDigitalSignageContentRequest {
+            client: self,
+            type_: None,
+            folder_id: None,
+            page_size: None,
+            next_page_token: None,
+        }
+    }
     /**
      * List digital signage contents.
      *
@@ -614,11 +1023,11 @@ impl Rooms {
         let url = self
             .client
             .url(&format!("/rooms/digital_signage?{}", query_), None);
-        let crate::Response::<crate::types::ListDigitalSignageContentResponse> {
-            mut status,
-            mut headers,
-            mut body,
-        } = self
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let mut pages = crate::utils::Pages::new(self.client.page_limits);
+        pages.visit(&url)?;
+
+        let mut response: crate::Response<crate::types::ListDigitalSignageContentResponse> = self
             .client
             .get(
                 &url,
@@ -628,56 +1037,90 @@ impl Rooms {
                 },
             )
             .await?;
-
-        let mut contents = body.contents;
-        let mut page = body.next_page_token;
+        let mut contents = Vec::new();
 
         // Paginate if we should.
-        while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                crate::Response::<crate::types::ListDigitalSignageContentResponse> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}?next_page_token={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            } else {
-                crate::Response::<crate::types::ListDigitalSignageContentResponse> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}&next_page_token={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
-
-            contents.append(&mut body.contents);
-
-            if !body.next_page_token.is_empty() && body.next_page_token != page {
-                page = body.next_page_token.to_string();
+        loop {
+            let page = if response.body.next_page_token.is_empty() {
+                None
             } else {
-                page = "".to_string();
-            }
+                Some(format!(
+                    "{}{}next_page_token={}",
+                    url, sep, response.body.next_page_token
+                ))
+            };
+            let more = pages.keep(&mut response.body.contents);
+            contents.append(&mut response.body.contents);
+
+            let next = match page {
+                Some(next) if more && pages.visit(&next)? => next,
+                _ => break,
+            };
+            response = self
+                .client
+                .get(
+                    &next,
+                    crate::Message {
+                        body: None,
+                        content_type: None,
+                    },
+                )
+                .await?;
         }
 
         // Return our response data.
-        Ok(crate::Response::new(status, headers, contents))
+        Ok(crate::Response::new(
+            response.status,
+            response.headers,
+            contents,
+        ))
+    }
+    /**
+     * List digital signage contents.
+     *
+     * This function performs a `GET` to the `/rooms/digital_signage` endpoint.
+     *
+     * As opposed to `list_all_digital_signage_content`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * List information about existing [Zoom Rooms digital signage](https://support.zoom.us/hc/en-us/articles/360000030683-Zoom-Rooms-digital-signage) content in a Zoom account.<br> You can also access this information by logging into your Zoom account in the Zoom web portal and visiting the [Digital Signage Content](https://zoom.us/digitalsignage#/) page listed under **Room Management**.
+     *
+     * **Prerequisites:**
+     * * Pro or a higher account with Zoom Rooms.
+     * * Existing content files or folder in [Digital Signage Content](https://zoom.us/digitalsignage#/) page.
+     *
+     *
+     *
+     */
+    pub fn list_all_digital_signage_content_stream(
+        &self,
+        type_: &str,
+        folder_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Site>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !folder_id.is_empty() {
+            query_args.push(("folder_id".to_string(), folder_id.to_string()));
+        }
+        if !type_.is_empty() {
+            query_args.push(("type".to_string(), type_.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/rooms/digital_signage?{}", query_), None);
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let next = format!("{}{}next_page_token=", url, sep);
+        self.client.get_pages_stream(
+            url,
+            move |body: crate::types::ListDigitalSignageContentResponse| {
+                let page = if body.next_page_token.is_empty() {
+                    None
+                } else {
+                    Some(format!("{}{}", next, body.next_page_token))
+                };
+
+                (body.contents, page)
+            },
+        )
     }
     /**
      * Update E911 digital signage.
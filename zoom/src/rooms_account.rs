@@ -1,6 +1,86 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`RoomsAccount::get_zr_account_setting`], set with a builder.
+pub struct RoomsAccountGetZrAccountSettingRequest<'a> {
+    client: &'a RoomsAccount,
+    setting_type: Option<crate::types::SettingType>,
+}
+
+impl<'a> RoomsAccountGetZrAccountSettingRequest<'a> {
+    /// Sets the `setting_type` query parameter.
+    pub fn setting_type(mut self, setting_type: crate::types::SettingType) -> Self {
+        self.setting_type = Some(setting_type);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::Domains>> {
+        let Self {
+            client,
+            setting_type,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = setting_type {
+            query_args.push(("setting_type".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/rooms/account_settings?{}", query_), None);
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`RoomsAccount::update_zoom_room_acc_settings`], set with a builder.
+pub struct RoomsAccountUpdateZoomRoomAccSettingsRequest<'a> {
+    client: &'a RoomsAccount,
+    setting_type: Option<&'a str>,
+}
+
+impl<'a> RoomsAccountUpdateZoomRoomAccSettingsRequest<'a> {
+    /// Sets the `setting_type` query parameter.
+    pub fn setting_type(mut self, setting_type: &'a str) -> Self {
+        self.setting_type = Some(setting_type);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::Domains>> {
+        let Self {
+            client,
+            setting_type,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = setting_type {
+            query_args.push(("setting_type".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/rooms/account_settings?{}", query_), None);
+        client
+            .client
+            .patch(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
 pub struct RoomsAccount {
     pub client: Client,
 }
@@ -107,6 +187,27 @@ impl RoomsAccount {
             )
             .await
     }
+    /**
+     * Get Zoom Room account settings.
+     *
+     * This function performs a `GET` to the `/rooms/account_settings` endpoint.
+     *
+     * As opposed to `get_zr_account_setting`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get details on Account Settings of a Zoom Room. With this API, you can view either the **Account Meeting Settings** or the **Alert Settings** (Client Alert Settings and Notfication Settings) of the Zoom Rooms account. By default, only **Account Meeting Settings** are returned. To view only **Alert Settings**, specify `alert` as the value of the `setting_type` query parameter.<br><br>
+     * **Prerequisites:**<br>
+     * * Zoom Room licenses
+     * * Owner or Admin privileges on the Zoom Account.<br>
+     * **Scopes:** `room:read:admin`<br><br>
+     *  
+     *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     */
+    pub fn get_zr_account_setting_request(&self) -> RoomsAccountGetZrAccountSettingRequest<'_> {
+        RoomsAccountGetZrAccountSettingRequest {
+            client: self,
+            setting_type: None,
+        }
+    }
     /**
      * Update Zoom Room account settings.
      *
@@ -148,4 +249,27 @@ impl RoomsAccount {
             )
             .await
     }
+    /**
+     * Update Zoom Room account settings.
+     *
+     * This function performs a `PATCH` to the `/rooms/account_settings` endpoint.
+     *
+     * As opposed to `update_zoom_room_acc_settings`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Update account settings applied for Zoom Rooms in a Zoom account. With this API, you can update either the **Account Meeting Settings** or the **Alert Settings** (Client Alert Settings and Notfication Settings) of the Zoom Rooms account by specifying the required setting type in the `setting_type` parameter. To update only **Alert Settings**, specify `alert` as the value of the `setting_type` query parameter and to update only **Account Meeting Settings**, specify `meeting` as the value of the `setting_type` query parameter.<br><br>
+     * **Prerequisites:**<br>
+     * * Zoom Room licenses
+     * * Owner or Admin privileges on the Zoom Account.<br>
+     * **Scopes:** `room:write:admin`<br>
+     *
+     *   **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     */
+    pub fn update_zoom_room_acc_settings_request(
+        &self,
+    ) -> RoomsAccountUpdateZoomRoomAccSettingsRequest<'_> {
+        RoomsAccountUpdateZoomRoomAccSettingsRequest {
+            client: self,
+            setting_type: None,
+        }
+    }
 }
@@ -1,6 +1,182 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`RoomsLocation::list_zr_locations`], set with a builder.
+pub struct RoomsLocationListZrLocationsRequest<'a> {
+    client: &'a RoomsLocation,
+    parent_location_id: Option<&'a str>,
+    type_: Option<&'a str>,
+    page_size: Option<i64>,
+    next_page_token: Option<&'a str>,
+}
+
+impl<'a> RoomsLocationListZrLocationsRequest<'a> {
+    /// Sets the `parent_location_id` query parameter.
+    pub fn parent_location_id(mut self, parent_location_id: &'a str) -> Self {
+        self.parent_location_id = Some(parent_location_id);
+        self
+    }
+
+    /// Sets the `type` query parameter.
+    pub fn type_(mut self, type_: &'a str) -> Self {
+        self.type_ = Some(type_);
+        self
+    }
+
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.next_page_token = Some(next_page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::AddAzrLocationResponse>>> {
+        let Self {
+            client,
+            parent_location_id,
+            type_,
+            page_size,
+            next_page_token,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = next_page_token {
+            query_args.push(("next_page_token".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_size {
+            query_args.push(("page_size".to_string(), v.to_string()));
+        }
+        if let Some(v) = parent_location_id {
+            query_args.push(("parent_location_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = type_ {
+            query_args.push(("type".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/rooms/locations?{}", query_), None);
+        let resp: crate::Response<crate::types::ListZrLocationsResponseData> = client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await?;
+
+        // Return our response data.
+        Ok(crate::Response::new(
+            resp.status,
+            resp.headers,
+            resp.body.locations.to_vec(),
+        ))
+    }
+}
+
+/// The parameters of [`RoomsLocation::get_zr_location_setting`], set with a builder.
+pub struct RoomsLocationGetZrLocationSettingRequest<'a> {
+    client: &'a RoomsLocation,
+    location_id: &'a str,
+    setting_type: Option<&'a str>,
+}
+
+impl<'a> RoomsLocationGetZrLocationSettingRequest<'a> {
+    /// Sets the `setting_type` query parameter.
+    pub fn setting_type(mut self, setting_type: &'a str) -> Self {
+        self.setting_type = Some(setting_type);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::Domains>> {
+        let Self {
+            client,
+            location_id,
+            setting_type,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = setting_type {
+            query_args.push(("setting_type".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/rooms/locations/{}/settings?{}",
+                crate::progenitor_support::encode_path(location_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`RoomsLocation::update_zr_location_settings`], set with a builder.
+pub struct RoomsLocationUpdateZrLocationSettingsRequest<'a> {
+    client: &'a RoomsLocation,
+    location_id: &'a str,
+    setting_type: Option<&'a str>,
+}
+
+impl<'a> RoomsLocationUpdateZrLocationSettingsRequest<'a> {
+    /// Sets the `setting_type` query parameter.
+    pub fn setting_type(mut self, setting_type: &'a str) -> Self {
+        self.setting_type = Some(setting_type);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            location_id,
+            setting_type,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = setting_type {
+            query_args.push(("setting_type".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/rooms/locations/{}/settings?{}",
+                crate::progenitor_support::encode_path(location_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .patch(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
 pub struct RoomsLocation {
     pub client: Client,
 }
@@ -77,6 +253,30 @@ impl RoomsLocation {
             resp.body.locations.to_vec(),
         ))
     }
+    /**
+     * List Zoom Room locations.
+     *
+     * This function performs a `GET` to the `/rooms/locations` endpoint.
+     *
+     * As opposed to `list_zr_locations`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * A Zoom account owner or a Zoom Room administrator can establish a [location hierarchy](https://support.zoom.us/hc/en-us/articles/115000342983-Zoom-Rooms-Location-Hierarchy) to help manage Zoom Rooms that are spread among a variety of locations. Use this API to list the different location types used for Zoom Rooms in an account.<br><br>
+     * **Prerequisites:**
+     * * Account owner or admin permissions.
+     * * Zoom Rooms Version 4.0 or higher<br><br>
+     * **Scopes:** `room:read:admin`<br>
+     *  
+     *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     */
+    pub fn list_zr_locations_request(&self) -> RoomsLocationListZrLocationsRequest<'_> {
+        RoomsLocationListZrLocationsRequest {
+            client: self,
+            parent_location_id: None,
+            type_: None,
+            page_size: None,
+            next_page_token: None,
+        }
+    }
     /**
      * List Zoom Room locations.
      *
@@ -111,11 +311,11 @@ impl RoomsLocation {
         let url = self
             .client
             .url(&format!("/rooms/locations?{}", query_), None);
-        let crate::Response::<crate::types::ListZrLocationsResponseData> {
-            mut status,
-            mut headers,
-            mut body,
-        } = self
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let mut pages = crate::utils::Pages::new(self.client.page_limits);
+        pages.visit(&url)?;
+
+        let mut response: crate::Response<crate::types::ListZrLocationsResponseData> = self
             .client
             .get(
                 &url,
@@ -125,56 +325,93 @@ impl RoomsLocation {
                 },
             )
             .await?;
-
-        let mut locations = body.locations;
-        let mut page = body.next_page_token;
+        let mut locations = Vec::new();
 
         // Paginate if we should.
-        while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                crate::Response::<crate::types::ListZrLocationsResponseData> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}?next_page_token={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
+        loop {
+            let page = if response.body.next_page_token.is_empty() {
+                None
             } else {
-                crate::Response::<crate::types::ListZrLocationsResponseData> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}&next_page_token={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
+                Some(format!(
+                    "{}{}next_page_token={}",
+                    url, sep, response.body.next_page_token
+                ))
+            };
+            let more = pages.keep(&mut response.body.locations);
+            locations.append(&mut response.body.locations);
 
-            locations.append(&mut body.locations);
-
-            if !body.next_page_token.is_empty() && body.next_page_token != page {
-                page = body.next_page_token.to_string();
-            } else {
-                page = "".to_string();
-            }
+            let next = match page {
+                Some(next) if more && pages.visit(&next)? => next,
+                _ => break,
+            };
+            response = self
+                .client
+                .get(
+                    &next,
+                    crate::Message {
+                        body: None,
+                        content_type: None,
+                    },
+                )
+                .await?;
         }
 
         // Return our response data.
-        Ok(crate::Response::new(status, headers, locations))
+        Ok(crate::Response::new(
+            response.status,
+            response.headers,
+            locations,
+        ))
+    }
+    /**
+     * List Zoom Room locations.
+     *
+     * This function performs a `GET` to the `/rooms/locations` endpoint.
+     *
+     * As opposed to `list_all_zr_locations`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * A Zoom account owner or a Zoom Room administrator can establish a [location hierarchy](https://support.zoom.us/hc/en-us/articles/115000342983-Zoom-Rooms-Location-Hierarchy) to help manage Zoom Rooms that are spread among a variety of locations. Use this API to list the different location types used for Zoom Rooms in an account.<br><br>
+     * **Prerequisites:**
+     * * Account owner or admin permissions.
+     * * Zoom Rooms Version 4.0 or higher<br><br>
+     * **Scopes:** `room:read:admin`<br>
+     *  
+     *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     */
+    pub fn list_all_zr_locations_stream(
+        &self,
+        parent_location_id: &str,
+        type_: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::AddAzrLocationResponse>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !parent_location_id.is_empty() {
+            query_args.push((
+                "parent_location_id".to_string(),
+                parent_location_id.to_string(),
+            ));
+        }
+        if !type_.is_empty() {
+            query_args.push(("type".to_string(), type_.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/rooms/locations?{}", query_), None);
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let next = format!("{}{}next_page_token=", url, sep);
+        self.client.get_pages_stream(
+            url,
+            move |body: crate::types::ListZrLocationsResponseData| {
+                let page = if body.next_page_token.is_empty() {
+                    None
+                } else {
+                    Some(format!("{}{}", next, body.next_page_token))
+                };
+
+                (body.locations, page)
+            },
+        )
     }
     /**
      * Add a location.
@@ -333,6 +570,30 @@ impl RoomsLocation {
             )
             .await
     }
+    /**
+     * Get location settings.
+     *
+     * This function performs a `GET` to the `/rooms/locations/{locationId}/settings` endpoint.
+     *
+     * As opposed to `get_zr_location_setting`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Get information on meeting or alert settings applied to Zoom Rooms located in a specific location. By default, only **Meeting Settings** are returned. To view only **Alert Settings**, specify `alert` as the value of the `setting_type` query parameter.<br><br>
+     * **Prerequisites:**<br>
+     * * Zoom Room licenses
+     * * Owner or Admin privileges on the Zoom Account.<br>
+     * **Scopes:** `room:read:admin`<br>
+     *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     */
+    pub fn get_zr_location_setting_request<'a>(
+        &'a self,
+        location_id: &'a str,
+    ) -> RoomsLocationGetZrLocationSettingRequest<'a> {
+        RoomsLocationGetZrLocationSettingRequest {
+            client: self,
+            location_id,
+            setting_type: None,
+        }
+    }
     /**
      * Update location settings.
      *
@@ -380,6 +641,30 @@ impl RoomsLocation {
             )
             .await
     }
+    /**
+     * Update location settings.
+     *
+     * This function performs a `PATCH` to the `/rooms/locations/{locationId}/settings` endpoint.
+     *
+     * As opposed to `update_zr_location_settings`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Update information on either meeting or alert settings applied to Zoom Rooms located in a specific location. To update **Alert Settings**, specify `alert` as the value of the `setting_type` query parameter. Similarly, to update **Meeting Settings**, specify `meeting` as the value of the `setting_type` query parameter.<br><br>
+     * **Prerequisites:**<br>
+     * * Zoom Room licenses
+     * * Owner or Admin privileges on the Zoom Account.<br>
+     * **Scopes:** `room:write:admin`<br>
+     *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     */
+    pub fn update_zr_location_settings_request<'a>(
+        &'a self,
+        location_id: &'a str,
+    ) -> RoomsLocationUpdateZrLocationSettingsRequest<'a> {
+        RoomsLocationUpdateZrLocationSettingsRequest {
+            client: self,
+            location_id,
+            setting_type: None,
+        }
+    }
     /**
      * Get Zoom Room location structure.
      *
@@ -1,6 +1,71 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`SipConnectedAudio::list_internal_numbers`], set with a builder.
+pub struct SipConnectedAudioListInternalNumbersRequest<'a> {
+    client: &'a SipConnectedAudio,
+    account_id: &'a str,
+    page_size: Option<i64>,
+    next_page_token: Option<&'a str>,
+}
+
+impl<'a> SipConnectedAudioListInternalNumbersRequest<'a> {
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.next_page_token = Some(next_page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::InternalNumbers>>> {
+        let Self {
+            client,
+            account_id,
+            page_size,
+            next_page_token,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = next_page_token {
+            query_args.push(("next_page_token".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_size {
+            query_args.push(("page_size".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/accounts/{}/sip_trunk/internal_numbers?{}",
+                crate::progenitor_support::encode_path(account_id),
+                query_
+            ),
+            None,
+        );
+        let resp: crate::Response<crate::types::ListInternalNumbersResponse> = client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await?;
+
+        // Return our response data.
+        Ok(crate::Response::new(
+            resp.status,
+            resp.headers,
+            resp.body.internal_numbers.to_vec(),
+        ))
+    }
+}
+
 pub struct SipConnectedAudio {
     pub client: Client,
 }
@@ -431,6 +496,32 @@ impl SipConnectedAudio {
             resp.body.internal_numbers.to_vec(),
         ))
     }
+    /**
+     * List internal numbers.
+     *
+     * This function performs a `GET` to the `/accounts/{accountId}/sip_trunk/internal_numbers` endpoint.
+     *
+     * As opposed to `list_internal_numbers`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * This API allows a master account with SIP Connected Audio plan to list internal phone numbers (i.e., numbers that are not provided by Zoom but are owned by the organization consuming the API) assigned to a master account or a sub account.<br><br>To list internal numbers of a sub account, provide the account ID of the sub account in the `accountId` path parameter. To list internal numbers of a  master account, provide `me` as the value of the `accountId` path parameter.
+     * <br><b>Prerequisites:</b><br>
+     * * The account making this API request must be a [master account](https://marketplace.zoom.us/docs/api-reference/master-account-apis) with SIP Connected Audio Plan.<br><br>
+     * **Scope:** `sip_trunk:master`
+     *
+     *
+     *
+     */
+    pub fn list_internal_numbers_request<'a>(
+        &'a self,
+        account_id: &'a str,
+    ) -> SipConnectedAudioListInternalNumbersRequest<'a> {
+        SipConnectedAudioListInternalNumbersRequest {
+            client: self,
+            account_id,
+            page_size: None,
+            next_page_token: None,
+        }
+    }
     /**
      * List internal numbers.
      *
@@ -457,11 +548,11 @@ impl SipConnectedAudio {
             ),
             None,
         );
-        let crate::Response::<crate::types::ListInternalNumbersResponse> {
-            mut status,
-            mut headers,
-            mut body,
-        } = self
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let mut pages = crate::utils::Pages::new(self.client.page_limits);
+        pages.visit(&url)?;
+
+        let mut response: crate::Response<crate::types::ListInternalNumbersResponse> = self
             .client
             .get(
                 &url,
@@ -471,56 +562,84 @@ impl SipConnectedAudio {
                 },
             )
             .await?;
-
-        let mut internal_numbers = body.internal_numbers;
-        let mut page = body.next_page_token;
+        let mut internal_numbers = Vec::new();
 
         // Paginate if we should.
-        while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                crate::Response::<crate::types::ListInternalNumbersResponse> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}?next_page_token={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
+        loop {
+            let page = if response.body.next_page_token.is_empty() {
+                None
             } else {
-                crate::Response::<crate::types::ListInternalNumbersResponse> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}&next_page_token={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
-
-            internal_numbers.append(&mut body.internal_numbers);
+                Some(format!(
+                    "{}{}next_page_token={}",
+                    url, sep, response.body.next_page_token
+                ))
+            };
+            let more = pages.keep(&mut response.body.internal_numbers);
+            internal_numbers.append(&mut response.body.internal_numbers);
 
-            if !body.next_page_token.is_empty() && body.next_page_token != page {
-                page = body.next_page_token.to_string();
-            } else {
-                page = "".to_string();
-            }
+            let next = match page {
+                Some(next) if more && pages.visit(&next)? => next,
+                _ => break,
+            };
+            response = self
+                .client
+                .get(
+                    &next,
+                    crate::Message {
+                        body: None,
+                        content_type: None,
+                    },
+                )
+                .await?;
         }
 
         // Return our response data.
-        Ok(crate::Response::new(status, headers, internal_numbers))
+        Ok(crate::Response::new(
+            response.status,
+            response.headers,
+            internal_numbers,
+        ))
+    }
+    /**
+     * List internal numbers.
+     *
+     * This function performs a `GET` to the `/accounts/{accountId}/sip_trunk/internal_numbers` endpoint.
+     *
+     * As opposed to `list_all_internal_numbers`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * This API allows a master account with SIP Connected Audio plan to list internal phone numbers (i.e., numbers that are not provided by Zoom but are owned by the organization consuming the API) assigned to a master account or a sub account.<br><br>To list internal numbers of a sub account, provide the account ID of the sub account in the `accountId` path parameter. To list internal numbers of a  master account, provide `me` as the value of the `accountId` path parameter.
+     * <br><b>Prerequisites:</b><br>
+     * * The account making this API request must be a [master account](https://marketplace.zoom.us/docs/api-reference/master-account-apis) with SIP Connected Audio Plan.<br><br>
+     * **Scope:** `sip_trunk:master`
+     *
+     *
+     *
+     */
+    pub fn list_all_internal_numbers_stream(
+        &self,
+        account_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::InternalNumbers>> + Unpin + '_ {
+        let url = self.client.url(
+            &format!(
+                "/accounts/{}/sip_trunk/internal_numbers",
+                crate::progenitor_support::encode_path(account_id),
+            ),
+            None,
+        );
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let next = format!("{}{}next_page_token=", url, sep);
+        self.client.get_pages_stream(
+            url,
+            move |body: crate::types::ListInternalNumbersResponse| {
+                let page = if body.next_page_token.is_empty() {
+                    None
+                } else {
+                    Some(format!("{}{}", next, body.next_page_token))
+                };
+
+                (body.internal_numbers, page)
+            },
+        )
     }
     /**
      * Add internal numbers.
@@ -12,8 +12,9 @@ pub enum PayMode {
     Master,
     #[serde(rename = "sub")]
     Sub,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PayMode {
@@ -21,7 +22,7 @@ impl std::fmt::Display for PayMode {
         match self {
             PayMode::Master => "master",
             PayMode::Sub => "sub",
-            PayMode::FallthroughString => "*",
+            PayMode::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -77,6 +78,10 @@ pub struct Options {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub share_rc: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The account object represents an account on Zoom. The account owner is the user who created the account or had an account created for them. You can read more about the Zoom account structure <a href='https://medium.com/zoom-developer-blog/a-brief-look-at-zoom-account-structures-1d19c745bf8a' target='_blank'>here</a>.
@@ -132,6 +137,10 @@ pub struct Account {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub vanity_url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -217,6 +226,10 @@ pub struct Accounts {
         deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub subscription_start_time: Option<chrono::DateTime<chrono::Utc>>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Account object in the account list.
@@ -231,10 +244,19 @@ pub struct AccountListItem {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub accounts: Vec<Accounts>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Domains {}
+pub struct Domains {
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
 
 /// Account plan object.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -258,6 +280,10 @@ pub struct AccountPlan {
         rename = "type"
     )]
     pub type_: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Account base plan object.
@@ -282,6 +308,10 @@ pub struct PlanBase {
         rename = "type"
     )]
     pub type_: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Additional audio conferencing <a href="https://marketplace.zoom.us/docs/api-reference/other-references/plans#audio-conferencing-plans">plan type</a>.
@@ -333,6 +363,10 @@ pub struct PlanAudio {
         rename = "type"
     )]
     pub type_: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Additional phone base plans.
@@ -357,6 +391,10 @@ pub struct AccountPlansPhonePlanBase {
         rename = "type"
     )]
     pub type_: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -380,6 +418,10 @@ pub struct PlanNumber {
         rename = "type"
     )]
     pub type_: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Phone Plan Object
@@ -408,6 +450,10 @@ pub struct PhonePlan {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub plan_number: Vec<PlanNumber>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Account Plans object.
@@ -464,6 +510,10 @@ pub struct AccountPlans {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub plan_zoom_rooms: Option<AccountPlan>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -480,8 +530,9 @@ pub enum AudioType {
     ThirdParty,
     #[serde(rename = "voip")]
     Voip,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AudioType {
@@ -491,7 +542,7 @@ impl std::fmt::Display for AudioType {
             AudioType::Telephony => "telephony",
             AudioType::ThirdParty => "thirdParty",
             AudioType::Voip => "voip",
-            AudioType::FallthroughString => "*",
+            AudioType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -512,8 +563,9 @@ pub enum RequirePasswordPmiMeetings {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for RequirePasswordPmiMeetings {
@@ -523,7 +575,7 @@ impl std::fmt::Display for RequirePasswordPmiMeetings {
             RequirePasswordPmiMeetings::JbhOnly => "jbh_only",
             RequirePasswordPmiMeetings::None => "none",
             RequirePasswordPmiMeetings::Noop => "",
-            RequirePasswordPmiMeetings::FallthroughString => "*",
+            RequirePasswordPmiMeetings::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -604,6 +656,10 @@ pub struct MeetingPasswordRequirement {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub weak_enhance_detection: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Account Settings: Schedule Meeting.
@@ -746,6 +802,10 @@ pub struct ScheduleMeeting {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub use_pmi_for_scheduled_meetings: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -762,8 +822,9 @@ pub enum EntryExitChime {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EntryExitChime {
@@ -773,7 +834,7 @@ impl std::fmt::Display for EntryExitChime {
             EntryExitChime::Host => "host",
             EntryExitChime::None => "none",
             EntryExitChime::Noop => "",
-            EntryExitChime::FallthroughString => "*",
+            EntryExitChime::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -832,6 +893,10 @@ pub struct Files {
         rename = "type"
     )]
     pub type_: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Settings to manage virtual background.
@@ -870,6 +935,10 @@ pub struct VirtualBackgroundSettings {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub files: Vec<Files>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -887,8 +956,9 @@ pub enum WhoCanShareScreen {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for WhoCanShareScreen {
@@ -897,7 +967,7 @@ impl std::fmt::Display for WhoCanShareScreen {
             WhoCanShareScreen::All => "all",
             WhoCanShareScreen::Host => "host",
             WhoCanShareScreen::Noop => "",
-            WhoCanShareScreen::FallthroughString => "*",
+            WhoCanShareScreen::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1332,6 +1402,10 @@ pub struct InMeeting {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub workplace_by_facebook: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Account Settings: Notification.
@@ -1385,6 +1459,10 @@ pub struct EmailNotification {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub schedule_for_reminder: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Account Settings: Zoom Rooms.
@@ -1470,6 +1548,10 @@ pub struct ZoomRooms {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub zr_post_meeting_feedback: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// This object refers to the [enhanced password rules](https://support.zoom.us/hc/en-us/articles/360034675592-Advanced-security-settings#h_bf8a25f6-9a66-447a-befd-f02ed3404f89) that allows Zoom account admins and owners to apply extra requiremets to the users' Zoom login password.
@@ -1509,6 +1591,10 @@ pub struct PasswordRequirement {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub weak_enhance_detection: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -1533,8 +1619,9 @@ pub enum SignInWithTwoFactorAuth {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SignInWithTwoFactorAuth {
@@ -1545,7 +1632,7 @@ impl std::fmt::Display for SignInWithTwoFactorAuth {
             SignInWithTwoFactorAuth::None => "none",
             SignInWithTwoFactorAuth::Role => "role",
             SignInWithTwoFactorAuth::Noop => "",
-            SignInWithTwoFactorAuth::FallthroughString => "*",
+            SignInWithTwoFactorAuth::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1630,6 +1717,10 @@ pub struct Security {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub sign_in_with_two_factor_auth_roles: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -1646,8 +1737,9 @@ pub enum AutoRecording {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AutoRecording {
@@ -1657,7 +1749,7 @@ impl std::fmt::Display for AutoRecording {
             AutoRecording::Local => "local",
             AutoRecording::None => "none",
             AutoRecording::Noop => "",
-            AutoRecording::FallthroughString => "*",
+            AutoRecording::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -1713,6 +1805,10 @@ pub struct RecordingPasswordRequirement {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub only_allow_numeric: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Setting to allow cloud recording access only from specific IP address ranges.
@@ -1738,6 +1834,10 @@ pub struct IpAddressAccessControl {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub ip_addresses_or_ranges: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -1782,6 +1882,10 @@ pub struct Settings {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub video_file: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// [Archiving solution](https://support.zoom.us/hc/en-us/articles/360050431572-Archiving-Meeting-and-Webinar-data) settings. This setting can only be used if you have been granted with archiving solution access by the Zoom support team.
@@ -1810,6 +1914,10 @@ pub struct Archive {
         rename = "type"
     )]
     pub type_: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Account Settings: Recording.
@@ -1988,6 +2096,10 @@ pub struct Recording {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub show_timestamp: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Indicates where most of the participants call into or call from duriing a meeting.
@@ -2011,6 +2123,10 @@ pub struct TelephonyRegions {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub selection_values: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Account Settings: Telephony.
@@ -2038,6 +2154,10 @@ pub struct Telephony {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub third_party_audio: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Account Settings: Integration.
@@ -2092,6 +2212,10 @@ pub struct Integration {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub microsoft_one_drive: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Account Settings: Feature.
@@ -2106,6 +2230,10 @@ pub struct Feature {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub meeting_capacity: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Account Settings: TSP.
@@ -2144,6 +2272,10 @@ pub struct Tsp {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub show_international_numbers_link: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -2169,12 +2301,20 @@ pub struct RecordingStorageLocation {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub value: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Profile {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub recording_storage_location: Option<RecordingStorageLocation>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -2201,6 +2341,10 @@ pub struct AccountSettings {
     pub tsp: Option<Tsp>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub zoom_rooms: Option<ZoomRooms>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Indicates where most of the participants call into or call from duriing a meeting.
@@ -2215,6 +2359,10 @@ pub struct UpdateAccountSettingsTelephonyRegions {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub selection_values: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Account Settings Update: Telephony.
@@ -2242,6 +2390,10 @@ pub struct UpdateAccountSettingsTelephony {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub third_party_audio: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -2268,6 +2420,10 @@ pub struct UpdateAccountSettings {
     pub tsp: Option<Tsp>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub zoom_rooms: Option<ZoomRooms>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// List of managed domains.
@@ -2291,6 +2447,10 @@ pub struct DomainsList {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_records: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Billing Contact object.
@@ -2386,6 +2546,10 @@ pub struct BillingContact {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub zip: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Billing Contact object.
@@ -2481,6 +2645,10 @@ pub struct Contact {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub zip: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Custom Question.
@@ -2504,6 +2672,10 @@ pub struct CustomQuestion {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub value: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// DateTime Object.
@@ -2527,6 +2699,10 @@ pub struct DateTime {
         deserialize_with = "crate::utils::date_format::deserialize"
     )]
     pub to: Option<chrono::NaiveDate>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -2541,8 +2717,9 @@ pub enum Protocol {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Protocol {
@@ -2551,7 +2728,7 @@ impl std::fmt::Display for Protocol {
             Protocol::H323 => "H.323",
             Protocol::Sip => "SIP",
             Protocol::Noop => "",
-            Protocol::FallthroughString => "*",
+            Protocol::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2577,8 +2754,9 @@ pub enum Encryption {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Encryption {
@@ -2588,7 +2766,7 @@ impl std::fmt::Display for Encryption {
             Encryption::No => "no",
             Encryption::Yes => "yes",
             Encryption::Noop => "",
-            Encryption::FallthroughString => "*",
+            Encryption::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2631,6 +2809,10 @@ pub struct Device {
      */
     #[serde(default, skip_serializing_if = "Protocol::is_noop")]
     pub protocol: Protocol,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Group object.
@@ -2654,6 +2836,10 @@ pub struct Group {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_members: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -2667,6 +2853,10 @@ pub struct Groups {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types are flattened into one object:
@@ -2706,6 +2896,10 @@ pub struct GroupList {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_records: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Group member object.
@@ -2757,6 +2951,10 @@ pub struct GroupMember {
         rename = "type"
     )]
     pub type_: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -2787,6 +2985,10 @@ pub struct TrackingFields {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub visible: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Occurence object. This object is only returned for Recurring Webinars.
@@ -2828,6 +3030,10 @@ pub struct Occurrence {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub status: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -2842,8 +3048,9 @@ pub enum Audio {
     Telephony,
     #[serde(rename = "voip")]
     Voip,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Audio {
@@ -2852,7 +3059,7 @@ impl std::fmt::Display for Audio {
             Audio::Both => "both",
             Audio::Telephony => "telephony",
             Audio::Voip => "voip",
-            Audio::FallthroughString => "*",
+            Audio::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2870,8 +3077,9 @@ pub enum Type {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Type {
@@ -2880,7 +3088,7 @@ impl std::fmt::Display for Type {
             Type::Toll => "toll",
             Type::Tollfree => "tollfree",
             Type::Noop => "",
-            Type::FallthroughString => "*",
+            Type::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2935,6 +3143,10 @@ pub struct GlobalDialInNumbers {
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
     pub type_: Option<Type>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -2952,8 +3164,9 @@ pub enum EncryptionType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EncryptionType {
@@ -2962,7 +3175,7 @@ impl std::fmt::Display for EncryptionType {
             EncryptionType::E2Ee => "e2ee",
             EncryptionType::EnhancedEncryption => "enhanced_encryption",
             EncryptionType::Noop => "",
-            EncryptionType::FallthroughString => "*",
+            EncryptionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -2988,8 +3201,9 @@ pub enum Method {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Method {
@@ -2998,7 +3212,7 @@ impl std::fmt::Display for Method {
             Method::Approve => "approve",
             Method::Deny => "deny",
             Method::Noop => "",
-            Method::FallthroughString => "*",
+            Method::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3049,6 +3263,10 @@ pub struct ApprovedDeniedCountriesRegions {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub method: Option<Method>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -3071,6 +3289,10 @@ pub struct AuthenticationException {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -3096,6 +3318,10 @@ pub struct Rooms {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub participants: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Setting to [pre-assign breakout rooms](https://support.zoom.us/hc/en-us/articles/360032752671-Pre-assigning-participants-to-breakout-rooms#h_36f71353-4190-48a2-b999-ca129861c1f4).
@@ -3118,6 +3344,10 @@ pub struct BreakoutRoom {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub rooms: Vec<Rooms>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -3140,6 +3370,10 @@ pub struct Interpreters {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub languages: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -3161,6 +3395,10 @@ pub struct LanguageInterpretation {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub interpreters: Vec<Interpreters>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -3183,6 +3421,10 @@ pub struct CustomKeys {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub value: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Meeting settings.
@@ -3480,6 +3722,10 @@ pub struct MeetingSettings {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub watermark: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -3504,8 +3750,9 @@ pub enum WeeklyDays {
     Six,
     #[serde(rename = "7")]
     Seven,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for WeeklyDays {
@@ -3518,7 +3765,7 @@ impl std::fmt::Display for WeeklyDays {
             WeeklyDays::Five => "5",
             WeeklyDays::Six => "6",
             WeeklyDays::Seven => "7",
-            WeeklyDays::FallthroughString => "*",
+            WeeklyDays::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3596,6 +3843,10 @@ pub struct Recurrence {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub weekly_days: Option<WeeklyDays>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Meeting object
@@ -3747,6 +3998,10 @@ pub struct MeetingInfo {
         rename = "type"
     )]
     pub type_: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -3761,8 +4016,9 @@ pub enum Status {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Status {
@@ -3771,7 +4027,7 @@ impl std::fmt::Display for Status {
             Status::Started => "started",
             Status::Waiting => "waiting",
             Status::Noop => "",
-            Status::FallthroughString => "*",
+            Status::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -3946,6 +4202,10 @@ pub struct MeetingInfoGet {
         rename = "type"
     )]
     pub type_: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Meeting metric details.
@@ -4124,6 +4384,10 @@ pub struct MeetingMetric {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub uuid: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Meeting invitation details.
@@ -4138,6 +4402,10 @@ pub struct MeetingInvitation {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub invitation: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Meeting live stream.
@@ -4170,6 +4438,10 @@ pub struct MeetingLiveStream {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub stream_url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -4188,8 +4460,9 @@ pub enum Action {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Action {
@@ -4198,7 +4471,7 @@ impl std::fmt::Display for Action {
             Action::Start => "start",
             Action::Stop => "stop",
             Action::Noop => "",
-            Action::FallthroughString => "*",
+            Action::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -4230,6 +4503,10 @@ pub struct MeetingLiveStreamStatusSettings {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub display_name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Meeting live stream status.
@@ -4245,6 +4522,10 @@ pub struct MeetingLiveStreamStatus {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub settings: Option<MeetingLiveStreamStatusSettings>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Update the live stream session's settings. You can **only** update settings for a stopped live stream.
@@ -4267,6 +4548,10 @@ pub struct WebinarLiveStreamStatusSettings {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub display_name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Webinar live stream status.
@@ -4282,6 +4567,10 @@ pub struct WebinarLiveStreamStatus {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub settings: Option<WebinarLiveStreamStatusSettings>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Pagination Object.
@@ -4332,6 +4621,10 @@ pub struct Pagination {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_records: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Pagination object.
@@ -4373,6 +4666,10 @@ pub struct PaginationToken {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_records: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Pagination object.
@@ -4396,6 +4693,10 @@ pub struct PaginationToken4ImChat {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub page_size: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Panelist base object.
@@ -4419,6 +4720,10 @@ pub struct Panelist {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -4432,6 +4737,10 @@ pub struct Panelists {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub join_url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types are flattened into one object:
@@ -4474,6 +4783,10 @@ pub struct PanelistList {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_records: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Quality of Service object.
@@ -4524,6 +4837,10 @@ pub struct QosAudio {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub max_loss: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -4536,8 +4853,9 @@ pub enum RecordingStatus {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for RecordingStatus {
@@ -4545,7 +4863,7 @@ impl std::fmt::Display for RecordingStatus {
         match self {
             RecordingStatus::Completed => "completed",
             RecordingStatus::Noop => "",
-            RecordingStatus::FallthroughString => "*",
+            RecordingStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -4655,6 +4973,10 @@ pub struct RecordingData {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub status: Option<RecordingStatus>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The recording file object.
@@ -4737,6 +5059,10 @@ pub struct RecordingSeparateAudio {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub status: Option<RecordingStatus>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -4753,8 +5079,9 @@ pub enum ShareRecording {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ShareRecording {
@@ -4764,7 +5091,7 @@ impl std::fmt::Display for ShareRecording {
             ShareRecording::None => "none",
             ShareRecording::Publicly => "publicly",
             ShareRecording::Noop => "",
-            ShareRecording::FallthroughString => "*",
+            ShareRecording::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -4835,6 +5162,10 @@ pub struct RecordingSettings {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub viewer_download: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Registrant Status
@@ -4856,6 +5187,10 @@ pub struct RecordingRegistrantStatus {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub registrants: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Registrant base object.
@@ -5014,6 +5349,10 @@ pub struct Registrant {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub zip: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -5030,8 +5369,9 @@ pub enum RegistrantStatusAction {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for RegistrantStatusAction {
@@ -5041,7 +5381,7 @@ impl std::fmt::Display for RegistrantStatusAction {
             RegistrantStatusAction::Cancel => "cancel",
             RegistrantStatusAction::Deny => "deny",
             RegistrantStatusAction::Noop => "",
-            RegistrantStatusAction::FallthroughString => "*",
+            RegistrantStatusAction::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -5073,6 +5413,10 @@ pub struct Assistants {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -5091,6 +5435,10 @@ pub struct RegistrantStatus {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub registrants: Vec<Assistants>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// List of a Role Members
@@ -5150,6 +5498,10 @@ pub struct RoleMembersList {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_records: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Add Role Members
@@ -5164,6 +5516,10 @@ pub struct AddRoleMembers {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub members: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Base object for sessions.
@@ -5252,6 +5608,10 @@ pub struct Session {
         rename = "type"
     )]
     pub type_: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -5274,6 +5634,10 @@ pub struct SessionUpdateTrackingFields {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub value: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -5286,6 +5650,10 @@ pub struct SettingsData {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub registrants_confirmation_email: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types are flattened into one object:
@@ -5407,6 +5775,10 @@ pub struct SessionUpdate {
         rename = "type"
     )]
     pub type_: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Recurrence object. Use this object only for a webinar of type `9` i.e., a recurring webinar with fixed time.
@@ -5485,6 +5857,10 @@ pub struct RecurrenceWebinar {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub weekly_days: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -5503,8 +5879,9 @@ pub enum AnswerQuestions {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AnswerQuestions {
@@ -5513,7 +5890,7 @@ impl std::fmt::Display for AnswerQuestions {
             AnswerQuestions::All => "all",
             AnswerQuestions::Only => "only",
             AnswerQuestions::Noop => "",
-            AnswerQuestions::FallthroughString => "*",
+            AnswerQuestions::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -5565,6 +5942,10 @@ pub struct QuestionAnswer {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub enable: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Send reminder email to attendees and panelists.
@@ -5588,6 +5969,10 @@ pub struct AttendeesPanelistsReminderEmailNotification {
         rename = "type"
     )]
     pub type_: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Send follow-up email to attendees.
@@ -5611,6 +5996,10 @@ pub struct FollowUpAttendeesEmailNotification {
         rename = "type"
     )]
     pub type_: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Send follow-up email to absentees.
@@ -5634,6 +6023,10 @@ pub struct FollowUpAbsenteesEmailNotification {
         rename = "type"
     )]
     pub type_: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Webinar settings.
@@ -5907,6 +6300,10 @@ pub struct WebinarSettings {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub survey_url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Base webinar object for sessions.
@@ -5995,6 +6392,10 @@ pub struct SessionWebinar {
         rename = "type"
     )]
     pub type_: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types are flattened into one object:
@@ -6099,6 +6500,10 @@ pub struct SessionWebinarUpdate {
         rename = "type"
     )]
     pub type_: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -6116,8 +6521,9 @@ pub enum TspAccountsListDialInNumbersType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for TspAccountsListDialInNumbersType {
@@ -6127,7 +6533,7 @@ impl std::fmt::Display for TspAccountsListDialInNumbersType {
             TspAccountsListDialInNumbersType::Toll => "toll",
             TspAccountsListDialInNumbersType::Tollfree => "tollfree",
             TspAccountsListDialInNumbersType::Noop => "",
-            TspAccountsListDialInNumbersType::FallthroughString => "*",
+            TspAccountsListDialInNumbersType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -6174,6 +6580,10 @@ pub struct DialInNumbers {
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
     pub type_: Option<TspAccountsListDialInNumbersType>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -6188,8 +6598,9 @@ pub enum TspBridge {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for TspBridge {
@@ -6198,7 +6609,7 @@ impl std::fmt::Display for TspBridge {
             TspBridge::EuTspTb => "EU_TSP_TB",
             TspBridge::UsTspTb => "US_TSP_TB",
             TspBridge::Noop => "",
-            TspBridge::FallthroughString => "*",
+            TspBridge::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -6245,6 +6656,10 @@ pub struct TspAccountsList {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub tsp_bridge: Option<TspBridge>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -6267,6 +6682,10 @@ pub struct DedicatedDialInNumber {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub number: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// List of PAC accounts.
@@ -6317,6 +6736,10 @@ pub struct Pac {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub participant_password: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The user object represents a specific user on Zoom.
@@ -6430,6 +6853,10 @@ pub struct User {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub use_pmi: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Custom attribute(s) of the user.
@@ -6462,6 +6889,10 @@ pub struct CustomAttributes {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub value: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -6485,8 +6916,9 @@ pub enum Label {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Label {
@@ -6497,7 +6929,7 @@ impl std::fmt::Display for Label {
             Label::Mobile => "Mobile",
             Label::Office => "Office",
             Label::Noop => "",
-            Label::FallthroughString => "*",
+            Label::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -6548,6 +6980,10 @@ pub struct PhoneNumbers {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub number: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The user update object represents a user on Zoom.
@@ -6725,6 +7161,10 @@ pub struct UserUpdate {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub vanity_name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// List of user's assistants.
@@ -6739,6 +7179,10 @@ pub struct UserAssistantsList {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub assistants: Vec<Assistants>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -6770,6 +7214,10 @@ pub struct Schedulers {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub pmi: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// List of user's schedulers.
@@ -6784,6 +7232,10 @@ pub struct UserSchedulersList {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub schedulers: Vec<Schedulers>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Account wide meeting/webinar [passcode requirements](https://support.zoom.us/hc/en-us/articles/360033559832-Meeting-and-webinar-passwords#h_a427384b-e383-4f80-864d-794bf0a37604).
@@ -6855,6 +7307,10 @@ pub struct UserSettingsMeetingPasswordRequirement {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub weak_enhance_detection: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -6989,6 +7445,10 @@ pub struct UserSettingsMeeting {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub use_pmi_for_scheduled_meetings: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -7281,6 +7741,10 @@ pub struct UserSettingsInMeeting {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub workplace_by_facebook: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -7318,6 +7782,10 @@ pub struct UserSettingsNotification {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub schedule_for_reminder: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// This object represents the minimum passcode requirements set for recordings via Account Recording Settings.
@@ -7364,6 +7832,10 @@ pub struct UserSettingsRecordingPasswordRequirement {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub only_allow_numeric: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -7505,6 +7977,10 @@ pub struct UserSettingsRecording {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub show_timestamp: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -7527,6 +8003,10 @@ pub struct UserSettingsTelephony {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub third_party_audio: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -7616,6 +8096,10 @@ pub struct UserSettingsFeature {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub zoom_phone: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Account Settings: TSP.
@@ -7646,6 +8130,10 @@ pub struct UserSettingsTsp {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub show_international_numbers_link: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -7666,6 +8154,10 @@ pub struct UserSettings {
     pub telephony: Option<UserSettingsTelephony>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub tsp: Option<UserSettingsTsp>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -7688,6 +8180,10 @@ pub struct SettingsUpdateTelephony {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub third_party_audio: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -7761,6 +8257,10 @@ pub struct UserSettingsUpdateFeature {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub zoom_phone: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -7781,6 +8281,10 @@ pub struct UserSettingsUpdate {
     pub telephony: Option<SettingsUpdateTelephony>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub tsp: Option<UserSettingsTsp>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -7795,6 +8299,10 @@ pub struct UserPermissions {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub permissions: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Webinar object.
@@ -7919,6 +8427,10 @@ pub struct WebinarInfo {
         rename = "type"
     )]
     pub type_: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Webinar metric details.
@@ -8088,6 +8600,10 @@ pub struct Webinars {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub uuid: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Webinar panelist.
@@ -8102,6 +8618,10 @@ pub struct WebinarPanelist {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub panelists: Vec<Panelist>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Zoom room.
@@ -8233,6 +8753,10 @@ pub struct ZoomRoom {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub status: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -8247,8 +8771,9 @@ pub enum PollQuestionsType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PollQuestionsType {
@@ -8257,7 +8782,7 @@ impl std::fmt::Display for PollQuestionsType {
             PollQuestionsType::Multiple => "multiple",
             PollQuestionsType::Single => "single",
             PollQuestionsType::Noop => "",
-            PollQuestionsType::FallthroughString => "*",
+            PollQuestionsType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -8297,6 +8822,10 @@ pub struct Questions {
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
     pub type_: Option<PollQuestionsType>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Poll
@@ -8320,6 +8849,10 @@ pub struct Poll {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub title: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -8356,8 +8889,9 @@ pub enum FieldName {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for FieldName {
@@ -8377,7 +8911,7 @@ impl std::fmt::Display for FieldName {
             FieldName::State => "state",
             FieldName::Zip => "zip",
             FieldName::Noop => "",
-            FieldName::FallthroughString => "*",
+            FieldName::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -8404,6 +8938,10 @@ pub struct MeetingRegistrantQuestions {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub required: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -8418,8 +8956,9 @@ pub enum MeetingRegistrantQuestionsCustomType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for MeetingRegistrantQuestionsCustomType {
@@ -8428,7 +8967,7 @@ impl std::fmt::Display for MeetingRegistrantQuestionsCustomType {
             MeetingRegistrantQuestionsCustomType::Short => "short",
             MeetingRegistrantQuestionsCustomType::Single => "single",
             MeetingRegistrantQuestionsCustomType::Noop => "",
-            MeetingRegistrantQuestionsCustomType::FallthroughString => "*",
+            MeetingRegistrantQuestionsCustomType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -8476,6 +9015,10 @@ pub struct CustomQuestions {
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
     pub type_: Option<MeetingRegistrantQuestionsCustomType>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Meeting Registrant Questions
@@ -8499,6 +9042,10 @@ pub struct MeetingRegistrantQuestionsData {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub questions: Vec<MeetingRegistrantQuestions>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Webinar Registrant Questions
@@ -8522,6 +9069,10 @@ pub struct WebinarRegistrantQuestions {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub questions: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -8560,8 +9111,9 @@ pub enum RecordingRegistrantQuestionsFieldName {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for RecordingRegistrantQuestionsFieldName {
@@ -8584,7 +9136,7 @@ impl std::fmt::Display for RecordingRegistrantQuestionsFieldName {
             RecordingRegistrantQuestionsFieldName::State => "state",
             RecordingRegistrantQuestionsFieldName::Zip => "zip",
             RecordingRegistrantQuestionsFieldName::Noop => "",
-            RecordingRegistrantQuestionsFieldName::FallthroughString => "*",
+            RecordingRegistrantQuestionsFieldName::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -8611,6 +9163,10 @@ pub struct RecordingRegistrantQuestions {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub required: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -8627,8 +9183,9 @@ pub enum RecordingRegistrantQuestionsCustomType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for RecordingRegistrantQuestionsCustomType {
@@ -8638,7 +9195,7 @@ impl std::fmt::Display for RecordingRegistrantQuestionsCustomType {
             RecordingRegistrantQuestionsCustomType::Short => "short",
             RecordingRegistrantQuestionsCustomType::Single => "single",
             RecordingRegistrantQuestionsCustomType::Noop => "",
-            RecordingRegistrantQuestionsCustomType::FallthroughString => "*",
+            RecordingRegistrantQuestionsCustomType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -8686,6 +9243,10 @@ pub struct RecordingRegistrantQuestionsCustom {
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
     pub type_: Option<RecordingRegistrantQuestionsCustomType>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Recording Registrant Questions
@@ -8709,6 +9270,10 @@ pub struct RecordingRegistrantQuestionsData {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub questions: Vec<RecordingRegistrantQuestions>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Tracking Field
@@ -8748,6 +9313,10 @@ pub struct TrackingField {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub visible: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Create Webinar settings.
@@ -8996,6 +9565,10 @@ pub struct CreateWebinarSettings {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub survey_url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -9018,6 +9591,10 @@ pub struct MeetingCreateTrackingFields {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub value: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Language interpretation [settings](https://support.zoom.us/hc/en-us/articles/360034919791-Language-interpretation-in-meetings-and-webinars#h_01EGGQFD3Q4BST3378SA762MJ1) for meetings.
@@ -9046,6 +9623,10 @@ pub struct MeetingCreateSettingsLanguageInterpretation {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub interpreters: Vec<Interpreters>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Meeting settings.
@@ -9308,6 +9889,10 @@ pub struct MeetingCreateSettings {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub watermark: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Base object for meeting.
@@ -9422,6 +10007,10 @@ pub struct MeetingCreate {
         rename = "type"
     )]
     pub type_: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Base webinar object for sessions.
@@ -9510,6 +10099,10 @@ pub struct CreateWebinar {
         rename = "type"
     )]
     pub type_: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -9520,6 +10113,10 @@ pub struct TspGlobalDialIn {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub audio_url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -9550,6 +10147,10 @@ pub struct ChannelSettings {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub posting_permissions: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -9593,6 +10194,10 @@ pub struct Properties {
         rename = "type"
     )]
     pub type_: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The channel object represents a Zoom chat [channel](https://support.zoom.us/hc/en-us/articles/200912909-Getting-Started-With-Channels-Group-Messaging-).
@@ -9603,6 +10208,10 @@ pub struct Channel {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub properties: Option<Properties>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -9643,6 +10252,10 @@ pub struct CpuUsage {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub zoom_min_cpu_usage: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// QoS metrics on audio being sent by a participant who joined the meeting via a Cloud Room Connector.
@@ -9693,6 +10306,10 @@ pub struct AudioDeviceFromCrc {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub max_loss: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// QoS metrics on audio received by a participant who joined the meeting via a Cloud Room Connector.
@@ -9743,6 +10360,10 @@ pub struct AudioDeviceCrc {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub max_loss: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// QoS metrics on video input being sent from a Cloud Room Connector used by the participant to join the meeting.
@@ -9793,6 +10414,10 @@ pub struct VideoDeviceFromCrc {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub max_loss: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// QoS metrics on video output received by a participant who joined the meeting via a Cloud Room Connector.
@@ -9843,6 +10468,10 @@ pub struct VideoDeviceCrc {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub max_loss: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// QoS metrics on screen shares by a participant who joined the meeting via a Cloud Room Connector.
@@ -9893,6 +10522,10 @@ pub struct AsDeviceFromCrc {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub max_loss: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// QoS metrics on screen shares output being received by a participant who joined the meeting via a Cloud Room Connector.
@@ -9943,6 +10576,10 @@ pub struct AsDeviceCrc {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub max_loss: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -10006,6 +10643,10 @@ pub struct UserQos {
     pub video_input: Option<Domains>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub video_output: Option<Domains>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Participant QOS.
@@ -10128,6 +10769,10 @@ pub struct ParticipantQos {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub version: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -10141,8 +10786,9 @@ pub enum AccountSettingsRecordingAuthenticationUpdateOptionType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AccountSettingsRecordingAuthenticationUpdateOptionType {
@@ -10154,7 +10800,7 @@ impl std::fmt::Display for AccountSettingsRecordingAuthenticationUpdateOptionTyp
             }
             AccountSettingsRecordingAuthenticationUpdateOptionType::Internally => "internally",
             AccountSettingsRecordingAuthenticationUpdateOptionType::Noop => "",
-            AccountSettingsRecordingAuthenticationUpdateOptionType::FallthroughString => "*",
+            AccountSettingsRecordingAuthenticationUpdateOptionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -10180,8 +10826,9 @@ pub enum AccountSettingsRecordingAuthenticationUpdateOptionAction {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AccountSettingsRecordingAuthenticationUpdateOptionAction {
@@ -10191,7 +10838,7 @@ impl std::fmt::Display for AccountSettingsRecordingAuthenticationUpdateOptionAct
             AccountSettingsRecordingAuthenticationUpdateOptionAction::Delete => "delete",
             AccountSettingsRecordingAuthenticationUpdateOptionAction::Update => "update",
             AccountSettingsRecordingAuthenticationUpdateOptionAction::Noop => "",
-            AccountSettingsRecordingAuthenticationUpdateOptionAction::FallthroughString => "*",
+            AccountSettingsRecordingAuthenticationUpdateOptionAction::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -10247,6 +10894,10 @@ pub struct AuthenticationOption {
     pub name: String,
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
     pub type_: Option<AccountSettingsRecordingAuthenticationUpdateOptionType>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Only authenticated users can view cloud recordings
@@ -10268,6 +10919,10 @@ pub struct AccountSettingsRecordingAuthenticationUpdate {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub recording_authentication: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Quality of Service object.
@@ -10327,6 +10982,10 @@ pub struct QoSPhone {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub network_delay: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Meeting/webinar [passcode requirements](https://support.zoom.us/hc/en-us/articles/360033559832-Meeting-and-webinar-passwords#h_a427384b-e383-4f80-864d-794bf0a37604).
@@ -10398,6 +11057,10 @@ pub struct MeetingSecuritySettingsPasswordRequirement {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub weak_enhance_detection: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Specify the settings to be applied if waiting room is enabled.
@@ -10430,6 +11093,10 @@ pub struct WaitingRoomSettings {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub whitelisted_domains_for_waiting_room: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -10552,12 +11219,20 @@ pub struct MeetingSecurity {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub webinar_password: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct MeetingSecuritySettings {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub meeting_security: Option<MeetingSecurity>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Meeting metric details.
@@ -10745,6 +11420,10 @@ pub struct Metrics {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub uuid: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -10775,8 +11454,9 @@ pub enum FileType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for FileType {
@@ -10789,7 +11469,7 @@ impl std::fmt::Display for FileType {
             FileType::Mp4 => "MP4",
             FileType::Transcript => "TRANSCRIPT",
             FileType::Noop => "",
-            FileType::FallthroughString => "*",
+            FileType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -10844,8 +11524,9 @@ pub enum RecordingType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for RecordingType {
@@ -10863,7 +11544,7 @@ impl std::fmt::Display for RecordingType {
             RecordingType::SharedScreenWithSpeakerViewCc => "shared_screen_with_speaker_view(CC)",
             RecordingType::SpeakerView => "speaker_view",
             RecordingType::Noop => "",
-            RecordingType::FallthroughString => "*",
+            RecordingType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -10953,6 +11634,10 @@ pub struct ArchiveFiles {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub status: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -11057,6 +11742,10 @@ pub struct CloudArchivedFiles {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub uuid: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -11070,6 +11759,10 @@ pub struct Attendees {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Invite Links
@@ -11093,6 +11786,10 @@ pub struct InviteLink {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub ttl: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -11115,6 +11812,10 @@ pub struct InviteLinksAttendees {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Invite links response.
@@ -11129,6 +11830,10 @@ pub struct InviteLinks {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub attendees: Vec<InviteLinksAttendees>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -11138,8 +11843,9 @@ pub enum RecordingStatusUpdateBodyRequestAction {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for RecordingStatusUpdateBodyRequestAction {
@@ -11147,7 +11853,7 @@ impl std::fmt::Display for RecordingStatusUpdateBodyRequestAction {
         match self {
             RecordingStatusUpdateBodyRequestAction::Recover => "recover",
             RecordingStatusUpdateBodyRequestAction::Noop => "",
-            RecordingStatusUpdateBodyRequestAction::FallthroughString => "*",
+            RecordingStatusUpdateBodyRequestAction::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -11163,6 +11869,10 @@ impl RecordingStatusUpdateBodyRequestAction {
 pub struct RecordingStatusUpdateBodyRequest {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub action: Option<RecordingStatusUpdateBodyRequestAction>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -11176,6 +11886,10 @@ pub struct UploadVbRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub file: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Specify emergency address for the account.
@@ -11237,6 +11951,10 @@ pub struct EmergencyAddress {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub zip: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -11254,6 +11972,10 @@ pub struct SetUpAccountRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub extension_number: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -11274,8 +11996,9 @@ pub enum ListAccountPhoneNumbersType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ListAccountPhoneNumbersType {
@@ -11285,7 +12008,7 @@ impl std::fmt::Display for ListAccountPhoneNumbersType {
             ListAccountPhoneNumbersType::Assigned => "assigned",
             ListAccountPhoneNumbersType::Unassigned => "unassigned",
             ListAccountPhoneNumbersType::Noop => "",
-            ListAccountPhoneNumbersType::FallthroughString => "*",
+            ListAccountPhoneNumbersType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -11315,8 +12038,9 @@ pub enum ExtensionType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ExtensionType {
@@ -11327,7 +12051,7 @@ impl std::fmt::Display for ExtensionType {
             ExtensionType::CommonAreaPhone => "commonAreaPhone",
             ExtensionType::User => "user",
             ExtensionType::Noop => "",
-            ExtensionType::FallthroughString => "*",
+            ExtensionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -11351,8 +12075,9 @@ pub enum Source {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Source {
@@ -11361,7 +12086,7 @@ impl std::fmt::Display for Source {
             Source::External => "external",
             Source::Internal => "internal",
             Source::Noop => "",
-            Source::FallthroughString => "*",
+            Source::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -11385,8 +12110,9 @@ pub enum ListAccountPhoneNumbersResponseStatus {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ListAccountPhoneNumbersResponseStatus {
@@ -11395,7 +12121,7 @@ impl std::fmt::Display for ListAccountPhoneNumbersResponseStatus {
             ListAccountPhoneNumbersResponseStatus::Available => "available",
             ListAccountPhoneNumbersResponseStatus::Pending => "pending",
             ListAccountPhoneNumbersResponseStatus::Noop => "",
-            ListAccountPhoneNumbersResponseStatus::FallthroughString => "*",
+            ListAccountPhoneNumbersResponseStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -11443,6 +12169,10 @@ pub struct Assignee {
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
     pub type_: Option<ExtensionType>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -11465,6 +12195,10 @@ pub struct Site {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Displayed when the `type` request parameter is `byoc`.
@@ -11488,6 +12222,10 @@ pub struct Carrier {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Displayed when the `type` request parameter is `byoc`.
@@ -11511,6 +12249,10 @@ pub struct SipGroup {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -11592,6 +12334,10 @@ pub struct ListAccountPhoneNumbersResponse {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub status: Option<ListAccountPhoneNumbersResponseStatus>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -11629,6 +12375,10 @@ pub struct ListAccountPhoneNumbersResponseData {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_records: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -11647,8 +12397,9 @@ pub enum TransportProtocol {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for TransportProtocol {
@@ -11659,7 +12410,7 @@ impl std::fmt::Display for TransportProtocol {
             TransportProtocol::Tls => "TLS",
             TransportProtocol::Udp => "UDP",
             TransportProtocol::Noop => "",
-            TransportProtocol::FallthroughString => "*",
+            TransportProtocol::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -11826,6 +12577,10 @@ pub struct Phones {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub voice_mail: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -11884,6 +12639,10 @@ pub struct ListSipPhonesResponse {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_records: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -12032,6 +12791,10 @@ pub struct CreateSipPhoneRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub voice_mail: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -12171,6 +12934,10 @@ pub struct UpdateSipPhoneRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub voice_mail: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -12189,8 +12956,9 @@ pub enum ListZoomRoomsStatus {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ListZoomRoomsStatus {
@@ -12201,7 +12969,7 @@ impl std::fmt::Display for ListZoomRoomsStatus {
             ListZoomRoomsStatus::Offline => "Offline",
             ListZoomRoomsStatus::UnderConstruction => "UnderConstruction",
             ListZoomRoomsStatus::Noop => "",
-            ListZoomRoomsStatus::FallthroughString => "*",
+            ListZoomRoomsStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -12227,8 +12995,9 @@ pub enum ListZoomRoomsType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ListZoomRoomsType {
@@ -12238,7 +13007,7 @@ impl std::fmt::Display for ListZoomRoomsType {
             ListZoomRoomsType::SchedulingDisplayOnly => "SchedulingDisplayOnly",
             ListZoomRoomsType::ZoomRoom => "ZoomRoom",
             ListZoomRoomsType::Noop => "",
-            ListZoomRoomsType::FallthroughString => "*",
+            ListZoomRoomsType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -12302,6 +13071,10 @@ pub struct ListZoomRoomsResponse {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub status: Option<ListZoomRoomsStatus>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -12333,6 +13106,10 @@ pub struct ListZoomRoomsResponseData {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub rooms: Vec<ListZoomRoomsResponse>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -12364,6 +13141,10 @@ pub struct AddRoomRequest {
         rename = "type"
     )]
     pub type_: ListZoomRoomsType,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -12409,6 +13190,10 @@ pub struct AddRoomResponse {
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
     pub type_: Option<ListZoomRoomsType>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Auto Receptionist for each site.
@@ -12450,6 +13235,10 @@ pub struct MainAutoReceptionist {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Site country
@@ -12473,6 +13262,10 @@ pub struct Country {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -12514,6 +13307,10 @@ pub struct Sites {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub site_code: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -12554,6 +13351,10 @@ pub struct ListPhoneSitesResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub total_records: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -12614,6 +13415,10 @@ pub struct DefaultEmergencyAddress {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub zip: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Short extension of the phone site.
@@ -12628,6 +13433,10 @@ pub struct ShortExtension {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub length: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -12666,6 +13475,10 @@ pub struct CreatePhoneSiteRequest {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub site_code: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// [Main Auto Receptionist](https://support.zoom.us/hc/en-us/articles/360021121312#h_bc7ff1d5-0e6c-40cd-b889-62010cb98c57) for each site.
@@ -12707,6 +13520,10 @@ pub struct GetSiteResponseMainAutoReceptionist {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -12753,6 +13570,10 @@ pub struct GetSiteResponse {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub site_code: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -12775,6 +13596,10 @@ pub struct UpdateSiteDetailsRequest {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub site_code: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -12794,8 +13619,9 @@ pub enum PresenceStatus {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PresenceStatus {
@@ -12806,7 +13632,7 @@ impl std::fmt::Display for PresenceStatus {
             PresenceStatus::DoNotDisturb => "Do_Not_Disturb",
             PresenceStatus::Offline => "Offline",
             PresenceStatus::Noop => "",
-            PresenceStatus::FallthroughString => "*",
+            PresenceStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -12946,6 +13772,10 @@ pub struct Contacts {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub sip_phone_number: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -12974,6 +13804,10 @@ pub struct SearchCompanyContactsResponse {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub page_size: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -12992,8 +13826,9 @@ pub enum GetChatMessagesResponseStatus {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GetChatMessagesResponseStatus {
@@ -13003,7 +13838,7 @@ impl std::fmt::Display for GetChatMessagesResponseStatus {
             GetChatMessagesResponseStatus::Edited => "Edited",
             GetChatMessagesResponseStatus::Normal => "Normal",
             GetChatMessagesResponseStatus::Noop => "",
-            GetChatMessagesResponseStatus::FallthroughString => "*",
+            GetChatMessagesResponseStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -13125,6 +13960,10 @@ pub struct Messages {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub timestamp: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Chat message object.
@@ -13166,6 +14005,10 @@ pub struct GetChatMessagesResponse {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub page_size: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -13206,6 +14049,10 @@ pub struct AtItems {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub start_position: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -13246,6 +14093,10 @@ pub struct SendaChatMessageRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub to_contact: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -13286,6 +14137,10 @@ pub struct MarkMessageRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub to_contact: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -13302,8 +14157,9 @@ pub enum ReactMessageRequestAction {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ReactMessageRequestAction {
@@ -13312,7 +14168,7 @@ impl std::fmt::Display for ReactMessageRequestAction {
             ReactMessageRequestAction::Add => "add",
             ReactMessageRequestAction::Remove => "remove",
             ReactMessageRequestAction::Noop => "",
-            ReactMessageRequestAction::FallthroughString => "*",
+            ReactMessageRequestAction::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -13360,6 +14216,10 @@ pub struct ReactMessageRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub to_contact: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -13382,6 +14242,10 @@ pub struct Reactions {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_count: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A chat message object.
@@ -13500,6 +14364,10 @@ pub struct GetChatMessageResponse {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub timestamp: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -13531,6 +14399,10 @@ pub struct EditMessageRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub to_contact: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -13565,6 +14437,10 @@ pub struct Channels {
         rename = "type"
     )]
     pub type_: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -13605,6 +14481,10 @@ pub struct GetChannelsResponse {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_records: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -13618,6 +14498,10 @@ pub struct Members {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub email: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -13650,6 +14534,10 @@ pub struct CreateChannelRequest {
         rename = "type"
     )]
     pub type_: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -13691,6 +14579,10 @@ pub struct CreateChannelResponse {
         rename = "type"
     )]
     pub type_: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -13731,6 +14623,10 @@ pub struct GetUserContactsResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub last_name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -13762,6 +14658,10 @@ pub struct GetUserContactsResponseData {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub page_size: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -13838,6 +14738,10 @@ pub struct GetUserContactResponse {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub presence_status: Option<PresenceStatus>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -13854,8 +14758,9 @@ pub enum Role {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Role {
@@ -13865,7 +14770,7 @@ impl std::fmt::Display for Role {
             Role::Member => "member",
             Role::Owner => "owner",
             Role::Noop => "",
-            Role::FallthroughString => "*",
+            Role::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -13929,6 +14834,10 @@ pub struct ListChannelMembersResponse {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub role: Option<Role>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -13969,6 +14878,10 @@ pub struct ListChannelMembersResponseData {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_records: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -13982,6 +14895,10 @@ pub struct InviteChannelMembersRequest {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub members: Vec<Members>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -14007,6 +14924,10 @@ pub struct InviteChannelMembersResponse {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub ids: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -14029,6 +14950,10 @@ pub struct JoinChannelResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The account object represents an account on Zoom. The account owner is the user who created the account or had an account created for them. You can read more about the Zoom account structure <a href='https://medium.com/zoom-developer-blog/a-brief-look-at-zoom-account-structures-1d19c745bf8a' target='_blank'>here</a>.
@@ -14084,6 +15009,10 @@ pub struct AccountCreateRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub password: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -14124,6 +15053,10 @@ pub struct AccountCreateResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub owner_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -14178,6 +15111,10 @@ pub struct AccountResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub vanity_url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -14196,8 +15133,9 @@ pub enum OptionData {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OptionData {
@@ -14206,7 +15144,7 @@ impl std::fmt::Display for OptionData {
             OptionData::MeetingAuthentication => "meeting_authentication",
             OptionData::RecordingAuthentication => "recording_authentication",
             OptionData::Noop => "",
-            OptionData::FallthroughString => "*",
+            OptionData::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -14321,8 +15259,9 @@ pub enum AccountSettingsUpdateOption {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AccountSettingsUpdateOption {
@@ -14333,7 +15272,7 @@ impl std::fmt::Display for AccountSettingsUpdateOption {
             AccountSettingsUpdateOption::RecordingAuthentication => "recording_authentication",
             AccountSettingsUpdateOption::Security => "security",
             AccountSettingsUpdateOption::Noop => "",
-            AccountSettingsUpdateOption::FallthroughString => "*",
+            AccountSettingsUpdateOption::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -14359,6 +15298,10 @@ pub struct AccountTrustedDomainResponse {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub trusted_domains: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -14375,8 +15318,9 @@ pub enum AccountPlansResponsePlanBaseStatus {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AccountPlansResponsePlanBaseStatus {
@@ -14386,7 +15330,7 @@ impl std::fmt::Display for AccountPlansResponsePlanBaseStatus {
             AccountPlansResponsePlanBaseStatus::Cancel => "cancel",
             AccountPlansResponsePlanBaseStatus::Expired => "expired",
             AccountPlansResponsePlanBaseStatus::Noop => "",
-            AccountPlansResponsePlanBaseStatus::FallthroughString => "*",
+            AccountPlansResponsePlanBaseStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -14442,6 +15386,10 @@ pub struct PlanZoomRooms {
         rename = "type"
     )]
     pub type_: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Account plan object.
@@ -14489,6 +15437,10 @@ pub struct PlanWebinar {
         rename = "type"
     )]
     pub type_: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -14503,8 +15455,9 @@ pub enum PlanRecordingStatus {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PlanRecordingStatus {
@@ -14513,7 +15466,7 @@ impl std::fmt::Display for PlanRecordingStatus {
             PlanRecordingStatus::Active => "Active",
             PlanRecordingStatus::Cancelled => "Cancelled",
             PlanRecordingStatus::Noop => "",
-            PlanRecordingStatus::FallthroughString => "*",
+            PlanRecordingStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -14597,6 +15550,10 @@ pub struct AccountPlansResponsePlanAudio {
         rename = "type"
     )]
     pub type_: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Additional phone base plans.
@@ -14644,6 +15601,10 @@ pub struct AccountPlansResponsePlanPhoneBase {
         rename = "type"
     )]
     pub type_: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Phone Plan Object
@@ -14672,6 +15633,10 @@ pub struct PlanPhone {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub plan_number: Vec<PlanZoomRooms>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -14736,6 +15701,10 @@ pub struct AccountPlansResponse {
     pub plan_webinar: Vec<PlanWebinar>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub plan_zoom_rooms: Option<PlanZoomRooms>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -14745,6 +15714,10 @@ pub struct AccountPlanCreateRequest {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub contact: Option<Contact>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types are flattened into one object:
@@ -14780,6 +15753,10 @@ pub struct AccountPlanAddonCreateRequest {
         rename = "type"
     )]
     pub type_: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types:
@@ -14829,6 +15806,10 @@ pub struct GroupCreateRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -14860,6 +15841,10 @@ pub struct GroupResponse {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_members: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -14910,6 +15895,10 @@ pub struct UserCreateResponse {
         rename = "type"
     )]
     pub type_: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -14965,6 +15954,10 @@ pub struct GroupMembersResponseData {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_records: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -14978,6 +15971,10 @@ pub struct AddRoleMembersRequest {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub members: Vec<Assistants>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -15000,6 +15997,10 @@ pub struct GroupMembersCreateResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub ids: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -15016,8 +16017,9 @@ pub enum UpdateGroupMemberRequestAction {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for UpdateGroupMemberRequestAction {
@@ -15026,7 +16028,7 @@ impl std::fmt::Display for UpdateGroupMemberRequestAction {
             UpdateGroupMemberRequestAction::Move => "move",
             UpdateGroupMemberRequestAction::SetPrimary => "set_primary",
             UpdateGroupMemberRequestAction::Noop => "",
-            UpdateGroupMemberRequestAction::FallthroughString => "*",
+            UpdateGroupMemberRequestAction::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -15059,6 +16061,10 @@ pub struct UpdateGroupMemberRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub target_group_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types are flattened into one object:
@@ -15105,8 +16111,9 @@ pub enum ImGroupCreateRequestType {
     Restricted,
     #[serde(rename = "shared")]
     Shared,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ImGroupCreateRequestType {
@@ -15115,7 +16122,7 @@ impl std::fmt::Display for ImGroupCreateRequestType {
             ImGroupCreateRequestType::Normal => "normal",
             ImGroupCreateRequestType::Restricted => "restricted",
             ImGroupCreateRequestType::Shared => "shared",
-            ImGroupCreateRequestType::FallthroughString => "*",
+            ImGroupCreateRequestType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -15152,6 +16159,10 @@ pub struct ImGroupCreateRequest {
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
     pub type_: Option<ImGroupCreateRequestType>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -15207,6 +16218,10 @@ pub struct ImGroupCreateResponse {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_members: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types are flattened into one object:
@@ -15242,6 +16257,10 @@ pub struct DashboardImResponse {
         deserialize_with = "crate::utils::date_format::deserialize"
     )]
     pub to: Option<chrono::NaiveDate>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -15256,8 +16275,9 @@ pub enum ImChatSessionsResponseType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ImChatSessionsResponseType {
@@ -15266,7 +16286,7 @@ impl std::fmt::Display for ImChatSessionsResponseType {
             ImChatSessionsResponseType::One => "1:1",
             ImChatSessionsResponseType::Group => "Group",
             ImChatSessionsResponseType::Noop => "",
-            ImChatSessionsResponseType::FallthroughString => "*",
+            ImChatSessionsResponseType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -15312,6 +16332,10 @@ pub struct Sessions {
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
     pub type_: Option<ImChatSessionsResponseType>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -15325,6 +16349,10 @@ pub struct ImChatSessionsResponseData {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub sessions: Vec<Sessions>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types are flattened into one object:
@@ -15375,6 +16403,10 @@ pub struct ImChatMessagesResponse {
         deserialize_with = "crate::utils::date_format::deserialize"
     )]
     pub to: Option<chrono::NaiveDate>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -15424,6 +16456,10 @@ pub struct ImChatMessagesResponseData {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub sender: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -15437,6 +16473,10 @@ pub struct ImChatMessagesResponseDataType {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub messages: Vec<ImChatMessagesResponseData>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types are flattened into one object:
@@ -15470,8 +16510,9 @@ pub enum MeetingsType {
     Scheduled,
     #[serde(rename = "upcoming")]
     Upcoming,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for MeetingsType {
@@ -15480,7 +16521,7 @@ impl std::fmt::Display for MeetingsType {
             MeetingsType::Live => "live",
             MeetingsType::Scheduled => "scheduled",
             MeetingsType::Upcoming => "upcoming",
-            MeetingsType::FallthroughString => "*",
+            MeetingsType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -15524,6 +16565,10 @@ pub struct MeetingCreateResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub registration_url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types are flattened into one object:
@@ -15589,6 +16634,10 @@ pub struct MeetingResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub uuid: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types are flattened into one object:
@@ -15618,6 +16667,10 @@ pub struct MeetingUpdateRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub schedule_for: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types are flattened into one object:
@@ -15647,8 +16700,9 @@ pub enum MeetingStatusRequestAction {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for MeetingStatusRequestAction {
@@ -15657,7 +16711,7 @@ impl std::fmt::Display for MeetingStatusRequestAction {
             MeetingStatusRequestAction::End => "end",
             MeetingStatusRequestAction::Recover => "recover",
             MeetingStatusRequestAction::Noop => "",
-            MeetingStatusRequestAction::FallthroughString => "*",
+            MeetingStatusRequestAction::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -15678,6 +16732,10 @@ pub struct MeetingStatusRequest {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub action: Option<MeetingStatusRequestAction>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -15692,8 +16750,9 @@ pub enum MeetingRegistrantsStatus {
     Denied,
     #[serde(rename = "pending")]
     Pending,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for MeetingRegistrantsStatus {
@@ -15702,7 +16761,7 @@ impl std::fmt::Display for MeetingRegistrantsStatus {
             MeetingRegistrantsStatus::Approved => "approved",
             MeetingRegistrantsStatus::Denied => "denied",
             MeetingRegistrantsStatus::Pending => "pending",
-            MeetingRegistrantsStatus::FallthroughString => "*",
+            MeetingRegistrantsStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -15755,6 +16814,10 @@ pub struct MeetingRegistrantCreateResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub topic: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -15868,6 +16931,10 @@ pub struct PastMeetingDetailsResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub uuid: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -15899,6 +16966,10 @@ pub struct Participants {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub user_email: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -15912,6 +16983,10 @@ pub struct PastMeetingParticipantsResponse {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub participants: Vec<Participants>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types are flattened into one object:
@@ -15946,8 +17021,9 @@ pub enum MeetingPollCreateResponseStatus {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for MeetingPollCreateResponseStatus {
@@ -15958,7 +17034,7 @@ impl std::fmt::Display for MeetingPollCreateResponseStatus {
             MeetingPollCreateResponseStatus::Sharing => "sharing",
             MeetingPollCreateResponseStatus::Started => "started",
             MeetingPollCreateResponseStatus::Noop => "",
-            MeetingPollCreateResponseStatus::FallthroughString => "*",
+            MeetingPollCreateResponseStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -15986,6 +17062,10 @@ pub struct MeetingPollGetResponse {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub status: Option<MeetingPollCreateResponseStatus>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types are flattened into one object:
@@ -16024,6 +17104,10 @@ pub struct RecordingGetResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub password: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types are flattened into one object:
@@ -16049,8 +17133,9 @@ pub enum RecordingDeleteAction {
     #[serde(rename = "trash")]
     #[default]
     Trash,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for RecordingDeleteAction {
@@ -16058,7 +17143,7 @@ impl std::fmt::Display for RecordingDeleteAction {
         match self {
             RecordingDeleteAction::Delete => "delete",
             RecordingDeleteAction::Trash => "trash",
-            RecordingDeleteAction::FallthroughString => "*",
+            RecordingDeleteAction::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -16102,6 +17187,10 @@ pub struct MeetingRecordingRegistrantCreateResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub topic: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -16118,8 +17207,9 @@ pub enum DashboardMeetingsType {
     Past,
     #[serde(rename = "pastOne")]
     PastOne,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DashboardMeetingsType {
@@ -16128,7 +17218,7 @@ impl std::fmt::Display for DashboardMeetingsType {
             DashboardMeetingsType::Live => "live",
             DashboardMeetingsType::Past => "past",
             DashboardMeetingsType::PastOne => "pastOne",
-            DashboardMeetingsType::FallthroughString => "*",
+            DashboardMeetingsType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -16144,8 +17234,9 @@ pub enum IncludeFields {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for IncludeFields {
@@ -16153,7 +17244,7 @@ impl std::fmt::Display for IncludeFields {
         match self {
             IncludeFields::TrackingFields => "tracking_fields",
             IncludeFields::Noop => "",
-            IncludeFields::FallthroughString => "*",
+            IncludeFields::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -16176,6 +17267,10 @@ pub struct DashboardMeetingsResponse {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub meetings: Vec<Metrics>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types are flattened into one object:
@@ -16207,8 +17302,9 @@ pub enum DashboardMeetingParticipantsIncludeFields {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DashboardMeetingParticipantsIncludeFields {
@@ -16216,7 +17312,7 @@ impl std::fmt::Display for DashboardMeetingParticipantsIncludeFields {
         match self {
             DashboardMeetingParticipantsIncludeFields::RegistrantId => "registrant_id",
             DashboardMeetingParticipantsIncludeFields::Noop => "",
-            DashboardMeetingParticipantsIncludeFields::FallthroughString => "*",
+            DashboardMeetingParticipantsIncludeFields::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -16256,8 +17352,9 @@ pub enum DashboardMeetingParticipantsResponseDevice {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DashboardMeetingParticipantsResponseDevice {
@@ -16270,7 +17367,7 @@ impl std::fmt::Display for DashboardMeetingParticipantsResponseDevice {
             DashboardMeetingParticipantsResponseDevice::Windows => "Windows",
             DashboardMeetingParticipantsResponseDevice::IOs => "iOS",
             DashboardMeetingParticipantsResponseDevice::Noop => "",
-            DashboardMeetingParticipantsResponseDevice::FallthroughString => "*",
+            DashboardMeetingParticipantsResponseDevice::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -16306,8 +17403,9 @@ pub enum NetworkType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for NetworkType {
@@ -16319,7 +17417,7 @@ impl std::fmt::Display for NetworkType {
             NetworkType::Wifi => "Wifi",
             NetworkType::Wired => "Wired",
             NetworkType::Noop => "",
-            NetworkType::FallthroughString => "*",
+            NetworkType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -16347,8 +17445,9 @@ pub enum AudioQuality {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AudioQuality {
@@ -16359,7 +17458,7 @@ impl std::fmt::Display for AudioQuality {
             AudioQuality::Good => "good",
             AudioQuality::Poor => "poor",
             AudioQuality::Noop => "",
-            AudioQuality::FallthroughString => "*",
+            AudioQuality::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -16385,8 +17484,9 @@ pub enum DashboardMeetingParticipantsResponseStatus {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DashboardMeetingParticipantsResponseStatus {
@@ -16395,7 +17495,7 @@ impl std::fmt::Display for DashboardMeetingParticipantsResponseStatus {
             DashboardMeetingParticipantsResponseStatus::InMeeting => "in_meeting",
             DashboardMeetingParticipantsResponseStatus::InWaitingRoom => "in_waiting_room",
             DashboardMeetingParticipantsResponseStatus::Noop => "",
-            DashboardMeetingParticipantsResponseStatus::FallthroughString => "*",
+            DashboardMeetingParticipantsResponseStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -16685,6 +17785,10 @@ pub struct DashboardMeetingParticipantsResponse {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub video_quality: Option<AudioQuality>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -16700,6 +17804,10 @@ pub struct DashboardMeetingParticipantsResponseData {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub participants: Vec<DashboardMeetingParticipantsResponse>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types are flattened into one object:
@@ -16747,6 +17855,10 @@ pub struct Details {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub start_time: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -16787,6 +17899,10 @@ pub struct DashboardMeetingParticipantShareResponseParticipants {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub user_name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -16800,6 +17916,10 @@ pub struct DashboardMeetingParticipantShareResponse {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub participants: Vec<DashboardMeetingParticipantShareResponseParticipants>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types are flattened into one object:
@@ -16828,8 +17948,9 @@ pub enum DashboardWebinarsType {
     Live,
     #[serde(rename = "past")]
     Past,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DashboardWebinarsType {
@@ -16837,7 +17958,7 @@ impl std::fmt::Display for DashboardWebinarsType {
         match self {
             DashboardWebinarsType::Live => "live",
             DashboardWebinarsType::Past => "past",
-            DashboardWebinarsType::FallthroughString => "*",
+            DashboardWebinarsType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -16854,6 +17975,10 @@ pub struct DashboardWebinarsResponse {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub webinars: Vec<Webinars>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types are flattened into one object:
@@ -17126,6 +18251,10 @@ pub struct DashboardWebinarParticipantsResponse {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub video_quality: Option<AudioQuality>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -17141,6 +18270,10 @@ pub struct DashboardWebinarParticipantsResponseData {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub participants: Vec<DashboardWebinarParticipantsResponse>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types are flattened into one object:
@@ -17332,6 +18465,10 @@ pub struct Users {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub voice_send: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -17342,6 +18479,10 @@ pub struct DashboardImResponseData {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub users: Vec<Users>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types are flattened into one object:
@@ -17401,6 +18542,10 @@ pub struct DashboardChatResponse {
         deserialize_with = "crate::utils::date_format::deserialize"
     )]
     pub to: Option<chrono::NaiveDate>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -17523,6 +18668,10 @@ pub struct DashboardChatResponseUsers {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub video_sent: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -17533,6 +18682,10 @@ pub struct DashboardChatResponseData {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub users: Vec<DashboardChatResponseUsers>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types are flattened into one object:
@@ -17577,6 +18730,10 @@ pub struct ClientFeedbacks {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub participants_count: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -17614,6 +18771,10 @@ pub struct DashboardClientFeedbackResponse {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_records: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -17645,6 +18806,10 @@ pub struct DashboardIssueZoomRoomResponse {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_records: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -17661,6 +18826,10 @@ pub struct DashboardIssueZoomRoomResponseData {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub zoom_rooms: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types are flattened into one object:
@@ -17696,6 +18865,10 @@ pub struct IssueDetails {
         deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub time: Option<chrono::DateTime<chrono::Utc>>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -17706,6 +18879,10 @@ pub struct DashboardIssueDetailZoomRoomResponse {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub issue_details: Vec<IssueDetails>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types are flattened into one object:
@@ -17774,6 +18951,10 @@ pub struct Dates {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub participants: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -17805,6 +18986,10 @@ pub struct ReportDailyResponse {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub year: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -17819,8 +19004,9 @@ pub enum ReportUsersType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ReportUsersType {
@@ -17829,7 +19015,7 @@ impl std::fmt::Display for ReportUsersType {
             ReportUsersType::Active => "active",
             ReportUsersType::Inactive => "inactive",
             ReportUsersType::Noop => "",
-            ReportUsersType::FallthroughString => "*",
+            ReportUsersType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -17851,8 +19037,9 @@ pub enum ReportMeetingsType {
     Past,
     #[serde(rename = "pastOne")]
     PastOne,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ReportMeetingsType {
@@ -17860,7 +19047,7 @@ impl std::fmt::Display for ReportMeetingsType {
         match self {
             ReportMeetingsType::Past => "past",
             ReportMeetingsType::PastOne => "pastOne",
-            ReportMeetingsType::FallthroughString => "*",
+            ReportMeetingsType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -17986,6 +19173,10 @@ pub struct Meetings {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub uuid: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -18026,6 +19217,10 @@ pub struct ReportMeetingsResponse {
         deserialize_with = "crate::utils::date_format::deserialize"
     )]
     pub to: Option<chrono::NaiveDate>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types are flattened into one object:
@@ -18173,6 +19368,10 @@ pub struct ReportMeetingDetailsResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub uuid: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -18266,6 +19465,10 @@ pub struct ReportMeetingParticipantsResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub user_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -18281,6 +19484,10 @@ pub struct ReportMeetingParticipantsResponseData {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub participants: Vec<ReportMeetingParticipantsResponse>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types are flattened into one object:
@@ -18337,6 +19544,10 @@ pub struct QuestionDetails {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub question: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -18368,6 +19579,10 @@ pub struct ReportMeetingPollsResponseQuestions {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub question_details: Vec<QuestionDetails>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -18408,6 +19623,10 @@ pub struct ReportMeetingPollsResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub uuid: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -18492,6 +19711,10 @@ pub struct ReportWebinarParticipantsResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub user_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -18507,6 +19730,10 @@ pub struct ReportWebinarParticipantsResponseData {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub participants: Vec<ReportWebinarParticipantsResponse>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types are flattened into one object:
@@ -18545,6 +19772,10 @@ pub struct ReportWebinarQaResponseQuestionsQuestionDetails {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub question: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -18576,6 +19807,10 @@ pub struct ReportWebinarQaResponseQuestions {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub question_details: Vec<ReportWebinarQaResponseQuestionsQuestionDetails>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -18616,6 +19851,10 @@ pub struct ReportWebinarQaResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub uuid: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -18628,8 +19867,9 @@ pub enum QueryDateType {
     #[serde(rename = "start_time")]
     #[default]
     StartTime,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for QueryDateType {
@@ -18637,7 +19877,7 @@ impl std::fmt::Display for QueryDateType {
         match self {
             QueryDateType::EndTime => "end_time",
             QueryDateType::StartTime => "start_time",
-            QueryDateType::FallthroughString => "*",
+            QueryDateType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -18657,6 +19897,10 @@ pub struct ReportCloudRecordingResponse {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub cloud_recording_storage: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types are flattened into one object:
@@ -18706,8 +19950,9 @@ pub enum CategoryType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for CategoryType {
@@ -18726,7 +19971,7 @@ impl std::fmt::Display for CategoryType {
             CategoryType::Webinar => "webinar",
             CategoryType::ZoomRooms => "zoom_rooms",
             CategoryType::Noop => "",
-            CategoryType::FallthroughString => "*",
+            CategoryType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -18785,6 +20030,10 @@ pub struct OperationLogs {
         deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub time: Option<chrono::DateTime<chrono::Utc>>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -18798,6 +20047,10 @@ pub struct ReportOperationLogsResponse {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub operation_logs: Vec<OperationLogs>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types are flattened into one object:
@@ -18848,6 +20101,10 @@ pub struct CreateRoleRequest {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub privileges: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -18900,6 +20157,10 @@ pub struct CreateRoleResponse {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_members: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -18922,6 +20183,10 @@ pub struct AddRoleMembersResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub ids: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -18954,6 +20219,10 @@ pub struct TspResponseDialInNumbers {
         rename = "type"
     )]
     pub type_: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -19018,6 +20287,10 @@ pub struct TspResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub tsp_provider: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -19061,6 +20334,10 @@ pub struct TspUpdateRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub tsp_provider: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -19080,8 +20357,9 @@ pub enum UsersStatus {
     Inactive,
     #[serde(rename = "pending")]
     Pending,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for UsersStatus {
@@ -19090,7 +20368,7 @@ impl std::fmt::Display for UsersStatus {
             UsersStatus::Active => "active",
             UsersStatus::Inactive => "inactive",
             UsersStatus::Pending => "pending",
-            UsersStatus::FallthroughString => "*",
+            UsersStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -19110,8 +20388,9 @@ pub enum UsersIncludeFields {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for UsersIncludeFields {
@@ -19120,7 +20399,7 @@ impl std::fmt::Display for UsersIncludeFields {
             UsersIncludeFields::CustomAttributes => "custom_attributes",
             UsersIncludeFields::HostKey => "host_key",
             UsersIncludeFields::Noop => "",
-            UsersIncludeFields::FallthroughString => "*",
+            UsersIncludeFields::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -19161,6 +20440,10 @@ pub struct UsersResponseCustomAttributes {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub value: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -19346,6 +20629,10 @@ pub struct UsersResponse {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub verified: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -19404,6 +20691,10 @@ pub struct UsersResponseData {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub users: Vec<UsersResponse>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -19422,8 +20713,9 @@ pub enum UserCreateRequestAction {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for UserCreateRequestAction {
@@ -19434,7 +20726,7 @@ impl std::fmt::Display for UserCreateRequestAction {
             UserCreateRequestAction::CustCreate => "custCreate",
             UserCreateRequestAction::SsoCreate => "ssoCreate",
             UserCreateRequestAction::Noop => "",
-            UserCreateRequestAction::FallthroughString => "*",
+            UserCreateRequestAction::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -19494,6 +20786,10 @@ pub struct UserInfo {
         rename = "type"
     )]
     pub type_: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -19505,6 +20801,10 @@ pub struct UserCreateRequest {
     pub action: UserCreateRequestAction,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub user_info: Option<UserInfo>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -19545,8 +20845,9 @@ pub enum LoginType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for LoginType {
@@ -19565,7 +20866,7 @@ impl std::fmt::Display for LoginType {
             LoginType::NinetyEight => "98",
             LoginType::NinetyNine => "99",
             LoginType::Noop => "",
-            LoginType::FallthroughString => "*",
+            LoginType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -19624,6 +20925,10 @@ pub struct UserResponsePhoneNumbers {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub verified: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Custom attribute(s) that have been assigned to the user.
@@ -19656,6 +20961,10 @@ pub struct UserResponseCustomAttributes {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub value: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -19880,6 +21189,10 @@ pub struct UserResponse {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub verified: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types are flattened into one object:
@@ -19911,8 +21224,9 @@ pub enum UserDeleteAction {
     #[serde(rename = "disassociate")]
     #[default]
     Disassociate,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for UserDeleteAction {
@@ -19920,7 +21234,7 @@ impl std::fmt::Display for UserDeleteAction {
         match self {
             UserDeleteAction::Delete => "delete",
             UserDeleteAction::Disassociate => "disassociate",
-            UserDeleteAction::FallthroughString => "*",
+            UserDeleteAction::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -19937,6 +21251,10 @@ pub struct UserZakResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub token: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types:
@@ -20028,8 +21346,9 @@ pub enum UserSettingsUpdateOption {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for UserSettingsUpdateOption {
@@ -20039,7 +21358,7 @@ impl std::fmt::Display for UserSettingsUpdateOption {
             UserSettingsUpdateOption::MeetingSecuirty => "meeting_secuirty",
             UserSettingsUpdateOption::RecordingAuthentication => "recording_authentication",
             UserSettingsUpdateOption::Noop => "",
-            UserSettingsUpdateOption::FallthroughString => "*",
+            UserSettingsUpdateOption::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -20063,8 +21382,9 @@ pub enum UserStatusRequestAction {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for UserStatusRequestAction {
@@ -20073,7 +21393,7 @@ impl std::fmt::Display for UserStatusRequestAction {
             UserStatusRequestAction::Activate => "activate",
             UserStatusRequestAction::Deactivate => "deactivate",
             UserStatusRequestAction::Noop => "",
-            UserStatusRequestAction::FallthroughString => "*",
+            UserStatusRequestAction::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -20093,6 +21413,10 @@ pub struct UserStatusRequest {
      */
     #[serde(default, skip_serializing_if = "UserStatusRequestAction::is_noop")]
     pub action: UserStatusRequestAction,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -20106,6 +21430,10 @@ pub struct UserPasswordRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub password: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -20155,6 +21483,10 @@ pub struct PacAccounts {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub participant_password: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -20165,6 +21497,10 @@ pub struct UserPaCsResponse {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub pac_accounts: Vec<PacAccounts>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// List of TSP accounts.
@@ -20211,6 +21547,10 @@ pub struct TspAccounts {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub tsp_bridge: Option<TspBridge>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -20221,6 +21561,10 @@ pub struct UserTsPsResponse {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub tsp_accounts: Vec<TspAccounts>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// TSP account of the user.
@@ -20267,6 +21611,10 @@ pub struct TspAccount {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub tsp_bridge: Option<TspBridge>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// TSP account.
@@ -20304,6 +21652,10 @@ pub struct TspAccountData {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub tsp_bridge: Option<TspBridge>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -20318,8 +21670,9 @@ pub enum UserTokenType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for UserTokenType {
@@ -20328,7 +21681,7 @@ impl std::fmt::Display for UserTokenType {
             UserTokenType::Token => "token",
             UserTokenType::Zak => "zak",
             UserTokenType::Noop => "",
-            UserTokenType::FallthroughString => "*",
+            UserTokenType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -20350,6 +21703,10 @@ pub struct UserEmailResponse {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub existed_email: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -20362,6 +21719,10 @@ pub struct UserVanityNameResponse {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub existed: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -20419,6 +21780,10 @@ pub struct WebinarCreateResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub uuid: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types are flattened into one object:
@@ -20475,6 +21840,10 @@ pub struct WebinarResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub uuid: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types are flattened into one object:
@@ -20540,6 +21909,10 @@ pub struct ListWebinarParticipantsResponse {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_records: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -20549,8 +21922,9 @@ pub enum WebinarStatusRequestAction {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for WebinarStatusRequestAction {
@@ -20558,7 +21932,7 @@ impl std::fmt::Display for WebinarStatusRequestAction {
         match self {
             WebinarStatusRequestAction::End => "end",
             WebinarStatusRequestAction::Noop => "",
-            WebinarStatusRequestAction::FallthroughString => "*",
+            WebinarStatusRequestAction::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -20574,6 +21948,10 @@ impl WebinarStatusRequestAction {
 pub struct WebinarStatusRequest {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub action: Option<WebinarStatusRequestAction>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -20596,6 +21974,10 @@ pub struct WebinarPanelistCreateResponse {
         deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -20645,6 +22027,10 @@ pub struct WebinarRegistrantCreateResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub topic: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -20676,6 +22062,10 @@ pub struct AddBatchWebinarRegistrantsRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub last_name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -20691,6 +22081,10 @@ pub struct AddBatchRegistrantsRequest {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub registrants: Vec<AddBatchWebinarRegistrantsRequest>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -20722,6 +22116,10 @@ pub struct AddBatchWebinarRegistrantsResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub registrant_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -20732,6 +22130,10 @@ pub struct AddBatchRegistrantsResponse {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub registrants: Vec<AddBatchWebinarRegistrantsResponse>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -20787,6 +22189,10 @@ pub struct SendchatbotRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub visible_to_user: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -20824,6 +22230,10 @@ pub struct EditChatbotMessageRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub user_jid: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -20873,6 +22283,10 @@ pub struct EditChatbotMessageResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub user_jid: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -20904,6 +22318,10 @@ pub struct DeleteChatbotMessageRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub user_jid: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -20953,6 +22371,10 @@ pub struct DeleteChatbotMessageResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub user_jid: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -20984,6 +22406,10 @@ pub struct GetLiveStreamDetailsResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub stream_url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -21024,6 +22450,10 @@ pub struct ClientFeedbackDetails {
         deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub time: Option<chrono::DateTime<chrono::Utc>>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -21034,6 +22464,10 @@ pub struct DashboardClientFeedbackDetailResponse {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub client_feedback_details: Vec<ClientFeedbackDetails>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types are flattened into one object:
@@ -21102,6 +22536,10 @@ pub struct ListimmessagesResponseMessages {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub timstamp: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -21142,6 +22580,10 @@ pub struct ListimmessagesResponse {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub page_size: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -21155,6 +22597,10 @@ pub struct SendimmessagesRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub message: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -21169,6 +22615,10 @@ pub struct CallingPlan {
         rename = "type"
     )]
     pub type_: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -21191,6 +22641,10 @@ pub struct PhoneUserResponseNumbers {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub number: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A list of the user's policies. Policies are exceptions to the user's calling plan restrictions.
@@ -21204,6 +22658,10 @@ pub struct Policy {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub international_calling: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Zoom User Profile
@@ -21290,6 +22748,10 @@ pub struct PhoneUserResponse {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub status: Option<UserStatusRequestAction>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A list of the user's policies.
@@ -21303,6 +22765,10 @@ pub struct UpdateUserProfileRequestPolicy {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub international_calling: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -21330,6 +22796,10 @@ pub struct UpdateUserProfileRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub site_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -21353,6 +22823,10 @@ pub struct PhoneSettingResponseCountry {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -21375,6 +22849,10 @@ pub struct MultipleSites {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub site_code: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// BYOC setting
@@ -21388,6 +22866,10 @@ pub struct Byoc {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub enable: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -21400,6 +22882,10 @@ pub struct ShowDeviceIpCallLog {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub enable: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Zoom Account Setting
@@ -21425,6 +22911,10 @@ pub struct PhoneSettingResponse {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub show_device_ip_for_call_log: Option<ShowDeviceIpCallLog>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Only [master account owners](https://marketplace.zoom.us/docs/api-reference/master-account-apis) can use this MA API to enable BYOC(Bring your own carrier) option for a sub account.<br>
@@ -21450,6 +22940,10 @@ pub struct UpdatePhoneSettingsRequestByoc {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub enable: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -21467,6 +22961,10 @@ pub struct UpdatePhoneSettingsRequest {
     pub byoc: Option<UpdatePhoneSettingsRequestByoc>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub show_device_ip_for_call_log: Option<ShowDeviceIpCallLog>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Outbound Caller Info
@@ -21481,6 +22979,10 @@ pub struct OutboundCaller {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub number: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -21494,6 +22996,10 @@ pub struct KeysPositions {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub primary_number: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Contains information on phones or devices provisioned for the user.
@@ -21504,6 +23010,10 @@ pub struct DeskPhone {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub keys_positions: Option<KeysPositions>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// [Shared voicemail access](https://support.zoom.us/hc/en-us/articles/360033863991-Sharing-and-controlling-access-to-a-voicemail-inbox) assigned to user.
@@ -21543,6 +23053,10 @@ pub struct VoiceMail {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub shared_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -21573,6 +23087,10 @@ pub struct OutboundCallerIds {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub number: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The site's country.
@@ -21605,6 +23123,10 @@ pub struct PhoneUserSettingsResponseCountry {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Phone User Setting
@@ -21662,6 +23184,10 @@ pub struct PhoneUserSettingsResponse {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub voice_mail: Option<VoiceMail>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -21691,8 +23217,9 @@ pub enum ListSettingTemplatesResponseType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ListSettingTemplatesResponseType {
@@ -21705,7 +23232,7 @@ impl std::fmt::Display for ListSettingTemplatesResponseType {
             ListSettingTemplatesResponseType::User => "user",
             ListSettingTemplatesResponseType::Zr => "zr",
             ListSettingTemplatesResponseType::Noop => "",
-            ListSettingTemplatesResponseType::FallthroughString => "*",
+            ListSettingTemplatesResponseType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -21758,6 +23285,10 @@ pub struct Templates {
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
     pub type_: Option<ListSettingTemplatesResponseType>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -21795,6 +23326,10 @@ pub struct ListSettingTemplatesResponse {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_records: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -21815,8 +23350,9 @@ pub enum AddSettingTemplateRequestType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AddSettingTemplateRequestType {
@@ -21828,7 +23364,7 @@ impl std::fmt::Display for AddSettingTemplateRequestType {
             AddSettingTemplateRequestType::Interop => "interop",
             AddSettingTemplateRequestType::User => "user",
             AddSettingTemplateRequestType::Noop => "",
-            AddSettingTemplateRequestType::FallthroughString => "*",
+            AddSettingTemplateRequestType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -21878,6 +23414,10 @@ pub struct AddSettingTemplateRequest {
         rename = "type"
     )]
     pub type_: AddSettingTemplateRequestType,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -21919,6 +23459,10 @@ pub struct AddSettingTemplateResponse {
         rename = "type"
     )]
     pub type_: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -21988,6 +23532,10 @@ pub struct BatchAddLocationsRequestEmergencyAddress {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub zip: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -22037,6 +23585,10 @@ pub struct NetworkSwitches {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub port_range_to: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -22120,6 +23672,10 @@ pub struct Locations {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub sip_group_name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -22139,6 +23695,10 @@ pub struct BatchAddLocationsRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub site_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -22161,6 +23721,10 @@ pub struct BatchAddLocationsResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub location_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The emergency service location's ELIN (Emergency Location Identification Number).
@@ -22184,6 +23748,10 @@ pub struct Elin {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub phone_number_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The emergency service location's SIP group information.
@@ -22207,6 +23775,10 @@ pub struct ListLocationsResponseSipGroup {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The emergency service location's site information.
@@ -22230,6 +23802,10 @@ pub struct ListLocationsResponseSite {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -22318,6 +23894,10 @@ pub struct ListLocationsResponse {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub site: Option<ListLocationsResponseSite>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -22349,6 +23929,10 @@ pub struct ListLocationsResponseData {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub page_size: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -22434,6 +24018,10 @@ pub struct AddLocationRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub site_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The ELIN (Emergency Location Identification Number).
@@ -22457,6 +24045,10 @@ pub struct GetLocationResponseElin {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub phone_number_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The emergency location's address information.
@@ -22527,6 +24119,10 @@ pub struct GetLocationResponseEmergencyAddress {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub zip: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The emergency location's SIP group information.
@@ -22550,6 +24146,10 @@ pub struct GetLocationResponseSipGroup {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The emergency location's site information.
@@ -22573,6 +24173,10 @@ pub struct GetLocationResponseSite {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -22657,6 +24261,10 @@ pub struct GetLocationResponse {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub site: Option<GetLocationResponseSite>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -22730,6 +24338,10 @@ pub struct UpdateLocationRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub sip_group_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The SIP trunk group.
@@ -22753,6 +24365,10 @@ pub struct SipTrunk {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -22797,6 +24413,10 @@ pub struct SipGroups {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub sip_trunk: Option<SipTrunk>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -22828,6 +24448,10 @@ pub struct ListSipGroupsResponse {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub sip_groups: Vec<SipGroups>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -22850,8 +24474,9 @@ pub enum GetSettingTemplateResponseType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GetSettingTemplateResponseType {
@@ -22864,7 +24489,7 @@ impl std::fmt::Display for GetSettingTemplateResponseType {
             GetSettingTemplateResponseType::User => "user",
             GetSettingTemplateResponseType::Zr => "zr",
             GetSettingTemplateResponseType::Noop => "",
-            GetSettingTemplateResponseType::FallthroughString => "*",
+            GetSettingTemplateResponseType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -22896,6 +24521,10 @@ pub struct GetSettingTemplateResponseProfile {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub country: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -22933,6 +24562,10 @@ pub struct AutoCallRecording {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub recording_transcription: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -22961,6 +24594,10 @@ pub struct AdHocCallRecording {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub recording_transcription: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -22981,6 +24618,10 @@ pub struct Sms {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub international_sms: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -23001,6 +24642,10 @@ pub struct Voicemail {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub enable: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -23013,6 +24658,10 @@ pub struct GetSettingTemplateResponsePolicy {
     pub sms: Option<Sms>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub voicemail: Option<Voicemail>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -23048,6 +24697,10 @@ pub struct CustomHours {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub weekday: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -23076,8 +24729,9 @@ pub enum GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectO
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display
@@ -23092,7 +24746,7 @@ GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorTy
 GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType::User => "user",
 GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType::ZoomRoom => "zoomRoom",
 GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType::Noop => "",
-GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType::FallthroughString => "*",
+GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType::Unknown(s) => s.as_str(),
 }
 .fmt(f)
     }
@@ -23130,6 +24784,10 @@ pub struct ConnectOperator {
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
     pub type_:
         Option<GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -23176,6 +24834,10 @@ pub struct BusinessHours {
         rename = "type"
     )]
     pub type_: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -23200,6 +24862,10 @@ pub struct CloseHours {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub max_wait_time: Option<serde_json::Value>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -23208,6 +24874,10 @@ pub struct CallHandling {
     pub business_hours: Option<BusinessHours>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub close_hours: Option<CloseHours>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -23221,6 +24891,10 @@ pub struct GetSettingTemplateResponseUserSettingsDeskPhone {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub pin_code: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -23239,8 +24913,9 @@ pub enum HoldMusic {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for HoldMusic {
@@ -23249,7 +24924,7 @@ impl std::fmt::Display for HoldMusic {
             HoldMusic::Default => "default",
             HoldMusic::Disable => "disable",
             HoldMusic::Noop => "",
-            HoldMusic::FallthroughString => "*",
+            HoldMusic::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -23293,6 +24968,10 @@ pub struct GetSettingTemplateResponseUserSettings {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub hold_music: Option<HoldMusic>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -23335,6 +25014,10 @@ pub struct GetSettingTemplateResponse {
     pub type_: Option<GetSettingTemplateResponseType>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub user_settings: Option<GetSettingTemplateResponseUserSettings>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -23351,8 +25034,9 @@ pub enum RecordingCalls {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for RecordingCalls {
@@ -23362,7 +25046,7 @@ impl std::fmt::Display for RecordingCalls {
             RecordingCalls::Inbound => "inbound",
             RecordingCalls::Outbound => "outbound",
             RecordingCalls::Noop => "",
-            RecordingCalls::FallthroughString => "*",
+            RecordingCalls::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -23405,6 +25089,10 @@ pub struct UpdateSettingTemplateRequestPolicyAutoCallRecording {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub recording_transcription: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -23417,6 +25105,10 @@ pub struct UpdateSettingTemplateRequestPolicy {
     pub sms: Option<Sms>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub voicemail: Option<Voicemail>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -23445,6 +25137,10 @@ pub struct UpdateSettingTemplateRequest {
     pub profile: Option<GetSettingTemplateResponseProfile>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub user_settings: Option<GetSettingTemplateResponseUserSettings>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -23456,8 +25152,9 @@ pub enum PhoneUserCallLogsType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PhoneUserCallLogsType {
@@ -23466,7 +25163,7 @@ impl std::fmt::Display for PhoneUserCallLogsType {
             PhoneUserCallLogsType::All => "all",
             PhoneUserCallLogsType::Missed => "missed",
             PhoneUserCallLogsType::Noop => "",
-            PhoneUserCallLogsType::FallthroughString => "*",
+            PhoneUserCallLogsType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -23488,8 +25185,9 @@ pub enum TimeType {
     #[serde(rename = "startTime")]
     #[default]
     StartTime,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for TimeType {
@@ -23497,7 +25195,7 @@ impl std::fmt::Display for TimeType {
         match self {
             TimeType::EndTime => "endTime",
             TimeType::StartTime => "startTime",
-            TimeType::FallthroughString => "*",
+            TimeType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -23551,6 +25249,10 @@ pub struct ForwardedTo {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub phone_number: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
@@ -23568,8 +25270,9 @@ pub enum PhoneUserCallLogsResponseForwardedByExtensionType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PhoneUserCallLogsResponseForwardedByExtensionType {
@@ -23583,7 +25286,7 @@ impl std::fmt::Display for PhoneUserCallLogsResponseForwardedByExtensionType {
             PhoneUserCallLogsResponseForwardedByExtensionType::SharedLineGroup => "sharedLineGroup",
             PhoneUserCallLogsResponseForwardedByExtensionType::User => "user",
             PhoneUserCallLogsResponseForwardedByExtensionType::Noop => "",
-            PhoneUserCallLogsResponseForwardedByExtensionType::FallthroughString => "*",
+            PhoneUserCallLogsResponseForwardedByExtensionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -23651,6 +25354,10 @@ pub struct ForwardedBy {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub phone_number: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Indicates who accepted the call.
@@ -23701,6 +25408,10 @@ pub struct AcceptedBy {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub phone_number: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -23750,6 +25461,10 @@ pub struct OutgoingBy {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub phone_number: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -23975,6 +25690,10 @@ pub struct CallLogs {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub waiting_time: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Phone User Call Logs
@@ -24043,6 +25762,10 @@ pub struct PhoneUserCallLogsResponse {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_records: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -24164,6 +25887,10 @@ pub struct Recordings {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Phone User Recordings
@@ -24232,6 +25959,10 @@ pub struct PhoneUserRecordingsResponse {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_records: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -24246,8 +25977,9 @@ pub enum PhoneUserVoiceMailsStatus {
     Read,
     #[serde(rename = "unread")]
     Unread,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PhoneUserVoiceMailsStatus {
@@ -24256,7 +25988,7 @@ impl std::fmt::Display for PhoneUserVoiceMailsStatus {
             PhoneUserVoiceMailsStatus::All => "all",
             PhoneUserVoiceMailsStatus::Read => "read",
             PhoneUserVoiceMailsStatus::Unread => "unread",
-            PhoneUserVoiceMailsStatus::FallthroughString => "*",
+            PhoneUserVoiceMailsStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -24274,8 +26006,9 @@ pub enum PhoneUserVoiceMailsResponseStatus {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PhoneUserVoiceMailsResponseStatus {
@@ -24284,7 +26017,7 @@ impl std::fmt::Display for PhoneUserVoiceMailsResponseStatus {
             PhoneUserVoiceMailsResponseStatus::Read => "read",
             PhoneUserVoiceMailsResponseStatus::Unread => "unread",
             PhoneUserVoiceMailsResponseStatus::Noop => "",
-            PhoneUserVoiceMailsResponseStatus::FallthroughString => "*",
+            PhoneUserVoiceMailsResponseStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -24393,6 +26126,10 @@ pub struct VoiceMails {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub status: Option<PhoneUserVoiceMailsResponseStatus>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Phone User Voice Mails
@@ -24461,6 +26198,10 @@ pub struct PhoneUserVoiceMailsResponse {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub voice_mails: Vec<VoiceMails>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Update the voicemail setting.
@@ -24491,6 +26232,10 @@ pub struct AddUserSettingRequestVoiceMail {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub download: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -24500,6 +26245,10 @@ pub struct AddUserSettingRequest {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub voice_mail: Option<AddUserSettingRequestVoiceMail>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -24538,12 +26287,20 @@ pub struct AddUserSettingResponseVoiceMail {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub shared_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AddUserSettingResponse {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub voice_mail: Option<AddUserSettingResponseVoiceMail>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Update the voicemail setting.
@@ -24583,6 +26340,10 @@ pub struct UpdateUserSettingRequestVoiceMail {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub shared_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -24592,6 +26353,10 @@ pub struct UpdateUserSettingRequest {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub voice_mail: Option<UpdateUserSettingRequestVoiceMail>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -24617,8 +26382,9 @@ pub enum CallType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for CallType {
@@ -24630,7 +26396,7 @@ impl std::fmt::Display for CallType {
             CallType::Tollfree => "tollfree",
             CallType::Voip => "voip",
             CallType::Noop => "",
-            CallType::FallthroughString => "*",
+            CallType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -24673,6 +26439,10 @@ pub struct Owner {
     pub name: String,
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
     pub type_: Option<PhoneUserCallLogsResponseForwardedByExtensionType>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -24892,6 +26662,10 @@ pub struct AccountCallLogsResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub waiting_time: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Account Call Logs
@@ -24960,6 +26734,10 @@ pub struct AccountCallLogsResponseData {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_records: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -24970,6 +26748,10 @@ pub struct AddByocNumberResponse {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub phone_numbers: Vec<PhoneUserResponseNumbers>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -24984,6 +26766,10 @@ pub struct CallingPlans {
         rename = "type"
     )]
     pub type_: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -24994,6 +26780,10 @@ pub struct AssignCallingPlanRequest {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub calling_plans: Vec<CallingPlans>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -25008,8 +26798,9 @@ pub enum GetPhoneRecordingsResponseOwnerType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GetPhoneRecordingsResponseOwnerType {
@@ -25018,7 +26809,7 @@ impl std::fmt::Display for GetPhoneRecordingsResponseOwnerType {
             GetPhoneRecordingsResponseOwnerType::CallQueue => "call queue",
             GetPhoneRecordingsResponseOwnerType::User => "user",
             GetPhoneRecordingsResponseOwnerType::Noop => "",
-            GetPhoneRecordingsResponseOwnerType::FallthroughString => "*",
+            GetPhoneRecordingsResponseOwnerType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -25065,6 +26856,10 @@ pub struct GetPhoneRecordingsResponseOwner {
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
     pub type_: Option<GetPhoneRecordingsResponseOwnerType>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -25079,8 +26874,9 @@ pub enum Direction {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Direction {
@@ -25089,7 +26885,7 @@ impl std::fmt::Display for Direction {
             Direction::Inbound => "inbound",
             Direction::Outbound => "outbound",
             Direction::Noop => "",
-            Direction::FallthroughString => "*",
+            Direction::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -25235,6 +27031,10 @@ pub struct GetPhoneRecordingsResponse {
     pub recording_type: String,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub site: Option<Site>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -25272,6 +27072,10 @@ pub struct GetPhoneRecordingsResponseData {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_records: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -25330,6 +27134,10 @@ pub struct ByocSipTrunk {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub sbc_label: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -25358,6 +27166,10 @@ pub struct ListByocsipTrunkResponse {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub page_size: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -25389,6 +27201,10 @@ pub struct SipTrunks {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -25402,6 +27218,10 @@ pub struct PostPhoneSipTrunkRequest {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub sip_trunks: Vec<SipTrunks>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -25424,6 +27244,10 @@ pub struct UpdatePhoneSipTrunkRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -25455,6 +27279,10 @@ pub struct AccountPlanBaseDeleteRequest {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub reason: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -25467,8 +27295,9 @@ pub enum AccountPlanAddonCancelRequestAction {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AccountPlanAddonCancelRequestAction {
@@ -25476,7 +27305,7 @@ impl std::fmt::Display for AccountPlanAddonCancelRequestAction {
         match self {
             AccountPlanAddonCancelRequestAction::Cancel => "cancel",
             AccountPlanAddonCancelRequestAction::Noop => "",
-            AccountPlanAddonCancelRequestAction::FallthroughString => "*",
+            AccountPlanAddonCancelRequestAction::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -25524,6 +27353,10 @@ pub struct AccountPlanAddonCancelRequest {
         rename = "type"
     )]
     pub type_: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -25583,6 +27416,10 @@ pub struct ActivityLogs {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub version: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Report object
@@ -25633,6 +27470,10 @@ pub struct ReportSignInOutActivitiesResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub to: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -25764,6 +27605,10 @@ pub struct GetGroupSettingsResponseScheduleMeeting {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub use_pmi_for_schedule_meetings: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -26123,6 +27968,10 @@ pub struct GetGroupSettingsResponseInMeeting {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub who_can_share_screen_when_someone_is_sharing: Option<WhoCanShareScreen>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -26167,6 +28016,10 @@ pub struct GetGroupSettingsResponseEmailNotification {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub schedule_for_host_reminder: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// [Archiving solution](https://support.zoom.us/hc/en-us/articles/360050431572-Archiving-Meeting-and-Webinar-data) settings. This setting can only be used if you have been granted archiving solution access by the Zoom Support team.
@@ -26195,6 +28048,10 @@ pub struct GetGroupSettingsResponseRecordingArchive {
         rename = "type"
     )]
     pub type_: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -26331,6 +28188,10 @@ pub struct GetGroupSettingsResponseRecording {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub show_timestamp: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -26357,6 +28218,10 @@ pub struct GetGroupSettingsResponseTelephony {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub third_party_audio: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -26373,6 +28238,10 @@ pub struct GetGroupSettingsResponse {
     pub schedule_meeting: Option<GetGroupSettingsResponseScheduleMeeting>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub telephony: Option<GetGroupSettingsResponseTelephony>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types:
@@ -26527,6 +28396,10 @@ pub struct UpdateGroupSettingsRequestScheduleMeeting {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub upcoming_meeting_reminder: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -26881,6 +28754,10 @@ pub struct UpdateGroupSettingsRequestInMeeting {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub who_can_share_screen_when_someone_is_sharing: Option<WhoCanShareScreen>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -26902,6 +28779,10 @@ pub struct UpdateGroupSettingsRequestTelephony {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub third_party_audio: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -26918,6 +28799,10 @@ pub struct UpdateGroupSettingsRequest {
     pub schedule_meeting: Option<UpdateGroupSettingsRequestScheduleMeeting>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub telephony: Option<UpdateGroupSettingsRequestTelephony>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types:
@@ -26970,8 +28855,9 @@ pub enum UpdateGroupSettingsOption {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for UpdateGroupSettingsOption {
@@ -26981,7 +28867,7 @@ impl std::fmt::Display for UpdateGroupSettingsOption {
             UpdateGroupSettingsOption::MeetingSecurity => "meeting_security",
             UpdateGroupSettingsOption::RecordingAuthentication => "recording_authentication",
             UpdateGroupSettingsOption::Noop => "",
-            UpdateGroupSettingsOption::FallthroughString => "*",
+            UpdateGroupSettingsOption::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -27099,6 +28985,10 @@ pub struct GetGroupLockSettingsResponseScheduleMeeting {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub upcoming_meeting_reminder: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -27385,6 +29275,10 @@ pub struct GetGroupLockSettingsResponseInMeeting {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub whiteboard: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -27468,6 +29362,10 @@ pub struct GetGroupLockSettingsResponseRecording {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub recording_authentication: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -27488,6 +29386,10 @@ pub struct GetGroupLockSettingsResponseTelephony {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub third_party_audio: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -27502,6 +29404,10 @@ pub struct GetGroupLockSettingsResponse {
     pub schedule_meeting: Option<GetGroupLockSettingsResponseScheduleMeeting>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub telephony: Option<GetGroupLockSettingsResponseTelephony>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types:
@@ -27648,6 +29554,10 @@ pub struct GroupLockedSettingsRequestScheduleMeeting {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub upcoming_meeting_reminder: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -27724,6 +29634,10 @@ pub struct GroupLockedSettingsRequestRecording {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub recording_authentication: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -27738,6 +29652,10 @@ pub struct GroupLockedSettingsRequest {
     pub schedule_meeting: Option<GroupLockedSettingsRequestScheduleMeeting>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub telephony: Option<GetGroupLockSettingsResponseTelephony>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// All of the following types:
@@ -27782,6 +29700,10 @@ pub struct SubAccountPrivileges {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub second_level: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -27839,6 +29761,10 @@ pub struct GetRoleInformationResponse {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_members: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// This field will only be displayed to accounts that are enrolled in the partner plan and follow master accounts and sub accounts structure.
@@ -27853,6 +29779,10 @@ pub struct UpdateRoleRequestSubAccountPrivileges {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub second_level: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -27910,6 +29840,10 @@ pub struct UpdateRoleRequest {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_members: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -27942,6 +29876,10 @@ pub struct GetPlanUsageResponseBase {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub usage: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Recording Plan
@@ -28002,6 +29940,10 @@ pub struct PlanRecording {
         rename = "type"
     )]
     pub type_: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// [Zoom United](https://zoom.us/pricing/zoom-bundles) plan.
@@ -28044,6 +29986,10 @@ pub struct PlanUnited {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub usage: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -28094,6 +30040,10 @@ pub struct GetPlanUsageResponse {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub plan_zoom_rooms: Vec<GetPlanUsageResponseBase>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -28107,6 +30057,10 @@ pub struct SwitchUserAccountRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub account_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -28141,8 +30095,9 @@ pub enum GetAccountCloudRecordingResponseMeetingsFilesFileType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GetAccountCloudRecordingResponseMeetingsFilesFileType {
@@ -28156,7 +30111,7 @@ impl std::fmt::Display for GetAccountCloudRecordingResponseMeetingsFilesFileType
             GetAccountCloudRecordingResponseMeetingsFilesFileType::Tb => "TB",
             GetAccountCloudRecordingResponseMeetingsFilesFileType::Transcript => "TRANSCRIPT",
             GetAccountCloudRecordingResponseMeetingsFilesFileType::Noop => "",
-            GetAccountCloudRecordingResponseMeetingsFilesFileType::FallthroughString => "*",
+            GetAccountCloudRecordingResponseMeetingsFilesFileType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -28183,8 +30138,9 @@ pub enum GetAccountCloudRecordingResponseMeetingsFilesStatus {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GetAccountCloudRecordingResponseMeetingsFilesStatus {
@@ -28193,7 +30149,7 @@ impl std::fmt::Display for GetAccountCloudRecordingResponseMeetingsFilesStatus {
             GetAccountCloudRecordingResponseMeetingsFilesStatus::Completed => "completed",
             GetAccountCloudRecordingResponseMeetingsFilesStatus::Processing => "processing",
             GetAccountCloudRecordingResponseMeetingsFilesStatus::Noop => "",
-            GetAccountCloudRecordingResponseMeetingsFilesStatus::FallthroughString => "*",
+            GetAccountCloudRecordingResponseMeetingsFilesStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -28259,8 +30215,9 @@ pub enum GetAccountCloudRecordingResponseMeetingsFilesType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GetAccountCloudRecordingResponseMeetingsFilesType {
@@ -28289,7 +30246,7 @@ impl std::fmt::Display for GetAccountCloudRecordingResponseMeetingsFilesType {
             GetAccountCloudRecordingResponseMeetingsFilesType::Thumbnail => "thumbnail",
             GetAccountCloudRecordingResponseMeetingsFilesType::Timeline => "timeline",
             GetAccountCloudRecordingResponseMeetingsFilesType::Noop => "",
-            GetAccountCloudRecordingResponseMeetingsFilesType::FallthroughString => "*",
+            GetAccountCloudRecordingResponseMeetingsFilesType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -28409,6 +30366,10 @@ pub struct RecordingFiles {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub status: Option<GetAccountCloudRecordingResponseMeetingsFilesStatus>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -28494,6 +30455,10 @@ pub struct GetAccountCloudRecordingResponseMeetings {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub uuid: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -28552,6 +30517,10 @@ pub struct GetAccountCloudRecordingResponse {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_records: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -28601,6 +30570,10 @@ pub struct TrackingSources {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub visitor_count: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -28623,6 +30596,10 @@ pub struct GetTrackingSourcesResponse {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub tracking_sources: Vec<TrackingSources>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -28662,12 +30639,20 @@ pub struct Basic {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub support_phone: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UpdateZrAccProfileRequest {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub basic: Option<Basic>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -28682,8 +30667,9 @@ pub enum SettingType {
     #[serde(rename = "meeting")]
     #[default]
     Meeting,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SettingType {
@@ -28691,7 +30677,7 @@ impl std::fmt::Display for SettingType {
         match self {
             SettingType::Alert => "alert",
             SettingType::Meeting => "meeting",
-            SettingType::FallthroughString => "*",
+            SettingType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -28718,8 +30704,9 @@ pub enum ListZrLocationsResponseType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ListZrLocationsResponseType {
@@ -28732,7 +30719,7 @@ impl std::fmt::Display for ListZrLocationsResponseType {
             ListZrLocationsResponseType::Floor => "floor",
             ListZrLocationsResponseType::States => "states",
             ListZrLocationsResponseType::Noop => "",
-            ListZrLocationsResponseType::FallthroughString => "*",
+            ListZrLocationsResponseType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -28779,6 +30766,10 @@ pub struct AddAzrLocationResponse {
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
     pub type_: Option<ListZrLocationsResponseType>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -28807,6 +30798,10 @@ pub struct ListZrLocationsResponseData {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub page_size: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -28829,6 +30824,10 @@ pub struct AddAzrLocationRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub parent_location_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -28905,12 +30904,20 @@ pub struct GetZrLocationProfileResponseBasic {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub timezone: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetZrLocationProfileResponse {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub basic: Option<GetZrLocationProfileResponseBasic>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -28927,6 +30934,10 @@ pub struct GetZrLocationStructureResponse {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub structures: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -28992,12 +31003,20 @@ pub struct GetZrProfileResponseBasic {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub support_phone: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetZrProfileResponse {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub basic: Option<GetZrProfileResponseBasic>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -29054,12 +31073,20 @@ pub struct UpdateRoomProfileRequestBasic {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub support_phone: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UpdateRoomProfileRequest {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub basic: Option<UpdateRoomProfileRequestBasic>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -29080,8 +31107,9 @@ pub enum DeviceType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DeviceType {
@@ -29093,7 +31121,7 @@ impl std::fmt::Display for DeviceType {
             DeviceType::ZoomRoomsComputer => "Zoom Rooms Computer",
             DeviceType::ZoomRoomsControlSystem => "Zoom Rooms Control System",
             DeviceType::Noop => "",
-            DeviceType::FallthroughString => "*",
+            DeviceType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -29117,8 +31145,9 @@ pub enum ListZrDevicesResponseStatus {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ListZrDevicesResponseStatus {
@@ -29127,7 +31156,7 @@ impl std::fmt::Display for ListZrDevicesResponseStatus {
             ListZrDevicesResponseStatus::Offline => "Offline",
             ListZrDevicesResponseStatus::Online => "Online",
             ListZrDevicesResponseStatus::Noop => "",
-            ListZrDevicesResponseStatus::FallthroughString => "*",
+            ListZrDevicesResponseStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -29187,6 +31216,10 @@ pub struct Devices {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub status: Option<ListZrDevicesResponseStatus>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -29197,6 +31230,10 @@ pub struct ListZrDevicesResponse {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub devices: Vec<Devices>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -29219,6 +31256,10 @@ pub struct ListSipTrunkNumbersResponse {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_records: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -29251,6 +31292,10 @@ pub struct AssignSipConfigRequest {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub show_zoom_provided_numbers: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -29267,6 +31312,10 @@ pub struct AssignSipTrunkNumbersRequest {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub phone_numbers: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -29298,6 +31347,10 @@ pub struct InMeetingFiles {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub file_size: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -29317,6 +31370,10 @@ pub struct ListPastMeetingFilesResponse {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_records: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -29366,6 +31423,10 @@ pub struct ClientSatisfaction {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub satisfaction_percent: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -29403,6 +31464,10 @@ pub struct ListMeetingSatisfactionResponse {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_records: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -29417,8 +31482,9 @@ pub enum ListPhoneDevicesType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ListPhoneDevicesType {
@@ -29427,7 +31493,7 @@ impl std::fmt::Display for ListPhoneDevicesType {
             ListPhoneDevicesType::Assigned => "assigned",
             ListPhoneDevicesType::Unassigned => "unassigned",
             ListPhoneDevicesType::Noop => "",
-            ListPhoneDevicesType::FallthroughString => "*",
+            ListPhoneDevicesType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -29468,6 +31534,10 @@ pub struct AddAutoReceptionistResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -29482,8 +31552,9 @@ pub enum ListPhoneDevicesResponseStatus {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ListPhoneDevicesResponseStatus {
@@ -29492,7 +31563,7 @@ impl std::fmt::Display for ListPhoneDevicesResponseStatus {
             ListPhoneDevicesResponseStatus::Offline => "offline",
             ListPhoneDevicesResponseStatus::Online => "online",
             ListPhoneDevicesResponseStatus::Noop => "",
-            ListPhoneDevicesResponseStatus::FallthroughString => "*",
+            ListPhoneDevicesResponseStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -29551,6 +31622,10 @@ pub struct ListPhoneDevicesResponse {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub status: Option<ListPhoneDevicesResponseStatus>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -29588,6 +31663,10 @@ pub struct ListPhoneDevicesResponseData {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub total_records: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -29638,6 +31717,10 @@ pub struct AddPhoneDeviceRequest {
         rename = "type"
     )]
     pub type_: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// User to whom the device has been assigned.
@@ -29670,6 +31753,10 @@ pub struct GetDeviceResponseAssignee {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -29692,8 +31779,9 @@ pub enum GetDeviceResponseProvisionType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GetDeviceResponseProvisionType {
@@ -29703,7 +31791,7 @@ impl std::fmt::Display for GetDeviceResponseProvisionType {
             GetDeviceResponseProvisionType::Manual => "manual",
             GetDeviceResponseProvisionType::Ztp => "ztp",
             GetDeviceResponseProvisionType::Noop => "",
-            GetDeviceResponseProvisionType::FallthroughString => "*",
+            GetDeviceResponseProvisionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -29745,6 +31833,10 @@ pub struct LineSubscription {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub phone_number: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Return additional provisioning information with generic device SIP credentials.
@@ -29773,6 +31865,10 @@ pub struct SharedLine {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub outbound_caller_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -29836,6 +31932,10 @@ pub struct SipAccounts {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub user_name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Provisioning information of a device.
@@ -29864,6 +31964,10 @@ pub struct Provision {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -29921,6 +32025,10 @@ pub struct GetDeviceResponse {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub status: Option<ListPhoneDevicesResponseStatus>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -29952,6 +32060,10 @@ pub struct UpdateDeviceRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub mac_address: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -30022,6 +32134,10 @@ pub struct ExternalContacts {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub phone_numbers: Vec<String>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -30053,6 +32169,10 @@ pub struct ListExternalContactsResponse {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub page_size: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -30123,6 +32243,10 @@ pub struct AddExternalContactRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub routing_path: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -30193,6 +32317,10 @@ pub struct UpdateExternalContactRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub routing_path: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -30206,6 +32334,10 @@ pub struct ChangeZrLocationRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub location_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -30219,6 +32351,10 @@ pub struct ChangeParentLocationRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub parent_location_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -30246,6 +32382,10 @@ pub struct ListCallQueuesResponsePhoneNumbers {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub source: Option<Source>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -30293,6 +32433,10 @@ pub struct CallQueues {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub status: Option<ReportUsersType>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -30330,6 +32474,10 @@ pub struct ListCallQueuesResponse {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_records: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A list of one or more phone users to be included in the call queue. Provide either users or common area phone(s). Provide at least one user in the users object.
@@ -30353,6 +32501,10 @@ pub struct CreateCallQueueRequestMembers {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub users: Vec<Assistants>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -30398,6 +32550,10 @@ pub struct CreateCallQueueRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub site_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -30438,6 +32594,10 @@ pub struct CreateCallQueueResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub status: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -30454,8 +32614,9 @@ pub enum Level {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Level {
@@ -30464,7 +32625,7 @@ impl std::fmt::Display for Level {
             Level::Manager => "manager",
             Level::User => "user",
             Level::Noop => "",
-            Level::FallthroughString => "*",
+            Level::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -30511,6 +32672,10 @@ pub struct GetCallQueueResponseMembersUsers {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub receive_call: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -30527,6 +32692,10 @@ pub struct GetCallQueueResponseMembers {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub users: Vec<GetCallQueueResponseMembersUsers>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -30576,6 +32745,10 @@ pub struct GetCallQueueResponse {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub status: Option<ReportUsersType>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -30630,6 +32803,10 @@ pub struct UpdateCallQueueRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub timezone: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A maximum of 10 members can be added at a time.
@@ -30653,6 +32830,10 @@ pub struct AddMembersCallQueueRequest {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub users: Vec<Assistants>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -30662,6 +32843,10 @@ pub struct AddMembersCallQueueRequestData {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub members: Option<AddMembersCallQueueRequest>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -30675,6 +32860,10 @@ pub struct ChangeCallQueueManagerRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub member_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -30697,6 +32886,10 @@ pub struct GetPhoneNumberDetailsResponseCarrier {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -30719,6 +32912,10 @@ pub struct AddCommonAreaPhoneResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -30794,6 +32991,10 @@ pub struct GetPhoneNumberDetailsResponse {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub status: Option<ListAccountPhoneNumbersResponseStatus>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -30828,6 +33029,10 @@ pub struct UpdatePhoneNumberDetailsRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub sip_group_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -30841,6 +33046,10 @@ pub struct ChangeMainCompanyNumberRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub phone_number: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -30891,6 +33100,10 @@ pub struct ListCallingPlansResponse {
         rename = "type"
     )]
     pub type_: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -30901,6 +33114,10 @@ pub struct ListCallingPlansResponseData {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub calling_plans: Vec<ListCallingPlansResponse>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -30924,6 +33141,10 @@ pub struct ListPhoneUsersResponseCallingPlans {
         rename = "type"
     )]
     pub type_: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -30990,6 +33211,10 @@ pub struct ListPhoneUsersResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub status: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -31027,6 +33252,10 @@ pub struct ListPhoneUsersResponseData {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub users: Vec<ListPhoneUsersResponse>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -31049,6 +33278,10 @@ pub struct UpdateAutoReceptionistRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -31115,6 +33348,10 @@ pub struct CommonAreaPhones {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub status: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -31152,6 +33389,10 @@ pub struct ListCommonAreaPhonesResponse {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_records: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -31238,6 +33479,10 @@ pub struct AddCommonAreaPhoneRequest {
         rename = "type"
     )]
     pub type_: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -31296,6 +33541,10 @@ pub struct GetCommonAreaPhoneResponseProvisionSipAccounts {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub user_name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Provisioning information of the common area phone.
@@ -31324,6 +33573,10 @@ pub struct GetCommonAreaPhoneResponseProvision {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -31360,6 +33613,10 @@ pub struct GetCommonAreaPhoneResponseNumbers {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub source: Option<Source>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -31390,6 +33647,10 @@ pub struct ProvisionTemplate {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub supported: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 ///
@@ -31403,6 +33664,10 @@ pub struct InternationalCall {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub enable: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -31419,8 +33684,9 @@ pub enum GetCommonAreaPhoneResponsePolicyHotDeskingStatus {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GetCommonAreaPhoneResponsePolicyHotDeskingStatus {
@@ -31430,7 +33696,7 @@ impl std::fmt::Display for GetCommonAreaPhoneResponsePolicyHotDeskingStatus {
             GetCommonAreaPhoneResponsePolicyHotDeskingStatus::On => "on",
             GetCommonAreaPhoneResponsePolicyHotDeskingStatus::Unsupported => "unsupported",
             GetCommonAreaPhoneResponsePolicyHotDeskingStatus::Noop => "",
-            GetCommonAreaPhoneResponsePolicyHotDeskingStatus::FallthroughString => "*",
+            GetCommonAreaPhoneResponsePolicyHotDeskingStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -31450,6 +33716,10 @@ pub struct HotDesking {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub status: Option<GetCommonAreaPhoneResponsePolicyHotDeskingStatus>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -31469,6 +33739,10 @@ pub struct GetCommonAreaPhoneResponsePolicy {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub select_outbound_caller_id: Option<InternationalCall>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -31541,6 +33815,10 @@ pub struct GetCommonAreaPhoneResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub status: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -31554,6 +33832,10 @@ pub struct UpdateCommonAreaPhoneRequestPolicy {
     pub international_call: Option<ShowDeviceIpCallLog>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub select_outbound_caller_id: Option<ShowDeviceIpCallLog>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -31605,6 +33887,10 @@ pub struct UpdateCommonAreaPhoneRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub site_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -31615,6 +33901,10 @@ pub struct AssignPhoneNumbersCommonAreaRequest {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub phone_numbers: Vec<PhoneUserResponseNumbers>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -31637,6 +33927,10 @@ pub struct AssignPhoneNumbersCommonAreaResponse {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub number: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -31647,6 +33941,10 @@ pub struct AssignPhoneNumbersCommonAreaResponseData {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub phone_numbers: Vec<AssignPhoneNumbersCommonAreaResponse>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -31661,6 +33959,10 @@ pub struct AssignCallingPlansCommonAreaPhoneRequest {
         rename = "type"
     )]
     pub type_: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -31671,6 +33973,10 @@ pub struct AssignCallingPlansCommonAreaPhoneRequestData {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub calling_plans: Vec<AssignCallingPlansCommonAreaPhoneRequest>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -31694,6 +34000,10 @@ pub struct AssignCallingPlansCommonAreaPhoneResponse {
         rename = "type"
     )]
     pub type_: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -31704,6 +34014,10 @@ pub struct AssignCallingPlansCommonAreaPhoneResponseData {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub calling_plans: Vec<AssignCallingPlansCommonAreaPhoneResponse>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -31720,8 +34034,9 @@ pub enum MatchType {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for MatchType {
@@ -31730,7 +34045,7 @@ impl std::fmt::Display for MatchType {
             MatchType::PhoneNumber => "phoneNumber",
             MatchType::Prefix => "prefix",
             MatchType::Noop => "",
-            MatchType::FallthroughString => "*",
+            MatchType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -31788,6 +34103,10 @@ pub struct BlockedList {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub status: Option<ReportUsersType>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -31825,6 +34144,10 @@ pub struct ListBlockedResponse {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_records: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -31864,6 +34187,10 @@ pub struct UpdateBlockedListRequest {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub status: Option<ReportUsersType>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -31923,6 +34250,10 @@ pub struct BillingReports {
         rename = "type"
     )]
     pub type_: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -31942,6 +34273,10 @@ pub struct GetBillingReportResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub currency: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -32009,6 +34344,10 @@ pub struct Invoices {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub total_amount: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -32028,6 +34367,10 @@ pub struct GetBillingInvoicesReportsResponse {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub invoices: Vec<Invoices>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -32055,6 +34398,10 @@ pub struct ListSharedLineGroupsResponsePhoneNumbers {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub status: Option<ListAccountPhoneNumbersResponseStatus>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -32102,6 +34449,10 @@ pub struct SharedLineGroups {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub status: Option<ReportUsersType>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -32139,6 +34490,10 @@ pub struct ListSharedLineGroupsResponse {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_records: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -32179,6 +34534,10 @@ pub struct CreateSharedLineGroupRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub site_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Site assigned to the Shared Line Group.
@@ -32202,6 +34561,10 @@ pub struct GetSharedLineGroupResponseSite {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// View current [members](https://support.zoom.us/hc/en-us/articles/360038850792-Setting-up-shared-line-groups#h_3ffbbb77-a009-4c09-91e4-81fc264b61d6) of the shared line group.
@@ -32225,6 +34588,10 @@ pub struct GetSharedLineGroupResponseMembers {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub users: Vec<Site>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -32295,6 +34662,10 @@ pub struct GetSharedLineGroupResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub timezone: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// If you have multiple direct phone numbers assigned to the shared line group, select a number from those numbers as the primary number. The primary number shares the same line as the extension number. This means if a caller is routed to the shared line group through an auto receptionist, the line associated with the primary number will be used. A pending number cannot be used as a Primary Number.
@@ -32318,6 +34689,10 @@ pub struct PrimaryNumber {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub number: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -32359,6 +34734,10 @@ pub struct UpdateSharedLineGroupRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub timezone: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Members can comprise of users on the account as well as common area phones. You can add a maximum of 10 members at once.
@@ -32382,6 +34761,10 @@ pub struct AddMembersSharedLineGroupRequest {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub users: Vec<Assistants>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -32391,6 +34774,10 @@ pub struct AddMembersSharedLineGroupRequestData {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub members: Option<AddMembersSharedLineGroupRequest>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -32413,6 +34800,10 @@ pub struct AddAutoReceptionistRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub site_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -32516,6 +34907,10 @@ pub struct GetCallQueueRecordingsResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -32571,6 +34966,10 @@ pub struct GetCallQueueRecordingsResponseData {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub total_records: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -32611,6 +35010,10 @@ pub struct Params {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub resource_email: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -32626,6 +35029,10 @@ pub struct CheckInRoomsRequest {
     pub method: String,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub params: Option<Params>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -32639,6 +35046,10 @@ pub struct InMeetingRecordingControlRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub method: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -32697,6 +35108,10 @@ pub struct ListSipTrunksResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub sip_server_address: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -32716,6 +35131,10 @@ pub struct ListSipTrunksResponseData {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_records: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -32747,6 +35166,10 @@ pub struct AssignSipTrunksRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub outbound_caller_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -32760,6 +35183,10 @@ pub struct AssignSipTrunksRequestData {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub sip_trunks: Vec<AssignSipTrunksRequest>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -32809,6 +35236,10 @@ pub struct AssignSipTrunksResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub sip_server_address: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -32819,6 +35250,10 @@ pub struct AssignSipTrunksResponseData {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub sip_trunks: Vec<AssignSipTrunksResponse>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -32850,6 +35285,10 @@ pub struct CalloutCountries {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -32869,6 +35308,10 @@ pub struct ListInternalCalloutCountriesResponse {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_records: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -32882,6 +35325,10 @@ pub struct AddCalloutCountriesRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -32895,6 +35342,10 @@ pub struct AddCalloutCountriesRequestData {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub callout_countries: Vec<AddCalloutCountriesRequest>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -32905,6 +35356,10 @@ pub struct AddCalloutCountriesResponse {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub callout_countries: Vec<CalloutCountries>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -32921,8 +35376,9 @@ pub enum Languages {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Languages {
@@ -32932,7 +35388,7 @@ impl std::fmt::Display for Languages {
             Languages::EnGb => "en-GB",
             Languages::EnUs => "en-US",
             Languages::Noop => "",
-            Languages::FallthroughString => "*",
+            Languages::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -33021,6 +35477,10 @@ pub struct InternalNumbers {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub visible: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -33058,6 +35518,10 @@ pub struct ListInternalNumbersResponse {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_records: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -33068,6 +35532,10 @@ pub struct AddInternalNumbersRequest {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub internal_numbers: Vec<InternalNumbers>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -33156,6 +35624,10 @@ pub struct AddInternalNumbersResponse {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub visible: bool,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -33166,6 +35638,10 @@ pub struct AddInternalNumbersResponseData {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub internal_numbers: Vec<AddInternalNumbersResponse>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -33186,8 +35662,9 @@ pub enum ChangeZoomRoomsAppVersionRequestAction {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ChangeZoomRoomsAppVersionRequestAction {
@@ -33197,7 +35674,7 @@ impl std::fmt::Display for ChangeZoomRoomsAppVersionRequestAction {
             ChangeZoomRoomsAppVersionRequestAction::Downgrade => "downgrade",
             ChangeZoomRoomsAppVersionRequestAction::Upgrade => "upgrade",
             ChangeZoomRoomsAppVersionRequestAction::Noop => "",
-            ChangeZoomRoomsAppVersionRequestAction::FallthroughString => "*",
+            ChangeZoomRoomsAppVersionRequestAction::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -33220,6 +35697,10 @@ pub struct ChangeZoomRoomsAppVersionRequest {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub action: Option<ChangeZoomRoomsAppVersionRequestAction>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -33248,6 +35729,10 @@ pub struct ListDigitalSignageContentResponse {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub page_size: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -33267,6 +35752,10 @@ pub struct ListWebinarTemplatesResponse {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_records: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -33308,8 +35797,9 @@ pub enum UpdatePresenceStatusRequest {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for UpdatePresenceStatusRequest {
@@ -33323,7 +35813,7 @@ impl std::fmt::Display for UpdatePresenceStatusRequest {
             UpdatePresenceStatusRequest::OnACall => "On_A_Call",
             UpdatePresenceStatusRequest::Presenting => "Presenting",
             UpdatePresenceStatusRequest::Noop => "",
-            UpdatePresenceStatusRequest::FallthroughString => "*",
+            UpdatePresenceStatusRequest::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -33368,6 +35858,10 @@ pub struct UpdatePresenceStatusRequestData {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub status: Option<UpdatePresenceStatusRequest>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Caller object contains information of the caller.
@@ -33463,6 +35957,10 @@ pub struct Caller {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub site_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Callee object contains information of the callee.
@@ -33558,6 +36056,10 @@ pub struct Callee {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub site_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -33617,6 +36119,10 @@ pub struct ListCallLogsMetricsResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub mos: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -33675,6 +36181,10 @@ pub struct ListCallLogsMetricsResponseData {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_records: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -33733,6 +36243,10 @@ pub struct Qos {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub network_delay: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -33748,6 +36262,10 @@ pub struct Sending {
     pub date_time: Option<chrono::DateTime<chrono::Utc>>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub qos: Option<Qos>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Quality of Service object that represents the call quality data of the caller.
@@ -33789,6 +36307,10 @@ pub struct CallerQos {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub sending: Vec<Sending>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -33829,6 +36351,10 @@ pub struct CalleeQos {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub sending: Vec<Sending>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -33849,6 +36375,10 @@ pub struct GetCallQoSResponse {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub caller_qos: Option<CallerQos>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -33871,6 +36401,10 @@ pub struct CreateBatchPollsRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub title: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -33907,6 +36441,10 @@ pub struct Polls {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub title: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -33917,6 +36455,10 @@ pub struct CreateBatchPollsResponse {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub polls: Vec<Polls>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -33930,6 +36472,10 @@ pub struct ManageE911SignageResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub event_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -34015,6 +36561,10 @@ pub struct AccountBillingInvoicesResponse {
         deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub total_amount: f64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -34034,6 +36584,10 @@ pub struct AccountBillingInvoicesResponseData {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub invoices: Vec<AccountBillingInvoicesResponse>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -34110,6 +36664,10 @@ pub struct InvoiceItems {
         deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub total_amount: f64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -34210,6 +36768,10 @@ pub struct GetAccountBillingInvoiceResponse {
         deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub total_amount: f64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -34227,8 +36789,9 @@ pub enum Quality {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Quality {
@@ -34237,7 +36800,7 @@ impl std::fmt::Display for Quality {
             Quality::Good => "GOOD",
             Quality::NotGood => "NOT GOOD",
             Quality::Noop => "",
-            Quality::FallthroughString => "*",
+            Quality::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -34286,6 +36849,10 @@ pub struct ParticipantFeedbackResponseParticipants {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub user_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -34314,6 +36881,10 @@ pub struct ParticipantFeedbackResponse {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub participants: Vec<ParticipantFeedbackResponseParticipants>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -34330,8 +36901,9 @@ pub enum Result {
     #[serde(rename = "")]
     #[default]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Result {
@@ -34341,7 +36913,7 @@ impl std::fmt::Display for Result {
             Result::NoAnswer => "no_answer",
             Result::Recorded => "recorded",
             Result::Noop => "",
-            Result::FallthroughString => "*",
+            Result::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -34392,6 +36964,10 @@ pub struct ForwardTo {
         rename = "type"
     )]
     pub type_: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -34459,6 +37035,10 @@ pub struct LogDetails {
     pub result: Option<Result>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub site: Option<Site>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -34622,6 +37202,10 @@ pub struct GetCallLogDetailsResponse {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub result: Option<Result>,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -34665,6 +37249,10 @@ pub struct AddByocNumberRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub site_id: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -34697,6 +37285,10 @@ pub struct ListMeetingTemplatesResponse {
         rename = "type"
     )]
     pub type_: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -34716,6 +37308,10 @@ pub struct ListMeetingTemplatesResponseData {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_records: i64,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /**
@@ -34728,8 +37324,9 @@ pub enum ListArchivedFilesQueryDateType {
     #[serde(rename = "meeting_start_time")]
     #[default]
     MeetingStartTime,
-    #[serde(other)]
-    FallthroughString,
+    #[serde(untagged)]
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ListArchivedFilesQueryDateType {
@@ -34737,7 +37334,7 @@ impl std::fmt::Display for ListArchivedFilesQueryDateType {
         match self {
             ListArchivedFilesQueryDateType::ArchiveCompleteTime => "archive_complete_time",
             ListArchivedFilesQueryDateType::MeetingStartTime => "meeting_start_time",
-            ListArchivedFilesQueryDateType::FallthroughString => "*",
+            ListArchivedFilesQueryDateType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
@@ -34801,6 +37398,10 @@ pub struct ListArchivedFilesResponseMeetingsArchive {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub status: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -34902,6 +37503,10 @@ pub struct ListArchivedFilesResponseMeetings {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub uuid: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -34948,4 +37553,8 @@ pub struct ListArchivedFilesResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub to: String,
+    /// The fields of the object that are not in the spec.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
@@ -1,6 +1,604 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Users::get_page`], set with a builder.
+pub struct UsersGetPageRequest<'a> {
+    client: &'a Users,
+    status: Option<crate::types::UsersStatus>,
+    page_size: Option<i64>,
+    role_id: Option<&'a str>,
+    page_number: Option<&'a str>,
+    include_fields: Option<crate::types::UsersIncludeFields>,
+    next_page_token: Option<&'a str>,
+}
+
+impl<'a> UsersGetPageRequest<'a> {
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: crate::types::UsersStatus) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Sets the `role_id` query parameter.
+    pub fn role_id(mut self, role_id: &'a str) -> Self {
+        self.role_id = Some(role_id);
+        self
+    }
+
+    /// Sets the `page_number` query parameter.
+    pub fn page_number(mut self, page_number: &'a str) -> Self {
+        self.page_number = Some(page_number);
+        self
+    }
+
+    /// Sets the `include_fields` query parameter.
+    pub fn include_fields(mut self, include_fields: crate::types::UsersIncludeFields) -> Self {
+        self.include_fields = Some(include_fields);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.next_page_token = Some(next_page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::UsersResponse>>> {
+        let Self {
+            client,
+            status,
+            page_size,
+            role_id,
+            page_number,
+            include_fields,
+            next_page_token,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = include_fields {
+            query_args.push(("include_fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = next_page_token {
+            query_args.push(("next_page_token".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_number {
+            query_args.push(("page_number".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_size {
+            query_args.push(("page_size".to_string(), v.to_string()));
+        }
+        if let Some(v) = role_id {
+            query_args.push(("role_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = status {
+            query_args.push(("status".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(&format!("/users?{}", query_), None);
+        let resp: crate::Response<crate::types::UsersResponseData> = client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await?;
+
+        // Return our response data.
+        Ok(crate::Response::new(
+            resp.status,
+            resp.headers,
+            resp.body.users.to_vec(),
+        ))
+    }
+}
+
+/// The parameters of [`Users::user`], set with a builder.
+pub struct UsersUserRequest<'a> {
+    client: &'a Users,
+    user_id: &'a str,
+    login_type: Option<crate::types::LoginType>,
+    encrypted_email: Option<bool>,
+}
+
+impl<'a> UsersUserRequest<'a> {
+    /// Sets the `login_type` query parameter.
+    pub fn login_type(mut self, login_type: crate::types::LoginType) -> Self {
+        self.login_type = Some(login_type);
+        self
+    }
+
+    /// Sets the `encrypted_email` query parameter.
+    pub fn encrypted_email(mut self, encrypted_email: bool) -> Self {
+        self.encrypted_email = Some(encrypted_email);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::UserResponseAllOf>> {
+        let Self {
+            client,
+            user_id,
+            login_type,
+            encrypted_email,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = encrypted_email {
+            query_args.push(("encrypted_email".to_string(), v.to_string()));
+        }
+        if let Some(v) = login_type {
+            query_args.push(("login_type".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/users/{}?{}",
+                crate::progenitor_support::encode_path(user_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Users::delete`], set with a builder.
+pub struct UsersDeleteRequest<'a> {
+    client: &'a Users,
+    user_id: &'a str,
+    action: Option<crate::types::UserDeleteAction>,
+    transfer_email: Option<&'a str>,
+    transfer_meeting: Option<bool>,
+    transfer_webinar: Option<bool>,
+    transfer_recording: Option<bool>,
+}
+
+impl<'a> UsersDeleteRequest<'a> {
+    /// Sets the `action` query parameter.
+    pub fn action(mut self, action: crate::types::UserDeleteAction) -> Self {
+        self.action = Some(action);
+        self
+    }
+
+    /// Sets the `transfer_email` query parameter.
+    pub fn transfer_email(mut self, transfer_email: &'a str) -> Self {
+        self.transfer_email = Some(transfer_email);
+        self
+    }
+
+    /// Sets the `transfer_meeting` query parameter.
+    pub fn transfer_meeting(mut self, transfer_meeting: bool) -> Self {
+        self.transfer_meeting = Some(transfer_meeting);
+        self
+    }
+
+    /// Sets the `transfer_webinar` query parameter.
+    pub fn transfer_webinar(mut self, transfer_webinar: bool) -> Self {
+        self.transfer_webinar = Some(transfer_webinar);
+        self
+    }
+
+    /// Sets the `transfer_recording` query parameter.
+    pub fn transfer_recording(mut self, transfer_recording: bool) -> Self {
+        self.transfer_recording = Some(transfer_recording);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            user_id,
+            action,
+            transfer_email,
+            transfer_meeting,
+            transfer_webinar,
+            transfer_recording,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = action {
+            query_args.push(("action".to_string(), v.to_string()));
+        }
+        if let Some(v) = transfer_email {
+            query_args.push(("transfer_email".to_string(), v.to_string()));
+        }
+        if let Some(v) = transfer_meeting {
+            query_args.push(("transfer_meeting".to_string(), v.to_string()));
+        }
+        if let Some(v) = transfer_recording {
+            query_args.push(("transfer_recording".to_string(), v.to_string()));
+        }
+        if let Some(v) = transfer_webinar {
+            query_args.push(("transfer_webinar".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/users/{}?{}",
+                crate::progenitor_support::encode_path(user_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .delete(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Users::update`], set with a builder.
+pub struct UsersUpdateRequest<'a> {
+    client: &'a Users,
+    user_id: &'a str,
+    login_type: Option<crate::types::LoginType>,
+    body: &'a crate::types::UserUpdate,
+}
+
+impl<'a> UsersUpdateRequest<'a> {
+    /// Sets the `login_type` query parameter.
+    pub fn login_type(mut self, login_type: crate::types::LoginType) -> Self {
+        self.login_type = Some(login_type);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            user_id,
+            login_type,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = login_type {
+            query_args.push(("login_type".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/users/{}?{}",
+                crate::progenitor_support::encode_path(user_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .patch(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Users::setting`], set with a builder.
+pub struct UsersSettingRequest<'a> {
+    client: &'a Users,
+    user_id: &'a str,
+    login_type: Option<crate::types::LoginType>,
+    option: Option<crate::types::OptionData>,
+    custom_query_fields: Option<&'a str>,
+}
+
+impl<'a> UsersSettingRequest<'a> {
+    /// Sets the `login_type` query parameter.
+    pub fn login_type(mut self, login_type: crate::types::LoginType) -> Self {
+        self.login_type = Some(login_type);
+        self
+    }
+
+    /// Sets the `option` query parameter.
+    pub fn option(mut self, option: crate::types::OptionData) -> Self {
+        self.option = Some(option);
+        self
+    }
+
+    /// Sets the `custom_query_fields` query parameter.
+    pub fn custom_query_fields(mut self, custom_query_fields: &'a str) -> Self {
+        self.custom_query_fields = Some(custom_query_fields);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::UserSettingsResponseOneOf>> {
+        let Self {
+            client,
+            user_id,
+            login_type,
+            option,
+            custom_query_fields,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = custom_query_fields {
+            query_args.push(("custom_query_fields".to_string(), v.to_string()));
+        }
+        if let Some(v) = login_type {
+            query_args.push(("login_type".to_string(), v.to_string()));
+        }
+        if let Some(v) = option {
+            query_args.push(("option".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/users/{}/settings?{}",
+                crate::progenitor_support::encode_path(user_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Users::settings_update`], set with a builder.
+pub struct UsersSettingsUpdateRequest<'a> {
+    client: &'a Users,
+    option: Option<crate::types::UserSettingsUpdateOption>,
+    user_id: &'a str,
+    body: &'a crate::types::UserSettingsUpdateRequestOneOf,
+}
+
+impl<'a> UsersSettingsUpdateRequest<'a> {
+    /// Sets the `option` query parameter.
+    pub fn option(mut self, option: crate::types::UserSettingsUpdateOption) -> Self {
+        self.option = Some(option);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            option,
+            user_id,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = option {
+            query_args.push(("option".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/users/{}/settings?{}",
+                crate::progenitor_support::encode_path(user_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .patch(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Users::token`], set with a builder.
+pub struct UsersTokenRequest<'a> {
+    client: &'a Users,
+    user_id: &'a str,
+    type_: Option<crate::types::UserTokenType>,
+    ttl: Option<i64>,
+}
+
+impl<'a> UsersTokenRequest<'a> {
+    /// Sets the `type` query parameter.
+    pub fn type_(mut self, type_: crate::types::UserTokenType) -> Self {
+        self.type_ = Some(type_);
+        self
+    }
+
+    /// Sets the `ttl` query parameter.
+    pub fn ttl(mut self, ttl: i64) -> Self {
+        self.ttl = Some(ttl);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::UserZakResponse>> {
+        let Self {
+            client,
+            user_id,
+            type_,
+            ttl,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = ttl {
+            query_args.push(("ttl".to_string(), v.to_string()));
+        }
+        if let Some(v) = type_ {
+            query_args.push(("type".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/users/{}/token?{}",
+                crate::progenitor_support::encode_path(user_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Users::email`], set with a builder.
+pub struct UsersEmailRequest<'a> {
+    client: &'a Users,
+    email: Option<&'a str>,
+}
+
+impl<'a> UsersEmailRequest<'a> {
+    /// Sets the `email` query parameter.
+    pub fn email(mut self, email: &'a str) -> Self {
+        self.email = Some(email);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::UserEmailResponse>> {
+        let Self { client, email } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = email {
+            query_args.push(("email".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(&format!("/users/email?{}", query_), None);
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Users::vanity_name`], set with a builder.
+pub struct UsersVanityNameRequest<'a> {
+    client: &'a Users,
+    vanity_name: Option<&'a str>,
+}
+
+impl<'a> UsersVanityNameRequest<'a> {
+    /// Sets the `vanity_name` query parameter.
+    pub fn vanity_name(mut self, vanity_name: &'a str) -> Self {
+        self.vanity_name = Some(vanity_name);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::UserVanityNameResponse>> {
+        let Self {
+            client,
+            vanity_name,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = vanity_name {
+            query_args.push(("vanity_name".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client
+            .client
+            .url(&format!("/users/vanity_name?{}", query_), None);
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Users::del_vb`], set with a builder.
+pub struct UsersDelVbRequest<'a> {
+    client: &'a Users,
+    user_id: &'a str,
+    file_ids: Option<&'a str>,
+}
+
+impl<'a> UsersDelVbRequest<'a> {
+    /// Sets the `file_ids` query parameter.
+    pub fn file_ids(mut self, file_ids: &'a str) -> Self {
+        self.file_ids = Some(file_ids);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            user_id,
+            file_ids,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = file_ids {
+            query_args.push(("file_ids".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/users/{}/settings/virtual_backgrounds?{}",
+                crate::progenitor_support::encode_path(user_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .delete(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct Users {
     pub client: Client,
 }
@@ -84,6 +682,28 @@ impl Users {
             resp.body.users.to_vec(),
         ))
     }
+    /**
+     * List users.
+     *
+     * This function performs a `GET` to the `/users` endpoint.
+     *
+     * As opposed to `get_page`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Use this API to list your account's users.
+     *
+     * **Scopes:** `user:read:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     */
+    pub fn get_page_request(&self) -> UsersGetPageRequest<'_> {
+        UsersGetPageRequest {
+            client: self,
+            status: None,
+            page_size: None,
+            role_id: None,
+            page_number: None,
+            include_fields: None,
+            next_page_token: None,
+        }
+    }
     /**
      * List users.
      *
@@ -113,11 +733,11 @@ impl Users {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/users?{}", query_), None);
-        let crate::Response::<crate::types::UsersResponseData> {
-            mut status,
-            mut headers,
-            mut body,
-        } = self
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let mut pages = crate::utils::Pages::new(self.client.page_limits);
+        pages.visit(&url)?;
+
+        let mut response: crate::Response<crate::types::UsersResponseData> = self
             .client
             .get(
                 &url,
@@ -127,56 +747,85 @@ impl Users {
                 },
             )
             .await?;
-
-        let mut users = body.users;
-        let mut page = body.next_page_token;
+        let mut users = Vec::new();
 
         // Paginate if we should.
-        while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                crate::Response::<crate::types::UsersResponseData> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}?next_page_token={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
+        loop {
+            let page = if response.body.next_page_token.is_empty() {
+                None
             } else {
-                crate::Response::<crate::types::UsersResponseData> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}&next_page_token={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
-
-            users.append(&mut body.users);
+                Some(format!(
+                    "{}{}next_page_token={}",
+                    url, sep, response.body.next_page_token
+                ))
+            };
+            let more = pages.keep(&mut response.body.users);
+            users.append(&mut response.body.users);
 
-            if !body.next_page_token.is_empty() && body.next_page_token != page {
-                page = body.next_page_token.to_string();
-            } else {
-                page = "".to_string();
-            }
+            let next = match page {
+                Some(next) if more && pages.visit(&next)? => next,
+                _ => break,
+            };
+            response = self
+                .client
+                .get(
+                    &next,
+                    crate::Message {
+                        body: None,
+                        content_type: None,
+                    },
+                )
+                .await?;
         }
 
         // Return our response data.
-        Ok(crate::Response::new(status, headers, users))
+        Ok(crate::Response::new(
+            response.status,
+            response.headers,
+            users,
+        ))
+    }
+    /**
+     * List users.
+     *
+     * This function performs a `GET` to the `/users` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Use this API to list your account's users.
+     *
+     * **Scopes:** `user:read:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     */
+    pub fn get_all_stream(
+        &self,
+        status: crate::types::UsersStatus,
+        role_id: &str,
+        include_fields: crate::types::UsersIncludeFields,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::UsersResponse>> + Unpin + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !include_fields.to_string().is_empty() {
+            query_args.push(("include_fields".to_string(), include_fields.to_string()));
+        }
+        if !role_id.is_empty() {
+            query_args.push(("role_id".to_string(), role_id.to_string()));
+        }
+        if !status.to_string().is_empty() {
+            query_args.push(("status".to_string(), status.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/users?{}", query_), None);
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let next = format!("{}{}next_page_token=", url, sep);
+        self.client
+            .get_pages_stream(url, move |body: crate::types::UsersResponseData| {
+                let page = if body.next_page_token.is_empty() {
+                    None
+                } else {
+                    Some(format!("{}{}", next, body.next_page_token))
+                };
+
+                (body.users, page)
+            })
     }
     /**
      * Create users.
@@ -260,6 +909,27 @@ impl Users {
             )
             .await
     }
+    /**
+     * Get a user.
+     *
+     * This function performs a `GET` to the `/users/{userId}` endpoint.
+     *
+     * As opposed to `user`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * View a specific user's information on a Zoom account. A Zoom account can have one or more users. For user-level apps, pass [the `me` value](https://marketplace.zoom.us/docs/api-reference/using-zoom-apis#mekeyword) instead of the `userId` parameter.
+     *
+     * <p style="background-color:#e1f5fe; color:#000000; padding:8px"> <b>Note:</b> Users who have not activated their account have a status of <i>pending</i>, and the <code>created_at</code> time displays the time at which the API call was made.</p>
+     *
+     * **Scopes:** `user:read:admin`, `user:read`, `user_info:read`<p style="background-color:#e1f5fe; color:#000000; padding:8px"> <b>Note:</b> The `user_info:read` scope is only available when you pass the `me` value for the `$userId` value.</p></br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Light`
+     */
+    pub fn user_request<'a>(&'a self, user_id: &'a str) -> UsersUserRequest<'a> {
+        UsersUserRequest {
+            client: self,
+            user_id,
+            login_type: None,
+            encrypted_email: None,
+        }
+    }
     /**
      * Delete a user.
      *
@@ -329,6 +999,32 @@ impl Users {
             )
             .await
     }
+    /**
+     * Delete a user.
+     *
+     * This function performs a `DELETE` to the `/users/{userId}` endpoint.
+     *
+     * As opposed to `delete`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Use this API to disassociate (unlink) a user or permanently delete a user. For user-level apps, pass [the `me` value](https://marketplace.zoom.us/docs/api-reference/using-zoom-apis#mekeyword) instead of the `userId` parameter.
+     *
+     * **Deleting** a user **permanently** removes the user and their data from Zoom. Users can create a new Zoom account using the same email address. An account owner or an account admin can transfer meetings, webinars and cloud recordings to another Zoom user account before deleting.
+     *
+     * **Disassociating** a user unlinks the user from the associated Zoom account and provides the user their own basic free Zoom account. The disassociated user can then purchase their own Zoom licenses. An account owner or account admin can transfer the user's meetings, webinars, and cloud recordings to another user before disassociation.
+     *
+     * **Scopes:** `user:write:admin`, `user:write`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Light`
+     */
+    pub fn delete_request<'a>(&'a self, user_id: &'a str) -> UsersDeleteRequest<'a> {
+        UsersDeleteRequest {
+            client: self,
+            user_id,
+            action: None,
+            transfer_email: None,
+            transfer_meeting: None,
+            transfer_webinar: None,
+            transfer_recording: None,
+        }
+    }
     /**
      * Update a user.
      *
@@ -378,6 +1074,29 @@ impl Users {
             )
             .await
     }
+    /**
+     * Update a user.
+     *
+     * This function performs a `PATCH` to the `/users/{userId}` endpoint.
+     *
+     * As opposed to `update`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Update information on a user's [Zoom profile](https://support.zoom.us/hc/en-us/articles/201363203-My-Profile). For user-level apps, pass [the `me` value](https://marketplace.zoom.us/docs/api-reference/using-zoom-apis#mekeyword) instead of the `userId` parameter.
+     *
+     * **Scopes:** `user:write:admin` `user:write`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Light`
+     */
+    pub fn update_request<'a>(
+        &'a self,
+        user_id: &'a str,
+        body: &'a crate::types::UserUpdate,
+    ) -> UsersUpdateRequest<'a> {
+        UsersUpdateRequest {
+            client: self,
+            user_id,
+            login_type: None,
+            body,
+        }
+    }
     /**
      * Get user's ZAK.
      *
@@ -997,6 +1716,26 @@ impl Users {
             )
             .await
     }
+    /**
+     * Get user settings.
+     *
+     * This function performs a `GET` to the `/users/{userId}/settings` endpoint.
+     *
+     * As opposed to `setting`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Retrieve a user's settings. For user-level apps, pass [the `me` value](https://marketplace.zoom.us/docs/api-reference/using-zoom-apis#mekeyword) instead of the `userId` parameter.
+     *
+     * **Scopes:** `user:read:admin`, `user:read`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     */
+    pub fn setting_request<'a>(&'a self, user_id: &'a str) -> UsersSettingRequest<'a> {
+        UsersSettingRequest {
+            client: self,
+            user_id,
+            login_type: None,
+            option: None,
+            custom_query_fields: None,
+        }
+    }
     /**
      * Update user settings.
      *
@@ -1040,6 +1779,29 @@ impl Users {
             )
             .await
     }
+    /**
+     * Update user settings.
+     *
+     * This function performs a `PATCH` to the `/users/{userId}/settings` endpoint.
+     *
+     * As opposed to `settings_update`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Update a user's settings. For user-level apps, pass [the `me` value](https://marketplace.zoom.us/docs/api-reference/using-zoom-apis#mekeyword) instead of the `userId` parameter.
+     *
+     * **Scopes:** `user:write:admin`, `user:write`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     */
+    pub fn settings_update_request<'a>(
+        &'a self,
+        user_id: &'a str,
+        body: &'a crate::types::UserSettingsUpdateRequestOneOf,
+    ) -> UsersSettingsUpdateRequest<'a> {
+        UsersSettingsUpdateRequest {
+            client: self,
+            option: None,
+            user_id,
+            body,
+        }
+    }
     /**
      * Update user status.
      *
@@ -1202,6 +1964,27 @@ impl Users {
             )
             .await
     }
+    /**
+     * Get a user token.
+     *
+     * This function performs a `GET` to the `/users/{userId}/token` endpoint.
+     *
+     * As opposed to `token`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Retrieve a user's token. For user-level apps, pass [the `me` value](https://marketplace.zoom.us/docs/api-reference/using-zoom-apis#mekeyword) instead of the `userId` parameter.
+     *
+     * This token is used for starting meetings with the Client SDK. If a user signed into Zoom using Google or Facebook, a null value will be returned for the token. To get the token with this API, ask the user to sign into Zoom using their email and password instead.
+     *
+     * **Scopes:** `user:read:admin`, `user:read`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Light`
+     */
+    pub fn token_request<'a>(&'a self, user_id: &'a str) -> UsersTokenRequest<'a> {
+        UsersTokenRequest {
+            client: self,
+            user_id,
+            type_: None,
+            ttl: None,
+        }
+    }
     /**
      * Revoke a user's SSO token.
      *
@@ -1273,6 +2056,28 @@ impl Users {
             )
             .await
     }
+    /**
+     * Check a user email.
+     *
+     * This function performs a `GET` to the `/users/email` endpoint.
+     *
+     * As opposed to `email`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Verify if a user's email is registered with Zoom.<br><br>
+     *
+     * <b>Note: </b>You can successfully check if a user is a registered Zoom user only if the user **signed up for Zoom via email and is within your account.** If you provide an email address of a user who is not in your account, the value of "existed_email" parameter will be "false" irrespective of whether or not the user is registered with Zoom. The response of this API call will not include users who joined Zoom using options such as "Sign in with SSO", "Sign in with Google" or "Sign in with Facebook" even if they are in the same account as yours.
+     *
+     * **Scopes:** `user:read:admin` `user:read`
+     *  
+     *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Light`
+     *
+     */
+    pub fn email_request(&self) -> UsersEmailRequest<'_> {
+        UsersEmailRequest {
+            client: self,
+            email: None,
+        }
+    }
     /**
      * Update a user's email.
      *
@@ -1352,6 +2157,25 @@ impl Users {
             )
             .await
     }
+    /**
+     * Check a user's PM room.
+     *
+     * This function performs a `GET` to the `/users/vanity_name` endpoint.
+     *
+     * As opposed to `vanity_name`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * A personal meeting room is a virtual meeting room that can be permanently assigned to a user.
+     * Use this API to check if a personal meeting room with the given name exists or not.<br><br>
+     * **Scopes:** `user:read:admin` `user:read`<br>
+     *  
+     *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Light`
+     */
+    pub fn vanity_name_request(&self) -> UsersVanityNameRequest<'_> {
+        UsersVanityNameRequest {
+            client: self,
+            vanity_name: None,
+        }
+    }
     /**
      * Switch a user's account.
      *
@@ -1526,4 +2350,25 @@ impl Users {
             )
             .await
     }
+    /**
+     * Delete virtual background files.
+     *
+     * This function performs a `DELETE` to the `/users/{userId}/settings/virtual_backgrounds` endpoint.
+     *
+     * As opposed to `del_vb`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Delete existing virtual background file(s) of a user. For user-level apps, pass [the `me` value](https://marketplace.zoom.us/docs/api-reference/using-zoom-apis#mekeyword) instead of the `userId` parameter.
+     *
+     * **Scopes:** `user:write:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Light`
+     *
+     * **Prerequisites:**
+     * * Virtual background feature must be [enabled](https://support.zoom.us/hc/en-us/articles/210707503-Virtual-Background#h_2ef28080-fce9-4ac2-b567-dc958afab1b7) on the account.
+     */
+    pub fn del_vb_request<'a>(&'a self, user_id: &'a str) -> UsersDelVbRequest<'a> {
+        UsersDelVbRequest {
+            client: self,
+            user_id,
+            file_ids: None,
+        }
+    }
 }
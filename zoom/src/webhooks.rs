@@ -0,0 +1,435 @@
+//! For validating the webhook endpoint of an app, and verifying and parsing the event
+//! notifications Zoom sends to it.
+//!
+//! FROM: <https://developers.zoom.us/docs/api/rest/webhook-reference/#verify-webhook-events>
+use std::time::{Duration, SystemTime};
+
+use hmac::{Hmac, Mac};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+
+use crate::types;
+
+/// The header Zoom signs event notifications with.
+pub const SIGNATURE_HEADER: &str = "x-zm-signature";
+
+/// The header holding the time Zoom signed a notification at, in seconds since the
+/// epoch.
+pub const TIMESTAMP_HEADER: &str = "x-zm-request-timestamp";
+
+/// How far the timestamp of a notification may be from the current time by default.
+pub const DEFAULT_TOLERANCE: Duration = Duration::from_secs(300);
+
+/// Errors returned while verifying or parsing an event notification.
+#[derive(Debug, thiserror::Error)]
+pub enum WebhookError {
+    /// A header the notification needs is missing
+    #[error("missing {0} header")]
+    MissingHeader(&'static str),
+    /// The signature or timestamp header is malformed
+    #[error("invalid x-zm-signature or x-zm-request-timestamp header")]
+    InvalidHeader,
+    /// The signature does not match the payload
+    #[error("the signature does not match the payload")]
+    SignatureMismatch,
+    /// The notification is too old, or from the future
+    #[error("the notification timestamp is outside of the tolerance")]
+    TimestampOutsideTolerance,
+    /// The payload does not match its event
+    #[error(transparent)]
+    SerdeJsonError(#[from] serde_json::Error),
+}
+
+/// An event notification, by its event.
+///
+/// Events without a variant of their own are kept as JSON.
+#[derive(Debug, Clone, PartialEq)]
+pub enum WebhookEvent {
+    /// The challenge Zoom sends to validate the endpoint, to answer with
+    /// `Webhook::validate_url`.
+    UrlValidation(UrlValidation),
+    MeetingEnded(Box<Event<MeetingObject>>),
+    MeetingStarted(Box<Event<MeetingObject>>),
+    PhoneCalleeEnded(Box<Event<PhoneCallObject>>),
+    PhoneCallerEnded(Box<Event<PhoneCallObject>>),
+    RecordingCompleted(Box<Event<RecordingObject>>),
+    Other(serde_json::Value),
+}
+
+/// The envelope of an event notification.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default, JsonSchema)]
+pub struct Event<T> {
+    /// A token to download the recording files of the event with, for
+    /// `recording.completed` when it is enabled for the app.
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub download_token: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub event: String,
+    /// When the event happened, in milliseconds since the epoch.
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub event_ts: i64,
+    pub payload: EventPayload<T>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default, JsonSchema)]
+pub struct EventPayload<T> {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub account_id: String,
+    pub object: T,
+    /// The email of the user who caused the event, if any.
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub operator: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub operator_id: String,
+}
+
+/// The meeting of `meeting.*` events.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct MeetingObject {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub host_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    #[serde(flatten)]
+    pub meeting: types::MeetingInfo,
+    /// The ID of this instance of the meeting.
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub uuid: String,
+}
+
+/// The recorded meeting of `recording.*` events.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct RecordingObject {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub account_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub host_email: String,
+    /// The ID of the meeting, which Zoom sends as a number rather than the string of
+    /// `meeting.id`.
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    #[serde(flatten)]
+    pub meeting: types::GetAccountCloudRecordingResponseMeetings,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub password: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub share_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub timezone: String,
+}
+
+/// The call of `phone.*_ended` events.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default, JsonSchema)]
+pub struct PhoneCallObject {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub call_end_time: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub call_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub callee: Option<PhoneCallParty>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub caller: Option<PhoneCallParty>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub connected_start_time: String,
+    /// How the call ended, like `Call connected` or `No Answer`.
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub handup_result: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub ringing_start_time: String,
+}
+
+/// The caller or callee of a call.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default, JsonSchema)]
+pub struct PhoneCallParty {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub connection_type: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub device_type: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub extension_number: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub extension_type: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub phone_number: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub timezone: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub user_id: String,
+}
+
+/// The challenge of an `endpoint.url_validation` notification.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default, JsonSchema)]
+pub struct UrlValidation {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "plainToken"
+    )]
+    pub plain_token: String,
+}
+
+/// The JSON body to answer an `endpoint.url_validation` notification with.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default, JsonSchema)]
+pub struct UrlValidationResponse {
+    #[serde(rename = "plainToken")]
+    pub plain_token: String,
+    #[serde(rename = "encryptedToken")]
+    pub encrypted_token: String,
+}
+
+/// Verifies the event notifications of an app, with the secret token of its webhook.
+#[derive(Clone)]
+pub struct Webhook {
+    secret: String,
+    tolerance: Duration,
+}
+
+impl Webhook {
+    /// Create a new verifier from the secret token of the app.
+    pub fn new<S>(secret: S) -> Self
+    where
+        S: ToString,
+    {
+        Webhook {
+            secret: secret.to_string(),
+            tolerance: DEFAULT_TOLERANCE,
+        }
+    }
+
+    /// Sets how far the timestamp of a notification may be from the current time, to
+    /// protect against replayed notifications.
+    pub fn with_tolerance(&mut self, tolerance: Duration) -> &mut Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    fn mac(&self, message: &[u8]) -> Hmac<Sha256> {
+        let mut mac = Hmac::<Sha256>::new_from_slice(self.secret.as_bytes())
+            .expect("HMAC can take a key of any size");
+        mac.update(message);
+        mac
+    }
+
+    fn message(timestamp: &str, payload: &[u8]) -> Vec<u8> {
+        [b"v0:", timestamp.as_bytes(), b":", payload].concat()
+    }
+
+    /// Returns the answer to the challenge Zoom sends to validate the endpoint.
+    pub fn validate_url(&self, plain_token: &str) -> UrlValidationResponse {
+        let encrypted_token = self.mac(plain_token.as_bytes()).finalize().into_bytes();
+        UrlValidationResponse {
+            plain_token: plain_token.to_string(),
+            encrypted_token: hex::encode(encrypted_token),
+        }
+    }
+
+    /// Returns the `x-zm-signature` header Zoom would send along with a payload at
+    /// `timestamp`, in seconds since the epoch, to test webhook handlers.
+    pub fn sign(&self, payload: &[u8], timestamp: u64) -> String {
+        let message = Self::message(&timestamp.to_string(), payload);
+        let signature = self.mac(&message).finalize().into_bytes();
+        format!("v0={}", hex::encode(signature))
+    }
+
+    /// Verifies the `x-zm-signature` and `x-zm-request-timestamp` headers of a
+    /// notification against its raw body.
+    pub fn verify(
+        &self,
+        payload: &[u8],
+        timestamp: &str,
+        signature: &str,
+    ) -> Result<(), WebhookError> {
+        let timestamp = timestamp.trim();
+        let seconds = timestamp
+            .parse::<u64>()
+            .map_err(|_| WebhookError::InvalidHeader)?;
+        let signature = signature
+            .trim()
+            .strip_prefix("v0=")
+            .and_then(|signature| hex::decode(signature).ok())
+            .ok_or(WebhookError::InvalidHeader)?;
+
+        self.mac(&Self::message(timestamp, payload))
+            .verify_slice(&signature)
+            .map_err(|_| WebhookError::SignatureMismatch)?;
+
+        let signed_at = SystemTime::UNIX_EPOCH + Duration::from_secs(seconds);
+        let age = match SystemTime::now().duration_since(signed_at) {
+            Ok(age) => age,
+            Err(e) => e.duration(),
+        };
+        if age > self.tolerance {
+            return Err(WebhookError::TimestampOutsideTolerance);
+        }
+
+        Ok(())
+    }
+
+    /// Verifies a notification and parses its body, from its headers.
+    ///
+    /// Zoom signs `v0:{timestamp}:{body}`, and sends the signature in `x-zm-signature`
+    /// with the timestamp in `x-zm-request-timestamp`.
+    pub fn construct_event<I, K, V>(
+        &self,
+        headers: I,
+        payload: &[u8],
+    ) -> Result<WebhookEvent, WebhookError>
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: AsRef<str>,
+        V: AsRef<[u8]>,
+    {
+        let [signature, timestamp] =
+            crate::utils::find_headers(headers, [SIGNATURE_HEADER, TIMESTAMP_HEADER]);
+        let signature = signature.ok_or(WebhookError::MissingHeader(SIGNATURE_HEADER))?;
+        let timestamp = timestamp.ok_or(WebhookError::MissingHeader(TIMESTAMP_HEADER))?;
+
+        self.verify(payload, &timestamp, &signature)?;
+        parse_event(payload)
+    }
+}
+
+/// Parses the body of a notification into an event, without verifying it.
+pub fn parse_event(payload: &[u8]) -> Result<WebhookEvent, WebhookError> {
+    #[derive(Deserialize)]
+    struct Challenge {
+        payload: UrlValidation,
+    }
+
+    let event = serde_json::from_slice::<serde_json::Value>(payload)?;
+    let kind = event.get("event").and_then(|kind| kind.as_str());
+    Ok(match kind {
+        Some("endpoint.url_validation") => {
+            let Challenge { payload } = serde_json::from_value(event)?;
+            WebhookEvent::UrlValidation(payload)
+        }
+        Some("meeting.ended") => WebhookEvent::MeetingEnded(serde_json::from_value(event)?),
+        Some("meeting.started") => WebhookEvent::MeetingStarted(serde_json::from_value(event)?),
+        Some("phone.callee_ended") => {
+            WebhookEvent::PhoneCalleeEnded(serde_json::from_value(event)?)
+        }
+        Some("phone.caller_ended") => {
+            WebhookEvent::PhoneCallerEnded(serde_json::from_value(event)?)
+        }
+        Some("recording.completed") => {
+            WebhookEvent::RecordingCompleted(serde_json::from_value(event)?)
+        }
+        _ => WebhookEvent::Other(event),
+    })
+}
@@ -1,6 +1,661 @@
 use crate::Client;
 use crate::ClientResult;
 
+/// The parameters of [`Webinars::get`], set with a builder.
+pub struct WebinarsGetRequest<'a> {
+    client: &'a Webinars,
+    user_id: &'a str,
+    page_size: Option<i64>,
+    page_number: Option<i64>,
+}
+
+impl<'a> WebinarsGetRequest<'a> {
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Sets the `page_number` query parameter.
+    pub fn page_number(mut self, page_number: i64) -> Self {
+        self.page_number = Some(page_number);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::Domains>> {
+        let Self {
+            client,
+            user_id,
+            page_size,
+            page_number,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = page_number {
+            query_args.push(("page_number".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_size {
+            query_args.push(("page_size".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/users/{}/webinars?{}",
+                crate::progenitor_support::encode_path(user_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Webinars::webinar`], set with a builder.
+pub struct WebinarsWebinarRequest<'a> {
+    client: &'a Webinars,
+    webinar_id: i64,
+    occurrence_id: Option<&'a str>,
+    show_previous_occurrences: Option<bool>,
+}
+
+impl<'a> WebinarsWebinarRequest<'a> {
+    /// Sets the `occurrence_id` query parameter.
+    pub fn occurrence_id(mut self, occurrence_id: &'a str) -> Self {
+        self.occurrence_id = Some(occurrence_id);
+        self
+    }
+
+    /// Sets the `show_previous_occurrences` query parameter.
+    pub fn show_previous_occurrences(mut self, show_previous_occurrences: bool) -> Self {
+        self.show_previous_occurrences = Some(show_previous_occurrences);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::WebinarResponseAllOf>> {
+        let Self {
+            client,
+            webinar_id,
+            occurrence_id,
+            show_previous_occurrences,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = occurrence_id {
+            query_args.push(("occurrence_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = show_previous_occurrences {
+            query_args.push(("show_previous_occurrences".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/webinars/{}?{}",
+                crate::progenitor_support::encode_path(&webinar_id.to_string()),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Webinars::delete`], set with a builder.
+pub struct WebinarsDeleteRequest<'a> {
+    client: &'a Webinars,
+    webinar_id: i64,
+    occurrence_id: Option<&'a str>,
+    cancel_webinar_reminder: Option<&'a str>,
+}
+
+impl<'a> WebinarsDeleteRequest<'a> {
+    /// Sets the `occurrence_id` query parameter.
+    pub fn occurrence_id(mut self, occurrence_id: &'a str) -> Self {
+        self.occurrence_id = Some(occurrence_id);
+        self
+    }
+
+    /// Sets the `cancel_webinar_reminder` query parameter.
+    pub fn cancel_webinar_reminder(mut self, cancel_webinar_reminder: &'a str) -> Self {
+        self.cancel_webinar_reminder = Some(cancel_webinar_reminder);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            webinar_id,
+            occurrence_id,
+            cancel_webinar_reminder,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = cancel_webinar_reminder {
+            query_args.push(("cancel_webinar_reminder".to_string(), v.to_string()));
+        }
+        if let Some(v) = occurrence_id {
+            query_args.push(("occurrence_id".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/webinars/{}?{}",
+                crate::progenitor_support::encode_path(&webinar_id.to_string()),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .delete(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Webinars::update`], set with a builder.
+pub struct WebinarsUpdateRequest<'a> {
+    client: &'a Webinars,
+    webinar_id: i64,
+    occurrence_id: Option<&'a str>,
+}
+
+impl<'a> WebinarsUpdateRequest<'a> {
+    /// Sets the `occurrence_id` query parameter.
+    pub fn occurrence_id(mut self, occurrence_id: &'a str) -> Self {
+        self.occurrence_id = Some(occurrence_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            webinar_id,
+            occurrence_id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = occurrence_id {
+            query_args.push(("occurrence_id".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/webinars/{}?{}",
+                crate::progenitor_support::encode_path(&webinar_id.to_string()),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .patch(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Webinars::list_participants`], set with a builder.
+pub struct WebinarsListParticipantsRequest<'a> {
+    client: &'a Webinars,
+    webinar_id: &'a str,
+    page_size: Option<i64>,
+    next_page_token: Option<&'a str>,
+}
+
+impl<'a> WebinarsListParticipantsRequest<'a> {
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.next_page_token = Some(next_page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<Vec<crate::types::Participants>>> {
+        let Self {
+            client,
+            webinar_id,
+            page_size,
+            next_page_token,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = next_page_token {
+            query_args.push(("next_page_token".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_size {
+            query_args.push(("page_size".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/past_webinars/{}/participants?{}",
+                crate::progenitor_support::encode_path(webinar_id),
+                query_
+            ),
+            None,
+        );
+        let resp: crate::Response<crate::types::ListWebinarParticipantsResponse> = client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await?;
+
+        // Return our response data.
+        Ok(crate::Response::new(
+            resp.status,
+            resp.headers,
+            resp.body.participants.to_vec(),
+        ))
+    }
+}
+
+/// The parameters of [`Webinars::registrant`], set with a builder.
+pub struct WebinarsRegistrantRequest<'a> {
+    client: &'a Webinars,
+    webinar_id: &'a str,
+    occurrence_id: Option<&'a str>,
+    status: Option<crate::types::MeetingRegistrantsStatus>,
+    tracking_source_id: Option<&'a str>,
+    page_size: Option<i64>,
+    page_number: Option<i64>,
+    next_page_token: Option<&'a str>,
+}
+
+impl<'a> WebinarsRegistrantRequest<'a> {
+    /// Sets the `occurrence_id` query parameter.
+    pub fn occurrence_id(mut self, occurrence_id: &'a str) -> Self {
+        self.occurrence_id = Some(occurrence_id);
+        self
+    }
+
+    /// Sets the `status` query parameter.
+    pub fn status(mut self, status: crate::types::MeetingRegistrantsStatus) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Sets the `tracking_source_id` query parameter.
+    pub fn tracking_source_id(mut self, tracking_source_id: &'a str) -> Self {
+        self.tracking_source_id = Some(tracking_source_id);
+        self
+    }
+
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Sets the `page_number` query parameter.
+    pub fn page_number(mut self, page_number: i64) -> Self {
+        self.page_number = Some(page_number);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.next_page_token = Some(next_page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::Domains>> {
+        let Self {
+            client,
+            webinar_id,
+            occurrence_id,
+            status,
+            tracking_source_id,
+            page_size,
+            page_number,
+            next_page_token,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = next_page_token {
+            query_args.push(("next_page_token".to_string(), v.to_string()));
+        }
+        if let Some(v) = occurrence_id {
+            query_args.push(("occurrence_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_number {
+            query_args.push(("page_number".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_size {
+            query_args.push(("page_size".to_string(), v.to_string()));
+        }
+        if let Some(v) = status {
+            query_args.push(("status".to_string(), v.to_string()));
+        }
+        if let Some(v) = tracking_source_id {
+            query_args.push(("tracking_source_id".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/webinars/{}/registrants?{}",
+                crate::progenitor_support::encode_path(webinar_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Webinars::registrant_create`], set with a builder.
+pub struct WebinarsRegistrantCreateRequest<'a> {
+    client: &'a Webinars,
+    webinar_id: &'a str,
+    occurrence_ids: Option<&'a str>,
+}
+
+impl<'a> WebinarsRegistrantCreateRequest<'a> {
+    /// Sets the `occurrence_ids` query parameter.
+    pub fn occurrence_ids(mut self, occurrence_ids: &'a str) -> Self {
+        self.occurrence_ids = Some(occurrence_ids);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(
+        self,
+    ) -> ClientResult<crate::Response<crate::types::WebinarRegistrantCreateResponse>> {
+        let Self {
+            client,
+            webinar_id,
+            occurrence_ids,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = occurrence_ids {
+            query_args.push(("occurrence_ids".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/webinars/{}/registrants?{}",
+                crate::progenitor_support::encode_path(webinar_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .post(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Webinars::registrant_status`], set with a builder.
+pub struct WebinarsRegistrantStatusRequest<'a> {
+    client: &'a Webinars,
+    webinar_id: i64,
+    occurrence_id: Option<&'a str>,
+    body: &'a crate::types::RegistrantStatus,
+}
+
+impl<'a> WebinarsRegistrantStatusRequest<'a> {
+    /// Sets the `occurrence_id` query parameter.
+    pub fn occurrence_id(mut self, occurrence_id: &'a str) -> Self {
+        self.occurrence_id = Some(occurrence_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            webinar_id,
+            occurrence_id,
+            body,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = occurrence_id {
+            query_args.push(("occurrence_id".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/webinars/{}/registrants/status?{}",
+                crate::progenitor_support::encode_path(&webinar_id.to_string()),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .put(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Webinars::registrant_get`], set with a builder.
+pub struct WebinarsRegistrantGetRequest<'a> {
+    client: &'a Webinars,
+    webinar_id: &'a str,
+    registrant_id: &'a str,
+    occurrence_id: Option<&'a str>,
+}
+
+impl<'a> WebinarsRegistrantGetRequest<'a> {
+    /// Sets the `occurrence_id` query parameter.
+    pub fn occurrence_id(mut self, occurrence_id: &'a str) -> Self {
+        self.occurrence_id = Some(occurrence_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::Domains>> {
+        let Self {
+            client,
+            webinar_id,
+            registrant_id,
+            occurrence_id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = occurrence_id {
+            query_args.push(("occurrence_id".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/webinars/{}/registrants/{}?{}",
+                crate::progenitor_support::encode_path(webinar_id),
+                crate::progenitor_support::encode_path(registrant_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Webinars::delete_registrant`], set with a builder.
+pub struct WebinarsDeleteRegistrantRequest<'a> {
+    client: &'a Webinars,
+    webinar_id: &'a str,
+    registrant_id: &'a str,
+    occurrence_id: Option<&'a str>,
+}
+
+impl<'a> WebinarsDeleteRegistrantRequest<'a> {
+    /// Sets the `occurrence_id` query parameter.
+    pub fn occurrence_id(mut self, occurrence_id: &'a str) -> Self {
+        self.occurrence_id = Some(occurrence_id);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<()>> {
+        let Self {
+            client,
+            webinar_id,
+            registrant_id,
+            occurrence_id,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = occurrence_id {
+            query_args.push(("occurrence_id".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/webinars/{}/registrants/{}?{}",
+                crate::progenitor_support::encode_path(webinar_id),
+                crate::progenitor_support::encode_path(registrant_id),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .delete(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
+/// The parameters of [`Webinars::absentee`], set with a builder.
+pub struct WebinarsAbsenteeRequest<'a> {
+    client: &'a Webinars,
+    occurrence_id: Option<&'a str>,
+    page_size: Option<i64>,
+    next_page_token: Option<&'a str>,
+    webinar_uuid: &'a str,
+}
+
+impl<'a> WebinarsAbsenteeRequest<'a> {
+    /// Sets the `occurrence_id` query parameter.
+    pub fn occurrence_id(mut self, occurrence_id: &'a str) -> Self {
+        self.occurrence_id = Some(occurrence_id);
+        self
+    }
+
+    /// Sets the `page_size` query parameter.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Sets the `next_page_token` query parameter.
+    pub fn next_page_token(mut self, next_page_token: &'a str) -> Self {
+        self.next_page_token = Some(next_page_token);
+        self
+    }
+
+    /// Sends the request, with the parameters that were set.
+    pub async fn send(self) -> ClientResult<crate::Response<crate::types::Domains>> {
+        let Self {
+            client,
+            occurrence_id,
+            page_size,
+            next_page_token,
+            webinar_uuid,
+        } = self;
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(v) = next_page_token {
+            query_args.push(("next_page_token".to_string(), v.to_string()));
+        }
+        if let Some(v) = occurrence_id {
+            query_args.push(("occurrence_id".to_string(), v.to_string()));
+        }
+        if let Some(v) = page_size {
+            query_args.push(("page_size".to_string(), v.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = client.client.url(
+            &format!(
+                "/past_webinars/{}/absentees?{}",
+                crate::progenitor_support::encode_path(webinar_uuid),
+                query_
+            ),
+            None,
+        );
+        client
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+}
+
 pub struct Webinars {
     pub client: Client,
 }
@@ -66,6 +721,30 @@ impl Webinars {
             )
             .await
     }
+    /**
+     * List webinars.
+     *
+     * This function performs a `GET` to the `/users/{userId}/webinars` endpoint.
+     *
+     * As opposed to `get`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Use this API to list all the webinars that are scheduled by or on-behalf a user (webinar host). For user-level apps, pass [the `me` value](https://marketplace.zoom.us/docs/api-reference/using-zoom-apis#mekeyword) instead of the `userId` parameter.
+     *
+     * Zoom users with a [Webinar Plan](https://zoom.us/webinar) have access to creating and managing webinars. Webinars allow a host to broadcast a Zoom meeting to up to 10,000 attendees.
+     *
+     * **Scopes:** `webinar:read:admin`, `webinar:read`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     *
+     * **Prerequisites:**
+     * * Pro or higher plan with a Webinar Add-on.
+     */
+    pub fn get_request<'a>(&'a self, user_id: &'a str) -> WebinarsGetRequest<'a> {
+        WebinarsGetRequest {
+            client: self,
+            user_id,
+            page_size: None,
+            page_number: None,
+        }
+    }
     /**
      * Create a webinar.
      *
@@ -156,6 +835,26 @@ impl Webinars {
             )
             .await
     }
+    /**
+     * Get a webinar.
+     *
+     * This function performs a `GET` to the `/webinars/{webinarId}` endpoint.
+     *
+     * As opposed to `webinar`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Zoom users with a [Webinar Plan](https://zoom.us/webinar) have access to creating and managing Webinars. Webinar allows a host to broadcast a Zoom meeting to up to 10,000 attendees.<br>Use this API to get details of a scheduled webinar.<br><br>
+     * **Scopes:** `webinar:read:admin` `webinar:read`<br>
+     *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Light`<br>**Prerequisites:**
+     * * Pro or higher plan with a Webinar Add-on.
+     */
+    pub fn webinar_request(&self, webinar_id: i64) -> WebinarsWebinarRequest<'_> {
+        WebinarsWebinarRequest {
+            client: self,
+            webinar_id,
+            occurrence_id: None,
+            show_previous_occurrences: None,
+        }
+    }
     /**
      * Delete a webinar.
      *
@@ -213,6 +912,28 @@ impl Webinars {
             )
             .await
     }
+    /**
+     * Delete a webinar.
+     *
+     * This function performs a `DELETE` to the `/webinars/{webinarId}` endpoint.
+     *
+     * As opposed to `delete`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Delete a Webinar.<br><br>
+     * **Scopes:** `webinar:write:admin` `webinar:write`<br>
+     *  
+     *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Light`<br>
+     * **Prerequisites:**<br>
+     * * Pro or higher plan with a Webinar Add-on.
+     */
+    pub fn delete_request(&self, webinar_id: i64) -> WebinarsDeleteRequest<'_> {
+        WebinarsDeleteRequest {
+            client: self,
+            webinar_id,
+            occurrence_id: None,
+            cancel_webinar_reminder: None,
+        }
+    }
     /**
      * Update a webinar.
      *
@@ -259,6 +980,28 @@ impl Webinars {
             )
             .await
     }
+    /**
+     * Update a webinar.
+     *
+     * This function performs a `PATCH` to the `/webinars/{webinarId}` endpoint.
+     *
+     * As opposed to `update`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Zoom users with a [Webinar Plan](https://zoom.us/webinar) have access to creating and managing Webinars. Webinar allows a host to broadcast a Zoom meeting to up to 10,000 attendees.<br>
+     * Use this API to make updates to a scheduled Webinar.<br><br>
+     * **Scopes:** `webinar:write:admin` `webinar:write`<br>
+     *  
+     *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Light`<br>
+     * **Prerequisites:**<br>
+     * * Pro or higher plan with a Webinar Add-on.
+     */
+    pub fn update_request(&self, webinar_id: i64) -> WebinarsUpdateRequest<'_> {
+        WebinarsUpdateRequest {
+            client: self,
+            webinar_id,
+            occurrence_id: None,
+        }
+    }
     /**
      * List webinar participants.
      *
@@ -322,6 +1065,36 @@ impl Webinars {
             resp.body.participants.to_vec(),
         ))
     }
+    /**
+     * List webinar participants.
+     *
+     * This function performs a `GET` to the `/past_webinars/{webinarId}/participants` endpoint.
+     *
+     * As opposed to `list_participants`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Use this API to list all the participants who attended a webinar hosted in the past. <br>
+     *
+     * **Prerequisites:**
+     * * Pro or higher plan with a Webinar Add-on.<br>
+     * **Scopes:** `webinar:read:admin` `webinar:read`<br>
+     * <br>
+     * **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`<br>
+     *
+     *
+     *
+     *
+     */
+    pub fn list_participants_request<'a>(
+        &'a self,
+        webinar_id: &'a str,
+    ) -> WebinarsListParticipantsRequest<'a> {
+        WebinarsListParticipantsRequest {
+            client: self,
+            webinar_id,
+            page_size: None,
+            next_page_token: None,
+        }
+    }
     /**
      * List webinar participants.
      *
@@ -352,11 +1125,11 @@ impl Webinars {
             ),
             None,
         );
-        let crate::Response::<crate::types::ListWebinarParticipantsResponse> {
-            mut status,
-            mut headers,
-            mut body,
-        } = self
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let mut pages = crate::utils::Pages::new(self.client.page_limits);
+        pages.visit(&url)?;
+
+        let mut response: crate::Response<crate::types::ListWebinarParticipantsResponse> = self
             .client
             .get(
                 &url,
@@ -366,56 +1139,88 @@ impl Webinars {
                 },
             )
             .await?;
-
-        let mut participants = body.participants;
-        let mut page = body.next_page_token;
+        let mut participants = Vec::new();
 
         // Paginate if we should.
-        while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                crate::Response::<crate::types::ListWebinarParticipantsResponse> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}?next_page_token={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
+        loop {
+            let page = if response.body.next_page_token.is_empty() {
+                None
             } else {
-                crate::Response::<crate::types::ListWebinarParticipantsResponse> {
-                    status,
-                    headers,
-                    body,
-                } = self
-                    .client
-                    .get(
-                        &format!("{}&next_page_token={}", url, page),
-                        crate::Message {
-                            body: None,
-                            content_type: None,
-                        },
-                    )
-                    .await?;
-            }
-
-            participants.append(&mut body.participants);
+                Some(format!(
+                    "{}{}next_page_token={}",
+                    url, sep, response.body.next_page_token
+                ))
+            };
+            let more = pages.keep(&mut response.body.participants);
+            participants.append(&mut response.body.participants);
 
-            if !body.next_page_token.is_empty() && body.next_page_token != page {
-                page = body.next_page_token.to_string();
-            } else {
-                page = "".to_string();
-            }
+            let next = match page {
+                Some(next) if more && pages.visit(&next)? => next,
+                _ => break,
+            };
+            response = self
+                .client
+                .get(
+                    &next,
+                    crate::Message {
+                        body: None,
+                        content_type: None,
+                    },
+                )
+                .await?;
         }
 
         // Return our response data.
-        Ok(crate::Response::new(status, headers, participants))
+        Ok(crate::Response::new(
+            response.status,
+            response.headers,
+            participants,
+        ))
+    }
+    /**
+     * List webinar participants.
+     *
+     * This function performs a `GET` to the `/past_webinars/{webinarId}/participants` endpoint.
+     *
+     * As opposed to `list_all_participants`, this function returns a stream that only fetches the next page of the request once the items of the current one have been consumed.
+     *
+     * Use this API to list all the participants who attended a webinar hosted in the past. <br>
+     *
+     * **Prerequisites:**
+     * * Pro or higher plan with a Webinar Add-on.<br>
+     * **Scopes:** `webinar:read:admin` `webinar:read`<br>
+     * <br>
+     * **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`<br>
+     *
+     *
+     *
+     *
+     */
+    pub fn list_all_participants_stream(
+        &self,
+        webinar_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Participants>> + Unpin + '_ {
+        let url = self.client.url(
+            &format!(
+                "/past_webinars/{}/participants",
+                crate::progenitor_support::encode_path(webinar_id),
+            ),
+            None,
+        );
+        let sep = if url.contains('?') { '&' } else { '?' };
+        let next = format!("{}{}next_page_token=", url, sep);
+        self.client.get_pages_stream(
+            url,
+            move |body: crate::types::ListWebinarParticipantsResponse| {
+                let page = if body.next_page_token.is_empty() {
+                    None
+                } else {
+                    Some(format!("{}{}", next, body.next_page_token))
+                };
+
+                (body.participants, page)
+            },
+        )
     }
     /**
      * Update webinar status.
@@ -687,6 +1492,34 @@ impl Webinars {
             )
             .await
     }
+    /**
+     * List webinar registrants.
+     *
+     * This function performs a `GET` to the `/webinars/{webinarId}/registrants` endpoint.
+     *
+     * As opposed to `registrant`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Zoom users with a [Webinar Plan](https://zoom.us/webinar) have access to creating and managing Webinars. Webinar allows a host to broadcast a Zoom meeting to up to 10,000 attendees. Scheduling a [Webinar with registration](https://support.zoom.us/hc/en-us/articles/204619915-Scheduling-a-Webinar-with-Registration) requires your registrants to complete a brief form before receiving the link to join the Webinar.<br>
+     * Use this API to list all the users that have registered for a webinar.<br><br>
+     * **Prerequisites:**
+     * * Pro or higher plan with a Webinar Add-on.<br>
+     * **Scopes:** `webinar:read:admin` `webinar:read`<br>
+     *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`<br>
+     *
+     *
+     */
+    pub fn registrant_request<'a>(&'a self, webinar_id: &'a str) -> WebinarsRegistrantRequest<'a> {
+        WebinarsRegistrantRequest {
+            client: self,
+            webinar_id,
+            occurrence_id: None,
+            status: None,
+            tracking_source_id: None,
+            page_size: None,
+            page_number: None,
+            next_page_token: None,
+        }
+    }
     /**
      * Add a webinar registrant.
      *
@@ -732,6 +1565,30 @@ impl Webinars {
             )
             .await
     }
+    /**
+     * Add a webinar registrant.
+     *
+     * This function performs a `POST` to the `/webinars/{webinarId}/registrants` endpoint.
+     *
+     * As opposed to `registrant_create`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Zoom users with a [Webinar Plan](https://zoom.us/webinar) have access to creating and managing Webinars. Webinar allows a host to broadcast a Zoom meeting to up to 10,000 attendees. Scheduling a [Webinar with registration](https://support.zoom.us/hc/en-us/articles/204619915-Scheduling-a-Webinar-with-Registration) requires your registrants to complete a brief form before receiving the link to join the Webinar.<br>Use this API to create and submit the registration of a user for a webinar.<br><br>
+     * **Scopes:** `webinar:write:admin` `webinar:write`<br>
+     *  
+     *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Light`<br>
+     * **Prerequisites:**
+     * * Pro or higher plan with a Webinar Add-on.
+     */
+    pub fn registrant_create_request<'a>(
+        &'a self,
+        webinar_id: &'a str,
+    ) -> WebinarsRegistrantCreateRequest<'a> {
+        WebinarsRegistrantCreateRequest {
+            client: self,
+            webinar_id,
+            occurrence_ids: None,
+        }
+    }
     /**
      * Perform batch registration.
      *
@@ -825,6 +1682,32 @@ impl Webinars {
             )
             .await
     }
+    /**
+     * Update registrant's status.
+     *
+     * This function performs a `PUT` to the `/webinars/{webinarId}/registrants/status` endpoint.
+     *
+     * As opposed to `registrant_status`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Update a webinar registrant's status. Using this API, you can specify whether you want to approve a registration, deny a registration or cancel a previously approved registration.<br><br>
+     * **Scopes:** `webinar:write:admin` `webinar:write`<br>
+     *  
+     *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`<br>
+     *
+     *
+     */
+    pub fn registrant_status_request<'a>(
+        &'a self,
+        webinar_id: i64,
+        body: &'a crate::types::RegistrantStatus,
+    ) -> WebinarsRegistrantStatusRequest<'a> {
+        WebinarsRegistrantStatusRequest {
+            client: self,
+            webinar_id,
+            occurrence_id: None,
+            body,
+        }
+    }
     /**
      * List past webinar instances.
      *
@@ -1186,6 +2069,32 @@ impl Webinars {
             )
             .await
     }
+    /**
+     * Get a webinar registrant.
+     *
+     * This function performs a `GET` to the `/webinars/{webinarId}/registrants/{registrantId}` endpoint.
+     *
+     * As opposed to `registrant_get`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Zoom users with a [Webinar Plan](https://zoom.us/webinar) have access to creating and managing Webinars. Webinar allows a host to broadcast a Zoom meeting to up to 10,000 attendees. Scheduling a [Webinar with registration](https://support.zoom.us/hc/en-us/articles/204619915-Scheduling-a-Webinar-with-Registration) requires your registrants to complete a brief form before receiving the link to join the Webinar.<br>Use this API to get details on a specific user who has registered for the Webinar.<br><br>
+     * **Scopes:** `webinar:read:admin` `webinar:read`<br>
+     *  
+     *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Light`<br>
+     * **Prerequisites:**<br>
+     * * The account must have a Webinar plan.
+     */
+    pub fn registrant_get_request<'a>(
+        &'a self,
+        webinar_id: &'a str,
+        registrant_id: &'a str,
+    ) -> WebinarsRegistrantGetRequest<'a> {
+        WebinarsRegistrantGetRequest {
+            client: self,
+            webinar_id,
+            registrant_id,
+            occurrence_id: None,
+        }
+    }
     /**
      * Delete a webinar registrant.
      *
@@ -1232,6 +2141,30 @@ impl Webinars {
             )
             .await
     }
+    /**
+     * Delete a webinar registrant.
+     *
+     * This function performs a `DELETE` to the `/webinars/{webinarId}/registrants/{registrantId}` endpoint.
+     *
+     * As opposed to `delete_registrant`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * Delete a webinar registrant.<br><br>
+     * **Scopes**: `webinar:write:admin` `webinar:write`<br>
+     *  <br>
+     *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Light`
+     */
+    pub fn delete_registrant_request<'a>(
+        &'a self,
+        webinar_id: &'a str,
+        registrant_id: &'a str,
+    ) -> WebinarsDeleteRegistrantRequest<'a> {
+        WebinarsDeleteRegistrantRequest {
+            client: self,
+            webinar_id,
+            registrant_id,
+            occurrence_id: None,
+        }
+    }
     /**
      * Get webinar absentees.
      *
@@ -1284,6 +2217,26 @@ impl Webinars {
             )
             .await
     }
+    /**
+     * Get webinar absentees.
+     *
+     * This function performs a `GET` to the `/past_webinars/{WebinarUUID}/absentees` endpoint.
+     *
+     * As opposed to `absentee`, this function returns a builder, so only the parameters that are needed have to be set, before sending the request with `send`.
+     *
+     * List absentees of a webinar.<br><br>
+     * **Scopes:** `webinar:read:admin` `webinar:read`<br>
+     *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Heavy`
+     */
+    pub fn absentee_request<'a>(&'a self, webinar_uuid: &'a str) -> WebinarsAbsenteeRequest<'a> {
+        WebinarsAbsenteeRequest {
+            client: self,
+            occurrence_id: None,
+            page_size: None,
+            next_page_token: None,
+            webinar_uuid,
+        }
+    }
     /**
      * Get webinar tracking sources.
      *
@@ -1,4 +1,16 @@
+use std::mem;
+
 use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+use futures::StreamExt;
+use wiremock::{
+    matchers::{method, path, query_param, query_param_is_missing},
+    Mock, MockServer, ResponseTemplate,
+};
+
+use zoom_api::{
+    webhooks::{self, Webhook, WebhookError, WebhookEvent},
+    Client, ClientError,
+};
 
 const RECORDED_MEETINGS: &str = r#"{
   "from": "2021-07-16",
@@ -83,3 +95,183 @@ fn test_deserialize_recorded_meetings() {
         deserialized.from.unwrap()
     );
 }
+
+async fn mount_phones_page(server: &MockServer, token: Option<&str>, phone: &str, next: &str) {
+    let mock = Mock::given(method("GET")).and(path("/phone/common_area_phones"));
+    let mock = match token {
+        Some(token) => mock.and(query_param("next_page_token", token)),
+        None => mock.and(query_param_is_missing("next_page_token")),
+    };
+
+    mock.respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+        "page_size": 1,
+        "total_records": 3,
+        "next_page_token": next,
+        "common_area_phones": [{ "id": phone }]
+    })))
+    .mount(server)
+    .await;
+}
+
+fn pages_client(server: &MockServer) -> Client {
+    let mut client = Client::new("client_id", "client_secret", "redirect_uri", "token", "");
+    client.with_host_override(server.uri());
+    client
+}
+
+#[tokio::test]
+async fn test_get_all_fails_on_next_page_token_cycle() {
+    let server = MockServer::start().await;
+
+    // The tokens go A, B and then back to A.
+    mount_phones_page(&server, None, "1", "A").await;
+    mount_phones_page(&server, Some("A"), "2", "B").await;
+    mount_phones_page(&server, Some("B"), "3", "A").await;
+
+    match pages_client(&server).common_area_phones().list_all().await {
+        Err(ClientError::IncompletePages { page_token }) => {
+            assert!(page_token.ends_with("next_page_token=A"))
+        }
+        result => unreachable!("Expected incomplete pages, got {:?}", result),
+    }
+    assert_eq!(server.received_requests().await.unwrap().len(), 3);
+
+    mem::drop(server)
+}
+
+#[tokio::test]
+async fn test_stream_respects_max_pages() {
+    let server = MockServer::start().await;
+
+    mount_phones_page(&server, None, "1", "A").await;
+    mount_phones_page(&server, Some("A"), "2", "B").await;
+    mount_phones_page(&server, Some("B"), "3", "").await;
+
+    let mut client = pages_client(&server);
+    client.with_max_pages(2);
+    let ids = client
+        .common_area_phones()
+        .list_all_stream()
+        .map(|phone| phone.unwrap().id)
+        .collect::<Vec<_>>()
+        .await;
+    assert_eq!(ids, vec!["1", "2"]);
+    assert_eq!(server.received_requests().await.unwrap().len(), 2);
+
+    mem::drop(server)
+}
+
+const SECRET_TOKEN: &str = "nbCsHzSKQx2bM1SmNu9ShA";
+
+fn now() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::SystemTime::UNIX_EPOCH)
+        .unwrap()
+        .as_secs()
+}
+
+fn signed_headers(webhook: &Webhook, payload: &[u8], timestamp: u64) -> Vec<(String, String)> {
+    vec![
+        ("x-zm-request-timestamp".to_string(), timestamp.to_string()),
+        (
+            "x-zm-signature".to_string(),
+            webhook.sign(payload, timestamp),
+        ),
+    ]
+}
+
+#[test]
+fn test_webhook_answers_url_validation() {
+    let payload = include_bytes!("fixtures/url_validation.json");
+    let webhook = Webhook::new(SECRET_TOKEN);
+
+    let event = webhook
+        .construct_event(signed_headers(&webhook, payload, now()), payload)
+        .unwrap();
+    let WebhookEvent::UrlValidation(validation) = event else {
+        unreachable!("Expected a URL validation, got {:?}", event);
+    };
+
+    let response = webhook.validate_url(&validation.plain_token);
+    assert_eq!(
+        serde_json::to_value(response).unwrap(),
+        serde_json::json!({
+            "plainToken": "qgg8vlvZRS6UYooatFL8Aw",
+            "encryptedToken": "ff8452a4a9714ae292c98a6a8787f32ac4956d8e54f3241df4ee79799ffeec9b",
+        })
+    );
+}
+
+#[test]
+fn test_webhook_rejects_bad_signatures() {
+    let payload = include_bytes!("fixtures/meeting_started.json");
+    let mut webhook = Webhook::new(SECRET_TOKEN);
+
+    let headers = signed_headers(&Webhook::new("another secret"), payload, now());
+    assert!(matches!(
+        webhook.construct_event(headers, payload),
+        Err(WebhookError::SignatureMismatch)
+    ));
+
+    let headers = signed_headers(&webhook, payload, now() - 600);
+    assert!(matches!(
+        webhook.construct_event(headers.clone(), payload),
+        Err(WebhookError::TimestampOutsideTolerance)
+    ));
+    webhook.with_tolerance(std::time::Duration::from_secs(3600));
+    assert!(webhook.construct_event(headers, payload).is_ok());
+
+    let headers = vec![("x-zm-request-timestamp", now().to_string())];
+    assert!(matches!(
+        webhook.construct_event(headers, payload),
+        Err(WebhookError::MissingHeader("x-zm-signature"))
+    ));
+}
+
+#[test]
+fn test_webhook_events_are_typed() {
+    let webhook = Webhook::new(SECRET_TOKEN);
+
+    let payload = include_bytes!("fixtures/meeting_started.json");
+    let event = webhook
+        .construct_event(signed_headers(&webhook, payload, now()), payload)
+        .unwrap();
+    let WebhookEvent::MeetingStarted(meeting) = event else {
+        unreachable!("Expected a meeting, got {:?}", event);
+    };
+    assert_eq!(meeting.payload.object.id, 1234567890);
+    assert_eq!(meeting.payload.object.meeting.topic, "My Meeting");
+    assert_eq!(meeting.payload.object.meeting.type_, 2);
+
+    let payload = include_bytes!("fixtures/recording_completed.json");
+    let event = webhooks::parse_event(payload).unwrap();
+    let WebhookEvent::RecordingCompleted(recording) = event else {
+        unreachable!("Expected a recording, got {:?}", event);
+    };
+    assert!(!recording.download_token.is_empty());
+    assert_eq!(recording.payload.object.host_email, "jchill@example.com");
+    assert_eq!(recording.payload.object.id, 1234567890);
+    let meeting = &recording.payload.object.meeting;
+    assert_eq!(meeting.topic, "My Personal Recording");
+    assert_eq!(meeting.recording_files.len(), 2);
+    assert_eq!(meeting.recording_files[1].file_size, 3081811.0);
+
+    let payload = include_bytes!("fixtures/phone_callee_ended.json");
+    let event = webhooks::parse_event(payload).unwrap();
+    let WebhookEvent::PhoneCalleeEnded(call) = event else {
+        unreachable!("Expected a call, got {:?}", event);
+    };
+    assert_eq!(call.payload.object.call_id, "6998252113337041462");
+    assert_eq!(
+        call.payload.object.callee.as_ref().unwrap().name,
+        "Joe Blow"
+    );
+    assert_eq!(call.payload.object.handup_result, "Call connected");
+
+    let payload =
+        br#"{"event": "user.created", "payload": {"object": {"id": "z8yCxjabcdEFGHfp8uQ"}}}"#;
+    assert!(matches!(
+        webhooks::parse_event(payload).unwrap(),
+        WebhookEvent::Other(_)
+    ));
+}